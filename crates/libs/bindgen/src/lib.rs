@@ -557,14 +557,16 @@ fn expand_input(input: &[&str]) -> Vec<File> {
     let mut input = vec![];
 
     if use_default {
-        input = [
-            std::include_bytes!("../default/Windows.winmd").to_vec(),
-            std::include_bytes!("../default/Windows.Win32.winmd").to_vec(),
-            std::include_bytes!("../default/Windows.Wdk.winmd").to_vec(),
-        ]
-        .into_iter()
-        .map(|bytes| File::new(bytes).unwrap())
-        .collect();
+        let bytes: [&'static [u8]; 3] = [
+            std::include_bytes!("../default/Windows.winmd"),
+            std::include_bytes!("../default/Windows.Win32.winmd"),
+            std::include_bytes!("../default/Windows.Wdk.winmd"),
+        ];
+
+        input = bytes
+            .into_iter()
+            .map(|bytes| File::from_static(bytes).unwrap())
+            .collect();
     }
 
     for path in &paths {
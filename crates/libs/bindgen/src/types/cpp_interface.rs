@@ -337,6 +337,49 @@ impl CppInterface {
                 }
             });
 
+            let mut names = MethodNames::new();
+
+            let tear_off_methods: Vec<_> = methods.iter().map(|method| match method {
+                CppMethodOrName::Method(method) => {
+                    let name = names.add(method.def);
+                    let signature = method.write_abi(config, true);
+                    let upcall = method.write_upcall(&impl_name, &name);
+
+                    quote! {
+                        unsafe extern "system" fn #name<Identity: windows_core::IUnknownImpl + #impl_name> #signature {
+                            unsafe {
+                                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                                #upcall
+                            }
+                        }
+                    }
+                }
+                _ => quote! {},
+            }).collect();
+
+            let mut names = MethodNames::new();
+
+            let tear_off_fields: Vec<_> = methods
+                .iter()
+                .map(|method| match method {
+                    CppMethodOrName::Method(method) => {
+                        let name = names.add(method.def);
+                        quote! { #name: #name::<Identity>, }
+                    }
+                    CppMethodOrName::Name(method) => {
+                        let name = names.add(*method);
+                        quote! { #name: 0, }
+                    }
+                })
+                .collect();
+
+            let tear_off_base = base_interfaces.last().map(|ty| match ty {
+                Type::IUnknown => quote! { windows_core::IUnknown_Vtbl },
+                Type::Object => quote! { windows_core::IInspectable_Vtbl },
+                Type::CppInterface(ty) => ty.write_vtbl_name(config),
+                rest => panic!("{rest:?}"),
+            });
+
             result.combine( if has_unknown_base {
                 let matches = base_interfaces.iter().filter_map(|ty|{
                     match ty {
@@ -367,6 +410,19 @@ impl CppInterface {
                         }
                     }
                     #cfg
+                    impl<Identity: windows_core::IUnknownImpl + #impl_name> windows_core::imp::TearOffVtable<Identity> for #vtbl_name
+                    where
+                        #tear_off_base: windows_core::imp::TearOffVtable<Identity>
+                    {
+                        const VTABLE: Self = {
+                            #(#tear_off_methods)*
+                            Self {
+                                base__: <#tear_off_base as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+                                #(#tear_off_fields)*
+                            }
+                        };
+                    }
+                    #cfg
                     impl windows_core::RuntimeName for #name {}
                 }
             } else {
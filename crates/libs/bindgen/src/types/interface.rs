@@ -413,6 +413,43 @@ impl Interface {
 
                 let mut names = MethodNames::new();
 
+                let tear_off_fields: Vec<_> = methods
+                    .iter()
+                    .map(|method| match method {
+                        MethodOrName::Method(method) => {
+                            let name = names.add(method.def);
+                            quote! { #name: #name::<#(#generics,)* Identity>, }
+                        }
+                        MethodOrName::Name(method) => {
+                            let name = names.add(*method);
+                            quote! { #name: 0, }
+                        }
+                    })
+                    .collect();
+
+                let mut names = MethodNames::new();
+
+                let tear_off_methods: Vec<_> = methods.iter().map(|method| match method {
+                MethodOrName::Method(method) => {
+                    let name = names.add(method.def);
+                    let signature = method.write_abi(config, true);
+                    let call = quote! { #impl_name::#name };
+                    let upcall = method.write_upcall(call, true);
+
+                    quote! {
+                        unsafe extern "system" fn #name<#constraints Identity: windows_core::IUnknownImpl + #impl_name <#(#generics,)*>> (#signature) -> windows_core::HRESULT {
+                            unsafe {
+                                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                                #upcall
+                            }
+                        }
+                    }
+                }
+                _ => quote! {},
+            }).collect();
+
+                let mut names = MethodNames::new();
+
                 let trait_methods: Vec<_> = methods
                     .iter()
                     .map(|method| match method {
@@ -454,6 +491,17 @@ impl Interface {
                         iid == &<#name as windows_core::Interface>::IID
                     }
                 }
+                #cfg
+                impl<#constraints Identity: windows_core::IUnknownImpl + #impl_name <#(#generics,)*>> windows_core::imp::TearOffVtable<Identity> for #vtbl_name {
+                    const VTABLE: Self = {
+                        #(#tear_off_methods)*
+                        Self {
+                            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+                            #(#tear_off_fields)*
+                            #named_phantoms
+                        }
+                    };
+                }
             });
             }
 
@@ -2,7 +2,7 @@ use super::*;
 
 pub struct File {
     pub(crate) reader: *const Reader,
-    bytes: Vec<u8>,
+    bytes: Bytes,
     strings: usize,
     blobs: usize,
     tables: [Table; 17],
@@ -44,6 +44,16 @@ unsafe impl Sync for File {}
 
 impl File {
     pub fn new(bytes: Vec<u8>) -> Option<Self> {
+        Self::parse(Bytes::Owned(bytes))
+    }
+
+    /// Parses a metadata file that borrows static bytes, such as those embedded with `include_bytes!`, without
+    /// copying them.
+    pub fn from_static(bytes: &'static [u8]) -> Option<Self> {
+        Self::parse(Bytes::Static(bytes))
+    }
+
+    fn parse(bytes: Bytes) -> Option<Self> {
         let mut result = Self {
             bytes,
             reader: std::ptr::null(),
@@ -52,7 +62,7 @@ impl File {
             tables: Default::default(),
         };
 
-        let dos = result.bytes.view_as::<IMAGE_DOS_HEADER>(0)?;
+        let dos = result.bytes.copy_as::<IMAGE_DOS_HEADER>(0)?;
 
         if dos.e_magic != IMAGE_DOS_SIGNATURE
             || result.bytes.copy_as::<u32>(dos.e_lfanew as usize)? != IMAGE_NT_SIGNATURE
@@ -61,7 +71,7 @@ impl File {
         }
 
         let file_offset = dos.e_lfanew as usize + size_of::<u32>();
-        let file = result.bytes.view_as::<IMAGE_FILE_HEADER>(file_offset)?;
+        let file = result.bytes.copy_as::<IMAGE_FILE_HEADER>(file_offset)?;
 
         let optional_offset = file_offset + size_of::<IMAGE_FILE_HEADER>();
 
@@ -69,11 +79,11 @@ impl File {
             IMAGE_NT_OPTIONAL_HDR32_MAGIC => {
                 let optional = result
                     .bytes
-                    .view_as::<IMAGE_OPTIONAL_HEADER32>(optional_offset)?;
+                    .copy_as::<IMAGE_OPTIONAL_HEADER32>(optional_offset)?;
                 (
                     optional.DataDirectory[IMAGE_DIRECTORY_ENTRY_COM_DESCRIPTOR as usize]
                         .VirtualAddress,
-                    result.bytes.view_as_slice_of::<IMAGE_SECTION_HEADER>(
+                    result.bytes.copy_as_vec_of::<IMAGE_SECTION_HEADER>(
                         optional_offset + size_of::<IMAGE_OPTIONAL_HEADER32>(),
                         file.NumberOfSections as usize,
                     )?,
@@ -82,11 +92,11 @@ impl File {
             IMAGE_NT_OPTIONAL_HDR64_MAGIC => {
                 let optional = result
                     .bytes
-                    .view_as::<IMAGE_OPTIONAL_HEADER64>(optional_offset)?;
+                    .copy_as::<IMAGE_OPTIONAL_HEADER64>(optional_offset)?;
                 (
                     optional.DataDirectory[IMAGE_DIRECTORY_ENTRY_COM_DESCRIPTOR as usize]
                         .VirtualAddress,
-                    result.bytes.view_as_slice_of::<IMAGE_SECTION_HEADER>(
+                    result.bytes.copy_as_vec_of::<IMAGE_SECTION_HEADER>(
                         optional_offset + size_of::<IMAGE_OPTIONAL_HEADER64>(),
                         file.NumberOfSections as usize,
                     )?,
@@ -95,8 +105,8 @@ impl File {
             _ => return None,
         };
 
-        let clr = result.bytes.view_as::<IMAGE_COR20_HEADER>(offset_from_rva(
-            section_from_rva(sections, com_virtual_address)?,
+        let clr = result.bytes.copy_as::<IMAGE_COR20_HEADER>(offset_from_rva(
+            section_from_rva(&sections, com_virtual_address)?,
             com_virtual_address,
         ))?;
 
@@ -105,10 +115,10 @@ impl File {
         }
 
         let metadata_offset = offset_from_rva(
-            section_from_rva(sections, clr.MetaData.VirtualAddress)?,
+            section_from_rva(&sections, clr.MetaData.VirtualAddress)?,
             clr.MetaData.VirtualAddress,
         );
-        let metadata = result.bytes.view_as::<METADATA_HEADER>(metadata_offset)?;
+        let metadata = result.bytes.copy_as::<METADATA_HEADER>(metadata_offset)?;
 
         if metadata.signature != METADATA_SIGNATURE {
             return None;
@@ -685,29 +695,32 @@ fn offset_from_rva(section: &IMAGE_SECTION_HEADER, rva: u32) -> usize {
     (rva - section.VirtualAddress + section.PointerToRawData) as usize
 }
 
+// The bytes backing a `File`. Reads are unaligned so that borrowed bytes, which may have any alignment, can be
+// parsed in place.
+enum Bytes {
+    Owned(Vec<u8>),
+    Static(&'static [u8]),
+}
+
+impl std::ops::Deref for Bytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Self::Owned(bytes) => bytes,
+            Self::Static(bytes) => bytes,
+        }
+    }
+}
+
 trait View {
-    fn view_as<T>(&self, offset: usize) -> Option<&T>;
-    fn view_as_slice_of<T>(&self, offset: usize, len: usize) -> Option<&[T]>;
     fn copy_as<T: Copy>(&self, offset: usize) -> Option<T>;
+    fn copy_as_vec_of<T: Copy>(&self, offset: usize, len: usize) -> Option<Vec<T>>;
     fn view_as_str(&self, offset: usize) -> Option<&[u8]>;
     fn is_proper_length<T>(&self, offset: usize) -> Option<()>;
-    fn is_proper_length_and_alignment<T>(&self, offset: usize, count: usize) -> Option<*const T>;
 }
 
 impl View for [u8] {
-    fn view_as<T>(&self, offset: usize) -> Option<&T> {
-        unsafe { Some(&*self.is_proper_length_and_alignment(offset, 1)?) }
-    }
-
-    fn view_as_slice_of<T>(&self, offset: usize, len: usize) -> Option<&[T]> {
-        unsafe {
-            Some(std::slice::from_raw_parts(
-                self.is_proper_length_and_alignment(offset, len)?,
-                len,
-            ))
-        }
-    }
-
     fn copy_as<T>(&self, offset: usize) -> Option<T> {
         self.is_proper_length::<T>(offset)?;
 
@@ -722,6 +735,12 @@ impl View for [u8] {
         }
     }
 
+    fn copy_as_vec_of<T: Copy>(&self, offset: usize, len: usize) -> Option<Vec<T>> {
+        (0..len)
+            .map(|pos| self.copy_as(offset + pos * size_of::<T>()))
+            .collect()
+    }
+
     fn view_as_str(&self, offset: usize) -> Option<&[u8]> {
         let buffer = &self[offset..];
         let index = buffer.iter().position(|c| *c == b'\0')?;
@@ -735,17 +754,6 @@ impl View for [u8] {
             None
         }
     }
-
-    fn is_proper_length_and_alignment<T>(&self, offset: usize, count: usize) -> Option<*const T> {
-        self.is_proper_length::<T>(offset * count)?;
-        let ptr = &self[offset] as *const u8 as *const T;
-
-        if ptr.align_offset(align_of::<T>()) == 0 {
-            Some(ptr)
-        } else {
-            None
-        }
-    }
 }
 
 #[derive(Default)]
@@ -807,7 +815,7 @@ impl Column {
 }
 
 #[repr(C)]
-#[derive(Default)]
+#[derive(Clone, Copy, Default)]
 struct METADATA_HEADER {
     signature: u32,
     major_version: u16,
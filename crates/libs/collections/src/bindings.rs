@@ -78,6 +78,40 @@ impl<T: windows_core::RuntimeType + 'static> IIterable_Vtbl<T> {
         iid == &<IIterable<T> as windows_core::Interface>::IID
     }
 }
+impl<
+        T: windows_core::RuntimeType + 'static,
+        Identity: windows_core::IUnknownImpl + IIterable_Impl<T>,
+    > windows_core::imp::TearOffVtable<Identity> for IIterable_Vtbl<T>
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn First<
+            T: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IIterable_Impl<T>,
+        >(
+            this: *mut core::ffi::c_void,
+            result__: *mut *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IIterable_Impl::First(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<
+                Identity,
+            >>::VTABLE,
+            First: First::<T, Identity>,
+            T: core::marker::PhantomData::<T>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IIterable_Vtbl<T>
@@ -300,6 +334,105 @@ impl<T: windows_core::RuntimeType + 'static> IIterator_Vtbl<T> {
         iid == &<IIterator<T> as windows_core::Interface>::IID
     }
 }
+impl<
+        T: windows_core::RuntimeType + 'static,
+        Identity: windows_core::IUnknownImpl + IIterator_Impl<T>,
+    > windows_core::imp::TearOffVtable<Identity> for IIterator_Vtbl<T>
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Current<
+            T: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IIterator_Impl<T>,
+        >(
+            this: *mut core::ffi::c_void,
+            result__: *mut windows_core::AbiType<T>,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IIterator_Impl::Current(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn HasCurrent<
+            T: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IIterator_Impl<T>,
+        >(
+            this: *mut core::ffi::c_void,
+            result__: *mut bool,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IIterator_Impl::HasCurrent(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveNext<
+            T: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IIterator_Impl<T>,
+        >(
+            this: *mut core::ffi::c_void,
+            result__: *mut bool,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IIterator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMany<
+            T: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IIterator_Impl<T>,
+        >(
+            this: *mut core::ffi::c_void,
+            items_array_size: u32,
+            items: *mut T,
+            result__: *mut u32,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IIterator_Impl::GetMany(
+                    this,
+                    core::slice::from_raw_parts_mut(
+                        core::mem::transmute_copy(&items),
+                        items_array_size as usize,
+                    ),
+                ) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<
+                Identity,
+            >>::VTABLE,
+            Current: Current::<T, Identity>,
+            HasCurrent: HasCurrent::<T, Identity>,
+            MoveNext: MoveNext::<T, Identity>,
+            GetMany: GetMany::<T, Identity>,
+            T: core::marker::PhantomData::<T>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IIterator_Vtbl<T>
@@ -472,6 +605,64 @@ impl<K: windows_core::RuntimeType + 'static, V: windows_core::RuntimeType + 'sta
         iid == &<IKeyValuePair<K, V> as windows_core::Interface>::IID
     }
 }
+impl<
+        K: windows_core::RuntimeType + 'static,
+        V: windows_core::RuntimeType + 'static,
+        Identity: windows_core::IUnknownImpl + IKeyValuePair_Impl<K, V>,
+    > windows_core::imp::TearOffVtable<Identity> for IKeyValuePair_Vtbl<K, V>
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Key<
+            K: windows_core::RuntimeType + 'static,
+            V: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IKeyValuePair_Impl<K, V>,
+        >(
+            this: *mut core::ffi::c_void,
+            result__: *mut windows_core::AbiType<K>,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKeyValuePair_Impl::Key(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Value<
+            K: windows_core::RuntimeType + 'static,
+            V: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IKeyValuePair_Impl<K, V>,
+        >(
+            this: *mut core::ffi::c_void,
+            result__: *mut windows_core::AbiType<V>,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKeyValuePair_Impl::Value(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<
+                Identity,
+            >>::VTABLE,
+            Key: Key::<K, V, Identity>,
+            Value: Value::<K, V, Identity>,
+            K: core::marker::PhantomData::<K>,
+            V: core::marker::PhantomData::<V>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IKeyValuePair_Vtbl<K, V>
@@ -839,104 +1030,257 @@ impl<K: windows_core::RuntimeType + 'static, V: windows_core::RuntimeType + 'sta
         iid == &<IMap<K, V> as windows_core::Interface>::IID
     }
 }
-#[repr(C)]
-#[doc(hidden)]
-pub struct IMap_Vtbl<K, V>
-where
-    K: windows_core::RuntimeType + 'static,
-    V: windows_core::RuntimeType + 'static,
-{
-    pub base__: windows_core::IInspectable_Vtbl,
-    pub Lookup: unsafe extern "system" fn(
-        *mut core::ffi::c_void,
-        windows_core::AbiType<K>,
-        *mut windows_core::AbiType<V>,
-    ) -> windows_core::HRESULT,
-    pub Size: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
-    pub HasKey: unsafe extern "system" fn(
-        *mut core::ffi::c_void,
-        windows_core::AbiType<K>,
-        *mut bool,
-    ) -> windows_core::HRESULT,
-    pub GetView: unsafe extern "system" fn(
-        *mut core::ffi::c_void,
-        *mut *mut core::ffi::c_void,
-    ) -> windows_core::HRESULT,
-    pub Insert: unsafe extern "system" fn(
-        *mut core::ffi::c_void,
-        windows_core::AbiType<K>,
-        windows_core::AbiType<V>,
-        *mut bool,
-    ) -> windows_core::HRESULT,
-    pub Remove: unsafe extern "system" fn(
-        *mut core::ffi::c_void,
-        windows_core::AbiType<K>,
-    ) -> windows_core::HRESULT,
-    pub Clear: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
-    K: core::marker::PhantomData<K>,
-    V: core::marker::PhantomData<V>,
-}
-#[repr(transparent)]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct IMapView<K, V>(
-    windows_core::IUnknown,
-    core::marker::PhantomData<K>,
-    core::marker::PhantomData<V>,
-)
-where
-    K: windows_core::RuntimeType + 'static,
-    V: windows_core::RuntimeType + 'static;
-impl<K: windows_core::RuntimeType + 'static, V: windows_core::RuntimeType + 'static>
-    windows_core::imp::CanInto<windows_core::IUnknown> for IMapView<K, V>
-{
-}
-impl<K: windows_core::RuntimeType + 'static, V: windows_core::RuntimeType + 'static>
-    windows_core::imp::CanInto<windows_core::IInspectable> for IMapView<K, V>
-{
-}
-unsafe impl<K: windows_core::RuntimeType + 'static, V: windows_core::RuntimeType + 'static>
-    windows_core::Interface for IMapView<K, V>
-{
-    type Vtable = IMapView_Vtbl<K, V>;
-    const IID: windows_core::GUID =
-        windows_core::GUID::from_signature(<Self as windows_core::RuntimeType>::SIGNATURE);
-}
-impl<K: windows_core::RuntimeType + 'static, V: windows_core::RuntimeType + 'static>
-    windows_core::RuntimeType for IMapView<K, V>
-{
-    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::new()
-        .push_slice(b"pinterface({e480ce40-a338-4ada-adcf-272272e48cb9}")
-        .push_slice(b";")
-        .push_other(K::SIGNATURE)
-        .push_slice(b";")
-        .push_other(V::SIGNATURE)
-        .push_slice(b")");
-}
-impl<K: windows_core::RuntimeType + 'static, V: windows_core::RuntimeType + 'static>
-    windows_core::imp::CanInto<IIterable<IKeyValuePair<K, V>>> for IMapView<K, V>
-{
-    const QUERY: bool = true;
-}
-impl<K: windows_core::RuntimeType + 'static, V: windows_core::RuntimeType + 'static>
-    IMapView<K, V>
+impl<
+        K: windows_core::RuntimeType + 'static,
+        V: windows_core::RuntimeType + 'static,
+        Identity: windows_core::IUnknownImpl + IMap_Impl<K, V>,
+    > windows_core::imp::TearOffVtable<Identity> for IMap_Vtbl<K, V>
 {
-    pub fn Lookup<P0>(&self, key: P0) -> windows_core::Result<V>
-    where
-        P0: windows_core::Param<K>,
-    {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).Lookup)(
-                windows_core::Interface::as_raw(this),
-                key.param().abi(),
-                &mut result__,
-            )
-            .and_then(|| windows_core::Type::from_abi(result__))
+    const VTABLE: Self = {
+        unsafe extern "system" fn Lookup<
+            K: windows_core::RuntimeType + 'static,
+            V: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IMap_Impl<K, V>,
+        >(
+            this: *mut core::ffi::c_void,
+            key: windows_core::AbiType<K>,
+            result__: *mut windows_core::AbiType<V>,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMap_Impl::Lookup(this, core::mem::transmute_copy(&key)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub fn Size(&self) -> windows_core::Result<u32> {
-        let this = self;
+        unsafe extern "system" fn Size<
+            K: windows_core::RuntimeType + 'static,
+            V: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IMap_Impl<K, V>,
+        >(
+            this: *mut core::ffi::c_void,
+            result__: *mut u32,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMap_Impl::Size(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn HasKey<
+            K: windows_core::RuntimeType + 'static,
+            V: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IMap_Impl<K, V>,
+        >(
+            this: *mut core::ffi::c_void,
+            key: windows_core::AbiType<K>,
+            result__: *mut bool,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMap_Impl::HasKey(this, core::mem::transmute_copy(&key)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetView<
+            K: windows_core::RuntimeType + 'static,
+            V: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IMap_Impl<K, V>,
+        >(
+            this: *mut core::ffi::c_void,
+            result__: *mut *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMap_Impl::GetView(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Insert<
+            K: windows_core::RuntimeType + 'static,
+            V: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IMap_Impl<K, V>,
+        >(
+            this: *mut core::ffi::c_void,
+            key: windows_core::AbiType<K>,
+            value: windows_core::AbiType<V>,
+            result__: *mut bool,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMap_Impl::Insert(
+                    this,
+                    core::mem::transmute_copy(&key),
+                    core::mem::transmute_copy(&value),
+                ) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Remove<
+            K: windows_core::RuntimeType + 'static,
+            V: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IMap_Impl<K, V>,
+        >(
+            this: *mut core::ffi::c_void,
+            key: windows_core::AbiType<K>,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMap_Impl::Remove(this, core::mem::transmute_copy(&key)).into()
+            }
+        }
+        unsafe extern "system" fn Clear<
+            K: windows_core::RuntimeType + 'static,
+            V: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IMap_Impl<K, V>,
+        >(
+            this: *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMap_Impl::Clear(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<
+                Identity,
+            >>::VTABLE,
+            Lookup: Lookup::<K, V, Identity>,
+            Size: Size::<K, V, Identity>,
+            HasKey: HasKey::<K, V, Identity>,
+            GetView: GetView::<K, V, Identity>,
+            Insert: Insert::<K, V, Identity>,
+            Remove: Remove::<K, V, Identity>,
+            Clear: Clear::<K, V, Identity>,
+            K: core::marker::PhantomData::<K>,
+            V: core::marker::PhantomData::<V>,
+        }
+    };
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IMap_Vtbl<K, V>
+where
+    K: windows_core::RuntimeType + 'static,
+    V: windows_core::RuntimeType + 'static,
+{
+    pub base__: windows_core::IInspectable_Vtbl,
+    pub Lookup: unsafe extern "system" fn(
+        *mut core::ffi::c_void,
+        windows_core::AbiType<K>,
+        *mut windows_core::AbiType<V>,
+    ) -> windows_core::HRESULT,
+    pub Size: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
+    pub HasKey: unsafe extern "system" fn(
+        *mut core::ffi::c_void,
+        windows_core::AbiType<K>,
+        *mut bool,
+    ) -> windows_core::HRESULT,
+    pub GetView: unsafe extern "system" fn(
+        *mut core::ffi::c_void,
+        *mut *mut core::ffi::c_void,
+    ) -> windows_core::HRESULT,
+    pub Insert: unsafe extern "system" fn(
+        *mut core::ffi::c_void,
+        windows_core::AbiType<K>,
+        windows_core::AbiType<V>,
+        *mut bool,
+    ) -> windows_core::HRESULT,
+    pub Remove: unsafe extern "system" fn(
+        *mut core::ffi::c_void,
+        windows_core::AbiType<K>,
+    ) -> windows_core::HRESULT,
+    pub Clear: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+    K: core::marker::PhantomData<K>,
+    V: core::marker::PhantomData<V>,
+}
+#[repr(transparent)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IMapView<K, V>(
+    windows_core::IUnknown,
+    core::marker::PhantomData<K>,
+    core::marker::PhantomData<V>,
+)
+where
+    K: windows_core::RuntimeType + 'static,
+    V: windows_core::RuntimeType + 'static;
+impl<K: windows_core::RuntimeType + 'static, V: windows_core::RuntimeType + 'static>
+    windows_core::imp::CanInto<windows_core::IUnknown> for IMapView<K, V>
+{
+}
+impl<K: windows_core::RuntimeType + 'static, V: windows_core::RuntimeType + 'static>
+    windows_core::imp::CanInto<windows_core::IInspectable> for IMapView<K, V>
+{
+}
+unsafe impl<K: windows_core::RuntimeType + 'static, V: windows_core::RuntimeType + 'static>
+    windows_core::Interface for IMapView<K, V>
+{
+    type Vtable = IMapView_Vtbl<K, V>;
+    const IID: windows_core::GUID =
+        windows_core::GUID::from_signature(<Self as windows_core::RuntimeType>::SIGNATURE);
+}
+impl<K: windows_core::RuntimeType + 'static, V: windows_core::RuntimeType + 'static>
+    windows_core::RuntimeType for IMapView<K, V>
+{
+    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::new()
+        .push_slice(b"pinterface({e480ce40-a338-4ada-adcf-272272e48cb9}")
+        .push_slice(b";")
+        .push_other(K::SIGNATURE)
+        .push_slice(b";")
+        .push_other(V::SIGNATURE)
+        .push_slice(b")");
+}
+impl<K: windows_core::RuntimeType + 'static, V: windows_core::RuntimeType + 'static>
+    windows_core::imp::CanInto<IIterable<IKeyValuePair<K, V>>> for IMapView<K, V>
+{
+    const QUERY: bool = true;
+}
+impl<K: windows_core::RuntimeType + 'static, V: windows_core::RuntimeType + 'static>
+    IMapView<K, V>
+{
+    pub fn Lookup<P0>(&self, key: P0) -> windows_core::Result<V>
+    where
+        P0: windows_core::Param<K>,
+    {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).Lookup)(
+                windows_core::Interface::as_raw(this),
+                key.param().abi(),
+                &mut result__,
+            )
+            .and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub fn Size(&self) -> windows_core::Result<u32> {
+        let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
             (windows_core::Interface::vtable(this).Size)(
@@ -1130,63 +1474,162 @@ impl<K: windows_core::RuntimeType + 'static, V: windows_core::RuntimeType + 'sta
         iid == &<IMapView<K, V> as windows_core::Interface>::IID
     }
 }
-#[repr(C)]
-#[doc(hidden)]
-pub struct IMapView_Vtbl<K, V>
-where
-    K: windows_core::RuntimeType + 'static,
-    V: windows_core::RuntimeType + 'static,
-{
-    pub base__: windows_core::IInspectable_Vtbl,
-    pub Lookup: unsafe extern "system" fn(
-        *mut core::ffi::c_void,
-        windows_core::AbiType<K>,
-        *mut windows_core::AbiType<V>,
-    ) -> windows_core::HRESULT,
-    pub Size: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
-    pub HasKey: unsafe extern "system" fn(
-        *mut core::ffi::c_void,
-        windows_core::AbiType<K>,
-        *mut bool,
-    ) -> windows_core::HRESULT,
-    pub Split: unsafe extern "system" fn(
-        *mut core::ffi::c_void,
-        *mut *mut core::ffi::c_void,
-        *mut *mut core::ffi::c_void,
-    ) -> windows_core::HRESULT,
-    K: core::marker::PhantomData<K>,
-    V: core::marker::PhantomData<V>,
-}
-#[repr(transparent)]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct IVector<T>(windows_core::IUnknown, core::marker::PhantomData<T>)
-where
-    T: windows_core::RuntimeType + 'static;
-impl<T: windows_core::RuntimeType + 'static> windows_core::imp::CanInto<windows_core::IUnknown>
-    for IVector<T>
-{
-}
-impl<T: windows_core::RuntimeType + 'static> windows_core::imp::CanInto<windows_core::IInspectable>
-    for IVector<T>
-{
-}
-unsafe impl<T: windows_core::RuntimeType + 'static> windows_core::Interface for IVector<T> {
-    type Vtable = IVector_Vtbl<T>;
-    const IID: windows_core::GUID =
-        windows_core::GUID::from_signature(<Self as windows_core::RuntimeType>::SIGNATURE);
-}
-impl<T: windows_core::RuntimeType + 'static> windows_core::RuntimeType for IVector<T> {
-    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::new()
-        .push_slice(b"pinterface({913337e9-11a1-4345-a3a2-4e7f956e222d}")
-        .push_slice(b";")
-        .push_other(T::SIGNATURE)
-        .push_slice(b")");
-}
-impl<T: windows_core::RuntimeType + 'static> windows_core::imp::CanInto<IIterable<T>>
-    for IVector<T>
+impl<
+        K: windows_core::RuntimeType + 'static,
+        V: windows_core::RuntimeType + 'static,
+        Identity: windows_core::IUnknownImpl + IMapView_Impl<K, V>,
+    > windows_core::imp::TearOffVtable<Identity> for IMapView_Vtbl<K, V>
 {
-    const QUERY: bool = true;
-}
+    const VTABLE: Self = {
+        unsafe extern "system" fn Lookup<
+            K: windows_core::RuntimeType + 'static,
+            V: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IMapView_Impl<K, V>,
+        >(
+            this: *mut core::ffi::c_void,
+            key: windows_core::AbiType<K>,
+            result__: *mut windows_core::AbiType<V>,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMapView_Impl::Lookup(this, core::mem::transmute_copy(&key)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Size<
+            K: windows_core::RuntimeType + 'static,
+            V: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IMapView_Impl<K, V>,
+        >(
+            this: *mut core::ffi::c_void,
+            result__: *mut u32,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMapView_Impl::Size(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn HasKey<
+            K: windows_core::RuntimeType + 'static,
+            V: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IMapView_Impl<K, V>,
+        >(
+            this: *mut core::ffi::c_void,
+            key: windows_core::AbiType<K>,
+            result__: *mut bool,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMapView_Impl::HasKey(this, core::mem::transmute_copy(&key)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Split<
+            K: windows_core::RuntimeType + 'static,
+            V: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IMapView_Impl<K, V>,
+        >(
+            this: *mut core::ffi::c_void,
+            first: *mut *mut core::ffi::c_void,
+            second: *mut *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMapView_Impl::Split(
+                    this,
+                    core::mem::transmute_copy(&first),
+                    core::mem::transmute_copy(&second),
+                )
+                .into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<
+                Identity,
+            >>::VTABLE,
+            Lookup: Lookup::<K, V, Identity>,
+            Size: Size::<K, V, Identity>,
+            HasKey: HasKey::<K, V, Identity>,
+            Split: Split::<K, V, Identity>,
+            K: core::marker::PhantomData::<K>,
+            V: core::marker::PhantomData::<V>,
+        }
+    };
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IMapView_Vtbl<K, V>
+where
+    K: windows_core::RuntimeType + 'static,
+    V: windows_core::RuntimeType + 'static,
+{
+    pub base__: windows_core::IInspectable_Vtbl,
+    pub Lookup: unsafe extern "system" fn(
+        *mut core::ffi::c_void,
+        windows_core::AbiType<K>,
+        *mut windows_core::AbiType<V>,
+    ) -> windows_core::HRESULT,
+    pub Size: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
+    pub HasKey: unsafe extern "system" fn(
+        *mut core::ffi::c_void,
+        windows_core::AbiType<K>,
+        *mut bool,
+    ) -> windows_core::HRESULT,
+    pub Split: unsafe extern "system" fn(
+        *mut core::ffi::c_void,
+        *mut *mut core::ffi::c_void,
+        *mut *mut core::ffi::c_void,
+    ) -> windows_core::HRESULT,
+    K: core::marker::PhantomData<K>,
+    V: core::marker::PhantomData<V>,
+}
+#[repr(transparent)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IVector<T>(windows_core::IUnknown, core::marker::PhantomData<T>)
+where
+    T: windows_core::RuntimeType + 'static;
+impl<T: windows_core::RuntimeType + 'static> windows_core::imp::CanInto<windows_core::IUnknown>
+    for IVector<T>
+{
+}
+impl<T: windows_core::RuntimeType + 'static> windows_core::imp::CanInto<windows_core::IInspectable>
+    for IVector<T>
+{
+}
+unsafe impl<T: windows_core::RuntimeType + 'static> windows_core::Interface for IVector<T> {
+    type Vtable = IVector_Vtbl<T>;
+    const IID: windows_core::GUID =
+        windows_core::GUID::from_signature(<Self as windows_core::RuntimeType>::SIGNATURE);
+}
+impl<T: windows_core::RuntimeType + 'static> windows_core::RuntimeType for IVector<T> {
+    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::new()
+        .push_slice(b"pinterface({913337e9-11a1-4345-a3a2-4e7f956e222d}")
+        .push_slice(b";")
+        .push_other(T::SIGNATURE)
+        .push_slice(b")");
+}
+impl<T: windows_core::RuntimeType + 'static> windows_core::imp::CanInto<IIterable<T>>
+    for IVector<T>
+{
+    const QUERY: bool = true;
+}
 impl<T: windows_core::RuntimeType + 'static> IVector<T> {
     pub fn GetAt(&self, index: u32) -> windows_core::Result<T> {
         let this = self;
@@ -1322,88 +1765,338 @@ impl<T: windows_core::RuntimeType + 'static> IVector<T> {
             )
             .map(|| result__)
         }
-    }
-    pub fn ReplaceAll(
-        &self,
-        items: &[<T as windows_core::Type<T>>::Default],
-    ) -> windows_core::Result<()> {
-        let this = self;
-        unsafe {
-            (windows_core::Interface::vtable(this).ReplaceAll)(
-                windows_core::Interface::as_raw(this),
-                items.len().try_into().unwrap(),
-                core::mem::transmute(items.as_ptr()),
-            )
-            .ok()
+    }
+    pub fn ReplaceAll(
+        &self,
+        items: &[<T as windows_core::Type<T>>::Default],
+    ) -> windows_core::Result<()> {
+        let this = self;
+        unsafe {
+            (windows_core::Interface::vtable(this).ReplaceAll)(
+                windows_core::Interface::as_raw(this),
+                items.len().try_into().unwrap(),
+                core::mem::transmute(items.as_ptr()),
+            )
+            .ok()
+        }
+    }
+    pub fn First(&self) -> windows_core::Result<IIterator<T>> {
+        let this = &windows_core::Interface::cast::<IIterable<T>>(self)?;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).First)(
+                windows_core::Interface::as_raw(this),
+                &mut result__,
+            )
+            .and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+}
+impl<T: windows_core::RuntimeType + 'static> IntoIterator for IVector<T> {
+    type Item = T;
+    type IntoIter = IIterator<Self::Item>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIterator::into_iter(&self)
+    }
+}
+impl<T: windows_core::RuntimeType + 'static> IntoIterator for &IVector<T> {
+    type Item = T;
+    type IntoIter = IIterator<Self::Item>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.First().unwrap()
+    }
+}
+impl<T: windows_core::RuntimeType + 'static> windows_core::RuntimeName for IVector<T> {
+    const NAME: &'static str = "Windows.Foundation.Collections.IVector";
+}
+pub trait IVector_Impl<T>: IIterable_Impl<T>
+where
+    T: windows_core::RuntimeType + 'static,
+{
+    fn GetAt(&self, index: u32) -> windows_core::Result<T>;
+    fn Size(&self) -> windows_core::Result<u32>;
+    fn GetView(&self) -> windows_core::Result<IVectorView<T>>;
+    fn IndexOf(&self, value: windows_core::Ref<T>, index: &mut u32) -> windows_core::Result<bool>;
+    fn SetAt(&self, index: u32, value: windows_core::Ref<T>) -> windows_core::Result<()>;
+    fn InsertAt(&self, index: u32, value: windows_core::Ref<T>) -> windows_core::Result<()>;
+    fn RemoveAt(&self, index: u32) -> windows_core::Result<()>;
+    fn Append(&self, value: windows_core::Ref<T>) -> windows_core::Result<()>;
+    fn RemoveAtEnd(&self) -> windows_core::Result<()>;
+    fn Clear(&self) -> windows_core::Result<()>;
+    fn GetMany(
+        &self,
+        startIndex: u32,
+        items: &mut [<T as windows_core::Type<T>>::Default],
+    ) -> windows_core::Result<u32>;
+    fn ReplaceAll(
+        &self,
+        items: &[<T as windows_core::Type<T>>::Default],
+    ) -> windows_core::Result<()>;
+}
+impl<T: windows_core::RuntimeType + 'static> IVector_Vtbl<T> {
+    pub const fn new<Identity: IVector_Impl<T>, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn GetAt<
+            T: windows_core::RuntimeType + 'static,
+            Identity: IVector_Impl<T>,
+            const OFFSET: isize,
+        >(
+            this: *mut core::ffi::c_void,
+            index: u32,
+            result__: *mut windows_core::AbiType<T>,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity =
+                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IVector_Impl::GetAt(this, index) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Size<
+            T: windows_core::RuntimeType + 'static,
+            Identity: IVector_Impl<T>,
+            const OFFSET: isize,
+        >(
+            this: *mut core::ffi::c_void,
+            result__: *mut u32,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity =
+                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IVector_Impl::Size(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetView<
+            T: windows_core::RuntimeType + 'static,
+            Identity: IVector_Impl<T>,
+            const OFFSET: isize,
+        >(
+            this: *mut core::ffi::c_void,
+            result__: *mut *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity =
+                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IVector_Impl::GetView(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IndexOf<
+            T: windows_core::RuntimeType + 'static,
+            Identity: IVector_Impl<T>,
+            const OFFSET: isize,
+        >(
+            this: *mut core::ffi::c_void,
+            value: windows_core::AbiType<T>,
+            index: *mut u32,
+            result__: *mut bool,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity =
+                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IVector_Impl::IndexOf(
+                    this,
+                    core::mem::transmute_copy(&value),
+                    core::mem::transmute_copy(&index),
+                ) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAt<
+            T: windows_core::RuntimeType + 'static,
+            Identity: IVector_Impl<T>,
+            const OFFSET: isize,
+        >(
+            this: *mut core::ffi::c_void,
+            index: u32,
+            value: windows_core::AbiType<T>,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity =
+                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IVector_Impl::SetAt(this, index, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn InsertAt<
+            T: windows_core::RuntimeType + 'static,
+            Identity: IVector_Impl<T>,
+            const OFFSET: isize,
+        >(
+            this: *mut core::ffi::c_void,
+            index: u32,
+            value: windows_core::AbiType<T>,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity =
+                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IVector_Impl::InsertAt(this, index, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveAt<
+            T: windows_core::RuntimeType + 'static,
+            Identity: IVector_Impl<T>,
+            const OFFSET: isize,
+        >(
+            this: *mut core::ffi::c_void,
+            index: u32,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity =
+                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IVector_Impl::RemoveAt(this, index).into()
+            }
+        }
+        unsafe extern "system" fn Append<
+            T: windows_core::RuntimeType + 'static,
+            Identity: IVector_Impl<T>,
+            const OFFSET: isize,
+        >(
+            this: *mut core::ffi::c_void,
+            value: windows_core::AbiType<T>,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity =
+                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IVector_Impl::Append(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveAtEnd<
+            T: windows_core::RuntimeType + 'static,
+            Identity: IVector_Impl<T>,
+            const OFFSET: isize,
+        >(
+            this: *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity =
+                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IVector_Impl::RemoveAtEnd(this).into()
+            }
+        }
+        unsafe extern "system" fn Clear<
+            T: windows_core::RuntimeType + 'static,
+            Identity: IVector_Impl<T>,
+            const OFFSET: isize,
+        >(
+            this: *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity =
+                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IVector_Impl::Clear(this).into()
+            }
+        }
+        unsafe extern "system" fn GetMany<
+            T: windows_core::RuntimeType + 'static,
+            Identity: IVector_Impl<T>,
+            const OFFSET: isize,
+        >(
+            this: *mut core::ffi::c_void,
+            startindex: u32,
+            items_array_size: u32,
+            items: *mut T,
+            result__: *mut u32,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity =
+                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IVector_Impl::GetMany(
+                    this,
+                    startindex,
+                    core::slice::from_raw_parts_mut(
+                        core::mem::transmute_copy(&items),
+                        items_array_size as usize,
+                    ),
+                ) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReplaceAll<
+            T: windows_core::RuntimeType + 'static,
+            Identity: IVector_Impl<T>,
+            const OFFSET: isize,
+        >(
+            this: *mut core::ffi::c_void,
+            items_array_size: u32,
+            items: *const T,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity =
+                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IVector_Impl::ReplaceAll(
+                    this,
+                    core::slice::from_raw_parts(
+                        core::mem::transmute_copy(&items),
+                        items_array_size as usize,
+                    ),
+                )
+                .into()
+            }
         }
-    }
-    pub fn First(&self) -> windows_core::Result<IIterator<T>> {
-        let this = &windows_core::Interface::cast::<IIterable<T>>(self)?;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).First)(
-                windows_core::Interface::as_raw(this),
-                &mut result__,
-            )
-            .and_then(|| windows_core::Type::from_abi(result__))
+        Self {
+            base__: windows_core::IInspectable_Vtbl::new::<Identity, IVector<T>, OFFSET>(),
+            GetAt: GetAt::<T, Identity, OFFSET>,
+            Size: Size::<T, Identity, OFFSET>,
+            GetView: GetView::<T, Identity, OFFSET>,
+            IndexOf: IndexOf::<T, Identity, OFFSET>,
+            SetAt: SetAt::<T, Identity, OFFSET>,
+            InsertAt: InsertAt::<T, Identity, OFFSET>,
+            RemoveAt: RemoveAt::<T, Identity, OFFSET>,
+            Append: Append::<T, Identity, OFFSET>,
+            RemoveAtEnd: RemoveAtEnd::<T, Identity, OFFSET>,
+            Clear: Clear::<T, Identity, OFFSET>,
+            GetMany: GetMany::<T, Identity, OFFSET>,
+            ReplaceAll: ReplaceAll::<T, Identity, OFFSET>,
+            T: core::marker::PhantomData::<T>,
         }
     }
-}
-impl<T: windows_core::RuntimeType + 'static> IntoIterator for IVector<T> {
-    type Item = T;
-    type IntoIter = IIterator<Self::Item>;
-    fn into_iter(self) -> Self::IntoIter {
-        IntoIterator::into_iter(&self)
-    }
-}
-impl<T: windows_core::RuntimeType + 'static> IntoIterator for &IVector<T> {
-    type Item = T;
-    type IntoIter = IIterator<Self::Item>;
-    fn into_iter(self) -> Self::IntoIter {
-        self.First().unwrap()
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVector<T> as windows_core::Interface>::IID
     }
 }
-impl<T: windows_core::RuntimeType + 'static> windows_core::RuntimeName for IVector<T> {
-    const NAME: &'static str = "Windows.Foundation.Collections.IVector";
-}
-pub trait IVector_Impl<T>: IIterable_Impl<T>
-where
-    T: windows_core::RuntimeType + 'static,
+impl<
+        T: windows_core::RuntimeType + 'static,
+        Identity: windows_core::IUnknownImpl + IVector_Impl<T>,
+    > windows_core::imp::TearOffVtable<Identity> for IVector_Vtbl<T>
 {
-    fn GetAt(&self, index: u32) -> windows_core::Result<T>;
-    fn Size(&self) -> windows_core::Result<u32>;
-    fn GetView(&self) -> windows_core::Result<IVectorView<T>>;
-    fn IndexOf(&self, value: windows_core::Ref<T>, index: &mut u32) -> windows_core::Result<bool>;
-    fn SetAt(&self, index: u32, value: windows_core::Ref<T>) -> windows_core::Result<()>;
-    fn InsertAt(&self, index: u32, value: windows_core::Ref<T>) -> windows_core::Result<()>;
-    fn RemoveAt(&self, index: u32) -> windows_core::Result<()>;
-    fn Append(&self, value: windows_core::Ref<T>) -> windows_core::Result<()>;
-    fn RemoveAtEnd(&self) -> windows_core::Result<()>;
-    fn Clear(&self) -> windows_core::Result<()>;
-    fn GetMany(
-        &self,
-        startIndex: u32,
-        items: &mut [<T as windows_core::Type<T>>::Default],
-    ) -> windows_core::Result<u32>;
-    fn ReplaceAll(
-        &self,
-        items: &[<T as windows_core::Type<T>>::Default],
-    ) -> windows_core::Result<()>;
-}
-impl<T: windows_core::RuntimeType + 'static> IVector_Vtbl<T> {
-    pub const fn new<Identity: IVector_Impl<T>, const OFFSET: isize>() -> Self {
+    const VTABLE: Self = {
         unsafe extern "system" fn GetAt<
             T: windows_core::RuntimeType + 'static,
-            Identity: IVector_Impl<T>,
-            const OFFSET: isize,
+            Identity: windows_core::IUnknownImpl + IVector_Impl<T>,
         >(
             this: *mut core::ffi::c_void,
             index: u32,
             result__: *mut windows_core::AbiType<T>,
         ) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity =
-                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IVector_Impl::GetAt(this, index) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -1416,15 +2109,13 @@ impl<T: windows_core::RuntimeType + 'static> IVector_Vtbl<T> {
         }
         unsafe extern "system" fn Size<
             T: windows_core::RuntimeType + 'static,
-            Identity: IVector_Impl<T>,
-            const OFFSET: isize,
+            Identity: windows_core::IUnknownImpl + IVector_Impl<T>,
         >(
             this: *mut core::ffi::c_void,
             result__: *mut u32,
         ) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity =
-                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IVector_Impl::Size(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -1436,15 +2127,13 @@ impl<T: windows_core::RuntimeType + 'static> IVector_Vtbl<T> {
         }
         unsafe extern "system" fn GetView<
             T: windows_core::RuntimeType + 'static,
-            Identity: IVector_Impl<T>,
-            const OFFSET: isize,
+            Identity: windows_core::IUnknownImpl + IVector_Impl<T>,
         >(
             this: *mut core::ffi::c_void,
             result__: *mut *mut core::ffi::c_void,
         ) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity =
-                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IVector_Impl::GetView(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -1457,8 +2146,7 @@ impl<T: windows_core::RuntimeType + 'static> IVector_Vtbl<T> {
         }
         unsafe extern "system" fn IndexOf<
             T: windows_core::RuntimeType + 'static,
-            Identity: IVector_Impl<T>,
-            const OFFSET: isize,
+            Identity: windows_core::IUnknownImpl + IVector_Impl<T>,
         >(
             this: *mut core::ffi::c_void,
             value: windows_core::AbiType<T>,
@@ -1466,8 +2154,7 @@ impl<T: windows_core::RuntimeType + 'static> IVector_Vtbl<T> {
             result__: *mut bool,
         ) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity =
-                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IVector_Impl::IndexOf(
                     this,
                     core::mem::transmute_copy(&value),
@@ -1483,92 +2170,79 @@ impl<T: windows_core::RuntimeType + 'static> IVector_Vtbl<T> {
         }
         unsafe extern "system" fn SetAt<
             T: windows_core::RuntimeType + 'static,
-            Identity: IVector_Impl<T>,
-            const OFFSET: isize,
+            Identity: windows_core::IUnknownImpl + IVector_Impl<T>,
         >(
             this: *mut core::ffi::c_void,
             index: u32,
             value: windows_core::AbiType<T>,
         ) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity =
-                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IVector_Impl::SetAt(this, index, core::mem::transmute_copy(&value)).into()
             }
         }
         unsafe extern "system" fn InsertAt<
             T: windows_core::RuntimeType + 'static,
-            Identity: IVector_Impl<T>,
-            const OFFSET: isize,
+            Identity: windows_core::IUnknownImpl + IVector_Impl<T>,
         >(
             this: *mut core::ffi::c_void,
             index: u32,
             value: windows_core::AbiType<T>,
         ) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity =
-                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IVector_Impl::InsertAt(this, index, core::mem::transmute_copy(&value)).into()
             }
         }
         unsafe extern "system" fn RemoveAt<
             T: windows_core::RuntimeType + 'static,
-            Identity: IVector_Impl<T>,
-            const OFFSET: isize,
+            Identity: windows_core::IUnknownImpl + IVector_Impl<T>,
         >(
             this: *mut core::ffi::c_void,
             index: u32,
         ) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity =
-                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IVector_Impl::RemoveAt(this, index).into()
             }
         }
         unsafe extern "system" fn Append<
             T: windows_core::RuntimeType + 'static,
-            Identity: IVector_Impl<T>,
-            const OFFSET: isize,
+            Identity: windows_core::IUnknownImpl + IVector_Impl<T>,
         >(
             this: *mut core::ffi::c_void,
             value: windows_core::AbiType<T>,
         ) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity =
-                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IVector_Impl::Append(this, core::mem::transmute_copy(&value)).into()
             }
         }
         unsafe extern "system" fn RemoveAtEnd<
             T: windows_core::RuntimeType + 'static,
-            Identity: IVector_Impl<T>,
-            const OFFSET: isize,
+            Identity: windows_core::IUnknownImpl + IVector_Impl<T>,
         >(
             this: *mut core::ffi::c_void,
         ) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity =
-                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IVector_Impl::RemoveAtEnd(this).into()
             }
         }
         unsafe extern "system" fn Clear<
             T: windows_core::RuntimeType + 'static,
-            Identity: IVector_Impl<T>,
-            const OFFSET: isize,
+            Identity: windows_core::IUnknownImpl + IVector_Impl<T>,
         >(
             this: *mut core::ffi::c_void,
         ) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity =
-                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IVector_Impl::Clear(this).into()
             }
         }
         unsafe extern "system" fn GetMany<
             T: windows_core::RuntimeType + 'static,
-            Identity: IVector_Impl<T>,
-            const OFFSET: isize,
+            Identity: windows_core::IUnknownImpl + IVector_Impl<T>,
         >(
             this: *mut core::ffi::c_void,
             startindex: u32,
@@ -1577,8 +2251,7 @@ impl<T: windows_core::RuntimeType + 'static> IVector_Vtbl<T> {
             result__: *mut u32,
         ) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity =
-                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IVector_Impl::GetMany(
                     this,
                     startindex,
@@ -1597,16 +2270,14 @@ impl<T: windows_core::RuntimeType + 'static> IVector_Vtbl<T> {
         }
         unsafe extern "system" fn ReplaceAll<
             T: windows_core::RuntimeType + 'static,
-            Identity: IVector_Impl<T>,
-            const OFFSET: isize,
+            Identity: windows_core::IUnknownImpl + IVector_Impl<T>,
         >(
             this: *mut core::ffi::c_void,
             items_array_size: u32,
             items: *const T,
         ) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity =
-                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IVector_Impl::ReplaceAll(
                     this,
                     core::slice::from_raw_parts(
@@ -1618,25 +2289,24 @@ impl<T: windows_core::RuntimeType + 'static> IVector_Vtbl<T> {
             }
         }
         Self {
-            base__: windows_core::IInspectable_Vtbl::new::<Identity, IVector<T>, OFFSET>(),
-            GetAt: GetAt::<T, Identity, OFFSET>,
-            Size: Size::<T, Identity, OFFSET>,
-            GetView: GetView::<T, Identity, OFFSET>,
-            IndexOf: IndexOf::<T, Identity, OFFSET>,
-            SetAt: SetAt::<T, Identity, OFFSET>,
-            InsertAt: InsertAt::<T, Identity, OFFSET>,
-            RemoveAt: RemoveAt::<T, Identity, OFFSET>,
-            Append: Append::<T, Identity, OFFSET>,
-            RemoveAtEnd: RemoveAtEnd::<T, Identity, OFFSET>,
-            Clear: Clear::<T, Identity, OFFSET>,
-            GetMany: GetMany::<T, Identity, OFFSET>,
-            ReplaceAll: ReplaceAll::<T, Identity, OFFSET>,
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<
+                Identity,
+            >>::VTABLE,
+            GetAt: GetAt::<T, Identity>,
+            Size: Size::<T, Identity>,
+            GetView: GetView::<T, Identity>,
+            IndexOf: IndexOf::<T, Identity>,
+            SetAt: SetAt::<T, Identity>,
+            InsertAt: InsertAt::<T, Identity>,
+            RemoveAt: RemoveAt::<T, Identity>,
+            Append: Append::<T, Identity>,
+            RemoveAtEnd: RemoveAtEnd::<T, Identity>,
+            Clear: Clear::<T, Identity>,
+            GetMany: GetMany::<T, Identity>,
+            ReplaceAll: ReplaceAll::<T, Identity>,
             T: core::marker::PhantomData::<T>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IVector<T> as windows_core::Interface>::IID
-    }
+    };
 }
 #[repr(C)]
 #[doc(hidden)]
@@ -1932,6 +2602,114 @@ impl<T: windows_core::RuntimeType + 'static> IVectorView_Vtbl<T> {
         iid == &<IVectorView<T> as windows_core::Interface>::IID
     }
 }
+impl<
+        T: windows_core::RuntimeType + 'static,
+        Identity: windows_core::IUnknownImpl + IVectorView_Impl<T>,
+    > windows_core::imp::TearOffVtable<Identity> for IVectorView_Vtbl<T>
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAt<
+            T: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IVectorView_Impl<T>,
+        >(
+            this: *mut core::ffi::c_void,
+            index: u32,
+            result__: *mut windows_core::AbiType<T>,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVectorView_Impl::GetAt(this, index) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Size<
+            T: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IVectorView_Impl<T>,
+        >(
+            this: *mut core::ffi::c_void,
+            result__: *mut u32,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVectorView_Impl::Size(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IndexOf<
+            T: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IVectorView_Impl<T>,
+        >(
+            this: *mut core::ffi::c_void,
+            value: windows_core::AbiType<T>,
+            index: *mut u32,
+            result__: *mut bool,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVectorView_Impl::IndexOf(
+                    this,
+                    core::mem::transmute_copy(&value),
+                    core::mem::transmute_copy(&index),
+                ) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMany<
+            T: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IVectorView_Impl<T>,
+        >(
+            this: *mut core::ffi::c_void,
+            startindex: u32,
+            items_array_size: u32,
+            items: *mut T,
+            result__: *mut u32,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVectorView_Impl::GetMany(
+                    this,
+                    startindex,
+                    core::slice::from_raw_parts_mut(
+                        core::mem::transmute_copy(&items),
+                        items_array_size as usize,
+                    ),
+                ) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<
+                Identity,
+            >>::VTABLE,
+            GetAt: GetAt::<T, Identity>,
+            Size: Size::<T, Identity>,
+            IndexOf: IndexOf::<T, Identity>,
+            GetMany: GetMany::<T, Identity>,
+            T: core::marker::PhantomData::<T>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IVectorView_Vtbl<T>
@@ -42,6 +42,28 @@ pub trait ComObjectInner: Sized {
     fn into_object(self) -> ComObject<Self>;
 }
 
+/// Identifies types that can be created as aggregated inner COM objects.
+///
+/// The `#[implement]` macro generates implementations of this trait for types that specify the
+/// `aggregatable` option. Use [`ComObject::new_aggregated`] to create an aggregated object.
+///
+/// This trait is an implementation detail of the Windows crates.
+/// User code should not deal directly with this trait.
+pub trait ComObjectAggregate: ComObjectInner {
+    /// Moves an instance of this type into a new ComObject box that is controlled by `outer`.
+    fn into_aggregated_object(self, outer: &IUnknown) -> ComObject<Self>;
+}
+
+/// Provides the aggregated inner object of a COM object.
+///
+/// Types that specify the `aggregates` option of the `#[implement]` macro must implement this
+/// trait. Calls to `QueryInterface` for interfaces that the outer object doesn't implement are
+/// forwarded to the inner object, so that both objects share a single COM identity.
+pub trait ComObjectAggregator {
+    /// Returns the non-delegating `IUnknown` of the aggregated inner object, if it has been created.
+    fn aggregated(&self) -> Option<InterfaceRef<'_, IUnknown>>;
+}
+
 /// Describes the COM interfaces implemented by a specific COM object.
 ///
 /// The `#[implement]` macro generates implementations of this trait. Implementations are attached
@@ -81,6 +103,26 @@ impl<T: ComObjectInner> ComObject<T> {
         T::into_object(value)
     }
 
+    /// Allocates a heap cell (box) and moves `value` into it as an aggregated inner object that
+    /// is controlled by `outer`. Returns a counted pointer to `value`.
+    ///
+    /// The returned `ComObject` owns a reference to the inner object's non-delegating `IUnknown`,
+    /// which the outer object should hold for as long as it lives. Every other interface of the
+    /// inner object forwards `QueryInterface`, `AddRef` and `Release` to `outer`, which is not
+    /// reference counted by the inner object. References to those interfaces are also counted by
+    /// the inner object, so that it outlives them. Use the `aggregates` option of the
+    /// `#[implement]` macro to make the inner object's interfaces available from `outer`.
+    ///
+    /// # Safety
+    ///
+    /// `outer` must outlive the returned object and every interface obtained from it.
+    pub unsafe fn new_aggregated(value: T, outer: &IUnknown) -> Self
+    where
+        T: ComObjectAggregate,
+    {
+        T::into_aggregated_object(value, outer)
+    }
+
     /// Creates a new `ComObject` that points to an existing boxed instance.
     ///
     /// # Safety
@@ -141,6 +183,7 @@ impl<T: ComObjectInner> ComObject<T> {
     #[inline(always)]
     pub fn take(self) -> Result<T, Self> {
         if self.is_reference_count_one() {
+            crate::imp::untrack_object(self.ptr.as_ptr() as *const core::ffi::c_void);
             let outer_box: Box<T::Outer> = unsafe { core::mem::transmute(self) };
             Ok(outer_box.into_inner())
         } else {
@@ -191,6 +234,13 @@ impl<T: ComObjectInner> ComObject<T> {
     where
         T::Outer: ComObjectInterface<I>,
     {
+        // The interfaces of an aggregated object, other than its identity, are also counted by the
+        // controlling `IUnknown`, so the reference is added through the interface before `self`
+        // releases its own. The inner object remains alive through the interface's reference.
+        if self.get_box().controlling_unknown().is_some() {
+            return self.to_interface();
+        }
+
         unsafe {
             let raw = self.get_box().as_interface_ref().as_raw();
             core::mem::forget(self);
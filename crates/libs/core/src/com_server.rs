@@ -0,0 +1,131 @@
+use super::*;
+use core::ffi::c_void;
+
+/// The threading model that an in-process COM class is registered with.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ThreadingModel {
+    /// The class may only be used from a single-threaded apartment.
+    Apartment,
+    /// The class may only be used from the multithreaded apartment.
+    Free,
+    /// The class may be used from any apartment.
+    Both,
+    /// The class may be used from any apartment and is not tied to any thread.
+    Neutral,
+}
+
+impl ThreadingModel {
+    /// Returns the value of the `ThreadingModel` registry value for this threading model.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Apartment => "Apartment",
+            Self::Free => "Free",
+            Self::Both => "Both",
+            Self::Neutral => "Neutral",
+        }
+    }
+}
+
+/// The registration data for an in-process COM class, for use by installers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ClassRegistration {
+    /// The class identifier that is passed to `DllGetClassObject`.
+    pub clsid: GUID,
+    /// The name of the Rust type that implements the class.
+    pub name: &'static str,
+    /// The threading model of the class.
+    pub threading_model: ThreadingModel,
+}
+
+/// Identifies types that can be created by a COM class factory.
+///
+/// The `#[implement]` macro generates implementations of this trait for types that specify the
+/// `clsid` option. Use the [`com_server!`] macro to export these classes from a DLL.
+pub trait ComClass: ComObjectInner {
+    /// The registration data for this class.
+    const REGISTRATION: ClassRegistration;
+
+    /// Creates a new default instance of this class, as `IClassFactory::CreateInstance` would.
+    ///
+    /// # Safety
+    ///
+    /// `outer` must be null or a valid `IUnknown` pointer and `iid` and `interface` must be valid pointers.
+    unsafe fn create_instance(
+        outer: *mut c_void,
+        iid: *const GUID,
+        interface: *mut *mut c_void,
+    ) -> HRESULT;
+}
+
+/// Returns `true` if there are no live objects and no server locks, which is what
+/// `DllCanUnloadNow` reports.
+pub fn can_unload_now() -> bool {
+    imp::object_count() == 0 && imp::lock_count() == 0
+}
+
+/// Exports the classes of an in-process COM server.
+///
+/// Each class must be annotated with `#[implement(..., clsid = "...")]` and implement `Default`.
+/// The macro exports `DllGetClassObject` and `DllCanUnloadNow` and defines a `COM_CLASSES`
+/// constant holding the [`ClassRegistration`] of each class, for use by installers.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[implement(IValue, clsid = "2bf5b6c0-cf8a-4d36-a1f1-4a0e9b1b3f6e")]
+/// #[derive(Default)]
+/// struct Value(i32);
+///
+/// windows_core::com_server!(Value);
+/// ```
+#[macro_export]
+macro_rules! com_server {
+    ($($class:ty),* $(,)?) => {
+        /// The registration data of the classes exported by this server.
+        pub const COM_CLASSES: &[::windows_core::ClassRegistration] = &[
+            $(<$class as ::windows_core::ComClass>::REGISTRATION,)*
+        ];
+
+        /// Retrieves the class factory for a class exported by this server.
+        ///
+        /// # Safety
+        ///
+        /// The pointers must be valid as described by the `DllGetClassObject` documentation.
+        #[no_mangle]
+        pub unsafe extern "system" fn DllGetClassObject(
+            clsid: *const ::windows_core::GUID,
+            iid: *const ::windows_core::GUID,
+            interface: *mut *mut ::core::ffi::c_void,
+        ) -> ::windows_core::HRESULT {
+            if clsid.is_null() || iid.is_null() || interface.is_null() {
+                return ::windows_core::imp::E_POINTER;
+            }
+
+            $(
+                if *clsid == <$class as ::windows_core::ComClass>::REGISTRATION.clsid {
+                    static FACTORY: ::windows_core::StaticComObject<::windows_core::imp::ClassFactory> =
+                        ::windows_core::imp::ClassFactory::new::<$class>().into_static();
+
+                    return ::windows_core::Interface::query(
+                        &*FACTORY.as_interface::<::windows_core::IUnknown>(),
+                        iid,
+                        interface,
+                    );
+                }
+            )*
+
+            *interface = ::core::ptr::null_mut();
+            ::windows_core::imp::CLASS_E_CLASSNOTAVAILABLE
+        }
+
+        /// Reports whether the server can be unloaded.
+        #[no_mangle]
+        pub extern "system" fn DllCanUnloadNow() -> ::windows_core::HRESULT {
+            if ::windows_core::can_unload_now() {
+                ::windows_core::imp::S_OK
+            } else {
+                ::windows_core::imp::S_FALSE
+            }
+        }
+    };
+}
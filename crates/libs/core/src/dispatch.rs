@@ -0,0 +1,94 @@
+use super::*;
+
+/// Identifies the `IDispatch` interface and the automation `VARIANT` type that it uses.
+///
+/// This is implemented by the `windows` crate so that dual interfaces defined with the
+/// `#[interface]` macro can convert their arguments without `windows-core` depending on `VARIANT`.
+pub trait DispatchInterface: Interface {
+    /// The automation `VARIANT` type.
+    type Variant: Default;
+}
+
+/// Identifies a dual interface, which is an interface that derives directly from `IDispatch`.
+///
+/// The `#[interface]` macro implements this trait for such interfaces. The dispatch identifier of
+/// a member is its index in [`DualInterface::NAMES`] plus one.
+pub trait DualInterface: Interface {
+    /// The automation `VARIANT` type used by the interface's `IDispatch` implementation.
+    type Variant: Default;
+
+    /// The names of the members that can be called through `IDispatch::Invoke`.
+    ///
+    /// Methods that take or return pointers cannot be called by scripting hosts and are omitted.
+    const NAMES: &'static [&'static str];
+}
+
+/// Provides the `IDispatch` implementation of a COM object.
+///
+/// The `#[implement(IFoo, dispatch)]` macro implements this trait for objects whose first
+/// interface is a dual interface. The `windows` crate in turn implements `IDispatch_Impl` for
+/// every type that implements this trait.
+pub trait Dispatch: IUnknownImpl {
+    /// The automation `VARIANT` type.
+    type Variant;
+
+    /// The names of the members that can be called, as described by [`DualInterface::NAMES`].
+    const NAMES: &'static [&'static str];
+
+    /// Calls the member with the given dispatch identifier.
+    ///
+    /// The arguments are in reverse order, as they are in `DISPPARAMS`.
+    ///
+    /// # Safety
+    ///
+    /// The member is an `unsafe` interface method and the same requirements apply.
+    unsafe fn invoke(&self, id: i32, args: &[Self::Variant]) -> Result<Self::Variant>;
+
+    /// Returns the dispatch identifier of the member with the given name, ignoring case as
+    /// automation does.
+    fn dispatch_id(name: &[u16]) -> Option<i32> {
+        fn fold(c: u16) -> u16 {
+            if (u16::from(b'A')..=u16::from(b'Z')).contains(&c) {
+                c + 32
+            } else {
+                c
+            }
+        }
+
+        Self::NAMES
+            .iter()
+            .position(|candidate| {
+                candidate
+                    .encode_utf16()
+                    .map(fold)
+                    .eq(name.iter().copied().map(fold))
+            })
+            .map(|index| index as i32 + 1)
+    }
+}
+
+/// Converts Rust values to and from the automation `VARIANT` type `V`.
+///
+/// This is implemented for every type with `TryFrom<&V>` and `From` conversions, such as those
+/// that the `windows` crate provides for `VARIANT`.
+pub trait DispatchValue<V>: Sized {
+    /// Converts an argument to the Rust type expected by a method.
+    fn from_variant(variant: &V) -> Result<Self>;
+
+    /// Converts the value returned by a method.
+    fn into_variant(self) -> V;
+}
+
+impl<V, T> DispatchValue<V> for T
+where
+    T: for<'a> TryFrom<&'a V, Error = Error>,
+    V: From<T>,
+{
+    fn from_variant(variant: &V) -> Result<Self> {
+        Self::try_from(variant)
+    }
+
+    fn into_variant(self) -> V {
+        V::from(self)
+    }
+}
@@ -0,0 +1,71 @@
+use super::*;
+use crate::{implement, ComClass, IUnknown, Ref, Result, BOOL, GUID, HRESULT};
+use core::ffi::c_void;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// The number of live objects implemented by this module.
+static OBJECTS: AtomicUsize = AtomicUsize::new(0);
+
+/// The number of outstanding `IClassFactory::LockServer` locks.
+static LOCKS: AtomicUsize = AtomicUsize::new(0);
+
+pub fn object_count() -> usize {
+    OBJECTS.load(Ordering::Acquire)
+}
+
+pub fn lock_count() -> usize {
+    LOCKS.load(Ordering::Acquire)
+}
+
+pub fn module_add_ref() {
+    OBJECTS.fetch_add(1, Ordering::Relaxed);
+}
+
+pub fn module_release() {
+    OBJECTS.fetch_sub(1, Ordering::Release);
+}
+
+#[implement(IClassFactory)]
+pub struct ClassFactory {
+    create: unsafe fn(*mut c_void, *const GUID, *mut *mut c_void) -> HRESULT,
+}
+
+impl ClassFactory {
+    pub const fn new<T: ComClass>() -> Self {
+        Self {
+            create: T::create_instance,
+        }
+    }
+}
+
+impl IClassFactory_Impl for ClassFactory_Impl {
+    #[expect(clippy::not_unsafe_ptr_arg_deref)]
+    fn CreateInstance(
+        &self,
+        outer: Ref<IUnknown>,
+        iid: *const GUID,
+        interface: *mut *mut c_void,
+    ) -> Result<()> {
+        if iid.is_null() || interface.is_null() {
+            return Err(E_POINTER.into());
+        }
+
+        unsafe {
+            *interface = core::ptr::null_mut();
+            (self.create)(core::mem::transmute_copy(&outer), iid, interface).ok()
+        }
+    }
+
+    fn LockServer(&self, lock: BOOL) -> Result<()> {
+        if lock.as_bool() {
+            LOCKS.fetch_add(1, Ordering::Relaxed);
+        } else {
+            // An unlock without a matching lock leaves the count at zero rather than wrapping.
+            _ = LOCKS.fetch_update(Ordering::Release, Ordering::Relaxed, |locks| {
+                locks.checked_sub(1)
+            });
+        }
+
+        Ok(())
+    }
+}
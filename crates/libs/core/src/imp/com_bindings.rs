@@ -18,7 +18,16 @@ pub const AGILEREFERENCE_DEFAULT: AgileReferenceOptions = AgileReferenceOptions(
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct AgileReferenceOptions(pub i32);
+pub const CLASS_E_CLASSNOTAVAILABLE: windows_core::HRESULT =
+    windows_core::HRESULT(0x80040111_u32 as _);
+pub const CLASS_E_NOAGGREGATION: windows_core::HRESULT = windows_core::HRESULT(0x80040110_u32 as _);
 pub const CO_E_NOTINITIALIZED: windows_core::HRESULT = windows_core::HRESULT(0x800401F0_u32 as _);
+pub const DISP_E_BADINDEX: windows_core::HRESULT = windows_core::HRESULT(0x8002000B_u32 as _);
+pub const DISP_E_BADPARAMCOUNT: windows_core::HRESULT = windows_core::HRESULT(0x8002000E_u32 as _);
+pub const DISP_E_MEMBERNOTFOUND: windows_core::HRESULT = windows_core::HRESULT(0x80020003_u32 as _);
+pub const DISP_E_NONAMEDARGS: windows_core::HRESULT = windows_core::HRESULT(0x80020007_u32 as _);
+pub const DISP_E_TYPEMISMATCH: windows_core::HRESULT = windows_core::HRESULT(0x80020005_u32 as _);
+pub const DISP_E_UNKNOWNNAME: windows_core::HRESULT = windows_core::HRESULT(0x80020006_u32 as _);
 pub const E_INVALIDARG: windows_core::HRESULT = windows_core::HRESULT(0x80070057_u32 as _);
 pub const E_NOINTERFACE: windows_core::HRESULT = windows_core::HRESULT(0x80004002_u32 as _);
 pub const E_POINTER: windows_core::HRESULT = windows_core::HRESULT(0x80004003_u32 as _);
@@ -44,6 +53,18 @@ impl IAgileObject_Vtbl {
         iid == &<IAgileObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAgileObject_Impl>
+    windows_core::imp::TearOffVtable<Identity> for IAgileObject_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        Self {
+            base__:
+                <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAgileObject {}
 windows_core::imp::define_interface!(
     IAgileReference,
@@ -111,7 +132,182 @@ impl IAgileReference_Vtbl {
         iid == &<IAgileReference as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAgileReference_Impl>
+    windows_core::imp::TearOffVtable<Identity> for IAgileReference_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Resolve<
+            Identity: windows_core::IUnknownImpl + IAgileReference_Impl,
+        >(
+            this: *mut core::ffi::c_void,
+            riid: *const windows_core::GUID,
+            ppvobjectreference: *mut *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAgileReference_Impl::Resolve(
+                    this,
+                    core::mem::transmute_copy(&riid),
+                    core::mem::transmute_copy(&ppvobjectreference),
+                )
+                .into()
+            }
+        }
+        Self {
+            base__:
+                <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Resolve: Resolve::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAgileReference {}
+windows_core::imp::define_interface!(
+    IClassFactory,
+    IClassFactory_Vtbl,
+    0x00000001_0000_0000_c000_000000000046
+);
+windows_core::imp::interface_hierarchy!(IClassFactory, windows_core::IUnknown);
+impl IClassFactory {
+    pub unsafe fn CreateInstance<P0, T>(&self, punkouter: P0) -> windows_core::Result<T>
+    where
+        P0: windows_core::Param<windows_core::IUnknown>,
+        T: windows_core::Interface,
+    {
+        let mut result__ = core::ptr::null_mut();
+        unsafe {
+            (windows_core::Interface::vtable(self).CreateInstance)(
+                windows_core::Interface::as_raw(self),
+                punkouter.param().abi(),
+                &T::IID,
+                &mut result__,
+            )
+            .and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn LockServer(&self, flock: bool) -> windows_core::Result<()> {
+        unsafe {
+            (windows_core::Interface::vtable(self).LockServer)(
+                windows_core::Interface::as_raw(self),
+                flock.into(),
+            )
+            .ok()
+        }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IClassFactory_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub CreateInstance: unsafe extern "system" fn(
+        *mut core::ffi::c_void,
+        *mut core::ffi::c_void,
+        *const windows_core::GUID,
+        *mut *mut core::ffi::c_void,
+    ) -> windows_core::HRESULT,
+    pub LockServer: unsafe extern "system" fn(
+        *mut core::ffi::c_void,
+        windows_core::BOOL,
+    ) -> windows_core::HRESULT,
+}
+pub trait IClassFactory_Impl: windows_core::IUnknownImpl {
+    fn CreateInstance(
+        &self,
+        punkouter: windows_core::Ref<windows_core::IUnknown>,
+        riid: *const windows_core::GUID,
+        ppvobject: *mut *mut core::ffi::c_void,
+    ) -> windows_core::Result<()>;
+    fn LockServer(&self, flock: windows_core::BOOL) -> windows_core::Result<()>;
+}
+impl IClassFactory_Vtbl {
+    pub const fn new<Identity: IClassFactory_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn CreateInstance<
+            Identity: IClassFactory_Impl,
+            const OFFSET: isize,
+        >(
+            this: *mut core::ffi::c_void,
+            punkouter: *mut core::ffi::c_void,
+            riid: *const windows_core::GUID,
+            ppvobject: *mut *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity =
+                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IClassFactory_Impl::CreateInstance(
+                    this,
+                    core::mem::transmute_copy(&punkouter),
+                    core::mem::transmute_copy(&riid),
+                    core::mem::transmute_copy(&ppvobject),
+                )
+                .into()
+            }
+        }
+        unsafe extern "system" fn LockServer<Identity: IClassFactory_Impl, const OFFSET: isize>(
+            this: *mut core::ffi::c_void,
+            flock: windows_core::BOOL,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity =
+                    &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IClassFactory_Impl::LockServer(this, core::mem::transmute_copy(&flock)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            CreateInstance: CreateInstance::<Identity, OFFSET>,
+            LockServer: LockServer::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClassFactory as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IClassFactory_Impl>
+    windows_core::imp::TearOffVtable<Identity> for IClassFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateInstance<
+            Identity: windows_core::IUnknownImpl + IClassFactory_Impl,
+        >(
+            this: *mut core::ffi::c_void,
+            punkouter: *mut core::ffi::c_void,
+            riid: *const windows_core::GUID,
+            ppvobject: *mut *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IClassFactory_Impl::CreateInstance(
+                    this,
+                    core::mem::transmute_copy(&punkouter),
+                    core::mem::transmute_copy(&riid),
+                    core::mem::transmute_copy(&ppvobject),
+                )
+                .into()
+            }
+        }
+        unsafe extern "system" fn LockServer<
+            Identity: windows_core::IUnknownImpl + IClassFactory_Impl,
+        >(
+            this: *mut core::ffi::c_void,
+            flock: windows_core::BOOL,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IClassFactory_Impl::LockServer(this, core::mem::transmute_copy(&flock)).into()
+            }
+        }
+        Self {
+            base__:
+                <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateInstance: CreateInstance::<Identity>,
+            LockServer: LockServer::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IClassFactory {}
 windows_core::imp::define_interface!(
     IWeakReference,
     IWeakReference_Vtbl,
@@ -178,6 +374,36 @@ impl IWeakReference_Vtbl {
         iid == &<IWeakReference as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWeakReference_Impl>
+    windows_core::imp::TearOffVtable<Identity> for IWeakReference_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Resolve<
+            Identity: windows_core::IUnknownImpl + IWeakReference_Impl,
+        >(
+            this: *mut core::ffi::c_void,
+            riid: *const windows_core::GUID,
+            objectreference: *mut *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWeakReference_Impl::Resolve(
+                    this,
+                    core::mem::transmute_copy(&riid),
+                    core::mem::transmute_copy(&objectreference),
+                )
+                .into()
+            }
+        }
+        Self {
+            base__:
+                <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Resolve: Resolve::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWeakReference {}
 windows_core::imp::define_interface!(
     IWeakReferenceSource,
@@ -239,8 +465,39 @@ impl IWeakReferenceSource_Vtbl {
         iid == &<IWeakReferenceSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWeakReferenceSource_Impl>
+    windows_core::imp::TearOffVtable<Identity> for IWeakReferenceSource_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetWeakReference<
+            Identity: windows_core::IUnknownImpl + IWeakReferenceSource_Impl,
+        >(
+            this: *mut core::ffi::c_void,
+            weakreference: *mut *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWeakReferenceSource_Impl::GetWeakReference(this) {
+                    Ok(ok__) => {
+                        weakreference.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__:
+                <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetWeakReference: GetWeakReference::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWeakReferenceSource {}
 pub const JSCRIPT_E_CANTEXECUTE: windows_core::HRESULT = windows_core::HRESULT(0x89020001_u32 as _);
 pub const REGDB_E_CLASSNOTREG: windows_core::HRESULT = windows_core::HRESULT(0x80040154_u32 as _);
 pub const RPC_E_DISCONNECTED: windows_core::HRESULT = windows_core::HRESULT(0x80010108_u32 as _);
+pub const S_FALSE: windows_core::HRESULT = windows_core::HRESULT(0x1_u32 as _);
 pub const S_OK: windows_core::HRESULT = windows_core::HRESULT(0x0_u32 as _);
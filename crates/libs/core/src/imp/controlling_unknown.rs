@@ -0,0 +1,40 @@
+use crate::IUnknown;
+use core::ffi::c_void;
+use core::ptr::null_mut;
+
+/// Holds the controlling outer `IUnknown` of an aggregated COM object.
+///
+/// The pointer is not reference counted. The outer object owns the inner (aggregated) object,
+/// so holding a counted reference back to the outer object would create a reference cycle.
+#[repr(transparent)]
+pub struct ControllingUnknown(*mut c_void);
+
+// The outer object is responsible for its own thread safety, just as it would be if the
+// inner object held a counted `IUnknown` reference to it.
+unsafe impl Send for ControllingUnknown {}
+unsafe impl Sync for ControllingUnknown {}
+
+impl ControllingUnknown {
+    /// Creates a `ControllingUnknown` for an object that is not aggregated.
+    pub const fn none() -> Self {
+        Self(null_mut())
+    }
+
+    /// Creates a `ControllingUnknown` that refers to `outer` without adding a reference.
+    pub fn new(outer: &IUnknown) -> Self {
+        Self(crate::Interface::as_raw(outer))
+    }
+
+    /// Returns the controlling outer `IUnknown`, if the object is aggregated.
+    #[inline(always)]
+    pub fn get(&self) -> Option<&IUnknown> {
+        // SAFETY: `IUnknown` is a non-null pointer so `Option<IUnknown>` has the same layout as `*mut c_void`.
+        unsafe { (*(&self.0 as *const *mut c_void as *const Option<IUnknown>)).as_ref() }
+    }
+}
+
+impl Default for ControllingUnknown {
+    fn default() -> Self {
+        Self::none()
+    }
+}
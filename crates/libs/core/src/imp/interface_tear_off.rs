@@ -0,0 +1,143 @@
+use super::*;
+use crate::{
+    IInspectable, IInspectable_Vtbl, IUnknownImpl, IUnknown_Vtbl, Interface, GUID, HRESULT,
+};
+use core::ffi::c_void;
+use core::ptr::NonNull;
+
+/// Implements one of the `tearoff` interfaces of an `#[implement]` object.
+///
+/// A tear-off is allocated by `QueryInterface` and holds a reference to its object, so interfaces
+/// that are rarely used don't add a vtable pointer to every instance. The vtable of a tear-off is
+/// built by [`TearOffVtable`] and each of its methods forwards to the object.
+#[repr(C)]
+pub struct InterfaceTearOff<T: IUnknownImpl> {
+    vtable: *const c_void,
+    count: RefCount,
+    object: NonNull<T>,
+}
+
+impl<T: IUnknownImpl> InterfaceTearOff<T> {
+    /// Creates a tear-off with the given vtable and returns its interface pointer, which owns the
+    /// only reference to the tear-off. The tear-off adds a reference to `object`.
+    pub fn create(object: &T, vtable: *const c_void) -> *mut c_void {
+        object.AddRef();
+
+        Box::into_raw(Box::new(Self {
+            vtable,
+            count: RefCount::new(1),
+            object: NonNull::from(object),
+        })) as *mut c_void
+    }
+
+    /// Returns the object that the tear-off belongs to.
+    ///
+    /// # Safety
+    ///
+    /// `this` must be an interface pointer returned by [`Self::create`] that has not been released.
+    #[inline(always)]
+    pub unsafe fn object<'a>(this: *mut c_void) -> &'a T {
+        unsafe { (*(this as *const Self)).object.as_ref() }
+    }
+
+    // The object's identity and other interfaces are only available from the object itself.
+    unsafe extern "system" fn query_interface(
+        this: *mut c_void,
+        iid: *const GUID,
+        interface: *mut *mut c_void,
+    ) -> HRESULT {
+        unsafe { Self::object(this).QueryInterface(iid, interface) }
+    }
+
+    unsafe extern "system" fn add_ref(this: *mut c_void) -> u32 {
+        unsafe { (*(this as *const Self)).count.add_ref() }
+    }
+
+    unsafe extern "system" fn release(this: *mut c_void) -> u32 {
+        unsafe {
+            let remaining = (*(this as *const Self)).count.release();
+
+            if remaining == 0 {
+                let object = Box::from_raw(this as *mut Self).object;
+                T::Release(object.as_ptr());
+            }
+
+            remaining
+        }
+    }
+
+    // The object's identity reports its interfaces and runtime class name, so the answer doesn't
+    // depend on which interface pointer the caller holds.
+    unsafe fn identity(this: *mut c_void) -> Result<IInspectable, HRESULT> {
+        unsafe {
+            let mut identity = core::ptr::null_mut();
+            let hr = Self::object(this).QueryInterface(&IInspectable::IID, &mut identity);
+
+            if hr.is_err() {
+                return Err(hr);
+            }
+
+            Ok(IInspectable::from_raw(identity))
+        }
+    }
+
+    unsafe extern "system" fn get_iids(
+        this: *mut c_void,
+        count: *mut u32,
+        values: *mut *mut GUID,
+    ) -> HRESULT {
+        unsafe {
+            match Self::identity(this) {
+                Ok(identity) => (identity.vtable().GetIids)(identity.as_raw(), count, values),
+                Err(hr) => hr,
+            }
+        }
+    }
+
+    unsafe extern "system" fn get_runtime_class_name(
+        this: *mut c_void,
+        value: *mut *mut c_void,
+    ) -> HRESULT {
+        unsafe {
+            match Self::identity(this) {
+                Ok(identity) => (identity.vtable().GetRuntimeClassName)(identity.as_raw(), value),
+                Err(hr) => hr,
+            }
+        }
+    }
+
+    unsafe extern "system" fn get_trust_level(this: *mut c_void, value: *mut i32) -> HRESULT {
+        unsafe { Self::object(this).GetTrustLevel(value) }
+    }
+}
+
+/// Provides the vtable of an interface for tear-offs of `T`.
+///
+/// The `#[interface]` macro and the `windows-bindgen` crate implement this trait for the vtable
+/// of every interface that derives from `IUnknown`. The methods of the vtable use
+/// [`InterfaceTearOff::object`] to find the object and then call its implementation of the
+/// interface.
+///
+/// This trait is an implementation detail of the Windows crates.
+/// User code should not deal directly with this trait.
+pub trait TearOffVtable<T: IUnknownImpl>: Sized {
+    /// The vtable for tear-offs of `T`.
+    const VTABLE: Self;
+}
+
+impl<T: IUnknownImpl> TearOffVtable<T> for IUnknown_Vtbl {
+    const VTABLE: Self = Self {
+        QueryInterface: InterfaceTearOff::<T>::query_interface,
+        AddRef: InterfaceTearOff::<T>::add_ref,
+        Release: InterfaceTearOff::<T>::release,
+    };
+}
+
+impl<T: IUnknownImpl> TearOffVtable<T> for IInspectable_Vtbl {
+    const VTABLE: Self = Self {
+        base: <IUnknown_Vtbl as TearOffVtable<T>>::VTABLE,
+        GetIids: InterfaceTearOff::<T>::get_iids,
+        GetRuntimeClassName: InterfaceTearOff::<T>::get_runtime_class_name,
+        GetTrustLevel: InterfaceTearOff::<T>::get_trust_level,
+    };
+}
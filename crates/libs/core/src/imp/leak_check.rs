@@ -0,0 +1,30 @@
+use super::*;
+use core::ffi::c_void;
+
+/// Records a new object created by `#[implement]` when the `leak_check` feature is enabled.
+///
+/// # Safety
+///
+/// `count` must remain valid until the object is passed to [`untrack_object`].
+#[inline(always)]
+pub unsafe fn track_object(
+    object: *const c_void,
+    type_name: &'static str,
+    count: *const WeakRefCount,
+) {
+    #[cfg(feature = "leak_check")]
+    crate::leak_check::track(object, type_name, count);
+
+    #[cfg(not(feature = "leak_check"))]
+    let _ = (object, type_name, count);
+}
+
+/// Forgets an object that is about to be destroyed when the `leak_check` feature is enabled.
+#[inline(always)]
+pub fn untrack_object(object: *const c_void) {
+    #[cfg(feature = "leak_check")]
+    crate::leak_check::untrack(object);
+
+    #[cfg(not(feature = "leak_check"))]
+    let _ = object;
+}
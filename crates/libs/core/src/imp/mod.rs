@@ -3,14 +3,22 @@ include!("windows.rs");
 
 mod bindings;
 mod can_into;
+mod class_factory;
 mod com_bindings;
+mod controlling_unknown;
+mod interface_tear_off;
+mod leak_check;
 mod ref_count;
 mod sha1;
 mod weak_ref_count;
 
 pub(crate) use bindings::*;
 pub use can_into::*;
+pub use class_factory::*;
 pub use com_bindings::*;
+pub use controlling_unknown::*;
+pub use interface_tear_off::*;
+pub use leak_check::*;
 pub use ref_count::*;
 pub use sha1::*;
 pub use weak_ref_count::*;
@@ -15,6 +15,11 @@ impl RefCount {
         (self.0.fetch_add(1, Ordering::Relaxed) + 1) as u32
     }
 
+    /// Returns the current value, which is only a snapshot if other threads hold references.
+    pub fn count(&self) -> u32 {
+        self.0.load(Ordering::Relaxed) as u32
+    }
+
     /// Decrements the reference count, returning the new value.
     ///
     /// This operation inserts an `Acquire` fence when the reference count reaches zero.
@@ -23,6 +23,17 @@ impl WeakRefCount {
             .unwrap_or_else(|pointer| unsafe { TearOff::decode(pointer).strong_count.add_ref() })
     }
 
+    /// Returns the current strong reference count, which is only a snapshot if other threads hold references.
+    pub fn count(&self) -> u32 {
+        let count_or_pointer = self.0.load(Ordering::Relaxed);
+
+        if is_weak_ref(count_or_pointer) {
+            unsafe { TearOff::decode(count_or_pointer).strong_count.count() }
+        } else {
+            count_or_pointer as u32
+        }
+    }
+
     #[inline(always)]
     pub fn is_one(&self) -> bool {
         self.0.load(Ordering::Acquire) == 1
@@ -0,0 +1,81 @@
+use super::*;
+use core::ffi::c_void;
+use std::sync::Mutex;
+
+struct TrackedObject {
+    object: usize,
+    type_name: &'static str,
+    count: usize,
+}
+
+static OBJECTS: Mutex<Vec<TrackedObject>> = Mutex::new(Vec::new());
+
+fn objects() -> std::sync::MutexGuard<'static, Vec<TrackedObject>> {
+    // A test that panics while holding the lock should not hide leaks from other tests.
+    OBJECTS.lock().unwrap_or_else(|error| error.into_inner())
+}
+
+pub(crate) fn track(
+    object: *const c_void,
+    type_name: &'static str,
+    count: *const imp::WeakRefCount,
+) {
+    objects().push(TrackedObject {
+        object: object as usize,
+        type_name,
+        count: count as usize,
+    });
+}
+
+pub(crate) fn untrack(object: *const c_void) {
+    let mut objects = objects();
+
+    if let Some(index) = objects
+        .iter()
+        .rposition(|tracked| tracked.object == object as usize)
+    {
+        objects.remove(index);
+    }
+}
+
+/// A live COM object, as reported by [`leak_check`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LiveObject {
+    /// The name of the Rust type that implements the object.
+    pub type_name: &'static str,
+    /// The address of the object.
+    pub address: usize,
+    /// The number of strong references to the object.
+    pub ref_count: u32,
+}
+
+impl core::fmt::Display for LiveObject {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} at {:#x} with {} references",
+            self.type_name, self.address, self.ref_count
+        )
+    }
+}
+
+/// Returns the COM objects created with `#[implement]` that have not yet been destroyed, oldest first.
+///
+/// This requires the `leak_check` feature. Static COM objects are never destroyed and are not
+/// included. Tests can assert that the result is empty once all objects should have been released.
+///
+/// ```rust,ignore
+/// let live = windows_core::leak_check();
+/// assert!(live.is_empty(), "leaked: {live:#?}");
+/// ```
+pub fn leak_check() -> Vec<LiveObject> {
+    objects()
+        .iter()
+        .map(|tracked| LiveObject {
+            type_name: tracked.type_name,
+            address: tracked.object,
+            // Objects are untracked before they are destroyed and the lock is held, so the count is still valid.
+            ref_count: unsafe { (*(tracked.count as *const imp::WeakRefCount)).count() },
+        })
+        .collect()
+}
@@ -24,9 +24,13 @@ pub mod imp;
 
 mod as_impl;
 mod com_object;
+mod com_server;
+mod dispatch;
 mod guid;
 mod inspectable;
 mod interface;
+#[cfg(feature = "leak_check")]
+mod leak_check;
 mod out_param;
 mod out_ref;
 mod param;
@@ -35,15 +39,20 @@ mod r#ref;
 mod runtime_name;
 mod runtime_type;
 mod scoped_interface;
+mod strings;
 mod r#type;
 mod unknown;
 mod weak;
 
 pub use as_impl::*;
 pub use com_object::*;
+pub use com_server::*;
+pub use dispatch::*;
 pub use guid::*;
 pub use inspectable::*;
 pub use interface::*;
+#[cfg(feature = "leak_check")]
+pub use leak_check::*;
 pub use out_param::*;
 pub use out_ref::*;
 pub use param::*;
@@ -59,3 +68,4 @@ pub use windows_implement::implement;
 pub use windows_interface::interface;
 pub use windows_link::link;
 pub use windows_result::*;
+pub use windows_strings::*;
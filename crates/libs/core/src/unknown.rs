@@ -116,6 +116,16 @@ pub trait IUnknownImpl {
     /// Gets the trust level of the current object.
     unsafe fn GetTrustLevel(&self, value: *mut i32) -> HRESULT;
 
+    /// Gets the controlling outer `IUnknown` if this object was created as an aggregated inner object.
+    ///
+    /// The `IUnknown` methods of every interface other than the object's identity are forwarded
+    /// to the controlling `IUnknown`, so that the outer and inner objects share a single COM identity.
+    /// References to those interfaces are also counted by the inner object to keep it alive.
+    #[inline(always)]
+    fn controlling_unknown(&self) -> Option<&IUnknown> {
+        None
+    }
+
     /// Gets a borrowed reference to an interface that is implemented by this ComObject.
     ///
     /// The returned reference does not have an additional reference count.
@@ -158,6 +168,12 @@ impl IUnknown_Vtbl {
         ) -> HRESULT {
             unsafe {
                 let this = (this as *mut *mut c_void).offset(OFFSET) as *mut T;
+                // Only the identity interface, at offset zero, is non-delegating for aggregated objects.
+                if OFFSET != 0 {
+                    if let Some(outer) = (*this).controlling_unknown() {
+                        return (outer.vtable().QueryInterface)(outer.as_raw(), iid, interface);
+                    }
+                }
                 (*this).QueryInterface(iid, interface)
             }
         }
@@ -166,6 +182,14 @@ impl IUnknown_Vtbl {
         ) -> u32 {
             unsafe {
                 let this = (this as *mut *mut c_void).offset(OFFSET) as *mut T;
+                // Delegated references also count the inner object, so that it lives for as long
+                // as any of its interfaces even if the outer object lets go of it.
+                if OFFSET != 0 {
+                    if let Some(outer) = (*this).controlling_unknown() {
+                        (*this).AddRef();
+                        return (outer.vtable().AddRef)(outer.as_raw());
+                    }
+                }
                 (*this).AddRef()
             }
         }
@@ -174,6 +198,14 @@ impl IUnknown_Vtbl {
         ) -> u32 {
             unsafe {
                 let this = (this as *mut *mut c_void).offset(OFFSET) as *mut T;
+                if OFFSET != 0 {
+                    if let Some(outer) = (*this).controlling_unknown() {
+                        // The outer object is released first as it may in turn release the inner object.
+                        let remaining = (outer.vtable().Release)(outer.as_raw());
+                        T::Release(this);
+                        return remaining;
+                    }
+                }
                 T::Release(this)
             }
         }
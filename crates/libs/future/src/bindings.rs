@@ -1135,6 +1135,59 @@ impl IAsyncAction_Vtbl {
         iid == &<IAsyncAction as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAsyncAction_Impl>
+    windows_core::imp::TearOffVtable<Identity> for IAsyncAction_Vtbl
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetCompleted<
+            Identity: windows_core::IUnknownImpl + IAsyncAction_Impl,
+        >(
+            this: *mut core::ffi::c_void,
+            handler: *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAsyncAction_Impl::SetCompleted(this, core::mem::transmute_copy(&handler)).into()
+            }
+        }
+        unsafe extern "system" fn Completed<
+            Identity: windows_core::IUnknownImpl + IAsyncAction_Impl,
+        >(
+            this: *mut core::ffi::c_void,
+            result__: *mut *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAsyncAction_Impl::Completed(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetResults<
+            Identity: windows_core::IUnknownImpl + IAsyncAction_Impl,
+        >(
+            this: *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAsyncAction_Impl::GetResults(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<
+                Identity,
+            >>::VTABLE,
+            SetCompleted: SetCompleted::<Identity>,
+            Completed: Completed::<Identity>,
+            GetResults: GetResults::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAsyncAction_Vtbl {
@@ -1436,6 +1489,106 @@ impl<TProgress: windows_core::RuntimeType + 'static> IAsyncActionWithProgress_Vt
         iid == &<IAsyncActionWithProgress<TProgress> as windows_core::Interface>::IID
     }
 }
+impl<
+        TProgress: windows_core::RuntimeType + 'static,
+        Identity: windows_core::IUnknownImpl + IAsyncActionWithProgress_Impl<TProgress>,
+    > windows_core::imp::TearOffVtable<Identity> for IAsyncActionWithProgress_Vtbl<TProgress>
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetProgress<
+            TProgress: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IAsyncActionWithProgress_Impl<TProgress>,
+        >(
+            this: *mut core::ffi::c_void,
+            handler: *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAsyncActionWithProgress_Impl::SetProgress(
+                    this,
+                    core::mem::transmute_copy(&handler),
+                )
+                .into()
+            }
+        }
+        unsafe extern "system" fn Progress<
+            TProgress: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IAsyncActionWithProgress_Impl<TProgress>,
+        >(
+            this: *mut core::ffi::c_void,
+            result__: *mut *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAsyncActionWithProgress_Impl::Progress(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCompleted<
+            TProgress: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IAsyncActionWithProgress_Impl<TProgress>,
+        >(
+            this: *mut core::ffi::c_void,
+            handler: *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAsyncActionWithProgress_Impl::SetCompleted(
+                    this,
+                    core::mem::transmute_copy(&handler),
+                )
+                .into()
+            }
+        }
+        unsafe extern "system" fn Completed<
+            TProgress: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IAsyncActionWithProgress_Impl<TProgress>,
+        >(
+            this: *mut core::ffi::c_void,
+            result__: *mut *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAsyncActionWithProgress_Impl::Completed(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetResults<
+            TProgress: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IAsyncActionWithProgress_Impl<TProgress>,
+        >(
+            this: *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAsyncActionWithProgress_Impl::GetResults(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<
+                Identity,
+            >>::VTABLE,
+            SetProgress: SetProgress::<TProgress, Identity>,
+            Progress: Progress::<TProgress, Identity>,
+            SetCompleted: SetCompleted::<TProgress, Identity>,
+            Completed: Completed::<TProgress, Identity>,
+            GetResults: GetResults::<TProgress, Identity>,
+            TProgress: core::marker::PhantomData::<TProgress>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAsyncActionWithProgress_Vtbl<TProgress>
@@ -1616,6 +1769,85 @@ impl IAsyncInfo_Vtbl {
         iid == &<IAsyncInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAsyncInfo_Impl>
+    windows_core::imp::TearOffVtable<Identity> for IAsyncInfo_Vtbl
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + IAsyncInfo_Impl>(
+            this: *mut core::ffi::c_void,
+            result__: *mut u32,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAsyncInfo_Impl::Id(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Status<Identity: windows_core::IUnknownImpl + IAsyncInfo_Impl>(
+            this: *mut core::ffi::c_void,
+            result__: *mut AsyncStatus,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAsyncInfo_Impl::Status(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ErrorCode<
+            Identity: windows_core::IUnknownImpl + IAsyncInfo_Impl,
+        >(
+            this: *mut core::ffi::c_void,
+            result__: *mut windows_core::HRESULT,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAsyncInfo_Impl::ErrorCode(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Cancel<Identity: windows_core::IUnknownImpl + IAsyncInfo_Impl>(
+            this: *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAsyncInfo_Impl::Cancel(this).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IAsyncInfo_Impl>(
+            this: *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAsyncInfo_Impl::Close(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<
+                Identity,
+            >>::VTABLE,
+            Id: Id::<Identity>,
+            Status: Status::<Identity>,
+            ErrorCode: ErrorCode::<Identity>,
+            Cancel: Cancel::<Identity>,
+            Close: Close::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAsyncInfo_Vtbl {
@@ -1842,6 +2074,73 @@ impl<TResult: windows_core::RuntimeType + 'static> IAsyncOperation_Vtbl<TResult>
         iid == &<IAsyncOperation<TResult> as windows_core::Interface>::IID
     }
 }
+impl<
+        TResult: windows_core::RuntimeType + 'static,
+        Identity: windows_core::IUnknownImpl + IAsyncOperation_Impl<TResult>,
+    > windows_core::imp::TearOffVtable<Identity> for IAsyncOperation_Vtbl<TResult>
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetCompleted<
+            TResult: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IAsyncOperation_Impl<TResult>,
+        >(
+            this: *mut core::ffi::c_void,
+            handler: *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAsyncOperation_Impl::SetCompleted(this, core::mem::transmute_copy(&handler)).into()
+            }
+        }
+        unsafe extern "system" fn Completed<
+            TResult: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IAsyncOperation_Impl<TResult>,
+        >(
+            this: *mut core::ffi::c_void,
+            result__: *mut *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAsyncOperation_Impl::Completed(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetResults<
+            TResult: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IAsyncOperation_Impl<TResult>,
+        >(
+            this: *mut core::ffi::c_void,
+            result__: *mut windows_core::AbiType<TResult>,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAsyncOperation_Impl::GetResults(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<
+                Identity,
+            >>::VTABLE,
+            SetCompleted: SetCompleted::<TResult, Identity>,
+            Completed: Completed::<TResult, Identity>,
+            GetResults: GetResults::<TResult, Identity>,
+            TResult: core::marker::PhantomData::<TResult>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAsyncOperation_Vtbl<TResult>
@@ -2202,6 +2501,122 @@ impl<
         iid == &<IAsyncOperationWithProgress<TResult, TProgress> as windows_core::Interface>::IID
     }
 }
+impl<
+        TResult: windows_core::RuntimeType + 'static,
+        TProgress: windows_core::RuntimeType + 'static,
+        Identity: windows_core::IUnknownImpl + IAsyncOperationWithProgress_Impl<TResult, TProgress>,
+    > windows_core::imp::TearOffVtable<Identity>
+    for IAsyncOperationWithProgress_Vtbl<TResult, TProgress>
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetProgress<
+            TResult: windows_core::RuntimeType + 'static,
+            TProgress: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IAsyncOperationWithProgress_Impl<TResult, TProgress>,
+        >(
+            this: *mut core::ffi::c_void,
+            handler: *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAsyncOperationWithProgress_Impl::SetProgress(
+                    this,
+                    core::mem::transmute_copy(&handler),
+                )
+                .into()
+            }
+        }
+        unsafe extern "system" fn Progress<
+            TResult: windows_core::RuntimeType + 'static,
+            TProgress: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IAsyncOperationWithProgress_Impl<TResult, TProgress>,
+        >(
+            this: *mut core::ffi::c_void,
+            result__: *mut *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAsyncOperationWithProgress_Impl::Progress(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCompleted<
+            TResult: windows_core::RuntimeType + 'static,
+            TProgress: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IAsyncOperationWithProgress_Impl<TResult, TProgress>,
+        >(
+            this: *mut core::ffi::c_void,
+            handler: *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAsyncOperationWithProgress_Impl::SetCompleted(
+                    this,
+                    core::mem::transmute_copy(&handler),
+                )
+                .into()
+            }
+        }
+        unsafe extern "system" fn Completed<
+            TResult: windows_core::RuntimeType + 'static,
+            TProgress: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IAsyncOperationWithProgress_Impl<TResult, TProgress>,
+        >(
+            this: *mut core::ffi::c_void,
+            result__: *mut *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAsyncOperationWithProgress_Impl::Completed(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetResults<
+            TResult: windows_core::RuntimeType + 'static,
+            TProgress: windows_core::RuntimeType + 'static,
+            Identity: windows_core::IUnknownImpl + IAsyncOperationWithProgress_Impl<TResult, TProgress>,
+        >(
+            this: *mut core::ffi::c_void,
+            result__: *mut windows_core::AbiType<TResult>,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAsyncOperationWithProgress_Impl::GetResults(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<
+                Identity,
+            >>::VTABLE,
+            SetProgress: SetProgress::<TResult, TProgress, Identity>,
+            Progress: Progress::<TResult, TProgress, Identity>,
+            SetCompleted: SetCompleted::<TResult, TProgress, Identity>,
+            Completed: Completed::<TResult, TProgress, Identity>,
+            GetResults: GetResults::<TResult, TProgress, Identity>,
+            TResult: core::marker::PhantomData::<TResult>,
+            TProgress: core::marker::PhantomData::<TProgress>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAsyncOperationWithProgress_Vtbl<TResult, TProgress>
@@ -24,6 +24,19 @@ pub(crate) fn gen_all(inputs: &ImplementInputs) -> Vec<syn::Item> {
     items.push(gen_impl_impl(inputs));
     items.push(gen_iunknown_impl(inputs));
     items.push(gen_impl_com_object_inner(inputs));
+
+    if inputs.aggregatable {
+        items.push(gen_impl_com_object_aggregate(inputs));
+    }
+
+    if let Some(clsid) = inputs.clsid {
+        items.push(gen_impl_com_class(inputs, clsid));
+    }
+
+    if inputs.dispatch {
+        items.push(gen_impl_dispatch(inputs));
+    }
+
     items.extend(gen_impl_from(inputs));
     items.extend(gen_impl_com_object_interfaces(inputs));
 
@@ -85,6 +98,12 @@ fn gen_impl_struct(inputs: &ImplementInputs) -> syn::Item {
         count: ::windows_core::imp::WeakRefCount,
     });
 
+    if inputs.aggregatable {
+        impl_fields.extend(quote! {
+            outer: ::windows_core::imp::ControllingUnknown,
+        });
+    }
+
     parse_quote! {
         #[repr(C)]
         #[allow(non_camel_case_types)]
@@ -167,6 +186,17 @@ fn gen_impl_impl(inputs: &ImplementInputs) -> syn::Item {
         });
     }
 
+    for tear_off in &inputs.tear_offs {
+        let vtbl_ty = tear_off.implement.to_vtbl_ident();
+        let vtable_const_ident = &tear_off.vtable_const_ident;
+
+        // The methods of a tear-off find the object through the tear-off rather than an offset.
+        output.items.push(parse_quote! {
+            const #vtable_const_ident: #vtbl_ty =
+                <#vtbl_ty as ::windows_core::imp::TearOffVtable<#impl_ident::#generics>>::VTABLE;
+        });
+    }
+
     syn::Item::Impl(output)
 }
 
@@ -195,6 +225,7 @@ fn gen_iunknown_impl(inputs: &ImplementInputs) -> syn::Item {
 
             #[inline(always)]
             fn into_inner(self) -> Self::Impl {
+                ::windows_core::imp::module_release();
                 self.this
             }
 
@@ -207,7 +238,9 @@ fn gen_iunknown_impl(inputs: &ImplementInputs) -> syn::Item {
             unsafe fn Release(self_: *mut Self) -> u32 {
                 let remaining = (*self_).count.release();
                 if remaining == 0 {
+                    ::windows_core::imp::untrack_object(self_ as *const ::core::ffi::c_void);
                     _ = ::windows_core::imp::Box::from_raw(self_);
+                    ::windows_core::imp::module_release();
                 }
                 remaining
             }
@@ -239,6 +272,15 @@ fn gen_iunknown_impl(inputs: &ImplementInputs) -> syn::Item {
     let query_interface_fn = gen_query_interface(inputs);
     output.items.push(syn::ImplItem::Fn(query_interface_fn));
 
+    if inputs.aggregatable {
+        output.items.push(parse_quote! {
+            #[inline(always)]
+            fn controlling_unknown(&self) -> ::core::option::Option<&::windows_core::IUnknown> {
+                self.outer.get()
+            }
+        });
+    }
+
     syn::Item::Impl(output)
 }
 
@@ -262,9 +304,12 @@ fn gen_impl_com_object_inner(inputs: &ImplementInputs) -> syn::Item {
             // This is why this function returns ComObject<Self> instead of returning #impl_ident.
 
             fn into_object(self) -> ::windows_core::ComObject<Self> {
+                // Every object is counted so that `DllCanUnloadNow` knows when they are all gone.
+                ::windows_core::imp::module_add_ref();
                 let boxed = ::windows_core::imp::Box::<#impl_ident::#generics>::new(self.into_outer());
                 unsafe {
                     let ptr = ::windows_core::imp::Box::into_raw(boxed);
+                    ::windows_core::imp::track_object(ptr as *const ::core::ffi::c_void, ::core::any::type_name::<Self>(), &(*ptr).count);
                     ::windows_core::ComObject::from_raw(
                         ::core::ptr::NonNull::new_unchecked(ptr)
                     )
@@ -274,6 +319,109 @@ fn gen_impl_com_object_inner(inputs: &ImplementInputs) -> syn::Item {
     }
 }
 
+/// Generates the implementation of `ComObjectAggregate` for types that are `aggregatable`.
+fn gen_impl_com_object_aggregate(inputs: &ImplementInputs) -> syn::Item {
+    let original_ident = &inputs.original_type.ident;
+    let generics = &inputs.generics;
+    let constraints = &inputs.constraints;
+    let impl_ident = &inputs.impl_ident;
+
+    parse_quote! {
+        impl #generics ::windows_core::ComObjectAggregate for #original_ident::#generics where #constraints {
+            // Like `into_object`, this never exposes an owned instance of the outer type.
+            fn into_aggregated_object(self, outer: &::windows_core::IUnknown) -> ::windows_core::ComObject<Self> {
+                ::windows_core::imp::module_add_ref();
+                let mut boxed = ::windows_core::imp::Box::<#impl_ident::#generics>::new(self.into_outer());
+                boxed.outer = ::windows_core::imp::ControllingUnknown::new(outer);
+                unsafe {
+                    let ptr = ::windows_core::imp::Box::into_raw(boxed);
+                    ::windows_core::imp::track_object(ptr as *const ::core::ffi::c_void, ::core::any::type_name::<Self>(), &(*ptr).count);
+                    ::windows_core::ComObject::from_raw(
+                        ::core::ptr::NonNull::new_unchecked(ptr)
+                    )
+                }
+            }
+        }
+    }
+}
+
+/// Generates the implementation of `Dispatch` for types that specify `dispatch`, which the
+/// `windows` crate uses to implement `IDispatch` in terms of the first (dual) interface.
+fn gen_impl_dispatch(inputs: &ImplementInputs) -> syn::Item {
+    let impl_ident = &inputs.impl_ident;
+    let generics = &inputs.generics;
+    let constraints = &inputs.constraints;
+    let interface = inputs.interface_chains[0].implement.to_ident();
+
+    parse_quote! {
+        impl #generics ::windows_core::Dispatch for #impl_ident::#generics where #constraints {
+            type Variant = <#interface as ::windows_core::DualInterface>::Variant;
+            const NAMES: &'static [&'static str] = <#interface as ::windows_core::DualInterface>::NAMES;
+
+            unsafe fn invoke(&self, id: i32, args: &[Self::Variant]) -> ::windows_core::Result<Self::Variant> {
+                unsafe { <#interface>::dispatch_invoke(self, id, args) }
+            }
+        }
+    }
+}
+
+/// Generates the implementation of `ComClass` for types that specify a `clsid`.
+fn gen_impl_com_class(inputs: &ImplementInputs, clsid: u128) -> syn::Item {
+    let original_ident = &inputs.original_type.ident;
+    let name = original_ident.to_string();
+    let clsid = proc_macro2::Literal::u128_unsuffixed(clsid);
+
+    let threading_model = if inputs.agile {
+        quote! { Both }
+    } else {
+        quote! { Apartment }
+    };
+
+    // Only aggregatable objects may be created with a controlling `IUnknown`, and COM requires
+    // that the interface requested for an aggregated object be `IUnknown`.
+    let aggregate = if inputs.aggregatable {
+        quote! {
+            if let ::core::option::Option::Some(outer) = <::windows_core::IUnknown as ::windows_core::Interface>::from_raw_borrowed(&outer) {
+                if *iid != <::windows_core::IUnknown as ::windows_core::Interface>::IID {
+                    return ::windows_core::imp::CLASS_E_NOAGGREGATION;
+                }
+
+                let object = ::windows_core::ComObject::new_aggregated(<Self as ::core::default::Default>::default(), outer);
+                *interface = ::windows_core::Interface::into_raw(object.into_interface::<::windows_core::IUnknown>());
+                return ::windows_core::HRESULT(0);
+            }
+        }
+    } else {
+        quote! {
+            if !outer.is_null() {
+                return ::windows_core::imp::CLASS_E_NOAGGREGATION;
+            }
+        }
+    };
+
+    parse_quote! {
+        impl ::windows_core::ComClass for #original_ident {
+            const REGISTRATION: ::windows_core::ClassRegistration = ::windows_core::ClassRegistration {
+                clsid: ::windows_core::GUID::from_u128(#clsid),
+                name: #name,
+                threading_model: ::windows_core::ThreadingModel::#threading_model,
+            };
+
+            unsafe fn create_instance(
+                outer: *mut ::core::ffi::c_void,
+                iid: *const ::windows_core::GUID,
+                interface: *mut *mut ::core::ffi::c_void,
+            ) -> ::windows_core::HRESULT {
+                unsafe {
+                    #aggregate
+                    let object = ::windows_core::ComObject::new(<Self as ::core::default::Default>::default());
+                    ::windows_core::Interface::query(&*object.as_interface::<::windows_core::IUnknown>(), iid, interface)
+                }
+            }
+        }
+    }
+}
+
 /// Generates the `query_interface` method.
 fn gen_query_interface(inputs: &ImplementInputs) -> syn::ImplItemFn {
     let queries = inputs.interface_chains.iter().map(|interface_chain| {
@@ -287,6 +435,18 @@ fn gen_query_interface(inputs: &ImplementInputs) -> syn::ImplItemFn {
         }
     });
 
+    let tear_off_queries = inputs.tear_offs.iter().map(|tear_off| {
+        let vtbl_ty = tear_off.implement.to_vtbl_ident();
+        let vtable_const_ident = &tear_off.vtable_const_ident;
+        quote_spanned! {
+            tear_off.implement.span =>
+            if #vtbl_ty::matches(&iid) {
+                *interface = ::windows_core::imp::InterfaceTearOff::create(self, &Self::#vtable_const_ident as *const _ as *const ::core::ffi::c_void);
+                return ::windows_core::HRESULT(0);
+            }
+        }
+    });
+
     // Dynamic casting requires that the object not contain non-static lifetimes.
     let enable_dyn_casting = inputs.original_type.generics.lifetimes().count() == 0;
     let dynamic_cast_query = if enable_dyn_casting {
@@ -342,6 +502,51 @@ fn gen_query_interface(inputs: &ImplementInputs) -> syn::ImplItemFn {
         }
     };
 
+    // An aggregated object leaves marshaling and weak references to its controlling `IUnknown`.
+    let (marshal_query, tear_off_query) = if inputs.aggregatable {
+        (
+            quote! {
+                if self.outer.get().is_none() {
+                    #marshal_query
+                }
+            },
+            quote! {
+                if self.outer.get().is_none() {
+                    #tear_off_query
+                }
+            },
+        )
+    } else {
+        (marshal_query, tear_off_query)
+    };
+
+    // The reference is added through the returned interface so that every interface other than
+    // the identity of an aggregated object is counted by the controlling `IUnknown`.
+    let add_ref = if inputs.aggregatable {
+        quote! {
+            let vtable = *(interface_ptr as *const *const ::windows_core::IUnknown_Vtbl);
+            ((*vtable).AddRef)(interface_ptr as *mut ::core::ffi::c_void);
+        }
+    } else {
+        quote! {
+            self.count.add_ref();
+        }
+    };
+
+    // Interfaces that this object doesn't implement may be implemented by its aggregated inner
+    // object, whose non-delegating `QueryInterface` adds the reference through this object.
+    let aggregated_query = if inputs.aggregates {
+        let original_ident = &inputs.original_type.ident;
+        let generics = &inputs.generics;
+        quote! {
+            if let ::core::option::Option::Some(inner) = <#original_ident::#generics as ::windows_core::ComObjectAggregator>::aggregated(&self.this) {
+                return ::windows_core::Interface::query(&*inner, &iid, interface);
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     parse_quote! {
         unsafe fn QueryInterface(
             &self,
@@ -358,9 +563,11 @@ fn gen_query_interface(inputs: &ImplementInputs) -> syn::ImplItemFn {
                 let interface_ptr: *const ::core::ffi::c_void = 'found: {
                     #identity_query
                     #(#queries)*
+                    #(#tear_off_queries)*
                     #marshal_query
                     #dynamic_cast_query
                     #tear_off_query
+                    #aggregated_query
 
                     *interface = ::core::ptr::null_mut();
                     return ::windows_core::imp::E_NOINTERFACE;
@@ -368,7 +575,7 @@ fn gen_query_interface(inputs: &ImplementInputs) -> syn::ImplItemFn {
 
                 debug_assert!(!interface_ptr.is_null());
                 *interface = interface_ptr as *mut ::core::ffi::c_void;
-                self.count.add_ref();
+                #add_ref
                 return ::windows_core::HRESULT(0);
             }
         }
@@ -395,6 +602,12 @@ fn gen_into_outer(inputs: &ImplementInputs) -> syn::ImplItem {
         });
     }
 
+    if inputs.aggregatable {
+        initializers.extend(quote! {
+            outer: ::windows_core::imp::ControllingUnknown::none(),
+        });
+    }
+
     // If the type is generic then into_outer() cannot be a const fn.
     let maybe_const = if inputs.is_generic {
         quote!()
@@ -5,6 +5,7 @@
 //! Learn more about Rust for Windows here: <https://github.com/microsoft/windows-rs>
 
 use quote::{quote, ToTokens};
+use syn::spanned::Spanned;
 
 mod r#gen;
 use r#gen::gen_all;
@@ -48,8 +49,59 @@ fn implement_core(
     attributes: proc_macro2::TokenStream,
     item_tokens: proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
-    let attributes = syn::parse2::<ImplementAttributes>(attributes).unwrap();
-    let original_type = syn::parse2::<syn::ItemStruct>(item_tokens).unwrap();
+    match try_implement_core(attributes, item_tokens) {
+        Ok(tokens) => tokens,
+        Err(error) => error.to_compile_error(),
+    }
+}
+
+fn try_implement_core(
+    attributes: proc_macro2::TokenStream,
+    item_tokens: proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let attributes = syn::parse2::<ImplementAttributes>(attributes)?;
+    let original_type = parse_struct(item_tokens)?;
+
+    if let Some((key, _)) = &attributes.clsid {
+        if !original_type.generics.params.is_empty() {
+            return Err(syn::Error::new(
+                key.span(),
+                "`clsid` cannot be used with a generic type",
+            ));
+        }
+    }
+
+    if let Some(key) = &attributes.dispatch {
+        if attributes.implement.is_empty() {
+            return Err(syn::Error::new(
+                key.span(),
+                "`dispatch` requires a dual interface to be implemented first",
+            ));
+        }
+    }
+
+    for tear_off in &attributes.tear_offs {
+        if attributes.aggregatable {
+            return Err(syn::Error::new(
+                tear_off.span,
+                "`tearoff` cannot be used with `aggregatable`",
+            ));
+        }
+
+        if attributes
+            .implement
+            .iter()
+            .any(|existing| existing.type_name == tear_off.type_name)
+        {
+            return Err(syn::Error::new(
+                tear_off.span,
+                format!(
+                    "interface `{}` is implemented more than once",
+                    tear_off.type_name
+                ),
+            ));
+        }
+    }
 
     // Do a little thinking and assemble ImplementInputs.  We pass ImplementInputs to
     // all of our gen_* function.
@@ -58,6 +110,11 @@ fn implement_core(
         interface_chains: convert_implements_to_interface_chains(attributes.implement),
         trust_level: attributes.trust_level,
         agile: attributes.agile,
+        aggregatable: attributes.aggregatable,
+        aggregates: attributes.aggregates,
+        clsid: attributes.clsid.map(|(_, clsid)| clsid),
+        dispatch: attributes.dispatch.is_some(),
+        tear_offs: convert_tear_offs(attributes.tear_offs),
         impl_ident: quote::format_ident!("{}_Impl", &original_type.ident),
         constraints: {
             if let Some(where_clause) = &original_type.generics.where_clause {
@@ -83,7 +140,25 @@ fn implement_core(
         tokens.extend(item.into_token_stream());
     }
 
-    tokens
+    Ok(tokens)
+}
+
+/// Parses the item that `#[implement]` is applied to, which must be a struct.
+fn parse_struct(item_tokens: proc_macro2::TokenStream) -> syn::Result<syn::ItemStruct> {
+    let (span, kind) = match syn::parse2::<syn::Item>(item_tokens)? {
+        syn::Item::Struct(item) => return Ok(item),
+        syn::Item::Enum(item) => (item.enum_token.span, "an enum"),
+        syn::Item::Union(item) => (item.union_token.span, "a union"),
+        syn::Item::Trait(item) => (item.trait_token.span, "a trait"),
+        syn::Item::Fn(item) => (item.sig.fn_token.span, "a function"),
+        syn::Item::Impl(item) => (item.impl_token.span, "an impl block"),
+        item => (item.span(), "this item"),
+    };
+
+    Err(syn::Error::new(
+        span,
+        format!("`#[implement]` can only be applied to a struct, not {kind}"),
+    ))
 }
 
 /// This provides the inputs to the `gen_*` functions, which generate the proc macro output.
@@ -103,6 +178,21 @@ struct ImplementInputs {
     /// Determines whether `IAgileObject` and `IMarshal` are implemented automatically.
     agile: bool,
 
+    /// Determines whether the object can be created as an aggregated inner object.
+    aggregatable: bool,
+
+    /// Determines whether unknown interfaces are forwarded to an aggregated inner object.
+    aggregates: bool,
+
+    /// The class identifier, if the type is a COM class that can be created by a class factory.
+    clsid: Option<u128>,
+
+    /// The interfaces that are implemented by tear-offs allocated on demand.
+    tear_offs: Vec<TearOff>,
+
+    /// Determines whether `IDispatch` is implemented using the first interface, which must be a dual interface.
+    dispatch: bool,
+
     /// The identifier of the `Foo_Impl` type.
     impl_ident: syn::Ident,
 
@@ -129,8 +219,17 @@ struct InterfaceChain {
     implement: ImplementType,
 }
 
+/// Describes one interface that is implemented by a tear-off.
+struct TearOff {
+    /// The name of the associated constant item for the tear-off's vtable, e.g. `VTABLE_TEAROFF1_IFOO`.
+    vtable_const_ident: syn::Ident,
+
+    implement: ImplementType,
+}
+
 struct ImplementType {
     type_name: String,
+    path: Vec<syn::Ident>,
     generics: Vec<ImplementType>,
 
     /// The best span for diagnostics.
@@ -139,10 +238,9 @@ struct ImplementType {
 
 impl ImplementType {
     fn to_ident(&self) -> proc_macro2::TokenStream {
-        let type_name = syn::parse_str::<proc_macro2::TokenStream>(&self.type_name)
-            .expect("Invalid token stream");
+        let path = &self.path;
         let generics = self.generics.iter().map(|g| g.to_ident());
-        quote! { #type_name<#(#generics,)*> }
+        quote! { #(#path)::*<#(#generics,)*> }
     }
     fn to_vtbl_ident(&self) -> proc_macro2::TokenStream {
         let ident = self.to_ident();
@@ -157,6 +255,13 @@ struct ImplementAttributes {
     pub implement: Vec<ImplementType>,
     pub trust_level: usize,
     pub agile: bool,
+    pub aggregatable: bool,
+    pub aggregates: bool,
+    pub clsid: Option<(syn::Ident, u128)>,
+    pub dispatch: Option<syn::Ident>,
+    pub tear_offs: Vec<ImplementType>,
+    /// Tracks which options have been specified so that repeated options can be rejected.
+    options: Vec<String>,
 }
 
 impl syn::parse::Parse for ImplementAttributes {
@@ -177,9 +282,13 @@ impl syn::parse::Parse for ImplementAttributes {
 impl ImplementAttributes {
     fn parse_implement(&mut self, cursor: syn::parse::ParseStream) -> syn::parse::Result<()> {
         let tree = cursor.parse::<UseTree2>()?;
-        self.walk_implement(&tree, &mut String::new())?;
+        self.walk_implement(&tree, &mut Vec::new())?;
 
         if !cursor.is_empty() {
+            if !cursor.peek(syn::Token![,]) {
+                return Err(cursor.error("expected `,` between interfaces"));
+            }
+
             cursor.parse::<syn::Token![,]>()?;
         }
 
@@ -189,77 +298,170 @@ impl ImplementAttributes {
     fn walk_implement(
         &mut self,
         tree: &UseTree2,
-        namespace: &mut String,
+        namespace: &mut Vec<syn::Ident>,
     ) -> syn::parse::Result<()> {
         match tree {
             UseTree2::Path(input) => {
-                if !namespace.is_empty() {
-                    namespace.push_str("::");
-                }
-
-                namespace.push_str(&input.ident.to_string());
+                let len = namespace.len();
+                namespace.push(input.ident.clone());
                 self.walk_implement(&input.tree, namespace)?;
+                namespace.truncate(len);
             }
             UseTree2::Name(_) => {
-                self.implement.push(tree.to_element_type(namespace)?);
+                let implement = tree.to_element_type(namespace)?;
+
+                if self
+                    .implement
+                    .iter()
+                    .any(|existing| existing.type_name == implement.type_name)
+                {
+                    return Err(syn::Error::new(
+                        implement.span,
+                        format!(
+                            "interface `{}` is implemented more than once",
+                            implement.type_name
+                        ),
+                    ));
+                }
+
+                self.implement.push(implement);
             }
             UseTree2::Group(input) => {
                 for tree in &input.items {
                     self.walk_implement(tree, namespace)?;
                 }
             }
-            UseTree2::TrustLevel(input) => self.trust_level = *input,
-            UseTree2::Agile(agile) => self.agile = *agile,
+            UseTree2::TrustLevel(key, input) => {
+                self.option(key, namespace)?;
+                self.trust_level = *input;
+            }
+            UseTree2::Agile(key, agile) => {
+                self.option(key, namespace)?;
+                self.agile = *agile;
+            }
+            UseTree2::Aggregatable(key) => {
+                self.option(key, namespace)?;
+                self.aggregatable = true;
+            }
+            UseTree2::Aggregates(key) => {
+                self.option(key, namespace)?;
+                self.aggregates = true;
+            }
+            UseTree2::Clsid(key, clsid) => {
+                self.option(key, namespace)?;
+                self.clsid = Some((key.clone(), *clsid));
+            }
+            UseTree2::Dispatch(key) => {
+                self.option(key, namespace)?;
+                self.dispatch = Some(key.clone());
+            }
+            UseTree2::TearOff(key, trees) => {
+                self.option(key, namespace)?;
+
+                let mut tear_offs = Self::default();
+
+                for tree in trees {
+                    tear_offs.walk_implement(tree, &mut Vec::new())?;
+                }
+
+                if !tear_offs.options.is_empty() {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        "`tearoff` can only contain interfaces",
+                    ));
+                }
+
+                self.tear_offs = tear_offs.implement;
+            }
         }
 
         Ok(())
     }
+
+    /// Checks that an option is specified at most once and not within a path or group.
+    fn option(&mut self, key: &syn::Ident, namespace: &[syn::Ident]) -> syn::parse::Result<()> {
+        if !namespace.is_empty() {
+            return Err(syn::Error::new(
+                key.span(),
+                format!("`{key}` cannot be part of a path"),
+            ));
+        }
+
+        let name = key.to_string();
+
+        if self.options.contains(&name) {
+            return Err(syn::Error::new(
+                key.span(),
+                format!("`{key}` is specified more than once"),
+            ));
+        }
+
+        self.options.push(name);
+        Ok(())
+    }
 }
 
 enum UseTree2 {
     Path(UsePath2),
     Name(UseName2),
     Group(UseGroup2),
-    TrustLevel(usize),
-    Agile(bool),
+    TrustLevel(syn::Ident, usize),
+    Agile(syn::Ident, bool),
+    Aggregatable(syn::Ident),
+    Aggregates(syn::Ident),
+    Clsid(syn::Ident, u128),
+    Dispatch(syn::Ident),
+    TearOff(syn::Ident, Vec<UseTree2>),
 }
 
 impl UseTree2 {
-    fn to_element_type(&self, namespace: &mut String) -> syn::parse::Result<ImplementType> {
+    fn to_element_type(
+        &self,
+        namespace: &mut Vec<syn::Ident>,
+    ) -> syn::parse::Result<ImplementType> {
         match self {
             Self::Path(input) => {
-                if !namespace.is_empty() {
-                    namespace.push_str("::");
-                }
-
-                namespace.push_str(&input.ident.to_string());
+                namespace.push(input.ident.clone());
                 input.tree.to_element_type(namespace)
             }
             Self::Name(input) => {
-                let mut type_name = input.ident.to_string();
-                let span = input.ident.span();
+                let mut path = namespace.clone();
+                path.push(input.ident.clone());
 
-                if !namespace.is_empty() {
-                    type_name = format!("{namespace}::{type_name}");
-                }
+                let type_name = path
+                    .iter()
+                    .map(|ident| ident.to_string())
+                    .collect::<Vec<_>>()
+                    .join("::");
 
+                let span = input.ident.span();
                 let mut generics = vec![];
 
                 for g in &input.generics {
-                    generics.push(g.to_element_type(&mut String::new())?);
+                    generics.push(g.to_element_type(&mut Vec::new())?);
                 }
 
                 Ok(ImplementType {
                     type_name,
+                    path,
                     generics,
                     span,
                 })
             }
             Self::Group(input) => Err(syn::parse::Error::new(
                 input.brace_token.span.join(),
-                "Syntax not supported",
+                "a group of interfaces cannot be used as a generic argument",
+            )),
+            Self::TrustLevel(key, _)
+            | Self::Agile(key, _)
+            | Self::Aggregatable(key)
+            | Self::Aggregates(key)
+            | Self::Clsid(key, _)
+            | Self::Dispatch(key)
+            | Self::TearOff(key, _) => Err(syn::parse::Error::new(
+                key.span(),
+                format!("`{key}` cannot be used as a generic argument"),
             )),
-            _ => unimplemented!(),
         }
     }
 }
@@ -292,36 +494,57 @@ impl syn::parse::Parse for UseTree2 {
                     tree: Box::new(input.parse()?),
                 }))
             } else if input.peek(syn::Token![=]) {
+                input.parse::<syn::Token![=]>()?;
+
+                // The value is parsed as any token so that literals such as `"true"` get a targeted diagnostic.
+                let value = input.parse::<proc_macro2::TokenTree>()?;
+
                 if ident == "TrustLevel" {
-                    input.parse::<syn::Token![=]>()?;
-                    let span = input.span();
-                    let value = input.call(syn::Ident::parse_any)?;
                     match value.to_string().as_str() {
-                        "Partial" => Ok(Self::TrustLevel(1)),
-                        "Full" => Ok(Self::TrustLevel(2)),
+                        "Partial" => Ok(Self::TrustLevel(ident, 1)),
+                        "Full" => Ok(Self::TrustLevel(ident, 2)),
                         _ => Err(syn::parse::Error::new(
-                            span,
-                            "`TrustLevel` must be `Partial` or `Full`",
+                            value.span(),
+                            format!("`TrustLevel` must be `Partial` or `Full`, not `{value}`"),
                         )),
                     }
                 } else if ident == "Agile" {
-                    input.parse::<syn::Token![=]>()?;
-                    let span = input.span();
-                    let value = input.call(syn::Ident::parse_any)?;
                     match value.to_string().as_str() {
-                        "true" => Ok(Self::Agile(true)),
-                        "false" => Ok(Self::Agile(false)),
+                        "true" => Ok(Self::Agile(ident, true)),
+                        "false" => Ok(Self::Agile(ident, false)),
                         _ => Err(syn::parse::Error::new(
-                            span,
-                            "`Agile` must be `true` or `false`",
+                            value.span(),
+                            format!("`Agile` must be `true` or `false`, not `{value}`"),
+                        )),
+                    }
+                } else if ident == "clsid" {
+                    match parse_guid(&value) {
+                        Some(clsid) => Ok(Self::Clsid(ident, clsid)),
+                        None => Err(syn::parse::Error::new(
+                            value.span(),
+                            format!("`clsid` must be a GUID string such as \"00000000-0000-0000-0000-000000000000\", not `{value}`"),
                         )),
                     }
                 } else {
                     Err(syn::parse::Error::new(
                         ident.span(),
-                        "Unrecognized key-value pair",
+                        format!(
+                            "unknown option `{ident}`; expected `TrustLevel`, `Agile` or `clsid`"
+                        ),
                     ))
                 }
+            } else if ident == "aggregatable" && !input.peek(syn::Token![<]) {
+                Ok(Self::Aggregatable(ident))
+            } else if ident == "aggregates" && !input.peek(syn::Token![<]) {
+                Ok(Self::Aggregates(ident))
+            } else if ident == "dispatch" && !input.peek(syn::Token![<]) {
+                Ok(Self::Dispatch(ident))
+            } else if ident == "tearoff" && input.peek(syn::token::Paren) {
+                let content;
+                syn::parenthesized!(content in input);
+                let items = content.parse_terminated(Self::parse, syn::Token![,])?;
+
+                Ok(Self::TearOff(ident, items.into_iter().collect()))
             } else {
                 let generics = if input.peek(syn::Token![<]) {
                     input.parse::<syn::Token![<]>()?;
@@ -333,6 +556,10 @@ impl syn::parse::Parse for UseTree2 {
                             break;
                         }
                     }
+                    if !input.peek(syn::Token![>]) {
+                        return Err(input.error("expected `,` or `>` in generic arguments"));
+                    }
+
                     input.parse::<syn::Token![>]>()?;
                     generics
                 } else {
@@ -347,12 +574,38 @@ impl syn::parse::Parse for UseTree2 {
             let items = content.parse_terminated(Self::parse, syn::Token![,])?;
 
             Ok(Self::Group(UseGroup2 { brace_token, items }))
+        } else if input.peek(syn::LitStr) {
+            Err(input.error("expected an interface name rather than a string literal"))
         } else {
             Err(lookahead.error())
         }
     }
 }
 
+/// Parses a string literal containing a GUID in the registry format without braces.
+fn parse_guid(value: &proc_macro2::TokenTree) -> Option<u128> {
+    let proc_macro2::TokenTree::Literal(literal) = value else {
+        return None;
+    };
+
+    let syn::Lit::Str(value) = syn::Lit::new(literal.clone()) else {
+        return None;
+    };
+
+    let value = value.value();
+    let parts: Vec<&str> = value.split('-').collect();
+
+    if parts.iter().map(|part| part.len()).ne([8, 4, 4, 4, 12])
+        || !parts
+            .iter()
+            .all(|part| part.chars().all(|c| c.is_ascii_hexdigit()))
+    {
+        return None;
+    }
+
+    u128::from_str_radix(&parts.concat(), 16).ok()
+}
+
 fn convert_implements_to_interface_chains(implements: Vec<ImplementType>) -> Vec<InterfaceChain> {
     let mut chains = Vec::with_capacity(implements.len());
 
@@ -388,6 +641,27 @@ fn convert_implements_to_interface_chains(implements: Vec<ImplementType>) -> Vec
     chains
 }
 
+fn convert_tear_offs(implements: Vec<ImplementType>) -> Vec<TearOff> {
+    implements
+        .into_iter()
+        .enumerate()
+        .map(|(i, implement)| {
+            let mut vtable_const_string = format!("VTABLE_TEAROFF{}", i + 1);
+
+            let suffix = get_interface_ident_suffix(&implement.type_name);
+            if !suffix.is_empty() {
+                vtable_const_string.push('_');
+                vtable_const_string.push_str(&suffix.to_ascii_uppercase());
+            }
+
+            TearOff {
+                vtable_const_ident: syn::Ident::new(&vtable_const_string, implement.span),
+                implement,
+            }
+        })
+        .collect()
+}
+
 fn get_interface_ident_suffix(type_name: &str) -> String {
     let mut suffix = String::new();
     for c in type_name.chars() {
@@ -133,3 +133,43 @@ fn tuple_type() {
         },
     );
 }
+
+#[test]
+fn aggregatable() {
+    implement(
+        quote!(IFoo, aggregatable),
+        quote! {
+            struct Foo(pub i32);
+        },
+    );
+}
+
+#[test]
+fn dispatch() {
+    implement(
+        quote!(IFoo, dispatch),
+        quote! {
+            struct Foo(pub i32);
+        },
+    );
+}
+
+#[test]
+fn tear_off() {
+    implement(
+        quote!(IFoo, tearoff(IBar, baz::IBaz)),
+        quote! {
+            struct Foo(pub i32);
+        },
+    );
+}
+
+#[test]
+fn aggregates() {
+    implement(
+        quote!(IFoo, aggregates),
+        quote! {
+            struct Foo(pub i32);
+        },
+    );
+}
@@ -31,6 +31,27 @@ use syn::spanned::Spanned;
 /// let object: IValue = Value(123).into();
 /// // Call interface methods...
 /// ```
+///
+/// A method declared as returning `Result<T>` has a trailing `*mut T` out parameter in the ABI, as with a
+/// `[retval]` parameter in IDL. The implementation returns `Result<T>` and callers receive `Result<T>`, with
+/// interface types returned as owned interfaces. Alternatively, the final `*mut T` parameter of a method returning
+/// `HRESULT` may be marked `#[retval]` so that the implementation deals with the raw out parameter while callers
+/// still receive `Result<T>`.
+///
+/// ```rust,no_run
+/// use windows_core::*;
+///
+/// #[interface("0e7e5a7f-7d2c-4b8e-9e5d-3c5d1a2b4c6d")]
+/// unsafe trait IValues: IUnknown {
+///     fn GetValue(&self) -> Result<f64>;
+///     fn GetOther(&self, #[retval] value: *mut f64) -> HRESULT;
+/// }
+/// ```
+///
+/// An interface that derives directly from `IDispatch` is a dual interface. Its methods are also described by a
+/// compile-time dispatch table so that `#[implement(IFoo, dispatch)]` can implement `IDispatch` for scripting
+/// hosts, converting arguments and return values to and from `VARIANT`. Methods that take or return pointers are
+/// left out of the table.
 #[proc_macro_attribute]
 pub fn interface(
     attributes: proc_macro::TokenStream,
@@ -97,6 +118,8 @@ impl Interface {
         let com_trait = self.get_com_trait();
         let vtable = self.gen_vtable(&vtable_name);
         let conversions = self.gen_conversions();
+        let dispatch = self.gen_dispatch();
+        let tear_off = self.gen_tear_off();
 
         Ok(quote! {
             #[repr(transparent)]
@@ -117,6 +140,8 @@ impl Interface {
             #com_trait
             #vtable
             #conversions
+            #dispatch
+            #tear_off
         })
     }
 
@@ -135,7 +160,23 @@ impl Interface {
                 let args = m.gen_consume_args();
                 let ret = &m.ret;
 
-                if m.is_result() {
+                if let Some(retval) = &m.retval {
+                    let ty = retval.ty();
+
+                    let result = match retval {
+                        Retval::Result(_) => quote! { &mut result__ },
+                        Retval::Param(_) => quote! { &mut result__ as *mut _ as *mut #ty },
+                    };
+
+                    quote! {
+                        #[inline(always)]
+                        #vis unsafe fn #name<#(#generics),*>(&self, #(#params),*) -> ::windows_core::Result<#ty> {
+                            let mut result__ = ::core::mem::zeroed::<<#ty as ::windows_core::Type<#ty>>::Abi>();
+                            (::windows_core::Interface::vtable(self).#name)(::windows_core::Interface::as_raw(self), #(#args,)* #result)
+                                .and_then(|| <#ty as ::windows_core::Type<#ty>>::from_abi(result__))
+                        }
+                    }
+                } else if m.is_result() {
                     quote! {
                     #[inline(always)]
                     #vis unsafe fn #name<#(#generics),*>(&self, #(#params),*) #ret {
@@ -201,7 +242,11 @@ impl Interface {
                 let ret = &m.ret;
                 let args = m.gen_args();
 
-                if m.is_result() {
+                if let Some(Retval::Result(ty)) = &m.retval {
+                    quote! {
+                        pub #name: unsafe extern "system" fn(this: *mut ::core::ffi::c_void, #(#args,)* result__: *mut <#ty as ::windows_core::Type<#ty>>::Abi) -> ::windows_core::HRESULT,
+                    }
+                } else if m.is_result() {
                     quote! {
                         pub #name: unsafe extern "system" fn(this: *mut ::core::ffi::c_void, #(#args),*) -> ::windows_core::HRESULT,
                     }
@@ -227,64 +272,16 @@ impl Interface {
             _ => quote!(),
         };
 
+        let thunk = if parent_vtable.is_some() {
+            Thunk::Offset
+        } else {
+            Thunk::Scoped
+        };
+
         let functions = self
             .methods
             .iter()
-            .map(|m| {
-                let name = &m.name;
-                let args = m.gen_args();
-                let params = &m
-                    .args
-                    .iter()
-                    .map(|a| {
-                        let pat = &a.pat;
-                        quote! { #pat }
-                    })
-                    .collect::<Vec<_>>();
-                let ret = &m.ret;
-
-                let ret = if m.is_result() {
-                    quote! { -> ::windows_core::HRESULT }
-                } else {
-                    quote! { #ret }
-                };
-
-                if parent_vtable.is_some() {
-                    quote! {
-                        unsafe extern "system" fn #name<
-                            Identity: ::windows_core::IUnknownImpl,
-                            const OFFSET: isize
-                        >(
-                            this: *mut ::core::ffi::c_void, // <-- This is the COM "this" pointer, which is not the same as &T or &T_Impl.
-                            #(#args),*
-                        ) #ret
-                        where
-                            Identity : #trait_name
-                        {
-                            // This step is essentially a virtual dispatch adjustor thunk. Its purpose is to adjust
-                            // the "this" pointer from the address used by the COM interface to the root of the
-                            // MyApp_Impl object.  Since a given MyApp_Impl may implement more than one COM interface
-                            // (and more than one COM interface chain), we need to know how to get from COM's "this"
-                            // back to &MyApp_Impl. The OFFSET constant gives us the value (in pointer-sized units).
-                            let this_outer: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-
-                            // Last, we invoke the implementation function.
-                            // We use explicit <Impl as IFoo_Impl> so that we can select the correct method
-                            // for situations where IFoo3 derives from IFoo2 and both declare a method with
-                            // the same name.
-                            <Identity as #trait_name>::#name(this_outer, #(#params),*).into()
-                        }
-                    }
-                } else {
-                    quote! {
-                        unsafe extern "system" fn #name<Impl: #trait_name>(this: *mut ::core::ffi::c_void, #(#args),*) #ret {
-                            let this = (this as *mut *mut ::core::ffi::c_void) as *const ::windows_core::ScopedHeap;
-                            let this = (*this).this as *const Impl;
-                            (*this).#name(#(#params),*).into()
-                        }
-                    }
-                }
-            })
+            .map(|m| self.gen_thunk(m, thunk))
             .collect::<Vec<_>>();
 
         if let Some(parent_vtable) = parent_vtable {
@@ -360,6 +357,116 @@ impl Interface {
         }
     }
 
+    /// Generates the function behind a vtable entry, which finds the implementation from the COM
+    /// "this" pointer and then calls the corresponding method of the `_Impl` trait.
+    fn gen_thunk(&self, m: &InterfaceMethod, thunk: Thunk) -> proc_macro2::TokenStream {
+        let trait_name = quote::format_ident!("{}_Impl", self.name);
+        let name = &m.name;
+        let mut args = m.gen_args();
+        let params = &m
+            .args
+            .iter()
+            .map(|a| {
+                let pat = &a.pat;
+                quote! { #pat }
+            })
+            .collect::<Vec<_>>();
+        let ret = &m.ret;
+
+        let ret = if m.is_result() || m.retval.is_some() {
+            quote! { -> ::windows_core::HRESULT }
+        } else {
+            quote! { #ret }
+        };
+
+        // With `-> Result<T>` the value is written to the trailing out parameter that the ABI adds.
+        let result = |call: proc_macro2::TokenStream| {
+            if let Some(Retval::Result(_)) = &m.retval {
+                quote! {
+                    match #call {
+                        ::core::result::Result::Ok(ok__) => {
+                            result__.write(::core::mem::transmute(ok__));
+                            ::windows_core::HRESULT(0)
+                        }
+                        ::core::result::Result::Err(err) => err.into(),
+                    }
+                }
+            } else {
+                quote! { #call.into() }
+            }
+        };
+
+        // The ABI of a primitive type is the type itself so the transmute is only sometimes needed.
+        let allow = if let Some(Retval::Result(ty)) = &m.retval {
+            args.push(quote! { result__: *mut <#ty as ::windows_core::Type<#ty>>::Abi });
+            quote! { #[allow(clippy::useless_transmute)] }
+        } else {
+            quote! {}
+        };
+
+        match thunk {
+            Thunk::Offset => {
+                let call =
+                    result(quote! { <Identity as #trait_name>::#name(this_outer, #(#params),*) });
+
+                quote! {
+                    #allow
+                    unsafe extern "system" fn #name<
+                        Identity: ::windows_core::IUnknownImpl,
+                        const OFFSET: isize
+                    >(
+                        this: *mut ::core::ffi::c_void, // <-- This is the COM "this" pointer, which is not the same as &T or &T_Impl.
+                        #(#args),*
+                    ) #ret
+                    where
+                        Identity : #trait_name
+                    {
+                        // This step is essentially a virtual dispatch adjustor thunk. Its purpose is to adjust
+                        // the "this" pointer from the address used by the COM interface to the root of the
+                        // MyApp_Impl object.  Since a given MyApp_Impl may implement more than one COM interface
+                        // (and more than one COM interface chain), we need to know how to get from COM's "this"
+                        // back to &MyApp_Impl. The OFFSET constant gives us the value (in pointer-sized units).
+                        let this_outer: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+
+                        // Last, we invoke the implementation function.
+                        // We use explicit <Impl as IFoo_Impl> so that we can select the correct method
+                        // for situations where IFoo3 derives from IFoo2 and both declare a method with
+                        // the same name.
+                        #call
+                    }
+                }
+            }
+            Thunk::TearOff => {
+                let call =
+                    result(quote! { <Identity as #trait_name>::#name(this_outer, #(#params),*) });
+
+                quote! {
+                    #allow
+                    unsafe extern "system" fn #name<Identity: ::windows_core::IUnknownImpl + #trait_name>(
+                        this: *mut ::core::ffi::c_void,
+                        #(#args),*
+                    ) #ret {
+                        // A tear-off is a separate allocation that refers to the object.
+                        let this_outer: &Identity = ::windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                        #call
+                    }
+                }
+            }
+            Thunk::Scoped => {
+                let call = result(quote! { (*this).#name(#(#params),*) });
+
+                quote! {
+                    #allow
+                    unsafe extern "system" fn #name<Impl: #trait_name>(this: *mut ::core::ffi::c_void, #(#args),*) #ret {
+                        let this = (this as *mut *mut ::core::ffi::c_void) as *const ::windows_core::ScopedHeap;
+                        let this = (*this).this as *const Impl;
+                        #call
+                    }
+                }
+            }
+        }
+    }
+
     /// Generates various conversions such as from and to `IUnknown`
     fn gen_conversions(&self) -> proc_macro2::TokenStream {
         let name = &self.name;
@@ -394,6 +501,129 @@ impl Interface {
         }
     }
 
+    /// Generates the vtable for tear-offs, whose methods find the object through the tear-off
+    fn gen_tear_off(&self) -> proc_macro2::TokenStream {
+        let Some(parent_vtable) = self.parent_vtable() else {
+            return quote! {};
+        };
+
+        let vtable_name = quote::format_ident!("{}_Vtbl", self.name);
+        let trait_name = quote::format_ident!("{}_Impl", self.name);
+        let functions = self
+            .methods
+            .iter()
+            .map(|m| self.gen_thunk(m, Thunk::TearOff));
+
+        let entries = self.methods.iter().map(|m| {
+            let name = &m.name;
+            quote!(#name: #name::<Identity>)
+        });
+
+        // The parent is a constraint, rather than assumed, so that this only has to be provided by
+        // the parent when a tear-off of this interface is used.
+        quote! {
+            impl<Identity: ::windows_core::IUnknownImpl + #trait_name> ::windows_core::imp::TearOffVtable<Identity> for #vtable_name
+            where
+                #parent_vtable: ::windows_core::imp::TearOffVtable<Identity>
+            {
+                const VTABLE: Self = {
+                    #(#functions)*
+                    Self {
+                        base__: <#parent_vtable as ::windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+                        #(#entries),*
+                    }
+                };
+            }
+        }
+    }
+
+    /// Generates the dispatch table and `IDispatch::Invoke` support for a dual interface
+    fn gen_dispatch(&self) -> proc_macro2::TokenStream {
+        if !self.parent_is_idispatch() {
+            return quote! {};
+        }
+
+        let vis = &self.visibility;
+        let name = &self.name;
+        let parent = self.parent_type();
+        let trait_name = quote::format_ident!("{}_Impl", name);
+        let methods: Vec<_> = self
+            .methods
+            .iter()
+            .filter(|m| m.is_dispatchable())
+            .collect();
+        let names = methods.iter().map(|m| m.name.to_string());
+
+        let arms = methods.iter().enumerate().map(|(index, m)| {
+            let id = proc_macro2::Literal::i32_unsuffixed(index as i32 + 1);
+            let method = &m.name;
+            let count = proc_macro2::Literal::usize_unsuffixed(m.args.len());
+
+            // The arguments are in reverse order, as they are in `DISPPARAMS`.
+            let args = m.args.iter().enumerate().map(|(index, a)| {
+                let ty = &a.ty;
+                let index = proc_macro2::Literal::usize_unsuffixed(m.args.len() - 1 - index);
+                quote! { <#ty as ::windows_core::DispatchValue<Variant>>::from_variant(&args[#index])? }
+            });
+
+            let call = quote! { <Identity as #trait_name>::#method(this, #(#args),*) };
+
+            let result = if m.retval.is_some() {
+                quote! { ::core::result::Result::Ok(::windows_core::DispatchValue::into_variant(#call?)) }
+            } else if m.is_result() {
+                quote! {
+                    #call?;
+                    ::core::result::Result::Ok(::core::default::Default::default())
+                }
+            } else if is_hresult(&m.ret) {
+                quote! {
+                    #call.ok()?;
+                    ::core::result::Result::Ok(::core::default::Default::default())
+                }
+            } else if matches!(m.ret, syn::ReturnType::Type(..)) {
+                quote! { ::core::result::Result::Ok(::windows_core::DispatchValue::into_variant(#call)) }
+            } else {
+                quote! {
+                    #call;
+                    ::core::result::Result::Ok(::core::default::Default::default())
+                }
+            };
+
+            quote! {
+                #id => {
+                    if args.len() != #count {
+                        return ::core::result::Result::Err(::windows_core::Error::from_hresult(::windows_core::imp::DISP_E_BADPARAMCOUNT));
+                    }
+
+                    unsafe { #result }
+                }
+            }
+        });
+
+        quote! {
+            impl ::windows_core::DualInterface for #name {
+                type Variant = <#parent as ::windows_core::DispatchInterface>::Variant;
+                const NAMES: &'static [&'static str] = &[#(#names),*];
+            }
+            impl #name {
+                /// Calls the member with the given dispatch identifier, for `IDispatch::Invoke`.
+                #[doc(hidden)]
+                #vis unsafe fn dispatch_invoke<Identity: #trait_name>(
+                    this: &Identity,
+                    id: i32,
+                    args: &[<Self as ::windows_core::DualInterface>::Variant],
+                ) -> ::windows_core::Result<<Self as ::windows_core::DualInterface>::Variant> {
+                    type Variant = <#name as ::windows_core::DualInterface>::Variant;
+
+                    match id {
+                        #(#arms)*
+                        _ => ::core::result::Result::Err(::windows_core::Error::from_hresult(::windows_core::imp::DISP_E_MEMBERNOTFOUND)),
+                    }
+                }
+            }
+        }
+    }
+
     fn parent_type(&self) -> proc_macro2::TokenStream {
         if let Some(parent) = &self.parent {
             quote!(#parent)
@@ -419,6 +649,15 @@ impl Interface {
         }
     }
 
+    /// Whether the interface is a dual interface, which derives directly from `IDispatch`
+    fn parent_is_idispatch(&self) -> bool {
+        if let Some(ident) = self.parent_path().last() {
+            ident == "IDispatch"
+        } else {
+            false
+        }
+    }
+
     fn parent_path(&self) -> Vec<syn::Ident> {
         if let Some(parent) = &self.parent {
             parent
@@ -453,16 +692,43 @@ impl syn::parse::Parse for Interface {
             if path.is_ident("doc") {
                 docs.push(attr);
             } else {
-                return Err(syn::Error::new(path.span(), "Unrecognized attribute "));
+                return Err(syn::Error::new(
+                    path.span(),
+                    "unexpected attribute; only doc comments are supported on an interface",
+                ));
             }
         }
 
         let visibility = input.parse::<syn::Visibility>()?;
+
+        if input.peek(syn::Token![trait]) {
+            return Err(input.error("the interface must be declared as an `unsafe trait`"));
+        }
+
+        if !input.peek(syn::Token![unsafe]) || !input.peek2(syn::Token![trait]) {
+            return Err(input.error("`#[interface]` can only be applied to an `unsafe trait`"));
+        }
+
         _ = input.parse::<syn::Token![unsafe]>()?;
         _ = input.parse::<syn::Token![trait]>()?;
         let name = input.parse::<syn::Ident>()?;
-        _ = input.parse::<syn::Token![:]>();
-        let parent = input.parse::<syn::Path>().ok();
+
+        if input.peek(syn::Token![<]) {
+            return Err(input.error("an interface cannot have generic parameters"));
+        }
+
+        let parent = if input.parse::<Option<syn::Token![:]>>()?.is_some() {
+            let parent = input.parse::<syn::Path>()?;
+
+            if input.peek(syn::Token![+]) {
+                return Err(input.error("an interface can only derive from one other interface"));
+            }
+
+            Some(parent)
+        } else {
+            None
+        };
+
         let content;
         syn::braced!(content in input);
         let mut methods = Vec::new();
@@ -538,6 +804,24 @@ impl Guid {
                 ensure_length(delimited.next(), 4, 12, value.span())?,
             ];
 
+            if delimited.next().is_some() {
+                bail!(
+                    value,
+                    "The IID must have exactly five parts separated by `-`"
+                );
+            }
+
+            if let Some(c) = guid_value
+                .chars()
+                .find(|c| *c != '-' && !c.is_ascii_hexdigit())
+            {
+                bail!(
+                    value,
+                    "The IID contains `{}` which is not a hexadecimal digit",
+                    c
+                );
+            }
+
             let data1 = hex_lit(&chunks[0]);
             let data2 = hex_lit(&chunks[1]);
             let data3 = hex_lit(&chunks[2]);
@@ -577,9 +861,23 @@ impl Guid {
 
 impl syn::parse::Parse for Guid {
     fn parse(cursor: syn::parse::ParseStream) -> syn::Result<Self> {
-        let string: Option<syn::LitStr> = cursor.parse().ok();
+        if cursor.is_empty() {
+            return Ok(Self(None));
+        }
+
+        if !cursor.peek(syn::LitStr) {
+            return Err(cursor.error(
+                "expected the interface IID as a string literal such as \"094d70d6-5202-44b8-abb8-43860da5aca2\"",
+            ));
+        }
+
+        let string = cursor.parse::<syn::LitStr>()?;
 
-        Ok(Self(string))
+        if !cursor.is_empty() {
+            return Err(cursor.error("unexpected tokens after the interface IID"));
+        }
+
+        Ok(Self(Some(string)))
     }
 }
 
@@ -597,27 +895,57 @@ struct InterfaceMethod {
     pub visibility: syn::Visibility,
     pub args: Vec<InterfaceMethodArg>,
     pub ret: syn::ReturnType,
+    pub retval: Option<Retval>,
     pub docs: Vec<syn::Attribute>,
 }
 
+/// How a vtable function finds the implementation from the COM "this" pointer.
+#[derive(Clone, Copy)]
+enum Thunk {
+    /// The interface is at a fixed offset within the `#[implement]` object.
+    Offset,
+    /// The interface is a tear-off that refers to the `#[implement]` object.
+    TearOff,
+    /// The interface is a `ScopedInterface`.
+    Scoped,
+}
+
+/// A value that callers receive as `Result<T>` rather than through an out parameter
+enum Retval {
+    /// The method is declared as returning `Result<T>` so the ABI has an additional trailing out parameter
+    Result(syn::Type),
+    /// The final parameter is declared as `#[retval] value: *mut T`
+    Param(syn::Type),
+}
+
+impl Retval {
+    fn ty(&self) -> &syn::Type {
+        match self {
+            Self::Result(ty) | Self::Param(ty) => ty,
+        }
+    }
+}
+
 impl InterfaceMethod {
+    /// Whether the method is declared as returning `Result<()>`
     fn is_result(&self) -> bool {
-        if let syn::ReturnType::Type(_, ty) = &self.ret {
-            if let syn::Type::Path(path) = &**ty {
-                if let Some(segment) = path.path.segments.last() {
-                    let ident = segment.ident.to_string();
-                    if ident == "Result" {
-                        if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
-                            if args.args.len() == 1 {
-                                return true;
-                            }
-                        }
-                    }
-                }
-            }
-        }
+        matches!(result_type(&self.ret), Some(syn::Type::Tuple(tuple)) if tuple.elems.is_empty())
+    }
+
+    /// Whether the method can be called through `IDispatch::Invoke`, which excludes methods with
+    /// pointer, reference or `#[retval]` parameters and methods returning pointers
+    fn is_dispatchable(&self) -> bool {
+        let is_pointer = |ty: &syn::Type| matches!(ty, syn::Type::Ptr(_) | syn::Type::Reference(_));
 
-        false
+        !matches!(self.retval, Some(Retval::Param(_)))
+            && self
+                .args
+                .iter()
+                .all(|a| !is_pointer(&a.ty) && a.borrow_type().is_none())
+            && match &self.ret {
+                syn::ReturnType::Default => true,
+                syn::ReturnType::Type(_, ty) => !is_pointer(ty),
+            }
     }
 
     /// Generates arguments (of the form `$pat: $type`)
@@ -632,8 +960,16 @@ impl InterfaceMethod {
             .collect::<Vec<_>>()
     }
 
+    /// The arguments that callers provide, which excludes a `#[retval]` parameter
+    fn consume_args(&self) -> &[InterfaceMethodArg] {
+        match self.retval {
+            Some(Retval::Param(_)) => &self.args[..self.args.len() - 1],
+            _ => &self.args,
+        }
+    }
+
     fn gen_consume_generics(&self) -> Vec<proc_macro2::TokenStream> {
-        self.args
+        self.consume_args()
             .iter()
             .enumerate()
             .filter_map(|(generic_index, a)| {
@@ -652,7 +988,7 @@ impl InterfaceMethod {
     }
 
     fn gen_consume_params(&self) -> Vec<proc_macro2::TokenStream> {
-        self.args
+        self.consume_args()
             .iter()
             .enumerate()
             .map(|(generic_index, a)| {
@@ -670,7 +1006,7 @@ impl InterfaceMethod {
     }
 
     fn gen_consume_args(&self) -> Vec<proc_macro2::TokenStream> {
-        self.args
+        self.consume_args()
             .iter()
             .map(|a| {
                 let pat = &a.pat;
@@ -705,21 +1041,69 @@ impl syn::parse::Parse for InterfaceMethod {
             sig.receiver(),
             "the method to have &self as its first argument"
         );
+        if let Some(receiver) = sig.receiver() {
+            if receiver.reference.is_none() || receiver.mutability.is_some() {
+                bail!(receiver, "interface methods must take `&self`");
+            }
+        }
         unexpected_token!(sig.variadic, "variadic args");
-        let args = sig
+        let inputs: Vec<_> = sig
             .inputs
             .into_iter()
             .filter_map(|a| match a {
                 syn::FnArg::Receiver(_) => None,
                 syn::FnArg::Typed(p) => Some(p),
             })
-            .map(|p| {
-                Ok(InterfaceMethodArg {
-                    ty: p.ty,
-                    pat: p.pat,
-                })
-            })
-            .collect::<Result<Vec<InterfaceMethodArg>, syn::Error>>()?;
+            .collect();
+
+        let mut retval = None;
+        let mut args = Vec::new();
+
+        for (index, p) in inputs.iter().enumerate() {
+            for attr in &p.attrs {
+                if !attr.path().is_ident("retval") {
+                    bail!(attr, "unexpected attribute");
+                }
+
+                if index + 1 != inputs.len() {
+                    bail!(attr, "only the last parameter may be `#[retval]`");
+                }
+
+                let syn::Type::Ptr(ptr) = &*p.ty else {
+                    bail!(p.ty, "a `#[retval]` parameter must have a `*mut T` type");
+                };
+
+                if ptr.mutability.is_none() {
+                    bail!(p.ty, "a `#[retval]` parameter must have a `*mut T` type");
+                }
+
+                if !is_hresult(&sig.output) {
+                    let message = "a method with a `#[retval]` parameter must return `HRESULT`";
+
+                    match &sig.output {
+                        syn::ReturnType::Type(_, ty) => {
+                            bail!(ty, "{}", message);
+                        }
+                        syn::ReturnType::Default => {
+                            bail!(sig.ident, "{}", message);
+                        }
+                    }
+                }
+
+                retval = Some(Retval::Param((*ptr.elem).clone()));
+            }
+
+            args.push(InterfaceMethodArg {
+                ty: p.ty.clone(),
+                pat: p.pat.clone(),
+            });
+        }
+
+        if let Some(ty) = result_type(&sig.output) {
+            if !matches!(ty, syn::Type::Tuple(tuple) if tuple.elems.is_empty()) {
+                retval = Some(Retval::Result(ty.clone()));
+            }
+        }
 
         let ret = sig.output;
         Ok(Self {
@@ -727,11 +1111,47 @@ impl syn::parse::Parse for InterfaceMethod {
             visibility,
             args,
             ret,
+            retval,
             docs,
         })
     }
 }
 
+/// Gets the `T` from a `Result<T>` return type
+fn result_type(ret: &syn::ReturnType) -> Option<&syn::Type> {
+    if let syn::ReturnType::Type(_, ty) = ret {
+        if let syn::Type::Path(path) = &**ty {
+            if let Some(segment) = path.path.segments.last() {
+                if segment.ident == "Result" {
+                    if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                        if args.args.len() == 1 {
+                            if let Some(syn::GenericArgument::Type(ty)) = args.args.first() {
+                                return Some(ty);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    None
+}
+
+fn is_hresult(ret: &syn::ReturnType) -> bool {
+    if let syn::ReturnType::Type(_, ty) = ret {
+        if let syn::Type::Path(path) = &**ty {
+            return path
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "HRESULT");
+        }
+    }
+
+    false
+}
+
 /// An argument to an interface method
 struct InterfaceMethodArg {
     /// The type of the argument
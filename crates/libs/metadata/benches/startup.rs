@@ -0,0 +1,105 @@
+//! Measures the time taken to load the default metadata and find a single function, as `windows-bindgen` does for
+//! a single-function `--filter`.
+//!
+//! Run with `cargo bench -p windows-metadata --bench startup [-- <Namespace.Function> <.winmd files>...]`.
+
+use std::time::{Duration, Instant};
+use windows_metadata::*;
+
+const ITERATIONS: u32 = 20;
+
+fn main() {
+    let mut args = std::env::args()
+        .skip(1)
+        .filter(|arg| !arg.starts_with("--"));
+
+    let filter = args
+        .next()
+        .unwrap_or_else(|| "Windows.Wdk.Foundation.NtClose".to_string());
+
+    let mut paths: Vec<String> = args.collect();
+
+    if paths.is_empty() {
+        let default = concat!(env!("CARGO_MANIFEST_DIR"), "/../bindgen/default");
+
+        for entry in std::fs::read_dir(default).expect("failed to read default metadata directory")
+        {
+            let path = entry.unwrap().path();
+
+            if path
+                .extension()
+                .is_some_and(|extension| extension == "winmd")
+            {
+                paths.push(path.to_string_lossy().into_owned());
+            }
+        }
+    }
+
+    let (namespace, name) = filter
+        .rsplit_once('.')
+        .expect("filter must be a fully qualified function name");
+
+    let bytes: Vec<Vec<u8>> = paths
+        .iter()
+        .map(|path| std::fs::read(path).expect("failed to read .winmd file"))
+        .collect();
+
+    // Leaked once up front to stand in for bytes embedded with `include_bytes!`.
+    let embedded: Vec<&'static [u8]> = bytes
+        .iter()
+        .map(|bytes| &*Vec::leak(bytes.clone()))
+        .collect();
+
+    // Copies every file and then touches every namespace and the nested types so that everything is indexed up
+    // front, as the reader did before files could borrow their bytes and namespaces were indexed on demand.
+    let eager = measure(|| {
+        let files = bytes
+            .iter()
+            .map(|bytes| reader::File::new(bytes.clone()).unwrap())
+            .collect();
+
+        let index = reader::TypeIndex::new(files);
+        _ = index.iter().count();
+
+        if let Some(def) = index.types().next() {
+            _ = index.nested(def).count();
+        }
+
+        find(&index, namespace, name)
+    });
+
+    // Borrows every file, groups every type by namespace, and only indexes the names of the types within the
+    // namespace containing the function.
+    let lazy = measure(|| {
+        let files = embedded
+            .iter()
+            .map(|bytes| reader::File::from_static(bytes).unwrap())
+            .collect();
+
+        let index = reader::TypeIndex::new(files);
+        find(&index, namespace, name)
+    });
+
+    println!("files: {}", paths.join(", "));
+    println!("filter: {filter}");
+    println!("eager: {eager:?}");
+    println!("lazy: {lazy:?}");
+    println!("speedup: {:.1}x", eager.as_secs_f64() / lazy.as_secs_f64());
+}
+
+fn find(index: &reader::TypeIndex, namespace: &str, name: &str) -> bool {
+    index
+        .get(namespace, "Apis")
+        .any(|apis| apis.methods().any(|method| method.name() == name))
+}
+
+fn measure<F: FnMut() -> bool>(mut f: F) -> Duration {
+    assert!(f(), "function not found");
+    let time = Instant::now();
+
+    for _ in 0..ITERATIONS {
+        std::hint::black_box(f());
+    }
+
+    time.elapsed() / ITERATIONS
+}
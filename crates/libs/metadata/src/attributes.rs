@@ -59,7 +59,9 @@ impl MethodAttributes {
     pub const Abstract: Self = Self(0x400);
     pub const HideBySig: Self = Self(0x80);
     pub const NewSlot: Self = Self(0x100);
+    pub const Private: Self = Self(0x1);
     pub const Public: Self = Self(0x6);
+    pub const RTSpecialName: Self = Self(0x1000);
     pub const SpecialName: Self = Self(0x800);
     pub const Virtual: Self = Self(0x40);
 }
@@ -67,6 +69,7 @@ impl MethodAttributes {
 flags!(MethodImplAttributes, u16);
 impl MethodImplAttributes {
     pub const PreserveSig: Self = Self(0x80);
+    pub const Runtime: Self = Self(0x3);
 }
 
 // These are not really ECMA-335 attributes but instead the flags found in the method signature.
@@ -93,6 +96,7 @@ impl PInvokeAttributes {
 flags!(TypeAttributes, u32);
 impl TypeAttributes {
     pub const Public: Self = Self(0x1);
+    pub const NestedPublic: Self = Self(0x2);
     pub const ExplicitLayout: Self = Self(0x10);
     pub const Abstract: Self = Self(0x80);
     pub const Sealed: Self = Self(0x100);
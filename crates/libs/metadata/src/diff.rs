@@ -0,0 +1,727 @@
+use super::*;
+
+/// Indicates whether a [`Change`] breaks existing binaries or only adds to the API surface.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "json",
+    derive(serde::Serialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum ChangeKind {
+    Breaking,
+    Additive,
+}
+
+impl std::fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Breaking => write!(f, "breaking"),
+            Self::Additive => write!(f, "additive"),
+        }
+    }
+}
+
+/// A single difference between two sets of metadata.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
+pub struct Change {
+    pub kind: ChangeKind,
+    pub namespace: String,
+    pub name: String,
+    #[cfg_attr(feature = "json", serde(skip_serializing_if = "Option::is_none"))]
+    pub member: Option<String>,
+    pub reason: String,
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: {}.{}", self.kind, self.namespace, self.name)?;
+
+        if let Some(member) = &self.member {
+            write!(f, "::{member}")?;
+        }
+
+        write!(f, ": {}", self.reason)
+    }
+}
+
+/// The set of changes between two sets of metadata, as returned by [`diff`].
+///
+/// The `Display` implementation produces one line per change, ordered with breaking changes first.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Diff {
+    pub changes: Vec<Change>,
+}
+
+impl Diff {
+    /// Returns `true` if any of the changes break compatibility with the old metadata.
+    pub fn is_breaking(&self) -> bool {
+        self.changes
+            .iter()
+            .any(|change| change.kind == ChangeKind::Breaking)
+    }
+
+    /// Returns `true` if there are no changes at all.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn breaking(&self) -> impl Iterator<Item = &Change> + '_ {
+        self.changes
+            .iter()
+            .filter(|change| change.kind == ChangeKind::Breaking)
+    }
+
+    pub fn additive(&self) -> impl Iterator<Item = &Change> + '_ {
+        self.changes
+            .iter()
+            .filter(|change| change.kind == ChangeKind::Additive)
+    }
+
+    /// Serializes the changes as a JSON object with `breaking` and `changes` fields.
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&serde_json::json!({
+            "breaking": self.is_breaking(),
+            "changes": self.changes,
+        }))
+        .expect("changes only contain strings and enums, which always serialize")
+    }
+
+    fn push(&mut self, kind: ChangeKind, scope: &Scope, member: Option<&str>, reason: String) {
+        self.changes.push(Change {
+            kind,
+            namespace: scope.namespace.to_string(),
+            name: scope.name.clone(),
+            member: member.map(|member| member.to_string()),
+            reason,
+        });
+    }
+
+    fn breaking_change(&mut self, scope: &Scope, member: Option<&str>, reason: String) {
+        self.push(ChangeKind::Breaking, scope, member, reason);
+    }
+
+    fn additive_change(&mut self, scope: &Scope, member: Option<&str>, reason: String) {
+        self.push(ChangeKind::Additive, scope, member, reason);
+    }
+}
+
+impl std::fmt::Display for Diff {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+
+        Ok(())
+    }
+}
+
+struct Scope<'a> {
+    namespace: &'a str,
+    name: String,
+}
+
+/// Compares two sets of metadata and classifies every difference as breaking or additive.
+///
+/// Types are matched by namespace and name. Interfaces and delegates are compared by vtable slot so that
+/// reordering, removing, or changing the signature of a method is reported as breaking, while methods
+/// appended to the end of a non-exclusive interface are additive. Structs are compared field by field since
+/// any change affects their layout, and enums are compared by the value of each constant.
+pub fn diff(old: &reader::TypeIndex, new: &reader::TypeIndex) -> Diff {
+    let old_types = collect(old);
+    let new_types = collect(new);
+    let mut diff = Diff::default();
+
+    for ((namespace, name), old_defs) in &old_types {
+        let scope = Scope {
+            namespace,
+            name: name.to_string(),
+        };
+
+        let Some(new_defs) = new_types.get(&(namespace, name)) else {
+            diff.breaking_change(&scope, None, "type removed".to_string());
+            continue;
+        };
+
+        if old_defs.len() != new_defs.len() {
+            diff.breaking_change(
+                &scope,
+                None,
+                format!(
+                    "number of definitions changed from {} to {}",
+                    old_defs.len(),
+                    new_defs.len()
+                ),
+            );
+        }
+
+        for (old_def, new_def) in old_defs.iter().zip(new_defs) {
+            diff_type(&mut diff, &scope, *old_def, *new_def);
+        }
+    }
+
+    for (namespace, name) in new_types.keys() {
+        if !old_types.contains_key(&(namespace, name)) {
+            let scope = Scope {
+                namespace,
+                name: name.to_string(),
+            };
+
+            diff.additive_change(&scope, None, "type added".to_string());
+        }
+    }
+
+    diff.changes.sort();
+    diff
+}
+
+fn collect(index: &reader::TypeIndex) -> BTreeMap<(&str, &str), Vec<reader::TypeDef<'_>>> {
+    let mut types: BTreeMap<(&str, &str), Vec<reader::TypeDef>> = BTreeMap::new();
+
+    for (namespace, name, def) in index.iter() {
+        types.entry((namespace, name)).or_default().push(def);
+    }
+
+    for defs in types.values_mut() {
+        defs.sort();
+    }
+
+    types
+}
+
+fn diff_type(diff: &mut Diff, scope: &Scope, old: reader::TypeDef, new: reader::TypeDef) {
+    let category = old.category();
+
+    if category != new.category() {
+        diff.breaking_change(
+            scope,
+            None,
+            format!(
+                "changed from {} to {}",
+                category_name(category),
+                category_name(new.category())
+            ),
+        );
+        return;
+    }
+
+    let old_generics = generics(old);
+    let new_generics = generics(new);
+
+    if old_generics.len() != new_generics.len() {
+        diff.breaking_change(
+            scope,
+            None,
+            format!(
+                "generic parameter count changed from {} to {}",
+                old_generics.len(),
+                new_generics.len()
+            ),
+        );
+        return;
+    }
+
+    match category {
+        reader::TypeCategory::Interface => {
+            diff_guid(diff, scope, old, new);
+            diff_required_interfaces(diff, scope, old, new, &old_generics);
+            let exclusive = old.has_attribute("ExclusiveToAttribute");
+            diff_vtable(diff, scope, old, new, &old_generics, exclusive);
+        }
+        reader::TypeCategory::Delegate => {
+            diff_guid(diff, scope, old, new);
+            diff_vtable(diff, scope, old, new, &old_generics, false);
+        }
+        reader::TypeCategory::Struct => diff_struct(diff, scope, old, new),
+        reader::TypeCategory::Enum => diff_enum(diff, scope, old, new),
+        reader::TypeCategory::Class | reader::TypeCategory::Attribute => {
+            diff_class(diff, scope, old, new, &old_generics)
+        }
+    }
+}
+
+fn diff_guid(diff: &mut Diff, scope: &Scope, old: reader::TypeDef, new: reader::TypeDef) {
+    let old_guid = old.guid_attribute();
+    let new_guid = new.guid_attribute();
+
+    if old_guid != new_guid {
+        diff.breaking_change(
+            scope,
+            None,
+            format!(
+                "GUID changed from {} to {}",
+                guid_name(old_guid),
+                guid_name(new_guid)
+            ),
+        );
+    }
+}
+
+fn diff_required_interfaces(
+    diff: &mut Diff,
+    scope: &Scope,
+    old: reader::TypeDef,
+    new: reader::TypeDef,
+    generics: &[Type],
+) {
+    let old_required = interfaces(old, generics);
+    let new_required = interfaces(new, generics);
+
+    for name in old_required.keys() {
+        if !new_required.contains_key(name) {
+            diff.breaking_change(scope, None, format!("no longer requires `{name}`"));
+        }
+    }
+
+    for name in new_required.keys() {
+        if !old_required.contains_key(name) {
+            diff.breaking_change(scope, None, format!("now requires `{name}`"));
+        }
+    }
+}
+
+fn diff_vtable(
+    diff: &mut Diff,
+    scope: &Scope,
+    old: reader::TypeDef,
+    new: reader::TypeDef,
+    generics: &[Type],
+    exclusive: bool,
+) {
+    let old_methods: Vec<_> = old.methods().collect();
+    let new_methods: Vec<_> = new.methods().collect();
+
+    // The slots that methods have moved to, which are reported as moves rather than additions.
+    let mut moved = BTreeSet::new();
+
+    for (slot, old_method) in old_methods.iter().enumerate() {
+        let name = old_method.name();
+        let old_signature = signature(*old_method, generics);
+
+        match new_methods.get(slot) {
+            Some(new_method) if new_method.name() == name => {
+                let new_signature = signature(*new_method, generics);
+
+                if old_signature != new_signature {
+                    diff.breaking_change(
+                        scope,
+                        Some(name),
+                        format!("signature changed from `{old_signature}` to `{new_signature}`"),
+                    );
+                }
+            }
+            _ => {
+                // Overloads share a name so a method is only found elsewhere if its signature also matches.
+                if let Some(new_slot) = new_methods.iter().position(|new_method| {
+                    new_method.name() == name && signature(*new_method, generics) == old_signature
+                }) {
+                    moved.insert(new_slot);
+
+                    diff.breaking_change(
+                        scope,
+                        Some(name),
+                        format!("vtable slot moved from {slot} to {new_slot}"),
+                    );
+                } else {
+                    diff.breaking_change(scope, Some(name), "method removed".to_string());
+                }
+            }
+        }
+    }
+
+    // Methods may only be appended to a non-exclusive interface. An exclusive interface is versioned along with its
+    // class, which adds members by implementing a new exclusive interface instead.
+    for (slot, new_method) in new_methods.iter().enumerate().skip(old_methods.len()) {
+        if moved.contains(&slot) {
+            continue;
+        }
+
+        if exclusive {
+            diff.breaking_change(
+                scope,
+                Some(new_method.name()),
+                format!("method added at vtable slot {slot} of an exclusive interface"),
+            );
+        } else {
+            diff.additive_change(
+                scope,
+                Some(new_method.name()),
+                format!("method added at vtable slot {slot}"),
+            );
+        }
+    }
+}
+
+fn diff_struct(diff: &mut Diff, scope: &Scope, old: reader::TypeDef, new: reader::TypeDef) {
+    let old_union = old.flags().contains(TypeAttributes::ExplicitLayout);
+
+    if old_union != new.flags().contains(TypeAttributes::ExplicitLayout) {
+        diff.breaking_change(
+            scope,
+            None,
+            if old_union {
+                "changed from union to struct".to_string()
+            } else {
+                "changed from struct to union".to_string()
+            },
+        );
+    }
+
+    let old_layout = old
+        .class_layout()
+        .map(|layout| (layout.packing_size(), layout.class_size()));
+
+    let new_layout = new
+        .class_layout()
+        .map(|layout| (layout.packing_size(), layout.class_size()));
+
+    if old_layout != new_layout {
+        diff.breaking_change(scope, None, "packing or size changed".to_string());
+    }
+
+    let old_fields: Vec<_> = old.fields().collect();
+    let new_fields: Vec<_> = new.fields().collect();
+
+    for pos in 0..old_fields.len().max(new_fields.len()) {
+        match (old_fields.get(pos), new_fields.get(pos)) {
+            (Some(old_field), Some(new_field)) => {
+                if old_field.name() != new_field.name() {
+                    diff.breaking_change(
+                        scope,
+                        Some(old_field.name()),
+                        format!("field {pos} renamed to `{}`", new_field.name()),
+                    );
+                }
+
+                let old_ty = old_field.ty();
+                let new_ty = new_field.ty();
+
+                if old_ty != new_ty {
+                    diff.breaking_change(
+                        scope,
+                        Some(old_field.name()),
+                        format!("field type changed from `{old_ty}` to `{new_ty}`"),
+                    );
+                }
+            }
+            (Some(old_field), None) => {
+                diff.breaking_change(scope, Some(old_field.name()), "field removed".to_string());
+            }
+            (None, Some(new_field)) => {
+                diff.breaking_change(scope, Some(new_field.name()), "field added".to_string());
+            }
+            (None, None) => unreachable!(),
+        }
+    }
+
+    let new_nested: Vec<_> = new.index().nested(new).collect();
+
+    for old_nested in old.index().nested(old) {
+        let nested_scope = Scope {
+            namespace: scope.namespace,
+            name: format!("{}::{}", scope.name, old_nested.name()),
+        };
+
+        if let Some(new_nested) = new_nested
+            .iter()
+            .find(|new_nested| new_nested.name() == old_nested.name())
+        {
+            diff_struct(diff, &nested_scope, old_nested, *new_nested);
+        } else {
+            diff.breaking_change(&nested_scope, None, "nested type removed".to_string());
+        }
+    }
+}
+
+fn diff_enum(diff: &mut Diff, scope: &Scope, old: reader::TypeDef, new: reader::TypeDef) {
+    let old_underlying = underlying_type(old);
+    let new_underlying = underlying_type(new);
+
+    if old_underlying != new_underlying {
+        diff.breaking_change(
+            scope,
+            None,
+            format!(
+                "underlying type changed from `{}` to `{}`",
+                old_underlying.unwrap_or(Type::Void),
+                new_underlying.unwrap_or(Type::Void)
+            ),
+        );
+    }
+
+    diff_constants(diff, scope, old, new);
+}
+
+fn diff_class(
+    diff: &mut Diff,
+    scope: &Scope,
+    old: reader::TypeDef,
+    new: reader::TypeDef,
+    generics: &[Type],
+) {
+    let old_extends = extends_name(old);
+    let new_extends = extends_name(new);
+
+    if old_extends != new_extends {
+        diff.breaking_change(
+            scope,
+            None,
+            format!("base type changed from `{old_extends}` to `{new_extends}`"),
+        );
+    }
+
+    if !old.flags().contains(TypeAttributes::Sealed) && new.flags().contains(TypeAttributes::Sealed)
+    {
+        diff.breaking_change(scope, None, "class is now sealed".to_string());
+    }
+
+    let old_interfaces = interfaces(old, generics);
+    let new_interfaces = interfaces(new, generics);
+
+    for (name, old_default) in &old_interfaces {
+        match new_interfaces.get(name) {
+            Some(new_default) => {
+                if *old_default && !new_default {
+                    diff.breaking_change(
+                        scope,
+                        None,
+                        format!("`{name}` is no longer the default interface"),
+                    );
+                }
+            }
+            None => {
+                diff.breaking_change(scope, None, format!("no longer implements `{name}`"));
+            }
+        }
+    }
+
+    for (name, new_default) in &new_interfaces {
+        match old_interfaces.get(name) {
+            Some(old_default) => {
+                if !old_default && *new_default {
+                    diff.breaking_change(
+                        scope,
+                        None,
+                        format!("`{name}` is now the default interface"),
+                    );
+                }
+            }
+            None => {
+                diff.additive_change(scope, None, format!("now implements `{name}`"));
+            }
+        }
+    }
+
+    let old_methods = methods(old, generics);
+    let new_methods = methods(new, generics);
+
+    for (name, old_signatures) in &old_methods {
+        let Some(new_signatures) = new_methods.get(name) else {
+            diff.breaking_change(scope, Some(name), "method removed".to_string());
+            continue;
+        };
+
+        for old_signature in old_signatures {
+            if !new_signatures.contains(old_signature) {
+                if old_signatures.len() == 1 && new_signatures.len() == 1 {
+                    diff.breaking_change(
+                        scope,
+                        Some(name),
+                        format!(
+                            "signature changed from `{old_signature}` to `{}`",
+                            new_signatures[0]
+                        ),
+                    );
+                } else {
+                    diff.breaking_change(
+                        scope,
+                        Some(name),
+                        format!("overload `{old_signature}` removed"),
+                    );
+                }
+            }
+        }
+
+        if old_signatures.len() == 1 && new_signatures.len() == 1 {
+            continue;
+        }
+
+        for new_signature in new_signatures {
+            if !old_signatures.contains(new_signature) {
+                diff.additive_change(
+                    scope,
+                    Some(name),
+                    format!("overload `{new_signature}` added"),
+                );
+            }
+        }
+    }
+
+    for name in new_methods.keys() {
+        if !old_methods.contains_key(name) {
+            diff.additive_change(scope, Some(name), "method added".to_string());
+        }
+    }
+
+    diff_constants(diff, scope, old, new);
+}
+
+fn diff_constants(diff: &mut Diff, scope: &Scope, old: reader::TypeDef, new: reader::TypeDef) {
+    let old_constants = constants(old);
+    let new_constants = constants(new);
+
+    for (name, (old_ty, old_value)) in &old_constants {
+        let Some((new_ty, new_value)) = new_constants.get(name) else {
+            diff.breaking_change(scope, Some(name), "constant removed".to_string());
+            continue;
+        };
+
+        if old_ty != new_ty {
+            diff.breaking_change(
+                scope,
+                Some(name),
+                format!("type changed from `{old_ty}` to `{new_ty}`"),
+            );
+        } else if old_value != new_value {
+            diff.breaking_change(
+                scope,
+                Some(name),
+                format!(
+                    "value changed from {} to {}",
+                    value_name(old_value),
+                    value_name(new_value)
+                ),
+            );
+        }
+    }
+
+    for name in new_constants.keys() {
+        if !old_constants.contains_key(name) {
+            diff.additive_change(scope, Some(name), "constant added".to_string());
+        }
+    }
+}
+
+fn generics(def: reader::TypeDef) -> Vec<Type> {
+    def.generic_params()
+        .map(|param| Type::Generic(param.sequence()))
+        .collect()
+}
+
+// Returns the implemented or required interfaces and whether each is marked as the default interface.
+fn interfaces(def: reader::TypeDef, generics: &[Type]) -> BTreeMap<String, bool> {
+    def.interface_impls()
+        .map(|map| {
+            (
+                map.interface(generics).to_string(),
+                map.has_attribute("DefaultAttribute"),
+            )
+        })
+        .collect()
+}
+
+fn methods(def: reader::TypeDef, generics: &[Type]) -> BTreeMap<String, Vec<String>> {
+    let mut methods: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    for method in def.methods() {
+        methods
+            .entry(method.name().to_string())
+            .or_default()
+            .push(signature(method, generics));
+    }
+
+    methods
+}
+
+fn constants(def: reader::TypeDef) -> BTreeMap<String, (Type, Option<Value>)> {
+    def.fields()
+        .filter(|field| field.flags().contains(FieldAttributes::Literal))
+        .map(|field| {
+            (
+                field.name().to_string(),
+                (
+                    field.ty(),
+                    field.constant().map(|constant| constant.value()),
+                ),
+            )
+        })
+        .collect()
+}
+
+fn underlying_type(def: reader::TypeDef) -> Option<Type> {
+    def.fields()
+        .find(|field| !field.flags().contains(FieldAttributes::Literal))
+        .map(|field| field.ty())
+}
+
+fn extends_name(def: reader::TypeDef) -> String {
+    def.extends()
+        .map(|extends| format!("{}.{}", extends.namespace(), extends.name()))
+        .unwrap_or_default()
+}
+
+// Produces a canonical representation of everything about a method that affects its ABI. Parameter
+// names are deliberately excluded since renaming a parameter is not a breaking change.
+fn signature(method: reader::MethodDef, generics: &[Type]) -> String {
+    let signature = method.signature(generics);
+    let params: Vec<_> = method.params().collect();
+    let mut result = String::new();
+
+    let calling_convention = method.calling_convention();
+
+    if !calling_convention.is_empty() {
+        result.push_str(&format!("extern {calling_convention:?} "));
+    }
+
+    result.push_str("fn(");
+
+    for (pos, ty) in signature.types.iter().enumerate() {
+        if pos > 0 {
+            result.push_str(", ");
+        }
+
+        let is_out = params.iter().any(|param| {
+            param.sequence() as usize == pos + 1 && param.flags().contains(ParamAttributes::Out)
+        });
+
+        if is_out {
+            result.push_str("out ");
+        }
+
+        result.push_str(&ty.to_string());
+    }
+
+    if signature.flags.contains(MethodCallAttributes::VARARG) {
+        result.push_str(", ...");
+    }
+
+    result.push(')');
+
+    if signature.return_type != Type::Void {
+        result.push_str(&format!(" -> {}", signature.return_type));
+    }
+
+    result
+}
+
+fn category_name(category: reader::TypeCategory) -> &'static str {
+    match category {
+        reader::TypeCategory::Interface => "interface",
+        reader::TypeCategory::Class => "class",
+        reader::TypeCategory::Enum => "enum",
+        reader::TypeCategory::Struct => "struct",
+        reader::TypeCategory::Delegate => "delegate",
+        reader::TypeCategory::Attribute => "attribute",
+    }
+}
+
+fn guid_name(guid: Option<GUID>) -> String {
+    guid.map_or_else(|| "none".to_string(), |guid| guid.to_string())
+}
+
+fn value_name(value: &Option<Value>) -> String {
+    value
+        .as_ref()
+        .map_or_else(|| "none".to_string(), |value| value.to_string())
+}
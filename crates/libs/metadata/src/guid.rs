@@ -0,0 +1,153 @@
+use super::*;
+
+/// A globally unique identifier as stored by the `GuidAttribute` on interfaces and delegates.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GUID {
+    pub data1: u32,
+    pub data2: u16,
+    pub data3: u16,
+    pub data4: [u8; 8],
+}
+
+impl GUID {
+    /// Creates a `GUID` with the given constant values.
+    pub const fn from_values(data1: u32, data2: u16, data3: u16, data4: [u8; 8]) -> Self {
+        Self {
+            data1,
+            data2,
+            data3,
+            data4,
+        }
+    }
+
+    /// Creates a `GUID` from a `u128` value.
+    pub const fn from_u128(uuid: u128) -> Self {
+        Self {
+            data1: (uuid >> 96) as u32,
+            data2: ((uuid >> 80) & 0xffff) as u16,
+            data3: ((uuid >> 64) & 0xffff) as u16,
+            data4: (uuid as u64).to_be_bytes(),
+        }
+    }
+
+    /// Converts a `GUID` to a `u128` value.
+    pub const fn to_u128(&self) -> u128 {
+        ((self.data1 as u128) << 96)
+            + ((self.data2 as u128) << 80)
+            + ((self.data3 as u128) << 64)
+            + u64::from_be_bytes(self.data4) as u128
+    }
+
+    /// Creates a `GUID` for a parameterized WinRT type from its type signature.
+    ///
+    /// This is the same name-based (version 5) `GUID` that the WinRT type system derives for `pinterface` signatures.
+    pub fn from_signature(signature: &str) -> Self {
+        let mut data = vec![
+            0x11, 0xf4, 0x7a, 0xd5, 0x7b, 0x73, 0x42, 0xc0, 0xab, 0xae, 0x87, 0x8b, 0x1e, 0x16,
+            0xad, 0xee,
+        ];
+
+        data.extend_from_slice(signature.as_bytes());
+        let bytes = sha1::sha1(&data);
+
+        let first = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        let second = u16::from_be_bytes([bytes[4], bytes[5]]);
+        let third = (u16::from_be_bytes([bytes[6], bytes[7]]) & 0x0fff) | (5 << 12);
+        let fourth = (bytes[8] & 0x3f) | 0x80;
+
+        Self::from_values(
+            first,
+            second,
+            third,
+            [
+                fourth, bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15],
+            ],
+        )
+    }
+
+    /// Formats the `GUID` as it appears within a WinRT type signature, which is lowercase and enclosed in braces.
+    pub fn to_signature(&self) -> String {
+        format!(
+            "{{{:08x}-{:04x}-{:04x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}}}",
+            self.data1,
+            self.data2,
+            self.data3,
+            self.data4[0],
+            self.data4[1],
+            self.data4[2],
+            self.data4[3],
+            self.data4[4],
+            self.data4[5],
+            self.data4[6],
+            self.data4[7]
+        )
+    }
+
+    /// Creates a `GUID` from the positional arguments of a `GuidAttribute`.
+    pub fn from_args(args: &[(String, Value)]) -> Option<Self> {
+        let [(_, Value::U32(data1)), (_, Value::U16(data2)), (_, Value::U16(data3)), rest @ ..] =
+            args
+        else {
+            return None;
+        };
+
+        let mut data4 = [0; 8];
+
+        if rest.len() != data4.len() {
+            return None;
+        }
+
+        for (byte, (_, value)) in data4.iter_mut().zip(rest) {
+            let Value::U8(value) = value else {
+                return None;
+            };
+
+            *byte = *value;
+        }
+
+        Some(Self::from_values(*data1, *data2, *data3, data4))
+    }
+
+    /// Returns the positional arguments needed to write a `GuidAttribute`.
+    pub fn to_args(&self) -> Vec<(String, Value)> {
+        let mut args = vec![
+            (String::new(), Value::U32(self.data1)),
+            (String::new(), Value::U16(self.data2)),
+            (String::new(), Value::U16(self.data3)),
+        ];
+
+        args.extend(
+            self.data4
+                .iter()
+                .map(|value| (String::new(), Value::U8(*value))),
+        );
+
+        args
+    }
+}
+
+impl std::fmt::Display for GUID {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{:08X?}-{:04X?}-{:04X?}-{:02X?}{:02X?}-{:02X?}{:02X?}{:02X?}{:02X?}{:02X?}{:02X?}",
+            self.data1,
+            self.data2,
+            self.data3,
+            self.data4[0],
+            self.data4[1],
+            self.data4[2],
+            self.data4[3],
+            self.data4[4],
+            self.data4[5],
+            self.data4[6],
+            self.data4[7]
+        )
+    }
+}
+
+impl std::fmt::Debug for GUID {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
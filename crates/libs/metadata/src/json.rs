@@ -0,0 +1,351 @@
+use super::*;
+use reader::HasAttributes;
+use serde::Serialize;
+
+/// The version of the schema produced by [`export_json`]. This is incremented whenever an existing field is
+/// removed or changes meaning. New fields may be added without changing the version.
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+/// Exports the types in the index as JSON, including only the namespaces matching the filter.
+///
+/// A filter entry matches a namespace with the same name as well as any namespace nested within it, so
+/// `Windows.Foundation` matches both `Windows.Foundation` and `Windows.Foundation.Collections`. An empty filter
+/// matches every namespace.
+///
+/// The document has the following shape, with namespaces and types ordered by name and all other lists in
+/// metadata order:
+///
+/// ```text
+/// {
+///   "version": 1,
+///   "namespaces": [{ "name": string, "types": [type] }]
+/// }
+///
+/// type: {
+///   "name": string,                 // including any generic arity suffix, such as "IVector`1"
+///   "kind": "interface" | "class" | "enum" | "struct" | "delegate" | "attribute",
+///   "flags": number,                // raw ECMA-335 TypeAttributes
+///   "extends"?: string,
+///   "guid"?: string,                // from the GuidAttribute, formatted as "XXXXXXXX-XXXX-XXXX-XXXX-XXXXXXXXXXXX"
+///   "layout"?: { "packing": number, "size": number },
+///   "generics": [string],
+///   "interfaces": [{ "type": string, "attributes": [attribute] }],
+///   "fields": [{ "name": string, "type": string, "flags": number, "constant"?: value, "attributes": [attribute] }],
+///   "methods": [method],
+///   "nested": [type],
+///   "attributes": [attribute]
+/// }
+///
+/// method: {
+///   "name": string,
+///   "flags": number,                // raw ECMA-335 MethodAttributes
+///   "impl_flags": number,           // raw ECMA-335 MethodImplAttributes
+///   "return": param,
+///   "params": [param],
+///   "import"?: { "library": string, "name": string, "flags": number },
+///   "attributes": [attribute]
+/// }
+///
+/// param: { "name"?: string, "type": string, "flags": number, "attributes": [attribute] }
+///
+/// attribute: { "type": string, "args": [{ "name"?: string } + value] }
+///
+/// value: { "type": string, "value": boolean | number | string | null }
+/// ```
+///
+/// Floating-point values that are not finite have a `null` value.
+///
+/// Type strings use the same notation as the `Display` implementation of [`Type`], with generic parameters
+/// referred to by name.
+pub fn export_json(index: &reader::TypeIndex, filter: &[&str]) -> String {
+    let mut namespaces: BTreeMap<&str, Vec<reader::TypeDef>> = BTreeMap::new();
+
+    for (namespace, _, def) in index.iter() {
+        if includes(filter, namespace) {
+            namespaces.entry(namespace).or_default().push(def);
+        }
+    }
+
+    let namespaces = namespaces
+        .into_iter()
+        .map(|(namespace, mut defs)| {
+            defs.sort_by(|a, b| a.name().cmp(b.name()).then(a.cmp(b)));
+
+            JsonNamespace {
+                name: namespace.to_string(),
+                types: defs.into_iter().map(|def| json_type(index, def)).collect(),
+            }
+        })
+        .collect();
+
+    serde_json::to_string_pretty(&JsonMetadata {
+        version: JSON_SCHEMA_VERSION,
+        namespaces,
+    })
+    .unwrap()
+}
+
+fn includes(filter: &[&str], namespace: &str) -> bool {
+    filter.is_empty()
+        || filter.iter().any(|filter| {
+            namespace
+                .strip_prefix(filter)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+        })
+}
+
+#[derive(Serialize)]
+struct JsonMetadata {
+    version: u32,
+    namespaces: Vec<JsonNamespace>,
+}
+
+#[derive(Serialize)]
+struct JsonNamespace {
+    name: String,
+    types: Vec<JsonType>,
+}
+
+#[derive(Serialize)]
+struct JsonType {
+    name: String,
+    kind: &'static str,
+    flags: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    extends: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    guid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    layout: Option<JsonLayout>,
+    generics: Vec<String>,
+    interfaces: Vec<JsonInterface>,
+    fields: Vec<JsonField>,
+    methods: Vec<JsonMethod>,
+    nested: Vec<JsonType>,
+    attributes: Vec<JsonAttribute>,
+}
+
+#[derive(Serialize)]
+struct JsonLayout {
+    packing: u16,
+    size: u32,
+}
+
+#[derive(Serialize)]
+struct JsonInterface {
+    #[serde(rename = "type")]
+    ty: String,
+    attributes: Vec<JsonAttribute>,
+}
+
+#[derive(Serialize)]
+struct JsonField {
+    name: String,
+    #[serde(rename = "type")]
+    ty: String,
+    flags: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    constant: Option<JsonValue>,
+    attributes: Vec<JsonAttribute>,
+}
+
+#[derive(Serialize)]
+struct JsonMethod {
+    name: String,
+    flags: u16,
+    impl_flags: u16,
+    #[serde(rename = "return")]
+    return_type: JsonParam,
+    params: Vec<JsonParam>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    import: Option<JsonImport>,
+    attributes: Vec<JsonAttribute>,
+}
+
+#[derive(Serialize)]
+struct JsonParam {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(rename = "type")]
+    ty: String,
+    flags: u16,
+    attributes: Vec<JsonAttribute>,
+}
+
+#[derive(Serialize)]
+struct JsonImport {
+    library: String,
+    name: String,
+    flags: u16,
+}
+
+#[derive(Serialize)]
+struct JsonAttribute {
+    #[serde(rename = "type")]
+    ty: String,
+    args: Vec<JsonArg>,
+}
+
+#[derive(Serialize)]
+struct JsonArg {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(flatten)]
+    value: JsonValue,
+}
+
+#[derive(Serialize)]
+struct JsonValue {
+    #[serde(rename = "type")]
+    ty: String,
+    value: serde_json::Value,
+}
+
+fn json_type(index: &reader::TypeIndex, def: reader::TypeDef) -> JsonType {
+    let generic_names: Vec<String> = def
+        .generic_params()
+        .map(|param| param.name().to_string())
+        .collect();
+
+    let generics: Vec<Type> = generic_names
+        .iter()
+        .map(|name| Type::named("", name))
+        .collect();
+
+    JsonType {
+        name: def.name().to_string(),
+        kind: match def.category() {
+            reader::TypeCategory::Interface => "interface",
+            reader::TypeCategory::Class => "class",
+            reader::TypeCategory::Enum => "enum",
+            reader::TypeCategory::Struct => "struct",
+            reader::TypeCategory::Delegate => "delegate",
+            reader::TypeCategory::Attribute => "attribute",
+        },
+        flags: def.flags().0,
+        extends: def
+            .extends()
+            .map(|extends| extends.ty(&generics).to_string()),
+        guid: def.guid_attribute().map(|guid| guid.to_string()),
+        layout: def.class_layout().map(|layout| JsonLayout {
+            packing: layout.packing_size(),
+            size: layout.class_size(),
+        }),
+        generics: generic_names,
+        interfaces: def
+            .interface_impls()
+            .map(|map| JsonInterface {
+                ty: map.interface(&generics).to_string(),
+                attributes: json_attributes(map.attributes()),
+            })
+            .collect(),
+        fields: def
+            .fields()
+            .map(|field| JsonField {
+                name: field.name().to_string(),
+                ty: field.ty().to_string(),
+                flags: field.flags().0,
+                constant: field
+                    .constant()
+                    .map(|constant| json_value(constant.value())),
+                attributes: json_attributes(field.attributes()),
+            })
+            .collect(),
+        methods: def
+            .methods()
+            .map(|method| json_method(method, &generics))
+            .collect(),
+        nested: index
+            .nested(def)
+            .map(|nested| json_type(index, nested))
+            .collect(),
+        attributes: json_attributes(def.attributes()),
+    }
+}
+
+fn json_method(method: reader::MethodDef, generics: &[Type]) -> JsonMethod {
+    let signature = method.signature(generics);
+    let params: Vec<_> = method.params().collect();
+
+    let param = |sequence: usize, ty: &Type| {
+        let param = params
+            .iter()
+            .find(|param| param.sequence() as usize == sequence);
+
+        JsonParam {
+            name: param
+                .map(|param| param.name().to_string())
+                .filter(|name| !name.is_empty()),
+            ty: ty.to_string(),
+            flags: param.map_or(0, |param| param.flags().0),
+            attributes: param.map_or_else(Vec::new, |param| json_attributes(param.attributes())),
+        }
+    };
+
+    JsonMethod {
+        name: method.name().to_string(),
+        flags: method.flags().0,
+        impl_flags: method.impl_flags().0,
+        return_type: param(0, &signature.return_type),
+        params: signature
+            .types
+            .iter()
+            .enumerate()
+            .map(|(pos, ty)| param(pos + 1, ty))
+            .collect(),
+        import: method.impl_map().map(|map| JsonImport {
+            library: map.import_scope().name().to_string(),
+            name: map.import_name().to_string(),
+            flags: map.flags().0,
+        }),
+        attributes: json_attributes(method.attributes()),
+    }
+}
+
+fn json_attributes<'a>(
+    attributes: impl Iterator<Item = reader::Attribute<'a>>,
+) -> Vec<JsonAttribute> {
+    attributes
+        .map(|attribute| {
+            let parent = attribute.ctor().parent();
+
+            JsonAttribute {
+                ty: format!("{}.{}", parent.namespace(), parent.name()),
+                args: attribute
+                    .value()
+                    .into_iter()
+                    .map(|(name, value)| JsonArg {
+                        name: (!name.is_empty()).then_some(name),
+                        value: json_value(value),
+                    })
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+fn json_value(value: Value) -> JsonValue {
+    let ty = match &value {
+        Value::AttributeEnum(name, _) => name.clone(),
+        value => value.ty().to_string(),
+    };
+
+    let value = match value {
+        Value::Bool(value) => value.into(),
+        Value::U8(value) => value.into(),
+        Value::I8(value) => value.into(),
+        Value::U16(value) => value.into(),
+        Value::I16(value) => value.into(),
+        Value::U32(value) => value.into(),
+        Value::I32(value) => value.into(),
+        Value::U64(value) => value.into(),
+        Value::I64(value) => value.into(),
+        Value::F32(value) => value.into(),
+        Value::F64(value) => value.into(),
+        Value::Utf8(value) => value.into(),
+        Value::Utf16(value) => value.into(),
+        Value::AttributeEnum(_, value) => value.into(),
+    };
+
+    JsonValue { ty, value }
+}
@@ -5,6 +5,7 @@ use std::cmp::Ordering;
 use std::collections::*;
 
 mod attributes;
+pub mod model;
 pub mod reader;
 mod value;
 pub mod writer;
@@ -23,4 +24,20 @@ pub use type_name::*;
 mod signature;
 pub use signature::*;
 
+mod guid;
+pub use guid::*;
+
+mod sha1;
+
+mod diff;
+pub use diff::*;
+
+mod validate;
+pub use validate::*;
+
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "json")]
+pub use json::*;
+
 pub use reader::{AsRow, HasAttributes};
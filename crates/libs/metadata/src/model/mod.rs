@@ -0,0 +1,676 @@
+//! A declarative model of WinRT types that can be lowered to a [`writer::File`].
+//!
+//! The model applies the WinRT conventions that would otherwise have to be written by hand with the table-level
+//! methods of [`writer::File`]: type and member flags, `GuidAttribute`s, generic arity suffixes, exclusive-to
+//! interfaces, default interfaces, activation and static factories, and contract versions.
+//!
+//! ```
+//! use windows_metadata::*;
+//! use windows_metadata::model::*;
+//!
+//! let class = RuntimeClass {
+//!     namespace: "Sample".to_string(),
+//!     name: "Widget".to_string(),
+//!     default_interface: Some(Interface {
+//!         name: "IWidget".to_string(),
+//!         guid: GUID::from_u128(0x9d5a_8e6c_7d1a_4b2e_9b3f_5c1d_2e3f_4a5b),
+//!         methods: vec![Method::new("Draw", &[Param::new("count", Type::I32)], Type::Void)],
+//!         ..Default::default()
+//!     }),
+//!     activatable: true,
+//!     ..Default::default()
+//! };
+//!
+//! let mut file = writer::File::new("Sample");
+//! model::write(&mut file, &[class.into()]);
+//! let bytes = file.into_stream();
+//! ```
+
+use super::*;
+
+#[cfg(feature = "rust")]
+mod rust;
+#[cfg(feature = "rust")]
+pub use rust::*;
+
+/// Any of the types that may be written by [`write`].
+#[derive(Clone, Debug)]
+pub enum Item {
+    Class(RuntimeClass),
+    Interface(Interface),
+    Struct(Struct),
+    Enum(Enum),
+    Delegate(Delegate),
+    ApiContract(ApiContract),
+}
+
+/// Writes the items to the file.
+pub fn write(file: &mut writer::File, items: &[Item]) {
+    for item in items {
+        match item {
+            Item::Class(ty) => ty.write(file),
+            Item::Interface(ty) => ty.write(file, None, None),
+            Item::Struct(ty) => ty.write(file),
+            Item::Enum(ty) => ty.write(file),
+            Item::Delegate(ty) => ty.write(file),
+            Item::ApiContract(ty) => ty.write(file),
+        }
+    }
+}
+
+/// The contract, and version of that contract, in which a type was introduced.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ContractVersion {
+    /// The full name of the [`ApiContract`], such as `Windows.Foundation.UniversalApiContract`.
+    pub contract: String,
+    pub version: u32,
+}
+
+impl ContractVersion {
+    pub fn new(contract: &str, version: u32) -> Self {
+        Self {
+            contract: contract.to_string(),
+            version,
+        }
+    }
+}
+
+/// A runtime class. Classes are always sealed.
+///
+/// Interfaces owned by the class are exclusive to it. An interface with an empty namespace or no contract takes
+/// the namespace or contract of the class.
+#[derive(Clone, Debug, Default)]
+pub struct RuntimeClass {
+    pub namespace: String,
+    pub name: String,
+    /// The exclusive interface that defines the instance members of the class.
+    pub default_interface: Option<Interface>,
+    /// Additional exclusive interfaces that define instance members of the class.
+    pub interfaces: Vec<Interface>,
+    /// Interfaces that are not exclusive to the class, such as `Windows.Foundation.IClosable`.
+    pub implements: Vec<Type>,
+    /// Exclusive interfaces that define the static members of the class.
+    pub statics: Vec<Interface>,
+    /// Exclusive interfaces that define parameterized constructors for the class.
+    pub factories: Vec<Interface>,
+    /// Whether the class has a default constructor.
+    pub activatable: bool,
+    pub contract: Option<ContractVersion>,
+}
+
+/// An interface or, when owned by a [`RuntimeClass`], an interface exclusive to that class.
+#[derive(Clone, Debug, Default)]
+pub struct Interface {
+    pub namespace: String,
+    /// The name without any generic arity suffix.
+    pub name: String,
+    pub guid: GUID,
+    /// The names of any generic parameters, which are referred to within the interface by position with `Type::Generic`.
+    pub generics: Vec<String>,
+    /// Required interfaces.
+    pub requires: Vec<Type>,
+    pub methods: Vec<Method>,
+    pub contract: Option<ContractVersion>,
+}
+
+/// An interface or delegate method. Methods named with a `get_`, `put_`, `add_`, or `remove_` prefix are marked as
+/// property or event accessors.
+#[derive(Clone, Debug, Default)]
+pub struct Method {
+    pub name: String,
+    pub params: Vec<Param>,
+    pub return_type: Type,
+}
+
+impl Method {
+    pub fn new(name: &str, params: &[Param], return_type: Type) -> Self {
+        Self {
+            name: name.to_string(),
+            params: params.to_vec(),
+            return_type,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Param {
+    pub name: String,
+    pub ty: Type,
+    pub out: bool,
+}
+
+impl Param {
+    /// Creates an input parameter.
+    pub fn new(name: &str, ty: Type) -> Self {
+        Self {
+            name: name.to_string(),
+            ty,
+            out: false,
+        }
+    }
+
+    /// Creates an output parameter.
+    pub fn out(name: &str, ty: Type) -> Self {
+        Self {
+            name: name.to_string(),
+            ty,
+            out: true,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Struct {
+    pub namespace: String,
+    pub name: String,
+    pub fields: Vec<Field>,
+    pub contract: Option<ContractVersion>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Field {
+    pub name: String,
+    pub ty: Type,
+}
+
+impl Field {
+    pub fn new(name: &str, ty: Type) -> Self {
+        Self {
+            name: name.to_string(),
+            ty,
+        }
+    }
+}
+
+/// An enum with an underlying type of `i32`, or `u32` for flags.
+#[derive(Clone, Debug, Default)]
+pub struct Enum {
+    pub namespace: String,
+    pub name: String,
+    pub flags: bool,
+    pub values: Vec<(String, i64)>,
+    pub contract: Option<ContractVersion>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Delegate {
+    pub namespace: String,
+    /// The name without any generic arity suffix.
+    pub name: String,
+    pub guid: GUID,
+    pub generics: Vec<String>,
+    pub params: Vec<Param>,
+    pub return_type: Type,
+    pub contract: Option<ContractVersion>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ApiContract {
+    pub namespace: String,
+    pub name: String,
+    pub version: u32,
+}
+
+macro_rules! item {
+    ($($ty:ident => $variant:ident)+) => {
+        $(
+            impl From<$ty> for Item {
+                fn from(from: $ty) -> Self {
+                    Self::$variant(from)
+                }
+            }
+        )*
+    };
+}
+
+item! {
+    RuntimeClass => Class
+    Interface => Interface
+    Struct => Struct
+    Enum => Enum
+    Delegate => Delegate
+    ApiContract => ApiContract
+}
+
+impl RuntimeClass {
+    fn write(&self, file: &mut writer::File) {
+        let exclusive_to = format!("{}.{}", self.namespace, self.name);
+
+        let owned = |interface: &Interface| {
+            let mut interface = interface.clone();
+
+            if interface.namespace.is_empty() {
+                interface.namespace = self.namespace.clone();
+            }
+
+            if interface.contract.is_none() {
+                interface.contract = self.contract.clone();
+            }
+
+            interface
+        };
+
+        let default_interface = self.default_interface.as_ref().map(owned);
+        let interfaces: Vec<_> = self.interfaces.iter().map(owned).collect();
+        let statics: Vec<_> = self.statics.iter().map(owned).collect();
+        let factories: Vec<_> = self.factories.iter().map(owned).collect();
+
+        let object = file.TypeRef("System", "Object");
+
+        let def = file.TypeDef(
+            &self.namespace,
+            &self.name,
+            writer::TypeDefOrRef::TypeRef(object),
+            TypeAttributes::Public | TypeAttributes::Sealed | TypeAttributes::WindowsRuntime,
+        );
+
+        if let Some(interface) = &default_interface {
+            let interface_impl = file.InterfaceImpl(def, &interface.ty());
+            attribute(
+                file,
+                writer::HasAttribute::InterfaceImpl(interface_impl),
+                "DefaultAttribute",
+                &[],
+            );
+        }
+
+        for interface in &interfaces {
+            file.InterfaceImpl(def, &interface.ty());
+        }
+
+        for interface in &self.implements {
+            file.InterfaceImpl(def, interface);
+        }
+
+        let parent = writer::HasAttribute::TypeDef(def);
+        let contract = self.contract.as_ref();
+
+        if self.activatable {
+            attribute(
+                file,
+                parent,
+                "ActivatableAttribute",
+                &version_args(contract),
+            );
+        }
+
+        for factory in &factories {
+            attribute(
+                file,
+                parent,
+                "ActivatableAttribute",
+                &factory_args(factory, contract),
+            );
+        }
+
+        for statics in &statics {
+            attribute(
+                file,
+                parent,
+                "StaticAttribute",
+                &factory_args(statics, contract),
+            );
+        }
+
+        contract_version(file, parent, contract);
+
+        for interface in default_interface
+            .iter()
+            .chain(&interfaces)
+            .chain(&statics)
+            .chain(&factories)
+        {
+            interface.write(file, Some(&exclusive_to), contract);
+        }
+    }
+}
+
+impl Interface {
+    fn ty(&self) -> Type {
+        Type::named(&self.namespace, &self.name)
+    }
+
+    fn write(
+        &self,
+        file: &mut writer::File,
+        exclusive_to: Option<&str>,
+        class_contract: Option<&ContractVersion>,
+    ) {
+        let def = file.TypeDef(
+            &self.namespace,
+            &arity_name(&self.name, &self.generics),
+            writer::TypeDefOrRef::default(),
+            TypeAttributes::Public
+                | TypeAttributes::Interface
+                | TypeAttributes::Abstract
+                | TypeAttributes::WindowsRuntime,
+        );
+
+        for method in &self.methods {
+            method.write(
+                file,
+                MethodAttributes::Public
+                    | MethodAttributes::Virtual
+                    | MethodAttributes::HideBySig
+                    | MethodAttributes::NewSlot
+                    | MethodAttributes::Abstract,
+                MethodImplAttributes::default(),
+            );
+        }
+
+        generic_params(file, def, &self.generics);
+
+        for interface in &self.requires {
+            file.InterfaceImpl(def, interface);
+        }
+
+        let parent = writer::HasAttribute::TypeDef(def);
+        guid_attribute(file, parent, self.guid);
+
+        if let Some(exclusive_to) = exclusive_to {
+            attribute(
+                file,
+                parent,
+                "ExclusiveToAttribute",
+                &[(
+                    Type::named("System", "Type"),
+                    Value::Utf8(exclusive_to.to_string()),
+                )],
+            );
+        }
+
+        contract_version(file, parent, self.contract.as_ref().or(class_contract));
+    }
+}
+
+impl Method {
+    fn write(
+        &self,
+        file: &mut writer::File,
+        flags: MethodAttributes,
+        impl_flags: MethodImplAttributes,
+    ) {
+        let mut flags = flags;
+
+        if ["get_", "put_", "add_", "remove_"]
+            .iter()
+            .any(|prefix| self.name.starts_with(prefix))
+        {
+            flags |= MethodAttributes::SpecialName;
+        }
+
+        let signature = Signature {
+            return_type: self.return_type.clone(),
+            types: self.params.iter().map(|param| param.ty.clone()).collect(),
+            ..Default::default()
+        };
+
+        file.MethodDef(&self.name, &signature, flags, impl_flags);
+
+        for (sequence, param) in self.params.iter().enumerate() {
+            let flags = if param.out {
+                ParamAttributes::Out
+            } else {
+                ParamAttributes::In
+            };
+
+            file.Param(&param.name, sequence as u16 + 1, flags);
+        }
+    }
+}
+
+impl Struct {
+    fn write(&self, file: &mut writer::File) {
+        let value_type = file.TypeRef("System", "ValueType");
+
+        let def = file.TypeDef(
+            &self.namespace,
+            &self.name,
+            writer::TypeDefOrRef::TypeRef(value_type),
+            TypeAttributes::Public
+                | TypeAttributes::Sealed
+                | TypeAttributes::SequentialLayout
+                | TypeAttributes::WindowsRuntime,
+        );
+
+        for field in &self.fields {
+            file.Field(&field.name, &field.ty, FieldAttributes::Public);
+        }
+
+        contract_version(
+            file,
+            writer::HasAttribute::TypeDef(def),
+            self.contract.as_ref(),
+        );
+    }
+}
+
+impl Enum {
+    fn write(&self, file: &mut writer::File) {
+        let enum_type = file.TypeRef("System", "Enum");
+
+        let def = file.TypeDef(
+            &self.namespace,
+            &self.name,
+            writer::TypeDefOrRef::TypeRef(enum_type),
+            TypeAttributes::Public | TypeAttributes::Sealed | TypeAttributes::WindowsRuntime,
+        );
+
+        let underlying = if self.flags { Type::U32 } else { Type::I32 };
+
+        file.Field(
+            "value__",
+            &underlying,
+            FieldAttributes::Private
+                | FieldAttributes::SpecialName
+                | FieldAttributes::RTSpecialName,
+        );
+
+        for (name, value) in &self.values {
+            let field = file.Field(
+                name,
+                &Type::named(&self.namespace, &self.name),
+                FieldAttributes::Public
+                    | FieldAttributes::Static
+                    | FieldAttributes::Literal
+                    | FieldAttributes::HasDefault,
+            );
+
+            let value = if self.flags {
+                Value::U32((*value).try_into().unwrap_or_else(|_| {
+                    panic!("value of `{name}` does not fit in the underlying type")
+                }))
+            } else {
+                Value::I32((*value).try_into().unwrap_or_else(|_| {
+                    panic!("value of `{name}` does not fit in the underlying type")
+                }))
+            };
+
+            file.Constant(writer::HasConstant::Field(field), &value);
+        }
+
+        let parent = writer::HasAttribute::TypeDef(def);
+
+        if self.flags {
+            let attribute_ref =
+                writer::MemberRefParent::TypeRef(file.TypeRef("System", "FlagsAttribute"));
+            let ctor = file.MemberRef(".ctor", &Signature::default(), attribute_ref);
+            file.Attribute(parent, writer::AttributeType::MemberRef(ctor), &[]);
+        }
+
+        contract_version(file, parent, self.contract.as_ref());
+    }
+}
+
+impl Delegate {
+    fn write(&self, file: &mut writer::File) {
+        let delegate_type = file.TypeRef("System", "MulticastDelegate");
+
+        let def = file.TypeDef(
+            &self.namespace,
+            &arity_name(&self.name, &self.generics),
+            writer::TypeDefOrRef::TypeRef(delegate_type),
+            TypeAttributes::Public | TypeAttributes::Sealed | TypeAttributes::WindowsRuntime,
+        );
+
+        Method::new(
+            ".ctor",
+            &[
+                Param::new("object", Type::Object),
+                Param::new("method", Type::ISize),
+            ],
+            Type::Void,
+        )
+        .write(
+            file,
+            MethodAttributes::Private
+                | MethodAttributes::HideBySig
+                | MethodAttributes::SpecialName
+                | MethodAttributes::RTSpecialName,
+            MethodImplAttributes::Runtime,
+        );
+
+        Method {
+            name: "Invoke".to_string(),
+            params: self.params.clone(),
+            return_type: self.return_type.clone(),
+        }
+        .write(
+            file,
+            MethodAttributes::Public
+                | MethodAttributes::Virtual
+                | MethodAttributes::HideBySig
+                | MethodAttributes::NewSlot,
+            MethodImplAttributes::Runtime,
+        );
+
+        generic_params(file, def, &self.generics);
+
+        let parent = writer::HasAttribute::TypeDef(def);
+        guid_attribute(file, parent, self.guid);
+        contract_version(file, parent, self.contract.as_ref());
+    }
+}
+
+impl ApiContract {
+    fn write(&self, file: &mut writer::File) {
+        let value_type = file.TypeRef("System", "ValueType");
+
+        let def = file.TypeDef(
+            &self.namespace,
+            &self.name,
+            writer::TypeDefOrRef::TypeRef(value_type),
+            TypeAttributes::Public
+                | TypeAttributes::Sealed
+                | TypeAttributes::SequentialLayout
+                | TypeAttributes::WindowsRuntime,
+        );
+
+        let parent = writer::HasAttribute::TypeDef(def);
+        attribute(file, parent, "ApiContractAttribute", &[]);
+
+        attribute(
+            file,
+            parent,
+            "ContractVersionAttribute",
+            &[(Type::U32, Value::U32(self.version))],
+        );
+    }
+}
+
+fn arity_name(name: &str, generics: &[String]) -> String {
+    if generics.is_empty() {
+        name.to_string()
+    } else {
+        format!("{name}`{}", generics.len())
+    }
+}
+
+fn generic_params(file: &mut writer::File, def: writer::TypeDef, generics: &[String]) {
+    for (number, name) in generics.iter().enumerate() {
+        file.GenericParam(
+            name,
+            writer::TypeOrMethodDef::TypeDef(def),
+            number as u16,
+            GenericParamAttributes::default(),
+        );
+    }
+}
+
+// Writes an attribute from the `Windows.Foundation.Metadata` namespace with the given positional arguments.
+fn attribute(
+    file: &mut writer::File,
+    parent: writer::HasAttribute,
+    name: &str,
+    args: &[(Type, Value)],
+) {
+    let signature = Signature {
+        types: args.iter().map(|(ty, _)| ty.clone()).collect(),
+        ..Default::default()
+    };
+
+    let attribute_ref =
+        writer::MemberRefParent::TypeRef(file.TypeRef("Windows.Foundation.Metadata", name));
+
+    let ctor = file.MemberRef(".ctor", &signature, attribute_ref);
+
+    let args: Vec<_> = args
+        .iter()
+        .map(|(_, value)| (String::new(), value.clone()))
+        .collect();
+
+    file.Attribute(parent, writer::AttributeType::MemberRef(ctor), &args);
+}
+
+fn guid_attribute(file: &mut writer::File, parent: writer::HasAttribute, guid: GUID) {
+    let args: Vec<_> = guid
+        .to_args()
+        .into_iter()
+        .map(|(_, value)| (value.ty(), value))
+        .collect();
+
+    attribute(file, parent, "GuidAttribute", &args);
+}
+
+fn contract_version(
+    file: &mut writer::File,
+    parent: writer::HasAttribute,
+    contract: Option<&ContractVersion>,
+) {
+    if let Some(contract) = contract {
+        attribute(
+            file,
+            parent,
+            "ContractVersionAttribute",
+            &[
+                (
+                    Type::named("System", "Type"),
+                    Value::Utf8(contract.contract.clone()),
+                ),
+                (Type::U32, Value::U32(contract.version)),
+            ],
+        );
+    }
+}
+
+// The arguments for an `ActivatableAttribute` without a factory interface.
+fn version_args(contract: Option<&ContractVersion>) -> Vec<(Type, Value)> {
+    match contract {
+        Some(contract) => vec![
+            (Type::U32, Value::U32(contract.version)),
+            (Type::String, Value::Utf8(contract.contract.clone())),
+        ],
+        None => vec![(Type::U32, Value::U32(1))],
+    }
+}
+
+// The arguments for an `ActivatableAttribute` or `StaticAttribute` with a factory interface.
+fn factory_args(interface: &Interface, contract: Option<&ContractVersion>) -> Vec<(Type, Value)> {
+    let mut args = vec![(
+        Type::named("System", "Type"),
+        Value::Utf8(format!("{}.{}", interface.namespace, interface.name)),
+    )];
+
+    args.extend(version_args(contract));
+    args
+}
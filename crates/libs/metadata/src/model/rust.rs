@@ -0,0 +1,708 @@
+use super::*;
+
+/// Reads annotated Rust items from the source and returns the matching model items.
+///
+/// This is intended for use from a build script so that a component's API may be written in Rust rather than IDL.
+/// The source is only parsed and need not be part of the crate being built. Items without one of the following
+/// attributes are ignored:
+///
+/// ```text
+/// #[winrt::interface]
+/// trait IWidget: Windows::Foundation::IClosable {
+///     fn get_Size(&self) -> Result<i32>;
+///     fn Draw(&self, count: i32, result: &mut HSTRING) -> Result<()>;
+/// }
+///
+/// #[winrt::interface("1b2c3d4e-5f60-4718-9a2b-3c4d5e6f7081")]
+/// trait IWidgetStatics {
+///     fn Create(&self, values: &[u8]) -> Result<Widget>;
+/// }
+///
+/// #[winrt::class(default = IWidget, statics = IWidgetStatics, activatable)]
+/// struct Widget;
+///
+/// #[winrt::delegate]
+/// trait Handler<T> {
+///     fn Invoke(&self, value: T) -> Result<()>;
+/// }
+///
+/// #[winrt::structure]
+/// struct Point { X: f32, Y: f32 }
+///
+/// #[winrt::enumeration(flags)]
+/// enum Options { None, Fast, Small = 4 }
+/// ```
+///
+/// Keywords are not valid in attribute paths, hence `structure` and `enumeration` rather than `struct` and `enum`.
+/// An enumeration with the `flags` argument has an underlying type of `u32` rather than `i32`.
+///
+/// A class may name its interfaces with `default`, `interfaces`, `statics`, and `factory`, each of which must be
+/// an interface defined in the same source and which is then exclusive to the class. Other interfaces the class
+/// implements are named with `implements`. The `activatable` flag indicates a default constructor.
+///
+/// Methods take `&self` and return `Result<T>`, `T`, or nothing. A `&mut T` parameter is an output parameter and a
+/// `&[T]` parameter is an input array. Single-segment type names refer to the given namespace while paths such as
+/// `Windows::Foundation::Uri` name a type in another namespace. The primitive types, `HSTRING`, `IInspectable`, and
+/// `GUID` map to their WinRT equivalents.
+///
+/// Interfaces and delegates without an explicit GUID are given a name-based GUID derived from the full name and
+/// methods of the type, so it is stable from one build to the next but changes along with the type's methods.
+///
+/// The attributes are provided by the `windows-winrt` crate. They remove the items that they are applied to, so the
+/// source may also be a module of the crate without having to resolve the types that it names.
+///
+/// A build script might write the metadata for `bindgen --implement` as follows:
+///
+/// ```no_run
+/// use windows_metadata::*;
+///
+/// let source = std::fs::read_to_string("src/api.rs").unwrap();
+/// let items = model::from_rust("Sample", &source).unwrap();
+/// let mut file = writer::File::new("Sample");
+/// model::write(&mut file, &items);
+/// std::fs::write("Sample.winmd", file.into_stream()).unwrap();
+/// ```
+///
+/// An error is returned if the source cannot be parsed or an annotated item cannot be represented in metadata.
+pub fn from_rust(namespace: &str, source: &str) -> syn::Result<Vec<Item>> {
+    let file = syn::parse_file(source)?;
+
+    let mut items = vec![];
+    let mut interfaces = BTreeMap::new();
+    let mut classes = vec![];
+
+    for item in &file.items {
+        match item {
+            syn::Item::Trait(item) => match winrt_attribute(&item.attrs)? {
+                Some(("interface", attribute)) => {
+                    let interface = interface(namespace, item, attribute)?;
+                    interfaces.insert(interface.name.clone(), items.len());
+                    items.push(Some(Item::Interface(interface)));
+                }
+                Some(("delegate", attribute)) => {
+                    items.push(Some(Item::Delegate(delegate(namespace, item, attribute)?)))
+                }
+                Some((kind, attribute)) => {
+                    return Err(syn::Error::new_spanned(
+                        attribute,
+                        format!(
+                            "`{}`: `winrt::{kind}` cannot be applied to a trait",
+                            item.ident
+                        ),
+                    ))
+                }
+                None => {}
+            },
+            syn::Item::Struct(item) => match winrt_attribute(&item.attrs)? {
+                Some(("class", attribute)) => {
+                    // The class is added once the interfaces that it claims have been read.
+                    let (class, claimed) = class(namespace, item, attribute)?;
+                    classes.push((items.len(), class, claimed));
+                    items.push(None);
+                }
+                Some(("structure", _)) => {
+                    items.push(Some(Item::Struct(structure(namespace, item)?)))
+                }
+                Some((kind, attribute)) => {
+                    return Err(syn::Error::new_spanned(
+                        attribute,
+                        format!(
+                            "`{}`: `winrt::{kind}` cannot be applied to a struct",
+                            item.ident
+                        ),
+                    ))
+                }
+                None => {}
+            },
+            syn::Item::Enum(item) => match winrt_attribute(&item.attrs)? {
+                Some(("enumeration", attribute)) => {
+                    items.push(Some(Item::Enum(enumeration(namespace, item, attribute)?)))
+                }
+                Some((kind, attribute)) => {
+                    return Err(syn::Error::new_spanned(
+                        attribute,
+                        format!(
+                            "`{}`: `winrt::{kind}` cannot be applied to an enum",
+                            item.ident
+                        ),
+                    ))
+                }
+                None => {}
+            },
+            _ => {}
+        }
+    }
+
+    // Interfaces named by a class are moved into the class so that they are written as exclusive to it.
+    for (pos, mut class, claimed) in classes {
+        let mut claim = |(path, ty): &(syn::Path, Type)| {
+            let owned = match ty {
+                Type::Name(type_name) if type_name.namespace == namespace => interfaces
+                    .get(&type_name.name)
+                    .and_then(|pos| items[*pos].take()),
+                _ => None,
+            };
+
+            match owned {
+                Some(Item::Interface(interface)) => Ok(interface),
+                _ => Err(syn::Error::new_spanned(
+                    path,
+                    format!(
+                        "`{}`: interface `{}` must be defined in the same source and may only belong to one class",
+                        class.name,
+                        path.segments
+                            .iter()
+                            .map(|segment| segment.ident.to_string())
+                            .collect::<Vec<_>>()
+                            .join("::")
+                    ),
+                )),
+            }
+        };
+
+        class.default_interface = claimed.default.as_ref().map(&mut claim).transpose()?;
+        class.interfaces = claimed
+            .interfaces
+            .iter()
+            .map(&mut claim)
+            .collect::<syn::Result<_>>()?;
+        class.statics = claimed
+            .statics
+            .iter()
+            .map(&mut claim)
+            .collect::<syn::Result<_>>()?;
+        class.factories = claimed
+            .factories
+            .iter()
+            .map(&mut claim)
+            .collect::<syn::Result<_>>()?;
+        items[pos] = Some(Item::Class(class));
+    }
+
+    Ok(items.into_iter().flatten().collect())
+}
+
+// The interfaces named by a class attribute, which are resolved once the whole source has been read.
+#[derive(Default)]
+struct Claimed {
+    default: Option<(syn::Path, Type)>,
+    interfaces: Vec<(syn::Path, Type)>,
+    statics: Vec<(syn::Path, Type)>,
+    factories: Vec<(syn::Path, Type)>,
+}
+
+fn winrt_attribute(
+    attrs: &[syn::Attribute],
+) -> syn::Result<Option<(&'static str, &syn::Attribute)>> {
+    for attribute in attrs {
+        let segments: Vec<_> = attribute.path().segments.iter().collect();
+
+        if segments.len() != 2 || segments[0].ident != "winrt" {
+            continue;
+        }
+
+        let kind = ["interface", "delegate", "class", "structure", "enumeration"]
+            .into_iter()
+            .find(|kind| segments[1].ident == kind);
+
+        return match kind {
+            Some(kind) => Ok(Some((kind, attribute))),
+            None => Err(syn::Error::new_spanned(
+                attribute,
+                format!("unknown attribute `winrt::{}`", segments[1].ident),
+            )),
+        };
+    }
+
+    Ok(None)
+}
+
+fn interface(
+    namespace: &str,
+    item: &syn::ItemTrait,
+    attribute: &syn::Attribute,
+) -> syn::Result<Interface> {
+    let name = item.ident.to_string();
+    let generics = generics(&name, &item.generics)?;
+
+    let requires = item
+        .supertraits
+        .iter()
+        .map(|bound| match bound {
+            syn::TypeParamBound::Trait(bound) => {
+                path_type(namespace, &generics, &name, &bound.path)
+            }
+            _ => Err(syn::Error::new_spanned(
+                bound,
+                format!("`{name}`: required interfaces must be named by path"),
+            )),
+        })
+        .collect::<syn::Result<_>>()?;
+
+    let methods = methods(namespace, &generics, &name, item)?;
+    let guid = guid(attribute, namespace, &name, &methods)?;
+
+    Ok(Interface {
+        namespace: namespace.to_string(),
+        name,
+        guid,
+        generics,
+        requires,
+        methods,
+        contract: None,
+    })
+}
+
+fn delegate(
+    namespace: &str,
+    item: &syn::ItemTrait,
+    attribute: &syn::Attribute,
+) -> syn::Result<Delegate> {
+    let name = item.ident.to_string();
+    let generics = generics(&name, &item.generics)?;
+    let mut methods = methods(namespace, &generics, &name, item)?;
+
+    if methods.len() != 1 {
+        return Err(syn::Error::new_spanned(
+            &item.ident,
+            format!("`{name}`: delegate must have exactly one method"),
+        ));
+    }
+
+    let guid = guid(attribute, namespace, &name, &methods)?;
+    let method = methods.remove(0);
+
+    Ok(Delegate {
+        namespace: namespace.to_string(),
+        name,
+        guid,
+        generics,
+        params: method.params,
+        return_type: method.return_type,
+        contract: None,
+    })
+}
+
+fn class(
+    namespace: &str,
+    item: &syn::ItemStruct,
+    attribute: &syn::Attribute,
+) -> syn::Result<(RuntimeClass, Claimed)> {
+    let name = item.ident.to_string();
+    let mut claimed = Claimed::default();
+    let mut implements = vec![];
+    let mut activatable = false;
+
+    if !matches!(attribute.meta, syn::Meta::Path(_)) {
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("activatable") {
+                activatable = true;
+                return Ok(());
+            }
+
+            let value: syn::Path = meta.value()?.parse()?;
+            let ty = path_type(namespace, &[], &name, &value)?;
+
+            if meta.path.is_ident("default") {
+                if claimed.default.replace((value, ty)).is_some() {
+                    return Err(
+                        meta.error(format!("`{name}`: only one default interface is allowed"))
+                    );
+                }
+            } else if meta.path.is_ident("interfaces") {
+                claimed.interfaces.push((value, ty));
+            } else if meta.path.is_ident("statics") {
+                claimed.statics.push((value, ty));
+            } else if meta.path.is_ident("factory") {
+                claimed.factories.push((value, ty));
+            } else if meta.path.is_ident("implements") {
+                implements.push(ty);
+            } else {
+                return Err(meta.error(format!("`{name}`: unknown class argument")));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    let class = RuntimeClass {
+        namespace: namespace.to_string(),
+        name,
+        implements,
+        activatable,
+        ..Default::default()
+    };
+
+    Ok((class, claimed))
+}
+
+fn structure(namespace: &str, item: &syn::ItemStruct) -> syn::Result<Struct> {
+    let name = item.ident.to_string();
+
+    let syn::Fields::Named(fields) = &item.fields else {
+        return Err(syn::Error::new_spanned(
+            &item.ident,
+            format!("`{name}`: struct must have named fields"),
+        ));
+    };
+
+    let fields = fields
+        .named
+        .iter()
+        .map(|field| {
+            let field_name = field.ident.as_ref().unwrap().to_string();
+            let location = format!("{name}::{field_name}");
+            Ok(Field::new(
+                &field_name,
+                to_type(namespace, &[], &location, &field.ty)?,
+            ))
+        })
+        .collect::<syn::Result<_>>()?;
+
+    Ok(Struct {
+        namespace: namespace.to_string(),
+        name,
+        fields,
+        contract: None,
+    })
+}
+
+fn enumeration(
+    namespace: &str,
+    item: &syn::ItemEnum,
+    attribute: &syn::Attribute,
+) -> syn::Result<Enum> {
+    let name = item.ident.to_string();
+    let mut flags = false;
+
+    if !matches!(attribute.meta, syn::Meta::Path(_)) {
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("flags") {
+                flags = true;
+                Ok(())
+            } else {
+                Err(meta.error(format!("`{name}`: unknown enum argument")))
+            }
+        })?;
+    }
+
+    let mut next = 0;
+
+    let values = item
+        .variants
+        .iter()
+        .map(|variant| {
+            let value = match &variant.discriminant {
+                Some((_, expr)) => discriminant(expr).ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        expr,
+                        format!(
+                            "`{name}::{}`: discriminant must be an integer literal",
+                            variant.ident
+                        ),
+                    )
+                })?,
+                None => next,
+            };
+
+            next = value + 1;
+            Ok((variant.ident.to_string(), value))
+        })
+        .collect::<syn::Result<_>>()?;
+
+    Ok(Enum {
+        namespace: namespace.to_string(),
+        name,
+        flags,
+        values,
+        contract: None,
+    })
+}
+
+fn discriminant(expr: &syn::Expr) -> Option<i64> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(value),
+            ..
+        }) => value.base10_parse().ok(),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => discriminant(expr).map(|value| -value),
+        _ => None,
+    }
+}
+
+fn generics(name: &str, generics: &syn::Generics) -> syn::Result<Vec<String>> {
+    generics
+        .params
+        .iter()
+        .map(|param| match param {
+            syn::GenericParam::Type(param) if param.bounds.is_empty() => {
+                Ok(param.ident.to_string())
+            }
+            _ => Err(syn::Error::new_spanned(
+                param,
+                format!("`{name}`: only unbounded type parameters are supported"),
+            )),
+        })
+        .collect()
+}
+
+fn methods(
+    namespace: &str,
+    generics: &[String],
+    name: &str,
+    item: &syn::ItemTrait,
+) -> syn::Result<Vec<Method>> {
+    item.items
+        .iter()
+        .map(|item| {
+            let syn::TraitItem::Fn(method) = item else {
+                return Err(syn::Error::new_spanned(
+                    item,
+                    format!("`{name}`: only methods are supported"),
+                ));
+            };
+
+            let location = format!("{name}::{}", method.sig.ident);
+            let mut inputs = method.sig.inputs.iter();
+
+            if !matches!(inputs.next(), Some(syn::FnArg::Receiver(receiver)) if receiver.reference.is_some() && receiver.mutability.is_none())
+            {
+                return Err(syn::Error::new_spanned(
+                    &method.sig,
+                    format!("`{location}`: method must take `&self`"),
+                ));
+            }
+
+            let params = inputs
+                .map(|input| {
+                    // A receiver is only valid as the first input, which has already been read.
+                    let syn::FnArg::Typed(input) = input else {
+                        return Err(syn::Error::new_spanned(
+                            input,
+                            format!("`{location}`: unexpected receiver"),
+                        ));
+                    };
+
+                    let syn::Pat::Ident(pat) = &*input.pat else {
+                        return Err(syn::Error::new_spanned(
+                            &input.pat,
+                            format!("`{location}`: parameters must be named"),
+                        ));
+                    };
+
+                    let param_name = pat.ident.to_string();
+
+                    Ok(match &*input.ty {
+                        syn::Type::Reference(reference) if reference.mutability.is_some() => {
+                            Param::out(&param_name, to_type(namespace, generics, &location, &reference.elem)?)
+                        }
+                        ty => Param::new(&param_name, to_type(namespace, generics, &location, ty)?),
+                    })
+                })
+                .collect::<syn::Result<_>>()?;
+
+            let return_type = match &method.sig.output {
+                syn::ReturnType::Default => Type::Void,
+                syn::ReturnType::Type(_, ty) => match result_type(ty) {
+                    Some(Some(ty)) => to_type(namespace, generics, &location, ty)?,
+                    Some(None) => Type::Void,
+                    None => to_type(namespace, generics, &location, ty)?,
+                },
+            };
+
+            Ok(Method {
+                name: method.sig.ident.to_string(),
+                params,
+                return_type,
+            })
+        })
+        .collect()
+}
+
+// Returns the `T` of a `Result<T>` return type, or `Some(None)` for `Result<()>`.
+fn result_type(ty: &syn::Type) -> Option<Option<&syn::Type>> {
+    let syn::Type::Path(path) = ty else {
+        return None;
+    };
+
+    let segment = path.path.segments.last()?;
+
+    if segment.ident != "Result" {
+        return None;
+    }
+
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+
+    match args.args.first()? {
+        syn::GenericArgument::Type(syn::Type::Tuple(tuple)) if tuple.elems.is_empty() => Some(None),
+        syn::GenericArgument::Type(ty) => Some(Some(ty)),
+        _ => None,
+    }
+}
+
+fn to_type(
+    namespace: &str,
+    generics: &[String],
+    location: &str,
+    ty: &syn::Type,
+) -> syn::Result<Type> {
+    match ty {
+        syn::Type::Path(path) if path.qself.is_none() => {
+            path_type(namespace, generics, location, &path.path)
+        }
+        syn::Type::Reference(reference) if reference.mutability.is_none() => match &*reference.elem
+        {
+            syn::Type::Slice(slice) => Ok(Type::Array(Box::new(to_type(
+                namespace,
+                generics,
+                location,
+                &slice.elem,
+            )?))),
+            _ => Err(syn::Error::new_spanned(
+                ty,
+                format!("`{location}`: only slices may be passed by shared reference"),
+            )),
+        },
+        _ => Err(syn::Error::new_spanned(
+            ty,
+            format!("`{location}`: unsupported type"),
+        )),
+    }
+}
+
+fn path_type(
+    namespace: &str,
+    generics: &[String],
+    location: &str,
+    path: &syn::Path,
+) -> syn::Result<Type> {
+    let segments: Vec<_> = path.segments.iter().collect();
+
+    let Some(last) = segments.last() else {
+        return Err(syn::Error::new_spanned(
+            path,
+            format!("`{location}`: unsupported type"),
+        ));
+    };
+
+    let name = last.ident.to_string();
+
+    let args: Vec<Type> = match &last.arguments {
+        syn::PathArguments::None => vec![],
+        syn::PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .map(|arg| match arg {
+                syn::GenericArgument::Type(ty) => to_type(namespace, generics, location, ty),
+                _ => Err(syn::Error::new_spanned(
+                    arg,
+                    format!("`{location}`: only type arguments are supported"),
+                )),
+            })
+            .collect::<syn::Result<_>>()?,
+        syn::PathArguments::Parenthesized(_) => {
+            return Err(syn::Error::new_spanned(
+                path,
+                format!("`{location}`: unsupported type"),
+            ))
+        }
+    };
+
+    if segments.len() == 1 && args.is_empty() {
+        if let Some(pos) = generics.iter().position(|generic| *generic == name) {
+            return Ok(Type::Generic(pos as u16));
+        }
+
+        if let Some(ty) = primitive(&name) {
+            return Ok(ty);
+        }
+    }
+
+    let namespace = if segments.len() == 1 {
+        namespace.to_string()
+    } else {
+        segments[..segments.len() - 1]
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<_>>()
+            .join(".")
+    };
+
+    let name = if args.is_empty() {
+        name
+    } else {
+        format!("{name}`{}", args.len())
+    };
+
+    Ok(Type::Name(TypeName {
+        namespace,
+        name,
+        generics: args,
+    }))
+}
+
+fn primitive(name: &str) -> Option<Type> {
+    Some(match name {
+        "bool" => Type::Bool,
+        "i8" => Type::I8,
+        "u8" => Type::U8,
+        "i16" => Type::I16,
+        "u16" => Type::U16,
+        "i32" => Type::I32,
+        "u32" => Type::U32,
+        "i64" => Type::I64,
+        "u64" => Type::U64,
+        "f32" => Type::F32,
+        "f64" => Type::F64,
+        "HSTRING" => Type::String,
+        "IInspectable" => Type::Object,
+        "GUID" => Type::named("System", "Guid"),
+        _ => return None,
+    })
+}
+
+fn guid(
+    attribute: &syn::Attribute,
+    namespace: &str,
+    name: &str,
+    methods: &[Method],
+) -> syn::Result<GUID> {
+    if let syn::Meta::List(_) = attribute.meta {
+        let literal: syn::LitStr = attribute.parse_args()?;
+        let value = literal.value();
+        let digits = value.replace('-', "");
+
+        return match u128::from_str_radix(&digits, 16) {
+            Ok(value) if digits.len() == 32 => Ok(GUID::from_u128(value)),
+            _ => Err(syn::Error::new_spanned(
+                literal,
+                format!("`{name}`: invalid GUID `{value}`"),
+            )),
+        };
+    }
+
+    let mut signature = format!("{namespace}.{name}");
+
+    for method in methods {
+        let params: Vec<_> = method
+            .params
+            .iter()
+            .map(|param| format!("{}{}", if param.out { "out " } else { "" }, param.ty))
+            .collect();
+
+        signature.push_str(&format!(
+            ";{}({})->{}",
+            method.name,
+            params.join(","),
+            method.return_type
+        ));
+    }
+
+    Ok(GUID::from_signature(&signature))
+}
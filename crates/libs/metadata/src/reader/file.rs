@@ -1,7 +1,7 @@
 use super::*;
 
 pub struct File {
-    bytes: Vec<u8>,
+    bytes: Bytes,
     strings: usize,
     blobs: usize,
     tables: [Table; 17],
@@ -12,7 +12,24 @@ impl File {
         std::fs::read(path).ok().and_then(Self::new)
     }
 
+    /// Parses a metadata file that owns its bytes.
     pub fn new(bytes: Vec<u8>) -> Option<Self> {
+        Self::parse(Bytes::Owned(bytes))
+    }
+
+    /// Parses a metadata file that borrows static bytes, such as those embedded with `include_bytes!`, without
+    /// copying them.
+    pub fn from_static(bytes: &'static [u8]) -> Option<Self> {
+        Self::parse(Bytes::Static(bytes))
+    }
+
+    /// Parses a metadata file from any source of bytes, such as a memory-mapped file, without copying them. The
+    /// source is kept alive for as long as the file.
+    pub fn from_source<T: AsRef<[u8]> + Send + Sync + 'static>(source: T) -> Option<Self> {
+        Self::parse(Bytes::Source(Box::new(source)))
+    }
+
+    fn parse(bytes: Bytes) -> Option<Self> {
         let mut result = File {
             bytes,
             strings: 0,
@@ -20,7 +37,7 @@ impl File {
             tables: Default::default(),
         };
 
-        let dos = result.bytes.view_as::<IMAGE_DOS_HEADER>(0)?;
+        let dos = result.bytes.copy_as::<IMAGE_DOS_HEADER>(0)?;
 
         if dos.e_magic != IMAGE_DOS_SIGNATURE
             || result.bytes.copy_as::<u32>(dos.e_lfanew as usize)? != IMAGE_NT_SIGNATURE
@@ -29,7 +46,7 @@ impl File {
         }
 
         let file_offset = dos.e_lfanew as usize + size_of::<u32>();
-        let file = result.bytes.view_as::<IMAGE_FILE_HEADER>(file_offset)?;
+        let file = result.bytes.copy_as::<IMAGE_FILE_HEADER>(file_offset)?;
 
         let optional_offset = file_offset + size_of::<IMAGE_FILE_HEADER>();
 
@@ -37,11 +54,11 @@ impl File {
             IMAGE_NT_OPTIONAL_HDR32_MAGIC => {
                 let optional = result
                     .bytes
-                    .view_as::<IMAGE_OPTIONAL_HEADER32>(optional_offset)?;
+                    .copy_as::<IMAGE_OPTIONAL_HEADER32>(optional_offset)?;
                 (
                     optional.DataDirectory[IMAGE_DIRECTORY_ENTRY_COM_DESCRIPTOR as usize]
                         .VirtualAddress,
-                    result.bytes.view_as_slice_of::<IMAGE_SECTION_HEADER>(
+                    result.bytes.copy_as_vec_of::<IMAGE_SECTION_HEADER>(
                         optional_offset + size_of::<IMAGE_OPTIONAL_HEADER32>(),
                         file.NumberOfSections as usize,
                     )?,
@@ -50,11 +67,11 @@ impl File {
             IMAGE_NT_OPTIONAL_HDR64_MAGIC => {
                 let optional = result
                     .bytes
-                    .view_as::<IMAGE_OPTIONAL_HEADER64>(optional_offset)?;
+                    .copy_as::<IMAGE_OPTIONAL_HEADER64>(optional_offset)?;
                 (
                     optional.DataDirectory[IMAGE_DIRECTORY_ENTRY_COM_DESCRIPTOR as usize]
                         .VirtualAddress,
-                    result.bytes.view_as_slice_of::<IMAGE_SECTION_HEADER>(
+                    result.bytes.copy_as_vec_of::<IMAGE_SECTION_HEADER>(
                         optional_offset + size_of::<IMAGE_OPTIONAL_HEADER64>(),
                         file.NumberOfSections as usize,
                     )?,
@@ -63,8 +80,8 @@ impl File {
             _ => return None,
         };
 
-        let clr = result.bytes.view_as::<IMAGE_COR20_HEADER>(offset_from_rva(
-            section_from_rva(sections, com_virtual_address)?,
+        let clr = result.bytes.copy_as::<IMAGE_COR20_HEADER>(offset_from_rva(
+            section_from_rva(&sections, com_virtual_address)?,
             com_virtual_address,
         ))?;
 
@@ -73,10 +90,10 @@ impl File {
         }
 
         let metadata_offset = offset_from_rva(
-            section_from_rva(sections, clr.MetaData.VirtualAddress)?,
+            section_from_rva(&sections, clr.MetaData.VirtualAddress)?,
             clr.MetaData.VirtualAddress,
         );
-        let metadata = result.bytes.view_as::<METADATA_HEADER>(metadata_offset)?;
+        let metadata = result.bytes.copy_as::<METADATA_HEADER>(metadata_offset)?;
 
         if metadata.signature != METADATA_SIGNATURE {
             return None;
@@ -492,7 +509,12 @@ impl File {
     }
 
     pub(crate) fn str(&self, row: usize, table: usize, column: usize) -> &str {
-        let offset = self.strings + self.usize(row, table, column);
+        self.heap_str(self.usize(row, table, column))
+    }
+
+    // Returns the string at the given index into the `#Strings` heap.
+    pub(crate) fn heap_str(&self, index: usize) -> &str {
+        let offset = self.strings + index;
         let bytes = &self.bytes[offset..];
         let nul_pos = bytes
             .iter()
@@ -626,6 +648,10 @@ impl File {
         first
     }
 
+    pub(crate) fn len(&self, table: usize) -> usize {
+        self.tables[table].len
+    }
+
     pub(crate) fn TypeDef(&self) -> std::ops::Range<usize> {
         0..self.tables[TypeDef::TABLE].len
     }
@@ -645,29 +671,34 @@ fn offset_from_rva(section: &IMAGE_SECTION_HEADER, rva: u32) -> usize {
     (rva - section.VirtualAddress + section.PointerToRawData) as usize
 }
 
+// The bytes backing a `File`. Reads are unaligned so that borrowed bytes, which may have any alignment, can be
+// parsed in place.
+enum Bytes {
+    Owned(Vec<u8>),
+    Static(&'static [u8]),
+    Source(Box<dyn AsRef<[u8]> + Send + Sync>),
+}
+
+impl std::ops::Deref for Bytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Self::Owned(bytes) => bytes,
+            Self::Static(bytes) => bytes,
+            Self::Source(source) => (**source).as_ref(),
+        }
+    }
+}
+
 trait View {
-    fn view_as<T>(&self, offset: usize) -> Option<&T>;
-    fn view_as_slice_of<T>(&self, offset: usize, len: usize) -> Option<&[T]>;
     fn copy_as<T: Copy>(&self, offset: usize) -> Option<T>;
+    fn copy_as_vec_of<T: Copy>(&self, offset: usize, len: usize) -> Option<Vec<T>>;
     fn view_as_str(&self, offset: usize) -> Option<&[u8]>;
     fn is_proper_length<T>(&self, offset: usize) -> Option<()>;
-    fn is_proper_length_and_alignment<T>(&self, offset: usize, count: usize) -> Option<*const T>;
 }
 
 impl View for [u8] {
-    fn view_as<T>(&self, offset: usize) -> Option<&T> {
-        unsafe { Some(&*self.is_proper_length_and_alignment(offset, 1)?) }
-    }
-
-    fn view_as_slice_of<T>(&self, offset: usize, len: usize) -> Option<&[T]> {
-        unsafe {
-            Some(std::slice::from_raw_parts(
-                self.is_proper_length_and_alignment(offset, len)?,
-                len,
-            ))
-        }
-    }
-
     fn copy_as<T>(&self, offset: usize) -> Option<T> {
         self.is_proper_length::<T>(offset)?;
 
@@ -682,6 +713,12 @@ impl View for [u8] {
         }
     }
 
+    fn copy_as_vec_of<T: Copy>(&self, offset: usize, len: usize) -> Option<Vec<T>> {
+        (0..len)
+            .map(|pos| self.copy_as(offset + pos * size_of::<T>()))
+            .collect()
+    }
+
     fn view_as_str(&self, offset: usize) -> Option<&[u8]> {
         let buffer = &self[offset..];
         let pos = buffer.iter().position(|c| *c == b'\0')?;
@@ -695,17 +732,6 @@ impl View for [u8] {
             None
         }
     }
-
-    fn is_proper_length_and_alignment<T>(&self, offset: usize, count: usize) -> Option<*const T> {
-        self.is_proper_length::<T>(offset * count)?;
-        let ptr = &self[offset] as *const u8 as *const T;
-
-        if ptr.align_offset(align_of::<T>()) == 0 {
-            Some(ptr)
-        } else {
-            None
-        }
-    }
 }
 
 #[derive(Default)]
@@ -767,7 +793,7 @@ impl Column {
 }
 
 #[repr(C)]
-#[derive(Default)]
+#[derive(Clone, Copy, Default)]
 struct METADATA_HEADER {
     signature: u32,
     major_version: u16,
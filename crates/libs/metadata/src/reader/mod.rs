@@ -5,6 +5,7 @@ mod codes;
 mod file;
 mod item_index;
 mod row;
+mod runtime_signature;
 mod tables;
 mod type_category;
 mod type_index;
@@ -18,7 +19,7 @@ pub use tables::*;
 pub use type_category::*;
 pub use type_index::*;
 
-fn trim_tick(name: &str) -> &str {
+pub(crate) fn trim_tick(name: &str) -> &str {
     if name.as_bytes().iter().rev().nth(1) == Some(&b'`') {
         &name[..name.len() - 2]
     } else {
@@ -160,6 +160,7 @@ pub trait HasAttributes<'a> {
     fn attributes(&self) -> RowIterator<'a, Attribute<'a>>;
     fn find_attribute(&self, name: &str) -> Option<Attribute<'a>>;
     fn has_attribute(&self, name: &str) -> bool;
+    fn guid_attribute(&self) -> Option<GUID>;
 }
 
 impl<'a, R: AsRow<'a> + Into<HasAttribute<'a>>> HasAttributes<'a> for R {
@@ -175,4 +176,9 @@ impl<'a, R: AsRow<'a> + Into<HasAttribute<'a>>> HasAttributes<'a> for R {
     fn has_attribute(&self, name: &str) -> bool {
         self.find_attribute(name).is_some()
     }
+
+    fn guid_attribute(&self) -> Option<GUID> {
+        self.find_attribute("GuidAttribute")
+            .and_then(|attribute| GUID::from_args(&attribute.value()))
+    }
 }
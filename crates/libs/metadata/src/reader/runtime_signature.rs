@@ -0,0 +1,120 @@
+use super::*;
+
+impl TypeIndex {
+    /// Returns the WinRT type signature for the given type, as used to derive the IIDs of parameterized types.
+    ///
+    /// Returns `None` if the type, or any type it depends on, is not a WinRT type or cannot be found in the index.
+    pub fn runtime_signature(&self, ty: &Type) -> Option<String> {
+        match ty {
+            Type::Bool => Some("b1".to_string()),
+            Type::Char => Some("c2".to_string()),
+            Type::I8 => Some("i1".to_string()),
+            Type::U8 => Some("u1".to_string()),
+            Type::I16 => Some("i2".to_string()),
+            Type::U16 => Some("u2".to_string()),
+            Type::I32 => Some("i4".to_string()),
+            Type::U32 => Some("u4".to_string()),
+            Type::I64 => Some("i8".to_string()),
+            Type::U64 => Some("u8".to_string()),
+            Type::F32 => Some("f4".to_string()),
+            Type::F64 => Some("f8".to_string()),
+            Type::String => Some("string".to_string()),
+            Type::Object => Some("cinterface(IInspectable)".to_string()),
+            Type::Name(name) => self.type_name_signature(name),
+            _ => None,
+        }
+    }
+
+    /// Returns the IID of the given interface or delegate type, including instances of parameterized types such
+    /// as `IVector<Windows.Foundation.Uri>`. For a runtime class, this is the IID of its default interface.
+    pub fn runtime_iid(&self, ty: &Type) -> Option<GUID> {
+        let Type::Name(name) = ty else {
+            return None;
+        };
+
+        let def = self.get(&name.namespace, trim_tick(&name.name)).next()?;
+
+        match def.category() {
+            TypeCategory::Interface | TypeCategory::Delegate => {
+                if name.generics.is_empty() {
+                    def.guid_attribute()
+                } else {
+                    Some(GUID::from_signature(&self.type_name_signature(name)?))
+                }
+            }
+            TypeCategory::Class => self.runtime_iid(&default_interface(def)?),
+            _ => None,
+        }
+    }
+
+    fn type_name_signature(&self, name: &TypeName) -> Option<String> {
+        if name.namespace == "System" && name.name == "Guid" {
+            return Some("g16".to_string());
+        }
+
+        let def = self.get(&name.namespace, trim_tick(&name.name)).next()?;
+
+        if !def.flags().contains(TypeAttributes::WindowsRuntime) {
+            return None;
+        }
+
+        let full_name = format!("{}.{}", name.namespace, trim_tick(&name.name));
+
+        match def.category() {
+            TypeCategory::Interface | TypeCategory::Delegate => {
+                let guid = def.guid_attribute()?.to_signature();
+
+                if name.generics.is_empty() {
+                    if def.category() == TypeCategory::Delegate {
+                        Some(format!("delegate({guid})"))
+                    } else {
+                        Some(guid)
+                    }
+                } else {
+                    let mut signature = format!("pinterface({guid}");
+
+                    for ty in &name.generics {
+                        signature.push(';');
+                        signature.push_str(&self.runtime_signature(ty)?);
+                    }
+
+                    signature.push(')');
+                    Some(signature)
+                }
+            }
+            TypeCategory::Class => Some(format!(
+                "rc({full_name};{})",
+                self.runtime_signature(&default_interface(def)?)?
+            )),
+            TypeCategory::Enum => {
+                let underlying = def
+                    .fields()
+                    .find(|field| !field.flags().contains(FieldAttributes::Literal))?
+                    .ty();
+
+                Some(format!(
+                    "enum({full_name};{})",
+                    self.runtime_signature(&underlying)?
+                ))
+            }
+            TypeCategory::Struct => {
+                let mut signature = format!("struct({full_name}");
+
+                for field in def.fields() {
+                    signature.push(';');
+                    signature.push_str(&self.runtime_signature(&field.ty())?);
+                }
+
+                signature.push(')');
+                Some(signature)
+            }
+            TypeCategory::Attribute => None,
+        }
+    }
+}
+
+fn default_interface(def: TypeDef) -> Option<Type> {
+    def.interface_impls()
+        .find(|map| map.has_attribute("DefaultAttribute"))
+        .map(|map| map.interface(&[]))
+}
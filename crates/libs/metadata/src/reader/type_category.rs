@@ -1,4 +1,4 @@
-#[derive(PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum TypeCategory {
     Interface,
     Class,
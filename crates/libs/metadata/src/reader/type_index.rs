@@ -1,9 +1,20 @@
 use super::*;
+use std::sync::OnceLock;
 
 pub struct TypeIndex {
     files: Vec<File>,
-    types: HashMap<String, HashMap<String, Vec<(usize, usize)>>>,
-    nested: HashMap<(usize, usize), Vec<usize>>,
+    namespaces: OnceLock<HashMap<String, Namespace>>,
+    nested: OnceLock<HashMap<(usize, usize), Vec<usize>>>,
+}
+
+// The type definitions within a namespace. The first query of the index walks every `TypeDef` row to group them by
+// namespace, but only decodes each distinct namespace string once per file. The name lookup for a namespace, which
+// decodes the name of each of its types, is only built once a type within that namespace is requested, as most
+// callers only need a small fraction of the available namespaces.
+#[derive(Default)]
+struct Namespace {
+    defs: Vec<(usize, usize)>,
+    types: OnceLock<HashMap<String, Vec<(usize, usize)>>>,
 }
 
 impl TypeIndex {
@@ -11,52 +22,89 @@ impl TypeIndex {
         Some(Self::new(vec![File::read(path)?]))
     }
 
+    /// Creates an index over the given files. No tables are walked until the index is first queried. The first
+    /// query groups every type by namespace, while the names of the types within a namespace are only indexed once
+    /// that namespace is first queried and nested types are only indexed once they are first requested.
     pub fn new(files: Vec<File>) -> Self {
-        let mut types: HashMap<String, HashMap<String, Vec<(usize, usize)>>> = HashMap::new();
-        let mut nested: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+        Self {
+            files,
+            namespaces: OnceLock::new(),
+            nested: OnceLock::new(),
+        }
+    }
+
+    fn namespaces(&self) -> &HashMap<String, Namespace> {
+        self.namespaces.get_or_init(|| {
+            let mut namespaces: HashMap<String, Namespace> = HashMap::new();
 
-        for (file_pos, file) in files.iter().enumerate() {
-            for def_pos in file.TypeDef() {
-                let namespace = file.str(def_pos, TypeDef::TABLE, 2);
+            for (file_pos, file) in self.files.iter().enumerate() {
+                // Group by string heap index so that each namespace string is only decoded once per file.
+                let mut indexes: HashMap<usize, Vec<(usize, usize)>> = HashMap::new();
 
-                if namespace.is_empty() {
-                    // Skips `<Module>` as well as nested types.
-                    continue;
+                for def_pos in file.TypeDef() {
+                    indexes
+                        .entry(file.usize(def_pos, TypeDef::TABLE, 2))
+                        .or_default()
+                        .push((file_pos, def_pos));
                 }
 
-                let name = file.str(def_pos, TypeDef::TABLE, 1);
+                for (index, defs) in indexes {
+                    let namespace = file.heap_str(index);
+
+                    if namespace.is_empty() {
+                        // Skips `<Module>` as well as nested types.
+                        continue;
+                    }
+
+                    namespaces
+                        .entry(namespace.to_string())
+                        .or_default()
+                        .defs
+                        .extend(defs);
+                }
+            }
+
+            for namespace in namespaces.values_mut() {
+                namespace.defs.sort_unstable();
+            }
+
+            namespaces
+        })
+    }
+
+    fn namespace_types<'a>(
+        &'a self,
+        namespace: &'a Namespace,
+    ) -> &'a HashMap<String, Vec<(usize, usize)>> {
+        namespace.types.get_or_init(|| {
+            let mut types: HashMap<String, Vec<(usize, usize)>> = HashMap::new();
+
+            for (file, pos) in &namespace.defs {
+                let name = self.files[*file].str(*pos, TypeDef::TABLE, 1);
 
                 types
-                    .entry(namespace.to_string())
-                    .or_default()
                     .entry(trim_tick(name).to_string())
                     .or_default()
-                    .push((file_pos, def_pos));
+                    .push((*file, *pos));
             }
 
-            for map in file.NestedClass() {
-                let inner = file.usize(map, NestedClass::TABLE, 0) - 1;
-                let outer = file.usize(map, NestedClass::TABLE, 1) - 1;
-                nested.entry((file_pos, outer)).or_default().push(inner);
-            }
-        }
-
-        Self {
-            files,
-            types,
-            nested,
-        }
+            types
+        })
     }
 
     pub(crate) fn files(&self, pos: usize) -> &File {
         &self.files[pos]
     }
 
+    pub(crate) fn file_count(&self) -> usize {
+        self.files.len()
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = (&str, &str, TypeDef<'_>)> + '_ {
-        self.types
+        self.namespaces()
             .iter()
             .flat_map(|(namespace, types)| {
-                types
+                self.namespace_types(types)
                     .iter()
                     .map(move |(name, types)| (namespace.as_str(), name.as_str(), types))
             })
@@ -67,17 +115,16 @@ impl TypeIndex {
     }
 
     pub fn types(&self) -> impl Iterator<Item = TypeDef<'_>> + '_ {
-        self.types
+        self.namespaces()
             .values()
-            .flat_map(|types| types.values())
-            .flatten()
+            .flat_map(|namespace| namespace.defs.iter())
             .map(|(file, pos)| TypeDef(Row::new(self, *file, *pos)))
     }
 
     pub fn get(&self, namespace: &str, name: &str) -> impl Iterator<Item = TypeDef<'_>> + '_ {
-        self.types
+        self.namespaces()
             .get(namespace)
-            .and_then(|types| types.get(name))
+            .and_then(|types| self.namespace_types(types).get(name))
             .into_iter()
             .flatten()
             .map(|(file, pos)| TypeDef(Row::new(self, *file, *pos)))
@@ -99,7 +146,7 @@ impl TypeIndex {
     }
 
     pub fn nested(&self, ty: TypeDef) -> impl Iterator<Item = TypeDef<'_>> + '_ {
-        self.nested
+        self.nested_map()
             .get(&(ty.0.file, ty.0.pos))
             .into_iter()
             .flatten()
@@ -112,4 +159,20 @@ impl TypeIndex {
                 })
             })
     }
+
+    fn nested_map(&self) -> &HashMap<(usize, usize), Vec<usize>> {
+        self.nested.get_or_init(|| {
+            let mut nested: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+
+            for (file_pos, file) in self.files.iter().enumerate() {
+                for map in file.NestedClass() {
+                    let inner = file.usize(map, NestedClass::TABLE, 0) - 1;
+                    let outer = file.usize(map, NestedClass::TABLE, 1) - 1;
+                    nested.entry((file_pos, outer)).or_default().push(inner);
+                }
+            }
+
+            nested
+        })
+    }
 }
@@ -1,7 +1,8 @@
 use super::*;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Default)]
 pub enum Type {
+    #[default]
     Void,
     Bool,
     Char,
@@ -55,3 +56,36 @@ impl Type {
         }
     }
 }
+
+impl std::fmt::Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Void => write!(f, "void"),
+            Self::Bool => write!(f, "bool"),
+            Self::Char => write!(f, "char"),
+            Self::I8 => write!(f, "i8"),
+            Self::U8 => write!(f, "u8"),
+            Self::I16 => write!(f, "i16"),
+            Self::U16 => write!(f, "u16"),
+            Self::I32 => write!(f, "i32"),
+            Self::U32 => write!(f, "u32"),
+            Self::I64 => write!(f, "i64"),
+            Self::U64 => write!(f, "u64"),
+            Self::F32 => write!(f, "f32"),
+            Self::F64 => write!(f, "f64"),
+            Self::ISize => write!(f, "isize"),
+            Self::USize => write!(f, "usize"),
+            Self::String => write!(f, "string"),
+            Self::Object => write!(f, "object"),
+            Self::AttributeEnum => write!(f, "enum"),
+            Self::Name(name) => write!(f, "{name}"),
+            Self::Array(ty) => write!(f, "[{ty}]"),
+            Self::ArrayRef(ty) => write!(f, "&mut [{ty}]"),
+            Self::ConstRef(ty) => write!(f, "&{ty}"),
+            Self::Generic(number) => write!(f, "!{number}"),
+            Self::PtrMut(ty, pointers) => write!(f, "{}{ty}", "*mut ".repeat(*pointers)),
+            Self::PtrConst(ty, pointers) => write!(f, "{}{ty}", "*const ".repeat(*pointers)),
+            Self::ArrayFixed(ty, len) => write!(f, "[{ty}; {len}]"),
+        }
+    }
+}
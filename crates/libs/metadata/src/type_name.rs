@@ -16,3 +16,29 @@ impl TypeName {
         }
     }
 }
+
+impl std::fmt::Display for TypeName {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.namespace.is_empty() {
+            write!(f, "{}", self.name)?;
+        } else {
+            write!(f, "{}.{}", self.namespace, self.name)?;
+        }
+
+        if !self.generics.is_empty() {
+            write!(f, "<")?;
+
+            for (pos, ty) in self.generics.iter().enumerate() {
+                if pos > 0 {
+                    write!(f, ", ")?;
+                }
+
+                write!(f, "{ty}")?;
+            }
+
+            write!(f, ">")?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,463 @@
+use super::*;
+
+/// Indicates whether a [`Diagnostic`] describes metadata that consumers will reject or merely metadata that is unusual.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "json",
+    derive(serde::Serialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// A problem found by [`validate`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub location: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: {}: {}", self.severity, self.location, self.message)
+    }
+}
+
+/// Checks the metadata for structural problems that other metadata consumers are likely to reject.
+///
+/// This covers ECMA-335 invariants, such as the sort order of tables that are searched with a binary search,
+/// as well as the rules of the WinRT type system, such as requiring a `GuidAttribute` on every WinRT interface.
+/// This is primarily useful for validating metadata produced with the [`writer`] before it is shipped.
+pub fn validate(index: &reader::TypeIndex) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+
+    for file in 0..index.file_count() {
+        validate_tables(&mut diagnostics, index, file);
+
+        // The first row is always the `<Module>` type.
+        for pos in index.files(file).TypeDef().skip(1) {
+            let def = reader::TypeDef(reader::Row::new(index, file, pos));
+            validate_type(&mut diagnostics, index, def);
+        }
+    }
+
+    diagnostics
+}
+
+fn error(diagnostics: &mut Vec<Diagnostic>, location: String, message: String) {
+    diagnostics.push(Diagnostic {
+        severity: Severity::Error,
+        location,
+        message,
+    });
+}
+
+fn warning(diagnostics: &mut Vec<Diagnostic>, location: String, message: String) {
+    diagnostics.push(Diagnostic {
+        severity: Severity::Warning,
+        location,
+        message,
+    });
+}
+
+fn validate_tables(diagnostics: &mut Vec<Diagnostic>, index: &reader::TypeIndex, file: usize) {
+    use reader::AsRow;
+
+    // These tables must be sorted by the given column so that readers can find rows with a binary search.
+    let sorted = [
+        ("ClassLayout", reader::ClassLayout::TABLE, 2),
+        ("Constant", reader::Constant::TABLE, 1),
+        ("CustomAttribute", reader::Attribute::TABLE, 0),
+        ("GenericParam", reader::GenericParam::TABLE, 2),
+        ("ImplMap", reader::ImplMap::TABLE, 1),
+        ("InterfaceImpl", reader::InterfaceImpl::TABLE, 0),
+        ("NestedClass", reader::NestedClass::TABLE, 0),
+    ];
+
+    let bytes = index.files(file);
+
+    for (name, table, column) in sorted {
+        let len = bytes.len(table);
+
+        if let Some(row) = (1..len)
+            .find(|row| bytes.usize(row - 1, table, column) > bytes.usize(*row, table, column))
+        {
+            error(
+                diagnostics,
+                format!("file {file}: {name} table"),
+                format!("row {row} is out of order"),
+            );
+        }
+    }
+
+    // The field and method lists of each type must be contiguous runs that follow on from the previous type.
+    for (name, column) in [("field", 4), ("method", 5)] {
+        let table = reader::TypeDef::TABLE;
+
+        if let Some(row) = (1..bytes.len(table))
+            .find(|row| bytes.usize(row - 1, table, column) > bytes.usize(*row, table, column))
+        {
+            error(
+                diagnostics,
+                format!("file {file}: TypeDef table"),
+                format!("{name} list of row {row} precedes that of the previous row"),
+            );
+        }
+    }
+}
+
+fn validate_type(
+    diagnostics: &mut Vec<Diagnostic>,
+    index: &reader::TypeIndex,
+    def: reader::TypeDef,
+) {
+    let location = type_location(def);
+    let flags = def.flags();
+
+    if flags.is_nested() {
+        if !def.namespace().is_empty() {
+            error(
+                diagnostics,
+                location.clone(),
+                "nested type must not have a namespace".to_string(),
+            );
+        }
+    } else if def.namespace().is_empty() {
+        error(
+            diagnostics,
+            location.clone(),
+            "type must have a namespace".to_string(),
+        );
+    }
+
+    let params = def.generic_params().count();
+    let arity = def
+        .name()
+        .rsplit_once('`')
+        .and_then(|(_, arity)| arity.parse::<usize>().ok());
+
+    match arity {
+        Some(arity) if arity != params => error(
+            diagnostics,
+            location.clone(),
+            format!("name implies {arity} generic parameters but {params} are defined"),
+        ),
+        None if params > 0 && flags.contains(TypeAttributes::WindowsRuntime) => error(
+            diagnostics,
+            location.clone(),
+            format!("generic type with {params} parameters must have a name ending in `{params}"),
+        ),
+        _ => {}
+    }
+
+    let generics: Vec<_> = def
+        .generic_params()
+        .map(|param| Type::Generic(param.sequence()))
+        .collect();
+
+    for field in def.fields() {
+        validate_type_ref(
+            diagnostics,
+            index,
+            &format!("{location}::{}", field.name()),
+            &field.ty(),
+        );
+    }
+
+    for method in def.methods() {
+        let method_location = format!("{location}::{}", method.name());
+        let signature = method.signature(&generics);
+
+        validate_type_ref(diagnostics, index, &method_location, &signature.return_type);
+
+        for ty in &signature.types {
+            validate_type_ref(diagnostics, index, &method_location, ty);
+        }
+
+        let mut previous = None;
+
+        for param in method.params() {
+            let sequence = param.sequence() as usize;
+
+            if sequence > signature.types.len() {
+                error(
+                    diagnostics,
+                    method_location.clone(),
+                    format!(
+                        "parameter `{}` has sequence {sequence} but the signature only has {} parameters",
+                        param.name(),
+                        signature.types.len()
+                    ),
+                );
+            }
+
+            if previous.is_some_and(|previous| previous >= sequence) {
+                error(
+                    diagnostics,
+                    method_location.clone(),
+                    format!("parameter `{}` is out of sequence", param.name()),
+                );
+            }
+
+            previous = Some(sequence);
+        }
+    }
+
+    for map in def.interface_impls() {
+        validate_type_ref(diagnostics, index, &location, &map.interface(&generics));
+    }
+
+    if flags.contains(TypeAttributes::WindowsRuntime) {
+        validate_winrt_type(diagnostics, index, def, &location, &generics);
+    } else if def.category() == reader::TypeCategory::Enum {
+        validate_enum(diagnostics, def, &location);
+    }
+}
+
+fn validate_winrt_type(
+    diagnostics: &mut Vec<Diagnostic>,
+    index: &reader::TypeIndex,
+    def: reader::TypeDef,
+    location: &str,
+    generics: &[Type],
+) {
+    match def.category() {
+        reader::TypeCategory::Interface => {
+            if def.guid_attribute().is_none() {
+                error(
+                    diagnostics,
+                    location.to_string(),
+                    "interface must have a `GuidAttribute`".to_string(),
+                );
+            }
+
+            for method in def.methods() {
+                if !method
+                    .flags()
+                    .contains(MethodAttributes::Abstract | MethodAttributes::Virtual)
+                {
+                    error(
+                        diagnostics,
+                        format!("{location}::{}", method.name()),
+                        "interface method must be abstract and virtual".to_string(),
+                    );
+                }
+            }
+
+            if let Some(attribute) = def.find_attribute("ExclusiveToAttribute") {
+                validate_exclusive_to(diagnostics, index, def, location, attribute);
+            }
+        }
+        reader::TypeCategory::Delegate => {
+            if def.guid_attribute().is_none() {
+                error(
+                    diagnostics,
+                    location.to_string(),
+                    "delegate must have a `GuidAttribute`".to_string(),
+                );
+            }
+
+            if !def.methods().any(|method| method.name() == "Invoke") {
+                error(
+                    diagnostics,
+                    location.to_string(),
+                    "delegate must have an `Invoke` method".to_string(),
+                );
+            }
+        }
+        reader::TypeCategory::Enum => {
+            if let Some(ty) = validate_enum(diagnostics, def, location) {
+                if !matches!(ty, Type::I32 | Type::U32) {
+                    error(
+                        diagnostics,
+                        location.to_string(),
+                        format!("enum underlying type must be `i32` or `u32` but is `{ty}`"),
+                    );
+                }
+            }
+        }
+        reader::TypeCategory::Class => {
+            let defaults: Vec<_> = def
+                .interface_impls()
+                .filter(|map| map.has_attribute("DefaultAttribute"))
+                .collect();
+
+            if defaults.len() > 1 {
+                error(
+                    diagnostics,
+                    location.to_string(),
+                    format!(
+                        "class has {} default interfaces but at most one is allowed",
+                        defaults.len()
+                    ),
+                );
+            }
+
+            for map in defaults {
+                let Type::Name(name) = map.interface(generics) else {
+                    continue;
+                };
+
+                if let Some(interface) = resolve(index, &name) {
+                    if !interface.has_attribute("ExclusiveToAttribute") {
+                        warning(
+                            diagnostics,
+                            location.to_string(),
+                            format!("default interface `{name}` is not exclusive to the class"),
+                        );
+                    }
+                }
+            }
+        }
+        reader::TypeCategory::Struct | reader::TypeCategory::Attribute => {}
+    }
+}
+
+fn validate_enum(
+    diagnostics: &mut Vec<Diagnostic>,
+    def: reader::TypeDef,
+    location: &str,
+) -> Option<Type> {
+    let underlying = def
+        .fields()
+        .find(|field| !field.flags().contains(FieldAttributes::Literal))
+        .map(|field| field.ty());
+
+    if underlying.is_none() {
+        error(
+            diagnostics,
+            location.to_string(),
+            "enum must have a `value__` field".to_string(),
+        );
+    }
+
+    underlying
+}
+
+fn validate_exclusive_to(
+    diagnostics: &mut Vec<Diagnostic>,
+    index: &reader::TypeIndex,
+    def: reader::TypeDef,
+    location: &str,
+    attribute: reader::Attribute,
+) {
+    let Some((_, Value::Utf8(class_name))) = attribute.value().into_iter().next() else {
+        error(
+            diagnostics,
+            location.to_string(),
+            "`ExclusiveToAttribute` must name a class".to_string(),
+        );
+        return;
+    };
+
+    let Some((namespace, name)) = class_name.rsplit_once('.') else {
+        error(
+            diagnostics,
+            location.to_string(),
+            format!("`ExclusiveToAttribute` refers to `{class_name}` which is not a full name"),
+        );
+        return;
+    };
+
+    let Some(class) = index.get(namespace, name).next() else {
+        warning(
+            diagnostics,
+            location.to_string(),
+            format!("`ExclusiveToAttribute` refers to `{class_name}` which was not found"),
+        );
+        return;
+    };
+
+    let implemented = class.interface_impls().any(|map| {
+        matches!(map.interface(&[]), Type::Name(name) if name.namespace == def.namespace() && name.name == def.name())
+    });
+
+    // Static and factory interfaces are not implemented by the class but are instead named by its attributes.
+    let full_name = format!("{}.{}", def.namespace(), def.name());
+
+    let factory = class.attributes().any(|attribute| {
+        matches!(
+            attribute.ctor().parent().name(),
+            "StaticAttribute" | "ActivatableAttribute" | "ComposableAttribute"
+        ) && matches!(attribute.value().first(), Some((_, Value::Utf8(name))) if *name == full_name)
+    });
+
+    if !implemented && !factory {
+        error(
+            diagnostics,
+            location.to_string(),
+            format!("interface is exclusive to `{class_name}` which does not implement it"),
+        );
+    }
+}
+
+// Checks that references to generic types provide the number of type arguments that the type definition expects.
+fn validate_type_ref(
+    diagnostics: &mut Vec<Diagnostic>,
+    index: &reader::TypeIndex,
+    location: &str,
+    ty: &Type,
+) {
+    match ty {
+        Type::Name(name) => {
+            if let Some(def) = resolve(index, name) {
+                let params = def.generic_params().count();
+
+                if params != name.generics.len() {
+                    error(
+                        diagnostics,
+                        location.to_string(),
+                        format!(
+                            "`{name}` has {} type arguments but `{}.{}` expects {params}",
+                            name.generics.len(),
+                            def.namespace(),
+                            def.name()
+                        ),
+                    );
+                }
+            }
+
+            for ty in &name.generics {
+                validate_type_ref(diagnostics, index, location, ty);
+            }
+        }
+        Type::Array(ty)
+        | Type::ArrayRef(ty)
+        | Type::ConstRef(ty)
+        | Type::PtrMut(ty, _)
+        | Type::PtrConst(ty, _)
+        | Type::ArrayFixed(ty, _) => validate_type_ref(diagnostics, index, location, ty),
+        _ => {}
+    }
+}
+
+fn resolve<'a>(index: &'a reader::TypeIndex, name: &TypeName) -> Option<reader::TypeDef<'a>> {
+    if name.namespace.is_empty() {
+        return None;
+    }
+
+    index
+        .get(&name.namespace, reader::trim_tick(&name.name))
+        .next()
+}
+
+fn type_location(def: reader::TypeDef) -> String {
+    if def.namespace().is_empty() {
+        def.name().to_string()
+    } else {
+        format!("{}.{}", def.namespace(), def.name())
+    }
+}
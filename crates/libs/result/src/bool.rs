@@ -15,6 +15,7 @@ impl BOOL {
 
     /// Converts the [`BOOL`] to [`Result<()>`][Result<_>].
     #[inline]
+    #[cfg_attr(feature = "origin", track_caller)]
     pub fn ok(self) -> Result<()> {
         if self.as_bool() {
             Ok(())
@@ -0,0 +1,48 @@
+use super::*;
+
+/// Adds context to the errors returned by fallible functions.
+///
+/// The error code is preserved so that it still describes the failure when the error crosses a
+/// COM or WinRT boundary.
+///
+/// ```
+/// use windows_result::*;
+///
+/// const E_ACCESSDENIED: HRESULT = HRESULT(0x80070005_u32 as _);
+///
+/// fn open_key(name: &str) -> Result<()> {
+///     E_ACCESSDENIED.ok().with_context(|| format!("opening {name}"))
+/// }
+///
+/// let error = open_key("config").context("loading settings").unwrap_err();
+/// assert_eq!(error.code(), E_ACCESSDENIED);
+/// assert!(error.message().starts_with("loading settings: opening config: "));
+/// ```
+pub trait ResultExt<T> {
+    /// Adds context to the error, if any.
+    fn context<C: core::fmt::Display>(self, context: C) -> Result<T>;
+
+    /// Adds context to the error, if any, where the context is only evaluated on failure.
+    fn with_context<C: core::fmt::Display, F: FnOnce() -> C>(self, context: F) -> Result<T>;
+}
+
+impl<T, E> ResultExt<T> for core::result::Result<T, E>
+where
+    Error: From<E>,
+{
+    #[cfg_attr(feature = "origin", track_caller)]
+    fn context<C: core::fmt::Display>(self, context: C) -> Result<T> {
+        match self {
+            Ok(value) => Ok(value),
+            Err(error) => Err(Error::from(error).context(context)),
+        }
+    }
+
+    #[cfg_attr(feature = "origin", track_caller)]
+    fn with_context<C: core::fmt::Display, F: FnOnce() -> C>(self, context: F) -> Result<T> {
+        match self {
+            Ok(value) => Ok(value),
+            Err(error) => Err(Error::from(error).context(context())),
+        }
+    }
+}
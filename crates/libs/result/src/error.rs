@@ -31,7 +31,10 @@ use core::mem::size_of;
 ///   significantly reduces code size for codebase that make extensive use of [`Error`].
 ///
 /// Of course, these benefits come with a cost; you lose extended error information for those
-/// COM objects that support it.
+/// COM objects that support it. Since there is nowhere to store them, this also applies on every
+/// target to the messages passed to [`Error::new`], the context added with [`Error::context`], and
+/// the sources provided with `Error::with_source`, so [`Error::message`] always falls back to the
+/// description of the error code.
 ///
 /// This is controlled by a `--cfg` option rather than a Cargo feature because this compilation
 /// option sets a policy that applies to an entire graph of crates. Individual crates that take a
@@ -52,6 +55,10 @@ pub struct Error {
 
     /// Contains details about the error, such as error text.
     info: ErrorInfo,
+
+    /// The point at which the error was created, if it is a failure.
+    #[cfg(feature = "origin")]
+    origin: Option<alloc::sync::Arc<Origin>>,
 }
 
 /// We remap S_OK to this error because the S_OK representation (zero) is reserved for niche
@@ -83,38 +90,84 @@ impl Error {
         Self {
             code: S_EMPTY_ERROR,
             info: ErrorInfo::empty(),
+            #[cfg(feature = "origin")]
+            origin: None,
         }
     }
 
     /// Creates a new error object, capturing the stack and other information about the
     /// point of failure.
+    #[cfg_attr(feature = "origin", track_caller)]
     pub fn new<T: AsRef<str>>(code: HRESULT, message: T) -> Self {
-        #[cfg(windows)]
-        {
-            let message: &str = message.as_ref();
-            if message.is_empty() {
-                Self::from_hresult(code)
-            } else {
-                ErrorInfo::originate_error(code, message);
-                code.into()
-            }
-        }
-        #[cfg(not(windows))]
-        {
-            let _ = message;
+        let message: &str = message.as_ref();
+        if message.is_empty() {
             Self::from_hresult(code)
+        } else {
+            Self::from_info(code, ErrorInfo::originate_error(code, message)).originate()
         }
     }
 
-    /// Creates a new error object with an error code, but without additional error information.
-    pub fn from_hresult(code: HRESULT) -> Self {
+    /// Creates a new error object with a message and the lower-level error that caused it.
+    ///
+    /// The source is returned by [`std::error::Error::source`].
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "origin", track_caller)]
+    pub fn with_source<T: AsRef<str>, E: std::error::Error + Send + Sync + 'static>(
+        code: HRESULT,
+        message: T,
+        source: E,
+    ) -> Self {
+        let message: &str = message.as_ref();
+        let mut error = Self::new(code, message);
+        error.info.set_context(Context {
+            message: if message.is_empty() {
+                code.message()
+            } else {
+                message.into()
+            },
+            source: alloc::boxed::Box::new(source),
+        });
+        error
+    }
+
+    /// Adds context to the error while keeping its error code.
+    ///
+    /// The context is prepended to the error's message, so that it is included when the error
+    /// is displayed and when it is converted back into an [`HRESULT`]. The original error is
+    /// returned by [`std::error::Error::source`]. The error keeps its original `Origin` if the
+    /// `origin` feature is enabled.
+    pub fn context<C: core::fmt::Display>(self, context: C) -> Self {
+        let code = self.code();
+        let message = alloc::format!("{context}: {}", self.message());
+
+        // The error information only carries the combined message across `HRESULT` conversions.
+        let mut info = ErrorInfo::originate_error(code, &message);
+
+        #[cfg(feature = "origin")]
+        let origin = self.origin.clone();
+
+        info.set_context(Context {
+            message,
+            #[cfg(feature = "std")]
+            source: alloc::boxed::Box::new(self),
+        });
+
         Self {
             code: nonzero_hresult(code),
-            info: ErrorInfo::empty(),
+            info,
+            #[cfg(feature = "origin")]
+            origin,
         }
     }
 
+    /// Creates a new error object with an error code, but without additional error information.
+    #[cfg_attr(feature = "origin", track_caller)]
+    pub fn from_hresult(code: HRESULT) -> Self {
+        Self::from_info(code, ErrorInfo::empty()).originate()
+    }
+
     /// Creates a new `Error` from the Win32 error code returned by `GetLastError()`.
+    #[cfg_attr(feature = "origin", track_caller)]
     pub fn from_thread() -> Self {
         Self::from_hresult(HRESULT::from_thread())
     }
@@ -130,6 +183,10 @@ impl Error {
 
     /// The error message describing the error.
     pub fn message(&self) -> String {
+        if let Some(context) = self.info.context() {
+            return context.message.clone();
+        }
+
         if let Some(message) = self.info.message() {
             return message;
         }
@@ -143,10 +200,51 @@ impl Error {
     pub fn as_ptr(&self) -> *mut core::ffi::c_void {
         self.info.as_ptr()
     }
+
+    /// The point at which the error was created.
+    ///
+    /// This is only recorded for failures and is preserved when context is added to the error.
+    /// The location is also included when the error is formatted with `{:#?}`.
+    #[cfg(feature = "origin")]
+    pub fn origin(&self) -> Option<&Origin> {
+        self.origin.as_deref()
+    }
+
+    fn from_info(code: HRESULT, info: ErrorInfo) -> Self {
+        Self {
+            code: nonzero_hresult(code),
+            info,
+            #[cfg(feature = "origin")]
+            origin: None,
+        }
+    }
+
+    // Records the caller as the origin of a failure and reports it to the failure callback.
+    #[cfg(feature = "origin")]
+    #[track_caller]
+    fn originate(mut self) -> Self {
+        if self.code().is_err() {
+            let location = core::panic::Location::caller();
+            self.origin = Some(alloc::sync::Arc::new(Origin::capture(location)));
+            origin::notify(&self, location);
+        }
+
+        self
+    }
+
+    #[cfg(not(feature = "origin"))]
+    #[inline(always)]
+    fn originate(self) -> Self {
+        self
+    }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.info.context()?.source.as_ref())
+    }
+}
 
 impl From<Error> for HRESULT {
     fn from(error: Error) -> Self {
@@ -157,11 +255,9 @@ impl From<Error> for HRESULT {
 }
 
 impl From<HRESULT> for Error {
+    #[cfg_attr(feature = "origin", track_caller)]
     fn from(code: HRESULT) -> Self {
-        Self {
-            code: nonzero_hresult(code),
-            info: ErrorInfo::from_thread(),
-        }
+        Self::from_info(code, ErrorInfo::from_thread()).originate()
     }
 }
 
@@ -174,39 +270,53 @@ impl From<Error> for std::io::Error {
 
 #[cfg(feature = "std")]
 impl From<std::io::Error> for Error {
+    #[cfg_attr(feature = "origin", track_caller)]
     fn from(from: std::io::Error) -> Self {
         match from.raw_os_error() {
-            Some(status) => WIN32_ERROR(status as u32).into(),
-            None => HRESULT(E_UNEXPECTED).into(),
+            Some(status) => Self::from(WIN32_ERROR(status as u32)),
+            None => Self::from(HRESULT(E_UNEXPECTED)),
         }
     }
 }
 
 impl From<alloc::string::FromUtf16Error> for Error {
+    #[cfg_attr(feature = "origin", track_caller)]
     fn from(_: alloc::string::FromUtf16Error) -> Self {
-        WIN32_ERROR(ERROR_NO_UNICODE_TRANSLATION).into()
+        Self::from(WIN32_ERROR(ERROR_NO_UNICODE_TRANSLATION))
     }
 }
 
 impl From<alloc::string::FromUtf8Error> for Error {
+    #[cfg_attr(feature = "origin", track_caller)]
     fn from(_: alloc::string::FromUtf8Error) -> Self {
-        WIN32_ERROR(ERROR_NO_UNICODE_TRANSLATION).into()
+        Self::from(WIN32_ERROR(ERROR_NO_UNICODE_TRANSLATION))
     }
 }
 
 impl From<core::num::TryFromIntError> for Error {
+    #[cfg_attr(feature = "origin", track_caller)]
     fn from(_: core::num::TryFromIntError) -> Self {
-        WIN32_ERROR(ERROR_INVALID_DATA).into()
+        Self::from(WIN32_ERROR(ERROR_INVALID_DATA))
     }
 }
 
 impl core::fmt::Debug for Error {
     fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        #[cfg(feature = "origin")]
+        let alternate = fmt.alternate();
         let mut debug = fmt.debug_struct("Error");
         debug
             .field("code", &self.code())
-            .field("message", &self.message())
-            .finish()
+            .field("message", &self.message());
+
+        // The origin is only included in the alternate form so that enabling the `origin`
+        // feature anywhere in the build doesn't change the output of `{:?}`.
+        #[cfg(feature = "origin")]
+        if let (true, Some(origin)) = (alternate, &self.origin) {
+            debug.field("origin", &format_args!("{}", origin.location()));
+        }
+
+        debug.finish()
     }
 }
 
@@ -251,10 +361,25 @@ impl Ord for Error {
 
 use error_info::*;
 
+/// The lower-level error that caused an [`Error`].
+#[cfg(feature = "std")]
+type Source = alloc::boxed::Box<dyn std::error::Error + Send + Sync>;
+
+/// The message of an [`Error`] that was created with context, along with its source.
+///
+/// This is kept alongside the error information rather than recovered from it so that it
+/// behaves the same on every target. It is discarded with `windows_slim_errors`.
+struct Context {
+    message: String,
+    #[cfg(feature = "std")]
+    source: Source,
+}
+
 #[cfg(all(windows, not(windows_slim_errors)))]
 mod error_info {
     use super::*;
     use crate::com::ComPtr;
+    use alloc::sync::Arc;
 
     /// This type stores error detail, represented by a COM `IErrorInfo` object.
     ///
@@ -264,11 +389,15 @@ mod error_info {
     #[derive(Clone, Default)]
     pub(crate) struct ErrorInfo {
         pub(super) ptr: Option<ComPtr>,
+        context: Option<Arc<Context>>,
     }
 
     impl ErrorInfo {
         pub(crate) const fn empty() -> Self {
-            Self { ptr: None }
+            Self {
+                ptr: None,
+                context: None,
+            }
         }
 
         pub(crate) fn from_thread() -> Self {
@@ -277,6 +406,7 @@ mod error_info {
                 crate::bindings::GetErrorInfo(0, ptr.as_mut_ptr() as *mut _);
                 Self {
                     ptr: ptr.assume_init(),
+                    context: None,
                 }
             }
         }
@@ -289,11 +419,12 @@ mod error_info {
             }
         }
 
-        pub(crate) fn originate_error(code: HRESULT, message: &str) {
+        pub(crate) fn originate_error(code: HRESULT, message: &str) -> Self {
             let message: Vec<_> = message.encode_utf16().collect();
             unsafe {
                 RoOriginateErrorW(code.0, message.len() as u32, message.as_ptr());
             }
+            Self::from_thread()
         }
 
         pub(crate) fn message(&self) -> Option<String> {
@@ -338,6 +469,14 @@ mod error_info {
             Some(String::from_utf16_lossy(wide_trim_end(&message)))
         }
 
+        pub(super) fn set_context(&mut self, context: Context) {
+            self.context = Some(Arc::new(context));
+        }
+
+        pub(super) fn context(&self) -> Option<&Context> {
+            self.context.as_deref()
+        }
+
         pub(crate) fn as_ptr(&self) -> *mut core::ffi::c_void {
             if let Some(info) = self.ptr.as_ref() {
                 info.as_raw()
@@ -351,7 +490,86 @@ mod error_info {
     unsafe impl Sync for ErrorInfo {}
 }
 
-#[cfg(not(all(windows, not(windows_slim_errors))))]
+#[cfg(all(not(windows), not(windows_slim_errors)))]
+mod error_info {
+    use super::*;
+    use alloc::sync::Arc;
+
+    /// This type stores error detail on targets without COM error objects.
+    ///
+    /// The details are reference counted so that errors remain cheap to clone, and are carried
+    /// across `HRESULT` conversions by the current thread in the same way as `IErrorInfo`.
+    #[derive(Clone, Default)]
+    pub(crate) struct ErrorInfo {
+        ptr: Option<Arc<ErrorDetail>>,
+        context: Option<Arc<Context>>,
+    }
+
+    struct ErrorDetail {
+        message: String,
+    }
+
+    #[cfg(feature = "std")]
+    std::thread_local! {
+        static THREAD_ERROR_INFO: core::cell::RefCell<Option<Arc<ErrorDetail>>> = const { core::cell::RefCell::new(None) };
+    }
+
+    impl ErrorInfo {
+        pub(crate) const fn empty() -> Self {
+            Self {
+                ptr: None,
+                context: None,
+            }
+        }
+
+        pub(crate) fn from_thread() -> Self {
+            #[cfg(feature = "std")]
+            {
+                Self {
+                    ptr: THREAD_ERROR_INFO.with(|info| info.borrow_mut().take()),
+                    context: None,
+                }
+            }
+            #[cfg(not(feature = "std"))]
+            {
+                Self::empty()
+            }
+        }
+
+        pub(crate) fn into_thread(self) {
+            #[cfg(feature = "std")]
+            THREAD_ERROR_INFO.with(|info| *info.borrow_mut() = self.ptr);
+        }
+
+        pub(crate) fn originate_error(code: HRESULT, message: &str) -> Self {
+            // Like `RoOriginateError`, only failures are given error information.
+            if code.is_ok() {
+                return Self::empty();
+            }
+
+            Self {
+                ptr: Some(Arc::new(ErrorDetail {
+                    message: message.into(),
+                })),
+                context: None,
+            }
+        }
+
+        pub(crate) fn message(&self) -> Option<String> {
+            self.ptr.as_ref().map(|ptr| ptr.message.clone())
+        }
+
+        pub(super) fn set_context(&mut self, context: Context) {
+            self.context = Some(Arc::new(context));
+        }
+
+        pub(super) fn context(&self) -> Option<&Context> {
+            self.context.as_deref()
+        }
+    }
+}
+
+#[cfg(windows_slim_errors)]
 mod error_info {
     use super::*;
 
@@ -373,13 +591,20 @@ mod error_info {
 
         pub(crate) fn into_thread(self) {}
 
-        #[cfg(windows)]
-        pub(crate) fn originate_error(_code: HRESULT, _message: &str) {}
+        pub(crate) fn originate_error(_code: HRESULT, _message: &str) -> Self {
+            Self
+        }
 
         pub(crate) fn message(&self) -> Option<String> {
             None
         }
 
+        pub(super) fn set_context(&mut self, _context: Context) {}
+
+        pub(super) fn context(&self) -> Option<&Context> {
+            None
+        }
+
         #[cfg(windows)]
         pub(crate) fn as_ptr(&self) -> *mut core::ffi::c_void {
             core::ptr::null_mut()
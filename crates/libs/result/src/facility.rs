@@ -0,0 +1,118 @@
+/// The facility of an [`HRESULT`](crate::HRESULT) value, identifying the part of the system
+/// responsible for the error.
+///
+/// Facilities that aren't known to this crate are represented by [`Facility::Other`]. The
+/// facility of an `NTSTATUS` value uses a different numbering and is returned as an integer.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Facility {
+    /// `FACILITY_NULL`
+    Null,
+    /// `FACILITY_RPC`
+    Rpc,
+    /// `FACILITY_DISPATCH`
+    Dispatch,
+    /// `FACILITY_STORAGE`
+    Storage,
+    /// `FACILITY_ITF`, used for interface-specific errors.
+    Itf,
+    /// `FACILITY_WIN32`, used for Win32 error codes mapped to an `HRESULT`.
+    Win32,
+    /// `FACILITY_WINDOWS`
+    Windows,
+    /// `FACILITY_SECURITY`
+    Security,
+    /// `FACILITY_CONTROL`
+    Control,
+    /// `FACILITY_CERT`
+    Cert,
+    /// `FACILITY_INTERNET`
+    Internet,
+    /// `FACILITY_SETUPAPI`
+    SetupApi,
+    /// `FACILITY_SCARD`
+    SmartCard,
+    /// `FACILITY_COMPLUS`
+    ComPlus,
+    /// `FACILITY_URT`, used by the .NET runtime.
+    Urt,
+    /// `FACILITY_SXS`
+    Sxs,
+    /// `FACILITY_HTTP`
+    Http,
+    /// `FACILITY_WINDOWSUPDATE`
+    WindowsUpdate,
+    /// `FACILITY_GRAPHICS`
+    Graphics,
+    /// `FACILITY_XAML`
+    Xaml,
+    /// `FACILITY_WINRM`
+    WinRm,
+    /// `FACILITY_DXGI`
+    Dxgi,
+    /// `FACILITY_DIRECT3D11`
+    Direct3D11,
+    /// Any other facility.
+    Other(u16),
+}
+
+impl From<u16> for Facility {
+    fn from(value: u16) -> Self {
+        match value {
+            0 => Self::Null,
+            1 => Self::Rpc,
+            2 => Self::Dispatch,
+            3 => Self::Storage,
+            4 => Self::Itf,
+            7 => Self::Win32,
+            8 => Self::Windows,
+            9 => Self::Security,
+            10 => Self::Control,
+            11 => Self::Cert,
+            12 => Self::Internet,
+            15 => Self::SetupApi,
+            16 => Self::SmartCard,
+            17 => Self::ComPlus,
+            19 => Self::Urt,
+            23 => Self::Sxs,
+            25 => Self::Http,
+            36 => Self::WindowsUpdate,
+            38 => Self::Graphics,
+            43 => Self::Xaml,
+            51 => Self::WinRm,
+            0x87A => Self::Dxgi,
+            0x87C => Self::Direct3D11,
+            value => Self::Other(value),
+        }
+    }
+}
+
+impl From<Facility> for u16 {
+    fn from(value: Facility) -> Self {
+        match value {
+            Facility::Null => 0,
+            Facility::Rpc => 1,
+            Facility::Dispatch => 2,
+            Facility::Storage => 3,
+            Facility::Itf => 4,
+            Facility::Win32 => 7,
+            Facility::Windows => 8,
+            Facility::Security => 9,
+            Facility::Control => 10,
+            Facility::Cert => 11,
+            Facility::Internet => 12,
+            Facility::SetupApi => 15,
+            Facility::SmartCard => 16,
+            Facility::ComPlus => 17,
+            Facility::Urt => 19,
+            Facility::Sxs => 23,
+            Facility::Http => 25,
+            Facility::WindowsUpdate => 36,
+            Facility::Graphics => 38,
+            Facility::Xaml => 43,
+            Facility::WinRm => 51,
+            Facility::Dxgi => 0x87A,
+            Facility::Direct3D11 => 0x87C,
+            Facility::Other(value) => value,
+        }
+    }
+}
@@ -19,6 +19,67 @@ impl HRESULT {
         !self.is_ok()
     }
 
+    /// Returns the severity of `self`, which is either [`Severity::Success`] or [`Severity::Error`].
+    #[inline]
+    pub const fn severity(self) -> Severity {
+        if self.is_ok() {
+            Severity::Success
+        } else {
+            Severity::Error
+        }
+    }
+
+    /// Returns the facility of `self`.
+    #[inline]
+    pub fn facility(self) -> Facility {
+        Facility::from(((self.0 >> 16) & 0x7FF) as u16)
+    }
+
+    /// Returns the facility-specific code of `self`, which is the low 16 bits.
+    #[inline]
+    pub const fn code(self) -> u16 {
+        self.0 as u16
+    }
+
+    /// Returns [`true`] if `self` is a customer-defined rather than a Microsoft-defined code.
+    #[inline]
+    pub const fn is_customer(self) -> bool {
+        self.0 & 0x2000_0000 != 0
+    }
+
+    /// Returns [`true`] if `self` is an [`NTSTATUS`] value mapped to an `HRESULT`.
+    #[inline]
+    pub const fn is_ntstatus(self) -> bool {
+        self.0 & 0x1000_0000 != 0
+    }
+
+    /// Returns the Win32 error code that `self` was mapped from, if any.
+    ///
+    /// This is the inverse of [`WIN32_ERROR::to_hresult`].
+    #[inline]
+    pub const fn as_win32(self) -> Option<WIN32_ERROR> {
+        if self.0 == 0 {
+            Some(WIN32_ERROR(0))
+        } else if self.0 as u32 & 0xFFFF_0000 == 0x8007_0000 {
+            Some(WIN32_ERROR(self.0 as u32 & 0xFFFF))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the [`NTSTATUS`] value that `self` was mapped from, if any.
+    ///
+    /// This is the inverse of [`NTSTATUS::to_hresult`] for warnings and errors. Other status
+    /// values are not changed by the mapping and so can't be distinguished from an `HRESULT`.
+    #[inline]
+    pub const fn as_ntstatus(self) -> Option<NTSTATUS> {
+        if self.is_ntstatus() {
+            Some(NTSTATUS(self.0 & !0x1000_0000))
+        } else {
+            None
+        }
+    }
+
     /// Asserts that `self` is a success code.
     ///
     /// This will invoke the [`panic!`] macro if `self` is a failure code and display
@@ -31,17 +92,19 @@ impl HRESULT {
 
     /// Converts the [`HRESULT`] to [`Result<()>`][Result<_>].
     #[inline]
+    #[cfg_attr(feature = "origin", track_caller)]
     pub fn ok(self) -> Result<()> {
         if self.is_ok() {
             Ok(())
         } else {
-            Err(self.into())
+            Err(Error::from(self))
         }
     }
 
     /// Calls `op` if `self` is a success code, otherwise returns [`HRESULT`]
     /// converted to [`Result<T>`].
     #[inline]
+    #[cfg_attr(feature = "origin", track_caller)]
     pub fn map<F, T>(self, op: F) -> Result<T>
     where
         F: FnOnce() -> T,
@@ -53,6 +116,7 @@ impl HRESULT {
     /// Calls `op` if `self` is a success code, otherwise returns [`HRESULT`]
     /// converted to [`Result<T>`].
     #[inline]
+    #[cfg_attr(feature = "origin", track_caller)]
     pub fn and_then<F, T>(self, op: F) -> Result<T>
     where
         F: FnOnce() -> Result<T>,
@@ -62,53 +126,83 @@ impl HRESULT {
     }
 
     /// The error message describing the error.
+    ///
+    /// The message is provided by the system on Windows. With the `names` feature enabled, a
+    /// built-in message is used for common codes when the system doesn't provide one, including
+    /// on other targets. Otherwise, other targets only provide the hexadecimal value.
     pub fn message(self) -> String {
         #[cfg(windows)]
-        {
-            let mut message = HeapString::default();
-            let mut code = self.0;
-            let mut module = core::ptr::null_mut();
-
-            let mut flags = FORMAT_MESSAGE_ALLOCATE_BUFFER
-                | FORMAT_MESSAGE_FROM_SYSTEM
-                | FORMAT_MESSAGE_IGNORE_INSERTS;
-
-            unsafe {
-                if self.0 & 0x1000_0000 == 0x1000_0000 {
-                    code ^= 0x1000_0000;
-                    flags |= FORMAT_MESSAGE_FROM_HMODULE;
-
-                    module = LoadLibraryExA(
-                        c"ntdll.dll".as_ptr() as _,
-                        core::ptr::null_mut(),
-                        LOAD_LIBRARY_SEARCH_DEFAULT_DIRS,
-                    );
-                }
-
-                let size = FormatMessageW(
-                    flags,
-                    module as _,
-                    code as _,
-                    0,
-                    &mut message.0 as *mut _ as *mut _,
-                    0,
-                    core::ptr::null(),
-                );
+        let message = self.system_message();
 
-                if !message.0.is_null() && size > 0 {
-                    String::from_utf16_lossy(wide_trim_end(core::slice::from_raw_parts(
-                        message.0,
-                        size as usize,
-                    )))
-                } else {
-                    String::default()
-                }
-            }
+        #[cfg(not(windows))]
+        let message = String::new();
+
+        if !message.is_empty() {
+            return message;
         }
 
-        #[cfg(not(windows))]
-        {
-            return alloc::format!("0x{:08x}", self.0 as u32);
+        #[cfg(feature = "names")]
+        if let Some((_, _, message)) = names::hresult(self) {
+            return (*message).into();
+        }
+
+        if cfg!(windows) {
+            message
+        } else {
+            alloc::format!("0x{:08x}", self.0 as u32)
+        }
+    }
+
+    /// The symbolic name of the error code, such as `E_ACCESSDENIED`.
+    ///
+    /// Win32 and NT codes that have been mapped to an `HRESULT` use their own names, such as
+    /// `ERROR_FILE_NOT_FOUND`. Returns [`None`] if the code isn't one of the common codes known
+    /// to this crate.
+    #[cfg(feature = "names")]
+    pub fn name(self) -> Option<&'static str> {
+        names::hresult(self).map(|(_, name, _)| *name)
+    }
+
+    #[cfg(windows)]
+    fn system_message(self) -> String {
+        let mut message = HeapString::default();
+        let mut code = self.0;
+        let mut module = core::ptr::null_mut();
+
+        let mut flags = FORMAT_MESSAGE_ALLOCATE_BUFFER
+            | FORMAT_MESSAGE_FROM_SYSTEM
+            | FORMAT_MESSAGE_IGNORE_INSERTS;
+
+        unsafe {
+            if self.0 & 0x1000_0000 == 0x1000_0000 {
+                code ^= 0x1000_0000;
+                flags |= FORMAT_MESSAGE_FROM_HMODULE;
+
+                module = LoadLibraryExA(
+                    c"ntdll.dll".as_ptr() as _,
+                    core::ptr::null_mut(),
+                    LOAD_LIBRARY_SEARCH_DEFAULT_DIRS,
+                );
+            }
+
+            let size = FormatMessageW(
+                flags,
+                module as _,
+                code as _,
+                0,
+                &mut message.0 as *mut _ as *mut _,
+                0,
+                core::ptr::null(),
+            );
+
+            if !message.0.is_null() && size > 0 {
+                String::from_utf16_lossy(wide_trim_end(core::slice::from_raw_parts(
+                    message.0,
+                    size as usize,
+                )))
+            } else {
+                String::default()
+            }
         }
     }
 
@@ -31,6 +31,17 @@ mod bstr;
 mod error;
 pub use error::*;
 
+mod context;
+pub use context::ResultExt;
+
+#[cfg(feature = "origin")]
+mod origin;
+#[cfg(feature = "origin")]
+pub use origin::*;
+
+#[cfg(feature = "names")]
+mod names;
+
 mod hresult;
 pub use hresult::HRESULT;
 
@@ -46,5 +57,11 @@ pub use ntstatus::NTSTATUS;
 mod rpc_status;
 pub use rpc_status::RPC_STATUS;
 
+mod facility;
+pub use facility::Facility;
+
+mod severity;
+pub use severity::Severity;
+
 /// A specialized [`Result`] type that provides Windows error information.
 pub type Result<T> = core::result::Result<T, Error>;
@@ -0,0 +1,154 @@
+// Symbolic names and messages for common error codes.
+//
+// The names and values match the constants in the Windows metadata and the messages match the
+// system message tables, so that the same text is available on every target. Each table is
+// sorted by code so that it can be searched without allocating.
+
+use super::*;
+
+/// A code together with its symbolic name and system message.
+type Entry = (u32, &'static str, &'static str);
+
+pub(crate) fn hresult(code: HRESULT) -> Option<&'static Entry> {
+    let code = code.0 as u32;
+
+    if let Some(entry) = find(HRESULTS, code) {
+        return Some(entry);
+    }
+
+    // Win32 and NT codes that have been mapped to an `HRESULT` keep their own names.
+    if code & 0xFFFF_0000 == 0x8007_0000 {
+        win32_error(WIN32_ERROR(code & 0xFFFF))
+    } else if code & 0x1000_0000 == 0x1000_0000 {
+        ntstatus(NTSTATUS((code ^ 0x1000_0000) as i32))
+    } else {
+        None
+    }
+}
+
+pub(crate) fn win32_error(code: WIN32_ERROR) -> Option<&'static Entry> {
+    find(WIN32_ERRORS, code.0)
+}
+
+pub(crate) fn ntstatus(code: NTSTATUS) -> Option<&'static Entry> {
+    find(NTSTATUSES, code.0 as u32)
+}
+
+fn find(table: &'static [Entry], code: u32) -> Option<&'static Entry> {
+    let index = table.binary_search_by_key(&code, |entry| entry.0).ok()?;
+    Some(&table[index])
+}
+
+const HRESULTS: &[Entry] = &[
+    (0x0000_0000, "S_OK", "The operation completed successfully."),
+    (0x0000_0001, "S_FALSE", "Incorrect function."),
+    (0x8000_000A, "E_PENDING", "The data necessary to complete this operation is not yet available."),
+    (0x8000_000B, "E_BOUNDS", "The operation attempted to access data outside the valid range"),
+    (0x8000_000C, "E_CHANGED_STATE", "A concurrent or interleaved operation changed the state of the object, invalidating this operation."),
+    (0x8000_000D, "E_ILLEGAL_STATE_CHANGE", "An illegal state change was requested."),
+    (0x8000_000E, "E_ILLEGAL_METHOD_CALL", "A method was called at an unexpected time."),
+    (0x8000_0013, "RO_E_CLOSED", "The object has been closed."),
+    (0x8000_0017, "E_STRING_NOT_NULL_TERMINATED", "String not null terminated."),
+    (0x8000_0019, "E_ASYNC_OPERATION_NOT_STARTED", "An async operation was not properly started."),
+    (0x8000_4001, "E_NOTIMPL", "Not implemented"),
+    (0x8000_4002, "E_NOINTERFACE", "No such interface supported"),
+    (0x8000_4003, "E_POINTER", "Invalid pointer"),
+    (0x8000_4004, "E_ABORT", "Operation aborted"),
+    (0x8000_4005, "E_FAIL", "Unspecified error"),
+    (0x8000_FFFF, "E_UNEXPECTED", "Catastrophic failure"),
+    (0x8001_0106, "RPC_E_CHANGED_MODE", "Cannot change thread mode after it is set."),
+    (0x8001_0108, "RPC_E_DISCONNECTED", "The object invoked has disconnected from its clients."),
+    (0x8001_010E, "RPC_E_WRONG_THREAD", "The application called an interface that was marshalled for a different thread."),
+    (0x8002_0001, "DISP_E_UNKNOWNINTERFACE", "Unknown interface."),
+    (0x8002_0003, "DISP_E_MEMBERNOTFOUND", "Member not found."),
+    (0x8002_0004, "DISP_E_PARAMNOTFOUND", "Parameter not found."),
+    (0x8002_0005, "DISP_E_TYPEMISMATCH", "Type mismatch."),
+    (0x8002_0006, "DISP_E_UNKNOWNNAME", "Unknown name."),
+    (0x8002_0007, "DISP_E_NONAMEDARGS", "No named arguments."),
+    (0x8002_000B, "DISP_E_BADINDEX", "Invalid index."),
+    (0x8002_000E, "DISP_E_BADPARAMCOUNT", "Invalid number of parameters."),
+    (0x8002_802B, "TYPE_E_ELEMENTNOTFOUND", "Element not found."),
+    (0x8004_0110, "CLASS_E_NOAGGREGATION", "Class does not support aggregation (or class object is remote)"),
+    (0x8004_0111, "CLASS_E_CLASSNOTAVAILABLE", "ClassFactory cannot supply requested class"),
+    (0x8004_0154, "REGDB_E_CLASSNOTREG", "Class not registered"),
+    (0x8004_01F0, "CO_E_NOTINITIALIZED", "CoInitialize has not been called."),
+    (0x8007_0005, "E_ACCESSDENIED", "Access is denied."),
+    (0x8007_0006, "E_HANDLE", "The handle is invalid."),
+    (0x8007_000E, "E_OUTOFMEMORY", "Not enough memory resources are available to complete this operation."),
+    (0x8007_0057, "E_INVALIDARG", "The parameter is incorrect."),
+];
+
+const WIN32_ERRORS: &[Entry] = &[
+    (0, "ERROR_SUCCESS", "The operation completed successfully."),
+    (1, "ERROR_INVALID_FUNCTION", "Incorrect function."),
+    (2, "ERROR_FILE_NOT_FOUND", "The system cannot find the file specified."),
+    (3, "ERROR_PATH_NOT_FOUND", "The system cannot find the path specified."),
+    (4, "ERROR_TOO_MANY_OPEN_FILES", "The system cannot open the file."),
+    (5, "ERROR_ACCESS_DENIED", "Access is denied."),
+    (6, "ERROR_INVALID_HANDLE", "The handle is invalid."),
+    (8, "ERROR_NOT_ENOUGH_MEMORY", "Not enough memory resources are available to process this command."),
+    (13, "ERROR_INVALID_DATA", "The data is invalid."),
+    (14, "ERROR_OUTOFMEMORY", "Not enough memory resources are available to complete this operation."),
+    (15, "ERROR_INVALID_DRIVE", "The system cannot find the drive specified."),
+    (18, "ERROR_NO_MORE_FILES", "There are no more files."),
+    (19, "ERROR_WRITE_PROTECT", "The media is write protected."),
+    (21, "ERROR_NOT_READY", "The device is not ready."),
+    (32, "ERROR_SHARING_VIOLATION", "The process cannot access the file because it is being used by another process."),
+    (33, "ERROR_LOCK_VIOLATION", "The process cannot access the file because another process has locked a portion of the file."),
+    (38, "ERROR_HANDLE_EOF", "Reached the end of the file."),
+    (50, "ERROR_NOT_SUPPORTED", "The request is not supported."),
+    (80, "ERROR_FILE_EXISTS", "The file exists."),
+    (87, "ERROR_INVALID_PARAMETER", "The parameter is incorrect."),
+    (109, "ERROR_BROKEN_PIPE", "The pipe has been ended."),
+    (111, "ERROR_BUFFER_OVERFLOW", "The file name is too long."),
+    (112, "ERROR_DISK_FULL", "There is not enough space on the disk."),
+    (120, "ERROR_CALL_NOT_IMPLEMENTED", "This function is not supported on this system."),
+    (122, "ERROR_INSUFFICIENT_BUFFER", "The data area passed to a system call is too small."),
+    (123, "ERROR_INVALID_NAME", "The filename, directory name, or volume label syntax is incorrect."),
+    (126, "ERROR_MOD_NOT_FOUND", "The specified module could not be found."),
+    (127, "ERROR_PROC_NOT_FOUND", "The specified procedure could not be found."),
+    (145, "ERROR_DIR_NOT_EMPTY", "The directory is not empty."),
+    (183, "ERROR_ALREADY_EXISTS", "Cannot create a file when that file already exists."),
+    (203, "ERROR_ENVVAR_NOT_FOUND", "The system could not find the environment option that was entered."),
+    (206, "ERROR_FILENAME_EXCED_RANGE", "The filename or extension is too long."),
+    (232, "ERROR_NO_DATA", "The pipe is being closed."),
+    (234, "ERROR_MORE_DATA", "More data is available."),
+    (259, "ERROR_NO_MORE_ITEMS", "No more data is available."),
+    (267, "ERROR_DIRECTORY", "The directory name is invalid."),
+    (487, "ERROR_INVALID_ADDRESS", "Attempt to access invalid address."),
+    (995, "ERROR_OPERATION_ABORTED", "The I/O operation has been aborted because of either a thread exit or an application request."),
+    (996, "ERROR_IO_INCOMPLETE", "Overlapped I/O event is not in a signaled state."),
+    (997, "ERROR_IO_PENDING", "Overlapped I/O operation is in progress."),
+    (1060, "ERROR_SERVICE_DOES_NOT_EXIST", "The specified service does not exist as an installed service."),
+    (1113, "ERROR_NO_UNICODE_TRANSLATION", "No mapping for the Unicode character exists in the target multi-byte code page."),
+    (1168, "ERROR_NOT_FOUND", "Element not found."),
+    (1223, "ERROR_CANCELLED", "The operation was canceled by the user."),
+    (1400, "ERROR_INVALID_WINDOW_HANDLE", "Invalid window handle."),
+    (1460, "ERROR_TIMEOUT", "This operation returned because the timeout period expired."),
+    (5023, "ERROR_INVALID_STATE", "The group or resource is not in the correct state to perform the requested operation."),
+];
+
+const NTSTATUSES: &[Entry] = &[
+    (0x0000_0000, "STATUS_SUCCESS", "The operation completed successfully."),
+    (0x0000_0103, "STATUS_PENDING", "The operation that was requested is pending completion."),
+    (0x8000_0005, "STATUS_BUFFER_OVERFLOW", "{Buffer Overflow} The data was too large to fit into the specified buffer."),
+    (0x8000_001A, "STATUS_NO_MORE_ENTRIES", "{No More Entries} No more entries are available from an enumeration operation."),
+    (0xC000_0001, "STATUS_UNSUCCESSFUL", "{Operation Failed} The requested operation was unsuccessful."),
+    (0xC000_0002, "STATUS_NOT_IMPLEMENTED", "{Not Implemented} The requested operation is not implemented."),
+    (0xC000_0004, "STATUS_INFO_LENGTH_MISMATCH", "The specified information record length does not match the length required for the specified information class."),
+    (0xC000_0008, "STATUS_INVALID_HANDLE", "An invalid HANDLE was specified."),
+    (0xC000_000D, "STATUS_INVALID_PARAMETER", "An invalid parameter was passed to a service or function."),
+    (0xC000_0010, "STATUS_INVALID_DEVICE_REQUEST", "The specified request is not a valid operation for the target device."),
+    (0xC000_0017, "STATUS_NO_MEMORY", "{Not Enough Quota} Not enough virtual memory or paging file quota is available to complete the specified operation."),
+    (0xC000_0022, "STATUS_ACCESS_DENIED", "{Access Denied} A process has requested access to an object, but has not been granted those access rights."),
+    (0xC000_0023, "STATUS_BUFFER_TOO_SMALL", "{Buffer Too Small} The buffer is too small to contain the entry. No information has been written to the buffer."),
+    (0xC000_0024, "STATUS_OBJECT_TYPE_MISMATCH", "There is a mismatch between the type of object that is required by the requested operation and the type of object that is specified in the request."),
+    (0xC000_0034, "STATUS_OBJECT_NAME_NOT_FOUND", "Object Name not found."),
+    (0xC000_0035, "STATUS_OBJECT_NAME_COLLISION", "Object Name already exists."),
+    (0xC000_0043, "STATUS_SHARING_VIOLATION", "A file cannot be opened because the share access flags are incompatible."),
+    (0xC000_009A, "STATUS_INSUFFICIENT_RESOURCES", "Insufficient system resources exist to complete the API."),
+    (0xC000_00BB, "STATUS_NOT_SUPPORTED", "The request is not supported."),
+    (0xC000_00E5, "STATUS_INTERNAL_ERROR", "An internal error occurred."),
+    (0xC000_0120, "STATUS_CANCELLED", "The I/O request was canceled."),
+    (0xC000_0225, "STATUS_NOT_FOUND", "The object was not found."),
+];
@@ -29,6 +29,73 @@ impl NTSTATUS {
         })
     }
 
+    /// Returns the severity of `self`.
+    #[inline]
+    pub const fn severity(self) -> Severity {
+        match (self.0 as u32) >> 30 {
+            0 => Severity::Success,
+            1 => Severity::Informational,
+            2 => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
+    /// Returns the facility of `self`.
+    ///
+    /// The `NTSTATUS` facilities are numbered independently of the [`HRESULT`] facilities
+    /// described by [`Facility`].
+    #[inline]
+    pub const fn facility(self) -> u16 {
+        ((self.0 >> 16) & 0xFFF) as u16
+    }
+
+    /// Returns the facility-specific code of `self`, which is the low 16 bits.
+    #[inline]
+    pub const fn code(self) -> u16 {
+        self.0 as u16
+    }
+
+    /// Returns [`true`] if `self` is a customer-defined rather than a Microsoft-defined code.
+    #[inline]
+    pub const fn is_customer(self) -> bool {
+        self.0 & 0x2000_0000 != 0
+    }
+
+    /// Maps an NT status code to a Win32 error code.
+    ///
+    /// This is available on every target but is only a partial mapping. It agrees with
+    /// `RtlNtStatusToDosError` for the common status codes known to this crate, which are a
+    /// small subset of those known to Windows. Win32 error codes that have been mapped to an
+    /// `NTSTATUS` or [`HRESULT`] are unwrapped and customer codes are returned unchanged.
+    ///
+    /// Returns [`None`] for any other status code, including many that Windows does map. This
+    /// differs from `RtlNtStatusToDosError`, which returns `ERROR_MR_MID_NOT_FOUND` only for codes
+    /// it doesn't know. Code running on Windows that needs the complete mapping should call
+    /// `RtlNtStatusToDosError` instead.
+    pub fn to_win32(self) -> Option<WIN32_ERROR> {
+        let mut status = self.0 as u32;
+
+        if status == 0 || self.is_customer() {
+            return Some(WIN32_ERROR(status));
+        }
+
+        // Win32 error codes that have been mapped to an `HRESULT` or `NTSTATUS`.
+        if matches!(status & 0xFFFF_0000, 0x8007_0000 | 0xC007_0000) {
+            return Some(WIN32_ERROR(status & 0xFFFF));
+        }
+
+        // An `NTSTATUS` that has been mapped to an `HRESULT`.
+        if status & 0xF000_0000 == 0xD000_0000 {
+            status &= !0x1000_0000;
+        }
+
+        let index = DOS_ERRORS
+            .binary_search_by_key(&status, |(status, _)| *status)
+            .ok()?;
+
+        Some(WIN32_ERROR(DOS_ERRORS[index].1))
+    }
+
     /// Asserts that `self` is a success code.
     ///
     /// This will invoke the [`panic!`] macro if `self` is a failure code and display
@@ -41,9 +108,23 @@ impl NTSTATUS {
 
     /// Converts the [`NTSTATUS`] to [`Result<()>`][Result<_>].
     #[inline]
+    #[cfg_attr(feature = "origin", track_caller)]
     pub fn ok(self) -> Result<()> {
         self.to_hresult().ok()
     }
+
+    /// The error message describing the error.
+    pub fn message(self) -> String {
+        self.to_hresult().message()
+    }
+
+    /// The symbolic name of the status code, such as `STATUS_INVALID_PARAMETER`.
+    ///
+    /// Returns [`None`] if the code isn't one of the common codes known to this crate.
+    #[cfg(feature = "names")]
+    pub fn name(self) -> Option<&'static str> {
+        names::ntstatus(self).map(|(_, name, _)| *name)
+    }
 }
 
 impl From<NTSTATUS> for HRESULT {
@@ -53,8 +134,9 @@ impl From<NTSTATUS> for HRESULT {
 }
 
 impl From<NTSTATUS> for Error {
+    #[cfg_attr(feature = "origin", track_caller)]
     fn from(value: NTSTATUS) -> Self {
-        value.to_hresult().into()
+        Self::from(value.to_hresult())
     }
 }
 
@@ -69,3 +151,151 @@ impl core::fmt::Debug for NTSTATUS {
         f.write_fmt(format_args!("NTSTATUS({self})"))
     }
 }
+
+// Common status codes known to `RtlNtStatusToDosError` and the Win32 error codes they map to,
+// sorted by status code. This is not the complete table used by Windows.
+const DOS_ERRORS: &[(u32, u32)] = &[
+    (0x0000_0102, 258),  // STATUS_TIMEOUT
+    (0x0000_0103, 997),  // STATUS_PENDING
+    (0x0000_0105, 234),  // STATUS_MORE_ENTRIES
+    (0x0000_0106, 1300), // STATUS_NOT_ALL_ASSIGNED
+    (0x0000_010C, 1022), // STATUS_NOTIFY_ENUM_DIR
+    (0x4000_0000, 183),  // STATUS_OBJECT_NAME_EXISTS
+    (0x8000_0002, 998),  // STATUS_DATATYPE_MISALIGNMENT
+    (0x8000_0005, 234),  // STATUS_BUFFER_OVERFLOW
+    (0x8000_0006, 18),   // STATUS_NO_MORE_FILES
+    (0x8000_0011, 170),  // STATUS_DEVICE_BUSY
+    (0x8000_001A, 259),  // STATUS_NO_MORE_ENTRIES
+    (0xC000_0001, 31),   // STATUS_UNSUCCESSFUL
+    (0xC000_0002, 1),    // STATUS_NOT_IMPLEMENTED
+    (0xC000_0003, 87),   // STATUS_INVALID_INFO_CLASS
+    (0xC000_0004, 24),   // STATUS_INFO_LENGTH_MISMATCH
+    (0xC000_0005, 998),  // STATUS_ACCESS_VIOLATION
+    (0xC000_0006, 999),  // STATUS_IN_PAGE_ERROR
+    (0xC000_0007, 1454), // STATUS_PAGEFILE_QUOTA
+    (0xC000_0008, 6),    // STATUS_INVALID_HANDLE
+    (0xC000_0009, 1001), // STATUS_BAD_INITIAL_STACK
+    (0xC000_000B, 87),   // STATUS_INVALID_CID
+    (0xC000_000D, 87),   // STATUS_INVALID_PARAMETER
+    (0xC000_000E, 2),    // STATUS_NO_SUCH_DEVICE
+    (0xC000_000F, 2),    // STATUS_NO_SUCH_FILE
+    (0xC000_0010, 1),    // STATUS_INVALID_DEVICE_REQUEST
+    (0xC000_0011, 38),   // STATUS_END_OF_FILE
+    (0xC000_0012, 34),   // STATUS_WRONG_VOLUME
+    (0xC000_0013, 21),   // STATUS_NO_MEDIA_IN_DEVICE
+    (0xC000_0015, 27),   // STATUS_NONEXISTENT_SECTOR
+    (0xC000_0017, 8),    // STATUS_NO_MEMORY
+    (0xC000_0018, 487),  // STATUS_CONFLICTING_ADDRESSES
+    (0xC000_001A, 87),   // STATUS_UNABLE_TO_FREE_VM
+    (0xC000_001C, 1),    // STATUS_INVALID_SYSTEM_SERVICE
+    (0xC000_001F, 5),    // STATUS_INVALID_VIEW_SIZE
+    (0xC000_0021, 5),    // STATUS_ALREADY_COMMITTED
+    (0xC000_0022, 5),    // STATUS_ACCESS_DENIED
+    (0xC000_0023, 122),  // STATUS_BUFFER_TOO_SMALL
+    (0xC000_0024, 6),    // STATUS_OBJECT_TYPE_MISMATCH
+    (0xC000_0032, 1393), // STATUS_DISK_CORRUPT_ERROR
+    (0xC000_0033, 123),  // STATUS_OBJECT_NAME_INVALID
+    (0xC000_0034, 2),    // STATUS_OBJECT_NAME_NOT_FOUND
+    (0xC000_0035, 183),  // STATUS_OBJECT_NAME_COLLISION
+    (0xC000_0037, 6),    // STATUS_PORT_DISCONNECTED
+    (0xC000_0039, 161),  // STATUS_OBJECT_PATH_INVALID
+    (0xC000_003A, 3),    // STATUS_OBJECT_PATH_NOT_FOUND
+    (0xC000_003B, 161),  // STATUS_OBJECT_PATH_SYNTAX_BAD
+    (0xC000_003E, 23),   // STATUS_DATA_ERROR
+    (0xC000_003F, 23),   // STATUS_CRC_ERROR
+    (0xC000_0040, 8),    // STATUS_SECTION_TOO_BIG
+    (0xC000_0041, 5),    // STATUS_PORT_CONNECTION_REFUSED
+    (0xC000_0042, 6),    // STATUS_INVALID_PORT_HANDLE
+    (0xC000_0043, 32),   // STATUS_SHARING_VIOLATION
+    (0xC000_0044, 1816), // STATUS_QUOTA_EXCEEDED
+    (0xC000_0045, 87),   // STATUS_INVALID_PAGE_PROTECTION
+    (0xC000_0046, 288),  // STATUS_MUTANT_NOT_OWNED
+    (0xC000_0047, 298),  // STATUS_SEMAPHORE_LIMIT_EXCEEDED
+    (0xC000_004B, 5),    // STATUS_THREAD_IS_TERMINATING
+    (0xC000_004F, 282),  // STATUS_EAS_NOT_SUPPORTED
+    (0xC000_0054, 33),   // STATUS_FILE_LOCK_CONFLICT
+    (0xC000_0055, 33),   // STATUS_LOCK_NOT_GRANTED
+    (0xC000_0056, 5),    // STATUS_DELETE_PENDING
+    (0xC000_005A, 1307), // STATUS_INVALID_OWNER
+    (0xC000_005F, 1312), // STATUS_NO_SUCH_LOGON_SESSION
+    (0xC000_0060, 1313), // STATUS_NO_SUCH_PRIVILEGE
+    (0xC000_0061, 1314), // STATUS_PRIVILEGE_NOT_HELD
+    (0xC000_0064, 1317), // STATUS_NO_SUCH_USER
+    (0xC000_006A, 86),   // STATUS_WRONG_PASSWORD
+    (0xC000_006D, 1326), // STATUS_LOGON_FAILURE
+    (0xC000_006E, 1327), // STATUS_ACCOUNT_RESTRICTION
+    (0xC000_006F, 1328), // STATUS_INVALID_LOGON_HOURS
+    (0xC000_0071, 1330), // STATUS_PASSWORD_EXPIRED
+    (0xC000_0072, 1331), // STATUS_ACCOUNT_DISABLED
+    (0xC000_0073, 1332), // STATUS_NONE_MAPPED
+    (0xC000_0077, 1336), // STATUS_INVALID_ACL
+    (0xC000_0078, 1337), // STATUS_INVALID_SID
+    (0xC000_0079, 1338), // STATUS_INVALID_SECURITY_DESCR
+    (0xC000_007B, 193),  // STATUS_INVALID_IMAGE_FORMAT
+    (0xC000_007C, 1008), // STATUS_NO_TOKEN
+    (0xC000_007F, 112),  // STATUS_DISK_FULL
+    (0xC000_009A, 1450), // STATUS_INSUFFICIENT_RESOURCES
+    (0xC000_00A2, 19),   // STATUS_MEDIA_WRITE_PROTECTED
+    (0xC000_00A3, 21),   // STATUS_DEVICE_NOT_READY
+    (0xC000_00A5, 1346), // STATUS_BAD_IMPERSONATION_LEVEL
+    (0xC000_00A6, 1347), // STATUS_CANT_OPEN_ANONYMOUS
+    (0xC000_00AE, 231),  // STATUS_PIPE_BUSY
+    (0xC000_00AF, 1),    // STATUS_ILLEGAL_FUNCTION
+    (0xC000_00B0, 233),  // STATUS_PIPE_DISCONNECTED
+    (0xC000_00B1, 232),  // STATUS_PIPE_CLOSING
+    (0xC000_00B5, 121),  // STATUS_IO_TIMEOUT
+    (0xC000_00BA, 5),    // STATUS_FILE_IS_A_DIRECTORY
+    (0xC000_00BB, 50),   // STATUS_NOT_SUPPORTED
+    (0xC000_00BE, 53),   // STATUS_BAD_NETWORK_PATH
+    (0xC000_00BF, 54),   // STATUS_NETWORK_BUSY
+    (0xC000_00C0, 55),   // STATUS_DEVICE_DOES_NOT_EXIST
+    (0xC000_00C1, 56),   // STATUS_TOO_MANY_COMMANDS
+    (0xC000_00C3, 58),   // STATUS_INVALID_NETWORK_RESPONSE
+    (0xC000_00C4, 59),   // STATUS_UNEXPECTED_NETWORK_ERROR
+    (0xC000_00CA, 65),   // STATUS_NETWORK_ACCESS_DENIED
+    (0xC000_00CB, 66),   // STATUS_BAD_DEVICE_TYPE
+    (0xC000_00CC, 67),   // STATUS_BAD_NETWORK_NAME
+    (0xC000_00D4, 17),   // STATUS_NOT_SAME_DEVICE
+    (0xC000_00DF, 1355), // STATUS_NO_SUCH_DOMAIN
+    (0xC000_00E5, 1359), // STATUS_INTERNAL_ERROR
+    (0xC000_00EF, 87),   // STATUS_INVALID_PARAMETER_1
+    (0xC000_00F0, 87),   // STATUS_INVALID_PARAMETER_2
+    (0xC000_00F1, 87),   // STATUS_INVALID_PARAMETER_3
+    (0xC000_00F2, 87),   // STATUS_INVALID_PARAMETER_4
+    (0xC000_00F3, 87),   // STATUS_INVALID_PARAMETER_5
+    (0xC000_00F4, 87),   // STATUS_INVALID_PARAMETER_6
+    (0xC000_00F5, 87),   // STATUS_INVALID_PARAMETER_7
+    (0xC000_00F6, 87),   // STATUS_INVALID_PARAMETER_8
+    (0xC000_00F7, 87),   // STATUS_INVALID_PARAMETER_9
+    (0xC000_00F8, 87),   // STATUS_INVALID_PARAMETER_10
+    (0xC000_00F9, 87),   // STATUS_INVALID_PARAMETER_11
+    (0xC000_00FA, 87),   // STATUS_INVALID_PARAMETER_12
+    (0xC000_00FD, 1001), // STATUS_STACK_OVERFLOW
+    (0xC000_0101, 145),  // STATUS_DIRECTORY_NOT_EMPTY
+    (0xC000_0102, 1392), // STATUS_FILE_CORRUPT_ERROR
+    (0xC000_0103, 267),  // STATUS_NOT_A_DIRECTORY
+    (0xC000_0106, 206),  // STATUS_NAME_TOO_LONG
+    (0xC000_010A, 5),    // STATUS_PROCESS_IS_TERMINATING
+    (0xC000_011F, 4),    // STATUS_TOO_MANY_OPENED_FILES
+    (0xC000_0120, 995),  // STATUS_CANCELLED
+    (0xC000_0121, 5),    // STATUS_CANNOT_DELETE
+    (0xC000_0123, 5),    // STATUS_FILE_DELETED
+    (0xC000_0128, 6),    // STATUS_FILE_CLOSED
+    (0xC000_0135, 126),  // STATUS_DLL_NOT_FOUND
+    (0xC000_0138, 182),  // STATUS_ORDINAL_NOT_FOUND
+    (0xC000_0139, 127),  // STATUS_ENTRYPOINT_NOT_FOUND
+    (0xC000_014B, 109),  // STATUS_PIPE_BROKEN
+    (0xC000_0184, 22),   // STATUS_INVALID_DEVICE_STATE
+    (0xC000_0185, 1117), // STATUS_IO_DEVICE_ERROR
+    (0xC000_0206, 1784), // STATUS_INVALID_BUFFER_SIZE
+    (0xC000_020A, 52),   // STATUS_ADDRESS_ALREADY_EXISTS
+    (0xC000_020D, 64),   // STATUS_CONNECTION_RESET
+    (0xC000_0225, 1168), // STATUS_NOT_FOUND
+    (0xC000_022D, 1237), // STATUS_RETRY
+    (0xC000_0236, 1225), // STATUS_CONNECTION_REFUSED
+    (0xC000_023C, 1231), // STATUS_NETWORK_UNREACHABLE
+    (0xC000_023D, 1232), // STATUS_HOST_UNREACHABLE
+    (0xC000_0240, 1235), // STATUS_REQUEST_ABORTED
+    (0xC000_026E, 21),   // STATUS_VOLUME_DISMOUNTED
+    (0xC000_0275, 4390), // STATUS_NOT_A_REPARSE_POINT
+];
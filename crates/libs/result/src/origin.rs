@@ -0,0 +1,75 @@
+use super::*;
+use core::cell::Cell;
+use core::panic::Location;
+use std::backtrace::Backtrace;
+use std::sync::{PoisonError, RwLock};
+
+/// The point at which an [`Error`] was created.
+///
+/// This is recorded for every failure when the `origin` feature is enabled.
+#[derive(Debug)]
+pub struct Origin {
+    location: &'static Location<'static>,
+    backtrace: Backtrace,
+}
+
+impl Origin {
+    pub(crate) fn capture(location: &'static Location<'static>) -> Self {
+        Self {
+            location,
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    /// The source location of the code that created the error.
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+
+    /// The stack at the point the error was created.
+    ///
+    /// This is only captured if backtraces are enabled with the `RUST_BACKTRACE` or
+    /// `RUST_LIB_BACKTRACE` environment variables, as described by [`Backtrace::capture`].
+    pub fn backtrace(&self) -> &Backtrace {
+        &self.backtrace
+    }
+}
+
+/// A function that is called for every failure.
+pub type FailureCallback = fn(&Error, &Location<'_>);
+
+static CALLBACK: RwLock<Option<FailureCallback>> = RwLock::new(None);
+
+std::thread_local! {
+    static IN_CALLBACK: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Installs a process-wide callback that is called whenever an [`Error`] is created for a
+/// failure, replacing any previous callback.
+///
+/// This provides a single place to log failures or collect metrics. Errors created by the
+/// callback itself are not reported to it.
+pub fn set_failure_callback(callback: FailureCallback) {
+    *CALLBACK.write().unwrap_or_else(PoisonError::into_inner) = Some(callback);
+}
+
+pub(crate) fn notify(error: &Error, location: &Location<'_>) {
+    let Some(callback) = *CALLBACK.read().unwrap_or_else(PoisonError::into_inner) else {
+        return;
+    };
+
+    if IN_CALLBACK.with(|in_callback| in_callback.replace(true)) {
+        return;
+    }
+
+    struct Reset;
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            IN_CALLBACK.with(|in_callback| in_callback.set(false));
+        }
+    }
+
+    let _reset = Reset;
+    callback(error, location);
+}
@@ -27,6 +27,7 @@ impl RPC_STATUS {
 
     /// Converts the [`RPC_STATUS`] to [`Result<()>`][Result<_>].
     #[inline]
+    #[cfg_attr(feature = "origin", track_caller)]
     pub fn ok(self) -> Result<()> {
         self.to_hresult().ok()
     }
@@ -39,8 +40,9 @@ impl From<RPC_STATUS> for HRESULT {
 }
 
 impl From<RPC_STATUS> for Error {
+    #[cfg_attr(feature = "origin", track_caller)]
     fn from(value: RPC_STATUS) -> Self {
-        value.to_hresult().into()
+        Self::from(value.to_hresult())
     }
 }
 
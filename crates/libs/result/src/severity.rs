@@ -0,0 +1,15 @@
+/// The severity of an [`HRESULT`](crate::HRESULT) or [`NTSTATUS`](crate::NTSTATUS) value.
+///
+/// An `HRESULT` is either a success or an error, while an `NTSTATUS` may also be informational
+/// or a warning.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Severity {
+    /// The operation succeeded.
+    Success,
+    /// The operation succeeded with additional information.
+    Informational,
+    /// The operation completed with a warning.
+    Warning,
+    /// The operation failed.
+    Error,
+}
@@ -41,10 +41,24 @@ impl WIN32_ERROR {
     }
 
     #[inline]
+    #[cfg_attr(feature = "origin", track_caller)]
     pub fn ok(self) -> Result<()> {
         self.to_hresult().ok()
     }
 
+    /// The error message describing the error.
+    pub fn message(self) -> String {
+        self.to_hresult().message()
+    }
+
+    /// The symbolic name of the error code, such as `ERROR_FILE_NOT_FOUND`.
+    ///
+    /// Returns [`None`] if the code isn't one of the common codes known to this crate.
+    #[cfg(feature = "names")]
+    pub fn name(self) -> Option<&'static str> {
+        names::win32_error(self).map(|(_, name, _)| *name)
+    }
+
     /// Creates a new `WIN32_ERROR` from the Win32 error code returned by `GetLastError()`.
     pub fn from_thread() -> Self {
         Self(unsafe { GetLastError() })
@@ -58,8 +72,9 @@ impl From<WIN32_ERROR> for HRESULT {
 }
 
 impl From<WIN32_ERROR> for Error {
+    #[cfg_attr(feature = "origin", track_caller)]
     fn from(value: WIN32_ERROR) -> Self {
-        value.to_hresult().into()
+        Self::from(value.to_hresult())
     }
 }
 
@@ -21,7 +21,7 @@ impl BSTR {
         }
 
         let result = unsafe {
-            Self(bindings::SysAllocStringLen(
+            Self(alloc_string_len(
                 value.as_ptr(),
                 value.len().try_into().unwrap(),
             ))
@@ -59,7 +59,7 @@ impl Deref for BSTR {
         let len = if self.0.is_null() {
             0
         } else {
-            unsafe { bindings::SysStringLen(self.0) as usize }
+            unsafe { string_len(self.0) as usize }
         };
 
         if len > 0 {
@@ -155,7 +155,58 @@ impl<T: AsRef<str> + ?Sized> PartialEq<T> for BSTR {
 impl Drop for BSTR {
     fn drop(&mut self) {
         if !self.0.is_null() {
-            unsafe { bindings::SysFreeString(self.0) }
+            unsafe { free_string(self.0) }
         }
     }
 }
+
+// On Windows, `BSTR` values are allocated by the system so that they can be passed to and from
+// other components. Elsewhere they use the Rust global allocator with the same layout: a 32-bit
+// byte length prefix followed by the characters and a terminating null character.
+
+#[cfg(windows)]
+use bindings::{
+    SysAllocStringLen as alloc_string_len, SysFreeString as free_string, SysStringLen as string_len,
+};
+
+#[cfg(not(windows))]
+const PREFIX: usize = core::mem::size_of::<u32>();
+
+#[cfg(not(windows))]
+fn layout(len: u32) -> core::alloc::Layout {
+    let bytes = PREFIX + 2 * (len as usize + 1);
+    core::alloc::Layout::from_size_align(bytes, core::mem::align_of::<u32>())
+        .expect("allocation failed")
+}
+
+#[cfg(not(windows))]
+unsafe fn alloc_string_len(value: *const u16, len: u32) -> *const u16 {
+    let bytes = len.checked_mul(2).expect("allocation failed");
+
+    unsafe {
+        let prefix = alloc::alloc::alloc(layout(len)) as *mut u32;
+
+        if prefix.is_null() {
+            return core::ptr::null();
+        }
+
+        prefix.write(bytes);
+        let data = prefix.add(1) as *mut u16;
+        core::ptr::copy_nonoverlapping(value, data, len as usize);
+        data.add(len as usize).write(0);
+        data
+    }
+}
+
+#[cfg(not(windows))]
+unsafe fn string_len(value: *const u16) -> u32 {
+    unsafe { *(value as *const u32).sub(1) / 2 }
+}
+
+#[cfg(not(windows))]
+unsafe fn free_string(value: *const u16) {
+    unsafe {
+        let len = string_len(value);
+        alloc::alloc::dealloc((value as *mut u32).sub(1) as *mut u8, layout(len));
+    }
+}
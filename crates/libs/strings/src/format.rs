@@ -0,0 +1,70 @@
+use super::*;
+
+/// Creates an `HSTRING` using interpolation of runtime expressions.
+///
+/// This is like `format!` but encodes UTF-16 directly into an [`HStringBuilder`] as the text is
+/// formatted, rather than formatting into a `String` and then copying it.
+///
+/// # Panics
+///
+/// Panics if a formatting trait implementation returns an error.
+#[macro_export]
+macro_rules! format_h {
+    ($($arg:tt)*) => {
+        $crate::format_hstring(::core::format_args!($($arg)*))
+    };
+}
+
+/// Creates a [`WideString`] using interpolation of runtime expressions.
+///
+/// This is like `format!` but encodes UTF-16 directly as the text is formatted, rather than
+/// formatting into a `String` and then copying it. Since a `WideString` can't contain a null
+/// character, this returns a [`NulError`] with the position of the first one that the formatted
+/// text contains.
+///
+/// # Panics
+///
+/// Panics if a formatting trait implementation returns an error.
+#[macro_export]
+macro_rules! format_w {
+    ($($arg:tt)*) => {
+        $crate::format_wide_string(::core::format_args!($($arg)*))
+    };
+}
+
+#[doc(hidden)]
+pub fn format_hstring(args: core::fmt::Arguments) -> HSTRING {
+    if let Some(value) = args.as_str() {
+        return value.into();
+    }
+
+    let mut builder = HStringBuilder::new(0);
+    core::fmt::Write::write_fmt(&mut builder, args)
+        .expect("a formatting trait implementation returned an error");
+    builder.into()
+}
+
+#[doc(hidden)]
+pub fn format_wide_string(
+    args: core::fmt::Arguments,
+) -> core::result::Result<WideString, NulError> {
+    struct Writer(WideString, Option<NulError>);
+
+    impl core::fmt::Write for Writer {
+        fn write_str(&mut self, value: &str) -> core::fmt::Result {
+            self.0.push_str(value).map_err(|error| {
+                self.1 = Some(error);
+                core::fmt::Error
+            })
+        }
+    }
+
+    let mut writer = Writer(WideString::new(), None);
+
+    match core::fmt::Write::write_fmt(&mut writer, args) {
+        Ok(()) => Ok(writer.0),
+        Err(_) => Err(writer
+            .1
+            .expect("a formatting trait implementation returned an error")),
+    }
+}
@@ -25,9 +25,19 @@ impl HSTRING {
     }
 
     /// Get the contents of this `HSTRING` as a OsString.
+    ///
+    /// On targets other than Windows, unpaired surrogates are replaced with `U+FFFD`.
     #[cfg(feature = "std")]
     pub fn to_os_string(&self) -> std::ffi::OsString {
-        std::os::windows::ffi::OsStringExt::from_wide(self)
+        #[cfg(windows)]
+        {
+            std::os::windows::ffi::OsStringExt::from_wide(self)
+        }
+
+        #[cfg(not(windows))]
+        {
+            self.to_string_lossy().into()
+        }
     }
 
     /// Allow this string to be displayed.
@@ -35,6 +45,20 @@ impl HSTRING {
         Decode(move || core::char::decode_utf16(self.iter().cloned()))
     }
 
+    /// Returns `true` if the strings are equal when compared ordinally, ignoring case.
+    ///
+    /// See [`eq_ignore_case`] for details.
+    pub fn eq_ignore_case(&self, other: &[u16]) -> bool {
+        eq_ignore_case(self, other)
+    }
+
+    /// Compares the strings ordinally, ignoring case.
+    ///
+    /// See [`cmp_ignore_case`] for details.
+    pub fn cmp_ignore_case(&self, other: &[u16]) -> core::cmp::Ordering {
+        cmp_ignore_case(self, other)
+    }
+
     /// # Safety
     /// len must not be less than the number of items in the iterator.
     unsafe fn from_wide_iter<I: Iterator<Item = u16>>(iter: I, len: usize) -> Self {
@@ -149,12 +173,18 @@ impl From<&std::path::Path> for HSTRING {
 #[cfg(feature = "std")]
 impl From<&std::ffi::OsStr> for HSTRING {
     fn from(value: &std::ffi::OsStr) -> Self {
+        #[cfg(windows)]
         unsafe {
             Self::from_wide_iter(
                 std::os::windows::ffi::OsStrExt::encode_wide(value),
                 value.len(),
             )
         }
+
+        #[cfg(not(windows))]
+        {
+            value.to_string_lossy().as_ref().into()
+        }
     }
 }
 
@@ -294,9 +324,17 @@ impl PartialEq<&std::ffi::OsString> for HSTRING {
 #[cfg(feature = "std")]
 impl PartialEq<std::ffi::OsStr> for HSTRING {
     fn eq(&self, other: &std::ffi::OsStr) -> bool {
-        self.iter()
-            .copied()
-            .eq(std::os::windows::ffi::OsStrExt::encode_wide(other))
+        #[cfg(windows)]
+        {
+            self.iter()
+                .copied()
+                .eq(std::os::windows::ffi::OsStrExt::encode_wide(other))
+        }
+
+        #[cfg(not(windows))]
+        {
+            other.to_str().is_some_and(|other| *self == *other)
+        }
     }
 }
 
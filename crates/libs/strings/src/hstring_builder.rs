@@ -4,7 +4,13 @@ use super::*;
 ///
 /// This is similar to the `WindowsPreallocateStringBuffer` function but implemented directly in Rust for efficiency.
 /// It is implemented as a separate type since [HSTRING] values are immutable.
-pub struct HStringBuilder(*mut HStringHeader);
+///
+/// The builder also implements [`core::fmt::Write`], growing as needed, which is how the
+/// [`format_h!`] macro builds an `HSTRING` without an intermediate `String`.
+pub struct HStringBuilder {
+    header: *mut HStringHeader,
+    capacity: usize,
+}
 
 impl HStringBuilder {
     /// Creates a preallocated `HSTRING` value.
@@ -15,7 +21,59 @@ impl HStringBuilder {
             unsafe { core::ptr::write_bytes((*header).data, 0, len) };
         }
 
-        Self(header)
+        Self {
+            header,
+            capacity: len,
+        }
+    }
+
+    /// Creates an empty builder with space for at least `capacity` characters.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut builder = Self::new(0);
+        builder.reserve(capacity);
+        builder
+    }
+
+    /// Reserves space for at least `additional` more characters.
+    pub fn reserve(&mut self, additional: usize) {
+        let len = self.len();
+        let required = len.checked_add(additional).expect("capacity overflow");
+
+        if required <= self.capacity {
+            return;
+        }
+
+        let capacity = required.max(self.capacity * 2);
+        let header = HStringHeader::alloc(capacity.try_into().unwrap());
+
+        unsafe {
+            if let Some(previous) = self.as_header() {
+                core::ptr::copy_nonoverlapping(previous.data, (*header).data, len);
+            }
+
+            (*header).len = len as u32;
+            HStringHeader::free(self.header);
+        }
+
+        self.header = header;
+        self.capacity = capacity;
+    }
+
+    /// Appends the 16-bit characters (wchars) to the end of the string, growing it as needed.
+    pub fn push_wide<I: IntoIterator<Item = u16>>(&mut self, value: I) {
+        for wide in value {
+            if self.len() == self.capacity {
+                self.reserve(1);
+            }
+
+            let header = self.as_header_mut().unwrap();
+
+            unsafe {
+                header.data.add(header.len as usize).write(wide);
+            }
+
+            header.len += 1;
+        }
     }
 
     /// Shortens the string by removing any trailing 0 characters.
@@ -29,9 +87,10 @@ impl HStringBuilder {
 
             if header.len == 0 {
                 unsafe {
-                    HStringHeader::free(self.0);
+                    HStringHeader::free(self.header);
                 }
-                self.0 = core::ptr::null_mut();
+                self.header = core::ptr::null_mut();
+                self.capacity = 0;
             }
         }
     }
@@ -48,19 +107,20 @@ impl HStringBuilder {
     }
 
     fn as_header(&self) -> Option<&HStringHeader> {
-        unsafe { self.0.as_ref() }
+        unsafe { self.header.as_ref() }
     }
 
     fn as_header_mut(&mut self) -> Option<&mut HStringHeader> {
-        unsafe { self.0.as_mut() }
+        unsafe { self.header.as_mut() }
     }
 }
 
 impl From<HStringBuilder> for HSTRING {
     fn from(value: HStringBuilder) -> Self {
-        if let Some(header) = value.as_header() {
+        // An empty `HSTRING` is always null, even if the builder has reserved space.
+        if let Some(header) = value.as_header().filter(|header| header.len > 0) {
             unsafe { header.data.offset(header.len as isize).write(0) };
-            let result = Self(value.0);
+            let result = Self(value.header);
             core::mem::forget(value);
             result
         } else {
@@ -94,11 +154,20 @@ impl core::ops::DerefMut for HStringBuilder {
 impl Drop for HStringBuilder {
     fn drop(&mut self) {
         unsafe {
-            HStringHeader::free(self.0);
+            HStringHeader::free(self.header);
         }
     }
 }
 
+impl core::fmt::Write for HStringBuilder {
+    fn write_str(&mut self, value: &str) -> core::fmt::Result {
+        // Each UTF-8 byte produces at most one UTF-16 character.
+        self.reserve(value.len());
+        self.push_wide(value.encode_utf16());
+        Ok(())
+    }
+}
+
 impl core::fmt::Debug for HStringBuilder {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(
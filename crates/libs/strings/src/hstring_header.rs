@@ -23,9 +23,13 @@ impl HStringHeader {
         // The space for the terminating null character is already accounted for inside of `HStringHeader`.
         let bytes = core::mem::size_of::<Self>() + 2 * len as usize;
 
+        #[cfg(windows)]
         let header =
             unsafe { bindings::HeapAlloc(bindings::GetProcessHeap(), 0, bytes) } as *mut Self;
 
+        #[cfg(not(windows))]
+        let header = unsafe { alloc::alloc::alloc(Self::layout(bytes)) } as *mut Self;
+
         if header.is_null() {
             panic!("allocation failed");
         }
@@ -34,6 +38,11 @@ impl HStringHeader {
             // Use `ptr::write` (since `header` is uninitialized). `HStringHeader` is safe to be all zeros.
             header.write(core::mem::MaybeUninit::<Self>::zeroed().assume_init());
             (*header).len = len;
+            // `len` may later shrink, so the reserved field records the allocation size for `free`.
+            #[cfg(not(windows))]
+            {
+                (*header)._0 = len;
+            }
             (*header).count = RefCount::new(1);
             (*header).data = &mut (*header).buffer_start;
         }
@@ -46,9 +55,22 @@ impl HStringHeader {
             return;
         }
 
+        #[cfg(windows)]
         unsafe {
             bindings::HeapFree(bindings::GetProcessHeap(), 0, header as *mut _);
         }
+
+        #[cfg(not(windows))]
+        unsafe {
+            let bytes = core::mem::size_of::<Self>() + 2 * (*header)._0 as usize;
+            alloc::alloc::dealloc(header as *mut u8, Self::layout(bytes));
+        }
+    }
+
+    #[cfg(not(windows))]
+    fn layout(bytes: usize) -> core::alloc::Layout {
+        core::alloc::Layout::from_size_align(bytes, core::mem::align_of::<Self>())
+            .expect("allocation failed")
     }
 
     pub fn duplicate(&self) -> *mut Self {
@@ -0,0 +1,73 @@
+use super::*;
+use core::cell::Cell;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+/// A "fast pass" `HSTRING` that refers to a caller-owned buffer rather than copying it.
+///
+/// The header lives inside the `HStringReference` itself, so no memory is allocated. This is the
+/// equivalent of `WindowsCreateStringReference` and may be passed wherever an `HSTRING` parameter
+/// is expected. A callee that needs to keep the string copies it when it duplicates the `HSTRING`.
+pub struct HStringReference<'a> {
+    header: HStringHeader,
+    hstring: Cell<*mut HStringHeader>,
+    _marker: PhantomData<&'a [u16]>,
+}
+
+impl<'a> HStringReference<'a> {
+    /// Creates a reference to a slice of 16-bit characters (wchars) that ends with a null
+    /// character, or `None` if the slice isn't null-terminated or is too long for an `HSTRING`.
+    pub fn from_wide_with_nul(value: &'a [u16]) -> Option<Self> {
+        let (0, wide) = value.split_last()? else {
+            return None;
+        };
+
+        let header = HStringHeader {
+            flags: HSTRING_REFERENCE_FLAG,
+            len: wide.len().try_into().ok()?,
+            _0: 0,
+            _1: 0,
+            data: value.as_ptr() as *mut u16,
+            count: RefCount::new(0),
+            buffer_start: 0,
+        };
+
+        Some(Self {
+            header,
+            hstring: Cell::new(core::ptr::null_mut()),
+            _marker: PhantomData,
+        })
+    }
+
+    /// Get the string as 16-bit characters (wchars) without the trailing null character.
+    pub fn as_wide(&self) -> &'a [u16] {
+        unsafe { core::slice::from_raw_parts(self.header.data, self.header.len as usize) }
+    }
+}
+
+impl Deref for HStringReference<'_> {
+    type Target = HSTRING;
+
+    fn deref(&self) -> &HSTRING {
+        // The `HSTRING` points to the header and so is refreshed on every borrow in case this
+        // value has moved since it was last borrowed. An empty `HSTRING` is always null.
+        if self.header.len != 0 {
+            self.hstring
+                .set(&self.header as *const HStringHeader as *mut HStringHeader);
+        }
+
+        unsafe { &*(self.hstring.as_ptr() as *const HSTRING) }
+    }
+}
+
+impl<'a> From<&'a WideStr> for HStringReference<'a> {
+    fn from(value: &'a WideStr) -> Self {
+        Self::from_wide_with_nul(value.as_wide_with_nul()).unwrap()
+    }
+}
+
+impl core::fmt::Debug for HStringReference<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.display())
+    }
+}
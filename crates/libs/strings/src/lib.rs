@@ -1,5 +1,4 @@
 #![doc = include_str!("../readme.md")]
-#![cfg(windows)]
 #![debugger_visualizer(natvis_file = "../windows-strings.natvis")]
 #![cfg_attr(all(not(feature = "std")), no_std)]
 
@@ -9,15 +8,22 @@ use alloc::string::String;
 mod bstr;
 pub use bstr::*;
 
+mod case;
+pub use case::*;
+
 mod hstring;
 pub use hstring::*;
 
 mod hstring_builder;
 pub use hstring_builder::*;
 
+mod hstring_reference;
+pub use hstring_reference::*;
+
 mod hstring_header;
 use hstring_header::*;
 
+#[cfg(windows)]
 mod bindings;
 
 mod decode;
@@ -29,6 +35,9 @@ use ref_count::*;
 mod literals;
 pub use literals::*;
 
+mod format;
+pub use format::*;
+
 mod pcstr;
 pub use pcstr::*;
 
@@ -41,6 +50,12 @@ pub use pstr::*;
 mod pwstr;
 pub use pwstr::*;
 
+mod wide_str;
+pub use wide_str::*;
+
+mod wide_string;
+pub use wide_string::*;
+
 unsafe extern "C" {
     fn strlen(s: PCSTR) -> usize;
 }
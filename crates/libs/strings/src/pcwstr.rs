@@ -32,10 +32,23 @@ impl PCWSTR {
     ///
     /// The `PCWSTR`'s pointer needs to be valid for reads up until and including the next `\0`.
     pub unsafe fn len(&self) -> usize {
-        unsafe extern "C" {
-            fn wcslen(s: *const u16) -> usize;
+        // `wchar_t` is only 16 bits wide on Windows, so `wcslen` can't be used elsewhere.
+        #[cfg(windows)]
+        {
+            unsafe extern "C" {
+                fn wcslen(s: *const u16) -> usize;
+            }
+            unsafe { wcslen(self.0) }
+        }
+
+        #[cfg(not(windows))]
+        {
+            let mut len = 0;
+            while unsafe { *self.0.add(len) } != 0 {
+                len += 1;
+            }
+            len
         }
-        unsafe { wcslen(self.0) }
     }
 
     /// Returns `true` if the string length is zero, and `false` otherwise.
@@ -82,6 +95,24 @@ impl PCWSTR {
     pub unsafe fn display(&self) -> impl core::fmt::Display + '_ {
         unsafe { Decode(move || core::char::decode_utf16(self.as_wide().iter().cloned())) }
     }
+
+    /// Returns `true` if the strings are equal when compared ordinally, ignoring case.
+    ///
+    /// # Safety
+    ///
+    /// See the safety information for `PCWSTR::as_wide`. This applies to both strings.
+    pub unsafe fn eq_ignore_case(&self, other: PCWSTR) -> bool {
+        unsafe { eq_ignore_case(self.as_wide(), other.as_wide()) }
+    }
+
+    /// Compares the strings ordinally, ignoring case.
+    ///
+    /// # Safety
+    ///
+    /// See the safety information for `PCWSTR::as_wide`. This applies to both strings.
+    pub unsafe fn cmp_ignore_case(&self, other: PCWSTR) -> core::cmp::Ordering {
+        unsafe { cmp_ignore_case(self.as_wide(), other.as_wide()) }
+    }
 }
 
 impl Default for PCWSTR {
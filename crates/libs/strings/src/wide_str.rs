@@ -0,0 +1,204 @@
+use super::*;
+
+/// A borrowed null-terminated string of 16-bit Unicode characters.
+///
+/// This is to [`WideString`] what `CStr` is to `CString`. The string has no interior null
+/// characters so it may be passed to any API expecting a `PCWSTR`.
+#[repr(transparent)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WideStr([u16]);
+
+impl WideStr {
+    /// Wraps a raw null-terminated string.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be non-null and valid for reads up until and including the next `\0`,
+    /// and the string must not be modified for the returned lifetime.
+    pub unsafe fn from_ptr<'a>(ptr: *const u16) -> &'a Self {
+        unsafe {
+            let len = PCWSTR(ptr).len();
+            Self::from_wide_with_nul_unchecked(core::slice::from_raw_parts(ptr, len + 1))
+        }
+    }
+
+    /// Creates a `WideStr` from a slice of 16-bit characters up to and including the first null
+    /// character, or `None` if the slice doesn't contain a null character.
+    pub fn from_wide_until_nul(value: &[u16]) -> Option<&Self> {
+        let len = value.iter().position(|c| *c == 0)?;
+        Some(unsafe { Self::from_wide_with_nul_unchecked(&value[..=len]) })
+    }
+
+    /// Creates a `WideStr` from a slice of 16-bit characters without checking it.
+    ///
+    /// # Safety
+    ///
+    /// The slice must end with the only null character that it contains.
+    pub const unsafe fn from_wide_with_nul_unchecked(value: &[u16]) -> &Self {
+        unsafe { &*(value as *const [u16] as *const Self) }
+    }
+
+    /// Returns a `PCWSTR` that points to this string.
+    ///
+    /// The pointer is only valid for as long as this string is borrowed.
+    pub const fn as_pcwstr(&self) -> PCWSTR {
+        PCWSTR(self.0.as_ptr())
+    }
+
+    /// Returns a raw pointer to the string.
+    pub const fn as_ptr(&self) -> *const u16 {
+        self.0.as_ptr()
+    }
+
+    /// Get the string as 16-bit characters (wchars) without the trailing null character.
+    pub fn as_wide(&self) -> &[u16] {
+        &self.0[..self.len()]
+    }
+
+    /// Get the string as 16-bit characters (wchars) including the trailing null character.
+    pub const fn as_wide_with_nul(&self) -> &[u16] {
+        &self.0
+    }
+
+    /// String length without the trailing null character.
+    pub const fn len(&self) -> usize {
+        self.0.len() - 1
+    }
+
+    /// Returns `true` if the string length is zero, and `false` otherwise.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the 16-bit characters (wchars) of the string.
+    pub fn iter(&self) -> core::iter::Copied<core::slice::Iter<'_, u16>> {
+        self.as_wide().iter().copied()
+    }
+
+    /// Returns an iterator that decodes the string, yielding an error for each unpaired surrogate.
+    pub fn chars(&self) -> core::char::DecodeUtf16<core::iter::Copied<core::slice::Iter<'_, u16>>> {
+        core::char::decode_utf16(self.iter())
+    }
+
+    /// Copy the string into a Rust `String`.
+    pub fn to_string(&self) -> core::result::Result<String, alloc::string::FromUtf16Error> {
+        String::from_utf16(self.as_wide())
+    }
+
+    /// Copy the string into a Rust `String` lossily.
+    pub fn to_string_lossy(&self) -> String {
+        String::from_utf16_lossy(self.as_wide())
+    }
+
+    /// Copy the string into an `OsString`.
+    ///
+    /// On targets other than Windows, unpaired surrogates are replaced with `U+FFFD`.
+    #[cfg(feature = "std")]
+    pub fn to_os_string(&self) -> std::ffi::OsString {
+        #[cfg(windows)]
+        {
+            std::os::windows::ffi::OsStringExt::from_wide(self.as_wide())
+        }
+
+        #[cfg(not(windows))]
+        {
+            self.to_string_lossy().into()
+        }
+    }
+
+    /// Copy the string into an `HSTRING`.
+    pub fn to_hstring(&self) -> HSTRING {
+        HSTRING::from_wide(self.as_wide())
+    }
+
+    /// Allow this string to be displayed.
+    pub fn display(&self) -> impl core::fmt::Display + '_ {
+        Decode(move || self.chars())
+    }
+}
+
+impl Default for &WideStr {
+    fn default() -> Self {
+        unsafe { WideStr::from_wide_with_nul_unchecked(&[0]) }
+    }
+}
+
+impl AsRef<WideStr> for WideStr {
+    fn as_ref(&self) -> &WideStr {
+        self
+    }
+}
+
+impl AsRef<[u16]> for WideStr {
+    fn as_ref(&self) -> &[u16] {
+        self.as_wide()
+    }
+}
+
+impl<'a> IntoIterator for &'a WideStr {
+    type Item = u16;
+    type IntoIter = core::iter::Copied<core::slice::Iter<'a, u16>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl alloc::borrow::ToOwned for WideStr {
+    type Owned = WideString;
+
+    fn to_owned(&self) -> WideString {
+        unsafe { WideString::from_vec_with_nul_unchecked(self.0.into()) }
+    }
+}
+
+impl core::fmt::Debug for WideStr {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.display())
+    }
+}
+
+impl PartialEq<str> for WideStr {
+    fn eq(&self, other: &str) -> bool {
+        self.iter().eq(other.encode_utf16())
+    }
+}
+
+impl PartialEq<&str> for WideStr {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<WideStr> for str {
+    fn eq(&self, other: &WideStr) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<WideStr> for &str {
+    fn eq(&self, other: &WideStr) -> bool {
+        other == *self
+    }
+}
+
+impl TryFrom<&WideStr> for String {
+    type Error = alloc::string::FromUtf16Error;
+
+    fn try_from(value: &WideStr) -> core::result::Result<Self, Self::Error> {
+        value.to_string()
+    }
+}
+
+impl From<&WideStr> for HSTRING {
+    fn from(value: &WideStr) -> Self {
+        value.to_hstring()
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<&WideStr> for std::ffi::OsString {
+    fn from(value: &WideStr) -> Self {
+        value.to_os_string()
+    }
+}
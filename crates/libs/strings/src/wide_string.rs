@@ -0,0 +1,306 @@
+use super::*;
+use alloc::borrow::ToOwned;
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+/// An owned null-terminated string of 16-bit Unicode characters.
+///
+/// This is to [`WideStr`] what `CString` is to `CStr` and is the buffer to use with Win32 APIs
+/// that take a `PCWSTR`. Construction fails if the value contains an interior null character, as
+/// the string would otherwise be silently truncated. Use [`format_w!`] to build one from formatted
+/// text.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WideString(Vec<u16>);
+
+impl WideString {
+    /// Create an empty `WideString`.
+    pub fn new() -> Self {
+        Self(alloc::vec![0])
+    }
+
+    /// Create a `WideString` from a slice of 16-bit characters (wchars) without a trailing null
+    /// character.
+    pub fn from_wide(value: &[u16]) -> core::result::Result<Self, NulError> {
+        Self::from_wide_iter(value.iter().copied(), value.len())
+    }
+
+    /// Create a `WideString` from a vector of 16-bit characters (wchars) without checking it.
+    ///
+    /// # Safety
+    ///
+    /// The vector must end with the only null character that it contains.
+    pub unsafe fn from_vec_with_nul_unchecked(value: Vec<u16>) -> Self {
+        Self(value)
+    }
+
+    /// Copies a raw null-terminated string.
+    ///
+    /// # Safety
+    ///
+    /// See the safety information for `WideStr::from_ptr`.
+    pub unsafe fn from_ptr(ptr: *const u16) -> Self {
+        unsafe { WideStr::from_ptr(ptr).to_owned() }
+    }
+
+    fn from_wide_iter<I: Iterator<Item = u16>>(
+        iter: I,
+        len: usize,
+    ) -> core::result::Result<Self, NulError> {
+        let mut value = Vec::with_capacity(len + 1);
+
+        for c in iter {
+            if c == 0 {
+                return Err(NulError(value.len()));
+            }
+
+            value.push(c);
+        }
+
+        value.push(0);
+        Ok(Self(value))
+    }
+
+    /// Appends the string, or returns an error and leaves this string unchanged if it contains a
+    /// null character.
+    pub fn push_str(&mut self, value: &str) -> core::result::Result<(), NulError> {
+        let len = self.len();
+
+        if let Some(position) = value.encode_utf16().position(|c| c == 0) {
+            return Err(NulError(len + position));
+        }
+
+        self.0.pop();
+        self.0.extend(value.encode_utf16());
+        self.0.push(0);
+        Ok(())
+    }
+
+    /// Returns the borrowed string.
+    pub fn as_wide_str(&self) -> &WideStr {
+        unsafe { WideStr::from_wide_with_nul_unchecked(&self.0) }
+    }
+
+    /// Returns the characters including the trailing null character.
+    pub fn into_vec_with_nul(self) -> Vec<u16> {
+        self.0
+    }
+
+    /// Returns the characters without the trailing null character.
+    pub fn into_vec(mut self) -> Vec<u16> {
+        self.0.pop();
+        self.0
+    }
+}
+
+impl Default for WideString {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deref for WideString {
+    type Target = WideStr;
+
+    fn deref(&self) -> &WideStr {
+        self.as_wide_str()
+    }
+}
+
+impl AsRef<WideStr> for WideString {
+    fn as_ref(&self) -> &WideStr {
+        self
+    }
+}
+
+impl core::borrow::Borrow<WideStr> for WideString {
+    fn borrow(&self) -> &WideStr {
+        self
+    }
+}
+
+impl<'a> IntoIterator for &'a WideString {
+    type Item = u16;
+    type IntoIter = core::iter::Copied<core::slice::Iter<'a, u16>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl core::fmt::Write for WideString {
+    fn write_str(&mut self, value: &str) -> core::fmt::Result {
+        // A null character would silently truncate the string, so the text is rejected.
+        self.push_str(value).map_err(|_| core::fmt::Error)
+    }
+}
+
+impl core::fmt::Debug for WideString {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.display())
+    }
+}
+
+impl From<&WideStr> for WideString {
+    fn from(value: &WideStr) -> Self {
+        value.to_owned()
+    }
+}
+
+impl TryFrom<&str> for WideString {
+    type Error = NulError;
+
+    fn try_from(value: &str) -> core::result::Result<Self, Self::Error> {
+        Self::from_wide_iter(value.encode_utf16(), value.len())
+    }
+}
+
+impl TryFrom<String> for WideString {
+    type Error = NulError;
+
+    fn try_from(value: String) -> core::result::Result<Self, Self::Error> {
+        value.as_str().try_into()
+    }
+}
+
+impl TryFrom<&String> for WideString {
+    type Error = NulError;
+
+    fn try_from(value: &String) -> core::result::Result<Self, Self::Error> {
+        value.as_str().try_into()
+    }
+}
+
+impl TryFrom<&HSTRING> for WideString {
+    type Error = NulError;
+
+    fn try_from(value: &HSTRING) -> core::result::Result<Self, Self::Error> {
+        Self::from_wide(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<&std::ffi::OsStr> for WideString {
+    type Error = NulError;
+
+    // On targets other than Windows, invalid UTF-8 is replaced with `U+FFFD`.
+    fn try_from(value: &std::ffi::OsStr) -> core::result::Result<Self, Self::Error> {
+        #[cfg(windows)]
+        {
+            Self::from_wide_iter(
+                std::os::windows::ffi::OsStrExt::encode_wide(value),
+                value.len(),
+            )
+        }
+
+        #[cfg(not(windows))]
+        {
+            value.to_string_lossy().as_ref().try_into()
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<std::ffi::OsString> for WideString {
+    type Error = NulError;
+
+    fn try_from(value: std::ffi::OsString) -> core::result::Result<Self, Self::Error> {
+        value.as_os_str().try_into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<&std::ffi::OsString> for WideString {
+    type Error = NulError;
+
+    fn try_from(value: &std::ffi::OsString) -> core::result::Result<Self, Self::Error> {
+        value.as_os_str().try_into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<&std::path::Path> for WideString {
+    type Error = NulError;
+
+    fn try_from(value: &std::path::Path) -> core::result::Result<Self, Self::Error> {
+        value.as_os_str().try_into()
+    }
+}
+
+impl TryFrom<&WideString> for String {
+    type Error = alloc::string::FromUtf16Error;
+
+    fn try_from(value: &WideString) -> core::result::Result<Self, Self::Error> {
+        value.to_string()
+    }
+}
+
+impl TryFrom<WideString> for String {
+    type Error = alloc::string::FromUtf16Error;
+
+    fn try_from(value: WideString) -> core::result::Result<Self, Self::Error> {
+        value.to_string()
+    }
+}
+
+impl From<&WideString> for HSTRING {
+    fn from(value: &WideString) -> Self {
+        value.to_hstring()
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<WideString> for std::ffi::OsString {
+    fn from(value: WideString) -> Self {
+        value.to_os_string()
+    }
+}
+
+impl PartialEq<str> for WideString {
+    fn eq(&self, other: &str) -> bool {
+        **self == *other
+    }
+}
+
+impl PartialEq<&str> for WideString {
+    fn eq(&self, other: &&str) -> bool {
+        **self == **other
+    }
+}
+
+impl PartialEq<WideString> for &str {
+    fn eq(&self, other: &WideString) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<WideStr> for WideString {
+    fn eq(&self, other: &WideStr) -> bool {
+        **self == *other
+    }
+}
+
+impl PartialEq<WideString> for WideStr {
+    fn eq(&self, other: &WideString) -> bool {
+        *self == **other
+    }
+}
+
+/// The error returned when a string contains an interior null character and so can't be
+/// converted to a [`WideString`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NulError(usize);
+
+impl NulError {
+    /// Returns the position of the null character, in 16-bit characters.
+    pub fn nul_position(&self) -> usize {
+        self.0
+    }
+}
+
+impl core::fmt::Display for NulError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "null character found at position {}", self.0)
+    }
+}
+
+impl core::error::Error for NulError {}
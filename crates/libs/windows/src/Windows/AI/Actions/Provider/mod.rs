@@ -46,6 +46,27 @@ impl IActionFeedbackHandler_Vtbl {
         iid == &<IActionFeedbackHandler as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActionFeedbackHandler_Impl> windows_core::imp::TearOffVtable<Identity> for IActionFeedbackHandler_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ProcessFeedbackAsync<Identity: windows_core::IUnknownImpl + IActionFeedbackHandler_Impl>(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void, feedback: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActionFeedbackHandler_Impl::ProcessFeedbackAsync(this, core::mem::transmute_copy(&context), core::mem::transmute_copy(&feedback)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ProcessFeedbackAsync: ProcessFeedbackAsync::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IActionFeedbackHandler_Vtbl {
@@ -96,6 +117,24 @@ impl IActionProvider_Vtbl {
         iid == &<IActionProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActionProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IActionProvider_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn InvokeAsync<Identity: windows_core::IUnknownImpl + IActionProvider_Impl>(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActionProvider_Impl::InvokeAsync(this, core::mem::transmute_copy(&context)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, InvokeAsync: InvokeAsync::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IActionProvider_Vtbl {
@@ -67,6 +67,24 @@ impl IMcpNamedPipeConnectionServer_Vtbl {
         iid == &<IMcpNamedPipeConnectionServer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMcpNamedPipeConnectionServer_Impl> windows_core::imp::TearOffVtable<Identity> for IMcpNamedPipeConnectionServer_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Connect<Identity: windows_core::IUnknownImpl + IMcpNamedPipeConnectionServer_Impl>(this: *mut core::ffi::c_void, hostcontext: *mut core::ffi::c_void, pipename: *mut core::ffi::c_void, connectionresult: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMcpNamedPipeConnectionServer_Impl::Connect(this, core::mem::transmute_copy(&hostcontext), core::mem::transmute(&pipename), core::mem::transmute_copy(&connectionresult)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Connect: Connect::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IMcpNamedPipeConnectionServer_Vtbl {
@@ -142,6 +160,24 @@ impl IMcpSseConnectionServer_Vtbl {
         iid == &<IMcpSseConnectionServer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMcpSseConnectionServer_Impl> windows_core::imp::TearOffVtable<Identity> for IMcpSseConnectionServer_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Connect<Identity: windows_core::IUnknownImpl + IMcpSseConnectionServer_Impl>(this: *mut core::ffi::c_void, hostcontext: *mut core::ffi::c_void, connectionresult: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMcpSseConnectionServer_Impl::Connect(this, core::mem::transmute_copy(&hostcontext), core::mem::transmute_copy(&connectionresult)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Connect: Connect::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IMcpSseConnectionServer_Vtbl {
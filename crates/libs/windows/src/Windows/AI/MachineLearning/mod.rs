@@ -256,6 +256,67 @@ impl ILearningModelFeatureDescriptor_Vtbl {
         iid == &<ILearningModelFeatureDescriptor as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ILearningModelFeatureDescriptor_Impl> windows_core::imp::TearOffVtable<Identity> for ILearningModelFeatureDescriptor_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + ILearningModelFeatureDescriptor_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILearningModelFeatureDescriptor_Impl::Name(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Description<Identity: windows_core::IUnknownImpl + ILearningModelFeatureDescriptor_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILearningModelFeatureDescriptor_Impl::Description(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Kind<Identity: windows_core::IUnknownImpl + ILearningModelFeatureDescriptor_Impl>(this: *mut core::ffi::c_void, result__: *mut LearningModelFeatureKind) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILearningModelFeatureDescriptor_Impl::Kind(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsRequired<Identity: windows_core::IUnknownImpl + ILearningModelFeatureDescriptor_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILearningModelFeatureDescriptor_Impl::IsRequired(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Name: Name::<Identity>,
+            Description: Description::<Identity>,
+            Kind: Kind::<Identity>,
+            IsRequired: IsRequired::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ILearningModelFeatureDescriptor_Vtbl {
@@ -305,6 +366,23 @@ impl ILearningModelFeatureValue_Vtbl {
         iid == &<ILearningModelFeatureValue as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ILearningModelFeatureValue_Impl> windows_core::imp::TearOffVtable<Identity> for ILearningModelFeatureValue_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Kind<Identity: windows_core::IUnknownImpl + ILearningModelFeatureValue_Impl>(this: *mut core::ffi::c_void, result__: *mut LearningModelFeatureKind) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILearningModelFeatureValue_Impl::Kind(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Kind: Kind::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ILearningModelFeatureValue_Vtbl {
@@ -328,6 +406,9 @@ impl ILearningModelOperatorProvider_Vtbl {
         iid == &<ILearningModelOperatorProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ILearningModelOperatorProvider_Impl> windows_core::imp::TearOffVtable<Identity> for ILearningModelOperatorProvider_Vtbl {
+    const VTABLE: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ILearningModelOperatorProvider_Vtbl {
@@ -534,6 +615,40 @@ impl ITensor_Vtbl {
         iid == &<ITensor as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITensor_Impl> windows_core::imp::TearOffVtable<Identity> for ITensor_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn TensorKind<Identity: windows_core::IUnknownImpl + ITensor_Impl>(this: *mut core::ffi::c_void, result__: *mut TensorKind) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITensor_Impl::TensorKind(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Shape<Identity: windows_core::IUnknownImpl + ITensor_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITensor_Impl::Shape(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            TensorKind: TensorKind::<Identity>,
+            Shape: Shape::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ITensor_Vtbl {
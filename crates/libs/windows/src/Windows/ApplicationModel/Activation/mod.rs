@@ -1872,6 +1872,53 @@ impl IActivatedEventArgs_Vtbl {
         iid == &<IActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Kind<Identity: windows_core::IUnknownImpl + IActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut ActivationKind) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActivatedEventArgs_Impl::Kind(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PreviousExecutionState<Identity: windows_core::IUnknownImpl + IActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut ApplicationExecutionState) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActivatedEventArgs_Impl::PreviousExecutionState(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SplashScreen<Identity: windows_core::IUnknownImpl + IActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActivatedEventArgs_Impl::SplashScreen(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Kind: Kind::<Identity>,
+            PreviousExecutionState: PreviousExecutionState::<Identity>,
+            SplashScreen: SplashScreen::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IActivatedEventArgs_Vtbl {
@@ -1947,6 +1994,25 @@ impl IActivatedEventArgsWithUser_Vtbl {
         iid == &<IActivatedEventArgsWithUser as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "System")]
+impl<Identity: windows_core::IUnknownImpl + IActivatedEventArgsWithUser_Impl> windows_core::imp::TearOffVtable<Identity> for IActivatedEventArgsWithUser_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn User<Identity: windows_core::IUnknownImpl + IActivatedEventArgsWithUser_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActivatedEventArgsWithUser_Impl::User(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, User: User::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IActivatedEventArgsWithUser_Vtbl {
@@ -2021,6 +2087,26 @@ impl IApplicationViewActivatedEventArgs_Vtbl {
         iid == &<IApplicationViewActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IApplicationViewActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IApplicationViewActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CurrentlyShownApplicationViewId<Identity: windows_core::IUnknownImpl + IApplicationViewActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IApplicationViewActivatedEventArgs_Impl::CurrentlyShownApplicationViewId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CurrentlyShownApplicationViewId: CurrentlyShownApplicationViewId::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IApplicationViewActivatedEventArgs_Vtbl {
@@ -2090,6 +2176,24 @@ impl IAppointmentsProviderActivatedEventArgs_Vtbl {
         iid == &<IAppointmentsProviderActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppointmentsProviderActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IAppointmentsProviderActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Verb<Identity: windows_core::IUnknownImpl + IAppointmentsProviderActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppointmentsProviderActivatedEventArgs_Impl::Verb(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Verb: Verb::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAppointmentsProviderActivatedEventArgs_Vtbl {
@@ -2173,6 +2277,28 @@ impl IAppointmentsProviderAddAppointmentActivatedEventArgs_Vtbl {
         iid == &<IAppointmentsProviderAddAppointmentActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "ApplicationModel_Appointments_AppointmentsProvider")]
+impl<Identity: windows_core::IUnknownImpl + IAppointmentsProviderAddAppointmentActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IAppointmentsProviderAddAppointmentActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddAppointmentOperation<Identity: windows_core::IUnknownImpl + IAppointmentsProviderAddAppointmentActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppointmentsProviderAddAppointmentActivatedEventArgs_Impl::AddAppointmentOperation(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddAppointmentOperation: AddAppointmentOperation::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAppointmentsProviderAddAppointmentActivatedEventArgs_Vtbl {
@@ -2259,6 +2385,28 @@ impl IAppointmentsProviderRemoveAppointmentActivatedEventArgs_Vtbl {
         iid == &<IAppointmentsProviderRemoveAppointmentActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "ApplicationModel_Appointments_AppointmentsProvider")]
+impl<Identity: windows_core::IUnknownImpl + IAppointmentsProviderRemoveAppointmentActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IAppointmentsProviderRemoveAppointmentActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn RemoveAppointmentOperation<Identity: windows_core::IUnknownImpl + IAppointmentsProviderRemoveAppointmentActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppointmentsProviderRemoveAppointmentActivatedEventArgs_Impl::RemoveAppointmentOperation(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RemoveAppointmentOperation: RemoveAppointmentOperation::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAppointmentsProviderRemoveAppointmentActivatedEventArgs_Vtbl {
@@ -2345,6 +2493,28 @@ impl IAppointmentsProviderReplaceAppointmentActivatedEventArgs_Vtbl {
         iid == &<IAppointmentsProviderReplaceAppointmentActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "ApplicationModel_Appointments_AppointmentsProvider")]
+impl<Identity: windows_core::IUnknownImpl + IAppointmentsProviderReplaceAppointmentActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IAppointmentsProviderReplaceAppointmentActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ReplaceAppointmentOperation<Identity: windows_core::IUnknownImpl + IAppointmentsProviderReplaceAppointmentActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppointmentsProviderReplaceAppointmentActivatedEventArgs_Impl::ReplaceAppointmentOperation(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ReplaceAppointmentOperation: ReplaceAppointmentOperation::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAppointmentsProviderReplaceAppointmentActivatedEventArgs_Vtbl {
@@ -2471,6 +2641,55 @@ impl IAppointmentsProviderShowAppointmentDetailsActivatedEventArgs_Vtbl {
         iid == &<IAppointmentsProviderShowAppointmentDetailsActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppointmentsProviderShowAppointmentDetailsActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IAppointmentsProviderShowAppointmentDetailsActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn InstanceStartDate<Identity: windows_core::IUnknownImpl + IAppointmentsProviderShowAppointmentDetailsActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppointmentsProviderShowAppointmentDetailsActivatedEventArgs_Impl::InstanceStartDate(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LocalId<Identity: windows_core::IUnknownImpl + IAppointmentsProviderShowAppointmentDetailsActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppointmentsProviderShowAppointmentDetailsActivatedEventArgs_Impl::LocalId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RoamingId<Identity: windows_core::IUnknownImpl + IAppointmentsProviderShowAppointmentDetailsActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppointmentsProviderShowAppointmentDetailsActivatedEventArgs_Impl::RoamingId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InstanceStartDate: InstanceStartDate::<Identity>,
+            LocalId: LocalId::<Identity>,
+            RoamingId: RoamingId::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAppointmentsProviderShowAppointmentDetailsActivatedEventArgs_Vtbl {
@@ -2572,6 +2791,39 @@ impl IAppointmentsProviderShowTimeFrameActivatedEventArgs_Vtbl {
         iid == &<IAppointmentsProviderShowTimeFrameActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppointmentsProviderShowTimeFrameActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IAppointmentsProviderShowTimeFrameActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn TimeToShow<Identity: windows_core::IUnknownImpl + IAppointmentsProviderShowTimeFrameActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppointmentsProviderShowTimeFrameActivatedEventArgs_Impl::TimeToShow(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Duration<Identity: windows_core::IUnknownImpl + IAppointmentsProviderShowTimeFrameActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppointmentsProviderShowTimeFrameActivatedEventArgs_Impl::Duration(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            TimeToShow: TimeToShow::<Identity>,
+            Duration: Duration::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAppointmentsProviderShowTimeFrameActivatedEventArgs_Vtbl {
@@ -2627,6 +2879,25 @@ impl IBackgroundActivatedEventArgs_Vtbl {
         iid == &<IBackgroundActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "ApplicationModel_Background")]
+impl<Identity: windows_core::IUnknownImpl + IBackgroundActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn TaskInstance<Identity: windows_core::IUnknownImpl + IBackgroundActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundActivatedEventArgs_Impl::TaskInstance(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, TaskInstance: TaskInstance::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IBackgroundActivatedEventArgs_Vtbl {
@@ -2702,6 +2973,24 @@ impl IBarcodeScannerPreviewActivatedEventArgs_Vtbl {
         iid == &<IBarcodeScannerPreviewActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBarcodeScannerPreviewActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IBarcodeScannerPreviewActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ConnectionId<Identity: windows_core::IUnknownImpl + IBarcodeScannerPreviewActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBarcodeScannerPreviewActivatedEventArgs_Impl::ConnectionId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ConnectionId: ConnectionId::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IBarcodeScannerPreviewActivatedEventArgs_Vtbl {
@@ -2778,6 +3067,28 @@ impl ICachedFileUpdaterActivatedEventArgs_Vtbl {
         iid == &<ICachedFileUpdaterActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Storage_Provider")]
+impl<Identity: windows_core::IUnknownImpl + ICachedFileUpdaterActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for ICachedFileUpdaterActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CachedFileUpdaterUI<Identity: windows_core::IUnknownImpl + ICachedFileUpdaterActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICachedFileUpdaterActivatedEventArgs_Impl::CachedFileUpdaterUI(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CachedFileUpdaterUI: CachedFileUpdaterUI::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ICachedFileUpdaterActivatedEventArgs_Vtbl {
@@ -2875,6 +3186,41 @@ impl ICameraSettingsActivatedEventArgs_Vtbl {
         iid == &<ICameraSettingsActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICameraSettingsActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for ICameraSettingsActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn VideoDeviceController<Identity: windows_core::IUnknownImpl + ICameraSettingsActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICameraSettingsActivatedEventArgs_Impl::VideoDeviceController(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn VideoDeviceExtension<Identity: windows_core::IUnknownImpl + ICameraSettingsActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICameraSettingsActivatedEventArgs_Impl::VideoDeviceExtension(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            VideoDeviceController: VideoDeviceController::<Identity>,
+            VideoDeviceExtension: VideoDeviceExtension::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ICameraSettingsActivatedEventArgs_Vtbl {
@@ -2945,6 +3291,24 @@ impl ICommandLineActivatedEventArgs_Vtbl {
         iid == &<ICommandLineActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICommandLineActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for ICommandLineActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Operation<Identity: windows_core::IUnknownImpl + ICommandLineActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommandLineActivatedEventArgs_Impl::Operation(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Operation: Operation::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ICommandLineActivatedEventArgs_Vtbl {
@@ -3028,9 +3392,27 @@ impl IContactActivatedEventArgs_Vtbl {
         iid == &<IContactActivatedEventArgs as windows_core::Interface>::IID
     }
 }
-#[repr(C)]
-#[doc(hidden)]
-pub struct IContactActivatedEventArgs_Vtbl {
+impl<Identity: windows_core::IUnknownImpl + IContactActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IContactActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Verb<Identity: windows_core::IUnknownImpl + IContactActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactActivatedEventArgs_Impl::Verb(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Verb: Verb::<Identity> }
+    };
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IContactActivatedEventArgs_Vtbl {
     pub base__: windows_core::IInspectable_Vtbl,
     pub Verb: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
 }
@@ -3155,6 +3537,56 @@ impl IContactCallActivatedEventArgs_Vtbl {
         iid == &<IContactCallActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "ApplicationModel_Contacts")]
+impl<Identity: windows_core::IUnknownImpl + IContactCallActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IContactCallActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ServiceId<Identity: windows_core::IUnknownImpl + IContactCallActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactCallActivatedEventArgs_Impl::ServiceId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ServiceUserId<Identity: windows_core::IUnknownImpl + IContactCallActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactCallActivatedEventArgs_Impl::ServiceUserId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Contact<Identity: windows_core::IUnknownImpl + IContactCallActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactCallActivatedEventArgs_Impl::Contact(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ServiceId: ServiceId::<Identity>,
+            ServiceUserId: ServiceUserId::<Identity>,
+            Contact: Contact::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IContactCallActivatedEventArgs_Vtbl {
@@ -3266,6 +3698,42 @@ impl IContactMapActivatedEventArgs_Vtbl {
         iid == &<IContactMapActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "ApplicationModel_Contacts")]
+impl<Identity: windows_core::IUnknownImpl + IContactMapActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IContactMapActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Address<Identity: windows_core::IUnknownImpl + IContactMapActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactMapActivatedEventArgs_Impl::Address(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Contact<Identity: windows_core::IUnknownImpl + IContactMapActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactMapActivatedEventArgs_Impl::Contact(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Address: Address::<Identity>,
+            Contact: Contact::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IContactMapActivatedEventArgs_Vtbl {
@@ -3400,6 +3868,56 @@ impl IContactMessageActivatedEventArgs_Vtbl {
         iid == &<IContactMessageActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "ApplicationModel_Contacts")]
+impl<Identity: windows_core::IUnknownImpl + IContactMessageActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IContactMessageActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ServiceId<Identity: windows_core::IUnknownImpl + IContactMessageActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactMessageActivatedEventArgs_Impl::ServiceId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ServiceUserId<Identity: windows_core::IUnknownImpl + IContactMessageActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactMessageActivatedEventArgs_Impl::ServiceUserId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Contact<Identity: windows_core::IUnknownImpl + IContactMessageActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactMessageActivatedEventArgs_Impl::Contact(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ServiceId: ServiceId::<Identity>,
+            ServiceUserId: ServiceUserId::<Identity>,
+            Contact: Contact::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IContactMessageActivatedEventArgs_Vtbl {
@@ -3482,6 +4000,42 @@ impl IContactPanelActivatedEventArgs_Vtbl {
         iid == &<IContactPanelActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "ApplicationModel_Contacts")]
+impl<Identity: windows_core::IUnknownImpl + IContactPanelActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IContactPanelActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ContactPanel<Identity: windows_core::IUnknownImpl + IContactPanelActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactPanelActivatedEventArgs_Impl::ContactPanel(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Contact<Identity: windows_core::IUnknownImpl + IContactPanelActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactPanelActivatedEventArgs_Impl::Contact(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ContactPanel: ContactPanel::<Identity>,
+            Contact: Contact::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IContactPanelActivatedEventArgs_Vtbl {
@@ -3565,6 +4119,25 @@ impl IContactPickerActivatedEventArgs_Vtbl {
         iid == &<IContactPickerActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "ApplicationModel_Contacts_Provider")]
+impl<Identity: windows_core::IUnknownImpl + IContactPickerActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IContactPickerActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ContactPickerUI<Identity: windows_core::IUnknownImpl + IContactPickerActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactPickerActivatedEventArgs_Impl::ContactPickerUI(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ContactPickerUI: ContactPickerUI::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IContactPickerActivatedEventArgs_Vtbl {
@@ -3695,6 +4268,56 @@ impl IContactPostActivatedEventArgs_Vtbl {
         iid == &<IContactPostActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "ApplicationModel_Contacts")]
+impl<Identity: windows_core::IUnknownImpl + IContactPostActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IContactPostActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ServiceId<Identity: windows_core::IUnknownImpl + IContactPostActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactPostActivatedEventArgs_Impl::ServiceId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ServiceUserId<Identity: windows_core::IUnknownImpl + IContactPostActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactPostActivatedEventArgs_Impl::ServiceUserId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Contact<Identity: windows_core::IUnknownImpl + IContactPostActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactPostActivatedEventArgs_Impl::Contact(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ServiceId: ServiceId::<Identity>,
+            ServiceUserId: ServiceUserId::<Identity>,
+            Contact: Contact::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IContactPostActivatedEventArgs_Vtbl {
@@ -3827,6 +4450,56 @@ impl IContactVideoCallActivatedEventArgs_Vtbl {
         iid == &<IContactVideoCallActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "ApplicationModel_Contacts")]
+impl<Identity: windows_core::IUnknownImpl + IContactVideoCallActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IContactVideoCallActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ServiceId<Identity: windows_core::IUnknownImpl + IContactVideoCallActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactVideoCallActivatedEventArgs_Impl::ServiceId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ServiceUserId<Identity: windows_core::IUnknownImpl + IContactVideoCallActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactVideoCallActivatedEventArgs_Impl::ServiceUserId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Contact<Identity: windows_core::IUnknownImpl + IContactVideoCallActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactVideoCallActivatedEventArgs_Impl::Contact(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ServiceId: ServiceId::<Identity>,
+            ServiceUserId: ServiceUserId::<Identity>,
+            Contact: Contact::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IContactVideoCallActivatedEventArgs_Vtbl {
@@ -3901,6 +4574,24 @@ impl IContactsProviderActivatedEventArgs_Vtbl {
         iid == &<IContactsProviderActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IContactsProviderActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IContactsProviderActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Verb<Identity: windows_core::IUnknownImpl + IContactsProviderActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactsProviderActivatedEventArgs_Impl::Verb(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Verb: Verb::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IContactsProviderActivatedEventArgs_Vtbl {
@@ -3977,6 +4668,25 @@ impl IContinuationActivatedEventArgs_Vtbl {
         iid == &<IContinuationActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Foundation_Collections")]
+impl<Identity: windows_core::IUnknownImpl + IContinuationActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IContinuationActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ContinuationData<Identity: windows_core::IUnknownImpl + IContinuationActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContinuationActivatedEventArgs_Impl::ContinuationData(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ContinuationData: ContinuationData::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IContinuationActivatedEventArgs_Vtbl {
@@ -4074,6 +4784,41 @@ impl IDeviceActivatedEventArgs_Vtbl {
         iid == &<IDeviceActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDeviceActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IDeviceActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn DeviceInformationId<Identity: windows_core::IUnknownImpl + IDeviceActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDeviceActivatedEventArgs_Impl::DeviceInformationId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Verb<Identity: windows_core::IUnknownImpl + IDeviceActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDeviceActivatedEventArgs_Impl::Verb(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DeviceInformationId: DeviceInformationId::<Identity>,
+            Verb: Verb::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IDeviceActivatedEventArgs_Vtbl {
@@ -4151,6 +4896,28 @@ impl IDevicePairingActivatedEventArgs_Vtbl {
         iid == &<IDevicePairingActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Devices_Enumeration")]
+impl<Identity: windows_core::IUnknownImpl + IDevicePairingActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IDevicePairingActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn DeviceInformation<Identity: windows_core::IUnknownImpl + IDevicePairingActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDevicePairingActivatedEventArgs_Impl::DeviceInformation(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DeviceInformation: DeviceInformation::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IDevicePairingActivatedEventArgs_Vtbl {
@@ -4237,6 +5004,24 @@ impl IDialReceiverActivatedEventArgs_Vtbl {
         iid == &<IDialReceiverActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDialReceiverActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IDialReceiverActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn AppName<Identity: windows_core::IUnknownImpl + IDialReceiverActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDialReceiverActivatedEventArgs_Impl::AppName(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, AppName: AppName::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IDialReceiverActivatedEventArgs_Vtbl {
@@ -4335,6 +5120,42 @@ impl IFileActivatedEventArgs_Vtbl {
         iid == &<IFileActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Storage")]
+impl<Identity: windows_core::IUnknownImpl + IFileActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IFileActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Files<Identity: windows_core::IUnknownImpl + IFileActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFileActivatedEventArgs_Impl::Files(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Verb<Identity: windows_core::IUnknownImpl + IFileActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFileActivatedEventArgs_Impl::Verb(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Files: Files::<Identity>,
+            Verb: Verb::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IFileActivatedEventArgs_Vtbl {
@@ -4411,6 +5232,27 @@ impl IFileActivatedEventArgsWithCallerPackageFamilyName_Vtbl {
         iid == &<IFileActivatedEventArgsWithCallerPackageFamilyName as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IFileActivatedEventArgsWithCallerPackageFamilyName_Impl> windows_core::imp::TearOffVtable<Identity> for IFileActivatedEventArgsWithCallerPackageFamilyName_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CallerPackageFamilyName<Identity: windows_core::IUnknownImpl + IFileActivatedEventArgsWithCallerPackageFamilyName_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFileActivatedEventArgsWithCallerPackageFamilyName_Impl::CallerPackageFamilyName(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CallerPackageFamilyName: CallerPackageFamilyName::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IFileActivatedEventArgsWithCallerPackageFamilyName_Vtbl {
@@ -4502,6 +5344,28 @@ impl IFileActivatedEventArgsWithNeighboringFiles_Vtbl {
         iid == &<IFileActivatedEventArgsWithNeighboringFiles as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Storage_Search")]
+impl<Identity: windows_core::IUnknownImpl + IFileActivatedEventArgsWithNeighboringFiles_Impl> windows_core::imp::TearOffVtable<Identity> for IFileActivatedEventArgsWithNeighboringFiles_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn NeighboringFilesQuery<Identity: windows_core::IUnknownImpl + IFileActivatedEventArgsWithNeighboringFiles_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFileActivatedEventArgsWithNeighboringFiles_Impl::NeighboringFilesQuery(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            NeighboringFilesQuery: NeighboringFilesQuery::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IFileActivatedEventArgsWithNeighboringFiles_Vtbl {
@@ -4581,6 +5445,25 @@ impl IFileOpenPickerActivatedEventArgs_Vtbl {
         iid == &<IFileOpenPickerActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Storage_Pickers_Provider")]
+impl<Identity: windows_core::IUnknownImpl + IFileOpenPickerActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IFileOpenPickerActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn FileOpenPickerUI<Identity: windows_core::IUnknownImpl + IFileOpenPickerActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFileOpenPickerActivatedEventArgs_Impl::FileOpenPickerUI(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, FileOpenPickerUI: FileOpenPickerUI::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IFileOpenPickerActivatedEventArgs_Vtbl {
@@ -4634,6 +5517,27 @@ impl IFileOpenPickerActivatedEventArgs2_Vtbl {
         iid == &<IFileOpenPickerActivatedEventArgs2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IFileOpenPickerActivatedEventArgs2_Impl> windows_core::imp::TearOffVtable<Identity> for IFileOpenPickerActivatedEventArgs2_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CallerPackageFamilyName<Identity: windows_core::IUnknownImpl + IFileOpenPickerActivatedEventArgs2_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFileOpenPickerActivatedEventArgs2_Impl::CallerPackageFamilyName(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CallerPackageFamilyName: CallerPackageFamilyName::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IFileOpenPickerActivatedEventArgs2_Vtbl {
@@ -4715,6 +5619,25 @@ impl IFileOpenPickerContinuationEventArgs_Vtbl {
         iid == &<IFileOpenPickerContinuationEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl<Identity: windows_core::IUnknownImpl + IFileOpenPickerContinuationEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IFileOpenPickerContinuationEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Files<Identity: windows_core::IUnknownImpl + IFileOpenPickerContinuationEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFileOpenPickerContinuationEventArgs_Impl::Files(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Files: Files::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IFileOpenPickerContinuationEventArgs_Vtbl {
@@ -4794,6 +5717,25 @@ impl IFileSavePickerActivatedEventArgs_Vtbl {
         iid == &<IFileSavePickerActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Storage_Pickers_Provider")]
+impl<Identity: windows_core::IUnknownImpl + IFileSavePickerActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IFileSavePickerActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn FileSavePickerUI<Identity: windows_core::IUnknownImpl + IFileSavePickerActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFileSavePickerActivatedEventArgs_Impl::FileSavePickerUI(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, FileSavePickerUI: FileSavePickerUI::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IFileSavePickerActivatedEventArgs_Vtbl {
@@ -4869,6 +5811,41 @@ impl IFileSavePickerActivatedEventArgs2_Vtbl {
         iid == &<IFileSavePickerActivatedEventArgs2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IFileSavePickerActivatedEventArgs2_Impl> windows_core::imp::TearOffVtable<Identity> for IFileSavePickerActivatedEventArgs2_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CallerPackageFamilyName<Identity: windows_core::IUnknownImpl + IFileSavePickerActivatedEventArgs2_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFileSavePickerActivatedEventArgs2_Impl::CallerPackageFamilyName(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnterpriseId<Identity: windows_core::IUnknownImpl + IFileSavePickerActivatedEventArgs2_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFileSavePickerActivatedEventArgs2_Impl::EnterpriseId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CallerPackageFamilyName: CallerPackageFamilyName::<Identity>,
+            EnterpriseId: EnterpriseId::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IFileSavePickerActivatedEventArgs2_Vtbl {
@@ -4951,6 +5928,25 @@ impl IFileSavePickerContinuationEventArgs_Vtbl {
         iid == &<IFileSavePickerContinuationEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Streams"))]
+impl<Identity: windows_core::IUnknownImpl + IFileSavePickerContinuationEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IFileSavePickerContinuationEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn File<Identity: windows_core::IUnknownImpl + IFileSavePickerContinuationEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFileSavePickerContinuationEventArgs_Impl::File(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, File: File::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IFileSavePickerContinuationEventArgs_Vtbl {
@@ -5035,6 +6031,25 @@ impl IFolderPickerContinuationEventArgs_Vtbl {
         iid == &<IFolderPickerContinuationEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(all(feature = "Foundation_Collections", feature = "Storage_Search"))]
+impl<Identity: windows_core::IUnknownImpl + IFolderPickerContinuationEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IFolderPickerContinuationEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Folder<Identity: windows_core::IUnknownImpl + IFolderPickerContinuationEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFolderPickerContinuationEventArgs_Impl::Folder(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Folder: Folder::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IFolderPickerContinuationEventArgs_Vtbl {
@@ -5132,6 +6147,41 @@ impl ILaunchActivatedEventArgs_Vtbl {
         iid == &<ILaunchActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ILaunchActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for ILaunchActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Arguments<Identity: windows_core::IUnknownImpl + ILaunchActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILaunchActivatedEventArgs_Impl::Arguments(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn TileId<Identity: windows_core::IUnknownImpl + ILaunchActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILaunchActivatedEventArgs_Impl::TileId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Arguments: Arguments::<Identity>,
+            TileId: TileId::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ILaunchActivatedEventArgs_Vtbl {
@@ -5219,6 +6269,27 @@ impl ILaunchActivatedEventArgs2_Vtbl {
         iid == &<ILaunchActivatedEventArgs2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ILaunchActivatedEventArgs2_Impl> windows_core::imp::TearOffVtable<Identity> for ILaunchActivatedEventArgs2_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn TileActivatedInfo<Identity: windows_core::IUnknownImpl + ILaunchActivatedEventArgs2_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILaunchActivatedEventArgs2_Impl::TileActivatedInfo(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            TileActivatedInfo: TileActivatedInfo::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ILaunchActivatedEventArgs2_Vtbl {
@@ -5288,6 +6359,24 @@ impl ILockScreenActivatedEventArgs_Vtbl {
         iid == &<ILockScreenActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ILockScreenActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for ILockScreenActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Info<Identity: windows_core::IUnknownImpl + ILockScreenActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILockScreenActivatedEventArgs_Impl::Info(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Info: Info::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ILockScreenActivatedEventArgs_Vtbl {
@@ -5375,6 +6464,25 @@ impl ILockScreenCallActivatedEventArgs_Vtbl {
         iid == &<ILockScreenCallActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "ApplicationModel_Calls")]
+impl<Identity: windows_core::IUnknownImpl + ILockScreenCallActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for ILockScreenCallActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CallUI<Identity: windows_core::IUnknownImpl + ILockScreenCallActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILockScreenCallActivatedEventArgs_Impl::CallUI(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CallUI: CallUI::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ILockScreenCallActivatedEventArgs_Vtbl {
@@ -5446,6 +6554,23 @@ impl IPhoneCallActivatedEventArgs_Vtbl {
         iid == &<IPhoneCallActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPhoneCallActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IPhoneCallActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn LineId<Identity: windows_core::IUnknownImpl + IPhoneCallActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhoneCallActivatedEventArgs_Impl::LineId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, LineId: LineId::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPhoneCallActivatedEventArgs_Vtbl {
@@ -5518,6 +6643,27 @@ impl IPickerReturnedActivatedEventArgs_Vtbl {
         iid == &<IPickerReturnedActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPickerReturnedActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IPickerReturnedActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn PickerOperationId<Identity: windows_core::IUnknownImpl + IPickerReturnedActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPickerReturnedActivatedEventArgs_Impl::PickerOperationId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PickerOperationId: PickerOperationId::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPickerReturnedActivatedEventArgs_Vtbl {
@@ -5589,6 +6735,26 @@ impl IPrelaunchActivatedEventArgs_Vtbl {
         iid == &<IPrelaunchActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrelaunchActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IPrelaunchActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn PrelaunchActivated<Identity: windows_core::IUnknownImpl + IPrelaunchActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrelaunchActivatedEventArgs_Impl::PrelaunchActivated(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PrelaunchActivated: PrelaunchActivated::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPrelaunchActivatedEventArgs_Vtbl {
@@ -5662,6 +6828,25 @@ impl IPrint3DWorkflowActivatedEventArgs_Vtbl {
         iid == &<IPrint3DWorkflowActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Devices_Printers_Extensions")]
+impl<Identity: windows_core::IUnknownImpl + IPrint3DWorkflowActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IPrint3DWorkflowActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Workflow<Identity: windows_core::IUnknownImpl + IPrint3DWorkflowActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrint3DWorkflowActivatedEventArgs_Impl::Workflow(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Workflow: Workflow::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPrint3DWorkflowActivatedEventArgs_Vtbl {
@@ -5741,6 +6926,25 @@ impl IPrintTaskSettingsActivatedEventArgs_Vtbl {
         iid == &<IPrintTaskSettingsActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Devices_Printers_Extensions")]
+impl<Identity: windows_core::IUnknownImpl + IPrintTaskSettingsActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintTaskSettingsActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Configuration<Identity: windows_core::IUnknownImpl + IPrintTaskSettingsActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintTaskSettingsActivatedEventArgs_Impl::Configuration(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Configuration: Configuration::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPrintTaskSettingsActivatedEventArgs_Vtbl {
@@ -5792,11 +6996,32 @@ impl windows_core::RuntimeName for IProtocolActivatedEventArgs {
 pub trait IProtocolActivatedEventArgs_Impl: IActivatedEventArgs_Impl {
     fn Uri(&self) -> windows_core::Result<super::super::Foundation::Uri>;
 }
-impl IProtocolActivatedEventArgs_Vtbl {
-    pub const fn new<Identity: IProtocolActivatedEventArgs_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn Uri<Identity: IProtocolActivatedEventArgs_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl IProtocolActivatedEventArgs_Vtbl {
+    pub const fn new<Identity: IProtocolActivatedEventArgs_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn Uri<Identity: IProtocolActivatedEventArgs_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IProtocolActivatedEventArgs_Impl::Uri(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: windows_core::IInspectable_Vtbl::new::<Identity, IProtocolActivatedEventArgs, OFFSET>(), Uri: Uri::<Identity, OFFSET> }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtocolActivatedEventArgs as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IProtocolActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IProtocolActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Uri<Identity: windows_core::IUnknownImpl + IProtocolActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IProtocolActivatedEventArgs_Impl::Uri(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -5807,11 +7032,8 @@ impl IProtocolActivatedEventArgs_Vtbl {
                 }
             }
         }
-        Self { base__: windows_core::IInspectable_Vtbl::new::<Identity, IProtocolActivatedEventArgs, OFFSET>(), Uri: Uri::<Identity, OFFSET> }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IProtocolActivatedEventArgs as windows_core::Interface>::IID
-    }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Uri: Uri::<Identity> }
+    };
 }
 #[repr(C)]
 #[doc(hidden)]
@@ -5911,6 +7133,42 @@ impl IProtocolActivatedEventArgsWithCallerPackageFamilyNameAndData_Vtbl {
         iid == &<IProtocolActivatedEventArgsWithCallerPackageFamilyNameAndData as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Foundation_Collections")]
+impl<Identity: windows_core::IUnknownImpl + IProtocolActivatedEventArgsWithCallerPackageFamilyNameAndData_Impl> windows_core::imp::TearOffVtable<Identity> for IProtocolActivatedEventArgsWithCallerPackageFamilyNameAndData_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CallerPackageFamilyName<Identity: windows_core::IUnknownImpl + IProtocolActivatedEventArgsWithCallerPackageFamilyNameAndData_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProtocolActivatedEventArgsWithCallerPackageFamilyNameAndData_Impl::CallerPackageFamilyName(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Data<Identity: windows_core::IUnknownImpl + IProtocolActivatedEventArgsWithCallerPackageFamilyNameAndData_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProtocolActivatedEventArgsWithCallerPackageFamilyNameAndData_Impl::Data(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CallerPackageFamilyName: CallerPackageFamilyName::<Identity>,
+            Data: Data::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IProtocolActivatedEventArgsWithCallerPackageFamilyNameAndData_Vtbl {
@@ -5991,6 +7249,28 @@ impl IProtocolForResultsActivatedEventArgs_Vtbl {
         iid == &<IProtocolForResultsActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "System")]
+impl<Identity: windows_core::IUnknownImpl + IProtocolForResultsActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IProtocolForResultsActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ProtocolForResultsOperation<Identity: windows_core::IUnknownImpl + IProtocolForResultsActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProtocolForResultsActivatedEventArgs_Impl::ProtocolForResultsOperation(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ProtocolForResultsOperation: ProtocolForResultsOperation::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IProtocolForResultsActivatedEventArgs_Vtbl {
@@ -6066,6 +7346,24 @@ impl IRestrictedLaunchActivatedEventArgs_Vtbl {
         iid == &<IRestrictedLaunchActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRestrictedLaunchActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IRestrictedLaunchActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SharedContext<Identity: windows_core::IUnknownImpl + IRestrictedLaunchActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRestrictedLaunchActivatedEventArgs_Impl::SharedContext(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SharedContext: SharedContext::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IRestrictedLaunchActivatedEventArgs_Vtbl {
@@ -6160,6 +7458,41 @@ impl ISearchActivatedEventArgs_Vtbl {
         iid == &<ISearchActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISearchActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for ISearchActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryText<Identity: windows_core::IUnknownImpl + ISearchActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISearchActivatedEventArgs_Impl::QueryText(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Language<Identity: windows_core::IUnknownImpl + ISearchActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISearchActivatedEventArgs_Impl::Language(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryText: QueryText::<Identity>,
+            Language: Language::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ISearchActivatedEventArgs_Vtbl {
@@ -6215,6 +7548,28 @@ impl ISearchActivatedEventArgsWithLinguisticDetails_Vtbl {
         iid == &<ISearchActivatedEventArgsWithLinguisticDetails as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "ApplicationModel_Search")]
+impl<Identity: windows_core::IUnknownImpl + ISearchActivatedEventArgsWithLinguisticDetails_Impl> windows_core::imp::TearOffVtable<Identity> for ISearchActivatedEventArgsWithLinguisticDetails_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn LinguisticDetails<Identity: windows_core::IUnknownImpl + ISearchActivatedEventArgsWithLinguisticDetails_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISearchActivatedEventArgsWithLinguisticDetails_Impl::LinguisticDetails(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            LinguisticDetails: LinguisticDetails::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ISearchActivatedEventArgsWithLinguisticDetails_Vtbl {
@@ -6294,6 +7649,25 @@ impl IShareTargetActivatedEventArgs_Vtbl {
         iid == &<IShareTargetActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "ApplicationModel_DataTransfer_ShareTarget")]
+impl<Identity: windows_core::IUnknownImpl + IShareTargetActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IShareTargetActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ShareOperation<Identity: windows_core::IUnknownImpl + IShareTargetActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IShareTargetActivatedEventArgs_Impl::ShareOperation(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ShareOperation: ShareOperation::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IShareTargetActivatedEventArgs_Vtbl {
@@ -6378,6 +7752,24 @@ impl IStartupTaskActivatedEventArgs_Vtbl {
         iid == &<IStartupTaskActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IStartupTaskActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IStartupTaskActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn TaskId<Identity: windows_core::IUnknownImpl + IStartupTaskActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStartupTaskActivatedEventArgs_Impl::TaskId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, TaskId: TaskId::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IStartupTaskActivatedEventArgs_Vtbl {
@@ -6489,6 +7881,42 @@ impl IToastNotificationActivatedEventArgs_Vtbl {
         iid == &<IToastNotificationActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Foundation_Collections")]
+impl<Identity: windows_core::IUnknownImpl + IToastNotificationActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IToastNotificationActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Argument<Identity: windows_core::IUnknownImpl + IToastNotificationActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IToastNotificationActivatedEventArgs_Impl::Argument(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UserInput<Identity: windows_core::IUnknownImpl + IToastNotificationActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IToastNotificationActivatedEventArgs_Impl::UserInput(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Argument: Argument::<Identity>,
+            UserInput: UserInput::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IToastNotificationActivatedEventArgs_Vtbl {
@@ -6569,6 +7997,25 @@ impl IUserDataAccountProviderActivatedEventArgs_Vtbl {
         iid == &<IUserDataAccountProviderActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "ApplicationModel_UserDataAccounts_Provider")]
+impl<Identity: windows_core::IUnknownImpl + IUserDataAccountProviderActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IUserDataAccountProviderActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Operation<Identity: windows_core::IUnknownImpl + IUserDataAccountProviderActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUserDataAccountProviderActivatedEventArgs_Impl::Operation(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Operation: Operation::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IUserDataAccountProviderActivatedEventArgs_Vtbl {
@@ -6645,6 +8092,25 @@ impl IViewSwitcherProvider_Vtbl {
         iid == &<IViewSwitcherProvider as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "UI_ViewManagement")]
+impl<Identity: windows_core::IUnknownImpl + IViewSwitcherProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IViewSwitcherProvider_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ViewSwitcher<Identity: windows_core::IUnknownImpl + IViewSwitcherProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IViewSwitcherProvider_Impl::ViewSwitcher(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ViewSwitcher: ViewSwitcher::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IViewSwitcherProvider_Vtbl {
@@ -6721,6 +8187,25 @@ impl IVoiceCommandActivatedEventArgs_Vtbl {
         iid == &<IVoiceCommandActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Media_SpeechRecognition")]
+impl<Identity: windows_core::IUnknownImpl + IVoiceCommandActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IVoiceCommandActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Result<Identity: windows_core::IUnknownImpl + IVoiceCommandActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVoiceCommandActivatedEventArgs_Impl::Result(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Result: Result::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IVoiceCommandActivatedEventArgs_Vtbl {
@@ -6843,6 +8328,55 @@ impl IWalletActionActivatedEventArgs_Vtbl {
         iid == &<IWalletActionActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "ApplicationModel_Wallet")]
+impl<Identity: windows_core::IUnknownImpl + IWalletActionActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IWalletActionActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ItemId<Identity: windows_core::IUnknownImpl + IWalletActionActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWalletActionActivatedEventArgs_Impl::ItemId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ActionKind<Identity: windows_core::IUnknownImpl + IWalletActionActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut super::Wallet::WalletActionKind) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWalletActionActivatedEventArgs_Impl::ActionKind(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ActionId<Identity: windows_core::IUnknownImpl + IWalletActionActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWalletActionActivatedEventArgs_Impl::ActionId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ItemId: ItemId::<Identity>,
+            ActionKind: ActionKind::<Identity>,
+            ActionId: ActionId::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IWalletActionActivatedEventArgs_Vtbl {
@@ -6924,6 +8458,25 @@ impl IWebAccountProviderActivatedEventArgs_Vtbl {
         iid == &<IWebAccountProviderActivatedEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Security_Authentication_Web_Provider")]
+impl<Identity: windows_core::IUnknownImpl + IWebAccountProviderActivatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IWebAccountProviderActivatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Operation<Identity: windows_core::IUnknownImpl + IWebAccountProviderActivatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebAccountProviderActivatedEventArgs_Impl::Operation(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Operation: Operation::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IWebAccountProviderActivatedEventArgs_Vtbl {
@@ -7011,6 +8564,28 @@ impl IWebAuthenticationBrokerContinuationEventArgs_Vtbl {
         iid == &<IWebAuthenticationBrokerContinuationEventArgs as windows_core::Interface>::IID
     }
 }
+#[cfg(all(feature = "Foundation_Collections", feature = "Security_Authentication_Web"))]
+impl<Identity: windows_core::IUnknownImpl + IWebAuthenticationBrokerContinuationEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IWebAuthenticationBrokerContinuationEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn WebAuthenticationResult<Identity: windows_core::IUnknownImpl + IWebAuthenticationBrokerContinuationEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebAuthenticationBrokerContinuationEventArgs_Impl::WebAuthenticationResult(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            WebAuthenticationResult: WebAuthenticationResult::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IWebAuthenticationBrokerContinuationEventArgs_Vtbl {
@@ -2723,6 +2723,55 @@ impl IAppointmentParticipant_Vtbl {
         iid == &<IAppointmentParticipant as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppointmentParticipant_Impl> windows_core::imp::TearOffVtable<Identity> for IAppointmentParticipant_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn DisplayName<Identity: windows_core::IUnknownImpl + IAppointmentParticipant_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppointmentParticipant_Impl::DisplayName(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDisplayName<Identity: windows_core::IUnknownImpl + IAppointmentParticipant_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppointmentParticipant_Impl::SetDisplayName(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Address<Identity: windows_core::IUnknownImpl + IAppointmentParticipant_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppointmentParticipant_Impl::Address(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAddress<Identity: windows_core::IUnknownImpl + IAppointmentParticipant_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppointmentParticipant_Impl::SetAddress(this, core::mem::transmute(&value)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DisplayName: DisplayName::<Identity>,
+            SetDisplayName: SetDisplayName::<Identity>,
+            Address: Address::<Identity>,
+            SetAddress: SetAddress::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAppointmentParticipant_Vtbl {
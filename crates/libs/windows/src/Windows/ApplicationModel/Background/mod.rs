@@ -2276,6 +2276,9 @@ impl IBackgroundCondition_Vtbl {
         iid == &<IBackgroundCondition as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCondition_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCondition_Vtbl {
+    const VTABLE: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IBackgroundCondition_Vtbl {
@@ -2352,6 +2355,17 @@ impl IBackgroundTask_Vtbl {
         iid == &<IBackgroundTask as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundTask_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundTask_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Run<Identity: windows_core::IUnknownImpl + IBackgroundTask_Impl>(this: *mut core::ffi::c_void, taskinstance: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundTask_Impl::Run(this, core::mem::transmute_copy(&taskinstance)).into()
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Run: Run::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IBackgroundTask_Vtbl {
@@ -2660,6 +2674,121 @@ impl IBackgroundTaskInstance_Vtbl {
         iid == &<IBackgroundTaskInstance as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundTaskInstance_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundTaskInstance_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn InstanceId<Identity: windows_core::IUnknownImpl + IBackgroundTaskInstance_Impl>(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTaskInstance_Impl::InstanceId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Task<Identity: windows_core::IUnknownImpl + IBackgroundTaskInstance_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTaskInstance_Impl::Task(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Progress<Identity: windows_core::IUnknownImpl + IBackgroundTaskInstance_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTaskInstance_Impl::Progress(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProgress<Identity: windows_core::IUnknownImpl + IBackgroundTaskInstance_Impl>(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundTaskInstance_Impl::SetProgress(this, value).into()
+            }
+        }
+        unsafe extern "system" fn TriggerDetails<Identity: windows_core::IUnknownImpl + IBackgroundTaskInstance_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTaskInstance_Impl::TriggerDetails(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Canceled<Identity: windows_core::IUnknownImpl + IBackgroundTaskInstance_Impl>(this: *mut core::ffi::c_void, cancelhandler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTaskInstance_Impl::Canceled(this, core::mem::transmute_copy(&cancelhandler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveCanceled<Identity: windows_core::IUnknownImpl + IBackgroundTaskInstance_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundTaskInstance_Impl::RemoveCanceled(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn SuspendedCount<Identity: windows_core::IUnknownImpl + IBackgroundTaskInstance_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTaskInstance_Impl::SuspendedCount(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDeferral<Identity: windows_core::IUnknownImpl + IBackgroundTaskInstance_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTaskInstance_Impl::GetDeferral(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InstanceId: InstanceId::<Identity>,
+            Task: Task::<Identity>,
+            Progress: Progress::<Identity>,
+            SetProgress: SetProgress::<Identity>,
+            TriggerDetails: TriggerDetails::<Identity>,
+            Canceled: Canceled::<Identity>,
+            RemoveCanceled: RemoveCanceled::<Identity>,
+            SuspendedCount: SuspendedCount::<Identity>,
+            GetDeferral: GetDeferral::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IBackgroundTaskInstance_Vtbl {
@@ -2778,6 +2907,23 @@ impl IBackgroundTaskInstance2_Vtbl {
         iid == &<IBackgroundTaskInstance2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundTaskInstance2_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundTaskInstance2_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetThrottleCount<Identity: windows_core::IUnknownImpl + IBackgroundTaskInstance2_Impl>(this: *mut core::ffi::c_void, counter: BackgroundTaskThrottleCounter, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTaskInstance2_Impl::GetThrottleCount(this, counter) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetThrottleCount: GetThrottleCount::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IBackgroundTaskInstance2_Vtbl {
@@ -2890,6 +3036,25 @@ impl IBackgroundTaskInstance4_Vtbl {
         iid == &<IBackgroundTaskInstance4 as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "System")]
+impl<Identity: windows_core::IUnknownImpl + IBackgroundTaskInstance4_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundTaskInstance4_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn User<Identity: windows_core::IUnknownImpl + IBackgroundTaskInstance4_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTaskInstance4_Impl::User(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, User: User::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IBackgroundTaskInstance4_Vtbl {
@@ -3059,6 +3224,87 @@ impl IBackgroundTaskRegistration_Vtbl {
         iid == &<IBackgroundTaskRegistration as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundTaskRegistration_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundTaskRegistration_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn TaskId<Identity: windows_core::IUnknownImpl + IBackgroundTaskRegistration_Impl>(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTaskRegistration_Impl::TaskId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + IBackgroundTaskRegistration_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTaskRegistration_Impl::Name(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Progress<Identity: windows_core::IUnknownImpl + IBackgroundTaskRegistration_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTaskRegistration_Impl::Progress(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveProgress<Identity: windows_core::IUnknownImpl + IBackgroundTaskRegistration_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundTaskRegistration_Impl::RemoveProgress(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn Completed<Identity: windows_core::IUnknownImpl + IBackgroundTaskRegistration_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTaskRegistration_Impl::Completed(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveCompleted<Identity: windows_core::IUnknownImpl + IBackgroundTaskRegistration_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundTaskRegistration_Impl::RemoveCompleted(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn Unregister<Identity: windows_core::IUnknownImpl + IBackgroundTaskRegistration_Impl>(this: *mut core::ffi::c_void, canceltask: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundTaskRegistration_Impl::Unregister(this, canceltask).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            TaskId: TaskId::<Identity>,
+            Name: Name::<Identity>,
+            Progress: Progress::<Identity>,
+            RemoveProgress: RemoveProgress::<Identity>,
+            Completed: Completed::<Identity>,
+            RemoveCompleted: RemoveCompleted::<Identity>,
+            Unregister: Unregister::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IBackgroundTaskRegistration_Vtbl {
@@ -3159,6 +3405,24 @@ impl IBackgroundTaskRegistration2_Vtbl {
         iid == &<IBackgroundTaskRegistration2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundTaskRegistration2_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundTaskRegistration2_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Trigger<Identity: windows_core::IUnknownImpl + IBackgroundTaskRegistration2_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTaskRegistration2_Impl::Trigger(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Trigger: Trigger::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IBackgroundTaskRegistration2_Vtbl {
@@ -3253,6 +3517,24 @@ impl IBackgroundTaskRegistration3_Vtbl {
         iid == &<IBackgroundTaskRegistration3 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundTaskRegistration3_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundTaskRegistration3_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn TaskGroup<Identity: windows_core::IUnknownImpl + IBackgroundTaskRegistration3_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTaskRegistration3_Impl::TaskGroup(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, TaskGroup: TaskGroup::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IBackgroundTaskRegistration3_Vtbl {
@@ -3336,6 +3618,9 @@ impl IBackgroundTrigger_Vtbl {
         iid == &<IBackgroundTrigger as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundTrigger_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundTrigger_Vtbl {
+    const VTABLE: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IBackgroundTrigger_Vtbl {
@@ -2169,6 +2169,23 @@ impl IChatItem_Vtbl {
         iid == &<IChatItem as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IChatItem_Impl> windows_core::imp::TearOffVtable<Identity> for IChatItem_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ItemKind<Identity: windows_core::IUnknownImpl + IChatItem_Impl>(this: *mut core::ffi::c_void, result__: *mut ChatItemKind) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IChatItem_Impl::ItemKind(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ItemKind: ItemKind::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IChatItem_Vtbl {
@@ -670,6 +670,33 @@ impl ICoreApplicationUnhandledError_Vtbl {
         iid == &<ICoreApplicationUnhandledError as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICoreApplicationUnhandledError_Impl> windows_core::imp::TearOffVtable<Identity> for ICoreApplicationUnhandledError_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn UnhandledErrorDetected<Identity: windows_core::IUnknownImpl + ICoreApplicationUnhandledError_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreApplicationUnhandledError_Impl::UnhandledErrorDetected(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveUnhandledErrorDetected<Identity: windows_core::IUnknownImpl + ICoreApplicationUnhandledError_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreApplicationUnhandledError_Impl::RemoveUnhandledErrorDetected(this, token).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            UnhandledErrorDetected: UnhandledErrorDetected::<Identity>,
+            RemoveUnhandledErrorDetected: RemoveUnhandledErrorDetected::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ICoreApplicationUnhandledError_Vtbl {
@@ -903,6 +930,49 @@ impl IFrameworkView_Vtbl {
         iid == &<IFrameworkView as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "UI_Core")]
+impl<Identity: windows_core::IUnknownImpl + IFrameworkView_Impl> windows_core::imp::TearOffVtable<Identity> for IFrameworkView_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IFrameworkView_Impl>(this: *mut core::ffi::c_void, applicationview: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFrameworkView_Impl::Initialize(this, core::mem::transmute_copy(&applicationview)).into()
+            }
+        }
+        unsafe extern "system" fn SetWindow<Identity: windows_core::IUnknownImpl + IFrameworkView_Impl>(this: *mut core::ffi::c_void, window: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFrameworkView_Impl::SetWindow(this, core::mem::transmute_copy(&window)).into()
+            }
+        }
+        unsafe extern "system" fn Load<Identity: windows_core::IUnknownImpl + IFrameworkView_Impl>(this: *mut core::ffi::c_void, entrypoint: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFrameworkView_Impl::Load(this, core::mem::transmute(&entrypoint)).into()
+            }
+        }
+        unsafe extern "system" fn Run<Identity: windows_core::IUnknownImpl + IFrameworkView_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFrameworkView_Impl::Run(this).into()
+            }
+        }
+        unsafe extern "system" fn Uninitialize<Identity: windows_core::IUnknownImpl + IFrameworkView_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFrameworkView_Impl::Uninitialize(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            SetWindow: SetWindow::<Identity>,
+            Load: Load::<Identity>,
+            Run: Run::<Identity>,
+            Uninitialize: Uninitialize::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IFrameworkView_Vtbl {
@@ -957,6 +1027,24 @@ impl IFrameworkViewSource_Vtbl {
         iid == &<IFrameworkViewSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IFrameworkViewSource_Impl> windows_core::imp::TearOffVtable<Identity> for IFrameworkViewSource_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateView<Identity: windows_core::IUnknownImpl + IFrameworkViewSource_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFrameworkViewSource_Impl::CreateView(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateView: CreateView::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IFrameworkViewSource_Vtbl {
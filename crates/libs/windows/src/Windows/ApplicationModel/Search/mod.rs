@@ -140,6 +140,55 @@ impl ISearchPaneQueryChangedEventArgs_Vtbl {
         iid == &<ISearchPaneQueryChangedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISearchPaneQueryChangedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for ISearchPaneQueryChangedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryText<Identity: windows_core::IUnknownImpl + ISearchPaneQueryChangedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISearchPaneQueryChangedEventArgs_Impl::QueryText(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Language<Identity: windows_core::IUnknownImpl + ISearchPaneQueryChangedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISearchPaneQueryChangedEventArgs_Impl::Language(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LinguisticDetails<Identity: windows_core::IUnknownImpl + ISearchPaneQueryChangedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISearchPaneQueryChangedEventArgs_Impl::LinguisticDetails(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryText: QueryText::<Identity>,
+            Language: Language::<Identity>,
+            LinguisticDetails: LinguisticDetails::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ISearchPaneQueryChangedEventArgs_Vtbl {
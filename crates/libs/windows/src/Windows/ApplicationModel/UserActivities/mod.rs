@@ -171,6 +171,24 @@ impl IUserActivityContentInfo_Vtbl {
         iid == &<IUserActivityContentInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IUserActivityContentInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IUserActivityContentInfo_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ToJson<Identity: windows_core::IUnknownImpl + IUserActivityContentInfo_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUserActivityContentInfo_Impl::ToJson(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ToJson: ToJson::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IUserActivityContentInfo_Vtbl {
@@ -62,6 +62,23 @@ impl IUserDataAccountProviderOperation_Vtbl {
         iid == &<IUserDataAccountProviderOperation as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IUserDataAccountProviderOperation_Impl> windows_core::imp::TearOffVtable<Identity> for IUserDataAccountProviderOperation_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Kind<Identity: windows_core::IUnknownImpl + IUserDataAccountProviderOperation_Impl>(this: *mut core::ffi::c_void, result__: *mut UserDataAccountProviderOperationKind) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUserDataAccountProviderOperation_Impl::Kind(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Kind: Kind::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IUserDataAccountProviderOperation_Vtbl {
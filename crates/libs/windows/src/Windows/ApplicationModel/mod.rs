@@ -883,6 +883,24 @@ impl IEnteredBackgroundEventArgs_Vtbl {
         iid == &<IEnteredBackgroundEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnteredBackgroundEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IEnteredBackgroundEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDeferral<Identity: windows_core::IUnknownImpl + IEnteredBackgroundEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnteredBackgroundEventArgs_Impl::GetDeferral(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDeferral: GetDeferral::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IEnteredBackgroundEventArgs_Vtbl {
@@ -994,6 +1012,24 @@ impl ILeavingBackgroundEventArgs_Vtbl {
         iid == &<ILeavingBackgroundEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ILeavingBackgroundEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for ILeavingBackgroundEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDeferral<Identity: windows_core::IUnknownImpl + ILeavingBackgroundEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILeavingBackgroundEventArgs_Impl::GetDeferral(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDeferral: GetDeferral::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ILeavingBackgroundEventArgs_Vtbl {
@@ -1322,6 +1358,24 @@ impl IPackageCatalogStatics2_Vtbl {
         iid == &<IPackageCatalogStatics2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPackageCatalogStatics2_Impl> windows_core::imp::TearOffVtable<Identity> for IPackageCatalogStatics2_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn OpenForPackage<Identity: windows_core::IUnknownImpl + IPackageCatalogStatics2_Impl>(this: *mut core::ffi::c_void, package: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPackageCatalogStatics2_Impl::OpenForPackage(this, core::mem::transmute_copy(&package)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OpenForPackage: OpenForPackage::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPackageCatalogStatics2_Vtbl {
@@ -1584,6 +1638,17 @@ impl ISuspendingDeferral_Vtbl {
         iid == &<ISuspendingDeferral as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISuspendingDeferral_Impl> windows_core::imp::TearOffVtable<Identity> for ISuspendingDeferral_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Complete<Identity: windows_core::IUnknownImpl + ISuspendingDeferral_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISuspendingDeferral_Impl::Complete(this).into()
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Complete: Complete::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ISuspendingDeferral_Vtbl {
@@ -1634,6 +1699,27 @@ impl ISuspendingEventArgs_Vtbl {
         iid == &<ISuspendingEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISuspendingEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for ISuspendingEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SuspendingOperation<Identity: windows_core::IUnknownImpl + ISuspendingEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISuspendingEventArgs_Impl::SuspendingOperation(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SuspendingOperation: SuspendingOperation::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ISuspendingEventArgs_Vtbl {
@@ -1705,6 +1791,40 @@ impl ISuspendingOperation_Vtbl {
         iid == &<ISuspendingOperation as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISuspendingOperation_Impl> windows_core::imp::TearOffVtable<Identity> for ISuspendingOperation_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDeferral<Identity: windows_core::IUnknownImpl + ISuspendingOperation_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISuspendingOperation_Impl::GetDeferral(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Deadline<Identity: windows_core::IUnknownImpl + ISuspendingOperation_Impl>(this: *mut core::ffi::c_void, result__: *mut super::Foundation::DateTime) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISuspendingOperation_Impl::Deadline(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDeferral: GetDeferral::<Identity>,
+            Deadline: Deadline::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ISuspendingOperation_Vtbl {
@@ -936,6 +936,89 @@ impl IXmlCharacterData_Vtbl {
         iid == &<IXmlCharacterData as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXmlCharacterData_Impl> windows_core::imp::TearOffVtable<Identity> for IXmlCharacterData_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Data<Identity: windows_core::IUnknownImpl + IXmlCharacterData_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlCharacterData_Impl::Data(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetData<Identity: windows_core::IUnknownImpl + IXmlCharacterData_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlCharacterData_Impl::SetData(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Length<Identity: windows_core::IUnknownImpl + IXmlCharacterData_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlCharacterData_Impl::Length(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SubstringData<Identity: windows_core::IUnknownImpl + IXmlCharacterData_Impl>(this: *mut core::ffi::c_void, offset: u32, count: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlCharacterData_Impl::SubstringData(this, offset, count) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AppendData<Identity: windows_core::IUnknownImpl + IXmlCharacterData_Impl>(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlCharacterData_Impl::AppendData(this, core::mem::transmute(&data)).into()
+            }
+        }
+        unsafe extern "system" fn InsertData<Identity: windows_core::IUnknownImpl + IXmlCharacterData_Impl>(this: *mut core::ffi::c_void, offset: u32, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlCharacterData_Impl::InsertData(this, offset, core::mem::transmute(&data)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteData<Identity: windows_core::IUnknownImpl + IXmlCharacterData_Impl>(this: *mut core::ffi::c_void, offset: u32, count: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlCharacterData_Impl::DeleteData(this, offset, count).into()
+            }
+        }
+        unsafe extern "system" fn ReplaceData<Identity: windows_core::IUnknownImpl + IXmlCharacterData_Impl>(this: *mut core::ffi::c_void, offset: u32, count: u32, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlCharacterData_Impl::ReplaceData(this, offset, count, core::mem::transmute(&data)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Data: Data::<Identity>,
+            SetData: SetData::<Identity>,
+            Length: Length::<Identity>,
+            SubstringData: SubstringData::<Identity>,
+            AppendData: AppendData::<Identity>,
+            InsertData: InsertData::<Identity>,
+            DeleteData: DeleteData::<Identity>,
+            ReplaceData: ReplaceData::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IXmlCharacterData_Vtbl {
@@ -1702,6 +1785,312 @@ impl IXmlNode_Vtbl {
         iid == &<IXmlNode as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXmlNode_Impl> windows_core::imp::TearOffVtable<Identity> for IXmlNode_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn NodeValue<Identity: windows_core::IUnknownImpl + IXmlNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNode_Impl::NodeValue(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNodeValue<Identity: windows_core::IUnknownImpl + IXmlNode_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlNode_Impl::SetNodeValue(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn NodeType<Identity: windows_core::IUnknownImpl + IXmlNode_Impl>(this: *mut core::ffi::c_void, result__: *mut NodeType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNode_Impl::NodeType(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NodeName<Identity: windows_core::IUnknownImpl + IXmlNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNode_Impl::NodeName(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ParentNode<Identity: windows_core::IUnknownImpl + IXmlNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNode_Impl::ParentNode(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ChildNodes<Identity: windows_core::IUnknownImpl + IXmlNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNode_Impl::ChildNodes(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FirstChild<Identity: windows_core::IUnknownImpl + IXmlNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNode_Impl::FirstChild(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LastChild<Identity: windows_core::IUnknownImpl + IXmlNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNode_Impl::LastChild(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PreviousSibling<Identity: windows_core::IUnknownImpl + IXmlNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNode_Impl::PreviousSibling(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NextSibling<Identity: windows_core::IUnknownImpl + IXmlNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNode_Impl::NextSibling(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Attributes<Identity: windows_core::IUnknownImpl + IXmlNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNode_Impl::Attributes(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn HasChildNodes<Identity: windows_core::IUnknownImpl + IXmlNode_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNode_Impl::HasChildNodes(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OwnerDocument<Identity: windows_core::IUnknownImpl + IXmlNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNode_Impl::OwnerDocument(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InsertBefore<Identity: windows_core::IUnknownImpl + IXmlNode_Impl>(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void, referencechild: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNode_Impl::InsertBefore(this, core::mem::transmute_copy(&newchild), core::mem::transmute_copy(&referencechild)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReplaceChild<Identity: windows_core::IUnknownImpl + IXmlNode_Impl>(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void, referencechild: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNode_Impl::ReplaceChild(this, core::mem::transmute_copy(&newchild), core::mem::transmute_copy(&referencechild)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveChild<Identity: windows_core::IUnknownImpl + IXmlNode_Impl>(this: *mut core::ffi::c_void, childnode: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNode_Impl::RemoveChild(this, core::mem::transmute_copy(&childnode)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AppendChild<Identity: windows_core::IUnknownImpl + IXmlNode_Impl>(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNode_Impl::AppendChild(this, core::mem::transmute_copy(&newchild)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CloneNode<Identity: windows_core::IUnknownImpl + IXmlNode_Impl>(this: *mut core::ffi::c_void, deep: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNode_Impl::CloneNode(this, deep) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NamespaceUri<Identity: windows_core::IUnknownImpl + IXmlNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNode_Impl::NamespaceUri(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LocalName<Identity: windows_core::IUnknownImpl + IXmlNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNode_Impl::LocalName(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Prefix<Identity: windows_core::IUnknownImpl + IXmlNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNode_Impl::Prefix(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Normalize<Identity: windows_core::IUnknownImpl + IXmlNode_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlNode_Impl::Normalize(this).into()
+            }
+        }
+        unsafe extern "system" fn SetPrefix<Identity: windows_core::IUnknownImpl + IXmlNode_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlNode_Impl::SetPrefix(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            NodeValue: NodeValue::<Identity>,
+            SetNodeValue: SetNodeValue::<Identity>,
+            NodeType: NodeType::<Identity>,
+            NodeName: NodeName::<Identity>,
+            ParentNode: ParentNode::<Identity>,
+            ChildNodes: ChildNodes::<Identity>,
+            FirstChild: FirstChild::<Identity>,
+            LastChild: LastChild::<Identity>,
+            PreviousSibling: PreviousSibling::<Identity>,
+            NextSibling: NextSibling::<Identity>,
+            Attributes: Attributes::<Identity>,
+            HasChildNodes: HasChildNodes::<Identity>,
+            OwnerDocument: OwnerDocument::<Identity>,
+            InsertBefore: InsertBefore::<Identity>,
+            ReplaceChild: ReplaceChild::<Identity>,
+            RemoveChild: RemoveChild::<Identity>,
+            AppendChild: AppendChild::<Identity>,
+            CloneNode: CloneNode::<Identity>,
+            NamespaceUri: NamespaceUri::<Identity>,
+            LocalName: LocalName::<Identity>,
+            Prefix: Prefix::<Identity>,
+            Normalize: Normalize::<Identity>,
+            SetPrefix: SetPrefix::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IXmlNode_Vtbl {
@@ -1857,6 +2246,69 @@ impl IXmlNodeSelector_Vtbl {
         iid == &<IXmlNodeSelector as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXmlNodeSelector_Impl> windows_core::imp::TearOffVtable<Identity> for IXmlNodeSelector_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SelectSingleNode<Identity: windows_core::IUnknownImpl + IXmlNodeSelector_Impl>(this: *mut core::ffi::c_void, xpath: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNodeSelector_Impl::SelectSingleNode(this, core::mem::transmute(&xpath)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SelectNodes<Identity: windows_core::IUnknownImpl + IXmlNodeSelector_Impl>(this: *mut core::ffi::c_void, xpath: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNodeSelector_Impl::SelectNodes(this, core::mem::transmute(&xpath)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SelectSingleNodeNS<Identity: windows_core::IUnknownImpl + IXmlNodeSelector_Impl>(this: *mut core::ffi::c_void, xpath: *mut core::ffi::c_void, namespaces: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNodeSelector_Impl::SelectSingleNodeNS(this, core::mem::transmute(&xpath), core::mem::transmute_copy(&namespaces)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SelectNodesNS<Identity: windows_core::IUnknownImpl + IXmlNodeSelector_Impl>(this: *mut core::ffi::c_void, xpath: *mut core::ffi::c_void, namespaces: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNodeSelector_Impl::SelectNodesNS(this, core::mem::transmute(&xpath), core::mem::transmute_copy(&namespaces)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SelectSingleNode: SelectSingleNode::<Identity>,
+            SelectNodes: SelectNodes::<Identity>,
+            SelectSingleNodeNS: SelectSingleNodeNS::<Identity>,
+            SelectNodesNS: SelectNodesNS::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IXmlNodeSelector_Vtbl {
@@ -1944,6 +2396,48 @@ impl IXmlNodeSerializer_Vtbl {
         iid == &<IXmlNodeSerializer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXmlNodeSerializer_Impl> windows_core::imp::TearOffVtable<Identity> for IXmlNodeSerializer_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetXml<Identity: windows_core::IUnknownImpl + IXmlNodeSerializer_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNodeSerializer_Impl::GetXml(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InnerText<Identity: windows_core::IUnknownImpl + IXmlNodeSerializer_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlNodeSerializer_Impl::InnerText(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetInnerText<Identity: windows_core::IUnknownImpl + IXmlNodeSerializer_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlNodeSerializer_Impl::SetInnerText(this, core::mem::transmute(&value)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetXml: GetXml::<Identity>,
+            InnerText: InnerText::<Identity>,
+            SetInnerText: SetInnerText::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IXmlNodeSerializer_Vtbl {
@@ -2271,6 +2765,24 @@ impl IXmlText_Vtbl {
         iid == &<IXmlText as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXmlText_Impl> windows_core::imp::TearOffVtable<Identity> for IXmlText_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SplitText<Identity: windows_core::IUnknownImpl + IXmlText_Impl>(this: *mut core::ffi::c_void, offset: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlText_Impl::SplitText(this, offset) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SplitText: SplitText::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IXmlText_Vtbl {
@@ -203,6 +203,125 @@ impl IAdcControllerProvider_Vtbl {
         iid == &<IAdcControllerProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAdcControllerProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IAdcControllerProvider_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ChannelCount<Identity: windows_core::IUnknownImpl + IAdcControllerProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAdcControllerProvider_Impl::ChannelCount(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ResolutionInBits<Identity: windows_core::IUnknownImpl + IAdcControllerProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAdcControllerProvider_Impl::ResolutionInBits(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MinValue<Identity: windows_core::IUnknownImpl + IAdcControllerProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAdcControllerProvider_Impl::MinValue(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MaxValue<Identity: windows_core::IUnknownImpl + IAdcControllerProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAdcControllerProvider_Impl::MaxValue(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ChannelMode<Identity: windows_core::IUnknownImpl + IAdcControllerProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut ProviderAdcChannelMode) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAdcControllerProvider_Impl::ChannelMode(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetChannelMode<Identity: windows_core::IUnknownImpl + IAdcControllerProvider_Impl>(this: *mut core::ffi::c_void, value: ProviderAdcChannelMode) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAdcControllerProvider_Impl::SetChannelMode(this, value).into()
+            }
+        }
+        unsafe extern "system" fn IsChannelModeSupported<Identity: windows_core::IUnknownImpl + IAdcControllerProvider_Impl>(this: *mut core::ffi::c_void, channelmode: ProviderAdcChannelMode, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAdcControllerProvider_Impl::IsChannelModeSupported(this, channelmode) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AcquireChannel<Identity: windows_core::IUnknownImpl + IAdcControllerProvider_Impl>(this: *mut core::ffi::c_void, channel: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAdcControllerProvider_Impl::AcquireChannel(this, channel).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseChannel<Identity: windows_core::IUnknownImpl + IAdcControllerProvider_Impl>(this: *mut core::ffi::c_void, channel: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAdcControllerProvider_Impl::ReleaseChannel(this, channel).into()
+            }
+        }
+        unsafe extern "system" fn ReadValue<Identity: windows_core::IUnknownImpl + IAdcControllerProvider_Impl>(this: *mut core::ffi::c_void, channelnumber: i32, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAdcControllerProvider_Impl::ReadValue(this, channelnumber) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ChannelCount: ChannelCount::<Identity>,
+            ResolutionInBits: ResolutionInBits::<Identity>,
+            MinValue: MinValue::<Identity>,
+            MaxValue: MaxValue::<Identity>,
+            ChannelMode: ChannelMode::<Identity>,
+            SetChannelMode: SetChannelMode::<Identity>,
+            IsChannelModeSupported: IsChannelModeSupported::<Identity>,
+            AcquireChannel: AcquireChannel::<Identity>,
+            ReleaseChannel: ReleaseChannel::<Identity>,
+            ReadValue: ReadValue::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAdcControllerProvider_Vtbl {
@@ -259,6 +378,24 @@ impl IAdcProvider_Vtbl {
         iid == &<IAdcProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAdcProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IAdcProvider_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetControllers<Identity: windows_core::IUnknownImpl + IAdcProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAdcProvider_Impl::GetControllers(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetControllers: GetControllers::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAdcProvider_Vtbl {
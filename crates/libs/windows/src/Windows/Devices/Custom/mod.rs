@@ -264,6 +264,78 @@ impl IIOControlCode_Vtbl {
         iid == &<IIOControlCode as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IIOControlCode_Impl> windows_core::imp::TearOffVtable<Identity> for IIOControlCode_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn AccessMode<Identity: windows_core::IUnknownImpl + IIOControlCode_Impl>(this: *mut core::ffi::c_void, result__: *mut IOControlAccessMode) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IIOControlCode_Impl::AccessMode(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn BufferingMethod<Identity: windows_core::IUnknownImpl + IIOControlCode_Impl>(this: *mut core::ffi::c_void, result__: *mut IOControlBufferingMethod) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IIOControlCode_Impl::BufferingMethod(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Function<Identity: windows_core::IUnknownImpl + IIOControlCode_Impl>(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IIOControlCode_Impl::Function(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeviceType<Identity: windows_core::IUnknownImpl + IIOControlCode_Impl>(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IIOControlCode_Impl::DeviceType(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ControlCode<Identity: windows_core::IUnknownImpl + IIOControlCode_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IIOControlCode_Impl::ControlCode(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AccessMode: AccessMode::<Identity>,
+            BufferingMethod: BufferingMethod::<Identity>,
+            Function: Function::<Identity>,
+            DeviceType: DeviceType::<Identity>,
+            ControlCode: ControlCode::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IIOControlCode_Vtbl {
@@ -1842,6 +1842,9 @@ impl IDeviceEnumerationSettings_Vtbl {
         iid == &<IDeviceEnumerationSettings as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDeviceEnumerationSettings_Impl> windows_core::imp::TearOffVtable<Identity> for IDeviceEnumerationSettings_Vtbl {
+    const VTABLE: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IDeviceEnumerationSettings_Vtbl {
@@ -2097,6 +2100,9 @@ impl IDevicePairingSettings_Vtbl {
         iid == &<IDevicePairingSettings as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDevicePairingSettings_Impl> windows_core::imp::TearOffVtable<Identity> for IDevicePairingSettings_Vtbl {
+    const VTABLE: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IDevicePairingSettings_Vtbl {
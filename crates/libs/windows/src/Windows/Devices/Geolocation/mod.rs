@@ -1360,6 +1360,52 @@ impl IGeoshape_Vtbl {
         iid == &<IGeoshape as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IGeoshape_Impl> windows_core::imp::TearOffVtable<Identity> for IGeoshape_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn GeoshapeType<Identity: windows_core::IUnknownImpl + IGeoshape_Impl>(this: *mut core::ffi::c_void, result__: *mut GeoshapeType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGeoshape_Impl::GeoshapeType(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SpatialReferenceId<Identity: windows_core::IUnknownImpl + IGeoshape_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGeoshape_Impl::SpatialReferenceId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AltitudeReferenceSystem<Identity: windows_core::IUnknownImpl + IGeoshape_Impl>(this: *mut core::ffi::c_void, result__: *mut AltitudeReferenceSystem) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGeoshape_Impl::AltitudeReferenceSystem(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GeoshapeType: GeoshapeType::<Identity>,
+            SpatialReferenceId: SpatialReferenceId::<Identity>,
+            AltitudeReferenceSystem: AltitudeReferenceSystem::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IGeoshape_Vtbl {
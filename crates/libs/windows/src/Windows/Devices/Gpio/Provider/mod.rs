@@ -98,6 +98,40 @@ impl IGpioControllerProvider_Vtbl {
         iid == &<IGpioControllerProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IGpioControllerProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IGpioControllerProvider_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn PinCount<Identity: windows_core::IUnknownImpl + IGpioControllerProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGpioControllerProvider_Impl::PinCount(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OpenPinProvider<Identity: windows_core::IUnknownImpl + IGpioControllerProvider_Impl>(this: *mut core::ffi::c_void, pin: i32, sharingmode: ProviderGpioSharingMode, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGpioControllerProvider_Impl::OpenPinProvider(this, pin, sharingmode) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PinCount: PinCount::<Identity>,
+            OpenPinProvider: OpenPinProvider::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IGpioControllerProvider_Vtbl {
@@ -325,6 +359,132 @@ impl IGpioPinProvider_Vtbl {
         iid == &<IGpioPinProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IGpioPinProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IGpioPinProvider_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ValueChanged<Identity: windows_core::IUnknownImpl + IGpioPinProvider_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGpioPinProvider_Impl::ValueChanged(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveValueChanged<Identity: windows_core::IUnknownImpl + IGpioPinProvider_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGpioPinProvider_Impl::RemoveValueChanged(this, token).into()
+            }
+        }
+        unsafe extern "system" fn DebounceTimeout<Identity: windows_core::IUnknownImpl + IGpioPinProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGpioPinProvider_Impl::DebounceTimeout(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDebounceTimeout<Identity: windows_core::IUnknownImpl + IGpioPinProvider_Impl>(this: *mut core::ffi::c_void, value: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGpioPinProvider_Impl::SetDebounceTimeout(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn PinNumber<Identity: windows_core::IUnknownImpl + IGpioPinProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGpioPinProvider_Impl::PinNumber(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SharingMode<Identity: windows_core::IUnknownImpl + IGpioPinProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut ProviderGpioSharingMode) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGpioPinProvider_Impl::SharingMode(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsDriveModeSupported<Identity: windows_core::IUnknownImpl + IGpioPinProvider_Impl>(this: *mut core::ffi::c_void, drivemode: ProviderGpioPinDriveMode, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGpioPinProvider_Impl::IsDriveModeSupported(this, drivemode) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDriveMode<Identity: windows_core::IUnknownImpl + IGpioPinProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut ProviderGpioPinDriveMode) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGpioPinProvider_Impl::GetDriveMode(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDriveMode<Identity: windows_core::IUnknownImpl + IGpioPinProvider_Impl>(this: *mut core::ffi::c_void, value: ProviderGpioPinDriveMode) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGpioPinProvider_Impl::SetDriveMode(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Write<Identity: windows_core::IUnknownImpl + IGpioPinProvider_Impl>(this: *mut core::ffi::c_void, value: ProviderGpioPinValue) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGpioPinProvider_Impl::Write(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Read<Identity: windows_core::IUnknownImpl + IGpioPinProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut ProviderGpioPinValue) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGpioPinProvider_Impl::Read(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ValueChanged: ValueChanged::<Identity>,
+            RemoveValueChanged: RemoveValueChanged::<Identity>,
+            DebounceTimeout: DebounceTimeout::<Identity>,
+            SetDebounceTimeout: SetDebounceTimeout::<Identity>,
+            PinNumber: PinNumber::<Identity>,
+            SharingMode: SharingMode::<Identity>,
+            IsDriveModeSupported: IsDriveModeSupported::<Identity>,
+            GetDriveMode: GetDriveMode::<Identity>,
+            SetDriveMode: SetDriveMode::<Identity>,
+            Write: Write::<Identity>,
+            Read: Read::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IGpioPinProvider_Vtbl {
@@ -402,6 +562,24 @@ impl IGpioProvider_Vtbl {
         iid == &<IGpioProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IGpioProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IGpioProvider_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetControllers<Identity: windows_core::IUnknownImpl + IGpioProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGpioProvider_Impl::GetControllers(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetControllers: GetControllers::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IGpioProvider_Vtbl {
@@ -194,6 +194,33 @@ impl ILampArrayEffect_Vtbl {
         iid == &<ILampArrayEffect as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ILampArrayEffect_Impl> windows_core::imp::TearOffVtable<Identity> for ILampArrayEffect_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ZIndex<Identity: windows_core::IUnknownImpl + ILampArrayEffect_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILampArrayEffect_Impl::ZIndex(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetZIndex<Identity: windows_core::IUnknownImpl + ILampArrayEffect_Impl>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILampArrayEffect_Impl::SetZIndex(this, value).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ZIndex: ZIndex::<Identity>,
+            SetZIndex: SetZIndex::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ILampArrayEffect_Vtbl {
@@ -154,6 +154,54 @@ impl IMidiMessage_Vtbl {
         iid == &<IMidiMessage as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Storage_Streams")]
+impl<Identity: windows_core::IUnknownImpl + IMidiMessage_Impl> windows_core::imp::TearOffVtable<Identity> for IMidiMessage_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Timestamp<Identity: windows_core::IUnknownImpl + IMidiMessage_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMidiMessage_Impl::Timestamp(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RawData<Identity: windows_core::IUnknownImpl + IMidiMessage_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMidiMessage_Impl::RawData(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Type<Identity: windows_core::IUnknownImpl + IMidiMessage_Impl>(this: *mut core::ffi::c_void, result__: *mut MidiMessageType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMidiMessage_Impl::Type(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Timestamp: Timestamp::<Identity>,
+            RawData: RawData::<Identity>,
+            Type: Type::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IMidiMessage_Vtbl {
@@ -302,6 +350,42 @@ impl IMidiOutPort_Vtbl {
         iid == &<IMidiOutPort as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Storage_Streams")]
+impl<Identity: windows_core::IUnknownImpl + IMidiOutPort_Impl> windows_core::imp::TearOffVtable<Identity> for IMidiOutPort_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SendMessage<Identity: windows_core::IUnknownImpl + IMidiOutPort_Impl>(this: *mut core::ffi::c_void, midimessage: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMidiOutPort_Impl::SendMessage(this, core::mem::transmute_copy(&midimessage)).into()
+            }
+        }
+        unsafe extern "system" fn SendBuffer<Identity: windows_core::IUnknownImpl + IMidiOutPort_Impl>(this: *mut core::ffi::c_void, mididata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMidiOutPort_Impl::SendBuffer(this, core::mem::transmute_copy(&mididata)).into()
+            }
+        }
+        unsafe extern "system" fn DeviceId<Identity: windows_core::IUnknownImpl + IMidiOutPort_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMidiOutPort_Impl::DeviceId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SendMessage: SendMessage::<Identity>,
+            SendBuffer: SendBuffer::<Identity>,
+            DeviceId: DeviceId::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IMidiOutPort_Vtbl {
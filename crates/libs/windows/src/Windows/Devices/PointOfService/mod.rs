@@ -3647,6 +3647,24 @@ impl ICashDrawerEventSourceEventArgs_Vtbl {
         iid == &<ICashDrawerEventSourceEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICashDrawerEventSourceEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for ICashDrawerEventSourceEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CashDrawer<Identity: windows_core::IUnknownImpl + ICashDrawerEventSourceEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICashDrawerEventSourceEventArgs_Impl::CashDrawer(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CashDrawer: CashDrawer::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ICashDrawerEventSourceEventArgs_Vtbl {
@@ -4449,6 +4467,230 @@ impl ICommonClaimedPosPrinterStation_Vtbl {
         iid == &<ICommonClaimedPosPrinterStation as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICommonClaimedPosPrinterStation_Impl> windows_core::imp::TearOffVtable<Identity> for ICommonClaimedPosPrinterStation_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetCharactersPerLine<Identity: windows_core::IUnknownImpl + ICommonClaimedPosPrinterStation_Impl>(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICommonClaimedPosPrinterStation_Impl::SetCharactersPerLine(this, value).into()
+            }
+        }
+        unsafe extern "system" fn CharactersPerLine<Identity: windows_core::IUnknownImpl + ICommonClaimedPosPrinterStation_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonClaimedPosPrinterStation_Impl::CharactersPerLine(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLineHeight<Identity: windows_core::IUnknownImpl + ICommonClaimedPosPrinterStation_Impl>(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICommonClaimedPosPrinterStation_Impl::SetLineHeight(this, value).into()
+            }
+        }
+        unsafe extern "system" fn LineHeight<Identity: windows_core::IUnknownImpl + ICommonClaimedPosPrinterStation_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonClaimedPosPrinterStation_Impl::LineHeight(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLineSpacing<Identity: windows_core::IUnknownImpl + ICommonClaimedPosPrinterStation_Impl>(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICommonClaimedPosPrinterStation_Impl::SetLineSpacing(this, value).into()
+            }
+        }
+        unsafe extern "system" fn LineSpacing<Identity: windows_core::IUnknownImpl + ICommonClaimedPosPrinterStation_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonClaimedPosPrinterStation_Impl::LineSpacing(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LineWidth<Identity: windows_core::IUnknownImpl + ICommonClaimedPosPrinterStation_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonClaimedPosPrinterStation_Impl::LineWidth(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetIsLetterQuality<Identity: windows_core::IUnknownImpl + ICommonClaimedPosPrinterStation_Impl>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICommonClaimedPosPrinterStation_Impl::SetIsLetterQuality(this, value).into()
+            }
+        }
+        unsafe extern "system" fn IsLetterQuality<Identity: windows_core::IUnknownImpl + ICommonClaimedPosPrinterStation_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonClaimedPosPrinterStation_Impl::IsLetterQuality(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsPaperNearEnd<Identity: windows_core::IUnknownImpl + ICommonClaimedPosPrinterStation_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonClaimedPosPrinterStation_Impl::IsPaperNearEnd(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetColorCartridge<Identity: windows_core::IUnknownImpl + ICommonClaimedPosPrinterStation_Impl>(this: *mut core::ffi::c_void, value: PosPrinterColorCartridge) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICommonClaimedPosPrinterStation_Impl::SetColorCartridge(this, value).into()
+            }
+        }
+        unsafe extern "system" fn ColorCartridge<Identity: windows_core::IUnknownImpl + ICommonClaimedPosPrinterStation_Impl>(this: *mut core::ffi::c_void, result__: *mut PosPrinterColorCartridge) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonClaimedPosPrinterStation_Impl::ColorCartridge(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsCoverOpen<Identity: windows_core::IUnknownImpl + ICommonClaimedPosPrinterStation_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonClaimedPosPrinterStation_Impl::IsCoverOpen(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsCartridgeRemoved<Identity: windows_core::IUnknownImpl + ICommonClaimedPosPrinterStation_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonClaimedPosPrinterStation_Impl::IsCartridgeRemoved(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsCartridgeEmpty<Identity: windows_core::IUnknownImpl + ICommonClaimedPosPrinterStation_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonClaimedPosPrinterStation_Impl::IsCartridgeEmpty(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsHeadCleaning<Identity: windows_core::IUnknownImpl + ICommonClaimedPosPrinterStation_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonClaimedPosPrinterStation_Impl::IsHeadCleaning(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsPaperEmpty<Identity: windows_core::IUnknownImpl + ICommonClaimedPosPrinterStation_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonClaimedPosPrinterStation_Impl::IsPaperEmpty(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsReadyToPrint<Identity: windows_core::IUnknownImpl + ICommonClaimedPosPrinterStation_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonClaimedPosPrinterStation_Impl::IsReadyToPrint(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ValidateData<Identity: windows_core::IUnknownImpl + ICommonClaimedPosPrinterStation_Impl>(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonClaimedPosPrinterStation_Impl::ValidateData(this, core::mem::transmute(&data)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetCharactersPerLine: SetCharactersPerLine::<Identity>,
+            CharactersPerLine: CharactersPerLine::<Identity>,
+            SetLineHeight: SetLineHeight::<Identity>,
+            LineHeight: LineHeight::<Identity>,
+            SetLineSpacing: SetLineSpacing::<Identity>,
+            LineSpacing: LineSpacing::<Identity>,
+            LineWidth: LineWidth::<Identity>,
+            SetIsLetterQuality: SetIsLetterQuality::<Identity>,
+            IsLetterQuality: IsLetterQuality::<Identity>,
+            IsPaperNearEnd: IsPaperNearEnd::<Identity>,
+            SetColorCartridge: SetColorCartridge::<Identity>,
+            ColorCartridge: ColorCartridge::<Identity>,
+            IsCoverOpen: IsCoverOpen::<Identity>,
+            IsCartridgeRemoved: IsCartridgeRemoved::<Identity>,
+            IsCartridgeEmpty: IsCartridgeEmpty::<Identity>,
+            IsHeadCleaning: IsHeadCleaning::<Identity>,
+            IsPaperEmpty: IsPaperEmpty::<Identity>,
+            IsReadyToPrint: IsReadyToPrint::<Identity>,
+            ValidateData: ValidateData::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ICommonClaimedPosPrinterStation_Vtbl {
@@ -4769,6 +5011,183 @@ impl ICommonPosPrintStationCapabilities_Vtbl {
         iid == &<ICommonPosPrintStationCapabilities as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICommonPosPrintStationCapabilities_Impl> windows_core::imp::TearOffVtable<Identity> for ICommonPosPrintStationCapabilities_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsPrinterPresent<Identity: windows_core::IUnknownImpl + ICommonPosPrintStationCapabilities_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonPosPrintStationCapabilities_Impl::IsPrinterPresent(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsDualColorSupported<Identity: windows_core::IUnknownImpl + ICommonPosPrintStationCapabilities_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonPosPrintStationCapabilities_Impl::IsDualColorSupported(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ColorCartridgeCapabilities<Identity: windows_core::IUnknownImpl + ICommonPosPrintStationCapabilities_Impl>(this: *mut core::ffi::c_void, result__: *mut PosPrinterColorCapabilities) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonPosPrintStationCapabilities_Impl::ColorCartridgeCapabilities(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CartridgeSensors<Identity: windows_core::IUnknownImpl + ICommonPosPrintStationCapabilities_Impl>(this: *mut core::ffi::c_void, result__: *mut PosPrinterCartridgeSensors) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonPosPrintStationCapabilities_Impl::CartridgeSensors(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsBoldSupported<Identity: windows_core::IUnknownImpl + ICommonPosPrintStationCapabilities_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonPosPrintStationCapabilities_Impl::IsBoldSupported(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsItalicSupported<Identity: windows_core::IUnknownImpl + ICommonPosPrintStationCapabilities_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonPosPrintStationCapabilities_Impl::IsItalicSupported(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsUnderlineSupported<Identity: windows_core::IUnknownImpl + ICommonPosPrintStationCapabilities_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonPosPrintStationCapabilities_Impl::IsUnderlineSupported(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsDoubleHighPrintSupported<Identity: windows_core::IUnknownImpl + ICommonPosPrintStationCapabilities_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonPosPrintStationCapabilities_Impl::IsDoubleHighPrintSupported(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsDoubleWidePrintSupported<Identity: windows_core::IUnknownImpl + ICommonPosPrintStationCapabilities_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonPosPrintStationCapabilities_Impl::IsDoubleWidePrintSupported(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsDoubleHighDoubleWidePrintSupported<Identity: windows_core::IUnknownImpl + ICommonPosPrintStationCapabilities_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonPosPrintStationCapabilities_Impl::IsDoubleHighDoubleWidePrintSupported(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsPaperEmptySensorSupported<Identity: windows_core::IUnknownImpl + ICommonPosPrintStationCapabilities_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonPosPrintStationCapabilities_Impl::IsPaperEmptySensorSupported(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsPaperNearEndSensorSupported<Identity: windows_core::IUnknownImpl + ICommonPosPrintStationCapabilities_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonPosPrintStationCapabilities_Impl::IsPaperNearEndSensorSupported(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SupportedCharactersPerLine<Identity: windows_core::IUnknownImpl + ICommonPosPrintStationCapabilities_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonPosPrintStationCapabilities_Impl::SupportedCharactersPerLine(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsPrinterPresent: IsPrinterPresent::<Identity>,
+            IsDualColorSupported: IsDualColorSupported::<Identity>,
+            ColorCartridgeCapabilities: ColorCartridgeCapabilities::<Identity>,
+            CartridgeSensors: CartridgeSensors::<Identity>,
+            IsBoldSupported: IsBoldSupported::<Identity>,
+            IsItalicSupported: IsItalicSupported::<Identity>,
+            IsUnderlineSupported: IsUnderlineSupported::<Identity>,
+            IsDoubleHighPrintSupported: IsDoubleHighPrintSupported::<Identity>,
+            IsDoubleWidePrintSupported: IsDoubleWidePrintSupported::<Identity>,
+            IsDoubleHighDoubleWidePrintSupported: IsDoubleHighDoubleWidePrintSupported::<Identity>,
+            IsPaperEmptySensorSupported: IsPaperEmptySensorSupported::<Identity>,
+            IsPaperNearEndSensorSupported: IsPaperNearEndSensorSupported::<Identity>,
+            SupportedCharactersPerLine: SupportedCharactersPerLine::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ICommonPosPrintStationCapabilities_Vtbl {
@@ -5092,6 +5511,132 @@ impl ICommonReceiptSlipCapabilities_Vtbl {
         iid == &<ICommonReceiptSlipCapabilities as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICommonReceiptSlipCapabilities_Impl> windows_core::imp::TearOffVtable<Identity> for ICommonReceiptSlipCapabilities_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsBarcodeSupported<Identity: windows_core::IUnknownImpl + ICommonReceiptSlipCapabilities_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonReceiptSlipCapabilities_Impl::IsBarcodeSupported(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsBitmapSupported<Identity: windows_core::IUnknownImpl + ICommonReceiptSlipCapabilities_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonReceiptSlipCapabilities_Impl::IsBitmapSupported(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsLeft90RotationSupported<Identity: windows_core::IUnknownImpl + ICommonReceiptSlipCapabilities_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonReceiptSlipCapabilities_Impl::IsLeft90RotationSupported(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsRight90RotationSupported<Identity: windows_core::IUnknownImpl + ICommonReceiptSlipCapabilities_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonReceiptSlipCapabilities_Impl::IsRight90RotationSupported(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Is180RotationSupported<Identity: windows_core::IUnknownImpl + ICommonReceiptSlipCapabilities_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonReceiptSlipCapabilities_Impl::Is180RotationSupported(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsPrintAreaSupported<Identity: windows_core::IUnknownImpl + ICommonReceiptSlipCapabilities_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonReceiptSlipCapabilities_Impl::IsPrintAreaSupported(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RuledLineCapabilities<Identity: windows_core::IUnknownImpl + ICommonReceiptSlipCapabilities_Impl>(this: *mut core::ffi::c_void, result__: *mut PosPrinterRuledLineCapabilities) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonReceiptSlipCapabilities_Impl::RuledLineCapabilities(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SupportedBarcodeRotations<Identity: windows_core::IUnknownImpl + ICommonReceiptSlipCapabilities_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonReceiptSlipCapabilities_Impl::SupportedBarcodeRotations(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SupportedBitmapRotations<Identity: windows_core::IUnknownImpl + ICommonReceiptSlipCapabilities_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICommonReceiptSlipCapabilities_Impl::SupportedBitmapRotations(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsBarcodeSupported: IsBarcodeSupported::<Identity>,
+            IsBitmapSupported: IsBitmapSupported::<Identity>,
+            IsLeft90RotationSupported: IsLeft90RotationSupported::<Identity>,
+            IsRight90RotationSupported: IsRight90RotationSupported::<Identity>,
+            Is180RotationSupported: Is180RotationSupported::<Identity>,
+            IsPrintAreaSupported: IsPrintAreaSupported::<Identity>,
+            RuledLineCapabilities: RuledLineCapabilities::<Identity>,
+            SupportedBarcodeRotations: SupportedBarcodeRotations::<Identity>,
+            SupportedBitmapRotations: SupportedBitmapRotations::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ICommonReceiptSlipCapabilities_Vtbl {
@@ -5762,6 +6307,48 @@ impl IPosPrinterJob_Vtbl {
         iid == &<IPosPrinterJob as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPosPrinterJob_Impl> windows_core::imp::TearOffVtable<Identity> for IPosPrinterJob_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Print<Identity: windows_core::IUnknownImpl + IPosPrinterJob_Impl>(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPosPrinterJob_Impl::Print(this, core::mem::transmute(&data)).into()
+            }
+        }
+        unsafe extern "system" fn PrintLine<Identity: windows_core::IUnknownImpl + IPosPrinterJob_Impl>(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPosPrinterJob_Impl::PrintLine(this, core::mem::transmute(&data)).into()
+            }
+        }
+        unsafe extern "system" fn PrintNewline<Identity: windows_core::IUnknownImpl + IPosPrinterJob_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPosPrinterJob_Impl::PrintNewline(this).into()
+            }
+        }
+        unsafe extern "system" fn ExecuteAsync<Identity: windows_core::IUnknownImpl + IPosPrinterJob_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPosPrinterJob_Impl::ExecuteAsync(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Print: Print::<Identity>,
+            PrintLine: PrintLine::<Identity>,
+            PrintNewline: PrintNewline::<Identity>,
+            ExecuteAsync: ExecuteAsync::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPosPrinterJob_Vtbl {
@@ -6115,6 +6702,119 @@ impl IReceiptOrSlipJob_Vtbl {
         iid == &<IReceiptOrSlipJob as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Graphics_Imaging")]
+impl<Identity: windows_core::IUnknownImpl + IReceiptOrSlipJob_Impl> windows_core::imp::TearOffVtable<Identity> for IReceiptOrSlipJob_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetBarcodeRotation<Identity: windows_core::IUnknownImpl + IReceiptOrSlipJob_Impl>(this: *mut core::ffi::c_void, value: PosPrinterRotation) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IReceiptOrSlipJob_Impl::SetBarcodeRotation(this, value).into()
+            }
+        }
+        unsafe extern "system" fn SetPrintRotation<Identity: windows_core::IUnknownImpl + IReceiptOrSlipJob_Impl>(this: *mut core::ffi::c_void, value: PosPrinterRotation, includebitmaps: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IReceiptOrSlipJob_Impl::SetPrintRotation(this, value, includebitmaps).into()
+            }
+        }
+        unsafe extern "system" fn SetPrintArea<Identity: windows_core::IUnknownImpl + IReceiptOrSlipJob_Impl>(this: *mut core::ffi::c_void, value: super::super::Foundation::Rect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IReceiptOrSlipJob_Impl::SetPrintArea(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn SetBitmap<Identity: windows_core::IUnknownImpl + IReceiptOrSlipJob_Impl>(this: *mut core::ffi::c_void, bitmapnumber: u32, bitmap: *mut core::ffi::c_void, alignment: PosPrinterAlignment) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IReceiptOrSlipJob_Impl::SetBitmap(this, bitmapnumber, core::mem::transmute_copy(&bitmap), alignment).into()
+            }
+        }
+        unsafe extern "system" fn SetBitmapCustomWidthStandardAlign<Identity: windows_core::IUnknownImpl + IReceiptOrSlipJob_Impl>(this: *mut core::ffi::c_void, bitmapnumber: u32, bitmap: *mut core::ffi::c_void, alignment: PosPrinterAlignment, width: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IReceiptOrSlipJob_Impl::SetBitmapCustomWidthStandardAlign(this, bitmapnumber, core::mem::transmute_copy(&bitmap), alignment, width).into()
+            }
+        }
+        unsafe extern "system" fn SetCustomAlignedBitmap<Identity: windows_core::IUnknownImpl + IReceiptOrSlipJob_Impl>(this: *mut core::ffi::c_void, bitmapnumber: u32, bitmap: *mut core::ffi::c_void, alignmentdistance: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IReceiptOrSlipJob_Impl::SetCustomAlignedBitmap(this, bitmapnumber, core::mem::transmute_copy(&bitmap), alignmentdistance).into()
+            }
+        }
+        unsafe extern "system" fn SetBitmapCustomWidthCustomAlign<Identity: windows_core::IUnknownImpl + IReceiptOrSlipJob_Impl>(this: *mut core::ffi::c_void, bitmapnumber: u32, bitmap: *mut core::ffi::c_void, alignmentdistance: u32, width: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IReceiptOrSlipJob_Impl::SetBitmapCustomWidthCustomAlign(this, bitmapnumber, core::mem::transmute_copy(&bitmap), alignmentdistance, width).into()
+            }
+        }
+        unsafe extern "system" fn PrintSavedBitmap<Identity: windows_core::IUnknownImpl + IReceiptOrSlipJob_Impl>(this: *mut core::ffi::c_void, bitmapnumber: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IReceiptOrSlipJob_Impl::PrintSavedBitmap(this, bitmapnumber).into()
+            }
+        }
+        unsafe extern "system" fn DrawRuledLine<Identity: windows_core::IUnknownImpl + IReceiptOrSlipJob_Impl>(this: *mut core::ffi::c_void, positionlist: *mut core::ffi::c_void, linedirection: PosPrinterLineDirection, linewidth: u32, linestyle: PosPrinterLineStyle, linecolor: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IReceiptOrSlipJob_Impl::DrawRuledLine(this, core::mem::transmute(&positionlist), linedirection, linewidth, linestyle, linecolor).into()
+            }
+        }
+        unsafe extern "system" fn PrintBarcode<Identity: windows_core::IUnknownImpl + IReceiptOrSlipJob_Impl>(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, symbology: u32, height: u32, width: u32, textposition: PosPrinterBarcodeTextPosition, alignment: PosPrinterAlignment) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IReceiptOrSlipJob_Impl::PrintBarcode(this, core::mem::transmute(&data), symbology, height, width, textposition, alignment).into()
+            }
+        }
+        unsafe extern "system" fn PrintBarcodeCustomAlign<Identity: windows_core::IUnknownImpl + IReceiptOrSlipJob_Impl>(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, symbology: u32, height: u32, width: u32, textposition: PosPrinterBarcodeTextPosition, alignmentdistance: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IReceiptOrSlipJob_Impl::PrintBarcodeCustomAlign(this, core::mem::transmute(&data), symbology, height, width, textposition, alignmentdistance).into()
+            }
+        }
+        unsafe extern "system" fn PrintBitmap<Identity: windows_core::IUnknownImpl + IReceiptOrSlipJob_Impl>(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, alignment: PosPrinterAlignment) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IReceiptOrSlipJob_Impl::PrintBitmap(this, core::mem::transmute_copy(&bitmap), alignment).into()
+            }
+        }
+        unsafe extern "system" fn PrintBitmapCustomWidthStandardAlign<Identity: windows_core::IUnknownImpl + IReceiptOrSlipJob_Impl>(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, alignment: PosPrinterAlignment, width: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IReceiptOrSlipJob_Impl::PrintBitmapCustomWidthStandardAlign(this, core::mem::transmute_copy(&bitmap), alignment, width).into()
+            }
+        }
+        unsafe extern "system" fn PrintCustomAlignedBitmap<Identity: windows_core::IUnknownImpl + IReceiptOrSlipJob_Impl>(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, alignmentdistance: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IReceiptOrSlipJob_Impl::PrintCustomAlignedBitmap(this, core::mem::transmute_copy(&bitmap), alignmentdistance).into()
+            }
+        }
+        unsafe extern "system" fn PrintBitmapCustomWidthCustomAlign<Identity: windows_core::IUnknownImpl + IReceiptOrSlipJob_Impl>(this: *mut core::ffi::c_void, bitmap: *mut core::ffi::c_void, alignmentdistance: u32, width: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IReceiptOrSlipJob_Impl::PrintBitmapCustomWidthCustomAlign(this, core::mem::transmute_copy(&bitmap), alignmentdistance, width).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetBarcodeRotation: SetBarcodeRotation::<Identity>,
+            SetPrintRotation: SetPrintRotation::<Identity>,
+            SetPrintArea: SetPrintArea::<Identity>,
+            SetBitmap: SetBitmap::<Identity>,
+            SetBitmapCustomWidthStandardAlign: SetBitmapCustomWidthStandardAlign::<Identity>,
+            SetCustomAlignedBitmap: SetCustomAlignedBitmap::<Identity>,
+            SetBitmapCustomWidthCustomAlign: SetBitmapCustomWidthCustomAlign::<Identity>,
+            PrintSavedBitmap: PrintSavedBitmap::<Identity>,
+            DrawRuledLine: DrawRuledLine::<Identity>,
+            PrintBarcode: PrintBarcode::<Identity>,
+            PrintBarcodeCustomAlign: PrintBarcodeCustomAlign::<Identity>,
+            PrintBitmap: PrintBitmap::<Identity>,
+            PrintBitmapCustomWidthStandardAlign: PrintBitmapCustomWidthStandardAlign::<Identity>,
+            PrintCustomAlignedBitmap: PrintCustomAlignedBitmap::<Identity>,
+            PrintBitmapCustomWidthCustomAlign: PrintBitmapCustomWidthCustomAlign::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IReceiptOrSlipJob_Vtbl {
@@ -185,6 +185,113 @@ impl IPwmControllerProvider_Vtbl {
         iid == &<IPwmControllerProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPwmControllerProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IPwmControllerProvider_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn PinCount<Identity: windows_core::IUnknownImpl + IPwmControllerProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPwmControllerProvider_Impl::PinCount(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ActualFrequency<Identity: windows_core::IUnknownImpl + IPwmControllerProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPwmControllerProvider_Impl::ActualFrequency(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDesiredFrequency<Identity: windows_core::IUnknownImpl + IPwmControllerProvider_Impl>(this: *mut core::ffi::c_void, frequency: f64, result__: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPwmControllerProvider_Impl::SetDesiredFrequency(this, frequency) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MaxFrequency<Identity: windows_core::IUnknownImpl + IPwmControllerProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPwmControllerProvider_Impl::MaxFrequency(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MinFrequency<Identity: windows_core::IUnknownImpl + IPwmControllerProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPwmControllerProvider_Impl::MinFrequency(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AcquirePin<Identity: windows_core::IUnknownImpl + IPwmControllerProvider_Impl>(this: *mut core::ffi::c_void, pin: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPwmControllerProvider_Impl::AcquirePin(this, pin).into()
+            }
+        }
+        unsafe extern "system" fn ReleasePin<Identity: windows_core::IUnknownImpl + IPwmControllerProvider_Impl>(this: *mut core::ffi::c_void, pin: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPwmControllerProvider_Impl::ReleasePin(this, pin).into()
+            }
+        }
+        unsafe extern "system" fn EnablePin<Identity: windows_core::IUnknownImpl + IPwmControllerProvider_Impl>(this: *mut core::ffi::c_void, pin: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPwmControllerProvider_Impl::EnablePin(this, pin).into()
+            }
+        }
+        unsafe extern "system" fn DisablePin<Identity: windows_core::IUnknownImpl + IPwmControllerProvider_Impl>(this: *mut core::ffi::c_void, pin: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPwmControllerProvider_Impl::DisablePin(this, pin).into()
+            }
+        }
+        unsafe extern "system" fn SetPulseParameters<Identity: windows_core::IUnknownImpl + IPwmControllerProvider_Impl>(this: *mut core::ffi::c_void, pin: i32, dutycycle: f64, invertpolarity: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPwmControllerProvider_Impl::SetPulseParameters(this, pin, dutycycle, invertpolarity).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PinCount: PinCount::<Identity>,
+            ActualFrequency: ActualFrequency::<Identity>,
+            SetDesiredFrequency: SetDesiredFrequency::<Identity>,
+            MaxFrequency: MaxFrequency::<Identity>,
+            MinFrequency: MinFrequency::<Identity>,
+            AcquirePin: AcquirePin::<Identity>,
+            ReleasePin: ReleasePin::<Identity>,
+            EnablePin: EnablePin::<Identity>,
+            DisablePin: DisablePin::<Identity>,
+            SetPulseParameters: SetPulseParameters::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPwmControllerProvider_Vtbl {
@@ -241,6 +348,24 @@ impl IPwmProvider_Vtbl {
         iid == &<IPwmProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPwmProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IPwmProvider_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetControllers<Identity: windows_core::IUnknownImpl + IPwmProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPwmProvider_Impl::GetControllers(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetControllers: GetControllers::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPwmProvider_Vtbl {
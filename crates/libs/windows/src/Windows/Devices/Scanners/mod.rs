@@ -160,6 +160,59 @@ impl IImageScannerFormatConfiguration_Vtbl {
         iid == &<IImageScannerFormatConfiguration as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IImageScannerFormatConfiguration_Impl> windows_core::imp::TearOffVtable<Identity> for IImageScannerFormatConfiguration_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn DefaultFormat<Identity: windows_core::IUnknownImpl + IImageScannerFormatConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut ImageScannerFormat) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerFormatConfiguration_Impl::DefaultFormat(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Format<Identity: windows_core::IUnknownImpl + IImageScannerFormatConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut ImageScannerFormat) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerFormatConfiguration_Impl::Format(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetFormat<Identity: windows_core::IUnknownImpl + IImageScannerFormatConfiguration_Impl>(this: *mut core::ffi::c_void, value: ImageScannerFormat) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IImageScannerFormatConfiguration_Impl::SetFormat(this, value).into()
+            }
+        }
+        unsafe extern "system" fn IsFormatSupported<Identity: windows_core::IUnknownImpl + IImageScannerFormatConfiguration_Impl>(this: *mut core::ffi::c_void, value: ImageScannerFormat, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerFormatConfiguration_Impl::IsFormatSupported(this, value) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DefaultFormat: DefaultFormat::<Identity>,
+            Format: Format::<Identity>,
+            SetFormat: SetFormat::<Identity>,
+            IsFormatSupported: IsFormatSupported::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IImageScannerFormatConfiguration_Vtbl {
@@ -796,6 +849,354 @@ impl IImageScannerSourceConfiguration_Vtbl {
         iid == &<IImageScannerSourceConfiguration as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl> windows_core::imp::TearOffVtable<Identity> for IImageScannerSourceConfiguration_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn MinScanArea<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerSourceConfiguration_Impl::MinScanArea(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MaxScanArea<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Size) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerSourceConfiguration_Impl::MaxScanArea(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SelectedScanRegion<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerSourceConfiguration_Impl::SelectedScanRegion(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSelectedScanRegion<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, value: super::super::Foundation::Rect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IImageScannerSourceConfiguration_Impl::SetSelectedScanRegion(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn AutoCroppingMode<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut ImageScannerAutoCroppingMode) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerSourceConfiguration_Impl::AutoCroppingMode(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAutoCroppingMode<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, value: ImageScannerAutoCroppingMode) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IImageScannerSourceConfiguration_Impl::SetAutoCroppingMode(this, value).into()
+            }
+        }
+        unsafe extern "system" fn IsAutoCroppingModeSupported<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, value: ImageScannerAutoCroppingMode, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerSourceConfiguration_Impl::IsAutoCroppingModeSupported(this, value) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MinResolution<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut ImageScannerResolution) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerSourceConfiguration_Impl::MinResolution(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MaxResolution<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut ImageScannerResolution) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerSourceConfiguration_Impl::MaxResolution(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OpticalResolution<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut ImageScannerResolution) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerSourceConfiguration_Impl::OpticalResolution(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DesiredResolution<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut ImageScannerResolution) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerSourceConfiguration_Impl::DesiredResolution(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDesiredResolution<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, value: ImageScannerResolution) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IImageScannerSourceConfiguration_Impl::SetDesiredResolution(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn ActualResolution<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut ImageScannerResolution) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerSourceConfiguration_Impl::ActualResolution(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DefaultColorMode<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut ImageScannerColorMode) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerSourceConfiguration_Impl::DefaultColorMode(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ColorMode<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut ImageScannerColorMode) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerSourceConfiguration_Impl::ColorMode(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetColorMode<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, value: ImageScannerColorMode) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IImageScannerSourceConfiguration_Impl::SetColorMode(this, value).into()
+            }
+        }
+        unsafe extern "system" fn IsColorModeSupported<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, value: ImageScannerColorMode, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerSourceConfiguration_Impl::IsColorModeSupported(this, value) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MinBrightness<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerSourceConfiguration_Impl::MinBrightness(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MaxBrightness<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerSourceConfiguration_Impl::MaxBrightness(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn BrightnessStep<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerSourceConfiguration_Impl::BrightnessStep(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DefaultBrightness<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerSourceConfiguration_Impl::DefaultBrightness(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Brightness<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerSourceConfiguration_Impl::Brightness(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBrightness<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IImageScannerSourceConfiguration_Impl::SetBrightness(this, value).into()
+            }
+        }
+        unsafe extern "system" fn MinContrast<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerSourceConfiguration_Impl::MinContrast(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MaxContrast<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerSourceConfiguration_Impl::MaxContrast(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ContrastStep<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerSourceConfiguration_Impl::ContrastStep(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DefaultContrast<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerSourceConfiguration_Impl::DefaultContrast(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Contrast<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImageScannerSourceConfiguration_Impl::Contrast(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetContrast<Identity: windows_core::IUnknownImpl + IImageScannerSourceConfiguration_Impl>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IImageScannerSourceConfiguration_Impl::SetContrast(this, value).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MinScanArea: MinScanArea::<Identity>,
+            MaxScanArea: MaxScanArea::<Identity>,
+            SelectedScanRegion: SelectedScanRegion::<Identity>,
+            SetSelectedScanRegion: SetSelectedScanRegion::<Identity>,
+            AutoCroppingMode: AutoCroppingMode::<Identity>,
+            SetAutoCroppingMode: SetAutoCroppingMode::<Identity>,
+            IsAutoCroppingModeSupported: IsAutoCroppingModeSupported::<Identity>,
+            MinResolution: MinResolution::<Identity>,
+            MaxResolution: MaxResolution::<Identity>,
+            OpticalResolution: OpticalResolution::<Identity>,
+            DesiredResolution: DesiredResolution::<Identity>,
+            SetDesiredResolution: SetDesiredResolution::<Identity>,
+            ActualResolution: ActualResolution::<Identity>,
+            DefaultColorMode: DefaultColorMode::<Identity>,
+            ColorMode: ColorMode::<Identity>,
+            SetColorMode: SetColorMode::<Identity>,
+            IsColorModeSupported: IsColorModeSupported::<Identity>,
+            MinBrightness: MinBrightness::<Identity>,
+            MaxBrightness: MaxBrightness::<Identity>,
+            BrightnessStep: BrightnessStep::<Identity>,
+            DefaultBrightness: DefaultBrightness::<Identity>,
+            Brightness: Brightness::<Identity>,
+            SetBrightness: SetBrightness::<Identity>,
+            MinContrast: MinContrast::<Identity>,
+            MaxContrast: MaxContrast::<Identity>,
+            ContrastStep: ContrastStep::<Identity>,
+            DefaultContrast: DefaultContrast::<Identity>,
+            Contrast: Contrast::<Identity>,
+            SetContrast: SetContrast::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IImageScannerSourceConfiguration_Vtbl {
@@ -3401,6 +3401,69 @@ impl IHumanPresenceSensorExtension_Vtbl {
         iid == &<IHumanPresenceSensorExtension as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IHumanPresenceSensorExtension_Impl> windows_core::imp::TearOffVtable<Identity> for IHumanPresenceSensorExtension_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IHumanPresenceSensorExtension_Impl>(this: *mut core::ffi::c_void, deviceinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHumanPresenceSensorExtension_Impl::Initialize(this, core::mem::transmute(&deviceinterface)).into()
+            }
+        }
+        unsafe extern "system" fn Start<Identity: windows_core::IUnknownImpl + IHumanPresenceSensorExtension_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHumanPresenceSensorExtension_Impl::Start(this).into()
+            }
+        }
+        unsafe extern "system" fn ProcessReading<Identity: windows_core::IUnknownImpl + IHumanPresenceSensorExtension_Impl>(this: *mut core::ffi::c_void, reading: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHumanPresenceSensorExtension_Impl::ProcessReading(this, core::mem::transmute_copy(&reading)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ProcessReadingTimeoutExpired<Identity: windows_core::IUnknownImpl + IHumanPresenceSensorExtension_Impl>(this: *mut core::ffi::c_void, reading: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHumanPresenceSensorExtension_Impl::ProcessReadingTimeoutExpired(this, core::mem::transmute_copy(&reading)).into()
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + IHumanPresenceSensorExtension_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHumanPresenceSensorExtension_Impl::Stop(this).into()
+            }
+        }
+        unsafe extern "system" fn Uninitialize<Identity: windows_core::IUnknownImpl + IHumanPresenceSensorExtension_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHumanPresenceSensorExtension_Impl::Uninitialize(this).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IHumanPresenceSensorExtension_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHumanPresenceSensorExtension_Impl::Reset(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            Start: Start::<Identity>,
+            ProcessReading: ProcessReading::<Identity>,
+            ProcessReadingTimeoutExpired: ProcessReadingTimeoutExpired::<Identity>,
+            Stop: Stop::<Identity>,
+            Uninitialize: Uninitialize::<Identity>,
+            Reset: Reset::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IHumanPresenceSensorExtension_Vtbl {
@@ -4353,6 +4416,9 @@ impl ISensorDataThreshold_Vtbl {
         iid == &<ISensorDataThreshold as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISensorDataThreshold_Impl> windows_core::imp::TearOffVtable<Identity> for ISensorDataThreshold_Vtbl {
+    const VTABLE: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ISensorDataThreshold_Vtbl {
@@ -159,6 +159,55 @@ impl ISmsBinaryMessage_Vtbl {
         iid == &<ISmsBinaryMessage as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISmsBinaryMessage_Impl> windows_core::imp::TearOffVtable<Identity> for ISmsBinaryMessage_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Format<Identity: windows_core::IUnknownImpl + ISmsBinaryMessage_Impl>(this: *mut core::ffi::c_void, result__: *mut SmsDataFormat) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsBinaryMessage_Impl::Format(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetFormat<Identity: windows_core::IUnknownImpl + ISmsBinaryMessage_Impl>(this: *mut core::ffi::c_void, value: SmsDataFormat) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISmsBinaryMessage_Impl::SetFormat(this, value).into()
+            }
+        }
+        unsafe extern "system" fn GetData<Identity: windows_core::IUnknownImpl + ISmsBinaryMessage_Impl>(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsBinaryMessage_Impl::GetData(this) {
+                    Ok(ok__) => {
+                        let (ok_data__, ok_data_len__) = ok__.into_abi();
+                        result__.write(core::mem::transmute(ok_data__));
+                        result_size__.write(ok_data_len__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetData<Identity: windows_core::IUnknownImpl + ISmsBinaryMessage_Impl>(this: *mut core::ffi::c_void, value_array_size: u32, value: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISmsBinaryMessage_Impl::SetData(this, core::slice::from_raw_parts(core::mem::transmute_copy(&value), value_array_size as usize)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Format: Format::<Identity>,
+            SetFormat: SetFormat::<Identity>,
+            GetData: GetData::<Identity>,
+            SetData: SetData::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ISmsBinaryMessage_Vtbl {
@@ -416,6 +465,134 @@ impl ISmsDevice_Vtbl {
         iid == &<ISmsDevice as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISmsDevice_Impl> windows_core::imp::TearOffVtable<Identity> for ISmsDevice_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SendMessageAsync<Identity: windows_core::IUnknownImpl + ISmsDevice_Impl>(this: *mut core::ffi::c_void, message: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsDevice_Impl::SendMessageAsync(this, core::mem::transmute_copy(&message)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CalculateLength<Identity: windows_core::IUnknownImpl + ISmsDevice_Impl>(this: *mut core::ffi::c_void, message: *mut core::ffi::c_void, result__: *mut SmsEncodedLength) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsDevice_Impl::CalculateLength(this, core::mem::transmute_copy(&message)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AccountPhoneNumber<Identity: windows_core::IUnknownImpl + ISmsDevice_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsDevice_Impl::AccountPhoneNumber(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CellularClass<Identity: windows_core::IUnknownImpl + ISmsDevice_Impl>(this: *mut core::ffi::c_void, result__: *mut CellularClass) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsDevice_Impl::CellularClass(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MessageStore<Identity: windows_core::IUnknownImpl + ISmsDevice_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsDevice_Impl::MessageStore(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeviceStatus<Identity: windows_core::IUnknownImpl + ISmsDevice_Impl>(this: *mut core::ffi::c_void, result__: *mut SmsDeviceStatus) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsDevice_Impl::DeviceStatus(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SmsMessageReceived<Identity: windows_core::IUnknownImpl + ISmsDevice_Impl>(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsDevice_Impl::SmsMessageReceived(this, core::mem::transmute_copy(&eventhandler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveSmsMessageReceived<Identity: windows_core::IUnknownImpl + ISmsDevice_Impl>(this: *mut core::ffi::c_void, eventcookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISmsDevice_Impl::RemoveSmsMessageReceived(this, eventcookie).into()
+            }
+        }
+        unsafe extern "system" fn SmsDeviceStatusChanged<Identity: windows_core::IUnknownImpl + ISmsDevice_Impl>(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsDevice_Impl::SmsDeviceStatusChanged(this, core::mem::transmute_copy(&eventhandler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveSmsDeviceStatusChanged<Identity: windows_core::IUnknownImpl + ISmsDevice_Impl>(this: *mut core::ffi::c_void, eventcookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISmsDevice_Impl::RemoveSmsDeviceStatusChanged(this, eventcookie).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SendMessageAsync: SendMessageAsync::<Identity>,
+            CalculateLength: CalculateLength::<Identity>,
+            AccountPhoneNumber: AccountPhoneNumber::<Identity>,
+            CellularClass: CellularClass::<Identity>,
+            MessageStore: MessageStore::<Identity>,
+            DeviceStatus: DeviceStatus::<Identity>,
+            SmsMessageReceived: SmsMessageReceived::<Identity>,
+            RemoveSmsMessageReceived: RemoveSmsMessageReceived::<Identity>,
+            SmsDeviceStatusChanged: SmsDeviceStatusChanged::<Identity>,
+            RemoveSmsDeviceStatusChanged: RemoveSmsDeviceStatusChanged::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ISmsDevice_Vtbl {
@@ -618,6 +795,39 @@ impl ISmsMessage_Vtbl {
         iid == &<ISmsMessage as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISmsMessage_Impl> windows_core::imp::TearOffVtable<Identity> for ISmsMessage_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + ISmsMessage_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsMessage_Impl::Id(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MessageClass<Identity: windows_core::IUnknownImpl + ISmsMessage_Impl>(this: *mut core::ffi::c_void, result__: *mut SmsMessageClass) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsMessage_Impl::MessageClass(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Id: Id::<Identity>,
+            MessageClass: MessageClass::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ISmsMessage_Vtbl {
@@ -754,6 +964,80 @@ impl ISmsMessageBase_Vtbl {
         iid == &<ISmsMessageBase as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISmsMessageBase_Impl> windows_core::imp::TearOffVtable<Identity> for ISmsMessageBase_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn MessageType<Identity: windows_core::IUnknownImpl + ISmsMessageBase_Impl>(this: *mut core::ffi::c_void, result__: *mut SmsMessageType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsMessageBase_Impl::MessageType(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeviceId<Identity: windows_core::IUnknownImpl + ISmsMessageBase_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsMessageBase_Impl::DeviceId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CellularClass<Identity: windows_core::IUnknownImpl + ISmsMessageBase_Impl>(this: *mut core::ffi::c_void, result__: *mut CellularClass) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsMessageBase_Impl::CellularClass(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MessageClass<Identity: windows_core::IUnknownImpl + ISmsMessageBase_Impl>(this: *mut core::ffi::c_void, result__: *mut SmsMessageClass) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsMessageBase_Impl::MessageClass(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SimIccId<Identity: windows_core::IUnknownImpl + ISmsMessageBase_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsMessageBase_Impl::SimIccId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MessageType: MessageType::<Identity>,
+            DeviceId: DeviceId::<Identity>,
+            CellularClass: CellularClass::<Identity>,
+            MessageClass: MessageClass::<Identity>,
+            SimIccId: SimIccId::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ISmsMessageBase_Vtbl {
@@ -1149,6 +1433,162 @@ impl ISmsTextMessage_Vtbl {
         iid == &<ISmsTextMessage as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISmsTextMessage_Impl> windows_core::imp::TearOffVtable<Identity> for ISmsTextMessage_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Timestamp<Identity: windows_core::IUnknownImpl + ISmsTextMessage_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsTextMessage_Impl::Timestamp(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PartReferenceId<Identity: windows_core::IUnknownImpl + ISmsTextMessage_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsTextMessage_Impl::PartReferenceId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PartNumber<Identity: windows_core::IUnknownImpl + ISmsTextMessage_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsTextMessage_Impl::PartNumber(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PartCount<Identity: windows_core::IUnknownImpl + ISmsTextMessage_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsTextMessage_Impl::PartCount(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn To<Identity: windows_core::IUnknownImpl + ISmsTextMessage_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsTextMessage_Impl::To(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTo<Identity: windows_core::IUnknownImpl + ISmsTextMessage_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISmsTextMessage_Impl::SetTo(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn From<Identity: windows_core::IUnknownImpl + ISmsTextMessage_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsTextMessage_Impl::From(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetFrom<Identity: windows_core::IUnknownImpl + ISmsTextMessage_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISmsTextMessage_Impl::SetFrom(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Body<Identity: windows_core::IUnknownImpl + ISmsTextMessage_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsTextMessage_Impl::Body(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBody<Identity: windows_core::IUnknownImpl + ISmsTextMessage_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISmsTextMessage_Impl::SetBody(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Encoding<Identity: windows_core::IUnknownImpl + ISmsTextMessage_Impl>(this: *mut core::ffi::c_void, result__: *mut SmsEncoding) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsTextMessage_Impl::Encoding(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEncoding<Identity: windows_core::IUnknownImpl + ISmsTextMessage_Impl>(this: *mut core::ffi::c_void, value: SmsEncoding) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISmsTextMessage_Impl::SetEncoding(this, value).into()
+            }
+        }
+        unsafe extern "system" fn ToBinaryMessages<Identity: windows_core::IUnknownImpl + ISmsTextMessage_Impl>(this: *mut core::ffi::c_void, format: SmsDataFormat, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISmsTextMessage_Impl::ToBinaryMessages(this, format) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Timestamp: Timestamp::<Identity>,
+            PartReferenceId: PartReferenceId::<Identity>,
+            PartNumber: PartNumber::<Identity>,
+            PartCount: PartCount::<Identity>,
+            To: To::<Identity>,
+            SetTo: SetTo::<Identity>,
+            From: From::<Identity>,
+            SetFrom: SetFrom::<Identity>,
+            Body: Body::<Identity>,
+            SetBody: SetBody::<Identity>,
+            Encoding: Encoding::<Identity>,
+            SetEncoding: SetEncoding::<Identity>,
+            ToBinaryMessages: ToBinaryMessages::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ISmsTextMessage_Vtbl {
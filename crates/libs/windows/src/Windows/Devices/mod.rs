@@ -196,6 +196,84 @@ impl ILowLevelDevicesAggregateProvider_Vtbl {
         iid == &<ILowLevelDevicesAggregateProvider as windows_core::Interface>::IID
     }
 }
+#[cfg(all(feature = "Devices_Adc_Provider", feature = "Devices_Gpio_Provider", feature = "Devices_I2c_Provider", feature = "Devices_Pwm_Provider", feature = "Devices_Spi_Provider"))]
+impl<Identity: windows_core::IUnknownImpl + ILowLevelDevicesAggregateProvider_Impl> windows_core::imp::TearOffVtable<Identity> for ILowLevelDevicesAggregateProvider_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn AdcControllerProvider<Identity: windows_core::IUnknownImpl + ILowLevelDevicesAggregateProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILowLevelDevicesAggregateProvider_Impl::AdcControllerProvider(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PwmControllerProvider<Identity: windows_core::IUnknownImpl + ILowLevelDevicesAggregateProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILowLevelDevicesAggregateProvider_Impl::PwmControllerProvider(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GpioControllerProvider<Identity: windows_core::IUnknownImpl + ILowLevelDevicesAggregateProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILowLevelDevicesAggregateProvider_Impl::GpioControllerProvider(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn I2cControllerProvider<Identity: windows_core::IUnknownImpl + ILowLevelDevicesAggregateProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILowLevelDevicesAggregateProvider_Impl::I2cControllerProvider(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SpiControllerProvider<Identity: windows_core::IUnknownImpl + ILowLevelDevicesAggregateProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILowLevelDevicesAggregateProvider_Impl::SpiControllerProvider(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AdcControllerProvider: AdcControllerProvider::<Identity>,
+            PwmControllerProvider: PwmControllerProvider::<Identity>,
+            GpioControllerProvider: GpioControllerProvider::<Identity>,
+            I2cControllerProvider: I2cControllerProvider::<Identity>,
+            SpiControllerProvider: SpiControllerProvider::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ILowLevelDevicesAggregateProvider_Vtbl {
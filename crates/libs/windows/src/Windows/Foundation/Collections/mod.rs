@@ -91,6 +91,41 @@ impl<K: windows_core::RuntimeType + 'static> IMapChangedEventArgs_Vtbl<K> {
         iid == &<IMapChangedEventArgs<K> as windows_core::Interface>::IID
     }
 }
+impl<K: windows_core::RuntimeType + 'static, Identity: windows_core::IUnknownImpl + IMapChangedEventArgs_Impl<K>> windows_core::imp::TearOffVtable<Identity> for IMapChangedEventArgs_Vtbl<K> {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CollectionChange<K: windows_core::RuntimeType + 'static, Identity: windows_core::IUnknownImpl + IMapChangedEventArgs_Impl<K>>(this: *mut core::ffi::c_void, result__: *mut CollectionChange) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMapChangedEventArgs_Impl::CollectionChange(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Key<K: windows_core::RuntimeType + 'static, Identity: windows_core::IUnknownImpl + IMapChangedEventArgs_Impl<K>>(this: *mut core::ffi::c_void, result__: *mut windows_core::AbiType<K>) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMapChangedEventArgs_Impl::Key(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CollectionChange: CollectionChange::<K, Identity>,
+            Key: Key::<K, Identity>,
+            K: core::marker::PhantomData::<K>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IMapChangedEventArgs_Vtbl<K>
@@ -259,6 +294,35 @@ impl<K: windows_core::RuntimeType + 'static, V: windows_core::RuntimeType + 'sta
         iid == &<IObservableMap<K, V> as windows_core::Interface>::IID
     }
 }
+impl<K: windows_core::RuntimeType + 'static, V: windows_core::RuntimeType + 'static, Identity: windows_core::IUnknownImpl + IObservableMap_Impl<K, V>> windows_core::imp::TearOffVtable<Identity> for IObservableMap_Vtbl<K, V> {
+    const VTABLE: Self = {
+        unsafe extern "system" fn MapChanged<K: windows_core::RuntimeType + 'static, V: windows_core::RuntimeType + 'static, Identity: windows_core::IUnknownImpl + IObservableMap_Impl<K, V>>(this: *mut core::ffi::c_void, vhnd: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IObservableMap_Impl::MapChanged(this, core::mem::transmute_copy(&vhnd)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveMapChanged<K: windows_core::RuntimeType + 'static, V: windows_core::RuntimeType + 'static, Identity: windows_core::IUnknownImpl + IObservableMap_Impl<K, V>>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IObservableMap_Impl::RemoveMapChanged(this, token).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MapChanged: MapChanged::<K, V, Identity>,
+            RemoveMapChanged: RemoveMapChanged::<K, V, Identity>,
+            K: core::marker::PhantomData::<K>,
+            V: core::marker::PhantomData::<V>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IObservableMap_Vtbl<K, V>
@@ -445,6 +509,34 @@ impl<T: windows_core::RuntimeType + 'static> IObservableVector_Vtbl<T> {
         iid == &<IObservableVector<T> as windows_core::Interface>::IID
     }
 }
+impl<T: windows_core::RuntimeType + 'static, Identity: windows_core::IUnknownImpl + IObservableVector_Impl<T>> windows_core::imp::TearOffVtable<Identity> for IObservableVector_Vtbl<T> {
+    const VTABLE: Self = {
+        unsafe extern "system" fn VectorChanged<T: windows_core::RuntimeType + 'static, Identity: windows_core::IUnknownImpl + IObservableVector_Impl<T>>(this: *mut core::ffi::c_void, vhnd: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IObservableVector_Impl::VectorChanged(this, core::mem::transmute_copy(&vhnd)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveVectorChanged<T: windows_core::RuntimeType + 'static, Identity: windows_core::IUnknownImpl + IObservableVector_Impl<T>>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IObservableVector_Impl::RemoveVectorChanged(this, token).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            VectorChanged: VectorChanged::<T, Identity>,
+            RemoveVectorChanged: RemoveVectorChanged::<T, Identity>,
+            T: core::marker::PhantomData::<T>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IObservableVector_Vtbl<T>
@@ -557,6 +649,9 @@ impl IPropertySet_Vtbl {
         iid == &<IPropertySet as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPropertySet_Impl> windows_core::imp::TearOffVtable<Identity> for IPropertySet_Vtbl {
+    const VTABLE: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPropertySet_Vtbl {
@@ -626,6 +721,39 @@ impl IVectorChangedEventArgs_Vtbl {
         iid == &<IVectorChangedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVectorChangedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IVectorChangedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CollectionChange<Identity: windows_core::IUnknownImpl + IVectorChangedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut CollectionChange) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVectorChangedEventArgs_Impl::CollectionChange(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Index<Identity: windows_core::IUnknownImpl + IVectorChangedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVectorChangedEventArgs_Impl::Index(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CollectionChange: CollectionChange::<Identity>,
+            Index: Index::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IVectorChangedEventArgs_Vtbl {
@@ -367,6 +367,33 @@ impl IErrorReportingSettings_Vtbl {
         iid == &<IErrorReportingSettings as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IErrorReportingSettings_Impl> windows_core::imp::TearOffVtable<Identity> for IErrorReportingSettings_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetErrorOptions<Identity: windows_core::IUnknownImpl + IErrorReportingSettings_Impl>(this: *mut core::ffi::c_void, value: ErrorOptions) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IErrorReportingSettings_Impl::SetErrorOptions(this, value).into()
+            }
+        }
+        unsafe extern "system" fn GetErrorOptions<Identity: windows_core::IUnknownImpl + IErrorReportingSettings_Impl>(this: *mut core::ffi::c_void, result__: *mut ErrorOptions) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IErrorReportingSettings_Impl::GetErrorOptions(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetErrorOptions: SetErrorOptions::<Identity>,
+            GetErrorOptions: GetErrorOptions::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IErrorReportingSettings_Vtbl {
@@ -530,6 +557,83 @@ impl IFileLoggingSession_Vtbl {
         iid == &<IFileLoggingSession as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Storage_Streams")]
+impl<Identity: windows_core::IUnknownImpl + IFileLoggingSession_Impl> windows_core::imp::TearOffVtable<Identity> for IFileLoggingSession_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + IFileLoggingSession_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFileLoggingSession_Impl::Name(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddLoggingChannel<Identity: windows_core::IUnknownImpl + IFileLoggingSession_Impl>(this: *mut core::ffi::c_void, loggingchannel: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFileLoggingSession_Impl::AddLoggingChannel(this, core::mem::transmute_copy(&loggingchannel)).into()
+            }
+        }
+        unsafe extern "system" fn AddLoggingChannelWithLevel<Identity: windows_core::IUnknownImpl + IFileLoggingSession_Impl>(this: *mut core::ffi::c_void, loggingchannel: *mut core::ffi::c_void, maxlevel: LoggingLevel) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFileLoggingSession_Impl::AddLoggingChannelWithLevel(this, core::mem::transmute_copy(&loggingchannel), maxlevel).into()
+            }
+        }
+        unsafe extern "system" fn RemoveLoggingChannel<Identity: windows_core::IUnknownImpl + IFileLoggingSession_Impl>(this: *mut core::ffi::c_void, loggingchannel: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFileLoggingSession_Impl::RemoveLoggingChannel(this, core::mem::transmute_copy(&loggingchannel)).into()
+            }
+        }
+        unsafe extern "system" fn CloseAndSaveToFileAsync<Identity: windows_core::IUnknownImpl + IFileLoggingSession_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFileLoggingSession_Impl::CloseAndSaveToFileAsync(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LogFileGenerated<Identity: windows_core::IUnknownImpl + IFileLoggingSession_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFileLoggingSession_Impl::LogFileGenerated(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveLogFileGenerated<Identity: windows_core::IUnknownImpl + IFileLoggingSession_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFileLoggingSession_Impl::RemoveLogFileGenerated(this, token).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Name: Name::<Identity>,
+            AddLoggingChannel: AddLoggingChannel::<Identity>,
+            AddLoggingChannelWithLevel: AddLoggingChannelWithLevel::<Identity>,
+            RemoveLoggingChannel: RemoveLoggingChannel::<Identity>,
+            CloseAndSaveToFileAsync: CloseAndSaveToFileAsync::<Identity>,
+            LogFileGenerated: LogFileGenerated::<Identity>,
+            RemoveLogFileGenerated: RemoveLogFileGenerated::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IFileLoggingSession_Vtbl {
@@ -778,6 +882,101 @@ impl ILoggingChannel_Vtbl {
         iid == &<ILoggingChannel as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ILoggingChannel_Impl> windows_core::imp::TearOffVtable<Identity> for ILoggingChannel_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + ILoggingChannel_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILoggingChannel_Impl::Name(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Enabled<Identity: windows_core::IUnknownImpl + ILoggingChannel_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILoggingChannel_Impl::Enabled(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Level<Identity: windows_core::IUnknownImpl + ILoggingChannel_Impl>(this: *mut core::ffi::c_void, result__: *mut LoggingLevel) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILoggingChannel_Impl::Level(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LogMessage<Identity: windows_core::IUnknownImpl + ILoggingChannel_Impl>(this: *mut core::ffi::c_void, eventstring: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILoggingChannel_Impl::LogMessage(this, core::mem::transmute(&eventstring)).into()
+            }
+        }
+        unsafe extern "system" fn LogMessageWithLevel<Identity: windows_core::IUnknownImpl + ILoggingChannel_Impl>(this: *mut core::ffi::c_void, eventstring: *mut core::ffi::c_void, level: LoggingLevel) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILoggingChannel_Impl::LogMessageWithLevel(this, core::mem::transmute(&eventstring), level).into()
+            }
+        }
+        unsafe extern "system" fn LogValuePair<Identity: windows_core::IUnknownImpl + ILoggingChannel_Impl>(this: *mut core::ffi::c_void, value1: *mut core::ffi::c_void, value2: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILoggingChannel_Impl::LogValuePair(this, core::mem::transmute(&value1), value2).into()
+            }
+        }
+        unsafe extern "system" fn LogValuePairWithLevel<Identity: windows_core::IUnknownImpl + ILoggingChannel_Impl>(this: *mut core::ffi::c_void, value1: *mut core::ffi::c_void, value2: i32, level: LoggingLevel) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILoggingChannel_Impl::LogValuePairWithLevel(this, core::mem::transmute(&value1), value2, level).into()
+            }
+        }
+        unsafe extern "system" fn LoggingEnabled<Identity: windows_core::IUnknownImpl + ILoggingChannel_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILoggingChannel_Impl::LoggingEnabled(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveLoggingEnabled<Identity: windows_core::IUnknownImpl + ILoggingChannel_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILoggingChannel_Impl::RemoveLoggingEnabled(this, token).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Name: Name::<Identity>,
+            Enabled: Enabled::<Identity>,
+            Level: Level::<Identity>,
+            LogMessage: LogMessage::<Identity>,
+            LogMessageWithLevel: LogMessageWithLevel::<Identity>,
+            LogValuePair: LogValuePair::<Identity>,
+            LogValuePairWithLevel: LogValuePairWithLevel::<Identity>,
+            LoggingEnabled: LoggingEnabled::<Identity>,
+            RemoveLoggingEnabled: RemoveLoggingEnabled::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ILoggingChannel_Vtbl {
@@ -1122,6 +1321,63 @@ impl ILoggingSession_Vtbl {
         iid == &<ILoggingSession as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Storage_Streams")]
+impl<Identity: windows_core::IUnknownImpl + ILoggingSession_Impl> windows_core::imp::TearOffVtable<Identity> for ILoggingSession_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + ILoggingSession_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILoggingSession_Impl::Name(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SaveToFileAsync<Identity: windows_core::IUnknownImpl + ILoggingSession_Impl>(this: *mut core::ffi::c_void, folder: *mut core::ffi::c_void, filename: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILoggingSession_Impl::SaveToFileAsync(this, core::mem::transmute_copy(&folder), core::mem::transmute(&filename)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddLoggingChannel<Identity: windows_core::IUnknownImpl + ILoggingSession_Impl>(this: *mut core::ffi::c_void, loggingchannel: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILoggingSession_Impl::AddLoggingChannel(this, core::mem::transmute_copy(&loggingchannel)).into()
+            }
+        }
+        unsafe extern "system" fn AddLoggingChannelWithLevel<Identity: windows_core::IUnknownImpl + ILoggingSession_Impl>(this: *mut core::ffi::c_void, loggingchannel: *mut core::ffi::c_void, maxlevel: LoggingLevel) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILoggingSession_Impl::AddLoggingChannelWithLevel(this, core::mem::transmute_copy(&loggingchannel), maxlevel).into()
+            }
+        }
+        unsafe extern "system" fn RemoveLoggingChannel<Identity: windows_core::IUnknownImpl + ILoggingSession_Impl>(this: *mut core::ffi::c_void, loggingchannel: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILoggingSession_Impl::RemoveLoggingChannel(this, core::mem::transmute_copy(&loggingchannel)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Name: Name::<Identity>,
+            SaveToFileAsync: SaveToFileAsync::<Identity>,
+            AddLoggingChannel: AddLoggingChannel::<Identity>,
+            AddLoggingChannelWithLevel: AddLoggingChannelWithLevel::<Identity>,
+            RemoveLoggingChannel: RemoveLoggingChannel::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ILoggingSession_Vtbl {
@@ -1386,6 +1642,136 @@ impl ILoggingTarget_Vtbl {
         iid == &<ILoggingTarget as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ILoggingTarget_Impl> windows_core::imp::TearOffVtable<Identity> for ILoggingTarget_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsEnabled<Identity: windows_core::IUnknownImpl + ILoggingTarget_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILoggingTarget_Impl::IsEnabled(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsEnabledWithLevel<Identity: windows_core::IUnknownImpl + ILoggingTarget_Impl>(this: *mut core::ffi::c_void, level: LoggingLevel, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILoggingTarget_Impl::IsEnabledWithLevel(this, level) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsEnabledWithLevelAndKeywords<Identity: windows_core::IUnknownImpl + ILoggingTarget_Impl>(this: *mut core::ffi::c_void, level: LoggingLevel, keywords: i64, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILoggingTarget_Impl::IsEnabledWithLevelAndKeywords(this, level, keywords) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LogEvent<Identity: windows_core::IUnknownImpl + ILoggingTarget_Impl>(this: *mut core::ffi::c_void, eventname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILoggingTarget_Impl::LogEvent(this, core::mem::transmute(&eventname)).into()
+            }
+        }
+        unsafe extern "system" fn LogEventWithFields<Identity: windows_core::IUnknownImpl + ILoggingTarget_Impl>(this: *mut core::ffi::c_void, eventname: *mut core::ffi::c_void, fields: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILoggingTarget_Impl::LogEventWithFields(this, core::mem::transmute(&eventname), core::mem::transmute_copy(&fields)).into()
+            }
+        }
+        unsafe extern "system" fn LogEventWithFieldsAndLevel<Identity: windows_core::IUnknownImpl + ILoggingTarget_Impl>(this: *mut core::ffi::c_void, eventname: *mut core::ffi::c_void, fields: *mut core::ffi::c_void, level: LoggingLevel) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILoggingTarget_Impl::LogEventWithFieldsAndLevel(this, core::mem::transmute(&eventname), core::mem::transmute_copy(&fields), level).into()
+            }
+        }
+        unsafe extern "system" fn LogEventWithFieldsAndOptions<Identity: windows_core::IUnknownImpl + ILoggingTarget_Impl>(this: *mut core::ffi::c_void, eventname: *mut core::ffi::c_void, fields: *mut core::ffi::c_void, level: LoggingLevel, options: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILoggingTarget_Impl::LogEventWithFieldsAndOptions(this, core::mem::transmute(&eventname), core::mem::transmute_copy(&fields), level, core::mem::transmute_copy(&options)).into()
+            }
+        }
+        unsafe extern "system" fn StartActivity<Identity: windows_core::IUnknownImpl + ILoggingTarget_Impl>(this: *mut core::ffi::c_void, starteventname: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILoggingTarget_Impl::StartActivity(this, core::mem::transmute(&starteventname)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StartActivityWithFields<Identity: windows_core::IUnknownImpl + ILoggingTarget_Impl>(this: *mut core::ffi::c_void, starteventname: *mut core::ffi::c_void, fields: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILoggingTarget_Impl::StartActivityWithFields(this, core::mem::transmute(&starteventname), core::mem::transmute_copy(&fields)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StartActivityWithFieldsAndLevel<Identity: windows_core::IUnknownImpl + ILoggingTarget_Impl>(this: *mut core::ffi::c_void, starteventname: *mut core::ffi::c_void, fields: *mut core::ffi::c_void, level: LoggingLevel, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILoggingTarget_Impl::StartActivityWithFieldsAndLevel(this, core::mem::transmute(&starteventname), core::mem::transmute_copy(&fields), level) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StartActivityWithFieldsAndOptions<Identity: windows_core::IUnknownImpl + ILoggingTarget_Impl>(this: *mut core::ffi::c_void, starteventname: *mut core::ffi::c_void, fields: *mut core::ffi::c_void, level: LoggingLevel, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILoggingTarget_Impl::StartActivityWithFieldsAndOptions(this, core::mem::transmute(&starteventname), core::mem::transmute_copy(&fields), level, core::mem::transmute_copy(&options)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsEnabled: IsEnabled::<Identity>,
+            IsEnabledWithLevel: IsEnabledWithLevel::<Identity>,
+            IsEnabledWithLevelAndKeywords: IsEnabledWithLevelAndKeywords::<Identity>,
+            LogEvent: LogEvent::<Identity>,
+            LogEventWithFields: LogEventWithFields::<Identity>,
+            LogEventWithFieldsAndLevel: LogEventWithFieldsAndLevel::<Identity>,
+            LogEventWithFieldsAndOptions: LogEventWithFieldsAndOptions::<Identity>,
+            StartActivity: StartActivity::<Identity>,
+            StartActivityWithFields: StartActivityWithFields::<Identity>,
+            StartActivityWithFieldsAndLevel: StartActivityWithFieldsAndLevel::<Identity>,
+            StartActivityWithFieldsAndOptions: StartActivityWithFieldsAndOptions::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ILoggingTarget_Vtbl {
@@ -285,6 +285,17 @@ impl IClosable_Vtbl {
         iid == &<IClosable as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IClosable_Impl> windows_core::imp::TearOffVtable<Identity> for IClosable_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IClosable_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IClosable_Impl::Close(this).into()
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Close: Close::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IClosable_Vtbl {
@@ -355,6 +366,27 @@ impl IGetActivationFactory_Vtbl {
         iid == &<IGetActivationFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IGetActivationFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IGetActivationFactory_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetActivationFactory<Identity: windows_core::IUnknownImpl + IGetActivationFactory_Impl>(this: *mut core::ffi::c_void, activatableclassid: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGetActivationFactory_Impl::GetActivationFactory(this, core::mem::transmute(&activatableclassid)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetActivationFactory: GetActivationFactory::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IGetActivationFactory_Vtbl {
@@ -419,6 +451,24 @@ impl IMemoryBuffer_Vtbl {
         iid == &<IMemoryBuffer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMemoryBuffer_Impl> windows_core::imp::TearOffVtable<Identity> for IMemoryBuffer_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateReference<Identity: windows_core::IUnknownImpl + IMemoryBuffer_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMemoryBuffer_Impl::CreateReference(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateReference: CreateReference::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IMemoryBuffer_Vtbl {
@@ -519,6 +569,46 @@ impl IMemoryBufferReference_Vtbl {
         iid == &<IMemoryBufferReference as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMemoryBufferReference_Impl> windows_core::imp::TearOffVtable<Identity> for IMemoryBufferReference_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Capacity<Identity: windows_core::IUnknownImpl + IMemoryBufferReference_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMemoryBufferReference_Impl::Capacity(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Closed<Identity: windows_core::IUnknownImpl + IMemoryBufferReference_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMemoryBufferReference_Impl::Closed(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveClosed<Identity: windows_core::IUnknownImpl + IMemoryBufferReference_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMemoryBufferReference_Impl::RemoveClosed(this, cookie).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Capacity: Capacity::<Identity>,
+            Closed: Closed::<Identity>,
+            RemoveClosed: RemoveClosed::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IMemoryBufferReference_Vtbl {
@@ -1198,6 +1288,407 @@ impl IPropertyValue_Vtbl {
         iid == &<IPropertyValue as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl> windows_core::imp::TearOffVtable<Identity> for IPropertyValue_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Type<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, result__: *mut PropertyType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPropertyValue_Impl::Type(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsNumericScalar<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPropertyValue_Impl::IsNumericScalar(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetUInt8<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPropertyValue_Impl::GetUInt8(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetInt16<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, result__: *mut i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPropertyValue_Impl::GetInt16(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetUInt16<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPropertyValue_Impl::GetUInt16(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetInt32<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPropertyValue_Impl::GetInt32(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetUInt32<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPropertyValue_Impl::GetUInt32(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetInt64<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPropertyValue_Impl::GetInt64(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetUInt64<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPropertyValue_Impl::GetUInt64(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSingle<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPropertyValue_Impl::GetSingle(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDouble<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPropertyValue_Impl::GetDouble(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetChar16<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPropertyValue_Impl::GetChar16(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetBoolean<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPropertyValue_Impl::GetBoolean(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetString<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPropertyValue_Impl::GetString(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetGuid<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPropertyValue_Impl::GetGuid(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDateTime<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, result__: *mut DateTime) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPropertyValue_Impl::GetDateTime(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTimeSpan<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, result__: *mut TimeSpan) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPropertyValue_Impl::GetTimeSpan(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPoint<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, result__: *mut Point) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPropertyValue_Impl::GetPoint(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSize<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, result__: *mut Size) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPropertyValue_Impl::GetSize(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRect<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, result__: *mut Rect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPropertyValue_Impl::GetRect(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetUInt8Array<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyValue_Impl::GetUInt8Array(this, &mut windows_core::imp::array_proxy(core::mem::transmute_copy(&value), value_array_size)).into()
+            }
+        }
+        unsafe extern "system" fn GetInt16Array<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyValue_Impl::GetInt16Array(this, &mut windows_core::imp::array_proxy(core::mem::transmute_copy(&value), value_array_size)).into()
+            }
+        }
+        unsafe extern "system" fn GetUInt16Array<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyValue_Impl::GetUInt16Array(this, &mut windows_core::imp::array_proxy(core::mem::transmute_copy(&value), value_array_size)).into()
+            }
+        }
+        unsafe extern "system" fn GetInt32Array<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyValue_Impl::GetInt32Array(this, &mut windows_core::imp::array_proxy(core::mem::transmute_copy(&value), value_array_size)).into()
+            }
+        }
+        unsafe extern "system" fn GetUInt32Array<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyValue_Impl::GetUInt32Array(this, &mut windows_core::imp::array_proxy(core::mem::transmute_copy(&value), value_array_size)).into()
+            }
+        }
+        unsafe extern "system" fn GetInt64Array<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyValue_Impl::GetInt64Array(this, &mut windows_core::imp::array_proxy(core::mem::transmute_copy(&value), value_array_size)).into()
+            }
+        }
+        unsafe extern "system" fn GetUInt64Array<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyValue_Impl::GetUInt64Array(this, &mut windows_core::imp::array_proxy(core::mem::transmute_copy(&value), value_array_size)).into()
+            }
+        }
+        unsafe extern "system" fn GetSingleArray<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyValue_Impl::GetSingleArray(this, &mut windows_core::imp::array_proxy(core::mem::transmute_copy(&value), value_array_size)).into()
+            }
+        }
+        unsafe extern "system" fn GetDoubleArray<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyValue_Impl::GetDoubleArray(this, &mut windows_core::imp::array_proxy(core::mem::transmute_copy(&value), value_array_size)).into()
+            }
+        }
+        unsafe extern "system" fn GetChar16Array<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyValue_Impl::GetChar16Array(this, &mut windows_core::imp::array_proxy(core::mem::transmute_copy(&value), value_array_size)).into()
+            }
+        }
+        unsafe extern "system" fn GetBooleanArray<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyValue_Impl::GetBooleanArray(this, &mut windows_core::imp::array_proxy(core::mem::transmute_copy(&value), value_array_size)).into()
+            }
+        }
+        unsafe extern "system" fn GetStringArray<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut windows_core::HSTRING) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyValue_Impl::GetStringArray(this, &mut windows_core::imp::array_proxy(core::mem::transmute_copy(&value), value_array_size)).into()
+            }
+        }
+        unsafe extern "system" fn GetInspectableArray<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut windows_core::IInspectable) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyValue_Impl::GetInspectableArray(this, &mut windows_core::imp::array_proxy(core::mem::transmute_copy(&value), value_array_size)).into()
+            }
+        }
+        unsafe extern "system" fn GetGuidArray<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyValue_Impl::GetGuidArray(this, &mut windows_core::imp::array_proxy(core::mem::transmute_copy(&value), value_array_size)).into()
+            }
+        }
+        unsafe extern "system" fn GetDateTimeArray<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut DateTime) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyValue_Impl::GetDateTimeArray(this, &mut windows_core::imp::array_proxy(core::mem::transmute_copy(&value), value_array_size)).into()
+            }
+        }
+        unsafe extern "system" fn GetTimeSpanArray<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut TimeSpan) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyValue_Impl::GetTimeSpanArray(this, &mut windows_core::imp::array_proxy(core::mem::transmute_copy(&value), value_array_size)).into()
+            }
+        }
+        unsafe extern "system" fn GetPointArray<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut Point) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyValue_Impl::GetPointArray(this, &mut windows_core::imp::array_proxy(core::mem::transmute_copy(&value), value_array_size)).into()
+            }
+        }
+        unsafe extern "system" fn GetSizeArray<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut Size) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyValue_Impl::GetSizeArray(this, &mut windows_core::imp::array_proxy(core::mem::transmute_copy(&value), value_array_size)).into()
+            }
+        }
+        unsafe extern "system" fn GetRectArray<Identity: windows_core::IUnknownImpl + IPropertyValue_Impl>(this: *mut core::ffi::c_void, value_array_size: *mut u32, value: *mut *mut Rect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyValue_Impl::GetRectArray(this, &mut windows_core::imp::array_proxy(core::mem::transmute_copy(&value), value_array_size)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Type: Type::<Identity>,
+            IsNumericScalar: IsNumericScalar::<Identity>,
+            GetUInt8: GetUInt8::<Identity>,
+            GetInt16: GetInt16::<Identity>,
+            GetUInt16: GetUInt16::<Identity>,
+            GetInt32: GetInt32::<Identity>,
+            GetUInt32: GetUInt32::<Identity>,
+            GetInt64: GetInt64::<Identity>,
+            GetUInt64: GetUInt64::<Identity>,
+            GetSingle: GetSingle::<Identity>,
+            GetDouble: GetDouble::<Identity>,
+            GetChar16: GetChar16::<Identity>,
+            GetBoolean: GetBoolean::<Identity>,
+            GetString: GetString::<Identity>,
+            GetGuid: GetGuid::<Identity>,
+            GetDateTime: GetDateTime::<Identity>,
+            GetTimeSpan: GetTimeSpan::<Identity>,
+            GetPoint: GetPoint::<Identity>,
+            GetSize: GetSize::<Identity>,
+            GetRect: GetRect::<Identity>,
+            GetUInt8Array: GetUInt8Array::<Identity>,
+            GetInt16Array: GetInt16Array::<Identity>,
+            GetUInt16Array: GetUInt16Array::<Identity>,
+            GetInt32Array: GetInt32Array::<Identity>,
+            GetUInt32Array: GetUInt32Array::<Identity>,
+            GetInt64Array: GetInt64Array::<Identity>,
+            GetUInt64Array: GetUInt64Array::<Identity>,
+            GetSingleArray: GetSingleArray::<Identity>,
+            GetDoubleArray: GetDoubleArray::<Identity>,
+            GetChar16Array: GetChar16Array::<Identity>,
+            GetBooleanArray: GetBooleanArray::<Identity>,
+            GetStringArray: GetStringArray::<Identity>,
+            GetInspectableArray: GetInspectableArray::<Identity>,
+            GetGuidArray: GetGuidArray::<Identity>,
+            GetDateTimeArray: GetDateTimeArray::<Identity>,
+            GetTimeSpanArray: GetTimeSpanArray::<Identity>,
+            GetPointArray: GetPointArray::<Identity>,
+            GetSizeArray: GetSizeArray::<Identity>,
+            GetRectArray: GetRectArray::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPropertyValue_Vtbl {
@@ -1566,6 +2057,28 @@ impl<T: windows_core::RuntimeType + 'static> IReference_Vtbl<T> {
         iid == &<IReference<T> as windows_core::Interface>::IID
     }
 }
+impl<T: windows_core::RuntimeType + 'static, Identity: windows_core::IUnknownImpl + IReference_Impl<T>> windows_core::imp::TearOffVtable<Identity> for IReference_Vtbl<T> {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Value<T: windows_core::RuntimeType + 'static, Identity: windows_core::IUnknownImpl + IReference_Impl<T>>(this: *mut core::ffi::c_void, result__: *mut windows_core::AbiType<T>) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IReference_Impl::Value(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Value: Value::<T, Identity>,
+            T: core::marker::PhantomData::<T>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IReference_Vtbl<T>
@@ -1853,6 +2366,29 @@ impl<T: windows_core::RuntimeType + 'static> IReferenceArray_Vtbl<T> {
         iid == &<IReferenceArray<T> as windows_core::Interface>::IID
     }
 }
+impl<T: windows_core::RuntimeType + 'static, Identity: windows_core::IUnknownImpl + IReferenceArray_Impl<T>> windows_core::imp::TearOffVtable<Identity> for IReferenceArray_Vtbl<T> {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Value<T: windows_core::RuntimeType + 'static, Identity: windows_core::IUnknownImpl + IReferenceArray_Impl<T>>(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut windows_core::AbiType<T>) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IReferenceArray_Impl::Value(this) {
+                    Ok(ok__) => {
+                        let (ok_data__, ok_data_len__) = ok__.into_abi();
+                        result__.write(core::mem::transmute(ok_data__));
+                        result_size__.write(ok_data_len__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Value: Value::<T, Identity>,
+            T: core::marker::PhantomData::<T>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IReferenceArray_Vtbl<T>
@@ -1904,6 +2440,24 @@ impl IStringable_Vtbl {
         iid == &<IStringable as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IStringable_Impl> windows_core::imp::TearOffVtable<Identity> for IStringable_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ToString<Identity: windows_core::IUnknownImpl + IStringable_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStringable_Impl::ToString(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ToString: ToString::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IStringable_Vtbl {
@@ -2035,6 +2589,41 @@ impl IWwwFormUrlDecoderEntry_Vtbl {
         iid == &<IWwwFormUrlDecoderEntry as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWwwFormUrlDecoderEntry_Impl> windows_core::imp::TearOffVtable<Identity> for IWwwFormUrlDecoderEntry_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + IWwwFormUrlDecoderEntry_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWwwFormUrlDecoderEntry_Impl::Name(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Value<Identity: windows_core::IUnknownImpl + IWwwFormUrlDecoderEntry_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWwwFormUrlDecoderEntry_Impl::Value(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Name: Name::<Identity>,
+            Value: Value::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IWwwFormUrlDecoderEntry_Vtbl {
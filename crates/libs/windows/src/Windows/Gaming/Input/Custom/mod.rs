@@ -363,6 +363,41 @@ impl ICustomGameControllerFactory_Vtbl {
         iid == &<ICustomGameControllerFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICustomGameControllerFactory_Impl> windows_core::imp::TearOffVtable<Identity> for ICustomGameControllerFactory_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateGameController<Identity: windows_core::IUnknownImpl + ICustomGameControllerFactory_Impl>(this: *mut core::ffi::c_void, provider: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICustomGameControllerFactory_Impl::CreateGameController(this, core::mem::transmute_copy(&provider)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OnGameControllerAdded<Identity: windows_core::IUnknownImpl + ICustomGameControllerFactory_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICustomGameControllerFactory_Impl::OnGameControllerAdded(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn OnGameControllerRemoved<Identity: windows_core::IUnknownImpl + ICustomGameControllerFactory_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICustomGameControllerFactory_Impl::OnGameControllerRemoved(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateGameController: CreateGameController::<Identity>,
+            OnGameControllerAdded: OnGameControllerAdded::<Identity>,
+            OnGameControllerRemoved: OnGameControllerRemoved::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ICustomGameControllerFactory_Vtbl {
@@ -439,6 +474,27 @@ impl IGameControllerInputSink_Vtbl {
         iid == &<IGameControllerInputSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IGameControllerInputSink_Impl> windows_core::imp::TearOffVtable<Identity> for IGameControllerInputSink_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnInputResumed<Identity: windows_core::IUnknownImpl + IGameControllerInputSink_Impl>(this: *mut core::ffi::c_void, timestamp: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGameControllerInputSink_Impl::OnInputResumed(this, timestamp).into()
+            }
+        }
+        unsafe extern "system" fn OnInputSuspended<Identity: windows_core::IUnknownImpl + IGameControllerInputSink_Impl>(this: *mut core::ffi::c_void, timestamp: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGameControllerInputSink_Impl::OnInputSuspended(this, timestamp).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnInputResumed: OnInputResumed::<Identity>,
+            OnInputSuspended: OnInputSuspended::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IGameControllerInputSink_Vtbl {
@@ -573,6 +629,78 @@ impl IGameControllerProvider_Vtbl {
         iid == &<IGameControllerProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IGameControllerProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IGameControllerProvider_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn FirmwareVersionInfo<Identity: windows_core::IUnknownImpl + IGameControllerProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut GameControllerVersionInfo) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameControllerProvider_Impl::FirmwareVersionInfo(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn HardwareProductId<Identity: windows_core::IUnknownImpl + IGameControllerProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameControllerProvider_Impl::HardwareProductId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn HardwareVendorId<Identity: windows_core::IUnknownImpl + IGameControllerProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameControllerProvider_Impl::HardwareVendorId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn HardwareVersionInfo<Identity: windows_core::IUnknownImpl + IGameControllerProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut GameControllerVersionInfo) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameControllerProvider_Impl::HardwareVersionInfo(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsConnected<Identity: windows_core::IUnknownImpl + IGameControllerProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameControllerProvider_Impl::IsConnected(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            FirmwareVersionInfo: FirmwareVersionInfo::<Identity>,
+            HardwareProductId: HardwareProductId::<Identity>,
+            HardwareVendorId: HardwareVendorId::<Identity>,
+            HardwareVersionInfo: HardwareVersionInfo::<Identity>,
+            IsConnected: IsConnected::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IGameControllerProvider_Vtbl {
@@ -650,6 +778,27 @@ impl IGipGameControllerInputSink_Vtbl {
         iid == &<IGipGameControllerInputSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IGipGameControllerInputSink_Impl> windows_core::imp::TearOffVtable<Identity> for IGipGameControllerInputSink_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnKeyReceived<Identity: windows_core::IUnknownImpl + IGipGameControllerInputSink_Impl>(this: *mut core::ffi::c_void, timestamp: u64, keycode: u8, ispressed: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGipGameControllerInputSink_Impl::OnKeyReceived(this, timestamp, keycode, ispressed).into()
+            }
+        }
+        unsafe extern "system" fn OnMessageReceived<Identity: windows_core::IUnknownImpl + IGipGameControllerInputSink_Impl>(this: *mut core::ffi::c_void, timestamp: u64, messageclass: GipMessageClass, messageid: u8, sequenceid: u8, messagebuffer_array_size: u32, messagebuffer: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGipGameControllerInputSink_Impl::OnMessageReceived(this, timestamp, messageclass, messageid, sequenceid, core::slice::from_raw_parts(core::mem::transmute_copy(&messagebuffer), messagebuffer_array_size as usize)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnKeyReceived: OnKeyReceived::<Identity>,
+            OnMessageReceived: OnMessageReceived::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IGipGameControllerInputSink_Vtbl {
@@ -715,6 +864,20 @@ impl IHidGameControllerInputSink_Vtbl {
         iid == &<IHidGameControllerInputSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IHidGameControllerInputSink_Impl> windows_core::imp::TearOffVtable<Identity> for IHidGameControllerInputSink_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnInputReportReceived<Identity: windows_core::IUnknownImpl + IHidGameControllerInputSink_Impl>(this: *mut core::ffi::c_void, timestamp: u64, reportid: u8, reportbuffer_array_size: u32, reportbuffer: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHidGameControllerInputSink_Impl::OnInputReportReceived(this, timestamp, reportid, core::slice::from_raw_parts(core::mem::transmute_copy(&reportbuffer), reportbuffer_array_size as usize)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnInputReportReceived: OnInputReportReceived::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IHidGameControllerInputSink_Vtbl {
@@ -778,6 +941,17 @@ impl IXusbGameControllerInputSink_Vtbl {
         iid == &<IXusbGameControllerInputSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXusbGameControllerInputSink_Impl> windows_core::imp::TearOffVtable<Identity> for IXusbGameControllerInputSink_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnInputReceived<Identity: windows_core::IUnknownImpl + IXusbGameControllerInputSink_Impl>(this: *mut core::ffi::c_void, timestamp: u64, reportid: u8, inputbuffer_array_size: u32, inputbuffer: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXusbGameControllerInputSink_Impl::OnInputReceived(this, timestamp, reportid, core::slice::from_raw_parts(core::mem::transmute_copy(&inputbuffer), inputbuffer_array_size as usize)).into()
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnInputReceived: OnInputReceived::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IXusbGameControllerInputSink_Vtbl {
@@ -449,6 +449,60 @@ impl IForceFeedbackEffect_Vtbl {
         iid == &<IForceFeedbackEffect as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IForceFeedbackEffect_Impl> windows_core::imp::TearOffVtable<Identity> for IForceFeedbackEffect_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Gain<Identity: windows_core::IUnknownImpl + IForceFeedbackEffect_Impl>(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IForceFeedbackEffect_Impl::Gain(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetGain<Identity: windows_core::IUnknownImpl + IForceFeedbackEffect_Impl>(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IForceFeedbackEffect_Impl::SetGain(this, value).into()
+            }
+        }
+        unsafe extern "system" fn State<Identity: windows_core::IUnknownImpl + IForceFeedbackEffect_Impl>(this: *mut core::ffi::c_void, result__: *mut ForceFeedbackEffectState) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IForceFeedbackEffect_Impl::State(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Start<Identity: windows_core::IUnknownImpl + IForceFeedbackEffect_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IForceFeedbackEffect_Impl::Start(this).into()
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + IForceFeedbackEffect_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IForceFeedbackEffect_Impl::Stop(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Gain: Gain::<Identity>,
+            SetGain: SetGain::<Identity>,
+            State: State::<Identity>,
+            Start: Start::<Identity>,
+            Stop: Stop::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IForceFeedbackEffect_Vtbl {
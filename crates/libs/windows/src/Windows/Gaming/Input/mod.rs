@@ -1122,6 +1122,115 @@ impl IGameController_Vtbl {
         iid == &<IGameController as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "System")]
+impl<Identity: windows_core::IUnknownImpl + IGameController_Impl> windows_core::imp::TearOffVtable<Identity> for IGameController_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn HeadsetConnected<Identity: windows_core::IUnknownImpl + IGameController_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameController_Impl::HeadsetConnected(this, core::mem::transmute_copy(&value)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveHeadsetConnected<Identity: windows_core::IUnknownImpl + IGameController_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGameController_Impl::RemoveHeadsetConnected(this, token).into()
+            }
+        }
+        unsafe extern "system" fn HeadsetDisconnected<Identity: windows_core::IUnknownImpl + IGameController_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameController_Impl::HeadsetDisconnected(this, core::mem::transmute_copy(&value)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveHeadsetDisconnected<Identity: windows_core::IUnknownImpl + IGameController_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGameController_Impl::RemoveHeadsetDisconnected(this, token).into()
+            }
+        }
+        unsafe extern "system" fn UserChanged<Identity: windows_core::IUnknownImpl + IGameController_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameController_Impl::UserChanged(this, core::mem::transmute_copy(&value)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveUserChanged<Identity: windows_core::IUnknownImpl + IGameController_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGameController_Impl::RemoveUserChanged(this, token).into()
+            }
+        }
+        unsafe extern "system" fn Headset<Identity: windows_core::IUnknownImpl + IGameController_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameController_Impl::Headset(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsWireless<Identity: windows_core::IUnknownImpl + IGameController_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameController_Impl::IsWireless(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn User<Identity: windows_core::IUnknownImpl + IGameController_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameController_Impl::User(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            HeadsetConnected: HeadsetConnected::<Identity>,
+            RemoveHeadsetConnected: RemoveHeadsetConnected::<Identity>,
+            HeadsetDisconnected: HeadsetDisconnected::<Identity>,
+            RemoveHeadsetDisconnected: RemoveHeadsetDisconnected::<Identity>,
+            UserChanged: UserChanged::<Identity>,
+            RemoveUserChanged: RemoveUserChanged::<Identity>,
+            Headset: Headset::<Identity>,
+            IsWireless: IsWireless::<Identity>,
+            User: User::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IGameController_Vtbl {
@@ -1190,6 +1299,28 @@ impl IGameControllerBatteryInfo_Vtbl {
         iid == &<IGameControllerBatteryInfo as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Devices_Power")]
+impl<Identity: windows_core::IUnknownImpl + IGameControllerBatteryInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IGameControllerBatteryInfo_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn TryGetBatteryReport<Identity: windows_core::IUnknownImpl + IGameControllerBatteryInfo_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameControllerBatteryInfo_Impl::TryGetBatteryReport(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            TryGetBatteryReport: TryGetBatteryReport::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IGameControllerBatteryInfo_Vtbl {
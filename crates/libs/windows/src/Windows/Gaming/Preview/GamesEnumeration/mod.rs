@@ -691,6 +691,83 @@ impl IGameListEntry_Vtbl {
         iid == &<IGameListEntry as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "ApplicationModel")]
+impl<Identity: windows_core::IUnknownImpl + IGameListEntry_Impl> windows_core::imp::TearOffVtable<Identity> for IGameListEntry_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn DisplayInfo<Identity: windows_core::IUnknownImpl + IGameListEntry_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameListEntry_Impl::DisplayInfo(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LaunchAsync<Identity: windows_core::IUnknownImpl + IGameListEntry_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameListEntry_Impl::LaunchAsync(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Category<Identity: windows_core::IUnknownImpl + IGameListEntry_Impl>(this: *mut core::ffi::c_void, result__: *mut GameListCategory) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameListEntry_Impl::Category(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Properties<Identity: windows_core::IUnknownImpl + IGameListEntry_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameListEntry_Impl::Properties(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCategoryAsync<Identity: windows_core::IUnknownImpl + IGameListEntry_Impl>(this: *mut core::ffi::c_void, value: GameListCategory, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameListEntry_Impl::SetCategoryAsync(this, value) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DisplayInfo: DisplayInfo::<Identity>,
+            LaunchAsync: LaunchAsync::<Identity>,
+            Category: Category::<Identity>,
+            Properties: Properties::<Identity>,
+            SetCategoryAsync: SetCategoryAsync::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IGameListEntry_Vtbl {
@@ -623,6 +623,55 @@ impl INumberFormatter_Vtbl {
         iid == &<INumberFormatter as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INumberFormatter_Impl> windows_core::imp::TearOffVtable<Identity> for INumberFormatter_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn FormatInt<Identity: windows_core::IUnknownImpl + INumberFormatter_Impl>(this: *mut core::ffi::c_void, value: i64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberFormatter_Impl::FormatInt(this, value) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FormatUInt<Identity: windows_core::IUnknownImpl + INumberFormatter_Impl>(this: *mut core::ffi::c_void, value: u64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberFormatter_Impl::FormatUInt(this, value) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FormatDouble<Identity: windows_core::IUnknownImpl + INumberFormatter_Impl>(this: *mut core::ffi::c_void, value: f64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberFormatter_Impl::FormatDouble(this, value) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            FormatInt: FormatInt::<Identity>,
+            FormatUInt: FormatUInt::<Identity>,
+            FormatDouble: FormatDouble::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct INumberFormatter_Vtbl {
@@ -719,6 +768,55 @@ impl INumberFormatter2_Vtbl {
         iid == &<INumberFormatter2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INumberFormatter2_Impl> windows_core::imp::TearOffVtable<Identity> for INumberFormatter2_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn FormatInt<Identity: windows_core::IUnknownImpl + INumberFormatter2_Impl>(this: *mut core::ffi::c_void, value: i64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberFormatter2_Impl::FormatInt(this, value) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FormatUInt<Identity: windows_core::IUnknownImpl + INumberFormatter2_Impl>(this: *mut core::ffi::c_void, value: u64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberFormatter2_Impl::FormatUInt(this, value) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FormatDouble<Identity: windows_core::IUnknownImpl + INumberFormatter2_Impl>(this: *mut core::ffi::c_void, value: f64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberFormatter2_Impl::FormatDouble(this, value) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            FormatInt: FormatInt::<Identity>,
+            FormatUInt: FormatUInt::<Identity>,
+            FormatDouble: FormatDouble::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct INumberFormatter2_Vtbl {
@@ -1003,6 +1101,170 @@ impl INumberFormatterOptions_Vtbl {
         iid == &<INumberFormatterOptions as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INumberFormatterOptions_Impl> windows_core::imp::TearOffVtable<Identity> for INumberFormatterOptions_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Languages<Identity: windows_core::IUnknownImpl + INumberFormatterOptions_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberFormatterOptions_Impl::Languages(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GeographicRegion<Identity: windows_core::IUnknownImpl + INumberFormatterOptions_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberFormatterOptions_Impl::GeographicRegion(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IntegerDigits<Identity: windows_core::IUnknownImpl + INumberFormatterOptions_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberFormatterOptions_Impl::IntegerDigits(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetIntegerDigits<Identity: windows_core::IUnknownImpl + INumberFormatterOptions_Impl>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INumberFormatterOptions_Impl::SetIntegerDigits(this, value).into()
+            }
+        }
+        unsafe extern "system" fn FractionDigits<Identity: windows_core::IUnknownImpl + INumberFormatterOptions_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberFormatterOptions_Impl::FractionDigits(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetFractionDigits<Identity: windows_core::IUnknownImpl + INumberFormatterOptions_Impl>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INumberFormatterOptions_Impl::SetFractionDigits(this, value).into()
+            }
+        }
+        unsafe extern "system" fn IsGrouped<Identity: windows_core::IUnknownImpl + INumberFormatterOptions_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberFormatterOptions_Impl::IsGrouped(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetIsGrouped<Identity: windows_core::IUnknownImpl + INumberFormatterOptions_Impl>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INumberFormatterOptions_Impl::SetIsGrouped(this, value).into()
+            }
+        }
+        unsafe extern "system" fn IsDecimalPointAlwaysDisplayed<Identity: windows_core::IUnknownImpl + INumberFormatterOptions_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberFormatterOptions_Impl::IsDecimalPointAlwaysDisplayed(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetIsDecimalPointAlwaysDisplayed<Identity: windows_core::IUnknownImpl + INumberFormatterOptions_Impl>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INumberFormatterOptions_Impl::SetIsDecimalPointAlwaysDisplayed(this, value).into()
+            }
+        }
+        unsafe extern "system" fn NumeralSystem<Identity: windows_core::IUnknownImpl + INumberFormatterOptions_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberFormatterOptions_Impl::NumeralSystem(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNumeralSystem<Identity: windows_core::IUnknownImpl + INumberFormatterOptions_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INumberFormatterOptions_Impl::SetNumeralSystem(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn ResolvedLanguage<Identity: windows_core::IUnknownImpl + INumberFormatterOptions_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberFormatterOptions_Impl::ResolvedLanguage(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ResolvedGeographicRegion<Identity: windows_core::IUnknownImpl + INumberFormatterOptions_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberFormatterOptions_Impl::ResolvedGeographicRegion(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Languages: Languages::<Identity>,
+            GeographicRegion: GeographicRegion::<Identity>,
+            IntegerDigits: IntegerDigits::<Identity>,
+            SetIntegerDigits: SetIntegerDigits::<Identity>,
+            FractionDigits: FractionDigits::<Identity>,
+            SetFractionDigits: SetFractionDigits::<Identity>,
+            IsGrouped: IsGrouped::<Identity>,
+            SetIsGrouped: SetIsGrouped::<Identity>,
+            IsDecimalPointAlwaysDisplayed: IsDecimalPointAlwaysDisplayed::<Identity>,
+            SetIsDecimalPointAlwaysDisplayed: SetIsDecimalPointAlwaysDisplayed::<Identity>,
+            NumeralSystem: NumeralSystem::<Identity>,
+            SetNumeralSystem: SetNumeralSystem::<Identity>,
+            ResolvedLanguage: ResolvedLanguage::<Identity>,
+            ResolvedGeographicRegion: ResolvedGeographicRegion::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct INumberFormatterOptions_Vtbl {
@@ -1110,6 +1372,55 @@ impl INumberParser_Vtbl {
         iid == &<INumberParser as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INumberParser_Impl> windows_core::imp::TearOffVtable<Identity> for INumberParser_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ParseInt<Identity: windows_core::IUnknownImpl + INumberParser_Impl>(this: *mut core::ffi::c_void, text: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberParser_Impl::ParseInt(this, core::mem::transmute(&text)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ParseUInt<Identity: windows_core::IUnknownImpl + INumberParser_Impl>(this: *mut core::ffi::c_void, text: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberParser_Impl::ParseUInt(this, core::mem::transmute(&text)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ParseDouble<Identity: windows_core::IUnknownImpl + INumberParser_Impl>(this: *mut core::ffi::c_void, text: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberParser_Impl::ParseDouble(this, core::mem::transmute(&text)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ParseInt: ParseInt::<Identity>,
+            ParseUInt: ParseUInt::<Identity>,
+            ParseDouble: ParseDouble::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct INumberParser_Vtbl {
@@ -1266,6 +1577,91 @@ impl INumberRounder_Vtbl {
         iid == &<INumberRounder as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INumberRounder_Impl> windows_core::imp::TearOffVtable<Identity> for INumberRounder_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn RoundInt32<Identity: windows_core::IUnknownImpl + INumberRounder_Impl>(this: *mut core::ffi::c_void, value: i32, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberRounder_Impl::RoundInt32(this, value) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RoundUInt32<Identity: windows_core::IUnknownImpl + INumberRounder_Impl>(this: *mut core::ffi::c_void, value: u32, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberRounder_Impl::RoundUInt32(this, value) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RoundInt64<Identity: windows_core::IUnknownImpl + INumberRounder_Impl>(this: *mut core::ffi::c_void, value: i64, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberRounder_Impl::RoundInt64(this, value) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RoundUInt64<Identity: windows_core::IUnknownImpl + INumberRounder_Impl>(this: *mut core::ffi::c_void, value: u64, result__: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberRounder_Impl::RoundUInt64(this, value) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RoundSingle<Identity: windows_core::IUnknownImpl + INumberRounder_Impl>(this: *mut core::ffi::c_void, value: f32, result__: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberRounder_Impl::RoundSingle(this, value) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RoundDouble<Identity: windows_core::IUnknownImpl + INumberRounder_Impl>(this: *mut core::ffi::c_void, value: f64, result__: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberRounder_Impl::RoundDouble(this, value) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RoundInt32: RoundInt32::<Identity>,
+            RoundUInt32: RoundUInt32::<Identity>,
+            RoundInt64: RoundInt64::<Identity>,
+            RoundUInt64: RoundUInt64::<Identity>,
+            RoundSingle: RoundSingle::<Identity>,
+            RoundDouble: RoundDouble::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct INumberRounder_Vtbl {
@@ -1336,6 +1732,34 @@ impl INumberRounderOption_Vtbl {
         iid == &<INumberRounderOption as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INumberRounderOption_Impl> windows_core::imp::TearOffVtable<Identity> for INumberRounderOption_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn NumberRounder<Identity: windows_core::IUnknownImpl + INumberRounderOption_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INumberRounderOption_Impl::NumberRounder(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNumberRounder<Identity: windows_core::IUnknownImpl + INumberRounderOption_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INumberRounderOption_Impl::SetNumberRounder(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            NumberRounder: NumberRounder::<Identity>,
+            SetNumberRounder: SetNumberRounder::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct INumberRounderOption_Vtbl {
@@ -1442,6 +1866,33 @@ impl ISignedZeroOption_Vtbl {
         iid == &<ISignedZeroOption as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISignedZeroOption_Impl> windows_core::imp::TearOffVtable<Identity> for ISignedZeroOption_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsZeroSigned<Identity: windows_core::IUnknownImpl + ISignedZeroOption_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISignedZeroOption_Impl::IsZeroSigned(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetIsZeroSigned<Identity: windows_core::IUnknownImpl + ISignedZeroOption_Impl>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISignedZeroOption_Impl::SetIsZeroSigned(this, value).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsZeroSigned: IsZeroSigned::<Identity>,
+            SetIsZeroSigned: SetIsZeroSigned::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ISignedZeroOption_Vtbl {
@@ -1517,6 +1968,33 @@ impl ISignificantDigitsOption_Vtbl {
         iid == &<ISignificantDigitsOption as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISignificantDigitsOption_Impl> windows_core::imp::TearOffVtable<Identity> for ISignificantDigitsOption_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SignificantDigits<Identity: windows_core::IUnknownImpl + ISignificantDigitsOption_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISignificantDigitsOption_Impl::SignificantDigits(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSignificantDigits<Identity: windows_core::IUnknownImpl + ISignificantDigitsOption_Impl>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISignificantDigitsOption_Impl::SetSignificantDigits(this, value).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SignificantDigits: SignificantDigits::<Identity>,
+            SetSignificantDigits: SetSignificantDigits::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ISignificantDigitsOption_Vtbl {
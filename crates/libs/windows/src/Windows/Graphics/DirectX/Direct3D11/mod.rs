@@ -129,6 +129,17 @@ impl IDirect3DDevice_Vtbl {
         iid == &<IDirect3DDevice as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirect3DDevice_Impl> windows_core::imp::TearOffVtable<Identity> for IDirect3DDevice_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Trim<Identity: windows_core::IUnknownImpl + IDirect3DDevice_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirect3DDevice_Impl::Trim(this).into()
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Trim: Trim::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IDirect3DDevice_Vtbl {
@@ -180,6 +191,23 @@ impl IDirect3DSurface_Vtbl {
         iid == &<IDirect3DSurface as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirect3DSurface_Impl> windows_core::imp::TearOffVtable<Identity> for IDirect3DSurface_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Description<Identity: windows_core::IUnknownImpl + IDirect3DSurface_Impl>(this: *mut core::ffi::c_void, result__: *mut Direct3DSurfaceDescription) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirect3DSurface_Impl::Description(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Description: Description::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IDirect3DSurface_Vtbl {
@@ -55,6 +55,34 @@ impl IGraphicsEffect_Vtbl {
         iid == &<IGraphicsEffect as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IGraphicsEffect_Impl> windows_core::imp::TearOffVtable<Identity> for IGraphicsEffect_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + IGraphicsEffect_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGraphicsEffect_Impl::Name(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetName<Identity: windows_core::IUnknownImpl + IGraphicsEffect_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGraphicsEffect_Impl::SetName(this, core::mem::transmute(&name)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Name: Name::<Identity>,
+            SetName: SetName::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IGraphicsEffect_Vtbl {
@@ -79,6 +107,9 @@ impl IGraphicsEffectSource_Vtbl {
         iid == &<IGraphicsEffectSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IGraphicsEffectSource_Impl> windows_core::imp::TearOffVtable<Identity> for IGraphicsEffectSource_Vtbl {
+    const VTABLE: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IGraphicsEffectSource_Vtbl {
@@ -221,6 +221,34 @@ impl IPrintCustomOptionDetails_Vtbl {
         iid == &<IPrintCustomOptionDetails as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintCustomOptionDetails_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintCustomOptionDetails_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetDisplayName<Identity: windows_core::IUnknownImpl + IPrintCustomOptionDetails_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintCustomOptionDetails_Impl::SetDisplayName(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn DisplayName<Identity: windows_core::IUnknownImpl + IPrintCustomOptionDetails_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintCustomOptionDetails_Impl::DisplayName(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetDisplayName: SetDisplayName::<Identity>,
+            DisplayName: DisplayName::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPrintCustomOptionDetails_Vtbl {
@@ -386,6 +414,24 @@ impl IPrintItemListOptionDetails_Vtbl {
         iid == &<IPrintItemListOptionDetails as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintItemListOptionDetails_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintItemListOptionDetails_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Items<Identity: windows_core::IUnknownImpl + IPrintItemListOptionDetails_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintItemListOptionDetails_Impl::Items(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Items: Items::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPrintItemListOptionDetails_Vtbl {
@@ -536,6 +582,39 @@ impl IPrintNumberOptionDetails_Vtbl {
         iid == &<IPrintNumberOptionDetails as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintNumberOptionDetails_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintNumberOptionDetails_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn MinValue<Identity: windows_core::IUnknownImpl + IPrintNumberOptionDetails_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintNumberOptionDetails_Impl::MinValue(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MaxValue<Identity: windows_core::IUnknownImpl + IPrintNumberOptionDetails_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintNumberOptionDetails_Impl::MaxValue(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MinValue: MinValue::<Identity>,
+            MaxValue: MaxValue::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPrintNumberOptionDetails_Vtbl {
@@ -721,6 +800,108 @@ impl IPrintOptionDetails_Vtbl {
         iid == &<IPrintOptionDetails as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintOptionDetails_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintOptionDetails_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn OptionId<Identity: windows_core::IUnknownImpl + IPrintOptionDetails_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintOptionDetails_Impl::OptionId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OptionType<Identity: windows_core::IUnknownImpl + IPrintOptionDetails_Impl>(this: *mut core::ffi::c_void, result__: *mut PrintOptionType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintOptionDetails_Impl::OptionType(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetErrorText<Identity: windows_core::IUnknownImpl + IPrintOptionDetails_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOptionDetails_Impl::SetErrorText(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn ErrorText<Identity: windows_core::IUnknownImpl + IPrintOptionDetails_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintOptionDetails_Impl::ErrorText(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetState<Identity: windows_core::IUnknownImpl + IPrintOptionDetails_Impl>(this: *mut core::ffi::c_void, value: PrintOptionStates) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOptionDetails_Impl::SetState(this, value).into()
+            }
+        }
+        unsafe extern "system" fn State<Identity: windows_core::IUnknownImpl + IPrintOptionDetails_Impl>(this: *mut core::ffi::c_void, result__: *mut PrintOptionStates) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintOptionDetails_Impl::State(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Value<Identity: windows_core::IUnknownImpl + IPrintOptionDetails_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintOptionDetails_Impl::Value(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn TrySetValue<Identity: windows_core::IUnknownImpl + IPrintOptionDetails_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintOptionDetails_Impl::TrySetValue(this, core::mem::transmute_copy(&value)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OptionId: OptionId::<Identity>,
+            OptionType: OptionType::<Identity>,
+            SetErrorText: SetErrorText::<Identity>,
+            ErrorText: ErrorText::<Identity>,
+            SetState: SetState::<Identity>,
+            State: State::<Identity>,
+            Value: Value::<Identity>,
+            TrySetValue: TrySetValue::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPrintOptionDetails_Vtbl {
@@ -926,6 +1107,23 @@ impl IPrintTextOptionDetails_Vtbl {
         iid == &<IPrintTextOptionDetails as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintTextOptionDetails_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintTextOptionDetails_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn MaxCharacters<Identity: windows_core::IUnknownImpl + IPrintTextOptionDetails_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintTextOptionDetails_Impl::MaxCharacters(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, MaxCharacters: MaxCharacters::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPrintTextOptionDetails_Vtbl {
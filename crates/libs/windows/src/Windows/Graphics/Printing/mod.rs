@@ -25,6 +25,9 @@ impl IPrintDocumentSource_Vtbl {
         iid == &<IPrintDocumentSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintDocumentSource_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintDocumentSource_Vtbl {
+    const VTABLE: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPrintDocumentSource_Vtbl {
@@ -231,6 +234,26 @@ impl IPrintTaskOptionsCore_Vtbl {
         iid == &<IPrintTaskOptionsCore as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCore_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintTaskOptionsCore_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPageDescription<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCore_Impl>(this: *mut core::ffi::c_void, jobpagenumber: u32, result__: *mut PrintPageDescription) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintTaskOptionsCore_Impl::GetPageDescription(this, jobpagenumber) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPageDescription: GetPageDescription::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPrintTaskOptionsCore_Vtbl {
@@ -664,6 +687,259 @@ impl IPrintTaskOptionsCoreProperties_Vtbl {
         iid == &<IPrintTaskOptionsCoreProperties as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintTaskOptionsCoreProperties_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetMediaSize<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, value: PrintMediaSize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintTaskOptionsCoreProperties_Impl::SetMediaSize(this, value).into()
+            }
+        }
+        unsafe extern "system" fn MediaSize<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut PrintMediaSize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintTaskOptionsCoreProperties_Impl::MediaSize(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMediaType<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, value: PrintMediaType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintTaskOptionsCoreProperties_Impl::SetMediaType(this, value).into()
+            }
+        }
+        unsafe extern "system" fn MediaType<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut PrintMediaType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintTaskOptionsCoreProperties_Impl::MediaType(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetOrientation<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, value: PrintOrientation) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintTaskOptionsCoreProperties_Impl::SetOrientation(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Orientation<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut PrintOrientation) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintTaskOptionsCoreProperties_Impl::Orientation(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPrintQuality<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, value: PrintQuality) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintTaskOptionsCoreProperties_Impl::SetPrintQuality(this, value).into()
+            }
+        }
+        unsafe extern "system" fn PrintQuality<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut PrintQuality) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintTaskOptionsCoreProperties_Impl::PrintQuality(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetColorMode<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, value: PrintColorMode) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintTaskOptionsCoreProperties_Impl::SetColorMode(this, value).into()
+            }
+        }
+        unsafe extern "system" fn ColorMode<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut PrintColorMode) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintTaskOptionsCoreProperties_Impl::ColorMode(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDuplex<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, value: PrintDuplex) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintTaskOptionsCoreProperties_Impl::SetDuplex(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Duplex<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut PrintDuplex) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintTaskOptionsCoreProperties_Impl::Duplex(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCollation<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, value: PrintCollation) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintTaskOptionsCoreProperties_Impl::SetCollation(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Collation<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut PrintCollation) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintTaskOptionsCoreProperties_Impl::Collation(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetStaple<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, value: PrintStaple) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintTaskOptionsCoreProperties_Impl::SetStaple(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Staple<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut PrintStaple) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintTaskOptionsCoreProperties_Impl::Staple(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetHolePunch<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, value: PrintHolePunch) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintTaskOptionsCoreProperties_Impl::SetHolePunch(this, value).into()
+            }
+        }
+        unsafe extern "system" fn HolePunch<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut PrintHolePunch) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintTaskOptionsCoreProperties_Impl::HolePunch(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBinding<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, value: PrintBinding) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintTaskOptionsCoreProperties_Impl::SetBinding(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Binding<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut PrintBinding) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintTaskOptionsCoreProperties_Impl::Binding(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MinCopies<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintTaskOptionsCoreProperties_Impl::MinCopies(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MaxCopies<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintTaskOptionsCoreProperties_Impl::MaxCopies(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNumberOfCopies<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintTaskOptionsCoreProperties_Impl::SetNumberOfCopies(this, value).into()
+            }
+        }
+        unsafe extern "system" fn NumberOfCopies<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintTaskOptionsCoreProperties_Impl::NumberOfCopies(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetMediaSize: SetMediaSize::<Identity>,
+            MediaSize: MediaSize::<Identity>,
+            SetMediaType: SetMediaType::<Identity>,
+            MediaType: MediaType::<Identity>,
+            SetOrientation: SetOrientation::<Identity>,
+            Orientation: Orientation::<Identity>,
+            SetPrintQuality: SetPrintQuality::<Identity>,
+            PrintQuality: PrintQuality::<Identity>,
+            SetColorMode: SetColorMode::<Identity>,
+            ColorMode: ColorMode::<Identity>,
+            SetDuplex: SetDuplex::<Identity>,
+            Duplex: Duplex::<Identity>,
+            SetCollation: SetCollation::<Identity>,
+            Collation: Collation::<Identity>,
+            SetStaple: SetStaple::<Identity>,
+            Staple: Staple::<Identity>,
+            SetHolePunch: SetHolePunch::<Identity>,
+            HolePunch: HolePunch::<Identity>,
+            SetBinding: SetBinding::<Identity>,
+            Binding: Binding::<Identity>,
+            MinCopies: MinCopies::<Identity>,
+            MaxCopies: MaxCopies::<Identity>,
+            SetNumberOfCopies: SetNumberOfCopies::<Identity>,
+            NumberOfCopies: NumberOfCopies::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPrintTaskOptionsCoreProperties_Vtbl {
@@ -737,6 +1013,24 @@ impl IPrintTaskOptionsCoreUIConfiguration_Vtbl {
         iid == &<IPrintTaskOptionsCoreUIConfiguration as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreUIConfiguration_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintTaskOptionsCoreUIConfiguration_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn DisplayedOptions<Identity: windows_core::IUnknownImpl + IPrintTaskOptionsCoreUIConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintTaskOptionsCoreUIConfiguration_Impl::DisplayedOptions(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, DisplayedOptions: DisplayedOptions::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPrintTaskOptionsCoreUIConfiguration_Vtbl {
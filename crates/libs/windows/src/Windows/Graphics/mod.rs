@@ -54,6 +54,9 @@ impl IGeometrySource2D_Vtbl {
         iid == &<IGeometrySource2D as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IGeometrySource2D_Impl> windows_core::imp::TearOffVtable<Identity> for IGeometrySource2D_Vtbl {
+    const VTABLE: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IGeometrySource2D_Vtbl {
@@ -3361,6 +3361,49 @@ impl IAudioInputNode_Vtbl {
         iid == &<IAudioInputNode as windows_core::Interface>::IID
     }
 }
+#[cfg(all(feature = "Media_Effects", feature = "Media_MediaProperties"))]
+impl<Identity: windows_core::IUnknownImpl + IAudioInputNode_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioInputNode_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn OutgoingConnections<Identity: windows_core::IUnknownImpl + IAudioInputNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioInputNode_Impl::OutgoingConnections(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddOutgoingConnection<Identity: windows_core::IUnknownImpl + IAudioInputNode_Impl>(this: *mut core::ffi::c_void, destination: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioInputNode_Impl::AddOutgoingConnection(this, core::mem::transmute_copy(&destination)).into()
+            }
+        }
+        unsafe extern "system" fn AddOutgoingConnectionWithGain<Identity: windows_core::IUnknownImpl + IAudioInputNode_Impl>(this: *mut core::ffi::c_void, destination: *mut core::ffi::c_void, gain: f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioInputNode_Impl::AddOutgoingConnectionWithGain(this, core::mem::transmute_copy(&destination), gain).into()
+            }
+        }
+        unsafe extern "system" fn RemoveOutgoingConnection<Identity: windows_core::IUnknownImpl + IAudioInputNode_Impl>(this: *mut core::ffi::c_void, destination: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioInputNode_Impl::RemoveOutgoingConnection(this, core::mem::transmute_copy(&destination)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OutgoingConnections: OutgoingConnections::<Identity>,
+            AddOutgoingConnection: AddOutgoingConnection::<Identity>,
+            AddOutgoingConnectionWithGain: AddOutgoingConnectionWithGain::<Identity>,
+            RemoveOutgoingConnection: RemoveOutgoingConnection::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAudioInputNode_Vtbl {
@@ -3513,6 +3556,25 @@ impl IAudioInputNode2_Vtbl {
         iid == &<IAudioInputNode2 as windows_core::Interface>::IID
     }
 }
+#[cfg(all(feature = "Media_Effects", feature = "Media_MediaProperties"))]
+impl<Identity: windows_core::IUnknownImpl + IAudioInputNode2_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioInputNode2_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Emitter<Identity: windows_core::IUnknownImpl + IAudioInputNode2_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioInputNode2_Impl::Emitter(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Emitter: Emitter::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAudioInputNode2_Vtbl {
@@ -3729,6 +3791,117 @@ impl IAudioNode_Vtbl {
         iid == &<IAudioNode as windows_core::Interface>::IID
     }
 }
+#[cfg(all(feature = "Media_Effects", feature = "Media_MediaProperties"))]
+impl<Identity: windows_core::IUnknownImpl + IAudioNode_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioNode_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn EffectDefinitions<Identity: windows_core::IUnknownImpl + IAudioNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioNode_Impl::EffectDefinitions(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetOutgoingGain<Identity: windows_core::IUnknownImpl + IAudioNode_Impl>(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioNode_Impl::SetOutgoingGain(this, value).into()
+            }
+        }
+        unsafe extern "system" fn OutgoingGain<Identity: windows_core::IUnknownImpl + IAudioNode_Impl>(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioNode_Impl::OutgoingGain(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EncodingProperties<Identity: windows_core::IUnknownImpl + IAudioNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioNode_Impl::EncodingProperties(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ConsumeInput<Identity: windows_core::IUnknownImpl + IAudioNode_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioNode_Impl::ConsumeInput(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetConsumeInput<Identity: windows_core::IUnknownImpl + IAudioNode_Impl>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioNode_Impl::SetConsumeInput(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Start<Identity: windows_core::IUnknownImpl + IAudioNode_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioNode_Impl::Start(this).into()
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + IAudioNode_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioNode_Impl::Stop(this).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IAudioNode_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioNode_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn DisableEffectsByDefinition<Identity: windows_core::IUnknownImpl + IAudioNode_Impl>(this: *mut core::ffi::c_void, definition: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioNode_Impl::DisableEffectsByDefinition(this, core::mem::transmute_copy(&definition)).into()
+            }
+        }
+        unsafe extern "system" fn EnableEffectsByDefinition<Identity: windows_core::IUnknownImpl + IAudioNode_Impl>(this: *mut core::ffi::c_void, definition: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioNode_Impl::EnableEffectsByDefinition(this, core::mem::transmute_copy(&definition)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EffectDefinitions: EffectDefinitions::<Identity>,
+            SetOutgoingGain: SetOutgoingGain::<Identity>,
+            OutgoingGain: OutgoingGain::<Identity>,
+            EncodingProperties: EncodingProperties::<Identity>,
+            ConsumeInput: ConsumeInput::<Identity>,
+            SetConsumeInput: SetConsumeInput::<Identity>,
+            Start: Start::<Identity>,
+            Stop: Stop::<Identity>,
+            Reset: Reset::<Identity>,
+            DisableEffectsByDefinition: DisableEffectsByDefinition::<Identity>,
+            EnableEffectsByDefinition: EnableEffectsByDefinition::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAudioNode_Vtbl {
@@ -4027,6 +4200,35 @@ impl IAudioNodeWithListener_Vtbl {
         iid == &<IAudioNodeWithListener as windows_core::Interface>::IID
     }
 }
+#[cfg(all(feature = "Media_Effects", feature = "Media_MediaProperties"))]
+impl<Identity: windows_core::IUnknownImpl + IAudioNodeWithListener_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioNodeWithListener_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetListener<Identity: windows_core::IUnknownImpl + IAudioNodeWithListener_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioNodeWithListener_Impl::SetListener(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Listener<Identity: windows_core::IUnknownImpl + IAudioNodeWithListener_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioNodeWithListener_Impl::Listener(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetListener: SetListener::<Identity>,
+            Listener: Listener::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAudioNodeWithListener_Vtbl {
@@ -1785,6 +1785,74 @@ impl IMediaCue_Vtbl {
         iid == &<IMediaCue as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMediaCue_Impl> windows_core::imp::TearOffVtable<Identity> for IMediaCue_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetStartTime<Identity: windows_core::IUnknownImpl + IMediaCue_Impl>(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaCue_Impl::SetStartTime(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn StartTime<Identity: windows_core::IUnknownImpl + IMediaCue_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaCue_Impl::StartTime(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDuration<Identity: windows_core::IUnknownImpl + IMediaCue_Impl>(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaCue_Impl::SetDuration(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Duration<Identity: windows_core::IUnknownImpl + IMediaCue_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaCue_Impl::Duration(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetId<Identity: windows_core::IUnknownImpl + IMediaCue_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaCue_Impl::SetId(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + IMediaCue_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaCue_Impl::Id(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetStartTime: SetStartTime::<Identity>,
+            StartTime: StartTime::<Identity>,
+            SetDuration: SetDuration::<Identity>,
+            Duration: Duration::<Identity>,
+            SetId: SetId::<Identity>,
+            Id: Id::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IMediaCue_Vtbl {
@@ -1823,6 +1891,9 @@ impl IMediaSource_Vtbl {
         iid == &<IMediaSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMediaSource_Impl> windows_core::imp::TearOffVtable<Identity> for IMediaSource_Vtbl {
+    const VTABLE: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IMediaSource_Vtbl {
@@ -2152,6 +2223,68 @@ impl IMediaStreamDescriptor_Vtbl {
         iid == &<IMediaStreamDescriptor as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMediaStreamDescriptor_Impl> windows_core::imp::TearOffVtable<Identity> for IMediaStreamDescriptor_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsSelected<Identity: windows_core::IUnknownImpl + IMediaStreamDescriptor_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaStreamDescriptor_Impl::IsSelected(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetName<Identity: windows_core::IUnknownImpl + IMediaStreamDescriptor_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaStreamDescriptor_Impl::SetName(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + IMediaStreamDescriptor_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaStreamDescriptor_Impl::Name(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLanguage<Identity: windows_core::IUnknownImpl + IMediaStreamDescriptor_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaStreamDescriptor_Impl::SetLanguage(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Language<Identity: windows_core::IUnknownImpl + IMediaStreamDescriptor_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaStreamDescriptor_Impl::Language(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsSelected: IsSelected::<Identity>,
+            SetName: SetName::<Identity>,
+            Name: Name::<Identity>,
+            SetLanguage: SetLanguage::<Identity>,
+            Language: Language::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IMediaStreamDescriptor_Vtbl {
@@ -2248,6 +2381,34 @@ impl IMediaStreamDescriptor2_Vtbl {
         iid == &<IMediaStreamDescriptor2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMediaStreamDescriptor2_Impl> windows_core::imp::TearOffVtable<Identity> for IMediaStreamDescriptor2_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetLabel<Identity: windows_core::IUnknownImpl + IMediaStreamDescriptor2_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaStreamDescriptor2_Impl::SetLabel(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Label<Identity: windows_core::IUnknownImpl + IMediaStreamDescriptor2_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaStreamDescriptor2_Impl::Label(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetLabel: SetLabel::<Identity>,
+            Label: Label::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IMediaStreamDescriptor2_Vtbl {
@@ -2685,6 +2846,75 @@ impl IMediaTrack_Vtbl {
         iid == &<IMediaTrack as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMediaTrack_Impl> windows_core::imp::TearOffVtable<Identity> for IMediaTrack_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + IMediaTrack_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaTrack_Impl::Id(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Language<Identity: windows_core::IUnknownImpl + IMediaTrack_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaTrack_Impl::Language(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn TrackKind<Identity: windows_core::IUnknownImpl + IMediaTrack_Impl>(this: *mut core::ffi::c_void, result__: *mut MediaTrackKind) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaTrack_Impl::TrackKind(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLabel<Identity: windows_core::IUnknownImpl + IMediaTrack_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaTrack_Impl::SetLabel(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Label<Identity: windows_core::IUnknownImpl + IMediaTrack_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaTrack_Impl::Label(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Id: Id::<Identity>,
+            Language: Language::<Identity>,
+            TrackKind: TrackKind::<Identity>,
+            SetLabel: SetLabel::<Identity>,
+            Label: Label::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IMediaTrack_Vtbl {
@@ -2935,6 +3165,53 @@ impl ISingleSelectMediaTrackList_Vtbl {
         iid == &<ISingleSelectMediaTrackList as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISingleSelectMediaTrackList_Impl> windows_core::imp::TearOffVtable<Identity> for ISingleSelectMediaTrackList_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SelectedIndexChanged<Identity: windows_core::IUnknownImpl + ISingleSelectMediaTrackList_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISingleSelectMediaTrackList_Impl::SelectedIndexChanged(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveSelectedIndexChanged<Identity: windows_core::IUnknownImpl + ISingleSelectMediaTrackList_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISingleSelectMediaTrackList_Impl::RemoveSelectedIndexChanged(this, token).into()
+            }
+        }
+        unsafe extern "system" fn SetSelectedIndex<Identity: windows_core::IUnknownImpl + ISingleSelectMediaTrackList_Impl>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISingleSelectMediaTrackList_Impl::SetSelectedIndex(this, value).into()
+            }
+        }
+        unsafe extern "system" fn SelectedIndex<Identity: windows_core::IUnknownImpl + ISingleSelectMediaTrackList_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISingleSelectMediaTrackList_Impl::SelectedIndex(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SelectedIndexChanged: SelectedIndexChanged::<Identity>,
+            RemoveSelectedIndexChanged: RemoveSelectedIndexChanged::<Identity>,
+            SetSelectedIndex: SetSelectedIndex::<Identity>,
+            SelectedIndex: SelectedIndex::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ISingleSelectMediaTrackList_Vtbl {
@@ -3096,6 +3373,27 @@ impl ITimedMetadataTrackProvider_Vtbl {
         iid == &<ITimedMetadataTrackProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITimedMetadataTrackProvider_Impl> windows_core::imp::TearOffVtable<Identity> for ITimedMetadataTrackProvider_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn TimedMetadataTracks<Identity: windows_core::IUnknownImpl + ITimedMetadataTrackProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITimedMetadataTrackProvider_Impl::TimedMetadataTracks(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            TimedMetadataTracks: TimedMetadataTracks::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ITimedMetadataTrackProvider_Vtbl {
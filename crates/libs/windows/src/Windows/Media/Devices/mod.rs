@@ -2128,6 +2128,36 @@ impl IDefaultAudioDeviceChangedEventArgs_Vtbl {
         iid == &<IDefaultAudioDeviceChangedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDefaultAudioDeviceChangedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IDefaultAudioDeviceChangedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + IDefaultAudioDeviceChangedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDefaultAudioDeviceChangedEventArgs_Impl::Id(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Role<Identity: windows_core::IUnknownImpl + IDefaultAudioDeviceChangedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut AudioDeviceRole) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDefaultAudioDeviceChangedEventArgs_Impl::Role(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Id: Id::<Identity>, Role: Role::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IDefaultAudioDeviceChangedEventArgs_Vtbl {
@@ -2592,6 +2622,56 @@ impl IMediaDeviceController_Vtbl {
         iid == &<IMediaDeviceController as windows_core::Interface>::IID
     }
 }
+#[cfg(all(feature = "Media_Capture", feature = "Media_MediaProperties"))]
+impl<Identity: windows_core::IUnknownImpl + IMediaDeviceController_Impl> windows_core::imp::TearOffVtable<Identity> for IMediaDeviceController_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAvailableMediaStreamProperties<Identity: windows_core::IUnknownImpl + IMediaDeviceController_Impl>(this: *mut core::ffi::c_void, mediastreamtype: super::Capture::MediaStreamType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaDeviceController_Impl::GetAvailableMediaStreamProperties(this, mediastreamtype) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMediaStreamProperties<Identity: windows_core::IUnknownImpl + IMediaDeviceController_Impl>(this: *mut core::ffi::c_void, mediastreamtype: super::Capture::MediaStreamType, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaDeviceController_Impl::GetMediaStreamProperties(this, mediastreamtype) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMediaStreamPropertiesAsync<Identity: windows_core::IUnknownImpl + IMediaDeviceController_Impl>(this: *mut core::ffi::c_void, mediastreamtype: super::Capture::MediaStreamType, mediaencodingproperties: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaDeviceController_Impl::SetMediaStreamPropertiesAsync(this, mediastreamtype, core::mem::transmute_copy(&mediaencodingproperties)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAvailableMediaStreamProperties: GetAvailableMediaStreamProperties::<Identity>,
+            GetMediaStreamProperties: GetMediaStreamProperties::<Identity>,
+            SetMediaStreamPropertiesAsync: SetMediaStreamPropertiesAsync::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IMediaDeviceController_Vtbl {
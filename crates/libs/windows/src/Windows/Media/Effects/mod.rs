@@ -468,6 +468,42 @@ impl IAudioEffectDefinition_Vtbl {
         iid == &<IAudioEffectDefinition as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Foundation_Collections")]
+impl<Identity: windows_core::IUnknownImpl + IAudioEffectDefinition_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioEffectDefinition_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ActivatableClassId<Identity: windows_core::IUnknownImpl + IAudioEffectDefinition_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioEffectDefinition_Impl::ActivatableClassId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Properties<Identity: windows_core::IUnknownImpl + IAudioEffectDefinition_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioEffectDefinition_Impl::Properties(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ActivatableClassId: ActivatableClassId::<Identity>,
+            Properties: Properties::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAudioEffectDefinition_Vtbl {
@@ -677,6 +713,69 @@ impl IBasicAudioEffect_Vtbl {
         iid == &<IBasicAudioEffect as windows_core::Interface>::IID
     }
 }
+#[cfg(all(feature = "Foundation_Collections", feature = "Media_MediaProperties"))]
+impl<Identity: windows_core::IUnknownImpl + IBasicAudioEffect_Impl> windows_core::imp::TearOffVtable<Identity> for IBasicAudioEffect_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn UseInputFrameForOutput<Identity: windows_core::IUnknownImpl + IBasicAudioEffect_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBasicAudioEffect_Impl::UseInputFrameForOutput(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SupportedEncodingProperties<Identity: windows_core::IUnknownImpl + IBasicAudioEffect_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBasicAudioEffect_Impl::SupportedEncodingProperties(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEncodingProperties<Identity: windows_core::IUnknownImpl + IBasicAudioEffect_Impl>(this: *mut core::ffi::c_void, encodingproperties: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBasicAudioEffect_Impl::SetEncodingProperties(this, core::mem::transmute_copy(&encodingproperties)).into()
+            }
+        }
+        unsafe extern "system" fn ProcessFrame<Identity: windows_core::IUnknownImpl + IBasicAudioEffect_Impl>(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBasicAudioEffect_Impl::ProcessFrame(this, core::mem::transmute_copy(&context)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IBasicAudioEffect_Impl>(this: *mut core::ffi::c_void, reason: MediaEffectClosedReason) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBasicAudioEffect_Impl::Close(this, reason).into()
+            }
+        }
+        unsafe extern "system" fn DiscardQueuedFrames<Identity: windows_core::IUnknownImpl + IBasicAudioEffect_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBasicAudioEffect_Impl::DiscardQueuedFrames(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            UseInputFrameForOutput: UseInputFrameForOutput::<Identity>,
+            SupportedEncodingProperties: SupportedEncodingProperties::<Identity>,
+            SetEncodingProperties: SetEncodingProperties::<Identity>,
+            ProcessFrame: ProcessFrame::<Identity>,
+            Close: Close::<Identity>,
+            DiscardQueuedFrames: DiscardQueuedFrames::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IBasicAudioEffect_Vtbl {
@@ -870,6 +969,95 @@ impl IBasicVideoEffect_Vtbl {
         iid == &<IBasicVideoEffect as windows_core::Interface>::IID
     }
 }
+#[cfg(all(feature = "Foundation_Collections", feature = "Graphics_DirectX_Direct3D11", feature = "Media_MediaProperties"))]
+impl<Identity: windows_core::IUnknownImpl + IBasicVideoEffect_Impl> windows_core::imp::TearOffVtable<Identity> for IBasicVideoEffect_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsReadOnly<Identity: windows_core::IUnknownImpl + IBasicVideoEffect_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBasicVideoEffect_Impl::IsReadOnly(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SupportedMemoryTypes<Identity: windows_core::IUnknownImpl + IBasicVideoEffect_Impl>(this: *mut core::ffi::c_void, result__: *mut MediaMemoryTypes) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBasicVideoEffect_Impl::SupportedMemoryTypes(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn TimeIndependent<Identity: windows_core::IUnknownImpl + IBasicVideoEffect_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBasicVideoEffect_Impl::TimeIndependent(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SupportedEncodingProperties<Identity: windows_core::IUnknownImpl + IBasicVideoEffect_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBasicVideoEffect_Impl::SupportedEncodingProperties(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEncodingProperties<Identity: windows_core::IUnknownImpl + IBasicVideoEffect_Impl>(this: *mut core::ffi::c_void, encodingproperties: *mut core::ffi::c_void, device: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBasicVideoEffect_Impl::SetEncodingProperties(this, core::mem::transmute_copy(&encodingproperties), core::mem::transmute_copy(&device)).into()
+            }
+        }
+        unsafe extern "system" fn ProcessFrame<Identity: windows_core::IUnknownImpl + IBasicVideoEffect_Impl>(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBasicVideoEffect_Impl::ProcessFrame(this, core::mem::transmute_copy(&context)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IBasicVideoEffect_Impl>(this: *mut core::ffi::c_void, reason: MediaEffectClosedReason) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBasicVideoEffect_Impl::Close(this, reason).into()
+            }
+        }
+        unsafe extern "system" fn DiscardQueuedFrames<Identity: windows_core::IUnknownImpl + IBasicVideoEffect_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBasicVideoEffect_Impl::DiscardQueuedFrames(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsReadOnly: IsReadOnly::<Identity>,
+            SupportedMemoryTypes: SupportedMemoryTypes::<Identity>,
+            TimeIndependent: TimeIndependent::<Identity>,
+            SupportedEncodingProperties: SupportedEncodingProperties::<Identity>,
+            SetEncodingProperties: SetEncodingProperties::<Identity>,
+            ProcessFrame: ProcessFrame::<Identity>,
+            Close: Close::<Identity>,
+            DiscardQueuedFrames: DiscardQueuedFrames::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IBasicVideoEffect_Vtbl {
@@ -1041,6 +1229,55 @@ impl IVideoCompositor_Vtbl {
         iid == &<IVideoCompositor as windows_core::Interface>::IID
     }
 }
+#[cfg(all(feature = "Foundation_Collections", feature = "Graphics_DirectX_Direct3D11", feature = "Media_MediaProperties"))]
+impl<Identity: windows_core::IUnknownImpl + IVideoCompositor_Impl> windows_core::imp::TearOffVtable<Identity> for IVideoCompositor_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn TimeIndependent<Identity: windows_core::IUnknownImpl + IVideoCompositor_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVideoCompositor_Impl::TimeIndependent(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEncodingProperties<Identity: windows_core::IUnknownImpl + IVideoCompositor_Impl>(this: *mut core::ffi::c_void, backgroundproperties: *mut core::ffi::c_void, device: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVideoCompositor_Impl::SetEncodingProperties(this, core::mem::transmute_copy(&backgroundproperties), core::mem::transmute_copy(&device)).into()
+            }
+        }
+        unsafe extern "system" fn CompositeFrame<Identity: windows_core::IUnknownImpl + IVideoCompositor_Impl>(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVideoCompositor_Impl::CompositeFrame(this, core::mem::transmute_copy(&context)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IVideoCompositor_Impl>(this: *mut core::ffi::c_void, reason: MediaEffectClosedReason) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVideoCompositor_Impl::Close(this, reason).into()
+            }
+        }
+        unsafe extern "system" fn DiscardQueuedFrames<Identity: windows_core::IUnknownImpl + IVideoCompositor_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVideoCompositor_Impl::DiscardQueuedFrames(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            TimeIndependent: TimeIndependent::<Identity>,
+            SetEncodingProperties: SetEncodingProperties::<Identity>,
+            CompositeFrame: CompositeFrame::<Identity>,
+            Close: Close::<Identity>,
+            DiscardQueuedFrames: DiscardQueuedFrames::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IVideoCompositor_Vtbl {
@@ -1124,6 +1361,42 @@ impl IVideoCompositorDefinition_Vtbl {
         iid == &<IVideoCompositorDefinition as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Foundation_Collections")]
+impl<Identity: windows_core::IUnknownImpl + IVideoCompositorDefinition_Impl> windows_core::imp::TearOffVtable<Identity> for IVideoCompositorDefinition_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ActivatableClassId<Identity: windows_core::IUnknownImpl + IVideoCompositorDefinition_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVideoCompositorDefinition_Impl::ActivatableClassId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Properties<Identity: windows_core::IUnknownImpl + IVideoCompositorDefinition_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVideoCompositorDefinition_Impl::Properties(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ActivatableClassId: ActivatableClassId::<Identity>,
+            Properties: Properties::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IVideoCompositorDefinition_Vtbl {
@@ -1218,6 +1491,42 @@ impl IVideoEffectDefinition_Vtbl {
         iid == &<IVideoEffectDefinition as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Foundation_Collections")]
+impl<Identity: windows_core::IUnknownImpl + IVideoEffectDefinition_Impl> windows_core::imp::TearOffVtable<Identity> for IVideoEffectDefinition_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ActivatableClassId<Identity: windows_core::IUnknownImpl + IVideoEffectDefinition_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVideoEffectDefinition_Impl::ActivatableClassId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Properties<Identity: windows_core::IUnknownImpl + IVideoEffectDefinition_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVideoEffectDefinition_Impl::Properties(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ActivatableClassId: ActivatableClassId::<Identity>,
+            Properties: Properties::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IVideoEffectDefinition_Vtbl {
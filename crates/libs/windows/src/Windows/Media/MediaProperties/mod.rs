@@ -964,6 +964,62 @@ impl IMediaEncodingProperties_Vtbl {
         iid == &<IMediaEncodingProperties as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMediaEncodingProperties_Impl> windows_core::imp::TearOffVtable<Identity> for IMediaEncodingProperties_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Properties<Identity: windows_core::IUnknownImpl + IMediaEncodingProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaEncodingProperties_Impl::Properties(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Type<Identity: windows_core::IUnknownImpl + IMediaEncodingProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaEncodingProperties_Impl::Type(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSubtype<Identity: windows_core::IUnknownImpl + IMediaEncodingProperties_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaEncodingProperties_Impl::SetSubtype(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Subtype<Identity: windows_core::IUnknownImpl + IMediaEncodingProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaEncodingProperties_Impl::Subtype(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Properties: Properties::<Identity>,
+            Type: Type::<Identity>,
+            SetSubtype: SetSubtype::<Identity>,
+            Subtype: Subtype::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IMediaEncodingProperties_Vtbl {
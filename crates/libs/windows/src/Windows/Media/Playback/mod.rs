@@ -344,6 +344,34 @@ impl IMediaEnginePlaybackSource_Vtbl {
         iid == &<IMediaEnginePlaybackSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMediaEnginePlaybackSource_Impl> windows_core::imp::TearOffVtable<Identity> for IMediaEnginePlaybackSource_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CurrentItem<Identity: windows_core::IUnknownImpl + IMediaEnginePlaybackSource_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaEnginePlaybackSource_Impl::CurrentItem(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPlaybackSource<Identity: windows_core::IUnknownImpl + IMediaEnginePlaybackSource_Impl>(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaEnginePlaybackSource_Impl::SetPlaybackSource(this, core::mem::transmute_copy(&source)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CurrentItem: CurrentItem::<Identity>,
+            SetPlaybackSource: SetPlaybackSource::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IMediaEnginePlaybackSource_Vtbl {
@@ -885,6 +913,9 @@ impl IMediaPlaybackSource_Vtbl {
         iid == &<IMediaPlaybackSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMediaPlaybackSource_Impl> windows_core::imp::TearOffVtable<Identity> for IMediaPlaybackSource_Vtbl {
+    const VTABLE: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IMediaPlaybackSource_Vtbl {
@@ -118,6 +118,54 @@ impl INDClosedCaptionDataReceivedEventArgs_Vtbl {
         iid == &<INDClosedCaptionDataReceivedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INDClosedCaptionDataReceivedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for INDClosedCaptionDataReceivedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ClosedCaptionDataFormat<Identity: windows_core::IUnknownImpl + INDClosedCaptionDataReceivedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut NDClosedCaptionFormat) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDClosedCaptionDataReceivedEventArgs_Impl::ClosedCaptionDataFormat(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PresentationTimestamp<Identity: windows_core::IUnknownImpl + INDClosedCaptionDataReceivedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDClosedCaptionDataReceivedEventArgs_Impl::PresentationTimestamp(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ClosedCaptionData<Identity: windows_core::IUnknownImpl + INDClosedCaptionDataReceivedEventArgs_Impl>(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDClosedCaptionDataReceivedEventArgs_Impl::ClosedCaptionData(this) {
+                    Ok(ok__) => {
+                        let (ok_data__, ok_data_len__) = ok__.into_abi();
+                        result__.write(core::mem::transmute(ok_data__));
+                        result_size__.write(ok_data_len__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ClosedCaptionDataFormat: ClosedCaptionDataFormat::<Identity>,
+            PresentationTimestamp: PresentationTimestamp::<Identity>,
+            ClosedCaptionData: ClosedCaptionData::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct INDClosedCaptionDataReceivedEventArgs_Vtbl {
@@ -194,6 +242,43 @@ impl INDCustomData_Vtbl {
         iid == &<INDCustomData as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INDCustomData_Impl> windows_core::imp::TearOffVtable<Identity> for INDCustomData_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CustomDataTypeID<Identity: windows_core::IUnknownImpl + INDCustomData_Impl>(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDCustomData_Impl::CustomDataTypeID(this) {
+                    Ok(ok__) => {
+                        let (ok_data__, ok_data_len__) = ok__.into_abi();
+                        result__.write(core::mem::transmute(ok_data__));
+                        result_size__.write(ok_data_len__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CustomData<Identity: windows_core::IUnknownImpl + INDCustomData_Impl>(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDCustomData_Impl::CustomData(this) {
+                    Ok(ok__) => {
+                        let (ok_data__, ok_data_len__) = ok__.into_abi();
+                        result__.write(core::mem::transmute(ok_data__));
+                        result_size__.write(ok_data_len__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CustomDataTypeID: CustomDataTypeID::<Identity>,
+            CustomData: CustomData::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct INDCustomData_Vtbl {
@@ -381,6 +466,101 @@ impl INDDownloadEngine_Vtbl {
         iid == &<INDDownloadEngine as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INDDownloadEngine_Impl> windows_core::imp::TearOffVtable<Identity> for INDDownloadEngine_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Open<Identity: windows_core::IUnknownImpl + INDDownloadEngine_Impl>(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, sessionidbytes_array_size: u32, sessionidbytes: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INDDownloadEngine_Impl::Open(this, core::mem::transmute_copy(&uri), core::slice::from_raw_parts(core::mem::transmute_copy(&sessionidbytes), sessionidbytes_array_size as usize)).into()
+            }
+        }
+        unsafe extern "system" fn Pause<Identity: windows_core::IUnknownImpl + INDDownloadEngine_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INDDownloadEngine_Impl::Pause(this).into()
+            }
+        }
+        unsafe extern "system" fn Resume<Identity: windows_core::IUnknownImpl + INDDownloadEngine_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INDDownloadEngine_Impl::Resume(this).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + INDDownloadEngine_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INDDownloadEngine_Impl::Close(this).into()
+            }
+        }
+        unsafe extern "system" fn Seek<Identity: windows_core::IUnknownImpl + INDDownloadEngine_Impl>(this: *mut core::ffi::c_void, startposition: super::super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INDDownloadEngine_Impl::Seek(this, core::mem::transmute(&startposition)).into()
+            }
+        }
+        unsafe extern "system" fn CanSeek<Identity: windows_core::IUnknownImpl + INDDownloadEngine_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDDownloadEngine_Impl::CanSeek(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn BufferFullMinThresholdInSamples<Identity: windows_core::IUnknownImpl + INDDownloadEngine_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDDownloadEngine_Impl::BufferFullMinThresholdInSamples(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn BufferFullMaxThresholdInSamples<Identity: windows_core::IUnknownImpl + INDDownloadEngine_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDDownloadEngine_Impl::BufferFullMaxThresholdInSamples(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Notifier<Identity: windows_core::IUnknownImpl + INDDownloadEngine_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDDownloadEngine_Impl::Notifier(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Open: Open::<Identity>,
+            Pause: Pause::<Identity>,
+            Resume: Resume::<Identity>,
+            Close: Close::<Identity>,
+            Seek: Seek::<Identity>,
+            CanSeek: CanSeek::<Identity>,
+            BufferFullMinThresholdInSamples: BufferFullMinThresholdInSamples::<Identity>,
+            BufferFullMaxThresholdInSamples: BufferFullMaxThresholdInSamples::<Identity>,
+            Notifier: Notifier::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct INDDownloadEngine_Vtbl {
@@ -492,6 +672,55 @@ impl INDDownloadEngineNotifier_Vtbl {
         iid == &<INDDownloadEngineNotifier as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INDDownloadEngineNotifier_Impl> windows_core::imp::TearOffVtable<Identity> for INDDownloadEngineNotifier_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnStreamOpened<Identity: windows_core::IUnknownImpl + INDDownloadEngineNotifier_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INDDownloadEngineNotifier_Impl::OnStreamOpened(this).into()
+            }
+        }
+        unsafe extern "system" fn OnPlayReadyObjectReceived<Identity: windows_core::IUnknownImpl + INDDownloadEngineNotifier_Impl>(this: *mut core::ffi::c_void, databytes_array_size: u32, databytes: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INDDownloadEngineNotifier_Impl::OnPlayReadyObjectReceived(this, core::slice::from_raw_parts(core::mem::transmute_copy(&databytes), databytes_array_size as usize)).into()
+            }
+        }
+        unsafe extern "system" fn OnContentIDReceived<Identity: windows_core::IUnknownImpl + INDDownloadEngineNotifier_Impl>(this: *mut core::ffi::c_void, licensefetchdescriptor: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INDDownloadEngineNotifier_Impl::OnContentIDReceived(this, core::mem::transmute_copy(&licensefetchdescriptor)).into()
+            }
+        }
+        unsafe extern "system" fn OnDataReceived<Identity: windows_core::IUnknownImpl + INDDownloadEngineNotifier_Impl>(this: *mut core::ffi::c_void, databytes_array_size: u32, databytes: *const u8, bytesreceived: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INDDownloadEngineNotifier_Impl::OnDataReceived(this, core::slice::from_raw_parts(core::mem::transmute_copy(&databytes), databytes_array_size as usize), bytesreceived).into()
+            }
+        }
+        unsafe extern "system" fn OnEndOfStream<Identity: windows_core::IUnknownImpl + INDDownloadEngineNotifier_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INDDownloadEngineNotifier_Impl::OnEndOfStream(this).into()
+            }
+        }
+        unsafe extern "system" fn OnNetworkError<Identity: windows_core::IUnknownImpl + INDDownloadEngineNotifier_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INDDownloadEngineNotifier_Impl::OnNetworkError(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnStreamOpened: OnStreamOpened::<Identity>,
+            OnPlayReadyObjectReceived: OnPlayReadyObjectReceived::<Identity>,
+            OnContentIDReceived: OnContentIDReceived::<Identity>,
+            OnDataReceived: OnDataReceived::<Identity>,
+            OnEndOfStream: OnEndOfStream::<Identity>,
+            OnNetworkError: OnNetworkError::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct INDDownloadEngineNotifier_Vtbl {
@@ -547,6 +776,27 @@ impl INDLicenseFetchCompletedEventArgs_Vtbl {
         iid == &<INDLicenseFetchCompletedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INDLicenseFetchCompletedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for INDLicenseFetchCompletedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ResponseCustomData<Identity: windows_core::IUnknownImpl + INDLicenseFetchCompletedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDLicenseFetchCompletedEventArgs_Impl::ResponseCustomData(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ResponseCustomData: ResponseCustomData::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct INDLicenseFetchCompletedEventArgs_Vtbl {
@@ -656,6 +906,62 @@ impl INDLicenseFetchDescriptor_Vtbl {
         iid == &<INDLicenseFetchDescriptor as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INDLicenseFetchDescriptor_Impl> windows_core::imp::TearOffVtable<Identity> for INDLicenseFetchDescriptor_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ContentIDType<Identity: windows_core::IUnknownImpl + INDLicenseFetchDescriptor_Impl>(this: *mut core::ffi::c_void, result__: *mut NDContentIDType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDLicenseFetchDescriptor_Impl::ContentIDType(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ContentID<Identity: windows_core::IUnknownImpl + INDLicenseFetchDescriptor_Impl>(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDLicenseFetchDescriptor_Impl::ContentID(this) {
+                    Ok(ok__) => {
+                        let (ok_data__, ok_data_len__) = ok__.into_abi();
+                        result__.write(core::mem::transmute(ok_data__));
+                        result_size__.write(ok_data_len__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LicenseFetchChallengeCustomData<Identity: windows_core::IUnknownImpl + INDLicenseFetchDescriptor_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDLicenseFetchDescriptor_Impl::LicenseFetchChallengeCustomData(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLicenseFetchChallengeCustomData<Identity: windows_core::IUnknownImpl + INDLicenseFetchDescriptor_Impl>(this: *mut core::ffi::c_void, licensefetchchallengecustomdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INDLicenseFetchDescriptor_Impl::SetLicenseFetchChallengeCustomData(this, core::mem::transmute_copy(&licensefetchchallengecustomdata)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ContentIDType: ContentIDType::<Identity>,
+            ContentID: ContentID::<Identity>,
+            LicenseFetchChallengeCustomData: LicenseFetchChallengeCustomData::<Identity>,
+            SetLicenseFetchChallengeCustomData: SetLicenseFetchChallengeCustomData::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct INDLicenseFetchDescriptor_Vtbl {
@@ -719,6 +1025,27 @@ impl INDLicenseFetchResult_Vtbl {
         iid == &<INDLicenseFetchResult as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INDLicenseFetchResult_Impl> windows_core::imp::TearOffVtable<Identity> for INDLicenseFetchResult_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ResponseCustomData<Identity: windows_core::IUnknownImpl + INDLicenseFetchResult_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDLicenseFetchResult_Impl::ResponseCustomData(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ResponseCustomData: ResponseCustomData::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct INDLicenseFetchResult_Vtbl {
@@ -835,41 +1162,104 @@ impl INDMessenger_Vtbl {
         iid == &<INDMessenger as windows_core::Interface>::IID
     }
 }
-#[repr(C)]
-#[doc(hidden)]
-pub struct INDMessenger_Vtbl {
-    pub base__: windows_core::IInspectable_Vtbl,
-    pub SendRegistrationRequestAsync: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *const u8, u32, *const u8, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub SendProximityDetectionStartAsync: unsafe extern "system" fn(*mut core::ffi::c_void, NDProximityDetectionType, u32, *const u8, u32, *const u8, u32, *const u8, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub SendProximityDetectionResponseAsync: unsafe extern "system" fn(*mut core::ffi::c_void, NDProximityDetectionType, u32, *const u8, u32, *const u8, u32, *const u8, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub SendLicenseFetchRequestAsync: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *const u8, u32, *const u8, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-windows_core::imp::define_interface!(INDProximityDetectionCompletedEventArgs, INDProximityDetectionCompletedEventArgs_Vtbl, 0x2a706328_da25_4f8c_9eb7_5d0fc3658bca);
-impl windows_core::RuntimeType for INDProximityDetectionCompletedEventArgs {
-    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
-}
-windows_core::imp::interface_hierarchy!(INDProximityDetectionCompletedEventArgs, windows_core::IUnknown, windows_core::IInspectable);
-impl INDProximityDetectionCompletedEventArgs {
-    pub fn ProximityDetectionRetryCount(&self) -> windows_core::Result<u32> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).ProximityDetectionRetryCount)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
-        }
-    }
-}
-impl windows_core::RuntimeName for INDProximityDetectionCompletedEventArgs {
-    const NAME: &'static str = "Windows.Media.Protection.PlayReady.INDProximityDetectionCompletedEventArgs";
-}
-pub trait INDProximityDetectionCompletedEventArgs_Impl: windows_core::IUnknownImpl {
-    fn ProximityDetectionRetryCount(&self) -> windows_core::Result<u32>;
-}
-impl INDProximityDetectionCompletedEventArgs_Vtbl {
-    pub const fn new<Identity: INDProximityDetectionCompletedEventArgs_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn ProximityDetectionRetryCount<Identity: INDProximityDetectionCompletedEventArgs_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+impl<Identity: windows_core::IUnknownImpl + INDMessenger_Impl> windows_core::imp::TearOffVtable<Identity> for INDMessenger_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SendRegistrationRequestAsync<Identity: windows_core::IUnknownImpl + INDMessenger_Impl>(this: *mut core::ffi::c_void, sessionidbytes_array_size: u32, sessionidbytes: *const u8, challengedatabytes_array_size: u32, challengedatabytes: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match INDProximityDetectionCompletedEventArgs_Impl::ProximityDetectionRetryCount(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDMessenger_Impl::SendRegistrationRequestAsync(this, core::slice::from_raw_parts(core::mem::transmute_copy(&sessionidbytes), sessionidbytes_array_size as usize), core::slice::from_raw_parts(core::mem::transmute_copy(&challengedatabytes), challengedatabytes_array_size as usize)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SendProximityDetectionStartAsync<Identity: windows_core::IUnknownImpl + INDMessenger_Impl>(this: *mut core::ffi::c_void, pdtype: NDProximityDetectionType, transmitterchannelbytes_array_size: u32, transmitterchannelbytes: *const u8, sessionidbytes_array_size: u32, sessionidbytes: *const u8, challengedatabytes_array_size: u32, challengedatabytes: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDMessenger_Impl::SendProximityDetectionStartAsync(this, pdtype, core::slice::from_raw_parts(core::mem::transmute_copy(&transmitterchannelbytes), transmitterchannelbytes_array_size as usize), core::slice::from_raw_parts(core::mem::transmute_copy(&sessionidbytes), sessionidbytes_array_size as usize), core::slice::from_raw_parts(core::mem::transmute_copy(&challengedatabytes), challengedatabytes_array_size as usize)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SendProximityDetectionResponseAsync<Identity: windows_core::IUnknownImpl + INDMessenger_Impl>(this: *mut core::ffi::c_void, pdtype: NDProximityDetectionType, transmitterchannelbytes_array_size: u32, transmitterchannelbytes: *const u8, sessionidbytes_array_size: u32, sessionidbytes: *const u8, responsedatabytes_array_size: u32, responsedatabytes: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDMessenger_Impl::SendProximityDetectionResponseAsync(this, pdtype, core::slice::from_raw_parts(core::mem::transmute_copy(&transmitterchannelbytes), transmitterchannelbytes_array_size as usize), core::slice::from_raw_parts(core::mem::transmute_copy(&sessionidbytes), sessionidbytes_array_size as usize), core::slice::from_raw_parts(core::mem::transmute_copy(&responsedatabytes), responsedatabytes_array_size as usize)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SendLicenseFetchRequestAsync<Identity: windows_core::IUnknownImpl + INDMessenger_Impl>(this: *mut core::ffi::c_void, sessionidbytes_array_size: u32, sessionidbytes: *const u8, challengedatabytes_array_size: u32, challengedatabytes: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDMessenger_Impl::SendLicenseFetchRequestAsync(this, core::slice::from_raw_parts(core::mem::transmute_copy(&sessionidbytes), sessionidbytes_array_size as usize), core::slice::from_raw_parts(core::mem::transmute_copy(&challengedatabytes), challengedatabytes_array_size as usize)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SendRegistrationRequestAsync: SendRegistrationRequestAsync::<Identity>,
+            SendProximityDetectionStartAsync: SendProximityDetectionStartAsync::<Identity>,
+            SendProximityDetectionResponseAsync: SendProximityDetectionResponseAsync::<Identity>,
+            SendLicenseFetchRequestAsync: SendLicenseFetchRequestAsync::<Identity>,
+        }
+    };
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct INDMessenger_Vtbl {
+    pub base__: windows_core::IInspectable_Vtbl,
+    pub SendRegistrationRequestAsync: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *const u8, u32, *const u8, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub SendProximityDetectionStartAsync: unsafe extern "system" fn(*mut core::ffi::c_void, NDProximityDetectionType, u32, *const u8, u32, *const u8, u32, *const u8, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub SendProximityDetectionResponseAsync: unsafe extern "system" fn(*mut core::ffi::c_void, NDProximityDetectionType, u32, *const u8, u32, *const u8, u32, *const u8, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub SendLicenseFetchRequestAsync: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *const u8, u32, *const u8, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+windows_core::imp::define_interface!(INDProximityDetectionCompletedEventArgs, INDProximityDetectionCompletedEventArgs_Vtbl, 0x2a706328_da25_4f8c_9eb7_5d0fc3658bca);
+impl windows_core::RuntimeType for INDProximityDetectionCompletedEventArgs {
+    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
+}
+windows_core::imp::interface_hierarchy!(INDProximityDetectionCompletedEventArgs, windows_core::IUnknown, windows_core::IInspectable);
+impl INDProximityDetectionCompletedEventArgs {
+    pub fn ProximityDetectionRetryCount(&self) -> windows_core::Result<u32> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).ProximityDetectionRetryCount)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        }
+    }
+}
+impl windows_core::RuntimeName for INDProximityDetectionCompletedEventArgs {
+    const NAME: &'static str = "Windows.Media.Protection.PlayReady.INDProximityDetectionCompletedEventArgs";
+}
+pub trait INDProximityDetectionCompletedEventArgs_Impl: windows_core::IUnknownImpl {
+    fn ProximityDetectionRetryCount(&self) -> windows_core::Result<u32>;
+}
+impl INDProximityDetectionCompletedEventArgs_Vtbl {
+    pub const fn new<Identity: INDProximityDetectionCompletedEventArgs_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn ProximityDetectionRetryCount<Identity: INDProximityDetectionCompletedEventArgs_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match INDProximityDetectionCompletedEventArgs_Impl::ProximityDetectionRetryCount(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         windows_core::HRESULT(0)
@@ -887,6 +1277,26 @@ impl INDProximityDetectionCompletedEventArgs_Vtbl {
         iid == &<INDProximityDetectionCompletedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INDProximityDetectionCompletedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for INDProximityDetectionCompletedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ProximityDetectionRetryCount<Identity: windows_core::IUnknownImpl + INDProximityDetectionCompletedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDProximityDetectionCompletedEventArgs_Impl::ProximityDetectionRetryCount(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ProximityDetectionRetryCount: ProximityDetectionRetryCount::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct INDProximityDetectionCompletedEventArgs_Vtbl {
@@ -992,6 +1402,61 @@ impl INDRegistrationCompletedEventArgs_Vtbl {
         iid == &<INDRegistrationCompletedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INDRegistrationCompletedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for INDRegistrationCompletedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ResponseCustomData<Identity: windows_core::IUnknownImpl + INDRegistrationCompletedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDRegistrationCompletedEventArgs_Impl::ResponseCustomData(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn TransmitterProperties<Identity: windows_core::IUnknownImpl + INDRegistrationCompletedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDRegistrationCompletedEventArgs_Impl::TransmitterProperties(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn TransmitterCertificateAccepted<Identity: windows_core::IUnknownImpl + INDRegistrationCompletedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDRegistrationCompletedEventArgs_Impl::TransmitterCertificateAccepted(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTransmitterCertificateAccepted<Identity: windows_core::IUnknownImpl + INDRegistrationCompletedEventArgs_Impl>(this: *mut core::ffi::c_void, accept: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INDRegistrationCompletedEventArgs_Impl::SetTransmitterCertificateAccepted(this, accept).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ResponseCustomData: ResponseCustomData::<Identity>,
+            TransmitterProperties: TransmitterProperties::<Identity>,
+            TransmitterCertificateAccepted: TransmitterCertificateAccepted::<Identity>,
+            SetTransmitterCertificateAccepted: SetTransmitterCertificateAccepted::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct INDRegistrationCompletedEventArgs_Vtbl {
@@ -1043,6 +1508,25 @@ impl INDSendResult_Vtbl {
         iid == &<INDSendResult as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INDSendResult_Impl> windows_core::imp::TearOffVtable<Identity> for INDSendResult_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Response<Identity: windows_core::IUnknownImpl + INDSendResult_Impl>(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDSendResult_Impl::Response(this) {
+                    Ok(ok__) => {
+                        let (ok_data__, ok_data_len__) = ok__.into_abi();
+                        result__.write(core::mem::transmute(ok_data__));
+                        result_size__.write(ok_data_len__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Response: Response::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct INDSendResult_Vtbl {
@@ -1094,6 +1578,28 @@ impl INDStartResult_Vtbl {
         iid == &<INDStartResult as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Media_Core")]
+impl<Identity: windows_core::IUnknownImpl + INDStartResult_Impl> windows_core::imp::TearOffVtable<Identity> for INDStartResult_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn MediaStreamSource<Identity: windows_core::IUnknownImpl + INDStartResult_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDStartResult_Impl::MediaStreamSource(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MediaStreamSource: MediaStreamSource::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct INDStartResult_Vtbl {
@@ -1151,6 +1657,25 @@ impl INDStorageFileHelper_Vtbl {
         iid == &<INDStorageFileHelper as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Storage_Streams")]
+impl<Identity: windows_core::IUnknownImpl + INDStorageFileHelper_Impl> windows_core::imp::TearOffVtable<Identity> for INDStorageFileHelper_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFileURLs<Identity: windows_core::IUnknownImpl + INDStorageFileHelper_Impl>(this: *mut core::ffi::c_void, file: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDStorageFileHelper_Impl::GetFileURLs(this, core::mem::transmute_copy(&file)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetFileURLs: GetFileURLs::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct INDStorageFileHelper_Vtbl {
@@ -1268,6 +1793,62 @@ impl INDStreamParser_Vtbl {
         iid == &<INDStreamParser as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Media_Core")]
+impl<Identity: windows_core::IUnknownImpl + INDStreamParser_Impl> windows_core::imp::TearOffVtable<Identity> for INDStreamParser_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ParseData<Identity: windows_core::IUnknownImpl + INDStreamParser_Impl>(this: *mut core::ffi::c_void, databytes_array_size: u32, databytes: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INDStreamParser_Impl::ParseData(this, core::slice::from_raw_parts(core::mem::transmute_copy(&databytes), databytes_array_size as usize)).into()
+            }
+        }
+        unsafe extern "system" fn GetStreamInformation<Identity: windows_core::IUnknownImpl + INDStreamParser_Impl>(this: *mut core::ffi::c_void, descriptor: *mut core::ffi::c_void, streamtype: *mut NDMediaStreamType, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDStreamParser_Impl::GetStreamInformation(this, core::mem::transmute_copy(&descriptor), core::mem::transmute_copy(&streamtype)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn BeginOfStream<Identity: windows_core::IUnknownImpl + INDStreamParser_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INDStreamParser_Impl::BeginOfStream(this).into()
+            }
+        }
+        unsafe extern "system" fn EndOfStream<Identity: windows_core::IUnknownImpl + INDStreamParser_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INDStreamParser_Impl::EndOfStream(this).into()
+            }
+        }
+        unsafe extern "system" fn Notifier<Identity: windows_core::IUnknownImpl + INDStreamParser_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDStreamParser_Impl::Notifier(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ParseData: ParseData::<Identity>,
+            GetStreamInformation: GetStreamInformation::<Identity>,
+            BeginOfStream: BeginOfStream::<Identity>,
+            EndOfStream: EndOfStream::<Identity>,
+            Notifier: Notifier::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct INDStreamParser_Vtbl {
@@ -1370,6 +1951,42 @@ impl INDStreamParserNotifier_Vtbl {
         iid == &<INDStreamParserNotifier as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Media_Core")]
+impl<Identity: windows_core::IUnknownImpl + INDStreamParserNotifier_Impl> windows_core::imp::TearOffVtable<Identity> for INDStreamParserNotifier_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnContentIDReceived<Identity: windows_core::IUnknownImpl + INDStreamParserNotifier_Impl>(this: *mut core::ffi::c_void, licensefetchdescriptor: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INDStreamParserNotifier_Impl::OnContentIDReceived(this, core::mem::transmute_copy(&licensefetchdescriptor)).into()
+            }
+        }
+        unsafe extern "system" fn OnMediaStreamDescriptorCreated<Identity: windows_core::IUnknownImpl + INDStreamParserNotifier_Impl>(this: *mut core::ffi::c_void, audiostreamdescriptors: *mut core::ffi::c_void, videostreamdescriptors: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INDStreamParserNotifier_Impl::OnMediaStreamDescriptorCreated(this, core::mem::transmute_copy(&audiostreamdescriptors), core::mem::transmute_copy(&videostreamdescriptors)).into()
+            }
+        }
+        unsafe extern "system" fn OnSampleParsed<Identity: windows_core::IUnknownImpl + INDStreamParserNotifier_Impl>(this: *mut core::ffi::c_void, streamid: u32, streamtype: NDMediaStreamType, streamsample: *mut core::ffi::c_void, pts: i64, ccformat: NDClosedCaptionFormat, ccdatabytes_array_size: u32, ccdatabytes: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INDStreamParserNotifier_Impl::OnSampleParsed(this, streamid, streamtype, core::mem::transmute_copy(&streamsample), pts, ccformat, core::slice::from_raw_parts(core::mem::transmute_copy(&ccdatabytes), ccdatabytes_array_size as usize)).into()
+            }
+        }
+        unsafe extern "system" fn OnBeginSetupDecryptor<Identity: windows_core::IUnknownImpl + INDStreamParserNotifier_Impl>(this: *mut core::ffi::c_void, descriptor: *mut core::ffi::c_void, keyid: windows_core::GUID, probytes_array_size: u32, probytes: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INDStreamParserNotifier_Impl::OnBeginSetupDecryptor(this, core::mem::transmute_copy(&descriptor), core::mem::transmute(&keyid), core::slice::from_raw_parts(core::mem::transmute_copy(&probytes), probytes_array_size as usize)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnContentIDReceived: OnContentIDReceived::<Identity>,
+            OnMediaStreamDescriptorCreated: OnMediaStreamDescriptorCreated::<Identity>,
+            OnSampleParsed: OnSampleParsed::<Identity>,
+            OnBeginSetupDecryptor: OnBeginSetupDecryptor::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct INDStreamParserNotifier_Vtbl {
@@ -1660,33 +2277,192 @@ impl INDTransmitterProperties_Vtbl {
         iid == &<INDTransmitterProperties as windows_core::Interface>::IID
     }
 }
-#[repr(C)]
-#[doc(hidden)]
-pub struct INDTransmitterProperties_Vtbl {
-    pub base__: windows_core::IInspectable_Vtbl,
-    pub CertificateType: unsafe extern "system" fn(*mut core::ffi::c_void, *mut NDCertificateType) -> windows_core::HRESULT,
-    pub PlatformIdentifier: unsafe extern "system" fn(*mut core::ffi::c_void, *mut NDCertificatePlatformID) -> windows_core::HRESULT,
-    pub SupportedFeatures: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32, *mut *mut NDCertificateFeature) -> windows_core::HRESULT,
-    pub SecurityLevel: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
-    pub SecurityVersion: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
-    pub ExpirationDate: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT,
-    pub ClientID: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32, *mut *mut u8) -> windows_core::HRESULT,
-    pub ModelDigest: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32, *mut *mut u8) -> windows_core::HRESULT,
-    pub ModelManufacturerName: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub ModelName: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub ModelNumber: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-windows_core::imp::define_interface!(IPlayReadyContentHeader, IPlayReadyContentHeader_Vtbl, 0x9a438a6a_7f4c_452e_88bd_0148c6387a2c);
-impl windows_core::RuntimeType for IPlayReadyContentHeader {
-    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IPlayReadyContentHeader_Vtbl {
-    pub base__: windows_core::IInspectable_Vtbl,
-    pub KeyId: unsafe extern "system" fn(*mut core::ffi::c_void, *mut windows_core::GUID) -> windows_core::HRESULT,
-    pub KeyIdString: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub LicenseAcquisitionUrl: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+impl<Identity: windows_core::IUnknownImpl + INDTransmitterProperties_Impl> windows_core::imp::TearOffVtable<Identity> for INDTransmitterProperties_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CertificateType<Identity: windows_core::IUnknownImpl + INDTransmitterProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut NDCertificateType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDTransmitterProperties_Impl::CertificateType(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PlatformIdentifier<Identity: windows_core::IUnknownImpl + INDTransmitterProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut NDCertificatePlatformID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDTransmitterProperties_Impl::PlatformIdentifier(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SupportedFeatures<Identity: windows_core::IUnknownImpl + INDTransmitterProperties_Impl>(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut NDCertificateFeature) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDTransmitterProperties_Impl::SupportedFeatures(this) {
+                    Ok(ok__) => {
+                        let (ok_data__, ok_data_len__) = ok__.into_abi();
+                        result__.write(core::mem::transmute(ok_data__));
+                        result_size__.write(ok_data_len__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SecurityLevel<Identity: windows_core::IUnknownImpl + INDTransmitterProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDTransmitterProperties_Impl::SecurityLevel(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SecurityVersion<Identity: windows_core::IUnknownImpl + INDTransmitterProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDTransmitterProperties_Impl::SecurityVersion(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ExpirationDate<Identity: windows_core::IUnknownImpl + INDTransmitterProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDTransmitterProperties_Impl::ExpirationDate(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ClientID<Identity: windows_core::IUnknownImpl + INDTransmitterProperties_Impl>(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDTransmitterProperties_Impl::ClientID(this) {
+                    Ok(ok__) => {
+                        let (ok_data__, ok_data_len__) = ok__.into_abi();
+                        result__.write(core::mem::transmute(ok_data__));
+                        result_size__.write(ok_data_len__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ModelDigest<Identity: windows_core::IUnknownImpl + INDTransmitterProperties_Impl>(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDTransmitterProperties_Impl::ModelDigest(this) {
+                    Ok(ok__) => {
+                        let (ok_data__, ok_data_len__) = ok__.into_abi();
+                        result__.write(core::mem::transmute(ok_data__));
+                        result_size__.write(ok_data_len__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ModelManufacturerName<Identity: windows_core::IUnknownImpl + INDTransmitterProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDTransmitterProperties_Impl::ModelManufacturerName(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ModelName<Identity: windows_core::IUnknownImpl + INDTransmitterProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDTransmitterProperties_Impl::ModelName(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ModelNumber<Identity: windows_core::IUnknownImpl + INDTransmitterProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INDTransmitterProperties_Impl::ModelNumber(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CertificateType: CertificateType::<Identity>,
+            PlatformIdentifier: PlatformIdentifier::<Identity>,
+            SupportedFeatures: SupportedFeatures::<Identity>,
+            SecurityLevel: SecurityLevel::<Identity>,
+            SecurityVersion: SecurityVersion::<Identity>,
+            ExpirationDate: ExpirationDate::<Identity>,
+            ClientID: ClientID::<Identity>,
+            ModelDigest: ModelDigest::<Identity>,
+            ModelManufacturerName: ModelManufacturerName::<Identity>,
+            ModelName: ModelName::<Identity>,
+            ModelNumber: ModelNumber::<Identity>,
+        }
+    };
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct INDTransmitterProperties_Vtbl {
+    pub base__: windows_core::IInspectable_Vtbl,
+    pub CertificateType: unsafe extern "system" fn(*mut core::ffi::c_void, *mut NDCertificateType) -> windows_core::HRESULT,
+    pub PlatformIdentifier: unsafe extern "system" fn(*mut core::ffi::c_void, *mut NDCertificatePlatformID) -> windows_core::HRESULT,
+    pub SupportedFeatures: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32, *mut *mut NDCertificateFeature) -> windows_core::HRESULT,
+    pub SecurityLevel: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
+    pub SecurityVersion: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
+    pub ExpirationDate: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT,
+    pub ClientID: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32, *mut *mut u8) -> windows_core::HRESULT,
+    pub ModelDigest: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32, *mut *mut u8) -> windows_core::HRESULT,
+    pub ModelManufacturerName: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub ModelName: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub ModelNumber: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+windows_core::imp::define_interface!(IPlayReadyContentHeader, IPlayReadyContentHeader_Vtbl, 0x9a438a6a_7f4c_452e_88bd_0148c6387a2c);
+impl windows_core::RuntimeType for IPlayReadyContentHeader {
+    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IPlayReadyContentHeader_Vtbl {
+    pub base__: windows_core::IInspectable_Vtbl,
+    pub KeyId: unsafe extern "system" fn(*mut core::ffi::c_void, *mut windows_core::GUID) -> windows_core::HRESULT,
+    pub KeyIdString: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub LicenseAcquisitionUrl: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
     pub LicenseAcquisitionUserInterfaceUrl: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
     pub DomainServiceId: unsafe extern "system" fn(*mut core::ffi::c_void, *mut windows_core::GUID) -> windows_core::HRESULT,
     pub EncryptionType: unsafe extern "system" fn(*mut core::ffi::c_void, *mut PlayReadyEncryptionAlgorithm) -> windows_core::HRESULT,
@@ -1867,6 +2643,80 @@ impl IPlayReadyDomain_Vtbl {
         iid == &<IPlayReadyDomain as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPlayReadyDomain_Impl> windows_core::imp::TearOffVtable<Identity> for IPlayReadyDomain_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn AccountId<Identity: windows_core::IUnknownImpl + IPlayReadyDomain_Impl>(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPlayReadyDomain_Impl::AccountId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ServiceId<Identity: windows_core::IUnknownImpl + IPlayReadyDomain_Impl>(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPlayReadyDomain_Impl::ServiceId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Revision<Identity: windows_core::IUnknownImpl + IPlayReadyDomain_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPlayReadyDomain_Impl::Revision(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FriendlyName<Identity: windows_core::IUnknownImpl + IPlayReadyDomain_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPlayReadyDomain_Impl::FriendlyName(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DomainJoinUrl<Identity: windows_core::IUnknownImpl + IPlayReadyDomain_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPlayReadyDomain_Impl::DomainJoinUrl(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AccountId: AccountId::<Identity>,
+            ServiceId: ServiceId::<Identity>,
+            Revision: Revision::<Identity>,
+            FriendlyName: FriendlyName::<Identity>,
+            DomainJoinUrl: DomainJoinUrl::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPlayReadyDomain_Vtbl {
@@ -1985,31 +2835,133 @@ impl IPlayReadyLicense {
             (windows_core::Interface::vtable(this).ChainDepth)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn GetKIDAtChainDepth(&self, chaindepth: u32) -> windows_core::Result<windows_core::GUID> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).GetKIDAtChainDepth)(windows_core::Interface::as_raw(this), chaindepth, &mut result__).map(|| result__)
-        }
+    pub fn GetKIDAtChainDepth(&self, chaindepth: u32) -> windows_core::Result<windows_core::GUID> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).GetKIDAtChainDepth)(windows_core::Interface::as_raw(this), chaindepth, &mut result__).map(|| result__)
+        }
+    }
+}
+impl windows_core::RuntimeName for IPlayReadyLicense {
+    const NAME: &'static str = "Windows.Media.Protection.PlayReady.IPlayReadyLicense";
+}
+pub trait IPlayReadyLicense_Impl: windows_core::IUnknownImpl {
+    fn FullyEvaluated(&self) -> windows_core::Result<bool>;
+    fn UsableForPlay(&self) -> windows_core::Result<bool>;
+    fn ExpirationDate(&self) -> windows_core::Result<super::super::super::Foundation::IReference<super::super::super::Foundation::DateTime>>;
+    fn ExpireAfterFirstPlay(&self) -> windows_core::Result<u32>;
+    fn DomainAccountID(&self) -> windows_core::Result<windows_core::GUID>;
+    fn ChainDepth(&self) -> windows_core::Result<u32>;
+    fn GetKIDAtChainDepth(&self, chainDepth: u32) -> windows_core::Result<windows_core::GUID>;
+}
+impl IPlayReadyLicense_Vtbl {
+    pub const fn new<Identity: IPlayReadyLicense_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn FullyEvaluated<Identity: IPlayReadyLicense_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IPlayReadyLicense_Impl::FullyEvaluated(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UsableForPlay<Identity: IPlayReadyLicense_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IPlayReadyLicense_Impl::UsableForPlay(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ExpirationDate<Identity: IPlayReadyLicense_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IPlayReadyLicense_Impl::ExpirationDate(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ExpireAfterFirstPlay<Identity: IPlayReadyLicense_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IPlayReadyLicense_Impl::ExpireAfterFirstPlay(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DomainAccountID<Identity: IPlayReadyLicense_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IPlayReadyLicense_Impl::DomainAccountID(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ChainDepth<Identity: IPlayReadyLicense_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IPlayReadyLicense_Impl::ChainDepth(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetKIDAtChainDepth<Identity: IPlayReadyLicense_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, chaindepth: u32, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IPlayReadyLicense_Impl::GetKIDAtChainDepth(this, chaindepth) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IInspectable_Vtbl::new::<Identity, IPlayReadyLicense, OFFSET>(),
+            FullyEvaluated: FullyEvaluated::<Identity, OFFSET>,
+            UsableForPlay: UsableForPlay::<Identity, OFFSET>,
+            ExpirationDate: ExpirationDate::<Identity, OFFSET>,
+            ExpireAfterFirstPlay: ExpireAfterFirstPlay::<Identity, OFFSET>,
+            DomainAccountID: DomainAccountID::<Identity, OFFSET>,
+            ChainDepth: ChainDepth::<Identity, OFFSET>,
+            GetKIDAtChainDepth: GetKIDAtChainDepth::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayReadyLicense as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IPlayReadyLicense {
-    const NAME: &'static str = "Windows.Media.Protection.PlayReady.IPlayReadyLicense";
-}
-pub trait IPlayReadyLicense_Impl: windows_core::IUnknownImpl {
-    fn FullyEvaluated(&self) -> windows_core::Result<bool>;
-    fn UsableForPlay(&self) -> windows_core::Result<bool>;
-    fn ExpirationDate(&self) -> windows_core::Result<super::super::super::Foundation::IReference<super::super::super::Foundation::DateTime>>;
-    fn ExpireAfterFirstPlay(&self) -> windows_core::Result<u32>;
-    fn DomainAccountID(&self) -> windows_core::Result<windows_core::GUID>;
-    fn ChainDepth(&self) -> windows_core::Result<u32>;
-    fn GetKIDAtChainDepth(&self, chainDepth: u32) -> windows_core::Result<windows_core::GUID>;
-}
-impl IPlayReadyLicense_Vtbl {
-    pub const fn new<Identity: IPlayReadyLicense_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn FullyEvaluated<Identity: IPlayReadyLicense_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+impl<Identity: windows_core::IUnknownImpl + IPlayReadyLicense_Impl> windows_core::imp::TearOffVtable<Identity> for IPlayReadyLicense_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn FullyEvaluated<Identity: windows_core::IUnknownImpl + IPlayReadyLicense_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IPlayReadyLicense_Impl::FullyEvaluated(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -2019,9 +2971,9 @@ impl IPlayReadyLicense_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn UsableForPlay<Identity: IPlayReadyLicense_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+        unsafe extern "system" fn UsableForPlay<Identity: windows_core::IUnknownImpl + IPlayReadyLicense_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IPlayReadyLicense_Impl::UsableForPlay(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -2031,9 +2983,9 @@ impl IPlayReadyLicense_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ExpirationDate<Identity: IPlayReadyLicense_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn ExpirationDate<Identity: windows_core::IUnknownImpl + IPlayReadyLicense_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IPlayReadyLicense_Impl::ExpirationDate(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -2044,9 +2996,9 @@ impl IPlayReadyLicense_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ExpireAfterFirstPlay<Identity: IPlayReadyLicense_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn ExpireAfterFirstPlay<Identity: windows_core::IUnknownImpl + IPlayReadyLicense_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IPlayReadyLicense_Impl::ExpireAfterFirstPlay(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -2056,9 +3008,9 @@ impl IPlayReadyLicense_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn DomainAccountID<Identity: IPlayReadyLicense_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+        unsafe extern "system" fn DomainAccountID<Identity: windows_core::IUnknownImpl + IPlayReadyLicense_Impl>(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IPlayReadyLicense_Impl::DomainAccountID(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -2068,9 +3020,9 @@ impl IPlayReadyLicense_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ChainDepth<Identity: IPlayReadyLicense_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn ChainDepth<Identity: windows_core::IUnknownImpl + IPlayReadyLicense_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IPlayReadyLicense_Impl::ChainDepth(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -2080,9 +3032,9 @@ impl IPlayReadyLicense_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetKIDAtChainDepth<Identity: IPlayReadyLicense_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, chaindepth: u32, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetKIDAtChainDepth<Identity: windows_core::IUnknownImpl + IPlayReadyLicense_Impl>(this: *mut core::ffi::c_void, chaindepth: u32, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IPlayReadyLicense_Impl::GetKIDAtChainDepth(this, chaindepth) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -2093,19 +3045,16 @@ impl IPlayReadyLicense_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IInspectable_Vtbl::new::<Identity, IPlayReadyLicense, OFFSET>(),
-            FullyEvaluated: FullyEvaluated::<Identity, OFFSET>,
-            UsableForPlay: UsableForPlay::<Identity, OFFSET>,
-            ExpirationDate: ExpirationDate::<Identity, OFFSET>,
-            ExpireAfterFirstPlay: ExpireAfterFirstPlay::<Identity, OFFSET>,
-            DomainAccountID: DomainAccountID::<Identity, OFFSET>,
-            ChainDepth: ChainDepth::<Identity, OFFSET>,
-            GetKIDAtChainDepth: GetKIDAtChainDepth::<Identity, OFFSET>,
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            FullyEvaluated: FullyEvaluated::<Identity>,
+            UsableForPlay: UsableForPlay::<Identity>,
+            ExpirationDate: ExpirationDate::<Identity>,
+            ExpireAfterFirstPlay: ExpireAfterFirstPlay::<Identity>,
+            DomainAccountID: DomainAccountID::<Identity>,
+            ChainDepth: ChainDepth::<Identity>,
+            GetKIDAtChainDepth: GetKIDAtChainDepth::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IPlayReadyLicense as windows_core::Interface>::IID
-    }
+    };
 }
 #[repr(C)]
 #[doc(hidden)]
@@ -2299,6 +3248,54 @@ impl IPlayReadyLicenseAcquisitionServiceRequest_Vtbl {
         iid == &<IPlayReadyLicenseAcquisitionServiceRequest as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPlayReadyLicenseAcquisitionServiceRequest_Impl> windows_core::imp::TearOffVtable<Identity> for IPlayReadyLicenseAcquisitionServiceRequest_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ContentHeader<Identity: windows_core::IUnknownImpl + IPlayReadyLicenseAcquisitionServiceRequest_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPlayReadyLicenseAcquisitionServiceRequest_Impl::ContentHeader(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetContentHeader<Identity: windows_core::IUnknownImpl + IPlayReadyLicenseAcquisitionServiceRequest_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPlayReadyLicenseAcquisitionServiceRequest_Impl::SetContentHeader(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn DomainServiceId<Identity: windows_core::IUnknownImpl + IPlayReadyLicenseAcquisitionServiceRequest_Impl>(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPlayReadyLicenseAcquisitionServiceRequest_Impl::DomainServiceId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDomainServiceId<Identity: windows_core::IUnknownImpl + IPlayReadyLicenseAcquisitionServiceRequest_Impl>(this: *mut core::ffi::c_void, value: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPlayReadyLicenseAcquisitionServiceRequest_Impl::SetDomainServiceId(this, core::mem::transmute(&value)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ContentHeader: ContentHeader::<Identity>,
+            SetContentHeader: SetContentHeader::<Identity>,
+            DomainServiceId: DomainServiceId::<Identity>,
+            SetDomainServiceId: SetDomainServiceId::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPlayReadyLicenseAcquisitionServiceRequest_Vtbl {
@@ -2407,6 +3404,34 @@ impl IPlayReadyLicenseSession_Vtbl {
         iid == &<IPlayReadyLicenseSession as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPlayReadyLicenseSession_Impl> windows_core::imp::TearOffVtable<Identity> for IPlayReadyLicenseSession_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateLAServiceRequest<Identity: windows_core::IUnknownImpl + IPlayReadyLicenseSession_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPlayReadyLicenseSession_Impl::CreateLAServiceRequest(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ConfigureMediaProtectionManager<Identity: windows_core::IUnknownImpl + IPlayReadyLicenseSession_Impl>(this: *mut core::ffi::c_void, mpm: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPlayReadyLicenseSession_Impl::ConfigureMediaProtectionManager(this, core::mem::transmute_copy(&mpm)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateLAServiceRequest: CreateLAServiceRequest::<Identity>,
+            ConfigureMediaProtectionManager: ConfigureMediaProtectionManager::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPlayReadyLicenseSession_Vtbl {
@@ -2476,6 +3501,27 @@ impl IPlayReadyLicenseSession2_Vtbl {
         iid == &<IPlayReadyLicenseSession2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPlayReadyLicenseSession2_Impl> windows_core::imp::TearOffVtable<Identity> for IPlayReadyLicenseSession2_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateLicenseIterable<Identity: windows_core::IUnknownImpl + IPlayReadyLicenseSession2_Impl>(this: *mut core::ffi::c_void, contentheader: *mut core::ffi::c_void, fullyevaluated: bool, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPlayReadyLicenseSession2_Impl::CreateLicenseIterable(this, core::mem::transmute_copy(&contentheader), fullyevaluated) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateLicenseIterable: CreateLicenseIterable::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPlayReadyLicenseSession2_Vtbl {
@@ -2729,6 +3775,80 @@ impl IPlayReadySecureStopServiceRequest_Vtbl {
         iid == &<IPlayReadySecureStopServiceRequest as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPlayReadySecureStopServiceRequest_Impl> windows_core::imp::TearOffVtable<Identity> for IPlayReadySecureStopServiceRequest_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SessionID<Identity: windows_core::IUnknownImpl + IPlayReadySecureStopServiceRequest_Impl>(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPlayReadySecureStopServiceRequest_Impl::SessionID(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StartTime<Identity: windows_core::IUnknownImpl + IPlayReadySecureStopServiceRequest_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPlayReadySecureStopServiceRequest_Impl::StartTime(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UpdateTime<Identity: windows_core::IUnknownImpl + IPlayReadySecureStopServiceRequest_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPlayReadySecureStopServiceRequest_Impl::UpdateTime(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Stopped<Identity: windows_core::IUnknownImpl + IPlayReadySecureStopServiceRequest_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPlayReadySecureStopServiceRequest_Impl::Stopped(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PublisherCertificate<Identity: windows_core::IUnknownImpl + IPlayReadySecureStopServiceRequest_Impl>(this: *mut core::ffi::c_void, result_size__: *mut u32, result__: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPlayReadySecureStopServiceRequest_Impl::PublisherCertificate(this) {
+                    Ok(ok__) => {
+                        let (ok_data__, ok_data_len__) = ok__.into_abi();
+                        result__.write(core::mem::transmute(ok_data__));
+                        result_size__.write(ok_data_len__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SessionID: SessionID::<Identity>,
+            StartTime: StartTime::<Identity>,
+            UpdateTime: UpdateTime::<Identity>,
+            Stopped: Stopped::<Identity>,
+            PublisherCertificate: PublisherCertificate::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPlayReadySecureStopServiceRequest_Vtbl {
@@ -2967,6 +4087,124 @@ impl IPlayReadyServiceRequest_Vtbl {
         iid == &<IPlayReadyServiceRequest as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPlayReadyServiceRequest_Impl> windows_core::imp::TearOffVtable<Identity> for IPlayReadyServiceRequest_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Uri<Identity: windows_core::IUnknownImpl + IPlayReadyServiceRequest_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPlayReadyServiceRequest_Impl::Uri(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetUri<Identity: windows_core::IUnknownImpl + IPlayReadyServiceRequest_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPlayReadyServiceRequest_Impl::SetUri(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn ResponseCustomData<Identity: windows_core::IUnknownImpl + IPlayReadyServiceRequest_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPlayReadyServiceRequest_Impl::ResponseCustomData(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ChallengeCustomData<Identity: windows_core::IUnknownImpl + IPlayReadyServiceRequest_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPlayReadyServiceRequest_Impl::ChallengeCustomData(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetChallengeCustomData<Identity: windows_core::IUnknownImpl + IPlayReadyServiceRequest_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPlayReadyServiceRequest_Impl::SetChallengeCustomData(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn BeginServiceRequest<Identity: windows_core::IUnknownImpl + IPlayReadyServiceRequest_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPlayReadyServiceRequest_Impl::BeginServiceRequest(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NextServiceRequest<Identity: windows_core::IUnknownImpl + IPlayReadyServiceRequest_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPlayReadyServiceRequest_Impl::NextServiceRequest(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GenerateManualEnablingChallenge<Identity: windows_core::IUnknownImpl + IPlayReadyServiceRequest_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPlayReadyServiceRequest_Impl::GenerateManualEnablingChallenge(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ProcessManualEnablingResponse<Identity: windows_core::IUnknownImpl + IPlayReadyServiceRequest_Impl>(this: *mut core::ffi::c_void, responsebytes_array_size: u32, responsebytes: *const u8, result__: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPlayReadyServiceRequest_Impl::ProcessManualEnablingResponse(this, core::slice::from_raw_parts(core::mem::transmute_copy(&responsebytes), responsebytes_array_size as usize)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Uri: Uri::<Identity>,
+            SetUri: SetUri::<Identity>,
+            ResponseCustomData: ResponseCustomData::<Identity>,
+            ChallengeCustomData: ChallengeCustomData::<Identity>,
+            SetChallengeCustomData: SetChallengeCustomData::<Identity>,
+            BeginServiceRequest: BeginServiceRequest::<Identity>,
+            NextServiceRequest: NextServiceRequest::<Identity>,
+            GenerateManualEnablingChallenge: GenerateManualEnablingChallenge::<Identity>,
+            ProcessManualEnablingResponse: ProcessManualEnablingResponse::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPlayReadyServiceRequest_Vtbl {
@@ -393,6 +393,39 @@ impl IMediaProtectionServiceRequest_Vtbl {
         iid == &<IMediaProtectionServiceRequest as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMediaProtectionServiceRequest_Impl> windows_core::imp::TearOffVtable<Identity> for IMediaProtectionServiceRequest_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ProtectionSystem<Identity: windows_core::IUnknownImpl + IMediaProtectionServiceRequest_Impl>(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaProtectionServiceRequest_Impl::ProtectionSystem(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Type<Identity: windows_core::IUnknownImpl + IMediaProtectionServiceRequest_Impl>(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaProtectionServiceRequest_Impl::Type(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ProtectionSystem: ProtectionSystem::<Identity>,
+            Type: Type::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IMediaProtectionServiceRequest_Vtbl {
@@ -192,6 +192,87 @@ impl ISpeechRecognitionConstraint_Vtbl {
         iid == &<ISpeechRecognitionConstraint as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpeechRecognitionConstraint_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechRecognitionConstraint_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsEnabled<Identity: windows_core::IUnknownImpl + ISpeechRecognitionConstraint_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecognitionConstraint_Impl::IsEnabled(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetIsEnabled<Identity: windows_core::IUnknownImpl + ISpeechRecognitionConstraint_Impl>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecognitionConstraint_Impl::SetIsEnabled(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Tag<Identity: windows_core::IUnknownImpl + ISpeechRecognitionConstraint_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecognitionConstraint_Impl::Tag(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTag<Identity: windows_core::IUnknownImpl + ISpeechRecognitionConstraint_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecognitionConstraint_Impl::SetTag(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Type<Identity: windows_core::IUnknownImpl + ISpeechRecognitionConstraint_Impl>(this: *mut core::ffi::c_void, result__: *mut SpeechRecognitionConstraintType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecognitionConstraint_Impl::Type(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Probability<Identity: windows_core::IUnknownImpl + ISpeechRecognitionConstraint_Impl>(this: *mut core::ffi::c_void, result__: *mut SpeechRecognitionConstraintProbability) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecognitionConstraint_Impl::Probability(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProbability<Identity: windows_core::IUnknownImpl + ISpeechRecognitionConstraint_Impl>(this: *mut core::ffi::c_void, value: SpeechRecognitionConstraintProbability) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecognitionConstraint_Impl::SetProbability(this, value).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsEnabled: IsEnabled::<Identity>,
+            SetIsEnabled: SetIsEnabled::<Identity>,
+            Tag: Tag::<Identity>,
+            SetTag: SetTag::<Identity>,
+            Type: Type::<Identity>,
+            Probability: Probability::<Identity>,
+            SetProbability: SetProbability::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ISpeechRecognitionConstraint_Vtbl {
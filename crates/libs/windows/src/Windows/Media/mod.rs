@@ -402,6 +402,18 @@ impl IMediaExtension_Vtbl {
         iid == &<IMediaExtension as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Foundation_Collections")]
+impl<Identity: windows_core::IUnknownImpl + IMediaExtension_Impl> windows_core::imp::TearOffVtable<Identity> for IMediaExtension_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetProperties<Identity: windows_core::IUnknownImpl + IMediaExtension_Impl>(this: *mut core::ffi::c_void, configuration: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaExtension_Impl::SetProperties(this, core::mem::transmute_copy(&configuration)).into()
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetProperties: SetProperties::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IMediaExtension_Vtbl {
@@ -703,6 +715,138 @@ impl IMediaFrame_Vtbl {
         iid == &<IMediaFrame as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Foundation_Collections")]
+impl<Identity: windows_core::IUnknownImpl + IMediaFrame_Impl> windows_core::imp::TearOffVtable<Identity> for IMediaFrame_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Type<Identity: windows_core::IUnknownImpl + IMediaFrame_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaFrame_Impl::Type(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsReadOnly<Identity: windows_core::IUnknownImpl + IMediaFrame_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaFrame_Impl::IsReadOnly(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRelativeTime<Identity: windows_core::IUnknownImpl + IMediaFrame_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaFrame_Impl::SetRelativeTime(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn RelativeTime<Identity: windows_core::IUnknownImpl + IMediaFrame_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaFrame_Impl::RelativeTime(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSystemRelativeTime<Identity: windows_core::IUnknownImpl + IMediaFrame_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaFrame_Impl::SetSystemRelativeTime(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn SystemRelativeTime<Identity: windows_core::IUnknownImpl + IMediaFrame_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaFrame_Impl::SystemRelativeTime(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDuration<Identity: windows_core::IUnknownImpl + IMediaFrame_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaFrame_Impl::SetDuration(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Duration<Identity: windows_core::IUnknownImpl + IMediaFrame_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaFrame_Impl::Duration(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetIsDiscontinuous<Identity: windows_core::IUnknownImpl + IMediaFrame_Impl>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaFrame_Impl::SetIsDiscontinuous(this, value).into()
+            }
+        }
+        unsafe extern "system" fn IsDiscontinuous<Identity: windows_core::IUnknownImpl + IMediaFrame_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaFrame_Impl::IsDiscontinuous(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ExtendedProperties<Identity: windows_core::IUnknownImpl + IMediaFrame_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaFrame_Impl::ExtendedProperties(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Type: Type::<Identity>,
+            IsReadOnly: IsReadOnly::<Identity>,
+            SetRelativeTime: SetRelativeTime::<Identity>,
+            RelativeTime: RelativeTime::<Identity>,
+            SetSystemRelativeTime: SetSystemRelativeTime::<Identity>,
+            SystemRelativeTime: SystemRelativeTime::<Identity>,
+            SetDuration: SetDuration::<Identity>,
+            Duration: Duration::<Identity>,
+            SetIsDiscontinuous: SetIsDiscontinuous::<Identity>,
+            IsDiscontinuous: IsDiscontinuous::<Identity>,
+            ExtendedProperties: ExtendedProperties::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IMediaFrame_Vtbl {
@@ -809,6 +953,54 @@ impl IMediaMarker_Vtbl {
         iid == &<IMediaMarker as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMediaMarker_Impl> windows_core::imp::TearOffVtable<Identity> for IMediaMarker_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Time<Identity: windows_core::IUnknownImpl + IMediaMarker_Impl>(this: *mut core::ffi::c_void, result__: *mut super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaMarker_Impl::Time(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MediaMarkerType<Identity: windows_core::IUnknownImpl + IMediaMarker_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaMarker_Impl::MediaMarkerType(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Text<Identity: windows_core::IUnknownImpl + IMediaMarker_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaMarker_Impl::Text(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Time: Time::<Identity>,
+            MediaMarkerType: MediaMarkerType::<Identity>,
+            Text: Text::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IMediaMarker_Vtbl {
@@ -868,6 +1060,24 @@ impl IMediaMarkers_Vtbl {
         iid == &<IMediaMarkers as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMediaMarkers_Impl> windows_core::imp::TearOffVtable<Identity> for IMediaMarkers_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Markers<Identity: windows_core::IUnknownImpl + IMediaMarkers_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaMarkers_Impl::Markers(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Markers: Markers::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IMediaMarkers_Vtbl {
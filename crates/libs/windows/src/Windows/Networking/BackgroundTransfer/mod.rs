@@ -1477,6 +1477,125 @@ impl IBackgroundTransferBase_Vtbl {
         iid == &<IBackgroundTransferBase as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Security_Credentials")]
+impl<Identity: windows_core::IUnknownImpl + IBackgroundTransferBase_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundTransferBase_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetRequestHeader<Identity: windows_core::IUnknownImpl + IBackgroundTransferBase_Impl>(this: *mut core::ffi::c_void, headername: *mut core::ffi::c_void, headervalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundTransferBase_Impl::SetRequestHeader(this, core::mem::transmute(&headername), core::mem::transmute(&headervalue)).into()
+            }
+        }
+        unsafe extern "system" fn ServerCredential<Identity: windows_core::IUnknownImpl + IBackgroundTransferBase_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTransferBase_Impl::ServerCredential(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetServerCredential<Identity: windows_core::IUnknownImpl + IBackgroundTransferBase_Impl>(this: *mut core::ffi::c_void, credential: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundTransferBase_Impl::SetServerCredential(this, core::mem::transmute_copy(&credential)).into()
+            }
+        }
+        unsafe extern "system" fn ProxyCredential<Identity: windows_core::IUnknownImpl + IBackgroundTransferBase_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTransferBase_Impl::ProxyCredential(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProxyCredential<Identity: windows_core::IUnknownImpl + IBackgroundTransferBase_Impl>(this: *mut core::ffi::c_void, credential: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundTransferBase_Impl::SetProxyCredential(this, core::mem::transmute_copy(&credential)).into()
+            }
+        }
+        unsafe extern "system" fn Method<Identity: windows_core::IUnknownImpl + IBackgroundTransferBase_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTransferBase_Impl::Method(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMethod<Identity: windows_core::IUnknownImpl + IBackgroundTransferBase_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundTransferBase_Impl::SetMethod(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Group<Identity: windows_core::IUnknownImpl + IBackgroundTransferBase_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTransferBase_Impl::Group(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetGroup<Identity: windows_core::IUnknownImpl + IBackgroundTransferBase_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundTransferBase_Impl::SetGroup(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn CostPolicy<Identity: windows_core::IUnknownImpl + IBackgroundTransferBase_Impl>(this: *mut core::ffi::c_void, result__: *mut BackgroundTransferCostPolicy) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTransferBase_Impl::CostPolicy(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCostPolicy<Identity: windows_core::IUnknownImpl + IBackgroundTransferBase_Impl>(this: *mut core::ffi::c_void, value: BackgroundTransferCostPolicy) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundTransferBase_Impl::SetCostPolicy(this, value).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetRequestHeader: SetRequestHeader::<Identity>,
+            ServerCredential: ServerCredential::<Identity>,
+            SetServerCredential: SetServerCredential::<Identity>,
+            ProxyCredential: ProxyCredential::<Identity>,
+            SetProxyCredential: SetProxyCredential::<Identity>,
+            Method: Method::<Identity>,
+            SetMethod: SetMethod::<Identity>,
+            Group: Group::<Identity>,
+            SetGroup: SetGroup::<Identity>,
+            CostPolicy: CostPolicy::<Identity>,
+            SetCostPolicy: SetCostPolicy::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IBackgroundTransferBase_Vtbl {
@@ -1612,6 +1731,41 @@ impl IBackgroundTransferContentPartFactory_Vtbl {
         iid == &<IBackgroundTransferContentPartFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundTransferContentPartFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundTransferContentPartFactory_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateWithName<Identity: windows_core::IUnknownImpl + IBackgroundTransferContentPartFactory_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTransferContentPartFactory_Impl::CreateWithName(this, core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateWithNameAndFileName<Identity: windows_core::IUnknownImpl + IBackgroundTransferContentPartFactory_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, filename: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTransferContentPartFactory_Impl::CreateWithNameAndFileName(this, core::mem::transmute(&name), core::mem::transmute(&filename)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateWithName: CreateWithName::<Identity>,
+            CreateWithNameAndFileName: CreateWithNameAndFileName::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IBackgroundTransferContentPartFactory_Vtbl {
@@ -1844,6 +1998,117 @@ impl IBackgroundTransferOperation_Vtbl {
         iid == &<IBackgroundTransferOperation as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Storage_Streams")]
+impl<Identity: windows_core::IUnknownImpl + IBackgroundTransferOperation_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundTransferOperation_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Guid<Identity: windows_core::IUnknownImpl + IBackgroundTransferOperation_Impl>(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTransferOperation_Impl::Guid(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RequestedUri<Identity: windows_core::IUnknownImpl + IBackgroundTransferOperation_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTransferOperation_Impl::RequestedUri(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Method<Identity: windows_core::IUnknownImpl + IBackgroundTransferOperation_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTransferOperation_Impl::Method(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Group<Identity: windows_core::IUnknownImpl + IBackgroundTransferOperation_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTransferOperation_Impl::Group(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CostPolicy<Identity: windows_core::IUnknownImpl + IBackgroundTransferOperation_Impl>(this: *mut core::ffi::c_void, result__: *mut BackgroundTransferCostPolicy) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTransferOperation_Impl::CostPolicy(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCostPolicy<Identity: windows_core::IUnknownImpl + IBackgroundTransferOperation_Impl>(this: *mut core::ffi::c_void, value: BackgroundTransferCostPolicy) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundTransferOperation_Impl::SetCostPolicy(this, value).into()
+            }
+        }
+        unsafe extern "system" fn GetResultStreamAt<Identity: windows_core::IUnknownImpl + IBackgroundTransferOperation_Impl>(this: *mut core::ffi::c_void, position: u64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTransferOperation_Impl::GetResultStreamAt(this, position) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetResponseInformation<Identity: windows_core::IUnknownImpl + IBackgroundTransferOperation_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTransferOperation_Impl::GetResponseInformation(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Guid: Guid::<Identity>,
+            RequestedUri: RequestedUri::<Identity>,
+            Method: Method::<Identity>,
+            Group: Group::<Identity>,
+            CostPolicy: CostPolicy::<Identity>,
+            SetCostPolicy: SetCostPolicy::<Identity>,
+            GetResultStreamAt: GetResultStreamAt::<Identity>,
+            GetResponseInformation: GetResponseInformation::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IBackgroundTransferOperation_Vtbl {
@@ -1915,6 +2180,33 @@ impl IBackgroundTransferOperationPriority_Vtbl {
         iid == &<IBackgroundTransferOperationPriority as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundTransferOperationPriority_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundTransferOperationPriority_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Priority<Identity: windows_core::IUnknownImpl + IBackgroundTransferOperationPriority_Impl>(this: *mut core::ffi::c_void, result__: *mut BackgroundTransferPriority) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundTransferOperationPriority_Impl::Priority(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPriority<Identity: windows_core::IUnknownImpl + IBackgroundTransferOperationPriority_Impl>(this: *mut core::ffi::c_void, value: BackgroundTransferPriority) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundTransferOperationPriority_Impl::SetPriority(this, value).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Priority: Priority::<Identity>,
+            SetPriority: SetPriority::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IBackgroundTransferOperationPriority_Vtbl {
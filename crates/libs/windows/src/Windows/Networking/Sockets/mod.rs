@@ -628,6 +628,27 @@ impl IControlChannelTriggerEventDetails_Vtbl {
         iid == &<IControlChannelTriggerEventDetails as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IControlChannelTriggerEventDetails_Impl> windows_core::imp::TearOffVtable<Identity> for IControlChannelTriggerEventDetails_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ControlChannelTrigger<Identity: windows_core::IUnknownImpl + IControlChannelTriggerEventDetails_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IControlChannelTriggerEventDetails_Impl::ControlChannelTrigger(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ControlChannelTrigger: ControlChannelTrigger::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IControlChannelTriggerEventDetails_Vtbl {
@@ -730,6 +751,52 @@ impl IControlChannelTriggerResetEventDetails_Vtbl {
         iid == &<IControlChannelTriggerResetEventDetails as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IControlChannelTriggerResetEventDetails_Impl> windows_core::imp::TearOffVtable<Identity> for IControlChannelTriggerResetEventDetails_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ResetReason<Identity: windows_core::IUnknownImpl + IControlChannelTriggerResetEventDetails_Impl>(this: *mut core::ffi::c_void, result__: *mut ControlChannelTriggerResetReason) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IControlChannelTriggerResetEventDetails_Impl::ResetReason(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn HardwareSlotReset<Identity: windows_core::IUnknownImpl + IControlChannelTriggerResetEventDetails_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IControlChannelTriggerResetEventDetails_Impl::HardwareSlotReset(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SoftwareSlotReset<Identity: windows_core::IUnknownImpl + IControlChannelTriggerResetEventDetails_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IControlChannelTriggerResetEventDetails_Impl::SoftwareSlotReset(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ResetReason: ResetReason::<Identity>,
+            HardwareSlotReset: HardwareSlotReset::<Identity>,
+            SoftwareSlotReset: SoftwareSlotReset::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IControlChannelTriggerResetEventDetails_Vtbl {
@@ -1585,6 +1652,76 @@ impl IWebSocket_Vtbl {
         iid == &<IWebSocket as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Storage_Streams")]
+impl<Identity: windows_core::IUnknownImpl + IWebSocket_Impl> windows_core::imp::TearOffVtable<Identity> for IWebSocket_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn OutputStream<Identity: windows_core::IUnknownImpl + IWebSocket_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebSocket_Impl::OutputStream(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ConnectAsync<Identity: windows_core::IUnknownImpl + IWebSocket_Impl>(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebSocket_Impl::ConnectAsync(this, core::mem::transmute_copy(&uri)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRequestHeader<Identity: windows_core::IUnknownImpl + IWebSocket_Impl>(this: *mut core::ffi::c_void, headername: *mut core::ffi::c_void, headervalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebSocket_Impl::SetRequestHeader(this, core::mem::transmute(&headername), core::mem::transmute(&headervalue)).into()
+            }
+        }
+        unsafe extern "system" fn Closed<Identity: windows_core::IUnknownImpl + IWebSocket_Impl>(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebSocket_Impl::Closed(this, core::mem::transmute_copy(&eventhandler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveClosed<Identity: windows_core::IUnknownImpl + IWebSocket_Impl>(this: *mut core::ffi::c_void, eventcookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebSocket_Impl::RemoveClosed(this, eventcookie).into()
+            }
+        }
+        unsafe extern "system" fn CloseWithStatus<Identity: windows_core::IUnknownImpl + IWebSocket_Impl>(this: *mut core::ffi::c_void, code: u16, reason: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebSocket_Impl::CloseWithStatus(this, code, core::mem::transmute(&reason)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OutputStream: OutputStream::<Identity>,
+            ConnectAsync: ConnectAsync::<Identity>,
+            SetRequestHeader: SetRequestHeader::<Identity>,
+            Closed: Closed::<Identity>,
+            RemoveClosed: RemoveClosed::<Identity>,
+            CloseWithStatus: CloseWithStatus::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IWebSocket_Vtbl {
@@ -1768,6 +1905,90 @@ impl IWebSocketControl_Vtbl {
         iid == &<IWebSocketControl as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Security_Credentials")]
+impl<Identity: windows_core::IUnknownImpl + IWebSocketControl_Impl> windows_core::imp::TearOffVtable<Identity> for IWebSocketControl_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn OutboundBufferSizeInBytes<Identity: windows_core::IUnknownImpl + IWebSocketControl_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebSocketControl_Impl::OutboundBufferSizeInBytes(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetOutboundBufferSizeInBytes<Identity: windows_core::IUnknownImpl + IWebSocketControl_Impl>(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebSocketControl_Impl::SetOutboundBufferSizeInBytes(this, value).into()
+            }
+        }
+        unsafe extern "system" fn ServerCredential<Identity: windows_core::IUnknownImpl + IWebSocketControl_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebSocketControl_Impl::ServerCredential(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetServerCredential<Identity: windows_core::IUnknownImpl + IWebSocketControl_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebSocketControl_Impl::SetServerCredential(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn ProxyCredential<Identity: windows_core::IUnknownImpl + IWebSocketControl_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebSocketControl_Impl::ProxyCredential(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProxyCredential<Identity: windows_core::IUnknownImpl + IWebSocketControl_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebSocketControl_Impl::SetProxyCredential(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn SupportedProtocols<Identity: windows_core::IUnknownImpl + IWebSocketControl_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebSocketControl_Impl::SupportedProtocols(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OutboundBufferSizeInBytes: OutboundBufferSizeInBytes::<Identity>,
+            SetOutboundBufferSizeInBytes: SetOutboundBufferSizeInBytes::<Identity>,
+            ServerCredential: ServerCredential::<Identity>,
+            SetServerCredential: SetServerCredential::<Identity>,
+            ProxyCredential: ProxyCredential::<Identity>,
+            SetProxyCredential: SetProxyCredential::<Identity>,
+            SupportedProtocols: SupportedProtocols::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IWebSocketControl_Vtbl {
@@ -1891,6 +2112,28 @@ impl IWebSocketControl2_Vtbl {
         iid == &<IWebSocketControl2 as windows_core::Interface>::IID
     }
 }
+#[cfg(all(feature = "Security_Credentials", feature = "Security_Cryptography_Certificates"))]
+impl<Identity: windows_core::IUnknownImpl + IWebSocketControl2_Impl> windows_core::imp::TearOffVtable<Identity> for IWebSocketControl2_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn IgnorableServerCertificateErrors<Identity: windows_core::IUnknownImpl + IWebSocketControl2_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebSocketControl2_Impl::IgnorableServerCertificateErrors(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IgnorableServerCertificateErrors: IgnorableServerCertificateErrors::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IWebSocketControl2_Vtbl {
@@ -2000,6 +2243,54 @@ impl IWebSocketInformation_Vtbl {
         iid == &<IWebSocketInformation as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWebSocketInformation_Impl> windows_core::imp::TearOffVtable<Identity> for IWebSocketInformation_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn LocalAddress<Identity: windows_core::IUnknownImpl + IWebSocketInformation_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebSocketInformation_Impl::LocalAddress(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn BandwidthStatistics<Identity: windows_core::IUnknownImpl + IWebSocketInformation_Impl>(this: *mut core::ffi::c_void, result__: *mut BandwidthStatistics) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebSocketInformation_Impl::BandwidthStatistics(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Protocol<Identity: windows_core::IUnknownImpl + IWebSocketInformation_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebSocketInformation_Impl::Protocol(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            LocalAddress: LocalAddress::<Identity>,
+            BandwidthStatistics: BandwidthStatistics::<Identity>,
+            Protocol: Protocol::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IWebSocketInformation_Vtbl {
@@ -2145,6 +2436,69 @@ impl IWebSocketInformation2_Vtbl {
         iid == &<IWebSocketInformation2 as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Security_Cryptography_Certificates")]
+impl<Identity: windows_core::IUnknownImpl + IWebSocketInformation2_Impl> windows_core::imp::TearOffVtable<Identity> for IWebSocketInformation2_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ServerCertificate<Identity: windows_core::IUnknownImpl + IWebSocketInformation2_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebSocketInformation2_Impl::ServerCertificate(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ServerCertificateErrorSeverity<Identity: windows_core::IUnknownImpl + IWebSocketInformation2_Impl>(this: *mut core::ffi::c_void, result__: *mut SocketSslErrorSeverity) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebSocketInformation2_Impl::ServerCertificateErrorSeverity(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ServerCertificateErrors<Identity: windows_core::IUnknownImpl + IWebSocketInformation2_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebSocketInformation2_Impl::ServerCertificateErrors(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ServerIntermediateCertificates<Identity: windows_core::IUnknownImpl + IWebSocketInformation2_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebSocketInformation2_Impl::ServerIntermediateCertificates(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ServerCertificate: ServerCertificate::<Identity>,
+            ServerCertificateErrorSeverity: ServerCertificateErrorSeverity::<Identity>,
+            ServerCertificateErrors: ServerCertificateErrors::<Identity>,
+            ServerIntermediateCertificates: ServerIntermediateCertificates::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IWebSocketInformation2_Vtbl {
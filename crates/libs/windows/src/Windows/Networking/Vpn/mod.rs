@@ -194,6 +194,20 @@ impl IVpnChannelStatics_Vtbl {
         iid == &<IVpnChannelStatics as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVpnChannelStatics_Impl> windows_core::imp::TearOffVtable<Identity> for IVpnChannelStatics_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ProcessEventAsync<Identity: windows_core::IUnknownImpl + IVpnChannelStatics_Impl>(this: *mut core::ffi::c_void, thirdpartyplugin: *mut core::ffi::c_void, event: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVpnChannelStatics_Impl::ProcessEventAsync(this, core::mem::transmute_copy(&thirdpartyplugin), core::mem::transmute_copy(&event)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ProcessEventAsync: ProcessEventAsync::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IVpnChannelStatics_Vtbl {
@@ -316,6 +330,70 @@ impl IVpnCredential_Vtbl {
         iid == &<IVpnCredential as windows_core::Interface>::IID
     }
 }
+#[cfg(all(feature = "Security_Credentials", feature = "Security_Cryptography_Certificates"))]
+impl<Identity: windows_core::IUnknownImpl + IVpnCredential_Impl> windows_core::imp::TearOffVtable<Identity> for IVpnCredential_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn PasskeyCredential<Identity: windows_core::IUnknownImpl + IVpnCredential_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVpnCredential_Impl::PasskeyCredential(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CertificateCredential<Identity: windows_core::IUnknownImpl + IVpnCredential_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVpnCredential_Impl::CertificateCredential(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AdditionalPin<Identity: windows_core::IUnknownImpl + IVpnCredential_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVpnCredential_Impl::AdditionalPin(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OldPasswordCredential<Identity: windows_core::IUnknownImpl + IVpnCredential_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVpnCredential_Impl::OldPasswordCredential(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PasskeyCredential: PasskeyCredential::<Identity>,
+            CertificateCredential: CertificateCredential::<Identity>,
+            AdditionalPin: AdditionalPin::<Identity>,
+            OldPasswordCredential: OldPasswordCredential::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IVpnCredential_Vtbl {
@@ -503,6 +581,74 @@ impl IVpnCustomPrompt_Vtbl {
         iid == &<IVpnCustomPrompt as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVpnCustomPrompt_Impl> windows_core::imp::TearOffVtable<Identity> for IVpnCustomPrompt_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetLabel<Identity: windows_core::IUnknownImpl + IVpnCustomPrompt_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVpnCustomPrompt_Impl::SetLabel(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Label<Identity: windows_core::IUnknownImpl + IVpnCustomPrompt_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVpnCustomPrompt_Impl::Label(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCompulsory<Identity: windows_core::IUnknownImpl + IVpnCustomPrompt_Impl>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVpnCustomPrompt_Impl::SetCompulsory(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Compulsory<Identity: windows_core::IUnknownImpl + IVpnCustomPrompt_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVpnCustomPrompt_Impl::Compulsory(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBordered<Identity: windows_core::IUnknownImpl + IVpnCustomPrompt_Impl>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVpnCustomPrompt_Impl::SetBordered(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Bordered<Identity: windows_core::IUnknownImpl + IVpnCustomPrompt_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVpnCustomPrompt_Impl::Bordered(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetLabel: SetLabel::<Identity>,
+            Label: Label::<Identity>,
+            SetCompulsory: SetCompulsory::<Identity>,
+            Compulsory: Compulsory::<Identity>,
+            SetBordered: SetBordered::<Identity>,
+            Bordered: Bordered::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IVpnCustomPrompt_Vtbl {
@@ -648,6 +794,74 @@ impl IVpnCustomPromptElement_Vtbl {
         iid == &<IVpnCustomPromptElement as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVpnCustomPromptElement_Impl> windows_core::imp::TearOffVtable<Identity> for IVpnCustomPromptElement_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetDisplayName<Identity: windows_core::IUnknownImpl + IVpnCustomPromptElement_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVpnCustomPromptElement_Impl::SetDisplayName(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn DisplayName<Identity: windows_core::IUnknownImpl + IVpnCustomPromptElement_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVpnCustomPromptElement_Impl::DisplayName(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCompulsory<Identity: windows_core::IUnknownImpl + IVpnCustomPromptElement_Impl>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVpnCustomPromptElement_Impl::SetCompulsory(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Compulsory<Identity: windows_core::IUnknownImpl + IVpnCustomPromptElement_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVpnCustomPromptElement_Impl::Compulsory(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEmphasized<Identity: windows_core::IUnknownImpl + IVpnCustomPromptElement_Impl>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVpnCustomPromptElement_Impl::SetEmphasized(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Emphasized<Identity: windows_core::IUnknownImpl + IVpnCustomPromptElement_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVpnCustomPromptElement_Impl::Emphasized(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetDisplayName: SetDisplayName::<Identity>,
+            DisplayName: DisplayName::<Identity>,
+            SetCompulsory: SetCompulsory::<Identity>,
+            Compulsory: Compulsory::<Identity>,
+            SetEmphasized: SetEmphasized::<Identity>,
+            Emphasized: Emphasized::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IVpnCustomPromptElement_Vtbl {
@@ -791,6 +1005,27 @@ impl IVpnDomainNameInfoFactory_Vtbl {
         iid == &<IVpnDomainNameInfoFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVpnDomainNameInfoFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IVpnDomainNameInfoFactory_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateVpnDomainNameInfo<Identity: windows_core::IUnknownImpl + IVpnDomainNameInfoFactory_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, nametype: VpnDomainNameType, dnsserverlist: *mut core::ffi::c_void, proxyserverlist: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVpnDomainNameInfoFactory_Impl::CreateVpnDomainNameInfo(this, core::mem::transmute(&name), nametype, core::mem::transmute_copy(&dnsserverlist), core::mem::transmute_copy(&proxyserverlist)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateVpnDomainNameInfo: CreateVpnDomainNameInfo::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IVpnDomainNameInfoFactory_Vtbl {
@@ -879,6 +1114,27 @@ impl IVpnInterfaceIdFactory_Vtbl {
         iid == &<IVpnInterfaceIdFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVpnInterfaceIdFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IVpnInterfaceIdFactory_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateVpnInterfaceId<Identity: windows_core::IUnknownImpl + IVpnInterfaceIdFactory_Impl>(this: *mut core::ffi::c_void, address_array_size: u32, address: *const u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVpnInterfaceIdFactory_Impl::CreateVpnInterfaceId(this, core::slice::from_raw_parts(core::mem::transmute_copy(&address), address_array_size as usize)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateVpnInterfaceId: CreateVpnInterfaceId::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IVpnInterfaceIdFactory_Vtbl {
@@ -982,6 +1238,27 @@ impl IVpnNamespaceInfoFactory_Vtbl {
         iid == &<IVpnNamespaceInfoFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVpnNamespaceInfoFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IVpnNamespaceInfoFactory_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateVpnNamespaceInfo<Identity: windows_core::IUnknownImpl + IVpnNamespaceInfoFactory_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, dnsserverlist: *mut core::ffi::c_void, proxyserverlist: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVpnNamespaceInfoFactory_Impl::CreateVpnNamespaceInfo(this, core::mem::transmute(&name), core::mem::transmute_copy(&dnsserverlist), core::mem::transmute_copy(&proxyserverlist)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateVpnNamespaceInfo: CreateVpnNamespaceInfo::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IVpnNamespaceInfoFactory_Vtbl {
@@ -1105,6 +1382,27 @@ impl IVpnPacketBufferFactory_Vtbl {
         iid == &<IVpnPacketBufferFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVpnPacketBufferFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IVpnPacketBufferFactory_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateVpnPacketBuffer<Identity: windows_core::IUnknownImpl + IVpnPacketBufferFactory_Impl>(this: *mut core::ffi::c_void, parentbuffer: *mut core::ffi::c_void, offset: u32, length: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVpnPacketBufferFactory_Impl::CreateVpnPacketBuffer(this, core::mem::transmute_copy(&parentbuffer), offset, length) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateVpnPacketBuffer: CreateVpnPacketBuffer::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IVpnPacketBufferFactory_Vtbl {
@@ -1261,6 +1559,48 @@ impl IVpnPlugIn_Vtbl {
         iid == &<IVpnPlugIn as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVpnPlugIn_Impl> windows_core::imp::TearOffVtable<Identity> for IVpnPlugIn_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Connect<Identity: windows_core::IUnknownImpl + IVpnPlugIn_Impl>(this: *mut core::ffi::c_void, channel: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVpnPlugIn_Impl::Connect(this, core::mem::transmute_copy(&channel)).into()
+            }
+        }
+        unsafe extern "system" fn Disconnect<Identity: windows_core::IUnknownImpl + IVpnPlugIn_Impl>(this: *mut core::ffi::c_void, channel: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVpnPlugIn_Impl::Disconnect(this, core::mem::transmute_copy(&channel)).into()
+            }
+        }
+        unsafe extern "system" fn GetKeepAlivePayload<Identity: windows_core::IUnknownImpl + IVpnPlugIn_Impl>(this: *mut core::ffi::c_void, channel: *mut core::ffi::c_void, keepalivepacket: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVpnPlugIn_Impl::GetKeepAlivePayload(this, core::mem::transmute_copy(&channel), core::mem::transmute_copy(&keepalivepacket)).into()
+            }
+        }
+        unsafe extern "system" fn Encapsulate<Identity: windows_core::IUnknownImpl + IVpnPlugIn_Impl>(this: *mut core::ffi::c_void, channel: *mut core::ffi::c_void, packets: *mut core::ffi::c_void, encapulatedpackets: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVpnPlugIn_Impl::Encapsulate(this, core::mem::transmute_copy(&channel), core::mem::transmute_copy(&packets), core::mem::transmute_copy(&encapulatedpackets)).into()
+            }
+        }
+        unsafe extern "system" fn Decapsulate<Identity: windows_core::IUnknownImpl + IVpnPlugIn_Impl>(this: *mut core::ffi::c_void, channel: *mut core::ffi::c_void, encapbuffer: *mut core::ffi::c_void, decapsulatedpackets: *mut core::ffi::c_void, controlpacketstosend: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVpnPlugIn_Impl::Decapsulate(this, core::mem::transmute_copy(&channel), core::mem::transmute_copy(&encapbuffer), core::mem::transmute_copy(&decapsulatedpackets), core::mem::transmute_copy(&controlpacketstosend)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Connect: Connect::<Identity>,
+            Disconnect: Disconnect::<Identity>,
+            GetKeepAlivePayload: GetKeepAlivePayload::<Identity>,
+            Encapsulate: Encapsulate::<Identity>,
+            Decapsulate: Decapsulate::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IVpnPlugIn_Vtbl {
@@ -1335,6 +1675,20 @@ impl IVpnPlugInReconnectTransport_Vtbl {
         iid == &<IVpnPlugInReconnectTransport as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVpnPlugInReconnectTransport_Impl> windows_core::imp::TearOffVtable<Identity> for IVpnPlugInReconnectTransport_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ReconnectTransport<Identity: windows_core::IUnknownImpl + IVpnPlugInReconnectTransport_Impl>(this: *mut core::ffi::c_void, channel: *mut core::ffi::c_void, context: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVpnPlugInReconnectTransport_Impl::ReconnectTransport(this, core::mem::transmute_copy(&channel), core::mem::transmute_copy(&context)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ReconnectTransport: ReconnectTransport::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IVpnPlugInReconnectTransport_Vtbl {
@@ -1551,6 +1905,130 @@ impl IVpnProfile_Vtbl {
         iid == &<IVpnProfile as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVpnProfile_Impl> windows_core::imp::TearOffVtable<Identity> for IVpnProfile_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ProfileName<Identity: windows_core::IUnknownImpl + IVpnProfile_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVpnProfile_Impl::ProfileName(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProfileName<Identity: windows_core::IUnknownImpl + IVpnProfile_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVpnProfile_Impl::SetProfileName(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn AppTriggers<Identity: windows_core::IUnknownImpl + IVpnProfile_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVpnProfile_Impl::AppTriggers(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Routes<Identity: windows_core::IUnknownImpl + IVpnProfile_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVpnProfile_Impl::Routes(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DomainNameInfoList<Identity: windows_core::IUnknownImpl + IVpnProfile_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVpnProfile_Impl::DomainNameInfoList(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn TrafficFilters<Identity: windows_core::IUnknownImpl + IVpnProfile_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVpnProfile_Impl::TrafficFilters(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RememberCredentials<Identity: windows_core::IUnknownImpl + IVpnProfile_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVpnProfile_Impl::RememberCredentials(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRememberCredentials<Identity: windows_core::IUnknownImpl + IVpnProfile_Impl>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVpnProfile_Impl::SetRememberCredentials(this, value).into()
+            }
+        }
+        unsafe extern "system" fn AlwaysOn<Identity: windows_core::IUnknownImpl + IVpnProfile_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVpnProfile_Impl::AlwaysOn(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAlwaysOn<Identity: windows_core::IUnknownImpl + IVpnProfile_Impl>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVpnProfile_Impl::SetAlwaysOn(this, value).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ProfileName: ProfileName::<Identity>,
+            SetProfileName: SetProfileName::<Identity>,
+            AppTriggers: AppTriggers::<Identity>,
+            Routes: Routes::<Identity>,
+            DomainNameInfoList: DomainNameInfoList::<Identity>,
+            TrafficFilters: TrafficFilters::<Identity>,
+            RememberCredentials: RememberCredentials::<Identity>,
+            SetRememberCredentials: SetRememberCredentials::<Identity>,
+            AlwaysOn: AlwaysOn::<Identity>,
+            SetAlwaysOn: SetAlwaysOn::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IVpnProfile_Vtbl {
@@ -1642,6 +2120,24 @@ impl IVpnRouteFactory_Vtbl {
         iid == &<IVpnRouteFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVpnRouteFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IVpnRouteFactory_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateVpnRoute<Identity: windows_core::IUnknownImpl + IVpnRouteFactory_Impl>(this: *mut core::ffi::c_void, address: *mut core::ffi::c_void, prefixsize: u8, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVpnRouteFactory_Impl::CreateVpnRoute(this, core::mem::transmute_copy(&address), prefixsize) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateVpnRoute: CreateVpnRoute::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IVpnRouteFactory_Vtbl {
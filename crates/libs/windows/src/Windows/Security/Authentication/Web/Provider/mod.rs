@@ -207,6 +207,28 @@ impl IWebAccountProviderBaseReportOperation_Vtbl {
         iid == &<IWebAccountProviderBaseReportOperation as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Security_Authentication_Web_Core")]
+impl<Identity: windows_core::IUnknownImpl + IWebAccountProviderBaseReportOperation_Impl> windows_core::imp::TearOffVtable<Identity> for IWebAccountProviderBaseReportOperation_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ReportCompleted<Identity: windows_core::IUnknownImpl + IWebAccountProviderBaseReportOperation_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebAccountProviderBaseReportOperation_Impl::ReportCompleted(this).into()
+            }
+        }
+        unsafe extern "system" fn ReportError<Identity: windows_core::IUnknownImpl + IWebAccountProviderBaseReportOperation_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebAccountProviderBaseReportOperation_Impl::ReportError(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ReportCompleted: ReportCompleted::<Identity>,
+            ReportError: ReportError::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IWebAccountProviderBaseReportOperation_Vtbl {
@@ -284,6 +306,23 @@ impl IWebAccountProviderOperation_Vtbl {
         iid == &<IWebAccountProviderOperation as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWebAccountProviderOperation_Impl> windows_core::imp::TearOffVtable<Identity> for IWebAccountProviderOperation_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Kind<Identity: windows_core::IUnknownImpl + IWebAccountProviderOperation_Impl>(this: *mut core::ffi::c_void, result__: *mut WebAccountProviderOperationKind) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebAccountProviderOperation_Impl::Kind(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Kind: Kind::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IWebAccountProviderOperation_Vtbl {
@@ -388,6 +427,28 @@ impl IWebAccountProviderSilentReportOperation_Vtbl {
         iid == &<IWebAccountProviderSilentReportOperation as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Security_Authentication_Web_Core")]
+impl<Identity: windows_core::IUnknownImpl + IWebAccountProviderSilentReportOperation_Impl> windows_core::imp::TearOffVtable<Identity> for IWebAccountProviderSilentReportOperation_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ReportUserInteractionRequired<Identity: windows_core::IUnknownImpl + IWebAccountProviderSilentReportOperation_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebAccountProviderSilentReportOperation_Impl::ReportUserInteractionRequired(this).into()
+            }
+        }
+        unsafe extern "system" fn ReportUserInteractionRequiredWithError<Identity: windows_core::IUnknownImpl + IWebAccountProviderSilentReportOperation_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebAccountProviderSilentReportOperation_Impl::ReportUserInteractionRequiredWithError(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ReportUserInteractionRequired: ReportUserInteractionRequired::<Identity>,
+            ReportUserInteractionRequiredWithError: ReportUserInteractionRequiredWithError::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IWebAccountProviderSilentReportOperation_Vtbl {
@@ -439,6 +500,24 @@ impl IWebAccountProviderTokenObjects_Vtbl {
         iid == &<IWebAccountProviderTokenObjects as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWebAccountProviderTokenObjects_Impl> windows_core::imp::TearOffVtable<Identity> for IWebAccountProviderTokenObjects_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Operation<Identity: windows_core::IUnknownImpl + IWebAccountProviderTokenObjects_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebAccountProviderTokenObjects_Impl::Operation(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Operation: Operation::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IWebAccountProviderTokenObjects_Vtbl {
@@ -498,6 +577,25 @@ impl IWebAccountProviderTokenObjects2_Vtbl {
         iid == &<IWebAccountProviderTokenObjects2 as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "System")]
+impl<Identity: windows_core::IUnknownImpl + IWebAccountProviderTokenObjects2_Impl> windows_core::imp::TearOffVtable<Identity> for IWebAccountProviderTokenObjects2_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn User<Identity: windows_core::IUnknownImpl + IWebAccountProviderTokenObjects2_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebAccountProviderTokenObjects2_Impl::User(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, User: User::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IWebAccountProviderTokenObjects2_Vtbl {
@@ -614,6 +712,61 @@ impl IWebAccountProviderTokenOperation_Vtbl {
         iid == &<IWebAccountProviderTokenOperation as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWebAccountProviderTokenOperation_Impl> windows_core::imp::TearOffVtable<Identity> for IWebAccountProviderTokenOperation_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ProviderRequest<Identity: windows_core::IUnknownImpl + IWebAccountProviderTokenOperation_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebAccountProviderTokenOperation_Impl::ProviderRequest(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ProviderResponses<Identity: windows_core::IUnknownImpl + IWebAccountProviderTokenOperation_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebAccountProviderTokenOperation_Impl::ProviderResponses(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCacheExpirationTime<Identity: windows_core::IUnknownImpl + IWebAccountProviderTokenOperation_Impl>(this: *mut core::ffi::c_void, value: super::super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebAccountProviderTokenOperation_Impl::SetCacheExpirationTime(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn CacheExpirationTime<Identity: windows_core::IUnknownImpl + IWebAccountProviderTokenOperation_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebAccountProviderTokenOperation_Impl::CacheExpirationTime(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ProviderRequest: ProviderRequest::<Identity>,
+            ProviderResponses: ProviderResponses::<Identity>,
+            SetCacheExpirationTime: SetCacheExpirationTime::<Identity>,
+            CacheExpirationTime: CacheExpirationTime::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IWebAccountProviderTokenOperation_Vtbl {
@@ -673,6 +826,21 @@ impl IWebAccountProviderUIReportOperation_Vtbl {
         iid == &<IWebAccountProviderUIReportOperation as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Security_Authentication_Web_Core")]
+impl<Identity: windows_core::IUnknownImpl + IWebAccountProviderUIReportOperation_Impl> windows_core::imp::TearOffVtable<Identity> for IWebAccountProviderUIReportOperation_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ReportUserCanceled<Identity: windows_core::IUnknownImpl + IWebAccountProviderUIReportOperation_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebAccountProviderUIReportOperation_Impl::ReportUserCanceled(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ReportUserCanceled: ReportUserCanceled::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IWebAccountProviderUIReportOperation_Vtbl {
@@ -1271,6 +1271,138 @@ impl IStorageItemInformation_Vtbl {
         iid == &<IStorageItemInformation as windows_core::Interface>::IID
     }
 }
+#[cfg(all(feature = "Storage_FileProperties", feature = "Storage_Streams"))]
+impl<Identity: windows_core::IUnknownImpl + IStorageItemInformation_Impl> windows_core::imp::TearOffVtable<Identity> for IStorageItemInformation_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn MusicProperties<Identity: windows_core::IUnknownImpl + IStorageItemInformation_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageItemInformation_Impl::MusicProperties(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn VideoProperties<Identity: windows_core::IUnknownImpl + IStorageItemInformation_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageItemInformation_Impl::VideoProperties(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ImageProperties<Identity: windows_core::IUnknownImpl + IStorageItemInformation_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageItemInformation_Impl::ImageProperties(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DocumentProperties<Identity: windows_core::IUnknownImpl + IStorageItemInformation_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageItemInformation_Impl::DocumentProperties(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn BasicProperties<Identity: windows_core::IUnknownImpl + IStorageItemInformation_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageItemInformation_Impl::BasicProperties(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Thumbnail<Identity: windows_core::IUnknownImpl + IStorageItemInformation_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageItemInformation_Impl::Thumbnail(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ThumbnailUpdated<Identity: windows_core::IUnknownImpl + IStorageItemInformation_Impl>(this: *mut core::ffi::c_void, changedhandler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageItemInformation_Impl::ThumbnailUpdated(this, core::mem::transmute_copy(&changedhandler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveThumbnailUpdated<Identity: windows_core::IUnknownImpl + IStorageItemInformation_Impl>(this: *mut core::ffi::c_void, eventcookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IStorageItemInformation_Impl::RemoveThumbnailUpdated(this, eventcookie).into()
+            }
+        }
+        unsafe extern "system" fn PropertiesUpdated<Identity: windows_core::IUnknownImpl + IStorageItemInformation_Impl>(this: *mut core::ffi::c_void, changedhandler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageItemInformation_Impl::PropertiesUpdated(this, core::mem::transmute_copy(&changedhandler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemovePropertiesUpdated<Identity: windows_core::IUnknownImpl + IStorageItemInformation_Impl>(this: *mut core::ffi::c_void, eventcookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IStorageItemInformation_Impl::RemovePropertiesUpdated(this, eventcookie).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MusicProperties: MusicProperties::<Identity>,
+            VideoProperties: VideoProperties::<Identity>,
+            ImageProperties: ImageProperties::<Identity>,
+            DocumentProperties: DocumentProperties::<Identity>,
+            BasicProperties: BasicProperties::<Identity>,
+            Thumbnail: Thumbnail::<Identity>,
+            ThumbnailUpdated: ThumbnailUpdated::<Identity>,
+            RemoveThumbnailUpdated: RemoveThumbnailUpdated::<Identity>,
+            PropertiesUpdated: PropertiesUpdated::<Identity>,
+            RemovePropertiesUpdated: RemovePropertiesUpdated::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IStorageItemInformation_Vtbl {
@@ -511,6 +511,27 @@ impl IStorageProviderItemPropertySource_Vtbl {
         iid == &<IStorageProviderItemPropertySource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IStorageProviderItemPropertySource_Impl> windows_core::imp::TearOffVtable<Identity> for IStorageProviderItemPropertySource_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetItemProperties<Identity: windows_core::IUnknownImpl + IStorageProviderItemPropertySource_Impl>(this: *mut core::ffi::c_void, itempath: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderItemPropertySource_Impl::GetItemProperties(this, core::mem::transmute(&itempath)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetItemProperties: GetItemProperties::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IStorageProviderItemPropertySource_Vtbl {
@@ -624,6 +645,47 @@ impl IStorageProviderKnownFolderSyncInfoSource_Vtbl {
         iid == &<IStorageProviderKnownFolderSyncInfoSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IStorageProviderKnownFolderSyncInfoSource_Impl> windows_core::imp::TearOffVtable<Identity> for IStorageProviderKnownFolderSyncInfoSource_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetKnownFolderSyncInfo<Identity: windows_core::IUnknownImpl + IStorageProviderKnownFolderSyncInfoSource_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderKnownFolderSyncInfoSource_Impl::GetKnownFolderSyncInfo(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn KnownFolderSyncInfoChanged<Identity: windows_core::IUnknownImpl + IStorageProviderKnownFolderSyncInfoSource_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderKnownFolderSyncInfoSource_Impl::KnownFolderSyncInfoChanged(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveKnownFolderSyncInfoChanged<Identity: windows_core::IUnknownImpl + IStorageProviderKnownFolderSyncInfoSource_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IStorageProviderKnownFolderSyncInfoSource_Impl::RemoveKnownFolderSyncInfoChanged(this, token).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetKnownFolderSyncInfo: GetKnownFolderSyncInfo::<Identity>,
+            KnownFolderSyncInfoChanged: KnownFolderSyncInfoChanged::<Identity>,
+            RemoveKnownFolderSyncInfoChanged: RemoveKnownFolderSyncInfoChanged::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IStorageProviderKnownFolderSyncInfoSource_Vtbl {
@@ -676,6 +738,27 @@ impl IStorageProviderKnownFolderSyncInfoSourceFactory_Vtbl {
         iid == &<IStorageProviderKnownFolderSyncInfoSourceFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IStorageProviderKnownFolderSyncInfoSourceFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IStorageProviderKnownFolderSyncInfoSourceFactory_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetKnownFolderSyncInfoSource<Identity: windows_core::IUnknownImpl + IStorageProviderKnownFolderSyncInfoSourceFactory_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderKnownFolderSyncInfoSourceFactory_Impl::GetKnownFolderSyncInfoSource(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetKnownFolderSyncInfoSource: GetKnownFolderSyncInfoSource::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IStorageProviderKnownFolderSyncInfoSourceFactory_Vtbl {
@@ -752,6 +835,26 @@ impl IStorageProviderPropertyCapabilities_Vtbl {
         iid == &<IStorageProviderPropertyCapabilities as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IStorageProviderPropertyCapabilities_Impl> windows_core::imp::TearOffVtable<Identity> for IStorageProviderPropertyCapabilities_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsPropertySupported<Identity: windows_core::IUnknownImpl + IStorageProviderPropertyCapabilities_Impl>(this: *mut core::ffi::c_void, propertycanonicalname: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderPropertyCapabilities_Impl::IsPropertySupported(this, core::mem::transmute(&propertycanonicalname)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsPropertySupported: IsPropertySupported::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IStorageProviderPropertyCapabilities_Vtbl {
@@ -941,6 +1044,111 @@ impl IStorageProviderQueryResult_Vtbl {
         iid == &<IStorageProviderQueryResult as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Foundation_Collections")]
+impl<Identity: windows_core::IUnknownImpl + IStorageProviderQueryResult_Impl> windows_core::imp::TearOffVtable<Identity> for IStorageProviderQueryResult_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Kind<Identity: windows_core::IUnknownImpl + IStorageProviderQueryResult_Impl>(this: *mut core::ffi::c_void, result__: *mut StorageProviderResultKind) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderQueryResult_Impl::Kind(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetKind<Identity: windows_core::IUnknownImpl + IStorageProviderQueryResult_Impl>(this: *mut core::ffi::c_void, value: StorageProviderResultKind) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IStorageProviderQueryResult_Impl::SetKind(this, value).into()
+            }
+        }
+        unsafe extern "system" fn ResultId<Identity: windows_core::IUnknownImpl + IStorageProviderQueryResult_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderQueryResult_Impl::ResultId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetResultId<Identity: windows_core::IUnknownImpl + IStorageProviderQueryResult_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IStorageProviderQueryResult_Impl::SetResultId(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn RemoteFileId<Identity: windows_core::IUnknownImpl + IStorageProviderQueryResult_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderQueryResult_Impl::RemoteFileId(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRemoteFileId<Identity: windows_core::IUnknownImpl + IStorageProviderQueryResult_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IStorageProviderQueryResult_Impl::SetRemoteFileId(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn FilePath<Identity: windows_core::IUnknownImpl + IStorageProviderQueryResult_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderQueryResult_Impl::FilePath(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetFilePath<Identity: windows_core::IUnknownImpl + IStorageProviderQueryResult_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IStorageProviderQueryResult_Impl::SetFilePath(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn RequestedProperties<Identity: windows_core::IUnknownImpl + IStorageProviderQueryResult_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderQueryResult_Impl::RequestedProperties(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Kind: Kind::<Identity>,
+            SetKind: SetKind::<Identity>,
+            ResultId: ResultId::<Identity>,
+            SetResultId: SetResultId::<Identity>,
+            RemoteFileId: RemoteFileId::<Identity>,
+            SetRemoteFileId: SetRemoteFileId::<Identity>,
+            FilePath: FilePath::<Identity>,
+            SetFilePath: SetFilePath::<Identity>,
+            RequestedProperties: RequestedProperties::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IStorageProviderQueryResult_Vtbl {
@@ -1064,6 +1272,34 @@ impl IStorageProviderSearchHandler_Vtbl {
         iid == &<IStorageProviderSearchHandler as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IStorageProviderSearchHandler_Impl> windows_core::imp::TearOffVtable<Identity> for IStorageProviderSearchHandler_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Find<Identity: windows_core::IUnknownImpl + IStorageProviderSearchHandler_Impl>(this: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderSearchHandler_Impl::Find(this, core::mem::transmute_copy(&options)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReportUsage<Identity: windows_core::IUnknownImpl + IStorageProviderSearchHandler_Impl>(this: *mut core::ffi::c_void, resultusagekind: StorageProviderResultUsageKind, remotefileid: *mut core::ffi::c_void, resultid: *mut core::ffi::c_void, latency: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IStorageProviderSearchHandler_Impl::ReportUsage(this, resultusagekind, core::mem::transmute(&remotefileid), core::mem::transmute(&resultid), core::mem::transmute(&latency)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Find: Find::<Identity>,
+            ReportUsage: ReportUsage::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IStorageProviderSearchHandler_Vtbl {
@@ -1115,6 +1351,27 @@ impl IStorageProviderSearchHandlerFactory_Vtbl {
         iid == &<IStorageProviderSearchHandlerFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IStorageProviderSearchHandlerFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IStorageProviderSearchHandlerFactory_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateSearchHandler<Identity: windows_core::IUnknownImpl + IStorageProviderSearchHandlerFactory_Impl>(this: *mut core::ffi::c_void, cloudproviderid: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderSearchHandlerFactory_Impl::CreateSearchHandler(this, core::mem::transmute(&cloudproviderid)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateSearchHandler: CreateSearchHandler::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IStorageProviderSearchHandlerFactory_Vtbl {
@@ -1253,6 +1510,55 @@ impl IStorageProviderShareLinkSource_Vtbl {
         iid == &<IStorageProviderShareLinkSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IStorageProviderShareLinkSource_Impl> windows_core::imp::TearOffVtable<Identity> for IStorageProviderShareLinkSource_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateLinkAsync<Identity: windows_core::IUnknownImpl + IStorageProviderShareLinkSource_Impl>(this: *mut core::ffi::c_void, storageitemlist: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderShareLinkSource_Impl::CreateLinkAsync(this, core::mem::transmute_copy(&storageitemlist)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDefaultAccessControlStringAsync<Identity: windows_core::IUnknownImpl + IStorageProviderShareLinkSource_Impl>(this: *mut core::ffi::c_void, storageitemlist: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderShareLinkSource_Impl::GetDefaultAccessControlStringAsync(this, core::mem::transmute_copy(&storageitemlist)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetState<Identity: windows_core::IUnknownImpl + IStorageProviderShareLinkSource_Impl>(this: *mut core::ffi::c_void, storageitemlist: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderShareLinkSource_Impl::GetState(this, core::mem::transmute_copy(&storageitemlist)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateLinkAsync: CreateLinkAsync::<Identity>,
+            GetDefaultAccessControlStringAsync: GetDefaultAccessControlStringAsync::<Identity>,
+            GetState: GetState::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IStorageProviderShareLinkSource_Vtbl {
@@ -1366,6 +1672,47 @@ impl IStorageProviderStatusUISource_Vtbl {
         iid == &<IStorageProviderStatusUISource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IStorageProviderStatusUISource_Impl> windows_core::imp::TearOffVtable<Identity> for IStorageProviderStatusUISource_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStatusUI<Identity: windows_core::IUnknownImpl + IStorageProviderStatusUISource_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderStatusUISource_Impl::GetStatusUI(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StatusUIChanged<Identity: windows_core::IUnknownImpl + IStorageProviderStatusUISource_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderStatusUISource_Impl::StatusUIChanged(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveStatusUIChanged<Identity: windows_core::IUnknownImpl + IStorageProviderStatusUISource_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IStorageProviderStatusUISource_Impl::RemoveStatusUIChanged(this, token).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStatusUI: GetStatusUI::<Identity>,
+            StatusUIChanged: StatusUIChanged::<Identity>,
+            RemoveStatusUIChanged: RemoveStatusUIChanged::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IStorageProviderStatusUISource_Vtbl {
@@ -1418,6 +1765,27 @@ impl IStorageProviderStatusUISourceFactory_Vtbl {
         iid == &<IStorageProviderStatusUISourceFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IStorageProviderStatusUISourceFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IStorageProviderStatusUISourceFactory_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStatusUISource<Identity: windows_core::IUnknownImpl + IStorageProviderStatusUISourceFactory_Impl>(this: *mut core::ffi::c_void, syncrootid: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderStatusUISourceFactory_Impl::GetStatusUISource(this, core::mem::transmute(&syncrootid)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStatusUISource: GetStatusUISource::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IStorageProviderStatusUISourceFactory_Vtbl {
@@ -1529,6 +1897,62 @@ impl IStorageProviderSuggestionsHandler_Vtbl {
         iid == &<IStorageProviderSuggestionsHandler as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IStorageProviderSuggestionsHandler_Impl> windows_core::imp::TearOffVtable<Identity> for IStorageProviderSuggestionsHandler_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSuggestions<Identity: windows_core::IUnknownImpl + IStorageProviderSuggestionsHandler_Impl>(this: *mut core::ffi::c_void, options: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderSuggestionsHandler_Impl::GetSuggestions(this, core::mem::transmute_copy(&options)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Add<Identity: windows_core::IUnknownImpl + IStorageProviderSuggestionsHandler_Impl>(this: *mut core::ffi::c_void, kind: StorageProviderResultKind, remotefileid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IStorageProviderSuggestionsHandler_Impl::Add(this, kind, core::mem::transmute(&remotefileid)).into()
+            }
+        }
+        unsafe extern "system" fn Remove<Identity: windows_core::IUnknownImpl + IStorageProviderSuggestionsHandler_Impl>(this: *mut core::ffi::c_void, kind: StorageProviderResultKind, remotefileid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IStorageProviderSuggestionsHandler_Impl::Remove(this, kind, core::mem::transmute(&remotefileid)).into()
+            }
+        }
+        unsafe extern "system" fn GetDetails<Identity: windows_core::IUnknownImpl + IStorageProviderSuggestionsHandler_Impl>(this: *mut core::ffi::c_void, remotefileid: *mut core::ffi::c_void, propertiestofetch_array_size: u32, propertiestofetch: *const windows_core::HSTRING, queryid: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderSuggestionsHandler_Impl::GetDetails(this, core::mem::transmute(&remotefileid), core::slice::from_raw_parts(core::mem::transmute_copy(&propertiestofetch), propertiestofetch_array_size as usize), core::mem::transmute(&queryid)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReportUsage<Identity: windows_core::IUnknownImpl + IStorageProviderSuggestionsHandler_Impl>(this: *mut core::ffi::c_void, resultusagekind: StorageProviderResultUsageKind, remotefileid: *mut core::ffi::c_void, resultid: *mut core::ffi::c_void, latency: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IStorageProviderSuggestionsHandler_Impl::ReportUsage(this, resultusagekind, core::mem::transmute(&remotefileid), core::mem::transmute(&resultid), core::mem::transmute(&latency)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSuggestions: GetSuggestions::<Identity>,
+            Add: Add::<Identity>,
+            Remove: Remove::<Identity>,
+            GetDetails: GetDetails::<Identity>,
+            ReportUsage: ReportUsage::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IStorageProviderSuggestionsHandler_Vtbl {
@@ -1583,6 +2007,27 @@ impl IStorageProviderSuggestionsHandlerFactory_Vtbl {
         iid == &<IStorageProviderSuggestionsHandlerFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IStorageProviderSuggestionsHandlerFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IStorageProviderSuggestionsHandlerFactory_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateSuggestionsHandler<Identity: windows_core::IUnknownImpl + IStorageProviderSuggestionsHandlerFactory_Impl>(this: *mut core::ffi::c_void, cloudproviderid: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderSuggestionsHandlerFactory_Impl::CreateSuggestionsHandler(this, core::mem::transmute(&cloudproviderid)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateSuggestionsHandler: CreateSuggestionsHandler::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IStorageProviderSuggestionsHandlerFactory_Vtbl {
@@ -1815,6 +2260,75 @@ impl IStorageProviderUICommand_Vtbl {
         iid == &<IStorageProviderUICommand as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IStorageProviderUICommand_Impl> windows_core::imp::TearOffVtable<Identity> for IStorageProviderUICommand_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Label<Identity: windows_core::IUnknownImpl + IStorageProviderUICommand_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderUICommand_Impl::Label(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Description<Identity: windows_core::IUnknownImpl + IStorageProviderUICommand_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderUICommand_Impl::Description(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Icon<Identity: windows_core::IUnknownImpl + IStorageProviderUICommand_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderUICommand_Impl::Icon(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn State<Identity: windows_core::IUnknownImpl + IStorageProviderUICommand_Impl>(this: *mut core::ffi::c_void, result__: *mut StorageProviderUICommandState) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IStorageProviderUICommand_Impl::State(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Invoke<Identity: windows_core::IUnknownImpl + IStorageProviderUICommand_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IStorageProviderUICommand_Impl::Invoke(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Label: Label::<Identity>,
+            Description: Description::<Identity>,
+            Icon: Icon::<Identity>,
+            State: State::<Identity>,
+            Invoke: Invoke::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IStorageProviderUICommand_Vtbl {
@@ -1877,6 +2391,27 @@ impl IStorageProviderUriSource_Vtbl {
         iid == &<IStorageProviderUriSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IStorageProviderUriSource_Impl> windows_core::imp::TearOffVtable<Identity> for IStorageProviderUriSource_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPathForContentUri<Identity: windows_core::IUnknownImpl + IStorageProviderUriSource_Impl>(this: *mut core::ffi::c_void, contenturi: *mut core::ffi::c_void, result: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IStorageProviderUriSource_Impl::GetPathForContentUri(this, core::mem::transmute(&contenturi), core::mem::transmute_copy(&result)).into()
+            }
+        }
+        unsafe extern "system" fn GetContentInfoForPath<Identity: windows_core::IUnknownImpl + IStorageProviderUriSource_Impl>(this: *mut core::ffi::c_void, path: *mut core::ffi::c_void, result: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IStorageProviderUriSource_Impl::GetContentInfoForPath(this, core::mem::transmute(&path), core::mem::transmute_copy(&result)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPathForContentUri: GetPathForContentUri::<Identity>,
+            GetContentInfoForPath: GetContentInfoForPath::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IStorageProviderUriSource_Vtbl {
@@ -824,6 +824,46 @@ impl IBuffer_Vtbl {
         iid == &<IBuffer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBuffer_Impl> windows_core::imp::TearOffVtable<Identity> for IBuffer_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Capacity<Identity: windows_core::IUnknownImpl + IBuffer_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBuffer_Impl::Capacity(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Length<Identity: windows_core::IUnknownImpl + IBuffer_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBuffer_Impl::Length(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLength<Identity: windows_core::IUnknownImpl + IBuffer_Impl>(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBuffer_Impl::SetLength(this, value).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Capacity: Capacity::<Identity>,
+            Length: Length::<Identity>,
+            SetLength: SetLength::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IBuffer_Vtbl {
@@ -894,6 +934,24 @@ impl IContentTypeProvider_Vtbl {
         iid == &<IContentTypeProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IContentTypeProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IContentTypeProvider_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ContentType<Identity: windows_core::IUnknownImpl + IContentTypeProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContentTypeProvider_Impl::ContentType(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ContentType: ContentType::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IContentTypeProvider_Vtbl {
@@ -1437,187 +1495,513 @@ impl IDataReader_Vtbl {
         iid == &<IDataReader as windows_core::Interface>::IID
     }
 }
-#[repr(C)]
-#[doc(hidden)]
-pub struct IDataReader_Vtbl {
-    pub base__: windows_core::IInspectable_Vtbl,
-    pub UnconsumedBufferLength: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
-    pub UnicodeEncoding: unsafe extern "system" fn(*mut core::ffi::c_void, *mut UnicodeEncoding) -> windows_core::HRESULT,
-    pub SetUnicodeEncoding: unsafe extern "system" fn(*mut core::ffi::c_void, UnicodeEncoding) -> windows_core::HRESULT,
-    pub ByteOrder: unsafe extern "system" fn(*mut core::ffi::c_void, *mut ByteOrder) -> windows_core::HRESULT,
-    pub SetByteOrder: unsafe extern "system" fn(*mut core::ffi::c_void, ByteOrder) -> windows_core::HRESULT,
-    pub InputStreamOptions: unsafe extern "system" fn(*mut core::ffi::c_void, *mut InputStreamOptions) -> windows_core::HRESULT,
-    pub SetInputStreamOptions: unsafe extern "system" fn(*mut core::ffi::c_void, InputStreamOptions) -> windows_core::HRESULT,
-    pub ReadByte: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u8) -> windows_core::HRESULT,
-    pub ReadBytes: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *mut u8) -> windows_core::HRESULT,
-    pub ReadBuffer: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub ReadBoolean: unsafe extern "system" fn(*mut core::ffi::c_void, *mut bool) -> windows_core::HRESULT,
-    pub ReadGuid: unsafe extern "system" fn(*mut core::ffi::c_void, *mut windows_core::GUID) -> windows_core::HRESULT,
-    pub ReadInt16: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i16) -> windows_core::HRESULT,
-    pub ReadInt32: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
-    pub ReadInt64: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i64) -> windows_core::HRESULT,
-    pub ReadUInt16: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u16) -> windows_core::HRESULT,
-    pub ReadUInt32: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
-    pub ReadUInt64: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u64) -> windows_core::HRESULT,
-    pub ReadSingle: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
-    pub ReadDouble: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f64) -> windows_core::HRESULT,
-    pub ReadString: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub ReadDateTime: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::Foundation::DateTime) -> windows_core::HRESULT,
-    pub ReadTimeSpan: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT,
-    pub LoadAsync: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub DetachBuffer: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub DetachStream: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-windows_core::imp::define_interface!(IDataReaderFactory, IDataReaderFactory_Vtbl, 0xd7527847_57da_4e15_914c_06806699a098);
-impl windows_core::RuntimeType for IDataReaderFactory {
-    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IDataReaderFactory_Vtbl {
-    pub base__: windows_core::IInspectable_Vtbl,
-    pub CreateDataReader: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-windows_core::imp::define_interface!(IDataReaderStatics, IDataReaderStatics_Vtbl, 0x11fcbfc8_f93a_471b_b121_f379e349313c);
-impl windows_core::RuntimeType for IDataReaderStatics {
-    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IDataReaderStatics_Vtbl {
-    pub base__: windows_core::IInspectable_Vtbl,
-    pub FromBuffer: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-windows_core::imp::define_interface!(IDataWriter, IDataWriter_Vtbl, 0x64b89265_d341_4922_b38a_dd4af8808c4e);
-impl windows_core::RuntimeType for IDataWriter {
-    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
-}
-windows_core::imp::interface_hierarchy!(IDataWriter, windows_core::IUnknown, windows_core::IInspectable);
-impl IDataWriter {
-    pub fn UnstoredBufferLength(&self) -> windows_core::Result<u32> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).UnstoredBufferLength)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+impl<Identity: windows_core::IUnknownImpl + IDataReader_Impl> windows_core::imp::TearOffVtable<Identity> for IDataReader_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn UnconsumedBufferLength<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDataReader_Impl::UnconsumedBufferLength(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub fn UnicodeEncoding(&self) -> windows_core::Result<UnicodeEncoding> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).UnicodeEncoding)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        unsafe extern "system" fn UnicodeEncoding<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, result__: *mut UnicodeEncoding) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDataReader_Impl::UnicodeEncoding(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub fn SetUnicodeEncoding(&self, value: UnicodeEncoding) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetUnicodeEncoding)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn ByteOrder(&self) -> windows_core::Result<ByteOrder> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).ByteOrder)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        unsafe extern "system" fn SetUnicodeEncoding<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, value: UnicodeEncoding) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDataReader_Impl::SetUnicodeEncoding(this, value).into()
+            }
         }
-    }
-    pub fn SetByteOrder(&self, value: ByteOrder) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetByteOrder)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn WriteByte(&self, value: u8) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).WriteByte)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn WriteBytes(&self, value: &[u8]) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).WriteBytes)(windows_core::Interface::as_raw(this), value.len().try_into().unwrap(), value.as_ptr()).ok() }
-    }
-    pub fn WriteBuffer<P0>(&self, buffer: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<IBuffer>,
-    {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).WriteBuffer)(windows_core::Interface::as_raw(this), buffer.param().abi()).ok() }
-    }
-    pub fn WriteBufferRange<P0>(&self, buffer: P0, start: u32, count: u32) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<IBuffer>,
-    {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).WriteBufferRange)(windows_core::Interface::as_raw(this), buffer.param().abi(), start, count).ok() }
-    }
-    pub fn WriteBoolean(&self, value: bool) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).WriteBoolean)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn WriteGuid(&self, value: windows_core::GUID) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).WriteGuid)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn WriteInt16(&self, value: i16) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).WriteInt16)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn WriteInt32(&self, value: i32) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).WriteInt32)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn WriteInt64(&self, value: i64) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).WriteInt64)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn WriteUInt16(&self, value: u16) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).WriteUInt16)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn WriteUInt32(&self, value: u32) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).WriteUInt32)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn WriteUInt64(&self, value: u64) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).WriteUInt64)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn WriteSingle(&self, value: f32) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).WriteSingle)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn WriteDouble(&self, value: f64) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).WriteDouble)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn WriteDateTime(&self, value: super::super::Foundation::DateTime) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).WriteDateTime)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn WriteTimeSpan(&self, value: super::super::Foundation::TimeSpan) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).WriteTimeSpan)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn WriteString(&self, value: &windows_core::HSTRING) -> windows_core::Result<u32> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).WriteString)(windows_core::Interface::as_raw(this), core::mem::transmute_copy(value), &mut result__).map(|| result__)
+        unsafe extern "system" fn ByteOrder<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, result__: *mut ByteOrder) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDataReader_Impl::ByteOrder(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub fn MeasureString(&self, value: &windows_core::HSTRING) -> windows_core::Result<u32> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).MeasureString)(windows_core::Interface::as_raw(this), core::mem::transmute_copy(value), &mut result__).map(|| result__)
+        unsafe extern "system" fn SetByteOrder<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, value: ByteOrder) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDataReader_Impl::SetByteOrder(this, value).into()
+            }
         }
-    }
-    pub fn StoreAsync(&self) -> windows_core::Result<DataWriterStoreOperation> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).StoreAsync)(windows_core::Interface::as_raw(this), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn InputStreamOptions<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, result__: *mut InputStreamOptions) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDataReader_Impl::InputStreamOptions(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub fn FlushAsync(&self) -> windows_core::Result<windows_future::IAsyncOperation<bool>> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
+        unsafe extern "system" fn SetInputStreamOptions<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, value: InputStreamOptions) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDataReader_Impl::SetInputStreamOptions(this, value).into()
+            }
+        }
+        unsafe extern "system" fn ReadByte<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, result__: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDataReader_Impl::ReadByte(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReadBytes<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, value_array_size: u32, value: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDataReader_Impl::ReadBytes(this, core::slice::from_raw_parts_mut(core::mem::transmute_copy(&value), value_array_size as usize)).into()
+            }
+        }
+        unsafe extern "system" fn ReadBuffer<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, length: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDataReader_Impl::ReadBuffer(this, length) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReadBoolean<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDataReader_Impl::ReadBoolean(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReadGuid<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDataReader_Impl::ReadGuid(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReadInt16<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, result__: *mut i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDataReader_Impl::ReadInt16(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReadInt32<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDataReader_Impl::ReadInt32(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReadInt64<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDataReader_Impl::ReadInt64(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReadUInt16<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDataReader_Impl::ReadUInt16(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReadUInt32<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDataReader_Impl::ReadUInt32(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReadUInt64<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDataReader_Impl::ReadUInt64(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReadSingle<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDataReader_Impl::ReadSingle(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReadDouble<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, result__: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDataReader_Impl::ReadDouble(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReadString<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, codeunitcount: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDataReader_Impl::ReadString(this, codeunitcount) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReadDateTime<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDataReader_Impl::ReadDateTime(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReadTimeSpan<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDataReader_Impl::ReadTimeSpan(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LoadAsync<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, count: u32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDataReader_Impl::LoadAsync(this, count) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DetachBuffer<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDataReader_Impl::DetachBuffer(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DetachStream<Identity: windows_core::IUnknownImpl + IDataReader_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDataReader_Impl::DetachStream(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            UnconsumedBufferLength: UnconsumedBufferLength::<Identity>,
+            UnicodeEncoding: UnicodeEncoding::<Identity>,
+            SetUnicodeEncoding: SetUnicodeEncoding::<Identity>,
+            ByteOrder: ByteOrder::<Identity>,
+            SetByteOrder: SetByteOrder::<Identity>,
+            InputStreamOptions: InputStreamOptions::<Identity>,
+            SetInputStreamOptions: SetInputStreamOptions::<Identity>,
+            ReadByte: ReadByte::<Identity>,
+            ReadBytes: ReadBytes::<Identity>,
+            ReadBuffer: ReadBuffer::<Identity>,
+            ReadBoolean: ReadBoolean::<Identity>,
+            ReadGuid: ReadGuid::<Identity>,
+            ReadInt16: ReadInt16::<Identity>,
+            ReadInt32: ReadInt32::<Identity>,
+            ReadInt64: ReadInt64::<Identity>,
+            ReadUInt16: ReadUInt16::<Identity>,
+            ReadUInt32: ReadUInt32::<Identity>,
+            ReadUInt64: ReadUInt64::<Identity>,
+            ReadSingle: ReadSingle::<Identity>,
+            ReadDouble: ReadDouble::<Identity>,
+            ReadString: ReadString::<Identity>,
+            ReadDateTime: ReadDateTime::<Identity>,
+            ReadTimeSpan: ReadTimeSpan::<Identity>,
+            LoadAsync: LoadAsync::<Identity>,
+            DetachBuffer: DetachBuffer::<Identity>,
+            DetachStream: DetachStream::<Identity>,
+        }
+    };
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IDataReader_Vtbl {
+    pub base__: windows_core::IInspectable_Vtbl,
+    pub UnconsumedBufferLength: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
+    pub UnicodeEncoding: unsafe extern "system" fn(*mut core::ffi::c_void, *mut UnicodeEncoding) -> windows_core::HRESULT,
+    pub SetUnicodeEncoding: unsafe extern "system" fn(*mut core::ffi::c_void, UnicodeEncoding) -> windows_core::HRESULT,
+    pub ByteOrder: unsafe extern "system" fn(*mut core::ffi::c_void, *mut ByteOrder) -> windows_core::HRESULT,
+    pub SetByteOrder: unsafe extern "system" fn(*mut core::ffi::c_void, ByteOrder) -> windows_core::HRESULT,
+    pub InputStreamOptions: unsafe extern "system" fn(*mut core::ffi::c_void, *mut InputStreamOptions) -> windows_core::HRESULT,
+    pub SetInputStreamOptions: unsafe extern "system" fn(*mut core::ffi::c_void, InputStreamOptions) -> windows_core::HRESULT,
+    pub ReadByte: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u8) -> windows_core::HRESULT,
+    pub ReadBytes: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *mut u8) -> windows_core::HRESULT,
+    pub ReadBuffer: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub ReadBoolean: unsafe extern "system" fn(*mut core::ffi::c_void, *mut bool) -> windows_core::HRESULT,
+    pub ReadGuid: unsafe extern "system" fn(*mut core::ffi::c_void, *mut windows_core::GUID) -> windows_core::HRESULT,
+    pub ReadInt16: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i16) -> windows_core::HRESULT,
+    pub ReadInt32: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
+    pub ReadInt64: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i64) -> windows_core::HRESULT,
+    pub ReadUInt16: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u16) -> windows_core::HRESULT,
+    pub ReadUInt32: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
+    pub ReadUInt64: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u64) -> windows_core::HRESULT,
+    pub ReadSingle: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
+    pub ReadDouble: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f64) -> windows_core::HRESULT,
+    pub ReadString: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub ReadDateTime: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::Foundation::DateTime) -> windows_core::HRESULT,
+    pub ReadTimeSpan: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::Foundation::TimeSpan) -> windows_core::HRESULT,
+    pub LoadAsync: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub DetachBuffer: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub DetachStream: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+windows_core::imp::define_interface!(IDataReaderFactory, IDataReaderFactory_Vtbl, 0xd7527847_57da_4e15_914c_06806699a098);
+impl windows_core::RuntimeType for IDataReaderFactory {
+    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IDataReaderFactory_Vtbl {
+    pub base__: windows_core::IInspectable_Vtbl,
+    pub CreateDataReader: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+windows_core::imp::define_interface!(IDataReaderStatics, IDataReaderStatics_Vtbl, 0x11fcbfc8_f93a_471b_b121_f379e349313c);
+impl windows_core::RuntimeType for IDataReaderStatics {
+    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IDataReaderStatics_Vtbl {
+    pub base__: windows_core::IInspectable_Vtbl,
+    pub FromBuffer: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+windows_core::imp::define_interface!(IDataWriter, IDataWriter_Vtbl, 0x64b89265_d341_4922_b38a_dd4af8808c4e);
+impl windows_core::RuntimeType for IDataWriter {
+    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
+}
+windows_core::imp::interface_hierarchy!(IDataWriter, windows_core::IUnknown, windows_core::IInspectable);
+impl IDataWriter {
+    pub fn UnstoredBufferLength(&self) -> windows_core::Result<u32> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).UnstoredBufferLength)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        }
+    }
+    pub fn UnicodeEncoding(&self) -> windows_core::Result<UnicodeEncoding> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).UnicodeEncoding)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        }
+    }
+    pub fn SetUnicodeEncoding(&self, value: UnicodeEncoding) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetUnicodeEncoding)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn ByteOrder(&self) -> windows_core::Result<ByteOrder> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).ByteOrder)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        }
+    }
+    pub fn SetByteOrder(&self, value: ByteOrder) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetByteOrder)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn WriteByte(&self, value: u8) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).WriteByte)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn WriteBytes(&self, value: &[u8]) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).WriteBytes)(windows_core::Interface::as_raw(this), value.len().try_into().unwrap(), value.as_ptr()).ok() }
+    }
+    pub fn WriteBuffer<P0>(&self, buffer: P0) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<IBuffer>,
+    {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).WriteBuffer)(windows_core::Interface::as_raw(this), buffer.param().abi()).ok() }
+    }
+    pub fn WriteBufferRange<P0>(&self, buffer: P0, start: u32, count: u32) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<IBuffer>,
+    {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).WriteBufferRange)(windows_core::Interface::as_raw(this), buffer.param().abi(), start, count).ok() }
+    }
+    pub fn WriteBoolean(&self, value: bool) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).WriteBoolean)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn WriteGuid(&self, value: windows_core::GUID) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).WriteGuid)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn WriteInt16(&self, value: i16) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).WriteInt16)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn WriteInt32(&self, value: i32) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).WriteInt32)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn WriteInt64(&self, value: i64) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).WriteInt64)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn WriteUInt16(&self, value: u16) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).WriteUInt16)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn WriteUInt32(&self, value: u32) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).WriteUInt32)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn WriteUInt64(&self, value: u64) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).WriteUInt64)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn WriteSingle(&self, value: f32) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).WriteSingle)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn WriteDouble(&self, value: f64) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).WriteDouble)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn WriteDateTime(&self, value: super::super::Foundation::DateTime) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).WriteDateTime)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn WriteTimeSpan(&self, value: super::super::Foundation::TimeSpan) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).WriteTimeSpan)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn WriteString(&self, value: &windows_core::HSTRING) -> windows_core::Result<u32> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).WriteString)(windows_core::Interface::as_raw(this), core::mem::transmute_copy(value), &mut result__).map(|| result__)
+        }
+    }
+    pub fn MeasureString(&self, value: &windows_core::HSTRING) -> windows_core::Result<u32> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).MeasureString)(windows_core::Interface::as_raw(this), core::mem::transmute_copy(value), &mut result__).map(|| result__)
+        }
+    }
+    pub fn StoreAsync(&self) -> windows_core::Result<DataWriterStoreOperation> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).StoreAsync)(windows_core::Interface::as_raw(this), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub fn FlushAsync(&self) -> windows_core::Result<windows_future::IAsyncOperation<bool>> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
             (windows_core::Interface::vtable(this).FlushAsync)(windows_core::Interface::as_raw(this), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
     }
@@ -1668,11 +2052,268 @@ pub trait IDataWriter_Impl: windows_core::IUnknownImpl {
     fn DetachBuffer(&self) -> windows_core::Result<IBuffer>;
     fn DetachStream(&self) -> windows_core::Result<IOutputStream>;
 }
-impl IDataWriter_Vtbl {
-    pub const fn new<Identity: IDataWriter_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn UnstoredBufferLength<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+impl IDataWriter_Vtbl {
+    pub const fn new<Identity: IDataWriter_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn UnstoredBufferLength<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDataWriter_Impl::UnstoredBufferLength(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UnicodeEncoding<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut UnicodeEncoding) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDataWriter_Impl::UnicodeEncoding(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetUnicodeEncoding<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: UnicodeEncoding) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDataWriter_Impl::SetUnicodeEncoding(this, value).into()
+            }
+        }
+        unsafe extern "system" fn ByteOrder<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut ByteOrder) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDataWriter_Impl::ByteOrder(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetByteOrder<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: ByteOrder) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDataWriter_Impl::SetByteOrder(this, value).into()
+            }
+        }
+        unsafe extern "system" fn WriteByte<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDataWriter_Impl::WriteByte(this, value).into()
+            }
+        }
+        unsafe extern "system" fn WriteBytes<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value_array_size: u32, value: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDataWriter_Impl::WriteBytes(this, core::slice::from_raw_parts(core::mem::transmute_copy(&value), value_array_size as usize)).into()
+            }
+        }
+        unsafe extern "system" fn WriteBuffer<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDataWriter_Impl::WriteBuffer(this, core::mem::transmute_copy(&buffer)).into()
+            }
+        }
+        unsafe extern "system" fn WriteBufferRange<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void, start: u32, count: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDataWriter_Impl::WriteBufferRange(this, core::mem::transmute_copy(&buffer), start, count).into()
+            }
+        }
+        unsafe extern "system" fn WriteBoolean<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDataWriter_Impl::WriteBoolean(this, value).into()
+            }
+        }
+        unsafe extern "system" fn WriteGuid<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDataWriter_Impl::WriteGuid(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn WriteInt16<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDataWriter_Impl::WriteInt16(this, value).into()
+            }
+        }
+        unsafe extern "system" fn WriteInt32<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDataWriter_Impl::WriteInt32(this, value).into()
+            }
+        }
+        unsafe extern "system" fn WriteInt64<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDataWriter_Impl::WriteInt64(this, value).into()
+            }
+        }
+        unsafe extern "system" fn WriteUInt16<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDataWriter_Impl::WriteUInt16(this, value).into()
+            }
+        }
+        unsafe extern "system" fn WriteUInt32<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDataWriter_Impl::WriteUInt32(this, value).into()
+            }
+        }
+        unsafe extern "system" fn WriteUInt64<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDataWriter_Impl::WriteUInt64(this, value).into()
+            }
+        }
+        unsafe extern "system" fn WriteSingle<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDataWriter_Impl::WriteSingle(this, value).into()
+            }
+        }
+        unsafe extern "system" fn WriteDouble<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDataWriter_Impl::WriteDouble(this, value).into()
+            }
+        }
+        unsafe extern "system" fn WriteDateTime<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDataWriter_Impl::WriteDateTime(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn WriteTimeSpan<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDataWriter_Impl::WriteTimeSpan(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn WriteString<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDataWriter_Impl::WriteString(this, core::mem::transmute(&value)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MeasureString<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDataWriter_Impl::MeasureString(this, core::mem::transmute(&value)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StoreAsync<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDataWriter_Impl::StoreAsync(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FlushAsync<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDataWriter_Impl::FlushAsync(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DetachBuffer<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDataWriter_Impl::DetachBuffer(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DetachStream<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDataWriter_Impl::DetachStream(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IInspectable_Vtbl::new::<Identity, IDataWriter, OFFSET>(),
+            UnstoredBufferLength: UnstoredBufferLength::<Identity, OFFSET>,
+            UnicodeEncoding: UnicodeEncoding::<Identity, OFFSET>,
+            SetUnicodeEncoding: SetUnicodeEncoding::<Identity, OFFSET>,
+            ByteOrder: ByteOrder::<Identity, OFFSET>,
+            SetByteOrder: SetByteOrder::<Identity, OFFSET>,
+            WriteByte: WriteByte::<Identity, OFFSET>,
+            WriteBytes: WriteBytes::<Identity, OFFSET>,
+            WriteBuffer: WriteBuffer::<Identity, OFFSET>,
+            WriteBufferRange: WriteBufferRange::<Identity, OFFSET>,
+            WriteBoolean: WriteBoolean::<Identity, OFFSET>,
+            WriteGuid: WriteGuid::<Identity, OFFSET>,
+            WriteInt16: WriteInt16::<Identity, OFFSET>,
+            WriteInt32: WriteInt32::<Identity, OFFSET>,
+            WriteInt64: WriteInt64::<Identity, OFFSET>,
+            WriteUInt16: WriteUInt16::<Identity, OFFSET>,
+            WriteUInt32: WriteUInt32::<Identity, OFFSET>,
+            WriteUInt64: WriteUInt64::<Identity, OFFSET>,
+            WriteSingle: WriteSingle::<Identity, OFFSET>,
+            WriteDouble: WriteDouble::<Identity, OFFSET>,
+            WriteDateTime: WriteDateTime::<Identity, OFFSET>,
+            WriteTimeSpan: WriteTimeSpan::<Identity, OFFSET>,
+            WriteString: WriteString::<Identity, OFFSET>,
+            MeasureString: MeasureString::<Identity, OFFSET>,
+            StoreAsync: StoreAsync::<Identity, OFFSET>,
+            FlushAsync: FlushAsync::<Identity, OFFSET>,
+            DetachBuffer: DetachBuffer::<Identity, OFFSET>,
+            DetachStream: DetachStream::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataWriter as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IDataWriter_Impl> windows_core::imp::TearOffVtable<Identity> for IDataWriter_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn UnstoredBufferLength<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDataWriter_Impl::UnstoredBufferLength(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -1682,9 +2323,9 @@ impl IDataWriter_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn UnicodeEncoding<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut UnicodeEncoding) -> windows_core::HRESULT {
+        unsafe extern "system" fn UnicodeEncoding<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, result__: *mut UnicodeEncoding) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDataWriter_Impl::UnicodeEncoding(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -1694,15 +2335,15 @@ impl IDataWriter_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetUnicodeEncoding<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: UnicodeEncoding) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetUnicodeEncoding<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, value: UnicodeEncoding) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDataWriter_Impl::SetUnicodeEncoding(this, value).into()
             }
         }
-        unsafe extern "system" fn ByteOrder<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut ByteOrder) -> windows_core::HRESULT {
+        unsafe extern "system" fn ByteOrder<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, result__: *mut ByteOrder) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDataWriter_Impl::ByteOrder(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -1712,111 +2353,111 @@ impl IDataWriter_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetByteOrder<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: ByteOrder) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetByteOrder<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, value: ByteOrder) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDataWriter_Impl::SetByteOrder(this, value).into()
             }
         }
-        unsafe extern "system" fn WriteByte<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+        unsafe extern "system" fn WriteByte<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDataWriter_Impl::WriteByte(this, value).into()
             }
         }
-        unsafe extern "system" fn WriteBytes<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value_array_size: u32, value: *const u8) -> windows_core::HRESULT {
+        unsafe extern "system" fn WriteBytes<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, value_array_size: u32, value: *const u8) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDataWriter_Impl::WriteBytes(this, core::slice::from_raw_parts(core::mem::transmute_copy(&value), value_array_size as usize)).into()
             }
         }
-        unsafe extern "system" fn WriteBuffer<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn WriteBuffer<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDataWriter_Impl::WriteBuffer(this, core::mem::transmute_copy(&buffer)).into()
             }
         }
-        unsafe extern "system" fn WriteBufferRange<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void, start: u32, count: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn WriteBufferRange<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void, start: u32, count: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDataWriter_Impl::WriteBufferRange(this, core::mem::transmute_copy(&buffer), start, count).into()
             }
         }
-        unsafe extern "system" fn WriteBoolean<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+        unsafe extern "system" fn WriteBoolean<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDataWriter_Impl::WriteBoolean(this, value).into()
             }
         }
-        unsafe extern "system" fn WriteGuid<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: windows_core::GUID) -> windows_core::HRESULT {
+        unsafe extern "system" fn WriteGuid<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, value: windows_core::GUID) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDataWriter_Impl::WriteGuid(this, core::mem::transmute(&value)).into()
             }
         }
-        unsafe extern "system" fn WriteInt16<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: i16) -> windows_core::HRESULT {
+        unsafe extern "system" fn WriteInt16<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, value: i16) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDataWriter_Impl::WriteInt16(this, value).into()
             }
         }
-        unsafe extern "system" fn WriteInt32<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn WriteInt32<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDataWriter_Impl::WriteInt32(this, value).into()
             }
         }
-        unsafe extern "system" fn WriteInt64<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: i64) -> windows_core::HRESULT {
+        unsafe extern "system" fn WriteInt64<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, value: i64) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDataWriter_Impl::WriteInt64(this, value).into()
             }
         }
-        unsafe extern "system" fn WriteUInt16<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: u16) -> windows_core::HRESULT {
+        unsafe extern "system" fn WriteUInt16<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, value: u16) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDataWriter_Impl::WriteUInt16(this, value).into()
             }
         }
-        unsafe extern "system" fn WriteUInt32<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn WriteUInt32<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDataWriter_Impl::WriteUInt32(this, value).into()
             }
         }
-        unsafe extern "system" fn WriteUInt64<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: u64) -> windows_core::HRESULT {
+        unsafe extern "system" fn WriteUInt64<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, value: u64) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDataWriter_Impl::WriteUInt64(this, value).into()
             }
         }
-        unsafe extern "system" fn WriteSingle<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn WriteSingle<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDataWriter_Impl::WriteSingle(this, value).into()
             }
         }
-        unsafe extern "system" fn WriteDouble<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+        unsafe extern "system" fn WriteDouble<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDataWriter_Impl::WriteDouble(this, value).into()
             }
         }
-        unsafe extern "system" fn WriteDateTime<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
+        unsafe extern "system" fn WriteDateTime<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, value: super::super::Foundation::DateTime) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDataWriter_Impl::WriteDateTime(this, core::mem::transmute(&value)).into()
             }
         }
-        unsafe extern "system" fn WriteTimeSpan<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
+        unsafe extern "system" fn WriteTimeSpan<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, value: super::super::Foundation::TimeSpan) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDataWriter_Impl::WriteTimeSpan(this, core::mem::transmute(&value)).into()
             }
         }
-        unsafe extern "system" fn WriteString<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn WriteString<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDataWriter_Impl::WriteString(this, core::mem::transmute(&value)) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -1826,9 +2467,9 @@ impl IDataWriter_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn MeasureString<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn MeasureString<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDataWriter_Impl::MeasureString(this, core::mem::transmute(&value)) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -1838,9 +2479,9 @@ impl IDataWriter_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn StoreAsync<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn StoreAsync<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDataWriter_Impl::StoreAsync(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -1851,9 +2492,9 @@ impl IDataWriter_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn FlushAsync<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn FlushAsync<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDataWriter_Impl::FlushAsync(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -1864,9 +2505,9 @@ impl IDataWriter_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn DetachBuffer<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn DetachBuffer<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDataWriter_Impl::DetachBuffer(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -1877,9 +2518,9 @@ impl IDataWriter_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn DetachStream<Identity: IDataWriter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn DetachStream<Identity: windows_core::IUnknownImpl + IDataWriter_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDataWriter_Impl::DetachStream(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -1891,39 +2532,36 @@ impl IDataWriter_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IInspectable_Vtbl::new::<Identity, IDataWriter, OFFSET>(),
-            UnstoredBufferLength: UnstoredBufferLength::<Identity, OFFSET>,
-            UnicodeEncoding: UnicodeEncoding::<Identity, OFFSET>,
-            SetUnicodeEncoding: SetUnicodeEncoding::<Identity, OFFSET>,
-            ByteOrder: ByteOrder::<Identity, OFFSET>,
-            SetByteOrder: SetByteOrder::<Identity, OFFSET>,
-            WriteByte: WriteByte::<Identity, OFFSET>,
-            WriteBytes: WriteBytes::<Identity, OFFSET>,
-            WriteBuffer: WriteBuffer::<Identity, OFFSET>,
-            WriteBufferRange: WriteBufferRange::<Identity, OFFSET>,
-            WriteBoolean: WriteBoolean::<Identity, OFFSET>,
-            WriteGuid: WriteGuid::<Identity, OFFSET>,
-            WriteInt16: WriteInt16::<Identity, OFFSET>,
-            WriteInt32: WriteInt32::<Identity, OFFSET>,
-            WriteInt64: WriteInt64::<Identity, OFFSET>,
-            WriteUInt16: WriteUInt16::<Identity, OFFSET>,
-            WriteUInt32: WriteUInt32::<Identity, OFFSET>,
-            WriteUInt64: WriteUInt64::<Identity, OFFSET>,
-            WriteSingle: WriteSingle::<Identity, OFFSET>,
-            WriteDouble: WriteDouble::<Identity, OFFSET>,
-            WriteDateTime: WriteDateTime::<Identity, OFFSET>,
-            WriteTimeSpan: WriteTimeSpan::<Identity, OFFSET>,
-            WriteString: WriteString::<Identity, OFFSET>,
-            MeasureString: MeasureString::<Identity, OFFSET>,
-            StoreAsync: StoreAsync::<Identity, OFFSET>,
-            FlushAsync: FlushAsync::<Identity, OFFSET>,
-            DetachBuffer: DetachBuffer::<Identity, OFFSET>,
-            DetachStream: DetachStream::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDataWriter as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            UnstoredBufferLength: UnstoredBufferLength::<Identity>,
+            UnicodeEncoding: UnicodeEncoding::<Identity>,
+            SetUnicodeEncoding: SetUnicodeEncoding::<Identity>,
+            ByteOrder: ByteOrder::<Identity>,
+            SetByteOrder: SetByteOrder::<Identity>,
+            WriteByte: WriteByte::<Identity>,
+            WriteBytes: WriteBytes::<Identity>,
+            WriteBuffer: WriteBuffer::<Identity>,
+            WriteBufferRange: WriteBufferRange::<Identity>,
+            WriteBoolean: WriteBoolean::<Identity>,
+            WriteGuid: WriteGuid::<Identity>,
+            WriteInt16: WriteInt16::<Identity>,
+            WriteInt32: WriteInt32::<Identity>,
+            WriteInt64: WriteInt64::<Identity>,
+            WriteUInt16: WriteUInt16::<Identity>,
+            WriteUInt32: WriteUInt32::<Identity>,
+            WriteUInt64: WriteUInt64::<Identity>,
+            WriteSingle: WriteSingle::<Identity>,
+            WriteDouble: WriteDouble::<Identity>,
+            WriteDateTime: WriteDateTime::<Identity>,
+            WriteTimeSpan: WriteTimeSpan::<Identity>,
+            WriteString: WriteString::<Identity>,
+            MeasureString: MeasureString::<Identity>,
+            StoreAsync: StoreAsync::<Identity>,
+            FlushAsync: FlushAsync::<Identity>,
+            DetachBuffer: DetachBuffer::<Identity>,
+            DetachStream: DetachStream::<Identity>,
+        }
+    };
 }
 #[repr(C)]
 #[doc(hidden)]
@@ -2045,6 +2683,24 @@ impl IInputStream_Vtbl {
         iid == &<IInputStream as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInputStream_Impl> windows_core::imp::TearOffVtable<Identity> for IInputStream_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ReadAsync<Identity: windows_core::IUnknownImpl + IInputStream_Impl>(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void, count: u32, options: InputStreamOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInputStream_Impl::ReadAsync(this, core::mem::transmute_copy(&buffer), count, options) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ReadAsync: ReadAsync::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IInputStream_Vtbl {
@@ -2095,6 +2751,27 @@ impl IInputStreamReference_Vtbl {
         iid == &<IInputStreamReference as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInputStreamReference_Impl> windows_core::imp::TearOffVtable<Identity> for IInputStreamReference_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn OpenSequentialReadAsync<Identity: windows_core::IUnknownImpl + IInputStreamReference_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInputStreamReference_Impl::OpenSequentialReadAsync(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OpenSequentialReadAsync: OpenSequentialReadAsync::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IInputStreamReference_Vtbl {
@@ -2175,6 +2852,41 @@ impl IOutputStream_Vtbl {
         iid == &<IOutputStream as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOutputStream_Impl> windows_core::imp::TearOffVtable<Identity> for IOutputStream_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn WriteAsync<Identity: windows_core::IUnknownImpl + IOutputStream_Impl>(this: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOutputStream_Impl::WriteAsync(this, core::mem::transmute_copy(&buffer)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FlushAsync<Identity: windows_core::IUnknownImpl + IOutputStream_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOutputStream_Impl::FlushAsync(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            WriteAsync: WriteAsync::<Identity>,
+            FlushAsync: FlushAsync::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IOutputStream_Vtbl {
@@ -2250,6 +2962,35 @@ impl IPropertySetSerializer_Vtbl {
         iid == &<IPropertySetSerializer as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Foundation_Collections")]
+impl<Identity: windows_core::IUnknownImpl + IPropertySetSerializer_Impl> windows_core::imp::TearOffVtable<Identity> for IPropertySetSerializer_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Serialize<Identity: windows_core::IUnknownImpl + IPropertySetSerializer_Impl>(this: *mut core::ffi::c_void, propertyset: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPropertySetSerializer_Impl::Serialize(this, core::mem::transmute_copy(&propertyset)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Deserialize<Identity: windows_core::IUnknownImpl + IPropertySetSerializer_Impl>(this: *mut core::ffi::c_void, propertyset: *mut core::ffi::c_void, buffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertySetSerializer_Impl::Deserialize(this, core::mem::transmute_copy(&propertyset), core::mem::transmute_copy(&buffer)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Serialize: Serialize::<Identity>,
+            Deserialize: Deserialize::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IPropertySetSerializer_Vtbl {
@@ -2491,6 +3232,121 @@ impl IRandomAccessStream_Vtbl {
         iid == &<IRandomAccessStream as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRandomAccessStream_Impl> windows_core::imp::TearOffVtable<Identity> for IRandomAccessStream_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Size<Identity: windows_core::IUnknownImpl + IRandomAccessStream_Impl>(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRandomAccessStream_Impl::Size(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSize<Identity: windows_core::IUnknownImpl + IRandomAccessStream_Impl>(this: *mut core::ffi::c_void, value: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRandomAccessStream_Impl::SetSize(this, value).into()
+            }
+        }
+        unsafe extern "system" fn GetInputStreamAt<Identity: windows_core::IUnknownImpl + IRandomAccessStream_Impl>(this: *mut core::ffi::c_void, position: u64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRandomAccessStream_Impl::GetInputStreamAt(this, position) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOutputStreamAt<Identity: windows_core::IUnknownImpl + IRandomAccessStream_Impl>(this: *mut core::ffi::c_void, position: u64, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRandomAccessStream_Impl::GetOutputStreamAt(this, position) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Position<Identity: windows_core::IUnknownImpl + IRandomAccessStream_Impl>(this: *mut core::ffi::c_void, result__: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRandomAccessStream_Impl::Position(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Seek<Identity: windows_core::IUnknownImpl + IRandomAccessStream_Impl>(this: *mut core::ffi::c_void, position: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRandomAccessStream_Impl::Seek(this, position).into()
+            }
+        }
+        unsafe extern "system" fn CloneStream<Identity: windows_core::IUnknownImpl + IRandomAccessStream_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRandomAccessStream_Impl::CloneStream(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CanRead<Identity: windows_core::IUnknownImpl + IRandomAccessStream_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRandomAccessStream_Impl::CanRead(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CanWrite<Identity: windows_core::IUnknownImpl + IRandomAccessStream_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRandomAccessStream_Impl::CanWrite(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Size: Size::<Identity>,
+            SetSize: SetSize::<Identity>,
+            GetInputStreamAt: GetInputStreamAt::<Identity>,
+            GetOutputStreamAt: GetOutputStreamAt::<Identity>,
+            Position: Position::<Identity>,
+            Seek: Seek::<Identity>,
+            CloneStream: CloneStream::<Identity>,
+            CanRead: CanRead::<Identity>,
+            CanWrite: CanWrite::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IRandomAccessStream_Vtbl {
@@ -2549,6 +3405,24 @@ impl IRandomAccessStreamReference_Vtbl {
         iid == &<IRandomAccessStreamReference as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRandomAccessStreamReference_Impl> windows_core::imp::TearOffVtable<Identity> for IRandomAccessStreamReference_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn OpenReadAsync<Identity: windows_core::IUnknownImpl + IRandomAccessStreamReference_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRandomAccessStreamReference_Impl::OpenReadAsync(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OpenReadAsync: OpenReadAsync::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IRandomAccessStreamReference_Vtbl {
@@ -2694,6 +3568,9 @@ impl IRandomAccessStreamWithContentType_Vtbl {
         iid == &<IRandomAccessStreamWithContentType as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRandomAccessStreamWithContentType_Impl> windows_core::imp::TearOffVtable<Identity> for IRandomAccessStreamWithContentType_Vtbl {
+    const VTABLE: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IRandomAccessStreamWithContentType_Vtbl {
@@ -76,6 +76,33 @@ impl ISysStorageProviderEventSource_Vtbl {
         iid == &<ISysStorageProviderEventSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISysStorageProviderEventSource_Impl> windows_core::imp::TearOffVtable<Identity> for ISysStorageProviderEventSource_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn EventReceived<Identity: windows_core::IUnknownImpl + ISysStorageProviderEventSource_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISysStorageProviderEventSource_Impl::EventReceived(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveEventReceived<Identity: windows_core::IUnknownImpl + ISysStorageProviderEventSource_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISysStorageProviderEventSource_Impl::RemoveEventReceived(this, token).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EventReceived: EventReceived::<Identity>,
+            RemoveEventReceived: RemoveEventReceived::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ISysStorageProviderEventSource_Vtbl {
@@ -149,6 +176,41 @@ impl ISysStorageProviderHandlerFactory_Vtbl {
         iid == &<ISysStorageProviderHandlerFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISysStorageProviderHandlerFactory_Impl> windows_core::imp::TearOffVtable<Identity> for ISysStorageProviderHandlerFactory_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetHttpRequestProvider<Identity: windows_core::IUnknownImpl + ISysStorageProviderHandlerFactory_Impl>(this: *mut core::ffi::c_void, syncrootid: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISysStorageProviderHandlerFactory_Impl::GetHttpRequestProvider(this, core::mem::transmute(&syncrootid)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetEventSource<Identity: windows_core::IUnknownImpl + ISysStorageProviderHandlerFactory_Impl>(this: *mut core::ffi::c_void, syncrootid: *mut core::ffi::c_void, eventname: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISysStorageProviderHandlerFactory_Impl::GetEventSource(this, core::mem::transmute(&syncrootid), core::mem::transmute(&eventname)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetHttpRequestProvider: GetHttpRequestProvider::<Identity>,
+            GetEventSource: GetEventSource::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ISysStorageProviderHandlerFactory_Vtbl {
@@ -207,6 +269,25 @@ impl ISysStorageProviderHttpRequestProvider_Vtbl {
         iid == &<ISysStorageProviderHttpRequestProvider as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Web_Http")]
+impl<Identity: windows_core::IUnknownImpl + ISysStorageProviderHttpRequestProvider_Impl> windows_core::imp::TearOffVtable<Identity> for ISysStorageProviderHttpRequestProvider_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SendRequestAsync<Identity: windows_core::IUnknownImpl + ISysStorageProviderHttpRequestProvider_Impl>(this: *mut core::ffi::c_void, request: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISysStorageProviderHttpRequestProvider_Impl::SendRequestAsync(this, core::mem::transmute_copy(&request)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SendRequestAsync: SendRequestAsync::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ISysStorageProviderHttpRequestProvider_Vtbl {
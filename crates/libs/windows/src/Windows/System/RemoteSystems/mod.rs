@@ -285,6 +285,9 @@ impl IRemoteSystemFilter_Vtbl {
         iid == &<IRemoteSystemFilter as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRemoteSystemFilter_Impl> windows_core::imp::TearOffVtable<Identity> for IRemoteSystemFilter_Vtbl {
+    const VTABLE: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IRemoteSystemFilter_Vtbl {
@@ -2188,6 +2188,34 @@ impl ILauncherViewOptions_Vtbl {
         iid == &<ILauncherViewOptions as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "UI_ViewManagement")]
+impl<Identity: windows_core::IUnknownImpl + ILauncherViewOptions_Impl> windows_core::imp::TearOffVtable<Identity> for ILauncherViewOptions_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn DesiredRemainingView<Identity: windows_core::IUnknownImpl + ILauncherViewOptions_Impl>(this: *mut core::ffi::c_void, result__: *mut super::UI::ViewManagement::ViewSizePreference) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILauncherViewOptions_Impl::DesiredRemainingView(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDesiredRemainingView<Identity: windows_core::IUnknownImpl + ILauncherViewOptions_Impl>(this: *mut core::ffi::c_void, value: super::UI::ViewManagement::ViewSizePreference) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILauncherViewOptions_Impl::SetDesiredRemainingView(this, value).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DesiredRemainingView: DesiredRemainingView::<Identity>,
+            SetDesiredRemainingView: SetDesiredRemainingView::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ILauncherViewOptions_Vtbl {
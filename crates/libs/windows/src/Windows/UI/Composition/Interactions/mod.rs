@@ -375,6 +375,9 @@ impl ICompositionInteractionSource_Vtbl {
         iid == &<ICompositionInteractionSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICompositionInteractionSource_Impl> windows_core::imp::TearOffVtable<Identity> for ICompositionInteractionSource_Vtbl {
+    const VTABLE: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ICompositionInteractionSource_Vtbl {
@@ -794,6 +797,55 @@ impl IInteractionTrackerOwner_Vtbl {
         iid == &<IInteractionTrackerOwner as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInteractionTrackerOwner_Impl> windows_core::imp::TearOffVtable<Identity> for IInteractionTrackerOwner_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CustomAnimationStateEntered<Identity: windows_core::IUnknownImpl + IInteractionTrackerOwner_Impl>(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void, args: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInteractionTrackerOwner_Impl::CustomAnimationStateEntered(this, core::mem::transmute_copy(&sender), core::mem::transmute_copy(&args)).into()
+            }
+        }
+        unsafe extern "system" fn IdleStateEntered<Identity: windows_core::IUnknownImpl + IInteractionTrackerOwner_Impl>(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void, args: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInteractionTrackerOwner_Impl::IdleStateEntered(this, core::mem::transmute_copy(&sender), core::mem::transmute_copy(&args)).into()
+            }
+        }
+        unsafe extern "system" fn InertiaStateEntered<Identity: windows_core::IUnknownImpl + IInteractionTrackerOwner_Impl>(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void, args: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInteractionTrackerOwner_Impl::InertiaStateEntered(this, core::mem::transmute_copy(&sender), core::mem::transmute_copy(&args)).into()
+            }
+        }
+        unsafe extern "system" fn InteractingStateEntered<Identity: windows_core::IUnknownImpl + IInteractionTrackerOwner_Impl>(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void, args: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInteractionTrackerOwner_Impl::InteractingStateEntered(this, core::mem::transmute_copy(&sender), core::mem::transmute_copy(&args)).into()
+            }
+        }
+        unsafe extern "system" fn RequestIgnored<Identity: windows_core::IUnknownImpl + IInteractionTrackerOwner_Impl>(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void, args: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInteractionTrackerOwner_Impl::RequestIgnored(this, core::mem::transmute_copy(&sender), core::mem::transmute_copy(&args)).into()
+            }
+        }
+        unsafe extern "system" fn ValuesChanged<Identity: windows_core::IUnknownImpl + IInteractionTrackerOwner_Impl>(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void, args: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInteractionTrackerOwner_Impl::ValuesChanged(this, core::mem::transmute_copy(&sender), core::mem::transmute_copy(&args)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CustomAnimationStateEntered: CustomAnimationStateEntered::<Identity>,
+            IdleStateEntered: IdleStateEntered::<Identity>,
+            InertiaStateEntered: InertiaStateEntered::<Identity>,
+            InteractingStateEntered: InteractingStateEntered::<Identity>,
+            RequestIgnored: RequestIgnored::<Identity>,
+            ValuesChanged: ValuesChanged::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IInteractionTrackerOwner_Vtbl {
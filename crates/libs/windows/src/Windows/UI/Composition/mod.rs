@@ -14523,6 +14523,20 @@ impl IAnimationObject_Vtbl {
         iid == &<IAnimationObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAnimationObject_Impl> windows_core::imp::TearOffVtable<Identity> for IAnimationObject_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn PopulatePropertyInfo<Identity: windows_core::IUnknownImpl + IAnimationObject_Impl>(this: *mut core::ffi::c_void, propertyname: *mut core::ffi::c_void, propertyinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAnimationObject_Impl::PopulatePropertyInfo(this, core::mem::transmute(&propertyname), core::mem::transmute_copy(&propertyinfo)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PopulatePropertyInfo: PopulatePropertyInfo::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAnimationObject_Vtbl {
@@ -14718,6 +14732,9 @@ impl ICompositionAnimationBase_Vtbl {
         iid == &<ICompositionAnimationBase as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICompositionAnimationBase_Impl> windows_core::imp::TearOffVtable<Identity> for ICompositionAnimationBase_Vtbl {
+    const VTABLE: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ICompositionAnimationBase_Vtbl {
@@ -15783,6 +15800,34 @@ impl ICompositionSupportsSystemBackdrop_Vtbl {
         iid == &<ICompositionSupportsSystemBackdrop as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICompositionSupportsSystemBackdrop_Impl> windows_core::imp::TearOffVtable<Identity> for ICompositionSupportsSystemBackdrop_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SystemBackdrop<Identity: windows_core::IUnknownImpl + ICompositionSupportsSystemBackdrop_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICompositionSupportsSystemBackdrop_Impl::SystemBackdrop(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSystemBackdrop<Identity: windows_core::IUnknownImpl + ICompositionSupportsSystemBackdrop_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICompositionSupportsSystemBackdrop_Impl::SetSystemBackdrop(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SystemBackdrop: SystemBackdrop::<Identity>,
+            SetSystemBackdrop: SetSystemBackdrop::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ICompositionSupportsSystemBackdrop_Vtbl {
@@ -15807,6 +15852,9 @@ impl ICompositionSurface_Vtbl {
         iid == &<ICompositionSurface as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICompositionSurface_Impl> windows_core::imp::TearOffVtable<Identity> for ICompositionSurface_Vtbl {
+    const VTABLE: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ICompositionSurface_Vtbl {
@@ -15909,6 +15957,24 @@ impl ICompositionSurfaceFacade_Vtbl {
         iid == &<ICompositionSurfaceFacade as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICompositionSurfaceFacade_Impl> windows_core::imp::TearOffVtable<Identity> for ICompositionSurfaceFacade_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRealSurface<Identity: windows_core::IUnknownImpl + ICompositionSurfaceFacade_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICompositionSurfaceFacade_Impl::GetRealSurface(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetRealSurface: GetRealSurface::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ICompositionSurfaceFacade_Vtbl {
@@ -17072,6 +17138,9 @@ impl IVisualElement_Vtbl {
         iid == &<IVisualElement as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVisualElement_Impl> windows_core::imp::TearOffVtable<Identity> for IVisualElement_Vtbl {
+    const VTABLE: Self = { Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IVisualElement_Vtbl {
@@ -17118,6 +17187,27 @@ impl IVisualElement2_Vtbl {
         iid == &<IVisualElement2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVisualElement2_Impl> windows_core::imp::TearOffVtable<Identity> for IVisualElement2_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetVisualInternal<Identity: windows_core::IUnknownImpl + IVisualElement2_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVisualElement2_Impl::GetVisualInternal(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetVisualInternal: GetVisualInternal::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IVisualElement2_Vtbl {
@@ -2400,6 +2400,33 @@ impl ICoreAcceleratorKeys_Vtbl {
         iid == &<ICoreAcceleratorKeys as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICoreAcceleratorKeys_Impl> windows_core::imp::TearOffVtable<Identity> for ICoreAcceleratorKeys_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn AcceleratorKeyActivated<Identity: windows_core::IUnknownImpl + ICoreAcceleratorKeys_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreAcceleratorKeys_Impl::AcceleratorKeyActivated(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveAcceleratorKeyActivated<Identity: windows_core::IUnknownImpl + ICoreAcceleratorKeys_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreAcceleratorKeys_Impl::RemoveAcceleratorKeyActivated(this, cookie).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AcceleratorKeyActivated: AcceleratorKeyActivated::<Identity>,
+            RemoveAcceleratorKeyActivated: RemoveAcceleratorKeyActivated::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ICoreAcceleratorKeys_Vtbl {
@@ -2637,6 +2664,67 @@ impl ICoreInputSourceBase_Vtbl {
         iid == &<ICoreInputSourceBase as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICoreInputSourceBase_Impl> windows_core::imp::TearOffVtable<Identity> for ICoreInputSourceBase_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Dispatcher<Identity: windows_core::IUnknownImpl + ICoreInputSourceBase_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreInputSourceBase_Impl::Dispatcher(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsInputEnabled<Identity: windows_core::IUnknownImpl + ICoreInputSourceBase_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreInputSourceBase_Impl::IsInputEnabled(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetIsInputEnabled<Identity: windows_core::IUnknownImpl + ICoreInputSourceBase_Impl>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreInputSourceBase_Impl::SetIsInputEnabled(this, value).into()
+            }
+        }
+        unsafe extern "system" fn InputEnabled<Identity: windows_core::IUnknownImpl + ICoreInputSourceBase_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreInputSourceBase_Impl::InputEnabled(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveInputEnabled<Identity: windows_core::IUnknownImpl + ICoreInputSourceBase_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreInputSourceBase_Impl::RemoveInputEnabled(this, cookie).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Dispatcher: Dispatcher::<Identity>,
+            IsInputEnabled: IsInputEnabled::<Identity>,
+            SetIsInputEnabled: SetIsInputEnabled::<Identity>,
+            InputEnabled: InputEnabled::<Identity>,
+            RemoveInputEnabled: RemoveInputEnabled::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ICoreInputSourceBase_Vtbl {
@@ -3053,6 +3141,214 @@ impl ICorePointerInputSource_Vtbl {
         iid == &<ICorePointerInputSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICorePointerInputSource_Impl> windows_core::imp::TearOffVtable<Identity> for ICorePointerInputSource_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ReleasePointerCapture<Identity: windows_core::IUnknownImpl + ICorePointerInputSource_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorePointerInputSource_Impl::ReleasePointerCapture(this).into()
+            }
+        }
+        unsafe extern "system" fn SetPointerCapture<Identity: windows_core::IUnknownImpl + ICorePointerInputSource_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorePointerInputSource_Impl::SetPointerCapture(this).into()
+            }
+        }
+        unsafe extern "system" fn HasCapture<Identity: windows_core::IUnknownImpl + ICorePointerInputSource_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorePointerInputSource_Impl::HasCapture(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PointerPosition<Identity: windows_core::IUnknownImpl + ICorePointerInputSource_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorePointerInputSource_Impl::PointerPosition(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PointerCursor<Identity: windows_core::IUnknownImpl + ICorePointerInputSource_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorePointerInputSource_Impl::PointerCursor(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPointerCursor<Identity: windows_core::IUnknownImpl + ICorePointerInputSource_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorePointerInputSource_Impl::SetPointerCursor(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn PointerCaptureLost<Identity: windows_core::IUnknownImpl + ICorePointerInputSource_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorePointerInputSource_Impl::PointerCaptureLost(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemovePointerCaptureLost<Identity: windows_core::IUnknownImpl + ICorePointerInputSource_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorePointerInputSource_Impl::RemovePointerCaptureLost(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn PointerEntered<Identity: windows_core::IUnknownImpl + ICorePointerInputSource_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorePointerInputSource_Impl::PointerEntered(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemovePointerEntered<Identity: windows_core::IUnknownImpl + ICorePointerInputSource_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorePointerInputSource_Impl::RemovePointerEntered(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn PointerExited<Identity: windows_core::IUnknownImpl + ICorePointerInputSource_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorePointerInputSource_Impl::PointerExited(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemovePointerExited<Identity: windows_core::IUnknownImpl + ICorePointerInputSource_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorePointerInputSource_Impl::RemovePointerExited(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn PointerMoved<Identity: windows_core::IUnknownImpl + ICorePointerInputSource_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorePointerInputSource_Impl::PointerMoved(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemovePointerMoved<Identity: windows_core::IUnknownImpl + ICorePointerInputSource_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorePointerInputSource_Impl::RemovePointerMoved(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn PointerPressed<Identity: windows_core::IUnknownImpl + ICorePointerInputSource_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorePointerInputSource_Impl::PointerPressed(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemovePointerPressed<Identity: windows_core::IUnknownImpl + ICorePointerInputSource_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorePointerInputSource_Impl::RemovePointerPressed(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn PointerReleased<Identity: windows_core::IUnknownImpl + ICorePointerInputSource_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorePointerInputSource_Impl::PointerReleased(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemovePointerReleased<Identity: windows_core::IUnknownImpl + ICorePointerInputSource_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorePointerInputSource_Impl::RemovePointerReleased(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn PointerWheelChanged<Identity: windows_core::IUnknownImpl + ICorePointerInputSource_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorePointerInputSource_Impl::PointerWheelChanged(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemovePointerWheelChanged<Identity: windows_core::IUnknownImpl + ICorePointerInputSource_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorePointerInputSource_Impl::RemovePointerWheelChanged(this, cookie).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ReleasePointerCapture: ReleasePointerCapture::<Identity>,
+            SetPointerCapture: SetPointerCapture::<Identity>,
+            HasCapture: HasCapture::<Identity>,
+            PointerPosition: PointerPosition::<Identity>,
+            PointerCursor: PointerCursor::<Identity>,
+            SetPointerCursor: SetPointerCursor::<Identity>,
+            PointerCaptureLost: PointerCaptureLost::<Identity>,
+            RemovePointerCaptureLost: RemovePointerCaptureLost::<Identity>,
+            PointerEntered: PointerEntered::<Identity>,
+            RemovePointerEntered: RemovePointerEntered::<Identity>,
+            PointerExited: PointerExited::<Identity>,
+            RemovePointerExited: RemovePointerExited::<Identity>,
+            PointerMoved: PointerMoved::<Identity>,
+            RemovePointerMoved: RemovePointerMoved::<Identity>,
+            PointerPressed: PointerPressed::<Identity>,
+            RemovePointerPressed: RemovePointerPressed::<Identity>,
+            PointerReleased: PointerReleased::<Identity>,
+            RemovePointerReleased: RemovePointerReleased::<Identity>,
+            PointerWheelChanged: PointerWheelChanged::<Identity>,
+            RemovePointerWheelChanged: RemovePointerWheelChanged::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ICorePointerInputSource_Vtbl {
@@ -3261,6 +3557,25 @@ impl ICorePointerInputSource2_Vtbl {
         iid == &<ICorePointerInputSource2 as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "System")]
+impl<Identity: windows_core::IUnknownImpl + ICorePointerInputSource2_Impl> windows_core::imp::TearOffVtable<Identity> for ICorePointerInputSource2_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn DispatcherQueue<Identity: windows_core::IUnknownImpl + ICorePointerInputSource2_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorePointerInputSource2_Impl::DispatcherQueue(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, DispatcherQueue: DispatcherQueue::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ICorePointerInputSource2_Vtbl {
@@ -3400,47 +3715,114 @@ impl ICorePointerRedirector_Vtbl {
         iid == &<ICorePointerRedirector as windows_core::Interface>::IID
     }
 }
-#[repr(C)]
-#[doc(hidden)]
-pub struct ICorePointerRedirector_Vtbl {
-    pub base__: windows_core::IInspectable_Vtbl,
-    pub PointerRoutedAway: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut i64) -> windows_core::HRESULT,
-    pub RemovePointerRoutedAway: unsafe extern "system" fn(*mut core::ffi::c_void, i64) -> windows_core::HRESULT,
-    pub PointerRoutedTo: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut i64) -> windows_core::HRESULT,
-    pub RemovePointerRoutedTo: unsafe extern "system" fn(*mut core::ffi::c_void, i64) -> windows_core::HRESULT,
-    pub PointerRoutedReleased: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut i64) -> windows_core::HRESULT,
-    pub RemovePointerRoutedReleased: unsafe extern "system" fn(*mut core::ffi::c_void, i64) -> windows_core::HRESULT,
-}
-windows_core::imp::define_interface!(ICoreTouchHitTesting, ICoreTouchHitTesting_Vtbl, 0xb1d8a289_3acf_4124_9fa3_ea8aba353c21);
-impl windows_core::RuntimeType for ICoreTouchHitTesting {
-    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct ICoreTouchHitTesting_Vtbl {
-    pub base__: windows_core::IInspectable_Vtbl,
-    pub TouchHitTesting: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut i64) -> windows_core::HRESULT,
-    pub RemoveTouchHitTesting: unsafe extern "system" fn(*mut core::ffi::c_void, i64) -> windows_core::HRESULT,
-}
-windows_core::imp::define_interface!(ICoreWindow, ICoreWindow_Vtbl, 0x79b9d5f2_879e_4b89_b798_79e47598030c);
-impl windows_core::RuntimeType for ICoreWindow {
-    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
-}
-windows_core::imp::interface_hierarchy!(ICoreWindow, windows_core::IUnknown, windows_core::IInspectable);
-impl ICoreWindow {
-    pub fn AutomationHostProvider(&self) -> windows_core::Result<windows_core::IInspectable> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).AutomationHostProvider)(windows_core::Interface::as_raw(this), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub fn Bounds(&self) -> windows_core::Result<super::super::Foundation::Rect> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).Bounds)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
-        }
+impl<Identity: windows_core::IUnknownImpl + ICorePointerRedirector_Impl> windows_core::imp::TearOffVtable<Identity> for ICorePointerRedirector_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn PointerRoutedAway<Identity: windows_core::IUnknownImpl + ICorePointerRedirector_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorePointerRedirector_Impl::PointerRoutedAway(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemovePointerRoutedAway<Identity: windows_core::IUnknownImpl + ICorePointerRedirector_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorePointerRedirector_Impl::RemovePointerRoutedAway(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn PointerRoutedTo<Identity: windows_core::IUnknownImpl + ICorePointerRedirector_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorePointerRedirector_Impl::PointerRoutedTo(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemovePointerRoutedTo<Identity: windows_core::IUnknownImpl + ICorePointerRedirector_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorePointerRedirector_Impl::RemovePointerRoutedTo(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn PointerRoutedReleased<Identity: windows_core::IUnknownImpl + ICorePointerRedirector_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorePointerRedirector_Impl::PointerRoutedReleased(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemovePointerRoutedReleased<Identity: windows_core::IUnknownImpl + ICorePointerRedirector_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorePointerRedirector_Impl::RemovePointerRoutedReleased(this, cookie).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PointerRoutedAway: PointerRoutedAway::<Identity>,
+            RemovePointerRoutedAway: RemovePointerRoutedAway::<Identity>,
+            PointerRoutedTo: PointerRoutedTo::<Identity>,
+            RemovePointerRoutedTo: RemovePointerRoutedTo::<Identity>,
+            PointerRoutedReleased: PointerRoutedReleased::<Identity>,
+            RemovePointerRoutedReleased: RemovePointerRoutedReleased::<Identity>,
+        }
+    };
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct ICorePointerRedirector_Vtbl {
+    pub base__: windows_core::IInspectable_Vtbl,
+    pub PointerRoutedAway: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut i64) -> windows_core::HRESULT,
+    pub RemovePointerRoutedAway: unsafe extern "system" fn(*mut core::ffi::c_void, i64) -> windows_core::HRESULT,
+    pub PointerRoutedTo: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut i64) -> windows_core::HRESULT,
+    pub RemovePointerRoutedTo: unsafe extern "system" fn(*mut core::ffi::c_void, i64) -> windows_core::HRESULT,
+    pub PointerRoutedReleased: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut i64) -> windows_core::HRESULT,
+    pub RemovePointerRoutedReleased: unsafe extern "system" fn(*mut core::ffi::c_void, i64) -> windows_core::HRESULT,
+}
+windows_core::imp::define_interface!(ICoreTouchHitTesting, ICoreTouchHitTesting_Vtbl, 0xb1d8a289_3acf_4124_9fa3_ea8aba353c21);
+impl windows_core::RuntimeType for ICoreTouchHitTesting {
+    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct ICoreTouchHitTesting_Vtbl {
+    pub base__: windows_core::IInspectable_Vtbl,
+    pub TouchHitTesting: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut i64) -> windows_core::HRESULT,
+    pub RemoveTouchHitTesting: unsafe extern "system" fn(*mut core::ffi::c_void, i64) -> windows_core::HRESULT,
+}
+windows_core::imp::define_interface!(ICoreWindow, ICoreWindow_Vtbl, 0x79b9d5f2_879e_4b89_b798_79e47598030c);
+impl windows_core::RuntimeType for ICoreWindow {
+    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
+}
+windows_core::imp::interface_hierarchy!(ICoreWindow, windows_core::IUnknown, windows_core::IInspectable);
+impl ICoreWindow {
+    pub fn AutomationHostProvider(&self) -> windows_core::Result<windows_core::IInspectable> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).AutomationHostProvider)(windows_core::Interface::as_raw(this), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub fn Bounds(&self) -> windows_core::Result<super::super::Foundation::Rect> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).Bounds)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        }
     }
     #[cfg(feature = "Foundation_Collections")]
     pub fn CustomProperties(&self) -> windows_core::Result<super::super::Foundation::Collections::IPropertySet> {
@@ -4384,6 +4766,550 @@ impl ICoreWindow_Vtbl {
         iid == &<ICoreWindow as windows_core::Interface>::IID
     }
 }
+#[cfg(all(feature = "Foundation_Collections", feature = "System"))]
+impl<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl> windows_core::imp::TearOffVtable<Identity> for ICoreWindow_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn AutomationHostProvider<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::AutomationHostProvider(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Bounds<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Rect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::Bounds(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CustomProperties<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::CustomProperties(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Dispatcher<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::Dispatcher(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FlowDirection<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, result__: *mut CoreWindowFlowDirection) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::FlowDirection(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetFlowDirection<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, value: CoreWindowFlowDirection) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::SetFlowDirection(this, value).into()
+            }
+        }
+        unsafe extern "system" fn IsInputEnabled<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::IsInputEnabled(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetIsInputEnabled<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::SetIsInputEnabled(this, value).into()
+            }
+        }
+        unsafe extern "system" fn PointerCursor<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::PointerCursor(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPointerCursor<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::SetPointerCursor(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn PointerPosition<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::PointerPosition(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Visible<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::Visible(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Activate<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::Activate(this).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::Close(this).into()
+            }
+        }
+        unsafe extern "system" fn GetAsyncKeyState<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, virtualkey: super::super::System::VirtualKey, result__: *mut CoreVirtualKeyStates) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::GetAsyncKeyState(this, virtualkey) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetKeyState<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, virtualkey: super::super::System::VirtualKey, result__: *mut CoreVirtualKeyStates) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::GetKeyState(this, virtualkey) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReleasePointerCapture<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::ReleasePointerCapture(this).into()
+            }
+        }
+        unsafe extern "system" fn SetPointerCapture<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::SetPointerCapture(this).into()
+            }
+        }
+        unsafe extern "system" fn Activated<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::Activated(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveActivated<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::RemoveActivated(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn AutomationProviderRequested<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::AutomationProviderRequested(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveAutomationProviderRequested<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::RemoveAutomationProviderRequested(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn CharacterReceived<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::CharacterReceived(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveCharacterReceived<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::RemoveCharacterReceived(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn Closed<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::Closed(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveClosed<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::RemoveClosed(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn InputEnabled<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::InputEnabled(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveInputEnabled<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::RemoveInputEnabled(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn KeyDown<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::KeyDown(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveKeyDown<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::RemoveKeyDown(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn KeyUp<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::KeyUp(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveKeyUp<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::RemoveKeyUp(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn PointerCaptureLost<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::PointerCaptureLost(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemovePointerCaptureLost<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::RemovePointerCaptureLost(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn PointerEntered<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::PointerEntered(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemovePointerEntered<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::RemovePointerEntered(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn PointerExited<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::PointerExited(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemovePointerExited<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::RemovePointerExited(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn PointerMoved<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::PointerMoved(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemovePointerMoved<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::RemovePointerMoved(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn PointerPressed<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::PointerPressed(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemovePointerPressed<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::RemovePointerPressed(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn PointerReleased<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::PointerReleased(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemovePointerReleased<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::RemovePointerReleased(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn TouchHitTesting<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::TouchHitTesting(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveTouchHitTesting<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::RemoveTouchHitTesting(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn PointerWheelChanged<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::PointerWheelChanged(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemovePointerWheelChanged<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::RemovePointerWheelChanged(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn SizeChanged<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::SizeChanged(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveSizeChanged<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::RemoveSizeChanged(this, cookie).into()
+            }
+        }
+        unsafe extern "system" fn VisibilityChanged<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindow_Impl::VisibilityChanged(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveVisibilityChanged<Identity: windows_core::IUnknownImpl + ICoreWindow_Impl>(this: *mut core::ffi::c_void, cookie: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindow_Impl::RemoveVisibilityChanged(this, cookie).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AutomationHostProvider: AutomationHostProvider::<Identity>,
+            Bounds: Bounds::<Identity>,
+            CustomProperties: CustomProperties::<Identity>,
+            Dispatcher: Dispatcher::<Identity>,
+            FlowDirection: FlowDirection::<Identity>,
+            SetFlowDirection: SetFlowDirection::<Identity>,
+            IsInputEnabled: IsInputEnabled::<Identity>,
+            SetIsInputEnabled: SetIsInputEnabled::<Identity>,
+            PointerCursor: PointerCursor::<Identity>,
+            SetPointerCursor: SetPointerCursor::<Identity>,
+            PointerPosition: PointerPosition::<Identity>,
+            Visible: Visible::<Identity>,
+            Activate: Activate::<Identity>,
+            Close: Close::<Identity>,
+            GetAsyncKeyState: GetAsyncKeyState::<Identity>,
+            GetKeyState: GetKeyState::<Identity>,
+            ReleasePointerCapture: ReleasePointerCapture::<Identity>,
+            SetPointerCapture: SetPointerCapture::<Identity>,
+            Activated: Activated::<Identity>,
+            RemoveActivated: RemoveActivated::<Identity>,
+            AutomationProviderRequested: AutomationProviderRequested::<Identity>,
+            RemoveAutomationProviderRequested: RemoveAutomationProviderRequested::<Identity>,
+            CharacterReceived: CharacterReceived::<Identity>,
+            RemoveCharacterReceived: RemoveCharacterReceived::<Identity>,
+            Closed: Closed::<Identity>,
+            RemoveClosed: RemoveClosed::<Identity>,
+            InputEnabled: InputEnabled::<Identity>,
+            RemoveInputEnabled: RemoveInputEnabled::<Identity>,
+            KeyDown: KeyDown::<Identity>,
+            RemoveKeyDown: RemoveKeyDown::<Identity>,
+            KeyUp: KeyUp::<Identity>,
+            RemoveKeyUp: RemoveKeyUp::<Identity>,
+            PointerCaptureLost: PointerCaptureLost::<Identity>,
+            RemovePointerCaptureLost: RemovePointerCaptureLost::<Identity>,
+            PointerEntered: PointerEntered::<Identity>,
+            RemovePointerEntered: RemovePointerEntered::<Identity>,
+            PointerExited: PointerExited::<Identity>,
+            RemovePointerExited: RemovePointerExited::<Identity>,
+            PointerMoved: PointerMoved::<Identity>,
+            RemovePointerMoved: RemovePointerMoved::<Identity>,
+            PointerPressed: PointerPressed::<Identity>,
+            RemovePointerPressed: RemovePointerPressed::<Identity>,
+            PointerReleased: PointerReleased::<Identity>,
+            RemovePointerReleased: RemovePointerReleased::<Identity>,
+            TouchHitTesting: TouchHitTesting::<Identity>,
+            RemoveTouchHitTesting: RemoveTouchHitTesting::<Identity>,
+            PointerWheelChanged: PointerWheelChanged::<Identity>,
+            RemovePointerWheelChanged: RemovePointerWheelChanged::<Identity>,
+            SizeChanged: SizeChanged::<Identity>,
+            RemoveSizeChanged: RemoveSizeChanged::<Identity>,
+            VisibilityChanged: VisibilityChanged::<Identity>,
+            RemoveVisibilityChanged: RemoveVisibilityChanged::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ICoreWindow_Vtbl {
@@ -4601,6 +5527,33 @@ impl ICoreWindowEventArgs_Vtbl {
         iid == &<ICoreWindowEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICoreWindowEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for ICoreWindowEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Handled<Identity: windows_core::IUnknownImpl + ICoreWindowEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreWindowEventArgs_Impl::Handled(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetHandled<Identity: windows_core::IUnknownImpl + ICoreWindowEventArgs_Impl>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreWindowEventArgs_Impl::SetHandled(this, value).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Handled: Handled::<Identity>,
+            SetHandled: SetHandled::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ICoreWindowEventArgs_Vtbl {
@@ -4759,6 +5712,17 @@ impl IInitializeWithCoreWindow_Vtbl {
         iid == &<IInitializeWithCoreWindow as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInitializeWithCoreWindow_Impl> windows_core::imp::TearOffVtable<Identity> for IInitializeWithCoreWindow_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IInitializeWithCoreWindow_Impl>(this: *mut core::ffi::c_void, window: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInitializeWithCoreWindow_Impl::Initialize(this, core::mem::transmute_copy(&window)).into()
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Initialize: Initialize::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IInitializeWithCoreWindow_Vtbl {
@@ -225,6 +225,108 @@ impl IInkAnalysisNode_Vtbl {
         iid == &<IInkAnalysisNode as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInkAnalysisNode_Impl> windows_core::imp::TearOffVtable<Identity> for IInkAnalysisNode_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + IInkAnalysisNode_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkAnalysisNode_Impl::Id(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Kind<Identity: windows_core::IUnknownImpl + IInkAnalysisNode_Impl>(this: *mut core::ffi::c_void, result__: *mut InkAnalysisNodeKind) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkAnalysisNode_Impl::Kind(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn BoundingRect<Identity: windows_core::IUnknownImpl + IInkAnalysisNode_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkAnalysisNode_Impl::BoundingRect(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RotatedBoundingRect<Identity: windows_core::IUnknownImpl + IInkAnalysisNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkAnalysisNode_Impl::RotatedBoundingRect(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Children<Identity: windows_core::IUnknownImpl + IInkAnalysisNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkAnalysisNode_Impl::Children(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Parent<Identity: windows_core::IUnknownImpl + IInkAnalysisNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkAnalysisNode_Impl::Parent(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStrokeIds<Identity: windows_core::IUnknownImpl + IInkAnalysisNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkAnalysisNode_Impl::GetStrokeIds(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Id: Id::<Identity>,
+            Kind: Kind::<Identity>,
+            BoundingRect: BoundingRect::<Identity>,
+            RotatedBoundingRect: RotatedBoundingRect::<Identity>,
+            Children: Children::<Identity>,
+            Parent: Parent::<Identity>,
+            GetStrokeIds: GetStrokeIds::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IInkAnalysisNode_Vtbl {
@@ -338,6 +440,24 @@ impl IInkAnalyzerFactory_Vtbl {
         iid == &<IInkAnalyzerFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInkAnalyzerFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IInkAnalyzerFactory_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateAnalyzer<Identity: windows_core::IUnknownImpl + IInkAnalyzerFactory_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkAnalyzerFactory_Impl::CreateAnalyzer(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateAnalyzer: CreateAnalyzer::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IInkAnalyzerFactory_Vtbl {
@@ -244,6 +244,24 @@ impl IInkPointFactory_Vtbl {
         iid == &<IInkPointFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInkPointFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IInkPointFactory_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateInkPoint<Identity: windows_core::IUnknownImpl + IInkPointFactory_Impl>(this: *mut core::ffi::c_void, position: super::super::super::Foundation::Point, pressure: f32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkPointFactory_Impl::CreateInkPoint(this, core::mem::transmute(&position), pressure) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateInkPoint: CreateInkPoint::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IInkPointFactory_Vtbl {
@@ -416,6 +434,24 @@ impl IInkPresenterRulerFactory_Vtbl {
         iid == &<IInkPresenterRulerFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInkPresenterRulerFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IInkPresenterRulerFactory_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Create<Identity: windows_core::IUnknownImpl + IInkPresenterRulerFactory_Impl>(this: *mut core::ffi::c_void, inkpresenter: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkPresenterRulerFactory_Impl::Create(this, core::mem::transmute_copy(&inkpresenter)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Create: Create::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IInkPresenterRulerFactory_Vtbl {
@@ -597,6 +633,106 @@ impl IInkPresenterStencil_Vtbl {
         iid == &<IInkPresenterStencil as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInkPresenterStencil_Impl> windows_core::imp::TearOffVtable<Identity> for IInkPresenterStencil_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Kind<Identity: windows_core::IUnknownImpl + IInkPresenterStencil_Impl>(this: *mut core::ffi::c_void, result__: *mut InkPresenterStencilKind) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkPresenterStencil_Impl::Kind(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsVisible<Identity: windows_core::IUnknownImpl + IInkPresenterStencil_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkPresenterStencil_Impl::IsVisible(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetIsVisible<Identity: windows_core::IUnknownImpl + IInkPresenterStencil_Impl>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInkPresenterStencil_Impl::SetIsVisible(this, value).into()
+            }
+        }
+        unsafe extern "system" fn BackgroundColor<Identity: windows_core::IUnknownImpl + IInkPresenterStencil_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::Color) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkPresenterStencil_Impl::BackgroundColor(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBackgroundColor<Identity: windows_core::IUnknownImpl + IInkPresenterStencil_Impl>(this: *mut core::ffi::c_void, value: super::super::Color) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInkPresenterStencil_Impl::SetBackgroundColor(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn ForegroundColor<Identity: windows_core::IUnknownImpl + IInkPresenterStencil_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::Color) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkPresenterStencil_Impl::ForegroundColor(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetForegroundColor<Identity: windows_core::IUnknownImpl + IInkPresenterStencil_Impl>(this: *mut core::ffi::c_void, value: super::super::Color) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInkPresenterStencil_Impl::SetForegroundColor(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Transform<Identity: windows_core::IUnknownImpl + IInkPresenterStencil_Impl>(this: *mut core::ffi::c_void, result__: *mut windows_numerics::Matrix3x2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkPresenterStencil_Impl::Transform(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTransform<Identity: windows_core::IUnknownImpl + IInkPresenterStencil_Impl>(this: *mut core::ffi::c_void, value: windows_numerics::Matrix3x2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInkPresenterStencil_Impl::SetTransform(this, core::mem::transmute(&value)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Kind: Kind::<Identity>,
+            IsVisible: IsVisible::<Identity>,
+            SetIsVisible: SetIsVisible::<Identity>,
+            BackgroundColor: BackgroundColor::<Identity>,
+            SetBackgroundColor: SetBackgroundColor::<Identity>,
+            ForegroundColor: ForegroundColor::<Identity>,
+            SetForegroundColor: SetForegroundColor::<Identity>,
+            Transform: Transform::<Identity>,
+            SetTransform: SetTransform::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IInkPresenterStencil_Vtbl {
@@ -717,6 +853,48 @@ impl IInkRecognizerContainer_Vtbl {
         iid == &<IInkRecognizerContainer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInkRecognizerContainer_Impl> windows_core::imp::TearOffVtable<Identity> for IInkRecognizerContainer_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetDefaultRecognizer<Identity: windows_core::IUnknownImpl + IInkRecognizerContainer_Impl>(this: *mut core::ffi::c_void, recognizer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInkRecognizerContainer_Impl::SetDefaultRecognizer(this, core::mem::transmute_copy(&recognizer)).into()
+            }
+        }
+        unsafe extern "system" fn RecognizeAsync<Identity: windows_core::IUnknownImpl + IInkRecognizerContainer_Impl>(this: *mut core::ffi::c_void, strokecollection: *mut core::ffi::c_void, recognitiontarget: InkRecognitionTarget, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkRecognizerContainer_Impl::RecognizeAsync(this, core::mem::transmute_copy(&strokecollection), recognitiontarget) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRecognizers<Identity: windows_core::IUnknownImpl + IInkRecognizerContainer_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkRecognizerContainer_Impl::GetRecognizers(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetDefaultRecognizer: SetDefaultRecognizer::<Identity>,
+            RecognizeAsync: RecognizeAsync::<Identity>,
+            GetRecognizers: GetRecognizers::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IInkRecognizerContainer_Vtbl {
@@ -1125,6 +1303,182 @@ impl IInkStrokeContainer_Vtbl {
         iid == &<IInkStrokeContainer as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Storage_Streams")]
+impl<Identity: windows_core::IUnknownImpl + IInkStrokeContainer_Impl> windows_core::imp::TearOffVtable<Identity> for IInkStrokeContainer_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn BoundingRect<Identity: windows_core::IUnknownImpl + IInkStrokeContainer_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkStrokeContainer_Impl::BoundingRect(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddStroke<Identity: windows_core::IUnknownImpl + IInkStrokeContainer_Impl>(this: *mut core::ffi::c_void, stroke: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInkStrokeContainer_Impl::AddStroke(this, core::mem::transmute_copy(&stroke)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteSelected<Identity: windows_core::IUnknownImpl + IInkStrokeContainer_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkStrokeContainer_Impl::DeleteSelected(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveSelected<Identity: windows_core::IUnknownImpl + IInkStrokeContainer_Impl>(this: *mut core::ffi::c_void, translation: super::super::super::Foundation::Point, result__: *mut super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkStrokeContainer_Impl::MoveSelected(this, core::mem::transmute(&translation)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SelectWithPolyLine<Identity: windows_core::IUnknownImpl + IInkStrokeContainer_Impl>(this: *mut core::ffi::c_void, polyline: *mut core::ffi::c_void, result__: *mut super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkStrokeContainer_Impl::SelectWithPolyLine(this, core::mem::transmute_copy(&polyline)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SelectWithLine<Identity: windows_core::IUnknownImpl + IInkStrokeContainer_Impl>(this: *mut core::ffi::c_void, from: super::super::super::Foundation::Point, to: super::super::super::Foundation::Point, result__: *mut super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkStrokeContainer_Impl::SelectWithLine(this, core::mem::transmute(&from), core::mem::transmute(&to)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CopySelectedToClipboard<Identity: windows_core::IUnknownImpl + IInkStrokeContainer_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInkStrokeContainer_Impl::CopySelectedToClipboard(this).into()
+            }
+        }
+        unsafe extern "system" fn PasteFromClipboard<Identity: windows_core::IUnknownImpl + IInkStrokeContainer_Impl>(this: *mut core::ffi::c_void, position: super::super::super::Foundation::Point, result__: *mut super::super::super::Foundation::Rect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkStrokeContainer_Impl::PasteFromClipboard(this, core::mem::transmute(&position)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CanPasteFromClipboard<Identity: windows_core::IUnknownImpl + IInkStrokeContainer_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkStrokeContainer_Impl::CanPasteFromClipboard(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LoadAsync<Identity: windows_core::IUnknownImpl + IInkStrokeContainer_Impl>(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkStrokeContainer_Impl::LoadAsync(this, core::mem::transmute_copy(&inputstream)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SaveAsync<Identity: windows_core::IUnknownImpl + IInkStrokeContainer_Impl>(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkStrokeContainer_Impl::SaveAsync(this, core::mem::transmute_copy(&outputstream)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UpdateRecognitionResults<Identity: windows_core::IUnknownImpl + IInkStrokeContainer_Impl>(this: *mut core::ffi::c_void, recognitionresults: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInkStrokeContainer_Impl::UpdateRecognitionResults(this, core::mem::transmute_copy(&recognitionresults)).into()
+            }
+        }
+        unsafe extern "system" fn GetStrokes<Identity: windows_core::IUnknownImpl + IInkStrokeContainer_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkStrokeContainer_Impl::GetStrokes(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRecognitionResults<Identity: windows_core::IUnknownImpl + IInkStrokeContainer_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInkStrokeContainer_Impl::GetRecognitionResults(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            BoundingRect: BoundingRect::<Identity>,
+            AddStroke: AddStroke::<Identity>,
+            DeleteSelected: DeleteSelected::<Identity>,
+            MoveSelected: MoveSelected::<Identity>,
+            SelectWithPolyLine: SelectWithPolyLine::<Identity>,
+            SelectWithLine: SelectWithLine::<Identity>,
+            CopySelectedToClipboard: CopySelectedToClipboard::<Identity>,
+            PasteFromClipboard: PasteFromClipboard::<Identity>,
+            CanPasteFromClipboard: CanPasteFromClipboard::<Identity>,
+            LoadAsync: LoadAsync::<Identity>,
+            SaveAsync: SaveAsync::<Identity>,
+            UpdateRecognitionResults: UpdateRecognitionResults::<Identity>,
+            GetStrokes: GetStrokes::<Identity>,
+            GetRecognitionResults: GetRecognitionResults::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IInkStrokeContainer_Vtbl {
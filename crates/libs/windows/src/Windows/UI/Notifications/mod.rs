@@ -351,6 +351,40 @@ impl IAdaptiveNotificationContent_Vtbl {
         iid == &<IAdaptiveNotificationContent as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAdaptiveNotificationContent_Impl> windows_core::imp::TearOffVtable<Identity> for IAdaptiveNotificationContent_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Kind<Identity: windows_core::IUnknownImpl + IAdaptiveNotificationContent_Impl>(this: *mut core::ffi::c_void, result__: *mut AdaptiveNotificationContentKind) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAdaptiveNotificationContent_Impl::Kind(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Hints<Identity: windows_core::IUnknownImpl + IAdaptiveNotificationContent_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAdaptiveNotificationContent_Impl::Hints(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Kind: Kind::<Identity>,
+            Hints: Hints::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAdaptiveNotificationContent_Vtbl {
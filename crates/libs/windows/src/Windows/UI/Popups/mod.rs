@@ -173,6 +173,76 @@ impl IUICommand_Vtbl {
         iid == &<IUICommand as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IUICommand_Impl> windows_core::imp::TearOffVtable<Identity> for IUICommand_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Label<Identity: windows_core::IUnknownImpl + IUICommand_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUICommand_Impl::Label(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLabel<Identity: windows_core::IUnknownImpl + IUICommand_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUICommand_Impl::SetLabel(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Invoked<Identity: windows_core::IUnknownImpl + IUICommand_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUICommand_Impl::Invoked(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetInvoked<Identity: windows_core::IUnknownImpl + IUICommand_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUICommand_Impl::SetInvoked(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + IUICommand_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUICommand_Impl::Id(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetId<Identity: windows_core::IUnknownImpl + IUICommand_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUICommand_Impl::SetId(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Label: Label::<Identity>,
+            SetLabel: SetLabel::<Identity>,
+            Invoked: Invoked::<Identity>,
+            SetInvoked: SetInvoked::<Identity>,
+            Id: Id::<Identity>,
+            SetId: SetId::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IUICommand_Vtbl {
@@ -164,6 +164,24 @@ impl IAdaptiveCard_Vtbl {
         iid == &<IAdaptiveCard as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAdaptiveCard_Impl> windows_core::imp::TearOffVtable<Identity> for IAdaptiveCard_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ToJson<Identity: windows_core::IUnknownImpl + IAdaptiveCard_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAdaptiveCard_Impl::ToJson(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ToJson: ToJson::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAdaptiveCard_Vtbl {
@@ -214,6 +232,27 @@ impl IAdaptiveCardBuilderStatics_Vtbl {
         iid == &<IAdaptiveCardBuilderStatics as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAdaptiveCardBuilderStatics_Impl> windows_core::imp::TearOffVtable<Identity> for IAdaptiveCardBuilderStatics_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateAdaptiveCardFromJson<Identity: windows_core::IUnknownImpl + IAdaptiveCardBuilderStatics_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAdaptiveCardBuilderStatics_Impl::CreateAdaptiveCardFromJson(this, core::mem::transmute(&value)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateAdaptiveCardFromJson: CreateAdaptiveCardFromJson::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAdaptiveCardBuilderStatics_Vtbl {
@@ -1230,290 +1230,138 @@ impl ITextCharacterFormat_Vtbl {
         iid == &<ITextCharacterFormat as windows_core::Interface>::IID
     }
 }
-#[repr(C)]
-#[doc(hidden)]
-pub struct ITextCharacterFormat_Vtbl {
-    pub base__: windows_core::IInspectable_Vtbl,
-    pub AllCaps: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
-    pub SetAllCaps: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
-    pub BackgroundColor: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::Color) -> windows_core::HRESULT,
-    pub SetBackgroundColor: unsafe extern "system" fn(*mut core::ffi::c_void, super::Color) -> windows_core::HRESULT,
-    pub Bold: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
-    pub SetBold: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
-    pub FontStretch: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FontStretch) -> windows_core::HRESULT,
-    pub SetFontStretch: unsafe extern "system" fn(*mut core::ffi::c_void, FontStretch) -> windows_core::HRESULT,
-    pub FontStyle: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FontStyle) -> windows_core::HRESULT,
-    pub SetFontStyle: unsafe extern "system" fn(*mut core::ffi::c_void, FontStyle) -> windows_core::HRESULT,
-    pub ForegroundColor: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::Color) -> windows_core::HRESULT,
-    pub SetForegroundColor: unsafe extern "system" fn(*mut core::ffi::c_void, super::Color) -> windows_core::HRESULT,
-    pub Hidden: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
-    pub SetHidden: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
-    pub Italic: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
-    pub SetItalic: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
-    pub Kerning: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
-    pub SetKerning: unsafe extern "system" fn(*mut core::ffi::c_void, f32) -> windows_core::HRESULT,
-    pub LanguageTag: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub SetLanguageTag: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub LinkType: unsafe extern "system" fn(*mut core::ffi::c_void, *mut LinkType) -> windows_core::HRESULT,
-    pub Name: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub SetName: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub Outline: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
-    pub SetOutline: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
-    pub Position: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
-    pub SetPosition: unsafe extern "system" fn(*mut core::ffi::c_void, f32) -> windows_core::HRESULT,
-    pub ProtectedText: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
-    pub SetProtectedText: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
-    pub Size: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
-    pub SetSize: unsafe extern "system" fn(*mut core::ffi::c_void, f32) -> windows_core::HRESULT,
-    pub SmallCaps: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
-    pub SetSmallCaps: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
-    pub Spacing: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
-    pub SetSpacing: unsafe extern "system" fn(*mut core::ffi::c_void, f32) -> windows_core::HRESULT,
-    pub Strikethrough: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
-    pub SetStrikethrough: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
-    pub Subscript: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
-    pub SetSubscript: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
-    pub Superscript: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
-    pub SetSuperscript: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
-    pub TextScript: unsafe extern "system" fn(*mut core::ffi::c_void, *mut TextScript) -> windows_core::HRESULT,
-    pub SetTextScript: unsafe extern "system" fn(*mut core::ffi::c_void, TextScript) -> windows_core::HRESULT,
-    pub Underline: unsafe extern "system" fn(*mut core::ffi::c_void, *mut UnderlineType) -> windows_core::HRESULT,
-    pub SetUnderline: unsafe extern "system" fn(*mut core::ffi::c_void, UnderlineType) -> windows_core::HRESULT,
-    pub Weight: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
-    pub SetWeight: unsafe extern "system" fn(*mut core::ffi::c_void, i32) -> windows_core::HRESULT,
-    pub SetClone: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetClone: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub IsEqual: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut bool) -> windows_core::HRESULT,
-}
-windows_core::imp::define_interface!(ITextConstantsStatics, ITextConstantsStatics_Vtbl, 0x779e7c33_189d_4bfa_97c8_10db135d976e);
-impl windows_core::RuntimeType for ITextConstantsStatics {
-    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct ITextConstantsStatics_Vtbl {
-    pub base__: windows_core::IInspectable_Vtbl,
-    pub AutoColor: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::Color) -> windows_core::HRESULT,
-    pub MinUnitCount: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
-    pub MaxUnitCount: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
-    pub UndefinedColor: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::Color) -> windows_core::HRESULT,
-    pub UndefinedFloatValue: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
-    pub UndefinedInt32Value: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
-    pub UndefinedFontStretch: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FontStretch) -> windows_core::HRESULT,
-    pub UndefinedFontStyle: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FontStyle) -> windows_core::HRESULT,
-}
-windows_core::imp::define_interface!(ITextDocument, ITextDocument_Vtbl, 0xbeee4ddb_90b2_408c_a2f6_0a0ac31e33e4);
-impl windows_core::RuntimeType for ITextDocument {
-    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
-}
-windows_core::imp::interface_hierarchy!(ITextDocument, windows_core::IUnknown, windows_core::IInspectable);
-impl ITextDocument {
-    pub fn CaretType(&self) -> windows_core::Result<CaretType> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).CaretType)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+impl<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl> windows_core::imp::TearOffVtable<Identity> for ITextCharacterFormat_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn AllCaps<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::AllCaps(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub fn SetCaretType(&self, value: CaretType) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetCaretType)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn DefaultTabStop(&self) -> windows_core::Result<f32> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).DefaultTabStop)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        unsafe extern "system" fn SetAllCaps<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetAllCaps(this, value).into()
+            }
         }
-    }
-    pub fn SetDefaultTabStop(&self, value: f32) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetDefaultTabStop)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn Selection(&self) -> windows_core::Result<ITextSelection> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).Selection)(windows_core::Interface::as_raw(this), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn BackgroundColor<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut super::Color) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::BackgroundColor(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub fn UndoLimit(&self) -> windows_core::Result<u32> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).UndoLimit)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        unsafe extern "system" fn SetBackgroundColor<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: super::Color) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetBackgroundColor(this, core::mem::transmute(&value)).into()
+            }
         }
-    }
-    pub fn SetUndoLimit(&self, value: u32) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetUndoLimit)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn CanCopy(&self) -> windows_core::Result<bool> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).CanCopy)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        unsafe extern "system" fn Bold<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::Bold(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub fn CanPaste(&self) -> windows_core::Result<bool> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).CanPaste)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        unsafe extern "system" fn SetBold<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetBold(this, value).into()
+            }
         }
-    }
-    pub fn CanRedo(&self) -> windows_core::Result<bool> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).CanRedo)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        unsafe extern "system" fn FontStretch<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut FontStretch) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::FontStretch(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub fn CanUndo(&self) -> windows_core::Result<bool> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).CanUndo)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        unsafe extern "system" fn SetFontStretch<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: FontStretch) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetFontStretch(this, value).into()
+            }
         }
-    }
-    pub fn ApplyDisplayUpdates(&self) -> windows_core::Result<i32> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).ApplyDisplayUpdates)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        unsafe extern "system" fn FontStyle<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut FontStyle) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::FontStyle(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub fn BatchDisplayUpdates(&self) -> windows_core::Result<i32> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).BatchDisplayUpdates)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        unsafe extern "system" fn SetFontStyle<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: FontStyle) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetFontStyle(this, value).into()
+            }
         }
-    }
-    pub fn BeginUndoGroup(&self) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).BeginUndoGroup)(windows_core::Interface::as_raw(this)).ok() }
-    }
-    pub fn EndUndoGroup(&self) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).EndUndoGroup)(windows_core::Interface::as_raw(this)).ok() }
-    }
-    pub fn GetDefaultCharacterFormat(&self) -> windows_core::Result<ITextCharacterFormat> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).GetDefaultCharacterFormat)(windows_core::Interface::as_raw(this), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn ForegroundColor<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut super::Color) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::ForegroundColor(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub fn GetDefaultParagraphFormat(&self) -> windows_core::Result<ITextParagraphFormat> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).GetDefaultParagraphFormat)(windows_core::Interface::as_raw(this), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn SetForegroundColor<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: super::Color) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetForegroundColor(this, core::mem::transmute(&value)).into()
+            }
         }
-    }
-    pub fn GetRange(&self, startposition: i32, endposition: i32) -> windows_core::Result<ITextRange> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).GetRange)(windows_core::Interface::as_raw(this), startposition, endposition, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn Hidden<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::Hidden(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub fn GetRangeFromPoint(&self, point: super::super::Foundation::Point, options: PointOptions) -> windows_core::Result<ITextRange> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).GetRangeFromPoint)(windows_core::Interface::as_raw(this), point, options, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn SetHidden<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetHidden(this, value).into()
+            }
         }
-    }
-    pub fn GetText(&self, options: TextGetOptions, value: &mut windows_core::HSTRING) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).GetText)(windows_core::Interface::as_raw(this), options, value as *mut _ as _).ok() }
-    }
-    #[cfg(feature = "Storage_Streams")]
-    pub fn LoadFromStream<P1>(&self, options: TextSetOptions, value: P1) -> windows_core::Result<()>
-    where
-        P1: windows_core::Param<super::super::Storage::Streams::IRandomAccessStream>,
-    {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).LoadFromStream)(windows_core::Interface::as_raw(this), options, value.param().abi()).ok() }
-    }
-    pub fn Redo(&self) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).Redo)(windows_core::Interface::as_raw(this)).ok() }
-    }
-    #[cfg(feature = "Storage_Streams")]
-    pub fn SaveToStream<P1>(&self, options: TextGetOptions, value: P1) -> windows_core::Result<()>
-    where
-        P1: windows_core::Param<super::super::Storage::Streams::IRandomAccessStream>,
-    {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SaveToStream)(windows_core::Interface::as_raw(this), options, value.param().abi()).ok() }
-    }
-    pub fn SetDefaultCharacterFormat<P0>(&self, value: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<ITextCharacterFormat>,
-    {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetDefaultCharacterFormat)(windows_core::Interface::as_raw(this), value.param().abi()).ok() }
-    }
-    pub fn SetDefaultParagraphFormat<P0>(&self, value: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<ITextParagraphFormat>,
-    {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetDefaultParagraphFormat)(windows_core::Interface::as_raw(this), value.param().abi()).ok() }
-    }
-    pub fn SetText(&self, options: TextSetOptions, value: &windows_core::HSTRING) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetText)(windows_core::Interface::as_raw(this), options, core::mem::transmute_copy(value)).ok() }
-    }
-    pub fn Undo(&self) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).Undo)(windows_core::Interface::as_raw(this)).ok() }
-    }
-}
-#[cfg(feature = "Storage_Streams")]
-impl windows_core::RuntimeName for ITextDocument {
-    const NAME: &'static str = "Windows.UI.Text.ITextDocument";
-}
-#[cfg(feature = "Storage_Streams")]
-pub trait ITextDocument_Impl: windows_core::IUnknownImpl {
-    fn CaretType(&self) -> windows_core::Result<CaretType>;
-    fn SetCaretType(&self, value: CaretType) -> windows_core::Result<()>;
-    fn DefaultTabStop(&self) -> windows_core::Result<f32>;
-    fn SetDefaultTabStop(&self, value: f32) -> windows_core::Result<()>;
-    fn Selection(&self) -> windows_core::Result<ITextSelection>;
-    fn UndoLimit(&self) -> windows_core::Result<u32>;
-    fn SetUndoLimit(&self, value: u32) -> windows_core::Result<()>;
-    fn CanCopy(&self) -> windows_core::Result<bool>;
-    fn CanPaste(&self) -> windows_core::Result<bool>;
-    fn CanRedo(&self) -> windows_core::Result<bool>;
-    fn CanUndo(&self) -> windows_core::Result<bool>;
-    fn ApplyDisplayUpdates(&self) -> windows_core::Result<i32>;
-    fn BatchDisplayUpdates(&self) -> windows_core::Result<i32>;
-    fn BeginUndoGroup(&self) -> windows_core::Result<()>;
-    fn EndUndoGroup(&self) -> windows_core::Result<()>;
-    fn GetDefaultCharacterFormat(&self) -> windows_core::Result<ITextCharacterFormat>;
-    fn GetDefaultParagraphFormat(&self) -> windows_core::Result<ITextParagraphFormat>;
-    fn GetRange(&self, startPosition: i32, endPosition: i32) -> windows_core::Result<ITextRange>;
-    fn GetRangeFromPoint(&self, point: &super::super::Foundation::Point, options: PointOptions) -> windows_core::Result<ITextRange>;
-    fn GetText(&self, options: TextGetOptions, value: &mut windows_core::HSTRING) -> windows_core::Result<()>;
-    fn LoadFromStream(&self, options: TextSetOptions, value: windows_core::Ref<super::super::Storage::Streams::IRandomAccessStream>) -> windows_core::Result<()>;
-    fn Redo(&self) -> windows_core::Result<()>;
-    fn SaveToStream(&self, options: TextGetOptions, value: windows_core::Ref<super::super::Storage::Streams::IRandomAccessStream>) -> windows_core::Result<()>;
-    fn SetDefaultCharacterFormat(&self, value: windows_core::Ref<ITextCharacterFormat>) -> windows_core::Result<()>;
-    fn SetDefaultParagraphFormat(&self, value: windows_core::Ref<ITextParagraphFormat>) -> windows_core::Result<()>;
-    fn SetText(&self, options: TextSetOptions, value: &windows_core::HSTRING) -> windows_core::Result<()>;
-    fn Undo(&self) -> windows_core::Result<()>;
-}
-#[cfg(feature = "Storage_Streams")]
-impl ITextDocument_Vtbl {
-    pub const fn new<Identity: ITextDocument_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn CaretType<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut CaretType) -> windows_core::HRESULT {
+        unsafe extern "system" fn Italic<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextDocument_Impl::CaretType(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::Italic(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         windows_core::HRESULT(0)
@@ -1522,16 +1370,16 @@ impl ITextDocument_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetCaretType<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: CaretType) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetItalic<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextDocument_Impl::SetCaretType(this, value).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetItalic(this, value).into()
             }
         }
-        unsafe extern "system" fn DefaultTabStop<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn Kerning<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextDocument_Impl::DefaultTabStop(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::Kerning(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         windows_core::HRESULT(0)
@@ -1540,16 +1388,16 @@ impl ITextDocument_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetDefaultTabStop<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetKerning<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextDocument_Impl::SetDefaultTabStop(this, value).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetKerning(this, value).into()
             }
         }
-        unsafe extern "system" fn Selection<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn LanguageTag<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextDocument_Impl::Selection(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::LanguageTag(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         core::mem::forget(ok__);
@@ -1559,10 +1407,16 @@ impl ITextDocument_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn UndoLimit<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetLanguageTag<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextDocument_Impl::UndoLimit(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetLanguageTag(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn LinkType<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut LinkType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::LinkType(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         windows_core::HRESULT(0)
@@ -1571,28 +1425,29 @@ impl ITextDocument_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetUndoLimit<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextDocument_Impl::SetUndoLimit(this, value).into()
-            }
-        }
-        unsafe extern "system" fn CanCopy<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextDocument_Impl::CanCopy(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::Name(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn CanPaste<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetName<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextDocument_Impl::CanPaste(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetName(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Outline<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::Outline(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         windows_core::HRESULT(0)
@@ -1601,10 +1456,16 @@ impl ITextDocument_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CanRedo<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetOutline<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextDocument_Impl::CanRedo(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetOutline(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Position<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::Position(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         windows_core::HRESULT(0)
@@ -1613,10 +1474,16 @@ impl ITextDocument_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CanUndo<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetPosition<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextDocument_Impl::CanUndo(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetPosition(this, value).into()
+            }
+        }
+        unsafe extern "system" fn ProtectedText<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::ProtectedText(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         windows_core::HRESULT(0)
@@ -1625,10 +1492,16 @@ impl ITextDocument_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ApplyDisplayUpdates<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetProtectedText<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextDocument_Impl::ApplyDisplayUpdates(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetProtectedText(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Size<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::Size(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         windows_core::HRESULT(0)
@@ -1637,10 +1510,16 @@ impl ITextDocument_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn BatchDisplayUpdates<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetSize<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextDocument_Impl::BatchDisplayUpdates(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetSize(this, value).into()
+            }
+        }
+        unsafe extern "system" fn SmallCaps<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::SmallCaps(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         windows_core::HRESULT(0)
@@ -1649,562 +1528,508 @@ impl ITextDocument_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn BeginUndoGroup<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetSmallCaps<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextDocument_Impl::BeginUndoGroup(this).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetSmallCaps(this, value).into()
             }
         }
-        unsafe extern "system" fn EndUndoGroup<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Spacing<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextDocument_Impl::EndUndoGroup(this).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::Spacing(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
             }
         }
-        unsafe extern "system" fn GetDefaultCharacterFormat<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetSpacing<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextDocument_Impl::GetDefaultCharacterFormat(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetSpacing(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Strikethrough<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::Strikethrough(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
-                        core::mem::forget(ok__);
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn GetDefaultParagraphFormat<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetStrikethrough<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextDocument_Impl::GetDefaultParagraphFormat(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetStrikethrough(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Subscript<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::Subscript(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
-                        core::mem::forget(ok__);
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn GetRange<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, startposition: i32, endposition: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetSubscript<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextDocument_Impl::GetRange(this, startposition, endposition) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetSubscript(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Superscript<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::Superscript(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
-                        core::mem::forget(ok__);
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn GetRangeFromPoint<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, point: super::super::Foundation::Point, options: PointOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetSuperscript<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextDocument_Impl::GetRangeFromPoint(this, core::mem::transmute(&point), options) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetSuperscript(this, value).into()
+            }
+        }
+        unsafe extern "system" fn TextScript<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut TextScript) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::TextScript(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
-                        core::mem::forget(ok__);
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn GetText<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: TextGetOptions, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetTextScript<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: TextScript) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextDocument_Impl::GetText(this, options, core::mem::transmute_copy(&value)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetTextScript(this, value).into()
             }
         }
-        unsafe extern "system" fn LoadFromStream<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: TextSetOptions, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Underline<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut UnderlineType) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextDocument_Impl::LoadFromStream(this, options, core::mem::transmute_copy(&value)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::Underline(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
             }
         }
-        unsafe extern "system" fn Redo<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetUnderline<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: UnderlineType) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextDocument_Impl::Redo(this).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetUnderline(this, value).into()
             }
         }
-        unsafe extern "system" fn SaveToStream<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: TextGetOptions, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Weight<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextDocument_Impl::SaveToStream(this, options, core::mem::transmute_copy(&value)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::Weight(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
             }
         }
-        unsafe extern "system" fn SetDefaultCharacterFormat<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetWeight<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextDocument_Impl::SetDefaultCharacterFormat(this, core::mem::transmute_copy(&value)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetWeight(this, value).into()
             }
         }
-        unsafe extern "system" fn SetDefaultParagraphFormat<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetClone<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextDocument_Impl::SetDefaultParagraphFormat(this, core::mem::transmute_copy(&value)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextCharacterFormat_Impl::SetClone(this, core::mem::transmute_copy(&value)).into()
             }
         }
-        unsafe extern "system" fn SetText<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: TextSetOptions, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetClone<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextDocument_Impl::SetText(this, options, core::mem::transmute(&value)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::GetClone(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
             }
         }
-        unsafe extern "system" fn Undo<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn IsEqual<Identity: windows_core::IUnknownImpl + ITextCharacterFormat_Impl>(this: *mut core::ffi::c_void, format: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextDocument_Impl::Undo(this).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextCharacterFormat_Impl::IsEqual(this, core::mem::transmute_copy(&format)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
             }
         }
         Self {
-            base__: windows_core::IInspectable_Vtbl::new::<Identity, ITextDocument, OFFSET>(),
-            CaretType: CaretType::<Identity, OFFSET>,
-            SetCaretType: SetCaretType::<Identity, OFFSET>,
-            DefaultTabStop: DefaultTabStop::<Identity, OFFSET>,
-            SetDefaultTabStop: SetDefaultTabStop::<Identity, OFFSET>,
-            Selection: Selection::<Identity, OFFSET>,
-            UndoLimit: UndoLimit::<Identity, OFFSET>,
-            SetUndoLimit: SetUndoLimit::<Identity, OFFSET>,
-            CanCopy: CanCopy::<Identity, OFFSET>,
-            CanPaste: CanPaste::<Identity, OFFSET>,
-            CanRedo: CanRedo::<Identity, OFFSET>,
-            CanUndo: CanUndo::<Identity, OFFSET>,
-            ApplyDisplayUpdates: ApplyDisplayUpdates::<Identity, OFFSET>,
-            BatchDisplayUpdates: BatchDisplayUpdates::<Identity, OFFSET>,
-            BeginUndoGroup: BeginUndoGroup::<Identity, OFFSET>,
-            EndUndoGroup: EndUndoGroup::<Identity, OFFSET>,
-            GetDefaultCharacterFormat: GetDefaultCharacterFormat::<Identity, OFFSET>,
-            GetDefaultParagraphFormat: GetDefaultParagraphFormat::<Identity, OFFSET>,
-            GetRange: GetRange::<Identity, OFFSET>,
-            GetRangeFromPoint: GetRangeFromPoint::<Identity, OFFSET>,
-            GetText: GetText::<Identity, OFFSET>,
-            LoadFromStream: LoadFromStream::<Identity, OFFSET>,
-            Redo: Redo::<Identity, OFFSET>,
-            SaveToStream: SaveToStream::<Identity, OFFSET>,
-            SetDefaultCharacterFormat: SetDefaultCharacterFormat::<Identity, OFFSET>,
-            SetDefaultParagraphFormat: SetDefaultParagraphFormat::<Identity, OFFSET>,
-            SetText: SetText::<Identity, OFFSET>,
-            Undo: Undo::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ITextDocument as windows_core::Interface>::IID
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct ITextDocument_Vtbl {
-    pub base__: windows_core::IInspectable_Vtbl,
-    pub CaretType: unsafe extern "system" fn(*mut core::ffi::c_void, *mut CaretType) -> windows_core::HRESULT,
-    pub SetCaretType: unsafe extern "system" fn(*mut core::ffi::c_void, CaretType) -> windows_core::HRESULT,
-    pub DefaultTabStop: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
-    pub SetDefaultTabStop: unsafe extern "system" fn(*mut core::ffi::c_void, f32) -> windows_core::HRESULT,
-    pub Selection: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub UndoLimit: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
-    pub SetUndoLimit: unsafe extern "system" fn(*mut core::ffi::c_void, u32) -> windows_core::HRESULT,
-    pub CanCopy: unsafe extern "system" fn(*mut core::ffi::c_void, *mut bool) -> windows_core::HRESULT,
-    pub CanPaste: unsafe extern "system" fn(*mut core::ffi::c_void, *mut bool) -> windows_core::HRESULT,
-    pub CanRedo: unsafe extern "system" fn(*mut core::ffi::c_void, *mut bool) -> windows_core::HRESULT,
-    pub CanUndo: unsafe extern "system" fn(*mut core::ffi::c_void, *mut bool) -> windows_core::HRESULT,
-    pub ApplyDisplayUpdates: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
-    pub BatchDisplayUpdates: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
-    pub BeginUndoGroup: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub EndUndoGroup: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetDefaultCharacterFormat: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetDefaultParagraphFormat: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetRange: unsafe extern "system" fn(*mut core::ffi::c_void, i32, i32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetRangeFromPoint: unsafe extern "system" fn(*mut core::ffi::c_void, super::super::Foundation::Point, PointOptions, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetText: unsafe extern "system" fn(*mut core::ffi::c_void, TextGetOptions, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    #[cfg(feature = "Storage_Streams")]
-    pub LoadFromStream: unsafe extern "system" fn(*mut core::ffi::c_void, TextSetOptions, *mut core::ffi::c_void) -> windows_core::HRESULT,
-    #[cfg(not(feature = "Storage_Streams"))]
-    LoadFromStream: usize,
-    pub Redo: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
-    #[cfg(feature = "Storage_Streams")]
-    pub SaveToStream: unsafe extern "system" fn(*mut core::ffi::c_void, TextGetOptions, *mut core::ffi::c_void) -> windows_core::HRESULT,
-    #[cfg(not(feature = "Storage_Streams"))]
-    SaveToStream: usize,
-    pub SetDefaultCharacterFormat: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub SetDefaultParagraphFormat: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub SetText: unsafe extern "system" fn(*mut core::ffi::c_void, TextSetOptions, *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub Undo: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-windows_core::imp::define_interface!(ITextDocument2, ITextDocument2_Vtbl, 0xf2311112_8c89_49c9_9118_f057cbb814ee);
-impl windows_core::RuntimeType for ITextDocument2 {
-    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct ITextDocument2_Vtbl {
-    pub base__: windows_core::IInspectable_Vtbl,
-    pub AlignmentIncludesTrailingWhitespace: unsafe extern "system" fn(*mut core::ffi::c_void, *mut bool) -> windows_core::HRESULT,
-    pub SetAlignmentIncludesTrailingWhitespace: unsafe extern "system" fn(*mut core::ffi::c_void, bool) -> windows_core::HRESULT,
-    pub IgnoreTrailingCharacterSpacing: unsafe extern "system" fn(*mut core::ffi::c_void, *mut bool) -> windows_core::HRESULT,
-    pub SetIgnoreTrailingCharacterSpacing: unsafe extern "system" fn(*mut core::ffi::c_void, bool) -> windows_core::HRESULT,
-}
-windows_core::imp::define_interface!(ITextDocument3, ITextDocument3_Vtbl, 0x75ab03a1_a6f8_441d_aa18_0a851d6e5e3c);
-impl windows_core::RuntimeType for ITextDocument3 {
-    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AllCaps: AllCaps::<Identity>,
+            SetAllCaps: SetAllCaps::<Identity>,
+            BackgroundColor: BackgroundColor::<Identity>,
+            SetBackgroundColor: SetBackgroundColor::<Identity>,
+            Bold: Bold::<Identity>,
+            SetBold: SetBold::<Identity>,
+            FontStretch: FontStretch::<Identity>,
+            SetFontStretch: SetFontStretch::<Identity>,
+            FontStyle: FontStyle::<Identity>,
+            SetFontStyle: SetFontStyle::<Identity>,
+            ForegroundColor: ForegroundColor::<Identity>,
+            SetForegroundColor: SetForegroundColor::<Identity>,
+            Hidden: Hidden::<Identity>,
+            SetHidden: SetHidden::<Identity>,
+            Italic: Italic::<Identity>,
+            SetItalic: SetItalic::<Identity>,
+            Kerning: Kerning::<Identity>,
+            SetKerning: SetKerning::<Identity>,
+            LanguageTag: LanguageTag::<Identity>,
+            SetLanguageTag: SetLanguageTag::<Identity>,
+            LinkType: LinkType::<Identity>,
+            Name: Name::<Identity>,
+            SetName: SetName::<Identity>,
+            Outline: Outline::<Identity>,
+            SetOutline: SetOutline::<Identity>,
+            Position: Position::<Identity>,
+            SetPosition: SetPosition::<Identity>,
+            ProtectedText: ProtectedText::<Identity>,
+            SetProtectedText: SetProtectedText::<Identity>,
+            Size: Size::<Identity>,
+            SetSize: SetSize::<Identity>,
+            SmallCaps: SmallCaps::<Identity>,
+            SetSmallCaps: SetSmallCaps::<Identity>,
+            Spacing: Spacing::<Identity>,
+            SetSpacing: SetSpacing::<Identity>,
+            Strikethrough: Strikethrough::<Identity>,
+            SetStrikethrough: SetStrikethrough::<Identity>,
+            Subscript: Subscript::<Identity>,
+            SetSubscript: SetSubscript::<Identity>,
+            Superscript: Superscript::<Identity>,
+            SetSuperscript: SetSuperscript::<Identity>,
+            TextScript: TextScript::<Identity>,
+            SetTextScript: SetTextScript::<Identity>,
+            Underline: Underline::<Identity>,
+            SetUnderline: SetUnderline::<Identity>,
+            Weight: Weight::<Identity>,
+            SetWeight: SetWeight::<Identity>,
+            SetClone: SetClone::<Identity>,
+            GetClone: GetClone::<Identity>,
+            IsEqual: IsEqual::<Identity>,
+        }
+    };
 }
 #[repr(C)]
 #[doc(hidden)]
-pub struct ITextDocument3_Vtbl {
+pub struct ITextCharacterFormat_Vtbl {
     pub base__: windows_core::IInspectable_Vtbl,
-    pub ClearUndoRedoHistory: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub AllCaps: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
+    pub SetAllCaps: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
+    pub BackgroundColor: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::Color) -> windows_core::HRESULT,
+    pub SetBackgroundColor: unsafe extern "system" fn(*mut core::ffi::c_void, super::Color) -> windows_core::HRESULT,
+    pub Bold: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
+    pub SetBold: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
+    pub FontStretch: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FontStretch) -> windows_core::HRESULT,
+    pub SetFontStretch: unsafe extern "system" fn(*mut core::ffi::c_void, FontStretch) -> windows_core::HRESULT,
+    pub FontStyle: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FontStyle) -> windows_core::HRESULT,
+    pub SetFontStyle: unsafe extern "system" fn(*mut core::ffi::c_void, FontStyle) -> windows_core::HRESULT,
+    pub ForegroundColor: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::Color) -> windows_core::HRESULT,
+    pub SetForegroundColor: unsafe extern "system" fn(*mut core::ffi::c_void, super::Color) -> windows_core::HRESULT,
+    pub Hidden: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
+    pub SetHidden: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
+    pub Italic: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
+    pub SetItalic: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
+    pub Kerning: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
+    pub SetKerning: unsafe extern "system" fn(*mut core::ffi::c_void, f32) -> windows_core::HRESULT,
+    pub LanguageTag: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub SetLanguageTag: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub LinkType: unsafe extern "system" fn(*mut core::ffi::c_void, *mut LinkType) -> windows_core::HRESULT,
+    pub Name: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub SetName: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub Outline: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
+    pub SetOutline: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
+    pub Position: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
+    pub SetPosition: unsafe extern "system" fn(*mut core::ffi::c_void, f32) -> windows_core::HRESULT,
+    pub ProtectedText: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
+    pub SetProtectedText: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
+    pub Size: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
+    pub SetSize: unsafe extern "system" fn(*mut core::ffi::c_void, f32) -> windows_core::HRESULT,
+    pub SmallCaps: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
+    pub SetSmallCaps: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
+    pub Spacing: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
+    pub SetSpacing: unsafe extern "system" fn(*mut core::ffi::c_void, f32) -> windows_core::HRESULT,
+    pub Strikethrough: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
+    pub SetStrikethrough: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
+    pub Subscript: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
+    pub SetSubscript: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
+    pub Superscript: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
+    pub SetSuperscript: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
+    pub TextScript: unsafe extern "system" fn(*mut core::ffi::c_void, *mut TextScript) -> windows_core::HRESULT,
+    pub SetTextScript: unsafe extern "system" fn(*mut core::ffi::c_void, TextScript) -> windows_core::HRESULT,
+    pub Underline: unsafe extern "system" fn(*mut core::ffi::c_void, *mut UnderlineType) -> windows_core::HRESULT,
+    pub SetUnderline: unsafe extern "system" fn(*mut core::ffi::c_void, UnderlineType) -> windows_core::HRESULT,
+    pub Weight: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
+    pub SetWeight: unsafe extern "system" fn(*mut core::ffi::c_void, i32) -> windows_core::HRESULT,
+    pub SetClone: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetClone: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub IsEqual: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut bool) -> windows_core::HRESULT,
 }
-windows_core::imp::define_interface!(ITextDocument4, ITextDocument4_Vtbl, 0x619c20f2_cb3b_4521_981f_2865b1b93f04);
-impl windows_core::RuntimeType for ITextDocument4 {
+windows_core::imp::define_interface!(ITextConstantsStatics, ITextConstantsStatics_Vtbl, 0x779e7c33_189d_4bfa_97c8_10db135d976e);
+impl windows_core::RuntimeType for ITextConstantsStatics {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
 }
 #[repr(C)]
 #[doc(hidden)]
-pub struct ITextDocument4_Vtbl {
+pub struct ITextConstantsStatics_Vtbl {
     pub base__: windows_core::IInspectable_Vtbl,
-    pub SetMath: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetMath: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub SetMathMode: unsafe extern "system" fn(*mut core::ffi::c_void, RichEditMathMode) -> windows_core::HRESULT,
+    pub AutoColor: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::Color) -> windows_core::HRESULT,
+    pub MinUnitCount: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
+    pub MaxUnitCount: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
+    pub UndefinedColor: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::Color) -> windows_core::HRESULT,
+    pub UndefinedFloatValue: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
+    pub UndefinedInt32Value: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
+    pub UndefinedFontStretch: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FontStretch) -> windows_core::HRESULT,
+    pub UndefinedFontStyle: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FontStyle) -> windows_core::HRESULT,
 }
-windows_core::imp::define_interface!(ITextParagraphFormat, ITextParagraphFormat_Vtbl, 0x2cf8cfa6_4676_498a_93f5_bbdbfc0bd883);
-impl windows_core::RuntimeType for ITextParagraphFormat {
+windows_core::imp::define_interface!(ITextDocument, ITextDocument_Vtbl, 0xbeee4ddb_90b2_408c_a2f6_0a0ac31e33e4);
+impl windows_core::RuntimeType for ITextDocument {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
 }
-windows_core::imp::interface_hierarchy!(ITextParagraphFormat, windows_core::IUnknown, windows_core::IInspectable);
-impl ITextParagraphFormat {
-    pub fn Alignment(&self) -> windows_core::Result<ParagraphAlignment> {
+windows_core::imp::interface_hierarchy!(ITextDocument, windows_core::IUnknown, windows_core::IInspectable);
+impl ITextDocument {
+    pub fn CaretType(&self) -> windows_core::Result<CaretType> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).Alignment)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).CaretType)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn SetAlignment(&self, value: ParagraphAlignment) -> windows_core::Result<()> {
+    pub fn SetCaretType(&self, value: CaretType) -> windows_core::Result<()> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetAlignment)(windows_core::Interface::as_raw(this), value).ok() }
+        unsafe { (windows_core::Interface::vtable(this).SetCaretType)(windows_core::Interface::as_raw(this), value).ok() }
     }
-    pub fn FirstLineIndent(&self) -> windows_core::Result<f32> {
+    pub fn DefaultTabStop(&self) -> windows_core::Result<f32> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).FirstLineIndent)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).DefaultTabStop)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn KeepTogether(&self) -> windows_core::Result<FormatEffect> {
+    pub fn SetDefaultTabStop(&self, value: f32) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetDefaultTabStop)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn Selection(&self) -> windows_core::Result<ITextSelection> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).KeepTogether)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).Selection)(windows_core::Interface::as_raw(this), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
     }
-    pub fn SetKeepTogether(&self, value: FormatEffect) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetKeepTogether)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn KeepWithNext(&self) -> windows_core::Result<FormatEffect> {
+    pub fn UndoLimit(&self) -> windows_core::Result<u32> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).KeepWithNext)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).UndoLimit)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn SetKeepWithNext(&self, value: FormatEffect) -> windows_core::Result<()> {
+    pub fn SetUndoLimit(&self, value: u32) -> windows_core::Result<()> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetKeepWithNext)(windows_core::Interface::as_raw(this), value).ok() }
+        unsafe { (windows_core::Interface::vtable(this).SetUndoLimit)(windows_core::Interface::as_raw(this), value).ok() }
     }
-    pub fn LeftIndent(&self) -> windows_core::Result<f32> {
+    pub fn CanCopy(&self) -> windows_core::Result<bool> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).LeftIndent)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).CanCopy)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn LineSpacing(&self) -> windows_core::Result<f32> {
+    pub fn CanPaste(&self) -> windows_core::Result<bool> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).LineSpacing)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).CanPaste)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn LineSpacingRule(&self) -> windows_core::Result<LineSpacingRule> {
+    pub fn CanRedo(&self) -> windows_core::Result<bool> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).LineSpacingRule)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).CanRedo)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn ListAlignment(&self) -> windows_core::Result<MarkerAlignment> {
+    pub fn CanUndo(&self) -> windows_core::Result<bool> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).ListAlignment)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).CanUndo)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn SetListAlignment(&self, value: MarkerAlignment) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetListAlignment)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn ListLevelIndex(&self) -> windows_core::Result<i32> {
+    pub fn ApplyDisplayUpdates(&self) -> windows_core::Result<i32> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).ListLevelIndex)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).ApplyDisplayUpdates)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn SetListLevelIndex(&self, value: i32) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetListLevelIndex)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn ListStart(&self) -> windows_core::Result<i32> {
+    pub fn BatchDisplayUpdates(&self) -> windows_core::Result<i32> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).ListStart)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).BatchDisplayUpdates)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn SetListStart(&self, value: i32) -> windows_core::Result<()> {
+    pub fn BeginUndoGroup(&self) -> windows_core::Result<()> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetListStart)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn ListStyle(&self) -> windows_core::Result<MarkerStyle> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).ListStyle)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
-        }
-    }
-    pub fn SetListStyle(&self, value: MarkerStyle) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetListStyle)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn ListTab(&self) -> windows_core::Result<f32> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).ListTab)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
-        }
-    }
-    pub fn SetListTab(&self, value: f32) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetListTab)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn ListType(&self) -> windows_core::Result<MarkerType> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).ListType)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
-        }
-    }
-    pub fn SetListType(&self, value: MarkerType) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetListType)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn NoLineNumber(&self) -> windows_core::Result<FormatEffect> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).NoLineNumber)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
-        }
-    }
-    pub fn SetNoLineNumber(&self, value: FormatEffect) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetNoLineNumber)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn PageBreakBefore(&self) -> windows_core::Result<FormatEffect> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).PageBreakBefore)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
-        }
-    }
-    pub fn SetPageBreakBefore(&self, value: FormatEffect) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetPageBreakBefore)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn RightIndent(&self) -> windows_core::Result<f32> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).RightIndent)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
-        }
-    }
-    pub fn SetRightIndent(&self, value: f32) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetRightIndent)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn RightToLeft(&self) -> windows_core::Result<FormatEffect> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).RightToLeft)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
-        }
-    }
-    pub fn SetRightToLeft(&self, value: FormatEffect) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetRightToLeft)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn Style(&self) -> windows_core::Result<ParagraphStyle> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).Style)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
-        }
+        unsafe { (windows_core::Interface::vtable(this).BeginUndoGroup)(windows_core::Interface::as_raw(this)).ok() }
     }
-    pub fn SetStyle(&self, value: ParagraphStyle) -> windows_core::Result<()> {
+    pub fn EndUndoGroup(&self) -> windows_core::Result<()> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetStyle)(windows_core::Interface::as_raw(this), value).ok() }
+        unsafe { (windows_core::Interface::vtable(this).EndUndoGroup)(windows_core::Interface::as_raw(this)).ok() }
     }
-    pub fn SpaceAfter(&self) -> windows_core::Result<f32> {
+    pub fn GetDefaultCharacterFormat(&self) -> windows_core::Result<ITextCharacterFormat> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).SpaceAfter)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).GetDefaultCharacterFormat)(windows_core::Interface::as_raw(this), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
     }
-    pub fn SetSpaceAfter(&self, value: f32) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetSpaceAfter)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn SpaceBefore(&self) -> windows_core::Result<f32> {
+    pub fn GetDefaultParagraphFormat(&self) -> windows_core::Result<ITextParagraphFormat> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).SpaceBefore)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).GetDefaultParagraphFormat)(windows_core::Interface::as_raw(this), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
     }
-    pub fn SetSpaceBefore(&self, value: f32) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetSpaceBefore)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn WidowControl(&self) -> windows_core::Result<FormatEffect> {
+    pub fn GetRange(&self, startposition: i32, endposition: i32) -> windows_core::Result<ITextRange> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).WidowControl)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).GetRange)(windows_core::Interface::as_raw(this), startposition, endposition, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
     }
-    pub fn SetWidowControl(&self, value: FormatEffect) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetWidowControl)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn TabCount(&self) -> windows_core::Result<i32> {
+    pub fn GetRangeFromPoint(&self, point: super::super::Foundation::Point, options: PointOptions) -> windows_core::Result<ITextRange> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).TabCount)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).GetRangeFromPoint)(windows_core::Interface::as_raw(this), point, options, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
     }
-    pub fn AddTab(&self, position: f32, align: TabAlignment, leader: TabLeader) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).AddTab)(windows_core::Interface::as_raw(this), position, align, leader).ok() }
-    }
-    pub fn ClearAllTabs(&self) -> windows_core::Result<()> {
+    pub fn GetText(&self, options: TextGetOptions, value: &mut windows_core::HSTRING) -> windows_core::Result<()> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).ClearAllTabs)(windows_core::Interface::as_raw(this)).ok() }
+        unsafe { (windows_core::Interface::vtable(this).GetText)(windows_core::Interface::as_raw(this), options, value as *mut _ as _).ok() }
     }
-    pub fn DeleteTab(&self, position: f32) -> windows_core::Result<()> {
+    #[cfg(feature = "Storage_Streams")]
+    pub fn LoadFromStream<P1>(&self, options: TextSetOptions, value: P1) -> windows_core::Result<()>
+    where
+        P1: windows_core::Param<super::super::Storage::Streams::IRandomAccessStream>,
+    {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).DeleteTab)(windows_core::Interface::as_raw(this), position).ok() }
+        unsafe { (windows_core::Interface::vtable(this).LoadFromStream)(windows_core::Interface::as_raw(this), options, value.param().abi()).ok() }
     }
-    pub fn GetClone(&self) -> windows_core::Result<ITextParagraphFormat> {
+    pub fn Redo(&self) -> windows_core::Result<()> {
         let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).GetClone)(windows_core::Interface::as_raw(this), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
+        unsafe { (windows_core::Interface::vtable(this).Redo)(windows_core::Interface::as_raw(this)).ok() }
     }
-    pub fn GetTab(&self, index: i32, position: &mut f32, align: &mut TabAlignment, leader: &mut TabLeader) -> windows_core::Result<()> {
+    #[cfg(feature = "Storage_Streams")]
+    pub fn SaveToStream<P1>(&self, options: TextGetOptions, value: P1) -> windows_core::Result<()>
+    where
+        P1: windows_core::Param<super::super::Storage::Streams::IRandomAccessStream>,
+    {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).GetTab)(windows_core::Interface::as_raw(this), index, position, align, leader).ok() }
+        unsafe { (windows_core::Interface::vtable(this).SaveToStream)(windows_core::Interface::as_raw(this), options, value.param().abi()).ok() }
     }
-    pub fn IsEqual<P0>(&self, format: P0) -> windows_core::Result<bool>
+    pub fn SetDefaultCharacterFormat<P0>(&self, value: P0) -> windows_core::Result<()>
     where
-        P0: windows_core::Param<ITextParagraphFormat>,
+        P0: windows_core::Param<ITextCharacterFormat>,
     {
         let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).IsEqual)(windows_core::Interface::as_raw(this), format.param().abi(), &mut result__).map(|| result__)
-        }
+        unsafe { (windows_core::Interface::vtable(this).SetDefaultCharacterFormat)(windows_core::Interface::as_raw(this), value.param().abi()).ok() }
     }
-    pub fn SetClone<P0>(&self, format: P0) -> windows_core::Result<()>
+    pub fn SetDefaultParagraphFormat<P0>(&self, value: P0) -> windows_core::Result<()>
     where
         P0: windows_core::Param<ITextParagraphFormat>,
     {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetClone)(windows_core::Interface::as_raw(this), format.param().abi()).ok() }
+        unsafe { (windows_core::Interface::vtable(this).SetDefaultParagraphFormat)(windows_core::Interface::as_raw(this), value.param().abi()).ok() }
     }
-    pub fn SetIndents(&self, start: f32, left: f32, right: f32) -> windows_core::Result<()> {
+    pub fn SetText(&self, options: TextSetOptions, value: &windows_core::HSTRING) -> windows_core::Result<()> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetIndents)(windows_core::Interface::as_raw(this), start, left, right).ok() }
+        unsafe { (windows_core::Interface::vtable(this).SetText)(windows_core::Interface::as_raw(this), options, core::mem::transmute_copy(value)).ok() }
     }
-    pub fn SetLineSpacing(&self, rule: LineSpacingRule, spacing: f32) -> windows_core::Result<()> {
+    pub fn Undo(&self) -> windows_core::Result<()> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetLineSpacing)(windows_core::Interface::as_raw(this), rule, spacing).ok() }
+        unsafe { (windows_core::Interface::vtable(this).Undo)(windows_core::Interface::as_raw(this)).ok() }
     }
 }
-impl windows_core::RuntimeName for ITextParagraphFormat {
-    const NAME: &'static str = "Windows.UI.Text.ITextParagraphFormat";
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::RuntimeName for ITextDocument {
+    const NAME: &'static str = "Windows.UI.Text.ITextDocument";
 }
-pub trait ITextParagraphFormat_Impl: windows_core::IUnknownImpl {
-    fn Alignment(&self) -> windows_core::Result<ParagraphAlignment>;
-    fn SetAlignment(&self, value: ParagraphAlignment) -> windows_core::Result<()>;
-    fn FirstLineIndent(&self) -> windows_core::Result<f32>;
-    fn KeepTogether(&self) -> windows_core::Result<FormatEffect>;
-    fn SetKeepTogether(&self, value: FormatEffect) -> windows_core::Result<()>;
-    fn KeepWithNext(&self) -> windows_core::Result<FormatEffect>;
-    fn SetKeepWithNext(&self, value: FormatEffect) -> windows_core::Result<()>;
-    fn LeftIndent(&self) -> windows_core::Result<f32>;
-    fn LineSpacing(&self) -> windows_core::Result<f32>;
-    fn LineSpacingRule(&self) -> windows_core::Result<LineSpacingRule>;
-    fn ListAlignment(&self) -> windows_core::Result<MarkerAlignment>;
-    fn SetListAlignment(&self, value: MarkerAlignment) -> windows_core::Result<()>;
-    fn ListLevelIndex(&self) -> windows_core::Result<i32>;
-    fn SetListLevelIndex(&self, value: i32) -> windows_core::Result<()>;
-    fn ListStart(&self) -> windows_core::Result<i32>;
-    fn SetListStart(&self, value: i32) -> windows_core::Result<()>;
-    fn ListStyle(&self) -> windows_core::Result<MarkerStyle>;
-    fn SetListStyle(&self, value: MarkerStyle) -> windows_core::Result<()>;
-    fn ListTab(&self) -> windows_core::Result<f32>;
-    fn SetListTab(&self, value: f32) -> windows_core::Result<()>;
-    fn ListType(&self) -> windows_core::Result<MarkerType>;
-    fn SetListType(&self, value: MarkerType) -> windows_core::Result<()>;
-    fn NoLineNumber(&self) -> windows_core::Result<FormatEffect>;
-    fn SetNoLineNumber(&self, value: FormatEffect) -> windows_core::Result<()>;
-    fn PageBreakBefore(&self) -> windows_core::Result<FormatEffect>;
-    fn SetPageBreakBefore(&self, value: FormatEffect) -> windows_core::Result<()>;
-    fn RightIndent(&self) -> windows_core::Result<f32>;
-    fn SetRightIndent(&self, value: f32) -> windows_core::Result<()>;
-    fn RightToLeft(&self) -> windows_core::Result<FormatEffect>;
-    fn SetRightToLeft(&self, value: FormatEffect) -> windows_core::Result<()>;
-    fn Style(&self) -> windows_core::Result<ParagraphStyle>;
-    fn SetStyle(&self, value: ParagraphStyle) -> windows_core::Result<()>;
-    fn SpaceAfter(&self) -> windows_core::Result<f32>;
-    fn SetSpaceAfter(&self, value: f32) -> windows_core::Result<()>;
-    fn SpaceBefore(&self) -> windows_core::Result<f32>;
-    fn SetSpaceBefore(&self, value: f32) -> windows_core::Result<()>;
-    fn WidowControl(&self) -> windows_core::Result<FormatEffect>;
-    fn SetWidowControl(&self, value: FormatEffect) -> windows_core::Result<()>;
-    fn TabCount(&self) -> windows_core::Result<i32>;
-    fn AddTab(&self, position: f32, align: TabAlignment, leader: TabLeader) -> windows_core::Result<()>;
-    fn ClearAllTabs(&self) -> windows_core::Result<()>;
-    fn DeleteTab(&self, position: f32) -> windows_core::Result<()>;
-    fn GetClone(&self) -> windows_core::Result<ITextParagraphFormat>;
-    fn GetTab(&self, index: i32, position: &mut f32, align: &mut TabAlignment, leader: &mut TabLeader) -> windows_core::Result<()>;
-    fn IsEqual(&self, format: windows_core::Ref<ITextParagraphFormat>) -> windows_core::Result<bool>;
-    fn SetClone(&self, format: windows_core::Ref<ITextParagraphFormat>) -> windows_core::Result<()>;
-    fn SetIndents(&self, start: f32, left: f32, right: f32) -> windows_core::Result<()>;
-    fn SetLineSpacing(&self, rule: LineSpacingRule, spacing: f32) -> windows_core::Result<()>;
+#[cfg(feature = "Storage_Streams")]
+pub trait ITextDocument_Impl: windows_core::IUnknownImpl {
+    fn CaretType(&self) -> windows_core::Result<CaretType>;
+    fn SetCaretType(&self, value: CaretType) -> windows_core::Result<()>;
+    fn DefaultTabStop(&self) -> windows_core::Result<f32>;
+    fn SetDefaultTabStop(&self, value: f32) -> windows_core::Result<()>;
+    fn Selection(&self) -> windows_core::Result<ITextSelection>;
+    fn UndoLimit(&self) -> windows_core::Result<u32>;
+    fn SetUndoLimit(&self, value: u32) -> windows_core::Result<()>;
+    fn CanCopy(&self) -> windows_core::Result<bool>;
+    fn CanPaste(&self) -> windows_core::Result<bool>;
+    fn CanRedo(&self) -> windows_core::Result<bool>;
+    fn CanUndo(&self) -> windows_core::Result<bool>;
+    fn ApplyDisplayUpdates(&self) -> windows_core::Result<i32>;
+    fn BatchDisplayUpdates(&self) -> windows_core::Result<i32>;
+    fn BeginUndoGroup(&self) -> windows_core::Result<()>;
+    fn EndUndoGroup(&self) -> windows_core::Result<()>;
+    fn GetDefaultCharacterFormat(&self) -> windows_core::Result<ITextCharacterFormat>;
+    fn GetDefaultParagraphFormat(&self) -> windows_core::Result<ITextParagraphFormat>;
+    fn GetRange(&self, startPosition: i32, endPosition: i32) -> windows_core::Result<ITextRange>;
+    fn GetRangeFromPoint(&self, point: &super::super::Foundation::Point, options: PointOptions) -> windows_core::Result<ITextRange>;
+    fn GetText(&self, options: TextGetOptions, value: &mut windows_core::HSTRING) -> windows_core::Result<()>;
+    fn LoadFromStream(&self, options: TextSetOptions, value: windows_core::Ref<super::super::Storage::Streams::IRandomAccessStream>) -> windows_core::Result<()>;
+    fn Redo(&self) -> windows_core::Result<()>;
+    fn SaveToStream(&self, options: TextGetOptions, value: windows_core::Ref<super::super::Storage::Streams::IRandomAccessStream>) -> windows_core::Result<()>;
+    fn SetDefaultCharacterFormat(&self, value: windows_core::Ref<ITextCharacterFormat>) -> windows_core::Result<()>;
+    fn SetDefaultParagraphFormat(&self, value: windows_core::Ref<ITextParagraphFormat>) -> windows_core::Result<()>;
+    fn SetText(&self, options: TextSetOptions, value: &windows_core::HSTRING) -> windows_core::Result<()>;
+    fn Undo(&self) -> windows_core::Result<()>;
 }
-impl ITextParagraphFormat_Vtbl {
-    pub const fn new<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn Alignment<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut ParagraphAlignment) -> windows_core::HRESULT {
+#[cfg(feature = "Storage_Streams")]
+impl ITextDocument_Vtbl {
+    pub const fn new<Identity: ITextDocument_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn CaretType<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut CaretType) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::Alignment(this) {
+                match ITextDocument_Impl::CaretType(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         windows_core::HRESULT(0)
@@ -2213,16 +2038,16 @@ impl ITextParagraphFormat_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetAlignment<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: ParagraphAlignment) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetCaretType<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: CaretType) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::SetAlignment(this, value).into()
+                ITextDocument_Impl::SetCaretType(this, value).into()
             }
         }
-        unsafe extern "system" fn FirstLineIndent<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn DefaultTabStop<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::FirstLineIndent(this) {
+                match ITextDocument_Impl::DefaultTabStop(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         windows_core::HRESULT(0)
@@ -2231,28 +2056,29 @@ impl ITextParagraphFormat_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn KeepTogether<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetDefaultTabStop<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::KeepTogether(this) {
+                ITextDocument_Impl::SetDefaultTabStop(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Selection<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextDocument_Impl::Selection(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn SetKeepTogether<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::SetKeepTogether(this, value).into()
-            }
-        }
-        unsafe extern "system" fn KeepWithNext<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+        unsafe extern "system" fn UndoLimit<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::KeepWithNext(this) {
+                match ITextDocument_Impl::UndoLimit(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         windows_core::HRESULT(0)
@@ -2261,16 +2087,16 @@ impl ITextParagraphFormat_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetKeepWithNext<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetUndoLimit<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::SetKeepWithNext(this, value).into()
+                ITextDocument_Impl::SetUndoLimit(this, value).into()
             }
         }
-        unsafe extern "system" fn LeftIndent<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn CanCopy<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::LeftIndent(this) {
+                match ITextDocument_Impl::CanCopy(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         windows_core::HRESULT(0)
@@ -2279,10 +2105,10 @@ impl ITextParagraphFormat_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn LineSpacing<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn CanPaste<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::LineSpacing(this) {
+                match ITextDocument_Impl::CanPaste(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         windows_core::HRESULT(0)
@@ -2291,10 +2117,10 @@ impl ITextParagraphFormat_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn LineSpacingRule<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut LineSpacingRule) -> windows_core::HRESULT {
+        unsafe extern "system" fn CanRedo<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::LineSpacingRule(this) {
+                match ITextDocument_Impl::CanRedo(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         windows_core::HRESULT(0)
@@ -2303,10 +2129,10 @@ impl ITextParagraphFormat_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ListAlignment<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut MarkerAlignment) -> windows_core::HRESULT {
+        unsafe extern "system" fn CanUndo<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::ListAlignment(this) {
+                match ITextDocument_Impl::CanUndo(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         windows_core::HRESULT(0)
@@ -2315,16 +2141,10 @@ impl ITextParagraphFormat_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetListAlignment<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: MarkerAlignment) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::SetListAlignment(this, value).into()
-            }
-        }
-        unsafe extern "system" fn ListLevelIndex<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn ApplyDisplayUpdates<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::ListLevelIndex(this) {
+                match ITextDocument_Impl::ApplyDisplayUpdates(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         windows_core::HRESULT(0)
@@ -2333,16 +2153,10 @@ impl ITextParagraphFormat_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetListLevelIndex<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::SetListLevelIndex(this, value).into()
-            }
-        }
-        unsafe extern "system" fn ListStart<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn BatchDisplayUpdates<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::ListStart(this) {
+                match ITextDocument_Impl::BatchDisplayUpdates(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         windows_core::HRESULT(0)
@@ -2351,124 +2165,160 @@ impl ITextParagraphFormat_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetListStart<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn BeginUndoGroup<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::SetListStart(this, value).into()
+                ITextDocument_Impl::BeginUndoGroup(this).into()
             }
         }
-        unsafe extern "system" fn ListStyle<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut MarkerStyle) -> windows_core::HRESULT {
+        unsafe extern "system" fn EndUndoGroup<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::ListStyle(this) {
+                ITextDocument_Impl::EndUndoGroup(this).into()
+            }
+        }
+        unsafe extern "system" fn GetDefaultCharacterFormat<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextDocument_Impl::GetDefaultCharacterFormat(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn SetListStyle<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: MarkerStyle) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::SetListStyle(this, value).into()
-            }
-        }
-        unsafe extern "system" fn ListTab<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetDefaultParagraphFormat<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::ListTab(this) {
+                match ITextDocument_Impl::GetDefaultParagraphFormat(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn SetListTab<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetRange<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, startposition: i32, endposition: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::SetListTab(this, value).into()
+                match ITextDocument_Impl::GetRange(this, startposition, endposition) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
             }
         }
-        unsafe extern "system" fn ListType<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut MarkerType) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetRangeFromPoint<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, point: super::super::Foundation::Point, options: PointOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::ListType(this) {
+                match ITextDocument_Impl::GetRangeFromPoint(this, core::mem::transmute(&point), options) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn SetListType<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: MarkerType) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetText<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: TextGetOptions, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::SetListType(this, value).into()
+                ITextDocument_Impl::GetText(this, options, core::mem::transmute_copy(&value)).into()
             }
         }
-        unsafe extern "system" fn NoLineNumber<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+        unsafe extern "system" fn LoadFromStream<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: TextSetOptions, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::NoLineNumber(this) {
-                    Ok(ok__) => {
-                        result__.write(core::mem::transmute_copy(&ok__));
-                        windows_core::HRESULT(0)
-                    }
-                    Err(err) => err.into(),
-                }
+                ITextDocument_Impl::LoadFromStream(this, options, core::mem::transmute_copy(&value)).into()
             }
         }
-        unsafe extern "system" fn SetNoLineNumber<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+        unsafe extern "system" fn Redo<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::SetNoLineNumber(this, value).into()
+                ITextDocument_Impl::Redo(this).into()
             }
         }
-        unsafe extern "system" fn PageBreakBefore<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+        unsafe extern "system" fn SaveToStream<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: TextGetOptions, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::PageBreakBefore(this) {
-                    Ok(ok__) => {
-                        result__.write(core::mem::transmute_copy(&ok__));
-                        windows_core::HRESULT(0)
-                    }
-                    Err(err) => err.into(),
-                }
+                ITextDocument_Impl::SaveToStream(this, options, core::mem::transmute_copy(&value)).into()
             }
         }
-        unsafe extern "system" fn SetPageBreakBefore<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetDefaultCharacterFormat<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::SetPageBreakBefore(this, value).into()
+                ITextDocument_Impl::SetDefaultCharacterFormat(this, core::mem::transmute_copy(&value)).into()
             }
         }
-        unsafe extern "system" fn RightIndent<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetDefaultParagraphFormat<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::RightIndent(this) {
-                    Ok(ok__) => {
-                        result__.write(core::mem::transmute_copy(&ok__));
-                        windows_core::HRESULT(0)
-                    }
-                    Err(err) => err.into(),
-                }
+                ITextDocument_Impl::SetDefaultParagraphFormat(this, core::mem::transmute_copy(&value)).into()
             }
         }
-        unsafe extern "system" fn SetRightIndent<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetText<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: TextSetOptions, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::SetRightIndent(this, value).into()
+                ITextDocument_Impl::SetText(this, options, core::mem::transmute(&value)).into()
             }
         }
-        unsafe extern "system" fn RightToLeft<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+        unsafe extern "system" fn Undo<Identity: ITextDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::RightToLeft(this) {
+                ITextDocument_Impl::Undo(this).into()
+            }
+        }
+        Self {
+            base__: windows_core::IInspectable_Vtbl::new::<Identity, ITextDocument, OFFSET>(),
+            CaretType: CaretType::<Identity, OFFSET>,
+            SetCaretType: SetCaretType::<Identity, OFFSET>,
+            DefaultTabStop: DefaultTabStop::<Identity, OFFSET>,
+            SetDefaultTabStop: SetDefaultTabStop::<Identity, OFFSET>,
+            Selection: Selection::<Identity, OFFSET>,
+            UndoLimit: UndoLimit::<Identity, OFFSET>,
+            SetUndoLimit: SetUndoLimit::<Identity, OFFSET>,
+            CanCopy: CanCopy::<Identity, OFFSET>,
+            CanPaste: CanPaste::<Identity, OFFSET>,
+            CanRedo: CanRedo::<Identity, OFFSET>,
+            CanUndo: CanUndo::<Identity, OFFSET>,
+            ApplyDisplayUpdates: ApplyDisplayUpdates::<Identity, OFFSET>,
+            BatchDisplayUpdates: BatchDisplayUpdates::<Identity, OFFSET>,
+            BeginUndoGroup: BeginUndoGroup::<Identity, OFFSET>,
+            EndUndoGroup: EndUndoGroup::<Identity, OFFSET>,
+            GetDefaultCharacterFormat: GetDefaultCharacterFormat::<Identity, OFFSET>,
+            GetDefaultParagraphFormat: GetDefaultParagraphFormat::<Identity, OFFSET>,
+            GetRange: GetRange::<Identity, OFFSET>,
+            GetRangeFromPoint: GetRangeFromPoint::<Identity, OFFSET>,
+            GetText: GetText::<Identity, OFFSET>,
+            LoadFromStream: LoadFromStream::<Identity, OFFSET>,
+            Redo: Redo::<Identity, OFFSET>,
+            SaveToStream: SaveToStream::<Identity, OFFSET>,
+            SetDefaultCharacterFormat: SetDefaultCharacterFormat::<Identity, OFFSET>,
+            SetDefaultParagraphFormat: SetDefaultParagraphFormat::<Identity, OFFSET>,
+            SetText: SetText::<Identity, OFFSET>,
+            Undo: Undo::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextDocument as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl<Identity: windows_core::IUnknownImpl + ITextDocument_Impl> windows_core::imp::TearOffVtable<Identity> for ITextDocument_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CaretType<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void, result__: *mut CaretType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextDocument_Impl::CaretType(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         windows_core::HRESULT(0)
@@ -2477,16 +2327,16 @@ impl ITextParagraphFormat_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetRightToLeft<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetCaretType<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void, value: CaretType) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::SetRightToLeft(this, value).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextDocument_Impl::SetCaretType(this, value).into()
             }
         }
-        unsafe extern "system" fn Style<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut ParagraphStyle) -> windows_core::HRESULT {
+        unsafe extern "system" fn DefaultTabStop<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::Style(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextDocument_Impl::DefaultTabStop(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         windows_core::HRESULT(0)
@@ -2495,34 +2345,29 @@ impl ITextParagraphFormat_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetStyle<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: ParagraphStyle) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetDefaultTabStop<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::SetStyle(this, value).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextDocument_Impl::SetDefaultTabStop(this, value).into()
             }
         }
-        unsafe extern "system" fn SpaceAfter<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn Selection<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::SpaceAfter(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextDocument_Impl::Selection(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn SetSpaceAfter<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::SetSpaceAfter(this, value).into()
-            }
-        }
-        unsafe extern "system" fn SpaceBefore<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn UndoLimit<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::SpaceBefore(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextDocument_Impl::UndoLimit(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         windows_core::HRESULT(0)
@@ -2531,16 +2376,16 @@ impl ITextParagraphFormat_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetSpaceBefore<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetUndoLimit<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::SetSpaceBefore(this, value).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextDocument_Impl::SetUndoLimit(this, value).into()
             }
         }
-        unsafe extern "system" fn WidowControl<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+        unsafe extern "system" fn CanCopy<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::WidowControl(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextDocument_Impl::CanCopy(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         windows_core::HRESULT(0)
@@ -2549,16 +2394,22 @@ impl ITextParagraphFormat_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetWidowControl<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+        unsafe extern "system" fn CanPaste<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::SetWidowControl(this, value).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextDocument_Impl::CanPaste(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
             }
         }
-        unsafe extern "system" fn TabCount<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn CanRedo<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::TabCount(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextDocument_Impl::CanRedo(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         windows_core::HRESULT(0)
@@ -2567,28 +2418,58 @@ impl ITextParagraphFormat_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn AddTab<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, position: f32, align: TabAlignment, leader: TabLeader) -> windows_core::HRESULT {
+        unsafe extern "system" fn CanUndo<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::AddTab(this, position, align, leader).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextDocument_Impl::CanUndo(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
             }
         }
-        unsafe extern "system" fn ClearAllTabs<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn ApplyDisplayUpdates<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::ClearAllTabs(this).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextDocument_Impl::ApplyDisplayUpdates(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
             }
         }
-        unsafe extern "system" fn DeleteTab<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, position: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn BatchDisplayUpdates<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::DeleteTab(this, position).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextDocument_Impl::BatchDisplayUpdates(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
             }
         }
-        unsafe extern "system" fn GetClone<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn BeginUndoGroup<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::GetClone(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextDocument_Impl::BeginUndoGroup(this).into()
+            }
+        }
+        unsafe extern "system" fn EndUndoGroup<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextDocument_Impl::EndUndoGroup(this).into()
+            }
+        }
+        unsafe extern "system" fn GetDefaultCharacterFormat<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextDocument_Impl::GetDefaultCharacterFormat(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
                         core::mem::forget(ok__);
@@ -2598,536 +2479,2476 @@ impl ITextParagraphFormat_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetTab<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, index: i32, position: *mut f32, align: *mut TabAlignment, leader: *mut TabLeader) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetDefaultParagraphFormat<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::GetTab(this, index, core::mem::transmute_copy(&position), core::mem::transmute_copy(&align), core::mem::transmute_copy(&leader)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextDocument_Impl::GetDefaultParagraphFormat(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
             }
         }
-        unsafe extern "system" fn IsEqual<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, format: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetRange<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void, startposition: i32, endposition: i32, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ITextParagraphFormat_Impl::IsEqual(this, core::mem::transmute_copy(&format)) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextDocument_Impl::GetRange(this, startposition, endposition) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn SetClone<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, format: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetRangeFromPoint<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void, point: super::super::Foundation::Point, options: PointOptions, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::SetClone(this, core::mem::transmute_copy(&format)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextDocument_Impl::GetRangeFromPoint(this, core::mem::transmute(&point), options) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
             }
         }
-        unsafe extern "system" fn SetIndents<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, start: f32, left: f32, right: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetText<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void, options: TextGetOptions, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::SetIndents(this, start, left, right).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextDocument_Impl::GetText(this, options, core::mem::transmute_copy(&value)).into()
             }
         }
-        unsafe extern "system" fn SetLineSpacing<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, rule: LineSpacingRule, spacing: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn LoadFromStream<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void, options: TextSetOptions, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ITextParagraphFormat_Impl::SetLineSpacing(this, rule, spacing).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextDocument_Impl::LoadFromStream(this, options, core::mem::transmute_copy(&value)).into()
             }
         }
-        Self {
-            base__: windows_core::IInspectable_Vtbl::new::<Identity, ITextParagraphFormat, OFFSET>(),
-            Alignment: Alignment::<Identity, OFFSET>,
-            SetAlignment: SetAlignment::<Identity, OFFSET>,
-            FirstLineIndent: FirstLineIndent::<Identity, OFFSET>,
-            KeepTogether: KeepTogether::<Identity, OFFSET>,
-            SetKeepTogether: SetKeepTogether::<Identity, OFFSET>,
-            KeepWithNext: KeepWithNext::<Identity, OFFSET>,
-            SetKeepWithNext: SetKeepWithNext::<Identity, OFFSET>,
-            LeftIndent: LeftIndent::<Identity, OFFSET>,
-            LineSpacing: LineSpacing::<Identity, OFFSET>,
-            LineSpacingRule: LineSpacingRule::<Identity, OFFSET>,
-            ListAlignment: ListAlignment::<Identity, OFFSET>,
-            SetListAlignment: SetListAlignment::<Identity, OFFSET>,
-            ListLevelIndex: ListLevelIndex::<Identity, OFFSET>,
-            SetListLevelIndex: SetListLevelIndex::<Identity, OFFSET>,
-            ListStart: ListStart::<Identity, OFFSET>,
-            SetListStart: SetListStart::<Identity, OFFSET>,
-            ListStyle: ListStyle::<Identity, OFFSET>,
-            SetListStyle: SetListStyle::<Identity, OFFSET>,
-            ListTab: ListTab::<Identity, OFFSET>,
-            SetListTab: SetListTab::<Identity, OFFSET>,
-            ListType: ListType::<Identity, OFFSET>,
-            SetListType: SetListType::<Identity, OFFSET>,
-            NoLineNumber: NoLineNumber::<Identity, OFFSET>,
-            SetNoLineNumber: SetNoLineNumber::<Identity, OFFSET>,
-            PageBreakBefore: PageBreakBefore::<Identity, OFFSET>,
-            SetPageBreakBefore: SetPageBreakBefore::<Identity, OFFSET>,
-            RightIndent: RightIndent::<Identity, OFFSET>,
-            SetRightIndent: SetRightIndent::<Identity, OFFSET>,
-            RightToLeft: RightToLeft::<Identity, OFFSET>,
-            SetRightToLeft: SetRightToLeft::<Identity, OFFSET>,
-            Style: Style::<Identity, OFFSET>,
-            SetStyle: SetStyle::<Identity, OFFSET>,
-            SpaceAfter: SpaceAfter::<Identity, OFFSET>,
-            SetSpaceAfter: SetSpaceAfter::<Identity, OFFSET>,
-            SpaceBefore: SpaceBefore::<Identity, OFFSET>,
-            SetSpaceBefore: SetSpaceBefore::<Identity, OFFSET>,
-            WidowControl: WidowControl::<Identity, OFFSET>,
-            SetWidowControl: SetWidowControl::<Identity, OFFSET>,
-            TabCount: TabCount::<Identity, OFFSET>,
-            AddTab: AddTab::<Identity, OFFSET>,
-            ClearAllTabs: ClearAllTabs::<Identity, OFFSET>,
-            DeleteTab: DeleteTab::<Identity, OFFSET>,
-            GetClone: GetClone::<Identity, OFFSET>,
-            GetTab: GetTab::<Identity, OFFSET>,
-            IsEqual: IsEqual::<Identity, OFFSET>,
-            SetClone: SetClone::<Identity, OFFSET>,
-            SetIndents: SetIndents::<Identity, OFFSET>,
-            SetLineSpacing: SetLineSpacing::<Identity, OFFSET>,
+        unsafe extern "system" fn Redo<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextDocument_Impl::Redo(this).into()
+            }
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ITextParagraphFormat as windows_core::Interface>::IID
-    }
+        unsafe extern "system" fn SaveToStream<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void, options: TextGetOptions, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextDocument_Impl::SaveToStream(this, options, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn SetDefaultCharacterFormat<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextDocument_Impl::SetDefaultCharacterFormat(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn SetDefaultParagraphFormat<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextDocument_Impl::SetDefaultParagraphFormat(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn SetText<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void, options: TextSetOptions, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextDocument_Impl::SetText(this, options, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Undo<Identity: windows_core::IUnknownImpl + ITextDocument_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextDocument_Impl::Undo(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CaretType: CaretType::<Identity>,
+            SetCaretType: SetCaretType::<Identity>,
+            DefaultTabStop: DefaultTabStop::<Identity>,
+            SetDefaultTabStop: SetDefaultTabStop::<Identity>,
+            Selection: Selection::<Identity>,
+            UndoLimit: UndoLimit::<Identity>,
+            SetUndoLimit: SetUndoLimit::<Identity>,
+            CanCopy: CanCopy::<Identity>,
+            CanPaste: CanPaste::<Identity>,
+            CanRedo: CanRedo::<Identity>,
+            CanUndo: CanUndo::<Identity>,
+            ApplyDisplayUpdates: ApplyDisplayUpdates::<Identity>,
+            BatchDisplayUpdates: BatchDisplayUpdates::<Identity>,
+            BeginUndoGroup: BeginUndoGroup::<Identity>,
+            EndUndoGroup: EndUndoGroup::<Identity>,
+            GetDefaultCharacterFormat: GetDefaultCharacterFormat::<Identity>,
+            GetDefaultParagraphFormat: GetDefaultParagraphFormat::<Identity>,
+            GetRange: GetRange::<Identity>,
+            GetRangeFromPoint: GetRangeFromPoint::<Identity>,
+            GetText: GetText::<Identity>,
+            LoadFromStream: LoadFromStream::<Identity>,
+            Redo: Redo::<Identity>,
+            SaveToStream: SaveToStream::<Identity>,
+            SetDefaultCharacterFormat: SetDefaultCharacterFormat::<Identity>,
+            SetDefaultParagraphFormat: SetDefaultParagraphFormat::<Identity>,
+            SetText: SetText::<Identity>,
+            Undo: Undo::<Identity>,
+        }
+    };
 }
 #[repr(C)]
 #[doc(hidden)]
-pub struct ITextParagraphFormat_Vtbl {
+pub struct ITextDocument_Vtbl {
     pub base__: windows_core::IInspectable_Vtbl,
-    pub Alignment: unsafe extern "system" fn(*mut core::ffi::c_void, *mut ParagraphAlignment) -> windows_core::HRESULT,
-    pub SetAlignment: unsafe extern "system" fn(*mut core::ffi::c_void, ParagraphAlignment) -> windows_core::HRESULT,
-    pub FirstLineIndent: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
-    pub KeepTogether: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
-    pub SetKeepTogether: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
-    pub KeepWithNext: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
-    pub SetKeepWithNext: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
-    pub LeftIndent: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
-    pub LineSpacing: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
-    pub LineSpacingRule: unsafe extern "system" fn(*mut core::ffi::c_void, *mut LineSpacingRule) -> windows_core::HRESULT,
-    pub ListAlignment: unsafe extern "system" fn(*mut core::ffi::c_void, *mut MarkerAlignment) -> windows_core::HRESULT,
-    pub SetListAlignment: unsafe extern "system" fn(*mut core::ffi::c_void, MarkerAlignment) -> windows_core::HRESULT,
-    pub ListLevelIndex: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
-    pub SetListLevelIndex: unsafe extern "system" fn(*mut core::ffi::c_void, i32) -> windows_core::HRESULT,
-    pub ListStart: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
-    pub SetListStart: unsafe extern "system" fn(*mut core::ffi::c_void, i32) -> windows_core::HRESULT,
-    pub ListStyle: unsafe extern "system" fn(*mut core::ffi::c_void, *mut MarkerStyle) -> windows_core::HRESULT,
-    pub SetListStyle: unsafe extern "system" fn(*mut core::ffi::c_void, MarkerStyle) -> windows_core::HRESULT,
-    pub ListTab: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
-    pub SetListTab: unsafe extern "system" fn(*mut core::ffi::c_void, f32) -> windows_core::HRESULT,
-    pub ListType: unsafe extern "system" fn(*mut core::ffi::c_void, *mut MarkerType) -> windows_core::HRESULT,
-    pub SetListType: unsafe extern "system" fn(*mut core::ffi::c_void, MarkerType) -> windows_core::HRESULT,
-    pub NoLineNumber: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
-    pub SetNoLineNumber: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
-    pub PageBreakBefore: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
-    pub SetPageBreakBefore: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
-    pub RightIndent: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
-    pub SetRightIndent: unsafe extern "system" fn(*mut core::ffi::c_void, f32) -> windows_core::HRESULT,
-    pub RightToLeft: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
-    pub SetRightToLeft: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
-    pub Style: unsafe extern "system" fn(*mut core::ffi::c_void, *mut ParagraphStyle) -> windows_core::HRESULT,
-    pub SetStyle: unsafe extern "system" fn(*mut core::ffi::c_void, ParagraphStyle) -> windows_core::HRESULT,
-    pub SpaceAfter: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
-    pub SetSpaceAfter: unsafe extern "system" fn(*mut core::ffi::c_void, f32) -> windows_core::HRESULT,
-    pub SpaceBefore: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
-    pub SetSpaceBefore: unsafe extern "system" fn(*mut core::ffi::c_void, f32) -> windows_core::HRESULT,
-    pub WidowControl: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
-    pub SetWidowControl: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
-    pub TabCount: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
-    pub AddTab: unsafe extern "system" fn(*mut core::ffi::c_void, f32, TabAlignment, TabLeader) -> windows_core::HRESULT,
-    pub ClearAllTabs: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub DeleteTab: unsafe extern "system" fn(*mut core::ffi::c_void, f32) -> windows_core::HRESULT,
-    pub GetClone: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetTab: unsafe extern "system" fn(*mut core::ffi::c_void, i32, *mut f32, *mut TabAlignment, *mut TabLeader) -> windows_core::HRESULT,
-    pub IsEqual: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut bool) -> windows_core::HRESULT,
-    pub SetClone: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub SetIndents: unsafe extern "system" fn(*mut core::ffi::c_void, f32, f32, f32) -> windows_core::HRESULT,
-    pub SetLineSpacing: unsafe extern "system" fn(*mut core::ffi::c_void, LineSpacingRule, f32) -> windows_core::HRESULT,
+    pub CaretType: unsafe extern "system" fn(*mut core::ffi::c_void, *mut CaretType) -> windows_core::HRESULT,
+    pub SetCaretType: unsafe extern "system" fn(*mut core::ffi::c_void, CaretType) -> windows_core::HRESULT,
+    pub DefaultTabStop: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
+    pub SetDefaultTabStop: unsafe extern "system" fn(*mut core::ffi::c_void, f32) -> windows_core::HRESULT,
+    pub Selection: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub UndoLimit: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
+    pub SetUndoLimit: unsafe extern "system" fn(*mut core::ffi::c_void, u32) -> windows_core::HRESULT,
+    pub CanCopy: unsafe extern "system" fn(*mut core::ffi::c_void, *mut bool) -> windows_core::HRESULT,
+    pub CanPaste: unsafe extern "system" fn(*mut core::ffi::c_void, *mut bool) -> windows_core::HRESULT,
+    pub CanRedo: unsafe extern "system" fn(*mut core::ffi::c_void, *mut bool) -> windows_core::HRESULT,
+    pub CanUndo: unsafe extern "system" fn(*mut core::ffi::c_void, *mut bool) -> windows_core::HRESULT,
+    pub ApplyDisplayUpdates: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
+    pub BatchDisplayUpdates: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
+    pub BeginUndoGroup: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub EndUndoGroup: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetDefaultCharacterFormat: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetDefaultParagraphFormat: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetRange: unsafe extern "system" fn(*mut core::ffi::c_void, i32, i32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetRangeFromPoint: unsafe extern "system" fn(*mut core::ffi::c_void, super::super::Foundation::Point, PointOptions, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetText: unsafe extern "system" fn(*mut core::ffi::c_void, TextGetOptions, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    #[cfg(feature = "Storage_Streams")]
+    pub LoadFromStream: unsafe extern "system" fn(*mut core::ffi::c_void, TextSetOptions, *mut core::ffi::c_void) -> windows_core::HRESULT,
+    #[cfg(not(feature = "Storage_Streams"))]
+    LoadFromStream: usize,
+    pub Redo: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+    #[cfg(feature = "Storage_Streams")]
+    pub SaveToStream: unsafe extern "system" fn(*mut core::ffi::c_void, TextGetOptions, *mut core::ffi::c_void) -> windows_core::HRESULT,
+    #[cfg(not(feature = "Storage_Streams"))]
+    SaveToStream: usize,
+    pub SetDefaultCharacterFormat: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub SetDefaultParagraphFormat: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub SetText: unsafe extern "system" fn(*mut core::ffi::c_void, TextSetOptions, *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub Undo: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
 }
-windows_core::imp::define_interface!(ITextRange, ITextRange_Vtbl, 0x5b9e4e57_c072_42a0_8945_af503ee54768);
-impl windows_core::RuntimeType for ITextRange {
+windows_core::imp::define_interface!(ITextDocument2, ITextDocument2_Vtbl, 0xf2311112_8c89_49c9_9118_f057cbb814ee);
+impl windows_core::RuntimeType for ITextDocument2 {
     const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
 }
-windows_core::imp::interface_hierarchy!(ITextRange, windows_core::IUnknown, windows_core::IInspectable);
-impl ITextRange {
-    pub fn Character(&self) -> windows_core::Result<u16> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).Character)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
-        }
-    }
-    pub fn SetCharacter(&self, value: u16) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetCharacter)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn CharacterFormat(&self) -> windows_core::Result<ITextCharacterFormat> {
+#[repr(C)]
+#[doc(hidden)]
+pub struct ITextDocument2_Vtbl {
+    pub base__: windows_core::IInspectable_Vtbl,
+    pub AlignmentIncludesTrailingWhitespace: unsafe extern "system" fn(*mut core::ffi::c_void, *mut bool) -> windows_core::HRESULT,
+    pub SetAlignmentIncludesTrailingWhitespace: unsafe extern "system" fn(*mut core::ffi::c_void, bool) -> windows_core::HRESULT,
+    pub IgnoreTrailingCharacterSpacing: unsafe extern "system" fn(*mut core::ffi::c_void, *mut bool) -> windows_core::HRESULT,
+    pub SetIgnoreTrailingCharacterSpacing: unsafe extern "system" fn(*mut core::ffi::c_void, bool) -> windows_core::HRESULT,
+}
+windows_core::imp::define_interface!(ITextDocument3, ITextDocument3_Vtbl, 0x75ab03a1_a6f8_441d_aa18_0a851d6e5e3c);
+impl windows_core::RuntimeType for ITextDocument3 {
+    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct ITextDocument3_Vtbl {
+    pub base__: windows_core::IInspectable_Vtbl,
+    pub ClearUndoRedoHistory: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+windows_core::imp::define_interface!(ITextDocument4, ITextDocument4_Vtbl, 0x619c20f2_cb3b_4521_981f_2865b1b93f04);
+impl windows_core::RuntimeType for ITextDocument4 {
+    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct ITextDocument4_Vtbl {
+    pub base__: windows_core::IInspectable_Vtbl,
+    pub SetMath: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetMath: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub SetMathMode: unsafe extern "system" fn(*mut core::ffi::c_void, RichEditMathMode) -> windows_core::HRESULT,
+}
+windows_core::imp::define_interface!(ITextParagraphFormat, ITextParagraphFormat_Vtbl, 0x2cf8cfa6_4676_498a_93f5_bbdbfc0bd883);
+impl windows_core::RuntimeType for ITextParagraphFormat {
+    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
+}
+windows_core::imp::interface_hierarchy!(ITextParagraphFormat, windows_core::IUnknown, windows_core::IInspectable);
+impl ITextParagraphFormat {
+    pub fn Alignment(&self) -> windows_core::Result<ParagraphAlignment> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).CharacterFormat)(windows_core::Interface::as_raw(this), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+            (windows_core::Interface::vtable(this).Alignment)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn SetCharacterFormat<P0>(&self, value: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<ITextCharacterFormat>,
-    {
+    pub fn SetAlignment(&self, value: ParagraphAlignment) -> windows_core::Result<()> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetCharacterFormat)(windows_core::Interface::as_raw(this), value.param().abi()).ok() }
+        unsafe { (windows_core::Interface::vtable(this).SetAlignment)(windows_core::Interface::as_raw(this), value).ok() }
     }
-    pub fn FormattedText(&self) -> windows_core::Result<ITextRange> {
+    pub fn FirstLineIndent(&self) -> windows_core::Result<f32> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).FormattedText)(windows_core::Interface::as_raw(this), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+            (windows_core::Interface::vtable(this).FirstLineIndent)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn SetFormattedText<P0>(&self, value: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<ITextRange>,
-    {
+    pub fn KeepTogether(&self) -> windows_core::Result<FormatEffect> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetFormattedText)(windows_core::Interface::as_raw(this), value.param().abi()).ok() }
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).KeepTogether)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        }
     }
-    pub fn EndPosition(&self) -> windows_core::Result<i32> {
+    pub fn SetKeepTogether(&self, value: FormatEffect) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetKeepTogether)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn KeepWithNext(&self) -> windows_core::Result<FormatEffect> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).EndPosition)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).KeepWithNext)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn SetEndPosition(&self, value: i32) -> windows_core::Result<()> {
+    pub fn SetKeepWithNext(&self, value: FormatEffect) -> windows_core::Result<()> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetEndPosition)(windows_core::Interface::as_raw(this), value).ok() }
+        unsafe { (windows_core::Interface::vtable(this).SetKeepWithNext)(windows_core::Interface::as_raw(this), value).ok() }
     }
-    pub fn Gravity(&self) -> windows_core::Result<RangeGravity> {
+    pub fn LeftIndent(&self) -> windows_core::Result<f32> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).Gravity)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).LeftIndent)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn SetGravity(&self, value: RangeGravity) -> windows_core::Result<()> {
+    pub fn LineSpacing(&self) -> windows_core::Result<f32> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetGravity)(windows_core::Interface::as_raw(this), value).ok() }
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).LineSpacing)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        }
     }
-    pub fn Length(&self) -> windows_core::Result<i32> {
+    pub fn LineSpacingRule(&self) -> windows_core::Result<LineSpacingRule> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).Length)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).LineSpacingRule)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn Link(&self) -> windows_core::Result<windows_core::HSTRING> {
+    pub fn ListAlignment(&self) -> windows_core::Result<MarkerAlignment> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).Link)(windows_core::Interface::as_raw(this), &mut result__).map(|| core::mem::transmute(result__))
+            (windows_core::Interface::vtable(this).ListAlignment)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn SetLink(&self, value: &windows_core::HSTRING) -> windows_core::Result<()> {
+    pub fn SetListAlignment(&self, value: MarkerAlignment) -> windows_core::Result<()> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetLink)(windows_core::Interface::as_raw(this), core::mem::transmute_copy(value)).ok() }
+        unsafe { (windows_core::Interface::vtable(this).SetListAlignment)(windows_core::Interface::as_raw(this), value).ok() }
     }
-    pub fn ParagraphFormat(&self) -> windows_core::Result<ITextParagraphFormat> {
+    pub fn ListLevelIndex(&self) -> windows_core::Result<i32> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).ParagraphFormat)(windows_core::Interface::as_raw(this), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+            (windows_core::Interface::vtable(this).ListLevelIndex)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn SetParagraphFormat<P0>(&self, value: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<ITextParagraphFormat>,
-    {
+    pub fn SetListLevelIndex(&self, value: i32) -> windows_core::Result<()> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetParagraphFormat)(windows_core::Interface::as_raw(this), value.param().abi()).ok() }
+        unsafe { (windows_core::Interface::vtable(this).SetListLevelIndex)(windows_core::Interface::as_raw(this), value).ok() }
     }
-    pub fn StartPosition(&self) -> windows_core::Result<i32> {
+    pub fn ListStart(&self) -> windows_core::Result<i32> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).StartPosition)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).ListStart)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn SetStartPosition(&self, value: i32) -> windows_core::Result<()> {
+    pub fn SetListStart(&self, value: i32) -> windows_core::Result<()> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetStartPosition)(windows_core::Interface::as_raw(this), value).ok() }
+        unsafe { (windows_core::Interface::vtable(this).SetListStart)(windows_core::Interface::as_raw(this), value).ok() }
     }
-    pub fn StoryLength(&self) -> windows_core::Result<i32> {
+    pub fn ListStyle(&self) -> windows_core::Result<MarkerStyle> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).StoryLength)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).ListStyle)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn Text(&self) -> windows_core::Result<windows_core::HSTRING> {
+    pub fn SetListStyle(&self, value: MarkerStyle) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetListStyle)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn ListTab(&self) -> windows_core::Result<f32> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).Text)(windows_core::Interface::as_raw(this), &mut result__).map(|| core::mem::transmute(result__))
+            (windows_core::Interface::vtable(this).ListTab)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn SetText(&self, value: &windows_core::HSTRING) -> windows_core::Result<()> {
+    pub fn SetListTab(&self, value: f32) -> windows_core::Result<()> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetText)(windows_core::Interface::as_raw(this), core::mem::transmute_copy(value)).ok() }
+        unsafe { (windows_core::Interface::vtable(this).SetListTab)(windows_core::Interface::as_raw(this), value).ok() }
     }
-    pub fn CanPaste(&self, format: i32) -> windows_core::Result<bool> {
+    pub fn ListType(&self) -> windows_core::Result<MarkerType> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).CanPaste)(windows_core::Interface::as_raw(this), format, &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).ListType)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn ChangeCase(&self, value: LetterCase) -> windows_core::Result<()> {
+    pub fn SetListType(&self, value: MarkerType) -> windows_core::Result<()> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).ChangeCase)(windows_core::Interface::as_raw(this), value).ok() }
+        unsafe { (windows_core::Interface::vtable(this).SetListType)(windows_core::Interface::as_raw(this), value).ok() }
     }
-    pub fn Collapse(&self, value: bool) -> windows_core::Result<()> {
+    pub fn NoLineNumber(&self) -> windows_core::Result<FormatEffect> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).Collapse)(windows_core::Interface::as_raw(this), value).ok() }
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).NoLineNumber)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        }
     }
-    pub fn Copy(&self) -> windows_core::Result<()> {
+    pub fn SetNoLineNumber(&self, value: FormatEffect) -> windows_core::Result<()> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).Copy)(windows_core::Interface::as_raw(this)).ok() }
+        unsafe { (windows_core::Interface::vtable(this).SetNoLineNumber)(windows_core::Interface::as_raw(this), value).ok() }
     }
-    pub fn Cut(&self) -> windows_core::Result<()> {
+    pub fn PageBreakBefore(&self) -> windows_core::Result<FormatEffect> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).Cut)(windows_core::Interface::as_raw(this)).ok() }
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).PageBreakBefore)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        }
     }
-    pub fn Delete(&self, unit: TextRangeUnit, count: i32) -> windows_core::Result<i32> {
+    pub fn SetPageBreakBefore(&self, value: FormatEffect) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetPageBreakBefore)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn RightIndent(&self) -> windows_core::Result<f32> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).Delete)(windows_core::Interface::as_raw(this), unit, count, &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).RightIndent)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn EndOf(&self, unit: TextRangeUnit, extend: bool) -> windows_core::Result<i32> {
+    pub fn SetRightIndent(&self, value: f32) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetRightIndent)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn RightToLeft(&self) -> windows_core::Result<FormatEffect> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).EndOf)(windows_core::Interface::as_raw(this), unit, extend, &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).RightToLeft)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn Expand(&self, unit: TextRangeUnit) -> windows_core::Result<i32> {
+    pub fn SetRightToLeft(&self, value: FormatEffect) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetRightToLeft)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn Style(&self) -> windows_core::Result<ParagraphStyle> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).Expand)(windows_core::Interface::as_raw(this), unit, &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).Style)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn FindText(&self, value: &windows_core::HSTRING, scanlength: i32, options: FindOptions) -> windows_core::Result<i32> {
+    pub fn SetStyle(&self, value: ParagraphStyle) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetStyle)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn SpaceAfter(&self) -> windows_core::Result<f32> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).FindText)(windows_core::Interface::as_raw(this), core::mem::transmute_copy(value), scanlength, options, &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).SpaceAfter)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn GetCharacterUtf32(&self, value: &mut u32, offset: i32) -> windows_core::Result<()> {
+    pub fn SetSpaceAfter(&self, value: f32) -> windows_core::Result<()> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).GetCharacterUtf32)(windows_core::Interface::as_raw(this), value, offset).ok() }
+        unsafe { (windows_core::Interface::vtable(this).SetSpaceAfter)(windows_core::Interface::as_raw(this), value).ok() }
     }
-    pub fn GetClone(&self) -> windows_core::Result<ITextRange> {
+    pub fn SpaceBefore(&self) -> windows_core::Result<f32> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).GetClone)(windows_core::Interface::as_raw(this), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+            (windows_core::Interface::vtable(this).SpaceBefore)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn GetIndex(&self, unit: TextRangeUnit) -> windows_core::Result<i32> {
+    pub fn SetSpaceBefore(&self, value: f32) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetSpaceBefore)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn WidowControl(&self) -> windows_core::Result<FormatEffect> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).GetIndex)(windows_core::Interface::as_raw(this), unit, &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).WidowControl)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
         }
     }
-    pub fn GetPoint(&self, horizontalalign: HorizontalCharacterAlignment, verticalalign: VerticalCharacterAlignment, options: PointOptions, point: &mut super::super::Foundation::Point) -> windows_core::Result<()> {
+    pub fn SetWidowControl(&self, value: FormatEffect) -> windows_core::Result<()> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).GetPoint)(windows_core::Interface::as_raw(this), horizontalalign, verticalalign, options, point).ok() }
+        unsafe { (windows_core::Interface::vtable(this).SetWidowControl)(windows_core::Interface::as_raw(this), value).ok() }
     }
-    pub fn GetRect(&self, options: PointOptions, rect: &mut super::super::Foundation::Rect, hit: &mut i32) -> windows_core::Result<()> {
+    pub fn TabCount(&self) -> windows_core::Result<i32> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).GetRect)(windows_core::Interface::as_raw(this), options, rect, hit).ok() }
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).TabCount)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        }
     }
-    pub fn GetText(&self, options: TextGetOptions, value: &mut windows_core::HSTRING) -> windows_core::Result<()> {
+    pub fn AddTab(&self, position: f32, align: TabAlignment, leader: TabLeader) -> windows_core::Result<()> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).GetText)(windows_core::Interface::as_raw(this), options, value as *mut _ as _).ok() }
+        unsafe { (windows_core::Interface::vtable(this).AddTab)(windows_core::Interface::as_raw(this), position, align, leader).ok() }
     }
-    #[cfg(feature = "Storage_Streams")]
-    pub fn GetTextViaStream<P1>(&self, options: TextGetOptions, value: P1) -> windows_core::Result<()>
-    where
-        P1: windows_core::Param<super::super::Storage::Streams::IRandomAccessStream>,
-    {
+    pub fn ClearAllTabs(&self) -> windows_core::Result<()> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).GetTextViaStream)(windows_core::Interface::as_raw(this), options, value.param().abi()).ok() }
+        unsafe { (windows_core::Interface::vtable(this).ClearAllTabs)(windows_core::Interface::as_raw(this)).ok() }
     }
-    pub fn InRange<P0>(&self, range: P0) -> windows_core::Result<bool>
-    where
-        P0: windows_core::Param<ITextRange>,
-    {
+    pub fn DeleteTab(&self, position: f32) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).DeleteTab)(windows_core::Interface::as_raw(this), position).ok() }
+    }
+    pub fn GetClone(&self) -> windows_core::Result<ITextParagraphFormat> {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).InRange)(windows_core::Interface::as_raw(this), range.param().abi(), &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).GetClone)(windows_core::Interface::as_raw(this), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
     }
-    #[cfg(feature = "Storage_Streams")]
-    pub fn InsertImage<P5>(&self, width: i32, height: i32, ascent: i32, verticalalign: VerticalCharacterAlignment, alternatetext: &windows_core::HSTRING, value: P5) -> windows_core::Result<()>
-    where
-        P5: windows_core::Param<super::super::Storage::Streams::IRandomAccessStream>,
-    {
+    pub fn GetTab(&self, index: i32, position: &mut f32, align: &mut TabAlignment, leader: &mut TabLeader) -> windows_core::Result<()> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).InsertImage)(windows_core::Interface::as_raw(this), width, height, ascent, verticalalign, core::mem::transmute_copy(alternatetext), value.param().abi()).ok() }
+        unsafe { (windows_core::Interface::vtable(this).GetTab)(windows_core::Interface::as_raw(this), index, position, align, leader).ok() }
     }
-    pub fn InStory<P0>(&self, range: P0) -> windows_core::Result<bool>
+    pub fn IsEqual<P0>(&self, format: P0) -> windows_core::Result<bool>
     where
-        P0: windows_core::Param<ITextRange>,
+        P0: windows_core::Param<ITextParagraphFormat>,
     {
         let this = self;
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).InStory)(windows_core::Interface::as_raw(this), range.param().abi(), &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(this).IsEqual)(windows_core::Interface::as_raw(this), format.param().abi(), &mut result__).map(|| result__)
         }
     }
-    pub fn IsEqual<P0>(&self, range: P0) -> windows_core::Result<bool>
+    pub fn SetClone<P0>(&self, format: P0) -> windows_core::Result<()>
     where
-        P0: windows_core::Param<ITextRange>,
+        P0: windows_core::Param<ITextParagraphFormat>,
     {
         let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).IsEqual)(windows_core::Interface::as_raw(this), range.param().abi(), &mut result__).map(|| result__)
-        }
-    }
-    pub fn Move(&self, unit: TextRangeUnit, count: i32) -> windows_core::Result<i32> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).Move)(windows_core::Interface::as_raw(this), unit, count, &mut result__).map(|| result__)
-        }
-    }
-    pub fn MoveEnd(&self, unit: TextRangeUnit, count: i32) -> windows_core::Result<i32> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).MoveEnd)(windows_core::Interface::as_raw(this), unit, count, &mut result__).map(|| result__)
-        }
-    }
-    pub fn MoveStart(&self, unit: TextRangeUnit, count: i32) -> windows_core::Result<i32> {
-        let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).MoveStart)(windows_core::Interface::as_raw(this), unit, count, &mut result__).map(|| result__)
-        }
-    }
-    pub fn Paste(&self, format: i32) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).Paste)(windows_core::Interface::as_raw(this), format).ok() }
-    }
-    pub fn ScrollIntoView(&self, value: PointOptions) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).ScrollIntoView)(windows_core::Interface::as_raw(this), value).ok() }
-    }
-    pub fn MatchSelection(&self) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).MatchSelection)(windows_core::Interface::as_raw(this)).ok() }
-    }
-    pub fn SetIndex(&self, unit: TextRangeUnit, index: i32, extend: bool) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetIndex)(windows_core::Interface::as_raw(this), unit, index, extend).ok() }
-    }
-    pub fn SetPoint(&self, point: super::super::Foundation::Point, options: PointOptions, extend: bool) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetPoint)(windows_core::Interface::as_raw(this), point, options, extend).ok() }
-    }
-    pub fn SetRange(&self, startposition: i32, endposition: i32) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetRange)(windows_core::Interface::as_raw(this), startposition, endposition).ok() }
-    }
-    pub fn SetText2(&self, options: TextSetOptions, value: &windows_core::HSTRING) -> windows_core::Result<()> {
-        let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetText2)(windows_core::Interface::as_raw(this), options, core::mem::transmute_copy(value)).ok() }
+        unsafe { (windows_core::Interface::vtable(this).SetClone)(windows_core::Interface::as_raw(this), format.param().abi()).ok() }
     }
-    #[cfg(feature = "Storage_Streams")]
-    pub fn SetTextViaStream<P1>(&self, options: TextSetOptions, value: P1) -> windows_core::Result<()>
-    where
-        P1: windows_core::Param<super::super::Storage::Streams::IRandomAccessStream>,
-    {
+    pub fn SetIndents(&self, start: f32, left: f32, right: f32) -> windows_core::Result<()> {
         let this = self;
-        unsafe { (windows_core::Interface::vtable(this).SetTextViaStream)(windows_core::Interface::as_raw(this), options, value.param().abi()).ok() }
+        unsafe { (windows_core::Interface::vtable(this).SetIndents)(windows_core::Interface::as_raw(this), start, left, right).ok() }
     }
-    pub fn StartOf(&self, unit: TextRangeUnit, extend: bool) -> windows_core::Result<i32> {
+    pub fn SetLineSpacing(&self, rule: LineSpacingRule, spacing: f32) -> windows_core::Result<()> {
         let this = self;
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(this).StartOf)(windows_core::Interface::as_raw(this), unit, extend, &mut result__).map(|| result__)
-        }
+        unsafe { (windows_core::Interface::vtable(this).SetLineSpacing)(windows_core::Interface::as_raw(this), rule, spacing).ok() }
     }
 }
-#[cfg(feature = "Storage_Streams")]
-impl windows_core::RuntimeName for ITextRange {
-    const NAME: &'static str = "Windows.UI.Text.ITextRange";
+impl windows_core::RuntimeName for ITextParagraphFormat {
+    const NAME: &'static str = "Windows.UI.Text.ITextParagraphFormat";
 }
-#[cfg(feature = "Storage_Streams")]
-pub trait ITextRange_Impl: windows_core::IUnknownImpl {
-    fn Character(&self) -> windows_core::Result<u16>;
-    fn SetCharacter(&self, value: u16) -> windows_core::Result<()>;
-    fn CharacterFormat(&self) -> windows_core::Result<ITextCharacterFormat>;
-    fn SetCharacterFormat(&self, value: windows_core::Ref<ITextCharacterFormat>) -> windows_core::Result<()>;
-    fn FormattedText(&self) -> windows_core::Result<ITextRange>;
-    fn SetFormattedText(&self, value: windows_core::Ref<ITextRange>) -> windows_core::Result<()>;
-    fn EndPosition(&self) -> windows_core::Result<i32>;
-    fn SetEndPosition(&self, value: i32) -> windows_core::Result<()>;
-    fn Gravity(&self) -> windows_core::Result<RangeGravity>;
-    fn SetGravity(&self, value: RangeGravity) -> windows_core::Result<()>;
-    fn Length(&self) -> windows_core::Result<i32>;
-    fn Link(&self) -> windows_core::Result<windows_core::HSTRING>;
-    fn SetLink(&self, value: &windows_core::HSTRING) -> windows_core::Result<()>;
-    fn ParagraphFormat(&self) -> windows_core::Result<ITextParagraphFormat>;
-    fn SetParagraphFormat(&self, value: windows_core::Ref<ITextParagraphFormat>) -> windows_core::Result<()>;
-    fn StartPosition(&self) -> windows_core::Result<i32>;
-    fn SetStartPosition(&self, value: i32) -> windows_core::Result<()>;
-    fn StoryLength(&self) -> windows_core::Result<i32>;
-    fn Text(&self) -> windows_core::Result<windows_core::HSTRING>;
-    fn SetText(&self, value: &windows_core::HSTRING) -> windows_core::Result<()>;
-    fn CanPaste(&self, format: i32) -> windows_core::Result<bool>;
-    fn ChangeCase(&self, value: LetterCase) -> windows_core::Result<()>;
-    fn Collapse(&self, value: bool) -> windows_core::Result<()>;
-    fn Copy(&self) -> windows_core::Result<()>;
-    fn Cut(&self) -> windows_core::Result<()>;
-    fn Delete(&self, unit: TextRangeUnit, count: i32) -> windows_core::Result<i32>;
-    fn EndOf(&self, unit: TextRangeUnit, extend: bool) -> windows_core::Result<i32>;
-    fn Expand(&self, unit: TextRangeUnit) -> windows_core::Result<i32>;
-    fn FindText(&self, value: &windows_core::HSTRING, scanLength: i32, options: FindOptions) -> windows_core::Result<i32>;
-    fn GetCharacterUtf32(&self, value: &mut u32, offset: i32) -> windows_core::Result<()>;
-    fn GetClone(&self) -> windows_core::Result<ITextRange>;
-    fn GetIndex(&self, unit: TextRangeUnit) -> windows_core::Result<i32>;
-    fn GetPoint(&self, horizontalAlign: HorizontalCharacterAlignment, verticalAlign: VerticalCharacterAlignment, options: PointOptions, point: &mut super::super::Foundation::Point) -> windows_core::Result<()>;
-    fn GetRect(&self, options: PointOptions, rect: &mut super::super::Foundation::Rect, hit: &mut i32) -> windows_core::Result<()>;
-    fn GetText(&self, options: TextGetOptions, value: &mut windows_core::HSTRING) -> windows_core::Result<()>;
-    fn GetTextViaStream(&self, options: TextGetOptions, value: windows_core::Ref<super::super::Storage::Streams::IRandomAccessStream>) -> windows_core::Result<()>;
-    fn InRange(&self, range: windows_core::Ref<ITextRange>) -> windows_core::Result<bool>;
-    fn InsertImage(&self, width: i32, height: i32, ascent: i32, verticalAlign: VerticalCharacterAlignment, alternateText: &windows_core::HSTRING, value: windows_core::Ref<super::super::Storage::Streams::IRandomAccessStream>) -> windows_core::Result<()>;
-    fn InStory(&self, range: windows_core::Ref<ITextRange>) -> windows_core::Result<bool>;
-    fn IsEqual(&self, range: windows_core::Ref<ITextRange>) -> windows_core::Result<bool>;
-    fn Move(&self, unit: TextRangeUnit, count: i32) -> windows_core::Result<i32>;
-    fn MoveEnd(&self, unit: TextRangeUnit, count: i32) -> windows_core::Result<i32>;
-    fn MoveStart(&self, unit: TextRangeUnit, count: i32) -> windows_core::Result<i32>;
-    fn Paste(&self, format: i32) -> windows_core::Result<()>;
-    fn ScrollIntoView(&self, value: PointOptions) -> windows_core::Result<()>;
-    fn MatchSelection(&self) -> windows_core::Result<()>;
-    fn SetIndex(&self, unit: TextRangeUnit, index: i32, extend: bool) -> windows_core::Result<()>;
-    fn SetPoint(&self, point: &super::super::Foundation::Point, options: PointOptions, extend: bool) -> windows_core::Result<()>;
-    fn SetRange(&self, startPosition: i32, endPosition: i32) -> windows_core::Result<()>;
-    fn SetText2(&self, options: TextSetOptions, value: &windows_core::HSTRING) -> windows_core::Result<()>;
-    fn SetTextViaStream(&self, options: TextSetOptions, value: windows_core::Ref<super::super::Storage::Streams::IRandomAccessStream>) -> windows_core::Result<()>;
-    fn StartOf(&self, unit: TextRangeUnit, extend: bool) -> windows_core::Result<i32>;
+pub trait ITextParagraphFormat_Impl: windows_core::IUnknownImpl {
+    fn Alignment(&self) -> windows_core::Result<ParagraphAlignment>;
+    fn SetAlignment(&self, value: ParagraphAlignment) -> windows_core::Result<()>;
+    fn FirstLineIndent(&self) -> windows_core::Result<f32>;
+    fn KeepTogether(&self) -> windows_core::Result<FormatEffect>;
+    fn SetKeepTogether(&self, value: FormatEffect) -> windows_core::Result<()>;
+    fn KeepWithNext(&self) -> windows_core::Result<FormatEffect>;
+    fn SetKeepWithNext(&self, value: FormatEffect) -> windows_core::Result<()>;
+    fn LeftIndent(&self) -> windows_core::Result<f32>;
+    fn LineSpacing(&self) -> windows_core::Result<f32>;
+    fn LineSpacingRule(&self) -> windows_core::Result<LineSpacingRule>;
+    fn ListAlignment(&self) -> windows_core::Result<MarkerAlignment>;
+    fn SetListAlignment(&self, value: MarkerAlignment) -> windows_core::Result<()>;
+    fn ListLevelIndex(&self) -> windows_core::Result<i32>;
+    fn SetListLevelIndex(&self, value: i32) -> windows_core::Result<()>;
+    fn ListStart(&self) -> windows_core::Result<i32>;
+    fn SetListStart(&self, value: i32) -> windows_core::Result<()>;
+    fn ListStyle(&self) -> windows_core::Result<MarkerStyle>;
+    fn SetListStyle(&self, value: MarkerStyle) -> windows_core::Result<()>;
+    fn ListTab(&self) -> windows_core::Result<f32>;
+    fn SetListTab(&self, value: f32) -> windows_core::Result<()>;
+    fn ListType(&self) -> windows_core::Result<MarkerType>;
+    fn SetListType(&self, value: MarkerType) -> windows_core::Result<()>;
+    fn NoLineNumber(&self) -> windows_core::Result<FormatEffect>;
+    fn SetNoLineNumber(&self, value: FormatEffect) -> windows_core::Result<()>;
+    fn PageBreakBefore(&self) -> windows_core::Result<FormatEffect>;
+    fn SetPageBreakBefore(&self, value: FormatEffect) -> windows_core::Result<()>;
+    fn RightIndent(&self) -> windows_core::Result<f32>;
+    fn SetRightIndent(&self, value: f32) -> windows_core::Result<()>;
+    fn RightToLeft(&self) -> windows_core::Result<FormatEffect>;
+    fn SetRightToLeft(&self, value: FormatEffect) -> windows_core::Result<()>;
+    fn Style(&self) -> windows_core::Result<ParagraphStyle>;
+    fn SetStyle(&self, value: ParagraphStyle) -> windows_core::Result<()>;
+    fn SpaceAfter(&self) -> windows_core::Result<f32>;
+    fn SetSpaceAfter(&self, value: f32) -> windows_core::Result<()>;
+    fn SpaceBefore(&self) -> windows_core::Result<f32>;
+    fn SetSpaceBefore(&self, value: f32) -> windows_core::Result<()>;
+    fn WidowControl(&self) -> windows_core::Result<FormatEffect>;
+    fn SetWidowControl(&self, value: FormatEffect) -> windows_core::Result<()>;
+    fn TabCount(&self) -> windows_core::Result<i32>;
+    fn AddTab(&self, position: f32, align: TabAlignment, leader: TabLeader) -> windows_core::Result<()>;
+    fn ClearAllTabs(&self) -> windows_core::Result<()>;
+    fn DeleteTab(&self, position: f32) -> windows_core::Result<()>;
+    fn GetClone(&self) -> windows_core::Result<ITextParagraphFormat>;
+    fn GetTab(&self, index: i32, position: &mut f32, align: &mut TabAlignment, leader: &mut TabLeader) -> windows_core::Result<()>;
+    fn IsEqual(&self, format: windows_core::Ref<ITextParagraphFormat>) -> windows_core::Result<bool>;
+    fn SetClone(&self, format: windows_core::Ref<ITextParagraphFormat>) -> windows_core::Result<()>;
+    fn SetIndents(&self, start: f32, left: f32, right: f32) -> windows_core::Result<()>;
+    fn SetLineSpacing(&self, rule: LineSpacingRule, spacing: f32) -> windows_core::Result<()>;
+}
+impl ITextParagraphFormat_Vtbl {
+    pub const fn new<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn Alignment<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut ParagraphAlignment) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::Alignment(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAlignment<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: ParagraphAlignment) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::SetAlignment(this, value).into()
+            }
+        }
+        unsafe extern "system" fn FirstLineIndent<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::FirstLineIndent(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn KeepTogether<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::KeepTogether(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetKeepTogether<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::SetKeepTogether(this, value).into()
+            }
+        }
+        unsafe extern "system" fn KeepWithNext<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::KeepWithNext(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetKeepWithNext<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::SetKeepWithNext(this, value).into()
+            }
+        }
+        unsafe extern "system" fn LeftIndent<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::LeftIndent(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LineSpacing<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::LineSpacing(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LineSpacingRule<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut LineSpacingRule) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::LineSpacingRule(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ListAlignment<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut MarkerAlignment) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::ListAlignment(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetListAlignment<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: MarkerAlignment) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::SetListAlignment(this, value).into()
+            }
+        }
+        unsafe extern "system" fn ListLevelIndex<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::ListLevelIndex(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetListLevelIndex<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::SetListLevelIndex(this, value).into()
+            }
+        }
+        unsafe extern "system" fn ListStart<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::ListStart(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetListStart<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::SetListStart(this, value).into()
+            }
+        }
+        unsafe extern "system" fn ListStyle<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut MarkerStyle) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::ListStyle(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetListStyle<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: MarkerStyle) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::SetListStyle(this, value).into()
+            }
+        }
+        unsafe extern "system" fn ListTab<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::ListTab(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetListTab<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::SetListTab(this, value).into()
+            }
+        }
+        unsafe extern "system" fn ListType<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut MarkerType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::ListType(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetListType<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: MarkerType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::SetListType(this, value).into()
+            }
+        }
+        unsafe extern "system" fn NoLineNumber<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::NoLineNumber(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNoLineNumber<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::SetNoLineNumber(this, value).into()
+            }
+        }
+        unsafe extern "system" fn PageBreakBefore<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::PageBreakBefore(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPageBreakBefore<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::SetPageBreakBefore(this, value).into()
+            }
+        }
+        unsafe extern "system" fn RightIndent<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::RightIndent(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRightIndent<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::SetRightIndent(this, value).into()
+            }
+        }
+        unsafe extern "system" fn RightToLeft<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::RightToLeft(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRightToLeft<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::SetRightToLeft(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Style<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut ParagraphStyle) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::Style(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetStyle<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: ParagraphStyle) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::SetStyle(this, value).into()
+            }
+        }
+        unsafe extern "system" fn SpaceAfter<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::SpaceAfter(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSpaceAfter<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::SetSpaceAfter(this, value).into()
+            }
+        }
+        unsafe extern "system" fn SpaceBefore<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::SpaceBefore(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSpaceBefore<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::SetSpaceBefore(this, value).into()
+            }
+        }
+        unsafe extern "system" fn WidowControl<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::WidowControl(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetWidowControl<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::SetWidowControl(this, value).into()
+            }
+        }
+        unsafe extern "system" fn TabCount<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::TabCount(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddTab<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, position: f32, align: TabAlignment, leader: TabLeader) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::AddTab(this, position, align, leader).into()
+            }
+        }
+        unsafe extern "system" fn ClearAllTabs<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::ClearAllTabs(this).into()
+            }
+        }
+        unsafe extern "system" fn DeleteTab<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, position: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::DeleteTab(this, position).into()
+            }
+        }
+        unsafe extern "system" fn GetClone<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::GetClone(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTab<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, index: i32, position: *mut f32, align: *mut TabAlignment, leader: *mut TabLeader) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::GetTab(this, index, core::mem::transmute_copy(&position), core::mem::transmute_copy(&align), core::mem::transmute_copy(&leader)).into()
+            }
+        }
+        unsafe extern "system" fn IsEqual<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, format: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextParagraphFormat_Impl::IsEqual(this, core::mem::transmute_copy(&format)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetClone<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, format: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::SetClone(this, core::mem::transmute_copy(&format)).into()
+            }
+        }
+        unsafe extern "system" fn SetIndents<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, start: f32, left: f32, right: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::SetIndents(this, start, left, right).into()
+            }
+        }
+        unsafe extern "system" fn SetLineSpacing<Identity: ITextParagraphFormat_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, rule: LineSpacingRule, spacing: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextParagraphFormat_Impl::SetLineSpacing(this, rule, spacing).into()
+            }
+        }
+        Self {
+            base__: windows_core::IInspectable_Vtbl::new::<Identity, ITextParagraphFormat, OFFSET>(),
+            Alignment: Alignment::<Identity, OFFSET>,
+            SetAlignment: SetAlignment::<Identity, OFFSET>,
+            FirstLineIndent: FirstLineIndent::<Identity, OFFSET>,
+            KeepTogether: KeepTogether::<Identity, OFFSET>,
+            SetKeepTogether: SetKeepTogether::<Identity, OFFSET>,
+            KeepWithNext: KeepWithNext::<Identity, OFFSET>,
+            SetKeepWithNext: SetKeepWithNext::<Identity, OFFSET>,
+            LeftIndent: LeftIndent::<Identity, OFFSET>,
+            LineSpacing: LineSpacing::<Identity, OFFSET>,
+            LineSpacingRule: LineSpacingRule::<Identity, OFFSET>,
+            ListAlignment: ListAlignment::<Identity, OFFSET>,
+            SetListAlignment: SetListAlignment::<Identity, OFFSET>,
+            ListLevelIndex: ListLevelIndex::<Identity, OFFSET>,
+            SetListLevelIndex: SetListLevelIndex::<Identity, OFFSET>,
+            ListStart: ListStart::<Identity, OFFSET>,
+            SetListStart: SetListStart::<Identity, OFFSET>,
+            ListStyle: ListStyle::<Identity, OFFSET>,
+            SetListStyle: SetListStyle::<Identity, OFFSET>,
+            ListTab: ListTab::<Identity, OFFSET>,
+            SetListTab: SetListTab::<Identity, OFFSET>,
+            ListType: ListType::<Identity, OFFSET>,
+            SetListType: SetListType::<Identity, OFFSET>,
+            NoLineNumber: NoLineNumber::<Identity, OFFSET>,
+            SetNoLineNumber: SetNoLineNumber::<Identity, OFFSET>,
+            PageBreakBefore: PageBreakBefore::<Identity, OFFSET>,
+            SetPageBreakBefore: SetPageBreakBefore::<Identity, OFFSET>,
+            RightIndent: RightIndent::<Identity, OFFSET>,
+            SetRightIndent: SetRightIndent::<Identity, OFFSET>,
+            RightToLeft: RightToLeft::<Identity, OFFSET>,
+            SetRightToLeft: SetRightToLeft::<Identity, OFFSET>,
+            Style: Style::<Identity, OFFSET>,
+            SetStyle: SetStyle::<Identity, OFFSET>,
+            SpaceAfter: SpaceAfter::<Identity, OFFSET>,
+            SetSpaceAfter: SetSpaceAfter::<Identity, OFFSET>,
+            SpaceBefore: SpaceBefore::<Identity, OFFSET>,
+            SetSpaceBefore: SetSpaceBefore::<Identity, OFFSET>,
+            WidowControl: WidowControl::<Identity, OFFSET>,
+            SetWidowControl: SetWidowControl::<Identity, OFFSET>,
+            TabCount: TabCount::<Identity, OFFSET>,
+            AddTab: AddTab::<Identity, OFFSET>,
+            ClearAllTabs: ClearAllTabs::<Identity, OFFSET>,
+            DeleteTab: DeleteTab::<Identity, OFFSET>,
+            GetClone: GetClone::<Identity, OFFSET>,
+            GetTab: GetTab::<Identity, OFFSET>,
+            IsEqual: IsEqual::<Identity, OFFSET>,
+            SetClone: SetClone::<Identity, OFFSET>,
+            SetIndents: SetIndents::<Identity, OFFSET>,
+            SetLineSpacing: SetLineSpacing::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextParagraphFormat as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl> windows_core::imp::TearOffVtable<Identity> for ITextParagraphFormat_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Alignment<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut ParagraphAlignment) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::Alignment(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAlignment<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, value: ParagraphAlignment) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::SetAlignment(this, value).into()
+            }
+        }
+        unsafe extern "system" fn FirstLineIndent<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::FirstLineIndent(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn KeepTogether<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::KeepTogether(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetKeepTogether<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::SetKeepTogether(this, value).into()
+            }
+        }
+        unsafe extern "system" fn KeepWithNext<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::KeepWithNext(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetKeepWithNext<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::SetKeepWithNext(this, value).into()
+            }
+        }
+        unsafe extern "system" fn LeftIndent<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::LeftIndent(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LineSpacing<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::LineSpacing(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LineSpacingRule<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut LineSpacingRule) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::LineSpacingRule(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ListAlignment<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut MarkerAlignment) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::ListAlignment(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetListAlignment<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, value: MarkerAlignment) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::SetListAlignment(this, value).into()
+            }
+        }
+        unsafe extern "system" fn ListLevelIndex<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::ListLevelIndex(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetListLevelIndex<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::SetListLevelIndex(this, value).into()
+            }
+        }
+        unsafe extern "system" fn ListStart<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::ListStart(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetListStart<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::SetListStart(this, value).into()
+            }
+        }
+        unsafe extern "system" fn ListStyle<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut MarkerStyle) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::ListStyle(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetListStyle<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, value: MarkerStyle) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::SetListStyle(this, value).into()
+            }
+        }
+        unsafe extern "system" fn ListTab<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::ListTab(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetListTab<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::SetListTab(this, value).into()
+            }
+        }
+        unsafe extern "system" fn ListType<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut MarkerType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::ListType(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetListType<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, value: MarkerType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::SetListType(this, value).into()
+            }
+        }
+        unsafe extern "system" fn NoLineNumber<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::NoLineNumber(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNoLineNumber<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::SetNoLineNumber(this, value).into()
+            }
+        }
+        unsafe extern "system" fn PageBreakBefore<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::PageBreakBefore(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPageBreakBefore<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::SetPageBreakBefore(this, value).into()
+            }
+        }
+        unsafe extern "system" fn RightIndent<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::RightIndent(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRightIndent<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::SetRightIndent(this, value).into()
+            }
+        }
+        unsafe extern "system" fn RightToLeft<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::RightToLeft(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRightToLeft<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::SetRightToLeft(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Style<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut ParagraphStyle) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::Style(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetStyle<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, value: ParagraphStyle) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::SetStyle(this, value).into()
+            }
+        }
+        unsafe extern "system" fn SpaceAfter<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::SpaceAfter(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSpaceAfter<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::SetSpaceAfter(this, value).into()
+            }
+        }
+        unsafe extern "system" fn SpaceBefore<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::SpaceBefore(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSpaceBefore<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::SetSpaceBefore(this, value).into()
+            }
+        }
+        unsafe extern "system" fn WidowControl<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::WidowControl(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetWidowControl<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, value: FormatEffect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::SetWidowControl(this, value).into()
+            }
+        }
+        unsafe extern "system" fn TabCount<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::TabCount(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddTab<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, position: f32, align: TabAlignment, leader: TabLeader) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::AddTab(this, position, align, leader).into()
+            }
+        }
+        unsafe extern "system" fn ClearAllTabs<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::ClearAllTabs(this).into()
+            }
+        }
+        unsafe extern "system" fn DeleteTab<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, position: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::DeleteTab(this, position).into()
+            }
+        }
+        unsafe extern "system" fn GetClone<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::GetClone(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTab<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, index: i32, position: *mut f32, align: *mut TabAlignment, leader: *mut TabLeader) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::GetTab(this, index, core::mem::transmute_copy(&position), core::mem::transmute_copy(&align), core::mem::transmute_copy(&leader)).into()
+            }
+        }
+        unsafe extern "system" fn IsEqual<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, format: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextParagraphFormat_Impl::IsEqual(this, core::mem::transmute_copy(&format)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetClone<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, format: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::SetClone(this, core::mem::transmute_copy(&format)).into()
+            }
+        }
+        unsafe extern "system" fn SetIndents<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, start: f32, left: f32, right: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::SetIndents(this, start, left, right).into()
+            }
+        }
+        unsafe extern "system" fn SetLineSpacing<Identity: windows_core::IUnknownImpl + ITextParagraphFormat_Impl>(this: *mut core::ffi::c_void, rule: LineSpacingRule, spacing: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextParagraphFormat_Impl::SetLineSpacing(this, rule, spacing).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Alignment: Alignment::<Identity>,
+            SetAlignment: SetAlignment::<Identity>,
+            FirstLineIndent: FirstLineIndent::<Identity>,
+            KeepTogether: KeepTogether::<Identity>,
+            SetKeepTogether: SetKeepTogether::<Identity>,
+            KeepWithNext: KeepWithNext::<Identity>,
+            SetKeepWithNext: SetKeepWithNext::<Identity>,
+            LeftIndent: LeftIndent::<Identity>,
+            LineSpacing: LineSpacing::<Identity>,
+            LineSpacingRule: LineSpacingRule::<Identity>,
+            ListAlignment: ListAlignment::<Identity>,
+            SetListAlignment: SetListAlignment::<Identity>,
+            ListLevelIndex: ListLevelIndex::<Identity>,
+            SetListLevelIndex: SetListLevelIndex::<Identity>,
+            ListStart: ListStart::<Identity>,
+            SetListStart: SetListStart::<Identity>,
+            ListStyle: ListStyle::<Identity>,
+            SetListStyle: SetListStyle::<Identity>,
+            ListTab: ListTab::<Identity>,
+            SetListTab: SetListTab::<Identity>,
+            ListType: ListType::<Identity>,
+            SetListType: SetListType::<Identity>,
+            NoLineNumber: NoLineNumber::<Identity>,
+            SetNoLineNumber: SetNoLineNumber::<Identity>,
+            PageBreakBefore: PageBreakBefore::<Identity>,
+            SetPageBreakBefore: SetPageBreakBefore::<Identity>,
+            RightIndent: RightIndent::<Identity>,
+            SetRightIndent: SetRightIndent::<Identity>,
+            RightToLeft: RightToLeft::<Identity>,
+            SetRightToLeft: SetRightToLeft::<Identity>,
+            Style: Style::<Identity>,
+            SetStyle: SetStyle::<Identity>,
+            SpaceAfter: SpaceAfter::<Identity>,
+            SetSpaceAfter: SetSpaceAfter::<Identity>,
+            SpaceBefore: SpaceBefore::<Identity>,
+            SetSpaceBefore: SetSpaceBefore::<Identity>,
+            WidowControl: WidowControl::<Identity>,
+            SetWidowControl: SetWidowControl::<Identity>,
+            TabCount: TabCount::<Identity>,
+            AddTab: AddTab::<Identity>,
+            ClearAllTabs: ClearAllTabs::<Identity>,
+            DeleteTab: DeleteTab::<Identity>,
+            GetClone: GetClone::<Identity>,
+            GetTab: GetTab::<Identity>,
+            IsEqual: IsEqual::<Identity>,
+            SetClone: SetClone::<Identity>,
+            SetIndents: SetIndents::<Identity>,
+            SetLineSpacing: SetLineSpacing::<Identity>,
+        }
+    };
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct ITextParagraphFormat_Vtbl {
+    pub base__: windows_core::IInspectable_Vtbl,
+    pub Alignment: unsafe extern "system" fn(*mut core::ffi::c_void, *mut ParagraphAlignment) -> windows_core::HRESULT,
+    pub SetAlignment: unsafe extern "system" fn(*mut core::ffi::c_void, ParagraphAlignment) -> windows_core::HRESULT,
+    pub FirstLineIndent: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
+    pub KeepTogether: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
+    pub SetKeepTogether: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
+    pub KeepWithNext: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
+    pub SetKeepWithNext: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
+    pub LeftIndent: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
+    pub LineSpacing: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
+    pub LineSpacingRule: unsafe extern "system" fn(*mut core::ffi::c_void, *mut LineSpacingRule) -> windows_core::HRESULT,
+    pub ListAlignment: unsafe extern "system" fn(*mut core::ffi::c_void, *mut MarkerAlignment) -> windows_core::HRESULT,
+    pub SetListAlignment: unsafe extern "system" fn(*mut core::ffi::c_void, MarkerAlignment) -> windows_core::HRESULT,
+    pub ListLevelIndex: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
+    pub SetListLevelIndex: unsafe extern "system" fn(*mut core::ffi::c_void, i32) -> windows_core::HRESULT,
+    pub ListStart: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
+    pub SetListStart: unsafe extern "system" fn(*mut core::ffi::c_void, i32) -> windows_core::HRESULT,
+    pub ListStyle: unsafe extern "system" fn(*mut core::ffi::c_void, *mut MarkerStyle) -> windows_core::HRESULT,
+    pub SetListStyle: unsafe extern "system" fn(*mut core::ffi::c_void, MarkerStyle) -> windows_core::HRESULT,
+    pub ListTab: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
+    pub SetListTab: unsafe extern "system" fn(*mut core::ffi::c_void, f32) -> windows_core::HRESULT,
+    pub ListType: unsafe extern "system" fn(*mut core::ffi::c_void, *mut MarkerType) -> windows_core::HRESULT,
+    pub SetListType: unsafe extern "system" fn(*mut core::ffi::c_void, MarkerType) -> windows_core::HRESULT,
+    pub NoLineNumber: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
+    pub SetNoLineNumber: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
+    pub PageBreakBefore: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
+    pub SetPageBreakBefore: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
+    pub RightIndent: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
+    pub SetRightIndent: unsafe extern "system" fn(*mut core::ffi::c_void, f32) -> windows_core::HRESULT,
+    pub RightToLeft: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
+    pub SetRightToLeft: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
+    pub Style: unsafe extern "system" fn(*mut core::ffi::c_void, *mut ParagraphStyle) -> windows_core::HRESULT,
+    pub SetStyle: unsafe extern "system" fn(*mut core::ffi::c_void, ParagraphStyle) -> windows_core::HRESULT,
+    pub SpaceAfter: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
+    pub SetSpaceAfter: unsafe extern "system" fn(*mut core::ffi::c_void, f32) -> windows_core::HRESULT,
+    pub SpaceBefore: unsafe extern "system" fn(*mut core::ffi::c_void, *mut f32) -> windows_core::HRESULT,
+    pub SetSpaceBefore: unsafe extern "system" fn(*mut core::ffi::c_void, f32) -> windows_core::HRESULT,
+    pub WidowControl: unsafe extern "system" fn(*mut core::ffi::c_void, *mut FormatEffect) -> windows_core::HRESULT,
+    pub SetWidowControl: unsafe extern "system" fn(*mut core::ffi::c_void, FormatEffect) -> windows_core::HRESULT,
+    pub TabCount: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
+    pub AddTab: unsafe extern "system" fn(*mut core::ffi::c_void, f32, TabAlignment, TabLeader) -> windows_core::HRESULT,
+    pub ClearAllTabs: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub DeleteTab: unsafe extern "system" fn(*mut core::ffi::c_void, f32) -> windows_core::HRESULT,
+    pub GetClone: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetTab: unsafe extern "system" fn(*mut core::ffi::c_void, i32, *mut f32, *mut TabAlignment, *mut TabLeader) -> windows_core::HRESULT,
+    pub IsEqual: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut bool) -> windows_core::HRESULT,
+    pub SetClone: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub SetIndents: unsafe extern "system" fn(*mut core::ffi::c_void, f32, f32, f32) -> windows_core::HRESULT,
+    pub SetLineSpacing: unsafe extern "system" fn(*mut core::ffi::c_void, LineSpacingRule, f32) -> windows_core::HRESULT,
+}
+windows_core::imp::define_interface!(ITextRange, ITextRange_Vtbl, 0x5b9e4e57_c072_42a0_8945_af503ee54768);
+impl windows_core::RuntimeType for ITextRange {
+    const SIGNATURE: windows_core::imp::ConstBuffer = windows_core::imp::ConstBuffer::for_interface::<Self>();
+}
+windows_core::imp::interface_hierarchy!(ITextRange, windows_core::IUnknown, windows_core::IInspectable);
+impl ITextRange {
+    pub fn Character(&self) -> windows_core::Result<u16> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).Character)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        }
+    }
+    pub fn SetCharacter(&self, value: u16) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetCharacter)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn CharacterFormat(&self) -> windows_core::Result<ITextCharacterFormat> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).CharacterFormat)(windows_core::Interface::as_raw(this), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub fn SetCharacterFormat<P0>(&self, value: P0) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<ITextCharacterFormat>,
+    {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetCharacterFormat)(windows_core::Interface::as_raw(this), value.param().abi()).ok() }
+    }
+    pub fn FormattedText(&self) -> windows_core::Result<ITextRange> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).FormattedText)(windows_core::Interface::as_raw(this), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub fn SetFormattedText<P0>(&self, value: P0) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<ITextRange>,
+    {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetFormattedText)(windows_core::Interface::as_raw(this), value.param().abi()).ok() }
+    }
+    pub fn EndPosition(&self) -> windows_core::Result<i32> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).EndPosition)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        }
+    }
+    pub fn SetEndPosition(&self, value: i32) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetEndPosition)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn Gravity(&self) -> windows_core::Result<RangeGravity> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).Gravity)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        }
+    }
+    pub fn SetGravity(&self, value: RangeGravity) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetGravity)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn Length(&self) -> windows_core::Result<i32> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).Length)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        }
+    }
+    pub fn Link(&self) -> windows_core::Result<windows_core::HSTRING> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).Link)(windows_core::Interface::as_raw(this), &mut result__).map(|| core::mem::transmute(result__))
+        }
+    }
+    pub fn SetLink(&self, value: &windows_core::HSTRING) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetLink)(windows_core::Interface::as_raw(this), core::mem::transmute_copy(value)).ok() }
+    }
+    pub fn ParagraphFormat(&self) -> windows_core::Result<ITextParagraphFormat> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).ParagraphFormat)(windows_core::Interface::as_raw(this), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub fn SetParagraphFormat<P0>(&self, value: P0) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<ITextParagraphFormat>,
+    {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetParagraphFormat)(windows_core::Interface::as_raw(this), value.param().abi()).ok() }
+    }
+    pub fn StartPosition(&self) -> windows_core::Result<i32> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).StartPosition)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        }
+    }
+    pub fn SetStartPosition(&self, value: i32) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetStartPosition)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn StoryLength(&self) -> windows_core::Result<i32> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).StoryLength)(windows_core::Interface::as_raw(this), &mut result__).map(|| result__)
+        }
+    }
+    pub fn Text(&self) -> windows_core::Result<windows_core::HSTRING> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).Text)(windows_core::Interface::as_raw(this), &mut result__).map(|| core::mem::transmute(result__))
+        }
+    }
+    pub fn SetText(&self, value: &windows_core::HSTRING) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetText)(windows_core::Interface::as_raw(this), core::mem::transmute_copy(value)).ok() }
+    }
+    pub fn CanPaste(&self, format: i32) -> windows_core::Result<bool> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).CanPaste)(windows_core::Interface::as_raw(this), format, &mut result__).map(|| result__)
+        }
+    }
+    pub fn ChangeCase(&self, value: LetterCase) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).ChangeCase)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn Collapse(&self, value: bool) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).Collapse)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn Copy(&self) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).Copy)(windows_core::Interface::as_raw(this)).ok() }
+    }
+    pub fn Cut(&self) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).Cut)(windows_core::Interface::as_raw(this)).ok() }
+    }
+    pub fn Delete(&self, unit: TextRangeUnit, count: i32) -> windows_core::Result<i32> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).Delete)(windows_core::Interface::as_raw(this), unit, count, &mut result__).map(|| result__)
+        }
+    }
+    pub fn EndOf(&self, unit: TextRangeUnit, extend: bool) -> windows_core::Result<i32> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).EndOf)(windows_core::Interface::as_raw(this), unit, extend, &mut result__).map(|| result__)
+        }
+    }
+    pub fn Expand(&self, unit: TextRangeUnit) -> windows_core::Result<i32> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).Expand)(windows_core::Interface::as_raw(this), unit, &mut result__).map(|| result__)
+        }
+    }
+    pub fn FindText(&self, value: &windows_core::HSTRING, scanlength: i32, options: FindOptions) -> windows_core::Result<i32> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).FindText)(windows_core::Interface::as_raw(this), core::mem::transmute_copy(value), scanlength, options, &mut result__).map(|| result__)
+        }
+    }
+    pub fn GetCharacterUtf32(&self, value: &mut u32, offset: i32) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).GetCharacterUtf32)(windows_core::Interface::as_raw(this), value, offset).ok() }
+    }
+    pub fn GetClone(&self) -> windows_core::Result<ITextRange> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).GetClone)(windows_core::Interface::as_raw(this), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub fn GetIndex(&self, unit: TextRangeUnit) -> windows_core::Result<i32> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).GetIndex)(windows_core::Interface::as_raw(this), unit, &mut result__).map(|| result__)
+        }
+    }
+    pub fn GetPoint(&self, horizontalalign: HorizontalCharacterAlignment, verticalalign: VerticalCharacterAlignment, options: PointOptions, point: &mut super::super::Foundation::Point) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).GetPoint)(windows_core::Interface::as_raw(this), horizontalalign, verticalalign, options, point).ok() }
+    }
+    pub fn GetRect(&self, options: PointOptions, rect: &mut super::super::Foundation::Rect, hit: &mut i32) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).GetRect)(windows_core::Interface::as_raw(this), options, rect, hit).ok() }
+    }
+    pub fn GetText(&self, options: TextGetOptions, value: &mut windows_core::HSTRING) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).GetText)(windows_core::Interface::as_raw(this), options, value as *mut _ as _).ok() }
+    }
+    #[cfg(feature = "Storage_Streams")]
+    pub fn GetTextViaStream<P1>(&self, options: TextGetOptions, value: P1) -> windows_core::Result<()>
+    where
+        P1: windows_core::Param<super::super::Storage::Streams::IRandomAccessStream>,
+    {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).GetTextViaStream)(windows_core::Interface::as_raw(this), options, value.param().abi()).ok() }
+    }
+    pub fn InRange<P0>(&self, range: P0) -> windows_core::Result<bool>
+    where
+        P0: windows_core::Param<ITextRange>,
+    {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).InRange)(windows_core::Interface::as_raw(this), range.param().abi(), &mut result__).map(|| result__)
+        }
+    }
+    #[cfg(feature = "Storage_Streams")]
+    pub fn InsertImage<P5>(&self, width: i32, height: i32, ascent: i32, verticalalign: VerticalCharacterAlignment, alternatetext: &windows_core::HSTRING, value: P5) -> windows_core::Result<()>
+    where
+        P5: windows_core::Param<super::super::Storage::Streams::IRandomAccessStream>,
+    {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).InsertImage)(windows_core::Interface::as_raw(this), width, height, ascent, verticalalign, core::mem::transmute_copy(alternatetext), value.param().abi()).ok() }
+    }
+    pub fn InStory<P0>(&self, range: P0) -> windows_core::Result<bool>
+    where
+        P0: windows_core::Param<ITextRange>,
+    {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).InStory)(windows_core::Interface::as_raw(this), range.param().abi(), &mut result__).map(|| result__)
+        }
+    }
+    pub fn IsEqual<P0>(&self, range: P0) -> windows_core::Result<bool>
+    where
+        P0: windows_core::Param<ITextRange>,
+    {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).IsEqual)(windows_core::Interface::as_raw(this), range.param().abi(), &mut result__).map(|| result__)
+        }
+    }
+    pub fn Move(&self, unit: TextRangeUnit, count: i32) -> windows_core::Result<i32> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).Move)(windows_core::Interface::as_raw(this), unit, count, &mut result__).map(|| result__)
+        }
+    }
+    pub fn MoveEnd(&self, unit: TextRangeUnit, count: i32) -> windows_core::Result<i32> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).MoveEnd)(windows_core::Interface::as_raw(this), unit, count, &mut result__).map(|| result__)
+        }
+    }
+    pub fn MoveStart(&self, unit: TextRangeUnit, count: i32) -> windows_core::Result<i32> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).MoveStart)(windows_core::Interface::as_raw(this), unit, count, &mut result__).map(|| result__)
+        }
+    }
+    pub fn Paste(&self, format: i32) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).Paste)(windows_core::Interface::as_raw(this), format).ok() }
+    }
+    pub fn ScrollIntoView(&self, value: PointOptions) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).ScrollIntoView)(windows_core::Interface::as_raw(this), value).ok() }
+    }
+    pub fn MatchSelection(&self) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).MatchSelection)(windows_core::Interface::as_raw(this)).ok() }
+    }
+    pub fn SetIndex(&self, unit: TextRangeUnit, index: i32, extend: bool) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetIndex)(windows_core::Interface::as_raw(this), unit, index, extend).ok() }
+    }
+    pub fn SetPoint(&self, point: super::super::Foundation::Point, options: PointOptions, extend: bool) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetPoint)(windows_core::Interface::as_raw(this), point, options, extend).ok() }
+    }
+    pub fn SetRange(&self, startposition: i32, endposition: i32) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetRange)(windows_core::Interface::as_raw(this), startposition, endposition).ok() }
+    }
+    pub fn SetText2(&self, options: TextSetOptions, value: &windows_core::HSTRING) -> windows_core::Result<()> {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetText2)(windows_core::Interface::as_raw(this), options, core::mem::transmute_copy(value)).ok() }
+    }
+    #[cfg(feature = "Storage_Streams")]
+    pub fn SetTextViaStream<P1>(&self, options: TextSetOptions, value: P1) -> windows_core::Result<()>
+    where
+        P1: windows_core::Param<super::super::Storage::Streams::IRandomAccessStream>,
+    {
+        let this = self;
+        unsafe { (windows_core::Interface::vtable(this).SetTextViaStream)(windows_core::Interface::as_raw(this), options, value.param().abi()).ok() }
+    }
+    pub fn StartOf(&self, unit: TextRangeUnit, extend: bool) -> windows_core::Result<i32> {
+        let this = self;
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(this).StartOf)(windows_core::Interface::as_raw(this), unit, extend, &mut result__).map(|| result__)
+        }
+    }
+}
+#[cfg(feature = "Storage_Streams")]
+impl windows_core::RuntimeName for ITextRange {
+    const NAME: &'static str = "Windows.UI.Text.ITextRange";
+}
+#[cfg(feature = "Storage_Streams")]
+pub trait ITextRange_Impl: windows_core::IUnknownImpl {
+    fn Character(&self) -> windows_core::Result<u16>;
+    fn SetCharacter(&self, value: u16) -> windows_core::Result<()>;
+    fn CharacterFormat(&self) -> windows_core::Result<ITextCharacterFormat>;
+    fn SetCharacterFormat(&self, value: windows_core::Ref<ITextCharacterFormat>) -> windows_core::Result<()>;
+    fn FormattedText(&self) -> windows_core::Result<ITextRange>;
+    fn SetFormattedText(&self, value: windows_core::Ref<ITextRange>) -> windows_core::Result<()>;
+    fn EndPosition(&self) -> windows_core::Result<i32>;
+    fn SetEndPosition(&self, value: i32) -> windows_core::Result<()>;
+    fn Gravity(&self) -> windows_core::Result<RangeGravity>;
+    fn SetGravity(&self, value: RangeGravity) -> windows_core::Result<()>;
+    fn Length(&self) -> windows_core::Result<i32>;
+    fn Link(&self) -> windows_core::Result<windows_core::HSTRING>;
+    fn SetLink(&self, value: &windows_core::HSTRING) -> windows_core::Result<()>;
+    fn ParagraphFormat(&self) -> windows_core::Result<ITextParagraphFormat>;
+    fn SetParagraphFormat(&self, value: windows_core::Ref<ITextParagraphFormat>) -> windows_core::Result<()>;
+    fn StartPosition(&self) -> windows_core::Result<i32>;
+    fn SetStartPosition(&self, value: i32) -> windows_core::Result<()>;
+    fn StoryLength(&self) -> windows_core::Result<i32>;
+    fn Text(&self) -> windows_core::Result<windows_core::HSTRING>;
+    fn SetText(&self, value: &windows_core::HSTRING) -> windows_core::Result<()>;
+    fn CanPaste(&self, format: i32) -> windows_core::Result<bool>;
+    fn ChangeCase(&self, value: LetterCase) -> windows_core::Result<()>;
+    fn Collapse(&self, value: bool) -> windows_core::Result<()>;
+    fn Copy(&self) -> windows_core::Result<()>;
+    fn Cut(&self) -> windows_core::Result<()>;
+    fn Delete(&self, unit: TextRangeUnit, count: i32) -> windows_core::Result<i32>;
+    fn EndOf(&self, unit: TextRangeUnit, extend: bool) -> windows_core::Result<i32>;
+    fn Expand(&self, unit: TextRangeUnit) -> windows_core::Result<i32>;
+    fn FindText(&self, value: &windows_core::HSTRING, scanLength: i32, options: FindOptions) -> windows_core::Result<i32>;
+    fn GetCharacterUtf32(&self, value: &mut u32, offset: i32) -> windows_core::Result<()>;
+    fn GetClone(&self) -> windows_core::Result<ITextRange>;
+    fn GetIndex(&self, unit: TextRangeUnit) -> windows_core::Result<i32>;
+    fn GetPoint(&self, horizontalAlign: HorizontalCharacterAlignment, verticalAlign: VerticalCharacterAlignment, options: PointOptions, point: &mut super::super::Foundation::Point) -> windows_core::Result<()>;
+    fn GetRect(&self, options: PointOptions, rect: &mut super::super::Foundation::Rect, hit: &mut i32) -> windows_core::Result<()>;
+    fn GetText(&self, options: TextGetOptions, value: &mut windows_core::HSTRING) -> windows_core::Result<()>;
+    fn GetTextViaStream(&self, options: TextGetOptions, value: windows_core::Ref<super::super::Storage::Streams::IRandomAccessStream>) -> windows_core::Result<()>;
+    fn InRange(&self, range: windows_core::Ref<ITextRange>) -> windows_core::Result<bool>;
+    fn InsertImage(&self, width: i32, height: i32, ascent: i32, verticalAlign: VerticalCharacterAlignment, alternateText: &windows_core::HSTRING, value: windows_core::Ref<super::super::Storage::Streams::IRandomAccessStream>) -> windows_core::Result<()>;
+    fn InStory(&self, range: windows_core::Ref<ITextRange>) -> windows_core::Result<bool>;
+    fn IsEqual(&self, range: windows_core::Ref<ITextRange>) -> windows_core::Result<bool>;
+    fn Move(&self, unit: TextRangeUnit, count: i32) -> windows_core::Result<i32>;
+    fn MoveEnd(&self, unit: TextRangeUnit, count: i32) -> windows_core::Result<i32>;
+    fn MoveStart(&self, unit: TextRangeUnit, count: i32) -> windows_core::Result<i32>;
+    fn Paste(&self, format: i32) -> windows_core::Result<()>;
+    fn ScrollIntoView(&self, value: PointOptions) -> windows_core::Result<()>;
+    fn MatchSelection(&self) -> windows_core::Result<()>;
+    fn SetIndex(&self, unit: TextRangeUnit, index: i32, extend: bool) -> windows_core::Result<()>;
+    fn SetPoint(&self, point: &super::super::Foundation::Point, options: PointOptions, extend: bool) -> windows_core::Result<()>;
+    fn SetRange(&self, startPosition: i32, endPosition: i32) -> windows_core::Result<()>;
+    fn SetText2(&self, options: TextSetOptions, value: &windows_core::HSTRING) -> windows_core::Result<()>;
+    fn SetTextViaStream(&self, options: TextSetOptions, value: windows_core::Ref<super::super::Storage::Streams::IRandomAccessStream>) -> windows_core::Result<()>;
+    fn StartOf(&self, unit: TextRangeUnit, extend: bool) -> windows_core::Result<i32>;
+}
+#[cfg(feature = "Storage_Streams")]
+impl ITextRange_Vtbl {
+    pub const fn new<Identity: ITextRange_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn Character<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::Character(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCharacter<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::SetCharacter(this, value).into()
+            }
+        }
+        unsafe extern "system" fn CharacterFormat<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::CharacterFormat(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCharacterFormat<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::SetCharacterFormat(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn FormattedText<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::FormattedText(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetFormattedText<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::SetFormattedText(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn EndPosition<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::EndPosition(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEndPosition<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::SetEndPosition(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Gravity<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut RangeGravity) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::Gravity(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetGravity<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: RangeGravity) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::SetGravity(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Length<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::Length(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Link<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::Link(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLink<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::SetLink(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn ParagraphFormat<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::ParagraphFormat(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetParagraphFormat<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::SetParagraphFormat(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn StartPosition<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::StartPosition(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetStartPosition<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::SetStartPosition(this, value).into()
+            }
+        }
+        unsafe extern "system" fn StoryLength<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::StoryLength(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Text<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::Text(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetText<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::SetText(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn CanPaste<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, format: i32, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::CanPaste(this, format) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ChangeCase<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: LetterCase) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::ChangeCase(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Collapse<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::Collapse(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Copy<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::Copy(this).into()
+            }
+        }
+        unsafe extern "system" fn Cut<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::Cut(this).into()
+            }
+        }
+        unsafe extern "system" fn Delete<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::Delete(this, unit, count) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EndOf<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, unit: TextRangeUnit, extend: bool, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::EndOf(this, unit, extend) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Expand<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, unit: TextRangeUnit, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::Expand(this, unit) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindText<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, scanlength: i32, options: FindOptions, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::FindText(this, core::mem::transmute(&value), scanlength, options) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCharacterUtf32<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut u32, offset: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::GetCharacterUtf32(this, core::mem::transmute_copy(&value), offset).into()
+            }
+        }
+        unsafe extern "system" fn GetClone<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::GetClone(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetIndex<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, unit: TextRangeUnit, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::GetIndex(this, unit) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPoint<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, horizontalalign: HorizontalCharacterAlignment, verticalalign: VerticalCharacterAlignment, options: PointOptions, point: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::GetPoint(this, horizontalalign, verticalalign, options, core::mem::transmute_copy(&point)).into()
+            }
+        }
+        unsafe extern "system" fn GetRect<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: PointOptions, rect: *mut super::super::Foundation::Rect, hit: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::GetRect(this, options, core::mem::transmute_copy(&rect), core::mem::transmute_copy(&hit)).into()
+            }
+        }
+        unsafe extern "system" fn GetText<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: TextGetOptions, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::GetText(this, options, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn GetTextViaStream<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: TextGetOptions, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::GetTextViaStream(this, options, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn InRange<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, range: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::InRange(this, core::mem::transmute_copy(&range)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InsertImage<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, width: i32, height: i32, ascent: i32, verticalalign: VerticalCharacterAlignment, alternatetext: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::InsertImage(this, width, height, ascent, verticalalign, core::mem::transmute(&alternatetext), core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn InStory<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, range: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::InStory(this, core::mem::transmute_copy(&range)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsEqual<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, range: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::IsEqual(this, core::mem::transmute_copy(&range)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Move<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::Move(this, unit, count) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveEnd<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::MoveEnd(this, unit, count) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveStart<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::MoveStart(this, unit, count) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Paste<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, format: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::Paste(this, format).into()
+            }
+        }
+        unsafe extern "system" fn ScrollIntoView<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: PointOptions) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::ScrollIntoView(this, value).into()
+            }
+        }
+        unsafe extern "system" fn MatchSelection<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::MatchSelection(this).into()
+            }
+        }
+        unsafe extern "system" fn SetIndex<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, unit: TextRangeUnit, index: i32, extend: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::SetIndex(this, unit, index, extend).into()
+            }
+        }
+        unsafe extern "system" fn SetPoint<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, point: super::super::Foundation::Point, options: PointOptions, extend: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::SetPoint(this, core::mem::transmute(&point), options, extend).into()
+            }
+        }
+        unsafe extern "system" fn SetRange<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, startposition: i32, endposition: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::SetRange(this, startposition, endposition).into()
+            }
+        }
+        unsafe extern "system" fn SetText2<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: TextSetOptions, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::SetText2(this, options, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn SetTextViaStream<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: TextSetOptions, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITextRange_Impl::SetTextViaStream(this, options, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn StartOf<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, unit: TextRangeUnit, extend: bool, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITextRange_Impl::StartOf(this, unit, extend) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IInspectable_Vtbl::new::<Identity, ITextRange, OFFSET>(),
+            Character: Character::<Identity, OFFSET>,
+            SetCharacter: SetCharacter::<Identity, OFFSET>,
+            CharacterFormat: CharacterFormat::<Identity, OFFSET>,
+            SetCharacterFormat: SetCharacterFormat::<Identity, OFFSET>,
+            FormattedText: FormattedText::<Identity, OFFSET>,
+            SetFormattedText: SetFormattedText::<Identity, OFFSET>,
+            EndPosition: EndPosition::<Identity, OFFSET>,
+            SetEndPosition: SetEndPosition::<Identity, OFFSET>,
+            Gravity: Gravity::<Identity, OFFSET>,
+            SetGravity: SetGravity::<Identity, OFFSET>,
+            Length: Length::<Identity, OFFSET>,
+            Link: Link::<Identity, OFFSET>,
+            SetLink: SetLink::<Identity, OFFSET>,
+            ParagraphFormat: ParagraphFormat::<Identity, OFFSET>,
+            SetParagraphFormat: SetParagraphFormat::<Identity, OFFSET>,
+            StartPosition: StartPosition::<Identity, OFFSET>,
+            SetStartPosition: SetStartPosition::<Identity, OFFSET>,
+            StoryLength: StoryLength::<Identity, OFFSET>,
+            Text: Text::<Identity, OFFSET>,
+            SetText: SetText::<Identity, OFFSET>,
+            CanPaste: CanPaste::<Identity, OFFSET>,
+            ChangeCase: ChangeCase::<Identity, OFFSET>,
+            Collapse: Collapse::<Identity, OFFSET>,
+            Copy: Copy::<Identity, OFFSET>,
+            Cut: Cut::<Identity, OFFSET>,
+            Delete: Delete::<Identity, OFFSET>,
+            EndOf: EndOf::<Identity, OFFSET>,
+            Expand: Expand::<Identity, OFFSET>,
+            FindText: FindText::<Identity, OFFSET>,
+            GetCharacterUtf32: GetCharacterUtf32::<Identity, OFFSET>,
+            GetClone: GetClone::<Identity, OFFSET>,
+            GetIndex: GetIndex::<Identity, OFFSET>,
+            GetPoint: GetPoint::<Identity, OFFSET>,
+            GetRect: GetRect::<Identity, OFFSET>,
+            GetText: GetText::<Identity, OFFSET>,
+            GetTextViaStream: GetTextViaStream::<Identity, OFFSET>,
+            InRange: InRange::<Identity, OFFSET>,
+            InsertImage: InsertImage::<Identity, OFFSET>,
+            InStory: InStory::<Identity, OFFSET>,
+            IsEqual: IsEqual::<Identity, OFFSET>,
+            Move: Move::<Identity, OFFSET>,
+            MoveEnd: MoveEnd::<Identity, OFFSET>,
+            MoveStart: MoveStart::<Identity, OFFSET>,
+            Paste: Paste::<Identity, OFFSET>,
+            ScrollIntoView: ScrollIntoView::<Identity, OFFSET>,
+            MatchSelection: MatchSelection::<Identity, OFFSET>,
+            SetIndex: SetIndex::<Identity, OFFSET>,
+            SetPoint: SetPoint::<Identity, OFFSET>,
+            SetRange: SetRange::<Identity, OFFSET>,
+            SetText2: SetText2::<Identity, OFFSET>,
+            SetTextViaStream: SetTextViaStream::<Identity, OFFSET>,
+            StartOf: StartOf::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextRange as windows_core::Interface>::IID
+    }
 }
 #[cfg(feature = "Storage_Streams")]
-impl ITextRange_Vtbl {
-    pub const fn new<Identity: ITextRange_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn Character<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
+impl<Identity: windows_core::IUnknownImpl + ITextRange_Impl> windows_core::imp::TearOffVtable<Identity> for ITextRange_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Character<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, result__: *mut u16) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::Character(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3137,15 +4958,15 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetCharacter<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: u16) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetCharacter<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, value: u16) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::SetCharacter(this, value).into()
             }
         }
-        unsafe extern "system" fn CharacterFormat<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CharacterFormat<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::CharacterFormat(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3156,15 +4977,15 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetCharacterFormat<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetCharacterFormat<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::SetCharacterFormat(this, core::mem::transmute_copy(&value)).into()
             }
         }
-        unsafe extern "system" fn FormattedText<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn FormattedText<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::FormattedText(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3175,15 +4996,15 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetFormattedText<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetFormattedText<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::SetFormattedText(this, core::mem::transmute_copy(&value)).into()
             }
         }
-        unsafe extern "system" fn EndPosition<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn EndPosition<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::EndPosition(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3193,15 +5014,15 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetEndPosition<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetEndPosition<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::SetEndPosition(this, value).into()
             }
         }
-        unsafe extern "system" fn Gravity<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut RangeGravity) -> windows_core::HRESULT {
+        unsafe extern "system" fn Gravity<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, result__: *mut RangeGravity) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::Gravity(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3211,15 +5032,15 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetGravity<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: RangeGravity) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetGravity<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, value: RangeGravity) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::SetGravity(this, value).into()
             }
         }
-        unsafe extern "system" fn Length<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn Length<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::Length(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3229,9 +5050,9 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Link<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Link<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::Link(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3242,15 +5063,15 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetLink<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetLink<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::SetLink(this, core::mem::transmute(&value)).into()
             }
         }
-        unsafe extern "system" fn ParagraphFormat<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn ParagraphFormat<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::ParagraphFormat(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3261,15 +5082,15 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetParagraphFormat<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetParagraphFormat<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::SetParagraphFormat(this, core::mem::transmute_copy(&value)).into()
             }
         }
-        unsafe extern "system" fn StartPosition<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn StartPosition<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::StartPosition(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3279,15 +5100,15 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetStartPosition<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetStartPosition<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::SetStartPosition(this, value).into()
             }
         }
-        unsafe extern "system" fn StoryLength<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn StoryLength<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, result__: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::StoryLength(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3297,9 +5118,9 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Text<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Text<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::Text(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3310,15 +5131,15 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetText<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetText<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::SetText(this, core::mem::transmute(&value)).into()
             }
         }
-        unsafe extern "system" fn CanPaste<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, format: i32, result__: *mut bool) -> windows_core::HRESULT {
+        unsafe extern "system" fn CanPaste<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, format: i32, result__: *mut bool) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::CanPaste(this, format) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3328,33 +5149,33 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ChangeCase<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: LetterCase) -> windows_core::HRESULT {
+        unsafe extern "system" fn ChangeCase<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, value: LetterCase) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::ChangeCase(this, value).into()
             }
         }
-        unsafe extern "system" fn Collapse<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+        unsafe extern "system" fn Collapse<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::Collapse(this, value).into()
             }
         }
-        unsafe extern "system" fn Copy<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Copy<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::Copy(this).into()
             }
         }
-        unsafe extern "system" fn Cut<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Cut<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::Cut(this).into()
             }
         }
-        unsafe extern "system" fn Delete<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, result__: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, result__: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::Delete(this, unit, count) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3364,9 +5185,9 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn EndOf<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, unit: TextRangeUnit, extend: bool, result__: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn EndOf<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, unit: TextRangeUnit, extend: bool, result__: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::EndOf(this, unit, extend) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3376,9 +5197,9 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Expand<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, unit: TextRangeUnit, result__: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn Expand<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, unit: TextRangeUnit, result__: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::Expand(this, unit) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3388,9 +5209,9 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn FindText<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, scanlength: i32, options: FindOptions, result__: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn FindText<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, scanlength: i32, options: FindOptions, result__: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::FindText(this, core::mem::transmute(&value), scanlength, options) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3400,15 +5221,15 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetCharacterUtf32<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut u32, offset: i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetCharacterUtf32<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, value: *mut u32, offset: i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::GetCharacterUtf32(this, core::mem::transmute_copy(&value), offset).into()
             }
         }
-        unsafe extern "system" fn GetClone<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetClone<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::GetClone(this) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3419,9 +5240,9 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetIndex<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, unit: TextRangeUnit, result__: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetIndex<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, unit: TextRangeUnit, result__: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::GetIndex(this, unit) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3431,33 +5252,33 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetPoint<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, horizontalalign: HorizontalCharacterAlignment, verticalalign: VerticalCharacterAlignment, options: PointOptions, point: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetPoint<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, horizontalalign: HorizontalCharacterAlignment, verticalalign: VerticalCharacterAlignment, options: PointOptions, point: *mut super::super::Foundation::Point) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::GetPoint(this, horizontalalign, verticalalign, options, core::mem::transmute_copy(&point)).into()
             }
         }
-        unsafe extern "system" fn GetRect<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: PointOptions, rect: *mut super::super::Foundation::Rect, hit: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetRect<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, options: PointOptions, rect: *mut super::super::Foundation::Rect, hit: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::GetRect(this, options, core::mem::transmute_copy(&rect), core::mem::transmute_copy(&hit)).into()
             }
         }
-        unsafe extern "system" fn GetText<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: TextGetOptions, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetText<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, options: TextGetOptions, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::GetText(this, options, core::mem::transmute_copy(&value)).into()
             }
         }
-        unsafe extern "system" fn GetTextViaStream<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: TextGetOptions, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetTextViaStream<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, options: TextGetOptions, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::GetTextViaStream(this, options, core::mem::transmute_copy(&value)).into()
             }
         }
-        unsafe extern "system" fn InRange<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, range: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+        unsafe extern "system" fn InRange<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, range: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::InRange(this, core::mem::transmute_copy(&range)) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3467,15 +5288,15 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn InsertImage<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, width: i32, height: i32, ascent: i32, verticalalign: VerticalCharacterAlignment, alternatetext: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn InsertImage<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, width: i32, height: i32, ascent: i32, verticalalign: VerticalCharacterAlignment, alternatetext: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::InsertImage(this, width, height, ascent, verticalalign, core::mem::transmute(&alternatetext), core::mem::transmute_copy(&value)).into()
             }
         }
-        unsafe extern "system" fn InStory<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, range: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+        unsafe extern "system" fn InStory<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, range: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::InStory(this, core::mem::transmute_copy(&range)) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3485,9 +5306,9 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn IsEqual<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, range: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+        unsafe extern "system" fn IsEqual<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, range: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::IsEqual(this, core::mem::transmute_copy(&range)) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3497,9 +5318,9 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Move<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, result__: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn Move<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, result__: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::Move(this, unit, count) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3509,9 +5330,9 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn MoveEnd<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, result__: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn MoveEnd<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, result__: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::MoveEnd(this, unit, count) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3521,9 +5342,9 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn MoveStart<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, result__: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn MoveStart<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, result__: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::MoveStart(this, unit, count) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3533,57 +5354,57 @@ impl ITextRange_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Paste<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, format: i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn Paste<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, format: i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::Paste(this, format).into()
             }
         }
-        unsafe extern "system" fn ScrollIntoView<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: PointOptions) -> windows_core::HRESULT {
+        unsafe extern "system" fn ScrollIntoView<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, value: PointOptions) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::ScrollIntoView(this, value).into()
             }
         }
-        unsafe extern "system" fn MatchSelection<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn MatchSelection<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::MatchSelection(this).into()
             }
         }
-        unsafe extern "system" fn SetIndex<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, unit: TextRangeUnit, index: i32, extend: bool) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetIndex<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, unit: TextRangeUnit, index: i32, extend: bool) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::SetIndex(this, unit, index, extend).into()
             }
         }
-        unsafe extern "system" fn SetPoint<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, point: super::super::Foundation::Point, options: PointOptions, extend: bool) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetPoint<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, point: super::super::Foundation::Point, options: PointOptions, extend: bool) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::SetPoint(this, core::mem::transmute(&point), options, extend).into()
             }
         }
-        unsafe extern "system" fn SetRange<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, startposition: i32, endposition: i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetRange<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, startposition: i32, endposition: i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::SetRange(this, startposition, endposition).into()
             }
         }
-        unsafe extern "system" fn SetText2<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: TextSetOptions, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetText2<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, options: TextSetOptions, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::SetText2(this, options, core::mem::transmute(&value)).into()
             }
         }
-        unsafe extern "system" fn SetTextViaStream<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: TextSetOptions, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetTextViaStream<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, options: TextSetOptions, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITextRange_Impl::SetTextViaStream(this, options, core::mem::transmute_copy(&value)).into()
             }
         }
-        unsafe extern "system" fn StartOf<Identity: ITextRange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, unit: TextRangeUnit, extend: bool, result__: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn StartOf<Identity: windows_core::IUnknownImpl + ITextRange_Impl>(this: *mut core::ffi::c_void, unit: TextRangeUnit, extend: bool, result__: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITextRange_Impl::StartOf(this, unit, extend) {
                     Ok(ok__) => {
                         result__.write(core::mem::transmute_copy(&ok__));
@@ -3594,64 +5415,61 @@ impl ITextRange_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IInspectable_Vtbl::new::<Identity, ITextRange, OFFSET>(),
-            Character: Character::<Identity, OFFSET>,
-            SetCharacter: SetCharacter::<Identity, OFFSET>,
-            CharacterFormat: CharacterFormat::<Identity, OFFSET>,
-            SetCharacterFormat: SetCharacterFormat::<Identity, OFFSET>,
-            FormattedText: FormattedText::<Identity, OFFSET>,
-            SetFormattedText: SetFormattedText::<Identity, OFFSET>,
-            EndPosition: EndPosition::<Identity, OFFSET>,
-            SetEndPosition: SetEndPosition::<Identity, OFFSET>,
-            Gravity: Gravity::<Identity, OFFSET>,
-            SetGravity: SetGravity::<Identity, OFFSET>,
-            Length: Length::<Identity, OFFSET>,
-            Link: Link::<Identity, OFFSET>,
-            SetLink: SetLink::<Identity, OFFSET>,
-            ParagraphFormat: ParagraphFormat::<Identity, OFFSET>,
-            SetParagraphFormat: SetParagraphFormat::<Identity, OFFSET>,
-            StartPosition: StartPosition::<Identity, OFFSET>,
-            SetStartPosition: SetStartPosition::<Identity, OFFSET>,
-            StoryLength: StoryLength::<Identity, OFFSET>,
-            Text: Text::<Identity, OFFSET>,
-            SetText: SetText::<Identity, OFFSET>,
-            CanPaste: CanPaste::<Identity, OFFSET>,
-            ChangeCase: ChangeCase::<Identity, OFFSET>,
-            Collapse: Collapse::<Identity, OFFSET>,
-            Copy: Copy::<Identity, OFFSET>,
-            Cut: Cut::<Identity, OFFSET>,
-            Delete: Delete::<Identity, OFFSET>,
-            EndOf: EndOf::<Identity, OFFSET>,
-            Expand: Expand::<Identity, OFFSET>,
-            FindText: FindText::<Identity, OFFSET>,
-            GetCharacterUtf32: GetCharacterUtf32::<Identity, OFFSET>,
-            GetClone: GetClone::<Identity, OFFSET>,
-            GetIndex: GetIndex::<Identity, OFFSET>,
-            GetPoint: GetPoint::<Identity, OFFSET>,
-            GetRect: GetRect::<Identity, OFFSET>,
-            GetText: GetText::<Identity, OFFSET>,
-            GetTextViaStream: GetTextViaStream::<Identity, OFFSET>,
-            InRange: InRange::<Identity, OFFSET>,
-            InsertImage: InsertImage::<Identity, OFFSET>,
-            InStory: InStory::<Identity, OFFSET>,
-            IsEqual: IsEqual::<Identity, OFFSET>,
-            Move: Move::<Identity, OFFSET>,
-            MoveEnd: MoveEnd::<Identity, OFFSET>,
-            MoveStart: MoveStart::<Identity, OFFSET>,
-            Paste: Paste::<Identity, OFFSET>,
-            ScrollIntoView: ScrollIntoView::<Identity, OFFSET>,
-            MatchSelection: MatchSelection::<Identity, OFFSET>,
-            SetIndex: SetIndex::<Identity, OFFSET>,
-            SetPoint: SetPoint::<Identity, OFFSET>,
-            SetRange: SetRange::<Identity, OFFSET>,
-            SetText2: SetText2::<Identity, OFFSET>,
-            SetTextViaStream: SetTextViaStream::<Identity, OFFSET>,
-            StartOf: StartOf::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ITextRange as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Character: Character::<Identity>,
+            SetCharacter: SetCharacter::<Identity>,
+            CharacterFormat: CharacterFormat::<Identity>,
+            SetCharacterFormat: SetCharacterFormat::<Identity>,
+            FormattedText: FormattedText::<Identity>,
+            SetFormattedText: SetFormattedText::<Identity>,
+            EndPosition: EndPosition::<Identity>,
+            SetEndPosition: SetEndPosition::<Identity>,
+            Gravity: Gravity::<Identity>,
+            SetGravity: SetGravity::<Identity>,
+            Length: Length::<Identity>,
+            Link: Link::<Identity>,
+            SetLink: SetLink::<Identity>,
+            ParagraphFormat: ParagraphFormat::<Identity>,
+            SetParagraphFormat: SetParagraphFormat::<Identity>,
+            StartPosition: StartPosition::<Identity>,
+            SetStartPosition: SetStartPosition::<Identity>,
+            StoryLength: StoryLength::<Identity>,
+            Text: Text::<Identity>,
+            SetText: SetText::<Identity>,
+            CanPaste: CanPaste::<Identity>,
+            ChangeCase: ChangeCase::<Identity>,
+            Collapse: Collapse::<Identity>,
+            Copy: Copy::<Identity>,
+            Cut: Cut::<Identity>,
+            Delete: Delete::<Identity>,
+            EndOf: EndOf::<Identity>,
+            Expand: Expand::<Identity>,
+            FindText: FindText::<Identity>,
+            GetCharacterUtf32: GetCharacterUtf32::<Identity>,
+            GetClone: GetClone::<Identity>,
+            GetIndex: GetIndex::<Identity>,
+            GetPoint: GetPoint::<Identity>,
+            GetRect: GetRect::<Identity>,
+            GetText: GetText::<Identity>,
+            GetTextViaStream: GetTextViaStream::<Identity>,
+            InRange: InRange::<Identity>,
+            InsertImage: InsertImage::<Identity>,
+            InStory: InStory::<Identity>,
+            IsEqual: IsEqual::<Identity>,
+            Move: Move::<Identity>,
+            MoveEnd: MoveEnd::<Identity>,
+            MoveStart: MoveStart::<Identity>,
+            Paste: Paste::<Identity>,
+            ScrollIntoView: ScrollIntoView::<Identity>,
+            MatchSelection: MatchSelection::<Identity>,
+            SetIndex: SetIndex::<Identity>,
+            SetPoint: SetPoint::<Identity>,
+            SetRange: SetRange::<Identity>,
+            SetText2: SetText2::<Identity>,
+            SetTextViaStream: SetTextViaStream::<Identity>,
+            StartOf: StartOf::<Identity>,
+        }
+    };
 }
 #[repr(C)]
 #[doc(hidden)]
@@ -4250,6 +6068,132 @@ impl ITextSelection_Vtbl {
         iid == &<ITextSelection as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Storage_Streams")]
+impl<Identity: windows_core::IUnknownImpl + ITextSelection_Impl> windows_core::imp::TearOffVtable<Identity> for ITextSelection_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Options<Identity: windows_core::IUnknownImpl + ITextSelection_Impl>(this: *mut core::ffi::c_void, result__: *mut SelectionOptions) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextSelection_Impl::Options(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetOptions<Identity: windows_core::IUnknownImpl + ITextSelection_Impl>(this: *mut core::ffi::c_void, value: SelectionOptions) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextSelection_Impl::SetOptions(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Type<Identity: windows_core::IUnknownImpl + ITextSelection_Impl>(this: *mut core::ffi::c_void, result__: *mut SelectionType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextSelection_Impl::Type(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EndKey<Identity: windows_core::IUnknownImpl + ITextSelection_Impl>(this: *mut core::ffi::c_void, unit: TextRangeUnit, extend: bool, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextSelection_Impl::EndKey(this, unit, extend) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn HomeKey<Identity: windows_core::IUnknownImpl + ITextSelection_Impl>(this: *mut core::ffi::c_void, unit: TextRangeUnit, extend: bool, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextSelection_Impl::HomeKey(this, unit, extend) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveDown<Identity: windows_core::IUnknownImpl + ITextSelection_Impl>(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, extend: bool, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextSelection_Impl::MoveDown(this, unit, count, extend) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveLeft<Identity: windows_core::IUnknownImpl + ITextSelection_Impl>(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, extend: bool, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextSelection_Impl::MoveLeft(this, unit, count, extend) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveRight<Identity: windows_core::IUnknownImpl + ITextSelection_Impl>(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, extend: bool, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextSelection_Impl::MoveRight(this, unit, count, extend) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveUp<Identity: windows_core::IUnknownImpl + ITextSelection_Impl>(this: *mut core::ffi::c_void, unit: TextRangeUnit, count: i32, extend: bool, result__: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITextSelection_Impl::MoveUp(this, unit, count, extend) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn TypeText<Identity: windows_core::IUnknownImpl + ITextSelection_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITextSelection_Impl::TypeText(this, core::mem::transmute(&value)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Options: Options::<Identity>,
+            SetOptions: SetOptions::<Identity>,
+            Type: Type::<Identity>,
+            EndKey: EndKey::<Identity>,
+            HomeKey: HomeKey::<Identity>,
+            MoveDown: MoveDown::<Identity>,
+            MoveLeft: MoveLeft::<Identity>,
+            MoveRight: MoveRight::<Identity>,
+            MoveUp: MoveUp::<Identity>,
+            TypeText: TypeText::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ITextSelection_Vtbl {
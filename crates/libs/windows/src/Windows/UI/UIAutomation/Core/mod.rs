@@ -266,6 +266,22 @@ impl ICoreAutomationConnectionBoundObjectProvider_Vtbl {
         iid == &<ICoreAutomationConnectionBoundObjectProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICoreAutomationConnectionBoundObjectProvider_Impl> windows_core::imp::TearOffVtable<Identity> for ICoreAutomationConnectionBoundObjectProvider_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsComThreadingRequired<Identity: windows_core::IUnknownImpl + ICoreAutomationConnectionBoundObjectProvider_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                let ok__ = ICoreAutomationConnectionBoundObjectProvider_Impl::IsComThreadingRequired(this);
+                result__.write(core::mem::transmute_copy(&ok__));
+                windows_core::HRESULT(0)
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsComThreadingRequired: IsComThreadingRequired::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ICoreAutomationConnectionBoundObjectProvider_Vtbl {
@@ -377,6 +393,33 @@ impl ICoreAutomationRemoteOperationExtensionProvider_Vtbl {
         iid == &<ICoreAutomationRemoteOperationExtensionProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICoreAutomationRemoteOperationExtensionProvider_Impl> windows_core::imp::TearOffVtable<Identity> for ICoreAutomationRemoteOperationExtensionProvider_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn CallExtension<Identity: windows_core::IUnknownImpl + ICoreAutomationRemoteOperationExtensionProvider_Impl>(this: *mut core::ffi::c_void, extensionid: windows_core::GUID, context: *mut core::ffi::c_void, operandids_array_size: u32, operandids: *const AutomationRemoteOperationOperandId) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICoreAutomationRemoteOperationExtensionProvider_Impl::CallExtension(this, core::mem::transmute(&extensionid), core::mem::transmute_copy(&context), core::slice::from_raw_parts(core::mem::transmute_copy(&operandids), operandids_array_size as usize)).into()
+            }
+        }
+        unsafe extern "system" fn IsExtensionSupported<Identity: windows_core::IUnknownImpl + ICoreAutomationRemoteOperationExtensionProvider_Impl>(this: *mut core::ffi::c_void, extensionid: windows_core::GUID, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICoreAutomationRemoteOperationExtensionProvider_Impl::IsExtensionSupported(this, core::mem::transmute(&extensionid)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CallExtension: CallExtension::<Identity>,
+            IsExtensionSupported: IsExtensionSupported::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ICoreAutomationRemoteOperationExtensionProvider_Vtbl {
@@ -545,6 +545,27 @@ impl IActivatedEventArgsDeferral_Vtbl {
         iid == &<IActivatedEventArgsDeferral as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActivatedEventArgsDeferral_Impl> windows_core::imp::TearOffVtable<Identity> for IActivatedEventArgsDeferral_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ActivatedOperation<Identity: windows_core::IUnknownImpl + IActivatedEventArgsDeferral_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActivatedEventArgsDeferral_Impl::ActivatedOperation(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ActivatedOperation: ActivatedOperation::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IActivatedEventArgsDeferral_Vtbl {
@@ -741,6 +762,33 @@ impl IWebUIBackgroundTaskInstance_Vtbl {
         iid == &<IWebUIBackgroundTaskInstance as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWebUIBackgroundTaskInstance_Impl> windows_core::imp::TearOffVtable<Identity> for IWebUIBackgroundTaskInstance_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Succeeded<Identity: windows_core::IUnknownImpl + IWebUIBackgroundTaskInstance_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebUIBackgroundTaskInstance_Impl::Succeeded(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSucceeded<Identity: windows_core::IUnknownImpl + IWebUIBackgroundTaskInstance_Impl>(this: *mut core::ffi::c_void, succeeded: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebUIBackgroundTaskInstance_Impl::SetSucceeded(this, succeeded).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Succeeded: Succeeded::<Identity>,
+            SetSucceeded: SetSucceeded::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IWebUIBackgroundTaskInstance_Vtbl {
@@ -812,6 +860,27 @@ impl IWebUINavigatedEventArgs_Vtbl {
         iid == &<IWebUINavigatedEventArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWebUINavigatedEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IWebUINavigatedEventArgs_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn NavigatedOperation<Identity: windows_core::IUnknownImpl + IWebUINavigatedEventArgs_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebUINavigatedEventArgs_Impl::NavigatedOperation(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            NavigatedOperation: NavigatedOperation::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IWebUINavigatedEventArgs_Vtbl {
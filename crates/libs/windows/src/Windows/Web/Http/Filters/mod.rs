@@ -545,6 +545,24 @@ impl IHttpFilter_Vtbl {
         iid == &<IHttpFilter as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IHttpFilter_Impl> windows_core::imp::TearOffVtable<Identity> for IHttpFilter_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn SendRequestAsync<Identity: windows_core::IUnknownImpl + IHttpFilter_Impl>(this: *mut core::ffi::c_void, request: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHttpFilter_Impl::SendRequestAsync(this, core::mem::transmute_copy(&request)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SendRequestAsync: SendRequestAsync::<Identity> }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IHttpFilter_Vtbl {
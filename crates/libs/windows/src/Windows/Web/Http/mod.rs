@@ -2307,6 +2307,111 @@ impl IHttpContent_Vtbl {
         iid == &<IHttpContent as windows_core::Interface>::IID
     }
 }
+#[cfg(all(feature = "Storage_Streams", feature = "Web_Http_Headers"))]
+impl<Identity: windows_core::IUnknownImpl + IHttpContent_Impl> windows_core::imp::TearOffVtable<Identity> for IHttpContent_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Headers<Identity: windows_core::IUnknownImpl + IHttpContent_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHttpContent_Impl::Headers(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn BufferAllAsync<Identity: windows_core::IUnknownImpl + IHttpContent_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHttpContent_Impl::BufferAllAsync(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReadAsBufferAsync<Identity: windows_core::IUnknownImpl + IHttpContent_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHttpContent_Impl::ReadAsBufferAsync(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReadAsInputStreamAsync<Identity: windows_core::IUnknownImpl + IHttpContent_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHttpContent_Impl::ReadAsInputStreamAsync(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReadAsStringAsync<Identity: windows_core::IUnknownImpl + IHttpContent_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHttpContent_Impl::ReadAsStringAsync(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn TryComputeLength<Identity: windows_core::IUnknownImpl + IHttpContent_Impl>(this: *mut core::ffi::c_void, length: *mut u64, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHttpContent_Impl::TryComputeLength(this, core::mem::transmute_copy(&length)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn WriteToStreamAsync<Identity: windows_core::IUnknownImpl + IHttpContent_Impl>(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHttpContent_Impl::WriteToStreamAsync(this, core::mem::transmute_copy(&outputstream)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Headers: Headers::<Identity>,
+            BufferAllAsync: BufferAllAsync::<Identity>,
+            ReadAsBufferAsync: ReadAsBufferAsync::<Identity>,
+            ReadAsInputStreamAsync: ReadAsInputStreamAsync::<Identity>,
+            ReadAsStringAsync: ReadAsStringAsync::<Identity>,
+            TryComputeLength: TryComputeLength::<Identity>,
+            WriteToStreamAsync: WriteToStreamAsync::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IHttpContent_Vtbl {
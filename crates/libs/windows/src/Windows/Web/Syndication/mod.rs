@@ -288,6 +288,137 @@ impl ISyndicationClient_Vtbl {
         iid == &<ISyndicationClient as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Security_Credentials")]
+impl<Identity: windows_core::IUnknownImpl + ISyndicationClient_Impl> windows_core::imp::TearOffVtable<Identity> for ISyndicationClient_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn ServerCredential<Identity: windows_core::IUnknownImpl + ISyndicationClient_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISyndicationClient_Impl::ServerCredential(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetServerCredential<Identity: windows_core::IUnknownImpl + ISyndicationClient_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISyndicationClient_Impl::SetServerCredential(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn ProxyCredential<Identity: windows_core::IUnknownImpl + ISyndicationClient_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISyndicationClient_Impl::ProxyCredential(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProxyCredential<Identity: windows_core::IUnknownImpl + ISyndicationClient_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISyndicationClient_Impl::SetProxyCredential(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn MaxResponseBufferSize<Identity: windows_core::IUnknownImpl + ISyndicationClient_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISyndicationClient_Impl::MaxResponseBufferSize(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMaxResponseBufferSize<Identity: windows_core::IUnknownImpl + ISyndicationClient_Impl>(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISyndicationClient_Impl::SetMaxResponseBufferSize(this, value).into()
+            }
+        }
+        unsafe extern "system" fn Timeout<Identity: windows_core::IUnknownImpl + ISyndicationClient_Impl>(this: *mut core::ffi::c_void, result__: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISyndicationClient_Impl::Timeout(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTimeout<Identity: windows_core::IUnknownImpl + ISyndicationClient_Impl>(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISyndicationClient_Impl::SetTimeout(this, value).into()
+            }
+        }
+        unsafe extern "system" fn BypassCacheOnRetrieve<Identity: windows_core::IUnknownImpl + ISyndicationClient_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISyndicationClient_Impl::BypassCacheOnRetrieve(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBypassCacheOnRetrieve<Identity: windows_core::IUnknownImpl + ISyndicationClient_Impl>(this: *mut core::ffi::c_void, value: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISyndicationClient_Impl::SetBypassCacheOnRetrieve(this, value).into()
+            }
+        }
+        unsafe extern "system" fn SetRequestHeader<Identity: windows_core::IUnknownImpl + ISyndicationClient_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISyndicationClient_Impl::SetRequestHeader(this, core::mem::transmute(&name), core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn RetrieveFeedAsync<Identity: windows_core::IUnknownImpl + ISyndicationClient_Impl>(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISyndicationClient_Impl::RetrieveFeedAsync(this, core::mem::transmute_copy(&uri)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ServerCredential: ServerCredential::<Identity>,
+            SetServerCredential: SetServerCredential::<Identity>,
+            ProxyCredential: ProxyCredential::<Identity>,
+            SetProxyCredential: SetProxyCredential::<Identity>,
+            MaxResponseBufferSize: MaxResponseBufferSize::<Identity>,
+            SetMaxResponseBufferSize: SetMaxResponseBufferSize::<Identity>,
+            Timeout: Timeout::<Identity>,
+            SetTimeout: SetTimeout::<Identity>,
+            BypassCacheOnRetrieve: BypassCacheOnRetrieve::<Identity>,
+            SetBypassCacheOnRetrieve: SetBypassCacheOnRetrieve::<Identity>,
+            SetRequestHeader: SetRequestHeader::<Identity>,
+            RetrieveFeedAsync: RetrieveFeedAsync::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ISyndicationClient_Vtbl {
@@ -784,6 +915,161 @@ impl ISyndicationNode_Vtbl {
         iid == &<ISyndicationNode as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Data_Xml_Dom")]
+impl<Identity: windows_core::IUnknownImpl + ISyndicationNode_Impl> windows_core::imp::TearOffVtable<Identity> for ISyndicationNode_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn NodeName<Identity: windows_core::IUnknownImpl + ISyndicationNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISyndicationNode_Impl::NodeName(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNodeName<Identity: windows_core::IUnknownImpl + ISyndicationNode_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISyndicationNode_Impl::SetNodeName(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn NodeNamespace<Identity: windows_core::IUnknownImpl + ISyndicationNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISyndicationNode_Impl::NodeNamespace(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNodeNamespace<Identity: windows_core::IUnknownImpl + ISyndicationNode_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISyndicationNode_Impl::SetNodeNamespace(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn NodeValue<Identity: windows_core::IUnknownImpl + ISyndicationNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISyndicationNode_Impl::NodeValue(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNodeValue<Identity: windows_core::IUnknownImpl + ISyndicationNode_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISyndicationNode_Impl::SetNodeValue(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Language<Identity: windows_core::IUnknownImpl + ISyndicationNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISyndicationNode_Impl::Language(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLanguage<Identity: windows_core::IUnknownImpl + ISyndicationNode_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISyndicationNode_Impl::SetLanguage(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn BaseUri<Identity: windows_core::IUnknownImpl + ISyndicationNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISyndicationNode_Impl::BaseUri(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBaseUri<Identity: windows_core::IUnknownImpl + ISyndicationNode_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISyndicationNode_Impl::SetBaseUri(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn AttributeExtensions<Identity: windows_core::IUnknownImpl + ISyndicationNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISyndicationNode_Impl::AttributeExtensions(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ElementExtensions<Identity: windows_core::IUnknownImpl + ISyndicationNode_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISyndicationNode_Impl::ElementExtensions(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetXmlDocument<Identity: windows_core::IUnknownImpl + ISyndicationNode_Impl>(this: *mut core::ffi::c_void, format: SyndicationFormat, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISyndicationNode_Impl::GetXmlDocument(this, format) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            NodeName: NodeName::<Identity>,
+            SetNodeName: SetNodeName::<Identity>,
+            NodeNamespace: NodeNamespace::<Identity>,
+            SetNodeNamespace: SetNodeNamespace::<Identity>,
+            NodeValue: NodeValue::<Identity>,
+            SetNodeValue: SetNodeValue::<Identity>,
+            Language: Language::<Identity>,
+            SetLanguage: SetLanguage::<Identity>,
+            BaseUri: BaseUri::<Identity>,
+            SetBaseUri: SetBaseUri::<Identity>,
+            AttributeExtensions: AttributeExtensions::<Identity>,
+            ElementExtensions: ElementExtensions::<Identity>,
+            GetXmlDocument: GetXmlDocument::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ISyndicationNode_Vtbl {
@@ -1054,6 +1340,77 @@ impl ISyndicationText_Vtbl {
         iid == &<ISyndicationText as windows_core::Interface>::IID
     }
 }
+#[cfg(feature = "Data_Xml_Dom")]
+impl<Identity: windows_core::IUnknownImpl + ISyndicationText_Impl> windows_core::imp::TearOffVtable<Identity> for ISyndicationText_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Text<Identity: windows_core::IUnknownImpl + ISyndicationText_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISyndicationText_Impl::Text(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetText<Identity: windows_core::IUnknownImpl + ISyndicationText_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISyndicationText_Impl::SetText(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Type<Identity: windows_core::IUnknownImpl + ISyndicationText_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISyndicationText_Impl::Type(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetType<Identity: windows_core::IUnknownImpl + ISyndicationText_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISyndicationText_Impl::SetType(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Xml<Identity: windows_core::IUnknownImpl + ISyndicationText_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISyndicationText_Impl::Xml(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetXml<Identity: windows_core::IUnknownImpl + ISyndicationText_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISyndicationText_Impl::SetXml(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Text: Text::<Identity>,
+            SetText: SetText::<Identity>,
+            Type: Type::<Identity>,
+            SetType: SetType::<Identity>,
+            Xml: Xml::<Identity>,
+            SetXml: SetXml::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ISyndicationText_Vtbl {
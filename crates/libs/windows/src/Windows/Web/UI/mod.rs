@@ -1053,6 +1053,595 @@ impl IWebViewControl_Vtbl {
         iid == &<IWebViewControl as windows_core::Interface>::IID
     }
 }
+#[cfg(all(feature = "ApplicationModel_DataTransfer", feature = "Storage_Streams", feature = "UI", feature = "Web_Http"))]
+impl<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl> windows_core::imp::TearOffVtable<Identity> for IWebViewControl_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn Source<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::Source(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSource<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::SetSource(this, core::mem::transmute_copy(&source)).into()
+            }
+        }
+        unsafe extern "system" fn DocumentTitle<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::DocumentTitle(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CanGoBack<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::CanGoBack(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CanGoForward<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::CanGoForward(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDefaultBackgroundColor<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, value: super::super::UI::Color) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::SetDefaultBackgroundColor(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn DefaultBackgroundColor<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::UI::Color) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::DefaultBackgroundColor(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ContainsFullScreenElement<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, result__: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::ContainsFullScreenElement(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Settings<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::Settings(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeferredPermissionRequests<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::DeferredPermissionRequests(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GoForward<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::GoForward(this).into()
+            }
+        }
+        unsafe extern "system" fn GoBack<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::GoBack(this).into()
+            }
+        }
+        unsafe extern "system" fn Refresh<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::Refresh(this).into()
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::Stop(this).into()
+            }
+        }
+        unsafe extern "system" fn Navigate<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::Navigate(this, core::mem::transmute_copy(&source)).into()
+            }
+        }
+        unsafe extern "system" fn NavigateToString<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, text: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::NavigateToString(this, core::mem::transmute(&text)).into()
+            }
+        }
+        unsafe extern "system" fn NavigateToLocalStreamUri<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void, streamresolver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::NavigateToLocalStreamUri(this, core::mem::transmute_copy(&source), core::mem::transmute_copy(&streamresolver)).into()
+            }
+        }
+        unsafe extern "system" fn NavigateWithHttpRequestMessage<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, requestmessage: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::NavigateWithHttpRequestMessage(this, core::mem::transmute_copy(&requestmessage)).into()
+            }
+        }
+        unsafe extern "system" fn InvokeScriptAsync<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, scriptname: *mut core::ffi::c_void, arguments: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::InvokeScriptAsync(this, core::mem::transmute(&scriptname), core::mem::transmute_copy(&arguments)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CapturePreviewToStreamAsync<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::CapturePreviewToStreamAsync(this, core::mem::transmute_copy(&stream)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CaptureSelectedContentToDataPackageAsync<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::CaptureSelectedContentToDataPackageAsync(this) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn BuildLocalStreamUri<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, contentidentifier: *mut core::ffi::c_void, relativepath: *mut core::ffi::c_void, result__: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::BuildLocalStreamUri(this, core::mem::transmute(&contentidentifier), core::mem::transmute(&relativepath)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        core::mem::forget(ok__);
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDeferredPermissionRequestById<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, id: u32, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::GetDeferredPermissionRequestById(this, id, core::mem::transmute_copy(&result)).into()
+            }
+        }
+        unsafe extern "system" fn NavigationStarting<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::NavigationStarting(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveNavigationStarting<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::RemoveNavigationStarting(this, token).into()
+            }
+        }
+        unsafe extern "system" fn ContentLoading<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::ContentLoading(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveContentLoading<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::RemoveContentLoading(this, token).into()
+            }
+        }
+        unsafe extern "system" fn DOMContentLoaded<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::DOMContentLoaded(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveDOMContentLoaded<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::RemoveDOMContentLoaded(this, token).into()
+            }
+        }
+        unsafe extern "system" fn NavigationCompleted<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::NavigationCompleted(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveNavigationCompleted<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::RemoveNavigationCompleted(this, token).into()
+            }
+        }
+        unsafe extern "system" fn FrameNavigationStarting<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::FrameNavigationStarting(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveFrameNavigationStarting<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::RemoveFrameNavigationStarting(this, token).into()
+            }
+        }
+        unsafe extern "system" fn FrameContentLoading<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::FrameContentLoading(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveFrameContentLoading<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::RemoveFrameContentLoading(this, token).into()
+            }
+        }
+        unsafe extern "system" fn FrameDOMContentLoaded<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::FrameDOMContentLoaded(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveFrameDOMContentLoaded<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::RemoveFrameDOMContentLoaded(this, token).into()
+            }
+        }
+        unsafe extern "system" fn FrameNavigationCompleted<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::FrameNavigationCompleted(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveFrameNavigationCompleted<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::RemoveFrameNavigationCompleted(this, token).into()
+            }
+        }
+        unsafe extern "system" fn ScriptNotify<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::ScriptNotify(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveScriptNotify<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::RemoveScriptNotify(this, token).into()
+            }
+        }
+        unsafe extern "system" fn LongRunningScriptDetected<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::LongRunningScriptDetected(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveLongRunningScriptDetected<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::RemoveLongRunningScriptDetected(this, token).into()
+            }
+        }
+        unsafe extern "system" fn UnsafeContentWarningDisplaying<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::UnsafeContentWarningDisplaying(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveUnsafeContentWarningDisplaying<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::RemoveUnsafeContentWarningDisplaying(this, token).into()
+            }
+        }
+        unsafe extern "system" fn UnviewableContentIdentified<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::UnviewableContentIdentified(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveUnviewableContentIdentified<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::RemoveUnviewableContentIdentified(this, token).into()
+            }
+        }
+        unsafe extern "system" fn PermissionRequested<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::PermissionRequested(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemovePermissionRequested<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::RemovePermissionRequested(this, token).into()
+            }
+        }
+        unsafe extern "system" fn UnsupportedUriSchemeIdentified<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::UnsupportedUriSchemeIdentified(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveUnsupportedUriSchemeIdentified<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::RemoveUnsupportedUriSchemeIdentified(this, token).into()
+            }
+        }
+        unsafe extern "system" fn NewWindowRequested<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::NewWindowRequested(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveNewWindowRequested<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::RemoveNewWindowRequested(this, token).into()
+            }
+        }
+        unsafe extern "system" fn ContainsFullScreenElementChanged<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::ContainsFullScreenElementChanged(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveContainsFullScreenElementChanged<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::RemoveContainsFullScreenElementChanged(this, token).into()
+            }
+        }
+        unsafe extern "system" fn WebResourceRequested<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, result__: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebViewControl_Impl::WebResourceRequested(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        result__.write(core::mem::transmute_copy(&ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveWebResourceRequested<Identity: windows_core::IUnknownImpl + IWebViewControl_Impl>(this: *mut core::ffi::c_void, token: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl_Impl::RemoveWebResourceRequested(this, token).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Source: Source::<Identity>,
+            SetSource: SetSource::<Identity>,
+            DocumentTitle: DocumentTitle::<Identity>,
+            CanGoBack: CanGoBack::<Identity>,
+            CanGoForward: CanGoForward::<Identity>,
+            SetDefaultBackgroundColor: SetDefaultBackgroundColor::<Identity>,
+            DefaultBackgroundColor: DefaultBackgroundColor::<Identity>,
+            ContainsFullScreenElement: ContainsFullScreenElement::<Identity>,
+            Settings: Settings::<Identity>,
+            DeferredPermissionRequests: DeferredPermissionRequests::<Identity>,
+            GoForward: GoForward::<Identity>,
+            GoBack: GoBack::<Identity>,
+            Refresh: Refresh::<Identity>,
+            Stop: Stop::<Identity>,
+            Navigate: Navigate::<Identity>,
+            NavigateToString: NavigateToString::<Identity>,
+            NavigateToLocalStreamUri: NavigateToLocalStreamUri::<Identity>,
+            NavigateWithHttpRequestMessage: NavigateWithHttpRequestMessage::<Identity>,
+            InvokeScriptAsync: InvokeScriptAsync::<Identity>,
+            CapturePreviewToStreamAsync: CapturePreviewToStreamAsync::<Identity>,
+            CaptureSelectedContentToDataPackageAsync: CaptureSelectedContentToDataPackageAsync::<Identity>,
+            BuildLocalStreamUri: BuildLocalStreamUri::<Identity>,
+            GetDeferredPermissionRequestById: GetDeferredPermissionRequestById::<Identity>,
+            NavigationStarting: NavigationStarting::<Identity>,
+            RemoveNavigationStarting: RemoveNavigationStarting::<Identity>,
+            ContentLoading: ContentLoading::<Identity>,
+            RemoveContentLoading: RemoveContentLoading::<Identity>,
+            DOMContentLoaded: DOMContentLoaded::<Identity>,
+            RemoveDOMContentLoaded: RemoveDOMContentLoaded::<Identity>,
+            NavigationCompleted: NavigationCompleted::<Identity>,
+            RemoveNavigationCompleted: RemoveNavigationCompleted::<Identity>,
+            FrameNavigationStarting: FrameNavigationStarting::<Identity>,
+            RemoveFrameNavigationStarting: RemoveFrameNavigationStarting::<Identity>,
+            FrameContentLoading: FrameContentLoading::<Identity>,
+            RemoveFrameContentLoading: RemoveFrameContentLoading::<Identity>,
+            FrameDOMContentLoaded: FrameDOMContentLoaded::<Identity>,
+            RemoveFrameDOMContentLoaded: RemoveFrameDOMContentLoaded::<Identity>,
+            FrameNavigationCompleted: FrameNavigationCompleted::<Identity>,
+            RemoveFrameNavigationCompleted: RemoveFrameNavigationCompleted::<Identity>,
+            ScriptNotify: ScriptNotify::<Identity>,
+            RemoveScriptNotify: RemoveScriptNotify::<Identity>,
+            LongRunningScriptDetected: LongRunningScriptDetected::<Identity>,
+            RemoveLongRunningScriptDetected: RemoveLongRunningScriptDetected::<Identity>,
+            UnsafeContentWarningDisplaying: UnsafeContentWarningDisplaying::<Identity>,
+            RemoveUnsafeContentWarningDisplaying: RemoveUnsafeContentWarningDisplaying::<Identity>,
+            UnviewableContentIdentified: UnviewableContentIdentified::<Identity>,
+            RemoveUnviewableContentIdentified: RemoveUnviewableContentIdentified::<Identity>,
+            PermissionRequested: PermissionRequested::<Identity>,
+            RemovePermissionRequested: RemovePermissionRequested::<Identity>,
+            UnsupportedUriSchemeIdentified: UnsupportedUriSchemeIdentified::<Identity>,
+            RemoveUnsupportedUriSchemeIdentified: RemoveUnsupportedUriSchemeIdentified::<Identity>,
+            NewWindowRequested: NewWindowRequested::<Identity>,
+            RemoveNewWindowRequested: RemoveNewWindowRequested::<Identity>,
+            ContainsFullScreenElementChanged: ContainsFullScreenElementChanged::<Identity>,
+            RemoveContainsFullScreenElementChanged: RemoveContainsFullScreenElementChanged::<Identity>,
+            WebResourceRequested: WebResourceRequested::<Identity>,
+            RemoveWebResourceRequested: RemoveWebResourceRequested::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IWebViewControl_Vtbl {
@@ -1164,6 +1753,20 @@ impl IWebViewControl2_Vtbl {
         iid == &<IWebViewControl2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWebViewControl2_Impl> windows_core::imp::TearOffVtable<Identity> for IWebViewControl2_Vtbl {
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddInitializeScript<Identity: windows_core::IUnknownImpl + IWebViewControl2_Impl>(this: *mut core::ffi::c_void, script: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebViewControl2_Impl::AddInitializeScript(this, core::mem::transmute(&script)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddInitializeScript: AddInitializeScript::<Identity>,
+        }
+    };
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IWebViewControl2_Vtbl {
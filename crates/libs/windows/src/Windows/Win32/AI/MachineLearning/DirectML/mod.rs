@@ -2770,6 +2770,52 @@ impl IDMLBindingTable_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl<Identity: windows_core::IUnknownImpl + IDMLBindingTable_Impl> windows_core::imp::TearOffVtable<Identity> for IDMLBindingTable_Vtbl
+where
+    IDMLDeviceChild_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BindInputs<Identity: windows_core::IUnknownImpl + IDMLBindingTable_Impl>(this: *mut core::ffi::c_void, bindingcount: u32, bindings: *const DML_BINDING_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLBindingTable_Impl::BindInputs(this, core::mem::transmute_copy(&bindingcount), core::mem::transmute_copy(&bindings))
+            }
+        }
+        unsafe extern "system" fn BindOutputs<Identity: windows_core::IUnknownImpl + IDMLBindingTable_Impl>(this: *mut core::ffi::c_void, bindingcount: u32, bindings: *const DML_BINDING_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLBindingTable_Impl::BindOutputs(this, core::mem::transmute_copy(&bindingcount), core::mem::transmute_copy(&bindings))
+            }
+        }
+        unsafe extern "system" fn BindTemporaryResource<Identity: windows_core::IUnknownImpl + IDMLBindingTable_Impl>(this: *mut core::ffi::c_void, binding: *const DML_BINDING_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLBindingTable_Impl::BindTemporaryResource(this, core::mem::transmute_copy(&binding))
+            }
+        }
+        unsafe extern "system" fn BindPersistentResource<Identity: windows_core::IUnknownImpl + IDMLBindingTable_Impl>(this: *mut core::ffi::c_void, binding: *const DML_BINDING_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLBindingTable_Impl::BindPersistentResource(this, core::mem::transmute_copy(&binding))
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IDMLBindingTable_Impl>(this: *mut core::ffi::c_void, desc: *const DML_BINDING_TABLE_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLBindingTable_Impl::Reset(this, core::mem::transmute_copy(&desc)).into()
+            }
+        }
+        Self {
+            base__: <IDMLDeviceChild_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            BindInputs: BindInputs::<Identity>,
+            BindOutputs: BindOutputs::<Identity>,
+            BindTemporaryResource: BindTemporaryResource::<Identity>,
+            BindPersistentResource: BindPersistentResource::<Identity>,
+            Reset: Reset::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
 impl windows_core::RuntimeName for IDMLBindingTable {}
 windows_core::imp::define_interface!(IDMLCommandRecorder, IDMLCommandRecorder_Vtbl, 0xe6857a76_2e3e_4fdd_bff4_5d2ba10fb453);
 impl core::ops::Deref for IDMLCommandRecorder {
@@ -2819,6 +2865,21 @@ impl IDMLCommandRecorder_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl<Identity: windows_core::IUnknownImpl + IDMLCommandRecorder_Impl> windows_core::imp::TearOffVtable<Identity> for IDMLCommandRecorder_Vtbl
+where
+    IDMLDeviceChild_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RecordDispatch<Identity: windows_core::IUnknownImpl + IDMLCommandRecorder_Impl>(this: *mut core::ffi::c_void, commandlist: *mut core::ffi::c_void, dispatchable: *mut core::ffi::c_void, bindings: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLCommandRecorder_Impl::RecordDispatch(this, core::mem::transmute_copy(&commandlist), core::mem::transmute_copy(&dispatchable), core::mem::transmute_copy(&bindings))
+            }
+        }
+        Self { base__: <IDMLDeviceChild_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, RecordDispatch: RecordDispatch::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
 impl windows_core::RuntimeName for IDMLCommandRecorder {}
 windows_core::imp::define_interface!(IDMLCompiledOperator, IDMLCompiledOperator_Vtbl, 0x6b15e56a_bf5c_4902_92d8_da3a650afea4);
 impl core::ops::Deref for IDMLCompiledOperator {
@@ -2842,6 +2903,12 @@ impl IDMLCompiledOperator_Vtbl {
         iid == &<IDMLCompiledOperator as windows_core::Interface>::IID || iid == &<IDMLObject as windows_core::Interface>::IID || iid == &<IDMLDeviceChild as windows_core::Interface>::IID || iid == &<IDMLPageable as windows_core::Interface>::IID || iid == &<IDMLDispatchable as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDMLCompiledOperator_Impl> windows_core::imp::TearOffVtable<Identity> for IDMLCompiledOperator_Vtbl
+where
+    IDMLDispatchable_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IDMLDispatchable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IDMLCompiledOperator {}
 windows_core::imp::define_interface!(IDMLDebugDevice, IDMLDebugDevice_Vtbl, 0x7d6f3ac9_394a_4ac3_92a7_390cc57a8217);
 windows_core::imp::interface_hierarchy!(IDMLDebugDevice, windows_core::IUnknown);
@@ -2873,6 +2940,20 @@ impl IDMLDebugDevice_Vtbl {
         iid == &<IDMLDebugDevice as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDMLDebugDevice_Impl> windows_core::imp::TearOffVtable<Identity> for IDMLDebugDevice_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetMuteDebugOutput<Identity: windows_core::IUnknownImpl + IDMLDebugDevice_Impl>(this: *mut core::ffi::c_void, mute: windows_core::BOOL) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLDebugDevice_Impl::SetMuteDebugOutput(this, core::mem::transmute_copy(&mute))
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetMuteDebugOutput: SetMuteDebugOutput::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDMLDebugDevice {}
 windows_core::imp::define_interface!(IDMLDevice, IDMLDevice_Vtbl, 0x6dbd6437_96fd_423f_a98c_ae5e7c2a573f);
 impl core::ops::Deref for IDMLDevice {
@@ -3051,6 +3132,87 @@ impl IDMLDevice_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl<Identity: windows_core::IUnknownImpl + IDMLDevice_Impl> windows_core::imp::TearOffVtable<Identity> for IDMLDevice_Vtbl
+where
+    IDMLObject_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CheckFeatureSupport<Identity: windows_core::IUnknownImpl + IDMLDevice_Impl>(this: *mut core::ffi::c_void, feature: DML_FEATURE, featurequerydatasize: u32, featurequerydata: *const core::ffi::c_void, featuresupportdatasize: u32, featuresupportdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLDevice_Impl::CheckFeatureSupport(this, core::mem::transmute_copy(&feature), core::mem::transmute_copy(&featurequerydatasize), core::mem::transmute_copy(&featurequerydata), core::mem::transmute_copy(&featuresupportdatasize), core::mem::transmute_copy(&featuresupportdata)).into()
+            }
+        }
+        unsafe extern "system" fn CreateOperator<Identity: windows_core::IUnknownImpl + IDMLDevice_Impl>(this: *mut core::ffi::c_void, desc: *const DML_OPERATOR_DESC, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLDevice_Impl::CreateOperator(this, core::mem::transmute_copy(&desc), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppv)).into()
+            }
+        }
+        unsafe extern "system" fn CompileOperator<Identity: windows_core::IUnknownImpl + IDMLDevice_Impl>(this: *mut core::ffi::c_void, op: *mut core::ffi::c_void, flags: DML_EXECUTION_FLAGS, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLDevice_Impl::CompileOperator(this, core::mem::transmute_copy(&op), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppv)).into()
+            }
+        }
+        unsafe extern "system" fn CreateOperatorInitializer<Identity: windows_core::IUnknownImpl + IDMLDevice_Impl>(this: *mut core::ffi::c_void, operatorcount: u32, operators: *const *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLDevice_Impl::CreateOperatorInitializer(this, core::mem::transmute_copy(&operatorcount), core::mem::transmute_copy(&operators), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppv)).into()
+            }
+        }
+        unsafe extern "system" fn CreateCommandRecorder<Identity: windows_core::IUnknownImpl + IDMLDevice_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLDevice_Impl::CreateCommandRecorder(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppv)).into()
+            }
+        }
+        unsafe extern "system" fn CreateBindingTable<Identity: windows_core::IUnknownImpl + IDMLDevice_Impl>(this: *mut core::ffi::c_void, desc: *const DML_BINDING_TABLE_DESC, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLDevice_Impl::CreateBindingTable(this, core::mem::transmute_copy(&desc), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppv)).into()
+            }
+        }
+        unsafe extern "system" fn Evict<Identity: windows_core::IUnknownImpl + IDMLDevice_Impl>(this: *mut core::ffi::c_void, count: u32, ppobjects: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLDevice_Impl::Evict(this, core::mem::transmute_copy(&count), core::mem::transmute_copy(&ppobjects)).into()
+            }
+        }
+        unsafe extern "system" fn MakeResident<Identity: windows_core::IUnknownImpl + IDMLDevice_Impl>(this: *mut core::ffi::c_void, count: u32, ppobjects: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLDevice_Impl::MakeResident(this, core::mem::transmute_copy(&count), core::mem::transmute_copy(&ppobjects)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceRemovedReason<Identity: windows_core::IUnknownImpl + IDMLDevice_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLDevice_Impl::GetDeviceRemovedReason(this).into()
+            }
+        }
+        unsafe extern "system" fn GetParentDevice<Identity: windows_core::IUnknownImpl + IDMLDevice_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLDevice_Impl::GetParentDevice(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppv)).into()
+            }
+        }
+        Self {
+            base__: <IDMLObject_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CheckFeatureSupport: CheckFeatureSupport::<Identity>,
+            CreateOperator: CreateOperator::<Identity>,
+            CompileOperator: CompileOperator::<Identity>,
+            CreateOperatorInitializer: CreateOperatorInitializer::<Identity>,
+            CreateCommandRecorder: CreateCommandRecorder::<Identity>,
+            CreateBindingTable: CreateBindingTable::<Identity>,
+            Evict: Evict::<Identity>,
+            MakeResident: MakeResident::<Identity>,
+            GetDeviceRemovedReason: GetDeviceRemovedReason::<Identity>,
+            GetParentDevice: GetParentDevice::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
 impl windows_core::RuntimeName for IDMLDevice {}
 windows_core::imp::define_interface!(IDMLDevice1, IDMLDevice1_Vtbl, 0xa0884f9a_d2be_4355_aa5d_5901281ad1d2);
 impl core::ops::Deref for IDMLDevice1 {
@@ -3094,6 +3256,21 @@ impl IDMLDevice1_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl<Identity: windows_core::IUnknownImpl + IDMLDevice1_Impl> windows_core::imp::TearOffVtable<Identity> for IDMLDevice1_Vtbl
+where
+    IDMLDevice_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CompileGraph<Identity: windows_core::IUnknownImpl + IDMLDevice1_Impl>(this: *mut core::ffi::c_void, desc: *const DML_GRAPH_DESC, flags: DML_EXECUTION_FLAGS, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLDevice1_Impl::CompileGraph(this, core::mem::transmute_copy(&desc), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppv)).into()
+            }
+        }
+        Self { base__: <IDMLDevice_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CompileGraph: CompileGraph::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
 impl windows_core::RuntimeName for IDMLDevice1 {}
 windows_core::imp::define_interface!(IDMLDeviceChild, IDMLDeviceChild_Vtbl, 0x27e83142_8165_49e3_974e_2fd66e4cb69d);
 impl core::ops::Deref for IDMLDeviceChild {
@@ -3135,6 +3312,20 @@ impl IDMLDeviceChild_Vtbl {
         iid == &<IDMLDeviceChild as windows_core::Interface>::IID || iid == &<IDMLObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDMLDeviceChild_Impl> windows_core::imp::TearOffVtable<Identity> for IDMLDeviceChild_Vtbl
+where
+    IDMLObject_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDevice<Identity: windows_core::IUnknownImpl + IDMLDeviceChild_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLDeviceChild_Impl::GetDevice(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppv)).into()
+            }
+        }
+        Self { base__: <IDMLObject_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDevice: GetDevice::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDMLDeviceChild {}
 windows_core::imp::define_interface!(IDMLDispatchable, IDMLDispatchable_Vtbl, 0xdcb821a8_1039_441e_9f1c_b1759c2f3cec);
 impl core::ops::Deref for IDMLDispatchable {
@@ -3176,6 +3367,20 @@ impl IDMLDispatchable_Vtbl {
         iid == &<IDMLDispatchable as windows_core::Interface>::IID || iid == &<IDMLObject as windows_core::Interface>::IID || iid == &<IDMLDeviceChild as windows_core::Interface>::IID || iid == &<IDMLPageable as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDMLDispatchable_Impl> windows_core::imp::TearOffVtable<Identity> for IDMLDispatchable_Vtbl
+where
+    IDMLPageable_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetBindingProperties<Identity: windows_core::IUnknownImpl + IDMLDispatchable_Impl>(this: *mut core::ffi::c_void, result__: *mut DML_BINDING_PROPERTIES) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = IDMLDispatchable_Impl::GetBindingProperties(this)
+            }
+        }
+        Self { base__: <IDMLPageable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetBindingProperties: GetBindingProperties::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDMLDispatchable {}
 windows_core::imp::define_interface!(IDMLObject, IDMLObject_Vtbl, 0xc8263aac_9e0c_4a2d_9b8e_007521a3317c);
 windows_core::imp::interface_hierarchy!(IDMLObject, windows_core::IUnknown);
@@ -3252,6 +3457,44 @@ impl IDMLObject_Vtbl {
         iid == &<IDMLObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDMLObject_Impl> windows_core::imp::TearOffVtable<Identity> for IDMLObject_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPrivateData<Identity: windows_core::IUnknownImpl + IDMLObject_Impl>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, datasize: *mut u32, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLObject_Impl::GetPrivateData(this, core::mem::transmute_copy(&guid), core::mem::transmute_copy(&datasize), core::mem::transmute_copy(&data)).into()
+            }
+        }
+        unsafe extern "system" fn SetPrivateData<Identity: windows_core::IUnknownImpl + IDMLObject_Impl>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, datasize: u32, data: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLObject_Impl::SetPrivateData(this, core::mem::transmute_copy(&guid), core::mem::transmute_copy(&datasize), core::mem::transmute_copy(&data)).into()
+            }
+        }
+        unsafe extern "system" fn SetPrivateDataInterface<Identity: windows_core::IUnknownImpl + IDMLObject_Impl>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLObject_Impl::SetPrivateDataInterface(this, core::mem::transmute_copy(&guid), core::mem::transmute_copy(&data)).into()
+            }
+        }
+        unsafe extern "system" fn SetName<Identity: windows_core::IUnknownImpl + IDMLObject_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLObject_Impl::SetName(this, core::mem::transmute(&name)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPrivateData: GetPrivateData::<Identity>,
+            SetPrivateData: SetPrivateData::<Identity>,
+            SetPrivateDataInterface: SetPrivateDataInterface::<Identity>,
+            SetName: SetName::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDMLObject {}
 windows_core::imp::define_interface!(IDMLOperator, IDMLOperator_Vtbl, 0x26caae7a_3081_4633_9581_226fbe57695d);
 impl core::ops::Deref for IDMLOperator {
@@ -3275,6 +3518,12 @@ impl IDMLOperator_Vtbl {
         iid == &<IDMLOperator as windows_core::Interface>::IID || iid == &<IDMLObject as windows_core::Interface>::IID || iid == &<IDMLDeviceChild as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDMLOperator_Impl> windows_core::imp::TearOffVtable<Identity> for IDMLOperator_Vtbl
+where
+    IDMLDeviceChild_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IDMLDeviceChild_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IDMLOperator {}
 windows_core::imp::define_interface!(IDMLOperatorInitializer, IDMLOperatorInitializer_Vtbl, 0x427c1113_435c_469c_8676_4d5dd072f813);
 impl core::ops::Deref for IDMLOperatorInitializer {
@@ -3312,6 +3561,20 @@ impl IDMLOperatorInitializer_Vtbl {
         iid == &<IDMLOperatorInitializer as windows_core::Interface>::IID || iid == &<IDMLObject as windows_core::Interface>::IID || iid == &<IDMLDeviceChild as windows_core::Interface>::IID || iid == &<IDMLPageable as windows_core::Interface>::IID || iid == &<IDMLDispatchable as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDMLOperatorInitializer_Impl> windows_core::imp::TearOffVtable<Identity> for IDMLOperatorInitializer_Vtbl
+where
+    IDMLDispatchable_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IDMLOperatorInitializer_Impl>(this: *mut core::ffi::c_void, operatorcount: u32, operators: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMLOperatorInitializer_Impl::Reset(this, core::mem::transmute_copy(&operatorcount), core::mem::transmute_copy(&operators)).into()
+            }
+        }
+        Self { base__: <IDMLDispatchable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Reset: Reset::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDMLOperatorInitializer {}
 windows_core::imp::define_interface!(IDMLPageable, IDMLPageable_Vtbl, 0xb1ab0825_4542_4a4b_8617_6dde6e8f6201);
 impl core::ops::Deref for IDMLPageable {
@@ -3335,4 +3598,10 @@ impl IDMLPageable_Vtbl {
         iid == &<IDMLPageable as windows_core::Interface>::IID || iid == &<IDMLObject as windows_core::Interface>::IID || iid == &<IDMLDeviceChild as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDMLPageable_Impl> windows_core::imp::TearOffVtable<Identity> for IDMLPageable_Vtbl
+where
+    IDMLDeviceChild_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IDMLDeviceChild_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IDMLPageable {}
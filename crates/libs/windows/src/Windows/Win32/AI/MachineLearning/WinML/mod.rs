@@ -113,6 +113,56 @@ impl IMLOperatorAttributes_Vtbl {
         iid == &<IMLOperatorAttributes as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMLOperatorAttributes_Impl> windows_core::imp::TearOffVtable<Identity> for IMLOperatorAttributes_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAttributeElementCount<Identity: windows_core::IUnknownImpl + IMLOperatorAttributes_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCSTR, r#type: MLOperatorAttributeType, elementcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMLOperatorAttributes_Impl::GetAttributeElementCount(this, core::mem::transmute(&name), core::mem::transmute_copy(&r#type)) {
+                    Ok(ok__) => {
+                        elementcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAttribute<Identity: windows_core::IUnknownImpl + IMLOperatorAttributes_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCSTR, r#type: MLOperatorAttributeType, elementcount: u32, elementbytesize: usize, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorAttributes_Impl::GetAttribute(this, core::mem::transmute(&name), core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&elementcount), core::mem::transmute_copy(&elementbytesize), core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn GetStringAttributeElementLength<Identity: windows_core::IUnknownImpl + IMLOperatorAttributes_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCSTR, elementindex: u32, attributeelementbytesize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMLOperatorAttributes_Impl::GetStringAttributeElementLength(this, core::mem::transmute(&name), core::mem::transmute_copy(&elementindex)) {
+                    Ok(ok__) => {
+                        attributeelementbytesize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStringAttributeElement<Identity: windows_core::IUnknownImpl + IMLOperatorAttributes_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCSTR, elementindex: u32, attributeelementbytesize: u32, attributeelement: windows_core::PSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorAttributes_Impl::GetStringAttributeElement(this, core::mem::transmute(&name), core::mem::transmute_copy(&elementindex), core::mem::transmute_copy(&attributeelementbytesize), core::mem::transmute_copy(&attributeelement)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAttributeElementCount: GetAttributeElementCount::<Identity>,
+            GetAttribute: GetAttribute::<Identity>,
+            GetStringAttributeElementLength: GetStringAttributeElementLength::<Identity>,
+            GetStringAttributeElement: GetStringAttributeElement::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMLOperatorAttributes {}
 windows_core::imp::define_interface!(IMLOperatorKernel, IMLOperatorKernel_Vtbl, 0x11c4b4a0_b467_4eaa_a1a6_b961d8d0ed79);
 windows_core::imp::interface_hierarchy!(IMLOperatorKernel, windows_core::IUnknown);
@@ -147,6 +197,20 @@ impl IMLOperatorKernel_Vtbl {
         iid == &<IMLOperatorKernel as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMLOperatorKernel_Impl> windows_core::imp::TearOffVtable<Identity> for IMLOperatorKernel_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Compute<Identity: windows_core::IUnknownImpl + IMLOperatorKernel_Impl>(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorKernel_Impl::Compute(this, core::mem::transmute_copy(&context)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Compute: Compute::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IMLOperatorKernel {}
 windows_core::imp::define_interface!(IMLOperatorKernelContext, IMLOperatorKernelContext_Vtbl, 0x82536a28_f022_4769_9d3f_8b278f84c0c3);
 windows_core::imp::interface_hierarchy!(IMLOperatorKernelContext, windows_core::IUnknown);
@@ -269,6 +333,75 @@ impl IMLOperatorKernelContext_Vtbl {
         iid == &<IMLOperatorKernelContext as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMLOperatorKernelContext_Impl> windows_core::imp::TearOffVtable<Identity> for IMLOperatorKernelContext_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetInputTensor<Identity: windows_core::IUnknownImpl + IMLOperatorKernelContext_Impl>(this: *mut core::ffi::c_void, inputindex: u32, tensor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMLOperatorKernelContext_Impl::GetInputTensor(this, core::mem::transmute_copy(&inputindex)) {
+                    Ok(ok__) => {
+                        tensor.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOutputTensor<Identity: windows_core::IUnknownImpl + IMLOperatorKernelContext_Impl>(this: *mut core::ffi::c_void, outputindex: u32, dimensioncount: u32, dimensionsizes: *const u32, tensor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMLOperatorKernelContext_Impl::GetOutputTensor(this, core::mem::transmute_copy(&outputindex), core::mem::transmute_copy(&dimensioncount), core::mem::transmute_copy(&dimensionsizes)) {
+                    Ok(ok__) => {
+                        tensor.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOutputTensor2<Identity: windows_core::IUnknownImpl + IMLOperatorKernelContext_Impl>(this: *mut core::ffi::c_void, outputindex: u32, tensor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMLOperatorKernelContext_Impl::GetOutputTensor2(this, core::mem::transmute_copy(&outputindex)) {
+                    Ok(ok__) => {
+                        tensor.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AllocateTemporaryData<Identity: windows_core::IUnknownImpl + IMLOperatorKernelContext_Impl>(this: *mut core::ffi::c_void, size: usize, data: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMLOperatorKernelContext_Impl::AllocateTemporaryData(this, core::mem::transmute_copy(&size)) {
+                    Ok(ok__) => {
+                        data.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetExecutionInterface<Identity: windows_core::IUnknownImpl + IMLOperatorKernelContext_Impl>(this: *mut core::ffi::c_void, executionobject: *mut *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorKernelContext_Impl::GetExecutionInterface(this, core::mem::transmute_copy(&executionobject))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetInputTensor: GetInputTensor::<Identity>,
+            GetOutputTensor: GetOutputTensor::<Identity>,
+            GetOutputTensor2: GetOutputTensor2::<Identity>,
+            AllocateTemporaryData: AllocateTemporaryData::<Identity>,
+            GetExecutionInterface: GetExecutionInterface::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMLOperatorKernelContext {}
 windows_core::imp::define_interface!(IMLOperatorKernelCreationContext, IMLOperatorKernelCreationContext_Vtbl, 0x5459b53d_a0fc_4665_addd_70171ef7e631);
 impl core::ops::Deref for IMLOperatorKernelCreationContext {
@@ -436,6 +569,97 @@ impl IMLOperatorKernelCreationContext_Vtbl {
         iid == &<IMLOperatorKernelCreationContext as windows_core::Interface>::IID || iid == &<IMLOperatorAttributes as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMLOperatorKernelCreationContext_Impl> windows_core::imp::TearOffVtable<Identity> for IMLOperatorKernelCreationContext_Vtbl
+where
+    IMLOperatorAttributes_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetInputCount<Identity: windows_core::IUnknownImpl + IMLOperatorKernelCreationContext_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorKernelCreationContext_Impl::GetInputCount(this)
+            }
+        }
+        unsafe extern "system" fn GetOutputCount<Identity: windows_core::IUnknownImpl + IMLOperatorKernelCreationContext_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorKernelCreationContext_Impl::GetOutputCount(this)
+            }
+        }
+        unsafe extern "system" fn IsInputValid<Identity: windows_core::IUnknownImpl + IMLOperatorKernelCreationContext_Impl>(this: *mut core::ffi::c_void, inputindex: u32) -> bool {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorKernelCreationContext_Impl::IsInputValid(this, core::mem::transmute_copy(&inputindex))
+            }
+        }
+        unsafe extern "system" fn IsOutputValid<Identity: windows_core::IUnknownImpl + IMLOperatorKernelCreationContext_Impl>(this: *mut core::ffi::c_void, outputindex: u32) -> bool {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorKernelCreationContext_Impl::IsOutputValid(this, core::mem::transmute_copy(&outputindex))
+            }
+        }
+        unsafe extern "system" fn GetInputEdgeDescription<Identity: windows_core::IUnknownImpl + IMLOperatorKernelCreationContext_Impl>(this: *mut core::ffi::c_void, inputindex: u32, edgedescription: *mut MLOperatorEdgeDescription) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMLOperatorKernelCreationContext_Impl::GetInputEdgeDescription(this, core::mem::transmute_copy(&inputindex)) {
+                    Ok(ok__) => {
+                        edgedescription.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOutputEdgeDescription<Identity: windows_core::IUnknownImpl + IMLOperatorKernelCreationContext_Impl>(this: *mut core::ffi::c_void, outputindex: u32, edgedescription: *mut MLOperatorEdgeDescription) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMLOperatorKernelCreationContext_Impl::GetOutputEdgeDescription(this, core::mem::transmute_copy(&outputindex)) {
+                    Ok(ok__) => {
+                        edgedescription.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn HasTensorShapeDescription<Identity: windows_core::IUnknownImpl + IMLOperatorKernelCreationContext_Impl>(this: *mut core::ffi::c_void) -> bool {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorKernelCreationContext_Impl::HasTensorShapeDescription(this)
+            }
+        }
+        unsafe extern "system" fn GetTensorShapeDescription<Identity: windows_core::IUnknownImpl + IMLOperatorKernelCreationContext_Impl>(this: *mut core::ffi::c_void, shapedescription: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMLOperatorKernelCreationContext_Impl::GetTensorShapeDescription(this) {
+                    Ok(ok__) => {
+                        shapedescription.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetExecutionInterface<Identity: windows_core::IUnknownImpl + IMLOperatorKernelCreationContext_Impl>(this: *mut core::ffi::c_void, executionobject: *mut *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorKernelCreationContext_Impl::GetExecutionInterface(this, core::mem::transmute_copy(&executionobject))
+            }
+        }
+        Self {
+            base__: <IMLOperatorAttributes_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetInputCount: GetInputCount::<Identity>,
+            GetOutputCount: GetOutputCount::<Identity>,
+            IsInputValid: IsInputValid::<Identity>,
+            IsOutputValid: IsOutputValid::<Identity>,
+            GetInputEdgeDescription: GetInputEdgeDescription::<Identity>,
+            GetOutputEdgeDescription: GetOutputEdgeDescription::<Identity>,
+            HasTensorShapeDescription: HasTensorShapeDescription::<Identity>,
+            GetTensorShapeDescription: GetTensorShapeDescription::<Identity>,
+            GetExecutionInterface: GetExecutionInterface::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMLOperatorKernelCreationContext {}
 windows_core::imp::define_interface!(IMLOperatorKernelFactory, IMLOperatorKernelFactory_Vtbl, 0xef15ad6f_0dc9_4908_ab35_a575a30dfbf8);
 windows_core::imp::interface_hierarchy!(IMLOperatorKernelFactory, windows_core::IUnknown);
@@ -479,6 +703,26 @@ impl IMLOperatorKernelFactory_Vtbl {
         iid == &<IMLOperatorKernelFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMLOperatorKernelFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IMLOperatorKernelFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateKernel<Identity: windows_core::IUnknownImpl + IMLOperatorKernelFactory_Impl>(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void, kernel: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMLOperatorKernelFactory_Impl::CreateKernel(this, core::mem::transmute_copy(&context)) {
+                    Ok(ok__) => {
+                        kernel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateKernel: CreateKernel::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IMLOperatorKernelFactory {}
 windows_core::imp::define_interface!(IMLOperatorRegistry, IMLOperatorRegistry_Vtbl, 0x2af9dd2d_b516_4672_9ab5_530c208493ad);
 windows_core::imp::interface_hierarchy!(IMLOperatorRegistry, windows_core::IUnknown);
@@ -533,6 +777,30 @@ impl IMLOperatorRegistry_Vtbl {
         iid == &<IMLOperatorRegistry as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMLOperatorRegistry_Impl> windows_core::imp::TearOffVtable<Identity> for IMLOperatorRegistry_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterOperatorSetSchema<Identity: windows_core::IUnknownImpl + IMLOperatorRegistry_Impl>(this: *mut core::ffi::c_void, operatorsetid: *const MLOperatorSetId, baselineversion: i32, schema: *const *const MLOperatorSchemaDescription, schemacount: u32, typeinferrer: *mut core::ffi::c_void, shapeinferrer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorRegistry_Impl::RegisterOperatorSetSchema(this, core::mem::transmute_copy(&operatorsetid), core::mem::transmute_copy(&baselineversion), core::mem::transmute_copy(&schema), core::mem::transmute_copy(&schemacount), core::mem::transmute_copy(&typeinferrer), core::mem::transmute_copy(&shapeinferrer)).into()
+            }
+        }
+        unsafe extern "system" fn RegisterOperatorKernel<Identity: windows_core::IUnknownImpl + IMLOperatorRegistry_Impl>(this: *mut core::ffi::c_void, operatorkernel: *const MLOperatorKernelDescription, operatorkernelfactory: *mut core::ffi::c_void, shapeinferrer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorRegistry_Impl::RegisterOperatorKernel(this, core::mem::transmute_copy(&operatorkernel), core::mem::transmute_copy(&operatorkernelfactory), core::mem::transmute_copy(&shapeinferrer)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterOperatorSetSchema: RegisterOperatorSetSchema::<Identity>,
+            RegisterOperatorKernel: RegisterOperatorKernel::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMLOperatorRegistry {}
 windows_core::imp::define_interface!(IMLOperatorShapeInferenceContext, IMLOperatorShapeInferenceContext_Vtbl, 0x105b6b29_5408_4a68_9959_09b5955a3492);
 impl core::ops::Deref for IMLOperatorShapeInferenceContext {
@@ -675,6 +943,84 @@ impl IMLOperatorShapeInferenceContext_Vtbl {
         iid == &<IMLOperatorShapeInferenceContext as windows_core::Interface>::IID || iid == &<IMLOperatorAttributes as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMLOperatorShapeInferenceContext_Impl> windows_core::imp::TearOffVtable<Identity> for IMLOperatorShapeInferenceContext_Vtbl
+where
+    IMLOperatorAttributes_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetInputCount<Identity: windows_core::IUnknownImpl + IMLOperatorShapeInferenceContext_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorShapeInferenceContext_Impl::GetInputCount(this)
+            }
+        }
+        unsafe extern "system" fn GetOutputCount<Identity: windows_core::IUnknownImpl + IMLOperatorShapeInferenceContext_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorShapeInferenceContext_Impl::GetOutputCount(this)
+            }
+        }
+        unsafe extern "system" fn IsInputValid<Identity: windows_core::IUnknownImpl + IMLOperatorShapeInferenceContext_Impl>(this: *mut core::ffi::c_void, inputindex: u32) -> bool {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorShapeInferenceContext_Impl::IsInputValid(this, core::mem::transmute_copy(&inputindex))
+            }
+        }
+        unsafe extern "system" fn IsOutputValid<Identity: windows_core::IUnknownImpl + IMLOperatorShapeInferenceContext_Impl>(this: *mut core::ffi::c_void, outputindex: u32) -> bool {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorShapeInferenceContext_Impl::IsOutputValid(this, core::mem::transmute_copy(&outputindex))
+            }
+        }
+        unsafe extern "system" fn GetInputEdgeDescription<Identity: windows_core::IUnknownImpl + IMLOperatorShapeInferenceContext_Impl>(this: *mut core::ffi::c_void, inputindex: u32, edgedescription: *mut MLOperatorEdgeDescription) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMLOperatorShapeInferenceContext_Impl::GetInputEdgeDescription(this, core::mem::transmute_copy(&inputindex)) {
+                    Ok(ok__) => {
+                        edgedescription.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetInputTensorDimensionCount<Identity: windows_core::IUnknownImpl + IMLOperatorShapeInferenceContext_Impl>(this: *mut core::ffi::c_void, inputindex: u32, dimensioncount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMLOperatorShapeInferenceContext_Impl::GetInputTensorDimensionCount(this, core::mem::transmute_copy(&inputindex)) {
+                    Ok(ok__) => {
+                        dimensioncount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetInputTensorShape<Identity: windows_core::IUnknownImpl + IMLOperatorShapeInferenceContext_Impl>(this: *mut core::ffi::c_void, inputindex: u32, dimensioncount: u32, dimensions: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorShapeInferenceContext_Impl::GetInputTensorShape(this, core::mem::transmute_copy(&inputindex), core::mem::transmute_copy(&dimensioncount), core::mem::transmute_copy(&dimensions)).into()
+            }
+        }
+        unsafe extern "system" fn SetOutputTensorShape<Identity: windows_core::IUnknownImpl + IMLOperatorShapeInferenceContext_Impl>(this: *mut core::ffi::c_void, outputindex: u32, dimensioncount: u32, dimensions: *const u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorShapeInferenceContext_Impl::SetOutputTensorShape(this, core::mem::transmute_copy(&outputindex), core::mem::transmute_copy(&dimensioncount), core::mem::transmute_copy(&dimensions)).into()
+            }
+        }
+        Self {
+            base__: <IMLOperatorAttributes_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetInputCount: GetInputCount::<Identity>,
+            GetOutputCount: GetOutputCount::<Identity>,
+            IsInputValid: IsInputValid::<Identity>,
+            IsOutputValid: IsOutputValid::<Identity>,
+            GetInputEdgeDescription: GetInputEdgeDescription::<Identity>,
+            GetInputTensorDimensionCount: GetInputTensorDimensionCount::<Identity>,
+            GetInputTensorShape: GetInputTensorShape::<Identity>,
+            SetOutputTensorShape: SetOutputTensorShape::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMLOperatorShapeInferenceContext {}
 windows_core::imp::define_interface!(IMLOperatorShapeInferrer, IMLOperatorShapeInferrer_Vtbl, 0x540be5be_a6c9_40ee_83f6_d2b8b40a7798);
 windows_core::imp::interface_hierarchy!(IMLOperatorShapeInferrer, windows_core::IUnknown);
@@ -709,6 +1055,20 @@ impl IMLOperatorShapeInferrer_Vtbl {
         iid == &<IMLOperatorShapeInferrer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMLOperatorShapeInferrer_Impl> windows_core::imp::TearOffVtable<Identity> for IMLOperatorShapeInferrer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InferOutputShapes<Identity: windows_core::IUnknownImpl + IMLOperatorShapeInferrer_Impl>(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorShapeInferrer_Impl::InferOutputShapes(this, core::mem::transmute_copy(&context)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, InferOutputShapes: InferOutputShapes::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IMLOperatorShapeInferrer {}
 windows_core::imp::define_interface!(IMLOperatorTensor, IMLOperatorTensor_Vtbl, 0x7fe41f41_f430_440e_aece_54416dc8b9db);
 windows_core::imp::interface_hierarchy!(IMLOperatorTensor, windows_core::IUnknown);
@@ -819,6 +1179,65 @@ impl IMLOperatorTensor_Vtbl {
         iid == &<IMLOperatorTensor as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMLOperatorTensor_Impl> windows_core::imp::TearOffVtable<Identity> for IMLOperatorTensor_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDimensionCount<Identity: windows_core::IUnknownImpl + IMLOperatorTensor_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorTensor_Impl::GetDimensionCount(this)
+            }
+        }
+        unsafe extern "system" fn GetShape<Identity: windows_core::IUnknownImpl + IMLOperatorTensor_Impl>(this: *mut core::ffi::c_void, dimensioncount: u32, dimensions: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorTensor_Impl::GetShape(this, core::mem::transmute_copy(&dimensioncount), core::mem::transmute_copy(&dimensions)).into()
+            }
+        }
+        unsafe extern "system" fn GetTensorDataType<Identity: windows_core::IUnknownImpl + IMLOperatorTensor_Impl>(this: *mut core::ffi::c_void) -> MLOperatorTensorDataType {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorTensor_Impl::GetTensorDataType(this)
+            }
+        }
+        unsafe extern "system" fn IsCpuData<Identity: windows_core::IUnknownImpl + IMLOperatorTensor_Impl>(this: *mut core::ffi::c_void) -> bool {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorTensor_Impl::IsCpuData(this)
+            }
+        }
+        unsafe extern "system" fn IsDataInterface<Identity: windows_core::IUnknownImpl + IMLOperatorTensor_Impl>(this: *mut core::ffi::c_void) -> bool {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorTensor_Impl::IsDataInterface(this)
+            }
+        }
+        unsafe extern "system" fn GetData<Identity: windows_core::IUnknownImpl + IMLOperatorTensor_Impl>(this: *mut core::ffi::c_void) -> *mut core::ffi::c_void {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorTensor_Impl::GetData(this)
+            }
+        }
+        unsafe extern "system" fn GetDataInterface<Identity: windows_core::IUnknownImpl + IMLOperatorTensor_Impl>(this: *mut core::ffi::c_void, datainterface: *mut *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorTensor_Impl::GetDataInterface(this, core::mem::transmute_copy(&datainterface))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDimensionCount: GetDimensionCount::<Identity>,
+            GetShape: GetShape::<Identity>,
+            GetTensorDataType: GetTensorDataType::<Identity>,
+            IsCpuData: IsCpuData::<Identity>,
+            IsDataInterface: IsDataInterface::<Identity>,
+            GetData: GetData::<Identity>,
+            GetDataInterface: GetDataInterface::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMLOperatorTensor {}
 windows_core::imp::define_interface!(IMLOperatorTensorShapeDescription, IMLOperatorTensorShapeDescription_Vtbl, 0xf20e8cbe_3b28_4248_be95_f96fbc6e4643);
 windows_core::imp::interface_hierarchy!(IMLOperatorTensorShapeDescription, windows_core::IUnknown);
@@ -919,6 +1338,63 @@ impl IMLOperatorTensorShapeDescription_Vtbl {
         iid == &<IMLOperatorTensorShapeDescription as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMLOperatorTensorShapeDescription_Impl> windows_core::imp::TearOffVtable<Identity> for IMLOperatorTensorShapeDescription_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetInputTensorDimensionCount<Identity: windows_core::IUnknownImpl + IMLOperatorTensorShapeDescription_Impl>(this: *mut core::ffi::c_void, inputindex: u32, dimensioncount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMLOperatorTensorShapeDescription_Impl::GetInputTensorDimensionCount(this, core::mem::transmute_copy(&inputindex)) {
+                    Ok(ok__) => {
+                        dimensioncount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetInputTensorShape<Identity: windows_core::IUnknownImpl + IMLOperatorTensorShapeDescription_Impl>(this: *mut core::ffi::c_void, inputindex: u32, dimensioncount: u32, dimensions: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorTensorShapeDescription_Impl::GetInputTensorShape(this, core::mem::transmute_copy(&inputindex), core::mem::transmute_copy(&dimensioncount), core::mem::transmute_copy(&dimensions)).into()
+            }
+        }
+        unsafe extern "system" fn HasOutputShapeDescription<Identity: windows_core::IUnknownImpl + IMLOperatorTensorShapeDescription_Impl>(this: *mut core::ffi::c_void) -> bool {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorTensorShapeDescription_Impl::HasOutputShapeDescription(this)
+            }
+        }
+        unsafe extern "system" fn GetOutputTensorDimensionCount<Identity: windows_core::IUnknownImpl + IMLOperatorTensorShapeDescription_Impl>(this: *mut core::ffi::c_void, outputindex: u32, dimensioncount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMLOperatorTensorShapeDescription_Impl::GetOutputTensorDimensionCount(this, core::mem::transmute_copy(&outputindex)) {
+                    Ok(ok__) => {
+                        dimensioncount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOutputTensorShape<Identity: windows_core::IUnknownImpl + IMLOperatorTensorShapeDescription_Impl>(this: *mut core::ffi::c_void, outputindex: u32, dimensioncount: u32, dimensions: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorTensorShapeDescription_Impl::GetOutputTensorShape(this, core::mem::transmute_copy(&outputindex), core::mem::transmute_copy(&dimensioncount), core::mem::transmute_copy(&dimensions)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetInputTensorDimensionCount: GetInputTensorDimensionCount::<Identity>,
+            GetInputTensorShape: GetInputTensorShape::<Identity>,
+            HasOutputShapeDescription: HasOutputShapeDescription::<Identity>,
+            GetOutputTensorDimensionCount: GetOutputTensorDimensionCount::<Identity>,
+            GetOutputTensorShape: GetOutputTensorShape::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMLOperatorTensorShapeDescription {}
 windows_core::imp::define_interface!(IMLOperatorTypeInferenceContext, IMLOperatorTypeInferenceContext_Vtbl, 0xec893bb1_f938_427b_8488_c8dcf775f138);
 impl core::ops::Deref for IMLOperatorTypeInferenceContext {
@@ -1028,6 +1504,64 @@ impl IMLOperatorTypeInferenceContext_Vtbl {
         iid == &<IMLOperatorTypeInferenceContext as windows_core::Interface>::IID || iid == &<IMLOperatorAttributes as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMLOperatorTypeInferenceContext_Impl> windows_core::imp::TearOffVtable<Identity> for IMLOperatorTypeInferenceContext_Vtbl
+where
+    IMLOperatorAttributes_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetInputCount<Identity: windows_core::IUnknownImpl + IMLOperatorTypeInferenceContext_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorTypeInferenceContext_Impl::GetInputCount(this)
+            }
+        }
+        unsafe extern "system" fn GetOutputCount<Identity: windows_core::IUnknownImpl + IMLOperatorTypeInferenceContext_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorTypeInferenceContext_Impl::GetOutputCount(this)
+            }
+        }
+        unsafe extern "system" fn IsInputValid<Identity: windows_core::IUnknownImpl + IMLOperatorTypeInferenceContext_Impl>(this: *mut core::ffi::c_void, inputindex: u32) -> bool {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorTypeInferenceContext_Impl::IsInputValid(this, core::mem::transmute_copy(&inputindex))
+            }
+        }
+        unsafe extern "system" fn IsOutputValid<Identity: windows_core::IUnknownImpl + IMLOperatorTypeInferenceContext_Impl>(this: *mut core::ffi::c_void, outputindex: u32) -> bool {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorTypeInferenceContext_Impl::IsOutputValid(this, core::mem::transmute_copy(&outputindex))
+            }
+        }
+        unsafe extern "system" fn GetInputEdgeDescription<Identity: windows_core::IUnknownImpl + IMLOperatorTypeInferenceContext_Impl>(this: *mut core::ffi::c_void, inputindex: u32, edgedescription: *mut MLOperatorEdgeDescription) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMLOperatorTypeInferenceContext_Impl::GetInputEdgeDescription(this, core::mem::transmute_copy(&inputindex)) {
+                    Ok(ok__) => {
+                        edgedescription.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetOutputEdgeDescription<Identity: windows_core::IUnknownImpl + IMLOperatorTypeInferenceContext_Impl>(this: *mut core::ffi::c_void, outputindex: u32, edgedescription: *const MLOperatorEdgeDescription) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorTypeInferenceContext_Impl::SetOutputEdgeDescription(this, core::mem::transmute_copy(&outputindex), core::mem::transmute_copy(&edgedescription)).into()
+            }
+        }
+        Self {
+            base__: <IMLOperatorAttributes_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetInputCount: GetInputCount::<Identity>,
+            GetOutputCount: GetOutputCount::<Identity>,
+            IsInputValid: IsInputValid::<Identity>,
+            IsOutputValid: IsOutputValid::<Identity>,
+            GetInputEdgeDescription: GetInputEdgeDescription::<Identity>,
+            SetOutputEdgeDescription: SetOutputEdgeDescription::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMLOperatorTypeInferenceContext {}
 windows_core::imp::define_interface!(IMLOperatorTypeInferrer, IMLOperatorTypeInferrer_Vtbl, 0x781aeb48_9bcb_4797_bf77_8bf455217beb);
 windows_core::imp::interface_hierarchy!(IMLOperatorTypeInferrer, windows_core::IUnknown);
@@ -1062,6 +1596,20 @@ impl IMLOperatorTypeInferrer_Vtbl {
         iid == &<IMLOperatorTypeInferrer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMLOperatorTypeInferrer_Impl> windows_core::imp::TearOffVtable<Identity> for IMLOperatorTypeInferrer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InferOutputTypes<Identity: windows_core::IUnknownImpl + IMLOperatorTypeInferrer_Impl>(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLOperatorTypeInferrer_Impl::InferOutputTypes(this, core::mem::transmute_copy(&context)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, InferOutputTypes: InferOutputTypes::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IMLOperatorTypeInferrer {}
 windows_core::imp::define_interface!(IWinMLEvaluationContext, IWinMLEvaluationContext_Vtbl, 0x95848f9e_583d_4054_af12_916387cd8426);
 windows_core::imp::interface_hierarchy!(IWinMLEvaluationContext, windows_core::IUnknown);
@@ -1143,6 +1691,44 @@ impl IWinMLEvaluationContext_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl<Identity: windows_core::IUnknownImpl + IWinMLEvaluationContext_Impl> windows_core::imp::TearOffVtable<Identity> for IWinMLEvaluationContext_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BindValue<Identity: windows_core::IUnknownImpl + IWinMLEvaluationContext_Impl>(this: *mut core::ffi::c_void, pdescriptor: *const WINML_BINDING_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinMLEvaluationContext_Impl::BindValue(this, core::mem::transmute_copy(&pdescriptor)).into()
+            }
+        }
+        unsafe extern "system" fn GetValueByName<Identity: windows_core::IUnknownImpl + IWinMLEvaluationContext_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, pdescriptor: *mut *mut WINML_BINDING_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWinMLEvaluationContext_Impl::GetValueByName(this, core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        pdescriptor.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Clear<Identity: windows_core::IUnknownImpl + IWinMLEvaluationContext_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinMLEvaluationContext_Impl::Clear(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            BindValue: BindValue::<Identity>,
+            GetValueByName: GetValueByName::<Identity>,
+            Clear: Clear::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
 impl windows_core::RuntimeName for IWinMLEvaluationContext {}
 windows_core::imp::define_interface!(IWinMLModel, IWinMLModel_Vtbl, 0xe2eeb6a9_f31f_4055_a521_e30b5b33664a);
 windows_core::imp::interface_hierarchy!(IWinMLModel, windows_core::IUnknown);
@@ -1240,6 +1826,62 @@ impl IWinMLModel_Vtbl {
         iid == &<IWinMLModel as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWinMLModel_Impl> windows_core::imp::TearOffVtable<Identity> for IWinMLModel_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDescription<Identity: windows_core::IUnknownImpl + IWinMLModel_Impl>(this: *mut core::ffi::c_void, ppdescription: *mut *mut WINML_MODEL_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWinMLModel_Impl::GetDescription(this) {
+                    Ok(ok__) => {
+                        ppdescription.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumerateMetadata<Identity: windows_core::IUnknownImpl + IWinMLModel_Impl>(this: *mut core::ffi::c_void, index: u32, pkey: *mut windows_core::PCWSTR, pvalue: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinMLModel_Impl::EnumerateMetadata(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&pkey), core::mem::transmute_copy(&pvalue)).into()
+            }
+        }
+        unsafe extern "system" fn EnumerateModelInputs<Identity: windows_core::IUnknownImpl + IWinMLModel_Impl>(this: *mut core::ffi::c_void, index: u32, ppinputdescriptor: *mut *mut WINML_VARIABLE_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWinMLModel_Impl::EnumerateModelInputs(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        ppinputdescriptor.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumerateModelOutputs<Identity: windows_core::IUnknownImpl + IWinMLModel_Impl>(this: *mut core::ffi::c_void, index: u32, ppoutputdescriptor: *mut *mut WINML_VARIABLE_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWinMLModel_Impl::EnumerateModelOutputs(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        ppoutputdescriptor.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDescription: GetDescription::<Identity>,
+            EnumerateMetadata: EnumerateMetadata::<Identity>,
+            EnumerateModelInputs: EnumerateModelInputs::<Identity>,
+            EnumerateModelOutputs: EnumerateModelOutputs::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWinMLModel {}
 windows_core::imp::define_interface!(IWinMLRuntime, IWinMLRuntime_Vtbl, 0xa0425329_40ae_48d9_bce3_829ef7b8a41a);
 windows_core::imp::interface_hierarchy!(IWinMLRuntime, windows_core::IUnknown);
@@ -1332,6 +1974,50 @@ impl IWinMLRuntime_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl<Identity: windows_core::IUnknownImpl + IWinMLRuntime_Impl> windows_core::imp::TearOffVtable<Identity> for IWinMLRuntime_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn LoadModel<Identity: windows_core::IUnknownImpl + IWinMLRuntime_Impl>(this: *mut core::ffi::c_void, path: windows_core::PCWSTR, ppmodel: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWinMLRuntime_Impl::LoadModel(this, core::mem::transmute(&path)) {
+                    Ok(ok__) => {
+                        ppmodel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateEvaluationContext<Identity: windows_core::IUnknownImpl + IWinMLRuntime_Impl>(this: *mut core::ffi::c_void, device: *mut core::ffi::c_void, ppcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWinMLRuntime_Impl::CreateEvaluationContext(this, core::mem::transmute_copy(&device)) {
+                    Ok(ok__) => {
+                        ppcontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EvaluateModel<Identity: windows_core::IUnknownImpl + IWinMLRuntime_Impl>(this: *mut core::ffi::c_void, pcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinMLRuntime_Impl::EvaluateModel(this, core::mem::transmute_copy(&pcontext)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            LoadModel: LoadModel::<Identity>,
+            CreateEvaluationContext: CreateEvaluationContext::<Identity>,
+            EvaluateModel: EvaluateModel::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
 impl windows_core::RuntimeName for IWinMLRuntime {}
 windows_core::imp::define_interface!(IWinMLRuntimeFactory, IWinMLRuntimeFactory_Vtbl, 0xa807b84d_4ae5_4bc0_a76a_941aa246bd41);
 windows_core::imp::interface_hierarchy!(IWinMLRuntimeFactory, windows_core::IUnknown);
@@ -1372,6 +2058,26 @@ impl IWinMLRuntimeFactory_Vtbl {
         iid == &<IWinMLRuntimeFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWinMLRuntimeFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IWinMLRuntimeFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateRuntime<Identity: windows_core::IUnknownImpl + IWinMLRuntimeFactory_Impl>(this: *mut core::ffi::c_void, runtimetype: WINML_RUNTIME_TYPE, ppruntime: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWinMLRuntimeFactory_Impl::CreateRuntime(this, core::mem::transmute_copy(&runtimetype)) {
+                    Ok(ok__) => {
+                        ppruntime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateRuntime: CreateRuntime::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWinMLRuntimeFactory {}
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
@@ -552,6 +552,51 @@ impl IITDatabase_Vtbl {
         iid == &<IITDatabase as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IITDatabase_Impl> windows_core::imp::TearOffVtable<Identity> for IITDatabase_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Open<Identity: windows_core::IUnknownImpl + IITDatabase_Impl>(this: *mut core::ffi::c_void, lpszhost: windows_core::PCWSTR, lpszmoniker: windows_core::PCWSTR, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITDatabase_Impl::Open(this, core::mem::transmute(&lpszhost), core::mem::transmute(&lpszmoniker), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IITDatabase_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITDatabase_Impl::Close(this).into()
+            }
+        }
+        unsafe extern "system" fn CreateObject<Identity: windows_core::IUnknownImpl + IITDatabase_Impl>(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, pdwobjinstance: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITDatabase_Impl::CreateObject(this, core::mem::transmute_copy(&rclsid), core::mem::transmute_copy(&pdwobjinstance)).into()
+            }
+        }
+        unsafe extern "system" fn GetObject<Identity: windows_core::IUnknownImpl + IITDatabase_Impl>(this: *mut core::ffi::c_void, dwobjinstance: u32, riid: *const windows_core::GUID, ppvobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITDatabase_Impl::GetObject(this, core::mem::transmute_copy(&dwobjinstance), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvobj)).into()
+            }
+        }
+        unsafe extern "system" fn GetObjectPersistence<Identity: windows_core::IUnknownImpl + IITDatabase_Impl>(this: *mut core::ffi::c_void, lpwszobject: windows_core::PCWSTR, dwobjinstance: u32, ppvpersistence: *mut *mut core::ffi::c_void, fstream: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITDatabase_Impl::GetObjectPersistence(this, core::mem::transmute(&lpwszobject), core::mem::transmute_copy(&dwobjinstance), core::mem::transmute_copy(&ppvpersistence), core::mem::transmute_copy(&fstream)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Open: Open::<Identity>,
+            Close: Close::<Identity>,
+            CreateObject: CreateObject::<Identity>,
+            GetObject: GetObject::<Identity>,
+            GetObjectPersistence: GetObjectPersistence::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IITDatabase {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IITPropList, IITPropList_Vtbl, 0x1f403bb1_9997_11d0_a850_00aa006c7d01);
@@ -810,6 +855,143 @@ impl IITPropList_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IITPropList_Impl> windows_core::imp::TearOffVtable<Identity> for IITPropList_Vtbl
+where
+    super::super::System::Com::IPersistStreamInit_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Set<Identity: windows_core::IUnknownImpl + IITPropList_Impl>(this: *mut core::ffi::c_void, propid: u32, lpszwstring: windows_core::PCWSTR, dwoperation: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITPropList_Impl::Set(this, core::mem::transmute_copy(&propid), core::mem::transmute(&lpszwstring), core::mem::transmute_copy(&dwoperation)).into()
+            }
+        }
+        unsafe extern "system" fn Set2<Identity: windows_core::IUnknownImpl + IITPropList_Impl>(this: *mut core::ffi::c_void, propid: u32, lpvdata: *mut core::ffi::c_void, cbdata: u32, dwoperation: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITPropList_Impl::Set2(this, core::mem::transmute_copy(&propid), core::mem::transmute_copy(&lpvdata), core::mem::transmute_copy(&cbdata), core::mem::transmute_copy(&dwoperation)).into()
+            }
+        }
+        unsafe extern "system" fn Set3<Identity: windows_core::IUnknownImpl + IITPropList_Impl>(this: *mut core::ffi::c_void, propid: u32, dwdata: u32, dwoperation: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITPropList_Impl::Set3(this, core::mem::transmute_copy(&propid), core::mem::transmute_copy(&dwdata), core::mem::transmute_copy(&dwoperation)).into()
+            }
+        }
+        unsafe extern "system" fn Add<Identity: windows_core::IUnknownImpl + IITPropList_Impl>(this: *mut core::ffi::c_void, prop: *mut CProperty) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITPropList_Impl::Add(this, core::mem::transmute_copy(&prop)).into()
+            }
+        }
+        unsafe extern "system" fn Get<Identity: windows_core::IUnknownImpl + IITPropList_Impl>(this: *mut core::ffi::c_void, propid: u32, property: *mut CProperty) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITPropList_Impl::Get(this, core::mem::transmute_copy(&propid), core::mem::transmute_copy(&property)).into()
+            }
+        }
+        unsafe extern "system" fn Clear<Identity: windows_core::IUnknownImpl + IITPropList_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITPropList_Impl::Clear(this).into()
+            }
+        }
+        unsafe extern "system" fn SetPersist<Identity: windows_core::IUnknownImpl + IITPropList_Impl>(this: *mut core::ffi::c_void, fpersist: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITPropList_Impl::SetPersist(this, core::mem::transmute_copy(&fpersist)).into()
+            }
+        }
+        unsafe extern "system" fn SetPersist2<Identity: windows_core::IUnknownImpl + IITPropList_Impl>(this: *mut core::ffi::c_void, propid: u32, fpersist: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITPropList_Impl::SetPersist2(this, core::mem::transmute_copy(&propid), core::mem::transmute_copy(&fpersist)).into()
+            }
+        }
+        unsafe extern "system" fn GetFirst<Identity: windows_core::IUnknownImpl + IITPropList_Impl>(this: *mut core::ffi::c_void, property: *mut CProperty) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITPropList_Impl::GetFirst(this, core::mem::transmute_copy(&property)).into()
+            }
+        }
+        unsafe extern "system" fn GetNext<Identity: windows_core::IUnknownImpl + IITPropList_Impl>(this: *mut core::ffi::c_void, property: *mut CProperty) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITPropList_Impl::GetNext(this, core::mem::transmute_copy(&property)).into()
+            }
+        }
+        unsafe extern "system" fn GetPropCount<Identity: windows_core::IUnknownImpl + IITPropList_Impl>(this: *mut core::ffi::c_void, cprop: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITPropList_Impl::GetPropCount(this, core::mem::transmute_copy(&cprop)).into()
+            }
+        }
+        unsafe extern "system" fn SaveHeader<Identity: windows_core::IUnknownImpl + IITPropList_Impl>(this: *mut core::ffi::c_void, lpvdata: *mut core::ffi::c_void, dwhdrsize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITPropList_Impl::SaveHeader(this, core::mem::transmute_copy(&lpvdata), core::mem::transmute_copy(&dwhdrsize)).into()
+            }
+        }
+        unsafe extern "system" fn SaveData<Identity: windows_core::IUnknownImpl + IITPropList_Impl>(this: *mut core::ffi::c_void, lpvheader: *mut core::ffi::c_void, dwhdrsize: u32, lpvdata: *mut core::ffi::c_void, dwbufsize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITPropList_Impl::SaveData(this, core::mem::transmute_copy(&lpvheader), core::mem::transmute_copy(&dwhdrsize), core::mem::transmute_copy(&lpvdata), core::mem::transmute_copy(&dwbufsize)).into()
+            }
+        }
+        unsafe extern "system" fn GetHeaderSize<Identity: windows_core::IUnknownImpl + IITPropList_Impl>(this: *mut core::ffi::c_void, dwhdrsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITPropList_Impl::GetHeaderSize(this, core::mem::transmute_copy(&dwhdrsize)).into()
+            }
+        }
+        unsafe extern "system" fn GetDataSize<Identity: windows_core::IUnknownImpl + IITPropList_Impl>(this: *mut core::ffi::c_void, lpvheader: *mut core::ffi::c_void, dwhdrsize: u32, dwdatasize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITPropList_Impl::GetDataSize(this, core::mem::transmute_copy(&lpvheader), core::mem::transmute_copy(&dwhdrsize), core::mem::transmute_copy(&dwdatasize)).into()
+            }
+        }
+        unsafe extern "system" fn SaveDataToStream<Identity: windows_core::IUnknownImpl + IITPropList_Impl>(this: *mut core::ffi::c_void, lpvheader: *mut core::ffi::c_void, dwhdrsize: u32, pstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITPropList_Impl::SaveDataToStream(this, core::mem::transmute_copy(&lpvheader), core::mem::transmute_copy(&dwhdrsize), core::mem::transmute_copy(&pstream)).into()
+            }
+        }
+        unsafe extern "system" fn LoadFromMem<Identity: windows_core::IUnknownImpl + IITPropList_Impl>(this: *mut core::ffi::c_void, lpvdata: *mut core::ffi::c_void, dwbufsize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITPropList_Impl::LoadFromMem(this, core::mem::transmute_copy(&lpvdata), core::mem::transmute_copy(&dwbufsize)).into()
+            }
+        }
+        unsafe extern "system" fn SaveToMem<Identity: windows_core::IUnknownImpl + IITPropList_Impl>(this: *mut core::ffi::c_void, lpvdata: *mut core::ffi::c_void, dwbufsize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITPropList_Impl::SaveToMem(this, core::mem::transmute_copy(&lpvdata), core::mem::transmute_copy(&dwbufsize)).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IPersistStreamInit_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Set: Set::<Identity>,
+            Set2: Set2::<Identity>,
+            Set3: Set3::<Identity>,
+            Add: Add::<Identity>,
+            Get: Get::<Identity>,
+            Clear: Clear::<Identity>,
+            SetPersist: SetPersist::<Identity>,
+            SetPersist2: SetPersist2::<Identity>,
+            GetFirst: GetFirst::<Identity>,
+            GetNext: GetNext::<Identity>,
+            GetPropCount: GetPropCount::<Identity>,
+            SaveHeader: SaveHeader::<Identity>,
+            SaveData: SaveData::<Identity>,
+            GetHeaderSize: GetHeaderSize::<Identity>,
+            GetDataSize: GetDataSize::<Identity>,
+            SaveDataToStream: SaveDataToStream::<Identity>,
+            LoadFromMem: LoadFromMem::<Identity>,
+            SaveToMem: SaveToMem::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IITPropList {}
 windows_core::imp::define_interface!(IITResultSet, IITResultSet_Vtbl, 0x3bb91d41_998b_11d0_a850_00aa006c7d01);
 windows_core::imp::interface_hierarchy!(IITResultSet, windows_core::IUnknown);
@@ -1204,6 +1386,226 @@ impl IITResultSet_Vtbl {
         iid == &<IITResultSet as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IITResultSet_Impl> windows_core::imp::TearOffVtable<Identity> for IITResultSet_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetColumnPriority<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, lcolumnindex: i32, columnpriority: PRIORITY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::SetColumnPriority(this, core::mem::transmute_copy(&lcolumnindex), core::mem::transmute_copy(&columnpriority)).into()
+            }
+        }
+        unsafe extern "system" fn SetColumnHeap<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, lcolumnindex: i32, lpvheap: *mut core::ffi::c_void, pfncolheapfree: PFNCOLHEAPFREE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::SetColumnHeap(this, core::mem::transmute_copy(&lcolumnindex), core::mem::transmute_copy(&lpvheap), core::mem::transmute_copy(&pfncolheapfree)).into()
+            }
+        }
+        unsafe extern "system" fn SetKeyProp<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, propid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::SetKeyProp(this, core::mem::transmute_copy(&propid)).into()
+            }
+        }
+        unsafe extern "system" fn Add<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, propid: u32, dwdefaultdata: u32, priority: PRIORITY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::Add(this, core::mem::transmute_copy(&propid), core::mem::transmute_copy(&dwdefaultdata), core::mem::transmute_copy(&priority)).into()
+            }
+        }
+        unsafe extern "system" fn Add2<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, propid: u32, lpszwdefault: windows_core::PCWSTR, priority: PRIORITY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::Add2(this, core::mem::transmute_copy(&propid), core::mem::transmute(&lpszwdefault), core::mem::transmute_copy(&priority)).into()
+            }
+        }
+        unsafe extern "system" fn Add3<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, propid: u32, lpvdefaultdata: *mut core::ffi::c_void, cbdata: u32, priority: PRIORITY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::Add3(this, core::mem::transmute_copy(&propid), core::mem::transmute_copy(&lpvdefaultdata), core::mem::transmute_copy(&cbdata), core::mem::transmute_copy(&priority)).into()
+            }
+        }
+        unsafe extern "system" fn Add4<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, lpvhdr: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::Add4(this, core::mem::transmute_copy(&lpvhdr)).into()
+            }
+        }
+        unsafe extern "system" fn Append<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, lpvhdr: *mut core::ffi::c_void, lpvdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::Append(this, core::mem::transmute_copy(&lpvhdr), core::mem::transmute_copy(&lpvdata)).into()
+            }
+        }
+        unsafe extern "system" fn Set<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, lrowindex: i32, lcolumnindex: i32, lpvdata: *mut core::ffi::c_void, cbdata: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::Set(this, core::mem::transmute_copy(&lrowindex), core::mem::transmute_copy(&lcolumnindex), core::mem::transmute_copy(&lpvdata), core::mem::transmute_copy(&cbdata)).into()
+            }
+        }
+        unsafe extern "system" fn Set2<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, lrowindex: i32, lcolumnindex: i32, lpwstr: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::Set2(this, core::mem::transmute_copy(&lrowindex), core::mem::transmute_copy(&lcolumnindex), core::mem::transmute(&lpwstr)).into()
+            }
+        }
+        unsafe extern "system" fn Set3<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, lrowindex: i32, lcolumnindex: i32, dwdata: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::Set3(this, core::mem::transmute_copy(&lrowindex), core::mem::transmute_copy(&lcolumnindex), core::mem::transmute_copy(&dwdata)).into()
+            }
+        }
+        unsafe extern "system" fn Set4<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, lrowindex: i32, lpvhdr: *mut core::ffi::c_void, lpvdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::Set4(this, core::mem::transmute_copy(&lrowindex), core::mem::transmute_copy(&lpvhdr), core::mem::transmute_copy(&lpvdata)).into()
+            }
+        }
+        unsafe extern "system" fn Copy<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, prscopy: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::Copy(this, core::mem::transmute_copy(&prscopy)).into()
+            }
+        }
+        unsafe extern "system" fn AppendRows<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, pressrc: *mut core::ffi::c_void, lrowsrcfirst: i32, csrcrows: i32, lrowfirstdest: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::AppendRows(this, core::mem::transmute_copy(&pressrc), core::mem::transmute_copy(&lrowsrcfirst), core::mem::transmute_copy(&csrcrows), core::mem::transmute_copy(&lrowfirstdest)).into()
+            }
+        }
+        unsafe extern "system" fn Get<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, lrowindex: i32, lcolumnindex: i32, prop: *mut CProperty) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::Get(this, core::mem::transmute_copy(&lrowindex), core::mem::transmute_copy(&lcolumnindex), core::mem::transmute_copy(&prop)).into()
+            }
+        }
+        unsafe extern "system" fn GetKeyProp<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, keypropid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::GetKeyProp(this, core::mem::transmute_copy(&keypropid)).into()
+            }
+        }
+        unsafe extern "system" fn GetColumnPriority<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, lcolumnindex: i32, columnpriority: *mut PRIORITY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::GetColumnPriority(this, core::mem::transmute_copy(&lcolumnindex), core::mem::transmute_copy(&columnpriority)).into()
+            }
+        }
+        unsafe extern "system" fn GetRowCount<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, lnumberofrows: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::GetRowCount(this, core::mem::transmute_copy(&lnumberofrows)).into()
+            }
+        }
+        unsafe extern "system" fn GetColumnCount<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, lnumberofcolumns: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::GetColumnCount(this, core::mem::transmute_copy(&lnumberofcolumns)).into()
+            }
+        }
+        unsafe extern "system" fn GetColumn<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, lcolumnindex: i32, propid: *mut u32, dwtype: *mut u32, lpvdefaultvalue: *mut *mut core::ffi::c_void, cbsize: *mut u32, columnpriority: *mut PRIORITY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::GetColumn(this, core::mem::transmute_copy(&lcolumnindex), core::mem::transmute_copy(&propid), core::mem::transmute_copy(&dwtype), core::mem::transmute_copy(&lpvdefaultvalue), core::mem::transmute_copy(&cbsize), core::mem::transmute_copy(&columnpriority)).into()
+            }
+        }
+        unsafe extern "system" fn GetColumn2<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, lcolumnindex: i32, propid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::GetColumn2(this, core::mem::transmute_copy(&lcolumnindex), core::mem::transmute_copy(&propid)).into()
+            }
+        }
+        unsafe extern "system" fn GetColumnFromPropID<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, propid: u32, lcolumnindex: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::GetColumnFromPropID(this, core::mem::transmute_copy(&propid), core::mem::transmute_copy(&lcolumnindex)).into()
+            }
+        }
+        unsafe extern "system" fn Clear<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::Clear(this).into()
+            }
+        }
+        unsafe extern "system" fn ClearRows<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::ClearRows(this).into()
+            }
+        }
+        unsafe extern "system" fn Free<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::Free(this).into()
+            }
+        }
+        unsafe extern "system" fn IsCompleted<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::IsCompleted(this).into()
+            }
+        }
+        unsafe extern "system" fn Cancel<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::Cancel(this).into()
+            }
+        }
+        unsafe extern "system" fn Pause<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, fpause: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::Pause(this, core::mem::transmute_copy(&fpause)).into()
+            }
+        }
+        unsafe extern "system" fn GetRowStatus<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, lrowfirst: i32, crows: i32, lprowstatus: *mut ROWSTATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::GetRowStatus(this, core::mem::transmute_copy(&lrowfirst), core::mem::transmute_copy(&crows), core::mem::transmute_copy(&lprowstatus)).into()
+            }
+        }
+        unsafe extern "system" fn GetColumnStatus<Identity: windows_core::IUnknownImpl + IITResultSet_Impl>(this: *mut core::ffi::c_void, lpcolstatus: *mut COLUMNSTATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IITResultSet_Impl::GetColumnStatus(this, core::mem::transmute_copy(&lpcolstatus)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetColumnPriority: SetColumnPriority::<Identity>,
+            SetColumnHeap: SetColumnHeap::<Identity>,
+            SetKeyProp: SetKeyProp::<Identity>,
+            Add: Add::<Identity>,
+            Add2: Add2::<Identity>,
+            Add3: Add3::<Identity>,
+            Add4: Add4::<Identity>,
+            Append: Append::<Identity>,
+            Set: Set::<Identity>,
+            Set2: Set2::<Identity>,
+            Set3: Set3::<Identity>,
+            Set4: Set4::<Identity>,
+            Copy: Copy::<Identity>,
+            AppendRows: AppendRows::<Identity>,
+            Get: Get::<Identity>,
+            GetKeyProp: GetKeyProp::<Identity>,
+            GetColumnPriority: GetColumnPriority::<Identity>,
+            GetRowCount: GetRowCount::<Identity>,
+            GetColumnCount: GetColumnCount::<Identity>,
+            GetColumn: GetColumn::<Identity>,
+            GetColumn2: GetColumn2::<Identity>,
+            GetColumnFromPropID: GetColumnFromPropID::<Identity>,
+            Clear: Clear::<Identity>,
+            ClearRows: ClearRows::<Identity>,
+            Free: Free::<Identity>,
+            IsCompleted: IsCompleted::<Identity>,
+            Cancel: Cancel::<Identity>,
+            Pause: Pause::<Identity>,
+            GetRowStatus: GetRowStatus::<Identity>,
+            GetColumnStatus: GetColumnStatus::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IITResultSet {}
 pub const IITWBC_BREAK_ACCEPT_WILDCARDS: u32 = 1u32;
 pub const IITWBC_BREAK_AND_STEM: u32 = 2u32;
@@ -1254,6 +1656,30 @@ impl IStemSink_Vtbl {
         iid == &<IStemSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IStemSink_Impl> windows_core::imp::TearOffVtable<Identity> for IStemSink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn PutAltWord<Identity: windows_core::IUnknownImpl + IStemSink_Impl>(this: *mut core::ffi::c_void, pwcinbuf: windows_core::PCWSTR, cwc: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IStemSink_Impl::PutAltWord(this, core::mem::transmute(&pwcinbuf), core::mem::transmute_copy(&cwc)).into()
+            }
+        }
+        unsafe extern "system" fn PutWord<Identity: windows_core::IUnknownImpl + IStemSink_Impl>(this: *mut core::ffi::c_void, pwcinbuf: windows_core::PCWSTR, cwc: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IStemSink_Impl::PutWord(this, core::mem::transmute(&pwcinbuf), core::mem::transmute_copy(&cwc)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PutAltWord: PutAltWord::<Identity>,
+            PutWord: PutWord::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IStemSink {}
 windows_core::imp::define_interface!(IStemmerConfig, IStemmerConfig_Vtbl, 0x8fa0d5a7_dedf_11d0_9a61_00c04fb68bf7);
 windows_core::imp::interface_hierarchy!(IStemmerConfig, windows_core::IUnknown);
@@ -1346,6 +1772,52 @@ impl IStemmerConfig_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IStemmerConfig_Impl> windows_core::imp::TearOffVtable<Identity> for IStemmerConfig_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetLocaleInfo<Identity: windows_core::IUnknownImpl + IStemmerConfig_Impl>(this: *mut core::ffi::c_void, dwcodepageid: u32, lcid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IStemmerConfig_Impl::SetLocaleInfo(this, core::mem::transmute_copy(&dwcodepageid), core::mem::transmute_copy(&lcid)).into()
+            }
+        }
+        unsafe extern "system" fn GetLocaleInfo<Identity: windows_core::IUnknownImpl + IStemmerConfig_Impl>(this: *mut core::ffi::c_void, pdwcodepageid: *mut u32, plcid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IStemmerConfig_Impl::GetLocaleInfo(this, core::mem::transmute_copy(&pdwcodepageid), core::mem::transmute_copy(&plcid)).into()
+            }
+        }
+        unsafe extern "system" fn SetControlInfo<Identity: windows_core::IUnknownImpl + IStemmerConfig_Impl>(this: *mut core::ffi::c_void, grfstemflags: u32, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IStemmerConfig_Impl::SetControlInfo(this, core::mem::transmute_copy(&grfstemflags), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn GetControlInfo<Identity: windows_core::IUnknownImpl + IStemmerConfig_Impl>(this: *mut core::ffi::c_void, pgrfstemflags: *mut u32, pdwreserved: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IStemmerConfig_Impl::GetControlInfo(this, core::mem::transmute_copy(&pgrfstemflags), core::mem::transmute_copy(&pdwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn LoadExternalStemmerData<Identity: windows_core::IUnknownImpl + IStemmerConfig_Impl>(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, dwextdatatype: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IStemmerConfig_Impl::LoadExternalStemmerData(this, core::mem::transmute_copy(&pstream), core::mem::transmute_copy(&dwextdatatype)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetLocaleInfo: SetLocaleInfo::<Identity>,
+            GetLocaleInfo: GetLocaleInfo::<Identity>,
+            SetControlInfo: SetControlInfo::<Identity>,
+            GetControlInfo: GetControlInfo::<Identity>,
+            LoadExternalStemmerData: LoadExternalStemmerData::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IStemmerConfig {}
 pub const ITWW_CBKEY_MAX: u32 = 1024u32;
 pub const ITWW_OPEN_NOCONNECT: u32 = 1u32;
@@ -1511,6 +1983,86 @@ impl IWordBreakerConfig_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Search"))]
+impl<Identity: windows_core::IUnknownImpl + IWordBreakerConfig_Impl> windows_core::imp::TearOffVtable<Identity> for IWordBreakerConfig_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetLocaleInfo<Identity: windows_core::IUnknownImpl + IWordBreakerConfig_Impl>(this: *mut core::ffi::c_void, dwcodepageid: u32, lcid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWordBreakerConfig_Impl::SetLocaleInfo(this, core::mem::transmute_copy(&dwcodepageid), core::mem::transmute_copy(&lcid)).into()
+            }
+        }
+        unsafe extern "system" fn GetLocaleInfo<Identity: windows_core::IUnknownImpl + IWordBreakerConfig_Impl>(this: *mut core::ffi::c_void, pdwcodepageid: *mut u32, plcid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWordBreakerConfig_Impl::GetLocaleInfo(this, core::mem::transmute_copy(&pdwcodepageid), core::mem::transmute_copy(&plcid)).into()
+            }
+        }
+        unsafe extern "system" fn SetBreakWordType<Identity: windows_core::IUnknownImpl + IWordBreakerConfig_Impl>(this: *mut core::ffi::c_void, dwbreakwordtype: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWordBreakerConfig_Impl::SetBreakWordType(this, core::mem::transmute_copy(&dwbreakwordtype)).into()
+            }
+        }
+        unsafe extern "system" fn GetBreakWordType<Identity: windows_core::IUnknownImpl + IWordBreakerConfig_Impl>(this: *mut core::ffi::c_void, pdwbreakwordtype: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWordBreakerConfig_Impl::GetBreakWordType(this, core::mem::transmute_copy(&pdwbreakwordtype)).into()
+            }
+        }
+        unsafe extern "system" fn SetControlInfo<Identity: windows_core::IUnknownImpl + IWordBreakerConfig_Impl>(this: *mut core::ffi::c_void, grfbreakflags: u32, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWordBreakerConfig_Impl::SetControlInfo(this, core::mem::transmute_copy(&grfbreakflags), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn GetControlInfo<Identity: windows_core::IUnknownImpl + IWordBreakerConfig_Impl>(this: *mut core::ffi::c_void, pgrfbreakflags: *mut u32, pdwreserved: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWordBreakerConfig_Impl::GetControlInfo(this, core::mem::transmute_copy(&pgrfbreakflags), core::mem::transmute_copy(&pdwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn LoadExternalBreakerData<Identity: windows_core::IUnknownImpl + IWordBreakerConfig_Impl>(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, dwextdatatype: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWordBreakerConfig_Impl::LoadExternalBreakerData(this, core::mem::transmute_copy(&pstream), core::mem::transmute_copy(&dwextdatatype)).into()
+            }
+        }
+        unsafe extern "system" fn SetWordStemmer<Identity: windows_core::IUnknownImpl + IWordBreakerConfig_Impl>(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, pstemmer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWordBreakerConfig_Impl::SetWordStemmer(this, core::mem::transmute_copy(&rclsid), core::mem::transmute_copy(&pstemmer)).into()
+            }
+        }
+        unsafe extern "system" fn GetWordStemmer<Identity: windows_core::IUnknownImpl + IWordBreakerConfig_Impl>(this: *mut core::ffi::c_void, ppstemmer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWordBreakerConfig_Impl::GetWordStemmer(this) {
+                    Ok(ok__) => {
+                        ppstemmer.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetLocaleInfo: SetLocaleInfo::<Identity>,
+            GetLocaleInfo: GetLocaleInfo::<Identity>,
+            SetBreakWordType: SetBreakWordType::<Identity>,
+            GetBreakWordType: GetBreakWordType::<Identity>,
+            SetControlInfo: SetControlInfo::<Identity>,
+            GetControlInfo: GetControlInfo::<Identity>,
+            LoadExternalBreakerData: LoadExternalBreakerData::<Identity>,
+            SetWordStemmer: SetWordStemmer::<Identity>,
+            GetWordStemmer: GetWordStemmer::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Search"))]
 impl windows_core::RuntimeName for IWordBreakerConfig {}
 pub const MAX_COLUMNS: u32 = 256u32;
 pub type PFNCOLHEAPFREE = Option<unsafe extern "system" fn(param0: *mut core::ffi::c_void) -> i32>;
@@ -731,6 +731,94 @@ impl IMXAttributes_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IMXAttributes_Impl> windows_core::imp::TearOffVtable<Identity> for IMXAttributes_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn addAttribute<Identity: windows_core::IUnknownImpl + IMXAttributes_Impl>(this: *mut core::ffi::c_void, struri: *mut core::ffi::c_void, strlocalname: *mut core::ffi::c_void, strqname: *mut core::ffi::c_void, strtype: *mut core::ffi::c_void, strvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXAttributes_Impl::addAttribute(this, core::mem::transmute(&struri), core::mem::transmute(&strlocalname), core::mem::transmute(&strqname), core::mem::transmute(&strtype), core::mem::transmute(&strvalue)).into()
+            }
+        }
+        unsafe extern "system" fn addAttributeFromIndex<Identity: windows_core::IUnknownImpl + IMXAttributes_Impl>(this: *mut core::ffi::c_void, varatts: super::super::super::System::Variant::VARIANT, nindex: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXAttributes_Impl::addAttributeFromIndex(this, core::mem::transmute(&varatts), core::mem::transmute_copy(&nindex)).into()
+            }
+        }
+        unsafe extern "system" fn clear<Identity: windows_core::IUnknownImpl + IMXAttributes_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXAttributes_Impl::clear(this).into()
+            }
+        }
+        unsafe extern "system" fn removeAttribute<Identity: windows_core::IUnknownImpl + IMXAttributes_Impl>(this: *mut core::ffi::c_void, nindex: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXAttributes_Impl::removeAttribute(this, core::mem::transmute_copy(&nindex)).into()
+            }
+        }
+        unsafe extern "system" fn setAttribute<Identity: windows_core::IUnknownImpl + IMXAttributes_Impl>(this: *mut core::ffi::c_void, nindex: i32, struri: *mut core::ffi::c_void, strlocalname: *mut core::ffi::c_void, strqname: *mut core::ffi::c_void, strtype: *mut core::ffi::c_void, strvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXAttributes_Impl::setAttribute(this, core::mem::transmute_copy(&nindex), core::mem::transmute(&struri), core::mem::transmute(&strlocalname), core::mem::transmute(&strqname), core::mem::transmute(&strtype), core::mem::transmute(&strvalue)).into()
+            }
+        }
+        unsafe extern "system" fn setAttributes<Identity: windows_core::IUnknownImpl + IMXAttributes_Impl>(this: *mut core::ffi::c_void, varatts: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXAttributes_Impl::setAttributes(this, core::mem::transmute(&varatts)).into()
+            }
+        }
+        unsafe extern "system" fn setLocalName<Identity: windows_core::IUnknownImpl + IMXAttributes_Impl>(this: *mut core::ffi::c_void, nindex: i32, strlocalname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXAttributes_Impl::setLocalName(this, core::mem::transmute_copy(&nindex), core::mem::transmute(&strlocalname)).into()
+            }
+        }
+        unsafe extern "system" fn setQName<Identity: windows_core::IUnknownImpl + IMXAttributes_Impl>(this: *mut core::ffi::c_void, nindex: i32, strqname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXAttributes_Impl::setQName(this, core::mem::transmute_copy(&nindex), core::mem::transmute(&strqname)).into()
+            }
+        }
+        unsafe extern "system" fn setType<Identity: windows_core::IUnknownImpl + IMXAttributes_Impl>(this: *mut core::ffi::c_void, nindex: i32, strtype: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXAttributes_Impl::setType(this, core::mem::transmute_copy(&nindex), core::mem::transmute(&strtype)).into()
+            }
+        }
+        unsafe extern "system" fn setURI<Identity: windows_core::IUnknownImpl + IMXAttributes_Impl>(this: *mut core::ffi::c_void, nindex: i32, struri: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXAttributes_Impl::setURI(this, core::mem::transmute_copy(&nindex), core::mem::transmute(&struri)).into()
+            }
+        }
+        unsafe extern "system" fn setValue<Identity: windows_core::IUnknownImpl + IMXAttributes_Impl>(this: *mut core::ffi::c_void, nindex: i32, strvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXAttributes_Impl::setValue(this, core::mem::transmute_copy(&nindex), core::mem::transmute(&strvalue)).into()
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            addAttribute: addAttribute::<Identity>,
+            addAttributeFromIndex: addAttributeFromIndex::<Identity>,
+            clear: clear::<Identity>,
+            removeAttribute: removeAttribute::<Identity>,
+            setAttribute: setAttribute::<Identity>,
+            setAttributes: setAttributes::<Identity>,
+            setLocalName: setLocalName::<Identity>,
+            setQName: setQName::<Identity>,
+            setType: setType::<Identity>,
+            setURI: setURI::<Identity>,
+            setValue: setValue::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IMXAttributes {}
 windows_core::imp::define_interface!(IMXNamespaceManager, IMXNamespaceManager_Vtbl, 0xc90352f6_643c_4fbc_bb23_e996eb2d51fd);
 windows_core::imp::interface_hierarchy!(IMXNamespaceManager, windows_core::IUnknown);
@@ -907,6 +995,93 @@ impl IMXNamespaceManager_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IMXNamespaceManager_Impl> windows_core::imp::TearOffVtable<Identity> for IMXNamespaceManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn putAllowOverride<Identity: windows_core::IUnknownImpl + IMXNamespaceManager_Impl>(this: *mut core::ffi::c_void, foverride: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXNamespaceManager_Impl::putAllowOverride(this, core::mem::transmute_copy(&foverride)).into()
+            }
+        }
+        unsafe extern "system" fn getAllowOverride<Identity: windows_core::IUnknownImpl + IMXNamespaceManager_Impl>(this: *mut core::ffi::c_void, foverride: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMXNamespaceManager_Impl::getAllowOverride(this) {
+                    Ok(ok__) => {
+                        foverride.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn reset<Identity: windows_core::IUnknownImpl + IMXNamespaceManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXNamespaceManager_Impl::reset(this).into()
+            }
+        }
+        unsafe extern "system" fn pushContext<Identity: windows_core::IUnknownImpl + IMXNamespaceManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXNamespaceManager_Impl::pushContext(this).into()
+            }
+        }
+        unsafe extern "system" fn pushNodeContext<Identity: windows_core::IUnknownImpl + IMXNamespaceManager_Impl>(this: *mut core::ffi::c_void, contextnode: *mut core::ffi::c_void, fdeep: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXNamespaceManager_Impl::pushNodeContext(this, core::mem::transmute_copy(&contextnode), core::mem::transmute_copy(&fdeep)).into()
+            }
+        }
+        unsafe extern "system" fn popContext<Identity: windows_core::IUnknownImpl + IMXNamespaceManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXNamespaceManager_Impl::popContext(this).into()
+            }
+        }
+        unsafe extern "system" fn declarePrefix<Identity: windows_core::IUnknownImpl + IMXNamespaceManager_Impl>(this: *mut core::ffi::c_void, prefix: windows_core::PCWSTR, namespaceuri: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXNamespaceManager_Impl::declarePrefix(this, core::mem::transmute(&prefix), core::mem::transmute(&namespaceuri)).into()
+            }
+        }
+        unsafe extern "system" fn getDeclaredPrefix<Identity: windows_core::IUnknownImpl + IMXNamespaceManager_Impl>(this: *mut core::ffi::c_void, nindex: i32, pwchprefix: windows_core::PWSTR, pcchprefix: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXNamespaceManager_Impl::getDeclaredPrefix(this, core::mem::transmute_copy(&nindex), core::mem::transmute_copy(&pwchprefix), core::mem::transmute_copy(&pcchprefix)).into()
+            }
+        }
+        unsafe extern "system" fn getPrefix<Identity: windows_core::IUnknownImpl + IMXNamespaceManager_Impl>(this: *mut core::ffi::c_void, pwsznamespaceuri: windows_core::PCWSTR, nindex: i32, pwchprefix: windows_core::PWSTR, pcchprefix: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXNamespaceManager_Impl::getPrefix(this, core::mem::transmute(&pwsznamespaceuri), core::mem::transmute_copy(&nindex), core::mem::transmute_copy(&pwchprefix), core::mem::transmute_copy(&pcchprefix)).into()
+            }
+        }
+        unsafe extern "system" fn getURI<Identity: windows_core::IUnknownImpl + IMXNamespaceManager_Impl>(this: *mut core::ffi::c_void, pwchprefix: windows_core::PCWSTR, pcontextnode: *mut core::ffi::c_void, pwchuri: windows_core::PWSTR, pcchuri: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXNamespaceManager_Impl::getURI(this, core::mem::transmute(&pwchprefix), core::mem::transmute_copy(&pcontextnode), core::mem::transmute_copy(&pwchuri), core::mem::transmute_copy(&pcchuri)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            putAllowOverride: putAllowOverride::<Identity>,
+            getAllowOverride: getAllowOverride::<Identity>,
+            reset: reset::<Identity>,
+            pushContext: pushContext::<Identity>,
+            pushNodeContext: pushNodeContext::<Identity>,
+            popContext: popContext::<Identity>,
+            declarePrefix: declarePrefix::<Identity>,
+            getDeclaredPrefix: getDeclaredPrefix::<Identity>,
+            getPrefix: getPrefix::<Identity>,
+            getURI: getURI::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IMXNamespaceManager {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IMXNamespacePrefixes, IMXNamespacePrefixes_Vtbl, 0xc90352f4_643c_4fbc_bb23_e996eb2d51fd);
@@ -1006,6 +1181,56 @@ impl IMXNamespacePrefixes_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IMXNamespacePrefixes_Impl> windows_core::imp::TearOffVtable<Identity> for IMXNamespacePrefixes_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn get_item<Identity: windows_core::IUnknownImpl + IMXNamespacePrefixes_Impl>(this: *mut core::ffi::c_void, index: i32, prefix: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMXNamespacePrefixes_Impl::get_item(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        prefix.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn length<Identity: windows_core::IUnknownImpl + IMXNamespacePrefixes_Impl>(this: *mut core::ffi::c_void, length: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMXNamespacePrefixes_Impl::length(this) {
+                    Ok(ok__) => {
+                        length.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _newEnum<Identity: windows_core::IUnknownImpl + IMXNamespacePrefixes_Impl>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMXNamespacePrefixes_Impl::_newEnum(this) {
+                    Ok(ok__) => {
+                        ppunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            get_item: get_item::<Identity>,
+            length: length::<Identity>,
+            _newEnum: _newEnum::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IMXNamespacePrefixes {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IMXReaderControl, IMXReaderControl_Vtbl, 0x808f4e35_8d5a_4fbe_8466_33a41279ed30);
@@ -1078,6 +1303,38 @@ impl IMXReaderControl_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IMXReaderControl_Impl> windows_core::imp::TearOffVtable<Identity> for IMXReaderControl_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn abort<Identity: windows_core::IUnknownImpl + IMXReaderControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXReaderControl_Impl::abort(this).into()
+            }
+        }
+        unsafe extern "system" fn resume<Identity: windows_core::IUnknownImpl + IMXReaderControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXReaderControl_Impl::resume(this).into()
+            }
+        }
+        unsafe extern "system" fn suspend<Identity: windows_core::IUnknownImpl + IMXReaderControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXReaderControl_Impl::suspend(this).into()
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            abort: abort::<Identity>,
+            resume: resume::<Identity>,
+            suspend: suspend::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IMXReaderControl {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IMXSchemaDeclHandler, IMXSchemaDeclHandler_Vtbl, 0xfa4bb38c_faf9_4cca_9302_d1dd0fe520db);
@@ -1126,6 +1383,24 @@ impl IMXSchemaDeclHandler_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IMXSchemaDeclHandler_Impl> windows_core::imp::TearOffVtable<Identity> for IMXSchemaDeclHandler_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn schemaElementDecl<Identity: windows_core::IUnknownImpl + IMXSchemaDeclHandler_Impl>(this: *mut core::ffi::c_void, oschemaelement: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXSchemaDeclHandler_Impl::schemaElementDecl(this, core::mem::transmute_copy(&oschemaelement)).into()
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            schemaElementDecl: schemaElementDecl::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IMXSchemaDeclHandler {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IMXWriter, IMXWriter_Vtbl, 0x4d7ff4ba_1565_4ea8_94e1_6e724a46f98d);
@@ -1446,82 +1721,260 @@ impl IMXWriter_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IMXWriter {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IMXXMLFilter, IMXXMLFilter_Vtbl, 0xc90352f7_643c_4fbc_bb23_e996eb2d51fd);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IMXXMLFilter {
-    type Target = super::super::super::System::Com::IDispatch;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IMXXMLFilter, windows_core::IUnknown, super::super::super::System::Com::IDispatch);
-#[cfg(feature = "Win32_System_Com")]
-impl IMXXMLFilter {
-    pub unsafe fn getFeature(&self, strname: &windows_core::BSTR) -> windows_core::Result<super::super::super::Foundation::VARIANT_BOOL> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).getFeature)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(strname), &mut result__).map(|| result__)
+impl<Identity: windows_core::IUnknownImpl + IMXWriter_Impl> windows_core::imp::TearOffVtable<Identity> for IMXWriter_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Setoutput<Identity: windows_core::IUnknownImpl + IMXWriter_Impl>(this: *mut core::ffi::c_void, vardestination: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXWriter_Impl::Setoutput(this, core::mem::transmute(&vardestination)).into()
+            }
         }
-    }
-    pub unsafe fn putFeature(&self, strname: &windows_core::BSTR, fvalue: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).putFeature)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(strname), fvalue).ok() }
-    }
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub unsafe fn getProperty(&self, strname: &windows_core::BSTR) -> windows_core::Result<super::super::super::System::Variant::VARIANT> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).getProperty)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(strname), &mut result__).map(|| core::mem::transmute(result__))
+        unsafe extern "system" fn output<Identity: windows_core::IUnknownImpl + IMXWriter_Impl>(this: *mut core::ffi::c_void, vardestination: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMXWriter_Impl::output(this) {
+                    Ok(ok__) => {
+                        vardestination.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub unsafe fn putProperty(&self, strname: &windows_core::BSTR, varvalue: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).putProperty)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(strname), core::mem::transmute_copy(varvalue)).ok() }
-    }
-    pub unsafe fn entityResolver(&self) -> windows_core::Result<windows_core::IUnknown> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).entityResolver)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn Setencoding<Identity: windows_core::IUnknownImpl + IMXWriter_Impl>(this: *mut core::ffi::c_void, strencoding: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXWriter_Impl::Setencoding(this, core::mem::transmute(&strencoding)).into()
+            }
         }
-    }
-    pub unsafe fn putref_entityResolver<P0>(&self, oresolver: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<windows_core::IUnknown>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).putref_entityResolver)(windows_core::Interface::as_raw(self), oresolver.param().abi()).ok() }
-    }
-    pub unsafe fn contentHandler(&self) -> windows_core::Result<windows_core::IUnknown> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).contentHandler)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn encoding<Identity: windows_core::IUnknownImpl + IMXWriter_Impl>(this: *mut core::ffi::c_void, strencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMXWriter_Impl::encoding(this) {
+                    Ok(ok__) => {
+                        strencoding.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn putref_contentHandler<P0>(&self, ohandler: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<windows_core::IUnknown>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).putref_contentHandler)(windows_core::Interface::as_raw(self), ohandler.param().abi()).ok() }
-    }
-    pub unsafe fn dtdHandler(&self) -> windows_core::Result<windows_core::IUnknown> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).dtdHandler)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn SetbyteOrderMark<Identity: windows_core::IUnknownImpl + IMXWriter_Impl>(this: *mut core::ffi::c_void, fwritebyteordermark: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXWriter_Impl::SetbyteOrderMark(this, core::mem::transmute_copy(&fwritebyteordermark)).into()
+            }
         }
-    }
-    pub unsafe fn putref_dtdHandler<P0>(&self, ohandler: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<windows_core::IUnknown>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).putref_dtdHandler)(windows_core::Interface::as_raw(self), ohandler.param().abi()).ok() }
-    }
-    pub unsafe fn errorHandler(&self) -> windows_core::Result<windows_core::IUnknown> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).errorHandler)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn byteOrderMark<Identity: windows_core::IUnknownImpl + IMXWriter_Impl>(this: *mut core::ffi::c_void, fwritebyteordermark: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMXWriter_Impl::byteOrderMark(this) {
+                    Ok(ok__) => {
+                        fwritebyteordermark.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
+        unsafe extern "system" fn Setindent<Identity: windows_core::IUnknownImpl + IMXWriter_Impl>(this: *mut core::ffi::c_void, findentmode: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXWriter_Impl::Setindent(this, core::mem::transmute_copy(&findentmode)).into()
+            }
+        }
+        unsafe extern "system" fn indent<Identity: windows_core::IUnknownImpl + IMXWriter_Impl>(this: *mut core::ffi::c_void, findentmode: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMXWriter_Impl::indent(this) {
+                    Ok(ok__) => {
+                        findentmode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Setstandalone<Identity: windows_core::IUnknownImpl + IMXWriter_Impl>(this: *mut core::ffi::c_void, fvalue: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXWriter_Impl::Setstandalone(this, core::mem::transmute_copy(&fvalue)).into()
+            }
+        }
+        unsafe extern "system" fn standalone<Identity: windows_core::IUnknownImpl + IMXWriter_Impl>(this: *mut core::ffi::c_void, fvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMXWriter_Impl::standalone(this) {
+                    Ok(ok__) => {
+                        fvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetomitXMLDeclaration<Identity: windows_core::IUnknownImpl + IMXWriter_Impl>(this: *mut core::ffi::c_void, fvalue: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXWriter_Impl::SetomitXMLDeclaration(this, core::mem::transmute_copy(&fvalue)).into()
+            }
+        }
+        unsafe extern "system" fn omitXMLDeclaration<Identity: windows_core::IUnknownImpl + IMXWriter_Impl>(this: *mut core::ffi::c_void, fvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMXWriter_Impl::omitXMLDeclaration(this) {
+                    Ok(ok__) => {
+                        fvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Setversion<Identity: windows_core::IUnknownImpl + IMXWriter_Impl>(this: *mut core::ffi::c_void, strversion: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXWriter_Impl::Setversion(this, core::mem::transmute(&strversion)).into()
+            }
+        }
+        unsafe extern "system" fn version<Identity: windows_core::IUnknownImpl + IMXWriter_Impl>(this: *mut core::ffi::c_void, strversion: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMXWriter_Impl::version(this) {
+                    Ok(ok__) => {
+                        strversion.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetdisableOutputEscaping<Identity: windows_core::IUnknownImpl + IMXWriter_Impl>(this: *mut core::ffi::c_void, fvalue: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXWriter_Impl::SetdisableOutputEscaping(this, core::mem::transmute_copy(&fvalue)).into()
+            }
+        }
+        unsafe extern "system" fn disableOutputEscaping<Identity: windows_core::IUnknownImpl + IMXWriter_Impl>(this: *mut core::ffi::c_void, fvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMXWriter_Impl::disableOutputEscaping(this) {
+                    Ok(ok__) => {
+                        fvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn flush<Identity: windows_core::IUnknownImpl + IMXWriter_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXWriter_Impl::flush(this).into()
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Setoutput: Setoutput::<Identity>,
+            output: output::<Identity>,
+            Setencoding: Setencoding::<Identity>,
+            encoding: encoding::<Identity>,
+            SetbyteOrderMark: SetbyteOrderMark::<Identity>,
+            byteOrderMark: byteOrderMark::<Identity>,
+            Setindent: Setindent::<Identity>,
+            indent: indent::<Identity>,
+            Setstandalone: Setstandalone::<Identity>,
+            standalone: standalone::<Identity>,
+            SetomitXMLDeclaration: SetomitXMLDeclaration::<Identity>,
+            omitXMLDeclaration: omitXMLDeclaration::<Identity>,
+            Setversion: Setversion::<Identity>,
+            version: version::<Identity>,
+            SetdisableOutputEscaping: SetdisableOutputEscaping::<Identity>,
+            disableOutputEscaping: disableOutputEscaping::<Identity>,
+            flush: flush::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IMXWriter {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(IMXXMLFilter, IMXXMLFilter_Vtbl, 0xc90352f7_643c_4fbc_bb23_e996eb2d51fd);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for IMXXMLFilter {
+    type Target = super::super::super::System::Com::IDispatch;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(IMXXMLFilter, windows_core::IUnknown, super::super::super::System::Com::IDispatch);
+#[cfg(feature = "Win32_System_Com")]
+impl IMXXMLFilter {
+    pub unsafe fn getFeature(&self, strname: &windows_core::BSTR) -> windows_core::Result<super::super::super::Foundation::VARIANT_BOOL> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).getFeature)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(strname), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn putFeature(&self, strname: &windows_core::BSTR, fvalue: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).putFeature)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(strname), fvalue).ok() }
+    }
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub unsafe fn getProperty(&self, strname: &windows_core::BSTR) -> windows_core::Result<super::super::super::System::Variant::VARIANT> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).getProperty)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(strname), &mut result__).map(|| core::mem::transmute(result__))
+        }
+    }
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub unsafe fn putProperty(&self, strname: &windows_core::BSTR, varvalue: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).putProperty)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(strname), core::mem::transmute_copy(varvalue)).ok() }
+    }
+    pub unsafe fn entityResolver(&self) -> windows_core::Result<windows_core::IUnknown> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).entityResolver)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn putref_entityResolver<P0>(&self, oresolver: P0) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::IUnknown>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).putref_entityResolver)(windows_core::Interface::as_raw(self), oresolver.param().abi()).ok() }
+    }
+    pub unsafe fn contentHandler(&self) -> windows_core::Result<windows_core::IUnknown> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).contentHandler)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn putref_contentHandler<P0>(&self, ohandler: P0) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::IUnknown>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).putref_contentHandler)(windows_core::Interface::as_raw(self), ohandler.param().abi()).ok() }
+    }
+    pub unsafe fn dtdHandler(&self) -> windows_core::Result<windows_core::IUnknown> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).dtdHandler)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn putref_dtdHandler<P0>(&self, ohandler: P0) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::IUnknown>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).putref_dtdHandler)(windows_core::Interface::as_raw(self), ohandler.param().abi()).ok() }
+    }
+    pub unsafe fn errorHandler(&self) -> windows_core::Result<windows_core::IUnknown> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).errorHandler)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
     pub unsafe fn putref_errorHandler<P0>(&self, ohandler: P0) -> windows_core::Result<()>
     where
         P0: windows_core::Param<windows_core::IUnknown>,
@@ -1700,6 +2153,137 @@ impl IMXXMLFilter_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IMXXMLFilter_Impl> windows_core::imp::TearOffVtable<Identity> for IMXXMLFilter_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn getFeature<Identity: windows_core::IUnknownImpl + IMXXMLFilter_Impl>(this: *mut core::ffi::c_void, strname: *mut core::ffi::c_void, fvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMXXMLFilter_Impl::getFeature(this, core::mem::transmute(&strname)) {
+                    Ok(ok__) => {
+                        fvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putFeature<Identity: windows_core::IUnknownImpl + IMXXMLFilter_Impl>(this: *mut core::ffi::c_void, strname: *mut core::ffi::c_void, fvalue: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXXMLFilter_Impl::putFeature(this, core::mem::transmute(&strname), core::mem::transmute_copy(&fvalue)).into()
+            }
+        }
+        unsafe extern "system" fn getProperty<Identity: windows_core::IUnknownImpl + IMXXMLFilter_Impl>(this: *mut core::ffi::c_void, strname: *mut core::ffi::c_void, varvalue: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMXXMLFilter_Impl::getProperty(this, core::mem::transmute(&strname)) {
+                    Ok(ok__) => {
+                        varvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putProperty<Identity: windows_core::IUnknownImpl + IMXXMLFilter_Impl>(this: *mut core::ffi::c_void, strname: *mut core::ffi::c_void, varvalue: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXXMLFilter_Impl::putProperty(this, core::mem::transmute(&strname), core::mem::transmute(&varvalue)).into()
+            }
+        }
+        unsafe extern "system" fn entityResolver<Identity: windows_core::IUnknownImpl + IMXXMLFilter_Impl>(this: *mut core::ffi::c_void, oresolver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMXXMLFilter_Impl::entityResolver(this) {
+                    Ok(ok__) => {
+                        oresolver.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putref_entityResolver<Identity: windows_core::IUnknownImpl + IMXXMLFilter_Impl>(this: *mut core::ffi::c_void, oresolver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXXMLFilter_Impl::putref_entityResolver(this, core::mem::transmute_copy(&oresolver)).into()
+            }
+        }
+        unsafe extern "system" fn contentHandler<Identity: windows_core::IUnknownImpl + IMXXMLFilter_Impl>(this: *mut core::ffi::c_void, ohandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMXXMLFilter_Impl::contentHandler(this) {
+                    Ok(ok__) => {
+                        ohandler.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putref_contentHandler<Identity: windows_core::IUnknownImpl + IMXXMLFilter_Impl>(this: *mut core::ffi::c_void, ohandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXXMLFilter_Impl::putref_contentHandler(this, core::mem::transmute_copy(&ohandler)).into()
+            }
+        }
+        unsafe extern "system" fn dtdHandler<Identity: windows_core::IUnknownImpl + IMXXMLFilter_Impl>(this: *mut core::ffi::c_void, ohandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMXXMLFilter_Impl::dtdHandler(this) {
+                    Ok(ok__) => {
+                        ohandler.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putref_dtdHandler<Identity: windows_core::IUnknownImpl + IMXXMLFilter_Impl>(this: *mut core::ffi::c_void, ohandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXXMLFilter_Impl::putref_dtdHandler(this, core::mem::transmute_copy(&ohandler)).into()
+            }
+        }
+        unsafe extern "system" fn errorHandler<Identity: windows_core::IUnknownImpl + IMXXMLFilter_Impl>(this: *mut core::ffi::c_void, ohandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMXXMLFilter_Impl::errorHandler(this) {
+                    Ok(ok__) => {
+                        ohandler.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putref_errorHandler<Identity: windows_core::IUnknownImpl + IMXXMLFilter_Impl>(this: *mut core::ffi::c_void, ohandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMXXMLFilter_Impl::putref_errorHandler(this, core::mem::transmute_copy(&ohandler)).into()
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            getFeature: getFeature::<Identity>,
+            putFeature: putFeature::<Identity>,
+            getProperty: getProperty::<Identity>,
+            putProperty: putProperty::<Identity>,
+            entityResolver: entityResolver::<Identity>,
+            putref_entityResolver: putref_entityResolver::<Identity>,
+            contentHandler: contentHandler::<Identity>,
+            putref_contentHandler: putref_contentHandler::<Identity>,
+            dtdHandler: dtdHandler::<Identity>,
+            putref_dtdHandler: putref_dtdHandler::<Identity>,
+            errorHandler: errorHandler::<Identity>,
+            putref_errorHandler: putref_errorHandler::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IMXXMLFilter {}
 windows_core::imp::define_interface!(ISAXAttributes, ISAXAttributes_Vtbl, 0xf078abe1_45d2_4832_91ea_4466ce2f25c9);
 windows_core::imp::interface_hierarchy!(ISAXAttributes, windows_core::IUnknown);
@@ -1926,23 +2510,142 @@ impl ISAXAttributes_Vtbl {
         iid == &<ISAXAttributes as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for ISAXAttributes {}
-windows_core::imp::define_interface!(ISAXContentHandler, ISAXContentHandler_Vtbl, 0x1545cdfa_9e4e_4497_a8a4_2bf7d0112c44);
-windows_core::imp::interface_hierarchy!(ISAXContentHandler, windows_core::IUnknown);
-impl ISAXContentHandler {
-    pub unsafe fn putDocumentLocator<P0>(&self, plocator: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<ISAXLocator>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).putDocumentLocator)(windows_core::Interface::as_raw(self), plocator.param().abi()).ok() }
-    }
-    pub unsafe fn startDocument(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).startDocument)(windows_core::Interface::as_raw(self)).ok() }
-    }
-    pub unsafe fn endDocument(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).endDocument)(windows_core::Interface::as_raw(self)).ok() }
-    }
-    pub unsafe fn startPrefixMapping<P0, P2>(&self, pwchprefix: P0, cchprefix: i32, pwchuri: P2, cchuri: i32) -> windows_core::Result<()>
+impl<Identity: windows_core::IUnknownImpl + ISAXAttributes_Impl> windows_core::imp::TearOffVtable<Identity> for ISAXAttributes_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn getLength<Identity: windows_core::IUnknownImpl + ISAXAttributes_Impl>(this: *mut core::ffi::c_void, pnlength: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISAXAttributes_Impl::getLength(this) {
+                    Ok(ok__) => {
+                        pnlength.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getURI<Identity: windows_core::IUnknownImpl + ISAXAttributes_Impl>(this: *mut core::ffi::c_void, nindex: i32, ppwchuri: *mut *mut u16, pcchuri: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXAttributes_Impl::getURI(this, core::mem::transmute_copy(&nindex), core::mem::transmute_copy(&ppwchuri), core::mem::transmute_copy(&pcchuri)).into()
+            }
+        }
+        unsafe extern "system" fn getLocalName<Identity: windows_core::IUnknownImpl + ISAXAttributes_Impl>(this: *mut core::ffi::c_void, nindex: i32, ppwchlocalname: *mut *mut u16, pcchlocalname: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXAttributes_Impl::getLocalName(this, core::mem::transmute_copy(&nindex), core::mem::transmute_copy(&ppwchlocalname), core::mem::transmute_copy(&pcchlocalname)).into()
+            }
+        }
+        unsafe extern "system" fn getQName<Identity: windows_core::IUnknownImpl + ISAXAttributes_Impl>(this: *mut core::ffi::c_void, nindex: i32, ppwchqname: *mut *mut u16, pcchqname: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXAttributes_Impl::getQName(this, core::mem::transmute_copy(&nindex), core::mem::transmute_copy(&ppwchqname), core::mem::transmute_copy(&pcchqname)).into()
+            }
+        }
+        unsafe extern "system" fn getName<Identity: windows_core::IUnknownImpl + ISAXAttributes_Impl>(this: *mut core::ffi::c_void, nindex: i32, ppwchuri: *mut *mut u16, pcchuri: *mut i32, ppwchlocalname: *mut *mut u16, pcchlocalname: *mut i32, ppwchqname: *mut *mut u16, pcchqname: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXAttributes_Impl::getName(this, core::mem::transmute_copy(&nindex), core::mem::transmute_copy(&ppwchuri), core::mem::transmute_copy(&pcchuri), core::mem::transmute_copy(&ppwchlocalname), core::mem::transmute_copy(&pcchlocalname), core::mem::transmute_copy(&ppwchqname), core::mem::transmute_copy(&pcchqname)).into()
+            }
+        }
+        unsafe extern "system" fn getIndexFromName<Identity: windows_core::IUnknownImpl + ISAXAttributes_Impl>(this: *mut core::ffi::c_void, pwchuri: windows_core::PCWSTR, cchuri: i32, pwchlocalname: windows_core::PCWSTR, cchlocalname: i32, pnindex: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISAXAttributes_Impl::getIndexFromName(this, core::mem::transmute(&pwchuri), core::mem::transmute_copy(&cchuri), core::mem::transmute(&pwchlocalname), core::mem::transmute_copy(&cchlocalname)) {
+                    Ok(ok__) => {
+                        pnindex.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getIndexFromQName<Identity: windows_core::IUnknownImpl + ISAXAttributes_Impl>(this: *mut core::ffi::c_void, pwchqname: windows_core::PCWSTR, cchqname: i32, pnindex: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISAXAttributes_Impl::getIndexFromQName(this, core::mem::transmute(&pwchqname), core::mem::transmute_copy(&cchqname)) {
+                    Ok(ok__) => {
+                        pnindex.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getType<Identity: windows_core::IUnknownImpl + ISAXAttributes_Impl>(this: *mut core::ffi::c_void, nindex: i32, ppwchtype: *mut *mut u16, pcchtype: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXAttributes_Impl::getType(this, core::mem::transmute_copy(&nindex), core::mem::transmute_copy(&ppwchtype), core::mem::transmute_copy(&pcchtype)).into()
+            }
+        }
+        unsafe extern "system" fn getTypeFromName<Identity: windows_core::IUnknownImpl + ISAXAttributes_Impl>(this: *mut core::ffi::c_void, pwchuri: windows_core::PCWSTR, cchuri: i32, pwchlocalname: windows_core::PCWSTR, cchlocalname: i32, ppwchtype: *mut *mut u16, pcchtype: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXAttributes_Impl::getTypeFromName(this, core::mem::transmute(&pwchuri), core::mem::transmute_copy(&cchuri), core::mem::transmute(&pwchlocalname), core::mem::transmute_copy(&cchlocalname), core::mem::transmute_copy(&ppwchtype), core::mem::transmute_copy(&pcchtype)).into()
+            }
+        }
+        unsafe extern "system" fn getTypeFromQName<Identity: windows_core::IUnknownImpl + ISAXAttributes_Impl>(this: *mut core::ffi::c_void, pwchqname: windows_core::PCWSTR, cchqname: i32, ppwchtype: *mut *mut u16, pcchtype: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXAttributes_Impl::getTypeFromQName(this, core::mem::transmute(&pwchqname), core::mem::transmute_copy(&cchqname), core::mem::transmute_copy(&ppwchtype), core::mem::transmute_copy(&pcchtype)).into()
+            }
+        }
+        unsafe extern "system" fn getValue<Identity: windows_core::IUnknownImpl + ISAXAttributes_Impl>(this: *mut core::ffi::c_void, nindex: i32, ppwchvalue: *mut *mut u16, pcchvalue: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXAttributes_Impl::getValue(this, core::mem::transmute_copy(&nindex), core::mem::transmute_copy(&ppwchvalue), core::mem::transmute_copy(&pcchvalue)).into()
+            }
+        }
+        unsafe extern "system" fn getValueFromName<Identity: windows_core::IUnknownImpl + ISAXAttributes_Impl>(this: *mut core::ffi::c_void, pwchuri: windows_core::PCWSTR, cchuri: i32, pwchlocalname: windows_core::PCWSTR, cchlocalname: i32, ppwchvalue: *mut *mut u16, pcchvalue: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXAttributes_Impl::getValueFromName(this, core::mem::transmute(&pwchuri), core::mem::transmute_copy(&cchuri), core::mem::transmute(&pwchlocalname), core::mem::transmute_copy(&cchlocalname), core::mem::transmute_copy(&ppwchvalue), core::mem::transmute_copy(&pcchvalue)).into()
+            }
+        }
+        unsafe extern "system" fn getValueFromQName<Identity: windows_core::IUnknownImpl + ISAXAttributes_Impl>(this: *mut core::ffi::c_void, pwchqname: windows_core::PCWSTR, cchqname: i32, ppwchvalue: *mut *mut u16, pcchvalue: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXAttributes_Impl::getValueFromQName(this, core::mem::transmute(&pwchqname), core::mem::transmute_copy(&cchqname), core::mem::transmute_copy(&ppwchvalue), core::mem::transmute_copy(&pcchvalue)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            getLength: getLength::<Identity>,
+            getURI: getURI::<Identity>,
+            getLocalName: getLocalName::<Identity>,
+            getQName: getQName::<Identity>,
+            getName: getName::<Identity>,
+            getIndexFromName: getIndexFromName::<Identity>,
+            getIndexFromQName: getIndexFromQName::<Identity>,
+            getType: getType::<Identity>,
+            getTypeFromName: getTypeFromName::<Identity>,
+            getTypeFromQName: getTypeFromQName::<Identity>,
+            getValue: getValue::<Identity>,
+            getValueFromName: getValueFromName::<Identity>,
+            getValueFromQName: getValueFromQName::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for ISAXAttributes {}
+windows_core::imp::define_interface!(ISAXContentHandler, ISAXContentHandler_Vtbl, 0x1545cdfa_9e4e_4497_a8a4_2bf7d0112c44);
+windows_core::imp::interface_hierarchy!(ISAXContentHandler, windows_core::IUnknown);
+impl ISAXContentHandler {
+    pub unsafe fn putDocumentLocator<P0>(&self, plocator: P0) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<ISAXLocator>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).putDocumentLocator)(windows_core::Interface::as_raw(self), plocator.param().abi()).ok() }
+    }
+    pub unsafe fn startDocument(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).startDocument)(windows_core::Interface::as_raw(self)).ok() }
+    }
+    pub unsafe fn endDocument(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).endDocument)(windows_core::Interface::as_raw(self)).ok() }
+    }
+    pub unsafe fn startPrefixMapping<P0, P2>(&self, pwchprefix: P0, cchprefix: i32, pwchuri: P2, cchuri: i32) -> windows_core::Result<()>
     where
         P0: windows_core::Param<windows_core::PCWSTR>,
         P2: windows_core::Param<windows_core::PCWSTR>,
@@ -2114,6 +2817,93 @@ impl ISAXContentHandler_Vtbl {
         iid == &<ISAXContentHandler as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISAXContentHandler_Impl> windows_core::imp::TearOffVtable<Identity> for ISAXContentHandler_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn putDocumentLocator<Identity: windows_core::IUnknownImpl + ISAXContentHandler_Impl>(this: *mut core::ffi::c_void, plocator: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXContentHandler_Impl::putDocumentLocator(this, core::mem::transmute_copy(&plocator)).into()
+            }
+        }
+        unsafe extern "system" fn startDocument<Identity: windows_core::IUnknownImpl + ISAXContentHandler_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXContentHandler_Impl::startDocument(this).into()
+            }
+        }
+        unsafe extern "system" fn endDocument<Identity: windows_core::IUnknownImpl + ISAXContentHandler_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXContentHandler_Impl::endDocument(this).into()
+            }
+        }
+        unsafe extern "system" fn startPrefixMapping<Identity: windows_core::IUnknownImpl + ISAXContentHandler_Impl>(this: *mut core::ffi::c_void, pwchprefix: windows_core::PCWSTR, cchprefix: i32, pwchuri: windows_core::PCWSTR, cchuri: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXContentHandler_Impl::startPrefixMapping(this, core::mem::transmute(&pwchprefix), core::mem::transmute_copy(&cchprefix), core::mem::transmute(&pwchuri), core::mem::transmute_copy(&cchuri)).into()
+            }
+        }
+        unsafe extern "system" fn endPrefixMapping<Identity: windows_core::IUnknownImpl + ISAXContentHandler_Impl>(this: *mut core::ffi::c_void, pwchprefix: windows_core::PCWSTR, cchprefix: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXContentHandler_Impl::endPrefixMapping(this, core::mem::transmute(&pwchprefix), core::mem::transmute_copy(&cchprefix)).into()
+            }
+        }
+        unsafe extern "system" fn startElement<Identity: windows_core::IUnknownImpl + ISAXContentHandler_Impl>(this: *mut core::ffi::c_void, pwchnamespaceuri: windows_core::PCWSTR, cchnamespaceuri: i32, pwchlocalname: windows_core::PCWSTR, cchlocalname: i32, pwchqname: windows_core::PCWSTR, cchqname: i32, pattributes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXContentHandler_Impl::startElement(this, core::mem::transmute(&pwchnamespaceuri), core::mem::transmute_copy(&cchnamespaceuri), core::mem::transmute(&pwchlocalname), core::mem::transmute_copy(&cchlocalname), core::mem::transmute(&pwchqname), core::mem::transmute_copy(&cchqname), core::mem::transmute_copy(&pattributes)).into()
+            }
+        }
+        unsafe extern "system" fn endElement<Identity: windows_core::IUnknownImpl + ISAXContentHandler_Impl>(this: *mut core::ffi::c_void, pwchnamespaceuri: windows_core::PCWSTR, cchnamespaceuri: i32, pwchlocalname: windows_core::PCWSTR, cchlocalname: i32, pwchqname: windows_core::PCWSTR, cchqname: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXContentHandler_Impl::endElement(this, core::mem::transmute(&pwchnamespaceuri), core::mem::transmute_copy(&cchnamespaceuri), core::mem::transmute(&pwchlocalname), core::mem::transmute_copy(&cchlocalname), core::mem::transmute(&pwchqname), core::mem::transmute_copy(&cchqname)).into()
+            }
+        }
+        unsafe extern "system" fn characters<Identity: windows_core::IUnknownImpl + ISAXContentHandler_Impl>(this: *mut core::ffi::c_void, pwchchars: windows_core::PCWSTR, cchchars: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXContentHandler_Impl::characters(this, core::mem::transmute(&pwchchars), core::mem::transmute_copy(&cchchars)).into()
+            }
+        }
+        unsafe extern "system" fn ignorableWhitespace<Identity: windows_core::IUnknownImpl + ISAXContentHandler_Impl>(this: *mut core::ffi::c_void, pwchchars: windows_core::PCWSTR, cchchars: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXContentHandler_Impl::ignorableWhitespace(this, core::mem::transmute(&pwchchars), core::mem::transmute_copy(&cchchars)).into()
+            }
+        }
+        unsafe extern "system" fn processingInstruction<Identity: windows_core::IUnknownImpl + ISAXContentHandler_Impl>(this: *mut core::ffi::c_void, pwchtarget: windows_core::PCWSTR, cchtarget: i32, pwchdata: windows_core::PCWSTR, cchdata: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXContentHandler_Impl::processingInstruction(this, core::mem::transmute(&pwchtarget), core::mem::transmute_copy(&cchtarget), core::mem::transmute(&pwchdata), core::mem::transmute_copy(&cchdata)).into()
+            }
+        }
+        unsafe extern "system" fn skippedEntity<Identity: windows_core::IUnknownImpl + ISAXContentHandler_Impl>(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, cchname: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXContentHandler_Impl::skippedEntity(this, core::mem::transmute(&pwchname), core::mem::transmute_copy(&cchname)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            putDocumentLocator: putDocumentLocator::<Identity>,
+            startDocument: startDocument::<Identity>,
+            endDocument: endDocument::<Identity>,
+            startPrefixMapping: startPrefixMapping::<Identity>,
+            endPrefixMapping: endPrefixMapping::<Identity>,
+            startElement: startElement::<Identity>,
+            endElement: endElement::<Identity>,
+            characters: characters::<Identity>,
+            ignorableWhitespace: ignorableWhitespace::<Identity>,
+            processingInstruction: processingInstruction::<Identity>,
+            skippedEntity: skippedEntity::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISAXContentHandler {}
 windows_core::imp::define_interface!(ISAXDTDHandler, ISAXDTDHandler_Vtbl, 0xe15c1baf_afb3_4d60_8c36_19a8c45defed);
 windows_core::imp::interface_hierarchy!(ISAXDTDHandler, windows_core::IUnknown);
@@ -2171,6 +2961,30 @@ impl ISAXDTDHandler_Vtbl {
         iid == &<ISAXDTDHandler as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISAXDTDHandler_Impl> windows_core::imp::TearOffVtable<Identity> for ISAXDTDHandler_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn notationDecl<Identity: windows_core::IUnknownImpl + ISAXDTDHandler_Impl>(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, cchname: i32, pwchpublicid: windows_core::PCWSTR, cchpublicid: i32, pwchsystemid: windows_core::PCWSTR, cchsystemid: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXDTDHandler_Impl::notationDecl(this, core::mem::transmute(&pwchname), core::mem::transmute_copy(&cchname), core::mem::transmute(&pwchpublicid), core::mem::transmute_copy(&cchpublicid), core::mem::transmute(&pwchsystemid), core::mem::transmute_copy(&cchsystemid)).into()
+            }
+        }
+        unsafe extern "system" fn unparsedEntityDecl<Identity: windows_core::IUnknownImpl + ISAXDTDHandler_Impl>(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, cchname: i32, pwchpublicid: windows_core::PCWSTR, cchpublicid: i32, pwchsystemid: windows_core::PCWSTR, cchsystemid: i32, pwchnotationname: windows_core::PCWSTR, cchnotationname: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXDTDHandler_Impl::unparsedEntityDecl(this, core::mem::transmute(&pwchname), core::mem::transmute_copy(&cchname), core::mem::transmute(&pwchpublicid), core::mem::transmute_copy(&cchpublicid), core::mem::transmute(&pwchsystemid), core::mem::transmute_copy(&cchsystemid), core::mem::transmute(&pwchnotationname), core::mem::transmute_copy(&cchnotationname)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            notationDecl: notationDecl::<Identity>,
+            unparsedEntityDecl: unparsedEntityDecl::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISAXDTDHandler {}
 windows_core::imp::define_interface!(ISAXDeclHandler, ISAXDeclHandler_Vtbl, 0x862629ac_771a_47b2_8337_4e6843c1be90);
 windows_core::imp::interface_hierarchy!(ISAXDeclHandler, windows_core::IUnknown);
@@ -2261,6 +3075,44 @@ impl ISAXDeclHandler_Vtbl {
         iid == &<ISAXDeclHandler as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISAXDeclHandler_Impl> windows_core::imp::TearOffVtable<Identity> for ISAXDeclHandler_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn elementDecl<Identity: windows_core::IUnknownImpl + ISAXDeclHandler_Impl>(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, cchname: i32, pwchmodel: windows_core::PCWSTR, cchmodel: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXDeclHandler_Impl::elementDecl(this, core::mem::transmute(&pwchname), core::mem::transmute_copy(&cchname), core::mem::transmute(&pwchmodel), core::mem::transmute_copy(&cchmodel)).into()
+            }
+        }
+        unsafe extern "system" fn attributeDecl<Identity: windows_core::IUnknownImpl + ISAXDeclHandler_Impl>(this: *mut core::ffi::c_void, pwchelementname: windows_core::PCWSTR, cchelementname: i32, pwchattributename: windows_core::PCWSTR, cchattributename: i32, pwchtype: windows_core::PCWSTR, cchtype: i32, pwchvaluedefault: windows_core::PCWSTR, cchvaluedefault: i32, pwchvalue: windows_core::PCWSTR, cchvalue: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXDeclHandler_Impl::attributeDecl(this, core::mem::transmute(&pwchelementname), core::mem::transmute_copy(&cchelementname), core::mem::transmute(&pwchattributename), core::mem::transmute_copy(&cchattributename), core::mem::transmute(&pwchtype), core::mem::transmute_copy(&cchtype), core::mem::transmute(&pwchvaluedefault), core::mem::transmute_copy(&cchvaluedefault), core::mem::transmute(&pwchvalue), core::mem::transmute_copy(&cchvalue)).into()
+            }
+        }
+        unsafe extern "system" fn internalEntityDecl<Identity: windows_core::IUnknownImpl + ISAXDeclHandler_Impl>(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, cchname: i32, pwchvalue: windows_core::PCWSTR, cchvalue: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXDeclHandler_Impl::internalEntityDecl(this, core::mem::transmute(&pwchname), core::mem::transmute_copy(&cchname), core::mem::transmute(&pwchvalue), core::mem::transmute_copy(&cchvalue)).into()
+            }
+        }
+        unsafe extern "system" fn externalEntityDecl<Identity: windows_core::IUnknownImpl + ISAXDeclHandler_Impl>(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, cchname: i32, pwchpublicid: windows_core::PCWSTR, cchpublicid: i32, pwchsystemid: windows_core::PCWSTR, cchsystemid: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXDeclHandler_Impl::externalEntityDecl(this, core::mem::transmute(&pwchname), core::mem::transmute_copy(&cchname), core::mem::transmute(&pwchpublicid), core::mem::transmute_copy(&cchpublicid), core::mem::transmute(&pwchsystemid), core::mem::transmute_copy(&cchsystemid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            elementDecl: elementDecl::<Identity>,
+            attributeDecl: attributeDecl::<Identity>,
+            internalEntityDecl: internalEntityDecl::<Identity>,
+            externalEntityDecl: externalEntityDecl::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISAXDeclHandler {}
 windows_core::imp::define_interface!(ISAXEntityResolver, ISAXEntityResolver_Vtbl, 0x99bca7bd_e8c4_4d5f_a0cf_6d907901ff07);
 windows_core::imp::interface_hierarchy!(ISAXEntityResolver, windows_core::IUnknown);
@@ -2312,6 +3164,27 @@ impl ISAXEntityResolver_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISAXEntityResolver_Impl> windows_core::imp::TearOffVtable<Identity> for ISAXEntityResolver_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn resolveEntity<Identity: windows_core::IUnknownImpl + ISAXEntityResolver_Impl>(this: *mut core::ffi::c_void, pwchpublicid: windows_core::PCWSTR, pwchsystemid: windows_core::PCWSTR, pvarinput: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISAXEntityResolver_Impl::resolveEntity(this, core::mem::transmute(&pwchpublicid), core::mem::transmute(&pwchsystemid)) {
+                    Ok(ok__) => {
+                        pvarinput.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, resolveEntity: resolveEntity::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISAXEntityResolver {}
 windows_core::imp::define_interface!(ISAXErrorHandler, ISAXErrorHandler_Vtbl, 0xa60511c4_ccf5_479e_98a3_dc8dc545b7d0);
 windows_core::imp::interface_hierarchy!(ISAXErrorHandler, windows_core::IUnknown);
@@ -2382,6 +3255,37 @@ impl ISAXErrorHandler_Vtbl {
         iid == &<ISAXErrorHandler as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISAXErrorHandler_Impl> windows_core::imp::TearOffVtable<Identity> for ISAXErrorHandler_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn error<Identity: windows_core::IUnknownImpl + ISAXErrorHandler_Impl>(this: *mut core::ffi::c_void, plocator: *mut core::ffi::c_void, pwcherrormessage: windows_core::PCWSTR, hrerrorcode: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXErrorHandler_Impl::error(this, core::mem::transmute_copy(&plocator), core::mem::transmute(&pwcherrormessage), core::mem::transmute_copy(&hrerrorcode)).into()
+            }
+        }
+        unsafe extern "system" fn fatalError<Identity: windows_core::IUnknownImpl + ISAXErrorHandler_Impl>(this: *mut core::ffi::c_void, plocator: *mut core::ffi::c_void, pwcherrormessage: windows_core::PCWSTR, hrerrorcode: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXErrorHandler_Impl::fatalError(this, core::mem::transmute_copy(&plocator), core::mem::transmute(&pwcherrormessage), core::mem::transmute_copy(&hrerrorcode)).into()
+            }
+        }
+        unsafe extern "system" fn ignorableWarning<Identity: windows_core::IUnknownImpl + ISAXErrorHandler_Impl>(this: *mut core::ffi::c_void, plocator: *mut core::ffi::c_void, pwcherrormessage: windows_core::PCWSTR, hrerrorcode: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXErrorHandler_Impl::ignorableWarning(this, core::mem::transmute_copy(&plocator), core::mem::transmute(&pwcherrormessage), core::mem::transmute_copy(&hrerrorcode)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            error: error::<Identity>,
+            fatalError: fatalError::<Identity>,
+            ignorableWarning: ignorableWarning::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISAXErrorHandler {}
 windows_core::imp::define_interface!(ISAXLexicalHandler, ISAXLexicalHandler_Vtbl, 0x7f85d5f5_47a8_4497_bda5_84ba04819ea6);
 windows_core::imp::interface_hierarchy!(ISAXLexicalHandler, windows_core::IUnknown);
@@ -2502,6 +3406,65 @@ impl ISAXLexicalHandler_Vtbl {
         iid == &<ISAXLexicalHandler as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISAXLexicalHandler_Impl> windows_core::imp::TearOffVtable<Identity> for ISAXLexicalHandler_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn startDTD<Identity: windows_core::IUnknownImpl + ISAXLexicalHandler_Impl>(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, cchname: i32, pwchpublicid: windows_core::PCWSTR, cchpublicid: i32, pwchsystemid: windows_core::PCWSTR, cchsystemid: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXLexicalHandler_Impl::startDTD(this, core::mem::transmute(&pwchname), core::mem::transmute_copy(&cchname), core::mem::transmute(&pwchpublicid), core::mem::transmute_copy(&cchpublicid), core::mem::transmute(&pwchsystemid), core::mem::transmute_copy(&cchsystemid)).into()
+            }
+        }
+        unsafe extern "system" fn endDTD<Identity: windows_core::IUnknownImpl + ISAXLexicalHandler_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXLexicalHandler_Impl::endDTD(this).into()
+            }
+        }
+        unsafe extern "system" fn startEntity<Identity: windows_core::IUnknownImpl + ISAXLexicalHandler_Impl>(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, cchname: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXLexicalHandler_Impl::startEntity(this, core::mem::transmute(&pwchname), core::mem::transmute_copy(&cchname)).into()
+            }
+        }
+        unsafe extern "system" fn endEntity<Identity: windows_core::IUnknownImpl + ISAXLexicalHandler_Impl>(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, cchname: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXLexicalHandler_Impl::endEntity(this, core::mem::transmute(&pwchname), core::mem::transmute_copy(&cchname)).into()
+            }
+        }
+        unsafe extern "system" fn startCDATA<Identity: windows_core::IUnknownImpl + ISAXLexicalHandler_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXLexicalHandler_Impl::startCDATA(this).into()
+            }
+        }
+        unsafe extern "system" fn endCDATA<Identity: windows_core::IUnknownImpl + ISAXLexicalHandler_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXLexicalHandler_Impl::endCDATA(this).into()
+            }
+        }
+        unsafe extern "system" fn comment<Identity: windows_core::IUnknownImpl + ISAXLexicalHandler_Impl>(this: *mut core::ffi::c_void, pwchchars: windows_core::PCWSTR, cchchars: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXLexicalHandler_Impl::comment(this, core::mem::transmute(&pwchchars), core::mem::transmute_copy(&cchchars)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            startDTD: startDTD::<Identity>,
+            endDTD: endDTD::<Identity>,
+            startEntity: startEntity::<Identity>,
+            endEntity: endEntity::<Identity>,
+            startCDATA: startCDATA::<Identity>,
+            endCDATA: endCDATA::<Identity>,
+            comment: comment::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISAXLexicalHandler {}
 windows_core::imp::define_interface!(ISAXLocator, ISAXLocator_Vtbl, 0x9b7e472a_0de4_4640_bff3_84d38a051c31);
 windows_core::imp::interface_hierarchy!(ISAXLocator, windows_core::IUnknown);
@@ -2608,32 +3571,94 @@ impl ISAXLocator_Vtbl {
         iid == &<ISAXLocator as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for ISAXLocator {}
-windows_core::imp::define_interface!(ISAXXMLFilter, ISAXXMLFilter_Vtbl, 0x70409222_ca09_4475_acb8_40312fe8d145);
-impl core::ops::Deref for ISAXXMLFilter {
-    type Target = ISAXXMLReader;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(ISAXXMLFilter, windows_core::IUnknown, ISAXXMLReader);
-impl ISAXXMLFilter {
-    pub unsafe fn getParent(&self) -> windows_core::Result<ISAXXMLReader> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).getParent)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+impl<Identity: windows_core::IUnknownImpl + ISAXLocator_Impl> windows_core::imp::TearOffVtable<Identity> for ISAXLocator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn getColumnNumber<Identity: windows_core::IUnknownImpl + ISAXLocator_Impl>(this: *mut core::ffi::c_void, pncolumn: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISAXLocator_Impl::getColumnNumber(this) {
+                    Ok(ok__) => {
+                        pncolumn.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn putParent<P0>(&self, preader: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<ISAXXMLReader>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).putParent)(windows_core::Interface::as_raw(self), preader.param().abi()).ok() }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct ISAXXMLFilter_Vtbl {
+        unsafe extern "system" fn getLineNumber<Identity: windows_core::IUnknownImpl + ISAXLocator_Impl>(this: *mut core::ffi::c_void, pnline: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISAXLocator_Impl::getLineNumber(this) {
+                    Ok(ok__) => {
+                        pnline.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getPublicId<Identity: windows_core::IUnknownImpl + ISAXLocator_Impl>(this: *mut core::ffi::c_void, ppwchpublicid: *mut *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISAXLocator_Impl::getPublicId(this) {
+                    Ok(ok__) => {
+                        ppwchpublicid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getSystemId<Identity: windows_core::IUnknownImpl + ISAXLocator_Impl>(this: *mut core::ffi::c_void, ppwchsystemid: *mut *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISAXLocator_Impl::getSystemId(this) {
+                    Ok(ok__) => {
+                        ppwchsystemid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            getColumnNumber: getColumnNumber::<Identity>,
+            getLineNumber: getLineNumber::<Identity>,
+            getPublicId: getPublicId::<Identity>,
+            getSystemId: getSystemId::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for ISAXLocator {}
+windows_core::imp::define_interface!(ISAXXMLFilter, ISAXXMLFilter_Vtbl, 0x70409222_ca09_4475_acb8_40312fe8d145);
+impl core::ops::Deref for ISAXXMLFilter {
+    type Target = ISAXXMLReader;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(ISAXXMLFilter, windows_core::IUnknown, ISAXXMLReader);
+impl ISAXXMLFilter {
+    pub unsafe fn getParent(&self) -> windows_core::Result<ISAXXMLReader> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).getParent)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn putParent<P0>(&self, preader: P0) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<ISAXXMLReader>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).putParent)(windows_core::Interface::as_raw(self), preader.param().abi()).ok() }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct ISAXXMLFilter_Vtbl {
     pub base__: ISAXXMLReader_Vtbl,
     pub getParent: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
     pub putParent: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
@@ -2671,6 +3696,37 @@ impl ISAXXMLFilter_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISAXXMLFilter_Impl> windows_core::imp::TearOffVtable<Identity> for ISAXXMLFilter_Vtbl
+where
+    ISAXXMLReader_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn getParent<Identity: windows_core::IUnknownImpl + ISAXXMLFilter_Impl>(this: *mut core::ffi::c_void, ppreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISAXXMLFilter_Impl::getParent(this) {
+                    Ok(ok__) => {
+                        ppreader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putParent<Identity: windows_core::IUnknownImpl + ISAXXMLFilter_Impl>(this: *mut core::ffi::c_void, preader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXXMLFilter_Impl::putParent(this, core::mem::transmute_copy(&preader)).into()
+            }
+        }
+        Self {
+            base__: <ISAXXMLReader_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            getParent: getParent::<Identity>,
+            putParent: putParent::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISAXXMLFilter {}
 windows_core::imp::define_interface!(ISAXXMLReader, ISAXXMLReader_Vtbl, 0xa4f96ed0_f829_476e_81c0_cdc7bd2a0802);
 windows_core::imp::interface_hierarchy!(ISAXXMLReader, windows_core::IUnknown);
@@ -3029,6 +4085,191 @@ impl ISAXXMLReader_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISAXXMLReader_Impl> windows_core::imp::TearOffVtable<Identity> for ISAXXMLReader_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn getFeature<Identity: windows_core::IUnknownImpl + ISAXXMLReader_Impl>(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, pvfvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISAXXMLReader_Impl::getFeature(this, core::mem::transmute(&pwchname)) {
+                    Ok(ok__) => {
+                        pvfvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putFeature<Identity: windows_core::IUnknownImpl + ISAXXMLReader_Impl>(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, vfvalue: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXXMLReader_Impl::putFeature(this, core::mem::transmute(&pwchname), core::mem::transmute_copy(&vfvalue)).into()
+            }
+        }
+        unsafe extern "system" fn getProperty<Identity: windows_core::IUnknownImpl + ISAXXMLReader_Impl>(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, pvarvalue: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISAXXMLReader_Impl::getProperty(this, core::mem::transmute(&pwchname)) {
+                    Ok(ok__) => {
+                        pvarvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putProperty<Identity: windows_core::IUnknownImpl + ISAXXMLReader_Impl>(this: *mut core::ffi::c_void, pwchname: windows_core::PCWSTR, varvalue: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXXMLReader_Impl::putProperty(this, core::mem::transmute(&pwchname), core::mem::transmute(&varvalue)).into()
+            }
+        }
+        unsafe extern "system" fn getEntityResolver<Identity: windows_core::IUnknownImpl + ISAXXMLReader_Impl>(this: *mut core::ffi::c_void, ppresolver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISAXXMLReader_Impl::getEntityResolver(this) {
+                    Ok(ok__) => {
+                        ppresolver.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putEntityResolver<Identity: windows_core::IUnknownImpl + ISAXXMLReader_Impl>(this: *mut core::ffi::c_void, presolver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXXMLReader_Impl::putEntityResolver(this, core::mem::transmute_copy(&presolver)).into()
+            }
+        }
+        unsafe extern "system" fn getContentHandler<Identity: windows_core::IUnknownImpl + ISAXXMLReader_Impl>(this: *mut core::ffi::c_void, pphandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISAXXMLReader_Impl::getContentHandler(this) {
+                    Ok(ok__) => {
+                        pphandler.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putContentHandler<Identity: windows_core::IUnknownImpl + ISAXXMLReader_Impl>(this: *mut core::ffi::c_void, phandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXXMLReader_Impl::putContentHandler(this, core::mem::transmute_copy(&phandler)).into()
+            }
+        }
+        unsafe extern "system" fn getDTDHandler<Identity: windows_core::IUnknownImpl + ISAXXMLReader_Impl>(this: *mut core::ffi::c_void, pphandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISAXXMLReader_Impl::getDTDHandler(this) {
+                    Ok(ok__) => {
+                        pphandler.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putDTDHandler<Identity: windows_core::IUnknownImpl + ISAXXMLReader_Impl>(this: *mut core::ffi::c_void, phandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXXMLReader_Impl::putDTDHandler(this, core::mem::transmute_copy(&phandler)).into()
+            }
+        }
+        unsafe extern "system" fn getErrorHandler<Identity: windows_core::IUnknownImpl + ISAXXMLReader_Impl>(this: *mut core::ffi::c_void, pphandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISAXXMLReader_Impl::getErrorHandler(this) {
+                    Ok(ok__) => {
+                        pphandler.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putErrorHandler<Identity: windows_core::IUnknownImpl + ISAXXMLReader_Impl>(this: *mut core::ffi::c_void, phandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXXMLReader_Impl::putErrorHandler(this, core::mem::transmute_copy(&phandler)).into()
+            }
+        }
+        unsafe extern "system" fn getBaseURL<Identity: windows_core::IUnknownImpl + ISAXXMLReader_Impl>(this: *mut core::ffi::c_void, ppwchbaseurl: *mut *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISAXXMLReader_Impl::getBaseURL(this) {
+                    Ok(ok__) => {
+                        ppwchbaseurl.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putBaseURL<Identity: windows_core::IUnknownImpl + ISAXXMLReader_Impl>(this: *mut core::ffi::c_void, pwchbaseurl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXXMLReader_Impl::putBaseURL(this, core::mem::transmute(&pwchbaseurl)).into()
+            }
+        }
+        unsafe extern "system" fn getSecureBaseURL<Identity: windows_core::IUnknownImpl + ISAXXMLReader_Impl>(this: *mut core::ffi::c_void, ppwchsecurebaseurl: *mut *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISAXXMLReader_Impl::getSecureBaseURL(this) {
+                    Ok(ok__) => {
+                        ppwchsecurebaseurl.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putSecureBaseURL<Identity: windows_core::IUnknownImpl + ISAXXMLReader_Impl>(this: *mut core::ffi::c_void, pwchsecurebaseurl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXXMLReader_Impl::putSecureBaseURL(this, core::mem::transmute(&pwchsecurebaseurl)).into()
+            }
+        }
+        unsafe extern "system" fn parse<Identity: windows_core::IUnknownImpl + ISAXXMLReader_Impl>(this: *mut core::ffi::c_void, varinput: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXXMLReader_Impl::parse(this, core::mem::transmute(&varinput)).into()
+            }
+        }
+        unsafe extern "system" fn parseURL<Identity: windows_core::IUnknownImpl + ISAXXMLReader_Impl>(this: *mut core::ffi::c_void, pwchurl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISAXXMLReader_Impl::parseURL(this, core::mem::transmute(&pwchurl)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            getFeature: getFeature::<Identity>,
+            putFeature: putFeature::<Identity>,
+            getProperty: getProperty::<Identity>,
+            putProperty: putProperty::<Identity>,
+            getEntityResolver: getEntityResolver::<Identity>,
+            putEntityResolver: putEntityResolver::<Identity>,
+            getContentHandler: getContentHandler::<Identity>,
+            putContentHandler: putContentHandler::<Identity>,
+            getDTDHandler: getDTDHandler::<Identity>,
+            putDTDHandler: putDTDHandler::<Identity>,
+            getErrorHandler: getErrorHandler::<Identity>,
+            putErrorHandler: putErrorHandler::<Identity>,
+            getBaseURL: getBaseURL::<Identity>,
+            putBaseURL: putBaseURL::<Identity>,
+            getSecureBaseURL: getSecureBaseURL::<Identity>,
+            putSecureBaseURL: putSecureBaseURL::<Identity>,
+            parse: parse::<Identity>,
+            parseURL: parseURL::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISAXXMLReader {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISchema, ISchema_Vtbl, 0x50ea08b4_dd1b_4664_9a50_c2f40f4bd79a);
@@ -3254,75 +4495,203 @@ impl ISchema_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for ISchema {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(ISchemaAny, ISchemaAny_Vtbl, 0x50ea08bc_dd1b_4664_9a50_c2f40f4bd79a);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for ISchemaAny {
-    type Target = ISchemaParticle;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(ISchemaAny, windows_core::IUnknown, super::super::super::System::Com::IDispatch, ISchemaItem, ISchemaParticle);
-#[cfg(feature = "Win32_System_Com")]
-impl ISchemaAny {
-    pub unsafe fn namespaces(&self) -> windows_core::Result<ISchemaStringCollection> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).namespaces)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn processContents(&self) -> windows_core::Result<SCHEMAPROCESSCONTENTS> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).processContents)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
-        }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-#[repr(C)]
-#[doc(hidden)]
-pub struct ISchemaAny_Vtbl {
-    pub base__: ISchemaParticle_Vtbl,
-    pub namespaces: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub processContents: unsafe extern "system" fn(*mut core::ffi::c_void, *mut SCHEMAPROCESSCONTENTS) -> windows_core::HRESULT,
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-pub trait ISchemaAny_Impl: ISchemaParticle_Impl {
-    fn namespaces(&self) -> windows_core::Result<ISchemaStringCollection>;
-    fn processContents(&self) -> windows_core::Result<SCHEMAPROCESSCONTENTS>;
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl ISchemaAny_Vtbl {
-    pub const fn new<Identity: ISchemaAny_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn namespaces<Identity: ISchemaAny_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, namespaces: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ISchemaAny_Impl::namespaces(this) {
+impl<Identity: windows_core::IUnknownImpl + ISchema_Impl> windows_core::imp::TearOffVtable<Identity> for ISchema_Vtbl
+where
+    ISchemaItem_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn targetNamespace<Identity: windows_core::IUnknownImpl + ISchema_Impl>(this: *mut core::ffi::c_void, targetnamespace: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchema_Impl::targetNamespace(this) {
                     Ok(ok__) => {
-                        namespaces.write(core::mem::transmute(ok__));
+                        targetnamespace.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn processContents<Identity: ISchemaAny_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, processcontents: *mut SCHEMAPROCESSCONTENTS) -> windows_core::HRESULT {
+        unsafe extern "system" fn version<Identity: windows_core::IUnknownImpl + ISchema_Impl>(this: *mut core::ffi::c_void, version: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match ISchemaAny_Impl::processContents(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchema_Impl::version(this) {
                     Ok(ok__) => {
-                        processcontents.write(core::mem::transmute(ok__));
+                        version.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        Self {
-            base__: ISchemaParticle_Vtbl::new::<Identity, OFFSET>(),
+        unsafe extern "system" fn types<Identity: windows_core::IUnknownImpl + ISchema_Impl>(this: *mut core::ffi::c_void, types: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchema_Impl::types(this) {
+                    Ok(ok__) => {
+                        types.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn elements<Identity: windows_core::IUnknownImpl + ISchema_Impl>(this: *mut core::ffi::c_void, elements: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchema_Impl::elements(this) {
+                    Ok(ok__) => {
+                        elements.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn attributes<Identity: windows_core::IUnknownImpl + ISchema_Impl>(this: *mut core::ffi::c_void, attributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchema_Impl::attributes(this) {
+                    Ok(ok__) => {
+                        attributes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn attributeGroups<Identity: windows_core::IUnknownImpl + ISchema_Impl>(this: *mut core::ffi::c_void, attributegroups: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchema_Impl::attributeGroups(this) {
+                    Ok(ok__) => {
+                        attributegroups.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn modelGroups<Identity: windows_core::IUnknownImpl + ISchema_Impl>(this: *mut core::ffi::c_void, modelgroups: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchema_Impl::modelGroups(this) {
+                    Ok(ok__) => {
+                        modelgroups.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn notations<Identity: windows_core::IUnknownImpl + ISchema_Impl>(this: *mut core::ffi::c_void, notations: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchema_Impl::notations(this) {
+                    Ok(ok__) => {
+                        notations.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn schemaLocations<Identity: windows_core::IUnknownImpl + ISchema_Impl>(this: *mut core::ffi::c_void, schemalocations: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchema_Impl::schemaLocations(this) {
+                    Ok(ok__) => {
+                        schemalocations.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ISchemaItem_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            targetNamespace: targetNamespace::<Identity>,
+            version: version::<Identity>,
+            types: types::<Identity>,
+            elements: elements::<Identity>,
+            attributes: attributes::<Identity>,
+            attributeGroups: attributeGroups::<Identity>,
+            modelGroups: modelGroups::<Identity>,
+            notations: notations::<Identity>,
+            schemaLocations: schemaLocations::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for ISchema {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(ISchemaAny, ISchemaAny_Vtbl, 0x50ea08bc_dd1b_4664_9a50_c2f40f4bd79a);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for ISchemaAny {
+    type Target = ISchemaParticle;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(ISchemaAny, windows_core::IUnknown, super::super::super::System::Com::IDispatch, ISchemaItem, ISchemaParticle);
+#[cfg(feature = "Win32_System_Com")]
+impl ISchemaAny {
+    pub unsafe fn namespaces(&self) -> windows_core::Result<ISchemaStringCollection> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).namespaces)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn processContents(&self) -> windows_core::Result<SCHEMAPROCESSCONTENTS> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).processContents)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+#[repr(C)]
+#[doc(hidden)]
+pub struct ISchemaAny_Vtbl {
+    pub base__: ISchemaParticle_Vtbl,
+    pub namespaces: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub processContents: unsafe extern "system" fn(*mut core::ffi::c_void, *mut SCHEMAPROCESSCONTENTS) -> windows_core::HRESULT,
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+pub trait ISchemaAny_Impl: ISchemaParticle_Impl {
+    fn namespaces(&self) -> windows_core::Result<ISchemaStringCollection>;
+    fn processContents(&self) -> windows_core::Result<SCHEMAPROCESSCONTENTS>;
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl ISchemaAny_Vtbl {
+    pub const fn new<Identity: ISchemaAny_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn namespaces<Identity: ISchemaAny_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, namespaces: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ISchemaAny_Impl::namespaces(this) {
+                    Ok(ok__) => {
+                        namespaces.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn processContents<Identity: ISchemaAny_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, processcontents: *mut SCHEMAPROCESSCONTENTS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ISchemaAny_Impl::processContents(this) {
+                    Ok(ok__) => {
+                        processcontents.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: ISchemaParticle_Vtbl::new::<Identity, OFFSET>(),
             namespaces: namespaces::<Identity, OFFSET>,
             processContents: processContents::<Identity, OFFSET>,
         }
@@ -3332,6 +4701,43 @@ impl ISchemaAny_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISchemaAny_Impl> windows_core::imp::TearOffVtable<Identity> for ISchemaAny_Vtbl
+where
+    ISchemaParticle_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn namespaces<Identity: windows_core::IUnknownImpl + ISchemaAny_Impl>(this: *mut core::ffi::c_void, namespaces: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaAny_Impl::namespaces(this) {
+                    Ok(ok__) => {
+                        namespaces.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn processContents<Identity: windows_core::IUnknownImpl + ISchemaAny_Impl>(this: *mut core::ffi::c_void, processcontents: *mut SCHEMAPROCESSCONTENTS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaAny_Impl::processContents(this) {
+                    Ok(ok__) => {
+                        processcontents.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ISchemaParticle_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            namespaces: namespaces::<Identity>,
+            processContents: processContents::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISchemaAny {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISchemaAttribute, ISchemaAttribute_Vtbl, 0x50ea08b6_dd1b_4664_9a50_c2f40f4bd79a);
@@ -3494,6 +4900,95 @@ impl ISchemaAttribute_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISchemaAttribute_Impl> windows_core::imp::TearOffVtable<Identity> for ISchemaAttribute_Vtbl
+where
+    ISchemaItem_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn r#type<Identity: windows_core::IUnknownImpl + ISchemaAttribute_Impl>(this: *mut core::ffi::c_void, r#type: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaAttribute_Impl::r#type(this) {
+                    Ok(ok__) => {
+                        r#type.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn scope<Identity: windows_core::IUnknownImpl + ISchemaAttribute_Impl>(this: *mut core::ffi::c_void, scope: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaAttribute_Impl::scope(this) {
+                    Ok(ok__) => {
+                        scope.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn defaultValue<Identity: windows_core::IUnknownImpl + ISchemaAttribute_Impl>(this: *mut core::ffi::c_void, defaultvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaAttribute_Impl::defaultValue(this) {
+                    Ok(ok__) => {
+                        defaultvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn fixedValue<Identity: windows_core::IUnknownImpl + ISchemaAttribute_Impl>(this: *mut core::ffi::c_void, fixedvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaAttribute_Impl::fixedValue(this) {
+                    Ok(ok__) => {
+                        fixedvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn r#use<Identity: windows_core::IUnknownImpl + ISchemaAttribute_Impl>(this: *mut core::ffi::c_void, r#use: *mut SCHEMAUSE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaAttribute_Impl::r#use(this) {
+                    Ok(ok__) => {
+                        r#use.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn isReference<Identity: windows_core::IUnknownImpl + ISchemaAttribute_Impl>(this: *mut core::ffi::c_void, reference: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaAttribute_Impl::isReference(this) {
+                    Ok(ok__) => {
+                        reference.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ISchemaItem_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            r#type: r#type::<Identity>,
+            scope: scope::<Identity>,
+            defaultValue: defaultValue::<Identity>,
+            fixedValue: fixedValue::<Identity>,
+            r#use: r#use::<Identity>,
+            isReference: isReference::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISchemaAttribute {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISchemaAttributeGroup, ISchemaAttributeGroup_Vtbl, 0x50ea08ba_dd1b_4664_9a50_c2f40f4bd79a);
@@ -3568,6 +5063,43 @@ impl ISchemaAttributeGroup_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISchemaAttributeGroup_Impl> windows_core::imp::TearOffVtable<Identity> for ISchemaAttributeGroup_Vtbl
+where
+    ISchemaItem_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn anyAttribute<Identity: windows_core::IUnknownImpl + ISchemaAttributeGroup_Impl>(this: *mut core::ffi::c_void, anyattribute: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaAttributeGroup_Impl::anyAttribute(this) {
+                    Ok(ok__) => {
+                        anyattribute.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn attributes<Identity: windows_core::IUnknownImpl + ISchemaAttributeGroup_Impl>(this: *mut core::ffi::c_void, attributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaAttributeGroup_Impl::attributes(this) {
+                    Ok(ok__) => {
+                        attributes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ISchemaItem_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            anyAttribute: anyAttribute::<Identity>,
+            attributes: attributes::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISchemaAttributeGroup {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISchemaComplexType, ISchemaComplexType_Vtbl, 0x50ea08b9_dd1b_4664_9a50_c2f40f4bd79a);
@@ -3730,11 +5262,100 @@ impl ISchemaComplexType_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for ISchemaComplexType {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(ISchemaElement, ISchemaElement_Vtbl, 0x50ea08b7_dd1b_4664_9a50_c2f40f4bd79a);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for ISchemaElement {
+impl<Identity: windows_core::IUnknownImpl + ISchemaComplexType_Impl> windows_core::imp::TearOffVtable<Identity> for ISchemaComplexType_Vtbl
+where
+    ISchemaType_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn isAbstract<Identity: windows_core::IUnknownImpl + ISchemaComplexType_Impl>(this: *mut core::ffi::c_void, r#abstract: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaComplexType_Impl::isAbstract(this) {
+                    Ok(ok__) => {
+                        r#abstract.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn anyAttribute<Identity: windows_core::IUnknownImpl + ISchemaComplexType_Impl>(this: *mut core::ffi::c_void, anyattribute: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaComplexType_Impl::anyAttribute(this) {
+                    Ok(ok__) => {
+                        anyattribute.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn attributes<Identity: windows_core::IUnknownImpl + ISchemaComplexType_Impl>(this: *mut core::ffi::c_void, attributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaComplexType_Impl::attributes(this) {
+                    Ok(ok__) => {
+                        attributes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn contentType<Identity: windows_core::IUnknownImpl + ISchemaComplexType_Impl>(this: *mut core::ffi::c_void, contenttype: *mut SCHEMACONTENTTYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaComplexType_Impl::contentType(this) {
+                    Ok(ok__) => {
+                        contenttype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn contentModel<Identity: windows_core::IUnknownImpl + ISchemaComplexType_Impl>(this: *mut core::ffi::c_void, contentmodel: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaComplexType_Impl::contentModel(this) {
+                    Ok(ok__) => {
+                        contentmodel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn prohibitedSubstitutions<Identity: windows_core::IUnknownImpl + ISchemaComplexType_Impl>(this: *mut core::ffi::c_void, prohibited: *mut SCHEMADERIVATIONMETHOD) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaComplexType_Impl::prohibitedSubstitutions(this) {
+                    Ok(ok__) => {
+                        prohibited.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ISchemaType_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            isAbstract: isAbstract::<Identity>,
+            anyAttribute: anyAttribute::<Identity>,
+            attributes: attributes::<Identity>,
+            contentType: contentType::<Identity>,
+            contentModel: contentModel::<Identity>,
+            prohibitedSubstitutions: prohibitedSubstitutions::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for ISchemaComplexType {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(ISchemaElement, ISchemaElement_Vtbl, 0x50ea08b7_dd1b_4664_9a50_c2f40f4bd79a);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for ISchemaElement {
     type Target = ISchemaParticle;
     fn deref(&self) -> &Self::Target {
         unsafe { core::mem::transmute(self) }
@@ -3997,6 +5618,160 @@ impl ISchemaElement_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISchemaElement_Impl> windows_core::imp::TearOffVtable<Identity> for ISchemaElement_Vtbl
+where
+    ISchemaParticle_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn r#type<Identity: windows_core::IUnknownImpl + ISchemaElement_Impl>(this: *mut core::ffi::c_void, r#type: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaElement_Impl::r#type(this) {
+                    Ok(ok__) => {
+                        r#type.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn scope<Identity: windows_core::IUnknownImpl + ISchemaElement_Impl>(this: *mut core::ffi::c_void, scope: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaElement_Impl::scope(this) {
+                    Ok(ok__) => {
+                        scope.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn defaultValue<Identity: windows_core::IUnknownImpl + ISchemaElement_Impl>(this: *mut core::ffi::c_void, defaultvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaElement_Impl::defaultValue(this) {
+                    Ok(ok__) => {
+                        defaultvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn fixedValue<Identity: windows_core::IUnknownImpl + ISchemaElement_Impl>(this: *mut core::ffi::c_void, fixedvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaElement_Impl::fixedValue(this) {
+                    Ok(ok__) => {
+                        fixedvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn isNillable<Identity: windows_core::IUnknownImpl + ISchemaElement_Impl>(this: *mut core::ffi::c_void, nillable: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaElement_Impl::isNillable(this) {
+                    Ok(ok__) => {
+                        nillable.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn identityConstraints<Identity: windows_core::IUnknownImpl + ISchemaElement_Impl>(this: *mut core::ffi::c_void, constraints: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaElement_Impl::identityConstraints(this) {
+                    Ok(ok__) => {
+                        constraints.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn substitutionGroup<Identity: windows_core::IUnknownImpl + ISchemaElement_Impl>(this: *mut core::ffi::c_void, element: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaElement_Impl::substitutionGroup(this) {
+                    Ok(ok__) => {
+                        element.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn substitutionGroupExclusions<Identity: windows_core::IUnknownImpl + ISchemaElement_Impl>(this: *mut core::ffi::c_void, exclusions: *mut SCHEMADERIVATIONMETHOD) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaElement_Impl::substitutionGroupExclusions(this) {
+                    Ok(ok__) => {
+                        exclusions.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn disallowedSubstitutions<Identity: windows_core::IUnknownImpl + ISchemaElement_Impl>(this: *mut core::ffi::c_void, disallowed: *mut SCHEMADERIVATIONMETHOD) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaElement_Impl::disallowedSubstitutions(this) {
+                    Ok(ok__) => {
+                        disallowed.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn isAbstract<Identity: windows_core::IUnknownImpl + ISchemaElement_Impl>(this: *mut core::ffi::c_void, r#abstract: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaElement_Impl::isAbstract(this) {
+                    Ok(ok__) => {
+                        r#abstract.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn isReference<Identity: windows_core::IUnknownImpl + ISchemaElement_Impl>(this: *mut core::ffi::c_void, reference: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaElement_Impl::isReference(this) {
+                    Ok(ok__) => {
+                        reference.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ISchemaParticle_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            r#type: r#type::<Identity>,
+            scope: scope::<Identity>,
+            defaultValue: defaultValue::<Identity>,
+            fixedValue: fixedValue::<Identity>,
+            isNillable: isNillable::<Identity>,
+            identityConstraints: identityConstraints::<Identity>,
+            substitutionGroup: substitutionGroup::<Identity>,
+            substitutionGroupExclusions: substitutionGroupExclusions::<Identity>,
+            disallowedSubstitutions: disallowedSubstitutions::<Identity>,
+            isAbstract: isAbstract::<Identity>,
+            isReference: isReference::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISchemaElement {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISchemaIdentityConstraint, ISchemaIdentityConstraint_Vtbl, 0x50ea08bd_dd1b_4664_9a50_c2f40f4bd79a);
@@ -4096,6 +5871,56 @@ impl ISchemaIdentityConstraint_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISchemaIdentityConstraint_Impl> windows_core::imp::TearOffVtable<Identity> for ISchemaIdentityConstraint_Vtbl
+where
+    ISchemaItem_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn selector<Identity: windows_core::IUnknownImpl + ISchemaIdentityConstraint_Impl>(this: *mut core::ffi::c_void, selector: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaIdentityConstraint_Impl::selector(this) {
+                    Ok(ok__) => {
+                        selector.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn fields<Identity: windows_core::IUnknownImpl + ISchemaIdentityConstraint_Impl>(this: *mut core::ffi::c_void, fields: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaIdentityConstraint_Impl::fields(this) {
+                    Ok(ok__) => {
+                        fields.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn referencedKey<Identity: windows_core::IUnknownImpl + ISchemaIdentityConstraint_Impl>(this: *mut core::ffi::c_void, key: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaIdentityConstraint_Impl::referencedKey(this) {
+                    Ok(ok__) => {
+                        key.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ISchemaItem_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            selector: selector::<Identity>,
+            fields: fields::<Identity>,
+            referencedKey: referencedKey::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISchemaIdentityConstraint {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISchemaItem, ISchemaItem_Vtbl, 0x50ea08b3_dd1b_4664_9a50_c2f40f4bd79a);
@@ -4282,34 +6107,136 @@ impl ISchemaItem_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for ISchemaItem {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(ISchemaItemCollection, ISchemaItemCollection_Vtbl, 0x50ea08b2_dd1b_4664_9a50_c2f40f4bd79a);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for ISchemaItemCollection {
-    type Target = super::super::super::System::Com::IDispatch;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(ISchemaItemCollection, windows_core::IUnknown, super::super::super::System::Com::IDispatch);
-#[cfg(feature = "Win32_System_Com")]
-impl ISchemaItemCollection {
-    pub unsafe fn get_item(&self, index: i32) -> windows_core::Result<ISchemaItem> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).get_item)(windows_core::Interface::as_raw(self), index, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+impl<Identity: windows_core::IUnknownImpl + ISchemaItem_Impl> windows_core::imp::TearOffVtable<Identity> for ISchemaItem_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn name<Identity: windows_core::IUnknownImpl + ISchemaItem_Impl>(this: *mut core::ffi::c_void, name: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaItem_Impl::name(this) {
+                    Ok(ok__) => {
+                        name.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn itemByName(&self, name: &windows_core::BSTR) -> windows_core::Result<ISchemaItem> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).itemByName)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(name), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn namespaceURI<Identity: windows_core::IUnknownImpl + ISchemaItem_Impl>(this: *mut core::ffi::c_void, namespaceuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaItem_Impl::namespaceURI(this) {
+                    Ok(ok__) => {
+                        namespaceuri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn itemByQName(&self, name: &windows_core::BSTR, namespaceuri: &windows_core::BSTR) -> windows_core::Result<ISchemaItem> {
-        unsafe {
+        unsafe extern "system" fn schema<Identity: windows_core::IUnknownImpl + ISchemaItem_Impl>(this: *mut core::ffi::c_void, schema: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaItem_Impl::schema(this) {
+                    Ok(ok__) => {
+                        schema.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn id<Identity: windows_core::IUnknownImpl + ISchemaItem_Impl>(this: *mut core::ffi::c_void, id: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaItem_Impl::id(this) {
+                    Ok(ok__) => {
+                        id.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn itemType<Identity: windows_core::IUnknownImpl + ISchemaItem_Impl>(this: *mut core::ffi::c_void, itemtype: *mut SOMITEMTYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaItem_Impl::itemType(this) {
+                    Ok(ok__) => {
+                        itemtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn unhandledAttributes<Identity: windows_core::IUnknownImpl + ISchemaItem_Impl>(this: *mut core::ffi::c_void, attributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaItem_Impl::unhandledAttributes(this) {
+                    Ok(ok__) => {
+                        attributes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn writeAnnotation<Identity: windows_core::IUnknownImpl + ISchemaItem_Impl>(this: *mut core::ffi::c_void, annotationsink: *mut core::ffi::c_void, iswritten: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaItem_Impl::writeAnnotation(this, core::mem::transmute_copy(&annotationsink)) {
+                    Ok(ok__) => {
+                        iswritten.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            name: name::<Identity>,
+            namespaceURI: namespaceURI::<Identity>,
+            schema: schema::<Identity>,
+            id: id::<Identity>,
+            itemType: itemType::<Identity>,
+            unhandledAttributes: unhandledAttributes::<Identity>,
+            writeAnnotation: writeAnnotation::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for ISchemaItem {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(ISchemaItemCollection, ISchemaItemCollection_Vtbl, 0x50ea08b2_dd1b_4664_9a50_c2f40f4bd79a);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for ISchemaItemCollection {
+    type Target = super::super::super::System::Com::IDispatch;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(ISchemaItemCollection, windows_core::IUnknown, super::super::super::System::Com::IDispatch);
+#[cfg(feature = "Win32_System_Com")]
+impl ISchemaItemCollection {
+    pub unsafe fn get_item(&self, index: i32) -> windows_core::Result<ISchemaItem> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).get_item)(windows_core::Interface::as_raw(self), index, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn itemByName(&self, name: &windows_core::BSTR) -> windows_core::Result<ISchemaItem> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).itemByName)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(name), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn itemByQName(&self, name: &windows_core::BSTR, namespaceuri: &windows_core::BSTR) -> windows_core::Result<ISchemaItem> {
+        unsafe {
             let mut result__ = core::mem::zeroed();
             (windows_core::Interface::vtable(self).itemByQName)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(name), core::mem::transmute_copy(namespaceuri), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
@@ -4423,6 +6350,82 @@ impl ISchemaItemCollection_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISchemaItemCollection_Impl> windows_core::imp::TearOffVtable<Identity> for ISchemaItemCollection_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn get_item<Identity: windows_core::IUnknownImpl + ISchemaItemCollection_Impl>(this: *mut core::ffi::c_void, index: i32, item: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaItemCollection_Impl::get_item(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        item.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn itemByName<Identity: windows_core::IUnknownImpl + ISchemaItemCollection_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, item: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaItemCollection_Impl::itemByName(this, core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        item.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn itemByQName<Identity: windows_core::IUnknownImpl + ISchemaItemCollection_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void, item: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaItemCollection_Impl::itemByQName(this, core::mem::transmute(&name), core::mem::transmute(&namespaceuri)) {
+                    Ok(ok__) => {
+                        item.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn length<Identity: windows_core::IUnknownImpl + ISchemaItemCollection_Impl>(this: *mut core::ffi::c_void, length: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaItemCollection_Impl::length(this) {
+                    Ok(ok__) => {
+                        length.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _newEnum<Identity: windows_core::IUnknownImpl + ISchemaItemCollection_Impl>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaItemCollection_Impl::_newEnum(this) {
+                    Ok(ok__) => {
+                        ppunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            get_item: get_item::<Identity>,
+            itemByName: itemByName::<Identity>,
+            itemByQName: itemByQName::<Identity>,
+            length: length::<Identity>,
+            _newEnum: _newEnum::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISchemaItemCollection {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISchemaModelGroup, ISchemaModelGroup_Vtbl, 0x50ea08bb_dd1b_4664_9a50_c2f40f4bd79a);
@@ -4477,6 +6480,27 @@ impl ISchemaModelGroup_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISchemaModelGroup_Impl> windows_core::imp::TearOffVtable<Identity> for ISchemaModelGroup_Vtbl
+where
+    ISchemaParticle_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn particles<Identity: windows_core::IUnknownImpl + ISchemaModelGroup_Impl>(this: *mut core::ffi::c_void, particles: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaModelGroup_Impl::particles(this) {
+                    Ok(ok__) => {
+                        particles.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <ISchemaParticle_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, particles: particles::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISchemaModelGroup {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISchemaNotation, ISchemaNotation_Vtbl, 0x50ea08be_dd1b_4664_9a50_c2f40f4bd79a);
@@ -4555,6 +6579,43 @@ impl ISchemaNotation_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISchemaNotation_Impl> windows_core::imp::TearOffVtable<Identity> for ISchemaNotation_Vtbl
+where
+    ISchemaItem_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn systemIdentifier<Identity: windows_core::IUnknownImpl + ISchemaNotation_Impl>(this: *mut core::ffi::c_void, uri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaNotation_Impl::systemIdentifier(this) {
+                    Ok(ok__) => {
+                        uri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn publicIdentifier<Identity: windows_core::IUnknownImpl + ISchemaNotation_Impl>(this: *mut core::ffi::c_void, uri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaNotation_Impl::publicIdentifier(this) {
+                    Ok(ok__) => {
+                        uri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ISchemaItem_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            systemIdentifier: systemIdentifier::<Identity>,
+            publicIdentifier: publicIdentifier::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISchemaNotation {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISchemaParticle, ISchemaParticle_Vtbl, 0x50ea08b5_dd1b_4664_9a50_c2f40f4bd79a);
@@ -4637,6 +6698,43 @@ impl ISchemaParticle_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISchemaParticle_Impl> windows_core::imp::TearOffVtable<Identity> for ISchemaParticle_Vtbl
+where
+    ISchemaItem_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn minOccurs<Identity: windows_core::IUnknownImpl + ISchemaParticle_Impl>(this: *mut core::ffi::c_void, minoccurs: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaParticle_Impl::minOccurs(this) {
+                    Ok(ok__) => {
+                        minoccurs.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn maxOccurs<Identity: windows_core::IUnknownImpl + ISchemaParticle_Impl>(this: *mut core::ffi::c_void, maxoccurs: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaParticle_Impl::maxOccurs(this) {
+                    Ok(ok__) => {
+                        maxoccurs.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ISchemaItem_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            minOccurs: minOccurs::<Identity>,
+            maxOccurs: maxOccurs::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISchemaParticle {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISchemaStringCollection, ISchemaStringCollection_Vtbl, 0x50ea08b1_dd1b_4664_9a50_c2f40f4bd79a);
@@ -4736,6 +6834,56 @@ impl ISchemaStringCollection_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISchemaStringCollection_Impl> windows_core::imp::TearOffVtable<Identity> for ISchemaStringCollection_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn get_item<Identity: windows_core::IUnknownImpl + ISchemaStringCollection_Impl>(this: *mut core::ffi::c_void, index: i32, bstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaStringCollection_Impl::get_item(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        bstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn length<Identity: windows_core::IUnknownImpl + ISchemaStringCollection_Impl>(this: *mut core::ffi::c_void, length: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaStringCollection_Impl::length(this) {
+                    Ok(ok__) => {
+                        length.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _newEnum<Identity: windows_core::IUnknownImpl + ISchemaStringCollection_Impl>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaStringCollection_Impl::_newEnum(this) {
+                    Ok(ok__) => {
+                        ppunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            get_item: get_item::<Identity>,
+            length: length::<Identity>,
+            _newEnum: _newEnum::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISchemaStringCollection {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISchemaType, ISchemaType_Vtbl, 0x50ea08b8_dd1b_4664_9a50_c2f40f4bd79a);
@@ -5149,30 +7297,262 @@ impl ISchemaType_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for ISchemaType {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IServerXMLHTTPRequest, IServerXMLHTTPRequest_Vtbl, 0x2e9196bf_13ba_4dd4_91ca_6c571f281495);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IServerXMLHTTPRequest {
-    type Target = IXMLHTTPRequest;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IServerXMLHTTPRequest, windows_core::IUnknown, super::super::super::System::Com::IDispatch, IXMLHTTPRequest);
-#[cfg(feature = "Win32_System_Com")]
-impl IServerXMLHTTPRequest {
-    pub unsafe fn setTimeouts(&self, resolvetimeout: i32, connecttimeout: i32, sendtimeout: i32, receivetimeout: i32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).setTimeouts)(windows_core::Interface::as_raw(self), resolvetimeout, connecttimeout, sendtimeout, receivetimeout).ok() }
-    }
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub unsafe fn waitForResponse(&self, timeoutinseconds: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<super::super::super::Foundation::VARIANT_BOOL> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).waitForResponse)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(timeoutinseconds), &mut result__).map(|| result__)
+impl<Identity: windows_core::IUnknownImpl + ISchemaType_Impl> windows_core::imp::TearOffVtable<Identity> for ISchemaType_Vtbl
+where
+    ISchemaItem_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn baseTypes<Identity: windows_core::IUnknownImpl + ISchemaType_Impl>(this: *mut core::ffi::c_void, basetypes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaType_Impl::baseTypes(this) {
+                    Ok(ok__) => {
+                        basetypes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
+        unsafe extern "system" fn r#final<Identity: windows_core::IUnknownImpl + ISchemaType_Impl>(this: *mut core::ffi::c_void, r#final: *mut SCHEMADERIVATIONMETHOD) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaType_Impl::r#final(this) {
+                    Ok(ok__) => {
+                        r#final.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn variety<Identity: windows_core::IUnknownImpl + ISchemaType_Impl>(this: *mut core::ffi::c_void, variety: *mut SCHEMATYPEVARIETY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaType_Impl::variety(this) {
+                    Ok(ok__) => {
+                        variety.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn derivedBy<Identity: windows_core::IUnknownImpl + ISchemaType_Impl>(this: *mut core::ffi::c_void, derivedby: *mut SCHEMADERIVATIONMETHOD) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaType_Impl::derivedBy(this) {
+                    Ok(ok__) => {
+                        derivedby.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn isValid<Identity: windows_core::IUnknownImpl + ISchemaType_Impl>(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, valid: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaType_Impl::isValid(this, core::mem::transmute(&data)) {
+                    Ok(ok__) => {
+                        valid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn minExclusive<Identity: windows_core::IUnknownImpl + ISchemaType_Impl>(this: *mut core::ffi::c_void, minexclusive: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaType_Impl::minExclusive(this) {
+                    Ok(ok__) => {
+                        minexclusive.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn minInclusive<Identity: windows_core::IUnknownImpl + ISchemaType_Impl>(this: *mut core::ffi::c_void, mininclusive: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaType_Impl::minInclusive(this) {
+                    Ok(ok__) => {
+                        mininclusive.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn maxExclusive<Identity: windows_core::IUnknownImpl + ISchemaType_Impl>(this: *mut core::ffi::c_void, maxexclusive: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaType_Impl::maxExclusive(this) {
+                    Ok(ok__) => {
+                        maxexclusive.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn maxInclusive<Identity: windows_core::IUnknownImpl + ISchemaType_Impl>(this: *mut core::ffi::c_void, maxinclusive: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaType_Impl::maxInclusive(this) {
+                    Ok(ok__) => {
+                        maxinclusive.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn totalDigits<Identity: windows_core::IUnknownImpl + ISchemaType_Impl>(this: *mut core::ffi::c_void, totaldigits: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaType_Impl::totalDigits(this) {
+                    Ok(ok__) => {
+                        totaldigits.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn fractionDigits<Identity: windows_core::IUnknownImpl + ISchemaType_Impl>(this: *mut core::ffi::c_void, fractiondigits: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaType_Impl::fractionDigits(this) {
+                    Ok(ok__) => {
+                        fractiondigits.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn length<Identity: windows_core::IUnknownImpl + ISchemaType_Impl>(this: *mut core::ffi::c_void, length: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaType_Impl::length(this) {
+                    Ok(ok__) => {
+                        length.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn minLength<Identity: windows_core::IUnknownImpl + ISchemaType_Impl>(this: *mut core::ffi::c_void, minlength: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaType_Impl::minLength(this) {
+                    Ok(ok__) => {
+                        minlength.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn maxLength<Identity: windows_core::IUnknownImpl + ISchemaType_Impl>(this: *mut core::ffi::c_void, maxlength: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaType_Impl::maxLength(this) {
+                    Ok(ok__) => {
+                        maxlength.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn enumeration<Identity: windows_core::IUnknownImpl + ISchemaType_Impl>(this: *mut core::ffi::c_void, enumeration: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaType_Impl::enumeration(this) {
+                    Ok(ok__) => {
+                        enumeration.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn whitespace<Identity: windows_core::IUnknownImpl + ISchemaType_Impl>(this: *mut core::ffi::c_void, whitespace: *mut SCHEMAWHITESPACE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaType_Impl::whitespace(this) {
+                    Ok(ok__) => {
+                        whitespace.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn patterns<Identity: windows_core::IUnknownImpl + ISchemaType_Impl>(this: *mut core::ffi::c_void, patterns: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISchemaType_Impl::patterns(this) {
+                    Ok(ok__) => {
+                        patterns.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ISchemaItem_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            baseTypes: baseTypes::<Identity>,
+            r#final: r#final::<Identity>,
+            variety: variety::<Identity>,
+            derivedBy: derivedBy::<Identity>,
+            isValid: isValid::<Identity>,
+            minExclusive: minExclusive::<Identity>,
+            minInclusive: minInclusive::<Identity>,
+            maxExclusive: maxExclusive::<Identity>,
+            maxInclusive: maxInclusive::<Identity>,
+            totalDigits: totalDigits::<Identity>,
+            fractionDigits: fractionDigits::<Identity>,
+            length: length::<Identity>,
+            minLength: minLength::<Identity>,
+            maxLength: maxLength::<Identity>,
+            enumeration: enumeration::<Identity>,
+            whitespace: whitespace::<Identity>,
+            patterns: patterns::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for ISchemaType {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(IServerXMLHTTPRequest, IServerXMLHTTPRequest_Vtbl, 0x2e9196bf_13ba_4dd4_91ca_6c571f281495);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for IServerXMLHTTPRequest {
+    type Target = IXMLHTTPRequest;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(IServerXMLHTTPRequest, windows_core::IUnknown, super::super::super::System::Com::IDispatch, IXMLHTTPRequest);
+#[cfg(feature = "Win32_System_Com")]
+impl IServerXMLHTTPRequest {
+    pub unsafe fn setTimeouts(&self, resolvetimeout: i32, connecttimeout: i32, sendtimeout: i32, receivetimeout: i32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).setTimeouts)(windows_core::Interface::as_raw(self), resolvetimeout, connecttimeout, sendtimeout, receivetimeout).ok() }
+    }
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub unsafe fn waitForResponse(&self, timeoutinseconds: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<super::super::super::Foundation::VARIANT_BOOL> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).waitForResponse)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(timeoutinseconds), &mut result__).map(|| result__)
+        }
+    }
     #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
     pub unsafe fn getOption(&self, option: SERVERXMLHTTP_OPTION) -> windows_core::Result<super::super::super::System::Variant::VARIANT> {
         unsafe {
@@ -5263,6 +7643,57 @@ impl IServerXMLHTTPRequest_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IServerXMLHTTPRequest_Impl> windows_core::imp::TearOffVtable<Identity> for IServerXMLHTTPRequest_Vtbl
+where
+    IXMLHTTPRequest_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn setTimeouts<Identity: windows_core::IUnknownImpl + IServerXMLHTTPRequest_Impl>(this: *mut core::ffi::c_void, resolvetimeout: i32, connecttimeout: i32, sendtimeout: i32, receivetimeout: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IServerXMLHTTPRequest_Impl::setTimeouts(this, core::mem::transmute_copy(&resolvetimeout), core::mem::transmute_copy(&connecttimeout), core::mem::transmute_copy(&sendtimeout), core::mem::transmute_copy(&receivetimeout)).into()
+            }
+        }
+        unsafe extern "system" fn waitForResponse<Identity: windows_core::IUnknownImpl + IServerXMLHTTPRequest_Impl>(this: *mut core::ffi::c_void, timeoutinseconds: super::super::super::System::Variant::VARIANT, issuccessful: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IServerXMLHTTPRequest_Impl::waitForResponse(this, core::mem::transmute(&timeoutinseconds)) {
+                    Ok(ok__) => {
+                        issuccessful.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getOption<Identity: windows_core::IUnknownImpl + IServerXMLHTTPRequest_Impl>(this: *mut core::ffi::c_void, option: SERVERXMLHTTP_OPTION, value: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IServerXMLHTTPRequest_Impl::getOption(this, core::mem::transmute_copy(&option)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn setOption<Identity: windows_core::IUnknownImpl + IServerXMLHTTPRequest_Impl>(this: *mut core::ffi::c_void, option: SERVERXMLHTTP_OPTION, value: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IServerXMLHTTPRequest_Impl::setOption(this, core::mem::transmute_copy(&option), core::mem::transmute(&value)).into()
+            }
+        }
+        Self {
+            base__: <IXMLHTTPRequest_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            setTimeouts: setTimeouts::<Identity>,
+            waitForResponse: waitForResponse::<Identity>,
+            getOption: getOption::<Identity>,
+            setOption: setOption::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IServerXMLHTTPRequest {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IServerXMLHTTPRequest2, IServerXMLHTTPRequest2_Vtbl, 0x2e01311b_c322_4b0a_bd77_b90cfdc8dce7);
@@ -5327,6 +7758,31 @@ impl IServerXMLHTTPRequest2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IServerXMLHTTPRequest2_Impl> windows_core::imp::TearOffVtable<Identity> for IServerXMLHTTPRequest2_Vtbl
+where
+    IServerXMLHTTPRequest_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn setProxy<Identity: windows_core::IUnknownImpl + IServerXMLHTTPRequest2_Impl>(this: *mut core::ffi::c_void, proxysetting: SXH_PROXY_SETTING, varproxyserver: super::super::super::System::Variant::VARIANT, varbypasslist: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IServerXMLHTTPRequest2_Impl::setProxy(this, core::mem::transmute_copy(&proxysetting), core::mem::transmute(&varproxyserver), core::mem::transmute(&varbypasslist)).into()
+            }
+        }
+        unsafe extern "system" fn setProxyCredentials<Identity: windows_core::IUnknownImpl + IServerXMLHTTPRequest2_Impl>(this: *mut core::ffi::c_void, bstrusername: *mut core::ffi::c_void, bstrpassword: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IServerXMLHTTPRequest2_Impl::setProxyCredentials(this, core::mem::transmute(&bstrusername), core::mem::transmute(&bstrpassword)).into()
+            }
+        }
+        Self {
+            base__: <IServerXMLHTTPRequest_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            setProxy: setProxy::<Identity>,
+            setProxyCredentials: setProxyCredentials::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IServerXMLHTTPRequest2 {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IVBMXNamespaceManager, IVBMXNamespaceManager_Vtbl, 0xc90352f5_643c_4fbc_bb23_e996eb2d51fd);
@@ -5554,32 +8010,150 @@ impl IVBMXNamespaceManager_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IVBMXNamespaceManager {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IVBSAXAttributes, IVBSAXAttributes_Vtbl, 0x10dc0586_132b_4cac_8bb3_db00ac8b7ee0);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IVBSAXAttributes {
-    type Target = super::super::super::System::Com::IDispatch;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IVBSAXAttributes, windows_core::IUnknown, super::super::super::System::Com::IDispatch);
-#[cfg(feature = "Win32_System_Com")]
-impl IVBSAXAttributes {
-    pub unsafe fn length(&self) -> windows_core::Result<i32> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).length)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
-        }
-    }
-    pub unsafe fn getURI(&self, nindex: i32) -> windows_core::Result<windows_core::BSTR> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).getURI)(windows_core::Interface::as_raw(self), nindex, &mut result__).map(|| core::mem::transmute(result__))
+impl<Identity: windows_core::IUnknownImpl + IVBMXNamespaceManager_Impl> windows_core::imp::TearOffVtable<Identity> for IVBMXNamespaceManager_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetallowOverride<Identity: windows_core::IUnknownImpl + IVBMXNamespaceManager_Impl>(this: *mut core::ffi::c_void, foverride: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBMXNamespaceManager_Impl::SetallowOverride(this, core::mem::transmute_copy(&foverride)).into()
+            }
         }
-    }
+        unsafe extern "system" fn allowOverride<Identity: windows_core::IUnknownImpl + IVBMXNamespaceManager_Impl>(this: *mut core::ffi::c_void, foverride: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBMXNamespaceManager_Impl::allowOverride(this) {
+                    Ok(ok__) => {
+                        foverride.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn reset<Identity: windows_core::IUnknownImpl + IVBMXNamespaceManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBMXNamespaceManager_Impl::reset(this).into()
+            }
+        }
+        unsafe extern "system" fn pushContext<Identity: windows_core::IUnknownImpl + IVBMXNamespaceManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBMXNamespaceManager_Impl::pushContext(this).into()
+            }
+        }
+        unsafe extern "system" fn pushNodeContext<Identity: windows_core::IUnknownImpl + IVBMXNamespaceManager_Impl>(this: *mut core::ffi::c_void, contextnode: *mut core::ffi::c_void, fdeep: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBMXNamespaceManager_Impl::pushNodeContext(this, core::mem::transmute_copy(&contextnode), core::mem::transmute_copy(&fdeep)).into()
+            }
+        }
+        unsafe extern "system" fn popContext<Identity: windows_core::IUnknownImpl + IVBMXNamespaceManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBMXNamespaceManager_Impl::popContext(this).into()
+            }
+        }
+        unsafe extern "system" fn declarePrefix<Identity: windows_core::IUnknownImpl + IVBMXNamespaceManager_Impl>(this: *mut core::ffi::c_void, prefix: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBMXNamespaceManager_Impl::declarePrefix(this, core::mem::transmute(&prefix), core::mem::transmute(&namespaceuri)).into()
+            }
+        }
+        unsafe extern "system" fn getDeclaredPrefixes<Identity: windows_core::IUnknownImpl + IVBMXNamespaceManager_Impl>(this: *mut core::ffi::c_void, prefixes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBMXNamespaceManager_Impl::getDeclaredPrefixes(this) {
+                    Ok(ok__) => {
+                        prefixes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getPrefixes<Identity: windows_core::IUnknownImpl + IVBMXNamespaceManager_Impl>(this: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void, prefixes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBMXNamespaceManager_Impl::getPrefixes(this, core::mem::transmute(&namespaceuri)) {
+                    Ok(ok__) => {
+                        prefixes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getURI<Identity: windows_core::IUnknownImpl + IVBMXNamespaceManager_Impl>(this: *mut core::ffi::c_void, prefix: *mut core::ffi::c_void, uri: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBMXNamespaceManager_Impl::getURI(this, core::mem::transmute(&prefix)) {
+                    Ok(ok__) => {
+                        uri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getURIFromNode<Identity: windows_core::IUnknownImpl + IVBMXNamespaceManager_Impl>(this: *mut core::ffi::c_void, strprefix: *mut core::ffi::c_void, contextnode: *mut core::ffi::c_void, uri: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBMXNamespaceManager_Impl::getURIFromNode(this, core::mem::transmute(&strprefix), core::mem::transmute_copy(&contextnode)) {
+                    Ok(ok__) => {
+                        uri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetallowOverride: SetallowOverride::<Identity>,
+            allowOverride: allowOverride::<Identity>,
+            reset: reset::<Identity>,
+            pushContext: pushContext::<Identity>,
+            pushNodeContext: pushNodeContext::<Identity>,
+            popContext: popContext::<Identity>,
+            declarePrefix: declarePrefix::<Identity>,
+            getDeclaredPrefixes: getDeclaredPrefixes::<Identity>,
+            getPrefixes: getPrefixes::<Identity>,
+            getURI: getURI::<Identity>,
+            getURIFromNode: getURIFromNode::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IVBMXNamespaceManager {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(IVBSAXAttributes, IVBSAXAttributes_Vtbl, 0x10dc0586_132b_4cac_8bb3_db00ac8b7ee0);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for IVBSAXAttributes {
+    type Target = super::super::super::System::Com::IDispatch;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(IVBSAXAttributes, windows_core::IUnknown, super::super::super::System::Com::IDispatch);
+#[cfg(feature = "Win32_System_Com")]
+impl IVBSAXAttributes {
+    pub unsafe fn length(&self) -> windows_core::Result<i32> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).length)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn getURI(&self, nindex: i32) -> windows_core::Result<windows_core::BSTR> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).getURI)(windows_core::Interface::as_raw(self), nindex, &mut result__).map(|| core::mem::transmute(result__))
+        }
+    }
     pub unsafe fn getLocalName(&self, nindex: i32) -> windows_core::Result<windows_core::BSTR> {
         unsafe {
             let mut result__ = core::mem::zeroed();
@@ -5842,6 +8416,173 @@ impl IVBSAXAttributes_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IVBSAXAttributes_Impl> windows_core::imp::TearOffVtable<Identity> for IVBSAXAttributes_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn length<Identity: windows_core::IUnknownImpl + IVBSAXAttributes_Impl>(this: *mut core::ffi::c_void, nlength: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXAttributes_Impl::length(this) {
+                    Ok(ok__) => {
+                        nlength.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getURI<Identity: windows_core::IUnknownImpl + IVBSAXAttributes_Impl>(this: *mut core::ffi::c_void, nindex: i32, struri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXAttributes_Impl::getURI(this, core::mem::transmute_copy(&nindex)) {
+                    Ok(ok__) => {
+                        struri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getLocalName<Identity: windows_core::IUnknownImpl + IVBSAXAttributes_Impl>(this: *mut core::ffi::c_void, nindex: i32, strlocalname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXAttributes_Impl::getLocalName(this, core::mem::transmute_copy(&nindex)) {
+                    Ok(ok__) => {
+                        strlocalname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getQName<Identity: windows_core::IUnknownImpl + IVBSAXAttributes_Impl>(this: *mut core::ffi::c_void, nindex: i32, strqname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXAttributes_Impl::getQName(this, core::mem::transmute_copy(&nindex)) {
+                    Ok(ok__) => {
+                        strqname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getIndexFromName<Identity: windows_core::IUnknownImpl + IVBSAXAttributes_Impl>(this: *mut core::ffi::c_void, struri: *mut core::ffi::c_void, strlocalname: *mut core::ffi::c_void, nindex: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXAttributes_Impl::getIndexFromName(this, core::mem::transmute(&struri), core::mem::transmute(&strlocalname)) {
+                    Ok(ok__) => {
+                        nindex.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getIndexFromQName<Identity: windows_core::IUnknownImpl + IVBSAXAttributes_Impl>(this: *mut core::ffi::c_void, strqname: *mut core::ffi::c_void, nindex: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXAttributes_Impl::getIndexFromQName(this, core::mem::transmute(&strqname)) {
+                    Ok(ok__) => {
+                        nindex.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getType<Identity: windows_core::IUnknownImpl + IVBSAXAttributes_Impl>(this: *mut core::ffi::c_void, nindex: i32, strtype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXAttributes_Impl::getType(this, core::mem::transmute_copy(&nindex)) {
+                    Ok(ok__) => {
+                        strtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getTypeFromName<Identity: windows_core::IUnknownImpl + IVBSAXAttributes_Impl>(this: *mut core::ffi::c_void, struri: *mut core::ffi::c_void, strlocalname: *mut core::ffi::c_void, strtype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXAttributes_Impl::getTypeFromName(this, core::mem::transmute(&struri), core::mem::transmute(&strlocalname)) {
+                    Ok(ok__) => {
+                        strtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getTypeFromQName<Identity: windows_core::IUnknownImpl + IVBSAXAttributes_Impl>(this: *mut core::ffi::c_void, strqname: *mut core::ffi::c_void, strtype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXAttributes_Impl::getTypeFromQName(this, core::mem::transmute(&strqname)) {
+                    Ok(ok__) => {
+                        strtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getValue<Identity: windows_core::IUnknownImpl + IVBSAXAttributes_Impl>(this: *mut core::ffi::c_void, nindex: i32, strvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXAttributes_Impl::getValue(this, core::mem::transmute_copy(&nindex)) {
+                    Ok(ok__) => {
+                        strvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getValueFromName<Identity: windows_core::IUnknownImpl + IVBSAXAttributes_Impl>(this: *mut core::ffi::c_void, struri: *mut core::ffi::c_void, strlocalname: *mut core::ffi::c_void, strvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXAttributes_Impl::getValueFromName(this, core::mem::transmute(&struri), core::mem::transmute(&strlocalname)) {
+                    Ok(ok__) => {
+                        strvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getValueFromQName<Identity: windows_core::IUnknownImpl + IVBSAXAttributes_Impl>(this: *mut core::ffi::c_void, strqname: *mut core::ffi::c_void, strvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXAttributes_Impl::getValueFromQName(this, core::mem::transmute(&strqname)) {
+                    Ok(ok__) => {
+                        strvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            length: length::<Identity>,
+            getURI: getURI::<Identity>,
+            getLocalName: getLocalName::<Identity>,
+            getQName: getQName::<Identity>,
+            getIndexFromName: getIndexFromName::<Identity>,
+            getIndexFromQName: getIndexFromQName::<Identity>,
+            getType: getType::<Identity>,
+            getTypeFromName: getTypeFromName::<Identity>,
+            getTypeFromQName: getTypeFromQName::<Identity>,
+            getValue: getValue::<Identity>,
+            getValueFromName: getValueFromName::<Identity>,
+            getValueFromQName: getValueFromQName::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IVBSAXAttributes {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IVBSAXContentHandler, IVBSAXContentHandler_Vtbl, 0x2ed7290a_4dd5_4b46_bb26_4e4155e77faa);
@@ -6016,41 +8757,129 @@ impl IVBSAXContentHandler_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IVBSAXContentHandler {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IVBSAXDTDHandler, IVBSAXDTDHandler_Vtbl, 0x24fb3297_302d_4620_ba39_3a732d850558);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IVBSAXDTDHandler {
-    type Target = super::super::super::System::Com::IDispatch;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IVBSAXDTDHandler, windows_core::IUnknown, super::super::super::System::Com::IDispatch);
-#[cfg(feature = "Win32_System_Com")]
-impl IVBSAXDTDHandler {
-    pub unsafe fn notationDecl(&self, strname: *mut windows_core::BSTR, strpublicid: *mut windows_core::BSTR, strsystemid: *mut windows_core::BSTR) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).notationDecl)(windows_core::Interface::as_raw(self), core::mem::transmute(strname), core::mem::transmute(strpublicid), core::mem::transmute(strsystemid)).ok() }
-    }
-    pub unsafe fn unparsedEntityDecl(&self, strname: *mut windows_core::BSTR, strpublicid: *mut windows_core::BSTR, strsystemid: *mut windows_core::BSTR, strnotationname: *mut windows_core::BSTR) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).unparsedEntityDecl)(windows_core::Interface::as_raw(self), core::mem::transmute(strname), core::mem::transmute(strpublicid), core::mem::transmute(strsystemid), core::mem::transmute(strnotationname)).ok() }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-#[repr(C)]
-#[doc(hidden)]
-pub struct IVBSAXDTDHandler_Vtbl {
-    pub base__: super::super::super::System::Com::IDispatch_Vtbl,
-    pub notationDecl: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void, *mut *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub unparsedEntityDecl: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void, *mut *mut core::ffi::c_void, *mut *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-pub trait IVBSAXDTDHandler_Impl: super::super::super::System::Com::IDispatch_Impl {
-    fn notationDecl(&self, strname: *mut windows_core::BSTR, strpublicid: *mut windows_core::BSTR, strsystemid: *mut windows_core::BSTR) -> windows_core::Result<()>;
-    fn unparsedEntityDecl(&self, strname: *mut windows_core::BSTR, strpublicid: *mut windows_core::BSTR, strsystemid: *mut windows_core::BSTR, strnotationname: *mut windows_core::BSTR) -> windows_core::Result<()>;
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IVBSAXContentHandler_Impl> windows_core::imp::TearOffVtable<Identity> for IVBSAXContentHandler_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn putref_documentLocator<Identity: windows_core::IUnknownImpl + IVBSAXContentHandler_Impl>(this: *mut core::ffi::c_void, olocator: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXContentHandler_Impl::putref_documentLocator(this, core::mem::transmute_copy(&olocator)).into()
+            }
+        }
+        unsafe extern "system" fn startDocument<Identity: windows_core::IUnknownImpl + IVBSAXContentHandler_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXContentHandler_Impl::startDocument(this).into()
+            }
+        }
+        unsafe extern "system" fn endDocument<Identity: windows_core::IUnknownImpl + IVBSAXContentHandler_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXContentHandler_Impl::endDocument(this).into()
+            }
+        }
+        unsafe extern "system" fn startPrefixMapping<Identity: windows_core::IUnknownImpl + IVBSAXContentHandler_Impl>(this: *mut core::ffi::c_void, strprefix: *mut *mut core::ffi::c_void, struri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXContentHandler_Impl::startPrefixMapping(this, core::mem::transmute_copy(&strprefix), core::mem::transmute_copy(&struri)).into()
+            }
+        }
+        unsafe extern "system" fn endPrefixMapping<Identity: windows_core::IUnknownImpl + IVBSAXContentHandler_Impl>(this: *mut core::ffi::c_void, strprefix: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXContentHandler_Impl::endPrefixMapping(this, core::mem::transmute_copy(&strprefix)).into()
+            }
+        }
+        unsafe extern "system" fn startElement<Identity: windows_core::IUnknownImpl + IVBSAXContentHandler_Impl>(this: *mut core::ffi::c_void, strnamespaceuri: *mut *mut core::ffi::c_void, strlocalname: *mut *mut core::ffi::c_void, strqname: *mut *mut core::ffi::c_void, oattributes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXContentHandler_Impl::startElement(this, core::mem::transmute_copy(&strnamespaceuri), core::mem::transmute_copy(&strlocalname), core::mem::transmute_copy(&strqname), core::mem::transmute_copy(&oattributes)).into()
+            }
+        }
+        unsafe extern "system" fn endElement<Identity: windows_core::IUnknownImpl + IVBSAXContentHandler_Impl>(this: *mut core::ffi::c_void, strnamespaceuri: *mut *mut core::ffi::c_void, strlocalname: *mut *mut core::ffi::c_void, strqname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXContentHandler_Impl::endElement(this, core::mem::transmute_copy(&strnamespaceuri), core::mem::transmute_copy(&strlocalname), core::mem::transmute_copy(&strqname)).into()
+            }
+        }
+        unsafe extern "system" fn characters<Identity: windows_core::IUnknownImpl + IVBSAXContentHandler_Impl>(this: *mut core::ffi::c_void, strchars: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXContentHandler_Impl::characters(this, core::mem::transmute_copy(&strchars)).into()
+            }
+        }
+        unsafe extern "system" fn ignorableWhitespace<Identity: windows_core::IUnknownImpl + IVBSAXContentHandler_Impl>(this: *mut core::ffi::c_void, strchars: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXContentHandler_Impl::ignorableWhitespace(this, core::mem::transmute_copy(&strchars)).into()
+            }
+        }
+        unsafe extern "system" fn processingInstruction<Identity: windows_core::IUnknownImpl + IVBSAXContentHandler_Impl>(this: *mut core::ffi::c_void, strtarget: *mut *mut core::ffi::c_void, strdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXContentHandler_Impl::processingInstruction(this, core::mem::transmute_copy(&strtarget), core::mem::transmute_copy(&strdata)).into()
+            }
+        }
+        unsafe extern "system" fn skippedEntity<Identity: windows_core::IUnknownImpl + IVBSAXContentHandler_Impl>(this: *mut core::ffi::c_void, strname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXContentHandler_Impl::skippedEntity(this, core::mem::transmute_copy(&strname)).into()
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            putref_documentLocator: putref_documentLocator::<Identity>,
+            startDocument: startDocument::<Identity>,
+            endDocument: endDocument::<Identity>,
+            startPrefixMapping: startPrefixMapping::<Identity>,
+            endPrefixMapping: endPrefixMapping::<Identity>,
+            startElement: startElement::<Identity>,
+            endElement: endElement::<Identity>,
+            characters: characters::<Identity>,
+            ignorableWhitespace: ignorableWhitespace::<Identity>,
+            processingInstruction: processingInstruction::<Identity>,
+            skippedEntity: skippedEntity::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IVBSAXContentHandler {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(IVBSAXDTDHandler, IVBSAXDTDHandler_Vtbl, 0x24fb3297_302d_4620_ba39_3a732d850558);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for IVBSAXDTDHandler {
+    type Target = super::super::super::System::Com::IDispatch;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(IVBSAXDTDHandler, windows_core::IUnknown, super::super::super::System::Com::IDispatch);
+#[cfg(feature = "Win32_System_Com")]
+impl IVBSAXDTDHandler {
+    pub unsafe fn notationDecl(&self, strname: *mut windows_core::BSTR, strpublicid: *mut windows_core::BSTR, strsystemid: *mut windows_core::BSTR) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).notationDecl)(windows_core::Interface::as_raw(self), core::mem::transmute(strname), core::mem::transmute(strpublicid), core::mem::transmute(strsystemid)).ok() }
+    }
+    pub unsafe fn unparsedEntityDecl(&self, strname: *mut windows_core::BSTR, strpublicid: *mut windows_core::BSTR, strsystemid: *mut windows_core::BSTR, strnotationname: *mut windows_core::BSTR) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).unparsedEntityDecl)(windows_core::Interface::as_raw(self), core::mem::transmute(strname), core::mem::transmute(strpublicid), core::mem::transmute(strsystemid), core::mem::transmute(strnotationname)).ok() }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+#[repr(C)]
+#[doc(hidden)]
+pub struct IVBSAXDTDHandler_Vtbl {
+    pub base__: super::super::super::System::Com::IDispatch_Vtbl,
+    pub notationDecl: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void, *mut *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub unparsedEntityDecl: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void, *mut *mut core::ffi::c_void, *mut *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+pub trait IVBSAXDTDHandler_Impl: super::super::super::System::Com::IDispatch_Impl {
+    fn notationDecl(&self, strname: *mut windows_core::BSTR, strpublicid: *mut windows_core::BSTR, strsystemid: *mut windows_core::BSTR) -> windows_core::Result<()>;
+    fn unparsedEntityDecl(&self, strname: *mut windows_core::BSTR, strpublicid: *mut windows_core::BSTR, strsystemid: *mut windows_core::BSTR, strnotationname: *mut windows_core::BSTR) -> windows_core::Result<()>;
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl IVBSAXDTDHandler_Vtbl {
     pub const fn new<Identity: IVBSAXDTDHandler_Impl, const OFFSET: isize>() -> Self {
         unsafe extern "system" fn notationDecl<Identity: IVBSAXDTDHandler_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, strname: *mut *mut core::ffi::c_void, strpublicid: *mut *mut core::ffi::c_void, strsystemid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
@@ -6076,6 +8905,31 @@ impl IVBSAXDTDHandler_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IVBSAXDTDHandler_Impl> windows_core::imp::TearOffVtable<Identity> for IVBSAXDTDHandler_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn notationDecl<Identity: windows_core::IUnknownImpl + IVBSAXDTDHandler_Impl>(this: *mut core::ffi::c_void, strname: *mut *mut core::ffi::c_void, strpublicid: *mut *mut core::ffi::c_void, strsystemid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXDTDHandler_Impl::notationDecl(this, core::mem::transmute_copy(&strname), core::mem::transmute_copy(&strpublicid), core::mem::transmute_copy(&strsystemid)).into()
+            }
+        }
+        unsafe extern "system" fn unparsedEntityDecl<Identity: windows_core::IUnknownImpl + IVBSAXDTDHandler_Impl>(this: *mut core::ffi::c_void, strname: *mut *mut core::ffi::c_void, strpublicid: *mut *mut core::ffi::c_void, strsystemid: *mut *mut core::ffi::c_void, strnotationname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXDTDHandler_Impl::unparsedEntityDecl(this, core::mem::transmute_copy(&strname), core::mem::transmute_copy(&strpublicid), core::mem::transmute_copy(&strsystemid), core::mem::transmute_copy(&strnotationname)).into()
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            notationDecl: notationDecl::<Identity>,
+            unparsedEntityDecl: unparsedEntityDecl::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IVBSAXDTDHandler {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IVBSAXDeclHandler, IVBSAXDeclHandler_Vtbl, 0xe8917260_7579_4be1_b5dd_7afbfa6f077b);
@@ -6160,6 +9014,45 @@ impl IVBSAXDeclHandler_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IVBSAXDeclHandler_Impl> windows_core::imp::TearOffVtable<Identity> for IVBSAXDeclHandler_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn elementDecl<Identity: windows_core::IUnknownImpl + IVBSAXDeclHandler_Impl>(this: *mut core::ffi::c_void, strname: *mut *mut core::ffi::c_void, strmodel: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXDeclHandler_Impl::elementDecl(this, core::mem::transmute_copy(&strname), core::mem::transmute_copy(&strmodel)).into()
+            }
+        }
+        unsafe extern "system" fn attributeDecl<Identity: windows_core::IUnknownImpl + IVBSAXDeclHandler_Impl>(this: *mut core::ffi::c_void, strelementname: *mut *mut core::ffi::c_void, strattributename: *mut *mut core::ffi::c_void, strtype: *mut *mut core::ffi::c_void, strvaluedefault: *mut *mut core::ffi::c_void, strvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXDeclHandler_Impl::attributeDecl(this, core::mem::transmute_copy(&strelementname), core::mem::transmute_copy(&strattributename), core::mem::transmute_copy(&strtype), core::mem::transmute_copy(&strvaluedefault), core::mem::transmute_copy(&strvalue)).into()
+            }
+        }
+        unsafe extern "system" fn internalEntityDecl<Identity: windows_core::IUnknownImpl + IVBSAXDeclHandler_Impl>(this: *mut core::ffi::c_void, strname: *mut *mut core::ffi::c_void, strvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXDeclHandler_Impl::internalEntityDecl(this, core::mem::transmute_copy(&strname), core::mem::transmute_copy(&strvalue)).into()
+            }
+        }
+        unsafe extern "system" fn externalEntityDecl<Identity: windows_core::IUnknownImpl + IVBSAXDeclHandler_Impl>(this: *mut core::ffi::c_void, strname: *mut *mut core::ffi::c_void, strpublicid: *mut *mut core::ffi::c_void, strsystemid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXDeclHandler_Impl::externalEntityDecl(this, core::mem::transmute_copy(&strname), core::mem::transmute_copy(&strpublicid), core::mem::transmute_copy(&strsystemid)).into()
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            elementDecl: elementDecl::<Identity>,
+            attributeDecl: attributeDecl::<Identity>,
+            internalEntityDecl: internalEntityDecl::<Identity>,
+            externalEntityDecl: externalEntityDecl::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IVBSAXDeclHandler {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IVBSAXEntityResolver, IVBSAXEntityResolver_Vtbl, 0x0c05d096_f45b_4aca_ad1a_aa0bc25518dc);
@@ -6218,6 +9111,30 @@ impl IVBSAXEntityResolver_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IVBSAXEntityResolver_Impl> windows_core::imp::TearOffVtable<Identity> for IVBSAXEntityResolver_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn resolveEntity<Identity: windows_core::IUnknownImpl + IVBSAXEntityResolver_Impl>(this: *mut core::ffi::c_void, strpublicid: *mut *mut core::ffi::c_void, strsystemid: *mut *mut core::ffi::c_void, varinput: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXEntityResolver_Impl::resolveEntity(this, core::mem::transmute_copy(&strpublicid), core::mem::transmute_copy(&strsystemid)) {
+                    Ok(ok__) => {
+                        varinput.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            resolveEntity: resolveEntity::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IVBSAXEntityResolver {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IVBSAXErrorHandler, IVBSAXErrorHandler_Vtbl, 0xd963d3fe_173c_4862_9095_b92f66995f52);
@@ -6299,6 +9216,38 @@ impl IVBSAXErrorHandler_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IVBSAXErrorHandler_Impl> windows_core::imp::TearOffVtable<Identity> for IVBSAXErrorHandler_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn error<Identity: windows_core::IUnknownImpl + IVBSAXErrorHandler_Impl>(this: *mut core::ffi::c_void, olocator: *mut core::ffi::c_void, strerrormessage: *mut *mut core::ffi::c_void, nerrorcode: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXErrorHandler_Impl::error(this, core::mem::transmute_copy(&olocator), core::mem::transmute_copy(&strerrormessage), core::mem::transmute_copy(&nerrorcode)).into()
+            }
+        }
+        unsafe extern "system" fn fatalError<Identity: windows_core::IUnknownImpl + IVBSAXErrorHandler_Impl>(this: *mut core::ffi::c_void, olocator: *mut core::ffi::c_void, strerrormessage: *mut *mut core::ffi::c_void, nerrorcode: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXErrorHandler_Impl::fatalError(this, core::mem::transmute_copy(&olocator), core::mem::transmute_copy(&strerrormessage), core::mem::transmute_copy(&nerrorcode)).into()
+            }
+        }
+        unsafe extern "system" fn ignorableWarning<Identity: windows_core::IUnknownImpl + IVBSAXErrorHandler_Impl>(this: *mut core::ffi::c_void, olocator: *mut core::ffi::c_void, strerrormessage: *mut *mut core::ffi::c_void, nerrorcode: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXErrorHandler_Impl::ignorableWarning(this, core::mem::transmute_copy(&olocator), core::mem::transmute_copy(&strerrormessage), core::mem::transmute_copy(&nerrorcode)).into()
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            error: error::<Identity>,
+            fatalError: fatalError::<Identity>,
+            ignorableWarning: ignorableWarning::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IVBSAXErrorHandler {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IVBSAXLexicalHandler, IVBSAXLexicalHandler_Vtbl, 0x032aac35_8c0e_4d9d_979f_e3b702935576);
@@ -6419,6 +9368,66 @@ impl IVBSAXLexicalHandler_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IVBSAXLexicalHandler_Impl> windows_core::imp::TearOffVtable<Identity> for IVBSAXLexicalHandler_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn startDTD<Identity: windows_core::IUnknownImpl + IVBSAXLexicalHandler_Impl>(this: *mut core::ffi::c_void, strname: *mut *mut core::ffi::c_void, strpublicid: *mut *mut core::ffi::c_void, strsystemid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXLexicalHandler_Impl::startDTD(this, core::mem::transmute_copy(&strname), core::mem::transmute_copy(&strpublicid), core::mem::transmute_copy(&strsystemid)).into()
+            }
+        }
+        unsafe extern "system" fn endDTD<Identity: windows_core::IUnknownImpl + IVBSAXLexicalHandler_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXLexicalHandler_Impl::endDTD(this).into()
+            }
+        }
+        unsafe extern "system" fn startEntity<Identity: windows_core::IUnknownImpl + IVBSAXLexicalHandler_Impl>(this: *mut core::ffi::c_void, strname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXLexicalHandler_Impl::startEntity(this, core::mem::transmute_copy(&strname)).into()
+            }
+        }
+        unsafe extern "system" fn endEntity<Identity: windows_core::IUnknownImpl + IVBSAXLexicalHandler_Impl>(this: *mut core::ffi::c_void, strname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXLexicalHandler_Impl::endEntity(this, core::mem::transmute_copy(&strname)).into()
+            }
+        }
+        unsafe extern "system" fn startCDATA<Identity: windows_core::IUnknownImpl + IVBSAXLexicalHandler_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXLexicalHandler_Impl::startCDATA(this).into()
+            }
+        }
+        unsafe extern "system" fn endCDATA<Identity: windows_core::IUnknownImpl + IVBSAXLexicalHandler_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXLexicalHandler_Impl::endCDATA(this).into()
+            }
+        }
+        unsafe extern "system" fn comment<Identity: windows_core::IUnknownImpl + IVBSAXLexicalHandler_Impl>(this: *mut core::ffi::c_void, strchars: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXLexicalHandler_Impl::comment(this, core::mem::transmute_copy(&strchars)).into()
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            startDTD: startDTD::<Identity>,
+            endDTD: endDTD::<Identity>,
+            startEntity: startEntity::<Identity>,
+            endEntity: endEntity::<Identity>,
+            startCDATA: startCDATA::<Identity>,
+            endCDATA: endCDATA::<Identity>,
+            comment: comment::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IVBSAXLexicalHandler {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IVBSAXLocator, IVBSAXLocator_Vtbl, 0x796e7ac5_5aa2_4eff_acad_3faaf01a3288);
@@ -6539,6 +9548,69 @@ impl IVBSAXLocator_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IVBSAXLocator_Impl> windows_core::imp::TearOffVtable<Identity> for IVBSAXLocator_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn columnNumber<Identity: windows_core::IUnknownImpl + IVBSAXLocator_Impl>(this: *mut core::ffi::c_void, ncolumn: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXLocator_Impl::columnNumber(this) {
+                    Ok(ok__) => {
+                        ncolumn.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn lineNumber<Identity: windows_core::IUnknownImpl + IVBSAXLocator_Impl>(this: *mut core::ffi::c_void, nline: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXLocator_Impl::lineNumber(this) {
+                    Ok(ok__) => {
+                        nline.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn publicId<Identity: windows_core::IUnknownImpl + IVBSAXLocator_Impl>(this: *mut core::ffi::c_void, strpublicid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXLocator_Impl::publicId(this) {
+                    Ok(ok__) => {
+                        strpublicid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn systemId<Identity: windows_core::IUnknownImpl + IVBSAXLocator_Impl>(this: *mut core::ffi::c_void, strsystemid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXLocator_Impl::systemId(this) {
+                    Ok(ok__) => {
+                        strsystemid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            columnNumber: columnNumber::<Identity>,
+            lineNumber: lineNumber::<Identity>,
+            publicId: publicId::<Identity>,
+            systemId: systemId::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IVBSAXLocator {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IVBSAXXMLFilter, IVBSAXXMLFilter_Vtbl, 0x1299eb1b_5b88_433e_82de_82ca75ad4e04);
@@ -6611,6 +9683,37 @@ impl IVBSAXXMLFilter_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IVBSAXXMLFilter_Impl> windows_core::imp::TearOffVtable<Identity> for IVBSAXXMLFilter_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn parent<Identity: windows_core::IUnknownImpl + IVBSAXXMLFilter_Impl>(this: *mut core::ffi::c_void, oreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXXMLFilter_Impl::parent(this) {
+                    Ok(ok__) => {
+                        oreader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putref_parent<Identity: windows_core::IUnknownImpl + IVBSAXXMLFilter_Impl>(this: *mut core::ffi::c_void, oreader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXXMLFilter_Impl::putref_parent(this, core::mem::transmute_copy(&oreader)).into()
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            parent: parent::<Identity>,
+            putref_parent: putref_parent::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IVBSAXXMLFilter {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IVBSAXXMLReader, IVBSAXXMLReader_Vtbl, 0x8c033caa_6cd6_4f73_b728_4531af74945f);
@@ -6959,24 +10062,209 @@ impl IVBSAXXMLReader_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IVBSAXXMLReader {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IXMLAttribute, IXMLAttribute_Vtbl, 0xd4d4a0fc_3b73_11d1_b2b4_00c04fb92596);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IXMLAttribute {
-    type Target = super::super::super::System::Com::IDispatch;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IXMLAttribute, windows_core::IUnknown, super::super::super::System::Com::IDispatch);
-#[cfg(feature = "Win32_System_Com")]
-impl IXMLAttribute {
-    pub unsafe fn name(&self) -> windows_core::Result<windows_core::BSTR> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).name)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+impl<Identity: windows_core::IUnknownImpl + IVBSAXXMLReader_Impl> windows_core::imp::TearOffVtable<Identity> for IVBSAXXMLReader_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn getFeature<Identity: windows_core::IUnknownImpl + IVBSAXXMLReader_Impl>(this: *mut core::ffi::c_void, strname: *mut core::ffi::c_void, fvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXXMLReader_Impl::getFeature(this, core::mem::transmute(&strname)) {
+                    Ok(ok__) => {
+                        fvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putFeature<Identity: windows_core::IUnknownImpl + IVBSAXXMLReader_Impl>(this: *mut core::ffi::c_void, strname: *mut core::ffi::c_void, fvalue: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXXMLReader_Impl::putFeature(this, core::mem::transmute(&strname), core::mem::transmute_copy(&fvalue)).into()
+            }
+        }
+        unsafe extern "system" fn getProperty<Identity: windows_core::IUnknownImpl + IVBSAXXMLReader_Impl>(this: *mut core::ffi::c_void, strname: *mut core::ffi::c_void, varvalue: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXXMLReader_Impl::getProperty(this, core::mem::transmute(&strname)) {
+                    Ok(ok__) => {
+                        varvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putProperty<Identity: windows_core::IUnknownImpl + IVBSAXXMLReader_Impl>(this: *mut core::ffi::c_void, strname: *mut core::ffi::c_void, varvalue: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXXMLReader_Impl::putProperty(this, core::mem::transmute(&strname), core::mem::transmute(&varvalue)).into()
+            }
+        }
+        unsafe extern "system" fn entityResolver<Identity: windows_core::IUnknownImpl + IVBSAXXMLReader_Impl>(this: *mut core::ffi::c_void, oresolver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXXMLReader_Impl::entityResolver(this) {
+                    Ok(ok__) => {
+                        oresolver.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putref_entityResolver<Identity: windows_core::IUnknownImpl + IVBSAXXMLReader_Impl>(this: *mut core::ffi::c_void, oresolver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXXMLReader_Impl::putref_entityResolver(this, core::mem::transmute_copy(&oresolver)).into()
+            }
+        }
+        unsafe extern "system" fn contentHandler<Identity: windows_core::IUnknownImpl + IVBSAXXMLReader_Impl>(this: *mut core::ffi::c_void, ohandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXXMLReader_Impl::contentHandler(this) {
+                    Ok(ok__) => {
+                        ohandler.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putref_contentHandler<Identity: windows_core::IUnknownImpl + IVBSAXXMLReader_Impl>(this: *mut core::ffi::c_void, ohandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXXMLReader_Impl::putref_contentHandler(this, core::mem::transmute_copy(&ohandler)).into()
+            }
+        }
+        unsafe extern "system" fn dtdHandler<Identity: windows_core::IUnknownImpl + IVBSAXXMLReader_Impl>(this: *mut core::ffi::c_void, ohandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXXMLReader_Impl::dtdHandler(this) {
+                    Ok(ok__) => {
+                        ohandler.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putref_dtdHandler<Identity: windows_core::IUnknownImpl + IVBSAXXMLReader_Impl>(this: *mut core::ffi::c_void, ohandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXXMLReader_Impl::putref_dtdHandler(this, core::mem::transmute_copy(&ohandler)).into()
+            }
+        }
+        unsafe extern "system" fn errorHandler<Identity: windows_core::IUnknownImpl + IVBSAXXMLReader_Impl>(this: *mut core::ffi::c_void, ohandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXXMLReader_Impl::errorHandler(this) {
+                    Ok(ok__) => {
+                        ohandler.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putref_errorHandler<Identity: windows_core::IUnknownImpl + IVBSAXXMLReader_Impl>(this: *mut core::ffi::c_void, ohandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXXMLReader_Impl::putref_errorHandler(this, core::mem::transmute_copy(&ohandler)).into()
+            }
+        }
+        unsafe extern "system" fn baseURL<Identity: windows_core::IUnknownImpl + IVBSAXXMLReader_Impl>(this: *mut core::ffi::c_void, strbaseurl: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXXMLReader_Impl::baseURL(this) {
+                    Ok(ok__) => {
+                        strbaseurl.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetbaseURL<Identity: windows_core::IUnknownImpl + IVBSAXXMLReader_Impl>(this: *mut core::ffi::c_void, strbaseurl: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXXMLReader_Impl::SetbaseURL(this, core::mem::transmute(&strbaseurl)).into()
+            }
+        }
+        unsafe extern "system" fn secureBaseURL<Identity: windows_core::IUnknownImpl + IVBSAXXMLReader_Impl>(this: *mut core::ffi::c_void, strsecurebaseurl: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBSAXXMLReader_Impl::secureBaseURL(this) {
+                    Ok(ok__) => {
+                        strsecurebaseurl.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetsecureBaseURL<Identity: windows_core::IUnknownImpl + IVBSAXXMLReader_Impl>(this: *mut core::ffi::c_void, strsecurebaseurl: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXXMLReader_Impl::SetsecureBaseURL(this, core::mem::transmute(&strsecurebaseurl)).into()
+            }
+        }
+        unsafe extern "system" fn parse<Identity: windows_core::IUnknownImpl + IVBSAXXMLReader_Impl>(this: *mut core::ffi::c_void, varinput: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXXMLReader_Impl::parse(this, core::mem::transmute(&varinput)).into()
+            }
+        }
+        unsafe extern "system" fn parseURL<Identity: windows_core::IUnknownImpl + IVBSAXXMLReader_Impl>(this: *mut core::ffi::c_void, strurl: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBSAXXMLReader_Impl::parseURL(this, core::mem::transmute(&strurl)).into()
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            getFeature: getFeature::<Identity>,
+            putFeature: putFeature::<Identity>,
+            getProperty: getProperty::<Identity>,
+            putProperty: putProperty::<Identity>,
+            entityResolver: entityResolver::<Identity>,
+            putref_entityResolver: putref_entityResolver::<Identity>,
+            contentHandler: contentHandler::<Identity>,
+            putref_contentHandler: putref_contentHandler::<Identity>,
+            dtdHandler: dtdHandler::<Identity>,
+            putref_dtdHandler: putref_dtdHandler::<Identity>,
+            errorHandler: errorHandler::<Identity>,
+            putref_errorHandler: putref_errorHandler::<Identity>,
+            baseURL: baseURL::<Identity>,
+            SetbaseURL: SetbaseURL::<Identity>,
+            secureBaseURL: secureBaseURL::<Identity>,
+            SetsecureBaseURL: SetsecureBaseURL::<Identity>,
+            parse: parse::<Identity>,
+            parseURL: parseURL::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IVBSAXXMLReader {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(IXMLAttribute, IXMLAttribute_Vtbl, 0xd4d4a0fc_3b73_11d1_b2b4_00c04fb92596);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for IXMLAttribute {
+    type Target = super::super::super::System::Com::IDispatch;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(IXMLAttribute, windows_core::IUnknown, super::super::super::System::Com::IDispatch);
+#[cfg(feature = "Win32_System_Com")]
+impl IXMLAttribute {
+    pub unsafe fn name(&self) -> windows_core::Result<windows_core::BSTR> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).name)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
         }
     }
     pub unsafe fn value(&self) -> windows_core::Result<windows_core::BSTR> {
@@ -7037,6 +10325,43 @@ impl IXMLAttribute_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLAttribute_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLAttribute_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn name<Identity: windows_core::IUnknownImpl + IXMLAttribute_Impl>(this: *mut core::ffi::c_void, n: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLAttribute_Impl::name(this) {
+                    Ok(ok__) => {
+                        n.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn value<Identity: windows_core::IUnknownImpl + IXMLAttribute_Impl>(this: *mut core::ffi::c_void, v: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLAttribute_Impl::value(this) {
+                    Ok(ok__) => {
+                        v.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            name: name::<Identity>,
+            value: value::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXMLAttribute {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IXMLDOMAttribute, IXMLDOMAttribute_Vtbl, 0x2933bf85_7b36_11d2_b20e_00c04f983e60);
@@ -7135,6 +10460,50 @@ impl IXMLDOMAttribute_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMAttribute_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMAttribute_Vtbl
+where
+    IXMLDOMNode_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn name<Identity: windows_core::IUnknownImpl + IXMLDOMAttribute_Impl>(this: *mut core::ffi::c_void, attributename: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMAttribute_Impl::name(this) {
+                    Ok(ok__) => {
+                        attributename.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn value<Identity: windows_core::IUnknownImpl + IXMLDOMAttribute_Impl>(this: *mut core::ffi::c_void, attributevalue: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMAttribute_Impl::value(this) {
+                    Ok(ok__) => {
+                        attributevalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Setvalue<Identity: windows_core::IUnknownImpl + IXMLDOMAttribute_Impl>(this: *mut core::ffi::c_void, attributevalue: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMAttribute_Impl::Setvalue(this, core::mem::transmute(&attributevalue)).into()
+            }
+        }
+        Self {
+            base__: <IXMLDOMNode_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            name: name::<Identity>,
+            value: value::<Identity>,
+            Setvalue: Setvalue::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXMLDOMAttribute {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IXMLDOMCDATASection, IXMLDOMCDATASection_Vtbl, 0x2933bf8a_7b36_11d2_b20e_00c04f983e60);
@@ -7165,6 +10534,13 @@ impl IXMLDOMCDATASection_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMCDATASection_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMCDATASection_Vtbl
+where
+    IXMLDOMText_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IXMLDOMText_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXMLDOMCDATASection {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IXMLDOMCharacterData, IXMLDOMCharacterData_Vtbl, 0x2933bf84_7b36_11d2_b20e_00c04f983e60);
@@ -7324,6 +10700,91 @@ impl IXMLDOMCharacterData_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMCharacterData_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMCharacterData_Vtbl
+where
+    IXMLDOMNode_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn data<Identity: windows_core::IUnknownImpl + IXMLDOMCharacterData_Impl>(this: *mut core::ffi::c_void, data: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMCharacterData_Impl::data(this) {
+                    Ok(ok__) => {
+                        data.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Setdata<Identity: windows_core::IUnknownImpl + IXMLDOMCharacterData_Impl>(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMCharacterData_Impl::Setdata(this, core::mem::transmute(&data)).into()
+            }
+        }
+        unsafe extern "system" fn length<Identity: windows_core::IUnknownImpl + IXMLDOMCharacterData_Impl>(this: *mut core::ffi::c_void, datalength: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMCharacterData_Impl::length(this) {
+                    Ok(ok__) => {
+                        datalength.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn substringData<Identity: windows_core::IUnknownImpl + IXMLDOMCharacterData_Impl>(this: *mut core::ffi::c_void, offset: i32, count: i32, data: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMCharacterData_Impl::substringData(this, core::mem::transmute_copy(&offset), core::mem::transmute_copy(&count)) {
+                    Ok(ok__) => {
+                        data.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn appendData<Identity: windows_core::IUnknownImpl + IXMLDOMCharacterData_Impl>(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMCharacterData_Impl::appendData(this, core::mem::transmute(&data)).into()
+            }
+        }
+        unsafe extern "system" fn insertData<Identity: windows_core::IUnknownImpl + IXMLDOMCharacterData_Impl>(this: *mut core::ffi::c_void, offset: i32, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMCharacterData_Impl::insertData(this, core::mem::transmute_copy(&offset), core::mem::transmute(&data)).into()
+            }
+        }
+        unsafe extern "system" fn deleteData<Identity: windows_core::IUnknownImpl + IXMLDOMCharacterData_Impl>(this: *mut core::ffi::c_void, offset: i32, count: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMCharacterData_Impl::deleteData(this, core::mem::transmute_copy(&offset), core::mem::transmute_copy(&count)).into()
+            }
+        }
+        unsafe extern "system" fn replaceData<Identity: windows_core::IUnknownImpl + IXMLDOMCharacterData_Impl>(this: *mut core::ffi::c_void, offset: i32, count: i32, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMCharacterData_Impl::replaceData(this, core::mem::transmute_copy(&offset), core::mem::transmute_copy(&count), core::mem::transmute(&data)).into()
+            }
+        }
+        Self {
+            base__: <IXMLDOMNode_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            data: data::<Identity>,
+            Setdata: Setdata::<Identity>,
+            length: length::<Identity>,
+            substringData: substringData::<Identity>,
+            appendData: appendData::<Identity>,
+            insertData: insertData::<Identity>,
+            deleteData: deleteData::<Identity>,
+            replaceData: replaceData::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXMLDOMCharacterData {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IXMLDOMComment, IXMLDOMComment_Vtbl, 0x2933bf88_7b36_11d2_b20e_00c04f983e60);
@@ -7354,6 +10815,13 @@ impl IXMLDOMComment_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMComment_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMComment_Vtbl
+where
+    IXMLDOMCharacterData_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IXMLDOMCharacterData_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXMLDOMComment {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IXMLDOMDocument, IXMLDOMDocument_Vtbl, 0x2933bf81_7b36_11d2_b20e_00c04f983e60);
@@ -8020,680 +11488,730 @@ impl IXMLDOMDocument_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IXMLDOMDocument {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IXMLDOMDocument2, IXMLDOMDocument2_Vtbl, 0x2933bf95_7b36_11d2_b20e_00c04f983e60);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IXMLDOMDocument2 {
-    type Target = IXMLDOMDocument;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IXMLDOMDocument2, windows_core::IUnknown, super::super::super::System::Com::IDispatch, IXMLDOMNode, IXMLDOMDocument);
-#[cfg(feature = "Win32_System_Com")]
-impl IXMLDOMDocument2 {
-    pub unsafe fn namespaces(&self) -> windows_core::Result<IXMLDOMSchemaCollection> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).namespaces)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMDocument_Vtbl
+where
+    IXMLDOMNode_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn doctype<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, documenttype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument_Impl::doctype(this) {
+                    Ok(ok__) => {
+                        documenttype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub unsafe fn schemas(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).schemas)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        unsafe extern "system" fn implementation<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, r#impl: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument_Impl::implementation(this) {
+                    Ok(ok__) => {
+                        r#impl.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub unsafe fn putref_schemas(&self, othercollection: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).putref_schemas)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(othercollection)).ok() }
-    }
-    pub unsafe fn validate(&self) -> windows_core::Result<IXMLDOMParseError> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).validate)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn documentElement<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, domelement: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument_Impl::documentElement(this) {
+                    Ok(ok__) => {
+                        domelement.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub unsafe fn setProperty(&self, name: &windows_core::BSTR, value: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).setProperty)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(name), core::mem::transmute_copy(value)).ok() }
-    }
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub unsafe fn getProperty(&self, name: &windows_core::BSTR) -> windows_core::Result<super::super::super::System::Variant::VARIANT> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).getProperty)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(name), &mut result__).map(|| core::mem::transmute(result__))
+        unsafe extern "system" fn putref_documentElement<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, domelement: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMDocument_Impl::putref_documentElement(this, core::mem::transmute_copy(&domelement)).into()
+            }
         }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-#[repr(C)]
-#[doc(hidden)]
-pub struct IXMLDOMDocument2_Vtbl {
-    pub base__: IXMLDOMDocument_Vtbl,
-    pub namespaces: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub schemas: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
-    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
-    schemas: usize,
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub putref_schemas: unsafe extern "system" fn(*mut core::ffi::c_void, super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
-    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
-    putref_schemas: usize,
-    pub validate: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub setProperty: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
-    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
-    setProperty: usize,
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub getProperty: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
-    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
-    getProperty: usize,
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-pub trait IXMLDOMDocument2_Impl: IXMLDOMDocument_Impl {
-    fn namespaces(&self) -> windows_core::Result<IXMLDOMSchemaCollection>;
-    fn schemas(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT>;
-    fn putref_schemas(&self, othercollection: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()>;
-    fn validate(&self) -> windows_core::Result<IXMLDOMParseError>;
-    fn setProperty(&self, name: &windows_core::BSTR, value: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()>;
-    fn getProperty(&self, name: &windows_core::BSTR) -> windows_core::Result<super::super::super::System::Variant::VARIANT>;
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl IXMLDOMDocument2_Vtbl {
-    pub const fn new<Identity: IXMLDOMDocument2_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn namespaces<Identity: IXMLDOMDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, namespacecollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn createElement<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, tagname: *mut core::ffi::c_void, element: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMDocument2_Impl::namespaces(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument_Impl::createElement(this, core::mem::transmute(&tagname)) {
                     Ok(ok__) => {
-                        namespacecollection.write(core::mem::transmute(ok__));
+                        element.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn schemas<Identity: IXMLDOMDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, othercollection: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn createDocumentFragment<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, docfrag: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMDocument2_Impl::schemas(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument_Impl::createDocumentFragment(this) {
                     Ok(ok__) => {
-                        othercollection.write(core::mem::transmute(ok__));
+                        docfrag.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn putref_schemas<Identity: IXMLDOMDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, othercollection: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn createTextNode<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, text: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IXMLDOMDocument2_Impl::putref_schemas(this, core::mem::transmute(&othercollection)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument_Impl::createTextNode(this, core::mem::transmute(&data)) {
+                    Ok(ok__) => {
+                        text.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
             }
         }
-        unsafe extern "system" fn validate<Identity: IXMLDOMDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, errorobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn createComment<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, comment: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMDocument2_Impl::validate(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument_Impl::createComment(this, core::mem::transmute(&data)) {
                     Ok(ok__) => {
-                        errorobj.write(core::mem::transmute(ok__));
+                        comment.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn setProperty<Identity: IXMLDOMDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn createCDATASection<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, cdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IXMLDOMDocument2_Impl::setProperty(this, core::mem::transmute(&name), core::mem::transmute(&value)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument_Impl::createCDATASection(this, core::mem::transmute(&data)) {
+                    Ok(ok__) => {
+                        cdata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
             }
         }
-        unsafe extern "system" fn getProperty<Identity: IXMLDOMDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn createProcessingInstruction<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void, data: *mut core::ffi::c_void, pi: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMDocument2_Impl::getProperty(this, core::mem::transmute(&name)) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument_Impl::createProcessingInstruction(this, core::mem::transmute(&target), core::mem::transmute(&data)) {
                     Ok(ok__) => {
-                        value.write(core::mem::transmute(ok__));
+                        pi.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        Self {
-            base__: IXMLDOMDocument_Vtbl::new::<Identity, OFFSET>(),
-            namespaces: namespaces::<Identity, OFFSET>,
-            schemas: schemas::<Identity, OFFSET>,
-            putref_schemas: putref_schemas::<Identity, OFFSET>,
-            validate: validate::<Identity, OFFSET>,
-            setProperty: setProperty::<Identity, OFFSET>,
-            getProperty: getProperty::<Identity, OFFSET>,
+        unsafe extern "system" fn createAttribute<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, attribute: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument_Impl::createAttribute(this, core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        attribute.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IXMLDOMDocument2 as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID || iid == &<IXMLDOMNode as windows_core::Interface>::IID || iid == &<IXMLDOMDocument as windows_core::Interface>::IID
-    }
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IXMLDOMDocument2 {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IXMLDOMDocument3, IXMLDOMDocument3_Vtbl, 0x2933bf96_7b36_11d2_b20e_00c04f983e60);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IXMLDOMDocument3 {
-    type Target = IXMLDOMDocument2;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IXMLDOMDocument3, windows_core::IUnknown, super::super::super::System::Com::IDispatch, IXMLDOMNode, IXMLDOMDocument, IXMLDOMDocument2);
-#[cfg(feature = "Win32_System_Com")]
-impl IXMLDOMDocument3 {
-    pub unsafe fn validateNode<P0>(&self, node: P0) -> windows_core::Result<IXMLDOMParseError>
-    where
-        P0: windows_core::Param<IXMLDOMNode>,
-    {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).validateNode)(windows_core::Interface::as_raw(self), node.param().abi(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn createEntityReference<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, entityref: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument_Impl::createEntityReference(this, core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        entityref.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn importNode<P0>(&self, node: P0, deep: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::Result<IXMLDOMNode>
-    where
-        P0: windows_core::Param<IXMLDOMNode>,
-    {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).importNode)(windows_core::Interface::as_raw(self), node.param().abi(), deep, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn getElementsByTagName<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, tagname: *mut core::ffi::c_void, resultlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument_Impl::getElementsByTagName(this, core::mem::transmute(&tagname)) {
+                    Ok(ok__) => {
+                        resultlist.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-#[repr(C)]
-#[doc(hidden)]
-pub struct IXMLDOMDocument3_Vtbl {
-    pub base__: IXMLDOMDocument2_Vtbl,
-    pub validateNode: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub importNode: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, super::super::super::Foundation::VARIANT_BOOL, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-pub trait IXMLDOMDocument3_Impl: IXMLDOMDocument2_Impl {
-    fn validateNode(&self, node: windows_core::Ref<IXMLDOMNode>) -> windows_core::Result<IXMLDOMParseError>;
-    fn importNode(&self, node: windows_core::Ref<IXMLDOMNode>, deep: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::Result<IXMLDOMNode>;
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl IXMLDOMDocument3_Vtbl {
-    pub const fn new<Identity: IXMLDOMDocument3_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn validateNode<Identity: IXMLDOMDocument3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void, errorobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn createNode<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, r#type: super::super::super::System::Variant::VARIANT, name: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void, node: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMDocument3_Impl::validateNode(this, core::mem::transmute_copy(&node)) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument_Impl::createNode(this, core::mem::transmute(&r#type), core::mem::transmute(&name), core::mem::transmute(&namespaceuri)) {
                     Ok(ok__) => {
-                        errorobj.write(core::mem::transmute(ok__));
+                        node.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn importNode<Identity: IXMLDOMDocument3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void, deep: super::super::super::Foundation::VARIANT_BOOL, clone: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn nodeFromID<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, idstring: *mut core::ffi::c_void, node: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMDocument3_Impl::importNode(this, core::mem::transmute_copy(&node), core::mem::transmute_copy(&deep)) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument_Impl::nodeFromID(this, core::mem::transmute(&idstring)) {
                     Ok(ok__) => {
-                        clone.write(core::mem::transmute(ok__));
+                        node.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        Self {
-            base__: IXMLDOMDocument2_Vtbl::new::<Identity, OFFSET>(),
-            validateNode: validateNode::<Identity, OFFSET>,
-            importNode: importNode::<Identity, OFFSET>,
+        unsafe extern "system" fn load<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, xmlsource: super::super::super::System::Variant::VARIANT, issuccessful: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument_Impl::load(this, core::mem::transmute(&xmlsource)) {
+                    Ok(ok__) => {
+                        issuccessful.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IXMLDOMDocument3 as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID || iid == &<IXMLDOMNode as windows_core::Interface>::IID || iid == &<IXMLDOMDocument as windows_core::Interface>::IID || iid == &<IXMLDOMDocument2 as windows_core::Interface>::IID
-    }
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IXMLDOMDocument3 {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IXMLDOMDocumentFragment, IXMLDOMDocumentFragment_Vtbl, 0x3efaa413_272f_11d2_836f_0000f87a7782);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IXMLDOMDocumentFragment {
-    type Target = IXMLDOMNode;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IXMLDOMDocumentFragment, windows_core::IUnknown, super::super::super::System::Com::IDispatch, IXMLDOMNode);
-#[cfg(feature = "Win32_System_Com")]
-#[repr(C)]
-#[doc(hidden)]
-pub struct IXMLDOMDocumentFragment_Vtbl {
-    pub base__: IXMLDOMNode_Vtbl,
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-pub trait IXMLDOMDocumentFragment_Impl: IXMLDOMNode_Impl {}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl IXMLDOMDocumentFragment_Vtbl {
-    pub const fn new<Identity: IXMLDOMDocumentFragment_Impl, const OFFSET: isize>() -> Self {
-        Self { base__: IXMLDOMNode_Vtbl::new::<Identity, OFFSET>() }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IXMLDOMDocumentFragment as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID || iid == &<IXMLDOMNode as windows_core::Interface>::IID
-    }
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IXMLDOMDocumentFragment {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IXMLDOMDocumentType, IXMLDOMDocumentType_Vtbl, 0x2933bf8b_7b36_11d2_b20e_00c04f983e60);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IXMLDOMDocumentType {
-    type Target = IXMLDOMNode;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IXMLDOMDocumentType, windows_core::IUnknown, super::super::super::System::Com::IDispatch, IXMLDOMNode);
-#[cfg(feature = "Win32_System_Com")]
-impl IXMLDOMDocumentType {
-    pub unsafe fn name(&self) -> windows_core::Result<windows_core::BSTR> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).name)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        unsafe extern "system" fn readyState<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument_Impl::readyState(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn entities(&self) -> windows_core::Result<IXMLDOMNamedNodeMap> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).entities)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn parseError<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, errorobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument_Impl::parseError(this) {
+                    Ok(ok__) => {
+                        errorobj.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn notations(&self) -> windows_core::Result<IXMLDOMNamedNodeMap> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).notations)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn url<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, urlstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument_Impl::url(this) {
+                    Ok(ok__) => {
+                        urlstring.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-#[repr(C)]
-#[doc(hidden)]
-pub struct IXMLDOMDocumentType_Vtbl {
-    pub base__: IXMLDOMNode_Vtbl,
-    pub name: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub entities: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub notations: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-pub trait IXMLDOMDocumentType_Impl: IXMLDOMNode_Impl {
-    fn name(&self) -> windows_core::Result<windows_core::BSTR>;
-    fn entities(&self) -> windows_core::Result<IXMLDOMNamedNodeMap>;
-    fn notations(&self) -> windows_core::Result<IXMLDOMNamedNodeMap>;
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl IXMLDOMDocumentType_Vtbl {
-    pub const fn new<Identity: IXMLDOMDocumentType_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn name<Identity: IXMLDOMDocumentType_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, rootname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn r#async<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, isasync: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMDocumentType_Impl::name(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument_Impl::r#async(this) {
                     Ok(ok__) => {
-                        rootname.write(core::mem::transmute(ok__));
+                        isasync.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn entities<Identity: IXMLDOMDocumentType_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, entitymap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Setasync<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, isasync: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMDocumentType_Impl::entities(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMDocument_Impl::Setasync(this, core::mem::transmute_copy(&isasync)).into()
+            }
+        }
+        unsafe extern "system" fn abort<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMDocument_Impl::abort(this).into()
+            }
+        }
+        unsafe extern "system" fn loadXML<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, bstrxml: *mut core::ffi::c_void, issuccessful: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument_Impl::loadXML(this, core::mem::transmute(&bstrxml)) {
                     Ok(ok__) => {
-                        entitymap.write(core::mem::transmute(ok__));
+                        issuccessful.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn notations<Identity: IXMLDOMDocumentType_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, notationmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn save<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, destination: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMDocumentType_Impl::notations(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMDocument_Impl::save(this, core::mem::transmute(&destination)).into()
+            }
+        }
+        unsafe extern "system" fn validateOnParse<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, isvalidating: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument_Impl::validateOnParse(this) {
                     Ok(ok__) => {
-                        notationmap.write(core::mem::transmute(ok__));
+                        isvalidating.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        Self {
-            base__: IXMLDOMNode_Vtbl::new::<Identity, OFFSET>(),
-            name: name::<Identity, OFFSET>,
-            entities: entities::<Identity, OFFSET>,
-            notations: notations::<Identity, OFFSET>,
+        unsafe extern "system" fn SetvalidateOnParse<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, isvalidating: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMDocument_Impl::SetvalidateOnParse(this, core::mem::transmute_copy(&isvalidating)).into()
+            }
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IXMLDOMDocumentType as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID || iid == &<IXMLDOMNode as windows_core::Interface>::IID
-    }
+        unsafe extern "system" fn resolveExternals<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, isresolving: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument_Impl::resolveExternals(this) {
+                    Ok(ok__) => {
+                        isresolving.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetresolveExternals<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, isresolving: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMDocument_Impl::SetresolveExternals(this, core::mem::transmute_copy(&isresolving)).into()
+            }
+        }
+        unsafe extern "system" fn preserveWhiteSpace<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, ispreserving: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument_Impl::preserveWhiteSpace(this) {
+                    Ok(ok__) => {
+                        ispreserving.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetpreserveWhiteSpace<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, ispreserving: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMDocument_Impl::SetpreserveWhiteSpace(this, core::mem::transmute_copy(&ispreserving)).into()
+            }
+        }
+        unsafe extern "system" fn Setonreadystatechange<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, readystatechangesink: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMDocument_Impl::Setonreadystatechange(this, core::mem::transmute(&readystatechangesink)).into()
+            }
+        }
+        unsafe extern "system" fn Setondataavailable<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, ondataavailablesink: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMDocument_Impl::Setondataavailable(this, core::mem::transmute(&ondataavailablesink)).into()
+            }
+        }
+        unsafe extern "system" fn Setontransformnode<Identity: windows_core::IUnknownImpl + IXMLDOMDocument_Impl>(this: *mut core::ffi::c_void, ontransformnodesink: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMDocument_Impl::Setontransformnode(this, core::mem::transmute(&ontransformnodesink)).into()
+            }
+        }
+        Self {
+            base__: <IXMLDOMNode_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            doctype: doctype::<Identity>,
+            implementation: implementation::<Identity>,
+            documentElement: documentElement::<Identity>,
+            putref_documentElement: putref_documentElement::<Identity>,
+            createElement: createElement::<Identity>,
+            createDocumentFragment: createDocumentFragment::<Identity>,
+            createTextNode: createTextNode::<Identity>,
+            createComment: createComment::<Identity>,
+            createCDATASection: createCDATASection::<Identity>,
+            createProcessingInstruction: createProcessingInstruction::<Identity>,
+            createAttribute: createAttribute::<Identity>,
+            createEntityReference: createEntityReference::<Identity>,
+            getElementsByTagName: getElementsByTagName::<Identity>,
+            createNode: createNode::<Identity>,
+            nodeFromID: nodeFromID::<Identity>,
+            load: load::<Identity>,
+            readyState: readyState::<Identity>,
+            parseError: parseError::<Identity>,
+            url: url::<Identity>,
+            r#async: r#async::<Identity>,
+            Setasync: Setasync::<Identity>,
+            abort: abort::<Identity>,
+            loadXML: loadXML::<Identity>,
+            save: save::<Identity>,
+            validateOnParse: validateOnParse::<Identity>,
+            SetvalidateOnParse: SetvalidateOnParse::<Identity>,
+            resolveExternals: resolveExternals::<Identity>,
+            SetresolveExternals: SetresolveExternals::<Identity>,
+            preserveWhiteSpace: preserveWhiteSpace::<Identity>,
+            SetpreserveWhiteSpace: SetpreserveWhiteSpace::<Identity>,
+            Setonreadystatechange: Setonreadystatechange::<Identity>,
+            Setondataavailable: Setondataavailable::<Identity>,
+            Setontransformnode: Setontransformnode::<Identity>,
+        }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IXMLDOMDocumentType {}
+impl windows_core::RuntimeName for IXMLDOMDocument {}
 #[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IXMLDOMElement, IXMLDOMElement_Vtbl, 0x2933bf86_7b36_11d2_b20e_00c04f983e60);
+windows_core::imp::define_interface!(IXMLDOMDocument2, IXMLDOMDocument2_Vtbl, 0x2933bf95_7b36_11d2_b20e_00c04f983e60);
 #[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IXMLDOMElement {
-    type Target = IXMLDOMNode;
+impl core::ops::Deref for IXMLDOMDocument2 {
+    type Target = IXMLDOMDocument;
     fn deref(&self) -> &Self::Target {
         unsafe { core::mem::transmute(self) }
     }
 }
 #[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IXMLDOMElement, windows_core::IUnknown, super::super::super::System::Com::IDispatch, IXMLDOMNode);
+windows_core::imp::interface_hierarchy!(IXMLDOMDocument2, windows_core::IUnknown, super::super::super::System::Com::IDispatch, IXMLDOMNode, IXMLDOMDocument);
 #[cfg(feature = "Win32_System_Com")]
-impl IXMLDOMElement {
-    pub unsafe fn tagName(&self) -> windows_core::Result<windows_core::BSTR> {
+impl IXMLDOMDocument2 {
+    pub unsafe fn namespaces(&self) -> windows_core::Result<IXMLDOMSchemaCollection> {
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).tagName)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+            (windows_core::Interface::vtable(self).namespaces)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
     }
     #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub unsafe fn getAttribute(&self, name: &windows_core::BSTR) -> windows_core::Result<super::super::super::System::Variant::VARIANT> {
+    pub unsafe fn schemas(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT> {
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).getAttribute)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(name), &mut result__).map(|| core::mem::transmute(result__))
+            (windows_core::Interface::vtable(self).schemas)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
         }
     }
     #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub unsafe fn setAttribute(&self, name: &windows_core::BSTR, value: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).setAttribute)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(name), core::mem::transmute_copy(value)).ok() }
-    }
-    pub unsafe fn removeAttribute(&self, name: &windows_core::BSTR) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).removeAttribute)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(name)).ok() }
-    }
-    pub unsafe fn getAttributeNode(&self, name: &windows_core::BSTR) -> windows_core::Result<IXMLDOMAttribute> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).getAttributeNode)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(name), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
+    pub unsafe fn putref_schemas(&self, othercollection: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).putref_schemas)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(othercollection)).ok() }
     }
-    pub unsafe fn setAttributeNode<P0>(&self, domattribute: P0) -> windows_core::Result<IXMLDOMAttribute>
-    where
-        P0: windows_core::Param<IXMLDOMAttribute>,
-    {
+    pub unsafe fn validate(&self) -> windows_core::Result<IXMLDOMParseError> {
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).setAttributeNode)(windows_core::Interface::as_raw(self), domattribute.param().abi(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+            (windows_core::Interface::vtable(self).validate)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
     }
-    pub unsafe fn removeAttributeNode<P0>(&self, domattribute: P0) -> windows_core::Result<IXMLDOMAttribute>
-    where
-        P0: windows_core::Param<IXMLDOMAttribute>,
-    {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).removeAttributeNode)(windows_core::Interface::as_raw(self), domattribute.param().abi(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub unsafe fn setProperty(&self, name: &windows_core::BSTR, value: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).setProperty)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(name), core::mem::transmute_copy(value)).ok() }
     }
-    pub unsafe fn getElementsByTagName(&self, tagname: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNodeList> {
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub unsafe fn getProperty(&self, name: &windows_core::BSTR) -> windows_core::Result<super::super::super::System::Variant::VARIANT> {
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).getElementsByTagName)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(tagname), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+            (windows_core::Interface::vtable(self).getProperty)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(name), &mut result__).map(|| core::mem::transmute(result__))
         }
     }
-    pub unsafe fn normalize(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).normalize)(windows_core::Interface::as_raw(self)).ok() }
-    }
 }
 #[cfg(feature = "Win32_System_Com")]
 #[repr(C)]
 #[doc(hidden)]
-pub struct IXMLDOMElement_Vtbl {
-    pub base__: IXMLDOMNode_Vtbl,
-    pub tagName: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+pub struct IXMLDOMDocument2_Vtbl {
+    pub base__: IXMLDOMDocument_Vtbl,
+    pub namespaces: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
     #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub getAttribute: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
+    pub schemas: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
     #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
-    getAttribute: usize,
+    schemas: usize,
     #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub setAttribute: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
+    pub putref_schemas: unsafe extern "system" fn(*mut core::ffi::c_void, super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
     #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
-    setAttribute: usize,
-    pub removeAttribute: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub getAttributeNode: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub setAttributeNode: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub removeAttributeNode: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub getElementsByTagName: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub normalize: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+    putref_schemas: usize,
+    pub validate: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub setProperty: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
+    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
+    setProperty: usize,
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub getProperty: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
+    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
+    getProperty: usize,
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-pub trait IXMLDOMElement_Impl: IXMLDOMNode_Impl {
-    fn tagName(&self) -> windows_core::Result<windows_core::BSTR>;
-    fn getAttribute(&self, name: &windows_core::BSTR) -> windows_core::Result<super::super::super::System::Variant::VARIANT>;
-    fn setAttribute(&self, name: &windows_core::BSTR, value: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()>;
-    fn removeAttribute(&self, name: &windows_core::BSTR) -> windows_core::Result<()>;
-    fn getAttributeNode(&self, name: &windows_core::BSTR) -> windows_core::Result<IXMLDOMAttribute>;
-    fn setAttributeNode(&self, domattribute: windows_core::Ref<IXMLDOMAttribute>) -> windows_core::Result<IXMLDOMAttribute>;
-    fn removeAttributeNode(&self, domattribute: windows_core::Ref<IXMLDOMAttribute>) -> windows_core::Result<IXMLDOMAttribute>;
-    fn getElementsByTagName(&self, tagname: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNodeList>;
-    fn normalize(&self) -> windows_core::Result<()>;
+pub trait IXMLDOMDocument2_Impl: IXMLDOMDocument_Impl {
+    fn namespaces(&self) -> windows_core::Result<IXMLDOMSchemaCollection>;
+    fn schemas(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT>;
+    fn putref_schemas(&self, othercollection: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()>;
+    fn validate(&self) -> windows_core::Result<IXMLDOMParseError>;
+    fn setProperty(&self, name: &windows_core::BSTR, value: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()>;
+    fn getProperty(&self, name: &windows_core::BSTR) -> windows_core::Result<super::super::super::System::Variant::VARIANT>;
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl IXMLDOMElement_Vtbl {
-    pub const fn new<Identity: IXMLDOMElement_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn tagName<Identity: IXMLDOMElement_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, tagname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl IXMLDOMDocument2_Vtbl {
+    pub const fn new<Identity: IXMLDOMDocument2_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn namespaces<Identity: IXMLDOMDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, namespacecollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMElement_Impl::tagName(this) {
+                match IXMLDOMDocument2_Impl::namespaces(this) {
                     Ok(ok__) => {
-                        tagname.write(core::mem::transmute(ok__));
+                        namespacecollection.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn getAttribute<Identity: IXMLDOMElement_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn schemas<Identity: IXMLDOMDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, othercollection: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMElement_Impl::getAttribute(this, core::mem::transmute(&name)) {
+                match IXMLDOMDocument2_Impl::schemas(this) {
                     Ok(ok__) => {
-                        value.write(core::mem::transmute(ok__));
+                        othercollection.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn setAttribute<Identity: IXMLDOMElement_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn putref_schemas<Identity: IXMLDOMDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, othercollection: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IXMLDOMElement_Impl::setAttribute(this, core::mem::transmute(&name), core::mem::transmute(&value)).into()
+                IXMLDOMDocument2_Impl::putref_schemas(this, core::mem::transmute(&othercollection)).into()
             }
         }
-        unsafe extern "system" fn removeAttribute<Identity: IXMLDOMElement_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn validate<Identity: IXMLDOMDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, errorobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IXMLDOMElement_Impl::removeAttribute(this, core::mem::transmute(&name)).into()
+                match IXMLDOMDocument2_Impl::validate(this) {
+                    Ok(ok__) => {
+                        errorobj.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
             }
         }
-        unsafe extern "system" fn getAttributeNode<Identity: IXMLDOMElement_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, attributenode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn setProperty<Identity: IXMLDOMDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMElement_Impl::getAttributeNode(this, core::mem::transmute(&name)) {
+                IXMLDOMDocument2_Impl::setProperty(this, core::mem::transmute(&name), core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn getProperty<Identity: IXMLDOMDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMDocument2_Impl::getProperty(this, core::mem::transmute(&name)) {
                     Ok(ok__) => {
-                        attributenode.write(core::mem::transmute(ok__));
+                        value.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn setAttributeNode<Identity: IXMLDOMElement_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, domattribute: *mut core::ffi::c_void, attributenode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMElement_Impl::setAttributeNode(this, core::mem::transmute_copy(&domattribute)) {
+        Self {
+            base__: IXMLDOMDocument_Vtbl::new::<Identity, OFFSET>(),
+            namespaces: namespaces::<Identity, OFFSET>,
+            schemas: schemas::<Identity, OFFSET>,
+            putref_schemas: putref_schemas::<Identity, OFFSET>,
+            validate: validate::<Identity, OFFSET>,
+            setProperty: setProperty::<Identity, OFFSET>,
+            getProperty: getProperty::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMDocument2 as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID || iid == &<IXMLDOMNode as windows_core::Interface>::IID || iid == &<IXMLDOMDocument as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMDocument2_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMDocument2_Vtbl
+where
+    IXMLDOMDocument_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn namespaces<Identity: windows_core::IUnknownImpl + IXMLDOMDocument2_Impl>(this: *mut core::ffi::c_void, namespacecollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument2_Impl::namespaces(this) {
                     Ok(ok__) => {
-                        attributenode.write(core::mem::transmute(ok__));
+                        namespacecollection.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn removeAttributeNode<Identity: IXMLDOMElement_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, domattribute: *mut core::ffi::c_void, attributenode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn schemas<Identity: windows_core::IUnknownImpl + IXMLDOMDocument2_Impl>(this: *mut core::ffi::c_void, othercollection: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMElement_Impl::removeAttributeNode(this, core::mem::transmute_copy(&domattribute)) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument2_Impl::schemas(this) {
                     Ok(ok__) => {
-                        attributenode.write(core::mem::transmute(ok__));
+                        othercollection.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn getElementsByTagName<Identity: IXMLDOMElement_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, tagname: *mut core::ffi::c_void, resultlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn putref_schemas<Identity: windows_core::IUnknownImpl + IXMLDOMDocument2_Impl>(this: *mut core::ffi::c_void, othercollection: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMElement_Impl::getElementsByTagName(this, core::mem::transmute(&tagname)) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMDocument2_Impl::putref_schemas(this, core::mem::transmute(&othercollection)).into()
+            }
+        }
+        unsafe extern "system" fn validate<Identity: windows_core::IUnknownImpl + IXMLDOMDocument2_Impl>(this: *mut core::ffi::c_void, errorobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument2_Impl::validate(this) {
                     Ok(ok__) => {
-                        resultlist.write(core::mem::transmute(ok__));
+                        errorobj.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn normalize<Identity: IXMLDOMElement_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn setProperty<Identity: windows_core::IUnknownImpl + IXMLDOMDocument2_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IXMLDOMElement_Impl::normalize(this).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMDocument2_Impl::setProperty(this, core::mem::transmute(&name), core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn getProperty<Identity: windows_core::IUnknownImpl + IXMLDOMDocument2_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument2_Impl::getProperty(this, core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
             }
         }
         Self {
-            base__: IXMLDOMNode_Vtbl::new::<Identity, OFFSET>(),
-            tagName: tagName::<Identity, OFFSET>,
-            getAttribute: getAttribute::<Identity, OFFSET>,
-            setAttribute: setAttribute::<Identity, OFFSET>,
-            removeAttribute: removeAttribute::<Identity, OFFSET>,
-            getAttributeNode: getAttributeNode::<Identity, OFFSET>,
-            setAttributeNode: setAttributeNode::<Identity, OFFSET>,
-            removeAttributeNode: removeAttributeNode::<Identity, OFFSET>,
-            getElementsByTagName: getElementsByTagName::<Identity, OFFSET>,
-            normalize: normalize::<Identity, OFFSET>,
+            base__: <IXMLDOMDocument_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            namespaces: namespaces::<Identity>,
+            schemas: schemas::<Identity>,
+            putref_schemas: putref_schemas::<Identity>,
+            validate: validate::<Identity>,
+            setProperty: setProperty::<Identity>,
+            getProperty: getProperty::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IXMLDOMElement as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID || iid == &<IXMLDOMNode as windows_core::Interface>::IID
-    }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IXMLDOMElement {}
+impl windows_core::RuntimeName for IXMLDOMDocument2 {}
 #[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IXMLDOMEntity, IXMLDOMEntity_Vtbl, 0x2933bf8d_7b36_11d2_b20e_00c04f983e60);
+windows_core::imp::define_interface!(IXMLDOMDocument3, IXMLDOMDocument3_Vtbl, 0x2933bf96_7b36_11d2_b20e_00c04f983e60);
 #[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IXMLDOMEntity {
-    type Target = IXMLDOMNode;
+impl core::ops::Deref for IXMLDOMDocument3 {
+    type Target = IXMLDOMDocument2;
     fn deref(&self) -> &Self::Target {
         unsafe { core::mem::transmute(self) }
     }
 }
 #[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IXMLDOMEntity, windows_core::IUnknown, super::super::super::System::Com::IDispatch, IXMLDOMNode);
+windows_core::imp::interface_hierarchy!(IXMLDOMDocument3, windows_core::IUnknown, super::super::super::System::Com::IDispatch, IXMLDOMNode, IXMLDOMDocument, IXMLDOMDocument2);
 #[cfg(feature = "Win32_System_Com")]
-impl IXMLDOMEntity {
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub unsafe fn publicId(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).publicId)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
-        }
-    }
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub unsafe fn systemId(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT> {
+impl IXMLDOMDocument3 {
+    pub unsafe fn validateNode<P0>(&self, node: P0) -> windows_core::Result<IXMLDOMParseError>
+    where
+        P0: windows_core::Param<IXMLDOMNode>,
+    {
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).systemId)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+            (windows_core::Interface::vtable(self).validateNode)(windows_core::Interface::as_raw(self), node.param().abi(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
     }
-    pub unsafe fn notationName(&self) -> windows_core::Result<windows_core::BSTR> {
+    pub unsafe fn importNode<P0>(&self, node: P0, deep: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::Result<IXMLDOMNode>
+    where
+        P0: windows_core::Param<IXMLDOMNode>,
+    {
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).notationName)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+            (windows_core::Interface::vtable(self).importNode)(windows_core::Interface::as_raw(self), node.param().abi(), deep, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
     }
 }
 #[cfg(feature = "Win32_System_Com")]
 #[repr(C)]
 #[doc(hidden)]
-pub struct IXMLDOMEntity_Vtbl {
-    pub base__: IXMLDOMNode_Vtbl,
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub publicId: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
-    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
-    publicId: usize,
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub systemId: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
-    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
-    systemId: usize,
-    pub notationName: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+pub struct IXMLDOMDocument3_Vtbl {
+    pub base__: IXMLDOMDocument2_Vtbl,
+    pub validateNode: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub importNode: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, super::super::super::Foundation::VARIANT_BOOL, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-pub trait IXMLDOMEntity_Impl: IXMLDOMNode_Impl {
-    fn publicId(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT>;
-    fn systemId(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT>;
-    fn notationName(&self) -> windows_core::Result<windows_core::BSTR>;
+pub trait IXMLDOMDocument3_Impl: IXMLDOMDocument2_Impl {
+    fn validateNode(&self, node: windows_core::Ref<IXMLDOMNode>) -> windows_core::Result<IXMLDOMParseError>;
+    fn importNode(&self, node: windows_core::Ref<IXMLDOMNode>, deep: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::Result<IXMLDOMNode>;
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl IXMLDOMEntity_Vtbl {
-    pub const fn new<Identity: IXMLDOMEntity_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn publicId<Identity: IXMLDOMEntity_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, publicid: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+impl IXMLDOMDocument3_Vtbl {
+    pub const fn new<Identity: IXMLDOMDocument3_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn validateNode<Identity: IXMLDOMDocument3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void, errorobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMEntity_Impl::publicId(this) {
+                match IXMLDOMDocument3_Impl::validateNode(this, core::mem::transmute_copy(&node)) {
                     Ok(ok__) => {
-                        publicid.write(core::mem::transmute(ok__));
+                        errorobj.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn systemId<Identity: IXMLDOMEntity_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, systemid: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn importNode<Identity: IXMLDOMDocument3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void, deep: super::super::super::Foundation::VARIANT_BOOL, clone: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMEntity_Impl::systemId(this) {
+                match IXMLDOMDocument3_Impl::importNode(this, core::mem::transmute_copy(&node), core::mem::transmute_copy(&deep)) {
                     Ok(ok__) => {
-                        systemid.write(core::mem::transmute(ok__));
+                        clone.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn notationName<Identity: IXMLDOMEntity_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        Self {
+            base__: IXMLDOMDocument2_Vtbl::new::<Identity, OFFSET>(),
+            validateNode: validateNode::<Identity, OFFSET>,
+            importNode: importNode::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMDocument3 as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID || iid == &<IXMLDOMNode as windows_core::Interface>::IID || iid == &<IXMLDOMDocument as windows_core::Interface>::IID || iid == &<IXMLDOMDocument2 as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMDocument3_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMDocument3_Vtbl
+where
+    IXMLDOMDocument2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn validateNode<Identity: windows_core::IUnknownImpl + IXMLDOMDocument3_Impl>(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void, errorobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument3_Impl::validateNode(this, core::mem::transmute_copy(&node)) {
+                    Ok(ok__) => {
+                        errorobj.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn importNode<Identity: windows_core::IUnknownImpl + IXMLDOMDocument3_Impl>(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void, deep: super::super::super::Foundation::VARIANT_BOOL, clone: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMEntity_Impl::notationName(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocument3_Impl::importNode(this, core::mem::transmute_copy(&node), core::mem::transmute_copy(&deep)) {
                     Ok(ok__) => {
-                        name.write(core::mem::transmute(ok__));
+                        clone.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
@@ -8701,316 +12219,184 @@ impl IXMLDOMEntity_Vtbl {
             }
         }
         Self {
-            base__: IXMLDOMNode_Vtbl::new::<Identity, OFFSET>(),
-            publicId: publicId::<Identity, OFFSET>,
-            systemId: systemId::<Identity, OFFSET>,
-            notationName: notationName::<Identity, OFFSET>,
+            base__: <IXMLDOMDocument2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            validateNode: validateNode::<Identity>,
+            importNode: importNode::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IXMLDOMEntity as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID || iid == &<IXMLDOMNode as windows_core::Interface>::IID
-    }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IXMLDOMEntity {}
+impl windows_core::RuntimeName for IXMLDOMDocument3 {}
 #[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IXMLDOMEntityReference, IXMLDOMEntityReference_Vtbl, 0x2933bf8e_7b36_11d2_b20e_00c04f983e60);
+windows_core::imp::define_interface!(IXMLDOMDocumentFragment, IXMLDOMDocumentFragment_Vtbl, 0x3efaa413_272f_11d2_836f_0000f87a7782);
 #[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IXMLDOMEntityReference {
+impl core::ops::Deref for IXMLDOMDocumentFragment {
     type Target = IXMLDOMNode;
     fn deref(&self) -> &Self::Target {
         unsafe { core::mem::transmute(self) }
     }
 }
 #[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IXMLDOMEntityReference, windows_core::IUnknown, super::super::super::System::Com::IDispatch, IXMLDOMNode);
+windows_core::imp::interface_hierarchy!(IXMLDOMDocumentFragment, windows_core::IUnknown, super::super::super::System::Com::IDispatch, IXMLDOMNode);
 #[cfg(feature = "Win32_System_Com")]
 #[repr(C)]
 #[doc(hidden)]
-pub struct IXMLDOMEntityReference_Vtbl {
+pub struct IXMLDOMDocumentFragment_Vtbl {
     pub base__: IXMLDOMNode_Vtbl,
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-pub trait IXMLDOMEntityReference_Impl: IXMLDOMNode_Impl {}
+pub trait IXMLDOMDocumentFragment_Impl: IXMLDOMNode_Impl {}
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl IXMLDOMEntityReference_Vtbl {
-    pub const fn new<Identity: IXMLDOMEntityReference_Impl, const OFFSET: isize>() -> Self {
+impl IXMLDOMDocumentFragment_Vtbl {
+    pub const fn new<Identity: IXMLDOMDocumentFragment_Impl, const OFFSET: isize>() -> Self {
         Self { base__: IXMLDOMNode_Vtbl::new::<Identity, OFFSET>() }
     }
     pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IXMLDOMEntityReference as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID || iid == &<IXMLDOMNode as windows_core::Interface>::IID
-    }
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IXMLDOMEntityReference {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IXMLDOMImplementation, IXMLDOMImplementation_Vtbl, 0x2933bf8f_7b36_11d2_b20e_00c04f983e60);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IXMLDOMImplementation {
-    type Target = super::super::super::System::Com::IDispatch;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IXMLDOMImplementation, windows_core::IUnknown, super::super::super::System::Com::IDispatch);
-#[cfg(feature = "Win32_System_Com")]
-impl IXMLDOMImplementation {
-    pub unsafe fn hasFeature(&self, feature: &windows_core::BSTR, version: &windows_core::BSTR) -> windows_core::Result<super::super::super::Foundation::VARIANT_BOOL> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).hasFeature)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(feature), core::mem::transmute_copy(version), &mut result__).map(|| result__)
-        }
+        iid == &<IXMLDOMDocumentFragment as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID || iid == &<IXMLDOMNode as windows_core::Interface>::IID
     }
 }
-#[cfg(feature = "Win32_System_Com")]
-#[repr(C)]
-#[doc(hidden)]
-pub struct IXMLDOMImplementation_Vtbl {
-    pub base__: super::super::super::System::Com::IDispatch_Vtbl,
-    pub hasFeature: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void, *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT,
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-pub trait IXMLDOMImplementation_Impl: super::super::super::System::Com::IDispatch_Impl {
-    fn hasFeature(&self, feature: &windows_core::BSTR, version: &windows_core::BSTR) -> windows_core::Result<super::super::super::Foundation::VARIANT_BOOL>;
-}
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl IXMLDOMImplementation_Vtbl {
-    pub const fn new<Identity: IXMLDOMImplementation_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn hasFeature<Identity: IXMLDOMImplementation_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, feature: *mut core::ffi::c_void, version: *mut core::ffi::c_void, hasfeature: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMImplementation_Impl::hasFeature(this, core::mem::transmute(&feature), core::mem::transmute(&version)) {
-                    Ok(ok__) => {
-                        hasfeature.write(core::mem::transmute(ok__));
-                        windows_core::HRESULT(0)
-                    }
-                    Err(err) => err.into(),
-                }
-            }
-        }
-        Self { base__: super::super::super::System::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(), hasFeature: hasFeature::<Identity, OFFSET> }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IXMLDOMImplementation as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID
-    }
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMDocumentFragment_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMDocumentFragment_Vtbl
+where
+    IXMLDOMNode_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IXMLDOMNode_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IXMLDOMImplementation {}
+impl windows_core::RuntimeName for IXMLDOMDocumentFragment {}
 #[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IXMLDOMNamedNodeMap, IXMLDOMNamedNodeMap_Vtbl, 0x2933bf83_7b36_11d2_b20e_00c04f983e60);
+windows_core::imp::define_interface!(IXMLDOMDocumentType, IXMLDOMDocumentType_Vtbl, 0x2933bf8b_7b36_11d2_b20e_00c04f983e60);
 #[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IXMLDOMNamedNodeMap {
-    type Target = super::super::super::System::Com::IDispatch;
+impl core::ops::Deref for IXMLDOMDocumentType {
+    type Target = IXMLDOMNode;
     fn deref(&self) -> &Self::Target {
         unsafe { core::mem::transmute(self) }
     }
 }
 #[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IXMLDOMNamedNodeMap, windows_core::IUnknown, super::super::super::System::Com::IDispatch);
+windows_core::imp::interface_hierarchy!(IXMLDOMDocumentType, windows_core::IUnknown, super::super::super::System::Com::IDispatch, IXMLDOMNode);
 #[cfg(feature = "Win32_System_Com")]
-impl IXMLDOMNamedNodeMap {
-    pub unsafe fn getNamedItem(&self, name: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNode> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).getNamedItem)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(name), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn setNamedItem<P0>(&self, newitem: P0) -> windows_core::Result<IXMLDOMNode>
-    where
-        P0: windows_core::Param<IXMLDOMNode>,
-    {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).setNamedItem)(windows_core::Interface::as_raw(self), newitem.param().abi(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn removeNamedItem(&self, name: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNode> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).removeNamedItem)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(name), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn get_item(&self, index: i32) -> windows_core::Result<IXMLDOMNode> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).get_item)(windows_core::Interface::as_raw(self), index, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn length(&self) -> windows_core::Result<i32> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).length)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
-        }
-    }
-    pub unsafe fn getQualifiedItem(&self, basename: &windows_core::BSTR, namespaceuri: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNode> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).getQualifiedItem)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(basename), core::mem::transmute_copy(namespaceuri), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn removeQualifiedItem(&self, basename: &windows_core::BSTR, namespaceuri: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNode> {
+impl IXMLDOMDocumentType {
+    pub unsafe fn name(&self) -> windows_core::Result<windows_core::BSTR> {
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).removeQualifiedItem)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(basename), core::mem::transmute_copy(namespaceuri), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+            (windows_core::Interface::vtable(self).name)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
         }
     }
-    pub unsafe fn nextNode(&self) -> windows_core::Result<IXMLDOMNode> {
+    pub unsafe fn entities(&self) -> windows_core::Result<IXMLDOMNamedNodeMap> {
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).nextNode)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+            (windows_core::Interface::vtable(self).entities)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
     }
-    pub unsafe fn reset(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).reset)(windows_core::Interface::as_raw(self)).ok() }
-    }
-    pub unsafe fn _newEnum(&self) -> windows_core::Result<windows_core::IUnknown> {
+    pub unsafe fn notations(&self) -> windows_core::Result<IXMLDOMNamedNodeMap> {
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self)._newEnum)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+            (windows_core::Interface::vtable(self).notations)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
     }
 }
 #[cfg(feature = "Win32_System_Com")]
 #[repr(C)]
 #[doc(hidden)]
-pub struct IXMLDOMNamedNodeMap_Vtbl {
-    pub base__: super::super::super::System::Com::IDispatch_Vtbl,
-    pub getNamedItem: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub setNamedItem: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub removeNamedItem: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub get_item: unsafe extern "system" fn(*mut core::ffi::c_void, i32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub length: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
-    pub getQualifiedItem: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub removeQualifiedItem: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub nextNode: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub reset: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub _newEnum: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+pub struct IXMLDOMDocumentType_Vtbl {
+    pub base__: IXMLDOMNode_Vtbl,
+    pub name: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub entities: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub notations: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-pub trait IXMLDOMNamedNodeMap_Impl: super::super::super::System::Com::IDispatch_Impl {
-    fn getNamedItem(&self, name: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNode>;
-    fn setNamedItem(&self, newitem: windows_core::Ref<IXMLDOMNode>) -> windows_core::Result<IXMLDOMNode>;
-    fn removeNamedItem(&self, name: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNode>;
-    fn get_item(&self, index: i32) -> windows_core::Result<IXMLDOMNode>;
-    fn length(&self) -> windows_core::Result<i32>;
-    fn getQualifiedItem(&self, basename: &windows_core::BSTR, namespaceuri: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNode>;
-    fn removeQualifiedItem(&self, basename: &windows_core::BSTR, namespaceuri: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNode>;
-    fn nextNode(&self) -> windows_core::Result<IXMLDOMNode>;
-    fn reset(&self) -> windows_core::Result<()>;
-    fn _newEnum(&self) -> windows_core::Result<windows_core::IUnknown>;
+pub trait IXMLDOMDocumentType_Impl: IXMLDOMNode_Impl {
+    fn name(&self) -> windows_core::Result<windows_core::BSTR>;
+    fn entities(&self) -> windows_core::Result<IXMLDOMNamedNodeMap>;
+    fn notations(&self) -> windows_core::Result<IXMLDOMNamedNodeMap>;
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl IXMLDOMNamedNodeMap_Vtbl {
-    pub const fn new<Identity: IXMLDOMNamedNodeMap_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn getNamedItem<Identity: IXMLDOMNamedNodeMap_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, nameditem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMNamedNodeMap_Impl::getNamedItem(this, core::mem::transmute(&name)) {
-                    Ok(ok__) => {
-                        nameditem.write(core::mem::transmute(ok__));
-                        windows_core::HRESULT(0)
-                    }
-                    Err(err) => err.into(),
-                }
-            }
-        }
-        unsafe extern "system" fn setNamedItem<Identity: IXMLDOMNamedNodeMap_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, newitem: *mut core::ffi::c_void, nameitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMNamedNodeMap_Impl::setNamedItem(this, core::mem::transmute_copy(&newitem)) {
-                    Ok(ok__) => {
-                        nameitem.write(core::mem::transmute(ok__));
-                        windows_core::HRESULT(0)
-                    }
-                    Err(err) => err.into(),
-                }
-            }
-        }
-        unsafe extern "system" fn removeNamedItem<Identity: IXMLDOMNamedNodeMap_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, nameditem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl IXMLDOMDocumentType_Vtbl {
+    pub const fn new<Identity: IXMLDOMDocumentType_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn name<Identity: IXMLDOMDocumentType_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, rootname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMNamedNodeMap_Impl::removeNamedItem(this, core::mem::transmute(&name)) {
+                match IXMLDOMDocumentType_Impl::name(this) {
                     Ok(ok__) => {
-                        nameditem.write(core::mem::transmute(ok__));
+                        rootname.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn get_item<Identity: IXMLDOMNamedNodeMap_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, index: i32, listitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn entities<Identity: IXMLDOMDocumentType_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, entitymap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMNamedNodeMap_Impl::get_item(this, core::mem::transmute_copy(&index)) {
+                match IXMLDOMDocumentType_Impl::entities(this) {
                     Ok(ok__) => {
-                        listitem.write(core::mem::transmute(ok__));
+                        entitymap.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn length<Identity: IXMLDOMNamedNodeMap_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, listlength: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn notations<Identity: IXMLDOMDocumentType_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, notationmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMNamedNodeMap_Impl::length(this) {
+                match IXMLDOMDocumentType_Impl::notations(this) {
                     Ok(ok__) => {
-                        listlength.write(core::mem::transmute(ok__));
+                        notationmap.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn getQualifiedItem<Identity: IXMLDOMNamedNodeMap_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, basename: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void, qualifieditem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMNamedNodeMap_Impl::getQualifiedItem(this, core::mem::transmute(&basename), core::mem::transmute(&namespaceuri)) {
-                    Ok(ok__) => {
-                        qualifieditem.write(core::mem::transmute(ok__));
-                        windows_core::HRESULT(0)
-                    }
-                    Err(err) => err.into(),
-                }
-            }
+        Self {
+            base__: IXMLDOMNode_Vtbl::new::<Identity, OFFSET>(),
+            name: name::<Identity, OFFSET>,
+            entities: entities::<Identity, OFFSET>,
+            notations: notations::<Identity, OFFSET>,
         }
-        unsafe extern "system" fn removeQualifiedItem<Identity: IXMLDOMNamedNodeMap_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, basename: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void, qualifieditem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMNamedNodeMap_Impl::removeQualifiedItem(this, core::mem::transmute(&basename), core::mem::transmute(&namespaceuri)) {
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMDocumentType as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID || iid == &<IXMLDOMNode as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMDocumentType_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMDocumentType_Vtbl
+where
+    IXMLDOMNode_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn name<Identity: windows_core::IUnknownImpl + IXMLDOMDocumentType_Impl>(this: *mut core::ffi::c_void, rootname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocumentType_Impl::name(this) {
                     Ok(ok__) => {
-                        qualifieditem.write(core::mem::transmute(ok__));
+                        rootname.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn nextNode<Identity: IXMLDOMNamedNodeMap_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, nextitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn entities<Identity: windows_core::IUnknownImpl + IXMLDOMDocumentType_Impl>(this: *mut core::ffi::c_void, entitymap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMNamedNodeMap_Impl::nextNode(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocumentType_Impl::entities(this) {
                     Ok(ok__) => {
-                        nextitem.write(core::mem::transmute(ok__));
+                        entitymap.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn reset<Identity: IXMLDOMNamedNodeMap_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IXMLDOMNamedNodeMap_Impl::reset(this).into()
-            }
-        }
-        unsafe extern "system" fn _newEnum<Identity: IXMLDOMNamedNodeMap_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn notations<Identity: windows_core::IUnknownImpl + IXMLDOMDocumentType_Impl>(this: *mut core::ffi::c_void, notationmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IXMLDOMNamedNodeMap_Impl::_newEnum(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMDocumentType_Impl::notations(this) {
                     Ok(ok__) => {
-                        ppunk.write(core::mem::transmute(ok__));
+                        notationmap.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
@@ -9018,374 +12404,1785 @@ impl IXMLDOMNamedNodeMap_Vtbl {
             }
         }
         Self {
-            base__: super::super::super::System::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
-            getNamedItem: getNamedItem::<Identity, OFFSET>,
-            setNamedItem: setNamedItem::<Identity, OFFSET>,
-            removeNamedItem: removeNamedItem::<Identity, OFFSET>,
-            get_item: get_item::<Identity, OFFSET>,
-            length: length::<Identity, OFFSET>,
-            getQualifiedItem: getQualifiedItem::<Identity, OFFSET>,
-            removeQualifiedItem: removeQualifiedItem::<Identity, OFFSET>,
-            nextNode: nextNode::<Identity, OFFSET>,
-            reset: reset::<Identity, OFFSET>,
-            _newEnum: _newEnum::<Identity, OFFSET>,
+            base__: <IXMLDOMNode_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            name: name::<Identity>,
+            entities: entities::<Identity>,
+            notations: notations::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IXMLDOMNamedNodeMap as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID
-    }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IXMLDOMNamedNodeMap {}
+impl windows_core::RuntimeName for IXMLDOMDocumentType {}
 #[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IXMLDOMNode, IXMLDOMNode_Vtbl, 0x2933bf80_7b36_11d2_b20e_00c04f983e60);
+windows_core::imp::define_interface!(IXMLDOMElement, IXMLDOMElement_Vtbl, 0x2933bf86_7b36_11d2_b20e_00c04f983e60);
 #[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IXMLDOMNode {
-    type Target = super::super::super::System::Com::IDispatch;
+impl core::ops::Deref for IXMLDOMElement {
+    type Target = IXMLDOMNode;
     fn deref(&self) -> &Self::Target {
         unsafe { core::mem::transmute(self) }
     }
 }
 #[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IXMLDOMNode, windows_core::IUnknown, super::super::super::System::Com::IDispatch);
+windows_core::imp::interface_hierarchy!(IXMLDOMElement, windows_core::IUnknown, super::super::super::System::Com::IDispatch, IXMLDOMNode);
 #[cfg(feature = "Win32_System_Com")]
-impl IXMLDOMNode {
-    pub unsafe fn nodeName(&self) -> windows_core::Result<windows_core::BSTR> {
+impl IXMLDOMElement {
+    pub unsafe fn tagName(&self) -> windows_core::Result<windows_core::BSTR> {
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).nodeName)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+            (windows_core::Interface::vtable(self).tagName)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
         }
     }
     #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub unsafe fn nodeValue(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT> {
+    pub unsafe fn getAttribute(&self, name: &windows_core::BSTR) -> windows_core::Result<super::super::super::System::Variant::VARIANT> {
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).nodeValue)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+            (windows_core::Interface::vtable(self).getAttribute)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(name), &mut result__).map(|| core::mem::transmute(result__))
         }
     }
     #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub unsafe fn SetnodeValue(&self, value: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetnodeValue)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(value)).ok() }
-    }
-    pub unsafe fn nodeType(&self) -> windows_core::Result<DOMNodeType> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).nodeType)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
-        }
-    }
-    pub unsafe fn parentNode(&self) -> windows_core::Result<IXMLDOMNode> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).parentNode)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn childNodes(&self) -> windows_core::Result<IXMLDOMNodeList> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).childNodes)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn firstChild(&self) -> windows_core::Result<IXMLDOMNode> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).firstChild)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn lastChild(&self) -> windows_core::Result<IXMLDOMNode> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).lastChild)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn previousSibling(&self) -> windows_core::Result<IXMLDOMNode> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).previousSibling)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
+    pub unsafe fn setAttribute(&self, name: &windows_core::BSTR, value: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).setAttribute)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(name), core::mem::transmute_copy(value)).ok() }
     }
-    pub unsafe fn nextSibling(&self) -> windows_core::Result<IXMLDOMNode> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).nextSibling)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
+    pub unsafe fn removeAttribute(&self, name: &windows_core::BSTR) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).removeAttribute)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(name)).ok() }
     }
-    pub unsafe fn attributes(&self) -> windows_core::Result<IXMLDOMNamedNodeMap> {
+    pub unsafe fn getAttributeNode(&self, name: &windows_core::BSTR) -> windows_core::Result<IXMLDOMAttribute> {
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).attributes)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+            (windows_core::Interface::vtable(self).getAttributeNode)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(name), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
     }
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub unsafe fn insertBefore<P0>(&self, newchild: P0, refchild: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<IXMLDOMNode>
+    pub unsafe fn setAttributeNode<P0>(&self, domattribute: P0) -> windows_core::Result<IXMLDOMAttribute>
     where
-        P0: windows_core::Param<IXMLDOMNode>,
+        P0: windows_core::Param<IXMLDOMAttribute>,
     {
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).insertBefore)(windows_core::Interface::as_raw(self), newchild.param().abi(), core::mem::transmute_copy(refchild), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+            (windows_core::Interface::vtable(self).setAttributeNode)(windows_core::Interface::as_raw(self), domattribute.param().abi(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
     }
-    pub unsafe fn replaceChild<P0, P1>(&self, newchild: P0, oldchild: P1) -> windows_core::Result<IXMLDOMNode>
+    pub unsafe fn removeAttributeNode<P0>(&self, domattribute: P0) -> windows_core::Result<IXMLDOMAttribute>
     where
-        P0: windows_core::Param<IXMLDOMNode>,
-        P1: windows_core::Param<IXMLDOMNode>,
+        P0: windows_core::Param<IXMLDOMAttribute>,
     {
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).replaceChild)(windows_core::Interface::as_raw(self), newchild.param().abi(), oldchild.param().abi(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+            (windows_core::Interface::vtable(self).removeAttributeNode)(windows_core::Interface::as_raw(self), domattribute.param().abi(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
     }
-    pub unsafe fn removeChild<P0>(&self, childnode: P0) -> windows_core::Result<IXMLDOMNode>
-    where
-        P0: windows_core::Param<IXMLDOMNode>,
-    {
+    pub unsafe fn getElementsByTagName(&self, tagname: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNodeList> {
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).removeChild)(windows_core::Interface::as_raw(self), childnode.param().abi(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+            (windows_core::Interface::vtable(self).getElementsByTagName)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(tagname), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
     }
-    pub unsafe fn appendChild<P0>(&self, newchild: P0) -> windows_core::Result<IXMLDOMNode>
-    where
-        P0: windows_core::Param<IXMLDOMNode>,
-    {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).appendChild)(windows_core::Interface::as_raw(self), newchild.param().abi(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
+    pub unsafe fn normalize(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).normalize)(windows_core::Interface::as_raw(self)).ok() }
     }
-    pub unsafe fn hasChildNodes(&self) -> windows_core::Result<super::super::super::Foundation::VARIANT_BOOL> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).hasChildNodes)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+}
+#[cfg(feature = "Win32_System_Com")]
+#[repr(C)]
+#[doc(hidden)]
+pub struct IXMLDOMElement_Vtbl {
+    pub base__: IXMLDOMNode_Vtbl,
+    pub tagName: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub getAttribute: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
+    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
+    getAttribute: usize,
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub setAttribute: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
+    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
+    setAttribute: usize,
+    pub removeAttribute: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub getAttributeNode: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub setAttributeNode: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub removeAttributeNode: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub getElementsByTagName: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub normalize: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+pub trait IXMLDOMElement_Impl: IXMLDOMNode_Impl {
+    fn tagName(&self) -> windows_core::Result<windows_core::BSTR>;
+    fn getAttribute(&self, name: &windows_core::BSTR) -> windows_core::Result<super::super::super::System::Variant::VARIANT>;
+    fn setAttribute(&self, name: &windows_core::BSTR, value: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()>;
+    fn removeAttribute(&self, name: &windows_core::BSTR) -> windows_core::Result<()>;
+    fn getAttributeNode(&self, name: &windows_core::BSTR) -> windows_core::Result<IXMLDOMAttribute>;
+    fn setAttributeNode(&self, domattribute: windows_core::Ref<IXMLDOMAttribute>) -> windows_core::Result<IXMLDOMAttribute>;
+    fn removeAttributeNode(&self, domattribute: windows_core::Ref<IXMLDOMAttribute>) -> windows_core::Result<IXMLDOMAttribute>;
+    fn getElementsByTagName(&self, tagname: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNodeList>;
+    fn normalize(&self) -> windows_core::Result<()>;
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl IXMLDOMElement_Vtbl {
+    pub const fn new<Identity: IXMLDOMElement_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn tagName<Identity: IXMLDOMElement_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, tagname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMElement_Impl::tagName(this) {
+                    Ok(ok__) => {
+                        tagname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn ownerDocument(&self) -> windows_core::Result<IXMLDOMDocument> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).ownerDocument)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn getAttribute<Identity: IXMLDOMElement_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMElement_Impl::getAttribute(this, core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn cloneNode(&self, deep: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::Result<IXMLDOMNode> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).cloneNode)(windows_core::Interface::as_raw(self), deep, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn setAttribute<Identity: IXMLDOMElement_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IXMLDOMElement_Impl::setAttribute(this, core::mem::transmute(&name), core::mem::transmute(&value)).into()
+            }
         }
-    }
-    pub unsafe fn nodeTypeString(&self) -> windows_core::Result<windows_core::BSTR> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).nodeTypeString)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        unsafe extern "system" fn removeAttribute<Identity: IXMLDOMElement_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IXMLDOMElement_Impl::removeAttribute(this, core::mem::transmute(&name)).into()
+            }
         }
-    }
-    pub unsafe fn text(&self) -> windows_core::Result<windows_core::BSTR> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).text)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        unsafe extern "system" fn getAttributeNode<Identity: IXMLDOMElement_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, attributenode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMElement_Impl::getAttributeNode(this, core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        attributenode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn Settext(&self, text: &windows_core::BSTR) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Settext)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(text)).ok() }
-    }
-    pub unsafe fn specified(&self) -> windows_core::Result<super::super::super::Foundation::VARIANT_BOOL> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).specified)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        unsafe extern "system" fn setAttributeNode<Identity: IXMLDOMElement_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, domattribute: *mut core::ffi::c_void, attributenode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMElement_Impl::setAttributeNode(this, core::mem::transmute_copy(&domattribute)) {
+                    Ok(ok__) => {
+                        attributenode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn definition(&self) -> windows_core::Result<IXMLDOMNode> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).definition)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn removeAttributeNode<Identity: IXMLDOMElement_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, domattribute: *mut core::ffi::c_void, attributenode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMElement_Impl::removeAttributeNode(this, core::mem::transmute_copy(&domattribute)) {
+                    Ok(ok__) => {
+                        attributenode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub unsafe fn nodeTypedValue(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).nodeTypedValue)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        unsafe extern "system" fn getElementsByTagName<Identity: IXMLDOMElement_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, tagname: *mut core::ffi::c_void, resultlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMElement_Impl::getElementsByTagName(this, core::mem::transmute(&tagname)) {
+                    Ok(ok__) => {
+                        resultlist.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub unsafe fn SetnodeTypedValue(&self, typedvalue: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetnodeTypedValue)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(typedvalue)).ok() }
-    }
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub unsafe fn dataType(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).dataType)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        unsafe extern "system" fn normalize<Identity: IXMLDOMElement_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IXMLDOMElement_Impl::normalize(this).into()
+            }
+        }
+        Self {
+            base__: IXMLDOMNode_Vtbl::new::<Identity, OFFSET>(),
+            tagName: tagName::<Identity, OFFSET>,
+            getAttribute: getAttribute::<Identity, OFFSET>,
+            setAttribute: setAttribute::<Identity, OFFSET>,
+            removeAttribute: removeAttribute::<Identity, OFFSET>,
+            getAttributeNode: getAttributeNode::<Identity, OFFSET>,
+            setAttributeNode: setAttributeNode::<Identity, OFFSET>,
+            removeAttributeNode: removeAttributeNode::<Identity, OFFSET>,
+            getElementsByTagName: getElementsByTagName::<Identity, OFFSET>,
+            normalize: normalize::<Identity, OFFSET>,
         }
     }
-    pub unsafe fn SetdataType(&self, datatypename: &windows_core::BSTR) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetdataType)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(datatypename)).ok() }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMElement as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID || iid == &<IXMLDOMNode as windows_core::Interface>::IID
     }
-    pub unsafe fn xml(&self) -> windows_core::Result<windows_core::BSTR> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).xml)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMElement_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMElement_Vtbl
+where
+    IXMLDOMNode_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn tagName<Identity: windows_core::IUnknownImpl + IXMLDOMElement_Impl>(this: *mut core::ffi::c_void, tagname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMElement_Impl::tagName(this) {
+                    Ok(ok__) => {
+                        tagname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn transformNode<P0>(&self, stylesheet: P0) -> windows_core::Result<windows_core::BSTR>
-    where
-        P0: windows_core::Param<IXMLDOMNode>,
-    {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).transformNode)(windows_core::Interface::as_raw(self), stylesheet.param().abi(), &mut result__).map(|| core::mem::transmute(result__))
+        unsafe extern "system" fn getAttribute<Identity: windows_core::IUnknownImpl + IXMLDOMElement_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMElement_Impl::getAttribute(this, core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn selectNodes(&self, querystring: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNodeList> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).selectNodes)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(querystring), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn setAttribute<Identity: windows_core::IUnknownImpl + IXMLDOMElement_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMElement_Impl::setAttribute(this, core::mem::transmute(&name), core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn removeAttribute<Identity: windows_core::IUnknownImpl + IXMLDOMElement_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMElement_Impl::removeAttribute(this, core::mem::transmute(&name)).into()
+            }
+        }
+        unsafe extern "system" fn getAttributeNode<Identity: windows_core::IUnknownImpl + IXMLDOMElement_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, attributenode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMElement_Impl::getAttributeNode(this, core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        attributenode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn setAttributeNode<Identity: windows_core::IUnknownImpl + IXMLDOMElement_Impl>(this: *mut core::ffi::c_void, domattribute: *mut core::ffi::c_void, attributenode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMElement_Impl::setAttributeNode(this, core::mem::transmute_copy(&domattribute)) {
+                    Ok(ok__) => {
+                        attributenode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn removeAttributeNode<Identity: windows_core::IUnknownImpl + IXMLDOMElement_Impl>(this: *mut core::ffi::c_void, domattribute: *mut core::ffi::c_void, attributenode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMElement_Impl::removeAttributeNode(this, core::mem::transmute_copy(&domattribute)) {
+                    Ok(ok__) => {
+                        attributenode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getElementsByTagName<Identity: windows_core::IUnknownImpl + IXMLDOMElement_Impl>(this: *mut core::ffi::c_void, tagname: *mut core::ffi::c_void, resultlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMElement_Impl::getElementsByTagName(this, core::mem::transmute(&tagname)) {
+                    Ok(ok__) => {
+                        resultlist.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn normalize<Identity: windows_core::IUnknownImpl + IXMLDOMElement_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMElement_Impl::normalize(this).into()
+            }
         }
+        Self {
+            base__: <IXMLDOMNode_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            tagName: tagName::<Identity>,
+            getAttribute: getAttribute::<Identity>,
+            setAttribute: setAttribute::<Identity>,
+            removeAttribute: removeAttribute::<Identity>,
+            getAttributeNode: getAttributeNode::<Identity>,
+            setAttributeNode: setAttributeNode::<Identity>,
+            removeAttributeNode: removeAttributeNode::<Identity>,
+            getElementsByTagName: getElementsByTagName::<Identity>,
+            normalize: normalize::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IXMLDOMElement {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(IXMLDOMEntity, IXMLDOMEntity_Vtbl, 0x2933bf8d_7b36_11d2_b20e_00c04f983e60);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for IXMLDOMEntity {
+    type Target = IXMLDOMNode;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
     }
-    pub unsafe fn selectSingleNode(&self, querystring: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNode> {
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(IXMLDOMEntity, windows_core::IUnknown, super::super::super::System::Com::IDispatch, IXMLDOMNode);
+#[cfg(feature = "Win32_System_Com")]
+impl IXMLDOMEntity {
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub unsafe fn publicId(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT> {
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).selectSingleNode)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(querystring), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+            (windows_core::Interface::vtable(self).publicId)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
         }
     }
-    pub unsafe fn parsed(&self) -> windows_core::Result<super::super::super::Foundation::VARIANT_BOOL> {
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub unsafe fn systemId(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT> {
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).parsed)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+            (windows_core::Interface::vtable(self).systemId)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
         }
     }
-    pub unsafe fn namespaceURI(&self) -> windows_core::Result<windows_core::BSTR> {
+    pub unsafe fn notationName(&self) -> windows_core::Result<windows_core::BSTR> {
         unsafe {
             let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).namespaceURI)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+            (windows_core::Interface::vtable(self).notationName)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
         }
     }
-    pub unsafe fn prefix(&self) -> windows_core::Result<windows_core::BSTR> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).prefix)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+}
+#[cfg(feature = "Win32_System_Com")]
+#[repr(C)]
+#[doc(hidden)]
+pub struct IXMLDOMEntity_Vtbl {
+    pub base__: IXMLDOMNode_Vtbl,
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub publicId: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
+    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
+    publicId: usize,
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub systemId: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
+    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
+    systemId: usize,
+    pub notationName: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+pub trait IXMLDOMEntity_Impl: IXMLDOMNode_Impl {
+    fn publicId(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT>;
+    fn systemId(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT>;
+    fn notationName(&self) -> windows_core::Result<windows_core::BSTR>;
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl IXMLDOMEntity_Vtbl {
+    pub const fn new<Identity: IXMLDOMEntity_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn publicId<Identity: IXMLDOMEntity_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, publicid: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMEntity_Impl::publicId(this) {
+                    Ok(ok__) => {
+                        publicid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn systemId<Identity: IXMLDOMEntity_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, systemid: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMEntity_Impl::systemId(this) {
+                    Ok(ok__) => {
+                        systemid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn notationName<Identity: IXMLDOMEntity_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMEntity_Impl::notationName(this) {
+                    Ok(ok__) => {
+                        name.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: IXMLDOMNode_Vtbl::new::<Identity, OFFSET>(),
+            publicId: publicId::<Identity, OFFSET>,
+            systemId: systemId::<Identity, OFFSET>,
+            notationName: notationName::<Identity, OFFSET>,
         }
     }
-    pub unsafe fn baseName(&self) -> windows_core::Result<windows_core::BSTR> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).baseName)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMEntity as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID || iid == &<IXMLDOMNode as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMEntity_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMEntity_Vtbl
+where
+    IXMLDOMNode_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn publicId<Identity: windows_core::IUnknownImpl + IXMLDOMEntity_Impl>(this: *mut core::ffi::c_void, publicid: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMEntity_Impl::publicId(this) {
+                    Ok(ok__) => {
+                        publicid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn systemId<Identity: windows_core::IUnknownImpl + IXMLDOMEntity_Impl>(this: *mut core::ffi::c_void, systemid: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMEntity_Impl::systemId(this) {
+                    Ok(ok__) => {
+                        systemid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn notationName<Identity: windows_core::IUnknownImpl + IXMLDOMEntity_Impl>(this: *mut core::ffi::c_void, name: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMEntity_Impl::notationName(this) {
+                    Ok(ok__) => {
+                        name.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IXMLDOMNode_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            publicId: publicId::<Identity>,
+            systemId: systemId::<Identity>,
+            notationName: notationName::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IXMLDOMEntity {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(IXMLDOMEntityReference, IXMLDOMEntityReference_Vtbl, 0x2933bf8e_7b36_11d2_b20e_00c04f983e60);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for IXMLDOMEntityReference {
+    type Target = IXMLDOMNode;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(IXMLDOMEntityReference, windows_core::IUnknown, super::super::super::System::Com::IDispatch, IXMLDOMNode);
+#[cfg(feature = "Win32_System_Com")]
+#[repr(C)]
+#[doc(hidden)]
+pub struct IXMLDOMEntityReference_Vtbl {
+    pub base__: IXMLDOMNode_Vtbl,
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+pub trait IXMLDOMEntityReference_Impl: IXMLDOMNode_Impl {}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl IXMLDOMEntityReference_Vtbl {
+    pub const fn new<Identity: IXMLDOMEntityReference_Impl, const OFFSET: isize>() -> Self {
+        Self { base__: IXMLDOMNode_Vtbl::new::<Identity, OFFSET>() }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMEntityReference as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID || iid == &<IXMLDOMNode as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMEntityReference_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMEntityReference_Vtbl
+where
+    IXMLDOMNode_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IXMLDOMNode_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IXMLDOMEntityReference {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(IXMLDOMImplementation, IXMLDOMImplementation_Vtbl, 0x2933bf8f_7b36_11d2_b20e_00c04f983e60);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for IXMLDOMImplementation {
+    type Target = super::super::super::System::Com::IDispatch;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(IXMLDOMImplementation, windows_core::IUnknown, super::super::super::System::Com::IDispatch);
+#[cfg(feature = "Win32_System_Com")]
+impl IXMLDOMImplementation {
+    pub unsafe fn hasFeature(&self, feature: &windows_core::BSTR, version: &windows_core::BSTR) -> windows_core::Result<super::super::super::Foundation::VARIANT_BOOL> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).hasFeature)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(feature), core::mem::transmute_copy(version), &mut result__).map(|| result__)
+        }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+#[repr(C)]
+#[doc(hidden)]
+pub struct IXMLDOMImplementation_Vtbl {
+    pub base__: super::super::super::System::Com::IDispatch_Vtbl,
+    pub hasFeature: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void, *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT,
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+pub trait IXMLDOMImplementation_Impl: super::super::super::System::Com::IDispatch_Impl {
+    fn hasFeature(&self, feature: &windows_core::BSTR, version: &windows_core::BSTR) -> windows_core::Result<super::super::super::Foundation::VARIANT_BOOL>;
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl IXMLDOMImplementation_Vtbl {
+    pub const fn new<Identity: IXMLDOMImplementation_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn hasFeature<Identity: IXMLDOMImplementation_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, feature: *mut core::ffi::c_void, version: *mut core::ffi::c_void, hasfeature: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMImplementation_Impl::hasFeature(this, core::mem::transmute(&feature), core::mem::transmute(&version)) {
+                    Ok(ok__) => {
+                        hasfeature.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: super::super::super::System::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(), hasFeature: hasFeature::<Identity, OFFSET> }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMImplementation as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMImplementation_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMImplementation_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn hasFeature<Identity: windows_core::IUnknownImpl + IXMLDOMImplementation_Impl>(this: *mut core::ffi::c_void, feature: *mut core::ffi::c_void, version: *mut core::ffi::c_void, hasfeature: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMImplementation_Impl::hasFeature(this, core::mem::transmute(&feature), core::mem::transmute(&version)) {
+                    Ok(ok__) => {
+                        hasfeature.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            hasFeature: hasFeature::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IXMLDOMImplementation {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(IXMLDOMNamedNodeMap, IXMLDOMNamedNodeMap_Vtbl, 0x2933bf83_7b36_11d2_b20e_00c04f983e60);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for IXMLDOMNamedNodeMap {
+    type Target = super::super::super::System::Com::IDispatch;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(IXMLDOMNamedNodeMap, windows_core::IUnknown, super::super::super::System::Com::IDispatch);
+#[cfg(feature = "Win32_System_Com")]
+impl IXMLDOMNamedNodeMap {
+    pub unsafe fn getNamedItem(&self, name: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNode> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).getNamedItem)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(name), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn setNamedItem<P0>(&self, newitem: P0) -> windows_core::Result<IXMLDOMNode>
+    where
+        P0: windows_core::Param<IXMLDOMNode>,
+    {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).setNamedItem)(windows_core::Interface::as_raw(self), newitem.param().abi(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn removeNamedItem(&self, name: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNode> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).removeNamedItem)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(name), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn get_item(&self, index: i32) -> windows_core::Result<IXMLDOMNode> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).get_item)(windows_core::Interface::as_raw(self), index, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn length(&self) -> windows_core::Result<i32> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).length)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn getQualifiedItem(&self, basename: &windows_core::BSTR, namespaceuri: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNode> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).getQualifiedItem)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(basename), core::mem::transmute_copy(namespaceuri), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn removeQualifiedItem(&self, basename: &windows_core::BSTR, namespaceuri: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNode> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).removeQualifiedItem)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(basename), core::mem::transmute_copy(namespaceuri), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn nextNode(&self) -> windows_core::Result<IXMLDOMNode> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).nextNode)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn reset(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).reset)(windows_core::Interface::as_raw(self)).ok() }
+    }
+    pub unsafe fn _newEnum(&self) -> windows_core::Result<windows_core::IUnknown> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self)._newEnum)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+#[repr(C)]
+#[doc(hidden)]
+pub struct IXMLDOMNamedNodeMap_Vtbl {
+    pub base__: super::super::super::System::Com::IDispatch_Vtbl,
+    pub getNamedItem: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub setNamedItem: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub removeNamedItem: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub get_item: unsafe extern "system" fn(*mut core::ffi::c_void, i32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub length: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
+    pub getQualifiedItem: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub removeQualifiedItem: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub nextNode: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub reset: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub _newEnum: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+pub trait IXMLDOMNamedNodeMap_Impl: super::super::super::System::Com::IDispatch_Impl {
+    fn getNamedItem(&self, name: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNode>;
+    fn setNamedItem(&self, newitem: windows_core::Ref<IXMLDOMNode>) -> windows_core::Result<IXMLDOMNode>;
+    fn removeNamedItem(&self, name: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNode>;
+    fn get_item(&self, index: i32) -> windows_core::Result<IXMLDOMNode>;
+    fn length(&self) -> windows_core::Result<i32>;
+    fn getQualifiedItem(&self, basename: &windows_core::BSTR, namespaceuri: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNode>;
+    fn removeQualifiedItem(&self, basename: &windows_core::BSTR, namespaceuri: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNode>;
+    fn nextNode(&self) -> windows_core::Result<IXMLDOMNode>;
+    fn reset(&self) -> windows_core::Result<()>;
+    fn _newEnum(&self) -> windows_core::Result<windows_core::IUnknown>;
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl IXMLDOMNamedNodeMap_Vtbl {
+    pub const fn new<Identity: IXMLDOMNamedNodeMap_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn getNamedItem<Identity: IXMLDOMNamedNodeMap_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, nameditem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNamedNodeMap_Impl::getNamedItem(this, core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        nameditem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn setNamedItem<Identity: IXMLDOMNamedNodeMap_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, newitem: *mut core::ffi::c_void, nameitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNamedNodeMap_Impl::setNamedItem(this, core::mem::transmute_copy(&newitem)) {
+                    Ok(ok__) => {
+                        nameitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn removeNamedItem<Identity: IXMLDOMNamedNodeMap_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, nameditem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNamedNodeMap_Impl::removeNamedItem(this, core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        nameditem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_item<Identity: IXMLDOMNamedNodeMap_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, index: i32, listitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNamedNodeMap_Impl::get_item(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        listitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn length<Identity: IXMLDOMNamedNodeMap_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, listlength: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNamedNodeMap_Impl::length(this) {
+                    Ok(ok__) => {
+                        listlength.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getQualifiedItem<Identity: IXMLDOMNamedNodeMap_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, basename: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void, qualifieditem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNamedNodeMap_Impl::getQualifiedItem(this, core::mem::transmute(&basename), core::mem::transmute(&namespaceuri)) {
+                    Ok(ok__) => {
+                        qualifieditem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn removeQualifiedItem<Identity: IXMLDOMNamedNodeMap_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, basename: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void, qualifieditem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNamedNodeMap_Impl::removeQualifiedItem(this, core::mem::transmute(&basename), core::mem::transmute(&namespaceuri)) {
+                    Ok(ok__) => {
+                        qualifieditem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn nextNode<Identity: IXMLDOMNamedNodeMap_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, nextitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNamedNodeMap_Impl::nextNode(this) {
+                    Ok(ok__) => {
+                        nextitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn reset<Identity: IXMLDOMNamedNodeMap_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IXMLDOMNamedNodeMap_Impl::reset(this).into()
+            }
+        }
+        unsafe extern "system" fn _newEnum<Identity: IXMLDOMNamedNodeMap_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNamedNodeMap_Impl::_newEnum(this) {
+                    Ok(ok__) => {
+                        ppunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: super::super::super::System::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
+            getNamedItem: getNamedItem::<Identity, OFFSET>,
+            setNamedItem: setNamedItem::<Identity, OFFSET>,
+            removeNamedItem: removeNamedItem::<Identity, OFFSET>,
+            get_item: get_item::<Identity, OFFSET>,
+            length: length::<Identity, OFFSET>,
+            getQualifiedItem: getQualifiedItem::<Identity, OFFSET>,
+            removeQualifiedItem: removeQualifiedItem::<Identity, OFFSET>,
+            nextNode: nextNode::<Identity, OFFSET>,
+            reset: reset::<Identity, OFFSET>,
+            _newEnum: _newEnum::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMNamedNodeMap as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMNamedNodeMap_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMNamedNodeMap_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn getNamedItem<Identity: windows_core::IUnknownImpl + IXMLDOMNamedNodeMap_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, nameditem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMNamedNodeMap_Impl::getNamedItem(this, core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        nameditem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn setNamedItem<Identity: windows_core::IUnknownImpl + IXMLDOMNamedNodeMap_Impl>(this: *mut core::ffi::c_void, newitem: *mut core::ffi::c_void, nameitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMNamedNodeMap_Impl::setNamedItem(this, core::mem::transmute_copy(&newitem)) {
+                    Ok(ok__) => {
+                        nameitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn removeNamedItem<Identity: windows_core::IUnknownImpl + IXMLDOMNamedNodeMap_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, nameditem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMNamedNodeMap_Impl::removeNamedItem(this, core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        nameditem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_item<Identity: windows_core::IUnknownImpl + IXMLDOMNamedNodeMap_Impl>(this: *mut core::ffi::c_void, index: i32, listitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMNamedNodeMap_Impl::get_item(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        listitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn length<Identity: windows_core::IUnknownImpl + IXMLDOMNamedNodeMap_Impl>(this: *mut core::ffi::c_void, listlength: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMNamedNodeMap_Impl::length(this) {
+                    Ok(ok__) => {
+                        listlength.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getQualifiedItem<Identity: windows_core::IUnknownImpl + IXMLDOMNamedNodeMap_Impl>(this: *mut core::ffi::c_void, basename: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void, qualifieditem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMNamedNodeMap_Impl::getQualifiedItem(this, core::mem::transmute(&basename), core::mem::transmute(&namespaceuri)) {
+                    Ok(ok__) => {
+                        qualifieditem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn removeQualifiedItem<Identity: windows_core::IUnknownImpl + IXMLDOMNamedNodeMap_Impl>(this: *mut core::ffi::c_void, basename: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void, qualifieditem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMNamedNodeMap_Impl::removeQualifiedItem(this, core::mem::transmute(&basename), core::mem::transmute(&namespaceuri)) {
+                    Ok(ok__) => {
+                        qualifieditem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn nextNode<Identity: windows_core::IUnknownImpl + IXMLDOMNamedNodeMap_Impl>(this: *mut core::ffi::c_void, nextitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMNamedNodeMap_Impl::nextNode(this) {
+                    Ok(ok__) => {
+                        nextitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn reset<Identity: windows_core::IUnknownImpl + IXMLDOMNamedNodeMap_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMNamedNodeMap_Impl::reset(this).into()
+            }
+        }
+        unsafe extern "system" fn _newEnum<Identity: windows_core::IUnknownImpl + IXMLDOMNamedNodeMap_Impl>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMNamedNodeMap_Impl::_newEnum(this) {
+                    Ok(ok__) => {
+                        ppunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            getNamedItem: getNamedItem::<Identity>,
+            setNamedItem: setNamedItem::<Identity>,
+            removeNamedItem: removeNamedItem::<Identity>,
+            get_item: get_item::<Identity>,
+            length: length::<Identity>,
+            getQualifiedItem: getQualifiedItem::<Identity>,
+            removeQualifiedItem: removeQualifiedItem::<Identity>,
+            nextNode: nextNode::<Identity>,
+            reset: reset::<Identity>,
+            _newEnum: _newEnum::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IXMLDOMNamedNodeMap {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(IXMLDOMNode, IXMLDOMNode_Vtbl, 0x2933bf80_7b36_11d2_b20e_00c04f983e60);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for IXMLDOMNode {
+    type Target = super::super::super::System::Com::IDispatch;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(IXMLDOMNode, windows_core::IUnknown, super::super::super::System::Com::IDispatch);
+#[cfg(feature = "Win32_System_Com")]
+impl IXMLDOMNode {
+    pub unsafe fn nodeName(&self) -> windows_core::Result<windows_core::BSTR> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).nodeName)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        }
+    }
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub unsafe fn nodeValue(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).nodeValue)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        }
+    }
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub unsafe fn SetnodeValue(&self, value: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetnodeValue)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(value)).ok() }
+    }
+    pub unsafe fn nodeType(&self) -> windows_core::Result<DOMNodeType> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).nodeType)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn parentNode(&self) -> windows_core::Result<IXMLDOMNode> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).parentNode)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn childNodes(&self) -> windows_core::Result<IXMLDOMNodeList> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).childNodes)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn firstChild(&self) -> windows_core::Result<IXMLDOMNode> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).firstChild)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn lastChild(&self) -> windows_core::Result<IXMLDOMNode> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).lastChild)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn previousSibling(&self) -> windows_core::Result<IXMLDOMNode> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).previousSibling)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn nextSibling(&self) -> windows_core::Result<IXMLDOMNode> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).nextSibling)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn attributes(&self) -> windows_core::Result<IXMLDOMNamedNodeMap> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).attributes)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub unsafe fn insertBefore<P0>(&self, newchild: P0, refchild: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<IXMLDOMNode>
+    where
+        P0: windows_core::Param<IXMLDOMNode>,
+    {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).insertBefore)(windows_core::Interface::as_raw(self), newchild.param().abi(), core::mem::transmute_copy(refchild), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn replaceChild<P0, P1>(&self, newchild: P0, oldchild: P1) -> windows_core::Result<IXMLDOMNode>
+    where
+        P0: windows_core::Param<IXMLDOMNode>,
+        P1: windows_core::Param<IXMLDOMNode>,
+    {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).replaceChild)(windows_core::Interface::as_raw(self), newchild.param().abi(), oldchild.param().abi(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn removeChild<P0>(&self, childnode: P0) -> windows_core::Result<IXMLDOMNode>
+    where
+        P0: windows_core::Param<IXMLDOMNode>,
+    {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).removeChild)(windows_core::Interface::as_raw(self), childnode.param().abi(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn appendChild<P0>(&self, newchild: P0) -> windows_core::Result<IXMLDOMNode>
+    where
+        P0: windows_core::Param<IXMLDOMNode>,
+    {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).appendChild)(windows_core::Interface::as_raw(self), newchild.param().abi(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn hasChildNodes(&self) -> windows_core::Result<super::super::super::Foundation::VARIANT_BOOL> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).hasChildNodes)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn ownerDocument(&self) -> windows_core::Result<IXMLDOMDocument> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).ownerDocument)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn cloneNode(&self, deep: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::Result<IXMLDOMNode> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).cloneNode)(windows_core::Interface::as_raw(self), deep, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn nodeTypeString(&self) -> windows_core::Result<windows_core::BSTR> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).nodeTypeString)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        }
+    }
+    pub unsafe fn text(&self) -> windows_core::Result<windows_core::BSTR> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).text)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        }
+    }
+    pub unsafe fn Settext(&self, text: &windows_core::BSTR) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Settext)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(text)).ok() }
+    }
+    pub unsafe fn specified(&self) -> windows_core::Result<super::super::super::Foundation::VARIANT_BOOL> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).specified)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn definition(&self) -> windows_core::Result<IXMLDOMNode> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).definition)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub unsafe fn nodeTypedValue(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).nodeTypedValue)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        }
+    }
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub unsafe fn SetnodeTypedValue(&self, typedvalue: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetnodeTypedValue)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(typedvalue)).ok() }
+    }
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub unsafe fn dataType(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).dataType)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        }
+    }
+    pub unsafe fn SetdataType(&self, datatypename: &windows_core::BSTR) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetdataType)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(datatypename)).ok() }
+    }
+    pub unsafe fn xml(&self) -> windows_core::Result<windows_core::BSTR> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).xml)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        }
+    }
+    pub unsafe fn transformNode<P0>(&self, stylesheet: P0) -> windows_core::Result<windows_core::BSTR>
+    where
+        P0: windows_core::Param<IXMLDOMNode>,
+    {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).transformNode)(windows_core::Interface::as_raw(self), stylesheet.param().abi(), &mut result__).map(|| core::mem::transmute(result__))
+        }
+    }
+    pub unsafe fn selectNodes(&self, querystring: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNodeList> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).selectNodes)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(querystring), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn selectSingleNode(&self, querystring: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNode> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).selectSingleNode)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(querystring), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn parsed(&self) -> windows_core::Result<super::super::super::Foundation::VARIANT_BOOL> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).parsed)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn namespaceURI(&self) -> windows_core::Result<windows_core::BSTR> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).namespaceURI)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        }
+    }
+    pub unsafe fn prefix(&self) -> windows_core::Result<windows_core::BSTR> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).prefix)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        }
+    }
+    pub unsafe fn baseName(&self) -> windows_core::Result<windows_core::BSTR> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).baseName)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        }
+    }
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub unsafe fn transformNodeToObject<P0>(&self, stylesheet: P0, outputobject: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<IXMLDOMNode>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).transformNodeToObject)(windows_core::Interface::as_raw(self), stylesheet.param().abi(), core::mem::transmute_copy(outputobject)).ok() }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+#[repr(C)]
+#[doc(hidden)]
+pub struct IXMLDOMNode_Vtbl {
+    pub base__: super::super::super::System::Com::IDispatch_Vtbl,
+    pub nodeName: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub nodeValue: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
+    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
+    nodeValue: usize,
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub SetnodeValue: unsafe extern "system" fn(*mut core::ffi::c_void, super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
+    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
+    SetnodeValue: usize,
+    pub nodeType: unsafe extern "system" fn(*mut core::ffi::c_void, *mut DOMNodeType) -> windows_core::HRESULT,
+    pub parentNode: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub childNodes: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub firstChild: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub lastChild: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub previousSibling: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub nextSibling: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub attributes: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub insertBefore: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, super::super::super::System::Variant::VARIANT, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
+    insertBefore: usize,
+    pub replaceChild: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub removeChild: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub appendChild: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub hasChildNodes: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT,
+    pub ownerDocument: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub cloneNode: unsafe extern "system" fn(*mut core::ffi::c_void, super::super::super::Foundation::VARIANT_BOOL, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub nodeTypeString: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub text: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub Settext: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub specified: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT,
+    pub definition: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub nodeTypedValue: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
+    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
+    nodeTypedValue: usize,
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub SetnodeTypedValue: unsafe extern "system" fn(*mut core::ffi::c_void, super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
+    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
+    SetnodeTypedValue: usize,
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub dataType: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
+    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
+    dataType: usize,
+    pub SetdataType: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub xml: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub transformNode: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub selectNodes: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub selectSingleNode: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub parsed: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT,
+    pub namespaceURI: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub prefix: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub baseName: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub transformNodeToObject: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
+    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
+    transformNodeToObject: usize,
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+pub trait IXMLDOMNode_Impl: super::super::super::System::Com::IDispatch_Impl {
+    fn nodeName(&self) -> windows_core::Result<windows_core::BSTR>;
+    fn nodeValue(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT>;
+    fn SetnodeValue(&self, value: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()>;
+    fn nodeType(&self) -> windows_core::Result<DOMNodeType>;
+    fn parentNode(&self) -> windows_core::Result<IXMLDOMNode>;
+    fn childNodes(&self) -> windows_core::Result<IXMLDOMNodeList>;
+    fn firstChild(&self) -> windows_core::Result<IXMLDOMNode>;
+    fn lastChild(&self) -> windows_core::Result<IXMLDOMNode>;
+    fn previousSibling(&self) -> windows_core::Result<IXMLDOMNode>;
+    fn nextSibling(&self) -> windows_core::Result<IXMLDOMNode>;
+    fn attributes(&self) -> windows_core::Result<IXMLDOMNamedNodeMap>;
+    fn insertBefore(&self, newchild: windows_core::Ref<IXMLDOMNode>, refchild: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<IXMLDOMNode>;
+    fn replaceChild(&self, newchild: windows_core::Ref<IXMLDOMNode>, oldchild: windows_core::Ref<IXMLDOMNode>) -> windows_core::Result<IXMLDOMNode>;
+    fn removeChild(&self, childnode: windows_core::Ref<IXMLDOMNode>) -> windows_core::Result<IXMLDOMNode>;
+    fn appendChild(&self, newchild: windows_core::Ref<IXMLDOMNode>) -> windows_core::Result<IXMLDOMNode>;
+    fn hasChildNodes(&self) -> windows_core::Result<super::super::super::Foundation::VARIANT_BOOL>;
+    fn ownerDocument(&self) -> windows_core::Result<IXMLDOMDocument>;
+    fn cloneNode(&self, deep: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::Result<IXMLDOMNode>;
+    fn nodeTypeString(&self) -> windows_core::Result<windows_core::BSTR>;
+    fn text(&self) -> windows_core::Result<windows_core::BSTR>;
+    fn Settext(&self, text: &windows_core::BSTR) -> windows_core::Result<()>;
+    fn specified(&self) -> windows_core::Result<super::super::super::Foundation::VARIANT_BOOL>;
+    fn definition(&self) -> windows_core::Result<IXMLDOMNode>;
+    fn nodeTypedValue(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT>;
+    fn SetnodeTypedValue(&self, typedvalue: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()>;
+    fn dataType(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT>;
+    fn SetdataType(&self, datatypename: &windows_core::BSTR) -> windows_core::Result<()>;
+    fn xml(&self) -> windows_core::Result<windows_core::BSTR>;
+    fn transformNode(&self, stylesheet: windows_core::Ref<IXMLDOMNode>) -> windows_core::Result<windows_core::BSTR>;
+    fn selectNodes(&self, querystring: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNodeList>;
+    fn selectSingleNode(&self, querystring: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNode>;
+    fn parsed(&self) -> windows_core::Result<super::super::super::Foundation::VARIANT_BOOL>;
+    fn namespaceURI(&self) -> windows_core::Result<windows_core::BSTR>;
+    fn prefix(&self) -> windows_core::Result<windows_core::BSTR>;
+    fn baseName(&self) -> windows_core::Result<windows_core::BSTR>;
+    fn transformNodeToObject(&self, stylesheet: windows_core::Ref<IXMLDOMNode>, outputobject: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()>;
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl IXMLDOMNode_Vtbl {
+    pub const fn new<Identity: IXMLDOMNode_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn nodeName<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::nodeName(this) {
+                    Ok(ok__) => {
+                        name.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn nodeValue<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::nodeValue(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetnodeValue<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IXMLDOMNode_Impl::SetnodeValue(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn nodeType<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, r#type: *mut DOMNodeType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::nodeType(this) {
+                    Ok(ok__) => {
+                        r#type.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn parentNode<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, parent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::parentNode(this) {
+                    Ok(ok__) => {
+                        parent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn childNodes<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, childlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::childNodes(this) {
+                    Ok(ok__) => {
+                        childlist.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn firstChild<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, firstchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::firstChild(this) {
+                    Ok(ok__) => {
+                        firstchild.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn lastChild<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lastchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::lastChild(this) {
+                    Ok(ok__) => {
+                        lastchild.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn previousSibling<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, previoussibling: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::previousSibling(this) {
+                    Ok(ok__) => {
+                        previoussibling.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn nextSibling<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, nextsibling: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::nextSibling(this) {
+                    Ok(ok__) => {
+                        nextsibling.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn attributes<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, attributemap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::attributes(this) {
+                    Ok(ok__) => {
+                        attributemap.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn insertBefore<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void, refchild: super::super::super::System::Variant::VARIANT, outnewchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::insertBefore(this, core::mem::transmute_copy(&newchild), core::mem::transmute(&refchild)) {
+                    Ok(ok__) => {
+                        outnewchild.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn replaceChild<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void, oldchild: *mut core::ffi::c_void, outoldchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::replaceChild(this, core::mem::transmute_copy(&newchild), core::mem::transmute_copy(&oldchild)) {
+                    Ok(ok__) => {
+                        outoldchild.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn removeChild<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, childnode: *mut core::ffi::c_void, oldchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::removeChild(this, core::mem::transmute_copy(&childnode)) {
+                    Ok(ok__) => {
+                        oldchild.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn appendChild<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void, outnewchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::appendChild(this, core::mem::transmute_copy(&newchild)) {
+                    Ok(ok__) => {
+                        outnewchild.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn hasChildNodes<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, haschild: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::hasChildNodes(this) {
+                    Ok(ok__) => {
+                        haschild.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ownerDocument<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, xmldomdocument: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::ownerDocument(this) {
+                    Ok(ok__) => {
+                        xmldomdocument.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn cloneNode<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, deep: super::super::super::Foundation::VARIANT_BOOL, cloneroot: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::cloneNode(this, core::mem::transmute_copy(&deep)) {
+                    Ok(ok__) => {
+                        cloneroot.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn nodeTypeString<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, nodetype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::nodeTypeString(this) {
+                    Ok(ok__) => {
+                        nodetype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn text<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, text: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::text(this) {
+                    Ok(ok__) => {
+                        text.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Settext<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, text: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IXMLDOMNode_Impl::Settext(this, core::mem::transmute(&text)).into()
+            }
+        }
+        unsafe extern "system" fn specified<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, isspecified: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::specified(this) {
+                    Ok(ok__) => {
+                        isspecified.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn definition<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, definitionnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::definition(this) {
+                    Ok(ok__) => {
+                        definitionnode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn nodeTypedValue<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, typedvalue: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::nodeTypedValue(this) {
+                    Ok(ok__) => {
+                        typedvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetnodeTypedValue<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, typedvalue: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IXMLDOMNode_Impl::SetnodeTypedValue(this, core::mem::transmute(&typedvalue)).into()
+            }
+        }
+        unsafe extern "system" fn dataType<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, datatypename: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::dataType(this) {
+                    Ok(ok__) => {
+                        datatypename.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetdataType<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, datatypename: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IXMLDOMNode_Impl::SetdataType(this, core::mem::transmute(&datatypename)).into()
+            }
+        }
+        unsafe extern "system" fn xml<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, xmlstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::xml(this) {
+                    Ok(ok__) => {
+                        xmlstring.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn transformNode<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, stylesheet: *mut core::ffi::c_void, xmlstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::transformNode(this, core::mem::transmute_copy(&stylesheet)) {
+                    Ok(ok__) => {
+                        xmlstring.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn selectNodes<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, querystring: *mut core::ffi::c_void, resultlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::selectNodes(this, core::mem::transmute(&querystring)) {
+                    Ok(ok__) => {
+                        resultlist.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn selectSingleNode<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, querystring: *mut core::ffi::c_void, resultnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::selectSingleNode(this, core::mem::transmute(&querystring)) {
+                    Ok(ok__) => {
+                        resultnode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn parsed<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, isparsed: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::parsed(this) {
+                    Ok(ok__) => {
+                        isparsed.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn namespaceURI<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, namespaceuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::namespaceURI(this) {
+                    Ok(ok__) => {
+                        namespaceuri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn prefix<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, prefixstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::prefix(this) {
+                    Ok(ok__) => {
+                        prefixstring.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn baseName<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, namestring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMNode_Impl::baseName(this) {
+                    Ok(ok__) => {
+                        namestring.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn transformNodeToObject<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, stylesheet: *mut core::ffi::c_void, outputobject: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IXMLDOMNode_Impl::transformNodeToObject(this, core::mem::transmute_copy(&stylesheet), core::mem::transmute(&outputobject)).into()
+            }
+        }
+        Self {
+            base__: super::super::super::System::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
+            nodeName: nodeName::<Identity, OFFSET>,
+            nodeValue: nodeValue::<Identity, OFFSET>,
+            SetnodeValue: SetnodeValue::<Identity, OFFSET>,
+            nodeType: nodeType::<Identity, OFFSET>,
+            parentNode: parentNode::<Identity, OFFSET>,
+            childNodes: childNodes::<Identity, OFFSET>,
+            firstChild: firstChild::<Identity, OFFSET>,
+            lastChild: lastChild::<Identity, OFFSET>,
+            previousSibling: previousSibling::<Identity, OFFSET>,
+            nextSibling: nextSibling::<Identity, OFFSET>,
+            attributes: attributes::<Identity, OFFSET>,
+            insertBefore: insertBefore::<Identity, OFFSET>,
+            replaceChild: replaceChild::<Identity, OFFSET>,
+            removeChild: removeChild::<Identity, OFFSET>,
+            appendChild: appendChild::<Identity, OFFSET>,
+            hasChildNodes: hasChildNodes::<Identity, OFFSET>,
+            ownerDocument: ownerDocument::<Identity, OFFSET>,
+            cloneNode: cloneNode::<Identity, OFFSET>,
+            nodeTypeString: nodeTypeString::<Identity, OFFSET>,
+            text: text::<Identity, OFFSET>,
+            Settext: Settext::<Identity, OFFSET>,
+            specified: specified::<Identity, OFFSET>,
+            definition: definition::<Identity, OFFSET>,
+            nodeTypedValue: nodeTypedValue::<Identity, OFFSET>,
+            SetnodeTypedValue: SetnodeTypedValue::<Identity, OFFSET>,
+            dataType: dataType::<Identity, OFFSET>,
+            SetdataType: SetdataType::<Identity, OFFSET>,
+            xml: xml::<Identity, OFFSET>,
+            transformNode: transformNode::<Identity, OFFSET>,
+            selectNodes: selectNodes::<Identity, OFFSET>,
+            selectSingleNode: selectSingleNode::<Identity, OFFSET>,
+            parsed: parsed::<Identity, OFFSET>,
+            namespaceURI: namespaceURI::<Identity, OFFSET>,
+            prefix: prefix::<Identity, OFFSET>,
+            baseName: baseName::<Identity, OFFSET>,
+            transformNodeToObject: transformNodeToObject::<Identity, OFFSET>,
         }
     }
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub unsafe fn transformNodeToObject<P0>(&self, stylesheet: P0, outputobject: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<IXMLDOMNode>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).transformNodeToObject)(windows_core::Interface::as_raw(self), stylesheet.param().abi(), core::mem::transmute_copy(outputobject)).ok() }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMNode as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID
     }
 }
-#[cfg(feature = "Win32_System_Com")]
-#[repr(C)]
-#[doc(hidden)]
-pub struct IXMLDOMNode_Vtbl {
-    pub base__: super::super::super::System::Com::IDispatch_Vtbl,
-    pub nodeName: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub nodeValue: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
-    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
-    nodeValue: usize,
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub SetnodeValue: unsafe extern "system" fn(*mut core::ffi::c_void, super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
-    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
-    SetnodeValue: usize,
-    pub nodeType: unsafe extern "system" fn(*mut core::ffi::c_void, *mut DOMNodeType) -> windows_core::HRESULT,
-    pub parentNode: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub childNodes: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub firstChild: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub lastChild: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub previousSibling: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub nextSibling: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub attributes: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub insertBefore: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, super::super::super::System::Variant::VARIANT, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
-    insertBefore: usize,
-    pub replaceChild: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub removeChild: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub appendChild: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub hasChildNodes: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT,
-    pub ownerDocument: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub cloneNode: unsafe extern "system" fn(*mut core::ffi::c_void, super::super::super::Foundation::VARIANT_BOOL, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub nodeTypeString: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub text: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub Settext: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub specified: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT,
-    pub definition: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub nodeTypedValue: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
-    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
-    nodeTypedValue: usize,
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub SetnodeTypedValue: unsafe extern "system" fn(*mut core::ffi::c_void, super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
-    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
-    SetnodeTypedValue: usize,
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub dataType: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
-    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
-    dataType: usize,
-    pub SetdataType: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub xml: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub transformNode: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub selectNodes: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub selectSingleNode: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub parsed: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT,
-    pub namespaceURI: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub prefix: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub baseName: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub transformNodeToObject: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT,
-    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
-    transformNodeToObject: usize,
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-pub trait IXMLDOMNode_Impl: super::super::super::System::Com::IDispatch_Impl {
-    fn nodeName(&self) -> windows_core::Result<windows_core::BSTR>;
-    fn nodeValue(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT>;
-    fn SetnodeValue(&self, value: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()>;
-    fn nodeType(&self) -> windows_core::Result<DOMNodeType>;
-    fn parentNode(&self) -> windows_core::Result<IXMLDOMNode>;
-    fn childNodes(&self) -> windows_core::Result<IXMLDOMNodeList>;
-    fn firstChild(&self) -> windows_core::Result<IXMLDOMNode>;
-    fn lastChild(&self) -> windows_core::Result<IXMLDOMNode>;
-    fn previousSibling(&self) -> windows_core::Result<IXMLDOMNode>;
-    fn nextSibling(&self) -> windows_core::Result<IXMLDOMNode>;
-    fn attributes(&self) -> windows_core::Result<IXMLDOMNamedNodeMap>;
-    fn insertBefore(&self, newchild: windows_core::Ref<IXMLDOMNode>, refchild: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<IXMLDOMNode>;
-    fn replaceChild(&self, newchild: windows_core::Ref<IXMLDOMNode>, oldchild: windows_core::Ref<IXMLDOMNode>) -> windows_core::Result<IXMLDOMNode>;
-    fn removeChild(&self, childnode: windows_core::Ref<IXMLDOMNode>) -> windows_core::Result<IXMLDOMNode>;
-    fn appendChild(&self, newchild: windows_core::Ref<IXMLDOMNode>) -> windows_core::Result<IXMLDOMNode>;
-    fn hasChildNodes(&self) -> windows_core::Result<super::super::super::Foundation::VARIANT_BOOL>;
-    fn ownerDocument(&self) -> windows_core::Result<IXMLDOMDocument>;
-    fn cloneNode(&self, deep: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::Result<IXMLDOMNode>;
-    fn nodeTypeString(&self) -> windows_core::Result<windows_core::BSTR>;
-    fn text(&self) -> windows_core::Result<windows_core::BSTR>;
-    fn Settext(&self, text: &windows_core::BSTR) -> windows_core::Result<()>;
-    fn specified(&self) -> windows_core::Result<super::super::super::Foundation::VARIANT_BOOL>;
-    fn definition(&self) -> windows_core::Result<IXMLDOMNode>;
-    fn nodeTypedValue(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT>;
-    fn SetnodeTypedValue(&self, typedvalue: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()>;
-    fn dataType(&self) -> windows_core::Result<super::super::super::System::Variant::VARIANT>;
-    fn SetdataType(&self, datatypename: &windows_core::BSTR) -> windows_core::Result<()>;
-    fn xml(&self) -> windows_core::Result<windows_core::BSTR>;
-    fn transformNode(&self, stylesheet: windows_core::Ref<IXMLDOMNode>) -> windows_core::Result<windows_core::BSTR>;
-    fn selectNodes(&self, querystring: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNodeList>;
-    fn selectSingleNode(&self, querystring: &windows_core::BSTR) -> windows_core::Result<IXMLDOMNode>;
-    fn parsed(&self) -> windows_core::Result<super::super::super::Foundation::VARIANT_BOOL>;
-    fn namespaceURI(&self) -> windows_core::Result<windows_core::BSTR>;
-    fn prefix(&self) -> windows_core::Result<windows_core::BSTR>;
-    fn baseName(&self) -> windows_core::Result<windows_core::BSTR>;
-    fn transformNodeToObject(&self, stylesheet: windows_core::Ref<IXMLDOMNode>, outputobject: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<()>;
-}
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl IXMLDOMNode_Vtbl {
-    pub const fn new<Identity: IXMLDOMNode_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn nodeName<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMNode_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn nodeName<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, name: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::nodeName(this) {
                     Ok(ok__) => {
                         name.write(core::mem::transmute(ok__));
@@ -9395,9 +14192,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn nodeValue<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn nodeValue<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, value: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::nodeValue(this) {
                     Ok(ok__) => {
                         value.write(core::mem::transmute(ok__));
@@ -9407,15 +14204,15 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetnodeValue<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetnodeValue<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, value: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IXMLDOMNode_Impl::SetnodeValue(this, core::mem::transmute(&value)).into()
             }
         }
-        unsafe extern "system" fn nodeType<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, r#type: *mut DOMNodeType) -> windows_core::HRESULT {
+        unsafe extern "system" fn nodeType<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, r#type: *mut DOMNodeType) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::nodeType(this) {
                     Ok(ok__) => {
                         r#type.write(core::mem::transmute(ok__));
@@ -9425,9 +14222,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn parentNode<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, parent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn parentNode<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, parent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::parentNode(this) {
                     Ok(ok__) => {
                         parent.write(core::mem::transmute(ok__));
@@ -9437,9 +14234,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn childNodes<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, childlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn childNodes<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, childlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::childNodes(this) {
                     Ok(ok__) => {
                         childlist.write(core::mem::transmute(ok__));
@@ -9449,9 +14246,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn firstChild<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, firstchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn firstChild<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, firstchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::firstChild(this) {
                     Ok(ok__) => {
                         firstchild.write(core::mem::transmute(ok__));
@@ -9461,9 +14258,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn lastChild<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lastchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn lastChild<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, lastchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::lastChild(this) {
                     Ok(ok__) => {
                         lastchild.write(core::mem::transmute(ok__));
@@ -9473,9 +14270,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn previousSibling<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, previoussibling: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn previousSibling<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, previoussibling: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::previousSibling(this) {
                     Ok(ok__) => {
                         previoussibling.write(core::mem::transmute(ok__));
@@ -9485,9 +14282,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn nextSibling<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, nextsibling: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn nextSibling<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, nextsibling: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::nextSibling(this) {
                     Ok(ok__) => {
                         nextsibling.write(core::mem::transmute(ok__));
@@ -9497,9 +14294,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn attributes<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, attributemap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn attributes<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, attributemap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::attributes(this) {
                     Ok(ok__) => {
                         attributemap.write(core::mem::transmute(ok__));
@@ -9509,9 +14306,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn insertBefore<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void, refchild: super::super::super::System::Variant::VARIANT, outnewchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn insertBefore<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void, refchild: super::super::super::System::Variant::VARIANT, outnewchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::insertBefore(this, core::mem::transmute_copy(&newchild), core::mem::transmute(&refchild)) {
                     Ok(ok__) => {
                         outnewchild.write(core::mem::transmute(ok__));
@@ -9521,9 +14318,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn replaceChild<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void, oldchild: *mut core::ffi::c_void, outoldchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn replaceChild<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void, oldchild: *mut core::ffi::c_void, outoldchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::replaceChild(this, core::mem::transmute_copy(&newchild), core::mem::transmute_copy(&oldchild)) {
                     Ok(ok__) => {
                         outoldchild.write(core::mem::transmute(ok__));
@@ -9533,9 +14330,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn removeChild<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, childnode: *mut core::ffi::c_void, oldchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn removeChild<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, childnode: *mut core::ffi::c_void, oldchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::removeChild(this, core::mem::transmute_copy(&childnode)) {
                     Ok(ok__) => {
                         oldchild.write(core::mem::transmute(ok__));
@@ -9545,9 +14342,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn appendChild<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void, outnewchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn appendChild<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, newchild: *mut core::ffi::c_void, outnewchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::appendChild(this, core::mem::transmute_copy(&newchild)) {
                     Ok(ok__) => {
                         outnewchild.write(core::mem::transmute(ok__));
@@ -9557,9 +14354,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn hasChildNodes<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, haschild: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn hasChildNodes<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, haschild: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::hasChildNodes(this) {
                     Ok(ok__) => {
                         haschild.write(core::mem::transmute(ok__));
@@ -9569,9 +14366,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ownerDocument<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, xmldomdocument: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn ownerDocument<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, xmldomdocument: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::ownerDocument(this) {
                     Ok(ok__) => {
                         xmldomdocument.write(core::mem::transmute(ok__));
@@ -9581,9 +14378,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn cloneNode<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, deep: super::super::super::Foundation::VARIANT_BOOL, cloneroot: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn cloneNode<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, deep: super::super::super::Foundation::VARIANT_BOOL, cloneroot: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::cloneNode(this, core::mem::transmute_copy(&deep)) {
                     Ok(ok__) => {
                         cloneroot.write(core::mem::transmute(ok__));
@@ -9593,9 +14390,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn nodeTypeString<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, nodetype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn nodeTypeString<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, nodetype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::nodeTypeString(this) {
                     Ok(ok__) => {
                         nodetype.write(core::mem::transmute(ok__));
@@ -9605,9 +14402,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn text<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, text: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn text<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, text: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::text(this) {
                     Ok(ok__) => {
                         text.write(core::mem::transmute(ok__));
@@ -9617,15 +14414,15 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Settext<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, text: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Settext<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, text: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IXMLDOMNode_Impl::Settext(this, core::mem::transmute(&text)).into()
             }
         }
-        unsafe extern "system" fn specified<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, isspecified: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn specified<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, isspecified: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::specified(this) {
                     Ok(ok__) => {
                         isspecified.write(core::mem::transmute(ok__));
@@ -9635,9 +14432,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn definition<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, definitionnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn definition<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, definitionnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::definition(this) {
                     Ok(ok__) => {
                         definitionnode.write(core::mem::transmute(ok__));
@@ -9647,9 +14444,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn nodeTypedValue<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, typedvalue: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn nodeTypedValue<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, typedvalue: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::nodeTypedValue(this) {
                     Ok(ok__) => {
                         typedvalue.write(core::mem::transmute(ok__));
@@ -9659,15 +14456,15 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetnodeTypedValue<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, typedvalue: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetnodeTypedValue<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, typedvalue: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IXMLDOMNode_Impl::SetnodeTypedValue(this, core::mem::transmute(&typedvalue)).into()
             }
         }
-        unsafe extern "system" fn dataType<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, datatypename: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn dataType<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, datatypename: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::dataType(this) {
                     Ok(ok__) => {
                         datatypename.write(core::mem::transmute(ok__));
@@ -9677,15 +14474,15 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetdataType<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, datatypename: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetdataType<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, datatypename: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IXMLDOMNode_Impl::SetdataType(this, core::mem::transmute(&datatypename)).into()
             }
         }
-        unsafe extern "system" fn xml<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, xmlstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn xml<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, xmlstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::xml(this) {
                     Ok(ok__) => {
                         xmlstring.write(core::mem::transmute(ok__));
@@ -9695,9 +14492,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn transformNode<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, stylesheet: *mut core::ffi::c_void, xmlstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn transformNode<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, stylesheet: *mut core::ffi::c_void, xmlstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::transformNode(this, core::mem::transmute_copy(&stylesheet)) {
                     Ok(ok__) => {
                         xmlstring.write(core::mem::transmute(ok__));
@@ -9707,9 +14504,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn selectNodes<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, querystring: *mut core::ffi::c_void, resultlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn selectNodes<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, querystring: *mut core::ffi::c_void, resultlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::selectNodes(this, core::mem::transmute(&querystring)) {
                     Ok(ok__) => {
                         resultlist.write(core::mem::transmute(ok__));
@@ -9719,9 +14516,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn selectSingleNode<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, querystring: *mut core::ffi::c_void, resultnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn selectSingleNode<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, querystring: *mut core::ffi::c_void, resultnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::selectSingleNode(this, core::mem::transmute(&querystring)) {
                     Ok(ok__) => {
                         resultnode.write(core::mem::transmute(ok__));
@@ -9731,9 +14528,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn parsed<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, isparsed: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn parsed<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, isparsed: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::parsed(this) {
                     Ok(ok__) => {
                         isparsed.write(core::mem::transmute(ok__));
@@ -9743,9 +14540,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn namespaceURI<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, namespaceuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn namespaceURI<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, namespaceuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::namespaceURI(this) {
                     Ok(ok__) => {
                         namespaceuri.write(core::mem::transmute(ok__));
@@ -9755,9 +14552,9 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn prefix<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, prefixstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn prefix<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, prefixstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::prefix(this) {
                     Ok(ok__) => {
                         prefixstring.write(core::mem::transmute(ok__));
@@ -9767,67 +14564,64 @@ impl IXMLDOMNode_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn baseName<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, namestring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn baseName<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, namestring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMNode_Impl::baseName(this) {
                     Ok(ok__) => {
                         namestring.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
-                    }
-                    Err(err) => err.into(),
-                }
-            }
-        }
-        unsafe extern "system" fn transformNodeToObject<Identity: IXMLDOMNode_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, stylesheet: *mut core::ffi::c_void, outputobject: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IXMLDOMNode_Impl::transformNodeToObject(this, core::mem::transmute_copy(&stylesheet), core::mem::transmute(&outputobject)).into()
-            }
-        }
-        Self {
-            base__: super::super::super::System::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
-            nodeName: nodeName::<Identity, OFFSET>,
-            nodeValue: nodeValue::<Identity, OFFSET>,
-            SetnodeValue: SetnodeValue::<Identity, OFFSET>,
-            nodeType: nodeType::<Identity, OFFSET>,
-            parentNode: parentNode::<Identity, OFFSET>,
-            childNodes: childNodes::<Identity, OFFSET>,
-            firstChild: firstChild::<Identity, OFFSET>,
-            lastChild: lastChild::<Identity, OFFSET>,
-            previousSibling: previousSibling::<Identity, OFFSET>,
-            nextSibling: nextSibling::<Identity, OFFSET>,
-            attributes: attributes::<Identity, OFFSET>,
-            insertBefore: insertBefore::<Identity, OFFSET>,
-            replaceChild: replaceChild::<Identity, OFFSET>,
-            removeChild: removeChild::<Identity, OFFSET>,
-            appendChild: appendChild::<Identity, OFFSET>,
-            hasChildNodes: hasChildNodes::<Identity, OFFSET>,
-            ownerDocument: ownerDocument::<Identity, OFFSET>,
-            cloneNode: cloneNode::<Identity, OFFSET>,
-            nodeTypeString: nodeTypeString::<Identity, OFFSET>,
-            text: text::<Identity, OFFSET>,
-            Settext: Settext::<Identity, OFFSET>,
-            specified: specified::<Identity, OFFSET>,
-            definition: definition::<Identity, OFFSET>,
-            nodeTypedValue: nodeTypedValue::<Identity, OFFSET>,
-            SetnodeTypedValue: SetnodeTypedValue::<Identity, OFFSET>,
-            dataType: dataType::<Identity, OFFSET>,
-            SetdataType: SetdataType::<Identity, OFFSET>,
-            xml: xml::<Identity, OFFSET>,
-            transformNode: transformNode::<Identity, OFFSET>,
-            selectNodes: selectNodes::<Identity, OFFSET>,
-            selectSingleNode: selectSingleNode::<Identity, OFFSET>,
-            parsed: parsed::<Identity, OFFSET>,
-            namespaceURI: namespaceURI::<Identity, OFFSET>,
-            prefix: prefix::<Identity, OFFSET>,
-            baseName: baseName::<Identity, OFFSET>,
-            transformNodeToObject: transformNodeToObject::<Identity, OFFSET>,
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IXMLDOMNode as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID
-    }
+        unsafe extern "system" fn transformNodeToObject<Identity: windows_core::IUnknownImpl + IXMLDOMNode_Impl>(this: *mut core::ffi::c_void, stylesheet: *mut core::ffi::c_void, outputobject: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMNode_Impl::transformNodeToObject(this, core::mem::transmute_copy(&stylesheet), core::mem::transmute(&outputobject)).into()
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            nodeName: nodeName::<Identity>,
+            nodeValue: nodeValue::<Identity>,
+            SetnodeValue: SetnodeValue::<Identity>,
+            nodeType: nodeType::<Identity>,
+            parentNode: parentNode::<Identity>,
+            childNodes: childNodes::<Identity>,
+            firstChild: firstChild::<Identity>,
+            lastChild: lastChild::<Identity>,
+            previousSibling: previousSibling::<Identity>,
+            nextSibling: nextSibling::<Identity>,
+            attributes: attributes::<Identity>,
+            insertBefore: insertBefore::<Identity>,
+            replaceChild: replaceChild::<Identity>,
+            removeChild: removeChild::<Identity>,
+            appendChild: appendChild::<Identity>,
+            hasChildNodes: hasChildNodes::<Identity>,
+            ownerDocument: ownerDocument::<Identity>,
+            cloneNode: cloneNode::<Identity>,
+            nodeTypeString: nodeTypeString::<Identity>,
+            text: text::<Identity>,
+            Settext: Settext::<Identity>,
+            specified: specified::<Identity>,
+            definition: definition::<Identity>,
+            nodeTypedValue: nodeTypedValue::<Identity>,
+            SetnodeTypedValue: SetnodeTypedValue::<Identity>,
+            dataType: dataType::<Identity>,
+            SetdataType: SetdataType::<Identity>,
+            xml: xml::<Identity>,
+            transformNode: transformNode::<Identity>,
+            selectNodes: selectNodes::<Identity>,
+            selectSingleNode: selectSingleNode::<Identity>,
+            parsed: parsed::<Identity>,
+            namespaceURI: namespaceURI::<Identity>,
+            prefix: prefix::<Identity>,
+            baseName: baseName::<Identity>,
+            transformNodeToObject: transformNodeToObject::<Identity>,
+        }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXMLDOMNode {}
@@ -9962,6 +14756,76 @@ impl IXMLDOMNodeList_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMNodeList_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMNodeList_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn get_item<Identity: windows_core::IUnknownImpl + IXMLDOMNodeList_Impl>(this: *mut core::ffi::c_void, index: i32, listitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMNodeList_Impl::get_item(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        listitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn length<Identity: windows_core::IUnknownImpl + IXMLDOMNodeList_Impl>(this: *mut core::ffi::c_void, listlength: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMNodeList_Impl::length(this) {
+                    Ok(ok__) => {
+                        listlength.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn nextNode<Identity: windows_core::IUnknownImpl + IXMLDOMNodeList_Impl>(this: *mut core::ffi::c_void, nextitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMNodeList_Impl::nextNode(this) {
+                    Ok(ok__) => {
+                        nextitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn reset<Identity: windows_core::IUnknownImpl + IXMLDOMNodeList_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMNodeList_Impl::reset(this).into()
+            }
+        }
+        unsafe extern "system" fn _newEnum<Identity: windows_core::IUnknownImpl + IXMLDOMNodeList_Impl>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMNodeList_Impl::_newEnum(this) {
+                    Ok(ok__) => {
+                        ppunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            get_item: get_item::<Identity>,
+            length: length::<Identity>,
+            nextNode: nextNode::<Identity>,
+            reset: reset::<Identity>,
+            _newEnum: _newEnum::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXMLDOMNodeList {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IXMLDOMNotation, IXMLDOMNotation_Vtbl, 0x2933bf8c_7b36_11d2_b20e_00c04f983e60);
@@ -10044,6 +14908,39 @@ impl IXMLDOMNotation_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMNotation_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMNotation_Vtbl
+where
+    IXMLDOMNode_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn publicId<Identity: windows_core::IUnknownImpl + IXMLDOMNotation_Impl>(this: *mut core::ffi::c_void, publicid: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMNotation_Impl::publicId(this) {
+                    Ok(ok__) => {
+                        publicid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn systemId<Identity: windows_core::IUnknownImpl + IXMLDOMNotation_Impl>(this: *mut core::ffi::c_void, systemid: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMNotation_Impl::systemId(this) {
+                    Ok(ok__) => {
+                        systemid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IXMLDOMNode_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, publicId: publicId::<Identity>, systemId: systemId::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXMLDOMNotation {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IXMLDOMParseError, IXMLDOMParseError_Vtbl, 0x3efaa426_272f_11d2_836f_0000f87a7782);
@@ -10227,6 +15124,108 @@ impl IXMLDOMParseError_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMParseError_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMParseError_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn errorCode<Identity: windows_core::IUnknownImpl + IXMLDOMParseError_Impl>(this: *mut core::ffi::c_void, errorcode: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMParseError_Impl::errorCode(this) {
+                    Ok(ok__) => {
+                        errorcode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn url<Identity: windows_core::IUnknownImpl + IXMLDOMParseError_Impl>(this: *mut core::ffi::c_void, urlstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMParseError_Impl::url(this) {
+                    Ok(ok__) => {
+                        urlstring.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn reason<Identity: windows_core::IUnknownImpl + IXMLDOMParseError_Impl>(this: *mut core::ffi::c_void, reasonstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMParseError_Impl::reason(this) {
+                    Ok(ok__) => {
+                        reasonstring.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn srcText<Identity: windows_core::IUnknownImpl + IXMLDOMParseError_Impl>(this: *mut core::ffi::c_void, sourcestring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMParseError_Impl::srcText(this) {
+                    Ok(ok__) => {
+                        sourcestring.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn line<Identity: windows_core::IUnknownImpl + IXMLDOMParseError_Impl>(this: *mut core::ffi::c_void, linenumber: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMParseError_Impl::line(this) {
+                    Ok(ok__) => {
+                        linenumber.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn linepos<Identity: windows_core::IUnknownImpl + IXMLDOMParseError_Impl>(this: *mut core::ffi::c_void, lineposition: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMParseError_Impl::linepos(this) {
+                    Ok(ok__) => {
+                        lineposition.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn filepos<Identity: windows_core::IUnknownImpl + IXMLDOMParseError_Impl>(this: *mut core::ffi::c_void, fileposition: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMParseError_Impl::filepos(this) {
+                    Ok(ok__) => {
+                        fileposition.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            errorCode: errorCode::<Identity>,
+            url: url::<Identity>,
+            reason: reason::<Identity>,
+            srcText: srcText::<Identity>,
+            line: line::<Identity>,
+            linepos: linepos::<Identity>,
+            filepos: filepos::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXMLDOMParseError {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IXMLDOMParseError2, IXMLDOMParseError2_Vtbl, 0x3efaa428_272f_11d2_836f_0000f87a7782);
@@ -10347,6 +15346,69 @@ impl IXMLDOMParseError2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMParseError2_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMParseError2_Vtbl
+where
+    IXMLDOMParseError_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn errorXPath<Identity: windows_core::IUnknownImpl + IXMLDOMParseError2_Impl>(this: *mut core::ffi::c_void, xpathexpr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMParseError2_Impl::errorXPath(this) {
+                    Ok(ok__) => {
+                        xpathexpr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn allErrors<Identity: windows_core::IUnknownImpl + IXMLDOMParseError2_Impl>(this: *mut core::ffi::c_void, allerrors: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMParseError2_Impl::allErrors(this) {
+                    Ok(ok__) => {
+                        allerrors.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn errorParameters<Identity: windows_core::IUnknownImpl + IXMLDOMParseError2_Impl>(this: *mut core::ffi::c_void, index: i32, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMParseError2_Impl::errorParameters(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        param1.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn errorParametersCount<Identity: windows_core::IUnknownImpl + IXMLDOMParseError2_Impl>(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMParseError2_Impl::errorParametersCount(this) {
+                    Ok(ok__) => {
+                        count.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IXMLDOMParseError_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            errorXPath: errorXPath::<Identity>,
+            allErrors: allErrors::<Identity>,
+            errorParameters: errorParameters::<Identity>,
+            errorParametersCount: errorParametersCount::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXMLDOMParseError2 {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IXMLDOMParseErrorCollection, IXMLDOMParseErrorCollection_Vtbl, 0x3efaa429_272f_11d2_836f_0000f87a7782);
@@ -10453,9 +15515,82 @@ impl IXMLDOMParseErrorCollection_Vtbl {
                 IXMLDOMParseErrorCollection_Impl::reset(this).into()
             }
         }
-        unsafe extern "system" fn _newEnum<Identity: IXMLDOMParseErrorCollection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn _newEnum<Identity: IXMLDOMParseErrorCollection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMParseErrorCollection_Impl::_newEnum(this) {
+                    Ok(ok__) => {
+                        ppunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: super::super::super::System::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
+            get_item: get_item::<Identity, OFFSET>,
+            length: length::<Identity, OFFSET>,
+            next: next::<Identity, OFFSET>,
+            reset: reset::<Identity, OFFSET>,
+            _newEnum: _newEnum::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMParseErrorCollection as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMParseErrorCollection_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMParseErrorCollection_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn get_item<Identity: windows_core::IUnknownImpl + IXMLDOMParseErrorCollection_Impl>(this: *mut core::ffi::c_void, index: i32, error: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMParseErrorCollection_Impl::get_item(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        error.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn length<Identity: windows_core::IUnknownImpl + IXMLDOMParseErrorCollection_Impl>(this: *mut core::ffi::c_void, length: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMParseErrorCollection_Impl::length(this) {
+                    Ok(ok__) => {
+                        length.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn next<Identity: windows_core::IUnknownImpl + IXMLDOMParseErrorCollection_Impl>(this: *mut core::ffi::c_void, error: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMParseErrorCollection_Impl::next(this) {
+                    Ok(ok__) => {
+                        error.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn reset<Identity: windows_core::IUnknownImpl + IXMLDOMParseErrorCollection_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMParseErrorCollection_Impl::reset(this).into()
+            }
+        }
+        unsafe extern "system" fn _newEnum<Identity: windows_core::IUnknownImpl + IXMLDOMParseErrorCollection_Impl>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMParseErrorCollection_Impl::_newEnum(this) {
                     Ok(ok__) => {
                         ppunk.write(core::mem::transmute(ok__));
@@ -10466,17 +15601,14 @@ impl IXMLDOMParseErrorCollection_Vtbl {
             }
         }
         Self {
-            base__: super::super::super::System::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
-            get_item: get_item::<Identity, OFFSET>,
-            length: length::<Identity, OFFSET>,
-            next: next::<Identity, OFFSET>,
-            reset: reset::<Identity, OFFSET>,
-            _newEnum: _newEnum::<Identity, OFFSET>,
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            get_item: get_item::<Identity>,
+            length: length::<Identity>,
+            next: next::<Identity>,
+            reset: reset::<Identity>,
+            _newEnum: _newEnum::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IXMLDOMParseErrorCollection as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID
-    }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXMLDOMParseErrorCollection {}
@@ -10569,6 +15701,50 @@ impl IXMLDOMProcessingInstruction_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMProcessingInstruction_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMProcessingInstruction_Vtbl
+where
+    IXMLDOMNode_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn target<Identity: windows_core::IUnknownImpl + IXMLDOMProcessingInstruction_Impl>(this: *mut core::ffi::c_void, name: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMProcessingInstruction_Impl::target(this) {
+                    Ok(ok__) => {
+                        name.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn data<Identity: windows_core::IUnknownImpl + IXMLDOMProcessingInstruction_Impl>(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMProcessingInstruction_Impl::data(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Setdata<Identity: windows_core::IUnknownImpl + IXMLDOMProcessingInstruction_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMProcessingInstruction_Impl::Setdata(this, core::mem::transmute(&value)).into()
+            }
+        }
+        Self {
+            base__: <IXMLDOMNode_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            target: target::<Identity>,
+            data: data::<Identity>,
+            Setdata: Setdata::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXMLDOMProcessingInstruction {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IXMLDOMSchemaCollection, IXMLDOMSchemaCollection_Vtbl, 0x373984c8_b845_449b_91e7_45ac83036ade);
@@ -10732,6 +15908,90 @@ impl IXMLDOMSchemaCollection_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMSchemaCollection_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMSchemaCollection_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn add<Identity: windows_core::IUnknownImpl + IXMLDOMSchemaCollection_Impl>(this: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void, var: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMSchemaCollection_Impl::add(this, core::mem::transmute(&namespaceuri), core::mem::transmute(&var)).into()
+            }
+        }
+        unsafe extern "system" fn get<Identity: windows_core::IUnknownImpl + IXMLDOMSchemaCollection_Impl>(this: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void, schemanode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMSchemaCollection_Impl::get(this, core::mem::transmute(&namespaceuri)) {
+                    Ok(ok__) => {
+                        schemanode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn remove<Identity: windows_core::IUnknownImpl + IXMLDOMSchemaCollection_Impl>(this: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMSchemaCollection_Impl::remove(this, core::mem::transmute(&namespaceuri)).into()
+            }
+        }
+        unsafe extern "system" fn length<Identity: windows_core::IUnknownImpl + IXMLDOMSchemaCollection_Impl>(this: *mut core::ffi::c_void, length: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMSchemaCollection_Impl::length(this) {
+                    Ok(ok__) => {
+                        length.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_namespaceURI<Identity: windows_core::IUnknownImpl + IXMLDOMSchemaCollection_Impl>(this: *mut core::ffi::c_void, index: i32, length: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMSchemaCollection_Impl::get_namespaceURI(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        length.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn addCollection<Identity: windows_core::IUnknownImpl + IXMLDOMSchemaCollection_Impl>(this: *mut core::ffi::c_void, othercollection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMSchemaCollection_Impl::addCollection(this, core::mem::transmute_copy(&othercollection)).into()
+            }
+        }
+        unsafe extern "system" fn _newEnum<Identity: windows_core::IUnknownImpl + IXMLDOMSchemaCollection_Impl>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMSchemaCollection_Impl::_newEnum(this) {
+                    Ok(ok__) => {
+                        ppunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            add: add::<Identity>,
+            get: get::<Identity>,
+            remove: remove::<Identity>,
+            length: length::<Identity>,
+            get_namespaceURI: get_namespaceURI::<Identity>,
+            addCollection: addCollection::<Identity>,
+            _newEnum: _newEnum::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXMLDOMSchemaCollection {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IXMLDOMSchemaCollection2, IXMLDOMSchemaCollection2_Vtbl, 0x50ea08b0_dd1b_4664_9a50_c2f40f4bd79a);
@@ -10858,6 +16118,70 @@ impl IXMLDOMSchemaCollection2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMSchemaCollection2_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMSchemaCollection2_Vtbl
+where
+    IXMLDOMSchemaCollection_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn validate<Identity: windows_core::IUnknownImpl + IXMLDOMSchemaCollection2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMSchemaCollection2_Impl::validate(this).into()
+            }
+        }
+        unsafe extern "system" fn SetvalidateOnLoad<Identity: windows_core::IUnknownImpl + IXMLDOMSchemaCollection2_Impl>(this: *mut core::ffi::c_void, validateonload: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDOMSchemaCollection2_Impl::SetvalidateOnLoad(this, core::mem::transmute_copy(&validateonload)).into()
+            }
+        }
+        unsafe extern "system" fn validateOnLoad<Identity: windows_core::IUnknownImpl + IXMLDOMSchemaCollection2_Impl>(this: *mut core::ffi::c_void, validateonload: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMSchemaCollection2_Impl::validateOnLoad(this) {
+                    Ok(ok__) => {
+                        validateonload.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getSchema<Identity: windows_core::IUnknownImpl + IXMLDOMSchemaCollection2_Impl>(this: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void, schema: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMSchemaCollection2_Impl::getSchema(this, core::mem::transmute(&namespaceuri)) {
+                    Ok(ok__) => {
+                        schema.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getDeclaration<Identity: windows_core::IUnknownImpl + IXMLDOMSchemaCollection2_Impl>(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void, item: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMSchemaCollection2_Impl::getDeclaration(this, core::mem::transmute_copy(&node)) {
+                    Ok(ok__) => {
+                        item.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IXMLDOMSchemaCollection_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            validate: validate::<Identity>,
+            SetvalidateOnLoad: SetvalidateOnLoad::<Identity>,
+            validateOnLoad: validateOnLoad::<Identity>,
+            getSchema: getSchema::<Identity>,
+            getDeclaration: getDeclaration::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXMLDOMSchemaCollection2 {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IXMLDOMSelection, IXMLDOMSelection_Vtbl, 0xaa634fc7_5888_44a7_a257_3a47150d3a0e);
@@ -10987,15 +16311,148 @@ impl IXMLDOMSelection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Setexpr<Identity: IXMLDOMSelection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, expression: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Setexpr<Identity: IXMLDOMSelection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, expression: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IXMLDOMSelection_Impl::Setexpr(this, core::mem::transmute(&expression)).into()
+            }
+        }
+        unsafe extern "system" fn context<Identity: IXMLDOMSelection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMSelection_Impl::context(this) {
+                    Ok(ok__) => {
+                        ppnode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putref_context<Identity: IXMLDOMSelection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IXMLDOMSelection_Impl::putref_context(this, core::mem::transmute_copy(&pnode)).into()
+            }
+        }
+        unsafe extern "system" fn peekNode<Identity: IXMLDOMSelection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMSelection_Impl::peekNode(this) {
+                    Ok(ok__) => {
+                        ppnode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn matches<Identity: IXMLDOMSelection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMSelection_Impl::matches(this, core::mem::transmute_copy(&pnode)) {
+                    Ok(ok__) => {
+                        ppnode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn removeNext<Identity: IXMLDOMSelection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMSelection_Impl::removeNext(this) {
+                    Ok(ok__) => {
+                        ppnode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn removeAll<Identity: IXMLDOMSelection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IXMLDOMSelection_Impl::removeAll(this).into()
+            }
+        }
+        unsafe extern "system" fn clone<Identity: IXMLDOMSelection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMSelection_Impl::clone(this) {
+                    Ok(ok__) => {
+                        ppnode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getProperty<Identity: IXMLDOMSelection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDOMSelection_Impl::getProperty(this, core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn setProperty<Identity: IXMLDOMSelection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IXMLDOMSelection_Impl::setProperty(this, core::mem::transmute(&name), core::mem::transmute(&value)).into()
+            }
+        }
+        Self {
+            base__: IXMLDOMNodeList_Vtbl::new::<Identity, OFFSET>(),
+            expr: expr::<Identity, OFFSET>,
+            Setexpr: Setexpr::<Identity, OFFSET>,
+            context: context::<Identity, OFFSET>,
+            putref_context: putref_context::<Identity, OFFSET>,
+            peekNode: peekNode::<Identity, OFFSET>,
+            matches: matches::<Identity, OFFSET>,
+            removeNext: removeNext::<Identity, OFFSET>,
+            removeAll: removeAll::<Identity, OFFSET>,
+            clone: clone::<Identity, OFFSET>,
+            getProperty: getProperty::<Identity, OFFSET>,
+            setProperty: setProperty::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDOMSelection as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID || iid == &<IXMLDOMNodeList as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMSelection_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMSelection_Vtbl
+where
+    IXMLDOMNodeList_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn expr<Identity: windows_core::IUnknownImpl + IXMLDOMSelection_Impl>(this: *mut core::ffi::c_void, expression: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMSelection_Impl::expr(this) {
+                    Ok(ok__) => {
+                        expression.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Setexpr<Identity: windows_core::IUnknownImpl + IXMLDOMSelection_Impl>(this: *mut core::ffi::c_void, expression: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IXMLDOMSelection_Impl::Setexpr(this, core::mem::transmute(&expression)).into()
             }
         }
-        unsafe extern "system" fn context<Identity: IXMLDOMSelection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn context<Identity: windows_core::IUnknownImpl + IXMLDOMSelection_Impl>(this: *mut core::ffi::c_void, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMSelection_Impl::context(this) {
                     Ok(ok__) => {
                         ppnode.write(core::mem::transmute(ok__));
@@ -11005,15 +16462,15 @@ impl IXMLDOMSelection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn putref_context<Identity: IXMLDOMSelection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn putref_context<Identity: windows_core::IUnknownImpl + IXMLDOMSelection_Impl>(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IXMLDOMSelection_Impl::putref_context(this, core::mem::transmute_copy(&pnode)).into()
             }
         }
-        unsafe extern "system" fn peekNode<Identity: IXMLDOMSelection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn peekNode<Identity: windows_core::IUnknownImpl + IXMLDOMSelection_Impl>(this: *mut core::ffi::c_void, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMSelection_Impl::peekNode(this) {
                     Ok(ok__) => {
                         ppnode.write(core::mem::transmute(ok__));
@@ -11023,9 +16480,9 @@ impl IXMLDOMSelection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn matches<Identity: IXMLDOMSelection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn matches<Identity: windows_core::IUnknownImpl + IXMLDOMSelection_Impl>(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMSelection_Impl::matches(this, core::mem::transmute_copy(&pnode)) {
                     Ok(ok__) => {
                         ppnode.write(core::mem::transmute(ok__));
@@ -11035,9 +16492,9 @@ impl IXMLDOMSelection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn removeNext<Identity: IXMLDOMSelection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn removeNext<Identity: windows_core::IUnknownImpl + IXMLDOMSelection_Impl>(this: *mut core::ffi::c_void, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMSelection_Impl::removeNext(this) {
                     Ok(ok__) => {
                         ppnode.write(core::mem::transmute(ok__));
@@ -11047,15 +16504,15 @@ impl IXMLDOMSelection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn removeAll<Identity: IXMLDOMSelection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn removeAll<Identity: windows_core::IUnknownImpl + IXMLDOMSelection_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IXMLDOMSelection_Impl::removeAll(this).into()
             }
         }
-        unsafe extern "system" fn clone<Identity: IXMLDOMSelection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn clone<Identity: windows_core::IUnknownImpl + IXMLDOMSelection_Impl>(this: *mut core::ffi::c_void, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMSelection_Impl::clone(this) {
                     Ok(ok__) => {
                         ppnode.write(core::mem::transmute(ok__));
@@ -11065,9 +16522,9 @@ impl IXMLDOMSelection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn getProperty<Identity: IXMLDOMSelection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn getProperty<Identity: windows_core::IUnknownImpl + IXMLDOMSelection_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDOMSelection_Impl::getProperty(this, core::mem::transmute(&name)) {
                     Ok(ok__) => {
                         value.write(core::mem::transmute(ok__));
@@ -11077,30 +16534,27 @@ impl IXMLDOMSelection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn setProperty<Identity: IXMLDOMSelection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn setProperty<Identity: windows_core::IUnknownImpl + IXMLDOMSelection_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IXMLDOMSelection_Impl::setProperty(this, core::mem::transmute(&name), core::mem::transmute(&value)).into()
             }
         }
         Self {
-            base__: IXMLDOMNodeList_Vtbl::new::<Identity, OFFSET>(),
-            expr: expr::<Identity, OFFSET>,
-            Setexpr: Setexpr::<Identity, OFFSET>,
-            context: context::<Identity, OFFSET>,
-            putref_context: putref_context::<Identity, OFFSET>,
-            peekNode: peekNode::<Identity, OFFSET>,
-            matches: matches::<Identity, OFFSET>,
-            removeNext: removeNext::<Identity, OFFSET>,
-            removeAll: removeAll::<Identity, OFFSET>,
-            clone: clone::<Identity, OFFSET>,
-            getProperty: getProperty::<Identity, OFFSET>,
-            setProperty: setProperty::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IXMLDOMSelection as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID || iid == &<IXMLDOMNodeList as windows_core::Interface>::IID
-    }
+            base__: <IXMLDOMNodeList_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            expr: expr::<Identity>,
+            Setexpr: Setexpr::<Identity>,
+            context: context::<Identity>,
+            putref_context: putref_context::<Identity>,
+            peekNode: peekNode::<Identity>,
+            matches: matches::<Identity>,
+            removeNext: removeNext::<Identity>,
+            removeAll: removeAll::<Identity>,
+            clone: clone::<Identity>,
+            getProperty: getProperty::<Identity>,
+            setProperty: setProperty::<Identity>,
+        }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXMLDOMSelection {}
@@ -11157,6 +16611,27 @@ impl IXMLDOMText_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDOMText_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDOMText_Vtbl
+where
+    IXMLDOMCharacterData_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn splitText<Identity: windows_core::IUnknownImpl + IXMLDOMText_Impl>(this: *mut core::ffi::c_void, offset: i32, righthandtextnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDOMText_Impl::splitText(this, core::mem::transmute_copy(&offset)) {
+                    Ok(ok__) => {
+                        righthandtextnode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IXMLDOMCharacterData_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, splitText: splitText::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXMLDOMText {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IXMLDSOControl, IXMLDSOControl_Vtbl, 0x310afa62_0575_11d2_9ca9_0060b0ec3d39);
@@ -11283,6 +16758,70 @@ impl IXMLDSOControl_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDSOControl_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDSOControl_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn XMLDocument<Identity: windows_core::IUnknownImpl + IXMLDSOControl_Impl>(this: *mut core::ffi::c_void, ppdoc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDSOControl_Impl::XMLDocument(this) {
+                    Ok(ok__) => {
+                        ppdoc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetXMLDocument<Identity: windows_core::IUnknownImpl + IXMLDSOControl_Impl>(this: *mut core::ffi::c_void, ppdoc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDSOControl_Impl::SetXMLDocument(this, core::mem::transmute_copy(&ppdoc)).into()
+            }
+        }
+        unsafe extern "system" fn JavaDSOCompatible<Identity: windows_core::IUnknownImpl + IXMLDSOControl_Impl>(this: *mut core::ffi::c_void, fjavadsocompatible: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDSOControl_Impl::JavaDSOCompatible(this) {
+                    Ok(ok__) => {
+                        fjavadsocompatible.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetJavaDSOCompatible<Identity: windows_core::IUnknownImpl + IXMLDSOControl_Impl>(this: *mut core::ffi::c_void, fjavadsocompatible: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLDSOControl_Impl::SetJavaDSOCompatible(this, core::mem::transmute_copy(&fjavadsocompatible)).into()
+            }
+        }
+        unsafe extern "system" fn readyState<Identity: windows_core::IUnknownImpl + IXMLDSOControl_Impl>(this: *mut core::ffi::c_void, state: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLDSOControl_Impl::readyState(this) {
+                    Ok(ok__) => {
+                        state.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            XMLDocument: XMLDocument::<Identity>,
+            SetXMLDocument: SetXMLDocument::<Identity>,
+            JavaDSOCompatible: JavaDSOCompatible::<Identity>,
+            SetJavaDSOCompatible: SetJavaDSOCompatible::<Identity>,
+            readyState: readyState::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXMLDSOControl {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IXMLDocument, IXMLDocument_Vtbl, 0xf52e2b61_18a1_11d1_b105_00805f49916b);
@@ -11418,11 +16957,195 @@ pub trait IXMLDocument_Impl: super::super::super::System::Com::IDispatch_Impl {
     fn createElement(&self, vtype: &super::super::super::System::Variant::VARIANT, var1: &super::super::super::System::Variant::VARIANT) -> windows_core::Result<IXMLElement>;
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl IXMLDocument_Vtbl {
-    pub const fn new<Identity: IXMLDocument_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn root<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+impl IXMLDocument_Vtbl {
+    pub const fn new<Identity: IXMLDocument_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn root<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument_Impl::root(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn fileSize<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument_Impl::fileSize(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn fileModifiedDate<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument_Impl::fileModifiedDate(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn fileUpdatedDate<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument_Impl::fileUpdatedDate(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn URL<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument_Impl::URL(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetURL<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IXMLDocument_Impl::SetURL(this, core::mem::transmute(&p)).into()
+            }
+        }
+        unsafe extern "system" fn mimeType<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument_Impl::mimeType(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn readyState<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pl: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument_Impl::readyState(this) {
+                    Ok(ok__) => {
+                        pl.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn charset<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument_Impl::charset(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Setcharset<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IXMLDocument_Impl::Setcharset(this, core::mem::transmute(&p)).into()
+            }
+        }
+        unsafe extern "system" fn version<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument_Impl::version(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn doctype<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument_Impl::doctype(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn dtdURL<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument_Impl::dtdURL(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn createElement<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, vtype: super::super::super::System::Variant::VARIANT, var1: super::super::super::System::Variant::VARIANT, ppelem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument_Impl::createElement(this, core::mem::transmute(&vtype), core::mem::transmute(&var1)) {
+                    Ok(ok__) => {
+                        ppelem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: super::super::super::System::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
+            root: root::<Identity, OFFSET>,
+            fileSize: fileSize::<Identity, OFFSET>,
+            fileModifiedDate: fileModifiedDate::<Identity, OFFSET>,
+            fileUpdatedDate: fileUpdatedDate::<Identity, OFFSET>,
+            URL: URL::<Identity, OFFSET>,
+            SetURL: SetURL::<Identity, OFFSET>,
+            mimeType: mimeType::<Identity, OFFSET>,
+            readyState: readyState::<Identity, OFFSET>,
+            charset: charset::<Identity, OFFSET>,
+            Setcharset: Setcharset::<Identity, OFFSET>,
+            version: version::<Identity, OFFSET>,
+            doctype: doctype::<Identity, OFFSET>,
+            dtdURL: dtdURL::<Identity, OFFSET>,
+            createElement: createElement::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDocument as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDocument_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDocument_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn root<Identity: windows_core::IUnknownImpl + IXMLDocument_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument_Impl::root(this) {
                     Ok(ok__) => {
                         p.write(core::mem::transmute(ok__));
@@ -11432,9 +17155,9 @@ impl IXMLDocument_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn fileSize<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn fileSize<Identity: windows_core::IUnknownImpl + IXMLDocument_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument_Impl::fileSize(this) {
                     Ok(ok__) => {
                         p.write(core::mem::transmute(ok__));
@@ -11444,9 +17167,9 @@ impl IXMLDocument_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn fileModifiedDate<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn fileModifiedDate<Identity: windows_core::IUnknownImpl + IXMLDocument_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument_Impl::fileModifiedDate(this) {
                     Ok(ok__) => {
                         p.write(core::mem::transmute(ok__));
@@ -11456,9 +17179,9 @@ impl IXMLDocument_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn fileUpdatedDate<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn fileUpdatedDate<Identity: windows_core::IUnknownImpl + IXMLDocument_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument_Impl::fileUpdatedDate(this) {
                     Ok(ok__) => {
                         p.write(core::mem::transmute(ok__));
@@ -11468,9 +17191,9 @@ impl IXMLDocument_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn URL<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn URL<Identity: windows_core::IUnknownImpl + IXMLDocument_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument_Impl::URL(this) {
                     Ok(ok__) => {
                         p.write(core::mem::transmute(ok__));
@@ -11480,15 +17203,15 @@ impl IXMLDocument_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetURL<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetURL<Identity: windows_core::IUnknownImpl + IXMLDocument_Impl>(this: *mut core::ffi::c_void, p: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IXMLDocument_Impl::SetURL(this, core::mem::transmute(&p)).into()
             }
         }
-        unsafe extern "system" fn mimeType<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn mimeType<Identity: windows_core::IUnknownImpl + IXMLDocument_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument_Impl::mimeType(this) {
                     Ok(ok__) => {
                         p.write(core::mem::transmute(ok__));
@@ -11498,9 +17221,9 @@ impl IXMLDocument_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn readyState<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pl: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn readyState<Identity: windows_core::IUnknownImpl + IXMLDocument_Impl>(this: *mut core::ffi::c_void, pl: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument_Impl::readyState(this) {
                     Ok(ok__) => {
                         pl.write(core::mem::transmute(ok__));
@@ -11510,9 +17233,9 @@ impl IXMLDocument_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn charset<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn charset<Identity: windows_core::IUnknownImpl + IXMLDocument_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument_Impl::charset(this) {
                     Ok(ok__) => {
                         p.write(core::mem::transmute(ok__));
@@ -11522,15 +17245,15 @@ impl IXMLDocument_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Setcharset<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Setcharset<Identity: windows_core::IUnknownImpl + IXMLDocument_Impl>(this: *mut core::ffi::c_void, p: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IXMLDocument_Impl::Setcharset(this, core::mem::transmute(&p)).into()
             }
         }
-        unsafe extern "system" fn version<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn version<Identity: windows_core::IUnknownImpl + IXMLDocument_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument_Impl::version(this) {
                     Ok(ok__) => {
                         p.write(core::mem::transmute(ok__));
@@ -11540,9 +17263,9 @@ impl IXMLDocument_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn doctype<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn doctype<Identity: windows_core::IUnknownImpl + IXMLDocument_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument_Impl::doctype(this) {
                     Ok(ok__) => {
                         p.write(core::mem::transmute(ok__));
@@ -11552,9 +17275,9 @@ impl IXMLDocument_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn dtdURL<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn dtdURL<Identity: windows_core::IUnknownImpl + IXMLDocument_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument_Impl::dtdURL(this) {
                     Ok(ok__) => {
                         p.write(core::mem::transmute(ok__));
@@ -11564,9 +17287,9 @@ impl IXMLDocument_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn createElement<Identity: IXMLDocument_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, vtype: super::super::super::System::Variant::VARIANT, var1: super::super::super::System::Variant::VARIANT, ppelem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn createElement<Identity: windows_core::IUnknownImpl + IXMLDocument_Impl>(this: *mut core::ffi::c_void, vtype: super::super::super::System::Variant::VARIANT, var1: super::super::super::System::Variant::VARIANT, ppelem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument_Impl::createElement(this, core::mem::transmute(&vtype), core::mem::transmute(&var1)) {
                     Ok(ok__) => {
                         ppelem.write(core::mem::transmute(ok__));
@@ -11577,26 +17300,23 @@ impl IXMLDocument_Vtbl {
             }
         }
         Self {
-            base__: super::super::super::System::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
-            root: root::<Identity, OFFSET>,
-            fileSize: fileSize::<Identity, OFFSET>,
-            fileModifiedDate: fileModifiedDate::<Identity, OFFSET>,
-            fileUpdatedDate: fileUpdatedDate::<Identity, OFFSET>,
-            URL: URL::<Identity, OFFSET>,
-            SetURL: SetURL::<Identity, OFFSET>,
-            mimeType: mimeType::<Identity, OFFSET>,
-            readyState: readyState::<Identity, OFFSET>,
-            charset: charset::<Identity, OFFSET>,
-            Setcharset: Setcharset::<Identity, OFFSET>,
-            version: version::<Identity, OFFSET>,
-            doctype: doctype::<Identity, OFFSET>,
-            dtdURL: dtdURL::<Identity, OFFSET>,
-            createElement: createElement::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IXMLDocument as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID
-    }
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            root: root::<Identity>,
+            fileSize: fileSize::<Identity>,
+            fileModifiedDate: fileModifiedDate::<Identity>,
+            fileUpdatedDate: fileUpdatedDate::<Identity>,
+            URL: URL::<Identity>,
+            SetURL: SetURL::<Identity>,
+            mimeType: mimeType::<Identity>,
+            readyState: readyState::<Identity>,
+            charset: charset::<Identity>,
+            Setcharset: Setcharset::<Identity>,
+            version: version::<Identity>,
+            doctype: doctype::<Identity>,
+            dtdURL: dtdURL::<Identity>,
+            createElement: createElement::<Identity>,
+        }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXMLDocument {}
@@ -11747,11 +17467,215 @@ pub trait IXMLDocument2_Impl: super::super::super::System::Com::IDispatch_Impl {
     fn Setasync(&self, f: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::Result<()>;
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl IXMLDocument2_Vtbl {
-    pub const fn new<Identity: IXMLDocument2_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn root<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+impl IXMLDocument2_Vtbl {
+    pub const fn new<Identity: IXMLDocument2_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn root<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument2_Impl::root(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn fileSize<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument2_Impl::fileSize(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn fileModifiedDate<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument2_Impl::fileModifiedDate(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn fileUpdatedDate<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument2_Impl::fileUpdatedDate(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn URL<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument2_Impl::URL(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetURL<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IXMLDocument2_Impl::SetURL(this, core::mem::transmute(&p)).into()
+            }
+        }
+        unsafe extern "system" fn mimeType<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument2_Impl::mimeType(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn readyState<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pl: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument2_Impl::readyState(this) {
+                    Ok(ok__) => {
+                        pl.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn charset<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument2_Impl::charset(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Setcharset<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IXMLDocument2_Impl::Setcharset(this, core::mem::transmute(&p)).into()
+            }
+        }
+        unsafe extern "system" fn version<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument2_Impl::version(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn doctype<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument2_Impl::doctype(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn dtdURL<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument2_Impl::dtdURL(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn createElement<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, vtype: super::super::super::System::Variant::VARIANT, var1: super::super::super::System::Variant::VARIANT, ppelem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument2_Impl::createElement(this, core::mem::transmute(&vtype), core::mem::transmute(&var1)) {
+                    Ok(ok__) => {
+                        ppelem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn r#async<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pf: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IXMLDocument2_Impl::r#async(this) {
+                    Ok(ok__) => {
+                        pf.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Setasync<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, f: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IXMLDocument2_Impl::Setasync(this, core::mem::transmute_copy(&f)).into()
+            }
+        }
+        Self {
+            base__: super::super::super::System::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
+            root: root::<Identity, OFFSET>,
+            fileSize: fileSize::<Identity, OFFSET>,
+            fileModifiedDate: fileModifiedDate::<Identity, OFFSET>,
+            fileUpdatedDate: fileUpdatedDate::<Identity, OFFSET>,
+            URL: URL::<Identity, OFFSET>,
+            SetURL: SetURL::<Identity, OFFSET>,
+            mimeType: mimeType::<Identity, OFFSET>,
+            readyState: readyState::<Identity, OFFSET>,
+            charset: charset::<Identity, OFFSET>,
+            Setcharset: Setcharset::<Identity, OFFSET>,
+            version: version::<Identity, OFFSET>,
+            doctype: doctype::<Identity, OFFSET>,
+            dtdURL: dtdURL::<Identity, OFFSET>,
+            createElement: createElement::<Identity, OFFSET>,
+            r#async: r#async::<Identity, OFFSET>,
+            Setasync: Setasync::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLDocument2 as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLDocument2_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLDocument2_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn root<Identity: windows_core::IUnknownImpl + IXMLDocument2_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument2_Impl::root(this) {
                     Ok(ok__) => {
                         p.write(core::mem::transmute(ok__));
@@ -11761,9 +17685,9 @@ impl IXMLDocument2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn fileSize<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn fileSize<Identity: windows_core::IUnknownImpl + IXMLDocument2_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument2_Impl::fileSize(this) {
                     Ok(ok__) => {
                         p.write(core::mem::transmute(ok__));
@@ -11773,9 +17697,9 @@ impl IXMLDocument2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn fileModifiedDate<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn fileModifiedDate<Identity: windows_core::IUnknownImpl + IXMLDocument2_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument2_Impl::fileModifiedDate(this) {
                     Ok(ok__) => {
                         p.write(core::mem::transmute(ok__));
@@ -11785,9 +17709,9 @@ impl IXMLDocument2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn fileUpdatedDate<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn fileUpdatedDate<Identity: windows_core::IUnknownImpl + IXMLDocument2_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument2_Impl::fileUpdatedDate(this) {
                     Ok(ok__) => {
                         p.write(core::mem::transmute(ok__));
@@ -11797,9 +17721,9 @@ impl IXMLDocument2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn URL<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn URL<Identity: windows_core::IUnknownImpl + IXMLDocument2_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument2_Impl::URL(this) {
                     Ok(ok__) => {
                         p.write(core::mem::transmute(ok__));
@@ -11809,15 +17733,15 @@ impl IXMLDocument2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetURL<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetURL<Identity: windows_core::IUnknownImpl + IXMLDocument2_Impl>(this: *mut core::ffi::c_void, p: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IXMLDocument2_Impl::SetURL(this, core::mem::transmute(&p)).into()
             }
         }
-        unsafe extern "system" fn mimeType<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn mimeType<Identity: windows_core::IUnknownImpl + IXMLDocument2_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument2_Impl::mimeType(this) {
                     Ok(ok__) => {
                         p.write(core::mem::transmute(ok__));
@@ -11827,9 +17751,9 @@ impl IXMLDocument2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn readyState<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pl: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn readyState<Identity: windows_core::IUnknownImpl + IXMLDocument2_Impl>(this: *mut core::ffi::c_void, pl: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument2_Impl::readyState(this) {
                     Ok(ok__) => {
                         pl.write(core::mem::transmute(ok__));
@@ -11839,9 +17763,9 @@ impl IXMLDocument2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn charset<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn charset<Identity: windows_core::IUnknownImpl + IXMLDocument2_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument2_Impl::charset(this) {
                     Ok(ok__) => {
                         p.write(core::mem::transmute(ok__));
@@ -11851,15 +17775,15 @@ impl IXMLDocument2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Setcharset<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Setcharset<Identity: windows_core::IUnknownImpl + IXMLDocument2_Impl>(this: *mut core::ffi::c_void, p: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IXMLDocument2_Impl::Setcharset(this, core::mem::transmute(&p)).into()
             }
         }
-        unsafe extern "system" fn version<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn version<Identity: windows_core::IUnknownImpl + IXMLDocument2_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument2_Impl::version(this) {
                     Ok(ok__) => {
                         p.write(core::mem::transmute(ok__));
@@ -11869,9 +17793,9 @@ impl IXMLDocument2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn doctype<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn doctype<Identity: windows_core::IUnknownImpl + IXMLDocument2_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument2_Impl::doctype(this) {
                     Ok(ok__) => {
                         p.write(core::mem::transmute(ok__));
@@ -11881,9 +17805,9 @@ impl IXMLDocument2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn dtdURL<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn dtdURL<Identity: windows_core::IUnknownImpl + IXMLDocument2_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument2_Impl::dtdURL(this) {
                     Ok(ok__) => {
                         p.write(core::mem::transmute(ok__));
@@ -11893,9 +17817,9 @@ impl IXMLDocument2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn createElement<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, vtype: super::super::super::System::Variant::VARIANT, var1: super::super::super::System::Variant::VARIANT, ppelem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn createElement<Identity: windows_core::IUnknownImpl + IXMLDocument2_Impl>(this: *mut core::ffi::c_void, vtype: super::super::super::System::Variant::VARIANT, var1: super::super::super::System::Variant::VARIANT, ppelem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument2_Impl::createElement(this, core::mem::transmute(&vtype), core::mem::transmute(&var1)) {
                     Ok(ok__) => {
                         ppelem.write(core::mem::transmute(ok__));
@@ -11905,9 +17829,9 @@ impl IXMLDocument2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn r#async<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pf: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn r#async<Identity: windows_core::IUnknownImpl + IXMLDocument2_Impl>(this: *mut core::ffi::c_void, pf: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IXMLDocument2_Impl::r#async(this) {
                     Ok(ok__) => {
                         pf.write(core::mem::transmute(ok__));
@@ -11917,35 +17841,32 @@ impl IXMLDocument2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Setasync<Identity: IXMLDocument2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, f: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn Setasync<Identity: windows_core::IUnknownImpl + IXMLDocument2_Impl>(this: *mut core::ffi::c_void, f: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IXMLDocument2_Impl::Setasync(this, core::mem::transmute_copy(&f)).into()
             }
         }
         Self {
-            base__: super::super::super::System::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
-            root: root::<Identity, OFFSET>,
-            fileSize: fileSize::<Identity, OFFSET>,
-            fileModifiedDate: fileModifiedDate::<Identity, OFFSET>,
-            fileUpdatedDate: fileUpdatedDate::<Identity, OFFSET>,
-            URL: URL::<Identity, OFFSET>,
-            SetURL: SetURL::<Identity, OFFSET>,
-            mimeType: mimeType::<Identity, OFFSET>,
-            readyState: readyState::<Identity, OFFSET>,
-            charset: charset::<Identity, OFFSET>,
-            Setcharset: Setcharset::<Identity, OFFSET>,
-            version: version::<Identity, OFFSET>,
-            doctype: doctype::<Identity, OFFSET>,
-            dtdURL: dtdURL::<Identity, OFFSET>,
-            createElement: createElement::<Identity, OFFSET>,
-            r#async: r#async::<Identity, OFFSET>,
-            Setasync: Setasync::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IXMLDocument2 as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID
-    }
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            root: root::<Identity>,
+            fileSize: fileSize::<Identity>,
+            fileModifiedDate: fileModifiedDate::<Identity>,
+            fileUpdatedDate: fileUpdatedDate::<Identity>,
+            URL: URL::<Identity>,
+            SetURL: SetURL::<Identity>,
+            mimeType: mimeType::<Identity>,
+            readyState: readyState::<Identity>,
+            charset: charset::<Identity>,
+            Setcharset: Setcharset::<Identity>,
+            version: version::<Identity>,
+            doctype: doctype::<Identity>,
+            dtdURL: dtdURL::<Identity>,
+            createElement: createElement::<Identity>,
+            r#async: r#async::<Identity>,
+            Setasync: Setasync::<Identity>,
+        }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXMLDocument2 {}
@@ -12196,6 +18117,137 @@ impl IXMLElement_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLElement_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLElement_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn tagName<Identity: windows_core::IUnknownImpl + IXMLElement_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLElement_Impl::tagName(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SettagName<Identity: windows_core::IUnknownImpl + IXMLElement_Impl>(this: *mut core::ffi::c_void, p: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLElement_Impl::SettagName(this, core::mem::transmute(&p)).into()
+            }
+        }
+        unsafe extern "system" fn parent<Identity: windows_core::IUnknownImpl + IXMLElement_Impl>(this: *mut core::ffi::c_void, ppparent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLElement_Impl::parent(this) {
+                    Ok(ok__) => {
+                        ppparent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn setAttribute<Identity: windows_core::IUnknownImpl + IXMLElement_Impl>(this: *mut core::ffi::c_void, strpropertyname: *mut core::ffi::c_void, propertyvalue: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLElement_Impl::setAttribute(this, core::mem::transmute(&strpropertyname), core::mem::transmute(&propertyvalue)).into()
+            }
+        }
+        unsafe extern "system" fn getAttribute<Identity: windows_core::IUnknownImpl + IXMLElement_Impl>(this: *mut core::ffi::c_void, strpropertyname: *mut core::ffi::c_void, propertyvalue: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLElement_Impl::getAttribute(this, core::mem::transmute(&strpropertyname)) {
+                    Ok(ok__) => {
+                        propertyvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn removeAttribute<Identity: windows_core::IUnknownImpl + IXMLElement_Impl>(this: *mut core::ffi::c_void, strpropertyname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLElement_Impl::removeAttribute(this, core::mem::transmute(&strpropertyname)).into()
+            }
+        }
+        unsafe extern "system" fn children<Identity: windows_core::IUnknownImpl + IXMLElement_Impl>(this: *mut core::ffi::c_void, pp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLElement_Impl::children(this) {
+                    Ok(ok__) => {
+                        pp.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn r#type<Identity: windows_core::IUnknownImpl + IXMLElement_Impl>(this: *mut core::ffi::c_void, pltype: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLElement_Impl::r#type(this) {
+                    Ok(ok__) => {
+                        pltype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn text<Identity: windows_core::IUnknownImpl + IXMLElement_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLElement_Impl::text(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Settext<Identity: windows_core::IUnknownImpl + IXMLElement_Impl>(this: *mut core::ffi::c_void, p: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLElement_Impl::Settext(this, core::mem::transmute(&p)).into()
+            }
+        }
+        unsafe extern "system" fn addChild<Identity: windows_core::IUnknownImpl + IXMLElement_Impl>(this: *mut core::ffi::c_void, pchildelem: *mut core::ffi::c_void, lindex: i32, lreserved: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLElement_Impl::addChild(this, core::mem::transmute_copy(&pchildelem), core::mem::transmute_copy(&lindex), core::mem::transmute_copy(&lreserved)).into()
+            }
+        }
+        unsafe extern "system" fn removeChild<Identity: windows_core::IUnknownImpl + IXMLElement_Impl>(this: *mut core::ffi::c_void, pchildelem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLElement_Impl::removeChild(this, core::mem::transmute_copy(&pchildelem)).into()
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            tagName: tagName::<Identity>,
+            SettagName: SettagName::<Identity>,
+            parent: parent::<Identity>,
+            setAttribute: setAttribute::<Identity>,
+            getAttribute: getAttribute::<Identity>,
+            removeAttribute: removeAttribute::<Identity>,
+            children: children::<Identity>,
+            r#type: r#type::<Identity>,
+            text: text::<Identity>,
+            Settext: Settext::<Identity>,
+            addChild: addChild::<Identity>,
+            removeChild: removeChild::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXMLElement {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IXMLElement2, IXMLElement2_Vtbl, 0x2b8de2ff_8d2d_11d1_b2fc_00c04fd915a9);
@@ -12459,10 +18511,154 @@ impl IXMLElement2_Vtbl {
             removeChild: removeChild::<Identity, OFFSET>,
             attributes: attributes::<Identity, OFFSET>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IXMLElement2 as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID
-    }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLElement2 as windows_core::Interface>::IID || iid == &<super::super::super::System::Com::IDispatch as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLElement2_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLElement2_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn tagName<Identity: windows_core::IUnknownImpl + IXMLElement2_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLElement2_Impl::tagName(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SettagName<Identity: windows_core::IUnknownImpl + IXMLElement2_Impl>(this: *mut core::ffi::c_void, p: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLElement2_Impl::SettagName(this, core::mem::transmute(&p)).into()
+            }
+        }
+        unsafe extern "system" fn parent<Identity: windows_core::IUnknownImpl + IXMLElement2_Impl>(this: *mut core::ffi::c_void, ppparent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLElement2_Impl::parent(this) {
+                    Ok(ok__) => {
+                        ppparent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn setAttribute<Identity: windows_core::IUnknownImpl + IXMLElement2_Impl>(this: *mut core::ffi::c_void, strpropertyname: *mut core::ffi::c_void, propertyvalue: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLElement2_Impl::setAttribute(this, core::mem::transmute(&strpropertyname), core::mem::transmute(&propertyvalue)).into()
+            }
+        }
+        unsafe extern "system" fn getAttribute<Identity: windows_core::IUnknownImpl + IXMLElement2_Impl>(this: *mut core::ffi::c_void, strpropertyname: *mut core::ffi::c_void, propertyvalue: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLElement2_Impl::getAttribute(this, core::mem::transmute(&strpropertyname)) {
+                    Ok(ok__) => {
+                        propertyvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn removeAttribute<Identity: windows_core::IUnknownImpl + IXMLElement2_Impl>(this: *mut core::ffi::c_void, strpropertyname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLElement2_Impl::removeAttribute(this, core::mem::transmute(&strpropertyname)).into()
+            }
+        }
+        unsafe extern "system" fn children<Identity: windows_core::IUnknownImpl + IXMLElement2_Impl>(this: *mut core::ffi::c_void, pp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLElement2_Impl::children(this) {
+                    Ok(ok__) => {
+                        pp.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn r#type<Identity: windows_core::IUnknownImpl + IXMLElement2_Impl>(this: *mut core::ffi::c_void, pltype: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLElement2_Impl::r#type(this) {
+                    Ok(ok__) => {
+                        pltype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn text<Identity: windows_core::IUnknownImpl + IXMLElement2_Impl>(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLElement2_Impl::text(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Settext<Identity: windows_core::IUnknownImpl + IXMLElement2_Impl>(this: *mut core::ffi::c_void, p: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLElement2_Impl::Settext(this, core::mem::transmute(&p)).into()
+            }
+        }
+        unsafe extern "system" fn addChild<Identity: windows_core::IUnknownImpl + IXMLElement2_Impl>(this: *mut core::ffi::c_void, pchildelem: *mut core::ffi::c_void, lindex: i32, lreserved: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLElement2_Impl::addChild(this, core::mem::transmute_copy(&pchildelem), core::mem::transmute_copy(&lindex), core::mem::transmute_copy(&lreserved)).into()
+            }
+        }
+        unsafe extern "system" fn removeChild<Identity: windows_core::IUnknownImpl + IXMLElement2_Impl>(this: *mut core::ffi::c_void, pchildelem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLElement2_Impl::removeChild(this, core::mem::transmute_copy(&pchildelem)).into()
+            }
+        }
+        unsafe extern "system" fn attributes<Identity: windows_core::IUnknownImpl + IXMLElement2_Impl>(this: *mut core::ffi::c_void, pp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLElement2_Impl::attributes(this) {
+                    Ok(ok__) => {
+                        pp.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            tagName: tagName::<Identity>,
+            SettagName: SettagName::<Identity>,
+            parent: parent::<Identity>,
+            setAttribute: setAttribute::<Identity>,
+            getAttribute: getAttribute::<Identity>,
+            removeAttribute: removeAttribute::<Identity>,
+            children: children::<Identity>,
+            r#type: r#type::<Identity>,
+            text: text::<Identity>,
+            Settext: Settext::<Identity>,
+            addChild: addChild::<Identity>,
+            removeChild: removeChild::<Identity>,
+            attributes: attributes::<Identity>,
+        }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXMLElement2 {}
@@ -12580,6 +18776,63 @@ impl IXMLElementCollection_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLElementCollection_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLElementCollection_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Setlength<Identity: windows_core::IUnknownImpl + IXMLElementCollection_Impl>(this: *mut core::ffi::c_void, v: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLElementCollection_Impl::Setlength(this, core::mem::transmute_copy(&v)).into()
+            }
+        }
+        unsafe extern "system" fn length<Identity: windows_core::IUnknownImpl + IXMLElementCollection_Impl>(this: *mut core::ffi::c_void, p: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLElementCollection_Impl::length(this) {
+                    Ok(ok__) => {
+                        p.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _newEnum<Identity: windows_core::IUnknownImpl + IXMLElementCollection_Impl>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLElementCollection_Impl::_newEnum(this) {
+                    Ok(ok__) => {
+                        ppunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn item<Identity: windows_core::IUnknownImpl + IXMLElementCollection_Impl>(this: *mut core::ffi::c_void, var1: super::super::super::System::Variant::VARIANT, var2: super::super::super::System::Variant::VARIANT, ppdisp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLElementCollection_Impl::item(this, core::mem::transmute(&var1), core::mem::transmute(&var2)) {
+                    Ok(ok__) => {
+                        ppdisp.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Setlength: Setlength::<Identity>,
+            length: length::<Identity>,
+            _newEnum: _newEnum::<Identity>,
+            item: item::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXMLElementCollection {}
 windows_core::imp::define_interface!(IXMLError, IXMLError_Vtbl, 0x948c5ad3_c58d_11d0_9c0b_00c04fc99c8e);
 windows_core::imp::interface_hierarchy!(IXMLError, windows_core::IUnknown);
@@ -12611,6 +18864,20 @@ impl IXMLError_Vtbl {
         iid == &<IXMLError as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXMLError_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLError_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetErrorInfo<Identity: windows_core::IUnknownImpl + IXMLError_Impl>(this: *mut core::ffi::c_void, perrorreturn: *mut XML_ERROR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLError_Impl::GetErrorInfo(this, core::mem::transmute_copy(&perrorreturn)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetErrorInfo: GetErrorInfo::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IXMLError {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IXMLHTTPRequest, IXMLHTTPRequest_Vtbl, 0xed8c108d_4349_11d2_91a4_00c04f7969e8);
@@ -12915,6 +19182,169 @@ impl IXMLHTTPRequest_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLHTTPRequest_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn open<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest_Impl>(this: *mut core::ffi::c_void, bstrmethod: *mut core::ffi::c_void, bstrurl: *mut core::ffi::c_void, varasync: super::super::super::System::Variant::VARIANT, bstruser: super::super::super::System::Variant::VARIANT, bstrpassword: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLHTTPRequest_Impl::open(this, core::mem::transmute(&bstrmethod), core::mem::transmute(&bstrurl), core::mem::transmute(&varasync), core::mem::transmute(&bstruser), core::mem::transmute(&bstrpassword)).into()
+            }
+        }
+        unsafe extern "system" fn setRequestHeader<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest_Impl>(this: *mut core::ffi::c_void, bstrheader: *mut core::ffi::c_void, bstrvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLHTTPRequest_Impl::setRequestHeader(this, core::mem::transmute(&bstrheader), core::mem::transmute(&bstrvalue)).into()
+            }
+        }
+        unsafe extern "system" fn getResponseHeader<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest_Impl>(this: *mut core::ffi::c_void, bstrheader: *mut core::ffi::c_void, pbstrvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLHTTPRequest_Impl::getResponseHeader(this, core::mem::transmute(&bstrheader)) {
+                    Ok(ok__) => {
+                        pbstrvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn getAllResponseHeaders<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest_Impl>(this: *mut core::ffi::c_void, pbstrheaders: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLHTTPRequest_Impl::getAllResponseHeaders(this) {
+                    Ok(ok__) => {
+                        pbstrheaders.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn send<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest_Impl>(this: *mut core::ffi::c_void, varbody: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLHTTPRequest_Impl::send(this, core::mem::transmute(&varbody)).into()
+            }
+        }
+        unsafe extern "system" fn abort<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLHTTPRequest_Impl::abort(this).into()
+            }
+        }
+        unsafe extern "system" fn status<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest_Impl>(this: *mut core::ffi::c_void, plstatus: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLHTTPRequest_Impl::status(this) {
+                    Ok(ok__) => {
+                        plstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn statusText<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest_Impl>(this: *mut core::ffi::c_void, pbstrstatus: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLHTTPRequest_Impl::statusText(this) {
+                    Ok(ok__) => {
+                        pbstrstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn responseXML<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest_Impl>(this: *mut core::ffi::c_void, ppbody: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLHTTPRequest_Impl::responseXML(this) {
+                    Ok(ok__) => {
+                        ppbody.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn responseText<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest_Impl>(this: *mut core::ffi::c_void, pbstrbody: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLHTTPRequest_Impl::responseText(this) {
+                    Ok(ok__) => {
+                        pbstrbody.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn responseBody<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest_Impl>(this: *mut core::ffi::c_void, pvarbody: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLHTTPRequest_Impl::responseBody(this) {
+                    Ok(ok__) => {
+                        pvarbody.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn responseStream<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest_Impl>(this: *mut core::ffi::c_void, pvarbody: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLHTTPRequest_Impl::responseStream(this) {
+                    Ok(ok__) => {
+                        pvarbody.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn readyState<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest_Impl>(this: *mut core::ffi::c_void, plstate: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLHTTPRequest_Impl::readyState(this) {
+                    Ok(ok__) => {
+                        plstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Setonreadystatechange<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest_Impl>(this: *mut core::ffi::c_void, preadystatesink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLHTTPRequest_Impl::Setonreadystatechange(this, core::mem::transmute_copy(&preadystatesink)).into()
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            open: open::<Identity>,
+            setRequestHeader: setRequestHeader::<Identity>,
+            getResponseHeader: getResponseHeader::<Identity>,
+            getAllResponseHeaders: getAllResponseHeaders::<Identity>,
+            send: send::<Identity>,
+            abort: abort::<Identity>,
+            status: status::<Identity>,
+            statusText: statusText::<Identity>,
+            responseXML: responseXML::<Identity>,
+            responseText: responseText::<Identity>,
+            responseBody: responseBody::<Identity>,
+            responseStream: responseStream::<Identity>,
+            readyState: readyState::<Identity>,
+            Setonreadystatechange: Setonreadystatechange::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXMLHTTPRequest {}
 windows_core::imp::define_interface!(IXMLHTTPRequest2, IXMLHTTPRequest2_Vtbl, 0xe5d37dc0_552a_4d52_9cc0_a14d546fbd04);
 windows_core::imp::interface_hierarchy!(IXMLHTTPRequest2, windows_core::IUnknown);
@@ -13121,6 +19551,105 @@ impl IXMLHTTPRequest2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest2_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLHTTPRequest2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Open<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest2_Impl>(this: *mut core::ffi::c_void, pwszmethod: windows_core::PCWSTR, pwszurl: windows_core::PCWSTR, pstatuscallback: *mut core::ffi::c_void, pwszusername: windows_core::PCWSTR, pwszpassword: windows_core::PCWSTR, pwszproxyusername: windows_core::PCWSTR, pwszproxypassword: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLHTTPRequest2_Impl::Open(this, core::mem::transmute(&pwszmethod), core::mem::transmute(&pwszurl), core::mem::transmute_copy(&pstatuscallback), core::mem::transmute(&pwszusername), core::mem::transmute(&pwszpassword), core::mem::transmute(&pwszproxyusername), core::mem::transmute(&pwszproxypassword)).into()
+            }
+        }
+        unsafe extern "system" fn Send<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest2_Impl>(this: *mut core::ffi::c_void, pbody: *mut core::ffi::c_void, cbbody: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLHTTPRequest2_Impl::Send(this, core::mem::transmute_copy(&pbody), core::mem::transmute_copy(&cbbody)).into()
+            }
+        }
+        unsafe extern "system" fn Abort<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLHTTPRequest2_Impl::Abort(this).into()
+            }
+        }
+        unsafe extern "system" fn SetCookie<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest2_Impl>(this: *mut core::ffi::c_void, pcookie: *const XHR_COOKIE, pdwcookiestate: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLHTTPRequest2_Impl::SetCookie(this, core::mem::transmute_copy(&pcookie)) {
+                    Ok(ok__) => {
+                        pdwcookiestate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCustomResponseStream<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest2_Impl>(this: *mut core::ffi::c_void, psequentialstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLHTTPRequest2_Impl::SetCustomResponseStream(this, core::mem::transmute_copy(&psequentialstream)).into()
+            }
+        }
+        unsafe extern "system" fn SetProperty<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest2_Impl>(this: *mut core::ffi::c_void, eproperty: XHR_PROPERTY, ullvalue: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLHTTPRequest2_Impl::SetProperty(this, core::mem::transmute_copy(&eproperty), core::mem::transmute_copy(&ullvalue)).into()
+            }
+        }
+        unsafe extern "system" fn SetRequestHeader<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest2_Impl>(this: *mut core::ffi::c_void, pwszheader: windows_core::PCWSTR, pwszvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLHTTPRequest2_Impl::SetRequestHeader(this, core::mem::transmute(&pwszheader), core::mem::transmute(&pwszvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetAllResponseHeaders<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest2_Impl>(this: *mut core::ffi::c_void, ppwszheaders: *mut *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLHTTPRequest2_Impl::GetAllResponseHeaders(this) {
+                    Ok(ok__) => {
+                        ppwszheaders.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCookie<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest2_Impl>(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, pwszname: windows_core::PCWSTR, dwflags: u32, pccookies: *mut u32, ppcookies: *mut *mut XHR_COOKIE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLHTTPRequest2_Impl::GetCookie(this, core::mem::transmute(&pwszurl), core::mem::transmute(&pwszname), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pccookies), core::mem::transmute_copy(&ppcookies)).into()
+            }
+        }
+        unsafe extern "system" fn GetResponseHeader<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest2_Impl>(this: *mut core::ffi::c_void, pwszheader: windows_core::PCWSTR, ppwszvalue: *mut *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXMLHTTPRequest2_Impl::GetResponseHeader(this, core::mem::transmute(&pwszheader)) {
+                    Ok(ok__) => {
+                        ppwszvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Open: Open::<Identity>,
+            Send: Send::<Identity>,
+            Abort: Abort::<Identity>,
+            SetCookie: SetCookie::<Identity>,
+            SetCustomResponseStream: SetCustomResponseStream::<Identity>,
+            SetProperty: SetProperty::<Identity>,
+            SetRequestHeader: SetRequestHeader::<Identity>,
+            GetAllResponseHeaders: GetAllResponseHeaders::<Identity>,
+            GetCookie: GetCookie::<Identity>,
+            GetResponseHeader: GetResponseHeader::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IXMLHTTPRequest2 {}
 windows_core::imp::define_interface!(IXMLHTTPRequest2Callback, IXMLHTTPRequest2Callback_Vtbl, 0xa44a9299_e321_40de_8866_341b41669162);
 windows_core::imp::interface_hierarchy!(IXMLHTTPRequest2Callback, windows_core::IUnknown);
@@ -13215,22 +19744,68 @@ impl IXMLHTTPRequest2Callback_Vtbl {
         }
         unsafe extern "system" fn OnError<Identity: IXMLHTTPRequest2Callback_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pxhr: *mut core::ffi::c_void, hrerror: windows_core::HRESULT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IXMLHTTPRequest2Callback_Impl::OnError(this, core::mem::transmute_copy(&pxhr), core::mem::transmute_copy(&hrerror)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            OnRedirect: OnRedirect::<Identity, OFFSET>,
+            OnHeadersAvailable: OnHeadersAvailable::<Identity, OFFSET>,
+            OnDataAvailable: OnDataAvailable::<Identity, OFFSET>,
+            OnResponseReceived: OnResponseReceived::<Identity, OFFSET>,
+            OnError: OnError::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLHTTPRequest2Callback as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest2Callback_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLHTTPRequest2Callback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnRedirect<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest2Callback_Impl>(this: *mut core::ffi::c_void, pxhr: *mut core::ffi::c_void, pwszredirecturl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLHTTPRequest2Callback_Impl::OnRedirect(this, core::mem::transmute_copy(&pxhr), core::mem::transmute(&pwszredirecturl)).into()
+            }
+        }
+        unsafe extern "system" fn OnHeadersAvailable<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest2Callback_Impl>(this: *mut core::ffi::c_void, pxhr: *mut core::ffi::c_void, dwstatus: u32, pwszstatus: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLHTTPRequest2Callback_Impl::OnHeadersAvailable(this, core::mem::transmute_copy(&pxhr), core::mem::transmute_copy(&dwstatus), core::mem::transmute(&pwszstatus)).into()
+            }
+        }
+        unsafe extern "system" fn OnDataAvailable<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest2Callback_Impl>(this: *mut core::ffi::c_void, pxhr: *mut core::ffi::c_void, presponsestream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLHTTPRequest2Callback_Impl::OnDataAvailable(this, core::mem::transmute_copy(&pxhr), core::mem::transmute_copy(&presponsestream)).into()
+            }
+        }
+        unsafe extern "system" fn OnResponseReceived<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest2Callback_Impl>(this: *mut core::ffi::c_void, pxhr: *mut core::ffi::c_void, presponsestream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLHTTPRequest2Callback_Impl::OnResponseReceived(this, core::mem::transmute_copy(&pxhr), core::mem::transmute_copy(&presponsestream)).into()
+            }
+        }
+        unsafe extern "system" fn OnError<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest2Callback_Impl>(this: *mut core::ffi::c_void, pxhr: *mut core::ffi::c_void, hrerror: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IXMLHTTPRequest2Callback_Impl::OnError(this, core::mem::transmute_copy(&pxhr), core::mem::transmute_copy(&hrerror)).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            OnRedirect: OnRedirect::<Identity, OFFSET>,
-            OnHeadersAvailable: OnHeadersAvailable::<Identity, OFFSET>,
-            OnDataAvailable: OnDataAvailable::<Identity, OFFSET>,
-            OnResponseReceived: OnResponseReceived::<Identity, OFFSET>,
-            OnError: OnError::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnRedirect: OnRedirect::<Identity>,
+            OnHeadersAvailable: OnHeadersAvailable::<Identity>,
+            OnDataAvailable: OnDataAvailable::<Identity>,
+            OnResponseReceived: OnResponseReceived::<Identity>,
+            OnError: OnError::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IXMLHTTPRequest2Callback as windows_core::Interface>::IID
-    }
+    };
 }
 #[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IXMLHTTPRequest2Callback {}
@@ -13276,6 +19851,21 @@ impl IXMLHTTPRequest3_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest3_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLHTTPRequest3_Vtbl
+where
+    IXMLHTTPRequest2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetClientCertificate<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest3_Impl>(this: *mut core::ffi::c_void, cbclientcertificatehash: u32, pbclientcertificatehash: *const u8, pwszpin: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLHTTPRequest3_Impl::SetClientCertificate(this, core::mem::transmute_copy(&cbclientcertificatehash), core::mem::transmute_copy(&pbclientcertificatehash), core::mem::transmute(&pwszpin)).into()
+            }
+        }
+        Self { base__: <IXMLHTTPRequest2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetClientCertificate: SetClientCertificate::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IXMLHTTPRequest3 {}
 windows_core::imp::define_interface!(IXMLHTTPRequest3Callback, IXMLHTTPRequest3Callback_Vtbl, 0xb9e57830_8c6c_4a6f_9c13_47772bb047bb);
 impl core::ops::Deref for IXMLHTTPRequest3Callback {
@@ -13337,6 +19927,31 @@ impl IXMLHTTPRequest3Callback_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest3Callback_Impl> windows_core::imp::TearOffVtable<Identity> for IXMLHTTPRequest3Callback_Vtbl
+where
+    IXMLHTTPRequest2Callback_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnServerCertificateReceived<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest3Callback_Impl>(this: *mut core::ffi::c_void, pxhr: *mut core::ffi::c_void, dwcertificateerrors: u32, cservercertificatechain: u32, rgservercertificatechain: *const XHR_CERT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLHTTPRequest3Callback_Impl::OnServerCertificateReceived(this, core::mem::transmute_copy(&pxhr), core::mem::transmute_copy(&dwcertificateerrors), core::mem::transmute_copy(&cservercertificatechain), core::mem::transmute_copy(&rgservercertificatechain)).into()
+            }
+        }
+        unsafe extern "system" fn OnClientCertificateRequested<Identity: windows_core::IUnknownImpl + IXMLHTTPRequest3Callback_Impl>(this: *mut core::ffi::c_void, pxhr: *mut core::ffi::c_void, cissuerlist: u32, rgpwszissuerlist: *const *const u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXMLHTTPRequest3Callback_Impl::OnClientCertificateRequested(this, core::mem::transmute_copy(&pxhr), core::mem::transmute_copy(&cissuerlist), core::mem::transmute_copy(&rgpwszissuerlist)).into()
+            }
+        }
+        Self {
+            base__: <IXMLHTTPRequest2Callback_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnServerCertificateReceived: OnServerCertificateReceived::<Identity>,
+            OnClientCertificateRequested: OnClientCertificateRequested::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IXMLHTTPRequest3Callback {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IXSLProcessor, IXSLProcessor_Vtbl, 0x2933bf92_7b36_11d2_b20e_00c04f983e60);
@@ -13636,6 +20251,163 @@ impl IXSLProcessor_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXSLProcessor_Impl> windows_core::imp::TearOffVtable<Identity> for IXSLProcessor_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Setinput<Identity: windows_core::IUnknownImpl + IXSLProcessor_Impl>(this: *mut core::ffi::c_void, var: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXSLProcessor_Impl::Setinput(this, core::mem::transmute(&var)).into()
+            }
+        }
+        unsafe extern "system" fn input<Identity: windows_core::IUnknownImpl + IXSLProcessor_Impl>(this: *mut core::ffi::c_void, pvar: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXSLProcessor_Impl::input(this) {
+                    Ok(ok__) => {
+                        pvar.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ownerTemplate<Identity: windows_core::IUnknownImpl + IXSLProcessor_Impl>(this: *mut core::ffi::c_void, pptemplate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXSLProcessor_Impl::ownerTemplate(this) {
+                    Ok(ok__) => {
+                        pptemplate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn setStartMode<Identity: windows_core::IUnknownImpl + IXSLProcessor_Impl>(this: *mut core::ffi::c_void, mode: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXSLProcessor_Impl::setStartMode(this, core::mem::transmute(&mode), core::mem::transmute(&namespaceuri)).into()
+            }
+        }
+        unsafe extern "system" fn startMode<Identity: windows_core::IUnknownImpl + IXSLProcessor_Impl>(this: *mut core::ffi::c_void, mode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXSLProcessor_Impl::startMode(this) {
+                    Ok(ok__) => {
+                        mode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn startModeURI<Identity: windows_core::IUnknownImpl + IXSLProcessor_Impl>(this: *mut core::ffi::c_void, namespaceuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXSLProcessor_Impl::startModeURI(this) {
+                    Ok(ok__) => {
+                        namespaceuri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Setoutput<Identity: windows_core::IUnknownImpl + IXSLProcessor_Impl>(this: *mut core::ffi::c_void, output: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXSLProcessor_Impl::Setoutput(this, core::mem::transmute(&output)).into()
+            }
+        }
+        unsafe extern "system" fn output<Identity: windows_core::IUnknownImpl + IXSLProcessor_Impl>(this: *mut core::ffi::c_void, poutput: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXSLProcessor_Impl::output(this) {
+                    Ok(ok__) => {
+                        poutput.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn transform<Identity: windows_core::IUnknownImpl + IXSLProcessor_Impl>(this: *mut core::ffi::c_void, pdone: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXSLProcessor_Impl::transform(this) {
+                    Ok(ok__) => {
+                        pdone.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn reset<Identity: windows_core::IUnknownImpl + IXSLProcessor_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXSLProcessor_Impl::reset(this).into()
+            }
+        }
+        unsafe extern "system" fn readyState<Identity: windows_core::IUnknownImpl + IXSLProcessor_Impl>(this: *mut core::ffi::c_void, preadystate: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXSLProcessor_Impl::readyState(this) {
+                    Ok(ok__) => {
+                        preadystate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn addParameter<Identity: windows_core::IUnknownImpl + IXSLProcessor_Impl>(this: *mut core::ffi::c_void, basename: *mut core::ffi::c_void, parameter: super::super::super::System::Variant::VARIANT, namespaceuri: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXSLProcessor_Impl::addParameter(this, core::mem::transmute(&basename), core::mem::transmute(&parameter), core::mem::transmute(&namespaceuri)).into()
+            }
+        }
+        unsafe extern "system" fn addObject<Identity: windows_core::IUnknownImpl + IXSLProcessor_Impl>(this: *mut core::ffi::c_void, obj: *mut core::ffi::c_void, namespaceuri: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXSLProcessor_Impl::addObject(this, core::mem::transmute_copy(&obj), core::mem::transmute(&namespaceuri)).into()
+            }
+        }
+        unsafe extern "system" fn stylesheet<Identity: windows_core::IUnknownImpl + IXSLProcessor_Impl>(this: *mut core::ffi::c_void, stylesheet: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXSLProcessor_Impl::stylesheet(this) {
+                    Ok(ok__) => {
+                        stylesheet.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Setinput: Setinput::<Identity>,
+            input: input::<Identity>,
+            ownerTemplate: ownerTemplate::<Identity>,
+            setStartMode: setStartMode::<Identity>,
+            startMode: startMode::<Identity>,
+            startModeURI: startModeURI::<Identity>,
+            Setoutput: Setoutput::<Identity>,
+            output: output::<Identity>,
+            transform: transform::<Identity>,
+            reset: reset::<Identity>,
+            readyState: readyState::<Identity>,
+            addParameter: addParameter::<Identity>,
+            addObject: addObject::<Identity>,
+            stylesheet: stylesheet::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXSLProcessor {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IXSLTemplate, IXSLTemplate_Vtbl, 0x2933bf93_7b36_11d2_b20e_00c04f983e60);
@@ -13729,6 +20501,50 @@ impl IXSLTemplate_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXSLTemplate_Impl> windows_core::imp::TearOffVtable<Identity> for IXSLTemplate_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn putref_stylesheet<Identity: windows_core::IUnknownImpl + IXSLTemplate_Impl>(this: *mut core::ffi::c_void, stylesheet: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXSLTemplate_Impl::putref_stylesheet(this, core::mem::transmute_copy(&stylesheet)).into()
+            }
+        }
+        unsafe extern "system" fn stylesheet<Identity: windows_core::IUnknownImpl + IXSLTemplate_Impl>(this: *mut core::ffi::c_void, stylesheet: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXSLTemplate_Impl::stylesheet(this) {
+                    Ok(ok__) => {
+                        stylesheet.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn createProcessor<Identity: windows_core::IUnknownImpl + IXSLTemplate_Impl>(this: *mut core::ffi::c_void, ppprocessor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXSLTemplate_Impl::createProcessor(this) {
+                    Ok(ok__) => {
+                        ppprocessor.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            putref_stylesheet: putref_stylesheet::<Identity>,
+            stylesheet: stylesheet::<Identity>,
+            createProcessor: createProcessor::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXSLTemplate {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IXTLRuntime, IXTLRuntime_Vtbl, 0x3efaa425_272f_11d2_836f_0000f87a7782);
@@ -13977,6 +20793,134 @@ impl IXTLRuntime_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IXTLRuntime_Impl> windows_core::imp::TearOffVtable<Identity> for IXTLRuntime_Vtbl
+where
+    IXMLDOMNode_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn uniqueID<Identity: windows_core::IUnknownImpl + IXTLRuntime_Impl>(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void, pid: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXTLRuntime_Impl::uniqueID(this, core::mem::transmute_copy(&pnode)) {
+                    Ok(ok__) => {
+                        pid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn depth<Identity: windows_core::IUnknownImpl + IXTLRuntime_Impl>(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void, pdepth: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXTLRuntime_Impl::depth(this, core::mem::transmute_copy(&pnode)) {
+                    Ok(ok__) => {
+                        pdepth.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn childNumber<Identity: windows_core::IUnknownImpl + IXTLRuntime_Impl>(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void, pnumber: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXTLRuntime_Impl::childNumber(this, core::mem::transmute_copy(&pnode)) {
+                    Ok(ok__) => {
+                        pnumber.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ancestorChildNumber<Identity: windows_core::IUnknownImpl + IXTLRuntime_Impl>(this: *mut core::ffi::c_void, bstrnodename: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void, pnumber: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXTLRuntime_Impl::ancestorChildNumber(this, core::mem::transmute(&bstrnodename), core::mem::transmute_copy(&pnode)) {
+                    Ok(ok__) => {
+                        pnumber.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn absoluteChildNumber<Identity: windows_core::IUnknownImpl + IXTLRuntime_Impl>(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void, pnumber: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXTLRuntime_Impl::absoluteChildNumber(this, core::mem::transmute_copy(&pnode)) {
+                    Ok(ok__) => {
+                        pnumber.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn formatIndex<Identity: windows_core::IUnknownImpl + IXTLRuntime_Impl>(this: *mut core::ffi::c_void, lindex: i32, bstrformat: *mut core::ffi::c_void, pbstrformattedstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXTLRuntime_Impl::formatIndex(this, core::mem::transmute_copy(&lindex), core::mem::transmute(&bstrformat)) {
+                    Ok(ok__) => {
+                        pbstrformattedstring.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn formatNumber<Identity: windows_core::IUnknownImpl + IXTLRuntime_Impl>(this: *mut core::ffi::c_void, dblnumber: f64, bstrformat: *mut core::ffi::c_void, pbstrformattedstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXTLRuntime_Impl::formatNumber(this, core::mem::transmute_copy(&dblnumber), core::mem::transmute(&bstrformat)) {
+                    Ok(ok__) => {
+                        pbstrformattedstring.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn formatDate<Identity: windows_core::IUnknownImpl + IXTLRuntime_Impl>(this: *mut core::ffi::c_void, vardate: super::super::super::System::Variant::VARIANT, bstrformat: *mut core::ffi::c_void, vardestlocale: super::super::super::System::Variant::VARIANT, pbstrformattedstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXTLRuntime_Impl::formatDate(this, core::mem::transmute(&vardate), core::mem::transmute(&bstrformat), core::mem::transmute(&vardestlocale)) {
+                    Ok(ok__) => {
+                        pbstrformattedstring.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn formatTime<Identity: windows_core::IUnknownImpl + IXTLRuntime_Impl>(this: *mut core::ffi::c_void, vartime: super::super::super::System::Variant::VARIANT, bstrformat: *mut core::ffi::c_void, vardestlocale: super::super::super::System::Variant::VARIANT, pbstrformattedstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXTLRuntime_Impl::formatTime(this, core::mem::transmute(&vartime), core::mem::transmute(&bstrformat), core::mem::transmute(&vardestlocale)) {
+                    Ok(ok__) => {
+                        pbstrformattedstring.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IXMLDOMNode_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            uniqueID: uniqueID::<Identity>,
+            depth: depth::<Identity>,
+            childNumber: childNumber::<Identity>,
+            ancestorChildNumber: ancestorChildNumber::<Identity>,
+            absoluteChildNumber: absoluteChildNumber::<Identity>,
+            formatIndex: formatIndex::<Identity>,
+            formatNumber: formatNumber::<Identity>,
+            formatDate: formatDate::<Identity>,
+            formatTime: formatTime::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IXTLRuntime {}
 pub const MXHTMLWriter60: windows_core::GUID = windows_core::GUID::from_u128(0x88d96a10_f192_11d4_a65f_0040963251e5);
 pub const MXNamespaceManager60: windows_core::GUID = windows_core::GUID::from_u128(0x88d96a11_f192_11d4_a65f_0040963251e5);
@@ -14260,6 +21204,13 @@ impl XMLDOMDocumentEvents_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + XMLDOMDocumentEvents_Impl> windows_core::imp::TearOffVtable<Identity> for XMLDOMDocumentEvents_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for XMLDOMDocumentEvents {}
 pub const XMLDSOControl: windows_core::GUID = windows_core::GUID::from_u128(0x550dda30_0541_11d2_9ca9_0060b0ec3d39);
 pub const XMLDocument: windows_core::GUID = windows_core::GUID::from_u128(0xcfc399af_d876_11d0_9c10_00c04fc99c8e);
@@ -435,6 +435,213 @@ impl IXmlReader_Vtbl {
         iid == &<IXmlReader as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXmlReader_Impl> windows_core::imp::TearOffVtable<Identity> for IXmlReader_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetInput<Identity: windows_core::IUnknownImpl + IXmlReader_Impl>(this: *mut core::ffi::c_void, pinput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlReader_Impl::SetInput(this, core::mem::transmute_copy(&pinput)).into()
+            }
+        }
+        unsafe extern "system" fn GetProperty<Identity: windows_core::IUnknownImpl + IXmlReader_Impl>(this: *mut core::ffi::c_void, nproperty: u32, ppvalue: *mut isize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlReader_Impl::GetProperty(this, core::mem::transmute_copy(&nproperty)) {
+                    Ok(ok__) => {
+                        ppvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProperty<Identity: windows_core::IUnknownImpl + IXmlReader_Impl>(this: *mut core::ffi::c_void, nproperty: u32, pvalue: isize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlReader_Impl::SetProperty(this, core::mem::transmute_copy(&nproperty), core::mem::transmute_copy(&pvalue)).into()
+            }
+        }
+        unsafe extern "system" fn Read<Identity: windows_core::IUnknownImpl + IXmlReader_Impl>(this: *mut core::ffi::c_void, pnodetype: *mut XmlNodeType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlReader_Impl::Read(this, core::mem::transmute_copy(&pnodetype))
+            }
+        }
+        unsafe extern "system" fn GetNodeType<Identity: windows_core::IUnknownImpl + IXmlReader_Impl>(this: *mut core::ffi::c_void, pnodetype: *mut XmlNodeType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlReader_Impl::GetNodeType(this) {
+                    Ok(ok__) => {
+                        pnodetype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveToFirstAttribute<Identity: windows_core::IUnknownImpl + IXmlReader_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlReader_Impl::MoveToFirstAttribute(this)
+            }
+        }
+        unsafe extern "system" fn MoveToNextAttribute<Identity: windows_core::IUnknownImpl + IXmlReader_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlReader_Impl::MoveToNextAttribute(this)
+            }
+        }
+        unsafe extern "system" fn MoveToAttributeByName<Identity: windows_core::IUnknownImpl + IXmlReader_Impl>(this: *mut core::ffi::c_void, pwszlocalname: windows_core::PCWSTR, pwsznamespaceuri: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlReader_Impl::MoveToAttributeByName(this, core::mem::transmute(&pwszlocalname), core::mem::transmute(&pwsznamespaceuri))
+            }
+        }
+        unsafe extern "system" fn MoveToElement<Identity: windows_core::IUnknownImpl + IXmlReader_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlReader_Impl::MoveToElement(this).into()
+            }
+        }
+        unsafe extern "system" fn GetQualifiedName<Identity: windows_core::IUnknownImpl + IXmlReader_Impl>(this: *mut core::ffi::c_void, ppwszqualifiedname: *mut windows_core::PCWSTR, pcwchqualifiedname: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlReader_Impl::GetQualifiedName(this, core::mem::transmute_copy(&ppwszqualifiedname), core::mem::transmute_copy(&pcwchqualifiedname)).into()
+            }
+        }
+        unsafe extern "system" fn GetNamespaceUri<Identity: windows_core::IUnknownImpl + IXmlReader_Impl>(this: *mut core::ffi::c_void, ppwsznamespaceuri: *mut windows_core::PCWSTR, pcwchnamespaceuri: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlReader_Impl::GetNamespaceUri(this, core::mem::transmute_copy(&ppwsznamespaceuri), core::mem::transmute_copy(&pcwchnamespaceuri)).into()
+            }
+        }
+        unsafe extern "system" fn GetLocalName<Identity: windows_core::IUnknownImpl + IXmlReader_Impl>(this: *mut core::ffi::c_void, ppwszlocalname: *mut windows_core::PCWSTR, pcwchlocalname: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlReader_Impl::GetLocalName(this, core::mem::transmute_copy(&ppwszlocalname), core::mem::transmute_copy(&pcwchlocalname)).into()
+            }
+        }
+        unsafe extern "system" fn GetPrefix<Identity: windows_core::IUnknownImpl + IXmlReader_Impl>(this: *mut core::ffi::c_void, ppwszprefix: *mut windows_core::PCWSTR, pcwchprefix: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlReader_Impl::GetPrefix(this, core::mem::transmute_copy(&ppwszprefix), core::mem::transmute_copy(&pcwchprefix)).into()
+            }
+        }
+        unsafe extern "system" fn GetValue<Identity: windows_core::IUnknownImpl + IXmlReader_Impl>(this: *mut core::ffi::c_void, ppwszvalue: *mut windows_core::PCWSTR, pcwchvalue: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlReader_Impl::GetValue(this, core::mem::transmute_copy(&ppwszvalue), core::mem::transmute_copy(&pcwchvalue)).into()
+            }
+        }
+        unsafe extern "system" fn ReadValueChunk<Identity: windows_core::IUnknownImpl + IXmlReader_Impl>(this: *mut core::ffi::c_void, pwchbuffer: windows_core::PWSTR, cwchchunksize: u32, pcwchread: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlReader_Impl::ReadValueChunk(this, core::mem::transmute_copy(&pwchbuffer), core::mem::transmute_copy(&cwchchunksize), core::mem::transmute_copy(&pcwchread))
+            }
+        }
+        unsafe extern "system" fn GetBaseUri<Identity: windows_core::IUnknownImpl + IXmlReader_Impl>(this: *mut core::ffi::c_void, ppwszbaseuri: *mut windows_core::PCWSTR, pcwchbaseuri: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlReader_Impl::GetBaseUri(this, core::mem::transmute_copy(&ppwszbaseuri), core::mem::transmute_copy(&pcwchbaseuri)).into()
+            }
+        }
+        unsafe extern "system" fn IsDefault<Identity: windows_core::IUnknownImpl + IXmlReader_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlReader_Impl::IsDefault(this)
+            }
+        }
+        unsafe extern "system" fn IsEmptyElement<Identity: windows_core::IUnknownImpl + IXmlReader_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlReader_Impl::IsEmptyElement(this)
+            }
+        }
+        unsafe extern "system" fn GetLineNumber<Identity: windows_core::IUnknownImpl + IXmlReader_Impl>(this: *mut core::ffi::c_void, pnlinenumber: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlReader_Impl::GetLineNumber(this) {
+                    Ok(ok__) => {
+                        pnlinenumber.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLinePosition<Identity: windows_core::IUnknownImpl + IXmlReader_Impl>(this: *mut core::ffi::c_void, pnlineposition: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlReader_Impl::GetLinePosition(this) {
+                    Ok(ok__) => {
+                        pnlineposition.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAttributeCount<Identity: windows_core::IUnknownImpl + IXmlReader_Impl>(this: *mut core::ffi::c_void, pnattributecount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlReader_Impl::GetAttributeCount(this) {
+                    Ok(ok__) => {
+                        pnattributecount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDepth<Identity: windows_core::IUnknownImpl + IXmlReader_Impl>(this: *mut core::ffi::c_void, pndepth: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlReader_Impl::GetDepth(this) {
+                    Ok(ok__) => {
+                        pndepth.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsEOF<Identity: windows_core::IUnknownImpl + IXmlReader_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlReader_Impl::IsEOF(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetInput: SetInput::<Identity>,
+            GetProperty: GetProperty::<Identity>,
+            SetProperty: SetProperty::<Identity>,
+            Read: Read::<Identity>,
+            GetNodeType: GetNodeType::<Identity>,
+            MoveToFirstAttribute: MoveToFirstAttribute::<Identity>,
+            MoveToNextAttribute: MoveToNextAttribute::<Identity>,
+            MoveToAttributeByName: MoveToAttributeByName::<Identity>,
+            MoveToElement: MoveToElement::<Identity>,
+            GetQualifiedName: GetQualifiedName::<Identity>,
+            GetNamespaceUri: GetNamespaceUri::<Identity>,
+            GetLocalName: GetLocalName::<Identity>,
+            GetPrefix: GetPrefix::<Identity>,
+            GetValue: GetValue::<Identity>,
+            ReadValueChunk: ReadValueChunk::<Identity>,
+            GetBaseUri: GetBaseUri::<Identity>,
+            IsDefault: IsDefault::<Identity>,
+            IsEmptyElement: IsEmptyElement::<Identity>,
+            GetLineNumber: GetLineNumber::<Identity>,
+            GetLinePosition: GetLinePosition::<Identity>,
+            GetAttributeCount: GetAttributeCount::<Identity>,
+            GetDepth: GetDepth::<Identity>,
+            IsEOF: IsEOF::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IXmlReader {}
 windows_core::imp::define_interface!(IXmlResolver, IXmlResolver_Vtbl, 0x7279fc82_709d_4095_b63d_69fe4b0d9030);
 windows_core::imp::interface_hierarchy!(IXmlResolver, windows_core::IUnknown);
@@ -480,6 +687,26 @@ impl IXmlResolver_Vtbl {
         iid == &<IXmlResolver as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXmlResolver_Impl> windows_core::imp::TearOffVtable<Identity> for IXmlResolver_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ResolveUri<Identity: windows_core::IUnknownImpl + IXmlResolver_Impl>(this: *mut core::ffi::c_void, pwszbaseuri: windows_core::PCWSTR, pwszpublicidentifier: windows_core::PCWSTR, pwszsystemidentifier: windows_core::PCWSTR, ppresolvedinput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlResolver_Impl::ResolveUri(this, core::mem::transmute(&pwszbaseuri), core::mem::transmute(&pwszpublicidentifier), core::mem::transmute(&pwszsystemidentifier)) {
+                    Ok(ok__) => {
+                        ppresolvedinput.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ResolveUri: ResolveUri::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IXmlResolver {}
 windows_core::imp::define_interface!(IXmlWriter, IXmlWriter_Vtbl, 0x7279fc88_709d_4095_b63d_69fe4b0d9030);
 windows_core::imp::interface_hierarchy!(IXmlWriter, windows_core::IUnknown);
@@ -926,6 +1153,225 @@ impl IXmlWriter_Vtbl {
         iid == &<IXmlWriter as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl> windows_core::imp::TearOffVtable<Identity> for IXmlWriter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetOutput<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, poutput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::SetOutput(this, core::mem::transmute_copy(&poutput)).into()
+            }
+        }
+        unsafe extern "system" fn GetProperty<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, nproperty: u32, ppvalue: *mut isize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlWriter_Impl::GetProperty(this, core::mem::transmute_copy(&nproperty)) {
+                    Ok(ok__) => {
+                        ppvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProperty<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, nproperty: u32, pvalue: isize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::SetProperty(this, core::mem::transmute_copy(&nproperty), core::mem::transmute_copy(&pvalue)).into()
+            }
+        }
+        unsafe extern "system" fn WriteAttributes<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, preader: *mut core::ffi::c_void, fwritedefaultattributes: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteAttributes(this, core::mem::transmute_copy(&preader), core::mem::transmute_copy(&fwritedefaultattributes)).into()
+            }
+        }
+        unsafe extern "system" fn WriteAttributeString<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, pwszprefix: windows_core::PCWSTR, pwszlocalname: windows_core::PCWSTR, pwsznamespaceuri: windows_core::PCWSTR, pwszvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteAttributeString(this, core::mem::transmute(&pwszprefix), core::mem::transmute(&pwszlocalname), core::mem::transmute(&pwsznamespaceuri), core::mem::transmute(&pwszvalue)).into()
+            }
+        }
+        unsafe extern "system" fn WriteCData<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, pwsztext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteCData(this, core::mem::transmute(&pwsztext)).into()
+            }
+        }
+        unsafe extern "system" fn WriteCharEntity<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, wch: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteCharEntity(this, core::mem::transmute_copy(&wch)).into()
+            }
+        }
+        unsafe extern "system" fn WriteChars<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, pwch: windows_core::PCWSTR, cwch: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteChars(this, core::mem::transmute(&pwch), core::mem::transmute_copy(&cwch)).into()
+            }
+        }
+        unsafe extern "system" fn WriteComment<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, pwszcomment: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteComment(this, core::mem::transmute(&pwszcomment)).into()
+            }
+        }
+        unsafe extern "system" fn WriteDocType<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR, pwszpublicid: windows_core::PCWSTR, pwszsystemid: windows_core::PCWSTR, pwszsubset: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteDocType(this, core::mem::transmute(&pwszname), core::mem::transmute(&pwszpublicid), core::mem::transmute(&pwszsystemid), core::mem::transmute(&pwszsubset)).into()
+            }
+        }
+        unsafe extern "system" fn WriteElementString<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, pwszprefix: windows_core::PCWSTR, pwszlocalname: windows_core::PCWSTR, pwsznamespaceuri: windows_core::PCWSTR, pwszvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteElementString(this, core::mem::transmute(&pwszprefix), core::mem::transmute(&pwszlocalname), core::mem::transmute(&pwsznamespaceuri), core::mem::transmute(&pwszvalue)).into()
+            }
+        }
+        unsafe extern "system" fn WriteEndDocument<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteEndDocument(this).into()
+            }
+        }
+        unsafe extern "system" fn WriteEndElement<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteEndElement(this).into()
+            }
+        }
+        unsafe extern "system" fn WriteEntityRef<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteEntityRef(this, core::mem::transmute(&pwszname)).into()
+            }
+        }
+        unsafe extern "system" fn WriteFullEndElement<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteFullEndElement(this).into()
+            }
+        }
+        unsafe extern "system" fn WriteName<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteName(this, core::mem::transmute(&pwszname)).into()
+            }
+        }
+        unsafe extern "system" fn WriteNmToken<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, pwsznmtoken: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteNmToken(this, core::mem::transmute(&pwsznmtoken)).into()
+            }
+        }
+        unsafe extern "system" fn WriteNode<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, preader: *mut core::ffi::c_void, fwritedefaultattributes: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteNode(this, core::mem::transmute_copy(&preader), core::mem::transmute_copy(&fwritedefaultattributes)).into()
+            }
+        }
+        unsafe extern "system" fn WriteNodeShallow<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, preader: *mut core::ffi::c_void, fwritedefaultattributes: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteNodeShallow(this, core::mem::transmute_copy(&preader), core::mem::transmute_copy(&fwritedefaultattributes)).into()
+            }
+        }
+        unsafe extern "system" fn WriteProcessingInstruction<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR, pwsztext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteProcessingInstruction(this, core::mem::transmute(&pwszname), core::mem::transmute(&pwsztext)).into()
+            }
+        }
+        unsafe extern "system" fn WriteQualifiedName<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, pwszlocalname: windows_core::PCWSTR, pwsznamespaceuri: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteQualifiedName(this, core::mem::transmute(&pwszlocalname), core::mem::transmute(&pwsznamespaceuri)).into()
+            }
+        }
+        unsafe extern "system" fn WriteRaw<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, pwszdata: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteRaw(this, core::mem::transmute(&pwszdata)).into()
+            }
+        }
+        unsafe extern "system" fn WriteRawChars<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, pwch: windows_core::PCWSTR, cwch: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteRawChars(this, core::mem::transmute(&pwch), core::mem::transmute_copy(&cwch)).into()
+            }
+        }
+        unsafe extern "system" fn WriteStartDocument<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, standalone: XmlStandalone) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteStartDocument(this, core::mem::transmute_copy(&standalone)).into()
+            }
+        }
+        unsafe extern "system" fn WriteStartElement<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, pwszprefix: windows_core::PCWSTR, pwszlocalname: windows_core::PCWSTR, pwsznamespaceuri: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteStartElement(this, core::mem::transmute(&pwszprefix), core::mem::transmute(&pwszlocalname), core::mem::transmute(&pwsznamespaceuri)).into()
+            }
+        }
+        unsafe extern "system" fn WriteString<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, pwsztext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteString(this, core::mem::transmute(&pwsztext)).into()
+            }
+        }
+        unsafe extern "system" fn WriteSurrogateCharEntity<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, wchlow: u16, wchhigh: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteSurrogateCharEntity(this, core::mem::transmute_copy(&wchlow), core::mem::transmute_copy(&wchhigh)).into()
+            }
+        }
+        unsafe extern "system" fn WriteWhitespace<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void, pwszwhitespace: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::WriteWhitespace(this, core::mem::transmute(&pwszwhitespace)).into()
+            }
+        }
+        unsafe extern "system" fn Flush<Identity: windows_core::IUnknownImpl + IXmlWriter_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriter_Impl::Flush(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetOutput: SetOutput::<Identity>,
+            GetProperty: GetProperty::<Identity>,
+            SetProperty: SetProperty::<Identity>,
+            WriteAttributes: WriteAttributes::<Identity>,
+            WriteAttributeString: WriteAttributeString::<Identity>,
+            WriteCData: WriteCData::<Identity>,
+            WriteCharEntity: WriteCharEntity::<Identity>,
+            WriteChars: WriteChars::<Identity>,
+            WriteComment: WriteComment::<Identity>,
+            WriteDocType: WriteDocType::<Identity>,
+            WriteElementString: WriteElementString::<Identity>,
+            WriteEndDocument: WriteEndDocument::<Identity>,
+            WriteEndElement: WriteEndElement::<Identity>,
+            WriteEntityRef: WriteEntityRef::<Identity>,
+            WriteFullEndElement: WriteFullEndElement::<Identity>,
+            WriteName: WriteName::<Identity>,
+            WriteNmToken: WriteNmToken::<Identity>,
+            WriteNode: WriteNode::<Identity>,
+            WriteNodeShallow: WriteNodeShallow::<Identity>,
+            WriteProcessingInstruction: WriteProcessingInstruction::<Identity>,
+            WriteQualifiedName: WriteQualifiedName::<Identity>,
+            WriteRaw: WriteRaw::<Identity>,
+            WriteRawChars: WriteRawChars::<Identity>,
+            WriteStartDocument: WriteStartDocument::<Identity>,
+            WriteStartElement: WriteStartElement::<Identity>,
+            WriteString: WriteString::<Identity>,
+            WriteSurrogateCharEntity: WriteSurrogateCharEntity::<Identity>,
+            WriteWhitespace: WriteWhitespace::<Identity>,
+            Flush: Flush::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IXmlWriter {}
 windows_core::imp::define_interface!(IXmlWriterLite, IXmlWriterLite_Vtbl, 0x862494c6_1310_4aad_b3cd_2dbeebf670d3);
 windows_core::imp::interface_hierarchy!(IXmlWriterLite, windows_core::IUnknown);
@@ -1342,6 +1788,218 @@ impl IXmlWriterLite_Vtbl {
         iid == &<IXmlWriterLite as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl> windows_core::imp::TearOffVtable<Identity> for IXmlWriterLite_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetOutput<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, poutput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::SetOutput(this, core::mem::transmute_copy(&poutput)).into()
+            }
+        }
+        unsafe extern "system" fn GetProperty<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, nproperty: u32, ppvalue: *mut isize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXmlWriterLite_Impl::GetProperty(this, core::mem::transmute_copy(&nproperty)) {
+                    Ok(ok__) => {
+                        ppvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProperty<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, nproperty: u32, pvalue: isize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::SetProperty(this, core::mem::transmute_copy(&nproperty), core::mem::transmute_copy(&pvalue)).into()
+            }
+        }
+        unsafe extern "system" fn WriteAttributes<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, preader: *mut core::ffi::c_void, fwritedefaultattributes: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteAttributes(this, core::mem::transmute_copy(&preader), core::mem::transmute_copy(&fwritedefaultattributes)).into()
+            }
+        }
+        unsafe extern "system" fn WriteAttributeString<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, pwszqname: windows_core::PCWSTR, cwszqname: u32, pwszvalue: windows_core::PCWSTR, cwszvalue: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteAttributeString(this, core::mem::transmute(&pwszqname), core::mem::transmute_copy(&cwszqname), core::mem::transmute(&pwszvalue), core::mem::transmute_copy(&cwszvalue)).into()
+            }
+        }
+        unsafe extern "system" fn WriteCData<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, pwsztext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteCData(this, core::mem::transmute(&pwsztext)).into()
+            }
+        }
+        unsafe extern "system" fn WriteCharEntity<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, wch: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteCharEntity(this, core::mem::transmute_copy(&wch)).into()
+            }
+        }
+        unsafe extern "system" fn WriteChars<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, pwch: windows_core::PCWSTR, cwch: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteChars(this, core::mem::transmute(&pwch), core::mem::transmute_copy(&cwch)).into()
+            }
+        }
+        unsafe extern "system" fn WriteComment<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, pwszcomment: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteComment(this, core::mem::transmute(&pwszcomment)).into()
+            }
+        }
+        unsafe extern "system" fn WriteDocType<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR, pwszpublicid: windows_core::PCWSTR, pwszsystemid: windows_core::PCWSTR, pwszsubset: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteDocType(this, core::mem::transmute(&pwszname), core::mem::transmute(&pwszpublicid), core::mem::transmute(&pwszsystemid), core::mem::transmute(&pwszsubset)).into()
+            }
+        }
+        unsafe extern "system" fn WriteElementString<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, pwszqname: windows_core::PCWSTR, cwszqname: u32, pwszvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteElementString(this, core::mem::transmute(&pwszqname), core::mem::transmute_copy(&cwszqname), core::mem::transmute(&pwszvalue)).into()
+            }
+        }
+        unsafe extern "system" fn WriteEndDocument<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteEndDocument(this).into()
+            }
+        }
+        unsafe extern "system" fn WriteEndElement<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, pwszqname: windows_core::PCWSTR, cwszqname: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteEndElement(this, core::mem::transmute(&pwszqname), core::mem::transmute_copy(&cwszqname)).into()
+            }
+        }
+        unsafe extern "system" fn WriteEntityRef<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteEntityRef(this, core::mem::transmute(&pwszname)).into()
+            }
+        }
+        unsafe extern "system" fn WriteFullEndElement<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, pwszqname: windows_core::PCWSTR, cwszqname: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteFullEndElement(this, core::mem::transmute(&pwszqname), core::mem::transmute_copy(&cwszqname)).into()
+            }
+        }
+        unsafe extern "system" fn WriteName<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteName(this, core::mem::transmute(&pwszname)).into()
+            }
+        }
+        unsafe extern "system" fn WriteNmToken<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, pwsznmtoken: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteNmToken(this, core::mem::transmute(&pwsznmtoken)).into()
+            }
+        }
+        unsafe extern "system" fn WriteNode<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, preader: *mut core::ffi::c_void, fwritedefaultattributes: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteNode(this, core::mem::transmute_copy(&preader), core::mem::transmute_copy(&fwritedefaultattributes)).into()
+            }
+        }
+        unsafe extern "system" fn WriteNodeShallow<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, preader: *mut core::ffi::c_void, fwritedefaultattributes: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteNodeShallow(this, core::mem::transmute_copy(&preader), core::mem::transmute_copy(&fwritedefaultattributes)).into()
+            }
+        }
+        unsafe extern "system" fn WriteProcessingInstruction<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR, pwsztext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteProcessingInstruction(this, core::mem::transmute(&pwszname), core::mem::transmute(&pwsztext)).into()
+            }
+        }
+        unsafe extern "system" fn WriteRaw<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, pwszdata: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteRaw(this, core::mem::transmute(&pwszdata)).into()
+            }
+        }
+        unsafe extern "system" fn WriteRawChars<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, pwch: windows_core::PCWSTR, cwch: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteRawChars(this, core::mem::transmute(&pwch), core::mem::transmute_copy(&cwch)).into()
+            }
+        }
+        unsafe extern "system" fn WriteStartDocument<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, standalone: XmlStandalone) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteStartDocument(this, core::mem::transmute_copy(&standalone)).into()
+            }
+        }
+        unsafe extern "system" fn WriteStartElement<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, pwszqname: windows_core::PCWSTR, cwszqname: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteStartElement(this, core::mem::transmute(&pwszqname), core::mem::transmute_copy(&cwszqname)).into()
+            }
+        }
+        unsafe extern "system" fn WriteString<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, pwsztext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteString(this, core::mem::transmute(&pwsztext)).into()
+            }
+        }
+        unsafe extern "system" fn WriteSurrogateCharEntity<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, wchlow: u16, wchhigh: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteSurrogateCharEntity(this, core::mem::transmute_copy(&wchlow), core::mem::transmute_copy(&wchhigh)).into()
+            }
+        }
+        unsafe extern "system" fn WriteWhitespace<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void, pwszwhitespace: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::WriteWhitespace(this, core::mem::transmute(&pwszwhitespace)).into()
+            }
+        }
+        unsafe extern "system" fn Flush<Identity: windows_core::IUnknownImpl + IXmlWriterLite_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXmlWriterLite_Impl::Flush(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetOutput: SetOutput::<Identity>,
+            GetProperty: GetProperty::<Identity>,
+            SetProperty: SetProperty::<Identity>,
+            WriteAttributes: WriteAttributes::<Identity>,
+            WriteAttributeString: WriteAttributeString::<Identity>,
+            WriteCData: WriteCData::<Identity>,
+            WriteCharEntity: WriteCharEntity::<Identity>,
+            WriteChars: WriteChars::<Identity>,
+            WriteComment: WriteComment::<Identity>,
+            WriteDocType: WriteDocType::<Identity>,
+            WriteElementString: WriteElementString::<Identity>,
+            WriteEndDocument: WriteEndDocument::<Identity>,
+            WriteEndElement: WriteEndElement::<Identity>,
+            WriteEntityRef: WriteEntityRef::<Identity>,
+            WriteFullEndElement: WriteFullEndElement::<Identity>,
+            WriteName: WriteName::<Identity>,
+            WriteNmToken: WriteNmToken::<Identity>,
+            WriteNode: WriteNode::<Identity>,
+            WriteNodeShallow: WriteNodeShallow::<Identity>,
+            WriteProcessingInstruction: WriteProcessingInstruction::<Identity>,
+            WriteRaw: WriteRaw::<Identity>,
+            WriteRawChars: WriteRawChars::<Identity>,
+            WriteStartDocument: WriteStartDocument::<Identity>,
+            WriteStartElement: WriteStartElement::<Identity>,
+            WriteString: WriteString::<Identity>,
+            WriteSurrogateCharEntity: WriteSurrogateCharEntity::<Identity>,
+            WriteWhitespace: WriteWhitespace::<Identity>,
+            Flush: Flush::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IXmlWriterLite {}
 pub const MX_E_ENCODING: XmlError = XmlError(-1072894462i32);
 pub const MX_E_ENCODINGSIGNATURE: XmlError = XmlError(-1072894460i32);
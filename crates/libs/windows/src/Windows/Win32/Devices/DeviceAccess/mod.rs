@@ -127,6 +127,44 @@ impl ICreateDeviceAccessAsync_Vtbl {
         iid == &<ICreateDeviceAccessAsync as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICreateDeviceAccessAsync_Impl> windows_core::imp::TearOffVtable<Identity> for ICreateDeviceAccessAsync_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Cancel<Identity: windows_core::IUnknownImpl + ICreateDeviceAccessAsync_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateDeviceAccessAsync_Impl::Cancel(this).into()
+            }
+        }
+        unsafe extern "system" fn Wait<Identity: windows_core::IUnknownImpl + ICreateDeviceAccessAsync_Impl>(this: *mut core::ffi::c_void, timeout: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateDeviceAccessAsync_Impl::Wait(this, core::mem::transmute_copy(&timeout)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + ICreateDeviceAccessAsync_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateDeviceAccessAsync_Impl::Close(this).into()
+            }
+        }
+        unsafe extern "system" fn GetResult<Identity: windows_core::IUnknownImpl + ICreateDeviceAccessAsync_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, deviceaccess: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateDeviceAccessAsync_Impl::GetResult(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&deviceaccess)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Cancel: Cancel::<Identity>,
+            Wait: Wait::<Identity>,
+            Close: Close::<Identity>,
+            GetResult: GetResult::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICreateDeviceAccessAsync {}
 windows_core::imp::define_interface!(IDeviceIoControl, IDeviceIoControl_Vtbl, 0x9eefe161_23ab_4f18_9b49_991b586ae970);
 windows_core::imp::interface_hierarchy!(IDeviceIoControl, windows_core::IUnknown);
@@ -200,6 +238,37 @@ impl IDeviceIoControl_Vtbl {
         iid == &<IDeviceIoControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDeviceIoControl_Impl> windows_core::imp::TearOffVtable<Identity> for IDeviceIoControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DeviceIoControlSync<Identity: windows_core::IUnknownImpl + IDeviceIoControl_Impl>(this: *mut core::ffi::c_void, iocontrolcode: u32, inputbuffer: *const u8, inputbuffersize: u32, outputbuffer: *mut u8, outputbuffersize: u32, bytesreturned: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDeviceIoControl_Impl::DeviceIoControlSync(this, core::mem::transmute_copy(&iocontrolcode), core::mem::transmute_copy(&inputbuffer), core::mem::transmute_copy(&inputbuffersize), core::mem::transmute_copy(&outputbuffer), core::mem::transmute_copy(&outputbuffersize), core::mem::transmute_copy(&bytesreturned)).into()
+            }
+        }
+        unsafe extern "system" fn DeviceIoControlAsync<Identity: windows_core::IUnknownImpl + IDeviceIoControl_Impl>(this: *mut core::ffi::c_void, iocontrolcode: u32, inputbuffer: *const u8, inputbuffersize: u32, outputbuffer: *mut u8, outputbuffersize: u32, requestcompletioncallback: *mut core::ffi::c_void, cancelcontext: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDeviceIoControl_Impl::DeviceIoControlAsync(this, core::mem::transmute_copy(&iocontrolcode), core::mem::transmute_copy(&inputbuffer), core::mem::transmute_copy(&inputbuffersize), core::mem::transmute_copy(&outputbuffer), core::mem::transmute_copy(&outputbuffersize), core::mem::transmute_copy(&requestcompletioncallback), core::mem::transmute_copy(&cancelcontext)).into()
+            }
+        }
+        unsafe extern "system" fn CancelOperation<Identity: windows_core::IUnknownImpl + IDeviceIoControl_Impl>(this: *mut core::ffi::c_void, cancelcontext: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDeviceIoControl_Impl::CancelOperation(this, core::mem::transmute_copy(&cancelcontext)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DeviceIoControlSync: DeviceIoControlSync::<Identity>,
+            DeviceIoControlAsync: DeviceIoControlAsync::<Identity>,
+            CancelOperation: CancelOperation::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDeviceIoControl {}
 windows_core::imp::define_interface!(IDeviceRequestCompletionCallback, IDeviceRequestCompletionCallback_Vtbl, 0x999bad24_9acd_45bb_8669_2a2fc0288b04);
 windows_core::imp::interface_hierarchy!(IDeviceRequestCompletionCallback, windows_core::IUnknown);
@@ -231,4 +300,18 @@ impl IDeviceRequestCompletionCallback_Vtbl {
         iid == &<IDeviceRequestCompletionCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDeviceRequestCompletionCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IDeviceRequestCompletionCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Invoke<Identity: windows_core::IUnknownImpl + IDeviceRequestCompletionCallback_Impl>(this: *mut core::ffi::c_void, requestresult: windows_core::HRESULT, bytesreturned: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDeviceRequestCompletionCallback_Impl::Invoke(this, core::mem::transmute_copy(&requestresult), core::mem::transmute_copy(&bytesreturned)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Invoke: Invoke::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDeviceRequestCompletionCallback {}
@@ -2723,6 +2723,44 @@ impl ICloneViewHelper_Vtbl {
         iid == &<ICloneViewHelper as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICloneViewHelper_Impl> windows_core::imp::TearOffVtable<Identity> for ICloneViewHelper_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetConnectedIDs<Identity: windows_core::IUnknownImpl + ICloneViewHelper_Impl>(this: *mut core::ffi::c_void, wszadaptorname: windows_core::PCWSTR, pulcount: *mut u32, pulid: *mut u32, ulflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICloneViewHelper_Impl::GetConnectedIDs(this, core::mem::transmute(&wszadaptorname), core::mem::transmute_copy(&pulcount), core::mem::transmute_copy(&pulid), core::mem::transmute_copy(&ulflags)).into()
+            }
+        }
+        unsafe extern "system" fn GetActiveTopology<Identity: windows_core::IUnknownImpl + ICloneViewHelper_Impl>(this: *mut core::ffi::c_void, wszadaptorname: windows_core::PCWSTR, ulsourceid: u32, pulcount: *mut u32, pultargetid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICloneViewHelper_Impl::GetActiveTopology(this, core::mem::transmute(&wszadaptorname), core::mem::transmute_copy(&ulsourceid), core::mem::transmute_copy(&pulcount), core::mem::transmute_copy(&pultargetid)).into()
+            }
+        }
+        unsafe extern "system" fn SetActiveTopology<Identity: windows_core::IUnknownImpl + ICloneViewHelper_Impl>(this: *mut core::ffi::c_void, wszadaptorname: windows_core::PCWSTR, ulsourceid: u32, ulcount: u32, pultargetid: *const u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICloneViewHelper_Impl::SetActiveTopology(this, core::mem::transmute(&wszadaptorname), core::mem::transmute_copy(&ulsourceid), core::mem::transmute_copy(&ulcount), core::mem::transmute_copy(&pultargetid)).into()
+            }
+        }
+        unsafe extern "system" fn Commit<Identity: windows_core::IUnknownImpl + ICloneViewHelper_Impl>(this: *mut core::ffi::c_void, ffinalcall: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICloneViewHelper_Impl::Commit(this, core::mem::transmute_copy(&ffinalcall)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetConnectedIDs: GetConnectedIDs::<Identity>,
+            GetActiveTopology: GetActiveTopology::<Identity>,
+            SetActiveTopology: SetActiveTopology::<Identity>,
+            Commit: Commit::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICloneViewHelper {}
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -3190,6 +3228,65 @@ impl IViewHelper_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IViewHelper_Impl> windows_core::imp::TearOffVtable<Identity> for IViewHelper_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetConnectedIDs<Identity: windows_core::IUnknownImpl + IViewHelper_Impl>(this: *mut core::ffi::c_void, wszadaptorname: windows_core::PCWSTR, pulcount: *mut u32, pulid: *mut u32, ulflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IViewHelper_Impl::GetConnectedIDs(this, core::mem::transmute(&wszadaptorname), core::mem::transmute_copy(&pulcount), core::mem::transmute_copy(&pulid), core::mem::transmute_copy(&ulflags)).into()
+            }
+        }
+        unsafe extern "system" fn GetActiveTopology<Identity: windows_core::IUnknownImpl + IViewHelper_Impl>(this: *mut core::ffi::c_void, wszadaptorname: windows_core::PCWSTR, ulsourceid: u32, pulcount: *mut u32, pultargetid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IViewHelper_Impl::GetActiveTopology(this, core::mem::transmute(&wszadaptorname), core::mem::transmute_copy(&ulsourceid), core::mem::transmute_copy(&pulcount), core::mem::transmute_copy(&pultargetid)).into()
+            }
+        }
+        unsafe extern "system" fn SetActiveTopology<Identity: windows_core::IUnknownImpl + IViewHelper_Impl>(this: *mut core::ffi::c_void, wszadaptorname: windows_core::PCWSTR, ulsourceid: u32, ulcount: u32, pultargetid: *const u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IViewHelper_Impl::SetActiveTopology(this, core::mem::transmute(&wszadaptorname), core::mem::transmute_copy(&ulsourceid), core::mem::transmute_copy(&ulcount), core::mem::transmute_copy(&pultargetid)).into()
+            }
+        }
+        unsafe extern "system" fn Commit<Identity: windows_core::IUnknownImpl + IViewHelper_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IViewHelper_Impl::Commit(this).into()
+            }
+        }
+        unsafe extern "system" fn SetConfiguration<Identity: windows_core::IUnknownImpl + IViewHelper_Impl>(this: *mut core::ffi::c_void, pistream: *mut core::ffi::c_void, pulstatus: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IViewHelper_Impl::SetConfiguration(this, core::mem::transmute_copy(&pistream)) {
+                    Ok(ok__) => {
+                        pulstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetProceedOnNewConfiguration<Identity: windows_core::IUnknownImpl + IViewHelper_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IViewHelper_Impl::GetProceedOnNewConfiguration(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetConnectedIDs: GetConnectedIDs::<Identity>,
+            GetActiveTopology: GetActiveTopology::<Identity>,
+            SetActiveTopology: SetActiveTopology::<Identity>,
+            Commit: Commit::<Identity>,
+            SetConfiguration: SetConfiguration::<Identity>,
+            GetProceedOnNewConfiguration: GetProceedOnNewConfiguration::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IViewHelper {}
 pub const JOIN_BEVEL: i32 = 1i32;
 pub const JOIN_MITER: i32 = 2i32;
@@ -155,6 +155,36 @@ impl IUPnPAddressFamilyControl_Vtbl {
         iid == &<IUPnPAddressFamilyControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IUPnPAddressFamilyControl_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPAddressFamilyControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAddressFamily<Identity: windows_core::IUnknownImpl + IUPnPAddressFamilyControl_Impl>(this: *mut core::ffi::c_void, dwflags: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPAddressFamilyControl_Impl::SetAddressFamily(this, core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn GetAddressFamily<Identity: windows_core::IUnknownImpl + IUPnPAddressFamilyControl_Impl>(this: *mut core::ffi::c_void, pdwflags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPAddressFamilyControl_Impl::GetAddressFamily(this) {
+                    Ok(ok__) => {
+                        pdwflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAddressFamily: SetAddressFamily::<Identity>,
+            GetAddressFamily: GetAddressFamily::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IUPnPAddressFamilyControl {}
 windows_core::imp::define_interface!(IUPnPAsyncResult, IUPnPAsyncResult_Vtbl, 0x4d65fd08_d13e_4274_9c8b_dd8d028c8644);
 windows_core::imp::interface_hierarchy!(IUPnPAsyncResult, windows_core::IUnknown);
@@ -186,6 +216,23 @@ impl IUPnPAsyncResult_Vtbl {
         iid == &<IUPnPAsyncResult as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IUPnPAsyncResult_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPAsyncResult_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AsyncOperationComplete<Identity: windows_core::IUnknownImpl + IUPnPAsyncResult_Impl>(this: *mut core::ffi::c_void, ullrequestid: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPAsyncResult_Impl::AsyncOperationComplete(this, core::mem::transmute_copy(&ullrequestid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AsyncOperationComplete: AsyncOperationComplete::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IUPnPAsyncResult {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IUPnPDescriptionDocument, IUPnPDescriptionDocument_Vtbl, 0x11d1c1b2_7daa_4c9e_9595_7f82ed206d1e);
@@ -345,6 +392,90 @@ impl IUPnPDescriptionDocument_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IUPnPDescriptionDocument_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPDescriptionDocument_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ReadyState<Identity: windows_core::IUnknownImpl + IUPnPDescriptionDocument_Impl>(this: *mut core::ffi::c_void, plreadystate: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDescriptionDocument_Impl::ReadyState(this) {
+                    Ok(ok__) => {
+                        plreadystate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Load<Identity: windows_core::IUnknownImpl + IUPnPDescriptionDocument_Impl>(this: *mut core::ffi::c_void, bstrurl: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPDescriptionDocument_Impl::Load(this, core::mem::transmute(&bstrurl)).into()
+            }
+        }
+        unsafe extern "system" fn LoadAsync<Identity: windows_core::IUnknownImpl + IUPnPDescriptionDocument_Impl>(this: *mut core::ffi::c_void, bstrurl: *mut core::ffi::c_void, punkcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPDescriptionDocument_Impl::LoadAsync(this, core::mem::transmute(&bstrurl), core::mem::transmute_copy(&punkcallback)).into()
+            }
+        }
+        unsafe extern "system" fn LoadResult<Identity: windows_core::IUnknownImpl + IUPnPDescriptionDocument_Impl>(this: *mut core::ffi::c_void, phrerror: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDescriptionDocument_Impl::LoadResult(this) {
+                    Ok(ok__) => {
+                        phrerror.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Abort<Identity: windows_core::IUnknownImpl + IUPnPDescriptionDocument_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPDescriptionDocument_Impl::Abort(this).into()
+            }
+        }
+        unsafe extern "system" fn RootDevice<Identity: windows_core::IUnknownImpl + IUPnPDescriptionDocument_Impl>(this: *mut core::ffi::c_void, ppudrootdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDescriptionDocument_Impl::RootDevice(this) {
+                    Ok(ok__) => {
+                        ppudrootdevice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeviceByUDN<Identity: windows_core::IUnknownImpl + IUPnPDescriptionDocument_Impl>(this: *mut core::ffi::c_void, bstrudn: *mut core::ffi::c_void, ppuddevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDescriptionDocument_Impl::DeviceByUDN(this, core::mem::transmute(&bstrudn)) {
+                    Ok(ok__) => {
+                        ppuddevice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ReadyState: ReadyState::<Identity>,
+            Load: Load::<Identity>,
+            LoadAsync: LoadAsync::<Identity>,
+            LoadResult: LoadResult::<Identity>,
+            Abort: Abort::<Identity>,
+            RootDevice: RootDevice::<Identity>,
+            DeviceByUDN: DeviceByUDN::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IUPnPDescriptionDocument {}
 windows_core::imp::define_interface!(IUPnPDescriptionDocumentCallback, IUPnPDescriptionDocumentCallback_Vtbl, 0x77394c69_5486_40d6_9bc3_4991983e02da);
 windows_core::imp::interface_hierarchy!(IUPnPDescriptionDocumentCallback, windows_core::IUnknown);
@@ -376,6 +507,20 @@ impl IUPnPDescriptionDocumentCallback_Vtbl {
         iid == &<IUPnPDescriptionDocumentCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IUPnPDescriptionDocumentCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPDescriptionDocumentCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn LoadComplete<Identity: windows_core::IUnknownImpl + IUPnPDescriptionDocumentCallback_Impl>(this: *mut core::ffi::c_void, hrloadresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPDescriptionDocumentCallback_Impl::LoadComplete(this, core::mem::transmute_copy(&hrloadresult)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, LoadComplete: LoadComplete::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IUPnPDescriptionDocumentCallback {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IUPnPDevice, IUPnPDevice_Vtbl, 0x3d44d0d1_98c9_4889_acd1_f9d674bf2221);
@@ -811,6 +956,264 @@ impl IUPnPDevice_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IUPnPDevice_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPDevice_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsRootDevice<Identity: windows_core::IUnknownImpl + IUPnPDevice_Impl>(this: *mut core::ffi::c_void, pvarb: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDevice_Impl::IsRootDevice(this) {
+                    Ok(ok__) => {
+                        pvarb.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RootDevice<Identity: windows_core::IUnknownImpl + IUPnPDevice_Impl>(this: *mut core::ffi::c_void, ppudrootdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDevice_Impl::RootDevice(this) {
+                    Ok(ok__) => {
+                        ppudrootdevice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ParentDevice<Identity: windows_core::IUnknownImpl + IUPnPDevice_Impl>(this: *mut core::ffi::c_void, ppuddeviceparent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDevice_Impl::ParentDevice(this) {
+                    Ok(ok__) => {
+                        ppuddeviceparent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn HasChildren<Identity: windows_core::IUnknownImpl + IUPnPDevice_Impl>(this: *mut core::ffi::c_void, pvarb: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDevice_Impl::HasChildren(this) {
+                    Ok(ok__) => {
+                        pvarb.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Children<Identity: windows_core::IUnknownImpl + IUPnPDevice_Impl>(this: *mut core::ffi::c_void, ppudchildren: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDevice_Impl::Children(this) {
+                    Ok(ok__) => {
+                        ppudchildren.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UniqueDeviceName<Identity: windows_core::IUnknownImpl + IUPnPDevice_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDevice_Impl::UniqueDeviceName(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FriendlyName<Identity: windows_core::IUnknownImpl + IUPnPDevice_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDevice_Impl::FriendlyName(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Type<Identity: windows_core::IUnknownImpl + IUPnPDevice_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDevice_Impl::Type(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PresentationURL<Identity: windows_core::IUnknownImpl + IUPnPDevice_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDevice_Impl::PresentationURL(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ManufacturerName<Identity: windows_core::IUnknownImpl + IUPnPDevice_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDevice_Impl::ManufacturerName(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ManufacturerURL<Identity: windows_core::IUnknownImpl + IUPnPDevice_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDevice_Impl::ManufacturerURL(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ModelName<Identity: windows_core::IUnknownImpl + IUPnPDevice_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDevice_Impl::ModelName(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ModelNumber<Identity: windows_core::IUnknownImpl + IUPnPDevice_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDevice_Impl::ModelNumber(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Description<Identity: windows_core::IUnknownImpl + IUPnPDevice_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDevice_Impl::Description(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ModelURL<Identity: windows_core::IUnknownImpl + IUPnPDevice_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDevice_Impl::ModelURL(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UPC<Identity: windows_core::IUnknownImpl + IUPnPDevice_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDevice_Impl::UPC(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SerialNumber<Identity: windows_core::IUnknownImpl + IUPnPDevice_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDevice_Impl::SerialNumber(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IconURL<Identity: windows_core::IUnknownImpl + IUPnPDevice_Impl>(this: *mut core::ffi::c_void, bstrencodingformat: *mut core::ffi::c_void, lsizex: i32, lsizey: i32, lbitdepth: i32, pbstriconurl: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDevice_Impl::IconURL(this, core::mem::transmute(&bstrencodingformat), core::mem::transmute_copy(&lsizex), core::mem::transmute_copy(&lsizey), core::mem::transmute_copy(&lbitdepth)) {
+                    Ok(ok__) => {
+                        pbstriconurl.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Services<Identity: windows_core::IUnknownImpl + IUPnPDevice_Impl>(this: *mut core::ffi::c_void, ppusservices: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDevice_Impl::Services(this) {
+                    Ok(ok__) => {
+                        ppusservices.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsRootDevice: IsRootDevice::<Identity>,
+            RootDevice: RootDevice::<Identity>,
+            ParentDevice: ParentDevice::<Identity>,
+            HasChildren: HasChildren::<Identity>,
+            Children: Children::<Identity>,
+            UniqueDeviceName: UniqueDeviceName::<Identity>,
+            FriendlyName: FriendlyName::<Identity>,
+            Type: Type::<Identity>,
+            PresentationURL: PresentationURL::<Identity>,
+            ManufacturerName: ManufacturerName::<Identity>,
+            ManufacturerURL: ManufacturerURL::<Identity>,
+            ModelName: ModelName::<Identity>,
+            ModelNumber: ModelNumber::<Identity>,
+            Description: Description::<Identity>,
+            ModelURL: ModelURL::<Identity>,
+            UPC: UPC::<Identity>,
+            SerialNumber: SerialNumber::<Identity>,
+            IconURL: IconURL::<Identity>,
+            Services: Services::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IUPnPDevice {}
 windows_core::imp::define_interface!(IUPnPDeviceControl, IUPnPDeviceControl_Vtbl, 0x204810ba_73b2_11d4_bf42_00b0d0118b56);
 windows_core::imp::interface_hierarchy!(IUPnPDeviceControl, windows_core::IUnknown);
@@ -873,6 +1276,37 @@ impl IUPnPDeviceControl_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IUPnPDeviceControl_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPDeviceControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IUPnPDeviceControl_Impl>(this: *mut core::ffi::c_void, bstrxmldesc: *mut core::ffi::c_void, bstrdeviceidentifier: *mut core::ffi::c_void, bstrinitstring: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPDeviceControl_Impl::Initialize(this, core::mem::transmute(&bstrxmldesc), core::mem::transmute(&bstrdeviceidentifier), core::mem::transmute(&bstrinitstring)).into()
+            }
+        }
+        unsafe extern "system" fn GetServiceObject<Identity: windows_core::IUnknownImpl + IUPnPDeviceControl_Impl>(this: *mut core::ffi::c_void, bstrudn: *mut core::ffi::c_void, bstrserviceid: *mut core::ffi::c_void, ppdispservice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDeviceControl_Impl::GetServiceObject(this, core::mem::transmute(&bstrudn), core::mem::transmute(&bstrserviceid)) {
+                    Ok(ok__) => {
+                        ppdispservice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            GetServiceObject: GetServiceObject::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IUPnPDeviceControl {}
 windows_core::imp::define_interface!(IUPnPDeviceControlHttpHeaders, IUPnPDeviceControlHttpHeaders_Vtbl, 0x204810bb_73b2_11d4_bf42_00b0d0118b56);
 windows_core::imp::interface_hierarchy!(IUPnPDeviceControlHttpHeaders, windows_core::IUnknown);
@@ -913,6 +1347,29 @@ impl IUPnPDeviceControlHttpHeaders_Vtbl {
         iid == &<IUPnPDeviceControlHttpHeaders as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IUPnPDeviceControlHttpHeaders_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPDeviceControlHttpHeaders_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAdditionalResponseHeaders<Identity: windows_core::IUnknownImpl + IUPnPDeviceControlHttpHeaders_Impl>(this: *mut core::ffi::c_void, bstrhttpresponseheaders: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDeviceControlHttpHeaders_Impl::GetAdditionalResponseHeaders(this) {
+                    Ok(ok__) => {
+                        bstrhttpresponseheaders.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAdditionalResponseHeaders: GetAdditionalResponseHeaders::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IUPnPDeviceControlHttpHeaders {}
 windows_core::imp::define_interface!(IUPnPDeviceDocumentAccess, IUPnPDeviceDocumentAccess_Vtbl, 0xe7772804_3287_418e_9072_cf2b47238981);
 windows_core::imp::interface_hierarchy!(IUPnPDeviceDocumentAccess, windows_core::IUnknown);
@@ -953,6 +1410,26 @@ impl IUPnPDeviceDocumentAccess_Vtbl {
         iid == &<IUPnPDeviceDocumentAccess as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IUPnPDeviceDocumentAccess_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPDeviceDocumentAccess_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDocumentURL<Identity: windows_core::IUnknownImpl + IUPnPDeviceDocumentAccess_Impl>(this: *mut core::ffi::c_void, pbstrdocument: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDeviceDocumentAccess_Impl::GetDocumentURL(this) {
+                    Ok(ok__) => {
+                        pbstrdocument.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDocumentURL: GetDocumentURL::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IUPnPDeviceDocumentAccess {}
 windows_core::imp::define_interface!(IUPnPDeviceDocumentAccessEx, IUPnPDeviceDocumentAccessEx_Vtbl, 0xc4bc4050_6178_4bd1_a4b8_6398321f3247);
 windows_core::imp::interface_hierarchy!(IUPnPDeviceDocumentAccessEx, windows_core::IUnknown);
@@ -993,6 +1470,26 @@ impl IUPnPDeviceDocumentAccessEx_Vtbl {
         iid == &<IUPnPDeviceDocumentAccessEx as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IUPnPDeviceDocumentAccessEx_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPDeviceDocumentAccessEx_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDocument<Identity: windows_core::IUnknownImpl + IUPnPDeviceDocumentAccessEx_Impl>(this: *mut core::ffi::c_void, pbstrdocument: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDeviceDocumentAccessEx_Impl::GetDocument(this) {
+                    Ok(ok__) => {
+                        pbstrdocument.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDocument: GetDocument::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IUPnPDeviceDocumentAccessEx {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IUPnPDeviceFinder, IUPnPDeviceFinder_Vtbl, 0xadda3d55_6f72_4319_bff9_18600a539b10);
@@ -1119,6 +1616,70 @@ impl IUPnPDeviceFinder_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IUPnPDeviceFinder_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPDeviceFinder_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FindByType<Identity: windows_core::IUnknownImpl + IUPnPDeviceFinder_Impl>(this: *mut core::ffi::c_void, bstrtypeuri: *mut core::ffi::c_void, dwflags: u32, pdevices: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDeviceFinder_Impl::FindByType(this, core::mem::transmute(&bstrtypeuri), core::mem::transmute_copy(&dwflags)) {
+                    Ok(ok__) => {
+                        pdevices.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateAsyncFind<Identity: windows_core::IUnknownImpl + IUPnPDeviceFinder_Impl>(this: *mut core::ffi::c_void, bstrtypeuri: *mut core::ffi::c_void, dwflags: u32, punkdevicefindercallback: *mut core::ffi::c_void, plfinddata: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDeviceFinder_Impl::CreateAsyncFind(this, core::mem::transmute(&bstrtypeuri), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&punkdevicefindercallback)) {
+                    Ok(ok__) => {
+                        plfinddata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StartAsyncFind<Identity: windows_core::IUnknownImpl + IUPnPDeviceFinder_Impl>(this: *mut core::ffi::c_void, lfinddata: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPDeviceFinder_Impl::StartAsyncFind(this, core::mem::transmute_copy(&lfinddata)).into()
+            }
+        }
+        unsafe extern "system" fn CancelAsyncFind<Identity: windows_core::IUnknownImpl + IUPnPDeviceFinder_Impl>(this: *mut core::ffi::c_void, lfinddata: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPDeviceFinder_Impl::CancelAsyncFind(this, core::mem::transmute_copy(&lfinddata)).into()
+            }
+        }
+        unsafe extern "system" fn FindByUDN<Identity: windows_core::IUnknownImpl + IUPnPDeviceFinder_Impl>(this: *mut core::ffi::c_void, bstrudn: *mut core::ffi::c_void, pdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDeviceFinder_Impl::FindByUDN(this, core::mem::transmute(&bstrudn)) {
+                    Ok(ok__) => {
+                        pdevice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            FindByType: FindByType::<Identity>,
+            CreateAsyncFind: CreateAsyncFind::<Identity>,
+            StartAsyncFind: StartAsyncFind::<Identity>,
+            CancelAsyncFind: CancelAsyncFind::<Identity>,
+            FindByUDN: FindByUDN::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IUPnPDeviceFinder {}
 windows_core::imp::define_interface!(IUPnPDeviceFinderAddCallbackWithInterface, IUPnPDeviceFinderAddCallbackWithInterface_Vtbl, 0x983dfc0b_1796_44df_8975_ca545b620ee5);
 windows_core::imp::interface_hierarchy!(IUPnPDeviceFinderAddCallbackWithInterface, windows_core::IUnknown);
@@ -1160,6 +1721,24 @@ impl IUPnPDeviceFinderAddCallbackWithInterface_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IUPnPDeviceFinderAddCallbackWithInterface_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPDeviceFinderAddCallbackWithInterface_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DeviceAddedWithInterface<Identity: windows_core::IUnknownImpl + IUPnPDeviceFinderAddCallbackWithInterface_Impl>(this: *mut core::ffi::c_void, lfinddata: i32, pdevice: *mut core::ffi::c_void, pguidinterface: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPDeviceFinderAddCallbackWithInterface_Impl::DeviceAddedWithInterface(this, core::mem::transmute_copy(&lfinddata), core::mem::transmute_copy(&pdevice), core::mem::transmute_copy(&pguidinterface)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DeviceAddedWithInterface: DeviceAddedWithInterface::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IUPnPDeviceFinderAddCallbackWithInterface {}
 windows_core::imp::define_interface!(IUPnPDeviceFinderCallback, IUPnPDeviceFinderCallback_Vtbl, 0x415a984a_88b3_49f3_92af_0508bedf0d6c);
 windows_core::imp::interface_hierarchy!(IUPnPDeviceFinderCallback, windows_core::IUnknown);
@@ -1228,6 +1807,38 @@ impl IUPnPDeviceFinderCallback_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IUPnPDeviceFinderCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPDeviceFinderCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DeviceAdded<Identity: windows_core::IUnknownImpl + IUPnPDeviceFinderCallback_Impl>(this: *mut core::ffi::c_void, lfinddata: i32, pdevice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPDeviceFinderCallback_Impl::DeviceAdded(this, core::mem::transmute_copy(&lfinddata), core::mem::transmute_copy(&pdevice)).into()
+            }
+        }
+        unsafe extern "system" fn DeviceRemoved<Identity: windows_core::IUnknownImpl + IUPnPDeviceFinderCallback_Impl>(this: *mut core::ffi::c_void, lfinddata: i32, bstrudn: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPDeviceFinderCallback_Impl::DeviceRemoved(this, core::mem::transmute_copy(&lfinddata), core::mem::transmute(&bstrudn)).into()
+            }
+        }
+        unsafe extern "system" fn SearchComplete<Identity: windows_core::IUnknownImpl + IUPnPDeviceFinderCallback_Impl>(this: *mut core::ffi::c_void, lfinddata: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPDeviceFinderCallback_Impl::SearchComplete(this, core::mem::transmute_copy(&lfinddata)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DeviceAdded: DeviceAdded::<Identity>,
+            DeviceRemoved: DeviceRemoved::<Identity>,
+            SearchComplete: SearchComplete::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IUPnPDeviceFinderCallback {}
 windows_core::imp::define_interface!(IUPnPDeviceProvider, IUPnPDeviceProvider_Vtbl, 0x204810b8_73b2_11d4_bf42_00b0d0118b56);
 windows_core::imp::interface_hierarchy!(IUPnPDeviceProvider, windows_core::IUnknown);
@@ -1270,6 +1881,26 @@ impl IUPnPDeviceProvider_Vtbl {
         iid == &<IUPnPDeviceProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IUPnPDeviceProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPDeviceProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Start<Identity: windows_core::IUnknownImpl + IUPnPDeviceProvider_Impl>(this: *mut core::ffi::c_void, bstrinitstring: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPDeviceProvider_Impl::Start(this, core::mem::transmute(&bstrinitstring)).into()
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + IUPnPDeviceProvider_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPDeviceProvider_Impl::Stop(this).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Start: Start::<Identity>, Stop: Stop::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IUPnPDeviceProvider {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IUPnPDevices, IUPnPDevices_Vtbl, 0xfdbc0c73_bda3_4c66_ac4f_f2d96fdad68c);
@@ -1369,6 +2000,56 @@ impl IUPnPDevices_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IUPnPDevices_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPDevices_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + IUPnPDevices_Impl>(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDevices_Impl::Count(this) {
+                    Ok(ok__) => {
+                        plcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + IUPnPDevices_Impl>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDevices_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        ppunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_Item<Identity: windows_core::IUnknownImpl + IUPnPDevices_Impl>(this: *mut core::ffi::c_void, bstrudn: *mut core::ffi::c_void, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPDevices_Impl::get_Item(this, core::mem::transmute(&bstrudn)) {
+                    Ok(ok__) => {
+                        ppdevice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Count: Count::<Identity>,
+            _NewEnum: _NewEnum::<Identity>,
+            get_Item: get_Item::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IUPnPDevices {}
 windows_core::imp::define_interface!(IUPnPEventSink, IUPnPEventSink_Vtbl, 0x204810b4_73b2_11d4_bf42_00b0d0118b56);
 windows_core::imp::interface_hierarchy!(IUPnPEventSink, windows_core::IUnknown);
@@ -1422,6 +2103,31 @@ impl IUPnPEventSink_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IUPnPEventSink_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPEventSink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnStateChanged<Identity: windows_core::IUnknownImpl + IUPnPEventSink_Impl>(this: *mut core::ffi::c_void, cchanges: u32, rgdispidchanges: *const i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPEventSink_Impl::OnStateChanged(this, core::mem::transmute_copy(&cchanges), core::mem::transmute_copy(&rgdispidchanges)).into()
+            }
+        }
+        unsafe extern "system" fn OnStateChangedSafe<Identity: windows_core::IUnknownImpl + IUPnPEventSink_Impl>(this: *mut core::ffi::c_void, varsadispidchanges: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPEventSink_Impl::OnStateChangedSafe(this, core::mem::transmute(&varsadispidchanges)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnStateChanged: OnStateChanged::<Identity>,
+            OnStateChangedSafe: OnStateChangedSafe::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IUPnPEventSink {}
 windows_core::imp::define_interface!(IUPnPEventSource, IUPnPEventSource_Vtbl, 0x204810b5_73b2_11d4_bf42_00b0d0118b56);
 windows_core::imp::interface_hierarchy!(IUPnPEventSource, windows_core::IUnknown);
@@ -1470,6 +2176,30 @@ impl IUPnPEventSource_Vtbl {
         iid == &<IUPnPEventSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IUPnPEventSource_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPEventSource_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Advise<Identity: windows_core::IUnknownImpl + IUPnPEventSource_Impl>(this: *mut core::ffi::c_void, pessubscriber: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPEventSource_Impl::Advise(this, core::mem::transmute_copy(&pessubscriber)).into()
+            }
+        }
+        unsafe extern "system" fn Unadvise<Identity: windows_core::IUnknownImpl + IUPnPEventSource_Impl>(this: *mut core::ffi::c_void, pessubscriber: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPEventSource_Impl::Unadvise(this, core::mem::transmute_copy(&pessubscriber)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Advise: Advise::<Identity>,
+            Unadvise: Unadvise::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IUPnPEventSource {}
 windows_core::imp::define_interface!(IUPnPHttpHeaderControl, IUPnPHttpHeaderControl_Vtbl, 0x0405af4f_8b5c_447c_80f2_b75984a31f3c);
 windows_core::imp::interface_hierarchy!(IUPnPHttpHeaderControl, windows_core::IUnknown);
@@ -1501,6 +2231,20 @@ impl IUPnPHttpHeaderControl_Vtbl {
         iid == &<IUPnPHttpHeaderControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IUPnPHttpHeaderControl_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPHttpHeaderControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddRequestHeaders<Identity: windows_core::IUnknownImpl + IUPnPHttpHeaderControl_Impl>(this: *mut core::ffi::c_void, bstrhttpheaders: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPHttpHeaderControl_Impl::AddRequestHeaders(this, core::mem::transmute(&bstrhttpheaders)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, AddRequestHeaders: AddRequestHeaders::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IUPnPHttpHeaderControl {}
 windows_core::imp::define_interface!(IUPnPRegistrar, IUPnPRegistrar_Vtbl, 0x204810b6_73b2_11d4_bf42_00b0d0118b56);
 windows_core::imp::interface_hierarchy!(IUPnPRegistrar, windows_core::IUnknown);
@@ -1625,6 +2369,76 @@ impl IUPnPRegistrar_Vtbl {
         iid == &<IUPnPRegistrar as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IUPnPRegistrar_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPRegistrar_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterDevice<Identity: windows_core::IUnknownImpl + IUPnPRegistrar_Impl>(this: *mut core::ffi::c_void, bstrxmldesc: *mut core::ffi::c_void, bstrprogiddevicecontrolclass: *mut core::ffi::c_void, bstrinitstring: *mut core::ffi::c_void, bstrcontainerid: *mut core::ffi::c_void, bstrresourcepath: *mut core::ffi::c_void, nlifetime: i32, pbstrdeviceidentifier: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPRegistrar_Impl::RegisterDevice(this, core::mem::transmute(&bstrxmldesc), core::mem::transmute(&bstrprogiddevicecontrolclass), core::mem::transmute(&bstrinitstring), core::mem::transmute(&bstrcontainerid), core::mem::transmute(&bstrresourcepath), core::mem::transmute_copy(&nlifetime)) {
+                    Ok(ok__) => {
+                        pbstrdeviceidentifier.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RegisterRunningDevice<Identity: windows_core::IUnknownImpl + IUPnPRegistrar_Impl>(this: *mut core::ffi::c_void, bstrxmldesc: *mut core::ffi::c_void, punkdevicecontrol: *mut core::ffi::c_void, bstrinitstring: *mut core::ffi::c_void, bstrresourcepath: *mut core::ffi::c_void, nlifetime: i32, pbstrdeviceidentifier: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPRegistrar_Impl::RegisterRunningDevice(this, core::mem::transmute(&bstrxmldesc), core::mem::transmute_copy(&punkdevicecontrol), core::mem::transmute(&bstrinitstring), core::mem::transmute(&bstrresourcepath), core::mem::transmute_copy(&nlifetime)) {
+                    Ok(ok__) => {
+                        pbstrdeviceidentifier.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RegisterDeviceProvider<Identity: windows_core::IUnknownImpl + IUPnPRegistrar_Impl>(this: *mut core::ffi::c_void, bstrprovidername: *mut core::ffi::c_void, bstrprogidproviderclass: *mut core::ffi::c_void, bstrinitstring: *mut core::ffi::c_void, bstrcontainerid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPRegistrar_Impl::RegisterDeviceProvider(this, core::mem::transmute(&bstrprovidername), core::mem::transmute(&bstrprogidproviderclass), core::mem::transmute(&bstrinitstring), core::mem::transmute(&bstrcontainerid)).into()
+            }
+        }
+        unsafe extern "system" fn GetUniqueDeviceName<Identity: windows_core::IUnknownImpl + IUPnPRegistrar_Impl>(this: *mut core::ffi::c_void, bstrdeviceidentifier: *mut core::ffi::c_void, bstrtemplateudn: *mut core::ffi::c_void, pbstrudn: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPRegistrar_Impl::GetUniqueDeviceName(this, core::mem::transmute(&bstrdeviceidentifier), core::mem::transmute(&bstrtemplateudn)) {
+                    Ok(ok__) => {
+                        pbstrudn.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UnregisterDevice<Identity: windows_core::IUnknownImpl + IUPnPRegistrar_Impl>(this: *mut core::ffi::c_void, bstrdeviceidentifier: *mut core::ffi::c_void, fpermanent: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPRegistrar_Impl::UnregisterDevice(this, core::mem::transmute(&bstrdeviceidentifier), core::mem::transmute_copy(&fpermanent)).into()
+            }
+        }
+        unsafe extern "system" fn UnregisterDeviceProvider<Identity: windows_core::IUnknownImpl + IUPnPRegistrar_Impl>(this: *mut core::ffi::c_void, bstrprovidername: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPRegistrar_Impl::UnregisterDeviceProvider(this, core::mem::transmute(&bstrprovidername)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterDevice: RegisterDevice::<Identity>,
+            RegisterRunningDevice: RegisterRunningDevice::<Identity>,
+            RegisterDeviceProvider: RegisterDeviceProvider::<Identity>,
+            GetUniqueDeviceName: GetUniqueDeviceName::<Identity>,
+            UnregisterDevice: UnregisterDevice::<Identity>,
+            UnregisterDeviceProvider: UnregisterDeviceProvider::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IUPnPRegistrar {}
 windows_core::imp::define_interface!(IUPnPRemoteEndpointInfo, IUPnPRemoteEndpointInfo_Vtbl, 0xc92eb863_0269_4aff_9c72_75321bba2952);
 windows_core::imp::interface_hierarchy!(IUPnPRemoteEndpointInfo, windows_core::IUnknown);
@@ -1661,11 +2475,63 @@ pub trait IUPnPRemoteEndpointInfo_Impl: windows_core::IUnknownImpl {
     fn GetStringValue(&self, bstrvaluename: &windows_core::BSTR) -> windows_core::Result<windows_core::BSTR>;
     fn GetGuidValue(&self, bstrvaluename: &windows_core::BSTR) -> windows_core::Result<windows_core::GUID>;
 }
-impl IUPnPRemoteEndpointInfo_Vtbl {
-    pub const fn new<Identity: IUPnPRemoteEndpointInfo_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn GetDwordValue<Identity: IUPnPRemoteEndpointInfo_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrvaluename: *mut core::ffi::c_void, pdwvalue: *mut u32) -> windows_core::HRESULT {
+impl IUPnPRemoteEndpointInfo_Vtbl {
+    pub const fn new<Identity: IUPnPRemoteEndpointInfo_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn GetDwordValue<Identity: IUPnPRemoteEndpointInfo_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrvaluename: *mut core::ffi::c_void, pdwvalue: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IUPnPRemoteEndpointInfo_Impl::GetDwordValue(this, core::mem::transmute(&bstrvaluename)) {
+                    Ok(ok__) => {
+                        pdwvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStringValue<Identity: IUPnPRemoteEndpointInfo_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrvaluename: *mut core::ffi::c_void, pbstrvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IUPnPRemoteEndpointInfo_Impl::GetStringValue(this, core::mem::transmute(&bstrvaluename)) {
+                    Ok(ok__) => {
+                        pbstrvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetGuidValue<Identity: IUPnPRemoteEndpointInfo_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrvaluename: *mut core::ffi::c_void, pguidvalue: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IUPnPRemoteEndpointInfo_Impl::GetGuidValue(this, core::mem::transmute(&bstrvaluename)) {
+                    Ok(ok__) => {
+                        pguidvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            GetDwordValue: GetDwordValue::<Identity, OFFSET>,
+            GetStringValue: GetStringValue::<Identity, OFFSET>,
+            GetGuidValue: GetGuidValue::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPRemoteEndpointInfo as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IUPnPRemoteEndpointInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPRemoteEndpointInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDwordValue<Identity: windows_core::IUnknownImpl + IUPnPRemoteEndpointInfo_Impl>(this: *mut core::ffi::c_void, bstrvaluename: *mut core::ffi::c_void, pdwvalue: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IUPnPRemoteEndpointInfo_Impl::GetDwordValue(this, core::mem::transmute(&bstrvaluename)) {
                     Ok(ok__) => {
                         pdwvalue.write(core::mem::transmute(ok__));
@@ -1675,9 +2541,9 @@ impl IUPnPRemoteEndpointInfo_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetStringValue<Identity: IUPnPRemoteEndpointInfo_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrvaluename: *mut core::ffi::c_void, pbstrvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetStringValue<Identity: windows_core::IUnknownImpl + IUPnPRemoteEndpointInfo_Impl>(this: *mut core::ffi::c_void, bstrvaluename: *mut core::ffi::c_void, pbstrvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IUPnPRemoteEndpointInfo_Impl::GetStringValue(this, core::mem::transmute(&bstrvaluename)) {
                     Ok(ok__) => {
                         pbstrvalue.write(core::mem::transmute(ok__));
@@ -1687,9 +2553,9 @@ impl IUPnPRemoteEndpointInfo_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetGuidValue<Identity: IUPnPRemoteEndpointInfo_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrvaluename: *mut core::ffi::c_void, pguidvalue: *mut windows_core::GUID) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetGuidValue<Identity: windows_core::IUnknownImpl + IUPnPRemoteEndpointInfo_Impl>(this: *mut core::ffi::c_void, bstrvaluename: *mut core::ffi::c_void, pguidvalue: *mut windows_core::GUID) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IUPnPRemoteEndpointInfo_Impl::GetGuidValue(this, core::mem::transmute(&bstrvaluename)) {
                     Ok(ok__) => {
                         pguidvalue.write(core::mem::transmute(ok__));
@@ -1700,15 +2566,12 @@ impl IUPnPRemoteEndpointInfo_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            GetDwordValue: GetDwordValue::<Identity, OFFSET>,
-            GetStringValue: GetStringValue::<Identity, OFFSET>,
-            GetGuidValue: GetGuidValue::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDwordValue: GetDwordValue::<Identity>,
+            GetStringValue: GetStringValue::<Identity>,
+            GetGuidValue: GetGuidValue::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IUPnPRemoteEndpointInfo as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IUPnPRemoteEndpointInfo {}
 windows_core::imp::define_interface!(IUPnPReregistrar, IUPnPReregistrar_Vtbl, 0x204810b7_73b2_11d4_bf42_00b0d0118b56);
@@ -1759,6 +2622,30 @@ impl IUPnPReregistrar_Vtbl {
         iid == &<IUPnPReregistrar as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IUPnPReregistrar_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPReregistrar_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ReregisterDevice<Identity: windows_core::IUnknownImpl + IUPnPReregistrar_Impl>(this: *mut core::ffi::c_void, bstrdeviceidentifier: *mut core::ffi::c_void, bstrxmldesc: *mut core::ffi::c_void, bstrprogiddevicecontrolclass: *mut core::ffi::c_void, bstrinitstring: *mut core::ffi::c_void, bstrcontainerid: *mut core::ffi::c_void, bstrresourcepath: *mut core::ffi::c_void, nlifetime: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPReregistrar_Impl::ReregisterDevice(this, core::mem::transmute(&bstrdeviceidentifier), core::mem::transmute(&bstrxmldesc), core::mem::transmute(&bstrprogiddevicecontrolclass), core::mem::transmute(&bstrinitstring), core::mem::transmute(&bstrcontainerid), core::mem::transmute(&bstrresourcepath), core::mem::transmute_copy(&nlifetime)).into()
+            }
+        }
+        unsafe extern "system" fn ReregisterRunningDevice<Identity: windows_core::IUnknownImpl + IUPnPReregistrar_Impl>(this: *mut core::ffi::c_void, bstrdeviceidentifier: *mut core::ffi::c_void, bstrxmldesc: *mut core::ffi::c_void, punkdevicecontrol: *mut core::ffi::c_void, bstrinitstring: *mut core::ffi::c_void, bstrresourcepath: *mut core::ffi::c_void, nlifetime: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPReregistrar_Impl::ReregisterRunningDevice(this, core::mem::transmute(&bstrdeviceidentifier), core::mem::transmute(&bstrxmldesc), core::mem::transmute_copy(&punkdevicecontrol), core::mem::transmute(&bstrinitstring), core::mem::transmute(&bstrresourcepath), core::mem::transmute_copy(&nlifetime)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ReregisterDevice: ReregisterDevice::<Identity>,
+            ReregisterRunningDevice: ReregisterRunningDevice::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IUPnPReregistrar {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IUPnPService, IUPnPService_Vtbl, 0xa295019c_dc65_47dd_90dc_7fe918a1ab44);
@@ -1923,6 +2810,89 @@ impl IUPnPService_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IUPnPService_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPService_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryStateVariable<Identity: windows_core::IUnknownImpl + IUPnPService_Impl>(this: *mut core::ffi::c_void, bstrvariablename: *mut core::ffi::c_void, pvalue: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPService_Impl::QueryStateVariable(this, core::mem::transmute(&bstrvariablename)) {
+                    Ok(ok__) => {
+                        pvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InvokeAction<Identity: windows_core::IUnknownImpl + IUPnPService_Impl>(this: *mut core::ffi::c_void, bstractionname: *mut core::ffi::c_void, vinactionargs: super::super::super::System::Variant::VARIANT, pvoutactionargs: *mut super::super::super::System::Variant::VARIANT, pvretval: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPService_Impl::InvokeAction(this, core::mem::transmute(&bstractionname), core::mem::transmute(&vinactionargs), core::mem::transmute_copy(&pvoutactionargs)) {
+                    Ok(ok__) => {
+                        pvretval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ServiceTypeIdentifier<Identity: windows_core::IUnknownImpl + IUPnPService_Impl>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPService_Impl::ServiceTypeIdentifier(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddCallback<Identity: windows_core::IUnknownImpl + IUPnPService_Impl>(this: *mut core::ffi::c_void, punkcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPService_Impl::AddCallback(this, core::mem::transmute_copy(&punkcallback)).into()
+            }
+        }
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + IUPnPService_Impl>(this: *mut core::ffi::c_void, pbstrid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPService_Impl::Id(this) {
+                    Ok(ok__) => {
+                        pbstrid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LastTransportStatus<Identity: windows_core::IUnknownImpl + IUPnPService_Impl>(this: *mut core::ffi::c_void, plvalue: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPService_Impl::LastTransportStatus(this) {
+                    Ok(ok__) => {
+                        plvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryStateVariable: QueryStateVariable::<Identity>,
+            InvokeAction: InvokeAction::<Identity>,
+            ServiceTypeIdentifier: ServiceTypeIdentifier::<Identity>,
+            AddCallback: AddCallback::<Identity>,
+            Id: Id::<Identity>,
+            LastTransportStatus: LastTransportStatus::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IUPnPService {}
 windows_core::imp::define_interface!(IUPnPServiceAsync, IUPnPServiceAsync_Vtbl, 0x098bdaf5_5ec1_49e7_a260_b3a11dd8680c);
 windows_core::imp::interface_hierarchy!(IUPnPServiceAsync, windows_core::IUnknown);
@@ -2126,6 +3096,110 @@ impl IUPnPServiceAsync_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IUPnPServiceAsync_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPServiceAsync_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BeginInvokeAction<Identity: windows_core::IUnknownImpl + IUPnPServiceAsync_Impl>(this: *mut core::ffi::c_void, bstractionname: *mut core::ffi::c_void, vinactionargs: super::super::super::System::Variant::VARIANT, pasyncresult: *mut core::ffi::c_void, pullrequestid: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPServiceAsync_Impl::BeginInvokeAction(this, core::mem::transmute(&bstractionname), core::mem::transmute(&vinactionargs), core::mem::transmute_copy(&pasyncresult)) {
+                    Ok(ok__) => {
+                        pullrequestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EndInvokeAction<Identity: windows_core::IUnknownImpl + IUPnPServiceAsync_Impl>(this: *mut core::ffi::c_void, ullrequestid: u64, pvoutactionargs: *mut super::super::super::System::Variant::VARIANT, pvretval: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPServiceAsync_Impl::EndInvokeAction(this, core::mem::transmute_copy(&ullrequestid), core::mem::transmute_copy(&pvoutactionargs), core::mem::transmute_copy(&pvretval)).into()
+            }
+        }
+        unsafe extern "system" fn BeginQueryStateVariable<Identity: windows_core::IUnknownImpl + IUPnPServiceAsync_Impl>(this: *mut core::ffi::c_void, bstrvariablename: *mut core::ffi::c_void, pasyncresult: *mut core::ffi::c_void, pullrequestid: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPServiceAsync_Impl::BeginQueryStateVariable(this, core::mem::transmute(&bstrvariablename), core::mem::transmute_copy(&pasyncresult)) {
+                    Ok(ok__) => {
+                        pullrequestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EndQueryStateVariable<Identity: windows_core::IUnknownImpl + IUPnPServiceAsync_Impl>(this: *mut core::ffi::c_void, ullrequestid: u64, pvalue: *mut super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPServiceAsync_Impl::EndQueryStateVariable(this, core::mem::transmute_copy(&ullrequestid), core::mem::transmute_copy(&pvalue)).into()
+            }
+        }
+        unsafe extern "system" fn BeginSubscribeToEvents<Identity: windows_core::IUnknownImpl + IUPnPServiceAsync_Impl>(this: *mut core::ffi::c_void, punkcallback: *mut core::ffi::c_void, pasyncresult: *mut core::ffi::c_void, pullrequestid: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPServiceAsync_Impl::BeginSubscribeToEvents(this, core::mem::transmute_copy(&punkcallback), core::mem::transmute_copy(&pasyncresult)) {
+                    Ok(ok__) => {
+                        pullrequestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EndSubscribeToEvents<Identity: windows_core::IUnknownImpl + IUPnPServiceAsync_Impl>(this: *mut core::ffi::c_void, ullrequestid: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPServiceAsync_Impl::EndSubscribeToEvents(this, core::mem::transmute_copy(&ullrequestid)).into()
+            }
+        }
+        unsafe extern "system" fn BeginSCPDDownload<Identity: windows_core::IUnknownImpl + IUPnPServiceAsync_Impl>(this: *mut core::ffi::c_void, pasyncresult: *mut core::ffi::c_void, pullrequestid: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPServiceAsync_Impl::BeginSCPDDownload(this, core::mem::transmute_copy(&pasyncresult)) {
+                    Ok(ok__) => {
+                        pullrequestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EndSCPDDownload<Identity: windows_core::IUnknownImpl + IUPnPServiceAsync_Impl>(this: *mut core::ffi::c_void, ullrequestid: u64, pbstrscpddoc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPServiceAsync_Impl::EndSCPDDownload(this, core::mem::transmute_copy(&ullrequestid)) {
+                    Ok(ok__) => {
+                        pbstrscpddoc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CancelAsyncOperation<Identity: windows_core::IUnknownImpl + IUPnPServiceAsync_Impl>(this: *mut core::ffi::c_void, ullrequestid: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPServiceAsync_Impl::CancelAsyncOperation(this, core::mem::transmute_copy(&ullrequestid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            BeginInvokeAction: BeginInvokeAction::<Identity>,
+            EndInvokeAction: EndInvokeAction::<Identity>,
+            BeginQueryStateVariable: BeginQueryStateVariable::<Identity>,
+            EndQueryStateVariable: EndQueryStateVariable::<Identity>,
+            BeginSubscribeToEvents: BeginSubscribeToEvents::<Identity>,
+            EndSubscribeToEvents: EndSubscribeToEvents::<Identity>,
+            BeginSCPDDownload: BeginSCPDDownload::<Identity>,
+            EndSCPDDownload: EndSCPDDownload::<Identity>,
+            CancelAsyncOperation: CancelAsyncOperation::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IUPnPServiceAsync {}
 windows_core::imp::define_interface!(IUPnPServiceCallback, IUPnPServiceCallback_Vtbl, 0x31fadca9_ab73_464b_b67d_5c1d0f83c8b8);
 windows_core::imp::interface_hierarchy!(IUPnPServiceCallback, windows_core::IUnknown);
@@ -2190,6 +3264,31 @@ impl IUPnPServiceCallback_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IUPnPServiceCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPServiceCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StateVariableChanged<Identity: windows_core::IUnknownImpl + IUPnPServiceCallback_Impl>(this: *mut core::ffi::c_void, pus: *mut core::ffi::c_void, pcwszstatevarname: windows_core::PCWSTR, vavalue: super::super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPServiceCallback_Impl::StateVariableChanged(this, core::mem::transmute_copy(&pus), core::mem::transmute(&pcwszstatevarname), core::mem::transmute(&vavalue)).into()
+            }
+        }
+        unsafe extern "system" fn ServiceInstanceDied<Identity: windows_core::IUnknownImpl + IUPnPServiceCallback_Impl>(this: *mut core::ffi::c_void, pus: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPServiceCallback_Impl::ServiceInstanceDied(this, core::mem::transmute_copy(&pus)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            StateVariableChanged: StateVariableChanged::<Identity>,
+            ServiceInstanceDied: ServiceInstanceDied::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IUPnPServiceCallback {}
 windows_core::imp::define_interface!(IUPnPServiceDocumentAccess, IUPnPServiceDocumentAccess_Vtbl, 0x21905529_0a5e_4589_825d_7e6d87ea6998);
 windows_core::imp::interface_hierarchy!(IUPnPServiceDocumentAccess, windows_core::IUnknown);
@@ -2254,6 +3353,42 @@ impl IUPnPServiceDocumentAccess_Vtbl {
         iid == &<IUPnPServiceDocumentAccess as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IUPnPServiceDocumentAccess_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPServiceDocumentAccess_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDocumentURL<Identity: windows_core::IUnknownImpl + IUPnPServiceDocumentAccess_Impl>(this: *mut core::ffi::c_void, pbstrdocurl: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPServiceDocumentAccess_Impl::GetDocumentURL(this) {
+                    Ok(ok__) => {
+                        pbstrdocurl.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDocument<Identity: windows_core::IUnknownImpl + IUPnPServiceDocumentAccess_Impl>(this: *mut core::ffi::c_void, pbstrdoc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPServiceDocumentAccess_Impl::GetDocument(this) {
+                    Ok(ok__) => {
+                        pbstrdoc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDocumentURL: GetDocumentURL::<Identity>,
+            GetDocument: GetDocument::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IUPnPServiceDocumentAccess {}
 windows_core::imp::define_interface!(IUPnPServiceEnumProperty, IUPnPServiceEnumProperty_Vtbl, 0x38873b37_91bb_49f4_b249_2e8efbb8a816);
 windows_core::imp::interface_hierarchy!(IUPnPServiceEnumProperty, windows_core::IUnknown);
@@ -2285,6 +3420,23 @@ impl IUPnPServiceEnumProperty_Vtbl {
         iid == &<IUPnPServiceEnumProperty as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IUPnPServiceEnumProperty_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPServiceEnumProperty_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetServiceEnumProperty<Identity: windows_core::IUnknownImpl + IUPnPServiceEnumProperty_Impl>(this: *mut core::ffi::c_void, dwmask: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUPnPServiceEnumProperty_Impl::SetServiceEnumProperty(this, core::mem::transmute_copy(&dwmask)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetServiceEnumProperty: SetServiceEnumProperty::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IUPnPServiceEnumProperty {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IUPnPServices, IUPnPServices_Vtbl, 0x3f8c8e9e_9a7a_4dc8_bc41_ff31fa374956);
@@ -2384,6 +3536,56 @@ impl IUPnPServices_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IUPnPServices_Impl> windows_core::imp::TearOffVtable<Identity> for IUPnPServices_Vtbl
+where
+    super::super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + IUPnPServices_Impl>(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPServices_Impl::Count(this) {
+                    Ok(ok__) => {
+                        plcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + IUPnPServices_Impl>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPServices_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        ppunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_Item<Identity: windows_core::IUnknownImpl + IUPnPServices_Impl>(this: *mut core::ffi::c_void, bstrserviceid: *mut core::ffi::c_void, ppservice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUPnPServices_Impl::get_Item(this, core::mem::transmute(&bstrserviceid)) {
+                    Ok(ok__) => {
+                        ppservice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Count: Count::<Identity>,
+            _NewEnum: _NewEnum::<Identity>,
+            get_Item: get_Item::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IUPnPServices {}
 pub const REMOTE_ADDRESS_VALUE_NAME: windows_core::PCWSTR = windows_core::w!("RemoteAddress");
 pub const SWDeviceCapabilitiesDriverRequired: SW_DEVICE_CAPABILITIES = SW_DEVICE_CAPABILITIES(8i32);
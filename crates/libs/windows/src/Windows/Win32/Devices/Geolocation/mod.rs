@@ -1096,6 +1096,108 @@ impl ICivicAddressReport_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ICivicAddressReport_Impl> windows_core::imp::TearOffVtable<Identity> for ICivicAddressReport_Vtbl
+where
+    ILocationReport_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAddressLine1<Identity: windows_core::IUnknownImpl + ICivicAddressReport_Impl>(this: *mut core::ffi::c_void, pbstraddress1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICivicAddressReport_Impl::GetAddressLine1(this) {
+                    Ok(ok__) => {
+                        pbstraddress1.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAddressLine2<Identity: windows_core::IUnknownImpl + ICivicAddressReport_Impl>(this: *mut core::ffi::c_void, pbstraddress2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICivicAddressReport_Impl::GetAddressLine2(this) {
+                    Ok(ok__) => {
+                        pbstraddress2.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCity<Identity: windows_core::IUnknownImpl + ICivicAddressReport_Impl>(this: *mut core::ffi::c_void, pbstrcity: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICivicAddressReport_Impl::GetCity(this) {
+                    Ok(ok__) => {
+                        pbstrcity.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStateProvince<Identity: windows_core::IUnknownImpl + ICivicAddressReport_Impl>(this: *mut core::ffi::c_void, pbstrstateprovince: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICivicAddressReport_Impl::GetStateProvince(this) {
+                    Ok(ok__) => {
+                        pbstrstateprovince.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPostalCode<Identity: windows_core::IUnknownImpl + ICivicAddressReport_Impl>(this: *mut core::ffi::c_void, pbstrpostalcode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICivicAddressReport_Impl::GetPostalCode(this) {
+                    Ok(ok__) => {
+                        pbstrpostalcode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCountryRegion<Identity: windows_core::IUnknownImpl + ICivicAddressReport_Impl>(this: *mut core::ffi::c_void, pbstrcountryregion: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICivicAddressReport_Impl::GetCountryRegion(this) {
+                    Ok(ok__) => {
+                        pbstrcountryregion.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDetailLevel<Identity: windows_core::IUnknownImpl + ICivicAddressReport_Impl>(this: *mut core::ffi::c_void, pdetaillevel: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICivicAddressReport_Impl::GetDetailLevel(this) {
+                    Ok(ok__) => {
+                        pdetaillevel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ILocationReport_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAddressLine1: GetAddressLine1::<Identity>,
+            GetAddressLine2: GetAddressLine2::<Identity>,
+            GetCity: GetCity::<Identity>,
+            GetStateProvince: GetStateProvince::<Identity>,
+            GetPostalCode: GetPostalCode::<Identity>,
+            GetCountryRegion: GetCountryRegion::<Identity>,
+            GetDetailLevel: GetDetailLevel::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ICivicAddressReport {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ICivicAddressReportFactory, ICivicAddressReportFactory_Vtbl, 0xbf773b93_c64f_4bee_beb2_67c0b8df66e0);
@@ -1150,6 +1252,27 @@ impl ICivicAddressReportFactory_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ICivicAddressReportFactory_Impl> windows_core::imp::TearOffVtable<Identity> for ICivicAddressReportFactory_Vtbl
+where
+    ILocationReportFactory_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CivicAddressReport<Identity: windows_core::IUnknownImpl + ICivicAddressReportFactory_Impl>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICivicAddressReportFactory_Impl::CivicAddressReport(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <ILocationReportFactory_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CivicAddressReport: CivicAddressReport::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ICivicAddressReportFactory {}
 windows_core::imp::define_interface!(IDefaultLocation, IDefaultLocation_Vtbl, 0xa65af77e_969a_4a2e_8aca_33bb7cbb1235);
 windows_core::imp::interface_hierarchy!(IDefaultLocation, windows_core::IUnknown);
@@ -1208,6 +1331,36 @@ impl IDefaultLocation_Vtbl {
         iid == &<IDefaultLocation as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDefaultLocation_Impl> windows_core::imp::TearOffVtable<Identity> for IDefaultLocation_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetReport<Identity: windows_core::IUnknownImpl + IDefaultLocation_Impl>(this: *mut core::ffi::c_void, reporttype: *const windows_core::GUID, plocationreport: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDefaultLocation_Impl::SetReport(this, core::mem::transmute_copy(&reporttype), core::mem::transmute_copy(&plocationreport)).into()
+            }
+        }
+        unsafe extern "system" fn GetReport<Identity: windows_core::IUnknownImpl + IDefaultLocation_Impl>(this: *mut core::ffi::c_void, reporttype: *const windows_core::GUID, pplocationreport: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDefaultLocation_Impl::GetReport(this, core::mem::transmute_copy(&reporttype)) {
+                    Ok(ok__) => {
+                        pplocationreport.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetReport: SetReport::<Identity>,
+            GetReport: GetReport::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDefaultLocation {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IDispCivicAddressReport, IDispCivicAddressReport_Vtbl, 0x16ff1a34_9e30_42c3_b44d_e22513b5767a);
@@ -1412,6 +1565,121 @@ impl IDispCivicAddressReport_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IDispCivicAddressReport_Impl> windows_core::imp::TearOffVtable<Identity> for IDispCivicAddressReport_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddressLine1<Identity: windows_core::IUnknownImpl + IDispCivicAddressReport_Impl>(this: *mut core::ffi::c_void, paddress1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispCivicAddressReport_Impl::AddressLine1(this) {
+                    Ok(ok__) => {
+                        paddress1.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddressLine2<Identity: windows_core::IUnknownImpl + IDispCivicAddressReport_Impl>(this: *mut core::ffi::c_void, paddress2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispCivicAddressReport_Impl::AddressLine2(this) {
+                    Ok(ok__) => {
+                        paddress2.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn City<Identity: windows_core::IUnknownImpl + IDispCivicAddressReport_Impl>(this: *mut core::ffi::c_void, pcity: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispCivicAddressReport_Impl::City(this) {
+                    Ok(ok__) => {
+                        pcity.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StateProvince<Identity: windows_core::IUnknownImpl + IDispCivicAddressReport_Impl>(this: *mut core::ffi::c_void, pstateprovince: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispCivicAddressReport_Impl::StateProvince(this) {
+                    Ok(ok__) => {
+                        pstateprovince.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PostalCode<Identity: windows_core::IUnknownImpl + IDispCivicAddressReport_Impl>(this: *mut core::ffi::c_void, ppostalcode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispCivicAddressReport_Impl::PostalCode(this) {
+                    Ok(ok__) => {
+                        ppostalcode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CountryRegion<Identity: windows_core::IUnknownImpl + IDispCivicAddressReport_Impl>(this: *mut core::ffi::c_void, pcountryregion: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispCivicAddressReport_Impl::CountryRegion(this) {
+                    Ok(ok__) => {
+                        pcountryregion.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DetailLevel<Identity: windows_core::IUnknownImpl + IDispCivicAddressReport_Impl>(this: *mut core::ffi::c_void, pdetaillevel: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispCivicAddressReport_Impl::DetailLevel(this) {
+                    Ok(ok__) => {
+                        pdetaillevel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Timestamp<Identity: windows_core::IUnknownImpl + IDispCivicAddressReport_Impl>(this: *mut core::ffi::c_void, pval: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispCivicAddressReport_Impl::Timestamp(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddressLine1: AddressLine1::<Identity>,
+            AddressLine2: AddressLine2::<Identity>,
+            City: City::<Identity>,
+            StateProvince: StateProvince::<Identity>,
+            PostalCode: PostalCode::<Identity>,
+            CountryRegion: CountryRegion::<Identity>,
+            DetailLevel: DetailLevel::<Identity>,
+            Timestamp: Timestamp::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IDispCivicAddressReport {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IDispLatLongReport, IDispLatLongReport_Vtbl, 0x8ae32723_389b_4a11_9957_5bdd48fc9617);
@@ -1574,6 +1842,95 @@ impl IDispLatLongReport_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IDispLatLongReport_Impl> windows_core::imp::TearOffVtable<Identity> for IDispLatLongReport_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Latitude<Identity: windows_core::IUnknownImpl + IDispLatLongReport_Impl>(this: *mut core::ffi::c_void, pval: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispLatLongReport_Impl::Latitude(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Longitude<Identity: windows_core::IUnknownImpl + IDispLatLongReport_Impl>(this: *mut core::ffi::c_void, pval: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispLatLongReport_Impl::Longitude(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ErrorRadius<Identity: windows_core::IUnknownImpl + IDispLatLongReport_Impl>(this: *mut core::ffi::c_void, pval: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispLatLongReport_Impl::ErrorRadius(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Altitude<Identity: windows_core::IUnknownImpl + IDispLatLongReport_Impl>(this: *mut core::ffi::c_void, pval: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispLatLongReport_Impl::Altitude(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AltitudeError<Identity: windows_core::IUnknownImpl + IDispLatLongReport_Impl>(this: *mut core::ffi::c_void, pval: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispLatLongReport_Impl::AltitudeError(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Timestamp<Identity: windows_core::IUnknownImpl + IDispLatLongReport_Impl>(this: *mut core::ffi::c_void, pval: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispLatLongReport_Impl::Timestamp(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Latitude: Latitude::<Identity>,
+            Longitude: Longitude::<Identity>,
+            ErrorRadius: ErrorRadius::<Identity>,
+            Altitude: Altitude::<Identity>,
+            AltitudeError: AltitudeError::<Identity>,
+            Timestamp: Timestamp::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IDispLatLongReport {}
 windows_core::imp::define_interface!(ILatLongReport, ILatLongReport_Vtbl, 0x7fed806d_0ef8_4f07_80ac_36a0beae3134);
 impl core::ops::Deref for ILatLongReport {
@@ -1710,6 +2067,82 @@ impl ILatLongReport_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ILatLongReport_Impl> windows_core::imp::TearOffVtable<Identity> for ILatLongReport_Vtbl
+where
+    ILocationReport_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetLatitude<Identity: windows_core::IUnknownImpl + ILatLongReport_Impl>(this: *mut core::ffi::c_void, platitude: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILatLongReport_Impl::GetLatitude(this) {
+                    Ok(ok__) => {
+                        platitude.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLongitude<Identity: windows_core::IUnknownImpl + ILatLongReport_Impl>(this: *mut core::ffi::c_void, plongitude: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILatLongReport_Impl::GetLongitude(this) {
+                    Ok(ok__) => {
+                        plongitude.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetErrorRadius<Identity: windows_core::IUnknownImpl + ILatLongReport_Impl>(this: *mut core::ffi::c_void, perrorradius: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILatLongReport_Impl::GetErrorRadius(this) {
+                    Ok(ok__) => {
+                        perrorradius.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAltitude<Identity: windows_core::IUnknownImpl + ILatLongReport_Impl>(this: *mut core::ffi::c_void, paltitude: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILatLongReport_Impl::GetAltitude(this) {
+                    Ok(ok__) => {
+                        paltitude.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAltitudeError<Identity: windows_core::IUnknownImpl + ILatLongReport_Impl>(this: *mut core::ffi::c_void, paltitudeerror: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILatLongReport_Impl::GetAltitudeError(this) {
+                    Ok(ok__) => {
+                        paltitudeerror.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ILocationReport_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetLatitude: GetLatitude::<Identity>,
+            GetLongitude: GetLongitude::<Identity>,
+            GetErrorRadius: GetErrorRadius::<Identity>,
+            GetAltitude: GetAltitude::<Identity>,
+            GetAltitudeError: GetAltitudeError::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ILatLongReport {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ILatLongReportFactory, ILatLongReportFactory_Vtbl, 0x3f0804cb_b114_447d_83dd_390174ebb082);
@@ -1764,6 +2197,27 @@ impl ILatLongReportFactory_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ILatLongReportFactory_Impl> windows_core::imp::TearOffVtable<Identity> for ILatLongReportFactory_Vtbl
+where
+    ILocationReportFactory_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn LatLongReport<Identity: windows_core::IUnknownImpl + ILatLongReportFactory_Impl>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILatLongReportFactory_Impl::LatLongReport(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <ILocationReportFactory_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, LatLongReport: LatLongReport::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ILatLongReportFactory {}
 windows_core::imp::define_interface!(ILocation, ILocation_Vtbl, 0xab2ece69_56d9_4f28_b525_de1b0ee44237);
 windows_core::imp::interface_hierarchy!(ILocation, windows_core::IUnknown);
@@ -1944,6 +2398,104 @@ impl ILocation_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Devices_Sensors")]
+impl<Identity: windows_core::IUnknownImpl + ILocation_Impl> windows_core::imp::TearOffVtable<Identity> for ILocation_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterForReport<Identity: windows_core::IUnknownImpl + ILocation_Impl>(this: *mut core::ffi::c_void, pevents: *mut core::ffi::c_void, reporttype: *const windows_core::GUID, dwrequestedreportinterval: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILocation_Impl::RegisterForReport(this, core::mem::transmute_copy(&pevents), core::mem::transmute_copy(&reporttype), core::mem::transmute_copy(&dwrequestedreportinterval)).into()
+            }
+        }
+        unsafe extern "system" fn UnregisterForReport<Identity: windows_core::IUnknownImpl + ILocation_Impl>(this: *mut core::ffi::c_void, reporttype: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILocation_Impl::UnregisterForReport(this, core::mem::transmute_copy(&reporttype)).into()
+            }
+        }
+        unsafe extern "system" fn GetReport<Identity: windows_core::IUnknownImpl + ILocation_Impl>(this: *mut core::ffi::c_void, reporttype: *const windows_core::GUID, pplocationreport: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILocation_Impl::GetReport(this, core::mem::transmute_copy(&reporttype)) {
+                    Ok(ok__) => {
+                        pplocationreport.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetReportStatus<Identity: windows_core::IUnknownImpl + ILocation_Impl>(this: *mut core::ffi::c_void, reporttype: *const windows_core::GUID, pstatus: *mut LOCATION_REPORT_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILocation_Impl::GetReportStatus(this, core::mem::transmute_copy(&reporttype)) {
+                    Ok(ok__) => {
+                        pstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetReportInterval<Identity: windows_core::IUnknownImpl + ILocation_Impl>(this: *mut core::ffi::c_void, reporttype: *const windows_core::GUID, pmilliseconds: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILocation_Impl::GetReportInterval(this, core::mem::transmute_copy(&reporttype)) {
+                    Ok(ok__) => {
+                        pmilliseconds.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetReportInterval<Identity: windows_core::IUnknownImpl + ILocation_Impl>(this: *mut core::ffi::c_void, reporttype: *const windows_core::GUID, millisecondsrequested: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILocation_Impl::SetReportInterval(this, core::mem::transmute_copy(&reporttype), core::mem::transmute_copy(&millisecondsrequested)).into()
+            }
+        }
+        unsafe extern "system" fn GetDesiredAccuracy<Identity: windows_core::IUnknownImpl + ILocation_Impl>(this: *mut core::ffi::c_void, reporttype: *const windows_core::GUID, pdesiredaccuracy: *mut super::Sensors::LOCATION_DESIRED_ACCURACY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILocation_Impl::GetDesiredAccuracy(this, core::mem::transmute_copy(&reporttype)) {
+                    Ok(ok__) => {
+                        pdesiredaccuracy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDesiredAccuracy<Identity: windows_core::IUnknownImpl + ILocation_Impl>(this: *mut core::ffi::c_void, reporttype: *const windows_core::GUID, desiredaccuracy: super::Sensors::LOCATION_DESIRED_ACCURACY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILocation_Impl::SetDesiredAccuracy(this, core::mem::transmute_copy(&reporttype), core::mem::transmute_copy(&desiredaccuracy)).into()
+            }
+        }
+        unsafe extern "system" fn RequestPermissions<Identity: windows_core::IUnknownImpl + ILocation_Impl>(this: *mut core::ffi::c_void, hparent: super::super::Foundation::HWND, preporttypes: *const windows_core::GUID, count: u32, fmodal: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILocation_Impl::RequestPermissions(this, core::mem::transmute_copy(&hparent), core::mem::transmute_copy(&preporttypes), core::mem::transmute_copy(&count), core::mem::transmute_copy(&fmodal)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterForReport: RegisterForReport::<Identity>,
+            UnregisterForReport: UnregisterForReport::<Identity>,
+            GetReport: GetReport::<Identity>,
+            GetReportStatus: GetReportStatus::<Identity>,
+            GetReportInterval: GetReportInterval::<Identity>,
+            SetReportInterval: SetReportInterval::<Identity>,
+            GetDesiredAccuracy: GetDesiredAccuracy::<Identity>,
+            SetDesiredAccuracy: SetDesiredAccuracy::<Identity>,
+            RequestPermissions: RequestPermissions::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Devices_Sensors")]
 impl windows_core::RuntimeName for ILocation {}
 windows_core::imp::define_interface!(ILocationEvents, ILocationEvents_Vtbl, 0xcae02bbf_798b_4508_a207_35a7906dc73d);
 windows_core::imp::interface_hierarchy!(ILocationEvents, windows_core::IUnknown);
@@ -1993,6 +2545,30 @@ impl ILocationEvents_Vtbl {
         iid == &<ILocationEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ILocationEvents_Impl> windows_core::imp::TearOffVtable<Identity> for ILocationEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnLocationChanged<Identity: windows_core::IUnknownImpl + ILocationEvents_Impl>(this: *mut core::ffi::c_void, reporttype: *const windows_core::GUID, plocationreport: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILocationEvents_Impl::OnLocationChanged(this, core::mem::transmute_copy(&reporttype), core::mem::transmute_copy(&plocationreport)).into()
+            }
+        }
+        unsafe extern "system" fn OnStatusChanged<Identity: windows_core::IUnknownImpl + ILocationEvents_Impl>(this: *mut core::ffi::c_void, reporttype: *const windows_core::GUID, newstatus: LOCATION_REPORT_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILocationEvents_Impl::OnStatusChanged(this, core::mem::transmute_copy(&reporttype), core::mem::transmute_copy(&newstatus)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnLocationChanged: OnLocationChanged::<Identity>,
+            OnStatusChanged: OnStatusChanged::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ILocationEvents {}
 windows_core::imp::define_interface!(ILocationPower, ILocationPower_Vtbl, 0x193e7729_ab6b_4b12_8617_7596e1bb191c);
 windows_core::imp::interface_hierarchy!(ILocationPower, windows_core::IUnknown);
@@ -2035,6 +2611,30 @@ impl ILocationPower_Vtbl {
         iid == &<ILocationPower as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ILocationPower_Impl> windows_core::imp::TearOffVtable<Identity> for ILocationPower_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Connect<Identity: windows_core::IUnknownImpl + ILocationPower_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILocationPower_Impl::Connect(this).into()
+            }
+        }
+        unsafe extern "system" fn Disconnect<Identity: windows_core::IUnknownImpl + ILocationPower_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILocationPower_Impl::Disconnect(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Connect: Connect::<Identity>,
+            Disconnect: Disconnect::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ILocationPower {}
 windows_core::imp::define_interface!(ILocationReport, ILocationReport_Vtbl, 0xc8b7f7ee_75d0_4db9_b62d_7a0f369ca456);
 windows_core::imp::interface_hierarchy!(ILocationReport, windows_core::IUnknown);
@@ -2127,6 +2727,56 @@ impl ILocationReport_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ILocationReport_Impl> windows_core::imp::TearOffVtable<Identity> for ILocationReport_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSensorID<Identity: windows_core::IUnknownImpl + ILocationReport_Impl>(this: *mut core::ffi::c_void, psensorid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILocationReport_Impl::GetSensorID(this) {
+                    Ok(ok__) => {
+                        psensorid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTimestamp<Identity: windows_core::IUnknownImpl + ILocationReport_Impl>(this: *mut core::ffi::c_void, pcreationtime: *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILocationReport_Impl::GetTimestamp(this) {
+                    Ok(ok__) => {
+                        pcreationtime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetValue<Identity: windows_core::IUnknownImpl + ILocationReport_Impl>(this: *mut core::ffi::c_void, pkey: *const super::super::Foundation::PROPERTYKEY, pvalue: *mut super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILocationReport_Impl::GetValue(this, core::mem::transmute_copy(&pkey)) {
+                    Ok(ok__) => {
+                        pvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSensorID: GetSensorID::<Identity>,
+            GetTimestamp: GetTimestamp::<Identity>,
+            GetValue: GetValue::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ILocationReport {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ILocationReportFactory, ILocationReportFactory_Vtbl, 0x2daec322_90b2_47e4_bb08_0da841935a6b);
@@ -2286,6 +2936,91 @@ impl ILocationReportFactory_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ILocationReportFactory_Impl> windows_core::imp::TearOffVtable<Identity> for ILocationReportFactory_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ListenForReports<Identity: windows_core::IUnknownImpl + ILocationReportFactory_Impl>(this: *mut core::ffi::c_void, requestedreportinterval: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILocationReportFactory_Impl::ListenForReports(this, core::mem::transmute_copy(&requestedreportinterval)).into()
+            }
+        }
+        unsafe extern "system" fn StopListeningForReports<Identity: windows_core::IUnknownImpl + ILocationReportFactory_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILocationReportFactory_Impl::StopListeningForReports(this).into()
+            }
+        }
+        unsafe extern "system" fn Status<Identity: windows_core::IUnknownImpl + ILocationReportFactory_Impl>(this: *mut core::ffi::c_void, pval: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILocationReportFactory_Impl::Status(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReportInterval<Identity: windows_core::IUnknownImpl + ILocationReportFactory_Impl>(this: *mut core::ffi::c_void, pmilliseconds: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILocationReportFactory_Impl::ReportInterval(this) {
+                    Ok(ok__) => {
+                        pmilliseconds.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetReportInterval<Identity: windows_core::IUnknownImpl + ILocationReportFactory_Impl>(this: *mut core::ffi::c_void, millisecondsrequested: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILocationReportFactory_Impl::SetReportInterval(this, core::mem::transmute_copy(&millisecondsrequested)).into()
+            }
+        }
+        unsafe extern "system" fn DesiredAccuracy<Identity: windows_core::IUnknownImpl + ILocationReportFactory_Impl>(this: *mut core::ffi::c_void, pdesiredaccuracy: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILocationReportFactory_Impl::DesiredAccuracy(this) {
+                    Ok(ok__) => {
+                        pdesiredaccuracy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDesiredAccuracy<Identity: windows_core::IUnknownImpl + ILocationReportFactory_Impl>(this: *mut core::ffi::c_void, desiredaccuracy: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILocationReportFactory_Impl::SetDesiredAccuracy(this, core::mem::transmute_copy(&desiredaccuracy)).into()
+            }
+        }
+        unsafe extern "system" fn RequestPermissions<Identity: windows_core::IUnknownImpl + ILocationReportFactory_Impl>(this: *mut core::ffi::c_void, hwnd: *const u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILocationReportFactory_Impl::RequestPermissions(this, core::mem::transmute_copy(&hwnd)).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ListenForReports: ListenForReports::<Identity>,
+            StopListeningForReports: StopListeningForReports::<Identity>,
+            Status: Status::<Identity>,
+            ReportInterval: ReportInterval::<Identity>,
+            SetReportInterval: SetReportInterval::<Identity>,
+            DesiredAccuracy: DesiredAccuracy::<Identity>,
+            SetDesiredAccuracy: SetDesiredAccuracy::<Identity>,
+            RequestPermissions: RequestPermissions::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ILocationReportFactory {}
 pub const IOCTL_GNSS_CONFIG_SUPL_CERT: u32 = 2228488u32;
 pub const IOCTL_GNSS_CREATE_GEOFENCE: u32 = 2228544u32;
@@ -2360,6 +3095,13 @@ impl _ICivicAddressReportFactoryEvents_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + _ICivicAddressReportFactoryEvents_Impl> windows_core::imp::TearOffVtable<Identity> for _ICivicAddressReportFactoryEvents_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for _ICivicAddressReportFactoryEvents {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(_ILatLongReportFactoryEvents, _ILatLongReportFactoryEvents_Vtbl, 0x16ee6cb7_ab3c_424b_849f_269be551fcbc);
@@ -2390,4 +3132,11 @@ impl _ILatLongReportFactoryEvents_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + _ILatLongReportFactoryEvents_Impl> windows_core::imp::TearOffVtable<Identity> for _ILatLongReportFactoryEvents_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for _ILatLongReportFactoryEvents {}
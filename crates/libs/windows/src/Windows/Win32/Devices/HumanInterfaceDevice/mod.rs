@@ -3867,6 +3867,20 @@ impl IDirectInput2A_Vtbl {
         iid == &<IDirectInput2A as windows_core::Interface>::IID || iid == &<IDirectInputA as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectInput2A_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectInput2A_Vtbl
+where
+    IDirectInputA_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FindDevice<Identity: windows_core::IUnknownImpl + IDirectInput2A_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: windows_core::PCSTR, param2: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInput2A_Impl::FindDevice(this, core::mem::transmute_copy(&param0), core::mem::transmute(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        Self { base__: <IDirectInputA_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, FindDevice: FindDevice::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDirectInput2A {}
 windows_core::imp::define_interface!(IDirectInput2W, IDirectInput2W_Vtbl, 0x5944e663_aa8a_11cf_bfc7_444553540000);
 impl core::ops::Deref for IDirectInput2W {
@@ -3907,6 +3921,20 @@ impl IDirectInput2W_Vtbl {
         iid == &<IDirectInput2W as windows_core::Interface>::IID || iid == &<IDirectInputW as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectInput2W_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectInput2W_Vtbl
+where
+    IDirectInputW_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FindDevice<Identity: windows_core::IUnknownImpl + IDirectInput2W_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: windows_core::PCWSTR, param2: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInput2W_Impl::FindDevice(this, core::mem::transmute_copy(&param0), core::mem::transmute(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        Self { base__: <IDirectInputW_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, FindDevice: FindDevice::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDirectInput2W {}
 windows_core::imp::define_interface!(IDirectInput7A, IDirectInput7A_Vtbl, 0x9a4cb684_236d_11d3_8e9d_00c04f6844ae);
 impl core::ops::Deref for IDirectInput7A {
@@ -3947,6 +3975,20 @@ impl IDirectInput7A_Vtbl {
         iid == &<IDirectInput7A as windows_core::Interface>::IID || iid == &<IDirectInputA as windows_core::Interface>::IID || iid == &<IDirectInput2A as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectInput7A_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectInput7A_Vtbl
+where
+    IDirectInput2A_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateDeviceEx<Identity: windows_core::IUnknownImpl + IDirectInput7A_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *const windows_core::GUID, param2: *mut *mut core::ffi::c_void, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInput7A_Impl::CreateDeviceEx(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        Self { base__: <IDirectInput2A_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateDeviceEx: CreateDeviceEx::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDirectInput7A {}
 windows_core::imp::define_interface!(IDirectInput7W, IDirectInput7W_Vtbl, 0x9a4cb685_236d_11d3_8e9d_00c04f6844ae);
 impl core::ops::Deref for IDirectInput7W {
@@ -3987,6 +4029,20 @@ impl IDirectInput7W_Vtbl {
         iid == &<IDirectInput7W as windows_core::Interface>::IID || iid == &<IDirectInputW as windows_core::Interface>::IID || iid == &<IDirectInput2W as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectInput7W_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectInput7W_Vtbl
+where
+    IDirectInput2W_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateDeviceEx<Identity: windows_core::IUnknownImpl + IDirectInput7W_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *const windows_core::GUID, param2: *mut *mut core::ffi::c_void, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInput7W_Impl::CreateDeviceEx(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        Self { base__: <IDirectInput2W_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateDeviceEx: CreateDeviceEx::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDirectInput7W {}
 windows_core::imp::define_interface!(IDirectInput8A, IDirectInput8A_Vtbl, 0xbf798030_483a_4da2_aa99_5d64ed369700);
 windows_core::imp::interface_hierarchy!(IDirectInput8A, windows_core::IUnknown);
@@ -4114,6 +4170,72 @@ impl IDirectInput8A_Vtbl {
         iid == &<IDirectInput8A as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectInput8A_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectInput8A_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateDevice<Identity: windows_core::IUnknownImpl + IDirectInput8A_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInput8A_Impl::CreateDevice(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn EnumDevices<Identity: windows_core::IUnknownImpl + IDirectInput8A_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: LPDIENUMDEVICESCALLBACKA, param2: *mut core::ffi::c_void, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInput8A_Impl::EnumDevices(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceStatus<Identity: windows_core::IUnknownImpl + IDirectInput8A_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInput8A_Impl::GetDeviceStatus(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn RunControlPanel<Identity: windows_core::IUnknownImpl + IDirectInput8A_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInput8A_Impl::RunControlPanel(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectInput8A_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HINSTANCE, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInput8A_Impl::Initialize(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn FindDevice<Identity: windows_core::IUnknownImpl + IDirectInput8A_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: windows_core::PCSTR, param2: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInput8A_Impl::FindDevice(this, core::mem::transmute_copy(&param0), core::mem::transmute(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn EnumDevicesBySemantics<Identity: windows_core::IUnknownImpl + IDirectInput8A_Impl>(this: *mut core::ffi::c_void, param0: windows_core::PCSTR, param1: *mut DIACTIONFORMATA, param2: LPDIENUMDEVICESBYSEMANTICSCBA, param3: *mut core::ffi::c_void, param4: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInput8A_Impl::EnumDevicesBySemantics(this, core::mem::transmute(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
+            }
+        }
+        unsafe extern "system" fn ConfigureDevices<Identity: windows_core::IUnknownImpl + IDirectInput8A_Impl>(this: *mut core::ffi::c_void, param0: LPDICONFIGUREDEVICESCALLBACK, param1: *mut DICONFIGUREDEVICESPARAMSA, param2: u32, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInput8A_Impl::ConfigureDevices(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateDevice: CreateDevice::<Identity>,
+            EnumDevices: EnumDevices::<Identity>,
+            GetDeviceStatus: GetDeviceStatus::<Identity>,
+            RunControlPanel: RunControlPanel::<Identity>,
+            Initialize: Initialize::<Identity>,
+            FindDevice: FindDevice::<Identity>,
+            EnumDevicesBySemantics: EnumDevicesBySemantics::<Identity>,
+            ConfigureDevices: ConfigureDevices::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectInput8A {}
 windows_core::imp::define_interface!(IDirectInput8W, IDirectInput8W_Vtbl, 0xbf798031_483a_4da2_aa99_5d64ed369700);
 windows_core::imp::interface_hierarchy!(IDirectInput8W, windows_core::IUnknown);
@@ -4241,6 +4363,72 @@ impl IDirectInput8W_Vtbl {
         iid == &<IDirectInput8W as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectInput8W_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectInput8W_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateDevice<Identity: windows_core::IUnknownImpl + IDirectInput8W_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInput8W_Impl::CreateDevice(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn EnumDevices<Identity: windows_core::IUnknownImpl + IDirectInput8W_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: LPDIENUMDEVICESCALLBACKW, param2: *mut core::ffi::c_void, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInput8W_Impl::EnumDevices(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceStatus<Identity: windows_core::IUnknownImpl + IDirectInput8W_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInput8W_Impl::GetDeviceStatus(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn RunControlPanel<Identity: windows_core::IUnknownImpl + IDirectInput8W_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInput8W_Impl::RunControlPanel(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectInput8W_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HINSTANCE, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInput8W_Impl::Initialize(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn FindDevice<Identity: windows_core::IUnknownImpl + IDirectInput8W_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: windows_core::PCWSTR, param2: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInput8W_Impl::FindDevice(this, core::mem::transmute_copy(&param0), core::mem::transmute(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn EnumDevicesBySemantics<Identity: windows_core::IUnknownImpl + IDirectInput8W_Impl>(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR, param1: *mut DIACTIONFORMATW, param2: LPDIENUMDEVICESBYSEMANTICSCBW, param3: *mut core::ffi::c_void, param4: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInput8W_Impl::EnumDevicesBySemantics(this, core::mem::transmute(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
+            }
+        }
+        unsafe extern "system" fn ConfigureDevices<Identity: windows_core::IUnknownImpl + IDirectInput8W_Impl>(this: *mut core::ffi::c_void, param0: LPDICONFIGUREDEVICESCALLBACK, param1: *mut DICONFIGUREDEVICESPARAMSW, param2: u32, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInput8W_Impl::ConfigureDevices(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateDevice: CreateDevice::<Identity>,
+            EnumDevices: EnumDevices::<Identity>,
+            GetDeviceStatus: GetDeviceStatus::<Identity>,
+            RunControlPanel: RunControlPanel::<Identity>,
+            Initialize: Initialize::<Identity>,
+            FindDevice: FindDevice::<Identity>,
+            EnumDevicesBySemantics: EnumDevicesBySemantics::<Identity>,
+            ConfigureDevices: ConfigureDevices::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectInput8W {}
 windows_core::imp::define_interface!(IDirectInputA, IDirectInputA_Vtbl, 0x89521360_aa8a_11cf_bfc7_444553540000);
 windows_core::imp::interface_hierarchy!(IDirectInputA, windows_core::IUnknown);
@@ -4326,6 +4514,51 @@ impl IDirectInputA_Vtbl {
         iid == &<IDirectInputA as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectInputA_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectInputA_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateDevice<Identity: windows_core::IUnknownImpl + IDirectInputA_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputA_Impl::CreateDevice(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn EnumDevices<Identity: windows_core::IUnknownImpl + IDirectInputA_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: LPDIENUMDEVICESCALLBACKA, param2: *mut core::ffi::c_void, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputA_Impl::EnumDevices(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceStatus<Identity: windows_core::IUnknownImpl + IDirectInputA_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputA_Impl::GetDeviceStatus(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn RunControlPanel<Identity: windows_core::IUnknownImpl + IDirectInputA_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputA_Impl::RunControlPanel(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectInputA_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HINSTANCE, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputA_Impl::Initialize(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateDevice: CreateDevice::<Identity>,
+            EnumDevices: EnumDevices::<Identity>,
+            GetDeviceStatus: GetDeviceStatus::<Identity>,
+            RunControlPanel: RunControlPanel::<Identity>,
+            Initialize: Initialize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectInputA {}
 windows_core::imp::define_interface!(IDirectInputDevice2A, IDirectInputDevice2A_Vtbl, 0x5944e682_c92e_11cf_bfc7_444553540000);
 impl core::ops::Deref for IDirectInputDevice2A {
@@ -4465,6 +4698,79 @@ impl IDirectInputDevice2A_Vtbl {
         iid == &<IDirectInputDevice2A as windows_core::Interface>::IID || iid == &<IDirectInputDeviceA as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectInputDevice2A_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectInputDevice2A_Vtbl
+where
+    IDirectInputDeviceA_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateEffect<Identity: windows_core::IUnknownImpl + IDirectInputDevice2A_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIEFFECT, param2: *mut *mut core::ffi::c_void, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice2A_Impl::CreateEffect(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn EnumEffects<Identity: windows_core::IUnknownImpl + IDirectInputDevice2A_Impl>(this: *mut core::ffi::c_void, param0: LPDIENUMEFFECTSCALLBACKA, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice2A_Impl::EnumEffects(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn GetEffectInfo<Identity: windows_core::IUnknownImpl + IDirectInputDevice2A_Impl>(this: *mut core::ffi::c_void, param0: *mut DIEFFECTINFOA, param1: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice2A_Impl::GetEffectInfo(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetForceFeedbackState<Identity: windows_core::IUnknownImpl + IDirectInputDevice2A_Impl>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice2A_Impl::GetForceFeedbackState(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SendForceFeedbackCommand<Identity: windows_core::IUnknownImpl + IDirectInputDevice2A_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice2A_Impl::SendForceFeedbackCommand(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn EnumCreatedEffectObjects<Identity: windows_core::IUnknownImpl + IDirectInputDevice2A_Impl>(this: *mut core::ffi::c_void, param0: LPDIENUMCREATEDEFFECTOBJECTSCALLBACK, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice2A_Impl::EnumCreatedEffectObjects(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn Escape<Identity: windows_core::IUnknownImpl + IDirectInputDevice2A_Impl>(this: *mut core::ffi::c_void, param0: *mut DIEFFESCAPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice2A_Impl::Escape(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Poll<Identity: windows_core::IUnknownImpl + IDirectInputDevice2A_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice2A_Impl::Poll(this).into()
+            }
+        }
+        unsafe extern "system" fn SendDeviceData<Identity: windows_core::IUnknownImpl + IDirectInputDevice2A_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIDEVICEOBJECTDATA, param2: *mut u32, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice2A_Impl::SendDeviceData(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        Self {
+            base__: <IDirectInputDeviceA_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateEffect: CreateEffect::<Identity>,
+            EnumEffects: EnumEffects::<Identity>,
+            GetEffectInfo: GetEffectInfo::<Identity>,
+            GetForceFeedbackState: GetForceFeedbackState::<Identity>,
+            SendForceFeedbackCommand: SendForceFeedbackCommand::<Identity>,
+            EnumCreatedEffectObjects: EnumCreatedEffectObjects::<Identity>,
+            Escape: Escape::<Identity>,
+            Poll: Poll::<Identity>,
+            SendDeviceData: SendDeviceData::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectInputDevice2A {}
 windows_core::imp::define_interface!(IDirectInputDevice2W, IDirectInputDevice2W_Vtbl, 0x5944e683_c92e_11cf_bfc7_444553540000);
 impl core::ops::Deref for IDirectInputDevice2W {
@@ -4604,6 +4910,79 @@ impl IDirectInputDevice2W_Vtbl {
         iid == &<IDirectInputDevice2W as windows_core::Interface>::IID || iid == &<IDirectInputDeviceW as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectInputDevice2W_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectInputDevice2W_Vtbl
+where
+    IDirectInputDeviceW_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateEffect<Identity: windows_core::IUnknownImpl + IDirectInputDevice2W_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIEFFECT, param2: *mut *mut core::ffi::c_void, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice2W_Impl::CreateEffect(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn EnumEffects<Identity: windows_core::IUnknownImpl + IDirectInputDevice2W_Impl>(this: *mut core::ffi::c_void, param0: LPDIENUMEFFECTSCALLBACKW, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice2W_Impl::EnumEffects(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn GetEffectInfo<Identity: windows_core::IUnknownImpl + IDirectInputDevice2W_Impl>(this: *mut core::ffi::c_void, param0: *mut DIEFFECTINFOW, param1: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice2W_Impl::GetEffectInfo(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetForceFeedbackState<Identity: windows_core::IUnknownImpl + IDirectInputDevice2W_Impl>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice2W_Impl::GetForceFeedbackState(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SendForceFeedbackCommand<Identity: windows_core::IUnknownImpl + IDirectInputDevice2W_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice2W_Impl::SendForceFeedbackCommand(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn EnumCreatedEffectObjects<Identity: windows_core::IUnknownImpl + IDirectInputDevice2W_Impl>(this: *mut core::ffi::c_void, param0: LPDIENUMCREATEDEFFECTOBJECTSCALLBACK, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice2W_Impl::EnumCreatedEffectObjects(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn Escape<Identity: windows_core::IUnknownImpl + IDirectInputDevice2W_Impl>(this: *mut core::ffi::c_void, param0: *mut DIEFFESCAPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice2W_Impl::Escape(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Poll<Identity: windows_core::IUnknownImpl + IDirectInputDevice2W_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice2W_Impl::Poll(this).into()
+            }
+        }
+        unsafe extern "system" fn SendDeviceData<Identity: windows_core::IUnknownImpl + IDirectInputDevice2W_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIDEVICEOBJECTDATA, param2: *mut u32, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice2W_Impl::SendDeviceData(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        Self {
+            base__: <IDirectInputDeviceW_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateEffect: CreateEffect::<Identity>,
+            EnumEffects: EnumEffects::<Identity>,
+            GetEffectInfo: GetEffectInfo::<Identity>,
+            GetForceFeedbackState: GetForceFeedbackState::<Identity>,
+            SendForceFeedbackCommand: SendForceFeedbackCommand::<Identity>,
+            EnumCreatedEffectObjects: EnumCreatedEffectObjects::<Identity>,
+            Escape: Escape::<Identity>,
+            Poll: Poll::<Identity>,
+            SendDeviceData: SendDeviceData::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectInputDevice2W {}
 windows_core::imp::define_interface!(IDirectInputDevice7A, IDirectInputDevice7A_Vtbl, 0x57d7c6bc_2356_11d3_8e9d_00c04f6844ae);
 impl core::ops::Deref for IDirectInputDevice7A {
@@ -4662,6 +5041,30 @@ impl IDirectInputDevice7A_Vtbl {
         iid == &<IDirectInputDevice7A as windows_core::Interface>::IID || iid == &<IDirectInputDeviceA as windows_core::Interface>::IID || iid == &<IDirectInputDevice2A as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectInputDevice7A_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectInputDevice7A_Vtbl
+where
+    IDirectInputDevice2A_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumEffectsInFile<Identity: windows_core::IUnknownImpl + IDirectInputDevice7A_Impl>(this: *mut core::ffi::c_void, param0: windows_core::PCSTR, param1: LPDIENUMEFFECTSINFILECALLBACK, param2: *mut core::ffi::c_void, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice7A_Impl::EnumEffectsInFile(this, core::mem::transmute(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn WriteEffectToFile<Identity: windows_core::IUnknownImpl + IDirectInputDevice7A_Impl>(this: *mut core::ffi::c_void, param0: windows_core::PCSTR, param1: u32, param2: *mut DIFILEEFFECT, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice7A_Impl::WriteEffectToFile(this, core::mem::transmute(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        Self {
+            base__: <IDirectInputDevice2A_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnumEffectsInFile: EnumEffectsInFile::<Identity>,
+            WriteEffectToFile: WriteEffectToFile::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectInputDevice7A {}
 windows_core::imp::define_interface!(IDirectInputDevice7W, IDirectInputDevice7W_Vtbl, 0x57d7c6bd_2356_11d3_8e9d_00c04f6844ae);
 impl core::ops::Deref for IDirectInputDevice7W {
@@ -4720,6 +5123,30 @@ impl IDirectInputDevice7W_Vtbl {
         iid == &<IDirectInputDevice7W as windows_core::Interface>::IID || iid == &<IDirectInputDeviceW as windows_core::Interface>::IID || iid == &<IDirectInputDevice2W as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectInputDevice7W_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectInputDevice7W_Vtbl
+where
+    IDirectInputDevice2W_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumEffectsInFile<Identity: windows_core::IUnknownImpl + IDirectInputDevice7W_Impl>(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR, param1: LPDIENUMEFFECTSINFILECALLBACK, param2: *mut core::ffi::c_void, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice7W_Impl::EnumEffectsInFile(this, core::mem::transmute(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn WriteEffectToFile<Identity: windows_core::IUnknownImpl + IDirectInputDevice7W_Impl>(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR, param1: u32, param2: *mut DIFILEEFFECT, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice7W_Impl::WriteEffectToFile(this, core::mem::transmute(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        Self {
+            base__: <IDirectInputDevice2W_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnumEffectsInFile: EnumEffectsInFile::<Identity>,
+            WriteEffectToFile: WriteEffectToFile::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectInputDevice7W {}
 windows_core::imp::define_interface!(IDirectInputDevice8A, IDirectInputDevice8A_Vtbl, 0x54d41080_dc15_4833_a41b_748f73a38179);
 windows_core::imp::interface_hierarchy!(IDirectInputDevice8A, windows_core::IUnknown);
@@ -5105,31 +5532,244 @@ impl IDirectInputDevice8A_Vtbl {
         iid == &<IDirectInputDevice8A as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IDirectInputDevice8A {}
-windows_core::imp::define_interface!(IDirectInputDevice8W, IDirectInputDevice8W_Vtbl, 0x54d41081_dc15_4833_a41b_748f73a38179);
-windows_core::imp::interface_hierarchy!(IDirectInputDevice8W, windows_core::IUnknown);
-impl IDirectInputDevice8W {
-    pub unsafe fn GetCapabilities(&self, param0: *mut DIDEVCAPS) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetCapabilities)(windows_core::Interface::as_raw(self), param0 as _).ok() }
-    }
-    pub unsafe fn EnumObjects(&self, param0: LPDIENUMDEVICEOBJECTSCALLBACKW, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).EnumObjects)(windows_core::Interface::as_raw(self), param0, param1 as _, param2).ok() }
-    }
-    pub unsafe fn GetProperty(&self, param0: *const windows_core::GUID, param1: *mut DIPROPHEADER) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetProperty)(windows_core::Interface::as_raw(self), param0, param1 as _).ok() }
-    }
-    pub unsafe fn SetProperty(&self, param0: *const windows_core::GUID, param1: *mut DIPROPHEADER) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetProperty)(windows_core::Interface::as_raw(self), param0, param1 as _).ok() }
-    }
-    pub unsafe fn Acquire(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Acquire)(windows_core::Interface::as_raw(self)).ok() }
-    }
-    pub unsafe fn Unacquire(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Unacquire)(windows_core::Interface::as_raw(self)).ok() }
-    }
-    pub unsafe fn GetDeviceState(&self, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetDeviceState)(windows_core::Interface::as_raw(self), param0, param1 as _).ok() }
-    }
+impl<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectInputDevice8A_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCapabilities<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: *mut DIDEVCAPS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::GetCapabilities(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn EnumObjects<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: LPDIENUMDEVICEOBJECTSCALLBACKA, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::EnumObjects(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn GetProperty<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIPROPHEADER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::GetProperty(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetProperty<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIPROPHEADER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::SetProperty(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn Acquire<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::Acquire(this).into()
+            }
+        }
+        unsafe extern "system" fn Unacquire<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::Unacquire(this).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceState<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::GetDeviceState(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceData<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIDEVICEOBJECTDATA, param2: *mut u32, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::GetDeviceData(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn SetDataFormat<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: *mut DIDATAFORMAT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::SetDataFormat(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SetEventNotification<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::SetEventNotification(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SetCooperativeLevel<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::SetCooperativeLevel(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetObjectInfo<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: *mut DIDEVICEOBJECTINSTANCEA, param1: u32, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::GetObjectInfo(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceInfo<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: *mut DIDEVICEINSTANCEA) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::GetDeviceInfo(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn RunControlPanel<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::RunControlPanel(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HINSTANCE, param1: u32, param2: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::Initialize(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn CreateEffect<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIEFFECT, param2: *mut *mut core::ffi::c_void, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::CreateEffect(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn EnumEffects<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: LPDIENUMEFFECTSCALLBACKA, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::EnumEffects(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn GetEffectInfo<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: *mut DIEFFECTINFOA, param1: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::GetEffectInfo(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetForceFeedbackState<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::GetForceFeedbackState(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SendForceFeedbackCommand<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::SendForceFeedbackCommand(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn EnumCreatedEffectObjects<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: LPDIENUMCREATEDEFFECTOBJECTSCALLBACK, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::EnumCreatedEffectObjects(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn Escape<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: *mut DIEFFESCAPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::Escape(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Poll<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::Poll(this).into()
+            }
+        }
+        unsafe extern "system" fn SendDeviceData<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIDEVICEOBJECTDATA, param2: *mut u32, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::SendDeviceData(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn EnumEffectsInFile<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: windows_core::PCSTR, param1: LPDIENUMEFFECTSINFILECALLBACK, param2: *mut core::ffi::c_void, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::EnumEffectsInFile(this, core::mem::transmute(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn WriteEffectToFile<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: windows_core::PCSTR, param1: u32, param2: *mut DIFILEEFFECT, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::WriteEffectToFile(this, core::mem::transmute(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn BuildActionMap<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: *mut DIACTIONFORMATA, param1: windows_core::PCSTR, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::BuildActionMap(this, core::mem::transmute_copy(&param0), core::mem::transmute(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn SetActionMap<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: *mut DIACTIONFORMATA, param1: windows_core::PCSTR, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::SetActionMap(this, core::mem::transmute_copy(&param0), core::mem::transmute(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn GetImageInfo<Identity: windows_core::IUnknownImpl + IDirectInputDevice8A_Impl>(this: *mut core::ffi::c_void, param0: *mut DIDEVICEIMAGEINFOHEADERA) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8A_Impl::GetImageInfo(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCapabilities: GetCapabilities::<Identity>,
+            EnumObjects: EnumObjects::<Identity>,
+            GetProperty: GetProperty::<Identity>,
+            SetProperty: SetProperty::<Identity>,
+            Acquire: Acquire::<Identity>,
+            Unacquire: Unacquire::<Identity>,
+            GetDeviceState: GetDeviceState::<Identity>,
+            GetDeviceData: GetDeviceData::<Identity>,
+            SetDataFormat: SetDataFormat::<Identity>,
+            SetEventNotification: SetEventNotification::<Identity>,
+            SetCooperativeLevel: SetCooperativeLevel::<Identity>,
+            GetObjectInfo: GetObjectInfo::<Identity>,
+            GetDeviceInfo: GetDeviceInfo::<Identity>,
+            RunControlPanel: RunControlPanel::<Identity>,
+            Initialize: Initialize::<Identity>,
+            CreateEffect: CreateEffect::<Identity>,
+            EnumEffects: EnumEffects::<Identity>,
+            GetEffectInfo: GetEffectInfo::<Identity>,
+            GetForceFeedbackState: GetForceFeedbackState::<Identity>,
+            SendForceFeedbackCommand: SendForceFeedbackCommand::<Identity>,
+            EnumCreatedEffectObjects: EnumCreatedEffectObjects::<Identity>,
+            Escape: Escape::<Identity>,
+            Poll: Poll::<Identity>,
+            SendDeviceData: SendDeviceData::<Identity>,
+            EnumEffectsInFile: EnumEffectsInFile::<Identity>,
+            WriteEffectToFile: WriteEffectToFile::<Identity>,
+            BuildActionMap: BuildActionMap::<Identity>,
+            SetActionMap: SetActionMap::<Identity>,
+            GetImageInfo: GetImageInfo::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IDirectInputDevice8A {}
+windows_core::imp::define_interface!(IDirectInputDevice8W, IDirectInputDevice8W_Vtbl, 0x54d41081_dc15_4833_a41b_748f73a38179);
+windows_core::imp::interface_hierarchy!(IDirectInputDevice8W, windows_core::IUnknown);
+impl IDirectInputDevice8W {
+    pub unsafe fn GetCapabilities(&self, param0: *mut DIDEVCAPS) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetCapabilities)(windows_core::Interface::as_raw(self), param0 as _).ok() }
+    }
+    pub unsafe fn EnumObjects(&self, param0: LPDIENUMDEVICEOBJECTSCALLBACKW, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).EnumObjects)(windows_core::Interface::as_raw(self), param0, param1 as _, param2).ok() }
+    }
+    pub unsafe fn GetProperty(&self, param0: *const windows_core::GUID, param1: *mut DIPROPHEADER) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetProperty)(windows_core::Interface::as_raw(self), param0, param1 as _).ok() }
+    }
+    pub unsafe fn SetProperty(&self, param0: *const windows_core::GUID, param1: *mut DIPROPHEADER) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetProperty)(windows_core::Interface::as_raw(self), param0, param1 as _).ok() }
+    }
+    pub unsafe fn Acquire(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Acquire)(windows_core::Interface::as_raw(self)).ok() }
+    }
+    pub unsafe fn Unacquire(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Unacquire)(windows_core::Interface::as_raw(self)).ok() }
+    }
+    pub unsafe fn GetDeviceState(&self, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetDeviceState)(windows_core::Interface::as_raw(self), param0, param1 as _).ok() }
+    }
     pub unsafe fn GetDeviceData(&self, param0: u32, param1: *mut DIDEVICEOBJECTDATA, param2: *mut u32, param3: u32) -> windows_core::Result<()> {
         unsafe { (windows_core::Interface::vtable(self).GetDeviceData)(windows_core::Interface::as_raw(self), param0, param1 as _, param2 as _, param3).ok() }
     }
@@ -5490,6 +6130,219 @@ impl IDirectInputDevice8W_Vtbl {
         iid == &<IDirectInputDevice8W as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectInputDevice8W_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCapabilities<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: *mut DIDEVCAPS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::GetCapabilities(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn EnumObjects<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: LPDIENUMDEVICEOBJECTSCALLBACKW, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::EnumObjects(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn GetProperty<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIPROPHEADER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::GetProperty(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetProperty<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIPROPHEADER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::SetProperty(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn Acquire<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::Acquire(this).into()
+            }
+        }
+        unsafe extern "system" fn Unacquire<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::Unacquire(this).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceState<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::GetDeviceState(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceData<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIDEVICEOBJECTDATA, param2: *mut u32, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::GetDeviceData(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn SetDataFormat<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: *mut DIDATAFORMAT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::SetDataFormat(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SetEventNotification<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::SetEventNotification(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SetCooperativeLevel<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::SetCooperativeLevel(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetObjectInfo<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: *mut DIDEVICEOBJECTINSTANCEW, param1: u32, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::GetObjectInfo(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceInfo<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: *mut DIDEVICEINSTANCEW) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::GetDeviceInfo(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn RunControlPanel<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::RunControlPanel(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HINSTANCE, param1: u32, param2: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::Initialize(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn CreateEffect<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIEFFECT, param2: *mut *mut core::ffi::c_void, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::CreateEffect(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn EnumEffects<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: LPDIENUMEFFECTSCALLBACKW, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::EnumEffects(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn GetEffectInfo<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: *mut DIEFFECTINFOW, param1: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::GetEffectInfo(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetForceFeedbackState<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::GetForceFeedbackState(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SendForceFeedbackCommand<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::SendForceFeedbackCommand(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn EnumCreatedEffectObjects<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: LPDIENUMCREATEDEFFECTOBJECTSCALLBACK, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::EnumCreatedEffectObjects(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn Escape<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: *mut DIEFFESCAPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::Escape(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Poll<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::Poll(this).into()
+            }
+        }
+        unsafe extern "system" fn SendDeviceData<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIDEVICEOBJECTDATA, param2: *mut u32, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::SendDeviceData(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn EnumEffectsInFile<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR, param1: LPDIENUMEFFECTSINFILECALLBACK, param2: *mut core::ffi::c_void, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::EnumEffectsInFile(this, core::mem::transmute(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn WriteEffectToFile<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR, param1: u32, param2: *mut DIFILEEFFECT, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::WriteEffectToFile(this, core::mem::transmute(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn BuildActionMap<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: *mut DIACTIONFORMATW, param1: windows_core::PCWSTR, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::BuildActionMap(this, core::mem::transmute_copy(&param0), core::mem::transmute(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn SetActionMap<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: *mut DIACTIONFORMATW, param1: windows_core::PCWSTR, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::SetActionMap(this, core::mem::transmute_copy(&param0), core::mem::transmute(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn GetImageInfo<Identity: windows_core::IUnknownImpl + IDirectInputDevice8W_Impl>(this: *mut core::ffi::c_void, param0: *mut DIDEVICEIMAGEINFOHEADERW) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDevice8W_Impl::GetImageInfo(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCapabilities: GetCapabilities::<Identity>,
+            EnumObjects: EnumObjects::<Identity>,
+            GetProperty: GetProperty::<Identity>,
+            SetProperty: SetProperty::<Identity>,
+            Acquire: Acquire::<Identity>,
+            Unacquire: Unacquire::<Identity>,
+            GetDeviceState: GetDeviceState::<Identity>,
+            GetDeviceData: GetDeviceData::<Identity>,
+            SetDataFormat: SetDataFormat::<Identity>,
+            SetEventNotification: SetEventNotification::<Identity>,
+            SetCooperativeLevel: SetCooperativeLevel::<Identity>,
+            GetObjectInfo: GetObjectInfo::<Identity>,
+            GetDeviceInfo: GetDeviceInfo::<Identity>,
+            RunControlPanel: RunControlPanel::<Identity>,
+            Initialize: Initialize::<Identity>,
+            CreateEffect: CreateEffect::<Identity>,
+            EnumEffects: EnumEffects::<Identity>,
+            GetEffectInfo: GetEffectInfo::<Identity>,
+            GetForceFeedbackState: GetForceFeedbackState::<Identity>,
+            SendForceFeedbackCommand: SendForceFeedbackCommand::<Identity>,
+            EnumCreatedEffectObjects: EnumCreatedEffectObjects::<Identity>,
+            Escape: Escape::<Identity>,
+            Poll: Poll::<Identity>,
+            SendDeviceData: SendDeviceData::<Identity>,
+            EnumEffectsInFile: EnumEffectsInFile::<Identity>,
+            WriteEffectToFile: WriteEffectToFile::<Identity>,
+            BuildActionMap: BuildActionMap::<Identity>,
+            SetActionMap: SetActionMap::<Identity>,
+            GetImageInfo: GetImageInfo::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectInputDevice8W {}
 windows_core::imp::define_interface!(IDirectInputDeviceA, IDirectInputDeviceA_Vtbl, 0x5944e680_c92e_11cf_bfc7_444553540000);
 windows_core::imp::interface_hierarchy!(IDirectInputDeviceA, windows_core::IUnknown);
@@ -5692,6 +6545,121 @@ impl IDirectInputDeviceA_Vtbl {
         iid == &<IDirectInputDeviceA as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectInputDeviceA_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectInputDeviceA_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCapabilities<Identity: windows_core::IUnknownImpl + IDirectInputDeviceA_Impl>(this: *mut core::ffi::c_void, param0: *mut DIDEVCAPS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceA_Impl::GetCapabilities(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn EnumObjects<Identity: windows_core::IUnknownImpl + IDirectInputDeviceA_Impl>(this: *mut core::ffi::c_void, param0: LPDIENUMDEVICEOBJECTSCALLBACKA, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceA_Impl::EnumObjects(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn GetProperty<Identity: windows_core::IUnknownImpl + IDirectInputDeviceA_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIPROPHEADER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceA_Impl::GetProperty(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetProperty<Identity: windows_core::IUnknownImpl + IDirectInputDeviceA_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIPROPHEADER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceA_Impl::SetProperty(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn Acquire<Identity: windows_core::IUnknownImpl + IDirectInputDeviceA_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceA_Impl::Acquire(this).into()
+            }
+        }
+        unsafe extern "system" fn Unacquire<Identity: windows_core::IUnknownImpl + IDirectInputDeviceA_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceA_Impl::Unacquire(this).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceState<Identity: windows_core::IUnknownImpl + IDirectInputDeviceA_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceA_Impl::GetDeviceState(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceData<Identity: windows_core::IUnknownImpl + IDirectInputDeviceA_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIDEVICEOBJECTDATA, param2: *mut u32, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceA_Impl::GetDeviceData(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn SetDataFormat<Identity: windows_core::IUnknownImpl + IDirectInputDeviceA_Impl>(this: *mut core::ffi::c_void, param0: *mut DIDATAFORMAT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceA_Impl::SetDataFormat(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SetEventNotification<Identity: windows_core::IUnknownImpl + IDirectInputDeviceA_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceA_Impl::SetEventNotification(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SetCooperativeLevel<Identity: windows_core::IUnknownImpl + IDirectInputDeviceA_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceA_Impl::SetCooperativeLevel(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetObjectInfo<Identity: windows_core::IUnknownImpl + IDirectInputDeviceA_Impl>(this: *mut core::ffi::c_void, param0: *mut DIDEVICEOBJECTINSTANCEA, param1: u32, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceA_Impl::GetObjectInfo(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceInfo<Identity: windows_core::IUnknownImpl + IDirectInputDeviceA_Impl>(this: *mut core::ffi::c_void, param0: *mut DIDEVICEINSTANCEA) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceA_Impl::GetDeviceInfo(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn RunControlPanel<Identity: windows_core::IUnknownImpl + IDirectInputDeviceA_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceA_Impl::RunControlPanel(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectInputDeviceA_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HINSTANCE, param1: u32, param2: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceA_Impl::Initialize(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCapabilities: GetCapabilities::<Identity>,
+            EnumObjects: EnumObjects::<Identity>,
+            GetProperty: GetProperty::<Identity>,
+            SetProperty: SetProperty::<Identity>,
+            Acquire: Acquire::<Identity>,
+            Unacquire: Unacquire::<Identity>,
+            GetDeviceState: GetDeviceState::<Identity>,
+            GetDeviceData: GetDeviceData::<Identity>,
+            SetDataFormat: SetDataFormat::<Identity>,
+            SetEventNotification: SetEventNotification::<Identity>,
+            SetCooperativeLevel: SetCooperativeLevel::<Identity>,
+            GetObjectInfo: GetObjectInfo::<Identity>,
+            GetDeviceInfo: GetDeviceInfo::<Identity>,
+            RunControlPanel: RunControlPanel::<Identity>,
+            Initialize: Initialize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectInputDeviceA {}
 windows_core::imp::define_interface!(IDirectInputDeviceW, IDirectInputDeviceW_Vtbl, 0x5944e681_c92e_11cf_bfc7_444553540000);
 windows_core::imp::interface_hierarchy!(IDirectInputDeviceW, windows_core::IUnknown);
@@ -5894,6 +6862,121 @@ impl IDirectInputDeviceW_Vtbl {
         iid == &<IDirectInputDeviceW as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectInputDeviceW_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectInputDeviceW_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCapabilities<Identity: windows_core::IUnknownImpl + IDirectInputDeviceW_Impl>(this: *mut core::ffi::c_void, param0: *mut DIDEVCAPS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceW_Impl::GetCapabilities(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn EnumObjects<Identity: windows_core::IUnknownImpl + IDirectInputDeviceW_Impl>(this: *mut core::ffi::c_void, param0: LPDIENUMDEVICEOBJECTSCALLBACKW, param1: *mut core::ffi::c_void, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceW_Impl::EnumObjects(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn GetProperty<Identity: windows_core::IUnknownImpl + IDirectInputDeviceW_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIPROPHEADER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceW_Impl::GetProperty(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetProperty<Identity: windows_core::IUnknownImpl + IDirectInputDeviceW_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut DIPROPHEADER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceW_Impl::SetProperty(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn Acquire<Identity: windows_core::IUnknownImpl + IDirectInputDeviceW_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceW_Impl::Acquire(this).into()
+            }
+        }
+        unsafe extern "system" fn Unacquire<Identity: windows_core::IUnknownImpl + IDirectInputDeviceW_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceW_Impl::Unacquire(this).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceState<Identity: windows_core::IUnknownImpl + IDirectInputDeviceW_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceW_Impl::GetDeviceState(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceData<Identity: windows_core::IUnknownImpl + IDirectInputDeviceW_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIDEVICEOBJECTDATA, param2: *mut u32, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceW_Impl::GetDeviceData(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn SetDataFormat<Identity: windows_core::IUnknownImpl + IDirectInputDeviceW_Impl>(this: *mut core::ffi::c_void, param0: *mut DIDATAFORMAT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceW_Impl::SetDataFormat(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SetEventNotification<Identity: windows_core::IUnknownImpl + IDirectInputDeviceW_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceW_Impl::SetEventNotification(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SetCooperativeLevel<Identity: windows_core::IUnknownImpl + IDirectInputDeviceW_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceW_Impl::SetCooperativeLevel(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetObjectInfo<Identity: windows_core::IUnknownImpl + IDirectInputDeviceW_Impl>(this: *mut core::ffi::c_void, param0: *mut DIDEVICEOBJECTINSTANCEW, param1: u32, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceW_Impl::GetObjectInfo(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceInfo<Identity: windows_core::IUnknownImpl + IDirectInputDeviceW_Impl>(this: *mut core::ffi::c_void, param0: *mut DIDEVICEINSTANCEW) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceW_Impl::GetDeviceInfo(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn RunControlPanel<Identity: windows_core::IUnknownImpl + IDirectInputDeviceW_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceW_Impl::RunControlPanel(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectInputDeviceW_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HINSTANCE, param1: u32, param2: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputDeviceW_Impl::Initialize(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCapabilities: GetCapabilities::<Identity>,
+            EnumObjects: EnumObjects::<Identity>,
+            GetProperty: GetProperty::<Identity>,
+            SetProperty: SetProperty::<Identity>,
+            Acquire: Acquire::<Identity>,
+            Unacquire: Unacquire::<Identity>,
+            GetDeviceState: GetDeviceState::<Identity>,
+            GetDeviceData: GetDeviceData::<Identity>,
+            SetDataFormat: SetDataFormat::<Identity>,
+            SetEventNotification: SetEventNotification::<Identity>,
+            SetCooperativeLevel: SetCooperativeLevel::<Identity>,
+            GetObjectInfo: GetObjectInfo::<Identity>,
+            GetDeviceInfo: GetDeviceInfo::<Identity>,
+            RunControlPanel: RunControlPanel::<Identity>,
+            Initialize: Initialize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectInputDeviceW {}
 windows_core::imp::define_interface!(IDirectInputEffect, IDirectInputEffect_Vtbl, 0xe7e1f7c0_88d2_11d0_9ad0_00a0c9a06e35);
 windows_core::imp::interface_hierarchy!(IDirectInputEffect, windows_core::IUnknown);
@@ -6036,6 +7119,86 @@ impl IDirectInputEffect_Vtbl {
         iid == &<IDirectInputEffect as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectInputEffect_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectInputEffect_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectInputEffect_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HINSTANCE, param1: u32, param2: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputEffect_Impl::Initialize(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn GetEffectGuid<Identity: windows_core::IUnknownImpl + IDirectInputEffect_Impl>(this: *mut core::ffi::c_void, param0: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputEffect_Impl::GetEffectGuid(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetParameters<Identity: windows_core::IUnknownImpl + IDirectInputEffect_Impl>(this: *mut core::ffi::c_void, param0: *mut DIEFFECT, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputEffect_Impl::GetParameters(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetParameters<Identity: windows_core::IUnknownImpl + IDirectInputEffect_Impl>(this: *mut core::ffi::c_void, param0: *mut DIEFFECT, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputEffect_Impl::SetParameters(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn Start<Identity: windows_core::IUnknownImpl + IDirectInputEffect_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputEffect_Impl::Start(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + IDirectInputEffect_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputEffect_Impl::Stop(this).into()
+            }
+        }
+        unsafe extern "system" fn GetEffectStatus<Identity: windows_core::IUnknownImpl + IDirectInputEffect_Impl>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputEffect_Impl::GetEffectStatus(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Download<Identity: windows_core::IUnknownImpl + IDirectInputEffect_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputEffect_Impl::Download(this).into()
+            }
+        }
+        unsafe extern "system" fn Unload<Identity: windows_core::IUnknownImpl + IDirectInputEffect_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputEffect_Impl::Unload(this).into()
+            }
+        }
+        unsafe extern "system" fn Escape<Identity: windows_core::IUnknownImpl + IDirectInputEffect_Impl>(this: *mut core::ffi::c_void, param0: *mut DIEFFESCAPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputEffect_Impl::Escape(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            GetEffectGuid: GetEffectGuid::<Identity>,
+            GetParameters: GetParameters::<Identity>,
+            SetParameters: SetParameters::<Identity>,
+            Start: Start::<Identity>,
+            Stop: Stop::<Identity>,
+            GetEffectStatus: GetEffectStatus::<Identity>,
+            Download: Download::<Identity>,
+            Unload: Unload::<Identity>,
+            Escape: Escape::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectInputEffect {}
 windows_core::imp::define_interface!(IDirectInputEffectDriver, IDirectInputEffectDriver_Vtbl, 0x02538130_898f_11d0_9ad0_00a0c9a06e35);
 windows_core::imp::interface_hierarchy!(IDirectInputEffectDriver, windows_core::IUnknown);
@@ -6190,6 +7353,93 @@ impl IDirectInputEffectDriver_Vtbl {
         iid == &<IDirectInputEffectDriver as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectInputEffectDriver_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectInputEffectDriver_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DeviceID<Identity: windows_core::IUnknownImpl + IDirectInputEffectDriver_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: u32, param3: u32, param4: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputEffectDriver_Impl::DeviceID(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
+            }
+        }
+        unsafe extern "system" fn GetVersions<Identity: windows_core::IUnknownImpl + IDirectInputEffectDriver_Impl>(this: *mut core::ffi::c_void, param0: *mut DIDRIVERVERSIONS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputEffectDriver_Impl::GetVersions(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Escape<Identity: windows_core::IUnknownImpl + IDirectInputEffectDriver_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: *mut DIEFFESCAPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputEffectDriver_Impl::Escape(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn SetGain<Identity: windows_core::IUnknownImpl + IDirectInputEffectDriver_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputEffectDriver_Impl::SetGain(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SendForceFeedbackCommand<Identity: windows_core::IUnknownImpl + IDirectInputEffectDriver_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputEffectDriver_Impl::SendForceFeedbackCommand(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetForceFeedbackState<Identity: windows_core::IUnknownImpl + IDirectInputEffectDriver_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIDEVICESTATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputEffectDriver_Impl::GetForceFeedbackState(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn DownloadEffect<Identity: windows_core::IUnknownImpl + IDirectInputEffectDriver_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: *mut u32, param3: *mut DIEFFECT, param4: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputEffectDriver_Impl::DownloadEffect(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
+            }
+        }
+        unsafe extern "system" fn DestroyEffect<Identity: windows_core::IUnknownImpl + IDirectInputEffectDriver_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputEffectDriver_Impl::DestroyEffect(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn StartEffect<Identity: windows_core::IUnknownImpl + IDirectInputEffectDriver_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: u32, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputEffectDriver_Impl::StartEffect(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn StopEffect<Identity: windows_core::IUnknownImpl + IDirectInputEffectDriver_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputEffectDriver_Impl::StopEffect(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetEffectStatus<Identity: windows_core::IUnknownImpl + IDirectInputEffectDriver_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputEffectDriver_Impl::GetEffectStatus(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DeviceID: DeviceID::<Identity>,
+            GetVersions: GetVersions::<Identity>,
+            Escape: Escape::<Identity>,
+            SetGain: SetGain::<Identity>,
+            SendForceFeedbackCommand: SendForceFeedbackCommand::<Identity>,
+            GetForceFeedbackState: GetForceFeedbackState::<Identity>,
+            DownloadEffect: DownloadEffect::<Identity>,
+            DestroyEffect: DestroyEffect::<Identity>,
+            StartEffect: StartEffect::<Identity>,
+            StopEffect: StopEffect::<Identity>,
+            GetEffectStatus: GetEffectStatus::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectInputEffectDriver {}
 windows_core::imp::define_interface!(IDirectInputJoyConfig, IDirectInputJoyConfig_Vtbl, 0x1de12ab1_c9f5_11cf_bfc7_444553540000);
 windows_core::imp::interface_hierarchy!(IDirectInputJoyConfig, windows_core::IUnknown);
@@ -6427,6 +7677,129 @@ impl IDirectInputJoyConfig_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Registry")]
+impl<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectInputJoyConfig_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Acquire<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig_Impl::Acquire(this).into()
+            }
+        }
+        unsafe extern "system" fn Unacquire<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig_Impl::Unacquire(this).into()
+            }
+        }
+        unsafe extern "system" fn SetCooperativeLevel<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig_Impl::SetCooperativeLevel(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SendNotify<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig_Impl::SendNotify(this).into()
+            }
+        }
+        unsafe extern "system" fn EnumTypes<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig_Impl>(this: *mut core::ffi::c_void, param0: LPDIJOYTYPECALLBACK, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig_Impl::EnumTypes(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetTypeInfo<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig_Impl>(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR, param1: *mut DIJOYTYPEINFO, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig_Impl::GetTypeInfo(this, core::mem::transmute(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn SetTypeInfo<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig_Impl>(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR, param1: *mut DIJOYTYPEINFO, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig_Impl::SetTypeInfo(this, core::mem::transmute(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteType<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig_Impl>(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig_Impl::DeleteType(this, core::mem::transmute(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetConfig<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIJOYCONFIG, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig_Impl::GetConfig(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn SetConfig<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIJOYCONFIG, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig_Impl::SetConfig(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteConfig<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig_Impl::DeleteConfig(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetUserValues<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig_Impl>(this: *mut core::ffi::c_void, param0: *mut DIJOYUSERVALUES, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig_Impl::GetUserValues(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetUserValues<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig_Impl>(this: *mut core::ffi::c_void, param0: *mut DIJOYUSERVALUES, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig_Impl::SetUserValues(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn AddNewHardware<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig_Impl::AddNewHardware(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn OpenTypeKey<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig_Impl>(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR, param1: u32, param2: *mut super::super::System::Registry::HKEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig_Impl::OpenTypeKey(this, core::mem::transmute(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn OpenConfigKey<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: *mut super::super::System::Registry::HKEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig_Impl::OpenConfigKey(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Acquire: Acquire::<Identity>,
+            Unacquire: Unacquire::<Identity>,
+            SetCooperativeLevel: SetCooperativeLevel::<Identity>,
+            SendNotify: SendNotify::<Identity>,
+            EnumTypes: EnumTypes::<Identity>,
+            GetTypeInfo: GetTypeInfo::<Identity>,
+            SetTypeInfo: SetTypeInfo::<Identity>,
+            DeleteType: DeleteType::<Identity>,
+            GetConfig: GetConfig::<Identity>,
+            SetConfig: SetConfig::<Identity>,
+            DeleteConfig: DeleteConfig::<Identity>,
+            GetUserValues: GetUserValues::<Identity>,
+            SetUserValues: SetUserValues::<Identity>,
+            AddNewHardware: AddNewHardware::<Identity>,
+            OpenTypeKey: OpenTypeKey::<Identity>,
+            OpenConfigKey: OpenConfigKey::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Registry")]
 impl windows_core::RuntimeName for IDirectInputJoyConfig {}
 windows_core::imp::define_interface!(IDirectInputJoyConfig8, IDirectInputJoyConfig8_Vtbl, 0xeb0d7dfa_1990_4f27_b4d6_edf2eec4a44c);
 windows_core::imp::interface_hierarchy!(IDirectInputJoyConfig8, windows_core::IUnknown);
@@ -6665,6 +8038,129 @@ impl IDirectInputJoyConfig8_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Registry")]
+impl<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig8_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectInputJoyConfig8_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Acquire<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig8_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig8_Impl::Acquire(this).into()
+            }
+        }
+        unsafe extern "system" fn Unacquire<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig8_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig8_Impl::Unacquire(this).into()
+            }
+        }
+        unsafe extern "system" fn SetCooperativeLevel<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig8_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig8_Impl::SetCooperativeLevel(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SendNotify<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig8_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig8_Impl::SendNotify(this).into()
+            }
+        }
+        unsafe extern "system" fn EnumTypes<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig8_Impl>(this: *mut core::ffi::c_void, param0: LPDIJOYTYPECALLBACK, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig8_Impl::EnumTypes(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetTypeInfo<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig8_Impl>(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR, param1: *mut DIJOYTYPEINFO, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig8_Impl::GetTypeInfo(this, core::mem::transmute(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn SetTypeInfo<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig8_Impl>(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR, param1: *mut DIJOYTYPEINFO, param2: u32, param3: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig8_Impl::SetTypeInfo(this, core::mem::transmute(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteType<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig8_Impl>(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig8_Impl::DeleteType(this, core::mem::transmute(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetConfig<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig8_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIJOYCONFIG, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig8_Impl::GetConfig(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn SetConfig<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig8_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DIJOYCONFIG, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig8_Impl::SetConfig(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteConfig<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig8_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig8_Impl::DeleteConfig(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetUserValues<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig8_Impl>(this: *mut core::ffi::c_void, param0: *mut DIJOYUSERVALUES, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig8_Impl::GetUserValues(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetUserValues<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig8_Impl>(this: *mut core::ffi::c_void, param0: *mut DIJOYUSERVALUES, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig8_Impl::SetUserValues(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn AddNewHardware<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig8_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig8_Impl::AddNewHardware(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn OpenTypeKey<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig8_Impl>(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR, param1: u32, param2: *mut super::super::System::Registry::HKEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig8_Impl::OpenTypeKey(this, core::mem::transmute(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn OpenAppStatusKey<Identity: windows_core::IUnknownImpl + IDirectInputJoyConfig8_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::System::Registry::HKEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputJoyConfig8_Impl::OpenAppStatusKey(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Acquire: Acquire::<Identity>,
+            Unacquire: Unacquire::<Identity>,
+            SetCooperativeLevel: SetCooperativeLevel::<Identity>,
+            SendNotify: SendNotify::<Identity>,
+            EnumTypes: EnumTypes::<Identity>,
+            GetTypeInfo: GetTypeInfo::<Identity>,
+            SetTypeInfo: SetTypeInfo::<Identity>,
+            DeleteType: DeleteType::<Identity>,
+            GetConfig: GetConfig::<Identity>,
+            SetConfig: SetConfig::<Identity>,
+            DeleteConfig: DeleteConfig::<Identity>,
+            GetUserValues: GetUserValues::<Identity>,
+            SetUserValues: SetUserValues::<Identity>,
+            AddNewHardware: AddNewHardware::<Identity>,
+            OpenTypeKey: OpenTypeKey::<Identity>,
+            OpenAppStatusKey: OpenAppStatusKey::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Registry")]
 impl windows_core::RuntimeName for IDirectInputJoyConfig8 {}
 windows_core::imp::define_interface!(IDirectInputW, IDirectInputW_Vtbl, 0x89521361_aa8a_11cf_bfc7_444553540000);
 windows_core::imp::interface_hierarchy!(IDirectInputW, windows_core::IUnknown);
@@ -6750,6 +8246,51 @@ impl IDirectInputW_Vtbl {
         iid == &<IDirectInputW as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectInputW_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectInputW_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateDevice<Identity: windows_core::IUnknownImpl + IDirectInputW_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputW_Impl::CreateDevice(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn EnumDevices<Identity: windows_core::IUnknownImpl + IDirectInputW_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: LPDIENUMDEVICESCALLBACKW, param2: *mut core::ffi::c_void, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputW_Impl::EnumDevices(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceStatus<Identity: windows_core::IUnknownImpl + IDirectInputW_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputW_Impl::GetDeviceStatus(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn RunControlPanel<Identity: windows_core::IUnknownImpl + IDirectInputW_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputW_Impl::RunControlPanel(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectInputW_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HINSTANCE, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectInputW_Impl::Initialize(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateDevice: CreateDevice::<Identity>,
+            EnumDevices: EnumDevices::<Identity>,
+            GetDeviceStatus: GetDeviceStatus::<Identity>,
+            RunControlPanel: RunControlPanel::<Identity>,
+            Initialize: Initialize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectInputW {}
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
@@ -249,6 +249,63 @@ impl IEnumWIA_DEV_CAPS_Vtbl {
         iid == &<IEnumWIA_DEV_CAPS as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumWIA_DEV_CAPS_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumWIA_DEV_CAPS_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumWIA_DEV_CAPS_Impl>(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut WIA_DEV_CAP, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumWIA_DEV_CAPS_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgelt), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumWIA_DEV_CAPS_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumWIA_DEV_CAPS_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumWIA_DEV_CAPS_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumWIA_DEV_CAPS_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumWIA_DEV_CAPS_Impl>(this: *mut core::ffi::c_void, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumWIA_DEV_CAPS_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppienum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IEnumWIA_DEV_CAPS_Impl>(this: *mut core::ffi::c_void, pcelt: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumWIA_DEV_CAPS_Impl::GetCount(this) {
+                    Ok(ok__) => {
+                        pcelt.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+            GetCount: GetCount::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumWIA_DEV_CAPS {}
 windows_core::imp::define_interface!(IEnumWIA_DEV_INFO, IEnumWIA_DEV_INFO_Vtbl, 0x5e38b83c_8cf1_11d1_bf92_0060081ed811);
 windows_core::imp::interface_hierarchy!(IEnumWIA_DEV_INFO, windows_core::IUnknown);
@@ -349,6 +406,63 @@ impl IEnumWIA_DEV_INFO_Vtbl {
         iid == &<IEnumWIA_DEV_INFO as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumWIA_DEV_INFO_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumWIA_DEV_INFO_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumWIA_DEV_INFO_Impl>(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumWIA_DEV_INFO_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgelt), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumWIA_DEV_INFO_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumWIA_DEV_INFO_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumWIA_DEV_INFO_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumWIA_DEV_INFO_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumWIA_DEV_INFO_Impl>(this: *mut core::ffi::c_void, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumWIA_DEV_INFO_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppienum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IEnumWIA_DEV_INFO_Impl>(this: *mut core::ffi::c_void, celt: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumWIA_DEV_INFO_Impl::GetCount(this) {
+                    Ok(ok__) => {
+                        celt.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+            GetCount: GetCount::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumWIA_DEV_INFO {}
 windows_core::imp::define_interface!(IEnumWIA_FORMAT_INFO, IEnumWIA_FORMAT_INFO_Vtbl, 0x81befc5b_656d_44f1_b24c_d41d51b4dc81);
 windows_core::imp::interface_hierarchy!(IEnumWIA_FORMAT_INFO, windows_core::IUnknown);
@@ -449,6 +563,63 @@ impl IEnumWIA_FORMAT_INFO_Vtbl {
         iid == &<IEnumWIA_FORMAT_INFO as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumWIA_FORMAT_INFO_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumWIA_FORMAT_INFO_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumWIA_FORMAT_INFO_Impl>(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut WIA_FORMAT_INFO, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumWIA_FORMAT_INFO_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgelt), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumWIA_FORMAT_INFO_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumWIA_FORMAT_INFO_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumWIA_FORMAT_INFO_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumWIA_FORMAT_INFO_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumWIA_FORMAT_INFO_Impl>(this: *mut core::ffi::c_void, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumWIA_FORMAT_INFO_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppienum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IEnumWIA_FORMAT_INFO_Impl>(this: *mut core::ffi::c_void, pcelt: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumWIA_FORMAT_INFO_Impl::GetCount(this) {
+                    Ok(ok__) => {
+                        pcelt.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+            GetCount: GetCount::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumWIA_FORMAT_INFO {}
 windows_core::imp::define_interface!(IEnumWiaItem, IEnumWiaItem_Vtbl, 0x5e8383fc_3391_11d2_9a33_00c04fa36145);
 windows_core::imp::interface_hierarchy!(IEnumWiaItem, windows_core::IUnknown);
@@ -549,6 +720,63 @@ impl IEnumWiaItem_Vtbl {
         iid == &<IEnumWiaItem as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumWiaItem_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumWiaItem_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumWiaItem_Impl>(this: *mut core::ffi::c_void, celt: u32, ppiwiaitem: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumWiaItem_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&ppiwiaitem), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumWiaItem_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumWiaItem_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumWiaItem_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumWiaItem_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumWiaItem_Impl>(this: *mut core::ffi::c_void, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumWiaItem_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppienum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IEnumWiaItem_Impl>(this: *mut core::ffi::c_void, celt: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumWiaItem_Impl::GetCount(this) {
+                    Ok(ok__) => {
+                        celt.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+            GetCount: GetCount::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumWiaItem {}
 windows_core::imp::define_interface!(IEnumWiaItem2, IEnumWiaItem2_Vtbl, 0x59970af4_cd0d_44d9_ab24_52295630e582);
 windows_core::imp::interface_hierarchy!(IEnumWiaItem2, windows_core::IUnknown);
@@ -649,6 +877,63 @@ impl IEnumWiaItem2_Vtbl {
         iid == &<IEnumWiaItem2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumWiaItem2_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumWiaItem2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumWiaItem2_Impl>(this: *mut core::ffi::c_void, celt: u32, ppiwiaitem2: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumWiaItem2_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&ppiwiaitem2), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumWiaItem2_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumWiaItem2_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumWiaItem2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumWiaItem2_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumWiaItem2_Impl>(this: *mut core::ffi::c_void, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumWiaItem2_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppienum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IEnumWiaItem2_Impl>(this: *mut core::ffi::c_void, celt: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumWiaItem2_Impl::GetCount(this) {
+                    Ok(ok__) => {
+                        celt.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+            GetCount: GetCount::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumWiaItem2 {}
 pub const IMPRINTER: u32 = 65536u32;
 pub const IMPRINTER_READY: u32 = 4096u32;
@@ -720,6 +1005,36 @@ impl IWiaAppErrorHandler_Vtbl {
         iid == &<IWiaAppErrorHandler as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWiaAppErrorHandler_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaAppErrorHandler_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetWindow<Identity: windows_core::IUnknownImpl + IWiaAppErrorHandler_Impl>(this: *mut core::ffi::c_void, phwnd: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaAppErrorHandler_Impl::GetWindow(this) {
+                    Ok(ok__) => {
+                        phwnd.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReportStatus<Identity: windows_core::IUnknownImpl + IWiaAppErrorHandler_Impl>(this: *mut core::ffi::c_void, lflags: i32, pwiaitem2: *mut core::ffi::c_void, hrstatus: windows_core::HRESULT, lpercentcomplete: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaAppErrorHandler_Impl::ReportStatus(this, core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&pwiaitem2), core::mem::transmute_copy(&hrstatus), core::mem::transmute_copy(&lpercentcomplete)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetWindow: GetWindow::<Identity>,
+            ReportStatus: ReportStatus::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWiaAppErrorHandler {}
 windows_core::imp::define_interface!(IWiaDataCallback, IWiaDataCallback_Vtbl, 0xa558a866_a5b0_11d2_a08f_00c04f72dc3c);
 windows_core::imp::interface_hierarchy!(IWiaDataCallback, windows_core::IUnknown);
@@ -751,6 +1066,20 @@ impl IWiaDataCallback_Vtbl {
         iid == &<IWiaDataCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWiaDataCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaDataCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BandedDataCallback<Identity: windows_core::IUnknownImpl + IWiaDataCallback_Impl>(this: *mut core::ffi::c_void, lmessage: i32, lstatus: i32, lpercentcomplete: i32, loffset: i32, llength: i32, lreserved: i32, lreslength: i32, pbbuffer: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaDataCallback_Impl::BandedDataCallback(this, core::mem::transmute_copy(&lmessage), core::mem::transmute_copy(&lstatus), core::mem::transmute_copy(&lpercentcomplete), core::mem::transmute_copy(&loffset), core::mem::transmute_copy(&llength), core::mem::transmute_copy(&lreserved), core::mem::transmute_copy(&lreslength), core::mem::transmute_copy(&pbbuffer)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, BandedDataCallback: BandedDataCallback::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWiaDataCallback {}
 windows_core::imp::define_interface!(IWiaDataTransfer, IWiaDataTransfer_Vtbl, 0xa6cef998_a5b0_11d2_a08f_00c04f72dc3c);
 windows_core::imp::interface_hierarchy!(IWiaDataTransfer, windows_core::IUnknown);
@@ -855,6 +1184,58 @@ impl IWiaDataTransfer_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+impl<Identity: windows_core::IUnknownImpl + IWiaDataTransfer_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaDataTransfer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn idtGetData<Identity: windows_core::IUnknownImpl + IWiaDataTransfer_Impl>(this: *mut core::ffi::c_void, pmedium: *mut super::super::System::Com::STGMEDIUM, piwiadatacallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaDataTransfer_Impl::idtGetData(this, core::mem::transmute_copy(&pmedium), core::mem::transmute_copy(&piwiadatacallback)).into()
+            }
+        }
+        unsafe extern "system" fn idtGetBandedData<Identity: windows_core::IUnknownImpl + IWiaDataTransfer_Impl>(this: *mut core::ffi::c_void, pwiadatatransinfo: *mut WIA_DATA_TRANSFER_INFO, piwiadatacallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaDataTransfer_Impl::idtGetBandedData(this, core::mem::transmute_copy(&pwiadatatransinfo), core::mem::transmute_copy(&piwiadatacallback)).into()
+            }
+        }
+        unsafe extern "system" fn idtQueryGetData<Identity: windows_core::IUnknownImpl + IWiaDataTransfer_Impl>(this: *mut core::ffi::c_void, pfe: *const WIA_FORMAT_INFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaDataTransfer_Impl::idtQueryGetData(this, core::mem::transmute_copy(&pfe)).into()
+            }
+        }
+        unsafe extern "system" fn idtEnumWIA_FORMAT_INFO<Identity: windows_core::IUnknownImpl + IWiaDataTransfer_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaDataTransfer_Impl::idtEnumWIA_FORMAT_INFO(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn idtGetExtendedTransferInfo<Identity: windows_core::IUnknownImpl + IWiaDataTransfer_Impl>(this: *mut core::ffi::c_void, pextendedtransferinfo: *mut WIA_EXTENDED_TRANSFER_INFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaDataTransfer_Impl::idtGetExtendedTransferInfo(this, core::mem::transmute_copy(&pextendedtransferinfo)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            idtGetData: idtGetData::<Identity>,
+            idtGetBandedData: idtGetBandedData::<Identity>,
+            idtQueryGetData: idtQueryGetData::<Identity>,
+            idtEnumWIA_FORMAT_INFO: idtEnumWIA_FORMAT_INFO::<Identity>,
+            idtGetExtendedTransferInfo: idtGetExtendedTransferInfo::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
 impl windows_core::RuntimeName for IWiaDataTransfer {}
 windows_core::imp::define_interface!(IWiaDevMgr, IWiaDevMgr_Vtbl, 0x5eb2502a_8cf1_11d1_bf92_0060081ed811);
 windows_core::imp::interface_hierarchy!(IWiaDevMgr, windows_core::IUnknown);
@@ -1018,20 +1399,111 @@ impl IWiaDevMgr_Vtbl {
         iid == &<IWiaDevMgr as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IWiaDevMgr {}
-windows_core::imp::define_interface!(IWiaDevMgr2, IWiaDevMgr2_Vtbl, 0x79c07cf1_cbdd_41ee_8ec3_f00080cada7a);
-windows_core::imp::interface_hierarchy!(IWiaDevMgr2, windows_core::IUnknown);
-impl IWiaDevMgr2 {
-    pub unsafe fn EnumDeviceInfo(&self, lflags: i32) -> windows_core::Result<IEnumWIA_DEV_INFO> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).EnumDeviceInfo)(windows_core::Interface::as_raw(self), lflags, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+impl<Identity: windows_core::IUnknownImpl + IWiaDevMgr_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaDevMgr_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumDeviceInfo<Identity: windows_core::IUnknownImpl + IWiaDevMgr_Impl>(this: *mut core::ffi::c_void, lflag: i32, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaDevMgr_Impl::EnumDeviceInfo(this, core::mem::transmute_copy(&lflag)) {
+                    Ok(ok__) => {
+                        ppienum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn CreateDevice(&self, lflags: i32, bstrdeviceid: &windows_core::BSTR) -> windows_core::Result<IWiaItem2> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateDevice)(windows_core::Interface::as_raw(self), lflags, core::mem::transmute_copy(bstrdeviceid), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn CreateDevice<Identity: windows_core::IUnknownImpl + IWiaDevMgr_Impl>(this: *mut core::ffi::c_void, bstrdeviceid: *mut core::ffi::c_void, ppwiaitemroot: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaDevMgr_Impl::CreateDevice(this, core::mem::transmute(&bstrdeviceid)) {
+                    Ok(ok__) => {
+                        ppwiaitemroot.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SelectDeviceDlg<Identity: windows_core::IUnknownImpl + IWiaDevMgr_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, ldevicetype: i32, lflags: i32, pbstrdeviceid: *mut *mut core::ffi::c_void, ppitemroot: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaDevMgr_Impl::SelectDeviceDlg(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&ldevicetype), core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&pbstrdeviceid), core::mem::transmute_copy(&ppitemroot)).into()
+            }
+        }
+        unsafe extern "system" fn SelectDeviceDlgID<Identity: windows_core::IUnknownImpl + IWiaDevMgr_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, ldevicetype: i32, lflags: i32, pbstrdeviceid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaDevMgr_Impl::SelectDeviceDlgID(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&ldevicetype), core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&pbstrdeviceid)).into()
+            }
+        }
+        unsafe extern "system" fn GetImageDlg<Identity: windows_core::IUnknownImpl + IWiaDevMgr_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, ldevicetype: i32, lflags: i32, lintent: i32, pitemroot: *mut core::ffi::c_void, bstrfilename: *mut core::ffi::c_void, pguidformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaDevMgr_Impl::GetImageDlg(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&ldevicetype), core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&lintent), core::mem::transmute_copy(&pitemroot), core::mem::transmute(&bstrfilename), core::mem::transmute_copy(&pguidformat)).into()
+            }
+        }
+        unsafe extern "system" fn RegisterEventCallbackProgram<Identity: windows_core::IUnknownImpl + IWiaDevMgr_Impl>(this: *mut core::ffi::c_void, lflags: i32, bstrdeviceid: *mut core::ffi::c_void, peventguid: *const windows_core::GUID, bstrcommandline: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, bstrdescription: *mut core::ffi::c_void, bstricon: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaDevMgr_Impl::RegisterEventCallbackProgram(this, core::mem::transmute_copy(&lflags), core::mem::transmute(&bstrdeviceid), core::mem::transmute_copy(&peventguid), core::mem::transmute(&bstrcommandline), core::mem::transmute(&bstrname), core::mem::transmute(&bstrdescription), core::mem::transmute(&bstricon)).into()
+            }
+        }
+        unsafe extern "system" fn RegisterEventCallbackInterface<Identity: windows_core::IUnknownImpl + IWiaDevMgr_Impl>(this: *mut core::ffi::c_void, lflags: i32, bstrdeviceid: *mut core::ffi::c_void, peventguid: *const windows_core::GUID, piwiaeventcallback: *mut core::ffi::c_void, peventobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaDevMgr_Impl::RegisterEventCallbackInterface(this, core::mem::transmute_copy(&lflags), core::mem::transmute(&bstrdeviceid), core::mem::transmute_copy(&peventguid), core::mem::transmute_copy(&piwiaeventcallback)) {
+                    Ok(ok__) => {
+                        peventobject.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RegisterEventCallbackCLSID<Identity: windows_core::IUnknownImpl + IWiaDevMgr_Impl>(this: *mut core::ffi::c_void, lflags: i32, bstrdeviceid: *mut core::ffi::c_void, peventguid: *const windows_core::GUID, pclsid: *const windows_core::GUID, bstrname: *mut core::ffi::c_void, bstrdescription: *mut core::ffi::c_void, bstricon: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaDevMgr_Impl::RegisterEventCallbackCLSID(this, core::mem::transmute_copy(&lflags), core::mem::transmute(&bstrdeviceid), core::mem::transmute_copy(&peventguid), core::mem::transmute_copy(&pclsid), core::mem::transmute(&bstrname), core::mem::transmute(&bstrdescription), core::mem::transmute(&bstricon)).into()
+            }
+        }
+        unsafe extern "system" fn AddDeviceDlg<Identity: windows_core::IUnknownImpl + IWiaDevMgr_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, lflags: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaDevMgr_Impl::AddDeviceDlg(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&lflags)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnumDeviceInfo: EnumDeviceInfo::<Identity>,
+            CreateDevice: CreateDevice::<Identity>,
+            SelectDeviceDlg: SelectDeviceDlg::<Identity>,
+            SelectDeviceDlgID: SelectDeviceDlgID::<Identity>,
+            GetImageDlg: GetImageDlg::<Identity>,
+            RegisterEventCallbackProgram: RegisterEventCallbackProgram::<Identity>,
+            RegisterEventCallbackInterface: RegisterEventCallbackInterface::<Identity>,
+            RegisterEventCallbackCLSID: RegisterEventCallbackCLSID::<Identity>,
+            AddDeviceDlg: AddDeviceDlg::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IWiaDevMgr {}
+windows_core::imp::define_interface!(IWiaDevMgr2, IWiaDevMgr2_Vtbl, 0x79c07cf1_cbdd_41ee_8ec3_f00080cada7a);
+windows_core::imp::interface_hierarchy!(IWiaDevMgr2, windows_core::IUnknown);
+impl IWiaDevMgr2 {
+    pub unsafe fn EnumDeviceInfo(&self, lflags: i32) -> windows_core::Result<IEnumWIA_DEV_INFO> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).EnumDeviceInfo)(windows_core::Interface::as_raw(self), lflags, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn CreateDevice(&self, lflags: i32, bstrdeviceid: &windows_core::BSTR) -> windows_core::Result<IWiaItem2> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateDevice)(windows_core::Interface::as_raw(self), lflags, core::mem::transmute_copy(bstrdeviceid), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
     }
     pub unsafe fn SelectDeviceDlg(&self, hwndparent: super::super::Foundation::HWND, ldevicetype: i32, lflags: i32, pbstrdeviceid: *mut windows_core::BSTR, ppitemroot: *mut Option<IWiaItem2>) -> windows_core::Result<()> {
@@ -1166,6 +1638,90 @@ impl IWiaDevMgr2_Vtbl {
         iid == &<IWiaDevMgr2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWiaDevMgr2_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaDevMgr2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumDeviceInfo<Identity: windows_core::IUnknownImpl + IWiaDevMgr2_Impl>(this: *mut core::ffi::c_void, lflags: i32, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaDevMgr2_Impl::EnumDeviceInfo(this, core::mem::transmute_copy(&lflags)) {
+                    Ok(ok__) => {
+                        ppienum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateDevice<Identity: windows_core::IUnknownImpl + IWiaDevMgr2_Impl>(this: *mut core::ffi::c_void, lflags: i32, bstrdeviceid: *mut core::ffi::c_void, ppwiaitem2root: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaDevMgr2_Impl::CreateDevice(this, core::mem::transmute_copy(&lflags), core::mem::transmute(&bstrdeviceid)) {
+                    Ok(ok__) => {
+                        ppwiaitem2root.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SelectDeviceDlg<Identity: windows_core::IUnknownImpl + IWiaDevMgr2_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, ldevicetype: i32, lflags: i32, pbstrdeviceid: *mut *mut core::ffi::c_void, ppitemroot: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaDevMgr2_Impl::SelectDeviceDlg(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&ldevicetype), core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&pbstrdeviceid), core::mem::transmute_copy(&ppitemroot)).into()
+            }
+        }
+        unsafe extern "system" fn SelectDeviceDlgID<Identity: windows_core::IUnknownImpl + IWiaDevMgr2_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, ldevicetype: i32, lflags: i32, pbstrdeviceid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaDevMgr2_Impl::SelectDeviceDlgID(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&ldevicetype), core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&pbstrdeviceid)).into()
+            }
+        }
+        unsafe extern "system" fn RegisterEventCallbackInterface<Identity: windows_core::IUnknownImpl + IWiaDevMgr2_Impl>(this: *mut core::ffi::c_void, lflags: i32, bstrdeviceid: *mut core::ffi::c_void, peventguid: *const windows_core::GUID, piwiaeventcallback: *mut core::ffi::c_void, peventobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaDevMgr2_Impl::RegisterEventCallbackInterface(this, core::mem::transmute_copy(&lflags), core::mem::transmute(&bstrdeviceid), core::mem::transmute_copy(&peventguid), core::mem::transmute_copy(&piwiaeventcallback)) {
+                    Ok(ok__) => {
+                        peventobject.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RegisterEventCallbackProgram<Identity: windows_core::IUnknownImpl + IWiaDevMgr2_Impl>(this: *mut core::ffi::c_void, lflags: i32, bstrdeviceid: *mut core::ffi::c_void, peventguid: *const windows_core::GUID, bstrfullappname: *mut core::ffi::c_void, bstrcommandlinearg: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, bstrdescription: *mut core::ffi::c_void, bstricon: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaDevMgr2_Impl::RegisterEventCallbackProgram(this, core::mem::transmute_copy(&lflags), core::mem::transmute(&bstrdeviceid), core::mem::transmute_copy(&peventguid), core::mem::transmute(&bstrfullappname), core::mem::transmute(&bstrcommandlinearg), core::mem::transmute(&bstrname), core::mem::transmute(&bstrdescription), core::mem::transmute(&bstricon)).into()
+            }
+        }
+        unsafe extern "system" fn RegisterEventCallbackCLSID<Identity: windows_core::IUnknownImpl + IWiaDevMgr2_Impl>(this: *mut core::ffi::c_void, lflags: i32, bstrdeviceid: *mut core::ffi::c_void, peventguid: *const windows_core::GUID, pclsid: *const windows_core::GUID, bstrname: *mut core::ffi::c_void, bstrdescription: *mut core::ffi::c_void, bstricon: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaDevMgr2_Impl::RegisterEventCallbackCLSID(this, core::mem::transmute_copy(&lflags), core::mem::transmute(&bstrdeviceid), core::mem::transmute_copy(&peventguid), core::mem::transmute_copy(&pclsid), core::mem::transmute(&bstrname), core::mem::transmute(&bstrdescription), core::mem::transmute(&bstricon)).into()
+            }
+        }
+        unsafe extern "system" fn GetImageDlg<Identity: windows_core::IUnknownImpl + IWiaDevMgr2_Impl>(this: *mut core::ffi::c_void, lflags: i32, bstrdeviceid: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, bstrfoldername: *mut core::ffi::c_void, bstrfilename: *mut core::ffi::c_void, plnumfiles: *mut i32, ppbstrfilepaths: *mut *mut *mut core::ffi::c_void, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaDevMgr2_Impl::GetImageDlg(this, core::mem::transmute_copy(&lflags), core::mem::transmute(&bstrdeviceid), core::mem::transmute_copy(&hwndparent), core::mem::transmute(&bstrfoldername), core::mem::transmute(&bstrfilename), core::mem::transmute_copy(&plnumfiles), core::mem::transmute_copy(&ppbstrfilepaths), core::mem::transmute_copy(&ppitem)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnumDeviceInfo: EnumDeviceInfo::<Identity>,
+            CreateDevice: CreateDevice::<Identity>,
+            SelectDeviceDlg: SelectDeviceDlg::<Identity>,
+            SelectDeviceDlgID: SelectDeviceDlgID::<Identity>,
+            RegisterEventCallbackInterface: RegisterEventCallbackInterface::<Identity>,
+            RegisterEventCallbackProgram: RegisterEventCallbackProgram::<Identity>,
+            RegisterEventCallbackCLSID: RegisterEventCallbackCLSID::<Identity>,
+            GetImageDlg: GetImageDlg::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWiaDevMgr2 {}
 windows_core::imp::define_interface!(IWiaDrvItem, IWiaDrvItem_Vtbl, 0x1f02b5c5_b00c_11d2_a094_00c04f72dc3c);
 windows_core::imp::interface_hierarchy!(IWiaDrvItem, windows_core::IUnknown);
@@ -1437,6 +1993,167 @@ impl IWiaDrvItem_Vtbl {
         iid == &<IWiaDrvItem as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWiaDrvItem_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaDrvItem_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetItemFlags<Identity: windows_core::IUnknownImpl + IWiaDrvItem_Impl>(this: *mut core::ffi::c_void, __midl__iwiadrvitem0000: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaDrvItem_Impl::GetItemFlags(this) {
+                    Ok(ok__) => {
+                        __midl__iwiadrvitem0000.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDeviceSpecContext<Identity: windows_core::IUnknownImpl + IWiaDrvItem_Impl>(this: *mut core::ffi::c_void, __midl__iwiadrvitem0001: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaDrvItem_Impl::GetDeviceSpecContext(this) {
+                    Ok(ok__) => {
+                        __midl__iwiadrvitem0001.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFullItemName<Identity: windows_core::IUnknownImpl + IWiaDrvItem_Impl>(this: *mut core::ffi::c_void, __midl__iwiadrvitem0002: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaDrvItem_Impl::GetFullItemName(this) {
+                    Ok(ok__) => {
+                        __midl__iwiadrvitem0002.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetItemName<Identity: windows_core::IUnknownImpl + IWiaDrvItem_Impl>(this: *mut core::ffi::c_void, __midl__iwiadrvitem0003: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaDrvItem_Impl::GetItemName(this) {
+                    Ok(ok__) => {
+                        __midl__iwiadrvitem0003.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddItemToFolder<Identity: windows_core::IUnknownImpl + IWiaDrvItem_Impl>(this: *mut core::ffi::c_void, __midl__iwiadrvitem0004: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaDrvItem_Impl::AddItemToFolder(this, core::mem::transmute_copy(&__midl__iwiadrvitem0004)).into()
+            }
+        }
+        unsafe extern "system" fn UnlinkItemTree<Identity: windows_core::IUnknownImpl + IWiaDrvItem_Impl>(this: *mut core::ffi::c_void, __midl__iwiadrvitem0005: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaDrvItem_Impl::UnlinkItemTree(this, core::mem::transmute_copy(&__midl__iwiadrvitem0005)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveItemFromFolder<Identity: windows_core::IUnknownImpl + IWiaDrvItem_Impl>(this: *mut core::ffi::c_void, __midl__iwiadrvitem0006: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaDrvItem_Impl::RemoveItemFromFolder(this, core::mem::transmute_copy(&__midl__iwiadrvitem0006)).into()
+            }
+        }
+        unsafe extern "system" fn FindItemByName<Identity: windows_core::IUnknownImpl + IWiaDrvItem_Impl>(this: *mut core::ffi::c_void, __midl__iwiadrvitem0007: i32, __midl__iwiadrvitem0008: *mut core::ffi::c_void, __midl__iwiadrvitem0009: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaDrvItem_Impl::FindItemByName(this, core::mem::transmute_copy(&__midl__iwiadrvitem0007), core::mem::transmute(&__midl__iwiadrvitem0008)) {
+                    Ok(ok__) => {
+                        __midl__iwiadrvitem0009.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindChildItemByName<Identity: windows_core::IUnknownImpl + IWiaDrvItem_Impl>(this: *mut core::ffi::c_void, __midl__iwiadrvitem0010: *mut core::ffi::c_void, __midl__iwiadrvitem0011: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaDrvItem_Impl::FindChildItemByName(this, core::mem::transmute(&__midl__iwiadrvitem0010)) {
+                    Ok(ok__) => {
+                        __midl__iwiadrvitem0011.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetParentItem<Identity: windows_core::IUnknownImpl + IWiaDrvItem_Impl>(this: *mut core::ffi::c_void, __midl__iwiadrvitem0012: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaDrvItem_Impl::GetParentItem(this) {
+                    Ok(ok__) => {
+                        __midl__iwiadrvitem0012.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFirstChildItem<Identity: windows_core::IUnknownImpl + IWiaDrvItem_Impl>(this: *mut core::ffi::c_void, __midl__iwiadrvitem0013: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaDrvItem_Impl::GetFirstChildItem(this) {
+                    Ok(ok__) => {
+                        __midl__iwiadrvitem0013.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetNextSiblingItem<Identity: windows_core::IUnknownImpl + IWiaDrvItem_Impl>(this: *mut core::ffi::c_void, __midl__iwiadrvitem0014: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaDrvItem_Impl::GetNextSiblingItem(this) {
+                    Ok(ok__) => {
+                        __midl__iwiadrvitem0014.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DumpItemData<Identity: windows_core::IUnknownImpl + IWiaDrvItem_Impl>(this: *mut core::ffi::c_void, __midl__iwiadrvitem0015: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaDrvItem_Impl::DumpItemData(this) {
+                    Ok(ok__) => {
+                        __midl__iwiadrvitem0015.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetItemFlags: GetItemFlags::<Identity>,
+            GetDeviceSpecContext: GetDeviceSpecContext::<Identity>,
+            GetFullItemName: GetFullItemName::<Identity>,
+            GetItemName: GetItemName::<Identity>,
+            AddItemToFolder: AddItemToFolder::<Identity>,
+            UnlinkItemTree: UnlinkItemTree::<Identity>,
+            RemoveItemFromFolder: RemoveItemFromFolder::<Identity>,
+            FindItemByName: FindItemByName::<Identity>,
+            FindChildItemByName: FindChildItemByName::<Identity>,
+            GetParentItem: GetParentItem::<Identity>,
+            GetFirstChildItem: GetFirstChildItem::<Identity>,
+            GetNextSiblingItem: GetNextSiblingItem::<Identity>,
+            DumpItemData: DumpItemData::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWiaDrvItem {}
 windows_core::imp::define_interface!(IWiaErrorHandler, IWiaErrorHandler_Vtbl, 0x0e4a51b1_bc1f_443d_a835_72e890759ef3);
 windows_core::imp::interface_hierarchy!(IWiaErrorHandler, windows_core::IUnknown);
@@ -1498,6 +2215,36 @@ impl IWiaErrorHandler_Vtbl {
         iid == &<IWiaErrorHandler as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWiaErrorHandler_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaErrorHandler_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ReportStatus<Identity: windows_core::IUnknownImpl + IWiaErrorHandler_Impl>(this: *mut core::ffi::c_void, lflags: i32, hwndparent: super::super::Foundation::HWND, pwiaitem2: *mut core::ffi::c_void, hrstatus: windows_core::HRESULT, lpercentcomplete: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaErrorHandler_Impl::ReportStatus(this, core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&pwiaitem2), core::mem::transmute_copy(&hrstatus), core::mem::transmute_copy(&lpercentcomplete)).into()
+            }
+        }
+        unsafe extern "system" fn GetStatusDescription<Identity: windows_core::IUnknownImpl + IWiaErrorHandler_Impl>(this: *mut core::ffi::c_void, lflags: i32, pwiaitem2: *mut core::ffi::c_void, hrstatus: windows_core::HRESULT, pbstrdescription: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaErrorHandler_Impl::GetStatusDescription(this, core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&pwiaitem2), core::mem::transmute_copy(&hrstatus)) {
+                    Ok(ok__) => {
+                        pbstrdescription.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ReportStatus: ReportStatus::<Identity>,
+            GetStatusDescription: GetStatusDescription::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWiaErrorHandler {}
 windows_core::imp::define_interface!(IWiaEventCallback, IWiaEventCallback_Vtbl, 0xae6287b0_0084_11d2_973b_00a0c9068f2e);
 windows_core::imp::interface_hierarchy!(IWiaEventCallback, windows_core::IUnknown);
@@ -1529,6 +2276,20 @@ impl IWiaEventCallback_Vtbl {
         iid == &<IWiaEventCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWiaEventCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaEventCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ImageEventCallback<Identity: windows_core::IUnknownImpl + IWiaEventCallback_Impl>(this: *mut core::ffi::c_void, peventguid: *const windows_core::GUID, bstreventdescription: *mut core::ffi::c_void, bstrdeviceid: *mut core::ffi::c_void, bstrdevicedescription: *mut core::ffi::c_void, dwdevicetype: u32, bstrfullitemname: *mut core::ffi::c_void, puleventtype: *mut u32, ulreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaEventCallback_Impl::ImageEventCallback(this, core::mem::transmute_copy(&peventguid), core::mem::transmute(&bstreventdescription), core::mem::transmute(&bstrdeviceid), core::mem::transmute(&bstrdevicedescription), core::mem::transmute_copy(&dwdevicetype), core::mem::transmute(&bstrfullitemname), core::mem::transmute_copy(&puleventtype), core::mem::transmute_copy(&ulreserved)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ImageEventCallback: ImageEventCallback::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWiaEventCallback {}
 windows_core::imp::define_interface!(IWiaImageFilter, IWiaImageFilter_Vtbl, 0xa8a79ffa_450b_41f1_8f87_849ccd94ebf6);
 windows_core::imp::interface_hierarchy!(IWiaImageFilter, windows_core::IUnknown);
@@ -1620,22 +2381,61 @@ impl IWiaImageFilter_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
-impl windows_core::RuntimeName for IWiaImageFilter {}
-windows_core::imp::define_interface!(IWiaItem, IWiaItem_Vtbl, 0x4db1ad10_3391_11d2_9a33_00c04fa36145);
-windows_core::imp::interface_hierarchy!(IWiaItem, windows_core::IUnknown);
-impl IWiaItem {
-    pub unsafe fn GetItemType(&self) -> windows_core::Result<i32> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetItemType)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+impl<Identity: windows_core::IUnknownImpl + IWiaImageFilter_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaImageFilter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InitializeFilter<Identity: windows_core::IUnknownImpl + IWiaImageFilter_Impl>(this: *mut core::ffi::c_void, pwiaitem2: *mut core::ffi::c_void, pwiatransfercallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaImageFilter_Impl::InitializeFilter(this, core::mem::transmute_copy(&pwiaitem2), core::mem::transmute_copy(&pwiatransfercallback)).into()
+            }
         }
-    }
-    pub unsafe fn AnalyzeItem(&self, lflags: i32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).AnalyzeItem)(windows_core::Interface::as_raw(self), lflags).ok() }
-    }
-    pub unsafe fn EnumChildItems(&self) -> windows_core::Result<IEnumWiaItem> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
+        unsafe extern "system" fn SetNewCallback<Identity: windows_core::IUnknownImpl + IWiaImageFilter_Impl>(this: *mut core::ffi::c_void, pwiatransfercallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaImageFilter_Impl::SetNewCallback(this, core::mem::transmute_copy(&pwiatransfercallback)).into()
+            }
+        }
+        unsafe extern "system" fn FilterPreviewImage<Identity: windows_core::IUnknownImpl + IWiaImageFilter_Impl>(this: *mut core::ffi::c_void, lflags: i32, pwiachilditem2: *mut core::ffi::c_void, inputimageextents: super::super::Foundation::RECT, pinputstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaImageFilter_Impl::FilterPreviewImage(this, core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&pwiachilditem2), core::mem::transmute(&inputimageextents), core::mem::transmute_copy(&pinputstream)).into()
+            }
+        }
+        unsafe extern "system" fn ApplyProperties<Identity: windows_core::IUnknownImpl + IWiaImageFilter_Impl>(this: *mut core::ffi::c_void, pwiapropertystorage: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaImageFilter_Impl::ApplyProperties(this, core::mem::transmute_copy(&pwiapropertystorage)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InitializeFilter: InitializeFilter::<Identity>,
+            SetNewCallback: SetNewCallback::<Identity>,
+            FilterPreviewImage: FilterPreviewImage::<Identity>,
+            ApplyProperties: ApplyProperties::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::RuntimeName for IWiaImageFilter {}
+windows_core::imp::define_interface!(IWiaItem, IWiaItem_Vtbl, 0x4db1ad10_3391_11d2_9a33_00c04fa36145);
+windows_core::imp::interface_hierarchy!(IWiaItem, windows_core::IUnknown);
+impl IWiaItem {
+    pub unsafe fn GetItemType(&self) -> windows_core::Result<i32> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetItemType)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn AnalyzeItem(&self, lflags: i32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).AnalyzeItem)(windows_core::Interface::as_raw(self), lflags).ok() }
+    }
+    pub unsafe fn EnumChildItems(&self) -> windows_core::Result<IEnumWiaItem> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
             (windows_core::Interface::vtable(self).EnumChildItems)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
     }
@@ -1912,6 +2712,181 @@ impl IWiaItem_Vtbl {
         iid == &<IWiaItem as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWiaItem_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaItem_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetItemType<Identity: windows_core::IUnknownImpl + IWiaItem_Impl>(this: *mut core::ffi::c_void, pitemtype: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaItem_Impl::GetItemType(this) {
+                    Ok(ok__) => {
+                        pitemtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AnalyzeItem<Identity: windows_core::IUnknownImpl + IWiaItem_Impl>(this: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaItem_Impl::AnalyzeItem(this, core::mem::transmute_copy(&lflags)).into()
+            }
+        }
+        unsafe extern "system" fn EnumChildItems<Identity: windows_core::IUnknownImpl + IWiaItem_Impl>(this: *mut core::ffi::c_void, ppienumwiaitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaItem_Impl::EnumChildItems(this) {
+                    Ok(ok__) => {
+                        ppienumwiaitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeleteItem<Identity: windows_core::IUnknownImpl + IWiaItem_Impl>(this: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaItem_Impl::DeleteItem(this, core::mem::transmute_copy(&lflags)).into()
+            }
+        }
+        unsafe extern "system" fn CreateChildItem<Identity: windows_core::IUnknownImpl + IWiaItem_Impl>(this: *mut core::ffi::c_void, lflags: i32, bstritemname: *mut core::ffi::c_void, bstrfullitemname: *mut core::ffi::c_void, ppiwiaitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaItem_Impl::CreateChildItem(this, core::mem::transmute_copy(&lflags), core::mem::transmute(&bstritemname), core::mem::transmute(&bstrfullitemname)) {
+                    Ok(ok__) => {
+                        ppiwiaitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumRegisterEventInfo<Identity: windows_core::IUnknownImpl + IWiaItem_Impl>(this: *mut core::ffi::c_void, lflags: i32, peventguid: *const windows_core::GUID, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaItem_Impl::EnumRegisterEventInfo(this, core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&peventguid)) {
+                    Ok(ok__) => {
+                        ppienum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindItemByName<Identity: windows_core::IUnknownImpl + IWiaItem_Impl>(this: *mut core::ffi::c_void, lflags: i32, bstrfullitemname: *mut core::ffi::c_void, ppiwiaitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaItem_Impl::FindItemByName(this, core::mem::transmute_copy(&lflags), core::mem::transmute(&bstrfullitemname)) {
+                    Ok(ok__) => {
+                        ppiwiaitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeviceDlg<Identity: windows_core::IUnknownImpl + IWiaItem_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, lflags: i32, lintent: i32, plitemcount: *mut i32, ppiwiaitem: *mut *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaItem_Impl::DeviceDlg(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&lintent), core::mem::transmute_copy(&plitemcount), core::mem::transmute_copy(&ppiwiaitem)).into()
+            }
+        }
+        unsafe extern "system" fn DeviceCommand<Identity: windows_core::IUnknownImpl + IWiaItem_Impl>(this: *mut core::ffi::c_void, lflags: i32, pcmdguid: *const windows_core::GUID, piwiaitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaItem_Impl::DeviceCommand(this, core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&pcmdguid), core::mem::transmute_copy(&piwiaitem)).into()
+            }
+        }
+        unsafe extern "system" fn GetRootItem<Identity: windows_core::IUnknownImpl + IWiaItem_Impl>(this: *mut core::ffi::c_void, ppiwiaitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaItem_Impl::GetRootItem(this) {
+                    Ok(ok__) => {
+                        ppiwiaitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumDeviceCapabilities<Identity: windows_core::IUnknownImpl + IWiaItem_Impl>(this: *mut core::ffi::c_void, lflags: i32, ppienumwia_dev_caps: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaItem_Impl::EnumDeviceCapabilities(this, core::mem::transmute_copy(&lflags)) {
+                    Ok(ok__) => {
+                        ppienumwia_dev_caps.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DumpItemData<Identity: windows_core::IUnknownImpl + IWiaItem_Impl>(this: *mut core::ffi::c_void, bstrdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaItem_Impl::DumpItemData(this) {
+                    Ok(ok__) => {
+                        bstrdata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DumpDrvItemData<Identity: windows_core::IUnknownImpl + IWiaItem_Impl>(this: *mut core::ffi::c_void, bstrdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaItem_Impl::DumpDrvItemData(this) {
+                    Ok(ok__) => {
+                        bstrdata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DumpTreeItemData<Identity: windows_core::IUnknownImpl + IWiaItem_Impl>(this: *mut core::ffi::c_void, bstrdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaItem_Impl::DumpTreeItemData(this) {
+                    Ok(ok__) => {
+                        bstrdata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Diagnostic<Identity: windows_core::IUnknownImpl + IWiaItem_Impl>(this: *mut core::ffi::c_void, ulsize: u32, pbuffer: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaItem_Impl::Diagnostic(this, core::mem::transmute_copy(&ulsize), core::mem::transmute_copy(&pbuffer)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetItemType: GetItemType::<Identity>,
+            AnalyzeItem: AnalyzeItem::<Identity>,
+            EnumChildItems: EnumChildItems::<Identity>,
+            DeleteItem: DeleteItem::<Identity>,
+            CreateChildItem: CreateChildItem::<Identity>,
+            EnumRegisterEventInfo: EnumRegisterEventInfo::<Identity>,
+            FindItemByName: FindItemByName::<Identity>,
+            DeviceDlg: DeviceDlg::<Identity>,
+            DeviceCommand: DeviceCommand::<Identity>,
+            GetRootItem: GetRootItem::<Identity>,
+            EnumDeviceCapabilities: EnumDeviceCapabilities::<Identity>,
+            DumpItemData: DumpItemData::<Identity>,
+            DumpDrvItemData: DumpDrvItemData::<Identity>,
+            DumpTreeItemData: DumpTreeItemData::<Identity>,
+            Diagnostic: Diagnostic::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWiaItem {}
 windows_core::imp::define_interface!(IWiaItem2, IWiaItem2_Vtbl, 0x6cba0075_1287_407d_9b77_cf0e030435cc);
 windows_core::imp::interface_hierarchy!(IWiaItem2, windows_core::IUnknown);
@@ -2034,11 +3009,196 @@ pub trait IWiaItem2_Impl: windows_core::IUnknownImpl {
     fn EnumRegisterEventInfo(&self, lflags: i32, peventguid: *const windows_core::GUID) -> windows_core::Result<IEnumWIA_DEV_CAPS>;
     fn Diagnostic(&self, ulsize: u32, pbuffer: *const u8) -> windows_core::Result<()>;
 }
-impl IWiaItem2_Vtbl {
-    pub const fn new<Identity: IWiaItem2_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn CreateChildItem<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, litemflags: i32, lcreationflags: i32, bstritemname: *mut core::ffi::c_void, ppiwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl IWiaItem2_Vtbl {
+    pub const fn new<Identity: IWiaItem2_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn CreateChildItem<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, litemflags: i32, lcreationflags: i32, bstritemname: *mut core::ffi::c_void, ppiwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWiaItem2_Impl::CreateChildItem(this, core::mem::transmute_copy(&litemflags), core::mem::transmute_copy(&lcreationflags), core::mem::transmute(&bstritemname)) {
+                    Ok(ok__) => {
+                        ppiwiaitem2.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeleteItem<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWiaItem2_Impl::DeleteItem(this, core::mem::transmute_copy(&lflags)).into()
+            }
+        }
+        unsafe extern "system" fn EnumChildItems<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pcategoryguid: *const windows_core::GUID, ppienumwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWiaItem2_Impl::EnumChildItems(this, core::mem::transmute_copy(&pcategoryguid)) {
+                    Ok(ok__) => {
+                        ppienumwiaitem2.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindItemByName<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lflags: i32, bstrfullitemname: *mut core::ffi::c_void, ppiwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWiaItem2_Impl::FindItemByName(this, core::mem::transmute_copy(&lflags), core::mem::transmute(&bstrfullitemname)) {
+                    Ok(ok__) => {
+                        ppiwiaitem2.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetItemCategory<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pitemcategoryguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWiaItem2_Impl::GetItemCategory(this) {
+                    Ok(ok__) => {
+                        pitemcategoryguid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetItemType<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pitemtype: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWiaItem2_Impl::GetItemType(this) {
+                    Ok(ok__) => {
+                        pitemtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeviceDlg<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lflags: i32, hwndparent: super::super::Foundation::HWND, bstrfoldername: *mut core::ffi::c_void, bstrfilename: *mut core::ffi::c_void, plnumfiles: *mut i32, ppbstrfilepaths: *mut *mut *mut core::ffi::c_void, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWiaItem2_Impl::DeviceDlg(this, core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&hwndparent), core::mem::transmute(&bstrfoldername), core::mem::transmute(&bstrfilename), core::mem::transmute_copy(&plnumfiles), core::mem::transmute_copy(&ppbstrfilepaths), core::mem::transmute_copy(&ppitem)).into()
+            }
+        }
+        unsafe extern "system" fn DeviceCommand<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lflags: i32, pcmdguid: *const windows_core::GUID, ppiwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWiaItem2_Impl::DeviceCommand(this, core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&pcmdguid), core::mem::transmute_copy(&ppiwiaitem2)).into()
+            }
+        }
+        unsafe extern "system" fn EnumDeviceCapabilities<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lflags: i32, ppienumwia_dev_caps: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWiaItem2_Impl::EnumDeviceCapabilities(this, core::mem::transmute_copy(&lflags)) {
+                    Ok(ok__) => {
+                        ppienumwia_dev_caps.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CheckExtension<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lflags: i32, bstrname: *mut core::ffi::c_void, riidextensioninterface: *const windows_core::GUID, pbextensionexists: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWiaItem2_Impl::CheckExtension(this, core::mem::transmute_copy(&lflags), core::mem::transmute(&bstrname), core::mem::transmute_copy(&riidextensioninterface), core::mem::transmute_copy(&pbextensionexists)).into()
+            }
+        }
+        unsafe extern "system" fn GetExtension<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lflags: i32, bstrname: *mut core::ffi::c_void, riidextensioninterface: *const windows_core::GUID, ppout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWiaItem2_Impl::GetExtension(this, core::mem::transmute_copy(&lflags), core::mem::transmute(&bstrname), core::mem::transmute_copy(&riidextensioninterface), core::mem::transmute_copy(&ppout)).into()
+            }
+        }
+        unsafe extern "system" fn GetParentItem<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppiwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWiaItem2_Impl::GetParentItem(this) {
+                    Ok(ok__) => {
+                        ppiwiaitem2.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRootItem<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppiwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWiaItem2_Impl::GetRootItem(this) {
+                    Ok(ok__) => {
+                        ppiwiaitem2.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPreviewComponent<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lflags: i32, ppwiapreview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWiaItem2_Impl::GetPreviewComponent(this, core::mem::transmute_copy(&lflags)) {
+                    Ok(ok__) => {
+                        ppwiapreview.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumRegisterEventInfo<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lflags: i32, peventguid: *const windows_core::GUID, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWiaItem2_Impl::EnumRegisterEventInfo(this, core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&peventguid)) {
+                    Ok(ok__) => {
+                        ppienum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Diagnostic<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ulsize: u32, pbuffer: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWiaItem2_Impl::Diagnostic(this, core::mem::transmute_copy(&ulsize), core::mem::transmute_copy(&pbuffer)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            CreateChildItem: CreateChildItem::<Identity, OFFSET>,
+            DeleteItem: DeleteItem::<Identity, OFFSET>,
+            EnumChildItems: EnumChildItems::<Identity, OFFSET>,
+            FindItemByName: FindItemByName::<Identity, OFFSET>,
+            GetItemCategory: GetItemCategory::<Identity, OFFSET>,
+            GetItemType: GetItemType::<Identity, OFFSET>,
+            DeviceDlg: DeviceDlg::<Identity, OFFSET>,
+            DeviceCommand: DeviceCommand::<Identity, OFFSET>,
+            EnumDeviceCapabilities: EnumDeviceCapabilities::<Identity, OFFSET>,
+            CheckExtension: CheckExtension::<Identity, OFFSET>,
+            GetExtension: GetExtension::<Identity, OFFSET>,
+            GetParentItem: GetParentItem::<Identity, OFFSET>,
+            GetRootItem: GetRootItem::<Identity, OFFSET>,
+            GetPreviewComponent: GetPreviewComponent::<Identity, OFFSET>,
+            EnumRegisterEventInfo: EnumRegisterEventInfo::<Identity, OFFSET>,
+            Diagnostic: Diagnostic::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWiaItem2 as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IWiaItem2_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaItem2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateChildItem<Identity: windows_core::IUnknownImpl + IWiaItem2_Impl>(this: *mut core::ffi::c_void, litemflags: i32, lcreationflags: i32, bstritemname: *mut core::ffi::c_void, ppiwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWiaItem2_Impl::CreateChildItem(this, core::mem::transmute_copy(&litemflags), core::mem::transmute_copy(&lcreationflags), core::mem::transmute(&bstritemname)) {
                     Ok(ok__) => {
                         ppiwiaitem2.write(core::mem::transmute(ok__));
@@ -2048,15 +3208,15 @@ impl IWiaItem2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn DeleteItem<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn DeleteItem<Identity: windows_core::IUnknownImpl + IWiaItem2_Impl>(this: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWiaItem2_Impl::DeleteItem(this, core::mem::transmute_copy(&lflags)).into()
             }
         }
-        unsafe extern "system" fn EnumChildItems<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pcategoryguid: *const windows_core::GUID, ppienumwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn EnumChildItems<Identity: windows_core::IUnknownImpl + IWiaItem2_Impl>(this: *mut core::ffi::c_void, pcategoryguid: *const windows_core::GUID, ppienumwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWiaItem2_Impl::EnumChildItems(this, core::mem::transmute_copy(&pcategoryguid)) {
                     Ok(ok__) => {
                         ppienumwiaitem2.write(core::mem::transmute(ok__));
@@ -2066,9 +3226,9 @@ impl IWiaItem2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn FindItemByName<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lflags: i32, bstrfullitemname: *mut core::ffi::c_void, ppiwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn FindItemByName<Identity: windows_core::IUnknownImpl + IWiaItem2_Impl>(this: *mut core::ffi::c_void, lflags: i32, bstrfullitemname: *mut core::ffi::c_void, ppiwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWiaItem2_Impl::FindItemByName(this, core::mem::transmute_copy(&lflags), core::mem::transmute(&bstrfullitemname)) {
                     Ok(ok__) => {
                         ppiwiaitem2.write(core::mem::transmute(ok__));
@@ -2078,9 +3238,9 @@ impl IWiaItem2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetItemCategory<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pitemcategoryguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetItemCategory<Identity: windows_core::IUnknownImpl + IWiaItem2_Impl>(this: *mut core::ffi::c_void, pitemcategoryguid: *mut windows_core::GUID) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWiaItem2_Impl::GetItemCategory(this) {
                     Ok(ok__) => {
                         pitemcategoryguid.write(core::mem::transmute(ok__));
@@ -2090,9 +3250,9 @@ impl IWiaItem2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetItemType<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pitemtype: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetItemType<Identity: windows_core::IUnknownImpl + IWiaItem2_Impl>(this: *mut core::ffi::c_void, pitemtype: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWiaItem2_Impl::GetItemType(this) {
                     Ok(ok__) => {
                         pitemtype.write(core::mem::transmute(ok__));
@@ -2102,21 +3262,21 @@ impl IWiaItem2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn DeviceDlg<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lflags: i32, hwndparent: super::super::Foundation::HWND, bstrfoldername: *mut core::ffi::c_void, bstrfilename: *mut core::ffi::c_void, plnumfiles: *mut i32, ppbstrfilepaths: *mut *mut *mut core::ffi::c_void, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn DeviceDlg<Identity: windows_core::IUnknownImpl + IWiaItem2_Impl>(this: *mut core::ffi::c_void, lflags: i32, hwndparent: super::super::Foundation::HWND, bstrfoldername: *mut core::ffi::c_void, bstrfilename: *mut core::ffi::c_void, plnumfiles: *mut i32, ppbstrfilepaths: *mut *mut *mut core::ffi::c_void, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWiaItem2_Impl::DeviceDlg(this, core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&hwndparent), core::mem::transmute(&bstrfoldername), core::mem::transmute(&bstrfilename), core::mem::transmute_copy(&plnumfiles), core::mem::transmute_copy(&ppbstrfilepaths), core::mem::transmute_copy(&ppitem)).into()
             }
         }
-        unsafe extern "system" fn DeviceCommand<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lflags: i32, pcmdguid: *const windows_core::GUID, ppiwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn DeviceCommand<Identity: windows_core::IUnknownImpl + IWiaItem2_Impl>(this: *mut core::ffi::c_void, lflags: i32, pcmdguid: *const windows_core::GUID, ppiwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWiaItem2_Impl::DeviceCommand(this, core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&pcmdguid), core::mem::transmute_copy(&ppiwiaitem2)).into()
             }
         }
-        unsafe extern "system" fn EnumDeviceCapabilities<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lflags: i32, ppienumwia_dev_caps: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn EnumDeviceCapabilities<Identity: windows_core::IUnknownImpl + IWiaItem2_Impl>(this: *mut core::ffi::c_void, lflags: i32, ppienumwia_dev_caps: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWiaItem2_Impl::EnumDeviceCapabilities(this, core::mem::transmute_copy(&lflags)) {
                     Ok(ok__) => {
                         ppienumwia_dev_caps.write(core::mem::transmute(ok__));
@@ -2126,21 +3286,21 @@ impl IWiaItem2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CheckExtension<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lflags: i32, bstrname: *mut core::ffi::c_void, riidextensioninterface: *const windows_core::GUID, pbextensionexists: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn CheckExtension<Identity: windows_core::IUnknownImpl + IWiaItem2_Impl>(this: *mut core::ffi::c_void, lflags: i32, bstrname: *mut core::ffi::c_void, riidextensioninterface: *const windows_core::GUID, pbextensionexists: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWiaItem2_Impl::CheckExtension(this, core::mem::transmute_copy(&lflags), core::mem::transmute(&bstrname), core::mem::transmute_copy(&riidextensioninterface), core::mem::transmute_copy(&pbextensionexists)).into()
             }
         }
-        unsafe extern "system" fn GetExtension<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lflags: i32, bstrname: *mut core::ffi::c_void, riidextensioninterface: *const windows_core::GUID, ppout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetExtension<Identity: windows_core::IUnknownImpl + IWiaItem2_Impl>(this: *mut core::ffi::c_void, lflags: i32, bstrname: *mut core::ffi::c_void, riidextensioninterface: *const windows_core::GUID, ppout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWiaItem2_Impl::GetExtension(this, core::mem::transmute_copy(&lflags), core::mem::transmute(&bstrname), core::mem::transmute_copy(&riidextensioninterface), core::mem::transmute_copy(&ppout)).into()
             }
         }
-        unsafe extern "system" fn GetParentItem<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppiwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetParentItem<Identity: windows_core::IUnknownImpl + IWiaItem2_Impl>(this: *mut core::ffi::c_void, ppiwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWiaItem2_Impl::GetParentItem(this) {
                     Ok(ok__) => {
                         ppiwiaitem2.write(core::mem::transmute(ok__));
@@ -2150,9 +3310,9 @@ impl IWiaItem2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetRootItem<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppiwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetRootItem<Identity: windows_core::IUnknownImpl + IWiaItem2_Impl>(this: *mut core::ffi::c_void, ppiwiaitem2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWiaItem2_Impl::GetRootItem(this) {
                     Ok(ok__) => {
                         ppiwiaitem2.write(core::mem::transmute(ok__));
@@ -2162,9 +3322,9 @@ impl IWiaItem2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetPreviewComponent<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lflags: i32, ppwiapreview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetPreviewComponent<Identity: windows_core::IUnknownImpl + IWiaItem2_Impl>(this: *mut core::ffi::c_void, lflags: i32, ppwiapreview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWiaItem2_Impl::GetPreviewComponent(this, core::mem::transmute_copy(&lflags)) {
                     Ok(ok__) => {
                         ppwiapreview.write(core::mem::transmute(ok__));
@@ -2174,9 +3334,9 @@ impl IWiaItem2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn EnumRegisterEventInfo<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lflags: i32, peventguid: *const windows_core::GUID, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn EnumRegisterEventInfo<Identity: windows_core::IUnknownImpl + IWiaItem2_Impl>(this: *mut core::ffi::c_void, lflags: i32, peventguid: *const windows_core::GUID, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWiaItem2_Impl::EnumRegisterEventInfo(this, core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&peventguid)) {
                     Ok(ok__) => {
                         ppienum.write(core::mem::transmute(ok__));
@@ -2186,35 +3346,32 @@ impl IWiaItem2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Diagnostic<Identity: IWiaItem2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ulsize: u32, pbuffer: *const u8) -> windows_core::HRESULT {
+        unsafe extern "system" fn Diagnostic<Identity: windows_core::IUnknownImpl + IWiaItem2_Impl>(this: *mut core::ffi::c_void, ulsize: u32, pbuffer: *const u8) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWiaItem2_Impl::Diagnostic(this, core::mem::transmute_copy(&ulsize), core::mem::transmute_copy(&pbuffer)).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            CreateChildItem: CreateChildItem::<Identity, OFFSET>,
-            DeleteItem: DeleteItem::<Identity, OFFSET>,
-            EnumChildItems: EnumChildItems::<Identity, OFFSET>,
-            FindItemByName: FindItemByName::<Identity, OFFSET>,
-            GetItemCategory: GetItemCategory::<Identity, OFFSET>,
-            GetItemType: GetItemType::<Identity, OFFSET>,
-            DeviceDlg: DeviceDlg::<Identity, OFFSET>,
-            DeviceCommand: DeviceCommand::<Identity, OFFSET>,
-            EnumDeviceCapabilities: EnumDeviceCapabilities::<Identity, OFFSET>,
-            CheckExtension: CheckExtension::<Identity, OFFSET>,
-            GetExtension: GetExtension::<Identity, OFFSET>,
-            GetParentItem: GetParentItem::<Identity, OFFSET>,
-            GetRootItem: GetRootItem::<Identity, OFFSET>,
-            GetPreviewComponent: GetPreviewComponent::<Identity, OFFSET>,
-            EnumRegisterEventInfo: EnumRegisterEventInfo::<Identity, OFFSET>,
-            Diagnostic: Diagnostic::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWiaItem2 as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateChildItem: CreateChildItem::<Identity>,
+            DeleteItem: DeleteItem::<Identity>,
+            EnumChildItems: EnumChildItems::<Identity>,
+            FindItemByName: FindItemByName::<Identity>,
+            GetItemCategory: GetItemCategory::<Identity>,
+            GetItemType: GetItemType::<Identity>,
+            DeviceDlg: DeviceDlg::<Identity>,
+            DeviceCommand: DeviceCommand::<Identity>,
+            EnumDeviceCapabilities: EnumDeviceCapabilities::<Identity>,
+            CheckExtension: CheckExtension::<Identity>,
+            GetExtension: GetExtension::<Identity>,
+            GetParentItem: GetParentItem::<Identity>,
+            GetRootItem: GetRootItem::<Identity>,
+            GetPreviewComponent: GetPreviewComponent::<Identity>,
+            EnumRegisterEventInfo: EnumRegisterEventInfo::<Identity>,
+            Diagnostic: Diagnostic::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IWiaItem2 {}
 windows_core::imp::define_interface!(IWiaItemExtras, IWiaItemExtras_Vtbl, 0x6291ef2c_36ef_4532_876a_8e132593778d);
@@ -2283,6 +3440,43 @@ impl IWiaItemExtras_Vtbl {
         iid == &<IWiaItemExtras as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWiaItemExtras_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaItemExtras_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetExtendedErrorInfo<Identity: windows_core::IUnknownImpl + IWiaItemExtras_Impl>(this: *mut core::ffi::c_void, bstrerrortext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaItemExtras_Impl::GetExtendedErrorInfo(this) {
+                    Ok(ok__) => {
+                        bstrerrortext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Escape<Identity: windows_core::IUnknownImpl + IWiaItemExtras_Impl>(this: *mut core::ffi::c_void, dwescapecode: u32, lpindata: *const u8, cbindatasize: u32, poutdata: *mut u8, dwoutdatasize: u32, pdwactualdatasize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaItemExtras_Impl::Escape(this, core::mem::transmute_copy(&dwescapecode), core::mem::transmute_copy(&lpindata), core::mem::transmute_copy(&cbindatasize), core::mem::transmute_copy(&poutdata), core::mem::transmute_copy(&dwoutdatasize), core::mem::transmute_copy(&pdwactualdatasize)).into()
+            }
+        }
+        unsafe extern "system" fn CancelPendingIO<Identity: windows_core::IUnknownImpl + IWiaItemExtras_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaItemExtras_Impl::CancelPendingIO(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetExtendedErrorInfo: GetExtendedErrorInfo::<Identity>,
+            Escape: Escape::<Identity>,
+            CancelPendingIO: CancelPendingIO::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWiaItemExtras {}
 windows_core::imp::define_interface!(IWiaLog, IWiaLog_Vtbl, 0xa00c10b6_82a1_452f_8b6c_86062aad6890);
 windows_core::imp::interface_hierarchy!(IWiaLog, windows_core::IUnknown);
@@ -2341,6 +3535,37 @@ impl IWiaLog_Vtbl {
         iid == &<IWiaLog as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWiaLog_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaLog_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InitializeLog<Identity: windows_core::IUnknownImpl + IWiaLog_Impl>(this: *mut core::ffi::c_void, hinstance: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaLog_Impl::InitializeLog(this, core::mem::transmute_copy(&hinstance)).into()
+            }
+        }
+        unsafe extern "system" fn hResult<Identity: windows_core::IUnknownImpl + IWiaLog_Impl>(this: *mut core::ffi::c_void, hresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaLog_Impl::hResult(this, core::mem::transmute_copy(&hresult)).into()
+            }
+        }
+        unsafe extern "system" fn Log<Identity: windows_core::IUnknownImpl + IWiaLog_Impl>(this: *mut core::ffi::c_void, lflags: i32, lresid: i32, ldetail: i32, bstrtext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaLog_Impl::Log(this, core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&lresid), core::mem::transmute_copy(&ldetail), core::mem::transmute(&bstrtext)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InitializeLog: InitializeLog::<Identity>,
+            hResult: hResult::<Identity>,
+            Log: Log::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWiaLog {}
 windows_core::imp::define_interface!(IWiaLogEx, IWiaLogEx_Vtbl, 0xaf1f22ac_7a40_4787_b421_aeb47a1fbd0b);
 windows_core::imp::interface_hierarchy!(IWiaLogEx, windows_core::IUnknown);
@@ -2423,6 +3648,51 @@ impl IWiaLogEx_Vtbl {
         iid == &<IWiaLogEx as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWiaLogEx_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaLogEx_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InitializeLogEx<Identity: windows_core::IUnknownImpl + IWiaLogEx_Impl>(this: *mut core::ffi::c_void, hinstance: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaLogEx_Impl::InitializeLogEx(this, core::mem::transmute_copy(&hinstance)).into()
+            }
+        }
+        unsafe extern "system" fn hResult<Identity: windows_core::IUnknownImpl + IWiaLogEx_Impl>(this: *mut core::ffi::c_void, hresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaLogEx_Impl::hResult(this, core::mem::transmute_copy(&hresult)).into()
+            }
+        }
+        unsafe extern "system" fn Log<Identity: windows_core::IUnknownImpl + IWiaLogEx_Impl>(this: *mut core::ffi::c_void, lflags: i32, lresid: i32, ldetail: i32, bstrtext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaLogEx_Impl::Log(this, core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&lresid), core::mem::transmute_copy(&ldetail), core::mem::transmute(&bstrtext)).into()
+            }
+        }
+        unsafe extern "system" fn hResultEx<Identity: windows_core::IUnknownImpl + IWiaLogEx_Impl>(this: *mut core::ffi::c_void, lmethodid: i32, hresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaLogEx_Impl::hResultEx(this, core::mem::transmute_copy(&lmethodid), core::mem::transmute_copy(&hresult)).into()
+            }
+        }
+        unsafe extern "system" fn LogEx<Identity: windows_core::IUnknownImpl + IWiaLogEx_Impl>(this: *mut core::ffi::c_void, lmethodid: i32, lflags: i32, lresid: i32, ldetail: i32, bstrtext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaLogEx_Impl::LogEx(this, core::mem::transmute_copy(&lmethodid), core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&lresid), core::mem::transmute_copy(&ldetail), core::mem::transmute(&bstrtext)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InitializeLogEx: InitializeLogEx::<Identity>,
+            hResult: hResult::<Identity>,
+            Log: Log::<Identity>,
+            hResultEx: hResultEx::<Identity>,
+            LogEx: LogEx::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWiaLogEx {}
 windows_core::imp::define_interface!(IWiaMiniDrv, IWiaMiniDrv_Vtbl, 0xd8cdee14_3c6c_11d2_9a35_00c04fa36145);
 windows_core::imp::interface_hierarchy!(IWiaMiniDrv, windows_core::IUnknown);
@@ -2736,6 +4006,184 @@ impl IWiaMiniDrv_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl<Identity: windows_core::IUnknownImpl + IWiaMiniDrv_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaMiniDrv_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn drvInitializeWia<Identity: windows_core::IUnknownImpl + IWiaMiniDrv_Impl>(this: *mut core::ffi::c_void, __midl__iwiaminidrv0000: *const u8, __midl__iwiaminidrv0001: i32, __midl__iwiaminidrv0002: *mut core::ffi::c_void, __midl__iwiaminidrv0003: *mut core::ffi::c_void, __midl__iwiaminidrv0004: *mut core::ffi::c_void, __midl__iwiaminidrv0005: *mut core::ffi::c_void, __midl__iwiaminidrv0006: *mut *mut core::ffi::c_void, __midl__iwiaminidrv0007: *mut *mut core::ffi::c_void, __midl__iwiaminidrv0008: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaMiniDrv_Impl::drvInitializeWia(this, core::mem::transmute_copy(&__midl__iwiaminidrv0000), core::mem::transmute_copy(&__midl__iwiaminidrv0001), core::mem::transmute(&__midl__iwiaminidrv0002), core::mem::transmute(&__midl__iwiaminidrv0003), core::mem::transmute_copy(&__midl__iwiaminidrv0004), core::mem::transmute_copy(&__midl__iwiaminidrv0005), core::mem::transmute_copy(&__midl__iwiaminidrv0006), core::mem::transmute_copy(&__midl__iwiaminidrv0007), core::mem::transmute_copy(&__midl__iwiaminidrv0008)).into()
+            }
+        }
+        unsafe extern "system" fn drvAcquireItemData<Identity: windows_core::IUnknownImpl + IWiaMiniDrv_Impl>(this: *mut core::ffi::c_void, __midl__iwiaminidrv0009: *const u8, __midl__iwiaminidrv0010: i32, __midl__iwiaminidrv0011: *mut MINIDRV_TRANSFER_CONTEXT, __midl__iwiaminidrv0012: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaMiniDrv_Impl::drvAcquireItemData(this, core::mem::transmute_copy(&__midl__iwiaminidrv0009), core::mem::transmute_copy(&__midl__iwiaminidrv0010), core::mem::transmute_copy(&__midl__iwiaminidrv0011), core::mem::transmute_copy(&__midl__iwiaminidrv0012)).into()
+            }
+        }
+        unsafe extern "system" fn drvInitItemProperties<Identity: windows_core::IUnknownImpl + IWiaMiniDrv_Impl>(this: *mut core::ffi::c_void, __midl__iwiaminidrv0013: *const u8, __midl__iwiaminidrv0014: i32, __midl__iwiaminidrv0015: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaMiniDrv_Impl::drvInitItemProperties(this, core::mem::transmute_copy(&__midl__iwiaminidrv0013), core::mem::transmute_copy(&__midl__iwiaminidrv0014)) {
+                    Ok(ok__) => {
+                        __midl__iwiaminidrv0015.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn drvValidateItemProperties<Identity: windows_core::IUnknownImpl + IWiaMiniDrv_Impl>(this: *mut core::ffi::c_void, __midl__iwiaminidrv0016: *const u8, __midl__iwiaminidrv0017: i32, __midl__iwiaminidrv0018: u32, __midl__iwiaminidrv0019: *const super::super::System::Com::StructuredStorage::PROPSPEC, __midl__iwiaminidrv0020: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaMiniDrv_Impl::drvValidateItemProperties(this, core::mem::transmute_copy(&__midl__iwiaminidrv0016), core::mem::transmute_copy(&__midl__iwiaminidrv0017), core::mem::transmute_copy(&__midl__iwiaminidrv0018), core::mem::transmute_copy(&__midl__iwiaminidrv0019)) {
+                    Ok(ok__) => {
+                        __midl__iwiaminidrv0020.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn drvWriteItemProperties<Identity: windows_core::IUnknownImpl + IWiaMiniDrv_Impl>(this: *mut core::ffi::c_void, __midl__iwiaminidrv0021: *const u8, __midl__iwiaminidrv0022: i32, __midl__iwiaminidrv0023: *const MINIDRV_TRANSFER_CONTEXT, __midl__iwiaminidrv0024: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaMiniDrv_Impl::drvWriteItemProperties(this, core::mem::transmute_copy(&__midl__iwiaminidrv0021), core::mem::transmute_copy(&__midl__iwiaminidrv0022), core::mem::transmute_copy(&__midl__iwiaminidrv0023)) {
+                    Ok(ok__) => {
+                        __midl__iwiaminidrv0024.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn drvReadItemProperties<Identity: windows_core::IUnknownImpl + IWiaMiniDrv_Impl>(this: *mut core::ffi::c_void, __midl__iwiaminidrv0025: *const u8, __midl__iwiaminidrv0026: i32, __midl__iwiaminidrv0027: u32, __midl__iwiaminidrv0028: *const super::super::System::Com::StructuredStorage::PROPSPEC, __midl__iwiaminidrv0029: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaMiniDrv_Impl::drvReadItemProperties(this, core::mem::transmute_copy(&__midl__iwiaminidrv0025), core::mem::transmute_copy(&__midl__iwiaminidrv0026), core::mem::transmute_copy(&__midl__iwiaminidrv0027), core::mem::transmute_copy(&__midl__iwiaminidrv0028)) {
+                    Ok(ok__) => {
+                        __midl__iwiaminidrv0029.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn drvLockWiaDevice<Identity: windows_core::IUnknownImpl + IWiaMiniDrv_Impl>(this: *mut core::ffi::c_void, __midl__iwiaminidrv0030: *const u8, __midl__iwiaminidrv0031: i32, __midl__iwiaminidrv0032: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaMiniDrv_Impl::drvLockWiaDevice(this, core::mem::transmute_copy(&__midl__iwiaminidrv0030), core::mem::transmute_copy(&__midl__iwiaminidrv0031)) {
+                    Ok(ok__) => {
+                        __midl__iwiaminidrv0032.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn drvUnLockWiaDevice<Identity: windows_core::IUnknownImpl + IWiaMiniDrv_Impl>(this: *mut core::ffi::c_void, __midl__iwiaminidrv0033: *const u8, __midl__iwiaminidrv0034: i32, __midl__iwiaminidrv0035: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaMiniDrv_Impl::drvUnLockWiaDevice(this, core::mem::transmute_copy(&__midl__iwiaminidrv0033), core::mem::transmute_copy(&__midl__iwiaminidrv0034)) {
+                    Ok(ok__) => {
+                        __midl__iwiaminidrv0035.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn drvAnalyzeItem<Identity: windows_core::IUnknownImpl + IWiaMiniDrv_Impl>(this: *mut core::ffi::c_void, __midl__iwiaminidrv0036: *const u8, __midl__iwiaminidrv0037: i32, __midl__iwiaminidrv0038: *const i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaMiniDrv_Impl::drvAnalyzeItem(this, core::mem::transmute_copy(&__midl__iwiaminidrv0036), core::mem::transmute_copy(&__midl__iwiaminidrv0037), core::mem::transmute_copy(&__midl__iwiaminidrv0038)).into()
+            }
+        }
+        unsafe extern "system" fn drvGetDeviceErrorStr<Identity: windows_core::IUnknownImpl + IWiaMiniDrv_Impl>(this: *mut core::ffi::c_void, __midl__iwiaminidrv0039: i32, __midl__iwiaminidrv0040: i32, __midl__iwiaminidrv0041: *mut windows_core::PWSTR, __midl__iwiaminidrv0042: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaMiniDrv_Impl::drvGetDeviceErrorStr(this, core::mem::transmute_copy(&__midl__iwiaminidrv0039), core::mem::transmute_copy(&__midl__iwiaminidrv0040), core::mem::transmute_copy(&__midl__iwiaminidrv0041), core::mem::transmute_copy(&__midl__iwiaminidrv0042)).into()
+            }
+        }
+        unsafe extern "system" fn drvDeviceCommand<Identity: windows_core::IUnknownImpl + IWiaMiniDrv_Impl>(this: *mut core::ffi::c_void, __midl__iwiaminidrv0043: *const u8, __midl__iwiaminidrv0044: i32, __midl__iwiaminidrv0045: *const windows_core::GUID, __midl__iwiaminidrv0046: *mut *mut core::ffi::c_void, __midl__iwiaminidrv0047: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaMiniDrv_Impl::drvDeviceCommand(this, core::mem::transmute_copy(&__midl__iwiaminidrv0043), core::mem::transmute_copy(&__midl__iwiaminidrv0044), core::mem::transmute_copy(&__midl__iwiaminidrv0045), core::mem::transmute_copy(&__midl__iwiaminidrv0046), core::mem::transmute_copy(&__midl__iwiaminidrv0047)).into()
+            }
+        }
+        unsafe extern "system" fn drvGetCapabilities<Identity: windows_core::IUnknownImpl + IWiaMiniDrv_Impl>(this: *mut core::ffi::c_void, __midl__iwiaminidrv0048: *const u8, __midl__iwiaminidrv0049: i32, __midl__iwiaminidrv0050: *mut i32, __midl__iwiaminidrv0051: *mut *mut WIA_DEV_CAP_DRV, __midl__iwiaminidrv0052: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaMiniDrv_Impl::drvGetCapabilities(this, core::mem::transmute_copy(&__midl__iwiaminidrv0048), core::mem::transmute_copy(&__midl__iwiaminidrv0049), core::mem::transmute_copy(&__midl__iwiaminidrv0050), core::mem::transmute_copy(&__midl__iwiaminidrv0051), core::mem::transmute_copy(&__midl__iwiaminidrv0052)).into()
+            }
+        }
+        unsafe extern "system" fn drvDeleteItem<Identity: windows_core::IUnknownImpl + IWiaMiniDrv_Impl>(this: *mut core::ffi::c_void, __midl__iwiaminidrv0053: *const u8, __midl__iwiaminidrv0054: i32, __midl__iwiaminidrv0055: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaMiniDrv_Impl::drvDeleteItem(this, core::mem::transmute_copy(&__midl__iwiaminidrv0053), core::mem::transmute_copy(&__midl__iwiaminidrv0054)) {
+                    Ok(ok__) => {
+                        __midl__iwiaminidrv0055.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn drvFreeDrvItemContext<Identity: windows_core::IUnknownImpl + IWiaMiniDrv_Impl>(this: *mut core::ffi::c_void, __midl__iwiaminidrv0056: i32, __midl__iwiaminidrv0057: *const u8, __midl__iwiaminidrv0058: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaMiniDrv_Impl::drvFreeDrvItemContext(this, core::mem::transmute_copy(&__midl__iwiaminidrv0056), core::mem::transmute_copy(&__midl__iwiaminidrv0057)) {
+                    Ok(ok__) => {
+                        __midl__iwiaminidrv0058.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn drvGetWiaFormatInfo<Identity: windows_core::IUnknownImpl + IWiaMiniDrv_Impl>(this: *mut core::ffi::c_void, __midl__iwiaminidrv0059: *const u8, __midl__iwiaminidrv0060: i32, __midl__iwiaminidrv0061: *mut i32, __midl__iwiaminidrv0062: *mut *mut WIA_FORMAT_INFO, __midl__iwiaminidrv0063: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaMiniDrv_Impl::drvGetWiaFormatInfo(this, core::mem::transmute_copy(&__midl__iwiaminidrv0059), core::mem::transmute_copy(&__midl__iwiaminidrv0060), core::mem::transmute_copy(&__midl__iwiaminidrv0061), core::mem::transmute_copy(&__midl__iwiaminidrv0062), core::mem::transmute_copy(&__midl__iwiaminidrv0063)).into()
+            }
+        }
+        unsafe extern "system" fn drvNotifyPnpEvent<Identity: windows_core::IUnknownImpl + IWiaMiniDrv_Impl>(this: *mut core::ffi::c_void, peventguid: *const windows_core::GUID, bstrdeviceid: *mut core::ffi::c_void, ulreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaMiniDrv_Impl::drvNotifyPnpEvent(this, core::mem::transmute_copy(&peventguid), core::mem::transmute(&bstrdeviceid), core::mem::transmute_copy(&ulreserved)).into()
+            }
+        }
+        unsafe extern "system" fn drvUnInitializeWia<Identity: windows_core::IUnknownImpl + IWiaMiniDrv_Impl>(this: *mut core::ffi::c_void, __midl__iwiaminidrv0064: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaMiniDrv_Impl::drvUnInitializeWia(this, core::mem::transmute_copy(&__midl__iwiaminidrv0064)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            drvInitializeWia: drvInitializeWia::<Identity>,
+            drvAcquireItemData: drvAcquireItemData::<Identity>,
+            drvInitItemProperties: drvInitItemProperties::<Identity>,
+            drvValidateItemProperties: drvValidateItemProperties::<Identity>,
+            drvWriteItemProperties: drvWriteItemProperties::<Identity>,
+            drvReadItemProperties: drvReadItemProperties::<Identity>,
+            drvLockWiaDevice: drvLockWiaDevice::<Identity>,
+            drvUnLockWiaDevice: drvUnLockWiaDevice::<Identity>,
+            drvAnalyzeItem: drvAnalyzeItem::<Identity>,
+            drvGetDeviceErrorStr: drvGetDeviceErrorStr::<Identity>,
+            drvDeviceCommand: drvDeviceCommand::<Identity>,
+            drvGetCapabilities: drvGetCapabilities::<Identity>,
+            drvDeleteItem: drvDeleteItem::<Identity>,
+            drvFreeDrvItemContext: drvFreeDrvItemContext::<Identity>,
+            drvGetWiaFormatInfo: drvGetWiaFormatInfo::<Identity>,
+            drvNotifyPnpEvent: drvNotifyPnpEvent::<Identity>,
+            drvUnInitializeWia: drvUnInitializeWia::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
 impl windows_core::RuntimeName for IWiaMiniDrv {}
 windows_core::imp::define_interface!(IWiaMiniDrvCallBack, IWiaMiniDrvCallBack_Vtbl, 0x33a57d5a_3de8_11d2_9a36_00c04fa36145);
 windows_core::imp::interface_hierarchy!(IWiaMiniDrvCallBack, windows_core::IUnknown);
@@ -2767,6 +4215,20 @@ impl IWiaMiniDrvCallBack_Vtbl {
         iid == &<IWiaMiniDrvCallBack as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWiaMiniDrvCallBack_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaMiniDrvCallBack_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn MiniDrvCallback<Identity: windows_core::IUnknownImpl + IWiaMiniDrvCallBack_Impl>(this: *mut core::ffi::c_void, lreason: i32, lstatus: i32, lpercentcomplete: i32, loffset: i32, llength: i32, ptranctx: *const MINIDRV_TRANSFER_CONTEXT, lreserved: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaMiniDrvCallBack_Impl::MiniDrvCallback(this, core::mem::transmute_copy(&lreason), core::mem::transmute_copy(&lstatus), core::mem::transmute_copy(&lpercentcomplete), core::mem::transmute_copy(&loffset), core::mem::transmute_copy(&llength), core::mem::transmute_copy(&ptranctx), core::mem::transmute_copy(&lreserved)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, MiniDrvCallback: MiniDrvCallback::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWiaMiniDrvCallBack {}
 windows_core::imp::define_interface!(IWiaMiniDrvTransferCallback, IWiaMiniDrvTransferCallback_Vtbl, 0xa9d2ee89_2ce5_4ff0_8adb_c961d1d774ca);
 windows_core::imp::interface_hierarchy!(IWiaMiniDrvTransferCallback, windows_core::IUnknown);
@@ -2829,6 +4291,37 @@ impl IWiaMiniDrvTransferCallback_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IWiaMiniDrvTransferCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaMiniDrvTransferCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetNextStream<Identity: windows_core::IUnknownImpl + IWiaMiniDrvTransferCallback_Impl>(this: *mut core::ffi::c_void, lflags: i32, bstritemname: *mut core::ffi::c_void, bstrfullitemname: *mut core::ffi::c_void, ppistream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaMiniDrvTransferCallback_Impl::GetNextStream(this, core::mem::transmute_copy(&lflags), core::mem::transmute(&bstritemname), core::mem::transmute(&bstrfullitemname)) {
+                    Ok(ok__) => {
+                        ppistream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SendMessage<Identity: windows_core::IUnknownImpl + IWiaMiniDrvTransferCallback_Impl>(this: *mut core::ffi::c_void, lflags: i32, pwiatransferparams: *const WiaTransferParams) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaMiniDrvTransferCallback_Impl::SendMessage(this, core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&pwiatransferparams)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetNextStream: GetNextStream::<Identity>,
+            SendMessage: SendMessage::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IWiaMiniDrvTransferCallback {}
 windows_core::imp::define_interface!(IWiaNotifyDevMgr, IWiaNotifyDevMgr_Vtbl, 0x70681ea0_e7bf_4291_9fb1_4e8813a3f78e);
 windows_core::imp::interface_hierarchy!(IWiaNotifyDevMgr, windows_core::IUnknown);
@@ -2860,6 +4353,20 @@ impl IWiaNotifyDevMgr_Vtbl {
         iid == &<IWiaNotifyDevMgr as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWiaNotifyDevMgr_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaNotifyDevMgr_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn NewDeviceArrival<Identity: windows_core::IUnknownImpl + IWiaNotifyDevMgr_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaNotifyDevMgr_Impl::NewDeviceArrival(this).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, NewDeviceArrival: NewDeviceArrival::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWiaNotifyDevMgr {}
 windows_core::imp::define_interface!(IWiaPreview, IWiaPreview_Vtbl, 0x95c2b4fd_33f2_4d86_ad40_9431f0df08f7);
 windows_core::imp::interface_hierarchy!(IWiaPreview, windows_core::IUnknown);
@@ -2938,6 +4445,44 @@ impl IWiaPreview_Vtbl {
         iid == &<IWiaPreview as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWiaPreview_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaPreview_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetNewPreview<Identity: windows_core::IUnknownImpl + IWiaPreview_Impl>(this: *mut core::ffi::c_void, lflags: i32, pwiaitem2: *mut core::ffi::c_void, pwiatransfercallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaPreview_Impl::GetNewPreview(this, core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&pwiaitem2), core::mem::transmute_copy(&pwiatransfercallback)).into()
+            }
+        }
+        unsafe extern "system" fn UpdatePreview<Identity: windows_core::IUnknownImpl + IWiaPreview_Impl>(this: *mut core::ffi::c_void, lflags: i32, pchildwiaitem2: *mut core::ffi::c_void, pwiatransfercallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaPreview_Impl::UpdatePreview(this, core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&pchildwiaitem2), core::mem::transmute_copy(&pwiatransfercallback)).into()
+            }
+        }
+        unsafe extern "system" fn DetectRegions<Identity: windows_core::IUnknownImpl + IWiaPreview_Impl>(this: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaPreview_Impl::DetectRegions(this, core::mem::transmute_copy(&lflags)).into()
+            }
+        }
+        unsafe extern "system" fn Clear<Identity: windows_core::IUnknownImpl + IWiaPreview_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaPreview_Impl::Clear(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetNewPreview: GetNewPreview::<Identity>,
+            UpdatePreview: UpdatePreview::<Identity>,
+            DetectRegions: DetectRegions::<Identity>,
+            Clear: Clear::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWiaPreview {}
 windows_core::imp::define_interface!(IWiaPropertyStorage, IWiaPropertyStorage_Vtbl, 0x98b5e8a0_29cc_491a_aac0_e6db4fdcceb6);
 windows_core::imp::interface_hierarchy!(IWiaPropertyStorage, windows_core::IUnknown);
@@ -3208,6 +4753,141 @@ impl IWiaPropertyStorage_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWiaPropertyStorage_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaPropertyStorage_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ReadMultiple<Identity: windows_core::IUnknownImpl + IWiaPropertyStorage_Impl>(this: *mut core::ffi::c_void, cpspec: u32, rgpspec: *const super::super::System::Com::StructuredStorage::PROPSPEC, rgpropvar: *mut super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaPropertyStorage_Impl::ReadMultiple(this, core::mem::transmute_copy(&cpspec), core::mem::transmute_copy(&rgpspec), core::mem::transmute_copy(&rgpropvar)).into()
+            }
+        }
+        unsafe extern "system" fn WriteMultiple<Identity: windows_core::IUnknownImpl + IWiaPropertyStorage_Impl>(this: *mut core::ffi::c_void, cpspec: u32, rgpspec: *const super::super::System::Com::StructuredStorage::PROPSPEC, rgpropvar: *const super::super::System::Com::StructuredStorage::PROPVARIANT, propidnamefirst: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaPropertyStorage_Impl::WriteMultiple(this, core::mem::transmute_copy(&cpspec), core::mem::transmute_copy(&rgpspec), core::mem::transmute_copy(&rgpropvar), core::mem::transmute_copy(&propidnamefirst)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteMultiple<Identity: windows_core::IUnknownImpl + IWiaPropertyStorage_Impl>(this: *mut core::ffi::c_void, cpspec: u32, rgpspec: *const super::super::System::Com::StructuredStorage::PROPSPEC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaPropertyStorage_Impl::DeleteMultiple(this, core::mem::transmute_copy(&cpspec), core::mem::transmute_copy(&rgpspec)).into()
+            }
+        }
+        unsafe extern "system" fn ReadPropertyNames<Identity: windows_core::IUnknownImpl + IWiaPropertyStorage_Impl>(this: *mut core::ffi::c_void, cpropid: u32, rgpropid: *const u32, rglpwstrname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaPropertyStorage_Impl::ReadPropertyNames(this, core::mem::transmute_copy(&cpropid), core::mem::transmute_copy(&rgpropid), core::mem::transmute_copy(&rglpwstrname)).into()
+            }
+        }
+        unsafe extern "system" fn WritePropertyNames<Identity: windows_core::IUnknownImpl + IWiaPropertyStorage_Impl>(this: *mut core::ffi::c_void, cpropid: u32, rgpropid: *const u32, rglpwstrname: *const windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaPropertyStorage_Impl::WritePropertyNames(this, core::mem::transmute_copy(&cpropid), core::mem::transmute_copy(&rgpropid), core::mem::transmute_copy(&rglpwstrname)).into()
+            }
+        }
+        unsafe extern "system" fn DeletePropertyNames<Identity: windows_core::IUnknownImpl + IWiaPropertyStorage_Impl>(this: *mut core::ffi::c_void, cpropid: u32, rgpropid: *const u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaPropertyStorage_Impl::DeletePropertyNames(this, core::mem::transmute_copy(&cpropid), core::mem::transmute_copy(&rgpropid)).into()
+            }
+        }
+        unsafe extern "system" fn Commit<Identity: windows_core::IUnknownImpl + IWiaPropertyStorage_Impl>(this: *mut core::ffi::c_void, grfcommitflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaPropertyStorage_Impl::Commit(this, core::mem::transmute_copy(&grfcommitflags)).into()
+            }
+        }
+        unsafe extern "system" fn Revert<Identity: windows_core::IUnknownImpl + IWiaPropertyStorage_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaPropertyStorage_Impl::Revert(this).into()
+            }
+        }
+        unsafe extern "system" fn Enum<Identity: windows_core::IUnknownImpl + IWiaPropertyStorage_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaPropertyStorage_Impl::Enum(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTimes<Identity: windows_core::IUnknownImpl + IWiaPropertyStorage_Impl>(this: *mut core::ffi::c_void, pctime: *const super::super::Foundation::FILETIME, patime: *const super::super::Foundation::FILETIME, pmtime: *const super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaPropertyStorage_Impl::SetTimes(this, core::mem::transmute_copy(&pctime), core::mem::transmute_copy(&patime), core::mem::transmute_copy(&pmtime)).into()
+            }
+        }
+        unsafe extern "system" fn SetClass<Identity: windows_core::IUnknownImpl + IWiaPropertyStorage_Impl>(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaPropertyStorage_Impl::SetClass(this, core::mem::transmute_copy(&clsid)).into()
+            }
+        }
+        unsafe extern "system" fn Stat<Identity: windows_core::IUnknownImpl + IWiaPropertyStorage_Impl>(this: *mut core::ffi::c_void, pstatpsstg: *mut super::super::System::Com::StructuredStorage::STATPROPSETSTG) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaPropertyStorage_Impl::Stat(this, core::mem::transmute_copy(&pstatpsstg)).into()
+            }
+        }
+        unsafe extern "system" fn GetPropertyAttributes<Identity: windows_core::IUnknownImpl + IWiaPropertyStorage_Impl>(this: *mut core::ffi::c_void, cpspec: u32, rgpspec: *const super::super::System::Com::StructuredStorage::PROPSPEC, rgflags: *mut u32, rgpropvar: *mut super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaPropertyStorage_Impl::GetPropertyAttributes(this, core::mem::transmute_copy(&cpspec), core::mem::transmute_copy(&rgpspec), core::mem::transmute_copy(&rgflags), core::mem::transmute_copy(&rgpropvar)).into()
+            }
+        }
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IWiaPropertyStorage_Impl>(this: *mut core::ffi::c_void, pulnumprops: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaPropertyStorage_Impl::GetCount(this) {
+                    Ok(ok__) => {
+                        pulnumprops.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPropertyStream<Identity: windows_core::IUnknownImpl + IWiaPropertyStorage_Impl>(this: *mut core::ffi::c_void, pcompatibilityid: *mut windows_core::GUID, ppistream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaPropertyStorage_Impl::GetPropertyStream(this, core::mem::transmute_copy(&pcompatibilityid), core::mem::transmute_copy(&ppistream)).into()
+            }
+        }
+        unsafe extern "system" fn SetPropertyStream<Identity: windows_core::IUnknownImpl + IWiaPropertyStorage_Impl>(this: *mut core::ffi::c_void, pcompatibilityid: *mut windows_core::GUID, pistream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaPropertyStorage_Impl::SetPropertyStream(this, core::mem::transmute_copy(&pcompatibilityid), core::mem::transmute_copy(&pistream)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ReadMultiple: ReadMultiple::<Identity>,
+            WriteMultiple: WriteMultiple::<Identity>,
+            DeleteMultiple: DeleteMultiple::<Identity>,
+            ReadPropertyNames: ReadPropertyNames::<Identity>,
+            WritePropertyNames: WritePropertyNames::<Identity>,
+            DeletePropertyNames: DeletePropertyNames::<Identity>,
+            Commit: Commit::<Identity>,
+            Revert: Revert::<Identity>,
+            Enum: Enum::<Identity>,
+            SetTimes: SetTimes::<Identity>,
+            SetClass: SetClass::<Identity>,
+            Stat: Stat::<Identity>,
+            GetPropertyAttributes: GetPropertyAttributes::<Identity>,
+            GetCount: GetCount::<Identity>,
+            GetPropertyStream: GetPropertyStream::<Identity>,
+            SetPropertyStream: SetPropertyStream::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWiaPropertyStorage {}
 windows_core::imp::define_interface!(IWiaSegmentationFilter, IWiaSegmentationFilter_Vtbl, 0xec46a697_ac04_4447_8f65_ff63d5154b21);
 windows_core::imp::interface_hierarchy!(IWiaSegmentationFilter, windows_core::IUnknown);
@@ -3250,6 +4930,21 @@ impl IWiaSegmentationFilter_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IWiaSegmentationFilter_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaSegmentationFilter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DetectRegions<Identity: windows_core::IUnknownImpl + IWiaSegmentationFilter_Impl>(this: *mut core::ffi::c_void, lflags: i32, pinputstream: *mut core::ffi::c_void, pwiaitem2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaSegmentationFilter_Impl::DetectRegions(this, core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&pinputstream), core::mem::transmute_copy(&pwiaitem2)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, DetectRegions: DetectRegions::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IWiaSegmentationFilter {}
 windows_core::imp::define_interface!(IWiaTransfer, IWiaTransfer_Vtbl, 0xc39d6942_2f4e_4d04_92fe_4ef4d3a1de5a);
 windows_core::imp::interface_hierarchy!(IWiaTransfer, windows_core::IUnknown);
@@ -3343,6 +5038,51 @@ impl IWiaTransfer_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IWiaTransfer_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaTransfer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Download<Identity: windows_core::IUnknownImpl + IWiaTransfer_Impl>(this: *mut core::ffi::c_void, lflags: i32, piwiatransfercallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaTransfer_Impl::Download(this, core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&piwiatransfercallback)).into()
+            }
+        }
+        unsafe extern "system" fn Upload<Identity: windows_core::IUnknownImpl + IWiaTransfer_Impl>(this: *mut core::ffi::c_void, lflags: i32, psource: *mut core::ffi::c_void, piwiatransfercallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaTransfer_Impl::Upload(this, core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&psource), core::mem::transmute_copy(&piwiatransfercallback)).into()
+            }
+        }
+        unsafe extern "system" fn Cancel<Identity: windows_core::IUnknownImpl + IWiaTransfer_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaTransfer_Impl::Cancel(this).into()
+            }
+        }
+        unsafe extern "system" fn EnumWIA_FORMAT_INFO<Identity: windows_core::IUnknownImpl + IWiaTransfer_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaTransfer_Impl::EnumWIA_FORMAT_INFO(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Download: Download::<Identity>,
+            Upload: Upload::<Identity>,
+            Cancel: Cancel::<Identity>,
+            EnumWIA_FORMAT_INFO: EnumWIA_FORMAT_INFO::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IWiaTransfer {}
 windows_core::imp::define_interface!(IWiaTransferCallback, IWiaTransferCallback_Vtbl, 0x27d4eaaf_28a6_4ca5_9aab_e678168b9527);
 windows_core::imp::interface_hierarchy!(IWiaTransferCallback, windows_core::IUnknown);
@@ -3405,6 +5145,37 @@ impl IWiaTransferCallback_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IWiaTransferCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaTransferCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn TransferCallback<Identity: windows_core::IUnknownImpl + IWiaTransferCallback_Impl>(this: *mut core::ffi::c_void, lflags: i32, pwiatransferparams: *const WiaTransferParams) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaTransferCallback_Impl::TransferCallback(this, core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&pwiatransferparams)).into()
+            }
+        }
+        unsafe extern "system" fn GetNextStream<Identity: windows_core::IUnknownImpl + IWiaTransferCallback_Impl>(this: *mut core::ffi::c_void, lflags: i32, bstritemname: *mut core::ffi::c_void, bstrfullitemname: *mut core::ffi::c_void, ppdestination: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaTransferCallback_Impl::GetNextStream(this, core::mem::transmute_copy(&lflags), core::mem::transmute(&bstritemname), core::mem::transmute(&bstrfullitemname)) {
+                    Ok(ok__) => {
+                        ppdestination.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            TransferCallback: TransferCallback::<Identity>,
+            GetNextStream: GetNextStream::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IWiaTransferCallback {}
 windows_core::imp::define_interface!(IWiaUIExtension, IWiaUIExtension_Vtbl, 0xda319113_50ee_4c80_b460_57d005d44a2c);
 windows_core::imp::interface_hierarchy!(IWiaUIExtension, windows_core::IUnknown);
@@ -3474,6 +5245,38 @@ impl IWiaUIExtension_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+impl<Identity: windows_core::IUnknownImpl + IWiaUIExtension_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaUIExtension_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DeviceDialog<Identity: windows_core::IUnknownImpl + IWiaUIExtension_Impl>(this: *mut core::ffi::c_void, pdevicedialogdata: *const DEVICEDIALOGDATA) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaUIExtension_Impl::DeviceDialog(this, core::mem::transmute_copy(&pdevicedialogdata)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceIcon<Identity: windows_core::IUnknownImpl + IWiaUIExtension_Impl>(this: *mut core::ffi::c_void, bstrdeviceid: *mut core::ffi::c_void, phicon: *mut super::super::UI::WindowsAndMessaging::HICON, nsize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaUIExtension_Impl::GetDeviceIcon(this, core::mem::transmute(&bstrdeviceid), core::mem::transmute_copy(&phicon), core::mem::transmute_copy(&nsize)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceBitmapLogo<Identity: windows_core::IUnknownImpl + IWiaUIExtension_Impl>(this: *mut core::ffi::c_void, bstrdeviceid: *mut core::ffi::c_void, phbitmap: *mut super::super::Graphics::Gdi::HBITMAP, nmaxwidth: u32, nmaxheight: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaUIExtension_Impl::GetDeviceBitmapLogo(this, core::mem::transmute(&bstrdeviceid), core::mem::transmute_copy(&phbitmap), core::mem::transmute_copy(&nmaxwidth), core::mem::transmute_copy(&nmaxheight)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DeviceDialog: DeviceDialog::<Identity>,
+            GetDeviceIcon: GetDeviceIcon::<Identity>,
+            GetDeviceBitmapLogo: GetDeviceBitmapLogo::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
 impl windows_core::RuntimeName for IWiaUIExtension {}
 windows_core::imp::define_interface!(IWiaUIExtension2, IWiaUIExtension2_Vtbl, 0x305600d7_5088_46d7_9a15_b77b09cdba7a);
 windows_core::imp::interface_hierarchy!(IWiaUIExtension2, windows_core::IUnknown);
@@ -3527,6 +5330,31 @@ impl IWiaUIExtension2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl<Identity: windows_core::IUnknownImpl + IWiaUIExtension2_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaUIExtension2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DeviceDialog<Identity: windows_core::IUnknownImpl + IWiaUIExtension2_Impl>(this: *mut core::ffi::c_void, pdevicedialogdata: *const DEVICEDIALOGDATA2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaUIExtension2_Impl::DeviceDialog(this, core::mem::transmute_copy(&pdevicedialogdata)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceIcon<Identity: windows_core::IUnknownImpl + IWiaUIExtension2_Impl>(this: *mut core::ffi::c_void, bstrdeviceid: *mut core::ffi::c_void, phicon: *mut super::super::UI::WindowsAndMessaging::HICON, nsize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaUIExtension2_Impl::GetDeviceIcon(this, core::mem::transmute(&bstrdeviceid), core::mem::transmute_copy(&phicon), core::mem::transmute_copy(&nsize)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DeviceDialog: DeviceDialog::<Identity>,
+            GetDeviceIcon: GetDeviceIcon::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
 impl windows_core::RuntimeName for IWiaUIExtension2 {}
 windows_core::imp::define_interface!(IWiaVideo, IWiaVideo_Vtbl, 0xd52920aa_db88_41f0_946c_e00dc0a19cfa);
 windows_core::imp::interface_hierarchy!(IWiaVideo, windows_core::IUnknown);
@@ -3741,6 +5569,131 @@ impl IWiaVideo_Vtbl {
         iid == &<IWiaVideo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWiaVideo_Impl> windows_core::imp::TearOffVtable<Identity> for IWiaVideo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn PreviewVisible<Identity: windows_core::IUnknownImpl + IWiaVideo_Impl>(this: *mut core::ffi::c_void, pbpreviewvisible: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaVideo_Impl::PreviewVisible(this) {
+                    Ok(ok__) => {
+                        pbpreviewvisible.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPreviewVisible<Identity: windows_core::IUnknownImpl + IWiaVideo_Impl>(this: *mut core::ffi::c_void, bpreviewvisible: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaVideo_Impl::SetPreviewVisible(this, core::mem::transmute_copy(&bpreviewvisible)).into()
+            }
+        }
+        unsafe extern "system" fn ImagesDirectory<Identity: windows_core::IUnknownImpl + IWiaVideo_Impl>(this: *mut core::ffi::c_void, pbstrimagedirectory: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaVideo_Impl::ImagesDirectory(this) {
+                    Ok(ok__) => {
+                        pbstrimagedirectory.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetImagesDirectory<Identity: windows_core::IUnknownImpl + IWiaVideo_Impl>(this: *mut core::ffi::c_void, bstrimagedirectory: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaVideo_Impl::SetImagesDirectory(this, core::mem::transmute(&bstrimagedirectory)).into()
+            }
+        }
+        unsafe extern "system" fn CreateVideoByWiaDevID<Identity: windows_core::IUnknownImpl + IWiaVideo_Impl>(this: *mut core::ffi::c_void, bstrwiadeviceid: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, bstretchtofitparent: windows_core::BOOL, bautobeginplayback: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaVideo_Impl::CreateVideoByWiaDevID(this, core::mem::transmute(&bstrwiadeviceid), core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&bstretchtofitparent), core::mem::transmute_copy(&bautobeginplayback)).into()
+            }
+        }
+        unsafe extern "system" fn CreateVideoByDevNum<Identity: windows_core::IUnknownImpl + IWiaVideo_Impl>(this: *mut core::ffi::c_void, uidevicenumber: u32, hwndparent: super::super::Foundation::HWND, bstretchtofitparent: windows_core::BOOL, bautobeginplayback: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaVideo_Impl::CreateVideoByDevNum(this, core::mem::transmute_copy(&uidevicenumber), core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&bstretchtofitparent), core::mem::transmute_copy(&bautobeginplayback)).into()
+            }
+        }
+        unsafe extern "system" fn CreateVideoByName<Identity: windows_core::IUnknownImpl + IWiaVideo_Impl>(this: *mut core::ffi::c_void, bstrfriendlyname: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, bstretchtofitparent: windows_core::BOOL, bautobeginplayback: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaVideo_Impl::CreateVideoByName(this, core::mem::transmute(&bstrfriendlyname), core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&bstretchtofitparent), core::mem::transmute_copy(&bautobeginplayback)).into()
+            }
+        }
+        unsafe extern "system" fn DestroyVideo<Identity: windows_core::IUnknownImpl + IWiaVideo_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaVideo_Impl::DestroyVideo(this).into()
+            }
+        }
+        unsafe extern "system" fn Play<Identity: windows_core::IUnknownImpl + IWiaVideo_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaVideo_Impl::Play(this).into()
+            }
+        }
+        unsafe extern "system" fn Pause<Identity: windows_core::IUnknownImpl + IWiaVideo_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaVideo_Impl::Pause(this).into()
+            }
+        }
+        unsafe extern "system" fn TakePicture<Identity: windows_core::IUnknownImpl + IWiaVideo_Impl>(this: *mut core::ffi::c_void, pbstrnewimagefilename: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaVideo_Impl::TakePicture(this) {
+                    Ok(ok__) => {
+                        pbstrnewimagefilename.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ResizeVideo<Identity: windows_core::IUnknownImpl + IWiaVideo_Impl>(this: *mut core::ffi::c_void, bstretchtofitparent: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWiaVideo_Impl::ResizeVideo(this, core::mem::transmute_copy(&bstretchtofitparent)).into()
+            }
+        }
+        unsafe extern "system" fn GetCurrentState<Identity: windows_core::IUnknownImpl + IWiaVideo_Impl>(this: *mut core::ffi::c_void, pstate: *mut WIAVIDEO_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWiaVideo_Impl::GetCurrentState(this) {
+                    Ok(ok__) => {
+                        pstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PreviewVisible: PreviewVisible::<Identity>,
+            SetPreviewVisible: SetPreviewVisible::<Identity>,
+            ImagesDirectory: ImagesDirectory::<Identity>,
+            SetImagesDirectory: SetImagesDirectory::<Identity>,
+            CreateVideoByWiaDevID: CreateVideoByWiaDevID::<Identity>,
+            CreateVideoByDevNum: CreateVideoByDevNum::<Identity>,
+            CreateVideoByName: CreateVideoByName::<Identity>,
+            DestroyVideo: DestroyVideo::<Identity>,
+            Play: Play::<Identity>,
+            Pause: Pause::<Identity>,
+            TakePicture: TakePicture::<Identity>,
+            ResizeVideo: ResizeVideo::<Identity>,
+            GetCurrentState: GetCurrentState::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWiaVideo {}
 pub const LAMP_ERR: u32 = 2048u32;
 pub const LANDSCAPE: u32 = 1u32;
@@ -369,6 +369,36 @@ impl ILocationPermissions_Vtbl {
         iid == &<ILocationPermissions as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ILocationPermissions_Impl> windows_core::imp::TearOffVtable<Identity> for ILocationPermissions_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetGlobalLocationPermission<Identity: windows_core::IUnknownImpl + ILocationPermissions_Impl>(this: *mut core::ffi::c_void, pfenabled: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ILocationPermissions_Impl::GetGlobalLocationPermission(this) {
+                    Ok(ok__) => {
+                        pfenabled.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CheckLocationCapability<Identity: windows_core::IUnknownImpl + ILocationPermissions_Impl>(this: *mut core::ffi::c_void, dwclientthreadid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILocationPermissions_Impl::CheckLocationCapability(this, core::mem::transmute_copy(&dwclientthreadid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetGlobalLocationPermission: GetGlobalLocationPermission::<Identity>,
+            CheckLocationCapability: CheckLocationCapability::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ILocationPermissions {}
 windows_core::imp::define_interface!(ISensor, ISensor_Vtbl, 0x5fa08f80_2657_458e_af75_46f73fa6ac5c);
 windows_core::imp::interface_hierarchy!(ISensor, windows_core::IUnknown);
@@ -707,6 +737,194 @@ impl ISensor_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Devices_PortableDevices", feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISensor_Impl> windows_core::imp::TearOffVtable<Identity> for ISensor_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetID<Identity: windows_core::IUnknownImpl + ISensor_Impl>(this: *mut core::ffi::c_void, pid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISensor_Impl::GetID(this) {
+                    Ok(ok__) => {
+                        pid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCategory<Identity: windows_core::IUnknownImpl + ISensor_Impl>(this: *mut core::ffi::c_void, psensorcategory: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISensor_Impl::GetCategory(this) {
+                    Ok(ok__) => {
+                        psensorcategory.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetType<Identity: windows_core::IUnknownImpl + ISensor_Impl>(this: *mut core::ffi::c_void, psensortype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISensor_Impl::GetType(this) {
+                    Ok(ok__) => {
+                        psensortype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFriendlyName<Identity: windows_core::IUnknownImpl + ISensor_Impl>(this: *mut core::ffi::c_void, pfriendlyname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISensor_Impl::GetFriendlyName(this) {
+                    Ok(ok__) => {
+                        pfriendlyname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetProperty<Identity: windows_core::IUnknownImpl + ISensor_Impl>(this: *mut core::ffi::c_void, key: *const super::super::Foundation::PROPERTYKEY, pproperty: *mut super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISensor_Impl::GetProperty(this, core::mem::transmute_copy(&key)) {
+                    Ok(ok__) => {
+                        pproperty.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + ISensor_Impl>(this: *mut core::ffi::c_void, pkeys: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISensor_Impl::GetProperties(this, core::mem::transmute_copy(&pkeys)) {
+                    Ok(ok__) => {
+                        ppproperties.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSupportedDataFields<Identity: windows_core::IUnknownImpl + ISensor_Impl>(this: *mut core::ffi::c_void, ppdatafields: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISensor_Impl::GetSupportedDataFields(this) {
+                    Ok(ok__) => {
+                        ppdatafields.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProperties<Identity: windows_core::IUnknownImpl + ISensor_Impl>(this: *mut core::ffi::c_void, pproperties: *mut core::ffi::c_void, ppresults: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISensor_Impl::SetProperties(this, core::mem::transmute_copy(&pproperties)) {
+                    Ok(ok__) => {
+                        ppresults.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SupportsDataField<Identity: windows_core::IUnknownImpl + ISensor_Impl>(this: *mut core::ffi::c_void, key: *const super::super::Foundation::PROPERTYKEY, pissupported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISensor_Impl::SupportsDataField(this, core::mem::transmute_copy(&key)) {
+                    Ok(ok__) => {
+                        pissupported.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetState<Identity: windows_core::IUnknownImpl + ISensor_Impl>(this: *mut core::ffi::c_void, pstate: *mut SensorState) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISensor_Impl::GetState(this) {
+                    Ok(ok__) => {
+                        pstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetData<Identity: windows_core::IUnknownImpl + ISensor_Impl>(this: *mut core::ffi::c_void, ppdatareport: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISensor_Impl::GetData(this) {
+                    Ok(ok__) => {
+                        ppdatareport.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SupportsEvent<Identity: windows_core::IUnknownImpl + ISensor_Impl>(this: *mut core::ffi::c_void, eventguid: *const windows_core::GUID, pissupported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISensor_Impl::SupportsEvent(this, core::mem::transmute_copy(&eventguid)) {
+                    Ok(ok__) => {
+                        pissupported.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetEventInterest<Identity: windows_core::IUnknownImpl + ISensor_Impl>(this: *mut core::ffi::c_void, ppvalues: *mut *mut windows_core::GUID, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensor_Impl::GetEventInterest(this, core::mem::transmute_copy(&ppvalues), core::mem::transmute_copy(&pcount)).into()
+            }
+        }
+        unsafe extern "system" fn SetEventInterest<Identity: windows_core::IUnknownImpl + ISensor_Impl>(this: *mut core::ffi::c_void, pvalues: *const windows_core::GUID, count: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensor_Impl::SetEventInterest(this, core::mem::transmute_copy(&pvalues), core::mem::transmute_copy(&count)).into()
+            }
+        }
+        unsafe extern "system" fn SetEventSink<Identity: windows_core::IUnknownImpl + ISensor_Impl>(this: *mut core::ffi::c_void, pevents: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensor_Impl::SetEventSink(this, core::mem::transmute_copy(&pevents)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetID: GetID::<Identity>,
+            GetCategory: GetCategory::<Identity>,
+            GetType: GetType::<Identity>,
+            GetFriendlyName: GetFriendlyName::<Identity>,
+            GetProperty: GetProperty::<Identity>,
+            GetProperties: GetProperties::<Identity>,
+            GetSupportedDataFields: GetSupportedDataFields::<Identity>,
+            SetProperties: SetProperties::<Identity>,
+            SupportsDataField: SupportsDataField::<Identity>,
+            GetState: GetState::<Identity>,
+            GetData: GetData::<Identity>,
+            SupportsEvent: SupportsEvent::<Identity>,
+            GetEventInterest: GetEventInterest::<Identity>,
+            SetEventInterest: SetEventInterest::<Identity>,
+            SetEventSink: SetEventSink::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Devices_PortableDevices", feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISensor {}
 windows_core::imp::define_interface!(ISensorCollection, ISensorCollection_Vtbl, 0x23571e11_e545_4dd8_a337_b89bf44b10df);
 windows_core::imp::interface_hierarchy!(ISensorCollection, windows_core::IUnknown);
@@ -825,6 +1043,70 @@ impl ISensorCollection_Vtbl {
         iid == &<ISensorCollection as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISensorCollection_Impl> windows_core::imp::TearOffVtable<Identity> for ISensorCollection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAt<Identity: windows_core::IUnknownImpl + ISensorCollection_Impl>(this: *mut core::ffi::c_void, ulindex: u32, ppsensor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISensorCollection_Impl::GetAt(this, core::mem::transmute_copy(&ulindex)) {
+                    Ok(ok__) => {
+                        ppsensor.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + ISensorCollection_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISensorCollection_Impl::GetCount(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Add<Identity: windows_core::IUnknownImpl + ISensorCollection_Impl>(this: *mut core::ffi::c_void, psensor: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensorCollection_Impl::Add(this, core::mem::transmute_copy(&psensor)).into()
+            }
+        }
+        unsafe extern "system" fn Remove<Identity: windows_core::IUnknownImpl + ISensorCollection_Impl>(this: *mut core::ffi::c_void, psensor: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensorCollection_Impl::Remove(this, core::mem::transmute_copy(&psensor)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveByID<Identity: windows_core::IUnknownImpl + ISensorCollection_Impl>(this: *mut core::ffi::c_void, sensorid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensorCollection_Impl::RemoveByID(this, core::mem::transmute_copy(&sensorid)).into()
+            }
+        }
+        unsafe extern "system" fn Clear<Identity: windows_core::IUnknownImpl + ISensorCollection_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensorCollection_Impl::Clear(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAt: GetAt::<Identity>,
+            GetCount: GetCount::<Identity>,
+            Add: Add::<Identity>,
+            Remove: Remove::<Identity>,
+            RemoveByID: RemoveByID::<Identity>,
+            Clear: Clear::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISensorCollection {}
 windows_core::imp::define_interface!(ISensorDataReport, ISensorDataReport_Vtbl, 0x0ab9df9b_c4b5_4796_8898_0470706a2e1d);
 windows_core::imp::interface_hierarchy!(ISensorDataReport, windows_core::IUnknown);
@@ -924,6 +1206,56 @@ impl ISensorDataReport_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Devices_PortableDevices", feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISensorDataReport_Impl> windows_core::imp::TearOffVtable<Identity> for ISensorDataReport_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetTimestamp<Identity: windows_core::IUnknownImpl + ISensorDataReport_Impl>(this: *mut core::ffi::c_void, ptimestamp: *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISensorDataReport_Impl::GetTimestamp(this) {
+                    Ok(ok__) => {
+                        ptimestamp.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSensorValue<Identity: windows_core::IUnknownImpl + ISensorDataReport_Impl>(this: *mut core::ffi::c_void, pkey: *const super::super::Foundation::PROPERTYKEY, pvalue: *mut super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISensorDataReport_Impl::GetSensorValue(this, core::mem::transmute_copy(&pkey)) {
+                    Ok(ok__) => {
+                        pvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSensorValues<Identity: windows_core::IUnknownImpl + ISensorDataReport_Impl>(this: *mut core::ffi::c_void, pkeys: *mut core::ffi::c_void, ppvalues: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISensorDataReport_Impl::GetSensorValues(this, core::mem::transmute_copy(&pkeys)) {
+                    Ok(ok__) => {
+                        ppvalues.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetTimestamp: GetTimestamp::<Identity>,
+            GetSensorValue: GetSensorValue::<Identity>,
+            GetSensorValues: GetSensorValues::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Devices_PortableDevices", feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISensorDataReport {}
 windows_core::imp::define_interface!(ISensorEvents, ISensorEvents_Vtbl, 0x5d8dcc91_4641_47e7_b7c3_b74f48a6c391);
 windows_core::imp::interface_hierarchy!(ISensorEvents, windows_core::IUnknown);
@@ -1012,6 +1344,45 @@ impl ISensorEvents_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Devices_PortableDevices")]
+impl<Identity: windows_core::IUnknownImpl + ISensorEvents_Impl> windows_core::imp::TearOffVtable<Identity> for ISensorEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnStateChanged<Identity: windows_core::IUnknownImpl + ISensorEvents_Impl>(this: *mut core::ffi::c_void, psensor: *mut core::ffi::c_void, state: SensorState) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensorEvents_Impl::OnStateChanged(this, core::mem::transmute_copy(&psensor), core::mem::transmute_copy(&state)).into()
+            }
+        }
+        unsafe extern "system" fn OnDataUpdated<Identity: windows_core::IUnknownImpl + ISensorEvents_Impl>(this: *mut core::ffi::c_void, psensor: *mut core::ffi::c_void, pnewdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensorEvents_Impl::OnDataUpdated(this, core::mem::transmute_copy(&psensor), core::mem::transmute_copy(&pnewdata)).into()
+            }
+        }
+        unsafe extern "system" fn OnEvent<Identity: windows_core::IUnknownImpl + ISensorEvents_Impl>(this: *mut core::ffi::c_void, psensor: *mut core::ffi::c_void, eventid: *const windows_core::GUID, peventdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensorEvents_Impl::OnEvent(this, core::mem::transmute_copy(&psensor), core::mem::transmute_copy(&eventid), core::mem::transmute_copy(&peventdata)).into()
+            }
+        }
+        unsafe extern "system" fn OnLeave<Identity: windows_core::IUnknownImpl + ISensorEvents_Impl>(this: *mut core::ffi::c_void, id: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensorEvents_Impl::OnLeave(this, core::mem::transmute_copy(&id)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnStateChanged: OnStateChanged::<Identity>,
+            OnDataUpdated: OnDataUpdated::<Identity>,
+            OnEvent: OnEvent::<Identity>,
+            OnLeave: OnLeave::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Devices_PortableDevices")]
 impl windows_core::RuntimeName for ISensorEvents {}
 windows_core::imp::define_interface!(ISensorManager, ISensorManager_Vtbl, 0xbd77db67_45a8_42dc_8d00_6dcf15f8377a);
 windows_core::imp::interface_hierarchy!(ISensorManager, windows_core::IUnknown);
@@ -1127,6 +1498,69 @@ impl ISensorManager_Vtbl {
         iid == &<ISensorManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISensorManager_Impl> windows_core::imp::TearOffVtable<Identity> for ISensorManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSensorsByCategory<Identity: windows_core::IUnknownImpl + ISensorManager_Impl>(this: *mut core::ffi::c_void, sensorcategory: *const windows_core::GUID, ppsensorsfound: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISensorManager_Impl::GetSensorsByCategory(this, core::mem::transmute_copy(&sensorcategory)) {
+                    Ok(ok__) => {
+                        ppsensorsfound.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSensorsByType<Identity: windows_core::IUnknownImpl + ISensorManager_Impl>(this: *mut core::ffi::c_void, sensortype: *const windows_core::GUID, ppsensorsfound: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISensorManager_Impl::GetSensorsByType(this, core::mem::transmute_copy(&sensortype)) {
+                    Ok(ok__) => {
+                        ppsensorsfound.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSensorByID<Identity: windows_core::IUnknownImpl + ISensorManager_Impl>(this: *mut core::ffi::c_void, sensorid: *const windows_core::GUID, ppsensor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISensorManager_Impl::GetSensorByID(this, core::mem::transmute_copy(&sensorid)) {
+                    Ok(ok__) => {
+                        ppsensor.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEventSink<Identity: windows_core::IUnknownImpl + ISensorManager_Impl>(this: *mut core::ffi::c_void, pevents: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensorManager_Impl::SetEventSink(this, core::mem::transmute_copy(&pevents)).into()
+            }
+        }
+        unsafe extern "system" fn RequestPermissions<Identity: windows_core::IUnknownImpl + ISensorManager_Impl>(this: *mut core::ffi::c_void, hparent: super::super::Foundation::HWND, psensors: *mut core::ffi::c_void, fmodal: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensorManager_Impl::RequestPermissions(this, core::mem::transmute_copy(&hparent), core::mem::transmute_copy(&psensors), core::mem::transmute_copy(&fmodal)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSensorsByCategory: GetSensorsByCategory::<Identity>,
+            GetSensorsByType: GetSensorsByType::<Identity>,
+            GetSensorByID: GetSensorByID::<Identity>,
+            SetEventSink: SetEventSink::<Identity>,
+            RequestPermissions: RequestPermissions::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISensorManager {}
 windows_core::imp::define_interface!(ISensorManagerEvents, ISensorManagerEvents_Vtbl, 0x9b3b0b86_266a_4aad_b21f_fde5501001b7);
 windows_core::imp::interface_hierarchy!(ISensorManagerEvents, windows_core::IUnknown);
@@ -1161,6 +1595,20 @@ impl ISensorManagerEvents_Vtbl {
         iid == &<ISensorManagerEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISensorManagerEvents_Impl> windows_core::imp::TearOffVtable<Identity> for ISensorManagerEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnSensorEnter<Identity: windows_core::IUnknownImpl + ISensorManagerEvents_Impl>(this: *mut core::ffi::c_void, psensor: *mut core::ffi::c_void, state: SensorState) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensorManagerEvents_Impl::OnSensorEnter(this, core::mem::transmute_copy(&psensor), core::mem::transmute_copy(&state)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnSensorEnter: OnSensorEnter::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ISensorManagerEvents {}
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -328,6 +328,30 @@ impl IWSDAddress_Vtbl {
         iid == &<IWSDAddress as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDAddress_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDAddress_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Serialize<Identity: windows_core::IUnknownImpl + IWSDAddress_Impl>(this: *mut core::ffi::c_void, pszbuffer: windows_core::PWSTR, cchlength: u32, fsafe: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDAddress_Impl::Serialize(this, core::mem::transmute_copy(&pszbuffer), core::mem::transmute_copy(&cchlength), core::mem::transmute_copy(&fsafe)).into()
+            }
+        }
+        unsafe extern "system" fn Deserialize<Identity: windows_core::IUnknownImpl + IWSDAddress_Impl>(this: *mut core::ffi::c_void, pszbuffer: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDAddress_Impl::Deserialize(this, core::mem::transmute(&pszbuffer)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Serialize: Serialize::<Identity>,
+            Deserialize: Deserialize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWSDAddress {}
 windows_core::imp::define_interface!(IWSDAsyncCallback, IWSDAsyncCallback_Vtbl, 0xa63e109d_ce72_49e2_ba98_e845f5ee1666);
 windows_core::imp::interface_hierarchy!(IWSDAsyncCallback, windows_core::IUnknown);
@@ -363,6 +387,23 @@ impl IWSDAsyncCallback_Vtbl {
         iid == &<IWSDAsyncCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDAsyncCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDAsyncCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AsyncOperationComplete<Identity: windows_core::IUnknownImpl + IWSDAsyncCallback_Impl>(this: *mut core::ffi::c_void, pasyncresult: *mut core::ffi::c_void, pasyncstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDAsyncCallback_Impl::AsyncOperationComplete(this, core::mem::transmute_copy(&pasyncresult), core::mem::transmute_copy(&pasyncstate)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AsyncOperationComplete: AsyncOperationComplete::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWSDAsyncCallback {}
 windows_core::imp::define_interface!(IWSDAsyncResult, IWSDAsyncResult_Vtbl, 0x11a9852a_8dd8_423e_b537_9356db4fbfb8);
 windows_core::imp::interface_hierarchy!(IWSDAsyncResult, windows_core::IUnknown);
@@ -491,6 +532,77 @@ impl IWSDAsyncResult_Vtbl {
         iid == &<IWSDAsyncResult as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDAsyncResult_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDAsyncResult_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetCallback<Identity: windows_core::IUnknownImpl + IWSDAsyncResult_Impl>(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, pasyncstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDAsyncResult_Impl::SetCallback(this, core::mem::transmute_copy(&pcallback), core::mem::transmute_copy(&pasyncstate)).into()
+            }
+        }
+        unsafe extern "system" fn SetWaitHandle<Identity: windows_core::IUnknownImpl + IWSDAsyncResult_Impl>(this: *mut core::ffi::c_void, hwaithandle: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDAsyncResult_Impl::SetWaitHandle(this, core::mem::transmute_copy(&hwaithandle)).into()
+            }
+        }
+        unsafe extern "system" fn HasCompleted<Identity: windows_core::IUnknownImpl + IWSDAsyncResult_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDAsyncResult_Impl::HasCompleted(this).into()
+            }
+        }
+        unsafe extern "system" fn GetAsyncState<Identity: windows_core::IUnknownImpl + IWSDAsyncResult_Impl>(this: *mut core::ffi::c_void, ppasyncstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDAsyncResult_Impl::GetAsyncState(this) {
+                    Ok(ok__) => {
+                        ppasyncstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Abort<Identity: windows_core::IUnknownImpl + IWSDAsyncResult_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDAsyncResult_Impl::Abort(this).into()
+            }
+        }
+        unsafe extern "system" fn GetEvent<Identity: windows_core::IUnknownImpl + IWSDAsyncResult_Impl>(this: *mut core::ffi::c_void, pevent: *mut WSD_EVENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDAsyncResult_Impl::GetEvent(this, core::mem::transmute_copy(&pevent)).into()
+            }
+        }
+        unsafe extern "system" fn GetEndpointProxy<Identity: windows_core::IUnknownImpl + IWSDAsyncResult_Impl>(this: *mut core::ffi::c_void, ppendpoint: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDAsyncResult_Impl::GetEndpointProxy(this) {
+                    Ok(ok__) => {
+                        ppendpoint.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetCallback: SetCallback::<Identity>,
+            SetWaitHandle: SetWaitHandle::<Identity>,
+            HasCompleted: HasCompleted::<Identity>,
+            GetAsyncState: GetAsyncState::<Identity>,
+            Abort: Abort::<Identity>,
+            GetEvent: GetEvent::<Identity>,
+            GetEndpointProxy: GetEndpointProxy::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWSDAsyncResult {}
 windows_core::imp::define_interface!(IWSDAttachment, IWSDAttachment_Vtbl, 0x5d55a616_9df8_4b09_b156_9ba351a48b76);
 windows_core::imp::interface_hierarchy!(IWSDAttachment, windows_core::IUnknown);
@@ -508,6 +620,12 @@ impl IWSDAttachment_Vtbl {
         iid == &<IWSDAttachment as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDAttachment_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDAttachment_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IWSDAttachment {}
 windows_core::imp::define_interface!(IWSDDeviceHost, IWSDDeviceHost_Vtbl, 0x917fe891_3d13_4138_9809_934c8abeb12c);
 windows_core::imp::interface_hierarchy!(IWSDDeviceHost, windows_core::IUnknown);
@@ -702,6 +820,100 @@ impl IWSDDeviceHost_Vtbl {
         iid == &<IWSDDeviceHost as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDDeviceHost_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDDeviceHost_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Init<Identity: windows_core::IUnknownImpl + IWSDDeviceHost_Impl>(this: *mut core::ffi::c_void, pszlocalid: windows_core::PCWSTR, pcontext: *mut core::ffi::c_void, pphostaddresses: *const *mut core::ffi::c_void, dwhostaddresscount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDDeviceHost_Impl::Init(this, core::mem::transmute(&pszlocalid), core::mem::transmute_copy(&pcontext), core::mem::transmute_copy(&pphostaddresses), core::mem::transmute_copy(&dwhostaddresscount)).into()
+            }
+        }
+        unsafe extern "system" fn Start<Identity: windows_core::IUnknownImpl + IWSDDeviceHost_Impl>(this: *mut core::ffi::c_void, ullinstanceid: u64, pscopelist: *const WSD_URI_LIST, pnotificationsink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDDeviceHost_Impl::Start(this, core::mem::transmute_copy(&ullinstanceid), core::mem::transmute_copy(&pscopelist), core::mem::transmute_copy(&pnotificationsink)).into()
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + IWSDDeviceHost_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDDeviceHost_Impl::Stop(this).into()
+            }
+        }
+        unsafe extern "system" fn Terminate<Identity: windows_core::IUnknownImpl + IWSDDeviceHost_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDDeviceHost_Impl::Terminate(this).into()
+            }
+        }
+        unsafe extern "system" fn RegisterPortType<Identity: windows_core::IUnknownImpl + IWSDDeviceHost_Impl>(this: *mut core::ffi::c_void, pporttype: *const WSD_PORT_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDDeviceHost_Impl::RegisterPortType(this, core::mem::transmute_copy(&pporttype)).into()
+            }
+        }
+        unsafe extern "system" fn SetMetadata<Identity: windows_core::IUnknownImpl + IWSDDeviceHost_Impl>(this: *mut core::ffi::c_void, pthismodelmetadata: *const WSD_THIS_MODEL_METADATA, pthisdevicemetadata: *const WSD_THIS_DEVICE_METADATA, phostmetadata: *const WSD_HOST_METADATA, pcustommetadata: *const WSD_METADATA_SECTION_LIST) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDDeviceHost_Impl::SetMetadata(this, core::mem::transmute_copy(&pthismodelmetadata), core::mem::transmute_copy(&pthisdevicemetadata), core::mem::transmute_copy(&phostmetadata), core::mem::transmute_copy(&pcustommetadata)).into()
+            }
+        }
+        unsafe extern "system" fn RegisterService<Identity: windows_core::IUnknownImpl + IWSDDeviceHost_Impl>(this: *mut core::ffi::c_void, pszserviceid: windows_core::PCWSTR, pservice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDDeviceHost_Impl::RegisterService(this, core::mem::transmute(&pszserviceid), core::mem::transmute_copy(&pservice)).into()
+            }
+        }
+        unsafe extern "system" fn RetireService<Identity: windows_core::IUnknownImpl + IWSDDeviceHost_Impl>(this: *mut core::ffi::c_void, pszserviceid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDDeviceHost_Impl::RetireService(this, core::mem::transmute(&pszserviceid)).into()
+            }
+        }
+        unsafe extern "system" fn AddDynamicService<Identity: windows_core::IUnknownImpl + IWSDDeviceHost_Impl>(this: *mut core::ffi::c_void, pszserviceid: windows_core::PCWSTR, pszendpointaddress: windows_core::PCWSTR, pporttype: *const WSD_PORT_TYPE, pportname: *const WSDXML_NAME, pany: *const WSDXML_ELEMENT, pservice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDDeviceHost_Impl::AddDynamicService(this, core::mem::transmute(&pszserviceid), core::mem::transmute(&pszendpointaddress), core::mem::transmute_copy(&pporttype), core::mem::transmute_copy(&pportname), core::mem::transmute_copy(&pany), core::mem::transmute_copy(&pservice)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveDynamicService<Identity: windows_core::IUnknownImpl + IWSDDeviceHost_Impl>(this: *mut core::ffi::c_void, pszserviceid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDDeviceHost_Impl::RemoveDynamicService(this, core::mem::transmute(&pszserviceid)).into()
+            }
+        }
+        unsafe extern "system" fn SetServiceDiscoverable<Identity: windows_core::IUnknownImpl + IWSDDeviceHost_Impl>(this: *mut core::ffi::c_void, pszserviceid: windows_core::PCWSTR, fdiscoverable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDDeviceHost_Impl::SetServiceDiscoverable(this, core::mem::transmute(&pszserviceid), core::mem::transmute_copy(&fdiscoverable)).into()
+            }
+        }
+        unsafe extern "system" fn SignalEvent<Identity: windows_core::IUnknownImpl + IWSDDeviceHost_Impl>(this: *mut core::ffi::c_void, pszserviceid: windows_core::PCWSTR, pbody: *const core::ffi::c_void, poperation: *const WSD_OPERATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDDeviceHost_Impl::SignalEvent(this, core::mem::transmute(&pszserviceid), core::mem::transmute_copy(&pbody), core::mem::transmute_copy(&poperation)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Init: Init::<Identity>,
+            Start: Start::<Identity>,
+            Stop: Stop::<Identity>,
+            Terminate: Terminate::<Identity>,
+            RegisterPortType: RegisterPortType::<Identity>,
+            SetMetadata: SetMetadata::<Identity>,
+            RegisterService: RegisterService::<Identity>,
+            RetireService: RetireService::<Identity>,
+            AddDynamicService: AddDynamicService::<Identity>,
+            RemoveDynamicService: RemoveDynamicService::<Identity>,
+            SetServiceDiscoverable: SetServiceDiscoverable::<Identity>,
+            SignalEvent: SignalEvent::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWSDDeviceHost {}
 windows_core::imp::define_interface!(IWSDDeviceHostNotify, IWSDDeviceHostNotify_Vtbl, 0xb5bee9f9_eeda_41fe_96f7_f45e14990fb0);
 windows_core::imp::interface_hierarchy!(IWSDDeviceHostNotify, windows_core::IUnknown);
@@ -745,6 +957,26 @@ impl IWSDDeviceHostNotify_Vtbl {
         iid == &<IWSDDeviceHostNotify as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDDeviceHostNotify_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDDeviceHostNotify_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetService<Identity: windows_core::IUnknownImpl + IWSDDeviceHostNotify_Impl>(this: *mut core::ffi::c_void, pszserviceid: windows_core::PCWSTR, ppservice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDDeviceHostNotify_Impl::GetService(this, core::mem::transmute(&pszserviceid)) {
+                    Ok(ok__) => {
+                        ppservice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetService: GetService::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWSDDeviceHostNotify {}
 windows_core::imp::define_interface!(IWSDDeviceProxy, IWSDDeviceProxy_Vtbl, 0xeee0c031_c578_4c0e_9a3b_973c35f409db);
 windows_core::imp::interface_hierarchy!(IWSDDeviceProxy, windows_core::IUnknown);
@@ -972,6 +1204,134 @@ impl IWSDDeviceProxy_Vtbl {
         iid == &<IWSDDeviceProxy as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDDeviceProxy_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDDeviceProxy_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Init<Identity: windows_core::IUnknownImpl + IWSDDeviceProxy_Impl>(this: *mut core::ffi::c_void, pszdeviceid: windows_core::PCWSTR, pdeviceaddress: *mut core::ffi::c_void, pszlocalid: windows_core::PCWSTR, pcontext: *mut core::ffi::c_void, psponsor: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDDeviceProxy_Impl::Init(this, core::mem::transmute(&pszdeviceid), core::mem::transmute_copy(&pdeviceaddress), core::mem::transmute(&pszlocalid), core::mem::transmute_copy(&pcontext), core::mem::transmute_copy(&psponsor)).into()
+            }
+        }
+        unsafe extern "system" fn BeginGetMetadata<Identity: windows_core::IUnknownImpl + IWSDDeviceProxy_Impl>(this: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDDeviceProxy_Impl::BeginGetMetadata(this) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EndGetMetadata<Identity: windows_core::IUnknownImpl + IWSDDeviceProxy_Impl>(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDDeviceProxy_Impl::EndGetMetadata(this, core::mem::transmute_copy(&presult)).into()
+            }
+        }
+        unsafe extern "system" fn GetHostMetadata<Identity: windows_core::IUnknownImpl + IWSDDeviceProxy_Impl>(this: *mut core::ffi::c_void, pphostmetadata: *mut *mut WSD_HOST_METADATA) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDDeviceProxy_Impl::GetHostMetadata(this) {
+                    Ok(ok__) => {
+                        pphostmetadata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetThisModelMetadata<Identity: windows_core::IUnknownImpl + IWSDDeviceProxy_Impl>(this: *mut core::ffi::c_void, ppmanufacturermetadata: *mut *mut WSD_THIS_MODEL_METADATA) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDDeviceProxy_Impl::GetThisModelMetadata(this) {
+                    Ok(ok__) => {
+                        ppmanufacturermetadata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetThisDeviceMetadata<Identity: windows_core::IUnknownImpl + IWSDDeviceProxy_Impl>(this: *mut core::ffi::c_void, ppthisdevicemetadata: *mut *mut WSD_THIS_DEVICE_METADATA) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDDeviceProxy_Impl::GetThisDeviceMetadata(this) {
+                    Ok(ok__) => {
+                        ppthisdevicemetadata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAllMetadata<Identity: windows_core::IUnknownImpl + IWSDDeviceProxy_Impl>(this: *mut core::ffi::c_void, ppmetadata: *mut *mut WSD_METADATA_SECTION_LIST) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDDeviceProxy_Impl::GetAllMetadata(this) {
+                    Ok(ok__) => {
+                        ppmetadata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetServiceProxyById<Identity: windows_core::IUnknownImpl + IWSDDeviceProxy_Impl>(this: *mut core::ffi::c_void, pszserviceid: windows_core::PCWSTR, ppserviceproxy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDDeviceProxy_Impl::GetServiceProxyById(this, core::mem::transmute(&pszserviceid)) {
+                    Ok(ok__) => {
+                        ppserviceproxy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetServiceProxyByType<Identity: windows_core::IUnknownImpl + IWSDDeviceProxy_Impl>(this: *mut core::ffi::c_void, ptype: *const WSDXML_NAME, ppserviceproxy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDDeviceProxy_Impl::GetServiceProxyByType(this, core::mem::transmute_copy(&ptype)) {
+                    Ok(ok__) => {
+                        ppserviceproxy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetEndpointProxy<Identity: windows_core::IUnknownImpl + IWSDDeviceProxy_Impl>(this: *mut core::ffi::c_void, ppproxy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDDeviceProxy_Impl::GetEndpointProxy(this) {
+                    Ok(ok__) => {
+                        ppproxy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Init: Init::<Identity>,
+            BeginGetMetadata: BeginGetMetadata::<Identity>,
+            EndGetMetadata: EndGetMetadata::<Identity>,
+            GetHostMetadata: GetHostMetadata::<Identity>,
+            GetThisModelMetadata: GetThisModelMetadata::<Identity>,
+            GetThisDeviceMetadata: GetThisDeviceMetadata::<Identity>,
+            GetAllMetadata: GetAllMetadata::<Identity>,
+            GetServiceProxyById: GetServiceProxyById::<Identity>,
+            GetServiceProxyByType: GetServiceProxyByType::<Identity>,
+            GetEndpointProxy: GetEndpointProxy::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWSDDeviceProxy {}
 windows_core::imp::define_interface!(IWSDEndpointProxy, IWSDEndpointProxy_Vtbl, 0x1860d430_b24c_4975_9f90_dbb39baa24ec);
 windows_core::imp::interface_hierarchy!(IWSDEndpointProxy, windows_core::IUnknown);
@@ -1112,35 +1472,112 @@ impl IWSDEndpointProxy_Vtbl {
         iid == &<IWSDEndpointProxy as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IWSDEndpointProxy {}
-windows_core::imp::define_interface!(IWSDEventingStatus, IWSDEventingStatus_Vtbl, 0x49b17f52_637a_407a_ae99_fbe82a4d38c0);
-windows_core::imp::interface_hierarchy!(IWSDEventingStatus, windows_core::IUnknown);
-impl IWSDEventingStatus {
-    pub unsafe fn SubscriptionRenewed<P0>(&self, pszsubscriptionaction: P0)
-    where
-        P0: windows_core::Param<windows_core::PCWSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).SubscriptionRenewed)(windows_core::Interface::as_raw(self), pszsubscriptionaction.param().abi()) }
-    }
-    pub unsafe fn SubscriptionRenewalFailed<P0>(&self, pszsubscriptionaction: P0, hr: windows_core::HRESULT)
-    where
-        P0: windows_core::Param<windows_core::PCWSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).SubscriptionRenewalFailed)(windows_core::Interface::as_raw(self), pszsubscriptionaction.param().abi(), hr) }
-    }
-    pub unsafe fn SubscriptionEnded<P0>(&self, pszsubscriptionaction: P0)
-    where
-        P0: windows_core::Param<windows_core::PCWSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).SubscriptionEnded)(windows_core::Interface::as_raw(self), pszsubscriptionaction.param().abi()) }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IWSDEventingStatus_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    pub SubscriptionRenewed: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::PCWSTR),
-    pub SubscriptionRenewalFailed: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::PCWSTR, windows_core::HRESULT),
+impl<Identity: windows_core::IUnknownImpl + IWSDEndpointProxy_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDEndpointProxy_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SendOneWayRequest<Identity: windows_core::IUnknownImpl + IWSDEndpointProxy_Impl>(this: *mut core::ffi::c_void, pbody: *const core::ffi::c_void, poperation: *const WSD_OPERATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDEndpointProxy_Impl::SendOneWayRequest(this, core::mem::transmute_copy(&pbody), core::mem::transmute_copy(&poperation)).into()
+            }
+        }
+        unsafe extern "system" fn SendTwoWayRequest<Identity: windows_core::IUnknownImpl + IWSDEndpointProxy_Impl>(this: *mut core::ffi::c_void, pbody: *const core::ffi::c_void, poperation: *const WSD_OPERATION, presponsecontext: *const WSD_SYNCHRONOUS_RESPONSE_CONTEXT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDEndpointProxy_Impl::SendTwoWayRequest(this, core::mem::transmute_copy(&pbody), core::mem::transmute_copy(&poperation), core::mem::transmute_copy(&presponsecontext)).into()
+            }
+        }
+        unsafe extern "system" fn SendTwoWayRequestAsync<Identity: windows_core::IUnknownImpl + IWSDEndpointProxy_Impl>(this: *mut core::ffi::c_void, pbody: *const core::ffi::c_void, poperation: *const WSD_OPERATION, pasyncstate: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, presult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDEndpointProxy_Impl::SendTwoWayRequestAsync(this, core::mem::transmute_copy(&pbody), core::mem::transmute_copy(&poperation), core::mem::transmute_copy(&pasyncstate), core::mem::transmute_copy(&pcallback)) {
+                    Ok(ok__) => {
+                        presult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AbortAsyncOperation<Identity: windows_core::IUnknownImpl + IWSDEndpointProxy_Impl>(this: *mut core::ffi::c_void, pasyncresult: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDEndpointProxy_Impl::AbortAsyncOperation(this, core::mem::transmute_copy(&pasyncresult)).into()
+            }
+        }
+        unsafe extern "system" fn ProcessFault<Identity: windows_core::IUnknownImpl + IWSDEndpointProxy_Impl>(this: *mut core::ffi::c_void, pfault: *const WSD_SOAP_FAULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDEndpointProxy_Impl::ProcessFault(this, core::mem::transmute_copy(&pfault)).into()
+            }
+        }
+        unsafe extern "system" fn GetErrorInfo<Identity: windows_core::IUnknownImpl + IWSDEndpointProxy_Impl>(this: *mut core::ffi::c_void, ppszerrorinfo: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDEndpointProxy_Impl::GetErrorInfo(this) {
+                    Ok(ok__) => {
+                        ppszerrorinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFaultInfo<Identity: windows_core::IUnknownImpl + IWSDEndpointProxy_Impl>(this: *mut core::ffi::c_void, ppfault: *mut *mut WSD_SOAP_FAULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDEndpointProxy_Impl::GetFaultInfo(this) {
+                    Ok(ok__) => {
+                        ppfault.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SendOneWayRequest: SendOneWayRequest::<Identity>,
+            SendTwoWayRequest: SendTwoWayRequest::<Identity>,
+            SendTwoWayRequestAsync: SendTwoWayRequestAsync::<Identity>,
+            AbortAsyncOperation: AbortAsyncOperation::<Identity>,
+            ProcessFault: ProcessFault::<Identity>,
+            GetErrorInfo: GetErrorInfo::<Identity>,
+            GetFaultInfo: GetFaultInfo::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IWSDEndpointProxy {}
+windows_core::imp::define_interface!(IWSDEventingStatus, IWSDEventingStatus_Vtbl, 0x49b17f52_637a_407a_ae99_fbe82a4d38c0);
+windows_core::imp::interface_hierarchy!(IWSDEventingStatus, windows_core::IUnknown);
+impl IWSDEventingStatus {
+    pub unsafe fn SubscriptionRenewed<P0>(&self, pszsubscriptionaction: P0)
+    where
+        P0: windows_core::Param<windows_core::PCWSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).SubscriptionRenewed)(windows_core::Interface::as_raw(self), pszsubscriptionaction.param().abi()) }
+    }
+    pub unsafe fn SubscriptionRenewalFailed<P0>(&self, pszsubscriptionaction: P0, hr: windows_core::HRESULT)
+    where
+        P0: windows_core::Param<windows_core::PCWSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).SubscriptionRenewalFailed)(windows_core::Interface::as_raw(self), pszsubscriptionaction.param().abi(), hr) }
+    }
+    pub unsafe fn SubscriptionEnded<P0>(&self, pszsubscriptionaction: P0)
+    where
+        P0: windows_core::Param<windows_core::PCWSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).SubscriptionEnded)(windows_core::Interface::as_raw(self), pszsubscriptionaction.param().abi()) }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IWSDEventingStatus_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub SubscriptionRenewed: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::PCWSTR),
+    pub SubscriptionRenewalFailed: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::PCWSTR, windows_core::HRESULT),
     pub SubscriptionEnded: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::PCWSTR),
 }
 pub trait IWSDEventingStatus_Impl: windows_core::IUnknownImpl {
@@ -1179,6 +1616,37 @@ impl IWSDEventingStatus_Vtbl {
         iid == &<IWSDEventingStatus as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDEventingStatus_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDEventingStatus_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SubscriptionRenewed<Identity: windows_core::IUnknownImpl + IWSDEventingStatus_Impl>(this: *mut core::ffi::c_void, pszsubscriptionaction: windows_core::PCWSTR) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDEventingStatus_Impl::SubscriptionRenewed(this, core::mem::transmute(&pszsubscriptionaction))
+            }
+        }
+        unsafe extern "system" fn SubscriptionRenewalFailed<Identity: windows_core::IUnknownImpl + IWSDEventingStatus_Impl>(this: *mut core::ffi::c_void, pszsubscriptionaction: windows_core::PCWSTR, hr: windows_core::HRESULT) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDEventingStatus_Impl::SubscriptionRenewalFailed(this, core::mem::transmute(&pszsubscriptionaction), core::mem::transmute_copy(&hr))
+            }
+        }
+        unsafe extern "system" fn SubscriptionEnded<Identity: windows_core::IUnknownImpl + IWSDEventingStatus_Impl>(this: *mut core::ffi::c_void, pszsubscriptionaction: windows_core::PCWSTR) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDEventingStatus_Impl::SubscriptionEnded(this, core::mem::transmute(&pszsubscriptionaction))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SubscriptionRenewed: SubscriptionRenewed::<Identity>,
+            SubscriptionRenewalFailed: SubscriptionRenewalFailed::<Identity>,
+            SubscriptionEnded: SubscriptionEnded::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWSDEventingStatus {}
 windows_core::imp::define_interface!(IWSDHttpAddress, IWSDHttpAddress_Vtbl, 0xd09ac7bd_2a3e_4b85_8605_2737ff3e4ea0);
 impl core::ops::Deref for IWSDHttpAddress {
@@ -1267,6 +1735,50 @@ impl IWSDHttpAddress_Vtbl {
         iid == &<IWSDHttpAddress as windows_core::Interface>::IID || iid == &<IWSDAddress as windows_core::Interface>::IID || iid == &<IWSDTransportAddress as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDHttpAddress_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDHttpAddress_Vtbl
+where
+    IWSDTransportAddress_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSecure<Identity: windows_core::IUnknownImpl + IWSDHttpAddress_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDHttpAddress_Impl::GetSecure(this).into()
+            }
+        }
+        unsafe extern "system" fn SetSecure<Identity: windows_core::IUnknownImpl + IWSDHttpAddress_Impl>(this: *mut core::ffi::c_void, fsecure: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDHttpAddress_Impl::SetSecure(this, core::mem::transmute_copy(&fsecure)).into()
+            }
+        }
+        unsafe extern "system" fn GetPath<Identity: windows_core::IUnknownImpl + IWSDHttpAddress_Impl>(this: *mut core::ffi::c_void, ppszpath: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDHttpAddress_Impl::GetPath(this) {
+                    Ok(ok__) => {
+                        ppszpath.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPath<Identity: windows_core::IUnknownImpl + IWSDHttpAddress_Impl>(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDHttpAddress_Impl::SetPath(this, core::mem::transmute(&pszpath)).into()
+            }
+        }
+        Self {
+            base__: <IWSDTransportAddress_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSecure: GetSecure::<Identity>,
+            SetSecure: SetSecure::<Identity>,
+            GetPath: GetPath::<Identity>,
+            SetPath: SetPath::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWSDHttpAddress {}
 windows_core::imp::define_interface!(IWSDHttpAuthParameters, IWSDHttpAuthParameters_Vtbl, 0x0b476df0_8dac_480d_b05c_99781a5884aa);
 windows_core::imp::interface_hierarchy!(IWSDHttpAuthParameters, windows_core::IUnknown);
@@ -1331,6 +1843,42 @@ impl IWSDHttpAuthParameters_Vtbl {
         iid == &<IWSDHttpAuthParameters as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDHttpAuthParameters_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDHttpAuthParameters_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetClientAccessToken<Identity: windows_core::IUnknownImpl + IWSDHttpAuthParameters_Impl>(this: *mut core::ffi::c_void, phtoken: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDHttpAuthParameters_Impl::GetClientAccessToken(this) {
+                    Ok(ok__) => {
+                        phtoken.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAuthType<Identity: windows_core::IUnknownImpl + IWSDHttpAuthParameters_Impl>(this: *mut core::ffi::c_void, pauthtype: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDHttpAuthParameters_Impl::GetAuthType(this) {
+                    Ok(ok__) => {
+                        pauthtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetClientAccessToken: GetClientAccessToken::<Identity>,
+            GetAuthType: GetAuthType::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWSDHttpAuthParameters {}
 windows_core::imp::define_interface!(IWSDHttpMessageParameters, IWSDHttpMessageParameters_Vtbl, 0x540bd122_5c83_4dec_b396_ea62a2697fdf);
 impl core::ops::Deref for IWSDHttpMessageParameters {
@@ -1515,6 +2063,103 @@ impl IWSDHttpMessageParameters_Vtbl {
         iid == &<IWSDHttpMessageParameters as windows_core::Interface>::IID || iid == &<IWSDMessageParameters as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDHttpMessageParameters_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDHttpMessageParameters_Vtbl
+where
+    IWSDMessageParameters_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetInboundHttpHeaders<Identity: windows_core::IUnknownImpl + IWSDHttpMessageParameters_Impl>(this: *mut core::ffi::c_void, pszheaders: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDHttpMessageParameters_Impl::SetInboundHttpHeaders(this, core::mem::transmute(&pszheaders)).into()
+            }
+        }
+        unsafe extern "system" fn GetInboundHttpHeaders<Identity: windows_core::IUnknownImpl + IWSDHttpMessageParameters_Impl>(this: *mut core::ffi::c_void, ppszheaders: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDHttpMessageParameters_Impl::GetInboundHttpHeaders(this) {
+                    Ok(ok__) => {
+                        ppszheaders.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetOutboundHttpHeaders<Identity: windows_core::IUnknownImpl + IWSDHttpMessageParameters_Impl>(this: *mut core::ffi::c_void, pszheaders: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDHttpMessageParameters_Impl::SetOutboundHttpHeaders(this, core::mem::transmute(&pszheaders)).into()
+            }
+        }
+        unsafe extern "system" fn GetOutboundHttpHeaders<Identity: windows_core::IUnknownImpl + IWSDHttpMessageParameters_Impl>(this: *mut core::ffi::c_void, ppszheaders: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDHttpMessageParameters_Impl::GetOutboundHttpHeaders(this) {
+                    Ok(ok__) => {
+                        ppszheaders.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetID<Identity: windows_core::IUnknownImpl + IWSDHttpMessageParameters_Impl>(this: *mut core::ffi::c_void, pszid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDHttpMessageParameters_Impl::SetID(this, core::mem::transmute(&pszid)).into()
+            }
+        }
+        unsafe extern "system" fn GetID<Identity: windows_core::IUnknownImpl + IWSDHttpMessageParameters_Impl>(this: *mut core::ffi::c_void, ppszid: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDHttpMessageParameters_Impl::GetID(this) {
+                    Ok(ok__) => {
+                        ppszid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetContext<Identity: windows_core::IUnknownImpl + IWSDHttpMessageParameters_Impl>(this: *mut core::ffi::c_void, pcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDHttpMessageParameters_Impl::SetContext(this, core::mem::transmute_copy(&pcontext)).into()
+            }
+        }
+        unsafe extern "system" fn GetContext<Identity: windows_core::IUnknownImpl + IWSDHttpMessageParameters_Impl>(this: *mut core::ffi::c_void, ppcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDHttpMessageParameters_Impl::GetContext(this) {
+                    Ok(ok__) => {
+                        ppcontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Clear<Identity: windows_core::IUnknownImpl + IWSDHttpMessageParameters_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDHttpMessageParameters_Impl::Clear(this).into()
+            }
+        }
+        Self {
+            base__: <IWSDMessageParameters_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetInboundHttpHeaders: SetInboundHttpHeaders::<Identity>,
+            GetInboundHttpHeaders: GetInboundHttpHeaders::<Identity>,
+            SetOutboundHttpHeaders: SetOutboundHttpHeaders::<Identity>,
+            GetOutboundHttpHeaders: GetOutboundHttpHeaders::<Identity>,
+            SetID: SetID::<Identity>,
+            GetID: GetID::<Identity>,
+            SetContext: SetContext::<Identity>,
+            GetContext: GetContext::<Identity>,
+            Clear: Clear::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWSDHttpMessageParameters {}
 windows_core::imp::define_interface!(IWSDInboundAttachment, IWSDInboundAttachment_Vtbl, 0x5bd6ca65_233c_4fb8_9f7a_2641619655c9);
 impl core::ops::Deref for IWSDInboundAttachment {
@@ -1563,6 +2208,26 @@ impl IWSDInboundAttachment_Vtbl {
         iid == &<IWSDInboundAttachment as windows_core::Interface>::IID || iid == &<IWSDAttachment as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDInboundAttachment_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDInboundAttachment_Vtbl
+where
+    IWSDAttachment_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Read<Identity: windows_core::IUnknownImpl + IWSDInboundAttachment_Impl>(this: *mut core::ffi::c_void, pbuffer: *mut u8, dwbytestoread: u32, pdwnumberofbytesread: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDInboundAttachment_Impl::Read(this, core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&dwbytestoread), core::mem::transmute_copy(&pdwnumberofbytesread)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IWSDInboundAttachment_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDInboundAttachment_Impl::Close(this).into()
+            }
+        }
+        Self { base__: <IWSDAttachment_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Read: Read::<Identity>, Close: Close::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWSDInboundAttachment {}
 windows_core::imp::define_interface!(IWSDMessageParameters, IWSDMessageParameters_Vtbl, 0x1fafe8a2_e6fc_4b80_b6cf_b7d45c416d7c);
 windows_core::imp::interface_hierarchy!(IWSDMessageParameters, windows_core::IUnknown);
@@ -1678,46 +2343,129 @@ impl IWSDMessageParameters_Vtbl {
         iid == &<IWSDMessageParameters as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IWSDMessageParameters {}
-windows_core::imp::define_interface!(IWSDMetadataExchange, IWSDMetadataExchange_Vtbl, 0x06996d57_1d67_4928_9307_3d7833fdb846);
-windows_core::imp::interface_hierarchy!(IWSDMetadataExchange, windows_core::IUnknown);
-impl IWSDMetadataExchange {
-    pub unsafe fn GetMetadata(&self) -> windows_core::Result<*mut WSD_METADATA_SECTION_LIST> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetMetadata)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
-        }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IWSDMetadataExchange_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    pub GetMetadata: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut WSD_METADATA_SECTION_LIST) -> windows_core::HRESULT,
-}
-pub trait IWSDMetadataExchange_Impl: windows_core::IUnknownImpl {
-    fn GetMetadata(&self) -> windows_core::Result<*mut WSD_METADATA_SECTION_LIST>;
-}
-impl IWSDMetadataExchange_Vtbl {
-    pub const fn new<Identity: IWSDMetadataExchange_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn GetMetadata<Identity: IWSDMetadataExchange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, metadataout: *mut *mut WSD_METADATA_SECTION_LIST) -> windows_core::HRESULT {
+impl<Identity: windows_core::IUnknownImpl + IWSDMessageParameters_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDMessageParameters_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetLocalAddress<Identity: windows_core::IUnknownImpl + IWSDMessageParameters_Impl>(this: *mut core::ffi::c_void, ppaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IWSDMetadataExchange_Impl::GetMetadata(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDMessageParameters_Impl::GetLocalAddress(this) {
                     Ok(ok__) => {
-                        metadataout.write(core::mem::transmute(ok__));
+                        ppaddress.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        Self { base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(), GetMetadata: GetMetadata::<Identity, OFFSET> }
-    }
+        unsafe extern "system" fn SetLocalAddress<Identity: windows_core::IUnknownImpl + IWSDMessageParameters_Impl>(this: *mut core::ffi::c_void, paddress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDMessageParameters_Impl::SetLocalAddress(this, core::mem::transmute_copy(&paddress)).into()
+            }
+        }
+        unsafe extern "system" fn GetRemoteAddress<Identity: windows_core::IUnknownImpl + IWSDMessageParameters_Impl>(this: *mut core::ffi::c_void, ppaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDMessageParameters_Impl::GetRemoteAddress(this) {
+                    Ok(ok__) => {
+                        ppaddress.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRemoteAddress<Identity: windows_core::IUnknownImpl + IWSDMessageParameters_Impl>(this: *mut core::ffi::c_void, paddress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDMessageParameters_Impl::SetRemoteAddress(this, core::mem::transmute_copy(&paddress)).into()
+            }
+        }
+        unsafe extern "system" fn GetLowerParameters<Identity: windows_core::IUnknownImpl + IWSDMessageParameters_Impl>(this: *mut core::ffi::c_void, pptxparams: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDMessageParameters_Impl::GetLowerParameters(this) {
+                    Ok(ok__) => {
+                        pptxparams.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetLocalAddress: GetLocalAddress::<Identity>,
+            SetLocalAddress: SetLocalAddress::<Identity>,
+            GetRemoteAddress: GetRemoteAddress::<Identity>,
+            SetRemoteAddress: SetRemoteAddress::<Identity>,
+            GetLowerParameters: GetLowerParameters::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IWSDMessageParameters {}
+windows_core::imp::define_interface!(IWSDMetadataExchange, IWSDMetadataExchange_Vtbl, 0x06996d57_1d67_4928_9307_3d7833fdb846);
+windows_core::imp::interface_hierarchy!(IWSDMetadataExchange, windows_core::IUnknown);
+impl IWSDMetadataExchange {
+    pub unsafe fn GetMetadata(&self) -> windows_core::Result<*mut WSD_METADATA_SECTION_LIST> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetMetadata)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IWSDMetadataExchange_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub GetMetadata: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut WSD_METADATA_SECTION_LIST) -> windows_core::HRESULT,
+}
+pub trait IWSDMetadataExchange_Impl: windows_core::IUnknownImpl {
+    fn GetMetadata(&self) -> windows_core::Result<*mut WSD_METADATA_SECTION_LIST>;
+}
+impl IWSDMetadataExchange_Vtbl {
+    pub const fn new<Identity: IWSDMetadataExchange_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn GetMetadata<Identity: IWSDMetadataExchange_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, metadataout: *mut *mut WSD_METADATA_SECTION_LIST) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWSDMetadataExchange_Impl::GetMetadata(this) {
+                    Ok(ok__) => {
+                        metadataout.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(), GetMetadata: GetMetadata::<Identity, OFFSET> }
+    }
     pub fn matches(iid: &windows_core::GUID) -> bool {
         iid == &<IWSDMetadataExchange as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDMetadataExchange_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDMetadataExchange_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMetadata<Identity: windows_core::IUnknownImpl + IWSDMetadataExchange_Impl>(this: *mut core::ffi::c_void, metadataout: *mut *mut WSD_METADATA_SECTION_LIST) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDMetadataExchange_Impl::GetMetadata(this) {
+                    Ok(ok__) => {
+                        metadataout.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetMetadata: GetMetadata::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWSDMetadataExchange {}
 windows_core::imp::define_interface!(IWSDOutboundAttachment, IWSDOutboundAttachment_Vtbl, 0xaa302f8d_5a22_4ba5_b392_aa8486f4c15d);
 impl core::ops::Deref for IWSDOutboundAttachment {
@@ -1791,6 +2539,43 @@ impl IWSDOutboundAttachment_Vtbl {
         iid == &<IWSDOutboundAttachment as windows_core::Interface>::IID || iid == &<IWSDAttachment as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDOutboundAttachment_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDOutboundAttachment_Vtbl
+where
+    IWSDAttachment_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Write<Identity: windows_core::IUnknownImpl + IWSDOutboundAttachment_Impl>(this: *mut core::ffi::c_void, pbuffer: *const u8, dwbytestowrite: u32, pdwnumberofbyteswritten: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDOutboundAttachment_Impl::Write(this, core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&dwbytestowrite)) {
+                    Ok(ok__) => {
+                        pdwnumberofbyteswritten.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IWSDOutboundAttachment_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDOutboundAttachment_Impl::Close(this).into()
+            }
+        }
+        unsafe extern "system" fn Abort<Identity: windows_core::IUnknownImpl + IWSDOutboundAttachment_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDOutboundAttachment_Impl::Abort(this).into()
+            }
+        }
+        Self {
+            base__: <IWSDAttachment_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Write: Write::<Identity>,
+            Close: Close::<Identity>,
+            Abort: Abort::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWSDOutboundAttachment {}
 windows_core::imp::define_interface!(IWSDSSLClientCertificate, IWSDSSLClientCertificate_Vtbl, 0xde105e87_a0da_418e_98ad_27b9eed87bdc);
 windows_core::imp::interface_hierarchy!(IWSDSSLClientCertificate, windows_core::IUnknown);
@@ -1862,6 +2647,43 @@ impl IWSDSSLClientCertificate_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Security_Cryptography")]
+impl<Identity: windows_core::IUnknownImpl + IWSDSSLClientCertificate_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDSSLClientCertificate_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetClientCertificate<Identity: windows_core::IUnknownImpl + IWSDSSLClientCertificate_Impl>(this: *mut core::ffi::c_void, ppcertcontext: *mut *mut super::super::Security::Cryptography::CERT_CONTEXT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDSSLClientCertificate_Impl::GetClientCertificate(this) {
+                    Ok(ok__) => {
+                        ppcertcontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMappedAccessToken<Identity: windows_core::IUnknownImpl + IWSDSSLClientCertificate_Impl>(this: *mut core::ffi::c_void, phtoken: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDSSLClientCertificate_Impl::GetMappedAccessToken(this) {
+                    Ok(ok__) => {
+                        phtoken.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetClientCertificate: GetClientCertificate::<Identity>,
+            GetMappedAccessToken: GetMappedAccessToken::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Security_Cryptography")]
 impl windows_core::RuntimeName for IWSDSSLClientCertificate {}
 windows_core::imp::define_interface!(IWSDScopeMatchingRule, IWSDScopeMatchingRule_Vtbl, 0xfcafe424_fef5_481a_bd9f_33ce0574256f);
 windows_core::imp::interface_hierarchy!(IWSDScopeMatchingRule, windows_core::IUnknown);
@@ -1930,6 +2752,42 @@ impl IWSDScopeMatchingRule_Vtbl {
         iid == &<IWSDScopeMatchingRule as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDScopeMatchingRule_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDScopeMatchingRule_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetScopeRule<Identity: windows_core::IUnknownImpl + IWSDScopeMatchingRule_Impl>(this: *mut core::ffi::c_void, ppszscopematchingrule: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDScopeMatchingRule_Impl::GetScopeRule(this) {
+                    Ok(ok__) => {
+                        ppszscopematchingrule.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MatchScopes<Identity: windows_core::IUnknownImpl + IWSDScopeMatchingRule_Impl>(this: *mut core::ffi::c_void, pszscope1: windows_core::PCWSTR, pszscope2: windows_core::PCWSTR, pfmatch: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDScopeMatchingRule_Impl::MatchScopes(this, core::mem::transmute(&pszscope1), core::mem::transmute(&pszscope2)) {
+                    Ok(ok__) => {
+                        pfmatch.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetScopeRule: GetScopeRule::<Identity>,
+            MatchScopes: MatchScopes::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWSDScopeMatchingRule {}
 windows_core::imp::define_interface!(IWSDServiceMessaging, IWSDServiceMessaging_Vtbl, 0x94974cf4_0cab_460d_a3f6_7a0ad623c0e6);
 windows_core::imp::interface_hierarchy!(IWSDServiceMessaging, windows_core::IUnknown);
@@ -1982,6 +2840,30 @@ impl IWSDServiceMessaging_Vtbl {
         iid == &<IWSDServiceMessaging as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDServiceMessaging_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDServiceMessaging_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SendResponse<Identity: windows_core::IUnknownImpl + IWSDServiceMessaging_Impl>(this: *mut core::ffi::c_void, pbody: *const core::ffi::c_void, poperation: *const WSD_OPERATION, pmessageparameters: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDServiceMessaging_Impl::SendResponse(this, core::mem::transmute_copy(&pbody), core::mem::transmute_copy(&poperation), core::mem::transmute_copy(&pmessageparameters)).into()
+            }
+        }
+        unsafe extern "system" fn FaultRequest<Identity: windows_core::IUnknownImpl + IWSDServiceMessaging_Impl>(this: *mut core::ffi::c_void, prequestheader: *const WSD_SOAP_HEADER, pmessageparameters: *mut core::ffi::c_void, pfault: *const WSD_SOAP_FAULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDServiceMessaging_Impl::FaultRequest(this, core::mem::transmute_copy(&prequestheader), core::mem::transmute_copy(&pmessageparameters), core::mem::transmute_copy(&pfault)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SendResponse: SendResponse::<Identity>,
+            FaultRequest: FaultRequest::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWSDServiceMessaging {}
 windows_core::imp::define_interface!(IWSDServiceProxy, IWSDServiceProxy_Vtbl, 0xd4c7fb9c_03ab_4175_9d67_094fafebf487);
 impl core::ops::Deref for IWSDServiceProxy {
@@ -2139,6 +3021,89 @@ impl IWSDServiceProxy_Vtbl {
         iid == &<IWSDServiceProxy as windows_core::Interface>::IID || iid == &<IWSDMetadataExchange as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDServiceProxy_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDServiceProxy_Vtbl
+where
+    IWSDMetadataExchange_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BeginGetMetadata<Identity: windows_core::IUnknownImpl + IWSDServiceProxy_Impl>(this: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDServiceProxy_Impl::BeginGetMetadata(this) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EndGetMetadata<Identity: windows_core::IUnknownImpl + IWSDServiceProxy_Impl>(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void, ppmetadata: *mut *mut WSD_METADATA_SECTION_LIST) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDServiceProxy_Impl::EndGetMetadata(this, core::mem::transmute_copy(&presult)) {
+                    Ok(ok__) => {
+                        ppmetadata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetServiceMetadata<Identity: windows_core::IUnknownImpl + IWSDServiceProxy_Impl>(this: *mut core::ffi::c_void, ppservicemetadata: *mut *mut WSD_SERVICE_METADATA) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDServiceProxy_Impl::GetServiceMetadata(this) {
+                    Ok(ok__) => {
+                        ppservicemetadata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SubscribeToOperation<Identity: windows_core::IUnknownImpl + IWSDServiceProxy_Impl>(this: *mut core::ffi::c_void, poperation: *const WSD_OPERATION, punknown: *mut core::ffi::c_void, pany: *const WSDXML_ELEMENT, ppany: *mut *mut WSDXML_ELEMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDServiceProxy_Impl::SubscribeToOperation(this, core::mem::transmute_copy(&poperation), core::mem::transmute_copy(&punknown), core::mem::transmute_copy(&pany), core::mem::transmute_copy(&ppany)).into()
+            }
+        }
+        unsafe extern "system" fn UnsubscribeToOperation<Identity: windows_core::IUnknownImpl + IWSDServiceProxy_Impl>(this: *mut core::ffi::c_void, poperation: *const WSD_OPERATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDServiceProxy_Impl::UnsubscribeToOperation(this, core::mem::transmute_copy(&poperation)).into()
+            }
+        }
+        unsafe extern "system" fn SetEventingStatusCallback<Identity: windows_core::IUnknownImpl + IWSDServiceProxy_Impl>(this: *mut core::ffi::c_void, pstatus: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDServiceProxy_Impl::SetEventingStatusCallback(this, core::mem::transmute_copy(&pstatus)).into()
+            }
+        }
+        unsafe extern "system" fn GetEndpointProxy<Identity: windows_core::IUnknownImpl + IWSDServiceProxy_Impl>(this: *mut core::ffi::c_void, ppproxy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDServiceProxy_Impl::GetEndpointProxy(this) {
+                    Ok(ok__) => {
+                        ppproxy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWSDMetadataExchange_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            BeginGetMetadata: BeginGetMetadata::<Identity>,
+            EndGetMetadata: EndGetMetadata::<Identity>,
+            GetServiceMetadata: GetServiceMetadata::<Identity>,
+            SubscribeToOperation: SubscribeToOperation::<Identity>,
+            UnsubscribeToOperation: UnsubscribeToOperation::<Identity>,
+            SetEventingStatusCallback: SetEventingStatusCallback::<Identity>,
+            GetEndpointProxy: GetEndpointProxy::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWSDServiceProxy {}
 windows_core::imp::define_interface!(IWSDServiceProxyEventing, IWSDServiceProxyEventing_Vtbl, 0xf9279d6d_1012_4a94_b8cc_fd35d2202bfe);
 impl core::ops::Deref for IWSDServiceProxyEventing {
@@ -2379,26 +3344,144 @@ impl IWSDServiceProxyEventing_Vtbl {
         iid == &<IWSDServiceProxyEventing as windows_core::Interface>::IID || iid == &<IWSDMetadataExchange as windows_core::Interface>::IID || iid == &<IWSDServiceProxy as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IWSDServiceProxyEventing {}
-windows_core::imp::define_interface!(IWSDSignatureProperty, IWSDSignatureProperty_Vtbl, 0x03ce20aa_71c4_45e2_b32e_3766c61c790f);
-windows_core::imp::interface_hierarchy!(IWSDSignatureProperty, windows_core::IUnknown);
-impl IWSDSignatureProperty {
-    pub unsafe fn IsMessageSigned(&self) -> windows_core::Result<windows_core::BOOL> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).IsMessageSigned)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+impl<Identity: windows_core::IUnknownImpl + IWSDServiceProxyEventing_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDServiceProxyEventing_Vtbl
+where
+    IWSDServiceProxy_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SubscribeToMultipleOperations<Identity: windows_core::IUnknownImpl + IWSDServiceProxyEventing_Impl>(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, punknown: *mut core::ffi::c_void, pexpires: *const WSD_EVENTING_EXPIRES, pany: *const WSDXML_ELEMENT, ppexpires: *mut *mut WSD_EVENTING_EXPIRES, ppany: *mut *mut WSDXML_ELEMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDServiceProxyEventing_Impl::SubscribeToMultipleOperations(this, core::mem::transmute_copy(&poperations), core::mem::transmute_copy(&dwoperationcount), core::mem::transmute_copy(&punknown), core::mem::transmute_copy(&pexpires), core::mem::transmute_copy(&pany), core::mem::transmute_copy(&ppexpires), core::mem::transmute_copy(&ppany)).into()
+            }
         }
-    }
-    pub unsafe fn IsMessageSignatureTrusted(&self) -> windows_core::Result<windows_core::BOOL> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).IsMessageSignatureTrusted)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        unsafe extern "system" fn BeginSubscribeToMultipleOperations<Identity: windows_core::IUnknownImpl + IWSDServiceProxyEventing_Impl>(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, punknown: *mut core::ffi::c_void, pexpires: *const WSD_EVENTING_EXPIRES, pany: *const WSDXML_ELEMENT, pasyncstate: *mut core::ffi::c_void, pasynccallback: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDServiceProxyEventing_Impl::BeginSubscribeToMultipleOperations(this, core::mem::transmute_copy(&poperations), core::mem::transmute_copy(&dwoperationcount), core::mem::transmute_copy(&punknown), core::mem::transmute_copy(&pexpires), core::mem::transmute_copy(&pany), core::mem::transmute_copy(&pasyncstate), core::mem::transmute_copy(&pasynccallback)) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn GetKeyInfo(&self, pbkeyinfo: Option<*mut u8>, pdwkeyinfosize: *mut u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetKeyInfo)(windows_core::Interface::as_raw(self), pbkeyinfo.unwrap_or(core::mem::zeroed()) as _, pdwkeyinfosize as _).ok() }
-    }
-    pub unsafe fn GetSignature(&self, pbsignature: Option<*mut u8>, pdwsignaturesize: *mut u32) -> windows_core::Result<()> {
+        unsafe extern "system" fn EndSubscribeToMultipleOperations<Identity: windows_core::IUnknownImpl + IWSDServiceProxyEventing_Impl>(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, presult: *mut core::ffi::c_void, ppexpires: *mut *mut WSD_EVENTING_EXPIRES, ppany: *mut *mut WSDXML_ELEMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDServiceProxyEventing_Impl::EndSubscribeToMultipleOperations(this, core::mem::transmute_copy(&poperations), core::mem::transmute_copy(&dwoperationcount), core::mem::transmute_copy(&presult), core::mem::transmute_copy(&ppexpires), core::mem::transmute_copy(&ppany)).into()
+            }
+        }
+        unsafe extern "system" fn UnsubscribeToMultipleOperations<Identity: windows_core::IUnknownImpl + IWSDServiceProxyEventing_Impl>(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, pany: *const WSDXML_ELEMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDServiceProxyEventing_Impl::UnsubscribeToMultipleOperations(this, core::mem::transmute_copy(&poperations), core::mem::transmute_copy(&dwoperationcount), core::mem::transmute_copy(&pany)).into()
+            }
+        }
+        unsafe extern "system" fn BeginUnsubscribeToMultipleOperations<Identity: windows_core::IUnknownImpl + IWSDServiceProxyEventing_Impl>(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, pany: *const WSDXML_ELEMENT, pasyncstate: *mut core::ffi::c_void, pasynccallback: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDServiceProxyEventing_Impl::BeginUnsubscribeToMultipleOperations(this, core::mem::transmute_copy(&poperations), core::mem::transmute_copy(&dwoperationcount), core::mem::transmute_copy(&pany), core::mem::transmute_copy(&pasyncstate), core::mem::transmute_copy(&pasynccallback)) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EndUnsubscribeToMultipleOperations<Identity: windows_core::IUnknownImpl + IWSDServiceProxyEventing_Impl>(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, presult: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDServiceProxyEventing_Impl::EndUnsubscribeToMultipleOperations(this, core::mem::transmute_copy(&poperations), core::mem::transmute_copy(&dwoperationcount), core::mem::transmute_copy(&presult)).into()
+            }
+        }
+        unsafe extern "system" fn RenewMultipleOperations<Identity: windows_core::IUnknownImpl + IWSDServiceProxyEventing_Impl>(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, pexpires: *const WSD_EVENTING_EXPIRES, pany: *const WSDXML_ELEMENT, ppexpires: *mut *mut WSD_EVENTING_EXPIRES, ppany: *mut *mut WSDXML_ELEMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDServiceProxyEventing_Impl::RenewMultipleOperations(this, core::mem::transmute_copy(&poperations), core::mem::transmute_copy(&dwoperationcount), core::mem::transmute_copy(&pexpires), core::mem::transmute_copy(&pany), core::mem::transmute_copy(&ppexpires), core::mem::transmute_copy(&ppany)).into()
+            }
+        }
+        unsafe extern "system" fn BeginRenewMultipleOperations<Identity: windows_core::IUnknownImpl + IWSDServiceProxyEventing_Impl>(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, pexpires: *const WSD_EVENTING_EXPIRES, pany: *const WSDXML_ELEMENT, pasyncstate: *mut core::ffi::c_void, pasynccallback: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDServiceProxyEventing_Impl::BeginRenewMultipleOperations(this, core::mem::transmute_copy(&poperations), core::mem::transmute_copy(&dwoperationcount), core::mem::transmute_copy(&pexpires), core::mem::transmute_copy(&pany), core::mem::transmute_copy(&pasyncstate), core::mem::transmute_copy(&pasynccallback)) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EndRenewMultipleOperations<Identity: windows_core::IUnknownImpl + IWSDServiceProxyEventing_Impl>(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, presult: *mut core::ffi::c_void, ppexpires: *mut *mut WSD_EVENTING_EXPIRES, ppany: *mut *mut WSDXML_ELEMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDServiceProxyEventing_Impl::EndRenewMultipleOperations(this, core::mem::transmute_copy(&poperations), core::mem::transmute_copy(&dwoperationcount), core::mem::transmute_copy(&presult), core::mem::transmute_copy(&ppexpires), core::mem::transmute_copy(&ppany)).into()
+            }
+        }
+        unsafe extern "system" fn GetStatusForMultipleOperations<Identity: windows_core::IUnknownImpl + IWSDServiceProxyEventing_Impl>(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, pany: *const WSDXML_ELEMENT, ppexpires: *mut *mut WSD_EVENTING_EXPIRES, ppany: *mut *mut WSDXML_ELEMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDServiceProxyEventing_Impl::GetStatusForMultipleOperations(this, core::mem::transmute_copy(&poperations), core::mem::transmute_copy(&dwoperationcount), core::mem::transmute_copy(&pany), core::mem::transmute_copy(&ppexpires), core::mem::transmute_copy(&ppany)).into()
+            }
+        }
+        unsafe extern "system" fn BeginGetStatusForMultipleOperations<Identity: windows_core::IUnknownImpl + IWSDServiceProxyEventing_Impl>(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, pany: *const WSDXML_ELEMENT, pasyncstate: *mut core::ffi::c_void, pasynccallback: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDServiceProxyEventing_Impl::BeginGetStatusForMultipleOperations(this, core::mem::transmute_copy(&poperations), core::mem::transmute_copy(&dwoperationcount), core::mem::transmute_copy(&pany), core::mem::transmute_copy(&pasyncstate), core::mem::transmute_copy(&pasynccallback)) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EndGetStatusForMultipleOperations<Identity: windows_core::IUnknownImpl + IWSDServiceProxyEventing_Impl>(this: *mut core::ffi::c_void, poperations: *const WSD_OPERATION, dwoperationcount: u32, presult: *mut core::ffi::c_void, ppexpires: *mut *mut WSD_EVENTING_EXPIRES, ppany: *mut *mut WSDXML_ELEMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDServiceProxyEventing_Impl::EndGetStatusForMultipleOperations(this, core::mem::transmute_copy(&poperations), core::mem::transmute_copy(&dwoperationcount), core::mem::transmute_copy(&presult), core::mem::transmute_copy(&ppexpires), core::mem::transmute_copy(&ppany)).into()
+            }
+        }
+        Self {
+            base__: <IWSDServiceProxy_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SubscribeToMultipleOperations: SubscribeToMultipleOperations::<Identity>,
+            BeginSubscribeToMultipleOperations: BeginSubscribeToMultipleOperations::<Identity>,
+            EndSubscribeToMultipleOperations: EndSubscribeToMultipleOperations::<Identity>,
+            UnsubscribeToMultipleOperations: UnsubscribeToMultipleOperations::<Identity>,
+            BeginUnsubscribeToMultipleOperations: BeginUnsubscribeToMultipleOperations::<Identity>,
+            EndUnsubscribeToMultipleOperations: EndUnsubscribeToMultipleOperations::<Identity>,
+            RenewMultipleOperations: RenewMultipleOperations::<Identity>,
+            BeginRenewMultipleOperations: BeginRenewMultipleOperations::<Identity>,
+            EndRenewMultipleOperations: EndRenewMultipleOperations::<Identity>,
+            GetStatusForMultipleOperations: GetStatusForMultipleOperations::<Identity>,
+            BeginGetStatusForMultipleOperations: BeginGetStatusForMultipleOperations::<Identity>,
+            EndGetStatusForMultipleOperations: EndGetStatusForMultipleOperations::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IWSDServiceProxyEventing {}
+windows_core::imp::define_interface!(IWSDSignatureProperty, IWSDSignatureProperty_Vtbl, 0x03ce20aa_71c4_45e2_b32e_3766c61c790f);
+windows_core::imp::interface_hierarchy!(IWSDSignatureProperty, windows_core::IUnknown);
+impl IWSDSignatureProperty {
+    pub unsafe fn IsMessageSigned(&self) -> windows_core::Result<windows_core::BOOL> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).IsMessageSigned)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn IsMessageSignatureTrusted(&self) -> windows_core::Result<windows_core::BOOL> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).IsMessageSignatureTrusted)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn GetKeyInfo(&self, pbkeyinfo: Option<*mut u8>, pdwkeyinfosize: *mut u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetKeyInfo)(windows_core::Interface::as_raw(self), pbkeyinfo.unwrap_or(core::mem::zeroed()) as _, pdwkeyinfosize as _).ok() }
+    }
+    pub unsafe fn GetSignature(&self, pbsignature: Option<*mut u8>, pdwsignaturesize: *mut u32) -> windows_core::Result<()> {
         unsafe { (windows_core::Interface::vtable(self).GetSignature)(windows_core::Interface::as_raw(self), pbsignature.unwrap_or(core::mem::zeroed()) as _, pdwsignaturesize as _).ok() }
     }
     pub unsafe fn GetSignedInfoHash(&self, pbsignedinfohash: Option<*mut u8>, pdwhashsize: *mut u32) -> windows_core::Result<()> {
@@ -2479,6 +3562,63 @@ impl IWSDSignatureProperty_Vtbl {
         iid == &<IWSDSignatureProperty as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDSignatureProperty_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDSignatureProperty_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsMessageSigned<Identity: windows_core::IUnknownImpl + IWSDSignatureProperty_Impl>(this: *mut core::ffi::c_void, pbsigned: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDSignatureProperty_Impl::IsMessageSigned(this) {
+                    Ok(ok__) => {
+                        pbsigned.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsMessageSignatureTrusted<Identity: windows_core::IUnknownImpl + IWSDSignatureProperty_Impl>(this: *mut core::ffi::c_void, pbsignaturetrusted: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDSignatureProperty_Impl::IsMessageSignatureTrusted(this) {
+                    Ok(ok__) => {
+                        pbsignaturetrusted.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetKeyInfo<Identity: windows_core::IUnknownImpl + IWSDSignatureProperty_Impl>(this: *mut core::ffi::c_void, pbkeyinfo: *mut u8, pdwkeyinfosize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDSignatureProperty_Impl::GetKeyInfo(this, core::mem::transmute_copy(&pbkeyinfo), core::mem::transmute_copy(&pdwkeyinfosize)).into()
+            }
+        }
+        unsafe extern "system" fn GetSignature<Identity: windows_core::IUnknownImpl + IWSDSignatureProperty_Impl>(this: *mut core::ffi::c_void, pbsignature: *mut u8, pdwsignaturesize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDSignatureProperty_Impl::GetSignature(this, core::mem::transmute_copy(&pbsignature), core::mem::transmute_copy(&pdwsignaturesize)).into()
+            }
+        }
+        unsafe extern "system" fn GetSignedInfoHash<Identity: windows_core::IUnknownImpl + IWSDSignatureProperty_Impl>(this: *mut core::ffi::c_void, pbsignedinfohash: *mut u8, pdwhashsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDSignatureProperty_Impl::GetSignedInfoHash(this, core::mem::transmute_copy(&pbsignedinfohash), core::mem::transmute_copy(&pdwhashsize)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsMessageSigned: IsMessageSigned::<Identity>,
+            IsMessageSignatureTrusted: IsMessageSignatureTrusted::<Identity>,
+            GetKeyInfo: GetKeyInfo::<Identity>,
+            GetSignature: GetSignature::<Identity>,
+            GetSignedInfoHash: GetSignedInfoHash::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWSDSignatureProperty {}
 windows_core::imp::define_interface!(IWSDTransportAddress, IWSDTransportAddress_Vtbl, 0x70d23498_4ee6_4340_a3df_d845d2235467);
 impl core::ops::Deref for IWSDTransportAddress {
@@ -2597,6 +3737,69 @@ impl IWSDTransportAddress_Vtbl {
         iid == &<IWSDTransportAddress as windows_core::Interface>::IID || iid == &<IWSDAddress as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDTransportAddress_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDTransportAddress_Vtbl
+where
+    IWSDAddress_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPort<Identity: windows_core::IUnknownImpl + IWSDTransportAddress_Impl>(this: *mut core::ffi::c_void, pwport: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDTransportAddress_Impl::GetPort(this) {
+                    Ok(ok__) => {
+                        pwport.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPort<Identity: windows_core::IUnknownImpl + IWSDTransportAddress_Impl>(this: *mut core::ffi::c_void, wport: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDTransportAddress_Impl::SetPort(this, core::mem::transmute_copy(&wport)).into()
+            }
+        }
+        unsafe extern "system" fn GetTransportAddress<Identity: windows_core::IUnknownImpl + IWSDTransportAddress_Impl>(this: *mut core::ffi::c_void, ppszaddress: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDTransportAddress_Impl::GetTransportAddress(this) {
+                    Ok(ok__) => {
+                        ppszaddress.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTransportAddressEx<Identity: windows_core::IUnknownImpl + IWSDTransportAddress_Impl>(this: *mut core::ffi::c_void, fsafe: windows_core::BOOL, ppszaddress: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDTransportAddress_Impl::GetTransportAddressEx(this, core::mem::transmute_copy(&fsafe)) {
+                    Ok(ok__) => {
+                        ppszaddress.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTransportAddress<Identity: windows_core::IUnknownImpl + IWSDTransportAddress_Impl>(this: *mut core::ffi::c_void, pszaddress: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDTransportAddress_Impl::SetTransportAddress(this, core::mem::transmute(&pszaddress)).into()
+            }
+        }
+        Self {
+            base__: <IWSDAddress_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPort: GetPort::<Identity>,
+            SetPort: SetPort::<Identity>,
+            GetTransportAddress: GetTransportAddress::<Identity>,
+            GetTransportAddressEx: GetTransportAddressEx::<Identity>,
+            SetTransportAddress: SetTransportAddress::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWSDTransportAddress {}
 windows_core::imp::define_interface!(IWSDUdpAddress, IWSDUdpAddress_Vtbl, 0x74d6124a_a441_4f78_a1eb_97a8d1996893);
 impl core::ops::Deref for IWSDUdpAddress {
@@ -2783,6 +3986,105 @@ impl IWSDUdpAddress_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Networking_WinSock")]
+impl<Identity: windows_core::IUnknownImpl + IWSDUdpAddress_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDUdpAddress_Vtbl
+where
+    IWSDTransportAddress_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetSockaddr<Identity: windows_core::IUnknownImpl + IWSDUdpAddress_Impl>(this: *mut core::ffi::c_void, psockaddr: *const super::super::Networking::WinSock::SOCKADDR_STORAGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDUdpAddress_Impl::SetSockaddr(this, core::mem::transmute_copy(&psockaddr)).into()
+            }
+        }
+        unsafe extern "system" fn GetSockaddr<Identity: windows_core::IUnknownImpl + IWSDUdpAddress_Impl>(this: *mut core::ffi::c_void, psockaddr: *mut super::super::Networking::WinSock::SOCKADDR_STORAGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDUdpAddress_Impl::GetSockaddr(this, core::mem::transmute_copy(&psockaddr)).into()
+            }
+        }
+        unsafe extern "system" fn SetExclusive<Identity: windows_core::IUnknownImpl + IWSDUdpAddress_Impl>(this: *mut core::ffi::c_void, fexclusive: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDUdpAddress_Impl::SetExclusive(this, core::mem::transmute_copy(&fexclusive)).into()
+            }
+        }
+        unsafe extern "system" fn GetExclusive<Identity: windows_core::IUnknownImpl + IWSDUdpAddress_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDUdpAddress_Impl::GetExclusive(this).into()
+            }
+        }
+        unsafe extern "system" fn SetMessageType<Identity: windows_core::IUnknownImpl + IWSDUdpAddress_Impl>(this: *mut core::ffi::c_void, messagetype: WSDUdpMessageType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDUdpAddress_Impl::SetMessageType(this, core::mem::transmute_copy(&messagetype)).into()
+            }
+        }
+        unsafe extern "system" fn GetMessageType<Identity: windows_core::IUnknownImpl + IWSDUdpAddress_Impl>(this: *mut core::ffi::c_void, pmessagetype: *mut WSDUdpMessageType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDUdpAddress_Impl::GetMessageType(this) {
+                    Ok(ok__) => {
+                        pmessagetype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTTL<Identity: windows_core::IUnknownImpl + IWSDUdpAddress_Impl>(this: *mut core::ffi::c_void, dwttl: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDUdpAddress_Impl::SetTTL(this, core::mem::transmute_copy(&dwttl)).into()
+            }
+        }
+        unsafe extern "system" fn GetTTL<Identity: windows_core::IUnknownImpl + IWSDUdpAddress_Impl>(this: *mut core::ffi::c_void, pdwttl: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDUdpAddress_Impl::GetTTL(this) {
+                    Ok(ok__) => {
+                        pdwttl.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAlias<Identity: windows_core::IUnknownImpl + IWSDUdpAddress_Impl>(this: *mut core::ffi::c_void, palias: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDUdpAddress_Impl::SetAlias(this, core::mem::transmute_copy(&palias)).into()
+            }
+        }
+        unsafe extern "system" fn GetAlias<Identity: windows_core::IUnknownImpl + IWSDUdpAddress_Impl>(this: *mut core::ffi::c_void, palias: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDUdpAddress_Impl::GetAlias(this) {
+                    Ok(ok__) => {
+                        palias.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWSDTransportAddress_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetSockaddr: SetSockaddr::<Identity>,
+            GetSockaddr: GetSockaddr::<Identity>,
+            SetExclusive: SetExclusive::<Identity>,
+            GetExclusive: GetExclusive::<Identity>,
+            SetMessageType: SetMessageType::<Identity>,
+            GetMessageType: GetMessageType::<Identity>,
+            SetTTL: SetTTL::<Identity>,
+            GetTTL: GetTTL::<Identity>,
+            SetAlias: SetAlias::<Identity>,
+            GetAlias: GetAlias::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Networking_WinSock")]
 impl windows_core::RuntimeName for IWSDUdpAddress {}
 windows_core::imp::define_interface!(IWSDUdpMessageParameters, IWSDUdpMessageParameters_Vtbl, 0x9934149f_8f0c_447b_aa0b_73124b0ca7f0);
 impl core::ops::Deref for IWSDUdpMessageParameters {
@@ -2835,6 +4137,30 @@ impl IWSDUdpMessageParameters_Vtbl {
         iid == &<IWSDUdpMessageParameters as windows_core::Interface>::IID || iid == &<IWSDMessageParameters as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDUdpMessageParameters_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDUdpMessageParameters_Vtbl
+where
+    IWSDMessageParameters_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetRetransmitParams<Identity: windows_core::IUnknownImpl + IWSDUdpMessageParameters_Impl>(this: *mut core::ffi::c_void, pparams: *const WSDUdpRetransmitParams) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDUdpMessageParameters_Impl::SetRetransmitParams(this, core::mem::transmute_copy(&pparams)).into()
+            }
+        }
+        unsafe extern "system" fn GetRetransmitParams<Identity: windows_core::IUnknownImpl + IWSDUdpMessageParameters_Impl>(this: *mut core::ffi::c_void, pparams: *mut WSDUdpRetransmitParams) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDUdpMessageParameters_Impl::GetRetransmitParams(this, core::mem::transmute_copy(&pparams)).into()
+            }
+        }
+        Self {
+            base__: <IWSDMessageParameters_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetRetransmitParams: SetRetransmitParams::<Identity>,
+            GetRetransmitParams: GetRetransmitParams::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWSDUdpMessageParameters {}
 windows_core::imp::define_interface!(IWSDXMLContext, IWSDXMLContext_Vtbl, 0x75d8f3ee_3e5a_43b4_a15a_bcf6887460c0);
 windows_core::imp::interface_hierarchy!(IWSDXMLContext, windows_core::IUnknown);
@@ -2913,6 +4239,44 @@ impl IWSDXMLContext_Vtbl {
         iid == &<IWSDXMLContext as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDXMLContext_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDXMLContext_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddNamespace<Identity: windows_core::IUnknownImpl + IWSDXMLContext_Impl>(this: *mut core::ffi::c_void, pszuri: windows_core::PCWSTR, pszsuggestedprefix: windows_core::PCWSTR, ppnamespace: *mut *mut WSDXML_NAMESPACE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDXMLContext_Impl::AddNamespace(this, core::mem::transmute(&pszuri), core::mem::transmute(&pszsuggestedprefix), core::mem::transmute_copy(&ppnamespace)).into()
+            }
+        }
+        unsafe extern "system" fn AddNameToNamespace<Identity: windows_core::IUnknownImpl + IWSDXMLContext_Impl>(this: *mut core::ffi::c_void, pszuri: windows_core::PCWSTR, pszname: windows_core::PCWSTR, ppname: *mut *mut WSDXML_NAME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDXMLContext_Impl::AddNameToNamespace(this, core::mem::transmute(&pszuri), core::mem::transmute(&pszname), core::mem::transmute_copy(&ppname)).into()
+            }
+        }
+        unsafe extern "system" fn SetNamespaces<Identity: windows_core::IUnknownImpl + IWSDXMLContext_Impl>(this: *mut core::ffi::c_void, pnamespaces: *const *const WSDXML_NAMESPACE, wnamespacescount: u16, blayernumber: u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDXMLContext_Impl::SetNamespaces(this, core::mem::transmute_copy(&pnamespaces), core::mem::transmute_copy(&wnamespacescount), core::mem::transmute_copy(&blayernumber)).into()
+            }
+        }
+        unsafe extern "system" fn SetTypes<Identity: windows_core::IUnknownImpl + IWSDXMLContext_Impl>(this: *mut core::ffi::c_void, ptypes: *const *const WSDXML_TYPE, dwtypescount: u32, blayernumber: u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDXMLContext_Impl::SetTypes(this, core::mem::transmute_copy(&ptypes), core::mem::transmute_copy(&dwtypescount), core::mem::transmute_copy(&blayernumber)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddNamespace: AddNamespace::<Identity>,
+            AddNameToNamespace: AddNameToNamespace::<Identity>,
+            SetNamespaces: SetNamespaces::<Identity>,
+            SetTypes: SetTypes::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWSDXMLContext {}
 windows_core::imp::define_interface!(IWSDiscoveredService, IWSDiscoveredService_Vtbl, 0x4bad8a3b_b374_4420_9632_aac945b374aa);
 windows_core::imp::interface_hierarchy!(IWSDiscoveredService, windows_core::IUnknown);
@@ -3024,9 +4388,159 @@ impl IWSDiscoveredService_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetTypes<Identity: IWSDiscoveredService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pptypeslist: *mut *mut WSD_NAME_LIST) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetTypes<Identity: IWSDiscoveredService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pptypeslist: *mut *mut WSD_NAME_LIST) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWSDiscoveredService_Impl::GetTypes(this) {
+                    Ok(ok__) => {
+                        pptypeslist.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetScopes<Identity: IWSDiscoveredService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppscopeslist: *mut *mut WSD_URI_LIST) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWSDiscoveredService_Impl::GetScopes(this) {
+                    Ok(ok__) => {
+                        ppscopeslist.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetXAddrs<Identity: IWSDiscoveredService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppxaddrslist: *mut *mut WSD_URI_LIST) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWSDiscoveredService_Impl::GetXAddrs(this) {
+                    Ok(ok__) => {
+                        ppxaddrslist.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMetadataVersion<Identity: IWSDiscoveredService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pullmetadataversion: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWSDiscoveredService_Impl::GetMetadataVersion(this) {
+                    Ok(ok__) => {
+                        pullmetadataversion.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetExtendedDiscoXML<Identity: IWSDiscoveredService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppheaderany: *mut *mut WSDXML_ELEMENT, ppbodyany: *mut *mut WSDXML_ELEMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWSDiscoveredService_Impl::GetExtendedDiscoXML(this, core::mem::transmute_copy(&ppheaderany), core::mem::transmute_copy(&ppbodyany)).into()
+            }
+        }
+        unsafe extern "system" fn GetProbeResolveTag<Identity: IWSDiscoveredService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppsztag: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWSDiscoveredService_Impl::GetProbeResolveTag(this) {
+                    Ok(ok__) => {
+                        ppsztag.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRemoteTransportAddress<Identity: IWSDiscoveredService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppszremotetransportaddress: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWSDiscoveredService_Impl::GetRemoteTransportAddress(this) {
+                    Ok(ok__) => {
+                        ppszremotetransportaddress.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLocalTransportAddress<Identity: IWSDiscoveredService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppszlocaltransportaddress: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWSDiscoveredService_Impl::GetLocalTransportAddress(this) {
+                    Ok(ok__) => {
+                        ppszlocaltransportaddress.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLocalInterfaceGUID<Identity: IWSDiscoveredService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWSDiscoveredService_Impl::GetLocalInterfaceGUID(this) {
+                    Ok(ok__) => {
+                        pguid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetInstanceId<Identity: IWSDiscoveredService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pullinstanceid: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWSDiscoveredService_Impl::GetInstanceId(this) {
+                    Ok(ok__) => {
+                        pullinstanceid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            GetEndpointReference: GetEndpointReference::<Identity, OFFSET>,
+            GetTypes: GetTypes::<Identity, OFFSET>,
+            GetScopes: GetScopes::<Identity, OFFSET>,
+            GetXAddrs: GetXAddrs::<Identity, OFFSET>,
+            GetMetadataVersion: GetMetadataVersion::<Identity, OFFSET>,
+            GetExtendedDiscoXML: GetExtendedDiscoXML::<Identity, OFFSET>,
+            GetProbeResolveTag: GetProbeResolveTag::<Identity, OFFSET>,
+            GetRemoteTransportAddress: GetRemoteTransportAddress::<Identity, OFFSET>,
+            GetLocalTransportAddress: GetLocalTransportAddress::<Identity, OFFSET>,
+            GetLocalInterfaceGUID: GetLocalInterfaceGUID::<Identity, OFFSET>,
+            GetInstanceId: GetInstanceId::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSDiscoveredService as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IWSDiscoveredService_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDiscoveredService_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetEndpointReference<Identity: windows_core::IUnknownImpl + IWSDiscoveredService_Impl>(this: *mut core::ffi::c_void, ppendpointreference: *mut *mut WSD_ENDPOINT_REFERENCE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDiscoveredService_Impl::GetEndpointReference(this) {
+                    Ok(ok__) => {
+                        ppendpointreference.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTypes<Identity: windows_core::IUnknownImpl + IWSDiscoveredService_Impl>(this: *mut core::ffi::c_void, pptypeslist: *mut *mut WSD_NAME_LIST) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWSDiscoveredService_Impl::GetTypes(this) {
                     Ok(ok__) => {
                         pptypeslist.write(core::mem::transmute(ok__));
@@ -3036,9 +4550,9 @@ impl IWSDiscoveredService_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetScopes<Identity: IWSDiscoveredService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppscopeslist: *mut *mut WSD_URI_LIST) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetScopes<Identity: windows_core::IUnknownImpl + IWSDiscoveredService_Impl>(this: *mut core::ffi::c_void, ppscopeslist: *mut *mut WSD_URI_LIST) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWSDiscoveredService_Impl::GetScopes(this) {
                     Ok(ok__) => {
                         ppscopeslist.write(core::mem::transmute(ok__));
@@ -3048,9 +4562,9 @@ impl IWSDiscoveredService_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetXAddrs<Identity: IWSDiscoveredService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppxaddrslist: *mut *mut WSD_URI_LIST) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetXAddrs<Identity: windows_core::IUnknownImpl + IWSDiscoveredService_Impl>(this: *mut core::ffi::c_void, ppxaddrslist: *mut *mut WSD_URI_LIST) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWSDiscoveredService_Impl::GetXAddrs(this) {
                     Ok(ok__) => {
                         ppxaddrslist.write(core::mem::transmute(ok__));
@@ -3060,9 +4574,9 @@ impl IWSDiscoveredService_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetMetadataVersion<Identity: IWSDiscoveredService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pullmetadataversion: *mut u64) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetMetadataVersion<Identity: windows_core::IUnknownImpl + IWSDiscoveredService_Impl>(this: *mut core::ffi::c_void, pullmetadataversion: *mut u64) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWSDiscoveredService_Impl::GetMetadataVersion(this) {
                     Ok(ok__) => {
                         pullmetadataversion.write(core::mem::transmute(ok__));
@@ -3072,15 +4586,15 @@ impl IWSDiscoveredService_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetExtendedDiscoXML<Identity: IWSDiscoveredService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppheaderany: *mut *mut WSDXML_ELEMENT, ppbodyany: *mut *mut WSDXML_ELEMENT) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetExtendedDiscoXML<Identity: windows_core::IUnknownImpl + IWSDiscoveredService_Impl>(this: *mut core::ffi::c_void, ppheaderany: *mut *mut WSDXML_ELEMENT, ppbodyany: *mut *mut WSDXML_ELEMENT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWSDiscoveredService_Impl::GetExtendedDiscoXML(this, core::mem::transmute_copy(&ppheaderany), core::mem::transmute_copy(&ppbodyany)).into()
             }
         }
-        unsafe extern "system" fn GetProbeResolveTag<Identity: IWSDiscoveredService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppsztag: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetProbeResolveTag<Identity: windows_core::IUnknownImpl + IWSDiscoveredService_Impl>(this: *mut core::ffi::c_void, ppsztag: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWSDiscoveredService_Impl::GetProbeResolveTag(this) {
                     Ok(ok__) => {
                         ppsztag.write(core::mem::transmute(ok__));
@@ -3090,9 +4604,9 @@ impl IWSDiscoveredService_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetRemoteTransportAddress<Identity: IWSDiscoveredService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppszremotetransportaddress: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetRemoteTransportAddress<Identity: windows_core::IUnknownImpl + IWSDiscoveredService_Impl>(this: *mut core::ffi::c_void, ppszremotetransportaddress: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWSDiscoveredService_Impl::GetRemoteTransportAddress(this) {
                     Ok(ok__) => {
                         ppszremotetransportaddress.write(core::mem::transmute(ok__));
@@ -3102,9 +4616,9 @@ impl IWSDiscoveredService_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetLocalTransportAddress<Identity: IWSDiscoveredService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppszlocaltransportaddress: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetLocalTransportAddress<Identity: windows_core::IUnknownImpl + IWSDiscoveredService_Impl>(this: *mut core::ffi::c_void, ppszlocaltransportaddress: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWSDiscoveredService_Impl::GetLocalTransportAddress(this) {
                     Ok(ok__) => {
                         ppszlocaltransportaddress.write(core::mem::transmute(ok__));
@@ -3114,9 +4628,9 @@ impl IWSDiscoveredService_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetLocalInterfaceGUID<Identity: IWSDiscoveredService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetLocalInterfaceGUID<Identity: windows_core::IUnknownImpl + IWSDiscoveredService_Impl>(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWSDiscoveredService_Impl::GetLocalInterfaceGUID(this) {
                     Ok(ok__) => {
                         pguid.write(core::mem::transmute(ok__));
@@ -3126,9 +4640,9 @@ impl IWSDiscoveredService_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetInstanceId<Identity: IWSDiscoveredService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pullinstanceid: *mut u64) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetInstanceId<Identity: windows_core::IUnknownImpl + IWSDiscoveredService_Impl>(this: *mut core::ffi::c_void, pullinstanceid: *mut u64) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWSDiscoveredService_Impl::GetInstanceId(this) {
                     Ok(ok__) => {
                         pullinstanceid.write(core::mem::transmute(ok__));
@@ -3139,23 +4653,20 @@ impl IWSDiscoveredService_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            GetEndpointReference: GetEndpointReference::<Identity, OFFSET>,
-            GetTypes: GetTypes::<Identity, OFFSET>,
-            GetScopes: GetScopes::<Identity, OFFSET>,
-            GetXAddrs: GetXAddrs::<Identity, OFFSET>,
-            GetMetadataVersion: GetMetadataVersion::<Identity, OFFSET>,
-            GetExtendedDiscoXML: GetExtendedDiscoXML::<Identity, OFFSET>,
-            GetProbeResolveTag: GetProbeResolveTag::<Identity, OFFSET>,
-            GetRemoteTransportAddress: GetRemoteTransportAddress::<Identity, OFFSET>,
-            GetLocalTransportAddress: GetLocalTransportAddress::<Identity, OFFSET>,
-            GetLocalInterfaceGUID: GetLocalInterfaceGUID::<Identity, OFFSET>,
-            GetInstanceId: GetInstanceId::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWSDiscoveredService as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetEndpointReference: GetEndpointReference::<Identity>,
+            GetTypes: GetTypes::<Identity>,
+            GetScopes: GetScopes::<Identity>,
+            GetXAddrs: GetXAddrs::<Identity>,
+            GetMetadataVersion: GetMetadataVersion::<Identity>,
+            GetExtendedDiscoXML: GetExtendedDiscoXML::<Identity>,
+            GetProbeResolveTag: GetProbeResolveTag::<Identity>,
+            GetRemoteTransportAddress: GetRemoteTransportAddress::<Identity>,
+            GetLocalTransportAddress: GetLocalTransportAddress::<Identity>,
+            GetLocalInterfaceGUID: GetLocalInterfaceGUID::<Identity>,
+            GetInstanceId: GetInstanceId::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IWSDiscoveredService {}
 windows_core::imp::define_interface!(IWSDiscoveryProvider, IWSDiscoveryProvider_Vtbl, 0x8ffc8e55_f0eb_480f_88b7_b435dd281d45);
@@ -3287,6 +4798,71 @@ impl IWSDiscoveryProvider_Vtbl {
         iid == &<IWSDiscoveryProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDiscoveryProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDiscoveryProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAddressFamily<Identity: windows_core::IUnknownImpl + IWSDiscoveryProvider_Impl>(this: *mut core::ffi::c_void, dwaddressfamily: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryProvider_Impl::SetAddressFamily(this, core::mem::transmute_copy(&dwaddressfamily)).into()
+            }
+        }
+        unsafe extern "system" fn Attach<Identity: windows_core::IUnknownImpl + IWSDiscoveryProvider_Impl>(this: *mut core::ffi::c_void, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryProvider_Impl::Attach(this, core::mem::transmute_copy(&psink)).into()
+            }
+        }
+        unsafe extern "system" fn Detach<Identity: windows_core::IUnknownImpl + IWSDiscoveryProvider_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryProvider_Impl::Detach(this).into()
+            }
+        }
+        unsafe extern "system" fn SearchById<Identity: windows_core::IUnknownImpl + IWSDiscoveryProvider_Impl>(this: *mut core::ffi::c_void, pszid: windows_core::PCWSTR, psztag: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryProvider_Impl::SearchById(this, core::mem::transmute(&pszid), core::mem::transmute(&psztag)).into()
+            }
+        }
+        unsafe extern "system" fn SearchByAddress<Identity: windows_core::IUnknownImpl + IWSDiscoveryProvider_Impl>(this: *mut core::ffi::c_void, pszaddress: windows_core::PCWSTR, psztag: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryProvider_Impl::SearchByAddress(this, core::mem::transmute(&pszaddress), core::mem::transmute(&psztag)).into()
+            }
+        }
+        unsafe extern "system" fn SearchByType<Identity: windows_core::IUnknownImpl + IWSDiscoveryProvider_Impl>(this: *mut core::ffi::c_void, ptypeslist: *const WSD_NAME_LIST, pscopeslist: *const WSD_URI_LIST, pszmatchby: windows_core::PCWSTR, psztag: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryProvider_Impl::SearchByType(this, core::mem::transmute_copy(&ptypeslist), core::mem::transmute_copy(&pscopeslist), core::mem::transmute(&pszmatchby), core::mem::transmute(&psztag)).into()
+            }
+        }
+        unsafe extern "system" fn GetXMLContext<Identity: windows_core::IUnknownImpl + IWSDiscoveryProvider_Impl>(this: *mut core::ffi::c_void, ppcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDiscoveryProvider_Impl::GetXMLContext(this) {
+                    Ok(ok__) => {
+                        ppcontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAddressFamily: SetAddressFamily::<Identity>,
+            Attach: Attach::<Identity>,
+            Detach: Detach::<Identity>,
+            SearchById: SearchById::<Identity>,
+            SearchByAddress: SearchByAddress::<Identity>,
+            SearchByType: SearchByType::<Identity>,
+            GetXMLContext: GetXMLContext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWSDiscoveryProvider {}
 windows_core::imp::define_interface!(IWSDiscoveryProviderNotify, IWSDiscoveryProviderNotify_Vtbl, 0x73ee3ced_b6e6_4329_a546_3e8ad46563d2);
 windows_core::imp::interface_hierarchy!(IWSDiscoveryProviderNotify, windows_core::IUnknown);
@@ -3369,6 +4945,44 @@ impl IWSDiscoveryProviderNotify_Vtbl {
         iid == &<IWSDiscoveryProviderNotify as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDiscoveryProviderNotify_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDiscoveryProviderNotify_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Add<Identity: windows_core::IUnknownImpl + IWSDiscoveryProviderNotify_Impl>(this: *mut core::ffi::c_void, pservice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryProviderNotify_Impl::Add(this, core::mem::transmute_copy(&pservice)).into()
+            }
+        }
+        unsafe extern "system" fn Remove<Identity: windows_core::IUnknownImpl + IWSDiscoveryProviderNotify_Impl>(this: *mut core::ffi::c_void, pservice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryProviderNotify_Impl::Remove(this, core::mem::transmute_copy(&pservice)).into()
+            }
+        }
+        unsafe extern "system" fn SearchFailed<Identity: windows_core::IUnknownImpl + IWSDiscoveryProviderNotify_Impl>(this: *mut core::ffi::c_void, hr: windows_core::HRESULT, psztag: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryProviderNotify_Impl::SearchFailed(this, core::mem::transmute_copy(&hr), core::mem::transmute(&psztag)).into()
+            }
+        }
+        unsafe extern "system" fn SearchComplete<Identity: windows_core::IUnknownImpl + IWSDiscoveryProviderNotify_Impl>(this: *mut core::ffi::c_void, psztag: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryProviderNotify_Impl::SearchComplete(this, core::mem::transmute(&psztag)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Add: Add::<Identity>,
+            Remove: Remove::<Identity>,
+            SearchFailed: SearchFailed::<Identity>,
+            SearchComplete: SearchComplete::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWSDiscoveryProviderNotify {}
 windows_core::imp::define_interface!(IWSDiscoveryPublisher, IWSDiscoveryPublisher_Vtbl, 0xae01e1a8_3ff9_4148_8116_057cc616fe13);
 windows_core::imp::interface_hierarchy!(IWSDiscoveryPublisher, windows_core::IUnknown);
@@ -3710,6 +5324,165 @@ impl IWSDiscoveryPublisher_Vtbl {
         iid == &<IWSDiscoveryPublisher as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDiscoveryPublisher_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDiscoveryPublisher_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAddressFamily<Identity: windows_core::IUnknownImpl + IWSDiscoveryPublisher_Impl>(this: *mut core::ffi::c_void, dwaddressfamily: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryPublisher_Impl::SetAddressFamily(this, core::mem::transmute_copy(&dwaddressfamily)).into()
+            }
+        }
+        unsafe extern "system" fn RegisterNotificationSink<Identity: windows_core::IUnknownImpl + IWSDiscoveryPublisher_Impl>(this: *mut core::ffi::c_void, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryPublisher_Impl::RegisterNotificationSink(this, core::mem::transmute_copy(&psink)).into()
+            }
+        }
+        unsafe extern "system" fn UnRegisterNotificationSink<Identity: windows_core::IUnknownImpl + IWSDiscoveryPublisher_Impl>(this: *mut core::ffi::c_void, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryPublisher_Impl::UnRegisterNotificationSink(this, core::mem::transmute_copy(&psink)).into()
+            }
+        }
+        unsafe extern "system" fn Publish<Identity: windows_core::IUnknownImpl + IWSDiscoveryPublisher_Impl>(this: *mut core::ffi::c_void, pszid: windows_core::PCWSTR, ullmetadataversion: u64, ullinstanceid: u64, ullmessagenumber: u64, pszsessionid: windows_core::PCWSTR, ptypeslist: *const WSD_NAME_LIST, pscopeslist: *const WSD_URI_LIST, pxaddrslist: *const WSD_URI_LIST) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryPublisher_Impl::Publish(this, core::mem::transmute(&pszid), core::mem::transmute_copy(&ullmetadataversion), core::mem::transmute_copy(&ullinstanceid), core::mem::transmute_copy(&ullmessagenumber), core::mem::transmute(&pszsessionid), core::mem::transmute_copy(&ptypeslist), core::mem::transmute_copy(&pscopeslist), core::mem::transmute_copy(&pxaddrslist)).into()
+            }
+        }
+        unsafe extern "system" fn UnPublish<Identity: windows_core::IUnknownImpl + IWSDiscoveryPublisher_Impl>(this: *mut core::ffi::c_void, pszid: windows_core::PCWSTR, ullinstanceid: u64, ullmessagenumber: u64, pszsessionid: windows_core::PCWSTR, pany: *const WSDXML_ELEMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryPublisher_Impl::UnPublish(this, core::mem::transmute(&pszid), core::mem::transmute_copy(&ullinstanceid), core::mem::transmute_copy(&ullmessagenumber), core::mem::transmute(&pszsessionid), core::mem::transmute_copy(&pany)).into()
+            }
+        }
+        unsafe extern "system" fn MatchProbe<Identity: windows_core::IUnknownImpl + IWSDiscoveryPublisher_Impl>(this: *mut core::ffi::c_void, pprobemessage: *const WSD_SOAP_MESSAGE, pmessageparameters: *mut core::ffi::c_void, pszid: windows_core::PCWSTR, ullmetadataversion: u64, ullinstanceid: u64, ullmessagenumber: u64, pszsessionid: windows_core::PCWSTR, ptypeslist: *const WSD_NAME_LIST, pscopeslist: *const WSD_URI_LIST, pxaddrslist: *const WSD_URI_LIST) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryPublisher_Impl::MatchProbe(this, core::mem::transmute_copy(&pprobemessage), core::mem::transmute_copy(&pmessageparameters), core::mem::transmute(&pszid), core::mem::transmute_copy(&ullmetadataversion), core::mem::transmute_copy(&ullinstanceid), core::mem::transmute_copy(&ullmessagenumber), core::mem::transmute(&pszsessionid), core::mem::transmute_copy(&ptypeslist), core::mem::transmute_copy(&pscopeslist), core::mem::transmute_copy(&pxaddrslist)).into()
+            }
+        }
+        unsafe extern "system" fn MatchResolve<Identity: windows_core::IUnknownImpl + IWSDiscoveryPublisher_Impl>(this: *mut core::ffi::c_void, presolvemessage: *const WSD_SOAP_MESSAGE, pmessageparameters: *mut core::ffi::c_void, pszid: windows_core::PCWSTR, ullmetadataversion: u64, ullinstanceid: u64, ullmessagenumber: u64, pszsessionid: windows_core::PCWSTR, ptypeslist: *const WSD_NAME_LIST, pscopeslist: *const WSD_URI_LIST, pxaddrslist: *const WSD_URI_LIST) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryPublisher_Impl::MatchResolve(this, core::mem::transmute_copy(&presolvemessage), core::mem::transmute_copy(&pmessageparameters), core::mem::transmute(&pszid), core::mem::transmute_copy(&ullmetadataversion), core::mem::transmute_copy(&ullinstanceid), core::mem::transmute_copy(&ullmessagenumber), core::mem::transmute(&pszsessionid), core::mem::transmute_copy(&ptypeslist), core::mem::transmute_copy(&pscopeslist), core::mem::transmute_copy(&pxaddrslist)).into()
+            }
+        }
+        unsafe extern "system" fn PublishEx<Identity: windows_core::IUnknownImpl + IWSDiscoveryPublisher_Impl>(this: *mut core::ffi::c_void, pszid: windows_core::PCWSTR, ullmetadataversion: u64, ullinstanceid: u64, ullmessagenumber: u64, pszsessionid: windows_core::PCWSTR, ptypeslist: *const WSD_NAME_LIST, pscopeslist: *const WSD_URI_LIST, pxaddrslist: *const WSD_URI_LIST, pheaderany: *const WSDXML_ELEMENT, preferenceparameterany: *const WSDXML_ELEMENT, ppolicyany: *const WSDXML_ELEMENT, pendpointreferenceany: *const WSDXML_ELEMENT, pany: *const WSDXML_ELEMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryPublisher_Impl::PublishEx(
+                    this,
+                    core::mem::transmute(&pszid),
+                    core::mem::transmute_copy(&ullmetadataversion),
+                    core::mem::transmute_copy(&ullinstanceid),
+                    core::mem::transmute_copy(&ullmessagenumber),
+                    core::mem::transmute(&pszsessionid),
+                    core::mem::transmute_copy(&ptypeslist),
+                    core::mem::transmute_copy(&pscopeslist),
+                    core::mem::transmute_copy(&pxaddrslist),
+                    core::mem::transmute_copy(&pheaderany),
+                    core::mem::transmute_copy(&preferenceparameterany),
+                    core::mem::transmute_copy(&ppolicyany),
+                    core::mem::transmute_copy(&pendpointreferenceany),
+                    core::mem::transmute_copy(&pany),
+                )
+                .into()
+            }
+        }
+        unsafe extern "system" fn MatchProbeEx<Identity: windows_core::IUnknownImpl + IWSDiscoveryPublisher_Impl>(this: *mut core::ffi::c_void, pprobemessage: *const WSD_SOAP_MESSAGE, pmessageparameters: *mut core::ffi::c_void, pszid: windows_core::PCWSTR, ullmetadataversion: u64, ullinstanceid: u64, ullmessagenumber: u64, pszsessionid: windows_core::PCWSTR, ptypeslist: *const WSD_NAME_LIST, pscopeslist: *const WSD_URI_LIST, pxaddrslist: *const WSD_URI_LIST, pheaderany: *const WSDXML_ELEMENT, preferenceparameterany: *const WSDXML_ELEMENT, ppolicyany: *const WSDXML_ELEMENT, pendpointreferenceany: *const WSDXML_ELEMENT, pany: *const WSDXML_ELEMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryPublisher_Impl::MatchProbeEx(
+                    this,
+                    core::mem::transmute_copy(&pprobemessage),
+                    core::mem::transmute_copy(&pmessageparameters),
+                    core::mem::transmute(&pszid),
+                    core::mem::transmute_copy(&ullmetadataversion),
+                    core::mem::transmute_copy(&ullinstanceid),
+                    core::mem::transmute_copy(&ullmessagenumber),
+                    core::mem::transmute(&pszsessionid),
+                    core::mem::transmute_copy(&ptypeslist),
+                    core::mem::transmute_copy(&pscopeslist),
+                    core::mem::transmute_copy(&pxaddrslist),
+                    core::mem::transmute_copy(&pheaderany),
+                    core::mem::transmute_copy(&preferenceparameterany),
+                    core::mem::transmute_copy(&ppolicyany),
+                    core::mem::transmute_copy(&pendpointreferenceany),
+                    core::mem::transmute_copy(&pany),
+                )
+                .into()
+            }
+        }
+        unsafe extern "system" fn MatchResolveEx<Identity: windows_core::IUnknownImpl + IWSDiscoveryPublisher_Impl>(this: *mut core::ffi::c_void, presolvemessage: *const WSD_SOAP_MESSAGE, pmessageparameters: *mut core::ffi::c_void, pszid: windows_core::PCWSTR, ullmetadataversion: u64, ullinstanceid: u64, ullmessagenumber: u64, pszsessionid: windows_core::PCWSTR, ptypeslist: *const WSD_NAME_LIST, pscopeslist: *const WSD_URI_LIST, pxaddrslist: *const WSD_URI_LIST, pheaderany: *const WSDXML_ELEMENT, preferenceparameterany: *const WSDXML_ELEMENT, ppolicyany: *const WSDXML_ELEMENT, pendpointreferenceany: *const WSDXML_ELEMENT, pany: *const WSDXML_ELEMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryPublisher_Impl::MatchResolveEx(
+                    this,
+                    core::mem::transmute_copy(&presolvemessage),
+                    core::mem::transmute_copy(&pmessageparameters),
+                    core::mem::transmute(&pszid),
+                    core::mem::transmute_copy(&ullmetadataversion),
+                    core::mem::transmute_copy(&ullinstanceid),
+                    core::mem::transmute_copy(&ullmessagenumber),
+                    core::mem::transmute(&pszsessionid),
+                    core::mem::transmute_copy(&ptypeslist),
+                    core::mem::transmute_copy(&pscopeslist),
+                    core::mem::transmute_copy(&pxaddrslist),
+                    core::mem::transmute_copy(&pheaderany),
+                    core::mem::transmute_copy(&preferenceparameterany),
+                    core::mem::transmute_copy(&ppolicyany),
+                    core::mem::transmute_copy(&pendpointreferenceany),
+                    core::mem::transmute_copy(&pany),
+                )
+                .into()
+            }
+        }
+        unsafe extern "system" fn RegisterScopeMatchingRule<Identity: windows_core::IUnknownImpl + IWSDiscoveryPublisher_Impl>(this: *mut core::ffi::c_void, pscopematchingrule: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryPublisher_Impl::RegisterScopeMatchingRule(this, core::mem::transmute_copy(&pscopematchingrule)).into()
+            }
+        }
+        unsafe extern "system" fn UnRegisterScopeMatchingRule<Identity: windows_core::IUnknownImpl + IWSDiscoveryPublisher_Impl>(this: *mut core::ffi::c_void, pscopematchingrule: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryPublisher_Impl::UnRegisterScopeMatchingRule(this, core::mem::transmute_copy(&pscopematchingrule)).into()
+            }
+        }
+        unsafe extern "system" fn GetXMLContext<Identity: windows_core::IUnknownImpl + IWSDiscoveryPublisher_Impl>(this: *mut core::ffi::c_void, ppcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSDiscoveryPublisher_Impl::GetXMLContext(this) {
+                    Ok(ok__) => {
+                        ppcontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAddressFamily: SetAddressFamily::<Identity>,
+            RegisterNotificationSink: RegisterNotificationSink::<Identity>,
+            UnRegisterNotificationSink: UnRegisterNotificationSink::<Identity>,
+            Publish: Publish::<Identity>,
+            UnPublish: UnPublish::<Identity>,
+            MatchProbe: MatchProbe::<Identity>,
+            MatchResolve: MatchResolve::<Identity>,
+            PublishEx: PublishEx::<Identity>,
+            MatchProbeEx: MatchProbeEx::<Identity>,
+            MatchResolveEx: MatchResolveEx::<Identity>,
+            RegisterScopeMatchingRule: RegisterScopeMatchingRule::<Identity>,
+            UnRegisterScopeMatchingRule: UnRegisterScopeMatchingRule::<Identity>,
+            GetXMLContext: GetXMLContext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWSDiscoveryPublisher {}
 windows_core::imp::define_interface!(IWSDiscoveryPublisherNotify, IWSDiscoveryPublisherNotify_Vtbl, 0xe67651b0_337a_4b3c_9758_733388568251);
 windows_core::imp::interface_hierarchy!(IWSDiscoveryPublisherNotify, windows_core::IUnknown);
@@ -3762,6 +5535,30 @@ impl IWSDiscoveryPublisherNotify_Vtbl {
         iid == &<IWSDiscoveryPublisherNotify as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSDiscoveryPublisherNotify_Impl> windows_core::imp::TearOffVtable<Identity> for IWSDiscoveryPublisherNotify_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ProbeHandler<Identity: windows_core::IUnknownImpl + IWSDiscoveryPublisherNotify_Impl>(this: *mut core::ffi::c_void, psoap: *const WSD_SOAP_MESSAGE, pmessageparameters: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryPublisherNotify_Impl::ProbeHandler(this, core::mem::transmute_copy(&psoap), core::mem::transmute_copy(&pmessageparameters)).into()
+            }
+        }
+        unsafe extern "system" fn ResolveHandler<Identity: windows_core::IUnknownImpl + IWSDiscoveryPublisherNotify_Impl>(this: *mut core::ffi::c_void, psoap: *const WSD_SOAP_MESSAGE, pmessageparameters: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSDiscoveryPublisherNotify_Impl::ResolveHandler(this, core::mem::transmute_copy(&psoap), core::mem::transmute_copy(&pmessageparameters)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ProbeHandler: ProbeHandler::<Identity>,
+            ResolveHandler: ResolveHandler::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWSDiscoveryPublisherNotify {}
 pub const MulticastDiscovery: DeviceDiscoveryMechanism = DeviceDiscoveryMechanism(0i32);
 pub const ONE_WAY: WSDUdpMessageType = WSDUdpMessageType(0i32);
@@ -320,6 +320,50 @@ impl IGameExplorer_Vtbl {
         iid == &<IGameExplorer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IGameExplorer_Impl> windows_core::imp::TearOffVtable<Identity> for IGameExplorer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddGame<Identity: windows_core::IUnknownImpl + IGameExplorer_Impl>(this: *mut core::ffi::c_void, bstrgdfbinarypath: *mut core::ffi::c_void, bstrgameinstalldirectory: *mut core::ffi::c_void, installscope: GAME_INSTALL_SCOPE, pguidinstanceid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGameExplorer_Impl::AddGame(this, core::mem::transmute(&bstrgdfbinarypath), core::mem::transmute(&bstrgameinstalldirectory), core::mem::transmute_copy(&installscope), core::mem::transmute_copy(&pguidinstanceid)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveGame<Identity: windows_core::IUnknownImpl + IGameExplorer_Impl>(this: *mut core::ffi::c_void, guidinstanceid: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGameExplorer_Impl::RemoveGame(this, core::mem::transmute(&guidinstanceid)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateGame<Identity: windows_core::IUnknownImpl + IGameExplorer_Impl>(this: *mut core::ffi::c_void, guidinstanceid: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGameExplorer_Impl::UpdateGame(this, core::mem::transmute(&guidinstanceid)).into()
+            }
+        }
+        unsafe extern "system" fn VerifyAccess<Identity: windows_core::IUnknownImpl + IGameExplorer_Impl>(this: *mut core::ffi::c_void, bstrgdfbinarypath: *mut core::ffi::c_void, pfhasaccess: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameExplorer_Impl::VerifyAccess(this, core::mem::transmute(&bstrgdfbinarypath)) {
+                    Ok(ok__) => {
+                        pfhasaccess.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddGame: AddGame::<Identity>,
+            RemoveGame: RemoveGame::<Identity>,
+            UpdateGame: UpdateGame::<Identity>,
+            VerifyAccess: VerifyAccess::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IGameExplorer {}
 windows_core::imp::define_interface!(IGameExplorer2, IGameExplorer2_Vtbl, 0x86874aa7_a1ed_450d_a7eb_b89e20b2fff3);
 windows_core::imp::interface_hierarchy!(IGameExplorer2, windows_core::IUnknown);
@@ -397,6 +441,43 @@ impl IGameExplorer2_Vtbl {
         iid == &<IGameExplorer2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IGameExplorer2_Impl> windows_core::imp::TearOffVtable<Identity> for IGameExplorer2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InstallGame<Identity: windows_core::IUnknownImpl + IGameExplorer2_Impl>(this: *mut core::ffi::c_void, binarygdfpath: windows_core::PCWSTR, installdirectory: windows_core::PCWSTR, installscope: GAME_INSTALL_SCOPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGameExplorer2_Impl::InstallGame(this, core::mem::transmute(&binarygdfpath), core::mem::transmute(&installdirectory), core::mem::transmute_copy(&installscope)).into()
+            }
+        }
+        unsafe extern "system" fn UninstallGame<Identity: windows_core::IUnknownImpl + IGameExplorer2_Impl>(this: *mut core::ffi::c_void, binarygdfpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGameExplorer2_Impl::UninstallGame(this, core::mem::transmute(&binarygdfpath)).into()
+            }
+        }
+        unsafe extern "system" fn CheckAccess<Identity: windows_core::IUnknownImpl + IGameExplorer2_Impl>(this: *mut core::ffi::c_void, binarygdfpath: windows_core::PCWSTR, phasaccess: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameExplorer2_Impl::CheckAccess(this, core::mem::transmute(&binarygdfpath)) {
+                    Ok(ok__) => {
+                        phasaccess.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InstallGame: InstallGame::<Identity>,
+            UninstallGame: UninstallGame::<Identity>,
+            CheckAccess: CheckAccess::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IGameExplorer2 {}
 windows_core::imp::define_interface!(IGameStatistics, IGameStatistics_Vtbl, 0x3887c9ca_04a0_42ae_bc4c_5fa6c7721145);
 windows_core::imp::interface_hierarchy!(IGameStatistics, windows_core::IUnknown);
@@ -633,6 +714,142 @@ impl IGameStatistics_Vtbl {
         iid == &<IGameStatistics as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IGameStatistics_Impl> windows_core::imp::TearOffVtable<Identity> for IGameStatistics_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMaxCategoryLength<Identity: windows_core::IUnknownImpl + IGameStatistics_Impl>(this: *mut core::ffi::c_void, cch: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameStatistics_Impl::GetMaxCategoryLength(this) {
+                    Ok(ok__) => {
+                        cch.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMaxNameLength<Identity: windows_core::IUnknownImpl + IGameStatistics_Impl>(this: *mut core::ffi::c_void, cch: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameStatistics_Impl::GetMaxNameLength(this) {
+                    Ok(ok__) => {
+                        cch.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMaxValueLength<Identity: windows_core::IUnknownImpl + IGameStatistics_Impl>(this: *mut core::ffi::c_void, cch: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameStatistics_Impl::GetMaxValueLength(this) {
+                    Ok(ok__) => {
+                        cch.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMaxCategories<Identity: windows_core::IUnknownImpl + IGameStatistics_Impl>(this: *mut core::ffi::c_void, pmax: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameStatistics_Impl::GetMaxCategories(this) {
+                    Ok(ok__) => {
+                        pmax.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMaxStatsPerCategory<Identity: windows_core::IUnknownImpl + IGameStatistics_Impl>(this: *mut core::ffi::c_void, pmax: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameStatistics_Impl::GetMaxStatsPerCategory(this) {
+                    Ok(ok__) => {
+                        pmax.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCategoryTitle<Identity: windows_core::IUnknownImpl + IGameStatistics_Impl>(this: *mut core::ffi::c_void, categoryindex: u16, title: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGameStatistics_Impl::SetCategoryTitle(this, core::mem::transmute_copy(&categoryindex), core::mem::transmute(&title)).into()
+            }
+        }
+        unsafe extern "system" fn GetCategoryTitle<Identity: windows_core::IUnknownImpl + IGameStatistics_Impl>(this: *mut core::ffi::c_void, categoryindex: u16, ptitle: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameStatistics_Impl::GetCategoryTitle(this, core::mem::transmute_copy(&categoryindex)) {
+                    Ok(ok__) => {
+                        ptitle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStatistic<Identity: windows_core::IUnknownImpl + IGameStatistics_Impl>(this: *mut core::ffi::c_void, categoryindex: u16, statindex: u16, pname: *mut windows_core::PWSTR, pvalue: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGameStatistics_Impl::GetStatistic(this, core::mem::transmute_copy(&categoryindex), core::mem::transmute_copy(&statindex), core::mem::transmute_copy(&pname), core::mem::transmute_copy(&pvalue)).into()
+            }
+        }
+        unsafe extern "system" fn SetStatistic<Identity: windows_core::IUnknownImpl + IGameStatistics_Impl>(this: *mut core::ffi::c_void, categoryindex: u16, statindex: u16, name: windows_core::PCWSTR, value: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGameStatistics_Impl::SetStatistic(this, core::mem::transmute_copy(&categoryindex), core::mem::transmute_copy(&statindex), core::mem::transmute(&name), core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Save<Identity: windows_core::IUnknownImpl + IGameStatistics_Impl>(this: *mut core::ffi::c_void, trackchanges: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGameStatistics_Impl::Save(this, core::mem::transmute_copy(&trackchanges)).into()
+            }
+        }
+        unsafe extern "system" fn SetLastPlayedCategory<Identity: windows_core::IUnknownImpl + IGameStatistics_Impl>(this: *mut core::ffi::c_void, categoryindex: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGameStatistics_Impl::SetLastPlayedCategory(this, core::mem::transmute_copy(&categoryindex)).into()
+            }
+        }
+        unsafe extern "system" fn GetLastPlayedCategory<Identity: windows_core::IUnknownImpl + IGameStatistics_Impl>(this: *mut core::ffi::c_void, pcategoryindex: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGameStatistics_Impl::GetLastPlayedCategory(this) {
+                    Ok(ok__) => {
+                        pcategoryindex.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetMaxCategoryLength: GetMaxCategoryLength::<Identity>,
+            GetMaxNameLength: GetMaxNameLength::<Identity>,
+            GetMaxValueLength: GetMaxValueLength::<Identity>,
+            GetMaxCategories: GetMaxCategories::<Identity>,
+            GetMaxStatsPerCategory: GetMaxStatsPerCategory::<Identity>,
+            SetCategoryTitle: SetCategoryTitle::<Identity>,
+            GetCategoryTitle: GetCategoryTitle::<Identity>,
+            GetStatistic: GetStatistic::<Identity>,
+            SetStatistic: SetStatistic::<Identity>,
+            Save: Save::<Identity>,
+            SetLastPlayedCategory: SetLastPlayedCategory::<Identity>,
+            GetLastPlayedCategory: GetLastPlayedCategory::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IGameStatistics {}
 windows_core::imp::define_interface!(IGameStatisticsMgr, IGameStatisticsMgr_Vtbl, 0xaff3ea11_e70e_407d_95dd_35e612c41ce2);
 windows_core::imp::interface_hierarchy!(IGameStatisticsMgr, windows_core::IUnknown);
@@ -685,6 +902,30 @@ impl IGameStatisticsMgr_Vtbl {
         iid == &<IGameStatisticsMgr as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IGameStatisticsMgr_Impl> windows_core::imp::TearOffVtable<Identity> for IGameStatisticsMgr_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetGameStatistics<Identity: windows_core::IUnknownImpl + IGameStatisticsMgr_Impl>(this: *mut core::ffi::c_void, gdfbinarypath: windows_core::PCWSTR, opentype: GAMESTATS_OPEN_TYPE, popenresult: *mut GAMESTATS_OPEN_RESULT, ppistats: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGameStatisticsMgr_Impl::GetGameStatistics(this, core::mem::transmute(&gdfbinarypath), core::mem::transmute_copy(&opentype), core::mem::transmute_copy(&popenresult), core::mem::transmute_copy(&ppistats)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveGameStatistics<Identity: windows_core::IUnknownImpl + IGameStatisticsMgr_Impl>(this: *mut core::ffi::c_void, gdfbinarypath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGameStatisticsMgr_Impl::RemoveGameStatistics(this, core::mem::transmute(&gdfbinarypath)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetGameStatistics: GetGameStatistics::<Identity>,
+            RemoveGameStatistics: RemoveGameStatistics::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IGameStatisticsMgr {}
 windows_core::imp::define_interface!(IXblIdpAuthManager, IXblIdpAuthManager_Vtbl, 0xeb5ddb08_8bbf_449b_ac21_b02ddeb3b136);
 windows_core::imp::interface_hierarchy!(IXblIdpAuthManager, windows_core::IUnknown);
@@ -823,6 +1064,76 @@ impl IXblIdpAuthManager_Vtbl {
         iid == &<IXblIdpAuthManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXblIdpAuthManager_Impl> windows_core::imp::TearOffVtable<Identity> for IXblIdpAuthManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetGamerAccount<Identity: windows_core::IUnknownImpl + IXblIdpAuthManager_Impl>(this: *mut core::ffi::c_void, msaaccountid: windows_core::PCWSTR, xuid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXblIdpAuthManager_Impl::SetGamerAccount(this, core::mem::transmute(&msaaccountid), core::mem::transmute(&xuid)).into()
+            }
+        }
+        unsafe extern "system" fn GetGamerAccount<Identity: windows_core::IUnknownImpl + IXblIdpAuthManager_Impl>(this: *mut core::ffi::c_void, msaaccountid: *mut windows_core::PWSTR, xuid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXblIdpAuthManager_Impl::GetGamerAccount(this, core::mem::transmute_copy(&msaaccountid), core::mem::transmute_copy(&xuid)).into()
+            }
+        }
+        unsafe extern "system" fn SetAppViewInitialized<Identity: windows_core::IUnknownImpl + IXblIdpAuthManager_Impl>(this: *mut core::ffi::c_void, appsid: windows_core::PCWSTR, msaaccountid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXblIdpAuthManager_Impl::SetAppViewInitialized(this, core::mem::transmute(&appsid), core::mem::transmute(&msaaccountid)).into()
+            }
+        }
+        unsafe extern "system" fn GetEnvironment<Identity: windows_core::IUnknownImpl + IXblIdpAuthManager_Impl>(this: *mut core::ffi::c_void, environment: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthManager_Impl::GetEnvironment(this) {
+                    Ok(ok__) => {
+                        environment.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSandbox<Identity: windows_core::IUnknownImpl + IXblIdpAuthManager_Impl>(this: *mut core::ffi::c_void, sandbox: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthManager_Impl::GetSandbox(this) {
+                    Ok(ok__) => {
+                        sandbox.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTokenAndSignatureWithTokenResult<Identity: windows_core::IUnknownImpl + IXblIdpAuthManager_Impl>(this: *mut core::ffi::c_void, msaaccountid: windows_core::PCWSTR, appsid: windows_core::PCWSTR, msatarget: windows_core::PCWSTR, msapolicy: windows_core::PCWSTR, httpmethod: windows_core::PCWSTR, uri: windows_core::PCWSTR, headers: windows_core::PCWSTR, body: *const u8, bodysize: u32, forcerefresh: windows_core::BOOL, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthManager_Impl::GetTokenAndSignatureWithTokenResult(this, core::mem::transmute(&msaaccountid), core::mem::transmute(&appsid), core::mem::transmute(&msatarget), core::mem::transmute(&msapolicy), core::mem::transmute(&httpmethod), core::mem::transmute(&uri), core::mem::transmute(&headers), core::mem::transmute_copy(&body), core::mem::transmute_copy(&bodysize), core::mem::transmute_copy(&forcerefresh)) {
+                    Ok(ok__) => {
+                        result.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetGamerAccount: SetGamerAccount::<Identity>,
+            GetGamerAccount: GetGamerAccount::<Identity>,
+            SetAppViewInitialized: SetAppViewInitialized::<Identity>,
+            GetEnvironment: GetEnvironment::<Identity>,
+            GetSandbox: GetSandbox::<Identity>,
+            GetTokenAndSignatureWithTokenResult: GetTokenAndSignatureWithTokenResult::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IXblIdpAuthManager {}
 windows_core::imp::define_interface!(IXblIdpAuthManager2, IXblIdpAuthManager2_Vtbl, 0xbf8c0950_8389_43dd_9a76_a19728ec5dc5);
 windows_core::imp::interface_hierarchy!(IXblIdpAuthManager2, windows_core::IUnknown);
@@ -874,6 +1185,29 @@ impl IXblIdpAuthManager2_Vtbl {
         iid == &<IXblIdpAuthManager2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXblIdpAuthManager2_Impl> windows_core::imp::TearOffVtable<Identity> for IXblIdpAuthManager2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetUserlessTokenAndSignatureWithTokenResult<Identity: windows_core::IUnknownImpl + IXblIdpAuthManager2_Impl>(this: *mut core::ffi::c_void, appsid: windows_core::PCWSTR, msatarget: windows_core::PCWSTR, msapolicy: windows_core::PCWSTR, httpmethod: windows_core::PCWSTR, uri: windows_core::PCWSTR, headers: windows_core::PCWSTR, body: *const u8, bodysize: u32, forcerefresh: windows_core::BOOL, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthManager2_Impl::GetUserlessTokenAndSignatureWithTokenResult(this, core::mem::transmute(&appsid), core::mem::transmute(&msatarget), core::mem::transmute(&msapolicy), core::mem::transmute(&httpmethod), core::mem::transmute(&uri), core::mem::transmute(&headers), core::mem::transmute_copy(&body), core::mem::transmute_copy(&bodysize), core::mem::transmute_copy(&forcerefresh)) {
+                    Ok(ok__) => {
+                        result.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetUserlessTokenAndSignatureWithTokenResult: GetUserlessTokenAndSignatureWithTokenResult::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IXblIdpAuthManager2 {}
 windows_core::imp::define_interface!(IXblIdpAuthTokenResult, IXblIdpAuthTokenResult_Vtbl, 0x46ce0225_f267_4d68_b299_b2762552dec1);
 windows_core::imp::interface_hierarchy!(IXblIdpAuthTokenResult, windows_core::IUnknown);
@@ -1316,6 +1650,276 @@ impl IXblIdpAuthTokenResult_Vtbl {
         iid == &<IXblIdpAuthTokenResult as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult_Impl> windows_core::imp::TearOffVtable<Identity> for IXblIdpAuthTokenResult_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStatus<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult_Impl>(this: *mut core::ffi::c_void, status: *mut XBL_IDP_AUTH_TOKEN_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthTokenResult_Impl::GetStatus(this) {
+                    Ok(ok__) => {
+                        status.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetErrorCode<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult_Impl>(this: *mut core::ffi::c_void, errorcode: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthTokenResult_Impl::GetErrorCode(this) {
+                    Ok(ok__) => {
+                        errorcode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetToken<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult_Impl>(this: *mut core::ffi::c_void, token: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthTokenResult_Impl::GetToken(this) {
+                    Ok(ok__) => {
+                        token.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSignature<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult_Impl>(this: *mut core::ffi::c_void, signature: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthTokenResult_Impl::GetSignature(this) {
+                    Ok(ok__) => {
+                        signature.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSandbox<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult_Impl>(this: *mut core::ffi::c_void, sandbox: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthTokenResult_Impl::GetSandbox(this) {
+                    Ok(ok__) => {
+                        sandbox.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetEnvironment<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult_Impl>(this: *mut core::ffi::c_void, environment: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthTokenResult_Impl::GetEnvironment(this) {
+                    Ok(ok__) => {
+                        environment.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMsaAccountId<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult_Impl>(this: *mut core::ffi::c_void, msaaccountid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthTokenResult_Impl::GetMsaAccountId(this) {
+                    Ok(ok__) => {
+                        msaaccountid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetXuid<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult_Impl>(this: *mut core::ffi::c_void, xuid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthTokenResult_Impl::GetXuid(this) {
+                    Ok(ok__) => {
+                        xuid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetGamertag<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult_Impl>(this: *mut core::ffi::c_void, gamertag: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthTokenResult_Impl::GetGamertag(this) {
+                    Ok(ok__) => {
+                        gamertag.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAgeGroup<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult_Impl>(this: *mut core::ffi::c_void, agegroup: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthTokenResult_Impl::GetAgeGroup(this) {
+                    Ok(ok__) => {
+                        agegroup.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPrivileges<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult_Impl>(this: *mut core::ffi::c_void, privileges: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthTokenResult_Impl::GetPrivileges(this) {
+                    Ok(ok__) => {
+                        privileges.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMsaTarget<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult_Impl>(this: *mut core::ffi::c_void, msatarget: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthTokenResult_Impl::GetMsaTarget(this) {
+                    Ok(ok__) => {
+                        msatarget.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMsaPolicy<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult_Impl>(this: *mut core::ffi::c_void, msapolicy: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthTokenResult_Impl::GetMsaPolicy(this) {
+                    Ok(ok__) => {
+                        msapolicy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMsaAppId<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult_Impl>(this: *mut core::ffi::c_void, msaappid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthTokenResult_Impl::GetMsaAppId(this) {
+                    Ok(ok__) => {
+                        msaappid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRedirect<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult_Impl>(this: *mut core::ffi::c_void, redirect: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthTokenResult_Impl::GetRedirect(this) {
+                    Ok(ok__) => {
+                        redirect.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMessage<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult_Impl>(this: *mut core::ffi::c_void, message: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthTokenResult_Impl::GetMessage(this) {
+                    Ok(ok__) => {
+                        message.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHelpId<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult_Impl>(this: *mut core::ffi::c_void, helpid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthTokenResult_Impl::GetHelpId(this) {
+                    Ok(ok__) => {
+                        helpid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetEnforcementBans<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult_Impl>(this: *mut core::ffi::c_void, enforcementbans: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthTokenResult_Impl::GetEnforcementBans(this) {
+                    Ok(ok__) => {
+                        enforcementbans.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRestrictions<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult_Impl>(this: *mut core::ffi::c_void, restrictions: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthTokenResult_Impl::GetRestrictions(this) {
+                    Ok(ok__) => {
+                        restrictions.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTitleRestrictions<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult_Impl>(this: *mut core::ffi::c_void, titlerestrictions: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthTokenResult_Impl::GetTitleRestrictions(this) {
+                    Ok(ok__) => {
+                        titlerestrictions.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStatus: GetStatus::<Identity>,
+            GetErrorCode: GetErrorCode::<Identity>,
+            GetToken: GetToken::<Identity>,
+            GetSignature: GetSignature::<Identity>,
+            GetSandbox: GetSandbox::<Identity>,
+            GetEnvironment: GetEnvironment::<Identity>,
+            GetMsaAccountId: GetMsaAccountId::<Identity>,
+            GetXuid: GetXuid::<Identity>,
+            GetGamertag: GetGamertag::<Identity>,
+            GetAgeGroup: GetAgeGroup::<Identity>,
+            GetPrivileges: GetPrivileges::<Identity>,
+            GetMsaTarget: GetMsaTarget::<Identity>,
+            GetMsaPolicy: GetMsaPolicy::<Identity>,
+            GetMsaAppId: GetMsaAppId::<Identity>,
+            GetRedirect: GetRedirect::<Identity>,
+            GetMessage: GetMessage::<Identity>,
+            GetHelpId: GetHelpId::<Identity>,
+            GetEnforcementBans: GetEnforcementBans::<Identity>,
+            GetRestrictions: GetRestrictions::<Identity>,
+            GetTitleRestrictions: GetTitleRestrictions::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IXblIdpAuthTokenResult {}
 windows_core::imp::define_interface!(IXblIdpAuthTokenResult2, IXblIdpAuthTokenResult2_Vtbl, 0x75d760b0_60b9_412d_994f_26b2cd5f7812);
 windows_core::imp::interface_hierarchy!(IXblIdpAuthTokenResult2, windows_core::IUnknown);
@@ -1401,6 +2005,55 @@ impl IXblIdpAuthTokenResult2_Vtbl {
         iid == &<IXblIdpAuthTokenResult2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult2_Impl> windows_core::imp::TearOffVtable<Identity> for IXblIdpAuthTokenResult2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetModernGamertag<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult2_Impl>(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthTokenResult2_Impl::GetModernGamertag(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetModernGamertagSuffix<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult2_Impl>(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthTokenResult2_Impl::GetModernGamertagSuffix(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetUniqueModernGamertag<Identity: windows_core::IUnknownImpl + IXblIdpAuthTokenResult2_Impl>(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXblIdpAuthTokenResult2_Impl::GetUniqueModernGamertag(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetModernGamertag: GetModernGamertag::<Identity>,
+            GetModernGamertagSuffix: GetModernGamertagSuffix::<Identity>,
+            GetUniqueModernGamertag: GetUniqueModernGamertag::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IXblIdpAuthTokenResult2 {}
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -7600,6 +7600,26 @@ impl IComprehensiveSpellCheckProvider_Vtbl {
         iid == &<IComprehensiveSpellCheckProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IComprehensiveSpellCheckProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IComprehensiveSpellCheckProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ComprehensiveCheck<Identity: windows_core::IUnknownImpl + IComprehensiveSpellCheckProvider_Impl>(this: *mut core::ffi::c_void, text: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IComprehensiveSpellCheckProvider_Impl::ComprehensiveCheck(this, core::mem::transmute(&text)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ComprehensiveCheck: ComprehensiveCheck::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IComprehensiveSpellCheckProvider {}
 pub const IDN_ALLOW_UNASSIGNED: u32 = 1u32;
 pub const IDN_EMAIL_ADDRESS: u32 = 4u32;
@@ -7674,6 +7694,44 @@ impl IEnumCodePage_Vtbl {
         iid == &<IEnumCodePage as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumCodePage_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumCodePage_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumCodePage_Impl>(this: *mut core::ffi::c_void, ppenum: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumCodePage_Impl::Clone(this, core::mem::transmute_copy(&ppenum)).into()
+            }
+        }
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumCodePage_Impl>(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut MIMECPINFO, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumCodePage_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgelt), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumCodePage_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumCodePage_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumCodePage_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumCodePage_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Clone: Clone::<Identity>,
+            Next: Next::<Identity>,
+            Reset: Reset::<Identity>,
+            Skip: Skip::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumCodePage {}
 windows_core::imp::define_interface!(IEnumRfc1766, IEnumRfc1766_Vtbl, 0x3dc39d1d_c030_11d0_b81b_00c04fc9b31f);
 windows_core::imp::interface_hierarchy!(IEnumRfc1766, windows_core::IUnknown);
@@ -7744,6 +7802,44 @@ impl IEnumRfc1766_Vtbl {
         iid == &<IEnumRfc1766 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumRfc1766_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumRfc1766_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumRfc1766_Impl>(this: *mut core::ffi::c_void, ppenum: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumRfc1766_Impl::Clone(this, core::mem::transmute_copy(&ppenum)).into()
+            }
+        }
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumRfc1766_Impl>(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut RFC1766INFO, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumRfc1766_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgelt), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumRfc1766_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumRfc1766_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumRfc1766_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumRfc1766_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Clone: Clone::<Identity>,
+            Next: Next::<Identity>,
+            Reset: Reset::<Identity>,
+            Skip: Skip::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumRfc1766 {}
 windows_core::imp::define_interface!(IEnumScript, IEnumScript_Vtbl, 0xae5f1430_388b_11d2_8380_00c04f8f5da1);
 windows_core::imp::interface_hierarchy!(IEnumScript, windows_core::IUnknown);
@@ -7814,6 +7910,44 @@ impl IEnumScript_Vtbl {
         iid == &<IEnumScript as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumScript_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumScript_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumScript_Impl>(this: *mut core::ffi::c_void, ppenum: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumScript_Impl::Clone(this, core::mem::transmute_copy(&ppenum)).into()
+            }
+        }
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumScript_Impl>(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut SCRIPTINFO, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumScript_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgelt), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumScript_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumScript_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumScript_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumScript_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Clone: Clone::<Identity>,
+            Next: Next::<Identity>,
+            Reset: Reset::<Identity>,
+            Skip: Skip::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumScript {}
 windows_core::imp::define_interface!(IEnumSpellingError, IEnumSpellingError_Vtbl, 0x803e3bd4_2828_4410_8290_418d1d73c762);
 windows_core::imp::interface_hierarchy!(IEnumSpellingError, windows_core::IUnknown);
@@ -7845,6 +7979,20 @@ impl IEnumSpellingError_Vtbl {
         iid == &<IEnumSpellingError as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumSpellingError_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumSpellingError_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumSpellingError_Impl>(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumSpellingError_Impl::Next(this, core::mem::transmute_copy(&value))
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Next: Next::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IEnumSpellingError {}
 windows_core::imp::define_interface!(IMLangCodePages, IMLangCodePages_Vtbl, 0x359f3443_bd4a_11d0_b188_00aa0038c969);
 windows_core::imp::interface_hierarchy!(IMLangCodePages, windows_core::IUnknown);
@@ -7942,6 +8090,62 @@ impl IMLangCodePages_Vtbl {
         iid == &<IMLangCodePages as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMLangCodePages_Impl> windows_core::imp::TearOffVtable<Identity> for IMLangCodePages_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCharCodePages<Identity: windows_core::IUnknownImpl + IMLangCodePages_Impl>(this: *mut core::ffi::c_void, chsrc: u16, pdwcodepages: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMLangCodePages_Impl::GetCharCodePages(this, core::mem::transmute_copy(&chsrc)) {
+                    Ok(ok__) => {
+                        pdwcodepages.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStrCodePages<Identity: windows_core::IUnknownImpl + IMLangCodePages_Impl>(this: *mut core::ffi::c_void, pszsrc: windows_core::PCWSTR, cchsrc: i32, dwprioritycodepages: u32, pdwcodepages: *mut u32, pcchcodepages: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangCodePages_Impl::GetStrCodePages(this, core::mem::transmute(&pszsrc), core::mem::transmute_copy(&cchsrc), core::mem::transmute_copy(&dwprioritycodepages), core::mem::transmute_copy(&pdwcodepages), core::mem::transmute_copy(&pcchcodepages)).into()
+            }
+        }
+        unsafe extern "system" fn CodePageToCodePages<Identity: windows_core::IUnknownImpl + IMLangCodePages_Impl>(this: *mut core::ffi::c_void, ucodepage: u32, pdwcodepages: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMLangCodePages_Impl::CodePageToCodePages(this, core::mem::transmute_copy(&ucodepage)) {
+                    Ok(ok__) => {
+                        pdwcodepages.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CodePagesToCodePage<Identity: windows_core::IUnknownImpl + IMLangCodePages_Impl>(this: *mut core::ffi::c_void, dwcodepages: u32, udefaultcodepage: u32, pucodepage: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMLangCodePages_Impl::CodePagesToCodePage(this, core::mem::transmute_copy(&dwcodepages), core::mem::transmute_copy(&udefaultcodepage)) {
+                    Ok(ok__) => {
+                        pucodepage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCharCodePages: GetCharCodePages::<Identity>,
+            GetStrCodePages: GetStrCodePages::<Identity>,
+            CodePageToCodePages: CodePageToCodePages::<Identity>,
+            CodePagesToCodePage: CodePagesToCodePage::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMLangCodePages {}
 windows_core::imp::define_interface!(IMLangConvertCharset, IMLangConvertCharset_Vtbl, 0xd66d6f98_cdaa_11d0_b822_00c04fc9b31f);
 windows_core::imp::interface_hierarchy!(IMLangConvertCharset, windows_core::IUnknown);
@@ -8081,6 +8285,83 @@ impl IMLangConvertCharset_Vtbl {
         iid == &<IMLangConvertCharset as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMLangConvertCharset_Impl> windows_core::imp::TearOffVtable<Identity> for IMLangConvertCharset_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IMLangConvertCharset_Impl>(this: *mut core::ffi::c_void, uisrccodepage: u32, uidstcodepage: u32, dwproperty: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangConvertCharset_Impl::Initialize(this, core::mem::transmute_copy(&uisrccodepage), core::mem::transmute_copy(&uidstcodepage), core::mem::transmute_copy(&dwproperty)).into()
+            }
+        }
+        unsafe extern "system" fn GetSourceCodePage<Identity: windows_core::IUnknownImpl + IMLangConvertCharset_Impl>(this: *mut core::ffi::c_void, puisrccodepage: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMLangConvertCharset_Impl::GetSourceCodePage(this) {
+                    Ok(ok__) => {
+                        puisrccodepage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDestinationCodePage<Identity: windows_core::IUnknownImpl + IMLangConvertCharset_Impl>(this: *mut core::ffi::c_void, puidstcodepage: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMLangConvertCharset_Impl::GetDestinationCodePage(this) {
+                    Ok(ok__) => {
+                        puidstcodepage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetProperty<Identity: windows_core::IUnknownImpl + IMLangConvertCharset_Impl>(this: *mut core::ffi::c_void, pdwproperty: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMLangConvertCharset_Impl::GetProperty(this) {
+                    Ok(ok__) => {
+                        pdwproperty.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DoConversion<Identity: windows_core::IUnknownImpl + IMLangConvertCharset_Impl>(this: *mut core::ffi::c_void, psrcstr: *const u8, pcsrcsize: *mut u32, pdststr: *mut u8, pcdstsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangConvertCharset_Impl::DoConversion(this, core::mem::transmute_copy(&psrcstr), core::mem::transmute_copy(&pcsrcsize), core::mem::transmute_copy(&pdststr), core::mem::transmute_copy(&pcdstsize)).into()
+            }
+        }
+        unsafe extern "system" fn DoConversionToUnicode<Identity: windows_core::IUnknownImpl + IMLangConvertCharset_Impl>(this: *mut core::ffi::c_void, psrcstr: windows_core::PCSTR, pcsrcsize: *mut u32, pdststr: windows_core::PWSTR, pcdstsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangConvertCharset_Impl::DoConversionToUnicode(this, core::mem::transmute(&psrcstr), core::mem::transmute_copy(&pcsrcsize), core::mem::transmute_copy(&pdststr), core::mem::transmute_copy(&pcdstsize)).into()
+            }
+        }
+        unsafe extern "system" fn DoConversionFromUnicode<Identity: windows_core::IUnknownImpl + IMLangConvertCharset_Impl>(this: *mut core::ffi::c_void, psrcstr: windows_core::PCWSTR, pcsrcsize: *mut u32, pdststr: windows_core::PSTR, pcdstsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangConvertCharset_Impl::DoConversionFromUnicode(this, core::mem::transmute(&psrcstr), core::mem::transmute_copy(&pcsrcsize), core::mem::transmute_copy(&pdststr), core::mem::transmute_copy(&pcdstsize)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            GetSourceCodePage: GetSourceCodePage::<Identity>,
+            GetDestinationCodePage: GetDestinationCodePage::<Identity>,
+            GetProperty: GetProperty::<Identity>,
+            DoConversion: DoConversion::<Identity>,
+            DoConversionToUnicode: DoConversionToUnicode::<Identity>,
+            DoConversionFromUnicode: DoConversionFromUnicode::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMLangConvertCharset {}
 windows_core::imp::define_interface!(IMLangFontLink, IMLangFontLink_Vtbl, 0x359f3441_bd4a_11d0_b188_00aa0038c969);
 impl core::ops::Deref for IMLangFontLink {
@@ -8172,6 +8453,45 @@ impl IMLangFontLink_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IMLangFontLink_Impl> windows_core::imp::TearOffVtable<Identity> for IMLangFontLink_Vtbl
+where
+    IMLangCodePages_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFontCodePages<Identity: windows_core::IUnknownImpl + IMLangFontLink_Impl>(this: *mut core::ffi::c_void, hdc: super::Graphics::Gdi::HDC, hfont: super::Graphics::Gdi::HFONT, pdwcodepages: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangFontLink_Impl::GetFontCodePages(this, core::mem::transmute_copy(&hdc), core::mem::transmute_copy(&hfont), core::mem::transmute_copy(&pdwcodepages)).into()
+            }
+        }
+        unsafe extern "system" fn MapFont<Identity: windows_core::IUnknownImpl + IMLangFontLink_Impl>(this: *mut core::ffi::c_void, hdc: super::Graphics::Gdi::HDC, dwcodepages: u32, hsrcfont: super::Graphics::Gdi::HFONT, phdestfont: *mut super::Graphics::Gdi::HFONT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangFontLink_Impl::MapFont(this, core::mem::transmute_copy(&hdc), core::mem::transmute_copy(&dwcodepages), core::mem::transmute_copy(&hsrcfont), core::mem::transmute_copy(&phdestfont)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseFont<Identity: windows_core::IUnknownImpl + IMLangFontLink_Impl>(this: *mut core::ffi::c_void, hfont: super::Graphics::Gdi::HFONT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangFontLink_Impl::ReleaseFont(this, core::mem::transmute_copy(&hfont)).into()
+            }
+        }
+        unsafe extern "system" fn ResetFontMapping<Identity: windows_core::IUnknownImpl + IMLangFontLink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangFontLink_Impl::ResetFontMapping(this).into()
+            }
+        }
+        Self {
+            base__: <IMLangCodePages_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFontCodePages: GetFontCodePages::<Identity>,
+            MapFont: MapFont::<Identity>,
+            ReleaseFont: ReleaseFont::<Identity>,
+            ResetFontMapping: ResetFontMapping::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IMLangFontLink {}
 windows_core::imp::define_interface!(IMLangFontLink2, IMLangFontLink2_Vtbl, 0xdccfc162_2b38_11d2_b7ec_00c04f8f5d9a);
 impl core::ops::Deref for IMLangFontLink2 {
@@ -8312,6 +8632,72 @@ impl IMLangFontLink2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IMLangFontLink2_Impl> windows_core::imp::TearOffVtable<Identity> for IMLangFontLink2_Vtbl
+where
+    IMLangCodePages_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFontCodePages<Identity: windows_core::IUnknownImpl + IMLangFontLink2_Impl>(this: *mut core::ffi::c_void, hdc: super::Graphics::Gdi::HDC, hfont: super::Graphics::Gdi::HFONT, pdwcodepages: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangFontLink2_Impl::GetFontCodePages(this, core::mem::transmute_copy(&hdc), core::mem::transmute_copy(&hfont), core::mem::transmute_copy(&pdwcodepages)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseFont<Identity: windows_core::IUnknownImpl + IMLangFontLink2_Impl>(this: *mut core::ffi::c_void, hfont: super::Graphics::Gdi::HFONT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangFontLink2_Impl::ReleaseFont(this, core::mem::transmute_copy(&hfont)).into()
+            }
+        }
+        unsafe extern "system" fn ResetFontMapping<Identity: windows_core::IUnknownImpl + IMLangFontLink2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangFontLink2_Impl::ResetFontMapping(this).into()
+            }
+        }
+        unsafe extern "system" fn MapFont<Identity: windows_core::IUnknownImpl + IMLangFontLink2_Impl>(this: *mut core::ffi::c_void, hdc: super::Graphics::Gdi::HDC, dwcodepages: u32, chsrc: u16, pfont: *mut super::Graphics::Gdi::HFONT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangFontLink2_Impl::MapFont(this, core::mem::transmute_copy(&hdc), core::mem::transmute_copy(&dwcodepages), core::mem::transmute_copy(&chsrc), core::mem::transmute_copy(&pfont)).into()
+            }
+        }
+        unsafe extern "system" fn GetFontUnicodeRanges<Identity: windows_core::IUnknownImpl + IMLangFontLink2_Impl>(this: *mut core::ffi::c_void, hdc: super::Graphics::Gdi::HDC, puiranges: *const u32, puranges: *mut UNICODERANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangFontLink2_Impl::GetFontUnicodeRanges(this, core::mem::transmute_copy(&hdc), core::mem::transmute_copy(&puiranges), core::mem::transmute_copy(&puranges)).into()
+            }
+        }
+        unsafe extern "system" fn GetScriptFontInfo<Identity: windows_core::IUnknownImpl + IMLangFontLink2_Impl>(this: *mut core::ffi::c_void, sid: u8, dwflags: u32, puifonts: *mut u32, pscriptfont: *mut SCRIPTFONTINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangFontLink2_Impl::GetScriptFontInfo(this, core::mem::transmute_copy(&sid), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&puifonts), core::mem::transmute_copy(&pscriptfont)).into()
+            }
+        }
+        unsafe extern "system" fn CodePageToScriptID<Identity: windows_core::IUnknownImpl + IMLangFontLink2_Impl>(this: *mut core::ffi::c_void, uicodepage: u32, psid: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMLangFontLink2_Impl::CodePageToScriptID(this, core::mem::transmute_copy(&uicodepage)) {
+                    Ok(ok__) => {
+                        psid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IMLangCodePages_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFontCodePages: GetFontCodePages::<Identity>,
+            ReleaseFont: ReleaseFont::<Identity>,
+            ResetFontMapping: ResetFontMapping::<Identity>,
+            MapFont: MapFont::<Identity>,
+            GetFontUnicodeRanges: GetFontUnicodeRanges::<Identity>,
+            GetScriptFontInfo: GetScriptFontInfo::<Identity>,
+            CodePageToScriptID: CodePageToScriptID::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IMLangFontLink2 {}
 windows_core::imp::define_interface!(IMLangLineBreakConsole, IMLangLineBreakConsole_Vtbl, 0xf5be2ee1_bfd7_11d0_b188_00aa0038c969);
 windows_core::imp::interface_hierarchy!(IMLangLineBreakConsole, windows_core::IUnknown);
@@ -8373,6 +8759,37 @@ impl IMLangLineBreakConsole_Vtbl {
         iid == &<IMLangLineBreakConsole as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMLangLineBreakConsole_Impl> windows_core::imp::TearOffVtable<Identity> for IMLangLineBreakConsole_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BreakLineML<Identity: windows_core::IUnknownImpl + IMLangLineBreakConsole_Impl>(this: *mut core::ffi::c_void, psrcmlstr: *mut core::ffi::c_void, lsrcpos: i32, lsrclen: i32, cmincolumns: i32, cmaxcolumns: i32, pllinelen: *mut i32, plskiplen: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangLineBreakConsole_Impl::BreakLineML(this, core::mem::transmute_copy(&psrcmlstr), core::mem::transmute_copy(&lsrcpos), core::mem::transmute_copy(&lsrclen), core::mem::transmute_copy(&cmincolumns), core::mem::transmute_copy(&cmaxcolumns), core::mem::transmute_copy(&pllinelen), core::mem::transmute_copy(&plskiplen)).into()
+            }
+        }
+        unsafe extern "system" fn BreakLineW<Identity: windows_core::IUnknownImpl + IMLangLineBreakConsole_Impl>(this: *mut core::ffi::c_void, locale: u32, pszsrc: windows_core::PCWSTR, cchsrc: i32, cmaxcolumns: i32, pcchline: *mut i32, pcchskip: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangLineBreakConsole_Impl::BreakLineW(this, core::mem::transmute_copy(&locale), core::mem::transmute(&pszsrc), core::mem::transmute_copy(&cchsrc), core::mem::transmute_copy(&cmaxcolumns), core::mem::transmute_copy(&pcchline), core::mem::transmute_copy(&pcchskip)).into()
+            }
+        }
+        unsafe extern "system" fn BreakLineA<Identity: windows_core::IUnknownImpl + IMLangLineBreakConsole_Impl>(this: *mut core::ffi::c_void, locale: u32, ucodepage: u32, pszsrc: windows_core::PCSTR, cchsrc: i32, cmaxcolumns: i32, pcchline: *mut i32, pcchskip: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangLineBreakConsole_Impl::BreakLineA(this, core::mem::transmute_copy(&locale), core::mem::transmute_copy(&ucodepage), core::mem::transmute(&pszsrc), core::mem::transmute_copy(&cchsrc), core::mem::transmute_copy(&cmaxcolumns), core::mem::transmute_copy(&pcchline), core::mem::transmute_copy(&pcchskip)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            BreakLineML: BreakLineML::<Identity>,
+            BreakLineW: BreakLineW::<Identity>,
+            BreakLineA: BreakLineA::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMLangLineBreakConsole {}
 windows_core::imp::define_interface!(IMLangString, IMLangString_Vtbl, 0xc04d65ce_b70d_11d0_b188_00aa0038c969);
 windows_core::imp::interface_hierarchy!(IMLangString, windows_core::IUnknown);
@@ -8449,10 +8866,48 @@ impl IMLangString_Vtbl {
         iid == &<IMLangString as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IMLangString {}
-windows_core::imp::define_interface!(IMLangStringAStr, IMLangStringAStr_Vtbl, 0xc04d65d2_b70d_11d0_b188_00aa0038c969);
-impl core::ops::Deref for IMLangStringAStr {
-    type Target = IMLangString;
+impl<Identity: windows_core::IUnknownImpl + IMLangString_Impl> windows_core::imp::TearOffVtable<Identity> for IMLangString_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Sync<Identity: windows_core::IUnknownImpl + IMLangString_Impl>(this: *mut core::ffi::c_void, fnoaccess: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangString_Impl::Sync(this, core::mem::transmute_copy(&fnoaccess)).into()
+            }
+        }
+        unsafe extern "system" fn GetLength<Identity: windows_core::IUnknownImpl + IMLangString_Impl>(this: *mut core::ffi::c_void, pllen: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangString_Impl::GetLength(this, core::mem::transmute_copy(&pllen)).into()
+            }
+        }
+        unsafe extern "system" fn SetMLStr<Identity: windows_core::IUnknownImpl + IMLangString_Impl>(this: *mut core::ffi::c_void, ldestpos: i32, ldestlen: i32, psrcmlstr: *mut core::ffi::c_void, lsrcpos: i32, lsrclen: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangString_Impl::SetMLStr(this, core::mem::transmute_copy(&ldestpos), core::mem::transmute_copy(&ldestlen), core::mem::transmute_copy(&psrcmlstr), core::mem::transmute_copy(&lsrcpos), core::mem::transmute_copy(&lsrclen)).into()
+            }
+        }
+        unsafe extern "system" fn GetMLStr<Identity: windows_core::IUnknownImpl + IMLangString_Impl>(this: *mut core::ffi::c_void, lsrcpos: i32, lsrclen: i32, punkouter: *mut core::ffi::c_void, dwclscontext: u32, piid: *const windows_core::GUID, ppdestmlstr: *mut *mut core::ffi::c_void, pldestpos: *mut i32, pldestlen: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangString_Impl::GetMLStr(this, core::mem::transmute_copy(&lsrcpos), core::mem::transmute_copy(&lsrclen), core::mem::transmute_copy(&punkouter), core::mem::transmute_copy(&dwclscontext), core::mem::transmute_copy(&piid), core::mem::transmute_copy(&ppdestmlstr), core::mem::transmute_copy(&pldestpos), core::mem::transmute_copy(&pldestlen)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Sync: Sync::<Identity>,
+            GetLength: GetLength::<Identity>,
+            SetMLStr: SetMLStr::<Identity>,
+            GetMLStr: GetMLStr::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IMLangString {}
+windows_core::imp::define_interface!(IMLangStringAStr, IMLangStringAStr_Vtbl, 0xc04d65d2_b70d_11d0_b188_00aa0038c969);
+impl core::ops::Deref for IMLangStringAStr {
+    type Target = IMLangString;
     fn deref(&self) -> &Self::Target {
         unsafe { core::mem::transmute(self) }
     }
@@ -8576,6 +9031,72 @@ impl IMLangStringAStr_Vtbl {
         iid == &<IMLangStringAStr as windows_core::Interface>::IID || iid == &<IMLangString as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMLangStringAStr_Impl> windows_core::imp::TearOffVtable<Identity> for IMLangStringAStr_Vtbl
+where
+    IMLangString_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAStr<Identity: windows_core::IUnknownImpl + IMLangStringAStr_Impl>(this: *mut core::ffi::c_void, ldestpos: i32, ldestlen: i32, ucodepage: u32, pszsrc: windows_core::PCSTR, cchsrc: i32, pcchactual: *mut i32, plactuallen: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringAStr_Impl::SetAStr(this, core::mem::transmute_copy(&ldestpos), core::mem::transmute_copy(&ldestlen), core::mem::transmute_copy(&ucodepage), core::mem::transmute(&pszsrc), core::mem::transmute_copy(&cchsrc), core::mem::transmute_copy(&pcchactual), core::mem::transmute_copy(&plactuallen)).into()
+            }
+        }
+        unsafe extern "system" fn SetStrBufA<Identity: windows_core::IUnknownImpl + IMLangStringAStr_Impl>(this: *mut core::ffi::c_void, ldestpos: i32, ldestlen: i32, ucodepage: u32, psrcbuf: *mut core::ffi::c_void, pcchactual: *mut i32, plactuallen: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringAStr_Impl::SetStrBufA(this, core::mem::transmute_copy(&ldestpos), core::mem::transmute_copy(&ldestlen), core::mem::transmute_copy(&ucodepage), core::mem::transmute_copy(&psrcbuf), core::mem::transmute_copy(&pcchactual), core::mem::transmute_copy(&plactuallen)).into()
+            }
+        }
+        unsafe extern "system" fn GetAStr<Identity: windows_core::IUnknownImpl + IMLangStringAStr_Impl>(this: *mut core::ffi::c_void, lsrcpos: i32, lsrclen: i32, ucodepagein: u32, pucodepageout: *const u32, pszdest: windows_core::PSTR, cchdest: i32, pcchactual: *mut i32, plactuallen: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringAStr_Impl::GetAStr(this, core::mem::transmute_copy(&lsrcpos), core::mem::transmute_copy(&lsrclen), core::mem::transmute_copy(&ucodepagein), core::mem::transmute_copy(&pucodepageout), core::mem::transmute_copy(&pszdest), core::mem::transmute_copy(&cchdest), core::mem::transmute_copy(&pcchactual), core::mem::transmute_copy(&plactuallen)).into()
+            }
+        }
+        unsafe extern "system" fn GetStrBufA<Identity: windows_core::IUnknownImpl + IMLangStringAStr_Impl>(this: *mut core::ffi::c_void, lsrcpos: i32, lsrcmaxlen: i32, pudestcodepage: *mut u32, ppdestbuf: *mut *mut core::ffi::c_void, pldestlen: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringAStr_Impl::GetStrBufA(this, core::mem::transmute_copy(&lsrcpos), core::mem::transmute_copy(&lsrcmaxlen), core::mem::transmute_copy(&pudestcodepage), core::mem::transmute_copy(&ppdestbuf), core::mem::transmute_copy(&pldestlen)).into()
+            }
+        }
+        unsafe extern "system" fn LockAStr<Identity: windows_core::IUnknownImpl + IMLangStringAStr_Impl>(this: *mut core::ffi::c_void, lsrcpos: i32, lsrclen: i32, lflags: i32, ucodepagein: u32, cchrequest: i32, pucodepageout: *mut u32, ppszdest: *mut windows_core::PSTR, pcchdest: *mut i32, pldestlen: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringAStr_Impl::LockAStr(this, core::mem::transmute_copy(&lsrcpos), core::mem::transmute_copy(&lsrclen), core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&ucodepagein), core::mem::transmute_copy(&cchrequest), core::mem::transmute_copy(&pucodepageout), core::mem::transmute_copy(&ppszdest), core::mem::transmute_copy(&pcchdest), core::mem::transmute_copy(&pldestlen)).into()
+            }
+        }
+        unsafe extern "system" fn UnlockAStr<Identity: windows_core::IUnknownImpl + IMLangStringAStr_Impl>(this: *mut core::ffi::c_void, pszsrc: windows_core::PCSTR, cchsrc: i32, pcchactual: *mut i32, plactuallen: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringAStr_Impl::UnlockAStr(this, core::mem::transmute(&pszsrc), core::mem::transmute_copy(&cchsrc), core::mem::transmute_copy(&pcchactual), core::mem::transmute_copy(&plactuallen)).into()
+            }
+        }
+        unsafe extern "system" fn SetLocale<Identity: windows_core::IUnknownImpl + IMLangStringAStr_Impl>(this: *mut core::ffi::c_void, ldestpos: i32, ldestlen: i32, locale: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringAStr_Impl::SetLocale(this, core::mem::transmute_copy(&ldestpos), core::mem::transmute_copy(&ldestlen), core::mem::transmute_copy(&locale)).into()
+            }
+        }
+        unsafe extern "system" fn GetLocale<Identity: windows_core::IUnknownImpl + IMLangStringAStr_Impl>(this: *mut core::ffi::c_void, lsrcpos: i32, lsrcmaxlen: i32, plocale: *mut u32, pllocalepos: *mut i32, pllocalelen: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringAStr_Impl::GetLocale(this, core::mem::transmute_copy(&lsrcpos), core::mem::transmute_copy(&lsrcmaxlen), core::mem::transmute_copy(&plocale), core::mem::transmute_copy(&pllocalepos), core::mem::transmute_copy(&pllocalelen)).into()
+            }
+        }
+        Self {
+            base__: <IMLangString_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAStr: SetAStr::<Identity>,
+            SetStrBufA: SetStrBufA::<Identity>,
+            GetAStr: GetAStr::<Identity>,
+            GetStrBufA: GetStrBufA::<Identity>,
+            LockAStr: LockAStr::<Identity>,
+            UnlockAStr: UnlockAStr::<Identity>,
+            SetLocale: SetLocale::<Identity>,
+            GetLocale: GetLocale::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMLangStringAStr {}
 windows_core::imp::define_interface!(IMLangStringBufA, IMLangStringBufA_Vtbl, 0xd24acd23_ba72_11d0_b188_00aa0038c969);
 windows_core::imp::interface_hierarchy!(IMLangStringBufA, windows_core::IUnknown);
@@ -8661,6 +9182,51 @@ impl IMLangStringBufA_Vtbl {
         iid == &<IMLangStringBufA as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMLangStringBufA_Impl> windows_core::imp::TearOffVtable<Identity> for IMLangStringBufA_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStatus<Identity: windows_core::IUnknownImpl + IMLangStringBufA_Impl>(this: *mut core::ffi::c_void, plflags: *mut i32, pcchbuf: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringBufA_Impl::GetStatus(this, core::mem::transmute_copy(&plflags), core::mem::transmute_copy(&pcchbuf)).into()
+            }
+        }
+        unsafe extern "system" fn LockBuf<Identity: windows_core::IUnknownImpl + IMLangStringBufA_Impl>(this: *mut core::ffi::c_void, cchoffset: i32, cchmaxlock: i32, ppszbuf: *mut *mut i8, pcchbuf: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringBufA_Impl::LockBuf(this, core::mem::transmute_copy(&cchoffset), core::mem::transmute_copy(&cchmaxlock), core::mem::transmute_copy(&ppszbuf), core::mem::transmute_copy(&pcchbuf)).into()
+            }
+        }
+        unsafe extern "system" fn UnlockBuf<Identity: windows_core::IUnknownImpl + IMLangStringBufA_Impl>(this: *mut core::ffi::c_void, pszbuf: windows_core::PCSTR, cchoffset: i32, cchwrite: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringBufA_Impl::UnlockBuf(this, core::mem::transmute(&pszbuf), core::mem::transmute_copy(&cchoffset), core::mem::transmute_copy(&cchwrite)).into()
+            }
+        }
+        unsafe extern "system" fn Insert<Identity: windows_core::IUnknownImpl + IMLangStringBufA_Impl>(this: *mut core::ffi::c_void, cchoffset: i32, cchmaxinsert: i32, pcchactual: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringBufA_Impl::Insert(this, core::mem::transmute_copy(&cchoffset), core::mem::transmute_copy(&cchmaxinsert), core::mem::transmute_copy(&pcchactual)).into()
+            }
+        }
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IMLangStringBufA_Impl>(this: *mut core::ffi::c_void, cchoffset: i32, cchdelete: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringBufA_Impl::Delete(this, core::mem::transmute_copy(&cchoffset), core::mem::transmute_copy(&cchdelete)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStatus: GetStatus::<Identity>,
+            LockBuf: LockBuf::<Identity>,
+            UnlockBuf: UnlockBuf::<Identity>,
+            Insert: Insert::<Identity>,
+            Delete: Delete::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMLangStringBufA {}
 windows_core::imp::define_interface!(IMLangStringBufW, IMLangStringBufW_Vtbl, 0xd24acd21_ba72_11d0_b188_00aa0038c969);
 windows_core::imp::interface_hierarchy!(IMLangStringBufW, windows_core::IUnknown);
@@ -8746,6 +9312,51 @@ impl IMLangStringBufW_Vtbl {
         iid == &<IMLangStringBufW as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMLangStringBufW_Impl> windows_core::imp::TearOffVtable<Identity> for IMLangStringBufW_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStatus<Identity: windows_core::IUnknownImpl + IMLangStringBufW_Impl>(this: *mut core::ffi::c_void, plflags: *mut i32, pcchbuf: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringBufW_Impl::GetStatus(this, core::mem::transmute_copy(&plflags), core::mem::transmute_copy(&pcchbuf)).into()
+            }
+        }
+        unsafe extern "system" fn LockBuf<Identity: windows_core::IUnknownImpl + IMLangStringBufW_Impl>(this: *mut core::ffi::c_void, cchoffset: i32, cchmaxlock: i32, ppszbuf: *mut *mut u16, pcchbuf: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringBufW_Impl::LockBuf(this, core::mem::transmute_copy(&cchoffset), core::mem::transmute_copy(&cchmaxlock), core::mem::transmute_copy(&ppszbuf), core::mem::transmute_copy(&pcchbuf)).into()
+            }
+        }
+        unsafe extern "system" fn UnlockBuf<Identity: windows_core::IUnknownImpl + IMLangStringBufW_Impl>(this: *mut core::ffi::c_void, pszbuf: windows_core::PCWSTR, cchoffset: i32, cchwrite: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringBufW_Impl::UnlockBuf(this, core::mem::transmute(&pszbuf), core::mem::transmute_copy(&cchoffset), core::mem::transmute_copy(&cchwrite)).into()
+            }
+        }
+        unsafe extern "system" fn Insert<Identity: windows_core::IUnknownImpl + IMLangStringBufW_Impl>(this: *mut core::ffi::c_void, cchoffset: i32, cchmaxinsert: i32, pcchactual: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringBufW_Impl::Insert(this, core::mem::transmute_copy(&cchoffset), core::mem::transmute_copy(&cchmaxinsert), core::mem::transmute_copy(&pcchactual)).into()
+            }
+        }
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IMLangStringBufW_Impl>(this: *mut core::ffi::c_void, cchoffset: i32, cchdelete: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringBufW_Impl::Delete(this, core::mem::transmute_copy(&cchoffset), core::mem::transmute_copy(&cchdelete)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStatus: GetStatus::<Identity>,
+            LockBuf: LockBuf::<Identity>,
+            UnlockBuf: UnlockBuf::<Identity>,
+            Insert: Insert::<Identity>,
+            Delete: Delete::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMLangStringBufW {}
 windows_core::imp::define_interface!(IMLangStringWStr, IMLangStringWStr_Vtbl, 0xc04d65d0_b70d_11d0_b188_00aa0038c969);
 impl core::ops::Deref for IMLangStringWStr {
@@ -8873,6 +9484,72 @@ impl IMLangStringWStr_Vtbl {
         iid == &<IMLangStringWStr as windows_core::Interface>::IID || iid == &<IMLangString as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMLangStringWStr_Impl> windows_core::imp::TearOffVtable<Identity> for IMLangStringWStr_Vtbl
+where
+    IMLangString_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetWStr<Identity: windows_core::IUnknownImpl + IMLangStringWStr_Impl>(this: *mut core::ffi::c_void, ldestpos: i32, ldestlen: i32, pszsrc: windows_core::PCWSTR, cchsrc: i32, pcchactual: *mut i32, plactuallen: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringWStr_Impl::SetWStr(this, core::mem::transmute_copy(&ldestpos), core::mem::transmute_copy(&ldestlen), core::mem::transmute(&pszsrc), core::mem::transmute_copy(&cchsrc), core::mem::transmute_copy(&pcchactual), core::mem::transmute_copy(&plactuallen)).into()
+            }
+        }
+        unsafe extern "system" fn SetStrBufW<Identity: windows_core::IUnknownImpl + IMLangStringWStr_Impl>(this: *mut core::ffi::c_void, ldestpos: i32, ldestlen: i32, psrcbuf: *mut core::ffi::c_void, pcchactual: *mut i32, plactuallen: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringWStr_Impl::SetStrBufW(this, core::mem::transmute_copy(&ldestpos), core::mem::transmute_copy(&ldestlen), core::mem::transmute_copy(&psrcbuf), core::mem::transmute_copy(&pcchactual), core::mem::transmute_copy(&plactuallen)).into()
+            }
+        }
+        unsafe extern "system" fn GetWStr<Identity: windows_core::IUnknownImpl + IMLangStringWStr_Impl>(this: *mut core::ffi::c_void, lsrcpos: i32, lsrclen: i32, pszdest: windows_core::PWSTR, cchdest: i32, pcchactual: *mut i32, plactuallen: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringWStr_Impl::GetWStr(this, core::mem::transmute_copy(&lsrcpos), core::mem::transmute_copy(&lsrclen), core::mem::transmute_copy(&pszdest), core::mem::transmute_copy(&cchdest), core::mem::transmute_copy(&pcchactual), core::mem::transmute_copy(&plactuallen)).into()
+            }
+        }
+        unsafe extern "system" fn GetStrBufW<Identity: windows_core::IUnknownImpl + IMLangStringWStr_Impl>(this: *mut core::ffi::c_void, lsrcpos: i32, lsrcmaxlen: i32, ppdestbuf: *mut *mut core::ffi::c_void, pldestlen: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringWStr_Impl::GetStrBufW(this, core::mem::transmute_copy(&lsrcpos), core::mem::transmute_copy(&lsrcmaxlen), core::mem::transmute_copy(&ppdestbuf), core::mem::transmute_copy(&pldestlen)).into()
+            }
+        }
+        unsafe extern "system" fn LockWStr<Identity: windows_core::IUnknownImpl + IMLangStringWStr_Impl>(this: *mut core::ffi::c_void, lsrcpos: i32, lsrclen: i32, lflags: i32, cchrequest: i32, ppszdest: *mut windows_core::PWSTR, pcchdest: *mut i32, pldestlen: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringWStr_Impl::LockWStr(this, core::mem::transmute_copy(&lsrcpos), core::mem::transmute_copy(&lsrclen), core::mem::transmute_copy(&lflags), core::mem::transmute_copy(&cchrequest), core::mem::transmute_copy(&ppszdest), core::mem::transmute_copy(&pcchdest), core::mem::transmute_copy(&pldestlen)).into()
+            }
+        }
+        unsafe extern "system" fn UnlockWStr<Identity: windows_core::IUnknownImpl + IMLangStringWStr_Impl>(this: *mut core::ffi::c_void, pszsrc: windows_core::PCWSTR, cchsrc: i32, pcchactual: *mut i32, plactuallen: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringWStr_Impl::UnlockWStr(this, core::mem::transmute(&pszsrc), core::mem::transmute_copy(&cchsrc), core::mem::transmute_copy(&pcchactual), core::mem::transmute_copy(&plactuallen)).into()
+            }
+        }
+        unsafe extern "system" fn SetLocale<Identity: windows_core::IUnknownImpl + IMLangStringWStr_Impl>(this: *mut core::ffi::c_void, ldestpos: i32, ldestlen: i32, locale: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringWStr_Impl::SetLocale(this, core::mem::transmute_copy(&ldestpos), core::mem::transmute_copy(&ldestlen), core::mem::transmute_copy(&locale)).into()
+            }
+        }
+        unsafe extern "system" fn GetLocale<Identity: windows_core::IUnknownImpl + IMLangStringWStr_Impl>(this: *mut core::ffi::c_void, lsrcpos: i32, lsrcmaxlen: i32, plocale: *mut u32, pllocalepos: *mut i32, pllocalelen: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMLangStringWStr_Impl::GetLocale(this, core::mem::transmute_copy(&lsrcpos), core::mem::transmute_copy(&lsrcmaxlen), core::mem::transmute_copy(&plocale), core::mem::transmute_copy(&pllocalepos), core::mem::transmute_copy(&pllocalelen)).into()
+            }
+        }
+        Self {
+            base__: <IMLangString_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetWStr: SetWStr::<Identity>,
+            SetStrBufW: SetStrBufW::<Identity>,
+            GetWStr: GetWStr::<Identity>,
+            GetStrBufW: GetStrBufW::<Identity>,
+            LockWStr: LockWStr::<Identity>,
+            UnlockWStr: UnlockWStr::<Identity>,
+            SetLocale: SetLocale::<Identity>,
+            GetLocale: GetLocale::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMLangStringWStr {}
 windows_core::imp::define_interface!(IMultiLanguage, IMultiLanguage_Vtbl, 0x275c23e1_3747_11d0_9fea_00aa003f8646);
 windows_core::imp::interface_hierarchy!(IMultiLanguage, windows_core::IUnknown);
@@ -9135,6 +9812,157 @@ impl IMultiLanguage_Vtbl {
         iid == &<IMultiLanguage as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMultiLanguage_Impl> windows_core::imp::TearOffVtable<Identity> for IMultiLanguage_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetNumberOfCodePageInfo<Identity: windows_core::IUnknownImpl + IMultiLanguage_Impl>(this: *mut core::ffi::c_void, pccodepage: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMultiLanguage_Impl::GetNumberOfCodePageInfo(this) {
+                    Ok(ok__) => {
+                        pccodepage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCodePageInfo<Identity: windows_core::IUnknownImpl + IMultiLanguage_Impl>(this: *mut core::ffi::c_void, uicodepage: u32, pcodepageinfo: *mut MIMECPINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage_Impl::GetCodePageInfo(this, core::mem::transmute_copy(&uicodepage), core::mem::transmute_copy(&pcodepageinfo)).into()
+            }
+        }
+        unsafe extern "system" fn GetFamilyCodePage<Identity: windows_core::IUnknownImpl + IMultiLanguage_Impl>(this: *mut core::ffi::c_void, uicodepage: u32, puifamilycodepage: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMultiLanguage_Impl::GetFamilyCodePage(this, core::mem::transmute_copy(&uicodepage)) {
+                    Ok(ok__) => {
+                        puifamilycodepage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumCodePages<Identity: windows_core::IUnknownImpl + IMultiLanguage_Impl>(this: *mut core::ffi::c_void, grfflags: u32, ppenumcodepage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMultiLanguage_Impl::EnumCodePages(this, core::mem::transmute_copy(&grfflags)) {
+                    Ok(ok__) => {
+                        ppenumcodepage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCharsetInfo<Identity: windows_core::IUnknownImpl + IMultiLanguage_Impl>(this: *mut core::ffi::c_void, charset: *mut core::ffi::c_void, pcharsetinfo: *mut MIMECSETINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage_Impl::GetCharsetInfo(this, core::mem::transmute(&charset), core::mem::transmute_copy(&pcharsetinfo)).into()
+            }
+        }
+        unsafe extern "system" fn IsConvertible<Identity: windows_core::IUnknownImpl + IMultiLanguage_Impl>(this: *mut core::ffi::c_void, dwsrcencoding: u32, dwdstencoding: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage_Impl::IsConvertible(this, core::mem::transmute_copy(&dwsrcencoding), core::mem::transmute_copy(&dwdstencoding)).into()
+            }
+        }
+        unsafe extern "system" fn ConvertString<Identity: windows_core::IUnknownImpl + IMultiLanguage_Impl>(this: *mut core::ffi::c_void, pdwmode: *mut u32, dwsrcencoding: u32, dwdstencoding: u32, psrcstr: *const u8, pcsrcsize: *mut u32, pdststr: *mut u8, pcdstsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage_Impl::ConvertString(this, core::mem::transmute_copy(&pdwmode), core::mem::transmute_copy(&dwsrcencoding), core::mem::transmute_copy(&dwdstencoding), core::mem::transmute_copy(&psrcstr), core::mem::transmute_copy(&pcsrcsize), core::mem::transmute_copy(&pdststr), core::mem::transmute_copy(&pcdstsize)).into()
+            }
+        }
+        unsafe extern "system" fn ConvertStringToUnicode<Identity: windows_core::IUnknownImpl + IMultiLanguage_Impl>(this: *mut core::ffi::c_void, pdwmode: *mut u32, dwencoding: u32, psrcstr: windows_core::PCSTR, pcsrcsize: *mut u32, pdststr: windows_core::PWSTR, pcdstsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage_Impl::ConvertStringToUnicode(this, core::mem::transmute_copy(&pdwmode), core::mem::transmute_copy(&dwencoding), core::mem::transmute(&psrcstr), core::mem::transmute_copy(&pcsrcsize), core::mem::transmute_copy(&pdststr), core::mem::transmute_copy(&pcdstsize)).into()
+            }
+        }
+        unsafe extern "system" fn ConvertStringFromUnicode<Identity: windows_core::IUnknownImpl + IMultiLanguage_Impl>(this: *mut core::ffi::c_void, pdwmode: *mut u32, dwencoding: u32, psrcstr: windows_core::PCWSTR, pcsrcsize: *mut u32, pdststr: windows_core::PSTR, pcdstsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage_Impl::ConvertStringFromUnicode(this, core::mem::transmute_copy(&pdwmode), core::mem::transmute_copy(&dwencoding), core::mem::transmute(&psrcstr), core::mem::transmute_copy(&pcsrcsize), core::mem::transmute_copy(&pdststr), core::mem::transmute_copy(&pcdstsize)).into()
+            }
+        }
+        unsafe extern "system" fn ConvertStringReset<Identity: windows_core::IUnknownImpl + IMultiLanguage_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage_Impl::ConvertStringReset(this).into()
+            }
+        }
+        unsafe extern "system" fn GetRfc1766FromLcid<Identity: windows_core::IUnknownImpl + IMultiLanguage_Impl>(this: *mut core::ffi::c_void, locale: u32, pbstrrfc1766: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMultiLanguage_Impl::GetRfc1766FromLcid(this, core::mem::transmute_copy(&locale)) {
+                    Ok(ok__) => {
+                        pbstrrfc1766.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLcidFromRfc1766<Identity: windows_core::IUnknownImpl + IMultiLanguage_Impl>(this: *mut core::ffi::c_void, plocale: *mut u32, bstrrfc1766: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage_Impl::GetLcidFromRfc1766(this, core::mem::transmute_copy(&plocale), core::mem::transmute(&bstrrfc1766)).into()
+            }
+        }
+        unsafe extern "system" fn EnumRfc1766<Identity: windows_core::IUnknownImpl + IMultiLanguage_Impl>(this: *mut core::ffi::c_void, ppenumrfc1766: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMultiLanguage_Impl::EnumRfc1766(this) {
+                    Ok(ok__) => {
+                        ppenumrfc1766.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRfc1766Info<Identity: windows_core::IUnknownImpl + IMultiLanguage_Impl>(this: *mut core::ffi::c_void, locale: u32, prfc1766info: *mut RFC1766INFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage_Impl::GetRfc1766Info(this, core::mem::transmute_copy(&locale), core::mem::transmute_copy(&prfc1766info)).into()
+            }
+        }
+        unsafe extern "system" fn CreateConvertCharset<Identity: windows_core::IUnknownImpl + IMultiLanguage_Impl>(this: *mut core::ffi::c_void, uisrccodepage: u32, uidstcodepage: u32, dwproperty: u32, ppmlangconvertcharset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMultiLanguage_Impl::CreateConvertCharset(this, core::mem::transmute_copy(&uisrccodepage), core::mem::transmute_copy(&uidstcodepage), core::mem::transmute_copy(&dwproperty)) {
+                    Ok(ok__) => {
+                        ppmlangconvertcharset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetNumberOfCodePageInfo: GetNumberOfCodePageInfo::<Identity>,
+            GetCodePageInfo: GetCodePageInfo::<Identity>,
+            GetFamilyCodePage: GetFamilyCodePage::<Identity>,
+            EnumCodePages: EnumCodePages::<Identity>,
+            GetCharsetInfo: GetCharsetInfo::<Identity>,
+            IsConvertible: IsConvertible::<Identity>,
+            ConvertString: ConvertString::<Identity>,
+            ConvertStringToUnicode: ConvertStringToUnicode::<Identity>,
+            ConvertStringFromUnicode: ConvertStringFromUnicode::<Identity>,
+            ConvertStringReset: ConvertStringReset::<Identity>,
+            GetRfc1766FromLcid: GetRfc1766FromLcid::<Identity>,
+            GetLcidFromRfc1766: GetLcidFromRfc1766::<Identity>,
+            EnumRfc1766: EnumRfc1766::<Identity>,
+            GetRfc1766Info: GetRfc1766Info::<Identity>,
+            CreateConvertCharset: CreateConvertCharset::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMultiLanguage {}
 windows_core::imp::define_interface!(IMultiLanguage2, IMultiLanguage2_Vtbl, 0xdccfc164_2b38_11d2_b7ec_00c04f8f5d9a);
 windows_core::imp::interface_hierarchy!(IMultiLanguage2, windows_core::IUnknown);
@@ -9589,12 +10417,260 @@ impl IMultiLanguage2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
-impl windows_core::RuntimeName for IMultiLanguage2 {}
-windows_core::imp::define_interface!(IMultiLanguage3, IMultiLanguage3_Vtbl, 0x4e5868ab_b157_4623_9acc_6a1d9caebe04);
-impl core::ops::Deref for IMultiLanguage3 {
-    type Target = IMultiLanguage2;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
+impl<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl> windows_core::imp::TearOffVtable<Identity> for IMultiLanguage2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetNumberOfCodePageInfo<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, pccodepage: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMultiLanguage2_Impl::GetNumberOfCodePageInfo(this) {
+                    Ok(ok__) => {
+                        pccodepage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCodePageInfo<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, uicodepage: u32, langid: u16, pcodepageinfo: *mut MIMECPINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage2_Impl::GetCodePageInfo(this, core::mem::transmute_copy(&uicodepage), core::mem::transmute_copy(&langid), core::mem::transmute_copy(&pcodepageinfo)).into()
+            }
+        }
+        unsafe extern "system" fn GetFamilyCodePage<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, uicodepage: u32, puifamilycodepage: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMultiLanguage2_Impl::GetFamilyCodePage(this, core::mem::transmute_copy(&uicodepage)) {
+                    Ok(ok__) => {
+                        puifamilycodepage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumCodePages<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, grfflags: u32, langid: u16, ppenumcodepage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMultiLanguage2_Impl::EnumCodePages(this, core::mem::transmute_copy(&grfflags), core::mem::transmute_copy(&langid)) {
+                    Ok(ok__) => {
+                        ppenumcodepage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCharsetInfo<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, charset: *mut core::ffi::c_void, pcharsetinfo: *mut MIMECSETINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage2_Impl::GetCharsetInfo(this, core::mem::transmute(&charset), core::mem::transmute_copy(&pcharsetinfo)).into()
+            }
+        }
+        unsafe extern "system" fn IsConvertible<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, dwsrcencoding: u32, dwdstencoding: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage2_Impl::IsConvertible(this, core::mem::transmute_copy(&dwsrcencoding), core::mem::transmute_copy(&dwdstencoding)).into()
+            }
+        }
+        unsafe extern "system" fn ConvertString<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, pdwmode: *mut u32, dwsrcencoding: u32, dwdstencoding: u32, psrcstr: *const u8, pcsrcsize: *mut u32, pdststr: *mut u8, pcdstsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage2_Impl::ConvertString(this, core::mem::transmute_copy(&pdwmode), core::mem::transmute_copy(&dwsrcencoding), core::mem::transmute_copy(&dwdstencoding), core::mem::transmute_copy(&psrcstr), core::mem::transmute_copy(&pcsrcsize), core::mem::transmute_copy(&pdststr), core::mem::transmute_copy(&pcdstsize)).into()
+            }
+        }
+        unsafe extern "system" fn ConvertStringToUnicode<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, pdwmode: *mut u32, dwencoding: u32, psrcstr: windows_core::PCSTR, pcsrcsize: *mut u32, pdststr: windows_core::PWSTR, pcdstsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage2_Impl::ConvertStringToUnicode(this, core::mem::transmute_copy(&pdwmode), core::mem::transmute_copy(&dwencoding), core::mem::transmute(&psrcstr), core::mem::transmute_copy(&pcsrcsize), core::mem::transmute_copy(&pdststr), core::mem::transmute_copy(&pcdstsize)).into()
+            }
+        }
+        unsafe extern "system" fn ConvertStringFromUnicode<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, pdwmode: *mut u32, dwencoding: u32, psrcstr: windows_core::PCWSTR, pcsrcsize: *mut u32, pdststr: windows_core::PSTR, pcdstsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage2_Impl::ConvertStringFromUnicode(this, core::mem::transmute_copy(&pdwmode), core::mem::transmute_copy(&dwencoding), core::mem::transmute(&psrcstr), core::mem::transmute_copy(&pcsrcsize), core::mem::transmute_copy(&pdststr), core::mem::transmute_copy(&pcdstsize)).into()
+            }
+        }
+        unsafe extern "system" fn ConvertStringReset<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage2_Impl::ConvertStringReset(this).into()
+            }
+        }
+        unsafe extern "system" fn GetRfc1766FromLcid<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, locale: u32, pbstrrfc1766: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMultiLanguage2_Impl::GetRfc1766FromLcid(this, core::mem::transmute_copy(&locale)) {
+                    Ok(ok__) => {
+                        pbstrrfc1766.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLcidFromRfc1766<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, plocale: *mut u32, bstrrfc1766: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage2_Impl::GetLcidFromRfc1766(this, core::mem::transmute_copy(&plocale), core::mem::transmute(&bstrrfc1766)).into()
+            }
+        }
+        unsafe extern "system" fn EnumRfc1766<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, langid: u16, ppenumrfc1766: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMultiLanguage2_Impl::EnumRfc1766(this, core::mem::transmute_copy(&langid)) {
+                    Ok(ok__) => {
+                        ppenumrfc1766.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRfc1766Info<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, locale: u32, langid: u16, prfc1766info: *mut RFC1766INFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage2_Impl::GetRfc1766Info(this, core::mem::transmute_copy(&locale), core::mem::transmute_copy(&langid), core::mem::transmute_copy(&prfc1766info)).into()
+            }
+        }
+        unsafe extern "system" fn CreateConvertCharset<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, uisrccodepage: u32, uidstcodepage: u32, dwproperty: u32, ppmlangconvertcharset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMultiLanguage2_Impl::CreateConvertCharset(this, core::mem::transmute_copy(&uisrccodepage), core::mem::transmute_copy(&uidstcodepage), core::mem::transmute_copy(&dwproperty)) {
+                    Ok(ok__) => {
+                        ppmlangconvertcharset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ConvertStringInIStream<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, pdwmode: *mut u32, dwflag: u32, lpfallback: windows_core::PCWSTR, dwsrcencoding: u32, dwdstencoding: u32, pstmin: *mut core::ffi::c_void, pstmout: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage2_Impl::ConvertStringInIStream(this, core::mem::transmute_copy(&pdwmode), core::mem::transmute_copy(&dwflag), core::mem::transmute(&lpfallback), core::mem::transmute_copy(&dwsrcencoding), core::mem::transmute_copy(&dwdstencoding), core::mem::transmute_copy(&pstmin), core::mem::transmute_copy(&pstmout)).into()
+            }
+        }
+        unsafe extern "system" fn ConvertStringToUnicodeEx<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, pdwmode: *mut u32, dwencoding: u32, psrcstr: windows_core::PCSTR, pcsrcsize: *mut u32, pdststr: windows_core::PWSTR, pcdstsize: *mut u32, dwflag: u32, lpfallback: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage2_Impl::ConvertStringToUnicodeEx(this, core::mem::transmute_copy(&pdwmode), core::mem::transmute_copy(&dwencoding), core::mem::transmute(&psrcstr), core::mem::transmute_copy(&pcsrcsize), core::mem::transmute_copy(&pdststr), core::mem::transmute_copy(&pcdstsize), core::mem::transmute_copy(&dwflag), core::mem::transmute(&lpfallback)).into()
+            }
+        }
+        unsafe extern "system" fn ConvertStringFromUnicodeEx<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, pdwmode: *mut u32, dwencoding: u32, psrcstr: windows_core::PCWSTR, pcsrcsize: *mut u32, pdststr: windows_core::PSTR, pcdstsize: *mut u32, dwflag: u32, lpfallback: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage2_Impl::ConvertStringFromUnicodeEx(this, core::mem::transmute_copy(&pdwmode), core::mem::transmute_copy(&dwencoding), core::mem::transmute(&psrcstr), core::mem::transmute_copy(&pcsrcsize), core::mem::transmute_copy(&pdststr), core::mem::transmute_copy(&pcdstsize), core::mem::transmute_copy(&dwflag), core::mem::transmute(&lpfallback)).into()
+            }
+        }
+        unsafe extern "system" fn DetectCodepageInIStream<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, dwflag: u32, dwprefwincodepage: u32, pstmin: *mut core::ffi::c_void, lpencoding: *mut DetectEncodingInfo, pnscores: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage2_Impl::DetectCodepageInIStream(this, core::mem::transmute_copy(&dwflag), core::mem::transmute_copy(&dwprefwincodepage), core::mem::transmute_copy(&pstmin), core::mem::transmute_copy(&lpencoding), core::mem::transmute_copy(&pnscores)).into()
+            }
+        }
+        unsafe extern "system" fn DetectInputCodepage<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, dwflag: u32, dwprefwincodepage: u32, psrcstr: windows_core::PCSTR, pcsrcsize: *mut i32, lpencoding: *mut DetectEncodingInfo, pnscores: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage2_Impl::DetectInputCodepage(this, core::mem::transmute_copy(&dwflag), core::mem::transmute_copy(&dwprefwincodepage), core::mem::transmute(&psrcstr), core::mem::transmute_copy(&pcsrcsize), core::mem::transmute_copy(&lpencoding), core::mem::transmute_copy(&pnscores)).into()
+            }
+        }
+        unsafe extern "system" fn ValidateCodePage<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, uicodepage: u32, hwnd: super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage2_Impl::ValidateCodePage(this, core::mem::transmute_copy(&uicodepage), core::mem::transmute_copy(&hwnd)).into()
+            }
+        }
+        unsafe extern "system" fn GetCodePageDescription<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, uicodepage: u32, lcid: u32, lpwidecharstr: windows_core::PWSTR, cchwidechar: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage2_Impl::GetCodePageDescription(this, core::mem::transmute_copy(&uicodepage), core::mem::transmute_copy(&lcid), core::mem::transmute_copy(&lpwidecharstr), core::mem::transmute_copy(&cchwidechar)).into()
+            }
+        }
+        unsafe extern "system" fn IsCodePageInstallable<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, uicodepage: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage2_Impl::IsCodePageInstallable(this, core::mem::transmute_copy(&uicodepage)).into()
+            }
+        }
+        unsafe extern "system" fn SetMimeDBSource<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, dwsource: MIMECONTF) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage2_Impl::SetMimeDBSource(this, core::mem::transmute_copy(&dwsource)).into()
+            }
+        }
+        unsafe extern "system" fn GetNumberOfScripts<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, pnscripts: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMultiLanguage2_Impl::GetNumberOfScripts(this) {
+                    Ok(ok__) => {
+                        pnscripts.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumScripts<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, dwflags: u32, langid: u16, ppenumscript: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMultiLanguage2_Impl::EnumScripts(this, core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&langid)) {
+                    Ok(ok__) => {
+                        ppenumscript.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ValidateCodePageEx<Identity: windows_core::IUnknownImpl + IMultiLanguage2_Impl>(this: *mut core::ffi::c_void, uicodepage: u32, hwnd: super::Foundation::HWND, dwfiodcontrol: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage2_Impl::ValidateCodePageEx(this, core::mem::transmute_copy(&uicodepage), core::mem::transmute_copy(&hwnd), core::mem::transmute_copy(&dwfiodcontrol)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetNumberOfCodePageInfo: GetNumberOfCodePageInfo::<Identity>,
+            GetCodePageInfo: GetCodePageInfo::<Identity>,
+            GetFamilyCodePage: GetFamilyCodePage::<Identity>,
+            EnumCodePages: EnumCodePages::<Identity>,
+            GetCharsetInfo: GetCharsetInfo::<Identity>,
+            IsConvertible: IsConvertible::<Identity>,
+            ConvertString: ConvertString::<Identity>,
+            ConvertStringToUnicode: ConvertStringToUnicode::<Identity>,
+            ConvertStringFromUnicode: ConvertStringFromUnicode::<Identity>,
+            ConvertStringReset: ConvertStringReset::<Identity>,
+            GetRfc1766FromLcid: GetRfc1766FromLcid::<Identity>,
+            GetLcidFromRfc1766: GetLcidFromRfc1766::<Identity>,
+            EnumRfc1766: EnumRfc1766::<Identity>,
+            GetRfc1766Info: GetRfc1766Info::<Identity>,
+            CreateConvertCharset: CreateConvertCharset::<Identity>,
+            ConvertStringInIStream: ConvertStringInIStream::<Identity>,
+            ConvertStringToUnicodeEx: ConvertStringToUnicodeEx::<Identity>,
+            ConvertStringFromUnicodeEx: ConvertStringFromUnicodeEx::<Identity>,
+            DetectCodepageInIStream: DetectCodepageInIStream::<Identity>,
+            DetectInputCodepage: DetectInputCodepage::<Identity>,
+            ValidateCodePage: ValidateCodePage::<Identity>,
+            GetCodePageDescription: GetCodePageDescription::<Identity>,
+            IsCodePageInstallable: IsCodePageInstallable::<Identity>,
+            SetMimeDBSource: SetMimeDBSource::<Identity>,
+            GetNumberOfScripts: GetNumberOfScripts::<Identity>,
+            EnumScripts: EnumScripts::<Identity>,
+            ValidateCodePageEx: ValidateCodePageEx::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::RuntimeName for IMultiLanguage2 {}
+windows_core::imp::define_interface!(IMultiLanguage3, IMultiLanguage3_Vtbl, 0x4e5868ab_b157_4623_9acc_6a1d9caebe04);
+impl core::ops::Deref for IMultiLanguage3 {
+    type Target = IMultiLanguage2;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
     }
 }
 windows_core::imp::interface_hierarchy!(IMultiLanguage3, windows_core::IUnknown, IMultiLanguage2);
@@ -9655,6 +10731,31 @@ impl IMultiLanguage3_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IMultiLanguage3_Impl> windows_core::imp::TearOffVtable<Identity> for IMultiLanguage3_Vtbl
+where
+    IMultiLanguage2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DetectOutboundCodePage<Identity: windows_core::IUnknownImpl + IMultiLanguage3_Impl>(this: *mut core::ffi::c_void, dwflags: u32, lpwidecharstr: windows_core::PCWSTR, cchwidechar: u32, puipreferredcodepages: *const u32, npreferredcodepages: u32, puidetectedcodepages: *mut u32, pndetectedcodepages: *mut u32, lpspecialchar: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage3_Impl::DetectOutboundCodePage(this, core::mem::transmute_copy(&dwflags), core::mem::transmute(&lpwidecharstr), core::mem::transmute_copy(&cchwidechar), core::mem::transmute_copy(&puipreferredcodepages), core::mem::transmute_copy(&npreferredcodepages), core::mem::transmute_copy(&puidetectedcodepages), core::mem::transmute_copy(&pndetectedcodepages), core::mem::transmute(&lpspecialchar)).into()
+            }
+        }
+        unsafe extern "system" fn DetectOutboundCodePageInIStream<Identity: windows_core::IUnknownImpl + IMultiLanguage3_Impl>(this: *mut core::ffi::c_void, dwflags: u32, pstrin: *mut core::ffi::c_void, puipreferredcodepages: *const u32, npreferredcodepages: u32, puidetectedcodepages: *mut u32, pndetectedcodepages: *mut u32, lpspecialchar: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiLanguage3_Impl::DetectOutboundCodePageInIStream(this, core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pstrin), core::mem::transmute_copy(&puipreferredcodepages), core::mem::transmute_copy(&npreferredcodepages), core::mem::transmute_copy(&puidetectedcodepages), core::mem::transmute_copy(&pndetectedcodepages), core::mem::transmute(&lpspecialchar)).into()
+            }
+        }
+        Self {
+            base__: <IMultiLanguage2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DetectOutboundCodePage: DetectOutboundCodePage::<Identity>,
+            DetectOutboundCodePageInIStream: DetectOutboundCodePageInIStream::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IMultiLanguage3 {}
 windows_core::imp::define_interface!(IOptionDescription, IOptionDescription_Vtbl, 0x432e5f85_35cf_4606_a801_6f70277e1d7a);
 windows_core::imp::interface_hierarchy!(IOptionDescription, windows_core::IUnknown);
@@ -9768,6 +10869,69 @@ impl IOptionDescription_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOptionDescription_Impl> windows_core::imp::TearOffVtable<Identity> for IOptionDescription_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + IOptionDescription_Impl>(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOptionDescription_Impl::Id(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Heading<Identity: windows_core::IUnknownImpl + IOptionDescription_Impl>(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOptionDescription_Impl::Heading(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Description<Identity: windows_core::IUnknownImpl + IOptionDescription_Impl>(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOptionDescription_Impl::Description(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Labels<Identity: windows_core::IUnknownImpl + IOptionDescription_Impl>(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOptionDescription_Impl::Labels(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Id: Id::<Identity>,
+            Heading: Heading::<Identity>,
+            Description: Description::<Identity>,
+            Labels: Labels::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOptionDescription {}
 pub const IS_TEXT_UNICODE_ASCII16: IS_TEXT_UNICODE_RESULT = IS_TEXT_UNICODE_RESULT(1u32);
 pub const IS_TEXT_UNICODE_CONTROLS: IS_TEXT_UNICODE_RESULT = IS_TEXT_UNICODE_RESULT(4u32);
@@ -10069,6 +11233,135 @@ impl ISpellCheckProvider_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + ISpellCheckProvider_Impl> windows_core::imp::TearOffVtable<Identity> for ISpellCheckProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn LanguageTag<Identity: windows_core::IUnknownImpl + ISpellCheckProvider_Impl>(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellCheckProvider_Impl::LanguageTag(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Check<Identity: windows_core::IUnknownImpl + ISpellCheckProvider_Impl>(this: *mut core::ffi::c_void, text: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellCheckProvider_Impl::Check(this, core::mem::transmute(&text)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Suggest<Identity: windows_core::IUnknownImpl + ISpellCheckProvider_Impl>(this: *mut core::ffi::c_void, word: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellCheckProvider_Impl::Suggest(this, core::mem::transmute(&word)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOptionValue<Identity: windows_core::IUnknownImpl + ISpellCheckProvider_Impl>(this: *mut core::ffi::c_void, optionid: windows_core::PCWSTR, value: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellCheckProvider_Impl::GetOptionValue(this, core::mem::transmute(&optionid)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetOptionValue<Identity: windows_core::IUnknownImpl + ISpellCheckProvider_Impl>(this: *mut core::ffi::c_void, optionid: windows_core::PCWSTR, value: u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpellCheckProvider_Impl::SetOptionValue(this, core::mem::transmute(&optionid), core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn OptionIds<Identity: windows_core::IUnknownImpl + ISpellCheckProvider_Impl>(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellCheckProvider_Impl::OptionIds(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + ISpellCheckProvider_Impl>(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellCheckProvider_Impl::Id(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LocalizedName<Identity: windows_core::IUnknownImpl + ISpellCheckProvider_Impl>(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellCheckProvider_Impl::LocalizedName(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOptionDescription<Identity: windows_core::IUnknownImpl + ISpellCheckProvider_Impl>(this: *mut core::ffi::c_void, optionid: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellCheckProvider_Impl::GetOptionDescription(this, core::mem::transmute(&optionid)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InitializeWordlist<Identity: windows_core::IUnknownImpl + ISpellCheckProvider_Impl>(this: *mut core::ffi::c_void, wordlisttype: WORDLIST_TYPE, words: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpellCheckProvider_Impl::InitializeWordlist(this, core::mem::transmute_copy(&wordlisttype), core::mem::transmute_copy(&words)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            LanguageTag: LanguageTag::<Identity>,
+            Check: Check::<Identity>,
+            Suggest: Suggest::<Identity>,
+            GetOptionValue: GetOptionValue::<Identity>,
+            SetOptionValue: SetOptionValue::<Identity>,
+            OptionIds: OptionIds::<Identity>,
+            Id: Id::<Identity>,
+            LocalizedName: LocalizedName::<Identity>,
+            GetOptionDescription: GetOptionDescription::<Identity>,
+            InitializeWordlist: InitializeWordlist::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for ISpellCheckProvider {}
 windows_core::imp::define_interface!(ISpellCheckProviderFactory, ISpellCheckProviderFactory_Vtbl, 0x9f671e11_77d6_4c92_aefb_615215e3a4be);
 windows_core::imp::interface_hierarchy!(ISpellCheckProviderFactory, windows_core::IUnknown);
@@ -10117,11 +11410,64 @@ pub trait ISpellCheckProviderFactory_Impl: windows_core::IUnknownImpl {
     fn CreateSpellCheckProvider(&self, languagetag: &windows_core::PCWSTR) -> windows_core::Result<ISpellCheckProvider>;
 }
 #[cfg(feature = "Win32_System_Com")]
-impl ISpellCheckProviderFactory_Vtbl {
-    pub const fn new<Identity: ISpellCheckProviderFactory_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn SupportedLanguages<Identity: ISpellCheckProviderFactory_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl ISpellCheckProviderFactory_Vtbl {
+    pub const fn new<Identity: ISpellCheckProviderFactory_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn SupportedLanguages<Identity: ISpellCheckProviderFactory_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ISpellCheckProviderFactory_Impl::SupportedLanguages(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsSupported<Identity: ISpellCheckProviderFactory_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, languagetag: windows_core::PCWSTR, value: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ISpellCheckProviderFactory_Impl::IsSupported(this, core::mem::transmute(&languagetag)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSpellCheckProvider<Identity: ISpellCheckProviderFactory_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, languagetag: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ISpellCheckProviderFactory_Impl::CreateSpellCheckProvider(this, core::mem::transmute(&languagetag)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            SupportedLanguages: SupportedLanguages::<Identity, OFFSET>,
+            IsSupported: IsSupported::<Identity, OFFSET>,
+            CreateSpellCheckProvider: CreateSpellCheckProvider::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpellCheckProviderFactory as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + ISpellCheckProviderFactory_Impl> windows_core::imp::TearOffVtable<Identity> for ISpellCheckProviderFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SupportedLanguages<Identity: windows_core::IUnknownImpl + ISpellCheckProviderFactory_Impl>(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ISpellCheckProviderFactory_Impl::SupportedLanguages(this) {
                     Ok(ok__) => {
                         value.write(core::mem::transmute(ok__));
@@ -10131,9 +11477,9 @@ impl ISpellCheckProviderFactory_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn IsSupported<Identity: ISpellCheckProviderFactory_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, languagetag: windows_core::PCWSTR, value: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn IsSupported<Identity: windows_core::IUnknownImpl + ISpellCheckProviderFactory_Impl>(this: *mut core::ffi::c_void, languagetag: windows_core::PCWSTR, value: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ISpellCheckProviderFactory_Impl::IsSupported(this, core::mem::transmute(&languagetag)) {
                     Ok(ok__) => {
                         value.write(core::mem::transmute(ok__));
@@ -10143,9 +11489,9 @@ impl ISpellCheckProviderFactory_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateSpellCheckProvider<Identity: ISpellCheckProviderFactory_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, languagetag: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateSpellCheckProvider<Identity: windows_core::IUnknownImpl + ISpellCheckProviderFactory_Impl>(this: *mut core::ffi::c_void, languagetag: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ISpellCheckProviderFactory_Impl::CreateSpellCheckProvider(this, core::mem::transmute(&languagetag)) {
                     Ok(ok__) => {
                         value.write(core::mem::transmute(ok__));
@@ -10156,15 +11502,12 @@ impl ISpellCheckProviderFactory_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            SupportedLanguages: SupportedLanguages::<Identity, OFFSET>,
-            IsSupported: IsSupported::<Identity, OFFSET>,
-            CreateSpellCheckProvider: CreateSpellCheckProvider::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SupportedLanguages: SupportedLanguages::<Identity>,
+            IsSupported: IsSupported::<Identity>,
+            CreateSpellCheckProvider: CreateSpellCheckProvider::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ISpellCheckProviderFactory as windows_core::Interface>::IID
-    }
+    };
 }
 #[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for ISpellCheckProviderFactory {}
@@ -10486,6 +11829,175 @@ impl ISpellChecker_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + ISpellChecker_Impl> windows_core::imp::TearOffVtable<Identity> for ISpellChecker_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn LanguageTag<Identity: windows_core::IUnknownImpl + ISpellChecker_Impl>(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellChecker_Impl::LanguageTag(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Check<Identity: windows_core::IUnknownImpl + ISpellChecker_Impl>(this: *mut core::ffi::c_void, text: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellChecker_Impl::Check(this, core::mem::transmute(&text)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Suggest<Identity: windows_core::IUnknownImpl + ISpellChecker_Impl>(this: *mut core::ffi::c_void, word: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellChecker_Impl::Suggest(this, core::mem::transmute(&word)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Add<Identity: windows_core::IUnknownImpl + ISpellChecker_Impl>(this: *mut core::ffi::c_void, word: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpellChecker_Impl::Add(this, core::mem::transmute(&word)).into()
+            }
+        }
+        unsafe extern "system" fn Ignore<Identity: windows_core::IUnknownImpl + ISpellChecker_Impl>(this: *mut core::ffi::c_void, word: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpellChecker_Impl::Ignore(this, core::mem::transmute(&word)).into()
+            }
+        }
+        unsafe extern "system" fn AutoCorrect<Identity: windows_core::IUnknownImpl + ISpellChecker_Impl>(this: *mut core::ffi::c_void, from: windows_core::PCWSTR, to: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpellChecker_Impl::AutoCorrect(this, core::mem::transmute(&from), core::mem::transmute(&to)).into()
+            }
+        }
+        unsafe extern "system" fn GetOptionValue<Identity: windows_core::IUnknownImpl + ISpellChecker_Impl>(this: *mut core::ffi::c_void, optionid: windows_core::PCWSTR, value: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellChecker_Impl::GetOptionValue(this, core::mem::transmute(&optionid)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OptionIds<Identity: windows_core::IUnknownImpl + ISpellChecker_Impl>(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellChecker_Impl::OptionIds(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + ISpellChecker_Impl>(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellChecker_Impl::Id(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LocalizedName<Identity: windows_core::IUnknownImpl + ISpellChecker_Impl>(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellChecker_Impl::LocalizedName(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn add_SpellCheckerChanged<Identity: windows_core::IUnknownImpl + ISpellChecker_Impl>(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, eventcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellChecker_Impl::add_SpellCheckerChanged(this, core::mem::transmute_copy(&handler)) {
+                    Ok(ok__) => {
+                        eventcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn remove_SpellCheckerChanged<Identity: windows_core::IUnknownImpl + ISpellChecker_Impl>(this: *mut core::ffi::c_void, eventcookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpellChecker_Impl::remove_SpellCheckerChanged(this, core::mem::transmute_copy(&eventcookie)).into()
+            }
+        }
+        unsafe extern "system" fn GetOptionDescription<Identity: windows_core::IUnknownImpl + ISpellChecker_Impl>(this: *mut core::ffi::c_void, optionid: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellChecker_Impl::GetOptionDescription(this, core::mem::transmute(&optionid)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ComprehensiveCheck<Identity: windows_core::IUnknownImpl + ISpellChecker_Impl>(this: *mut core::ffi::c_void, text: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellChecker_Impl::ComprehensiveCheck(this, core::mem::transmute(&text)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            LanguageTag: LanguageTag::<Identity>,
+            Check: Check::<Identity>,
+            Suggest: Suggest::<Identity>,
+            Add: Add::<Identity>,
+            Ignore: Ignore::<Identity>,
+            AutoCorrect: AutoCorrect::<Identity>,
+            GetOptionValue: GetOptionValue::<Identity>,
+            OptionIds: OptionIds::<Identity>,
+            Id: Id::<Identity>,
+            LocalizedName: LocalizedName::<Identity>,
+            add_SpellCheckerChanged: add_SpellCheckerChanged::<Identity>,
+            remove_SpellCheckerChanged: remove_SpellCheckerChanged::<Identity>,
+            GetOptionDescription: GetOptionDescription::<Identity>,
+            ComprehensiveCheck: ComprehensiveCheck::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for ISpellChecker {}
 windows_core::imp::define_interface!(ISpellChecker2, ISpellChecker2_Vtbl, 0xe7ed1c71_87f7_4378_a840_c9200dacee47);
 impl core::ops::Deref for ISpellChecker2 {
@@ -10529,6 +12041,21 @@ impl ISpellChecker2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + ISpellChecker2_Impl> windows_core::imp::TearOffVtable<Identity> for ISpellChecker2_Vtbl
+where
+    ISpellChecker_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Remove<Identity: windows_core::IUnknownImpl + ISpellChecker2_Impl>(this: *mut core::ffi::c_void, word: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpellChecker2_Impl::Remove(this, core::mem::transmute(&word)).into()
+            }
+        }
+        Self { base__: <ISpellChecker_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Remove: Remove::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for ISpellChecker2 {}
 windows_core::imp::define_interface!(ISpellCheckerChangedEventHandler, ISpellCheckerChangedEventHandler_Vtbl, 0x0b83a5b0_792f_4eab_9799_acf52c5ed08a);
 windows_core::imp::interface_hierarchy!(ISpellCheckerChangedEventHandler, windows_core::IUnknown);
@@ -10563,6 +12090,20 @@ impl ISpellCheckerChangedEventHandler_Vtbl {
         iid == &<ISpellCheckerChangedEventHandler as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpellCheckerChangedEventHandler_Impl> windows_core::imp::TearOffVtable<Identity> for ISpellCheckerChangedEventHandler_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Invoke<Identity: windows_core::IUnknownImpl + ISpellCheckerChangedEventHandler_Impl>(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpellCheckerChangedEventHandler_Impl::Invoke(this, core::mem::transmute_copy(&sender)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Invoke: Invoke::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ISpellCheckerChangedEventHandler {}
 windows_core::imp::define_interface!(ISpellCheckerFactory, ISpellCheckerFactory_Vtbl, 0x8e018a9d_2415_4677_bf08_794ea61f94bb);
 windows_core::imp::interface_hierarchy!(ISpellCheckerFactory, windows_core::IUnknown);
@@ -10661,6 +12202,56 @@ impl ISpellCheckerFactory_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + ISpellCheckerFactory_Impl> windows_core::imp::TearOffVtable<Identity> for ISpellCheckerFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SupportedLanguages<Identity: windows_core::IUnknownImpl + ISpellCheckerFactory_Impl>(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellCheckerFactory_Impl::SupportedLanguages(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsSupported<Identity: windows_core::IUnknownImpl + ISpellCheckerFactory_Impl>(this: *mut core::ffi::c_void, languagetag: windows_core::PCWSTR, value: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellCheckerFactory_Impl::IsSupported(this, core::mem::transmute(&languagetag)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSpellChecker<Identity: windows_core::IUnknownImpl + ISpellCheckerFactory_Impl>(this: *mut core::ffi::c_void, languagetag: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellCheckerFactory_Impl::CreateSpellChecker(this, core::mem::transmute(&languagetag)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SupportedLanguages: SupportedLanguages::<Identity>,
+            IsSupported: IsSupported::<Identity>,
+            CreateSpellChecker: CreateSpellChecker::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for ISpellCheckerFactory {}
 windows_core::imp::define_interface!(ISpellingError, ISpellingError_Vtbl, 0xb7c82d61_fbe8_4b47_9b27_6c0d2e0de0a3);
 windows_core::imp::interface_hierarchy!(ISpellingError, windows_core::IUnknown);
@@ -10767,6 +12358,68 @@ impl ISpellingError_Vtbl {
         iid == &<ISpellingError as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpellingError_Impl> windows_core::imp::TearOffVtable<Identity> for ISpellingError_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StartIndex<Identity: windows_core::IUnknownImpl + ISpellingError_Impl>(this: *mut core::ffi::c_void, value: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellingError_Impl::StartIndex(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Length<Identity: windows_core::IUnknownImpl + ISpellingError_Impl>(this: *mut core::ffi::c_void, value: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellingError_Impl::Length(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CorrectiveAction<Identity: windows_core::IUnknownImpl + ISpellingError_Impl>(this: *mut core::ffi::c_void, value: *mut CORRECTIVE_ACTION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellingError_Impl::CorrectiveAction(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Replacement<Identity: windows_core::IUnknownImpl + ISpellingError_Impl>(this: *mut core::ffi::c_void, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpellingError_Impl::Replacement(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            StartIndex: StartIndex::<Identity>,
+            Length: Length::<Identity>,
+            CorrectiveAction: CorrectiveAction::<Identity>,
+            Replacement: Replacement::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpellingError {}
 windows_core::imp::define_interface!(IUserDictionariesRegistrar, IUserDictionariesRegistrar_Vtbl, 0xaa176b85_0e12_4844_8e1a_eef1da77f586);
 windows_core::imp::interface_hierarchy!(IUserDictionariesRegistrar, windows_core::IUnknown);
@@ -10821,6 +12474,30 @@ impl IUserDictionariesRegistrar_Vtbl {
         iid == &<IUserDictionariesRegistrar as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IUserDictionariesRegistrar_Impl> windows_core::imp::TearOffVtable<Identity> for IUserDictionariesRegistrar_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterUserDictionary<Identity: windows_core::IUnknownImpl + IUserDictionariesRegistrar_Impl>(this: *mut core::ffi::c_void, dictionarypath: windows_core::PCWSTR, languagetag: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUserDictionariesRegistrar_Impl::RegisterUserDictionary(this, core::mem::transmute(&dictionarypath), core::mem::transmute(&languagetag)).into()
+            }
+        }
+        unsafe extern "system" fn UnregisterUserDictionary<Identity: windows_core::IUnknownImpl + IUserDictionariesRegistrar_Impl>(this: *mut core::ffi::c_void, dictionarypath: windows_core::PCWSTR, languagetag: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUserDictionariesRegistrar_Impl::UnregisterUserDictionary(this, core::mem::transmute(&dictionarypath), core::mem::transmute(&languagetag)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterUserDictionary: RegisterUserDictionary::<Identity>,
+            UnregisterUserDictionary: UnregisterUserDictionary::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IUserDictionariesRegistrar {}
 pub type LANGGROUPLOCALE_ENUMPROCA = Option<unsafe extern "system" fn(param0: u32, param1: u32, param2: windows_core::PCSTR, param3: isize) -> windows_core::BOOL>;
 pub type LANGGROUPLOCALE_ENUMPROCW = Option<unsafe extern "system" fn(param0: u32, param1: u32, param2: windows_core::PCWSTR, param3: isize) -> windows_core::BOOL>;
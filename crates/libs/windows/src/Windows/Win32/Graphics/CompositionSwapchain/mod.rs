@@ -97,6 +97,38 @@ impl ICompositionFramePresentStatistics_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + ICompositionFramePresentStatistics_Impl> windows_core::imp::TearOffVtable<Identity> for ICompositionFramePresentStatistics_Vtbl
+where
+    IPresentStatistics_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetContentTag<Identity: windows_core::IUnknownImpl + ICompositionFramePresentStatistics_Impl>(this: *mut core::ffi::c_void) -> usize {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICompositionFramePresentStatistics_Impl::GetContentTag(this)
+            }
+        }
+        unsafe extern "system" fn GetCompositionFrameId<Identity: windows_core::IUnknownImpl + ICompositionFramePresentStatistics_Impl>(this: *mut core::ffi::c_void) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICompositionFramePresentStatistics_Impl::GetCompositionFrameId(this)
+            }
+        }
+        unsafe extern "system" fn GetDisplayInstanceArray<Identity: windows_core::IUnknownImpl + ICompositionFramePresentStatistics_Impl>(this: *mut core::ffi::c_void, displayinstancearraycount: *mut u32, displayinstancearray: *mut *mut CompositionFrameDisplayInstance) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICompositionFramePresentStatistics_Impl::GetDisplayInstanceArray(this, core::mem::transmute_copy(&displayinstancearraycount), core::mem::transmute_copy(&displayinstancearray))
+            }
+        }
+        Self {
+            base__: <IPresentStatistics_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetContentTag: GetContentTag::<Identity>,
+            GetCompositionFrameId: GetCompositionFrameId::<Identity>,
+            GetDisplayInstanceArray: GetDisplayInstanceArray::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for ICompositionFramePresentStatistics {}
 windows_core::imp::define_interface!(IIndependentFlipFramePresentStatistics, IIndependentFlipFramePresentStatistics_Vtbl, 0x8c93be27_ad94_4da0_8fd4_2413132d124e);
 impl core::ops::Deref for IIndependentFlipFramePresentStatistics {
@@ -197,6 +229,51 @@ impl IIndependentFlipFramePresentStatistics_Vtbl {
         iid == &<IIndependentFlipFramePresentStatistics as windows_core::Interface>::IID || iid == &<IPresentStatistics as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IIndependentFlipFramePresentStatistics_Impl> windows_core::imp::TearOffVtable<Identity> for IIndependentFlipFramePresentStatistics_Vtbl
+where
+    IPresentStatistics_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetOutputAdapterLUID<Identity: windows_core::IUnknownImpl + IIndependentFlipFramePresentStatistics_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::LUID) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = IIndependentFlipFramePresentStatistics_Impl::GetOutputAdapterLUID(this)
+            }
+        }
+        unsafe extern "system" fn GetOutputVidPnSourceId<Identity: windows_core::IUnknownImpl + IIndependentFlipFramePresentStatistics_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIndependentFlipFramePresentStatistics_Impl::GetOutputVidPnSourceId(this)
+            }
+        }
+        unsafe extern "system" fn GetContentTag<Identity: windows_core::IUnknownImpl + IIndependentFlipFramePresentStatistics_Impl>(this: *mut core::ffi::c_void) -> usize {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIndependentFlipFramePresentStatistics_Impl::GetContentTag(this)
+            }
+        }
+        unsafe extern "system" fn GetDisplayedTime<Identity: windows_core::IUnknownImpl + IIndependentFlipFramePresentStatistics_Impl>(this: *mut core::ffi::c_void, result__: *mut SystemInterruptTime) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = IIndependentFlipFramePresentStatistics_Impl::GetDisplayedTime(this)
+            }
+        }
+        unsafe extern "system" fn GetPresentDuration<Identity: windows_core::IUnknownImpl + IIndependentFlipFramePresentStatistics_Impl>(this: *mut core::ffi::c_void, result__: *mut SystemInterruptTime) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = IIndependentFlipFramePresentStatistics_Impl::GetPresentDuration(this)
+            }
+        }
+        Self {
+            base__: <IPresentStatistics_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetOutputAdapterLUID: GetOutputAdapterLUID::<Identity>,
+            GetOutputVidPnSourceId: GetOutputVidPnSourceId::<Identity>,
+            GetContentTag: GetContentTag::<Identity>,
+            GetDisplayedTime: GetDisplayedTime::<Identity>,
+            GetPresentDuration: GetPresentDuration::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IIndependentFlipFramePresentStatistics {}
 windows_core::imp::define_interface!(IPresentStatistics, IPresentStatistics_Vtbl, 0xb44b8bda_7282_495d_9dd7_ceadd8b4bb86);
 windows_core::imp::interface_hierarchy!(IPresentStatistics, windows_core::IUnknown);
@@ -243,6 +320,30 @@ impl IPresentStatistics_Vtbl {
         iid == &<IPresentStatistics as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPresentStatistics_Impl> windows_core::imp::TearOffVtable<Identity> for IPresentStatistics_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPresentId<Identity: windows_core::IUnknownImpl + IPresentStatistics_Impl>(this: *mut core::ffi::c_void) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPresentStatistics_Impl::GetPresentId(this)
+            }
+        }
+        unsafe extern "system" fn GetKind<Identity: windows_core::IUnknownImpl + IPresentStatistics_Impl>(this: *mut core::ffi::c_void) -> PresentStatisticsKind {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPresentStatistics_Impl::GetKind(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPresentId: GetPresentId::<Identity>,
+            GetKind: GetKind::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPresentStatistics {}
 windows_core::imp::define_interface!(IPresentStatusPresentStatistics, IPresentStatusPresentStatistics_Vtbl, 0xc9ed2a41_79cb_435e_964e_c8553055420c);
 impl core::ops::Deref for IPresentStatusPresentStatistics {
@@ -295,6 +396,30 @@ impl IPresentStatusPresentStatistics_Vtbl {
         iid == &<IPresentStatusPresentStatistics as windows_core::Interface>::IID || iid == &<IPresentStatistics as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPresentStatusPresentStatistics_Impl> windows_core::imp::TearOffVtable<Identity> for IPresentStatusPresentStatistics_Vtbl
+where
+    IPresentStatistics_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCompositionFrameId<Identity: windows_core::IUnknownImpl + IPresentStatusPresentStatistics_Impl>(this: *mut core::ffi::c_void) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPresentStatusPresentStatistics_Impl::GetCompositionFrameId(this)
+            }
+        }
+        unsafe extern "system" fn GetPresentStatus<Identity: windows_core::IUnknownImpl + IPresentStatusPresentStatistics_Impl>(this: *mut core::ffi::c_void) -> PresentStatus {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPresentStatusPresentStatistics_Impl::GetPresentStatus(this)
+            }
+        }
+        Self {
+            base__: <IPresentStatistics_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCompositionFrameId: GetCompositionFrameId::<Identity>,
+            GetPresentStatus: GetPresentStatus::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPresentStatusPresentStatistics {}
 windows_core::imp::define_interface!(IPresentationBuffer, IPresentationBuffer_Vtbl, 0x2e217d3a_5abb_4138_9a13_a775593c89ca);
 windows_core::imp::interface_hierarchy!(IPresentationBuffer, windows_core::IUnknown);
@@ -359,6 +484,42 @@ impl IPresentationBuffer_Vtbl {
         iid == &<IPresentationBuffer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPresentationBuffer_Impl> windows_core::imp::TearOffVtable<Identity> for IPresentationBuffer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAvailableEvent<Identity: windows_core::IUnknownImpl + IPresentationBuffer_Impl>(this: *mut core::ffi::c_void, availableeventhandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPresentationBuffer_Impl::GetAvailableEvent(this) {
+                    Ok(ok__) => {
+                        availableeventhandle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsAvailable<Identity: windows_core::IUnknownImpl + IPresentationBuffer_Impl>(this: *mut core::ffi::c_void, isavailable: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPresentationBuffer_Impl::IsAvailable(this) {
+                    Ok(ok__) => {
+                        isavailable.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAvailableEvent: GetAvailableEvent::<Identity>,
+            IsAvailable: IsAvailable::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPresentationBuffer {}
 windows_core::imp::define_interface!(IPresentationContent, IPresentationContent_Vtbl, 0x5668bb79_3d8e_415c_b215_f38020f2d252);
 windows_core::imp::interface_hierarchy!(IPresentationContent, windows_core::IUnknown);
@@ -390,6 +551,20 @@ impl IPresentationContent_Vtbl {
         iid == &<IPresentationContent as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPresentationContent_Impl> windows_core::imp::TearOffVtable<Identity> for IPresentationContent_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetTag<Identity: windows_core::IUnknownImpl + IPresentationContent_Impl>(this: *mut core::ffi::c_void, tag: usize) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPresentationContent_Impl::SetTag(this, core::mem::transmute_copy(&tag))
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetTag: SetTag::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IPresentationContent {}
 windows_core::imp::define_interface!(IPresentationFactory, IPresentationFactory_Vtbl, 0x8fb37b58_1d74_4f64_a49c_1f97a80a2ec0);
 windows_core::imp::interface_hierarchy!(IPresentationFactory, windows_core::IUnknown);
@@ -457,6 +632,43 @@ impl IPresentationFactory_Vtbl {
         iid == &<IPresentationFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPresentationFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IPresentationFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsPresentationSupported<Identity: windows_core::IUnknownImpl + IPresentationFactory_Impl>(this: *mut core::ffi::c_void) -> u8 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPresentationFactory_Impl::IsPresentationSupported(this)
+            }
+        }
+        unsafe extern "system" fn IsPresentationSupportedWithIndependentFlip<Identity: windows_core::IUnknownImpl + IPresentationFactory_Impl>(this: *mut core::ffi::c_void) -> u8 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPresentationFactory_Impl::IsPresentationSupportedWithIndependentFlip(this)
+            }
+        }
+        unsafe extern "system" fn CreatePresentationManager<Identity: windows_core::IUnknownImpl + IPresentationFactory_Impl>(this: *mut core::ffi::c_void, pppresentationmanager: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPresentationFactory_Impl::CreatePresentationManager(this) {
+                    Ok(ok__) => {
+                        pppresentationmanager.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsPresentationSupported: IsPresentationSupported::<Identity>,
+            IsPresentationSupportedWithIndependentFlip: IsPresentationSupportedWithIndependentFlip::<Identity>,
+            CreatePresentationManager: CreatePresentationManager::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPresentationFactory {}
 windows_core::imp::define_interface!(IPresentationManager, IPresentationManager_Vtbl, 0xfb562f82_6292_470a_88b1_843661e7f20c);
 windows_core::imp::interface_hierarchy!(IPresentationManager, windows_core::IUnknown);
@@ -687,6 +899,137 @@ impl IPresentationManager_Vtbl {
         iid == &<IPresentationManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPresentationManager_Impl> windows_core::imp::TearOffVtable<Identity> for IPresentationManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddBufferFromResource<Identity: windows_core::IUnknownImpl + IPresentationManager_Impl>(this: *mut core::ffi::c_void, resource: *mut core::ffi::c_void, presentationbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPresentationManager_Impl::AddBufferFromResource(this, core::mem::transmute_copy(&resource)) {
+                    Ok(ok__) => {
+                        presentationbuffer.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreatePresentationSurface<Identity: windows_core::IUnknownImpl + IPresentationManager_Impl>(this: *mut core::ffi::c_void, compositionsurfacehandle: super::super::Foundation::HANDLE, presentationsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPresentationManager_Impl::CreatePresentationSurface(this, core::mem::transmute_copy(&compositionsurfacehandle)) {
+                    Ok(ok__) => {
+                        presentationsurface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetNextPresentId<Identity: windows_core::IUnknownImpl + IPresentationManager_Impl>(this: *mut core::ffi::c_void) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPresentationManager_Impl::GetNextPresentId(this)
+            }
+        }
+        unsafe extern "system" fn SetTargetTime<Identity: windows_core::IUnknownImpl + IPresentationManager_Impl>(this: *mut core::ffi::c_void, targettime: SystemInterruptTime) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPresentationManager_Impl::SetTargetTime(this, core::mem::transmute(&targettime)).into()
+            }
+        }
+        unsafe extern "system" fn SetPreferredPresentDuration<Identity: windows_core::IUnknownImpl + IPresentationManager_Impl>(this: *mut core::ffi::c_void, preferredduration: SystemInterruptTime, deviationtolerance: SystemInterruptTime) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPresentationManager_Impl::SetPreferredPresentDuration(this, core::mem::transmute(&preferredduration), core::mem::transmute(&deviationtolerance)).into()
+            }
+        }
+        unsafe extern "system" fn ForceVSyncInterrupt<Identity: windows_core::IUnknownImpl + IPresentationManager_Impl>(this: *mut core::ffi::c_void, forcevsyncinterrupt: u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPresentationManager_Impl::ForceVSyncInterrupt(this, core::mem::transmute_copy(&forcevsyncinterrupt)).into()
+            }
+        }
+        unsafe extern "system" fn Present<Identity: windows_core::IUnknownImpl + IPresentationManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPresentationManager_Impl::Present(this).into()
+            }
+        }
+        unsafe extern "system" fn GetPresentRetiringFence<Identity: windows_core::IUnknownImpl + IPresentationManager_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, fence: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPresentationManager_Impl::GetPresentRetiringFence(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&fence)).into()
+            }
+        }
+        unsafe extern "system" fn CancelPresentsFrom<Identity: windows_core::IUnknownImpl + IPresentationManager_Impl>(this: *mut core::ffi::c_void, presentidtocancelfrom: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPresentationManager_Impl::CancelPresentsFrom(this, core::mem::transmute_copy(&presentidtocancelfrom)).into()
+            }
+        }
+        unsafe extern "system" fn GetLostEvent<Identity: windows_core::IUnknownImpl + IPresentationManager_Impl>(this: *mut core::ffi::c_void, losteventhandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPresentationManager_Impl::GetLostEvent(this) {
+                    Ok(ok__) => {
+                        losteventhandle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPresentStatisticsAvailableEvent<Identity: windows_core::IUnknownImpl + IPresentationManager_Impl>(this: *mut core::ffi::c_void, presentstatisticsavailableeventhandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPresentationManager_Impl::GetPresentStatisticsAvailableEvent(this) {
+                    Ok(ok__) => {
+                        presentstatisticsavailableeventhandle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnablePresentStatisticsKind<Identity: windows_core::IUnknownImpl + IPresentationManager_Impl>(this: *mut core::ffi::c_void, presentstatisticskind: PresentStatisticsKind, enabled: u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPresentationManager_Impl::EnablePresentStatisticsKind(this, core::mem::transmute_copy(&presentstatisticskind), core::mem::transmute_copy(&enabled)).into()
+            }
+        }
+        unsafe extern "system" fn GetNextPresentStatistics<Identity: windows_core::IUnknownImpl + IPresentationManager_Impl>(this: *mut core::ffi::c_void, nextpresentstatistics: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPresentationManager_Impl::GetNextPresentStatistics(this) {
+                    Ok(ok__) => {
+                        nextpresentstatistics.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddBufferFromResource: AddBufferFromResource::<Identity>,
+            CreatePresentationSurface: CreatePresentationSurface::<Identity>,
+            GetNextPresentId: GetNextPresentId::<Identity>,
+            SetTargetTime: SetTargetTime::<Identity>,
+            SetPreferredPresentDuration: SetPreferredPresentDuration::<Identity>,
+            ForceVSyncInterrupt: ForceVSyncInterrupt::<Identity>,
+            Present: Present::<Identity>,
+            GetPresentRetiringFence: GetPresentRetiringFence::<Identity>,
+            CancelPresentsFrom: CancelPresentsFrom::<Identity>,
+            GetLostEvent: GetLostEvent::<Identity>,
+            GetPresentStatisticsAvailableEvent: GetPresentStatisticsAvailableEvent::<Identity>,
+            EnablePresentStatisticsKind: EnablePresentStatisticsKind::<Identity>,
+            GetNextPresentStatistics: GetNextPresentStatistics::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPresentationManager {}
 windows_core::imp::define_interface!(IPresentationSurface, IPresentationSurface_Vtbl, 0x956710fb_ea40_4eba_a3eb_4375a0eb4edc);
 impl core::ops::Deref for IPresentationSurface {
@@ -828,6 +1171,73 @@ impl IPresentationSurface_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IPresentationSurface_Impl> windows_core::imp::TearOffVtable<Identity> for IPresentationSurface_Vtbl
+where
+    IPresentationContent_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetBuffer<Identity: windows_core::IUnknownImpl + IPresentationSurface_Impl>(this: *mut core::ffi::c_void, presentationbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPresentationSurface_Impl::SetBuffer(this, core::mem::transmute_copy(&presentationbuffer)).into()
+            }
+        }
+        unsafe extern "system" fn SetColorSpace<Identity: windows_core::IUnknownImpl + IPresentationSurface_Impl>(this: *mut core::ffi::c_void, colorspace: super::Dxgi::Common::DXGI_COLOR_SPACE_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPresentationSurface_Impl::SetColorSpace(this, core::mem::transmute_copy(&colorspace)).into()
+            }
+        }
+        unsafe extern "system" fn SetAlphaMode<Identity: windows_core::IUnknownImpl + IPresentationSurface_Impl>(this: *mut core::ffi::c_void, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPresentationSurface_Impl::SetAlphaMode(this, core::mem::transmute_copy(&alphamode)).into()
+            }
+        }
+        unsafe extern "system" fn SetSourceRect<Identity: windows_core::IUnknownImpl + IPresentationSurface_Impl>(this: *mut core::ffi::c_void, sourcerect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPresentationSurface_Impl::SetSourceRect(this, core::mem::transmute_copy(&sourcerect)).into()
+            }
+        }
+        unsafe extern "system" fn SetTransform<Identity: windows_core::IUnknownImpl + IPresentationSurface_Impl>(this: *mut core::ffi::c_void, transform: *const PresentationTransform) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPresentationSurface_Impl::SetTransform(this, core::mem::transmute_copy(&transform)).into()
+            }
+        }
+        unsafe extern "system" fn RestrictToOutput<Identity: windows_core::IUnknownImpl + IPresentationSurface_Impl>(this: *mut core::ffi::c_void, output: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPresentationSurface_Impl::RestrictToOutput(this, core::mem::transmute_copy(&output)).into()
+            }
+        }
+        unsafe extern "system" fn SetDisableReadback<Identity: windows_core::IUnknownImpl + IPresentationSurface_Impl>(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPresentationSurface_Impl::SetDisableReadback(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn SetLetterboxingMargins<Identity: windows_core::IUnknownImpl + IPresentationSurface_Impl>(this: *mut core::ffi::c_void, leftletterboxsize: f32, topletterboxsize: f32, rightletterboxsize: f32, bottomletterboxsize: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPresentationSurface_Impl::SetLetterboxingMargins(this, core::mem::transmute_copy(&leftletterboxsize), core::mem::transmute_copy(&topletterboxsize), core::mem::transmute_copy(&rightletterboxsize), core::mem::transmute_copy(&bottomletterboxsize)).into()
+            }
+        }
+        Self {
+            base__: <IPresentationContent_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetBuffer: SetBuffer::<Identity>,
+            SetColorSpace: SetColorSpace::<Identity>,
+            SetAlphaMode: SetAlphaMode::<Identity>,
+            SetSourceRect: SetSourceRect::<Identity>,
+            SetTransform: SetTransform::<Identity>,
+            RestrictToOutput: RestrictToOutput::<Identity>,
+            SetDisableReadback: SetDisableReadback::<Identity>,
+            SetLetterboxingMargins: SetLetterboxingMargins::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IPresentationSurface {}
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
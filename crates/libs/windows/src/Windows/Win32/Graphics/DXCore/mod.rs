@@ -226,6 +226,92 @@ impl IDXCoreAdapter_Vtbl {
         iid == &<IDXCoreAdapter as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDXCoreAdapter_Impl> windows_core::imp::TearOffVtable<Identity> for IDXCoreAdapter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsValid<Identity: windows_core::IUnknownImpl + IDXCoreAdapter_Impl>(this: *mut core::ffi::c_void) -> bool {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXCoreAdapter_Impl::IsValid(this)
+            }
+        }
+        unsafe extern "system" fn IsAttributeSupported<Identity: windows_core::IUnknownImpl + IDXCoreAdapter_Impl>(this: *mut core::ffi::c_void, attributeguid: *const windows_core::GUID) -> bool {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXCoreAdapter_Impl::IsAttributeSupported(this, core::mem::transmute_copy(&attributeguid))
+            }
+        }
+        unsafe extern "system" fn IsPropertySupported<Identity: windows_core::IUnknownImpl + IDXCoreAdapter_Impl>(this: *mut core::ffi::c_void, property: DXCoreAdapterProperty) -> bool {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXCoreAdapter_Impl::IsPropertySupported(this, core::mem::transmute_copy(&property))
+            }
+        }
+        unsafe extern "system" fn GetProperty<Identity: windows_core::IUnknownImpl + IDXCoreAdapter_Impl>(this: *mut core::ffi::c_void, property: DXCoreAdapterProperty, buffersize: usize, propertydata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXCoreAdapter_Impl::GetProperty(this, core::mem::transmute_copy(&property), core::mem::transmute_copy(&buffersize), core::mem::transmute_copy(&propertydata)).into()
+            }
+        }
+        unsafe extern "system" fn GetPropertySize<Identity: windows_core::IUnknownImpl + IDXCoreAdapter_Impl>(this: *mut core::ffi::c_void, property: DXCoreAdapterProperty, buffersize: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXCoreAdapter_Impl::GetPropertySize(this, core::mem::transmute_copy(&property)) {
+                    Ok(ok__) => {
+                        buffersize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsQueryStateSupported<Identity: windows_core::IUnknownImpl + IDXCoreAdapter_Impl>(this: *mut core::ffi::c_void, property: DXCoreAdapterState) -> bool {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXCoreAdapter_Impl::IsQueryStateSupported(this, core::mem::transmute_copy(&property))
+            }
+        }
+        unsafe extern "system" fn QueryState<Identity: windows_core::IUnknownImpl + IDXCoreAdapter_Impl>(this: *mut core::ffi::c_void, state: DXCoreAdapterState, inputstatedetailssize: usize, inputstatedetails: *const core::ffi::c_void, outputbuffersize: usize, outputbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXCoreAdapter_Impl::QueryState(this, core::mem::transmute_copy(&state), core::mem::transmute_copy(&inputstatedetailssize), core::mem::transmute_copy(&inputstatedetails), core::mem::transmute_copy(&outputbuffersize), core::mem::transmute_copy(&outputbuffer)).into()
+            }
+        }
+        unsafe extern "system" fn IsSetStateSupported<Identity: windows_core::IUnknownImpl + IDXCoreAdapter_Impl>(this: *mut core::ffi::c_void, property: DXCoreAdapterState) -> bool {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXCoreAdapter_Impl::IsSetStateSupported(this, core::mem::transmute_copy(&property))
+            }
+        }
+        unsafe extern "system" fn SetState<Identity: windows_core::IUnknownImpl + IDXCoreAdapter_Impl>(this: *mut core::ffi::c_void, state: DXCoreAdapterState, inputstatedetailssize: usize, inputstatedetails: *const core::ffi::c_void, inputdatasize: usize, inputdata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXCoreAdapter_Impl::SetState(this, core::mem::transmute_copy(&state), core::mem::transmute_copy(&inputstatedetailssize), core::mem::transmute_copy(&inputstatedetails), core::mem::transmute_copy(&inputdatasize), core::mem::transmute_copy(&inputdata)).into()
+            }
+        }
+        unsafe extern "system" fn GetFactory<Identity: windows_core::IUnknownImpl + IDXCoreAdapter_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvfactory: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXCoreAdapter_Impl::GetFactory(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvfactory)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsValid: IsValid::<Identity>,
+            IsAttributeSupported: IsAttributeSupported::<Identity>,
+            IsPropertySupported: IsPropertySupported::<Identity>,
+            GetProperty: GetProperty::<Identity>,
+            GetPropertySize: GetPropertySize::<Identity>,
+            IsQueryStateSupported: IsQueryStateSupported::<Identity>,
+            QueryState: QueryState::<Identity>,
+            IsSetStateSupported: IsSetStateSupported::<Identity>,
+            SetState: SetState::<Identity>,
+            GetFactory: GetFactory::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDXCoreAdapter {}
 windows_core::imp::define_interface!(IDXCoreAdapterFactory, IDXCoreAdapterFactory_Vtbl, 0x78ee5945_c36e_4b13_a669_005dd11c0f06);
 windows_core::imp::interface_hierarchy!(IDXCoreAdapterFactory, windows_core::IUnknown);
@@ -328,6 +414,57 @@ impl IDXCoreAdapterFactory_Vtbl {
         iid == &<IDXCoreAdapterFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDXCoreAdapterFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IDXCoreAdapterFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateAdapterList<Identity: windows_core::IUnknownImpl + IDXCoreAdapterFactory_Impl>(this: *mut core::ffi::c_void, numattributes: u32, filterattributes: *const windows_core::GUID, riid: *const windows_core::GUID, ppvadapterlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXCoreAdapterFactory_Impl::CreateAdapterList(this, core::mem::transmute_copy(&numattributes), core::mem::transmute_copy(&filterattributes), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvadapterlist)).into()
+            }
+        }
+        unsafe extern "system" fn GetAdapterByLuid<Identity: windows_core::IUnknownImpl + IDXCoreAdapterFactory_Impl>(this: *mut core::ffi::c_void, adapterluid: *const super::super::Foundation::LUID, riid: *const windows_core::GUID, ppvadapter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXCoreAdapterFactory_Impl::GetAdapterByLuid(this, core::mem::transmute_copy(&adapterluid), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvadapter)).into()
+            }
+        }
+        unsafe extern "system" fn IsNotificationTypeSupported<Identity: windows_core::IUnknownImpl + IDXCoreAdapterFactory_Impl>(this: *mut core::ffi::c_void, notificationtype: DXCoreNotificationType) -> bool {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXCoreAdapterFactory_Impl::IsNotificationTypeSupported(this, core::mem::transmute_copy(&notificationtype))
+            }
+        }
+        unsafe extern "system" fn RegisterEventNotification<Identity: windows_core::IUnknownImpl + IDXCoreAdapterFactory_Impl>(this: *mut core::ffi::c_void, dxcoreobject: *mut core::ffi::c_void, notificationtype: DXCoreNotificationType, callbackfunction: PFN_DXCORE_NOTIFICATION_CALLBACK, callbackcontext: *const core::ffi::c_void, eventcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXCoreAdapterFactory_Impl::RegisterEventNotification(this, core::mem::transmute_copy(&dxcoreobject), core::mem::transmute_copy(&notificationtype), core::mem::transmute_copy(&callbackfunction), core::mem::transmute_copy(&callbackcontext)) {
+                    Ok(ok__) => {
+                        eventcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UnregisterEventNotification<Identity: windows_core::IUnknownImpl + IDXCoreAdapterFactory_Impl>(this: *mut core::ffi::c_void, eventcookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXCoreAdapterFactory_Impl::UnregisterEventNotification(this, core::mem::transmute_copy(&eventcookie)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateAdapterList: CreateAdapterList::<Identity>,
+            GetAdapterByLuid: GetAdapterByLuid::<Identity>,
+            IsNotificationTypeSupported: IsNotificationTypeSupported::<Identity>,
+            RegisterEventNotification: RegisterEventNotification::<Identity>,
+            UnregisterEventNotification: UnregisterEventNotification::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDXCoreAdapterFactory {}
 windows_core::imp::define_interface!(IDXCoreAdapterList, IDXCoreAdapterList_Vtbl, 0x526c7776_40e9_459b_b711_f32ad76dfc28);
 windows_core::imp::interface_hierarchy!(IDXCoreAdapterList, windows_core::IUnknown);
@@ -430,6 +567,58 @@ impl IDXCoreAdapterList_Vtbl {
         iid == &<IDXCoreAdapterList as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDXCoreAdapterList_Impl> windows_core::imp::TearOffVtable<Identity> for IDXCoreAdapterList_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAdapter<Identity: windows_core::IUnknownImpl + IDXCoreAdapterList_Impl>(this: *mut core::ffi::c_void, index: u32, riid: *const windows_core::GUID, ppvadapter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXCoreAdapterList_Impl::GetAdapter(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvadapter)).into()
+            }
+        }
+        unsafe extern "system" fn GetAdapterCount<Identity: windows_core::IUnknownImpl + IDXCoreAdapterList_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXCoreAdapterList_Impl::GetAdapterCount(this)
+            }
+        }
+        unsafe extern "system" fn IsStale<Identity: windows_core::IUnknownImpl + IDXCoreAdapterList_Impl>(this: *mut core::ffi::c_void) -> bool {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXCoreAdapterList_Impl::IsStale(this)
+            }
+        }
+        unsafe extern "system" fn GetFactory<Identity: windows_core::IUnknownImpl + IDXCoreAdapterList_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvfactory: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXCoreAdapterList_Impl::GetFactory(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvfactory)).into()
+            }
+        }
+        unsafe extern "system" fn Sort<Identity: windows_core::IUnknownImpl + IDXCoreAdapterList_Impl>(this: *mut core::ffi::c_void, numpreferences: u32, preferences: *const DXCoreAdapterPreference) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXCoreAdapterList_Impl::Sort(this, core::mem::transmute_copy(&numpreferences), core::mem::transmute_copy(&preferences)).into()
+            }
+        }
+        unsafe extern "system" fn IsAdapterPreferenceSupported<Identity: windows_core::IUnknownImpl + IDXCoreAdapterList_Impl>(this: *mut core::ffi::c_void, preference: DXCoreAdapterPreference) -> bool {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXCoreAdapterList_Impl::IsAdapterPreferenceSupported(this, core::mem::transmute_copy(&preference))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAdapter: GetAdapter::<Identity>,
+            GetAdapterCount: GetAdapterCount::<Identity>,
+            IsStale: IsStale::<Identity>,
+            GetFactory: GetFactory::<Identity>,
+            Sort: Sort::<Identity>,
+            IsAdapterPreferenceSupported: IsAdapterPreferenceSupported::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDXCoreAdapterList {}
 pub const InstanceLuid: DXCoreAdapterProperty = DXCoreAdapterProperty(0u32);
 pub const IsDetachable: DXCoreAdapterProperty = DXCoreAdapterProperty(13u32);
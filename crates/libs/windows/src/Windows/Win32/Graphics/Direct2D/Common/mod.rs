@@ -394,4 +394,63 @@ impl ID2D1SimplifiedGeometrySink_Vtbl {
         iid == &<ID2D1SimplifiedGeometrySink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID2D1SimplifiedGeometrySink_Impl> windows_core::imp::TearOffVtable<Identity> for ID2D1SimplifiedGeometrySink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetFillMode<Identity: windows_core::IUnknownImpl + ID2D1SimplifiedGeometrySink_Impl>(this: *mut core::ffi::c_void, fillmode: D2D1_FILL_MODE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID2D1SimplifiedGeometrySink_Impl::SetFillMode(this, core::mem::transmute_copy(&fillmode))
+            }
+        }
+        unsafe extern "system" fn SetSegmentFlags<Identity: windows_core::IUnknownImpl + ID2D1SimplifiedGeometrySink_Impl>(this: *mut core::ffi::c_void, vertexflags: D2D1_PATH_SEGMENT) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID2D1SimplifiedGeometrySink_Impl::SetSegmentFlags(this, core::mem::transmute_copy(&vertexflags))
+            }
+        }
+        unsafe extern "system" fn BeginFigure<Identity: windows_core::IUnknownImpl + ID2D1SimplifiedGeometrySink_Impl>(this: *mut core::ffi::c_void, startpoint: windows_numerics::Vector2, figurebegin: D2D1_FIGURE_BEGIN) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID2D1SimplifiedGeometrySink_Impl::BeginFigure(this, core::mem::transmute(&startpoint), core::mem::transmute_copy(&figurebegin))
+            }
+        }
+        unsafe extern "system" fn AddLines<Identity: windows_core::IUnknownImpl + ID2D1SimplifiedGeometrySink_Impl>(this: *mut core::ffi::c_void, points: *const windows_numerics::Vector2, pointscount: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID2D1SimplifiedGeometrySink_Impl::AddLines(this, core::mem::transmute_copy(&points), core::mem::transmute_copy(&pointscount))
+            }
+        }
+        unsafe extern "system" fn AddBeziers<Identity: windows_core::IUnknownImpl + ID2D1SimplifiedGeometrySink_Impl>(this: *mut core::ffi::c_void, beziers: *const D2D1_BEZIER_SEGMENT, bezierscount: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID2D1SimplifiedGeometrySink_Impl::AddBeziers(this, core::mem::transmute_copy(&beziers), core::mem::transmute_copy(&bezierscount))
+            }
+        }
+        unsafe extern "system" fn EndFigure<Identity: windows_core::IUnknownImpl + ID2D1SimplifiedGeometrySink_Impl>(this: *mut core::ffi::c_void, figureend: D2D1_FIGURE_END) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID2D1SimplifiedGeometrySink_Impl::EndFigure(this, core::mem::transmute_copy(&figureend))
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + ID2D1SimplifiedGeometrySink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID2D1SimplifiedGeometrySink_Impl::Close(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetFillMode: SetFillMode::<Identity>,
+            SetSegmentFlags: SetSegmentFlags::<Identity>,
+            BeginFigure: BeginFigure::<Identity>,
+            AddLines: AddLines::<Identity>,
+            AddBeziers: AddBeziers::<Identity>,
+            EndFigure: EndFigure::<Identity>,
+            Close: Close::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID2D1SimplifiedGeometrySink {}
@@ -160,6 +160,29 @@ impl IDxcAssembler_Vtbl {
         iid == &<IDxcAssembler as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDxcAssembler_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcAssembler_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AssembleToContainer<Identity: windows_core::IUnknownImpl + IDxcAssembler_Impl>(this: *mut core::ffi::c_void, pshader: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcAssembler_Impl::AssembleToContainer(this, core::mem::transmute_copy(&pshader)) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AssembleToContainer: AssembleToContainer::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDxcAssembler {}
 windows_core::imp::define_interface!(IDxcBlob, IDxcBlob_Vtbl, 0x8ba5fb08_5195_40e2_ac58_0d989c3a0102);
 windows_core::imp::interface_hierarchy!(IDxcBlob, windows_core::IUnknown);
@@ -206,6 +229,30 @@ impl IDxcBlob_Vtbl {
         iid == &<IDxcBlob as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDxcBlob_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcBlob_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetBufferPointer<Identity: windows_core::IUnknownImpl + IDxcBlob_Impl>(this: *mut core::ffi::c_void) -> *mut core::ffi::c_void {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcBlob_Impl::GetBufferPointer(this)
+            }
+        }
+        unsafe extern "system" fn GetBufferSize<Identity: windows_core::IUnknownImpl + IDxcBlob_Impl>(this: *mut core::ffi::c_void) -> usize {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcBlob_Impl::GetBufferSize(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetBufferPointer: GetBufferPointer::<Identity>,
+            GetBufferSize: GetBufferSize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDxcBlob {}
 windows_core::imp::define_interface!(IDxcBlobEncoding, IDxcBlobEncoding_Vtbl, 0x7241d424_2646_4191_97c0_98e96e42fc68);
 impl core::ops::Deref for IDxcBlobEncoding {
@@ -243,6 +290,20 @@ impl IDxcBlobEncoding_Vtbl {
         iid == &<IDxcBlobEncoding as windows_core::Interface>::IID || iid == &<IDxcBlob as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDxcBlobEncoding_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcBlobEncoding_Vtbl
+where
+    IDxcBlob_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetEncoding<Identity: windows_core::IUnknownImpl + IDxcBlobEncoding_Impl>(this: *mut core::ffi::c_void, pknown: *mut windows_core::BOOL, pcodepage: *mut DXC_CP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcBlobEncoding_Impl::GetEncoding(this, core::mem::transmute_copy(&pknown), core::mem::transmute_copy(&pcodepage)).into()
+            }
+        }
+        Self { base__: <IDxcBlob_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetEncoding: GetEncoding::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDxcBlobEncoding {}
 windows_core::imp::define_interface!(IDxcBlobUtf16, IDxcBlobUtf16_Vtbl, 0xa3f84eab_0faa_497e_a39c_ee6ed60b2d84);
 impl core::ops::Deref for IDxcBlobUtf16 {
@@ -295,6 +356,30 @@ impl IDxcBlobUtf16_Vtbl {
         iid == &<IDxcBlobUtf16 as windows_core::Interface>::IID || iid == &<IDxcBlob as windows_core::Interface>::IID || iid == &<IDxcBlobEncoding as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDxcBlobUtf16_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcBlobUtf16_Vtbl
+where
+    IDxcBlobEncoding_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStringPointer<Identity: windows_core::IUnknownImpl + IDxcBlobUtf16_Impl>(this: *mut core::ffi::c_void) -> windows_core::PCWSTR {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcBlobUtf16_Impl::GetStringPointer(this)
+            }
+        }
+        unsafe extern "system" fn GetStringLength<Identity: windows_core::IUnknownImpl + IDxcBlobUtf16_Impl>(this: *mut core::ffi::c_void) -> usize {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcBlobUtf16_Impl::GetStringLength(this)
+            }
+        }
+        Self {
+            base__: <IDxcBlobEncoding_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStringPointer: GetStringPointer::<Identity>,
+            GetStringLength: GetStringLength::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDxcBlobUtf16 {}
 windows_core::imp::define_interface!(IDxcBlobUtf8, IDxcBlobUtf8_Vtbl, 0x3da636c9_ba71_4024_a301_30cbf125305b);
 impl core::ops::Deref for IDxcBlobUtf8 {
@@ -347,6 +432,30 @@ impl IDxcBlobUtf8_Vtbl {
         iid == &<IDxcBlobUtf8 as windows_core::Interface>::IID || iid == &<IDxcBlob as windows_core::Interface>::IID || iid == &<IDxcBlobEncoding as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDxcBlobUtf8_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcBlobUtf8_Vtbl
+where
+    IDxcBlobEncoding_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStringPointer<Identity: windows_core::IUnknownImpl + IDxcBlobUtf8_Impl>(this: *mut core::ffi::c_void) -> windows_core::PCSTR {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcBlobUtf8_Impl::GetStringPointer(this)
+            }
+        }
+        unsafe extern "system" fn GetStringLength<Identity: windows_core::IUnknownImpl + IDxcBlobUtf8_Impl>(this: *mut core::ffi::c_void) -> usize {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcBlobUtf8_Impl::GetStringLength(this)
+            }
+        }
+        Self {
+            base__: <IDxcBlobEncoding_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStringPointer: GetStringPointer::<Identity>,
+            GetStringLength: GetStringLength::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDxcBlobUtf8 {}
 windows_core::imp::define_interface!(IDxcCompiler, IDxcCompiler_Vtbl, 0x8c210bf3_011f_4422_8d70_6f9acb8db617);
 windows_core::imp::interface_hierarchy!(IDxcCompiler, windows_core::IUnknown);
@@ -447,6 +556,55 @@ impl IDxcCompiler_Vtbl {
         iid == &<IDxcCompiler as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDxcCompiler_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcCompiler_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Compile<Identity: windows_core::IUnknownImpl + IDxcCompiler_Impl>(this: *mut core::ffi::c_void, psource: *mut core::ffi::c_void, psourcename: windows_core::PCWSTR, pentrypoint: windows_core::PCWSTR, ptargetprofile: windows_core::PCWSTR, parguments: *const windows_core::PCWSTR, argcount: u32, pdefines: *const DxcDefine, definecount: u32, pincludehandler: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcCompiler_Impl::Compile(this, core::mem::transmute_copy(&psource), core::mem::transmute(&psourcename), core::mem::transmute(&pentrypoint), core::mem::transmute(&ptargetprofile), core::mem::transmute_copy(&parguments), core::mem::transmute_copy(&argcount), core::mem::transmute_copy(&pdefines), core::mem::transmute_copy(&definecount), core::mem::transmute_copy(&pincludehandler)) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Preprocess<Identity: windows_core::IUnknownImpl + IDxcCompiler_Impl>(this: *mut core::ffi::c_void, psource: *mut core::ffi::c_void, psourcename: windows_core::PCWSTR, parguments: *const windows_core::PCWSTR, argcount: u32, pdefines: *const DxcDefine, definecount: u32, pincludehandler: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcCompiler_Impl::Preprocess(this, core::mem::transmute_copy(&psource), core::mem::transmute(&psourcename), core::mem::transmute_copy(&parguments), core::mem::transmute_copy(&argcount), core::mem::transmute_copy(&pdefines), core::mem::transmute_copy(&definecount), core::mem::transmute_copy(&pincludehandler)) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Disassemble<Identity: windows_core::IUnknownImpl + IDxcCompiler_Impl>(this: *mut core::ffi::c_void, psource: *mut core::ffi::c_void, ppdisassembly: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcCompiler_Impl::Disassemble(this, core::mem::transmute_copy(&psource)) {
+                    Ok(ok__) => {
+                        ppdisassembly.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Compile: Compile::<Identity>,
+            Preprocess: Preprocess::<Identity>,
+            Disassemble: Disassemble::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDxcCompiler {}
 windows_core::imp::define_interface!(IDxcCompiler2, IDxcCompiler2_Vtbl, 0xa005a9d9_b8bb_4594_b5c9_0e633bec4d37);
 impl core::ops::Deref for IDxcCompiler2 {
@@ -508,6 +666,20 @@ impl IDxcCompiler2_Vtbl {
         iid == &<IDxcCompiler2 as windows_core::Interface>::IID || iid == &<IDxcCompiler as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDxcCompiler2_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcCompiler2_Vtbl
+where
+    IDxcCompiler_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CompileWithDebug<Identity: windows_core::IUnknownImpl + IDxcCompiler2_Impl>(this: *mut core::ffi::c_void, psource: *mut core::ffi::c_void, psourcename: windows_core::PCWSTR, pentrypoint: windows_core::PCWSTR, ptargetprofile: windows_core::PCWSTR, parguments: *const windows_core::PCWSTR, argcount: u32, pdefines: *const DxcDefine, definecount: u32, pincludehandler: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void, ppdebugblobname: *mut windows_core::PWSTR, ppdebugblob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcCompiler2_Impl::CompileWithDebug(this, core::mem::transmute_copy(&psource), core::mem::transmute(&psourcename), core::mem::transmute(&pentrypoint), core::mem::transmute(&ptargetprofile), core::mem::transmute_copy(&parguments), core::mem::transmute_copy(&argcount), core::mem::transmute_copy(&pdefines), core::mem::transmute_copy(&definecount), core::mem::transmute_copy(&pincludehandler), core::mem::transmute_copy(&ppresult), core::mem::transmute_copy(&ppdebugblobname), core::mem::transmute_copy(&ppdebugblob)).into()
+            }
+        }
+        Self { base__: <IDxcCompiler_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CompileWithDebug: CompileWithDebug::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDxcCompiler2 {}
 windows_core::imp::define_interface!(IDxcCompiler3, IDxcCompiler3_Vtbl, 0x228b4687_5a6a_4730_900c_9702b2203f54);
 windows_core::imp::interface_hierarchy!(IDxcCompiler3, windows_core::IUnknown);
@@ -563,6 +735,30 @@ impl IDxcCompiler3_Vtbl {
         iid == &<IDxcCompiler3 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDxcCompiler3_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcCompiler3_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Compile<Identity: windows_core::IUnknownImpl + IDxcCompiler3_Impl>(this: *mut core::ffi::c_void, psource: *const DxcBuffer, parguments: *const windows_core::PCWSTR, argcount: u32, pincludehandler: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcCompiler3_Impl::Compile(this, core::mem::transmute_copy(&psource), core::mem::transmute_copy(&parguments), core::mem::transmute_copy(&argcount), core::mem::transmute_copy(&pincludehandler), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppresult)).into()
+            }
+        }
+        unsafe extern "system" fn Disassemble<Identity: windows_core::IUnknownImpl + IDxcCompiler3_Impl>(this: *mut core::ffi::c_void, pobject: *const DxcBuffer, riid: *const windows_core::GUID, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcCompiler3_Impl::Disassemble(this, core::mem::transmute_copy(&pobject), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppresult)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Compile: Compile::<Identity>,
+            Disassemble: Disassemble::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDxcCompiler3 {}
 windows_core::imp::define_interface!(IDxcCompilerArgs, IDxcCompilerArgs_Vtbl, 0x73effe2a_70dc_45f8_9690_eff64c02429d);
 windows_core::imp::interface_hierarchy!(IDxcCompilerArgs, windows_core::IUnknown);
@@ -645,6 +841,51 @@ impl IDxcCompilerArgs_Vtbl {
         iid == &<IDxcCompilerArgs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDxcCompilerArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcCompilerArgs_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetArguments<Identity: windows_core::IUnknownImpl + IDxcCompilerArgs_Impl>(this: *mut core::ffi::c_void) -> *mut windows_core::PCWSTR {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcCompilerArgs_Impl::GetArguments(this)
+            }
+        }
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IDxcCompilerArgs_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcCompilerArgs_Impl::GetCount(this)
+            }
+        }
+        unsafe extern "system" fn AddArguments<Identity: windows_core::IUnknownImpl + IDxcCompilerArgs_Impl>(this: *mut core::ffi::c_void, parguments: *const windows_core::PCWSTR, argcount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcCompilerArgs_Impl::AddArguments(this, core::mem::transmute_copy(&parguments), core::mem::transmute_copy(&argcount)).into()
+            }
+        }
+        unsafe extern "system" fn AddArgumentsUTF8<Identity: windows_core::IUnknownImpl + IDxcCompilerArgs_Impl>(this: *mut core::ffi::c_void, parguments: *const windows_core::PCSTR, argcount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcCompilerArgs_Impl::AddArgumentsUTF8(this, core::mem::transmute_copy(&parguments), core::mem::transmute_copy(&argcount)).into()
+            }
+        }
+        unsafe extern "system" fn AddDefines<Identity: windows_core::IUnknownImpl + IDxcCompilerArgs_Impl>(this: *mut core::ffi::c_void, pdefines: *const DxcDefine, definecount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcCompilerArgs_Impl::AddDefines(this, core::mem::transmute_copy(&pdefines), core::mem::transmute_copy(&definecount)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetArguments: GetArguments::<Identity>,
+            GetCount: GetCount::<Identity>,
+            AddArguments: AddArguments::<Identity>,
+            AddArgumentsUTF8: AddArgumentsUTF8::<Identity>,
+            AddDefines: AddDefines::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDxcCompilerArgs {}
 windows_core::imp::define_interface!(IDxcContainerBuilder, IDxcContainerBuilder_Vtbl, 0x334b1f50_2292_4b35_99a1_25588d8c17fe);
 windows_core::imp::interface_hierarchy!(IDxcContainerBuilder, windows_core::IUnknown);
@@ -730,6 +971,50 @@ impl IDxcContainerBuilder_Vtbl {
         iid == &<IDxcContainerBuilder as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDxcContainerBuilder_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcContainerBuilder_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Load<Identity: windows_core::IUnknownImpl + IDxcContainerBuilder_Impl>(this: *mut core::ffi::c_void, pdxilcontainerheader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcContainerBuilder_Impl::Load(this, core::mem::transmute_copy(&pdxilcontainerheader)).into()
+            }
+        }
+        unsafe extern "system" fn AddPart<Identity: windows_core::IUnknownImpl + IDxcContainerBuilder_Impl>(this: *mut core::ffi::c_void, fourcc: u32, psource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcContainerBuilder_Impl::AddPart(this, core::mem::transmute_copy(&fourcc), core::mem::transmute_copy(&psource)).into()
+            }
+        }
+        unsafe extern "system" fn RemovePart<Identity: windows_core::IUnknownImpl + IDxcContainerBuilder_Impl>(this: *mut core::ffi::c_void, fourcc: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcContainerBuilder_Impl::RemovePart(this, core::mem::transmute_copy(&fourcc)).into()
+            }
+        }
+        unsafe extern "system" fn SerializeContainer<Identity: windows_core::IUnknownImpl + IDxcContainerBuilder_Impl>(this: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcContainerBuilder_Impl::SerializeContainer(this) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Load: Load::<Identity>,
+            AddPart: AddPart::<Identity>,
+            RemovePart: RemovePart::<Identity>,
+            SerializeContainer: SerializeContainer::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDxcContainerBuilder {}
 windows_core::imp::define_interface!(IDxcContainerReflection, IDxcContainerReflection_Vtbl, 0xd2c21b26_8350_4bdc_976a_331ce6f4c54c);
 windows_core::imp::interface_hierarchy!(IDxcContainerReflection, windows_core::IUnknown);
@@ -863,6 +1148,82 @@ impl IDxcContainerReflection_Vtbl {
         iid == &<IDxcContainerReflection as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDxcContainerReflection_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcContainerReflection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Load<Identity: windows_core::IUnknownImpl + IDxcContainerReflection_Impl>(this: *mut core::ffi::c_void, pcontainer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcContainerReflection_Impl::Load(this, core::mem::transmute_copy(&pcontainer)).into()
+            }
+        }
+        unsafe extern "system" fn GetPartCount<Identity: windows_core::IUnknownImpl + IDxcContainerReflection_Impl>(this: *mut core::ffi::c_void, presult: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcContainerReflection_Impl::GetPartCount(this) {
+                    Ok(ok__) => {
+                        presult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPartKind<Identity: windows_core::IUnknownImpl + IDxcContainerReflection_Impl>(this: *mut core::ffi::c_void, idx: u32, presult: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcContainerReflection_Impl::GetPartKind(this, core::mem::transmute_copy(&idx)) {
+                    Ok(ok__) => {
+                        presult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPartContent<Identity: windows_core::IUnknownImpl + IDxcContainerReflection_Impl>(this: *mut core::ffi::c_void, idx: u32, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcContainerReflection_Impl::GetPartContent(this, core::mem::transmute_copy(&idx)) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindFirstPartKind<Identity: windows_core::IUnknownImpl + IDxcContainerReflection_Impl>(this: *mut core::ffi::c_void, kind: u32, presult: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcContainerReflection_Impl::FindFirstPartKind(this, core::mem::transmute_copy(&kind)) {
+                    Ok(ok__) => {
+                        presult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPartReflection<Identity: windows_core::IUnknownImpl + IDxcContainerReflection_Impl>(this: *mut core::ffi::c_void, idx: u32, iid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcContainerReflection_Impl::GetPartReflection(this, core::mem::transmute_copy(&idx), core::mem::transmute_copy(&iid), core::mem::transmute_copy(&ppvobject)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Load: Load::<Identity>,
+            GetPartCount: GetPartCount::<Identity>,
+            GetPartKind: GetPartKind::<Identity>,
+            GetPartContent: GetPartContent::<Identity>,
+            FindFirstPartKind: FindFirstPartKind::<Identity>,
+            GetPartReflection: GetPartReflection::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDxcContainerReflection {}
 windows_core::imp::define_interface!(IDxcExtraOutputs, IDxcExtraOutputs_Vtbl, 0x319b37a2_a5c2_494a_a5de_4801b2faf989);
 windows_core::imp::interface_hierarchy!(IDxcExtraOutputs, windows_core::IUnknown);
@@ -912,6 +1273,30 @@ impl IDxcExtraOutputs_Vtbl {
         iid == &<IDxcExtraOutputs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDxcExtraOutputs_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcExtraOutputs_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetOutputCount<Identity: windows_core::IUnknownImpl + IDxcExtraOutputs_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcExtraOutputs_Impl::GetOutputCount(this)
+            }
+        }
+        unsafe extern "system" fn GetOutput<Identity: windows_core::IUnknownImpl + IDxcExtraOutputs_Impl>(this: *mut core::ffi::c_void, uindex: u32, iid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void, ppoutputtype: *mut *mut core::ffi::c_void, ppoutputname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcExtraOutputs_Impl::GetOutput(this, core::mem::transmute_copy(&uindex), core::mem::transmute_copy(&iid), core::mem::transmute_copy(&ppvobject), core::mem::transmute_copy(&ppoutputtype), core::mem::transmute_copy(&ppoutputname)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetOutputCount: GetOutputCount::<Identity>,
+            GetOutput: GetOutput::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDxcExtraOutputs {}
 windows_core::imp::define_interface!(IDxcIncludeHandler, IDxcIncludeHandler_Vtbl, 0x7f61fc7d_950d_467f_b3e3_3c02fb49187c);
 windows_core::imp::interface_hierarchy!(IDxcIncludeHandler, windows_core::IUnknown);
@@ -955,6 +1340,26 @@ impl IDxcIncludeHandler_Vtbl {
         iid == &<IDxcIncludeHandler as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDxcIncludeHandler_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcIncludeHandler_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn LoadSource<Identity: windows_core::IUnknownImpl + IDxcIncludeHandler_Impl>(this: *mut core::ffi::c_void, pfilename: windows_core::PCWSTR, ppincludesource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcIncludeHandler_Impl::LoadSource(this, core::mem::transmute(&pfilename)) {
+                    Ok(ok__) => {
+                        ppincludesource.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, LoadSource: LoadSource::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDxcIncludeHandler {}
 windows_core::imp::define_interface!(IDxcLibrary, IDxcLibrary_Vtbl, 0xe5204dc7_d18c_4c3c_bdfb_851673980fe7);
 windows_core::imp::interface_hierarchy!(IDxcLibrary, windows_core::IUnknown);
@@ -1214,15 +1619,150 @@ impl IDxcLibrary_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
-impl windows_core::RuntimeName for IDxcLibrary {}
-windows_core::imp::define_interface!(IDxcLinker, IDxcLinker_Vtbl, 0xf1b5be2a_62dd_4327_a1c2_42ac1e1e78e6);
-windows_core::imp::interface_hierarchy!(IDxcLinker, windows_core::IUnknown);
-impl IDxcLinker {
-    pub unsafe fn RegisterLibrary<P0, P1>(&self, plibname: P0, plib: P1) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<windows_core::PCWSTR>,
-        P1: windows_core::Param<IDxcBlob>,
-    {
+impl<Identity: windows_core::IUnknownImpl + IDxcLibrary_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcLibrary_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetMalloc<Identity: windows_core::IUnknownImpl + IDxcLibrary_Impl>(this: *mut core::ffi::c_void, pmalloc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcLibrary_Impl::SetMalloc(this, core::mem::transmute_copy(&pmalloc)).into()
+            }
+        }
+        unsafe extern "system" fn CreateBlobFromBlob<Identity: windows_core::IUnknownImpl + IDxcLibrary_Impl>(this: *mut core::ffi::c_void, pblob: *mut core::ffi::c_void, offset: u32, length: u32, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcLibrary_Impl::CreateBlobFromBlob(this, core::mem::transmute_copy(&pblob), core::mem::transmute_copy(&offset), core::mem::transmute_copy(&length)) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateBlobFromFile<Identity: windows_core::IUnknownImpl + IDxcLibrary_Impl>(this: *mut core::ffi::c_void, pfilename: windows_core::PCWSTR, codepage: *const DXC_CP, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcLibrary_Impl::CreateBlobFromFile(this, core::mem::transmute(&pfilename), core::mem::transmute_copy(&codepage)) {
+                    Ok(ok__) => {
+                        pblobencoding.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateBlobWithEncodingFromPinned<Identity: windows_core::IUnknownImpl + IDxcLibrary_Impl>(this: *mut core::ffi::c_void, ptext: *const core::ffi::c_void, size: u32, codepage: DXC_CP, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcLibrary_Impl::CreateBlobWithEncodingFromPinned(this, core::mem::transmute_copy(&ptext), core::mem::transmute_copy(&size), core::mem::transmute_copy(&codepage)) {
+                    Ok(ok__) => {
+                        pblobencoding.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateBlobWithEncodingOnHeapCopy<Identity: windows_core::IUnknownImpl + IDxcLibrary_Impl>(this: *mut core::ffi::c_void, ptext: *const core::ffi::c_void, size: u32, codepage: DXC_CP, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcLibrary_Impl::CreateBlobWithEncodingOnHeapCopy(this, core::mem::transmute_copy(&ptext), core::mem::transmute_copy(&size), core::mem::transmute_copy(&codepage)) {
+                    Ok(ok__) => {
+                        pblobencoding.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateBlobWithEncodingOnMalloc<Identity: windows_core::IUnknownImpl + IDxcLibrary_Impl>(this: *mut core::ffi::c_void, ptext: *const core::ffi::c_void, pimalloc: *mut core::ffi::c_void, size: u32, codepage: DXC_CP, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcLibrary_Impl::CreateBlobWithEncodingOnMalloc(this, core::mem::transmute_copy(&ptext), core::mem::transmute_copy(&pimalloc), core::mem::transmute_copy(&size), core::mem::transmute_copy(&codepage)) {
+                    Ok(ok__) => {
+                        pblobencoding.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateIncludeHandler<Identity: windows_core::IUnknownImpl + IDxcLibrary_Impl>(this: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcLibrary_Impl::CreateIncludeHandler(this) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateStreamFromBlobReadOnly<Identity: windows_core::IUnknownImpl + IDxcLibrary_Impl>(this: *mut core::ffi::c_void, pblob: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcLibrary_Impl::CreateStreamFromBlobReadOnly(this, core::mem::transmute_copy(&pblob)) {
+                    Ok(ok__) => {
+                        ppstream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetBlobAsUtf8<Identity: windows_core::IUnknownImpl + IDxcLibrary_Impl>(this: *mut core::ffi::c_void, pblob: *mut core::ffi::c_void, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcLibrary_Impl::GetBlobAsUtf8(this, core::mem::transmute_copy(&pblob)) {
+                    Ok(ok__) => {
+                        pblobencoding.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetBlobAsUtf16<Identity: windows_core::IUnknownImpl + IDxcLibrary_Impl>(this: *mut core::ffi::c_void, pblob: *mut core::ffi::c_void, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcLibrary_Impl::GetBlobAsUtf16(this, core::mem::transmute_copy(&pblob)) {
+                    Ok(ok__) => {
+                        pblobencoding.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetMalloc: SetMalloc::<Identity>,
+            CreateBlobFromBlob: CreateBlobFromBlob::<Identity>,
+            CreateBlobFromFile: CreateBlobFromFile::<Identity>,
+            CreateBlobWithEncodingFromPinned: CreateBlobWithEncodingFromPinned::<Identity>,
+            CreateBlobWithEncodingOnHeapCopy: CreateBlobWithEncodingOnHeapCopy::<Identity>,
+            CreateBlobWithEncodingOnMalloc: CreateBlobWithEncodingOnMalloc::<Identity>,
+            CreateIncludeHandler: CreateIncludeHandler::<Identity>,
+            CreateStreamFromBlobReadOnly: CreateStreamFromBlobReadOnly::<Identity>,
+            GetBlobAsUtf8: GetBlobAsUtf8::<Identity>,
+            GetBlobAsUtf16: GetBlobAsUtf16::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::RuntimeName for IDxcLibrary {}
+windows_core::imp::define_interface!(IDxcLinker, IDxcLinker_Vtbl, 0xf1b5be2a_62dd_4327_a1c2_42ac1e1e78e6);
+windows_core::imp::interface_hierarchy!(IDxcLinker, windows_core::IUnknown);
+impl IDxcLinker {
+    pub unsafe fn RegisterLibrary<P0, P1>(&self, plibname: P0, plib: P1) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::PCWSTR>,
+        P1: windows_core::Param<IDxcBlob>,
+    {
         unsafe { (windows_core::Interface::vtable(self).RegisterLibrary)(windows_core::Interface::as_raw(self), plibname.param().abi(), plib.param().abi()).ok() }
     }
     pub unsafe fn Link<P0, P1>(&self, pentryname: P0, ptargetprofile: P1, plibnames: &[windows_core::PCWSTR], parguments: Option<&[windows_core::PCWSTR]>) -> windows_core::Result<IDxcOperationResult>
@@ -1277,6 +1817,36 @@ impl IDxcLinker_Vtbl {
         iid == &<IDxcLinker as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDxcLinker_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcLinker_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterLibrary<Identity: windows_core::IUnknownImpl + IDxcLinker_Impl>(this: *mut core::ffi::c_void, plibname: windows_core::PCWSTR, plib: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcLinker_Impl::RegisterLibrary(this, core::mem::transmute(&plibname), core::mem::transmute_copy(&plib)).into()
+            }
+        }
+        unsafe extern "system" fn Link<Identity: windows_core::IUnknownImpl + IDxcLinker_Impl>(this: *mut core::ffi::c_void, pentryname: windows_core::PCWSTR, ptargetprofile: windows_core::PCWSTR, plibnames: *const windows_core::PCWSTR, libcount: u32, parguments: *const windows_core::PCWSTR, argcount: u32, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcLinker_Impl::Link(this, core::mem::transmute(&pentryname), core::mem::transmute(&ptargetprofile), core::mem::transmute_copy(&plibnames), core::mem::transmute_copy(&libcount), core::mem::transmute_copy(&parguments), core::mem::transmute_copy(&argcount)) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterLibrary: RegisterLibrary::<Identity>,
+            Link: Link::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDxcLinker {}
 windows_core::imp::define_interface!(IDxcOperationResult, IDxcOperationResult_Vtbl, 0xcedb484a_d4e9_445a_b991_ca21ca157dc2);
 windows_core::imp::interface_hierarchy!(IDxcOperationResult, windows_core::IUnknown);
@@ -1362,6 +1932,55 @@ impl IDxcOperationResult_Vtbl {
         iid == &<IDxcOperationResult as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDxcOperationResult_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcOperationResult_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStatus<Identity: windows_core::IUnknownImpl + IDxcOperationResult_Impl>(this: *mut core::ffi::c_void, pstatus: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcOperationResult_Impl::GetStatus(this) {
+                    Ok(ok__) => {
+                        pstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetResult<Identity: windows_core::IUnknownImpl + IDxcOperationResult_Impl>(this: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcOperationResult_Impl::GetResult(this) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetErrorBuffer<Identity: windows_core::IUnknownImpl + IDxcOperationResult_Impl>(this: *mut core::ffi::c_void, pperrors: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcOperationResult_Impl::GetErrorBuffer(this) {
+                    Ok(ok__) => {
+                        pperrors.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStatus: GetStatus::<Identity>,
+            GetResult: GetResult::<Identity>,
+            GetErrorBuffer: GetErrorBuffer::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDxcOperationResult {}
 windows_core::imp::define_interface!(IDxcOptimizer, IDxcOptimizer_Vtbl, 0x25740e2e_9cba_401b_9119_4fb42f39f270);
 windows_core::imp::interface_hierarchy!(IDxcOptimizer, windows_core::IUnknown);
@@ -1441,6 +2060,49 @@ impl IDxcOptimizer_Vtbl {
         iid == &<IDxcOptimizer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDxcOptimizer_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcOptimizer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAvailablePassCount<Identity: windows_core::IUnknownImpl + IDxcOptimizer_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcOptimizer_Impl::GetAvailablePassCount(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAvailablePass<Identity: windows_core::IUnknownImpl + IDxcOptimizer_Impl>(this: *mut core::ffi::c_void, index: u32, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcOptimizer_Impl::GetAvailablePass(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RunOptimizer<Identity: windows_core::IUnknownImpl + IDxcOptimizer_Impl>(this: *mut core::ffi::c_void, pblob: *mut core::ffi::c_void, ppoptions: *const windows_core::PCWSTR, optioncount: u32, poutputmodule: *mut *mut core::ffi::c_void, ppoutputtext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcOptimizer_Impl::RunOptimizer(this, core::mem::transmute_copy(&pblob), core::mem::transmute_copy(&ppoptions), core::mem::transmute_copy(&optioncount), core::mem::transmute_copy(&poutputmodule), core::mem::transmute_copy(&ppoutputtext)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAvailablePassCount: GetAvailablePassCount::<Identity>,
+            GetAvailablePass: GetAvailablePass::<Identity>,
+            RunOptimizer: RunOptimizer::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDxcOptimizer {}
 windows_core::imp::define_interface!(IDxcOptimizerPass, IDxcOptimizerPass_Vtbl, 0xae2cd79f_cc22_453f_9b6b_b124e7a5204c);
 windows_core::imp::interface_hierarchy!(IDxcOptimizerPass, windows_core::IUnknown);
@@ -1568,6 +2230,81 @@ impl IDxcOptimizerPass_Vtbl {
         iid == &<IDxcOptimizerPass as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDxcOptimizerPass_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcOptimizerPass_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetOptionName<Identity: windows_core::IUnknownImpl + IDxcOptimizerPass_Impl>(this: *mut core::ffi::c_void, ppresult: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcOptimizerPass_Impl::GetOptionName(this) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDescription<Identity: windows_core::IUnknownImpl + IDxcOptimizerPass_Impl>(this: *mut core::ffi::c_void, ppresult: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcOptimizerPass_Impl::GetDescription(this) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOptionArgCount<Identity: windows_core::IUnknownImpl + IDxcOptimizerPass_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcOptimizerPass_Impl::GetOptionArgCount(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOptionArgName<Identity: windows_core::IUnknownImpl + IDxcOptimizerPass_Impl>(this: *mut core::ffi::c_void, argindex: u32, ppresult: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcOptimizerPass_Impl::GetOptionArgName(this, core::mem::transmute_copy(&argindex)) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOptionArgDescription<Identity: windows_core::IUnknownImpl + IDxcOptimizerPass_Impl>(this: *mut core::ffi::c_void, argindex: u32, ppresult: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcOptimizerPass_Impl::GetOptionArgDescription(this, core::mem::transmute_copy(&argindex)) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetOptionName: GetOptionName::<Identity>,
+            GetDescription: GetDescription::<Identity>,
+            GetOptionArgCount: GetOptionArgCount::<Identity>,
+            GetOptionArgName: GetOptionArgName::<Identity>,
+            GetOptionArgDescription: GetOptionArgDescription::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDxcOptimizerPass {}
 windows_core::imp::define_interface!(IDxcPdbUtils, IDxcPdbUtils_Vtbl, 0xe6c9647e_9d6a_4c3b_b94c_524b5a6c343d);
 windows_core::imp::interface_hierarchy!(IDxcPdbUtils, windows_core::IUnknown);
@@ -2049,16 +2786,302 @@ impl IDxcPdbUtils_Vtbl {
         iid == &<IDxcPdbUtils as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IDxcPdbUtils {}
-windows_core::imp::define_interface!(IDxcResult, IDxcResult_Vtbl, 0x58346cda_dde7_4497_9461_6f87af5e0659);
-impl core::ops::Deref for IDxcResult {
-    type Target = IDxcOperationResult;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(IDxcResult, windows_core::IUnknown, IDxcOperationResult);
-impl IDxcResult {
+impl<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcPdbUtils_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Load<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void, ppdbordxil: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcPdbUtils_Impl::Load(this, core::mem::transmute_copy(&ppdbordxil)).into()
+            }
+        }
+        unsafe extern "system" fn GetSourceCount<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcPdbUtils_Impl::GetSourceCount(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSource<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void, uindex: u32, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcPdbUtils_Impl::GetSource(this, core::mem::transmute_copy(&uindex)) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSourceName<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void, uindex: u32, presult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcPdbUtils_Impl::GetSourceName(this, core::mem::transmute_copy(&uindex)) {
+                    Ok(ok__) => {
+                        presult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFlagCount<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcPdbUtils_Impl::GetFlagCount(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFlag<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void, uindex: u32, presult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcPdbUtils_Impl::GetFlag(this, core::mem::transmute_copy(&uindex)) {
+                    Ok(ok__) => {
+                        presult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetArgCount<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcPdbUtils_Impl::GetArgCount(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetArg<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void, uindex: u32, presult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcPdbUtils_Impl::GetArg(this, core::mem::transmute_copy(&uindex)) {
+                    Ok(ok__) => {
+                        presult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetArgPairCount<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcPdbUtils_Impl::GetArgPairCount(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetArgPair<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void, uindex: u32, pname: *mut *mut core::ffi::c_void, pvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcPdbUtils_Impl::GetArgPair(this, core::mem::transmute_copy(&uindex), core::mem::transmute_copy(&pname), core::mem::transmute_copy(&pvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetDefineCount<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcPdbUtils_Impl::GetDefineCount(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDefine<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void, uindex: u32, presult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcPdbUtils_Impl::GetDefine(this, core::mem::transmute_copy(&uindex)) {
+                    Ok(ok__) => {
+                        presult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTargetProfile<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void, presult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcPdbUtils_Impl::GetTargetProfile(this) {
+                    Ok(ok__) => {
+                        presult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetEntryPoint<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void, presult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcPdbUtils_Impl::GetEntryPoint(this) {
+                    Ok(ok__) => {
+                        presult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMainFileName<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void, presult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcPdbUtils_Impl::GetMainFileName(this) {
+                    Ok(ok__) => {
+                        presult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHash<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcPdbUtils_Impl::GetHash(this) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void, presult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcPdbUtils_Impl::GetName(this) {
+                    Ok(ok__) => {
+                        presult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsFullPDB<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcPdbUtils_Impl::IsFullPDB(this)
+            }
+        }
+        unsafe extern "system" fn GetFullPDB<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void, ppfullpdb: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcPdbUtils_Impl::GetFullPDB(this) {
+                    Ok(ok__) => {
+                        ppfullpdb.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetVersionInfo<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void, ppversioninfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcPdbUtils_Impl::GetVersionInfo(this) {
+                    Ok(ok__) => {
+                        ppversioninfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCompiler<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void, pcompiler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcPdbUtils_Impl::SetCompiler(this, core::mem::transmute_copy(&pcompiler)).into()
+            }
+        }
+        unsafe extern "system" fn CompileForFullPDB<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcPdbUtils_Impl::CompileForFullPDB(this) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OverrideArgs<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void, pargpairs: *const DxcArgPair, unumargpairs: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcPdbUtils_Impl::OverrideArgs(this, core::mem::transmute_copy(&pargpairs), core::mem::transmute_copy(&unumargpairs)).into()
+            }
+        }
+        unsafe extern "system" fn OverrideRootSignature<Identity: windows_core::IUnknownImpl + IDxcPdbUtils_Impl>(this: *mut core::ffi::c_void, prootsignature: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcPdbUtils_Impl::OverrideRootSignature(this, core::mem::transmute(&prootsignature)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Load: Load::<Identity>,
+            GetSourceCount: GetSourceCount::<Identity>,
+            GetSource: GetSource::<Identity>,
+            GetSourceName: GetSourceName::<Identity>,
+            GetFlagCount: GetFlagCount::<Identity>,
+            GetFlag: GetFlag::<Identity>,
+            GetArgCount: GetArgCount::<Identity>,
+            GetArg: GetArg::<Identity>,
+            GetArgPairCount: GetArgPairCount::<Identity>,
+            GetArgPair: GetArgPair::<Identity>,
+            GetDefineCount: GetDefineCount::<Identity>,
+            GetDefine: GetDefine::<Identity>,
+            GetTargetProfile: GetTargetProfile::<Identity>,
+            GetEntryPoint: GetEntryPoint::<Identity>,
+            GetMainFileName: GetMainFileName::<Identity>,
+            GetHash: GetHash::<Identity>,
+            GetName: GetName::<Identity>,
+            IsFullPDB: IsFullPDB::<Identity>,
+            GetFullPDB: GetFullPDB::<Identity>,
+            GetVersionInfo: GetVersionInfo::<Identity>,
+            SetCompiler: SetCompiler::<Identity>,
+            CompileForFullPDB: CompileForFullPDB::<Identity>,
+            OverrideArgs: OverrideArgs::<Identity>,
+            OverrideRootSignature: OverrideRootSignature::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IDxcPdbUtils {}
+windows_core::imp::define_interface!(IDxcResult, IDxcResult_Vtbl, 0x58346cda_dde7_4497_9461_6f87af5e0659);
+impl core::ops::Deref for IDxcResult {
+    type Target = IDxcOperationResult;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(IDxcResult, windows_core::IUnknown, IDxcOperationResult);
+impl IDxcResult {
     pub unsafe fn HasOutput(&self, dxcoutkind: DXC_OUT_KIND) -> windows_core::BOOL {
         unsafe { (windows_core::Interface::vtable(self).HasOutput)(windows_core::Interface::as_raw(self), dxcoutkind) }
     }
@@ -2140,6 +3163,51 @@ impl IDxcResult_Vtbl {
         iid == &<IDxcResult as windows_core::Interface>::IID || iid == &<IDxcOperationResult as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDxcResult_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcResult_Vtbl
+where
+    IDxcOperationResult_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn HasOutput<Identity: windows_core::IUnknownImpl + IDxcResult_Impl>(this: *mut core::ffi::c_void, dxcoutkind: DXC_OUT_KIND) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcResult_Impl::HasOutput(this, core::mem::transmute_copy(&dxcoutkind))
+            }
+        }
+        unsafe extern "system" fn GetOutput<Identity: windows_core::IUnknownImpl + IDxcResult_Impl>(this: *mut core::ffi::c_void, dxcoutkind: DXC_OUT_KIND, iid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void, ppoutputname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcResult_Impl::GetOutput(this, core::mem::transmute_copy(&dxcoutkind), core::mem::transmute_copy(&iid), core::mem::transmute_copy(&ppvobject), core::mem::transmute_copy(&ppoutputname)).into()
+            }
+        }
+        unsafe extern "system" fn GetNumOutputs<Identity: windows_core::IUnknownImpl + IDxcResult_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcResult_Impl::GetNumOutputs(this)
+            }
+        }
+        unsafe extern "system" fn GetOutputByIndex<Identity: windows_core::IUnknownImpl + IDxcResult_Impl>(this: *mut core::ffi::c_void, index: u32) -> DXC_OUT_KIND {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcResult_Impl::GetOutputByIndex(this, core::mem::transmute_copy(&index))
+            }
+        }
+        unsafe extern "system" fn PrimaryOutput<Identity: windows_core::IUnknownImpl + IDxcResult_Impl>(this: *mut core::ffi::c_void) -> DXC_OUT_KIND {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcResult_Impl::PrimaryOutput(this)
+            }
+        }
+        Self {
+            base__: <IDxcOperationResult_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            HasOutput: HasOutput::<Identity>,
+            GetOutput: GetOutput::<Identity>,
+            GetNumOutputs: GetNumOutputs::<Identity>,
+            GetOutputByIndex: GetOutputByIndex::<Identity>,
+            PrimaryOutput: PrimaryOutput::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDxcResult {}
 windows_core::imp::define_interface!(IDxcUtils, IDxcUtils_Vtbl, 0x4605c4cb_2019_492a_ada4_65f20bb7d67f);
 windows_core::imp::interface_hierarchy!(IDxcUtils, windows_core::IUnknown);
@@ -2445,6 +3513,168 @@ impl IDxcUtils_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IDxcUtils_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcUtils_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateBlobFromBlob<Identity: windows_core::IUnknownImpl + IDxcUtils_Impl>(this: *mut core::ffi::c_void, pblob: *mut core::ffi::c_void, offset: u32, length: u32, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcUtils_Impl::CreateBlobFromBlob(this, core::mem::transmute_copy(&pblob), core::mem::transmute_copy(&offset), core::mem::transmute_copy(&length)) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateBlobFromPinned<Identity: windows_core::IUnknownImpl + IDxcUtils_Impl>(this: *mut core::ffi::c_void, pdata: *const core::ffi::c_void, size: u32, codepage: DXC_CP, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcUtils_Impl::CreateBlobFromPinned(this, core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&size), core::mem::transmute_copy(&codepage)) {
+                    Ok(ok__) => {
+                        pblobencoding.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveToBlob<Identity: windows_core::IUnknownImpl + IDxcUtils_Impl>(this: *mut core::ffi::c_void, pdata: *const core::ffi::c_void, pimalloc: *mut core::ffi::c_void, size: u32, codepage: DXC_CP, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcUtils_Impl::MoveToBlob(this, core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&pimalloc), core::mem::transmute_copy(&size), core::mem::transmute_copy(&codepage)) {
+                    Ok(ok__) => {
+                        pblobencoding.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateBlob<Identity: windows_core::IUnknownImpl + IDxcUtils_Impl>(this: *mut core::ffi::c_void, pdata: *const core::ffi::c_void, size: u32, codepage: DXC_CP, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcUtils_Impl::CreateBlob(this, core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&size), core::mem::transmute_copy(&codepage)) {
+                    Ok(ok__) => {
+                        pblobencoding.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LoadFile<Identity: windows_core::IUnknownImpl + IDxcUtils_Impl>(this: *mut core::ffi::c_void, pfilename: windows_core::PCWSTR, pcodepage: *const DXC_CP, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcUtils_Impl::LoadFile(this, core::mem::transmute(&pfilename), core::mem::transmute_copy(&pcodepage)) {
+                    Ok(ok__) => {
+                        pblobencoding.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateReadOnlyStreamFromBlob<Identity: windows_core::IUnknownImpl + IDxcUtils_Impl>(this: *mut core::ffi::c_void, pblob: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcUtils_Impl::CreateReadOnlyStreamFromBlob(this, core::mem::transmute_copy(&pblob)) {
+                    Ok(ok__) => {
+                        ppstream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateDefaultIncludeHandler<Identity: windows_core::IUnknownImpl + IDxcUtils_Impl>(this: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcUtils_Impl::CreateDefaultIncludeHandler(this) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetBlobAsUtf8<Identity: windows_core::IUnknownImpl + IDxcUtils_Impl>(this: *mut core::ffi::c_void, pblob: *mut core::ffi::c_void, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcUtils_Impl::GetBlobAsUtf8(this, core::mem::transmute_copy(&pblob)) {
+                    Ok(ok__) => {
+                        pblobencoding.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetBlobAsUtf16<Identity: windows_core::IUnknownImpl + IDxcUtils_Impl>(this: *mut core::ffi::c_void, pblob: *mut core::ffi::c_void, pblobencoding: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcUtils_Impl::GetBlobAsUtf16(this, core::mem::transmute_copy(&pblob)) {
+                    Ok(ok__) => {
+                        pblobencoding.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDxilContainerPart<Identity: windows_core::IUnknownImpl + IDxcUtils_Impl>(this: *mut core::ffi::c_void, pshader: *const DxcBuffer, dxcpart: u32, pppartdata: *mut *mut core::ffi::c_void, ppartsizeinbytes: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcUtils_Impl::GetDxilContainerPart(this, core::mem::transmute_copy(&pshader), core::mem::transmute_copy(&dxcpart), core::mem::transmute_copy(&pppartdata), core::mem::transmute_copy(&ppartsizeinbytes)).into()
+            }
+        }
+        unsafe extern "system" fn CreateReflection<Identity: windows_core::IUnknownImpl + IDxcUtils_Impl>(this: *mut core::ffi::c_void, pdata: *const DxcBuffer, iid: *const windows_core::GUID, ppvreflection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcUtils_Impl::CreateReflection(this, core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&iid), core::mem::transmute_copy(&ppvreflection)).into()
+            }
+        }
+        unsafe extern "system" fn BuildArguments<Identity: windows_core::IUnknownImpl + IDxcUtils_Impl>(this: *mut core::ffi::c_void, psourcename: windows_core::PCWSTR, pentrypoint: windows_core::PCWSTR, ptargetprofile: windows_core::PCWSTR, parguments: *const windows_core::PCWSTR, argcount: u32, pdefines: *const DxcDefine, definecount: u32, ppargs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcUtils_Impl::BuildArguments(this, core::mem::transmute(&psourcename), core::mem::transmute(&pentrypoint), core::mem::transmute(&ptargetprofile), core::mem::transmute_copy(&parguments), core::mem::transmute_copy(&argcount), core::mem::transmute_copy(&pdefines), core::mem::transmute_copy(&definecount)) {
+                    Ok(ok__) => {
+                        ppargs.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPDBContents<Identity: windows_core::IUnknownImpl + IDxcUtils_Impl>(this: *mut core::ffi::c_void, ppdbblob: *mut core::ffi::c_void, pphash: *mut *mut core::ffi::c_void, ppcontainer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcUtils_Impl::GetPDBContents(this, core::mem::transmute_copy(&ppdbblob), core::mem::transmute_copy(&pphash), core::mem::transmute_copy(&ppcontainer)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateBlobFromBlob: CreateBlobFromBlob::<Identity>,
+            CreateBlobFromPinned: CreateBlobFromPinned::<Identity>,
+            MoveToBlob: MoveToBlob::<Identity>,
+            CreateBlob: CreateBlob::<Identity>,
+            LoadFile: LoadFile::<Identity>,
+            CreateReadOnlyStreamFromBlob: CreateReadOnlyStreamFromBlob::<Identity>,
+            CreateDefaultIncludeHandler: CreateDefaultIncludeHandler::<Identity>,
+            GetBlobAsUtf8: GetBlobAsUtf8::<Identity>,
+            GetBlobAsUtf16: GetBlobAsUtf16::<Identity>,
+            GetDxilContainerPart: GetDxilContainerPart::<Identity>,
+            CreateReflection: CreateReflection::<Identity>,
+            BuildArguments: BuildArguments::<Identity>,
+            GetPDBContents: GetPDBContents::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IDxcUtils {}
 windows_core::imp::define_interface!(IDxcValidator, IDxcValidator_Vtbl, 0xa6e82bd2_1fd7_4826_9811_2857e797f49a);
 windows_core::imp::interface_hierarchy!(IDxcValidator, windows_core::IUnknown);
@@ -2488,6 +3718,26 @@ impl IDxcValidator_Vtbl {
         iid == &<IDxcValidator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDxcValidator_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcValidator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Validate<Identity: windows_core::IUnknownImpl + IDxcValidator_Impl>(this: *mut core::ffi::c_void, pshader: *mut core::ffi::c_void, flags: u32, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcValidator_Impl::Validate(this, core::mem::transmute_copy(&pshader), core::mem::transmute_copy(&flags)) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Validate: Validate::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDxcValidator {}
 windows_core::imp::define_interface!(IDxcValidator2, IDxcValidator2_Vtbl, 0x458e1fd1_b1b2_4750_a6e1_9c10f03bed92);
 impl core::ops::Deref for IDxcValidator2 {
@@ -2537,6 +3787,26 @@ impl IDxcValidator2_Vtbl {
         iid == &<IDxcValidator2 as windows_core::Interface>::IID || iid == &<IDxcValidator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDxcValidator2_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcValidator2_Vtbl
+where
+    IDxcValidator_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ValidateWithDebug<Identity: windows_core::IUnknownImpl + IDxcValidator2_Impl>(this: *mut core::ffi::c_void, pshader: *mut core::ffi::c_void, flags: u32, poptdebugbitcode: *const DxcBuffer, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcValidator2_Impl::ValidateWithDebug(this, core::mem::transmute_copy(&pshader), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&poptdebugbitcode)) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IDxcValidator_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ValidateWithDebug: ValidateWithDebug::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDxcValidator2 {}
 windows_core::imp::define_interface!(IDxcVersionInfo, IDxcVersionInfo_Vtbl, 0xb04f5b50_2059_4f12_a8ff_a1e0cde1cc7e);
 windows_core::imp::interface_hierarchy!(IDxcVersionInfo, windows_core::IUnknown);
@@ -2592,6 +3862,36 @@ impl IDxcVersionInfo_Vtbl {
         iid == &<IDxcVersionInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDxcVersionInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcVersionInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetVersion<Identity: windows_core::IUnknownImpl + IDxcVersionInfo_Impl>(this: *mut core::ffi::c_void, pmajor: *mut u32, pminor: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcVersionInfo_Impl::GetVersion(this, core::mem::transmute_copy(&pmajor), core::mem::transmute_copy(&pminor)).into()
+            }
+        }
+        unsafe extern "system" fn GetFlags<Identity: windows_core::IUnknownImpl + IDxcVersionInfo_Impl>(this: *mut core::ffi::c_void, pflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcVersionInfo_Impl::GetFlags(this) {
+                    Ok(ok__) => {
+                        pflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetVersion: GetVersion::<Identity>,
+            GetFlags: GetFlags::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDxcVersionInfo {}
 windows_core::imp::define_interface!(IDxcVersionInfo2, IDxcVersionInfo2_Vtbl, 0xfb6904c4_42f0_4b62_9c46_983af7da7c83);
 impl core::ops::Deref for IDxcVersionInfo2 {
@@ -2629,6 +3929,20 @@ impl IDxcVersionInfo2_Vtbl {
         iid == &<IDxcVersionInfo2 as windows_core::Interface>::IID || iid == &<IDxcVersionInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDxcVersionInfo2_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcVersionInfo2_Vtbl
+where
+    IDxcVersionInfo_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCommitInfo<Identity: windows_core::IUnknownImpl + IDxcVersionInfo2_Impl>(this: *mut core::ffi::c_void, pcommitcount: *mut u32, pcommithash: *mut *mut i8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDxcVersionInfo2_Impl::GetCommitInfo(this, core::mem::transmute_copy(&pcommitcount), core::mem::transmute_copy(&pcommithash)).into()
+            }
+        }
+        Self { base__: <IDxcVersionInfo_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetCommitInfo: GetCommitInfo::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDxcVersionInfo2 {}
 windows_core::imp::define_interface!(IDxcVersionInfo3, IDxcVersionInfo3_Vtbl, 0x5e13e843_9d25_473c_9ad2_03b2d0b44b1e);
 windows_core::imp::interface_hierarchy!(IDxcVersionInfo3, windows_core::IUnknown);
@@ -2669,4 +3983,27 @@ impl IDxcVersionInfo3_Vtbl {
         iid == &<IDxcVersionInfo3 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDxcVersionInfo3_Impl> windows_core::imp::TearOffVtable<Identity> for IDxcVersionInfo3_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCustomVersionString<Identity: windows_core::IUnknownImpl + IDxcVersionInfo3_Impl>(this: *mut core::ffi::c_void, pversionstring: *mut *mut i8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDxcVersionInfo3_Impl::GetCustomVersionString(this) {
+                    Ok(ok__) => {
+                        pversionstring.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCustomVersionString: GetCustomVersionString::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDxcVersionInfo3 {}
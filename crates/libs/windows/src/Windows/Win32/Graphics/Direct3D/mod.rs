@@ -788,6 +788,30 @@ impl ID3DBlob_Vtbl {
         iid == &<ID3DBlob as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3DBlob_Impl> windows_core::imp::TearOffVtable<Identity> for ID3DBlob_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetBufferPointer<Identity: windows_core::IUnknownImpl + ID3DBlob_Impl>(this: *mut core::ffi::c_void) -> *mut core::ffi::c_void {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3DBlob_Impl::GetBufferPointer(this)
+            }
+        }
+        unsafe extern "system" fn GetBufferSize<Identity: windows_core::IUnknownImpl + ID3DBlob_Impl>(this: *mut core::ffi::c_void) -> usize {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3DBlob_Impl::GetBufferSize(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetBufferPointer: GetBufferPointer::<Identity>,
+            GetBufferSize: GetBufferSize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3DBlob {}
 windows_core::imp::define_interface!(ID3DDestructionNotifier, ID3DDestructionNotifier_Vtbl, 0xa06eb39a_50da_425b_8c31_4eecd6c270f3);
 windows_core::imp::interface_hierarchy!(ID3DDestructionNotifier, windows_core::IUnknown);
@@ -845,6 +869,36 @@ impl ID3DDestructionNotifier_Vtbl {
         iid == &<ID3DDestructionNotifier as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3DDestructionNotifier_Impl> windows_core::imp::TearOffVtable<Identity> for ID3DDestructionNotifier_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterDestructionCallback<Identity: windows_core::IUnknownImpl + ID3DDestructionNotifier_Impl>(this: *mut core::ffi::c_void, callbackfn: PFN_DESTRUCTION_CALLBACK, pdata: *const core::ffi::c_void, pcallbackid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3DDestructionNotifier_Impl::RegisterDestructionCallback(this, core::mem::transmute_copy(&callbackfn), core::mem::transmute_copy(&pdata)) {
+                    Ok(ok__) => {
+                        pcallbackid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UnregisterDestructionCallback<Identity: windows_core::IUnknownImpl + ID3DDestructionNotifier_Impl>(this: *mut core::ffi::c_void, callbackid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3DDestructionNotifier_Impl::UnregisterDestructionCallback(this, core::mem::transmute_copy(&callbackid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterDestructionCallback: RegisterDestructionCallback::<Identity>,
+            UnregisterDestructionCallback: UnregisterDestructionCallback::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3DDestructionNotifier {}
 windows_core::imp::define_interface!(ID3DInclude, ID3DInclude_Vtbl);
 impl ID3DInclude {
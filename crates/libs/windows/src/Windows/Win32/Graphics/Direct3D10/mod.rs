@@ -2500,6 +2500,44 @@ impl ID3D10Asynchronous_Vtbl {
         iid == &<ID3D10Asynchronous as windows_core::Interface>::IID || iid == &<ID3D10DeviceChild as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D10Asynchronous_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10Asynchronous_Vtbl
+where
+    ID3D10DeviceChild_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Begin<Identity: windows_core::IUnknownImpl + ID3D10Asynchronous_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Asynchronous_Impl::Begin(this)
+            }
+        }
+        unsafe extern "system" fn End<Identity: windows_core::IUnknownImpl + ID3D10Asynchronous_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Asynchronous_Impl::End(this)
+            }
+        }
+        unsafe extern "system" fn GetData<Identity: windows_core::IUnknownImpl + ID3D10Asynchronous_Impl>(this: *mut core::ffi::c_void, pdata: *mut core::ffi::c_void, datasize: u32, getdataflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Asynchronous_Impl::GetData(this, core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&datasize), core::mem::transmute_copy(&getdataflags)).into()
+            }
+        }
+        unsafe extern "system" fn GetDataSize<Identity: windows_core::IUnknownImpl + ID3D10Asynchronous_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Asynchronous_Impl::GetDataSize(this)
+            }
+        }
+        Self {
+            base__: <ID3D10DeviceChild_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Begin: Begin::<Identity>,
+            End: End::<Identity>,
+            GetData: GetData::<Identity>,
+            GetDataSize: GetDataSize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D10Asynchronous {}
 windows_core::imp::define_interface!(ID3D10BlendState, ID3D10BlendState_Vtbl, 0xedad8d19_8a35_4d6d_8566_2ea276cde161);
 impl core::ops::Deref for ID3D10BlendState {
@@ -2539,6 +2577,20 @@ impl ID3D10BlendState_Vtbl {
         iid == &<ID3D10BlendState as windows_core::Interface>::IID || iid == &<ID3D10DeviceChild as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D10BlendState_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10BlendState_Vtbl
+where
+    ID3D10DeviceChild_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D10BlendState_Impl>(this: *mut core::ffi::c_void, pdesc: *mut D3D10_BLEND_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10BlendState_Impl::GetDesc(this, core::mem::transmute_copy(&pdesc))
+            }
+        }
+        Self { base__: <ID3D10DeviceChild_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDesc: GetDesc::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ID3D10BlendState {}
 windows_core::imp::define_interface!(ID3D10BlendState1, ID3D10BlendState1_Vtbl, 0xedad8d99_8a35_4d6d_8566_2ea276cde161);
 impl core::ops::Deref for ID3D10BlendState1 {
@@ -2578,6 +2630,20 @@ impl ID3D10BlendState1_Vtbl {
         iid == &<ID3D10BlendState1 as windows_core::Interface>::IID || iid == &<ID3D10DeviceChild as windows_core::Interface>::IID || iid == &<ID3D10BlendState as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D10BlendState1_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10BlendState1_Vtbl
+where
+    ID3D10BlendState_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc1<Identity: windows_core::IUnknownImpl + ID3D10BlendState1_Impl>(this: *mut core::ffi::c_void, pdesc: *mut D3D10_BLEND_DESC1) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10BlendState1_Impl::GetDesc1(this, core::mem::transmute_copy(&pdesc))
+            }
+        }
+        Self { base__: <ID3D10BlendState_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDesc1: GetDesc1::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ID3D10BlendState1 {}
 windows_core::imp::define_interface!(ID3D10Buffer, ID3D10Buffer_Vtbl, 0x9b7e4c02_342c_4106_a19f_4f2704f689f0);
 impl core::ops::Deref for ID3D10Buffer {
@@ -2644,6 +2710,37 @@ impl ID3D10Buffer_Vtbl {
         iid == &<ID3D10Buffer as windows_core::Interface>::IID || iid == &<ID3D10DeviceChild as windows_core::Interface>::IID || iid == &<ID3D10Resource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D10Buffer_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10Buffer_Vtbl
+where
+    ID3D10Resource_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Map<Identity: windows_core::IUnknownImpl + ID3D10Buffer_Impl>(this: *mut core::ffi::c_void, maptype: D3D10_MAP, mapflags: u32, ppdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Buffer_Impl::Map(this, core::mem::transmute_copy(&maptype), core::mem::transmute_copy(&mapflags), core::mem::transmute_copy(&ppdata)).into()
+            }
+        }
+        unsafe extern "system" fn Unmap<Identity: windows_core::IUnknownImpl + ID3D10Buffer_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Buffer_Impl::Unmap(this)
+            }
+        }
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D10Buffer_Impl>(this: *mut core::ffi::c_void, pdesc: *mut D3D10_BUFFER_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Buffer_Impl::GetDesc(this, core::mem::transmute_copy(&pdesc))
+            }
+        }
+        Self {
+            base__: <ID3D10Resource_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Map: Map::<Identity>,
+            Unmap: Unmap::<Identity>,
+            GetDesc: GetDesc::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D10Buffer {}
 windows_core::imp::define_interface!(ID3D10Counter, ID3D10Counter_Vtbl, 0x9b7e4c11_342c_4106_a19f_4f2704f689f0);
 impl core::ops::Deref for ID3D10Counter {
@@ -2687,6 +2784,20 @@ impl ID3D10Counter_Vtbl {
         iid == &<ID3D10Counter as windows_core::Interface>::IID || iid == &<ID3D10DeviceChild as windows_core::Interface>::IID || iid == &<ID3D10Asynchronous as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D10Counter_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10Counter_Vtbl
+where
+    ID3D10Asynchronous_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D10Counter_Impl>(this: *mut core::ffi::c_void, pdesc: *mut D3D10_COUNTER_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Counter_Impl::GetDesc(this, core::mem::transmute_copy(&pdesc))
+            }
+        }
+        Self { base__: <ID3D10Asynchronous_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDesc: GetDesc::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ID3D10Counter {}
 windows_core::imp::define_interface!(ID3D10Debug, ID3D10Debug_Vtbl, 0x9b7e4e01_342c_4106_a19f_4f2704f689f0);
 windows_core::imp::interface_hierarchy!(ID3D10Debug, windows_core::IUnknown);
@@ -2818,6 +2929,72 @@ impl ID3D10Debug_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi")]
+impl<Identity: windows_core::IUnknownImpl + ID3D10Debug_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10Debug_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetFeatureMask<Identity: windows_core::IUnknownImpl + ID3D10Debug_Impl>(this: *mut core::ffi::c_void, mask: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Debug_Impl::SetFeatureMask(this, core::mem::transmute_copy(&mask)).into()
+            }
+        }
+        unsafe extern "system" fn GetFeatureMask<Identity: windows_core::IUnknownImpl + ID3D10Debug_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Debug_Impl::GetFeatureMask(this)
+            }
+        }
+        unsafe extern "system" fn SetPresentPerRenderOpDelay<Identity: windows_core::IUnknownImpl + ID3D10Debug_Impl>(this: *mut core::ffi::c_void, milliseconds: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Debug_Impl::SetPresentPerRenderOpDelay(this, core::mem::transmute_copy(&milliseconds)).into()
+            }
+        }
+        unsafe extern "system" fn GetPresentPerRenderOpDelay<Identity: windows_core::IUnknownImpl + ID3D10Debug_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Debug_Impl::GetPresentPerRenderOpDelay(this)
+            }
+        }
+        unsafe extern "system" fn SetSwapChain<Identity: windows_core::IUnknownImpl + ID3D10Debug_Impl>(this: *mut core::ffi::c_void, pswapchain: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Debug_Impl::SetSwapChain(this, core::mem::transmute_copy(&pswapchain)).into()
+            }
+        }
+        unsafe extern "system" fn GetSwapChain<Identity: windows_core::IUnknownImpl + ID3D10Debug_Impl>(this: *mut core::ffi::c_void, ppswapchain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D10Debug_Impl::GetSwapChain(this) {
+                    Ok(ok__) => {
+                        ppswapchain.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Validate<Identity: windows_core::IUnknownImpl + ID3D10Debug_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Debug_Impl::Validate(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetFeatureMask: SetFeatureMask::<Identity>,
+            GetFeatureMask: GetFeatureMask::<Identity>,
+            SetPresentPerRenderOpDelay: SetPresentPerRenderOpDelay::<Identity>,
+            GetPresentPerRenderOpDelay: GetPresentPerRenderOpDelay::<Identity>,
+            SetSwapChain: SetSwapChain::<Identity>,
+            GetSwapChain: GetSwapChain::<Identity>,
+            Validate: Validate::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi")]
 impl windows_core::RuntimeName for ID3D10Debug {}
 windows_core::imp::define_interface!(ID3D10DepthStencilState, ID3D10DepthStencilState_Vtbl, 0x2b4b1cc8_a4ad_41f8_8322_ca86fc3ec675);
 impl core::ops::Deref for ID3D10DepthStencilState {
@@ -2857,6 +3034,20 @@ impl ID3D10DepthStencilState_Vtbl {
         iid == &<ID3D10DepthStencilState as windows_core::Interface>::IID || iid == &<ID3D10DeviceChild as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D10DepthStencilState_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10DepthStencilState_Vtbl
+where
+    ID3D10DeviceChild_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D10DepthStencilState_Impl>(this: *mut core::ffi::c_void, pdesc: *mut D3D10_DEPTH_STENCIL_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10DepthStencilState_Impl::GetDesc(this, core::mem::transmute_copy(&pdesc))
+            }
+        }
+        Self { base__: <ID3D10DeviceChild_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDesc: GetDesc::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ID3D10DepthStencilState {}
 windows_core::imp::define_interface!(ID3D10DepthStencilView, ID3D10DepthStencilView_Vtbl, 0x9b7e4c09_342c_4106_a19f_4f2704f689f0);
 impl core::ops::Deref for ID3D10DepthStencilView {
@@ -2903,6 +3094,21 @@ impl ID3D10DepthStencilView_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + ID3D10DepthStencilView_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10DepthStencilView_Vtbl
+where
+    ID3D10View_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D10DepthStencilView_Impl>(this: *mut core::ffi::c_void, pdesc: *mut D3D10_DEPTH_STENCIL_VIEW_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10DepthStencilView_Impl::GetDesc(this, core::mem::transmute_copy(&pdesc))
+            }
+        }
+        Self { base__: <ID3D10View_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDesc: GetDesc::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for ID3D10DepthStencilView {}
 windows_core::imp::define_interface!(ID3D10Device, ID3D10Device_Vtbl, 0x9b7e4c0f_342c_4106_a19f_4f2704f689f0);
 windows_core::imp::interface_hierarchy!(ID3D10Device, windows_core::IUnknown);
@@ -4265,161 +4471,937 @@ impl ID3D10Device_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
-impl windows_core::RuntimeName for ID3D10Device {}
-windows_core::imp::define_interface!(ID3D10Device1, ID3D10Device1_Vtbl, 0x9b7e4c8f_342c_4106_a19f_4f2704f689f0);
-impl core::ops::Deref for ID3D10Device1 {
-    type Target = ID3D10Device;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(ID3D10Device1, windows_core::IUnknown, ID3D10Device);
-impl ID3D10Device1 {
-    #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
-    pub unsafe fn CreateShaderResourceView1<P0>(&self, presource: P0, pdesc: Option<*const D3D10_SHADER_RESOURCE_VIEW_DESC1>, ppsrview: Option<*mut Option<ID3D10ShaderResourceView1>>) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<ID3D10Resource>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).CreateShaderResourceView1)(windows_core::Interface::as_raw(self), presource.param().abi(), pdesc.unwrap_or(core::mem::zeroed()) as _, ppsrview.unwrap_or(core::mem::zeroed()) as _).ok() }
-    }
-    pub unsafe fn CreateBlendState1(&self, pblendstatedesc: *const D3D10_BLEND_DESC1, ppblendstate: Option<*mut Option<ID3D10BlendState1>>) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).CreateBlendState1)(windows_core::Interface::as_raw(self), pblendstatedesc, ppblendstate.unwrap_or(core::mem::zeroed()) as _).ok() }
-    }
-    pub unsafe fn GetFeatureLevel(&self) -> D3D10_FEATURE_LEVEL1 {
-        unsafe { (windows_core::Interface::vtable(self).GetFeatureLevel)(windows_core::Interface::as_raw(self)) }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct ID3D10Device1_Vtbl {
-    pub base__: ID3D10Device_Vtbl,
-    #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
-    pub CreateShaderResourceView1: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *const D3D10_SHADER_RESOURCE_VIEW_DESC1, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    #[cfg(not(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common")))]
-    CreateShaderResourceView1: usize,
-    pub CreateBlendState1: unsafe extern "system" fn(*mut core::ffi::c_void, *const D3D10_BLEND_DESC1, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetFeatureLevel: unsafe extern "system" fn(*mut core::ffi::c_void) -> D3D10_FEATURE_LEVEL1,
-}
-unsafe impl Send for ID3D10Device1 {}
-unsafe impl Sync for ID3D10Device1 {}
-#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
-pub trait ID3D10Device1_Impl: ID3D10Device_Impl {
-    fn CreateShaderResourceView1(&self, presource: windows_core::Ref<ID3D10Resource>, pdesc: *const D3D10_SHADER_RESOURCE_VIEW_DESC1, ppsrview: windows_core::OutRef<ID3D10ShaderResourceView1>) -> windows_core::Result<()>;
-    fn CreateBlendState1(&self, pblendstatedesc: *const D3D10_BLEND_DESC1, ppblendstate: windows_core::OutRef<ID3D10BlendState1>) -> windows_core::Result<()>;
-    fn GetFeatureLevel(&self) -> D3D10_FEATURE_LEVEL1;
-}
-#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
-impl ID3D10Device1_Vtbl {
-    pub const fn new<Identity: ID3D10Device1_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn CreateShaderResourceView1<Identity: ID3D10Device1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc: *const D3D10_SHADER_RESOURCE_VIEW_DESC1, ppsrview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10Device_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn VSSetConstantBuffers<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *const *mut core::ffi::c_void) {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ID3D10Device1_Impl::CreateShaderResourceView1(this, core::mem::transmute_copy(&presource), core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&ppsrview)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::VSSetConstantBuffers(this, core::mem::transmute_copy(&startslot), core::mem::transmute_copy(&numbuffers), core::mem::transmute_copy(&ppconstantbuffers))
             }
         }
-        unsafe extern "system" fn CreateBlendState1<Identity: ID3D10Device1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pblendstatedesc: *const D3D10_BLEND_DESC1, ppblendstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn PSSetShaderResources<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *const *mut core::ffi::c_void) {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ID3D10Device1_Impl::CreateBlendState1(this, core::mem::transmute_copy(&pblendstatedesc), core::mem::transmute_copy(&ppblendstate)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::PSSetShaderResources(this, core::mem::transmute_copy(&startslot), core::mem::transmute_copy(&numviews), core::mem::transmute_copy(&ppshaderresourceviews))
             }
         }
-        unsafe extern "system" fn GetFeatureLevel<Identity: ID3D10Device1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> D3D10_FEATURE_LEVEL1 {
+        unsafe extern "system" fn PSSetShader<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, ppixelshader: *mut core::ffi::c_void) {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ID3D10Device1_Impl::GetFeatureLevel(this)
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::PSSetShader(this, core::mem::transmute_copy(&ppixelshader))
             }
         }
-        Self {
-            base__: ID3D10Device_Vtbl::new::<Identity, OFFSET>(),
-            CreateShaderResourceView1: CreateShaderResourceView1::<Identity, OFFSET>,
-            CreateBlendState1: CreateBlendState1::<Identity, OFFSET>,
-            GetFeatureLevel: GetFeatureLevel::<Identity, OFFSET>,
+        unsafe extern "system" fn PSSetSamplers<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *const *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::PSSetSamplers(this, core::mem::transmute_copy(&startslot), core::mem::transmute_copy(&numsamplers), core::mem::transmute_copy(&ppsamplers))
+            }
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ID3D10Device1 as windows_core::Interface>::IID || iid == &<ID3D10Device as windows_core::Interface>::IID
-    }
-}
-#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
-impl windows_core::RuntimeName for ID3D10Device1 {}
-windows_core::imp::define_interface!(ID3D10DeviceChild, ID3D10DeviceChild_Vtbl, 0x9b7e4c00_342c_4106_a19f_4f2704f689f0);
-windows_core::imp::interface_hierarchy!(ID3D10DeviceChild, windows_core::IUnknown);
-impl ID3D10DeviceChild {
-    pub unsafe fn GetDevice(&self) -> windows_core::Result<ID3D10Device> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetDevice)(windows_core::Interface::as_raw(self), &mut result__);
-            windows_core::Type::from_abi(result__)
+        unsafe extern "system" fn VSSetShader<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pvertexshader: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::VSSetShader(this, core::mem::transmute_copy(&pvertexshader))
+            }
         }
-    }
-    pub unsafe fn GetPrivateData(&self, guid: *const windows_core::GUID, pdatasize: *mut u32, pdata: Option<*mut core::ffi::c_void>) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetPrivateData)(windows_core::Interface::as_raw(self), guid, pdatasize as _, pdata.unwrap_or(core::mem::zeroed()) as _).ok() }
-    }
-    pub unsafe fn SetPrivateData(&self, guid: *const windows_core::GUID, datasize: u32, pdata: Option<*const core::ffi::c_void>) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetPrivateData)(windows_core::Interface::as_raw(self), guid, datasize, pdata.unwrap_or(core::mem::zeroed()) as _).ok() }
-    }
-    pub unsafe fn SetPrivateDataInterface<P1>(&self, guid: *const windows_core::GUID, pdata: P1) -> windows_core::Result<()>
-    where
-        P1: windows_core::Param<windows_core::IUnknown>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).SetPrivateDataInterface)(windows_core::Interface::as_raw(self), guid, pdata.param().abi()).ok() }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct ID3D10DeviceChild_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    pub GetDevice: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void),
-    pub GetPrivateData: unsafe extern "system" fn(*mut core::ffi::c_void, *const windows_core::GUID, *mut u32, *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub SetPrivateData: unsafe extern "system" fn(*mut core::ffi::c_void, *const windows_core::GUID, u32, *const core::ffi::c_void) -> windows_core::HRESULT,
-    pub SetPrivateDataInterface: unsafe extern "system" fn(*mut core::ffi::c_void, *const windows_core::GUID, *mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-unsafe impl Send for ID3D10DeviceChild {}
-unsafe impl Sync for ID3D10DeviceChild {}
-pub trait ID3D10DeviceChild_Impl: windows_core::IUnknownImpl {
-    fn GetDevice(&self, ppdevice: windows_core::OutRef<ID3D10Device>);
-    fn GetPrivateData(&self, guid: *const windows_core::GUID, pdatasize: *mut u32, pdata: *mut core::ffi::c_void) -> windows_core::Result<()>;
-    fn SetPrivateData(&self, guid: *const windows_core::GUID, datasize: u32, pdata: *const core::ffi::c_void) -> windows_core::Result<()>;
-    fn SetPrivateDataInterface(&self, guid: *const windows_core::GUID, pdata: windows_core::Ref<windows_core::IUnknown>) -> windows_core::Result<()>;
-}
-impl ID3D10DeviceChild_Vtbl {
-    pub const fn new<Identity: ID3D10DeviceChild_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn GetDevice<Identity: ID3D10DeviceChild_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppdevice: *mut *mut core::ffi::c_void) {
+        unsafe extern "system" fn DrawIndexed<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, indexcount: u32, startindexlocation: u32, basevertexlocation: i32) {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ID3D10DeviceChild_Impl::GetDevice(this, core::mem::transmute_copy(&ppdevice))
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::DrawIndexed(this, core::mem::transmute_copy(&indexcount), core::mem::transmute_copy(&startindexlocation), core::mem::transmute_copy(&basevertexlocation))
             }
         }
-        unsafe extern "system" fn GetPrivateData<Identity: ID3D10DeviceChild_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pdatasize: *mut u32, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Draw<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, vertexcount: u32, startvertexlocation: u32) {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ID3D10DeviceChild_Impl::GetPrivateData(this, core::mem::transmute_copy(&guid), core::mem::transmute_copy(&pdatasize), core::mem::transmute_copy(&pdata)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::Draw(this, core::mem::transmute_copy(&vertexcount), core::mem::transmute_copy(&startvertexlocation))
             }
         }
-        unsafe extern "system" fn SetPrivateData<Identity: ID3D10DeviceChild_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, datasize: u32, pdata: *const core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn PSSetConstantBuffers<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *const *mut core::ffi::c_void) {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ID3D10DeviceChild_Impl::SetPrivateData(this, core::mem::transmute_copy(&guid), core::mem::transmute_copy(&datasize), core::mem::transmute_copy(&pdata)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::PSSetConstantBuffers(this, core::mem::transmute_copy(&startslot), core::mem::transmute_copy(&numbuffers), core::mem::transmute_copy(&ppconstantbuffers))
             }
         }
-        unsafe extern "system" fn SetPrivateDataInterface<Identity: ID3D10DeviceChild_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn IASetInputLayout<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pinputlayout: *mut core::ffi::c_void) {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ID3D10DeviceChild_Impl::SetPrivateDataInterface(this, core::mem::transmute_copy(&guid), core::mem::transmute_copy(&pdata)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::IASetInputLayout(this, core::mem::transmute_copy(&pinputlayout))
             }
         }
-        Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            GetDevice: GetDevice::<Identity, OFFSET>,
-            GetPrivateData: GetPrivateData::<Identity, OFFSET>,
-            SetPrivateData: SetPrivateData::<Identity, OFFSET>,
-            SetPrivateDataInterface: SetPrivateDataInterface::<Identity, OFFSET>,
+        unsafe extern "system" fn IASetVertexBuffers<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppvertexbuffers: *const *mut core::ffi::c_void, pstrides: *const u32, poffsets: *const u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::IASetVertexBuffers(this, core::mem::transmute_copy(&startslot), core::mem::transmute_copy(&numbuffers), core::mem::transmute_copy(&ppvertexbuffers), core::mem::transmute_copy(&pstrides), core::mem::transmute_copy(&poffsets))
+            }
+        }
+        unsafe extern "system" fn IASetIndexBuffer<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pindexbuffer: *mut core::ffi::c_void, format: super::Dxgi::Common::DXGI_FORMAT, offset: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::IASetIndexBuffer(this, core::mem::transmute_copy(&pindexbuffer), core::mem::transmute_copy(&format), core::mem::transmute_copy(&offset))
+            }
+        }
+        unsafe extern "system" fn DrawIndexedInstanced<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, indexcountperinstance: u32, instancecount: u32, startindexlocation: u32, basevertexlocation: i32, startinstancelocation: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::DrawIndexedInstanced(this, core::mem::transmute_copy(&indexcountperinstance), core::mem::transmute_copy(&instancecount), core::mem::transmute_copy(&startindexlocation), core::mem::transmute_copy(&basevertexlocation), core::mem::transmute_copy(&startinstancelocation))
+            }
+        }
+        unsafe extern "system" fn DrawInstanced<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, vertexcountperinstance: u32, instancecount: u32, startvertexlocation: u32, startinstancelocation: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::DrawInstanced(this, core::mem::transmute_copy(&vertexcountperinstance), core::mem::transmute_copy(&instancecount), core::mem::transmute_copy(&startvertexlocation), core::mem::transmute_copy(&startinstancelocation))
+            }
+        }
+        unsafe extern "system" fn GSSetConstantBuffers<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *const *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::GSSetConstantBuffers(this, core::mem::transmute_copy(&startslot), core::mem::transmute_copy(&numbuffers), core::mem::transmute_copy(&ppconstantbuffers))
+            }
+        }
+        unsafe extern "system" fn GSSetShader<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pshader: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::GSSetShader(this, core::mem::transmute_copy(&pshader))
+            }
+        }
+        unsafe extern "system" fn IASetPrimitiveTopology<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, topology: super::Direct3D::D3D_PRIMITIVE_TOPOLOGY) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::IASetPrimitiveTopology(this, core::mem::transmute_copy(&topology))
+            }
+        }
+        unsafe extern "system" fn VSSetShaderResources<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *const *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::VSSetShaderResources(this, core::mem::transmute_copy(&startslot), core::mem::transmute_copy(&numviews), core::mem::transmute_copy(&ppshaderresourceviews))
+            }
+        }
+        unsafe extern "system" fn VSSetSamplers<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *const *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::VSSetSamplers(this, core::mem::transmute_copy(&startslot), core::mem::transmute_copy(&numsamplers), core::mem::transmute_copy(&ppsamplers))
+            }
+        }
+        unsafe extern "system" fn SetPredication<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, ppredicate: *mut core::ffi::c_void, predicatevalue: windows_core::BOOL) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::SetPredication(this, core::mem::transmute_copy(&ppredicate), core::mem::transmute_copy(&predicatevalue))
+            }
+        }
+        unsafe extern "system" fn GSSetShaderResources<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *const *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::GSSetShaderResources(this, core::mem::transmute_copy(&startslot), core::mem::transmute_copy(&numviews), core::mem::transmute_copy(&ppshaderresourceviews))
+            }
+        }
+        unsafe extern "system" fn GSSetSamplers<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *const *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::GSSetSamplers(this, core::mem::transmute_copy(&startslot), core::mem::transmute_copy(&numsamplers), core::mem::transmute_copy(&ppsamplers))
+            }
+        }
+        unsafe extern "system" fn OMSetRenderTargets<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, numviews: u32, pprendertargetviews: *const *mut core::ffi::c_void, pdepthstencilview: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::OMSetRenderTargets(this, core::mem::transmute_copy(&numviews), core::mem::transmute_copy(&pprendertargetviews), core::mem::transmute_copy(&pdepthstencilview))
+            }
+        }
+        unsafe extern "system" fn OMSetBlendState<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pblendstate: *mut core::ffi::c_void, blendfactor: *const f32, samplemask: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::OMSetBlendState(this, core::mem::transmute_copy(&pblendstate), core::mem::transmute_copy(&blendfactor), core::mem::transmute_copy(&samplemask))
+            }
+        }
+        unsafe extern "system" fn OMSetDepthStencilState<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pdepthstencilstate: *mut core::ffi::c_void, stencilref: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::OMSetDepthStencilState(this, core::mem::transmute_copy(&pdepthstencilstate), core::mem::transmute_copy(&stencilref))
+            }
+        }
+        unsafe extern "system" fn SOSetTargets<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, numbuffers: u32, ppsotargets: *const *mut core::ffi::c_void, poffsets: *const u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::SOSetTargets(this, core::mem::transmute_copy(&numbuffers), core::mem::transmute_copy(&ppsotargets), core::mem::transmute_copy(&poffsets))
+            }
+        }
+        unsafe extern "system" fn DrawAuto<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::DrawAuto(this)
+            }
+        }
+        unsafe extern "system" fn RSSetState<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, prasterizerstate: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::RSSetState(this, core::mem::transmute_copy(&prasterizerstate))
+            }
+        }
+        unsafe extern "system" fn RSSetViewports<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, numviewports: u32, pviewports: *const D3D10_VIEWPORT) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::RSSetViewports(this, core::mem::transmute_copy(&numviewports), core::mem::transmute_copy(&pviewports))
+            }
+        }
+        unsafe extern "system" fn RSSetScissorRects<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, numrects: u32, prects: *const super::super::Foundation::RECT) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::RSSetScissorRects(this, core::mem::transmute_copy(&numrects), core::mem::transmute_copy(&prects))
+            }
+        }
+        unsafe extern "system" fn CopySubresourceRegion<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pdstresource: *mut core::ffi::c_void, dstsubresource: u32, dstx: u32, dsty: u32, dstz: u32, psrcresource: *mut core::ffi::c_void, srcsubresource: u32, psrcbox: *const D3D10_BOX) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::CopySubresourceRegion(this, core::mem::transmute_copy(&pdstresource), core::mem::transmute_copy(&dstsubresource), core::mem::transmute_copy(&dstx), core::mem::transmute_copy(&dsty), core::mem::transmute_copy(&dstz), core::mem::transmute_copy(&psrcresource), core::mem::transmute_copy(&srcsubresource), core::mem::transmute_copy(&psrcbox))
+            }
+        }
+        unsafe extern "system" fn CopyResource<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pdstresource: *mut core::ffi::c_void, psrcresource: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::CopyResource(this, core::mem::transmute_copy(&pdstresource), core::mem::transmute_copy(&psrcresource))
+            }
+        }
+        unsafe extern "system" fn UpdateSubresource<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pdstresource: *mut core::ffi::c_void, dstsubresource: u32, pdstbox: *const D3D10_BOX, psrcdata: *const core::ffi::c_void, srcrowpitch: u32, srcdepthpitch: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::UpdateSubresource(this, core::mem::transmute_copy(&pdstresource), core::mem::transmute_copy(&dstsubresource), core::mem::transmute_copy(&pdstbox), core::mem::transmute_copy(&psrcdata), core::mem::transmute_copy(&srcrowpitch), core::mem::transmute_copy(&srcdepthpitch))
+            }
+        }
+        unsafe extern "system" fn ClearRenderTargetView<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, prendertargetview: *mut core::ffi::c_void, colorrgba: *const f32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::ClearRenderTargetView(this, core::mem::transmute_copy(&prendertargetview), core::mem::transmute_copy(&colorrgba))
+            }
+        }
+        unsafe extern "system" fn ClearDepthStencilView<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pdepthstencilview: *mut core::ffi::c_void, clearflags: u32, depth: f32, stencil: u8) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::ClearDepthStencilView(this, core::mem::transmute_copy(&pdepthstencilview), core::mem::transmute_copy(&clearflags), core::mem::transmute_copy(&depth), core::mem::transmute_copy(&stencil))
+            }
+        }
+        unsafe extern "system" fn GenerateMips<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pshaderresourceview: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::GenerateMips(this, core::mem::transmute_copy(&pshaderresourceview))
+            }
+        }
+        unsafe extern "system" fn ResolveSubresource<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pdstresource: *mut core::ffi::c_void, dstsubresource: u32, psrcresource: *mut core::ffi::c_void, srcsubresource: u32, format: super::Dxgi::Common::DXGI_FORMAT) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::ResolveSubresource(this, core::mem::transmute_copy(&pdstresource), core::mem::transmute_copy(&dstsubresource), core::mem::transmute_copy(&psrcresource), core::mem::transmute_copy(&srcsubresource), core::mem::transmute_copy(&format))
+            }
+        }
+        unsafe extern "system" fn VSGetConstantBuffers<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *mut *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::VSGetConstantBuffers(this, core::mem::transmute_copy(&startslot), core::mem::transmute_copy(&numbuffers), core::mem::transmute_copy(&ppconstantbuffers))
+            }
+        }
+        unsafe extern "system" fn PSGetShaderResources<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *mut *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::PSGetShaderResources(this, core::mem::transmute_copy(&startslot), core::mem::transmute_copy(&numviews), core::mem::transmute_copy(&ppshaderresourceviews))
+            }
+        }
+        unsafe extern "system" fn PSGetShader<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pppixelshader: *mut *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::PSGetShader(this, core::mem::transmute_copy(&pppixelshader))
+            }
+        }
+        unsafe extern "system" fn PSGetSamplers<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *mut *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::PSGetSamplers(this, core::mem::transmute_copy(&startslot), core::mem::transmute_copy(&numsamplers), core::mem::transmute_copy(&ppsamplers))
+            }
+        }
+        unsafe extern "system" fn VSGetShader<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, ppvertexshader: *mut *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::VSGetShader(this, core::mem::transmute_copy(&ppvertexshader))
+            }
+        }
+        unsafe extern "system" fn PSGetConstantBuffers<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *mut *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::PSGetConstantBuffers(this, core::mem::transmute_copy(&startslot), core::mem::transmute_copy(&numbuffers), core::mem::transmute_copy(&ppconstantbuffers))
+            }
+        }
+        unsafe extern "system" fn IAGetInputLayout<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, ppinputlayout: *mut *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::IAGetInputLayout(this, core::mem::transmute_copy(&ppinputlayout))
+            }
+        }
+        unsafe extern "system" fn IAGetVertexBuffers<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppvertexbuffers: *mut *mut core::ffi::c_void, pstrides: *mut u32, poffsets: *mut u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::IAGetVertexBuffers(this, core::mem::transmute_copy(&startslot), core::mem::transmute_copy(&numbuffers), core::mem::transmute_copy(&ppvertexbuffers), core::mem::transmute_copy(&pstrides), core::mem::transmute_copy(&poffsets))
+            }
+        }
+        unsafe extern "system" fn IAGetIndexBuffer<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pindexbuffer: *mut *mut core::ffi::c_void, format: *mut super::Dxgi::Common::DXGI_FORMAT, offset: *mut u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::IAGetIndexBuffer(this, core::mem::transmute_copy(&pindexbuffer), core::mem::transmute_copy(&format), core::mem::transmute_copy(&offset))
+            }
+        }
+        unsafe extern "system" fn GSGetConstantBuffers<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, startslot: u32, numbuffers: u32, ppconstantbuffers: *mut *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::GSGetConstantBuffers(this, core::mem::transmute_copy(&startslot), core::mem::transmute_copy(&numbuffers), core::mem::transmute_copy(&ppconstantbuffers))
+            }
+        }
+        unsafe extern "system" fn GSGetShader<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, ppgeometryshader: *mut *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::GSGetShader(this, core::mem::transmute_copy(&ppgeometryshader))
+            }
+        }
+        unsafe extern "system" fn IAGetPrimitiveTopology<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, ptopology: *mut super::Direct3D::D3D_PRIMITIVE_TOPOLOGY) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::IAGetPrimitiveTopology(this, core::mem::transmute_copy(&ptopology))
+            }
+        }
+        unsafe extern "system" fn VSGetShaderResources<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *mut *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::VSGetShaderResources(this, core::mem::transmute_copy(&startslot), core::mem::transmute_copy(&numviews), core::mem::transmute_copy(&ppshaderresourceviews))
+            }
+        }
+        unsafe extern "system" fn VSGetSamplers<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *mut *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::VSGetSamplers(this, core::mem::transmute_copy(&startslot), core::mem::transmute_copy(&numsamplers), core::mem::transmute_copy(&ppsamplers))
+            }
+        }
+        unsafe extern "system" fn GetPredication<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pppredicate: *mut *mut core::ffi::c_void, ppredicatevalue: *mut windows_core::BOOL) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::GetPredication(this, core::mem::transmute_copy(&pppredicate), core::mem::transmute_copy(&ppredicatevalue))
+            }
+        }
+        unsafe extern "system" fn GSGetShaderResources<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, ppshaderresourceviews: *mut *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::GSGetShaderResources(this, core::mem::transmute_copy(&startslot), core::mem::transmute_copy(&numviews), core::mem::transmute_copy(&ppshaderresourceviews))
+            }
+        }
+        unsafe extern "system" fn GSGetSamplers<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, startslot: u32, numsamplers: u32, ppsamplers: *mut *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::GSGetSamplers(this, core::mem::transmute_copy(&startslot), core::mem::transmute_copy(&numsamplers), core::mem::transmute_copy(&ppsamplers))
+            }
+        }
+        unsafe extern "system" fn OMGetRenderTargets<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, numviews: u32, pprendertargetviews: *mut *mut core::ffi::c_void, ppdepthstencilview: *mut *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::OMGetRenderTargets(this, core::mem::transmute_copy(&numviews), core::mem::transmute_copy(&pprendertargetviews), core::mem::transmute_copy(&ppdepthstencilview))
+            }
+        }
+        unsafe extern "system" fn OMGetBlendState<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, ppblendstate: *mut *mut core::ffi::c_void, blendfactor: *mut f32, psamplemask: *mut u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::OMGetBlendState(this, core::mem::transmute_copy(&ppblendstate), core::mem::transmute_copy(&blendfactor), core::mem::transmute_copy(&psamplemask))
+            }
+        }
+        unsafe extern "system" fn OMGetDepthStencilState<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, ppdepthstencilstate: *mut *mut core::ffi::c_void, pstencilref: *mut u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::OMGetDepthStencilState(this, core::mem::transmute_copy(&ppdepthstencilstate), core::mem::transmute_copy(&pstencilref))
+            }
+        }
+        unsafe extern "system" fn SOGetTargets<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, numbuffers: u32, ppsotargets: *mut *mut core::ffi::c_void, poffsets: *mut u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::SOGetTargets(this, core::mem::transmute_copy(&numbuffers), core::mem::transmute_copy(&ppsotargets), core::mem::transmute_copy(&poffsets))
+            }
+        }
+        unsafe extern "system" fn RSGetState<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pprasterizerstate: *mut *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::RSGetState(this, core::mem::transmute_copy(&pprasterizerstate))
+            }
+        }
+        unsafe extern "system" fn RSGetViewports<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, numviewports: *mut u32, pviewports: *mut D3D10_VIEWPORT) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::RSGetViewports(this, core::mem::transmute_copy(&numviewports), core::mem::transmute_copy(&pviewports))
+            }
+        }
+        unsafe extern "system" fn RSGetScissorRects<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, numrects: *mut u32, prects: *mut super::super::Foundation::RECT) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::RSGetScissorRects(this, core::mem::transmute_copy(&numrects), core::mem::transmute_copy(&prects))
+            }
+        }
+        unsafe extern "system" fn GetDeviceRemovedReason<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::GetDeviceRemovedReason(this).into()
+            }
+        }
+        unsafe extern "system" fn SetExceptionMode<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, raiseflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::SetExceptionMode(this, core::mem::transmute_copy(&raiseflags)).into()
+            }
+        }
+        unsafe extern "system" fn GetExceptionMode<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::GetExceptionMode(this)
+            }
+        }
+        unsafe extern "system" fn GetPrivateData<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pdatasize: *mut u32, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::GetPrivateData(this, core::mem::transmute_copy(&guid), core::mem::transmute_copy(&pdatasize), core::mem::transmute_copy(&pdata)).into()
+            }
+        }
+        unsafe extern "system" fn SetPrivateData<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, datasize: u32, pdata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::SetPrivateData(this, core::mem::transmute_copy(&guid), core::mem::transmute_copy(&datasize), core::mem::transmute_copy(&pdata)).into()
+            }
+        }
+        unsafe extern "system" fn SetPrivateDataInterface<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::SetPrivateDataInterface(this, core::mem::transmute_copy(&guid), core::mem::transmute_copy(&pdata)).into()
+            }
+        }
+        unsafe extern "system" fn ClearState<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::ClearState(this)
+            }
+        }
+        unsafe extern "system" fn Flush<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::Flush(this)
+            }
+        }
+        unsafe extern "system" fn CreateBuffer<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D10_BUFFER_DESC, pinitialdata: *const D3D10_SUBRESOURCE_DATA, ppbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::CreateBuffer(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&pinitialdata), core::mem::transmute_copy(&ppbuffer)).into()
+            }
+        }
+        unsafe extern "system" fn CreateTexture1D<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D10_TEXTURE1D_DESC, pinitialdata: *const D3D10_SUBRESOURCE_DATA, pptexture1d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D10Device_Impl::CreateTexture1D(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&pinitialdata)) {
+                    Ok(ok__) => {
+                        pptexture1d.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateTexture2D<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D10_TEXTURE2D_DESC, pinitialdata: *const D3D10_SUBRESOURCE_DATA, pptexture2d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D10Device_Impl::CreateTexture2D(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&pinitialdata)) {
+                    Ok(ok__) => {
+                        pptexture2d.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateTexture3D<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D10_TEXTURE3D_DESC, pinitialdata: *const D3D10_SUBRESOURCE_DATA, pptexture3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D10Device_Impl::CreateTexture3D(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&pinitialdata)) {
+                    Ok(ok__) => {
+                        pptexture3d.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateShaderResourceView<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc: *const D3D10_SHADER_RESOURCE_VIEW_DESC, ppsrview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::CreateShaderResourceView(this, core::mem::transmute_copy(&presource), core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&ppsrview)).into()
+            }
+        }
+        unsafe extern "system" fn CreateRenderTargetView<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc: *const D3D10_RENDER_TARGET_VIEW_DESC, pprtview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::CreateRenderTargetView(this, core::mem::transmute_copy(&presource), core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&pprtview)).into()
+            }
+        }
+        unsafe extern "system" fn CreateDepthStencilView<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc: *const D3D10_DEPTH_STENCIL_VIEW_DESC, ppdepthstencilview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::CreateDepthStencilView(this, core::mem::transmute_copy(&presource), core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&ppdepthstencilview)).into()
+            }
+        }
+        unsafe extern "system" fn CreateInputLayout<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pinputelementdescs: *const D3D10_INPUT_ELEMENT_DESC, numelements: u32, pshaderbytecodewithinputsignature: *const core::ffi::c_void, bytecodelength: usize, ppinputlayout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::CreateInputLayout(this, core::mem::transmute_copy(&pinputelementdescs), core::mem::transmute_copy(&numelements), core::mem::transmute_copy(&pshaderbytecodewithinputsignature), core::mem::transmute_copy(&bytecodelength), core::mem::transmute_copy(&ppinputlayout)).into()
+            }
+        }
+        unsafe extern "system" fn CreateVertexShader<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pshaderbytecode: *const core::ffi::c_void, bytecodelength: usize, ppvertexshader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::CreateVertexShader(this, core::mem::transmute_copy(&pshaderbytecode), core::mem::transmute_copy(&bytecodelength), core::mem::transmute_copy(&ppvertexshader)).into()
+            }
+        }
+        unsafe extern "system" fn CreateGeometryShader<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pshaderbytecode: *const core::ffi::c_void, bytecodelength: usize, ppgeometryshader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::CreateGeometryShader(this, core::mem::transmute_copy(&pshaderbytecode), core::mem::transmute_copy(&bytecodelength), core::mem::transmute_copy(&ppgeometryshader)).into()
+            }
+        }
+        unsafe extern "system" fn CreateGeometryShaderWithStreamOutput<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pshaderbytecode: *const core::ffi::c_void, bytecodelength: usize, psodeclaration: *const D3D10_SO_DECLARATION_ENTRY, numentries: u32, outputstreamstride: u32, ppgeometryshader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::CreateGeometryShaderWithStreamOutput(this, core::mem::transmute_copy(&pshaderbytecode), core::mem::transmute_copy(&bytecodelength), core::mem::transmute_copy(&psodeclaration), core::mem::transmute_copy(&numentries), core::mem::transmute_copy(&outputstreamstride), core::mem::transmute_copy(&ppgeometryshader)).into()
+            }
+        }
+        unsafe extern "system" fn CreatePixelShader<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pshaderbytecode: *const core::ffi::c_void, bytecodelength: usize, pppixelshader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::CreatePixelShader(this, core::mem::transmute_copy(&pshaderbytecode), core::mem::transmute_copy(&bytecodelength), core::mem::transmute_copy(&pppixelshader)).into()
+            }
+        }
+        unsafe extern "system" fn CreateBlendState<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pblendstatedesc: *const D3D10_BLEND_DESC, ppblendstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::CreateBlendState(this, core::mem::transmute_copy(&pblendstatedesc), core::mem::transmute_copy(&ppblendstate)).into()
+            }
+        }
+        unsafe extern "system" fn CreateDepthStencilState<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pdepthstencildesc: *const D3D10_DEPTH_STENCIL_DESC, ppdepthstencilstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::CreateDepthStencilState(this, core::mem::transmute_copy(&pdepthstencildesc), core::mem::transmute_copy(&ppdepthstencilstate)).into()
+            }
+        }
+        unsafe extern "system" fn CreateRasterizerState<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, prasterizerdesc: *const D3D10_RASTERIZER_DESC, pprasterizerstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::CreateRasterizerState(this, core::mem::transmute_copy(&prasterizerdesc), core::mem::transmute_copy(&pprasterizerstate)).into()
+            }
+        }
+        unsafe extern "system" fn CreateSamplerState<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, psamplerdesc: *const D3D10_SAMPLER_DESC, ppsamplerstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::CreateSamplerState(this, core::mem::transmute_copy(&psamplerdesc), core::mem::transmute_copy(&ppsamplerstate)).into()
+            }
+        }
+        unsafe extern "system" fn CreateQuery<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pquerydesc: *const D3D10_QUERY_DESC, ppquery: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::CreateQuery(this, core::mem::transmute_copy(&pquerydesc), core::mem::transmute_copy(&ppquery)).into()
+            }
+        }
+        unsafe extern "system" fn CreatePredicate<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, ppredicatedesc: *const D3D10_QUERY_DESC, pppredicate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::CreatePredicate(this, core::mem::transmute_copy(&ppredicatedesc), core::mem::transmute_copy(&pppredicate)).into()
+            }
+        }
+        unsafe extern "system" fn CreateCounter<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pcounterdesc: *const D3D10_COUNTER_DESC, ppcounter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::CreateCounter(this, core::mem::transmute_copy(&pcounterdesc), core::mem::transmute_copy(&ppcounter)).into()
+            }
+        }
+        unsafe extern "system" fn CheckFormatSupport<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, format: super::Dxgi::Common::DXGI_FORMAT, pformatsupport: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D10Device_Impl::CheckFormatSupport(this, core::mem::transmute_copy(&format)) {
+                    Ok(ok__) => {
+                        pformatsupport.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CheckMultisampleQualityLevels<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, format: super::Dxgi::Common::DXGI_FORMAT, samplecount: u32, pnumqualitylevels: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D10Device_Impl::CheckMultisampleQualityLevels(this, core::mem::transmute_copy(&format), core::mem::transmute_copy(&samplecount)) {
+                    Ok(ok__) => {
+                        pnumqualitylevels.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CheckCounterInfo<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pcounterinfo: *mut D3D10_COUNTER_INFO) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::CheckCounterInfo(this, core::mem::transmute_copy(&pcounterinfo))
+            }
+        }
+        unsafe extern "system" fn CheckCounter<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D10_COUNTER_DESC, ptype: *mut D3D10_COUNTER_TYPE, pactivecounters: *mut u32, szname: windows_core::PSTR, pnamelength: *mut u32, szunits: windows_core::PSTR, punitslength: *mut u32, szdescription: windows_core::PSTR, pdescriptionlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::CheckCounter(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&ptype), core::mem::transmute_copy(&pactivecounters), core::mem::transmute_copy(&szname), core::mem::transmute_copy(&pnamelength), core::mem::transmute_copy(&szunits), core::mem::transmute_copy(&punitslength), core::mem::transmute_copy(&szdescription), core::mem::transmute_copy(&pdescriptionlength)).into()
+            }
+        }
+        unsafe extern "system" fn GetCreationFlags<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::GetCreationFlags(this)
+            }
+        }
+        unsafe extern "system" fn OpenSharedResource<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, hresource: super::super::Foundation::HANDLE, returnedinterface: *const windows_core::GUID, ppresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::OpenSharedResource(this, core::mem::transmute_copy(&hresource), core::mem::transmute_copy(&returnedinterface), core::mem::transmute_copy(&ppresource)).into()
+            }
+        }
+        unsafe extern "system" fn SetTextFilterSize<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, width: u32, height: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::SetTextFilterSize(this, core::mem::transmute_copy(&width), core::mem::transmute_copy(&height))
+            }
+        }
+        unsafe extern "system" fn GetTextFilterSize<Identity: windows_core::IUnknownImpl + ID3D10Device_Impl>(this: *mut core::ffi::c_void, pwidth: *mut u32, pheight: *mut u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device_Impl::GetTextFilterSize(this, core::mem::transmute_copy(&pwidth), core::mem::transmute_copy(&pheight))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            VSSetConstantBuffers: VSSetConstantBuffers::<Identity>,
+            PSSetShaderResources: PSSetShaderResources::<Identity>,
+            PSSetShader: PSSetShader::<Identity>,
+            PSSetSamplers: PSSetSamplers::<Identity>,
+            VSSetShader: VSSetShader::<Identity>,
+            DrawIndexed: DrawIndexed::<Identity>,
+            Draw: Draw::<Identity>,
+            PSSetConstantBuffers: PSSetConstantBuffers::<Identity>,
+            IASetInputLayout: IASetInputLayout::<Identity>,
+            IASetVertexBuffers: IASetVertexBuffers::<Identity>,
+            IASetIndexBuffer: IASetIndexBuffer::<Identity>,
+            DrawIndexedInstanced: DrawIndexedInstanced::<Identity>,
+            DrawInstanced: DrawInstanced::<Identity>,
+            GSSetConstantBuffers: GSSetConstantBuffers::<Identity>,
+            GSSetShader: GSSetShader::<Identity>,
+            IASetPrimitiveTopology: IASetPrimitiveTopology::<Identity>,
+            VSSetShaderResources: VSSetShaderResources::<Identity>,
+            VSSetSamplers: VSSetSamplers::<Identity>,
+            SetPredication: SetPredication::<Identity>,
+            GSSetShaderResources: GSSetShaderResources::<Identity>,
+            GSSetSamplers: GSSetSamplers::<Identity>,
+            OMSetRenderTargets: OMSetRenderTargets::<Identity>,
+            OMSetBlendState: OMSetBlendState::<Identity>,
+            OMSetDepthStencilState: OMSetDepthStencilState::<Identity>,
+            SOSetTargets: SOSetTargets::<Identity>,
+            DrawAuto: DrawAuto::<Identity>,
+            RSSetState: RSSetState::<Identity>,
+            RSSetViewports: RSSetViewports::<Identity>,
+            RSSetScissorRects: RSSetScissorRects::<Identity>,
+            CopySubresourceRegion: CopySubresourceRegion::<Identity>,
+            CopyResource: CopyResource::<Identity>,
+            UpdateSubresource: UpdateSubresource::<Identity>,
+            ClearRenderTargetView: ClearRenderTargetView::<Identity>,
+            ClearDepthStencilView: ClearDepthStencilView::<Identity>,
+            GenerateMips: GenerateMips::<Identity>,
+            ResolveSubresource: ResolveSubresource::<Identity>,
+            VSGetConstantBuffers: VSGetConstantBuffers::<Identity>,
+            PSGetShaderResources: PSGetShaderResources::<Identity>,
+            PSGetShader: PSGetShader::<Identity>,
+            PSGetSamplers: PSGetSamplers::<Identity>,
+            VSGetShader: VSGetShader::<Identity>,
+            PSGetConstantBuffers: PSGetConstantBuffers::<Identity>,
+            IAGetInputLayout: IAGetInputLayout::<Identity>,
+            IAGetVertexBuffers: IAGetVertexBuffers::<Identity>,
+            IAGetIndexBuffer: IAGetIndexBuffer::<Identity>,
+            GSGetConstantBuffers: GSGetConstantBuffers::<Identity>,
+            GSGetShader: GSGetShader::<Identity>,
+            IAGetPrimitiveTopology: IAGetPrimitiveTopology::<Identity>,
+            VSGetShaderResources: VSGetShaderResources::<Identity>,
+            VSGetSamplers: VSGetSamplers::<Identity>,
+            GetPredication: GetPredication::<Identity>,
+            GSGetShaderResources: GSGetShaderResources::<Identity>,
+            GSGetSamplers: GSGetSamplers::<Identity>,
+            OMGetRenderTargets: OMGetRenderTargets::<Identity>,
+            OMGetBlendState: OMGetBlendState::<Identity>,
+            OMGetDepthStencilState: OMGetDepthStencilState::<Identity>,
+            SOGetTargets: SOGetTargets::<Identity>,
+            RSGetState: RSGetState::<Identity>,
+            RSGetViewports: RSGetViewports::<Identity>,
+            RSGetScissorRects: RSGetScissorRects::<Identity>,
+            GetDeviceRemovedReason: GetDeviceRemovedReason::<Identity>,
+            SetExceptionMode: SetExceptionMode::<Identity>,
+            GetExceptionMode: GetExceptionMode::<Identity>,
+            GetPrivateData: GetPrivateData::<Identity>,
+            SetPrivateData: SetPrivateData::<Identity>,
+            SetPrivateDataInterface: SetPrivateDataInterface::<Identity>,
+            ClearState: ClearState::<Identity>,
+            Flush: Flush::<Identity>,
+            CreateBuffer: CreateBuffer::<Identity>,
+            CreateTexture1D: CreateTexture1D::<Identity>,
+            CreateTexture2D: CreateTexture2D::<Identity>,
+            CreateTexture3D: CreateTexture3D::<Identity>,
+            CreateShaderResourceView: CreateShaderResourceView::<Identity>,
+            CreateRenderTargetView: CreateRenderTargetView::<Identity>,
+            CreateDepthStencilView: CreateDepthStencilView::<Identity>,
+            CreateInputLayout: CreateInputLayout::<Identity>,
+            CreateVertexShader: CreateVertexShader::<Identity>,
+            CreateGeometryShader: CreateGeometryShader::<Identity>,
+            CreateGeometryShaderWithStreamOutput: CreateGeometryShaderWithStreamOutput::<Identity>,
+            CreatePixelShader: CreatePixelShader::<Identity>,
+            CreateBlendState: CreateBlendState::<Identity>,
+            CreateDepthStencilState: CreateDepthStencilState::<Identity>,
+            CreateRasterizerState: CreateRasterizerState::<Identity>,
+            CreateSamplerState: CreateSamplerState::<Identity>,
+            CreateQuery: CreateQuery::<Identity>,
+            CreatePredicate: CreatePredicate::<Identity>,
+            CreateCounter: CreateCounter::<Identity>,
+            CheckFormatSupport: CheckFormatSupport::<Identity>,
+            CheckMultisampleQualityLevels: CheckMultisampleQualityLevels::<Identity>,
+            CheckCounterInfo: CheckCounterInfo::<Identity>,
+            CheckCounter: CheckCounter::<Identity>,
+            GetCreationFlags: GetCreationFlags::<Identity>,
+            OpenSharedResource: OpenSharedResource::<Identity>,
+            SetTextFilterSize: SetTextFilterSize::<Identity>,
+            GetTextFilterSize: GetTextFilterSize::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::RuntimeName for ID3D10Device {}
+windows_core::imp::define_interface!(ID3D10Device1, ID3D10Device1_Vtbl, 0x9b7e4c8f_342c_4106_a19f_4f2704f689f0);
+impl core::ops::Deref for ID3D10Device1 {
+    type Target = ID3D10Device;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(ID3D10Device1, windows_core::IUnknown, ID3D10Device);
+impl ID3D10Device1 {
+    #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+    pub unsafe fn CreateShaderResourceView1<P0>(&self, presource: P0, pdesc: Option<*const D3D10_SHADER_RESOURCE_VIEW_DESC1>, ppsrview: Option<*mut Option<ID3D10ShaderResourceView1>>) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<ID3D10Resource>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).CreateShaderResourceView1)(windows_core::Interface::as_raw(self), presource.param().abi(), pdesc.unwrap_or(core::mem::zeroed()) as _, ppsrview.unwrap_or(core::mem::zeroed()) as _).ok() }
+    }
+    pub unsafe fn CreateBlendState1(&self, pblendstatedesc: *const D3D10_BLEND_DESC1, ppblendstate: Option<*mut Option<ID3D10BlendState1>>) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).CreateBlendState1)(windows_core::Interface::as_raw(self), pblendstatedesc, ppblendstate.unwrap_or(core::mem::zeroed()) as _).ok() }
+    }
+    pub unsafe fn GetFeatureLevel(&self) -> D3D10_FEATURE_LEVEL1 {
+        unsafe { (windows_core::Interface::vtable(self).GetFeatureLevel)(windows_core::Interface::as_raw(self)) }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct ID3D10Device1_Vtbl {
+    pub base__: ID3D10Device_Vtbl,
+    #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+    pub CreateShaderResourceView1: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *const D3D10_SHADER_RESOURCE_VIEW_DESC1, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    #[cfg(not(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common")))]
+    CreateShaderResourceView1: usize,
+    pub CreateBlendState1: unsafe extern "system" fn(*mut core::ffi::c_void, *const D3D10_BLEND_DESC1, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetFeatureLevel: unsafe extern "system" fn(*mut core::ffi::c_void) -> D3D10_FEATURE_LEVEL1,
+}
+unsafe impl Send for ID3D10Device1 {}
+unsafe impl Sync for ID3D10Device1 {}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+pub trait ID3D10Device1_Impl: ID3D10Device_Impl {
+    fn CreateShaderResourceView1(&self, presource: windows_core::Ref<ID3D10Resource>, pdesc: *const D3D10_SHADER_RESOURCE_VIEW_DESC1, ppsrview: windows_core::OutRef<ID3D10ShaderResourceView1>) -> windows_core::Result<()>;
+    fn CreateBlendState1(&self, pblendstatedesc: *const D3D10_BLEND_DESC1, ppblendstate: windows_core::OutRef<ID3D10BlendState1>) -> windows_core::Result<()>;
+    fn GetFeatureLevel(&self) -> D3D10_FEATURE_LEVEL1;
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl ID3D10Device1_Vtbl {
+    pub const fn new<Identity: ID3D10Device1_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn CreateShaderResourceView1<Identity: ID3D10Device1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc: *const D3D10_SHADER_RESOURCE_VIEW_DESC1, ppsrview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10Device1_Impl::CreateShaderResourceView1(this, core::mem::transmute_copy(&presource), core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&ppsrview)).into()
+            }
+        }
+        unsafe extern "system" fn CreateBlendState1<Identity: ID3D10Device1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pblendstatedesc: *const D3D10_BLEND_DESC1, ppblendstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10Device1_Impl::CreateBlendState1(this, core::mem::transmute_copy(&pblendstatedesc), core::mem::transmute_copy(&ppblendstate)).into()
+            }
+        }
+        unsafe extern "system" fn GetFeatureLevel<Identity: ID3D10Device1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> D3D10_FEATURE_LEVEL1 {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10Device1_Impl::GetFeatureLevel(this)
+            }
+        }
+        Self {
+            base__: ID3D10Device_Vtbl::new::<Identity, OFFSET>(),
+            CreateShaderResourceView1: CreateShaderResourceView1::<Identity, OFFSET>,
+            CreateBlendState1: CreateBlendState1::<Identity, OFFSET>,
+            GetFeatureLevel: GetFeatureLevel::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10Device1 as windows_core::Interface>::IID || iid == &<ID3D10Device as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D10Device1_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10Device1_Vtbl
+where
+    ID3D10Device_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateShaderResourceView1<Identity: windows_core::IUnknownImpl + ID3D10Device1_Impl>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc: *const D3D10_SHADER_RESOURCE_VIEW_DESC1, ppsrview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device1_Impl::CreateShaderResourceView1(this, core::mem::transmute_copy(&presource), core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&ppsrview)).into()
+            }
+        }
+        unsafe extern "system" fn CreateBlendState1<Identity: windows_core::IUnknownImpl + ID3D10Device1_Impl>(this: *mut core::ffi::c_void, pblendstatedesc: *const D3D10_BLEND_DESC1, ppblendstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device1_Impl::CreateBlendState1(this, core::mem::transmute_copy(&pblendstatedesc), core::mem::transmute_copy(&ppblendstate)).into()
+            }
+        }
+        unsafe extern "system" fn GetFeatureLevel<Identity: windows_core::IUnknownImpl + ID3D10Device1_Impl>(this: *mut core::ffi::c_void) -> D3D10_FEATURE_LEVEL1 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Device1_Impl::GetFeatureLevel(this)
+            }
+        }
+        Self {
+            base__: <ID3D10Device_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateShaderResourceView1: CreateShaderResourceView1::<Identity>,
+            CreateBlendState1: CreateBlendState1::<Identity>,
+            GetFeatureLevel: GetFeatureLevel::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::RuntimeName for ID3D10Device1 {}
+windows_core::imp::define_interface!(ID3D10DeviceChild, ID3D10DeviceChild_Vtbl, 0x9b7e4c00_342c_4106_a19f_4f2704f689f0);
+windows_core::imp::interface_hierarchy!(ID3D10DeviceChild, windows_core::IUnknown);
+impl ID3D10DeviceChild {
+    pub unsafe fn GetDevice(&self) -> windows_core::Result<ID3D10Device> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetDevice)(windows_core::Interface::as_raw(self), &mut result__);
+            windows_core::Type::from_abi(result__)
+        }
+    }
+    pub unsafe fn GetPrivateData(&self, guid: *const windows_core::GUID, pdatasize: *mut u32, pdata: Option<*mut core::ffi::c_void>) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetPrivateData)(windows_core::Interface::as_raw(self), guid, pdatasize as _, pdata.unwrap_or(core::mem::zeroed()) as _).ok() }
+    }
+    pub unsafe fn SetPrivateData(&self, guid: *const windows_core::GUID, datasize: u32, pdata: Option<*const core::ffi::c_void>) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetPrivateData)(windows_core::Interface::as_raw(self), guid, datasize, pdata.unwrap_or(core::mem::zeroed()) as _).ok() }
+    }
+    pub unsafe fn SetPrivateDataInterface<P1>(&self, guid: *const windows_core::GUID, pdata: P1) -> windows_core::Result<()>
+    where
+        P1: windows_core::Param<windows_core::IUnknown>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).SetPrivateDataInterface)(windows_core::Interface::as_raw(self), guid, pdata.param().abi()).ok() }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct ID3D10DeviceChild_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub GetDevice: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void),
+    pub GetPrivateData: unsafe extern "system" fn(*mut core::ffi::c_void, *const windows_core::GUID, *mut u32, *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub SetPrivateData: unsafe extern "system" fn(*mut core::ffi::c_void, *const windows_core::GUID, u32, *const core::ffi::c_void) -> windows_core::HRESULT,
+    pub SetPrivateDataInterface: unsafe extern "system" fn(*mut core::ffi::c_void, *const windows_core::GUID, *mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+unsafe impl Send for ID3D10DeviceChild {}
+unsafe impl Sync for ID3D10DeviceChild {}
+pub trait ID3D10DeviceChild_Impl: windows_core::IUnknownImpl {
+    fn GetDevice(&self, ppdevice: windows_core::OutRef<ID3D10Device>);
+    fn GetPrivateData(&self, guid: *const windows_core::GUID, pdatasize: *mut u32, pdata: *mut core::ffi::c_void) -> windows_core::Result<()>;
+    fn SetPrivateData(&self, guid: *const windows_core::GUID, datasize: u32, pdata: *const core::ffi::c_void) -> windows_core::Result<()>;
+    fn SetPrivateDataInterface(&self, guid: *const windows_core::GUID, pdata: windows_core::Ref<windows_core::IUnknown>) -> windows_core::Result<()>;
+}
+impl ID3D10DeviceChild_Vtbl {
+    pub const fn new<Identity: ID3D10DeviceChild_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn GetDevice<Identity: ID3D10DeviceChild_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppdevice: *mut *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10DeviceChild_Impl::GetDevice(this, core::mem::transmute_copy(&ppdevice))
+            }
+        }
+        unsafe extern "system" fn GetPrivateData<Identity: ID3D10DeviceChild_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pdatasize: *mut u32, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10DeviceChild_Impl::GetPrivateData(this, core::mem::transmute_copy(&guid), core::mem::transmute_copy(&pdatasize), core::mem::transmute_copy(&pdata)).into()
+            }
+        }
+        unsafe extern "system" fn SetPrivateData<Identity: ID3D10DeviceChild_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, datasize: u32, pdata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10DeviceChild_Impl::SetPrivateData(this, core::mem::transmute_copy(&guid), core::mem::transmute_copy(&datasize), core::mem::transmute_copy(&pdata)).into()
+            }
+        }
+        unsafe extern "system" fn SetPrivateDataInterface<Identity: ID3D10DeviceChild_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10DeviceChild_Impl::SetPrivateDataInterface(this, core::mem::transmute_copy(&guid), core::mem::transmute_copy(&pdata)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            GetDevice: GetDevice::<Identity, OFFSET>,
+            GetPrivateData: GetPrivateData::<Identity, OFFSET>,
+            SetPrivateData: SetPrivateData::<Identity, OFFSET>,
+            SetPrivateDataInterface: SetPrivateDataInterface::<Identity, OFFSET>,
         }
     }
     pub fn matches(iid: &windows_core::GUID) -> bool {
         iid == &<ID3D10DeviceChild as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D10DeviceChild_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10DeviceChild_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDevice<Identity: windows_core::IUnknownImpl + ID3D10DeviceChild_Impl>(this: *mut core::ffi::c_void, ppdevice: *mut *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10DeviceChild_Impl::GetDevice(this, core::mem::transmute_copy(&ppdevice))
+            }
+        }
+        unsafe extern "system" fn GetPrivateData<Identity: windows_core::IUnknownImpl + ID3D10DeviceChild_Impl>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pdatasize: *mut u32, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10DeviceChild_Impl::GetPrivateData(this, core::mem::transmute_copy(&guid), core::mem::transmute_copy(&pdatasize), core::mem::transmute_copy(&pdata)).into()
+            }
+        }
+        unsafe extern "system" fn SetPrivateData<Identity: windows_core::IUnknownImpl + ID3D10DeviceChild_Impl>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, datasize: u32, pdata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10DeviceChild_Impl::SetPrivateData(this, core::mem::transmute_copy(&guid), core::mem::transmute_copy(&datasize), core::mem::transmute_copy(&pdata)).into()
+            }
+        }
+        unsafe extern "system" fn SetPrivateDataInterface<Identity: windows_core::IUnknownImpl + ID3D10DeviceChild_Impl>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10DeviceChild_Impl::SetPrivateDataInterface(this, core::mem::transmute_copy(&guid), core::mem::transmute_copy(&pdata)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDevice: GetDevice::<Identity>,
+            GetPrivateData: GetPrivateData::<Identity>,
+            SetPrivateData: SetPrivateData::<Identity>,
+            SetPrivateDataInterface: SetPrivateDataInterface::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D10DeviceChild {}
 windows_core::imp::define_interface!(ID3D10Effect, ID3D10Effect_Vtbl, 0x51b0ca8b_ec0b_4519_870d_8ee1cb5017c7);
 windows_core::imp::interface_hierarchy!(ID3D10Effect, windows_core::IUnknown);
@@ -4616,10 +5598,117 @@ impl ID3D10Effect_Vtbl {
             Optimize: Optimize::<Identity, OFFSET>,
             IsOptimized: IsOptimized::<Identity, OFFSET>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ID3D10Effect as windows_core::Interface>::IID
-    }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10Effect as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + ID3D10Effect_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10Effect_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsValid<Identity: windows_core::IUnknownImpl + ID3D10Effect_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Effect_Impl::IsValid(this)
+            }
+        }
+        unsafe extern "system" fn IsPool<Identity: windows_core::IUnknownImpl + ID3D10Effect_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Effect_Impl::IsPool(this)
+            }
+        }
+        unsafe extern "system" fn GetDevice<Identity: windows_core::IUnknownImpl + ID3D10Effect_Impl>(this: *mut core::ffi::c_void, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D10Effect_Impl::GetDevice(this) {
+                    Ok(ok__) => {
+                        ppdevice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D10Effect_Impl>(this: *mut core::ffi::c_void, pdesc: *mut D3D10_EFFECT_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Effect_Impl::GetDesc(this, core::mem::transmute_copy(&pdesc)).into()
+            }
+        }
+        unsafe extern "system" fn GetConstantBufferByIndex<Identity: windows_core::IUnknownImpl + ID3D10Effect_Impl>(this: *mut core::ffi::c_void, index: u32) -> Option<ID3D10EffectConstantBuffer> {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Effect_Impl::GetConstantBufferByIndex(this, core::mem::transmute_copy(&index))
+            }
+        }
+        unsafe extern "system" fn GetConstantBufferByName<Identity: windows_core::IUnknownImpl + ID3D10Effect_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCSTR) -> Option<ID3D10EffectConstantBuffer> {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Effect_Impl::GetConstantBufferByName(this, core::mem::transmute(&name))
+            }
+        }
+        unsafe extern "system" fn GetVariableByIndex<Identity: windows_core::IUnknownImpl + ID3D10Effect_Impl>(this: *mut core::ffi::c_void, index: u32) -> Option<ID3D10EffectVariable> {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Effect_Impl::GetVariableByIndex(this, core::mem::transmute_copy(&index))
+            }
+        }
+        unsafe extern "system" fn GetVariableByName<Identity: windows_core::IUnknownImpl + ID3D10Effect_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCSTR) -> Option<ID3D10EffectVariable> {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Effect_Impl::GetVariableByName(this, core::mem::transmute(&name))
+            }
+        }
+        unsafe extern "system" fn GetVariableBySemantic<Identity: windows_core::IUnknownImpl + ID3D10Effect_Impl>(this: *mut core::ffi::c_void, semantic: windows_core::PCSTR) -> Option<ID3D10EffectVariable> {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Effect_Impl::GetVariableBySemantic(this, core::mem::transmute(&semantic))
+            }
+        }
+        unsafe extern "system" fn GetTechniqueByIndex<Identity: windows_core::IUnknownImpl + ID3D10Effect_Impl>(this: *mut core::ffi::c_void, index: u32) -> Option<ID3D10EffectTechnique> {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Effect_Impl::GetTechniqueByIndex(this, core::mem::transmute_copy(&index))
+            }
+        }
+        unsafe extern "system" fn GetTechniqueByName<Identity: windows_core::IUnknownImpl + ID3D10Effect_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCSTR) -> Option<ID3D10EffectTechnique> {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Effect_Impl::GetTechniqueByName(this, core::mem::transmute(&name))
+            }
+        }
+        unsafe extern "system" fn Optimize<Identity: windows_core::IUnknownImpl + ID3D10Effect_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Effect_Impl::Optimize(this).into()
+            }
+        }
+        unsafe extern "system" fn IsOptimized<Identity: windows_core::IUnknownImpl + ID3D10Effect_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Effect_Impl::IsOptimized(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsValid: IsValid::<Identity>,
+            IsPool: IsPool::<Identity>,
+            GetDevice: GetDevice::<Identity>,
+            GetDesc: GetDesc::<Identity>,
+            GetConstantBufferByIndex: GetConstantBufferByIndex::<Identity>,
+            GetConstantBufferByName: GetConstantBufferByName::<Identity>,
+            GetVariableByIndex: GetVariableByIndex::<Identity>,
+            GetVariableByName: GetVariableByName::<Identity>,
+            GetVariableBySemantic: GetVariableBySemantic::<Identity>,
+            GetTechniqueByIndex: GetTechniqueByIndex::<Identity>,
+            GetTechniqueByName: GetTechniqueByName::<Identity>,
+            Optimize: Optimize::<Identity>,
+            IsOptimized: IsOptimized::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for ID3D10Effect {}
 windows_core::imp::define_interface!(ID3D10EffectBlendVariable, ID3D10EffectBlendVariable_Vtbl);
@@ -5297,6 +6386,20 @@ impl ID3D10EffectPool_Vtbl {
         iid == &<ID3D10EffectPool as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D10EffectPool_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10EffectPool_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AsEffect<Identity: windows_core::IUnknownImpl + ID3D10EffectPool_Impl>(this: *mut core::ffi::c_void) -> Option<ID3D10Effect> {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10EffectPool_Impl::AsEffect(this)
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, AsEffect: AsEffect::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ID3D10EffectPool {}
 windows_core::imp::define_interface!(ID3D10EffectRasterizerVariable, ID3D10EffectRasterizerVariable_Vtbl);
 impl core::ops::Deref for ID3D10EffectRasterizerVariable {
@@ -6921,6 +8024,12 @@ impl ID3D10GeometryShader_Vtbl {
         iid == &<ID3D10GeometryShader as windows_core::Interface>::IID || iid == &<ID3D10DeviceChild as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D10GeometryShader_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10GeometryShader_Vtbl
+where
+    ID3D10DeviceChild_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <ID3D10DeviceChild_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for ID3D10GeometryShader {}
 windows_core::imp::define_interface!(ID3D10InfoQueue, ID3D10InfoQueue_Vtbl, 0x1b940b17_2642_4d1f_ab1f_b99bad0c395f);
 windows_core::imp::interface_hierarchy!(ID3D10InfoQueue, windows_core::IUnknown);
@@ -7116,260 +8225,515 @@ pub trait ID3D10InfoQueue_Impl: windows_core::IUnknownImpl {
     fn SetMuteDebugOutput(&self, bmute: windows_core::BOOL);
     fn GetMuteDebugOutput(&self) -> windows_core::BOOL;
 }
-impl ID3D10InfoQueue_Vtbl {
-    pub const fn new<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn SetMessageCountLimit<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, messagecountlimit: u64) -> windows_core::HRESULT {
+impl ID3D10InfoQueue_Vtbl {
+    pub const fn new<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn SetMessageCountLimit<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, messagecountlimit: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::SetMessageCountLimit(this, core::mem::transmute_copy(&messagecountlimit)).into()
+            }
+        }
+        unsafe extern "system" fn ClearStoredMessages<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::ClearStoredMessages(this)
+            }
+        }
+        unsafe extern "system" fn GetMessage<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, messageindex: u64, pmessage: *mut D3D10_MESSAGE, pmessagebytelength: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::GetMessage(this, core::mem::transmute_copy(&messageindex), core::mem::transmute_copy(&pmessage), core::mem::transmute_copy(&pmessagebytelength)).into()
+            }
+        }
+        unsafe extern "system" fn GetNumMessagesAllowedByStorageFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u64 {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::GetNumMessagesAllowedByStorageFilter(this)
+            }
+        }
+        unsafe extern "system" fn GetNumMessagesDeniedByStorageFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u64 {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::GetNumMessagesDeniedByStorageFilter(this)
+            }
+        }
+        unsafe extern "system" fn GetNumStoredMessages<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u64 {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::GetNumStoredMessages(this)
+            }
+        }
+        unsafe extern "system" fn GetNumStoredMessagesAllowedByRetrievalFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u64 {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::GetNumStoredMessagesAllowedByRetrievalFilter(this)
+            }
+        }
+        unsafe extern "system" fn GetNumMessagesDiscardedByMessageCountLimit<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u64 {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::GetNumMessagesDiscardedByMessageCountLimit(this)
+            }
+        }
+        unsafe extern "system" fn GetMessageCountLimit<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u64 {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::GetMessageCountLimit(this)
+            }
+        }
+        unsafe extern "system" fn AddStorageFilterEntries<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfilter: *const D3D10_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::AddStorageFilterEntries(this, core::mem::transmute_copy(&pfilter)).into()
+            }
+        }
+        unsafe extern "system" fn GetStorageFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfilter: *mut D3D10_INFO_QUEUE_FILTER, pfilterbytelength: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::GetStorageFilter(this, core::mem::transmute_copy(&pfilter), core::mem::transmute_copy(&pfilterbytelength)).into()
+            }
+        }
+        unsafe extern "system" fn ClearStorageFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::ClearStorageFilter(this)
+            }
+        }
+        unsafe extern "system" fn PushEmptyStorageFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::PushEmptyStorageFilter(this).into()
+            }
+        }
+        unsafe extern "system" fn PushCopyOfStorageFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::PushCopyOfStorageFilter(this).into()
+            }
+        }
+        unsafe extern "system" fn PushStorageFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfilter: *const D3D10_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::PushStorageFilter(this, core::mem::transmute_copy(&pfilter)).into()
+            }
+        }
+        unsafe extern "system" fn PopStorageFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::PopStorageFilter(this)
+            }
+        }
+        unsafe extern "system" fn GetStorageFilterStackSize<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::GetStorageFilterStackSize(this)
+            }
+        }
+        unsafe extern "system" fn AddRetrievalFilterEntries<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfilter: *const D3D10_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::AddRetrievalFilterEntries(this, core::mem::transmute_copy(&pfilter)).into()
+            }
+        }
+        unsafe extern "system" fn GetRetrievalFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfilter: *mut D3D10_INFO_QUEUE_FILTER, pfilterbytelength: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::GetRetrievalFilter(this, core::mem::transmute_copy(&pfilter), core::mem::transmute_copy(&pfilterbytelength)).into()
+            }
+        }
+        unsafe extern "system" fn ClearRetrievalFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::ClearRetrievalFilter(this)
+            }
+        }
+        unsafe extern "system" fn PushEmptyRetrievalFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::PushEmptyRetrievalFilter(this).into()
+            }
+        }
+        unsafe extern "system" fn PushCopyOfRetrievalFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::PushCopyOfRetrievalFilter(this).into()
+            }
+        }
+        unsafe extern "system" fn PushRetrievalFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfilter: *const D3D10_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::PushRetrievalFilter(this, core::mem::transmute_copy(&pfilter)).into()
+            }
+        }
+        unsafe extern "system" fn PopRetrievalFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::PopRetrievalFilter(this)
+            }
+        }
+        unsafe extern "system" fn GetRetrievalFilterStackSize<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::GetRetrievalFilterStackSize(this)
+            }
+        }
+        unsafe extern "system" fn AddMessage<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, category: D3D10_MESSAGE_CATEGORY, severity: D3D10_MESSAGE_SEVERITY, id: D3D10_MESSAGE_ID, pdescription: windows_core::PCSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::AddMessage(this, core::mem::transmute_copy(&category), core::mem::transmute_copy(&severity), core::mem::transmute_copy(&id), core::mem::transmute(&pdescription)).into()
+            }
+        }
+        unsafe extern "system" fn AddApplicationMessage<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, severity: D3D10_MESSAGE_SEVERITY, pdescription: windows_core::PCSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::AddApplicationMessage(this, core::mem::transmute_copy(&severity), core::mem::transmute(&pdescription)).into()
+            }
+        }
+        unsafe extern "system" fn SetBreakOnCategory<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, category: D3D10_MESSAGE_CATEGORY, benable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::SetBreakOnCategory(this, core::mem::transmute_copy(&category), core::mem::transmute_copy(&benable)).into()
+            }
+        }
+        unsafe extern "system" fn SetBreakOnSeverity<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, severity: D3D10_MESSAGE_SEVERITY, benable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::SetBreakOnSeverity(this, core::mem::transmute_copy(&severity), core::mem::transmute_copy(&benable)).into()
+            }
+        }
+        unsafe extern "system" fn SetBreakOnID<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, id: D3D10_MESSAGE_ID, benable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::SetBreakOnID(this, core::mem::transmute_copy(&id), core::mem::transmute_copy(&benable)).into()
+            }
+        }
+        unsafe extern "system" fn GetBreakOnCategory<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, category: D3D10_MESSAGE_CATEGORY) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::GetBreakOnCategory(this, core::mem::transmute_copy(&category))
+            }
+        }
+        unsafe extern "system" fn GetBreakOnSeverity<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, severity: D3D10_MESSAGE_SEVERITY) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::GetBreakOnSeverity(this, core::mem::transmute_copy(&severity))
+            }
+        }
+        unsafe extern "system" fn GetBreakOnID<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, id: D3D10_MESSAGE_ID) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::GetBreakOnID(this, core::mem::transmute_copy(&id))
+            }
+        }
+        unsafe extern "system" fn SetMuteDebugOutput<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bmute: windows_core::BOOL) {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::SetMuteDebugOutput(this, core::mem::transmute_copy(&bmute))
+            }
+        }
+        unsafe extern "system" fn GetMuteDebugOutput<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D10InfoQueue_Impl::GetMuteDebugOutput(this)
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            SetMessageCountLimit: SetMessageCountLimit::<Identity, OFFSET>,
+            ClearStoredMessages: ClearStoredMessages::<Identity, OFFSET>,
+            GetMessage: GetMessage::<Identity, OFFSET>,
+            GetNumMessagesAllowedByStorageFilter: GetNumMessagesAllowedByStorageFilter::<Identity, OFFSET>,
+            GetNumMessagesDeniedByStorageFilter: GetNumMessagesDeniedByStorageFilter::<Identity, OFFSET>,
+            GetNumStoredMessages: GetNumStoredMessages::<Identity, OFFSET>,
+            GetNumStoredMessagesAllowedByRetrievalFilter: GetNumStoredMessagesAllowedByRetrievalFilter::<Identity, OFFSET>,
+            GetNumMessagesDiscardedByMessageCountLimit: GetNumMessagesDiscardedByMessageCountLimit::<Identity, OFFSET>,
+            GetMessageCountLimit: GetMessageCountLimit::<Identity, OFFSET>,
+            AddStorageFilterEntries: AddStorageFilterEntries::<Identity, OFFSET>,
+            GetStorageFilter: GetStorageFilter::<Identity, OFFSET>,
+            ClearStorageFilter: ClearStorageFilter::<Identity, OFFSET>,
+            PushEmptyStorageFilter: PushEmptyStorageFilter::<Identity, OFFSET>,
+            PushCopyOfStorageFilter: PushCopyOfStorageFilter::<Identity, OFFSET>,
+            PushStorageFilter: PushStorageFilter::<Identity, OFFSET>,
+            PopStorageFilter: PopStorageFilter::<Identity, OFFSET>,
+            GetStorageFilterStackSize: GetStorageFilterStackSize::<Identity, OFFSET>,
+            AddRetrievalFilterEntries: AddRetrievalFilterEntries::<Identity, OFFSET>,
+            GetRetrievalFilter: GetRetrievalFilter::<Identity, OFFSET>,
+            ClearRetrievalFilter: ClearRetrievalFilter::<Identity, OFFSET>,
+            PushEmptyRetrievalFilter: PushEmptyRetrievalFilter::<Identity, OFFSET>,
+            PushCopyOfRetrievalFilter: PushCopyOfRetrievalFilter::<Identity, OFFSET>,
+            PushRetrievalFilter: PushRetrievalFilter::<Identity, OFFSET>,
+            PopRetrievalFilter: PopRetrievalFilter::<Identity, OFFSET>,
+            GetRetrievalFilterStackSize: GetRetrievalFilterStackSize::<Identity, OFFSET>,
+            AddMessage: AddMessage::<Identity, OFFSET>,
+            AddApplicationMessage: AddApplicationMessage::<Identity, OFFSET>,
+            SetBreakOnCategory: SetBreakOnCategory::<Identity, OFFSET>,
+            SetBreakOnSeverity: SetBreakOnSeverity::<Identity, OFFSET>,
+            SetBreakOnID: SetBreakOnID::<Identity, OFFSET>,
+            GetBreakOnCategory: GetBreakOnCategory::<Identity, OFFSET>,
+            GetBreakOnSeverity: GetBreakOnSeverity::<Identity, OFFSET>,
+            GetBreakOnID: GetBreakOnID::<Identity, OFFSET>,
+            SetMuteDebugOutput: SetMuteDebugOutput::<Identity, OFFSET>,
+            GetMuteDebugOutput: GetMuteDebugOutput::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10InfoQueue as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10InfoQueue_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetMessageCountLimit<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void, messagecountlimit: u64) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::SetMessageCountLimit(this, core::mem::transmute_copy(&messagecountlimit)).into()
             }
         }
-        unsafe extern "system" fn ClearStoredMessages<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) {
+        unsafe extern "system" fn ClearStoredMessages<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void) {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::ClearStoredMessages(this)
             }
         }
-        unsafe extern "system" fn GetMessage<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, messageindex: u64, pmessage: *mut D3D10_MESSAGE, pmessagebytelength: *mut usize) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetMessage<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void, messageindex: u64, pmessage: *mut D3D10_MESSAGE, pmessagebytelength: *mut usize) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::GetMessage(this, core::mem::transmute_copy(&messageindex), core::mem::transmute_copy(&pmessage), core::mem::transmute_copy(&pmessagebytelength)).into()
             }
         }
-        unsafe extern "system" fn GetNumMessagesAllowedByStorageFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u64 {
+        unsafe extern "system" fn GetNumMessagesAllowedByStorageFilter<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void) -> u64 {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::GetNumMessagesAllowedByStorageFilter(this)
             }
         }
-        unsafe extern "system" fn GetNumMessagesDeniedByStorageFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u64 {
+        unsafe extern "system" fn GetNumMessagesDeniedByStorageFilter<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void) -> u64 {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::GetNumMessagesDeniedByStorageFilter(this)
             }
         }
-        unsafe extern "system" fn GetNumStoredMessages<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u64 {
+        unsafe extern "system" fn GetNumStoredMessages<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void) -> u64 {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::GetNumStoredMessages(this)
             }
         }
-        unsafe extern "system" fn GetNumStoredMessagesAllowedByRetrievalFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u64 {
+        unsafe extern "system" fn GetNumStoredMessagesAllowedByRetrievalFilter<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void) -> u64 {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::GetNumStoredMessagesAllowedByRetrievalFilter(this)
             }
         }
-        unsafe extern "system" fn GetNumMessagesDiscardedByMessageCountLimit<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u64 {
+        unsafe extern "system" fn GetNumMessagesDiscardedByMessageCountLimit<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void) -> u64 {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::GetNumMessagesDiscardedByMessageCountLimit(this)
             }
         }
-        unsafe extern "system" fn GetMessageCountLimit<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u64 {
+        unsafe extern "system" fn GetMessageCountLimit<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void) -> u64 {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::GetMessageCountLimit(this)
             }
         }
-        unsafe extern "system" fn AddStorageFilterEntries<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfilter: *const D3D10_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+        unsafe extern "system" fn AddStorageFilterEntries<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void, pfilter: *const D3D10_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::AddStorageFilterEntries(this, core::mem::transmute_copy(&pfilter)).into()
             }
         }
-        unsafe extern "system" fn GetStorageFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfilter: *mut D3D10_INFO_QUEUE_FILTER, pfilterbytelength: *mut usize) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetStorageFilter<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void, pfilter: *mut D3D10_INFO_QUEUE_FILTER, pfilterbytelength: *mut usize) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::GetStorageFilter(this, core::mem::transmute_copy(&pfilter), core::mem::transmute_copy(&pfilterbytelength)).into()
             }
         }
-        unsafe extern "system" fn ClearStorageFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) {
+        unsafe extern "system" fn ClearStorageFilter<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void) {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::ClearStorageFilter(this)
             }
         }
-        unsafe extern "system" fn PushEmptyStorageFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn PushEmptyStorageFilter<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::PushEmptyStorageFilter(this).into()
             }
         }
-        unsafe extern "system" fn PushCopyOfStorageFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn PushCopyOfStorageFilter<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::PushCopyOfStorageFilter(this).into()
             }
         }
-        unsafe extern "system" fn PushStorageFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfilter: *const D3D10_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+        unsafe extern "system" fn PushStorageFilter<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void, pfilter: *const D3D10_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::PushStorageFilter(this, core::mem::transmute_copy(&pfilter)).into()
             }
         }
-        unsafe extern "system" fn PopStorageFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) {
+        unsafe extern "system" fn PopStorageFilter<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void) {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::PopStorageFilter(this)
             }
         }
-        unsafe extern "system" fn GetStorageFilterStackSize<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u32 {
+        unsafe extern "system" fn GetStorageFilterStackSize<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void) -> u32 {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::GetStorageFilterStackSize(this)
             }
         }
-        unsafe extern "system" fn AddRetrievalFilterEntries<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfilter: *const D3D10_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+        unsafe extern "system" fn AddRetrievalFilterEntries<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void, pfilter: *const D3D10_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::AddRetrievalFilterEntries(this, core::mem::transmute_copy(&pfilter)).into()
             }
         }
-        unsafe extern "system" fn GetRetrievalFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfilter: *mut D3D10_INFO_QUEUE_FILTER, pfilterbytelength: *mut usize) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetRetrievalFilter<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void, pfilter: *mut D3D10_INFO_QUEUE_FILTER, pfilterbytelength: *mut usize) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::GetRetrievalFilter(this, core::mem::transmute_copy(&pfilter), core::mem::transmute_copy(&pfilterbytelength)).into()
             }
         }
-        unsafe extern "system" fn ClearRetrievalFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) {
+        unsafe extern "system" fn ClearRetrievalFilter<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void) {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::ClearRetrievalFilter(this)
             }
         }
-        unsafe extern "system" fn PushEmptyRetrievalFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn PushEmptyRetrievalFilter<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::PushEmptyRetrievalFilter(this).into()
             }
         }
-        unsafe extern "system" fn PushCopyOfRetrievalFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn PushCopyOfRetrievalFilter<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::PushCopyOfRetrievalFilter(this).into()
             }
         }
-        unsafe extern "system" fn PushRetrievalFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfilter: *const D3D10_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+        unsafe extern "system" fn PushRetrievalFilter<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void, pfilter: *const D3D10_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::PushRetrievalFilter(this, core::mem::transmute_copy(&pfilter)).into()
             }
         }
-        unsafe extern "system" fn PopRetrievalFilter<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) {
+        unsafe extern "system" fn PopRetrievalFilter<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void) {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::PopRetrievalFilter(this)
             }
         }
-        unsafe extern "system" fn GetRetrievalFilterStackSize<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u32 {
+        unsafe extern "system" fn GetRetrievalFilterStackSize<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void) -> u32 {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::GetRetrievalFilterStackSize(this)
             }
         }
-        unsafe extern "system" fn AddMessage<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, category: D3D10_MESSAGE_CATEGORY, severity: D3D10_MESSAGE_SEVERITY, id: D3D10_MESSAGE_ID, pdescription: windows_core::PCSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn AddMessage<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void, category: D3D10_MESSAGE_CATEGORY, severity: D3D10_MESSAGE_SEVERITY, id: D3D10_MESSAGE_ID, pdescription: windows_core::PCSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::AddMessage(this, core::mem::transmute_copy(&category), core::mem::transmute_copy(&severity), core::mem::transmute_copy(&id), core::mem::transmute(&pdescription)).into()
             }
         }
-        unsafe extern "system" fn AddApplicationMessage<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, severity: D3D10_MESSAGE_SEVERITY, pdescription: windows_core::PCSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn AddApplicationMessage<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void, severity: D3D10_MESSAGE_SEVERITY, pdescription: windows_core::PCSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::AddApplicationMessage(this, core::mem::transmute_copy(&severity), core::mem::transmute(&pdescription)).into()
             }
         }
-        unsafe extern "system" fn SetBreakOnCategory<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, category: D3D10_MESSAGE_CATEGORY, benable: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBreakOnCategory<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void, category: D3D10_MESSAGE_CATEGORY, benable: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::SetBreakOnCategory(this, core::mem::transmute_copy(&category), core::mem::transmute_copy(&benable)).into()
             }
         }
-        unsafe extern "system" fn SetBreakOnSeverity<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, severity: D3D10_MESSAGE_SEVERITY, benable: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBreakOnSeverity<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void, severity: D3D10_MESSAGE_SEVERITY, benable: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::SetBreakOnSeverity(this, core::mem::transmute_copy(&severity), core::mem::transmute_copy(&benable)).into()
             }
         }
-        unsafe extern "system" fn SetBreakOnID<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, id: D3D10_MESSAGE_ID, benable: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBreakOnID<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void, id: D3D10_MESSAGE_ID, benable: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::SetBreakOnID(this, core::mem::transmute_copy(&id), core::mem::transmute_copy(&benable)).into()
             }
         }
-        unsafe extern "system" fn GetBreakOnCategory<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, category: D3D10_MESSAGE_CATEGORY) -> windows_core::BOOL {
+        unsafe extern "system" fn GetBreakOnCategory<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void, category: D3D10_MESSAGE_CATEGORY) -> windows_core::BOOL {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::GetBreakOnCategory(this, core::mem::transmute_copy(&category))
             }
         }
-        unsafe extern "system" fn GetBreakOnSeverity<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, severity: D3D10_MESSAGE_SEVERITY) -> windows_core::BOOL {
+        unsafe extern "system" fn GetBreakOnSeverity<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void, severity: D3D10_MESSAGE_SEVERITY) -> windows_core::BOOL {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::GetBreakOnSeverity(this, core::mem::transmute_copy(&severity))
             }
         }
-        unsafe extern "system" fn GetBreakOnID<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, id: D3D10_MESSAGE_ID) -> windows_core::BOOL {
+        unsafe extern "system" fn GetBreakOnID<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void, id: D3D10_MESSAGE_ID) -> windows_core::BOOL {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::GetBreakOnID(this, core::mem::transmute_copy(&id))
             }
         }
-        unsafe extern "system" fn SetMuteDebugOutput<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bmute: windows_core::BOOL) {
+        unsafe extern "system" fn SetMuteDebugOutput<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void, bmute: windows_core::BOOL) {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::SetMuteDebugOutput(this, core::mem::transmute_copy(&bmute))
             }
         }
-        unsafe extern "system" fn GetMuteDebugOutput<Identity: ID3D10InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+        unsafe extern "system" fn GetMuteDebugOutput<Identity: windows_core::IUnknownImpl + ID3D10InfoQueue_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D10InfoQueue_Impl::GetMuteDebugOutput(this)
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            SetMessageCountLimit: SetMessageCountLimit::<Identity, OFFSET>,
-            ClearStoredMessages: ClearStoredMessages::<Identity, OFFSET>,
-            GetMessage: GetMessage::<Identity, OFFSET>,
-            GetNumMessagesAllowedByStorageFilter: GetNumMessagesAllowedByStorageFilter::<Identity, OFFSET>,
-            GetNumMessagesDeniedByStorageFilter: GetNumMessagesDeniedByStorageFilter::<Identity, OFFSET>,
-            GetNumStoredMessages: GetNumStoredMessages::<Identity, OFFSET>,
-            GetNumStoredMessagesAllowedByRetrievalFilter: GetNumStoredMessagesAllowedByRetrievalFilter::<Identity, OFFSET>,
-            GetNumMessagesDiscardedByMessageCountLimit: GetNumMessagesDiscardedByMessageCountLimit::<Identity, OFFSET>,
-            GetMessageCountLimit: GetMessageCountLimit::<Identity, OFFSET>,
-            AddStorageFilterEntries: AddStorageFilterEntries::<Identity, OFFSET>,
-            GetStorageFilter: GetStorageFilter::<Identity, OFFSET>,
-            ClearStorageFilter: ClearStorageFilter::<Identity, OFFSET>,
-            PushEmptyStorageFilter: PushEmptyStorageFilter::<Identity, OFFSET>,
-            PushCopyOfStorageFilter: PushCopyOfStorageFilter::<Identity, OFFSET>,
-            PushStorageFilter: PushStorageFilter::<Identity, OFFSET>,
-            PopStorageFilter: PopStorageFilter::<Identity, OFFSET>,
-            GetStorageFilterStackSize: GetStorageFilterStackSize::<Identity, OFFSET>,
-            AddRetrievalFilterEntries: AddRetrievalFilterEntries::<Identity, OFFSET>,
-            GetRetrievalFilter: GetRetrievalFilter::<Identity, OFFSET>,
-            ClearRetrievalFilter: ClearRetrievalFilter::<Identity, OFFSET>,
-            PushEmptyRetrievalFilter: PushEmptyRetrievalFilter::<Identity, OFFSET>,
-            PushCopyOfRetrievalFilter: PushCopyOfRetrievalFilter::<Identity, OFFSET>,
-            PushRetrievalFilter: PushRetrievalFilter::<Identity, OFFSET>,
-            PopRetrievalFilter: PopRetrievalFilter::<Identity, OFFSET>,
-            GetRetrievalFilterStackSize: GetRetrievalFilterStackSize::<Identity, OFFSET>,
-            AddMessage: AddMessage::<Identity, OFFSET>,
-            AddApplicationMessage: AddApplicationMessage::<Identity, OFFSET>,
-            SetBreakOnCategory: SetBreakOnCategory::<Identity, OFFSET>,
-            SetBreakOnSeverity: SetBreakOnSeverity::<Identity, OFFSET>,
-            SetBreakOnID: SetBreakOnID::<Identity, OFFSET>,
-            GetBreakOnCategory: GetBreakOnCategory::<Identity, OFFSET>,
-            GetBreakOnSeverity: GetBreakOnSeverity::<Identity, OFFSET>,
-            GetBreakOnID: GetBreakOnID::<Identity, OFFSET>,
-            SetMuteDebugOutput: SetMuteDebugOutput::<Identity, OFFSET>,
-            GetMuteDebugOutput: GetMuteDebugOutput::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ID3D10InfoQueue as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetMessageCountLimit: SetMessageCountLimit::<Identity>,
+            ClearStoredMessages: ClearStoredMessages::<Identity>,
+            GetMessage: GetMessage::<Identity>,
+            GetNumMessagesAllowedByStorageFilter: GetNumMessagesAllowedByStorageFilter::<Identity>,
+            GetNumMessagesDeniedByStorageFilter: GetNumMessagesDeniedByStorageFilter::<Identity>,
+            GetNumStoredMessages: GetNumStoredMessages::<Identity>,
+            GetNumStoredMessagesAllowedByRetrievalFilter: GetNumStoredMessagesAllowedByRetrievalFilter::<Identity>,
+            GetNumMessagesDiscardedByMessageCountLimit: GetNumMessagesDiscardedByMessageCountLimit::<Identity>,
+            GetMessageCountLimit: GetMessageCountLimit::<Identity>,
+            AddStorageFilterEntries: AddStorageFilterEntries::<Identity>,
+            GetStorageFilter: GetStorageFilter::<Identity>,
+            ClearStorageFilter: ClearStorageFilter::<Identity>,
+            PushEmptyStorageFilter: PushEmptyStorageFilter::<Identity>,
+            PushCopyOfStorageFilter: PushCopyOfStorageFilter::<Identity>,
+            PushStorageFilter: PushStorageFilter::<Identity>,
+            PopStorageFilter: PopStorageFilter::<Identity>,
+            GetStorageFilterStackSize: GetStorageFilterStackSize::<Identity>,
+            AddRetrievalFilterEntries: AddRetrievalFilterEntries::<Identity>,
+            GetRetrievalFilter: GetRetrievalFilter::<Identity>,
+            ClearRetrievalFilter: ClearRetrievalFilter::<Identity>,
+            PushEmptyRetrievalFilter: PushEmptyRetrievalFilter::<Identity>,
+            PushCopyOfRetrievalFilter: PushCopyOfRetrievalFilter::<Identity>,
+            PushRetrievalFilter: PushRetrievalFilter::<Identity>,
+            PopRetrievalFilter: PopRetrievalFilter::<Identity>,
+            GetRetrievalFilterStackSize: GetRetrievalFilterStackSize::<Identity>,
+            AddMessage: AddMessage::<Identity>,
+            AddApplicationMessage: AddApplicationMessage::<Identity>,
+            SetBreakOnCategory: SetBreakOnCategory::<Identity>,
+            SetBreakOnSeverity: SetBreakOnSeverity::<Identity>,
+            SetBreakOnID: SetBreakOnID::<Identity>,
+            GetBreakOnCategory: GetBreakOnCategory::<Identity>,
+            GetBreakOnSeverity: GetBreakOnSeverity::<Identity>,
+            GetBreakOnID: GetBreakOnID::<Identity>,
+            SetMuteDebugOutput: SetMuteDebugOutput::<Identity>,
+            GetMuteDebugOutput: GetMuteDebugOutput::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for ID3D10InfoQueue {}
 windows_core::imp::define_interface!(ID3D10InputLayout, ID3D10InputLayout_Vtbl, 0x9b7e4c0b_342c_4106_a19f_4f2704f689f0);
@@ -7396,6 +8760,12 @@ impl ID3D10InputLayout_Vtbl {
         iid == &<ID3D10InputLayout as windows_core::Interface>::IID || iid == &<ID3D10DeviceChild as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D10InputLayout_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10InputLayout_Vtbl
+where
+    ID3D10DeviceChild_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <ID3D10DeviceChild_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for ID3D10InputLayout {}
 windows_core::imp::define_interface!(ID3D10Multithread, ID3D10Multithread_Vtbl, 0x9b7e4e00_342c_4106_a19f_4f2704f689f0);
 windows_core::imp::interface_hierarchy!(ID3D10Multithread, windows_core::IUnknown);
@@ -7468,6 +8838,44 @@ impl ID3D10Multithread_Vtbl {
         iid == &<ID3D10Multithread as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D10Multithread_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10Multithread_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Enter<Identity: windows_core::IUnknownImpl + ID3D10Multithread_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Multithread_Impl::Enter(this)
+            }
+        }
+        unsafe extern "system" fn Leave<Identity: windows_core::IUnknownImpl + ID3D10Multithread_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Multithread_Impl::Leave(this)
+            }
+        }
+        unsafe extern "system" fn SetMultithreadProtected<Identity: windows_core::IUnknownImpl + ID3D10Multithread_Impl>(this: *mut core::ffi::c_void, bmtprotect: windows_core::BOOL) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Multithread_Impl::SetMultithreadProtected(this, core::mem::transmute_copy(&bmtprotect))
+            }
+        }
+        unsafe extern "system" fn GetMultithreadProtected<Identity: windows_core::IUnknownImpl + ID3D10Multithread_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Multithread_Impl::GetMultithreadProtected(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Enter: Enter::<Identity>,
+            Leave: Leave::<Identity>,
+            SetMultithreadProtected: SetMultithreadProtected::<Identity>,
+            GetMultithreadProtected: GetMultithreadProtected::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D10Multithread {}
 windows_core::imp::define_interface!(ID3D10PixelShader, ID3D10PixelShader_Vtbl, 0x4968b601_9d00_4cde_8346_8e7f675819b6);
 impl core::ops::Deref for ID3D10PixelShader {
@@ -7493,6 +8901,12 @@ impl ID3D10PixelShader_Vtbl {
         iid == &<ID3D10PixelShader as windows_core::Interface>::IID || iid == &<ID3D10DeviceChild as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D10PixelShader_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10PixelShader_Vtbl
+where
+    ID3D10DeviceChild_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <ID3D10DeviceChild_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for ID3D10PixelShader {}
 windows_core::imp::define_interface!(ID3D10Predicate, ID3D10Predicate_Vtbl, 0x9b7e4c10_342c_4106_a19f_4f2704f689f0);
 impl core::ops::Deref for ID3D10Predicate {
@@ -7518,6 +8932,12 @@ impl ID3D10Predicate_Vtbl {
         iid == &<ID3D10Predicate as windows_core::Interface>::IID || iid == &<ID3D10DeviceChild as windows_core::Interface>::IID || iid == &<ID3D10Asynchronous as windows_core::Interface>::IID || iid == &<ID3D10Query as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D10Predicate_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10Predicate_Vtbl
+where
+    ID3D10Query_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <ID3D10Query_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for ID3D10Predicate {}
 windows_core::imp::define_interface!(ID3D10Query, ID3D10Query_Vtbl, 0x9b7e4c0e_342c_4106_a19f_4f2704f689f0);
 impl core::ops::Deref for ID3D10Query {
@@ -7561,6 +8981,20 @@ impl ID3D10Query_Vtbl {
         iid == &<ID3D10Query as windows_core::Interface>::IID || iid == &<ID3D10DeviceChild as windows_core::Interface>::IID || iid == &<ID3D10Asynchronous as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D10Query_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10Query_Vtbl
+where
+    ID3D10Asynchronous_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D10Query_Impl>(this: *mut core::ffi::c_void, pdesc: *mut D3D10_QUERY_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Query_Impl::GetDesc(this, core::mem::transmute_copy(&pdesc))
+            }
+        }
+        Self { base__: <ID3D10Asynchronous_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDesc: GetDesc::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ID3D10Query {}
 windows_core::imp::define_interface!(ID3D10RasterizerState, ID3D10RasterizerState_Vtbl, 0xa2a07292_89af_4345_be2e_c53d9fbb6e9f);
 impl core::ops::Deref for ID3D10RasterizerState {
@@ -7600,6 +9034,20 @@ impl ID3D10RasterizerState_Vtbl {
         iid == &<ID3D10RasterizerState as windows_core::Interface>::IID || iid == &<ID3D10DeviceChild as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D10RasterizerState_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10RasterizerState_Vtbl
+where
+    ID3D10DeviceChild_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D10RasterizerState_Impl>(this: *mut core::ffi::c_void, pdesc: *mut D3D10_RASTERIZER_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10RasterizerState_Impl::GetDesc(this, core::mem::transmute_copy(&pdesc))
+            }
+        }
+        Self { base__: <ID3D10DeviceChild_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDesc: GetDesc::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ID3D10RasterizerState {}
 windows_core::imp::define_interface!(ID3D10RenderTargetView, ID3D10RenderTargetView_Vtbl, 0x9b7e4c08_342c_4106_a19f_4f2704f689f0);
 impl core::ops::Deref for ID3D10RenderTargetView {
@@ -7646,6 +9094,21 @@ impl ID3D10RenderTargetView_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + ID3D10RenderTargetView_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10RenderTargetView_Vtbl
+where
+    ID3D10View_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D10RenderTargetView_Impl>(this: *mut core::ffi::c_void, pdesc: *mut D3D10_RENDER_TARGET_VIEW_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10RenderTargetView_Impl::GetDesc(this, core::mem::transmute_copy(&pdesc))
+            }
+        }
+        Self { base__: <ID3D10View_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDesc: GetDesc::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for ID3D10RenderTargetView {}
 windows_core::imp::define_interface!(ID3D10Resource, ID3D10Resource_Vtbl, 0x9b7e4c01_342c_4106_a19f_4f2704f689f0);
 impl core::ops::Deref for ID3D10Resource {
@@ -7716,6 +9179,37 @@ impl ID3D10Resource_Vtbl {
         iid == &<ID3D10Resource as windows_core::Interface>::IID || iid == &<ID3D10DeviceChild as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D10Resource_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10Resource_Vtbl
+where
+    ID3D10DeviceChild_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetType<Identity: windows_core::IUnknownImpl + ID3D10Resource_Impl>(this: *mut core::ffi::c_void, rtype: *mut D3D10_RESOURCE_DIMENSION) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Resource_Impl::GetType(this, core::mem::transmute_copy(&rtype))
+            }
+        }
+        unsafe extern "system" fn SetEvictionPriority<Identity: windows_core::IUnknownImpl + ID3D10Resource_Impl>(this: *mut core::ffi::c_void, evictionpriority: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Resource_Impl::SetEvictionPriority(this, core::mem::transmute_copy(&evictionpriority))
+            }
+        }
+        unsafe extern "system" fn GetEvictionPriority<Identity: windows_core::IUnknownImpl + ID3D10Resource_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Resource_Impl::GetEvictionPriority(this)
+            }
+        }
+        Self {
+            base__: <ID3D10DeviceChild_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetType: GetType::<Identity>,
+            SetEvictionPriority: SetEvictionPriority::<Identity>,
+            GetEvictionPriority: GetEvictionPriority::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D10Resource {}
 windows_core::imp::define_interface!(ID3D10SamplerState, ID3D10SamplerState_Vtbl, 0x9b7e4c0c_342c_4106_a19f_4f2704f689f0);
 impl core::ops::Deref for ID3D10SamplerState {
@@ -7755,6 +9249,20 @@ impl ID3D10SamplerState_Vtbl {
         iid == &<ID3D10SamplerState as windows_core::Interface>::IID || iid == &<ID3D10DeviceChild as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D10SamplerState_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10SamplerState_Vtbl
+where
+    ID3D10DeviceChild_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D10SamplerState_Impl>(this: *mut core::ffi::c_void, pdesc: *mut D3D10_SAMPLER_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10SamplerState_Impl::GetDesc(this, core::mem::transmute_copy(&pdesc))
+            }
+        }
+        Self { base__: <ID3D10DeviceChild_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDesc: GetDesc::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ID3D10SamplerState {}
 windows_core::imp::define_interface!(ID3D10ShaderReflection, ID3D10ShaderReflection_Vtbl, 0xd40e20b6_f8f7_42ad_ab20_4baf8f15dfaa);
 windows_core::imp::interface_hierarchy!(ID3D10ShaderReflection, windows_core::IUnknown);
@@ -7859,18 +9367,71 @@ impl ID3D10ShaderReflection_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            GetDesc: GetDesc::<Identity, OFFSET>,
-            GetConstantBufferByIndex: GetConstantBufferByIndex::<Identity, OFFSET>,
-            GetConstantBufferByName: GetConstantBufferByName::<Identity, OFFSET>,
-            GetResourceBindingDesc: GetResourceBindingDesc::<Identity, OFFSET>,
-            GetInputParameterDesc: GetInputParameterDesc::<Identity, OFFSET>,
-            GetOutputParameterDesc: GetOutputParameterDesc::<Identity, OFFSET>,
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            GetDesc: GetDesc::<Identity, OFFSET>,
+            GetConstantBufferByIndex: GetConstantBufferByIndex::<Identity, OFFSET>,
+            GetConstantBufferByName: GetConstantBufferByName::<Identity, OFFSET>,
+            GetResourceBindingDesc: GetResourceBindingDesc::<Identity, OFFSET>,
+            GetInputParameterDesc: GetInputParameterDesc::<Identity, OFFSET>,
+            GetOutputParameterDesc: GetOutputParameterDesc::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D10ShaderReflection as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D")]
+impl<Identity: windows_core::IUnknownImpl + ID3D10ShaderReflection_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10ShaderReflection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D10ShaderReflection_Impl>(this: *mut core::ffi::c_void, pdesc: *mut D3D10_SHADER_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10ShaderReflection_Impl::GetDesc(this, core::mem::transmute_copy(&pdesc)).into()
+            }
+        }
+        unsafe extern "system" fn GetConstantBufferByIndex<Identity: windows_core::IUnknownImpl + ID3D10ShaderReflection_Impl>(this: *mut core::ffi::c_void, index: u32) -> Option<ID3D10ShaderReflectionConstantBuffer> {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10ShaderReflection_Impl::GetConstantBufferByIndex(this, core::mem::transmute_copy(&index))
+            }
+        }
+        unsafe extern "system" fn GetConstantBufferByName<Identity: windows_core::IUnknownImpl + ID3D10ShaderReflection_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCSTR) -> Option<ID3D10ShaderReflectionConstantBuffer> {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10ShaderReflection_Impl::GetConstantBufferByName(this, core::mem::transmute(&name))
+            }
+        }
+        unsafe extern "system" fn GetResourceBindingDesc<Identity: windows_core::IUnknownImpl + ID3D10ShaderReflection_Impl>(this: *mut core::ffi::c_void, resourceindex: u32, pdesc: *mut D3D10_SHADER_INPUT_BIND_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10ShaderReflection_Impl::GetResourceBindingDesc(this, core::mem::transmute_copy(&resourceindex), core::mem::transmute_copy(&pdesc)).into()
+            }
+        }
+        unsafe extern "system" fn GetInputParameterDesc<Identity: windows_core::IUnknownImpl + ID3D10ShaderReflection_Impl>(this: *mut core::ffi::c_void, parameterindex: u32, pdesc: *mut D3D10_SIGNATURE_PARAMETER_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10ShaderReflection_Impl::GetInputParameterDesc(this, core::mem::transmute_copy(&parameterindex), core::mem::transmute_copy(&pdesc)).into()
+            }
+        }
+        unsafe extern "system" fn GetOutputParameterDesc<Identity: windows_core::IUnknownImpl + ID3D10ShaderReflection_Impl>(this: *mut core::ffi::c_void, parameterindex: u32, pdesc: *mut D3D10_SIGNATURE_PARAMETER_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10ShaderReflection_Impl::GetOutputParameterDesc(this, core::mem::transmute_copy(&parameterindex), core::mem::transmute_copy(&pdesc)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDesc: GetDesc::<Identity>,
+            GetConstantBufferByIndex: GetConstantBufferByIndex::<Identity>,
+            GetConstantBufferByName: GetConstantBufferByName::<Identity>,
+            GetResourceBindingDesc: GetResourceBindingDesc::<Identity>,
+            GetInputParameterDesc: GetInputParameterDesc::<Identity>,
+            GetOutputParameterDesc: GetOutputParameterDesc::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ID3D10ShaderReflection as windows_core::Interface>::IID
-    }
+    };
 }
 #[cfg(feature = "Win32_Graphics_Direct3D")]
 impl windows_core::RuntimeName for ID3D10ShaderReflection {}
@@ -8176,6 +9737,164 @@ impl ID3D10ShaderReflection1_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct3D")]
+impl<Identity: windows_core::IUnknownImpl + ID3D10ShaderReflection1_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10ShaderReflection1_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D10ShaderReflection1_Impl>(this: *mut core::ffi::c_void, pdesc: *mut D3D10_SHADER_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10ShaderReflection1_Impl::GetDesc(this, core::mem::transmute_copy(&pdesc)).into()
+            }
+        }
+        unsafe extern "system" fn GetConstantBufferByIndex<Identity: windows_core::IUnknownImpl + ID3D10ShaderReflection1_Impl>(this: *mut core::ffi::c_void, index: u32) -> Option<ID3D10ShaderReflectionConstantBuffer> {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10ShaderReflection1_Impl::GetConstantBufferByIndex(this, core::mem::transmute_copy(&index))
+            }
+        }
+        unsafe extern "system" fn GetConstantBufferByName<Identity: windows_core::IUnknownImpl + ID3D10ShaderReflection1_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCSTR) -> Option<ID3D10ShaderReflectionConstantBuffer> {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10ShaderReflection1_Impl::GetConstantBufferByName(this, core::mem::transmute(&name))
+            }
+        }
+        unsafe extern "system" fn GetResourceBindingDesc<Identity: windows_core::IUnknownImpl + ID3D10ShaderReflection1_Impl>(this: *mut core::ffi::c_void, resourceindex: u32, pdesc: *mut D3D10_SHADER_INPUT_BIND_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10ShaderReflection1_Impl::GetResourceBindingDesc(this, core::mem::transmute_copy(&resourceindex), core::mem::transmute_copy(&pdesc)).into()
+            }
+        }
+        unsafe extern "system" fn GetInputParameterDesc<Identity: windows_core::IUnknownImpl + ID3D10ShaderReflection1_Impl>(this: *mut core::ffi::c_void, parameterindex: u32, pdesc: *mut D3D10_SIGNATURE_PARAMETER_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10ShaderReflection1_Impl::GetInputParameterDesc(this, core::mem::transmute_copy(&parameterindex), core::mem::transmute_copy(&pdesc)).into()
+            }
+        }
+        unsafe extern "system" fn GetOutputParameterDesc<Identity: windows_core::IUnknownImpl + ID3D10ShaderReflection1_Impl>(this: *mut core::ffi::c_void, parameterindex: u32, pdesc: *mut D3D10_SIGNATURE_PARAMETER_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10ShaderReflection1_Impl::GetOutputParameterDesc(this, core::mem::transmute_copy(&parameterindex), core::mem::transmute_copy(&pdesc)).into()
+            }
+        }
+        unsafe extern "system" fn GetVariableByName<Identity: windows_core::IUnknownImpl + ID3D10ShaderReflection1_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCSTR) -> Option<ID3D10ShaderReflectionVariable> {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10ShaderReflection1_Impl::GetVariableByName(this, core::mem::transmute(&name))
+            }
+        }
+        unsafe extern "system" fn GetResourceBindingDescByName<Identity: windows_core::IUnknownImpl + ID3D10ShaderReflection1_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCSTR, pdesc: *mut D3D10_SHADER_INPUT_BIND_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10ShaderReflection1_Impl::GetResourceBindingDescByName(this, core::mem::transmute(&name), core::mem::transmute_copy(&pdesc)).into()
+            }
+        }
+        unsafe extern "system" fn GetMovInstructionCount<Identity: windows_core::IUnknownImpl + ID3D10ShaderReflection1_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D10ShaderReflection1_Impl::GetMovInstructionCount(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMovcInstructionCount<Identity: windows_core::IUnknownImpl + ID3D10ShaderReflection1_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D10ShaderReflection1_Impl::GetMovcInstructionCount(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetConversionInstructionCount<Identity: windows_core::IUnknownImpl + ID3D10ShaderReflection1_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D10ShaderReflection1_Impl::GetConversionInstructionCount(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetBitwiseInstructionCount<Identity: windows_core::IUnknownImpl + ID3D10ShaderReflection1_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D10ShaderReflection1_Impl::GetBitwiseInstructionCount(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetGSInputPrimitive<Identity: windows_core::IUnknownImpl + ID3D10ShaderReflection1_Impl>(this: *mut core::ffi::c_void, pprim: *mut super::Direct3D::D3D_PRIMITIVE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D10ShaderReflection1_Impl::GetGSInputPrimitive(this) {
+                    Ok(ok__) => {
+                        pprim.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsLevel9Shader<Identity: windows_core::IUnknownImpl + ID3D10ShaderReflection1_Impl>(this: *mut core::ffi::c_void, pblevel9shader: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D10ShaderReflection1_Impl::IsLevel9Shader(this) {
+                    Ok(ok__) => {
+                        pblevel9shader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsSampleFrequencyShader<Identity: windows_core::IUnknownImpl + ID3D10ShaderReflection1_Impl>(this: *mut core::ffi::c_void, pbsamplefrequency: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D10ShaderReflection1_Impl::IsSampleFrequencyShader(this) {
+                    Ok(ok__) => {
+                        pbsamplefrequency.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDesc: GetDesc::<Identity>,
+            GetConstantBufferByIndex: GetConstantBufferByIndex::<Identity>,
+            GetConstantBufferByName: GetConstantBufferByName::<Identity>,
+            GetResourceBindingDesc: GetResourceBindingDesc::<Identity>,
+            GetInputParameterDesc: GetInputParameterDesc::<Identity>,
+            GetOutputParameterDesc: GetOutputParameterDesc::<Identity>,
+            GetVariableByName: GetVariableByName::<Identity>,
+            GetResourceBindingDescByName: GetResourceBindingDescByName::<Identity>,
+            GetMovInstructionCount: GetMovInstructionCount::<Identity>,
+            GetMovcInstructionCount: GetMovcInstructionCount::<Identity>,
+            GetConversionInstructionCount: GetConversionInstructionCount::<Identity>,
+            GetBitwiseInstructionCount: GetBitwiseInstructionCount::<Identity>,
+            GetGSInputPrimitive: GetGSInputPrimitive::<Identity>,
+            IsLevel9Shader: IsLevel9Shader::<Identity>,
+            IsSampleFrequencyShader: IsSampleFrequencyShader::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct3D")]
 impl windows_core::RuntimeName for ID3D10ShaderReflection1 {}
 windows_core::imp::define_interface!(ID3D10ShaderReflectionConstantBuffer, ID3D10ShaderReflectionConstantBuffer_Vtbl);
 impl ID3D10ShaderReflectionConstantBuffer {
@@ -8440,6 +10159,21 @@ impl ID3D10ShaderResourceView_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D10ShaderResourceView_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10ShaderResourceView_Vtbl
+where
+    ID3D10View_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D10ShaderResourceView_Impl>(this: *mut core::ffi::c_void, pdesc: *mut D3D10_SHADER_RESOURCE_VIEW_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10ShaderResourceView_Impl::GetDesc(this, core::mem::transmute_copy(&pdesc))
+            }
+        }
+        Self { base__: <ID3D10View_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDesc: GetDesc::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
 impl windows_core::RuntimeName for ID3D10ShaderResourceView {}
 windows_core::imp::define_interface!(ID3D10ShaderResourceView1, ID3D10ShaderResourceView1_Vtbl, 0x9b7e4c87_342c_4106_a19f_4f2704f689f0);
 impl core::ops::Deref for ID3D10ShaderResourceView1 {
@@ -8486,6 +10220,21 @@ impl ID3D10ShaderResourceView1_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D10ShaderResourceView1_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10ShaderResourceView1_Vtbl
+where
+    ID3D10ShaderResourceView_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc1<Identity: windows_core::IUnknownImpl + ID3D10ShaderResourceView1_Impl>(this: *mut core::ffi::c_void, pdesc: *mut D3D10_SHADER_RESOURCE_VIEW_DESC1) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10ShaderResourceView1_Impl::GetDesc1(this, core::mem::transmute_copy(&pdesc))
+            }
+        }
+        Self { base__: <ID3D10ShaderResourceView_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDesc1: GetDesc1::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
 impl windows_core::RuntimeName for ID3D10ShaderResourceView1 {}
 windows_core::imp::define_interface!(ID3D10StateBlock, ID3D10StateBlock_Vtbl, 0x0803425a_57f5_4dd6_9465_a87570834a08);
 windows_core::imp::interface_hierarchy!(ID3D10StateBlock, windows_core::IUnknown);
@@ -8567,6 +10316,50 @@ impl ID3D10StateBlock_Vtbl {
         iid == &<ID3D10StateBlock as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D10StateBlock_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10StateBlock_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Capture<Identity: windows_core::IUnknownImpl + ID3D10StateBlock_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10StateBlock_Impl::Capture(this).into()
+            }
+        }
+        unsafe extern "system" fn Apply<Identity: windows_core::IUnknownImpl + ID3D10StateBlock_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10StateBlock_Impl::Apply(this).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseAllDeviceObjects<Identity: windows_core::IUnknownImpl + ID3D10StateBlock_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10StateBlock_Impl::ReleaseAllDeviceObjects(this).into()
+            }
+        }
+        unsafe extern "system" fn GetDevice<Identity: windows_core::IUnknownImpl + ID3D10StateBlock_Impl>(this: *mut core::ffi::c_void, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D10StateBlock_Impl::GetDevice(this) {
+                    Ok(ok__) => {
+                        ppdevice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Capture: Capture::<Identity>,
+            Apply: Apply::<Identity>,
+            ReleaseAllDeviceObjects: ReleaseAllDeviceObjects::<Identity>,
+            GetDevice: GetDevice::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D10StateBlock {}
 windows_core::imp::define_interface!(ID3D10SwitchToRef, ID3D10SwitchToRef_Vtbl, 0x9b7e4e02_342c_4106_a19f_4f2704f689f0);
 windows_core::imp::interface_hierarchy!(ID3D10SwitchToRef, windows_core::IUnknown);
@@ -8615,6 +10408,30 @@ impl ID3D10SwitchToRef_Vtbl {
         iid == &<ID3D10SwitchToRef as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D10SwitchToRef_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10SwitchToRef_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetUseRef<Identity: windows_core::IUnknownImpl + ID3D10SwitchToRef_Impl>(this: *mut core::ffi::c_void, useref: windows_core::BOOL) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10SwitchToRef_Impl::SetUseRef(this, core::mem::transmute_copy(&useref))
+            }
+        }
+        unsafe extern "system" fn GetUseRef<Identity: windows_core::IUnknownImpl + ID3D10SwitchToRef_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10SwitchToRef_Impl::GetUseRef(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetUseRef: SetUseRef::<Identity>,
+            GetUseRef: GetUseRef::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D10SwitchToRef {}
 windows_core::imp::define_interface!(ID3D10Texture1D, ID3D10Texture1D_Vtbl, 0x9b7e4c03_342c_4106_a19f_4f2704f689f0);
 impl core::ops::Deref for ID3D10Texture1D {
@@ -8688,6 +10505,38 @@ impl ID3D10Texture1D_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + ID3D10Texture1D_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10Texture1D_Vtbl
+where
+    ID3D10Resource_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Map<Identity: windows_core::IUnknownImpl + ID3D10Texture1D_Impl>(this: *mut core::ffi::c_void, subresource: u32, maptype: D3D10_MAP, mapflags: u32, ppdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Texture1D_Impl::Map(this, core::mem::transmute_copy(&subresource), core::mem::transmute_copy(&maptype), core::mem::transmute_copy(&mapflags), core::mem::transmute_copy(&ppdata)).into()
+            }
+        }
+        unsafe extern "system" fn Unmap<Identity: windows_core::IUnknownImpl + ID3D10Texture1D_Impl>(this: *mut core::ffi::c_void, subresource: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Texture1D_Impl::Unmap(this, core::mem::transmute_copy(&subresource))
+            }
+        }
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D10Texture1D_Impl>(this: *mut core::ffi::c_void, pdesc: *mut D3D10_TEXTURE1D_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Texture1D_Impl::GetDesc(this, core::mem::transmute_copy(&pdesc))
+            }
+        }
+        Self {
+            base__: <ID3D10Resource_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Map: Map::<Identity>,
+            Unmap: Unmap::<Identity>,
+            GetDesc: GetDesc::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for ID3D10Texture1D {}
 windows_core::imp::define_interface!(ID3D10Texture2D, ID3D10Texture2D_Vtbl, 0x9b7e4c04_342c_4106_a19f_4f2704f689f0);
 impl core::ops::Deref for ID3D10Texture2D {
@@ -8770,6 +10619,44 @@ impl ID3D10Texture2D_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + ID3D10Texture2D_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10Texture2D_Vtbl
+where
+    ID3D10Resource_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Map<Identity: windows_core::IUnknownImpl + ID3D10Texture2D_Impl>(this: *mut core::ffi::c_void, subresource: u32, maptype: D3D10_MAP, mapflags: u32, pmappedtex2d: *mut D3D10_MAPPED_TEXTURE2D) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D10Texture2D_Impl::Map(this, core::mem::transmute_copy(&subresource), core::mem::transmute_copy(&maptype), core::mem::transmute_copy(&mapflags)) {
+                    Ok(ok__) => {
+                        pmappedtex2d.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Unmap<Identity: windows_core::IUnknownImpl + ID3D10Texture2D_Impl>(this: *mut core::ffi::c_void, subresource: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Texture2D_Impl::Unmap(this, core::mem::transmute_copy(&subresource))
+            }
+        }
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D10Texture2D_Impl>(this: *mut core::ffi::c_void, pdesc: *mut D3D10_TEXTURE2D_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Texture2D_Impl::GetDesc(this, core::mem::transmute_copy(&pdesc))
+            }
+        }
+        Self {
+            base__: <ID3D10Resource_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Map: Map::<Identity>,
+            Unmap: Unmap::<Identity>,
+            GetDesc: GetDesc::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for ID3D10Texture2D {}
 windows_core::imp::define_interface!(ID3D10Texture3D, ID3D10Texture3D_Vtbl, 0x9b7e4c05_342c_4106_a19f_4f2704f689f0);
 impl core::ops::Deref for ID3D10Texture3D {
@@ -8852,6 +10739,44 @@ impl ID3D10Texture3D_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + ID3D10Texture3D_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10Texture3D_Vtbl
+where
+    ID3D10Resource_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Map<Identity: windows_core::IUnknownImpl + ID3D10Texture3D_Impl>(this: *mut core::ffi::c_void, subresource: u32, maptype: D3D10_MAP, mapflags: u32, pmappedtex3d: *mut D3D10_MAPPED_TEXTURE3D) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D10Texture3D_Impl::Map(this, core::mem::transmute_copy(&subresource), core::mem::transmute_copy(&maptype), core::mem::transmute_copy(&mapflags)) {
+                    Ok(ok__) => {
+                        pmappedtex3d.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Unmap<Identity: windows_core::IUnknownImpl + ID3D10Texture3D_Impl>(this: *mut core::ffi::c_void, subresource: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Texture3D_Impl::Unmap(this, core::mem::transmute_copy(&subresource))
+            }
+        }
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D10Texture3D_Impl>(this: *mut core::ffi::c_void, pdesc: *mut D3D10_TEXTURE3D_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10Texture3D_Impl::GetDesc(this, core::mem::transmute_copy(&pdesc))
+            }
+        }
+        Self {
+            base__: <ID3D10Resource_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Map: Map::<Identity>,
+            Unmap: Unmap::<Identity>,
+            GetDesc: GetDesc::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for ID3D10Texture3D {}
 windows_core::imp::define_interface!(ID3D10VertexShader, ID3D10VertexShader_Vtbl, 0x9b7e4c0a_342c_4106_a19f_4f2704f689f0);
 impl core::ops::Deref for ID3D10VertexShader {
@@ -8877,6 +10802,12 @@ impl ID3D10VertexShader_Vtbl {
         iid == &<ID3D10VertexShader as windows_core::Interface>::IID || iid == &<ID3D10DeviceChild as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D10VertexShader_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10VertexShader_Vtbl
+where
+    ID3D10DeviceChild_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <ID3D10DeviceChild_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for ID3D10VertexShader {}
 windows_core::imp::define_interface!(ID3D10View, ID3D10View_Vtbl, 0xc902b03f_60a7_49ba_9936_2a3ab37a7e33);
 impl core::ops::Deref for ID3D10View {
@@ -8920,6 +10851,20 @@ impl ID3D10View_Vtbl {
         iid == &<ID3D10View as windows_core::Interface>::IID || iid == &<ID3D10DeviceChild as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D10View_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D10View_Vtbl
+where
+    ID3D10DeviceChild_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetResource<Identity: windows_core::IUnknownImpl + ID3D10View_Impl>(this: *mut core::ffi::c_void, ppresource: *mut *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D10View_Impl::GetResource(this, core::mem::transmute_copy(&ppresource))
+            }
+        }
+        Self { base__: <ID3D10DeviceChild_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetResource: GetResource::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ID3D10View {}
 #[cfg(feature = "Win32_Graphics_Dxgi")]
 pub type PFN_D3D10_CREATE_DEVICE1 = Option<unsafe extern "system" fn(param0: windows_core::Ref<super::Dxgi::IDXGIAdapter>, param1: D3D10_DRIVER_TYPE, param2: super::super::Foundation::HMODULE, param3: u32, param4: D3D10_FEATURE_LEVEL1, param5: u32, param6: windows_core::OutRef<ID3D10Device1>) -> windows_core::HRESULT>;
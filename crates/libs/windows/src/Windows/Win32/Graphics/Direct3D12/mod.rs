@@ -8160,6 +8160,20 @@ impl ID3D12CommandAllocator_Vtbl {
         iid == &<ID3D12CommandAllocator as windows_core::Interface>::IID || iid == &<ID3D12Object as windows_core::Interface>::IID || iid == &<ID3D12DeviceChild as windows_core::Interface>::IID || iid == &<ID3D12Pageable as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12CommandAllocator_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12CommandAllocator_Vtbl
+where
+    ID3D12Pageable_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + ID3D12CommandAllocator_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12CommandAllocator_Impl::Reset(this).into()
+            }
+        }
+        Self { base__: <ID3D12Pageable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Reset: Reset::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ID3D12CommandAllocator {}
 windows_core::imp::define_interface!(ID3D12CommandList, ID3D12CommandList_Vtbl, 0x7116d91c_e7e4_47ce_b8c6_ec8168f437e5);
 impl core::ops::Deref for ID3D12CommandList {
@@ -8199,6 +8213,20 @@ impl ID3D12CommandList_Vtbl {
         iid == &<ID3D12CommandList as windows_core::Interface>::IID || iid == &<ID3D12Object as windows_core::Interface>::IID || iid == &<ID3D12DeviceChild as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12CommandList_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12CommandList_Vtbl
+where
+    ID3D12DeviceChild_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetType<Identity: windows_core::IUnknownImpl + ID3D12CommandList_Impl>(this: *mut core::ffi::c_void) -> D3D12_COMMAND_LIST_TYPE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12CommandList_Impl::GetType(this)
+            }
+        }
+        Self { base__: <ID3D12DeviceChild_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetType: GetType::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ID3D12CommandList {}
 windows_core::imp::define_interface!(ID3D12CommandQueue, ID3D12CommandQueue_Vtbl, 0x0ec870a6_5d7e_4c22_8cfc_5baae07616ed);
 impl core::ops::Deref for ID3D12CommandQueue {
@@ -8388,6 +8416,99 @@ impl ID3D12CommandQueue_Vtbl {
         iid == &<ID3D12CommandQueue as windows_core::Interface>::IID || iid == &<ID3D12Object as windows_core::Interface>::IID || iid == &<ID3D12DeviceChild as windows_core::Interface>::IID || iid == &<ID3D12Pageable as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12CommandQueue_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12CommandQueue_Vtbl
+where
+    ID3D12Pageable_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn UpdateTileMappings<Identity: windows_core::IUnknownImpl + ID3D12CommandQueue_Impl>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, numresourceregions: u32, presourceregionstartcoordinates: *const D3D12_TILED_RESOURCE_COORDINATE, presourceregionsizes: *const D3D12_TILE_REGION_SIZE, pheap: *mut core::ffi::c_void, numranges: u32, prangeflags: *const D3D12_TILE_RANGE_FLAGS, pheaprangestartoffsets: *const u32, prangetilecounts: *const u32, flags: D3D12_TILE_MAPPING_FLAGS) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12CommandQueue_Impl::UpdateTileMappings(this, core::mem::transmute_copy(&presource), core::mem::transmute_copy(&numresourceregions), core::mem::transmute_copy(&presourceregionstartcoordinates), core::mem::transmute_copy(&presourceregionsizes), core::mem::transmute_copy(&pheap), core::mem::transmute_copy(&numranges), core::mem::transmute_copy(&prangeflags), core::mem::transmute_copy(&pheaprangestartoffsets), core::mem::transmute_copy(&prangetilecounts), core::mem::transmute_copy(&flags))
+            }
+        }
+        unsafe extern "system" fn CopyTileMappings<Identity: windows_core::IUnknownImpl + ID3D12CommandQueue_Impl>(this: *mut core::ffi::c_void, pdstresource: *mut core::ffi::c_void, pdstregionstartcoordinate: *const D3D12_TILED_RESOURCE_COORDINATE, psrcresource: *mut core::ffi::c_void, psrcregionstartcoordinate: *const D3D12_TILED_RESOURCE_COORDINATE, pregionsize: *const D3D12_TILE_REGION_SIZE, flags: D3D12_TILE_MAPPING_FLAGS) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12CommandQueue_Impl::CopyTileMappings(this, core::mem::transmute_copy(&pdstresource), core::mem::transmute_copy(&pdstregionstartcoordinate), core::mem::transmute_copy(&psrcresource), core::mem::transmute_copy(&psrcregionstartcoordinate), core::mem::transmute_copy(&pregionsize), core::mem::transmute_copy(&flags))
+            }
+        }
+        unsafe extern "system" fn ExecuteCommandLists<Identity: windows_core::IUnknownImpl + ID3D12CommandQueue_Impl>(this: *mut core::ffi::c_void, numcommandlists: u32, ppcommandlists: *const *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12CommandQueue_Impl::ExecuteCommandLists(this, core::mem::transmute_copy(&numcommandlists), core::mem::transmute_copy(&ppcommandlists))
+            }
+        }
+        unsafe extern "system" fn SetMarker<Identity: windows_core::IUnknownImpl + ID3D12CommandQueue_Impl>(this: *mut core::ffi::c_void, metadata: u32, pdata: *const core::ffi::c_void, size: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12CommandQueue_Impl::SetMarker(this, core::mem::transmute_copy(&metadata), core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&size))
+            }
+        }
+        unsafe extern "system" fn BeginEvent<Identity: windows_core::IUnknownImpl + ID3D12CommandQueue_Impl>(this: *mut core::ffi::c_void, metadata: u32, pdata: *const core::ffi::c_void, size: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12CommandQueue_Impl::BeginEvent(this, core::mem::transmute_copy(&metadata), core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&size))
+            }
+        }
+        unsafe extern "system" fn EndEvent<Identity: windows_core::IUnknownImpl + ID3D12CommandQueue_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12CommandQueue_Impl::EndEvent(this)
+            }
+        }
+        unsafe extern "system" fn Signal<Identity: windows_core::IUnknownImpl + ID3D12CommandQueue_Impl>(this: *mut core::ffi::c_void, pfence: *mut core::ffi::c_void, value: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12CommandQueue_Impl::Signal(this, core::mem::transmute_copy(&pfence), core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Wait<Identity: windows_core::IUnknownImpl + ID3D12CommandQueue_Impl>(this: *mut core::ffi::c_void, pfence: *mut core::ffi::c_void, value: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12CommandQueue_Impl::Wait(this, core::mem::transmute_copy(&pfence), core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn GetTimestampFrequency<Identity: windows_core::IUnknownImpl + ID3D12CommandQueue_Impl>(this: *mut core::ffi::c_void, pfrequency: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D12CommandQueue_Impl::GetTimestampFrequency(this) {
+                    Ok(ok__) => {
+                        pfrequency.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetClockCalibration<Identity: windows_core::IUnknownImpl + ID3D12CommandQueue_Impl>(this: *mut core::ffi::c_void, pgputimestamp: *mut u64, pcputimestamp: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12CommandQueue_Impl::GetClockCalibration(this, core::mem::transmute_copy(&pgputimestamp), core::mem::transmute_copy(&pcputimestamp)).into()
+            }
+        }
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D12CommandQueue_Impl>(this: *mut core::ffi::c_void, result__: *mut D3D12_COMMAND_QUEUE_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = ID3D12CommandQueue_Impl::GetDesc(this)
+            }
+        }
+        Self {
+            base__: <ID3D12Pageable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            UpdateTileMappings: UpdateTileMappings::<Identity>,
+            CopyTileMappings: CopyTileMappings::<Identity>,
+            ExecuteCommandLists: ExecuteCommandLists::<Identity>,
+            SetMarker: SetMarker::<Identity>,
+            BeginEvent: BeginEvent::<Identity>,
+            EndEvent: EndEvent::<Identity>,
+            Signal: Signal::<Identity>,
+            Wait: Wait::<Identity>,
+            GetTimestampFrequency: GetTimestampFrequency::<Identity>,
+            GetClockCalibration: GetClockCalibration::<Identity>,
+            GetDesc: GetDesc::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12CommandQueue {}
 windows_core::imp::define_interface!(ID3D12CommandSignature, ID3D12CommandSignature_Vtbl, 0xc36a797c_ec80_4f0a_8985_a7b2475082d1);
 impl core::ops::Deref for ID3D12CommandSignature {
@@ -8413,6 +8534,12 @@ impl ID3D12CommandSignature_Vtbl {
         iid == &<ID3D12CommandSignature as windows_core::Interface>::IID || iid == &<ID3D12Object as windows_core::Interface>::IID || iid == &<ID3D12DeviceChild as windows_core::Interface>::IID || iid == &<ID3D12Pageable as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12CommandSignature_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12CommandSignature_Vtbl
+where
+    ID3D12Pageable_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <ID3D12Pageable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for ID3D12CommandSignature {}
 windows_core::imp::define_interface!(ID3D12Debug, ID3D12Debug_Vtbl, 0x344488b7_6846_474b_b989_f027448245e0);
 windows_core::imp::interface_hierarchy!(ID3D12Debug, windows_core::IUnknown);
@@ -8446,6 +8573,20 @@ impl ID3D12Debug_Vtbl {
         iid == &<ID3D12Debug as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12Debug_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Debug_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnableDebugLayer<Identity: windows_core::IUnknownImpl + ID3D12Debug_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Debug_Impl::EnableDebugLayer(this)
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, EnableDebugLayer: EnableDebugLayer::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ID3D12Debug {}
 windows_core::imp::define_interface!(ID3D12Debug1, ID3D12Debug1_Vtbl, 0xaffaa4ca_63fe_4d8e_b8ad_159000af4304);
 windows_core::imp::interface_hierarchy!(ID3D12Debug1, windows_core::IUnknown);
@@ -8506,6 +8647,37 @@ impl ID3D12Debug1_Vtbl {
         iid == &<ID3D12Debug1 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12Debug1_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Debug1_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnableDebugLayer<Identity: windows_core::IUnknownImpl + ID3D12Debug1_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Debug1_Impl::EnableDebugLayer(this)
+            }
+        }
+        unsafe extern "system" fn SetEnableGPUBasedValidation<Identity: windows_core::IUnknownImpl + ID3D12Debug1_Impl>(this: *mut core::ffi::c_void, enable: windows_core::BOOL) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Debug1_Impl::SetEnableGPUBasedValidation(this, core::mem::transmute_copy(&enable))
+            }
+        }
+        unsafe extern "system" fn SetEnableSynchronizedCommandQueueValidation<Identity: windows_core::IUnknownImpl + ID3D12Debug1_Impl>(this: *mut core::ffi::c_void, enable: windows_core::BOOL) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Debug1_Impl::SetEnableSynchronizedCommandQueueValidation(this, core::mem::transmute_copy(&enable))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnableDebugLayer: EnableDebugLayer::<Identity>,
+            SetEnableGPUBasedValidation: SetEnableGPUBasedValidation::<Identity>,
+            SetEnableSynchronizedCommandQueueValidation: SetEnableSynchronizedCommandQueueValidation::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12Debug1 {}
 windows_core::imp::define_interface!(ID3D12Debug2, ID3D12Debug2_Vtbl, 0x93a665c4_a3b2_4e5d_b692_a26ae14e3374);
 windows_core::imp::interface_hierarchy!(ID3D12Debug2, windows_core::IUnknown);
@@ -8539,6 +8711,23 @@ impl ID3D12Debug2_Vtbl {
         iid == &<ID3D12Debug2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12Debug2_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Debug2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetGPUBasedValidationFlags<Identity: windows_core::IUnknownImpl + ID3D12Debug2_Impl>(this: *mut core::ffi::c_void, flags: D3D12_GPU_BASED_VALIDATION_FLAGS) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Debug2_Impl::SetGPUBasedValidationFlags(this, core::mem::transmute_copy(&flags))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetGPUBasedValidationFlags: SetGPUBasedValidationFlags::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12Debug2 {}
 windows_core::imp::define_interface!(ID3D12Debug3, ID3D12Debug3_Vtbl, 0x5cf4e58f_f671_4ff1_a542_3686e3d153d1);
 impl core::ops::Deref for ID3D12Debug3 {
@@ -8605,6 +8794,37 @@ impl ID3D12Debug3_Vtbl {
         iid == &<ID3D12Debug3 as windows_core::Interface>::IID || iid == &<ID3D12Debug as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12Debug3_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Debug3_Vtbl
+where
+    ID3D12Debug_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetEnableGPUBasedValidation<Identity: windows_core::IUnknownImpl + ID3D12Debug3_Impl>(this: *mut core::ffi::c_void, enable: windows_core::BOOL) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Debug3_Impl::SetEnableGPUBasedValidation(this, core::mem::transmute_copy(&enable))
+            }
+        }
+        unsafe extern "system" fn SetEnableSynchronizedCommandQueueValidation<Identity: windows_core::IUnknownImpl + ID3D12Debug3_Impl>(this: *mut core::ffi::c_void, enable: windows_core::BOOL) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Debug3_Impl::SetEnableSynchronizedCommandQueueValidation(this, core::mem::transmute_copy(&enable))
+            }
+        }
+        unsafe extern "system" fn SetGPUBasedValidationFlags<Identity: windows_core::IUnknownImpl + ID3D12Debug3_Impl>(this: *mut core::ffi::c_void, flags: D3D12_GPU_BASED_VALIDATION_FLAGS) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Debug3_Impl::SetGPUBasedValidationFlags(this, core::mem::transmute_copy(&flags))
+            }
+        }
+        Self {
+            base__: <ID3D12Debug_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetEnableGPUBasedValidation: SetEnableGPUBasedValidation::<Identity>,
+            SetEnableSynchronizedCommandQueueValidation: SetEnableSynchronizedCommandQueueValidation::<Identity>,
+            SetGPUBasedValidationFlags: SetGPUBasedValidationFlags::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12Debug3 {}
 windows_core::imp::define_interface!(ID3D12Debug4, ID3D12Debug4_Vtbl, 0x014b816e_9ec5_4a2f_a845_ffbe441ce13a);
 impl core::ops::Deref for ID3D12Debug4 {
@@ -8644,6 +8864,20 @@ impl ID3D12Debug4_Vtbl {
         iid == &<ID3D12Debug4 as windows_core::Interface>::IID || iid == &<ID3D12Debug as windows_core::Interface>::IID || iid == &<ID3D12Debug3 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12Debug4_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Debug4_Vtbl
+where
+    ID3D12Debug3_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DisableDebugLayer<Identity: windows_core::IUnknownImpl + ID3D12Debug4_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Debug4_Impl::DisableDebugLayer(this)
+            }
+        }
+        Self { base__: <ID3D12Debug3_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, DisableDebugLayer: DisableDebugLayer::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ID3D12Debug4 {}
 windows_core::imp::define_interface!(ID3D12Debug5, ID3D12Debug5_Vtbl, 0x548d6b12_09fa_40e0_9069_5dcd589a52c9);
 impl core::ops::Deref for ID3D12Debug5 {
@@ -8683,6 +8917,20 @@ impl ID3D12Debug5_Vtbl {
         iid == &<ID3D12Debug5 as windows_core::Interface>::IID || iid == &<ID3D12Debug as windows_core::Interface>::IID || iid == &<ID3D12Debug3 as windows_core::Interface>::IID || iid == &<ID3D12Debug4 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12Debug5_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Debug5_Vtbl
+where
+    ID3D12Debug4_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetEnableAutoName<Identity: windows_core::IUnknownImpl + ID3D12Debug5_Impl>(this: *mut core::ffi::c_void, enable: windows_core::BOOL) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Debug5_Impl::SetEnableAutoName(this, core::mem::transmute_copy(&enable))
+            }
+        }
+        Self { base__: <ID3D12Debug4_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetEnableAutoName: SetEnableAutoName::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ID3D12Debug5 {}
 windows_core::imp::define_interface!(ID3D12Debug6, ID3D12Debug6_Vtbl, 0x82a816d6_5d01_4157_97d0_4975463fd1ed);
 impl core::ops::Deref for ID3D12Debug6 {
@@ -8722,6 +8970,23 @@ impl ID3D12Debug6_Vtbl {
         iid == &<ID3D12Debug6 as windows_core::Interface>::IID || iid == &<ID3D12Debug as windows_core::Interface>::IID || iid == &<ID3D12Debug3 as windows_core::Interface>::IID || iid == &<ID3D12Debug4 as windows_core::Interface>::IID || iid == &<ID3D12Debug5 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12Debug6_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Debug6_Vtbl
+where
+    ID3D12Debug5_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetForceLegacyBarrierValidation<Identity: windows_core::IUnknownImpl + ID3D12Debug6_Impl>(this: *mut core::ffi::c_void, enable: windows_core::BOOL) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Debug6_Impl::SetForceLegacyBarrierValidation(this, core::mem::transmute_copy(&enable))
+            }
+        }
+        Self {
+            base__: <ID3D12Debug5_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetForceLegacyBarrierValidation: SetForceLegacyBarrierValidation::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12Debug6 {}
 windows_core::imp::define_interface!(ID3D12DebugCommandList, ID3D12DebugCommandList_Vtbl, 0x09e0bf36_54ac_484f_8847_4baeeab6053f);
 windows_core::imp::interface_hierarchy!(ID3D12DebugCommandList, windows_core::IUnknown);
@@ -8785,6 +9050,37 @@ impl ID3D12DebugCommandList_Vtbl {
         iid == &<ID3D12DebugCommandList as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12DebugCommandList_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12DebugCommandList_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AssertResourceState<Identity: windows_core::IUnknownImpl + ID3D12DebugCommandList_Impl>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, subresource: u32, state: u32) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DebugCommandList_Impl::AssertResourceState(this, core::mem::transmute_copy(&presource), core::mem::transmute_copy(&subresource), core::mem::transmute_copy(&state))
+            }
+        }
+        unsafe extern "system" fn SetFeatureMask<Identity: windows_core::IUnknownImpl + ID3D12DebugCommandList_Impl>(this: *mut core::ffi::c_void, mask: D3D12_DEBUG_FEATURE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DebugCommandList_Impl::SetFeatureMask(this, core::mem::transmute_copy(&mask)).into()
+            }
+        }
+        unsafe extern "system" fn GetFeatureMask<Identity: windows_core::IUnknownImpl + ID3D12DebugCommandList_Impl>(this: *mut core::ffi::c_void) -> D3D12_DEBUG_FEATURE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DebugCommandList_Impl::GetFeatureMask(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AssertResourceState: AssertResourceState::<Identity>,
+            SetFeatureMask: SetFeatureMask::<Identity>,
+            GetFeatureMask: GetFeatureMask::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12DebugCommandList {}
 windows_core::imp::define_interface!(ID3D12DebugCommandList1, ID3D12DebugCommandList1_Vtbl, 0x102ca951_311b_4b01_b11f_ecb83e061b37);
 windows_core::imp::interface_hierarchy!(ID3D12DebugCommandList1, windows_core::IUnknown);
@@ -8848,6 +9144,37 @@ impl ID3D12DebugCommandList1_Vtbl {
         iid == &<ID3D12DebugCommandList1 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12DebugCommandList1_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12DebugCommandList1_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AssertResourceState<Identity: windows_core::IUnknownImpl + ID3D12DebugCommandList1_Impl>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, subresource: u32, state: u32) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DebugCommandList1_Impl::AssertResourceState(this, core::mem::transmute_copy(&presource), core::mem::transmute_copy(&subresource), core::mem::transmute_copy(&state))
+            }
+        }
+        unsafe extern "system" fn SetDebugParameter<Identity: windows_core::IUnknownImpl + ID3D12DebugCommandList1_Impl>(this: *mut core::ffi::c_void, r#type: D3D12_DEBUG_COMMAND_LIST_PARAMETER_TYPE, pdata: *const core::ffi::c_void, datasize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DebugCommandList1_Impl::SetDebugParameter(this, core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&datasize)).into()
+            }
+        }
+        unsafe extern "system" fn GetDebugParameter<Identity: windows_core::IUnknownImpl + ID3D12DebugCommandList1_Impl>(this: *mut core::ffi::c_void, r#type: D3D12_DEBUG_COMMAND_LIST_PARAMETER_TYPE, pdata: *mut core::ffi::c_void, datasize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DebugCommandList1_Impl::GetDebugParameter(this, core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&datasize)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AssertResourceState: AssertResourceState::<Identity>,
+            SetDebugParameter: SetDebugParameter::<Identity>,
+            GetDebugParameter: GetDebugParameter::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12DebugCommandList1 {}
 windows_core::imp::define_interface!(ID3D12DebugCommandList2, ID3D12DebugCommandList2_Vtbl, 0xaeb575cf_4e06_48be_ba3b_c450fc96652e);
 impl core::ops::Deref for ID3D12DebugCommandList2 {
@@ -8902,6 +9229,30 @@ impl ID3D12DebugCommandList2_Vtbl {
         iid == &<ID3D12DebugCommandList2 as windows_core::Interface>::IID || iid == &<ID3D12DebugCommandList as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12DebugCommandList2_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12DebugCommandList2_Vtbl
+where
+    ID3D12DebugCommandList_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetDebugParameter<Identity: windows_core::IUnknownImpl + ID3D12DebugCommandList2_Impl>(this: *mut core::ffi::c_void, r#type: D3D12_DEBUG_COMMAND_LIST_PARAMETER_TYPE, pdata: *const core::ffi::c_void, datasize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DebugCommandList2_Impl::SetDebugParameter(this, core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&datasize)).into()
+            }
+        }
+        unsafe extern "system" fn GetDebugParameter<Identity: windows_core::IUnknownImpl + ID3D12DebugCommandList2_Impl>(this: *mut core::ffi::c_void, r#type: D3D12_DEBUG_COMMAND_LIST_PARAMETER_TYPE, pdata: *mut core::ffi::c_void, datasize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DebugCommandList2_Impl::GetDebugParameter(this, core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&datasize)).into()
+            }
+        }
+        Self {
+            base__: <ID3D12DebugCommandList_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetDebugParameter: SetDebugParameter::<Identity>,
+            GetDebugParameter: GetDebugParameter::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12DebugCommandList2 {}
 windows_core::imp::define_interface!(ID3D12DebugCommandList3, ID3D12DebugCommandList3_Vtbl, 0x197d5e15_4d37_4d34_af78_724cd70fdb1f);
 impl core::ops::Deref for ID3D12DebugCommandList3 {
@@ -8962,6 +9313,30 @@ impl ID3D12DebugCommandList3_Vtbl {
         iid == &<ID3D12DebugCommandList3 as windows_core::Interface>::IID || iid == &<ID3D12DebugCommandList as windows_core::Interface>::IID || iid == &<ID3D12DebugCommandList2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12DebugCommandList3_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12DebugCommandList3_Vtbl
+where
+    ID3D12DebugCommandList2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AssertResourceAccess<Identity: windows_core::IUnknownImpl + ID3D12DebugCommandList3_Impl>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, subresource: u32, access: D3D12_BARRIER_ACCESS) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DebugCommandList3_Impl::AssertResourceAccess(this, core::mem::transmute_copy(&presource), core::mem::transmute_copy(&subresource), core::mem::transmute_copy(&access))
+            }
+        }
+        unsafe extern "system" fn AssertTextureLayout<Identity: windows_core::IUnknownImpl + ID3D12DebugCommandList3_Impl>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, subresource: u32, layout: D3D12_BARRIER_LAYOUT) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DebugCommandList3_Impl::AssertTextureLayout(this, core::mem::transmute_copy(&presource), core::mem::transmute_copy(&subresource), core::mem::transmute_copy(&layout))
+            }
+        }
+        Self {
+            base__: <ID3D12DebugCommandList2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AssertResourceAccess: AssertResourceAccess::<Identity>,
+            AssertTextureLayout: AssertTextureLayout::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12DebugCommandList3 {}
 windows_core::imp::define_interface!(ID3D12DebugCommandQueue, ID3D12DebugCommandQueue_Vtbl, 0x09e0bf36_54ac_484f_8847_4baeeab6053a);
 windows_core::imp::interface_hierarchy!(ID3D12DebugCommandQueue, windows_core::IUnknown);
@@ -8998,6 +9373,23 @@ impl ID3D12DebugCommandQueue_Vtbl {
         iid == &<ID3D12DebugCommandQueue as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12DebugCommandQueue_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12DebugCommandQueue_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AssertResourceState<Identity: windows_core::IUnknownImpl + ID3D12DebugCommandQueue_Impl>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, subresource: u32, state: u32) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DebugCommandQueue_Impl::AssertResourceState(this, core::mem::transmute_copy(&presource), core::mem::transmute_copy(&subresource), core::mem::transmute_copy(&state))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AssertResourceState: AssertResourceState::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12DebugCommandQueue {}
 windows_core::imp::define_interface!(ID3D12DebugCommandQueue1, ID3D12DebugCommandQueue1_Vtbl, 0x16be35a2_bfd6_49f2_bcae_eaae4aff862d);
 impl core::ops::Deref for ID3D12DebugCommandQueue1 {
@@ -9058,6 +9450,30 @@ impl ID3D12DebugCommandQueue1_Vtbl {
         iid == &<ID3D12DebugCommandQueue1 as windows_core::Interface>::IID || iid == &<ID3D12DebugCommandQueue as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12DebugCommandQueue1_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12DebugCommandQueue1_Vtbl
+where
+    ID3D12DebugCommandQueue_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AssertResourceAccess<Identity: windows_core::IUnknownImpl + ID3D12DebugCommandQueue1_Impl>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, subresource: u32, access: D3D12_BARRIER_ACCESS) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DebugCommandQueue1_Impl::AssertResourceAccess(this, core::mem::transmute_copy(&presource), core::mem::transmute_copy(&subresource), core::mem::transmute_copy(&access))
+            }
+        }
+        unsafe extern "system" fn AssertTextureLayout<Identity: windows_core::IUnknownImpl + ID3D12DebugCommandQueue1_Impl>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, subresource: u32, layout: D3D12_BARRIER_LAYOUT) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DebugCommandQueue1_Impl::AssertTextureLayout(this, core::mem::transmute_copy(&presource), core::mem::transmute_copy(&subresource), core::mem::transmute_copy(&layout))
+            }
+        }
+        Self {
+            base__: <ID3D12DebugCommandQueue_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AssertResourceAccess: AssertResourceAccess::<Identity>,
+            AssertTextureLayout: AssertTextureLayout::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12DebugCommandQueue1 {}
 windows_core::imp::define_interface!(ID3D12DebugDevice, ID3D12DebugDevice_Vtbl, 0x3febd6dd_4973_4787_8194_e45f9e28923e);
 windows_core::imp::interface_hierarchy!(ID3D12DebugDevice, windows_core::IUnknown);
@@ -9118,6 +9534,37 @@ impl ID3D12DebugDevice_Vtbl {
         iid == &<ID3D12DebugDevice as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12DebugDevice_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12DebugDevice_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetFeatureMask<Identity: windows_core::IUnknownImpl + ID3D12DebugDevice_Impl>(this: *mut core::ffi::c_void, mask: D3D12_DEBUG_FEATURE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DebugDevice_Impl::SetFeatureMask(this, core::mem::transmute_copy(&mask)).into()
+            }
+        }
+        unsafe extern "system" fn GetFeatureMask<Identity: windows_core::IUnknownImpl + ID3D12DebugDevice_Impl>(this: *mut core::ffi::c_void) -> D3D12_DEBUG_FEATURE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DebugDevice_Impl::GetFeatureMask(this)
+            }
+        }
+        unsafe extern "system" fn ReportLiveDeviceObjects<Identity: windows_core::IUnknownImpl + ID3D12DebugDevice_Impl>(this: *mut core::ffi::c_void, flags: D3D12_RLDO_FLAGS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DebugDevice_Impl::ReportLiveDeviceObjects(this, core::mem::transmute_copy(&flags)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetFeatureMask: SetFeatureMask::<Identity>,
+            GetFeatureMask: GetFeatureMask::<Identity>,
+            ReportLiveDeviceObjects: ReportLiveDeviceObjects::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12DebugDevice {}
 windows_core::imp::define_interface!(ID3D12DebugDevice1, ID3D12DebugDevice1_Vtbl, 0xa9b71770_d099_4a65_a698_3dee10020f88);
 windows_core::imp::interface_hierarchy!(ID3D12DebugDevice1, windows_core::IUnknown);
@@ -9178,17 +9625,48 @@ impl ID3D12DebugDevice1_Vtbl {
         iid == &<ID3D12DebugDevice1 as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for ID3D12DebugDevice1 {}
-windows_core::imp::define_interface!(ID3D12DebugDevice2, ID3D12DebugDevice2_Vtbl, 0x60eccbc1_378d_4df1_894c_f8ac5ce4d7dd);
-impl core::ops::Deref for ID3D12DebugDevice2 {
-    type Target = ID3D12DebugDevice;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(ID3D12DebugDevice2, windows_core::IUnknown, ID3D12DebugDevice);
-impl ID3D12DebugDevice2 {
-    pub unsafe fn SetDebugParameter(&self, r#type: D3D12_DEBUG_DEVICE_PARAMETER_TYPE, pdata: *const core::ffi::c_void, datasize: u32) -> windows_core::Result<()> {
+impl<Identity: windows_core::IUnknownImpl + ID3D12DebugDevice1_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12DebugDevice1_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetDebugParameter<Identity: windows_core::IUnknownImpl + ID3D12DebugDevice1_Impl>(this: *mut core::ffi::c_void, r#type: D3D12_DEBUG_DEVICE_PARAMETER_TYPE, pdata: *const core::ffi::c_void, datasize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DebugDevice1_Impl::SetDebugParameter(this, core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&datasize)).into()
+            }
+        }
+        unsafe extern "system" fn GetDebugParameter<Identity: windows_core::IUnknownImpl + ID3D12DebugDevice1_Impl>(this: *mut core::ffi::c_void, r#type: D3D12_DEBUG_DEVICE_PARAMETER_TYPE, pdata: *mut core::ffi::c_void, datasize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DebugDevice1_Impl::GetDebugParameter(this, core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&datasize)).into()
+            }
+        }
+        unsafe extern "system" fn ReportLiveDeviceObjects<Identity: windows_core::IUnknownImpl + ID3D12DebugDevice1_Impl>(this: *mut core::ffi::c_void, flags: D3D12_RLDO_FLAGS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DebugDevice1_Impl::ReportLiveDeviceObjects(this, core::mem::transmute_copy(&flags)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetDebugParameter: SetDebugParameter::<Identity>,
+            GetDebugParameter: GetDebugParameter::<Identity>,
+            ReportLiveDeviceObjects: ReportLiveDeviceObjects::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for ID3D12DebugDevice1 {}
+windows_core::imp::define_interface!(ID3D12DebugDevice2, ID3D12DebugDevice2_Vtbl, 0x60eccbc1_378d_4df1_894c_f8ac5ce4d7dd);
+impl core::ops::Deref for ID3D12DebugDevice2 {
+    type Target = ID3D12DebugDevice;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(ID3D12DebugDevice2, windows_core::IUnknown, ID3D12DebugDevice);
+impl ID3D12DebugDevice2 {
+    pub unsafe fn SetDebugParameter(&self, r#type: D3D12_DEBUG_DEVICE_PARAMETER_TYPE, pdata: *const core::ffi::c_void, datasize: u32) -> windows_core::Result<()> {
         unsafe { (windows_core::Interface::vtable(self).SetDebugParameter)(windows_core::Interface::as_raw(self), r#type, pdata, datasize).ok() }
     }
     pub unsafe fn GetDebugParameter(&self, r#type: D3D12_DEBUG_DEVICE_PARAMETER_TYPE, pdata: *mut core::ffi::c_void, datasize: u32) -> windows_core::Result<()> {
@@ -9232,6 +9710,30 @@ impl ID3D12DebugDevice2_Vtbl {
         iid == &<ID3D12DebugDevice2 as windows_core::Interface>::IID || iid == &<ID3D12DebugDevice as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12DebugDevice2_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12DebugDevice2_Vtbl
+where
+    ID3D12DebugDevice_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetDebugParameter<Identity: windows_core::IUnknownImpl + ID3D12DebugDevice2_Impl>(this: *mut core::ffi::c_void, r#type: D3D12_DEBUG_DEVICE_PARAMETER_TYPE, pdata: *const core::ffi::c_void, datasize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DebugDevice2_Impl::SetDebugParameter(this, core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&datasize)).into()
+            }
+        }
+        unsafe extern "system" fn GetDebugParameter<Identity: windows_core::IUnknownImpl + ID3D12DebugDevice2_Impl>(this: *mut core::ffi::c_void, r#type: D3D12_DEBUG_DEVICE_PARAMETER_TYPE, pdata: *mut core::ffi::c_void, datasize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DebugDevice2_Impl::GetDebugParameter(this, core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&datasize)).into()
+            }
+        }
+        Self {
+            base__: <ID3D12DebugDevice_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetDebugParameter: SetDebugParameter::<Identity>,
+            GetDebugParameter: GetDebugParameter::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12DebugDevice2 {}
 windows_core::imp::define_interface!(ID3D12DescriptorHeap, ID3D12DescriptorHeap_Vtbl, 0x8efb471d_616c_4f49_90f7_127bb763fa51);
 impl core::ops::Deref for ID3D12DescriptorHeap {
@@ -9310,6 +9812,37 @@ impl ID3D12DescriptorHeap_Vtbl {
         iid == &<ID3D12DescriptorHeap as windows_core::Interface>::IID || iid == &<ID3D12Object as windows_core::Interface>::IID || iid == &<ID3D12DeviceChild as windows_core::Interface>::IID || iid == &<ID3D12Pageable as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12DescriptorHeap_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12DescriptorHeap_Vtbl
+where
+    ID3D12Pageable_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D12DescriptorHeap_Impl>(this: *mut core::ffi::c_void, result__: *mut D3D12_DESCRIPTOR_HEAP_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = ID3D12DescriptorHeap_Impl::GetDesc(this)
+            }
+        }
+        unsafe extern "system" fn GetCPUDescriptorHandleForHeapStart<Identity: windows_core::IUnknownImpl + ID3D12DescriptorHeap_Impl>(this: *mut core::ffi::c_void, result__: *mut D3D12_CPU_DESCRIPTOR_HANDLE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = ID3D12DescriptorHeap_Impl::GetCPUDescriptorHandleForHeapStart(this)
+            }
+        }
+        unsafe extern "system" fn GetGPUDescriptorHandleForHeapStart<Identity: windows_core::IUnknownImpl + ID3D12DescriptorHeap_Impl>(this: *mut core::ffi::c_void, result__: *mut D3D12_GPU_DESCRIPTOR_HANDLE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = ID3D12DescriptorHeap_Impl::GetGPUDescriptorHandleForHeapStart(this)
+            }
+        }
+        Self {
+            base__: <ID3D12Pageable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDesc: GetDesc::<Identity>,
+            GetCPUDescriptorHandleForHeapStart: GetCPUDescriptorHandleForHeapStart::<Identity>,
+            GetGPUDescriptorHandleForHeapStart: GetGPUDescriptorHandleForHeapStart::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12DescriptorHeap {}
 windows_core::imp::define_interface!(ID3D12Device, ID3D12Device_Vtbl, 0x189819f1_1db6_4b57_be54_1821339b85f7);
 impl core::ops::Deref for ID3D12Device {
@@ -9941,6 +10474,288 @@ impl ID3D12Device_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Device_Vtbl
+where
+    ID3D12Object_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetNodeCount<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::GetNodeCount(this)
+            }
+        }
+        unsafe extern "system" fn CreateCommandQueue<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_COMMAND_QUEUE_DESC, riid: *const windows_core::GUID, ppcommandqueue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::CreateCommandQueue(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppcommandqueue)).into()
+            }
+        }
+        unsafe extern "system" fn CreateCommandAllocator<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, r#type: D3D12_COMMAND_LIST_TYPE, riid: *const windows_core::GUID, ppcommandallocator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::CreateCommandAllocator(this, core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppcommandallocator)).into()
+            }
+        }
+        unsafe extern "system" fn CreateGraphicsPipelineState<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_GRAPHICS_PIPELINE_STATE_DESC, riid: *const windows_core::GUID, pppipelinestate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::CreateGraphicsPipelineState(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&pppipelinestate)).into()
+            }
+        }
+        unsafe extern "system" fn CreateComputePipelineState<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_COMPUTE_PIPELINE_STATE_DESC, riid: *const windows_core::GUID, pppipelinestate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::CreateComputePipelineState(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&pppipelinestate)).into()
+            }
+        }
+        unsafe extern "system" fn CreateCommandList<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, nodemask: u32, r#type: D3D12_COMMAND_LIST_TYPE, pcommandallocator: *mut core::ffi::c_void, pinitialstate: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppcommandlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::CreateCommandList(this, core::mem::transmute_copy(&nodemask), core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pcommandallocator), core::mem::transmute_copy(&pinitialstate), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppcommandlist)).into()
+            }
+        }
+        unsafe extern "system" fn CheckFeatureSupport<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, feature: D3D12_FEATURE, pfeaturesupportdata: *mut core::ffi::c_void, featuresupportdatasize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::CheckFeatureSupport(this, core::mem::transmute_copy(&feature), core::mem::transmute_copy(&pfeaturesupportdata), core::mem::transmute_copy(&featuresupportdatasize)).into()
+            }
+        }
+        unsafe extern "system" fn CreateDescriptorHeap<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, pdescriptorheapdesc: *const D3D12_DESCRIPTOR_HEAP_DESC, riid: *const windows_core::GUID, ppvheap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::CreateDescriptorHeap(this, core::mem::transmute_copy(&pdescriptorheapdesc), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvheap)).into()
+            }
+        }
+        unsafe extern "system" fn GetDescriptorHandleIncrementSize<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, descriptorheaptype: D3D12_DESCRIPTOR_HEAP_TYPE) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::GetDescriptorHandleIncrementSize(this, core::mem::transmute_copy(&descriptorheaptype))
+            }
+        }
+        unsafe extern "system" fn CreateRootSignature<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, nodemask: u32, pblobwithrootsignature: *const core::ffi::c_void, bloblengthinbytes: usize, riid: *const windows_core::GUID, ppvrootsignature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::CreateRootSignature(this, core::mem::transmute_copy(&nodemask), core::mem::transmute_copy(&pblobwithrootsignature), core::mem::transmute_copy(&bloblengthinbytes), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvrootsignature)).into()
+            }
+        }
+        unsafe extern "system" fn CreateConstantBufferView<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_CONSTANT_BUFFER_VIEW_DESC, destdescriptor: D3D12_CPU_DESCRIPTOR_HANDLE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::CreateConstantBufferView(this, core::mem::transmute_copy(&pdesc), core::mem::transmute(&destdescriptor))
+            }
+        }
+        unsafe extern "system" fn CreateShaderResourceView<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc: *const D3D12_SHADER_RESOURCE_VIEW_DESC, destdescriptor: D3D12_CPU_DESCRIPTOR_HANDLE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::CreateShaderResourceView(this, core::mem::transmute_copy(&presource), core::mem::transmute_copy(&pdesc), core::mem::transmute(&destdescriptor))
+            }
+        }
+        unsafe extern "system" fn CreateUnorderedAccessView<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pcounterresource: *mut core::ffi::c_void, pdesc: *const D3D12_UNORDERED_ACCESS_VIEW_DESC, destdescriptor: D3D12_CPU_DESCRIPTOR_HANDLE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::CreateUnorderedAccessView(this, core::mem::transmute_copy(&presource), core::mem::transmute_copy(&pcounterresource), core::mem::transmute_copy(&pdesc), core::mem::transmute(&destdescriptor))
+            }
+        }
+        unsafe extern "system" fn CreateRenderTargetView<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc: *const D3D12_RENDER_TARGET_VIEW_DESC, destdescriptor: D3D12_CPU_DESCRIPTOR_HANDLE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::CreateRenderTargetView(this, core::mem::transmute_copy(&presource), core::mem::transmute_copy(&pdesc), core::mem::transmute(&destdescriptor))
+            }
+        }
+        unsafe extern "system" fn CreateDepthStencilView<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc: *const D3D12_DEPTH_STENCIL_VIEW_DESC, destdescriptor: D3D12_CPU_DESCRIPTOR_HANDLE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::CreateDepthStencilView(this, core::mem::transmute_copy(&presource), core::mem::transmute_copy(&pdesc), core::mem::transmute(&destdescriptor))
+            }
+        }
+        unsafe extern "system" fn CreateSampler<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_SAMPLER_DESC, destdescriptor: D3D12_CPU_DESCRIPTOR_HANDLE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::CreateSampler(this, core::mem::transmute_copy(&pdesc), core::mem::transmute(&destdescriptor))
+            }
+        }
+        unsafe extern "system" fn CopyDescriptors<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, numdestdescriptorranges: u32, pdestdescriptorrangestarts: *const D3D12_CPU_DESCRIPTOR_HANDLE, pdestdescriptorrangesizes: *const u32, numsrcdescriptorranges: u32, psrcdescriptorrangestarts: *const D3D12_CPU_DESCRIPTOR_HANDLE, psrcdescriptorrangesizes: *const u32, descriptorheapstype: D3D12_DESCRIPTOR_HEAP_TYPE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::CopyDescriptors(this, core::mem::transmute_copy(&numdestdescriptorranges), core::mem::transmute_copy(&pdestdescriptorrangestarts), core::mem::transmute_copy(&pdestdescriptorrangesizes), core::mem::transmute_copy(&numsrcdescriptorranges), core::mem::transmute_copy(&psrcdescriptorrangestarts), core::mem::transmute_copy(&psrcdescriptorrangesizes), core::mem::transmute_copy(&descriptorheapstype))
+            }
+        }
+        unsafe extern "system" fn CopyDescriptorsSimple<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, numdescriptors: u32, destdescriptorrangestart: D3D12_CPU_DESCRIPTOR_HANDLE, srcdescriptorrangestart: D3D12_CPU_DESCRIPTOR_HANDLE, descriptorheapstype: D3D12_DESCRIPTOR_HEAP_TYPE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::CopyDescriptorsSimple(this, core::mem::transmute_copy(&numdescriptors), core::mem::transmute(&destdescriptorrangestart), core::mem::transmute(&srcdescriptorrangestart), core::mem::transmute_copy(&descriptorheapstype))
+            }
+        }
+        unsafe extern "system" fn GetResourceAllocationInfo<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, result__: *mut D3D12_RESOURCE_ALLOCATION_INFO, visiblemask: u32, numresourcedescs: u32, presourcedescs: *const D3D12_RESOURCE_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = ID3D12Device_Impl::GetResourceAllocationInfo(this, core::mem::transmute_copy(&visiblemask), core::mem::transmute_copy(&numresourcedescs), core::mem::transmute_copy(&presourcedescs))
+            }
+        }
+        unsafe extern "system" fn GetCustomHeapProperties<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, result__: *mut D3D12_HEAP_PROPERTIES, nodemask: u32, heaptype: D3D12_HEAP_TYPE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = ID3D12Device_Impl::GetCustomHeapProperties(this, core::mem::transmute_copy(&nodemask), core::mem::transmute_copy(&heaptype))
+            }
+        }
+        unsafe extern "system" fn CreateCommittedResource<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, pheapproperties: *const D3D12_HEAP_PROPERTIES, heapflags: D3D12_HEAP_FLAGS, pdesc: *const D3D12_RESOURCE_DESC, initialresourcestate: D3D12_RESOURCE_STATES, poptimizedclearvalue: *const D3D12_CLEAR_VALUE, riidresource: *const windows_core::GUID, ppvresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::CreateCommittedResource(this, core::mem::transmute_copy(&pheapproperties), core::mem::transmute_copy(&heapflags), core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&initialresourcestate), core::mem::transmute_copy(&poptimizedclearvalue), core::mem::transmute_copy(&riidresource), core::mem::transmute_copy(&ppvresource)).into()
+            }
+        }
+        unsafe extern "system" fn CreateHeap<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_HEAP_DESC, riid: *const windows_core::GUID, ppvheap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::CreateHeap(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvheap)).into()
+            }
+        }
+        unsafe extern "system" fn CreatePlacedResource<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, pheap: *mut core::ffi::c_void, heapoffset: u64, pdesc: *const D3D12_RESOURCE_DESC, initialstate: D3D12_RESOURCE_STATES, poptimizedclearvalue: *const D3D12_CLEAR_VALUE, riid: *const windows_core::GUID, ppvresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::CreatePlacedResource(this, core::mem::transmute_copy(&pheap), core::mem::transmute_copy(&heapoffset), core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&initialstate), core::mem::transmute_copy(&poptimizedclearvalue), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvresource)).into()
+            }
+        }
+        unsafe extern "system" fn CreateReservedResource<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_RESOURCE_DESC, initialstate: D3D12_RESOURCE_STATES, poptimizedclearvalue: *const D3D12_CLEAR_VALUE, riid: *const windows_core::GUID, ppvresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::CreateReservedResource(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&initialstate), core::mem::transmute_copy(&poptimizedclearvalue), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvresource)).into()
+            }
+        }
+        unsafe extern "system" fn CreateSharedHandle<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, pobject: *mut core::ffi::c_void, pattributes: *const super::super::Security::SECURITY_ATTRIBUTES, access: u32, name: windows_core::PCWSTR, phandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D12Device_Impl::CreateSharedHandle(this, core::mem::transmute_copy(&pobject), core::mem::transmute_copy(&pattributes), core::mem::transmute_copy(&access), core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        phandle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OpenSharedHandle<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, nthandle: super::super::Foundation::HANDLE, riid: *const windows_core::GUID, ppvobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::OpenSharedHandle(this, core::mem::transmute_copy(&nthandle), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvobj)).into()
+            }
+        }
+        unsafe extern "system" fn OpenSharedHandleByName<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, access: u32, pnthandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D12Device_Impl::OpenSharedHandleByName(this, core::mem::transmute(&name), core::mem::transmute_copy(&access)) {
+                    Ok(ok__) => {
+                        pnthandle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MakeResident<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, numobjects: u32, ppobjects: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::MakeResident(this, core::mem::transmute_copy(&numobjects), core::mem::transmute_copy(&ppobjects)).into()
+            }
+        }
+        unsafe extern "system" fn Evict<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, numobjects: u32, ppobjects: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::Evict(this, core::mem::transmute_copy(&numobjects), core::mem::transmute_copy(&ppobjects)).into()
+            }
+        }
+        unsafe extern "system" fn CreateFence<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, initialvalue: u64, flags: D3D12_FENCE_FLAGS, riid: *const windows_core::GUID, ppfence: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::CreateFence(this, core::mem::transmute_copy(&initialvalue), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppfence)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceRemovedReason<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::GetDeviceRemovedReason(this).into()
+            }
+        }
+        unsafe extern "system" fn GetCopyableFootprints<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, presourcedesc: *const D3D12_RESOURCE_DESC, firstsubresource: u32, numsubresources: u32, baseoffset: u64, playouts: *mut D3D12_PLACED_SUBRESOURCE_FOOTPRINT, pnumrows: *mut u32, prowsizeinbytes: *mut u64, ptotalbytes: *mut u64) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::GetCopyableFootprints(this, core::mem::transmute_copy(&presourcedesc), core::mem::transmute_copy(&firstsubresource), core::mem::transmute_copy(&numsubresources), core::mem::transmute_copy(&baseoffset), core::mem::transmute_copy(&playouts), core::mem::transmute_copy(&pnumrows), core::mem::transmute_copy(&prowsizeinbytes), core::mem::transmute_copy(&ptotalbytes))
+            }
+        }
+        unsafe extern "system" fn CreateQueryHeap<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_QUERY_HEAP_DESC, riid: *const windows_core::GUID, ppvheap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::CreateQueryHeap(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvheap)).into()
+            }
+        }
+        unsafe extern "system" fn SetStablePowerState<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, enable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::SetStablePowerState(this, core::mem::transmute_copy(&enable)).into()
+            }
+        }
+        unsafe extern "system" fn CreateCommandSignature<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_COMMAND_SIGNATURE_DESC, prootsignature: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvcommandsignature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::CreateCommandSignature(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&prootsignature), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvcommandsignature)).into()
+            }
+        }
+        unsafe extern "system" fn GetResourceTiling<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, ptiledresource: *mut core::ffi::c_void, pnumtilesforentireresource: *mut u32, ppackedmipdesc: *mut D3D12_PACKED_MIP_INFO, pstandardtileshapefornonpackedmips: *mut D3D12_TILE_SHAPE, pnumsubresourcetilings: *mut u32, firstsubresourcetilingtoget: u32, psubresourcetilingsfornonpackedmips: *mut D3D12_SUBRESOURCE_TILING) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device_Impl::GetResourceTiling(this, core::mem::transmute_copy(&ptiledresource), core::mem::transmute_copy(&pnumtilesforentireresource), core::mem::transmute_copy(&ppackedmipdesc), core::mem::transmute_copy(&pstandardtileshapefornonpackedmips), core::mem::transmute_copy(&pnumsubresourcetilings), core::mem::transmute_copy(&firstsubresourcetilingtoget), core::mem::transmute_copy(&psubresourcetilingsfornonpackedmips))
+            }
+        }
+        unsafe extern "system" fn GetAdapterLuid<Identity: windows_core::IUnknownImpl + ID3D12Device_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::LUID) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = ID3D12Device_Impl::GetAdapterLuid(this)
+            }
+        }
+        Self {
+            base__: <ID3D12Object_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetNodeCount: GetNodeCount::<Identity>,
+            CreateCommandQueue: CreateCommandQueue::<Identity>,
+            CreateCommandAllocator: CreateCommandAllocator::<Identity>,
+            CreateGraphicsPipelineState: CreateGraphicsPipelineState::<Identity>,
+            CreateComputePipelineState: CreateComputePipelineState::<Identity>,
+            CreateCommandList: CreateCommandList::<Identity>,
+            CheckFeatureSupport: CheckFeatureSupport::<Identity>,
+            CreateDescriptorHeap: CreateDescriptorHeap::<Identity>,
+            GetDescriptorHandleIncrementSize: GetDescriptorHandleIncrementSize::<Identity>,
+            CreateRootSignature: CreateRootSignature::<Identity>,
+            CreateConstantBufferView: CreateConstantBufferView::<Identity>,
+            CreateShaderResourceView: CreateShaderResourceView::<Identity>,
+            CreateUnorderedAccessView: CreateUnorderedAccessView::<Identity>,
+            CreateRenderTargetView: CreateRenderTargetView::<Identity>,
+            CreateDepthStencilView: CreateDepthStencilView::<Identity>,
+            CreateSampler: CreateSampler::<Identity>,
+            CopyDescriptors: CopyDescriptors::<Identity>,
+            CopyDescriptorsSimple: CopyDescriptorsSimple::<Identity>,
+            GetResourceAllocationInfo: GetResourceAllocationInfo::<Identity>,
+            GetCustomHeapProperties: GetCustomHeapProperties::<Identity>,
+            CreateCommittedResource: CreateCommittedResource::<Identity>,
+            CreateHeap: CreateHeap::<Identity>,
+            CreatePlacedResource: CreatePlacedResource::<Identity>,
+            CreateReservedResource: CreateReservedResource::<Identity>,
+            CreateSharedHandle: CreateSharedHandle::<Identity>,
+            OpenSharedHandle: OpenSharedHandle::<Identity>,
+            OpenSharedHandleByName: OpenSharedHandleByName::<Identity>,
+            MakeResident: MakeResident::<Identity>,
+            Evict: Evict::<Identity>,
+            CreateFence: CreateFence::<Identity>,
+            GetDeviceRemovedReason: GetDeviceRemovedReason::<Identity>,
+            GetCopyableFootprints: GetCopyableFootprints::<Identity>,
+            CreateQueryHeap: CreateQueryHeap::<Identity>,
+            SetStablePowerState: SetStablePowerState::<Identity>,
+            CreateCommandSignature: CreateCommandSignature::<Identity>,
+            GetResourceTiling: GetResourceTiling::<Identity>,
+            GetAdapterLuid: GetAdapterLuid::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
 impl windows_core::RuntimeName for ID3D12Device {}
 windows_core::imp::define_interface!(ID3D12Device1, ID3D12Device1_Vtbl, 0x77acce80_638e_4e65_8895_c1f23386863e);
 impl core::ops::Deref for ID3D12Device1 {
@@ -10014,6 +10829,38 @@ impl ID3D12Device1_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12Device1_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Device1_Vtbl
+where
+    ID3D12Device_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreatePipelineLibrary<Identity: windows_core::IUnknownImpl + ID3D12Device1_Impl>(this: *mut core::ffi::c_void, plibraryblob: *const core::ffi::c_void, bloblength: usize, riid: *const windows_core::GUID, pppipelinelibrary: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device1_Impl::CreatePipelineLibrary(this, core::mem::transmute_copy(&plibraryblob), core::mem::transmute_copy(&bloblength), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&pppipelinelibrary)).into()
+            }
+        }
+        unsafe extern "system" fn SetEventOnMultipleFenceCompletion<Identity: windows_core::IUnknownImpl + ID3D12Device1_Impl>(this: *mut core::ffi::c_void, ppfences: *const *mut core::ffi::c_void, pfencevalues: *const u64, numfences: u32, flags: D3D12_MULTIPLE_FENCE_WAIT_FLAGS, hevent: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device1_Impl::SetEventOnMultipleFenceCompletion(this, core::mem::transmute_copy(&ppfences), core::mem::transmute_copy(&pfencevalues), core::mem::transmute_copy(&numfences), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&hevent)).into()
+            }
+        }
+        unsafe extern "system" fn SetResidencyPriority<Identity: windows_core::IUnknownImpl + ID3D12Device1_Impl>(this: *mut core::ffi::c_void, numobjects: u32, ppobjects: *const *mut core::ffi::c_void, ppriorities: *const D3D12_RESIDENCY_PRIORITY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device1_Impl::SetResidencyPriority(this, core::mem::transmute_copy(&numobjects), core::mem::transmute_copy(&ppobjects), core::mem::transmute_copy(&ppriorities)).into()
+            }
+        }
+        Self {
+            base__: <ID3D12Device_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreatePipelineLibrary: CreatePipelineLibrary::<Identity>,
+            SetEventOnMultipleFenceCompletion: SetEventOnMultipleFenceCompletion::<Identity>,
+            SetResidencyPriority: SetResidencyPriority::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
 impl windows_core::RuntimeName for ID3D12Device1 {}
 windows_core::imp::define_interface!(ID3D12Device10, ID3D12Device10_Vtbl, 0x517f8718_aa66_49f9_b02b_a7ab89c06031);
 impl core::ops::Deref for ID3D12Device10 {
@@ -10107,20 +10954,52 @@ impl ID3D12Device10_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
-impl windows_core::RuntimeName for ID3D12Device10 {}
-windows_core::imp::define_interface!(ID3D12Device11, ID3D12Device11_Vtbl, 0x5405c344_d457_444e_b4dd_2366e45aee39);
-impl core::ops::Deref for ID3D12Device11 {
-    type Target = ID3D12Device10;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(ID3D12Device11, windows_core::IUnknown, ID3D12Object, ID3D12Device, ID3D12Device1, ID3D12Device2, ID3D12Device3, ID3D12Device4, ID3D12Device5, ID3D12Device6, ID3D12Device7, ID3D12Device8, ID3D12Device9, ID3D12Device10);
-impl ID3D12Device11 {
-    pub unsafe fn CreateSampler2(&self, pdesc: *const D3D12_SAMPLER_DESC2, destdescriptor: D3D12_CPU_DESCRIPTOR_HANDLE) {
-        unsafe { (windows_core::Interface::vtable(self).CreateSampler2)(windows_core::Interface::as_raw(self), pdesc, core::mem::transmute(destdescriptor)) }
-    }
-}
+impl<Identity: windows_core::IUnknownImpl + ID3D12Device10_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Device10_Vtbl
+where
+    ID3D12Device9_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateCommittedResource3<Identity: windows_core::IUnknownImpl + ID3D12Device10_Impl>(this: *mut core::ffi::c_void, pheapproperties: *const D3D12_HEAP_PROPERTIES, heapflags: D3D12_HEAP_FLAGS, pdesc: *const D3D12_RESOURCE_DESC1, initiallayout: D3D12_BARRIER_LAYOUT, poptimizedclearvalue: *const D3D12_CLEAR_VALUE, pprotectedsession: *mut core::ffi::c_void, numcastableformats: u32, pcastableformats: *const super::Dxgi::Common::DXGI_FORMAT, riidresource: *const windows_core::GUID, ppvresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device10_Impl::CreateCommittedResource3(this, core::mem::transmute_copy(&pheapproperties), core::mem::transmute_copy(&heapflags), core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&initiallayout), core::mem::transmute_copy(&poptimizedclearvalue), core::mem::transmute_copy(&pprotectedsession), core::mem::transmute_copy(&numcastableformats), core::mem::transmute_copy(&pcastableformats), core::mem::transmute_copy(&riidresource), core::mem::transmute_copy(&ppvresource)).into()
+            }
+        }
+        unsafe extern "system" fn CreatePlacedResource2<Identity: windows_core::IUnknownImpl + ID3D12Device10_Impl>(this: *mut core::ffi::c_void, pheap: *mut core::ffi::c_void, heapoffset: u64, pdesc: *const D3D12_RESOURCE_DESC1, initiallayout: D3D12_BARRIER_LAYOUT, poptimizedclearvalue: *const D3D12_CLEAR_VALUE, numcastableformats: u32, pcastableformats: *const super::Dxgi::Common::DXGI_FORMAT, riid: *const windows_core::GUID, ppvresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device10_Impl::CreatePlacedResource2(this, core::mem::transmute_copy(&pheap), core::mem::transmute_copy(&heapoffset), core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&initiallayout), core::mem::transmute_copy(&poptimizedclearvalue), core::mem::transmute_copy(&numcastableformats), core::mem::transmute_copy(&pcastableformats), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvresource)).into()
+            }
+        }
+        unsafe extern "system" fn CreateReservedResource2<Identity: windows_core::IUnknownImpl + ID3D12Device10_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_RESOURCE_DESC, initiallayout: D3D12_BARRIER_LAYOUT, poptimizedclearvalue: *const D3D12_CLEAR_VALUE, pprotectedsession: *mut core::ffi::c_void, numcastableformats: u32, pcastableformats: *const super::Dxgi::Common::DXGI_FORMAT, riid: *const windows_core::GUID, ppvresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device10_Impl::CreateReservedResource2(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&initiallayout), core::mem::transmute_copy(&poptimizedclearvalue), core::mem::transmute_copy(&pprotectedsession), core::mem::transmute_copy(&numcastableformats), core::mem::transmute_copy(&pcastableformats), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvresource)).into()
+            }
+        }
+        Self {
+            base__: <ID3D12Device9_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateCommittedResource3: CreateCommittedResource3::<Identity>,
+            CreatePlacedResource2: CreatePlacedResource2::<Identity>,
+            CreateReservedResource2: CreateReservedResource2::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl windows_core::RuntimeName for ID3D12Device10 {}
+windows_core::imp::define_interface!(ID3D12Device11, ID3D12Device11_Vtbl, 0x5405c344_d457_444e_b4dd_2366e45aee39);
+impl core::ops::Deref for ID3D12Device11 {
+    type Target = ID3D12Device10;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(ID3D12Device11, windows_core::IUnknown, ID3D12Object, ID3D12Device, ID3D12Device1, ID3D12Device2, ID3D12Device3, ID3D12Device4, ID3D12Device5, ID3D12Device6, ID3D12Device7, ID3D12Device8, ID3D12Device9, ID3D12Device10);
+impl ID3D12Device11 {
+    pub unsafe fn CreateSampler2(&self, pdesc: *const D3D12_SAMPLER_DESC2, destdescriptor: D3D12_CPU_DESCRIPTOR_HANDLE) {
+        unsafe { (windows_core::Interface::vtable(self).CreateSampler2)(windows_core::Interface::as_raw(self), pdesc, core::mem::transmute(destdescriptor)) }
+    }
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct ID3D12Device11_Vtbl {
@@ -10149,6 +11028,21 @@ impl ID3D12Device11_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12Device11_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Device11_Vtbl
+where
+    ID3D12Device10_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateSampler2<Identity: windows_core::IUnknownImpl + ID3D12Device11_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_SAMPLER_DESC2, destdescriptor: D3D12_CPU_DESCRIPTOR_HANDLE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device11_Impl::CreateSampler2(this, core::mem::transmute_copy(&pdesc), core::mem::transmute(&destdescriptor))
+            }
+        }
+        Self { base__: <ID3D12Device10_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateSampler2: CreateSampler2::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
 impl windows_core::RuntimeName for ID3D12Device11 {}
 windows_core::imp::define_interface!(ID3D12Device12, ID3D12Device12_Vtbl, 0x5af5c532_4c91_4cd0_b541_15a405395fc5);
 impl core::ops::Deref for ID3D12Device12 {
@@ -10212,6 +11106,24 @@ impl ID3D12Device12_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12Device12_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Device12_Vtbl
+where
+    ID3D12Device11_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetResourceAllocationInfo3<Identity: windows_core::IUnknownImpl + ID3D12Device12_Impl>(this: *mut core::ffi::c_void, result__: *mut D3D12_RESOURCE_ALLOCATION_INFO, visiblemask: u32, numresourcedescs: u32, presourcedescs: *const D3D12_RESOURCE_DESC1, pnumcastableformats: *const u32, ppcastableformats: *const *const super::Dxgi::Common::DXGI_FORMAT, presourceallocationinfo1: *mut D3D12_RESOURCE_ALLOCATION_INFO1) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = ID3D12Device12_Impl::GetResourceAllocationInfo3(this, core::mem::transmute_copy(&visiblemask), core::mem::transmute_copy(&numresourcedescs), core::mem::transmute_copy(&presourcedescs), core::mem::transmute_copy(&pnumcastableformats), core::mem::transmute_copy(&ppcastableformats), core::mem::transmute_copy(&presourceallocationinfo1))
+            }
+        }
+        Self {
+            base__: <ID3D12Device11_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetResourceAllocationInfo3: GetResourceAllocationInfo3::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
 impl windows_core::RuntimeName for ID3D12Device12 {}
 windows_core::imp::define_interface!(ID3D12Device13, ID3D12Device13_Vtbl, 0x14eecffc_4df8_40f7_a118_5c816f45695e);
 impl core::ops::Deref for ID3D12Device13 {
@@ -10272,6 +11184,24 @@ impl ID3D12Device13_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12Device13_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Device13_Vtbl
+where
+    ID3D12Device12_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OpenExistingHeapFromAddress1<Identity: windows_core::IUnknownImpl + ID3D12Device13_Impl>(this: *mut core::ffi::c_void, paddress: *const core::ffi::c_void, size: usize, riid: *const windows_core::GUID, ppvheap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device13_Impl::OpenExistingHeapFromAddress1(this, core::mem::transmute_copy(&paddress), core::mem::transmute_copy(&size), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvheap)).into()
+            }
+        }
+        Self {
+            base__: <ID3D12Device12_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OpenExistingHeapFromAddress1: OpenExistingHeapFromAddress1::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
 impl windows_core::RuntimeName for ID3D12Device13 {}
 windows_core::imp::define_interface!(ID3D12Device14, ID3D12Device14_Vtbl, 0x5f6e592d_d895_44c2_8e4a_88ad4926d323);
 impl core::ops::Deref for ID3D12Device14 {
@@ -10337,6 +11267,24 @@ impl ID3D12Device14_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12Device14_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Device14_Vtbl
+where
+    ID3D12Device13_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateRootSignatureFromSubobjectInLibrary<Identity: windows_core::IUnknownImpl + ID3D12Device14_Impl>(this: *mut core::ffi::c_void, nodemask: u32, plibraryblob: *const core::ffi::c_void, bloblengthinbytes: usize, subobjectname: windows_core::PCWSTR, riid: *const windows_core::GUID, ppvrootsignature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device14_Impl::CreateRootSignatureFromSubobjectInLibrary(this, core::mem::transmute_copy(&nodemask), core::mem::transmute_copy(&plibraryblob), core::mem::transmute_copy(&bloblengthinbytes), core::mem::transmute(&subobjectname), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvrootsignature)).into()
+            }
+        }
+        Self {
+            base__: <ID3D12Device13_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateRootSignatureFromSubobjectInLibrary: CreateRootSignatureFromSubobjectInLibrary::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
 impl windows_core::RuntimeName for ID3D12Device14 {}
 windows_core::imp::define_interface!(ID3D12Device2, ID3D12Device2_Vtbl, 0x30baa41e_b15b_475c_a0bb_1af5c5b64328);
 impl core::ops::Deref for ID3D12Device2 {
@@ -10383,6 +11331,21 @@ impl ID3D12Device2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12Device2_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Device2_Vtbl
+where
+    ID3D12Device1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreatePipelineState<Identity: windows_core::IUnknownImpl + ID3D12Device2_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_PIPELINE_STATE_STREAM_DESC, riid: *const windows_core::GUID, pppipelinestate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device2_Impl::CreatePipelineState(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&pppipelinestate)).into()
+            }
+        }
+        Self { base__: <ID3D12Device1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreatePipelineState: CreatePipelineState::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
 impl windows_core::RuntimeName for ID3D12Device2 {}
 windows_core::imp::define_interface!(ID3D12Device3, ID3D12Device3_Vtbl, 0x81dadc15_2bad_4392_93c5_101345c4aa98);
 impl core::ops::Deref for ID3D12Device3 {
@@ -10463,6 +11426,38 @@ impl ID3D12Device3_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12Device3_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Device3_Vtbl
+where
+    ID3D12Device2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OpenExistingHeapFromAddress<Identity: windows_core::IUnknownImpl + ID3D12Device3_Impl>(this: *mut core::ffi::c_void, paddress: *const core::ffi::c_void, riid: *const windows_core::GUID, ppvheap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device3_Impl::OpenExistingHeapFromAddress(this, core::mem::transmute_copy(&paddress), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvheap)).into()
+            }
+        }
+        unsafe extern "system" fn OpenExistingHeapFromFileMapping<Identity: windows_core::IUnknownImpl + ID3D12Device3_Impl>(this: *mut core::ffi::c_void, hfilemapping: super::super::Foundation::HANDLE, riid: *const windows_core::GUID, ppvheap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device3_Impl::OpenExistingHeapFromFileMapping(this, core::mem::transmute_copy(&hfilemapping), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvheap)).into()
+            }
+        }
+        unsafe extern "system" fn EnqueueMakeResident<Identity: windows_core::IUnknownImpl + ID3D12Device3_Impl>(this: *mut core::ffi::c_void, flags: D3D12_RESIDENCY_FLAGS, numobjects: u32, ppobjects: *const *mut core::ffi::c_void, pfencetosignal: *mut core::ffi::c_void, fencevaluetosignal: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device3_Impl::EnqueueMakeResident(this, core::mem::transmute_copy(&flags), core::mem::transmute_copy(&numobjects), core::mem::transmute_copy(&ppobjects), core::mem::transmute_copy(&pfencetosignal), core::mem::transmute_copy(&fencevaluetosignal)).into()
+            }
+        }
+        Self {
+            base__: <ID3D12Device2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OpenExistingHeapFromAddress: OpenExistingHeapFromAddress::<Identity>,
+            OpenExistingHeapFromFileMapping: OpenExistingHeapFromFileMapping::<Identity>,
+            EnqueueMakeResident: EnqueueMakeResident::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
 impl windows_core::RuntimeName for ID3D12Device3 {}
 windows_core::imp::define_interface!(ID3D12Device4, ID3D12Device4_Vtbl, 0xe865df17_a9ee_46f9_a463_3098315aa2e5);
 impl core::ops::Deref for ID3D12Device4 {
@@ -10604,6 +11599,59 @@ impl ID3D12Device4_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12Device4_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Device4_Vtbl
+where
+    ID3D12Device3_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateCommandList1<Identity: windows_core::IUnknownImpl + ID3D12Device4_Impl>(this: *mut core::ffi::c_void, nodemask: u32, r#type: D3D12_COMMAND_LIST_TYPE, flags: D3D12_COMMAND_LIST_FLAGS, riid: *const windows_core::GUID, ppcommandlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device4_Impl::CreateCommandList1(this, core::mem::transmute_copy(&nodemask), core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppcommandlist)).into()
+            }
+        }
+        unsafe extern "system" fn CreateProtectedResourceSession<Identity: windows_core::IUnknownImpl + ID3D12Device4_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_PROTECTED_RESOURCE_SESSION_DESC, riid: *const windows_core::GUID, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device4_Impl::CreateProtectedResourceSession(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppsession)).into()
+            }
+        }
+        unsafe extern "system" fn CreateCommittedResource1<Identity: windows_core::IUnknownImpl + ID3D12Device4_Impl>(this: *mut core::ffi::c_void, pheapproperties: *const D3D12_HEAP_PROPERTIES, heapflags: D3D12_HEAP_FLAGS, pdesc: *const D3D12_RESOURCE_DESC, initialresourcestate: D3D12_RESOURCE_STATES, poptimizedclearvalue: *const D3D12_CLEAR_VALUE, pprotectedsession: *mut core::ffi::c_void, riidresource: *const windows_core::GUID, ppvresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device4_Impl::CreateCommittedResource1(this, core::mem::transmute_copy(&pheapproperties), core::mem::transmute_copy(&heapflags), core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&initialresourcestate), core::mem::transmute_copy(&poptimizedclearvalue), core::mem::transmute_copy(&pprotectedsession), core::mem::transmute_copy(&riidresource), core::mem::transmute_copy(&ppvresource)).into()
+            }
+        }
+        unsafe extern "system" fn CreateHeap1<Identity: windows_core::IUnknownImpl + ID3D12Device4_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_HEAP_DESC, pprotectedsession: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvheap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device4_Impl::CreateHeap1(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&pprotectedsession), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvheap)).into()
+            }
+        }
+        unsafe extern "system" fn CreateReservedResource1<Identity: windows_core::IUnknownImpl + ID3D12Device4_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_RESOURCE_DESC, initialstate: D3D12_RESOURCE_STATES, poptimizedclearvalue: *const D3D12_CLEAR_VALUE, pprotectedsession: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device4_Impl::CreateReservedResource1(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&initialstate), core::mem::transmute_copy(&poptimizedclearvalue), core::mem::transmute_copy(&pprotectedsession), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvresource)).into()
+            }
+        }
+        unsafe extern "system" fn GetResourceAllocationInfo1<Identity: windows_core::IUnknownImpl + ID3D12Device4_Impl>(this: *mut core::ffi::c_void, result__: *mut D3D12_RESOURCE_ALLOCATION_INFO, visiblemask: u32, numresourcedescs: u32, presourcedescs: *const D3D12_RESOURCE_DESC, presourceallocationinfo1: *mut D3D12_RESOURCE_ALLOCATION_INFO1) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = ID3D12Device4_Impl::GetResourceAllocationInfo1(this, core::mem::transmute_copy(&visiblemask), core::mem::transmute_copy(&numresourcedescs), core::mem::transmute_copy(&presourcedescs), core::mem::transmute_copy(&presourceallocationinfo1))
+            }
+        }
+        Self {
+            base__: <ID3D12Device3_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateCommandList1: CreateCommandList1::<Identity>,
+            CreateProtectedResourceSession: CreateProtectedResourceSession::<Identity>,
+            CreateCommittedResource1: CreateCommittedResource1::<Identity>,
+            CreateHeap1: CreateHeap1::<Identity>,
+            CreateReservedResource1: CreateReservedResource1::<Identity>,
+            GetResourceAllocationInfo1: GetResourceAllocationInfo1::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
 impl windows_core::RuntimeName for ID3D12Device4 {}
 windows_core::imp::define_interface!(ID3D12Device5, ID3D12Device5_Vtbl, 0x8b4f173b_2fea_4b80_8f58_4307191ab95d);
 impl core::ops::Deref for ID3D12Device5 {
@@ -10750,6 +11798,73 @@ impl ID3D12Device5_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12Device5_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Device5_Vtbl
+where
+    ID3D12Device4_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateLifetimeTracker<Identity: windows_core::IUnknownImpl + ID3D12Device5_Impl>(this: *mut core::ffi::c_void, powner: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvtracker: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device5_Impl::CreateLifetimeTracker(this, core::mem::transmute_copy(&powner), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvtracker)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveDevice<Identity: windows_core::IUnknownImpl + ID3D12Device5_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device5_Impl::RemoveDevice(this)
+            }
+        }
+        unsafe extern "system" fn EnumerateMetaCommands<Identity: windows_core::IUnknownImpl + ID3D12Device5_Impl>(this: *mut core::ffi::c_void, pnummetacommands: *mut u32, pdescs: *mut D3D12_META_COMMAND_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device5_Impl::EnumerateMetaCommands(this, core::mem::transmute_copy(&pnummetacommands), core::mem::transmute_copy(&pdescs)).into()
+            }
+        }
+        unsafe extern "system" fn EnumerateMetaCommandParameters<Identity: windows_core::IUnknownImpl + ID3D12Device5_Impl>(this: *mut core::ffi::c_void, commandid: *const windows_core::GUID, stage: D3D12_META_COMMAND_PARAMETER_STAGE, ptotalstructuresizeinbytes: *mut u32, pparametercount: *mut u32, pparameterdescs: *mut D3D12_META_COMMAND_PARAMETER_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device5_Impl::EnumerateMetaCommandParameters(this, core::mem::transmute_copy(&commandid), core::mem::transmute_copy(&stage), core::mem::transmute_copy(&ptotalstructuresizeinbytes), core::mem::transmute_copy(&pparametercount), core::mem::transmute_copy(&pparameterdescs)).into()
+            }
+        }
+        unsafe extern "system" fn CreateMetaCommand<Identity: windows_core::IUnknownImpl + ID3D12Device5_Impl>(this: *mut core::ffi::c_void, commandid: *const windows_core::GUID, nodemask: u32, pcreationparametersdata: *const core::ffi::c_void, creationparametersdatasizeinbytes: usize, riid: *const windows_core::GUID, ppmetacommand: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device5_Impl::CreateMetaCommand(this, core::mem::transmute_copy(&commandid), core::mem::transmute_copy(&nodemask), core::mem::transmute_copy(&pcreationparametersdata), core::mem::transmute_copy(&creationparametersdatasizeinbytes), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppmetacommand)).into()
+            }
+        }
+        unsafe extern "system" fn CreateStateObject<Identity: windows_core::IUnknownImpl + ID3D12Device5_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_STATE_OBJECT_DESC, riid: *const windows_core::GUID, ppstateobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device5_Impl::CreateStateObject(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppstateobject)).into()
+            }
+        }
+        unsafe extern "system" fn GetRaytracingAccelerationStructurePrebuildInfo<Identity: windows_core::IUnknownImpl + ID3D12Device5_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_BUILD_RAYTRACING_ACCELERATION_STRUCTURE_INPUTS, pinfo: *mut D3D12_RAYTRACING_ACCELERATION_STRUCTURE_PREBUILD_INFO) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device5_Impl::GetRaytracingAccelerationStructurePrebuildInfo(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&pinfo))
+            }
+        }
+        unsafe extern "system" fn CheckDriverMatchingIdentifier<Identity: windows_core::IUnknownImpl + ID3D12Device5_Impl>(this: *mut core::ffi::c_void, serializeddatatype: D3D12_SERIALIZED_DATA_TYPE, pidentifiertocheck: *const D3D12_SERIALIZED_DATA_DRIVER_MATCHING_IDENTIFIER) -> D3D12_DRIVER_MATCHING_IDENTIFIER_STATUS {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device5_Impl::CheckDriverMatchingIdentifier(this, core::mem::transmute_copy(&serializeddatatype), core::mem::transmute_copy(&pidentifiertocheck))
+            }
+        }
+        Self {
+            base__: <ID3D12Device4_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateLifetimeTracker: CreateLifetimeTracker::<Identity>,
+            RemoveDevice: RemoveDevice::<Identity>,
+            EnumerateMetaCommands: EnumerateMetaCommands::<Identity>,
+            EnumerateMetaCommandParameters: EnumerateMetaCommandParameters::<Identity>,
+            CreateMetaCommand: CreateMetaCommand::<Identity>,
+            CreateStateObject: CreateStateObject::<Identity>,
+            GetRaytracingAccelerationStructurePrebuildInfo: GetRaytracingAccelerationStructurePrebuildInfo::<Identity>,
+            CheckDriverMatchingIdentifier: CheckDriverMatchingIdentifier::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
 impl windows_core::RuntimeName for ID3D12Device5 {}
 windows_core::imp::define_interface!(ID3D12Device6, ID3D12Device6_Vtbl, 0xc70b221b_40e4_4a17_89af_025a0727a6dc);
 impl core::ops::Deref for ID3D12Device6 {
@@ -10792,6 +11907,24 @@ impl ID3D12Device6_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12Device6_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Device6_Vtbl
+where
+    ID3D12Device5_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetBackgroundProcessingMode<Identity: windows_core::IUnknownImpl + ID3D12Device6_Impl>(this: *mut core::ffi::c_void, mode: D3D12_BACKGROUND_PROCESSING_MODE, measurementsaction: D3D12_MEASUREMENTS_ACTION, heventtosignaluponcompletion: super::super::Foundation::HANDLE, pbfurthermeasurementsdesired: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device6_Impl::SetBackgroundProcessingMode(this, core::mem::transmute_copy(&mode), core::mem::transmute_copy(&measurementsaction), core::mem::transmute_copy(&heventtosignaluponcompletion), core::mem::transmute_copy(&pbfurthermeasurementsdesired)).into()
+            }
+        }
+        Self {
+            base__: <ID3D12Device5_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetBackgroundProcessingMode: SetBackgroundProcessingMode::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
 impl windows_core::RuntimeName for ID3D12Device6 {}
 windows_core::imp::define_interface!(ID3D12Device7, ID3D12Device7_Vtbl, 0x5c014b53_68a1_4b9b_8bd1_dd6046b9358b);
 impl core::ops::Deref for ID3D12Device7 {
@@ -10858,6 +11991,31 @@ impl ID3D12Device7_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12Device7_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Device7_Vtbl
+where
+    ID3D12Device6_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddToStateObject<Identity: windows_core::IUnknownImpl + ID3D12Device7_Impl>(this: *mut core::ffi::c_void, paddition: *const D3D12_STATE_OBJECT_DESC, pstateobjecttogrowfrom: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppnewstateobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device7_Impl::AddToStateObject(this, core::mem::transmute_copy(&paddition), core::mem::transmute_copy(&pstateobjecttogrowfrom), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppnewstateobject)).into()
+            }
+        }
+        unsafe extern "system" fn CreateProtectedResourceSession1<Identity: windows_core::IUnknownImpl + ID3D12Device7_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_PROTECTED_RESOURCE_SESSION_DESC1, riid: *const windows_core::GUID, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device7_Impl::CreateProtectedResourceSession1(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppsession)).into()
+            }
+        }
+        Self {
+            base__: <ID3D12Device6_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddToStateObject: AddToStateObject::<Identity>,
+            CreateProtectedResourceSession1: CreateProtectedResourceSession1::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
 impl windows_core::RuntimeName for ID3D12Device7 {}
 windows_core::imp::define_interface!(ID3D12Device8, ID3D12Device8_Vtbl, 0x9218e6bb_f944_4f7e_a75c_b1b2c7b701f3);
 impl core::ops::Deref for ID3D12Device8 {
@@ -10983,6 +12141,52 @@ impl ID3D12Device8_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12Device8_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Device8_Vtbl
+where
+    ID3D12Device7_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetResourceAllocationInfo2<Identity: windows_core::IUnknownImpl + ID3D12Device8_Impl>(this: *mut core::ffi::c_void, result__: *mut D3D12_RESOURCE_ALLOCATION_INFO, visiblemask: u32, numresourcedescs: u32, presourcedescs: *const D3D12_RESOURCE_DESC1, presourceallocationinfo1: *mut D3D12_RESOURCE_ALLOCATION_INFO1) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = ID3D12Device8_Impl::GetResourceAllocationInfo2(this, core::mem::transmute_copy(&visiblemask), core::mem::transmute_copy(&numresourcedescs), core::mem::transmute_copy(&presourcedescs), core::mem::transmute_copy(&presourceallocationinfo1))
+            }
+        }
+        unsafe extern "system" fn CreateCommittedResource2<Identity: windows_core::IUnknownImpl + ID3D12Device8_Impl>(this: *mut core::ffi::c_void, pheapproperties: *const D3D12_HEAP_PROPERTIES, heapflags: D3D12_HEAP_FLAGS, pdesc: *const D3D12_RESOURCE_DESC1, initialresourcestate: D3D12_RESOURCE_STATES, poptimizedclearvalue: *const D3D12_CLEAR_VALUE, pprotectedsession: *mut core::ffi::c_void, riidresource: *const windows_core::GUID, ppvresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device8_Impl::CreateCommittedResource2(this, core::mem::transmute_copy(&pheapproperties), core::mem::transmute_copy(&heapflags), core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&initialresourcestate), core::mem::transmute_copy(&poptimizedclearvalue), core::mem::transmute_copy(&pprotectedsession), core::mem::transmute_copy(&riidresource), core::mem::transmute_copy(&ppvresource)).into()
+            }
+        }
+        unsafe extern "system" fn CreatePlacedResource1<Identity: windows_core::IUnknownImpl + ID3D12Device8_Impl>(this: *mut core::ffi::c_void, pheap: *mut core::ffi::c_void, heapoffset: u64, pdesc: *const D3D12_RESOURCE_DESC1, initialstate: D3D12_RESOURCE_STATES, poptimizedclearvalue: *const D3D12_CLEAR_VALUE, riid: *const windows_core::GUID, ppvresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device8_Impl::CreatePlacedResource1(this, core::mem::transmute_copy(&pheap), core::mem::transmute_copy(&heapoffset), core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&initialstate), core::mem::transmute_copy(&poptimizedclearvalue), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvresource)).into()
+            }
+        }
+        unsafe extern "system" fn CreateSamplerFeedbackUnorderedAccessView<Identity: windows_core::IUnknownImpl + ID3D12Device8_Impl>(this: *mut core::ffi::c_void, ptargetedresource: *mut core::ffi::c_void, pfeedbackresource: *mut core::ffi::c_void, destdescriptor: D3D12_CPU_DESCRIPTOR_HANDLE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device8_Impl::CreateSamplerFeedbackUnorderedAccessView(this, core::mem::transmute_copy(&ptargetedresource), core::mem::transmute_copy(&pfeedbackresource), core::mem::transmute(&destdescriptor))
+            }
+        }
+        unsafe extern "system" fn GetCopyableFootprints1<Identity: windows_core::IUnknownImpl + ID3D12Device8_Impl>(this: *mut core::ffi::c_void, presourcedesc: *const D3D12_RESOURCE_DESC1, firstsubresource: u32, numsubresources: u32, baseoffset: u64, playouts: *mut D3D12_PLACED_SUBRESOURCE_FOOTPRINT, pnumrows: *mut u32, prowsizeinbytes: *mut u64, ptotalbytes: *mut u64) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device8_Impl::GetCopyableFootprints1(this, core::mem::transmute_copy(&presourcedesc), core::mem::transmute_copy(&firstsubresource), core::mem::transmute_copy(&numsubresources), core::mem::transmute_copy(&baseoffset), core::mem::transmute_copy(&playouts), core::mem::transmute_copy(&pnumrows), core::mem::transmute_copy(&prowsizeinbytes), core::mem::transmute_copy(&ptotalbytes))
+            }
+        }
+        Self {
+            base__: <ID3D12Device7_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetResourceAllocationInfo2: GetResourceAllocationInfo2::<Identity>,
+            CreateCommittedResource2: CreateCommittedResource2::<Identity>,
+            CreatePlacedResource1: CreatePlacedResource1::<Identity>,
+            CreateSamplerFeedbackUnorderedAccessView: CreateSamplerFeedbackUnorderedAccessView::<Identity>,
+            GetCopyableFootprints1: GetCopyableFootprints1::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
 impl windows_core::RuntimeName for ID3D12Device8 {}
 windows_core::imp::define_interface!(ID3D12Device9, ID3D12Device9_Vtbl, 0x4c80e962_f032_4f60_bc9e_ebc2cfa1d83c);
 impl core::ops::Deref for ID3D12Device9 {
@@ -11059,6 +12263,38 @@ impl ID3D12Device9_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12Device9_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Device9_Vtbl
+where
+    ID3D12Device8_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateShaderCacheSession<Identity: windows_core::IUnknownImpl + ID3D12Device9_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_SHADER_CACHE_SESSION_DESC, riid: *const windows_core::GUID, ppvsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device9_Impl::CreateShaderCacheSession(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvsession)).into()
+            }
+        }
+        unsafe extern "system" fn ShaderCacheControl<Identity: windows_core::IUnknownImpl + ID3D12Device9_Impl>(this: *mut core::ffi::c_void, kinds: D3D12_SHADER_CACHE_KIND_FLAGS, control: D3D12_SHADER_CACHE_CONTROL_FLAGS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device9_Impl::ShaderCacheControl(this, core::mem::transmute_copy(&kinds), core::mem::transmute_copy(&control)).into()
+            }
+        }
+        unsafe extern "system" fn CreateCommandQueue1<Identity: windows_core::IUnknownImpl + ID3D12Device9_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_COMMAND_QUEUE_DESC, creatorid: *const windows_core::GUID, riid: *const windows_core::GUID, ppcommandqueue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Device9_Impl::CreateCommandQueue1(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&creatorid), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppcommandqueue)).into()
+            }
+        }
+        Self {
+            base__: <ID3D12Device8_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateShaderCacheSession: CreateShaderCacheSession::<Identity>,
+            ShaderCacheControl: ShaderCacheControl::<Identity>,
+            CreateCommandQueue1: CreateCommandQueue1::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
 impl windows_core::RuntimeName for ID3D12Device9 {}
 windows_core::imp::define_interface!(ID3D12DeviceChild, ID3D12DeviceChild_Vtbl, 0x905db94b_a00c_4140_9df5_2b64ca9ea357);
 impl core::ops::Deref for ID3D12DeviceChild {
@@ -11101,6 +12337,20 @@ impl ID3D12DeviceChild_Vtbl {
         iid == &<ID3D12DeviceChild as windows_core::Interface>::IID || iid == &<ID3D12Object as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12DeviceChild_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12DeviceChild_Vtbl
+where
+    ID3D12Object_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDevice<Identity: windows_core::IUnknownImpl + ID3D12DeviceChild_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DeviceChild_Impl::GetDevice(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvdevice)).into()
+            }
+        }
+        Self { base__: <ID3D12Object_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDevice: GetDevice::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ID3D12DeviceChild {}
 windows_core::imp::define_interface!(ID3D12DeviceConfiguration, ID3D12DeviceConfiguration_Vtbl, 0x78dbf87b_f766_422b_a61c_c8c446bdb9ad);
 windows_core::imp::interface_hierarchy!(ID3D12DeviceConfiguration, windows_core::IUnknown);
@@ -11188,24 +12438,63 @@ impl ID3D12DeviceConfiguration_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct3D")]
-impl windows_core::RuntimeName for ID3D12DeviceConfiguration {}
-windows_core::imp::define_interface!(ID3D12DeviceConfiguration1, ID3D12DeviceConfiguration1_Vtbl, 0xed342442_6343_4e16_bb82_a3a577874e56);
-impl core::ops::Deref for ID3D12DeviceConfiguration1 {
-    type Target = ID3D12DeviceConfiguration;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(ID3D12DeviceConfiguration1, windows_core::IUnknown, ID3D12DeviceConfiguration);
-impl ID3D12DeviceConfiguration1 {
-    pub unsafe fn CreateVersionedRootSignatureDeserializerFromSubobjectInLibrary<P2, T>(&self, plibraryblob: *const core::ffi::c_void, size: usize, rootsignaturesubobjectname: P2) -> windows_core::Result<T>
-    where
-        P2: windows_core::Param<windows_core::PCWSTR>,
-        T: windows_core::Interface,
-    {
-        let mut result__ = core::ptr::null_mut();
-        unsafe { (windows_core::Interface::vtable(self).CreateVersionedRootSignatureDeserializerFromSubobjectInLibrary)(windows_core::Interface::as_raw(self), plibraryblob, size, rootsignaturesubobjectname.param().abi(), &T::IID, &mut result__).and_then(|| windows_core::Type::from_abi(result__)) }
-    }
+impl<Identity: windows_core::IUnknownImpl + ID3D12DeviceConfiguration_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12DeviceConfiguration_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D12DeviceConfiguration_Impl>(this: *mut core::ffi::c_void, result__: *mut D3D12_DEVICE_CONFIGURATION_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = ID3D12DeviceConfiguration_Impl::GetDesc(this)
+            }
+        }
+        unsafe extern "system" fn GetEnabledExperimentalFeatures<Identity: windows_core::IUnknownImpl + ID3D12DeviceConfiguration_Impl>(this: *mut core::ffi::c_void, pguids: *mut windows_core::GUID, numguids: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DeviceConfiguration_Impl::GetEnabledExperimentalFeatures(this, core::mem::transmute_copy(&pguids), core::mem::transmute_copy(&numguids)).into()
+            }
+        }
+        unsafe extern "system" fn SerializeVersionedRootSignature<Identity: windows_core::IUnknownImpl + ID3D12DeviceConfiguration_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_VERSIONED_ROOT_SIGNATURE_DESC, ppresult: *mut *mut core::ffi::c_void, pperror: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DeviceConfiguration_Impl::SerializeVersionedRootSignature(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&ppresult), core::mem::transmute_copy(&pperror)).into()
+            }
+        }
+        unsafe extern "system" fn CreateVersionedRootSignatureDeserializer<Identity: windows_core::IUnknownImpl + ID3D12DeviceConfiguration_Impl>(this: *mut core::ffi::c_void, pblob: *const core::ffi::c_void, size: usize, riid: *const windows_core::GUID, ppvdeserializer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DeviceConfiguration_Impl::CreateVersionedRootSignatureDeserializer(this, core::mem::transmute_copy(&pblob), core::mem::transmute_copy(&size), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvdeserializer)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDesc: GetDesc::<Identity>,
+            GetEnabledExperimentalFeatures: GetEnabledExperimentalFeatures::<Identity>,
+            SerializeVersionedRootSignature: SerializeVersionedRootSignature::<Identity>,
+            CreateVersionedRootSignatureDeserializer: CreateVersionedRootSignatureDeserializer::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct3D")]
+impl windows_core::RuntimeName for ID3D12DeviceConfiguration {}
+windows_core::imp::define_interface!(ID3D12DeviceConfiguration1, ID3D12DeviceConfiguration1_Vtbl, 0xed342442_6343_4e16_bb82_a3a577874e56);
+impl core::ops::Deref for ID3D12DeviceConfiguration1 {
+    type Target = ID3D12DeviceConfiguration;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(ID3D12DeviceConfiguration1, windows_core::IUnknown, ID3D12DeviceConfiguration);
+impl ID3D12DeviceConfiguration1 {
+    pub unsafe fn CreateVersionedRootSignatureDeserializerFromSubobjectInLibrary<P2, T>(&self, plibraryblob: *const core::ffi::c_void, size: usize, rootsignaturesubobjectname: P2) -> windows_core::Result<T>
+    where
+        P2: windows_core::Param<windows_core::PCWSTR>,
+        T: windows_core::Interface,
+    {
+        let mut result__ = core::ptr::null_mut();
+        unsafe { (windows_core::Interface::vtable(self).CreateVersionedRootSignatureDeserializerFromSubobjectInLibrary)(windows_core::Interface::as_raw(self), plibraryblob, size, rootsignaturesubobjectname.param().abi(), &T::IID, &mut result__).and_then(|| windows_core::Type::from_abi(result__)) }
+    }
 }
 #[repr(C)]
 #[doc(hidden)]
@@ -11238,6 +12527,24 @@ impl ID3D12DeviceConfiguration1_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct3D")]
+impl<Identity: windows_core::IUnknownImpl + ID3D12DeviceConfiguration1_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12DeviceConfiguration1_Vtbl
+where
+    ID3D12DeviceConfiguration_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateVersionedRootSignatureDeserializerFromSubobjectInLibrary<Identity: windows_core::IUnknownImpl + ID3D12DeviceConfiguration1_Impl>(this: *mut core::ffi::c_void, plibraryblob: *const core::ffi::c_void, size: usize, rootsignaturesubobjectname: windows_core::PCWSTR, riid: *const windows_core::GUID, ppvdeserializer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DeviceConfiguration1_Impl::CreateVersionedRootSignatureDeserializerFromSubobjectInLibrary(this, core::mem::transmute_copy(&plibraryblob), core::mem::transmute_copy(&size), core::mem::transmute(&rootsignaturesubobjectname), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvdeserializer)).into()
+            }
+        }
+        Self {
+            base__: <ID3D12DeviceConfiguration_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateVersionedRootSignatureDeserializerFromSubobjectInLibrary: CreateVersionedRootSignatureDeserializerFromSubobjectInLibrary::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct3D")]
 impl windows_core::RuntimeName for ID3D12DeviceConfiguration1 {}
 windows_core::imp::define_interface!(ID3D12DeviceFactory, ID3D12DeviceFactory_Vtbl, 0x61f307d3_d34e_4e7c_8374_3ba4de23cccb);
 windows_core::imp::interface_hierarchy!(ID3D12DeviceFactory, windows_core::IUnknown);
@@ -11361,6 +12668,66 @@ impl ID3D12DeviceFactory_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct3D")]
+impl<Identity: windows_core::IUnknownImpl + ID3D12DeviceFactory_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12DeviceFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InitializeFromGlobalState<Identity: windows_core::IUnknownImpl + ID3D12DeviceFactory_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DeviceFactory_Impl::InitializeFromGlobalState(this).into()
+            }
+        }
+        unsafe extern "system" fn ApplyToGlobalState<Identity: windows_core::IUnknownImpl + ID3D12DeviceFactory_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DeviceFactory_Impl::ApplyToGlobalState(this).into()
+            }
+        }
+        unsafe extern "system" fn SetFlags<Identity: windows_core::IUnknownImpl + ID3D12DeviceFactory_Impl>(this: *mut core::ffi::c_void, flags: D3D12_DEVICE_FACTORY_FLAGS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DeviceFactory_Impl::SetFlags(this, core::mem::transmute_copy(&flags)).into()
+            }
+        }
+        unsafe extern "system" fn GetFlags<Identity: windows_core::IUnknownImpl + ID3D12DeviceFactory_Impl>(this: *mut core::ffi::c_void) -> D3D12_DEVICE_FACTORY_FLAGS {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DeviceFactory_Impl::GetFlags(this)
+            }
+        }
+        unsafe extern "system" fn GetConfigurationInterface<Identity: windows_core::IUnknownImpl + ID3D12DeviceFactory_Impl>(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID, iid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DeviceFactory_Impl::GetConfigurationInterface(this, core::mem::transmute_copy(&clsid), core::mem::transmute_copy(&iid), core::mem::transmute_copy(&ppv)).into()
+            }
+        }
+        unsafe extern "system" fn EnableExperimentalFeatures<Identity: windows_core::IUnknownImpl + ID3D12DeviceFactory_Impl>(this: *mut core::ffi::c_void, numfeatures: u32, piids: *const windows_core::GUID, pconfigurationstructs: *const core::ffi::c_void, pconfigurationstructsizes: *const u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DeviceFactory_Impl::EnableExperimentalFeatures(this, core::mem::transmute_copy(&numfeatures), core::mem::transmute_copy(&piids), core::mem::transmute_copy(&pconfigurationstructs), core::mem::transmute_copy(&pconfigurationstructsizes)).into()
+            }
+        }
+        unsafe extern "system" fn CreateDevice<Identity: windows_core::IUnknownImpl + ID3D12DeviceFactory_Impl>(this: *mut core::ffi::c_void, adapter: *mut core::ffi::c_void, featurelevel: super::Direct3D::D3D_FEATURE_LEVEL, riid: *const windows_core::GUID, ppvdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DeviceFactory_Impl::CreateDevice(this, core::mem::transmute_copy(&adapter), core::mem::transmute_copy(&featurelevel), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvdevice)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InitializeFromGlobalState: InitializeFromGlobalState::<Identity>,
+            ApplyToGlobalState: ApplyToGlobalState::<Identity>,
+            SetFlags: SetFlags::<Identity>,
+            GetFlags: GetFlags::<Identity>,
+            GetConfigurationInterface: GetConfigurationInterface::<Identity>,
+            EnableExperimentalFeatures: EnableExperimentalFeatures::<Identity>,
+            CreateDevice: CreateDevice::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct3D")]
 impl windows_core::RuntimeName for ID3D12DeviceFactory {}
 windows_core::imp::define_interface!(ID3D12DeviceRemovedExtendedData, ID3D12DeviceRemovedExtendedData_Vtbl, 0x98931d33_5ae8_4791_aa3c_1a73a2934e71);
 windows_core::imp::interface_hierarchy!(ID3D12DeviceRemovedExtendedData, windows_core::IUnknown);
@@ -11427,6 +12794,42 @@ impl ID3D12DeviceRemovedExtendedData_Vtbl {
         iid == &<ID3D12DeviceRemovedExtendedData as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12DeviceRemovedExtendedData_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12DeviceRemovedExtendedData_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAutoBreadcrumbsOutput<Identity: windows_core::IUnknownImpl + ID3D12DeviceRemovedExtendedData_Impl>(this: *mut core::ffi::c_void, poutput: *mut D3D12_DRED_AUTO_BREADCRUMBS_OUTPUT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D12DeviceRemovedExtendedData_Impl::GetAutoBreadcrumbsOutput(this) {
+                    Ok(ok__) => {
+                        poutput.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPageFaultAllocationOutput<Identity: windows_core::IUnknownImpl + ID3D12DeviceRemovedExtendedData_Impl>(this: *mut core::ffi::c_void, poutput: *mut D3D12_DRED_PAGE_FAULT_OUTPUT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D12DeviceRemovedExtendedData_Impl::GetPageFaultAllocationOutput(this) {
+                    Ok(ok__) => {
+                        poutput.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAutoBreadcrumbsOutput: GetAutoBreadcrumbsOutput::<Identity>,
+            GetPageFaultAllocationOutput: GetPageFaultAllocationOutput::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12DeviceRemovedExtendedData {}
 windows_core::imp::define_interface!(ID3D12DeviceRemovedExtendedData1, ID3D12DeviceRemovedExtendedData1_Vtbl, 0x9727a022_cf1d_4dda_9eba_effa653fc506);
 impl core::ops::Deref for ID3D12DeviceRemovedExtendedData1 {
@@ -11499,6 +12902,42 @@ impl ID3D12DeviceRemovedExtendedData1_Vtbl {
         iid == &<ID3D12DeviceRemovedExtendedData1 as windows_core::Interface>::IID || iid == &<ID3D12DeviceRemovedExtendedData as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12DeviceRemovedExtendedData1_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12DeviceRemovedExtendedData1_Vtbl
+where
+    ID3D12DeviceRemovedExtendedData_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAutoBreadcrumbsOutput1<Identity: windows_core::IUnknownImpl + ID3D12DeviceRemovedExtendedData1_Impl>(this: *mut core::ffi::c_void, poutput: *mut D3D12_DRED_AUTO_BREADCRUMBS_OUTPUT1) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D12DeviceRemovedExtendedData1_Impl::GetAutoBreadcrumbsOutput1(this) {
+                    Ok(ok__) => {
+                        poutput.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPageFaultAllocationOutput1<Identity: windows_core::IUnknownImpl + ID3D12DeviceRemovedExtendedData1_Impl>(this: *mut core::ffi::c_void, poutput: *mut D3D12_DRED_PAGE_FAULT_OUTPUT1) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D12DeviceRemovedExtendedData1_Impl::GetPageFaultAllocationOutput1(this) {
+                    Ok(ok__) => {
+                        poutput.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ID3D12DeviceRemovedExtendedData_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAutoBreadcrumbsOutput1: GetAutoBreadcrumbsOutput1::<Identity>,
+            GetPageFaultAllocationOutput1: GetPageFaultAllocationOutput1::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12DeviceRemovedExtendedData1 {}
 windows_core::imp::define_interface!(ID3D12DeviceRemovedExtendedData2, ID3D12DeviceRemovedExtendedData2_Vtbl, 0x67fc5816_e4ca_4915_bf18_42541272da54);
 impl core::ops::Deref for ID3D12DeviceRemovedExtendedData2 {
@@ -11553,6 +12992,30 @@ impl ID3D12DeviceRemovedExtendedData2_Vtbl {
         iid == &<ID3D12DeviceRemovedExtendedData2 as windows_core::Interface>::IID || iid == &<ID3D12DeviceRemovedExtendedData as windows_core::Interface>::IID || iid == &<ID3D12DeviceRemovedExtendedData1 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12DeviceRemovedExtendedData2_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12DeviceRemovedExtendedData2_Vtbl
+where
+    ID3D12DeviceRemovedExtendedData1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPageFaultAllocationOutput2<Identity: windows_core::IUnknownImpl + ID3D12DeviceRemovedExtendedData2_Impl>(this: *mut core::ffi::c_void, poutput: *mut D3D12_DRED_PAGE_FAULT_OUTPUT2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DeviceRemovedExtendedData2_Impl::GetPageFaultAllocationOutput2(this, core::mem::transmute_copy(&poutput)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceState<Identity: windows_core::IUnknownImpl + ID3D12DeviceRemovedExtendedData2_Impl>(this: *mut core::ffi::c_void) -> D3D12_DRED_DEVICE_STATE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DeviceRemovedExtendedData2_Impl::GetDeviceState(this)
+            }
+        }
+        Self {
+            base__: <ID3D12DeviceRemovedExtendedData1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPageFaultAllocationOutput2: GetPageFaultAllocationOutput2::<Identity>,
+            GetDeviceState: GetDeviceState::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12DeviceRemovedExtendedData2 {}
 windows_core::imp::define_interface!(ID3D12DeviceRemovedExtendedDataSettings, ID3D12DeviceRemovedExtendedDataSettings_Vtbl, 0x82bc481c_6b9b_4030_aedb_7ee3d1df1e63);
 windows_core::imp::interface_hierarchy!(ID3D12DeviceRemovedExtendedDataSettings, windows_core::IUnknown);
@@ -11613,6 +13076,37 @@ impl ID3D12DeviceRemovedExtendedDataSettings_Vtbl {
         iid == &<ID3D12DeviceRemovedExtendedDataSettings as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12DeviceRemovedExtendedDataSettings_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12DeviceRemovedExtendedDataSettings_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAutoBreadcrumbsEnablement<Identity: windows_core::IUnknownImpl + ID3D12DeviceRemovedExtendedDataSettings_Impl>(this: *mut core::ffi::c_void, enablement: D3D12_DRED_ENABLEMENT) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DeviceRemovedExtendedDataSettings_Impl::SetAutoBreadcrumbsEnablement(this, core::mem::transmute_copy(&enablement))
+            }
+        }
+        unsafe extern "system" fn SetPageFaultEnablement<Identity: windows_core::IUnknownImpl + ID3D12DeviceRemovedExtendedDataSettings_Impl>(this: *mut core::ffi::c_void, enablement: D3D12_DRED_ENABLEMENT) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DeviceRemovedExtendedDataSettings_Impl::SetPageFaultEnablement(this, core::mem::transmute_copy(&enablement))
+            }
+        }
+        unsafe extern "system" fn SetWatsonDumpEnablement<Identity: windows_core::IUnknownImpl + ID3D12DeviceRemovedExtendedDataSettings_Impl>(this: *mut core::ffi::c_void, enablement: D3D12_DRED_ENABLEMENT) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DeviceRemovedExtendedDataSettings_Impl::SetWatsonDumpEnablement(this, core::mem::transmute_copy(&enablement))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAutoBreadcrumbsEnablement: SetAutoBreadcrumbsEnablement::<Identity>,
+            SetPageFaultEnablement: SetPageFaultEnablement::<Identity>,
+            SetWatsonDumpEnablement: SetWatsonDumpEnablement::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12DeviceRemovedExtendedDataSettings {}
 windows_core::imp::define_interface!(ID3D12DeviceRemovedExtendedDataSettings1, ID3D12DeviceRemovedExtendedDataSettings1_Vtbl, 0xdbd5ae51_3317_4f0a_adf9_1d7cedcaae0b);
 impl core::ops::Deref for ID3D12DeviceRemovedExtendedDataSettings1 {
@@ -11655,6 +13149,23 @@ impl ID3D12DeviceRemovedExtendedDataSettings1_Vtbl {
         iid == &<ID3D12DeviceRemovedExtendedDataSettings1 as windows_core::Interface>::IID || iid == &<ID3D12DeviceRemovedExtendedDataSettings as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12DeviceRemovedExtendedDataSettings1_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12DeviceRemovedExtendedDataSettings1_Vtbl
+where
+    ID3D12DeviceRemovedExtendedDataSettings_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetBreadcrumbContextEnablement<Identity: windows_core::IUnknownImpl + ID3D12DeviceRemovedExtendedDataSettings1_Impl>(this: *mut core::ffi::c_void, enablement: D3D12_DRED_ENABLEMENT) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DeviceRemovedExtendedDataSettings1_Impl::SetBreadcrumbContextEnablement(this, core::mem::transmute_copy(&enablement))
+            }
+        }
+        Self {
+            base__: <ID3D12DeviceRemovedExtendedDataSettings_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetBreadcrumbContextEnablement: SetBreadcrumbContextEnablement::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12DeviceRemovedExtendedDataSettings1 {}
 windows_core::imp::define_interface!(ID3D12DeviceRemovedExtendedDataSettings2, ID3D12DeviceRemovedExtendedDataSettings2_Vtbl, 0x61552388_01ab_4008_a436_83db189566ea);
 impl core::ops::Deref for ID3D12DeviceRemovedExtendedDataSettings2 {
@@ -11697,6 +13208,23 @@ impl ID3D12DeviceRemovedExtendedDataSettings2_Vtbl {
         iid == &<ID3D12DeviceRemovedExtendedDataSettings2 as windows_core::Interface>::IID || iid == &<ID3D12DeviceRemovedExtendedDataSettings as windows_core::Interface>::IID || iid == &<ID3D12DeviceRemovedExtendedDataSettings1 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12DeviceRemovedExtendedDataSettings2_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12DeviceRemovedExtendedDataSettings2_Vtbl
+where
+    ID3D12DeviceRemovedExtendedDataSettings1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn UseMarkersOnlyAutoBreadcrumbs<Identity: windows_core::IUnknownImpl + ID3D12DeviceRemovedExtendedDataSettings2_Impl>(this: *mut core::ffi::c_void, markersonly: windows_core::BOOL) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12DeviceRemovedExtendedDataSettings2_Impl::UseMarkersOnlyAutoBreadcrumbs(this, core::mem::transmute_copy(&markersonly))
+            }
+        }
+        Self {
+            base__: <ID3D12DeviceRemovedExtendedDataSettings1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            UseMarkersOnlyAutoBreadcrumbs: UseMarkersOnlyAutoBreadcrumbs::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12DeviceRemovedExtendedDataSettings2 {}
 windows_core::imp::define_interface!(ID3D12Fence, ID3D12Fence_Vtbl, 0x0a753dcf_c4d8_4b91_adf6_be5a60d95a76);
 impl core::ops::Deref for ID3D12Fence {
@@ -11763,6 +13291,37 @@ impl ID3D12Fence_Vtbl {
         iid == &<ID3D12Fence as windows_core::Interface>::IID || iid == &<ID3D12Object as windows_core::Interface>::IID || iid == &<ID3D12DeviceChild as windows_core::Interface>::IID || iid == &<ID3D12Pageable as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12Fence_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Fence_Vtbl
+where
+    ID3D12Pageable_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCompletedValue<Identity: windows_core::IUnknownImpl + ID3D12Fence_Impl>(this: *mut core::ffi::c_void) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Fence_Impl::GetCompletedValue(this)
+            }
+        }
+        unsafe extern "system" fn SetEventOnCompletion<Identity: windows_core::IUnknownImpl + ID3D12Fence_Impl>(this: *mut core::ffi::c_void, value: u64, hevent: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Fence_Impl::SetEventOnCompletion(this, core::mem::transmute_copy(&value), core::mem::transmute_copy(&hevent)).into()
+            }
+        }
+        unsafe extern "system" fn Signal<Identity: windows_core::IUnknownImpl + ID3D12Fence_Impl>(this: *mut core::ffi::c_void, value: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Fence_Impl::Signal(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        Self {
+            base__: <ID3D12Pageable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCompletedValue: GetCompletedValue::<Identity>,
+            SetEventOnCompletion: SetEventOnCompletion::<Identity>,
+            Signal: Signal::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12Fence {}
 windows_core::imp::define_interface!(ID3D12Fence1, ID3D12Fence1_Vtbl, 0x433685fe_e22b_4ca0_a8db_b5b4f4dd0e4a);
 impl core::ops::Deref for ID3D12Fence1 {
@@ -11802,6 +13361,20 @@ impl ID3D12Fence1_Vtbl {
         iid == &<ID3D12Fence1 as windows_core::Interface>::IID || iid == &<ID3D12Object as windows_core::Interface>::IID || iid == &<ID3D12DeviceChild as windows_core::Interface>::IID || iid == &<ID3D12Pageable as windows_core::Interface>::IID || iid == &<ID3D12Fence as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12Fence1_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Fence1_Vtbl
+where
+    ID3D12Fence_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCreationFlags<Identity: windows_core::IUnknownImpl + ID3D12Fence1_Impl>(this: *mut core::ffi::c_void) -> D3D12_FENCE_FLAGS {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Fence1_Impl::GetCreationFlags(this)
+            }
+        }
+        Self { base__: <ID3D12Fence_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetCreationFlags: GetCreationFlags::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ID3D12Fence1 {}
 windows_core::imp::define_interface!(ID3D12FunctionParameterReflection, ID3D12FunctionParameterReflection_Vtbl);
 impl ID3D12FunctionParameterReflection {
@@ -12128,6 +13701,71 @@ impl ID3D12GBVDiagnostics_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + ID3D12GBVDiagnostics_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12GBVDiagnostics_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetGBVEntireSubresourceStatesData<Identity: windows_core::IUnknownImpl + ID3D12GBVDiagnostics_Impl>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdata: *mut i32, datasize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GBVDiagnostics_Impl::GetGBVEntireSubresourceStatesData(this, core::mem::transmute_copy(&presource), core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&datasize)).into()
+            }
+        }
+        unsafe extern "system" fn GetGBVSubresourceState<Identity: windows_core::IUnknownImpl + ID3D12GBVDiagnostics_Impl>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, subresource: u32, pdata: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D12GBVDiagnostics_Impl::GetGBVSubresourceState(this, core::mem::transmute_copy(&presource), core::mem::transmute_copy(&subresource)) {
+                    Ok(ok__) => {
+                        pdata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetGBVResourceUniformState<Identity: windows_core::IUnknownImpl + ID3D12GBVDiagnostics_Impl>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdata: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D12GBVDiagnostics_Impl::GetGBVResourceUniformState(this, core::mem::transmute_copy(&presource)) {
+                    Ok(ok__) => {
+                        pdata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetGBVResourceInfo<Identity: windows_core::IUnknownImpl + ID3D12GBVDiagnostics_Impl>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, presourcedesc: *const D3D12_RESOURCE_DESC, presourcehash: *const u32, psubresourcestatesbyteoffset: *const u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GBVDiagnostics_Impl::GetGBVResourceInfo(this, core::mem::transmute_copy(&presource), core::mem::transmute_copy(&presourcedesc), core::mem::transmute_copy(&presourcehash), core::mem::transmute_copy(&psubresourcestatesbyteoffset)).into()
+            }
+        }
+        unsafe extern "system" fn GBVReserved0<Identity: windows_core::IUnknownImpl + ID3D12GBVDiagnostics_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GBVDiagnostics_Impl::GBVReserved0(this)
+            }
+        }
+        unsafe extern "system" fn GBVReserved1<Identity: windows_core::IUnknownImpl + ID3D12GBVDiagnostics_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GBVDiagnostics_Impl::GBVReserved1(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetGBVEntireSubresourceStatesData: GetGBVEntireSubresourceStatesData::<Identity>,
+            GetGBVSubresourceState: GetGBVSubresourceState::<Identity>,
+            GetGBVResourceUniformState: GetGBVResourceUniformState::<Identity>,
+            GetGBVResourceInfo: GetGBVResourceInfo::<Identity>,
+            GBVReserved0: GBVReserved0::<Identity>,
+            GBVReserved1: GBVReserved1::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for ID3D12GBVDiagnostics {}
 windows_core::imp::define_interface!(ID3D12GraphicsCommandList, ID3D12GraphicsCommandList_Vtbl, 0x5b160d0f_ac1b_4185_8ba8_b3ae42a5a455);
 impl core::ops::Deref for ID3D12GraphicsCommandList {
@@ -12851,13 +14489,381 @@ impl ID3D12GraphicsCommandList_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
-impl windows_core::RuntimeName for ID3D12GraphicsCommandList {}
-windows_core::imp::define_interface!(ID3D12GraphicsCommandList1, ID3D12GraphicsCommandList1_Vtbl, 0x553103fb_1fe7_4557_bb38_946d7d0e7ca7);
-impl core::ops::Deref for ID3D12GraphicsCommandList1 {
-    type Target = ID3D12GraphicsCommandList;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
+impl<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12GraphicsCommandList_Vtbl
+where
+    ID3D12CommandList_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::Close(this).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, pallocator: *mut core::ffi::c_void, pinitialstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::Reset(this, core::mem::transmute_copy(&pallocator), core::mem::transmute_copy(&pinitialstate)).into()
+            }
+        }
+        unsafe extern "system" fn ClearState<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, ppipelinestate: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::ClearState(this, core::mem::transmute_copy(&ppipelinestate))
+            }
+        }
+        unsafe extern "system" fn DrawInstanced<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, vertexcountperinstance: u32, instancecount: u32, startvertexlocation: u32, startinstancelocation: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::DrawInstanced(this, core::mem::transmute_copy(&vertexcountperinstance), core::mem::transmute_copy(&instancecount), core::mem::transmute_copy(&startvertexlocation), core::mem::transmute_copy(&startinstancelocation))
+            }
+        }
+        unsafe extern "system" fn DrawIndexedInstanced<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, indexcountperinstance: u32, instancecount: u32, startindexlocation: u32, basevertexlocation: i32, startinstancelocation: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::DrawIndexedInstanced(this, core::mem::transmute_copy(&indexcountperinstance), core::mem::transmute_copy(&instancecount), core::mem::transmute_copy(&startindexlocation), core::mem::transmute_copy(&basevertexlocation), core::mem::transmute_copy(&startinstancelocation))
+            }
+        }
+        unsafe extern "system" fn Dispatch<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, threadgroupcountx: u32, threadgroupcounty: u32, threadgroupcountz: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::Dispatch(this, core::mem::transmute_copy(&threadgroupcountx), core::mem::transmute_copy(&threadgroupcounty), core::mem::transmute_copy(&threadgroupcountz))
+            }
+        }
+        unsafe extern "system" fn CopyBufferRegion<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, pdstbuffer: *mut core::ffi::c_void, dstoffset: u64, psrcbuffer: *mut core::ffi::c_void, srcoffset: u64, numbytes: u64) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::CopyBufferRegion(this, core::mem::transmute_copy(&pdstbuffer), core::mem::transmute_copy(&dstoffset), core::mem::transmute_copy(&psrcbuffer), core::mem::transmute_copy(&srcoffset), core::mem::transmute_copy(&numbytes))
+            }
+        }
+        unsafe extern "system" fn CopyTextureRegion<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, pdst: *const D3D12_TEXTURE_COPY_LOCATION, dstx: u32, dsty: u32, dstz: u32, psrc: *const D3D12_TEXTURE_COPY_LOCATION, psrcbox: *const D3D12_BOX) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::CopyTextureRegion(this, core::mem::transmute_copy(&pdst), core::mem::transmute_copy(&dstx), core::mem::transmute_copy(&dsty), core::mem::transmute_copy(&dstz), core::mem::transmute_copy(&psrc), core::mem::transmute_copy(&psrcbox))
+            }
+        }
+        unsafe extern "system" fn CopyResource<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, pdstresource: *mut core::ffi::c_void, psrcresource: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::CopyResource(this, core::mem::transmute_copy(&pdstresource), core::mem::transmute_copy(&psrcresource))
+            }
+        }
+        unsafe extern "system" fn CopyTiles<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, ptiledresource: *mut core::ffi::c_void, ptileregionstartcoordinate: *const D3D12_TILED_RESOURCE_COORDINATE, ptileregionsize: *const D3D12_TILE_REGION_SIZE, pbuffer: *mut core::ffi::c_void, bufferstartoffsetinbytes: u64, flags: D3D12_TILE_COPY_FLAGS) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::CopyTiles(this, core::mem::transmute_copy(&ptiledresource), core::mem::transmute_copy(&ptileregionstartcoordinate), core::mem::transmute_copy(&ptileregionsize), core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&bufferstartoffsetinbytes), core::mem::transmute_copy(&flags))
+            }
+        }
+        unsafe extern "system" fn ResolveSubresource<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, pdstresource: *mut core::ffi::c_void, dstsubresource: u32, psrcresource: *mut core::ffi::c_void, srcsubresource: u32, format: super::Dxgi::Common::DXGI_FORMAT) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::ResolveSubresource(this, core::mem::transmute_copy(&pdstresource), core::mem::transmute_copy(&dstsubresource), core::mem::transmute_copy(&psrcresource), core::mem::transmute_copy(&srcsubresource), core::mem::transmute_copy(&format))
+            }
+        }
+        unsafe extern "system" fn IASetPrimitiveTopology<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, primitivetopology: super::Direct3D::D3D_PRIMITIVE_TOPOLOGY) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::IASetPrimitiveTopology(this, core::mem::transmute_copy(&primitivetopology))
+            }
+        }
+        unsafe extern "system" fn RSSetViewports<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, numviewports: u32, pviewports: *const D3D12_VIEWPORT) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::RSSetViewports(this, core::mem::transmute_copy(&numviewports), core::mem::transmute_copy(&pviewports))
+            }
+        }
+        unsafe extern "system" fn RSSetScissorRects<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, numrects: u32, prects: *const super::super::Foundation::RECT) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::RSSetScissorRects(this, core::mem::transmute_copy(&numrects), core::mem::transmute_copy(&prects))
+            }
+        }
+        unsafe extern "system" fn OMSetBlendFactor<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, blendfactor: *const f32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::OMSetBlendFactor(this, core::mem::transmute_copy(&blendfactor))
+            }
+        }
+        unsafe extern "system" fn OMSetStencilRef<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, stencilref: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::OMSetStencilRef(this, core::mem::transmute_copy(&stencilref))
+            }
+        }
+        unsafe extern "system" fn SetPipelineState<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, ppipelinestate: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::SetPipelineState(this, core::mem::transmute_copy(&ppipelinestate))
+            }
+        }
+        unsafe extern "system" fn ResourceBarrier<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, numbarriers: u32, pbarriers: *const D3D12_RESOURCE_BARRIER) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::ResourceBarrier(this, core::mem::transmute_copy(&numbarriers), core::mem::transmute_copy(&pbarriers))
+            }
+        }
+        unsafe extern "system" fn ExecuteBundle<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, pcommandlist: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::ExecuteBundle(this, core::mem::transmute_copy(&pcommandlist))
+            }
+        }
+        unsafe extern "system" fn SetDescriptorHeaps<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, numdescriptorheaps: u32, ppdescriptorheaps: *const *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::SetDescriptorHeaps(this, core::mem::transmute_copy(&numdescriptorheaps), core::mem::transmute_copy(&ppdescriptorheaps))
+            }
+        }
+        unsafe extern "system" fn SetComputeRootSignature<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, prootsignature: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::SetComputeRootSignature(this, core::mem::transmute_copy(&prootsignature))
+            }
+        }
+        unsafe extern "system" fn SetGraphicsRootSignature<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, prootsignature: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::SetGraphicsRootSignature(this, core::mem::transmute_copy(&prootsignature))
+            }
+        }
+        unsafe extern "system" fn SetComputeRootDescriptorTable<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, rootparameterindex: u32, basedescriptor: D3D12_GPU_DESCRIPTOR_HANDLE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::SetComputeRootDescriptorTable(this, core::mem::transmute_copy(&rootparameterindex), core::mem::transmute(&basedescriptor))
+            }
+        }
+        unsafe extern "system" fn SetGraphicsRootDescriptorTable<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, rootparameterindex: u32, basedescriptor: D3D12_GPU_DESCRIPTOR_HANDLE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::SetGraphicsRootDescriptorTable(this, core::mem::transmute_copy(&rootparameterindex), core::mem::transmute(&basedescriptor))
+            }
+        }
+        unsafe extern "system" fn SetComputeRoot32BitConstant<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, rootparameterindex: u32, srcdata: u32, destoffsetin32bitvalues: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::SetComputeRoot32BitConstant(this, core::mem::transmute_copy(&rootparameterindex), core::mem::transmute_copy(&srcdata), core::mem::transmute_copy(&destoffsetin32bitvalues))
+            }
+        }
+        unsafe extern "system" fn SetGraphicsRoot32BitConstant<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, rootparameterindex: u32, srcdata: u32, destoffsetin32bitvalues: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::SetGraphicsRoot32BitConstant(this, core::mem::transmute_copy(&rootparameterindex), core::mem::transmute_copy(&srcdata), core::mem::transmute_copy(&destoffsetin32bitvalues))
+            }
+        }
+        unsafe extern "system" fn SetComputeRoot32BitConstants<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, rootparameterindex: u32, num32bitvaluestoset: u32, psrcdata: *const core::ffi::c_void, destoffsetin32bitvalues: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::SetComputeRoot32BitConstants(this, core::mem::transmute_copy(&rootparameterindex), core::mem::transmute_copy(&num32bitvaluestoset), core::mem::transmute_copy(&psrcdata), core::mem::transmute_copy(&destoffsetin32bitvalues))
+            }
+        }
+        unsafe extern "system" fn SetGraphicsRoot32BitConstants<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, rootparameterindex: u32, num32bitvaluestoset: u32, psrcdata: *const core::ffi::c_void, destoffsetin32bitvalues: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::SetGraphicsRoot32BitConstants(this, core::mem::transmute_copy(&rootparameterindex), core::mem::transmute_copy(&num32bitvaluestoset), core::mem::transmute_copy(&psrcdata), core::mem::transmute_copy(&destoffsetin32bitvalues))
+            }
+        }
+        unsafe extern "system" fn SetComputeRootConstantBufferView<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, rootparameterindex: u32, bufferlocation: u64) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::SetComputeRootConstantBufferView(this, core::mem::transmute_copy(&rootparameterindex), core::mem::transmute_copy(&bufferlocation))
+            }
+        }
+        unsafe extern "system" fn SetGraphicsRootConstantBufferView<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, rootparameterindex: u32, bufferlocation: u64) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::SetGraphicsRootConstantBufferView(this, core::mem::transmute_copy(&rootparameterindex), core::mem::transmute_copy(&bufferlocation))
+            }
+        }
+        unsafe extern "system" fn SetComputeRootShaderResourceView<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, rootparameterindex: u32, bufferlocation: u64) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::SetComputeRootShaderResourceView(this, core::mem::transmute_copy(&rootparameterindex), core::mem::transmute_copy(&bufferlocation))
+            }
+        }
+        unsafe extern "system" fn SetGraphicsRootShaderResourceView<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, rootparameterindex: u32, bufferlocation: u64) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::SetGraphicsRootShaderResourceView(this, core::mem::transmute_copy(&rootparameterindex), core::mem::transmute_copy(&bufferlocation))
+            }
+        }
+        unsafe extern "system" fn SetComputeRootUnorderedAccessView<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, rootparameterindex: u32, bufferlocation: u64) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::SetComputeRootUnorderedAccessView(this, core::mem::transmute_copy(&rootparameterindex), core::mem::transmute_copy(&bufferlocation))
+            }
+        }
+        unsafe extern "system" fn SetGraphicsRootUnorderedAccessView<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, rootparameterindex: u32, bufferlocation: u64) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::SetGraphicsRootUnorderedAccessView(this, core::mem::transmute_copy(&rootparameterindex), core::mem::transmute_copy(&bufferlocation))
+            }
+        }
+        unsafe extern "system" fn IASetIndexBuffer<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, pview: *const D3D12_INDEX_BUFFER_VIEW) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::IASetIndexBuffer(this, core::mem::transmute_copy(&pview))
+            }
+        }
+        unsafe extern "system" fn IASetVertexBuffers<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, pviews: *const D3D12_VERTEX_BUFFER_VIEW) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::IASetVertexBuffers(this, core::mem::transmute_copy(&startslot), core::mem::transmute_copy(&numviews), core::mem::transmute_copy(&pviews))
+            }
+        }
+        unsafe extern "system" fn SOSetTargets<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, pviews: *const D3D12_STREAM_OUTPUT_BUFFER_VIEW) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::SOSetTargets(this, core::mem::transmute_copy(&startslot), core::mem::transmute_copy(&numviews), core::mem::transmute_copy(&pviews))
+            }
+        }
+        unsafe extern "system" fn OMSetRenderTargets<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, numrendertargetdescriptors: u32, prendertargetdescriptors: *const D3D12_CPU_DESCRIPTOR_HANDLE, rtssinglehandletodescriptorrange: windows_core::BOOL, pdepthstencildescriptor: *const D3D12_CPU_DESCRIPTOR_HANDLE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::OMSetRenderTargets(this, core::mem::transmute_copy(&numrendertargetdescriptors), core::mem::transmute_copy(&prendertargetdescriptors), core::mem::transmute_copy(&rtssinglehandletodescriptorrange), core::mem::transmute_copy(&pdepthstencildescriptor))
+            }
+        }
+        unsafe extern "system" fn ClearDepthStencilView<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, depthstencilview: D3D12_CPU_DESCRIPTOR_HANDLE, clearflags: D3D12_CLEAR_FLAGS, depth: f32, stencil: u8, numrects: u32, prects: *const super::super::Foundation::RECT) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::ClearDepthStencilView(this, core::mem::transmute(&depthstencilview), core::mem::transmute_copy(&clearflags), core::mem::transmute_copy(&depth), core::mem::transmute_copy(&stencil), core::mem::transmute_copy(&numrects), core::mem::transmute_copy(&prects))
+            }
+        }
+        unsafe extern "system" fn ClearRenderTargetView<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, rendertargetview: D3D12_CPU_DESCRIPTOR_HANDLE, colorrgba: *const f32, numrects: u32, prects: *const super::super::Foundation::RECT) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::ClearRenderTargetView(this, core::mem::transmute(&rendertargetview), core::mem::transmute_copy(&colorrgba), core::mem::transmute_copy(&numrects), core::mem::transmute_copy(&prects))
+            }
+        }
+        unsafe extern "system" fn ClearUnorderedAccessViewUint<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, viewgpuhandleincurrentheap: D3D12_GPU_DESCRIPTOR_HANDLE, viewcpuhandle: D3D12_CPU_DESCRIPTOR_HANDLE, presource: *mut core::ffi::c_void, values: *const u32, numrects: u32, prects: *const super::super::Foundation::RECT) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::ClearUnorderedAccessViewUint(this, core::mem::transmute(&viewgpuhandleincurrentheap), core::mem::transmute(&viewcpuhandle), core::mem::transmute_copy(&presource), core::mem::transmute_copy(&values), core::mem::transmute_copy(&numrects), core::mem::transmute_copy(&prects))
+            }
+        }
+        unsafe extern "system" fn ClearUnorderedAccessViewFloat<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, viewgpuhandleincurrentheap: D3D12_GPU_DESCRIPTOR_HANDLE, viewcpuhandle: D3D12_CPU_DESCRIPTOR_HANDLE, presource: *mut core::ffi::c_void, values: *const f32, numrects: u32, prects: *const super::super::Foundation::RECT) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::ClearUnorderedAccessViewFloat(this, core::mem::transmute(&viewgpuhandleincurrentheap), core::mem::transmute(&viewcpuhandle), core::mem::transmute_copy(&presource), core::mem::transmute_copy(&values), core::mem::transmute_copy(&numrects), core::mem::transmute_copy(&prects))
+            }
+        }
+        unsafe extern "system" fn DiscardResource<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pregion: *const D3D12_DISCARD_REGION) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::DiscardResource(this, core::mem::transmute_copy(&presource), core::mem::transmute_copy(&pregion))
+            }
+        }
+        unsafe extern "system" fn BeginQuery<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, pqueryheap: *mut core::ffi::c_void, r#type: D3D12_QUERY_TYPE, index: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::BeginQuery(this, core::mem::transmute_copy(&pqueryheap), core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&index))
+            }
+        }
+        unsafe extern "system" fn EndQuery<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, pqueryheap: *mut core::ffi::c_void, r#type: D3D12_QUERY_TYPE, index: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::EndQuery(this, core::mem::transmute_copy(&pqueryheap), core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&index))
+            }
+        }
+        unsafe extern "system" fn ResolveQueryData<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, pqueryheap: *mut core::ffi::c_void, r#type: D3D12_QUERY_TYPE, startindex: u32, numqueries: u32, pdestinationbuffer: *mut core::ffi::c_void, aligneddestinationbufferoffset: u64) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::ResolveQueryData(this, core::mem::transmute_copy(&pqueryheap), core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&startindex), core::mem::transmute_copy(&numqueries), core::mem::transmute_copy(&pdestinationbuffer), core::mem::transmute_copy(&aligneddestinationbufferoffset))
+            }
+        }
+        unsafe extern "system" fn SetPredication<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, pbuffer: *mut core::ffi::c_void, alignedbufferoffset: u64, operation: D3D12_PREDICATION_OP) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::SetPredication(this, core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&alignedbufferoffset), core::mem::transmute_copy(&operation))
+            }
+        }
+        unsafe extern "system" fn SetMarker<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, metadata: u32, pdata: *const core::ffi::c_void, size: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::SetMarker(this, core::mem::transmute_copy(&metadata), core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&size))
+            }
+        }
+        unsafe extern "system" fn BeginEvent<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, metadata: u32, pdata: *const core::ffi::c_void, size: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::BeginEvent(this, core::mem::transmute_copy(&metadata), core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&size))
+            }
+        }
+        unsafe extern "system" fn EndEvent<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::EndEvent(this)
+            }
+        }
+        unsafe extern "system" fn ExecuteIndirect<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList_Impl>(this: *mut core::ffi::c_void, pcommandsignature: *mut core::ffi::c_void, maxcommandcount: u32, pargumentbuffer: *mut core::ffi::c_void, argumentbufferoffset: u64, pcountbuffer: *mut core::ffi::c_void, countbufferoffset: u64) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList_Impl::ExecuteIndirect(this, core::mem::transmute_copy(&pcommandsignature), core::mem::transmute_copy(&maxcommandcount), core::mem::transmute_copy(&pargumentbuffer), core::mem::transmute_copy(&argumentbufferoffset), core::mem::transmute_copy(&pcountbuffer), core::mem::transmute_copy(&countbufferoffset))
+            }
+        }
+        Self {
+            base__: <ID3D12CommandList_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Close: Close::<Identity>,
+            Reset: Reset::<Identity>,
+            ClearState: ClearState::<Identity>,
+            DrawInstanced: DrawInstanced::<Identity>,
+            DrawIndexedInstanced: DrawIndexedInstanced::<Identity>,
+            Dispatch: Dispatch::<Identity>,
+            CopyBufferRegion: CopyBufferRegion::<Identity>,
+            CopyTextureRegion: CopyTextureRegion::<Identity>,
+            CopyResource: CopyResource::<Identity>,
+            CopyTiles: CopyTiles::<Identity>,
+            ResolveSubresource: ResolveSubresource::<Identity>,
+            IASetPrimitiveTopology: IASetPrimitiveTopology::<Identity>,
+            RSSetViewports: RSSetViewports::<Identity>,
+            RSSetScissorRects: RSSetScissorRects::<Identity>,
+            OMSetBlendFactor: OMSetBlendFactor::<Identity>,
+            OMSetStencilRef: OMSetStencilRef::<Identity>,
+            SetPipelineState: SetPipelineState::<Identity>,
+            ResourceBarrier: ResourceBarrier::<Identity>,
+            ExecuteBundle: ExecuteBundle::<Identity>,
+            SetDescriptorHeaps: SetDescriptorHeaps::<Identity>,
+            SetComputeRootSignature: SetComputeRootSignature::<Identity>,
+            SetGraphicsRootSignature: SetGraphicsRootSignature::<Identity>,
+            SetComputeRootDescriptorTable: SetComputeRootDescriptorTable::<Identity>,
+            SetGraphicsRootDescriptorTable: SetGraphicsRootDescriptorTable::<Identity>,
+            SetComputeRoot32BitConstant: SetComputeRoot32BitConstant::<Identity>,
+            SetGraphicsRoot32BitConstant: SetGraphicsRoot32BitConstant::<Identity>,
+            SetComputeRoot32BitConstants: SetComputeRoot32BitConstants::<Identity>,
+            SetGraphicsRoot32BitConstants: SetGraphicsRoot32BitConstants::<Identity>,
+            SetComputeRootConstantBufferView: SetComputeRootConstantBufferView::<Identity>,
+            SetGraphicsRootConstantBufferView: SetGraphicsRootConstantBufferView::<Identity>,
+            SetComputeRootShaderResourceView: SetComputeRootShaderResourceView::<Identity>,
+            SetGraphicsRootShaderResourceView: SetGraphicsRootShaderResourceView::<Identity>,
+            SetComputeRootUnorderedAccessView: SetComputeRootUnorderedAccessView::<Identity>,
+            SetGraphicsRootUnorderedAccessView: SetGraphicsRootUnorderedAccessView::<Identity>,
+            IASetIndexBuffer: IASetIndexBuffer::<Identity>,
+            IASetVertexBuffers: IASetVertexBuffers::<Identity>,
+            SOSetTargets: SOSetTargets::<Identity>,
+            OMSetRenderTargets: OMSetRenderTargets::<Identity>,
+            ClearDepthStencilView: ClearDepthStencilView::<Identity>,
+            ClearRenderTargetView: ClearRenderTargetView::<Identity>,
+            ClearUnorderedAccessViewUint: ClearUnorderedAccessViewUint::<Identity>,
+            ClearUnorderedAccessViewFloat: ClearUnorderedAccessViewFloat::<Identity>,
+            DiscardResource: DiscardResource::<Identity>,
+            BeginQuery: BeginQuery::<Identity>,
+            EndQuery: EndQuery::<Identity>,
+            ResolveQueryData: ResolveQueryData::<Identity>,
+            SetPredication: SetPredication::<Identity>,
+            SetMarker: SetMarker::<Identity>,
+            BeginEvent: BeginEvent::<Identity>,
+            EndEvent: EndEvent::<Identity>,
+            ExecuteIndirect: ExecuteIndirect::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::RuntimeName for ID3D12GraphicsCommandList {}
+windows_core::imp::define_interface!(ID3D12GraphicsCommandList1, ID3D12GraphicsCommandList1_Vtbl, 0x553103fb_1fe7_4557_bb38_946d7d0e7ca7);
+impl core::ops::Deref for ID3D12GraphicsCommandList1 {
+    type Target = ID3D12GraphicsCommandList;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
 }
 windows_core::imp::interface_hierarchy!(ID3D12GraphicsCommandList1, windows_core::IUnknown, ID3D12Object, ID3D12DeviceChild, ID3D12CommandList, ID3D12GraphicsCommandList);
 impl ID3D12GraphicsCommandList1 {
@@ -12972,6 +14978,59 @@ impl ID3D12GraphicsCommandList1_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList1_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12GraphicsCommandList1_Vtbl
+where
+    ID3D12GraphicsCommandList_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AtomicCopyBufferUINT<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList1_Impl>(this: *mut core::ffi::c_void, pdstbuffer: *mut core::ffi::c_void, dstoffset: u64, psrcbuffer: *mut core::ffi::c_void, srcoffset: u64, dependencies: u32, ppdependentresources: *const *mut core::ffi::c_void, pdependentsubresourceranges: *const D3D12_SUBRESOURCE_RANGE_UINT64) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList1_Impl::AtomicCopyBufferUINT(this, core::mem::transmute_copy(&pdstbuffer), core::mem::transmute_copy(&dstoffset), core::mem::transmute_copy(&psrcbuffer), core::mem::transmute_copy(&srcoffset), core::mem::transmute_copy(&dependencies), core::mem::transmute_copy(&ppdependentresources), core::mem::transmute_copy(&pdependentsubresourceranges))
+            }
+        }
+        unsafe extern "system" fn AtomicCopyBufferUINT64<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList1_Impl>(this: *mut core::ffi::c_void, pdstbuffer: *mut core::ffi::c_void, dstoffset: u64, psrcbuffer: *mut core::ffi::c_void, srcoffset: u64, dependencies: u32, ppdependentresources: *const *mut core::ffi::c_void, pdependentsubresourceranges: *const D3D12_SUBRESOURCE_RANGE_UINT64) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList1_Impl::AtomicCopyBufferUINT64(this, core::mem::transmute_copy(&pdstbuffer), core::mem::transmute_copy(&dstoffset), core::mem::transmute_copy(&psrcbuffer), core::mem::transmute_copy(&srcoffset), core::mem::transmute_copy(&dependencies), core::mem::transmute_copy(&ppdependentresources), core::mem::transmute_copy(&pdependentsubresourceranges))
+            }
+        }
+        unsafe extern "system" fn OMSetDepthBounds<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList1_Impl>(this: *mut core::ffi::c_void, min: f32, max: f32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList1_Impl::OMSetDepthBounds(this, core::mem::transmute_copy(&min), core::mem::transmute_copy(&max))
+            }
+        }
+        unsafe extern "system" fn SetSamplePositions<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList1_Impl>(this: *mut core::ffi::c_void, numsamplesperpixel: u32, numpixels: u32, psamplepositions: *const D3D12_SAMPLE_POSITION) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList1_Impl::SetSamplePositions(this, core::mem::transmute_copy(&numsamplesperpixel), core::mem::transmute_copy(&numpixels), core::mem::transmute_copy(&psamplepositions))
+            }
+        }
+        unsafe extern "system" fn ResolveSubresourceRegion<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList1_Impl>(this: *mut core::ffi::c_void, pdstresource: *mut core::ffi::c_void, dstsubresource: u32, dstx: u32, dsty: u32, psrcresource: *mut core::ffi::c_void, srcsubresource: u32, psrcrect: *const super::super::Foundation::RECT, format: super::Dxgi::Common::DXGI_FORMAT, resolvemode: D3D12_RESOLVE_MODE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList1_Impl::ResolveSubresourceRegion(this, core::mem::transmute_copy(&pdstresource), core::mem::transmute_copy(&dstsubresource), core::mem::transmute_copy(&dstx), core::mem::transmute_copy(&dsty), core::mem::transmute_copy(&psrcresource), core::mem::transmute_copy(&srcsubresource), core::mem::transmute_copy(&psrcrect), core::mem::transmute_copy(&format), core::mem::transmute_copy(&resolvemode))
+            }
+        }
+        unsafe extern "system" fn SetViewInstanceMask<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList1_Impl>(this: *mut core::ffi::c_void, mask: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList1_Impl::SetViewInstanceMask(this, core::mem::transmute_copy(&mask))
+            }
+        }
+        Self {
+            base__: <ID3D12GraphicsCommandList_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AtomicCopyBufferUINT: AtomicCopyBufferUINT::<Identity>,
+            AtomicCopyBufferUINT64: AtomicCopyBufferUINT64::<Identity>,
+            OMSetDepthBounds: OMSetDepthBounds::<Identity>,
+            SetSamplePositions: SetSamplePositions::<Identity>,
+            ResolveSubresourceRegion: ResolveSubresourceRegion::<Identity>,
+            SetViewInstanceMask: SetViewInstanceMask::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
 impl windows_core::RuntimeName for ID3D12GraphicsCommandList1 {}
 windows_core::imp::define_interface!(ID3D12GraphicsCommandList10, ID3D12GraphicsCommandList10_Vtbl, 0x7013c015_d161_4b63_a08c_238552dd8acc);
 impl core::ops::Deref for ID3D12GraphicsCommandList10 {
@@ -13042,6 +15101,31 @@ impl ID3D12GraphicsCommandList10_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList10_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12GraphicsCommandList10_Vtbl
+where
+    ID3D12GraphicsCommandList9_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetProgram<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList10_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_SET_PROGRAM_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList10_Impl::SetProgram(this, core::mem::transmute_copy(&pdesc))
+            }
+        }
+        unsafe extern "system" fn DispatchGraph<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList10_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_DISPATCH_GRAPH_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList10_Impl::DispatchGraph(this, core::mem::transmute_copy(&pdesc))
+            }
+        }
+        Self {
+            base__: <ID3D12GraphicsCommandList9_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetProgram: SetProgram::<Identity>,
+            DispatchGraph: DispatchGraph::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
 impl windows_core::RuntimeName for ID3D12GraphicsCommandList10 {}
 windows_core::imp::define_interface!(ID3D12GraphicsCommandList2, ID3D12GraphicsCommandList2_Vtbl, 0x38c3e585_ff17_412c_9150_4fc6f9d72a28);
 impl core::ops::Deref for ID3D12GraphicsCommandList2 {
@@ -13084,6 +15168,24 @@ impl ID3D12GraphicsCommandList2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList2_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12GraphicsCommandList2_Vtbl
+where
+    ID3D12GraphicsCommandList1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn WriteBufferImmediate<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList2_Impl>(this: *mut core::ffi::c_void, count: u32, pparams: *const D3D12_WRITEBUFFERIMMEDIATE_PARAMETER, pmodes: *const D3D12_WRITEBUFFERIMMEDIATE_MODE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList2_Impl::WriteBufferImmediate(this, core::mem::transmute_copy(&count), core::mem::transmute_copy(&pparams), core::mem::transmute_copy(&pmodes))
+            }
+        }
+        Self {
+            base__: <ID3D12GraphicsCommandList1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            WriteBufferImmediate: WriteBufferImmediate::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
 impl windows_core::RuntimeName for ID3D12GraphicsCommandList2 {}
 windows_core::imp::define_interface!(ID3D12GraphicsCommandList3, ID3D12GraphicsCommandList3_Vtbl, 0x6fda83a7_b84c_4e38_9ac8_c7bd22016b3d);
 impl core::ops::Deref for ID3D12GraphicsCommandList3 {
@@ -13129,6 +15231,24 @@ impl ID3D12GraphicsCommandList3_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList3_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12GraphicsCommandList3_Vtbl
+where
+    ID3D12GraphicsCommandList2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetProtectedResourceSession<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList3_Impl>(this: *mut core::ffi::c_void, pprotectedresourcesession: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList3_Impl::SetProtectedResourceSession(this, core::mem::transmute_copy(&pprotectedresourcesession))
+            }
+        }
+        Self {
+            base__: <ID3D12GraphicsCommandList2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetProtectedResourceSession: SetProtectedResourceSession::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
 impl windows_core::RuntimeName for ID3D12GraphicsCommandList3 {}
 windows_core::imp::define_interface!(ID3D12GraphicsCommandList4, ID3D12GraphicsCommandList4_Vtbl, 0x8754318e_d3a9_4541_98cf_645b50dc4874);
 impl core::ops::Deref for ID3D12GraphicsCommandList4 {
@@ -13287,6 +15407,80 @@ impl ID3D12GraphicsCommandList4_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList4_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12GraphicsCommandList4_Vtbl
+where
+    ID3D12GraphicsCommandList3_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BeginRenderPass<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList4_Impl>(this: *mut core::ffi::c_void, numrendertargets: u32, prendertargets: *const D3D12_RENDER_PASS_RENDER_TARGET_DESC, pdepthstencil: *const D3D12_RENDER_PASS_DEPTH_STENCIL_DESC, flags: D3D12_RENDER_PASS_FLAGS) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList4_Impl::BeginRenderPass(this, core::mem::transmute_copy(&numrendertargets), core::mem::transmute_copy(&prendertargets), core::mem::transmute_copy(&pdepthstencil), core::mem::transmute_copy(&flags))
+            }
+        }
+        unsafe extern "system" fn EndRenderPass<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList4_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList4_Impl::EndRenderPass(this)
+            }
+        }
+        unsafe extern "system" fn InitializeMetaCommand<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList4_Impl>(this: *mut core::ffi::c_void, pmetacommand: *mut core::ffi::c_void, pinitializationparametersdata: *const core::ffi::c_void, initializationparametersdatasizeinbytes: usize) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList4_Impl::InitializeMetaCommand(this, core::mem::transmute_copy(&pmetacommand), core::mem::transmute_copy(&pinitializationparametersdata), core::mem::transmute_copy(&initializationparametersdatasizeinbytes))
+            }
+        }
+        unsafe extern "system" fn ExecuteMetaCommand<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList4_Impl>(this: *mut core::ffi::c_void, pmetacommand: *mut core::ffi::c_void, pexecutionparametersdata: *const core::ffi::c_void, executionparametersdatasizeinbytes: usize) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList4_Impl::ExecuteMetaCommand(this, core::mem::transmute_copy(&pmetacommand), core::mem::transmute_copy(&pexecutionparametersdata), core::mem::transmute_copy(&executionparametersdatasizeinbytes))
+            }
+        }
+        unsafe extern "system" fn BuildRaytracingAccelerationStructure<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList4_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_BUILD_RAYTRACING_ACCELERATION_STRUCTURE_DESC, numpostbuildinfodescs: u32, ppostbuildinfodescs: *const D3D12_RAYTRACING_ACCELERATION_STRUCTURE_POSTBUILD_INFO_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList4_Impl::BuildRaytracingAccelerationStructure(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&numpostbuildinfodescs), core::mem::transmute_copy(&ppostbuildinfodescs))
+            }
+        }
+        unsafe extern "system" fn EmitRaytracingAccelerationStructurePostbuildInfo<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList4_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_RAYTRACING_ACCELERATION_STRUCTURE_POSTBUILD_INFO_DESC, numsourceaccelerationstructures: u32, psourceaccelerationstructuredata: *const u64) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList4_Impl::EmitRaytracingAccelerationStructurePostbuildInfo(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&numsourceaccelerationstructures), core::mem::transmute_copy(&psourceaccelerationstructuredata))
+            }
+        }
+        unsafe extern "system" fn CopyRaytracingAccelerationStructure<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList4_Impl>(this: *mut core::ffi::c_void, destaccelerationstructuredata: u64, sourceaccelerationstructuredata: u64, mode: D3D12_RAYTRACING_ACCELERATION_STRUCTURE_COPY_MODE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList4_Impl::CopyRaytracingAccelerationStructure(this, core::mem::transmute_copy(&destaccelerationstructuredata), core::mem::transmute_copy(&sourceaccelerationstructuredata), core::mem::transmute_copy(&mode))
+            }
+        }
+        unsafe extern "system" fn SetPipelineState1<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList4_Impl>(this: *mut core::ffi::c_void, pstateobject: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList4_Impl::SetPipelineState1(this, core::mem::transmute_copy(&pstateobject))
+            }
+        }
+        unsafe extern "system" fn DispatchRays<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList4_Impl>(this: *mut core::ffi::c_void, pdesc: *const D3D12_DISPATCH_RAYS_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList4_Impl::DispatchRays(this, core::mem::transmute_copy(&pdesc))
+            }
+        }
+        Self {
+            base__: <ID3D12GraphicsCommandList3_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            BeginRenderPass: BeginRenderPass::<Identity>,
+            EndRenderPass: EndRenderPass::<Identity>,
+            InitializeMetaCommand: InitializeMetaCommand::<Identity>,
+            ExecuteMetaCommand: ExecuteMetaCommand::<Identity>,
+            BuildRaytracingAccelerationStructure: BuildRaytracingAccelerationStructure::<Identity>,
+            EmitRaytracingAccelerationStructurePostbuildInfo: EmitRaytracingAccelerationStructurePostbuildInfo::<Identity>,
+            CopyRaytracingAccelerationStructure: CopyRaytracingAccelerationStructure::<Identity>,
+            SetPipelineState1: SetPipelineState1::<Identity>,
+            DispatchRays: DispatchRays::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
 impl windows_core::RuntimeName for ID3D12GraphicsCommandList4 {}
 windows_core::imp::define_interface!(ID3D12GraphicsCommandList5, ID3D12GraphicsCommandList5_Vtbl, 0x55050859_4024_474c_87f5_6472eaee44ea);
 impl core::ops::Deref for ID3D12GraphicsCommandList5 {
@@ -13347,6 +15541,31 @@ impl ID3D12GraphicsCommandList5_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList5_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12GraphicsCommandList5_Vtbl
+where
+    ID3D12GraphicsCommandList4_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RSSetShadingRate<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList5_Impl>(this: *mut core::ffi::c_void, baseshadingrate: D3D12_SHADING_RATE, combiners: *const D3D12_SHADING_RATE_COMBINER) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList5_Impl::RSSetShadingRate(this, core::mem::transmute_copy(&baseshadingrate), core::mem::transmute_copy(&combiners))
+            }
+        }
+        unsafe extern "system" fn RSSetShadingRateImage<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList5_Impl>(this: *mut core::ffi::c_void, shadingrateimage: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList5_Impl::RSSetShadingRateImage(this, core::mem::transmute_copy(&shadingrateimage))
+            }
+        }
+        Self {
+            base__: <ID3D12GraphicsCommandList4_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RSSetShadingRate: RSSetShadingRate::<Identity>,
+            RSSetShadingRateImage: RSSetShadingRateImage::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
 impl windows_core::RuntimeName for ID3D12GraphicsCommandList5 {}
 windows_core::imp::define_interface!(ID3D12GraphicsCommandList6, ID3D12GraphicsCommandList6_Vtbl, 0xc3827890_e548_4cfa_96cf_5689a9370f80);
 impl core::ops::Deref for ID3D12GraphicsCommandList6 {
@@ -13389,6 +15608,21 @@ impl ID3D12GraphicsCommandList6_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList6_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12GraphicsCommandList6_Vtbl
+where
+    ID3D12GraphicsCommandList5_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DispatchMesh<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList6_Impl>(this: *mut core::ffi::c_void, threadgroupcountx: u32, threadgroupcounty: u32, threadgroupcountz: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList6_Impl::DispatchMesh(this, core::mem::transmute_copy(&threadgroupcountx), core::mem::transmute_copy(&threadgroupcounty), core::mem::transmute_copy(&threadgroupcountz))
+            }
+        }
+        Self { base__: <ID3D12GraphicsCommandList5_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, DispatchMesh: DispatchMesh::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
 impl windows_core::RuntimeName for ID3D12GraphicsCommandList6 {}
 windows_core::imp::define_interface!(ID3D12GraphicsCommandList7, ID3D12GraphicsCommandList7_Vtbl, 0xdd171223_8b61_4769_90e3_160ccde4e2c1);
 impl core::ops::Deref for ID3D12GraphicsCommandList7 {
@@ -13431,6 +15665,21 @@ impl ID3D12GraphicsCommandList7_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList7_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12GraphicsCommandList7_Vtbl
+where
+    ID3D12GraphicsCommandList6_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Barrier<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList7_Impl>(this: *mut core::ffi::c_void, numbarriergroups: u32, pbarriergroups: *const D3D12_BARRIER_GROUP) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList7_Impl::Barrier(this, core::mem::transmute_copy(&numbarriergroups), core::mem::transmute_copy(&pbarriergroups))
+            }
+        }
+        Self { base__: <ID3D12GraphicsCommandList6_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Barrier: Barrier::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
 impl windows_core::RuntimeName for ID3D12GraphicsCommandList7 {}
 windows_core::imp::define_interface!(ID3D12GraphicsCommandList8, ID3D12GraphicsCommandList8_Vtbl, 0xee936ef9_599d_4d28_938e_23c4ad05ce51);
 impl core::ops::Deref for ID3D12GraphicsCommandList8 {
@@ -13484,6 +15733,24 @@ impl ID3D12GraphicsCommandList8_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList8_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12GraphicsCommandList8_Vtbl
+where
+    ID3D12GraphicsCommandList7_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OMSetFrontAndBackStencilRef<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList8_Impl>(this: *mut core::ffi::c_void, frontstencilref: u32, backstencilref: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList8_Impl::OMSetFrontAndBackStencilRef(this, core::mem::transmute_copy(&frontstencilref), core::mem::transmute_copy(&backstencilref))
+            }
+        }
+        Self {
+            base__: <ID3D12GraphicsCommandList7_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OMSetFrontAndBackStencilRef: OMSetFrontAndBackStencilRef::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
 impl windows_core::RuntimeName for ID3D12GraphicsCommandList8 {}
 windows_core::imp::define_interface!(ID3D12GraphicsCommandList9, ID3D12GraphicsCommandList9_Vtbl, 0x34ed2808_ffe6_4c2b_b11a_cabd2b0c59e1);
 impl core::ops::Deref for ID3D12GraphicsCommandList9 {
@@ -13553,6 +15820,31 @@ impl ID3D12GraphicsCommandList9_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList9_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12GraphicsCommandList9_Vtbl
+where
+    ID3D12GraphicsCommandList8_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RSSetDepthBias<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList9_Impl>(this: *mut core::ffi::c_void, depthbias: f32, depthbiasclamp: f32, slopescaleddepthbias: f32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList9_Impl::RSSetDepthBias(this, core::mem::transmute_copy(&depthbias), core::mem::transmute_copy(&depthbiasclamp), core::mem::transmute_copy(&slopescaleddepthbias))
+            }
+        }
+        unsafe extern "system" fn IASetIndexBufferStripCutValue<Identity: windows_core::IUnknownImpl + ID3D12GraphicsCommandList9_Impl>(this: *mut core::ffi::c_void, ibstripcutvalue: D3D12_INDEX_BUFFER_STRIP_CUT_VALUE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12GraphicsCommandList9_Impl::IASetIndexBufferStripCutValue(this, core::mem::transmute_copy(&ibstripcutvalue))
+            }
+        }
+        Self {
+            base__: <ID3D12GraphicsCommandList8_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RSSetDepthBias: RSSetDepthBias::<Identity>,
+            IASetIndexBufferStripCutValue: IASetIndexBufferStripCutValue::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
 impl windows_core::RuntimeName for ID3D12GraphicsCommandList9 {}
 windows_core::imp::define_interface!(ID3D12Heap, ID3D12Heap_Vtbl, 0x6b3b2502_6e51_45b3_90ee_9884265e8df3);
 impl core::ops::Deref for ID3D12Heap {
@@ -13596,6 +15888,20 @@ impl ID3D12Heap_Vtbl {
         iid == &<ID3D12Heap as windows_core::Interface>::IID || iid == &<ID3D12Object as windows_core::Interface>::IID || iid == &<ID3D12DeviceChild as windows_core::Interface>::IID || iid == &<ID3D12Pageable as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12Heap_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Heap_Vtbl
+where
+    ID3D12Pageable_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D12Heap_Impl>(this: *mut core::ffi::c_void, result__: *mut D3D12_HEAP_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = ID3D12Heap_Impl::GetDesc(this)
+            }
+        }
+        Self { base__: <ID3D12Pageable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDesc: GetDesc::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ID3D12Heap {}
 windows_core::imp::define_interface!(ID3D12Heap1, ID3D12Heap1_Vtbl, 0x572f7389_2168_49e3_9693_d6df5871bf6d);
 impl core::ops::Deref for ID3D12Heap1 {
@@ -13638,6 +15944,23 @@ impl ID3D12Heap1_Vtbl {
         iid == &<ID3D12Heap1 as windows_core::Interface>::IID || iid == &<ID3D12Object as windows_core::Interface>::IID || iid == &<ID3D12DeviceChild as windows_core::Interface>::IID || iid == &<ID3D12Pageable as windows_core::Interface>::IID || iid == &<ID3D12Heap as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12Heap1_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Heap1_Vtbl
+where
+    ID3D12Heap_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProtectedResourceSession<Identity: windows_core::IUnknownImpl + ID3D12Heap1_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppprotectedsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Heap1_Impl::GetProtectedResourceSession(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppprotectedsession)).into()
+            }
+        }
+        Self {
+            base__: <ID3D12Heap_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProtectedResourceSession: GetProtectedResourceSession::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12Heap1 {}
 windows_core::imp::define_interface!(ID3D12InfoQueue, ID3D12InfoQueue_Vtbl, 0x0742a90b_c387_483f_b946_30a7e4e61458);
 windows_core::imp::interface_hierarchy!(ID3D12InfoQueue, windows_core::IUnknown);
@@ -13901,192 +16224,447 @@ impl ID3D12InfoQueue_Vtbl {
                 ID3D12InfoQueue_Impl::GetStorageFilter(this, core::mem::transmute_copy(&pfilter), core::mem::transmute_copy(&pfilterbytelength)).into()
             }
         }
-        unsafe extern "system" fn ClearStorageFilter<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) {
+        unsafe extern "system" fn ClearStorageFilter<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::ClearStorageFilter(this)
+            }
+        }
+        unsafe extern "system" fn PushEmptyStorageFilter<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::PushEmptyStorageFilter(this).into()
+            }
+        }
+        unsafe extern "system" fn PushCopyOfStorageFilter<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::PushCopyOfStorageFilter(this).into()
+            }
+        }
+        unsafe extern "system" fn PushStorageFilter<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfilter: *const D3D12_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::PushStorageFilter(this, core::mem::transmute_copy(&pfilter)).into()
+            }
+        }
+        unsafe extern "system" fn PopStorageFilter<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::PopStorageFilter(this)
+            }
+        }
+        unsafe extern "system" fn GetStorageFilterStackSize<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::GetStorageFilterStackSize(this)
+            }
+        }
+        unsafe extern "system" fn AddRetrievalFilterEntries<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfilter: *const D3D12_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::AddRetrievalFilterEntries(this, core::mem::transmute_copy(&pfilter)).into()
+            }
+        }
+        unsafe extern "system" fn GetRetrievalFilter<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfilter: *mut D3D12_INFO_QUEUE_FILTER, pfilterbytelength: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::GetRetrievalFilter(this, core::mem::transmute_copy(&pfilter), core::mem::transmute_copy(&pfilterbytelength)).into()
+            }
+        }
+        unsafe extern "system" fn ClearRetrievalFilter<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::ClearRetrievalFilter(this)
+            }
+        }
+        unsafe extern "system" fn PushEmptyRetrievalFilter<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::PushEmptyRetrievalFilter(this).into()
+            }
+        }
+        unsafe extern "system" fn PushCopyOfRetrievalFilter<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::PushCopyOfRetrievalFilter(this).into()
+            }
+        }
+        unsafe extern "system" fn PushRetrievalFilter<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfilter: *const D3D12_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::PushRetrievalFilter(this, core::mem::transmute_copy(&pfilter)).into()
+            }
+        }
+        unsafe extern "system" fn PopRetrievalFilter<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::PopRetrievalFilter(this)
+            }
+        }
+        unsafe extern "system" fn GetRetrievalFilterStackSize<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::GetRetrievalFilterStackSize(this)
+            }
+        }
+        unsafe extern "system" fn AddMessage<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, category: D3D12_MESSAGE_CATEGORY, severity: D3D12_MESSAGE_SEVERITY, id: D3D12_MESSAGE_ID, pdescription: windows_core::PCSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::AddMessage(this, core::mem::transmute_copy(&category), core::mem::transmute_copy(&severity), core::mem::transmute_copy(&id), core::mem::transmute(&pdescription)).into()
+            }
+        }
+        unsafe extern "system" fn AddApplicationMessage<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, severity: D3D12_MESSAGE_SEVERITY, pdescription: windows_core::PCSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::AddApplicationMessage(this, core::mem::transmute_copy(&severity), core::mem::transmute(&pdescription)).into()
+            }
+        }
+        unsafe extern "system" fn SetBreakOnCategory<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, category: D3D12_MESSAGE_CATEGORY, benable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::SetBreakOnCategory(this, core::mem::transmute_copy(&category), core::mem::transmute_copy(&benable)).into()
+            }
+        }
+        unsafe extern "system" fn SetBreakOnSeverity<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, severity: D3D12_MESSAGE_SEVERITY, benable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::SetBreakOnSeverity(this, core::mem::transmute_copy(&severity), core::mem::transmute_copy(&benable)).into()
+            }
+        }
+        unsafe extern "system" fn SetBreakOnID<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, id: D3D12_MESSAGE_ID, benable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::SetBreakOnID(this, core::mem::transmute_copy(&id), core::mem::transmute_copy(&benable)).into()
+            }
+        }
+        unsafe extern "system" fn GetBreakOnCategory<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, category: D3D12_MESSAGE_CATEGORY) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::GetBreakOnCategory(this, core::mem::transmute_copy(&category))
+            }
+        }
+        unsafe extern "system" fn GetBreakOnSeverity<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, severity: D3D12_MESSAGE_SEVERITY) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::GetBreakOnSeverity(this, core::mem::transmute_copy(&severity))
+            }
+        }
+        unsafe extern "system" fn GetBreakOnID<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, id: D3D12_MESSAGE_ID) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::GetBreakOnID(this, core::mem::transmute_copy(&id))
+            }
+        }
+        unsafe extern "system" fn SetMuteDebugOutput<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bmute: windows_core::BOOL) {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::SetMuteDebugOutput(this, core::mem::transmute_copy(&bmute))
+            }
+        }
+        unsafe extern "system" fn GetMuteDebugOutput<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12InfoQueue_Impl::GetMuteDebugOutput(this)
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            SetMessageCountLimit: SetMessageCountLimit::<Identity, OFFSET>,
+            ClearStoredMessages: ClearStoredMessages::<Identity, OFFSET>,
+            GetMessage: GetMessage::<Identity, OFFSET>,
+            GetNumMessagesAllowedByStorageFilter: GetNumMessagesAllowedByStorageFilter::<Identity, OFFSET>,
+            GetNumMessagesDeniedByStorageFilter: GetNumMessagesDeniedByStorageFilter::<Identity, OFFSET>,
+            GetNumStoredMessages: GetNumStoredMessages::<Identity, OFFSET>,
+            GetNumStoredMessagesAllowedByRetrievalFilter: GetNumStoredMessagesAllowedByRetrievalFilter::<Identity, OFFSET>,
+            GetNumMessagesDiscardedByMessageCountLimit: GetNumMessagesDiscardedByMessageCountLimit::<Identity, OFFSET>,
+            GetMessageCountLimit: GetMessageCountLimit::<Identity, OFFSET>,
+            AddStorageFilterEntries: AddStorageFilterEntries::<Identity, OFFSET>,
+            GetStorageFilter: GetStorageFilter::<Identity, OFFSET>,
+            ClearStorageFilter: ClearStorageFilter::<Identity, OFFSET>,
+            PushEmptyStorageFilter: PushEmptyStorageFilter::<Identity, OFFSET>,
+            PushCopyOfStorageFilter: PushCopyOfStorageFilter::<Identity, OFFSET>,
+            PushStorageFilter: PushStorageFilter::<Identity, OFFSET>,
+            PopStorageFilter: PopStorageFilter::<Identity, OFFSET>,
+            GetStorageFilterStackSize: GetStorageFilterStackSize::<Identity, OFFSET>,
+            AddRetrievalFilterEntries: AddRetrievalFilterEntries::<Identity, OFFSET>,
+            GetRetrievalFilter: GetRetrievalFilter::<Identity, OFFSET>,
+            ClearRetrievalFilter: ClearRetrievalFilter::<Identity, OFFSET>,
+            PushEmptyRetrievalFilter: PushEmptyRetrievalFilter::<Identity, OFFSET>,
+            PushCopyOfRetrievalFilter: PushCopyOfRetrievalFilter::<Identity, OFFSET>,
+            PushRetrievalFilter: PushRetrievalFilter::<Identity, OFFSET>,
+            PopRetrievalFilter: PopRetrievalFilter::<Identity, OFFSET>,
+            GetRetrievalFilterStackSize: GetRetrievalFilterStackSize::<Identity, OFFSET>,
+            AddMessage: AddMessage::<Identity, OFFSET>,
+            AddApplicationMessage: AddApplicationMessage::<Identity, OFFSET>,
+            SetBreakOnCategory: SetBreakOnCategory::<Identity, OFFSET>,
+            SetBreakOnSeverity: SetBreakOnSeverity::<Identity, OFFSET>,
+            SetBreakOnID: SetBreakOnID::<Identity, OFFSET>,
+            GetBreakOnCategory: GetBreakOnCategory::<Identity, OFFSET>,
+            GetBreakOnSeverity: GetBreakOnSeverity::<Identity, OFFSET>,
+            GetBreakOnID: GetBreakOnID::<Identity, OFFSET>,
+            SetMuteDebugOutput: SetMuteDebugOutput::<Identity, OFFSET>,
+            GetMuteDebugOutput: GetMuteDebugOutput::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12InfoQueue as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12InfoQueue_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetMessageCountLimit<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void, messagecountlimit: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12InfoQueue_Impl::SetMessageCountLimit(this, core::mem::transmute_copy(&messagecountlimit)).into()
+            }
+        }
+        unsafe extern "system" fn ClearStoredMessages<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12InfoQueue_Impl::ClearStoredMessages(this)
+            }
+        }
+        unsafe extern "system" fn GetMessage<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void, messageindex: u64, pmessage: *mut D3D12_MESSAGE, pmessagebytelength: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12InfoQueue_Impl::GetMessage(this, core::mem::transmute_copy(&messageindex), core::mem::transmute_copy(&pmessage), core::mem::transmute_copy(&pmessagebytelength)).into()
+            }
+        }
+        unsafe extern "system" fn GetNumMessagesAllowedByStorageFilter<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12InfoQueue_Impl::GetNumMessagesAllowedByStorageFilter(this)
+            }
+        }
+        unsafe extern "system" fn GetNumMessagesDeniedByStorageFilter<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12InfoQueue_Impl::GetNumMessagesDeniedByStorageFilter(this)
+            }
+        }
+        unsafe extern "system" fn GetNumStoredMessages<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12InfoQueue_Impl::GetNumStoredMessages(this)
+            }
+        }
+        unsafe extern "system" fn GetNumStoredMessagesAllowedByRetrievalFilter<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12InfoQueue_Impl::GetNumStoredMessagesAllowedByRetrievalFilter(this)
+            }
+        }
+        unsafe extern "system" fn GetNumMessagesDiscardedByMessageCountLimit<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12InfoQueue_Impl::GetNumMessagesDiscardedByMessageCountLimit(this)
+            }
+        }
+        unsafe extern "system" fn GetMessageCountLimit<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12InfoQueue_Impl::GetMessageCountLimit(this)
+            }
+        }
+        unsafe extern "system" fn AddStorageFilterEntries<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void, pfilter: *const D3D12_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12InfoQueue_Impl::AddStorageFilterEntries(this, core::mem::transmute_copy(&pfilter)).into()
+            }
+        }
+        unsafe extern "system" fn GetStorageFilter<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void, pfilter: *mut D3D12_INFO_QUEUE_FILTER, pfilterbytelength: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12InfoQueue_Impl::GetStorageFilter(this, core::mem::transmute_copy(&pfilter), core::mem::transmute_copy(&pfilterbytelength)).into()
+            }
+        }
+        unsafe extern "system" fn ClearStorageFilter<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void) {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::ClearStorageFilter(this)
             }
         }
-        unsafe extern "system" fn PushEmptyStorageFilter<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn PushEmptyStorageFilter<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::PushEmptyStorageFilter(this).into()
             }
         }
-        unsafe extern "system" fn PushCopyOfStorageFilter<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn PushCopyOfStorageFilter<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::PushCopyOfStorageFilter(this).into()
             }
         }
-        unsafe extern "system" fn PushStorageFilter<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfilter: *const D3D12_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+        unsafe extern "system" fn PushStorageFilter<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void, pfilter: *const D3D12_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::PushStorageFilter(this, core::mem::transmute_copy(&pfilter)).into()
             }
         }
-        unsafe extern "system" fn PopStorageFilter<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) {
+        unsafe extern "system" fn PopStorageFilter<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void) {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::PopStorageFilter(this)
             }
         }
-        unsafe extern "system" fn GetStorageFilterStackSize<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u32 {
+        unsafe extern "system" fn GetStorageFilterStackSize<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void) -> u32 {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::GetStorageFilterStackSize(this)
             }
         }
-        unsafe extern "system" fn AddRetrievalFilterEntries<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfilter: *const D3D12_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+        unsafe extern "system" fn AddRetrievalFilterEntries<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void, pfilter: *const D3D12_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::AddRetrievalFilterEntries(this, core::mem::transmute_copy(&pfilter)).into()
             }
         }
-        unsafe extern "system" fn GetRetrievalFilter<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfilter: *mut D3D12_INFO_QUEUE_FILTER, pfilterbytelength: *mut usize) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetRetrievalFilter<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void, pfilter: *mut D3D12_INFO_QUEUE_FILTER, pfilterbytelength: *mut usize) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::GetRetrievalFilter(this, core::mem::transmute_copy(&pfilter), core::mem::transmute_copy(&pfilterbytelength)).into()
             }
         }
-        unsafe extern "system" fn ClearRetrievalFilter<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) {
+        unsafe extern "system" fn ClearRetrievalFilter<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void) {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::ClearRetrievalFilter(this)
             }
         }
-        unsafe extern "system" fn PushEmptyRetrievalFilter<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn PushEmptyRetrievalFilter<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::PushEmptyRetrievalFilter(this).into()
             }
         }
-        unsafe extern "system" fn PushCopyOfRetrievalFilter<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn PushCopyOfRetrievalFilter<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::PushCopyOfRetrievalFilter(this).into()
             }
         }
-        unsafe extern "system" fn PushRetrievalFilter<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfilter: *const D3D12_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+        unsafe extern "system" fn PushRetrievalFilter<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void, pfilter: *const D3D12_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::PushRetrievalFilter(this, core::mem::transmute_copy(&pfilter)).into()
             }
         }
-        unsafe extern "system" fn PopRetrievalFilter<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) {
+        unsafe extern "system" fn PopRetrievalFilter<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void) {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::PopRetrievalFilter(this)
             }
         }
-        unsafe extern "system" fn GetRetrievalFilterStackSize<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u32 {
+        unsafe extern "system" fn GetRetrievalFilterStackSize<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void) -> u32 {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::GetRetrievalFilterStackSize(this)
             }
         }
-        unsafe extern "system" fn AddMessage<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, category: D3D12_MESSAGE_CATEGORY, severity: D3D12_MESSAGE_SEVERITY, id: D3D12_MESSAGE_ID, pdescription: windows_core::PCSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn AddMessage<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void, category: D3D12_MESSAGE_CATEGORY, severity: D3D12_MESSAGE_SEVERITY, id: D3D12_MESSAGE_ID, pdescription: windows_core::PCSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::AddMessage(this, core::mem::transmute_copy(&category), core::mem::transmute_copy(&severity), core::mem::transmute_copy(&id), core::mem::transmute(&pdescription)).into()
             }
         }
-        unsafe extern "system" fn AddApplicationMessage<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, severity: D3D12_MESSAGE_SEVERITY, pdescription: windows_core::PCSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn AddApplicationMessage<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void, severity: D3D12_MESSAGE_SEVERITY, pdescription: windows_core::PCSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::AddApplicationMessage(this, core::mem::transmute_copy(&severity), core::mem::transmute(&pdescription)).into()
             }
         }
-        unsafe extern "system" fn SetBreakOnCategory<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, category: D3D12_MESSAGE_CATEGORY, benable: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBreakOnCategory<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void, category: D3D12_MESSAGE_CATEGORY, benable: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::SetBreakOnCategory(this, core::mem::transmute_copy(&category), core::mem::transmute_copy(&benable)).into()
             }
         }
-        unsafe extern "system" fn SetBreakOnSeverity<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, severity: D3D12_MESSAGE_SEVERITY, benable: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBreakOnSeverity<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void, severity: D3D12_MESSAGE_SEVERITY, benable: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::SetBreakOnSeverity(this, core::mem::transmute_copy(&severity), core::mem::transmute_copy(&benable)).into()
             }
         }
-        unsafe extern "system" fn SetBreakOnID<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, id: D3D12_MESSAGE_ID, benable: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBreakOnID<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void, id: D3D12_MESSAGE_ID, benable: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::SetBreakOnID(this, core::mem::transmute_copy(&id), core::mem::transmute_copy(&benable)).into()
             }
         }
-        unsafe extern "system" fn GetBreakOnCategory<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, category: D3D12_MESSAGE_CATEGORY) -> windows_core::BOOL {
+        unsafe extern "system" fn GetBreakOnCategory<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void, category: D3D12_MESSAGE_CATEGORY) -> windows_core::BOOL {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::GetBreakOnCategory(this, core::mem::transmute_copy(&category))
             }
         }
-        unsafe extern "system" fn GetBreakOnSeverity<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, severity: D3D12_MESSAGE_SEVERITY) -> windows_core::BOOL {
+        unsafe extern "system" fn GetBreakOnSeverity<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void, severity: D3D12_MESSAGE_SEVERITY) -> windows_core::BOOL {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::GetBreakOnSeverity(this, core::mem::transmute_copy(&severity))
             }
         }
-        unsafe extern "system" fn GetBreakOnID<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, id: D3D12_MESSAGE_ID) -> windows_core::BOOL {
+        unsafe extern "system" fn GetBreakOnID<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void, id: D3D12_MESSAGE_ID) -> windows_core::BOOL {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::GetBreakOnID(this, core::mem::transmute_copy(&id))
             }
         }
-        unsafe extern "system" fn SetMuteDebugOutput<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bmute: windows_core::BOOL) {
+        unsafe extern "system" fn SetMuteDebugOutput<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void, bmute: windows_core::BOOL) {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::SetMuteDebugOutput(this, core::mem::transmute_copy(&bmute))
             }
         }
-        unsafe extern "system" fn GetMuteDebugOutput<Identity: ID3D12InfoQueue_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+        unsafe extern "system" fn GetMuteDebugOutput<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12InfoQueue_Impl::GetMuteDebugOutput(this)
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            SetMessageCountLimit: SetMessageCountLimit::<Identity, OFFSET>,
-            ClearStoredMessages: ClearStoredMessages::<Identity, OFFSET>,
-            GetMessage: GetMessage::<Identity, OFFSET>,
-            GetNumMessagesAllowedByStorageFilter: GetNumMessagesAllowedByStorageFilter::<Identity, OFFSET>,
-            GetNumMessagesDeniedByStorageFilter: GetNumMessagesDeniedByStorageFilter::<Identity, OFFSET>,
-            GetNumStoredMessages: GetNumStoredMessages::<Identity, OFFSET>,
-            GetNumStoredMessagesAllowedByRetrievalFilter: GetNumStoredMessagesAllowedByRetrievalFilter::<Identity, OFFSET>,
-            GetNumMessagesDiscardedByMessageCountLimit: GetNumMessagesDiscardedByMessageCountLimit::<Identity, OFFSET>,
-            GetMessageCountLimit: GetMessageCountLimit::<Identity, OFFSET>,
-            AddStorageFilterEntries: AddStorageFilterEntries::<Identity, OFFSET>,
-            GetStorageFilter: GetStorageFilter::<Identity, OFFSET>,
-            ClearStorageFilter: ClearStorageFilter::<Identity, OFFSET>,
-            PushEmptyStorageFilter: PushEmptyStorageFilter::<Identity, OFFSET>,
-            PushCopyOfStorageFilter: PushCopyOfStorageFilter::<Identity, OFFSET>,
-            PushStorageFilter: PushStorageFilter::<Identity, OFFSET>,
-            PopStorageFilter: PopStorageFilter::<Identity, OFFSET>,
-            GetStorageFilterStackSize: GetStorageFilterStackSize::<Identity, OFFSET>,
-            AddRetrievalFilterEntries: AddRetrievalFilterEntries::<Identity, OFFSET>,
-            GetRetrievalFilter: GetRetrievalFilter::<Identity, OFFSET>,
-            ClearRetrievalFilter: ClearRetrievalFilter::<Identity, OFFSET>,
-            PushEmptyRetrievalFilter: PushEmptyRetrievalFilter::<Identity, OFFSET>,
-            PushCopyOfRetrievalFilter: PushCopyOfRetrievalFilter::<Identity, OFFSET>,
-            PushRetrievalFilter: PushRetrievalFilter::<Identity, OFFSET>,
-            PopRetrievalFilter: PopRetrievalFilter::<Identity, OFFSET>,
-            GetRetrievalFilterStackSize: GetRetrievalFilterStackSize::<Identity, OFFSET>,
-            AddMessage: AddMessage::<Identity, OFFSET>,
-            AddApplicationMessage: AddApplicationMessage::<Identity, OFFSET>,
-            SetBreakOnCategory: SetBreakOnCategory::<Identity, OFFSET>,
-            SetBreakOnSeverity: SetBreakOnSeverity::<Identity, OFFSET>,
-            SetBreakOnID: SetBreakOnID::<Identity, OFFSET>,
-            GetBreakOnCategory: GetBreakOnCategory::<Identity, OFFSET>,
-            GetBreakOnSeverity: GetBreakOnSeverity::<Identity, OFFSET>,
-            GetBreakOnID: GetBreakOnID::<Identity, OFFSET>,
-            SetMuteDebugOutput: SetMuteDebugOutput::<Identity, OFFSET>,
-            GetMuteDebugOutput: GetMuteDebugOutput::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ID3D12InfoQueue as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetMessageCountLimit: SetMessageCountLimit::<Identity>,
+            ClearStoredMessages: ClearStoredMessages::<Identity>,
+            GetMessage: GetMessage::<Identity>,
+            GetNumMessagesAllowedByStorageFilter: GetNumMessagesAllowedByStorageFilter::<Identity>,
+            GetNumMessagesDeniedByStorageFilter: GetNumMessagesDeniedByStorageFilter::<Identity>,
+            GetNumStoredMessages: GetNumStoredMessages::<Identity>,
+            GetNumStoredMessagesAllowedByRetrievalFilter: GetNumStoredMessagesAllowedByRetrievalFilter::<Identity>,
+            GetNumMessagesDiscardedByMessageCountLimit: GetNumMessagesDiscardedByMessageCountLimit::<Identity>,
+            GetMessageCountLimit: GetMessageCountLimit::<Identity>,
+            AddStorageFilterEntries: AddStorageFilterEntries::<Identity>,
+            GetStorageFilter: GetStorageFilter::<Identity>,
+            ClearStorageFilter: ClearStorageFilter::<Identity>,
+            PushEmptyStorageFilter: PushEmptyStorageFilter::<Identity>,
+            PushCopyOfStorageFilter: PushCopyOfStorageFilter::<Identity>,
+            PushStorageFilter: PushStorageFilter::<Identity>,
+            PopStorageFilter: PopStorageFilter::<Identity>,
+            GetStorageFilterStackSize: GetStorageFilterStackSize::<Identity>,
+            AddRetrievalFilterEntries: AddRetrievalFilterEntries::<Identity>,
+            GetRetrievalFilter: GetRetrievalFilter::<Identity>,
+            ClearRetrievalFilter: ClearRetrievalFilter::<Identity>,
+            PushEmptyRetrievalFilter: PushEmptyRetrievalFilter::<Identity>,
+            PushCopyOfRetrievalFilter: PushCopyOfRetrievalFilter::<Identity>,
+            PushRetrievalFilter: PushRetrievalFilter::<Identity>,
+            PopRetrievalFilter: PopRetrievalFilter::<Identity>,
+            GetRetrievalFilterStackSize: GetRetrievalFilterStackSize::<Identity>,
+            AddMessage: AddMessage::<Identity>,
+            AddApplicationMessage: AddApplicationMessage::<Identity>,
+            SetBreakOnCategory: SetBreakOnCategory::<Identity>,
+            SetBreakOnSeverity: SetBreakOnSeverity::<Identity>,
+            SetBreakOnID: SetBreakOnID::<Identity>,
+            GetBreakOnCategory: GetBreakOnCategory::<Identity>,
+            GetBreakOnSeverity: GetBreakOnSeverity::<Identity>,
+            GetBreakOnID: GetBreakOnID::<Identity>,
+            SetMuteDebugOutput: SetMuteDebugOutput::<Identity>,
+            GetMuteDebugOutput: GetMuteDebugOutput::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for ID3D12InfoQueue {}
 windows_core::imp::define_interface!(ID3D12InfoQueue1, ID3D12InfoQueue1_Vtbl, 0x2852dd88_b484_4c0c_b6b1_67168500e600);
@@ -14142,6 +16720,30 @@ impl ID3D12InfoQueue1_Vtbl {
         iid == &<ID3D12InfoQueue1 as windows_core::Interface>::IID || iid == &<ID3D12InfoQueue as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue1_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12InfoQueue1_Vtbl
+where
+    ID3D12InfoQueue_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterMessageCallback<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue1_Impl>(this: *mut core::ffi::c_void, callbackfunc: D3D12MessageFunc, callbackfilterflags: D3D12_MESSAGE_CALLBACK_FLAGS, pcontext: *mut core::ffi::c_void, pcallbackcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12InfoQueue1_Impl::RegisterMessageCallback(this, core::mem::transmute_copy(&callbackfunc), core::mem::transmute_copy(&callbackfilterflags), core::mem::transmute_copy(&pcontext), core::mem::transmute_copy(&pcallbackcookie)).into()
+            }
+        }
+        unsafe extern "system" fn UnregisterMessageCallback<Identity: windows_core::IUnknownImpl + ID3D12InfoQueue1_Impl>(this: *mut core::ffi::c_void, callbackcookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12InfoQueue1_Impl::UnregisterMessageCallback(this, core::mem::transmute_copy(&callbackcookie)).into()
+            }
+        }
+        Self {
+            base__: <ID3D12InfoQueue_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterMessageCallback: RegisterMessageCallback::<Identity>,
+            UnregisterMessageCallback: UnregisterMessageCallback::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12InfoQueue1 {}
 windows_core::imp::define_interface!(ID3D12LibraryReflection, ID3D12LibraryReflection_Vtbl, 0x8e349d19_54db_4a56_9dc9_119d87bdb804);
 windows_core::imp::interface_hierarchy!(ID3D12LibraryReflection, windows_core::IUnknown);
@@ -14199,6 +16801,36 @@ impl ID3D12LibraryReflection_Vtbl {
         iid == &<ID3D12LibraryReflection as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12LibraryReflection_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12LibraryReflection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D12LibraryReflection_Impl>(this: *mut core::ffi::c_void, pdesc: *mut D3D12_LIBRARY_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D12LibraryReflection_Impl::GetDesc(this) {
+                    Ok(ok__) => {
+                        pdesc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFunctionByIndex<Identity: windows_core::IUnknownImpl + ID3D12LibraryReflection_Impl>(this: *mut core::ffi::c_void, functionindex: i32) -> Option<ID3D12FunctionReflection> {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12LibraryReflection_Impl::GetFunctionByIndex(this, core::mem::transmute_copy(&functionindex))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDesc: GetDesc::<Identity>,
+            GetFunctionByIndex: GetFunctionByIndex::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12LibraryReflection {}
 windows_core::imp::define_interface!(ID3D12LifetimeOwner, ID3D12LifetimeOwner_Vtbl, 0xe667af9f_cd56_4f46_83ce_032e595d70a8);
 windows_core::imp::interface_hierarchy!(ID3D12LifetimeOwner, windows_core::IUnknown);
@@ -14232,6 +16864,23 @@ impl ID3D12LifetimeOwner_Vtbl {
         iid == &<ID3D12LifetimeOwner as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12LifetimeOwner_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12LifetimeOwner_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn LifetimeStateUpdated<Identity: windows_core::IUnknownImpl + ID3D12LifetimeOwner_Impl>(this: *mut core::ffi::c_void, newstate: D3D12_LIFETIME_STATE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12LifetimeOwner_Impl::LifetimeStateUpdated(this, core::mem::transmute_copy(&newstate))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            LifetimeStateUpdated: LifetimeStateUpdated::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12LifetimeOwner {}
 windows_core::imp::define_interface!(ID3D12LifetimeTracker, ID3D12LifetimeTracker_Vtbl, 0x3fd03d36_4eb1_424a_a582_494ecb8ba813);
 impl core::ops::Deref for ID3D12LifetimeTracker {
@@ -14274,6 +16923,20 @@ impl ID3D12LifetimeTracker_Vtbl {
         iid == &<ID3D12LifetimeTracker as windows_core::Interface>::IID || iid == &<ID3D12Object as windows_core::Interface>::IID || iid == &<ID3D12DeviceChild as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12LifetimeTracker_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12LifetimeTracker_Vtbl
+where
+    ID3D12DeviceChild_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DestroyOwnedObject<Identity: windows_core::IUnknownImpl + ID3D12LifetimeTracker_Impl>(this: *mut core::ffi::c_void, pobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12LifetimeTracker_Impl::DestroyOwnedObject(this, core::mem::transmute_copy(&pobject)).into()
+            }
+        }
+        Self { base__: <ID3D12DeviceChild_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, DestroyOwnedObject: DestroyOwnedObject::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ID3D12LifetimeTracker {}
 windows_core::imp::define_interface!(ID3D12ManualWriteTrackingResource, ID3D12ManualWriteTrackingResource_Vtbl, 0x86ca3b85_49ad_4b6e_aed5_eddb18540f41);
 windows_core::imp::interface_hierarchy!(ID3D12ManualWriteTrackingResource, windows_core::IUnknown);
@@ -14307,6 +16970,20 @@ impl ID3D12ManualWriteTrackingResource_Vtbl {
         iid == &<ID3D12ManualWriteTrackingResource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12ManualWriteTrackingResource_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12ManualWriteTrackingResource_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn TrackWrite<Identity: windows_core::IUnknownImpl + ID3D12ManualWriteTrackingResource_Impl>(this: *mut core::ffi::c_void, subresource: u32, pwrittenrange: *const D3D12_RANGE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12ManualWriteTrackingResource_Impl::TrackWrite(this, core::mem::transmute_copy(&subresource), core::mem::transmute_copy(&pwrittenrange))
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, TrackWrite: TrackWrite::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ID3D12ManualWriteTrackingResource {}
 windows_core::imp::define_interface!(ID3D12MetaCommand, ID3D12MetaCommand_Vtbl, 0xdbb84c27_36ce_4fc9_b801_f048c46ac570);
 impl core::ops::Deref for ID3D12MetaCommand {
@@ -14346,6 +17023,23 @@ impl ID3D12MetaCommand_Vtbl {
         iid == &<ID3D12MetaCommand as windows_core::Interface>::IID || iid == &<ID3D12Object as windows_core::Interface>::IID || iid == &<ID3D12DeviceChild as windows_core::Interface>::IID || iid == &<ID3D12Pageable as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12MetaCommand_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12MetaCommand_Vtbl
+where
+    ID3D12Pageable_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRequiredParameterResourceSize<Identity: windows_core::IUnknownImpl + ID3D12MetaCommand_Impl>(this: *mut core::ffi::c_void, stage: D3D12_META_COMMAND_PARAMETER_STAGE, parameterindex: u32) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12MetaCommand_Impl::GetRequiredParameterResourceSize(this, core::mem::transmute_copy(&stage), core::mem::transmute_copy(&parameterindex))
+            }
+        }
+        Self {
+            base__: <ID3D12Pageable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetRequiredParameterResourceSize: GetRequiredParameterResourceSize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12MetaCommand {}
 windows_core::imp::define_interface!(ID3D12Object, ID3D12Object_Vtbl, 0xc4fec28f_7966_4e95_9f94_f431cb56c3b8);
 windows_core::imp::interface_hierarchy!(ID3D12Object, windows_core::IUnknown);
@@ -14424,6 +17118,44 @@ impl ID3D12Object_Vtbl {
         iid == &<ID3D12Object as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12Object_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Object_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPrivateData<Identity: windows_core::IUnknownImpl + ID3D12Object_Impl>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pdatasize: *mut u32, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Object_Impl::GetPrivateData(this, core::mem::transmute_copy(&guid), core::mem::transmute_copy(&pdatasize), core::mem::transmute_copy(&pdata)).into()
+            }
+        }
+        unsafe extern "system" fn SetPrivateData<Identity: windows_core::IUnknownImpl + ID3D12Object_Impl>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, datasize: u32, pdata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Object_Impl::SetPrivateData(this, core::mem::transmute_copy(&guid), core::mem::transmute_copy(&datasize), core::mem::transmute_copy(&pdata)).into()
+            }
+        }
+        unsafe extern "system" fn SetPrivateDataInterface<Identity: windows_core::IUnknownImpl + ID3D12Object_Impl>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Object_Impl::SetPrivateDataInterface(this, core::mem::transmute_copy(&guid), core::mem::transmute_copy(&pdata)).into()
+            }
+        }
+        unsafe extern "system" fn SetName<Identity: windows_core::IUnknownImpl + ID3D12Object_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Object_Impl::SetName(this, core::mem::transmute(&name)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPrivateData: GetPrivateData::<Identity>,
+            SetPrivateData: SetPrivateData::<Identity>,
+            SetPrivateDataInterface: SetPrivateDataInterface::<Identity>,
+            SetName: SetName::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12Object {}
 windows_core::imp::define_interface!(ID3D12Pageable, ID3D12Pageable_Vtbl, 0x63ee58fb_1268_4835_86da_f008ce62f0d6);
 impl core::ops::Deref for ID3D12Pageable {
@@ -14449,6 +17181,12 @@ impl ID3D12Pageable_Vtbl {
         iid == &<ID3D12Pageable as windows_core::Interface>::IID || iid == &<ID3D12Object as windows_core::Interface>::IID || iid == &<ID3D12DeviceChild as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12Pageable_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Pageable_Vtbl
+where
+    ID3D12DeviceChild_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <ID3D12DeviceChild_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for ID3D12Pageable {}
 windows_core::imp::define_interface!(ID3D12PipelineLibrary, ID3D12PipelineLibrary_Vtbl, 0xc64226a8_9201_46af_b4cc_53fb9ff7414f);
 impl core::ops::Deref for ID3D12PipelineLibrary {
@@ -14560,6 +17298,52 @@ impl ID3D12PipelineLibrary_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + ID3D12PipelineLibrary_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12PipelineLibrary_Vtbl
+where
+    ID3D12DeviceChild_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StorePipeline<Identity: windows_core::IUnknownImpl + ID3D12PipelineLibrary_Impl>(this: *mut core::ffi::c_void, pname: windows_core::PCWSTR, ppipeline: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12PipelineLibrary_Impl::StorePipeline(this, core::mem::transmute(&pname), core::mem::transmute_copy(&ppipeline)).into()
+            }
+        }
+        unsafe extern "system" fn LoadGraphicsPipeline<Identity: windows_core::IUnknownImpl + ID3D12PipelineLibrary_Impl>(this: *mut core::ffi::c_void, pname: windows_core::PCWSTR, pdesc: *const D3D12_GRAPHICS_PIPELINE_STATE_DESC, riid: *const windows_core::GUID, pppipelinestate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12PipelineLibrary_Impl::LoadGraphicsPipeline(this, core::mem::transmute(&pname), core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&pppipelinestate)).into()
+            }
+        }
+        unsafe extern "system" fn LoadComputePipeline<Identity: windows_core::IUnknownImpl + ID3D12PipelineLibrary_Impl>(this: *mut core::ffi::c_void, pname: windows_core::PCWSTR, pdesc: *const D3D12_COMPUTE_PIPELINE_STATE_DESC, riid: *const windows_core::GUID, pppipelinestate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12PipelineLibrary_Impl::LoadComputePipeline(this, core::mem::transmute(&pname), core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&pppipelinestate)).into()
+            }
+        }
+        unsafe extern "system" fn GetSerializedSize<Identity: windows_core::IUnknownImpl + ID3D12PipelineLibrary_Impl>(this: *mut core::ffi::c_void) -> usize {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12PipelineLibrary_Impl::GetSerializedSize(this)
+            }
+        }
+        unsafe extern "system" fn Serialize<Identity: windows_core::IUnknownImpl + ID3D12PipelineLibrary_Impl>(this: *mut core::ffi::c_void, pdata: *mut core::ffi::c_void, datasizeinbytes: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12PipelineLibrary_Impl::Serialize(this, core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&datasizeinbytes)).into()
+            }
+        }
+        Self {
+            base__: <ID3D12DeviceChild_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            StorePipeline: StorePipeline::<Identity>,
+            LoadGraphicsPipeline: LoadGraphicsPipeline::<Identity>,
+            LoadComputePipeline: LoadComputePipeline::<Identity>,
+            GetSerializedSize: GetSerializedSize::<Identity>,
+            Serialize: Serialize::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for ID3D12PipelineLibrary {}
 windows_core::imp::define_interface!(ID3D12PipelineLibrary1, ID3D12PipelineLibrary1_Vtbl, 0x80eabf42_2568_4e5e_bd82_c37f86961dc3);
 impl core::ops::Deref for ID3D12PipelineLibrary1 {
@@ -14607,6 +17391,21 @@ impl ID3D12PipelineLibrary1_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + ID3D12PipelineLibrary1_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12PipelineLibrary1_Vtbl
+where
+    ID3D12PipelineLibrary_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn LoadPipeline<Identity: windows_core::IUnknownImpl + ID3D12PipelineLibrary1_Impl>(this: *mut core::ffi::c_void, pname: windows_core::PCWSTR, pdesc: *const D3D12_PIPELINE_STATE_STREAM_DESC, riid: *const windows_core::GUID, pppipelinestate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12PipelineLibrary1_Impl::LoadPipeline(this, core::mem::transmute(&pname), core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&pppipelinestate)).into()
+            }
+        }
+        Self { base__: <ID3D12PipelineLibrary_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, LoadPipeline: LoadPipeline::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for ID3D12PipelineLibrary1 {}
 windows_core::imp::define_interface!(ID3D12PipelineState, ID3D12PipelineState_Vtbl, 0x765a30f3_f624_4c6f_a828_ace948622445);
 impl core::ops::Deref for ID3D12PipelineState {
@@ -14662,6 +17461,27 @@ impl ID3D12PipelineState_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct3D")]
+impl<Identity: windows_core::IUnknownImpl + ID3D12PipelineState_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12PipelineState_Vtbl
+where
+    ID3D12Pageable_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCachedBlob<Identity: windows_core::IUnknownImpl + ID3D12PipelineState_Impl>(this: *mut core::ffi::c_void, ppblob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D12PipelineState_Impl::GetCachedBlob(this) {
+                    Ok(ok__) => {
+                        ppblob.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <ID3D12Pageable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetCachedBlob: GetCachedBlob::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct3D")]
 impl windows_core::RuntimeName for ID3D12PipelineState {}
 windows_core::imp::define_interface!(ID3D12ProtectedResourceSession, ID3D12ProtectedResourceSession_Vtbl, 0x6cd696f4_f289_40cc_8091_5a6c0a099c3d);
 impl core::ops::Deref for ID3D12ProtectedResourceSession {
@@ -14705,6 +17525,20 @@ impl ID3D12ProtectedResourceSession_Vtbl {
         iid == &<ID3D12ProtectedResourceSession as windows_core::Interface>::IID || iid == &<ID3D12Object as windows_core::Interface>::IID || iid == &<ID3D12DeviceChild as windows_core::Interface>::IID || iid == &<ID3D12ProtectedSession as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12ProtectedResourceSession_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12ProtectedResourceSession_Vtbl
+where
+    ID3D12ProtectedSession_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D12ProtectedResourceSession_Impl>(this: *mut core::ffi::c_void, result__: *mut D3D12_PROTECTED_RESOURCE_SESSION_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = ID3D12ProtectedResourceSession_Impl::GetDesc(this)
+            }
+        }
+        Self { base__: <ID3D12ProtectedSession_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDesc: GetDesc::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ID3D12ProtectedResourceSession {}
 windows_core::imp::define_interface!(ID3D12ProtectedResourceSession1, ID3D12ProtectedResourceSession1_Vtbl, 0xd6f12dd6_76fb_406e_8961_4296eefc0409);
 impl core::ops::Deref for ID3D12ProtectedResourceSession1 {
@@ -14748,6 +17582,20 @@ impl ID3D12ProtectedResourceSession1_Vtbl {
         iid == &<ID3D12ProtectedResourceSession1 as windows_core::Interface>::IID || iid == &<ID3D12Object as windows_core::Interface>::IID || iid == &<ID3D12DeviceChild as windows_core::Interface>::IID || iid == &<ID3D12ProtectedSession as windows_core::Interface>::IID || iid == &<ID3D12ProtectedResourceSession as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12ProtectedResourceSession1_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12ProtectedResourceSession1_Vtbl
+where
+    ID3D12ProtectedResourceSession_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc1<Identity: windows_core::IUnknownImpl + ID3D12ProtectedResourceSession1_Impl>(this: *mut core::ffi::c_void, result__: *mut D3D12_PROTECTED_RESOURCE_SESSION_DESC1) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = ID3D12ProtectedResourceSession1_Impl::GetDesc1(this)
+            }
+        }
+        Self { base__: <ID3D12ProtectedResourceSession_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDesc1: GetDesc1::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ID3D12ProtectedResourceSession1 {}
 windows_core::imp::define_interface!(ID3D12ProtectedSession, ID3D12ProtectedSession_Vtbl, 0xa1533d18_0ac1_4084_85b9_89a96116806b);
 impl core::ops::Deref for ID3D12ProtectedSession {
@@ -14805,6 +17653,30 @@ impl ID3D12ProtectedSession_Vtbl {
         iid == &<ID3D12ProtectedSession as windows_core::Interface>::IID || iid == &<ID3D12Object as windows_core::Interface>::IID || iid == &<ID3D12DeviceChild as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12ProtectedSession_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12ProtectedSession_Vtbl
+where
+    ID3D12DeviceChild_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStatusFence<Identity: windows_core::IUnknownImpl + ID3D12ProtectedSession_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppfence: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12ProtectedSession_Impl::GetStatusFence(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppfence)).into()
+            }
+        }
+        unsafe extern "system" fn GetSessionStatus<Identity: windows_core::IUnknownImpl + ID3D12ProtectedSession_Impl>(this: *mut core::ffi::c_void) -> D3D12_PROTECTED_SESSION_STATUS {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12ProtectedSession_Impl::GetSessionStatus(this)
+            }
+        }
+        Self {
+            base__: <ID3D12DeviceChild_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStatusFence: GetStatusFence::<Identity>,
+            GetSessionStatus: GetSessionStatus::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12ProtectedSession {}
 windows_core::imp::define_interface!(ID3D12QueryHeap, ID3D12QueryHeap_Vtbl, 0x0d9658ae_ed45_469e_a61d_970ec583cab4);
 impl core::ops::Deref for ID3D12QueryHeap {
@@ -14830,6 +17702,12 @@ impl ID3D12QueryHeap_Vtbl {
         iid == &<ID3D12QueryHeap as windows_core::Interface>::IID || iid == &<ID3D12Object as windows_core::Interface>::IID || iid == &<ID3D12DeviceChild as windows_core::Interface>::IID || iid == &<ID3D12Pageable as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12QueryHeap_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12QueryHeap_Vtbl
+where
+    ID3D12Pageable_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <ID3D12Pageable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for ID3D12QueryHeap {}
 windows_core::imp::define_interface!(ID3D12Resource, ID3D12Resource_Vtbl, 0x696442be_a72e_4059_bc79_5b5c98040fad);
 impl core::ops::Deref for ID3D12Resource {
@@ -14955,6 +17833,66 @@ impl ID3D12Resource_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + ID3D12Resource_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Resource_Vtbl
+where
+    ID3D12Pageable_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Map<Identity: windows_core::IUnknownImpl + ID3D12Resource_Impl>(this: *mut core::ffi::c_void, subresource: u32, preadrange: *const D3D12_RANGE, ppdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Resource_Impl::Map(this, core::mem::transmute_copy(&subresource), core::mem::transmute_copy(&preadrange), core::mem::transmute_copy(&ppdata)).into()
+            }
+        }
+        unsafe extern "system" fn Unmap<Identity: windows_core::IUnknownImpl + ID3D12Resource_Impl>(this: *mut core::ffi::c_void, subresource: u32, pwrittenrange: *const D3D12_RANGE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Resource_Impl::Unmap(this, core::mem::transmute_copy(&subresource), core::mem::transmute_copy(&pwrittenrange))
+            }
+        }
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D12Resource_Impl>(this: *mut core::ffi::c_void, result__: *mut D3D12_RESOURCE_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = ID3D12Resource_Impl::GetDesc(this)
+            }
+        }
+        unsafe extern "system" fn GetGPUVirtualAddress<Identity: windows_core::IUnknownImpl + ID3D12Resource_Impl>(this: *mut core::ffi::c_void) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Resource_Impl::GetGPUVirtualAddress(this)
+            }
+        }
+        unsafe extern "system" fn WriteToSubresource<Identity: windows_core::IUnknownImpl + ID3D12Resource_Impl>(this: *mut core::ffi::c_void, dstsubresource: u32, pdstbox: *const D3D12_BOX, psrcdata: *const core::ffi::c_void, srcrowpitch: u32, srcdepthpitch: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Resource_Impl::WriteToSubresource(this, core::mem::transmute_copy(&dstsubresource), core::mem::transmute_copy(&pdstbox), core::mem::transmute_copy(&psrcdata), core::mem::transmute_copy(&srcrowpitch), core::mem::transmute_copy(&srcdepthpitch)).into()
+            }
+        }
+        unsafe extern "system" fn ReadFromSubresource<Identity: windows_core::IUnknownImpl + ID3D12Resource_Impl>(this: *mut core::ffi::c_void, pdstdata: *mut core::ffi::c_void, dstrowpitch: u32, dstdepthpitch: u32, srcsubresource: u32, psrcbox: *const D3D12_BOX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Resource_Impl::ReadFromSubresource(this, core::mem::transmute_copy(&pdstdata), core::mem::transmute_copy(&dstrowpitch), core::mem::transmute_copy(&dstdepthpitch), core::mem::transmute_copy(&srcsubresource), core::mem::transmute_copy(&psrcbox)).into()
+            }
+        }
+        unsafe extern "system" fn GetHeapProperties<Identity: windows_core::IUnknownImpl + ID3D12Resource_Impl>(this: *mut core::ffi::c_void, pheapproperties: *mut D3D12_HEAP_PROPERTIES, pheapflags: *mut D3D12_HEAP_FLAGS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Resource_Impl::GetHeapProperties(this, core::mem::transmute_copy(&pheapproperties), core::mem::transmute_copy(&pheapflags)).into()
+            }
+        }
+        Self {
+            base__: <ID3D12Pageable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Map: Map::<Identity>,
+            Unmap: Unmap::<Identity>,
+            GetDesc: GetDesc::<Identity>,
+            GetGPUVirtualAddress: GetGPUVirtualAddress::<Identity>,
+            WriteToSubresource: WriteToSubresource::<Identity>,
+            ReadFromSubresource: ReadFromSubresource::<Identity>,
+            GetHeapProperties: GetHeapProperties::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for ID3D12Resource {}
 windows_core::imp::define_interface!(ID3D12Resource1, ID3D12Resource1_Vtbl, 0x9d5e227a_4430_4161_88b3_3eca6bb16e19);
 impl core::ops::Deref for ID3D12Resource1 {
@@ -15000,6 +17938,24 @@ impl ID3D12Resource1_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + ID3D12Resource1_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Resource1_Vtbl
+where
+    ID3D12Resource_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProtectedResourceSession<Identity: windows_core::IUnknownImpl + ID3D12Resource1_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppprotectedsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Resource1_Impl::GetProtectedResourceSession(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppprotectedsession)).into()
+            }
+        }
+        Self {
+            base__: <ID3D12Resource_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProtectedResourceSession: GetProtectedResourceSession::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for ID3D12Resource1 {}
 windows_core::imp::define_interface!(ID3D12Resource2, ID3D12Resource2_Vtbl, 0xbe36ec3b_ea85_4aeb_a45a_e9d76404a495);
 impl core::ops::Deref for ID3D12Resource2 {
@@ -15050,6 +18006,21 @@ impl ID3D12Resource2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + ID3D12Resource2_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Resource2_Vtbl
+where
+    ID3D12Resource1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc1<Identity: windows_core::IUnknownImpl + ID3D12Resource2_Impl>(this: *mut core::ffi::c_void, result__: *mut D3D12_RESOURCE_DESC1) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = ID3D12Resource2_Impl::GetDesc1(this)
+            }
+        }
+        Self { base__: <ID3D12Resource1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDesc1: GetDesc1::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for ID3D12Resource2 {}
 windows_core::imp::define_interface!(ID3D12RootSignature, ID3D12RootSignature_Vtbl, 0xc54a6b66_72df_4ee8_8be5_a946a1429214);
 impl core::ops::Deref for ID3D12RootSignature {
@@ -15075,6 +18046,12 @@ impl ID3D12RootSignature_Vtbl {
         iid == &<ID3D12RootSignature as windows_core::Interface>::IID || iid == &<ID3D12Object as windows_core::Interface>::IID || iid == &<ID3D12DeviceChild as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12RootSignature_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12RootSignature_Vtbl
+where
+    ID3D12DeviceChild_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <ID3D12DeviceChild_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for ID3D12RootSignature {}
 windows_core::imp::define_interface!(ID3D12RootSignatureDeserializer, ID3D12RootSignatureDeserializer_Vtbl, 0x34ab647b_3cc8_46ac_841b_c0965645c046);
 windows_core::imp::interface_hierarchy!(ID3D12RootSignatureDeserializer, windows_core::IUnknown);
@@ -15108,6 +18085,23 @@ impl ID3D12RootSignatureDeserializer_Vtbl {
         iid == &<ID3D12RootSignatureDeserializer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12RootSignatureDeserializer_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12RootSignatureDeserializer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRootSignatureDesc<Identity: windows_core::IUnknownImpl + ID3D12RootSignatureDeserializer_Impl>(this: *mut core::ffi::c_void) -> *mut D3D12_ROOT_SIGNATURE_DESC {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12RootSignatureDeserializer_Impl::GetRootSignatureDesc(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetRootSignatureDesc: GetRootSignatureDesc::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12RootSignatureDeserializer {}
 windows_core::imp::define_interface!(ID3D12SDKConfiguration, ID3D12SDKConfiguration_Vtbl, 0xe9eb5314_33aa_42b2_a718_d77f58b1f1c7);
 windows_core::imp::interface_hierarchy!(ID3D12SDKConfiguration, windows_core::IUnknown);
@@ -15144,6 +18138,20 @@ impl ID3D12SDKConfiguration_Vtbl {
         iid == &<ID3D12SDKConfiguration as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12SDKConfiguration_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12SDKConfiguration_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetSDKVersion<Identity: windows_core::IUnknownImpl + ID3D12SDKConfiguration_Impl>(this: *mut core::ffi::c_void, sdkversion: u32, sdkpath: windows_core::PCSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12SDKConfiguration_Impl::SetSDKVersion(this, core::mem::transmute_copy(&sdkversion), core::mem::transmute(&sdkpath)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetSDKVersion: SetSDKVersion::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ID3D12SDKConfiguration {}
 windows_core::imp::define_interface!(ID3D12SDKConfiguration1, ID3D12SDKConfiguration1_Vtbl, 0x8aaf9303_ad25_48b9_9a57_d9c37e009d9f);
 impl core::ops::Deref for ID3D12SDKConfiguration1 {
@@ -15203,6 +18211,30 @@ impl ID3D12SDKConfiguration1_Vtbl {
         iid == &<ID3D12SDKConfiguration1 as windows_core::Interface>::IID || iid == &<ID3D12SDKConfiguration as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12SDKConfiguration1_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12SDKConfiguration1_Vtbl
+where
+    ID3D12SDKConfiguration_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateDeviceFactory<Identity: windows_core::IUnknownImpl + ID3D12SDKConfiguration1_Impl>(this: *mut core::ffi::c_void, sdkversion: u32, sdkpath: windows_core::PCSTR, riid: *const windows_core::GUID, ppvfactory: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12SDKConfiguration1_Impl::CreateDeviceFactory(this, core::mem::transmute_copy(&sdkversion), core::mem::transmute(&sdkpath), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvfactory)).into()
+            }
+        }
+        unsafe extern "system" fn FreeUnusedSDKs<Identity: windows_core::IUnknownImpl + ID3D12SDKConfiguration1_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12SDKConfiguration1_Impl::FreeUnusedSDKs(this)
+            }
+        }
+        Self {
+            base__: <ID3D12SDKConfiguration_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateDeviceFactory: CreateDeviceFactory::<Identity>,
+            FreeUnusedSDKs: FreeUnusedSDKs::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12SDKConfiguration1 {}
 windows_core::imp::define_interface!(ID3D12ShaderCacheSession, ID3D12ShaderCacheSession_Vtbl, 0x28e2495d_0f64_4ae4_a6ec_129255dc49a8);
 impl core::ops::Deref for ID3D12ShaderCacheSession {
@@ -15285,6 +18317,44 @@ impl ID3D12ShaderCacheSession_Vtbl {
         iid == &<ID3D12ShaderCacheSession as windows_core::Interface>::IID || iid == &<ID3D12Object as windows_core::Interface>::IID || iid == &<ID3D12DeviceChild as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12ShaderCacheSession_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12ShaderCacheSession_Vtbl
+where
+    ID3D12DeviceChild_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FindValue<Identity: windows_core::IUnknownImpl + ID3D12ShaderCacheSession_Impl>(this: *mut core::ffi::c_void, pkey: *const core::ffi::c_void, keysize: u32, pvalue: *mut core::ffi::c_void, pvaluesize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12ShaderCacheSession_Impl::FindValue(this, core::mem::transmute_copy(&pkey), core::mem::transmute_copy(&keysize), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&pvaluesize)).into()
+            }
+        }
+        unsafe extern "system" fn StoreValue<Identity: windows_core::IUnknownImpl + ID3D12ShaderCacheSession_Impl>(this: *mut core::ffi::c_void, pkey: *const core::ffi::c_void, keysize: u32, pvalue: *const core::ffi::c_void, valuesize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12ShaderCacheSession_Impl::StoreValue(this, core::mem::transmute_copy(&pkey), core::mem::transmute_copy(&keysize), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&valuesize)).into()
+            }
+        }
+        unsafe extern "system" fn SetDeleteOnDestroy<Identity: windows_core::IUnknownImpl + ID3D12ShaderCacheSession_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12ShaderCacheSession_Impl::SetDeleteOnDestroy(this)
+            }
+        }
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D12ShaderCacheSession_Impl>(this: *mut core::ffi::c_void, result__: *mut D3D12_SHADER_CACHE_SESSION_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = ID3D12ShaderCacheSession_Impl::GetDesc(this)
+            }
+        }
+        Self {
+            base__: <ID3D12DeviceChild_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            FindValue: FindValue::<Identity>,
+            StoreValue: StoreValue::<Identity>,
+            SetDeleteOnDestroy: SetDeleteOnDestroy::<Identity>,
+            GetDesc: GetDesc::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12ShaderCacheSession {}
 windows_core::imp::define_interface!(ID3D12ShaderReflection, ID3D12ShaderReflection_Vtbl, 0x5a58797d_a72c_478d_8ba2_efc6b0efe88e);
 windows_core::imp::interface_hierarchy!(ID3D12ShaderReflection, windows_core::IUnknown);
@@ -15444,103 +18514,256 @@ impl ID3D12ShaderReflection_Vtbl {
     pub const fn new<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>() -> Self {
         unsafe extern "system" fn GetDesc<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdesc: *mut D3D12_SHADER_DESC) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12ShaderReflection_Impl::GetDesc(this, core::mem::transmute_copy(&pdesc)).into()
+            }
+        }
+        unsafe extern "system" fn GetConstantBufferByIndex<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, index: u32) -> Option<ID3D12ShaderReflectionConstantBuffer> {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12ShaderReflection_Impl::GetConstantBufferByIndex(this, core::mem::transmute_copy(&index))
+            }
+        }
+        unsafe extern "system" fn GetConstantBufferByName<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: windows_core::PCSTR) -> Option<ID3D12ShaderReflectionConstantBuffer> {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12ShaderReflection_Impl::GetConstantBufferByName(this, core::mem::transmute(&name))
+            }
+        }
+        unsafe extern "system" fn GetResourceBindingDesc<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, resourceindex: u32, pdesc: *mut D3D12_SHADER_INPUT_BIND_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12ShaderReflection_Impl::GetResourceBindingDesc(this, core::mem::transmute_copy(&resourceindex), core::mem::transmute_copy(&pdesc)).into()
+            }
+        }
+        unsafe extern "system" fn GetInputParameterDesc<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, parameterindex: u32, pdesc: *mut D3D12_SIGNATURE_PARAMETER_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12ShaderReflection_Impl::GetInputParameterDesc(this, core::mem::transmute_copy(&parameterindex), core::mem::transmute_copy(&pdesc)).into()
+            }
+        }
+        unsafe extern "system" fn GetOutputParameterDesc<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, parameterindex: u32, pdesc: *mut D3D12_SIGNATURE_PARAMETER_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12ShaderReflection_Impl::GetOutputParameterDesc(this, core::mem::transmute_copy(&parameterindex), core::mem::transmute_copy(&pdesc)).into()
+            }
+        }
+        unsafe extern "system" fn GetPatchConstantParameterDesc<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, parameterindex: u32, pdesc: *mut D3D12_SIGNATURE_PARAMETER_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12ShaderReflection_Impl::GetPatchConstantParameterDesc(this, core::mem::transmute_copy(&parameterindex), core::mem::transmute_copy(&pdesc)).into()
+            }
+        }
+        unsafe extern "system" fn GetVariableByName<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: windows_core::PCSTR) -> Option<ID3D12ShaderReflectionVariable> {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12ShaderReflection_Impl::GetVariableByName(this, core::mem::transmute(&name))
+            }
+        }
+        unsafe extern "system" fn GetResourceBindingDescByName<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: windows_core::PCSTR, pdesc: *mut D3D12_SHADER_INPUT_BIND_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12ShaderReflection_Impl::GetResourceBindingDescByName(this, core::mem::transmute(&name), core::mem::transmute_copy(&pdesc)).into()
+            }
+        }
+        unsafe extern "system" fn GetMovInstructionCount<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12ShaderReflection_Impl::GetMovInstructionCount(this)
+            }
+        }
+        unsafe extern "system" fn GetMovcInstructionCount<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12ShaderReflection_Impl::GetMovcInstructionCount(this)
+            }
+        }
+        unsafe extern "system" fn GetConversionInstructionCount<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12ShaderReflection_Impl::GetConversionInstructionCount(this)
+            }
+        }
+        unsafe extern "system" fn GetBitwiseInstructionCount<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12ShaderReflection_Impl::GetBitwiseInstructionCount(this)
+            }
+        }
+        unsafe extern "system" fn GetGSInputPrimitive<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> super::Direct3D::D3D_PRIMITIVE {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12ShaderReflection_Impl::GetGSInputPrimitive(this)
+            }
+        }
+        unsafe extern "system" fn IsSampleFrequencyShader<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12ShaderReflection_Impl::IsSampleFrequencyShader(this)
+            }
+        }
+        unsafe extern "system" fn GetNumInterfaceSlots<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12ShaderReflection_Impl::GetNumInterfaceSlots(this)
+            }
+        }
+        unsafe extern "system" fn GetMinFeatureLevel<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, plevel: *mut super::Direct3D::D3D_FEATURE_LEVEL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ID3D12ShaderReflection_Impl::GetMinFeatureLevel(this) {
+                    Ok(ok__) => {
+                        plevel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetThreadGroupSize<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, psizex: *mut u32, psizey: *mut u32, psizez: *mut u32) -> u32 {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12ShaderReflection_Impl::GetThreadGroupSize(this, core::mem::transmute_copy(&psizex), core::mem::transmute_copy(&psizey), core::mem::transmute_copy(&psizez))
+            }
+        }
+        unsafe extern "system" fn GetRequiresFlags<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u64 {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ID3D12ShaderReflection_Impl::GetRequiresFlags(this)
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            GetDesc: GetDesc::<Identity, OFFSET>,
+            GetConstantBufferByIndex: GetConstantBufferByIndex::<Identity, OFFSET>,
+            GetConstantBufferByName: GetConstantBufferByName::<Identity, OFFSET>,
+            GetResourceBindingDesc: GetResourceBindingDesc::<Identity, OFFSET>,
+            GetInputParameterDesc: GetInputParameterDesc::<Identity, OFFSET>,
+            GetOutputParameterDesc: GetOutputParameterDesc::<Identity, OFFSET>,
+            GetPatchConstantParameterDesc: GetPatchConstantParameterDesc::<Identity, OFFSET>,
+            GetVariableByName: GetVariableByName::<Identity, OFFSET>,
+            GetResourceBindingDescByName: GetResourceBindingDescByName::<Identity, OFFSET>,
+            GetMovInstructionCount: GetMovInstructionCount::<Identity, OFFSET>,
+            GetMovcInstructionCount: GetMovcInstructionCount::<Identity, OFFSET>,
+            GetConversionInstructionCount: GetConversionInstructionCount::<Identity, OFFSET>,
+            GetBitwiseInstructionCount: GetBitwiseInstructionCount::<Identity, OFFSET>,
+            GetGSInputPrimitive: GetGSInputPrimitive::<Identity, OFFSET>,
+            IsSampleFrequencyShader: IsSampleFrequencyShader::<Identity, OFFSET>,
+            GetNumInterfaceSlots: GetNumInterfaceSlots::<Identity, OFFSET>,
+            GetMinFeatureLevel: GetMinFeatureLevel::<Identity, OFFSET>,
+            GetThreadGroupSize: GetThreadGroupSize::<Identity, OFFSET>,
+            GetRequiresFlags: GetRequiresFlags::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12ShaderReflection as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D")]
+impl<Identity: windows_core::IUnknownImpl + ID3D12ShaderReflection_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12ShaderReflection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + ID3D12ShaderReflection_Impl>(this: *mut core::ffi::c_void, pdesc: *mut D3D12_SHADER_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12ShaderReflection_Impl::GetDesc(this, core::mem::transmute_copy(&pdesc)).into()
             }
         }
-        unsafe extern "system" fn GetConstantBufferByIndex<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, index: u32) -> Option<ID3D12ShaderReflectionConstantBuffer> {
+        unsafe extern "system" fn GetConstantBufferByIndex<Identity: windows_core::IUnknownImpl + ID3D12ShaderReflection_Impl>(this: *mut core::ffi::c_void, index: u32) -> Option<ID3D12ShaderReflectionConstantBuffer> {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12ShaderReflection_Impl::GetConstantBufferByIndex(this, core::mem::transmute_copy(&index))
             }
         }
-        unsafe extern "system" fn GetConstantBufferByName<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: windows_core::PCSTR) -> Option<ID3D12ShaderReflectionConstantBuffer> {
+        unsafe extern "system" fn GetConstantBufferByName<Identity: windows_core::IUnknownImpl + ID3D12ShaderReflection_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCSTR) -> Option<ID3D12ShaderReflectionConstantBuffer> {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12ShaderReflection_Impl::GetConstantBufferByName(this, core::mem::transmute(&name))
             }
         }
-        unsafe extern "system" fn GetResourceBindingDesc<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, resourceindex: u32, pdesc: *mut D3D12_SHADER_INPUT_BIND_DESC) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetResourceBindingDesc<Identity: windows_core::IUnknownImpl + ID3D12ShaderReflection_Impl>(this: *mut core::ffi::c_void, resourceindex: u32, pdesc: *mut D3D12_SHADER_INPUT_BIND_DESC) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12ShaderReflection_Impl::GetResourceBindingDesc(this, core::mem::transmute_copy(&resourceindex), core::mem::transmute_copy(&pdesc)).into()
             }
         }
-        unsafe extern "system" fn GetInputParameterDesc<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, parameterindex: u32, pdesc: *mut D3D12_SIGNATURE_PARAMETER_DESC) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetInputParameterDesc<Identity: windows_core::IUnknownImpl + ID3D12ShaderReflection_Impl>(this: *mut core::ffi::c_void, parameterindex: u32, pdesc: *mut D3D12_SIGNATURE_PARAMETER_DESC) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12ShaderReflection_Impl::GetInputParameterDesc(this, core::mem::transmute_copy(&parameterindex), core::mem::transmute_copy(&pdesc)).into()
             }
         }
-        unsafe extern "system" fn GetOutputParameterDesc<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, parameterindex: u32, pdesc: *mut D3D12_SIGNATURE_PARAMETER_DESC) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetOutputParameterDesc<Identity: windows_core::IUnknownImpl + ID3D12ShaderReflection_Impl>(this: *mut core::ffi::c_void, parameterindex: u32, pdesc: *mut D3D12_SIGNATURE_PARAMETER_DESC) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12ShaderReflection_Impl::GetOutputParameterDesc(this, core::mem::transmute_copy(&parameterindex), core::mem::transmute_copy(&pdesc)).into()
             }
         }
-        unsafe extern "system" fn GetPatchConstantParameterDesc<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, parameterindex: u32, pdesc: *mut D3D12_SIGNATURE_PARAMETER_DESC) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetPatchConstantParameterDesc<Identity: windows_core::IUnknownImpl + ID3D12ShaderReflection_Impl>(this: *mut core::ffi::c_void, parameterindex: u32, pdesc: *mut D3D12_SIGNATURE_PARAMETER_DESC) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12ShaderReflection_Impl::GetPatchConstantParameterDesc(this, core::mem::transmute_copy(&parameterindex), core::mem::transmute_copy(&pdesc)).into()
             }
         }
-        unsafe extern "system" fn GetVariableByName<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: windows_core::PCSTR) -> Option<ID3D12ShaderReflectionVariable> {
+        unsafe extern "system" fn GetVariableByName<Identity: windows_core::IUnknownImpl + ID3D12ShaderReflection_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCSTR) -> Option<ID3D12ShaderReflectionVariable> {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12ShaderReflection_Impl::GetVariableByName(this, core::mem::transmute(&name))
             }
         }
-        unsafe extern "system" fn GetResourceBindingDescByName<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: windows_core::PCSTR, pdesc: *mut D3D12_SHADER_INPUT_BIND_DESC) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetResourceBindingDescByName<Identity: windows_core::IUnknownImpl + ID3D12ShaderReflection_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCSTR, pdesc: *mut D3D12_SHADER_INPUT_BIND_DESC) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12ShaderReflection_Impl::GetResourceBindingDescByName(this, core::mem::transmute(&name), core::mem::transmute_copy(&pdesc)).into()
             }
         }
-        unsafe extern "system" fn GetMovInstructionCount<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u32 {
+        unsafe extern "system" fn GetMovInstructionCount<Identity: windows_core::IUnknownImpl + ID3D12ShaderReflection_Impl>(this: *mut core::ffi::c_void) -> u32 {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12ShaderReflection_Impl::GetMovInstructionCount(this)
             }
         }
-        unsafe extern "system" fn GetMovcInstructionCount<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u32 {
+        unsafe extern "system" fn GetMovcInstructionCount<Identity: windows_core::IUnknownImpl + ID3D12ShaderReflection_Impl>(this: *mut core::ffi::c_void) -> u32 {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12ShaderReflection_Impl::GetMovcInstructionCount(this)
             }
         }
-        unsafe extern "system" fn GetConversionInstructionCount<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u32 {
+        unsafe extern "system" fn GetConversionInstructionCount<Identity: windows_core::IUnknownImpl + ID3D12ShaderReflection_Impl>(this: *mut core::ffi::c_void) -> u32 {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12ShaderReflection_Impl::GetConversionInstructionCount(this)
             }
         }
-        unsafe extern "system" fn GetBitwiseInstructionCount<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u32 {
+        unsafe extern "system" fn GetBitwiseInstructionCount<Identity: windows_core::IUnknownImpl + ID3D12ShaderReflection_Impl>(this: *mut core::ffi::c_void) -> u32 {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12ShaderReflection_Impl::GetBitwiseInstructionCount(this)
             }
         }
-        unsafe extern "system" fn GetGSInputPrimitive<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> super::Direct3D::D3D_PRIMITIVE {
+        unsafe extern "system" fn GetGSInputPrimitive<Identity: windows_core::IUnknownImpl + ID3D12ShaderReflection_Impl>(this: *mut core::ffi::c_void) -> super::Direct3D::D3D_PRIMITIVE {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12ShaderReflection_Impl::GetGSInputPrimitive(this)
             }
         }
-        unsafe extern "system" fn IsSampleFrequencyShader<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+        unsafe extern "system" fn IsSampleFrequencyShader<Identity: windows_core::IUnknownImpl + ID3D12ShaderReflection_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12ShaderReflection_Impl::IsSampleFrequencyShader(this)
             }
         }
-        unsafe extern "system" fn GetNumInterfaceSlots<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u32 {
+        unsafe extern "system" fn GetNumInterfaceSlots<Identity: windows_core::IUnknownImpl + ID3D12ShaderReflection_Impl>(this: *mut core::ffi::c_void) -> u32 {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12ShaderReflection_Impl::GetNumInterfaceSlots(this)
             }
         }
-        unsafe extern "system" fn GetMinFeatureLevel<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, plevel: *mut super::Direct3D::D3D_FEATURE_LEVEL) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetMinFeatureLevel<Identity: windows_core::IUnknownImpl + ID3D12ShaderReflection_Impl>(this: *mut core::ffi::c_void, plevel: *mut super::Direct3D::D3D_FEATURE_LEVEL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ID3D12ShaderReflection_Impl::GetMinFeatureLevel(this) {
                     Ok(ok__) => {
                         plevel.write(core::mem::transmute(ok__));
@@ -15550,44 +18773,41 @@ impl ID3D12ShaderReflection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetThreadGroupSize<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, psizex: *mut u32, psizey: *mut u32, psizez: *mut u32) -> u32 {
+        unsafe extern "system" fn GetThreadGroupSize<Identity: windows_core::IUnknownImpl + ID3D12ShaderReflection_Impl>(this: *mut core::ffi::c_void, psizex: *mut u32, psizey: *mut u32, psizez: *mut u32) -> u32 {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12ShaderReflection_Impl::GetThreadGroupSize(this, core::mem::transmute_copy(&psizex), core::mem::transmute_copy(&psizey), core::mem::transmute_copy(&psizez))
             }
         }
-        unsafe extern "system" fn GetRequiresFlags<Identity: ID3D12ShaderReflection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u64 {
+        unsafe extern "system" fn GetRequiresFlags<Identity: windows_core::IUnknownImpl + ID3D12ShaderReflection_Impl>(this: *mut core::ffi::c_void) -> u64 {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ID3D12ShaderReflection_Impl::GetRequiresFlags(this)
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            GetDesc: GetDesc::<Identity, OFFSET>,
-            GetConstantBufferByIndex: GetConstantBufferByIndex::<Identity, OFFSET>,
-            GetConstantBufferByName: GetConstantBufferByName::<Identity, OFFSET>,
-            GetResourceBindingDesc: GetResourceBindingDesc::<Identity, OFFSET>,
-            GetInputParameterDesc: GetInputParameterDesc::<Identity, OFFSET>,
-            GetOutputParameterDesc: GetOutputParameterDesc::<Identity, OFFSET>,
-            GetPatchConstantParameterDesc: GetPatchConstantParameterDesc::<Identity, OFFSET>,
-            GetVariableByName: GetVariableByName::<Identity, OFFSET>,
-            GetResourceBindingDescByName: GetResourceBindingDescByName::<Identity, OFFSET>,
-            GetMovInstructionCount: GetMovInstructionCount::<Identity, OFFSET>,
-            GetMovcInstructionCount: GetMovcInstructionCount::<Identity, OFFSET>,
-            GetConversionInstructionCount: GetConversionInstructionCount::<Identity, OFFSET>,
-            GetBitwiseInstructionCount: GetBitwiseInstructionCount::<Identity, OFFSET>,
-            GetGSInputPrimitive: GetGSInputPrimitive::<Identity, OFFSET>,
-            IsSampleFrequencyShader: IsSampleFrequencyShader::<Identity, OFFSET>,
-            GetNumInterfaceSlots: GetNumInterfaceSlots::<Identity, OFFSET>,
-            GetMinFeatureLevel: GetMinFeatureLevel::<Identity, OFFSET>,
-            GetThreadGroupSize: GetThreadGroupSize::<Identity, OFFSET>,
-            GetRequiresFlags: GetRequiresFlags::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ID3D12ShaderReflection as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDesc: GetDesc::<Identity>,
+            GetConstantBufferByIndex: GetConstantBufferByIndex::<Identity>,
+            GetConstantBufferByName: GetConstantBufferByName::<Identity>,
+            GetResourceBindingDesc: GetResourceBindingDesc::<Identity>,
+            GetInputParameterDesc: GetInputParameterDesc::<Identity>,
+            GetOutputParameterDesc: GetOutputParameterDesc::<Identity>,
+            GetPatchConstantParameterDesc: GetPatchConstantParameterDesc::<Identity>,
+            GetVariableByName: GetVariableByName::<Identity>,
+            GetResourceBindingDescByName: GetResourceBindingDescByName::<Identity>,
+            GetMovInstructionCount: GetMovInstructionCount::<Identity>,
+            GetMovcInstructionCount: GetMovcInstructionCount::<Identity>,
+            GetConversionInstructionCount: GetConversionInstructionCount::<Identity>,
+            GetBitwiseInstructionCount: GetBitwiseInstructionCount::<Identity>,
+            GetGSInputPrimitive: GetGSInputPrimitive::<Identity>,
+            IsSampleFrequencyShader: IsSampleFrequencyShader::<Identity>,
+            GetNumInterfaceSlots: GetNumInterfaceSlots::<Identity>,
+            GetMinFeatureLevel: GetMinFeatureLevel::<Identity>,
+            GetThreadGroupSize: GetThreadGroupSize::<Identity>,
+            GetRequiresFlags: GetRequiresFlags::<Identity>,
+        }
+    };
 }
 #[cfg(feature = "Win32_Graphics_Direct3D")]
 impl windows_core::RuntimeName for ID3D12ShaderReflection {}
@@ -16010,6 +19230,44 @@ impl ID3D12SharingContract_Vtbl {
         iid == &<ID3D12SharingContract as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12SharingContract_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12SharingContract_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Present<Identity: windows_core::IUnknownImpl + ID3D12SharingContract_Impl>(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, subresource: u32, window: super::super::Foundation::HWND) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12SharingContract_Impl::Present(this, core::mem::transmute_copy(&presource), core::mem::transmute_copy(&subresource), core::mem::transmute_copy(&window))
+            }
+        }
+        unsafe extern "system" fn SharedFenceSignal<Identity: windows_core::IUnknownImpl + ID3D12SharingContract_Impl>(this: *mut core::ffi::c_void, pfence: *mut core::ffi::c_void, fencevalue: u64) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12SharingContract_Impl::SharedFenceSignal(this, core::mem::transmute_copy(&pfence), core::mem::transmute_copy(&fencevalue))
+            }
+        }
+        unsafe extern "system" fn BeginCapturableWork<Identity: windows_core::IUnknownImpl + ID3D12SharingContract_Impl>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12SharingContract_Impl::BeginCapturableWork(this, core::mem::transmute_copy(&guid))
+            }
+        }
+        unsafe extern "system" fn EndCapturableWork<Identity: windows_core::IUnknownImpl + ID3D12SharingContract_Impl>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12SharingContract_Impl::EndCapturableWork(this, core::mem::transmute_copy(&guid))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Present: Present::<Identity>,
+            SharedFenceSignal: SharedFenceSignal::<Identity>,
+            BeginCapturableWork: BeginCapturableWork::<Identity>,
+            EndCapturableWork: EndCapturableWork::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12SharingContract {}
 windows_core::imp::define_interface!(ID3D12StateObject, ID3D12StateObject_Vtbl, 0x47016943_fca8_4594_93ea_af258b55346d);
 impl core::ops::Deref for ID3D12StateObject {
@@ -16035,6 +19293,12 @@ impl ID3D12StateObject_Vtbl {
         iid == &<ID3D12StateObject as windows_core::Interface>::IID || iid == &<ID3D12Object as windows_core::Interface>::IID || iid == &<ID3D12DeviceChild as windows_core::Interface>::IID || iid == &<ID3D12Pageable as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12StateObject_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12StateObject_Vtbl
+where
+    ID3D12Pageable_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <ID3D12Pageable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for ID3D12StateObject {}
 windows_core::imp::define_interface!(ID3D12StateObjectProperties, ID3D12StateObjectProperties_Vtbl, 0xde5fa827_9bf9_4f26_89ff_d7f56fde3860);
 windows_core::imp::interface_hierarchy!(ID3D12StateObjectProperties, windows_core::IUnknown);
@@ -16113,6 +19377,44 @@ impl ID3D12StateObjectProperties_Vtbl {
         iid == &<ID3D12StateObjectProperties as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12StateObjectProperties_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12StateObjectProperties_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetShaderIdentifier<Identity: windows_core::IUnknownImpl + ID3D12StateObjectProperties_Impl>(this: *mut core::ffi::c_void, pexportname: windows_core::PCWSTR) -> *mut core::ffi::c_void {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12StateObjectProperties_Impl::GetShaderIdentifier(this, core::mem::transmute(&pexportname))
+            }
+        }
+        unsafe extern "system" fn GetShaderStackSize<Identity: windows_core::IUnknownImpl + ID3D12StateObjectProperties_Impl>(this: *mut core::ffi::c_void, pexportname: windows_core::PCWSTR) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12StateObjectProperties_Impl::GetShaderStackSize(this, core::mem::transmute(&pexportname))
+            }
+        }
+        unsafe extern "system" fn GetPipelineStackSize<Identity: windows_core::IUnknownImpl + ID3D12StateObjectProperties_Impl>(this: *mut core::ffi::c_void) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12StateObjectProperties_Impl::GetPipelineStackSize(this)
+            }
+        }
+        unsafe extern "system" fn SetPipelineStackSize<Identity: windows_core::IUnknownImpl + ID3D12StateObjectProperties_Impl>(this: *mut core::ffi::c_void, pipelinestacksizeinbytes: u64) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12StateObjectProperties_Impl::SetPipelineStackSize(this, core::mem::transmute_copy(&pipelinestacksizeinbytes))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetShaderIdentifier: GetShaderIdentifier::<Identity>,
+            GetShaderStackSize: GetShaderStackSize::<Identity>,
+            GetPipelineStackSize: GetPipelineStackSize::<Identity>,
+            SetPipelineStackSize: SetPipelineStackSize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12StateObjectProperties {}
 windows_core::imp::define_interface!(ID3D12StateObjectProperties1, ID3D12StateObjectProperties1_Vtbl, 0x460caac7_1d24_446a_a184_ca67db494138);
 impl core::ops::Deref for ID3D12StateObjectProperties1 {
@@ -16159,6 +19461,23 @@ impl ID3D12StateObjectProperties1_Vtbl {
         iid == &<ID3D12StateObjectProperties1 as windows_core::Interface>::IID || iid == &<ID3D12StateObjectProperties as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12StateObjectProperties1_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12StateObjectProperties1_Vtbl
+where
+    ID3D12StateObjectProperties_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProgramIdentifier<Identity: windows_core::IUnknownImpl + ID3D12StateObjectProperties1_Impl>(this: *mut core::ffi::c_void, result__: *mut D3D12_PROGRAM_IDENTIFIER, pprogramname: windows_core::PCWSTR) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = ID3D12StateObjectProperties1_Impl::GetProgramIdentifier(this, core::mem::transmute(&pprogramname))
+            }
+        }
+        Self {
+            base__: <ID3D12StateObjectProperties_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProgramIdentifier: GetProgramIdentifier::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12StateObjectProperties1 {}
 windows_core::imp::define_interface!(ID3D12SwapChainAssistant, ID3D12SwapChainAssistant_Vtbl, 0xf1df64b6_57fd_49cd_8807_c0eb88b45c8f);
 windows_core::imp::interface_hierarchy!(ID3D12SwapChainAssistant, windows_core::IUnknown);
@@ -16243,6 +19562,44 @@ impl ID3D12SwapChainAssistant_Vtbl {
         iid == &<ID3D12SwapChainAssistant as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12SwapChainAssistant_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12SwapChainAssistant_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetLUID<Identity: windows_core::IUnknownImpl + ID3D12SwapChainAssistant_Impl>(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::LUID) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = ID3D12SwapChainAssistant_Impl::GetLUID(this)
+            }
+        }
+        unsafe extern "system" fn GetSwapChainObject<Identity: windows_core::IUnknownImpl + ID3D12SwapChainAssistant_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12SwapChainAssistant_Impl::GetSwapChainObject(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppv)).into()
+            }
+        }
+        unsafe extern "system" fn GetCurrentResourceAndCommandQueue<Identity: windows_core::IUnknownImpl + ID3D12SwapChainAssistant_Impl>(this: *mut core::ffi::c_void, riidresource: *const windows_core::GUID, ppvresource: *mut *mut core::ffi::c_void, riidqueue: *const windows_core::GUID, ppvqueue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12SwapChainAssistant_Impl::GetCurrentResourceAndCommandQueue(this, core::mem::transmute_copy(&riidresource), core::mem::transmute_copy(&ppvresource), core::mem::transmute_copy(&riidqueue), core::mem::transmute_copy(&ppvqueue)).into()
+            }
+        }
+        unsafe extern "system" fn InsertImplicitSync<Identity: windows_core::IUnknownImpl + ID3D12SwapChainAssistant_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12SwapChainAssistant_Impl::InsertImplicitSync(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetLUID: GetLUID::<Identity>,
+            GetSwapChainObject: GetSwapChainObject::<Identity>,
+            GetCurrentResourceAndCommandQueue: GetCurrentResourceAndCommandQueue::<Identity>,
+            InsertImplicitSync: InsertImplicitSync::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12SwapChainAssistant {}
 windows_core::imp::define_interface!(ID3D12Tools, ID3D12Tools_Vtbl, 0x7071e1f0_e84b_4b33_974f_12fa49de65c5);
 windows_core::imp::interface_hierarchy!(ID3D12Tools, windows_core::IUnknown);
@@ -16291,6 +19648,30 @@ impl ID3D12Tools_Vtbl {
         iid == &<ID3D12Tools as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12Tools_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12Tools_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnableShaderInstrumentation<Identity: windows_core::IUnknownImpl + ID3D12Tools_Impl>(this: *mut core::ffi::c_void, benable: windows_core::BOOL) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Tools_Impl::EnableShaderInstrumentation(this, core::mem::transmute_copy(&benable))
+            }
+        }
+        unsafe extern "system" fn ShaderInstrumentationEnabled<Identity: windows_core::IUnknownImpl + ID3D12Tools_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12Tools_Impl::ShaderInstrumentationEnabled(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnableShaderInstrumentation: EnableShaderInstrumentation::<Identity>,
+            ShaderInstrumentationEnabled: ShaderInstrumentationEnabled::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12Tools {}
 windows_core::imp::define_interface!(ID3D12VersionedRootSignatureDeserializer, ID3D12VersionedRootSignatureDeserializer_Vtbl, 0x7f91ce67_090c_4bb7_b78e_ed8ff2e31da0);
 windows_core::imp::interface_hierarchy!(ID3D12VersionedRootSignatureDeserializer, windows_core::IUnknown);
@@ -16348,6 +19729,36 @@ impl ID3D12VersionedRootSignatureDeserializer_Vtbl {
         iid == &<ID3D12VersionedRootSignatureDeserializer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12VersionedRootSignatureDeserializer_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12VersionedRootSignatureDeserializer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRootSignatureDescAtVersion<Identity: windows_core::IUnknownImpl + ID3D12VersionedRootSignatureDeserializer_Impl>(this: *mut core::ffi::c_void, converttoversion: D3D_ROOT_SIGNATURE_VERSION, ppdesc: *mut *mut D3D12_VERSIONED_ROOT_SIGNATURE_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D12VersionedRootSignatureDeserializer_Impl::GetRootSignatureDescAtVersion(this, core::mem::transmute_copy(&converttoversion)) {
+                    Ok(ok__) => {
+                        ppdesc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetUnconvertedRootSignatureDesc<Identity: windows_core::IUnknownImpl + ID3D12VersionedRootSignatureDeserializer_Impl>(this: *mut core::ffi::c_void) -> *mut D3D12_VERSIONED_ROOT_SIGNATURE_DESC {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12VersionedRootSignatureDeserializer_Impl::GetUnconvertedRootSignatureDesc(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetRootSignatureDescAtVersion: GetRootSignatureDescAtVersion::<Identity>,
+            GetUnconvertedRootSignatureDesc: GetUnconvertedRootSignatureDesc::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12VersionedRootSignatureDeserializer {}
 windows_core::imp::define_interface!(ID3D12VirtualizationGuestDevice, ID3D12VirtualizationGuestDevice_Vtbl, 0xbc66d368_7373_4943_8757_fc87dc79e476);
 windows_core::imp::interface_hierarchy!(ID3D12VirtualizationGuestDevice, windows_core::IUnknown);
@@ -16420,6 +19831,42 @@ impl ID3D12VirtualizationGuestDevice_Vtbl {
         iid == &<ID3D12VirtualizationGuestDevice as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12VirtualizationGuestDevice_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12VirtualizationGuestDevice_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ShareWithHost<Identity: windows_core::IUnknownImpl + ID3D12VirtualizationGuestDevice_Impl>(this: *mut core::ffi::c_void, pobject: *mut core::ffi::c_void, phandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D12VirtualizationGuestDevice_Impl::ShareWithHost(this, core::mem::transmute_copy(&pobject)) {
+                    Ok(ok__) => {
+                        phandle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateFenceFd<Identity: windows_core::IUnknownImpl + ID3D12VirtualizationGuestDevice_Impl>(this: *mut core::ffi::c_void, pfence: *mut core::ffi::c_void, fencevalue: u64, pfencefd: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ID3D12VirtualizationGuestDevice_Impl::CreateFenceFd(this, core::mem::transmute_copy(&pfence), core::mem::transmute_copy(&fencevalue)) {
+                    Ok(ok__) => {
+                        pfencefd.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ShareWithHost: ShareWithHost::<Identity>,
+            CreateFenceFd: CreateFenceFd::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12VirtualizationGuestDevice {}
 windows_core::imp::define_interface!(ID3D12WorkGraphProperties, ID3D12WorkGraphProperties_Vtbl, 0x065acf71_f863_4b89_82f4_02e4d5886757);
 windows_core::imp::interface_hierarchy!(ID3D12WorkGraphProperties, windows_core::IUnknown);
@@ -16611,6 +20058,107 @@ impl ID3D12WorkGraphProperties_Vtbl {
         iid == &<ID3D12WorkGraphProperties as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ID3D12WorkGraphProperties_Impl> windows_core::imp::TearOffVtable<Identity> for ID3D12WorkGraphProperties_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetNumWorkGraphs<Identity: windows_core::IUnknownImpl + ID3D12WorkGraphProperties_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12WorkGraphProperties_Impl::GetNumWorkGraphs(this)
+            }
+        }
+        unsafe extern "system" fn GetProgramName<Identity: windows_core::IUnknownImpl + ID3D12WorkGraphProperties_Impl>(this: *mut core::ffi::c_void, workgraphindex: u32) -> windows_core::PCWSTR {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12WorkGraphProperties_Impl::GetProgramName(this, core::mem::transmute_copy(&workgraphindex))
+            }
+        }
+        unsafe extern "system" fn GetWorkGraphIndex<Identity: windows_core::IUnknownImpl + ID3D12WorkGraphProperties_Impl>(this: *mut core::ffi::c_void, pprogramname: windows_core::PCWSTR) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12WorkGraphProperties_Impl::GetWorkGraphIndex(this, core::mem::transmute(&pprogramname))
+            }
+        }
+        unsafe extern "system" fn GetNumNodes<Identity: windows_core::IUnknownImpl + ID3D12WorkGraphProperties_Impl>(this: *mut core::ffi::c_void, workgraphindex: u32) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12WorkGraphProperties_Impl::GetNumNodes(this, core::mem::transmute_copy(&workgraphindex))
+            }
+        }
+        unsafe extern "system" fn GetNodeID<Identity: windows_core::IUnknownImpl + ID3D12WorkGraphProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut D3D12_NODE_ID, workgraphindex: u32, nodeindex: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = ID3D12WorkGraphProperties_Impl::GetNodeID(this, core::mem::transmute_copy(&workgraphindex), core::mem::transmute_copy(&nodeindex))
+            }
+        }
+        unsafe extern "system" fn GetNodeIndex<Identity: windows_core::IUnknownImpl + ID3D12WorkGraphProperties_Impl>(this: *mut core::ffi::c_void, workgraphindex: u32, nodeid: D3D12_NODE_ID) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12WorkGraphProperties_Impl::GetNodeIndex(this, core::mem::transmute_copy(&workgraphindex), core::mem::transmute(&nodeid))
+            }
+        }
+        unsafe extern "system" fn GetNodeLocalRootArgumentsTableIndex<Identity: windows_core::IUnknownImpl + ID3D12WorkGraphProperties_Impl>(this: *mut core::ffi::c_void, workgraphindex: u32, nodeindex: u32) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12WorkGraphProperties_Impl::GetNodeLocalRootArgumentsTableIndex(this, core::mem::transmute_copy(&workgraphindex), core::mem::transmute_copy(&nodeindex))
+            }
+        }
+        unsafe extern "system" fn GetNumEntrypoints<Identity: windows_core::IUnknownImpl + ID3D12WorkGraphProperties_Impl>(this: *mut core::ffi::c_void, workgraphindex: u32) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12WorkGraphProperties_Impl::GetNumEntrypoints(this, core::mem::transmute_copy(&workgraphindex))
+            }
+        }
+        unsafe extern "system" fn GetEntrypointID<Identity: windows_core::IUnknownImpl + ID3D12WorkGraphProperties_Impl>(this: *mut core::ffi::c_void, result__: *mut D3D12_NODE_ID, workgraphindex: u32, entrypointindex: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = ID3D12WorkGraphProperties_Impl::GetEntrypointID(this, core::mem::transmute_copy(&workgraphindex), core::mem::transmute_copy(&entrypointindex))
+            }
+        }
+        unsafe extern "system" fn GetEntrypointIndex<Identity: windows_core::IUnknownImpl + ID3D12WorkGraphProperties_Impl>(this: *mut core::ffi::c_void, workgraphindex: u32, nodeid: D3D12_NODE_ID) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12WorkGraphProperties_Impl::GetEntrypointIndex(this, core::mem::transmute_copy(&workgraphindex), core::mem::transmute(&nodeid))
+            }
+        }
+        unsafe extern "system" fn GetEntrypointRecordSizeInBytes<Identity: windows_core::IUnknownImpl + ID3D12WorkGraphProperties_Impl>(this: *mut core::ffi::c_void, workgraphindex: u32, entrypointindex: u32) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12WorkGraphProperties_Impl::GetEntrypointRecordSizeInBytes(this, core::mem::transmute_copy(&workgraphindex), core::mem::transmute_copy(&entrypointindex))
+            }
+        }
+        unsafe extern "system" fn GetWorkGraphMemoryRequirements<Identity: windows_core::IUnknownImpl + ID3D12WorkGraphProperties_Impl>(this: *mut core::ffi::c_void, workgraphindex: u32, pworkgraphmemoryrequirements: *mut D3D12_WORK_GRAPH_MEMORY_REQUIREMENTS) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12WorkGraphProperties_Impl::GetWorkGraphMemoryRequirements(this, core::mem::transmute_copy(&workgraphindex), core::mem::transmute_copy(&pworkgraphmemoryrequirements))
+            }
+        }
+        unsafe extern "system" fn GetEntrypointRecordAlignmentInBytes<Identity: windows_core::IUnknownImpl + ID3D12WorkGraphProperties_Impl>(this: *mut core::ffi::c_void, workgraphindex: u32, entrypointindex: u32) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ID3D12WorkGraphProperties_Impl::GetEntrypointRecordAlignmentInBytes(this, core::mem::transmute_copy(&workgraphindex), core::mem::transmute_copy(&entrypointindex))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetNumWorkGraphs: GetNumWorkGraphs::<Identity>,
+            GetProgramName: GetProgramName::<Identity>,
+            GetWorkGraphIndex: GetWorkGraphIndex::<Identity>,
+            GetNumNodes: GetNumNodes::<Identity>,
+            GetNodeID: GetNodeID::<Identity>,
+            GetNodeIndex: GetNodeIndex::<Identity>,
+            GetNodeLocalRootArgumentsTableIndex: GetNodeLocalRootArgumentsTableIndex::<Identity>,
+            GetNumEntrypoints: GetNumEntrypoints::<Identity>,
+            GetEntrypointID: GetEntrypointID::<Identity>,
+            GetEntrypointIndex: GetEntrypointIndex::<Identity>,
+            GetEntrypointRecordSizeInBytes: GetEntrypointRecordSizeInBytes::<Identity>,
+            GetWorkGraphMemoryRequirements: GetWorkGraphMemoryRequirements::<Identity>,
+            GetEntrypointRecordAlignmentInBytes: GetEntrypointRecordAlignmentInBytes::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ID3D12WorkGraphProperties {}
 pub const LUID_DEFINED: u32 = 1u32;
 pub const NUM_D3D12_GPU_BASED_VALIDATION_SHADER_PATCH_MODES: D3D12_GPU_BASED_VALIDATION_SHADER_PATCH_MODE = D3D12_GPU_BASED_VALIDATION_SHADER_PATCH_MODE(4i32);
@@ -311,6 +311,66 @@ impl IDCompositionAffineTransform2DEffect_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDCompositionAffineTransform2DEffect_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionAffineTransform2DEffect_Vtbl
+where
+    IDCompositionFilterEffect_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetInterpolationMode<Identity: windows_core::IUnknownImpl + IDCompositionAffineTransform2DEffect_Impl>(this: *mut core::ffi::c_void, interpolationmode: super::Direct2D::Common::D2D1_2DAFFINETRANSFORM_INTERPOLATION_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionAffineTransform2DEffect_Impl::SetInterpolationMode(this, core::mem::transmute_copy(&interpolationmode)).into()
+            }
+        }
+        unsafe extern "system" fn SetBorderMode<Identity: windows_core::IUnknownImpl + IDCompositionAffineTransform2DEffect_Impl>(this: *mut core::ffi::c_void, bordermode: super::Direct2D::Common::D2D1_BORDER_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionAffineTransform2DEffect_Impl::SetBorderMode(this, core::mem::transmute_copy(&bordermode)).into()
+            }
+        }
+        unsafe extern "system" fn SetTransformMatrix<Identity: windows_core::IUnknownImpl + IDCompositionAffineTransform2DEffect_Impl>(this: *mut core::ffi::c_void, transformmatrix: *const windows_numerics::Matrix3x2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionAffineTransform2DEffect_Impl::SetTransformMatrix(this, core::mem::transmute_copy(&transformmatrix)).into()
+            }
+        }
+        unsafe extern "system" fn SetTransformMatrixElement<Identity: windows_core::IUnknownImpl + IDCompositionAffineTransform2DEffect_Impl>(this: *mut core::ffi::c_void, row: i32, column: i32, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionAffineTransform2DEffect_Impl::SetTransformMatrixElement(this, core::mem::transmute_copy(&row), core::mem::transmute_copy(&column), core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetTransformMatrixElement2<Identity: windows_core::IUnknownImpl + IDCompositionAffineTransform2DEffect_Impl>(this: *mut core::ffi::c_void, row: i32, column: i32, value: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionAffineTransform2DEffect_Impl::SetTransformMatrixElement2(this, core::mem::transmute_copy(&row), core::mem::transmute_copy(&column), core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn SetSharpness<Identity: windows_core::IUnknownImpl + IDCompositionAffineTransform2DEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionAffineTransform2DEffect_Impl::SetSharpness(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetSharpness2<Identity: windows_core::IUnknownImpl + IDCompositionAffineTransform2DEffect_Impl>(this: *mut core::ffi::c_void, sharpness: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionAffineTransform2DEffect_Impl::SetSharpness2(this, core::mem::transmute_copy(&sharpness)).into()
+            }
+        }
+        Self {
+            base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetInterpolationMode: SetInterpolationMode::<Identity>,
+            SetBorderMode: SetBorderMode::<Identity>,
+            SetTransformMatrix: SetTransformMatrix::<Identity>,
+            SetTransformMatrixElement: SetTransformMatrixElement::<Identity>,
+            SetTransformMatrixElement2: SetTransformMatrixElement2::<Identity>,
+            SetSharpness: SetSharpness::<Identity>,
+            SetSharpness2: SetSharpness2::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
 impl windows_core::RuntimeName for IDCompositionAffineTransform2DEffect {}
 windows_core::imp::define_interface!(IDCompositionAnimation, IDCompositionAnimation_Vtbl, 0xcbfd91d9_51b2_45e4_b3de_d19ccfb863c5);
 windows_core::imp::interface_hierarchy!(IDCompositionAnimation, windows_core::IUnknown);
@@ -405,6 +465,58 @@ impl IDCompositionAnimation_Vtbl {
         iid == &<IDCompositionAnimation as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionAnimation_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionAnimation_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IDCompositionAnimation_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionAnimation_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn SetAbsoluteBeginTime<Identity: windows_core::IUnknownImpl + IDCompositionAnimation_Impl>(this: *mut core::ffi::c_void, begintime: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionAnimation_Impl::SetAbsoluteBeginTime(this, core::mem::transmute_copy(&begintime)).into()
+            }
+        }
+        unsafe extern "system" fn AddCubic<Identity: windows_core::IUnknownImpl + IDCompositionAnimation_Impl>(this: *mut core::ffi::c_void, beginoffset: f64, constantcoefficient: f32, linearcoefficient: f32, quadraticcoefficient: f32, cubiccoefficient: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionAnimation_Impl::AddCubic(this, core::mem::transmute_copy(&beginoffset), core::mem::transmute_copy(&constantcoefficient), core::mem::transmute_copy(&linearcoefficient), core::mem::transmute_copy(&quadraticcoefficient), core::mem::transmute_copy(&cubiccoefficient)).into()
+            }
+        }
+        unsafe extern "system" fn AddSinusoidal<Identity: windows_core::IUnknownImpl + IDCompositionAnimation_Impl>(this: *mut core::ffi::c_void, beginoffset: f64, bias: f32, amplitude: f32, frequency: f32, phase: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionAnimation_Impl::AddSinusoidal(this, core::mem::transmute_copy(&beginoffset), core::mem::transmute_copy(&bias), core::mem::transmute_copy(&amplitude), core::mem::transmute_copy(&frequency), core::mem::transmute_copy(&phase)).into()
+            }
+        }
+        unsafe extern "system" fn AddRepeat<Identity: windows_core::IUnknownImpl + IDCompositionAnimation_Impl>(this: *mut core::ffi::c_void, beginoffset: f64, durationtorepeat: f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionAnimation_Impl::AddRepeat(this, core::mem::transmute_copy(&beginoffset), core::mem::transmute_copy(&durationtorepeat)).into()
+            }
+        }
+        unsafe extern "system" fn End<Identity: windows_core::IUnknownImpl + IDCompositionAnimation_Impl>(this: *mut core::ffi::c_void, endoffset: f64, endvalue: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionAnimation_Impl::End(this, core::mem::transmute_copy(&endoffset), core::mem::transmute_copy(&endvalue)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Reset: Reset::<Identity>,
+            SetAbsoluteBeginTime: SetAbsoluteBeginTime::<Identity>,
+            AddCubic: AddCubic::<Identity>,
+            AddSinusoidal: AddSinusoidal::<Identity>,
+            AddRepeat: AddRepeat::<Identity>,
+            End: End::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDCompositionAnimation {}
 windows_core::imp::define_interface!(IDCompositionArithmeticCompositeEffect, IDCompositionArithmeticCompositeEffect_Vtbl, 0x3b67dfa8_e3dd_4e61_b640_46c2f3d739dc);
 impl core::ops::Deref for IDCompositionArithmeticCompositeEffect {
@@ -565,6 +677,86 @@ impl IDCompositionArithmeticCompositeEffect_Vtbl {
         iid == &<IDCompositionArithmeticCompositeEffect as windows_core::Interface>::IID || iid == &<IDCompositionEffect as windows_core::Interface>::IID || iid == &<IDCompositionFilterEffect as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionArithmeticCompositeEffect_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionArithmeticCompositeEffect_Vtbl
+where
+    IDCompositionFilterEffect_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetCoefficients<Identity: windows_core::IUnknownImpl + IDCompositionArithmeticCompositeEffect_Impl>(this: *mut core::ffi::c_void, coefficients: *const windows_numerics::Vector4) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionArithmeticCompositeEffect_Impl::SetCoefficients(this, core::mem::transmute_copy(&coefficients)).into()
+            }
+        }
+        unsafe extern "system" fn SetClampOutput<Identity: windows_core::IUnknownImpl + IDCompositionArithmeticCompositeEffect_Impl>(this: *mut core::ffi::c_void, clampoutput: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionArithmeticCompositeEffect_Impl::SetClampOutput(this, core::mem::transmute_copy(&clampoutput)).into()
+            }
+        }
+        unsafe extern "system" fn SetCoefficient1<Identity: windows_core::IUnknownImpl + IDCompositionArithmeticCompositeEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionArithmeticCompositeEffect_Impl::SetCoefficient1(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetCoefficient12<Identity: windows_core::IUnknownImpl + IDCompositionArithmeticCompositeEffect_Impl>(this: *mut core::ffi::c_void, coeffcient1: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionArithmeticCompositeEffect_Impl::SetCoefficient12(this, core::mem::transmute_copy(&coeffcient1)).into()
+            }
+        }
+        unsafe extern "system" fn SetCoefficient2<Identity: windows_core::IUnknownImpl + IDCompositionArithmeticCompositeEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionArithmeticCompositeEffect_Impl::SetCoefficient2(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetCoefficient22<Identity: windows_core::IUnknownImpl + IDCompositionArithmeticCompositeEffect_Impl>(this: *mut core::ffi::c_void, coefficient2: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionArithmeticCompositeEffect_Impl::SetCoefficient22(this, core::mem::transmute_copy(&coefficient2)).into()
+            }
+        }
+        unsafe extern "system" fn SetCoefficient3<Identity: windows_core::IUnknownImpl + IDCompositionArithmeticCompositeEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionArithmeticCompositeEffect_Impl::SetCoefficient3(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetCoefficient32<Identity: windows_core::IUnknownImpl + IDCompositionArithmeticCompositeEffect_Impl>(this: *mut core::ffi::c_void, coefficient3: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionArithmeticCompositeEffect_Impl::SetCoefficient32(this, core::mem::transmute_copy(&coefficient3)).into()
+            }
+        }
+        unsafe extern "system" fn SetCoefficient4<Identity: windows_core::IUnknownImpl + IDCompositionArithmeticCompositeEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionArithmeticCompositeEffect_Impl::SetCoefficient4(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetCoefficient42<Identity: windows_core::IUnknownImpl + IDCompositionArithmeticCompositeEffect_Impl>(this: *mut core::ffi::c_void, coefficient4: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionArithmeticCompositeEffect_Impl::SetCoefficient42(this, core::mem::transmute_copy(&coefficient4)).into()
+            }
+        }
+        Self {
+            base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetCoefficients: SetCoefficients::<Identity>,
+            SetClampOutput: SetClampOutput::<Identity>,
+            SetCoefficient1: SetCoefficient1::<Identity>,
+            SetCoefficient12: SetCoefficient12::<Identity>,
+            SetCoefficient2: SetCoefficient2::<Identity>,
+            SetCoefficient22: SetCoefficient22::<Identity>,
+            SetCoefficient3: SetCoefficient3::<Identity>,
+            SetCoefficient32: SetCoefficient32::<Identity>,
+            SetCoefficient4: SetCoefficient4::<Identity>,
+            SetCoefficient42: SetCoefficient42::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDCompositionArithmeticCompositeEffect {}
 windows_core::imp::define_interface!(IDCompositionBlendEffect, IDCompositionBlendEffect_Vtbl, 0x33ecdc0a_578a_4a11_9c14_0cb90517f9c5);
 impl core::ops::Deref for IDCompositionBlendEffect {
@@ -609,6 +801,21 @@ impl IDCompositionBlendEffect_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDCompositionBlendEffect_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionBlendEffect_Vtbl
+where
+    IDCompositionFilterEffect_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetMode<Identity: windows_core::IUnknownImpl + IDCompositionBlendEffect_Impl>(this: *mut core::ffi::c_void, mode: super::Direct2D::Common::D2D1_BLEND_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionBlendEffect_Impl::SetMode(this, core::mem::transmute_copy(&mode)).into()
+            }
+        }
+        Self { base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetMode: SetMode::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
 impl windows_core::RuntimeName for IDCompositionBlendEffect {}
 windows_core::imp::define_interface!(IDCompositionBrightnessEffect, IDCompositionBrightnessEffect_Vtbl, 0x6027496e_cb3a_49ab_934f_d798da4f7da6);
 impl core::ops::Deref for IDCompositionBrightnessEffect {
@@ -769,6 +976,86 @@ impl IDCompositionBrightnessEffect_Vtbl {
         iid == &<IDCompositionBrightnessEffect as windows_core::Interface>::IID || iid == &<IDCompositionEffect as windows_core::Interface>::IID || iid == &<IDCompositionFilterEffect as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionBrightnessEffect_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionBrightnessEffect_Vtbl
+where
+    IDCompositionFilterEffect_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetWhitePoint<Identity: windows_core::IUnknownImpl + IDCompositionBrightnessEffect_Impl>(this: *mut core::ffi::c_void, whitepoint: *const windows_numerics::Vector2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionBrightnessEffect_Impl::SetWhitePoint(this, core::mem::transmute_copy(&whitepoint)).into()
+            }
+        }
+        unsafe extern "system" fn SetBlackPoint<Identity: windows_core::IUnknownImpl + IDCompositionBrightnessEffect_Impl>(this: *mut core::ffi::c_void, blackpoint: *const windows_numerics::Vector2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionBrightnessEffect_Impl::SetBlackPoint(this, core::mem::transmute_copy(&blackpoint)).into()
+            }
+        }
+        unsafe extern "system" fn SetWhitePointX<Identity: windows_core::IUnknownImpl + IDCompositionBrightnessEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionBrightnessEffect_Impl::SetWhitePointX(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetWhitePointX2<Identity: windows_core::IUnknownImpl + IDCompositionBrightnessEffect_Impl>(this: *mut core::ffi::c_void, whitepointx: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionBrightnessEffect_Impl::SetWhitePointX2(this, core::mem::transmute_copy(&whitepointx)).into()
+            }
+        }
+        unsafe extern "system" fn SetWhitePointY<Identity: windows_core::IUnknownImpl + IDCompositionBrightnessEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionBrightnessEffect_Impl::SetWhitePointY(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetWhitePointY2<Identity: windows_core::IUnknownImpl + IDCompositionBrightnessEffect_Impl>(this: *mut core::ffi::c_void, whitepointy: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionBrightnessEffect_Impl::SetWhitePointY2(this, core::mem::transmute_copy(&whitepointy)).into()
+            }
+        }
+        unsafe extern "system" fn SetBlackPointX<Identity: windows_core::IUnknownImpl + IDCompositionBrightnessEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionBrightnessEffect_Impl::SetBlackPointX(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetBlackPointX2<Identity: windows_core::IUnknownImpl + IDCompositionBrightnessEffect_Impl>(this: *mut core::ffi::c_void, blackpointx: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionBrightnessEffect_Impl::SetBlackPointX2(this, core::mem::transmute_copy(&blackpointx)).into()
+            }
+        }
+        unsafe extern "system" fn SetBlackPointY<Identity: windows_core::IUnknownImpl + IDCompositionBrightnessEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionBrightnessEffect_Impl::SetBlackPointY(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetBlackPointY2<Identity: windows_core::IUnknownImpl + IDCompositionBrightnessEffect_Impl>(this: *mut core::ffi::c_void, blackpointy: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionBrightnessEffect_Impl::SetBlackPointY2(this, core::mem::transmute_copy(&blackpointy)).into()
+            }
+        }
+        Self {
+            base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetWhitePoint: SetWhitePoint::<Identity>,
+            SetBlackPoint: SetBlackPoint::<Identity>,
+            SetWhitePointX: SetWhitePointX::<Identity>,
+            SetWhitePointX2: SetWhitePointX2::<Identity>,
+            SetWhitePointY: SetWhitePointY::<Identity>,
+            SetWhitePointY2: SetWhitePointY2::<Identity>,
+            SetBlackPointX: SetBlackPointX::<Identity>,
+            SetBlackPointX2: SetBlackPointX2::<Identity>,
+            SetBlackPointY: SetBlackPointY::<Identity>,
+            SetBlackPointY2: SetBlackPointY2::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDCompositionBrightnessEffect {}
 windows_core::imp::define_interface!(IDCompositionClip, IDCompositionClip_Vtbl, 0x64ac3703_9d3f_45ec_a109_7cac0e7a13a7);
 windows_core::imp::interface_hierarchy!(IDCompositionClip, windows_core::IUnknown);
@@ -786,6 +1073,12 @@ impl IDCompositionClip_Vtbl {
         iid == &<IDCompositionClip as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionClip_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionClip_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IDCompositionClip {}
 windows_core::imp::define_interface!(IDCompositionColorMatrixEffect, IDCompositionColorMatrixEffect_Vtbl, 0xc1170a22_3ce2_4966_90d4_55408bfc84c4);
 impl core::ops::Deref for IDCompositionColorMatrixEffect {
@@ -888,6 +1181,52 @@ impl IDCompositionColorMatrixEffect_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDCompositionColorMatrixEffect_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionColorMatrixEffect_Vtbl
+where
+    IDCompositionFilterEffect_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetMatrix<Identity: windows_core::IUnknownImpl + IDCompositionColorMatrixEffect_Impl>(this: *mut core::ffi::c_void, matrix: *const super::Direct2D::Common::D2D_MATRIX_5X4_F) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionColorMatrixEffect_Impl::SetMatrix(this, core::mem::transmute_copy(&matrix)).into()
+            }
+        }
+        unsafe extern "system" fn SetMatrixElement<Identity: windows_core::IUnknownImpl + IDCompositionColorMatrixEffect_Impl>(this: *mut core::ffi::c_void, row: i32, column: i32, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionColorMatrixEffect_Impl::SetMatrixElement(this, core::mem::transmute_copy(&row), core::mem::transmute_copy(&column), core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetMatrixElement2<Identity: windows_core::IUnknownImpl + IDCompositionColorMatrixEffect_Impl>(this: *mut core::ffi::c_void, row: i32, column: i32, value: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionColorMatrixEffect_Impl::SetMatrixElement2(this, core::mem::transmute_copy(&row), core::mem::transmute_copy(&column), core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn SetAlphaMode<Identity: windows_core::IUnknownImpl + IDCompositionColorMatrixEffect_Impl>(this: *mut core::ffi::c_void, mode: super::Direct2D::Common::D2D1_COLORMATRIX_ALPHA_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionColorMatrixEffect_Impl::SetAlphaMode(this, core::mem::transmute_copy(&mode)).into()
+            }
+        }
+        unsafe extern "system" fn SetClampOutput<Identity: windows_core::IUnknownImpl + IDCompositionColorMatrixEffect_Impl>(this: *mut core::ffi::c_void, clamp: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionColorMatrixEffect_Impl::SetClampOutput(this, core::mem::transmute_copy(&clamp)).into()
+            }
+        }
+        Self {
+            base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetMatrix: SetMatrix::<Identity>,
+            SetMatrixElement: SetMatrixElement::<Identity>,
+            SetMatrixElement2: SetMatrixElement2::<Identity>,
+            SetAlphaMode: SetAlphaMode::<Identity>,
+            SetClampOutput: SetClampOutput::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
 impl windows_core::RuntimeName for IDCompositionColorMatrixEffect {}
 windows_core::imp::define_interface!(IDCompositionCompositeEffect, IDCompositionCompositeEffect_Vtbl, 0x576616c0_a231_494d_a38d_00fd5ec4db46);
 impl core::ops::Deref for IDCompositionCompositeEffect {
@@ -932,6 +1271,21 @@ impl IDCompositionCompositeEffect_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDCompositionCompositeEffect_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionCompositeEffect_Vtbl
+where
+    IDCompositionFilterEffect_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetMode<Identity: windows_core::IUnknownImpl + IDCompositionCompositeEffect_Impl>(this: *mut core::ffi::c_void, mode: super::Direct2D::Common::D2D1_COMPOSITE_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionCompositeEffect_Impl::SetMode(this, core::mem::transmute_copy(&mode)).into()
+            }
+        }
+        Self { base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetMode: SetMode::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
 impl windows_core::RuntimeName for IDCompositionCompositeEffect {}
 windows_core::imp::define_interface!(IDCompositionDelegatedInkTrail, IDCompositionDelegatedInkTrail_Vtbl, 0xc2448e9b_547d_4057_8cf5_8144ede1c2da);
 windows_core::imp::interface_hierarchy!(IDCompositionDelegatedInkTrail, windows_core::IUnknown);
@@ -1027,6 +1381,57 @@ impl IDCompositionDelegatedInkTrail_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDCompositionDelegatedInkTrail_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionDelegatedInkTrail_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddTrailPoints<Identity: windows_core::IUnknownImpl + IDCompositionDelegatedInkTrail_Impl>(this: *mut core::ffi::c_void, inkpoints: *const DCompositionInkTrailPoint, inkpointscount: u32, generationid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDelegatedInkTrail_Impl::AddTrailPoints(this, core::mem::transmute_copy(&inkpoints), core::mem::transmute_copy(&inkpointscount)) {
+                    Ok(ok__) => {
+                        generationid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddTrailPointsWithPrediction<Identity: windows_core::IUnknownImpl + IDCompositionDelegatedInkTrail_Impl>(this: *mut core::ffi::c_void, inkpoints: *const DCompositionInkTrailPoint, inkpointscount: u32, predictedinkpoints: *const DCompositionInkTrailPoint, predictedinkpointscount: u32, generationid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDelegatedInkTrail_Impl::AddTrailPointsWithPrediction(this, core::mem::transmute_copy(&inkpoints), core::mem::transmute_copy(&inkpointscount), core::mem::transmute_copy(&predictedinkpoints), core::mem::transmute_copy(&predictedinkpointscount)) {
+                    Ok(ok__) => {
+                        generationid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveTrailPoints<Identity: windows_core::IUnknownImpl + IDCompositionDelegatedInkTrail_Impl>(this: *mut core::ffi::c_void, generationid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionDelegatedInkTrail_Impl::RemoveTrailPoints(this, core::mem::transmute_copy(&generationid)).into()
+            }
+        }
+        unsafe extern "system" fn StartNewTrail<Identity: windows_core::IUnknownImpl + IDCompositionDelegatedInkTrail_Impl>(this: *mut core::ffi::c_void, color: *const super::Direct2D::Common::D2D1_COLOR_F) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionDelegatedInkTrail_Impl::StartNewTrail(this, core::mem::transmute_copy(&color)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddTrailPoints: AddTrailPoints::<Identity>,
+            AddTrailPointsWithPrediction: AddTrailPointsWithPrediction::<Identity>,
+            RemoveTrailPoints: RemoveTrailPoints::<Identity>,
+            StartNewTrail: StartNewTrail::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
 impl windows_core::RuntimeName for IDCompositionDelegatedInkTrail {}
 windows_core::imp::define_interface!(IDCompositionDesktopDevice, IDCompositionDesktopDevice_Vtbl, 0x5f4633fe_1e08_4cb8_8c75_ce24333f5602);
 impl core::ops::Deref for IDCompositionDesktopDevice {
@@ -1121,21 +1526,71 @@ impl IDCompositionDesktopDevice_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-impl windows_core::RuntimeName for IDCompositionDesktopDevice {}
-windows_core::imp::define_interface!(IDCompositionDevice, IDCompositionDevice_Vtbl, 0xc37ea93a_e7aa_450d_b16f_9746cb0407f3);
-windows_core::imp::interface_hierarchy!(IDCompositionDevice, windows_core::IUnknown);
-impl IDCompositionDevice {
-    pub unsafe fn Commit(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Commit)(windows_core::Interface::as_raw(self)).ok() }
-    }
-    pub unsafe fn WaitForCommitCompletion(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).WaitForCommitCompletion)(windows_core::Interface::as_raw(self)).ok() }
-    }
-    #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-    pub unsafe fn GetFrameStatistics(&self) -> windows_core::Result<DCOMPOSITION_FRAME_STATISTICS> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetFrameStatistics)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+impl<Identity: windows_core::IUnknownImpl + IDCompositionDesktopDevice_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionDesktopDevice_Vtbl
+where
+    IDCompositionDevice2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateTargetForHwnd<Identity: windows_core::IUnknownImpl + IDCompositionDesktopDevice_Impl>(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, topmost: windows_core::BOOL, target: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDesktopDevice_Impl::CreateTargetForHwnd(this, core::mem::transmute_copy(&hwnd), core::mem::transmute_copy(&topmost)) {
+                    Ok(ok__) => {
+                        target.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSurfaceFromHandle<Identity: windows_core::IUnknownImpl + IDCompositionDesktopDevice_Impl>(this: *mut core::ffi::c_void, handle: super::super::Foundation::HANDLE, surface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDesktopDevice_Impl::CreateSurfaceFromHandle(this, core::mem::transmute_copy(&handle)) {
+                    Ok(ok__) => {
+                        surface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSurfaceFromHwnd<Identity: windows_core::IUnknownImpl + IDCompositionDesktopDevice_Impl>(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, surface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDesktopDevice_Impl::CreateSurfaceFromHwnd(this, core::mem::transmute_copy(&hwnd)) {
+                    Ok(ok__) => {
+                        surface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDCompositionDevice2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateTargetForHwnd: CreateTargetForHwnd::<Identity>,
+            CreateSurfaceFromHandle: CreateSurfaceFromHandle::<Identity>,
+            CreateSurfaceFromHwnd: CreateSurfaceFromHwnd::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::RuntimeName for IDCompositionDesktopDevice {}
+windows_core::imp::define_interface!(IDCompositionDevice, IDCompositionDevice_Vtbl, 0xc37ea93a_e7aa_450d_b16f_9746cb0407f3);
+windows_core::imp::interface_hierarchy!(IDCompositionDevice, windows_core::IUnknown);
+impl IDCompositionDevice {
+    pub unsafe fn Commit(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Commit)(windows_core::Interface::as_raw(self)).ok() }
+    }
+    pub unsafe fn WaitForCommitCompletion(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).WaitForCommitCompletion)(windows_core::Interface::as_raw(self)).ok() }
+    }
+    #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+    pub unsafe fn GetFrameStatistics(&self) -> windows_core::Result<DCOMPOSITION_FRAME_STATISTICS> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetFrameStatistics)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
         }
     }
     pub unsafe fn CreateTargetForHwnd(&self, hwnd: super::super::Foundation::HWND, topmost: bool) -> windows_core::Result<IDCompositionTarget> {
@@ -1644,215 +2099,27 @@ impl IDCompositionDevice_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-impl windows_core::RuntimeName for IDCompositionDevice {}
-windows_core::imp::define_interface!(IDCompositionDevice2, IDCompositionDevice2_Vtbl, 0x75f6468d_1b8e_447c_9bc6_75fea80b5b25);
-windows_core::imp::interface_hierarchy!(IDCompositionDevice2, windows_core::IUnknown);
-impl IDCompositionDevice2 {
-    pub unsafe fn Commit(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Commit)(windows_core::Interface::as_raw(self)).ok() }
-    }
-    pub unsafe fn WaitForCommitCompletion(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).WaitForCommitCompletion)(windows_core::Interface::as_raw(self)).ok() }
-    }
-    #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-    pub unsafe fn GetFrameStatistics(&self) -> windows_core::Result<DCOMPOSITION_FRAME_STATISTICS> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetFrameStatistics)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
-        }
-    }
-    pub unsafe fn CreateVisual(&self) -> windows_core::Result<IDCompositionVisual2> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateVisual)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn CreateSurfaceFactory<P0>(&self, renderingdevice: P0) -> windows_core::Result<IDCompositionSurfaceFactory>
-    where
-        P0: windows_core::Param<windows_core::IUnknown>,
-    {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateSurfaceFactory)(windows_core::Interface::as_raw(self), renderingdevice.param().abi(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-    pub unsafe fn CreateSurface(&self, width: u32, height: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE) -> windows_core::Result<IDCompositionSurface> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateSurface)(windows_core::Interface::as_raw(self), width, height, pixelformat, alphamode, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-    pub unsafe fn CreateVirtualSurface(&self, initialwidth: u32, initialheight: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE) -> windows_core::Result<IDCompositionVirtualSurface> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateVirtualSurface)(windows_core::Interface::as_raw(self), initialwidth, initialheight, pixelformat, alphamode, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn CreateTranslateTransform(&self) -> windows_core::Result<IDCompositionTranslateTransform> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateTranslateTransform)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn CreateScaleTransform(&self) -> windows_core::Result<IDCompositionScaleTransform> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateScaleTransform)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn CreateRotateTransform(&self) -> windows_core::Result<IDCompositionRotateTransform> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateRotateTransform)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn CreateSkewTransform(&self) -> windows_core::Result<IDCompositionSkewTransform> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateSkewTransform)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn CreateMatrixTransform(&self) -> windows_core::Result<IDCompositionMatrixTransform> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateMatrixTransform)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn CreateTransformGroup(&self, transforms: &[Option<IDCompositionTransform>]) -> windows_core::Result<IDCompositionTransform> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateTransformGroup)(windows_core::Interface::as_raw(self), core::mem::transmute(transforms.as_ptr()), transforms.len().try_into().unwrap(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn CreateTranslateTransform3D(&self) -> windows_core::Result<IDCompositionTranslateTransform3D> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateTranslateTransform3D)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn CreateScaleTransform3D(&self) -> windows_core::Result<IDCompositionScaleTransform3D> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateScaleTransform3D)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn CreateRotateTransform3D(&self) -> windows_core::Result<IDCompositionRotateTransform3D> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateRotateTransform3D)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn CreateMatrixTransform3D(&self) -> windows_core::Result<IDCompositionMatrixTransform3D> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateMatrixTransform3D)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn CreateTransform3DGroup(&self, transforms3d: &[Option<IDCompositionTransform3D>]) -> windows_core::Result<IDCompositionTransform3D> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateTransform3DGroup)(windows_core::Interface::as_raw(self), core::mem::transmute(transforms3d.as_ptr()), transforms3d.len().try_into().unwrap(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn CreateEffectGroup(&self) -> windows_core::Result<IDCompositionEffectGroup> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateEffectGroup)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn CreateRectangleClip(&self) -> windows_core::Result<IDCompositionRectangleClip> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateRectangleClip)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn CreateAnimation(&self) -> windows_core::Result<IDCompositionAnimation> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateAnimation)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IDCompositionDevice2_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    pub Commit: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub WaitForCommitCompletion: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
-    #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-    pub GetFrameStatistics: unsafe extern "system" fn(*mut core::ffi::c_void, *mut DCOMPOSITION_FRAME_STATISTICS) -> windows_core::HRESULT,
-    #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
-    GetFrameStatistics: usize,
-    pub CreateVisual: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub CreateSurfaceFactory: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-    pub CreateSurface: unsafe extern "system" fn(*mut core::ffi::c_void, u32, u32, super::Dxgi::Common::DXGI_FORMAT, super::Dxgi::Common::DXGI_ALPHA_MODE, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
-    CreateSurface: usize,
-    #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-    pub CreateVirtualSurface: unsafe extern "system" fn(*mut core::ffi::c_void, u32, u32, super::Dxgi::Common::DXGI_FORMAT, super::Dxgi::Common::DXGI_ALPHA_MODE, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
-    CreateVirtualSurface: usize,
-    pub CreateTranslateTransform: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub CreateScaleTransform: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub CreateRotateTransform: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub CreateSkewTransform: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub CreateMatrixTransform: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub CreateTransformGroup: unsafe extern "system" fn(*mut core::ffi::c_void, *const *mut core::ffi::c_void, u32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub CreateTranslateTransform3D: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub CreateScaleTransform3D: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub CreateRotateTransform3D: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub CreateMatrixTransform3D: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub CreateTransform3DGroup: unsafe extern "system" fn(*mut core::ffi::c_void, *const *mut core::ffi::c_void, u32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub CreateEffectGroup: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub CreateRectangleClip: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub CreateAnimation: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-pub trait IDCompositionDevice2_Impl: windows_core::IUnknownImpl {
-    fn Commit(&self) -> windows_core::Result<()>;
-    fn WaitForCommitCompletion(&self) -> windows_core::Result<()>;
-    fn GetFrameStatistics(&self) -> windows_core::Result<DCOMPOSITION_FRAME_STATISTICS>;
-    fn CreateVisual(&self) -> windows_core::Result<IDCompositionVisual2>;
-    fn CreateSurfaceFactory(&self, renderingdevice: windows_core::Ref<windows_core::IUnknown>) -> windows_core::Result<IDCompositionSurfaceFactory>;
-    fn CreateSurface(&self, width: u32, height: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE) -> windows_core::Result<IDCompositionSurface>;
-    fn CreateVirtualSurface(&self, initialwidth: u32, initialheight: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE) -> windows_core::Result<IDCompositionVirtualSurface>;
-    fn CreateTranslateTransform(&self) -> windows_core::Result<IDCompositionTranslateTransform>;
-    fn CreateScaleTransform(&self) -> windows_core::Result<IDCompositionScaleTransform>;
-    fn CreateRotateTransform(&self) -> windows_core::Result<IDCompositionRotateTransform>;
-    fn CreateSkewTransform(&self) -> windows_core::Result<IDCompositionSkewTransform>;
-    fn CreateMatrixTransform(&self) -> windows_core::Result<IDCompositionMatrixTransform>;
-    fn CreateTransformGroup(&self, transforms: *const Option<IDCompositionTransform>, elements: u32) -> windows_core::Result<IDCompositionTransform>;
-    fn CreateTranslateTransform3D(&self) -> windows_core::Result<IDCompositionTranslateTransform3D>;
-    fn CreateScaleTransform3D(&self) -> windows_core::Result<IDCompositionScaleTransform3D>;
-    fn CreateRotateTransform3D(&self) -> windows_core::Result<IDCompositionRotateTransform3D>;
-    fn CreateMatrixTransform3D(&self) -> windows_core::Result<IDCompositionMatrixTransform3D>;
-    fn CreateTransform3DGroup(&self, transforms3d: *const Option<IDCompositionTransform3D>, elements: u32) -> windows_core::Result<IDCompositionTransform3D>;
-    fn CreateEffectGroup(&self) -> windows_core::Result<IDCompositionEffectGroup>;
-    fn CreateRectangleClip(&self) -> windows_core::Result<IDCompositionRectangleClip>;
-    fn CreateAnimation(&self) -> windows_core::Result<IDCompositionAnimation>;
-}
-#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-impl IDCompositionDevice2_Vtbl {
-    pub const fn new<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn Commit<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionDevice_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Commit<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IDCompositionDevice2_Impl::Commit(this).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionDevice_Impl::Commit(this).into()
             }
         }
-        unsafe extern "system" fn WaitForCommitCompletion<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn WaitForCommitCompletion<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IDCompositionDevice2_Impl::WaitForCommitCompletion(this).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionDevice_Impl::WaitForCommitCompletion(this).into()
             }
         }
-        unsafe extern "system" fn GetFrameStatistics<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, statistics: *mut DCOMPOSITION_FRAME_STATISTICS) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetFrameStatistics<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void, statistics: *mut DCOMPOSITION_FRAME_STATISTICS) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IDCompositionDevice2_Impl::GetFrameStatistics(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice_Impl::GetFrameStatistics(this) {
                     Ok(ok__) => {
                         statistics.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
@@ -1861,22 +2128,796 @@ impl IDCompositionDevice2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateVisual<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, visual: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateTargetForHwnd<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, topmost: windows_core::BOOL, target: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IDCompositionDevice2_Impl::CreateVisual(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice_Impl::CreateTargetForHwnd(this, core::mem::transmute_copy(&hwnd), core::mem::transmute_copy(&topmost)) {
                     Ok(ok__) => {
-                        visual.write(core::mem::transmute(ok__));
+                        target.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn CreateSurfaceFactory<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, renderingdevice: *mut core::ffi::c_void, surfacefactory: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateVisual<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void, visual: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IDCompositionDevice2_Impl::CreateSurfaceFactory(this, core::mem::transmute_copy(&renderingdevice)) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice_Impl::CreateVisual(this) {
+                    Ok(ok__) => {
+                        visual.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSurface<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void, width: u32, height: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE, surface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice_Impl::CreateSurface(this, core::mem::transmute_copy(&width), core::mem::transmute_copy(&height), core::mem::transmute_copy(&pixelformat), core::mem::transmute_copy(&alphamode)) {
+                    Ok(ok__) => {
+                        surface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateVirtualSurface<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void, initialwidth: u32, initialheight: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE, virtualsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice_Impl::CreateVirtualSurface(this, core::mem::transmute_copy(&initialwidth), core::mem::transmute_copy(&initialheight), core::mem::transmute_copy(&pixelformat), core::mem::transmute_copy(&alphamode)) {
+                    Ok(ok__) => {
+                        virtualsurface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSurfaceFromHandle<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void, handle: super::super::Foundation::HANDLE, surface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice_Impl::CreateSurfaceFromHandle(this, core::mem::transmute_copy(&handle)) {
+                    Ok(ok__) => {
+                        surface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSurfaceFromHwnd<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, surface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice_Impl::CreateSurfaceFromHwnd(this, core::mem::transmute_copy(&hwnd)) {
+                    Ok(ok__) => {
+                        surface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateTranslateTransform<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void, translatetransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice_Impl::CreateTranslateTransform(this) {
+                    Ok(ok__) => {
+                        translatetransform.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateScaleTransform<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void, scaletransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice_Impl::CreateScaleTransform(this) {
+                    Ok(ok__) => {
+                        scaletransform.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateRotateTransform<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void, rotatetransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice_Impl::CreateRotateTransform(this) {
+                    Ok(ok__) => {
+                        rotatetransform.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSkewTransform<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void, skewtransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice_Impl::CreateSkewTransform(this) {
+                    Ok(ok__) => {
+                        skewtransform.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateMatrixTransform<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void, matrixtransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice_Impl::CreateMatrixTransform(this) {
+                    Ok(ok__) => {
+                        matrixtransform.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateTransformGroup<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void, transforms: *const *mut core::ffi::c_void, elements: u32, transformgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice_Impl::CreateTransformGroup(this, core::mem::transmute_copy(&transforms), core::mem::transmute_copy(&elements)) {
+                    Ok(ok__) => {
+                        transformgroup.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateTranslateTransform3D<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void, translatetransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice_Impl::CreateTranslateTransform3D(this) {
+                    Ok(ok__) => {
+                        translatetransform3d.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateScaleTransform3D<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void, scaletransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice_Impl::CreateScaleTransform3D(this) {
+                    Ok(ok__) => {
+                        scaletransform3d.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateRotateTransform3D<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void, rotatetransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice_Impl::CreateRotateTransform3D(this) {
+                    Ok(ok__) => {
+                        rotatetransform3d.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateMatrixTransform3D<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void, matrixtransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice_Impl::CreateMatrixTransform3D(this) {
+                    Ok(ok__) => {
+                        matrixtransform3d.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateTransform3DGroup<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void, transforms3d: *const *mut core::ffi::c_void, elements: u32, transform3dgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice_Impl::CreateTransform3DGroup(this, core::mem::transmute_copy(&transforms3d), core::mem::transmute_copy(&elements)) {
+                    Ok(ok__) => {
+                        transform3dgroup.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateEffectGroup<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void, effectgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice_Impl::CreateEffectGroup(this) {
+                    Ok(ok__) => {
+                        effectgroup.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateRectangleClip<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void, clip: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice_Impl::CreateRectangleClip(this) {
+                    Ok(ok__) => {
+                        clip.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateAnimation<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void, animation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice_Impl::CreateAnimation(this) {
+                    Ok(ok__) => {
+                        animation.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CheckDeviceState<Identity: windows_core::IUnknownImpl + IDCompositionDevice_Impl>(this: *mut core::ffi::c_void, pfvalid: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice_Impl::CheckDeviceState(this) {
+                    Ok(ok__) => {
+                        pfvalid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Commit: Commit::<Identity>,
+            WaitForCommitCompletion: WaitForCommitCompletion::<Identity>,
+            GetFrameStatistics: GetFrameStatistics::<Identity>,
+            CreateTargetForHwnd: CreateTargetForHwnd::<Identity>,
+            CreateVisual: CreateVisual::<Identity>,
+            CreateSurface: CreateSurface::<Identity>,
+            CreateVirtualSurface: CreateVirtualSurface::<Identity>,
+            CreateSurfaceFromHandle: CreateSurfaceFromHandle::<Identity>,
+            CreateSurfaceFromHwnd: CreateSurfaceFromHwnd::<Identity>,
+            CreateTranslateTransform: CreateTranslateTransform::<Identity>,
+            CreateScaleTransform: CreateScaleTransform::<Identity>,
+            CreateRotateTransform: CreateRotateTransform::<Identity>,
+            CreateSkewTransform: CreateSkewTransform::<Identity>,
+            CreateMatrixTransform: CreateMatrixTransform::<Identity>,
+            CreateTransformGroup: CreateTransformGroup::<Identity>,
+            CreateTranslateTransform3D: CreateTranslateTransform3D::<Identity>,
+            CreateScaleTransform3D: CreateScaleTransform3D::<Identity>,
+            CreateRotateTransform3D: CreateRotateTransform3D::<Identity>,
+            CreateMatrixTransform3D: CreateMatrixTransform3D::<Identity>,
+            CreateTransform3DGroup: CreateTransform3DGroup::<Identity>,
+            CreateEffectGroup: CreateEffectGroup::<Identity>,
+            CreateRectangleClip: CreateRectangleClip::<Identity>,
+            CreateAnimation: CreateAnimation::<Identity>,
+            CheckDeviceState: CheckDeviceState::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::RuntimeName for IDCompositionDevice {}
+windows_core::imp::define_interface!(IDCompositionDevice2, IDCompositionDevice2_Vtbl, 0x75f6468d_1b8e_447c_9bc6_75fea80b5b25);
+windows_core::imp::interface_hierarchy!(IDCompositionDevice2, windows_core::IUnknown);
+impl IDCompositionDevice2 {
+    pub unsafe fn Commit(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Commit)(windows_core::Interface::as_raw(self)).ok() }
+    }
+    pub unsafe fn WaitForCommitCompletion(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).WaitForCommitCompletion)(windows_core::Interface::as_raw(self)).ok() }
+    }
+    #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+    pub unsafe fn GetFrameStatistics(&self) -> windows_core::Result<DCOMPOSITION_FRAME_STATISTICS> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetFrameStatistics)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn CreateVisual(&self) -> windows_core::Result<IDCompositionVisual2> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateVisual)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn CreateSurfaceFactory<P0>(&self, renderingdevice: P0) -> windows_core::Result<IDCompositionSurfaceFactory>
+    where
+        P0: windows_core::Param<windows_core::IUnknown>,
+    {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateSurfaceFactory)(windows_core::Interface::as_raw(self), renderingdevice.param().abi(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+    pub unsafe fn CreateSurface(&self, width: u32, height: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE) -> windows_core::Result<IDCompositionSurface> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateSurface)(windows_core::Interface::as_raw(self), width, height, pixelformat, alphamode, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+    pub unsafe fn CreateVirtualSurface(&self, initialwidth: u32, initialheight: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE) -> windows_core::Result<IDCompositionVirtualSurface> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateVirtualSurface)(windows_core::Interface::as_raw(self), initialwidth, initialheight, pixelformat, alphamode, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn CreateTranslateTransform(&self) -> windows_core::Result<IDCompositionTranslateTransform> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateTranslateTransform)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn CreateScaleTransform(&self) -> windows_core::Result<IDCompositionScaleTransform> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateScaleTransform)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn CreateRotateTransform(&self) -> windows_core::Result<IDCompositionRotateTransform> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateRotateTransform)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn CreateSkewTransform(&self) -> windows_core::Result<IDCompositionSkewTransform> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateSkewTransform)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn CreateMatrixTransform(&self) -> windows_core::Result<IDCompositionMatrixTransform> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateMatrixTransform)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn CreateTransformGroup(&self, transforms: &[Option<IDCompositionTransform>]) -> windows_core::Result<IDCompositionTransform> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateTransformGroup)(windows_core::Interface::as_raw(self), core::mem::transmute(transforms.as_ptr()), transforms.len().try_into().unwrap(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn CreateTranslateTransform3D(&self) -> windows_core::Result<IDCompositionTranslateTransform3D> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateTranslateTransform3D)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn CreateScaleTransform3D(&self) -> windows_core::Result<IDCompositionScaleTransform3D> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateScaleTransform3D)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn CreateRotateTransform3D(&self) -> windows_core::Result<IDCompositionRotateTransform3D> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateRotateTransform3D)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn CreateMatrixTransform3D(&self) -> windows_core::Result<IDCompositionMatrixTransform3D> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateMatrixTransform3D)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn CreateTransform3DGroup(&self, transforms3d: &[Option<IDCompositionTransform3D>]) -> windows_core::Result<IDCompositionTransform3D> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateTransform3DGroup)(windows_core::Interface::as_raw(self), core::mem::transmute(transforms3d.as_ptr()), transforms3d.len().try_into().unwrap(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn CreateEffectGroup(&self) -> windows_core::Result<IDCompositionEffectGroup> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateEffectGroup)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn CreateRectangleClip(&self) -> windows_core::Result<IDCompositionRectangleClip> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateRectangleClip)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn CreateAnimation(&self) -> windows_core::Result<IDCompositionAnimation> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateAnimation)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IDCompositionDevice2_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub Commit: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub WaitForCommitCompletion: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+    #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+    pub GetFrameStatistics: unsafe extern "system" fn(*mut core::ffi::c_void, *mut DCOMPOSITION_FRAME_STATISTICS) -> windows_core::HRESULT,
+    #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+    GetFrameStatistics: usize,
+    pub CreateVisual: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub CreateSurfaceFactory: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+    pub CreateSurface: unsafe extern "system" fn(*mut core::ffi::c_void, u32, u32, super::Dxgi::Common::DXGI_FORMAT, super::Dxgi::Common::DXGI_ALPHA_MODE, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+    CreateSurface: usize,
+    #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+    pub CreateVirtualSurface: unsafe extern "system" fn(*mut core::ffi::c_void, u32, u32, super::Dxgi::Common::DXGI_FORMAT, super::Dxgi::Common::DXGI_ALPHA_MODE, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+    CreateVirtualSurface: usize,
+    pub CreateTranslateTransform: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub CreateScaleTransform: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub CreateRotateTransform: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub CreateSkewTransform: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub CreateMatrixTransform: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub CreateTransformGroup: unsafe extern "system" fn(*mut core::ffi::c_void, *const *mut core::ffi::c_void, u32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub CreateTranslateTransform3D: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub CreateScaleTransform3D: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub CreateRotateTransform3D: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub CreateMatrixTransform3D: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub CreateTransform3DGroup: unsafe extern "system" fn(*mut core::ffi::c_void, *const *mut core::ffi::c_void, u32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub CreateEffectGroup: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub CreateRectangleClip: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub CreateAnimation: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+pub trait IDCompositionDevice2_Impl: windows_core::IUnknownImpl {
+    fn Commit(&self) -> windows_core::Result<()>;
+    fn WaitForCommitCompletion(&self) -> windows_core::Result<()>;
+    fn GetFrameStatistics(&self) -> windows_core::Result<DCOMPOSITION_FRAME_STATISTICS>;
+    fn CreateVisual(&self) -> windows_core::Result<IDCompositionVisual2>;
+    fn CreateSurfaceFactory(&self, renderingdevice: windows_core::Ref<windows_core::IUnknown>) -> windows_core::Result<IDCompositionSurfaceFactory>;
+    fn CreateSurface(&self, width: u32, height: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE) -> windows_core::Result<IDCompositionSurface>;
+    fn CreateVirtualSurface(&self, initialwidth: u32, initialheight: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE) -> windows_core::Result<IDCompositionVirtualSurface>;
+    fn CreateTranslateTransform(&self) -> windows_core::Result<IDCompositionTranslateTransform>;
+    fn CreateScaleTransform(&self) -> windows_core::Result<IDCompositionScaleTransform>;
+    fn CreateRotateTransform(&self) -> windows_core::Result<IDCompositionRotateTransform>;
+    fn CreateSkewTransform(&self) -> windows_core::Result<IDCompositionSkewTransform>;
+    fn CreateMatrixTransform(&self) -> windows_core::Result<IDCompositionMatrixTransform>;
+    fn CreateTransformGroup(&self, transforms: *const Option<IDCompositionTransform>, elements: u32) -> windows_core::Result<IDCompositionTransform>;
+    fn CreateTranslateTransform3D(&self) -> windows_core::Result<IDCompositionTranslateTransform3D>;
+    fn CreateScaleTransform3D(&self) -> windows_core::Result<IDCompositionScaleTransform3D>;
+    fn CreateRotateTransform3D(&self) -> windows_core::Result<IDCompositionRotateTransform3D>;
+    fn CreateMatrixTransform3D(&self) -> windows_core::Result<IDCompositionMatrixTransform3D>;
+    fn CreateTransform3DGroup(&self, transforms3d: *const Option<IDCompositionTransform3D>, elements: u32) -> windows_core::Result<IDCompositionTransform3D>;
+    fn CreateEffectGroup(&self) -> windows_core::Result<IDCompositionEffectGroup>;
+    fn CreateRectangleClip(&self) -> windows_core::Result<IDCompositionRectangleClip>;
+    fn CreateAnimation(&self) -> windows_core::Result<IDCompositionAnimation>;
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl IDCompositionDevice2_Vtbl {
+    pub const fn new<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn Commit<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionDevice2_Impl::Commit(this).into()
+            }
+        }
+        unsafe extern "system" fn WaitForCommitCompletion<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionDevice2_Impl::WaitForCommitCompletion(this).into()
+            }
+        }
+        unsafe extern "system" fn GetFrameStatistics<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, statistics: *mut DCOMPOSITION_FRAME_STATISTICS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDCompositionDevice2_Impl::GetFrameStatistics(this) {
+                    Ok(ok__) => {
+                        statistics.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateVisual<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, visual: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDCompositionDevice2_Impl::CreateVisual(this) {
+                    Ok(ok__) => {
+                        visual.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSurfaceFactory<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, renderingdevice: *mut core::ffi::c_void, surfacefactory: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDCompositionDevice2_Impl::CreateSurfaceFactory(this, core::mem::transmute_copy(&renderingdevice)) {
+                    Ok(ok__) => {
+                        surfacefactory.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSurface<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, width: u32, height: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE, surface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDCompositionDevice2_Impl::CreateSurface(this, core::mem::transmute_copy(&width), core::mem::transmute_copy(&height), core::mem::transmute_copy(&pixelformat), core::mem::transmute_copy(&alphamode)) {
+                    Ok(ok__) => {
+                        surface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateVirtualSurface<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, initialwidth: u32, initialheight: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE, virtualsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDCompositionDevice2_Impl::CreateVirtualSurface(this, core::mem::transmute_copy(&initialwidth), core::mem::transmute_copy(&initialheight), core::mem::transmute_copy(&pixelformat), core::mem::transmute_copy(&alphamode)) {
+                    Ok(ok__) => {
+                        virtualsurface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateTranslateTransform<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, translatetransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDCompositionDevice2_Impl::CreateTranslateTransform(this) {
+                    Ok(ok__) => {
+                        translatetransform.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateScaleTransform<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, scaletransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDCompositionDevice2_Impl::CreateScaleTransform(this) {
+                    Ok(ok__) => {
+                        scaletransform.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateRotateTransform<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, rotatetransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDCompositionDevice2_Impl::CreateRotateTransform(this) {
+                    Ok(ok__) => {
+                        rotatetransform.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSkewTransform<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, skewtransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDCompositionDevice2_Impl::CreateSkewTransform(this) {
+                    Ok(ok__) => {
+                        skewtransform.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateMatrixTransform<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, matrixtransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDCompositionDevice2_Impl::CreateMatrixTransform(this) {
+                    Ok(ok__) => {
+                        matrixtransform.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateTransformGroup<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, transforms: *const *mut core::ffi::c_void, elements: u32, transformgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDCompositionDevice2_Impl::CreateTransformGroup(this, core::mem::transmute_copy(&transforms), core::mem::transmute_copy(&elements)) {
+                    Ok(ok__) => {
+                        transformgroup.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateTranslateTransform3D<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, translatetransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDCompositionDevice2_Impl::CreateTranslateTransform3D(this) {
+                    Ok(ok__) => {
+                        translatetransform3d.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateScaleTransform3D<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, scaletransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDCompositionDevice2_Impl::CreateScaleTransform3D(this) {
+                    Ok(ok__) => {
+                        scaletransform3d.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateRotateTransform3D<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, rotatetransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDCompositionDevice2_Impl::CreateRotateTransform3D(this) {
+                    Ok(ok__) => {
+                        rotatetransform3d.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateMatrixTransform3D<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, matrixtransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDCompositionDevice2_Impl::CreateMatrixTransform3D(this) {
+                    Ok(ok__) => {
+                        matrixtransform3d.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateTransform3DGroup<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, transforms3d: *const *mut core::ffi::c_void, elements: u32, transform3dgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDCompositionDevice2_Impl::CreateTransform3DGroup(this, core::mem::transmute_copy(&transforms3d), core::mem::transmute_copy(&elements)) {
+                    Ok(ok__) => {
+                        transform3dgroup.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateEffectGroup<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, effectgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDCompositionDevice2_Impl::CreateEffectGroup(this) {
+                    Ok(ok__) => {
+                        effectgroup.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateRectangleClip<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, clip: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDCompositionDevice2_Impl::CreateRectangleClip(this) {
+                    Ok(ok__) => {
+                        clip.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateAnimation<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDCompositionDevice2_Impl::CreateAnimation(this) {
+                    Ok(ok__) => {
+                        animation.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            Commit: Commit::<Identity, OFFSET>,
+            WaitForCommitCompletion: WaitForCommitCompletion::<Identity, OFFSET>,
+            GetFrameStatistics: GetFrameStatistics::<Identity, OFFSET>,
+            CreateVisual: CreateVisual::<Identity, OFFSET>,
+            CreateSurfaceFactory: CreateSurfaceFactory::<Identity, OFFSET>,
+            CreateSurface: CreateSurface::<Identity, OFFSET>,
+            CreateVirtualSurface: CreateVirtualSurface::<Identity, OFFSET>,
+            CreateTranslateTransform: CreateTranslateTransform::<Identity, OFFSET>,
+            CreateScaleTransform: CreateScaleTransform::<Identity, OFFSET>,
+            CreateRotateTransform: CreateRotateTransform::<Identity, OFFSET>,
+            CreateSkewTransform: CreateSkewTransform::<Identity, OFFSET>,
+            CreateMatrixTransform: CreateMatrixTransform::<Identity, OFFSET>,
+            CreateTransformGroup: CreateTransformGroup::<Identity, OFFSET>,
+            CreateTranslateTransform3D: CreateTranslateTransform3D::<Identity, OFFSET>,
+            CreateScaleTransform3D: CreateScaleTransform3D::<Identity, OFFSET>,
+            CreateRotateTransform3D: CreateRotateTransform3D::<Identity, OFFSET>,
+            CreateMatrixTransform3D: CreateMatrixTransform3D::<Identity, OFFSET>,
+            CreateTransform3DGroup: CreateTransform3DGroup::<Identity, OFFSET>,
+            CreateEffectGroup: CreateEffectGroup::<Identity, OFFSET>,
+            CreateRectangleClip: CreateRectangleClip::<Identity, OFFSET>,
+            CreateAnimation: CreateAnimation::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionDevice2 as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDCompositionDevice2_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionDevice2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Commit<Identity: windows_core::IUnknownImpl + IDCompositionDevice2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionDevice2_Impl::Commit(this).into()
+            }
+        }
+        unsafe extern "system" fn WaitForCommitCompletion<Identity: windows_core::IUnknownImpl + IDCompositionDevice2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionDevice2_Impl::WaitForCommitCompletion(this).into()
+            }
+        }
+        unsafe extern "system" fn GetFrameStatistics<Identity: windows_core::IUnknownImpl + IDCompositionDevice2_Impl>(this: *mut core::ffi::c_void, statistics: *mut DCOMPOSITION_FRAME_STATISTICS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice2_Impl::GetFrameStatistics(this) {
+                    Ok(ok__) => {
+                        statistics.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateVisual<Identity: windows_core::IUnknownImpl + IDCompositionDevice2_Impl>(this: *mut core::ffi::c_void, visual: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice2_Impl::CreateVisual(this) {
+                    Ok(ok__) => {
+                        visual.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSurfaceFactory<Identity: windows_core::IUnknownImpl + IDCompositionDevice2_Impl>(this: *mut core::ffi::c_void, renderingdevice: *mut core::ffi::c_void, surfacefactory: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice2_Impl::CreateSurfaceFactory(this, core::mem::transmute_copy(&renderingdevice)) {
                     Ok(ok__) => {
                         surfacefactory.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
@@ -1885,9 +2926,9 @@ impl IDCompositionDevice2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateSurface<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, width: u32, height: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE, surface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateSurface<Identity: windows_core::IUnknownImpl + IDCompositionDevice2_Impl>(this: *mut core::ffi::c_void, width: u32, height: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE, surface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDCompositionDevice2_Impl::CreateSurface(this, core::mem::transmute_copy(&width), core::mem::transmute_copy(&height), core::mem::transmute_copy(&pixelformat), core::mem::transmute_copy(&alphamode)) {
                     Ok(ok__) => {
                         surface.write(core::mem::transmute(ok__));
@@ -1897,9 +2938,9 @@ impl IDCompositionDevice2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateVirtualSurface<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, initialwidth: u32, initialheight: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE, virtualsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateVirtualSurface<Identity: windows_core::IUnknownImpl + IDCompositionDevice2_Impl>(this: *mut core::ffi::c_void, initialwidth: u32, initialheight: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE, virtualsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDCompositionDevice2_Impl::CreateVirtualSurface(this, core::mem::transmute_copy(&initialwidth), core::mem::transmute_copy(&initialheight), core::mem::transmute_copy(&pixelformat), core::mem::transmute_copy(&alphamode)) {
                     Ok(ok__) => {
                         virtualsurface.write(core::mem::transmute(ok__));
@@ -1909,9 +2950,9 @@ impl IDCompositionDevice2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateTranslateTransform<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, translatetransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateTranslateTransform<Identity: windows_core::IUnknownImpl + IDCompositionDevice2_Impl>(this: *mut core::ffi::c_void, translatetransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDCompositionDevice2_Impl::CreateTranslateTransform(this) {
                     Ok(ok__) => {
                         translatetransform.write(core::mem::transmute(ok__));
@@ -1921,9 +2962,9 @@ impl IDCompositionDevice2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateScaleTransform<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, scaletransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateScaleTransform<Identity: windows_core::IUnknownImpl + IDCompositionDevice2_Impl>(this: *mut core::ffi::c_void, scaletransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDCompositionDevice2_Impl::CreateScaleTransform(this) {
                     Ok(ok__) => {
                         scaletransform.write(core::mem::transmute(ok__));
@@ -1933,9 +2974,9 @@ impl IDCompositionDevice2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateRotateTransform<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, rotatetransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateRotateTransform<Identity: windows_core::IUnknownImpl + IDCompositionDevice2_Impl>(this: *mut core::ffi::c_void, rotatetransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDCompositionDevice2_Impl::CreateRotateTransform(this) {
                     Ok(ok__) => {
                         rotatetransform.write(core::mem::transmute(ok__));
@@ -1945,9 +2986,9 @@ impl IDCompositionDevice2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateSkewTransform<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, skewtransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateSkewTransform<Identity: windows_core::IUnknownImpl + IDCompositionDevice2_Impl>(this: *mut core::ffi::c_void, skewtransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDCompositionDevice2_Impl::CreateSkewTransform(this) {
                     Ok(ok__) => {
                         skewtransform.write(core::mem::transmute(ok__));
@@ -1957,9 +2998,9 @@ impl IDCompositionDevice2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateMatrixTransform<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, matrixtransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateMatrixTransform<Identity: windows_core::IUnknownImpl + IDCompositionDevice2_Impl>(this: *mut core::ffi::c_void, matrixtransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDCompositionDevice2_Impl::CreateMatrixTransform(this) {
                     Ok(ok__) => {
                         matrixtransform.write(core::mem::transmute(ok__));
@@ -1969,9 +3010,9 @@ impl IDCompositionDevice2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateTransformGroup<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, transforms: *const *mut core::ffi::c_void, elements: u32, transformgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateTransformGroup<Identity: windows_core::IUnknownImpl + IDCompositionDevice2_Impl>(this: *mut core::ffi::c_void, transforms: *const *mut core::ffi::c_void, elements: u32, transformgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDCompositionDevice2_Impl::CreateTransformGroup(this, core::mem::transmute_copy(&transforms), core::mem::transmute_copy(&elements)) {
                     Ok(ok__) => {
                         transformgroup.write(core::mem::transmute(ok__));
@@ -1981,9 +3022,9 @@ impl IDCompositionDevice2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateTranslateTransform3D<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, translatetransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateTranslateTransform3D<Identity: windows_core::IUnknownImpl + IDCompositionDevice2_Impl>(this: *mut core::ffi::c_void, translatetransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDCompositionDevice2_Impl::CreateTranslateTransform3D(this) {
                     Ok(ok__) => {
                         translatetransform3d.write(core::mem::transmute(ok__));
@@ -1993,9 +3034,9 @@ impl IDCompositionDevice2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateScaleTransform3D<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, scaletransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateScaleTransform3D<Identity: windows_core::IUnknownImpl + IDCompositionDevice2_Impl>(this: *mut core::ffi::c_void, scaletransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDCompositionDevice2_Impl::CreateScaleTransform3D(this) {
                     Ok(ok__) => {
                         scaletransform3d.write(core::mem::transmute(ok__));
@@ -2005,9 +3046,9 @@ impl IDCompositionDevice2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateRotateTransform3D<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, rotatetransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateRotateTransform3D<Identity: windows_core::IUnknownImpl + IDCompositionDevice2_Impl>(this: *mut core::ffi::c_void, rotatetransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDCompositionDevice2_Impl::CreateRotateTransform3D(this) {
                     Ok(ok__) => {
                         rotatetransform3d.write(core::mem::transmute(ok__));
@@ -2017,9 +3058,9 @@ impl IDCompositionDevice2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateMatrixTransform3D<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, matrixtransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateMatrixTransform3D<Identity: windows_core::IUnknownImpl + IDCompositionDevice2_Impl>(this: *mut core::ffi::c_void, matrixtransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDCompositionDevice2_Impl::CreateMatrixTransform3D(this) {
                     Ok(ok__) => {
                         matrixtransform3d.write(core::mem::transmute(ok__));
@@ -2029,9 +3070,9 @@ impl IDCompositionDevice2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateTransform3DGroup<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, transforms3d: *const *mut core::ffi::c_void, elements: u32, transform3dgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateTransform3DGroup<Identity: windows_core::IUnknownImpl + IDCompositionDevice2_Impl>(this: *mut core::ffi::c_void, transforms3d: *const *mut core::ffi::c_void, elements: u32, transform3dgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDCompositionDevice2_Impl::CreateTransform3DGroup(this, core::mem::transmute_copy(&transforms3d), core::mem::transmute_copy(&elements)) {
                     Ok(ok__) => {
                         transform3dgroup.write(core::mem::transmute(ok__));
@@ -2041,9 +3082,9 @@ impl IDCompositionDevice2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateEffectGroup<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, effectgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateEffectGroup<Identity: windows_core::IUnknownImpl + IDCompositionDevice2_Impl>(this: *mut core::ffi::c_void, effectgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDCompositionDevice2_Impl::CreateEffectGroup(this) {
                     Ok(ok__) => {
                         effectgroup.write(core::mem::transmute(ok__));
@@ -2053,9 +3094,9 @@ impl IDCompositionDevice2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateRectangleClip<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, clip: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateRectangleClip<Identity: windows_core::IUnknownImpl + IDCompositionDevice2_Impl>(this: *mut core::ffi::c_void, clip: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDCompositionDevice2_Impl::CreateRectangleClip(this) {
                     Ok(ok__) => {
                         clip.write(core::mem::transmute(ok__));
@@ -2065,9 +3106,9 @@ impl IDCompositionDevice2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateAnimation<Identity: IDCompositionDevice2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateAnimation<Identity: windows_core::IUnknownImpl + IDCompositionDevice2_Impl>(this: *mut core::ffi::c_void, animation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDCompositionDevice2_Impl::CreateAnimation(this) {
                     Ok(ok__) => {
                         animation.write(core::mem::transmute(ok__));
@@ -2078,33 +3119,30 @@ impl IDCompositionDevice2_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            Commit: Commit::<Identity, OFFSET>,
-            WaitForCommitCompletion: WaitForCommitCompletion::<Identity, OFFSET>,
-            GetFrameStatistics: GetFrameStatistics::<Identity, OFFSET>,
-            CreateVisual: CreateVisual::<Identity, OFFSET>,
-            CreateSurfaceFactory: CreateSurfaceFactory::<Identity, OFFSET>,
-            CreateSurface: CreateSurface::<Identity, OFFSET>,
-            CreateVirtualSurface: CreateVirtualSurface::<Identity, OFFSET>,
-            CreateTranslateTransform: CreateTranslateTransform::<Identity, OFFSET>,
-            CreateScaleTransform: CreateScaleTransform::<Identity, OFFSET>,
-            CreateRotateTransform: CreateRotateTransform::<Identity, OFFSET>,
-            CreateSkewTransform: CreateSkewTransform::<Identity, OFFSET>,
-            CreateMatrixTransform: CreateMatrixTransform::<Identity, OFFSET>,
-            CreateTransformGroup: CreateTransformGroup::<Identity, OFFSET>,
-            CreateTranslateTransform3D: CreateTranslateTransform3D::<Identity, OFFSET>,
-            CreateScaleTransform3D: CreateScaleTransform3D::<Identity, OFFSET>,
-            CreateRotateTransform3D: CreateRotateTransform3D::<Identity, OFFSET>,
-            CreateMatrixTransform3D: CreateMatrixTransform3D::<Identity, OFFSET>,
-            CreateTransform3DGroup: CreateTransform3DGroup::<Identity, OFFSET>,
-            CreateEffectGroup: CreateEffectGroup::<Identity, OFFSET>,
-            CreateRectangleClip: CreateRectangleClip::<Identity, OFFSET>,
-            CreateAnimation: CreateAnimation::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDCompositionDevice2 as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Commit: Commit::<Identity>,
+            WaitForCommitCompletion: WaitForCommitCompletion::<Identity>,
+            GetFrameStatistics: GetFrameStatistics::<Identity>,
+            CreateVisual: CreateVisual::<Identity>,
+            CreateSurfaceFactory: CreateSurfaceFactory::<Identity>,
+            CreateSurface: CreateSurface::<Identity>,
+            CreateVirtualSurface: CreateVirtualSurface::<Identity>,
+            CreateTranslateTransform: CreateTranslateTransform::<Identity>,
+            CreateScaleTransform: CreateScaleTransform::<Identity>,
+            CreateRotateTransform: CreateRotateTransform::<Identity>,
+            CreateSkewTransform: CreateSkewTransform::<Identity>,
+            CreateMatrixTransform: CreateMatrixTransform::<Identity>,
+            CreateTransformGroup: CreateTransformGroup::<Identity>,
+            CreateTranslateTransform3D: CreateTranslateTransform3D::<Identity>,
+            CreateScaleTransform3D: CreateScaleTransform3D::<Identity>,
+            CreateRotateTransform3D: CreateRotateTransform3D::<Identity>,
+            CreateMatrixTransform3D: CreateMatrixTransform3D::<Identity>,
+            CreateTransform3DGroup: CreateTransform3DGroup::<Identity>,
+            CreateEffectGroup: CreateEffectGroup::<Identity>,
+            CreateRectangleClip: CreateRectangleClip::<Identity>,
+            CreateAnimation: CreateAnimation::<Identity>,
+        }
+    };
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IDCompositionDevice2 {}
@@ -2411,6 +3449,186 @@ impl IDCompositionDevice3_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDCompositionDevice3_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionDevice3_Vtbl
+where
+    IDCompositionDevice2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateGaussianBlurEffect<Identity: windows_core::IUnknownImpl + IDCompositionDevice3_Impl>(this: *mut core::ffi::c_void, gaussianblureffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice3_Impl::CreateGaussianBlurEffect(this) {
+                    Ok(ok__) => {
+                        gaussianblureffect.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateBrightnessEffect<Identity: windows_core::IUnknownImpl + IDCompositionDevice3_Impl>(this: *mut core::ffi::c_void, brightnesseffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice3_Impl::CreateBrightnessEffect(this) {
+                    Ok(ok__) => {
+                        brightnesseffect.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateColorMatrixEffect<Identity: windows_core::IUnknownImpl + IDCompositionDevice3_Impl>(this: *mut core::ffi::c_void, colormatrixeffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice3_Impl::CreateColorMatrixEffect(this) {
+                    Ok(ok__) => {
+                        colormatrixeffect.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateShadowEffect<Identity: windows_core::IUnknownImpl + IDCompositionDevice3_Impl>(this: *mut core::ffi::c_void, shadoweffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice3_Impl::CreateShadowEffect(this) {
+                    Ok(ok__) => {
+                        shadoweffect.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateHueRotationEffect<Identity: windows_core::IUnknownImpl + IDCompositionDevice3_Impl>(this: *mut core::ffi::c_void, huerotationeffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice3_Impl::CreateHueRotationEffect(this) {
+                    Ok(ok__) => {
+                        huerotationeffect.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSaturationEffect<Identity: windows_core::IUnknownImpl + IDCompositionDevice3_Impl>(this: *mut core::ffi::c_void, saturationeffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice3_Impl::CreateSaturationEffect(this) {
+                    Ok(ok__) => {
+                        saturationeffect.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateTurbulenceEffect<Identity: windows_core::IUnknownImpl + IDCompositionDevice3_Impl>(this: *mut core::ffi::c_void, turbulenceeffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice3_Impl::CreateTurbulenceEffect(this) {
+                    Ok(ok__) => {
+                        turbulenceeffect.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateLinearTransferEffect<Identity: windows_core::IUnknownImpl + IDCompositionDevice3_Impl>(this: *mut core::ffi::c_void, lineartransfereffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice3_Impl::CreateLinearTransferEffect(this) {
+                    Ok(ok__) => {
+                        lineartransfereffect.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateTableTransferEffect<Identity: windows_core::IUnknownImpl + IDCompositionDevice3_Impl>(this: *mut core::ffi::c_void, tabletransfereffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice3_Impl::CreateTableTransferEffect(this) {
+                    Ok(ok__) => {
+                        tabletransfereffect.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateCompositeEffect<Identity: windows_core::IUnknownImpl + IDCompositionDevice3_Impl>(this: *mut core::ffi::c_void, compositeeffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice3_Impl::CreateCompositeEffect(this) {
+                    Ok(ok__) => {
+                        compositeeffect.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateBlendEffect<Identity: windows_core::IUnknownImpl + IDCompositionDevice3_Impl>(this: *mut core::ffi::c_void, blendeffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice3_Impl::CreateBlendEffect(this) {
+                    Ok(ok__) => {
+                        blendeffect.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateArithmeticCompositeEffect<Identity: windows_core::IUnknownImpl + IDCompositionDevice3_Impl>(this: *mut core::ffi::c_void, arithmeticcompositeeffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice3_Impl::CreateArithmeticCompositeEffect(this) {
+                    Ok(ok__) => {
+                        arithmeticcompositeeffect.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateAffineTransform2DEffect<Identity: windows_core::IUnknownImpl + IDCompositionDevice3_Impl>(this: *mut core::ffi::c_void, affinetransform2deffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionDevice3_Impl::CreateAffineTransform2DEffect(this) {
+                    Ok(ok__) => {
+                        affinetransform2deffect.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDCompositionDevice2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateGaussianBlurEffect: CreateGaussianBlurEffect::<Identity>,
+            CreateBrightnessEffect: CreateBrightnessEffect::<Identity>,
+            CreateColorMatrixEffect: CreateColorMatrixEffect::<Identity>,
+            CreateShadowEffect: CreateShadowEffect::<Identity>,
+            CreateHueRotationEffect: CreateHueRotationEffect::<Identity>,
+            CreateSaturationEffect: CreateSaturationEffect::<Identity>,
+            CreateTurbulenceEffect: CreateTurbulenceEffect::<Identity>,
+            CreateLinearTransferEffect: CreateLinearTransferEffect::<Identity>,
+            CreateTableTransferEffect: CreateTableTransferEffect::<Identity>,
+            CreateCompositeEffect: CreateCompositeEffect::<Identity>,
+            CreateBlendEffect: CreateBlendEffect::<Identity>,
+            CreateArithmeticCompositeEffect: CreateArithmeticCompositeEffect::<Identity>,
+            CreateAffineTransform2DEffect: CreateAffineTransform2DEffect::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IDCompositionDevice3 {}
 windows_core::imp::define_interface!(IDCompositionDevice4, IDCompositionDevice4_Vtbl, 0x85fc5cca_2da6_494c_86b6_4a775c049b8a);
 impl core::ops::Deref for IDCompositionDevice4 {
@@ -2453,11 +3671,51 @@ pub trait IDCompositionDevice4_Impl: IDCompositionDevice3_Impl {
     fn CreateCompositionTexture(&self, d3dtexture: windows_core::Ref<windows_core::IUnknown>) -> windows_core::Result<IDCompositionTexture>;
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-impl IDCompositionDevice4_Vtbl {
-    pub const fn new<Identity: IDCompositionDevice4_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn CheckCompositionTextureSupport<Identity: IDCompositionDevice4_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, renderingdevice: *mut core::ffi::c_void, supportscompositiontextures: *mut windows_core::BOOL) -> windows_core::HRESULT {
+impl IDCompositionDevice4_Vtbl {
+    pub const fn new<Identity: IDCompositionDevice4_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn CheckCompositionTextureSupport<Identity: IDCompositionDevice4_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, renderingdevice: *mut core::ffi::c_void, supportscompositiontextures: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDCompositionDevice4_Impl::CheckCompositionTextureSupport(this, core::mem::transmute_copy(&renderingdevice)) {
+                    Ok(ok__) => {
+                        supportscompositiontextures.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateCompositionTexture<Identity: IDCompositionDevice4_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, d3dtexture: *mut core::ffi::c_void, compositiontexture: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDCompositionDevice4_Impl::CreateCompositionTexture(this, core::mem::transmute_copy(&d3dtexture)) {
+                    Ok(ok__) => {
+                        compositiontexture.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: IDCompositionDevice3_Vtbl::new::<Identity, OFFSET>(),
+            CheckCompositionTextureSupport: CheckCompositionTextureSupport::<Identity, OFFSET>,
+            CreateCompositionTexture: CreateCompositionTexture::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionDevice4 as windows_core::Interface>::IID || iid == &<IDCompositionDevice2 as windows_core::Interface>::IID || iid == &<IDCompositionDevice3 as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDCompositionDevice4_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionDevice4_Vtbl
+where
+    IDCompositionDevice3_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CheckCompositionTextureSupport<Identity: windows_core::IUnknownImpl + IDCompositionDevice4_Impl>(this: *mut core::ffi::c_void, renderingdevice: *mut core::ffi::c_void, supportscompositiontextures: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDCompositionDevice4_Impl::CheckCompositionTextureSupport(this, core::mem::transmute_copy(&renderingdevice)) {
                     Ok(ok__) => {
                         supportscompositiontextures.write(core::mem::transmute(ok__));
@@ -2467,9 +3725,9 @@ impl IDCompositionDevice4_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateCompositionTexture<Identity: IDCompositionDevice4_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, d3dtexture: *mut core::ffi::c_void, compositiontexture: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateCompositionTexture<Identity: windows_core::IUnknownImpl + IDCompositionDevice4_Impl>(this: *mut core::ffi::c_void, d3dtexture: *mut core::ffi::c_void, compositiontexture: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDCompositionDevice4_Impl::CreateCompositionTexture(this, core::mem::transmute_copy(&d3dtexture)) {
                     Ok(ok__) => {
                         compositiontexture.write(core::mem::transmute(ok__));
@@ -2480,14 +3738,11 @@ impl IDCompositionDevice4_Vtbl {
             }
         }
         Self {
-            base__: IDCompositionDevice3_Vtbl::new::<Identity, OFFSET>(),
-            CheckCompositionTextureSupport: CheckCompositionTextureSupport::<Identity, OFFSET>,
-            CreateCompositionTexture: CreateCompositionTexture::<Identity, OFFSET>,
+            base__: <IDCompositionDevice3_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CheckCompositionTextureSupport: CheckCompositionTextureSupport::<Identity>,
+            CreateCompositionTexture: CreateCompositionTexture::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDCompositionDevice4 as windows_core::Interface>::IID || iid == &<IDCompositionDevice2 as windows_core::Interface>::IID || iid == &<IDCompositionDevice3 as windows_core::Interface>::IID
-    }
+    };
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IDCompositionDevice4 {}
@@ -2536,6 +3791,30 @@ impl IDCompositionDeviceDebug_Vtbl {
         iid == &<IDCompositionDeviceDebug as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionDeviceDebug_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionDeviceDebug_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnableDebugCounters<Identity: windows_core::IUnknownImpl + IDCompositionDeviceDebug_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionDeviceDebug_Impl::EnableDebugCounters(this).into()
+            }
+        }
+        unsafe extern "system" fn DisableDebugCounters<Identity: windows_core::IUnknownImpl + IDCompositionDeviceDebug_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionDeviceDebug_Impl::DisableDebugCounters(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnableDebugCounters: EnableDebugCounters::<Identity>,
+            DisableDebugCounters: DisableDebugCounters::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDCompositionDeviceDebug {}
 windows_core::imp::define_interface!(IDCompositionEffect, IDCompositionEffect_Vtbl, 0xec81b08f_bfcb_4e8d_b193_a915587999e8);
 windows_core::imp::interface_hierarchy!(IDCompositionEffect, windows_core::IUnknown);
@@ -2553,6 +3832,12 @@ impl IDCompositionEffect_Vtbl {
         iid == &<IDCompositionEffect as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionEffect_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionEffect_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IDCompositionEffect {}
 windows_core::imp::define_interface!(IDCompositionEffectGroup, IDCompositionEffectGroup_Vtbl, 0xa7929a74_e6b2_4bd6_8b95_4040119ca34d);
 impl core::ops::Deref for IDCompositionEffectGroup {
@@ -2623,6 +3908,37 @@ impl IDCompositionEffectGroup_Vtbl {
         iid == &<IDCompositionEffectGroup as windows_core::Interface>::IID || iid == &<IDCompositionEffect as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionEffectGroup_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionEffectGroup_Vtbl
+where
+    IDCompositionEffect_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetOpacity<Identity: windows_core::IUnknownImpl + IDCompositionEffectGroup_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionEffectGroup_Impl::SetOpacity(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetOpacity2<Identity: windows_core::IUnknownImpl + IDCompositionEffectGroup_Impl>(this: *mut core::ffi::c_void, opacity: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionEffectGroup_Impl::SetOpacity2(this, core::mem::transmute_copy(&opacity)).into()
+            }
+        }
+        unsafe extern "system" fn SetTransform3D<Identity: windows_core::IUnknownImpl + IDCompositionEffectGroup_Impl>(this: *mut core::ffi::c_void, transform3d: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionEffectGroup_Impl::SetTransform3D(this, core::mem::transmute_copy(&transform3d)).into()
+            }
+        }
+        Self {
+            base__: <IDCompositionEffect_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetOpacity: SetOpacity::<Identity>,
+            SetOpacity2: SetOpacity2::<Identity>,
+            SetTransform3D: SetTransform3D::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDCompositionEffectGroup {}
 windows_core::imp::define_interface!(IDCompositionFilterEffect, IDCompositionFilterEffect_Vtbl, 0x30c421d5_8cb2_4e9f_b133_37be270d4ac2);
 impl core::ops::Deref for IDCompositionFilterEffect {
@@ -2663,6 +3979,20 @@ impl IDCompositionFilterEffect_Vtbl {
         iid == &<IDCompositionFilterEffect as windows_core::Interface>::IID || iid == &<IDCompositionEffect as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionFilterEffect_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionFilterEffect_Vtbl
+where
+    IDCompositionEffect_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetInput<Identity: windows_core::IUnknownImpl + IDCompositionFilterEffect_Impl>(this: *mut core::ffi::c_void, index: u32, input: *mut core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionFilterEffect_Impl::SetInput(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&input), core::mem::transmute_copy(&flags)).into()
+            }
+        }
+        Self { base__: <IDCompositionEffect_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetInput: SetInput::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDCompositionFilterEffect {}
 windows_core::imp::define_interface!(IDCompositionGaussianBlurEffect, IDCompositionGaussianBlurEffect_Vtbl, 0x45d4d0b7_1bd4_454e_8894_2bfa68443033);
 impl core::ops::Deref for IDCompositionGaussianBlurEffect {
@@ -2737,6 +4067,38 @@ impl IDCompositionGaussianBlurEffect_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDCompositionGaussianBlurEffect_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionGaussianBlurEffect_Vtbl
+where
+    IDCompositionFilterEffect_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetStandardDeviation<Identity: windows_core::IUnknownImpl + IDCompositionGaussianBlurEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionGaussianBlurEffect_Impl::SetStandardDeviation(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetStandardDeviation2<Identity: windows_core::IUnknownImpl + IDCompositionGaussianBlurEffect_Impl>(this: *mut core::ffi::c_void, amount: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionGaussianBlurEffect_Impl::SetStandardDeviation2(this, core::mem::transmute_copy(&amount)).into()
+            }
+        }
+        unsafe extern "system" fn SetBorderMode<Identity: windows_core::IUnknownImpl + IDCompositionGaussianBlurEffect_Impl>(this: *mut core::ffi::c_void, mode: super::Direct2D::Common::D2D1_BORDER_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionGaussianBlurEffect_Impl::SetBorderMode(this, core::mem::transmute_copy(&mode)).into()
+            }
+        }
+        Self {
+            base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetStandardDeviation: SetStandardDeviation::<Identity>,
+            SetStandardDeviation2: SetStandardDeviation2::<Identity>,
+            SetBorderMode: SetBorderMode::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
 impl windows_core::RuntimeName for IDCompositionGaussianBlurEffect {}
 windows_core::imp::define_interface!(IDCompositionHueRotationEffect, IDCompositionHueRotationEffect_Vtbl, 0x6db9f920_0770_4781_b0c6_381912f9d167);
 impl core::ops::Deref for IDCompositionHueRotationEffect {
@@ -2792,6 +4154,30 @@ impl IDCompositionHueRotationEffect_Vtbl {
         iid == &<IDCompositionHueRotationEffect as windows_core::Interface>::IID || iid == &<IDCompositionEffect as windows_core::Interface>::IID || iid == &<IDCompositionFilterEffect as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionHueRotationEffect_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionHueRotationEffect_Vtbl
+where
+    IDCompositionFilterEffect_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAngle<Identity: windows_core::IUnknownImpl + IDCompositionHueRotationEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionHueRotationEffect_Impl::SetAngle(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetAngle2<Identity: windows_core::IUnknownImpl + IDCompositionHueRotationEffect_Impl>(this: *mut core::ffi::c_void, amountdegrees: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionHueRotationEffect_Impl::SetAngle2(this, core::mem::transmute_copy(&amountdegrees)).into()
+            }
+        }
+        Self {
+            base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAngle: SetAngle::<Identity>,
+            SetAngle2: SetAngle2::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDCompositionHueRotationEffect {}
 windows_core::imp::define_interface!(IDCompositionInkTrailDevice, IDCompositionInkTrailDevice_Vtbl, 0xdf0c7cec_cdeb_4d4a_b91c_721bf22f4e6c);
 windows_core::imp::interface_hierarchy!(IDCompositionInkTrailDevice, windows_core::IUnknown);
@@ -2859,6 +4245,42 @@ impl IDCompositionInkTrailDevice_Vtbl {
         iid == &<IDCompositionInkTrailDevice as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionInkTrailDevice_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionInkTrailDevice_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateDelegatedInkTrail<Identity: windows_core::IUnknownImpl + IDCompositionInkTrailDevice_Impl>(this: *mut core::ffi::c_void, inktrail: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionInkTrailDevice_Impl::CreateDelegatedInkTrail(this) {
+                    Ok(ok__) => {
+                        inktrail.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateDelegatedInkTrailForSwapChain<Identity: windows_core::IUnknownImpl + IDCompositionInkTrailDevice_Impl>(this: *mut core::ffi::c_void, swapchain: *mut core::ffi::c_void, inktrail: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionInkTrailDevice_Impl::CreateDelegatedInkTrailForSwapChain(this, core::mem::transmute_copy(&swapchain)) {
+                    Ok(ok__) => {
+                        inktrail.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateDelegatedInkTrail: CreateDelegatedInkTrail::<Identity>,
+            CreateDelegatedInkTrailForSwapChain: CreateDelegatedInkTrailForSwapChain::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDCompositionInkTrailDevice {}
 windows_core::imp::define_interface!(IDCompositionLinearTransferEffect, IDCompositionLinearTransferEffect_Vtbl, 0x4305ee5b_c4a0_4c88_9385_67124e017683);
 impl core::ops::Deref for IDCompositionLinearTransferEffect {
@@ -3056,112 +4478,269 @@ impl IDCompositionLinearTransferEffect_Vtbl {
                 IDCompositionLinearTransferEffect_Impl::SetGreenSlope(this, core::mem::transmute_copy(&animation)).into()
             }
         }
-        unsafe extern "system" fn SetGreenSlope2<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, greenslope: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetGreenSlope2<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, greenslope: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionLinearTransferEffect_Impl::SetGreenSlope2(this, core::mem::transmute_copy(&greenslope)).into()
+            }
+        }
+        unsafe extern "system" fn SetGreenDisable<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, greendisable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionLinearTransferEffect_Impl::SetGreenDisable(this, core::mem::transmute_copy(&greendisable)).into()
+            }
+        }
+        unsafe extern "system" fn SetBlueYIntercept<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionLinearTransferEffect_Impl::SetBlueYIntercept(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetBlueYIntercept2<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, blueyintercept: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionLinearTransferEffect_Impl::SetBlueYIntercept2(this, core::mem::transmute_copy(&blueyintercept)).into()
+            }
+        }
+        unsafe extern "system" fn SetBlueSlope<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionLinearTransferEffect_Impl::SetBlueSlope(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetBlueSlope2<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, blueslope: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionLinearTransferEffect_Impl::SetBlueSlope2(this, core::mem::transmute_copy(&blueslope)).into()
+            }
+        }
+        unsafe extern "system" fn SetBlueDisable<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bluedisable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionLinearTransferEffect_Impl::SetBlueDisable(this, core::mem::transmute_copy(&bluedisable)).into()
+            }
+        }
+        unsafe extern "system" fn SetAlphaYIntercept<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionLinearTransferEffect_Impl::SetAlphaYIntercept(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetAlphaYIntercept2<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, alphayintercept: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionLinearTransferEffect_Impl::SetAlphaYIntercept2(this, core::mem::transmute_copy(&alphayintercept)).into()
+            }
+        }
+        unsafe extern "system" fn SetAlphaSlope<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionLinearTransferEffect_Impl::SetAlphaSlope(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetAlphaSlope2<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, alphaslope: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionLinearTransferEffect_Impl::SetAlphaSlope2(this, core::mem::transmute_copy(&alphaslope)).into()
+            }
+        }
+        unsafe extern "system" fn SetAlphaDisable<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, alphadisable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionLinearTransferEffect_Impl::SetAlphaDisable(this, core::mem::transmute_copy(&alphadisable)).into()
+            }
+        }
+        unsafe extern "system" fn SetClampOutput<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, clampoutput: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionLinearTransferEffect_Impl::SetClampOutput(this, core::mem::transmute_copy(&clampoutput)).into()
+            }
+        }
+        Self {
+            base__: IDCompositionFilterEffect_Vtbl::new::<Identity, OFFSET>(),
+            SetRedYIntercept: SetRedYIntercept::<Identity, OFFSET>,
+            SetRedYIntercept2: SetRedYIntercept2::<Identity, OFFSET>,
+            SetRedSlope: SetRedSlope::<Identity, OFFSET>,
+            SetRedSlope2: SetRedSlope2::<Identity, OFFSET>,
+            SetRedDisable: SetRedDisable::<Identity, OFFSET>,
+            SetGreenYIntercept: SetGreenYIntercept::<Identity, OFFSET>,
+            SetGreenYIntercept2: SetGreenYIntercept2::<Identity, OFFSET>,
+            SetGreenSlope: SetGreenSlope::<Identity, OFFSET>,
+            SetGreenSlope2: SetGreenSlope2::<Identity, OFFSET>,
+            SetGreenDisable: SetGreenDisable::<Identity, OFFSET>,
+            SetBlueYIntercept: SetBlueYIntercept::<Identity, OFFSET>,
+            SetBlueYIntercept2: SetBlueYIntercept2::<Identity, OFFSET>,
+            SetBlueSlope: SetBlueSlope::<Identity, OFFSET>,
+            SetBlueSlope2: SetBlueSlope2::<Identity, OFFSET>,
+            SetBlueDisable: SetBlueDisable::<Identity, OFFSET>,
+            SetAlphaYIntercept: SetAlphaYIntercept::<Identity, OFFSET>,
+            SetAlphaYIntercept2: SetAlphaYIntercept2::<Identity, OFFSET>,
+            SetAlphaSlope: SetAlphaSlope::<Identity, OFFSET>,
+            SetAlphaSlope2: SetAlphaSlope2::<Identity, OFFSET>,
+            SetAlphaDisable: SetAlphaDisable::<Identity, OFFSET>,
+            SetClampOutput: SetClampOutput::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionLinearTransferEffect as windows_core::Interface>::IID || iid == &<IDCompositionEffect as windows_core::Interface>::IID || iid == &<IDCompositionFilterEffect as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IDCompositionLinearTransferEffect_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionLinearTransferEffect_Vtbl
+where
+    IDCompositionFilterEffect_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetRedYIntercept<Identity: windows_core::IUnknownImpl + IDCompositionLinearTransferEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionLinearTransferEffect_Impl::SetRedYIntercept(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetRedYIntercept2<Identity: windows_core::IUnknownImpl + IDCompositionLinearTransferEffect_Impl>(this: *mut core::ffi::c_void, redyintercept: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionLinearTransferEffect_Impl::SetRedYIntercept2(this, core::mem::transmute_copy(&redyintercept)).into()
+            }
+        }
+        unsafe extern "system" fn SetRedSlope<Identity: windows_core::IUnknownImpl + IDCompositionLinearTransferEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionLinearTransferEffect_Impl::SetRedSlope(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetRedSlope2<Identity: windows_core::IUnknownImpl + IDCompositionLinearTransferEffect_Impl>(this: *mut core::ffi::c_void, redslope: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionLinearTransferEffect_Impl::SetRedSlope2(this, core::mem::transmute_copy(&redslope)).into()
+            }
+        }
+        unsafe extern "system" fn SetRedDisable<Identity: windows_core::IUnknownImpl + IDCompositionLinearTransferEffect_Impl>(this: *mut core::ffi::c_void, reddisable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionLinearTransferEffect_Impl::SetRedDisable(this, core::mem::transmute_copy(&reddisable)).into()
+            }
+        }
+        unsafe extern "system" fn SetGreenYIntercept<Identity: windows_core::IUnknownImpl + IDCompositionLinearTransferEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionLinearTransferEffect_Impl::SetGreenYIntercept(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetGreenYIntercept2<Identity: windows_core::IUnknownImpl + IDCompositionLinearTransferEffect_Impl>(this: *mut core::ffi::c_void, greenyintercept: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionLinearTransferEffect_Impl::SetGreenYIntercept2(this, core::mem::transmute_copy(&greenyintercept)).into()
+            }
+        }
+        unsafe extern "system" fn SetGreenSlope<Identity: windows_core::IUnknownImpl + IDCompositionLinearTransferEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionLinearTransferEffect_Impl::SetGreenSlope(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetGreenSlope2<Identity: windows_core::IUnknownImpl + IDCompositionLinearTransferEffect_Impl>(this: *mut core::ffi::c_void, greenslope: f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionLinearTransferEffect_Impl::SetGreenSlope2(this, core::mem::transmute_copy(&greenslope)).into()
             }
         }
-        unsafe extern "system" fn SetGreenDisable<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, greendisable: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetGreenDisable<Identity: windows_core::IUnknownImpl + IDCompositionLinearTransferEffect_Impl>(this: *mut core::ffi::c_void, greendisable: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionLinearTransferEffect_Impl::SetGreenDisable(this, core::mem::transmute_copy(&greendisable)).into()
             }
         }
-        unsafe extern "system" fn SetBlueYIntercept<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBlueYIntercept<Identity: windows_core::IUnknownImpl + IDCompositionLinearTransferEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionLinearTransferEffect_Impl::SetBlueYIntercept(this, core::mem::transmute_copy(&animation)).into()
             }
         }
-        unsafe extern "system" fn SetBlueYIntercept2<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, blueyintercept: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBlueYIntercept2<Identity: windows_core::IUnknownImpl + IDCompositionLinearTransferEffect_Impl>(this: *mut core::ffi::c_void, blueyintercept: f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionLinearTransferEffect_Impl::SetBlueYIntercept2(this, core::mem::transmute_copy(&blueyintercept)).into()
             }
         }
-        unsafe extern "system" fn SetBlueSlope<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBlueSlope<Identity: windows_core::IUnknownImpl + IDCompositionLinearTransferEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionLinearTransferEffect_Impl::SetBlueSlope(this, core::mem::transmute_copy(&animation)).into()
             }
         }
-        unsafe extern "system" fn SetBlueSlope2<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, blueslope: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBlueSlope2<Identity: windows_core::IUnknownImpl + IDCompositionLinearTransferEffect_Impl>(this: *mut core::ffi::c_void, blueslope: f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionLinearTransferEffect_Impl::SetBlueSlope2(this, core::mem::transmute_copy(&blueslope)).into()
             }
         }
-        unsafe extern "system" fn SetBlueDisable<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bluedisable: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBlueDisable<Identity: windows_core::IUnknownImpl + IDCompositionLinearTransferEffect_Impl>(this: *mut core::ffi::c_void, bluedisable: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionLinearTransferEffect_Impl::SetBlueDisable(this, core::mem::transmute_copy(&bluedisable)).into()
             }
         }
-        unsafe extern "system" fn SetAlphaYIntercept<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetAlphaYIntercept<Identity: windows_core::IUnknownImpl + IDCompositionLinearTransferEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionLinearTransferEffect_Impl::SetAlphaYIntercept(this, core::mem::transmute_copy(&animation)).into()
             }
         }
-        unsafe extern "system" fn SetAlphaYIntercept2<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, alphayintercept: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetAlphaYIntercept2<Identity: windows_core::IUnknownImpl + IDCompositionLinearTransferEffect_Impl>(this: *mut core::ffi::c_void, alphayintercept: f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionLinearTransferEffect_Impl::SetAlphaYIntercept2(this, core::mem::transmute_copy(&alphayintercept)).into()
             }
         }
-        unsafe extern "system" fn SetAlphaSlope<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetAlphaSlope<Identity: windows_core::IUnknownImpl + IDCompositionLinearTransferEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionLinearTransferEffect_Impl::SetAlphaSlope(this, core::mem::transmute_copy(&animation)).into()
             }
         }
-        unsafe extern "system" fn SetAlphaSlope2<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, alphaslope: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetAlphaSlope2<Identity: windows_core::IUnknownImpl + IDCompositionLinearTransferEffect_Impl>(this: *mut core::ffi::c_void, alphaslope: f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionLinearTransferEffect_Impl::SetAlphaSlope2(this, core::mem::transmute_copy(&alphaslope)).into()
             }
         }
-        unsafe extern "system" fn SetAlphaDisable<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, alphadisable: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetAlphaDisable<Identity: windows_core::IUnknownImpl + IDCompositionLinearTransferEffect_Impl>(this: *mut core::ffi::c_void, alphadisable: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionLinearTransferEffect_Impl::SetAlphaDisable(this, core::mem::transmute_copy(&alphadisable)).into()
             }
         }
-        unsafe extern "system" fn SetClampOutput<Identity: IDCompositionLinearTransferEffect_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, clampoutput: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetClampOutput<Identity: windows_core::IUnknownImpl + IDCompositionLinearTransferEffect_Impl>(this: *mut core::ffi::c_void, clampoutput: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionLinearTransferEffect_Impl::SetClampOutput(this, core::mem::transmute_copy(&clampoutput)).into()
             }
         }
         Self {
-            base__: IDCompositionFilterEffect_Vtbl::new::<Identity, OFFSET>(),
-            SetRedYIntercept: SetRedYIntercept::<Identity, OFFSET>,
-            SetRedYIntercept2: SetRedYIntercept2::<Identity, OFFSET>,
-            SetRedSlope: SetRedSlope::<Identity, OFFSET>,
-            SetRedSlope2: SetRedSlope2::<Identity, OFFSET>,
-            SetRedDisable: SetRedDisable::<Identity, OFFSET>,
-            SetGreenYIntercept: SetGreenYIntercept::<Identity, OFFSET>,
-            SetGreenYIntercept2: SetGreenYIntercept2::<Identity, OFFSET>,
-            SetGreenSlope: SetGreenSlope::<Identity, OFFSET>,
-            SetGreenSlope2: SetGreenSlope2::<Identity, OFFSET>,
-            SetGreenDisable: SetGreenDisable::<Identity, OFFSET>,
-            SetBlueYIntercept: SetBlueYIntercept::<Identity, OFFSET>,
-            SetBlueYIntercept2: SetBlueYIntercept2::<Identity, OFFSET>,
-            SetBlueSlope: SetBlueSlope::<Identity, OFFSET>,
-            SetBlueSlope2: SetBlueSlope2::<Identity, OFFSET>,
-            SetBlueDisable: SetBlueDisable::<Identity, OFFSET>,
-            SetAlphaYIntercept: SetAlphaYIntercept::<Identity, OFFSET>,
-            SetAlphaYIntercept2: SetAlphaYIntercept2::<Identity, OFFSET>,
-            SetAlphaSlope: SetAlphaSlope::<Identity, OFFSET>,
-            SetAlphaSlope2: SetAlphaSlope2::<Identity, OFFSET>,
-            SetAlphaDisable: SetAlphaDisable::<Identity, OFFSET>,
-            SetClampOutput: SetClampOutput::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDCompositionLinearTransferEffect as windows_core::Interface>::IID || iid == &<IDCompositionEffect as windows_core::Interface>::IID || iid == &<IDCompositionFilterEffect as windows_core::Interface>::IID
-    }
+            base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetRedYIntercept: SetRedYIntercept::<Identity>,
+            SetRedYIntercept2: SetRedYIntercept2::<Identity>,
+            SetRedSlope: SetRedSlope::<Identity>,
+            SetRedSlope2: SetRedSlope2::<Identity>,
+            SetRedDisable: SetRedDisable::<Identity>,
+            SetGreenYIntercept: SetGreenYIntercept::<Identity>,
+            SetGreenYIntercept2: SetGreenYIntercept2::<Identity>,
+            SetGreenSlope: SetGreenSlope::<Identity>,
+            SetGreenSlope2: SetGreenSlope2::<Identity>,
+            SetGreenDisable: SetGreenDisable::<Identity>,
+            SetBlueYIntercept: SetBlueYIntercept::<Identity>,
+            SetBlueYIntercept2: SetBlueYIntercept2::<Identity>,
+            SetBlueSlope: SetBlueSlope::<Identity>,
+            SetBlueSlope2: SetBlueSlope2::<Identity>,
+            SetBlueDisable: SetBlueDisable::<Identity>,
+            SetAlphaYIntercept: SetAlphaYIntercept::<Identity>,
+            SetAlphaYIntercept2: SetAlphaYIntercept2::<Identity>,
+            SetAlphaSlope: SetAlphaSlope::<Identity>,
+            SetAlphaSlope2: SetAlphaSlope2::<Identity>,
+            SetAlphaDisable: SetAlphaDisable::<Identity>,
+            SetClampOutput: SetClampOutput::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IDCompositionLinearTransferEffect {}
 windows_core::imp::define_interface!(IDCompositionMatrixTransform, IDCompositionMatrixTransform_Vtbl, 0x16cdff07_c503_419c_83f2_0965c7af1fa6);
@@ -3230,6 +4809,37 @@ impl IDCompositionMatrixTransform_Vtbl {
         iid == &<IDCompositionMatrixTransform as windows_core::Interface>::IID || iid == &<IDCompositionEffect as windows_core::Interface>::IID || iid == &<IDCompositionTransform3D as windows_core::Interface>::IID || iid == &<IDCompositionTransform as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionMatrixTransform_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionMatrixTransform_Vtbl
+where
+    IDCompositionTransform_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetMatrix<Identity: windows_core::IUnknownImpl + IDCompositionMatrixTransform_Impl>(this: *mut core::ffi::c_void, matrix: *const windows_numerics::Matrix3x2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionMatrixTransform_Impl::SetMatrix(this, core::mem::transmute_copy(&matrix)).into()
+            }
+        }
+        unsafe extern "system" fn SetMatrixElement<Identity: windows_core::IUnknownImpl + IDCompositionMatrixTransform_Impl>(this: *mut core::ffi::c_void, row: i32, column: i32, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionMatrixTransform_Impl::SetMatrixElement(this, core::mem::transmute_copy(&row), core::mem::transmute_copy(&column), core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetMatrixElement2<Identity: windows_core::IUnknownImpl + IDCompositionMatrixTransform_Impl>(this: *mut core::ffi::c_void, row: i32, column: i32, value: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionMatrixTransform_Impl::SetMatrixElement2(this, core::mem::transmute_copy(&row), core::mem::transmute_copy(&column), core::mem::transmute_copy(&value)).into()
+            }
+        }
+        Self {
+            base__: <IDCompositionTransform_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetMatrix: SetMatrix::<Identity>,
+            SetMatrixElement: SetMatrixElement::<Identity>,
+            SetMatrixElement2: SetMatrixElement2::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDCompositionMatrixTransform {}
 windows_core::imp::define_interface!(IDCompositionMatrixTransform3D, IDCompositionMatrixTransform3D_Vtbl, 0x4b3363f0_643b_41b7_b6e0_ccf22d34467c);
 impl core::ops::Deref for IDCompositionMatrixTransform3D {
@@ -3297,6 +4907,37 @@ impl IDCompositionMatrixTransform3D_Vtbl {
         iid == &<IDCompositionMatrixTransform3D as windows_core::Interface>::IID || iid == &<IDCompositionEffect as windows_core::Interface>::IID || iid == &<IDCompositionTransform3D as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionMatrixTransform3D_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionMatrixTransform3D_Vtbl
+where
+    IDCompositionTransform3D_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetMatrix<Identity: windows_core::IUnknownImpl + IDCompositionMatrixTransform3D_Impl>(this: *mut core::ffi::c_void, matrix: *const windows_numerics::Matrix4x4) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionMatrixTransform3D_Impl::SetMatrix(this, core::mem::transmute_copy(&matrix)).into()
+            }
+        }
+        unsafe extern "system" fn SetMatrixElement<Identity: windows_core::IUnknownImpl + IDCompositionMatrixTransform3D_Impl>(this: *mut core::ffi::c_void, row: i32, column: i32, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionMatrixTransform3D_Impl::SetMatrixElement(this, core::mem::transmute_copy(&row), core::mem::transmute_copy(&column), core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetMatrixElement2<Identity: windows_core::IUnknownImpl + IDCompositionMatrixTransform3D_Impl>(this: *mut core::ffi::c_void, row: i32, column: i32, value: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionMatrixTransform3D_Impl::SetMatrixElement2(this, core::mem::transmute_copy(&row), core::mem::transmute_copy(&column), core::mem::transmute_copy(&value)).into()
+            }
+        }
+        Self {
+            base__: <IDCompositionTransform3D_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetMatrix: SetMatrix::<Identity>,
+            SetMatrixElement: SetMatrixElement::<Identity>,
+            SetMatrixElement2: SetMatrixElement2::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDCompositionMatrixTransform3D {}
 windows_core::imp::define_interface!(IDCompositionRectangleClip, IDCompositionRectangleClip_Vtbl, 0x9842ad7d_d9cf_4908_aed7_48b51da5e7c2);
 impl core::ops::Deref for IDCompositionRectangleClip {
@@ -3499,155 +5140,333 @@ impl IDCompositionRectangleClip_Vtbl {
         }
         unsafe extern "system" fn SetRight<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionRectangleClip_Impl::SetRight(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetRight2<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, right: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionRectangleClip_Impl::SetRight2(this, core::mem::transmute_copy(&right)).into()
+            }
+        }
+        unsafe extern "system" fn SetBottom<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionRectangleClip_Impl::SetBottom(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetBottom2<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bottom: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionRectangleClip_Impl::SetBottom2(this, core::mem::transmute_copy(&bottom)).into()
+            }
+        }
+        unsafe extern "system" fn SetTopLeftRadiusX<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionRectangleClip_Impl::SetTopLeftRadiusX(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetTopLeftRadiusX2<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionRectangleClip_Impl::SetTopLeftRadiusX2(this, core::mem::transmute_copy(&radius)).into()
+            }
+        }
+        unsafe extern "system" fn SetTopLeftRadiusY<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionRectangleClip_Impl::SetTopLeftRadiusY(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetTopLeftRadiusY2<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionRectangleClip_Impl::SetTopLeftRadiusY2(this, core::mem::transmute_copy(&radius)).into()
+            }
+        }
+        unsafe extern "system" fn SetTopRightRadiusX<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionRectangleClip_Impl::SetTopRightRadiusX(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetTopRightRadiusX2<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionRectangleClip_Impl::SetTopRightRadiusX2(this, core::mem::transmute_copy(&radius)).into()
+            }
+        }
+        unsafe extern "system" fn SetTopRightRadiusY<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionRectangleClip_Impl::SetTopRightRadiusY(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetTopRightRadiusY2<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionRectangleClip_Impl::SetTopRightRadiusY2(this, core::mem::transmute_copy(&radius)).into()
+            }
+        }
+        unsafe extern "system" fn SetBottomLeftRadiusX<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionRectangleClip_Impl::SetBottomLeftRadiusX(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetBottomLeftRadiusX2<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionRectangleClip_Impl::SetBottomLeftRadiusX2(this, core::mem::transmute_copy(&radius)).into()
+            }
+        }
+        unsafe extern "system" fn SetBottomLeftRadiusY<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionRectangleClip_Impl::SetBottomLeftRadiusY(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetBottomLeftRadiusY2<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionRectangleClip_Impl::SetBottomLeftRadiusY2(this, core::mem::transmute_copy(&radius)).into()
+            }
+        }
+        unsafe extern "system" fn SetBottomRightRadiusX<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionRectangleClip_Impl::SetBottomRightRadiusX(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetBottomRightRadiusX2<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionRectangleClip_Impl::SetBottomRightRadiusX2(this, core::mem::transmute_copy(&radius)).into()
+            }
+        }
+        unsafe extern "system" fn SetBottomRightRadiusY<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionRectangleClip_Impl::SetBottomRightRadiusY(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetBottomRightRadiusY2<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDCompositionRectangleClip_Impl::SetBottomRightRadiusY2(this, core::mem::transmute_copy(&radius)).into()
+            }
+        }
+        Self {
+            base__: IDCompositionClip_Vtbl::new::<Identity, OFFSET>(),
+            SetLeft: SetLeft::<Identity, OFFSET>,
+            SetLeft2: SetLeft2::<Identity, OFFSET>,
+            SetTop: SetTop::<Identity, OFFSET>,
+            SetTop2: SetTop2::<Identity, OFFSET>,
+            SetRight: SetRight::<Identity, OFFSET>,
+            SetRight2: SetRight2::<Identity, OFFSET>,
+            SetBottom: SetBottom::<Identity, OFFSET>,
+            SetBottom2: SetBottom2::<Identity, OFFSET>,
+            SetTopLeftRadiusX: SetTopLeftRadiusX::<Identity, OFFSET>,
+            SetTopLeftRadiusX2: SetTopLeftRadiusX2::<Identity, OFFSET>,
+            SetTopLeftRadiusY: SetTopLeftRadiusY::<Identity, OFFSET>,
+            SetTopLeftRadiusY2: SetTopLeftRadiusY2::<Identity, OFFSET>,
+            SetTopRightRadiusX: SetTopRightRadiusX::<Identity, OFFSET>,
+            SetTopRightRadiusX2: SetTopRightRadiusX2::<Identity, OFFSET>,
+            SetTopRightRadiusY: SetTopRightRadiusY::<Identity, OFFSET>,
+            SetTopRightRadiusY2: SetTopRightRadiusY2::<Identity, OFFSET>,
+            SetBottomLeftRadiusX: SetBottomLeftRadiusX::<Identity, OFFSET>,
+            SetBottomLeftRadiusX2: SetBottomLeftRadiusX2::<Identity, OFFSET>,
+            SetBottomLeftRadiusY: SetBottomLeftRadiusY::<Identity, OFFSET>,
+            SetBottomLeftRadiusY2: SetBottomLeftRadiusY2::<Identity, OFFSET>,
+            SetBottomRightRadiusX: SetBottomRightRadiusX::<Identity, OFFSET>,
+            SetBottomRightRadiusX2: SetBottomRightRadiusX2::<Identity, OFFSET>,
+            SetBottomRightRadiusY: SetBottomRightRadiusY::<Identity, OFFSET>,
+            SetBottomRightRadiusY2: SetBottomRightRadiusY2::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionRectangleClip as windows_core::Interface>::IID || iid == &<IDCompositionClip as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionRectangleClip_Vtbl
+where
+    IDCompositionClip_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetLeft<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRectangleClip_Impl::SetLeft(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetLeft2<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, left: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRectangleClip_Impl::SetLeft2(this, core::mem::transmute_copy(&left)).into()
+            }
+        }
+        unsafe extern "system" fn SetTop<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRectangleClip_Impl::SetTop(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetTop2<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, top: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRectangleClip_Impl::SetTop2(this, core::mem::transmute_copy(&top)).into()
+            }
+        }
+        unsafe extern "system" fn SetRight<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionRectangleClip_Impl::SetRight(this, core::mem::transmute_copy(&animation)).into()
             }
         }
-        unsafe extern "system" fn SetRight2<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, right: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetRight2<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, right: f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionRectangleClip_Impl::SetRight2(this, core::mem::transmute_copy(&right)).into()
             }
         }
-        unsafe extern "system" fn SetBottom<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBottom<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionRectangleClip_Impl::SetBottom(this, core::mem::transmute_copy(&animation)).into()
             }
         }
-        unsafe extern "system" fn SetBottom2<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bottom: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBottom2<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, bottom: f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionRectangleClip_Impl::SetBottom2(this, core::mem::transmute_copy(&bottom)).into()
             }
         }
-        unsafe extern "system" fn SetTopLeftRadiusX<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetTopLeftRadiusX<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionRectangleClip_Impl::SetTopLeftRadiusX(this, core::mem::transmute_copy(&animation)).into()
             }
         }
-        unsafe extern "system" fn SetTopLeftRadiusX2<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetTopLeftRadiusX2<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionRectangleClip_Impl::SetTopLeftRadiusX2(this, core::mem::transmute_copy(&radius)).into()
             }
         }
-        unsafe extern "system" fn SetTopLeftRadiusY<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetTopLeftRadiusY<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionRectangleClip_Impl::SetTopLeftRadiusY(this, core::mem::transmute_copy(&animation)).into()
             }
         }
-        unsafe extern "system" fn SetTopLeftRadiusY2<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetTopLeftRadiusY2<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionRectangleClip_Impl::SetTopLeftRadiusY2(this, core::mem::transmute_copy(&radius)).into()
             }
         }
-        unsafe extern "system" fn SetTopRightRadiusX<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetTopRightRadiusX<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionRectangleClip_Impl::SetTopRightRadiusX(this, core::mem::transmute_copy(&animation)).into()
             }
         }
-        unsafe extern "system" fn SetTopRightRadiusX2<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetTopRightRadiusX2<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionRectangleClip_Impl::SetTopRightRadiusX2(this, core::mem::transmute_copy(&radius)).into()
             }
         }
-        unsafe extern "system" fn SetTopRightRadiusY<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetTopRightRadiusY<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionRectangleClip_Impl::SetTopRightRadiusY(this, core::mem::transmute_copy(&animation)).into()
             }
         }
-        unsafe extern "system" fn SetTopRightRadiusY2<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetTopRightRadiusY2<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionRectangleClip_Impl::SetTopRightRadiusY2(this, core::mem::transmute_copy(&radius)).into()
             }
         }
-        unsafe extern "system" fn SetBottomLeftRadiusX<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBottomLeftRadiusX<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionRectangleClip_Impl::SetBottomLeftRadiusX(this, core::mem::transmute_copy(&animation)).into()
             }
         }
-        unsafe extern "system" fn SetBottomLeftRadiusX2<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBottomLeftRadiusX2<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionRectangleClip_Impl::SetBottomLeftRadiusX2(this, core::mem::transmute_copy(&radius)).into()
             }
         }
-        unsafe extern "system" fn SetBottomLeftRadiusY<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBottomLeftRadiusY<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionRectangleClip_Impl::SetBottomLeftRadiusY(this, core::mem::transmute_copy(&animation)).into()
             }
         }
-        unsafe extern "system" fn SetBottomLeftRadiusY2<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBottomLeftRadiusY2<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionRectangleClip_Impl::SetBottomLeftRadiusY2(this, core::mem::transmute_copy(&radius)).into()
             }
         }
-        unsafe extern "system" fn SetBottomRightRadiusX<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBottomRightRadiusX<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionRectangleClip_Impl::SetBottomRightRadiusX(this, core::mem::transmute_copy(&animation)).into()
             }
         }
-        unsafe extern "system" fn SetBottomRightRadiusX2<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBottomRightRadiusX2<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionRectangleClip_Impl::SetBottomRightRadiusX2(this, core::mem::transmute_copy(&radius)).into()
             }
         }
-        unsafe extern "system" fn SetBottomRightRadiusY<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBottomRightRadiusY<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionRectangleClip_Impl::SetBottomRightRadiusY(this, core::mem::transmute_copy(&animation)).into()
             }
         }
-        unsafe extern "system" fn SetBottomRightRadiusY2<Identity: IDCompositionRectangleClip_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBottomRightRadiusY2<Identity: windows_core::IUnknownImpl + IDCompositionRectangleClip_Impl>(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDCompositionRectangleClip_Impl::SetBottomRightRadiusY2(this, core::mem::transmute_copy(&radius)).into()
             }
         }
         Self {
-            base__: IDCompositionClip_Vtbl::new::<Identity, OFFSET>(),
-            SetLeft: SetLeft::<Identity, OFFSET>,
-            SetLeft2: SetLeft2::<Identity, OFFSET>,
-            SetTop: SetTop::<Identity, OFFSET>,
-            SetTop2: SetTop2::<Identity, OFFSET>,
-            SetRight: SetRight::<Identity, OFFSET>,
-            SetRight2: SetRight2::<Identity, OFFSET>,
-            SetBottom: SetBottom::<Identity, OFFSET>,
-            SetBottom2: SetBottom2::<Identity, OFFSET>,
-            SetTopLeftRadiusX: SetTopLeftRadiusX::<Identity, OFFSET>,
-            SetTopLeftRadiusX2: SetTopLeftRadiusX2::<Identity, OFFSET>,
-            SetTopLeftRadiusY: SetTopLeftRadiusY::<Identity, OFFSET>,
-            SetTopLeftRadiusY2: SetTopLeftRadiusY2::<Identity, OFFSET>,
-            SetTopRightRadiusX: SetTopRightRadiusX::<Identity, OFFSET>,
-            SetTopRightRadiusX2: SetTopRightRadiusX2::<Identity, OFFSET>,
-            SetTopRightRadiusY: SetTopRightRadiusY::<Identity, OFFSET>,
-            SetTopRightRadiusY2: SetTopRightRadiusY2::<Identity, OFFSET>,
-            SetBottomLeftRadiusX: SetBottomLeftRadiusX::<Identity, OFFSET>,
-            SetBottomLeftRadiusX2: SetBottomLeftRadiusX2::<Identity, OFFSET>,
-            SetBottomLeftRadiusY: SetBottomLeftRadiusY::<Identity, OFFSET>,
-            SetBottomLeftRadiusY2: SetBottomLeftRadiusY2::<Identity, OFFSET>,
-            SetBottomRightRadiusX: SetBottomRightRadiusX::<Identity, OFFSET>,
-            SetBottomRightRadiusX2: SetBottomRightRadiusX2::<Identity, OFFSET>,
-            SetBottomRightRadiusY: SetBottomRightRadiusY::<Identity, OFFSET>,
-            SetBottomRightRadiusY2: SetBottomRightRadiusY2::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDCompositionRectangleClip as windows_core::Interface>::IID || iid == &<IDCompositionClip as windows_core::Interface>::IID
-    }
+            base__: <IDCompositionClip_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetLeft: SetLeft::<Identity>,
+            SetLeft2: SetLeft2::<Identity>,
+            SetTop: SetTop::<Identity>,
+            SetTop2: SetTop2::<Identity>,
+            SetRight: SetRight::<Identity>,
+            SetRight2: SetRight2::<Identity>,
+            SetBottom: SetBottom::<Identity>,
+            SetBottom2: SetBottom2::<Identity>,
+            SetTopLeftRadiusX: SetTopLeftRadiusX::<Identity>,
+            SetTopLeftRadiusX2: SetTopLeftRadiusX2::<Identity>,
+            SetTopLeftRadiusY: SetTopLeftRadiusY::<Identity>,
+            SetTopLeftRadiusY2: SetTopLeftRadiusY2::<Identity>,
+            SetTopRightRadiusX: SetTopRightRadiusX::<Identity>,
+            SetTopRightRadiusX2: SetTopRightRadiusX2::<Identity>,
+            SetTopRightRadiusY: SetTopRightRadiusY::<Identity>,
+            SetTopRightRadiusY2: SetTopRightRadiusY2::<Identity>,
+            SetBottomLeftRadiusX: SetBottomLeftRadiusX::<Identity>,
+            SetBottomLeftRadiusX2: SetBottomLeftRadiusX2::<Identity>,
+            SetBottomLeftRadiusY: SetBottomLeftRadiusY::<Identity>,
+            SetBottomLeftRadiusY2: SetBottomLeftRadiusY2::<Identity>,
+            SetBottomRightRadiusX: SetBottomRightRadiusX::<Identity>,
+            SetBottomRightRadiusX2: SetBottomRightRadiusX2::<Identity>,
+            SetBottomRightRadiusY: SetBottomRightRadiusY::<Identity>,
+            SetBottomRightRadiusY2: SetBottomRightRadiusY2::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IDCompositionRectangleClip {}
 windows_core::imp::define_interface!(IDCompositionRotateTransform, IDCompositionRotateTransform_Vtbl, 0x641ed83c_ae96_46c5_90dc_32774cc5c6d5);
@@ -3758,6 +5577,58 @@ impl IDCompositionRotateTransform_Vtbl {
         iid == &<IDCompositionRotateTransform as windows_core::Interface>::IID || iid == &<IDCompositionEffect as windows_core::Interface>::IID || iid == &<IDCompositionTransform3D as windows_core::Interface>::IID || iid == &<IDCompositionTransform as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionRotateTransform_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionRotateTransform_Vtbl
+where
+    IDCompositionTransform_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAngle<Identity: windows_core::IUnknownImpl + IDCompositionRotateTransform_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRotateTransform_Impl::SetAngle(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetAngle2<Identity: windows_core::IUnknownImpl + IDCompositionRotateTransform_Impl>(this: *mut core::ffi::c_void, angle: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRotateTransform_Impl::SetAngle2(this, core::mem::transmute_copy(&angle)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterX<Identity: windows_core::IUnknownImpl + IDCompositionRotateTransform_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRotateTransform_Impl::SetCenterX(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterX2<Identity: windows_core::IUnknownImpl + IDCompositionRotateTransform_Impl>(this: *mut core::ffi::c_void, centerx: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRotateTransform_Impl::SetCenterX2(this, core::mem::transmute_copy(&centerx)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterY<Identity: windows_core::IUnknownImpl + IDCompositionRotateTransform_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRotateTransform_Impl::SetCenterY(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterY2<Identity: windows_core::IUnknownImpl + IDCompositionRotateTransform_Impl>(this: *mut core::ffi::c_void, centery: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRotateTransform_Impl::SetCenterY2(this, core::mem::transmute_copy(&centery)).into()
+            }
+        }
+        Self {
+            base__: <IDCompositionTransform_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAngle: SetAngle::<Identity>,
+            SetAngle2: SetAngle2::<Identity>,
+            SetCenterX: SetCenterX::<Identity>,
+            SetCenterX2: SetCenterX2::<Identity>,
+            SetCenterY: SetCenterY::<Identity>,
+            SetCenterY2: SetCenterY2::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDCompositionRotateTransform {}
 windows_core::imp::define_interface!(IDCompositionRotateTransform3D, IDCompositionRotateTransform3D_Vtbl, 0xd8f5b23f_d429_4a91_b55a_d2f45fd75b18);
 impl core::ops::Deref for IDCompositionRotateTransform3D {
@@ -3975,6 +5846,114 @@ impl IDCompositionRotateTransform3D_Vtbl {
         iid == &<IDCompositionRotateTransform3D as windows_core::Interface>::IID || iid == &<IDCompositionEffect as windows_core::Interface>::IID || iid == &<IDCompositionTransform3D as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionRotateTransform3D_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionRotateTransform3D_Vtbl
+where
+    IDCompositionTransform3D_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAngle<Identity: windows_core::IUnknownImpl + IDCompositionRotateTransform3D_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRotateTransform3D_Impl::SetAngle(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetAngle2<Identity: windows_core::IUnknownImpl + IDCompositionRotateTransform3D_Impl>(this: *mut core::ffi::c_void, angle: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRotateTransform3D_Impl::SetAngle2(this, core::mem::transmute_copy(&angle)).into()
+            }
+        }
+        unsafe extern "system" fn SetAxisX<Identity: windows_core::IUnknownImpl + IDCompositionRotateTransform3D_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRotateTransform3D_Impl::SetAxisX(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetAxisX2<Identity: windows_core::IUnknownImpl + IDCompositionRotateTransform3D_Impl>(this: *mut core::ffi::c_void, axisx: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRotateTransform3D_Impl::SetAxisX2(this, core::mem::transmute_copy(&axisx)).into()
+            }
+        }
+        unsafe extern "system" fn SetAxisY<Identity: windows_core::IUnknownImpl + IDCompositionRotateTransform3D_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRotateTransform3D_Impl::SetAxisY(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetAxisY2<Identity: windows_core::IUnknownImpl + IDCompositionRotateTransform3D_Impl>(this: *mut core::ffi::c_void, axisy: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRotateTransform3D_Impl::SetAxisY2(this, core::mem::transmute_copy(&axisy)).into()
+            }
+        }
+        unsafe extern "system" fn SetAxisZ<Identity: windows_core::IUnknownImpl + IDCompositionRotateTransform3D_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRotateTransform3D_Impl::SetAxisZ(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetAxisZ2<Identity: windows_core::IUnknownImpl + IDCompositionRotateTransform3D_Impl>(this: *mut core::ffi::c_void, axisz: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRotateTransform3D_Impl::SetAxisZ2(this, core::mem::transmute_copy(&axisz)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterX<Identity: windows_core::IUnknownImpl + IDCompositionRotateTransform3D_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRotateTransform3D_Impl::SetCenterX(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterX2<Identity: windows_core::IUnknownImpl + IDCompositionRotateTransform3D_Impl>(this: *mut core::ffi::c_void, centerx: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRotateTransform3D_Impl::SetCenterX2(this, core::mem::transmute_copy(&centerx)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterY<Identity: windows_core::IUnknownImpl + IDCompositionRotateTransform3D_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRotateTransform3D_Impl::SetCenterY(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterY2<Identity: windows_core::IUnknownImpl + IDCompositionRotateTransform3D_Impl>(this: *mut core::ffi::c_void, centery: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRotateTransform3D_Impl::SetCenterY2(this, core::mem::transmute_copy(&centery)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterZ<Identity: windows_core::IUnknownImpl + IDCompositionRotateTransform3D_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRotateTransform3D_Impl::SetCenterZ(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterZ2<Identity: windows_core::IUnknownImpl + IDCompositionRotateTransform3D_Impl>(this: *mut core::ffi::c_void, centerz: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionRotateTransform3D_Impl::SetCenterZ2(this, core::mem::transmute_copy(&centerz)).into()
+            }
+        }
+        Self {
+            base__: <IDCompositionTransform3D_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAngle: SetAngle::<Identity>,
+            SetAngle2: SetAngle2::<Identity>,
+            SetAxisX: SetAxisX::<Identity>,
+            SetAxisX2: SetAxisX2::<Identity>,
+            SetAxisY: SetAxisY::<Identity>,
+            SetAxisY2: SetAxisY2::<Identity>,
+            SetAxisZ: SetAxisZ::<Identity>,
+            SetAxisZ2: SetAxisZ2::<Identity>,
+            SetCenterX: SetCenterX::<Identity>,
+            SetCenterX2: SetCenterX2::<Identity>,
+            SetCenterY: SetCenterY::<Identity>,
+            SetCenterY2: SetCenterY2::<Identity>,
+            SetCenterZ: SetCenterZ::<Identity>,
+            SetCenterZ2: SetCenterZ2::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDCompositionRotateTransform3D {}
 windows_core::imp::define_interface!(IDCompositionSaturationEffect, IDCompositionSaturationEffect_Vtbl, 0xa08debda_3258_4fa4_9f16_9174d3fe93b1);
 impl core::ops::Deref for IDCompositionSaturationEffect {
@@ -4030,6 +6009,30 @@ impl IDCompositionSaturationEffect_Vtbl {
         iid == &<IDCompositionSaturationEffect as windows_core::Interface>::IID || iid == &<IDCompositionEffect as windows_core::Interface>::IID || iid == &<IDCompositionFilterEffect as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionSaturationEffect_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionSaturationEffect_Vtbl
+where
+    IDCompositionFilterEffect_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetSaturation<Identity: windows_core::IUnknownImpl + IDCompositionSaturationEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionSaturationEffect_Impl::SetSaturation(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetSaturation2<Identity: windows_core::IUnknownImpl + IDCompositionSaturationEffect_Impl>(this: *mut core::ffi::c_void, ratio: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionSaturationEffect_Impl::SetSaturation2(this, core::mem::transmute_copy(&ratio)).into()
+            }
+        }
+        Self {
+            base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetSaturation: SetSaturation::<Identity>,
+            SetSaturation2: SetSaturation2::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDCompositionSaturationEffect {}
 windows_core::imp::define_interface!(IDCompositionScaleTransform, IDCompositionScaleTransform_Vtbl, 0x71fde914_40ef_45ef_bd51_68b037c339f9);
 impl core::ops::Deref for IDCompositionScaleTransform {
@@ -4166,6 +6169,72 @@ impl IDCompositionScaleTransform_Vtbl {
         iid == &<IDCompositionScaleTransform as windows_core::Interface>::IID || iid == &<IDCompositionEffect as windows_core::Interface>::IID || iid == &<IDCompositionTransform3D as windows_core::Interface>::IID || iid == &<IDCompositionTransform as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionScaleTransform_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionScaleTransform_Vtbl
+where
+    IDCompositionTransform_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetScaleX<Identity: windows_core::IUnknownImpl + IDCompositionScaleTransform_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionScaleTransform_Impl::SetScaleX(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetScaleX2<Identity: windows_core::IUnknownImpl + IDCompositionScaleTransform_Impl>(this: *mut core::ffi::c_void, scalex: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionScaleTransform_Impl::SetScaleX2(this, core::mem::transmute_copy(&scalex)).into()
+            }
+        }
+        unsafe extern "system" fn SetScaleY<Identity: windows_core::IUnknownImpl + IDCompositionScaleTransform_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionScaleTransform_Impl::SetScaleY(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetScaleY2<Identity: windows_core::IUnknownImpl + IDCompositionScaleTransform_Impl>(this: *mut core::ffi::c_void, scaley: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionScaleTransform_Impl::SetScaleY2(this, core::mem::transmute_copy(&scaley)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterX<Identity: windows_core::IUnknownImpl + IDCompositionScaleTransform_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionScaleTransform_Impl::SetCenterX(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterX2<Identity: windows_core::IUnknownImpl + IDCompositionScaleTransform_Impl>(this: *mut core::ffi::c_void, centerx: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionScaleTransform_Impl::SetCenterX2(this, core::mem::transmute_copy(&centerx)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterY<Identity: windows_core::IUnknownImpl + IDCompositionScaleTransform_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionScaleTransform_Impl::SetCenterY(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterY2<Identity: windows_core::IUnknownImpl + IDCompositionScaleTransform_Impl>(this: *mut core::ffi::c_void, centery: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionScaleTransform_Impl::SetCenterY2(this, core::mem::transmute_copy(&centery)).into()
+            }
+        }
+        Self {
+            base__: <IDCompositionTransform_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetScaleX: SetScaleX::<Identity>,
+            SetScaleX2: SetScaleX2::<Identity>,
+            SetScaleY: SetScaleY::<Identity>,
+            SetScaleY2: SetScaleY2::<Identity>,
+            SetCenterX: SetCenterX::<Identity>,
+            SetCenterX2: SetCenterX2::<Identity>,
+            SetCenterY: SetCenterY::<Identity>,
+            SetCenterY2: SetCenterY2::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDCompositionScaleTransform {}
 windows_core::imp::define_interface!(IDCompositionScaleTransform3D, IDCompositionScaleTransform3D_Vtbl, 0x2a9e9ead_364b_4b15_a7c4_a1997f78b389);
 impl core::ops::Deref for IDCompositionScaleTransform3D {
@@ -4351,10 +6420,104 @@ impl IDCompositionScaleTransform3D_Vtbl {
             SetCenterZ: SetCenterZ::<Identity, OFFSET>,
             SetCenterZ2: SetCenterZ2::<Identity, OFFSET>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDCompositionScaleTransform3D as windows_core::Interface>::IID || iid == &<IDCompositionEffect as windows_core::Interface>::IID || iid == &<IDCompositionTransform3D as windows_core::Interface>::IID
-    }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionScaleTransform3D as windows_core::Interface>::IID || iid == &<IDCompositionEffect as windows_core::Interface>::IID || iid == &<IDCompositionTransform3D as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IDCompositionScaleTransform3D_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionScaleTransform3D_Vtbl
+where
+    IDCompositionTransform3D_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetScaleX<Identity: windows_core::IUnknownImpl + IDCompositionScaleTransform3D_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionScaleTransform3D_Impl::SetScaleX(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetScaleX2<Identity: windows_core::IUnknownImpl + IDCompositionScaleTransform3D_Impl>(this: *mut core::ffi::c_void, scalex: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionScaleTransform3D_Impl::SetScaleX2(this, core::mem::transmute_copy(&scalex)).into()
+            }
+        }
+        unsafe extern "system" fn SetScaleY<Identity: windows_core::IUnknownImpl + IDCompositionScaleTransform3D_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionScaleTransform3D_Impl::SetScaleY(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetScaleY2<Identity: windows_core::IUnknownImpl + IDCompositionScaleTransform3D_Impl>(this: *mut core::ffi::c_void, scaley: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionScaleTransform3D_Impl::SetScaleY2(this, core::mem::transmute_copy(&scaley)).into()
+            }
+        }
+        unsafe extern "system" fn SetScaleZ<Identity: windows_core::IUnknownImpl + IDCompositionScaleTransform3D_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionScaleTransform3D_Impl::SetScaleZ(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetScaleZ2<Identity: windows_core::IUnknownImpl + IDCompositionScaleTransform3D_Impl>(this: *mut core::ffi::c_void, scalez: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionScaleTransform3D_Impl::SetScaleZ2(this, core::mem::transmute_copy(&scalez)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterX<Identity: windows_core::IUnknownImpl + IDCompositionScaleTransform3D_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionScaleTransform3D_Impl::SetCenterX(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterX2<Identity: windows_core::IUnknownImpl + IDCompositionScaleTransform3D_Impl>(this: *mut core::ffi::c_void, centerx: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionScaleTransform3D_Impl::SetCenterX2(this, core::mem::transmute_copy(&centerx)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterY<Identity: windows_core::IUnknownImpl + IDCompositionScaleTransform3D_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionScaleTransform3D_Impl::SetCenterY(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterY2<Identity: windows_core::IUnknownImpl + IDCompositionScaleTransform3D_Impl>(this: *mut core::ffi::c_void, centery: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionScaleTransform3D_Impl::SetCenterY2(this, core::mem::transmute_copy(&centery)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterZ<Identity: windows_core::IUnknownImpl + IDCompositionScaleTransform3D_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionScaleTransform3D_Impl::SetCenterZ(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterZ2<Identity: windows_core::IUnknownImpl + IDCompositionScaleTransform3D_Impl>(this: *mut core::ffi::c_void, centerz: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionScaleTransform3D_Impl::SetCenterZ2(this, core::mem::transmute_copy(&centerz)).into()
+            }
+        }
+        Self {
+            base__: <IDCompositionTransform3D_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetScaleX: SetScaleX::<Identity>,
+            SetScaleX2: SetScaleX2::<Identity>,
+            SetScaleY: SetScaleY::<Identity>,
+            SetScaleY2: SetScaleY2::<Identity>,
+            SetScaleZ: SetScaleZ::<Identity>,
+            SetScaleZ2: SetScaleZ2::<Identity>,
+            SetCenterX: SetCenterX::<Identity>,
+            SetCenterX2: SetCenterX2::<Identity>,
+            SetCenterY: SetCenterY::<Identity>,
+            SetCenterY2: SetCenterY2::<Identity>,
+            SetCenterZ: SetCenterZ::<Identity>,
+            SetCenterZ2: SetCenterZ2::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IDCompositionScaleTransform3D {}
 windows_core::imp::define_interface!(IDCompositionShadowEffect, IDCompositionShadowEffect_Vtbl, 0x4ad18ac0_cfd2_4c2f_bb62_96e54fdb6879);
@@ -4531,6 +6694,93 @@ impl IDCompositionShadowEffect_Vtbl {
         iid == &<IDCompositionShadowEffect as windows_core::Interface>::IID || iid == &<IDCompositionEffect as windows_core::Interface>::IID || iid == &<IDCompositionFilterEffect as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionShadowEffect_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionShadowEffect_Vtbl
+where
+    IDCompositionFilterEffect_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetStandardDeviation<Identity: windows_core::IUnknownImpl + IDCompositionShadowEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionShadowEffect_Impl::SetStandardDeviation(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetStandardDeviation2<Identity: windows_core::IUnknownImpl + IDCompositionShadowEffect_Impl>(this: *mut core::ffi::c_void, amount: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionShadowEffect_Impl::SetStandardDeviation2(this, core::mem::transmute_copy(&amount)).into()
+            }
+        }
+        unsafe extern "system" fn SetColor<Identity: windows_core::IUnknownImpl + IDCompositionShadowEffect_Impl>(this: *mut core::ffi::c_void, color: *const windows_numerics::Vector4) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionShadowEffect_Impl::SetColor(this, core::mem::transmute_copy(&color)).into()
+            }
+        }
+        unsafe extern "system" fn SetRed<Identity: windows_core::IUnknownImpl + IDCompositionShadowEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionShadowEffect_Impl::SetRed(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetRed2<Identity: windows_core::IUnknownImpl + IDCompositionShadowEffect_Impl>(this: *mut core::ffi::c_void, amount: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionShadowEffect_Impl::SetRed2(this, core::mem::transmute_copy(&amount)).into()
+            }
+        }
+        unsafe extern "system" fn SetGreen<Identity: windows_core::IUnknownImpl + IDCompositionShadowEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionShadowEffect_Impl::SetGreen(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetGreen2<Identity: windows_core::IUnknownImpl + IDCompositionShadowEffect_Impl>(this: *mut core::ffi::c_void, amount: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionShadowEffect_Impl::SetGreen2(this, core::mem::transmute_copy(&amount)).into()
+            }
+        }
+        unsafe extern "system" fn SetBlue<Identity: windows_core::IUnknownImpl + IDCompositionShadowEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionShadowEffect_Impl::SetBlue(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetBlue2<Identity: windows_core::IUnknownImpl + IDCompositionShadowEffect_Impl>(this: *mut core::ffi::c_void, amount: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionShadowEffect_Impl::SetBlue2(this, core::mem::transmute_copy(&amount)).into()
+            }
+        }
+        unsafe extern "system" fn SetAlpha<Identity: windows_core::IUnknownImpl + IDCompositionShadowEffect_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionShadowEffect_Impl::SetAlpha(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetAlpha2<Identity: windows_core::IUnknownImpl + IDCompositionShadowEffect_Impl>(this: *mut core::ffi::c_void, amount: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionShadowEffect_Impl::SetAlpha2(this, core::mem::transmute_copy(&amount)).into()
+            }
+        }
+        Self {
+            base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetStandardDeviation: SetStandardDeviation::<Identity>,
+            SetStandardDeviation2: SetStandardDeviation2::<Identity>,
+            SetColor: SetColor::<Identity>,
+            SetRed: SetRed::<Identity>,
+            SetRed2: SetRed2::<Identity>,
+            SetGreen: SetGreen::<Identity>,
+            SetGreen2: SetGreen2::<Identity>,
+            SetBlue: SetBlue::<Identity>,
+            SetBlue2: SetBlue2::<Identity>,
+            SetAlpha: SetAlpha::<Identity>,
+            SetAlpha2: SetAlpha2::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDCompositionShadowEffect {}
 windows_core::imp::define_interface!(IDCompositionSkewTransform, IDCompositionSkewTransform_Vtbl, 0xe57aa735_dcdb_4c72_9c61_0591f58889ee);
 impl core::ops::Deref for IDCompositionSkewTransform {
@@ -4667,6 +6917,72 @@ impl IDCompositionSkewTransform_Vtbl {
         iid == &<IDCompositionSkewTransform as windows_core::Interface>::IID || iid == &<IDCompositionEffect as windows_core::Interface>::IID || iid == &<IDCompositionTransform3D as windows_core::Interface>::IID || iid == &<IDCompositionTransform as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionSkewTransform_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionSkewTransform_Vtbl
+where
+    IDCompositionTransform_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAngleX<Identity: windows_core::IUnknownImpl + IDCompositionSkewTransform_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionSkewTransform_Impl::SetAngleX(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetAngleX2<Identity: windows_core::IUnknownImpl + IDCompositionSkewTransform_Impl>(this: *mut core::ffi::c_void, anglex: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionSkewTransform_Impl::SetAngleX2(this, core::mem::transmute_copy(&anglex)).into()
+            }
+        }
+        unsafe extern "system" fn SetAngleY<Identity: windows_core::IUnknownImpl + IDCompositionSkewTransform_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionSkewTransform_Impl::SetAngleY(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetAngleY2<Identity: windows_core::IUnknownImpl + IDCompositionSkewTransform_Impl>(this: *mut core::ffi::c_void, angley: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionSkewTransform_Impl::SetAngleY2(this, core::mem::transmute_copy(&angley)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterX<Identity: windows_core::IUnknownImpl + IDCompositionSkewTransform_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionSkewTransform_Impl::SetCenterX(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterX2<Identity: windows_core::IUnknownImpl + IDCompositionSkewTransform_Impl>(this: *mut core::ffi::c_void, centerx: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionSkewTransform_Impl::SetCenterX2(this, core::mem::transmute_copy(&centerx)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterY<Identity: windows_core::IUnknownImpl + IDCompositionSkewTransform_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionSkewTransform_Impl::SetCenterY(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetCenterY2<Identity: windows_core::IUnknownImpl + IDCompositionSkewTransform_Impl>(this: *mut core::ffi::c_void, centery: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionSkewTransform_Impl::SetCenterY2(this, core::mem::transmute_copy(&centery)).into()
+            }
+        }
+        Self {
+            base__: <IDCompositionTransform_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAngleX: SetAngleX::<Identity>,
+            SetAngleX2: SetAngleX2::<Identity>,
+            SetAngleY: SetAngleY::<Identity>,
+            SetAngleY2: SetAngleY2::<Identity>,
+            SetCenterX: SetCenterX::<Identity>,
+            SetCenterX2: SetCenterX2::<Identity>,
+            SetCenterY: SetCenterY::<Identity>,
+            SetCenterY2: SetCenterY2::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDCompositionSkewTransform {}
 windows_core::imp::define_interface!(IDCompositionSurface, IDCompositionSurface_Vtbl, 0xbb8a4953_2c99_4f5a_96f5_4819027fa3ac);
 windows_core::imp::interface_hierarchy!(IDCompositionSurface, windows_core::IUnknown);
@@ -4753,6 +7069,51 @@ impl IDCompositionSurface_Vtbl {
         iid == &<IDCompositionSurface as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionSurface_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionSurface_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BeginDraw<Identity: windows_core::IUnknownImpl + IDCompositionSurface_Impl>(this: *mut core::ffi::c_void, updaterect: *const super::super::Foundation::RECT, iid: *const windows_core::GUID, updateobject: *mut *mut core::ffi::c_void, updateoffset: *mut super::super::Foundation::POINT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionSurface_Impl::BeginDraw(this, core::mem::transmute_copy(&updaterect), core::mem::transmute_copy(&iid), core::mem::transmute_copy(&updateobject), core::mem::transmute_copy(&updateoffset)).into()
+            }
+        }
+        unsafe extern "system" fn EndDraw<Identity: windows_core::IUnknownImpl + IDCompositionSurface_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionSurface_Impl::EndDraw(this).into()
+            }
+        }
+        unsafe extern "system" fn SuspendDraw<Identity: windows_core::IUnknownImpl + IDCompositionSurface_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionSurface_Impl::SuspendDraw(this).into()
+            }
+        }
+        unsafe extern "system" fn ResumeDraw<Identity: windows_core::IUnknownImpl + IDCompositionSurface_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionSurface_Impl::ResumeDraw(this).into()
+            }
+        }
+        unsafe extern "system" fn Scroll<Identity: windows_core::IUnknownImpl + IDCompositionSurface_Impl>(this: *mut core::ffi::c_void, scrollrect: *const super::super::Foundation::RECT, cliprect: *const super::super::Foundation::RECT, offsetx: i32, offsety: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionSurface_Impl::Scroll(this, core::mem::transmute_copy(&scrollrect), core::mem::transmute_copy(&cliprect), core::mem::transmute_copy(&offsetx), core::mem::transmute_copy(&offsety)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            BeginDraw: BeginDraw::<Identity>,
+            EndDraw: EndDraw::<Identity>,
+            SuspendDraw: SuspendDraw::<Identity>,
+            ResumeDraw: ResumeDraw::<Identity>,
+            Scroll: Scroll::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDCompositionSurface {}
 windows_core::imp::define_interface!(IDCompositionSurfaceFactory, IDCompositionSurfaceFactory_Vtbl, 0xe334bc12_3937_4e02_85eb_fcf4eb30d2c8);
 windows_core::imp::interface_hierarchy!(IDCompositionSurfaceFactory, windows_core::IUnknown);
@@ -4828,6 +7189,43 @@ impl IDCompositionSurfaceFactory_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDCompositionSurfaceFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionSurfaceFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateSurface<Identity: windows_core::IUnknownImpl + IDCompositionSurfaceFactory_Impl>(this: *mut core::ffi::c_void, width: u32, height: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE, surface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionSurfaceFactory_Impl::CreateSurface(this, core::mem::transmute_copy(&width), core::mem::transmute_copy(&height), core::mem::transmute_copy(&pixelformat), core::mem::transmute_copy(&alphamode)) {
+                    Ok(ok__) => {
+                        surface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateVirtualSurface<Identity: windows_core::IUnknownImpl + IDCompositionSurfaceFactory_Impl>(this: *mut core::ffi::c_void, initialwidth: u32, initialheight: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE, virtualsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDCompositionSurfaceFactory_Impl::CreateVirtualSurface(this, core::mem::transmute_copy(&initialwidth), core::mem::transmute_copy(&initialheight), core::mem::transmute_copy(&pixelformat), core::mem::transmute_copy(&alphamode)) {
+                    Ok(ok__) => {
+                        virtualsurface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateSurface: CreateSurface::<Identity>,
+            CreateVirtualSurface: CreateVirtualSurface::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IDCompositionSurfaceFactory {}
 windows_core::imp::define_interface!(IDCompositionTableTransferEffect, IDCompositionTableTransferEffect_Vtbl, 0x9b7e82e2_69c5_4eb4_a5f5_a7033f5132cd);
 impl core::ops::Deref for IDCompositionTableTransferEffect {
@@ -5072,6 +7470,135 @@ impl IDCompositionTableTransferEffect_Vtbl {
         iid == &<IDCompositionTableTransferEffect as windows_core::Interface>::IID || iid == &<IDCompositionEffect as windows_core::Interface>::IID || iid == &<IDCompositionFilterEffect as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionTableTransferEffect_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionTableTransferEffect_Vtbl
+where
+    IDCompositionFilterEffect_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetRedTable<Identity: windows_core::IUnknownImpl + IDCompositionTableTransferEffect_Impl>(this: *mut core::ffi::c_void, tablevalues: *const f32, count: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTableTransferEffect_Impl::SetRedTable(this, core::mem::transmute_copy(&tablevalues), core::mem::transmute_copy(&count)).into()
+            }
+        }
+        unsafe extern "system" fn SetGreenTable<Identity: windows_core::IUnknownImpl + IDCompositionTableTransferEffect_Impl>(this: *mut core::ffi::c_void, tablevalues: *const f32, count: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTableTransferEffect_Impl::SetGreenTable(this, core::mem::transmute_copy(&tablevalues), core::mem::transmute_copy(&count)).into()
+            }
+        }
+        unsafe extern "system" fn SetBlueTable<Identity: windows_core::IUnknownImpl + IDCompositionTableTransferEffect_Impl>(this: *mut core::ffi::c_void, tablevalues: *const f32, count: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTableTransferEffect_Impl::SetBlueTable(this, core::mem::transmute_copy(&tablevalues), core::mem::transmute_copy(&count)).into()
+            }
+        }
+        unsafe extern "system" fn SetAlphaTable<Identity: windows_core::IUnknownImpl + IDCompositionTableTransferEffect_Impl>(this: *mut core::ffi::c_void, tablevalues: *const f32, count: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTableTransferEffect_Impl::SetAlphaTable(this, core::mem::transmute_copy(&tablevalues), core::mem::transmute_copy(&count)).into()
+            }
+        }
+        unsafe extern "system" fn SetRedDisable<Identity: windows_core::IUnknownImpl + IDCompositionTableTransferEffect_Impl>(this: *mut core::ffi::c_void, reddisable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTableTransferEffect_Impl::SetRedDisable(this, core::mem::transmute_copy(&reddisable)).into()
+            }
+        }
+        unsafe extern "system" fn SetGreenDisable<Identity: windows_core::IUnknownImpl + IDCompositionTableTransferEffect_Impl>(this: *mut core::ffi::c_void, greendisable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTableTransferEffect_Impl::SetGreenDisable(this, core::mem::transmute_copy(&greendisable)).into()
+            }
+        }
+        unsafe extern "system" fn SetBlueDisable<Identity: windows_core::IUnknownImpl + IDCompositionTableTransferEffect_Impl>(this: *mut core::ffi::c_void, bluedisable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTableTransferEffect_Impl::SetBlueDisable(this, core::mem::transmute_copy(&bluedisable)).into()
+            }
+        }
+        unsafe extern "system" fn SetAlphaDisable<Identity: windows_core::IUnknownImpl + IDCompositionTableTransferEffect_Impl>(this: *mut core::ffi::c_void, alphadisable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTableTransferEffect_Impl::SetAlphaDisable(this, core::mem::transmute_copy(&alphadisable)).into()
+            }
+        }
+        unsafe extern "system" fn SetClampOutput<Identity: windows_core::IUnknownImpl + IDCompositionTableTransferEffect_Impl>(this: *mut core::ffi::c_void, clampoutput: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTableTransferEffect_Impl::SetClampOutput(this, core::mem::transmute_copy(&clampoutput)).into()
+            }
+        }
+        unsafe extern "system" fn SetRedTableValue<Identity: windows_core::IUnknownImpl + IDCompositionTableTransferEffect_Impl>(this: *mut core::ffi::c_void, index: u32, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTableTransferEffect_Impl::SetRedTableValue(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetRedTableValue2<Identity: windows_core::IUnknownImpl + IDCompositionTableTransferEffect_Impl>(this: *mut core::ffi::c_void, index: u32, value: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTableTransferEffect_Impl::SetRedTableValue2(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn SetGreenTableValue<Identity: windows_core::IUnknownImpl + IDCompositionTableTransferEffect_Impl>(this: *mut core::ffi::c_void, index: u32, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTableTransferEffect_Impl::SetGreenTableValue(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetGreenTableValue2<Identity: windows_core::IUnknownImpl + IDCompositionTableTransferEffect_Impl>(this: *mut core::ffi::c_void, index: u32, value: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTableTransferEffect_Impl::SetGreenTableValue2(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn SetBlueTableValue<Identity: windows_core::IUnknownImpl + IDCompositionTableTransferEffect_Impl>(this: *mut core::ffi::c_void, index: u32, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTableTransferEffect_Impl::SetBlueTableValue(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetBlueTableValue2<Identity: windows_core::IUnknownImpl + IDCompositionTableTransferEffect_Impl>(this: *mut core::ffi::c_void, index: u32, value: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTableTransferEffect_Impl::SetBlueTableValue2(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn SetAlphaTableValue<Identity: windows_core::IUnknownImpl + IDCompositionTableTransferEffect_Impl>(this: *mut core::ffi::c_void, index: u32, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTableTransferEffect_Impl::SetAlphaTableValue(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetAlphaTableValue2<Identity: windows_core::IUnknownImpl + IDCompositionTableTransferEffect_Impl>(this: *mut core::ffi::c_void, index: u32, value: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTableTransferEffect_Impl::SetAlphaTableValue2(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&value)).into()
+            }
+        }
+        Self {
+            base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetRedTable: SetRedTable::<Identity>,
+            SetGreenTable: SetGreenTable::<Identity>,
+            SetBlueTable: SetBlueTable::<Identity>,
+            SetAlphaTable: SetAlphaTable::<Identity>,
+            SetRedDisable: SetRedDisable::<Identity>,
+            SetGreenDisable: SetGreenDisable::<Identity>,
+            SetBlueDisable: SetBlueDisable::<Identity>,
+            SetAlphaDisable: SetAlphaDisable::<Identity>,
+            SetClampOutput: SetClampOutput::<Identity>,
+            SetRedTableValue: SetRedTableValue::<Identity>,
+            SetRedTableValue2: SetRedTableValue2::<Identity>,
+            SetGreenTableValue: SetGreenTableValue::<Identity>,
+            SetGreenTableValue2: SetGreenTableValue2::<Identity>,
+            SetBlueTableValue: SetBlueTableValue::<Identity>,
+            SetBlueTableValue2: SetBlueTableValue2::<Identity>,
+            SetAlphaTableValue: SetAlphaTableValue::<Identity>,
+            SetAlphaTableValue2: SetAlphaTableValue2::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDCompositionTableTransferEffect {}
 windows_core::imp::define_interface!(IDCompositionTarget, IDCompositionTarget_Vtbl, 0xeacdd04c_117e_4e17_88f4_d1b12b0e3d89);
 windows_core::imp::interface_hierarchy!(IDCompositionTarget, windows_core::IUnknown);
@@ -5106,6 +7633,20 @@ impl IDCompositionTarget_Vtbl {
         iid == &<IDCompositionTarget as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionTarget_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionTarget_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetRoot<Identity: windows_core::IUnknownImpl + IDCompositionTarget_Impl>(this: *mut core::ffi::c_void, visual: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTarget_Impl::SetRoot(this, core::mem::transmute_copy(&visual)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetRoot: SetRoot::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDCompositionTarget {}
 windows_core::imp::define_interface!(IDCompositionTexture, IDCompositionTexture_Vtbl, 0x929bb1aa_725f_433b_abd7_273075a835f2);
 windows_core::imp::interface_hierarchy!(IDCompositionTexture, windows_core::IUnknown);
@@ -5183,16 +7724,55 @@ impl IDCompositionTexture_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            SetSourceRect: SetSourceRect::<Identity, OFFSET>,
-            SetColorSpace: SetColorSpace::<Identity, OFFSET>,
-            SetAlphaMode: SetAlphaMode::<Identity, OFFSET>,
-            GetAvailableFence: GetAvailableFence::<Identity, OFFSET>,
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            SetSourceRect: SetSourceRect::<Identity, OFFSET>,
+            SetColorSpace: SetColorSpace::<Identity, OFFSET>,
+            SetAlphaMode: SetAlphaMode::<Identity, OFFSET>,
+            GetAvailableFence: GetAvailableFence::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionTexture as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+impl<Identity: windows_core::IUnknownImpl + IDCompositionTexture_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionTexture_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetSourceRect<Identity: windows_core::IUnknownImpl + IDCompositionTexture_Impl>(this: *mut core::ffi::c_void, sourcerect: *const super::Direct2D::Common::D2D_RECT_U) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTexture_Impl::SetSourceRect(this, core::mem::transmute_copy(&sourcerect)).into()
+            }
+        }
+        unsafe extern "system" fn SetColorSpace<Identity: windows_core::IUnknownImpl + IDCompositionTexture_Impl>(this: *mut core::ffi::c_void, colorspace: super::Dxgi::Common::DXGI_COLOR_SPACE_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTexture_Impl::SetColorSpace(this, core::mem::transmute_copy(&colorspace)).into()
+            }
+        }
+        unsafe extern "system" fn SetAlphaMode<Identity: windows_core::IUnknownImpl + IDCompositionTexture_Impl>(this: *mut core::ffi::c_void, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTexture_Impl::SetAlphaMode(this, core::mem::transmute_copy(&alphamode)).into()
+            }
+        }
+        unsafe extern "system" fn GetAvailableFence<Identity: windows_core::IUnknownImpl + IDCompositionTexture_Impl>(this: *mut core::ffi::c_void, fencevalue: *mut u64, iid: *const windows_core::GUID, availablefence: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTexture_Impl::GetAvailableFence(this, core::mem::transmute_copy(&fencevalue), core::mem::transmute_copy(&iid), core::mem::transmute_copy(&availablefence)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetSourceRect: SetSourceRect::<Identity>,
+            SetColorSpace: SetColorSpace::<Identity>,
+            SetAlphaMode: SetAlphaMode::<Identity>,
+            GetAvailableFence: GetAvailableFence::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDCompositionTexture as windows_core::Interface>::IID
-    }
+    };
 }
 #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
 impl windows_core::RuntimeName for IDCompositionTexture {}
@@ -5218,6 +7798,12 @@ impl IDCompositionTransform_Vtbl {
         iid == &<IDCompositionTransform as windows_core::Interface>::IID || iid == &<IDCompositionEffect as windows_core::Interface>::IID || iid == &<IDCompositionTransform3D as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionTransform_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionTransform_Vtbl
+where
+    IDCompositionTransform3D_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IDCompositionTransform3D_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IDCompositionTransform {}
 windows_core::imp::define_interface!(IDCompositionTransform3D, IDCompositionTransform3D_Vtbl, 0x71185722_246b_41f2_aad1_0443f7f4bfc2);
 impl core::ops::Deref for IDCompositionTransform3D {
@@ -5241,6 +7827,12 @@ impl IDCompositionTransform3D_Vtbl {
         iid == &<IDCompositionTransform3D as windows_core::Interface>::IID || iid == &<IDCompositionEffect as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionTransform3D_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionTransform3D_Vtbl
+where
+    IDCompositionEffect_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IDCompositionEffect_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IDCompositionTransform3D {}
 windows_core::imp::define_interface!(IDCompositionTranslateTransform, IDCompositionTranslateTransform_Vtbl, 0x06791122_c6f0_417d_8323_269e987f5954);
 impl core::ops::Deref for IDCompositionTranslateTransform {
@@ -5323,6 +7915,44 @@ impl IDCompositionTranslateTransform_Vtbl {
         iid == &<IDCompositionTranslateTransform as windows_core::Interface>::IID || iid == &<IDCompositionEffect as windows_core::Interface>::IID || iid == &<IDCompositionTransform3D as windows_core::Interface>::IID || iid == &<IDCompositionTransform as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionTranslateTransform_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionTranslateTransform_Vtbl
+where
+    IDCompositionTransform_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetOffsetX<Identity: windows_core::IUnknownImpl + IDCompositionTranslateTransform_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTranslateTransform_Impl::SetOffsetX(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetOffsetX2<Identity: windows_core::IUnknownImpl + IDCompositionTranslateTransform_Impl>(this: *mut core::ffi::c_void, offsetx: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTranslateTransform_Impl::SetOffsetX2(this, core::mem::transmute_copy(&offsetx)).into()
+            }
+        }
+        unsafe extern "system" fn SetOffsetY<Identity: windows_core::IUnknownImpl + IDCompositionTranslateTransform_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTranslateTransform_Impl::SetOffsetY(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetOffsetY2<Identity: windows_core::IUnknownImpl + IDCompositionTranslateTransform_Impl>(this: *mut core::ffi::c_void, offsety: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTranslateTransform_Impl::SetOffsetY2(this, core::mem::transmute_copy(&offsety)).into()
+            }
+        }
+        Self {
+            base__: <IDCompositionTransform_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetOffsetX: SetOffsetX::<Identity>,
+            SetOffsetX2: SetOffsetX2::<Identity>,
+            SetOffsetY: SetOffsetY::<Identity>,
+            SetOffsetY2: SetOffsetY2::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDCompositionTranslateTransform {}
 windows_core::imp::define_interface!(IDCompositionTranslateTransform3D, IDCompositionTranslateTransform3D_Vtbl, 0x91636d4b_9ba1_4532_aaf7_e3344994d788);
 impl core::ops::Deref for IDCompositionTranslateTransform3D {
@@ -5432,6 +8062,58 @@ impl IDCompositionTranslateTransform3D_Vtbl {
         iid == &<IDCompositionTranslateTransform3D as windows_core::Interface>::IID || iid == &<IDCompositionEffect as windows_core::Interface>::IID || iid == &<IDCompositionTransform3D as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionTranslateTransform3D_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionTranslateTransform3D_Vtbl
+where
+    IDCompositionTransform3D_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetOffsetX<Identity: windows_core::IUnknownImpl + IDCompositionTranslateTransform3D_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTranslateTransform3D_Impl::SetOffsetX(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetOffsetX2<Identity: windows_core::IUnknownImpl + IDCompositionTranslateTransform3D_Impl>(this: *mut core::ffi::c_void, offsetx: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTranslateTransform3D_Impl::SetOffsetX2(this, core::mem::transmute_copy(&offsetx)).into()
+            }
+        }
+        unsafe extern "system" fn SetOffsetY<Identity: windows_core::IUnknownImpl + IDCompositionTranslateTransform3D_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTranslateTransform3D_Impl::SetOffsetY(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetOffsetY2<Identity: windows_core::IUnknownImpl + IDCompositionTranslateTransform3D_Impl>(this: *mut core::ffi::c_void, offsety: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTranslateTransform3D_Impl::SetOffsetY2(this, core::mem::transmute_copy(&offsety)).into()
+            }
+        }
+        unsafe extern "system" fn SetOffsetZ<Identity: windows_core::IUnknownImpl + IDCompositionTranslateTransform3D_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTranslateTransform3D_Impl::SetOffsetZ(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetOffsetZ2<Identity: windows_core::IUnknownImpl + IDCompositionTranslateTransform3D_Impl>(this: *mut core::ffi::c_void, offsetz: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTranslateTransform3D_Impl::SetOffsetZ2(this, core::mem::transmute_copy(&offsetz)).into()
+            }
+        }
+        Self {
+            base__: <IDCompositionTransform3D_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetOffsetX: SetOffsetX::<Identity>,
+            SetOffsetX2: SetOffsetX2::<Identity>,
+            SetOffsetY: SetOffsetY::<Identity>,
+            SetOffsetY2: SetOffsetY2::<Identity>,
+            SetOffsetZ: SetOffsetZ::<Identity>,
+            SetOffsetZ2: SetOffsetZ2::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDCompositionTranslateTransform3D {}
 windows_core::imp::define_interface!(IDCompositionTurbulenceEffect, IDCompositionTurbulenceEffect_Vtbl, 0xa6a55bda_c09c_49f3_9193_a41922c89715);
 impl core::ops::Deref for IDCompositionTurbulenceEffect {
@@ -5551,6 +8233,66 @@ impl IDCompositionTurbulenceEffect_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDCompositionTurbulenceEffect_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionTurbulenceEffect_Vtbl
+where
+    IDCompositionFilterEffect_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetOffset<Identity: windows_core::IUnknownImpl + IDCompositionTurbulenceEffect_Impl>(this: *mut core::ffi::c_void, offset: *const windows_numerics::Vector2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTurbulenceEffect_Impl::SetOffset(this, core::mem::transmute_copy(&offset)).into()
+            }
+        }
+        unsafe extern "system" fn SetBaseFrequency<Identity: windows_core::IUnknownImpl + IDCompositionTurbulenceEffect_Impl>(this: *mut core::ffi::c_void, frequency: *const windows_numerics::Vector2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTurbulenceEffect_Impl::SetBaseFrequency(this, core::mem::transmute_copy(&frequency)).into()
+            }
+        }
+        unsafe extern "system" fn SetSize<Identity: windows_core::IUnknownImpl + IDCompositionTurbulenceEffect_Impl>(this: *mut core::ffi::c_void, size: *const windows_numerics::Vector2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTurbulenceEffect_Impl::SetSize(this, core::mem::transmute_copy(&size)).into()
+            }
+        }
+        unsafe extern "system" fn SetNumOctaves<Identity: windows_core::IUnknownImpl + IDCompositionTurbulenceEffect_Impl>(this: *mut core::ffi::c_void, numoctaves: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTurbulenceEffect_Impl::SetNumOctaves(this, core::mem::transmute_copy(&numoctaves)).into()
+            }
+        }
+        unsafe extern "system" fn SetSeed<Identity: windows_core::IUnknownImpl + IDCompositionTurbulenceEffect_Impl>(this: *mut core::ffi::c_void, seed: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTurbulenceEffect_Impl::SetSeed(this, core::mem::transmute_copy(&seed)).into()
+            }
+        }
+        unsafe extern "system" fn SetNoise<Identity: windows_core::IUnknownImpl + IDCompositionTurbulenceEffect_Impl>(this: *mut core::ffi::c_void, noise: super::Direct2D::Common::D2D1_TURBULENCE_NOISE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTurbulenceEffect_Impl::SetNoise(this, core::mem::transmute_copy(&noise)).into()
+            }
+        }
+        unsafe extern "system" fn SetStitchable<Identity: windows_core::IUnknownImpl + IDCompositionTurbulenceEffect_Impl>(this: *mut core::ffi::c_void, stitchable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionTurbulenceEffect_Impl::SetStitchable(this, core::mem::transmute_copy(&stitchable)).into()
+            }
+        }
+        Self {
+            base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetOffset: SetOffset::<Identity>,
+            SetBaseFrequency: SetBaseFrequency::<Identity>,
+            SetSize: SetSize::<Identity>,
+            SetNumOctaves: SetNumOctaves::<Identity>,
+            SetSeed: SetSeed::<Identity>,
+            SetNoise: SetNoise::<Identity>,
+            SetStitchable: SetStitchable::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
 impl windows_core::RuntimeName for IDCompositionTurbulenceEffect {}
 windows_core::imp::define_interface!(IDCompositionVirtualSurface, IDCompositionVirtualSurface_Vtbl, 0xae471c51_5f53_4a24_8d3e_d0c39c30b3f0);
 impl core::ops::Deref for IDCompositionVirtualSurface {
@@ -5599,6 +8341,26 @@ impl IDCompositionVirtualSurface_Vtbl {
         iid == &<IDCompositionVirtualSurface as windows_core::Interface>::IID || iid == &<IDCompositionSurface as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDCompositionVirtualSurface_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionVirtualSurface_Vtbl
+where
+    IDCompositionSurface_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Resize<Identity: windows_core::IUnknownImpl + IDCompositionVirtualSurface_Impl>(this: *mut core::ffi::c_void, width: u32, height: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVirtualSurface_Impl::Resize(this, core::mem::transmute_copy(&width), core::mem::transmute_copy(&height)).into()
+            }
+        }
+        unsafe extern "system" fn Trim<Identity: windows_core::IUnknownImpl + IDCompositionVirtualSurface_Impl>(this: *mut core::ffi::c_void, rectangles: *const super::super::Foundation::RECT, count: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVirtualSurface_Impl::Trim(this, core::mem::transmute_copy(&rectangles), core::mem::transmute_copy(&count)).into()
+            }
+        }
+        Self { base__: <IDCompositionSurface_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Resize: Resize::<Identity>, Trim: Trim::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDCompositionVirtualSurface {}
 windows_core::imp::define_interface!(IDCompositionVisual, IDCompositionVisual_Vtbl, 0x4d93059d_097b_4651_9a60_f0f25116e2f3);
 windows_core::imp::interface_hierarchy!(IDCompositionVisual, windows_core::IUnknown);
@@ -5860,6 +8622,136 @@ impl IDCompositionVisual_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDCompositionVisual_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionVisual_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetOffsetX<Identity: windows_core::IUnknownImpl + IDCompositionVisual_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual_Impl::SetOffsetX(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetOffsetX2<Identity: windows_core::IUnknownImpl + IDCompositionVisual_Impl>(this: *mut core::ffi::c_void, offsetx: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual_Impl::SetOffsetX2(this, core::mem::transmute_copy(&offsetx)).into()
+            }
+        }
+        unsafe extern "system" fn SetOffsetY<Identity: windows_core::IUnknownImpl + IDCompositionVisual_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual_Impl::SetOffsetY(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetOffsetY2<Identity: windows_core::IUnknownImpl + IDCompositionVisual_Impl>(this: *mut core::ffi::c_void, offsety: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual_Impl::SetOffsetY2(this, core::mem::transmute_copy(&offsety)).into()
+            }
+        }
+        unsafe extern "system" fn SetTransform<Identity: windows_core::IUnknownImpl + IDCompositionVisual_Impl>(this: *mut core::ffi::c_void, transform: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual_Impl::SetTransform(this, core::mem::transmute_copy(&transform)).into()
+            }
+        }
+        unsafe extern "system" fn SetTransform2<Identity: windows_core::IUnknownImpl + IDCompositionVisual_Impl>(this: *mut core::ffi::c_void, matrix: *const windows_numerics::Matrix3x2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual_Impl::SetTransform2(this, core::mem::transmute_copy(&matrix)).into()
+            }
+        }
+        unsafe extern "system" fn SetTransformParent<Identity: windows_core::IUnknownImpl + IDCompositionVisual_Impl>(this: *mut core::ffi::c_void, visual: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual_Impl::SetTransformParent(this, core::mem::transmute_copy(&visual)).into()
+            }
+        }
+        unsafe extern "system" fn SetEffect<Identity: windows_core::IUnknownImpl + IDCompositionVisual_Impl>(this: *mut core::ffi::c_void, effect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual_Impl::SetEffect(this, core::mem::transmute_copy(&effect)).into()
+            }
+        }
+        unsafe extern "system" fn SetBitmapInterpolationMode<Identity: windows_core::IUnknownImpl + IDCompositionVisual_Impl>(this: *mut core::ffi::c_void, interpolationmode: DCOMPOSITION_BITMAP_INTERPOLATION_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual_Impl::SetBitmapInterpolationMode(this, core::mem::transmute_copy(&interpolationmode)).into()
+            }
+        }
+        unsafe extern "system" fn SetBorderMode<Identity: windows_core::IUnknownImpl + IDCompositionVisual_Impl>(this: *mut core::ffi::c_void, bordermode: DCOMPOSITION_BORDER_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual_Impl::SetBorderMode(this, core::mem::transmute_copy(&bordermode)).into()
+            }
+        }
+        unsafe extern "system" fn SetClip<Identity: windows_core::IUnknownImpl + IDCompositionVisual_Impl>(this: *mut core::ffi::c_void, clip: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual_Impl::SetClip(this, core::mem::transmute_copy(&clip)).into()
+            }
+        }
+        unsafe extern "system" fn SetClip2<Identity: windows_core::IUnknownImpl + IDCompositionVisual_Impl>(this: *mut core::ffi::c_void, rect: *const super::Direct2D::Common::D2D_RECT_F) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual_Impl::SetClip2(this, core::mem::transmute_copy(&rect)).into()
+            }
+        }
+        unsafe extern "system" fn SetContent<Identity: windows_core::IUnknownImpl + IDCompositionVisual_Impl>(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual_Impl::SetContent(this, core::mem::transmute_copy(&content)).into()
+            }
+        }
+        unsafe extern "system" fn AddVisual<Identity: windows_core::IUnknownImpl + IDCompositionVisual_Impl>(this: *mut core::ffi::c_void, visual: *mut core::ffi::c_void, insertabove: windows_core::BOOL, referencevisual: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual_Impl::AddVisual(this, core::mem::transmute_copy(&visual), core::mem::transmute_copy(&insertabove), core::mem::transmute_copy(&referencevisual)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveVisual<Identity: windows_core::IUnknownImpl + IDCompositionVisual_Impl>(this: *mut core::ffi::c_void, visual: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual_Impl::RemoveVisual(this, core::mem::transmute_copy(&visual)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveAllVisuals<Identity: windows_core::IUnknownImpl + IDCompositionVisual_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual_Impl::RemoveAllVisuals(this).into()
+            }
+        }
+        unsafe extern "system" fn SetCompositeMode<Identity: windows_core::IUnknownImpl + IDCompositionVisual_Impl>(this: *mut core::ffi::c_void, compositemode: DCOMPOSITION_COMPOSITE_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual_Impl::SetCompositeMode(this, core::mem::transmute_copy(&compositemode)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetOffsetX: SetOffsetX::<Identity>,
+            SetOffsetX2: SetOffsetX2::<Identity>,
+            SetOffsetY: SetOffsetY::<Identity>,
+            SetOffsetY2: SetOffsetY2::<Identity>,
+            SetTransform: SetTransform::<Identity>,
+            SetTransform2: SetTransform2::<Identity>,
+            SetTransformParent: SetTransformParent::<Identity>,
+            SetEffect: SetEffect::<Identity>,
+            SetBitmapInterpolationMode: SetBitmapInterpolationMode::<Identity>,
+            SetBorderMode: SetBorderMode::<Identity>,
+            SetClip: SetClip::<Identity>,
+            SetClip2: SetClip2::<Identity>,
+            SetContent: SetContent::<Identity>,
+            AddVisual: AddVisual::<Identity>,
+            RemoveVisual: RemoveVisual::<Identity>,
+            RemoveAllVisuals: RemoveAllVisuals::<Identity>,
+            SetCompositeMode: SetCompositeMode::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
 impl windows_core::RuntimeName for IDCompositionVisual {}
 windows_core::imp::define_interface!(IDCompositionVisual2, IDCompositionVisual2_Vtbl, 0xe8de1639_4331_4b26_bc5f_6a321d347a85);
 impl core::ops::Deref for IDCompositionVisual2 {
@@ -5915,6 +8807,31 @@ impl IDCompositionVisual2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDCompositionVisual2_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionVisual2_Vtbl
+where
+    IDCompositionVisual_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetOpacityMode<Identity: windows_core::IUnknownImpl + IDCompositionVisual2_Impl>(this: *mut core::ffi::c_void, mode: DCOMPOSITION_OPACITY_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual2_Impl::SetOpacityMode(this, core::mem::transmute_copy(&mode)).into()
+            }
+        }
+        unsafe extern "system" fn SetBackFaceVisibility<Identity: windows_core::IUnknownImpl + IDCompositionVisual2_Impl>(this: *mut core::ffi::c_void, visibility: DCOMPOSITION_BACKFACE_VISIBILITY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual2_Impl::SetBackFaceVisibility(this, core::mem::transmute_copy(&visibility)).into()
+            }
+        }
+        Self {
+            base__: <IDCompositionVisual_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetOpacityMode: SetOpacityMode::<Identity>,
+            SetBackFaceVisibility: SetBackFaceVisibility::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
 impl windows_core::RuntimeName for IDCompositionVisual2 {}
 windows_core::imp::define_interface!(IDCompositionVisual3, IDCompositionVisual3_Vtbl, 0x2775f462_b6c1_4015_b0be_b3e7d6a4976d);
 impl core::ops::Deref for IDCompositionVisual3 {
@@ -6051,6 +8968,73 @@ impl IDCompositionVisual3_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDCompositionVisual3_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionVisual3_Vtbl
+where
+    IDCompositionVisualDebug_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetDepthMode<Identity: windows_core::IUnknownImpl + IDCompositionVisual3_Impl>(this: *mut core::ffi::c_void, mode: DCOMPOSITION_DEPTH_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual3_Impl::SetDepthMode(this, core::mem::transmute_copy(&mode)).into()
+            }
+        }
+        unsafe extern "system" fn SetOffsetZ<Identity: windows_core::IUnknownImpl + IDCompositionVisual3_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual3_Impl::SetOffsetZ(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetOffsetZ2<Identity: windows_core::IUnknownImpl + IDCompositionVisual3_Impl>(this: *mut core::ffi::c_void, offsetz: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual3_Impl::SetOffsetZ2(this, core::mem::transmute_copy(&offsetz)).into()
+            }
+        }
+        unsafe extern "system" fn SetOpacity<Identity: windows_core::IUnknownImpl + IDCompositionVisual3_Impl>(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual3_Impl::SetOpacity(this, core::mem::transmute_copy(&animation)).into()
+            }
+        }
+        unsafe extern "system" fn SetOpacity2<Identity: windows_core::IUnknownImpl + IDCompositionVisual3_Impl>(this: *mut core::ffi::c_void, opacity: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual3_Impl::SetOpacity2(this, core::mem::transmute_copy(&opacity)).into()
+            }
+        }
+        unsafe extern "system" fn SetTransform<Identity: windows_core::IUnknownImpl + IDCompositionVisual3_Impl>(this: *mut core::ffi::c_void, transform: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual3_Impl::SetTransform(this, core::mem::transmute_copy(&transform)).into()
+            }
+        }
+        unsafe extern "system" fn SetTransform2<Identity: windows_core::IUnknownImpl + IDCompositionVisual3_Impl>(this: *mut core::ffi::c_void, matrix: *const windows_numerics::Matrix4x4) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual3_Impl::SetTransform2(this, core::mem::transmute_copy(&matrix)).into()
+            }
+        }
+        unsafe extern "system" fn SetVisible<Identity: windows_core::IUnknownImpl + IDCompositionVisual3_Impl>(this: *mut core::ffi::c_void, visible: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisual3_Impl::SetVisible(this, core::mem::transmute_copy(&visible)).into()
+            }
+        }
+        Self {
+            base__: <IDCompositionVisualDebug_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetDepthMode: SetDepthMode::<Identity>,
+            SetOffsetZ: SetOffsetZ::<Identity>,
+            SetOffsetZ2: SetOffsetZ2::<Identity>,
+            SetOpacity: SetOpacity::<Identity>,
+            SetOpacity2: SetOpacity2::<Identity>,
+            SetTransform: SetTransform::<Identity>,
+            SetTransform2: SetTransform2::<Identity>,
+            SetVisible: SetVisible::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
 impl windows_core::RuntimeName for IDCompositionVisual3 {}
 windows_core::imp::define_interface!(IDCompositionVisualDebug, IDCompositionVisualDebug_Vtbl, 0xfed2b808_5eb4_43a0_aea3_35f65280f91b);
 impl core::ops::Deref for IDCompositionVisualDebug {
@@ -6134,4 +9118,43 @@ impl IDCompositionVisualDebug_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDCompositionVisualDebug_Impl> windows_core::imp::TearOffVtable<Identity> for IDCompositionVisualDebug_Vtbl
+where
+    IDCompositionVisual2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnableHeatMap<Identity: windows_core::IUnknownImpl + IDCompositionVisualDebug_Impl>(this: *mut core::ffi::c_void, color: *const super::Direct2D::Common::D2D1_COLOR_F) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisualDebug_Impl::EnableHeatMap(this, core::mem::transmute_copy(&color)).into()
+            }
+        }
+        unsafe extern "system" fn DisableHeatMap<Identity: windows_core::IUnknownImpl + IDCompositionVisualDebug_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisualDebug_Impl::DisableHeatMap(this).into()
+            }
+        }
+        unsafe extern "system" fn EnableRedrawRegions<Identity: windows_core::IUnknownImpl + IDCompositionVisualDebug_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisualDebug_Impl::EnableRedrawRegions(this).into()
+            }
+        }
+        unsafe extern "system" fn DisableRedrawRegions<Identity: windows_core::IUnknownImpl + IDCompositionVisualDebug_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDCompositionVisualDebug_Impl::DisableRedrawRegions(this).into()
+            }
+        }
+        Self {
+            base__: <IDCompositionVisual2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnableHeatMap: EnableHeatMap::<Identity>,
+            DisableHeatMap: DisableHeatMap::<Identity>,
+            EnableRedrawRegions: EnableRedrawRegions::<Identity>,
+            DisableRedrawRegions: DisableRedrawRegions::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
 impl windows_core::RuntimeName for IDCompositionVisualDebug {}
@@ -5861,6 +5861,44 @@ impl IDDVideoPortContainer_Vtbl {
         iid == &<IDDVideoPortContainer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDDVideoPortContainer_Impl> windows_core::imp::TearOffVtable<Identity> for IDDVideoPortContainer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateVideoPort<Identity: windows_core::IUnknownImpl + IDDVideoPortContainer_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDVIDEOPORTDESC, param2: *mut *mut core::ffi::c_void, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDDVideoPortContainer_Impl::CreateVideoPort(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn EnumVideoPorts<Identity: windows_core::IUnknownImpl + IDDVideoPortContainer_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDVIDEOPORTCAPS, param2: *mut core::ffi::c_void, param3: LPDDENUMVIDEOCALLBACK) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDDVideoPortContainer_Impl::EnumVideoPorts(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn GetVideoPortConnectInfo<Identity: windows_core::IUnknownImpl + IDDVideoPortContainer_Impl>(this: *mut core::ffi::c_void, param0: u32, pcinfo: *mut u32, param2: *mut DDVIDEOPORTCONNECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDDVideoPortContainer_Impl::GetVideoPortConnectInfo(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&pcinfo), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn QueryVideoPortStatus<Identity: windows_core::IUnknownImpl + IDDVideoPortContainer_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDVIDEOPORTSTATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDDVideoPortContainer_Impl::QueryVideoPortStatus(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateVideoPort: CreateVideoPort::<Identity>,
+            EnumVideoPorts: EnumVideoPorts::<Identity>,
+            GetVideoPortConnectInfo: GetVideoPortConnectInfo::<Identity>,
+            QueryVideoPortStatus: QueryVideoPortStatus::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDDVideoPortContainer {}
 windows_core::imp::define_interface!(IDirectDraw, IDirectDraw_Vtbl, 0x6c14db80_a733_11ce_a521_0020af0be560);
 windows_core::imp::interface_hierarchy!(IDirectDraw, windows_core::IUnknown);
@@ -6160,6 +6198,169 @@ impl IDirectDraw_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IDirectDraw_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectDraw_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Compact<Identity: windows_core::IUnknownImpl + IDirectDraw_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw_Impl::Compact(this).into()
+            }
+        }
+        unsafe extern "system" fn CreateClipper<Identity: windows_core::IUnknownImpl + IDirectDraw_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw_Impl::CreateClipper(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn CreatePalette<Identity: windows_core::IUnknownImpl + IDirectDraw_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut super::Gdi::PALETTEENTRY, param2: *mut *mut core::ffi::c_void, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw_Impl::CreatePalette(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn CreateSurface<Identity: windows_core::IUnknownImpl + IDirectDraw_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw_Impl::CreateSurface(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn DuplicateSurface<Identity: windows_core::IUnknownImpl + IDirectDraw_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectDraw_Impl::DuplicateSurface(this, core::mem::transmute_copy(&param0)) {
+                    Ok(ok__) => {
+                        param1.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumDisplayModes<Identity: windows_core::IUnknownImpl + IDirectDraw_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDSURFACEDESC, param2: *mut core::ffi::c_void, param3: LPDDENUMMODESCALLBACK) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw_Impl::EnumDisplayModes(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn EnumSurfaces<Identity: windows_core::IUnknownImpl + IDirectDraw_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDSURFACEDESC, param2: *mut core::ffi::c_void, param3: LPDDENUMSURFACESCALLBACK) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw_Impl::EnumSurfaces(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn FlipToGDISurface<Identity: windows_core::IUnknownImpl + IDirectDraw_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw_Impl::FlipToGDISurface(this).into()
+            }
+        }
+        unsafe extern "system" fn GetCaps<Identity: windows_core::IUnknownImpl + IDirectDraw_Impl>(this: *mut core::ffi::c_void, param0: *mut DDCAPS_DX7, param1: *mut DDCAPS_DX7) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw_Impl::GetCaps(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetDisplayMode<Identity: windows_core::IUnknownImpl + IDirectDraw_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw_Impl::GetDisplayMode(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetFourCCCodes<Identity: windows_core::IUnknownImpl + IDirectDraw_Impl>(this: *mut core::ffi::c_void, param0: *mut u32, param1: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw_Impl::GetFourCCCodes(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetGDISurface<Identity: windows_core::IUnknownImpl + IDirectDraw_Impl>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectDraw_Impl::GetGDISurface(this) {
+                    Ok(ok__) => {
+                        param0.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMonitorFrequency<Identity: windows_core::IUnknownImpl + IDirectDraw_Impl>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw_Impl::GetMonitorFrequency(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetScanLine<Identity: windows_core::IUnknownImpl + IDirectDraw_Impl>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw_Impl::GetScanLine(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetVerticalBlankStatus<Identity: windows_core::IUnknownImpl + IDirectDraw_Impl>(this: *mut core::ffi::c_void, param0: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw_Impl::GetVerticalBlankStatus(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectDraw_Impl>(this: *mut core::ffi::c_void, param0: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw_Impl::Initialize(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn RestoreDisplayMode<Identity: windows_core::IUnknownImpl + IDirectDraw_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw_Impl::RestoreDisplayMode(this).into()
+            }
+        }
+        unsafe extern "system" fn SetCooperativeLevel<Identity: windows_core::IUnknownImpl + IDirectDraw_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw_Impl::SetCooperativeLevel(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetDisplayMode<Identity: windows_core::IUnknownImpl + IDirectDraw_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw_Impl::SetDisplayMode(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn WaitForVerticalBlank<Identity: windows_core::IUnknownImpl + IDirectDraw_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw_Impl::WaitForVerticalBlank(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Compact: Compact::<Identity>,
+            CreateClipper: CreateClipper::<Identity>,
+            CreatePalette: CreatePalette::<Identity>,
+            CreateSurface: CreateSurface::<Identity>,
+            DuplicateSurface: DuplicateSurface::<Identity>,
+            EnumDisplayModes: EnumDisplayModes::<Identity>,
+            EnumSurfaces: EnumSurfaces::<Identity>,
+            FlipToGDISurface: FlipToGDISurface::<Identity>,
+            GetCaps: GetCaps::<Identity>,
+            GetDisplayMode: GetDisplayMode::<Identity>,
+            GetFourCCCodes: GetFourCCCodes::<Identity>,
+            GetGDISurface: GetGDISurface::<Identity>,
+            GetMonitorFrequency: GetMonitorFrequency::<Identity>,
+            GetScanLine: GetScanLine::<Identity>,
+            GetVerticalBlankStatus: GetVerticalBlankStatus::<Identity>,
+            Initialize: Initialize::<Identity>,
+            RestoreDisplayMode: RestoreDisplayMode::<Identity>,
+            SetCooperativeLevel: SetCooperativeLevel::<Identity>,
+            SetDisplayMode: SetDisplayMode::<Identity>,
+            WaitForVerticalBlank: WaitForVerticalBlank::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IDirectDraw {}
 windows_core::imp::define_interface!(IDirectDraw2, IDirectDraw2_Vtbl, 0xb3a6f3e0_2b43_11cf_a2de_00aa00b93356);
 windows_core::imp::interface_hierarchy!(IDirectDraw2, windows_core::IUnknown);
@@ -6471,6 +6672,176 @@ impl IDirectDraw2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IDirectDraw2_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectDraw2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Compact<Identity: windows_core::IUnknownImpl + IDirectDraw2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw2_Impl::Compact(this).into()
+            }
+        }
+        unsafe extern "system" fn CreateClipper<Identity: windows_core::IUnknownImpl + IDirectDraw2_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw2_Impl::CreateClipper(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn CreatePalette<Identity: windows_core::IUnknownImpl + IDirectDraw2_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut super::Gdi::PALETTEENTRY, param2: *mut *mut core::ffi::c_void, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw2_Impl::CreatePalette(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn CreateSurface<Identity: windows_core::IUnknownImpl + IDirectDraw2_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw2_Impl::CreateSurface(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn DuplicateSurface<Identity: windows_core::IUnknownImpl + IDirectDraw2_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectDraw2_Impl::DuplicateSurface(this, core::mem::transmute_copy(&param0)) {
+                    Ok(ok__) => {
+                        param1.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumDisplayModes<Identity: windows_core::IUnknownImpl + IDirectDraw2_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDSURFACEDESC, param2: *mut core::ffi::c_void, param3: LPDDENUMMODESCALLBACK) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw2_Impl::EnumDisplayModes(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn EnumSurfaces<Identity: windows_core::IUnknownImpl + IDirectDraw2_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDSURFACEDESC, param2: *mut core::ffi::c_void, param3: LPDDENUMSURFACESCALLBACK) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw2_Impl::EnumSurfaces(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn FlipToGDISurface<Identity: windows_core::IUnknownImpl + IDirectDraw2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw2_Impl::FlipToGDISurface(this).into()
+            }
+        }
+        unsafe extern "system" fn GetCaps<Identity: windows_core::IUnknownImpl + IDirectDraw2_Impl>(this: *mut core::ffi::c_void, param0: *mut DDCAPS_DX7, param1: *mut DDCAPS_DX7) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw2_Impl::GetCaps(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetDisplayMode<Identity: windows_core::IUnknownImpl + IDirectDraw2_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw2_Impl::GetDisplayMode(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetFourCCCodes<Identity: windows_core::IUnknownImpl + IDirectDraw2_Impl>(this: *mut core::ffi::c_void, param0: *mut u32, param1: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw2_Impl::GetFourCCCodes(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetGDISurface<Identity: windows_core::IUnknownImpl + IDirectDraw2_Impl>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectDraw2_Impl::GetGDISurface(this) {
+                    Ok(ok__) => {
+                        param0.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMonitorFrequency<Identity: windows_core::IUnknownImpl + IDirectDraw2_Impl>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw2_Impl::GetMonitorFrequency(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetScanLine<Identity: windows_core::IUnknownImpl + IDirectDraw2_Impl>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw2_Impl::GetScanLine(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetVerticalBlankStatus<Identity: windows_core::IUnknownImpl + IDirectDraw2_Impl>(this: *mut core::ffi::c_void, param0: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw2_Impl::GetVerticalBlankStatus(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectDraw2_Impl>(this: *mut core::ffi::c_void, param0: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw2_Impl::Initialize(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn RestoreDisplayMode<Identity: windows_core::IUnknownImpl + IDirectDraw2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw2_Impl::RestoreDisplayMode(this).into()
+            }
+        }
+        unsafe extern "system" fn SetCooperativeLevel<Identity: windows_core::IUnknownImpl + IDirectDraw2_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw2_Impl::SetCooperativeLevel(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetDisplayMode<Identity: windows_core::IUnknownImpl + IDirectDraw2_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: u32, param3: u32, param4: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw2_Impl::SetDisplayMode(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
+            }
+        }
+        unsafe extern "system" fn WaitForVerticalBlank<Identity: windows_core::IUnknownImpl + IDirectDraw2_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw2_Impl::WaitForVerticalBlank(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetAvailableVidMem<Identity: windows_core::IUnknownImpl + IDirectDraw2_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSCAPS, param1: *mut u32, param2: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw2_Impl::GetAvailableVidMem(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Compact: Compact::<Identity>,
+            CreateClipper: CreateClipper::<Identity>,
+            CreatePalette: CreatePalette::<Identity>,
+            CreateSurface: CreateSurface::<Identity>,
+            DuplicateSurface: DuplicateSurface::<Identity>,
+            EnumDisplayModes: EnumDisplayModes::<Identity>,
+            EnumSurfaces: EnumSurfaces::<Identity>,
+            FlipToGDISurface: FlipToGDISurface::<Identity>,
+            GetCaps: GetCaps::<Identity>,
+            GetDisplayMode: GetDisplayMode::<Identity>,
+            GetFourCCCodes: GetFourCCCodes::<Identity>,
+            GetGDISurface: GetGDISurface::<Identity>,
+            GetMonitorFrequency: GetMonitorFrequency::<Identity>,
+            GetScanLine: GetScanLine::<Identity>,
+            GetVerticalBlankStatus: GetVerticalBlankStatus::<Identity>,
+            Initialize: Initialize::<Identity>,
+            RestoreDisplayMode: RestoreDisplayMode::<Identity>,
+            SetCooperativeLevel: SetCooperativeLevel::<Identity>,
+            SetDisplayMode: SetDisplayMode::<Identity>,
+            WaitForVerticalBlank: WaitForVerticalBlank::<Identity>,
+            GetAvailableVidMem: GetAvailableVidMem::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IDirectDraw2 {}
 windows_core::imp::define_interface!(IDirectDraw4, IDirectDraw4_Vtbl, 0x9c59509a_39bd_11d1_8c4a_00c04fd930c5);
 windows_core::imp::interface_hierarchy!(IDirectDraw4, windows_core::IUnknown);
@@ -6843,43 +7214,247 @@ impl IDirectDraw4_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
-impl windows_core::RuntimeName for IDirectDraw4 {}
-windows_core::imp::define_interface!(IDirectDraw7, IDirectDraw7_Vtbl, 0x15e65ec0_3b9c_11d2_b92f_00609797ea5b);
-windows_core::imp::interface_hierarchy!(IDirectDraw7, windows_core::IUnknown);
-impl IDirectDraw7 {
-    pub unsafe fn Compact(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Compact)(windows_core::Interface::as_raw(self)).ok() }
-    }
-    pub unsafe fn CreateClipper<P2>(&self, param0: u32, param1: *mut Option<IDirectDrawClipper>, param2: P2) -> windows_core::Result<()>
-    where
-        P2: windows_core::Param<windows_core::IUnknown>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).CreateClipper)(windows_core::Interface::as_raw(self), param0, core::mem::transmute(param1), param2.param().abi()).ok() }
-    }
-    #[cfg(feature = "Win32_Graphics_Gdi")]
-    pub unsafe fn CreatePalette<P3>(&self, param0: u32, param1: *mut super::Gdi::PALETTEENTRY, param2: *mut Option<IDirectDrawPalette>, param3: P3) -> windows_core::Result<()>
-    where
-        P3: windows_core::Param<windows_core::IUnknown>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).CreatePalette)(windows_core::Interface::as_raw(self), param0, param1 as _, core::mem::transmute(param2), param3.param().abi()).ok() }
-    }
-    pub unsafe fn CreateSurface<P2>(&self, param0: *mut DDSURFACEDESC2, param1: *mut Option<IDirectDrawSurface7>, param2: P2) -> windows_core::Result<()>
-    where
-        P2: windows_core::Param<windows_core::IUnknown>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).CreateSurface)(windows_core::Interface::as_raw(self), param0 as _, core::mem::transmute(param1), param2.param().abi()).ok() }
-    }
-    pub unsafe fn DuplicateSurface<P0>(&self, param0: P0) -> windows_core::Result<IDirectDrawSurface7>
-    where
-        P0: windows_core::Param<IDirectDrawSurface7>,
-    {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).DuplicateSurface)(windows_core::Interface::as_raw(self), param0.param().abi(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn EnumDisplayModes(&self, param0: u32, param1: *mut DDSURFACEDESC2, param2: *mut core::ffi::c_void, param3: LPDDENUMMODESCALLBACK2) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).EnumDisplayModes)(windows_core::Interface::as_raw(self), param0, param1 as _, param2 as _, param3).ok() }
+impl<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectDraw4_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Compact<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw4_Impl::Compact(this).into()
+            }
+        }
+        unsafe extern "system" fn CreateClipper<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw4_Impl::CreateClipper(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn CreatePalette<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut super::Gdi::PALETTEENTRY, param2: *mut *mut core::ffi::c_void, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw4_Impl::CreatePalette(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn CreateSurface<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC2, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw4_Impl::CreateSurface(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn DuplicateSurface<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectDraw4_Impl::DuplicateSurface(this, core::mem::transmute_copy(&param0)) {
+                    Ok(ok__) => {
+                        param1.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumDisplayModes<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDSURFACEDESC2, param2: *mut core::ffi::c_void, param3: LPDDENUMMODESCALLBACK2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw4_Impl::EnumDisplayModes(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn EnumSurfaces<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDSURFACEDESC2, param2: *mut core::ffi::c_void, param3: LPDDENUMSURFACESCALLBACK2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw4_Impl::EnumSurfaces(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn FlipToGDISurface<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw4_Impl::FlipToGDISurface(this).into()
+            }
+        }
+        unsafe extern "system" fn GetCaps<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void, param0: *mut DDCAPS_DX7, param1: *mut DDCAPS_DX7) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw4_Impl::GetCaps(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetDisplayMode<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw4_Impl::GetDisplayMode(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetFourCCCodes<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void, param0: *mut u32, param1: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw4_Impl::GetFourCCCodes(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetGDISurface<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectDraw4_Impl::GetGDISurface(this) {
+                    Ok(ok__) => {
+                        param0.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMonitorFrequency<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw4_Impl::GetMonitorFrequency(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetScanLine<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw4_Impl::GetScanLine(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetVerticalBlankStatus<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void, param0: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw4_Impl::GetVerticalBlankStatus(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void, param0: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw4_Impl::Initialize(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn RestoreDisplayMode<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw4_Impl::RestoreDisplayMode(this).into()
+            }
+        }
+        unsafe extern "system" fn SetCooperativeLevel<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw4_Impl::SetCooperativeLevel(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetDisplayMode<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: u32, param3: u32, param4: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw4_Impl::SetDisplayMode(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
+            }
+        }
+        unsafe extern "system" fn WaitForVerticalBlank<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw4_Impl::WaitForVerticalBlank(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetAvailableVidMem<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSCAPS2, param1: *mut u32, param2: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw4_Impl::GetAvailableVidMem(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn GetSurfaceFromDC<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void, param0: super::Gdi::HDC, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectDraw4_Impl::GetSurfaceFromDC(this, core::mem::transmute_copy(&param0)) {
+                    Ok(ok__) => {
+                        param1.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RestoreAllSurfaces<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw4_Impl::RestoreAllSurfaces(this).into()
+            }
+        }
+        unsafe extern "system" fn TestCooperativeLevel<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw4_Impl::TestCooperativeLevel(this).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceIdentifier<Identity: windows_core::IUnknownImpl + IDirectDraw4_Impl>(this: *mut core::ffi::c_void, param0: *mut DDDEVICEIDENTIFIER, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw4_Impl::GetDeviceIdentifier(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Compact: Compact::<Identity>,
+            CreateClipper: CreateClipper::<Identity>,
+            CreatePalette: CreatePalette::<Identity>,
+            CreateSurface: CreateSurface::<Identity>,
+            DuplicateSurface: DuplicateSurface::<Identity>,
+            EnumDisplayModes: EnumDisplayModes::<Identity>,
+            EnumSurfaces: EnumSurfaces::<Identity>,
+            FlipToGDISurface: FlipToGDISurface::<Identity>,
+            GetCaps: GetCaps::<Identity>,
+            GetDisplayMode: GetDisplayMode::<Identity>,
+            GetFourCCCodes: GetFourCCCodes::<Identity>,
+            GetGDISurface: GetGDISurface::<Identity>,
+            GetMonitorFrequency: GetMonitorFrequency::<Identity>,
+            GetScanLine: GetScanLine::<Identity>,
+            GetVerticalBlankStatus: GetVerticalBlankStatus::<Identity>,
+            Initialize: Initialize::<Identity>,
+            RestoreDisplayMode: RestoreDisplayMode::<Identity>,
+            SetCooperativeLevel: SetCooperativeLevel::<Identity>,
+            SetDisplayMode: SetDisplayMode::<Identity>,
+            WaitForVerticalBlank: WaitForVerticalBlank::<Identity>,
+            GetAvailableVidMem: GetAvailableVidMem::<Identity>,
+            GetSurfaceFromDC: GetSurfaceFromDC::<Identity>,
+            RestoreAllSurfaces: RestoreAllSurfaces::<Identity>,
+            TestCooperativeLevel: TestCooperativeLevel::<Identity>,
+            GetDeviceIdentifier: GetDeviceIdentifier::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::RuntimeName for IDirectDraw4 {}
+windows_core::imp::define_interface!(IDirectDraw7, IDirectDraw7_Vtbl, 0x15e65ec0_3b9c_11d2_b92f_00609797ea5b);
+windows_core::imp::interface_hierarchy!(IDirectDraw7, windows_core::IUnknown);
+impl IDirectDraw7 {
+    pub unsafe fn Compact(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Compact)(windows_core::Interface::as_raw(self)).ok() }
+    }
+    pub unsafe fn CreateClipper<P2>(&self, param0: u32, param1: *mut Option<IDirectDrawClipper>, param2: P2) -> windows_core::Result<()>
+    where
+        P2: windows_core::Param<windows_core::IUnknown>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).CreateClipper)(windows_core::Interface::as_raw(self), param0, core::mem::transmute(param1), param2.param().abi()).ok() }
+    }
+    #[cfg(feature = "Win32_Graphics_Gdi")]
+    pub unsafe fn CreatePalette<P3>(&self, param0: u32, param1: *mut super::Gdi::PALETTEENTRY, param2: *mut Option<IDirectDrawPalette>, param3: P3) -> windows_core::Result<()>
+    where
+        P3: windows_core::Param<windows_core::IUnknown>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).CreatePalette)(windows_core::Interface::as_raw(self), param0, param1 as _, core::mem::transmute(param2), param3.param().abi()).ok() }
+    }
+    pub unsafe fn CreateSurface<P2>(&self, param0: *mut DDSURFACEDESC2, param1: *mut Option<IDirectDrawSurface7>, param2: P2) -> windows_core::Result<()>
+    where
+        P2: windows_core::Param<windows_core::IUnknown>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).CreateSurface)(windows_core::Interface::as_raw(self), param0 as _, core::mem::transmute(param1), param2.param().abi()).ok() }
+    }
+    pub unsafe fn DuplicateSurface<P0>(&self, param0: P0) -> windows_core::Result<IDirectDrawSurface7>
+    where
+        P0: windows_core::Param<IDirectDrawSurface7>,
+    {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).DuplicateSurface)(windows_core::Interface::as_raw(self), param0.param().abi(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn EnumDisplayModes(&self, param0: u32, param1: *mut DDSURFACEDESC2, param2: *mut core::ffi::c_void, param3: LPDDENUMMODESCALLBACK2) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).EnumDisplayModes)(windows_core::Interface::as_raw(self), param0, param1 as _, param2 as _, param3).ok() }
     }
     pub unsafe fn EnumSurfaces(&self, param0: u32, param1: *mut DDSURFACEDESC2, param2: *mut core::ffi::c_void, param3: LPDDENUMSURFACESCALLBACK7) -> windows_core::Result<()> {
         unsafe { (windows_core::Interface::vtable(self).EnumSurfaces)(windows_core::Interface::as_raw(self), param0, param1 as _, param2 as _, param3).ok() }
@@ -7239,106 +7814,324 @@ impl IDirectDraw7_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
-impl windows_core::RuntimeName for IDirectDraw7 {}
-windows_core::imp::define_interface!(IDirectDrawClipper, IDirectDrawClipper_Vtbl, 0x6c14db85_a733_11ce_a521_0020af0be560);
-windows_core::imp::interface_hierarchy!(IDirectDrawClipper, windows_core::IUnknown);
-impl IDirectDrawClipper {
-    #[cfg(feature = "Win32_Graphics_Gdi")]
-    pub unsafe fn GetClipList(&self, param0: *mut super::super::Foundation::RECT, param1: *mut super::Gdi::RGNDATA, param2: *mut u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetClipList)(windows_core::Interface::as_raw(self), param0 as _, param1 as _, param2 as _).ok() }
-    }
-    pub unsafe fn GetHWnd(&self, param0: *mut super::super::Foundation::HWND) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetHWnd)(windows_core::Interface::as_raw(self), param0 as _).ok() }
-    }
-    pub unsafe fn Initialize<P0>(&self, param0: P0, param1: u32) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<IDirectDraw>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).Initialize)(windows_core::Interface::as_raw(self), param0.param().abi(), param1).ok() }
-    }
-    pub unsafe fn IsClipListChanged(&self, param0: *mut windows_core::BOOL) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).IsClipListChanged)(windows_core::Interface::as_raw(self), param0 as _).ok() }
-    }
-    #[cfg(feature = "Win32_Graphics_Gdi")]
-    pub unsafe fn SetClipList(&self, param0: *mut super::Gdi::RGNDATA, param1: u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetClipList)(windows_core::Interface::as_raw(self), param0 as _, param1).ok() }
-    }
-    pub unsafe fn SetHWnd(&self, param0: u32, param1: super::super::Foundation::HWND) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetHWnd)(windows_core::Interface::as_raw(self), param0, param1).ok() }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IDirectDrawClipper_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    #[cfg(feature = "Win32_Graphics_Gdi")]
-    pub GetClipList: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::Foundation::RECT, *mut super::Gdi::RGNDATA, *mut u32) -> windows_core::HRESULT,
-    #[cfg(not(feature = "Win32_Graphics_Gdi"))]
-    GetClipList: usize,
-    pub GetHWnd: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::Foundation::HWND) -> windows_core::HRESULT,
-    pub Initialize: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, u32) -> windows_core::HRESULT,
-    pub IsClipListChanged: unsafe extern "system" fn(*mut core::ffi::c_void, *mut windows_core::BOOL) -> windows_core::HRESULT,
-    #[cfg(feature = "Win32_Graphics_Gdi")]
-    pub SetClipList: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::Gdi::RGNDATA, u32) -> windows_core::HRESULT,
-    #[cfg(not(feature = "Win32_Graphics_Gdi"))]
-    SetClipList: usize,
-    pub SetHWnd: unsafe extern "system" fn(*mut core::ffi::c_void, u32, super::super::Foundation::HWND) -> windows_core::HRESULT,
-}
-#[cfg(feature = "Win32_Graphics_Gdi")]
-pub trait IDirectDrawClipper_Impl: windows_core::IUnknownImpl {
-    fn GetClipList(&self, param0: *mut super::super::Foundation::RECT, param1: *mut super::Gdi::RGNDATA, param2: *mut u32) -> windows_core::Result<()>;
-    fn GetHWnd(&self, param0: *mut super::super::Foundation::HWND) -> windows_core::Result<()>;
-    fn Initialize(&self, param0: windows_core::Ref<IDirectDraw>, param1: u32) -> windows_core::Result<()>;
-    fn IsClipListChanged(&self, param0: *mut windows_core::BOOL) -> windows_core::Result<()>;
-    fn SetClipList(&self, param0: *mut super::Gdi::RGNDATA, param1: u32) -> windows_core::Result<()>;
-    fn SetHWnd(&self, param0: u32, param1: super::super::Foundation::HWND) -> windows_core::Result<()>;
-}
-#[cfg(feature = "Win32_Graphics_Gdi")]
-impl IDirectDrawClipper_Vtbl {
-    pub const fn new<Identity: IDirectDrawClipper_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn GetClipList<Identity: IDirectDrawClipper_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut super::Gdi::RGNDATA, param2: *mut u32) -> windows_core::HRESULT {
+impl<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectDraw7_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Compact<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IDirectDrawClipper_Impl::GetClipList(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::Compact(this).into()
             }
         }
-        unsafe extern "system" fn GetHWnd<Identity: IDirectDrawClipper_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateClipper<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IDirectDrawClipper_Impl::GetHWnd(this, core::mem::transmute_copy(&param0)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::CreateClipper(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
             }
         }
-        unsafe extern "system" fn Initialize<Identity: IDirectDrawClipper_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreatePalette<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut super::Gdi::PALETTEENTRY, param2: *mut *mut core::ffi::c_void, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IDirectDrawClipper_Impl::Initialize(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::CreatePalette(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
             }
         }
-        unsafe extern "system" fn IsClipListChanged<Identity: IDirectDrawClipper_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateSurface<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC2, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IDirectDrawClipper_Impl::IsClipListChanged(this, core::mem::transmute_copy(&param0)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::CreateSurface(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
             }
         }
-        unsafe extern "system" fn SetClipList<Identity: IDirectDrawClipper_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut super::Gdi::RGNDATA, param1: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn DuplicateSurface<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IDirectDrawClipper_Impl::SetClipList(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectDraw7_Impl::DuplicateSurface(this, core::mem::transmute_copy(&param0)) {
+                    Ok(ok__) => {
+                        param1.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
             }
         }
-        unsafe extern "system" fn SetHWnd<Identity: IDirectDrawClipper_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32, param1: super::super::Foundation::HWND) -> windows_core::HRESULT {
+        unsafe extern "system" fn EnumDisplayModes<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDSURFACEDESC2, param2: *mut core::ffi::c_void, param3: LPDDENUMMODESCALLBACK2) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IDirectDrawClipper_Impl::SetHWnd(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::EnumDisplayModes(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
             }
         }
-        Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            GetClipList: GetClipList::<Identity, OFFSET>,
-            GetHWnd: GetHWnd::<Identity, OFFSET>,
-            Initialize: Initialize::<Identity, OFFSET>,
-            IsClipListChanged: IsClipListChanged::<Identity, OFFSET>,
-            SetClipList: SetClipList::<Identity, OFFSET>,
+        unsafe extern "system" fn EnumSurfaces<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDSURFACEDESC2, param2: *mut core::ffi::c_void, param3: LPDDENUMSURFACESCALLBACK7) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::EnumSurfaces(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn FlipToGDISurface<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::FlipToGDISurface(this).into()
+            }
+        }
+        unsafe extern "system" fn GetCaps<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void, param0: *mut DDCAPS_DX7, param1: *mut DDCAPS_DX7) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::GetCaps(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetDisplayMode<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::GetDisplayMode(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetFourCCCodes<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void, param0: *mut u32, param1: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::GetFourCCCodes(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetGDISurface<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectDraw7_Impl::GetGDISurface(this) {
+                    Ok(ok__) => {
+                        param0.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMonitorFrequency<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::GetMonitorFrequency(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetScanLine<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::GetScanLine(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetVerticalBlankStatus<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void, param0: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::GetVerticalBlankStatus(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void, param0: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::Initialize(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn RestoreDisplayMode<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::RestoreDisplayMode(this).into()
+            }
+        }
+        unsafe extern "system" fn SetCooperativeLevel<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::SetCooperativeLevel(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetDisplayMode<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: u32, param3: u32, param4: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::SetDisplayMode(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
+            }
+        }
+        unsafe extern "system" fn WaitForVerticalBlank<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::WaitForVerticalBlank(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetAvailableVidMem<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSCAPS2, param1: *mut u32, param2: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::GetAvailableVidMem(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn GetSurfaceFromDC<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void, param0: super::Gdi::HDC, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectDraw7_Impl::GetSurfaceFromDC(this, core::mem::transmute_copy(&param0)) {
+                    Ok(ok__) => {
+                        param1.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RestoreAllSurfaces<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::RestoreAllSurfaces(this).into()
+            }
+        }
+        unsafe extern "system" fn TestCooperativeLevel<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::TestCooperativeLevel(this).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceIdentifier<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void, param0: *mut DDDEVICEIDENTIFIER2, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::GetDeviceIdentifier(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn StartModeTest<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::SIZE, param1: u32, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::StartModeTest(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn EvaluateMode<Identity: windows_core::IUnknownImpl + IDirectDraw7_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDraw7_Impl::EvaluateMode(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Compact: Compact::<Identity>,
+            CreateClipper: CreateClipper::<Identity>,
+            CreatePalette: CreatePalette::<Identity>,
+            CreateSurface: CreateSurface::<Identity>,
+            DuplicateSurface: DuplicateSurface::<Identity>,
+            EnumDisplayModes: EnumDisplayModes::<Identity>,
+            EnumSurfaces: EnumSurfaces::<Identity>,
+            FlipToGDISurface: FlipToGDISurface::<Identity>,
+            GetCaps: GetCaps::<Identity>,
+            GetDisplayMode: GetDisplayMode::<Identity>,
+            GetFourCCCodes: GetFourCCCodes::<Identity>,
+            GetGDISurface: GetGDISurface::<Identity>,
+            GetMonitorFrequency: GetMonitorFrequency::<Identity>,
+            GetScanLine: GetScanLine::<Identity>,
+            GetVerticalBlankStatus: GetVerticalBlankStatus::<Identity>,
+            Initialize: Initialize::<Identity>,
+            RestoreDisplayMode: RestoreDisplayMode::<Identity>,
+            SetCooperativeLevel: SetCooperativeLevel::<Identity>,
+            SetDisplayMode: SetDisplayMode::<Identity>,
+            WaitForVerticalBlank: WaitForVerticalBlank::<Identity>,
+            GetAvailableVidMem: GetAvailableVidMem::<Identity>,
+            GetSurfaceFromDC: GetSurfaceFromDC::<Identity>,
+            RestoreAllSurfaces: RestoreAllSurfaces::<Identity>,
+            TestCooperativeLevel: TestCooperativeLevel::<Identity>,
+            GetDeviceIdentifier: GetDeviceIdentifier::<Identity>,
+            StartModeTest: StartModeTest::<Identity>,
+            EvaluateMode: EvaluateMode::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::RuntimeName for IDirectDraw7 {}
+windows_core::imp::define_interface!(IDirectDrawClipper, IDirectDrawClipper_Vtbl, 0x6c14db85_a733_11ce_a521_0020af0be560);
+windows_core::imp::interface_hierarchy!(IDirectDrawClipper, windows_core::IUnknown);
+impl IDirectDrawClipper {
+    #[cfg(feature = "Win32_Graphics_Gdi")]
+    pub unsafe fn GetClipList(&self, param0: *mut super::super::Foundation::RECT, param1: *mut super::Gdi::RGNDATA, param2: *mut u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetClipList)(windows_core::Interface::as_raw(self), param0 as _, param1 as _, param2 as _).ok() }
+    }
+    pub unsafe fn GetHWnd(&self, param0: *mut super::super::Foundation::HWND) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetHWnd)(windows_core::Interface::as_raw(self), param0 as _).ok() }
+    }
+    pub unsafe fn Initialize<P0>(&self, param0: P0, param1: u32) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<IDirectDraw>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).Initialize)(windows_core::Interface::as_raw(self), param0.param().abi(), param1).ok() }
+    }
+    pub unsafe fn IsClipListChanged(&self, param0: *mut windows_core::BOOL) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).IsClipListChanged)(windows_core::Interface::as_raw(self), param0 as _).ok() }
+    }
+    #[cfg(feature = "Win32_Graphics_Gdi")]
+    pub unsafe fn SetClipList(&self, param0: *mut super::Gdi::RGNDATA, param1: u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetClipList)(windows_core::Interface::as_raw(self), param0 as _, param1).ok() }
+    }
+    pub unsafe fn SetHWnd(&self, param0: u32, param1: super::super::Foundation::HWND) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetHWnd)(windows_core::Interface::as_raw(self), param0, param1).ok() }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IDirectDrawClipper_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    #[cfg(feature = "Win32_Graphics_Gdi")]
+    pub GetClipList: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::Foundation::RECT, *mut super::Gdi::RGNDATA, *mut u32) -> windows_core::HRESULT,
+    #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+    GetClipList: usize,
+    pub GetHWnd: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::Foundation::HWND) -> windows_core::HRESULT,
+    pub Initialize: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, u32) -> windows_core::HRESULT,
+    pub IsClipListChanged: unsafe extern "system" fn(*mut core::ffi::c_void, *mut windows_core::BOOL) -> windows_core::HRESULT,
+    #[cfg(feature = "Win32_Graphics_Gdi")]
+    pub SetClipList: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::Gdi::RGNDATA, u32) -> windows_core::HRESULT,
+    #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+    SetClipList: usize,
+    pub SetHWnd: unsafe extern "system" fn(*mut core::ffi::c_void, u32, super::super::Foundation::HWND) -> windows_core::HRESULT,
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+pub trait IDirectDrawClipper_Impl: windows_core::IUnknownImpl {
+    fn GetClipList(&self, param0: *mut super::super::Foundation::RECT, param1: *mut super::Gdi::RGNDATA, param2: *mut u32) -> windows_core::Result<()>;
+    fn GetHWnd(&self, param0: *mut super::super::Foundation::HWND) -> windows_core::Result<()>;
+    fn Initialize(&self, param0: windows_core::Ref<IDirectDraw>, param1: u32) -> windows_core::Result<()>;
+    fn IsClipListChanged(&self, param0: *mut windows_core::BOOL) -> windows_core::Result<()>;
+    fn SetClipList(&self, param0: *mut super::Gdi::RGNDATA, param1: u32) -> windows_core::Result<()>;
+    fn SetHWnd(&self, param0: u32, param1: super::super::Foundation::HWND) -> windows_core::Result<()>;
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl IDirectDrawClipper_Vtbl {
+    pub const fn new<Identity: IDirectDrawClipper_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn GetClipList<Identity: IDirectDrawClipper_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut super::Gdi::RGNDATA, param2: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawClipper_Impl::GetClipList(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn GetHWnd<Identity: IDirectDrawClipper_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawClipper_Impl::GetHWnd(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: IDirectDrawClipper_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawClipper_Impl::Initialize(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn IsClipListChanged<Identity: IDirectDrawClipper_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawClipper_Impl::IsClipListChanged(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SetClipList<Identity: IDirectDrawClipper_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut super::Gdi::RGNDATA, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawClipper_Impl::SetClipList(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetHWnd<Identity: IDirectDrawClipper_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32, param1: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawClipper_Impl::SetHWnd(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            GetClipList: GetClipList::<Identity, OFFSET>,
+            GetHWnd: GetHWnd::<Identity, OFFSET>,
+            Initialize: Initialize::<Identity, OFFSET>,
+            IsClipListChanged: IsClipListChanged::<Identity, OFFSET>,
+            SetClipList: SetClipList::<Identity, OFFSET>,
             SetHWnd: SetHWnd::<Identity, OFFSET>,
         }
     }
@@ -7347,6 +8140,59 @@ impl IDirectDrawClipper_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IDirectDrawClipper_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectDrawClipper_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetClipList<Identity: windows_core::IUnknownImpl + IDirectDrawClipper_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut super::Gdi::RGNDATA, param2: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawClipper_Impl::GetClipList(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn GetHWnd<Identity: windows_core::IUnknownImpl + IDirectDrawClipper_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawClipper_Impl::GetHWnd(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectDrawClipper_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawClipper_Impl::Initialize(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn IsClipListChanged<Identity: windows_core::IUnknownImpl + IDirectDrawClipper_Impl>(this: *mut core::ffi::c_void, param0: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawClipper_Impl::IsClipListChanged(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SetClipList<Identity: windows_core::IUnknownImpl + IDirectDrawClipper_Impl>(this: *mut core::ffi::c_void, param0: *mut super::Gdi::RGNDATA, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawClipper_Impl::SetClipList(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetHWnd<Identity: windows_core::IUnknownImpl + IDirectDrawClipper_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawClipper_Impl::SetHWnd(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetClipList: GetClipList::<Identity>,
+            GetHWnd: GetHWnd::<Identity>,
+            Initialize: Initialize::<Identity>,
+            IsClipListChanged: IsClipListChanged::<Identity>,
+            SetClipList: SetClipList::<Identity>,
+            SetHWnd: SetHWnd::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IDirectDrawClipper {}
 windows_core::imp::define_interface!(IDirectDrawColorControl, IDirectDrawColorControl_Vtbl, 0x4b9f0ee0_0d7e_11d0_9b06_00a0c903a3b8);
 windows_core::imp::interface_hierarchy!(IDirectDrawColorControl, windows_core::IUnknown);
@@ -7393,6 +8239,30 @@ impl IDirectDrawColorControl_Vtbl {
         iid == &<IDirectDrawColorControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectDrawColorControl_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectDrawColorControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetColorControls<Identity: windows_core::IUnknownImpl + IDirectDrawColorControl_Impl>(this: *mut core::ffi::c_void, param0: *mut DDCOLORCONTROL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawColorControl_Impl::GetColorControls(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SetColorControls<Identity: windows_core::IUnknownImpl + IDirectDrawColorControl_Impl>(this: *mut core::ffi::c_void, param0: *mut DDCOLORCONTROL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawColorControl_Impl::SetColorControls(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetColorControls: GetColorControls::<Identity>,
+            SetColorControls: SetColorControls::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectDrawColorControl {}
 windows_core::imp::define_interface!(IDirectDrawGammaControl, IDirectDrawGammaControl_Vtbl, 0x69c11c3e_b46b_11d1_ad7a_00c04fc29b4e);
 windows_core::imp::interface_hierarchy!(IDirectDrawGammaControl, windows_core::IUnknown);
@@ -7439,6 +8309,30 @@ impl IDirectDrawGammaControl_Vtbl {
         iid == &<IDirectDrawGammaControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectDrawGammaControl_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectDrawGammaControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetGammaRamp<Identity: windows_core::IUnknownImpl + IDirectDrawGammaControl_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDGAMMARAMP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawGammaControl_Impl::GetGammaRamp(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetGammaRamp<Identity: windows_core::IUnknownImpl + IDirectDrawGammaControl_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDGAMMARAMP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawGammaControl_Impl::SetGammaRamp(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetGammaRamp: GetGammaRamp::<Identity>,
+            SetGammaRamp: SetGammaRamp::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectDrawGammaControl {}
 windows_core::imp::define_interface!(IDirectDrawKernel, IDirectDrawKernel_Vtbl, 0x8d56c120_6a08_11d0_9b06_00a0c903a3b8);
 windows_core::imp::interface_hierarchy!(IDirectDrawKernel, windows_core::IUnknown);
@@ -7497,7 +8391,38 @@ impl IDirectDrawKernel_Vtbl {
         iid == &<IDirectDrawKernel as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IDirectDrawKernel {}
+impl<Identity: windows_core::IUnknownImpl + IDirectDrawKernel_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectDrawKernel_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCaps<Identity: windows_core::IUnknownImpl + IDirectDrawKernel_Impl>(this: *mut core::ffi::c_void, param0: *mut DDKERNELCAPS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawKernel_Impl::GetCaps(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetKernelHandle<Identity: windows_core::IUnknownImpl + IDirectDrawKernel_Impl>(this: *mut core::ffi::c_void, param0: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawKernel_Impl::GetKernelHandle(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseKernelHandle<Identity: windows_core::IUnknownImpl + IDirectDrawKernel_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawKernel_Impl::ReleaseKernelHandle(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCaps: GetCaps::<Identity>,
+            GetKernelHandle: GetKernelHandle::<Identity>,
+            ReleaseKernelHandle: ReleaseKernelHandle::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IDirectDrawKernel {}
 windows_core::imp::define_interface!(IDirectDrawPalette, IDirectDrawPalette_Vtbl, 0x6c14db84_a733_11ce_a521_0020af0be560);
 windows_core::imp::interface_hierarchy!(IDirectDrawPalette, windows_core::IUnknown);
 impl IDirectDrawPalette {
@@ -7585,6 +8510,45 @@ impl IDirectDrawPalette_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IDirectDrawPalette_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectDrawPalette_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCaps<Identity: windows_core::IUnknownImpl + IDirectDrawPalette_Impl>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawPalette_Impl::GetCaps(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetEntries<Identity: windows_core::IUnknownImpl + IDirectDrawPalette_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: u32, param3: *mut super::Gdi::PALETTEENTRY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawPalette_Impl::GetEntries(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectDrawPalette_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: u32, param2: *mut super::Gdi::PALETTEENTRY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawPalette_Impl::Initialize(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn SetEntries<Identity: windows_core::IUnknownImpl + IDirectDrawPalette_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: u32, param3: *mut super::Gdi::PALETTEENTRY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawPalette_Impl::SetEntries(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCaps: GetCaps::<Identity>,
+            GetEntries: GetEntries::<Identity>,
+            Initialize: Initialize::<Identity>,
+            SetEntries: SetEntries::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IDirectDrawPalette {}
 windows_core::imp::define_interface!(IDirectDrawSurface, IDirectDrawSurface_Vtbl, 0x6c14db81_a733_11ce_a521_0020af0be560);
 windows_core::imp::interface_hierarchy!(IDirectDrawSurface, windows_core::IUnknown);
@@ -8062,6 +9026,260 @@ impl IDirectDrawSurface_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectDrawSurface_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddAttachedSurface<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::AddAttachedSurface(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn AddOverlayDirtyRect<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::AddOverlayDirtyRect(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Blt<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDBLTFX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::Blt(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
+            }
+        }
+        unsafe extern "system" fn BltBatch<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: *mut DDBLTBATCH, param1: u32, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::BltBatch(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn BltFast<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: *mut core::ffi::c_void, param3: *mut super::super::Foundation::RECT, param4: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::BltFast(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteAttachedSurface<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::DeleteAttachedSurface(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn EnumAttachedSurfaces<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: LPDDENUMSURFACESCALLBACK) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::EnumAttachedSurfaces(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn EnumOverlayZOrders<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void, param2: LPDDENUMSURFACESCALLBACK) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::EnumOverlayZOrders(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn Flip<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::Flip(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetAttachedSurface<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSCAPS, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::GetAttachedSurface(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetBltStatus<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::GetBltStatus(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetCaps<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSCAPS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::GetCaps(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetClipper<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectDrawSurface_Impl::GetClipper(this) {
+                    Ok(ok__) => {
+                        param0.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetColorKey<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::GetColorKey(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetDC<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: *mut super::Gdi::HDC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::GetDC(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetFlipStatus<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::GetFlipStatus(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetOverlayPosition<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: *mut i32, param1: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::GetOverlayPosition(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetPalette<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectDrawSurface_Impl::GetPalette(this) {
+                    Ok(ok__) => {
+                        param0.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPixelFormat<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: *mut DDPIXELFORMAT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::GetPixelFormat(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetSurfaceDesc<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::GetSurfaceDesc(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut DDSURFACEDESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::Initialize(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn IsLost<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::IsLost(this).into()
+            }
+        }
+        unsafe extern "system" fn Lock<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut DDSURFACEDESC, param2: u32, param3: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::Lock(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseDC<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: super::Gdi::HDC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::ReleaseDC(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Restore<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::Restore(this).into()
+            }
+        }
+        unsafe extern "system" fn SetClipper<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::SetClipper(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SetColorKey<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::SetColorKey(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetOverlayPosition<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: i32, param1: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::SetOverlayPosition(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetPalette<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::SetPalette(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Unlock<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::Unlock(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateOverlay<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDOVERLAYFX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::UpdateOverlay(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateOverlayDisplay<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::UpdateOverlayDisplay(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateOverlayZOrder<Identity: windows_core::IUnknownImpl + IDirectDrawSurface_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface_Impl::UpdateOverlayZOrder(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddAttachedSurface: AddAttachedSurface::<Identity>,
+            AddOverlayDirtyRect: AddOverlayDirtyRect::<Identity>,
+            Blt: Blt::<Identity>,
+            BltBatch: BltBatch::<Identity>,
+            BltFast: BltFast::<Identity>,
+            DeleteAttachedSurface: DeleteAttachedSurface::<Identity>,
+            EnumAttachedSurfaces: EnumAttachedSurfaces::<Identity>,
+            EnumOverlayZOrders: EnumOverlayZOrders::<Identity>,
+            Flip: Flip::<Identity>,
+            GetAttachedSurface: GetAttachedSurface::<Identity>,
+            GetBltStatus: GetBltStatus::<Identity>,
+            GetCaps: GetCaps::<Identity>,
+            GetClipper: GetClipper::<Identity>,
+            GetColorKey: GetColorKey::<Identity>,
+            GetDC: GetDC::<Identity>,
+            GetFlipStatus: GetFlipStatus::<Identity>,
+            GetOverlayPosition: GetOverlayPosition::<Identity>,
+            GetPalette: GetPalette::<Identity>,
+            GetPixelFormat: GetPixelFormat::<Identity>,
+            GetSurfaceDesc: GetSurfaceDesc::<Identity>,
+            Initialize: Initialize::<Identity>,
+            IsLost: IsLost::<Identity>,
+            Lock: Lock::<Identity>,
+            ReleaseDC: ReleaseDC::<Identity>,
+            Restore: Restore::<Identity>,
+            SetClipper: SetClipper::<Identity>,
+            SetColorKey: SetColorKey::<Identity>,
+            SetOverlayPosition: SetOverlayPosition::<Identity>,
+            SetPalette: SetPalette::<Identity>,
+            Unlock: Unlock::<Identity>,
+            UpdateOverlay: UpdateOverlay::<Identity>,
+            UpdateOverlayDisplay: UpdateOverlayDisplay::<Identity>,
+            UpdateOverlayZOrder: UpdateOverlayZOrder::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IDirectDrawSurface {}
 windows_core::imp::define_interface!(IDirectDrawSurface2, IDirectDrawSurface2_Vtbl, 0x57805885_6eec_11cf_9441_a82303c10e27);
 windows_core::imp::interface_hierarchy!(IDirectDrawSurface2, windows_core::IUnknown);
@@ -8575,69 +9793,344 @@ impl IDirectDrawSurface2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
-impl windows_core::RuntimeName for IDirectDrawSurface2 {}
-windows_core::imp::define_interface!(IDirectDrawSurface3, IDirectDrawSurface3_Vtbl, 0xda044e00_69b2_11d0_a1d5_00aa00b8dfbb);
-windows_core::imp::interface_hierarchy!(IDirectDrawSurface3, windows_core::IUnknown);
-impl IDirectDrawSurface3 {
-    pub unsafe fn AddAttachedSurface<P0>(&self, param0: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<IDirectDrawSurface3>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).AddAttachedSurface)(windows_core::Interface::as_raw(self), param0.param().abi()).ok() }
-    }
-    pub unsafe fn AddOverlayDirtyRect(&self, param0: *mut super::super::Foundation::RECT) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).AddOverlayDirtyRect)(windows_core::Interface::as_raw(self), param0 as _).ok() }
-    }
-    pub unsafe fn Blt<P1>(&self, param0: *mut super::super::Foundation::RECT, param1: P1, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDBLTFX) -> windows_core::Result<()>
-    where
-        P1: windows_core::Param<IDirectDrawSurface3>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).Blt)(windows_core::Interface::as_raw(self), param0 as _, param1.param().abi(), param2 as _, param3, core::mem::transmute(param4)).ok() }
-    }
-    pub unsafe fn BltBatch(&self, param0: *mut DDBLTBATCH, param1: u32, param2: u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).BltBatch)(windows_core::Interface::as_raw(self), core::mem::transmute(param0), param1, param2).ok() }
-    }
-    pub unsafe fn BltFast<P2>(&self, param0: u32, param1: u32, param2: P2, param3: *mut super::super::Foundation::RECT, param4: u32) -> windows_core::Result<()>
-    where
-        P2: windows_core::Param<IDirectDrawSurface3>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).BltFast)(windows_core::Interface::as_raw(self), param0, param1, param2.param().abi(), param3 as _, param4).ok() }
-    }
-    pub unsafe fn DeleteAttachedSurface<P1>(&self, param0: u32, param1: P1) -> windows_core::Result<()>
-    where
-        P1: windows_core::Param<IDirectDrawSurface3>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).DeleteAttachedSurface)(windows_core::Interface::as_raw(self), param0, param1.param().abi()).ok() }
-    }
-    pub unsafe fn EnumAttachedSurfaces(&self, param0: *mut core::ffi::c_void, param1: LPDDENUMSURFACESCALLBACK) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).EnumAttachedSurfaces)(windows_core::Interface::as_raw(self), param0 as _, param1).ok() }
-    }
-    pub unsafe fn EnumOverlayZOrders(&self, param0: u32, param1: *mut core::ffi::c_void, param2: LPDDENUMSURFACESCALLBACK) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).EnumOverlayZOrders)(windows_core::Interface::as_raw(self), param0, param1 as _, param2).ok() }
-    }
-    pub unsafe fn Flip<P0>(&self, param0: P0, param1: u32) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<IDirectDrawSurface3>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).Flip)(windows_core::Interface::as_raw(self), param0.param().abi(), param1).ok() }
-    }
-    pub unsafe fn GetAttachedSurface(&self, param0: *mut DDSCAPS, param1: *mut Option<IDirectDrawSurface3>) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetAttachedSurface)(windows_core::Interface::as_raw(self), param0 as _, core::mem::transmute(param1)).ok() }
-    }
-    pub unsafe fn GetBltStatus(&self, param0: u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetBltStatus)(windows_core::Interface::as_raw(self), param0).ok() }
-    }
-    pub unsafe fn GetCaps(&self, param0: *mut DDSCAPS) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetCaps)(windows_core::Interface::as_raw(self), param0 as _).ok() }
-    }
-    pub unsafe fn GetClipper(&self) -> windows_core::Result<IDirectDrawClipper> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetClipper)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+impl<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectDrawSurface2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddAttachedSurface<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::AddAttachedSurface(this, core::mem::transmute_copy(&param0)).into()
+            }
         }
-    }
-    pub unsafe fn GetColorKey(&self, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetColorKey)(windows_core::Interface::as_raw(self), param0, param1 as _).ok() }
+        unsafe extern "system" fn AddOverlayDirtyRect<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::AddOverlayDirtyRect(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Blt<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDBLTFX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::Blt(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
+            }
+        }
+        unsafe extern "system" fn BltBatch<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: *mut DDBLTBATCH, param1: u32, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::BltBatch(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn BltFast<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: *mut core::ffi::c_void, param3: *mut super::super::Foundation::RECT, param4: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::BltFast(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteAttachedSurface<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::DeleteAttachedSurface(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn EnumAttachedSurfaces<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: LPDDENUMSURFACESCALLBACK) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::EnumAttachedSurfaces(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn EnumOverlayZOrders<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void, param2: LPDDENUMSURFACESCALLBACK) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::EnumOverlayZOrders(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn Flip<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::Flip(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetAttachedSurface<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSCAPS, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::GetAttachedSurface(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetBltStatus<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::GetBltStatus(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetCaps<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSCAPS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::GetCaps(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetClipper<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectDrawSurface2_Impl::GetClipper(this) {
+                    Ok(ok__) => {
+                        param0.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetColorKey<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::GetColorKey(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetDC<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: *mut super::Gdi::HDC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::GetDC(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetFlipStatus<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::GetFlipStatus(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetOverlayPosition<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: *mut i32, param1: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::GetOverlayPosition(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetPalette<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectDrawSurface2_Impl::GetPalette(this) {
+                    Ok(ok__) => {
+                        param0.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPixelFormat<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: *mut DDPIXELFORMAT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::GetPixelFormat(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetSurfaceDesc<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::GetSurfaceDesc(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut DDSURFACEDESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::Initialize(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn IsLost<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::IsLost(this).into()
+            }
+        }
+        unsafe extern "system" fn Lock<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut DDSURFACEDESC, param2: u32, param3: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::Lock(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseDC<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: super::Gdi::HDC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::ReleaseDC(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Restore<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::Restore(this).into()
+            }
+        }
+        unsafe extern "system" fn SetClipper<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::SetClipper(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SetColorKey<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::SetColorKey(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetOverlayPosition<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: i32, param1: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::SetOverlayPosition(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetPalette<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::SetPalette(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Unlock<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::Unlock(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateOverlay<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDOVERLAYFX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::UpdateOverlay(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateOverlayDisplay<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::UpdateOverlayDisplay(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateOverlayZOrder<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::UpdateOverlayZOrder(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetDDInterface<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::GetDDInterface(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn PageLock<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::PageLock(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn PageUnlock<Identity: windows_core::IUnknownImpl + IDirectDrawSurface2_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface2_Impl::PageUnlock(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddAttachedSurface: AddAttachedSurface::<Identity>,
+            AddOverlayDirtyRect: AddOverlayDirtyRect::<Identity>,
+            Blt: Blt::<Identity>,
+            BltBatch: BltBatch::<Identity>,
+            BltFast: BltFast::<Identity>,
+            DeleteAttachedSurface: DeleteAttachedSurface::<Identity>,
+            EnumAttachedSurfaces: EnumAttachedSurfaces::<Identity>,
+            EnumOverlayZOrders: EnumOverlayZOrders::<Identity>,
+            Flip: Flip::<Identity>,
+            GetAttachedSurface: GetAttachedSurface::<Identity>,
+            GetBltStatus: GetBltStatus::<Identity>,
+            GetCaps: GetCaps::<Identity>,
+            GetClipper: GetClipper::<Identity>,
+            GetColorKey: GetColorKey::<Identity>,
+            GetDC: GetDC::<Identity>,
+            GetFlipStatus: GetFlipStatus::<Identity>,
+            GetOverlayPosition: GetOverlayPosition::<Identity>,
+            GetPalette: GetPalette::<Identity>,
+            GetPixelFormat: GetPixelFormat::<Identity>,
+            GetSurfaceDesc: GetSurfaceDesc::<Identity>,
+            Initialize: Initialize::<Identity>,
+            IsLost: IsLost::<Identity>,
+            Lock: Lock::<Identity>,
+            ReleaseDC: ReleaseDC::<Identity>,
+            Restore: Restore::<Identity>,
+            SetClipper: SetClipper::<Identity>,
+            SetColorKey: SetColorKey::<Identity>,
+            SetOverlayPosition: SetOverlayPosition::<Identity>,
+            SetPalette: SetPalette::<Identity>,
+            Unlock: Unlock::<Identity>,
+            UpdateOverlay: UpdateOverlay::<Identity>,
+            UpdateOverlayDisplay: UpdateOverlayDisplay::<Identity>,
+            UpdateOverlayZOrder: UpdateOverlayZOrder::<Identity>,
+            GetDDInterface: GetDDInterface::<Identity>,
+            PageLock: PageLock::<Identity>,
+            PageUnlock: PageUnlock::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::RuntimeName for IDirectDrawSurface2 {}
+windows_core::imp::define_interface!(IDirectDrawSurface3, IDirectDrawSurface3_Vtbl, 0xda044e00_69b2_11d0_a1d5_00aa00b8dfbb);
+windows_core::imp::interface_hierarchy!(IDirectDrawSurface3, windows_core::IUnknown);
+impl IDirectDrawSurface3 {
+    pub unsafe fn AddAttachedSurface<P0>(&self, param0: P0) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<IDirectDrawSurface3>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).AddAttachedSurface)(windows_core::Interface::as_raw(self), param0.param().abi()).ok() }
+    }
+    pub unsafe fn AddOverlayDirtyRect(&self, param0: *mut super::super::Foundation::RECT) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).AddOverlayDirtyRect)(windows_core::Interface::as_raw(self), param0 as _).ok() }
+    }
+    pub unsafe fn Blt<P1>(&self, param0: *mut super::super::Foundation::RECT, param1: P1, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDBLTFX) -> windows_core::Result<()>
+    where
+        P1: windows_core::Param<IDirectDrawSurface3>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).Blt)(windows_core::Interface::as_raw(self), param0 as _, param1.param().abi(), param2 as _, param3, core::mem::transmute(param4)).ok() }
+    }
+    pub unsafe fn BltBatch(&self, param0: *mut DDBLTBATCH, param1: u32, param2: u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).BltBatch)(windows_core::Interface::as_raw(self), core::mem::transmute(param0), param1, param2).ok() }
+    }
+    pub unsafe fn BltFast<P2>(&self, param0: u32, param1: u32, param2: P2, param3: *mut super::super::Foundation::RECT, param4: u32) -> windows_core::Result<()>
+    where
+        P2: windows_core::Param<IDirectDrawSurface3>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).BltFast)(windows_core::Interface::as_raw(self), param0, param1, param2.param().abi(), param3 as _, param4).ok() }
+    }
+    pub unsafe fn DeleteAttachedSurface<P1>(&self, param0: u32, param1: P1) -> windows_core::Result<()>
+    where
+        P1: windows_core::Param<IDirectDrawSurface3>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).DeleteAttachedSurface)(windows_core::Interface::as_raw(self), param0, param1.param().abi()).ok() }
+    }
+    pub unsafe fn EnumAttachedSurfaces(&self, param0: *mut core::ffi::c_void, param1: LPDDENUMSURFACESCALLBACK) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).EnumAttachedSurfaces)(windows_core::Interface::as_raw(self), param0 as _, param1).ok() }
+    }
+    pub unsafe fn EnumOverlayZOrders(&self, param0: u32, param1: *mut core::ffi::c_void, param2: LPDDENUMSURFACESCALLBACK) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).EnumOverlayZOrders)(windows_core::Interface::as_raw(self), param0, param1 as _, param2).ok() }
+    }
+    pub unsafe fn Flip<P0>(&self, param0: P0, param1: u32) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<IDirectDrawSurface3>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).Flip)(windows_core::Interface::as_raw(self), param0.param().abi(), param1).ok() }
+    }
+    pub unsafe fn GetAttachedSurface(&self, param0: *mut DDSCAPS, param1: *mut Option<IDirectDrawSurface3>) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetAttachedSurface)(windows_core::Interface::as_raw(self), param0 as _, core::mem::transmute(param1)).ok() }
+    }
+    pub unsafe fn GetBltStatus(&self, param0: u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetBltStatus)(windows_core::Interface::as_raw(self), param0).ok() }
+    }
+    pub unsafe fn GetCaps(&self, param0: *mut DDSCAPS) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetCaps)(windows_core::Interface::as_raw(self), param0 as _).ok() }
+    }
+    pub unsafe fn GetClipper(&self) -> windows_core::Result<IDirectDrawClipper> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetClipper)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn GetColorKey(&self, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetColorKey)(windows_core::Interface::as_raw(self), param0, param1 as _).ok() }
     }
     #[cfg(feature = "Win32_Graphics_Gdi")]
     pub unsafe fn GetDC(&self, param0: *mut super::Gdi::HDC) -> windows_core::Result<()> {
@@ -9100,44 +10593,326 @@ impl IDirectDrawSurface3_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
-impl windows_core::RuntimeName for IDirectDrawSurface3 {}
-windows_core::imp::define_interface!(IDirectDrawSurface4, IDirectDrawSurface4_Vtbl, 0x0b2b8630_ad35_11d0_8ea6_00609797ea5b);
-windows_core::imp::interface_hierarchy!(IDirectDrawSurface4, windows_core::IUnknown);
-impl IDirectDrawSurface4 {
-    pub unsafe fn AddAttachedSurface<P0>(&self, param0: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<IDirectDrawSurface4>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).AddAttachedSurface)(windows_core::Interface::as_raw(self), param0.param().abi()).ok() }
-    }
-    pub unsafe fn AddOverlayDirtyRect(&self, param0: *mut super::super::Foundation::RECT) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).AddOverlayDirtyRect)(windows_core::Interface::as_raw(self), param0 as _).ok() }
-    }
-    pub unsafe fn Blt<P1>(&self, param0: *mut super::super::Foundation::RECT, param1: P1, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDBLTFX) -> windows_core::Result<()>
-    where
-        P1: windows_core::Param<IDirectDrawSurface4>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).Blt)(windows_core::Interface::as_raw(self), param0 as _, param1.param().abi(), param2 as _, param3, core::mem::transmute(param4)).ok() }
-    }
-    pub unsafe fn BltBatch(&self, param0: *mut DDBLTBATCH, param1: u32, param2: u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).BltBatch)(windows_core::Interface::as_raw(self), core::mem::transmute(param0), param1, param2).ok() }
-    }
-    pub unsafe fn BltFast<P2>(&self, param0: u32, param1: u32, param2: P2, param3: *mut super::super::Foundation::RECT, param4: u32) -> windows_core::Result<()>
-    where
-        P2: windows_core::Param<IDirectDrawSurface4>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).BltFast)(windows_core::Interface::as_raw(self), param0, param1, param2.param().abi(), param3 as _, param4).ok() }
-    }
-    pub unsafe fn DeleteAttachedSurface<P1>(&self, param0: u32, param1: P1) -> windows_core::Result<()>
-    where
-        P1: windows_core::Param<IDirectDrawSurface4>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).DeleteAttachedSurface)(windows_core::Interface::as_raw(self), param0, param1.param().abi()).ok() }
-    }
-    pub unsafe fn EnumAttachedSurfaces(&self, param0: *mut core::ffi::c_void, param1: LPDDENUMSURFACESCALLBACK2) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).EnumAttachedSurfaces)(windows_core::Interface::as_raw(self), param0 as _, param1).ok() }
-    }
-    pub unsafe fn EnumOverlayZOrders(&self, param0: u32, param1: *mut core::ffi::c_void, param2: LPDDENUMSURFACESCALLBACK2) -> windows_core::Result<()> {
+impl<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectDrawSurface3_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddAttachedSurface<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::AddAttachedSurface(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn AddOverlayDirtyRect<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::AddOverlayDirtyRect(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Blt<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDBLTFX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::Blt(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
+            }
+        }
+        unsafe extern "system" fn BltBatch<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: *mut DDBLTBATCH, param1: u32, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::BltBatch(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn BltFast<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: *mut core::ffi::c_void, param3: *mut super::super::Foundation::RECT, param4: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::BltFast(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteAttachedSurface<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::DeleteAttachedSurface(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn EnumAttachedSurfaces<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: LPDDENUMSURFACESCALLBACK) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::EnumAttachedSurfaces(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn EnumOverlayZOrders<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void, param2: LPDDENUMSURFACESCALLBACK) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::EnumOverlayZOrders(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn Flip<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::Flip(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetAttachedSurface<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSCAPS, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::GetAttachedSurface(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetBltStatus<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::GetBltStatus(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetCaps<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSCAPS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::GetCaps(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetClipper<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectDrawSurface3_Impl::GetClipper(this) {
+                    Ok(ok__) => {
+                        param0.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetColorKey<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::GetColorKey(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetDC<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: *mut super::Gdi::HDC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::GetDC(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetFlipStatus<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::GetFlipStatus(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetOverlayPosition<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: *mut i32, param1: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::GetOverlayPosition(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetPalette<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectDrawSurface3_Impl::GetPalette(this) {
+                    Ok(ok__) => {
+                        param0.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPixelFormat<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: *mut DDPIXELFORMAT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::GetPixelFormat(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetSurfaceDesc<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::GetSurfaceDesc(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut DDSURFACEDESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::Initialize(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn IsLost<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::IsLost(this).into()
+            }
+        }
+        unsafe extern "system" fn Lock<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut DDSURFACEDESC, param2: u32, param3: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::Lock(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseDC<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: super::Gdi::HDC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::ReleaseDC(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Restore<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::Restore(this).into()
+            }
+        }
+        unsafe extern "system" fn SetClipper<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::SetClipper(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SetColorKey<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::SetColorKey(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetOverlayPosition<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: i32, param1: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::SetOverlayPosition(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetPalette<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::SetPalette(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Unlock<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::Unlock(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateOverlay<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDOVERLAYFX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::UpdateOverlay(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateOverlayDisplay<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::UpdateOverlayDisplay(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateOverlayZOrder<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::UpdateOverlayZOrder(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetDDInterface<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::GetDDInterface(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn PageLock<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::PageLock(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn PageUnlock<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::PageUnlock(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SetSurfaceDesc<Identity: windows_core::IUnknownImpl + IDirectDrawSurface3_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface3_Impl::SetSurfaceDesc(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddAttachedSurface: AddAttachedSurface::<Identity>,
+            AddOverlayDirtyRect: AddOverlayDirtyRect::<Identity>,
+            Blt: Blt::<Identity>,
+            BltBatch: BltBatch::<Identity>,
+            BltFast: BltFast::<Identity>,
+            DeleteAttachedSurface: DeleteAttachedSurface::<Identity>,
+            EnumAttachedSurfaces: EnumAttachedSurfaces::<Identity>,
+            EnumOverlayZOrders: EnumOverlayZOrders::<Identity>,
+            Flip: Flip::<Identity>,
+            GetAttachedSurface: GetAttachedSurface::<Identity>,
+            GetBltStatus: GetBltStatus::<Identity>,
+            GetCaps: GetCaps::<Identity>,
+            GetClipper: GetClipper::<Identity>,
+            GetColorKey: GetColorKey::<Identity>,
+            GetDC: GetDC::<Identity>,
+            GetFlipStatus: GetFlipStatus::<Identity>,
+            GetOverlayPosition: GetOverlayPosition::<Identity>,
+            GetPalette: GetPalette::<Identity>,
+            GetPixelFormat: GetPixelFormat::<Identity>,
+            GetSurfaceDesc: GetSurfaceDesc::<Identity>,
+            Initialize: Initialize::<Identity>,
+            IsLost: IsLost::<Identity>,
+            Lock: Lock::<Identity>,
+            ReleaseDC: ReleaseDC::<Identity>,
+            Restore: Restore::<Identity>,
+            SetClipper: SetClipper::<Identity>,
+            SetColorKey: SetColorKey::<Identity>,
+            SetOverlayPosition: SetOverlayPosition::<Identity>,
+            SetPalette: SetPalette::<Identity>,
+            Unlock: Unlock::<Identity>,
+            UpdateOverlay: UpdateOverlay::<Identity>,
+            UpdateOverlayDisplay: UpdateOverlayDisplay::<Identity>,
+            UpdateOverlayZOrder: UpdateOverlayZOrder::<Identity>,
+            GetDDInterface: GetDDInterface::<Identity>,
+            PageLock: PageLock::<Identity>,
+            PageUnlock: PageUnlock::<Identity>,
+            SetSurfaceDesc: SetSurfaceDesc::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::RuntimeName for IDirectDrawSurface3 {}
+windows_core::imp::define_interface!(IDirectDrawSurface4, IDirectDrawSurface4_Vtbl, 0x0b2b8630_ad35_11d0_8ea6_00609797ea5b);
+windows_core::imp::interface_hierarchy!(IDirectDrawSurface4, windows_core::IUnknown);
+impl IDirectDrawSurface4 {
+    pub unsafe fn AddAttachedSurface<P0>(&self, param0: P0) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<IDirectDrawSurface4>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).AddAttachedSurface)(windows_core::Interface::as_raw(self), param0.param().abi()).ok() }
+    }
+    pub unsafe fn AddOverlayDirtyRect(&self, param0: *mut super::super::Foundation::RECT) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).AddOverlayDirtyRect)(windows_core::Interface::as_raw(self), param0 as _).ok() }
+    }
+    pub unsafe fn Blt<P1>(&self, param0: *mut super::super::Foundation::RECT, param1: P1, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDBLTFX) -> windows_core::Result<()>
+    where
+        P1: windows_core::Param<IDirectDrawSurface4>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).Blt)(windows_core::Interface::as_raw(self), param0 as _, param1.param().abi(), param2 as _, param3, core::mem::transmute(param4)).ok() }
+    }
+    pub unsafe fn BltBatch(&self, param0: *mut DDBLTBATCH, param1: u32, param2: u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).BltBatch)(windows_core::Interface::as_raw(self), core::mem::transmute(param0), param1, param2).ok() }
+    }
+    pub unsafe fn BltFast<P2>(&self, param0: u32, param1: u32, param2: P2, param3: *mut super::super::Foundation::RECT, param4: u32) -> windows_core::Result<()>
+    where
+        P2: windows_core::Param<IDirectDrawSurface4>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).BltFast)(windows_core::Interface::as_raw(self), param0, param1, param2.param().abi(), param3 as _, param4).ok() }
+    }
+    pub unsafe fn DeleteAttachedSurface<P1>(&self, param0: u32, param1: P1) -> windows_core::Result<()>
+    where
+        P1: windows_core::Param<IDirectDrawSurface4>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).DeleteAttachedSurface)(windows_core::Interface::as_raw(self), param0, param1.param().abi()).ok() }
+    }
+    pub unsafe fn EnumAttachedSurfaces(&self, param0: *mut core::ffi::c_void, param1: LPDDENUMSURFACESCALLBACK2) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).EnumAttachedSurfaces)(windows_core::Interface::as_raw(self), param0 as _, param1).ok() }
+    }
+    pub unsafe fn EnumOverlayZOrders(&self, param0: u32, param1: *mut core::ffi::c_void, param2: LPDDENUMSURFACESCALLBACK2) -> windows_core::Result<()> {
         unsafe { (windows_core::Interface::vtable(self).EnumOverlayZOrders)(windows_core::Interface::as_raw(self), param0, param1 as _, param2).ok() }
     }
     pub unsafe fn Flip<P0>(&self, param0: P0, param1: u32) -> windows_core::Result<()>
@@ -9685,59 +11460,376 @@ impl IDirectDrawSurface4_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
-impl windows_core::RuntimeName for IDirectDrawSurface4 {}
-windows_core::imp::define_interface!(IDirectDrawSurface7, IDirectDrawSurface7_Vtbl, 0x06675a80_3b9b_11d2_b92f_00609797ea5b);
-windows_core::imp::interface_hierarchy!(IDirectDrawSurface7, windows_core::IUnknown);
-impl IDirectDrawSurface7 {
-    pub unsafe fn AddAttachedSurface<P0>(&self, param0: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<IDirectDrawSurface7>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).AddAttachedSurface)(windows_core::Interface::as_raw(self), param0.param().abi()).ok() }
-    }
-    pub unsafe fn AddOverlayDirtyRect(&self, param0: *mut super::super::Foundation::RECT) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).AddOverlayDirtyRect)(windows_core::Interface::as_raw(self), param0 as _).ok() }
-    }
-    pub unsafe fn Blt<P1>(&self, param0: *mut super::super::Foundation::RECT, param1: P1, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDBLTFX) -> windows_core::Result<()>
-    where
-        P1: windows_core::Param<IDirectDrawSurface7>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).Blt)(windows_core::Interface::as_raw(self), param0 as _, param1.param().abi(), param2 as _, param3, core::mem::transmute(param4)).ok() }
-    }
-    pub unsafe fn BltBatch(&self, param0: *mut DDBLTBATCH, param1: u32, param2: u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).BltBatch)(windows_core::Interface::as_raw(self), core::mem::transmute(param0), param1, param2).ok() }
-    }
-    pub unsafe fn BltFast<P2>(&self, param0: u32, param1: u32, param2: P2, param3: *mut super::super::Foundation::RECT, param4: u32) -> windows_core::Result<()>
-    where
-        P2: windows_core::Param<IDirectDrawSurface7>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).BltFast)(windows_core::Interface::as_raw(self), param0, param1, param2.param().abi(), param3 as _, param4).ok() }
-    }
-    pub unsafe fn DeleteAttachedSurface<P1>(&self, param0: u32, param1: P1) -> windows_core::Result<()>
-    where
-        P1: windows_core::Param<IDirectDrawSurface7>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).DeleteAttachedSurface)(windows_core::Interface::as_raw(self), param0, param1.param().abi()).ok() }
-    }
-    pub unsafe fn EnumAttachedSurfaces(&self, param0: *mut core::ffi::c_void, param1: LPDDENUMSURFACESCALLBACK7) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).EnumAttachedSurfaces)(windows_core::Interface::as_raw(self), param0 as _, param1).ok() }
-    }
-    pub unsafe fn EnumOverlayZOrders(&self, param0: u32, param1: *mut core::ffi::c_void, param2: LPDDENUMSURFACESCALLBACK7) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).EnumOverlayZOrders)(windows_core::Interface::as_raw(self), param0, param1 as _, param2).ok() }
-    }
-    pub unsafe fn Flip<P0>(&self, param0: P0, param1: u32) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<IDirectDrawSurface7>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).Flip)(windows_core::Interface::as_raw(self), param0.param().abi(), param1).ok() }
-    }
-    pub unsafe fn GetAttachedSurface(&self, param0: *mut DDSCAPS2, param1: *mut Option<IDirectDrawSurface7>) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetAttachedSurface)(windows_core::Interface::as_raw(self), param0 as _, core::mem::transmute(param1)).ok() }
-    }
-    pub unsafe fn GetBltStatus(&self, param0: u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetBltStatus)(windows_core::Interface::as_raw(self), param0).ok() }
-    }
-    pub unsafe fn GetCaps(&self, param0: *mut DDSCAPS2) -> windows_core::Result<()> {
+impl<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectDrawSurface4_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddAttachedSurface<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::AddAttachedSurface(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn AddOverlayDirtyRect<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::AddOverlayDirtyRect(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Blt<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDBLTFX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::Blt(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
+            }
+        }
+        unsafe extern "system" fn BltBatch<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *mut DDBLTBATCH, param1: u32, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::BltBatch(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn BltFast<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: *mut core::ffi::c_void, param3: *mut super::super::Foundation::RECT, param4: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::BltFast(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteAttachedSurface<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::DeleteAttachedSurface(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn EnumAttachedSurfaces<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: LPDDENUMSURFACESCALLBACK2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::EnumAttachedSurfaces(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn EnumOverlayZOrders<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void, param2: LPDDENUMSURFACESCALLBACK2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::EnumOverlayZOrders(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn Flip<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::Flip(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetAttachedSurface<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSCAPS2, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::GetAttachedSurface(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetBltStatus<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::GetBltStatus(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetCaps<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSCAPS2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::GetCaps(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetClipper<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectDrawSurface4_Impl::GetClipper(this) {
+                    Ok(ok__) => {
+                        param0.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetColorKey<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::GetColorKey(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetDC<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *mut super::Gdi::HDC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::GetDC(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetFlipStatus<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::GetFlipStatus(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetOverlayPosition<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *mut i32, param1: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::GetOverlayPosition(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetPalette<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectDrawSurface4_Impl::GetPalette(this) {
+                    Ok(ok__) => {
+                        param0.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPixelFormat<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *mut DDPIXELFORMAT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::GetPixelFormat(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetSurfaceDesc<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::GetSurfaceDesc(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut DDSURFACEDESC2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::Initialize(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn IsLost<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::IsLost(this).into()
+            }
+        }
+        unsafe extern "system" fn Lock<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut DDSURFACEDESC2, param2: u32, param3: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::Lock(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseDC<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: super::Gdi::HDC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::ReleaseDC(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Restore<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::Restore(this).into()
+            }
+        }
+        unsafe extern "system" fn SetClipper<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::SetClipper(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SetColorKey<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::SetColorKey(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetOverlayPosition<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: i32, param1: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::SetOverlayPosition(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetPalette<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::SetPalette(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Unlock<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::Unlock(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateOverlay<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDOVERLAYFX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::UpdateOverlay(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateOverlayDisplay<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::UpdateOverlayDisplay(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateOverlayZOrder<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::UpdateOverlayZOrder(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetDDInterface<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::GetDDInterface(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn PageLock<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::PageLock(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn PageUnlock<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::PageUnlock(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SetSurfaceDesc<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC2, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::SetSurfaceDesc(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetPrivateData<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut core::ffi::c_void, param2: u32, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::SetPrivateData(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn GetPrivateData<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut core::ffi::c_void, param2: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::GetPrivateData(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn FreePrivateData<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::FreePrivateData(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetUniquenessValue<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::GetUniquenessValue(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn ChangeUniquenessValue<Identity: windows_core::IUnknownImpl + IDirectDrawSurface4_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurface4_Impl::ChangeUniquenessValue(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddAttachedSurface: AddAttachedSurface::<Identity>,
+            AddOverlayDirtyRect: AddOverlayDirtyRect::<Identity>,
+            Blt: Blt::<Identity>,
+            BltBatch: BltBatch::<Identity>,
+            BltFast: BltFast::<Identity>,
+            DeleteAttachedSurface: DeleteAttachedSurface::<Identity>,
+            EnumAttachedSurfaces: EnumAttachedSurfaces::<Identity>,
+            EnumOverlayZOrders: EnumOverlayZOrders::<Identity>,
+            Flip: Flip::<Identity>,
+            GetAttachedSurface: GetAttachedSurface::<Identity>,
+            GetBltStatus: GetBltStatus::<Identity>,
+            GetCaps: GetCaps::<Identity>,
+            GetClipper: GetClipper::<Identity>,
+            GetColorKey: GetColorKey::<Identity>,
+            GetDC: GetDC::<Identity>,
+            GetFlipStatus: GetFlipStatus::<Identity>,
+            GetOverlayPosition: GetOverlayPosition::<Identity>,
+            GetPalette: GetPalette::<Identity>,
+            GetPixelFormat: GetPixelFormat::<Identity>,
+            GetSurfaceDesc: GetSurfaceDesc::<Identity>,
+            Initialize: Initialize::<Identity>,
+            IsLost: IsLost::<Identity>,
+            Lock: Lock::<Identity>,
+            ReleaseDC: ReleaseDC::<Identity>,
+            Restore: Restore::<Identity>,
+            SetClipper: SetClipper::<Identity>,
+            SetColorKey: SetColorKey::<Identity>,
+            SetOverlayPosition: SetOverlayPosition::<Identity>,
+            SetPalette: SetPalette::<Identity>,
+            Unlock: Unlock::<Identity>,
+            UpdateOverlay: UpdateOverlay::<Identity>,
+            UpdateOverlayDisplay: UpdateOverlayDisplay::<Identity>,
+            UpdateOverlayZOrder: UpdateOverlayZOrder::<Identity>,
+            GetDDInterface: GetDDInterface::<Identity>,
+            PageLock: PageLock::<Identity>,
+            PageUnlock: PageUnlock::<Identity>,
+            SetSurfaceDesc: SetSurfaceDesc::<Identity>,
+            SetPrivateData: SetPrivateData::<Identity>,
+            GetPrivateData: GetPrivateData::<Identity>,
+            FreePrivateData: FreePrivateData::<Identity>,
+            GetUniquenessValue: GetUniquenessValue::<Identity>,
+            ChangeUniquenessValue: ChangeUniquenessValue::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::RuntimeName for IDirectDrawSurface4 {}
+windows_core::imp::define_interface!(IDirectDrawSurface7, IDirectDrawSurface7_Vtbl, 0x06675a80_3b9b_11d2_b92f_00609797ea5b);
+windows_core::imp::interface_hierarchy!(IDirectDrawSurface7, windows_core::IUnknown);
+impl IDirectDrawSurface7 {
+    pub unsafe fn AddAttachedSurface<P0>(&self, param0: P0) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<IDirectDrawSurface7>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).AddAttachedSurface)(windows_core::Interface::as_raw(self), param0.param().abi()).ok() }
+    }
+    pub unsafe fn AddOverlayDirtyRect(&self, param0: *mut super::super::Foundation::RECT) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).AddOverlayDirtyRect)(windows_core::Interface::as_raw(self), param0 as _).ok() }
+    }
+    pub unsafe fn Blt<P1>(&self, param0: *mut super::super::Foundation::RECT, param1: P1, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDBLTFX) -> windows_core::Result<()>
+    where
+        P1: windows_core::Param<IDirectDrawSurface7>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).Blt)(windows_core::Interface::as_raw(self), param0 as _, param1.param().abi(), param2 as _, param3, core::mem::transmute(param4)).ok() }
+    }
+    pub unsafe fn BltBatch(&self, param0: *mut DDBLTBATCH, param1: u32, param2: u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).BltBatch)(windows_core::Interface::as_raw(self), core::mem::transmute(param0), param1, param2).ok() }
+    }
+    pub unsafe fn BltFast<P2>(&self, param0: u32, param1: u32, param2: P2, param3: *mut super::super::Foundation::RECT, param4: u32) -> windows_core::Result<()>
+    where
+        P2: windows_core::Param<IDirectDrawSurface7>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).BltFast)(windows_core::Interface::as_raw(self), param0, param1, param2.param().abi(), param3 as _, param4).ok() }
+    }
+    pub unsafe fn DeleteAttachedSurface<P1>(&self, param0: u32, param1: P1) -> windows_core::Result<()>
+    where
+        P1: windows_core::Param<IDirectDrawSurface7>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).DeleteAttachedSurface)(windows_core::Interface::as_raw(self), param0, param1.param().abi()).ok() }
+    }
+    pub unsafe fn EnumAttachedSurfaces(&self, param0: *mut core::ffi::c_void, param1: LPDDENUMSURFACESCALLBACK7) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).EnumAttachedSurfaces)(windows_core::Interface::as_raw(self), param0 as _, param1).ok() }
+    }
+    pub unsafe fn EnumOverlayZOrders(&self, param0: u32, param1: *mut core::ffi::c_void, param2: LPDDENUMSURFACESCALLBACK7) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).EnumOverlayZOrders)(windows_core::Interface::as_raw(self), param0, param1 as _, param2).ok() }
+    }
+    pub unsafe fn Flip<P0>(&self, param0: P0, param1: u32) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<IDirectDrawSurface7>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).Flip)(windows_core::Interface::as_raw(self), param0.param().abi(), param1).ok() }
+    }
+    pub unsafe fn GetAttachedSurface(&self, param0: *mut DDSCAPS2, param1: *mut Option<IDirectDrawSurface7>) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetAttachedSurface)(windows_core::Interface::as_raw(self), param0 as _, core::mem::transmute(param1)).ok() }
+    }
+    pub unsafe fn GetBltStatus(&self, param0: u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetBltStatus)(windows_core::Interface::as_raw(self), param0).ok() }
+    }
+    pub unsafe fn GetCaps(&self, param0: *mut DDSCAPS2) -> windows_core::Result<()> {
         unsafe { (windows_core::Interface::vtable(self).GetCaps)(windows_core::Interface::as_raw(self), param0 as _).ok() }
     }
     pub unsafe fn GetClipper(&self) -> windows_core::Result<IDirectDrawClipper> {
@@ -9973,83 +12065,431 @@ pub trait IDirectDrawSurface7_Impl: windows_core::IUnknownImpl {
     fn GetLOD(&self, param0: *mut u32) -> windows_core::Result<()>;
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
-impl IDirectDrawSurface7_Vtbl {
-    pub const fn new<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn AddAttachedSurface<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl IDirectDrawSurface7_Vtbl {
+    pub const fn new<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn AddAttachedSurface<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::AddAttachedSurface(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn AddOverlayDirtyRect<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::AddOverlayDirtyRect(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Blt<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDBLTFX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::Blt(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
+            }
+        }
+        unsafe extern "system" fn BltBatch<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut DDBLTBATCH, param1: u32, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::BltBatch(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn BltFast<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: *mut core::ffi::c_void, param3: *mut super::super::Foundation::RECT, param4: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::BltFast(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteAttachedSurface<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::DeleteAttachedSurface(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn EnumAttachedSurfaces<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: LPDDENUMSURFACESCALLBACK7) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::EnumAttachedSurfaces(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn EnumOverlayZOrders<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void, param2: LPDDENUMSURFACESCALLBACK7) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::EnumOverlayZOrders(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn Flip<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::Flip(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetAttachedSurface<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut DDSCAPS2, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::GetAttachedSurface(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetBltStatus<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::GetBltStatus(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetCaps<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut DDSCAPS2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::GetCaps(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetClipper<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDirectDrawSurface7_Impl::GetClipper(this) {
+                    Ok(ok__) => {
+                        param0.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetColorKey<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::GetColorKey(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetDC<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut super::Gdi::HDC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::GetDC(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetFlipStatus<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::GetFlipStatus(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetOverlayPosition<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut i32, param1: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::GetOverlayPosition(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetPalette<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDirectDrawSurface7_Impl::GetPalette(this) {
+                    Ok(ok__) => {
+                        param0.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPixelFormat<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut DDPIXELFORMAT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::GetPixelFormat(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetSurfaceDesc<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::GetSurfaceDesc(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut DDSURFACEDESC2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::Initialize(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn IsLost<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::IsLost(this).into()
+            }
+        }
+        unsafe extern "system" fn Lock<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut DDSURFACEDESC2, param2: u32, param3: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::Lock(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseDC<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: super::Gdi::HDC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::ReleaseDC(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Restore<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::Restore(this).into()
+            }
+        }
+        unsafe extern "system" fn SetClipper<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::SetClipper(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SetColorKey<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::SetColorKey(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetOverlayPosition<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: i32, param1: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::SetOverlayPosition(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetPalette<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::SetPalette(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn Unlock<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::Unlock(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateOverlay<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDOVERLAYFX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::UpdateOverlay(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateOverlayDisplay<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::UpdateOverlayDisplay(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateOverlayZOrder<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::UpdateOverlayZOrder(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetDDInterface<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::GetDDInterface(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn PageLock<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::PageLock(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn PageUnlock<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::PageUnlock(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SetSurfaceDesc<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC2, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::SetSurfaceDesc(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn SetPrivateData<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut core::ffi::c_void, param2: u32, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::SetPrivateData(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn GetPrivateData<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut core::ffi::c_void, param2: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::GetPrivateData(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn FreePrivateData<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::FreePrivateData(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetUniquenessValue<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::GetUniquenessValue(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn ChangeUniquenessValue<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::ChangeUniquenessValue(this).into()
+            }
+        }
+        unsafe extern "system" fn SetPriority<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::SetPriority(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetPriority<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::GetPriority(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SetLOD<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::SetLOD(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetLOD<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDirectDrawSurface7_Impl::GetLOD(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            AddAttachedSurface: AddAttachedSurface::<Identity, OFFSET>,
+            AddOverlayDirtyRect: AddOverlayDirtyRect::<Identity, OFFSET>,
+            Blt: Blt::<Identity, OFFSET>,
+            BltBatch: BltBatch::<Identity, OFFSET>,
+            BltFast: BltFast::<Identity, OFFSET>,
+            DeleteAttachedSurface: DeleteAttachedSurface::<Identity, OFFSET>,
+            EnumAttachedSurfaces: EnumAttachedSurfaces::<Identity, OFFSET>,
+            EnumOverlayZOrders: EnumOverlayZOrders::<Identity, OFFSET>,
+            Flip: Flip::<Identity, OFFSET>,
+            GetAttachedSurface: GetAttachedSurface::<Identity, OFFSET>,
+            GetBltStatus: GetBltStatus::<Identity, OFFSET>,
+            GetCaps: GetCaps::<Identity, OFFSET>,
+            GetClipper: GetClipper::<Identity, OFFSET>,
+            GetColorKey: GetColorKey::<Identity, OFFSET>,
+            GetDC: GetDC::<Identity, OFFSET>,
+            GetFlipStatus: GetFlipStatus::<Identity, OFFSET>,
+            GetOverlayPosition: GetOverlayPosition::<Identity, OFFSET>,
+            GetPalette: GetPalette::<Identity, OFFSET>,
+            GetPixelFormat: GetPixelFormat::<Identity, OFFSET>,
+            GetSurfaceDesc: GetSurfaceDesc::<Identity, OFFSET>,
+            Initialize: Initialize::<Identity, OFFSET>,
+            IsLost: IsLost::<Identity, OFFSET>,
+            Lock: Lock::<Identity, OFFSET>,
+            ReleaseDC: ReleaseDC::<Identity, OFFSET>,
+            Restore: Restore::<Identity, OFFSET>,
+            SetClipper: SetClipper::<Identity, OFFSET>,
+            SetColorKey: SetColorKey::<Identity, OFFSET>,
+            SetOverlayPosition: SetOverlayPosition::<Identity, OFFSET>,
+            SetPalette: SetPalette::<Identity, OFFSET>,
+            Unlock: Unlock::<Identity, OFFSET>,
+            UpdateOverlay: UpdateOverlay::<Identity, OFFSET>,
+            UpdateOverlayDisplay: UpdateOverlayDisplay::<Identity, OFFSET>,
+            UpdateOverlayZOrder: UpdateOverlayZOrder::<Identity, OFFSET>,
+            GetDDInterface: GetDDInterface::<Identity, OFFSET>,
+            PageLock: PageLock::<Identity, OFFSET>,
+            PageUnlock: PageUnlock::<Identity, OFFSET>,
+            SetSurfaceDesc: SetSurfaceDesc::<Identity, OFFSET>,
+            SetPrivateData: SetPrivateData::<Identity, OFFSET>,
+            GetPrivateData: GetPrivateData::<Identity, OFFSET>,
+            FreePrivateData: FreePrivateData::<Identity, OFFSET>,
+            GetUniquenessValue: GetUniquenessValue::<Identity, OFFSET>,
+            ChangeUniquenessValue: ChangeUniquenessValue::<Identity, OFFSET>,
+            SetPriority: SetPriority::<Identity, OFFSET>,
+            GetPriority: GetPriority::<Identity, OFFSET>,
+            SetLOD: SetLOD::<Identity, OFFSET>,
+            GetLOD: GetLOD::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectDrawSurface7 as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectDrawSurface7_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddAttachedSurface<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::AddAttachedSurface(this, core::mem::transmute_copy(&param0)).into()
             }
         }
-        unsafe extern "system" fn AddOverlayDirtyRect<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+        unsafe extern "system" fn AddOverlayDirtyRect<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::AddOverlayDirtyRect(this, core::mem::transmute_copy(&param0)).into()
             }
         }
-        unsafe extern "system" fn Blt<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDBLTFX) -> windows_core::HRESULT {
+        unsafe extern "system" fn Blt<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDBLTFX) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::Blt(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
             }
         }
-        unsafe extern "system" fn BltBatch<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut DDBLTBATCH, param1: u32, param2: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn BltBatch<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut DDBLTBATCH, param1: u32, param2: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::BltBatch(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
             }
         }
-        unsafe extern "system" fn BltFast<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: *mut core::ffi::c_void, param3: *mut super::super::Foundation::RECT, param4: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn BltFast<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: *mut core::ffi::c_void, param3: *mut super::super::Foundation::RECT, param4: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::BltFast(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
             }
         }
-        unsafe extern "system" fn DeleteAttachedSurface<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn DeleteAttachedSurface<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::DeleteAttachedSurface(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
             }
         }
-        unsafe extern "system" fn EnumAttachedSurfaces<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: LPDDENUMSURFACESCALLBACK7) -> windows_core::HRESULT {
+        unsafe extern "system" fn EnumAttachedSurfaces<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: LPDDENUMSURFACESCALLBACK7) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::EnumAttachedSurfaces(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
             }
         }
-        unsafe extern "system" fn EnumOverlayZOrders<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void, param2: LPDDENUMSURFACESCALLBACK7) -> windows_core::HRESULT {
+        unsafe extern "system" fn EnumOverlayZOrders<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void, param2: LPDDENUMSURFACESCALLBACK7) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::EnumOverlayZOrders(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
             }
         }
-        unsafe extern "system" fn Flip<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn Flip<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::Flip(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
             }
         }
-        unsafe extern "system" fn GetAttachedSurface<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut DDSCAPS2, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetAttachedSurface<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSCAPS2, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::GetAttachedSurface(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
             }
         }
-        unsafe extern "system" fn GetBltStatus<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetBltStatus<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::GetBltStatus(this, core::mem::transmute_copy(&param0)).into()
             }
         }
-        unsafe extern "system" fn GetCaps<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut DDSCAPS2) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetCaps<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSCAPS2) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::GetCaps(this, core::mem::transmute_copy(&param0)).into()
             }
         }
-        unsafe extern "system" fn GetClipper<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetClipper<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDirectDrawSurface7_Impl::GetClipper(this) {
                     Ok(ok__) => {
                         param0.write(core::mem::transmute(ok__));
@@ -10059,33 +12499,33 @@ impl IDirectDrawSurface7_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetColorKey<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetColorKey<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::GetColorKey(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
             }
         }
-        unsafe extern "system" fn GetDC<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut super::Gdi::HDC) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetDC<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut super::Gdi::HDC) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::GetDC(this, core::mem::transmute_copy(&param0)).into()
             }
         }
-        unsafe extern "system" fn GetFlipStatus<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetFlipStatus<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::GetFlipStatus(this, core::mem::transmute_copy(&param0)).into()
             }
         }
-        unsafe extern "system" fn GetOverlayPosition<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut i32, param1: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetOverlayPosition<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut i32, param1: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::GetOverlayPosition(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
             }
         }
-        unsafe extern "system" fn GetPalette<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetPalette<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDirectDrawSurface7_Impl::GetPalette(this) {
                     Ok(ok__) => {
                         param0.write(core::mem::transmute(ok__));
@@ -10095,227 +12535,224 @@ impl IDirectDrawSurface7_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetPixelFormat<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut DDPIXELFORMAT) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetPixelFormat<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut DDPIXELFORMAT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::GetPixelFormat(this, core::mem::transmute_copy(&param0)).into()
             }
         }
-        unsafe extern "system" fn GetSurfaceDesc<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC2) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetSurfaceDesc<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC2) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::GetSurfaceDesc(this, core::mem::transmute_copy(&param0)).into()
             }
         }
-        unsafe extern "system" fn Initialize<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut DDSURFACEDESC2) -> windows_core::HRESULT {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut DDSURFACEDESC2) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::Initialize(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
             }
         }
-        unsafe extern "system" fn IsLost<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn IsLost<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::IsLost(this).into()
             }
         }
-        unsafe extern "system" fn Lock<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut DDSURFACEDESC2, param2: u32, param3: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+        unsafe extern "system" fn Lock<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut DDSURFACEDESC2, param2: u32, param3: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::Lock(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
             }
         }
-        unsafe extern "system" fn ReleaseDC<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: super::Gdi::HDC) -> windows_core::HRESULT {
+        unsafe extern "system" fn ReleaseDC<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: super::Gdi::HDC) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::ReleaseDC(this, core::mem::transmute_copy(&param0)).into()
             }
         }
-        unsafe extern "system" fn Restore<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Restore<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::Restore(this).into()
             }
         }
-        unsafe extern "system" fn SetClipper<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetClipper<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::SetClipper(this, core::mem::transmute_copy(&param0)).into()
             }
         }
-        unsafe extern "system" fn SetColorKey<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetColorKey<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::SetColorKey(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
             }
         }
-        unsafe extern "system" fn SetOverlayPosition<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: i32, param1: i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetOverlayPosition<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: i32, param1: i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::SetOverlayPosition(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
             }
         }
-        unsafe extern "system" fn SetPalette<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetPalette<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::SetPalette(this, core::mem::transmute_copy(&param0)).into()
             }
         }
-        unsafe extern "system" fn Unlock<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+        unsafe extern "system" fn Unlock<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::Unlock(this, core::mem::transmute_copy(&param0)).into()
             }
         }
-        unsafe extern "system" fn UpdateOverlay<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDOVERLAYFX) -> windows_core::HRESULT {
+        unsafe extern "system" fn UpdateOverlay<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDOVERLAYFX) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::UpdateOverlay(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
             }
         }
-        unsafe extern "system" fn UpdateOverlayDisplay<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn UpdateOverlayDisplay<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::UpdateOverlayDisplay(this, core::mem::transmute_copy(&param0)).into()
             }
         }
-        unsafe extern "system" fn UpdateOverlayZOrder<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn UpdateOverlayZOrder<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::UpdateOverlayZOrder(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
             }
         }
-        unsafe extern "system" fn GetDDInterface<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetDDInterface<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::GetDDInterface(this, core::mem::transmute_copy(&param0)).into()
             }
         }
-        unsafe extern "system" fn PageLock<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn PageLock<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::PageLock(this, core::mem::transmute_copy(&param0)).into()
             }
         }
-        unsafe extern "system" fn PageUnlock<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn PageUnlock<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::PageUnlock(this, core::mem::transmute_copy(&param0)).into()
             }
         }
-        unsafe extern "system" fn SetSurfaceDesc<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC2, param1: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetSurfaceDesc<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC2, param1: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::SetSurfaceDesc(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
             }
         }
-        unsafe extern "system" fn SetPrivateData<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut core::ffi::c_void, param2: u32, param3: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetPrivateData<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut core::ffi::c_void, param2: u32, param3: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::SetPrivateData(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
             }
         }
-        unsafe extern "system" fn GetPrivateData<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut core::ffi::c_void, param2: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetPrivateData<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut core::ffi::c_void, param2: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::GetPrivateData(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
             }
         }
-        unsafe extern "system" fn FreePrivateData<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID) -> windows_core::HRESULT {
+        unsafe extern "system" fn FreePrivateData<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::FreePrivateData(this, core::mem::transmute_copy(&param0)).into()
             }
         }
-        unsafe extern "system" fn GetUniquenessValue<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetUniquenessValue<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::GetUniquenessValue(this, core::mem::transmute_copy(&param0)).into()
             }
         }
-        unsafe extern "system" fn ChangeUniquenessValue<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn ChangeUniquenessValue<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::ChangeUniquenessValue(this).into()
             }
         }
-        unsafe extern "system" fn SetPriority<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetPriority<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::SetPriority(this, core::mem::transmute_copy(&param0)).into()
             }
         }
-        unsafe extern "system" fn GetPriority<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetPriority<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::GetPriority(this, core::mem::transmute_copy(&param0)).into()
             }
         }
-        unsafe extern "system" fn SetLOD<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetLOD<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::SetLOD(this, core::mem::transmute_copy(&param0)).into()
             }
         }
-        unsafe extern "system" fn GetLOD<Identity: IDirectDrawSurface7_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetLOD<Identity: windows_core::IUnknownImpl + IDirectDrawSurface7_Impl>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDirectDrawSurface7_Impl::GetLOD(this, core::mem::transmute_copy(&param0)).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            AddAttachedSurface: AddAttachedSurface::<Identity, OFFSET>,
-            AddOverlayDirtyRect: AddOverlayDirtyRect::<Identity, OFFSET>,
-            Blt: Blt::<Identity, OFFSET>,
-            BltBatch: BltBatch::<Identity, OFFSET>,
-            BltFast: BltFast::<Identity, OFFSET>,
-            DeleteAttachedSurface: DeleteAttachedSurface::<Identity, OFFSET>,
-            EnumAttachedSurfaces: EnumAttachedSurfaces::<Identity, OFFSET>,
-            EnumOverlayZOrders: EnumOverlayZOrders::<Identity, OFFSET>,
-            Flip: Flip::<Identity, OFFSET>,
-            GetAttachedSurface: GetAttachedSurface::<Identity, OFFSET>,
-            GetBltStatus: GetBltStatus::<Identity, OFFSET>,
-            GetCaps: GetCaps::<Identity, OFFSET>,
-            GetClipper: GetClipper::<Identity, OFFSET>,
-            GetColorKey: GetColorKey::<Identity, OFFSET>,
-            GetDC: GetDC::<Identity, OFFSET>,
-            GetFlipStatus: GetFlipStatus::<Identity, OFFSET>,
-            GetOverlayPosition: GetOverlayPosition::<Identity, OFFSET>,
-            GetPalette: GetPalette::<Identity, OFFSET>,
-            GetPixelFormat: GetPixelFormat::<Identity, OFFSET>,
-            GetSurfaceDesc: GetSurfaceDesc::<Identity, OFFSET>,
-            Initialize: Initialize::<Identity, OFFSET>,
-            IsLost: IsLost::<Identity, OFFSET>,
-            Lock: Lock::<Identity, OFFSET>,
-            ReleaseDC: ReleaseDC::<Identity, OFFSET>,
-            Restore: Restore::<Identity, OFFSET>,
-            SetClipper: SetClipper::<Identity, OFFSET>,
-            SetColorKey: SetColorKey::<Identity, OFFSET>,
-            SetOverlayPosition: SetOverlayPosition::<Identity, OFFSET>,
-            SetPalette: SetPalette::<Identity, OFFSET>,
-            Unlock: Unlock::<Identity, OFFSET>,
-            UpdateOverlay: UpdateOverlay::<Identity, OFFSET>,
-            UpdateOverlayDisplay: UpdateOverlayDisplay::<Identity, OFFSET>,
-            UpdateOverlayZOrder: UpdateOverlayZOrder::<Identity, OFFSET>,
-            GetDDInterface: GetDDInterface::<Identity, OFFSET>,
-            PageLock: PageLock::<Identity, OFFSET>,
-            PageUnlock: PageUnlock::<Identity, OFFSET>,
-            SetSurfaceDesc: SetSurfaceDesc::<Identity, OFFSET>,
-            SetPrivateData: SetPrivateData::<Identity, OFFSET>,
-            GetPrivateData: GetPrivateData::<Identity, OFFSET>,
-            FreePrivateData: FreePrivateData::<Identity, OFFSET>,
-            GetUniquenessValue: GetUniquenessValue::<Identity, OFFSET>,
-            ChangeUniquenessValue: ChangeUniquenessValue::<Identity, OFFSET>,
-            SetPriority: SetPriority::<Identity, OFFSET>,
-            GetPriority: GetPriority::<Identity, OFFSET>,
-            SetLOD: SetLOD::<Identity, OFFSET>,
-            GetLOD: GetLOD::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDirectDrawSurface7 as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddAttachedSurface: AddAttachedSurface::<Identity>,
+            AddOverlayDirtyRect: AddOverlayDirtyRect::<Identity>,
+            Blt: Blt::<Identity>,
+            BltBatch: BltBatch::<Identity>,
+            BltFast: BltFast::<Identity>,
+            DeleteAttachedSurface: DeleteAttachedSurface::<Identity>,
+            EnumAttachedSurfaces: EnumAttachedSurfaces::<Identity>,
+            EnumOverlayZOrders: EnumOverlayZOrders::<Identity>,
+            Flip: Flip::<Identity>,
+            GetAttachedSurface: GetAttachedSurface::<Identity>,
+            GetBltStatus: GetBltStatus::<Identity>,
+            GetCaps: GetCaps::<Identity>,
+            GetClipper: GetClipper::<Identity>,
+            GetColorKey: GetColorKey::<Identity>,
+            GetDC: GetDC::<Identity>,
+            GetFlipStatus: GetFlipStatus::<Identity>,
+            GetOverlayPosition: GetOverlayPosition::<Identity>,
+            GetPalette: GetPalette::<Identity>,
+            GetPixelFormat: GetPixelFormat::<Identity>,
+            GetSurfaceDesc: GetSurfaceDesc::<Identity>,
+            Initialize: Initialize::<Identity>,
+            IsLost: IsLost::<Identity>,
+            Lock: Lock::<Identity>,
+            ReleaseDC: ReleaseDC::<Identity>,
+            Restore: Restore::<Identity>,
+            SetClipper: SetClipper::<Identity>,
+            SetColorKey: SetColorKey::<Identity>,
+            SetOverlayPosition: SetOverlayPosition::<Identity>,
+            SetPalette: SetPalette::<Identity>,
+            Unlock: Unlock::<Identity>,
+            UpdateOverlay: UpdateOverlay::<Identity>,
+            UpdateOverlayDisplay: UpdateOverlayDisplay::<Identity>,
+            UpdateOverlayZOrder: UpdateOverlayZOrder::<Identity>,
+            GetDDInterface: GetDDInterface::<Identity>,
+            PageLock: PageLock::<Identity>,
+            PageUnlock: PageUnlock::<Identity>,
+            SetSurfaceDesc: SetSurfaceDesc::<Identity>,
+            SetPrivateData: SetPrivateData::<Identity>,
+            GetPrivateData: GetPrivateData::<Identity>,
+            FreePrivateData: FreePrivateData::<Identity>,
+            GetUniquenessValue: GetUniquenessValue::<Identity>,
+            ChangeUniquenessValue: ChangeUniquenessValue::<Identity>,
+            SetPriority: SetPriority::<Identity>,
+            GetPriority: GetPriority::<Identity>,
+            SetLOD: SetLOD::<Identity>,
+            GetLOD: GetLOD::<Identity>,
+        }
+    };
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IDirectDrawSurface7 {}
@@ -10364,6 +12801,30 @@ impl IDirectDrawSurfaceKernel_Vtbl {
         iid == &<IDirectDrawSurfaceKernel as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectDrawSurfaceKernel_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectDrawSurfaceKernel_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetKernelHandle<Identity: windows_core::IUnknownImpl + IDirectDrawSurfaceKernel_Impl>(this: *mut core::ffi::c_void, param0: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurfaceKernel_Impl::GetKernelHandle(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseKernelHandle<Identity: windows_core::IUnknownImpl + IDirectDrawSurfaceKernel_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawSurfaceKernel_Impl::ReleaseKernelHandle(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetKernelHandle: GetKernelHandle::<Identity>,
+            ReleaseKernelHandle: ReleaseKernelHandle::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectDrawSurfaceKernel {}
 windows_core::imp::define_interface!(IDirectDrawVideoPort, IDirectDrawVideoPort_Vtbl, 0xb36d93e0_2b43_11cf_a2de_00aa00b93356);
 windows_core::imp::interface_hierarchy!(IDirectDrawVideoPort, windows_core::IUnknown);
@@ -10560,6 +13021,114 @@ impl IDirectDrawVideoPort_Vtbl {
         iid == &<IDirectDrawVideoPort as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectDrawVideoPort_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectDrawVideoPort_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Flip<Identity: windows_core::IUnknownImpl + IDirectDrawVideoPort_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawVideoPort_Impl::Flip(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn GetBandwidthInfo<Identity: windows_core::IUnknownImpl + IDirectDrawVideoPort_Impl>(this: *mut core::ffi::c_void, param0: *mut DDPIXELFORMAT, param1: u32, param2: u32, param3: u32, param4: *mut DDVIDEOPORTBANDWIDTH) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawVideoPort_Impl::GetBandwidthInfo(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)).into()
+            }
+        }
+        unsafe extern "system" fn GetColorControls<Identity: windows_core::IUnknownImpl + IDirectDrawVideoPort_Impl>(this: *mut core::ffi::c_void, param0: *mut DDCOLORCONTROL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawVideoPort_Impl::GetColorControls(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetInputFormats<Identity: windows_core::IUnknownImpl + IDirectDrawVideoPort_Impl>(this: *mut core::ffi::c_void, lpnumformats: *mut u32, param1: *mut DDPIXELFORMAT, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawVideoPort_Impl::GetInputFormats(this, core::mem::transmute_copy(&lpnumformats), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        unsafe extern "system" fn GetOutputFormats<Identity: windows_core::IUnknownImpl + IDirectDrawVideoPort_Impl>(this: *mut core::ffi::c_void, param0: *mut DDPIXELFORMAT, lpnumformats: *mut u32, param2: *mut DDPIXELFORMAT, param3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawVideoPort_Impl::GetOutputFormats(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&lpnumformats), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)).into()
+            }
+        }
+        unsafe extern "system" fn GetFieldPolarity<Identity: windows_core::IUnknownImpl + IDirectDrawVideoPort_Impl>(this: *mut core::ffi::c_void, param0: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawVideoPort_Impl::GetFieldPolarity(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetVideoLine<Identity: windows_core::IUnknownImpl + IDirectDrawVideoPort_Impl>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawVideoPort_Impl::GetVideoLine(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetVideoSignalStatus<Identity: windows_core::IUnknownImpl + IDirectDrawVideoPort_Impl>(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawVideoPort_Impl::GetVideoSignalStatus(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SetColorControls<Identity: windows_core::IUnknownImpl + IDirectDrawVideoPort_Impl>(this: *mut core::ffi::c_void, param0: *mut DDCOLORCONTROL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawVideoPort_Impl::SetColorControls(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SetTargetSurface<Identity: windows_core::IUnknownImpl + IDirectDrawVideoPort_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawVideoPort_Impl::SetTargetSurface(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn StartVideo<Identity: windows_core::IUnknownImpl + IDirectDrawVideoPort_Impl>(this: *mut core::ffi::c_void, param0: *mut DDVIDEOPORTINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawVideoPort_Impl::StartVideo(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn StopVideo<Identity: windows_core::IUnknownImpl + IDirectDrawVideoPort_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawVideoPort_Impl::StopVideo(this).into()
+            }
+        }
+        unsafe extern "system" fn UpdateVideo<Identity: windows_core::IUnknownImpl + IDirectDrawVideoPort_Impl>(this: *mut core::ffi::c_void, param0: *mut DDVIDEOPORTINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawVideoPort_Impl::UpdateVideo(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn WaitForSync<Identity: windows_core::IUnknownImpl + IDirectDrawVideoPort_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawVideoPort_Impl::WaitForSync(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Flip: Flip::<Identity>,
+            GetBandwidthInfo: GetBandwidthInfo::<Identity>,
+            GetColorControls: GetColorControls::<Identity>,
+            GetInputFormats: GetInputFormats::<Identity>,
+            GetOutputFormats: GetOutputFormats::<Identity>,
+            GetFieldPolarity: GetFieldPolarity::<Identity>,
+            GetVideoLine: GetVideoLine::<Identity>,
+            GetVideoSignalStatus: GetVideoSignalStatus::<Identity>,
+            SetColorControls: SetColorControls::<Identity>,
+            SetTargetSurface: SetTargetSurface::<Identity>,
+            StartVideo: StartVideo::<Identity>,
+            StopVideo: StopVideo::<Identity>,
+            UpdateVideo: UpdateVideo::<Identity>,
+            WaitForSync: WaitForSync::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectDrawVideoPort {}
 windows_core::imp::define_interface!(IDirectDrawVideoPortNotify, IDirectDrawVideoPortNotify_Vtbl, 0xa655fb94_0589_4e57_b333_567a89468c88);
 windows_core::imp::interface_hierarchy!(IDirectDrawVideoPortNotify, windows_core::IUnknown);
@@ -10606,6 +13175,30 @@ impl IDirectDrawVideoPortNotify_Vtbl {
         iid == &<IDirectDrawVideoPortNotify as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectDrawVideoPortNotify_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectDrawVideoPortNotify_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AcquireNotification<Identity: windows_core::IUnknownImpl + IDirectDrawVideoPortNotify_Impl>(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::HANDLE, param1: *mut DDVIDEOPORTNOTIFY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawVideoPortNotify_Impl::AcquireNotification(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseNotification<Identity: windows_core::IUnknownImpl + IDirectDrawVideoPortNotify_Impl>(this: *mut core::ffi::c_void, param0: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectDrawVideoPortNotify_Impl::ReleaseNotification(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AcquireNotification: AcquireNotification::<Identity>,
+            ReleaseNotification: ReleaseNotification::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectDrawVideoPortNotify {}
 pub const IRQINFO_HANDLED: u32 = 1u32;
 pub const IRQINFO_NOTHANDLED: u32 = 2u32;
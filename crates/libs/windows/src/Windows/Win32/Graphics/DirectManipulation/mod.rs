@@ -461,6 +461,20 @@ impl IDirectManipulationAutoScrollBehavior_Vtbl {
         iid == &<IDirectManipulationAutoScrollBehavior as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectManipulationAutoScrollBehavior_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectManipulationAutoScrollBehavior_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetConfiguration<Identity: windows_core::IUnknownImpl + IDirectManipulationAutoScrollBehavior_Impl>(this: *mut core::ffi::c_void, motiontypes: DIRECTMANIPULATION_MOTION_TYPES, scrollmotion: DIRECTMANIPULATION_AUTOSCROLL_CONFIGURATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationAutoScrollBehavior_Impl::SetConfiguration(this, core::mem::transmute_copy(&motiontypes), core::mem::transmute_copy(&scrollmotion)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetConfiguration: SetConfiguration::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDirectManipulationAutoScrollBehavior {}
 windows_core::imp::define_interface!(IDirectManipulationCompositor, IDirectManipulationCompositor_Vtbl, 0x537a0825_0387_4efa_b62f_71eb1f085a7e);
 windows_core::imp::interface_hierarchy!(IDirectManipulationCompositor, windows_core::IUnknown);
@@ -543,6 +557,44 @@ impl IDirectManipulationCompositor_Vtbl {
         iid == &<IDirectManipulationCompositor as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectManipulationCompositor_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectManipulationCompositor_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddContent<Identity: windows_core::IUnknownImpl + IDirectManipulationCompositor_Impl>(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void, device: *mut core::ffi::c_void, parentvisual: *mut core::ffi::c_void, childvisual: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationCompositor_Impl::AddContent(this, core::mem::transmute_copy(&content), core::mem::transmute_copy(&device), core::mem::transmute_copy(&parentvisual), core::mem::transmute_copy(&childvisual)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveContent<Identity: windows_core::IUnknownImpl + IDirectManipulationCompositor_Impl>(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationCompositor_Impl::RemoveContent(this, core::mem::transmute_copy(&content)).into()
+            }
+        }
+        unsafe extern "system" fn SetUpdateManager<Identity: windows_core::IUnknownImpl + IDirectManipulationCompositor_Impl>(this: *mut core::ffi::c_void, updatemanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationCompositor_Impl::SetUpdateManager(this, core::mem::transmute_copy(&updatemanager)).into()
+            }
+        }
+        unsafe extern "system" fn Flush<Identity: windows_core::IUnknownImpl + IDirectManipulationCompositor_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationCompositor_Impl::Flush(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddContent: AddContent::<Identity>,
+            RemoveContent: RemoveContent::<Identity>,
+            SetUpdateManager: SetUpdateManager::<Identity>,
+            Flush: Flush::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectManipulationCompositor {}
 windows_core::imp::define_interface!(IDirectManipulationCompositor2, IDirectManipulationCompositor2_Vtbl, 0xd38c7822_f1cb_43cb_b4b9_ac0c767a412e);
 impl core::ops::Deref for IDirectManipulationCompositor2 {
@@ -589,6 +641,23 @@ impl IDirectManipulationCompositor2_Vtbl {
         iid == &<IDirectManipulationCompositor2 as windows_core::Interface>::IID || iid == &<IDirectManipulationCompositor as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectManipulationCompositor2_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectManipulationCompositor2_Vtbl
+where
+    IDirectManipulationCompositor_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddContentWithCrossProcessChaining<Identity: windows_core::IUnknownImpl + IDirectManipulationCompositor2_Impl>(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void, device: *mut core::ffi::c_void, parentvisual: *mut core::ffi::c_void, childvisual: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationCompositor2_Impl::AddContentWithCrossProcessChaining(this, core::mem::transmute_copy(&content), core::mem::transmute_copy(&device), core::mem::transmute_copy(&parentvisual), core::mem::transmute_copy(&childvisual)).into()
+            }
+        }
+        Self {
+            base__: <IDirectManipulationCompositor_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddContentWithCrossProcessChaining: AddContentWithCrossProcessChaining::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectManipulationCompositor2 {}
 windows_core::imp::define_interface!(IDirectManipulationContent, IDirectManipulationContent_Vtbl, 0xb89962cb_3d89_442b_bb58_5098fa0f9f16);
 windows_core::imp::interface_hierarchy!(IDirectManipulationContent, windows_core::IUnknown);
@@ -726,6 +795,78 @@ impl IDirectManipulationContent_Vtbl {
         iid == &<IDirectManipulationContent as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectManipulationContent_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectManipulationContent_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetContentRect<Identity: windows_core::IUnknownImpl + IDirectManipulationContent_Impl>(this: *mut core::ffi::c_void, contentsize: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectManipulationContent_Impl::GetContentRect(this) {
+                    Ok(ok__) => {
+                        contentsize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetContentRect<Identity: windows_core::IUnknownImpl + IDirectManipulationContent_Impl>(this: *mut core::ffi::c_void, contentsize: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationContent_Impl::SetContentRect(this, core::mem::transmute_copy(&contentsize)).into()
+            }
+        }
+        unsafe extern "system" fn GetViewport<Identity: windows_core::IUnknownImpl + IDirectManipulationContent_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, object: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationContent_Impl::GetViewport(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&object)).into()
+            }
+        }
+        unsafe extern "system" fn GetTag<Identity: windows_core::IUnknownImpl + IDirectManipulationContent_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, object: *mut *mut core::ffi::c_void, id: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationContent_Impl::GetTag(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&object), core::mem::transmute_copy(&id)).into()
+            }
+        }
+        unsafe extern "system" fn SetTag<Identity: windows_core::IUnknownImpl + IDirectManipulationContent_Impl>(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void, id: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationContent_Impl::SetTag(this, core::mem::transmute_copy(&object), core::mem::transmute_copy(&id)).into()
+            }
+        }
+        unsafe extern "system" fn GetOutputTransform<Identity: windows_core::IUnknownImpl + IDirectManipulationContent_Impl>(this: *mut core::ffi::c_void, matrix: *mut f32, pointcount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationContent_Impl::GetOutputTransform(this, core::mem::transmute_copy(&matrix), core::mem::transmute_copy(&pointcount)).into()
+            }
+        }
+        unsafe extern "system" fn GetContentTransform<Identity: windows_core::IUnknownImpl + IDirectManipulationContent_Impl>(this: *mut core::ffi::c_void, matrix: *mut f32, pointcount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationContent_Impl::GetContentTransform(this, core::mem::transmute_copy(&matrix), core::mem::transmute_copy(&pointcount)).into()
+            }
+        }
+        unsafe extern "system" fn SyncContentTransform<Identity: windows_core::IUnknownImpl + IDirectManipulationContent_Impl>(this: *mut core::ffi::c_void, matrix: *const f32, pointcount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationContent_Impl::SyncContentTransform(this, core::mem::transmute_copy(&matrix), core::mem::transmute_copy(&pointcount)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetContentRect: GetContentRect::<Identity>,
+            SetContentRect: SetContentRect::<Identity>,
+            GetViewport: GetViewport::<Identity>,
+            GetTag: GetTag::<Identity>,
+            SetTag: SetTag::<Identity>,
+            GetOutputTransform: GetOutputTransform::<Identity>,
+            GetContentTransform: GetContentTransform::<Identity>,
+            SyncContentTransform: SyncContentTransform::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectManipulationContent {}
 windows_core::imp::define_interface!(IDirectManipulationDeferContactService, IDirectManipulationDeferContactService_Vtbl, 0x652d5c71_fe60_4a98_be70_e5f21291e7f1);
 windows_core::imp::interface_hierarchy!(IDirectManipulationDeferContactService, windows_core::IUnknown);
@@ -784,6 +925,37 @@ impl IDirectManipulationDeferContactService_Vtbl {
         iid == &<IDirectManipulationDeferContactService as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectManipulationDeferContactService_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectManipulationDeferContactService_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DeferContact<Identity: windows_core::IUnknownImpl + IDirectManipulationDeferContactService_Impl>(this: *mut core::ffi::c_void, pointerid: u32, timeout: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationDeferContactService_Impl::DeferContact(this, core::mem::transmute_copy(&pointerid), core::mem::transmute_copy(&timeout)).into()
+            }
+        }
+        unsafe extern "system" fn CancelContact<Identity: windows_core::IUnknownImpl + IDirectManipulationDeferContactService_Impl>(this: *mut core::ffi::c_void, pointerid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationDeferContactService_Impl::CancelContact(this, core::mem::transmute_copy(&pointerid)).into()
+            }
+        }
+        unsafe extern "system" fn CancelDeferral<Identity: windows_core::IUnknownImpl + IDirectManipulationDeferContactService_Impl>(this: *mut core::ffi::c_void, pointerid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationDeferContactService_Impl::CancelDeferral(this, core::mem::transmute_copy(&pointerid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DeferContact: DeferContact::<Identity>,
+            CancelContact: CancelContact::<Identity>,
+            CancelDeferral: CancelDeferral::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectManipulationDeferContactService {}
 windows_core::imp::define_interface!(IDirectManipulationDragDropBehavior, IDirectManipulationDragDropBehavior_Vtbl, 0x814b5af5_c2c8_4270_a9b7_a198ce8d02fa);
 windows_core::imp::interface_hierarchy!(IDirectManipulationDragDropBehavior, windows_core::IUnknown);
@@ -839,6 +1011,36 @@ impl IDirectManipulationDragDropBehavior_Vtbl {
         iid == &<IDirectManipulationDragDropBehavior as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectManipulationDragDropBehavior_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectManipulationDragDropBehavior_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetConfiguration<Identity: windows_core::IUnknownImpl + IDirectManipulationDragDropBehavior_Impl>(this: *mut core::ffi::c_void, configuration: DIRECTMANIPULATION_DRAG_DROP_CONFIGURATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationDragDropBehavior_Impl::SetConfiguration(this, core::mem::transmute_copy(&configuration)).into()
+            }
+        }
+        unsafe extern "system" fn GetStatus<Identity: windows_core::IUnknownImpl + IDirectManipulationDragDropBehavior_Impl>(this: *mut core::ffi::c_void, status: *mut DIRECTMANIPULATION_DRAG_DROP_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectManipulationDragDropBehavior_Impl::GetStatus(this) {
+                    Ok(ok__) => {
+                        status.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetConfiguration: SetConfiguration::<Identity>,
+            GetStatus: GetStatus::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectManipulationDragDropBehavior {}
 windows_core::imp::define_interface!(IDirectManipulationDragDropEventHandler, IDirectManipulationDragDropEventHandler_Vtbl, 0x1fa11b10_701b_41ae_b5f2_49e36bd595aa);
 windows_core::imp::interface_hierarchy!(IDirectManipulationDragDropEventHandler, windows_core::IUnknown);
@@ -873,6 +1075,23 @@ impl IDirectManipulationDragDropEventHandler_Vtbl {
         iid == &<IDirectManipulationDragDropEventHandler as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectManipulationDragDropEventHandler_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectManipulationDragDropEventHandler_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnDragDropStatusChange<Identity: windows_core::IUnknownImpl + IDirectManipulationDragDropEventHandler_Impl>(this: *mut core::ffi::c_void, viewport: *mut core::ffi::c_void, current: DIRECTMANIPULATION_DRAG_DROP_STATUS, previous: DIRECTMANIPULATION_DRAG_DROP_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationDragDropEventHandler_Impl::OnDragDropStatusChange(this, core::mem::transmute_copy(&viewport), core::mem::transmute_copy(&current), core::mem::transmute_copy(&previous)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnDragDropStatusChange: OnDragDropStatusChange::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectManipulationDragDropEventHandler {}
 windows_core::imp::define_interface!(IDirectManipulationFrameInfoProvider, IDirectManipulationFrameInfoProvider_Vtbl, 0xfb759dba_6f4c_4c01_874e_19c8a05907f9);
 windows_core::imp::interface_hierarchy!(IDirectManipulationFrameInfoProvider, windows_core::IUnknown);
@@ -904,6 +1123,20 @@ impl IDirectManipulationFrameInfoProvider_Vtbl {
         iid == &<IDirectManipulationFrameInfoProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectManipulationFrameInfoProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectManipulationFrameInfoProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetNextFrameInfo<Identity: windows_core::IUnknownImpl + IDirectManipulationFrameInfoProvider_Impl>(this: *mut core::ffi::c_void, time: *mut u64, processtime: *mut u64, compositiontime: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationFrameInfoProvider_Impl::GetNextFrameInfo(this, core::mem::transmute_copy(&time), core::mem::transmute_copy(&processtime), core::mem::transmute_copy(&compositiontime)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetNextFrameInfo: GetNextFrameInfo::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDirectManipulationFrameInfoProvider {}
 windows_core::imp::define_interface!(IDirectManipulationInteractionEventHandler, IDirectManipulationInteractionEventHandler_Vtbl, 0xe43f45b8_42b4_403e_b1f2_273b8f510830);
 windows_core::imp::interface_hierarchy!(IDirectManipulationInteractionEventHandler, windows_core::IUnknown);
@@ -938,6 +1171,20 @@ impl IDirectManipulationInteractionEventHandler_Vtbl {
         iid == &<IDirectManipulationInteractionEventHandler as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectManipulationInteractionEventHandler_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectManipulationInteractionEventHandler_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnInteraction<Identity: windows_core::IUnknownImpl + IDirectManipulationInteractionEventHandler_Impl>(this: *mut core::ffi::c_void, viewport: *mut core::ffi::c_void, interaction: DIRECTMANIPULATION_INTERACTION_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationInteractionEventHandler_Impl::OnInteraction(this, core::mem::transmute_copy(&viewport), core::mem::transmute_copy(&interaction)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnInteraction: OnInteraction::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDirectManipulationInteractionEventHandler {}
 windows_core::imp::define_interface!(IDirectManipulationManager, IDirectManipulationManager_Vtbl, 0xfbf5d3b4_70c7_4163_9322_5a6f660d6fbc);
 windows_core::imp::interface_hierarchy!(IDirectManipulationManager, windows_core::IUnknown);
@@ -1074,6 +1321,72 @@ impl IDirectManipulationManager_Vtbl {
     }
 }
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl<Identity: windows_core::IUnknownImpl + IDirectManipulationManager_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectManipulationManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Activate<Identity: windows_core::IUnknownImpl + IDirectManipulationManager_Impl>(this: *mut core::ffi::c_void, window: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationManager_Impl::Activate(this, core::mem::transmute_copy(&window)).into()
+            }
+        }
+        unsafe extern "system" fn Deactivate<Identity: windows_core::IUnknownImpl + IDirectManipulationManager_Impl>(this: *mut core::ffi::c_void, window: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationManager_Impl::Deactivate(this, core::mem::transmute_copy(&window)).into()
+            }
+        }
+        unsafe extern "system" fn RegisterHitTestTarget<Identity: windows_core::IUnknownImpl + IDirectManipulationManager_Impl>(this: *mut core::ffi::c_void, window: super::super::Foundation::HWND, hittestwindow: super::super::Foundation::HWND, r#type: DIRECTMANIPULATION_HITTEST_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationManager_Impl::RegisterHitTestTarget(this, core::mem::transmute_copy(&window), core::mem::transmute_copy(&hittestwindow), core::mem::transmute_copy(&r#type)).into()
+            }
+        }
+        unsafe extern "system" fn ProcessInput<Identity: windows_core::IUnknownImpl + IDirectManipulationManager_Impl>(this: *mut core::ffi::c_void, message: *const super::super::UI::WindowsAndMessaging::MSG, handled: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectManipulationManager_Impl::ProcessInput(this, core::mem::transmute_copy(&message)) {
+                    Ok(ok__) => {
+                        handled.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetUpdateManager<Identity: windows_core::IUnknownImpl + IDirectManipulationManager_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, object: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationManager_Impl::GetUpdateManager(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&object)).into()
+            }
+        }
+        unsafe extern "system" fn CreateViewport<Identity: windows_core::IUnknownImpl + IDirectManipulationManager_Impl>(this: *mut core::ffi::c_void, frameinfo: *mut core::ffi::c_void, window: super::super::Foundation::HWND, riid: *const windows_core::GUID, object: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationManager_Impl::CreateViewport(this, core::mem::transmute_copy(&frameinfo), core::mem::transmute_copy(&window), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&object)).into()
+            }
+        }
+        unsafe extern "system" fn CreateContent<Identity: windows_core::IUnknownImpl + IDirectManipulationManager_Impl>(this: *mut core::ffi::c_void, frameinfo: *mut core::ffi::c_void, clsid: *const windows_core::GUID, riid: *const windows_core::GUID, object: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationManager_Impl::CreateContent(this, core::mem::transmute_copy(&frameinfo), core::mem::transmute_copy(&clsid), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&object)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Activate: Activate::<Identity>,
+            Deactivate: Deactivate::<Identity>,
+            RegisterHitTestTarget: RegisterHitTestTarget::<Identity>,
+            ProcessInput: ProcessInput::<Identity>,
+            GetUpdateManager: GetUpdateManager::<Identity>,
+            CreateViewport: CreateViewport::<Identity>,
+            CreateContent: CreateContent::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
 impl windows_core::RuntimeName for IDirectManipulationManager {}
 windows_core::imp::define_interface!(IDirectManipulationManager2, IDirectManipulationManager2_Vtbl, 0xfa1005e9_3d16_484c_bfc9_62b61e56ec4e);
 impl core::ops::Deref for IDirectManipulationManager2 {
@@ -1118,6 +1431,21 @@ impl IDirectManipulationManager2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl<Identity: windows_core::IUnknownImpl + IDirectManipulationManager2_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectManipulationManager2_Vtbl
+where
+    IDirectManipulationManager_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateBehavior<Identity: windows_core::IUnknownImpl + IDirectManipulationManager2_Impl>(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID, riid: *const windows_core::GUID, object: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationManager2_Impl::CreateBehavior(this, core::mem::transmute_copy(&clsid), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&object)).into()
+            }
+        }
+        Self { base__: <IDirectManipulationManager_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateBehavior: CreateBehavior::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
 impl windows_core::RuntimeName for IDirectManipulationManager2 {}
 windows_core::imp::define_interface!(IDirectManipulationManager3, IDirectManipulationManager3_Vtbl, 0x2cb6b33d_ffe8_488c_b750_fbdfe88dca8c);
 impl core::ops::Deref for IDirectManipulationManager3 {
@@ -1162,6 +1490,21 @@ impl IDirectManipulationManager3_Vtbl {
     }
 }
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl<Identity: windows_core::IUnknownImpl + IDirectManipulationManager3_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectManipulationManager3_Vtbl
+where
+    IDirectManipulationManager2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetService<Identity: windows_core::IUnknownImpl + IDirectManipulationManager3_Impl>(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID, riid: *const windows_core::GUID, object: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationManager3_Impl::GetService(this, core::mem::transmute_copy(&clsid), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&object)).into()
+            }
+        }
+        Self { base__: <IDirectManipulationManager2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetService: GetService::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
 impl windows_core::RuntimeName for IDirectManipulationManager3 {}
 windows_core::imp::define_interface!(IDirectManipulationPrimaryContent, IDirectManipulationPrimaryContent_Vtbl, 0xc12851e4_1698_4625_b9b1_7ca3ec18630b);
 windows_core::imp::interface_hierarchy!(IDirectManipulationPrimaryContent, windows_core::IUnknown);
@@ -1292,6 +1635,79 @@ impl IDirectManipulationPrimaryContent_Vtbl {
         iid == &<IDirectManipulationPrimaryContent as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectManipulationPrimaryContent_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectManipulationPrimaryContent_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetSnapInterval<Identity: windows_core::IUnknownImpl + IDirectManipulationPrimaryContent_Impl>(this: *mut core::ffi::c_void, motion: DIRECTMANIPULATION_MOTION_TYPES, interval: f32, offset: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationPrimaryContent_Impl::SetSnapInterval(this, core::mem::transmute_copy(&motion), core::mem::transmute_copy(&interval), core::mem::transmute_copy(&offset)).into()
+            }
+        }
+        unsafe extern "system" fn SetSnapPoints<Identity: windows_core::IUnknownImpl + IDirectManipulationPrimaryContent_Impl>(this: *mut core::ffi::c_void, motion: DIRECTMANIPULATION_MOTION_TYPES, points: *const f32, pointcount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationPrimaryContent_Impl::SetSnapPoints(this, core::mem::transmute_copy(&motion), core::mem::transmute_copy(&points), core::mem::transmute_copy(&pointcount)).into()
+            }
+        }
+        unsafe extern "system" fn SetSnapType<Identity: windows_core::IUnknownImpl + IDirectManipulationPrimaryContent_Impl>(this: *mut core::ffi::c_void, motion: DIRECTMANIPULATION_MOTION_TYPES, r#type: DIRECTMANIPULATION_SNAPPOINT_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationPrimaryContent_Impl::SetSnapType(this, core::mem::transmute_copy(&motion), core::mem::transmute_copy(&r#type)).into()
+            }
+        }
+        unsafe extern "system" fn SetSnapCoordinate<Identity: windows_core::IUnknownImpl + IDirectManipulationPrimaryContent_Impl>(this: *mut core::ffi::c_void, motion: DIRECTMANIPULATION_MOTION_TYPES, coordinate: DIRECTMANIPULATION_SNAPPOINT_COORDINATE, origin: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationPrimaryContent_Impl::SetSnapCoordinate(this, core::mem::transmute_copy(&motion), core::mem::transmute_copy(&coordinate), core::mem::transmute_copy(&origin)).into()
+            }
+        }
+        unsafe extern "system" fn SetZoomBoundaries<Identity: windows_core::IUnknownImpl + IDirectManipulationPrimaryContent_Impl>(this: *mut core::ffi::c_void, zoomminimum: f32, zoommaximum: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationPrimaryContent_Impl::SetZoomBoundaries(this, core::mem::transmute_copy(&zoomminimum), core::mem::transmute_copy(&zoommaximum)).into()
+            }
+        }
+        unsafe extern "system" fn SetHorizontalAlignment<Identity: windows_core::IUnknownImpl + IDirectManipulationPrimaryContent_Impl>(this: *mut core::ffi::c_void, alignment: DIRECTMANIPULATION_HORIZONTALALIGNMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationPrimaryContent_Impl::SetHorizontalAlignment(this, core::mem::transmute_copy(&alignment)).into()
+            }
+        }
+        unsafe extern "system" fn SetVerticalAlignment<Identity: windows_core::IUnknownImpl + IDirectManipulationPrimaryContent_Impl>(this: *mut core::ffi::c_void, alignment: DIRECTMANIPULATION_VERTICALALIGNMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationPrimaryContent_Impl::SetVerticalAlignment(this, core::mem::transmute_copy(&alignment)).into()
+            }
+        }
+        unsafe extern "system" fn GetInertiaEndTransform<Identity: windows_core::IUnknownImpl + IDirectManipulationPrimaryContent_Impl>(this: *mut core::ffi::c_void, matrix: *mut f32, pointcount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationPrimaryContent_Impl::GetInertiaEndTransform(this, core::mem::transmute_copy(&matrix), core::mem::transmute_copy(&pointcount)).into()
+            }
+        }
+        unsafe extern "system" fn GetCenterPoint<Identity: windows_core::IUnknownImpl + IDirectManipulationPrimaryContent_Impl>(this: *mut core::ffi::c_void, centerx: *mut f32, centery: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationPrimaryContent_Impl::GetCenterPoint(this, core::mem::transmute_copy(&centerx), core::mem::transmute_copy(&centery)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetSnapInterval: SetSnapInterval::<Identity>,
+            SetSnapPoints: SetSnapPoints::<Identity>,
+            SetSnapType: SetSnapType::<Identity>,
+            SetSnapCoordinate: SetSnapCoordinate::<Identity>,
+            SetZoomBoundaries: SetZoomBoundaries::<Identity>,
+            SetHorizontalAlignment: SetHorizontalAlignment::<Identity>,
+            SetVerticalAlignment: SetVerticalAlignment::<Identity>,
+            GetInertiaEndTransform: GetInertiaEndTransform::<Identity>,
+            GetCenterPoint: GetCenterPoint::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectManipulationPrimaryContent {}
 windows_core::imp::define_interface!(IDirectManipulationUpdateHandler, IDirectManipulationUpdateHandler_Vtbl, 0x790b6337_64f8_4ff5_a269_b32bc2af27a7);
 windows_core::imp::interface_hierarchy!(IDirectManipulationUpdateHandler, windows_core::IUnknown);
@@ -1323,6 +1739,20 @@ impl IDirectManipulationUpdateHandler_Vtbl {
         iid == &<IDirectManipulationUpdateHandler as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectManipulationUpdateHandler_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectManipulationUpdateHandler_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Update<Identity: windows_core::IUnknownImpl + IDirectManipulationUpdateHandler_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationUpdateHandler_Impl::Update(this).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Update: Update::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDirectManipulationUpdateHandler {}
 windows_core::imp::define_interface!(IDirectManipulationUpdateManager, IDirectManipulationUpdateManager_Vtbl, 0xb0ae62fd_be34_46e7_9caa_d361facbb9cc);
 windows_core::imp::interface_hierarchy!(IDirectManipulationUpdateManager, windows_core::IUnknown);
@@ -1396,6 +1826,43 @@ impl IDirectManipulationUpdateManager_Vtbl {
         iid == &<IDirectManipulationUpdateManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectManipulationUpdateManager_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectManipulationUpdateManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterWaitHandleCallback<Identity: windows_core::IUnknownImpl + IDirectManipulationUpdateManager_Impl>(this: *mut core::ffi::c_void, handle: super::super::Foundation::HANDLE, eventhandler: *mut core::ffi::c_void, cookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectManipulationUpdateManager_Impl::RegisterWaitHandleCallback(this, core::mem::transmute_copy(&handle), core::mem::transmute_copy(&eventhandler)) {
+                    Ok(ok__) => {
+                        cookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UnregisterWaitHandleCallback<Identity: windows_core::IUnknownImpl + IDirectManipulationUpdateManager_Impl>(this: *mut core::ffi::c_void, cookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationUpdateManager_Impl::UnregisterWaitHandleCallback(this, core::mem::transmute_copy(&cookie)).into()
+            }
+        }
+        unsafe extern "system" fn Update<Identity: windows_core::IUnknownImpl + IDirectManipulationUpdateManager_Impl>(this: *mut core::ffi::c_void, frameinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationUpdateManager_Impl::Update(this, core::mem::transmute_copy(&frameinfo)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterWaitHandleCallback: RegisterWaitHandleCallback::<Identity>,
+            UnregisterWaitHandleCallback: UnregisterWaitHandleCallback::<Identity>,
+            Update: Update::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectManipulationUpdateManager {}
 windows_core::imp::define_interface!(IDirectManipulationViewport, IDirectManipulationViewport_Vtbl, 0x28b85a3d_60a0_48bd_9ba1_5ce8d9ea3a6d);
 windows_core::imp::interface_hierarchy!(IDirectManipulationViewport, windows_core::IUnknown);
@@ -1800,6 +2267,230 @@ impl IDirectManipulationViewport_Vtbl {
         iid == &<IDirectManipulationViewport as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectManipulationViewport_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Enable<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::Enable(this).into()
+            }
+        }
+        unsafe extern "system" fn Disable<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::Disable(this).into()
+            }
+        }
+        unsafe extern "system" fn SetContact<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void, pointerid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::SetContact(this, core::mem::transmute_copy(&pointerid)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseContact<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void, pointerid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::ReleaseContact(this, core::mem::transmute_copy(&pointerid)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseAllContacts<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::ReleaseAllContacts(this).into()
+            }
+        }
+        unsafe extern "system" fn GetStatus<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void, status: *mut DIRECTMANIPULATION_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectManipulationViewport_Impl::GetStatus(this) {
+                    Ok(ok__) => {
+                        status.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTag<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, object: *mut *mut core::ffi::c_void, id: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::GetTag(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&object), core::mem::transmute_copy(&id)).into()
+            }
+        }
+        unsafe extern "system" fn SetTag<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void, id: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::SetTag(this, core::mem::transmute_copy(&object), core::mem::transmute_copy(&id)).into()
+            }
+        }
+        unsafe extern "system" fn GetViewportRect<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void, viewport: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectManipulationViewport_Impl::GetViewportRect(this) {
+                    Ok(ok__) => {
+                        viewport.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetViewportRect<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void, viewport: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::SetViewportRect(this, core::mem::transmute_copy(&viewport)).into()
+            }
+        }
+        unsafe extern "system" fn ZoomToRect<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void, left: f32, top: f32, right: f32, bottom: f32, animate: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::ZoomToRect(this, core::mem::transmute_copy(&left), core::mem::transmute_copy(&top), core::mem::transmute_copy(&right), core::mem::transmute_copy(&bottom), core::mem::transmute_copy(&animate)).into()
+            }
+        }
+        unsafe extern "system" fn SetViewportTransform<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void, matrix: *const f32, pointcount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::SetViewportTransform(this, core::mem::transmute_copy(&matrix), core::mem::transmute_copy(&pointcount)).into()
+            }
+        }
+        unsafe extern "system" fn SyncDisplayTransform<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void, matrix: *const f32, pointcount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::SyncDisplayTransform(this, core::mem::transmute_copy(&matrix), core::mem::transmute_copy(&pointcount)).into()
+            }
+        }
+        unsafe extern "system" fn GetPrimaryContent<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, object: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::GetPrimaryContent(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&object)).into()
+            }
+        }
+        unsafe extern "system" fn AddContent<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::AddContent(this, core::mem::transmute_copy(&content)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveContent<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::RemoveContent(this, core::mem::transmute_copy(&content)).into()
+            }
+        }
+        unsafe extern "system" fn SetViewportOptions<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void, options: DIRECTMANIPULATION_VIEWPORT_OPTIONS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::SetViewportOptions(this, core::mem::transmute_copy(&options)).into()
+            }
+        }
+        unsafe extern "system" fn AddConfiguration<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void, configuration: DIRECTMANIPULATION_CONFIGURATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::AddConfiguration(this, core::mem::transmute_copy(&configuration)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveConfiguration<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void, configuration: DIRECTMANIPULATION_CONFIGURATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::RemoveConfiguration(this, core::mem::transmute_copy(&configuration)).into()
+            }
+        }
+        unsafe extern "system" fn ActivateConfiguration<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void, configuration: DIRECTMANIPULATION_CONFIGURATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::ActivateConfiguration(this, core::mem::transmute_copy(&configuration)).into()
+            }
+        }
+        unsafe extern "system" fn SetManualGesture<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void, configuration: DIRECTMANIPULATION_GESTURE_CONFIGURATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::SetManualGesture(this, core::mem::transmute_copy(&configuration)).into()
+            }
+        }
+        unsafe extern "system" fn SetChaining<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void, enabledtypes: DIRECTMANIPULATION_MOTION_TYPES) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::SetChaining(this, core::mem::transmute_copy(&enabledtypes)).into()
+            }
+        }
+        unsafe extern "system" fn AddEventHandler<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void, window: super::super::Foundation::HWND, eventhandler: *mut core::ffi::c_void, cookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectManipulationViewport_Impl::AddEventHandler(this, core::mem::transmute_copy(&window), core::mem::transmute_copy(&eventhandler)) {
+                    Ok(ok__) => {
+                        cookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveEventHandler<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void, cookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::RemoveEventHandler(this, core::mem::transmute_copy(&cookie)).into()
+            }
+        }
+        unsafe extern "system" fn SetInputMode<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void, mode: DIRECTMANIPULATION_INPUT_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::SetInputMode(this, core::mem::transmute_copy(&mode)).into()
+            }
+        }
+        unsafe extern "system" fn SetUpdateMode<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void, mode: DIRECTMANIPULATION_INPUT_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::SetUpdateMode(this, core::mem::transmute_copy(&mode)).into()
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::Stop(this).into()
+            }
+        }
+        unsafe extern "system" fn Abandon<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport_Impl::Abandon(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Enable: Enable::<Identity>,
+            Disable: Disable::<Identity>,
+            SetContact: SetContact::<Identity>,
+            ReleaseContact: ReleaseContact::<Identity>,
+            ReleaseAllContacts: ReleaseAllContacts::<Identity>,
+            GetStatus: GetStatus::<Identity>,
+            GetTag: GetTag::<Identity>,
+            SetTag: SetTag::<Identity>,
+            GetViewportRect: GetViewportRect::<Identity>,
+            SetViewportRect: SetViewportRect::<Identity>,
+            ZoomToRect: ZoomToRect::<Identity>,
+            SetViewportTransform: SetViewportTransform::<Identity>,
+            SyncDisplayTransform: SyncDisplayTransform::<Identity>,
+            GetPrimaryContent: GetPrimaryContent::<Identity>,
+            AddContent: AddContent::<Identity>,
+            RemoveContent: RemoveContent::<Identity>,
+            SetViewportOptions: SetViewportOptions::<Identity>,
+            AddConfiguration: AddConfiguration::<Identity>,
+            RemoveConfiguration: RemoveConfiguration::<Identity>,
+            ActivateConfiguration: ActivateConfiguration::<Identity>,
+            SetManualGesture: SetManualGesture::<Identity>,
+            SetChaining: SetChaining::<Identity>,
+            AddEventHandler: AddEventHandler::<Identity>,
+            RemoveEventHandler: RemoveEventHandler::<Identity>,
+            SetInputMode: SetInputMode::<Identity>,
+            SetUpdateMode: SetUpdateMode::<Identity>,
+            Stop: Stop::<Identity>,
+            Abandon: Abandon::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectManipulationViewport {}
 windows_core::imp::define_interface!(IDirectManipulationViewport2, IDirectManipulationViewport2_Vtbl, 0x923ccaac_61e1_4385_b726_017af189882a);
 impl core::ops::Deref for IDirectManipulationViewport2 {
@@ -1876,6 +2567,43 @@ impl IDirectManipulationViewport2_Vtbl {
         iid == &<IDirectManipulationViewport2 as windows_core::Interface>::IID || iid == &<IDirectManipulationViewport as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport2_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectManipulationViewport2_Vtbl
+where
+    IDirectManipulationViewport_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddBehavior<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport2_Impl>(this: *mut core::ffi::c_void, behavior: *mut core::ffi::c_void, cookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectManipulationViewport2_Impl::AddBehavior(this, core::mem::transmute_copy(&behavior)) {
+                    Ok(ok__) => {
+                        cookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveBehavior<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport2_Impl>(this: *mut core::ffi::c_void, cookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport2_Impl::RemoveBehavior(this, core::mem::transmute_copy(&cookie)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveAllBehaviors<Identity: windows_core::IUnknownImpl + IDirectManipulationViewport2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewport2_Impl::RemoveAllBehaviors(this).into()
+            }
+        }
+        Self {
+            base__: <IDirectManipulationViewport_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddBehavior: AddBehavior::<Identity>,
+            RemoveBehavior: RemoveBehavior::<Identity>,
+            RemoveAllBehaviors: RemoveAllBehaviors::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectManipulationViewport2 {}
 windows_core::imp::define_interface!(IDirectManipulationViewportEventHandler, IDirectManipulationViewportEventHandler_Vtbl, 0x952121da_d69f_45f9_b0f9_f23944321a6d);
 windows_core::imp::interface_hierarchy!(IDirectManipulationViewportEventHandler, windows_core::IUnknown);
@@ -1944,4 +2672,35 @@ impl IDirectManipulationViewportEventHandler_Vtbl {
         iid == &<IDirectManipulationViewportEventHandler as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectManipulationViewportEventHandler_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectManipulationViewportEventHandler_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnViewportStatusChanged<Identity: windows_core::IUnknownImpl + IDirectManipulationViewportEventHandler_Impl>(this: *mut core::ffi::c_void, viewport: *mut core::ffi::c_void, current: DIRECTMANIPULATION_STATUS, previous: DIRECTMANIPULATION_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewportEventHandler_Impl::OnViewportStatusChanged(this, core::mem::transmute_copy(&viewport), core::mem::transmute_copy(&current), core::mem::transmute_copy(&previous)).into()
+            }
+        }
+        unsafe extern "system" fn OnViewportUpdated<Identity: windows_core::IUnknownImpl + IDirectManipulationViewportEventHandler_Impl>(this: *mut core::ffi::c_void, viewport: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewportEventHandler_Impl::OnViewportUpdated(this, core::mem::transmute_copy(&viewport)).into()
+            }
+        }
+        unsafe extern "system" fn OnContentUpdated<Identity: windows_core::IUnknownImpl + IDirectManipulationViewportEventHandler_Impl>(this: *mut core::ffi::c_void, viewport: *mut core::ffi::c_void, content: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectManipulationViewportEventHandler_Impl::OnContentUpdated(this, core::mem::transmute_copy(&viewport), core::mem::transmute_copy(&content)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnViewportStatusChanged: OnViewportStatusChanged::<Identity>,
+            OnViewportUpdated: OnViewportUpdated::<Identity>,
+            OnContentUpdated: OnContentUpdated::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectManipulationViewportEventHandler {}
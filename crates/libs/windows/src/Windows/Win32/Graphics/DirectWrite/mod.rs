@@ -1649,6 +1649,30 @@ impl IDWriteAsyncResult_Vtbl {
         iid == &<IDWriteAsyncResult as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteAsyncResult_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteAsyncResult_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetWaitHandle<Identity: windows_core::IUnknownImpl + IDWriteAsyncResult_Impl>(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteAsyncResult_Impl::GetWaitHandle(this)
+            }
+        }
+        unsafe extern "system" fn GetResult<Identity: windows_core::IUnknownImpl + IDWriteAsyncResult_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteAsyncResult_Impl::GetResult(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetWaitHandle: GetWaitHandle::<Identity>,
+            GetResult: GetResult::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteAsyncResult {}
 windows_core::imp::define_interface!(IDWriteBitmapRenderTarget, IDWriteBitmapRenderTarget_Vtbl, 0x5e5a32a3_8dff_4773_9ff6_0696eab77267);
 windows_core::imp::interface_hierarchy!(IDWriteBitmapRenderTarget, windows_core::IUnknown);
@@ -1788,6 +1812,79 @@ impl IDWriteBitmapRenderTarget_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IDWriteBitmapRenderTarget_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteBitmapRenderTarget_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DrawGlyphRun<Identity: windows_core::IUnknownImpl + IDWriteBitmapRenderTarget_Impl>(this: *mut core::ffi::c_void, baselineoriginx: f32, baselineoriginy: f32, measuringmode: DWRITE_MEASURING_MODE, glyphrun: *const DWRITE_GLYPH_RUN, renderingparams: *mut core::ffi::c_void, textcolor: super::super::Foundation::COLORREF, blackboxrect: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteBitmapRenderTarget_Impl::DrawGlyphRun(this, core::mem::transmute_copy(&baselineoriginx), core::mem::transmute_copy(&baselineoriginy), core::mem::transmute_copy(&measuringmode), core::mem::transmute_copy(&glyphrun), core::mem::transmute_copy(&renderingparams), core::mem::transmute_copy(&textcolor), core::mem::transmute_copy(&blackboxrect)).into()
+            }
+        }
+        unsafe extern "system" fn GetMemoryDC<Identity: windows_core::IUnknownImpl + IDWriteBitmapRenderTarget_Impl>(this: *mut core::ffi::c_void) -> super::Gdi::HDC {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteBitmapRenderTarget_Impl::GetMemoryDC(this)
+            }
+        }
+        unsafe extern "system" fn GetPixelsPerDip<Identity: windows_core::IUnknownImpl + IDWriteBitmapRenderTarget_Impl>(this: *mut core::ffi::c_void) -> f32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteBitmapRenderTarget_Impl::GetPixelsPerDip(this)
+            }
+        }
+        unsafe extern "system" fn SetPixelsPerDip<Identity: windows_core::IUnknownImpl + IDWriteBitmapRenderTarget_Impl>(this: *mut core::ffi::c_void, pixelsperdip: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteBitmapRenderTarget_Impl::SetPixelsPerDip(this, core::mem::transmute_copy(&pixelsperdip)).into()
+            }
+        }
+        unsafe extern "system" fn GetCurrentTransform<Identity: windows_core::IUnknownImpl + IDWriteBitmapRenderTarget_Impl>(this: *mut core::ffi::c_void, transform: *mut DWRITE_MATRIX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteBitmapRenderTarget_Impl::GetCurrentTransform(this, core::mem::transmute_copy(&transform)).into()
+            }
+        }
+        unsafe extern "system" fn SetCurrentTransform<Identity: windows_core::IUnknownImpl + IDWriteBitmapRenderTarget_Impl>(this: *mut core::ffi::c_void, transform: *const DWRITE_MATRIX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteBitmapRenderTarget_Impl::SetCurrentTransform(this, core::mem::transmute_copy(&transform)).into()
+            }
+        }
+        unsafe extern "system" fn GetSize<Identity: windows_core::IUnknownImpl + IDWriteBitmapRenderTarget_Impl>(this: *mut core::ffi::c_void, size: *mut super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteBitmapRenderTarget_Impl::GetSize(this) {
+                    Ok(ok__) => {
+                        size.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Resize<Identity: windows_core::IUnknownImpl + IDWriteBitmapRenderTarget_Impl>(this: *mut core::ffi::c_void, width: u32, height: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteBitmapRenderTarget_Impl::Resize(this, core::mem::transmute_copy(&width), core::mem::transmute_copy(&height)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DrawGlyphRun: DrawGlyphRun::<Identity>,
+            GetMemoryDC: GetMemoryDC::<Identity>,
+            GetPixelsPerDip: GetPixelsPerDip::<Identity>,
+            SetPixelsPerDip: SetPixelsPerDip::<Identity>,
+            GetCurrentTransform: GetCurrentTransform::<Identity>,
+            SetCurrentTransform: SetCurrentTransform::<Identity>,
+            GetSize: GetSize::<Identity>,
+            Resize: Resize::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IDWriteBitmapRenderTarget {}
 windows_core::imp::define_interface!(IDWriteBitmapRenderTarget1, IDWriteBitmapRenderTarget1_Vtbl, 0x791e8298_3ef3_4230_9880_c9bdecc42064);
 impl core::ops::Deref for IDWriteBitmapRenderTarget1 {
@@ -1845,6 +1942,31 @@ impl IDWriteBitmapRenderTarget1_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IDWriteBitmapRenderTarget1_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteBitmapRenderTarget1_Vtbl
+where
+    IDWriteBitmapRenderTarget_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetTextAntialiasMode<Identity: windows_core::IUnknownImpl + IDWriteBitmapRenderTarget1_Impl>(this: *mut core::ffi::c_void) -> DWRITE_TEXT_ANTIALIAS_MODE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteBitmapRenderTarget1_Impl::GetTextAntialiasMode(this)
+            }
+        }
+        unsafe extern "system" fn SetTextAntialiasMode<Identity: windows_core::IUnknownImpl + IDWriteBitmapRenderTarget1_Impl>(this: *mut core::ffi::c_void, antialiasmode: DWRITE_TEXT_ANTIALIAS_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteBitmapRenderTarget1_Impl::SetTextAntialiasMode(this, core::mem::transmute_copy(&antialiasmode)).into()
+            }
+        }
+        Self {
+            base__: <IDWriteBitmapRenderTarget_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetTextAntialiasMode: GetTextAntialiasMode::<Identity>,
+            SetTextAntialiasMode: SetTextAntialiasMode::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IDWriteBitmapRenderTarget1 {}
 windows_core::imp::define_interface!(IDWriteBitmapRenderTarget2, IDWriteBitmapRenderTarget2_Vtbl, 0xc553a742_fc01_44da_a66e_b8b9ed6c3995);
 impl core::ops::Deref for IDWriteBitmapRenderTarget2 {
@@ -1896,6 +2018,27 @@ impl IDWriteBitmapRenderTarget2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IDWriteBitmapRenderTarget2_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteBitmapRenderTarget2_Vtbl
+where
+    IDWriteBitmapRenderTarget1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetBitmapData<Identity: windows_core::IUnknownImpl + IDWriteBitmapRenderTarget2_Impl>(this: *mut core::ffi::c_void, bitmapdata: *mut DWRITE_BITMAP_DATA_BGRA32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteBitmapRenderTarget2_Impl::GetBitmapData(this) {
+                    Ok(ok__) => {
+                        bitmapdata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IDWriteBitmapRenderTarget1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetBitmapData: GetBitmapData::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IDWriteBitmapRenderTarget2 {}
 windows_core::imp::define_interface!(IDWriteBitmapRenderTarget3, IDWriteBitmapRenderTarget3_Vtbl, 0xaeec37db_c337_40f1_8e2a_9a41b167b238);
 impl core::ops::Deref for IDWriteBitmapRenderTarget3 {
@@ -1968,6 +2111,38 @@ impl IDWriteBitmapRenderTarget3_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IDWriteBitmapRenderTarget3_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteBitmapRenderTarget3_Vtbl
+where
+    IDWriteBitmapRenderTarget2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPaintFeatureLevel<Identity: windows_core::IUnknownImpl + IDWriteBitmapRenderTarget3_Impl>(this: *mut core::ffi::c_void) -> DWRITE_PAINT_FEATURE_LEVEL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteBitmapRenderTarget3_Impl::GetPaintFeatureLevel(this)
+            }
+        }
+        unsafe extern "system" fn DrawPaintGlyphRun<Identity: windows_core::IUnknownImpl + IDWriteBitmapRenderTarget3_Impl>(this: *mut core::ffi::c_void, baselineoriginx: f32, baselineoriginy: f32, measuringmode: DWRITE_MEASURING_MODE, glyphrun: *const DWRITE_GLYPH_RUN, glyphimageformat: DWRITE_GLYPH_IMAGE_FORMATS, textcolor: super::super::Foundation::COLORREF, colorpaletteindex: u32, blackboxrect: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteBitmapRenderTarget3_Impl::DrawPaintGlyphRun(this, core::mem::transmute_copy(&baselineoriginx), core::mem::transmute_copy(&baselineoriginy), core::mem::transmute_copy(&measuringmode), core::mem::transmute_copy(&glyphrun), core::mem::transmute_copy(&glyphimageformat), core::mem::transmute_copy(&textcolor), core::mem::transmute_copy(&colorpaletteindex), core::mem::transmute_copy(&blackboxrect)).into()
+            }
+        }
+        unsafe extern "system" fn DrawGlyphRunWithColorSupport<Identity: windows_core::IUnknownImpl + IDWriteBitmapRenderTarget3_Impl>(this: *mut core::ffi::c_void, baselineoriginx: f32, baselineoriginy: f32, measuringmode: DWRITE_MEASURING_MODE, glyphrun: *const DWRITE_GLYPH_RUN, renderingparams: *mut core::ffi::c_void, textcolor: super::super::Foundation::COLORREF, colorpaletteindex: u32, blackboxrect: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteBitmapRenderTarget3_Impl::DrawGlyphRunWithColorSupport(this, core::mem::transmute_copy(&baselineoriginx), core::mem::transmute_copy(&baselineoriginy), core::mem::transmute_copy(&measuringmode), core::mem::transmute_copy(&glyphrun), core::mem::transmute_copy(&renderingparams), core::mem::transmute_copy(&textcolor), core::mem::transmute_copy(&colorpaletteindex), core::mem::transmute_copy(&blackboxrect)).into()
+            }
+        }
+        Self {
+            base__: <IDWriteBitmapRenderTarget2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPaintFeatureLevel: GetPaintFeatureLevel::<Identity>,
+            DrawPaintGlyphRun: DrawPaintGlyphRun::<Identity>,
+            DrawGlyphRunWithColorSupport: DrawGlyphRunWithColorSupport::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IDWriteBitmapRenderTarget3 {}
 windows_core::imp::define_interface!(IDWriteColorGlyphRunEnumerator, IDWriteColorGlyphRunEnumerator_Vtbl, 0xd31fbe17_f157_41a2_8d24_cb779e0560e8);
 windows_core::imp::interface_hierarchy!(IDWriteColorGlyphRunEnumerator, windows_core::IUnknown);
@@ -2034,6 +2209,42 @@ impl IDWriteColorGlyphRunEnumerator_Vtbl {
         iid == &<IDWriteColorGlyphRunEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteColorGlyphRunEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteColorGlyphRunEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IDWriteColorGlyphRunEnumerator_Impl>(this: *mut core::ffi::c_void, hasrun: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteColorGlyphRunEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasrun.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCurrentRun<Identity: windows_core::IUnknownImpl + IDWriteColorGlyphRunEnumerator_Impl>(this: *mut core::ffi::c_void, colorglyphrun: *mut *mut DWRITE_COLOR_GLYPH_RUN) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteColorGlyphRunEnumerator_Impl::GetCurrentRun(this) {
+                    Ok(ok__) => {
+                        colorglyphrun.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MoveNext: MoveNext::<Identity>,
+            GetCurrentRun: GetCurrentRun::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteColorGlyphRunEnumerator {}
 windows_core::imp::define_interface!(IDWriteColorGlyphRunEnumerator1, IDWriteColorGlyphRunEnumerator1_Vtbl, 0x7c5f86da_c7a1_4f05_b8e1_55a179fe5a35);
 impl core::ops::Deref for IDWriteColorGlyphRunEnumerator1 {
@@ -2082,6 +2293,26 @@ impl IDWriteColorGlyphRunEnumerator1_Vtbl {
         iid == &<IDWriteColorGlyphRunEnumerator1 as windows_core::Interface>::IID || iid == &<IDWriteColorGlyphRunEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteColorGlyphRunEnumerator1_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteColorGlyphRunEnumerator1_Vtbl
+where
+    IDWriteColorGlyphRunEnumerator_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrentRun<Identity: windows_core::IUnknownImpl + IDWriteColorGlyphRunEnumerator1_Impl>(this: *mut core::ffi::c_void, colorglyphrun: *mut *mut DWRITE_COLOR_GLYPH_RUN1) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteColorGlyphRunEnumerator1_Impl::GetCurrentRun(this) {
+                    Ok(ok__) => {
+                        colorglyphrun.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IDWriteColorGlyphRunEnumerator_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetCurrentRun: GetCurrentRun::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDWriteColorGlyphRunEnumerator1 {}
 windows_core::imp::define_interface!(IDWriteFactory, IDWriteFactory_Vtbl, 0xb859ee5a_d838_4b5b_a2e8_1adc7d93db48);
 windows_core::imp::interface_hierarchy!(IDWriteFactory, windows_core::IUnknown);
@@ -2547,108 +2778,393 @@ impl IDWriteFactory_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
-impl windows_core::RuntimeName for IDWriteFactory {}
-windows_core::imp::define_interface!(IDWriteFactory1, IDWriteFactory1_Vtbl, 0x30572f99_dac6_41db_a16e_0486307e606a);
-impl core::ops::Deref for IDWriteFactory1 {
-    type Target = IDWriteFactory;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(IDWriteFactory1, windows_core::IUnknown, IDWriteFactory);
-impl IDWriteFactory1 {
-    pub unsafe fn GetEudcFontCollection(&self, fontcollection: *mut Option<IDWriteFontCollection>, checkforupdates: bool) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetEudcFontCollection)(windows_core::Interface::as_raw(self), core::mem::transmute(fontcollection), checkforupdates.into()).ok() }
-    }
-    pub unsafe fn CreateCustomRenderingParams(&self, gamma: f32, enhancedcontrast: f32, enhancedcontrastgrayscale: f32, cleartypelevel: f32, pixelgeometry: DWRITE_PIXEL_GEOMETRY, renderingmode: DWRITE_RENDERING_MODE) -> windows_core::Result<IDWriteRenderingParams1> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateCustomRenderingParams)(windows_core::Interface::as_raw(self), gamma, enhancedcontrast, enhancedcontrastgrayscale, cleartypelevel, pixelgeometry, renderingmode, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IDWriteFactory1_Vtbl {
-    pub base__: IDWriteFactory_Vtbl,
-    pub GetEudcFontCollection: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void, windows_core::BOOL) -> windows_core::HRESULT,
-    pub CreateCustomRenderingParams: unsafe extern "system" fn(*mut core::ffi::c_void, f32, f32, f32, f32, DWRITE_PIXEL_GEOMETRY, DWRITE_RENDERING_MODE, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-unsafe impl Send for IDWriteFactory1 {}
-unsafe impl Sync for IDWriteFactory1 {}
-#[cfg(feature = "Win32_Graphics_Gdi")]
-pub trait IDWriteFactory1_Impl: IDWriteFactory_Impl {
-    fn GetEudcFontCollection(&self, fontcollection: windows_core::OutRef<IDWriteFontCollection>, checkforupdates: windows_core::BOOL) -> windows_core::Result<()>;
-    fn CreateCustomRenderingParams(&self, gamma: f32, enhancedcontrast: f32, enhancedcontrastgrayscale: f32, cleartypelevel: f32, pixelgeometry: DWRITE_PIXEL_GEOMETRY, renderingmode: DWRITE_RENDERING_MODE) -> windows_core::Result<IDWriteRenderingParams1>;
-}
-#[cfg(feature = "Win32_Graphics_Gdi")]
-impl IDWriteFactory1_Vtbl {
-    pub const fn new<Identity: IDWriteFactory1_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn GetEudcFontCollection<Identity: IDWriteFactory1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fontcollection: *mut *mut core::ffi::c_void, checkforupdates: windows_core::BOOL) -> windows_core::HRESULT {
+impl<Identity: windows_core::IUnknownImpl + IDWriteFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSystemFontCollection<Identity: windows_core::IUnknownImpl + IDWriteFactory_Impl>(this: *mut core::ffi::c_void, fontcollection: *mut *mut core::ffi::c_void, checkforupdates: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IDWriteFactory1_Impl::GetEudcFontCollection(this, core::mem::transmute_copy(&fontcollection), core::mem::transmute_copy(&checkforupdates)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFactory_Impl::GetSystemFontCollection(this, core::mem::transmute_copy(&fontcollection), core::mem::transmute_copy(&checkforupdates)).into()
             }
         }
-        unsafe extern "system" fn CreateCustomRenderingParams<Identity: IDWriteFactory1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, gamma: f32, enhancedcontrast: f32, enhancedcontrastgrayscale: f32, cleartypelevel: f32, pixelgeometry: DWRITE_PIXEL_GEOMETRY, renderingmode: DWRITE_RENDERING_MODE, renderingparams: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateCustomFontCollection<Identity: windows_core::IUnknownImpl + IDWriteFactory_Impl>(this: *mut core::ffi::c_void, collectionloader: *mut core::ffi::c_void, collectionkey: *const core::ffi::c_void, collectionkeysize: u32, fontcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IDWriteFactory1_Impl::CreateCustomRenderingParams(this, core::mem::transmute_copy(&gamma), core::mem::transmute_copy(&enhancedcontrast), core::mem::transmute_copy(&enhancedcontrastgrayscale), core::mem::transmute_copy(&cleartypelevel), core::mem::transmute_copy(&pixelgeometry), core::mem::transmute_copy(&renderingmode)) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory_Impl::CreateCustomFontCollection(this, core::mem::transmute_copy(&collectionloader), core::mem::transmute_copy(&collectionkey), core::mem::transmute_copy(&collectionkeysize)) {
                     Ok(ok__) => {
-                        renderingparams.write(core::mem::transmute(ok__));
+                        fontcollection.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        Self {
-            base__: IDWriteFactory_Vtbl::new::<Identity, OFFSET>(),
-            GetEudcFontCollection: GetEudcFontCollection::<Identity, OFFSET>,
-            CreateCustomRenderingParams: CreateCustomRenderingParams::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDWriteFactory1 as windows_core::Interface>::IID || iid == &<IDWriteFactory as windows_core::Interface>::IID
-    }
-}
-#[cfg(feature = "Win32_Graphics_Gdi")]
-impl windows_core::RuntimeName for IDWriteFactory1 {}
-windows_core::imp::define_interface!(IDWriteFactory2, IDWriteFactory2_Vtbl, 0x0439fc60_ca44_4994_8dee_3a9af7b732ec);
-impl core::ops::Deref for IDWriteFactory2 {
-    type Target = IDWriteFactory1;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(IDWriteFactory2, windows_core::IUnknown, IDWriteFactory, IDWriteFactory1);
-impl IDWriteFactory2 {
-    pub unsafe fn GetSystemFontFallback(&self) -> windows_core::Result<IDWriteFontFallback> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetSystemFontFallback)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn RegisterFontCollectionLoader<Identity: windows_core::IUnknownImpl + IDWriteFactory_Impl>(this: *mut core::ffi::c_void, fontcollectionloader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFactory_Impl::RegisterFontCollectionLoader(this, core::mem::transmute_copy(&fontcollectionloader)).into()
+            }
         }
-    }
-    pub unsafe fn CreateFontFallbackBuilder(&self) -> windows_core::Result<IDWriteFontFallbackBuilder> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateFontFallbackBuilder)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn UnregisterFontCollectionLoader<Identity: windows_core::IUnknownImpl + IDWriteFactory_Impl>(this: *mut core::ffi::c_void, fontcollectionloader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFactory_Impl::UnregisterFontCollectionLoader(this, core::mem::transmute_copy(&fontcollectionloader)).into()
+            }
         }
-    }
-    pub unsafe fn TranslateColorGlyphRun(&self, baselineoriginx: f32, baselineoriginy: f32, glyphrun: *const DWRITE_GLYPH_RUN, glyphrundescription: Option<*const DWRITE_GLYPH_RUN_DESCRIPTION>, measuringmode: DWRITE_MEASURING_MODE, worldtodevicetransform: Option<*const DWRITE_MATRIX>, colorpaletteindex: u32) -> windows_core::Result<IDWriteColorGlyphRunEnumerator> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).TranslateColorGlyphRun)(windows_core::Interface::as_raw(self), baselineoriginx, baselineoriginy, core::mem::transmute(glyphrun), glyphrundescription.unwrap_or(core::mem::zeroed()) as _, measuringmode, worldtodevicetransform.unwrap_or(core::mem::zeroed()) as _, colorpaletteindex, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn CreateFontFileReference<Identity: windows_core::IUnknownImpl + IDWriteFactory_Impl>(this: *mut core::ffi::c_void, filepath: windows_core::PCWSTR, lastwritetime: *const super::super::Foundation::FILETIME, fontfile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory_Impl::CreateFontFileReference(this, core::mem::transmute(&filepath), core::mem::transmute_copy(&lastwritetime)) {
+                    Ok(ok__) => {
+                        fontfile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn CreateCustomRenderingParams(&self, gamma: f32, enhancedcontrast: f32, grayscaleenhancedcontrast: f32, cleartypelevel: f32, pixelgeometry: DWRITE_PIXEL_GEOMETRY, renderingmode: DWRITE_RENDERING_MODE, gridfitmode: DWRITE_GRID_FIT_MODE) -> windows_core::Result<IDWriteRenderingParams2> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateCustomRenderingParams)(windows_core::Interface::as_raw(self), gamma, enhancedcontrast, grayscaleenhancedcontrast, cleartypelevel, pixelgeometry, renderingmode, gridfitmode, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn CreateCustomFontFileReference<Identity: windows_core::IUnknownImpl + IDWriteFactory_Impl>(this: *mut core::ffi::c_void, fontfilereferencekey: *const core::ffi::c_void, fontfilereferencekeysize: u32, fontfileloader: *mut core::ffi::c_void, fontfile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory_Impl::CreateCustomFontFileReference(this, core::mem::transmute_copy(&fontfilereferencekey), core::mem::transmute_copy(&fontfilereferencekeysize), core::mem::transmute_copy(&fontfileloader)) {
+                    Ok(ok__) => {
+                        fontfile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn CreateGlyphRunAnalysis(&self, glyphrun: *const DWRITE_GLYPH_RUN, transform: Option<*const DWRITE_MATRIX>, renderingmode: DWRITE_RENDERING_MODE, measuringmode: DWRITE_MEASURING_MODE, gridfitmode: DWRITE_GRID_FIT_MODE, antialiasmode: DWRITE_TEXT_ANTIALIAS_MODE, baselineoriginx: f32, baselineoriginy: f32) -> windows_core::Result<IDWriteGlyphRunAnalysis> {
-        unsafe {
+        unsafe extern "system" fn CreateFontFace<Identity: windows_core::IUnknownImpl + IDWriteFactory_Impl>(this: *mut core::ffi::c_void, fontfacetype: DWRITE_FONT_FACE_TYPE, numberoffiles: u32, fontfiles: *const *mut core::ffi::c_void, faceindex: u32, fontfacesimulationflags: DWRITE_FONT_SIMULATIONS, fontface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory_Impl::CreateFontFace(this, core::mem::transmute_copy(&fontfacetype), core::mem::transmute_copy(&numberoffiles), core::mem::transmute_copy(&fontfiles), core::mem::transmute_copy(&faceindex), core::mem::transmute_copy(&fontfacesimulationflags)) {
+                    Ok(ok__) => {
+                        fontface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateRenderingParams<Identity: windows_core::IUnknownImpl + IDWriteFactory_Impl>(this: *mut core::ffi::c_void, renderingparams: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory_Impl::CreateRenderingParams(this) {
+                    Ok(ok__) => {
+                        renderingparams.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateMonitorRenderingParams<Identity: windows_core::IUnknownImpl + IDWriteFactory_Impl>(this: *mut core::ffi::c_void, monitor: super::Gdi::HMONITOR, renderingparams: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory_Impl::CreateMonitorRenderingParams(this, core::mem::transmute_copy(&monitor)) {
+                    Ok(ok__) => {
+                        renderingparams.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateCustomRenderingParams<Identity: windows_core::IUnknownImpl + IDWriteFactory_Impl>(this: *mut core::ffi::c_void, gamma: f32, enhancedcontrast: f32, cleartypelevel: f32, pixelgeometry: DWRITE_PIXEL_GEOMETRY, renderingmode: DWRITE_RENDERING_MODE, renderingparams: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory_Impl::CreateCustomRenderingParams(this, core::mem::transmute_copy(&gamma), core::mem::transmute_copy(&enhancedcontrast), core::mem::transmute_copy(&cleartypelevel), core::mem::transmute_copy(&pixelgeometry), core::mem::transmute_copy(&renderingmode)) {
+                    Ok(ok__) => {
+                        renderingparams.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RegisterFontFileLoader<Identity: windows_core::IUnknownImpl + IDWriteFactory_Impl>(this: *mut core::ffi::c_void, fontfileloader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFactory_Impl::RegisterFontFileLoader(this, core::mem::transmute_copy(&fontfileloader)).into()
+            }
+        }
+        unsafe extern "system" fn UnregisterFontFileLoader<Identity: windows_core::IUnknownImpl + IDWriteFactory_Impl>(this: *mut core::ffi::c_void, fontfileloader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFactory_Impl::UnregisterFontFileLoader(this, core::mem::transmute_copy(&fontfileloader)).into()
+            }
+        }
+        unsafe extern "system" fn CreateTextFormat<Identity: windows_core::IUnknownImpl + IDWriteFactory_Impl>(this: *mut core::ffi::c_void, fontfamilyname: windows_core::PCWSTR, fontcollection: *mut core::ffi::c_void, fontweight: DWRITE_FONT_WEIGHT, fontstyle: DWRITE_FONT_STYLE, fontstretch: DWRITE_FONT_STRETCH, fontsize: f32, localename: windows_core::PCWSTR, textformat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory_Impl::CreateTextFormat(this, core::mem::transmute(&fontfamilyname), core::mem::transmute_copy(&fontcollection), core::mem::transmute_copy(&fontweight), core::mem::transmute_copy(&fontstyle), core::mem::transmute_copy(&fontstretch), core::mem::transmute_copy(&fontsize), core::mem::transmute(&localename)) {
+                    Ok(ok__) => {
+                        textformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateTypography<Identity: windows_core::IUnknownImpl + IDWriteFactory_Impl>(this: *mut core::ffi::c_void, typography: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory_Impl::CreateTypography(this) {
+                    Ok(ok__) => {
+                        typography.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetGdiInterop<Identity: windows_core::IUnknownImpl + IDWriteFactory_Impl>(this: *mut core::ffi::c_void, gdiinterop: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory_Impl::GetGdiInterop(this) {
+                    Ok(ok__) => {
+                        gdiinterop.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateTextLayout<Identity: windows_core::IUnknownImpl + IDWriteFactory_Impl>(this: *mut core::ffi::c_void, string: windows_core::PCWSTR, stringlength: u32, textformat: *mut core::ffi::c_void, maxwidth: f32, maxheight: f32, textlayout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory_Impl::CreateTextLayout(this, core::mem::transmute(&string), core::mem::transmute_copy(&stringlength), core::mem::transmute_copy(&textformat), core::mem::transmute_copy(&maxwidth), core::mem::transmute_copy(&maxheight)) {
+                    Ok(ok__) => {
+                        textlayout.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateGdiCompatibleTextLayout<Identity: windows_core::IUnknownImpl + IDWriteFactory_Impl>(this: *mut core::ffi::c_void, string: windows_core::PCWSTR, stringlength: u32, textformat: *mut core::ffi::c_void, layoutwidth: f32, layoutheight: f32, pixelsperdip: f32, transform: *const DWRITE_MATRIX, usegdinatural: windows_core::BOOL, textlayout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory_Impl::CreateGdiCompatibleTextLayout(this, core::mem::transmute(&string), core::mem::transmute_copy(&stringlength), core::mem::transmute_copy(&textformat), core::mem::transmute_copy(&layoutwidth), core::mem::transmute_copy(&layoutheight), core::mem::transmute_copy(&pixelsperdip), core::mem::transmute_copy(&transform), core::mem::transmute_copy(&usegdinatural)) {
+                    Ok(ok__) => {
+                        textlayout.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateEllipsisTrimmingSign<Identity: windows_core::IUnknownImpl + IDWriteFactory_Impl>(this: *mut core::ffi::c_void, textformat: *mut core::ffi::c_void, trimmingsign: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory_Impl::CreateEllipsisTrimmingSign(this, core::mem::transmute_copy(&textformat)) {
+                    Ok(ok__) => {
+                        trimmingsign.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateTextAnalyzer<Identity: windows_core::IUnknownImpl + IDWriteFactory_Impl>(this: *mut core::ffi::c_void, textanalyzer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory_Impl::CreateTextAnalyzer(this) {
+                    Ok(ok__) => {
+                        textanalyzer.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateNumberSubstitution<Identity: windows_core::IUnknownImpl + IDWriteFactory_Impl>(this: *mut core::ffi::c_void, substitutionmethod: DWRITE_NUMBER_SUBSTITUTION_METHOD, localename: windows_core::PCWSTR, ignoreuseroverride: windows_core::BOOL, numbersubstitution: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory_Impl::CreateNumberSubstitution(this, core::mem::transmute_copy(&substitutionmethod), core::mem::transmute(&localename), core::mem::transmute_copy(&ignoreuseroverride)) {
+                    Ok(ok__) => {
+                        numbersubstitution.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateGlyphRunAnalysis<Identity: windows_core::IUnknownImpl + IDWriteFactory_Impl>(this: *mut core::ffi::c_void, glyphrun: *const DWRITE_GLYPH_RUN, pixelsperdip: f32, transform: *const DWRITE_MATRIX, renderingmode: DWRITE_RENDERING_MODE, measuringmode: DWRITE_MEASURING_MODE, baselineoriginx: f32, baselineoriginy: f32, glyphrunanalysis: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory_Impl::CreateGlyphRunAnalysis(this, core::mem::transmute_copy(&glyphrun), core::mem::transmute_copy(&pixelsperdip), core::mem::transmute_copy(&transform), core::mem::transmute_copy(&renderingmode), core::mem::transmute_copy(&measuringmode), core::mem::transmute_copy(&baselineoriginx), core::mem::transmute_copy(&baselineoriginy)) {
+                    Ok(ok__) => {
+                        glyphrunanalysis.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSystemFontCollection: GetSystemFontCollection::<Identity>,
+            CreateCustomFontCollection: CreateCustomFontCollection::<Identity>,
+            RegisterFontCollectionLoader: RegisterFontCollectionLoader::<Identity>,
+            UnregisterFontCollectionLoader: UnregisterFontCollectionLoader::<Identity>,
+            CreateFontFileReference: CreateFontFileReference::<Identity>,
+            CreateCustomFontFileReference: CreateCustomFontFileReference::<Identity>,
+            CreateFontFace: CreateFontFace::<Identity>,
+            CreateRenderingParams: CreateRenderingParams::<Identity>,
+            CreateMonitorRenderingParams: CreateMonitorRenderingParams::<Identity>,
+            CreateCustomRenderingParams: CreateCustomRenderingParams::<Identity>,
+            RegisterFontFileLoader: RegisterFontFileLoader::<Identity>,
+            UnregisterFontFileLoader: UnregisterFontFileLoader::<Identity>,
+            CreateTextFormat: CreateTextFormat::<Identity>,
+            CreateTypography: CreateTypography::<Identity>,
+            GetGdiInterop: GetGdiInterop::<Identity>,
+            CreateTextLayout: CreateTextLayout::<Identity>,
+            CreateGdiCompatibleTextLayout: CreateGdiCompatibleTextLayout::<Identity>,
+            CreateEllipsisTrimmingSign: CreateEllipsisTrimmingSign::<Identity>,
+            CreateTextAnalyzer: CreateTextAnalyzer::<Identity>,
+            CreateNumberSubstitution: CreateNumberSubstitution::<Identity>,
+            CreateGlyphRunAnalysis: CreateGlyphRunAnalysis::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::RuntimeName for IDWriteFactory {}
+windows_core::imp::define_interface!(IDWriteFactory1, IDWriteFactory1_Vtbl, 0x30572f99_dac6_41db_a16e_0486307e606a);
+impl core::ops::Deref for IDWriteFactory1 {
+    type Target = IDWriteFactory;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(IDWriteFactory1, windows_core::IUnknown, IDWriteFactory);
+impl IDWriteFactory1 {
+    pub unsafe fn GetEudcFontCollection(&self, fontcollection: *mut Option<IDWriteFontCollection>, checkforupdates: bool) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetEudcFontCollection)(windows_core::Interface::as_raw(self), core::mem::transmute(fontcollection), checkforupdates.into()).ok() }
+    }
+    pub unsafe fn CreateCustomRenderingParams(&self, gamma: f32, enhancedcontrast: f32, enhancedcontrastgrayscale: f32, cleartypelevel: f32, pixelgeometry: DWRITE_PIXEL_GEOMETRY, renderingmode: DWRITE_RENDERING_MODE) -> windows_core::Result<IDWriteRenderingParams1> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateCustomRenderingParams)(windows_core::Interface::as_raw(self), gamma, enhancedcontrast, enhancedcontrastgrayscale, cleartypelevel, pixelgeometry, renderingmode, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IDWriteFactory1_Vtbl {
+    pub base__: IDWriteFactory_Vtbl,
+    pub GetEudcFontCollection: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void, windows_core::BOOL) -> windows_core::HRESULT,
+    pub CreateCustomRenderingParams: unsafe extern "system" fn(*mut core::ffi::c_void, f32, f32, f32, f32, DWRITE_PIXEL_GEOMETRY, DWRITE_RENDERING_MODE, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+unsafe impl Send for IDWriteFactory1 {}
+unsafe impl Sync for IDWriteFactory1 {}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+pub trait IDWriteFactory1_Impl: IDWriteFactory_Impl {
+    fn GetEudcFontCollection(&self, fontcollection: windows_core::OutRef<IDWriteFontCollection>, checkforupdates: windows_core::BOOL) -> windows_core::Result<()>;
+    fn CreateCustomRenderingParams(&self, gamma: f32, enhancedcontrast: f32, enhancedcontrastgrayscale: f32, cleartypelevel: f32, pixelgeometry: DWRITE_PIXEL_GEOMETRY, renderingmode: DWRITE_RENDERING_MODE) -> windows_core::Result<IDWriteRenderingParams1>;
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl IDWriteFactory1_Vtbl {
+    pub const fn new<Identity: IDWriteFactory1_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn GetEudcFontCollection<Identity: IDWriteFactory1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fontcollection: *mut *mut core::ffi::c_void, checkforupdates: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteFactory1_Impl::GetEudcFontCollection(this, core::mem::transmute_copy(&fontcollection), core::mem::transmute_copy(&checkforupdates)).into()
+            }
+        }
+        unsafe extern "system" fn CreateCustomRenderingParams<Identity: IDWriteFactory1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, gamma: f32, enhancedcontrast: f32, enhancedcontrastgrayscale: f32, cleartypelevel: f32, pixelgeometry: DWRITE_PIXEL_GEOMETRY, renderingmode: DWRITE_RENDERING_MODE, renderingparams: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDWriteFactory1_Impl::CreateCustomRenderingParams(this, core::mem::transmute_copy(&gamma), core::mem::transmute_copy(&enhancedcontrast), core::mem::transmute_copy(&enhancedcontrastgrayscale), core::mem::transmute_copy(&cleartypelevel), core::mem::transmute_copy(&pixelgeometry), core::mem::transmute_copy(&renderingmode)) {
+                    Ok(ok__) => {
+                        renderingparams.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: IDWriteFactory_Vtbl::new::<Identity, OFFSET>(),
+            GetEudcFontCollection: GetEudcFontCollection::<Identity, OFFSET>,
+            CreateCustomRenderingParams: CreateCustomRenderingParams::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFactory1 as windows_core::Interface>::IID || iid == &<IDWriteFactory as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IDWriteFactory1_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFactory1_Vtbl
+where
+    IDWriteFactory_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetEudcFontCollection<Identity: windows_core::IUnknownImpl + IDWriteFactory1_Impl>(this: *mut core::ffi::c_void, fontcollection: *mut *mut core::ffi::c_void, checkforupdates: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFactory1_Impl::GetEudcFontCollection(this, core::mem::transmute_copy(&fontcollection), core::mem::transmute_copy(&checkforupdates)).into()
+            }
+        }
+        unsafe extern "system" fn CreateCustomRenderingParams<Identity: windows_core::IUnknownImpl + IDWriteFactory1_Impl>(this: *mut core::ffi::c_void, gamma: f32, enhancedcontrast: f32, enhancedcontrastgrayscale: f32, cleartypelevel: f32, pixelgeometry: DWRITE_PIXEL_GEOMETRY, renderingmode: DWRITE_RENDERING_MODE, renderingparams: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory1_Impl::CreateCustomRenderingParams(this, core::mem::transmute_copy(&gamma), core::mem::transmute_copy(&enhancedcontrast), core::mem::transmute_copy(&enhancedcontrastgrayscale), core::mem::transmute_copy(&cleartypelevel), core::mem::transmute_copy(&pixelgeometry), core::mem::transmute_copy(&renderingmode)) {
+                    Ok(ok__) => {
+                        renderingparams.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDWriteFactory_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetEudcFontCollection: GetEudcFontCollection::<Identity>,
+            CreateCustomRenderingParams: CreateCustomRenderingParams::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::RuntimeName for IDWriteFactory1 {}
+windows_core::imp::define_interface!(IDWriteFactory2, IDWriteFactory2_Vtbl, 0x0439fc60_ca44_4994_8dee_3a9af7b732ec);
+impl core::ops::Deref for IDWriteFactory2 {
+    type Target = IDWriteFactory1;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(IDWriteFactory2, windows_core::IUnknown, IDWriteFactory, IDWriteFactory1);
+impl IDWriteFactory2 {
+    pub unsafe fn GetSystemFontFallback(&self) -> windows_core::Result<IDWriteFontFallback> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetSystemFontFallback)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn CreateFontFallbackBuilder(&self) -> windows_core::Result<IDWriteFontFallbackBuilder> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateFontFallbackBuilder)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn TranslateColorGlyphRun(&self, baselineoriginx: f32, baselineoriginy: f32, glyphrun: *const DWRITE_GLYPH_RUN, glyphrundescription: Option<*const DWRITE_GLYPH_RUN_DESCRIPTION>, measuringmode: DWRITE_MEASURING_MODE, worldtodevicetransform: Option<*const DWRITE_MATRIX>, colorpaletteindex: u32) -> windows_core::Result<IDWriteColorGlyphRunEnumerator> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).TranslateColorGlyphRun)(windows_core::Interface::as_raw(self), baselineoriginx, baselineoriginy, core::mem::transmute(glyphrun), glyphrundescription.unwrap_or(core::mem::zeroed()) as _, measuringmode, worldtodevicetransform.unwrap_or(core::mem::zeroed()) as _, colorpaletteindex, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn CreateCustomRenderingParams(&self, gamma: f32, enhancedcontrast: f32, grayscaleenhancedcontrast: f32, cleartypelevel: f32, pixelgeometry: DWRITE_PIXEL_GEOMETRY, renderingmode: DWRITE_RENDERING_MODE, gridfitmode: DWRITE_GRID_FIT_MODE) -> windows_core::Result<IDWriteRenderingParams2> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateCustomRenderingParams)(windows_core::Interface::as_raw(self), gamma, enhancedcontrast, grayscaleenhancedcontrast, cleartypelevel, pixelgeometry, renderingmode, gridfitmode, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn CreateGlyphRunAnalysis(&self, glyphrun: *const DWRITE_GLYPH_RUN, transform: Option<*const DWRITE_MATRIX>, renderingmode: DWRITE_RENDERING_MODE, measuringmode: DWRITE_MEASURING_MODE, gridfitmode: DWRITE_GRID_FIT_MODE, antialiasmode: DWRITE_TEXT_ANTIALIAS_MODE, baselineoriginx: f32, baselineoriginy: f32) -> windows_core::Result<IDWriteGlyphRunAnalysis> {
+        unsafe {
             let mut result__ = core::mem::zeroed();
             (windows_core::Interface::vtable(self).CreateGlyphRunAnalysis)(windows_core::Interface::as_raw(self), core::mem::transmute(glyphrun), transform.unwrap_or(core::mem::zeroed()) as _, renderingmode, measuringmode, gridfitmode, antialiasmode, baselineoriginx, baselineoriginy, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
@@ -2751,6 +3267,82 @@ impl IDWriteFactory2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IDWriteFactory2_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFactory2_Vtbl
+where
+    IDWriteFactory1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSystemFontFallback<Identity: windows_core::IUnknownImpl + IDWriteFactory2_Impl>(this: *mut core::ffi::c_void, fontfallback: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory2_Impl::GetSystemFontFallback(this) {
+                    Ok(ok__) => {
+                        fontfallback.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateFontFallbackBuilder<Identity: windows_core::IUnknownImpl + IDWriteFactory2_Impl>(this: *mut core::ffi::c_void, fontfallbackbuilder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory2_Impl::CreateFontFallbackBuilder(this) {
+                    Ok(ok__) => {
+                        fontfallbackbuilder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn TranslateColorGlyphRun<Identity: windows_core::IUnknownImpl + IDWriteFactory2_Impl>(this: *mut core::ffi::c_void, baselineoriginx: f32, baselineoriginy: f32, glyphrun: *const DWRITE_GLYPH_RUN, glyphrundescription: *const DWRITE_GLYPH_RUN_DESCRIPTION, measuringmode: DWRITE_MEASURING_MODE, worldtodevicetransform: *const DWRITE_MATRIX, colorpaletteindex: u32, colorlayers: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory2_Impl::TranslateColorGlyphRun(this, core::mem::transmute_copy(&baselineoriginx), core::mem::transmute_copy(&baselineoriginy), core::mem::transmute_copy(&glyphrun), core::mem::transmute_copy(&glyphrundescription), core::mem::transmute_copy(&measuringmode), core::mem::transmute_copy(&worldtodevicetransform), core::mem::transmute_copy(&colorpaletteindex)) {
+                    Ok(ok__) => {
+                        colorlayers.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateCustomRenderingParams<Identity: windows_core::IUnknownImpl + IDWriteFactory2_Impl>(this: *mut core::ffi::c_void, gamma: f32, enhancedcontrast: f32, grayscaleenhancedcontrast: f32, cleartypelevel: f32, pixelgeometry: DWRITE_PIXEL_GEOMETRY, renderingmode: DWRITE_RENDERING_MODE, gridfitmode: DWRITE_GRID_FIT_MODE, renderingparams: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory2_Impl::CreateCustomRenderingParams(this, core::mem::transmute_copy(&gamma), core::mem::transmute_copy(&enhancedcontrast), core::mem::transmute_copy(&grayscaleenhancedcontrast), core::mem::transmute_copy(&cleartypelevel), core::mem::transmute_copy(&pixelgeometry), core::mem::transmute_copy(&renderingmode), core::mem::transmute_copy(&gridfitmode)) {
+                    Ok(ok__) => {
+                        renderingparams.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateGlyphRunAnalysis<Identity: windows_core::IUnknownImpl + IDWriteFactory2_Impl>(this: *mut core::ffi::c_void, glyphrun: *const DWRITE_GLYPH_RUN, transform: *const DWRITE_MATRIX, renderingmode: DWRITE_RENDERING_MODE, measuringmode: DWRITE_MEASURING_MODE, gridfitmode: DWRITE_GRID_FIT_MODE, antialiasmode: DWRITE_TEXT_ANTIALIAS_MODE, baselineoriginx: f32, baselineoriginy: f32, glyphrunanalysis: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory2_Impl::CreateGlyphRunAnalysis(this, core::mem::transmute_copy(&glyphrun), core::mem::transmute_copy(&transform), core::mem::transmute_copy(&renderingmode), core::mem::transmute_copy(&measuringmode), core::mem::transmute_copy(&gridfitmode), core::mem::transmute_copy(&antialiasmode), core::mem::transmute_copy(&baselineoriginx), core::mem::transmute_copy(&baselineoriginy)) {
+                    Ok(ok__) => {
+                        glyphrunanalysis.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDWriteFactory1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSystemFontFallback: GetSystemFontFallback::<Identity>,
+            CreateFontFallbackBuilder: CreateFontFallbackBuilder::<Identity>,
+            TranslateColorGlyphRun: TranslateColorGlyphRun::<Identity>,
+            CreateCustomRenderingParams: CreateCustomRenderingParams::<Identity>,
+            CreateGlyphRunAnalysis: CreateGlyphRunAnalysis::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IDWriteFactory2 {}
 windows_core::imp::define_interface!(IDWriteFactory3, IDWriteFactory3_Vtbl, 0x9a1b41c3_d3bb_466a_87fc_fe67556a3b65);
 impl core::ops::Deref for IDWriteFactory3 {
@@ -2973,6 +3565,128 @@ impl IDWriteFactory3_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IDWriteFactory3_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFactory3_Vtbl
+where
+    IDWriteFactory2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateGlyphRunAnalysis<Identity: windows_core::IUnknownImpl + IDWriteFactory3_Impl>(this: *mut core::ffi::c_void, glyphrun: *const DWRITE_GLYPH_RUN, transform: *const DWRITE_MATRIX, renderingmode: DWRITE_RENDERING_MODE1, measuringmode: DWRITE_MEASURING_MODE, gridfitmode: DWRITE_GRID_FIT_MODE, antialiasmode: DWRITE_TEXT_ANTIALIAS_MODE, baselineoriginx: f32, baselineoriginy: f32, glyphrunanalysis: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory3_Impl::CreateGlyphRunAnalysis(this, core::mem::transmute_copy(&glyphrun), core::mem::transmute_copy(&transform), core::mem::transmute_copy(&renderingmode), core::mem::transmute_copy(&measuringmode), core::mem::transmute_copy(&gridfitmode), core::mem::transmute_copy(&antialiasmode), core::mem::transmute_copy(&baselineoriginx), core::mem::transmute_copy(&baselineoriginy)) {
+                    Ok(ok__) => {
+                        glyphrunanalysis.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateCustomRenderingParams<Identity: windows_core::IUnknownImpl + IDWriteFactory3_Impl>(this: *mut core::ffi::c_void, gamma: f32, enhancedcontrast: f32, grayscaleenhancedcontrast: f32, cleartypelevel: f32, pixelgeometry: DWRITE_PIXEL_GEOMETRY, renderingmode: DWRITE_RENDERING_MODE1, gridfitmode: DWRITE_GRID_FIT_MODE, renderingparams: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory3_Impl::CreateCustomRenderingParams(this, core::mem::transmute_copy(&gamma), core::mem::transmute_copy(&enhancedcontrast), core::mem::transmute_copy(&grayscaleenhancedcontrast), core::mem::transmute_copy(&cleartypelevel), core::mem::transmute_copy(&pixelgeometry), core::mem::transmute_copy(&renderingmode), core::mem::transmute_copy(&gridfitmode)) {
+                    Ok(ok__) => {
+                        renderingparams.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateFontFaceReference<Identity: windows_core::IUnknownImpl + IDWriteFactory3_Impl>(this: *mut core::ffi::c_void, fontfile: *mut core::ffi::c_void, faceindex: u32, fontsimulations: DWRITE_FONT_SIMULATIONS, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory3_Impl::CreateFontFaceReference(this, core::mem::transmute_copy(&fontfile), core::mem::transmute_copy(&faceindex), core::mem::transmute_copy(&fontsimulations)) {
+                    Ok(ok__) => {
+                        fontfacereference.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateFontFaceReference2<Identity: windows_core::IUnknownImpl + IDWriteFactory3_Impl>(this: *mut core::ffi::c_void, filepath: windows_core::PCWSTR, lastwritetime: *const super::super::Foundation::FILETIME, faceindex: u32, fontsimulations: DWRITE_FONT_SIMULATIONS, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory3_Impl::CreateFontFaceReference2(this, core::mem::transmute(&filepath), core::mem::transmute_copy(&lastwritetime), core::mem::transmute_copy(&faceindex), core::mem::transmute_copy(&fontsimulations)) {
+                    Ok(ok__) => {
+                        fontfacereference.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSystemFontSet<Identity: windows_core::IUnknownImpl + IDWriteFactory3_Impl>(this: *mut core::ffi::c_void, fontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory3_Impl::GetSystemFontSet(this) {
+                    Ok(ok__) => {
+                        fontset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateFontSetBuilder<Identity: windows_core::IUnknownImpl + IDWriteFactory3_Impl>(this: *mut core::ffi::c_void, fontsetbuilder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory3_Impl::CreateFontSetBuilder(this) {
+                    Ok(ok__) => {
+                        fontsetbuilder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateFontCollectionFromFontSet<Identity: windows_core::IUnknownImpl + IDWriteFactory3_Impl>(this: *mut core::ffi::c_void, fontset: *mut core::ffi::c_void, fontcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory3_Impl::CreateFontCollectionFromFontSet(this, core::mem::transmute_copy(&fontset)) {
+                    Ok(ok__) => {
+                        fontcollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSystemFontCollection<Identity: windows_core::IUnknownImpl + IDWriteFactory3_Impl>(this: *mut core::ffi::c_void, includedownloadablefonts: windows_core::BOOL, fontcollection: *mut *mut core::ffi::c_void, checkforupdates: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFactory3_Impl::GetSystemFontCollection(this, core::mem::transmute_copy(&includedownloadablefonts), core::mem::transmute_copy(&fontcollection), core::mem::transmute_copy(&checkforupdates)).into()
+            }
+        }
+        unsafe extern "system" fn GetFontDownloadQueue<Identity: windows_core::IUnknownImpl + IDWriteFactory3_Impl>(this: *mut core::ffi::c_void, fontdownloadqueue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory3_Impl::GetFontDownloadQueue(this) {
+                    Ok(ok__) => {
+                        fontdownloadqueue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDWriteFactory2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateGlyphRunAnalysis: CreateGlyphRunAnalysis::<Identity>,
+            CreateCustomRenderingParams: CreateCustomRenderingParams::<Identity>,
+            CreateFontFaceReference: CreateFontFaceReference::<Identity>,
+            CreateFontFaceReference2: CreateFontFaceReference2::<Identity>,
+            GetSystemFontSet: GetSystemFontSet::<Identity>,
+            CreateFontSetBuilder: CreateFontSetBuilder::<Identity>,
+            CreateFontCollectionFromFontSet: CreateFontCollectionFromFontSet::<Identity>,
+            GetSystemFontCollection: GetSystemFontCollection::<Identity>,
+            GetFontDownloadQueue: GetFontDownloadQueue::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IDWriteFactory3 {}
 windows_core::imp::define_interface!(IDWriteFactory4, IDWriteFactory4_Vtbl, 0x4b0b5bd3_0797_4549_8ac5_fe915cc53856);
 impl core::ops::Deref for IDWriteFactory4 {
@@ -3069,6 +3783,56 @@ impl IDWriteFactory4_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IDWriteFactory4_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFactory4_Vtbl
+where
+    IDWriteFactory3_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn TranslateColorGlyphRun<Identity: windows_core::IUnknownImpl + IDWriteFactory4_Impl>(this: *mut core::ffi::c_void, baselineorigin: windows_numerics::Vector2, glyphrun: *const DWRITE_GLYPH_RUN, glyphrundescription: *const DWRITE_GLYPH_RUN_DESCRIPTION, desiredglyphimageformats: DWRITE_GLYPH_IMAGE_FORMATS, measuringmode: DWRITE_MEASURING_MODE, worldanddpitransform: *const DWRITE_MATRIX, colorpaletteindex: u32, colorlayers: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory4_Impl::TranslateColorGlyphRun(this, core::mem::transmute(&baselineorigin), core::mem::transmute_copy(&glyphrun), core::mem::transmute_copy(&glyphrundescription), core::mem::transmute_copy(&desiredglyphimageformats), core::mem::transmute_copy(&measuringmode), core::mem::transmute_copy(&worldanddpitransform), core::mem::transmute_copy(&colorpaletteindex)) {
+                    Ok(ok__) => {
+                        colorlayers.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ComputeGlyphOrigins<Identity: windows_core::IUnknownImpl + IDWriteFactory4_Impl>(this: *mut core::ffi::c_void, glyphrun: *const DWRITE_GLYPH_RUN, baselineorigin: windows_numerics::Vector2, glyphorigins: *mut windows_numerics::Vector2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory4_Impl::ComputeGlyphOrigins(this, core::mem::transmute_copy(&glyphrun), core::mem::transmute(&baselineorigin)) {
+                    Ok(ok__) => {
+                        glyphorigins.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ComputeGlyphOrigins2<Identity: windows_core::IUnknownImpl + IDWriteFactory4_Impl>(this: *mut core::ffi::c_void, glyphrun: *const DWRITE_GLYPH_RUN, measuringmode: DWRITE_MEASURING_MODE, baselineorigin: windows_numerics::Vector2, worldanddpitransform: *const DWRITE_MATRIX, glyphorigins: *mut windows_numerics::Vector2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory4_Impl::ComputeGlyphOrigins2(this, core::mem::transmute_copy(&glyphrun), core::mem::transmute_copy(&measuringmode), core::mem::transmute(&baselineorigin), core::mem::transmute_copy(&worldanddpitransform)) {
+                    Ok(ok__) => {
+                        glyphorigins.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDWriteFactory3_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            TranslateColorGlyphRun: TranslateColorGlyphRun::<Identity>,
+            ComputeGlyphOrigins: ComputeGlyphOrigins::<Identity>,
+            ComputeGlyphOrigins2: ComputeGlyphOrigins2::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IDWriteFactory4 {}
 windows_core::imp::define_interface!(IDWriteFactory5, IDWriteFactory5_Vtbl, 0x958db99a_be2a_4f09_af7d_65189803d1d3);
 impl core::ops::Deref for IDWriteFactory5 {
@@ -3132,11 +3896,84 @@ pub trait IDWriteFactory5_Impl: IDWriteFactory4_Impl {
     fn UnpackFontFile(&self, containertype: DWRITE_CONTAINER_TYPE, filedata: *const core::ffi::c_void, filedatasize: u32) -> windows_core::Result<IDWriteFontFileStream>;
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
-impl IDWriteFactory5_Vtbl {
-    pub const fn new<Identity: IDWriteFactory5_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn CreateFontSetBuilder<Identity: IDWriteFactory5_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fontsetbuilder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl IDWriteFactory5_Vtbl {
+    pub const fn new<Identity: IDWriteFactory5_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn CreateFontSetBuilder<Identity: IDWriteFactory5_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fontsetbuilder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDWriteFactory5_Impl::CreateFontSetBuilder(this) {
+                    Ok(ok__) => {
+                        fontsetbuilder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateInMemoryFontFileLoader<Identity: IDWriteFactory5_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, newloader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDWriteFactory5_Impl::CreateInMemoryFontFileLoader(this) {
+                    Ok(ok__) => {
+                        newloader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateHttpFontFileLoader<Identity: IDWriteFactory5_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, referrerurl: windows_core::PCWSTR, extraheaders: windows_core::PCWSTR, newloader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDWriteFactory5_Impl::CreateHttpFontFileLoader(this, core::mem::transmute(&referrerurl), core::mem::transmute(&extraheaders)) {
+                    Ok(ok__) => {
+                        newloader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AnalyzeContainerType<Identity: IDWriteFactory5_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, filedata: *const core::ffi::c_void, filedatasize: u32) -> DWRITE_CONTAINER_TYPE {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteFactory5_Impl::AnalyzeContainerType(this, core::mem::transmute_copy(&filedata), core::mem::transmute_copy(&filedatasize))
+            }
+        }
+        unsafe extern "system" fn UnpackFontFile<Identity: IDWriteFactory5_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, containertype: DWRITE_CONTAINER_TYPE, filedata: *const core::ffi::c_void, filedatasize: u32, unpackedfontstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDWriteFactory5_Impl::UnpackFontFile(this, core::mem::transmute_copy(&containertype), core::mem::transmute_copy(&filedata), core::mem::transmute_copy(&filedatasize)) {
+                    Ok(ok__) => {
+                        unpackedfontstream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: IDWriteFactory4_Vtbl::new::<Identity, OFFSET>(),
+            CreateFontSetBuilder: CreateFontSetBuilder::<Identity, OFFSET>,
+            CreateInMemoryFontFileLoader: CreateInMemoryFontFileLoader::<Identity, OFFSET>,
+            CreateHttpFontFileLoader: CreateHttpFontFileLoader::<Identity, OFFSET>,
+            AnalyzeContainerType: AnalyzeContainerType::<Identity, OFFSET>,
+            UnpackFontFile: UnpackFontFile::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFactory5 as windows_core::Interface>::IID || iid == &<IDWriteFactory as windows_core::Interface>::IID || iid == &<IDWriteFactory1 as windows_core::Interface>::IID || iid == &<IDWriteFactory2 as windows_core::Interface>::IID || iid == &<IDWriteFactory3 as windows_core::Interface>::IID || iid == &<IDWriteFactory4 as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IDWriteFactory5_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFactory5_Vtbl
+where
+    IDWriteFactory4_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateFontSetBuilder<Identity: windows_core::IUnknownImpl + IDWriteFactory5_Impl>(this: *mut core::ffi::c_void, fontsetbuilder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDWriteFactory5_Impl::CreateFontSetBuilder(this) {
                     Ok(ok__) => {
                         fontsetbuilder.write(core::mem::transmute(ok__));
@@ -3146,9 +3983,9 @@ impl IDWriteFactory5_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateInMemoryFontFileLoader<Identity: IDWriteFactory5_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, newloader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateInMemoryFontFileLoader<Identity: windows_core::IUnknownImpl + IDWriteFactory5_Impl>(this: *mut core::ffi::c_void, newloader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDWriteFactory5_Impl::CreateInMemoryFontFileLoader(this) {
                     Ok(ok__) => {
                         newloader.write(core::mem::transmute(ok__));
@@ -3158,9 +3995,9 @@ impl IDWriteFactory5_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateHttpFontFileLoader<Identity: IDWriteFactory5_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, referrerurl: windows_core::PCWSTR, extraheaders: windows_core::PCWSTR, newloader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateHttpFontFileLoader<Identity: windows_core::IUnknownImpl + IDWriteFactory5_Impl>(this: *mut core::ffi::c_void, referrerurl: windows_core::PCWSTR, extraheaders: windows_core::PCWSTR, newloader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDWriteFactory5_Impl::CreateHttpFontFileLoader(this, core::mem::transmute(&referrerurl), core::mem::transmute(&extraheaders)) {
                     Ok(ok__) => {
                         newloader.write(core::mem::transmute(ok__));
@@ -3170,15 +4007,15 @@ impl IDWriteFactory5_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn AnalyzeContainerType<Identity: IDWriteFactory5_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, filedata: *const core::ffi::c_void, filedatasize: u32) -> DWRITE_CONTAINER_TYPE {
+        unsafe extern "system" fn AnalyzeContainerType<Identity: windows_core::IUnknownImpl + IDWriteFactory5_Impl>(this: *mut core::ffi::c_void, filedata: *const core::ffi::c_void, filedatasize: u32) -> DWRITE_CONTAINER_TYPE {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteFactory5_Impl::AnalyzeContainerType(this, core::mem::transmute_copy(&filedata), core::mem::transmute_copy(&filedatasize))
             }
         }
-        unsafe extern "system" fn UnpackFontFile<Identity: IDWriteFactory5_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, containertype: DWRITE_CONTAINER_TYPE, filedata: *const core::ffi::c_void, filedatasize: u32, unpackedfontstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn UnpackFontFile<Identity: windows_core::IUnknownImpl + IDWriteFactory5_Impl>(this: *mut core::ffi::c_void, containertype: DWRITE_CONTAINER_TYPE, filedata: *const core::ffi::c_void, filedatasize: u32, unpackedfontstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDWriteFactory5_Impl::UnpackFontFile(this, core::mem::transmute_copy(&containertype), core::mem::transmute_copy(&filedata), core::mem::transmute_copy(&filedatasize)) {
                     Ok(ok__) => {
                         unpackedfontstream.write(core::mem::transmute(ok__));
@@ -3189,17 +4026,14 @@ impl IDWriteFactory5_Vtbl {
             }
         }
         Self {
-            base__: IDWriteFactory4_Vtbl::new::<Identity, OFFSET>(),
-            CreateFontSetBuilder: CreateFontSetBuilder::<Identity, OFFSET>,
-            CreateInMemoryFontFileLoader: CreateInMemoryFontFileLoader::<Identity, OFFSET>,
-            CreateHttpFontFileLoader: CreateHttpFontFileLoader::<Identity, OFFSET>,
-            AnalyzeContainerType: AnalyzeContainerType::<Identity, OFFSET>,
-            UnpackFontFile: UnpackFontFile::<Identity, OFFSET>,
+            base__: <IDWriteFactory4_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateFontSetBuilder: CreateFontSetBuilder::<Identity>,
+            CreateInMemoryFontFileLoader: CreateInMemoryFontFileLoader::<Identity>,
+            CreateHttpFontFileLoader: CreateHttpFontFileLoader::<Identity>,
+            AnalyzeContainerType: AnalyzeContainerType::<Identity>,
+            UnpackFontFile: UnpackFontFile::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDWriteFactory5 as windows_core::Interface>::IID || iid == &<IDWriteFactory as windows_core::Interface>::IID || iid == &<IDWriteFactory1 as windows_core::Interface>::IID || iid == &<IDWriteFactory2 as windows_core::Interface>::IID || iid == &<IDWriteFactory3 as windows_core::Interface>::IID || iid == &<IDWriteFactory4 as windows_core::Interface>::IID
-    }
+    };
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IDWriteFactory5 {}
@@ -3396,6 +4230,108 @@ impl IDWriteFactory6_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IDWriteFactory6_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFactory6_Vtbl
+where
+    IDWriteFactory5_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateFontFaceReference<Identity: windows_core::IUnknownImpl + IDWriteFactory6_Impl>(this: *mut core::ffi::c_void, fontfile: *mut core::ffi::c_void, faceindex: u32, fontsimulations: DWRITE_FONT_SIMULATIONS, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory6_Impl::CreateFontFaceReference(this, core::mem::transmute_copy(&fontfile), core::mem::transmute_copy(&faceindex), core::mem::transmute_copy(&fontsimulations), core::mem::transmute_copy(&fontaxisvalues), core::mem::transmute_copy(&fontaxisvaluecount)) {
+                    Ok(ok__) => {
+                        fontfacereference.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateFontResource<Identity: windows_core::IUnknownImpl + IDWriteFactory6_Impl>(this: *mut core::ffi::c_void, fontfile: *mut core::ffi::c_void, faceindex: u32, fontresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory6_Impl::CreateFontResource(this, core::mem::transmute_copy(&fontfile), core::mem::transmute_copy(&faceindex)) {
+                    Ok(ok__) => {
+                        fontresource.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSystemFontSet<Identity: windows_core::IUnknownImpl + IDWriteFactory6_Impl>(this: *mut core::ffi::c_void, includedownloadablefonts: windows_core::BOOL, fontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory6_Impl::GetSystemFontSet(this, core::mem::transmute_copy(&includedownloadablefonts)) {
+                    Ok(ok__) => {
+                        fontset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSystemFontCollection<Identity: windows_core::IUnknownImpl + IDWriteFactory6_Impl>(this: *mut core::ffi::c_void, includedownloadablefonts: windows_core::BOOL, fontfamilymodel: DWRITE_FONT_FAMILY_MODEL, fontcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory6_Impl::GetSystemFontCollection(this, core::mem::transmute_copy(&includedownloadablefonts), core::mem::transmute_copy(&fontfamilymodel)) {
+                    Ok(ok__) => {
+                        fontcollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateFontCollectionFromFontSet<Identity: windows_core::IUnknownImpl + IDWriteFactory6_Impl>(this: *mut core::ffi::c_void, fontset: *mut core::ffi::c_void, fontfamilymodel: DWRITE_FONT_FAMILY_MODEL, fontcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory6_Impl::CreateFontCollectionFromFontSet(this, core::mem::transmute_copy(&fontset), core::mem::transmute_copy(&fontfamilymodel)) {
+                    Ok(ok__) => {
+                        fontcollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateFontSetBuilder<Identity: windows_core::IUnknownImpl + IDWriteFactory6_Impl>(this: *mut core::ffi::c_void, fontsetbuilder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory6_Impl::CreateFontSetBuilder(this) {
+                    Ok(ok__) => {
+                        fontsetbuilder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateTextFormat<Identity: windows_core::IUnknownImpl + IDWriteFactory6_Impl>(this: *mut core::ffi::c_void, fontfamilyname: windows_core::PCWSTR, fontcollection: *mut core::ffi::c_void, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, fontsize: f32, localename: windows_core::PCWSTR, textformat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory6_Impl::CreateTextFormat(this, core::mem::transmute(&fontfamilyname), core::mem::transmute_copy(&fontcollection), core::mem::transmute_copy(&fontaxisvalues), core::mem::transmute_copy(&fontaxisvaluecount), core::mem::transmute_copy(&fontsize), core::mem::transmute(&localename)) {
+                    Ok(ok__) => {
+                        textformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDWriteFactory5_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateFontFaceReference: CreateFontFaceReference::<Identity>,
+            CreateFontResource: CreateFontResource::<Identity>,
+            GetSystemFontSet: GetSystemFontSet::<Identity>,
+            GetSystemFontCollection: GetSystemFontCollection::<Identity>,
+            CreateFontCollectionFromFontSet: CreateFontCollectionFromFontSet::<Identity>,
+            CreateFontSetBuilder: CreateFontSetBuilder::<Identity>,
+            CreateTextFormat: CreateTextFormat::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IDWriteFactory6 {}
 windows_core::imp::define_interface!(IDWriteFactory7, IDWriteFactory7_Vtbl, 0x35d0e0b3_9076_4d2e_a016_a91b568a06b4);
 impl core::ops::Deref for IDWriteFactory7 {
@@ -3471,6 +4407,43 @@ impl IDWriteFactory7_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IDWriteFactory7_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFactory7_Vtbl
+where
+    IDWriteFactory6_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSystemFontSet<Identity: windows_core::IUnknownImpl + IDWriteFactory7_Impl>(this: *mut core::ffi::c_void, includedownloadablefonts: windows_core::BOOL, fontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory7_Impl::GetSystemFontSet(this, core::mem::transmute_copy(&includedownloadablefonts)) {
+                    Ok(ok__) => {
+                        fontset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSystemFontCollection<Identity: windows_core::IUnknownImpl + IDWriteFactory7_Impl>(this: *mut core::ffi::c_void, includedownloadablefonts: windows_core::BOOL, fontfamilymodel: DWRITE_FONT_FAMILY_MODEL, fontcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory7_Impl::GetSystemFontCollection(this, core::mem::transmute_copy(&includedownloadablefonts), core::mem::transmute_copy(&fontfamilymodel)) {
+                    Ok(ok__) => {
+                        fontcollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDWriteFactory6_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSystemFontSet: GetSystemFontSet::<Identity>,
+            GetSystemFontCollection: GetSystemFontCollection::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IDWriteFactory7 {}
 windows_core::imp::define_interface!(IDWriteFactory8, IDWriteFactory8_Vtbl, 0xee0a7fb5_def4_4c23_a454_c9c7dc878398);
 impl core::ops::Deref for IDWriteFactory8 {
@@ -3522,6 +4495,27 @@ impl IDWriteFactory8_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IDWriteFactory8_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFactory8_Vtbl
+where
+    IDWriteFactory7_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn TranslateColorGlyphRun<Identity: windows_core::IUnknownImpl + IDWriteFactory8_Impl>(this: *mut core::ffi::c_void, baselineorigin: windows_numerics::Vector2, glyphrun: *const DWRITE_GLYPH_RUN, glyphrundescription: *const DWRITE_GLYPH_RUN_DESCRIPTION, desiredglyphimageformats: DWRITE_GLYPH_IMAGE_FORMATS, paintfeaturelevel: DWRITE_PAINT_FEATURE_LEVEL, measuringmode: DWRITE_MEASURING_MODE, worldanddpitransform: *const DWRITE_MATRIX, colorpaletteindex: u32, colorenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFactory8_Impl::TranslateColorGlyphRun(this, core::mem::transmute(&baselineorigin), core::mem::transmute_copy(&glyphrun), core::mem::transmute_copy(&glyphrundescription), core::mem::transmute_copy(&desiredglyphimageformats), core::mem::transmute_copy(&paintfeaturelevel), core::mem::transmute_copy(&measuringmode), core::mem::transmute_copy(&worldanddpitransform), core::mem::transmute_copy(&colorpaletteindex)) {
+                    Ok(ok__) => {
+                        colorenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IDWriteFactory7_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, TranslateColorGlyphRun: TranslateColorGlyphRun::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IDWriteFactory8 {}
 windows_core::imp::define_interface!(IDWriteFont, IDWriteFont_Vtbl, 0xacd16696_8c14_4f5d_877e_fe3fc1d32737);
 windows_core::imp::interface_hierarchy!(IDWriteFont, windows_core::IUnknown);
@@ -3643,7 +4637,121 @@ impl IDWriteFont_Vtbl {
         }
         unsafe extern "system" fn GetFaceNames<Identity: IDWriteFont_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, names: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDWriteFont_Impl::GetFaceNames(this) {
+                    Ok(ok__) => {
+                        names.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetInformationalStrings<Identity: IDWriteFont_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, informationalstringid: DWRITE_INFORMATIONAL_STRING_ID, informationalstrings: *mut *mut core::ffi::c_void, exists: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteFont_Impl::GetInformationalStrings(this, core::mem::transmute_copy(&informationalstringid), core::mem::transmute_copy(&informationalstrings), core::mem::transmute_copy(&exists)).into()
+            }
+        }
+        unsafe extern "system" fn GetSimulations<Identity: IDWriteFont_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> DWRITE_FONT_SIMULATIONS {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteFont_Impl::GetSimulations(this)
+            }
+        }
+        unsafe extern "system" fn GetMetrics<Identity: IDWriteFont_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fontmetrics: *mut DWRITE_FONT_METRICS) {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteFont_Impl::GetMetrics(this, core::mem::transmute_copy(&fontmetrics))
+            }
+        }
+        unsafe extern "system" fn HasCharacter<Identity: IDWriteFont_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, unicodevalue: u32, exists: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDWriteFont_Impl::HasCharacter(this, core::mem::transmute_copy(&unicodevalue)) {
+                    Ok(ok__) => {
+                        exists.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateFontFace<Identity: IDWriteFont_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fontface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDWriteFont_Impl::CreateFontFace(this) {
+                    Ok(ok__) => {
+                        fontface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            GetFontFamily: GetFontFamily::<Identity, OFFSET>,
+            GetWeight: GetWeight::<Identity, OFFSET>,
+            GetStretch: GetStretch::<Identity, OFFSET>,
+            GetStyle: GetStyle::<Identity, OFFSET>,
+            IsSymbolFont: IsSymbolFont::<Identity, OFFSET>,
+            GetFaceNames: GetFaceNames::<Identity, OFFSET>,
+            GetInformationalStrings: GetInformationalStrings::<Identity, OFFSET>,
+            GetSimulations: GetSimulations::<Identity, OFFSET>,
+            GetMetrics: GetMetrics::<Identity, OFFSET>,
+            HasCharacter: HasCharacter::<Identity, OFFSET>,
+            CreateFontFace: CreateFontFace::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFont as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IDWriteFont_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFont_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFontFamily<Identity: windows_core::IUnknownImpl + IDWriteFont_Impl>(this: *mut core::ffi::c_void, fontfamily: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFont_Impl::GetFontFamily(this) {
+                    Ok(ok__) => {
+                        fontfamily.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetWeight<Identity: windows_core::IUnknownImpl + IDWriteFont_Impl>(this: *mut core::ffi::c_void) -> DWRITE_FONT_WEIGHT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFont_Impl::GetWeight(this)
+            }
+        }
+        unsafe extern "system" fn GetStretch<Identity: windows_core::IUnknownImpl + IDWriteFont_Impl>(this: *mut core::ffi::c_void) -> DWRITE_FONT_STRETCH {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFont_Impl::GetStretch(this)
+            }
+        }
+        unsafe extern "system" fn GetStyle<Identity: windows_core::IUnknownImpl + IDWriteFont_Impl>(this: *mut core::ffi::c_void) -> DWRITE_FONT_STYLE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFont_Impl::GetStyle(this)
+            }
+        }
+        unsafe extern "system" fn IsSymbolFont<Identity: windows_core::IUnknownImpl + IDWriteFont_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFont_Impl::IsSymbolFont(this)
+            }
+        }
+        unsafe extern "system" fn GetFaceNames<Identity: windows_core::IUnknownImpl + IDWriteFont_Impl>(this: *mut core::ffi::c_void, names: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDWriteFont_Impl::GetFaceNames(this) {
                     Ok(ok__) => {
                         names.write(core::mem::transmute(ok__));
@@ -3653,27 +4761,27 @@ impl IDWriteFont_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetInformationalStrings<Identity: IDWriteFont_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, informationalstringid: DWRITE_INFORMATIONAL_STRING_ID, informationalstrings: *mut *mut core::ffi::c_void, exists: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetInformationalStrings<Identity: windows_core::IUnknownImpl + IDWriteFont_Impl>(this: *mut core::ffi::c_void, informationalstringid: DWRITE_INFORMATIONAL_STRING_ID, informationalstrings: *mut *mut core::ffi::c_void, exists: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteFont_Impl::GetInformationalStrings(this, core::mem::transmute_copy(&informationalstringid), core::mem::transmute_copy(&informationalstrings), core::mem::transmute_copy(&exists)).into()
             }
         }
-        unsafe extern "system" fn GetSimulations<Identity: IDWriteFont_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> DWRITE_FONT_SIMULATIONS {
+        unsafe extern "system" fn GetSimulations<Identity: windows_core::IUnknownImpl + IDWriteFont_Impl>(this: *mut core::ffi::c_void) -> DWRITE_FONT_SIMULATIONS {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteFont_Impl::GetSimulations(this)
             }
         }
-        unsafe extern "system" fn GetMetrics<Identity: IDWriteFont_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fontmetrics: *mut DWRITE_FONT_METRICS) {
+        unsafe extern "system" fn GetMetrics<Identity: windows_core::IUnknownImpl + IDWriteFont_Impl>(this: *mut core::ffi::c_void, fontmetrics: *mut DWRITE_FONT_METRICS) {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteFont_Impl::GetMetrics(this, core::mem::transmute_copy(&fontmetrics))
             }
         }
-        unsafe extern "system" fn HasCharacter<Identity: IDWriteFont_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, unicodevalue: u32, exists: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn HasCharacter<Identity: windows_core::IUnknownImpl + IDWriteFont_Impl>(this: *mut core::ffi::c_void, unicodevalue: u32, exists: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDWriteFont_Impl::HasCharacter(this, core::mem::transmute_copy(&unicodevalue)) {
                     Ok(ok__) => {
                         exists.write(core::mem::transmute(ok__));
@@ -3683,9 +4791,9 @@ impl IDWriteFont_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateFontFace<Identity: IDWriteFont_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fontface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateFontFace<Identity: windows_core::IUnknownImpl + IDWriteFont_Impl>(this: *mut core::ffi::c_void, fontface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDWriteFont_Impl::CreateFontFace(this) {
                     Ok(ok__) => {
                         fontface.write(core::mem::transmute(ok__));
@@ -3696,23 +4804,20 @@ impl IDWriteFont_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            GetFontFamily: GetFontFamily::<Identity, OFFSET>,
-            GetWeight: GetWeight::<Identity, OFFSET>,
-            GetStretch: GetStretch::<Identity, OFFSET>,
-            GetStyle: GetStyle::<Identity, OFFSET>,
-            IsSymbolFont: IsSymbolFont::<Identity, OFFSET>,
-            GetFaceNames: GetFaceNames::<Identity, OFFSET>,
-            GetInformationalStrings: GetInformationalStrings::<Identity, OFFSET>,
-            GetSimulations: GetSimulations::<Identity, OFFSET>,
-            GetMetrics: GetMetrics::<Identity, OFFSET>,
-            HasCharacter: HasCharacter::<Identity, OFFSET>,
-            CreateFontFace: CreateFontFace::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDWriteFont as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFontFamily: GetFontFamily::<Identity>,
+            GetWeight: GetWeight::<Identity>,
+            GetStretch: GetStretch::<Identity>,
+            GetStyle: GetStyle::<Identity>,
+            IsSymbolFont: IsSymbolFont::<Identity>,
+            GetFaceNames: GetFaceNames::<Identity>,
+            GetInformationalStrings: GetInformationalStrings::<Identity>,
+            GetSimulations: GetSimulations::<Identity>,
+            GetMetrics: GetMetrics::<Identity>,
+            HasCharacter: HasCharacter::<Identity>,
+            CreateFontFace: CreateFontFace::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IDWriteFont {}
 windows_core::imp::define_interface!(IDWriteFont1, IDWriteFont1_Vtbl, 0xacd16696_8c14_4f5d_877e_fe3fc1d32738);
@@ -3796,6 +4901,44 @@ impl IDWriteFont1_Vtbl {
         iid == &<IDWriteFont1 as windows_core::Interface>::IID || iid == &<IDWriteFont as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFont1_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFont1_Vtbl
+where
+    IDWriteFont_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMetrics<Identity: windows_core::IUnknownImpl + IDWriteFont1_Impl>(this: *mut core::ffi::c_void, fontmetrics: *mut DWRITE_FONT_METRICS1) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFont1_Impl::GetMetrics(this, core::mem::transmute_copy(&fontmetrics))
+            }
+        }
+        unsafe extern "system" fn GetPanose<Identity: windows_core::IUnknownImpl + IDWriteFont1_Impl>(this: *mut core::ffi::c_void, panose: *mut DWRITE_PANOSE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFont1_Impl::GetPanose(this, core::mem::transmute_copy(&panose))
+            }
+        }
+        unsafe extern "system" fn GetUnicodeRanges<Identity: windows_core::IUnknownImpl + IDWriteFont1_Impl>(this: *mut core::ffi::c_void, maxrangecount: u32, unicoderanges: *mut DWRITE_UNICODE_RANGE, actualrangecount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFont1_Impl::GetUnicodeRanges(this, core::mem::transmute_copy(&maxrangecount), core::mem::transmute_copy(&unicoderanges), core::mem::transmute_copy(&actualrangecount)).into()
+            }
+        }
+        unsafe extern "system" fn IsMonospacedFont<Identity: windows_core::IUnknownImpl + IDWriteFont1_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFont1_Impl::IsMonospacedFont(this)
+            }
+        }
+        Self {
+            base__: <IDWriteFont_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetMetrics: GetMetrics::<Identity>,
+            GetPanose: GetPanose::<Identity>,
+            GetUnicodeRanges: GetUnicodeRanges::<Identity>,
+            IsMonospacedFont: IsMonospacedFont::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFont1 {}
 windows_core::imp::define_interface!(IDWriteFont2, IDWriteFont2_Vtbl, 0x29748ed6_8c9c_4a6a_be0b_d912e8538944);
 impl core::ops::Deref for IDWriteFont2 {
@@ -3835,6 +4978,20 @@ impl IDWriteFont2_Vtbl {
         iid == &<IDWriteFont2 as windows_core::Interface>::IID || iid == &<IDWriteFont as windows_core::Interface>::IID || iid == &<IDWriteFont1 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFont2_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFont2_Vtbl
+where
+    IDWriteFont1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsColorFont<Identity: windows_core::IUnknownImpl + IDWriteFont2_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFont2_Impl::IsColorFont(this)
+            }
+        }
+        Self { base__: <IDWriteFont1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, IsColorFont: IsColorFont::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFont2 {}
 windows_core::imp::define_interface!(IDWriteFont3, IDWriteFont3_Vtbl, 0x29748ed6_8c9c_4a6a_be0b_d912e8538944);
 impl core::ops::Deref for IDWriteFont3 {
@@ -3946,6 +5103,63 @@ impl IDWriteFont3_Vtbl {
         iid == &<IDWriteFont3 as windows_core::Interface>::IID || iid == &<IDWriteFont as windows_core::Interface>::IID || iid == &<IDWriteFont1 as windows_core::Interface>::IID || iid == &<IDWriteFont2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFont3_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFont3_Vtbl
+where
+    IDWriteFont2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateFontFace<Identity: windows_core::IUnknownImpl + IDWriteFont3_Impl>(this: *mut core::ffi::c_void, fontface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFont3_Impl::CreateFontFace(this) {
+                    Ok(ok__) => {
+                        fontface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Equals<Identity: windows_core::IUnknownImpl + IDWriteFont3_Impl>(this: *mut core::ffi::c_void, font: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFont3_Impl::Equals(this, core::mem::transmute_copy(&font))
+            }
+        }
+        unsafe extern "system" fn GetFontFaceReference<Identity: windows_core::IUnknownImpl + IDWriteFont3_Impl>(this: *mut core::ffi::c_void, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFont3_Impl::GetFontFaceReference(this) {
+                    Ok(ok__) => {
+                        fontfacereference.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn HasCharacter<Identity: windows_core::IUnknownImpl + IDWriteFont3_Impl>(this: *mut core::ffi::c_void, unicodevalue: u32) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFont3_Impl::HasCharacter(this, core::mem::transmute_copy(&unicodevalue))
+            }
+        }
+        unsafe extern "system" fn GetLocality<Identity: windows_core::IUnknownImpl + IDWriteFont3_Impl>(this: *mut core::ffi::c_void) -> DWRITE_LOCALITY {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFont3_Impl::GetLocality(this)
+            }
+        }
+        Self {
+            base__: <IDWriteFont2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateFontFace: CreateFontFace::<Identity>,
+            Equals: Equals::<Identity>,
+            GetFontFaceReference: GetFontFaceReference::<Identity>,
+            HasCharacter: HasCharacter::<Identity>,
+            GetLocality: GetLocality::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFont3 {}
 windows_core::imp::define_interface!(IDWriteFontCollection, IDWriteFontCollection_Vtbl, 0xa84cee02_3eea_4eee_a827_87c1a02a0fcc);
 windows_core::imp::interface_hierarchy!(IDWriteFontCollection, windows_core::IUnknown);
@@ -4042,6 +5256,56 @@ impl IDWriteFontCollection_Vtbl {
         iid == &<IDWriteFontCollection as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontCollection_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontCollection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFontFamilyCount<Identity: windows_core::IUnknownImpl + IDWriteFontCollection_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontCollection_Impl::GetFontFamilyCount(this)
+            }
+        }
+        unsafe extern "system" fn GetFontFamily<Identity: windows_core::IUnknownImpl + IDWriteFontCollection_Impl>(this: *mut core::ffi::c_void, index: u32, fontfamily: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontCollection_Impl::GetFontFamily(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        fontfamily.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindFamilyName<Identity: windows_core::IUnknownImpl + IDWriteFontCollection_Impl>(this: *mut core::ffi::c_void, familyname: windows_core::PCWSTR, index: *mut u32, exists: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontCollection_Impl::FindFamilyName(this, core::mem::transmute(&familyname), core::mem::transmute_copy(&index), core::mem::transmute_copy(&exists)).into()
+            }
+        }
+        unsafe extern "system" fn GetFontFromFontFace<Identity: windows_core::IUnknownImpl + IDWriteFontCollection_Impl>(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, font: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontCollection_Impl::GetFontFromFontFace(this, core::mem::transmute_copy(&fontface)) {
+                    Ok(ok__) => {
+                        font.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFontFamilyCount: GetFontFamilyCount::<Identity>,
+            GetFontFamily: GetFontFamily::<Identity>,
+            FindFamilyName: FindFamilyName::<Identity>,
+            GetFontFromFontFace: GetFontFromFontFace::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontCollection {}
 windows_core::imp::define_interface!(IDWriteFontCollection1, IDWriteFontCollection1_Vtbl, 0x53585141_d9f8_4095_8321_d73cf6bd116c);
 impl core::ops::Deref for IDWriteFontCollection1 {
@@ -4114,6 +5378,42 @@ impl IDWriteFontCollection1_Vtbl {
         iid == &<IDWriteFontCollection1 as windows_core::Interface>::IID || iid == &<IDWriteFontCollection as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontCollection1_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontCollection1_Vtbl
+where
+    IDWriteFontCollection_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFontSet<Identity: windows_core::IUnknownImpl + IDWriteFontCollection1_Impl>(this: *mut core::ffi::c_void, fontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontCollection1_Impl::GetFontSet(this) {
+                    Ok(ok__) => {
+                        fontset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFontFamily<Identity: windows_core::IUnknownImpl + IDWriteFontCollection1_Impl>(this: *mut core::ffi::c_void, index: u32, fontfamily: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontCollection1_Impl::GetFontFamily(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        fontfamily.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDWriteFontCollection_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFontSet: GetFontSet::<Identity>,
+            GetFontFamily: GetFontFamily::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontCollection1 {}
 windows_core::imp::define_interface!(IDWriteFontCollection2, IDWriteFontCollection2_Vtbl, 0x514039c6_4617_4064_bf8b_92ea83e506e0);
 impl core::ops::Deref for IDWriteFontCollection2 {
@@ -4222,6 +5522,62 @@ impl IDWriteFontCollection2_Vtbl {
         iid == &<IDWriteFontCollection2 as windows_core::Interface>::IID || iid == &<IDWriteFontCollection as windows_core::Interface>::IID || iid == &<IDWriteFontCollection1 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontCollection2_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontCollection2_Vtbl
+where
+    IDWriteFontCollection1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFontFamily<Identity: windows_core::IUnknownImpl + IDWriteFontCollection2_Impl>(this: *mut core::ffi::c_void, index: u32, fontfamily: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontCollection2_Impl::GetFontFamily(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        fontfamily.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMatchingFonts<Identity: windows_core::IUnknownImpl + IDWriteFontCollection2_Impl>(this: *mut core::ffi::c_void, familyname: windows_core::PCWSTR, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, fontlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontCollection2_Impl::GetMatchingFonts(this, core::mem::transmute(&familyname), core::mem::transmute_copy(&fontaxisvalues), core::mem::transmute_copy(&fontaxisvaluecount)) {
+                    Ok(ok__) => {
+                        fontlist.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFontFamilyModel<Identity: windows_core::IUnknownImpl + IDWriteFontCollection2_Impl>(this: *mut core::ffi::c_void) -> DWRITE_FONT_FAMILY_MODEL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontCollection2_Impl::GetFontFamilyModel(this)
+            }
+        }
+        unsafe extern "system" fn GetFontSet<Identity: windows_core::IUnknownImpl + IDWriteFontCollection2_Impl>(this: *mut core::ffi::c_void, fontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontCollection2_Impl::GetFontSet(this) {
+                    Ok(ok__) => {
+                        fontset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDWriteFontCollection1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFontFamily: GetFontFamily::<Identity>,
+            GetMatchingFonts: GetMatchingFonts::<Identity>,
+            GetFontFamilyModel: GetFontFamilyModel::<Identity>,
+            GetFontSet: GetFontSet::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontCollection2 {}
 windows_core::imp::define_interface!(IDWriteFontCollection3, IDWriteFontCollection3_Vtbl, 0xa4d055a6_f9e3_4e25_93b7_9e309f3af8e9);
 impl core::ops::Deref for IDWriteFontCollection3 {
@@ -4261,6 +5617,20 @@ impl IDWriteFontCollection3_Vtbl {
         iid == &<IDWriteFontCollection3 as windows_core::Interface>::IID || iid == &<IDWriteFontCollection as windows_core::Interface>::IID || iid == &<IDWriteFontCollection1 as windows_core::Interface>::IID || iid == &<IDWriteFontCollection2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontCollection3_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontCollection3_Vtbl
+where
+    IDWriteFontCollection2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetExpirationEvent<Identity: windows_core::IUnknownImpl + IDWriteFontCollection3_Impl>(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontCollection3_Impl::GetExpirationEvent(this)
+            }
+        }
+        Self { base__: <IDWriteFontCollection2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetExpirationEvent: GetExpirationEvent::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontCollection3 {}
 windows_core::imp::define_interface!(IDWriteFontCollectionLoader, IDWriteFontCollectionLoader_Vtbl, 0xcca920e4_52f0_492b_bfa8_29c72ee0a468);
 windows_core::imp::interface_hierarchy!(IDWriteFontCollectionLoader, windows_core::IUnknown);
@@ -4306,6 +5676,29 @@ impl IDWriteFontCollectionLoader_Vtbl {
         iid == &<IDWriteFontCollectionLoader as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontCollectionLoader_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontCollectionLoader_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateEnumeratorFromKey<Identity: windows_core::IUnknownImpl + IDWriteFontCollectionLoader_Impl>(this: *mut core::ffi::c_void, factory: *mut core::ffi::c_void, collectionkey: *const core::ffi::c_void, collectionkeysize: u32, fontfileenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontCollectionLoader_Impl::CreateEnumeratorFromKey(this, core::mem::transmute_copy(&factory), core::mem::transmute_copy(&collectionkey), core::mem::transmute_copy(&collectionkeysize)) {
+                    Ok(ok__) => {
+                        fontfileenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateEnumeratorFromKey: CreateEnumeratorFromKey::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontCollectionLoader {}
 windows_core::imp::define_interface!(IDWriteFontDownloadListener, IDWriteFontDownloadListener_Vtbl, 0xb06fe5b9_43ec_4393_881b_dbe4dc72fda7);
 windows_core::imp::interface_hierarchy!(IDWriteFontDownloadListener, windows_core::IUnknown);
@@ -4343,6 +5736,20 @@ impl IDWriteFontDownloadListener_Vtbl {
         iid == &<IDWriteFontDownloadListener as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontDownloadListener_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontDownloadListener_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DownloadCompleted<Identity: windows_core::IUnknownImpl + IDWriteFontDownloadListener_Impl>(this: *mut core::ffi::c_void, downloadqueue: *mut core::ffi::c_void, context: *mut core::ffi::c_void, downloadresult: windows_core::HRESULT) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontDownloadListener_Impl::DownloadCompleted(this, core::mem::transmute_copy(&downloadqueue), core::mem::transmute_copy(&context), core::mem::transmute_copy(&downloadresult))
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, DownloadCompleted: DownloadCompleted::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontDownloadListener {}
 windows_core::imp::define_interface!(IDWriteFontDownloadQueue, IDWriteFontDownloadQueue_Vtbl, 0xb71e6052_5aea_4fa3_832e_f60d431f7e91);
 windows_core::imp::interface_hierarchy!(IDWriteFontDownloadQueue, windows_core::IUnknown);
@@ -4449,10 +5856,68 @@ impl IDWriteFontDownloadQueue_Vtbl {
             CancelDownload: CancelDownload::<Identity, OFFSET>,
             GetGenerationCount: GetGenerationCount::<Identity, OFFSET>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDWriteFontDownloadQueue as windows_core::Interface>::IID
-    }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontDownloadQueue as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontDownloadQueue_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontDownloadQueue_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddListener<Identity: windows_core::IUnknownImpl + IDWriteFontDownloadQueue_Impl>(this: *mut core::ffi::c_void, listener: *mut core::ffi::c_void, token: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontDownloadQueue_Impl::AddListener(this, core::mem::transmute_copy(&listener)) {
+                    Ok(ok__) => {
+                        token.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveListener<Identity: windows_core::IUnknownImpl + IDWriteFontDownloadQueue_Impl>(this: *mut core::ffi::c_void, token: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontDownloadQueue_Impl::RemoveListener(this, core::mem::transmute_copy(&token)).into()
+            }
+        }
+        unsafe extern "system" fn IsEmpty<Identity: windows_core::IUnknownImpl + IDWriteFontDownloadQueue_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontDownloadQueue_Impl::IsEmpty(this)
+            }
+        }
+        unsafe extern "system" fn BeginDownload<Identity: windows_core::IUnknownImpl + IDWriteFontDownloadQueue_Impl>(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontDownloadQueue_Impl::BeginDownload(this, core::mem::transmute_copy(&context)).into()
+            }
+        }
+        unsafe extern "system" fn CancelDownload<Identity: windows_core::IUnknownImpl + IDWriteFontDownloadQueue_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontDownloadQueue_Impl::CancelDownload(this).into()
+            }
+        }
+        unsafe extern "system" fn GetGenerationCount<Identity: windows_core::IUnknownImpl + IDWriteFontDownloadQueue_Impl>(this: *mut core::ffi::c_void) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontDownloadQueue_Impl::GetGenerationCount(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddListener: AddListener::<Identity>,
+            RemoveListener: RemoveListener::<Identity>,
+            IsEmpty: IsEmpty::<Identity>,
+            BeginDownload: BeginDownload::<Identity>,
+            CancelDownload: CancelDownload::<Identity>,
+            GetGenerationCount: GetGenerationCount::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IDWriteFontDownloadQueue {}
 windows_core::imp::define_interface!(IDWriteFontFace, IDWriteFontFace_Vtbl, 0x5f49804d_7024_4d43_bfa9_d25984f53849);
@@ -4680,6 +6145,128 @@ impl IDWriteFontFace_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontFace_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontFace_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetType<Identity: windows_core::IUnknownImpl + IDWriteFontFace_Impl>(this: *mut core::ffi::c_void) -> DWRITE_FONT_FACE_TYPE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace_Impl::GetType(this)
+            }
+        }
+        unsafe extern "system" fn GetFiles<Identity: windows_core::IUnknownImpl + IDWriteFontFace_Impl>(this: *mut core::ffi::c_void, numberoffiles: *mut u32, fontfiles: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace_Impl::GetFiles(this, core::mem::transmute_copy(&numberoffiles), core::mem::transmute_copy(&fontfiles)).into()
+            }
+        }
+        unsafe extern "system" fn GetIndex<Identity: windows_core::IUnknownImpl + IDWriteFontFace_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace_Impl::GetIndex(this)
+            }
+        }
+        unsafe extern "system" fn GetSimulations<Identity: windows_core::IUnknownImpl + IDWriteFontFace_Impl>(this: *mut core::ffi::c_void) -> DWRITE_FONT_SIMULATIONS {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace_Impl::GetSimulations(this)
+            }
+        }
+        unsafe extern "system" fn IsSymbolFont<Identity: windows_core::IUnknownImpl + IDWriteFontFace_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace_Impl::IsSymbolFont(this)
+            }
+        }
+        unsafe extern "system" fn GetMetrics<Identity: windows_core::IUnknownImpl + IDWriteFontFace_Impl>(this: *mut core::ffi::c_void, fontfacemetrics: *mut DWRITE_FONT_METRICS) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace_Impl::GetMetrics(this, core::mem::transmute_copy(&fontfacemetrics))
+            }
+        }
+        unsafe extern "system" fn GetGlyphCount<Identity: windows_core::IUnknownImpl + IDWriteFontFace_Impl>(this: *mut core::ffi::c_void) -> u16 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace_Impl::GetGlyphCount(this)
+            }
+        }
+        unsafe extern "system" fn GetDesignGlyphMetrics<Identity: windows_core::IUnknownImpl + IDWriteFontFace_Impl>(this: *mut core::ffi::c_void, glyphindices: *const u16, glyphcount: u32, glyphmetrics: *mut DWRITE_GLYPH_METRICS, issideways: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace_Impl::GetDesignGlyphMetrics(this, core::mem::transmute_copy(&glyphindices), core::mem::transmute_copy(&glyphcount), core::mem::transmute_copy(&glyphmetrics), core::mem::transmute_copy(&issideways)).into()
+            }
+        }
+        unsafe extern "system" fn GetGlyphIndices<Identity: windows_core::IUnknownImpl + IDWriteFontFace_Impl>(this: *mut core::ffi::c_void, codepoints: *const u32, codepointcount: u32, glyphindices: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace_Impl::GetGlyphIndices(this, core::mem::transmute_copy(&codepoints), core::mem::transmute_copy(&codepointcount), core::mem::transmute_copy(&glyphindices)).into()
+            }
+        }
+        unsafe extern "system" fn TryGetFontTable<Identity: windows_core::IUnknownImpl + IDWriteFontFace_Impl>(this: *mut core::ffi::c_void, opentypetabletag: u32, tabledata: *mut *mut core::ffi::c_void, tablesize: *mut u32, tablecontext: *mut *mut core::ffi::c_void, exists: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace_Impl::TryGetFontTable(this, core::mem::transmute_copy(&opentypetabletag), core::mem::transmute_copy(&tabledata), core::mem::transmute_copy(&tablesize), core::mem::transmute_copy(&tablecontext), core::mem::transmute_copy(&exists)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseFontTable<Identity: windows_core::IUnknownImpl + IDWriteFontFace_Impl>(this: *mut core::ffi::c_void, tablecontext: *const core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace_Impl::ReleaseFontTable(this, core::mem::transmute_copy(&tablecontext))
+            }
+        }
+        unsafe extern "system" fn GetGlyphRunOutline<Identity: windows_core::IUnknownImpl + IDWriteFontFace_Impl>(this: *mut core::ffi::c_void, emsize: f32, glyphindices: *const u16, glyphadvances: *const f32, glyphoffsets: *const DWRITE_GLYPH_OFFSET, glyphcount: u32, issideways: windows_core::BOOL, isrighttoleft: windows_core::BOOL, geometrysink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace_Impl::GetGlyphRunOutline(this, core::mem::transmute_copy(&emsize), core::mem::transmute_copy(&glyphindices), core::mem::transmute_copy(&glyphadvances), core::mem::transmute_copy(&glyphoffsets), core::mem::transmute_copy(&glyphcount), core::mem::transmute_copy(&issideways), core::mem::transmute_copy(&isrighttoleft), core::mem::transmute_copy(&geometrysink)).into()
+            }
+        }
+        unsafe extern "system" fn GetRecommendedRenderingMode<Identity: windows_core::IUnknownImpl + IDWriteFontFace_Impl>(this: *mut core::ffi::c_void, emsize: f32, pixelsperdip: f32, measuringmode: DWRITE_MEASURING_MODE, renderingparams: *mut core::ffi::c_void, renderingmode: *mut DWRITE_RENDERING_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFace_Impl::GetRecommendedRenderingMode(this, core::mem::transmute_copy(&emsize), core::mem::transmute_copy(&pixelsperdip), core::mem::transmute_copy(&measuringmode), core::mem::transmute_copy(&renderingparams)) {
+                    Ok(ok__) => {
+                        renderingmode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetGdiCompatibleMetrics<Identity: windows_core::IUnknownImpl + IDWriteFontFace_Impl>(this: *mut core::ffi::c_void, emsize: f32, pixelsperdip: f32, transform: *const DWRITE_MATRIX, fontfacemetrics: *mut DWRITE_FONT_METRICS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace_Impl::GetGdiCompatibleMetrics(this, core::mem::transmute_copy(&emsize), core::mem::transmute_copy(&pixelsperdip), core::mem::transmute_copy(&transform), core::mem::transmute_copy(&fontfacemetrics)).into()
+            }
+        }
+        unsafe extern "system" fn GetGdiCompatibleGlyphMetrics<Identity: windows_core::IUnknownImpl + IDWriteFontFace_Impl>(this: *mut core::ffi::c_void, emsize: f32, pixelsperdip: f32, transform: *const DWRITE_MATRIX, usegdinatural: windows_core::BOOL, glyphindices: *const u16, glyphcount: u32, glyphmetrics: *mut DWRITE_GLYPH_METRICS, issideways: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace_Impl::GetGdiCompatibleGlyphMetrics(this, core::mem::transmute_copy(&emsize), core::mem::transmute_copy(&pixelsperdip), core::mem::transmute_copy(&transform), core::mem::transmute_copy(&usegdinatural), core::mem::transmute_copy(&glyphindices), core::mem::transmute_copy(&glyphcount), core::mem::transmute_copy(&glyphmetrics), core::mem::transmute_copy(&issideways)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetType: GetType::<Identity>,
+            GetFiles: GetFiles::<Identity>,
+            GetIndex: GetIndex::<Identity>,
+            GetSimulations: GetSimulations::<Identity>,
+            IsSymbolFont: IsSymbolFont::<Identity>,
+            GetMetrics: GetMetrics::<Identity>,
+            GetGlyphCount: GetGlyphCount::<Identity>,
+            GetDesignGlyphMetrics: GetDesignGlyphMetrics::<Identity>,
+            GetGlyphIndices: GetGlyphIndices::<Identity>,
+            TryGetFontTable: TryGetFontTable::<Identity>,
+            ReleaseFontTable: ReleaseFontTable::<Identity>,
+            GetGlyphRunOutline: GetGlyphRunOutline::<Identity>,
+            GetRecommendedRenderingMode: GetRecommendedRenderingMode::<Identity>,
+            GetGdiCompatibleMetrics: GetGdiCompatibleMetrics::<Identity>,
+            GetGdiCompatibleGlyphMetrics: GetGdiCompatibleGlyphMetrics::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
 impl windows_core::RuntimeName for IDWriteFontFace {}
 windows_core::imp::define_interface!(IDWriteFontFace1, IDWriteFontFace1_Vtbl, 0xa71efdb4_9fdb_4838_ad90_cfc3be8c3daf);
 impl core::ops::Deref for IDWriteFontFace1 {
@@ -4870,6 +6457,107 @@ impl IDWriteFontFace1_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontFace1_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontFace1_Vtbl
+where
+    IDWriteFontFace_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMetrics<Identity: windows_core::IUnknownImpl + IDWriteFontFace1_Impl>(this: *mut core::ffi::c_void, fontmetrics: *mut DWRITE_FONT_METRICS1) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace1_Impl::GetMetrics(this, core::mem::transmute_copy(&fontmetrics))
+            }
+        }
+        unsafe extern "system" fn GetGdiCompatibleMetrics<Identity: windows_core::IUnknownImpl + IDWriteFontFace1_Impl>(this: *mut core::ffi::c_void, emsize: f32, pixelsperdip: f32, transform: *const DWRITE_MATRIX, fontmetrics: *mut DWRITE_FONT_METRICS1) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace1_Impl::GetGdiCompatibleMetrics(this, core::mem::transmute_copy(&emsize), core::mem::transmute_copy(&pixelsperdip), core::mem::transmute_copy(&transform), core::mem::transmute_copy(&fontmetrics)).into()
+            }
+        }
+        unsafe extern "system" fn GetCaretMetrics<Identity: windows_core::IUnknownImpl + IDWriteFontFace1_Impl>(this: *mut core::ffi::c_void, caretmetrics: *mut DWRITE_CARET_METRICS) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace1_Impl::GetCaretMetrics(this, core::mem::transmute_copy(&caretmetrics))
+            }
+        }
+        unsafe extern "system" fn GetUnicodeRanges<Identity: windows_core::IUnknownImpl + IDWriteFontFace1_Impl>(this: *mut core::ffi::c_void, maxrangecount: u32, unicoderanges: *mut DWRITE_UNICODE_RANGE, actualrangecount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace1_Impl::GetUnicodeRanges(this, core::mem::transmute_copy(&maxrangecount), core::mem::transmute_copy(&unicoderanges), core::mem::transmute_copy(&actualrangecount)).into()
+            }
+        }
+        unsafe extern "system" fn IsMonospacedFont<Identity: windows_core::IUnknownImpl + IDWriteFontFace1_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace1_Impl::IsMonospacedFont(this)
+            }
+        }
+        unsafe extern "system" fn GetDesignGlyphAdvances<Identity: windows_core::IUnknownImpl + IDWriteFontFace1_Impl>(this: *mut core::ffi::c_void, glyphcount: u32, glyphindices: *const u16, glyphadvances: *mut i32, issideways: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace1_Impl::GetDesignGlyphAdvances(this, core::mem::transmute_copy(&glyphcount), core::mem::transmute_copy(&glyphindices), core::mem::transmute_copy(&glyphadvances), core::mem::transmute_copy(&issideways)).into()
+            }
+        }
+        unsafe extern "system" fn GetGdiCompatibleGlyphAdvances<Identity: windows_core::IUnknownImpl + IDWriteFontFace1_Impl>(this: *mut core::ffi::c_void, emsize: f32, pixelsperdip: f32, transform: *const DWRITE_MATRIX, usegdinatural: windows_core::BOOL, issideways: windows_core::BOOL, glyphcount: u32, glyphindices: *const u16, glyphadvances: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace1_Impl::GetGdiCompatibleGlyphAdvances(this, core::mem::transmute_copy(&emsize), core::mem::transmute_copy(&pixelsperdip), core::mem::transmute_copy(&transform), core::mem::transmute_copy(&usegdinatural), core::mem::transmute_copy(&issideways), core::mem::transmute_copy(&glyphcount), core::mem::transmute_copy(&glyphindices), core::mem::transmute_copy(&glyphadvances)).into()
+            }
+        }
+        unsafe extern "system" fn GetKerningPairAdjustments<Identity: windows_core::IUnknownImpl + IDWriteFontFace1_Impl>(this: *mut core::ffi::c_void, glyphcount: u32, glyphindices: *const u16, glyphadvanceadjustments: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace1_Impl::GetKerningPairAdjustments(this, core::mem::transmute_copy(&glyphcount), core::mem::transmute_copy(&glyphindices), core::mem::transmute_copy(&glyphadvanceadjustments)).into()
+            }
+        }
+        unsafe extern "system" fn HasKerningPairs<Identity: windows_core::IUnknownImpl + IDWriteFontFace1_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace1_Impl::HasKerningPairs(this)
+            }
+        }
+        unsafe extern "system" fn GetRecommendedRenderingMode<Identity: windows_core::IUnknownImpl + IDWriteFontFace1_Impl>(this: *mut core::ffi::c_void, fontemsize: f32, dpix: f32, dpiy: f32, transform: *const DWRITE_MATRIX, issideways: windows_core::BOOL, outlinethreshold: DWRITE_OUTLINE_THRESHOLD, measuringmode: DWRITE_MEASURING_MODE, renderingmode: *mut DWRITE_RENDERING_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFace1_Impl::GetRecommendedRenderingMode(this, core::mem::transmute_copy(&fontemsize), core::mem::transmute_copy(&dpix), core::mem::transmute_copy(&dpiy), core::mem::transmute_copy(&transform), core::mem::transmute_copy(&issideways), core::mem::transmute_copy(&outlinethreshold), core::mem::transmute_copy(&measuringmode)) {
+                    Ok(ok__) => {
+                        renderingmode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetVerticalGlyphVariants<Identity: windows_core::IUnknownImpl + IDWriteFontFace1_Impl>(this: *mut core::ffi::c_void, glyphcount: u32, nominalglyphindices: *const u16, verticalglyphindices: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace1_Impl::GetVerticalGlyphVariants(this, core::mem::transmute_copy(&glyphcount), core::mem::transmute_copy(&nominalglyphindices), core::mem::transmute_copy(&verticalglyphindices)).into()
+            }
+        }
+        unsafe extern "system" fn HasVerticalGlyphVariants<Identity: windows_core::IUnknownImpl + IDWriteFontFace1_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace1_Impl::HasVerticalGlyphVariants(this)
+            }
+        }
+        Self {
+            base__: <IDWriteFontFace_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetMetrics: GetMetrics::<Identity>,
+            GetGdiCompatibleMetrics: GetGdiCompatibleMetrics::<Identity>,
+            GetCaretMetrics: GetCaretMetrics::<Identity>,
+            GetUnicodeRanges: GetUnicodeRanges::<Identity>,
+            IsMonospacedFont: IsMonospacedFont::<Identity>,
+            GetDesignGlyphAdvances: GetDesignGlyphAdvances::<Identity>,
+            GetGdiCompatibleGlyphAdvances: GetGdiCompatibleGlyphAdvances::<Identity>,
+            GetKerningPairAdjustments: GetKerningPairAdjustments::<Identity>,
+            HasKerningPairs: HasKerningPairs::<Identity>,
+            GetRecommendedRenderingMode: GetRecommendedRenderingMode::<Identity>,
+            GetVerticalGlyphVariants: GetVerticalGlyphVariants::<Identity>,
+            HasVerticalGlyphVariants: HasVerticalGlyphVariants::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
 impl windows_core::RuntimeName for IDWriteFontFace1 {}
 windows_core::imp::define_interface!(IDWriteFontFace2, IDWriteFontFace2_Vtbl, 0xd8b768ff_64bc_4e66_982b_ec8e87f693f7);
 impl core::ops::Deref for IDWriteFontFace2 {
@@ -4966,6 +6654,52 @@ impl IDWriteFontFace2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontFace2_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontFace2_Vtbl
+where
+    IDWriteFontFace1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsColorFont<Identity: windows_core::IUnknownImpl + IDWriteFontFace2_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace2_Impl::IsColorFont(this)
+            }
+        }
+        unsafe extern "system" fn GetColorPaletteCount<Identity: windows_core::IUnknownImpl + IDWriteFontFace2_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace2_Impl::GetColorPaletteCount(this)
+            }
+        }
+        unsafe extern "system" fn GetPaletteEntryCount<Identity: windows_core::IUnknownImpl + IDWriteFontFace2_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace2_Impl::GetPaletteEntryCount(this)
+            }
+        }
+        unsafe extern "system" fn GetPaletteEntries<Identity: windows_core::IUnknownImpl + IDWriteFontFace2_Impl>(this: *mut core::ffi::c_void, colorpaletteindex: u32, firstentryindex: u32, entrycount: u32, paletteentries: *mut DWRITE_COLOR_F) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace2_Impl::GetPaletteEntries(this, core::mem::transmute_copy(&colorpaletteindex), core::mem::transmute_copy(&firstentryindex), core::mem::transmute_copy(&entrycount), core::mem::transmute_copy(&paletteentries)).into()
+            }
+        }
+        unsafe extern "system" fn GetRecommendedRenderingMode<Identity: windows_core::IUnknownImpl + IDWriteFontFace2_Impl>(this: *mut core::ffi::c_void, fontemsize: f32, dpix: f32, dpiy: f32, transform: *const DWRITE_MATRIX, issideways: windows_core::BOOL, outlinethreshold: DWRITE_OUTLINE_THRESHOLD, measuringmode: DWRITE_MEASURING_MODE, renderingparams: *mut core::ffi::c_void, renderingmode: *mut DWRITE_RENDERING_MODE, gridfitmode: *mut DWRITE_GRID_FIT_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace2_Impl::GetRecommendedRenderingMode(this, core::mem::transmute_copy(&fontemsize), core::mem::transmute_copy(&dpix), core::mem::transmute_copy(&dpiy), core::mem::transmute_copy(&transform), core::mem::transmute_copy(&issideways), core::mem::transmute_copy(&outlinethreshold), core::mem::transmute_copy(&measuringmode), core::mem::transmute_copy(&renderingparams), core::mem::transmute_copy(&renderingmode), core::mem::transmute_copy(&gridfitmode)).into()
+            }
+        }
+        Self {
+            base__: <IDWriteFontFace1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsColorFont: IsColorFont::<Identity>,
+            GetColorPaletteCount: GetColorPaletteCount::<Identity>,
+            GetPaletteEntryCount: GetPaletteEntryCount::<Identity>,
+            GetPaletteEntries: GetPaletteEntries::<Identity>,
+            GetRecommendedRenderingMode: GetRecommendedRenderingMode::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
 impl windows_core::RuntimeName for IDWriteFontFace2 {}
 windows_core::imp::define_interface!(IDWriteFontFace3, IDWriteFontFace3_Vtbl, 0xd37d7598_09be_4222_a236_2081341cc1f2);
 impl core::ops::Deref for IDWriteFontFace3 {
@@ -5213,10 +6947,149 @@ impl IDWriteFontFace3_Vtbl {
             AreCharactersLocal: AreCharactersLocal::<Identity, OFFSET>,
             AreGlyphsLocal: AreGlyphsLocal::<Identity, OFFSET>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDWriteFontFace3 as windows_core::Interface>::IID || iid == &<IDWriteFontFace as windows_core::Interface>::IID || iid == &<IDWriteFontFace1 as windows_core::Interface>::IID || iid == &<IDWriteFontFace2 as windows_core::Interface>::IID
-    }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontFace3 as windows_core::Interface>::IID || iid == &<IDWriteFontFace as windows_core::Interface>::IID || iid == &<IDWriteFontFace1 as windows_core::Interface>::IID || iid == &<IDWriteFontFace2 as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontFace3_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontFace3_Vtbl
+where
+    IDWriteFontFace2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFontFaceReference<Identity: windows_core::IUnknownImpl + IDWriteFontFace3_Impl>(this: *mut core::ffi::c_void, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFace3_Impl::GetFontFaceReference(this) {
+                    Ok(ok__) => {
+                        fontfacereference.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPanose<Identity: windows_core::IUnknownImpl + IDWriteFontFace3_Impl>(this: *mut core::ffi::c_void, panose: *mut DWRITE_PANOSE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace3_Impl::GetPanose(this, core::mem::transmute_copy(&panose))
+            }
+        }
+        unsafe extern "system" fn GetWeight<Identity: windows_core::IUnknownImpl + IDWriteFontFace3_Impl>(this: *mut core::ffi::c_void) -> DWRITE_FONT_WEIGHT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace3_Impl::GetWeight(this)
+            }
+        }
+        unsafe extern "system" fn GetStretch<Identity: windows_core::IUnknownImpl + IDWriteFontFace3_Impl>(this: *mut core::ffi::c_void) -> DWRITE_FONT_STRETCH {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace3_Impl::GetStretch(this)
+            }
+        }
+        unsafe extern "system" fn GetStyle<Identity: windows_core::IUnknownImpl + IDWriteFontFace3_Impl>(this: *mut core::ffi::c_void) -> DWRITE_FONT_STYLE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace3_Impl::GetStyle(this)
+            }
+        }
+        unsafe extern "system" fn GetFamilyNames<Identity: windows_core::IUnknownImpl + IDWriteFontFace3_Impl>(this: *mut core::ffi::c_void, names: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFace3_Impl::GetFamilyNames(this) {
+                    Ok(ok__) => {
+                        names.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFaceNames<Identity: windows_core::IUnknownImpl + IDWriteFontFace3_Impl>(this: *mut core::ffi::c_void, names: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFace3_Impl::GetFaceNames(this) {
+                    Ok(ok__) => {
+                        names.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetInformationalStrings<Identity: windows_core::IUnknownImpl + IDWriteFontFace3_Impl>(this: *mut core::ffi::c_void, informationalstringid: DWRITE_INFORMATIONAL_STRING_ID, informationalstrings: *mut *mut core::ffi::c_void, exists: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace3_Impl::GetInformationalStrings(this, core::mem::transmute_copy(&informationalstringid), core::mem::transmute_copy(&informationalstrings), core::mem::transmute_copy(&exists)).into()
+            }
+        }
+        unsafe extern "system" fn HasCharacter<Identity: windows_core::IUnknownImpl + IDWriteFontFace3_Impl>(this: *mut core::ffi::c_void, unicodevalue: u32) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace3_Impl::HasCharacter(this, core::mem::transmute_copy(&unicodevalue))
+            }
+        }
+        unsafe extern "system" fn GetRecommendedRenderingMode<Identity: windows_core::IUnknownImpl + IDWriteFontFace3_Impl>(this: *mut core::ffi::c_void, fontemsize: f32, dpix: f32, dpiy: f32, transform: *const DWRITE_MATRIX, issideways: windows_core::BOOL, outlinethreshold: DWRITE_OUTLINE_THRESHOLD, measuringmode: DWRITE_MEASURING_MODE, renderingparams: *mut core::ffi::c_void, renderingmode: *mut DWRITE_RENDERING_MODE1, gridfitmode: *mut DWRITE_GRID_FIT_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace3_Impl::GetRecommendedRenderingMode(this, core::mem::transmute_copy(&fontemsize), core::mem::transmute_copy(&dpix), core::mem::transmute_copy(&dpiy), core::mem::transmute_copy(&transform), core::mem::transmute_copy(&issideways), core::mem::transmute_copy(&outlinethreshold), core::mem::transmute_copy(&measuringmode), core::mem::transmute_copy(&renderingparams), core::mem::transmute_copy(&renderingmode), core::mem::transmute_copy(&gridfitmode)).into()
+            }
+        }
+        unsafe extern "system" fn IsCharacterLocal<Identity: windows_core::IUnknownImpl + IDWriteFontFace3_Impl>(this: *mut core::ffi::c_void, unicodevalue: u32) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace3_Impl::IsCharacterLocal(this, core::mem::transmute_copy(&unicodevalue))
+            }
+        }
+        unsafe extern "system" fn IsGlyphLocal<Identity: windows_core::IUnknownImpl + IDWriteFontFace3_Impl>(this: *mut core::ffi::c_void, glyphid: u16) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace3_Impl::IsGlyphLocal(this, core::mem::transmute_copy(&glyphid))
+            }
+        }
+        unsafe extern "system" fn AreCharactersLocal<Identity: windows_core::IUnknownImpl + IDWriteFontFace3_Impl>(this: *mut core::ffi::c_void, characters: windows_core::PCWSTR, charactercount: u32, enqueueifnotlocal: windows_core::BOOL, islocal: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFace3_Impl::AreCharactersLocal(this, core::mem::transmute(&characters), core::mem::transmute_copy(&charactercount), core::mem::transmute_copy(&enqueueifnotlocal)) {
+                    Ok(ok__) => {
+                        islocal.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AreGlyphsLocal<Identity: windows_core::IUnknownImpl + IDWriteFontFace3_Impl>(this: *mut core::ffi::c_void, glyphindices: *const u16, glyphcount: u32, enqueueifnotlocal: windows_core::BOOL, islocal: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFace3_Impl::AreGlyphsLocal(this, core::mem::transmute_copy(&glyphindices), core::mem::transmute_copy(&glyphcount), core::mem::transmute_copy(&enqueueifnotlocal)) {
+                    Ok(ok__) => {
+                        islocal.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDWriteFontFace2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFontFaceReference: GetFontFaceReference::<Identity>,
+            GetPanose: GetPanose::<Identity>,
+            GetWeight: GetWeight::<Identity>,
+            GetStretch: GetStretch::<Identity>,
+            GetStyle: GetStyle::<Identity>,
+            GetFamilyNames: GetFamilyNames::<Identity>,
+            GetFaceNames: GetFaceNames::<Identity>,
+            GetInformationalStrings: GetInformationalStrings::<Identity>,
+            HasCharacter: HasCharacter::<Identity>,
+            GetRecommendedRenderingMode: GetRecommendedRenderingMode::<Identity>,
+            IsCharacterLocal: IsCharacterLocal::<Identity>,
+            IsGlyphLocal: IsGlyphLocal::<Identity>,
+            AreCharactersLocal: AreCharactersLocal::<Identity>,
+            AreGlyphsLocal: AreGlyphsLocal::<Identity>,
+        }
+    };
 }
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
 impl windows_core::RuntimeName for IDWriteFontFace3 {}
@@ -5313,6 +7186,51 @@ impl IDWriteFontFace4_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontFace4_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontFace4_Vtbl
+where
+    IDWriteFontFace3_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetGlyphImageFormats<Identity: windows_core::IUnknownImpl + IDWriteFontFace4_Impl>(this: *mut core::ffi::c_void, glyphid: u16, pixelsperemfirst: u32, pixelsperemlast: u32, glyphimageformats: *mut DWRITE_GLYPH_IMAGE_FORMATS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFace4_Impl::GetGlyphImageFormats(this, core::mem::transmute_copy(&glyphid), core::mem::transmute_copy(&pixelsperemfirst), core::mem::transmute_copy(&pixelsperemlast)) {
+                    Ok(ok__) => {
+                        glyphimageformats.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetGlyphImageFormats2<Identity: windows_core::IUnknownImpl + IDWriteFontFace4_Impl>(this: *mut core::ffi::c_void) -> DWRITE_GLYPH_IMAGE_FORMATS {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace4_Impl::GetGlyphImageFormats2(this)
+            }
+        }
+        unsafe extern "system" fn GetGlyphImageData<Identity: windows_core::IUnknownImpl + IDWriteFontFace4_Impl>(this: *mut core::ffi::c_void, glyphid: u16, pixelsperem: u32, glyphimageformat: DWRITE_GLYPH_IMAGE_FORMATS, glyphdata: *mut DWRITE_GLYPH_IMAGE_DATA, glyphdatacontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace4_Impl::GetGlyphImageData(this, core::mem::transmute_copy(&glyphid), core::mem::transmute_copy(&pixelsperem), core::mem::transmute_copy(&glyphimageformat), core::mem::transmute_copy(&glyphdata), core::mem::transmute_copy(&glyphdatacontext)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseGlyphImageData<Identity: windows_core::IUnknownImpl + IDWriteFontFace4_Impl>(this: *mut core::ffi::c_void, glyphdatacontext: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace4_Impl::ReleaseGlyphImageData(this, core::mem::transmute_copy(&glyphdatacontext))
+            }
+        }
+        Self {
+            base__: <IDWriteFontFace3_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetGlyphImageFormats: GetGlyphImageFormats::<Identity>,
+            GetGlyphImageFormats2: GetGlyphImageFormats2::<Identity>,
+            GetGlyphImageData: GetGlyphImageData::<Identity>,
+            ReleaseGlyphImageData: ReleaseGlyphImageData::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
 impl windows_core::RuntimeName for IDWriteFontFace4 {}
 windows_core::imp::define_interface!(IDWriteFontFace5, IDWriteFontFace5_Vtbl, 0x98eff3a5_b667_479a_b145_e2fa5b9fdc29);
 impl core::ops::Deref for IDWriteFontFace5 {
@@ -5418,6 +7336,58 @@ impl IDWriteFontFace5_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontFace5_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontFace5_Vtbl
+where
+    IDWriteFontFace4_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFontAxisValueCount<Identity: windows_core::IUnknownImpl + IDWriteFontFace5_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace5_Impl::GetFontAxisValueCount(this)
+            }
+        }
+        unsafe extern "system" fn GetFontAxisValues<Identity: windows_core::IUnknownImpl + IDWriteFontFace5_Impl>(this: *mut core::ffi::c_void, fontaxisvalues: *mut DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace5_Impl::GetFontAxisValues(this, core::mem::transmute_copy(&fontaxisvalues), core::mem::transmute_copy(&fontaxisvaluecount)).into()
+            }
+        }
+        unsafe extern "system" fn HasVariations<Identity: windows_core::IUnknownImpl + IDWriteFontFace5_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace5_Impl::HasVariations(this)
+            }
+        }
+        unsafe extern "system" fn GetFontResource<Identity: windows_core::IUnknownImpl + IDWriteFontFace5_Impl>(this: *mut core::ffi::c_void, fontresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFace5_Impl::GetFontResource(this) {
+                    Ok(ok__) => {
+                        fontresource.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Equals<Identity: windows_core::IUnknownImpl + IDWriteFontFace5_Impl>(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace5_Impl::Equals(this, core::mem::transmute_copy(&fontface))
+            }
+        }
+        Self {
+            base__: <IDWriteFontFace4_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFontAxisValueCount: GetFontAxisValueCount::<Identity>,
+            GetFontAxisValues: GetFontAxisValues::<Identity>,
+            HasVariations: HasVariations::<Identity>,
+            GetFontResource: GetFontResource::<Identity>,
+            Equals: Equals::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
 impl windows_core::RuntimeName for IDWriteFontFace5 {}
 windows_core::imp::define_interface!(IDWriteFontFace6, IDWriteFontFace6_Vtbl, 0xc4b1fe1b_6e84_47d5_b54c_a597981b06ad);
 impl core::ops::Deref for IDWriteFontFace6 {
@@ -5493,6 +7463,43 @@ impl IDWriteFontFace6_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontFace6_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontFace6_Vtbl
+where
+    IDWriteFontFace5_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFamilyNames<Identity: windows_core::IUnknownImpl + IDWriteFontFace6_Impl>(this: *mut core::ffi::c_void, fontfamilymodel: DWRITE_FONT_FAMILY_MODEL, names: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFace6_Impl::GetFamilyNames(this, core::mem::transmute_copy(&fontfamilymodel)) {
+                    Ok(ok__) => {
+                        names.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFaceNames<Identity: windows_core::IUnknownImpl + IDWriteFontFace6_Impl>(this: *mut core::ffi::c_void, fontfamilymodel: DWRITE_FONT_FAMILY_MODEL, names: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFace6_Impl::GetFaceNames(this, core::mem::transmute_copy(&fontfamilymodel)) {
+                    Ok(ok__) => {
+                        names.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDWriteFontFace5_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFamilyNames: GetFamilyNames::<Identity>,
+            GetFaceNames: GetFaceNames::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
 impl windows_core::RuntimeName for IDWriteFontFace6 {}
 windows_core::imp::define_interface!(IDWriteFontFace7, IDWriteFontFace7_Vtbl, 0x3945b85b_bc95_40f7_b72c_8b73bfc7e13b);
 impl core::ops::Deref for IDWriteFontFace7 {
@@ -5559,6 +7566,37 @@ impl IDWriteFontFace7_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontFace7_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontFace7_Vtbl
+where
+    IDWriteFontFace6_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPaintFeatureLevel<Identity: windows_core::IUnknownImpl + IDWriteFontFace7_Impl>(this: *mut core::ffi::c_void, glyphimageformat: DWRITE_GLYPH_IMAGE_FORMATS) -> DWRITE_PAINT_FEATURE_LEVEL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFace7_Impl::GetPaintFeatureLevel(this, core::mem::transmute_copy(&glyphimageformat))
+            }
+        }
+        unsafe extern "system" fn CreatePaintReader<Identity: windows_core::IUnknownImpl + IDWriteFontFace7_Impl>(this: *mut core::ffi::c_void, glyphimageformat: DWRITE_GLYPH_IMAGE_FORMATS, paintfeaturelevel: DWRITE_PAINT_FEATURE_LEVEL, paintreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFace7_Impl::CreatePaintReader(this, core::mem::transmute_copy(&glyphimageformat), core::mem::transmute_copy(&paintfeaturelevel)) {
+                    Ok(ok__) => {
+                        paintreader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDWriteFontFace6_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPaintFeatureLevel: GetPaintFeatureLevel::<Identity>,
+            CreatePaintReader: CreatePaintReader::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
 impl windows_core::RuntimeName for IDWriteFontFace7 {}
 windows_core::imp::define_interface!(IDWriteFontFaceReference, IDWriteFontFaceReference_Vtbl, 0x5e7fa7ca_dde3_424c_89f0_9fcd6fed58cd);
 windows_core::imp::interface_hierarchy!(IDWriteFontFaceReference, windows_core::IUnknown);
@@ -5790,6 +7828,138 @@ impl IDWriteFontFaceReference_Vtbl {
         iid == &<IDWriteFontFaceReference as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontFaceReference_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontFaceReference_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateFontFace<Identity: windows_core::IUnknownImpl + IDWriteFontFaceReference_Impl>(this: *mut core::ffi::c_void, fontface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFaceReference_Impl::CreateFontFace(this) {
+                    Ok(ok__) => {
+                        fontface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateFontFaceWithSimulations<Identity: windows_core::IUnknownImpl + IDWriteFontFaceReference_Impl>(this: *mut core::ffi::c_void, fontfacesimulationflags: DWRITE_FONT_SIMULATIONS, fontface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFaceReference_Impl::CreateFontFaceWithSimulations(this, core::mem::transmute_copy(&fontfacesimulationflags)) {
+                    Ok(ok__) => {
+                        fontface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Equals<Identity: windows_core::IUnknownImpl + IDWriteFontFaceReference_Impl>(this: *mut core::ffi::c_void, fontfacereference: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFaceReference_Impl::Equals(this, core::mem::transmute_copy(&fontfacereference))
+            }
+        }
+        unsafe extern "system" fn GetFontFaceIndex<Identity: windows_core::IUnknownImpl + IDWriteFontFaceReference_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFaceReference_Impl::GetFontFaceIndex(this)
+            }
+        }
+        unsafe extern "system" fn GetSimulations<Identity: windows_core::IUnknownImpl + IDWriteFontFaceReference_Impl>(this: *mut core::ffi::c_void) -> DWRITE_FONT_SIMULATIONS {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFaceReference_Impl::GetSimulations(this)
+            }
+        }
+        unsafe extern "system" fn GetFontFile<Identity: windows_core::IUnknownImpl + IDWriteFontFaceReference_Impl>(this: *mut core::ffi::c_void, fontfile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFaceReference_Impl::GetFontFile(this) {
+                    Ok(ok__) => {
+                        fontfile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLocalFileSize<Identity: windows_core::IUnknownImpl + IDWriteFontFaceReference_Impl>(this: *mut core::ffi::c_void) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFaceReference_Impl::GetLocalFileSize(this)
+            }
+        }
+        unsafe extern "system" fn GetFileSize<Identity: windows_core::IUnknownImpl + IDWriteFontFaceReference_Impl>(this: *mut core::ffi::c_void) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFaceReference_Impl::GetFileSize(this)
+            }
+        }
+        unsafe extern "system" fn GetFileTime<Identity: windows_core::IUnknownImpl + IDWriteFontFaceReference_Impl>(this: *mut core::ffi::c_void, lastwritetime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFaceReference_Impl::GetFileTime(this) {
+                    Ok(ok__) => {
+                        lastwritetime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLocality<Identity: windows_core::IUnknownImpl + IDWriteFontFaceReference_Impl>(this: *mut core::ffi::c_void) -> DWRITE_LOCALITY {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFaceReference_Impl::GetLocality(this)
+            }
+        }
+        unsafe extern "system" fn EnqueueFontDownloadRequest<Identity: windows_core::IUnknownImpl + IDWriteFontFaceReference_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFaceReference_Impl::EnqueueFontDownloadRequest(this).into()
+            }
+        }
+        unsafe extern "system" fn EnqueueCharacterDownloadRequest<Identity: windows_core::IUnknownImpl + IDWriteFontFaceReference_Impl>(this: *mut core::ffi::c_void, characters: windows_core::PCWSTR, charactercount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFaceReference_Impl::EnqueueCharacterDownloadRequest(this, core::mem::transmute(&characters), core::mem::transmute_copy(&charactercount)).into()
+            }
+        }
+        unsafe extern "system" fn EnqueueGlyphDownloadRequest<Identity: windows_core::IUnknownImpl + IDWriteFontFaceReference_Impl>(this: *mut core::ffi::c_void, glyphindices: *const u16, glyphcount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFaceReference_Impl::EnqueueGlyphDownloadRequest(this, core::mem::transmute_copy(&glyphindices), core::mem::transmute_copy(&glyphcount)).into()
+            }
+        }
+        unsafe extern "system" fn EnqueueFileFragmentDownloadRequest<Identity: windows_core::IUnknownImpl + IDWriteFontFaceReference_Impl>(this: *mut core::ffi::c_void, fileoffset: u64, fragmentsize: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFaceReference_Impl::EnqueueFileFragmentDownloadRequest(this, core::mem::transmute_copy(&fileoffset), core::mem::transmute_copy(&fragmentsize)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateFontFace: CreateFontFace::<Identity>,
+            CreateFontFaceWithSimulations: CreateFontFaceWithSimulations::<Identity>,
+            Equals: Equals::<Identity>,
+            GetFontFaceIndex: GetFontFaceIndex::<Identity>,
+            GetSimulations: GetSimulations::<Identity>,
+            GetFontFile: GetFontFile::<Identity>,
+            GetLocalFileSize: GetLocalFileSize::<Identity>,
+            GetFileSize: GetFileSize::<Identity>,
+            GetFileTime: GetFileTime::<Identity>,
+            GetLocality: GetLocality::<Identity>,
+            EnqueueFontDownloadRequest: EnqueueFontDownloadRequest::<Identity>,
+            EnqueueCharacterDownloadRequest: EnqueueCharacterDownloadRequest::<Identity>,
+            EnqueueGlyphDownloadRequest: EnqueueGlyphDownloadRequest::<Identity>,
+            EnqueueFileFragmentDownloadRequest: EnqueueFileFragmentDownloadRequest::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontFaceReference {}
 windows_core::imp::define_interface!(IDWriteFontFaceReference1, IDWriteFontFaceReference1_Vtbl, 0xc081fe77_2fd1_41ac_a5a3_34983c4ba61a);
 impl core::ops::Deref for IDWriteFontFaceReference1 {
@@ -5865,6 +8035,43 @@ impl IDWriteFontFaceReference1_Vtbl {
         iid == &<IDWriteFontFaceReference1 as windows_core::Interface>::IID || iid == &<IDWriteFontFaceReference as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontFaceReference1_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontFaceReference1_Vtbl
+where
+    IDWriteFontFaceReference_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateFontFace<Identity: windows_core::IUnknownImpl + IDWriteFontFaceReference1_Impl>(this: *mut core::ffi::c_void, fontface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFaceReference1_Impl::CreateFontFace(this) {
+                    Ok(ok__) => {
+                        fontface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFontAxisValueCount<Identity: windows_core::IUnknownImpl + IDWriteFontFaceReference1_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFaceReference1_Impl::GetFontAxisValueCount(this)
+            }
+        }
+        unsafe extern "system" fn GetFontAxisValues<Identity: windows_core::IUnknownImpl + IDWriteFontFaceReference1_Impl>(this: *mut core::ffi::c_void, fontaxisvalues: *mut DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFaceReference1_Impl::GetFontAxisValues(this, core::mem::transmute_copy(&fontaxisvalues), core::mem::transmute_copy(&fontaxisvaluecount)).into()
+            }
+        }
+        Self {
+            base__: <IDWriteFontFaceReference_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateFontFace: CreateFontFace::<Identity>,
+            GetFontAxisValueCount: GetFontAxisValueCount::<Identity>,
+            GetFontAxisValues: GetFontAxisValues::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontFaceReference1 {}
 windows_core::imp::define_interface!(IDWriteFontFallback, IDWriteFontFallback_Vtbl, 0xefa008f9_f7a1_48bf_b05c_f224713cc0ff);
 windows_core::imp::interface_hierarchy!(IDWriteFontFallback, windows_core::IUnknown);
@@ -5903,6 +8110,20 @@ impl IDWriteFontFallback_Vtbl {
         iid == &<IDWriteFontFallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontFallback_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontFallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn MapCharacters<Identity: windows_core::IUnknownImpl + IDWriteFontFallback_Impl>(this: *mut core::ffi::c_void, analysissource: *mut core::ffi::c_void, textposition: u32, textlength: u32, basefontcollection: *mut core::ffi::c_void, basefamilyname: windows_core::PCWSTR, baseweight: DWRITE_FONT_WEIGHT, basestyle: DWRITE_FONT_STYLE, basestretch: DWRITE_FONT_STRETCH, mappedlength: *mut u32, mappedfont: *mut *mut core::ffi::c_void, scale: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFallback_Impl::MapCharacters(this, core::mem::transmute_copy(&analysissource), core::mem::transmute_copy(&textposition), core::mem::transmute_copy(&textlength), core::mem::transmute_copy(&basefontcollection), core::mem::transmute(&basefamilyname), core::mem::transmute_copy(&baseweight), core::mem::transmute_copy(&basestyle), core::mem::transmute_copy(&basestretch), core::mem::transmute_copy(&mappedlength), core::mem::transmute_copy(&mappedfont), core::mem::transmute_copy(&scale)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, MapCharacters: MapCharacters::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontFallback {}
 windows_core::imp::define_interface!(IDWriteFontFallback1, IDWriteFontFallback1_Vtbl, 0x2397599d_dd0d_4681_bd6a_f4f31eaade77);
 impl core::ops::Deref for IDWriteFontFallback1 {
@@ -5947,6 +8168,20 @@ impl IDWriteFontFallback1_Vtbl {
         iid == &<IDWriteFontFallback1 as windows_core::Interface>::IID || iid == &<IDWriteFontFallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontFallback1_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontFallback1_Vtbl
+where
+    IDWriteFontFallback_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn MapCharacters<Identity: windows_core::IUnknownImpl + IDWriteFontFallback1_Impl>(this: *mut core::ffi::c_void, analysissource: *mut core::ffi::c_void, textposition: u32, textlength: u32, basefontcollection: *mut core::ffi::c_void, basefamilyname: windows_core::PCWSTR, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, mappedlength: *mut u32, scale: *mut f32, mappedfontface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFallback1_Impl::MapCharacters(this, core::mem::transmute_copy(&analysissource), core::mem::transmute_copy(&textposition), core::mem::transmute_copy(&textlength), core::mem::transmute_copy(&basefontcollection), core::mem::transmute(&basefamilyname), core::mem::transmute_copy(&fontaxisvalues), core::mem::transmute_copy(&fontaxisvaluecount), core::mem::transmute_copy(&mappedlength), core::mem::transmute_copy(&scale), core::mem::transmute_copy(&mappedfontface)).into()
+            }
+        }
+        Self { base__: <IDWriteFontFallback_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, MapCharacters: MapCharacters::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontFallback1 {}
 windows_core::imp::define_interface!(IDWriteFontFallbackBuilder, IDWriteFontFallbackBuilder_Vtbl, 0xfd882d06_8aba_4fb8_b849_8be8b73e14de);
 windows_core::imp::interface_hierarchy!(IDWriteFontFallbackBuilder, windows_core::IUnknown);
@@ -6019,10 +8254,47 @@ impl IDWriteFontFallbackBuilder_Vtbl {
             AddMappings: AddMappings::<Identity, OFFSET>,
             CreateFontFallback: CreateFontFallback::<Identity, OFFSET>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDWriteFontFallbackBuilder as windows_core::Interface>::IID
-    }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontFallbackBuilder as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontFallbackBuilder_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontFallbackBuilder_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddMapping<Identity: windows_core::IUnknownImpl + IDWriteFontFallbackBuilder_Impl>(this: *mut core::ffi::c_void, ranges: *const DWRITE_UNICODE_RANGE, rangescount: u32, targetfamilynames: *const *const u16, targetfamilynamescount: u32, fontcollection: *mut core::ffi::c_void, localename: windows_core::PCWSTR, basefamilyname: windows_core::PCWSTR, scale: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFallbackBuilder_Impl::AddMapping(this, core::mem::transmute_copy(&ranges), core::mem::transmute_copy(&rangescount), core::mem::transmute_copy(&targetfamilynames), core::mem::transmute_copy(&targetfamilynamescount), core::mem::transmute_copy(&fontcollection), core::mem::transmute(&localename), core::mem::transmute(&basefamilyname), core::mem::transmute_copy(&scale)).into()
+            }
+        }
+        unsafe extern "system" fn AddMappings<Identity: windows_core::IUnknownImpl + IDWriteFontFallbackBuilder_Impl>(this: *mut core::ffi::c_void, fontfallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFallbackBuilder_Impl::AddMappings(this, core::mem::transmute_copy(&fontfallback)).into()
+            }
+        }
+        unsafe extern "system" fn CreateFontFallback<Identity: windows_core::IUnknownImpl + IDWriteFontFallbackBuilder_Impl>(this: *mut core::ffi::c_void, fontfallback: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFallbackBuilder_Impl::CreateFontFallback(this) {
+                    Ok(ok__) => {
+                        fontfallback.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddMapping: AddMapping::<Identity>,
+            AddMappings: AddMappings::<Identity>,
+            CreateFontFallback: CreateFontFallback::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IDWriteFontFallbackBuilder {}
 windows_core::imp::define_interface!(IDWriteFontFamily, IDWriteFontFamily_Vtbl, 0xda20d8ef_812a_4c43_9802_62ec4abd7add);
@@ -6117,6 +8389,55 @@ impl IDWriteFontFamily_Vtbl {
         iid == &<IDWriteFontFamily as windows_core::Interface>::IID || iid == &<IDWriteFontList as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontFamily_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontFamily_Vtbl
+where
+    IDWriteFontList_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFamilyNames<Identity: windows_core::IUnknownImpl + IDWriteFontFamily_Impl>(this: *mut core::ffi::c_void, names: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFamily_Impl::GetFamilyNames(this) {
+                    Ok(ok__) => {
+                        names.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFirstMatchingFont<Identity: windows_core::IUnknownImpl + IDWriteFontFamily_Impl>(this: *mut core::ffi::c_void, weight: DWRITE_FONT_WEIGHT, stretch: DWRITE_FONT_STRETCH, style: DWRITE_FONT_STYLE, matchingfont: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFamily_Impl::GetFirstMatchingFont(this, core::mem::transmute_copy(&weight), core::mem::transmute_copy(&stretch), core::mem::transmute_copy(&style)) {
+                    Ok(ok__) => {
+                        matchingfont.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMatchingFonts<Identity: windows_core::IUnknownImpl + IDWriteFontFamily_Impl>(this: *mut core::ffi::c_void, weight: DWRITE_FONT_WEIGHT, stretch: DWRITE_FONT_STRETCH, style: DWRITE_FONT_STYLE, matchingfonts: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFamily_Impl::GetMatchingFonts(this, core::mem::transmute_copy(&weight), core::mem::transmute_copy(&stretch), core::mem::transmute_copy(&style)) {
+                    Ok(ok__) => {
+                        matchingfonts.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDWriteFontList_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFamilyNames: GetFamilyNames::<Identity>,
+            GetFirstMatchingFont: GetFirstMatchingFont::<Identity>,
+            GetMatchingFonts: GetMatchingFonts::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontFamily {}
 windows_core::imp::define_interface!(IDWriteFontFamily1, IDWriteFontFamily1_Vtbl, 0xda20d8ef_812a_4c43_9802_62ec4abd7adf);
 impl core::ops::Deref for IDWriteFontFamily1 {
@@ -6201,6 +8522,49 @@ impl IDWriteFontFamily1_Vtbl {
         iid == &<IDWriteFontFamily1 as windows_core::Interface>::IID || iid == &<IDWriteFontList as windows_core::Interface>::IID || iid == &<IDWriteFontFamily as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontFamily1_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontFamily1_Vtbl
+where
+    IDWriteFontFamily_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFontLocality<Identity: windows_core::IUnknownImpl + IDWriteFontFamily1_Impl>(this: *mut core::ffi::c_void, listindex: u32) -> DWRITE_LOCALITY {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFamily1_Impl::GetFontLocality(this, core::mem::transmute_copy(&listindex))
+            }
+        }
+        unsafe extern "system" fn GetFont<Identity: windows_core::IUnknownImpl + IDWriteFontFamily1_Impl>(this: *mut core::ffi::c_void, listindex: u32, font: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFamily1_Impl::GetFont(this, core::mem::transmute_copy(&listindex)) {
+                    Ok(ok__) => {
+                        font.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFontFaceReference<Identity: windows_core::IUnknownImpl + IDWriteFontFamily1_Impl>(this: *mut core::ffi::c_void, listindex: u32, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFamily1_Impl::GetFontFaceReference(this, core::mem::transmute_copy(&listindex)) {
+                    Ok(ok__) => {
+                        fontfacereference.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDWriteFontFamily_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFontLocality: GetFontLocality::<Identity>,
+            GetFont: GetFont::<Identity>,
+            GetFontFaceReference: GetFontFaceReference::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontFamily1 {}
 windows_core::imp::define_interface!(IDWriteFontFamily2, IDWriteFontFamily2_Vtbl, 0x3ed49e77_a398_4261_b9cf_c126c2131ef3);
 impl core::ops::Deref for IDWriteFontFamily2 {
@@ -6273,6 +8637,42 @@ impl IDWriteFontFamily2_Vtbl {
         iid == &<IDWriteFontFamily2 as windows_core::Interface>::IID || iid == &<IDWriteFontList as windows_core::Interface>::IID || iid == &<IDWriteFontFamily as windows_core::Interface>::IID || iid == &<IDWriteFontFamily1 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontFamily2_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontFamily2_Vtbl
+where
+    IDWriteFontFamily1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMatchingFonts<Identity: windows_core::IUnknownImpl + IDWriteFontFamily2_Impl>(this: *mut core::ffi::c_void, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, matchingfonts: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFamily2_Impl::GetMatchingFonts(this, core::mem::transmute_copy(&fontaxisvalues), core::mem::transmute_copy(&fontaxisvaluecount)) {
+                    Ok(ok__) => {
+                        matchingfonts.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFontSet<Identity: windows_core::IUnknownImpl + IDWriteFontFamily2_Impl>(this: *mut core::ffi::c_void, fontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFamily2_Impl::GetFontSet(this) {
+                    Ok(ok__) => {
+                        fontset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDWriteFontFamily1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetMatchingFonts: GetMatchingFonts::<Identity>,
+            GetFontSet: GetFontSet::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontFamily2 {}
 windows_core::imp::define_interface!(IDWriteFontFile, IDWriteFontFile_Vtbl, 0x739d886a_cef5_47dc_8769_1a8b41bebbb0);
 windows_core::imp::interface_hierarchy!(IDWriteFontFile, windows_core::IUnknown);
@@ -6342,6 +8742,43 @@ impl IDWriteFontFile_Vtbl {
         iid == &<IDWriteFontFile as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontFile_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontFile_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetReferenceKey<Identity: windows_core::IUnknownImpl + IDWriteFontFile_Impl>(this: *mut core::ffi::c_void, fontfilereferencekey: *mut *mut core::ffi::c_void, fontfilereferencekeysize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFile_Impl::GetReferenceKey(this, core::mem::transmute_copy(&fontfilereferencekey), core::mem::transmute_copy(&fontfilereferencekeysize)).into()
+            }
+        }
+        unsafe extern "system" fn GetLoader<Identity: windows_core::IUnknownImpl + IDWriteFontFile_Impl>(this: *mut core::ffi::c_void, fontfileloader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFile_Impl::GetLoader(this) {
+                    Ok(ok__) => {
+                        fontfileloader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Analyze<Identity: windows_core::IUnknownImpl + IDWriteFontFile_Impl>(this: *mut core::ffi::c_void, issupportedfonttype: *mut windows_core::BOOL, fontfiletype: *mut DWRITE_FONT_FILE_TYPE, fontfacetype: *mut DWRITE_FONT_FACE_TYPE, numberoffaces: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFile_Impl::Analyze(this, core::mem::transmute_copy(&issupportedfonttype), core::mem::transmute_copy(&fontfiletype), core::mem::transmute_copy(&fontfacetype), core::mem::transmute_copy(&numberoffaces)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetReferenceKey: GetReferenceKey::<Identity>,
+            GetLoader: GetLoader::<Identity>,
+            Analyze: Analyze::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontFile {}
 windows_core::imp::define_interface!(IDWriteFontFileEnumerator, IDWriteFontFileEnumerator_Vtbl, 0x72755049_5ff7_435d_8348_4be97cfa6c7c);
 windows_core::imp::interface_hierarchy!(IDWriteFontFileEnumerator, windows_core::IUnknown);
@@ -6408,6 +8845,42 @@ impl IDWriteFontFileEnumerator_Vtbl {
         iid == &<IDWriteFontFileEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontFileEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontFileEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IDWriteFontFileEnumerator_Impl>(this: *mut core::ffi::c_void, hascurrentfile: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFileEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hascurrentfile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCurrentFontFile<Identity: windows_core::IUnknownImpl + IDWriteFontFileEnumerator_Impl>(this: *mut core::ffi::c_void, fontfile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFileEnumerator_Impl::GetCurrentFontFile(this) {
+                    Ok(ok__) => {
+                        fontfile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MoveNext: MoveNext::<Identity>,
+            GetCurrentFontFile: GetCurrentFontFile::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontFileEnumerator {}
 windows_core::imp::define_interface!(IDWriteFontFileLoader, IDWriteFontFileLoader_Vtbl, 0x727cad4e_d6af_4c9e_8a08_d695b11caa49);
 windows_core::imp::interface_hierarchy!(IDWriteFontFileLoader, windows_core::IUnknown);
@@ -6450,6 +8923,29 @@ impl IDWriteFontFileLoader_Vtbl {
         iid == &<IDWriteFontFileLoader as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontFileLoader_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontFileLoader_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateStreamFromKey<Identity: windows_core::IUnknownImpl + IDWriteFontFileLoader_Impl>(this: *mut core::ffi::c_void, fontfilereferencekey: *const core::ffi::c_void, fontfilereferencekeysize: u32, fontfilestream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFileLoader_Impl::CreateStreamFromKey(this, core::mem::transmute_copy(&fontfilereferencekey), core::mem::transmute_copy(&fontfilereferencekeysize)) {
+                    Ok(ok__) => {
+                        fontfilestream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateStreamFromKey: CreateStreamFromKey::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontFileLoader {}
 windows_core::imp::define_interface!(IDWriteFontFileStream, IDWriteFontFileStream_Vtbl, 0x6d4865fe_0ab8_4d91_8f62_5dd6be34a3e0);
 windows_core::imp::interface_hierarchy!(IDWriteFontFileStream, windows_core::IUnknown);
@@ -6540,6 +9036,56 @@ impl IDWriteFontFileStream_Vtbl {
         iid == &<IDWriteFontFileStream as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontFileStream_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontFileStream_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ReadFileFragment<Identity: windows_core::IUnknownImpl + IDWriteFontFileStream_Impl>(this: *mut core::ffi::c_void, fragmentstart: *mut *mut core::ffi::c_void, fileoffset: u64, fragmentsize: u64, fragmentcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFileStream_Impl::ReadFileFragment(this, core::mem::transmute_copy(&fragmentstart), core::mem::transmute_copy(&fileoffset), core::mem::transmute_copy(&fragmentsize), core::mem::transmute_copy(&fragmentcontext)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseFileFragment<Identity: windows_core::IUnknownImpl + IDWriteFontFileStream_Impl>(this: *mut core::ffi::c_void, fragmentcontext: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontFileStream_Impl::ReleaseFileFragment(this, core::mem::transmute_copy(&fragmentcontext))
+            }
+        }
+        unsafe extern "system" fn GetFileSize<Identity: windows_core::IUnknownImpl + IDWriteFontFileStream_Impl>(this: *mut core::ffi::c_void, filesize: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFileStream_Impl::GetFileSize(this) {
+                    Ok(ok__) => {
+                        filesize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLastWriteTime<Identity: windows_core::IUnknownImpl + IDWriteFontFileStream_Impl>(this: *mut core::ffi::c_void, lastwritetime: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontFileStream_Impl::GetLastWriteTime(this) {
+                    Ok(ok__) => {
+                        lastwritetime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ReadFileFragment: ReadFileFragment::<Identity>,
+            ReleaseFileFragment: ReleaseFileFragment::<Identity>,
+            GetFileSize: GetFileSize::<Identity>,
+            GetLastWriteTime: GetLastWriteTime::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontFileStream {}
 windows_core::imp::define_interface!(IDWriteFontList, IDWriteFontList_Vtbl, 0x1a0d8438_1d97_4ec1_aef9_a2fb86ed6acb);
 windows_core::imp::interface_hierarchy!(IDWriteFontList, windows_core::IUnknown);
@@ -6618,6 +9164,49 @@ impl IDWriteFontList_Vtbl {
         iid == &<IDWriteFontList as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontList_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontList_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFontCollection<Identity: windows_core::IUnknownImpl + IDWriteFontList_Impl>(this: *mut core::ffi::c_void, fontcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontList_Impl::GetFontCollection(this) {
+                    Ok(ok__) => {
+                        fontcollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFontCount<Identity: windows_core::IUnknownImpl + IDWriteFontList_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontList_Impl::GetFontCount(this)
+            }
+        }
+        unsafe extern "system" fn GetFont<Identity: windows_core::IUnknownImpl + IDWriteFontList_Impl>(this: *mut core::ffi::c_void, index: u32, font: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontList_Impl::GetFont(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        font.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFontCollection: GetFontCollection::<Identity>,
+            GetFontCount: GetFontCount::<Identity>,
+            GetFont: GetFont::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontList {}
 windows_core::imp::define_interface!(IDWriteFontList1, IDWriteFontList1_Vtbl, 0xda20d8ef_812a_4c43_9802_62ec4abd7ade);
 impl core::ops::Deref for IDWriteFontList1 {
@@ -6659,17 +9248,63 @@ pub trait IDWriteFontList1_Impl: IDWriteFontList_Impl {
     fn GetFont(&self, listindex: u32) -> windows_core::Result<IDWriteFont3>;
     fn GetFontFaceReference(&self, listindex: u32) -> windows_core::Result<IDWriteFontFaceReference>;
 }
-impl IDWriteFontList1_Vtbl {
-    pub const fn new<Identity: IDWriteFontList1_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn GetFontLocality<Identity: IDWriteFontList1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, listindex: u32) -> DWRITE_LOCALITY {
+impl IDWriteFontList1_Vtbl {
+    pub const fn new<Identity: IDWriteFontList1_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn GetFontLocality<Identity: IDWriteFontList1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, listindex: u32) -> DWRITE_LOCALITY {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteFontList1_Impl::GetFontLocality(this, core::mem::transmute_copy(&listindex))
+            }
+        }
+        unsafe extern "system" fn GetFont<Identity: IDWriteFontList1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, listindex: u32, font: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDWriteFontList1_Impl::GetFont(this, core::mem::transmute_copy(&listindex)) {
+                    Ok(ok__) => {
+                        font.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFontFaceReference<Identity: IDWriteFontList1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, listindex: u32, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDWriteFontList1_Impl::GetFontFaceReference(this, core::mem::transmute_copy(&listindex)) {
+                    Ok(ok__) => {
+                        fontfacereference.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: IDWriteFontList_Vtbl::new::<Identity, OFFSET>(),
+            GetFontLocality: GetFontLocality::<Identity, OFFSET>,
+            GetFont: GetFont::<Identity, OFFSET>,
+            GetFontFaceReference: GetFontFaceReference::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontList1 as windows_core::Interface>::IID || iid == &<IDWriteFontList as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontList1_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontList1_Vtbl
+where
+    IDWriteFontList_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFontLocality<Identity: windows_core::IUnknownImpl + IDWriteFontList1_Impl>(this: *mut core::ffi::c_void, listindex: u32) -> DWRITE_LOCALITY {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteFontList1_Impl::GetFontLocality(this, core::mem::transmute_copy(&listindex))
             }
         }
-        unsafe extern "system" fn GetFont<Identity: IDWriteFontList1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, listindex: u32, font: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetFont<Identity: windows_core::IUnknownImpl + IDWriteFontList1_Impl>(this: *mut core::ffi::c_void, listindex: u32, font: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDWriteFontList1_Impl::GetFont(this, core::mem::transmute_copy(&listindex)) {
                     Ok(ok__) => {
                         font.write(core::mem::transmute(ok__));
@@ -6679,9 +9314,9 @@ impl IDWriteFontList1_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetFontFaceReference<Identity: IDWriteFontList1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, listindex: u32, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetFontFaceReference<Identity: windows_core::IUnknownImpl + IDWriteFontList1_Impl>(this: *mut core::ffi::c_void, listindex: u32, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDWriteFontList1_Impl::GetFontFaceReference(this, core::mem::transmute_copy(&listindex)) {
                     Ok(ok__) => {
                         fontfacereference.write(core::mem::transmute(ok__));
@@ -6692,15 +9327,12 @@ impl IDWriteFontList1_Vtbl {
             }
         }
         Self {
-            base__: IDWriteFontList_Vtbl::new::<Identity, OFFSET>(),
-            GetFontLocality: GetFontLocality::<Identity, OFFSET>,
-            GetFont: GetFont::<Identity, OFFSET>,
-            GetFontFaceReference: GetFontFaceReference::<Identity, OFFSET>,
+            base__: <IDWriteFontList_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFontLocality: GetFontLocality::<Identity>,
+            GetFont: GetFont::<Identity>,
+            GetFontFaceReference: GetFontFaceReference::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDWriteFontList1 as windows_core::Interface>::IID || iid == &<IDWriteFontList as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IDWriteFontList1 {}
 windows_core::imp::define_interface!(IDWriteFontList2, IDWriteFontList2_Vtbl, 0xc0763a34_77af_445a_b735_08c37b0a5bf5);
@@ -6750,6 +9382,26 @@ impl IDWriteFontList2_Vtbl {
         iid == &<IDWriteFontList2 as windows_core::Interface>::IID || iid == &<IDWriteFontList as windows_core::Interface>::IID || iid == &<IDWriteFontList1 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontList2_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontList2_Vtbl
+where
+    IDWriteFontList1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFontSet<Identity: windows_core::IUnknownImpl + IDWriteFontList2_Impl>(this: *mut core::ffi::c_void, fontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontList2_Impl::GetFontSet(this) {
+                    Ok(ok__) => {
+                        fontset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IDWriteFontList1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetFontSet: GetFontSet::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontList2 {}
 windows_core::imp::define_interface!(IDWriteFontResource, IDWriteFontResource_Vtbl, 0x1f803a76_6871_48e8_987f_b975551c50f2);
 windows_core::imp::interface_hierarchy!(IDWriteFontResource, windows_core::IUnknown);
@@ -6954,6 +9606,124 @@ impl IDWriteFontResource_Vtbl {
         iid == &<IDWriteFontResource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontResource_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontResource_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFontFile<Identity: windows_core::IUnknownImpl + IDWriteFontResource_Impl>(this: *mut core::ffi::c_void, fontfile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontResource_Impl::GetFontFile(this) {
+                    Ok(ok__) => {
+                        fontfile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFontFaceIndex<Identity: windows_core::IUnknownImpl + IDWriteFontResource_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontResource_Impl::GetFontFaceIndex(this)
+            }
+        }
+        unsafe extern "system" fn GetFontAxisCount<Identity: windows_core::IUnknownImpl + IDWriteFontResource_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontResource_Impl::GetFontAxisCount(this)
+            }
+        }
+        unsafe extern "system" fn GetDefaultFontAxisValues<Identity: windows_core::IUnknownImpl + IDWriteFontResource_Impl>(this: *mut core::ffi::c_void, fontaxisvalues: *mut DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontResource_Impl::GetDefaultFontAxisValues(this, core::mem::transmute_copy(&fontaxisvalues), core::mem::transmute_copy(&fontaxisvaluecount)).into()
+            }
+        }
+        unsafe extern "system" fn GetFontAxisRanges<Identity: windows_core::IUnknownImpl + IDWriteFontResource_Impl>(this: *mut core::ffi::c_void, fontaxisranges: *mut DWRITE_FONT_AXIS_RANGE, fontaxisrangecount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontResource_Impl::GetFontAxisRanges(this, core::mem::transmute_copy(&fontaxisranges), core::mem::transmute_copy(&fontaxisrangecount)).into()
+            }
+        }
+        unsafe extern "system" fn GetFontAxisAttributes<Identity: windows_core::IUnknownImpl + IDWriteFontResource_Impl>(this: *mut core::ffi::c_void, axisindex: u32) -> DWRITE_FONT_AXIS_ATTRIBUTES {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontResource_Impl::GetFontAxisAttributes(this, core::mem::transmute_copy(&axisindex))
+            }
+        }
+        unsafe extern "system" fn GetAxisNames<Identity: windows_core::IUnknownImpl + IDWriteFontResource_Impl>(this: *mut core::ffi::c_void, axisindex: u32, names: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontResource_Impl::GetAxisNames(this, core::mem::transmute_copy(&axisindex)) {
+                    Ok(ok__) => {
+                        names.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAxisValueNameCount<Identity: windows_core::IUnknownImpl + IDWriteFontResource_Impl>(this: *mut core::ffi::c_void, axisindex: u32) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontResource_Impl::GetAxisValueNameCount(this, core::mem::transmute_copy(&axisindex))
+            }
+        }
+        unsafe extern "system" fn GetAxisValueNames<Identity: windows_core::IUnknownImpl + IDWriteFontResource_Impl>(this: *mut core::ffi::c_void, axisindex: u32, axisvalueindex: u32, fontaxisrange: *mut DWRITE_FONT_AXIS_RANGE, names: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontResource_Impl::GetAxisValueNames(this, core::mem::transmute_copy(&axisindex), core::mem::transmute_copy(&axisvalueindex), core::mem::transmute_copy(&fontaxisrange), core::mem::transmute_copy(&names)).into()
+            }
+        }
+        unsafe extern "system" fn HasVariations<Identity: windows_core::IUnknownImpl + IDWriteFontResource_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontResource_Impl::HasVariations(this)
+            }
+        }
+        unsafe extern "system" fn CreateFontFace<Identity: windows_core::IUnknownImpl + IDWriteFontResource_Impl>(this: *mut core::ffi::c_void, fontsimulations: DWRITE_FONT_SIMULATIONS, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, fontface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontResource_Impl::CreateFontFace(this, core::mem::transmute_copy(&fontsimulations), core::mem::transmute_copy(&fontaxisvalues), core::mem::transmute_copy(&fontaxisvaluecount)) {
+                    Ok(ok__) => {
+                        fontface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateFontFaceReference<Identity: windows_core::IUnknownImpl + IDWriteFontResource_Impl>(this: *mut core::ffi::c_void, fontsimulations: DWRITE_FONT_SIMULATIONS, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontResource_Impl::CreateFontFaceReference(this, core::mem::transmute_copy(&fontsimulations), core::mem::transmute_copy(&fontaxisvalues), core::mem::transmute_copy(&fontaxisvaluecount)) {
+                    Ok(ok__) => {
+                        fontfacereference.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFontFile: GetFontFile::<Identity>,
+            GetFontFaceIndex: GetFontFaceIndex::<Identity>,
+            GetFontAxisCount: GetFontAxisCount::<Identity>,
+            GetDefaultFontAxisValues: GetDefaultFontAxisValues::<Identity>,
+            GetFontAxisRanges: GetFontAxisRanges::<Identity>,
+            GetFontAxisAttributes: GetFontAxisAttributes::<Identity>,
+            GetAxisNames: GetAxisNames::<Identity>,
+            GetAxisValueNameCount: GetAxisValueNameCount::<Identity>,
+            GetAxisValueNames: GetAxisValueNames::<Identity>,
+            HasVariations: HasVariations::<Identity>,
+            CreateFontFace: CreateFontFace::<Identity>,
+            CreateFontFaceReference: CreateFontFaceReference::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontResource {}
 windows_core::imp::define_interface!(IDWriteFontSet, IDWriteFontSet_Vtbl, 0x53585141_d9f8_4095_8321_d73cf6bd116b);
 windows_core::imp::interface_hierarchy!(IDWriteFontSet, windows_core::IUnknown);
@@ -7019,46 +9789,165 @@ impl IDWriteFontSet {
         }
     }
 }
-#[repr(C)]
-#[doc(hidden)]
-pub struct IDWriteFontSet_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    pub GetFontCount: unsafe extern "system" fn(*mut core::ffi::c_void) -> u32,
-    pub GetFontFaceReference: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub FindFontFaceReference: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut u32, *mut windows_core::BOOL) -> windows_core::HRESULT,
-    pub FindFontFace: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut u32, *mut windows_core::BOOL) -> windows_core::HRESULT,
-    pub GetPropertyValues: unsafe extern "system" fn(*mut core::ffi::c_void, DWRITE_FONT_PROPERTY_ID, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetPropertyValues2: unsafe extern "system" fn(*mut core::ffi::c_void, DWRITE_FONT_PROPERTY_ID, windows_core::PCWSTR, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetPropertyValues3: unsafe extern "system" fn(*mut core::ffi::c_void, u32, DWRITE_FONT_PROPERTY_ID, *mut windows_core::BOOL, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetPropertyOccurrenceCount: unsafe extern "system" fn(*mut core::ffi::c_void, *const DWRITE_FONT_PROPERTY, *mut u32) -> windows_core::HRESULT,
-    pub GetMatchingFonts: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::PCWSTR, DWRITE_FONT_WEIGHT, DWRITE_FONT_STRETCH, DWRITE_FONT_STYLE, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetMatchingFonts2: unsafe extern "system" fn(*mut core::ffi::c_void, *const DWRITE_FONT_PROPERTY, u32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-unsafe impl Send for IDWriteFontSet {}
-unsafe impl Sync for IDWriteFontSet {}
-pub trait IDWriteFontSet_Impl: windows_core::IUnknownImpl {
-    fn GetFontCount(&self) -> u32;
-    fn GetFontFaceReference(&self, listindex: u32) -> windows_core::Result<IDWriteFontFaceReference>;
-    fn FindFontFaceReference(&self, fontfacereference: windows_core::Ref<IDWriteFontFaceReference>, listindex: *mut u32, exists: *mut windows_core::BOOL) -> windows_core::Result<()>;
-    fn FindFontFace(&self, fontface: windows_core::Ref<IDWriteFontFace>, listindex: *mut u32, exists: *mut windows_core::BOOL) -> windows_core::Result<()>;
-    fn GetPropertyValues(&self, propertyid: DWRITE_FONT_PROPERTY_ID) -> windows_core::Result<IDWriteStringList>;
-    fn GetPropertyValues2(&self, propertyid: DWRITE_FONT_PROPERTY_ID, preferredlocalenames: &windows_core::PCWSTR) -> windows_core::Result<IDWriteStringList>;
-    fn GetPropertyValues3(&self, listindex: u32, propertyid: DWRITE_FONT_PROPERTY_ID, exists: *mut windows_core::BOOL, values: windows_core::OutRef<IDWriteLocalizedStrings>) -> windows_core::Result<()>;
-    fn GetPropertyOccurrenceCount(&self, property: *const DWRITE_FONT_PROPERTY) -> windows_core::Result<u32>;
-    fn GetMatchingFonts(&self, familyname: &windows_core::PCWSTR, fontweight: DWRITE_FONT_WEIGHT, fontstretch: DWRITE_FONT_STRETCH, fontstyle: DWRITE_FONT_STYLE) -> windows_core::Result<IDWriteFontSet>;
-    fn GetMatchingFonts2(&self, properties: *const DWRITE_FONT_PROPERTY, propertycount: u32) -> windows_core::Result<IDWriteFontSet>;
-}
-impl IDWriteFontSet_Vtbl {
-    pub const fn new<Identity: IDWriteFontSet_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn GetFontCount<Identity: IDWriteFontSet_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u32 {
+#[repr(C)]
+#[doc(hidden)]
+pub struct IDWriteFontSet_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub GetFontCount: unsafe extern "system" fn(*mut core::ffi::c_void) -> u32,
+    pub GetFontFaceReference: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub FindFontFaceReference: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut u32, *mut windows_core::BOOL) -> windows_core::HRESULT,
+    pub FindFontFace: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut u32, *mut windows_core::BOOL) -> windows_core::HRESULT,
+    pub GetPropertyValues: unsafe extern "system" fn(*mut core::ffi::c_void, DWRITE_FONT_PROPERTY_ID, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetPropertyValues2: unsafe extern "system" fn(*mut core::ffi::c_void, DWRITE_FONT_PROPERTY_ID, windows_core::PCWSTR, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetPropertyValues3: unsafe extern "system" fn(*mut core::ffi::c_void, u32, DWRITE_FONT_PROPERTY_ID, *mut windows_core::BOOL, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetPropertyOccurrenceCount: unsafe extern "system" fn(*mut core::ffi::c_void, *const DWRITE_FONT_PROPERTY, *mut u32) -> windows_core::HRESULT,
+    pub GetMatchingFonts: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::PCWSTR, DWRITE_FONT_WEIGHT, DWRITE_FONT_STRETCH, DWRITE_FONT_STYLE, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetMatchingFonts2: unsafe extern "system" fn(*mut core::ffi::c_void, *const DWRITE_FONT_PROPERTY, u32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+unsafe impl Send for IDWriteFontSet {}
+unsafe impl Sync for IDWriteFontSet {}
+pub trait IDWriteFontSet_Impl: windows_core::IUnknownImpl {
+    fn GetFontCount(&self) -> u32;
+    fn GetFontFaceReference(&self, listindex: u32) -> windows_core::Result<IDWriteFontFaceReference>;
+    fn FindFontFaceReference(&self, fontfacereference: windows_core::Ref<IDWriteFontFaceReference>, listindex: *mut u32, exists: *mut windows_core::BOOL) -> windows_core::Result<()>;
+    fn FindFontFace(&self, fontface: windows_core::Ref<IDWriteFontFace>, listindex: *mut u32, exists: *mut windows_core::BOOL) -> windows_core::Result<()>;
+    fn GetPropertyValues(&self, propertyid: DWRITE_FONT_PROPERTY_ID) -> windows_core::Result<IDWriteStringList>;
+    fn GetPropertyValues2(&self, propertyid: DWRITE_FONT_PROPERTY_ID, preferredlocalenames: &windows_core::PCWSTR) -> windows_core::Result<IDWriteStringList>;
+    fn GetPropertyValues3(&self, listindex: u32, propertyid: DWRITE_FONT_PROPERTY_ID, exists: *mut windows_core::BOOL, values: windows_core::OutRef<IDWriteLocalizedStrings>) -> windows_core::Result<()>;
+    fn GetPropertyOccurrenceCount(&self, property: *const DWRITE_FONT_PROPERTY) -> windows_core::Result<u32>;
+    fn GetMatchingFonts(&self, familyname: &windows_core::PCWSTR, fontweight: DWRITE_FONT_WEIGHT, fontstretch: DWRITE_FONT_STRETCH, fontstyle: DWRITE_FONT_STYLE) -> windows_core::Result<IDWriteFontSet>;
+    fn GetMatchingFonts2(&self, properties: *const DWRITE_FONT_PROPERTY, propertycount: u32) -> windows_core::Result<IDWriteFontSet>;
+}
+impl IDWriteFontSet_Vtbl {
+    pub const fn new<Identity: IDWriteFontSet_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn GetFontCount<Identity: IDWriteFontSet_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteFontSet_Impl::GetFontCount(this)
+            }
+        }
+        unsafe extern "system" fn GetFontFaceReference<Identity: IDWriteFontSet_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, listindex: u32, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDWriteFontSet_Impl::GetFontFaceReference(this, core::mem::transmute_copy(&listindex)) {
+                    Ok(ok__) => {
+                        fontfacereference.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindFontFaceReference<Identity: IDWriteFontSet_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fontfacereference: *mut core::ffi::c_void, listindex: *mut u32, exists: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteFontSet_Impl::FindFontFaceReference(this, core::mem::transmute_copy(&fontfacereference), core::mem::transmute_copy(&listindex), core::mem::transmute_copy(&exists)).into()
+            }
+        }
+        unsafe extern "system" fn FindFontFace<Identity: IDWriteFontSet_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, listindex: *mut u32, exists: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteFontSet_Impl::FindFontFace(this, core::mem::transmute_copy(&fontface), core::mem::transmute_copy(&listindex), core::mem::transmute_copy(&exists)).into()
+            }
+        }
+        unsafe extern "system" fn GetPropertyValues<Identity: IDWriteFontSet_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, propertyid: DWRITE_FONT_PROPERTY_ID, values: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDWriteFontSet_Impl::GetPropertyValues(this, core::mem::transmute_copy(&propertyid)) {
+                    Ok(ok__) => {
+                        values.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPropertyValues2<Identity: IDWriteFontSet_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, propertyid: DWRITE_FONT_PROPERTY_ID, preferredlocalenames: windows_core::PCWSTR, values: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDWriteFontSet_Impl::GetPropertyValues2(this, core::mem::transmute_copy(&propertyid), core::mem::transmute(&preferredlocalenames)) {
+                    Ok(ok__) => {
+                        values.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPropertyValues3<Identity: IDWriteFontSet_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, listindex: u32, propertyid: DWRITE_FONT_PROPERTY_ID, exists: *mut windows_core::BOOL, values: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteFontSet_Impl::GetPropertyValues3(this, core::mem::transmute_copy(&listindex), core::mem::transmute_copy(&propertyid), core::mem::transmute_copy(&exists), core::mem::transmute_copy(&values)).into()
+            }
+        }
+        unsafe extern "system" fn GetPropertyOccurrenceCount<Identity: IDWriteFontSet_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, property: *const DWRITE_FONT_PROPERTY, propertyoccurrencecount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDWriteFontSet_Impl::GetPropertyOccurrenceCount(this, core::mem::transmute_copy(&property)) {
+                    Ok(ok__) => {
+                        propertyoccurrencecount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMatchingFonts<Identity: IDWriteFontSet_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, familyname: windows_core::PCWSTR, fontweight: DWRITE_FONT_WEIGHT, fontstretch: DWRITE_FONT_STRETCH, fontstyle: DWRITE_FONT_STYLE, filteredset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDWriteFontSet_Impl::GetMatchingFonts(this, core::mem::transmute(&familyname), core::mem::transmute_copy(&fontweight), core::mem::transmute_copy(&fontstretch), core::mem::transmute_copy(&fontstyle)) {
+                    Ok(ok__) => {
+                        filteredset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMatchingFonts2<Identity: IDWriteFontSet_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, properties: *const DWRITE_FONT_PROPERTY, propertycount: u32, filteredset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDWriteFontSet_Impl::GetMatchingFonts2(this, core::mem::transmute_copy(&properties), core::mem::transmute_copy(&propertycount)) {
+                    Ok(ok__) => {
+                        filteredset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            GetFontCount: GetFontCount::<Identity, OFFSET>,
+            GetFontFaceReference: GetFontFaceReference::<Identity, OFFSET>,
+            FindFontFaceReference: FindFontFaceReference::<Identity, OFFSET>,
+            FindFontFace: FindFontFace::<Identity, OFFSET>,
+            GetPropertyValues: GetPropertyValues::<Identity, OFFSET>,
+            GetPropertyValues2: GetPropertyValues2::<Identity, OFFSET>,
+            GetPropertyValues3: GetPropertyValues3::<Identity, OFFSET>,
+            GetPropertyOccurrenceCount: GetPropertyOccurrenceCount::<Identity, OFFSET>,
+            GetMatchingFonts: GetMatchingFonts::<Identity, OFFSET>,
+            GetMatchingFonts2: GetMatchingFonts2::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontSet as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontSet_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontSet_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFontCount<Identity: windows_core::IUnknownImpl + IDWriteFontSet_Impl>(this: *mut core::ffi::c_void) -> u32 {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteFontSet_Impl::GetFontCount(this)
             }
         }
-        unsafe extern "system" fn GetFontFaceReference<Identity: IDWriteFontSet_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, listindex: u32, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetFontFaceReference<Identity: windows_core::IUnknownImpl + IDWriteFontSet_Impl>(this: *mut core::ffi::c_void, listindex: u32, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDWriteFontSet_Impl::GetFontFaceReference(this, core::mem::transmute_copy(&listindex)) {
                     Ok(ok__) => {
                         fontfacereference.write(core::mem::transmute(ok__));
@@ -7068,21 +9957,21 @@ impl IDWriteFontSet_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn FindFontFaceReference<Identity: IDWriteFontSet_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fontfacereference: *mut core::ffi::c_void, listindex: *mut u32, exists: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn FindFontFaceReference<Identity: windows_core::IUnknownImpl + IDWriteFontSet_Impl>(this: *mut core::ffi::c_void, fontfacereference: *mut core::ffi::c_void, listindex: *mut u32, exists: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteFontSet_Impl::FindFontFaceReference(this, core::mem::transmute_copy(&fontfacereference), core::mem::transmute_copy(&listindex), core::mem::transmute_copy(&exists)).into()
             }
         }
-        unsafe extern "system" fn FindFontFace<Identity: IDWriteFontSet_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, listindex: *mut u32, exists: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn FindFontFace<Identity: windows_core::IUnknownImpl + IDWriteFontSet_Impl>(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, listindex: *mut u32, exists: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteFontSet_Impl::FindFontFace(this, core::mem::transmute_copy(&fontface), core::mem::transmute_copy(&listindex), core::mem::transmute_copy(&exists)).into()
             }
         }
-        unsafe extern "system" fn GetPropertyValues<Identity: IDWriteFontSet_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, propertyid: DWRITE_FONT_PROPERTY_ID, values: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetPropertyValues<Identity: windows_core::IUnknownImpl + IDWriteFontSet_Impl>(this: *mut core::ffi::c_void, propertyid: DWRITE_FONT_PROPERTY_ID, values: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDWriteFontSet_Impl::GetPropertyValues(this, core::mem::transmute_copy(&propertyid)) {
                     Ok(ok__) => {
                         values.write(core::mem::transmute(ok__));
@@ -7092,9 +9981,9 @@ impl IDWriteFontSet_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetPropertyValues2<Identity: IDWriteFontSet_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, propertyid: DWRITE_FONT_PROPERTY_ID, preferredlocalenames: windows_core::PCWSTR, values: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetPropertyValues2<Identity: windows_core::IUnknownImpl + IDWriteFontSet_Impl>(this: *mut core::ffi::c_void, propertyid: DWRITE_FONT_PROPERTY_ID, preferredlocalenames: windows_core::PCWSTR, values: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDWriteFontSet_Impl::GetPropertyValues2(this, core::mem::transmute_copy(&propertyid), core::mem::transmute(&preferredlocalenames)) {
                     Ok(ok__) => {
                         values.write(core::mem::transmute(ok__));
@@ -7104,15 +9993,15 @@ impl IDWriteFontSet_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetPropertyValues3<Identity: IDWriteFontSet_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, listindex: u32, propertyid: DWRITE_FONT_PROPERTY_ID, exists: *mut windows_core::BOOL, values: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetPropertyValues3<Identity: windows_core::IUnknownImpl + IDWriteFontSet_Impl>(this: *mut core::ffi::c_void, listindex: u32, propertyid: DWRITE_FONT_PROPERTY_ID, exists: *mut windows_core::BOOL, values: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteFontSet_Impl::GetPropertyValues3(this, core::mem::transmute_copy(&listindex), core::mem::transmute_copy(&propertyid), core::mem::transmute_copy(&exists), core::mem::transmute_copy(&values)).into()
             }
         }
-        unsafe extern "system" fn GetPropertyOccurrenceCount<Identity: IDWriteFontSet_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, property: *const DWRITE_FONT_PROPERTY, propertyoccurrencecount: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetPropertyOccurrenceCount<Identity: windows_core::IUnknownImpl + IDWriteFontSet_Impl>(this: *mut core::ffi::c_void, property: *const DWRITE_FONT_PROPERTY, propertyoccurrencecount: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDWriteFontSet_Impl::GetPropertyOccurrenceCount(this, core::mem::transmute_copy(&property)) {
                     Ok(ok__) => {
                         propertyoccurrencecount.write(core::mem::transmute(ok__));
@@ -7122,9 +10011,9 @@ impl IDWriteFontSet_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetMatchingFonts<Identity: IDWriteFontSet_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, familyname: windows_core::PCWSTR, fontweight: DWRITE_FONT_WEIGHT, fontstretch: DWRITE_FONT_STRETCH, fontstyle: DWRITE_FONT_STYLE, filteredset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetMatchingFonts<Identity: windows_core::IUnknownImpl + IDWriteFontSet_Impl>(this: *mut core::ffi::c_void, familyname: windows_core::PCWSTR, fontweight: DWRITE_FONT_WEIGHT, fontstretch: DWRITE_FONT_STRETCH, fontstyle: DWRITE_FONT_STYLE, filteredset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDWriteFontSet_Impl::GetMatchingFonts(this, core::mem::transmute(&familyname), core::mem::transmute_copy(&fontweight), core::mem::transmute_copy(&fontstretch), core::mem::transmute_copy(&fontstyle)) {
                     Ok(ok__) => {
                         filteredset.write(core::mem::transmute(ok__));
@@ -7134,9 +10023,9 @@ impl IDWriteFontSet_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetMatchingFonts2<Identity: IDWriteFontSet_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, properties: *const DWRITE_FONT_PROPERTY, propertycount: u32, filteredset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetMatchingFonts2<Identity: windows_core::IUnknownImpl + IDWriteFontSet_Impl>(this: *mut core::ffi::c_void, properties: *const DWRITE_FONT_PROPERTY, propertycount: u32, filteredset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDWriteFontSet_Impl::GetMatchingFonts2(this, core::mem::transmute_copy(&properties), core::mem::transmute_copy(&propertycount)) {
                     Ok(ok__) => {
                         filteredset.write(core::mem::transmute(ok__));
@@ -7147,22 +10036,19 @@ impl IDWriteFontSet_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            GetFontCount: GetFontCount::<Identity, OFFSET>,
-            GetFontFaceReference: GetFontFaceReference::<Identity, OFFSET>,
-            FindFontFaceReference: FindFontFaceReference::<Identity, OFFSET>,
-            FindFontFace: FindFontFace::<Identity, OFFSET>,
-            GetPropertyValues: GetPropertyValues::<Identity, OFFSET>,
-            GetPropertyValues2: GetPropertyValues2::<Identity, OFFSET>,
-            GetPropertyValues3: GetPropertyValues3::<Identity, OFFSET>,
-            GetPropertyOccurrenceCount: GetPropertyOccurrenceCount::<Identity, OFFSET>,
-            GetMatchingFonts: GetMatchingFonts::<Identity, OFFSET>,
-            GetMatchingFonts2: GetMatchingFonts2::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDWriteFontSet as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFontCount: GetFontCount::<Identity>,
+            GetFontFaceReference: GetFontFaceReference::<Identity>,
+            FindFontFaceReference: FindFontFaceReference::<Identity>,
+            FindFontFace: FindFontFace::<Identity>,
+            GetPropertyValues: GetPropertyValues::<Identity>,
+            GetPropertyValues2: GetPropertyValues2::<Identity>,
+            GetPropertyValues3: GetPropertyValues3::<Identity>,
+            GetPropertyOccurrenceCount: GetPropertyOccurrenceCount::<Identity>,
+            GetMatchingFonts: GetMatchingFonts::<Identity>,
+            GetMatchingFonts2: GetMatchingFonts2::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IDWriteFontSet {}
 windows_core::imp::define_interface!(IDWriteFontSet1, IDWriteFontSet1_Vtbl, 0x7e9fda85_6c92_4053_bc47_7ae3530db4d3);
@@ -7422,6 +10308,155 @@ impl IDWriteFontSet1_Vtbl {
         iid == &<IDWriteFontSet1 as windows_core::Interface>::IID || iid == &<IDWriteFontSet as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontSet1_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontSet1_Vtbl
+where
+    IDWriteFontSet_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMatchingFonts<Identity: windows_core::IUnknownImpl + IDWriteFontSet1_Impl>(this: *mut core::ffi::c_void, fontproperty: *const DWRITE_FONT_PROPERTY, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, matchingfonts: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontSet1_Impl::GetMatchingFonts(this, core::mem::transmute_copy(&fontproperty), core::mem::transmute_copy(&fontaxisvalues), core::mem::transmute_copy(&fontaxisvaluecount)) {
+                    Ok(ok__) => {
+                        matchingfonts.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFirstFontResources<Identity: windows_core::IUnknownImpl + IDWriteFontSet1_Impl>(this: *mut core::ffi::c_void, filteredfontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontSet1_Impl::GetFirstFontResources(this) {
+                    Ok(ok__) => {
+                        filteredfontset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFilteredFonts<Identity: windows_core::IUnknownImpl + IDWriteFontSet1_Impl>(this: *mut core::ffi::c_void, indices: *const u32, indexcount: u32, filteredfontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontSet1_Impl::GetFilteredFonts(this, core::mem::transmute_copy(&indices), core::mem::transmute_copy(&indexcount)) {
+                    Ok(ok__) => {
+                        filteredfontset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFilteredFonts2<Identity: windows_core::IUnknownImpl + IDWriteFontSet1_Impl>(this: *mut core::ffi::c_void, fontaxisranges: *const DWRITE_FONT_AXIS_RANGE, fontaxisrangecount: u32, selectanyrange: windows_core::BOOL, filteredfontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontSet1_Impl::GetFilteredFonts2(this, core::mem::transmute_copy(&fontaxisranges), core::mem::transmute_copy(&fontaxisrangecount), core::mem::transmute_copy(&selectanyrange)) {
+                    Ok(ok__) => {
+                        filteredfontset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFilteredFonts3<Identity: windows_core::IUnknownImpl + IDWriteFontSet1_Impl>(this: *mut core::ffi::c_void, properties: *const DWRITE_FONT_PROPERTY, propertycount: u32, selectanyproperty: windows_core::BOOL, filteredfontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontSet1_Impl::GetFilteredFonts3(this, core::mem::transmute_copy(&properties), core::mem::transmute_copy(&propertycount), core::mem::transmute_copy(&selectanyproperty)) {
+                    Ok(ok__) => {
+                        filteredfontset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFilteredFontIndices<Identity: windows_core::IUnknownImpl + IDWriteFontSet1_Impl>(this: *mut core::ffi::c_void, fontaxisranges: *const DWRITE_FONT_AXIS_RANGE, fontaxisrangecount: u32, selectanyrange: windows_core::BOOL, indices: *mut u32, maxindexcount: u32, actualindexcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontSet1_Impl::GetFilteredFontIndices(this, core::mem::transmute_copy(&fontaxisranges), core::mem::transmute_copy(&fontaxisrangecount), core::mem::transmute_copy(&selectanyrange), core::mem::transmute_copy(&indices), core::mem::transmute_copy(&maxindexcount), core::mem::transmute_copy(&actualindexcount)).into()
+            }
+        }
+        unsafe extern "system" fn GetFilteredFontIndices2<Identity: windows_core::IUnknownImpl + IDWriteFontSet1_Impl>(this: *mut core::ffi::c_void, properties: *const DWRITE_FONT_PROPERTY, propertycount: u32, selectanyproperty: windows_core::BOOL, indices: *mut u32, maxindexcount: u32, actualindexcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontSet1_Impl::GetFilteredFontIndices2(this, core::mem::transmute_copy(&properties), core::mem::transmute_copy(&propertycount), core::mem::transmute_copy(&selectanyproperty), core::mem::transmute_copy(&indices), core::mem::transmute_copy(&maxindexcount), core::mem::transmute_copy(&actualindexcount)).into()
+            }
+        }
+        unsafe extern "system" fn GetFontAxisRanges<Identity: windows_core::IUnknownImpl + IDWriteFontSet1_Impl>(this: *mut core::ffi::c_void, listindex: u32, fontaxisranges: *mut DWRITE_FONT_AXIS_RANGE, maxfontaxisrangecount: u32, actualfontaxisrangecount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontSet1_Impl::GetFontAxisRanges(this, core::mem::transmute_copy(&listindex), core::mem::transmute_copy(&fontaxisranges), core::mem::transmute_copy(&maxfontaxisrangecount), core::mem::transmute_copy(&actualfontaxisrangecount)).into()
+            }
+        }
+        unsafe extern "system" fn GetFontAxisRanges2<Identity: windows_core::IUnknownImpl + IDWriteFontSet1_Impl>(this: *mut core::ffi::c_void, fontaxisranges: *mut DWRITE_FONT_AXIS_RANGE, maxfontaxisrangecount: u32, actualfontaxisrangecount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontSet1_Impl::GetFontAxisRanges2(this, core::mem::transmute_copy(&fontaxisranges), core::mem::transmute_copy(&maxfontaxisrangecount), core::mem::transmute_copy(&actualfontaxisrangecount)).into()
+            }
+        }
+        unsafe extern "system" fn GetFontFaceReference<Identity: windows_core::IUnknownImpl + IDWriteFontSet1_Impl>(this: *mut core::ffi::c_void, listindex: u32, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontSet1_Impl::GetFontFaceReference(this, core::mem::transmute_copy(&listindex)) {
+                    Ok(ok__) => {
+                        fontfacereference.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateFontResource<Identity: windows_core::IUnknownImpl + IDWriteFontSet1_Impl>(this: *mut core::ffi::c_void, listindex: u32, fontresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontSet1_Impl::CreateFontResource(this, core::mem::transmute_copy(&listindex)) {
+                    Ok(ok__) => {
+                        fontresource.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateFontFace<Identity: windows_core::IUnknownImpl + IDWriteFontSet1_Impl>(this: *mut core::ffi::c_void, listindex: u32, fontface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontSet1_Impl::CreateFontFace(this, core::mem::transmute_copy(&listindex)) {
+                    Ok(ok__) => {
+                        fontface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFontLocality<Identity: windows_core::IUnknownImpl + IDWriteFontSet1_Impl>(this: *mut core::ffi::c_void, listindex: u32) -> DWRITE_LOCALITY {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontSet1_Impl::GetFontLocality(this, core::mem::transmute_copy(&listindex))
+            }
+        }
+        Self {
+            base__: <IDWriteFontSet_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetMatchingFonts: GetMatchingFonts::<Identity>,
+            GetFirstFontResources: GetFirstFontResources::<Identity>,
+            GetFilteredFonts: GetFilteredFonts::<Identity>,
+            GetFilteredFonts2: GetFilteredFonts2::<Identity>,
+            GetFilteredFonts3: GetFilteredFonts3::<Identity>,
+            GetFilteredFontIndices: GetFilteredFontIndices::<Identity>,
+            GetFilteredFontIndices2: GetFilteredFontIndices2::<Identity>,
+            GetFontAxisRanges: GetFontAxisRanges::<Identity>,
+            GetFontAxisRanges2: GetFontAxisRanges2::<Identity>,
+            GetFontFaceReference: GetFontFaceReference::<Identity>,
+            CreateFontResource: CreateFontResource::<Identity>,
+            CreateFontFace: CreateFontFace::<Identity>,
+            GetFontLocality: GetFontLocality::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontSet1 {}
 windows_core::imp::define_interface!(IDWriteFontSet2, IDWriteFontSet2_Vtbl, 0xdc7ead19_e54c_43af_b2da_4e2b79ba3f7f);
 impl core::ops::Deref for IDWriteFontSet2 {
@@ -7461,6 +10496,20 @@ impl IDWriteFontSet2_Vtbl {
         iid == &<IDWriteFontSet2 as windows_core::Interface>::IID || iid == &<IDWriteFontSet as windows_core::Interface>::IID || iid == &<IDWriteFontSet1 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontSet2_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontSet2_Vtbl
+where
+    IDWriteFontSet1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetExpirationEvent<Identity: windows_core::IUnknownImpl + IDWriteFontSet2_Impl>(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontSet2_Impl::GetExpirationEvent(this)
+            }
+        }
+        Self { base__: <IDWriteFontSet1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetExpirationEvent: GetExpirationEvent::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontSet2 {}
 windows_core::imp::define_interface!(IDWriteFontSet3, IDWriteFontSet3_Vtbl, 0x7c073ef2_a7f4_4045_8c32_8ab8ae640f90);
 impl core::ops::Deref for IDWriteFontSet3 {
@@ -7527,6 +10576,37 @@ impl IDWriteFontSet3_Vtbl {
         iid == &<IDWriteFontSet3 as windows_core::Interface>::IID || iid == &<IDWriteFontSet as windows_core::Interface>::IID || iid == &<IDWriteFontSet1 as windows_core::Interface>::IID || iid == &<IDWriteFontSet2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontSet3_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontSet3_Vtbl
+where
+    IDWriteFontSet2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFontSourceType<Identity: windows_core::IUnknownImpl + IDWriteFontSet3_Impl>(this: *mut core::ffi::c_void, fontindex: u32) -> DWRITE_FONT_SOURCE_TYPE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontSet3_Impl::GetFontSourceType(this, core::mem::transmute_copy(&fontindex))
+            }
+        }
+        unsafe extern "system" fn GetFontSourceNameLength<Identity: windows_core::IUnknownImpl + IDWriteFontSet3_Impl>(this: *mut core::ffi::c_void, listindex: u32) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontSet3_Impl::GetFontSourceNameLength(this, core::mem::transmute_copy(&listindex))
+            }
+        }
+        unsafe extern "system" fn GetFontSourceName<Identity: windows_core::IUnknownImpl + IDWriteFontSet3_Impl>(this: *mut core::ffi::c_void, listindex: u32, stringbuffer: windows_core::PWSTR, stringbuffersize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontSet3_Impl::GetFontSourceName(this, core::mem::transmute_copy(&listindex), core::mem::transmute_copy(&stringbuffer), core::mem::transmute_copy(&stringbuffersize)).into()
+            }
+        }
+        Self {
+            base__: <IDWriteFontSet2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFontSourceType: GetFontSourceType::<Identity>,
+            GetFontSourceNameLength: GetFontSourceNameLength::<Identity>,
+            GetFontSourceName: GetFontSourceName::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontSet3 {}
 windows_core::imp::define_interface!(IDWriteFontSet4, IDWriteFontSet4_Vtbl, 0xeec175fc_bea9_4c86_8b53_ccbdd7df0c82);
 impl core::ops::Deref for IDWriteFontSet4 {
@@ -7593,6 +10673,36 @@ impl IDWriteFontSet4_Vtbl {
         iid == &<IDWriteFontSet4 as windows_core::Interface>::IID || iid == &<IDWriteFontSet as windows_core::Interface>::IID || iid == &<IDWriteFontSet1 as windows_core::Interface>::IID || iid == &<IDWriteFontSet2 as windows_core::Interface>::IID || iid == &<IDWriteFontSet3 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontSet4_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontSet4_Vtbl
+where
+    IDWriteFontSet3_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ConvertWeightStretchStyleToFontAxisValues<Identity: windows_core::IUnknownImpl + IDWriteFontSet4_Impl>(this: *mut core::ffi::c_void, inputaxisvalues: *const DWRITE_FONT_AXIS_VALUE, inputaxiscount: u32, fontweight: DWRITE_FONT_WEIGHT, fontstretch: DWRITE_FONT_STRETCH, fontstyle: DWRITE_FONT_STYLE, fontsize: f32, outputaxisvalues: *mut DWRITE_FONT_AXIS_VALUE) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontSet4_Impl::ConvertWeightStretchStyleToFontAxisValues(this, core::mem::transmute_copy(&inputaxisvalues), core::mem::transmute_copy(&inputaxiscount), core::mem::transmute_copy(&fontweight), core::mem::transmute_copy(&fontstretch), core::mem::transmute_copy(&fontstyle), core::mem::transmute_copy(&fontsize), core::mem::transmute_copy(&outputaxisvalues))
+            }
+        }
+        unsafe extern "system" fn GetMatchingFonts<Identity: windows_core::IUnknownImpl + IDWriteFontSet4_Impl>(this: *mut core::ffi::c_void, familyname: windows_core::PCWSTR, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, allowedsimulations: DWRITE_FONT_SIMULATIONS, matchingfonts: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontSet4_Impl::GetMatchingFonts(this, core::mem::transmute(&familyname), core::mem::transmute_copy(&fontaxisvalues), core::mem::transmute_copy(&fontaxisvaluecount), core::mem::transmute_copy(&allowedsimulations)) {
+                    Ok(ok__) => {
+                        matchingfonts.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDWriteFontSet3_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ConvertWeightStretchStyleToFontAxisValues: ConvertWeightStretchStyleToFontAxisValues::<Identity>,
+            GetMatchingFonts: GetMatchingFonts::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontSet4 {}
 windows_core::imp::define_interface!(IDWriteFontSetBuilder, IDWriteFontSetBuilder_Vtbl, 0x2f642afe_9c68_4f40_b8be_457401afcb3d);
 windows_core::imp::interface_hierarchy!(IDWriteFontSetBuilder, windows_core::IUnknown);
@@ -7672,16 +10782,60 @@ impl IDWriteFontSetBuilder_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            AddFontFaceReference: AddFontFaceReference::<Identity, OFFSET>,
-            AddFontFaceReference2: AddFontFaceReference2::<Identity, OFFSET>,
-            AddFontSet: AddFontSet::<Identity, OFFSET>,
-            CreateFontSet: CreateFontSet::<Identity, OFFSET>,
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            AddFontFaceReference: AddFontFaceReference::<Identity, OFFSET>,
+            AddFontFaceReference2: AddFontFaceReference2::<Identity, OFFSET>,
+            AddFontSet: AddFontSet::<Identity, OFFSET>,
+            CreateFontSet: CreateFontSet::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontSetBuilder as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontSetBuilder_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontSetBuilder_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddFontFaceReference<Identity: windows_core::IUnknownImpl + IDWriteFontSetBuilder_Impl>(this: *mut core::ffi::c_void, fontfacereference: *mut core::ffi::c_void, properties: *const DWRITE_FONT_PROPERTY, propertycount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontSetBuilder_Impl::AddFontFaceReference(this, core::mem::transmute_copy(&fontfacereference), core::mem::transmute_copy(&properties), core::mem::transmute_copy(&propertycount)).into()
+            }
+        }
+        unsafe extern "system" fn AddFontFaceReference2<Identity: windows_core::IUnknownImpl + IDWriteFontSetBuilder_Impl>(this: *mut core::ffi::c_void, fontfacereference: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontSetBuilder_Impl::AddFontFaceReference2(this, core::mem::transmute_copy(&fontfacereference)).into()
+            }
+        }
+        unsafe extern "system" fn AddFontSet<Identity: windows_core::IUnknownImpl + IDWriteFontSetBuilder_Impl>(this: *mut core::ffi::c_void, fontset: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontSetBuilder_Impl::AddFontSet(this, core::mem::transmute_copy(&fontset)).into()
+            }
+        }
+        unsafe extern "system" fn CreateFontSet<Identity: windows_core::IUnknownImpl + IDWriteFontSetBuilder_Impl>(this: *mut core::ffi::c_void, fontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteFontSetBuilder_Impl::CreateFontSet(this) {
+                    Ok(ok__) => {
+                        fontset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddFontFaceReference: AddFontFaceReference::<Identity>,
+            AddFontFaceReference2: AddFontFaceReference2::<Identity>,
+            AddFontSet: AddFontSet::<Identity>,
+            CreateFontSet: CreateFontSet::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDWriteFontSetBuilder as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IDWriteFontSetBuilder {}
 windows_core::imp::define_interface!(IDWriteFontSetBuilder1, IDWriteFontSetBuilder1_Vtbl, 0x3ff7715f_3cdc_4dc6_9b72_ec5621dccafd);
@@ -7725,6 +10879,20 @@ impl IDWriteFontSetBuilder1_Vtbl {
         iid == &<IDWriteFontSetBuilder1 as windows_core::Interface>::IID || iid == &<IDWriteFontSetBuilder as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontSetBuilder1_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontSetBuilder1_Vtbl
+where
+    IDWriteFontSetBuilder_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddFontFile<Identity: windows_core::IUnknownImpl + IDWriteFontSetBuilder1_Impl>(this: *mut core::ffi::c_void, fontfile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontSetBuilder1_Impl::AddFontFile(this, core::mem::transmute_copy(&fontfile)).into()
+            }
+        }
+        Self { base__: <IDWriteFontSetBuilder_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, AddFontFile: AddFontFile::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontSetBuilder1 {}
 windows_core::imp::define_interface!(IDWriteFontSetBuilder2, IDWriteFontSetBuilder2_Vtbl, 0xee5ba612_b131_463c_8f4f_3189b9401e45);
 impl core::ops::Deref for IDWriteFontSetBuilder2 {
@@ -7785,6 +10953,30 @@ impl IDWriteFontSetBuilder2_Vtbl {
         iid == &<IDWriteFontSetBuilder2 as windows_core::Interface>::IID || iid == &<IDWriteFontSetBuilder as windows_core::Interface>::IID || iid == &<IDWriteFontSetBuilder1 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteFontSetBuilder2_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteFontSetBuilder2_Vtbl
+where
+    IDWriteFontSetBuilder1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddFont<Identity: windows_core::IUnknownImpl + IDWriteFontSetBuilder2_Impl>(this: *mut core::ffi::c_void, fontfile: *mut core::ffi::c_void, fontfaceindex: u32, fontsimulations: DWRITE_FONT_SIMULATIONS, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, fontaxisranges: *const DWRITE_FONT_AXIS_RANGE, fontaxisrangecount: u32, properties: *const DWRITE_FONT_PROPERTY, propertycount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontSetBuilder2_Impl::AddFont(this, core::mem::transmute_copy(&fontfile), core::mem::transmute_copy(&fontfaceindex), core::mem::transmute_copy(&fontsimulations), core::mem::transmute_copy(&fontaxisvalues), core::mem::transmute_copy(&fontaxisvaluecount), core::mem::transmute_copy(&fontaxisranges), core::mem::transmute_copy(&fontaxisrangecount), core::mem::transmute_copy(&properties), core::mem::transmute_copy(&propertycount)).into()
+            }
+        }
+        unsafe extern "system" fn AddFontFile<Identity: windows_core::IUnknownImpl + IDWriteFontSetBuilder2_Impl>(this: *mut core::ffi::c_void, filepath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteFontSetBuilder2_Impl::AddFontFile(this, core::mem::transmute(&filepath)).into()
+            }
+        }
+        Self {
+            base__: <IDWriteFontSetBuilder1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddFont: AddFont::<Identity>,
+            AddFontFile: AddFontFile::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteFontSetBuilder2 {}
 windows_core::imp::define_interface!(IDWriteGdiInterop, IDWriteGdiInterop_Vtbl, 0x1edd9491_9853_4299_898f_6432983b6f3a);
 windows_core::imp::interface_hierarchy!(IDWriteGdiInterop, windows_core::IUnknown);
@@ -7925,6 +11117,70 @@ impl IDWriteGdiInterop_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IDWriteGdiInterop_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteGdiInterop_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateFontFromLOGFONT<Identity: windows_core::IUnknownImpl + IDWriteGdiInterop_Impl>(this: *mut core::ffi::c_void, logfont: *const super::Gdi::LOGFONTW, font: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteGdiInterop_Impl::CreateFontFromLOGFONT(this, core::mem::transmute_copy(&logfont)) {
+                    Ok(ok__) => {
+                        font.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ConvertFontToLOGFONT<Identity: windows_core::IUnknownImpl + IDWriteGdiInterop_Impl>(this: *mut core::ffi::c_void, font: *mut core::ffi::c_void, logfont: *mut super::Gdi::LOGFONTW, issystemfont: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteGdiInterop_Impl::ConvertFontToLOGFONT(this, core::mem::transmute_copy(&font), core::mem::transmute_copy(&logfont), core::mem::transmute_copy(&issystemfont)).into()
+            }
+        }
+        unsafe extern "system" fn ConvertFontFaceToLOGFONT<Identity: windows_core::IUnknownImpl + IDWriteGdiInterop_Impl>(this: *mut core::ffi::c_void, font: *mut core::ffi::c_void, logfont: *mut super::Gdi::LOGFONTW) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteGdiInterop_Impl::ConvertFontFaceToLOGFONT(this, core::mem::transmute_copy(&font), core::mem::transmute_copy(&logfont)).into()
+            }
+        }
+        unsafe extern "system" fn CreateFontFaceFromHdc<Identity: windows_core::IUnknownImpl + IDWriteGdiInterop_Impl>(this: *mut core::ffi::c_void, hdc: super::Gdi::HDC, fontface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteGdiInterop_Impl::CreateFontFaceFromHdc(this, core::mem::transmute_copy(&hdc)) {
+                    Ok(ok__) => {
+                        fontface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateBitmapRenderTarget<Identity: windows_core::IUnknownImpl + IDWriteGdiInterop_Impl>(this: *mut core::ffi::c_void, hdc: super::Gdi::HDC, width: u32, height: u32, rendertarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteGdiInterop_Impl::CreateBitmapRenderTarget(this, core::mem::transmute_copy(&hdc), core::mem::transmute_copy(&width), core::mem::transmute_copy(&height)) {
+                    Ok(ok__) => {
+                        rendertarget.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateFontFromLOGFONT: CreateFontFromLOGFONT::<Identity>,
+            ConvertFontToLOGFONT: ConvertFontToLOGFONT::<Identity>,
+            ConvertFontFaceToLOGFONT: ConvertFontFaceToLOGFONT::<Identity>,
+            CreateFontFaceFromHdc: CreateFontFaceFromHdc::<Identity>,
+            CreateBitmapRenderTarget: CreateBitmapRenderTarget::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IDWriteGdiInterop {}
 windows_core::imp::define_interface!(IDWriteGdiInterop1, IDWriteGdiInterop1_Vtbl, 0x4556be70_3abd_4f70_90be_421780a6f515);
 impl core::ops::Deref for IDWriteGdiInterop1 {
@@ -8052,6 +11308,57 @@ impl IDWriteGdiInterop1_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Globalization", feature = "Win32_Graphics_Gdi"))]
+impl<Identity: windows_core::IUnknownImpl + IDWriteGdiInterop1_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteGdiInterop1_Vtbl
+where
+    IDWriteGdiInterop_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateFontFromLOGFONT<Identity: windows_core::IUnknownImpl + IDWriteGdiInterop1_Impl>(this: *mut core::ffi::c_void, logfont: *const super::Gdi::LOGFONTW, fontcollection: *mut core::ffi::c_void, font: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteGdiInterop1_Impl::CreateFontFromLOGFONT(this, core::mem::transmute_copy(&logfont), core::mem::transmute_copy(&fontcollection)) {
+                    Ok(ok__) => {
+                        font.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFontSignature<Identity: windows_core::IUnknownImpl + IDWriteGdiInterop1_Impl>(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, fontsignature: *mut super::super::Globalization::FONTSIGNATURE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteGdiInterop1_Impl::GetFontSignature(this, core::mem::transmute_copy(&fontface), core::mem::transmute_copy(&fontsignature)).into()
+            }
+        }
+        unsafe extern "system" fn GetFontSignature2<Identity: windows_core::IUnknownImpl + IDWriteGdiInterop1_Impl>(this: *mut core::ffi::c_void, font: *mut core::ffi::c_void, fontsignature: *mut super::super::Globalization::FONTSIGNATURE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteGdiInterop1_Impl::GetFontSignature2(this, core::mem::transmute_copy(&font), core::mem::transmute_copy(&fontsignature)).into()
+            }
+        }
+        unsafe extern "system" fn GetMatchingFontsByLOGFONT<Identity: windows_core::IUnknownImpl + IDWriteGdiInterop1_Impl>(this: *mut core::ffi::c_void, logfont: *const super::Gdi::LOGFONTA, fontset: *mut core::ffi::c_void, filteredset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteGdiInterop1_Impl::GetMatchingFontsByLOGFONT(this, core::mem::transmute_copy(&logfont), core::mem::transmute_copy(&fontset)) {
+                    Ok(ok__) => {
+                        filteredset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDWriteGdiInterop_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateFontFromLOGFONT: CreateFontFromLOGFONT::<Identity>,
+            GetFontSignature: GetFontSignature::<Identity>,
+            GetFontSignature2: GetFontSignature2::<Identity>,
+            GetMatchingFontsByLOGFONT: GetMatchingFontsByLOGFONT::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Globalization", feature = "Win32_Graphics_Gdi"))]
 impl windows_core::RuntimeName for IDWriteGdiInterop1 {}
 windows_core::imp::define_interface!(IDWriteGlyphRunAnalysis, IDWriteGlyphRunAnalysis_Vtbl, 0x7d97dbf7_e085_42d4_81e3_6a883bded118);
 windows_core::imp::interface_hierarchy!(IDWriteGlyphRunAnalysis, windows_core::IUnknown);
@@ -8124,6 +11431,43 @@ impl IDWriteGlyphRunAnalysis_Vtbl {
         iid == &<IDWriteGlyphRunAnalysis as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteGlyphRunAnalysis_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteGlyphRunAnalysis_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAlphaTextureBounds<Identity: windows_core::IUnknownImpl + IDWriteGlyphRunAnalysis_Impl>(this: *mut core::ffi::c_void, texturetype: DWRITE_TEXTURE_TYPE, texturebounds: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteGlyphRunAnalysis_Impl::GetAlphaTextureBounds(this, core::mem::transmute_copy(&texturetype)) {
+                    Ok(ok__) => {
+                        texturebounds.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateAlphaTexture<Identity: windows_core::IUnknownImpl + IDWriteGlyphRunAnalysis_Impl>(this: *mut core::ffi::c_void, texturetype: DWRITE_TEXTURE_TYPE, texturebounds: *const super::super::Foundation::RECT, alphavalues: *mut u8, buffersize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteGlyphRunAnalysis_Impl::CreateAlphaTexture(this, core::mem::transmute_copy(&texturetype), core::mem::transmute_copy(&texturebounds), core::mem::transmute_copy(&alphavalues), core::mem::transmute_copy(&buffersize)).into()
+            }
+        }
+        unsafe extern "system" fn GetAlphaBlendParams<Identity: windows_core::IUnknownImpl + IDWriteGlyphRunAnalysis_Impl>(this: *mut core::ffi::c_void, renderingparams: *mut core::ffi::c_void, blendgamma: *mut f32, blendenhancedcontrast: *mut f32, blendcleartypelevel: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteGlyphRunAnalysis_Impl::GetAlphaBlendParams(this, core::mem::transmute_copy(&renderingparams), core::mem::transmute_copy(&blendgamma), core::mem::transmute_copy(&blendenhancedcontrast), core::mem::transmute_copy(&blendcleartypelevel)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAlphaTextureBounds: GetAlphaTextureBounds::<Identity>,
+            CreateAlphaTexture: CreateAlphaTexture::<Identity>,
+            GetAlphaBlendParams: GetAlphaBlendParams::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteGlyphRunAnalysis {}
 windows_core::imp::define_interface!(IDWriteInMemoryFontFileLoader, IDWriteInMemoryFontFileLoader_Vtbl, 0xdc102f47_a12d_4b1c_822d_9e117e33043f);
 impl core::ops::Deref for IDWriteInMemoryFontFileLoader {
@@ -8191,6 +11535,36 @@ impl IDWriteInMemoryFontFileLoader_Vtbl {
         iid == &<IDWriteInMemoryFontFileLoader as windows_core::Interface>::IID || iid == &<IDWriteFontFileLoader as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteInMemoryFontFileLoader_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteInMemoryFontFileLoader_Vtbl
+where
+    IDWriteFontFileLoader_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateInMemoryFontFileReference<Identity: windows_core::IUnknownImpl + IDWriteInMemoryFontFileLoader_Impl>(this: *mut core::ffi::c_void, factory: *mut core::ffi::c_void, fontdata: *const core::ffi::c_void, fontdatasize: u32, ownerobject: *mut core::ffi::c_void, fontfile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteInMemoryFontFileLoader_Impl::CreateInMemoryFontFileReference(this, core::mem::transmute_copy(&factory), core::mem::transmute_copy(&fontdata), core::mem::transmute_copy(&fontdatasize), core::mem::transmute_copy(&ownerobject)) {
+                    Ok(ok__) => {
+                        fontfile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFileCount<Identity: windows_core::IUnknownImpl + IDWriteInMemoryFontFileLoader_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteInMemoryFontFileLoader_Impl::GetFileCount(this)
+            }
+        }
+        Self {
+            base__: <IDWriteFontFileLoader_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateInMemoryFontFileReference: CreateInMemoryFontFileReference::<Identity>,
+            GetFileCount: GetFileCount::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteInMemoryFontFileLoader {}
 windows_core::imp::define_interface!(IDWriteInlineObject, IDWriteInlineObject_Vtbl, 0x8339fde3_106f_47ab_8373_1c6295eb10b3);
 windows_core::imp::interface_hierarchy!(IDWriteInlineObject, windows_core::IUnknown);
@@ -8285,6 +11659,56 @@ impl IDWriteInlineObject_Vtbl {
         iid == &<IDWriteInlineObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteInlineObject_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteInlineObject_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Draw<Identity: windows_core::IUnknownImpl + IDWriteInlineObject_Impl>(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, renderer: *mut core::ffi::c_void, originx: f32, originy: f32, issideways: windows_core::BOOL, isrighttoleft: windows_core::BOOL, clientdrawingeffect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteInlineObject_Impl::Draw(this, core::mem::transmute_copy(&clientdrawingcontext), core::mem::transmute_copy(&renderer), core::mem::transmute_copy(&originx), core::mem::transmute_copy(&originy), core::mem::transmute_copy(&issideways), core::mem::transmute_copy(&isrighttoleft), core::mem::transmute_copy(&clientdrawingeffect)).into()
+            }
+        }
+        unsafe extern "system" fn GetMetrics<Identity: windows_core::IUnknownImpl + IDWriteInlineObject_Impl>(this: *mut core::ffi::c_void, metrics: *mut DWRITE_INLINE_OBJECT_METRICS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteInlineObject_Impl::GetMetrics(this) {
+                    Ok(ok__) => {
+                        metrics.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOverhangMetrics<Identity: windows_core::IUnknownImpl + IDWriteInlineObject_Impl>(this: *mut core::ffi::c_void, overhangs: *mut DWRITE_OVERHANG_METRICS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteInlineObject_Impl::GetOverhangMetrics(this) {
+                    Ok(ok__) => {
+                        overhangs.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetBreakConditions<Identity: windows_core::IUnknownImpl + IDWriteInlineObject_Impl>(this: *mut core::ffi::c_void, breakconditionbefore: *mut DWRITE_BREAK_CONDITION, breakconditionafter: *mut DWRITE_BREAK_CONDITION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteInlineObject_Impl::GetBreakConditions(this, core::mem::transmute_copy(&breakconditionbefore), core::mem::transmute_copy(&breakconditionafter)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Draw: Draw::<Identity>,
+            GetMetrics: GetMetrics::<Identity>,
+            GetOverhangMetrics: GetOverhangMetrics::<Identity>,
+            GetBreakConditions: GetBreakConditions::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteInlineObject {}
 windows_core::imp::define_interface!(IDWriteLocalFontFileLoader, IDWriteLocalFontFileLoader_Vtbl, 0xb2d9f3ec_c9fe_4a11_a2ec_d86208f7c0a2);
 impl core::ops::Deref for IDWriteLocalFontFileLoader {
@@ -8369,6 +11793,49 @@ impl IDWriteLocalFontFileLoader_Vtbl {
         iid == &<IDWriteLocalFontFileLoader as windows_core::Interface>::IID || iid == &<IDWriteFontFileLoader as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteLocalFontFileLoader_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteLocalFontFileLoader_Vtbl
+where
+    IDWriteFontFileLoader_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFilePathLengthFromKey<Identity: windows_core::IUnknownImpl + IDWriteLocalFontFileLoader_Impl>(this: *mut core::ffi::c_void, fontfilereferencekey: *const core::ffi::c_void, fontfilereferencekeysize: u32, filepathlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteLocalFontFileLoader_Impl::GetFilePathLengthFromKey(this, core::mem::transmute_copy(&fontfilereferencekey), core::mem::transmute_copy(&fontfilereferencekeysize)) {
+                    Ok(ok__) => {
+                        filepathlength.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFilePathFromKey<Identity: windows_core::IUnknownImpl + IDWriteLocalFontFileLoader_Impl>(this: *mut core::ffi::c_void, fontfilereferencekey: *const core::ffi::c_void, fontfilereferencekeysize: u32, filepath: windows_core::PWSTR, filepathsize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteLocalFontFileLoader_Impl::GetFilePathFromKey(this, core::mem::transmute_copy(&fontfilereferencekey), core::mem::transmute_copy(&fontfilereferencekeysize), core::mem::transmute_copy(&filepath), core::mem::transmute_copy(&filepathsize)).into()
+            }
+        }
+        unsafe extern "system" fn GetLastWriteTimeFromKey<Identity: windows_core::IUnknownImpl + IDWriteLocalFontFileLoader_Impl>(this: *mut core::ffi::c_void, fontfilereferencekey: *const core::ffi::c_void, fontfilereferencekeysize: u32, lastwritetime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteLocalFontFileLoader_Impl::GetLastWriteTimeFromKey(this, core::mem::transmute_copy(&fontfilereferencekey), core::mem::transmute_copy(&fontfilereferencekeysize)) {
+                    Ok(ok__) => {
+                        lastwritetime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDWriteFontFileLoader_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFilePathLengthFromKey: GetFilePathLengthFromKey::<Identity>,
+            GetFilePathFromKey: GetFilePathFromKey::<Identity>,
+            GetLastWriteTimeFromKey: GetLastWriteTimeFromKey::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteLocalFontFileLoader {}
 windows_core::imp::define_interface!(IDWriteLocalizedStrings, IDWriteLocalizedStrings_Vtbl, 0x08256209_099a_4b34_b86d_c22b110e7771);
 windows_core::imp::interface_hierarchy!(IDWriteLocalizedStrings, windows_core::IUnknown);
@@ -8481,10 +11948,74 @@ impl IDWriteLocalizedStrings_Vtbl {
             GetStringLength: GetStringLength::<Identity, OFFSET>,
             GetString: GetString::<Identity, OFFSET>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDWriteLocalizedStrings as windows_core::Interface>::IID
-    }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteLocalizedStrings as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IDWriteLocalizedStrings_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteLocalizedStrings_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IDWriteLocalizedStrings_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteLocalizedStrings_Impl::GetCount(this)
+            }
+        }
+        unsafe extern "system" fn FindLocaleName<Identity: windows_core::IUnknownImpl + IDWriteLocalizedStrings_Impl>(this: *mut core::ffi::c_void, localename: windows_core::PCWSTR, index: *mut u32, exists: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteLocalizedStrings_Impl::FindLocaleName(this, core::mem::transmute(&localename), core::mem::transmute_copy(&index), core::mem::transmute_copy(&exists)).into()
+            }
+        }
+        unsafe extern "system" fn GetLocaleNameLength<Identity: windows_core::IUnknownImpl + IDWriteLocalizedStrings_Impl>(this: *mut core::ffi::c_void, index: u32, length: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteLocalizedStrings_Impl::GetLocaleNameLength(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        length.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLocaleName<Identity: windows_core::IUnknownImpl + IDWriteLocalizedStrings_Impl>(this: *mut core::ffi::c_void, index: u32, localename: windows_core::PWSTR, size: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteLocalizedStrings_Impl::GetLocaleName(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&localename), core::mem::transmute_copy(&size)).into()
+            }
+        }
+        unsafe extern "system" fn GetStringLength<Identity: windows_core::IUnknownImpl + IDWriteLocalizedStrings_Impl>(this: *mut core::ffi::c_void, index: u32, length: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteLocalizedStrings_Impl::GetStringLength(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        length.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetString<Identity: windows_core::IUnknownImpl + IDWriteLocalizedStrings_Impl>(this: *mut core::ffi::c_void, index: u32, stringbuffer: windows_core::PWSTR, size: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteLocalizedStrings_Impl::GetString(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&stringbuffer), core::mem::transmute_copy(&size)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCount: GetCount::<Identity>,
+            FindLocaleName: FindLocaleName::<Identity>,
+            GetLocaleNameLength: GetLocaleNameLength::<Identity>,
+            GetLocaleName: GetLocaleName::<Identity>,
+            GetStringLength: GetStringLength::<Identity>,
+            GetString: GetString::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IDWriteLocalizedStrings {}
 windows_core::imp::define_interface!(IDWriteNumberSubstitution, IDWriteNumberSubstitution_Vtbl, 0x14885cc9_bab0_4f90_b6ed_5c366a2cd03d);
@@ -8505,6 +12036,12 @@ impl IDWriteNumberSubstitution_Vtbl {
         iid == &<IDWriteNumberSubstitution as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteNumberSubstitution_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteNumberSubstitution_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IDWriteNumberSubstitution {}
 windows_core::imp::define_interface!(IDWritePaintReader, IDWritePaintReader_Vtbl, 0x8128e912_3b97_42a5_ab6c_24aad3a86e54);
 windows_core::imp::interface_hierarchy!(IDWritePaintReader, windows_core::IUnknown);
@@ -8656,6 +12193,80 @@ impl IDWritePaintReader_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDWritePaintReader_Impl> windows_core::imp::TearOffVtable<Identity> for IDWritePaintReader_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetCurrentGlyph<Identity: windows_core::IUnknownImpl + IDWritePaintReader_Impl>(this: *mut core::ffi::c_void, glyphindex: u32, paintelement: *mut DWRITE_PAINT_ELEMENT, structsize: u32, clipbox: *mut super::Direct2D::Common::D2D_RECT_F, glyphattributes: *mut DWRITE_PAINT_ATTRIBUTES) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWritePaintReader_Impl::SetCurrentGlyph(this, core::mem::transmute_copy(&glyphindex), core::mem::transmute_copy(&paintelement), core::mem::transmute_copy(&structsize), core::mem::transmute_copy(&clipbox), core::mem::transmute_copy(&glyphattributes)).into()
+            }
+        }
+        unsafe extern "system" fn SetTextColor<Identity: windows_core::IUnknownImpl + IDWritePaintReader_Impl>(this: *mut core::ffi::c_void, textcolor: *const DWRITE_COLOR_F) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWritePaintReader_Impl::SetTextColor(this, core::mem::transmute_copy(&textcolor)).into()
+            }
+        }
+        unsafe extern "system" fn SetColorPaletteIndex<Identity: windows_core::IUnknownImpl + IDWritePaintReader_Impl>(this: *mut core::ffi::c_void, colorpaletteindex: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWritePaintReader_Impl::SetColorPaletteIndex(this, core::mem::transmute_copy(&colorpaletteindex)).into()
+            }
+        }
+        unsafe extern "system" fn SetCustomColorPalette<Identity: windows_core::IUnknownImpl + IDWritePaintReader_Impl>(this: *mut core::ffi::c_void, paletteentries: *const DWRITE_COLOR_F, paletteentrycount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWritePaintReader_Impl::SetCustomColorPalette(this, core::mem::transmute_copy(&paletteentries), core::mem::transmute_copy(&paletteentrycount)).into()
+            }
+        }
+        unsafe extern "system" fn MoveToFirstChild<Identity: windows_core::IUnknownImpl + IDWritePaintReader_Impl>(this: *mut core::ffi::c_void, paintelement: *mut DWRITE_PAINT_ELEMENT, structsize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWritePaintReader_Impl::MoveToFirstChild(this, core::mem::transmute_copy(&paintelement), core::mem::transmute_copy(&structsize)).into()
+            }
+        }
+        unsafe extern "system" fn MoveToNextSibling<Identity: windows_core::IUnknownImpl + IDWritePaintReader_Impl>(this: *mut core::ffi::c_void, paintelement: *mut DWRITE_PAINT_ELEMENT, structsize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWritePaintReader_Impl::MoveToNextSibling(this, core::mem::transmute_copy(&paintelement), core::mem::transmute_copy(&structsize)).into()
+            }
+        }
+        unsafe extern "system" fn MoveToParent<Identity: windows_core::IUnknownImpl + IDWritePaintReader_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWritePaintReader_Impl::MoveToParent(this).into()
+            }
+        }
+        unsafe extern "system" fn GetGradientStops<Identity: windows_core::IUnknownImpl + IDWritePaintReader_Impl>(this: *mut core::ffi::c_void, firstgradientstopindex: u32, gradientstopcount: u32, gradientstops: *mut super::Direct2D::Common::D2D1_GRADIENT_STOP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWritePaintReader_Impl::GetGradientStops(this, core::mem::transmute_copy(&firstgradientstopindex), core::mem::transmute_copy(&gradientstopcount), core::mem::transmute_copy(&gradientstops)).into()
+            }
+        }
+        unsafe extern "system" fn GetGradientStopColors<Identity: windows_core::IUnknownImpl + IDWritePaintReader_Impl>(this: *mut core::ffi::c_void, firstgradientstopindex: u32, gradientstopcount: u32, gradientstopcolors: *mut DWRITE_PAINT_COLOR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWritePaintReader_Impl::GetGradientStopColors(this, core::mem::transmute_copy(&firstgradientstopindex), core::mem::transmute_copy(&gradientstopcount), core::mem::transmute_copy(&gradientstopcolors)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetCurrentGlyph: SetCurrentGlyph::<Identity>,
+            SetTextColor: SetTextColor::<Identity>,
+            SetColorPaletteIndex: SetColorPaletteIndex::<Identity>,
+            SetCustomColorPalette: SetCustomColorPalette::<Identity>,
+            MoveToFirstChild: MoveToFirstChild::<Identity>,
+            MoveToNextSibling: MoveToNextSibling::<Identity>,
+            MoveToParent: MoveToParent::<Identity>,
+            GetGradientStops: GetGradientStops::<Identity>,
+            GetGradientStopColors: GetGradientStopColors::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
 impl windows_core::RuntimeName for IDWritePaintReader {}
 windows_core::imp::define_interface!(IDWritePixelSnapping, IDWritePixelSnapping_Vtbl, 0xeaf3a2da_ecf4_4d24_b644_b34f6842024b);
 windows_core::imp::interface_hierarchy!(IDWritePixelSnapping, windows_core::IUnknown);
@@ -8734,6 +12345,49 @@ impl IDWritePixelSnapping_Vtbl {
         iid == &<IDWritePixelSnapping as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWritePixelSnapping_Impl> windows_core::imp::TearOffVtable<Identity> for IDWritePixelSnapping_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsPixelSnappingDisabled<Identity: windows_core::IUnknownImpl + IDWritePixelSnapping_Impl>(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, isdisabled: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWritePixelSnapping_Impl::IsPixelSnappingDisabled(this, core::mem::transmute_copy(&clientdrawingcontext)) {
+                    Ok(ok__) => {
+                        isdisabled.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCurrentTransform<Identity: windows_core::IUnknownImpl + IDWritePixelSnapping_Impl>(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, transform: *mut DWRITE_MATRIX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWritePixelSnapping_Impl::GetCurrentTransform(this, core::mem::transmute_copy(&clientdrawingcontext), core::mem::transmute_copy(&transform)).into()
+            }
+        }
+        unsafe extern "system" fn GetPixelsPerDip<Identity: windows_core::IUnknownImpl + IDWritePixelSnapping_Impl>(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, pixelsperdip: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWritePixelSnapping_Impl::GetPixelsPerDip(this, core::mem::transmute_copy(&clientdrawingcontext)) {
+                    Ok(ok__) => {
+                        pixelsperdip.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsPixelSnappingDisabled: IsPixelSnappingDisabled::<Identity>,
+            GetCurrentTransform: GetCurrentTransform::<Identity>,
+            GetPixelsPerDip: GetPixelsPerDip::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWritePixelSnapping {}
 windows_core::imp::define_interface!(IDWriteRemoteFontFileLoader, IDWriteRemoteFontFileLoader_Vtbl, 0x68648c83_6ede_46c0_ab46_20083a887fde);
 impl core::ops::Deref for IDWriteRemoteFontFileLoader {
@@ -8832,6 +12486,55 @@ impl IDWriteRemoteFontFileLoader_Vtbl {
         iid == &<IDWriteRemoteFontFileLoader as windows_core::Interface>::IID || iid == &<IDWriteFontFileLoader as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteRemoteFontFileLoader_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteRemoteFontFileLoader_Vtbl
+where
+    IDWriteFontFileLoader_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateRemoteStreamFromKey<Identity: windows_core::IUnknownImpl + IDWriteRemoteFontFileLoader_Impl>(this: *mut core::ffi::c_void, fontfilereferencekey: *const core::ffi::c_void, fontfilereferencekeysize: u32, fontfilestream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteRemoteFontFileLoader_Impl::CreateRemoteStreamFromKey(this, core::mem::transmute_copy(&fontfilereferencekey), core::mem::transmute_copy(&fontfilereferencekeysize)) {
+                    Ok(ok__) => {
+                        fontfilestream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLocalityFromKey<Identity: windows_core::IUnknownImpl + IDWriteRemoteFontFileLoader_Impl>(this: *mut core::ffi::c_void, fontfilereferencekey: *const core::ffi::c_void, fontfilereferencekeysize: u32, locality: *mut DWRITE_LOCALITY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteRemoteFontFileLoader_Impl::GetLocalityFromKey(this, core::mem::transmute_copy(&fontfilereferencekey), core::mem::transmute_copy(&fontfilereferencekeysize)) {
+                    Ok(ok__) => {
+                        locality.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateFontFileReferenceFromUrl<Identity: windows_core::IUnknownImpl + IDWriteRemoteFontFileLoader_Impl>(this: *mut core::ffi::c_void, factory: *mut core::ffi::c_void, baseurl: windows_core::PCWSTR, fontfileurl: windows_core::PCWSTR, fontfile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteRemoteFontFileLoader_Impl::CreateFontFileReferenceFromUrl(this, core::mem::transmute_copy(&factory), core::mem::transmute(&baseurl), core::mem::transmute(&fontfileurl)) {
+                    Ok(ok__) => {
+                        fontfile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDWriteFontFileLoader_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateRemoteStreamFromKey: CreateRemoteStreamFromKey::<Identity>,
+            GetLocalityFromKey: GetLocalityFromKey::<Identity>,
+            CreateFontFileReferenceFromUrl: CreateFontFileReferenceFromUrl::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteRemoteFontFileLoader {}
 windows_core::imp::define_interface!(IDWriteRemoteFontFileStream, IDWriteRemoteFontFileStream_Vtbl, 0x4db3757a_2c72_4ed9_b2b6_1ababe1aff9c);
 impl core::ops::Deref for IDWriteRemoteFontFileStream {
@@ -8928,6 +12631,56 @@ impl IDWriteRemoteFontFileStream_Vtbl {
         iid == &<IDWriteRemoteFontFileStream as windows_core::Interface>::IID || iid == &<IDWriteFontFileStream as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteRemoteFontFileStream_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteRemoteFontFileStream_Vtbl
+where
+    IDWriteFontFileStream_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetLocalFileSize<Identity: windows_core::IUnknownImpl + IDWriteRemoteFontFileStream_Impl>(this: *mut core::ffi::c_void, localfilesize: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteRemoteFontFileStream_Impl::GetLocalFileSize(this) {
+                    Ok(ok__) => {
+                        localfilesize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFileFragmentLocality<Identity: windows_core::IUnknownImpl + IDWriteRemoteFontFileStream_Impl>(this: *mut core::ffi::c_void, fileoffset: u64, fragmentsize: u64, islocal: *mut windows_core::BOOL, partialsize: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteRemoteFontFileStream_Impl::GetFileFragmentLocality(this, core::mem::transmute_copy(&fileoffset), core::mem::transmute_copy(&fragmentsize), core::mem::transmute_copy(&islocal), core::mem::transmute_copy(&partialsize)).into()
+            }
+        }
+        unsafe extern "system" fn GetLocality<Identity: windows_core::IUnknownImpl + IDWriteRemoteFontFileStream_Impl>(this: *mut core::ffi::c_void) -> DWRITE_LOCALITY {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteRemoteFontFileStream_Impl::GetLocality(this)
+            }
+        }
+        unsafe extern "system" fn BeginDownload<Identity: windows_core::IUnknownImpl + IDWriteRemoteFontFileStream_Impl>(this: *mut core::ffi::c_void, downloadoperationid: *const windows_core::GUID, filefragments: *const DWRITE_FILE_FRAGMENT, fragmentcount: u32, asyncresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteRemoteFontFileStream_Impl::BeginDownload(this, core::mem::transmute_copy(&downloadoperationid), core::mem::transmute_copy(&filefragments), core::mem::transmute_copy(&fragmentcount)) {
+                    Ok(ok__) => {
+                        asyncresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDWriteFontFileStream_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetLocalFileSize: GetLocalFileSize::<Identity>,
+            GetFileFragmentLocality: GetFileFragmentLocality::<Identity>,
+            GetLocality: GetLocality::<Identity>,
+            BeginDownload: BeginDownload::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteRemoteFontFileStream {}
 windows_core::imp::define_interface!(IDWriteRenderingParams, IDWriteRenderingParams_Vtbl, 0x2f0da53a_2add_47cd_82ee_d9ec34688e75);
 windows_core::imp::interface_hierarchy!(IDWriteRenderingParams, windows_core::IUnknown);
@@ -9012,6 +12765,51 @@ impl IDWriteRenderingParams_Vtbl {
         iid == &<IDWriteRenderingParams as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteRenderingParams_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteRenderingParams_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetGamma<Identity: windows_core::IUnknownImpl + IDWriteRenderingParams_Impl>(this: *mut core::ffi::c_void) -> f32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteRenderingParams_Impl::GetGamma(this)
+            }
+        }
+        unsafe extern "system" fn GetEnhancedContrast<Identity: windows_core::IUnknownImpl + IDWriteRenderingParams_Impl>(this: *mut core::ffi::c_void) -> f32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteRenderingParams_Impl::GetEnhancedContrast(this)
+            }
+        }
+        unsafe extern "system" fn GetClearTypeLevel<Identity: windows_core::IUnknownImpl + IDWriteRenderingParams_Impl>(this: *mut core::ffi::c_void) -> f32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteRenderingParams_Impl::GetClearTypeLevel(this)
+            }
+        }
+        unsafe extern "system" fn GetPixelGeometry<Identity: windows_core::IUnknownImpl + IDWriteRenderingParams_Impl>(this: *mut core::ffi::c_void) -> DWRITE_PIXEL_GEOMETRY {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteRenderingParams_Impl::GetPixelGeometry(this)
+            }
+        }
+        unsafe extern "system" fn GetRenderingMode<Identity: windows_core::IUnknownImpl + IDWriteRenderingParams_Impl>(this: *mut core::ffi::c_void) -> DWRITE_RENDERING_MODE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteRenderingParams_Impl::GetRenderingMode(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetGamma: GetGamma::<Identity>,
+            GetEnhancedContrast: GetEnhancedContrast::<Identity>,
+            GetClearTypeLevel: GetClearTypeLevel::<Identity>,
+            GetPixelGeometry: GetPixelGeometry::<Identity>,
+            GetRenderingMode: GetRenderingMode::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteRenderingParams {}
 windows_core::imp::define_interface!(IDWriteRenderingParams1, IDWriteRenderingParams1_Vtbl, 0x94413cf4_a6fc_4248_8b50_6674348fcad3);
 impl core::ops::Deref for IDWriteRenderingParams1 {
@@ -9051,6 +12849,23 @@ impl IDWriteRenderingParams1_Vtbl {
         iid == &<IDWriteRenderingParams1 as windows_core::Interface>::IID || iid == &<IDWriteRenderingParams as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteRenderingParams1_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteRenderingParams1_Vtbl
+where
+    IDWriteRenderingParams_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetGrayscaleEnhancedContrast<Identity: windows_core::IUnknownImpl + IDWriteRenderingParams1_Impl>(this: *mut core::ffi::c_void) -> f32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteRenderingParams1_Impl::GetGrayscaleEnhancedContrast(this)
+            }
+        }
+        Self {
+            base__: <IDWriteRenderingParams_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetGrayscaleEnhancedContrast: GetGrayscaleEnhancedContrast::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteRenderingParams1 {}
 windows_core::imp::define_interface!(IDWriteRenderingParams2, IDWriteRenderingParams2_Vtbl, 0xf9d711c3_9777_40ae_87e8_3e5af9bf0948);
 impl core::ops::Deref for IDWriteRenderingParams2 {
@@ -9090,6 +12905,20 @@ impl IDWriteRenderingParams2_Vtbl {
         iid == &<IDWriteRenderingParams2 as windows_core::Interface>::IID || iid == &<IDWriteRenderingParams as windows_core::Interface>::IID || iid == &<IDWriteRenderingParams1 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteRenderingParams2_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteRenderingParams2_Vtbl
+where
+    IDWriteRenderingParams1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetGridFitMode<Identity: windows_core::IUnknownImpl + IDWriteRenderingParams2_Impl>(this: *mut core::ffi::c_void) -> DWRITE_GRID_FIT_MODE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteRenderingParams2_Impl::GetGridFitMode(this)
+            }
+        }
+        Self { base__: <IDWriteRenderingParams1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetGridFitMode: GetGridFitMode::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDWriteRenderingParams2 {}
 windows_core::imp::define_interface!(IDWriteRenderingParams3, IDWriteRenderingParams3_Vtbl, 0xb7924baa_391b_412a_8c5c_e44cc2d867dc);
 impl core::ops::Deref for IDWriteRenderingParams3 {
@@ -9129,6 +12958,20 @@ impl IDWriteRenderingParams3_Vtbl {
         iid == &<IDWriteRenderingParams3 as windows_core::Interface>::IID || iid == &<IDWriteRenderingParams as windows_core::Interface>::IID || iid == &<IDWriteRenderingParams1 as windows_core::Interface>::IID || iid == &<IDWriteRenderingParams2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteRenderingParams3_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteRenderingParams3_Vtbl
+where
+    IDWriteRenderingParams2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRenderingMode1<Identity: windows_core::IUnknownImpl + IDWriteRenderingParams3_Impl>(this: *mut core::ffi::c_void) -> DWRITE_RENDERING_MODE1 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteRenderingParams3_Impl::GetRenderingMode1(this)
+            }
+        }
+        Self { base__: <IDWriteRenderingParams2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetRenderingMode1: GetRenderingMode1::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDWriteRenderingParams3 {}
 windows_core::imp::define_interface!(IDWriteStringList, IDWriteStringList_Vtbl, 0xcfee3140_1157_47ca_8b85_31bfcf3f2d0e);
 windows_core::imp::interface_hierarchy!(IDWriteStringList, windows_core::IUnknown);
@@ -9231,6 +13074,63 @@ impl IDWriteStringList_Vtbl {
         iid == &<IDWriteStringList as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteStringList_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteStringList_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IDWriteStringList_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteStringList_Impl::GetCount(this)
+            }
+        }
+        unsafe extern "system" fn GetLocaleNameLength<Identity: windows_core::IUnknownImpl + IDWriteStringList_Impl>(this: *mut core::ffi::c_void, listindex: u32, length: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteStringList_Impl::GetLocaleNameLength(this, core::mem::transmute_copy(&listindex)) {
+                    Ok(ok__) => {
+                        length.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLocaleName<Identity: windows_core::IUnknownImpl + IDWriteStringList_Impl>(this: *mut core::ffi::c_void, listindex: u32, localename: windows_core::PWSTR, size: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteStringList_Impl::GetLocaleName(this, core::mem::transmute_copy(&listindex), core::mem::transmute_copy(&localename), core::mem::transmute_copy(&size)).into()
+            }
+        }
+        unsafe extern "system" fn GetStringLength<Identity: windows_core::IUnknownImpl + IDWriteStringList_Impl>(this: *mut core::ffi::c_void, listindex: u32, length: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteStringList_Impl::GetStringLength(this, core::mem::transmute_copy(&listindex)) {
+                    Ok(ok__) => {
+                        length.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetString<Identity: windows_core::IUnknownImpl + IDWriteStringList_Impl>(this: *mut core::ffi::c_void, listindex: u32, stringbuffer: windows_core::PWSTR, stringbuffersize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteStringList_Impl::GetString(this, core::mem::transmute_copy(&listindex), core::mem::transmute_copy(&stringbuffer), core::mem::transmute_copy(&stringbuffersize)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCount: GetCount::<Identity>,
+            GetLocaleNameLength: GetLocaleNameLength::<Identity>,
+            GetLocaleName: GetLocaleName::<Identity>,
+            GetStringLength: GetStringLength::<Identity>,
+            GetString: GetString::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteStringList {}
 windows_core::imp::define_interface!(IDWriteTextAnalysisSink, IDWriteTextAnalysisSink_Vtbl, 0x5810cd44_0ca0_4701_b3fa_bec5182ae4f6);
 windows_core::imp::interface_hierarchy!(IDWriteTextAnalysisSink, windows_core::IUnknown);
@@ -9306,6 +13206,44 @@ impl IDWriteTextAnalysisSink_Vtbl {
         iid == &<IDWriteTextAnalysisSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteTextAnalysisSink_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteTextAnalysisSink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetScriptAnalysis<Identity: windows_core::IUnknownImpl + IDWriteTextAnalysisSink_Impl>(this: *mut core::ffi::c_void, textposition: u32, textlength: u32, scriptanalysis: *const DWRITE_SCRIPT_ANALYSIS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextAnalysisSink_Impl::SetScriptAnalysis(this, core::mem::transmute_copy(&textposition), core::mem::transmute_copy(&textlength), core::mem::transmute_copy(&scriptanalysis)).into()
+            }
+        }
+        unsafe extern "system" fn SetLineBreakpoints<Identity: windows_core::IUnknownImpl + IDWriteTextAnalysisSink_Impl>(this: *mut core::ffi::c_void, textposition: u32, textlength: u32, linebreakpoints: *const DWRITE_LINE_BREAKPOINT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextAnalysisSink_Impl::SetLineBreakpoints(this, core::mem::transmute_copy(&textposition), core::mem::transmute_copy(&textlength), core::mem::transmute_copy(&linebreakpoints)).into()
+            }
+        }
+        unsafe extern "system" fn SetBidiLevel<Identity: windows_core::IUnknownImpl + IDWriteTextAnalysisSink_Impl>(this: *mut core::ffi::c_void, textposition: u32, textlength: u32, explicitlevel: u8, resolvedlevel: u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextAnalysisSink_Impl::SetBidiLevel(this, core::mem::transmute_copy(&textposition), core::mem::transmute_copy(&textlength), core::mem::transmute_copy(&explicitlevel), core::mem::transmute_copy(&resolvedlevel)).into()
+            }
+        }
+        unsafe extern "system" fn SetNumberSubstitution<Identity: windows_core::IUnknownImpl + IDWriteTextAnalysisSink_Impl>(this: *mut core::ffi::c_void, textposition: u32, textlength: u32, numbersubstitution: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextAnalysisSink_Impl::SetNumberSubstitution(this, core::mem::transmute_copy(&textposition), core::mem::transmute_copy(&textlength), core::mem::transmute_copy(&numbersubstitution)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetScriptAnalysis: SetScriptAnalysis::<Identity>,
+            SetLineBreakpoints: SetLineBreakpoints::<Identity>,
+            SetBidiLevel: SetBidiLevel::<Identity>,
+            SetNumberSubstitution: SetNumberSubstitution::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteTextAnalysisSink {}
 windows_core::imp::define_interface!(IDWriteTextAnalysisSink1, IDWriteTextAnalysisSink1_Vtbl, 0xb0d941a0_85e7_4d8b_9fd3_5ced9934482a);
 impl core::ops::Deref for IDWriteTextAnalysisSink1 {
@@ -9345,6 +13283,23 @@ impl IDWriteTextAnalysisSink1_Vtbl {
         iid == &<IDWriteTextAnalysisSink1 as windows_core::Interface>::IID || iid == &<IDWriteTextAnalysisSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteTextAnalysisSink1_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteTextAnalysisSink1_Vtbl
+where
+    IDWriteTextAnalysisSink_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetGlyphOrientation<Identity: windows_core::IUnknownImpl + IDWriteTextAnalysisSink1_Impl>(this: *mut core::ffi::c_void, textposition: u32, textlength: u32, glyphorientationangle: DWRITE_GLYPH_ORIENTATION_ANGLE, adjustedbidilevel: u8, issideways: windows_core::BOOL, isrighttoleft: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextAnalysisSink1_Impl::SetGlyphOrientation(this, core::mem::transmute_copy(&textposition), core::mem::transmute_copy(&textlength), core::mem::transmute_copy(&glyphorientationangle), core::mem::transmute_copy(&adjustedbidilevel), core::mem::transmute_copy(&issideways), core::mem::transmute_copy(&isrighttoleft)).into()
+            }
+        }
+        Self {
+            base__: <IDWriteTextAnalysisSink_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetGlyphOrientation: SetGlyphOrientation::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteTextAnalysisSink1 {}
 windows_core::imp::define_interface!(IDWriteTextAnalysisSource, IDWriteTextAnalysisSource_Vtbl, 0x688e1a58_5094_47c8_adc8_fbcea60ae92b);
 windows_core::imp::interface_hierarchy!(IDWriteTextAnalysisSource, windows_core::IUnknown);
@@ -9429,6 +13384,51 @@ impl IDWriteTextAnalysisSource_Vtbl {
         iid == &<IDWriteTextAnalysisSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteTextAnalysisSource_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteTextAnalysisSource_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetTextAtPosition<Identity: windows_core::IUnknownImpl + IDWriteTextAnalysisSource_Impl>(this: *mut core::ffi::c_void, textposition: u32, textstring: *mut *mut u16, textlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextAnalysisSource_Impl::GetTextAtPosition(this, core::mem::transmute_copy(&textposition), core::mem::transmute_copy(&textstring), core::mem::transmute_copy(&textlength)).into()
+            }
+        }
+        unsafe extern "system" fn GetTextBeforePosition<Identity: windows_core::IUnknownImpl + IDWriteTextAnalysisSource_Impl>(this: *mut core::ffi::c_void, textposition: u32, textstring: *mut *mut u16, textlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextAnalysisSource_Impl::GetTextBeforePosition(this, core::mem::transmute_copy(&textposition), core::mem::transmute_copy(&textstring), core::mem::transmute_copy(&textlength)).into()
+            }
+        }
+        unsafe extern "system" fn GetParagraphReadingDirection<Identity: windows_core::IUnknownImpl + IDWriteTextAnalysisSource_Impl>(this: *mut core::ffi::c_void) -> DWRITE_READING_DIRECTION {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextAnalysisSource_Impl::GetParagraphReadingDirection(this)
+            }
+        }
+        unsafe extern "system" fn GetLocaleName<Identity: windows_core::IUnknownImpl + IDWriteTextAnalysisSource_Impl>(this: *mut core::ffi::c_void, textposition: u32, textlength: *mut u32, localename: *mut *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextAnalysisSource_Impl::GetLocaleName(this, core::mem::transmute_copy(&textposition), core::mem::transmute_copy(&textlength), core::mem::transmute_copy(&localename)).into()
+            }
+        }
+        unsafe extern "system" fn GetNumberSubstitution<Identity: windows_core::IUnknownImpl + IDWriteTextAnalysisSource_Impl>(this: *mut core::ffi::c_void, textposition: u32, textlength: *mut u32, numbersubstitution: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextAnalysisSource_Impl::GetNumberSubstitution(this, core::mem::transmute_copy(&textposition), core::mem::transmute_copy(&textlength), core::mem::transmute_copy(&numbersubstitution)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetTextAtPosition: GetTextAtPosition::<Identity>,
+            GetTextBeforePosition: GetTextBeforePosition::<Identity>,
+            GetParagraphReadingDirection: GetParagraphReadingDirection::<Identity>,
+            GetLocaleName: GetLocaleName::<Identity>,
+            GetNumberSubstitution: GetNumberSubstitution::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteTextAnalysisSource {}
 windows_core::imp::define_interface!(IDWriteTextAnalysisSource1, IDWriteTextAnalysisSource1_Vtbl, 0x639cfad8_0fb4_4b21_a58a_067920120009);
 impl core::ops::Deref for IDWriteTextAnalysisSource1 {
@@ -9468,6 +13468,23 @@ impl IDWriteTextAnalysisSource1_Vtbl {
         iid == &<IDWriteTextAnalysisSource1 as windows_core::Interface>::IID || iid == &<IDWriteTextAnalysisSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteTextAnalysisSource1_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteTextAnalysisSource1_Vtbl
+where
+    IDWriteTextAnalysisSource_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetVerticalGlyphOrientation<Identity: windows_core::IUnknownImpl + IDWriteTextAnalysisSource1_Impl>(this: *mut core::ffi::c_void, textposition: u32, textlength: *mut u32, glyphorientation: *mut DWRITE_VERTICAL_GLYPH_ORIENTATION, bidilevel: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextAnalysisSource1_Impl::GetVerticalGlyphOrientation(this, core::mem::transmute_copy(&textposition), core::mem::transmute_copy(&textlength), core::mem::transmute_copy(&glyphorientation), core::mem::transmute_copy(&bidilevel)).into()
+            }
+        }
+        Self {
+            base__: <IDWriteTextAnalysisSource_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetVerticalGlyphOrientation: GetVerticalGlyphOrientation::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteTextAnalysisSource1 {}
 windows_core::imp::define_interface!(IDWriteTextAnalyzer, IDWriteTextAnalyzer_Vtbl, 0xb7e6163e_7f46_43b4_84b3_e4e6249c365d);
 windows_core::imp::interface_hierarchy!(IDWriteTextAnalyzer, windows_core::IUnknown);
@@ -9722,6 +13739,153 @@ impl IDWriteTextAnalyzer_Vtbl {
         iid == &<IDWriteTextAnalyzer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteTextAnalyzer_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteTextAnalyzer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AnalyzeScript<Identity: windows_core::IUnknownImpl + IDWriteTextAnalyzer_Impl>(this: *mut core::ffi::c_void, analysissource: *mut core::ffi::c_void, textposition: u32, textlength: u32, analysissink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextAnalyzer_Impl::AnalyzeScript(this, core::mem::transmute_copy(&analysissource), core::mem::transmute_copy(&textposition), core::mem::transmute_copy(&textlength), core::mem::transmute_copy(&analysissink)).into()
+            }
+        }
+        unsafe extern "system" fn AnalyzeBidi<Identity: windows_core::IUnknownImpl + IDWriteTextAnalyzer_Impl>(this: *mut core::ffi::c_void, analysissource: *mut core::ffi::c_void, textposition: u32, textlength: u32, analysissink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextAnalyzer_Impl::AnalyzeBidi(this, core::mem::transmute_copy(&analysissource), core::mem::transmute_copy(&textposition), core::mem::transmute_copy(&textlength), core::mem::transmute_copy(&analysissink)).into()
+            }
+        }
+        unsafe extern "system" fn AnalyzeNumberSubstitution<Identity: windows_core::IUnknownImpl + IDWriteTextAnalyzer_Impl>(this: *mut core::ffi::c_void, analysissource: *mut core::ffi::c_void, textposition: u32, textlength: u32, analysissink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextAnalyzer_Impl::AnalyzeNumberSubstitution(this, core::mem::transmute_copy(&analysissource), core::mem::transmute_copy(&textposition), core::mem::transmute_copy(&textlength), core::mem::transmute_copy(&analysissink)).into()
+            }
+        }
+        unsafe extern "system" fn AnalyzeLineBreakpoints<Identity: windows_core::IUnknownImpl + IDWriteTextAnalyzer_Impl>(this: *mut core::ffi::c_void, analysissource: *mut core::ffi::c_void, textposition: u32, textlength: u32, analysissink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextAnalyzer_Impl::AnalyzeLineBreakpoints(this, core::mem::transmute_copy(&analysissource), core::mem::transmute_copy(&textposition), core::mem::transmute_copy(&textlength), core::mem::transmute_copy(&analysissink)).into()
+            }
+        }
+        unsafe extern "system" fn GetGlyphs<Identity: windows_core::IUnknownImpl + IDWriteTextAnalyzer_Impl>(this: *mut core::ffi::c_void, textstring: windows_core::PCWSTR, textlength: u32, fontface: *mut core::ffi::c_void, issideways: windows_core::BOOL, isrighttoleft: windows_core::BOOL, scriptanalysis: *const DWRITE_SCRIPT_ANALYSIS, localename: windows_core::PCWSTR, numbersubstitution: *mut core::ffi::c_void, features: *const *const DWRITE_TYPOGRAPHIC_FEATURES, featurerangelengths: *const u32, featureranges: u32, maxglyphcount: u32, clustermap: *mut u16, textprops: *mut DWRITE_SHAPING_TEXT_PROPERTIES, glyphindices: *mut u16, glyphprops: *mut DWRITE_SHAPING_GLYPH_PROPERTIES, actualglyphcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextAnalyzer_Impl::GetGlyphs(
+                    this,
+                    core::mem::transmute(&textstring),
+                    core::mem::transmute_copy(&textlength),
+                    core::mem::transmute_copy(&fontface),
+                    core::mem::transmute_copy(&issideways),
+                    core::mem::transmute_copy(&isrighttoleft),
+                    core::mem::transmute_copy(&scriptanalysis),
+                    core::mem::transmute(&localename),
+                    core::mem::transmute_copy(&numbersubstitution),
+                    core::mem::transmute_copy(&features),
+                    core::mem::transmute_copy(&featurerangelengths),
+                    core::mem::transmute_copy(&featureranges),
+                    core::mem::transmute_copy(&maxglyphcount),
+                    core::mem::transmute_copy(&clustermap),
+                    core::mem::transmute_copy(&textprops),
+                    core::mem::transmute_copy(&glyphindices),
+                    core::mem::transmute_copy(&glyphprops),
+                    core::mem::transmute_copy(&actualglyphcount),
+                )
+                .into()
+            }
+        }
+        unsafe extern "system" fn GetGlyphPlacements<Identity: windows_core::IUnknownImpl + IDWriteTextAnalyzer_Impl>(this: *mut core::ffi::c_void, textstring: windows_core::PCWSTR, clustermap: *const u16, textprops: *mut DWRITE_SHAPING_TEXT_PROPERTIES, textlength: u32, glyphindices: *const u16, glyphprops: *const DWRITE_SHAPING_GLYPH_PROPERTIES, glyphcount: u32, fontface: *mut core::ffi::c_void, fontemsize: f32, issideways: windows_core::BOOL, isrighttoleft: windows_core::BOOL, scriptanalysis: *const DWRITE_SCRIPT_ANALYSIS, localename: windows_core::PCWSTR, features: *const *const DWRITE_TYPOGRAPHIC_FEATURES, featurerangelengths: *const u32, featureranges: u32, glyphadvances: *mut f32, glyphoffsets: *mut DWRITE_GLYPH_OFFSET) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextAnalyzer_Impl::GetGlyphPlacements(
+                    this,
+                    core::mem::transmute(&textstring),
+                    core::mem::transmute_copy(&clustermap),
+                    core::mem::transmute_copy(&textprops),
+                    core::mem::transmute_copy(&textlength),
+                    core::mem::transmute_copy(&glyphindices),
+                    core::mem::transmute_copy(&glyphprops),
+                    core::mem::transmute_copy(&glyphcount),
+                    core::mem::transmute_copy(&fontface),
+                    core::mem::transmute_copy(&fontemsize),
+                    core::mem::transmute_copy(&issideways),
+                    core::mem::transmute_copy(&isrighttoleft),
+                    core::mem::transmute_copy(&scriptanalysis),
+                    core::mem::transmute(&localename),
+                    core::mem::transmute_copy(&features),
+                    core::mem::transmute_copy(&featurerangelengths),
+                    core::mem::transmute_copy(&featureranges),
+                    core::mem::transmute_copy(&glyphadvances),
+                    core::mem::transmute_copy(&glyphoffsets),
+                )
+                .into()
+            }
+        }
+        unsafe extern "system" fn GetGdiCompatibleGlyphPlacements<Identity: windows_core::IUnknownImpl + IDWriteTextAnalyzer_Impl>(
+            this: *mut core::ffi::c_void,
+            textstring: windows_core::PCWSTR,
+            clustermap: *const u16,
+            textprops: *const DWRITE_SHAPING_TEXT_PROPERTIES,
+            textlength: u32,
+            glyphindices: *const u16,
+            glyphprops: *const DWRITE_SHAPING_GLYPH_PROPERTIES,
+            glyphcount: u32,
+            fontface: *mut core::ffi::c_void,
+            fontemsize: f32,
+            pixelsperdip: f32,
+            transform: *const DWRITE_MATRIX,
+            usegdinatural: windows_core::BOOL,
+            issideways: windows_core::BOOL,
+            isrighttoleft: windows_core::BOOL,
+            scriptanalysis: *const DWRITE_SCRIPT_ANALYSIS,
+            localename: windows_core::PCWSTR,
+            features: *const *const DWRITE_TYPOGRAPHIC_FEATURES,
+            featurerangelengths: *const u32,
+            featureranges: u32,
+            glyphadvances: *mut f32,
+            glyphoffsets: *mut DWRITE_GLYPH_OFFSET,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextAnalyzer_Impl::GetGdiCompatibleGlyphPlacements(
+                    this,
+                    core::mem::transmute(&textstring),
+                    core::mem::transmute_copy(&clustermap),
+                    core::mem::transmute_copy(&textprops),
+                    core::mem::transmute_copy(&textlength),
+                    core::mem::transmute_copy(&glyphindices),
+                    core::mem::transmute_copy(&glyphprops),
+                    core::mem::transmute_copy(&glyphcount),
+                    core::mem::transmute_copy(&fontface),
+                    core::mem::transmute_copy(&fontemsize),
+                    core::mem::transmute_copy(&pixelsperdip),
+                    core::mem::transmute_copy(&transform),
+                    core::mem::transmute_copy(&usegdinatural),
+                    core::mem::transmute_copy(&issideways),
+                    core::mem::transmute_copy(&isrighttoleft),
+                    core::mem::transmute_copy(&scriptanalysis),
+                    core::mem::transmute(&localename),
+                    core::mem::transmute_copy(&features),
+                    core::mem::transmute_copy(&featurerangelengths),
+                    core::mem::transmute_copy(&featureranges),
+                    core::mem::transmute_copy(&glyphadvances),
+                    core::mem::transmute_copy(&glyphoffsets),
+                )
+                .into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AnalyzeScript: AnalyzeScript::<Identity>,
+            AnalyzeBidi: AnalyzeBidi::<Identity>,
+            AnalyzeNumberSubstitution: AnalyzeNumberSubstitution::<Identity>,
+            AnalyzeLineBreakpoints: AnalyzeLineBreakpoints::<Identity>,
+            GetGlyphs: GetGlyphs::<Identity>,
+            GetGlyphPlacements: GetGlyphPlacements::<Identity>,
+            GetGdiCompatibleGlyphPlacements: GetGdiCompatibleGlyphPlacements::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteTextAnalyzer {}
 windows_core::imp::define_interface!(IDWriteTextAnalyzer1, IDWriteTextAnalyzer1_Vtbl, 0x80dad800_e21f_4e83_96ce_bfcce500db7c);
 impl core::ops::Deref for IDWriteTextAnalyzer1 {
@@ -9806,11 +13970,121 @@ pub trait IDWriteTextAnalyzer1_Impl: IDWriteTextAnalyzer_Impl {
     fn JustifyGlyphAdvances(&self, linewidth: f32, glyphcount: u32, justificationopportunities: *const DWRITE_JUSTIFICATION_OPPORTUNITY, glyphadvances: *const f32, glyphoffsets: *const DWRITE_GLYPH_OFFSET, justifiedglyphadvances: *mut f32, justifiedglyphoffsets: *mut DWRITE_GLYPH_OFFSET) -> windows_core::Result<()>;
     fn GetJustifiedGlyphs(&self, fontface: windows_core::Ref<IDWriteFontFace>, fontemsize: f32, scriptanalysis: &DWRITE_SCRIPT_ANALYSIS, textlength: u32, glyphcount: u32, maxglyphcount: u32, clustermap: *const u16, glyphindices: *const u16, glyphadvances: *const f32, justifiedglyphadvances: *const f32, justifiedglyphoffsets: *const DWRITE_GLYPH_OFFSET, glyphproperties: *const DWRITE_SHAPING_GLYPH_PROPERTIES, actualglyphcount: *mut u32, modifiedclustermap: *mut u16, modifiedglyphindices: *mut u16, modifiedglyphadvances: *mut f32, modifiedglyphoffsets: *mut DWRITE_GLYPH_OFFSET) -> windows_core::Result<()>;
 }
-impl IDWriteTextAnalyzer1_Vtbl {
-    pub const fn new<Identity: IDWriteTextAnalyzer1_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn ApplyCharacterSpacing<Identity: IDWriteTextAnalyzer1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, leadingspacing: f32, trailingspacing: f32, minimumadvancewidth: f32, textlength: u32, glyphcount: u32, clustermap: *const u16, glyphadvances: *const f32, glyphoffsets: *const DWRITE_GLYPH_OFFSET, glyphproperties: *const DWRITE_SHAPING_GLYPH_PROPERTIES, modifiedglyphadvances: *mut f32, modifiedglyphoffsets: *mut DWRITE_GLYPH_OFFSET) -> windows_core::HRESULT {
+impl IDWriteTextAnalyzer1_Vtbl {
+    pub const fn new<Identity: IDWriteTextAnalyzer1_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn ApplyCharacterSpacing<Identity: IDWriteTextAnalyzer1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, leadingspacing: f32, trailingspacing: f32, minimumadvancewidth: f32, textlength: u32, glyphcount: u32, clustermap: *const u16, glyphadvances: *const f32, glyphoffsets: *const DWRITE_GLYPH_OFFSET, glyphproperties: *const DWRITE_SHAPING_GLYPH_PROPERTIES, modifiedglyphadvances: *mut f32, modifiedglyphoffsets: *mut DWRITE_GLYPH_OFFSET) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextAnalyzer1_Impl::ApplyCharacterSpacing(
+                    this,
+                    core::mem::transmute_copy(&leadingspacing),
+                    core::mem::transmute_copy(&trailingspacing),
+                    core::mem::transmute_copy(&minimumadvancewidth),
+                    core::mem::transmute_copy(&textlength),
+                    core::mem::transmute_copy(&glyphcount),
+                    core::mem::transmute_copy(&clustermap),
+                    core::mem::transmute_copy(&glyphadvances),
+                    core::mem::transmute_copy(&glyphoffsets),
+                    core::mem::transmute_copy(&glyphproperties),
+                    core::mem::transmute_copy(&modifiedglyphadvances),
+                    core::mem::transmute_copy(&modifiedglyphoffsets),
+                )
+                .into()
+            }
+        }
+        unsafe extern "system" fn GetBaseline<Identity: IDWriteTextAnalyzer1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, baseline: DWRITE_BASELINE, isvertical: windows_core::BOOL, issimulationallowed: windows_core::BOOL, scriptanalysis: DWRITE_SCRIPT_ANALYSIS, localename: windows_core::PCWSTR, baselinecoordinate: *mut i32, exists: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextAnalyzer1_Impl::GetBaseline(this, core::mem::transmute_copy(&fontface), core::mem::transmute_copy(&baseline), core::mem::transmute_copy(&isvertical), core::mem::transmute_copy(&issimulationallowed), core::mem::transmute(&scriptanalysis), core::mem::transmute(&localename), core::mem::transmute_copy(&baselinecoordinate), core::mem::transmute_copy(&exists)).into()
+            }
+        }
+        unsafe extern "system" fn AnalyzeVerticalGlyphOrientation<Identity: IDWriteTextAnalyzer1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, analysissource: *mut core::ffi::c_void, textposition: u32, textlength: u32, analysissink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextAnalyzer1_Impl::AnalyzeVerticalGlyphOrientation(this, core::mem::transmute_copy(&analysissource), core::mem::transmute_copy(&textposition), core::mem::transmute_copy(&textlength), core::mem::transmute_copy(&analysissink)).into()
+            }
+        }
+        unsafe extern "system" fn GetGlyphOrientationTransform<Identity: IDWriteTextAnalyzer1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, glyphorientationangle: DWRITE_GLYPH_ORIENTATION_ANGLE, issideways: windows_core::BOOL, transform: *mut DWRITE_MATRIX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextAnalyzer1_Impl::GetGlyphOrientationTransform(this, core::mem::transmute_copy(&glyphorientationangle), core::mem::transmute_copy(&issideways), core::mem::transmute_copy(&transform)).into()
+            }
+        }
+        unsafe extern "system" fn GetScriptProperties<Identity: IDWriteTextAnalyzer1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, scriptanalysis: DWRITE_SCRIPT_ANALYSIS, scriptproperties: *mut DWRITE_SCRIPT_PROPERTIES) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextAnalyzer1_Impl::GetScriptProperties(this, core::mem::transmute(&scriptanalysis), core::mem::transmute_copy(&scriptproperties)).into()
+            }
+        }
+        unsafe extern "system" fn GetTextComplexity<Identity: IDWriteTextAnalyzer1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, textstring: windows_core::PCWSTR, textlength: u32, fontface: *mut core::ffi::c_void, istextsimple: *mut windows_core::BOOL, textlengthread: *mut u32, glyphindices: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextAnalyzer1_Impl::GetTextComplexity(this, core::mem::transmute(&textstring), core::mem::transmute_copy(&textlength), core::mem::transmute_copy(&fontface), core::mem::transmute_copy(&istextsimple), core::mem::transmute_copy(&textlengthread), core::mem::transmute_copy(&glyphindices)).into()
+            }
+        }
+        unsafe extern "system" fn GetJustificationOpportunities<Identity: IDWriteTextAnalyzer1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, fontemsize: f32, scriptanalysis: DWRITE_SCRIPT_ANALYSIS, textlength: u32, glyphcount: u32, textstring: windows_core::PCWSTR, clustermap: *const u16, glyphproperties: *const DWRITE_SHAPING_GLYPH_PROPERTIES, justificationopportunities: *mut DWRITE_JUSTIFICATION_OPPORTUNITY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextAnalyzer1_Impl::GetJustificationOpportunities(this, core::mem::transmute_copy(&fontface), core::mem::transmute_copy(&fontemsize), core::mem::transmute(&scriptanalysis), core::mem::transmute_copy(&textlength), core::mem::transmute_copy(&glyphcount), core::mem::transmute(&textstring), core::mem::transmute_copy(&clustermap), core::mem::transmute_copy(&glyphproperties), core::mem::transmute_copy(&justificationopportunities)).into()
+            }
+        }
+        unsafe extern "system" fn JustifyGlyphAdvances<Identity: IDWriteTextAnalyzer1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, linewidth: f32, glyphcount: u32, justificationopportunities: *const DWRITE_JUSTIFICATION_OPPORTUNITY, glyphadvances: *const f32, glyphoffsets: *const DWRITE_GLYPH_OFFSET, justifiedglyphadvances: *mut f32, justifiedglyphoffsets: *mut DWRITE_GLYPH_OFFSET) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextAnalyzer1_Impl::JustifyGlyphAdvances(this, core::mem::transmute_copy(&linewidth), core::mem::transmute_copy(&glyphcount), core::mem::transmute_copy(&justificationopportunities), core::mem::transmute_copy(&glyphadvances), core::mem::transmute_copy(&glyphoffsets), core::mem::transmute_copy(&justifiedglyphadvances), core::mem::transmute_copy(&justifiedglyphoffsets)).into()
+            }
+        }
+        unsafe extern "system" fn GetJustifiedGlyphs<Identity: IDWriteTextAnalyzer1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, fontemsize: f32, scriptanalysis: DWRITE_SCRIPT_ANALYSIS, textlength: u32, glyphcount: u32, maxglyphcount: u32, clustermap: *const u16, glyphindices: *const u16, glyphadvances: *const f32, justifiedglyphadvances: *const f32, justifiedglyphoffsets: *const DWRITE_GLYPH_OFFSET, glyphproperties: *const DWRITE_SHAPING_GLYPH_PROPERTIES, actualglyphcount: *mut u32, modifiedclustermap: *mut u16, modifiedglyphindices: *mut u16, modifiedglyphadvances: *mut f32, modifiedglyphoffsets: *mut DWRITE_GLYPH_OFFSET) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextAnalyzer1_Impl::GetJustifiedGlyphs(
+                    this,
+                    core::mem::transmute_copy(&fontface),
+                    core::mem::transmute_copy(&fontemsize),
+                    core::mem::transmute(&scriptanalysis),
+                    core::mem::transmute_copy(&textlength),
+                    core::mem::transmute_copy(&glyphcount),
+                    core::mem::transmute_copy(&maxglyphcount),
+                    core::mem::transmute_copy(&clustermap),
+                    core::mem::transmute_copy(&glyphindices),
+                    core::mem::transmute_copy(&glyphadvances),
+                    core::mem::transmute_copy(&justifiedglyphadvances),
+                    core::mem::transmute_copy(&justifiedglyphoffsets),
+                    core::mem::transmute_copy(&glyphproperties),
+                    core::mem::transmute_copy(&actualglyphcount),
+                    core::mem::transmute_copy(&modifiedclustermap),
+                    core::mem::transmute_copy(&modifiedglyphindices),
+                    core::mem::transmute_copy(&modifiedglyphadvances),
+                    core::mem::transmute_copy(&modifiedglyphoffsets),
+                )
+                .into()
+            }
+        }
+        Self {
+            base__: IDWriteTextAnalyzer_Vtbl::new::<Identity, OFFSET>(),
+            ApplyCharacterSpacing: ApplyCharacterSpacing::<Identity, OFFSET>,
+            GetBaseline: GetBaseline::<Identity, OFFSET>,
+            AnalyzeVerticalGlyphOrientation: AnalyzeVerticalGlyphOrientation::<Identity, OFFSET>,
+            GetGlyphOrientationTransform: GetGlyphOrientationTransform::<Identity, OFFSET>,
+            GetScriptProperties: GetScriptProperties::<Identity, OFFSET>,
+            GetTextComplexity: GetTextComplexity::<Identity, OFFSET>,
+            GetJustificationOpportunities: GetJustificationOpportunities::<Identity, OFFSET>,
+            JustifyGlyphAdvances: JustifyGlyphAdvances::<Identity, OFFSET>,
+            GetJustifiedGlyphs: GetJustifiedGlyphs::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteTextAnalyzer1 as windows_core::Interface>::IID || iid == &<IDWriteTextAnalyzer as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IDWriteTextAnalyzer1_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteTextAnalyzer1_Vtbl
+where
+    IDWriteTextAnalyzer_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ApplyCharacterSpacing<Identity: windows_core::IUnknownImpl + IDWriteTextAnalyzer1_Impl>(this: *mut core::ffi::c_void, leadingspacing: f32, trailingspacing: f32, minimumadvancewidth: f32, textlength: u32, glyphcount: u32, clustermap: *const u16, glyphadvances: *const f32, glyphoffsets: *const DWRITE_GLYPH_OFFSET, glyphproperties: *const DWRITE_SHAPING_GLYPH_PROPERTIES, modifiedglyphadvances: *mut f32, modifiedglyphoffsets: *mut DWRITE_GLYPH_OFFSET) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextAnalyzer1_Impl::ApplyCharacterSpacing(
                     this,
                     core::mem::transmute_copy(&leadingspacing),
@@ -9828,51 +14102,51 @@ impl IDWriteTextAnalyzer1_Vtbl {
                 .into()
             }
         }
-        unsafe extern "system" fn GetBaseline<Identity: IDWriteTextAnalyzer1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, baseline: DWRITE_BASELINE, isvertical: windows_core::BOOL, issimulationallowed: windows_core::BOOL, scriptanalysis: DWRITE_SCRIPT_ANALYSIS, localename: windows_core::PCWSTR, baselinecoordinate: *mut i32, exists: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetBaseline<Identity: windows_core::IUnknownImpl + IDWriteTextAnalyzer1_Impl>(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, baseline: DWRITE_BASELINE, isvertical: windows_core::BOOL, issimulationallowed: windows_core::BOOL, scriptanalysis: DWRITE_SCRIPT_ANALYSIS, localename: windows_core::PCWSTR, baselinecoordinate: *mut i32, exists: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextAnalyzer1_Impl::GetBaseline(this, core::mem::transmute_copy(&fontface), core::mem::transmute_copy(&baseline), core::mem::transmute_copy(&isvertical), core::mem::transmute_copy(&issimulationallowed), core::mem::transmute(&scriptanalysis), core::mem::transmute(&localename), core::mem::transmute_copy(&baselinecoordinate), core::mem::transmute_copy(&exists)).into()
             }
         }
-        unsafe extern "system" fn AnalyzeVerticalGlyphOrientation<Identity: IDWriteTextAnalyzer1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, analysissource: *mut core::ffi::c_void, textposition: u32, textlength: u32, analysissink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn AnalyzeVerticalGlyphOrientation<Identity: windows_core::IUnknownImpl + IDWriteTextAnalyzer1_Impl>(this: *mut core::ffi::c_void, analysissource: *mut core::ffi::c_void, textposition: u32, textlength: u32, analysissink: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextAnalyzer1_Impl::AnalyzeVerticalGlyphOrientation(this, core::mem::transmute_copy(&analysissource), core::mem::transmute_copy(&textposition), core::mem::transmute_copy(&textlength), core::mem::transmute_copy(&analysissink)).into()
             }
         }
-        unsafe extern "system" fn GetGlyphOrientationTransform<Identity: IDWriteTextAnalyzer1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, glyphorientationangle: DWRITE_GLYPH_ORIENTATION_ANGLE, issideways: windows_core::BOOL, transform: *mut DWRITE_MATRIX) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetGlyphOrientationTransform<Identity: windows_core::IUnknownImpl + IDWriteTextAnalyzer1_Impl>(this: *mut core::ffi::c_void, glyphorientationangle: DWRITE_GLYPH_ORIENTATION_ANGLE, issideways: windows_core::BOOL, transform: *mut DWRITE_MATRIX) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextAnalyzer1_Impl::GetGlyphOrientationTransform(this, core::mem::transmute_copy(&glyphorientationangle), core::mem::transmute_copy(&issideways), core::mem::transmute_copy(&transform)).into()
             }
         }
-        unsafe extern "system" fn GetScriptProperties<Identity: IDWriteTextAnalyzer1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, scriptanalysis: DWRITE_SCRIPT_ANALYSIS, scriptproperties: *mut DWRITE_SCRIPT_PROPERTIES) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetScriptProperties<Identity: windows_core::IUnknownImpl + IDWriteTextAnalyzer1_Impl>(this: *mut core::ffi::c_void, scriptanalysis: DWRITE_SCRIPT_ANALYSIS, scriptproperties: *mut DWRITE_SCRIPT_PROPERTIES) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextAnalyzer1_Impl::GetScriptProperties(this, core::mem::transmute(&scriptanalysis), core::mem::transmute_copy(&scriptproperties)).into()
             }
         }
-        unsafe extern "system" fn GetTextComplexity<Identity: IDWriteTextAnalyzer1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, textstring: windows_core::PCWSTR, textlength: u32, fontface: *mut core::ffi::c_void, istextsimple: *mut windows_core::BOOL, textlengthread: *mut u32, glyphindices: *mut u16) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetTextComplexity<Identity: windows_core::IUnknownImpl + IDWriteTextAnalyzer1_Impl>(this: *mut core::ffi::c_void, textstring: windows_core::PCWSTR, textlength: u32, fontface: *mut core::ffi::c_void, istextsimple: *mut windows_core::BOOL, textlengthread: *mut u32, glyphindices: *mut u16) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextAnalyzer1_Impl::GetTextComplexity(this, core::mem::transmute(&textstring), core::mem::transmute_copy(&textlength), core::mem::transmute_copy(&fontface), core::mem::transmute_copy(&istextsimple), core::mem::transmute_copy(&textlengthread), core::mem::transmute_copy(&glyphindices)).into()
             }
         }
-        unsafe extern "system" fn GetJustificationOpportunities<Identity: IDWriteTextAnalyzer1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, fontemsize: f32, scriptanalysis: DWRITE_SCRIPT_ANALYSIS, textlength: u32, glyphcount: u32, textstring: windows_core::PCWSTR, clustermap: *const u16, glyphproperties: *const DWRITE_SHAPING_GLYPH_PROPERTIES, justificationopportunities: *mut DWRITE_JUSTIFICATION_OPPORTUNITY) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetJustificationOpportunities<Identity: windows_core::IUnknownImpl + IDWriteTextAnalyzer1_Impl>(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, fontemsize: f32, scriptanalysis: DWRITE_SCRIPT_ANALYSIS, textlength: u32, glyphcount: u32, textstring: windows_core::PCWSTR, clustermap: *const u16, glyphproperties: *const DWRITE_SHAPING_GLYPH_PROPERTIES, justificationopportunities: *mut DWRITE_JUSTIFICATION_OPPORTUNITY) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextAnalyzer1_Impl::GetJustificationOpportunities(this, core::mem::transmute_copy(&fontface), core::mem::transmute_copy(&fontemsize), core::mem::transmute(&scriptanalysis), core::mem::transmute_copy(&textlength), core::mem::transmute_copy(&glyphcount), core::mem::transmute(&textstring), core::mem::transmute_copy(&clustermap), core::mem::transmute_copy(&glyphproperties), core::mem::transmute_copy(&justificationopportunities)).into()
             }
         }
-        unsafe extern "system" fn JustifyGlyphAdvances<Identity: IDWriteTextAnalyzer1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, linewidth: f32, glyphcount: u32, justificationopportunities: *const DWRITE_JUSTIFICATION_OPPORTUNITY, glyphadvances: *const f32, glyphoffsets: *const DWRITE_GLYPH_OFFSET, justifiedglyphadvances: *mut f32, justifiedglyphoffsets: *mut DWRITE_GLYPH_OFFSET) -> windows_core::HRESULT {
+        unsafe extern "system" fn JustifyGlyphAdvances<Identity: windows_core::IUnknownImpl + IDWriteTextAnalyzer1_Impl>(this: *mut core::ffi::c_void, linewidth: f32, glyphcount: u32, justificationopportunities: *const DWRITE_JUSTIFICATION_OPPORTUNITY, glyphadvances: *const f32, glyphoffsets: *const DWRITE_GLYPH_OFFSET, justifiedglyphadvances: *mut f32, justifiedglyphoffsets: *mut DWRITE_GLYPH_OFFSET) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextAnalyzer1_Impl::JustifyGlyphAdvances(this, core::mem::transmute_copy(&linewidth), core::mem::transmute_copy(&glyphcount), core::mem::transmute_copy(&justificationopportunities), core::mem::transmute_copy(&glyphadvances), core::mem::transmute_copy(&glyphoffsets), core::mem::transmute_copy(&justifiedglyphadvances), core::mem::transmute_copy(&justifiedglyphoffsets)).into()
             }
         }
-        unsafe extern "system" fn GetJustifiedGlyphs<Identity: IDWriteTextAnalyzer1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, fontemsize: f32, scriptanalysis: DWRITE_SCRIPT_ANALYSIS, textlength: u32, glyphcount: u32, maxglyphcount: u32, clustermap: *const u16, glyphindices: *const u16, glyphadvances: *const f32, justifiedglyphadvances: *const f32, justifiedglyphoffsets: *const DWRITE_GLYPH_OFFSET, glyphproperties: *const DWRITE_SHAPING_GLYPH_PROPERTIES, actualglyphcount: *mut u32, modifiedclustermap: *mut u16, modifiedglyphindices: *mut u16, modifiedglyphadvances: *mut f32, modifiedglyphoffsets: *mut DWRITE_GLYPH_OFFSET) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetJustifiedGlyphs<Identity: windows_core::IUnknownImpl + IDWriteTextAnalyzer1_Impl>(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, fontemsize: f32, scriptanalysis: DWRITE_SCRIPT_ANALYSIS, textlength: u32, glyphcount: u32, maxglyphcount: u32, clustermap: *const u16, glyphindices: *const u16, glyphadvances: *const f32, justifiedglyphadvances: *const f32, justifiedglyphoffsets: *const DWRITE_GLYPH_OFFSET, glyphproperties: *const DWRITE_SHAPING_GLYPH_PROPERTIES, actualglyphcount: *mut u32, modifiedclustermap: *mut u16, modifiedglyphindices: *mut u16, modifiedglyphadvances: *mut f32, modifiedglyphoffsets: *mut DWRITE_GLYPH_OFFSET) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextAnalyzer1_Impl::GetJustifiedGlyphs(
                     this,
                     core::mem::transmute_copy(&fontface),
@@ -9897,21 +14171,18 @@ impl IDWriteTextAnalyzer1_Vtbl {
             }
         }
         Self {
-            base__: IDWriteTextAnalyzer_Vtbl::new::<Identity, OFFSET>(),
-            ApplyCharacterSpacing: ApplyCharacterSpacing::<Identity, OFFSET>,
-            GetBaseline: GetBaseline::<Identity, OFFSET>,
-            AnalyzeVerticalGlyphOrientation: AnalyzeVerticalGlyphOrientation::<Identity, OFFSET>,
-            GetGlyphOrientationTransform: GetGlyphOrientationTransform::<Identity, OFFSET>,
-            GetScriptProperties: GetScriptProperties::<Identity, OFFSET>,
-            GetTextComplexity: GetTextComplexity::<Identity, OFFSET>,
-            GetJustificationOpportunities: GetJustificationOpportunities::<Identity, OFFSET>,
-            JustifyGlyphAdvances: JustifyGlyphAdvances::<Identity, OFFSET>,
-            GetJustifiedGlyphs: GetJustifiedGlyphs::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDWriteTextAnalyzer1 as windows_core::Interface>::IID || iid == &<IDWriteTextAnalyzer as windows_core::Interface>::IID
-    }
+            base__: <IDWriteTextAnalyzer_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ApplyCharacterSpacing: ApplyCharacterSpacing::<Identity>,
+            GetBaseline: GetBaseline::<Identity>,
+            AnalyzeVerticalGlyphOrientation: AnalyzeVerticalGlyphOrientation::<Identity>,
+            GetGlyphOrientationTransform: GetGlyphOrientationTransform::<Identity>,
+            GetScriptProperties: GetScriptProperties::<Identity>,
+            GetTextComplexity: GetTextComplexity::<Identity>,
+            GetJustificationOpportunities: GetJustificationOpportunities::<Identity>,
+            JustifyGlyphAdvances: JustifyGlyphAdvances::<Identity>,
+            GetJustifiedGlyphs: GetJustifiedGlyphs::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IDWriteTextAnalyzer1 {}
 windows_core::imp::define_interface!(IDWriteTextAnalyzer2, IDWriteTextAnalyzer2_Vtbl, 0x553a9ff3_5693_4df7_b52b_74806f7f2eb9);
@@ -9987,6 +14258,37 @@ impl IDWriteTextAnalyzer2_Vtbl {
         iid == &<IDWriteTextAnalyzer2 as windows_core::Interface>::IID || iid == &<IDWriteTextAnalyzer as windows_core::Interface>::IID || iid == &<IDWriteTextAnalyzer1 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteTextAnalyzer2_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteTextAnalyzer2_Vtbl
+where
+    IDWriteTextAnalyzer1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetGlyphOrientationTransform<Identity: windows_core::IUnknownImpl + IDWriteTextAnalyzer2_Impl>(this: *mut core::ffi::c_void, glyphorientationangle: DWRITE_GLYPH_ORIENTATION_ANGLE, issideways: windows_core::BOOL, originx: f32, originy: f32, transform: *mut DWRITE_MATRIX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextAnalyzer2_Impl::GetGlyphOrientationTransform(this, core::mem::transmute_copy(&glyphorientationangle), core::mem::transmute_copy(&issideways), core::mem::transmute_copy(&originx), core::mem::transmute_copy(&originy), core::mem::transmute_copy(&transform)).into()
+            }
+        }
+        unsafe extern "system" fn GetTypographicFeatures<Identity: windows_core::IUnknownImpl + IDWriteTextAnalyzer2_Impl>(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, scriptanalysis: DWRITE_SCRIPT_ANALYSIS, localename: windows_core::PCWSTR, maxtagcount: u32, actualtagcount: *mut u32, tags: *mut DWRITE_FONT_FEATURE_TAG) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextAnalyzer2_Impl::GetTypographicFeatures(this, core::mem::transmute_copy(&fontface), core::mem::transmute(&scriptanalysis), core::mem::transmute(&localename), core::mem::transmute_copy(&maxtagcount), core::mem::transmute_copy(&actualtagcount), core::mem::transmute_copy(&tags)).into()
+            }
+        }
+        unsafe extern "system" fn CheckTypographicFeature<Identity: windows_core::IUnknownImpl + IDWriteTextAnalyzer2_Impl>(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, scriptanalysis: DWRITE_SCRIPT_ANALYSIS, localename: windows_core::PCWSTR, featuretag: DWRITE_FONT_FEATURE_TAG, glyphcount: u32, glyphindices: *const u16, featureapplies: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextAnalyzer2_Impl::CheckTypographicFeature(this, core::mem::transmute_copy(&fontface), core::mem::transmute(&scriptanalysis), core::mem::transmute(&localename), core::mem::transmute_copy(&featuretag), core::mem::transmute_copy(&glyphcount), core::mem::transmute_copy(&glyphindices), core::mem::transmute_copy(&featureapplies)).into()
+            }
+        }
+        Self {
+            base__: <IDWriteTextAnalyzer1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetGlyphOrientationTransform: GetGlyphOrientationTransform::<Identity>,
+            GetTypographicFeatures: GetTypographicFeatures::<Identity>,
+            CheckTypographicFeature: CheckTypographicFeature::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteTextAnalyzer2 {}
 windows_core::imp::define_interface!(IDWriteTextFormat, IDWriteTextFormat_Vtbl, 0x9c906818_31d7_4fd3_a151_7c5e225db55a);
 windows_core::imp::interface_hierarchy!(IDWriteTextFormat, windows_core::IUnknown);
@@ -10323,6 +14625,197 @@ impl IDWriteTextFormat_Vtbl {
         iid == &<IDWriteTextFormat as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteTextFormat_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetTextAlignment<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void, textalignment: DWRITE_TEXT_ALIGNMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::SetTextAlignment(this, core::mem::transmute_copy(&textalignment)).into()
+            }
+        }
+        unsafe extern "system" fn SetParagraphAlignment<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void, paragraphalignment: DWRITE_PARAGRAPH_ALIGNMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::SetParagraphAlignment(this, core::mem::transmute_copy(&paragraphalignment)).into()
+            }
+        }
+        unsafe extern "system" fn SetWordWrapping<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void, wordwrapping: DWRITE_WORD_WRAPPING) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::SetWordWrapping(this, core::mem::transmute_copy(&wordwrapping)).into()
+            }
+        }
+        unsafe extern "system" fn SetReadingDirection<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void, readingdirection: DWRITE_READING_DIRECTION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::SetReadingDirection(this, core::mem::transmute_copy(&readingdirection)).into()
+            }
+        }
+        unsafe extern "system" fn SetFlowDirection<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void, flowdirection: DWRITE_FLOW_DIRECTION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::SetFlowDirection(this, core::mem::transmute_copy(&flowdirection)).into()
+            }
+        }
+        unsafe extern "system" fn SetIncrementalTabStop<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void, incrementaltabstop: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::SetIncrementalTabStop(this, core::mem::transmute_copy(&incrementaltabstop)).into()
+            }
+        }
+        unsafe extern "system" fn SetTrimming<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void, trimmingoptions: *const DWRITE_TRIMMING, trimmingsign: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::SetTrimming(this, core::mem::transmute_copy(&trimmingoptions), core::mem::transmute_copy(&trimmingsign)).into()
+            }
+        }
+        unsafe extern "system" fn SetLineSpacing<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void, linespacingmethod: DWRITE_LINE_SPACING_METHOD, linespacing: f32, baseline: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::SetLineSpacing(this, core::mem::transmute_copy(&linespacingmethod), core::mem::transmute_copy(&linespacing), core::mem::transmute_copy(&baseline)).into()
+            }
+        }
+        unsafe extern "system" fn GetTextAlignment<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void) -> DWRITE_TEXT_ALIGNMENT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::GetTextAlignment(this)
+            }
+        }
+        unsafe extern "system" fn GetParagraphAlignment<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void) -> DWRITE_PARAGRAPH_ALIGNMENT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::GetParagraphAlignment(this)
+            }
+        }
+        unsafe extern "system" fn GetWordWrapping<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void) -> DWRITE_WORD_WRAPPING {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::GetWordWrapping(this)
+            }
+        }
+        unsafe extern "system" fn GetReadingDirection<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void) -> DWRITE_READING_DIRECTION {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::GetReadingDirection(this)
+            }
+        }
+        unsafe extern "system" fn GetFlowDirection<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void) -> DWRITE_FLOW_DIRECTION {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::GetFlowDirection(this)
+            }
+        }
+        unsafe extern "system" fn GetIncrementalTabStop<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void) -> f32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::GetIncrementalTabStop(this)
+            }
+        }
+        unsafe extern "system" fn GetTrimming<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void, trimmingoptions: *mut DWRITE_TRIMMING, trimmingsign: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::GetTrimming(this, core::mem::transmute_copy(&trimmingoptions), core::mem::transmute_copy(&trimmingsign)).into()
+            }
+        }
+        unsafe extern "system" fn GetLineSpacing<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void, linespacingmethod: *mut DWRITE_LINE_SPACING_METHOD, linespacing: *mut f32, baseline: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::GetLineSpacing(this, core::mem::transmute_copy(&linespacingmethod), core::mem::transmute_copy(&linespacing), core::mem::transmute_copy(&baseline)).into()
+            }
+        }
+        unsafe extern "system" fn GetFontCollection<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void, fontcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteTextFormat_Impl::GetFontCollection(this) {
+                    Ok(ok__) => {
+                        fontcollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFontFamilyNameLength<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::GetFontFamilyNameLength(this)
+            }
+        }
+        unsafe extern "system" fn GetFontFamilyName<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void, fontfamilyname: windows_core::PWSTR, namesize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::GetFontFamilyName(this, core::mem::transmute_copy(&fontfamilyname), core::mem::transmute_copy(&namesize)).into()
+            }
+        }
+        unsafe extern "system" fn GetFontWeight<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void) -> DWRITE_FONT_WEIGHT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::GetFontWeight(this)
+            }
+        }
+        unsafe extern "system" fn GetFontStyle<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void) -> DWRITE_FONT_STYLE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::GetFontStyle(this)
+            }
+        }
+        unsafe extern "system" fn GetFontStretch<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void) -> DWRITE_FONT_STRETCH {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::GetFontStretch(this)
+            }
+        }
+        unsafe extern "system" fn GetFontSize<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void) -> f32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::GetFontSize(this)
+            }
+        }
+        unsafe extern "system" fn GetLocaleNameLength<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::GetLocaleNameLength(this)
+            }
+        }
+        unsafe extern "system" fn GetLocaleName<Identity: windows_core::IUnknownImpl + IDWriteTextFormat_Impl>(this: *mut core::ffi::c_void, localename: windows_core::PWSTR, namesize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat_Impl::GetLocaleName(this, core::mem::transmute_copy(&localename), core::mem::transmute_copy(&namesize)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetTextAlignment: SetTextAlignment::<Identity>,
+            SetParagraphAlignment: SetParagraphAlignment::<Identity>,
+            SetWordWrapping: SetWordWrapping::<Identity>,
+            SetReadingDirection: SetReadingDirection::<Identity>,
+            SetFlowDirection: SetFlowDirection::<Identity>,
+            SetIncrementalTabStop: SetIncrementalTabStop::<Identity>,
+            SetTrimming: SetTrimming::<Identity>,
+            SetLineSpacing: SetLineSpacing::<Identity>,
+            GetTextAlignment: GetTextAlignment::<Identity>,
+            GetParagraphAlignment: GetParagraphAlignment::<Identity>,
+            GetWordWrapping: GetWordWrapping::<Identity>,
+            GetReadingDirection: GetReadingDirection::<Identity>,
+            GetFlowDirection: GetFlowDirection::<Identity>,
+            GetIncrementalTabStop: GetIncrementalTabStop::<Identity>,
+            GetTrimming: GetTrimming::<Identity>,
+            GetLineSpacing: GetLineSpacing::<Identity>,
+            GetFontCollection: GetFontCollection::<Identity>,
+            GetFontFamilyNameLength: GetFontFamilyNameLength::<Identity>,
+            GetFontFamilyName: GetFontFamilyName::<Identity>,
+            GetFontWeight: GetFontWeight::<Identity>,
+            GetFontStyle: GetFontStyle::<Identity>,
+            GetFontStretch: GetFontStretch::<Identity>,
+            GetFontSize: GetFontSize::<Identity>,
+            GetLocaleNameLength: GetLocaleNameLength::<Identity>,
+            GetLocaleName: GetLocaleName::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteTextFormat {}
 windows_core::imp::define_interface!(IDWriteTextFormat1, IDWriteTextFormat1_Vtbl, 0x5f174b49_0d8b_4cfb_8bca_f1cce9d06c67);
 impl core::ops::Deref for IDWriteTextFormat1 {
@@ -10461,6 +14954,78 @@ impl IDWriteTextFormat1_Vtbl {
         iid == &<IDWriteTextFormat1 as windows_core::Interface>::IID || iid == &<IDWriteTextFormat as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteTextFormat1_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteTextFormat1_Vtbl
+where
+    IDWriteTextFormat_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetVerticalGlyphOrientation<Identity: windows_core::IUnknownImpl + IDWriteTextFormat1_Impl>(this: *mut core::ffi::c_void, glyphorientation: DWRITE_VERTICAL_GLYPH_ORIENTATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat1_Impl::SetVerticalGlyphOrientation(this, core::mem::transmute_copy(&glyphorientation)).into()
+            }
+        }
+        unsafe extern "system" fn GetVerticalGlyphOrientation<Identity: windows_core::IUnknownImpl + IDWriteTextFormat1_Impl>(this: *mut core::ffi::c_void) -> DWRITE_VERTICAL_GLYPH_ORIENTATION {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat1_Impl::GetVerticalGlyphOrientation(this)
+            }
+        }
+        unsafe extern "system" fn SetLastLineWrapping<Identity: windows_core::IUnknownImpl + IDWriteTextFormat1_Impl>(this: *mut core::ffi::c_void, islastlinewrappingenabled: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat1_Impl::SetLastLineWrapping(this, core::mem::transmute_copy(&islastlinewrappingenabled)).into()
+            }
+        }
+        unsafe extern "system" fn GetLastLineWrapping<Identity: windows_core::IUnknownImpl + IDWriteTextFormat1_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat1_Impl::GetLastLineWrapping(this)
+            }
+        }
+        unsafe extern "system" fn SetOpticalAlignment<Identity: windows_core::IUnknownImpl + IDWriteTextFormat1_Impl>(this: *mut core::ffi::c_void, opticalalignment: DWRITE_OPTICAL_ALIGNMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat1_Impl::SetOpticalAlignment(this, core::mem::transmute_copy(&opticalalignment)).into()
+            }
+        }
+        unsafe extern "system" fn GetOpticalAlignment<Identity: windows_core::IUnknownImpl + IDWriteTextFormat1_Impl>(this: *mut core::ffi::c_void) -> DWRITE_OPTICAL_ALIGNMENT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat1_Impl::GetOpticalAlignment(this)
+            }
+        }
+        unsafe extern "system" fn SetFontFallback<Identity: windows_core::IUnknownImpl + IDWriteTextFormat1_Impl>(this: *mut core::ffi::c_void, fontfallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat1_Impl::SetFontFallback(this, core::mem::transmute_copy(&fontfallback)).into()
+            }
+        }
+        unsafe extern "system" fn GetFontFallback<Identity: windows_core::IUnknownImpl + IDWriteTextFormat1_Impl>(this: *mut core::ffi::c_void, fontfallback: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteTextFormat1_Impl::GetFontFallback(this) {
+                    Ok(ok__) => {
+                        fontfallback.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDWriteTextFormat_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetVerticalGlyphOrientation: SetVerticalGlyphOrientation::<Identity>,
+            GetVerticalGlyphOrientation: GetVerticalGlyphOrientation::<Identity>,
+            SetLastLineWrapping: SetLastLineWrapping::<Identity>,
+            GetLastLineWrapping: GetLastLineWrapping::<Identity>,
+            SetOpticalAlignment: SetOpticalAlignment::<Identity>,
+            GetOpticalAlignment: GetOpticalAlignment::<Identity>,
+            SetFontFallback: SetFontFallback::<Identity>,
+            GetFontFallback: GetFontFallback::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteTextFormat1 {}
 windows_core::imp::define_interface!(IDWriteTextFormat2, IDWriteTextFormat2_Vtbl, 0xf67e0edd_9e3d_4ecc_8c32_4183253dfe70);
 impl core::ops::Deref for IDWriteTextFormat2 {
@@ -10515,6 +15080,30 @@ impl IDWriteTextFormat2_Vtbl {
         iid == &<IDWriteTextFormat2 as windows_core::Interface>::IID || iid == &<IDWriteTextFormat as windows_core::Interface>::IID || iid == &<IDWriteTextFormat1 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteTextFormat2_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteTextFormat2_Vtbl
+where
+    IDWriteTextFormat1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetLineSpacing<Identity: windows_core::IUnknownImpl + IDWriteTextFormat2_Impl>(this: *mut core::ffi::c_void, linespacingoptions: *const DWRITE_LINE_SPACING) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat2_Impl::SetLineSpacing(this, core::mem::transmute_copy(&linespacingoptions)).into()
+            }
+        }
+        unsafe extern "system" fn GetLineSpacing<Identity: windows_core::IUnknownImpl + IDWriteTextFormat2_Impl>(this: *mut core::ffi::c_void, linespacingoptions: *mut DWRITE_LINE_SPACING) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat2_Impl::GetLineSpacing(this, core::mem::transmute_copy(&linespacingoptions)).into()
+            }
+        }
+        Self {
+            base__: <IDWriteTextFormat1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetLineSpacing: SetLineSpacing::<Identity>,
+            GetLineSpacing: GetLineSpacing::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteTextFormat2 {}
 windows_core::imp::define_interface!(IDWriteTextFormat3, IDWriteTextFormat3_Vtbl, 0x6d3b5641_e550_430d_a85b_b7bf48a93427);
 impl core::ops::Deref for IDWriteTextFormat3 {
@@ -10600,10 +15189,55 @@ impl IDWriteTextFormat3_Vtbl {
             GetAutomaticFontAxes: GetAutomaticFontAxes::<Identity, OFFSET>,
             SetAutomaticFontAxes: SetAutomaticFontAxes::<Identity, OFFSET>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDWriteTextFormat3 as windows_core::Interface>::IID || iid == &<IDWriteTextFormat as windows_core::Interface>::IID || iid == &<IDWriteTextFormat1 as windows_core::Interface>::IID || iid == &<IDWriteTextFormat2 as windows_core::Interface>::IID
-    }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteTextFormat3 as windows_core::Interface>::IID || iid == &<IDWriteTextFormat as windows_core::Interface>::IID || iid == &<IDWriteTextFormat1 as windows_core::Interface>::IID || iid == &<IDWriteTextFormat2 as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IDWriteTextFormat3_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteTextFormat3_Vtbl
+where
+    IDWriteTextFormat2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetFontAxisValues<Identity: windows_core::IUnknownImpl + IDWriteTextFormat3_Impl>(this: *mut core::ffi::c_void, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat3_Impl::SetFontAxisValues(this, core::mem::transmute_copy(&fontaxisvalues), core::mem::transmute_copy(&fontaxisvaluecount)).into()
+            }
+        }
+        unsafe extern "system" fn GetFontAxisValueCount<Identity: windows_core::IUnknownImpl + IDWriteTextFormat3_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat3_Impl::GetFontAxisValueCount(this)
+            }
+        }
+        unsafe extern "system" fn GetFontAxisValues<Identity: windows_core::IUnknownImpl + IDWriteTextFormat3_Impl>(this: *mut core::ffi::c_void, fontaxisvalues: *mut DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat3_Impl::GetFontAxisValues(this, core::mem::transmute_copy(&fontaxisvalues), core::mem::transmute_copy(&fontaxisvaluecount)).into()
+            }
+        }
+        unsafe extern "system" fn GetAutomaticFontAxes<Identity: windows_core::IUnknownImpl + IDWriteTextFormat3_Impl>(this: *mut core::ffi::c_void) -> DWRITE_AUTOMATIC_FONT_AXES {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat3_Impl::GetAutomaticFontAxes(this)
+            }
+        }
+        unsafe extern "system" fn SetAutomaticFontAxes<Identity: windows_core::IUnknownImpl + IDWriteTextFormat3_Impl>(this: *mut core::ffi::c_void, automaticfontaxes: DWRITE_AUTOMATIC_FONT_AXES) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextFormat3_Impl::SetAutomaticFontAxes(this, core::mem::transmute_copy(&automaticfontaxes)).into()
+            }
+        }
+        Self {
+            base__: <IDWriteTextFormat2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetFontAxisValues: SetFontAxisValues::<Identity>,
+            GetFontAxisValueCount: GetFontAxisValueCount::<Identity>,
+            GetFontAxisValues: GetFontAxisValues::<Identity>,
+            GetAutomaticFontAxes: GetAutomaticFontAxes::<Identity>,
+            SetAutomaticFontAxes: SetAutomaticFontAxes::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IDWriteTextFormat3 {}
 windows_core::imp::define_interface!(IDWriteTextLayout, IDWriteTextLayout_Vtbl, 0x53737037_6d14_410b_9bfe_0b182bb70961);
@@ -10983,73 +15617,371 @@ impl IDWriteTextLayout_Vtbl {
         }
         unsafe extern "system" fn GetFontSize<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, currentposition: u32, fontsize: *mut f32, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextLayout_Impl::GetFontSize(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&fontsize), core::mem::transmute_copy(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn GetUnderline<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, currentposition: u32, hasunderline: *mut windows_core::BOOL, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextLayout_Impl::GetUnderline(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&hasunderline), core::mem::transmute_copy(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn GetStrikethrough<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, currentposition: u32, hasstrikethrough: *mut windows_core::BOOL, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextLayout_Impl::GetStrikethrough(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&hasstrikethrough), core::mem::transmute_copy(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn GetDrawingEffect<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, currentposition: u32, drawingeffect: *mut *mut core::ffi::c_void, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextLayout_Impl::GetDrawingEffect(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&drawingeffect), core::mem::transmute_copy(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn GetInlineObject<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, currentposition: u32, inlineobject: *mut *mut core::ffi::c_void, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextLayout_Impl::GetInlineObject(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&inlineobject), core::mem::transmute_copy(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn GetTypography<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, currentposition: u32, typography: *mut *mut core::ffi::c_void, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextLayout_Impl::GetTypography(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&typography), core::mem::transmute_copy(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn GetLocaleNameLength<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, currentposition: u32, namelength: *mut u32, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextLayout_Impl::GetLocaleNameLength(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&namelength), core::mem::transmute_copy(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn GetLocaleName<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, currentposition: u32, localename: windows_core::PWSTR, namesize: u32, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextLayout_Impl::GetLocaleName(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&localename), core::mem::transmute_copy(&namesize), core::mem::transmute_copy(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn Draw<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, renderer: *mut core::ffi::c_void, originx: f32, originy: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextLayout_Impl::Draw(this, core::mem::transmute_copy(&clientdrawingcontext), core::mem::transmute_copy(&renderer), core::mem::transmute_copy(&originx), core::mem::transmute_copy(&originy)).into()
+            }
+        }
+        unsafe extern "system" fn GetLineMetrics<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, linemetrics: *mut DWRITE_LINE_METRICS, maxlinecount: u32, actuallinecount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextLayout_Impl::GetLineMetrics(this, core::mem::transmute_copy(&linemetrics), core::mem::transmute_copy(&maxlinecount), core::mem::transmute_copy(&actuallinecount)).into()
+            }
+        }
+        unsafe extern "system" fn GetMetrics<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, textmetrics: *mut DWRITE_TEXT_METRICS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextLayout_Impl::GetMetrics(this, core::mem::transmute_copy(&textmetrics)).into()
+            }
+        }
+        unsafe extern "system" fn GetOverhangMetrics<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, overhangs: *mut DWRITE_OVERHANG_METRICS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDWriteTextLayout_Impl::GetOverhangMetrics(this) {
+                    Ok(ok__) => {
+                        overhangs.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetClusterMetrics<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, clustermetrics: *mut DWRITE_CLUSTER_METRICS, maxclustercount: u32, actualclustercount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextLayout_Impl::GetClusterMetrics(this, core::mem::transmute_copy(&clustermetrics), core::mem::transmute_copy(&maxclustercount), core::mem::transmute_copy(&actualclustercount)).into()
+            }
+        }
+        unsafe extern "system" fn DetermineMinWidth<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, minwidth: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDWriteTextLayout_Impl::DetermineMinWidth(this) {
+                    Ok(ok__) => {
+                        minwidth.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn HitTestPoint<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pointx: f32, pointy: f32, istrailinghit: *mut windows_core::BOOL, isinside: *mut windows_core::BOOL, hittestmetrics: *mut DWRITE_HIT_TEST_METRICS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextLayout_Impl::HitTestPoint(this, core::mem::transmute_copy(&pointx), core::mem::transmute_copy(&pointy), core::mem::transmute_copy(&istrailinghit), core::mem::transmute_copy(&isinside), core::mem::transmute_copy(&hittestmetrics)).into()
+            }
+        }
+        unsafe extern "system" fn HitTestTextPosition<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, textposition: u32, istrailinghit: windows_core::BOOL, pointx: *mut f32, pointy: *mut f32, hittestmetrics: *mut DWRITE_HIT_TEST_METRICS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextLayout_Impl::HitTestTextPosition(this, core::mem::transmute_copy(&textposition), core::mem::transmute_copy(&istrailinghit), core::mem::transmute_copy(&pointx), core::mem::transmute_copy(&pointy), core::mem::transmute_copy(&hittestmetrics)).into()
+            }
+        }
+        unsafe extern "system" fn HitTestTextRange<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, textposition: u32, textlength: u32, originx: f32, originy: f32, hittestmetrics: *mut DWRITE_HIT_TEST_METRICS, maxhittestmetricscount: u32, actualhittestmetricscount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDWriteTextLayout_Impl::HitTestTextRange(this, core::mem::transmute_copy(&textposition), core::mem::transmute_copy(&textlength), core::mem::transmute_copy(&originx), core::mem::transmute_copy(&originy), core::mem::transmute_copy(&hittestmetrics), core::mem::transmute_copy(&maxhittestmetricscount), core::mem::transmute_copy(&actualhittestmetricscount)).into()
+            }
+        }
+        Self {
+            base__: IDWriteTextFormat_Vtbl::new::<Identity, OFFSET>(),
+            SetMaxWidth: SetMaxWidth::<Identity, OFFSET>,
+            SetMaxHeight: SetMaxHeight::<Identity, OFFSET>,
+            SetFontCollection: SetFontCollection::<Identity, OFFSET>,
+            SetFontFamilyName: SetFontFamilyName::<Identity, OFFSET>,
+            SetFontWeight: SetFontWeight::<Identity, OFFSET>,
+            SetFontStyle: SetFontStyle::<Identity, OFFSET>,
+            SetFontStretch: SetFontStretch::<Identity, OFFSET>,
+            SetFontSize: SetFontSize::<Identity, OFFSET>,
+            SetUnderline: SetUnderline::<Identity, OFFSET>,
+            SetStrikethrough: SetStrikethrough::<Identity, OFFSET>,
+            SetDrawingEffect: SetDrawingEffect::<Identity, OFFSET>,
+            SetInlineObject: SetInlineObject::<Identity, OFFSET>,
+            SetTypography: SetTypography::<Identity, OFFSET>,
+            SetLocaleName: SetLocaleName::<Identity, OFFSET>,
+            GetMaxWidth: GetMaxWidth::<Identity, OFFSET>,
+            GetMaxHeight: GetMaxHeight::<Identity, OFFSET>,
+            GetFontCollection: GetFontCollection::<Identity, OFFSET>,
+            GetFontFamilyNameLength: GetFontFamilyNameLength::<Identity, OFFSET>,
+            GetFontFamilyName: GetFontFamilyName::<Identity, OFFSET>,
+            GetFontWeight: GetFontWeight::<Identity, OFFSET>,
+            GetFontStyle: GetFontStyle::<Identity, OFFSET>,
+            GetFontStretch: GetFontStretch::<Identity, OFFSET>,
+            GetFontSize: GetFontSize::<Identity, OFFSET>,
+            GetUnderline: GetUnderline::<Identity, OFFSET>,
+            GetStrikethrough: GetStrikethrough::<Identity, OFFSET>,
+            GetDrawingEffect: GetDrawingEffect::<Identity, OFFSET>,
+            GetInlineObject: GetInlineObject::<Identity, OFFSET>,
+            GetTypography: GetTypography::<Identity, OFFSET>,
+            GetLocaleNameLength: GetLocaleNameLength::<Identity, OFFSET>,
+            GetLocaleName: GetLocaleName::<Identity, OFFSET>,
+            Draw: Draw::<Identity, OFFSET>,
+            GetLineMetrics: GetLineMetrics::<Identity, OFFSET>,
+            GetMetrics: GetMetrics::<Identity, OFFSET>,
+            GetOverhangMetrics: GetOverhangMetrics::<Identity, OFFSET>,
+            GetClusterMetrics: GetClusterMetrics::<Identity, OFFSET>,
+            DetermineMinWidth: DetermineMinWidth::<Identity, OFFSET>,
+            HitTestPoint: HitTestPoint::<Identity, OFFSET>,
+            HitTestTextPosition: HitTestTextPosition::<Identity, OFFSET>,
+            HitTestTextRange: HitTestTextRange::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteTextLayout as windows_core::Interface>::IID || iid == &<IDWriteTextFormat as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteTextLayout_Vtbl
+where
+    IDWriteTextFormat_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetMaxWidth<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, maxwidth: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout_Impl::SetMaxWidth(this, core::mem::transmute_copy(&maxwidth)).into()
+            }
+        }
+        unsafe extern "system" fn SetMaxHeight<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, maxheight: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout_Impl::SetMaxHeight(this, core::mem::transmute_copy(&maxheight)).into()
+            }
+        }
+        unsafe extern "system" fn SetFontCollection<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, fontcollection: *mut core::ffi::c_void, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout_Impl::SetFontCollection(this, core::mem::transmute_copy(&fontcollection), core::mem::transmute(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn SetFontFamilyName<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, fontfamilyname: windows_core::PCWSTR, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout_Impl::SetFontFamilyName(this, core::mem::transmute(&fontfamilyname), core::mem::transmute(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn SetFontWeight<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, fontweight: DWRITE_FONT_WEIGHT, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout_Impl::SetFontWeight(this, core::mem::transmute_copy(&fontweight), core::mem::transmute(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn SetFontStyle<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, fontstyle: DWRITE_FONT_STYLE, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout_Impl::SetFontStyle(this, core::mem::transmute_copy(&fontstyle), core::mem::transmute(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn SetFontStretch<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, fontstretch: DWRITE_FONT_STRETCH, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout_Impl::SetFontStretch(this, core::mem::transmute_copy(&fontstretch), core::mem::transmute(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn SetFontSize<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, fontsize: f32, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout_Impl::SetFontSize(this, core::mem::transmute_copy(&fontsize), core::mem::transmute(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn SetUnderline<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, hasunderline: windows_core::BOOL, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout_Impl::SetUnderline(this, core::mem::transmute_copy(&hasunderline), core::mem::transmute(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn SetStrikethrough<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, hasstrikethrough: windows_core::BOOL, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout_Impl::SetStrikethrough(this, core::mem::transmute_copy(&hasstrikethrough), core::mem::transmute(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn SetDrawingEffect<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, drawingeffect: *mut core::ffi::c_void, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout_Impl::SetDrawingEffect(this, core::mem::transmute_copy(&drawingeffect), core::mem::transmute(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn SetInlineObject<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, inlineobject: *mut core::ffi::c_void, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout_Impl::SetInlineObject(this, core::mem::transmute_copy(&inlineobject), core::mem::transmute(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn SetTypography<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, typography: *mut core::ffi::c_void, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout_Impl::SetTypography(this, core::mem::transmute_copy(&typography), core::mem::transmute(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn SetLocaleName<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, localename: windows_core::PCWSTR, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout_Impl::SetLocaleName(this, core::mem::transmute(&localename), core::mem::transmute(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn GetMaxWidth<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void) -> f32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout_Impl::GetMaxWidth(this)
+            }
+        }
+        unsafe extern "system" fn GetMaxHeight<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void) -> f32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout_Impl::GetMaxHeight(this)
+            }
+        }
+        unsafe extern "system" fn GetFontCollection<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, currentposition: u32, fontcollection: *mut *mut core::ffi::c_void, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout_Impl::GetFontCollection(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&fontcollection), core::mem::transmute_copy(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn GetFontFamilyNameLength<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, currentposition: u32, namelength: *mut u32, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout_Impl::GetFontFamilyNameLength(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&namelength), core::mem::transmute_copy(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn GetFontFamilyName<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, currentposition: u32, fontfamilyname: windows_core::PWSTR, namesize: u32, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout_Impl::GetFontFamilyName(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&fontfamilyname), core::mem::transmute_copy(&namesize), core::mem::transmute_copy(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn GetFontWeight<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, currentposition: u32, fontweight: *mut DWRITE_FONT_WEIGHT, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout_Impl::GetFontWeight(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&fontweight), core::mem::transmute_copy(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn GetFontStyle<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, currentposition: u32, fontstyle: *mut DWRITE_FONT_STYLE, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout_Impl::GetFontStyle(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&fontstyle), core::mem::transmute_copy(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn GetFontStretch<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, currentposition: u32, fontstretch: *mut DWRITE_FONT_STRETCH, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout_Impl::GetFontStretch(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&fontstretch), core::mem::transmute_copy(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn GetFontSize<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, currentposition: u32, fontsize: *mut f32, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextLayout_Impl::GetFontSize(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&fontsize), core::mem::transmute_copy(&textrange)).into()
             }
         }
-        unsafe extern "system" fn GetUnderline<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, currentposition: u32, hasunderline: *mut windows_core::BOOL, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetUnderline<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, currentposition: u32, hasunderline: *mut windows_core::BOOL, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextLayout_Impl::GetUnderline(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&hasunderline), core::mem::transmute_copy(&textrange)).into()
             }
         }
-        unsafe extern "system" fn GetStrikethrough<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, currentposition: u32, hasstrikethrough: *mut windows_core::BOOL, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetStrikethrough<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, currentposition: u32, hasstrikethrough: *mut windows_core::BOOL, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextLayout_Impl::GetStrikethrough(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&hasstrikethrough), core::mem::transmute_copy(&textrange)).into()
             }
         }
-        unsafe extern "system" fn GetDrawingEffect<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, currentposition: u32, drawingeffect: *mut *mut core::ffi::c_void, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetDrawingEffect<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, currentposition: u32, drawingeffect: *mut *mut core::ffi::c_void, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextLayout_Impl::GetDrawingEffect(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&drawingeffect), core::mem::transmute_copy(&textrange)).into()
             }
         }
-        unsafe extern "system" fn GetInlineObject<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, currentposition: u32, inlineobject: *mut *mut core::ffi::c_void, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetInlineObject<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, currentposition: u32, inlineobject: *mut *mut core::ffi::c_void, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextLayout_Impl::GetInlineObject(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&inlineobject), core::mem::transmute_copy(&textrange)).into()
             }
         }
-        unsafe extern "system" fn GetTypography<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, currentposition: u32, typography: *mut *mut core::ffi::c_void, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetTypography<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, currentposition: u32, typography: *mut *mut core::ffi::c_void, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextLayout_Impl::GetTypography(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&typography), core::mem::transmute_copy(&textrange)).into()
             }
         }
-        unsafe extern "system" fn GetLocaleNameLength<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, currentposition: u32, namelength: *mut u32, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetLocaleNameLength<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, currentposition: u32, namelength: *mut u32, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextLayout_Impl::GetLocaleNameLength(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&namelength), core::mem::transmute_copy(&textrange)).into()
             }
         }
-        unsafe extern "system" fn GetLocaleName<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, currentposition: u32, localename: windows_core::PWSTR, namesize: u32, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetLocaleName<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, currentposition: u32, localename: windows_core::PWSTR, namesize: u32, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextLayout_Impl::GetLocaleName(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&localename), core::mem::transmute_copy(&namesize), core::mem::transmute_copy(&textrange)).into()
             }
         }
-        unsafe extern "system" fn Draw<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, renderer: *mut core::ffi::c_void, originx: f32, originy: f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn Draw<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, renderer: *mut core::ffi::c_void, originx: f32, originy: f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextLayout_Impl::Draw(this, core::mem::transmute_copy(&clientdrawingcontext), core::mem::transmute_copy(&renderer), core::mem::transmute_copy(&originx), core::mem::transmute_copy(&originy)).into()
             }
         }
-        unsafe extern "system" fn GetLineMetrics<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, linemetrics: *mut DWRITE_LINE_METRICS, maxlinecount: u32, actuallinecount: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetLineMetrics<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, linemetrics: *mut DWRITE_LINE_METRICS, maxlinecount: u32, actuallinecount: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextLayout_Impl::GetLineMetrics(this, core::mem::transmute_copy(&linemetrics), core::mem::transmute_copy(&maxlinecount), core::mem::transmute_copy(&actuallinecount)).into()
             }
         }
-        unsafe extern "system" fn GetMetrics<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, textmetrics: *mut DWRITE_TEXT_METRICS) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetMetrics<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, textmetrics: *mut DWRITE_TEXT_METRICS) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextLayout_Impl::GetMetrics(this, core::mem::transmute_copy(&textmetrics)).into()
             }
         }
-        unsafe extern "system" fn GetOverhangMetrics<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, overhangs: *mut DWRITE_OVERHANG_METRICS) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetOverhangMetrics<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, overhangs: *mut DWRITE_OVERHANG_METRICS) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDWriteTextLayout_Impl::GetOverhangMetrics(this) {
                     Ok(ok__) => {
                         overhangs.write(core::mem::transmute(ok__));
@@ -11059,15 +15991,15 @@ impl IDWriteTextLayout_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetClusterMetrics<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, clustermetrics: *mut DWRITE_CLUSTER_METRICS, maxclustercount: u32, actualclustercount: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetClusterMetrics<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, clustermetrics: *mut DWRITE_CLUSTER_METRICS, maxclustercount: u32, actualclustercount: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextLayout_Impl::GetClusterMetrics(this, core::mem::transmute_copy(&clustermetrics), core::mem::transmute_copy(&maxclustercount), core::mem::transmute_copy(&actualclustercount)).into()
             }
         }
-        unsafe extern "system" fn DetermineMinWidth<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, minwidth: *mut f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn DetermineMinWidth<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, minwidth: *mut f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDWriteTextLayout_Impl::DetermineMinWidth(this) {
                     Ok(ok__) => {
                         minwidth.write(core::mem::transmute(ok__));
@@ -11077,70 +16009,67 @@ impl IDWriteTextLayout_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn HitTestPoint<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pointx: f32, pointy: f32, istrailinghit: *mut windows_core::BOOL, isinside: *mut windows_core::BOOL, hittestmetrics: *mut DWRITE_HIT_TEST_METRICS) -> windows_core::HRESULT {
+        unsafe extern "system" fn HitTestPoint<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, pointx: f32, pointy: f32, istrailinghit: *mut windows_core::BOOL, isinside: *mut windows_core::BOOL, hittestmetrics: *mut DWRITE_HIT_TEST_METRICS) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextLayout_Impl::HitTestPoint(this, core::mem::transmute_copy(&pointx), core::mem::transmute_copy(&pointy), core::mem::transmute_copy(&istrailinghit), core::mem::transmute_copy(&isinside), core::mem::transmute_copy(&hittestmetrics)).into()
             }
         }
-        unsafe extern "system" fn HitTestTextPosition<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, textposition: u32, istrailinghit: windows_core::BOOL, pointx: *mut f32, pointy: *mut f32, hittestmetrics: *mut DWRITE_HIT_TEST_METRICS) -> windows_core::HRESULT {
+        unsafe extern "system" fn HitTestTextPosition<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, textposition: u32, istrailinghit: windows_core::BOOL, pointx: *mut f32, pointy: *mut f32, hittestmetrics: *mut DWRITE_HIT_TEST_METRICS) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextLayout_Impl::HitTestTextPosition(this, core::mem::transmute_copy(&textposition), core::mem::transmute_copy(&istrailinghit), core::mem::transmute_copy(&pointx), core::mem::transmute_copy(&pointy), core::mem::transmute_copy(&hittestmetrics)).into()
             }
         }
-        unsafe extern "system" fn HitTestTextRange<Identity: IDWriteTextLayout_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, textposition: u32, textlength: u32, originx: f32, originy: f32, hittestmetrics: *mut DWRITE_HIT_TEST_METRICS, maxhittestmetricscount: u32, actualhittestmetricscount: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn HitTestTextRange<Identity: windows_core::IUnknownImpl + IDWriteTextLayout_Impl>(this: *mut core::ffi::c_void, textposition: u32, textlength: u32, originx: f32, originy: f32, hittestmetrics: *mut DWRITE_HIT_TEST_METRICS, maxhittestmetricscount: u32, actualhittestmetricscount: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDWriteTextLayout_Impl::HitTestTextRange(this, core::mem::transmute_copy(&textposition), core::mem::transmute_copy(&textlength), core::mem::transmute_copy(&originx), core::mem::transmute_copy(&originy), core::mem::transmute_copy(&hittestmetrics), core::mem::transmute_copy(&maxhittestmetricscount), core::mem::transmute_copy(&actualhittestmetricscount)).into()
             }
         }
         Self {
-            base__: IDWriteTextFormat_Vtbl::new::<Identity, OFFSET>(),
-            SetMaxWidth: SetMaxWidth::<Identity, OFFSET>,
-            SetMaxHeight: SetMaxHeight::<Identity, OFFSET>,
-            SetFontCollection: SetFontCollection::<Identity, OFFSET>,
-            SetFontFamilyName: SetFontFamilyName::<Identity, OFFSET>,
-            SetFontWeight: SetFontWeight::<Identity, OFFSET>,
-            SetFontStyle: SetFontStyle::<Identity, OFFSET>,
-            SetFontStretch: SetFontStretch::<Identity, OFFSET>,
-            SetFontSize: SetFontSize::<Identity, OFFSET>,
-            SetUnderline: SetUnderline::<Identity, OFFSET>,
-            SetStrikethrough: SetStrikethrough::<Identity, OFFSET>,
-            SetDrawingEffect: SetDrawingEffect::<Identity, OFFSET>,
-            SetInlineObject: SetInlineObject::<Identity, OFFSET>,
-            SetTypography: SetTypography::<Identity, OFFSET>,
-            SetLocaleName: SetLocaleName::<Identity, OFFSET>,
-            GetMaxWidth: GetMaxWidth::<Identity, OFFSET>,
-            GetMaxHeight: GetMaxHeight::<Identity, OFFSET>,
-            GetFontCollection: GetFontCollection::<Identity, OFFSET>,
-            GetFontFamilyNameLength: GetFontFamilyNameLength::<Identity, OFFSET>,
-            GetFontFamilyName: GetFontFamilyName::<Identity, OFFSET>,
-            GetFontWeight: GetFontWeight::<Identity, OFFSET>,
-            GetFontStyle: GetFontStyle::<Identity, OFFSET>,
-            GetFontStretch: GetFontStretch::<Identity, OFFSET>,
-            GetFontSize: GetFontSize::<Identity, OFFSET>,
-            GetUnderline: GetUnderline::<Identity, OFFSET>,
-            GetStrikethrough: GetStrikethrough::<Identity, OFFSET>,
-            GetDrawingEffect: GetDrawingEffect::<Identity, OFFSET>,
-            GetInlineObject: GetInlineObject::<Identity, OFFSET>,
-            GetTypography: GetTypography::<Identity, OFFSET>,
-            GetLocaleNameLength: GetLocaleNameLength::<Identity, OFFSET>,
-            GetLocaleName: GetLocaleName::<Identity, OFFSET>,
-            Draw: Draw::<Identity, OFFSET>,
-            GetLineMetrics: GetLineMetrics::<Identity, OFFSET>,
-            GetMetrics: GetMetrics::<Identity, OFFSET>,
-            GetOverhangMetrics: GetOverhangMetrics::<Identity, OFFSET>,
-            GetClusterMetrics: GetClusterMetrics::<Identity, OFFSET>,
-            DetermineMinWidth: DetermineMinWidth::<Identity, OFFSET>,
-            HitTestPoint: HitTestPoint::<Identity, OFFSET>,
-            HitTestTextPosition: HitTestTextPosition::<Identity, OFFSET>,
-            HitTestTextRange: HitTestTextRange::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDWriteTextLayout as windows_core::Interface>::IID || iid == &<IDWriteTextFormat as windows_core::Interface>::IID
-    }
+            base__: <IDWriteTextFormat_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetMaxWidth: SetMaxWidth::<Identity>,
+            SetMaxHeight: SetMaxHeight::<Identity>,
+            SetFontCollection: SetFontCollection::<Identity>,
+            SetFontFamilyName: SetFontFamilyName::<Identity>,
+            SetFontWeight: SetFontWeight::<Identity>,
+            SetFontStyle: SetFontStyle::<Identity>,
+            SetFontStretch: SetFontStretch::<Identity>,
+            SetFontSize: SetFontSize::<Identity>,
+            SetUnderline: SetUnderline::<Identity>,
+            SetStrikethrough: SetStrikethrough::<Identity>,
+            SetDrawingEffect: SetDrawingEffect::<Identity>,
+            SetInlineObject: SetInlineObject::<Identity>,
+            SetTypography: SetTypography::<Identity>,
+            SetLocaleName: SetLocaleName::<Identity>,
+            GetMaxWidth: GetMaxWidth::<Identity>,
+            GetMaxHeight: GetMaxHeight::<Identity>,
+            GetFontCollection: GetFontCollection::<Identity>,
+            GetFontFamilyNameLength: GetFontFamilyNameLength::<Identity>,
+            GetFontFamilyName: GetFontFamilyName::<Identity>,
+            GetFontWeight: GetFontWeight::<Identity>,
+            GetFontStyle: GetFontStyle::<Identity>,
+            GetFontStretch: GetFontStretch::<Identity>,
+            GetFontSize: GetFontSize::<Identity>,
+            GetUnderline: GetUnderline::<Identity>,
+            GetStrikethrough: GetStrikethrough::<Identity>,
+            GetDrawingEffect: GetDrawingEffect::<Identity>,
+            GetInlineObject: GetInlineObject::<Identity>,
+            GetTypography: GetTypography::<Identity>,
+            GetLocaleNameLength: GetLocaleNameLength::<Identity>,
+            GetLocaleName: GetLocaleName::<Identity>,
+            Draw: Draw::<Identity>,
+            GetLineMetrics: GetLineMetrics::<Identity>,
+            GetMetrics: GetMetrics::<Identity>,
+            GetOverhangMetrics: GetOverhangMetrics::<Identity>,
+            GetClusterMetrics: GetClusterMetrics::<Identity>,
+            DetermineMinWidth: DetermineMinWidth::<Identity>,
+            HitTestPoint: HitTestPoint::<Identity>,
+            HitTestTextPosition: HitTestTextPosition::<Identity>,
+            HitTestTextRange: HitTestTextRange::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IDWriteTextLayout {}
 windows_core::imp::define_interface!(IDWriteTextLayout1, IDWriteTextLayout1_Vtbl, 0x9064d822_80a7_465c_a986_df65f78b8feb);
@@ -11220,6 +16149,44 @@ impl IDWriteTextLayout1_Vtbl {
         iid == &<IDWriteTextLayout1 as windows_core::Interface>::IID || iid == &<IDWriteTextFormat as windows_core::Interface>::IID || iid == &<IDWriteTextLayout as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteTextLayout1_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteTextLayout1_Vtbl
+where
+    IDWriteTextLayout_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetPairKerning<Identity: windows_core::IUnknownImpl + IDWriteTextLayout1_Impl>(this: *mut core::ffi::c_void, ispairkerningenabled: windows_core::BOOL, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout1_Impl::SetPairKerning(this, core::mem::transmute_copy(&ispairkerningenabled), core::mem::transmute(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn GetPairKerning<Identity: windows_core::IUnknownImpl + IDWriteTextLayout1_Impl>(this: *mut core::ffi::c_void, currentposition: u32, ispairkerningenabled: *mut windows_core::BOOL, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout1_Impl::GetPairKerning(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&ispairkerningenabled), core::mem::transmute_copy(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn SetCharacterSpacing<Identity: windows_core::IUnknownImpl + IDWriteTextLayout1_Impl>(this: *mut core::ffi::c_void, leadingspacing: f32, trailingspacing: f32, minimumadvancewidth: f32, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout1_Impl::SetCharacterSpacing(this, core::mem::transmute_copy(&leadingspacing), core::mem::transmute_copy(&trailingspacing), core::mem::transmute_copy(&minimumadvancewidth), core::mem::transmute(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn GetCharacterSpacing<Identity: windows_core::IUnknownImpl + IDWriteTextLayout1_Impl>(this: *mut core::ffi::c_void, currentposition: u32, leadingspacing: *mut f32, trailingspacing: *mut f32, minimumadvancewidth: *mut f32, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout1_Impl::GetCharacterSpacing(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&leadingspacing), core::mem::transmute_copy(&trailingspacing), core::mem::transmute_copy(&minimumadvancewidth), core::mem::transmute_copy(&textrange)).into()
+            }
+        }
+        Self {
+            base__: <IDWriteTextLayout_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetPairKerning: SetPairKerning::<Identity>,
+            GetPairKerning: GetPairKerning::<Identity>,
+            SetCharacterSpacing: SetCharacterSpacing::<Identity>,
+            GetCharacterSpacing: GetCharacterSpacing::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteTextLayout1 {}
 windows_core::imp::define_interface!(IDWriteTextLayout2, IDWriteTextLayout2_Vtbl, 0x1093c18f_8d5e_43f0_b064_0917311b525e);
 impl core::ops::Deref for IDWriteTextLayout2 {
@@ -11370,6 +16337,85 @@ impl IDWriteTextLayout2_Vtbl {
         iid == &<IDWriteTextLayout2 as windows_core::Interface>::IID || iid == &<IDWriteTextFormat as windows_core::Interface>::IID || iid == &<IDWriteTextLayout as windows_core::Interface>::IID || iid == &<IDWriteTextLayout1 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteTextLayout2_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteTextLayout2_Vtbl
+where
+    IDWriteTextLayout1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMetrics<Identity: windows_core::IUnknownImpl + IDWriteTextLayout2_Impl>(this: *mut core::ffi::c_void, textmetrics: *mut DWRITE_TEXT_METRICS1) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout2_Impl::GetMetrics(this, core::mem::transmute_copy(&textmetrics)).into()
+            }
+        }
+        unsafe extern "system" fn SetVerticalGlyphOrientation<Identity: windows_core::IUnknownImpl + IDWriteTextLayout2_Impl>(this: *mut core::ffi::c_void, glyphorientation: DWRITE_VERTICAL_GLYPH_ORIENTATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout2_Impl::SetVerticalGlyphOrientation(this, core::mem::transmute_copy(&glyphorientation)).into()
+            }
+        }
+        unsafe extern "system" fn GetVerticalGlyphOrientation<Identity: windows_core::IUnknownImpl + IDWriteTextLayout2_Impl>(this: *mut core::ffi::c_void) -> DWRITE_VERTICAL_GLYPH_ORIENTATION {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout2_Impl::GetVerticalGlyphOrientation(this)
+            }
+        }
+        unsafe extern "system" fn SetLastLineWrapping<Identity: windows_core::IUnknownImpl + IDWriteTextLayout2_Impl>(this: *mut core::ffi::c_void, islastlinewrappingenabled: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout2_Impl::SetLastLineWrapping(this, core::mem::transmute_copy(&islastlinewrappingenabled)).into()
+            }
+        }
+        unsafe extern "system" fn GetLastLineWrapping<Identity: windows_core::IUnknownImpl + IDWriteTextLayout2_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout2_Impl::GetLastLineWrapping(this)
+            }
+        }
+        unsafe extern "system" fn SetOpticalAlignment<Identity: windows_core::IUnknownImpl + IDWriteTextLayout2_Impl>(this: *mut core::ffi::c_void, opticalalignment: DWRITE_OPTICAL_ALIGNMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout2_Impl::SetOpticalAlignment(this, core::mem::transmute_copy(&opticalalignment)).into()
+            }
+        }
+        unsafe extern "system" fn GetOpticalAlignment<Identity: windows_core::IUnknownImpl + IDWriteTextLayout2_Impl>(this: *mut core::ffi::c_void) -> DWRITE_OPTICAL_ALIGNMENT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout2_Impl::GetOpticalAlignment(this)
+            }
+        }
+        unsafe extern "system" fn SetFontFallback<Identity: windows_core::IUnknownImpl + IDWriteTextLayout2_Impl>(this: *mut core::ffi::c_void, fontfallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout2_Impl::SetFontFallback(this, core::mem::transmute_copy(&fontfallback)).into()
+            }
+        }
+        unsafe extern "system" fn GetFontFallback<Identity: windows_core::IUnknownImpl + IDWriteTextLayout2_Impl>(this: *mut core::ffi::c_void, fontfallback: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteTextLayout2_Impl::GetFontFallback(this) {
+                    Ok(ok__) => {
+                        fontfallback.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDWriteTextLayout1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetMetrics: GetMetrics::<Identity>,
+            SetVerticalGlyphOrientation: SetVerticalGlyphOrientation::<Identity>,
+            GetVerticalGlyphOrientation: GetVerticalGlyphOrientation::<Identity>,
+            SetLastLineWrapping: SetLastLineWrapping::<Identity>,
+            GetLastLineWrapping: GetLastLineWrapping::<Identity>,
+            SetOpticalAlignment: SetOpticalAlignment::<Identity>,
+            GetOpticalAlignment: GetOpticalAlignment::<Identity>,
+            SetFontFallback: SetFontFallback::<Identity>,
+            GetFontFallback: GetFontFallback::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteTextLayout2 {}
 windows_core::imp::define_interface!(IDWriteTextLayout3, IDWriteTextLayout3_Vtbl, 0x07ddcd52_020e_4de8_ac33_6c953d83f92d);
 impl core::ops::Deref for IDWriteTextLayout3 {
@@ -11448,6 +16494,44 @@ impl IDWriteTextLayout3_Vtbl {
         iid == &<IDWriteTextLayout3 as windows_core::Interface>::IID || iid == &<IDWriteTextFormat as windows_core::Interface>::IID || iid == &<IDWriteTextLayout as windows_core::Interface>::IID || iid == &<IDWriteTextLayout1 as windows_core::Interface>::IID || iid == &<IDWriteTextLayout2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteTextLayout3_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteTextLayout3_Vtbl
+where
+    IDWriteTextLayout2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InvalidateLayout<Identity: windows_core::IUnknownImpl + IDWriteTextLayout3_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout3_Impl::InvalidateLayout(this).into()
+            }
+        }
+        unsafe extern "system" fn SetLineSpacing<Identity: windows_core::IUnknownImpl + IDWriteTextLayout3_Impl>(this: *mut core::ffi::c_void, linespacingoptions: *const DWRITE_LINE_SPACING) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout3_Impl::SetLineSpacing(this, core::mem::transmute_copy(&linespacingoptions)).into()
+            }
+        }
+        unsafe extern "system" fn GetLineSpacing<Identity: windows_core::IUnknownImpl + IDWriteTextLayout3_Impl>(this: *mut core::ffi::c_void, linespacingoptions: *mut DWRITE_LINE_SPACING) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout3_Impl::GetLineSpacing(this, core::mem::transmute_copy(&linespacingoptions)).into()
+            }
+        }
+        unsafe extern "system" fn GetLineMetrics<Identity: windows_core::IUnknownImpl + IDWriteTextLayout3_Impl>(this: *mut core::ffi::c_void, linemetrics: *mut DWRITE_LINE_METRICS1, maxlinecount: u32, actuallinecount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout3_Impl::GetLineMetrics(this, core::mem::transmute_copy(&linemetrics), core::mem::transmute_copy(&maxlinecount), core::mem::transmute_copy(&actuallinecount)).into()
+            }
+        }
+        Self {
+            base__: <IDWriteTextLayout2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InvalidateLayout: InvalidateLayout::<Identity>,
+            SetLineSpacing: SetLineSpacing::<Identity>,
+            GetLineSpacing: GetLineSpacing::<Identity>,
+            GetLineMetrics: GetLineMetrics::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteTextLayout3 {}
 windows_core::imp::define_interface!(IDWriteTextLayout4, IDWriteTextLayout4_Vtbl, 0x05a9bf42_223f_4441_b5fb_8263685f55e9);
 impl core::ops::Deref for IDWriteTextLayout4 {
@@ -11538,6 +16622,51 @@ impl IDWriteTextLayout4_Vtbl {
         iid == &<IDWriteTextLayout4 as windows_core::Interface>::IID || iid == &<IDWriteTextFormat as windows_core::Interface>::IID || iid == &<IDWriteTextLayout as windows_core::Interface>::IID || iid == &<IDWriteTextLayout1 as windows_core::Interface>::IID || iid == &<IDWriteTextLayout2 as windows_core::Interface>::IID || iid == &<IDWriteTextLayout3 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteTextLayout4_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteTextLayout4_Vtbl
+where
+    IDWriteTextLayout3_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetFontAxisValues<Identity: windows_core::IUnknownImpl + IDWriteTextLayout4_Impl>(this: *mut core::ffi::c_void, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout4_Impl::SetFontAxisValues(this, core::mem::transmute_copy(&fontaxisvalues), core::mem::transmute_copy(&fontaxisvaluecount), core::mem::transmute(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn GetFontAxisValueCount<Identity: windows_core::IUnknownImpl + IDWriteTextLayout4_Impl>(this: *mut core::ffi::c_void, currentposition: u32) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout4_Impl::GetFontAxisValueCount(this, core::mem::transmute_copy(&currentposition))
+            }
+        }
+        unsafe extern "system" fn GetFontAxisValues<Identity: windows_core::IUnknownImpl + IDWriteTextLayout4_Impl>(this: *mut core::ffi::c_void, currentposition: u32, fontaxisvalues: *mut DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout4_Impl::GetFontAxisValues(this, core::mem::transmute_copy(&currentposition), core::mem::transmute_copy(&fontaxisvalues), core::mem::transmute_copy(&fontaxisvaluecount), core::mem::transmute_copy(&textrange)).into()
+            }
+        }
+        unsafe extern "system" fn GetAutomaticFontAxes<Identity: windows_core::IUnknownImpl + IDWriteTextLayout4_Impl>(this: *mut core::ffi::c_void) -> DWRITE_AUTOMATIC_FONT_AXES {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout4_Impl::GetAutomaticFontAxes(this)
+            }
+        }
+        unsafe extern "system" fn SetAutomaticFontAxes<Identity: windows_core::IUnknownImpl + IDWriteTextLayout4_Impl>(this: *mut core::ffi::c_void, automaticfontaxes: DWRITE_AUTOMATIC_FONT_AXES) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextLayout4_Impl::SetAutomaticFontAxes(this, core::mem::transmute_copy(&automaticfontaxes)).into()
+            }
+        }
+        Self {
+            base__: <IDWriteTextLayout3_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetFontAxisValues: SetFontAxisValues::<Identity>,
+            GetFontAxisValueCount: GetFontAxisValueCount::<Identity>,
+            GetFontAxisValues: GetFontAxisValues::<Identity>,
+            GetAutomaticFontAxes: GetAutomaticFontAxes::<Identity>,
+            SetAutomaticFontAxes: SetAutomaticFontAxes::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteTextLayout4 {}
 windows_core::imp::define_interface!(IDWriteTextRenderer, IDWriteTextRenderer_Vtbl, 0xef8a8135_5cc6_45fe_8825_c5a0724eb819);
 impl core::ops::Deref for IDWriteTextRenderer {
@@ -11629,6 +16758,44 @@ impl IDWriteTextRenderer_Vtbl {
         iid == &<IDWriteTextRenderer as windows_core::Interface>::IID || iid == &<IDWritePixelSnapping as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteTextRenderer_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteTextRenderer_Vtbl
+where
+    IDWritePixelSnapping_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DrawGlyphRun<Identity: windows_core::IUnknownImpl + IDWriteTextRenderer_Impl>(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, baselineoriginx: f32, baselineoriginy: f32, measuringmode: DWRITE_MEASURING_MODE, glyphrun: *const DWRITE_GLYPH_RUN, glyphrundescription: *const DWRITE_GLYPH_RUN_DESCRIPTION, clientdrawingeffect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextRenderer_Impl::DrawGlyphRun(this, core::mem::transmute_copy(&clientdrawingcontext), core::mem::transmute_copy(&baselineoriginx), core::mem::transmute_copy(&baselineoriginy), core::mem::transmute_copy(&measuringmode), core::mem::transmute_copy(&glyphrun), core::mem::transmute_copy(&glyphrundescription), core::mem::transmute_copy(&clientdrawingeffect)).into()
+            }
+        }
+        unsafe extern "system" fn DrawUnderline<Identity: windows_core::IUnknownImpl + IDWriteTextRenderer_Impl>(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, baselineoriginx: f32, baselineoriginy: f32, underline: *const DWRITE_UNDERLINE, clientdrawingeffect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextRenderer_Impl::DrawUnderline(this, core::mem::transmute_copy(&clientdrawingcontext), core::mem::transmute_copy(&baselineoriginx), core::mem::transmute_copy(&baselineoriginy), core::mem::transmute_copy(&underline), core::mem::transmute_copy(&clientdrawingeffect)).into()
+            }
+        }
+        unsafe extern "system" fn DrawStrikethrough<Identity: windows_core::IUnknownImpl + IDWriteTextRenderer_Impl>(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, baselineoriginx: f32, baselineoriginy: f32, strikethrough: *const DWRITE_STRIKETHROUGH, clientdrawingeffect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextRenderer_Impl::DrawStrikethrough(this, core::mem::transmute_copy(&clientdrawingcontext), core::mem::transmute_copy(&baselineoriginx), core::mem::transmute_copy(&baselineoriginy), core::mem::transmute_copy(&strikethrough), core::mem::transmute_copy(&clientdrawingeffect)).into()
+            }
+        }
+        unsafe extern "system" fn DrawInlineObject<Identity: windows_core::IUnknownImpl + IDWriteTextRenderer_Impl>(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, originx: f32, originy: f32, inlineobject: *mut core::ffi::c_void, issideways: windows_core::BOOL, isrighttoleft: windows_core::BOOL, clientdrawingeffect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextRenderer_Impl::DrawInlineObject(this, core::mem::transmute_copy(&clientdrawingcontext), core::mem::transmute_copy(&originx), core::mem::transmute_copy(&originy), core::mem::transmute_copy(&inlineobject), core::mem::transmute_copy(&issideways), core::mem::transmute_copy(&isrighttoleft), core::mem::transmute_copy(&clientdrawingeffect)).into()
+            }
+        }
+        Self {
+            base__: <IDWritePixelSnapping_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DrawGlyphRun: DrawGlyphRun::<Identity>,
+            DrawUnderline: DrawUnderline::<Identity>,
+            DrawStrikethrough: DrawStrikethrough::<Identity>,
+            DrawInlineObject: DrawInlineObject::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteTextRenderer {}
 windows_core::imp::define_interface!(IDWriteTextRenderer1, IDWriteTextRenderer1_Vtbl, 0xd3e0e934_22a0_427e_aae4_7d9574b59db1);
 impl core::ops::Deref for IDWriteTextRenderer1 {
@@ -11720,6 +16887,44 @@ impl IDWriteTextRenderer1_Vtbl {
         iid == &<IDWriteTextRenderer1 as windows_core::Interface>::IID || iid == &<IDWritePixelSnapping as windows_core::Interface>::IID || iid == &<IDWriteTextRenderer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteTextRenderer1_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteTextRenderer1_Vtbl
+where
+    IDWriteTextRenderer_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DrawGlyphRun<Identity: windows_core::IUnknownImpl + IDWriteTextRenderer1_Impl>(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, baselineoriginx: f32, baselineoriginy: f32, orientationangle: DWRITE_GLYPH_ORIENTATION_ANGLE, measuringmode: DWRITE_MEASURING_MODE, glyphrun: *const DWRITE_GLYPH_RUN, glyphrundescription: *const DWRITE_GLYPH_RUN_DESCRIPTION, clientdrawingeffect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextRenderer1_Impl::DrawGlyphRun(this, core::mem::transmute_copy(&clientdrawingcontext), core::mem::transmute_copy(&baselineoriginx), core::mem::transmute_copy(&baselineoriginy), core::mem::transmute_copy(&orientationangle), core::mem::transmute_copy(&measuringmode), core::mem::transmute_copy(&glyphrun), core::mem::transmute_copy(&glyphrundescription), core::mem::transmute_copy(&clientdrawingeffect)).into()
+            }
+        }
+        unsafe extern "system" fn DrawUnderline<Identity: windows_core::IUnknownImpl + IDWriteTextRenderer1_Impl>(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, baselineoriginx: f32, baselineoriginy: f32, orientationangle: DWRITE_GLYPH_ORIENTATION_ANGLE, underline: *const DWRITE_UNDERLINE, clientdrawingeffect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextRenderer1_Impl::DrawUnderline(this, core::mem::transmute_copy(&clientdrawingcontext), core::mem::transmute_copy(&baselineoriginx), core::mem::transmute_copy(&baselineoriginy), core::mem::transmute_copy(&orientationangle), core::mem::transmute_copy(&underline), core::mem::transmute_copy(&clientdrawingeffect)).into()
+            }
+        }
+        unsafe extern "system" fn DrawStrikethrough<Identity: windows_core::IUnknownImpl + IDWriteTextRenderer1_Impl>(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, baselineoriginx: f32, baselineoriginy: f32, orientationangle: DWRITE_GLYPH_ORIENTATION_ANGLE, strikethrough: *const DWRITE_STRIKETHROUGH, clientdrawingeffect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextRenderer1_Impl::DrawStrikethrough(this, core::mem::transmute_copy(&clientdrawingcontext), core::mem::transmute_copy(&baselineoriginx), core::mem::transmute_copy(&baselineoriginy), core::mem::transmute_copy(&orientationangle), core::mem::transmute_copy(&strikethrough), core::mem::transmute_copy(&clientdrawingeffect)).into()
+            }
+        }
+        unsafe extern "system" fn DrawInlineObject<Identity: windows_core::IUnknownImpl + IDWriteTextRenderer1_Impl>(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, originx: f32, originy: f32, orientationangle: DWRITE_GLYPH_ORIENTATION_ANGLE, inlineobject: *mut core::ffi::c_void, issideways: windows_core::BOOL, isrighttoleft: windows_core::BOOL, clientdrawingeffect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTextRenderer1_Impl::DrawInlineObject(this, core::mem::transmute_copy(&clientdrawingcontext), core::mem::transmute_copy(&originx), core::mem::transmute_copy(&originy), core::mem::transmute_copy(&orientationangle), core::mem::transmute_copy(&inlineobject), core::mem::transmute_copy(&issideways), core::mem::transmute_copy(&isrighttoleft), core::mem::transmute_copy(&clientdrawingeffect)).into()
+            }
+        }
+        Self {
+            base__: <IDWriteTextRenderer_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DrawGlyphRun: DrawGlyphRun::<Identity>,
+            DrawUnderline: DrawUnderline::<Identity>,
+            DrawStrikethrough: DrawStrikethrough::<Identity>,
+            DrawInlineObject: DrawInlineObject::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteTextRenderer1 {}
 windows_core::imp::define_interface!(IDWriteTypography, IDWriteTypography_Vtbl, 0x55f1112b_1dc2_4b3c_9541_f46894ed85b6);
 windows_core::imp::interface_hierarchy!(IDWriteTypography, windows_core::IUnknown);
@@ -11789,4 +16994,41 @@ impl IDWriteTypography_Vtbl {
         iid == &<IDWriteTypography as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDWriteTypography_Impl> windows_core::imp::TearOffVtable<Identity> for IDWriteTypography_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddFontFeature<Identity: windows_core::IUnknownImpl + IDWriteTypography_Impl>(this: *mut core::ffi::c_void, fontfeature: DWRITE_FONT_FEATURE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTypography_Impl::AddFontFeature(this, core::mem::transmute(&fontfeature)).into()
+            }
+        }
+        unsafe extern "system" fn GetFontFeatureCount<Identity: windows_core::IUnknownImpl + IDWriteTypography_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDWriteTypography_Impl::GetFontFeatureCount(this)
+            }
+        }
+        unsafe extern "system" fn GetFontFeature<Identity: windows_core::IUnknownImpl + IDWriteTypography_Impl>(this: *mut core::ffi::c_void, fontfeatureindex: u32, fontfeature: *mut DWRITE_FONT_FEATURE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDWriteTypography_Impl::GetFontFeature(this, core::mem::transmute_copy(&fontfeatureindex)) {
+                    Ok(ok__) => {
+                        fontfeature.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddFontFeature: AddFontFeature::<Identity>,
+            GetFontFeatureCount: GetFontFeatureCount::<Identity>,
+            GetFontFeature: GetFontFeature::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDWriteTypography {}
@@ -1716,6 +1716,55 @@ impl IDXGIAdapter_Vtbl {
         iid == &<IDXGIAdapter as windows_core::Interface>::IID || iid == &<IDXGIObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDXGIAdapter_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIAdapter_Vtbl
+where
+    IDXGIObject_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumOutputs<Identity: windows_core::IUnknownImpl + IDXGIAdapter_Impl>(this: *mut core::ffi::c_void, output: u32, ppoutput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIAdapter_Impl::EnumOutputs(this, core::mem::transmute_copy(&output)) {
+                    Ok(ok__) => {
+                        ppoutput.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + IDXGIAdapter_Impl>(this: *mut core::ffi::c_void, pdesc: *mut DXGI_ADAPTER_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIAdapter_Impl::GetDesc(this) {
+                    Ok(ok__) => {
+                        pdesc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CheckInterfaceSupport<Identity: windows_core::IUnknownImpl + IDXGIAdapter_Impl>(this: *mut core::ffi::c_void, interfacename: *const windows_core::GUID, pumdversion: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIAdapter_Impl::CheckInterfaceSupport(this, core::mem::transmute_copy(&interfacename)) {
+                    Ok(ok__) => {
+                        pumdversion.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDXGIObject_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnumOutputs: EnumOutputs::<Identity>,
+            GetDesc: GetDesc::<Identity>,
+            CheckInterfaceSupport: CheckInterfaceSupport::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDXGIAdapter {}
 windows_core::imp::define_interface!(IDXGIAdapter1, IDXGIAdapter1_Vtbl, 0x29038f61_3839_4626_91fd_086879011a05);
 impl core::ops::Deref for IDXGIAdapter1 {
@@ -1764,6 +1813,26 @@ impl IDXGIAdapter1_Vtbl {
         iid == &<IDXGIAdapter1 as windows_core::Interface>::IID || iid == &<IDXGIObject as windows_core::Interface>::IID || iid == &<IDXGIAdapter as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDXGIAdapter1_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIAdapter1_Vtbl
+where
+    IDXGIAdapter_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc1<Identity: windows_core::IUnknownImpl + IDXGIAdapter1_Impl>(this: *mut core::ffi::c_void, pdesc: *mut DXGI_ADAPTER_DESC1) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIAdapter1_Impl::GetDesc1(this) {
+                    Ok(ok__) => {
+                        pdesc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IDXGIAdapter_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDesc1: GetDesc1::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDXGIAdapter1 {}
 windows_core::imp::define_interface!(IDXGIAdapter2, IDXGIAdapter2_Vtbl, 0x0aa1ae0a_fa0e_4b84_8644_e05ff8e5acb5);
 impl core::ops::Deref for IDXGIAdapter2 {
@@ -1812,6 +1881,26 @@ impl IDXGIAdapter2_Vtbl {
         iid == &<IDXGIAdapter2 as windows_core::Interface>::IID || iid == &<IDXGIObject as windows_core::Interface>::IID || iid == &<IDXGIAdapter as windows_core::Interface>::IID || iid == &<IDXGIAdapter1 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDXGIAdapter2_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIAdapter2_Vtbl
+where
+    IDXGIAdapter1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc2<Identity: windows_core::IUnknownImpl + IDXGIAdapter2_Impl>(this: *mut core::ffi::c_void, pdesc: *mut DXGI_ADAPTER_DESC2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIAdapter2_Impl::GetDesc2(this) {
+                    Ok(ok__) => {
+                        pdesc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IDXGIAdapter1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDesc2: GetDesc2::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDXGIAdapter2 {}
 windows_core::imp::define_interface!(IDXGIAdapter3, IDXGIAdapter3_Vtbl, 0x645967a4_1392_4310_a798_8053ce3e93fd);
 impl core::ops::Deref for IDXGIAdapter3 {
@@ -1932,6 +2021,70 @@ impl IDXGIAdapter3_Vtbl {
         iid == &<IDXGIAdapter3 as windows_core::Interface>::IID || iid == &<IDXGIObject as windows_core::Interface>::IID || iid == &<IDXGIAdapter as windows_core::Interface>::IID || iid == &<IDXGIAdapter1 as windows_core::Interface>::IID || iid == &<IDXGIAdapter2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDXGIAdapter3_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIAdapter3_Vtbl
+where
+    IDXGIAdapter2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterHardwareContentProtectionTeardownStatusEvent<Identity: windows_core::IUnknownImpl + IDXGIAdapter3_Impl>(this: *mut core::ffi::c_void, hevent: super::super::Foundation::HANDLE, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIAdapter3_Impl::RegisterHardwareContentProtectionTeardownStatusEvent(this, core::mem::transmute_copy(&hevent)) {
+                    Ok(ok__) => {
+                        pdwcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UnregisterHardwareContentProtectionTeardownStatus<Identity: windows_core::IUnknownImpl + IDXGIAdapter3_Impl>(this: *mut core::ffi::c_void, dwcookie: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIAdapter3_Impl::UnregisterHardwareContentProtectionTeardownStatus(this, core::mem::transmute_copy(&dwcookie))
+            }
+        }
+        unsafe extern "system" fn QueryVideoMemoryInfo<Identity: windows_core::IUnknownImpl + IDXGIAdapter3_Impl>(this: *mut core::ffi::c_void, nodeindex: u32, memorysegmentgroup: DXGI_MEMORY_SEGMENT_GROUP, pvideomemoryinfo: *mut DXGI_QUERY_VIDEO_MEMORY_INFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIAdapter3_Impl::QueryVideoMemoryInfo(this, core::mem::transmute_copy(&nodeindex), core::mem::transmute_copy(&memorysegmentgroup), core::mem::transmute_copy(&pvideomemoryinfo)).into()
+            }
+        }
+        unsafe extern "system" fn SetVideoMemoryReservation<Identity: windows_core::IUnknownImpl + IDXGIAdapter3_Impl>(this: *mut core::ffi::c_void, nodeindex: u32, memorysegmentgroup: DXGI_MEMORY_SEGMENT_GROUP, reservation: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIAdapter3_Impl::SetVideoMemoryReservation(this, core::mem::transmute_copy(&nodeindex), core::mem::transmute_copy(&memorysegmentgroup), core::mem::transmute_copy(&reservation)).into()
+            }
+        }
+        unsafe extern "system" fn RegisterVideoMemoryBudgetChangeNotificationEvent<Identity: windows_core::IUnknownImpl + IDXGIAdapter3_Impl>(this: *mut core::ffi::c_void, hevent: super::super::Foundation::HANDLE, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIAdapter3_Impl::RegisterVideoMemoryBudgetChangeNotificationEvent(this, core::mem::transmute_copy(&hevent)) {
+                    Ok(ok__) => {
+                        pdwcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UnregisterVideoMemoryBudgetChangeNotification<Identity: windows_core::IUnknownImpl + IDXGIAdapter3_Impl>(this: *mut core::ffi::c_void, dwcookie: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIAdapter3_Impl::UnregisterVideoMemoryBudgetChangeNotification(this, core::mem::transmute_copy(&dwcookie))
+            }
+        }
+        Self {
+            base__: <IDXGIAdapter2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterHardwareContentProtectionTeardownStatusEvent: RegisterHardwareContentProtectionTeardownStatusEvent::<Identity>,
+            UnregisterHardwareContentProtectionTeardownStatus: UnregisterHardwareContentProtectionTeardownStatus::<Identity>,
+            QueryVideoMemoryInfo: QueryVideoMemoryInfo::<Identity>,
+            SetVideoMemoryReservation: SetVideoMemoryReservation::<Identity>,
+            RegisterVideoMemoryBudgetChangeNotificationEvent: RegisterVideoMemoryBudgetChangeNotificationEvent::<Identity>,
+            UnregisterVideoMemoryBudgetChangeNotification: UnregisterVideoMemoryBudgetChangeNotification::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDXGIAdapter3 {}
 windows_core::imp::define_interface!(IDXGIAdapter4, IDXGIAdapter4_Vtbl, 0x3c8d99d1_4fbf_4181_a82c_af66bf7bd24e);
 impl core::ops::Deref for IDXGIAdapter4 {
@@ -1980,6 +2133,26 @@ impl IDXGIAdapter4_Vtbl {
         iid == &<IDXGIAdapter4 as windows_core::Interface>::IID || iid == &<IDXGIObject as windows_core::Interface>::IID || iid == &<IDXGIAdapter as windows_core::Interface>::IID || iid == &<IDXGIAdapter1 as windows_core::Interface>::IID || iid == &<IDXGIAdapter2 as windows_core::Interface>::IID || iid == &<IDXGIAdapter3 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDXGIAdapter4_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIAdapter4_Vtbl
+where
+    IDXGIAdapter3_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc3<Identity: windows_core::IUnknownImpl + IDXGIAdapter4_Impl>(this: *mut core::ffi::c_void, pdesc: *mut DXGI_ADAPTER_DESC3) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIAdapter4_Impl::GetDesc3(this) {
+                    Ok(ok__) => {
+                        pdesc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IDXGIAdapter3_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDesc3: GetDesc3::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDXGIAdapter4 {}
 windows_core::imp::define_interface!(IDXGIDebug, IDXGIDebug_Vtbl, 0x119e7452_de9e_40fe_8806_88f90c12b441);
 windows_core::imp::interface_hierarchy!(IDXGIDebug, windows_core::IUnknown);
@@ -2013,6 +2186,20 @@ impl IDXGIDebug_Vtbl {
         iid == &<IDXGIDebug as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDXGIDebug_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIDebug_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ReportLiveObjects<Identity: windows_core::IUnknownImpl + IDXGIDebug_Impl>(this: *mut core::ffi::c_void, apiid: windows_core::GUID, flags: DXGI_DEBUG_RLO_FLAGS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDebug_Impl::ReportLiveObjects(this, core::mem::transmute(&apiid), core::mem::transmute_copy(&flags)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ReportLiveObjects: ReportLiveObjects::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDXGIDebug {}
 windows_core::imp::define_interface!(IDXGIDebug1, IDXGIDebug1_Vtbl, 0xc5a05f0c_16f2_4adf_9f4d_a8c4d58ac550);
 impl core::ops::Deref for IDXGIDebug1 {
@@ -2079,6 +2266,37 @@ impl IDXGIDebug1_Vtbl {
         iid == &<IDXGIDebug1 as windows_core::Interface>::IID || iid == &<IDXGIDebug as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDXGIDebug1_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIDebug1_Vtbl
+where
+    IDXGIDebug_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnableLeakTrackingForThread<Identity: windows_core::IUnknownImpl + IDXGIDebug1_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDebug1_Impl::EnableLeakTrackingForThread(this)
+            }
+        }
+        unsafe extern "system" fn DisableLeakTrackingForThread<Identity: windows_core::IUnknownImpl + IDXGIDebug1_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDebug1_Impl::DisableLeakTrackingForThread(this)
+            }
+        }
+        unsafe extern "system" fn IsLeakTrackingEnabledForThread<Identity: windows_core::IUnknownImpl + IDXGIDebug1_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDebug1_Impl::IsLeakTrackingEnabledForThread(this)
+            }
+        }
+        Self {
+            base__: <IDXGIDebug_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnableLeakTrackingForThread: EnableLeakTrackingForThread::<Identity>,
+            DisableLeakTrackingForThread: DisableLeakTrackingForThread::<Identity>,
+            IsLeakTrackingEnabledForThread: IsLeakTrackingEnabledForThread::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDXGIDebug1 {}
 windows_core::imp::define_interface!(IDXGIDecodeSwapChain, IDXGIDecodeSwapChain_Vtbl, 0x2633066b_4514_4c7a_8fd8_12ea98059d18);
 windows_core::imp::interface_hierarchy!(IDXGIDecodeSwapChain, windows_core::IUnknown);
@@ -2229,6 +2447,91 @@ impl IDXGIDecodeSwapChain_Vtbl {
         iid == &<IDXGIDecodeSwapChain as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDXGIDecodeSwapChain_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIDecodeSwapChain_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn PresentBuffer<Identity: windows_core::IUnknownImpl + IDXGIDecodeSwapChain_Impl>(this: *mut core::ffi::c_void, buffertopresent: u32, syncinterval: u32, flags: DXGI_PRESENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDecodeSwapChain_Impl::PresentBuffer(this, core::mem::transmute_copy(&buffertopresent), core::mem::transmute_copy(&syncinterval), core::mem::transmute_copy(&flags))
+            }
+        }
+        unsafe extern "system" fn SetSourceRect<Identity: windows_core::IUnknownImpl + IDXGIDecodeSwapChain_Impl>(this: *mut core::ffi::c_void, prect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDecodeSwapChain_Impl::SetSourceRect(this, core::mem::transmute_copy(&prect)).into()
+            }
+        }
+        unsafe extern "system" fn SetTargetRect<Identity: windows_core::IUnknownImpl + IDXGIDecodeSwapChain_Impl>(this: *mut core::ffi::c_void, prect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDecodeSwapChain_Impl::SetTargetRect(this, core::mem::transmute_copy(&prect)).into()
+            }
+        }
+        unsafe extern "system" fn SetDestSize<Identity: windows_core::IUnknownImpl + IDXGIDecodeSwapChain_Impl>(this: *mut core::ffi::c_void, width: u32, height: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDecodeSwapChain_Impl::SetDestSize(this, core::mem::transmute_copy(&width), core::mem::transmute_copy(&height)).into()
+            }
+        }
+        unsafe extern "system" fn GetSourceRect<Identity: windows_core::IUnknownImpl + IDXGIDecodeSwapChain_Impl>(this: *mut core::ffi::c_void, prect: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIDecodeSwapChain_Impl::GetSourceRect(this) {
+                    Ok(ok__) => {
+                        prect.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTargetRect<Identity: windows_core::IUnknownImpl + IDXGIDecodeSwapChain_Impl>(this: *mut core::ffi::c_void, prect: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIDecodeSwapChain_Impl::GetTargetRect(this) {
+                    Ok(ok__) => {
+                        prect.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDestSize<Identity: windows_core::IUnknownImpl + IDXGIDecodeSwapChain_Impl>(this: *mut core::ffi::c_void, pwidth: *mut u32, pheight: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDecodeSwapChain_Impl::GetDestSize(this, core::mem::transmute_copy(&pwidth), core::mem::transmute_copy(&pheight)).into()
+            }
+        }
+        unsafe extern "system" fn SetColorSpace<Identity: windows_core::IUnknownImpl + IDXGIDecodeSwapChain_Impl>(this: *mut core::ffi::c_void, colorspace: DXGI_MULTIPLANE_OVERLAY_YCbCr_FLAGS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDecodeSwapChain_Impl::SetColorSpace(this, core::mem::transmute_copy(&colorspace)).into()
+            }
+        }
+        unsafe extern "system" fn GetColorSpace<Identity: windows_core::IUnknownImpl + IDXGIDecodeSwapChain_Impl>(this: *mut core::ffi::c_void) -> DXGI_MULTIPLANE_OVERLAY_YCbCr_FLAGS {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDecodeSwapChain_Impl::GetColorSpace(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PresentBuffer: PresentBuffer::<Identity>,
+            SetSourceRect: SetSourceRect::<Identity>,
+            SetTargetRect: SetTargetRect::<Identity>,
+            SetDestSize: SetDestSize::<Identity>,
+            GetSourceRect: GetSourceRect::<Identity>,
+            GetTargetRect: GetTargetRect::<Identity>,
+            GetDestSize: GetDestSize::<Identity>,
+            SetColorSpace: SetColorSpace::<Identity>,
+            GetColorSpace: GetColorSpace::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDXGIDecodeSwapChain {}
 windows_core::imp::define_interface!(IDXGIDevice, IDXGIDevice_Vtbl, 0x54ec77fa_1377_44e6_8c32_88fd5f44c84c);
 impl core::ops::Deref for IDXGIDevice {
@@ -2344,6 +2647,64 @@ impl IDXGIDevice_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDXGIDevice_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIDevice_Vtbl
+where
+    IDXGIObject_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAdapter<Identity: windows_core::IUnknownImpl + IDXGIDevice_Impl>(this: *mut core::ffi::c_void, padapter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIDevice_Impl::GetAdapter(this) {
+                    Ok(ok__) => {
+                        padapter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSurface<Identity: windows_core::IUnknownImpl + IDXGIDevice_Impl>(this: *mut core::ffi::c_void, pdesc: *const DXGI_SURFACE_DESC, numsurfaces: u32, usage: DXGI_USAGE, psharedresource: *const DXGI_SHARED_RESOURCE, ppsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDevice_Impl::CreateSurface(this, core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&numsurfaces), core::mem::transmute_copy(&usage), core::mem::transmute_copy(&psharedresource), core::mem::transmute_copy(&ppsurface)).into()
+            }
+        }
+        unsafe extern "system" fn QueryResourceResidency<Identity: windows_core::IUnknownImpl + IDXGIDevice_Impl>(this: *mut core::ffi::c_void, ppresources: *const *mut core::ffi::c_void, presidencystatus: *mut DXGI_RESIDENCY, numresources: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDevice_Impl::QueryResourceResidency(this, core::mem::transmute_copy(&ppresources), core::mem::transmute_copy(&presidencystatus), core::mem::transmute_copy(&numresources)).into()
+            }
+        }
+        unsafe extern "system" fn SetGPUThreadPriority<Identity: windows_core::IUnknownImpl + IDXGIDevice_Impl>(this: *mut core::ffi::c_void, priority: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDevice_Impl::SetGPUThreadPriority(this, core::mem::transmute_copy(&priority)).into()
+            }
+        }
+        unsafe extern "system" fn GetGPUThreadPriority<Identity: windows_core::IUnknownImpl + IDXGIDevice_Impl>(this: *mut core::ffi::c_void, ppriority: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIDevice_Impl::GetGPUThreadPriority(this) {
+                    Ok(ok__) => {
+                        ppriority.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDXGIObject_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAdapter: GetAdapter::<Identity>,
+            CreateSurface: CreateSurface::<Identity>,
+            QueryResourceResidency: QueryResourceResidency::<Identity>,
+            SetGPUThreadPriority: SetGPUThreadPriority::<Identity>,
+            GetGPUThreadPriority: GetGPUThreadPriority::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IDXGIDevice {}
 windows_core::imp::define_interface!(IDXGIDevice1, IDXGIDevice1_Vtbl, 0x77db970f_6276_48ba_ba28_070143b4392c);
 impl core::ops::Deref for IDXGIDevice1 {
@@ -2410,6 +2771,37 @@ impl IDXGIDevice1_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDXGIDevice1_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIDevice1_Vtbl
+where
+    IDXGIDevice_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetMaximumFrameLatency<Identity: windows_core::IUnknownImpl + IDXGIDevice1_Impl>(this: *mut core::ffi::c_void, maxlatency: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDevice1_Impl::SetMaximumFrameLatency(this, core::mem::transmute_copy(&maxlatency)).into()
+            }
+        }
+        unsafe extern "system" fn GetMaximumFrameLatency<Identity: windows_core::IUnknownImpl + IDXGIDevice1_Impl>(this: *mut core::ffi::c_void, pmaxlatency: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIDevice1_Impl::GetMaximumFrameLatency(this) {
+                    Ok(ok__) => {
+                        pmaxlatency.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDXGIDevice_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetMaximumFrameLatency: SetMaximumFrameLatency::<Identity>,
+            GetMaximumFrameLatency: GetMaximumFrameLatency::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IDXGIDevice1 {}
 windows_core::imp::define_interface!(IDXGIDevice2, IDXGIDevice2_Vtbl, 0x05008617_fbfd_4051_a790_144884b4f6a9);
 impl core::ops::Deref for IDXGIDevice2 {
@@ -2479,13 +2871,45 @@ impl IDXGIDevice2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-impl windows_core::RuntimeName for IDXGIDevice2 {}
-windows_core::imp::define_interface!(IDXGIDevice3, IDXGIDevice3_Vtbl, 0x6007896c_3244_4afd_bf18_a6d3beda5023);
-impl core::ops::Deref for IDXGIDevice3 {
-    type Target = IDXGIDevice2;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
+impl<Identity: windows_core::IUnknownImpl + IDXGIDevice2_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIDevice2_Vtbl
+where
+    IDXGIDevice1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OfferResources<Identity: windows_core::IUnknownImpl + IDXGIDevice2_Impl>(this: *mut core::ffi::c_void, numresources: u32, ppresources: *const *mut core::ffi::c_void, priority: DXGI_OFFER_RESOURCE_PRIORITY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDevice2_Impl::OfferResources(this, core::mem::transmute_copy(&numresources), core::mem::transmute_copy(&ppresources), core::mem::transmute_copy(&priority)).into()
+            }
+        }
+        unsafe extern "system" fn ReclaimResources<Identity: windows_core::IUnknownImpl + IDXGIDevice2_Impl>(this: *mut core::ffi::c_void, numresources: u32, ppresources: *const *mut core::ffi::c_void, pdiscarded: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDevice2_Impl::ReclaimResources(this, core::mem::transmute_copy(&numresources), core::mem::transmute_copy(&ppresources), core::mem::transmute_copy(&pdiscarded)).into()
+            }
+        }
+        unsafe extern "system" fn EnqueueSetEvent<Identity: windows_core::IUnknownImpl + IDXGIDevice2_Impl>(this: *mut core::ffi::c_void, hevent: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDevice2_Impl::EnqueueSetEvent(this, core::mem::transmute_copy(&hevent)).into()
+            }
+        }
+        Self {
+            base__: <IDXGIDevice1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OfferResources: OfferResources::<Identity>,
+            ReclaimResources: ReclaimResources::<Identity>,
+            EnqueueSetEvent: EnqueueSetEvent::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::RuntimeName for IDXGIDevice2 {}
+windows_core::imp::define_interface!(IDXGIDevice3, IDXGIDevice3_Vtbl, 0x6007896c_3244_4afd_bf18_a6d3beda5023);
+impl core::ops::Deref for IDXGIDevice3 {
+    type Target = IDXGIDevice2;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
 }
 windows_core::imp::interface_hierarchy!(IDXGIDevice3, windows_core::IUnknown, IDXGIObject, IDXGIDevice, IDXGIDevice1, IDXGIDevice2);
 impl IDXGIDevice3 {
@@ -2521,6 +2945,21 @@ impl IDXGIDevice3_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDXGIDevice3_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIDevice3_Vtbl
+where
+    IDXGIDevice2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Trim<Identity: windows_core::IUnknownImpl + IDXGIDevice3_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDevice3_Impl::Trim(this)
+            }
+        }
+        Self { base__: <IDXGIDevice2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Trim: Trim::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IDXGIDevice3 {}
 windows_core::imp::define_interface!(IDXGIDevice4, IDXGIDevice4_Vtbl, 0x95b4f95f_d8da_4ca4_9ee6_3b76d5968a10);
 impl core::ops::Deref for IDXGIDevice4 {
@@ -2578,6 +3017,31 @@ impl IDXGIDevice4_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDXGIDevice4_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIDevice4_Vtbl
+where
+    IDXGIDevice3_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OfferResources1<Identity: windows_core::IUnknownImpl + IDXGIDevice4_Impl>(this: *mut core::ffi::c_void, numresources: u32, ppresources: *const *mut core::ffi::c_void, priority: DXGI_OFFER_RESOURCE_PRIORITY, flags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDevice4_Impl::OfferResources1(this, core::mem::transmute_copy(&numresources), core::mem::transmute_copy(&ppresources), core::mem::transmute_copy(&priority), core::mem::transmute(&flags)).into()
+            }
+        }
+        unsafe extern "system" fn ReclaimResources1<Identity: windows_core::IUnknownImpl + IDXGIDevice4_Impl>(this: *mut core::ffi::c_void, numresources: u32, ppresources: *const *mut core::ffi::c_void, presults: *mut DXGI_RECLAIM_RESOURCE_RESULTS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDevice4_Impl::ReclaimResources1(this, core::mem::transmute_copy(&numresources), core::mem::transmute_copy(&ppresources), core::mem::transmute_copy(&presults)).into()
+            }
+        }
+        Self {
+            base__: <IDXGIDevice3_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OfferResources1: OfferResources1::<Identity>,
+            ReclaimResources1: ReclaimResources1::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IDXGIDevice4 {}
 windows_core::imp::define_interface!(IDXGIDeviceSubObject, IDXGIDeviceSubObject_Vtbl, 0x3d3e0379_f9de_4d58_bb6c_18d62992f1a6);
 impl core::ops::Deref for IDXGIDeviceSubObject {
@@ -2621,6 +3085,20 @@ impl IDXGIDeviceSubObject_Vtbl {
         iid == &<IDXGIDeviceSubObject as windows_core::Interface>::IID || iid == &<IDXGIObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDXGIDeviceSubObject_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIDeviceSubObject_Vtbl
+where
+    IDXGIObject_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDevice<Identity: windows_core::IUnknownImpl + IDXGIDeviceSubObject_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDeviceSubObject_Impl::GetDevice(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppdevice)).into()
+            }
+        }
+        Self { base__: <IDXGIObject_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDevice: GetDevice::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDXGIDeviceSubObject {}
 windows_core::imp::define_interface!(IDXGIDisplayControl, IDXGIDisplayControl_Vtbl, 0xea9dbf1a_c88e_4486_854a_98aa0138f30c);
 windows_core::imp::interface_hierarchy!(IDXGIDisplayControl, windows_core::IUnknown);
@@ -2669,6 +3147,30 @@ impl IDXGIDisplayControl_Vtbl {
         iid == &<IDXGIDisplayControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDXGIDisplayControl_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIDisplayControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsStereoEnabled<Identity: windows_core::IUnknownImpl + IDXGIDisplayControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDisplayControl_Impl::IsStereoEnabled(this)
+            }
+        }
+        unsafe extern "system" fn SetStereoEnabled<Identity: windows_core::IUnknownImpl + IDXGIDisplayControl_Impl>(this: *mut core::ffi::c_void, enabled: windows_core::BOOL) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIDisplayControl_Impl::SetStereoEnabled(this, core::mem::transmute_copy(&enabled))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsStereoEnabled: IsStereoEnabled::<Identity>,
+            SetStereoEnabled: SetStereoEnabled::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDXGIDisplayControl {}
 windows_core::imp::define_interface!(IDXGIFactory, IDXGIFactory_Vtbl, 0x7b7166ec_21c7_44ae_b21a_c9ae321ae369);
 impl core::ops::Deref for IDXGIFactory {
@@ -2796,6 +3298,70 @@ impl IDXGIFactory_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDXGIFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIFactory_Vtbl
+where
+    IDXGIObject_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumAdapters<Identity: windows_core::IUnknownImpl + IDXGIFactory_Impl>(this: *mut core::ffi::c_void, adapter: u32, ppadapter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIFactory_Impl::EnumAdapters(this, core::mem::transmute_copy(&adapter)) {
+                    Ok(ok__) => {
+                        ppadapter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MakeWindowAssociation<Identity: windows_core::IUnknownImpl + IDXGIFactory_Impl>(this: *mut core::ffi::c_void, windowhandle: super::super::Foundation::HWND, flags: DXGI_MWA_FLAGS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIFactory_Impl::MakeWindowAssociation(this, core::mem::transmute_copy(&windowhandle), core::mem::transmute_copy(&flags)).into()
+            }
+        }
+        unsafe extern "system" fn GetWindowAssociation<Identity: windows_core::IUnknownImpl + IDXGIFactory_Impl>(this: *mut core::ffi::c_void, pwindowhandle: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIFactory_Impl::GetWindowAssociation(this) {
+                    Ok(ok__) => {
+                        pwindowhandle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSwapChain<Identity: windows_core::IUnknownImpl + IDXGIFactory_Impl>(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, pdesc: *const DXGI_SWAP_CHAIN_DESC, ppswapchain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIFactory_Impl::CreateSwapChain(this, core::mem::transmute_copy(&pdevice), core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&ppswapchain))
+            }
+        }
+        unsafe extern "system" fn CreateSoftwareAdapter<Identity: windows_core::IUnknownImpl + IDXGIFactory_Impl>(this: *mut core::ffi::c_void, module: super::super::Foundation::HMODULE, ppadapter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIFactory_Impl::CreateSoftwareAdapter(this, core::mem::transmute_copy(&module)) {
+                    Ok(ok__) => {
+                        ppadapter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDXGIObject_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnumAdapters: EnumAdapters::<Identity>,
+            MakeWindowAssociation: MakeWindowAssociation::<Identity>,
+            GetWindowAssociation: GetWindowAssociation::<Identity>,
+            CreateSwapChain: CreateSwapChain::<Identity>,
+            CreateSoftwareAdapter: CreateSoftwareAdapter::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IDXGIFactory {}
 windows_core::imp::define_interface!(IDXGIFactory1, IDXGIFactory1_Vtbl, 0x770aae78_f26f_4dba_a829_253c83d1b387);
 impl core::ops::Deref for IDXGIFactory1 {
@@ -2858,6 +3424,37 @@ impl IDXGIFactory1_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDXGIFactory1_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIFactory1_Vtbl
+where
+    IDXGIFactory_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumAdapters1<Identity: windows_core::IUnknownImpl + IDXGIFactory1_Impl>(this: *mut core::ffi::c_void, adapter: u32, ppadapter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIFactory1_Impl::EnumAdapters1(this, core::mem::transmute_copy(&adapter)) {
+                    Ok(ok__) => {
+                        ppadapter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsCurrent<Identity: windows_core::IUnknownImpl + IDXGIFactory1_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIFactory1_Impl::IsCurrent(this)
+            }
+        }
+        Self {
+            base__: <IDXGIFactory_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnumAdapters1: EnumAdapters1::<Identity>,
+            IsCurrent: IsCurrent::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IDXGIFactory1 {}
 windows_core::imp::define_interface!(IDXGIFactory2, IDXGIFactory2_Vtbl, 0x50c83a1c_e072_4c48_87b0_3630fa36a6d0);
 impl core::ops::Deref for IDXGIFactory2 {
@@ -3120,6 +3717,142 @@ impl IDXGIFactory2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDXGIFactory2_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIFactory2_Vtbl
+where
+    IDXGIFactory1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsWindowedStereoEnabled<Identity: windows_core::IUnknownImpl + IDXGIFactory2_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIFactory2_Impl::IsWindowedStereoEnabled(this)
+            }
+        }
+        unsafe extern "system" fn CreateSwapChainForHwnd<Identity: windows_core::IUnknownImpl + IDXGIFactory2_Impl>(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, pdesc: *const DXGI_SWAP_CHAIN_DESC1, pfullscreendesc: *const DXGI_SWAP_CHAIN_FULLSCREEN_DESC, prestricttooutput: *mut core::ffi::c_void, ppswapchain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIFactory2_Impl::CreateSwapChainForHwnd(this, core::mem::transmute_copy(&pdevice), core::mem::transmute_copy(&hwnd), core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&pfullscreendesc), core::mem::transmute_copy(&prestricttooutput)) {
+                    Ok(ok__) => {
+                        ppswapchain.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSwapChainForCoreWindow<Identity: windows_core::IUnknownImpl + IDXGIFactory2_Impl>(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, pwindow: *mut core::ffi::c_void, pdesc: *const DXGI_SWAP_CHAIN_DESC1, prestricttooutput: *mut core::ffi::c_void, ppswapchain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIFactory2_Impl::CreateSwapChainForCoreWindow(this, core::mem::transmute_copy(&pdevice), core::mem::transmute_copy(&pwindow), core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&prestricttooutput)) {
+                    Ok(ok__) => {
+                        ppswapchain.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSharedResourceAdapterLuid<Identity: windows_core::IUnknownImpl + IDXGIFactory2_Impl>(this: *mut core::ffi::c_void, hresource: super::super::Foundation::HANDLE, pluid: *mut super::super::Foundation::LUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIFactory2_Impl::GetSharedResourceAdapterLuid(this, core::mem::transmute_copy(&hresource)) {
+                    Ok(ok__) => {
+                        pluid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RegisterStereoStatusWindow<Identity: windows_core::IUnknownImpl + IDXGIFactory2_Impl>(this: *mut core::ffi::c_void, windowhandle: super::super::Foundation::HWND, wmsg: u32, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIFactory2_Impl::RegisterStereoStatusWindow(this, core::mem::transmute_copy(&windowhandle), core::mem::transmute_copy(&wmsg)) {
+                    Ok(ok__) => {
+                        pdwcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RegisterStereoStatusEvent<Identity: windows_core::IUnknownImpl + IDXGIFactory2_Impl>(this: *mut core::ffi::c_void, hevent: super::super::Foundation::HANDLE, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIFactory2_Impl::RegisterStereoStatusEvent(this, core::mem::transmute_copy(&hevent)) {
+                    Ok(ok__) => {
+                        pdwcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UnregisterStereoStatus<Identity: windows_core::IUnknownImpl + IDXGIFactory2_Impl>(this: *mut core::ffi::c_void, dwcookie: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIFactory2_Impl::UnregisterStereoStatus(this, core::mem::transmute_copy(&dwcookie))
+            }
+        }
+        unsafe extern "system" fn RegisterOcclusionStatusWindow<Identity: windows_core::IUnknownImpl + IDXGIFactory2_Impl>(this: *mut core::ffi::c_void, windowhandle: super::super::Foundation::HWND, wmsg: u32, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIFactory2_Impl::RegisterOcclusionStatusWindow(this, core::mem::transmute_copy(&windowhandle), core::mem::transmute_copy(&wmsg)) {
+                    Ok(ok__) => {
+                        pdwcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RegisterOcclusionStatusEvent<Identity: windows_core::IUnknownImpl + IDXGIFactory2_Impl>(this: *mut core::ffi::c_void, hevent: super::super::Foundation::HANDLE, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIFactory2_Impl::RegisterOcclusionStatusEvent(this, core::mem::transmute_copy(&hevent)) {
+                    Ok(ok__) => {
+                        pdwcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UnregisterOcclusionStatus<Identity: windows_core::IUnknownImpl + IDXGIFactory2_Impl>(this: *mut core::ffi::c_void, dwcookie: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIFactory2_Impl::UnregisterOcclusionStatus(this, core::mem::transmute_copy(&dwcookie))
+            }
+        }
+        unsafe extern "system" fn CreateSwapChainForComposition<Identity: windows_core::IUnknownImpl + IDXGIFactory2_Impl>(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, pdesc: *const DXGI_SWAP_CHAIN_DESC1, prestricttooutput: *mut core::ffi::c_void, ppswapchain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIFactory2_Impl::CreateSwapChainForComposition(this, core::mem::transmute_copy(&pdevice), core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&prestricttooutput)) {
+                    Ok(ok__) => {
+                        ppswapchain.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDXGIFactory1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsWindowedStereoEnabled: IsWindowedStereoEnabled::<Identity>,
+            CreateSwapChainForHwnd: CreateSwapChainForHwnd::<Identity>,
+            CreateSwapChainForCoreWindow: CreateSwapChainForCoreWindow::<Identity>,
+            GetSharedResourceAdapterLuid: GetSharedResourceAdapterLuid::<Identity>,
+            RegisterStereoStatusWindow: RegisterStereoStatusWindow::<Identity>,
+            RegisterStereoStatusEvent: RegisterStereoStatusEvent::<Identity>,
+            UnregisterStereoStatus: UnregisterStereoStatus::<Identity>,
+            RegisterOcclusionStatusWindow: RegisterOcclusionStatusWindow::<Identity>,
+            RegisterOcclusionStatusEvent: RegisterOcclusionStatusEvent::<Identity>,
+            UnregisterOcclusionStatus: UnregisterOcclusionStatus::<Identity>,
+            CreateSwapChainForComposition: CreateSwapChainForComposition::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IDXGIFactory2 {}
 windows_core::imp::define_interface!(IDXGIFactory3, IDXGIFactory3_Vtbl, 0x25483823_cd46_4c7d_86ca_47aa95b837bd);
 impl core::ops::Deref for IDXGIFactory3 {
@@ -3162,6 +3895,21 @@ impl IDXGIFactory3_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDXGIFactory3_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIFactory3_Vtbl
+where
+    IDXGIFactory2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCreationFlags<Identity: windows_core::IUnknownImpl + IDXGIFactory3_Impl>(this: *mut core::ffi::c_void) -> DXGI_CREATE_FACTORY_FLAGS {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIFactory3_Impl::GetCreationFlags(this)
+            }
+        }
+        Self { base__: <IDXGIFactory2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetCreationFlags: GetCreationFlags::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IDXGIFactory3 {}
 windows_core::imp::define_interface!(IDXGIFactory4, IDXGIFactory4_Vtbl, 0x1bc6ea02_ef36_464f_bf0c_21ca39e5168a);
 impl core::ops::Deref for IDXGIFactory4 {
@@ -3227,6 +3975,31 @@ impl IDXGIFactory4_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDXGIFactory4_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIFactory4_Vtbl
+where
+    IDXGIFactory3_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumAdapterByLuid<Identity: windows_core::IUnknownImpl + IDXGIFactory4_Impl>(this: *mut core::ffi::c_void, adapterluid: super::super::Foundation::LUID, riid: *const windows_core::GUID, ppvadapter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIFactory4_Impl::EnumAdapterByLuid(this, core::mem::transmute(&adapterluid), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvadapter)).into()
+            }
+        }
+        unsafe extern "system" fn EnumWarpAdapter<Identity: windows_core::IUnknownImpl + IDXGIFactory4_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvadapter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIFactory4_Impl::EnumWarpAdapter(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvadapter)).into()
+            }
+        }
+        Self {
+            base__: <IDXGIFactory3_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnumAdapterByLuid: EnumAdapterByLuid::<Identity>,
+            EnumWarpAdapter: EnumWarpAdapter::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IDXGIFactory4 {}
 windows_core::imp::define_interface!(IDXGIFactory5, IDXGIFactory5_Vtbl, 0x7632e1f5_ee65_4dca_87fd_84cd75f8838d);
 impl core::ops::Deref for IDXGIFactory5 {
@@ -3269,12 +4042,27 @@ impl IDXGIFactory5_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-impl windows_core::RuntimeName for IDXGIFactory5 {}
-windows_core::imp::define_interface!(IDXGIFactory6, IDXGIFactory6_Vtbl, 0xc1b6694f_ff09_44a9_b03c_77900a0a1d17);
-impl core::ops::Deref for IDXGIFactory6 {
-    type Target = IDXGIFactory5;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
+impl<Identity: windows_core::IUnknownImpl + IDXGIFactory5_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIFactory5_Vtbl
+where
+    IDXGIFactory4_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CheckFeatureSupport<Identity: windows_core::IUnknownImpl + IDXGIFactory5_Impl>(this: *mut core::ffi::c_void, feature: DXGI_FEATURE, pfeaturesupportdata: *mut core::ffi::c_void, featuresupportdatasize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIFactory5_Impl::CheckFeatureSupport(this, core::mem::transmute_copy(&feature), core::mem::transmute_copy(&pfeaturesupportdata), core::mem::transmute_copy(&featuresupportdatasize)).into()
+            }
+        }
+        Self { base__: <IDXGIFactory4_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CheckFeatureSupport: CheckFeatureSupport::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::RuntimeName for IDXGIFactory5 {}
+windows_core::imp::define_interface!(IDXGIFactory6, IDXGIFactory6_Vtbl, 0xc1b6694f_ff09_44a9_b03c_77900a0a1d17);
+impl core::ops::Deref for IDXGIFactory6 {
+    type Target = IDXGIFactory5;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
     }
 }
 windows_core::imp::interface_hierarchy!(IDXGIFactory6, windows_core::IUnknown, IDXGIObject, IDXGIFactory, IDXGIFactory1, IDXGIFactory2, IDXGIFactory3, IDXGIFactory4, IDXGIFactory5);
@@ -3315,6 +4103,24 @@ impl IDXGIFactory6_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDXGIFactory6_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIFactory6_Vtbl
+where
+    IDXGIFactory5_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumAdapterByGpuPreference<Identity: windows_core::IUnknownImpl + IDXGIFactory6_Impl>(this: *mut core::ffi::c_void, adapter: u32, gpupreference: DXGI_GPU_PREFERENCE, riid: *const windows_core::GUID, ppvadapter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIFactory6_Impl::EnumAdapterByGpuPreference(this, core::mem::transmute_copy(&adapter), core::mem::transmute_copy(&gpupreference), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvadapter)).into()
+            }
+        }
+        Self {
+            base__: <IDXGIFactory5_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnumAdapterByGpuPreference: EnumAdapterByGpuPreference::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IDXGIFactory6 {}
 windows_core::imp::define_interface!(IDXGIFactory7, IDXGIFactory7_Vtbl, 0xa4966eed_76db_44da_84c1_ee9a7afb20a8);
 impl core::ops::Deref for IDXGIFactory7 {
@@ -3381,6 +4187,37 @@ impl IDXGIFactory7_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDXGIFactory7_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIFactory7_Vtbl
+where
+    IDXGIFactory6_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterAdaptersChangedEvent<Identity: windows_core::IUnknownImpl + IDXGIFactory7_Impl>(this: *mut core::ffi::c_void, hevent: super::super::Foundation::HANDLE, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIFactory7_Impl::RegisterAdaptersChangedEvent(this, core::mem::transmute_copy(&hevent)) {
+                    Ok(ok__) => {
+                        pdwcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UnregisterAdaptersChangedEvent<Identity: windows_core::IUnknownImpl + IDXGIFactory7_Impl>(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIFactory7_Impl::UnregisterAdaptersChangedEvent(this, core::mem::transmute_copy(&dwcookie)).into()
+            }
+        }
+        Self {
+            base__: <IDXGIFactory6_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterAdaptersChangedEvent: RegisterAdaptersChangedEvent::<Identity>,
+            UnregisterAdaptersChangedEvent: UnregisterAdaptersChangedEvent::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IDXGIFactory7 {}
 windows_core::imp::define_interface!(IDXGIFactoryMedia, IDXGIFactoryMedia_Vtbl, 0x41e7d1f2_a591_4f7b_a2e5_fa9c843e1c12);
 windows_core::imp::interface_hierarchy!(IDXGIFactoryMedia, windows_core::IUnknown);
@@ -3463,6 +4300,43 @@ impl IDXGIFactoryMedia_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDXGIFactoryMedia_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIFactoryMedia_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateSwapChainForCompositionSurfaceHandle<Identity: windows_core::IUnknownImpl + IDXGIFactoryMedia_Impl>(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, hsurface: super::super::Foundation::HANDLE, pdesc: *const DXGI_SWAP_CHAIN_DESC1, prestricttooutput: *mut core::ffi::c_void, ppswapchain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIFactoryMedia_Impl::CreateSwapChainForCompositionSurfaceHandle(this, core::mem::transmute_copy(&pdevice), core::mem::transmute_copy(&hsurface), core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&prestricttooutput)) {
+                    Ok(ok__) => {
+                        ppswapchain.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateDecodeSwapChainForCompositionSurfaceHandle<Identity: windows_core::IUnknownImpl + IDXGIFactoryMedia_Impl>(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, hsurface: super::super::Foundation::HANDLE, pdesc: *const DXGI_DECODE_SWAP_CHAIN_DESC, pyuvdecodebuffers: *mut core::ffi::c_void, prestricttooutput: *mut core::ffi::c_void, ppswapchain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIFactoryMedia_Impl::CreateDecodeSwapChainForCompositionSurfaceHandle(this, core::mem::transmute_copy(&pdevice), core::mem::transmute_copy(&hsurface), core::mem::transmute_copy(&pdesc), core::mem::transmute_copy(&pyuvdecodebuffers), core::mem::transmute_copy(&prestricttooutput)) {
+                    Ok(ok__) => {
+                        ppswapchain.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateSwapChainForCompositionSurfaceHandle: CreateSwapChainForCompositionSurfaceHandle::<Identity>,
+            CreateDecodeSwapChainForCompositionSurfaceHandle: CreateDecodeSwapChainForCompositionSurfaceHandle::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IDXGIFactoryMedia {}
 windows_core::imp::define_interface!(IDXGIInfoQueue, IDXGIInfoQueue_Vtbl, 0xd67441c7_672a_476f_9e82_cd55b44949ce);
 windows_core::imp::interface_hierarchy!(IDXGIInfoQueue, windows_core::IUnknown);
@@ -3937,6 +4811,275 @@ impl IDXGIInfoQueue_Vtbl {
         iid == &<IDXGIInfoQueue as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIInfoQueue_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetMessageCountLimit<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID, messagecountlimit: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::SetMessageCountLimit(this, core::mem::transmute(&producer), core::mem::transmute_copy(&messagecountlimit)).into()
+            }
+        }
+        unsafe extern "system" fn ClearStoredMessages<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::ClearStoredMessages(this, core::mem::transmute(&producer))
+            }
+        }
+        unsafe extern "system" fn GetMessage<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID, messageindex: u64, pmessage: *mut DXGI_INFO_QUEUE_MESSAGE, pmessagebytelength: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::GetMessage(this, core::mem::transmute(&producer), core::mem::transmute_copy(&messageindex), core::mem::transmute_copy(&pmessage), core::mem::transmute_copy(&pmessagebytelength)).into()
+            }
+        }
+        unsafe extern "system" fn GetNumStoredMessagesAllowedByRetrievalFilters<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::GetNumStoredMessagesAllowedByRetrievalFilters(this, core::mem::transmute(&producer))
+            }
+        }
+        unsafe extern "system" fn GetNumStoredMessages<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::GetNumStoredMessages(this, core::mem::transmute(&producer))
+            }
+        }
+        unsafe extern "system" fn GetNumMessagesDiscardedByMessageCountLimit<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::GetNumMessagesDiscardedByMessageCountLimit(this, core::mem::transmute(&producer))
+            }
+        }
+        unsafe extern "system" fn GetMessageCountLimit<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::GetMessageCountLimit(this, core::mem::transmute(&producer))
+            }
+        }
+        unsafe extern "system" fn GetNumMessagesAllowedByStorageFilter<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::GetNumMessagesAllowedByStorageFilter(this, core::mem::transmute(&producer))
+            }
+        }
+        unsafe extern "system" fn GetNumMessagesDeniedByStorageFilter<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> u64 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::GetNumMessagesDeniedByStorageFilter(this, core::mem::transmute(&producer))
+            }
+        }
+        unsafe extern "system" fn AddStorageFilterEntries<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID, pfilter: *const DXGI_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::AddStorageFilterEntries(this, core::mem::transmute(&producer), core::mem::transmute_copy(&pfilter)).into()
+            }
+        }
+        unsafe extern "system" fn GetStorageFilter<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID, pfilter: *mut DXGI_INFO_QUEUE_FILTER, pfilterbytelength: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::GetStorageFilter(this, core::mem::transmute(&producer), core::mem::transmute_copy(&pfilter), core::mem::transmute_copy(&pfilterbytelength)).into()
+            }
+        }
+        unsafe extern "system" fn ClearStorageFilter<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::ClearStorageFilter(this, core::mem::transmute(&producer))
+            }
+        }
+        unsafe extern "system" fn PushEmptyStorageFilter<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::PushEmptyStorageFilter(this, core::mem::transmute(&producer)).into()
+            }
+        }
+        unsafe extern "system" fn PushDenyAllStorageFilter<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::PushDenyAllStorageFilter(this, core::mem::transmute(&producer)).into()
+            }
+        }
+        unsafe extern "system" fn PushCopyOfStorageFilter<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::PushCopyOfStorageFilter(this, core::mem::transmute(&producer)).into()
+            }
+        }
+        unsafe extern "system" fn PushStorageFilter<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID, pfilter: *const DXGI_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::PushStorageFilter(this, core::mem::transmute(&producer), core::mem::transmute_copy(&pfilter)).into()
+            }
+        }
+        unsafe extern "system" fn PopStorageFilter<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::PopStorageFilter(this, core::mem::transmute(&producer))
+            }
+        }
+        unsafe extern "system" fn GetStorageFilterStackSize<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::GetStorageFilterStackSize(this, core::mem::transmute(&producer))
+            }
+        }
+        unsafe extern "system" fn AddRetrievalFilterEntries<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID, pfilter: *const DXGI_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::AddRetrievalFilterEntries(this, core::mem::transmute(&producer), core::mem::transmute_copy(&pfilter)).into()
+            }
+        }
+        unsafe extern "system" fn GetRetrievalFilter<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID, pfilter: *mut DXGI_INFO_QUEUE_FILTER, pfilterbytelength: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::GetRetrievalFilter(this, core::mem::transmute(&producer), core::mem::transmute_copy(&pfilter), core::mem::transmute_copy(&pfilterbytelength)).into()
+            }
+        }
+        unsafe extern "system" fn ClearRetrievalFilter<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::ClearRetrievalFilter(this, core::mem::transmute(&producer))
+            }
+        }
+        unsafe extern "system" fn PushEmptyRetrievalFilter<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::PushEmptyRetrievalFilter(this, core::mem::transmute(&producer)).into()
+            }
+        }
+        unsafe extern "system" fn PushDenyAllRetrievalFilter<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::PushDenyAllRetrievalFilter(this, core::mem::transmute(&producer)).into()
+            }
+        }
+        unsafe extern "system" fn PushCopyOfRetrievalFilter<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::PushCopyOfRetrievalFilter(this, core::mem::transmute(&producer)).into()
+            }
+        }
+        unsafe extern "system" fn PushRetrievalFilter<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID, pfilter: *const DXGI_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::PushRetrievalFilter(this, core::mem::transmute(&producer), core::mem::transmute_copy(&pfilter)).into()
+            }
+        }
+        unsafe extern "system" fn PopRetrievalFilter<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::PopRetrievalFilter(this, core::mem::transmute(&producer))
+            }
+        }
+        unsafe extern "system" fn GetRetrievalFilterStackSize<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::GetRetrievalFilterStackSize(this, core::mem::transmute(&producer))
+            }
+        }
+        unsafe extern "system" fn AddMessage<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID, category: DXGI_INFO_QUEUE_MESSAGE_CATEGORY, severity: DXGI_INFO_QUEUE_MESSAGE_SEVERITY, id: i32, pdescription: windows_core::PCSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::AddMessage(this, core::mem::transmute(&producer), core::mem::transmute_copy(&category), core::mem::transmute_copy(&severity), core::mem::transmute_copy(&id), core::mem::transmute(&pdescription)).into()
+            }
+        }
+        unsafe extern "system" fn AddApplicationMessage<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, severity: DXGI_INFO_QUEUE_MESSAGE_SEVERITY, pdescription: windows_core::PCSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::AddApplicationMessage(this, core::mem::transmute_copy(&severity), core::mem::transmute(&pdescription)).into()
+            }
+        }
+        unsafe extern "system" fn SetBreakOnCategory<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID, category: DXGI_INFO_QUEUE_MESSAGE_CATEGORY, benable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::SetBreakOnCategory(this, core::mem::transmute(&producer), core::mem::transmute_copy(&category), core::mem::transmute_copy(&benable)).into()
+            }
+        }
+        unsafe extern "system" fn SetBreakOnSeverity<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID, severity: DXGI_INFO_QUEUE_MESSAGE_SEVERITY, benable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::SetBreakOnSeverity(this, core::mem::transmute(&producer), core::mem::transmute_copy(&severity), core::mem::transmute_copy(&benable)).into()
+            }
+        }
+        unsafe extern "system" fn SetBreakOnID<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID, id: i32, benable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::SetBreakOnID(this, core::mem::transmute(&producer), core::mem::transmute_copy(&id), core::mem::transmute_copy(&benable)).into()
+            }
+        }
+        unsafe extern "system" fn GetBreakOnCategory<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID, category: DXGI_INFO_QUEUE_MESSAGE_CATEGORY) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::GetBreakOnCategory(this, core::mem::transmute(&producer), core::mem::transmute_copy(&category))
+            }
+        }
+        unsafe extern "system" fn GetBreakOnSeverity<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID, severity: DXGI_INFO_QUEUE_MESSAGE_SEVERITY) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::GetBreakOnSeverity(this, core::mem::transmute(&producer), core::mem::transmute_copy(&severity))
+            }
+        }
+        unsafe extern "system" fn GetBreakOnID<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID, id: i32) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::GetBreakOnID(this, core::mem::transmute(&producer), core::mem::transmute_copy(&id))
+            }
+        }
+        unsafe extern "system" fn SetMuteDebugOutput<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID, bmute: windows_core::BOOL) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::SetMuteDebugOutput(this, core::mem::transmute(&producer), core::mem::transmute_copy(&bmute))
+            }
+        }
+        unsafe extern "system" fn GetMuteDebugOutput<Identity: windows_core::IUnknownImpl + IDXGIInfoQueue_Impl>(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIInfoQueue_Impl::GetMuteDebugOutput(this, core::mem::transmute(&producer))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetMessageCountLimit: SetMessageCountLimit::<Identity>,
+            ClearStoredMessages: ClearStoredMessages::<Identity>,
+            GetMessage: GetMessage::<Identity>,
+            GetNumStoredMessagesAllowedByRetrievalFilters: GetNumStoredMessagesAllowedByRetrievalFilters::<Identity>,
+            GetNumStoredMessages: GetNumStoredMessages::<Identity>,
+            GetNumMessagesDiscardedByMessageCountLimit: GetNumMessagesDiscardedByMessageCountLimit::<Identity>,
+            GetMessageCountLimit: GetMessageCountLimit::<Identity>,
+            GetNumMessagesAllowedByStorageFilter: GetNumMessagesAllowedByStorageFilter::<Identity>,
+            GetNumMessagesDeniedByStorageFilter: GetNumMessagesDeniedByStorageFilter::<Identity>,
+            AddStorageFilterEntries: AddStorageFilterEntries::<Identity>,
+            GetStorageFilter: GetStorageFilter::<Identity>,
+            ClearStorageFilter: ClearStorageFilter::<Identity>,
+            PushEmptyStorageFilter: PushEmptyStorageFilter::<Identity>,
+            PushDenyAllStorageFilter: PushDenyAllStorageFilter::<Identity>,
+            PushCopyOfStorageFilter: PushCopyOfStorageFilter::<Identity>,
+            PushStorageFilter: PushStorageFilter::<Identity>,
+            PopStorageFilter: PopStorageFilter::<Identity>,
+            GetStorageFilterStackSize: GetStorageFilterStackSize::<Identity>,
+            AddRetrievalFilterEntries: AddRetrievalFilterEntries::<Identity>,
+            GetRetrievalFilter: GetRetrievalFilter::<Identity>,
+            ClearRetrievalFilter: ClearRetrievalFilter::<Identity>,
+            PushEmptyRetrievalFilter: PushEmptyRetrievalFilter::<Identity>,
+            PushDenyAllRetrievalFilter: PushDenyAllRetrievalFilter::<Identity>,
+            PushCopyOfRetrievalFilter: PushCopyOfRetrievalFilter::<Identity>,
+            PushRetrievalFilter: PushRetrievalFilter::<Identity>,
+            PopRetrievalFilter: PopRetrievalFilter::<Identity>,
+            GetRetrievalFilterStackSize: GetRetrievalFilterStackSize::<Identity>,
+            AddMessage: AddMessage::<Identity>,
+            AddApplicationMessage: AddApplicationMessage::<Identity>,
+            SetBreakOnCategory: SetBreakOnCategory::<Identity>,
+            SetBreakOnSeverity: SetBreakOnSeverity::<Identity>,
+            SetBreakOnID: SetBreakOnID::<Identity>,
+            GetBreakOnCategory: GetBreakOnCategory::<Identity>,
+            GetBreakOnSeverity: GetBreakOnSeverity::<Identity>,
+            GetBreakOnID: GetBreakOnID::<Identity>,
+            SetMuteDebugOutput: SetMuteDebugOutput::<Identity>,
+            GetMuteDebugOutput: GetMuteDebugOutput::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDXGIInfoQueue {}
 windows_core::imp::define_interface!(IDXGIKeyedMutex, IDXGIKeyedMutex_Vtbl, 0x9d8e1289_d7b3_465f_8126_250e349af85d);
 impl core::ops::Deref for IDXGIKeyedMutex {
@@ -3991,6 +5134,30 @@ impl IDXGIKeyedMutex_Vtbl {
         iid == &<IDXGIKeyedMutex as windows_core::Interface>::IID || iid == &<IDXGIObject as windows_core::Interface>::IID || iid == &<IDXGIDeviceSubObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDXGIKeyedMutex_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIKeyedMutex_Vtbl
+where
+    IDXGIDeviceSubObject_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AcquireSync<Identity: windows_core::IUnknownImpl + IDXGIKeyedMutex_Impl>(this: *mut core::ffi::c_void, key: u64, dwmilliseconds: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIKeyedMutex_Impl::AcquireSync(this, core::mem::transmute_copy(&key), core::mem::transmute_copy(&dwmilliseconds)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseSync<Identity: windows_core::IUnknownImpl + IDXGIKeyedMutex_Impl>(this: *mut core::ffi::c_void, key: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIKeyedMutex_Impl::ReleaseSync(this, core::mem::transmute_copy(&key)).into()
+            }
+        }
+        Self {
+            base__: <IDXGIDeviceSubObject_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AcquireSync: AcquireSync::<Identity>,
+            ReleaseSync: ReleaseSync::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDXGIKeyedMutex {}
 windows_core::imp::define_interface!(IDXGIObject, IDXGIObject_Vtbl, 0xaec22fb8_76f3_4639_9be0_28eb43a67a2e);
 windows_core::imp::interface_hierarchy!(IDXGIObject, windows_core::IUnknown);
@@ -4070,6 +5237,44 @@ impl IDXGIObject_Vtbl {
         iid == &<IDXGIObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDXGIObject_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIObject_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetPrivateData<Identity: windows_core::IUnknownImpl + IDXGIObject_Impl>(this: *mut core::ffi::c_void, name: *const windows_core::GUID, datasize: u32, pdata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIObject_Impl::SetPrivateData(this, core::mem::transmute_copy(&name), core::mem::transmute_copy(&datasize), core::mem::transmute_copy(&pdata)).into()
+            }
+        }
+        unsafe extern "system" fn SetPrivateDataInterface<Identity: windows_core::IUnknownImpl + IDXGIObject_Impl>(this: *mut core::ffi::c_void, name: *const windows_core::GUID, punknown: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIObject_Impl::SetPrivateDataInterface(this, core::mem::transmute_copy(&name), core::mem::transmute_copy(&punknown)).into()
+            }
+        }
+        unsafe extern "system" fn GetPrivateData<Identity: windows_core::IUnknownImpl + IDXGIObject_Impl>(this: *mut core::ffi::c_void, name: *const windows_core::GUID, pdatasize: *mut u32, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIObject_Impl::GetPrivateData(this, core::mem::transmute_copy(&name), core::mem::transmute_copy(&pdatasize), core::mem::transmute_copy(&pdata)).into()
+            }
+        }
+        unsafe extern "system" fn GetParent<Identity: windows_core::IUnknownImpl + IDXGIObject_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppparent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIObject_Impl::GetParent(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppparent)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetPrivateData: SetPrivateData::<Identity>,
+            SetPrivateDataInterface: SetPrivateDataInterface::<Identity>,
+            GetPrivateData: GetPrivateData::<Identity>,
+            GetParent: GetParent::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDXGIObject {}
 windows_core::imp::define_interface!(IDXGIOutput, IDXGIOutput_Vtbl, 0xae02eedb_c735_4690_8d52_5a8dc20213aa);
 impl core::ops::Deref for IDXGIOutput {
@@ -4292,6 +5497,107 @@ impl IDXGIOutput_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
+impl<Identity: windows_core::IUnknownImpl + IDXGIOutput_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIOutput_Vtbl
+where
+    IDXGIObject_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + IDXGIOutput_Impl>(this: *mut core::ffi::c_void, pdesc: *mut DXGI_OUTPUT_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIOutput_Impl::GetDesc(this) {
+                    Ok(ok__) => {
+                        pdesc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDisplayModeList<Identity: windows_core::IUnknownImpl + IDXGIOutput_Impl>(this: *mut core::ffi::c_void, enumformat: Common::DXGI_FORMAT, flags: DXGI_ENUM_MODES, pnummodes: *mut u32, pdesc: *mut Common::DXGI_MODE_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIOutput_Impl::GetDisplayModeList(this, core::mem::transmute_copy(&enumformat), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&pnummodes), core::mem::transmute_copy(&pdesc)).into()
+            }
+        }
+        unsafe extern "system" fn FindClosestMatchingMode<Identity: windows_core::IUnknownImpl + IDXGIOutput_Impl>(this: *mut core::ffi::c_void, pmodetomatch: *const Common::DXGI_MODE_DESC, pclosestmatch: *mut Common::DXGI_MODE_DESC, pconcerneddevice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIOutput_Impl::FindClosestMatchingMode(this, core::mem::transmute_copy(&pmodetomatch), core::mem::transmute_copy(&pclosestmatch), core::mem::transmute_copy(&pconcerneddevice)).into()
+            }
+        }
+        unsafe extern "system" fn WaitForVBlank<Identity: windows_core::IUnknownImpl + IDXGIOutput_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIOutput_Impl::WaitForVBlank(this).into()
+            }
+        }
+        unsafe extern "system" fn TakeOwnership<Identity: windows_core::IUnknownImpl + IDXGIOutput_Impl>(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, exclusive: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIOutput_Impl::TakeOwnership(this, core::mem::transmute_copy(&pdevice), core::mem::transmute_copy(&exclusive)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseOwnership<Identity: windows_core::IUnknownImpl + IDXGIOutput_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIOutput_Impl::ReleaseOwnership(this)
+            }
+        }
+        unsafe extern "system" fn GetGammaControlCapabilities<Identity: windows_core::IUnknownImpl + IDXGIOutput_Impl>(this: *mut core::ffi::c_void, pgammacaps: *mut Common::DXGI_GAMMA_CONTROL_CAPABILITIES) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIOutput_Impl::GetGammaControlCapabilities(this, core::mem::transmute_copy(&pgammacaps)).into()
+            }
+        }
+        unsafe extern "system" fn SetGammaControl<Identity: windows_core::IUnknownImpl + IDXGIOutput_Impl>(this: *mut core::ffi::c_void, parray: *const Common::DXGI_GAMMA_CONTROL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIOutput_Impl::SetGammaControl(this, core::mem::transmute_copy(&parray)).into()
+            }
+        }
+        unsafe extern "system" fn GetGammaControl<Identity: windows_core::IUnknownImpl + IDXGIOutput_Impl>(this: *mut core::ffi::c_void, parray: *mut Common::DXGI_GAMMA_CONTROL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIOutput_Impl::GetGammaControl(this, core::mem::transmute_copy(&parray)).into()
+            }
+        }
+        unsafe extern "system" fn SetDisplaySurface<Identity: windows_core::IUnknownImpl + IDXGIOutput_Impl>(this: *mut core::ffi::c_void, pscanoutsurface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIOutput_Impl::SetDisplaySurface(this, core::mem::transmute_copy(&pscanoutsurface)).into()
+            }
+        }
+        unsafe extern "system" fn GetDisplaySurfaceData<Identity: windows_core::IUnknownImpl + IDXGIOutput_Impl>(this: *mut core::ffi::c_void, pdestination: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIOutput_Impl::GetDisplaySurfaceData(this, core::mem::transmute_copy(&pdestination)).into()
+            }
+        }
+        unsafe extern "system" fn GetFrameStatistics<Identity: windows_core::IUnknownImpl + IDXGIOutput_Impl>(this: *mut core::ffi::c_void, pstats: *mut DXGI_FRAME_STATISTICS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIOutput_Impl::GetFrameStatistics(this, core::mem::transmute_copy(&pstats)).into()
+            }
+        }
+        Self {
+            base__: <IDXGIObject_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDesc: GetDesc::<Identity>,
+            GetDisplayModeList: GetDisplayModeList::<Identity>,
+            FindClosestMatchingMode: FindClosestMatchingMode::<Identity>,
+            WaitForVBlank: WaitForVBlank::<Identity>,
+            TakeOwnership: TakeOwnership::<Identity>,
+            ReleaseOwnership: ReleaseOwnership::<Identity>,
+            GetGammaControlCapabilities: GetGammaControlCapabilities::<Identity>,
+            SetGammaControl: SetGammaControl::<Identity>,
+            GetGammaControl: GetGammaControl::<Identity>,
+            SetDisplaySurface: SetDisplaySurface::<Identity>,
+            GetDisplaySurfaceData: GetDisplaySurfaceData::<Identity>,
+            GetFrameStatistics: GetFrameStatistics::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
 impl windows_core::RuntimeName for IDXGIOutput {}
 windows_core::imp::define_interface!(IDXGIOutput1, IDXGIOutput1_Vtbl, 0x00cddea8_939b_4b83_a340_a685226666cc);
 impl core::ops::Deref for IDXGIOutput1 {
@@ -4354,29 +5660,77 @@ pub trait IDXGIOutput1_Impl: IDXGIOutput_Impl {
     fn DuplicateOutput(&self, pdevice: windows_core::Ref<windows_core::IUnknown>) -> windows_core::Result<IDXGIOutputDuplication>;
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
-impl IDXGIOutput1_Vtbl {
-    pub const fn new<Identity: IDXGIOutput1_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn GetDisplayModeList1<Identity: IDXGIOutput1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, enumformat: Common::DXGI_FORMAT, flags: DXGI_ENUM_MODES, pnummodes: *mut u32, pdesc: *mut DXGI_MODE_DESC1) -> windows_core::HRESULT {
+impl IDXGIOutput1_Vtbl {
+    pub const fn new<Identity: IDXGIOutput1_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn GetDisplayModeList1<Identity: IDXGIOutput1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, enumformat: Common::DXGI_FORMAT, flags: DXGI_ENUM_MODES, pnummodes: *mut u32, pdesc: *mut DXGI_MODE_DESC1) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDXGIOutput1_Impl::GetDisplayModeList1(this, core::mem::transmute_copy(&enumformat), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&pnummodes), core::mem::transmute_copy(&pdesc)).into()
+            }
+        }
+        unsafe extern "system" fn FindClosestMatchingMode1<Identity: IDXGIOutput1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pmodetomatch: *const DXGI_MODE_DESC1, pclosestmatch: *mut DXGI_MODE_DESC1, pconcerneddevice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDXGIOutput1_Impl::FindClosestMatchingMode1(this, core::mem::transmute_copy(&pmodetomatch), core::mem::transmute_copy(&pclosestmatch), core::mem::transmute_copy(&pconcerneddevice)).into()
+            }
+        }
+        unsafe extern "system" fn GetDisplaySurfaceData1<Identity: IDXGIOutput1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdestination: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDXGIOutput1_Impl::GetDisplaySurfaceData1(this, core::mem::transmute_copy(&pdestination)).into()
+            }
+        }
+        unsafe extern "system" fn DuplicateOutput<Identity: IDXGIOutput1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, ppoutputduplication: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDXGIOutput1_Impl::DuplicateOutput(this, core::mem::transmute_copy(&pdevice)) {
+                    Ok(ok__) => {
+                        ppoutputduplication.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: IDXGIOutput_Vtbl::new::<Identity, OFFSET>(),
+            GetDisplayModeList1: GetDisplayModeList1::<Identity, OFFSET>,
+            FindClosestMatchingMode1: FindClosestMatchingMode1::<Identity, OFFSET>,
+            GetDisplaySurfaceData1: GetDisplaySurfaceData1::<Identity, OFFSET>,
+            DuplicateOutput: DuplicateOutput::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIOutput1 as windows_core::Interface>::IID || iid == &<IDXGIObject as windows_core::Interface>::IID || iid == &<IDXGIOutput as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
+impl<Identity: windows_core::IUnknownImpl + IDXGIOutput1_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIOutput1_Vtbl
+where
+    IDXGIOutput_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDisplayModeList1<Identity: windows_core::IUnknownImpl + IDXGIOutput1_Impl>(this: *mut core::ffi::c_void, enumformat: Common::DXGI_FORMAT, flags: DXGI_ENUM_MODES, pnummodes: *mut u32, pdesc: *mut DXGI_MODE_DESC1) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDXGIOutput1_Impl::GetDisplayModeList1(this, core::mem::transmute_copy(&enumformat), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&pnummodes), core::mem::transmute_copy(&pdesc)).into()
             }
         }
-        unsafe extern "system" fn FindClosestMatchingMode1<Identity: IDXGIOutput1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pmodetomatch: *const DXGI_MODE_DESC1, pclosestmatch: *mut DXGI_MODE_DESC1, pconcerneddevice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn FindClosestMatchingMode1<Identity: windows_core::IUnknownImpl + IDXGIOutput1_Impl>(this: *mut core::ffi::c_void, pmodetomatch: *const DXGI_MODE_DESC1, pclosestmatch: *mut DXGI_MODE_DESC1, pconcerneddevice: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDXGIOutput1_Impl::FindClosestMatchingMode1(this, core::mem::transmute_copy(&pmodetomatch), core::mem::transmute_copy(&pclosestmatch), core::mem::transmute_copy(&pconcerneddevice)).into()
             }
         }
-        unsafe extern "system" fn GetDisplaySurfaceData1<Identity: IDXGIOutput1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdestination: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetDisplaySurfaceData1<Identity: windows_core::IUnknownImpl + IDXGIOutput1_Impl>(this: *mut core::ffi::c_void, pdestination: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDXGIOutput1_Impl::GetDisplaySurfaceData1(this, core::mem::transmute_copy(&pdestination)).into()
             }
         }
-        unsafe extern "system" fn DuplicateOutput<Identity: IDXGIOutput1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, ppoutputduplication: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn DuplicateOutput<Identity: windows_core::IUnknownImpl + IDXGIOutput1_Impl>(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, ppoutputduplication: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDXGIOutput1_Impl::DuplicateOutput(this, core::mem::transmute_copy(&pdevice)) {
                     Ok(ok__) => {
                         ppoutputduplication.write(core::mem::transmute(ok__));
@@ -4387,16 +5741,13 @@ impl IDXGIOutput1_Vtbl {
             }
         }
         Self {
-            base__: IDXGIOutput_Vtbl::new::<Identity, OFFSET>(),
-            GetDisplayModeList1: GetDisplayModeList1::<Identity, OFFSET>,
-            FindClosestMatchingMode1: FindClosestMatchingMode1::<Identity, OFFSET>,
-            GetDisplaySurfaceData1: GetDisplaySurfaceData1::<Identity, OFFSET>,
-            DuplicateOutput: DuplicateOutput::<Identity, OFFSET>,
+            base__: <IDXGIOutput_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDisplayModeList1: GetDisplayModeList1::<Identity>,
+            FindClosestMatchingMode1: FindClosestMatchingMode1::<Identity>,
+            GetDisplaySurfaceData1: GetDisplaySurfaceData1::<Identity>,
+            DuplicateOutput: DuplicateOutput::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDXGIOutput1 as windows_core::Interface>::IID || iid == &<IDXGIObject as windows_core::Interface>::IID || iid == &<IDXGIOutput as windows_core::Interface>::IID
-    }
+    };
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
 impl windows_core::RuntimeName for IDXGIOutput1 {}
@@ -4441,6 +5792,21 @@ impl IDXGIOutput2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
+impl<Identity: windows_core::IUnknownImpl + IDXGIOutput2_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIOutput2_Vtbl
+where
+    IDXGIOutput1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SupportsOverlays<Identity: windows_core::IUnknownImpl + IDXGIOutput2_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIOutput2_Impl::SupportsOverlays(this)
+            }
+        }
+        Self { base__: <IDXGIOutput1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SupportsOverlays: SupportsOverlays::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
 impl windows_core::RuntimeName for IDXGIOutput2 {}
 windows_core::imp::define_interface!(IDXGIOutput3, IDXGIOutput3_Vtbl, 0x8a6bb301_7e7e_41f4_a8e0_5b32f7f99b18);
 impl core::ops::Deref for IDXGIOutput3 {
@@ -4499,6 +5865,27 @@ impl IDXGIOutput3_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
+impl<Identity: windows_core::IUnknownImpl + IDXGIOutput3_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIOutput3_Vtbl
+where
+    IDXGIOutput2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CheckOverlaySupport<Identity: windows_core::IUnknownImpl + IDXGIOutput3_Impl>(this: *mut core::ffi::c_void, enumformat: Common::DXGI_FORMAT, pconcerneddevice: *mut core::ffi::c_void, pflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIOutput3_Impl::CheckOverlaySupport(this, core::mem::transmute_copy(&enumformat), core::mem::transmute_copy(&pconcerneddevice)) {
+                    Ok(ok__) => {
+                        pflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IDXGIOutput2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CheckOverlaySupport: CheckOverlaySupport::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
 impl windows_core::RuntimeName for IDXGIOutput3 {}
 windows_core::imp::define_interface!(IDXGIOutput4, IDXGIOutput4_Vtbl, 0xdc7dca35_2196_414d_9f53_617884032a60);
 impl core::ops::Deref for IDXGIOutput4 {
@@ -4557,6 +5944,30 @@ impl IDXGIOutput4_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
+impl<Identity: windows_core::IUnknownImpl + IDXGIOutput4_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIOutput4_Vtbl
+where
+    IDXGIOutput3_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CheckOverlayColorSpaceSupport<Identity: windows_core::IUnknownImpl + IDXGIOutput4_Impl>(this: *mut core::ffi::c_void, format: Common::DXGI_FORMAT, colorspace: Common::DXGI_COLOR_SPACE_TYPE, pconcerneddevice: *mut core::ffi::c_void, pflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIOutput4_Impl::CheckOverlayColorSpaceSupport(this, core::mem::transmute_copy(&format), core::mem::transmute_copy(&colorspace), core::mem::transmute_copy(&pconcerneddevice)) {
+                    Ok(ok__) => {
+                        pflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDXGIOutput3_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CheckOverlayColorSpaceSupport: CheckOverlayColorSpaceSupport::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
 impl windows_core::RuntimeName for IDXGIOutput4 {}
 windows_core::imp::define_interface!(IDXGIOutput5, IDXGIOutput5_Vtbl, 0x80a07424_ab52_42eb_833c_0c42fd282d98);
 impl core::ops::Deref for IDXGIOutput5 {
@@ -4615,6 +6026,27 @@ impl IDXGIOutput5_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
+impl<Identity: windows_core::IUnknownImpl + IDXGIOutput5_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIOutput5_Vtbl
+where
+    IDXGIOutput4_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DuplicateOutput1<Identity: windows_core::IUnknownImpl + IDXGIOutput5_Impl>(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, flags: u32, supportedformatscount: u32, psupportedformats: *const Common::DXGI_FORMAT, ppoutputduplication: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIOutput5_Impl::DuplicateOutput1(this, core::mem::transmute_copy(&pdevice), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&supportedformatscount), core::mem::transmute_copy(&psupportedformats)) {
+                    Ok(ok__) => {
+                        ppoutputduplication.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IDXGIOutput4_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, DuplicateOutput1: DuplicateOutput1::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
 impl windows_core::RuntimeName for IDXGIOutput5 {}
 windows_core::imp::define_interface!(IDXGIOutput6, IDXGIOutput6_Vtbl, 0x068346e8_aaec_4b84_add7_137f513f77a1);
 impl core::ops::Deref for IDXGIOutput6 {
@@ -4694,6 +6126,43 @@ impl IDXGIOutput6_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
+impl<Identity: windows_core::IUnknownImpl + IDXGIOutput6_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIOutput6_Vtbl
+where
+    IDXGIOutput5_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc1<Identity: windows_core::IUnknownImpl + IDXGIOutput6_Impl>(this: *mut core::ffi::c_void, pdesc: *mut DXGI_OUTPUT_DESC1) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIOutput6_Impl::GetDesc1(this) {
+                    Ok(ok__) => {
+                        pdesc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CheckHardwareCompositionSupport<Identity: windows_core::IUnknownImpl + IDXGIOutput6_Impl>(this: *mut core::ffi::c_void, pflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIOutput6_Impl::CheckHardwareCompositionSupport(this) {
+                    Ok(ok__) => {
+                        pflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDXGIOutput5_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDesc1: GetDesc1::<Identity>,
+            CheckHardwareCompositionSupport: CheckHardwareCompositionSupport::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
 impl windows_core::RuntimeName for IDXGIOutput6 {}
 windows_core::imp::define_interface!(IDXGIOutputDuplication, IDXGIOutputDuplication_Vtbl, 0x191cfac3_a341_470d_b26e_a864f428319c);
 impl core::ops::Deref for IDXGIOutputDuplication {
@@ -4840,6 +6309,79 @@ impl IDXGIOutputDuplication_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDXGIOutputDuplication_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIOutputDuplication_Vtbl
+where
+    IDXGIObject_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + IDXGIOutputDuplication_Impl>(this: *mut core::ffi::c_void, pdesc: *mut DXGI_OUTDUPL_DESC) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIOutputDuplication_Impl::GetDesc(this, core::mem::transmute_copy(&pdesc))
+            }
+        }
+        unsafe extern "system" fn AcquireNextFrame<Identity: windows_core::IUnknownImpl + IDXGIOutputDuplication_Impl>(this: *mut core::ffi::c_void, timeoutinmilliseconds: u32, pframeinfo: *mut DXGI_OUTDUPL_FRAME_INFO, ppdesktopresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIOutputDuplication_Impl::AcquireNextFrame(this, core::mem::transmute_copy(&timeoutinmilliseconds), core::mem::transmute_copy(&pframeinfo), core::mem::transmute_copy(&ppdesktopresource)).into()
+            }
+        }
+        unsafe extern "system" fn GetFrameDirtyRects<Identity: windows_core::IUnknownImpl + IDXGIOutputDuplication_Impl>(this: *mut core::ffi::c_void, dirtyrectsbuffersize: u32, pdirtyrectsbuffer: *mut super::super::Foundation::RECT, pdirtyrectsbuffersizerequired: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIOutputDuplication_Impl::GetFrameDirtyRects(this, core::mem::transmute_copy(&dirtyrectsbuffersize), core::mem::transmute_copy(&pdirtyrectsbuffer), core::mem::transmute_copy(&pdirtyrectsbuffersizerequired)).into()
+            }
+        }
+        unsafe extern "system" fn GetFrameMoveRects<Identity: windows_core::IUnknownImpl + IDXGIOutputDuplication_Impl>(this: *mut core::ffi::c_void, moverectsbuffersize: u32, pmoverectbuffer: *mut DXGI_OUTDUPL_MOVE_RECT, pmoverectsbuffersizerequired: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIOutputDuplication_Impl::GetFrameMoveRects(this, core::mem::transmute_copy(&moverectsbuffersize), core::mem::transmute_copy(&pmoverectbuffer), core::mem::transmute_copy(&pmoverectsbuffersizerequired)).into()
+            }
+        }
+        unsafe extern "system" fn GetFramePointerShape<Identity: windows_core::IUnknownImpl + IDXGIOutputDuplication_Impl>(this: *mut core::ffi::c_void, pointershapebuffersize: u32, ppointershapebuffer: *mut core::ffi::c_void, ppointershapebuffersizerequired: *mut u32, ppointershapeinfo: *mut DXGI_OUTDUPL_POINTER_SHAPE_INFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIOutputDuplication_Impl::GetFramePointerShape(this, core::mem::transmute_copy(&pointershapebuffersize), core::mem::transmute_copy(&ppointershapebuffer), core::mem::transmute_copy(&ppointershapebuffersizerequired), core::mem::transmute_copy(&ppointershapeinfo)).into()
+            }
+        }
+        unsafe extern "system" fn MapDesktopSurface<Identity: windows_core::IUnknownImpl + IDXGIOutputDuplication_Impl>(this: *mut core::ffi::c_void, plockedrect: *mut DXGI_MAPPED_RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIOutputDuplication_Impl::MapDesktopSurface(this) {
+                    Ok(ok__) => {
+                        plockedrect.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UnMapDesktopSurface<Identity: windows_core::IUnknownImpl + IDXGIOutputDuplication_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIOutputDuplication_Impl::UnMapDesktopSurface(this).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseFrame<Identity: windows_core::IUnknownImpl + IDXGIOutputDuplication_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIOutputDuplication_Impl::ReleaseFrame(this).into()
+            }
+        }
+        Self {
+            base__: <IDXGIObject_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDesc: GetDesc::<Identity>,
+            AcquireNextFrame: AcquireNextFrame::<Identity>,
+            GetFrameDirtyRects: GetFrameDirtyRects::<Identity>,
+            GetFrameMoveRects: GetFrameMoveRects::<Identity>,
+            GetFramePointerShape: GetFramePointerShape::<Identity>,
+            MapDesktopSurface: MapDesktopSurface::<Identity>,
+            UnMapDesktopSurface: UnMapDesktopSurface::<Identity>,
+            ReleaseFrame: ReleaseFrame::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IDXGIOutputDuplication {}
 windows_core::imp::define_interface!(IDXGIResource, IDXGIResource_Vtbl, 0x035f3ab4_482e_4e50_b41f_8a7f8bd8960b);
 impl core::ops::Deref for IDXGIResource {
@@ -4945,6 +6487,62 @@ impl IDXGIResource_Vtbl {
         iid == &<IDXGIResource as windows_core::Interface>::IID || iid == &<IDXGIObject as windows_core::Interface>::IID || iid == &<IDXGIDeviceSubObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDXGIResource_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIResource_Vtbl
+where
+    IDXGIDeviceSubObject_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSharedHandle<Identity: windows_core::IUnknownImpl + IDXGIResource_Impl>(this: *mut core::ffi::c_void, psharedhandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIResource_Impl::GetSharedHandle(this) {
+                    Ok(ok__) => {
+                        psharedhandle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetUsage<Identity: windows_core::IUnknownImpl + IDXGIResource_Impl>(this: *mut core::ffi::c_void, pusage: *mut DXGI_USAGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIResource_Impl::GetUsage(this) {
+                    Ok(ok__) => {
+                        pusage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEvictionPriority<Identity: windows_core::IUnknownImpl + IDXGIResource_Impl>(this: *mut core::ffi::c_void, evictionpriority: DXGI_RESOURCE_PRIORITY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGIResource_Impl::SetEvictionPriority(this, core::mem::transmute_copy(&evictionpriority)).into()
+            }
+        }
+        unsafe extern "system" fn GetEvictionPriority<Identity: windows_core::IUnknownImpl + IDXGIResource_Impl>(this: *mut core::ffi::c_void, pevictionpriority: *mut DXGI_RESOURCE_PRIORITY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIResource_Impl::GetEvictionPriority(this) {
+                    Ok(ok__) => {
+                        pevictionpriority.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDXGIDeviceSubObject_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSharedHandle: GetSharedHandle::<Identity>,
+            GetUsage: GetUsage::<Identity>,
+            SetEvictionPriority: SetEvictionPriority::<Identity>,
+            GetEvictionPriority: GetEvictionPriority::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDXGIResource {}
 windows_core::imp::define_interface!(IDXGIResource1, IDXGIResource1_Vtbl, 0x30961379_4609_4a41_998e_54fe567ee0c1);
 impl core::ops::Deref for IDXGIResource1 {
@@ -5027,6 +6625,43 @@ impl IDXGIResource1_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Security")]
+impl<Identity: windows_core::IUnknownImpl + IDXGIResource1_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGIResource1_Vtbl
+where
+    IDXGIResource_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateSubresourceSurface<Identity: windows_core::IUnknownImpl + IDXGIResource1_Impl>(this: *mut core::ffi::c_void, index: u32, ppsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIResource1_Impl::CreateSubresourceSurface(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        ppsurface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSharedHandle<Identity: windows_core::IUnknownImpl + IDXGIResource1_Impl>(this: *mut core::ffi::c_void, pattributes: *const super::super::Security::SECURITY_ATTRIBUTES, dwaccess: u32, lpname: windows_core::PCWSTR, phandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGIResource1_Impl::CreateSharedHandle(this, core::mem::transmute_copy(&pattributes), core::mem::transmute_copy(&dwaccess), core::mem::transmute(&lpname)) {
+                    Ok(ok__) => {
+                        phandle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDXGIResource_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateSubresourceSurface: CreateSubresourceSurface::<Identity>,
+            CreateSharedHandle: CreateSharedHandle::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Security")]
 impl windows_core::RuntimeName for IDXGIResource1 {}
 windows_core::imp::define_interface!(IDXGISurface, IDXGISurface_Vtbl, 0xcafcb56c_6ac3_4889_bf47_9e23bbd260ec);
 impl core::ops::Deref for IDXGISurface {
@@ -5109,6 +6744,44 @@ impl IDXGISurface_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDXGISurface_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGISurface_Vtbl
+where
+    IDXGIDeviceSubObject_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + IDXGISurface_Impl>(this: *mut core::ffi::c_void, pdesc: *mut DXGI_SURFACE_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGISurface_Impl::GetDesc(this) {
+                    Ok(ok__) => {
+                        pdesc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Map<Identity: windows_core::IUnknownImpl + IDXGISurface_Impl>(this: *mut core::ffi::c_void, plockedrect: *mut DXGI_MAPPED_RECT, mapflags: DXGI_MAP_FLAGS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISurface_Impl::Map(this, core::mem::transmute_copy(&plockedrect), core::mem::transmute_copy(&mapflags)).into()
+            }
+        }
+        unsafe extern "system" fn Unmap<Identity: windows_core::IUnknownImpl + IDXGISurface_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISurface_Impl::Unmap(this).into()
+            }
+        }
+        Self {
+            base__: <IDXGIDeviceSubObject_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDesc: GetDesc::<Identity>,
+            Map: Map::<Identity>,
+            Unmap: Unmap::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IDXGISurface {}
 windows_core::imp::define_interface!(IDXGISurface1, IDXGISurface1_Vtbl, 0x4ae63092_6327_4c1b_80ae_bfe12ea32b86);
 impl core::ops::Deref for IDXGISurface1 {
@@ -5175,6 +6848,33 @@ impl IDXGISurface1_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
+impl<Identity: windows_core::IUnknownImpl + IDXGISurface1_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGISurface1_Vtbl
+where
+    IDXGISurface_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDC<Identity: windows_core::IUnknownImpl + IDXGISurface1_Impl>(this: *mut core::ffi::c_void, discard: windows_core::BOOL, phdc: *mut super::Gdi::HDC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGISurface1_Impl::GetDC(this, core::mem::transmute_copy(&discard)) {
+                    Ok(ok__) => {
+                        phdc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReleaseDC<Identity: windows_core::IUnknownImpl + IDXGISurface1_Impl>(this: *mut core::ffi::c_void, pdirtyrect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISurface1_Impl::ReleaseDC(this, core::mem::transmute_copy(&pdirtyrect)).into()
+            }
+        }
+        Self { base__: <IDXGISurface_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDC: GetDC::<Identity>, ReleaseDC: ReleaseDC::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
 impl windows_core::RuntimeName for IDXGISurface1 {}
 windows_core::imp::define_interface!(IDXGISurface2, IDXGISurface2_Vtbl, 0xaba496dd_b617_4cb8_a866_bc44d7eb1fa2);
 impl core::ops::Deref for IDXGISurface2 {
@@ -5221,6 +6921,21 @@ impl IDXGISurface2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
+impl<Identity: windows_core::IUnknownImpl + IDXGISurface2_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGISurface2_Vtbl
+where
+    IDXGISurface1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetResource<Identity: windows_core::IUnknownImpl + IDXGISurface2_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppparentresource: *mut *mut core::ffi::c_void, psubresourceindex: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISurface2_Impl::GetResource(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppparentresource), core::mem::transmute_copy(&psubresourceindex)).into()
+            }
+        }
+        Self { base__: <IDXGISurface1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetResource: GetResource::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
 impl windows_core::RuntimeName for IDXGISurface2 {}
 windows_core::imp::define_interface!(IDXGISwapChain, IDXGISwapChain_Vtbl, 0x310d36a0_d2e7_4c0a_aa04_6a9d23b8886a);
 impl core::ops::Deref for IDXGISwapChain {
@@ -5420,6 +7135,105 @@ impl IDXGISwapChain_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDXGISwapChain_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGISwapChain_Vtbl
+where
+    IDXGIDeviceSubObject_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Present<Identity: windows_core::IUnknownImpl + IDXGISwapChain_Impl>(this: *mut core::ffi::c_void, syncinterval: u32, flags: DXGI_PRESENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChain_Impl::Present(this, core::mem::transmute_copy(&syncinterval), core::mem::transmute_copy(&flags))
+            }
+        }
+        unsafe extern "system" fn GetBuffer<Identity: windows_core::IUnknownImpl + IDXGISwapChain_Impl>(this: *mut core::ffi::c_void, buffer: u32, riid: *const windows_core::GUID, ppsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChain_Impl::GetBuffer(this, core::mem::transmute_copy(&buffer), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppsurface)).into()
+            }
+        }
+        unsafe extern "system" fn SetFullscreenState<Identity: windows_core::IUnknownImpl + IDXGISwapChain_Impl>(this: *mut core::ffi::c_void, fullscreen: windows_core::BOOL, ptarget: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChain_Impl::SetFullscreenState(this, core::mem::transmute_copy(&fullscreen), core::mem::transmute_copy(&ptarget)).into()
+            }
+        }
+        unsafe extern "system" fn GetFullscreenState<Identity: windows_core::IUnknownImpl + IDXGISwapChain_Impl>(this: *mut core::ffi::c_void, pfullscreen: *mut windows_core::BOOL, pptarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChain_Impl::GetFullscreenState(this, core::mem::transmute_copy(&pfullscreen), core::mem::transmute_copy(&pptarget)).into()
+            }
+        }
+        unsafe extern "system" fn GetDesc<Identity: windows_core::IUnknownImpl + IDXGISwapChain_Impl>(this: *mut core::ffi::c_void, pdesc: *mut DXGI_SWAP_CHAIN_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGISwapChain_Impl::GetDesc(this) {
+                    Ok(ok__) => {
+                        pdesc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ResizeBuffers<Identity: windows_core::IUnknownImpl + IDXGISwapChain_Impl>(this: *mut core::ffi::c_void, buffercount: u32, width: u32, height: u32, newformat: Common::DXGI_FORMAT, swapchainflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChain_Impl::ResizeBuffers(this, core::mem::transmute_copy(&buffercount), core::mem::transmute_copy(&width), core::mem::transmute_copy(&height), core::mem::transmute_copy(&newformat), core::mem::transmute(&swapchainflags)).into()
+            }
+        }
+        unsafe extern "system" fn ResizeTarget<Identity: windows_core::IUnknownImpl + IDXGISwapChain_Impl>(this: *mut core::ffi::c_void, pnewtargetparameters: *const Common::DXGI_MODE_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChain_Impl::ResizeTarget(this, core::mem::transmute_copy(&pnewtargetparameters)).into()
+            }
+        }
+        unsafe extern "system" fn GetContainingOutput<Identity: windows_core::IUnknownImpl + IDXGISwapChain_Impl>(this: *mut core::ffi::c_void, ppoutput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGISwapChain_Impl::GetContainingOutput(this) {
+                    Ok(ok__) => {
+                        ppoutput.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFrameStatistics<Identity: windows_core::IUnknownImpl + IDXGISwapChain_Impl>(this: *mut core::ffi::c_void, pstats: *mut DXGI_FRAME_STATISTICS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChain_Impl::GetFrameStatistics(this, core::mem::transmute_copy(&pstats)).into()
+            }
+        }
+        unsafe extern "system" fn GetLastPresentCount<Identity: windows_core::IUnknownImpl + IDXGISwapChain_Impl>(this: *mut core::ffi::c_void, plastpresentcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGISwapChain_Impl::GetLastPresentCount(this) {
+                    Ok(ok__) => {
+                        plastpresentcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDXGIDeviceSubObject_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Present: Present::<Identity>,
+            GetBuffer: GetBuffer::<Identity>,
+            SetFullscreenState: SetFullscreenState::<Identity>,
+            GetFullscreenState: GetFullscreenState::<Identity>,
+            GetDesc: GetDesc::<Identity>,
+            ResizeBuffers: ResizeBuffers::<Identity>,
+            ResizeTarget: ResizeTarget::<Identity>,
+            GetContainingOutput: GetContainingOutput::<Identity>,
+            GetFrameStatistics: GetFrameStatistics::<Identity>,
+            GetLastPresentCount: GetLastPresentCount::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IDXGISwapChain {}
 windows_core::imp::define_interface!(IDXGISwapChain1, IDXGISwapChain1_Vtbl, 0x790a45f7_0d42_4876_983a_0a55cfe6f4aa);
 impl core::ops::Deref for IDXGISwapChain1 {
@@ -5659,6 +7473,130 @@ impl IDXGISwapChain1_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDXGISwapChain1_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGISwapChain1_Vtbl
+where
+    IDXGISwapChain_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDesc1<Identity: windows_core::IUnknownImpl + IDXGISwapChain1_Impl>(this: *mut core::ffi::c_void, pdesc: *mut DXGI_SWAP_CHAIN_DESC1) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGISwapChain1_Impl::GetDesc1(this) {
+                    Ok(ok__) => {
+                        pdesc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFullscreenDesc<Identity: windows_core::IUnknownImpl + IDXGISwapChain1_Impl>(this: *mut core::ffi::c_void, pdesc: *mut DXGI_SWAP_CHAIN_FULLSCREEN_DESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGISwapChain1_Impl::GetFullscreenDesc(this) {
+                    Ok(ok__) => {
+                        pdesc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHwnd<Identity: windows_core::IUnknownImpl + IDXGISwapChain1_Impl>(this: *mut core::ffi::c_void, phwnd: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGISwapChain1_Impl::GetHwnd(this) {
+                    Ok(ok__) => {
+                        phwnd.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCoreWindow<Identity: windows_core::IUnknownImpl + IDXGISwapChain1_Impl>(this: *mut core::ffi::c_void, refiid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChain1_Impl::GetCoreWindow(this, core::mem::transmute_copy(&refiid), core::mem::transmute_copy(&ppunk)).into()
+            }
+        }
+        unsafe extern "system" fn Present1<Identity: windows_core::IUnknownImpl + IDXGISwapChain1_Impl>(this: *mut core::ffi::c_void, syncinterval: u32, presentflags: DXGI_PRESENT, ppresentparameters: *const DXGI_PRESENT_PARAMETERS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChain1_Impl::Present1(this, core::mem::transmute_copy(&syncinterval), core::mem::transmute_copy(&presentflags), core::mem::transmute_copy(&ppresentparameters))
+            }
+        }
+        unsafe extern "system" fn IsTemporaryMonoSupported<Identity: windows_core::IUnknownImpl + IDXGISwapChain1_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChain1_Impl::IsTemporaryMonoSupported(this)
+            }
+        }
+        unsafe extern "system" fn GetRestrictToOutput<Identity: windows_core::IUnknownImpl + IDXGISwapChain1_Impl>(this: *mut core::ffi::c_void, pprestricttooutput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGISwapChain1_Impl::GetRestrictToOutput(this) {
+                    Ok(ok__) => {
+                        pprestricttooutput.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBackgroundColor<Identity: windows_core::IUnknownImpl + IDXGISwapChain1_Impl>(this: *mut core::ffi::c_void, pcolor: *const DXGI_RGBA) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChain1_Impl::SetBackgroundColor(this, core::mem::transmute_copy(&pcolor)).into()
+            }
+        }
+        unsafe extern "system" fn GetBackgroundColor<Identity: windows_core::IUnknownImpl + IDXGISwapChain1_Impl>(this: *mut core::ffi::c_void, pcolor: *mut DXGI_RGBA) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGISwapChain1_Impl::GetBackgroundColor(this) {
+                    Ok(ok__) => {
+                        pcolor.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRotation<Identity: windows_core::IUnknownImpl + IDXGISwapChain1_Impl>(this: *mut core::ffi::c_void, rotation: Common::DXGI_MODE_ROTATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChain1_Impl::SetRotation(this, core::mem::transmute_copy(&rotation)).into()
+            }
+        }
+        unsafe extern "system" fn GetRotation<Identity: windows_core::IUnknownImpl + IDXGISwapChain1_Impl>(this: *mut core::ffi::c_void, protation: *mut Common::DXGI_MODE_ROTATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGISwapChain1_Impl::GetRotation(this) {
+                    Ok(ok__) => {
+                        protation.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDXGISwapChain_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDesc1: GetDesc1::<Identity>,
+            GetFullscreenDesc: GetFullscreenDesc::<Identity>,
+            GetHwnd: GetHwnd::<Identity>,
+            GetCoreWindow: GetCoreWindow::<Identity>,
+            Present1: Present1::<Identity>,
+            IsTemporaryMonoSupported: IsTemporaryMonoSupported::<Identity>,
+            GetRestrictToOutput: GetRestrictToOutput::<Identity>,
+            SetBackgroundColor: SetBackgroundColor::<Identity>,
+            GetBackgroundColor: GetBackgroundColor::<Identity>,
+            SetRotation: SetRotation::<Identity>,
+            GetRotation: GetRotation::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IDXGISwapChain1 {}
 windows_core::imp::define_interface!(IDXGISwapChain2, IDXGISwapChain2_Vtbl, 0xa8be2ac4_199f_4946_b331_79599fb98de7);
 impl core::ops::Deref for IDXGISwapChain2 {
@@ -5785,6 +7723,72 @@ impl IDXGISwapChain2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDXGISwapChain2_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGISwapChain2_Vtbl
+where
+    IDXGISwapChain1_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetSourceSize<Identity: windows_core::IUnknownImpl + IDXGISwapChain2_Impl>(this: *mut core::ffi::c_void, width: u32, height: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChain2_Impl::SetSourceSize(this, core::mem::transmute_copy(&width), core::mem::transmute_copy(&height)).into()
+            }
+        }
+        unsafe extern "system" fn GetSourceSize<Identity: windows_core::IUnknownImpl + IDXGISwapChain2_Impl>(this: *mut core::ffi::c_void, pwidth: *mut u32, pheight: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChain2_Impl::GetSourceSize(this, core::mem::transmute_copy(&pwidth), core::mem::transmute_copy(&pheight)).into()
+            }
+        }
+        unsafe extern "system" fn SetMaximumFrameLatency<Identity: windows_core::IUnknownImpl + IDXGISwapChain2_Impl>(this: *mut core::ffi::c_void, maxlatency: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChain2_Impl::SetMaximumFrameLatency(this, core::mem::transmute_copy(&maxlatency)).into()
+            }
+        }
+        unsafe extern "system" fn GetMaximumFrameLatency<Identity: windows_core::IUnknownImpl + IDXGISwapChain2_Impl>(this: *mut core::ffi::c_void, pmaxlatency: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGISwapChain2_Impl::GetMaximumFrameLatency(this) {
+                    Ok(ok__) => {
+                        pmaxlatency.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFrameLatencyWaitableObject<Identity: windows_core::IUnknownImpl + IDXGISwapChain2_Impl>(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChain2_Impl::GetFrameLatencyWaitableObject(this)
+            }
+        }
+        unsafe extern "system" fn SetMatrixTransform<Identity: windows_core::IUnknownImpl + IDXGISwapChain2_Impl>(this: *mut core::ffi::c_void, pmatrix: *const DXGI_MATRIX_3X2_F) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChain2_Impl::SetMatrixTransform(this, core::mem::transmute_copy(&pmatrix)).into()
+            }
+        }
+        unsafe extern "system" fn GetMatrixTransform<Identity: windows_core::IUnknownImpl + IDXGISwapChain2_Impl>(this: *mut core::ffi::c_void, pmatrix: *mut DXGI_MATRIX_3X2_F) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChain2_Impl::GetMatrixTransform(this, core::mem::transmute_copy(&pmatrix)).into()
+            }
+        }
+        Self {
+            base__: <IDXGISwapChain1_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetSourceSize: SetSourceSize::<Identity>,
+            GetSourceSize: GetSourceSize::<Identity>,
+            SetMaximumFrameLatency: SetMaximumFrameLatency::<Identity>,
+            GetMaximumFrameLatency: GetMaximumFrameLatency::<Identity>,
+            GetFrameLatencyWaitableObject: GetFrameLatencyWaitableObject::<Identity>,
+            SetMatrixTransform: SetMatrixTransform::<Identity>,
+            GetMatrixTransform: GetMatrixTransform::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IDXGISwapChain2 {}
 windows_core::imp::define_interface!(IDXGISwapChain3, IDXGISwapChain3_Vtbl, 0x94d99bdb_f1f8_4ab0_b236_7da0170edab1);
 impl core::ops::Deref for IDXGISwapChain3 {
@@ -5887,6 +7891,51 @@ impl IDXGISwapChain3_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDXGISwapChain3_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGISwapChain3_Vtbl
+where
+    IDXGISwapChain2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrentBackBufferIndex<Identity: windows_core::IUnknownImpl + IDXGISwapChain3_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChain3_Impl::GetCurrentBackBufferIndex(this)
+            }
+        }
+        unsafe extern "system" fn CheckColorSpaceSupport<Identity: windows_core::IUnknownImpl + IDXGISwapChain3_Impl>(this: *mut core::ffi::c_void, colorspace: Common::DXGI_COLOR_SPACE_TYPE, pcolorspacesupport: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDXGISwapChain3_Impl::CheckColorSpaceSupport(this, core::mem::transmute_copy(&colorspace)) {
+                    Ok(ok__) => {
+                        pcolorspacesupport.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetColorSpace1<Identity: windows_core::IUnknownImpl + IDXGISwapChain3_Impl>(this: *mut core::ffi::c_void, colorspace: Common::DXGI_COLOR_SPACE_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChain3_Impl::SetColorSpace1(this, core::mem::transmute_copy(&colorspace)).into()
+            }
+        }
+        unsafe extern "system" fn ResizeBuffers1<Identity: windows_core::IUnknownImpl + IDXGISwapChain3_Impl>(this: *mut core::ffi::c_void, buffercount: u32, width: u32, height: u32, format: Common::DXGI_FORMAT, swapchainflags: u32, pcreationnodemask: *const u32, pppresentqueue: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChain3_Impl::ResizeBuffers1(this, core::mem::transmute_copy(&buffercount), core::mem::transmute_copy(&width), core::mem::transmute_copy(&height), core::mem::transmute_copy(&format), core::mem::transmute(&swapchainflags), core::mem::transmute_copy(&pcreationnodemask), core::mem::transmute_copy(&pppresentqueue)).into()
+            }
+        }
+        Self {
+            base__: <IDXGISwapChain2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrentBackBufferIndex: GetCurrentBackBufferIndex::<Identity>,
+            CheckColorSpaceSupport: CheckColorSpaceSupport::<Identity>,
+            SetColorSpace1: SetColorSpace1::<Identity>,
+            ResizeBuffers1: ResizeBuffers1::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IDXGISwapChain3 {}
 windows_core::imp::define_interface!(IDXGISwapChain4, IDXGISwapChain4_Vtbl, 0x3d585d5a_bd4a_489e_b1f4_3dbcb6452ffb);
 impl core::ops::Deref for IDXGISwapChain4 {
@@ -5929,6 +7978,21 @@ impl IDXGISwapChain4_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IDXGISwapChain4_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGISwapChain4_Vtbl
+where
+    IDXGISwapChain3_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetHDRMetaData<Identity: windows_core::IUnknownImpl + IDXGISwapChain4_Impl>(this: *mut core::ffi::c_void, r#type: DXGI_HDR_METADATA_TYPE, size: u32, pmetadata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChain4_Impl::SetHDRMetaData(this, core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&size), core::mem::transmute_copy(&pmetadata)).into()
+            }
+        }
+        Self { base__: <IDXGISwapChain3_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetHDRMetaData: SetHDRMetaData::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IDXGISwapChain4 {}
 windows_core::imp::define_interface!(IDXGISwapChainMedia, IDXGISwapChainMedia_Vtbl, 0xdd95b90b_f05f_4f6a_bd65_25bfb264bd84);
 windows_core::imp::interface_hierarchy!(IDXGISwapChainMedia, windows_core::IUnknown);
@@ -5989,6 +8053,37 @@ impl IDXGISwapChainMedia_Vtbl {
         iid == &<IDXGISwapChainMedia as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDXGISwapChainMedia_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGISwapChainMedia_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFrameStatisticsMedia<Identity: windows_core::IUnknownImpl + IDXGISwapChainMedia_Impl>(this: *mut core::ffi::c_void, pstats: *mut DXGI_FRAME_STATISTICS_MEDIA) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChainMedia_Impl::GetFrameStatisticsMedia(this, core::mem::transmute_copy(&pstats)).into()
+            }
+        }
+        unsafe extern "system" fn SetPresentDuration<Identity: windows_core::IUnknownImpl + IDXGISwapChainMedia_Impl>(this: *mut core::ffi::c_void, duration: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChainMedia_Impl::SetPresentDuration(this, core::mem::transmute_copy(&duration)).into()
+            }
+        }
+        unsafe extern "system" fn CheckPresentDurationSupport<Identity: windows_core::IUnknownImpl + IDXGISwapChainMedia_Impl>(this: *mut core::ffi::c_void, desiredpresentduration: u32, pclosestsmallerpresentduration: *mut u32, pclosestlargerpresentduration: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGISwapChainMedia_Impl::CheckPresentDurationSupport(this, core::mem::transmute_copy(&desiredpresentduration), core::mem::transmute_copy(&pclosestsmallerpresentduration), core::mem::transmute_copy(&pclosestlargerpresentduration)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFrameStatisticsMedia: GetFrameStatisticsMedia::<Identity>,
+            SetPresentDuration: SetPresentDuration::<Identity>,
+            CheckPresentDurationSupport: CheckPresentDurationSupport::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDXGISwapChainMedia {}
 windows_core::imp::define_interface!(IDXGraphicsAnalysis, IDXGraphicsAnalysis_Vtbl, 0x9f251514_9d4d_4902_9d60_18988ab7d4b5);
 windows_core::imp::interface_hierarchy!(IDXGraphicsAnalysis, windows_core::IUnknown);
@@ -6035,4 +8130,28 @@ impl IDXGraphicsAnalysis_Vtbl {
         iid == &<IDXGraphicsAnalysis as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDXGraphicsAnalysis_Impl> windows_core::imp::TearOffVtable<Identity> for IDXGraphicsAnalysis_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BeginCapture<Identity: windows_core::IUnknownImpl + IDXGraphicsAnalysis_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGraphicsAnalysis_Impl::BeginCapture(this)
+            }
+        }
+        unsafe extern "system" fn EndCapture<Identity: windows_core::IUnknownImpl + IDXGraphicsAnalysis_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDXGraphicsAnalysis_Impl::EndCapture(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            BeginCapture: BeginCapture::<Identity>,
+            EndCapture: EndCapture::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDXGraphicsAnalysis {}
@@ -4388,6 +4388,37 @@ impl IImageBytes_Vtbl {
         iid == &<IImageBytes as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IImageBytes_Impl> windows_core::imp::TearOffVtable<Identity> for IImageBytes_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CountBytes<Identity: windows_core::IUnknownImpl + IImageBytes_Impl>(this: *mut core::ffi::c_void, pcb: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IImageBytes_Impl::CountBytes(this, core::mem::transmute_copy(&pcb)).into()
+            }
+        }
+        unsafe extern "system" fn LockBytes<Identity: windows_core::IUnknownImpl + IImageBytes_Impl>(this: *mut core::ffi::c_void, cb: u32, uloffset: u32, ppvbytes: *const *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IImageBytes_Impl::LockBytes(this, core::mem::transmute_copy(&cb), core::mem::transmute_copy(&uloffset), core::mem::transmute_copy(&ppvbytes)).into()
+            }
+        }
+        unsafe extern "system" fn UnlockBytes<Identity: windows_core::IUnknownImpl + IImageBytes_Impl>(this: *mut core::ffi::c_void, pvbytes: *const core::ffi::c_void, cb: u32, uloffset: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IImageBytes_Impl::UnlockBytes(this, core::mem::transmute_copy(&pvbytes), core::mem::transmute_copy(&cb), core::mem::transmute_copy(&uloffset)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CountBytes: CountBytes::<Identity>,
+            LockBytes: LockBytes::<Identity>,
+            UnlockBytes: UnlockBytes::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IImageBytes {}
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
@@ -425,6 +425,43 @@ impl IWICBitmap_Vtbl {
         iid == &<IWICBitmap as windows_core::Interface>::IID || iid == &<IWICBitmapSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICBitmap_Impl> windows_core::imp::TearOffVtable<Identity> for IWICBitmap_Vtbl
+where
+    IWICBitmapSource_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Lock<Identity: windows_core::IUnknownImpl + IWICBitmap_Impl>(this: *mut core::ffi::c_void, prclock: *const WICRect, flags: u32, ppilock: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmap_Impl::Lock(this, core::mem::transmute_copy(&prclock), core::mem::transmute_copy(&flags)) {
+                    Ok(ok__) => {
+                        ppilock.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPalette<Identity: windows_core::IUnknownImpl + IWICBitmap_Impl>(this: *mut core::ffi::c_void, pipalette: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmap_Impl::SetPalette(this, core::mem::transmute_copy(&pipalette)).into()
+            }
+        }
+        unsafe extern "system" fn SetResolution<Identity: windows_core::IUnknownImpl + IWICBitmap_Impl>(this: *mut core::ffi::c_void, dpix: f64, dpiy: f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmap_Impl::SetResolution(this, core::mem::transmute_copy(&dpix), core::mem::transmute_copy(&dpiy)).into()
+            }
+        }
+        Self {
+            base__: <IWICBitmapSource_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Lock: Lock::<Identity>,
+            SetPalette: SetPalette::<Identity>,
+            SetResolution: SetResolution::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWICBitmap {}
 windows_core::imp::define_interface!(IWICBitmapClipper, IWICBitmapClipper_Vtbl, 0xe4fbcf03_223d_4e81_9333_d635556dd1b5);
 impl core::ops::Deref for IWICBitmapClipper {
@@ -465,6 +502,20 @@ impl IWICBitmapClipper_Vtbl {
         iid == &<IWICBitmapClipper as windows_core::Interface>::IID || iid == &<IWICBitmapSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICBitmapClipper_Impl> windows_core::imp::TearOffVtable<Identity> for IWICBitmapClipper_Vtbl
+where
+    IWICBitmapSource_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IWICBitmapClipper_Impl>(this: *mut core::ffi::c_void, pisource: *mut core::ffi::c_void, prc: *const WICRect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapClipper_Impl::Initialize(this, core::mem::transmute_copy(&pisource), core::mem::transmute_copy(&prc)).into()
+            }
+        }
+        Self { base__: <IWICBitmapSource_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Initialize: Initialize::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWICBitmapClipper {}
 windows_core::imp::define_interface!(IWICBitmapCodecInfo, IWICBitmapCodecInfo_Vtbl, 0xe87a44c4_b76e_4c47_8b09_298eb12a2714);
 impl core::ops::Deref for IWICBitmapCodecInfo {
@@ -694,6 +745,136 @@ impl IWICBitmapCodecInfo_Vtbl {
         iid == &<IWICBitmapCodecInfo as windows_core::Interface>::IID || iid == &<IWICComponentInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICBitmapCodecInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IWICBitmapCodecInfo_Vtbl
+where
+    IWICComponentInfo_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetContainerFormat<Identity: windows_core::IUnknownImpl + IWICBitmapCodecInfo_Impl>(this: *mut core::ffi::c_void, pguidcontainerformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapCodecInfo_Impl::GetContainerFormat(this) {
+                    Ok(ok__) => {
+                        pguidcontainerformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPixelFormats<Identity: windows_core::IUnknownImpl + IWICBitmapCodecInfo_Impl>(this: *mut core::ffi::c_void, cformats: u32, pguidpixelformats: *mut windows_core::GUID, pcactual: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapCodecInfo_Impl::GetPixelFormats(this, core::mem::transmute_copy(&cformats), core::mem::transmute_copy(&pguidpixelformats), core::mem::transmute_copy(&pcactual)).into()
+            }
+        }
+        unsafe extern "system" fn GetColorManagementVersion<Identity: windows_core::IUnknownImpl + IWICBitmapCodecInfo_Impl>(this: *mut core::ffi::c_void, cchcolormanagementversion: u32, wzcolormanagementversion: windows_core::PWSTR, pcchactual: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapCodecInfo_Impl::GetColorManagementVersion(this, core::mem::transmute_copy(&cchcolormanagementversion), core::mem::transmute_copy(&wzcolormanagementversion), core::mem::transmute_copy(&pcchactual)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceManufacturer<Identity: windows_core::IUnknownImpl + IWICBitmapCodecInfo_Impl>(this: *mut core::ffi::c_void, cchdevicemanufacturer: u32, wzdevicemanufacturer: windows_core::PWSTR, pcchactual: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapCodecInfo_Impl::GetDeviceManufacturer(this, core::mem::transmute_copy(&cchdevicemanufacturer), core::mem::transmute_copy(&wzdevicemanufacturer), core::mem::transmute_copy(&pcchactual)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceModels<Identity: windows_core::IUnknownImpl + IWICBitmapCodecInfo_Impl>(this: *mut core::ffi::c_void, cchdevicemodels: u32, wzdevicemodels: windows_core::PWSTR, pcchactual: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapCodecInfo_Impl::GetDeviceModels(this, core::mem::transmute_copy(&cchdevicemodels), core::mem::transmute_copy(&wzdevicemodels), core::mem::transmute_copy(&pcchactual)).into()
+            }
+        }
+        unsafe extern "system" fn GetMimeTypes<Identity: windows_core::IUnknownImpl + IWICBitmapCodecInfo_Impl>(this: *mut core::ffi::c_void, cchmimetypes: u32, wzmimetypes: windows_core::PWSTR, pcchactual: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapCodecInfo_Impl::GetMimeTypes(this, core::mem::transmute_copy(&cchmimetypes), core::mem::transmute_copy(&wzmimetypes), core::mem::transmute_copy(&pcchactual)).into()
+            }
+        }
+        unsafe extern "system" fn GetFileExtensions<Identity: windows_core::IUnknownImpl + IWICBitmapCodecInfo_Impl>(this: *mut core::ffi::c_void, cchfileextensions: u32, wzfileextensions: windows_core::PWSTR, pcchactual: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapCodecInfo_Impl::GetFileExtensions(this, core::mem::transmute_copy(&cchfileextensions), core::mem::transmute_copy(&wzfileextensions), core::mem::transmute_copy(&pcchactual)).into()
+            }
+        }
+        unsafe extern "system" fn DoesSupportAnimation<Identity: windows_core::IUnknownImpl + IWICBitmapCodecInfo_Impl>(this: *mut core::ffi::c_void, pfsupportanimation: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapCodecInfo_Impl::DoesSupportAnimation(this) {
+                    Ok(ok__) => {
+                        pfsupportanimation.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DoesSupportChromakey<Identity: windows_core::IUnknownImpl + IWICBitmapCodecInfo_Impl>(this: *mut core::ffi::c_void, pfsupportchromakey: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapCodecInfo_Impl::DoesSupportChromakey(this) {
+                    Ok(ok__) => {
+                        pfsupportchromakey.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DoesSupportLossless<Identity: windows_core::IUnknownImpl + IWICBitmapCodecInfo_Impl>(this: *mut core::ffi::c_void, pfsupportlossless: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapCodecInfo_Impl::DoesSupportLossless(this) {
+                    Ok(ok__) => {
+                        pfsupportlossless.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DoesSupportMultiframe<Identity: windows_core::IUnknownImpl + IWICBitmapCodecInfo_Impl>(this: *mut core::ffi::c_void, pfsupportmultiframe: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapCodecInfo_Impl::DoesSupportMultiframe(this) {
+                    Ok(ok__) => {
+                        pfsupportmultiframe.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MatchesMimeType<Identity: windows_core::IUnknownImpl + IWICBitmapCodecInfo_Impl>(this: *mut core::ffi::c_void, wzmimetype: windows_core::PCWSTR, pfmatches: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapCodecInfo_Impl::MatchesMimeType(this, core::mem::transmute(&wzmimetype)) {
+                    Ok(ok__) => {
+                        pfmatches.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWICComponentInfo_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetContainerFormat: GetContainerFormat::<Identity>,
+            GetPixelFormats: GetPixelFormats::<Identity>,
+            GetColorManagementVersion: GetColorManagementVersion::<Identity>,
+            GetDeviceManufacturer: GetDeviceManufacturer::<Identity>,
+            GetDeviceModels: GetDeviceModels::<Identity>,
+            GetMimeTypes: GetMimeTypes::<Identity>,
+            GetFileExtensions: GetFileExtensions::<Identity>,
+            DoesSupportAnimation: DoesSupportAnimation::<Identity>,
+            DoesSupportChromakey: DoesSupportChromakey::<Identity>,
+            DoesSupportLossless: DoesSupportLossless::<Identity>,
+            DoesSupportMultiframe: DoesSupportMultiframe::<Identity>,
+            MatchesMimeType: MatchesMimeType::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWICBitmapCodecInfo {}
 windows_core::imp::define_interface!(IWICBitmapCodecProgressNotification, IWICBitmapCodecProgressNotification_Vtbl, 0x64c1024e_c3cf_4462_8078_88c2b11c46d9);
 windows_core::imp::interface_hierarchy!(IWICBitmapCodecProgressNotification, windows_core::IUnknown);
@@ -725,6 +906,23 @@ impl IWICBitmapCodecProgressNotification_Vtbl {
         iid == &<IWICBitmapCodecProgressNotification as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICBitmapCodecProgressNotification_Impl> windows_core::imp::TearOffVtable<Identity> for IWICBitmapCodecProgressNotification_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterProgressNotification<Identity: windows_core::IUnknownImpl + IWICBitmapCodecProgressNotification_Impl>(this: *mut core::ffi::c_void, pfnprogressnotification: PFNProgressNotification, pvdata: *const core::ffi::c_void, dwprogressflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapCodecProgressNotification_Impl::RegisterProgressNotification(this, core::mem::transmute_copy(&pfnprogressnotification), core::mem::transmute_copy(&pvdata), core::mem::transmute_copy(&dwprogressflags)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterProgressNotification: RegisterProgressNotification::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWICBitmapCodecProgressNotification {}
 windows_core::imp::define_interface!(IWICBitmapDecoder, IWICBitmapDecoder_Vtbl, 0x9edde9e7_8dee_47ea_99df_e6faf2ed44bf);
 windows_core::imp::interface_hierarchy!(IWICBitmapDecoder, windows_core::IUnknown);
@@ -971,6 +1169,142 @@ impl IWICBitmapDecoder_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IWICBitmapDecoder_Impl> windows_core::imp::TearOffVtable<Identity> for IWICBitmapDecoder_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryCapability<Identity: windows_core::IUnknownImpl + IWICBitmapDecoder_Impl>(this: *mut core::ffi::c_void, pistream: *mut core::ffi::c_void, pdwcapability: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapDecoder_Impl::QueryCapability(this, core::mem::transmute_copy(&pistream)) {
+                    Ok(ok__) => {
+                        pdwcapability.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IWICBitmapDecoder_Impl>(this: *mut core::ffi::c_void, pistream: *mut core::ffi::c_void, cacheoptions: WICDecodeOptions) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapDecoder_Impl::Initialize(this, core::mem::transmute_copy(&pistream), core::mem::transmute_copy(&cacheoptions)).into()
+            }
+        }
+        unsafe extern "system" fn GetContainerFormat<Identity: windows_core::IUnknownImpl + IWICBitmapDecoder_Impl>(this: *mut core::ffi::c_void, pguidcontainerformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapDecoder_Impl::GetContainerFormat(this) {
+                    Ok(ok__) => {
+                        pguidcontainerformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDecoderInfo<Identity: windows_core::IUnknownImpl + IWICBitmapDecoder_Impl>(this: *mut core::ffi::c_void, ppidecoderinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapDecoder_Impl::GetDecoderInfo(this) {
+                    Ok(ok__) => {
+                        ppidecoderinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CopyPalette<Identity: windows_core::IUnknownImpl + IWICBitmapDecoder_Impl>(this: *mut core::ffi::c_void, pipalette: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapDecoder_Impl::CopyPalette(this, core::mem::transmute_copy(&pipalette)).into()
+            }
+        }
+        unsafe extern "system" fn GetMetadataQueryReader<Identity: windows_core::IUnknownImpl + IWICBitmapDecoder_Impl>(this: *mut core::ffi::c_void, ppimetadataqueryreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapDecoder_Impl::GetMetadataQueryReader(this) {
+                    Ok(ok__) => {
+                        ppimetadataqueryreader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPreview<Identity: windows_core::IUnknownImpl + IWICBitmapDecoder_Impl>(this: *mut core::ffi::c_void, ppibitmapsource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapDecoder_Impl::GetPreview(this) {
+                    Ok(ok__) => {
+                        ppibitmapsource.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetColorContexts<Identity: windows_core::IUnknownImpl + IWICBitmapDecoder_Impl>(this: *mut core::ffi::c_void, ccount: u32, ppicolorcontexts: *mut *mut core::ffi::c_void, pcactualcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapDecoder_Impl::GetColorContexts(this, core::mem::transmute_copy(&ccount), core::mem::transmute_copy(&ppicolorcontexts), core::mem::transmute_copy(&pcactualcount)).into()
+            }
+        }
+        unsafe extern "system" fn GetThumbnail<Identity: windows_core::IUnknownImpl + IWICBitmapDecoder_Impl>(this: *mut core::ffi::c_void, ppithumbnail: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapDecoder_Impl::GetThumbnail(this) {
+                    Ok(ok__) => {
+                        ppithumbnail.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFrameCount<Identity: windows_core::IUnknownImpl + IWICBitmapDecoder_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapDecoder_Impl::GetFrameCount(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFrame<Identity: windows_core::IUnknownImpl + IWICBitmapDecoder_Impl>(this: *mut core::ffi::c_void, index: u32, ppibitmapframe: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapDecoder_Impl::GetFrame(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        ppibitmapframe.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryCapability: QueryCapability::<Identity>,
+            Initialize: Initialize::<Identity>,
+            GetContainerFormat: GetContainerFormat::<Identity>,
+            GetDecoderInfo: GetDecoderInfo::<Identity>,
+            CopyPalette: CopyPalette::<Identity>,
+            GetMetadataQueryReader: GetMetadataQueryReader::<Identity>,
+            GetPreview: GetPreview::<Identity>,
+            GetColorContexts: GetColorContexts::<Identity>,
+            GetThumbnail: GetThumbnail::<Identity>,
+            GetFrameCount: GetFrameCount::<Identity>,
+            GetFrame: GetFrame::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IWICBitmapDecoder {}
 windows_core::imp::define_interface!(IWICBitmapDecoderInfo, IWICBitmapDecoderInfo_Vtbl, 0xd8cd007f_d08f_4191_9bfc_236ea7f0e4b5);
 impl core::ops::Deref for IWICBitmapDecoderInfo {
@@ -1063,6 +1397,50 @@ impl IWICBitmapDecoderInfo_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IWICBitmapDecoderInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IWICBitmapDecoderInfo_Vtbl
+where
+    IWICBitmapCodecInfo_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPatterns<Identity: windows_core::IUnknownImpl + IWICBitmapDecoderInfo_Impl>(this: *mut core::ffi::c_void, cbsizepatterns: u32, ppatterns: *mut WICBitmapPattern, pcpatterns: *mut u32, pcbpatternsactual: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapDecoderInfo_Impl::GetPatterns(this, core::mem::transmute_copy(&cbsizepatterns), core::mem::transmute_copy(&ppatterns), core::mem::transmute_copy(&pcpatterns), core::mem::transmute_copy(&pcbpatternsactual)).into()
+            }
+        }
+        unsafe extern "system" fn MatchesPattern<Identity: windows_core::IUnknownImpl + IWICBitmapDecoderInfo_Impl>(this: *mut core::ffi::c_void, pistream: *mut core::ffi::c_void, pfmatches: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapDecoderInfo_Impl::MatchesPattern(this, core::mem::transmute_copy(&pistream)) {
+                    Ok(ok__) => {
+                        pfmatches.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateInstance<Identity: windows_core::IUnknownImpl + IWICBitmapDecoderInfo_Impl>(this: *mut core::ffi::c_void, ppibitmapdecoder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapDecoderInfo_Impl::CreateInstance(this) {
+                    Ok(ok__) => {
+                        ppibitmapdecoder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWICBitmapCodecInfo_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPatterns: GetPatterns::<Identity>,
+            MatchesPattern: MatchesPattern::<Identity>,
+            CreateInstance: CreateInstance::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IWICBitmapDecoderInfo {}
 windows_core::imp::define_interface!(IWICBitmapEncoder, IWICBitmapEncoder_Vtbl, 0x00000103_a8f2_4877_ba0a_fd2b6645fb94);
 windows_core::imp::interface_hierarchy!(IWICBitmapEncoder, windows_core::IUnknown);
@@ -1255,8 +1633,107 @@ impl IWICBitmapEncoder_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
-impl windows_core::RuntimeName for IWICBitmapEncoder {}
-windows_core::imp::define_interface!(IWICBitmapEncoderInfo, IWICBitmapEncoderInfo_Vtbl, 0x94c9b4ee_a09f_4f92_8a1e_4a9bce7e76fb);
+impl<Identity: windows_core::IUnknownImpl + IWICBitmapEncoder_Impl> windows_core::imp::TearOffVtable<Identity> for IWICBitmapEncoder_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IWICBitmapEncoder_Impl>(this: *mut core::ffi::c_void, pistream: *mut core::ffi::c_void, cacheoption: WICBitmapEncoderCacheOption) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapEncoder_Impl::Initialize(this, core::mem::transmute_copy(&pistream), core::mem::transmute_copy(&cacheoption)).into()
+            }
+        }
+        unsafe extern "system" fn GetContainerFormat<Identity: windows_core::IUnknownImpl + IWICBitmapEncoder_Impl>(this: *mut core::ffi::c_void, pguidcontainerformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapEncoder_Impl::GetContainerFormat(this) {
+                    Ok(ok__) => {
+                        pguidcontainerformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetEncoderInfo<Identity: windows_core::IUnknownImpl + IWICBitmapEncoder_Impl>(this: *mut core::ffi::c_void, ppiencoderinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapEncoder_Impl::GetEncoderInfo(this) {
+                    Ok(ok__) => {
+                        ppiencoderinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetColorContexts<Identity: windows_core::IUnknownImpl + IWICBitmapEncoder_Impl>(this: *mut core::ffi::c_void, ccount: u32, ppicolorcontext: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapEncoder_Impl::SetColorContexts(this, core::mem::transmute_copy(&ccount), core::mem::transmute_copy(&ppicolorcontext)).into()
+            }
+        }
+        unsafe extern "system" fn SetPalette<Identity: windows_core::IUnknownImpl + IWICBitmapEncoder_Impl>(this: *mut core::ffi::c_void, pipalette: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapEncoder_Impl::SetPalette(this, core::mem::transmute_copy(&pipalette)).into()
+            }
+        }
+        unsafe extern "system" fn SetThumbnail<Identity: windows_core::IUnknownImpl + IWICBitmapEncoder_Impl>(this: *mut core::ffi::c_void, pithumbnail: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapEncoder_Impl::SetThumbnail(this, core::mem::transmute_copy(&pithumbnail)).into()
+            }
+        }
+        unsafe extern "system" fn SetPreview<Identity: windows_core::IUnknownImpl + IWICBitmapEncoder_Impl>(this: *mut core::ffi::c_void, pipreview: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapEncoder_Impl::SetPreview(this, core::mem::transmute_copy(&pipreview)).into()
+            }
+        }
+        unsafe extern "system" fn CreateNewFrame<Identity: windows_core::IUnknownImpl + IWICBitmapEncoder_Impl>(this: *mut core::ffi::c_void, ppiframeencode: *mut *mut core::ffi::c_void, ppiencoderoptions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapEncoder_Impl::CreateNewFrame(this, core::mem::transmute_copy(&ppiframeencode), core::mem::transmute_copy(&ppiencoderoptions)).into()
+            }
+        }
+        unsafe extern "system" fn Commit<Identity: windows_core::IUnknownImpl + IWICBitmapEncoder_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapEncoder_Impl::Commit(this).into()
+            }
+        }
+        unsafe extern "system" fn GetMetadataQueryWriter<Identity: windows_core::IUnknownImpl + IWICBitmapEncoder_Impl>(this: *mut core::ffi::c_void, ppimetadataquerywriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapEncoder_Impl::GetMetadataQueryWriter(this) {
+                    Ok(ok__) => {
+                        ppimetadataquerywriter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            GetContainerFormat: GetContainerFormat::<Identity>,
+            GetEncoderInfo: GetEncoderInfo::<Identity>,
+            SetColorContexts: SetColorContexts::<Identity>,
+            SetPalette: SetPalette::<Identity>,
+            SetThumbnail: SetThumbnail::<Identity>,
+            SetPreview: SetPreview::<Identity>,
+            CreateNewFrame: CreateNewFrame::<Identity>,
+            Commit: Commit::<Identity>,
+            GetMetadataQueryWriter: GetMetadataQueryWriter::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::RuntimeName for IWICBitmapEncoder {}
+windows_core::imp::define_interface!(IWICBitmapEncoderInfo, IWICBitmapEncoderInfo_Vtbl, 0x94c9b4ee_a09f_4f92_8a1e_4a9bce7e76fb);
 impl core::ops::Deref for IWICBitmapEncoderInfo {
     type Target = IWICBitmapCodecInfo;
     fn deref(&self) -> &Self::Target {
@@ -1301,6 +1778,26 @@ impl IWICBitmapEncoderInfo_Vtbl {
         iid == &<IWICBitmapEncoderInfo as windows_core::Interface>::IID || iid == &<IWICComponentInfo as windows_core::Interface>::IID || iid == &<IWICBitmapCodecInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICBitmapEncoderInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IWICBitmapEncoderInfo_Vtbl
+where
+    IWICBitmapCodecInfo_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateInstance<Identity: windows_core::IUnknownImpl + IWICBitmapEncoderInfo_Impl>(this: *mut core::ffi::c_void, ppibitmapencoder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapEncoderInfo_Impl::CreateInstance(this) {
+                    Ok(ok__) => {
+                        ppibitmapencoder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IWICBitmapCodecInfo_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateInstance: CreateInstance::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWICBitmapEncoderInfo {}
 windows_core::imp::define_interface!(IWICBitmapFlipRotator, IWICBitmapFlipRotator_Vtbl, 0x5009834f_2d6a_41ce_9e1b_17c5aff7a782);
 impl core::ops::Deref for IWICBitmapFlipRotator {
@@ -1341,6 +1838,20 @@ impl IWICBitmapFlipRotator_Vtbl {
         iid == &<IWICBitmapFlipRotator as windows_core::Interface>::IID || iid == &<IWICBitmapSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICBitmapFlipRotator_Impl> windows_core::imp::TearOffVtable<Identity> for IWICBitmapFlipRotator_Vtbl
+where
+    IWICBitmapSource_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IWICBitmapFlipRotator_Impl>(this: *mut core::ffi::c_void, pisource: *mut core::ffi::c_void, options: WICBitmapTransformOptions) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapFlipRotator_Impl::Initialize(this, core::mem::transmute_copy(&pisource), core::mem::transmute_copy(&options)).into()
+            }
+        }
+        Self { base__: <IWICBitmapSource_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Initialize: Initialize::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWICBitmapFlipRotator {}
 windows_core::imp::define_interface!(IWICBitmapFrameDecode, IWICBitmapFrameDecode_Vtbl, 0x3b16811b_6a43_4ec9_a813_3d930c13b940);
 impl core::ops::Deref for IWICBitmapFrameDecode {
@@ -1423,6 +1934,49 @@ impl IWICBitmapFrameDecode_Vtbl {
         iid == &<IWICBitmapFrameDecode as windows_core::Interface>::IID || iid == &<IWICBitmapSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICBitmapFrameDecode_Impl> windows_core::imp::TearOffVtable<Identity> for IWICBitmapFrameDecode_Vtbl
+where
+    IWICBitmapSource_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMetadataQueryReader<Identity: windows_core::IUnknownImpl + IWICBitmapFrameDecode_Impl>(this: *mut core::ffi::c_void, ppimetadataqueryreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapFrameDecode_Impl::GetMetadataQueryReader(this) {
+                    Ok(ok__) => {
+                        ppimetadataqueryreader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetColorContexts<Identity: windows_core::IUnknownImpl + IWICBitmapFrameDecode_Impl>(this: *mut core::ffi::c_void, ccount: u32, ppicolorcontexts: *mut *mut core::ffi::c_void, pcactualcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapFrameDecode_Impl::GetColorContexts(this, core::mem::transmute_copy(&ccount), core::mem::transmute_copy(&ppicolorcontexts), core::mem::transmute_copy(&pcactualcount)).into()
+            }
+        }
+        unsafe extern "system" fn GetThumbnail<Identity: windows_core::IUnknownImpl + IWICBitmapFrameDecode_Impl>(this: *mut core::ffi::c_void, ppithumbnail: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapFrameDecode_Impl::GetThumbnail(this) {
+                    Ok(ok__) => {
+                        ppithumbnail.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWICBitmapSource_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetMetadataQueryReader: GetMetadataQueryReader::<Identity>,
+            GetColorContexts: GetColorContexts::<Identity>,
+            GetThumbnail: GetThumbnail::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWICBitmapFrameDecode {}
 windows_core::imp::define_interface!(IWICBitmapFrameEncode, IWICBitmapFrameEncode_Vtbl, 0x00000105_a8f2_4877_ba0a_fd2b6645fb94);
 windows_core::imp::interface_hierarchy!(IWICBitmapFrameEncode, windows_core::IUnknown);
@@ -1605,6 +2159,100 @@ impl IWICBitmapFrameEncode_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl<Identity: windows_core::IUnknownImpl + IWICBitmapFrameEncode_Impl> windows_core::imp::TearOffVtable<Identity> for IWICBitmapFrameEncode_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IWICBitmapFrameEncode_Impl>(this: *mut core::ffi::c_void, piencoderoptions: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapFrameEncode_Impl::Initialize(this, core::mem::transmute_copy(&piencoderoptions)).into()
+            }
+        }
+        unsafe extern "system" fn SetSize<Identity: windows_core::IUnknownImpl + IWICBitmapFrameEncode_Impl>(this: *mut core::ffi::c_void, uiwidth: u32, uiheight: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapFrameEncode_Impl::SetSize(this, core::mem::transmute_copy(&uiwidth), core::mem::transmute_copy(&uiheight)).into()
+            }
+        }
+        unsafe extern "system" fn SetResolution<Identity: windows_core::IUnknownImpl + IWICBitmapFrameEncode_Impl>(this: *mut core::ffi::c_void, dpix: f64, dpiy: f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapFrameEncode_Impl::SetResolution(this, core::mem::transmute_copy(&dpix), core::mem::transmute_copy(&dpiy)).into()
+            }
+        }
+        unsafe extern "system" fn SetPixelFormat<Identity: windows_core::IUnknownImpl + IWICBitmapFrameEncode_Impl>(this: *mut core::ffi::c_void, ppixelformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapFrameEncode_Impl::SetPixelFormat(this, core::mem::transmute_copy(&ppixelformat)).into()
+            }
+        }
+        unsafe extern "system" fn SetColorContexts<Identity: windows_core::IUnknownImpl + IWICBitmapFrameEncode_Impl>(this: *mut core::ffi::c_void, ccount: u32, ppicolorcontext: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapFrameEncode_Impl::SetColorContexts(this, core::mem::transmute_copy(&ccount), core::mem::transmute_copy(&ppicolorcontext)).into()
+            }
+        }
+        unsafe extern "system" fn SetPalette<Identity: windows_core::IUnknownImpl + IWICBitmapFrameEncode_Impl>(this: *mut core::ffi::c_void, pipalette: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapFrameEncode_Impl::SetPalette(this, core::mem::transmute_copy(&pipalette)).into()
+            }
+        }
+        unsafe extern "system" fn SetThumbnail<Identity: windows_core::IUnknownImpl + IWICBitmapFrameEncode_Impl>(this: *mut core::ffi::c_void, pithumbnail: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapFrameEncode_Impl::SetThumbnail(this, core::mem::transmute_copy(&pithumbnail)).into()
+            }
+        }
+        unsafe extern "system" fn WritePixels<Identity: windows_core::IUnknownImpl + IWICBitmapFrameEncode_Impl>(this: *mut core::ffi::c_void, linecount: u32, cbstride: u32, cbbuffersize: u32, pbpixels: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapFrameEncode_Impl::WritePixels(this, core::mem::transmute_copy(&linecount), core::mem::transmute_copy(&cbstride), core::mem::transmute_copy(&cbbuffersize), core::mem::transmute_copy(&pbpixels)).into()
+            }
+        }
+        unsafe extern "system" fn WriteSource<Identity: windows_core::IUnknownImpl + IWICBitmapFrameEncode_Impl>(this: *mut core::ffi::c_void, pibitmapsource: *mut core::ffi::c_void, prc: *const WICRect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapFrameEncode_Impl::WriteSource(this, core::mem::transmute_copy(&pibitmapsource), core::mem::transmute_copy(&prc)).into()
+            }
+        }
+        unsafe extern "system" fn Commit<Identity: windows_core::IUnknownImpl + IWICBitmapFrameEncode_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapFrameEncode_Impl::Commit(this).into()
+            }
+        }
+        unsafe extern "system" fn GetMetadataQueryWriter<Identity: windows_core::IUnknownImpl + IWICBitmapFrameEncode_Impl>(this: *mut core::ffi::c_void, ppimetadataquerywriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapFrameEncode_Impl::GetMetadataQueryWriter(this) {
+                    Ok(ok__) => {
+                        ppimetadataquerywriter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            SetSize: SetSize::<Identity>,
+            SetResolution: SetResolution::<Identity>,
+            SetPixelFormat: SetPixelFormat::<Identity>,
+            SetColorContexts: SetColorContexts::<Identity>,
+            SetPalette: SetPalette::<Identity>,
+            SetThumbnail: SetThumbnail::<Identity>,
+            WritePixels: WritePixels::<Identity>,
+            WriteSource: WriteSource::<Identity>,
+            Commit: Commit::<Identity>,
+            GetMetadataQueryWriter: GetMetadataQueryWriter::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
 impl windows_core::RuntimeName for IWICBitmapFrameEncode {}
 windows_core::imp::define_interface!(IWICBitmapLock, IWICBitmapLock_Vtbl, 0x00000123_a8f2_4877_ba0a_fd2b6645fb94);
 windows_core::imp::interface_hierarchy!(IWICBitmapLock, windows_core::IUnknown);
@@ -1693,6 +2341,56 @@ impl IWICBitmapLock_Vtbl {
         iid == &<IWICBitmapLock as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICBitmapLock_Impl> windows_core::imp::TearOffVtable<Identity> for IWICBitmapLock_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSize<Identity: windows_core::IUnknownImpl + IWICBitmapLock_Impl>(this: *mut core::ffi::c_void, puiwidth: *mut u32, puiheight: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapLock_Impl::GetSize(this, core::mem::transmute_copy(&puiwidth), core::mem::transmute_copy(&puiheight)).into()
+            }
+        }
+        unsafe extern "system" fn GetStride<Identity: windows_core::IUnknownImpl + IWICBitmapLock_Impl>(this: *mut core::ffi::c_void, pcbstride: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapLock_Impl::GetStride(this) {
+                    Ok(ok__) => {
+                        pcbstride.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDataPointer<Identity: windows_core::IUnknownImpl + IWICBitmapLock_Impl>(this: *mut core::ffi::c_void, pcbbuffersize: *mut u32, ppbdata: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapLock_Impl::GetDataPointer(this, core::mem::transmute_copy(&pcbbuffersize), core::mem::transmute_copy(&ppbdata)).into()
+            }
+        }
+        unsafe extern "system" fn GetPixelFormat<Identity: windows_core::IUnknownImpl + IWICBitmapLock_Impl>(this: *mut core::ffi::c_void, ppixelformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapLock_Impl::GetPixelFormat(this) {
+                    Ok(ok__) => {
+                        ppixelformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSize: GetSize::<Identity>,
+            GetStride: GetStride::<Identity>,
+            GetDataPointer: GetDataPointer::<Identity>,
+            GetPixelFormat: GetPixelFormat::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWICBitmapLock {}
 windows_core::imp::define_interface!(IWICBitmapScaler, IWICBitmapScaler_Vtbl, 0x00000302_a8f2_4877_ba0a_fd2b6645fb94);
 impl core::ops::Deref for IWICBitmapScaler {
@@ -1733,6 +2431,20 @@ impl IWICBitmapScaler_Vtbl {
         iid == &<IWICBitmapScaler as windows_core::Interface>::IID || iid == &<IWICBitmapSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICBitmapScaler_Impl> windows_core::imp::TearOffVtable<Identity> for IWICBitmapScaler_Vtbl
+where
+    IWICBitmapSource_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IWICBitmapScaler_Impl>(this: *mut core::ffi::c_void, pisource: *mut core::ffi::c_void, uiwidth: u32, uiheight: u32, mode: WICBitmapInterpolationMode) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapScaler_Impl::Initialize(this, core::mem::transmute_copy(&pisource), core::mem::transmute_copy(&uiwidth), core::mem::transmute_copy(&uiheight), core::mem::transmute_copy(&mode)).into()
+            }
+        }
+        Self { base__: <IWICBitmapSource_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Initialize: Initialize::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWICBitmapScaler {}
 windows_core::imp::define_interface!(IWICBitmapSource, IWICBitmapSource_Vtbl, 0x00000120_a8f2_4877_ba0a_fd2b6645fb94);
 windows_core::imp::interface_hierarchy!(IWICBitmapSource, windows_core::IUnknown);
@@ -1827,6 +2539,57 @@ impl IWICBitmapSource_Vtbl {
         iid == &<IWICBitmapSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICBitmapSource_Impl> windows_core::imp::TearOffVtable<Identity> for IWICBitmapSource_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSize<Identity: windows_core::IUnknownImpl + IWICBitmapSource_Impl>(this: *mut core::ffi::c_void, puiwidth: *mut u32, puiheight: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapSource_Impl::GetSize(this, core::mem::transmute_copy(&puiwidth), core::mem::transmute_copy(&puiheight)).into()
+            }
+        }
+        unsafe extern "system" fn GetPixelFormat<Identity: windows_core::IUnknownImpl + IWICBitmapSource_Impl>(this: *mut core::ffi::c_void, ppixelformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapSource_Impl::GetPixelFormat(this) {
+                    Ok(ok__) => {
+                        ppixelformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetResolution<Identity: windows_core::IUnknownImpl + IWICBitmapSource_Impl>(this: *mut core::ffi::c_void, pdpix: *mut f64, pdpiy: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapSource_Impl::GetResolution(this, core::mem::transmute_copy(&pdpix), core::mem::transmute_copy(&pdpiy)).into()
+            }
+        }
+        unsafe extern "system" fn CopyPalette<Identity: windows_core::IUnknownImpl + IWICBitmapSource_Impl>(this: *mut core::ffi::c_void, pipalette: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapSource_Impl::CopyPalette(this, core::mem::transmute_copy(&pipalette)).into()
+            }
+        }
+        unsafe extern "system" fn CopyPixels<Identity: windows_core::IUnknownImpl + IWICBitmapSource_Impl>(this: *mut core::ffi::c_void, prc: *const WICRect, cbstride: u32, cbbuffersize: u32, pbbuffer: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapSource_Impl::CopyPixels(this, core::mem::transmute_copy(&prc), core::mem::transmute_copy(&cbstride), core::mem::transmute_copy(&cbbuffersize), core::mem::transmute_copy(&pbbuffer)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSize: GetSize::<Identity>,
+            GetPixelFormat: GetPixelFormat::<Identity>,
+            GetResolution: GetResolution::<Identity>,
+            CopyPalette: CopyPalette::<Identity>,
+            CopyPixels: CopyPixels::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWICBitmapSource {}
 windows_core::imp::define_interface!(IWICBitmapSourceTransform, IWICBitmapSourceTransform_Vtbl, 0x3b16811b_6a43_4ec9_b713_3d5a0c13b940);
 windows_core::imp::interface_hierarchy!(IWICBitmapSourceTransform, windows_core::IUnknown);
@@ -1906,22 +2669,66 @@ impl IWICBitmapSourceTransform_Vtbl {
         iid == &<IWICBitmapSourceTransform as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IWICBitmapSourceTransform {}
-windows_core::imp::define_interface!(IWICColorContext, IWICColorContext_Vtbl, 0x3c613a02_34b2_44ea_9a7c_45aea9c6fd6d);
-windows_core::imp::interface_hierarchy!(IWICColorContext, windows_core::IUnknown);
-impl IWICColorContext {
-    pub unsafe fn InitializeFromFilename<P0>(&self, wzfilename: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<windows_core::PCWSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).InitializeFromFilename)(windows_core::Interface::as_raw(self), wzfilename.param().abi()).ok() }
-    }
-    pub unsafe fn InitializeFromMemory(&self, pbbuffer: &[u8]) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).InitializeFromMemory)(windows_core::Interface::as_raw(self), core::mem::transmute(pbbuffer.as_ptr()), pbbuffer.len().try_into().unwrap()).ok() }
-    }
-    pub unsafe fn InitializeFromExifColorSpace(&self, value: u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).InitializeFromExifColorSpace)(windows_core::Interface::as_raw(self), value).ok() }
-    }
+impl<Identity: windows_core::IUnknownImpl + IWICBitmapSourceTransform_Impl> windows_core::imp::TearOffVtable<Identity> for IWICBitmapSourceTransform_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CopyPixels<Identity: windows_core::IUnknownImpl + IWICBitmapSourceTransform_Impl>(this: *mut core::ffi::c_void, prc: *const WICRect, uiwidth: u32, uiheight: u32, pguiddstformat: *const windows_core::GUID, dsttransform: WICBitmapTransformOptions, nstride: u32, cbbuffersize: u32, pbbuffer: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapSourceTransform_Impl::CopyPixels(this, core::mem::transmute_copy(&prc), core::mem::transmute_copy(&uiwidth), core::mem::transmute_copy(&uiheight), core::mem::transmute_copy(&pguiddstformat), core::mem::transmute_copy(&dsttransform), core::mem::transmute_copy(&nstride), core::mem::transmute_copy(&cbbuffersize), core::mem::transmute_copy(&pbbuffer)).into()
+            }
+        }
+        unsafe extern "system" fn GetClosestSize<Identity: windows_core::IUnknownImpl + IWICBitmapSourceTransform_Impl>(this: *mut core::ffi::c_void, puiwidth: *mut u32, puiheight: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapSourceTransform_Impl::GetClosestSize(this, core::mem::transmute_copy(&puiwidth), core::mem::transmute_copy(&puiheight)).into()
+            }
+        }
+        unsafe extern "system" fn GetClosestPixelFormat<Identity: windows_core::IUnknownImpl + IWICBitmapSourceTransform_Impl>(this: *mut core::ffi::c_void, pguiddstformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICBitmapSourceTransform_Impl::GetClosestPixelFormat(this, core::mem::transmute_copy(&pguiddstformat)).into()
+            }
+        }
+        unsafe extern "system" fn DoesSupportTransform<Identity: windows_core::IUnknownImpl + IWICBitmapSourceTransform_Impl>(this: *mut core::ffi::c_void, dsttransform: WICBitmapTransformOptions, pfissupported: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICBitmapSourceTransform_Impl::DoesSupportTransform(this, core::mem::transmute_copy(&dsttransform)) {
+                    Ok(ok__) => {
+                        pfissupported.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CopyPixels: CopyPixels::<Identity>,
+            GetClosestSize: GetClosestSize::<Identity>,
+            GetClosestPixelFormat: GetClosestPixelFormat::<Identity>,
+            DoesSupportTransform: DoesSupportTransform::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IWICBitmapSourceTransform {}
+windows_core::imp::define_interface!(IWICColorContext, IWICColorContext_Vtbl, 0x3c613a02_34b2_44ea_9a7c_45aea9c6fd6d);
+windows_core::imp::interface_hierarchy!(IWICColorContext, windows_core::IUnknown);
+impl IWICColorContext {
+    pub unsafe fn InitializeFromFilename<P0>(&self, wzfilename: P0) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::PCWSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).InitializeFromFilename)(windows_core::Interface::as_raw(self), wzfilename.param().abi()).ok() }
+    }
+    pub unsafe fn InitializeFromMemory(&self, pbbuffer: &[u8]) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).InitializeFromMemory)(windows_core::Interface::as_raw(self), core::mem::transmute(pbbuffer.as_ptr()), pbbuffer.len().try_into().unwrap()).ok() }
+    }
+    pub unsafe fn InitializeFromExifColorSpace(&self, value: u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).InitializeFromExifColorSpace)(windows_core::Interface::as_raw(self), value).ok() }
+    }
     pub unsafe fn GetType(&self) -> windows_core::Result<WICColorContextType> {
         unsafe {
             let mut result__ = core::mem::zeroed();
@@ -2021,6 +2828,70 @@ impl IWICColorContext_Vtbl {
         iid == &<IWICColorContext as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICColorContext_Impl> windows_core::imp::TearOffVtable<Identity> for IWICColorContext_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InitializeFromFilename<Identity: windows_core::IUnknownImpl + IWICColorContext_Impl>(this: *mut core::ffi::c_void, wzfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICColorContext_Impl::InitializeFromFilename(this, core::mem::transmute(&wzfilename)).into()
+            }
+        }
+        unsafe extern "system" fn InitializeFromMemory<Identity: windows_core::IUnknownImpl + IWICColorContext_Impl>(this: *mut core::ffi::c_void, pbbuffer: *const u8, cbbuffersize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICColorContext_Impl::InitializeFromMemory(this, core::mem::transmute_copy(&pbbuffer), core::mem::transmute_copy(&cbbuffersize)).into()
+            }
+        }
+        unsafe extern "system" fn InitializeFromExifColorSpace<Identity: windows_core::IUnknownImpl + IWICColorContext_Impl>(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICColorContext_Impl::InitializeFromExifColorSpace(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn GetType<Identity: windows_core::IUnknownImpl + IWICColorContext_Impl>(this: *mut core::ffi::c_void, ptype: *mut WICColorContextType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICColorContext_Impl::GetType(this) {
+                    Ok(ok__) => {
+                        ptype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetProfileBytes<Identity: windows_core::IUnknownImpl + IWICColorContext_Impl>(this: *mut core::ffi::c_void, cbbuffer: u32, pbbuffer: *mut u8, pcbactual: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICColorContext_Impl::GetProfileBytes(this, core::mem::transmute_copy(&cbbuffer), core::mem::transmute_copy(&pbbuffer), core::mem::transmute_copy(&pcbactual)).into()
+            }
+        }
+        unsafe extern "system" fn GetExifColorSpace<Identity: windows_core::IUnknownImpl + IWICColorContext_Impl>(this: *mut core::ffi::c_void, pvalue: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICColorContext_Impl::GetExifColorSpace(this) {
+                    Ok(ok__) => {
+                        pvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InitializeFromFilename: InitializeFromFilename::<Identity>,
+            InitializeFromMemory: InitializeFromMemory::<Identity>,
+            InitializeFromExifColorSpace: InitializeFromExifColorSpace::<Identity>,
+            GetType: GetType::<Identity>,
+            GetProfileBytes: GetProfileBytes::<Identity>,
+            GetExifColorSpace: GetExifColorSpace::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWICColorContext {}
 windows_core::imp::define_interface!(IWICColorTransform, IWICColorTransform_Vtbl, 0xb66f034f_d0e2_40ab_b436_6de39e321a94);
 impl core::ops::Deref for IWICColorTransform {
@@ -2063,6 +2934,20 @@ impl IWICColorTransform_Vtbl {
         iid == &<IWICColorTransform as windows_core::Interface>::IID || iid == &<IWICBitmapSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICColorTransform_Impl> windows_core::imp::TearOffVtable<Identity> for IWICColorTransform_Vtbl
+where
+    IWICBitmapSource_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IWICColorTransform_Impl>(this: *mut core::ffi::c_void, pibitmapsource: *mut core::ffi::c_void, picontextsource: *mut core::ffi::c_void, picontextdest: *mut core::ffi::c_void, pixelfmtdest: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICColorTransform_Impl::Initialize(this, core::mem::transmute_copy(&pibitmapsource), core::mem::transmute_copy(&picontextsource), core::mem::transmute_copy(&picontextdest), core::mem::transmute_copy(&pixelfmtdest)).into()
+            }
+        }
+        Self { base__: <IWICBitmapSource_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Initialize: Initialize::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWICColorTransform {}
 windows_core::imp::define_interface!(IWICComponentFactory, IWICComponentFactory_Vtbl, 0x412d0c3a_9650_44fa_af5b_dd2a06c8e8fb);
 impl core::ops::Deref for IWICComponentFactory {
@@ -2268,6 +3153,108 @@ impl IWICComponentFactory_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant", feature = "Win32_UI_WindowsAndMessaging"))]
+impl<Identity: windows_core::IUnknownImpl + IWICComponentFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IWICComponentFactory_Vtbl
+where
+    IWICImagingFactory_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateMetadataReader<Identity: windows_core::IUnknownImpl + IWICComponentFactory_Impl>(this: *mut core::ffi::c_void, guidmetadataformat: *const windows_core::GUID, pguidvendor: *const windows_core::GUID, dwoptions: u32, pistream: *mut core::ffi::c_void, ppireader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICComponentFactory_Impl::CreateMetadataReader(this, core::mem::transmute_copy(&guidmetadataformat), core::mem::transmute_copy(&pguidvendor), core::mem::transmute_copy(&dwoptions), core::mem::transmute_copy(&pistream)) {
+                    Ok(ok__) => {
+                        ppireader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateMetadataReaderFromContainer<Identity: windows_core::IUnknownImpl + IWICComponentFactory_Impl>(this: *mut core::ffi::c_void, guidcontainerformat: *const windows_core::GUID, pguidvendor: *const windows_core::GUID, dwoptions: u32, pistream: *mut core::ffi::c_void, ppireader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICComponentFactory_Impl::CreateMetadataReaderFromContainer(this, core::mem::transmute_copy(&guidcontainerformat), core::mem::transmute_copy(&pguidvendor), core::mem::transmute_copy(&dwoptions), core::mem::transmute_copy(&pistream)) {
+                    Ok(ok__) => {
+                        ppireader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateMetadataWriter<Identity: windows_core::IUnknownImpl + IWICComponentFactory_Impl>(this: *mut core::ffi::c_void, guidmetadataformat: *const windows_core::GUID, pguidvendor: *const windows_core::GUID, dwmetadataoptions: u32, ppiwriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICComponentFactory_Impl::CreateMetadataWriter(this, core::mem::transmute_copy(&guidmetadataformat), core::mem::transmute_copy(&pguidvendor), core::mem::transmute_copy(&dwmetadataoptions)) {
+                    Ok(ok__) => {
+                        ppiwriter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateMetadataWriterFromReader<Identity: windows_core::IUnknownImpl + IWICComponentFactory_Impl>(this: *mut core::ffi::c_void, pireader: *mut core::ffi::c_void, pguidvendor: *const windows_core::GUID, ppiwriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICComponentFactory_Impl::CreateMetadataWriterFromReader(this, core::mem::transmute_copy(&pireader), core::mem::transmute_copy(&pguidvendor)) {
+                    Ok(ok__) => {
+                        ppiwriter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateQueryReaderFromBlockReader<Identity: windows_core::IUnknownImpl + IWICComponentFactory_Impl>(this: *mut core::ffi::c_void, piblockreader: *mut core::ffi::c_void, ppiqueryreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICComponentFactory_Impl::CreateQueryReaderFromBlockReader(this, core::mem::transmute_copy(&piblockreader)) {
+                    Ok(ok__) => {
+                        ppiqueryreader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateQueryWriterFromBlockWriter<Identity: windows_core::IUnknownImpl + IWICComponentFactory_Impl>(this: *mut core::ffi::c_void, piblockwriter: *mut core::ffi::c_void, ppiquerywriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICComponentFactory_Impl::CreateQueryWriterFromBlockWriter(this, core::mem::transmute_copy(&piblockwriter)) {
+                    Ok(ok__) => {
+                        ppiquerywriter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateEncoderPropertyBag<Identity: windows_core::IUnknownImpl + IWICComponentFactory_Impl>(this: *mut core::ffi::c_void, ppropoptions: *const super::super::System::Com::StructuredStorage::PROPBAG2, ccount: u32, ppipropertybag: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICComponentFactory_Impl::CreateEncoderPropertyBag(this, core::mem::transmute_copy(&ppropoptions), core::mem::transmute_copy(&ccount)) {
+                    Ok(ok__) => {
+                        ppipropertybag.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWICImagingFactory_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateMetadataReader: CreateMetadataReader::<Identity>,
+            CreateMetadataReaderFromContainer: CreateMetadataReaderFromContainer::<Identity>,
+            CreateMetadataWriter: CreateMetadataWriter::<Identity>,
+            CreateMetadataWriterFromReader: CreateMetadataWriterFromReader::<Identity>,
+            CreateQueryReaderFromBlockReader: CreateQueryReaderFromBlockReader::<Identity>,
+            CreateQueryWriterFromBlockWriter: CreateQueryWriterFromBlockWriter::<Identity>,
+            CreateEncoderPropertyBag: CreateEncoderPropertyBag::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant", feature = "Win32_UI_WindowsAndMessaging"))]
 impl windows_core::RuntimeName for IWICComponentFactory {}
 windows_core::imp::define_interface!(IWICComponentInfo, IWICComponentInfo_Vtbl, 0x23bc3f0a_698b_4357_886b_f24d50671334);
 windows_core::imp::interface_hierarchy!(IWICComponentInfo, windows_core::IUnknown);
@@ -2422,6 +3409,96 @@ impl IWICComponentInfo_Vtbl {
         iid == &<IWICComponentInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICComponentInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IWICComponentInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetComponentType<Identity: windows_core::IUnknownImpl + IWICComponentInfo_Impl>(this: *mut core::ffi::c_void, ptype: *mut WICComponentType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICComponentInfo_Impl::GetComponentType(this) {
+                    Ok(ok__) => {
+                        ptype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCLSID<Identity: windows_core::IUnknownImpl + IWICComponentInfo_Impl>(this: *mut core::ffi::c_void, pclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICComponentInfo_Impl::GetCLSID(this) {
+                    Ok(ok__) => {
+                        pclsid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSigningStatus<Identity: windows_core::IUnknownImpl + IWICComponentInfo_Impl>(this: *mut core::ffi::c_void, pstatus: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICComponentInfo_Impl::GetSigningStatus(this) {
+                    Ok(ok__) => {
+                        pstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAuthor<Identity: windows_core::IUnknownImpl + IWICComponentInfo_Impl>(this: *mut core::ffi::c_void, cchauthor: u32, wzauthor: windows_core::PWSTR, pcchactual: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICComponentInfo_Impl::GetAuthor(this, core::mem::transmute_copy(&cchauthor), core::mem::transmute_copy(&wzauthor), core::mem::transmute_copy(&pcchactual)).into()
+            }
+        }
+        unsafe extern "system" fn GetVendorGUID<Identity: windows_core::IUnknownImpl + IWICComponentInfo_Impl>(this: *mut core::ffi::c_void, pguidvendor: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICComponentInfo_Impl::GetVendorGUID(this) {
+                    Ok(ok__) => {
+                        pguidvendor.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetVersion<Identity: windows_core::IUnknownImpl + IWICComponentInfo_Impl>(this: *mut core::ffi::c_void, cchversion: u32, wzversion: windows_core::PWSTR, pcchactual: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICComponentInfo_Impl::GetVersion(this, core::mem::transmute_copy(&cchversion), core::mem::transmute_copy(&wzversion), core::mem::transmute_copy(&pcchactual)).into()
+            }
+        }
+        unsafe extern "system" fn GetSpecVersion<Identity: windows_core::IUnknownImpl + IWICComponentInfo_Impl>(this: *mut core::ffi::c_void, cchspecversion: u32, wzspecversion: windows_core::PWSTR, pcchactual: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICComponentInfo_Impl::GetSpecVersion(this, core::mem::transmute_copy(&cchspecversion), core::mem::transmute_copy(&wzspecversion), core::mem::transmute_copy(&pcchactual)).into()
+            }
+        }
+        unsafe extern "system" fn GetFriendlyName<Identity: windows_core::IUnknownImpl + IWICComponentInfo_Impl>(this: *mut core::ffi::c_void, cchfriendlyname: u32, wzfriendlyname: windows_core::PWSTR, pcchactual: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICComponentInfo_Impl::GetFriendlyName(this, core::mem::transmute_copy(&cchfriendlyname), core::mem::transmute_copy(&wzfriendlyname), core::mem::transmute_copy(&pcchactual)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetComponentType: GetComponentType::<Identity>,
+            GetCLSID: GetCLSID::<Identity>,
+            GetSigningStatus: GetSigningStatus::<Identity>,
+            GetAuthor: GetAuthor::<Identity>,
+            GetVendorGUID: GetVendorGUID::<Identity>,
+            GetVersion: GetVersion::<Identity>,
+            GetSpecVersion: GetSpecVersion::<Identity>,
+            GetFriendlyName: GetFriendlyName::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWICComponentInfo {}
 windows_core::imp::define_interface!(IWICDdsDecoder, IWICDdsDecoder_Vtbl, 0x409cd537_8532_40cb_9774_e2feb2df4e9c);
 windows_core::imp::interface_hierarchy!(IWICDdsDecoder, windows_core::IUnknown);
@@ -2484,6 +3561,37 @@ impl IWICDdsDecoder_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IWICDdsDecoder_Impl> windows_core::imp::TearOffVtable<Identity> for IWICDdsDecoder_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetParameters<Identity: windows_core::IUnknownImpl + IWICDdsDecoder_Impl>(this: *mut core::ffi::c_void, pparameters: *mut WICDdsParameters) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDdsDecoder_Impl::GetParameters(this, core::mem::transmute_copy(&pparameters)).into()
+            }
+        }
+        unsafe extern "system" fn GetFrame<Identity: windows_core::IUnknownImpl + IWICDdsDecoder_Impl>(this: *mut core::ffi::c_void, arrayindex: u32, miplevel: u32, sliceindex: u32, ppibitmapframe: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICDdsDecoder_Impl::GetFrame(this, core::mem::transmute_copy(&arrayindex), core::mem::transmute_copy(&miplevel), core::mem::transmute_copy(&sliceindex)) {
+                    Ok(ok__) => {
+                        ppibitmapframe.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetParameters: GetParameters::<Identity>,
+            GetFrame: GetFrame::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IWICDdsDecoder {}
 windows_core::imp::define_interface!(IWICDdsEncoder, IWICDdsEncoder_Vtbl, 0x5cacdb4c_407e_41b3_b936_d0f010cd6732);
 windows_core::imp::interface_hierarchy!(IWICDdsEncoder, windows_core::IUnknown);
@@ -2553,15 +3661,47 @@ impl IWICDdsEncoder_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-impl windows_core::RuntimeName for IWICDdsEncoder {}
-windows_core::imp::define_interface!(IWICDdsFrameDecode, IWICDdsFrameDecode_Vtbl, 0x3d4c0c61_18a4_41e4_bd80_481a4fc9f464);
-windows_core::imp::interface_hierarchy!(IWICDdsFrameDecode, windows_core::IUnknown);
-impl IWICDdsFrameDecode {
-    pub unsafe fn GetSizeInBlocks(&self, pwidthinblocks: *mut u32, pheightinblocks: *mut u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetSizeInBlocks)(windows_core::Interface::as_raw(self), pwidthinblocks as _, pheightinblocks as _).ok() }
-    }
-    #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-    pub unsafe fn GetFormatInfo(&self) -> windows_core::Result<WICDdsFormatInfo> {
+impl<Identity: windows_core::IUnknownImpl + IWICDdsEncoder_Impl> windows_core::imp::TearOffVtable<Identity> for IWICDdsEncoder_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetParameters<Identity: windows_core::IUnknownImpl + IWICDdsEncoder_Impl>(this: *mut core::ffi::c_void, pparameters: *const WICDdsParameters) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDdsEncoder_Impl::SetParameters(this, core::mem::transmute_copy(&pparameters)).into()
+            }
+        }
+        unsafe extern "system" fn GetParameters<Identity: windows_core::IUnknownImpl + IWICDdsEncoder_Impl>(this: *mut core::ffi::c_void, pparameters: *mut WICDdsParameters) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDdsEncoder_Impl::GetParameters(this, core::mem::transmute_copy(&pparameters)).into()
+            }
+        }
+        unsafe extern "system" fn CreateNewFrame<Identity: windows_core::IUnknownImpl + IWICDdsEncoder_Impl>(this: *mut core::ffi::c_void, ppiframeencode: *mut *mut core::ffi::c_void, parrayindex: *mut u32, pmiplevel: *mut u32, psliceindex: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDdsEncoder_Impl::CreateNewFrame(this, core::mem::transmute_copy(&ppiframeencode), core::mem::transmute_copy(&parrayindex), core::mem::transmute_copy(&pmiplevel), core::mem::transmute_copy(&psliceindex)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetParameters: SetParameters::<Identity>,
+            GetParameters: GetParameters::<Identity>,
+            CreateNewFrame: CreateNewFrame::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::RuntimeName for IWICDdsEncoder {}
+windows_core::imp::define_interface!(IWICDdsFrameDecode, IWICDdsFrameDecode_Vtbl, 0x3d4c0c61_18a4_41e4_bd80_481a4fc9f464);
+windows_core::imp::interface_hierarchy!(IWICDdsFrameDecode, windows_core::IUnknown);
+impl IWICDdsFrameDecode {
+    pub unsafe fn GetSizeInBlocks(&self, pwidthinblocks: *mut u32, pheightinblocks: *mut u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetSizeInBlocks)(windows_core::Interface::as_raw(self), pwidthinblocks as _, pheightinblocks as _).ok() }
+    }
+    #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+    pub unsafe fn GetFormatInfo(&self) -> windows_core::Result<WICDdsFormatInfo> {
         unsafe {
             let mut result__ = core::mem::zeroed();
             (windows_core::Interface::vtable(self).GetFormatInfo)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
@@ -2627,6 +3767,44 @@ impl IWICDdsFrameDecode_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IWICDdsFrameDecode_Impl> windows_core::imp::TearOffVtable<Identity> for IWICDdsFrameDecode_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSizeInBlocks<Identity: windows_core::IUnknownImpl + IWICDdsFrameDecode_Impl>(this: *mut core::ffi::c_void, pwidthinblocks: *mut u32, pheightinblocks: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDdsFrameDecode_Impl::GetSizeInBlocks(this, core::mem::transmute_copy(&pwidthinblocks), core::mem::transmute_copy(&pheightinblocks)).into()
+            }
+        }
+        unsafe extern "system" fn GetFormatInfo<Identity: windows_core::IUnknownImpl + IWICDdsFrameDecode_Impl>(this: *mut core::ffi::c_void, pformatinfo: *mut WICDdsFormatInfo) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICDdsFrameDecode_Impl::GetFormatInfo(this) {
+                    Ok(ok__) => {
+                        pformatinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CopyBlocks<Identity: windows_core::IUnknownImpl + IWICDdsFrameDecode_Impl>(this: *mut core::ffi::c_void, prcboundsinblocks: *const WICRect, cbstride: u32, cbbuffersize: u32, pbbuffer: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDdsFrameDecode_Impl::CopyBlocks(this, core::mem::transmute_copy(&prcboundsinblocks), core::mem::transmute_copy(&cbstride), core::mem::transmute_copy(&cbbuffersize), core::mem::transmute_copy(&pbbuffer)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSizeInBlocks: GetSizeInBlocks::<Identity>,
+            GetFormatInfo: GetFormatInfo::<Identity>,
+            CopyBlocks: CopyBlocks::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IWICDdsFrameDecode {}
 windows_core::imp::define_interface!(IWICDevelopRaw, IWICDevelopRaw_Vtbl, 0xfbec5e44_f7be_4b65_b7f8_c0c81fef026d);
 impl core::ops::Deref for IWICDevelopRaw {
@@ -3160,224 +4338,620 @@ impl IWICDevelopRaw_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
-impl windows_core::RuntimeName for IWICDevelopRaw {}
-windows_core::imp::define_interface!(IWICDevelopRawNotificationCallback, IWICDevelopRawNotificationCallback_Vtbl, 0x95c75a6e_3e8c_4ec2_85a8_aebcc551e59b);
-windows_core::imp::interface_hierarchy!(IWICDevelopRawNotificationCallback, windows_core::IUnknown);
-impl IWICDevelopRawNotificationCallback {
-    pub unsafe fn Notify(&self, notificationmask: u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Notify)(windows_core::Interface::as_raw(self), notificationmask).ok() }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IWICDevelopRawNotificationCallback_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    pub Notify: unsafe extern "system" fn(*mut core::ffi::c_void, u32) -> windows_core::HRESULT,
-}
-pub trait IWICDevelopRawNotificationCallback_Impl: windows_core::IUnknownImpl {
-    fn Notify(&self, notificationmask: u32) -> windows_core::Result<()>;
-}
-impl IWICDevelopRawNotificationCallback_Vtbl {
-    pub const fn new<Identity: IWICDevelopRawNotificationCallback_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn Notify<Identity: IWICDevelopRawNotificationCallback_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, notificationmask: u32) -> windows_core::HRESULT {
+impl<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl> windows_core::imp::TearOffVtable<Identity> for IWICDevelopRaw_Vtbl
+where
+    IWICBitmapFrameDecode_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryRawCapabilitiesInfo<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, pinfo: *mut WICRawCapabilitiesInfo) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IWICDevelopRawNotificationCallback_Impl::Notify(this, core::mem::transmute_copy(&notificationmask)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDevelopRaw_Impl::QueryRawCapabilitiesInfo(this, core::mem::transmute_copy(&pinfo)).into()
             }
         }
-        Self { base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(), Notify: Notify::<Identity, OFFSET> }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWICDevelopRawNotificationCallback as windows_core::Interface>::IID
-    }
-}
-impl windows_core::RuntimeName for IWICDevelopRawNotificationCallback {}
-windows_core::imp::define_interface!(IWICEnumMetadataItem, IWICEnumMetadataItem_Vtbl, 0xdc2bb46d_3f07_481e_8625_220c4aedbb33);
-windows_core::imp::interface_hierarchy!(IWICEnumMetadataItem, windows_core::IUnknown);
-impl IWICEnumMetadataItem {
-    #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
-    pub unsafe fn Next(&self, celt: u32, rgeltschema: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, rgeltid: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, rgeltvalue: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, pceltfetched: *mut u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Next)(windows_core::Interface::as_raw(self), celt, core::mem::transmute(rgeltschema), core::mem::transmute(rgeltid), core::mem::transmute(rgeltvalue), pceltfetched as _).ok() }
-    }
-    pub unsafe fn Skip(&self, celt: u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Skip)(windows_core::Interface::as_raw(self), celt).ok() }
-    }
-    pub unsafe fn Reset(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Reset)(windows_core::Interface::as_raw(self)).ok() }
-    }
-    pub unsafe fn Clone(&self) -> windows_core::Result<IWICEnumMetadataItem> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).Clone)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IWICEnumMetadataItem_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
-    pub Next: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *mut super::super::System::Com::StructuredStorage::PROPVARIANT, *mut super::super::System::Com::StructuredStorage::PROPVARIANT, *mut super::super::System::Com::StructuredStorage::PROPVARIANT, *mut u32) -> windows_core::HRESULT,
-    #[cfg(not(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant")))]
-    Next: usize,
-    pub Skip: unsafe extern "system" fn(*mut core::ffi::c_void, u32) -> windows_core::HRESULT,
-    pub Reset: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub Clone: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
-pub trait IWICEnumMetadataItem_Impl: windows_core::IUnknownImpl {
-    fn Next(&self, celt: u32, rgeltschema: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, rgeltid: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, rgeltvalue: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, pceltfetched: *mut u32) -> windows_core::Result<()>;
-    fn Skip(&self, celt: u32) -> windows_core::Result<()>;
-    fn Reset(&self) -> windows_core::Result<()>;
-    fn Clone(&self) -> windows_core::Result<IWICEnumMetadataItem>;
-}
-#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
-impl IWICEnumMetadataItem_Vtbl {
-    pub const fn new<Identity: IWICEnumMetadataItem_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn Next<Identity: IWICEnumMetadataItem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, celt: u32, rgeltschema: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, rgeltid: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, rgeltvalue: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, pceltfetched: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn LoadParameterSet<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, parameterset: WICRawParameterSet) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IWICEnumMetadataItem_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgeltschema), core::mem::transmute_copy(&rgeltid), core::mem::transmute_copy(&rgeltvalue), core::mem::transmute_copy(&pceltfetched)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDevelopRaw_Impl::LoadParameterSet(this, core::mem::transmute_copy(&parameterset)).into()
             }
         }
-        unsafe extern "system" fn Skip<Identity: IWICEnumMetadataItem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetCurrentParameterSet<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, ppcurrentparameterset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IWICEnumMetadataItem_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICDevelopRaw_Impl::GetCurrentParameterSet(this) {
+                    Ok(ok__) => {
+                        ppcurrentparameterset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
             }
         }
-        unsafe extern "system" fn Reset<Identity: IWICEnumMetadataItem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetExposureCompensation<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, ev: f64) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IWICEnumMetadataItem_Impl::Reset(this).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDevelopRaw_Impl::SetExposureCompensation(this, core::mem::transmute_copy(&ev)).into()
             }
         }
-        unsafe extern "system" fn Clone<Identity: IWICEnumMetadataItem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppienummetadataitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetExposureCompensation<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, pev: *mut f64) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IWICEnumMetadataItem_Impl::Clone(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICDevelopRaw_Impl::GetExposureCompensation(this) {
                     Ok(ok__) => {
-                        ppienummetadataitem.write(core::mem::transmute(ok__));
+                        pev.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            Next: Next::<Identity, OFFSET>,
-            Skip: Skip::<Identity, OFFSET>,
-            Reset: Reset::<Identity, OFFSET>,
-            Clone: Clone::<Identity, OFFSET>,
+        unsafe extern "system" fn SetWhitePointRGB<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, red: u32, green: u32, blue: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDevelopRaw_Impl::SetWhitePointRGB(this, core::mem::transmute_copy(&red), core::mem::transmute_copy(&green), core::mem::transmute_copy(&blue)).into()
+            }
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWICEnumMetadataItem as windows_core::Interface>::IID
-    }
-}
-#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IWICEnumMetadataItem {}
-windows_core::imp::define_interface!(IWICFastMetadataEncoder, IWICFastMetadataEncoder_Vtbl, 0xb84e2c09_78c9_4ac4_8bd3_524ae1663a2f);
-windows_core::imp::interface_hierarchy!(IWICFastMetadataEncoder, windows_core::IUnknown);
-impl IWICFastMetadataEncoder {
-    pub unsafe fn Commit(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Commit)(windows_core::Interface::as_raw(self)).ok() }
-    }
-    pub unsafe fn GetMetadataQueryWriter(&self) -> windows_core::Result<IWICMetadataQueryWriter> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetMetadataQueryWriter)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn GetWhitePointRGB<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, pred: *mut u32, pgreen: *mut u32, pblue: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDevelopRaw_Impl::GetWhitePointRGB(this, core::mem::transmute_copy(&pred), core::mem::transmute_copy(&pgreen), core::mem::transmute_copy(&pblue)).into()
+            }
         }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IWICFastMetadataEncoder_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    pub Commit: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetMetadataQueryWriter: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-pub trait IWICFastMetadataEncoder_Impl: windows_core::IUnknownImpl {
-    fn Commit(&self) -> windows_core::Result<()>;
-    fn GetMetadataQueryWriter(&self) -> windows_core::Result<IWICMetadataQueryWriter>;
-}
-impl IWICFastMetadataEncoder_Vtbl {
-    pub const fn new<Identity: IWICFastMetadataEncoder_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn Commit<Identity: IWICFastMetadataEncoder_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetNamedWhitePoint<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, whitepoint: WICNamedWhitePoint) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IWICFastMetadataEncoder_Impl::Commit(this).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDevelopRaw_Impl::SetNamedWhitePoint(this, core::mem::transmute_copy(&whitepoint)).into()
             }
         }
-        unsafe extern "system" fn GetMetadataQueryWriter<Identity: IWICFastMetadataEncoder_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppimetadataquerywriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetNamedWhitePoint<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, pwhitepoint: *mut WICNamedWhitePoint) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IWICFastMetadataEncoder_Impl::GetMetadataQueryWriter(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICDevelopRaw_Impl::GetNamedWhitePoint(this) {
                     Ok(ok__) => {
-                        ppimetadataquerywriter.write(core::mem::transmute(ok__));
+                        pwhitepoint.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            Commit: Commit::<Identity, OFFSET>,
-            GetMetadataQueryWriter: GetMetadataQueryWriter::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWICFastMetadataEncoder as windows_core::Interface>::IID
-    }
-}
-impl windows_core::RuntimeName for IWICFastMetadataEncoder {}
-windows_core::imp::define_interface!(IWICFormatConverter, IWICFormatConverter_Vtbl, 0x00000301_a8f2_4877_ba0a_fd2b6645fb94);
-impl core::ops::Deref for IWICFormatConverter {
-    type Target = IWICBitmapSource;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(IWICFormatConverter, windows_core::IUnknown, IWICBitmapSource);
-impl IWICFormatConverter {
-    pub unsafe fn Initialize<P0, P3>(&self, pisource: P0, dstformat: *const windows_core::GUID, dither: WICBitmapDitherType, pipalette: P3, alphathresholdpercent: f64, palettetranslate: WICBitmapPaletteType) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<IWICBitmapSource>,
-        P3: windows_core::Param<IWICPalette>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).Initialize)(windows_core::Interface::as_raw(self), pisource.param().abi(), dstformat, dither, pipalette.param().abi(), alphathresholdpercent, palettetranslate).ok() }
-    }
-    pub unsafe fn CanConvert(&self, srcpixelformat: *const windows_core::GUID, dstpixelformat: *const windows_core::GUID) -> windows_core::Result<windows_core::BOOL> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CanConvert)(windows_core::Interface::as_raw(self), srcpixelformat, dstpixelformat, &mut result__).map(|| result__)
-        }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IWICFormatConverter_Vtbl {
-    pub base__: IWICBitmapSource_Vtbl,
-    pub Initialize: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *const windows_core::GUID, WICBitmapDitherType, *mut core::ffi::c_void, f64, WICBitmapPaletteType) -> windows_core::HRESULT,
-    pub CanConvert: unsafe extern "system" fn(*mut core::ffi::c_void, *const windows_core::GUID, *const windows_core::GUID, *mut windows_core::BOOL) -> windows_core::HRESULT,
-}
-pub trait IWICFormatConverter_Impl: IWICBitmapSource_Impl {
-    fn Initialize(&self, pisource: windows_core::Ref<IWICBitmapSource>, dstformat: *const windows_core::GUID, dither: WICBitmapDitherType, pipalette: windows_core::Ref<IWICPalette>, alphathresholdpercent: f64, palettetranslate: WICBitmapPaletteType) -> windows_core::Result<()>;
-    fn CanConvert(&self, srcpixelformat: *const windows_core::GUID, dstpixelformat: *const windows_core::GUID) -> windows_core::Result<windows_core::BOOL>;
-}
-impl IWICFormatConverter_Vtbl {
-    pub const fn new<Identity: IWICFormatConverter_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn Initialize<Identity: IWICFormatConverter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pisource: *mut core::ffi::c_void, dstformat: *const windows_core::GUID, dither: WICBitmapDitherType, pipalette: *mut core::ffi::c_void, alphathresholdpercent: f64, palettetranslate: WICBitmapPaletteType) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetWhitePointKelvin<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, whitepointkelvin: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IWICFormatConverter_Impl::Initialize(this, core::mem::transmute_copy(&pisource), core::mem::transmute_copy(&dstformat), core::mem::transmute_copy(&dither), core::mem::transmute_copy(&pipalette), core::mem::transmute_copy(&alphathresholdpercent), core::mem::transmute_copy(&palettetranslate)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDevelopRaw_Impl::SetWhitePointKelvin(this, core::mem::transmute_copy(&whitepointkelvin)).into()
             }
         }
-        unsafe extern "system" fn CanConvert<Identity: IWICFormatConverter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, srcpixelformat: *const windows_core::GUID, dstpixelformat: *const windows_core::GUID, pfcanconvert: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetWhitePointKelvin<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, pwhitepointkelvin: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICDevelopRaw_Impl::GetWhitePointKelvin(this) {
+                    Ok(ok__) => {
+                        pwhitepointkelvin.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetKelvinRangeInfo<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, pminkelvintemp: *mut u32, pmaxkelvintemp: *mut u32, pkelvintempstepvalue: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDevelopRaw_Impl::GetKelvinRangeInfo(this, core::mem::transmute_copy(&pminkelvintemp), core::mem::transmute_copy(&pmaxkelvintemp), core::mem::transmute_copy(&pkelvintempstepvalue)).into()
+            }
+        }
+        unsafe extern "system" fn SetContrast<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, contrast: f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDevelopRaw_Impl::SetContrast(this, core::mem::transmute_copy(&contrast)).into()
+            }
+        }
+        unsafe extern "system" fn GetContrast<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, pcontrast: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICDevelopRaw_Impl::GetContrast(this) {
+                    Ok(ok__) => {
+                        pcontrast.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetGamma<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, gamma: f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDevelopRaw_Impl::SetGamma(this, core::mem::transmute_copy(&gamma)).into()
+            }
+        }
+        unsafe extern "system" fn GetGamma<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, pgamma: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICDevelopRaw_Impl::GetGamma(this) {
+                    Ok(ok__) => {
+                        pgamma.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSharpness<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, sharpness: f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDevelopRaw_Impl::SetSharpness(this, core::mem::transmute_copy(&sharpness)).into()
+            }
+        }
+        unsafe extern "system" fn GetSharpness<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, psharpness: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICDevelopRaw_Impl::GetSharpness(this) {
+                    Ok(ok__) => {
+                        psharpness.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSaturation<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, saturation: f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDevelopRaw_Impl::SetSaturation(this, core::mem::transmute_copy(&saturation)).into()
+            }
+        }
+        unsafe extern "system" fn GetSaturation<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, psaturation: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICDevelopRaw_Impl::GetSaturation(this) {
+                    Ok(ok__) => {
+                        psaturation.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTint<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, tint: f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDevelopRaw_Impl::SetTint(this, core::mem::transmute_copy(&tint)).into()
+            }
+        }
+        unsafe extern "system" fn GetTint<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, ptint: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICDevelopRaw_Impl::GetTint(this) {
+                    Ok(ok__) => {
+                        ptint.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNoiseReduction<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, noisereduction: f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDevelopRaw_Impl::SetNoiseReduction(this, core::mem::transmute_copy(&noisereduction)).into()
+            }
+        }
+        unsafe extern "system" fn GetNoiseReduction<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, pnoisereduction: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICDevelopRaw_Impl::GetNoiseReduction(this) {
+                    Ok(ok__) => {
+                        pnoisereduction.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDestinationColorContext<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, pcolorcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDevelopRaw_Impl::SetDestinationColorContext(this, core::mem::transmute_copy(&pcolorcontext)).into()
+            }
+        }
+        unsafe extern "system" fn SetToneCurve<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, cbtonecurvesize: u32, ptonecurve: *const WICRawToneCurve) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDevelopRaw_Impl::SetToneCurve(this, core::mem::transmute_copy(&cbtonecurvesize), core::mem::transmute_copy(&ptonecurve)).into()
+            }
+        }
+        unsafe extern "system" fn GetToneCurve<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, cbtonecurvebuffersize: u32, ptonecurve: *mut WICRawToneCurve, pcbactualtonecurvebuffersize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDevelopRaw_Impl::GetToneCurve(this, core::mem::transmute_copy(&cbtonecurvebuffersize), core::mem::transmute_copy(&ptonecurve), core::mem::transmute_copy(&pcbactualtonecurvebuffersize)).into()
+            }
+        }
+        unsafe extern "system" fn SetRotation<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, rotation: f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDevelopRaw_Impl::SetRotation(this, core::mem::transmute_copy(&rotation)).into()
+            }
+        }
+        unsafe extern "system" fn GetRotation<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, protation: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICDevelopRaw_Impl::GetRotation(this) {
+                    Ok(ok__) => {
+                        protation.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRenderMode<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, rendermode: WICRawRenderMode) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDevelopRaw_Impl::SetRenderMode(this, core::mem::transmute_copy(&rendermode)).into()
+            }
+        }
+        unsafe extern "system" fn GetRenderMode<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, prendermode: *mut WICRawRenderMode) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICDevelopRaw_Impl::GetRenderMode(this) {
+                    Ok(ok__) => {
+                        prendermode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNotificationCallback<Identity: windows_core::IUnknownImpl + IWICDevelopRaw_Impl>(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDevelopRaw_Impl::SetNotificationCallback(this, core::mem::transmute_copy(&pcallback)).into()
+            }
+        }
+        Self {
+            base__: <IWICBitmapFrameDecode_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryRawCapabilitiesInfo: QueryRawCapabilitiesInfo::<Identity>,
+            LoadParameterSet: LoadParameterSet::<Identity>,
+            GetCurrentParameterSet: GetCurrentParameterSet::<Identity>,
+            SetExposureCompensation: SetExposureCompensation::<Identity>,
+            GetExposureCompensation: GetExposureCompensation::<Identity>,
+            SetWhitePointRGB: SetWhitePointRGB::<Identity>,
+            GetWhitePointRGB: GetWhitePointRGB::<Identity>,
+            SetNamedWhitePoint: SetNamedWhitePoint::<Identity>,
+            GetNamedWhitePoint: GetNamedWhitePoint::<Identity>,
+            SetWhitePointKelvin: SetWhitePointKelvin::<Identity>,
+            GetWhitePointKelvin: GetWhitePointKelvin::<Identity>,
+            GetKelvinRangeInfo: GetKelvinRangeInfo::<Identity>,
+            SetContrast: SetContrast::<Identity>,
+            GetContrast: GetContrast::<Identity>,
+            SetGamma: SetGamma::<Identity>,
+            GetGamma: GetGamma::<Identity>,
+            SetSharpness: SetSharpness::<Identity>,
+            GetSharpness: GetSharpness::<Identity>,
+            SetSaturation: SetSaturation::<Identity>,
+            GetSaturation: GetSaturation::<Identity>,
+            SetTint: SetTint::<Identity>,
+            GetTint: GetTint::<Identity>,
+            SetNoiseReduction: SetNoiseReduction::<Identity>,
+            GetNoiseReduction: GetNoiseReduction::<Identity>,
+            SetDestinationColorContext: SetDestinationColorContext::<Identity>,
+            SetToneCurve: SetToneCurve::<Identity>,
+            GetToneCurve: GetToneCurve::<Identity>,
+            SetRotation: SetRotation::<Identity>,
+            GetRotation: GetRotation::<Identity>,
+            SetRenderMode: SetRenderMode::<Identity>,
+            GetRenderMode: GetRenderMode::<Identity>,
+            SetNotificationCallback: SetNotificationCallback::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::RuntimeName for IWICDevelopRaw {}
+windows_core::imp::define_interface!(IWICDevelopRawNotificationCallback, IWICDevelopRawNotificationCallback_Vtbl, 0x95c75a6e_3e8c_4ec2_85a8_aebcc551e59b);
+windows_core::imp::interface_hierarchy!(IWICDevelopRawNotificationCallback, windows_core::IUnknown);
+impl IWICDevelopRawNotificationCallback {
+    pub unsafe fn Notify(&self, notificationmask: u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Notify)(windows_core::Interface::as_raw(self), notificationmask).ok() }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IWICDevelopRawNotificationCallback_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub Notify: unsafe extern "system" fn(*mut core::ffi::c_void, u32) -> windows_core::HRESULT,
+}
+pub trait IWICDevelopRawNotificationCallback_Impl: windows_core::IUnknownImpl {
+    fn Notify(&self, notificationmask: u32) -> windows_core::Result<()>;
+}
+impl IWICDevelopRawNotificationCallback_Vtbl {
+    pub const fn new<Identity: IWICDevelopRawNotificationCallback_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn Notify<Identity: IWICDevelopRawNotificationCallback_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, notificationmask: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWICDevelopRawNotificationCallback_Impl::Notify(this, core::mem::transmute_copy(&notificationmask)).into()
+            }
+        }
+        Self { base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(), Notify: Notify::<Identity, OFFSET> }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICDevelopRawNotificationCallback as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IWICDevelopRawNotificationCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IWICDevelopRawNotificationCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Notify<Identity: windows_core::IUnknownImpl + IWICDevelopRawNotificationCallback_Impl>(this: *mut core::ffi::c_void, notificationmask: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICDevelopRawNotificationCallback_Impl::Notify(this, core::mem::transmute_copy(&notificationmask)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Notify: Notify::<Identity> }
+    };
+}
+impl windows_core::RuntimeName for IWICDevelopRawNotificationCallback {}
+windows_core::imp::define_interface!(IWICEnumMetadataItem, IWICEnumMetadataItem_Vtbl, 0xdc2bb46d_3f07_481e_8625_220c4aedbb33);
+windows_core::imp::interface_hierarchy!(IWICEnumMetadataItem, windows_core::IUnknown);
+impl IWICEnumMetadataItem {
+    #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+    pub unsafe fn Next(&self, celt: u32, rgeltschema: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, rgeltid: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, rgeltvalue: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, pceltfetched: *mut u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Next)(windows_core::Interface::as_raw(self), celt, core::mem::transmute(rgeltschema), core::mem::transmute(rgeltid), core::mem::transmute(rgeltvalue), pceltfetched as _).ok() }
+    }
+    pub unsafe fn Skip(&self, celt: u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Skip)(windows_core::Interface::as_raw(self), celt).ok() }
+    }
+    pub unsafe fn Reset(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Reset)(windows_core::Interface::as_raw(self)).ok() }
+    }
+    pub unsafe fn Clone(&self) -> windows_core::Result<IWICEnumMetadataItem> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).Clone)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IWICEnumMetadataItem_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+    pub Next: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *mut super::super::System::Com::StructuredStorage::PROPVARIANT, *mut super::super::System::Com::StructuredStorage::PROPVARIANT, *mut super::super::System::Com::StructuredStorage::PROPVARIANT, *mut u32) -> windows_core::HRESULT,
+    #[cfg(not(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant")))]
+    Next: usize,
+    pub Skip: unsafe extern "system" fn(*mut core::ffi::c_void, u32) -> windows_core::HRESULT,
+    pub Reset: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub Clone: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+pub trait IWICEnumMetadataItem_Impl: windows_core::IUnknownImpl {
+    fn Next(&self, celt: u32, rgeltschema: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, rgeltid: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, rgeltvalue: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, pceltfetched: *mut u32) -> windows_core::Result<()>;
+    fn Skip(&self, celt: u32) -> windows_core::Result<()>;
+    fn Reset(&self) -> windows_core::Result<()>;
+    fn Clone(&self) -> windows_core::Result<IWICEnumMetadataItem>;
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+impl IWICEnumMetadataItem_Vtbl {
+    pub const fn new<Identity: IWICEnumMetadataItem_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn Next<Identity: IWICEnumMetadataItem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, celt: u32, rgeltschema: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, rgeltid: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, rgeltvalue: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWICEnumMetadataItem_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgeltschema), core::mem::transmute_copy(&rgeltid), core::mem::transmute_copy(&rgeltvalue), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: IWICEnumMetadataItem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWICEnumMetadataItem_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: IWICEnumMetadataItem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWICEnumMetadataItem_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: IWICEnumMetadataItem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppienummetadataitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWICEnumMetadataItem_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppienummetadataitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            Next: Next::<Identity, OFFSET>,
+            Skip: Skip::<Identity, OFFSET>,
+            Reset: Reset::<Identity, OFFSET>,
+            Clone: Clone::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICEnumMetadataItem as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWICEnumMetadataItem_Impl> windows_core::imp::TearOffVtable<Identity> for IWICEnumMetadataItem_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IWICEnumMetadataItem_Impl>(this: *mut core::ffi::c_void, celt: u32, rgeltschema: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, rgeltid: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, rgeltvalue: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICEnumMetadataItem_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgeltschema), core::mem::transmute_copy(&rgeltid), core::mem::transmute_copy(&rgeltvalue), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IWICEnumMetadataItem_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICEnumMetadataItem_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IWICEnumMetadataItem_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICEnumMetadataItem_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IWICEnumMetadataItem_Impl>(this: *mut core::ffi::c_void, ppienummetadataitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICEnumMetadataItem_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppienummetadataitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IWICEnumMetadataItem {}
+windows_core::imp::define_interface!(IWICFastMetadataEncoder, IWICFastMetadataEncoder_Vtbl, 0xb84e2c09_78c9_4ac4_8bd3_524ae1663a2f);
+windows_core::imp::interface_hierarchy!(IWICFastMetadataEncoder, windows_core::IUnknown);
+impl IWICFastMetadataEncoder {
+    pub unsafe fn Commit(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Commit)(windows_core::Interface::as_raw(self)).ok() }
+    }
+    pub unsafe fn GetMetadataQueryWriter(&self) -> windows_core::Result<IWICMetadataQueryWriter> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetMetadataQueryWriter)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IWICFastMetadataEncoder_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub Commit: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetMetadataQueryWriter: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+pub trait IWICFastMetadataEncoder_Impl: windows_core::IUnknownImpl {
+    fn Commit(&self) -> windows_core::Result<()>;
+    fn GetMetadataQueryWriter(&self) -> windows_core::Result<IWICMetadataQueryWriter>;
+}
+impl IWICFastMetadataEncoder_Vtbl {
+    pub const fn new<Identity: IWICFastMetadataEncoder_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn Commit<Identity: IWICFastMetadataEncoder_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWICFastMetadataEncoder_Impl::Commit(this).into()
+            }
+        }
+        unsafe extern "system" fn GetMetadataQueryWriter<Identity: IWICFastMetadataEncoder_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppimetadataquerywriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWICFastMetadataEncoder_Impl::GetMetadataQueryWriter(this) {
+                    Ok(ok__) => {
+                        ppimetadataquerywriter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            Commit: Commit::<Identity, OFFSET>,
+            GetMetadataQueryWriter: GetMetadataQueryWriter::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICFastMetadataEncoder as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IWICFastMetadataEncoder_Impl> windows_core::imp::TearOffVtable<Identity> for IWICFastMetadataEncoder_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Commit<Identity: windows_core::IUnknownImpl + IWICFastMetadataEncoder_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICFastMetadataEncoder_Impl::Commit(this).into()
+            }
+        }
+        unsafe extern "system" fn GetMetadataQueryWriter<Identity: windows_core::IUnknownImpl + IWICFastMetadataEncoder_Impl>(this: *mut core::ffi::c_void, ppimetadataquerywriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICFastMetadataEncoder_Impl::GetMetadataQueryWriter(this) {
+                    Ok(ok__) => {
+                        ppimetadataquerywriter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Commit: Commit::<Identity>,
+            GetMetadataQueryWriter: GetMetadataQueryWriter::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IWICFastMetadataEncoder {}
+windows_core::imp::define_interface!(IWICFormatConverter, IWICFormatConverter_Vtbl, 0x00000301_a8f2_4877_ba0a_fd2b6645fb94);
+impl core::ops::Deref for IWICFormatConverter {
+    type Target = IWICBitmapSource;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(IWICFormatConverter, windows_core::IUnknown, IWICBitmapSource);
+impl IWICFormatConverter {
+    pub unsafe fn Initialize<P0, P3>(&self, pisource: P0, dstformat: *const windows_core::GUID, dither: WICBitmapDitherType, pipalette: P3, alphathresholdpercent: f64, palettetranslate: WICBitmapPaletteType) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<IWICBitmapSource>,
+        P3: windows_core::Param<IWICPalette>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).Initialize)(windows_core::Interface::as_raw(self), pisource.param().abi(), dstformat, dither, pipalette.param().abi(), alphathresholdpercent, palettetranslate).ok() }
+    }
+    pub unsafe fn CanConvert(&self, srcpixelformat: *const windows_core::GUID, dstpixelformat: *const windows_core::GUID) -> windows_core::Result<windows_core::BOOL> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CanConvert)(windows_core::Interface::as_raw(self), srcpixelformat, dstpixelformat, &mut result__).map(|| result__)
+        }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IWICFormatConverter_Vtbl {
+    pub base__: IWICBitmapSource_Vtbl,
+    pub Initialize: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *const windows_core::GUID, WICBitmapDitherType, *mut core::ffi::c_void, f64, WICBitmapPaletteType) -> windows_core::HRESULT,
+    pub CanConvert: unsafe extern "system" fn(*mut core::ffi::c_void, *const windows_core::GUID, *const windows_core::GUID, *mut windows_core::BOOL) -> windows_core::HRESULT,
+}
+pub trait IWICFormatConverter_Impl: IWICBitmapSource_Impl {
+    fn Initialize(&self, pisource: windows_core::Ref<IWICBitmapSource>, dstformat: *const windows_core::GUID, dither: WICBitmapDitherType, pipalette: windows_core::Ref<IWICPalette>, alphathresholdpercent: f64, palettetranslate: WICBitmapPaletteType) -> windows_core::Result<()>;
+    fn CanConvert(&self, srcpixelformat: *const windows_core::GUID, dstpixelformat: *const windows_core::GUID) -> windows_core::Result<windows_core::BOOL>;
+}
+impl IWICFormatConverter_Vtbl {
+    pub const fn new<Identity: IWICFormatConverter_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn Initialize<Identity: IWICFormatConverter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pisource: *mut core::ffi::c_void, dstformat: *const windows_core::GUID, dither: WICBitmapDitherType, pipalette: *mut core::ffi::c_void, alphathresholdpercent: f64, palettetranslate: WICBitmapPaletteType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWICFormatConverter_Impl::Initialize(this, core::mem::transmute_copy(&pisource), core::mem::transmute_copy(&dstformat), core::mem::transmute_copy(&dither), core::mem::transmute_copy(&pipalette), core::mem::transmute_copy(&alphathresholdpercent), core::mem::transmute_copy(&palettetranslate)).into()
+            }
+        }
+        unsafe extern "system" fn CanConvert<Identity: IWICFormatConverter_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, srcpixelformat: *const windows_core::GUID, dstpixelformat: *const windows_core::GUID, pfcanconvert: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
                 match IWICFormatConverter_Impl::CanConvert(this, core::mem::transmute_copy(&srcpixelformat), core::mem::transmute_copy(&dstpixelformat)) {
                     Ok(ok__) => {
                         pfcanconvert.write(core::mem::transmute(ok__));
@@ -3393,6 +4967,36 @@ impl IWICFormatConverter_Vtbl {
         iid == &<IWICFormatConverter as windows_core::Interface>::IID || iid == &<IWICBitmapSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICFormatConverter_Impl> windows_core::imp::TearOffVtable<Identity> for IWICFormatConverter_Vtbl
+where
+    IWICBitmapSource_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IWICFormatConverter_Impl>(this: *mut core::ffi::c_void, pisource: *mut core::ffi::c_void, dstformat: *const windows_core::GUID, dither: WICBitmapDitherType, pipalette: *mut core::ffi::c_void, alphathresholdpercent: f64, palettetranslate: WICBitmapPaletteType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICFormatConverter_Impl::Initialize(this, core::mem::transmute_copy(&pisource), core::mem::transmute_copy(&dstformat), core::mem::transmute_copy(&dither), core::mem::transmute_copy(&pipalette), core::mem::transmute_copy(&alphathresholdpercent), core::mem::transmute_copy(&palettetranslate)).into()
+            }
+        }
+        unsafe extern "system" fn CanConvert<Identity: windows_core::IUnknownImpl + IWICFormatConverter_Impl>(this: *mut core::ffi::c_void, srcpixelformat: *const windows_core::GUID, dstpixelformat: *const windows_core::GUID, pfcanconvert: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICFormatConverter_Impl::CanConvert(this, core::mem::transmute_copy(&srcpixelformat), core::mem::transmute_copy(&dstpixelformat)) {
+                    Ok(ok__) => {
+                        pfcanconvert.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWICBitmapSource_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            CanConvert: CanConvert::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWICFormatConverter {}
 windows_core::imp::define_interface!(IWICFormatConverterInfo, IWICFormatConverterInfo_Vtbl, 0x9f34fb65_13f4_4f15_bc57_3726b5e53d9f);
 impl core::ops::Deref for IWICFormatConverterInfo {
@@ -3454,6 +5058,36 @@ impl IWICFormatConverterInfo_Vtbl {
         iid == &<IWICFormatConverterInfo as windows_core::Interface>::IID || iid == &<IWICComponentInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICFormatConverterInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IWICFormatConverterInfo_Vtbl
+where
+    IWICComponentInfo_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPixelFormats<Identity: windows_core::IUnknownImpl + IWICFormatConverterInfo_Impl>(this: *mut core::ffi::c_void, cformats: u32, ppixelformatguids: *mut windows_core::GUID, pcactual: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICFormatConverterInfo_Impl::GetPixelFormats(this, core::mem::transmute_copy(&cformats), core::mem::transmute_copy(&ppixelformatguids), core::mem::transmute_copy(&pcactual)).into()
+            }
+        }
+        unsafe extern "system" fn CreateInstance<Identity: windows_core::IUnknownImpl + IWICFormatConverterInfo_Impl>(this: *mut core::ffi::c_void, ppiconverter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICFormatConverterInfo_Impl::CreateInstance(this) {
+                    Ok(ok__) => {
+                        ppiconverter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWICComponentInfo_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPixelFormats: GetPixelFormats::<Identity>,
+            CreateInstance: CreateInstance::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWICFormatConverterInfo {}
 windows_core::imp::define_interface!(IWICImagingFactory, IWICImagingFactory_Vtbl, 0xec5ec8a9_c395_4314_9c77_54d7a935ff70);
 windows_core::imp::interface_hierarchy!(IWICImagingFactory, windows_core::IUnknown);
@@ -4045,6 +5679,342 @@ impl IWICImagingFactory_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com", feature = "Win32_UI_WindowsAndMessaging"))]
+impl<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IWICImagingFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateDecoderFromFilename<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, wzfilename: windows_core::PCWSTR, pguidvendor: *const windows_core::GUID, dwdesiredaccess: super::super::Foundation::GENERIC_ACCESS_RIGHTS, metadataoptions: WICDecodeOptions, ppidecoder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateDecoderFromFilename(this, core::mem::transmute(&wzfilename), core::mem::transmute_copy(&pguidvendor), core::mem::transmute_copy(&dwdesiredaccess), core::mem::transmute_copy(&metadataoptions)) {
+                    Ok(ok__) => {
+                        ppidecoder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateDecoderFromStream<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, pistream: *mut core::ffi::c_void, pguidvendor: *const windows_core::GUID, metadataoptions: WICDecodeOptions, ppidecoder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateDecoderFromStream(this, core::mem::transmute_copy(&pistream), core::mem::transmute_copy(&pguidvendor), core::mem::transmute_copy(&metadataoptions)) {
+                    Ok(ok__) => {
+                        ppidecoder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateDecoderFromFileHandle<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, hfile: usize, pguidvendor: *const windows_core::GUID, metadataoptions: WICDecodeOptions, ppidecoder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateDecoderFromFileHandle(this, core::mem::transmute_copy(&hfile), core::mem::transmute_copy(&pguidvendor), core::mem::transmute_copy(&metadataoptions)) {
+                    Ok(ok__) => {
+                        ppidecoder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateComponentInfo<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, clsidcomponent: *const windows_core::GUID, ppiinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateComponentInfo(this, core::mem::transmute_copy(&clsidcomponent)) {
+                    Ok(ok__) => {
+                        ppiinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateDecoder<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, guidcontainerformat: *const windows_core::GUID, pguidvendor: *const windows_core::GUID, ppidecoder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateDecoder(this, core::mem::transmute_copy(&guidcontainerformat), core::mem::transmute_copy(&pguidvendor)) {
+                    Ok(ok__) => {
+                        ppidecoder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateEncoder<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, guidcontainerformat: *const windows_core::GUID, pguidvendor: *const windows_core::GUID, ppiencoder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateEncoder(this, core::mem::transmute_copy(&guidcontainerformat), core::mem::transmute_copy(&pguidvendor)) {
+                    Ok(ok__) => {
+                        ppiencoder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreatePalette<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, ppipalette: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreatePalette(this) {
+                    Ok(ok__) => {
+                        ppipalette.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateFormatConverter<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, ppiformatconverter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateFormatConverter(this) {
+                    Ok(ok__) => {
+                        ppiformatconverter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateBitmapScaler<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, ppibitmapscaler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateBitmapScaler(this) {
+                    Ok(ok__) => {
+                        ppibitmapscaler.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateBitmapClipper<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, ppibitmapclipper: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateBitmapClipper(this) {
+                    Ok(ok__) => {
+                        ppibitmapclipper.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateBitmapFlipRotator<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, ppibitmapfliprotator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateBitmapFlipRotator(this) {
+                    Ok(ok__) => {
+                        ppibitmapfliprotator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateStream<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, ppiwicstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateStream(this) {
+                    Ok(ok__) => {
+                        ppiwicstream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateColorContext<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, ppiwiccolorcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateColorContext(this) {
+                    Ok(ok__) => {
+                        ppiwiccolorcontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateColorTransformer<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, ppiwiccolortransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateColorTransformer(this) {
+                    Ok(ok__) => {
+                        ppiwiccolortransform.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateBitmap<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, uiwidth: u32, uiheight: u32, pixelformat: *const windows_core::GUID, option: WICBitmapCreateCacheOption, ppibitmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateBitmap(this, core::mem::transmute_copy(&uiwidth), core::mem::transmute_copy(&uiheight), core::mem::transmute_copy(&pixelformat), core::mem::transmute_copy(&option)) {
+                    Ok(ok__) => {
+                        ppibitmap.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateBitmapFromSource<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, pibitmapsource: *mut core::ffi::c_void, option: WICBitmapCreateCacheOption, ppibitmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateBitmapFromSource(this, core::mem::transmute_copy(&pibitmapsource), core::mem::transmute_copy(&option)) {
+                    Ok(ok__) => {
+                        ppibitmap.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateBitmapFromSourceRect<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, pibitmapsource: *mut core::ffi::c_void, x: u32, y: u32, width: u32, height: u32, ppibitmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateBitmapFromSourceRect(this, core::mem::transmute_copy(&pibitmapsource), core::mem::transmute_copy(&x), core::mem::transmute_copy(&y), core::mem::transmute_copy(&width), core::mem::transmute_copy(&height)) {
+                    Ok(ok__) => {
+                        ppibitmap.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateBitmapFromMemory<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, uiwidth: u32, uiheight: u32, pixelformat: *const windows_core::GUID, cbstride: u32, cbbuffersize: u32, pbbuffer: *const u8, ppibitmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateBitmapFromMemory(this, core::mem::transmute_copy(&uiwidth), core::mem::transmute_copy(&uiheight), core::mem::transmute_copy(&pixelformat), core::mem::transmute_copy(&cbstride), core::mem::transmute_copy(&cbbuffersize), core::mem::transmute_copy(&pbbuffer)) {
+                    Ok(ok__) => {
+                        ppibitmap.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateBitmapFromHBITMAP<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, hbitmap: super::Gdi::HBITMAP, hpalette: super::Gdi::HPALETTE, options: WICBitmapAlphaChannelOption, ppibitmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateBitmapFromHBITMAP(this, core::mem::transmute_copy(&hbitmap), core::mem::transmute_copy(&hpalette), core::mem::transmute_copy(&options)) {
+                    Ok(ok__) => {
+                        ppibitmap.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateBitmapFromHICON<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, hicon: super::super::UI::WindowsAndMessaging::HICON, ppibitmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateBitmapFromHICON(this, core::mem::transmute_copy(&hicon)) {
+                    Ok(ok__) => {
+                        ppibitmap.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateComponentEnumerator<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, componenttypes: u32, options: u32, ppienumunknown: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateComponentEnumerator(this, core::mem::transmute_copy(&componenttypes), core::mem::transmute_copy(&options)) {
+                    Ok(ok__) => {
+                        ppienumunknown.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateFastMetadataEncoderFromDecoder<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, pidecoder: *mut core::ffi::c_void, ppifastencoder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateFastMetadataEncoderFromDecoder(this, core::mem::transmute_copy(&pidecoder)) {
+                    Ok(ok__) => {
+                        ppifastencoder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateFastMetadataEncoderFromFrameDecode<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, piframedecoder: *mut core::ffi::c_void, ppifastencoder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateFastMetadataEncoderFromFrameDecode(this, core::mem::transmute_copy(&piframedecoder)) {
+                    Ok(ok__) => {
+                        ppifastencoder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateQueryWriter<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, guidmetadataformat: *const windows_core::GUID, pguidvendor: *const windows_core::GUID, ppiquerywriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateQueryWriter(this, core::mem::transmute_copy(&guidmetadataformat), core::mem::transmute_copy(&pguidvendor)) {
+                    Ok(ok__) => {
+                        ppiquerywriter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateQueryWriterFromReader<Identity: windows_core::IUnknownImpl + IWICImagingFactory_Impl>(this: *mut core::ffi::c_void, piqueryreader: *mut core::ffi::c_void, pguidvendor: *const windows_core::GUID, ppiquerywriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICImagingFactory_Impl::CreateQueryWriterFromReader(this, core::mem::transmute_copy(&piqueryreader), core::mem::transmute_copy(&pguidvendor)) {
+                    Ok(ok__) => {
+                        ppiquerywriter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateDecoderFromFilename: CreateDecoderFromFilename::<Identity>,
+            CreateDecoderFromStream: CreateDecoderFromStream::<Identity>,
+            CreateDecoderFromFileHandle: CreateDecoderFromFileHandle::<Identity>,
+            CreateComponentInfo: CreateComponentInfo::<Identity>,
+            CreateDecoder: CreateDecoder::<Identity>,
+            CreateEncoder: CreateEncoder::<Identity>,
+            CreatePalette: CreatePalette::<Identity>,
+            CreateFormatConverter: CreateFormatConverter::<Identity>,
+            CreateBitmapScaler: CreateBitmapScaler::<Identity>,
+            CreateBitmapClipper: CreateBitmapClipper::<Identity>,
+            CreateBitmapFlipRotator: CreateBitmapFlipRotator::<Identity>,
+            CreateStream: CreateStream::<Identity>,
+            CreateColorContext: CreateColorContext::<Identity>,
+            CreateColorTransformer: CreateColorTransformer::<Identity>,
+            CreateBitmap: CreateBitmap::<Identity>,
+            CreateBitmapFromSource: CreateBitmapFromSource::<Identity>,
+            CreateBitmapFromSourceRect: CreateBitmapFromSourceRect::<Identity>,
+            CreateBitmapFromMemory: CreateBitmapFromMemory::<Identity>,
+            CreateBitmapFromHBITMAP: CreateBitmapFromHBITMAP::<Identity>,
+            CreateBitmapFromHICON: CreateBitmapFromHICON::<Identity>,
+            CreateComponentEnumerator: CreateComponentEnumerator::<Identity>,
+            CreateFastMetadataEncoderFromDecoder: CreateFastMetadataEncoderFromDecoder::<Identity>,
+            CreateFastMetadataEncoderFromFrameDecode: CreateFastMetadataEncoderFromFrameDecode::<Identity>,
+            CreateQueryWriter: CreateQueryWriter::<Identity>,
+            CreateQueryWriterFromReader: CreateQueryWriterFromReader::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com", feature = "Win32_UI_WindowsAndMessaging"))]
 impl windows_core::RuntimeName for IWICImagingFactory {}
 windows_core::imp::define_interface!(IWICJpegFrameDecode, IWICJpegFrameDecode_Vtbl, 0x8939f66e_c46a_4c21_a9d1_98b327ce1679);
 windows_core::imp::interface_hierarchy!(IWICJpegFrameDecode, windows_core::IUnknown);
@@ -4211,6 +6181,93 @@ impl IWICJpegFrameDecode_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IWICJpegFrameDecode_Impl> windows_core::imp::TearOffVtable<Identity> for IWICJpegFrameDecode_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DoesSupportIndexing<Identity: windows_core::IUnknownImpl + IWICJpegFrameDecode_Impl>(this: *mut core::ffi::c_void, pfindexingsupported: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICJpegFrameDecode_Impl::DoesSupportIndexing(this) {
+                    Ok(ok__) => {
+                        pfindexingsupported.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetIndexing<Identity: windows_core::IUnknownImpl + IWICJpegFrameDecode_Impl>(this: *mut core::ffi::c_void, options: WICJpegIndexingOptions, horizontalintervalsize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICJpegFrameDecode_Impl::SetIndexing(this, core::mem::transmute_copy(&options), core::mem::transmute_copy(&horizontalintervalsize)).into()
+            }
+        }
+        unsafe extern "system" fn ClearIndexing<Identity: windows_core::IUnknownImpl + IWICJpegFrameDecode_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICJpegFrameDecode_Impl::ClearIndexing(this).into()
+            }
+        }
+        unsafe extern "system" fn GetAcHuffmanTable<Identity: windows_core::IUnknownImpl + IWICJpegFrameDecode_Impl>(this: *mut core::ffi::c_void, scanindex: u32, tableindex: u32, pachuffmantable: *mut super::Dxgi::Common::DXGI_JPEG_AC_HUFFMAN_TABLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICJpegFrameDecode_Impl::GetAcHuffmanTable(this, core::mem::transmute_copy(&scanindex), core::mem::transmute_copy(&tableindex), core::mem::transmute_copy(&pachuffmantable)).into()
+            }
+        }
+        unsafe extern "system" fn GetDcHuffmanTable<Identity: windows_core::IUnknownImpl + IWICJpegFrameDecode_Impl>(this: *mut core::ffi::c_void, scanindex: u32, tableindex: u32, pdchuffmantable: *mut super::Dxgi::Common::DXGI_JPEG_DC_HUFFMAN_TABLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICJpegFrameDecode_Impl::GetDcHuffmanTable(this, core::mem::transmute_copy(&scanindex), core::mem::transmute_copy(&tableindex), core::mem::transmute_copy(&pdchuffmantable)).into()
+            }
+        }
+        unsafe extern "system" fn GetQuantizationTable<Identity: windows_core::IUnknownImpl + IWICJpegFrameDecode_Impl>(this: *mut core::ffi::c_void, scanindex: u32, tableindex: u32, pquantizationtable: *mut super::Dxgi::Common::DXGI_JPEG_QUANTIZATION_TABLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICJpegFrameDecode_Impl::GetQuantizationTable(this, core::mem::transmute_copy(&scanindex), core::mem::transmute_copy(&tableindex), core::mem::transmute_copy(&pquantizationtable)).into()
+            }
+        }
+        unsafe extern "system" fn GetFrameHeader<Identity: windows_core::IUnknownImpl + IWICJpegFrameDecode_Impl>(this: *mut core::ffi::c_void, pframeheader: *mut WICJpegFrameHeader) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICJpegFrameDecode_Impl::GetFrameHeader(this, core::mem::transmute_copy(&pframeheader)).into()
+            }
+        }
+        unsafe extern "system" fn GetScanHeader<Identity: windows_core::IUnknownImpl + IWICJpegFrameDecode_Impl>(this: *mut core::ffi::c_void, scanindex: u32, pscanheader: *mut WICJpegScanHeader) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICJpegFrameDecode_Impl::GetScanHeader(this, core::mem::transmute_copy(&scanindex), core::mem::transmute_copy(&pscanheader)).into()
+            }
+        }
+        unsafe extern "system" fn CopyScan<Identity: windows_core::IUnknownImpl + IWICJpegFrameDecode_Impl>(this: *mut core::ffi::c_void, scanindex: u32, scanoffset: u32, cbscandata: u32, pbscandata: *mut u8, pcbscandataactual: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICJpegFrameDecode_Impl::CopyScan(this, core::mem::transmute_copy(&scanindex), core::mem::transmute_copy(&scanoffset), core::mem::transmute_copy(&cbscandata), core::mem::transmute_copy(&pbscandata), core::mem::transmute_copy(&pcbscandataactual)).into()
+            }
+        }
+        unsafe extern "system" fn CopyMinimalStream<Identity: windows_core::IUnknownImpl + IWICJpegFrameDecode_Impl>(this: *mut core::ffi::c_void, streamoffset: u32, cbstreamdata: u32, pbstreamdata: *mut u8, pcbstreamdataactual: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICJpegFrameDecode_Impl::CopyMinimalStream(this, core::mem::transmute_copy(&streamoffset), core::mem::transmute_copy(&cbstreamdata), core::mem::transmute_copy(&pbstreamdata), core::mem::transmute_copy(&pcbstreamdataactual)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DoesSupportIndexing: DoesSupportIndexing::<Identity>,
+            SetIndexing: SetIndexing::<Identity>,
+            ClearIndexing: ClearIndexing::<Identity>,
+            GetAcHuffmanTable: GetAcHuffmanTable::<Identity>,
+            GetDcHuffmanTable: GetDcHuffmanTable::<Identity>,
+            GetQuantizationTable: GetQuantizationTable::<Identity>,
+            GetFrameHeader: GetFrameHeader::<Identity>,
+            GetScanHeader: GetScanHeader::<Identity>,
+            CopyScan: CopyScan::<Identity>,
+            CopyMinimalStream: CopyMinimalStream::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IWICJpegFrameDecode {}
 windows_core::imp::define_interface!(IWICJpegFrameEncode, IWICJpegFrameEncode_Vtbl, 0x2f0c601f_d2c6_468c_abfa_49495d983ed1);
 windows_core::imp::interface_hierarchy!(IWICJpegFrameEncode, windows_core::IUnknown);
@@ -4296,6 +6353,45 @@ impl IWICJpegFrameEncode_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl<Identity: windows_core::IUnknownImpl + IWICJpegFrameEncode_Impl> windows_core::imp::TearOffVtable<Identity> for IWICJpegFrameEncode_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAcHuffmanTable<Identity: windows_core::IUnknownImpl + IWICJpegFrameEncode_Impl>(this: *mut core::ffi::c_void, scanindex: u32, tableindex: u32, pachuffmantable: *mut super::Dxgi::Common::DXGI_JPEG_AC_HUFFMAN_TABLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICJpegFrameEncode_Impl::GetAcHuffmanTable(this, core::mem::transmute_copy(&scanindex), core::mem::transmute_copy(&tableindex), core::mem::transmute_copy(&pachuffmantable)).into()
+            }
+        }
+        unsafe extern "system" fn GetDcHuffmanTable<Identity: windows_core::IUnknownImpl + IWICJpegFrameEncode_Impl>(this: *mut core::ffi::c_void, scanindex: u32, tableindex: u32, pdchuffmantable: *mut super::Dxgi::Common::DXGI_JPEG_DC_HUFFMAN_TABLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICJpegFrameEncode_Impl::GetDcHuffmanTable(this, core::mem::transmute_copy(&scanindex), core::mem::transmute_copy(&tableindex), core::mem::transmute_copy(&pdchuffmantable)).into()
+            }
+        }
+        unsafe extern "system" fn GetQuantizationTable<Identity: windows_core::IUnknownImpl + IWICJpegFrameEncode_Impl>(this: *mut core::ffi::c_void, scanindex: u32, tableindex: u32, pquantizationtable: *mut super::Dxgi::Common::DXGI_JPEG_QUANTIZATION_TABLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICJpegFrameEncode_Impl::GetQuantizationTable(this, core::mem::transmute_copy(&scanindex), core::mem::transmute_copy(&tableindex), core::mem::transmute_copy(&pquantizationtable)).into()
+            }
+        }
+        unsafe extern "system" fn WriteScan<Identity: windows_core::IUnknownImpl + IWICJpegFrameEncode_Impl>(this: *mut core::ffi::c_void, cbscandata: u32, pbscandata: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICJpegFrameEncode_Impl::WriteScan(this, core::mem::transmute_copy(&cbscandata), core::mem::transmute_copy(&pbscandata)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAcHuffmanTable: GetAcHuffmanTable::<Identity>,
+            GetDcHuffmanTable: GetDcHuffmanTable::<Identity>,
+            GetQuantizationTable: GetQuantizationTable::<Identity>,
+            WriteScan: WriteScan::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
 impl windows_core::RuntimeName for IWICJpegFrameEncode {}
 windows_core::imp::define_interface!(IWICMetadataBlockReader, IWICMetadataBlockReader_Vtbl, 0xfeaa2a8d_b3f3_43e4_b25c_d1de990a1ae1);
 windows_core::imp::interface_hierarchy!(IWICMetadataBlockReader, windows_core::IUnknown);
@@ -4409,6 +6505,69 @@ impl IWICMetadataBlockReader_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IWICMetadataBlockReader_Impl> windows_core::imp::TearOffVtable<Identity> for IWICMetadataBlockReader_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetContainerFormat<Identity: windows_core::IUnknownImpl + IWICMetadataBlockReader_Impl>(this: *mut core::ffi::c_void, pguidcontainerformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICMetadataBlockReader_Impl::GetContainerFormat(this) {
+                    Ok(ok__) => {
+                        pguidcontainerformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IWICMetadataBlockReader_Impl>(this: *mut core::ffi::c_void, pccount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICMetadataBlockReader_Impl::GetCount(this) {
+                    Ok(ok__) => {
+                        pccount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetReaderByIndex<Identity: windows_core::IUnknownImpl + IWICMetadataBlockReader_Impl>(this: *mut core::ffi::c_void, nindex: u32, ppimetadatareader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICMetadataBlockReader_Impl::GetReaderByIndex(this, core::mem::transmute_copy(&nindex)) {
+                    Ok(ok__) => {
+                        ppimetadatareader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetEnumerator<Identity: windows_core::IUnknownImpl + IWICMetadataBlockReader_Impl>(this: *mut core::ffi::c_void, ppienummetadata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICMetadataBlockReader_Impl::GetEnumerator(this) {
+                    Ok(ok__) => {
+                        ppienummetadata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetContainerFormat: GetContainerFormat::<Identity>,
+            GetCount: GetCount::<Identity>,
+            GetReaderByIndex: GetReaderByIndex::<Identity>,
+            GetEnumerator: GetEnumerator::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IWICMetadataBlockReader {}
 windows_core::imp::define_interface!(IWICMetadataBlockWriter, IWICMetadataBlockWriter_Vtbl, 0x08fb9676_b444_41e8_8dbe_6a53a542bff1);
 impl core::ops::Deref for IWICMetadataBlockWriter {
@@ -4518,6 +6677,58 @@ impl IWICMetadataBlockWriter_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IWICMetadataBlockWriter_Impl> windows_core::imp::TearOffVtable<Identity> for IWICMetadataBlockWriter_Vtbl
+where
+    IWICMetadataBlockReader_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InitializeFromBlockReader<Identity: windows_core::IUnknownImpl + IWICMetadataBlockWriter_Impl>(this: *mut core::ffi::c_void, pimdblockreader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICMetadataBlockWriter_Impl::InitializeFromBlockReader(this, core::mem::transmute_copy(&pimdblockreader)).into()
+            }
+        }
+        unsafe extern "system" fn GetWriterByIndex<Identity: windows_core::IUnknownImpl + IWICMetadataBlockWriter_Impl>(this: *mut core::ffi::c_void, nindex: u32, ppimetadatawriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICMetadataBlockWriter_Impl::GetWriterByIndex(this, core::mem::transmute_copy(&nindex)) {
+                    Ok(ok__) => {
+                        ppimetadatawriter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddWriter<Identity: windows_core::IUnknownImpl + IWICMetadataBlockWriter_Impl>(this: *mut core::ffi::c_void, pimetadatawriter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICMetadataBlockWriter_Impl::AddWriter(this, core::mem::transmute_copy(&pimetadatawriter)).into()
+            }
+        }
+        unsafe extern "system" fn SetWriterByIndex<Identity: windows_core::IUnknownImpl + IWICMetadataBlockWriter_Impl>(this: *mut core::ffi::c_void, nindex: u32, pimetadatawriter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICMetadataBlockWriter_Impl::SetWriterByIndex(this, core::mem::transmute_copy(&nindex), core::mem::transmute_copy(&pimetadatawriter)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveWriterByIndex<Identity: windows_core::IUnknownImpl + IWICMetadataBlockWriter_Impl>(this: *mut core::ffi::c_void, nindex: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICMetadataBlockWriter_Impl::RemoveWriterByIndex(this, core::mem::transmute_copy(&nindex)).into()
+            }
+        }
+        Self {
+            base__: <IWICMetadataBlockReader_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InitializeFromBlockReader: InitializeFromBlockReader::<Identity>,
+            GetWriterByIndex: GetWriterByIndex::<Identity>,
+            AddWriter: AddWriter::<Identity>,
+            SetWriterByIndex: SetWriterByIndex::<Identity>,
+            RemoveWriterByIndex: RemoveWriterByIndex::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IWICMetadataBlockWriter {}
 windows_core::imp::define_interface!(IWICMetadataHandlerInfo, IWICMetadataHandlerInfo_Vtbl, 0xaba958bf_c672_44d1_8d61_ce6df2e682c2);
 impl core::ops::Deref for IWICMetadataHandlerInfo {
@@ -4666,6 +6877,89 @@ impl IWICMetadataHandlerInfo_Vtbl {
         iid == &<IWICMetadataHandlerInfo as windows_core::Interface>::IID || iid == &<IWICComponentInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICMetadataHandlerInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IWICMetadataHandlerInfo_Vtbl
+where
+    IWICComponentInfo_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMetadataFormat<Identity: windows_core::IUnknownImpl + IWICMetadataHandlerInfo_Impl>(this: *mut core::ffi::c_void, pguidmetadataformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICMetadataHandlerInfo_Impl::GetMetadataFormat(this) {
+                    Ok(ok__) => {
+                        pguidmetadataformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetContainerFormats<Identity: windows_core::IUnknownImpl + IWICMetadataHandlerInfo_Impl>(this: *mut core::ffi::c_void, ccontainerformats: u32, pguidcontainerformats: *mut windows_core::GUID, pcchactual: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICMetadataHandlerInfo_Impl::GetContainerFormats(this, core::mem::transmute_copy(&ccontainerformats), core::mem::transmute_copy(&pguidcontainerformats), core::mem::transmute_copy(&pcchactual)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceManufacturer<Identity: windows_core::IUnknownImpl + IWICMetadataHandlerInfo_Impl>(this: *mut core::ffi::c_void, cchdevicemanufacturer: u32, wzdevicemanufacturer: windows_core::PWSTR, pcchactual: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICMetadataHandlerInfo_Impl::GetDeviceManufacturer(this, core::mem::transmute_copy(&cchdevicemanufacturer), core::mem::transmute_copy(&wzdevicemanufacturer), core::mem::transmute_copy(&pcchactual)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceModels<Identity: windows_core::IUnknownImpl + IWICMetadataHandlerInfo_Impl>(this: *mut core::ffi::c_void, cchdevicemodels: u32, wzdevicemodels: windows_core::PWSTR, pcchactual: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICMetadataHandlerInfo_Impl::GetDeviceModels(this, core::mem::transmute_copy(&cchdevicemodels), core::mem::transmute_copy(&wzdevicemodels), core::mem::transmute_copy(&pcchactual)).into()
+            }
+        }
+        unsafe extern "system" fn DoesRequireFullStream<Identity: windows_core::IUnknownImpl + IWICMetadataHandlerInfo_Impl>(this: *mut core::ffi::c_void, pfrequiresfullstream: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICMetadataHandlerInfo_Impl::DoesRequireFullStream(this) {
+                    Ok(ok__) => {
+                        pfrequiresfullstream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DoesSupportPadding<Identity: windows_core::IUnknownImpl + IWICMetadataHandlerInfo_Impl>(this: *mut core::ffi::c_void, pfsupportspadding: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICMetadataHandlerInfo_Impl::DoesSupportPadding(this) {
+                    Ok(ok__) => {
+                        pfsupportspadding.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DoesRequireFixedSize<Identity: windows_core::IUnknownImpl + IWICMetadataHandlerInfo_Impl>(this: *mut core::ffi::c_void, pffixedsize: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICMetadataHandlerInfo_Impl::DoesRequireFixedSize(this) {
+                    Ok(ok__) => {
+                        pffixedsize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWICComponentInfo_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetMetadataFormat: GetMetadataFormat::<Identity>,
+            GetContainerFormats: GetContainerFormats::<Identity>,
+            GetDeviceManufacturer: GetDeviceManufacturer::<Identity>,
+            GetDeviceModels: GetDeviceModels::<Identity>,
+            DoesRequireFullStream: DoesRequireFullStream::<Identity>,
+            DoesSupportPadding: DoesSupportPadding::<Identity>,
+            DoesRequireFixedSize: DoesRequireFixedSize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWICMetadataHandlerInfo {}
 windows_core::imp::define_interface!(IWICMetadataQueryReader, IWICMetadataQueryReader_Vtbl, 0x30989668_e1c9_4597_b395_458eedb808df);
 windows_core::imp::interface_hierarchy!(IWICMetadataQueryReader, windows_core::IUnknown);
@@ -4721,7 +7015,61 @@ impl IWICMetadataQueryReader_Vtbl {
     pub const fn new<Identity: IWICMetadataQueryReader_Impl, const OFFSET: isize>() -> Self {
         unsafe extern "system" fn GetContainerFormat<Identity: IWICMetadataQueryReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pguidcontainerformat: *mut windows_core::GUID) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWICMetadataQueryReader_Impl::GetContainerFormat(this) {
+                    Ok(ok__) => {
+                        pguidcontainerformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLocation<Identity: IWICMetadataQueryReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, cchmaxlength: u32, wznamespace: windows_core::PWSTR, pcchactuallength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWICMetadataQueryReader_Impl::GetLocation(this, core::mem::transmute_copy(&cchmaxlength), core::mem::transmute_copy(&wznamespace), core::mem::transmute_copy(&pcchactuallength)).into()
+            }
+        }
+        unsafe extern "system" fn GetMetadataByName<Identity: IWICMetadataQueryReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, wzname: windows_core::PCWSTR, pvarvalue: *mut super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWICMetadataQueryReader_Impl::GetMetadataByName(this, core::mem::transmute(&wzname), core::mem::transmute_copy(&pvarvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetEnumerator<Identity: IWICMetadataQueryReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppienumstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWICMetadataQueryReader_Impl::GetEnumerator(this) {
+                    Ok(ok__) => {
+                        ppienumstring.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            GetContainerFormat: GetContainerFormat::<Identity, OFFSET>,
+            GetLocation: GetLocation::<Identity, OFFSET>,
+            GetMetadataByName: GetMetadataByName::<Identity, OFFSET>,
+            GetEnumerator: GetEnumerator::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICMetadataQueryReader as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWICMetadataQueryReader_Impl> windows_core::imp::TearOffVtable<Identity> for IWICMetadataQueryReader_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetContainerFormat<Identity: windows_core::IUnknownImpl + IWICMetadataQueryReader_Impl>(this: *mut core::ffi::c_void, pguidcontainerformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWICMetadataQueryReader_Impl::GetContainerFormat(this) {
                     Ok(ok__) => {
                         pguidcontainerformat.write(core::mem::transmute(ok__));
@@ -4731,21 +7079,21 @@ impl IWICMetadataQueryReader_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetLocation<Identity: IWICMetadataQueryReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, cchmaxlength: u32, wznamespace: windows_core::PWSTR, pcchactuallength: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetLocation<Identity: windows_core::IUnknownImpl + IWICMetadataQueryReader_Impl>(this: *mut core::ffi::c_void, cchmaxlength: u32, wznamespace: windows_core::PWSTR, pcchactuallength: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWICMetadataQueryReader_Impl::GetLocation(this, core::mem::transmute_copy(&cchmaxlength), core::mem::transmute_copy(&wznamespace), core::mem::transmute_copy(&pcchactuallength)).into()
             }
         }
-        unsafe extern "system" fn GetMetadataByName<Identity: IWICMetadataQueryReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, wzname: windows_core::PCWSTR, pvarvalue: *mut super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetMetadataByName<Identity: windows_core::IUnknownImpl + IWICMetadataQueryReader_Impl>(this: *mut core::ffi::c_void, wzname: windows_core::PCWSTR, pvarvalue: *mut super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWICMetadataQueryReader_Impl::GetMetadataByName(this, core::mem::transmute(&wzname), core::mem::transmute_copy(&pvarvalue)).into()
             }
         }
-        unsafe extern "system" fn GetEnumerator<Identity: IWICMetadataQueryReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppienumstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetEnumerator<Identity: windows_core::IUnknownImpl + IWICMetadataQueryReader_Impl>(this: *mut core::ffi::c_void, ppienumstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWICMetadataQueryReader_Impl::GetEnumerator(this) {
                     Ok(ok__) => {
                         ppienumstring.write(core::mem::transmute(ok__));
@@ -4756,16 +7104,13 @@ impl IWICMetadataQueryReader_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            GetContainerFormat: GetContainerFormat::<Identity, OFFSET>,
-            GetLocation: GetLocation::<Identity, OFFSET>,
-            GetMetadataByName: GetMetadataByName::<Identity, OFFSET>,
-            GetEnumerator: GetEnumerator::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetContainerFormat: GetContainerFormat::<Identity>,
+            GetLocation: GetLocation::<Identity>,
+            GetMetadataByName: GetMetadataByName::<Identity>,
+            GetEnumerator: GetEnumerator::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWICMetadataQueryReader as windows_core::Interface>::IID
-    }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWICMetadataQueryReader {}
@@ -4833,6 +7178,31 @@ impl IWICMetadataQueryWriter_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWICMetadataQueryWriter_Impl> windows_core::imp::TearOffVtable<Identity> for IWICMetadataQueryWriter_Vtbl
+where
+    IWICMetadataQueryReader_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetMetadataByName<Identity: windows_core::IUnknownImpl + IWICMetadataQueryWriter_Impl>(this: *mut core::ffi::c_void, wzname: windows_core::PCWSTR, pvarvalue: *const super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICMetadataQueryWriter_Impl::SetMetadataByName(this, core::mem::transmute(&wzname), core::mem::transmute_copy(&pvarvalue)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveMetadataByName<Identity: windows_core::IUnknownImpl + IWICMetadataQueryWriter_Impl>(this: *mut core::ffi::c_void, wzname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICMetadataQueryWriter_Impl::RemoveMetadataByName(this, core::mem::transmute(&wzname)).into()
+            }
+        }
+        Self {
+            base__: <IWICMetadataQueryReader_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetMetadataByName: SetMetadataByName::<Identity>,
+            RemoveMetadataByName: RemoveMetadataByName::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWICMetadataQueryWriter {}
 windows_core::imp::define_interface!(IWICMetadataReader, IWICMetadataReader_Vtbl, 0x9204fe99_d8fc_4fd5_a001_9536b067a899);
 windows_core::imp::interface_hierarchy!(IWICMetadataReader, windows_core::IUnknown);
@@ -4974,6 +7344,83 @@ impl IWICMetadataReader_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWICMetadataReader_Impl> windows_core::imp::TearOffVtable<Identity> for IWICMetadataReader_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMetadataFormat<Identity: windows_core::IUnknownImpl + IWICMetadataReader_Impl>(this: *mut core::ffi::c_void, pguidmetadataformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICMetadataReader_Impl::GetMetadataFormat(this) {
+                    Ok(ok__) => {
+                        pguidmetadataformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMetadataHandlerInfo<Identity: windows_core::IUnknownImpl + IWICMetadataReader_Impl>(this: *mut core::ffi::c_void, ppihandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICMetadataReader_Impl::GetMetadataHandlerInfo(this) {
+                    Ok(ok__) => {
+                        ppihandler.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IWICMetadataReader_Impl>(this: *mut core::ffi::c_void, pccount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICMetadataReader_Impl::GetCount(this) {
+                    Ok(ok__) => {
+                        pccount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetValueByIndex<Identity: windows_core::IUnknownImpl + IWICMetadataReader_Impl>(this: *mut core::ffi::c_void, nindex: u32, pvarschema: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, pvarid: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, pvarvalue: *mut super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICMetadataReader_Impl::GetValueByIndex(this, core::mem::transmute_copy(&nindex), core::mem::transmute_copy(&pvarschema), core::mem::transmute_copy(&pvarid), core::mem::transmute_copy(&pvarvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetValue<Identity: windows_core::IUnknownImpl + IWICMetadataReader_Impl>(this: *mut core::ffi::c_void, pvarschema: *const super::super::System::Com::StructuredStorage::PROPVARIANT, pvarid: *const super::super::System::Com::StructuredStorage::PROPVARIANT, pvarvalue: *mut super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICMetadataReader_Impl::GetValue(this, core::mem::transmute_copy(&pvarschema), core::mem::transmute_copy(&pvarid), core::mem::transmute_copy(&pvarvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetEnumerator<Identity: windows_core::IUnknownImpl + IWICMetadataReader_Impl>(this: *mut core::ffi::c_void, ppienummetadata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICMetadataReader_Impl::GetEnumerator(this) {
+                    Ok(ok__) => {
+                        ppienummetadata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetMetadataFormat: GetMetadataFormat::<Identity>,
+            GetMetadataHandlerInfo: GetMetadataHandlerInfo::<Identity>,
+            GetCount: GetCount::<Identity>,
+            GetValueByIndex: GetValueByIndex::<Identity>,
+            GetValue: GetValue::<Identity>,
+            GetEnumerator: GetEnumerator::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWICMetadataReader {}
 windows_core::imp::define_interface!(IWICMetadataReaderInfo, IWICMetadataReaderInfo_Vtbl, 0xeebf1f5b_07c1_4447_a3ab_22acaf78a804);
 impl core::ops::Deref for IWICMetadataReaderInfo {
@@ -5066,6 +7513,50 @@ impl IWICMetadataReaderInfo_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IWICMetadataReaderInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IWICMetadataReaderInfo_Vtbl
+where
+    IWICMetadataHandlerInfo_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPatterns<Identity: windows_core::IUnknownImpl + IWICMetadataReaderInfo_Impl>(this: *mut core::ffi::c_void, guidcontainerformat: *const windows_core::GUID, cbsize: u32, ppattern: *mut WICMetadataPattern, pccount: *mut u32, pcbactual: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICMetadataReaderInfo_Impl::GetPatterns(this, core::mem::transmute_copy(&guidcontainerformat), core::mem::transmute_copy(&cbsize), core::mem::transmute_copy(&ppattern), core::mem::transmute_copy(&pccount), core::mem::transmute_copy(&pcbactual)).into()
+            }
+        }
+        unsafe extern "system" fn MatchesPattern<Identity: windows_core::IUnknownImpl + IWICMetadataReaderInfo_Impl>(this: *mut core::ffi::c_void, guidcontainerformat: *const windows_core::GUID, pistream: *mut core::ffi::c_void, pfmatches: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICMetadataReaderInfo_Impl::MatchesPattern(this, core::mem::transmute_copy(&guidcontainerformat), core::mem::transmute_copy(&pistream)) {
+                    Ok(ok__) => {
+                        pfmatches.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateInstance<Identity: windows_core::IUnknownImpl + IWICMetadataReaderInfo_Impl>(this: *mut core::ffi::c_void, ppireader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICMetadataReaderInfo_Impl::CreateInstance(this) {
+                    Ok(ok__) => {
+                        ppireader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWICMetadataHandlerInfo_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPatterns: GetPatterns::<Identity>,
+            MatchesPattern: MatchesPattern::<Identity>,
+            CreateInstance: CreateInstance::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IWICMetadataReaderInfo {}
 windows_core::imp::define_interface!(IWICMetadataWriter, IWICMetadataWriter_Vtbl, 0xf7836e16_3be0_470b_86bb_160d0aecd7de);
 impl core::ops::Deref for IWICMetadataWriter {
@@ -5157,6 +7648,45 @@ impl IWICMetadataWriter_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWICMetadataWriter_Impl> windows_core::imp::TearOffVtable<Identity> for IWICMetadataWriter_Vtbl
+where
+    IWICMetadataReader_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetValue<Identity: windows_core::IUnknownImpl + IWICMetadataWriter_Impl>(this: *mut core::ffi::c_void, pvarschema: *const super::super::System::Com::StructuredStorage::PROPVARIANT, pvarid: *const super::super::System::Com::StructuredStorage::PROPVARIANT, pvarvalue: *const super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICMetadataWriter_Impl::SetValue(this, core::mem::transmute_copy(&pvarschema), core::mem::transmute_copy(&pvarid), core::mem::transmute_copy(&pvarvalue)).into()
+            }
+        }
+        unsafe extern "system" fn SetValueByIndex<Identity: windows_core::IUnknownImpl + IWICMetadataWriter_Impl>(this: *mut core::ffi::c_void, nindex: u32, pvarschema: *const super::super::System::Com::StructuredStorage::PROPVARIANT, pvarid: *const super::super::System::Com::StructuredStorage::PROPVARIANT, pvarvalue: *const super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICMetadataWriter_Impl::SetValueByIndex(this, core::mem::transmute_copy(&nindex), core::mem::transmute_copy(&pvarschema), core::mem::transmute_copy(&pvarid), core::mem::transmute_copy(&pvarvalue)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveValue<Identity: windows_core::IUnknownImpl + IWICMetadataWriter_Impl>(this: *mut core::ffi::c_void, pvarschema: *const super::super::System::Com::StructuredStorage::PROPVARIANT, pvarid: *const super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICMetadataWriter_Impl::RemoveValue(this, core::mem::transmute_copy(&pvarschema), core::mem::transmute_copy(&pvarid)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveValueByIndex<Identity: windows_core::IUnknownImpl + IWICMetadataWriter_Impl>(this: *mut core::ffi::c_void, nindex: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICMetadataWriter_Impl::RemoveValueByIndex(this, core::mem::transmute_copy(&nindex)).into()
+            }
+        }
+        Self {
+            base__: <IWICMetadataReader_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetValue: SetValue::<Identity>,
+            SetValueByIndex: SetValueByIndex::<Identity>,
+            RemoveValue: RemoveValue::<Identity>,
+            RemoveValueByIndex: RemoveValueByIndex::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWICMetadataWriter {}
 windows_core::imp::define_interface!(IWICMetadataWriterInfo, IWICMetadataWriterInfo_Vtbl, 0xb22e3fba_3925_4323_b5c1_9ebfc430f236);
 impl core::ops::Deref for IWICMetadataWriterInfo {
@@ -5218,6 +7748,36 @@ impl IWICMetadataWriterInfo_Vtbl {
         iid == &<IWICMetadataWriterInfo as windows_core::Interface>::IID || iid == &<IWICComponentInfo as windows_core::Interface>::IID || iid == &<IWICMetadataHandlerInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICMetadataWriterInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IWICMetadataWriterInfo_Vtbl
+where
+    IWICMetadataHandlerInfo_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetHeader<Identity: windows_core::IUnknownImpl + IWICMetadataWriterInfo_Impl>(this: *mut core::ffi::c_void, guidcontainerformat: *const windows_core::GUID, cbsize: u32, pheader: *mut WICMetadataHeader, pcbactual: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICMetadataWriterInfo_Impl::GetHeader(this, core::mem::transmute_copy(&guidcontainerformat), core::mem::transmute_copy(&cbsize), core::mem::transmute_copy(&pheader), core::mem::transmute_copy(&pcbactual)).into()
+            }
+        }
+        unsafe extern "system" fn CreateInstance<Identity: windows_core::IUnknownImpl + IWICMetadataWriterInfo_Impl>(this: *mut core::ffi::c_void, ppiwriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICMetadataWriterInfo_Impl::CreateInstance(this) {
+                    Ok(ok__) => {
+                        ppiwriter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWICMetadataHandlerInfo_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetHeader: GetHeader::<Identity>,
+            CreateInstance: CreateInstance::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWICMetadataWriterInfo {}
 windows_core::imp::define_interface!(IWICPalette, IWICPalette_Vtbl, 0x00000040_a8f2_4877_ba0a_fd2b6645fb94);
 windows_core::imp::interface_hierarchy!(IWICPalette, windows_core::IUnknown);
@@ -5341,7 +7901,120 @@ impl IWICPalette_Vtbl {
         }
         unsafe extern "system" fn GetColorCount<Identity: IWICPalette_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pccount: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWICPalette_Impl::GetColorCount(this) {
+                    Ok(ok__) => {
+                        pccount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetColors<Identity: IWICPalette_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ccount: u32, pcolors: *mut u32, pcactualcolors: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWICPalette_Impl::GetColors(this, core::mem::transmute_copy(&ccount), core::mem::transmute_copy(&pcolors), core::mem::transmute_copy(&pcactualcolors)).into()
+            }
+        }
+        unsafe extern "system" fn IsBlackWhite<Identity: IWICPalette_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfisblackwhite: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWICPalette_Impl::IsBlackWhite(this) {
+                    Ok(ok__) => {
+                        pfisblackwhite.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsGrayscale<Identity: IWICPalette_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfisgrayscale: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWICPalette_Impl::IsGrayscale(this) {
+                    Ok(ok__) => {
+                        pfisgrayscale.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn HasAlpha<Identity: IWICPalette_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfhasalpha: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWICPalette_Impl::HasAlpha(this) {
+                    Ok(ok__) => {
+                        pfhasalpha.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            InitializePredefined: InitializePredefined::<Identity, OFFSET>,
+            InitializeCustom: InitializeCustom::<Identity, OFFSET>,
+            InitializeFromBitmap: InitializeFromBitmap::<Identity, OFFSET>,
+            InitializeFromPalette: InitializeFromPalette::<Identity, OFFSET>,
+            GetType: GetType::<Identity, OFFSET>,
+            GetColorCount: GetColorCount::<Identity, OFFSET>,
+            GetColors: GetColors::<Identity, OFFSET>,
+            IsBlackWhite: IsBlackWhite::<Identity, OFFSET>,
+            IsGrayscale: IsGrayscale::<Identity, OFFSET>,
+            HasAlpha: HasAlpha::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICPalette as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IWICPalette_Impl> windows_core::imp::TearOffVtable<Identity> for IWICPalette_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InitializePredefined<Identity: windows_core::IUnknownImpl + IWICPalette_Impl>(this: *mut core::ffi::c_void, epalettetype: WICBitmapPaletteType, faddtransparentcolor: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICPalette_Impl::InitializePredefined(this, core::mem::transmute_copy(&epalettetype), core::mem::transmute_copy(&faddtransparentcolor)).into()
+            }
+        }
+        unsafe extern "system" fn InitializeCustom<Identity: windows_core::IUnknownImpl + IWICPalette_Impl>(this: *mut core::ffi::c_void, pcolors: *const u32, ccount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICPalette_Impl::InitializeCustom(this, core::mem::transmute_copy(&pcolors), core::mem::transmute_copy(&ccount)).into()
+            }
+        }
+        unsafe extern "system" fn InitializeFromBitmap<Identity: windows_core::IUnknownImpl + IWICPalette_Impl>(this: *mut core::ffi::c_void, pisurface: *mut core::ffi::c_void, ccount: u32, faddtransparentcolor: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICPalette_Impl::InitializeFromBitmap(this, core::mem::transmute_copy(&pisurface), core::mem::transmute_copy(&ccount), core::mem::transmute_copy(&faddtransparentcolor)).into()
+            }
+        }
+        unsafe extern "system" fn InitializeFromPalette<Identity: windows_core::IUnknownImpl + IWICPalette_Impl>(this: *mut core::ffi::c_void, pipalette: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICPalette_Impl::InitializeFromPalette(this, core::mem::transmute_copy(&pipalette)).into()
+            }
+        }
+        unsafe extern "system" fn GetType<Identity: windows_core::IUnknownImpl + IWICPalette_Impl>(this: *mut core::ffi::c_void, pepalettetype: *mut WICBitmapPaletteType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICPalette_Impl::GetType(this) {
+                    Ok(ok__) => {
+                        pepalettetype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetColorCount<Identity: windows_core::IUnknownImpl + IWICPalette_Impl>(this: *mut core::ffi::c_void, pccount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWICPalette_Impl::GetColorCount(this) {
                     Ok(ok__) => {
                         pccount.write(core::mem::transmute(ok__));
@@ -5351,15 +8024,15 @@ impl IWICPalette_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetColors<Identity: IWICPalette_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ccount: u32, pcolors: *mut u32, pcactualcolors: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetColors<Identity: windows_core::IUnknownImpl + IWICPalette_Impl>(this: *mut core::ffi::c_void, ccount: u32, pcolors: *mut u32, pcactualcolors: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWICPalette_Impl::GetColors(this, core::mem::transmute_copy(&ccount), core::mem::transmute_copy(&pcolors), core::mem::transmute_copy(&pcactualcolors)).into()
             }
         }
-        unsafe extern "system" fn IsBlackWhite<Identity: IWICPalette_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfisblackwhite: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn IsBlackWhite<Identity: windows_core::IUnknownImpl + IWICPalette_Impl>(this: *mut core::ffi::c_void, pfisblackwhite: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWICPalette_Impl::IsBlackWhite(this) {
                     Ok(ok__) => {
                         pfisblackwhite.write(core::mem::transmute(ok__));
@@ -5369,9 +8042,9 @@ impl IWICPalette_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn IsGrayscale<Identity: IWICPalette_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfisgrayscale: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn IsGrayscale<Identity: windows_core::IUnknownImpl + IWICPalette_Impl>(this: *mut core::ffi::c_void, pfisgrayscale: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWICPalette_Impl::IsGrayscale(this) {
                     Ok(ok__) => {
                         pfisgrayscale.write(core::mem::transmute(ok__));
@@ -5381,9 +8054,9 @@ impl IWICPalette_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn HasAlpha<Identity: IWICPalette_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfhasalpha: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn HasAlpha<Identity: windows_core::IUnknownImpl + IWICPalette_Impl>(this: *mut core::ffi::c_void, pfhasalpha: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWICPalette_Impl::HasAlpha(this) {
                     Ok(ok__) => {
                         pfhasalpha.write(core::mem::transmute(ok__));
@@ -5394,22 +8067,19 @@ impl IWICPalette_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            InitializePredefined: InitializePredefined::<Identity, OFFSET>,
-            InitializeCustom: InitializeCustom::<Identity, OFFSET>,
-            InitializeFromBitmap: InitializeFromBitmap::<Identity, OFFSET>,
-            InitializeFromPalette: InitializeFromPalette::<Identity, OFFSET>,
-            GetType: GetType::<Identity, OFFSET>,
-            GetColorCount: GetColorCount::<Identity, OFFSET>,
-            GetColors: GetColors::<Identity, OFFSET>,
-            IsBlackWhite: IsBlackWhite::<Identity, OFFSET>,
-            IsGrayscale: IsGrayscale::<Identity, OFFSET>,
-            HasAlpha: HasAlpha::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWICPalette as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InitializePredefined: InitializePredefined::<Identity>,
+            InitializeCustom: InitializeCustom::<Identity>,
+            InitializeFromBitmap: InitializeFromBitmap::<Identity>,
+            InitializeFromPalette: InitializeFromPalette::<Identity>,
+            GetType: GetType::<Identity>,
+            GetColorCount: GetColorCount::<Identity>,
+            GetColors: GetColors::<Identity>,
+            IsBlackWhite: IsBlackWhite::<Identity>,
+            IsGrayscale: IsGrayscale::<Identity>,
+            HasAlpha: HasAlpha::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IWICPalette {}
 #[cfg(feature = "Win32_System_Com")]
@@ -5477,6 +8147,31 @@ impl IWICPersistStream_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IWICPersistStream_Impl> windows_core::imp::TearOffVtable<Identity> for IWICPersistStream_Vtbl
+where
+    super::super::System::Com::IPersistStream_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn LoadEx<Identity: windows_core::IUnknownImpl + IWICPersistStream_Impl>(this: *mut core::ffi::c_void, pistream: *mut core::ffi::c_void, pguidpreferredvendor: *const windows_core::GUID, dwpersistoptions: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICPersistStream_Impl::LoadEx(this, core::mem::transmute_copy(&pistream), core::mem::transmute_copy(&pguidpreferredvendor), core::mem::transmute_copy(&dwpersistoptions)).into()
+            }
+        }
+        unsafe extern "system" fn SaveEx<Identity: windows_core::IUnknownImpl + IWICPersistStream_Impl>(this: *mut core::ffi::c_void, pistream: *mut core::ffi::c_void, dwpersistoptions: u32, fcleardirty: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICPersistStream_Impl::SaveEx(this, core::mem::transmute_copy(&pistream), core::mem::transmute_copy(&dwpersistoptions), core::mem::transmute_copy(&fcleardirty)).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IPersistStream_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            LoadEx: LoadEx::<Identity>,
+            SaveEx: SaveEx::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IWICPersistStream {}
 windows_core::imp::define_interface!(IWICPixelFormatInfo, IWICPixelFormatInfo_Vtbl, 0xe8eda601_3d48_431a_ab44_69059be88bbe);
 impl core::ops::Deref for IWICPixelFormatInfo {
@@ -5601,6 +8296,75 @@ impl IWICPixelFormatInfo_Vtbl {
         iid == &<IWICPixelFormatInfo as windows_core::Interface>::IID || iid == &<IWICComponentInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICPixelFormatInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IWICPixelFormatInfo_Vtbl
+where
+    IWICComponentInfo_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFormatGUID<Identity: windows_core::IUnknownImpl + IWICPixelFormatInfo_Impl>(this: *mut core::ffi::c_void, pformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICPixelFormatInfo_Impl::GetFormatGUID(this) {
+                    Ok(ok__) => {
+                        pformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetColorContext<Identity: windows_core::IUnknownImpl + IWICPixelFormatInfo_Impl>(this: *mut core::ffi::c_void, ppicolorcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICPixelFormatInfo_Impl::GetColorContext(this) {
+                    Ok(ok__) => {
+                        ppicolorcontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetBitsPerPixel<Identity: windows_core::IUnknownImpl + IWICPixelFormatInfo_Impl>(this: *mut core::ffi::c_void, puibitsperpixel: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICPixelFormatInfo_Impl::GetBitsPerPixel(this) {
+                    Ok(ok__) => {
+                        puibitsperpixel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetChannelCount<Identity: windows_core::IUnknownImpl + IWICPixelFormatInfo_Impl>(this: *mut core::ffi::c_void, puichannelcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICPixelFormatInfo_Impl::GetChannelCount(this) {
+                    Ok(ok__) => {
+                        puichannelcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetChannelMask<Identity: windows_core::IUnknownImpl + IWICPixelFormatInfo_Impl>(this: *mut core::ffi::c_void, uichannelindex: u32, cbmaskbuffer: u32, pbmaskbuffer: *mut u8, pcbactual: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICPixelFormatInfo_Impl::GetChannelMask(this, core::mem::transmute_copy(&uichannelindex), core::mem::transmute_copy(&cbmaskbuffer), core::mem::transmute_copy(&pbmaskbuffer), core::mem::transmute_copy(&pcbactual)).into()
+            }
+        }
+        Self {
+            base__: <IWICComponentInfo_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFormatGUID: GetFormatGUID::<Identity>,
+            GetColorContext: GetColorContext::<Identity>,
+            GetBitsPerPixel: GetBitsPerPixel::<Identity>,
+            GetChannelCount: GetChannelCount::<Identity>,
+            GetChannelMask: GetChannelMask::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWICPixelFormatInfo {}
 windows_core::imp::define_interface!(IWICPixelFormatInfo2, IWICPixelFormatInfo2_Vtbl, 0xa9db33a2_af5f_43c7_b679_74f5984b5aa4);
 impl core::ops::Deref for IWICPixelFormatInfo2 {
@@ -5671,6 +8435,42 @@ impl IWICPixelFormatInfo2_Vtbl {
         iid == &<IWICPixelFormatInfo2 as windows_core::Interface>::IID || iid == &<IWICComponentInfo as windows_core::Interface>::IID || iid == &<IWICPixelFormatInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICPixelFormatInfo2_Impl> windows_core::imp::TearOffVtable<Identity> for IWICPixelFormatInfo2_Vtbl
+where
+    IWICPixelFormatInfo_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SupportsTransparency<Identity: windows_core::IUnknownImpl + IWICPixelFormatInfo2_Impl>(this: *mut core::ffi::c_void, pfsupportstransparency: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICPixelFormatInfo2_Impl::SupportsTransparency(this) {
+                    Ok(ok__) => {
+                        pfsupportstransparency.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetNumericRepresentation<Identity: windows_core::IUnknownImpl + IWICPixelFormatInfo2_Impl>(this: *mut core::ffi::c_void, pnumericrepresentation: *mut WICPixelFormatNumericRepresentation) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICPixelFormatInfo2_Impl::GetNumericRepresentation(this) {
+                    Ok(ok__) => {
+                        pnumericrepresentation.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWICPixelFormatInfo_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SupportsTransparency: SupportsTransparency::<Identity>,
+            GetNumericRepresentation: GetNumericRepresentation::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWICPixelFormatInfo2 {}
 windows_core::imp::define_interface!(IWICPlanarBitmapFrameEncode, IWICPlanarBitmapFrameEncode_Vtbl, 0xf928b7b8_2221_40c1_b72e_7e82f1974d1a);
 windows_core::imp::interface_hierarchy!(IWICPlanarBitmapFrameEncode, windows_core::IUnknown);
@@ -5717,6 +8517,30 @@ impl IWICPlanarBitmapFrameEncode_Vtbl {
         iid == &<IWICPlanarBitmapFrameEncode as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICPlanarBitmapFrameEncode_Impl> windows_core::imp::TearOffVtable<Identity> for IWICPlanarBitmapFrameEncode_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn WritePixels<Identity: windows_core::IUnknownImpl + IWICPlanarBitmapFrameEncode_Impl>(this: *mut core::ffi::c_void, linecount: u32, pplanes: *const WICBitmapPlane, cplanes: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICPlanarBitmapFrameEncode_Impl::WritePixels(this, core::mem::transmute_copy(&linecount), core::mem::transmute_copy(&pplanes), core::mem::transmute_copy(&cplanes)).into()
+            }
+        }
+        unsafe extern "system" fn WriteSource<Identity: windows_core::IUnknownImpl + IWICPlanarBitmapFrameEncode_Impl>(this: *mut core::ffi::c_void, ppplanes: *const *mut core::ffi::c_void, cplanes: u32, prcsource: *const WICRect) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICPlanarBitmapFrameEncode_Impl::WriteSource(this, core::mem::transmute_copy(&ppplanes), core::mem::transmute_copy(&cplanes), core::mem::transmute_copy(&prcsource)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            WritePixels: WritePixels::<Identity>,
+            WriteSource: WriteSource::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWICPlanarBitmapFrameEncode {}
 windows_core::imp::define_interface!(IWICPlanarBitmapSourceTransform, IWICPlanarBitmapSourceTransform_Vtbl, 0x3aff9cce_be95_4303_b927_e7d16ff4a613);
 windows_core::imp::interface_hierarchy!(IWICPlanarBitmapSourceTransform, windows_core::IUnknown);
@@ -5763,6 +8587,30 @@ impl IWICPlanarBitmapSourceTransform_Vtbl {
         iid == &<IWICPlanarBitmapSourceTransform as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICPlanarBitmapSourceTransform_Impl> windows_core::imp::TearOffVtable<Identity> for IWICPlanarBitmapSourceTransform_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DoesSupportTransform<Identity: windows_core::IUnknownImpl + IWICPlanarBitmapSourceTransform_Impl>(this: *mut core::ffi::c_void, puiwidth: *mut u32, puiheight: *mut u32, dsttransform: WICBitmapTransformOptions, dstplanaroptions: WICPlanarOptions, pguiddstformats: *const windows_core::GUID, pplanedescriptions: *mut WICBitmapPlaneDescription, cplanes: u32, pfissupported: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICPlanarBitmapSourceTransform_Impl::DoesSupportTransform(this, core::mem::transmute_copy(&puiwidth), core::mem::transmute_copy(&puiheight), core::mem::transmute_copy(&dsttransform), core::mem::transmute_copy(&dstplanaroptions), core::mem::transmute_copy(&pguiddstformats), core::mem::transmute_copy(&pplanedescriptions), core::mem::transmute_copy(&cplanes), core::mem::transmute_copy(&pfissupported)).into()
+            }
+        }
+        unsafe extern "system" fn CopyPixels<Identity: windows_core::IUnknownImpl + IWICPlanarBitmapSourceTransform_Impl>(this: *mut core::ffi::c_void, prcsource: *const WICRect, uiwidth: u32, uiheight: u32, dsttransform: WICBitmapTransformOptions, dstplanaroptions: WICPlanarOptions, pdstplanes: *const WICBitmapPlane, cplanes: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICPlanarBitmapSourceTransform_Impl::CopyPixels(this, core::mem::transmute_copy(&prcsource), core::mem::transmute_copy(&uiwidth), core::mem::transmute_copy(&uiheight), core::mem::transmute_copy(&dsttransform), core::mem::transmute_copy(&dstplanaroptions), core::mem::transmute_copy(&pdstplanes), core::mem::transmute_copy(&cplanes)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DoesSupportTransform: DoesSupportTransform::<Identity>,
+            CopyPixels: CopyPixels::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWICPlanarBitmapSourceTransform {}
 windows_core::imp::define_interface!(IWICPlanarFormatConverter, IWICPlanarFormatConverter_Vtbl, 0xbebee9cb_83b0_4dcc_8132_b0aaa55eac96);
 impl core::ops::Deref for IWICPlanarFormatConverter {
@@ -5823,6 +8671,36 @@ impl IWICPlanarFormatConverter_Vtbl {
         iid == &<IWICPlanarFormatConverter as windows_core::Interface>::IID || iid == &<IWICBitmapSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICPlanarFormatConverter_Impl> windows_core::imp::TearOffVtable<Identity> for IWICPlanarFormatConverter_Vtbl
+where
+    IWICBitmapSource_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IWICPlanarFormatConverter_Impl>(this: *mut core::ffi::c_void, ppplanes: *const *mut core::ffi::c_void, cplanes: u32, dstformat: *const windows_core::GUID, dither: WICBitmapDitherType, pipalette: *mut core::ffi::c_void, alphathresholdpercent: f64, palettetranslate: WICBitmapPaletteType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICPlanarFormatConverter_Impl::Initialize(this, core::mem::transmute_copy(&ppplanes), core::mem::transmute_copy(&cplanes), core::mem::transmute_copy(&dstformat), core::mem::transmute_copy(&dither), core::mem::transmute_copy(&pipalette), core::mem::transmute_copy(&alphathresholdpercent), core::mem::transmute_copy(&palettetranslate)).into()
+            }
+        }
+        unsafe extern "system" fn CanConvert<Identity: windows_core::IUnknownImpl + IWICPlanarFormatConverter_Impl>(this: *mut core::ffi::c_void, psrcpixelformats: *const windows_core::GUID, csrcplanes: u32, dstpixelformat: *const windows_core::GUID, pfcanconvert: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICPlanarFormatConverter_Impl::CanConvert(this, core::mem::transmute_copy(&psrcpixelformats), core::mem::transmute_copy(&csrcplanes), core::mem::transmute_copy(&dstpixelformat)) {
+                    Ok(ok__) => {
+                        pfcanconvert.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWICBitmapSource_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            CanConvert: CanConvert::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWICPlanarFormatConverter {}
 windows_core::imp::define_interface!(IWICProgressCallback, IWICProgressCallback_Vtbl, 0x4776f9cd_9517_45fa_bf24_e89c5ec5c60c);
 windows_core::imp::interface_hierarchy!(IWICProgressCallback, windows_core::IUnknown);
@@ -5854,6 +8732,20 @@ impl IWICProgressCallback_Vtbl {
         iid == &<IWICProgressCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICProgressCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IWICProgressCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Notify<Identity: windows_core::IUnknownImpl + IWICProgressCallback_Impl>(this: *mut core::ffi::c_void, uframenum: u32, operation: WICProgressOperation, dblprogress: f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICProgressCallback_Impl::Notify(this, core::mem::transmute_copy(&uframenum), core::mem::transmute_copy(&operation), core::mem::transmute_copy(&dblprogress)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Notify: Notify::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWICProgressCallback {}
 windows_core::imp::define_interface!(IWICProgressiveLevelControl, IWICProgressiveLevelControl_Vtbl, 0xdaac296f_7aa5_4dbf_8d15_225c5976f891);
 windows_core::imp::interface_hierarchy!(IWICProgressiveLevelControl, windows_core::IUnknown);
@@ -5930,6 +8822,49 @@ impl IWICProgressiveLevelControl_Vtbl {
         iid == &<IWICProgressiveLevelControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWICProgressiveLevelControl_Impl> windows_core::imp::TearOffVtable<Identity> for IWICProgressiveLevelControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetLevelCount<Identity: windows_core::IUnknownImpl + IWICProgressiveLevelControl_Impl>(this: *mut core::ffi::c_void, pclevels: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICProgressiveLevelControl_Impl::GetLevelCount(this) {
+                    Ok(ok__) => {
+                        pclevels.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCurrentLevel<Identity: windows_core::IUnknownImpl + IWICProgressiveLevelControl_Impl>(this: *mut core::ffi::c_void, pnlevel: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICProgressiveLevelControl_Impl::GetCurrentLevel(this) {
+                    Ok(ok__) => {
+                        pnlevel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCurrentLevel<Identity: windows_core::IUnknownImpl + IWICProgressiveLevelControl_Impl>(this: *mut core::ffi::c_void, nlevel: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICProgressiveLevelControl_Impl::SetCurrentLevel(this, core::mem::transmute_copy(&nlevel)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetLevelCount: GetLevelCount::<Identity>,
+            GetCurrentLevel: GetCurrentLevel::<Identity>,
+            SetCurrentLevel: SetCurrentLevel::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWICProgressiveLevelControl {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWICStream, IWICStream_Vtbl, 0x135ff860_22b7_4ddf_b0f6_218f4f299a43);
@@ -6023,6 +8958,45 @@ impl IWICStream_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IWICStream_Impl> windows_core::imp::TearOffVtable<Identity> for IWICStream_Vtbl
+where
+    super::super::System::Com::IStream_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InitializeFromIStream<Identity: windows_core::IUnknownImpl + IWICStream_Impl>(this: *mut core::ffi::c_void, pistream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICStream_Impl::InitializeFromIStream(this, core::mem::transmute_copy(&pistream)).into()
+            }
+        }
+        unsafe extern "system" fn InitializeFromFilename<Identity: windows_core::IUnknownImpl + IWICStream_Impl>(this: *mut core::ffi::c_void, wzfilename: windows_core::PCWSTR, dwdesiredaccess: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICStream_Impl::InitializeFromFilename(this, core::mem::transmute(&wzfilename), core::mem::transmute_copy(&dwdesiredaccess)).into()
+            }
+        }
+        unsafe extern "system" fn InitializeFromMemory<Identity: windows_core::IUnknownImpl + IWICStream_Impl>(this: *mut core::ffi::c_void, pbbuffer: *const u8, cbbuffersize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICStream_Impl::InitializeFromMemory(this, core::mem::transmute_copy(&pbbuffer), core::mem::transmute_copy(&cbbuffersize)).into()
+            }
+        }
+        unsafe extern "system" fn InitializeFromIStreamRegion<Identity: windows_core::IUnknownImpl + IWICStream_Impl>(this: *mut core::ffi::c_void, pistream: *mut core::ffi::c_void, uloffset: u64, ulmaxsize: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICStream_Impl::InitializeFromIStreamRegion(this, core::mem::transmute_copy(&pistream), core::mem::transmute_copy(&uloffset), core::mem::transmute_copy(&ulmaxsize)).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IStream_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InitializeFromIStream: InitializeFromIStream::<Identity>,
+            InitializeFromFilename: InitializeFromFilename::<Identity>,
+            InitializeFromMemory: InitializeFromMemory::<Identity>,
+            InitializeFromIStreamRegion: InitializeFromIStreamRegion::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IWICStream {}
 windows_core::imp::define_interface!(IWICStreamProvider, IWICStreamProvider_Vtbl, 0x449494bc_b468_4927_96d7_ba90d31ab505);
 windows_core::imp::interface_hierarchy!(IWICStreamProvider, windows_core::IUnknown);
@@ -6127,6 +9101,63 @@ impl IWICStreamProvider_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IWICStreamProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IWICStreamProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStream<Identity: windows_core::IUnknownImpl + IWICStreamProvider_Impl>(this: *mut core::ffi::c_void, ppistream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICStreamProvider_Impl::GetStream(this) {
+                    Ok(ok__) => {
+                        ppistream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPersistOptions<Identity: windows_core::IUnknownImpl + IWICStreamProvider_Impl>(this: *mut core::ffi::c_void, pdwpersistoptions: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICStreamProvider_Impl::GetPersistOptions(this) {
+                    Ok(ok__) => {
+                        pdwpersistoptions.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPreferredVendorGUID<Identity: windows_core::IUnknownImpl + IWICStreamProvider_Impl>(this: *mut core::ffi::c_void, pguidpreferredvendor: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWICStreamProvider_Impl::GetPreferredVendorGUID(this) {
+                    Ok(ok__) => {
+                        pguidpreferredvendor.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RefreshStream<Identity: windows_core::IUnknownImpl + IWICStreamProvider_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWICStreamProvider_Impl::RefreshStream(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStream: GetStream::<Identity>,
+            GetPersistOptions: GetPersistOptions::<Identity>,
+            GetPreferredVendorGUID: GetPreferredVendorGUID::<Identity>,
+            RefreshStream: RefreshStream::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IWICStreamProvider {}
 pub type PFNProgressNotification = Option<unsafe extern "system" fn(pvdata: *const core::ffi::c_void, uframenum: u32, operation: WICProgressOperation, dblprogress: f64) -> windows_core::HRESULT>;
 pub const WIC8BIMIptcDigestIptcDigest: WIC8BIMIptcDigestProperties = WIC8BIMIptcDigestProperties(2i32);
@@ -3085,6 +3085,23 @@ impl IAsyncGetSendNotificationCookie_Vtbl {
         iid == &<IAsyncGetSendNotificationCookie as windows_core::Interface>::IID || iid == &<IPrintAsyncCookie as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAsyncGetSendNotificationCookie_Impl> windows_core::imp::TearOffVtable<Identity> for IAsyncGetSendNotificationCookie_Vtbl
+where
+    IPrintAsyncCookie_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FinishAsyncCallWithData<Identity: windows_core::IUnknownImpl + IAsyncGetSendNotificationCookie_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAsyncGetSendNotificationCookie_Impl::FinishAsyncCallWithData(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        Self {
+            base__: <IPrintAsyncCookie_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            FinishAsyncCallWithData: FinishAsyncCallWithData::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAsyncGetSendNotificationCookie {}
 windows_core::imp::define_interface!(IAsyncGetSrvReferralCookie, IAsyncGetSrvReferralCookie_Vtbl, 0);
 windows_core::imp::interface_hierarchy!(IAsyncGetSrvReferralCookie, windows_core::IUnknown);
@@ -3146,6 +3163,37 @@ impl IAsyncGetSrvReferralCookie_Vtbl {
         iid == &<IAsyncGetSrvReferralCookie as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAsyncGetSrvReferralCookie_Impl> windows_core::imp::TearOffVtable<Identity> for IAsyncGetSrvReferralCookie_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FinishAsyncCall<Identity: windows_core::IUnknownImpl + IAsyncGetSrvReferralCookie_Impl>(this: *mut core::ffi::c_void, param0: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAsyncGetSrvReferralCookie_Impl::FinishAsyncCall(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn CancelAsyncCall<Identity: windows_core::IUnknownImpl + IAsyncGetSrvReferralCookie_Impl>(this: *mut core::ffi::c_void, param0: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAsyncGetSrvReferralCookie_Impl::CancelAsyncCall(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn FinishAsyncCallWithData<Identity: windows_core::IUnknownImpl + IAsyncGetSrvReferralCookie_Impl>(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAsyncGetSrvReferralCookie_Impl::FinishAsyncCallWithData(this, core::mem::transmute(&param0)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            FinishAsyncCall: FinishAsyncCall::<Identity>,
+            CancelAsyncCall: CancelAsyncCall::<Identity>,
+            FinishAsyncCallWithData: FinishAsyncCallWithData::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAsyncGetSrvReferralCookie {}
 windows_core::imp::define_interface!(IBidiAsyncNotifyChannel, IBidiAsyncNotifyChannel_Vtbl, 0x532818f7_921b_4fb2_bff8_2f4fd52ebebf);
 impl core::ops::Deref for IBidiAsyncNotifyChannel {
@@ -3242,6 +3290,51 @@ impl IBidiAsyncNotifyChannel_Vtbl {
         iid == &<IBidiAsyncNotifyChannel as windows_core::Interface>::IID || iid == &<IPrintAsyncNotifyChannel as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBidiAsyncNotifyChannel_Impl> windows_core::imp::TearOffVtable<Identity> for IBidiAsyncNotifyChannel_Vtbl
+where
+    IPrintAsyncNotifyChannel_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateNotificationChannel<Identity: windows_core::IUnknownImpl + IBidiAsyncNotifyChannel_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBidiAsyncNotifyChannel_Impl::CreateNotificationChannel(this).into()
+            }
+        }
+        unsafe extern "system" fn GetPrintName<Identity: windows_core::IUnknownImpl + IBidiAsyncNotifyChannel_Impl>(this: *mut core::ffi::c_void, param0: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBidiAsyncNotifyChannel_Impl::GetPrintName(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn GetChannelNotificationType<Identity: windows_core::IUnknownImpl + IBidiAsyncNotifyChannel_Impl>(this: *mut core::ffi::c_void, param0: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBidiAsyncNotifyChannel_Impl::GetChannelNotificationType(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn AsyncGetNotificationSendResponse<Identity: windows_core::IUnknownImpl + IBidiAsyncNotifyChannel_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBidiAsyncNotifyChannel_Impl::AsyncGetNotificationSendResponse(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        unsafe extern "system" fn AsyncCloseChannel<Identity: windows_core::IUnknownImpl + IBidiAsyncNotifyChannel_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBidiAsyncNotifyChannel_Impl::AsyncCloseChannel(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        Self {
+            base__: <IPrintAsyncNotifyChannel_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateNotificationChannel: CreateNotificationChannel::<Identity>,
+            GetPrintName: GetPrintName::<Identity>,
+            GetChannelNotificationType: GetChannelNotificationType::<Identity>,
+            AsyncGetNotificationSendResponse: AsyncGetNotificationSendResponse::<Identity>,
+            AsyncCloseChannel: AsyncCloseChannel::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBidiAsyncNotifyChannel {}
 windows_core::imp::define_interface!(IBidiRequest, IBidiRequest_Vtbl, 0x8f348bd7_4b47_4755_8a9d_0f422df3dc89);
 windows_core::imp::interface_hierarchy!(IBidiRequest, windows_core::IUnknown);
@@ -3345,6 +3438,63 @@ impl IBidiRequest_Vtbl {
         iid == &<IBidiRequest as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBidiRequest_Impl> windows_core::imp::TearOffVtable<Identity> for IBidiRequest_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetSchema<Identity: windows_core::IUnknownImpl + IBidiRequest_Impl>(this: *mut core::ffi::c_void, pszschema: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBidiRequest_Impl::SetSchema(this, core::mem::transmute(&pszschema)).into()
+            }
+        }
+        unsafe extern "system" fn SetInputData<Identity: windows_core::IUnknownImpl + IBidiRequest_Impl>(this: *mut core::ffi::c_void, dwtype: u32, pdata: *const u8, usize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBidiRequest_Impl::SetInputData(this, core::mem::transmute_copy(&dwtype), core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&usize)).into()
+            }
+        }
+        unsafe extern "system" fn GetResult<Identity: windows_core::IUnknownImpl + IBidiRequest_Impl>(this: *mut core::ffi::c_void, phr: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBidiRequest_Impl::GetResult(this) {
+                    Ok(ok__) => {
+                        phr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOutputData<Identity: windows_core::IUnknownImpl + IBidiRequest_Impl>(this: *mut core::ffi::c_void, dwindex: u32, ppszschema: *mut windows_core::PWSTR, pdwtype: *mut u32, ppdata: *mut *mut u8, usize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBidiRequest_Impl::GetOutputData(this, core::mem::transmute_copy(&dwindex), core::mem::transmute_copy(&ppszschema), core::mem::transmute_copy(&pdwtype), core::mem::transmute_copy(&ppdata), core::mem::transmute_copy(&usize)).into()
+            }
+        }
+        unsafe extern "system" fn GetEnumCount<Identity: windows_core::IUnknownImpl + IBidiRequest_Impl>(this: *mut core::ffi::c_void, pdwtotal: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBidiRequest_Impl::GetEnumCount(this) {
+                    Ok(ok__) => {
+                        pdwtotal.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetSchema: SetSchema::<Identity>,
+            SetInputData: SetInputData::<Identity>,
+            GetResult: GetResult::<Identity>,
+            GetOutputData: GetOutputData::<Identity>,
+            GetEnumCount: GetEnumCount::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBidiRequest {}
 windows_core::imp::define_interface!(IBidiRequestContainer, IBidiRequestContainer_Vtbl, 0xd752f6c0_94a8_4275_a77d_8f1d1a1121ae);
 windows_core::imp::interface_hierarchy!(IBidiRequestContainer, windows_core::IUnknown);
@@ -3431,6 +3581,50 @@ impl IBidiRequestContainer_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IBidiRequestContainer_Impl> windows_core::imp::TearOffVtable<Identity> for IBidiRequestContainer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddRequest<Identity: windows_core::IUnknownImpl + IBidiRequestContainer_Impl>(this: *mut core::ffi::c_void, prequest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBidiRequestContainer_Impl::AddRequest(this, core::mem::transmute_copy(&prequest)).into()
+            }
+        }
+        unsafe extern "system" fn GetEnumObject<Identity: windows_core::IUnknownImpl + IBidiRequestContainer_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBidiRequestContainer_Impl::GetEnumObject(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRequestCount<Identity: windows_core::IUnknownImpl + IBidiRequestContainer_Impl>(this: *mut core::ffi::c_void, pucount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBidiRequestContainer_Impl::GetRequestCount(this) {
+                    Ok(ok__) => {
+                        pucount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddRequest: AddRequest::<Identity>,
+            GetEnumObject: GetEnumObject::<Identity>,
+            GetRequestCount: GetRequestCount::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IBidiRequestContainer {}
 windows_core::imp::define_interface!(IBidiSpl, IBidiSpl_Vtbl, 0xd580dc0e_de39_4649_baa8_bf0b85a03a97);
 windows_core::imp::interface_hierarchy!(IBidiSpl, windows_core::IUnknown);
@@ -3512,6 +3706,44 @@ impl IBidiSpl_Vtbl {
         iid == &<IBidiSpl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBidiSpl_Impl> windows_core::imp::TearOffVtable<Identity> for IBidiSpl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BindDevice<Identity: windows_core::IUnknownImpl + IBidiSpl_Impl>(this: *mut core::ffi::c_void, pszdevicename: windows_core::PCWSTR, dwaccess: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBidiSpl_Impl::BindDevice(this, core::mem::transmute(&pszdevicename), core::mem::transmute_copy(&dwaccess)).into()
+            }
+        }
+        unsafe extern "system" fn UnbindDevice<Identity: windows_core::IUnknownImpl + IBidiSpl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBidiSpl_Impl::UnbindDevice(this).into()
+            }
+        }
+        unsafe extern "system" fn SendRecv<Identity: windows_core::IUnknownImpl + IBidiSpl_Impl>(this: *mut core::ffi::c_void, pszaction: windows_core::PCWSTR, prequest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBidiSpl_Impl::SendRecv(this, core::mem::transmute(&pszaction), core::mem::transmute_copy(&prequest)).into()
+            }
+        }
+        unsafe extern "system" fn MultiSendRecv<Identity: windows_core::IUnknownImpl + IBidiSpl_Impl>(this: *mut core::ffi::c_void, pszaction: windows_core::PCWSTR, prequestcontainer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBidiSpl_Impl::MultiSendRecv(this, core::mem::transmute(&pszaction), core::mem::transmute_copy(&prequestcontainer)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            BindDevice: BindDevice::<Identity>,
+            UnbindDevice: UnbindDevice::<Identity>,
+            SendRecv: SendRecv::<Identity>,
+            MultiSendRecv: MultiSendRecv::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBidiSpl {}
 windows_core::imp::define_interface!(IBidiSpl2, IBidiSpl2_Vtbl, 0x0e8f51b8_8273_4906_8e7b_be453ffd2e2b);
 windows_core::imp::interface_hierarchy!(IBidiSpl2, windows_core::IUnknown);
@@ -3613,6 +3845,57 @@ impl IBidiSpl2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IBidiSpl2_Impl> windows_core::imp::TearOffVtable<Identity> for IBidiSpl2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BindDevice<Identity: windows_core::IUnknownImpl + IBidiSpl2_Impl>(this: *mut core::ffi::c_void, pszdevicename: windows_core::PCWSTR, dwaccess: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBidiSpl2_Impl::BindDevice(this, core::mem::transmute(&pszdevicename), core::mem::transmute_copy(&dwaccess)).into()
+            }
+        }
+        unsafe extern "system" fn UnbindDevice<Identity: windows_core::IUnknownImpl + IBidiSpl2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBidiSpl2_Impl::UnbindDevice(this).into()
+            }
+        }
+        unsafe extern "system" fn SendRecvXMLString<Identity: windows_core::IUnknownImpl + IBidiSpl2_Impl>(this: *mut core::ffi::c_void, bstrrequest: *mut core::ffi::c_void, pbstrresponse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBidiSpl2_Impl::SendRecvXMLString(this, core::mem::transmute(&bstrrequest)) {
+                    Ok(ok__) => {
+                        pbstrresponse.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SendRecvXMLStream<Identity: windows_core::IUnknownImpl + IBidiSpl2_Impl>(this: *mut core::ffi::c_void, psrequest: *mut core::ffi::c_void, ppsresponse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBidiSpl2_Impl::SendRecvXMLStream(this, core::mem::transmute_copy(&psrequest)) {
+                    Ok(ok__) => {
+                        ppsresponse.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            BindDevice: BindDevice::<Identity>,
+            UnbindDevice: UnbindDevice::<Identity>,
+            SendRecvXMLString: SendRecvXMLString::<Identity>,
+            SendRecvXMLStream: SendRecvXMLStream::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IBidiSpl2 {}
 pub const IDI_CPSUI_ADVANCE: u32 = 64058u32;
 pub const IDI_CPSUI_AUTOSEL: u32 = 64025u32;
@@ -3999,6 +4282,49 @@ impl IFixedDocument_Vtbl {
         iid == &<IFixedDocument as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IFixedDocument_Impl> windows_core::imp::TearOffVtable<Identity> for IFixedDocument_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetUri<Identity: windows_core::IUnknownImpl + IFixedDocument_Impl>(this: *mut core::ffi::c_void, uri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFixedDocument_Impl::GetUri(this) {
+                    Ok(ok__) => {
+                        uri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPrintTicket<Identity: windows_core::IUnknownImpl + IFixedDocument_Impl>(this: *mut core::ffi::c_void, ppprintticket: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFixedDocument_Impl::GetPrintTicket(this) {
+                    Ok(ok__) => {
+                        ppprintticket.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPrintTicket<Identity: windows_core::IUnknownImpl + IFixedDocument_Impl>(this: *mut core::ffi::c_void, pprintticket: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFixedDocument_Impl::SetPrintTicket(this, core::mem::transmute_copy(&pprintticket)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetUri: GetUri::<Identity>,
+            GetPrintTicket: GetPrintTicket::<Identity>,
+            SetPrintTicket: SetPrintTicket::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IFixedDocument {}
 windows_core::imp::define_interface!(IFixedDocumentSequence, IFixedDocumentSequence_Vtbl, 0x8028d181_2c32_4249_8493_1bfb22045574);
 windows_core::imp::interface_hierarchy!(IFixedDocumentSequence, windows_core::IUnknown);
@@ -4078,6 +4404,49 @@ impl IFixedDocumentSequence_Vtbl {
         iid == &<IFixedDocumentSequence as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IFixedDocumentSequence_Impl> windows_core::imp::TearOffVtable<Identity> for IFixedDocumentSequence_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetUri<Identity: windows_core::IUnknownImpl + IFixedDocumentSequence_Impl>(this: *mut core::ffi::c_void, uri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFixedDocumentSequence_Impl::GetUri(this) {
+                    Ok(ok__) => {
+                        uri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPrintTicket<Identity: windows_core::IUnknownImpl + IFixedDocumentSequence_Impl>(this: *mut core::ffi::c_void, ppprintticket: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFixedDocumentSequence_Impl::GetPrintTicket(this) {
+                    Ok(ok__) => {
+                        ppprintticket.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPrintTicket<Identity: windows_core::IUnknownImpl + IFixedDocumentSequence_Impl>(this: *mut core::ffi::c_void, pprintticket: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFixedDocumentSequence_Impl::SetPrintTicket(this, core::mem::transmute_copy(&pprintticket)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetUri: GetUri::<Identity>,
+            GetPrintTicket: GetPrintTicket::<Identity>,
+            SetPrintTicket: SetPrintTicket::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IFixedDocumentSequence {}
 windows_core::imp::define_interface!(IFixedPage, IFixedPage_Vtbl, 0x3d9f6448_7e95_4cb5_94fb_0180c2883a57);
 impl core::ops::Deref for IFixedPage {
@@ -4238,31 +4607,114 @@ impl IFixedPage_Vtbl {
         iid == &<IFixedPage as windows_core::Interface>::IID || iid == &<IPartBase as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IFixedPage {}
-#[cfg(feature = "Win32_System_Ole")]
-windows_core::imp::define_interface!(IImgCreateErrorInfo, IImgCreateErrorInfo_Vtbl, 0x1c55a64c_07cd_4fb5_90f7_b753d91f0c9e);
-#[cfg(feature = "Win32_System_Ole")]
-impl core::ops::Deref for IImgCreateErrorInfo {
-    type Target = super::super::System::Ole::ICreateErrorInfo;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Ole")]
-windows_core::imp::interface_hierarchy!(IImgCreateErrorInfo, windows_core::IUnknown, super::super::System::Ole::ICreateErrorInfo);
-#[cfg(feature = "Win32_System_Ole")]
-impl IImgCreateErrorInfo {
-    pub unsafe fn AttachToErrorInfo(&self, perrorinfo: *mut ImgErrorInfo) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).AttachToErrorInfo)(windows_core::Interface::as_raw(self), core::mem::transmute(perrorinfo)).ok() }
-    }
-}
-#[cfg(feature = "Win32_System_Ole")]
-#[repr(C)]
-#[doc(hidden)]
-pub struct IImgCreateErrorInfo_Vtbl {
-    pub base__: super::super::System::Ole::ICreateErrorInfo_Vtbl,
-    pub AttachToErrorInfo: unsafe extern "system" fn(*mut core::ffi::c_void, *mut ImgErrorInfo) -> windows_core::HRESULT,
-}
+impl<Identity: windows_core::IUnknownImpl + IFixedPage_Impl> windows_core::imp::TearOffVtable<Identity> for IFixedPage_Vtbl
+where
+    IPartBase_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPrintTicket<Identity: windows_core::IUnknownImpl + IFixedPage_Impl>(this: *mut core::ffi::c_void, ppprintticket: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFixedPage_Impl::GetPrintTicket(this) {
+                    Ok(ok__) => {
+                        ppprintticket.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPagePart<Identity: windows_core::IUnknownImpl + IFixedPage_Impl>(this: *mut core::ffi::c_void, uri: windows_core::PCWSTR, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFixedPage_Impl::GetPagePart(this, core::mem::transmute(&uri)) {
+                    Ok(ok__) => {
+                        ppunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetWriteStream<Identity: windows_core::IUnknownImpl + IFixedPage_Impl>(this: *mut core::ffi::c_void, ppwritestream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFixedPage_Impl::GetWriteStream(this) {
+                    Ok(ok__) => {
+                        ppwritestream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPrintTicket<Identity: windows_core::IUnknownImpl + IFixedPage_Impl>(this: *mut core::ffi::c_void, ppprintticket: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFixedPage_Impl::SetPrintTicket(this, core::mem::transmute_copy(&ppprintticket)).into()
+            }
+        }
+        unsafe extern "system" fn SetPagePart<Identity: windows_core::IUnknownImpl + IFixedPage_Impl>(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFixedPage_Impl::SetPagePart(this, core::mem::transmute_copy(&punk)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteResource<Identity: windows_core::IUnknownImpl + IFixedPage_Impl>(this: *mut core::ffi::c_void, uri: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFixedPage_Impl::DeleteResource(this, core::mem::transmute(&uri)).into()
+            }
+        }
+        unsafe extern "system" fn GetXpsPartIterator<Identity: windows_core::IUnknownImpl + IFixedPage_Impl>(this: *mut core::ffi::c_void, pxpspartit: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFixedPage_Impl::GetXpsPartIterator(this) {
+                    Ok(ok__) => {
+                        pxpspartit.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IPartBase_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPrintTicket: GetPrintTicket::<Identity>,
+            GetPagePart: GetPagePart::<Identity>,
+            GetWriteStream: GetWriteStream::<Identity>,
+            SetPrintTicket: SetPrintTicket::<Identity>,
+            SetPagePart: SetPagePart::<Identity>,
+            DeleteResource: DeleteResource::<Identity>,
+            GetXpsPartIterator: GetXpsPartIterator::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IFixedPage {}
+#[cfg(feature = "Win32_System_Ole")]
+windows_core::imp::define_interface!(IImgCreateErrorInfo, IImgCreateErrorInfo_Vtbl, 0x1c55a64c_07cd_4fb5_90f7_b753d91f0c9e);
+#[cfg(feature = "Win32_System_Ole")]
+impl core::ops::Deref for IImgCreateErrorInfo {
+    type Target = super::super::System::Ole::ICreateErrorInfo;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Ole")]
+windows_core::imp::interface_hierarchy!(IImgCreateErrorInfo, windows_core::IUnknown, super::super::System::Ole::ICreateErrorInfo);
+#[cfg(feature = "Win32_System_Ole")]
+impl IImgCreateErrorInfo {
+    pub unsafe fn AttachToErrorInfo(&self, perrorinfo: *mut ImgErrorInfo) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).AttachToErrorInfo)(windows_core::Interface::as_raw(self), core::mem::transmute(perrorinfo)).ok() }
+    }
+}
+#[cfg(feature = "Win32_System_Ole")]
+#[repr(C)]
+#[doc(hidden)]
+pub struct IImgCreateErrorInfo_Vtbl {
+    pub base__: super::super::System::Ole::ICreateErrorInfo_Vtbl,
+    pub AttachToErrorInfo: unsafe extern "system" fn(*mut core::ffi::c_void, *mut ImgErrorInfo) -> windows_core::HRESULT,
+}
 #[cfg(feature = "Win32_System_Ole")]
 pub trait IImgCreateErrorInfo_Impl: super::super::System::Ole::ICreateErrorInfo_Impl {
     fn AttachToErrorInfo(&self, perrorinfo: *mut ImgErrorInfo) -> windows_core::Result<()>;
@@ -4283,6 +4735,24 @@ impl IImgCreateErrorInfo_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Ole")]
+impl<Identity: windows_core::IUnknownImpl + IImgCreateErrorInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IImgCreateErrorInfo_Vtbl
+where
+    super::super::System::Ole::ICreateErrorInfo_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AttachToErrorInfo<Identity: windows_core::IUnknownImpl + IImgCreateErrorInfo_Impl>(this: *mut core::ffi::c_void, perrorinfo: *mut ImgErrorInfo) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IImgCreateErrorInfo_Impl::AttachToErrorInfo(this, core::mem::transmute_copy(&perrorinfo)).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Ole::ICreateErrorInfo_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AttachToErrorInfo: AttachToErrorInfo::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Ole")]
 impl windows_core::RuntimeName for IImgCreateErrorInfo {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IImgErrorInfo, IImgErrorInfo_Vtbl, 0x2bce4ece_d30e_445a_9423_6829be945ad8);
@@ -4457,6 +4927,102 @@ impl IImgErrorInfo_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IImgErrorInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IImgErrorInfo_Vtbl
+where
+    super::super::System::Com::IErrorInfo_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDeveloperDescription<Identity: windows_core::IUnknownImpl + IImgErrorInfo_Impl>(this: *mut core::ffi::c_void, pbstrdevdescription: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImgErrorInfo_Impl::GetDeveloperDescription(this) {
+                    Ok(ok__) => {
+                        pbstrdevdescription.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetUserErrorId<Identity: windows_core::IUnknownImpl + IImgErrorInfo_Impl>(this: *mut core::ffi::c_void, perrorid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImgErrorInfo_Impl::GetUserErrorId(this) {
+                    Ok(ok__) => {
+                        perrorid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetUserParameterCount<Identity: windows_core::IUnknownImpl + IImgErrorInfo_Impl>(this: *mut core::ffi::c_void, pcuserparams: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImgErrorInfo_Impl::GetUserParameterCount(this) {
+                    Ok(ok__) => {
+                        pcuserparams.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetUserParameter<Identity: windows_core::IUnknownImpl + IImgErrorInfo_Impl>(this: *mut core::ffi::c_void, cparam: u32, pbstrparam: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImgErrorInfo_Impl::GetUserParameter(this, core::mem::transmute_copy(&cparam)) {
+                    Ok(ok__) => {
+                        pbstrparam.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetUserFallback<Identity: windows_core::IUnknownImpl + IImgErrorInfo_Impl>(this: *mut core::ffi::c_void, pbstrfallback: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImgErrorInfo_Impl::GetUserFallback(this) {
+                    Ok(ok__) => {
+                        pbstrfallback.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetExceptionId<Identity: windows_core::IUnknownImpl + IImgErrorInfo_Impl>(this: *mut core::ffi::c_void, pexceptionid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IImgErrorInfo_Impl::GetExceptionId(this) {
+                    Ok(ok__) => {
+                        pexceptionid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DetachErrorInfo<Identity: windows_core::IUnknownImpl + IImgErrorInfo_Impl>(this: *mut core::ffi::c_void, perrorinfo: *mut ImgErrorInfo) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IImgErrorInfo_Impl::DetachErrorInfo(this, core::mem::transmute_copy(&perrorinfo)).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IErrorInfo_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDeveloperDescription: GetDeveloperDescription::<Identity>,
+            GetUserErrorId: GetUserErrorId::<Identity>,
+            GetUserParameterCount: GetUserParameterCount::<Identity>,
+            GetUserParameter: GetUserParameter::<Identity>,
+            GetUserFallback: GetUserFallback::<Identity>,
+            GetExceptionId: GetExceptionId::<Identity>,
+            DetachErrorInfo: DetachErrorInfo::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IImgErrorInfo {}
 windows_core::imp::define_interface!(IInterFilterCommunicator, IInterFilterCommunicator_Vtbl, 0x4daf1e69_81fd_462d_940f_8cd3ddf56fca);
 windows_core::imp::interface_hierarchy!(IInterFilterCommunicator, windows_core::IUnknown);
@@ -4503,6 +5069,30 @@ impl IInterFilterCommunicator_Vtbl {
         iid == &<IInterFilterCommunicator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInterFilterCommunicator_Impl> windows_core::imp::TearOffVtable<Identity> for IInterFilterCommunicator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RequestReader<Identity: windows_core::IUnknownImpl + IInterFilterCommunicator_Impl>(this: *mut core::ffi::c_void, ppireader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInterFilterCommunicator_Impl::RequestReader(this, core::mem::transmute_copy(&ppireader)).into()
+            }
+        }
+        unsafe extern "system" fn RequestWriter<Identity: windows_core::IUnknownImpl + IInterFilterCommunicator_Impl>(this: *mut core::ffi::c_void, ppiwriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInterFilterCommunicator_Impl::RequestWriter(this, core::mem::transmute_copy(&ppiwriter)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RequestReader: RequestReader::<Identity>,
+            RequestWriter: RequestWriter::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IInterFilterCommunicator {}
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
@@ -4637,6 +5227,62 @@ impl IPartBase_Vtbl {
         iid == &<IPartBase as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPartBase_Impl> windows_core::imp::TearOffVtable<Identity> for IPartBase_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetUri<Identity: windows_core::IUnknownImpl + IPartBase_Impl>(this: *mut core::ffi::c_void, uri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPartBase_Impl::GetUri(this) {
+                    Ok(ok__) => {
+                        uri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStream<Identity: windows_core::IUnknownImpl + IPartBase_Impl>(this: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPartBase_Impl::GetStream(this) {
+                    Ok(ok__) => {
+                        ppstream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPartCompression<Identity: windows_core::IUnknownImpl + IPartBase_Impl>(this: *mut core::ffi::c_void, pcompression: *mut EXpsCompressionOptions) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPartBase_Impl::GetPartCompression(this) {
+                    Ok(ok__) => {
+                        pcompression.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPartCompression<Identity: windows_core::IUnknownImpl + IPartBase_Impl>(this: *mut core::ffi::c_void, compression: EXpsCompressionOptions) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPartBase_Impl::SetPartCompression(this, core::mem::transmute_copy(&compression)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetUri: GetUri::<Identity>,
+            GetStream: GetStream::<Identity>,
+            GetPartCompression: GetPartCompression::<Identity>,
+            SetPartCompression: SetPartCompression::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPartBase {}
 windows_core::imp::define_interface!(IPartColorProfile, IPartColorProfile_Vtbl, 0x63cca95b_7d18_4762_b15e_98658693d24a);
 impl core::ops::Deref for IPartColorProfile {
@@ -4660,6 +5306,12 @@ impl IPartColorProfile_Vtbl {
         iid == &<IPartColorProfile as windows_core::Interface>::IID || iid == &<IPartBase as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPartColorProfile_Impl> windows_core::imp::TearOffVtable<Identity> for IPartColorProfile_Vtbl
+where
+    IPartBase_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IPartBase_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IPartColorProfile {}
 windows_core::imp::define_interface!(IPartDiscardControl, IPartDiscardControl_Vtbl, 0xcc350c00_095b_42a5_bf0f_c8780edadb3c);
 windows_core::imp::interface_hierarchy!(IPartDiscardControl, windows_core::IUnknown);
@@ -4691,6 +5343,23 @@ impl IPartDiscardControl_Vtbl {
         iid == &<IPartDiscardControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPartDiscardControl_Impl> windows_core::imp::TearOffVtable<Identity> for IPartDiscardControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDiscardProperties<Identity: windows_core::IUnknownImpl + IPartDiscardControl_Impl>(this: *mut core::ffi::c_void, urisentinelpage: *mut *mut core::ffi::c_void, uriparttodiscard: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPartDiscardControl_Impl::GetDiscardProperties(this, core::mem::transmute_copy(&urisentinelpage), core::mem::transmute_copy(&uriparttodiscard)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDiscardProperties: GetDiscardProperties::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPartDiscardControl {}
 windows_core::imp::define_interface!(IPartFont, IPartFont_Vtbl, 0xe07fe0ab_1124_43d0_a865_e8ffb6a3ea82);
 impl core::ops::Deref for IPartFont {
@@ -4758,6 +5427,37 @@ impl IPartFont_Vtbl {
         iid == &<IPartFont as windows_core::Interface>::IID || iid == &<IPartBase as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPartFont_Impl> windows_core::imp::TearOffVtable<Identity> for IPartFont_Vtbl
+where
+    IPartBase_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFontProperties<Identity: windows_core::IUnknownImpl + IPartFont_Impl>(this: *mut core::ffi::c_void, pcontenttype: *mut *mut core::ffi::c_void, pfontoptions: *mut EXpsFontOptions) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPartFont_Impl::GetFontProperties(this, core::mem::transmute_copy(&pcontenttype), core::mem::transmute_copy(&pfontoptions)).into()
+            }
+        }
+        unsafe extern "system" fn SetFontContent<Identity: windows_core::IUnknownImpl + IPartFont_Impl>(this: *mut core::ffi::c_void, pcontenttype: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPartFont_Impl::SetFontContent(this, core::mem::transmute(&pcontenttype)).into()
+            }
+        }
+        unsafe extern "system" fn SetFontOptions<Identity: windows_core::IUnknownImpl + IPartFont_Impl>(this: *mut core::ffi::c_void, options: EXpsFontOptions) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPartFont_Impl::SetFontOptions(this, core::mem::transmute_copy(&options)).into()
+            }
+        }
+        Self {
+            base__: <IPartBase_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFontProperties: GetFontProperties::<Identity>,
+            SetFontContent: SetFontContent::<Identity>,
+            SetFontOptions: SetFontOptions::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPartFont {}
 windows_core::imp::define_interface!(IPartFont2, IPartFont2_Vtbl, 0x511e025f_d6cb_43be_bf65_63fe88515a39);
 impl core::ops::Deref for IPartFont2 {
@@ -4804,6 +5504,26 @@ impl IPartFont2_Vtbl {
         iid == &<IPartFont2 as windows_core::Interface>::IID || iid == &<IPartBase as windows_core::Interface>::IID || iid == &<IPartFont as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPartFont2_Impl> windows_core::imp::TearOffVtable<Identity> for IPartFont2_Vtbl
+where
+    IPartFont_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFontRestriction<Identity: windows_core::IUnknownImpl + IPartFont2_Impl>(this: *mut core::ffi::c_void, prestriction: *mut EXpsFontRestriction) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPartFont2_Impl::GetFontRestriction(this) {
+                    Ok(ok__) => {
+                        prestriction.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IPartFont_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetFontRestriction: GetFontRestriction::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IPartFont2 {}
 windows_core::imp::define_interface!(IPartImage, IPartImage_Vtbl, 0x725f2e3c_401a_4705_9de0_fe6f1353b87f);
 impl core::ops::Deref for IPartImage {
@@ -4868,6 +5588,36 @@ impl IPartImage_Vtbl {
         iid == &<IPartImage as windows_core::Interface>::IID || iid == &<IPartBase as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPartImage_Impl> windows_core::imp::TearOffVtable<Identity> for IPartImage_Vtbl
+where
+    IPartBase_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetImageProperties<Identity: windows_core::IUnknownImpl + IPartImage_Impl>(this: *mut core::ffi::c_void, pcontenttype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPartImage_Impl::GetImageProperties(this) {
+                    Ok(ok__) => {
+                        pcontenttype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetImageContent<Identity: windows_core::IUnknownImpl + IPartImage_Impl>(this: *mut core::ffi::c_void, pcontenttype: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPartImage_Impl::SetImageContent(this, core::mem::transmute(&pcontenttype)).into()
+            }
+        }
+        Self {
+            base__: <IPartBase_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetImageProperties: GetImageProperties::<Identity>,
+            SetImageContent: SetImageContent::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPartImage {}
 windows_core::imp::define_interface!(IPartPrintTicket, IPartPrintTicket_Vtbl, 0x4a0f50f6_f9a2_41f0_99e7_5ae955be8e9e);
 impl core::ops::Deref for IPartPrintTicket {
@@ -4891,6 +5641,12 @@ impl IPartPrintTicket_Vtbl {
         iid == &<IPartPrintTicket as windows_core::Interface>::IID || iid == &<IPartBase as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPartPrintTicket_Impl> windows_core::imp::TearOffVtable<Identity> for IPartPrintTicket_Vtbl
+where
+    IPartBase_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IPartBase_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IPartPrintTicket {}
 windows_core::imp::define_interface!(IPartResourceDictionary, IPartResourceDictionary_Vtbl, 0x16cfce6d_e744_4fb3_b474_f1d54f024a01);
 impl core::ops::Deref for IPartResourceDictionary {
@@ -4914,6 +5670,12 @@ impl IPartResourceDictionary_Vtbl {
         iid == &<IPartResourceDictionary as windows_core::Interface>::IID || iid == &<IPartBase as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPartResourceDictionary_Impl> windows_core::imp::TearOffVtable<Identity> for IPartResourceDictionary_Vtbl
+where
+    IPartBase_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IPartBase_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IPartResourceDictionary {}
 windows_core::imp::define_interface!(IPartThumbnail, IPartThumbnail_Vtbl, 0x027ed1c9_ba39_4cc5_aa55_7ec3a0de171a);
 impl core::ops::Deref for IPartThumbnail {
@@ -4978,6 +5740,36 @@ impl IPartThumbnail_Vtbl {
         iid == &<IPartThumbnail as windows_core::Interface>::IID || iid == &<IPartBase as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPartThumbnail_Impl> windows_core::imp::TearOffVtable<Identity> for IPartThumbnail_Vtbl
+where
+    IPartBase_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetThumbnailProperties<Identity: windows_core::IUnknownImpl + IPartThumbnail_Impl>(this: *mut core::ffi::c_void, pcontenttype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPartThumbnail_Impl::GetThumbnailProperties(this) {
+                    Ok(ok__) => {
+                        pcontenttype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetThumbnailContent<Identity: windows_core::IUnknownImpl + IPartThumbnail_Impl>(this: *mut core::ffi::c_void, pcontenttype: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPartThumbnail_Impl::SetThumbnailContent(this, core::mem::transmute(&pcontenttype)).into()
+            }
+        }
+        Self {
+            base__: <IPartBase_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetThumbnailProperties: GetThumbnailProperties::<Identity>,
+            SetThumbnailContent: SetThumbnailContent::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPartThumbnail {}
 windows_core::imp::define_interface!(IPrintAsyncCookie, IPrintAsyncCookie_Vtbl, 0);
 windows_core::imp::interface_hierarchy!(IPrintAsyncCookie, windows_core::IUnknown);
@@ -5024,6 +5816,30 @@ impl IPrintAsyncCookie_Vtbl {
         iid == &<IPrintAsyncCookie as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintAsyncCookie_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintAsyncCookie_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FinishAsyncCall<Identity: windows_core::IUnknownImpl + IPrintAsyncCookie_Impl>(this: *mut core::ffi::c_void, param0: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintAsyncCookie_Impl::FinishAsyncCall(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn CancelAsyncCall<Identity: windows_core::IUnknownImpl + IPrintAsyncCookie_Impl>(this: *mut core::ffi::c_void, param0: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintAsyncCookie_Impl::CancelAsyncCall(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            FinishAsyncCall: FinishAsyncCall::<Identity>,
+            CancelAsyncCall: CancelAsyncCall::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPrintAsyncCookie {}
 windows_core::imp::define_interface!(IPrintAsyncNewChannelCookie, IPrintAsyncNewChannelCookie_Vtbl, 0);
 impl core::ops::Deref for IPrintAsyncNewChannelCookie {
@@ -5061,6 +5877,23 @@ impl IPrintAsyncNewChannelCookie_Vtbl {
         iid == &<IPrintAsyncNewChannelCookie as windows_core::Interface>::IID || iid == &<IPrintAsyncCookie as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintAsyncNewChannelCookie_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintAsyncNewChannelCookie_Vtbl
+where
+    IPrintAsyncCookie_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FinishAsyncCallWithData<Identity: windows_core::IUnknownImpl + IPrintAsyncNewChannelCookie_Impl>(this: *mut core::ffi::c_void, param0: *const *mut core::ffi::c_void, param1: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintAsyncNewChannelCookie_Impl::FinishAsyncCallWithData(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1)).into()
+            }
+        }
+        Self {
+            base__: <IPrintAsyncCookie_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            FinishAsyncCallWithData: FinishAsyncCallWithData::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPrintAsyncNewChannelCookie {}
 windows_core::imp::define_interface!(IPrintAsyncNotify, IPrintAsyncNotify_Vtbl, 0x532818f7_921b_4fb2_bff8_2f4fd52ebebf);
 windows_core::imp::interface_hierarchy!(IPrintAsyncNotify, windows_core::IUnknown);
@@ -5131,6 +5964,42 @@ impl IPrintAsyncNotify_Vtbl {
         iid == &<IPrintAsyncNotify as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintAsyncNotify_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintAsyncNotify_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreatePrintAsyncNotifyChannel<Identity: windows_core::IUnknownImpl + IPrintAsyncNotify_Impl>(this: *mut core::ffi::c_void, param0: u32, param1: *const windows_core::GUID, param2: PrintAsyncNotifyUserFilter, param3: PrintAsyncNotifyConversationStyle, param4: *mut core::ffi::c_void, param5: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintAsyncNotify_Impl::CreatePrintAsyncNotifyChannel(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3), core::mem::transmute_copy(&param4)) {
+                    Ok(ok__) => {
+                        param5.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreatePrintAsyncNotifyRegistration<Identity: windows_core::IUnknownImpl + IPrintAsyncNotify_Impl>(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: PrintAsyncNotifyUserFilter, param2: PrintAsyncNotifyConversationStyle, param3: *mut core::ffi::c_void, param4: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintAsyncNotify_Impl::CreatePrintAsyncNotifyRegistration(this, core::mem::transmute_copy(&param0), core::mem::transmute_copy(&param1), core::mem::transmute_copy(&param2), core::mem::transmute_copy(&param3)) {
+                    Ok(ok__) => {
+                        param4.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreatePrintAsyncNotifyChannel: CreatePrintAsyncNotifyChannel::<Identity>,
+            CreatePrintAsyncNotifyRegistration: CreatePrintAsyncNotifyRegistration::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPrintAsyncNotify {}
 windows_core::imp::define_interface!(IPrintAsyncNotifyCallback, IPrintAsyncNotifyCallback_Vtbl, 0x7def34c1_9d92_4c99_b3b3_db94a9d4191b);
 windows_core::imp::interface_hierarchy!(IPrintAsyncNotifyCallback, windows_core::IUnknown);
@@ -5185,6 +6054,30 @@ impl IPrintAsyncNotifyCallback_Vtbl {
         iid == &<IPrintAsyncNotifyCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintAsyncNotifyCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintAsyncNotifyCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnEventNotify<Identity: windows_core::IUnknownImpl + IPrintAsyncNotifyCallback_Impl>(this: *mut core::ffi::c_void, pchannel: *mut core::ffi::c_void, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintAsyncNotifyCallback_Impl::OnEventNotify(this, core::mem::transmute_copy(&pchannel), core::mem::transmute_copy(&pdata)).into()
+            }
+        }
+        unsafe extern "system" fn ChannelClosed<Identity: windows_core::IUnknownImpl + IPrintAsyncNotifyCallback_Impl>(this: *mut core::ffi::c_void, pchannel: *mut core::ffi::c_void, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintAsyncNotifyCallback_Impl::ChannelClosed(this, core::mem::transmute_copy(&pchannel), core::mem::transmute_copy(&pdata)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnEventNotify: OnEventNotify::<Identity>,
+            ChannelClosed: ChannelClosed::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPrintAsyncNotifyCallback {}
 windows_core::imp::define_interface!(IPrintAsyncNotifyChannel, IPrintAsyncNotifyChannel_Vtbl, 0x4a5031b1_1f3f_4db0_a462_4530ed8b0451);
 windows_core::imp::interface_hierarchy!(IPrintAsyncNotifyChannel, windows_core::IUnknown);
@@ -5237,6 +6130,30 @@ impl IPrintAsyncNotifyChannel_Vtbl {
         iid == &<IPrintAsyncNotifyChannel as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintAsyncNotifyChannel_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintAsyncNotifyChannel_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SendNotification<Identity: windows_core::IUnknownImpl + IPrintAsyncNotifyChannel_Impl>(this: *mut core::ffi::c_void, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintAsyncNotifyChannel_Impl::SendNotification(this, core::mem::transmute_copy(&pdata)).into()
+            }
+        }
+        unsafe extern "system" fn CloseChannel<Identity: windows_core::IUnknownImpl + IPrintAsyncNotifyChannel_Impl>(this: *mut core::ffi::c_void, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintAsyncNotifyChannel_Impl::CloseChannel(this, core::mem::transmute_copy(&pdata)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SendNotification: SendNotification::<Identity>,
+            CloseChannel: CloseChannel::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPrintAsyncNotifyChannel {}
 windows_core::imp::define_interface!(IPrintAsyncNotifyDataObject, IPrintAsyncNotifyDataObject_Vtbl, 0x77cf513e_5d49_4789_9f30_d0822b335c0d);
 windows_core::imp::interface_hierarchy!(IPrintAsyncNotifyDataObject, windows_core::IUnknown);
@@ -5283,6 +6200,30 @@ impl IPrintAsyncNotifyDataObject_Vtbl {
         iid == &<IPrintAsyncNotifyDataObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintAsyncNotifyDataObject_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintAsyncNotifyDataObject_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AcquireData<Identity: windows_core::IUnknownImpl + IPrintAsyncNotifyDataObject_Impl>(this: *mut core::ffi::c_void, ppnotificationdata: *mut *mut u8, psize: *mut u32, ppschema: *mut *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintAsyncNotifyDataObject_Impl::AcquireData(this, core::mem::transmute_copy(&ppnotificationdata), core::mem::transmute_copy(&psize), core::mem::transmute_copy(&ppschema)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseData<Identity: windows_core::IUnknownImpl + IPrintAsyncNotifyDataObject_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintAsyncNotifyDataObject_Impl::ReleaseData(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AcquireData: AcquireData::<Identity>,
+            ReleaseData: ReleaseData::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPrintAsyncNotifyDataObject {}
 windows_core::imp::define_interface!(IPrintAsyncNotifyRegistration, IPrintAsyncNotifyRegistration_Vtbl, 0x0f6f27b6_6f86_4591_9203_64c3bfadedfe);
 windows_core::imp::interface_hierarchy!(IPrintAsyncNotifyRegistration, windows_core::IUnknown);
@@ -5329,6 +6270,30 @@ impl IPrintAsyncNotifyRegistration_Vtbl {
         iid == &<IPrintAsyncNotifyRegistration as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintAsyncNotifyRegistration_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintAsyncNotifyRegistration_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterForNotifications<Identity: windows_core::IUnknownImpl + IPrintAsyncNotifyRegistration_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintAsyncNotifyRegistration_Impl::RegisterForNotifications(this).into()
+            }
+        }
+        unsafe extern "system" fn UnregisterForNotifications<Identity: windows_core::IUnknownImpl + IPrintAsyncNotifyRegistration_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintAsyncNotifyRegistration_Impl::UnregisterForNotifications(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterForNotifications: RegisterForNotifications::<Identity>,
+            UnregisterForNotifications: UnregisterForNotifications::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPrintAsyncNotifyRegistration {}
 windows_core::imp::define_interface!(IPrintAsyncNotifyServerReferral, IPrintAsyncNotifyServerReferral_Vtbl, 0);
 windows_core::imp::interface_hierarchy!(IPrintAsyncNotifyServerReferral, windows_core::IUnknown);
@@ -5402,6 +6367,43 @@ impl IPrintAsyncNotifyServerReferral_Vtbl {
         iid == &<IPrintAsyncNotifyServerReferral as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintAsyncNotifyServerReferral_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintAsyncNotifyServerReferral_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetServerReferral<Identity: windows_core::IUnknownImpl + IPrintAsyncNotifyServerReferral_Impl>(this: *mut core::ffi::c_void, param0: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintAsyncNotifyServerReferral_Impl::GetServerReferral(this) {
+                    Ok(ok__) => {
+                        param0.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AsyncGetServerReferral<Identity: windows_core::IUnknownImpl + IPrintAsyncNotifyServerReferral_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintAsyncNotifyServerReferral_Impl::AsyncGetServerReferral(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        unsafe extern "system" fn SetServerReferral<Identity: windows_core::IUnknownImpl + IPrintAsyncNotifyServerReferral_Impl>(this: *mut core::ffi::c_void, prmtserverreferral: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintAsyncNotifyServerReferral_Impl::SetServerReferral(this, core::mem::transmute(&prmtserverreferral)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetServerReferral: GetServerReferral::<Identity>,
+            AsyncGetServerReferral: AsyncGetServerReferral::<Identity>,
+            SetServerReferral: SetServerReferral::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPrintAsyncNotifyServerReferral {}
 windows_core::imp::define_interface!(IPrintBidiAsyncNotifyRegistration, IPrintBidiAsyncNotifyRegistration_Vtbl, 0);
 impl core::ops::Deref for IPrintBidiAsyncNotifyRegistration {
@@ -5442,6 +6444,23 @@ impl IPrintBidiAsyncNotifyRegistration_Vtbl {
         iid == &<IPrintBidiAsyncNotifyRegistration as windows_core::Interface>::IID || iid == &<IPrintAsyncNotifyRegistration as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintBidiAsyncNotifyRegistration_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintBidiAsyncNotifyRegistration_Vtbl
+where
+    IPrintAsyncNotifyRegistration_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AsyncGetNewChannel<Identity: windows_core::IUnknownImpl + IPrintBidiAsyncNotifyRegistration_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintBidiAsyncNotifyRegistration_Impl::AsyncGetNewChannel(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        Self {
+            base__: <IPrintAsyncNotifyRegistration_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AsyncGetNewChannel: AsyncGetNewChannel::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPrintBidiAsyncNotifyRegistration {}
 windows_core::imp::define_interface!(IPrintClassObjectFactory, IPrintClassObjectFactory_Vtbl, 0x9af593dd_9b02_48a8_9bad_69ace423f88b);
 windows_core::imp::interface_hierarchy!(IPrintClassObjectFactory, windows_core::IUnknown);
@@ -5476,6 +6495,23 @@ impl IPrintClassObjectFactory_Vtbl {
         iid == &<IPrintClassObjectFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintClassObjectFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintClassObjectFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPrintClassObject<Identity: windows_core::IUnknownImpl + IPrintClassObjectFactory_Impl>(this: *mut core::ffi::c_void, pszprintername: windows_core::PCWSTR, riid: *const windows_core::GUID, ppnewobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintClassObjectFactory_Impl::GetPrintClassObject(this, core::mem::transmute(&pszprintername), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppnewobject)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPrintClassObject: GetPrintClassObject::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPrintClassObjectFactory {}
 windows_core::imp::define_interface!(IPrintCoreHelper, IPrintCoreHelper_Vtbl, 0xa89ec53e_3905_49c6_9c1a_c0a88117fdb6);
 windows_core::imp::interface_hierarchy!(IPrintCoreHelper, windows_core::IUnknown);
@@ -5657,6 +6693,86 @@ impl IPrintCoreHelper_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IPrintCoreHelper_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintCoreHelper_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetOption<Identity: windows_core::IUnknownImpl + IPrintCoreHelper_Impl>(this: *mut core::ffi::c_void, pdevmode: *const super::Gdi::DEVMODEA, cbsize: u32, pszfeaturerequested: windows_core::PCSTR, ppszoption: *mut windows_core::PCSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintCoreHelper_Impl::GetOption(this, core::mem::transmute_copy(&pdevmode), core::mem::transmute_copy(&cbsize), core::mem::transmute(&pszfeaturerequested)) {
+                    Ok(ok__) => {
+                        ppszoption.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetOptions<Identity: windows_core::IUnknownImpl + IPrintCoreHelper_Impl>(this: *mut core::ffi::c_void, pdevmode: *mut super::Gdi::DEVMODEA, cbsize: u32, bresolveconflicts: windows_core::BOOL, pfopairs: *const PRINT_FEATURE_OPTION, cpairs: u32, pcpairswritten: *mut u32, pdwresult: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintCoreHelper_Impl::SetOptions(this, core::mem::transmute_copy(&pdevmode), core::mem::transmute_copy(&cbsize), core::mem::transmute_copy(&bresolveconflicts), core::mem::transmute_copy(&pfopairs), core::mem::transmute_copy(&cpairs), core::mem::transmute_copy(&pcpairswritten), core::mem::transmute_copy(&pdwresult)).into()
+            }
+        }
+        unsafe extern "system" fn EnumConstrainedOptions<Identity: windows_core::IUnknownImpl + IPrintCoreHelper_Impl>(this: *mut core::ffi::c_void, pdevmode: *const super::Gdi::DEVMODEA, cbsize: u32, pszfeaturekeyword: windows_core::PCSTR, pconstrainedoptionlist: *const *const *const windows_core::PCSTR, pdwnumoptions: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintCoreHelper_Impl::EnumConstrainedOptions(this, core::mem::transmute_copy(&pdevmode), core::mem::transmute_copy(&cbsize), core::mem::transmute(&pszfeaturekeyword), core::mem::transmute_copy(&pconstrainedoptionlist), core::mem::transmute_copy(&pdwnumoptions)).into()
+            }
+        }
+        unsafe extern "system" fn WhyConstrained<Identity: windows_core::IUnknownImpl + IPrintCoreHelper_Impl>(this: *mut core::ffi::c_void, pdevmode: *const super::Gdi::DEVMODEA, cbsize: u32, pszfeaturekeyword: windows_core::PCSTR, pszoptionkeyword: windows_core::PCSTR, ppfoconstraints: *mut *mut PRINT_FEATURE_OPTION, pdwnumoptions: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintCoreHelper_Impl::WhyConstrained(this, core::mem::transmute_copy(&pdevmode), core::mem::transmute_copy(&cbsize), core::mem::transmute(&pszfeaturekeyword), core::mem::transmute(&pszoptionkeyword), core::mem::transmute_copy(&ppfoconstraints), core::mem::transmute_copy(&pdwnumoptions)).into()
+            }
+        }
+        unsafe extern "system" fn EnumFeatures<Identity: windows_core::IUnknownImpl + IPrintCoreHelper_Impl>(this: *mut core::ffi::c_void, pfeaturelist: *mut *mut *mut windows_core::PCSTR, pdwnumfeatures: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintCoreHelper_Impl::EnumFeatures(this, core::mem::transmute_copy(&pfeaturelist), core::mem::transmute_copy(&pdwnumfeatures)).into()
+            }
+        }
+        unsafe extern "system" fn EnumOptions<Identity: windows_core::IUnknownImpl + IPrintCoreHelper_Impl>(this: *mut core::ffi::c_void, pszfeaturekeyword: windows_core::PCSTR, poptionlist: *mut *mut *mut windows_core::PCSTR, pdwnumoptions: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintCoreHelper_Impl::EnumOptions(this, core::mem::transmute(&pszfeaturekeyword), core::mem::transmute_copy(&poptionlist), core::mem::transmute_copy(&pdwnumoptions)).into()
+            }
+        }
+        unsafe extern "system" fn GetFontSubstitution<Identity: windows_core::IUnknownImpl + IPrintCoreHelper_Impl>(this: *mut core::ffi::c_void, psztruetypefontname: windows_core::PCWSTR, ppszdevfontname: *const windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintCoreHelper_Impl::GetFontSubstitution(this, core::mem::transmute(&psztruetypefontname), core::mem::transmute_copy(&ppszdevfontname)).into()
+            }
+        }
+        unsafe extern "system" fn SetFontSubstitution<Identity: windows_core::IUnknownImpl + IPrintCoreHelper_Impl>(this: *mut core::ffi::c_void, psztruetypefontname: windows_core::PCWSTR, pszdevfontname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintCoreHelper_Impl::SetFontSubstitution(this, core::mem::transmute(&psztruetypefontname), core::mem::transmute(&pszdevfontname)).into()
+            }
+        }
+        unsafe extern "system" fn CreateInstanceOfMSXMLObject<Identity: windows_core::IUnknownImpl + IPrintCoreHelper_Impl>(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, punkouter: *mut core::ffi::c_void, dwclscontext: u32, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintCoreHelper_Impl::CreateInstanceOfMSXMLObject(this, core::mem::transmute_copy(&rclsid), core::mem::transmute_copy(&punkouter), core::mem::transmute_copy(&dwclscontext), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppv)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetOption: GetOption::<Identity>,
+            SetOptions: SetOptions::<Identity>,
+            EnumConstrainedOptions: EnumConstrainedOptions::<Identity>,
+            WhyConstrained: WhyConstrained::<Identity>,
+            EnumFeatures: EnumFeatures::<Identity>,
+            EnumOptions: EnumOptions::<Identity>,
+            GetFontSubstitution: GetFontSubstitution::<Identity>,
+            SetFontSubstitution: SetFontSubstitution::<Identity>,
+            CreateInstanceOfMSXMLObject: CreateInstanceOfMSXMLObject::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IPrintCoreHelper {}
 windows_core::imp::define_interface!(IPrintCoreHelperPS, IPrintCoreHelperPS_Vtbl, 0xc2c14f6f_95d3_4d63_96cf_6bd9e6c907c2);
 impl core::ops::Deref for IPrintCoreHelperPS {
@@ -5736,6 +6852,38 @@ impl IPrintCoreHelperPS_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IPrintCoreHelperPS_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintCoreHelperPS_Vtbl
+where
+    IPrintCoreHelper_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetGlobalAttribute<Identity: windows_core::IUnknownImpl + IPrintCoreHelperPS_Impl>(this: *mut core::ffi::c_void, pszattribute: windows_core::PCSTR, pdwdatatype: *mut u32, ppbdata: *mut *mut u8, pcbsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintCoreHelperPS_Impl::GetGlobalAttribute(this, core::mem::transmute(&pszattribute), core::mem::transmute_copy(&pdwdatatype), core::mem::transmute_copy(&ppbdata), core::mem::transmute_copy(&pcbsize)).into()
+            }
+        }
+        unsafe extern "system" fn GetFeatureAttribute<Identity: windows_core::IUnknownImpl + IPrintCoreHelperPS_Impl>(this: *mut core::ffi::c_void, pszfeaturekeyword: windows_core::PCSTR, pszattribute: windows_core::PCSTR, pdwdatatype: *mut u32, ppbdata: *mut *mut u8, pcbsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintCoreHelperPS_Impl::GetFeatureAttribute(this, core::mem::transmute(&pszfeaturekeyword), core::mem::transmute(&pszattribute), core::mem::transmute_copy(&pdwdatatype), core::mem::transmute_copy(&ppbdata), core::mem::transmute_copy(&pcbsize)).into()
+            }
+        }
+        unsafe extern "system" fn GetOptionAttribute<Identity: windows_core::IUnknownImpl + IPrintCoreHelperPS_Impl>(this: *mut core::ffi::c_void, pszfeaturekeyword: windows_core::PCSTR, pszoptionkeyword: windows_core::PCSTR, pszattribute: windows_core::PCSTR, pdwdatatype: *mut u32, ppbdata: *mut *mut u8, pcbsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintCoreHelperPS_Impl::GetOptionAttribute(this, core::mem::transmute(&pszfeaturekeyword), core::mem::transmute(&pszoptionkeyword), core::mem::transmute(&pszattribute), core::mem::transmute_copy(&pdwdatatype), core::mem::transmute_copy(&ppbdata), core::mem::transmute_copy(&pcbsize)).into()
+            }
+        }
+        Self {
+            base__: <IPrintCoreHelper_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetGlobalAttribute: GetGlobalAttribute::<Identity>,
+            GetFeatureAttribute: GetFeatureAttribute::<Identity>,
+            GetOptionAttribute: GetOptionAttribute::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IPrintCoreHelperPS {}
 windows_core::imp::define_interface!(IPrintCoreHelperUni, IPrintCoreHelperUni_Vtbl, 0x7e8e51d6_e5ee_4426_817b_958b9444eb79);
 impl core::ops::Deref for IPrintCoreHelperUni {
@@ -5808,13 +6956,44 @@ impl IPrintCoreHelperUni_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
-impl windows_core::RuntimeName for IPrintCoreHelperUni {}
-windows_core::imp::define_interface!(IPrintCoreHelperUni2, IPrintCoreHelperUni2_Vtbl, 0x6c8afdfc_ead0_4d2d_8071_9bf0175a6c3a);
-impl core::ops::Deref for IPrintCoreHelperUni2 {
-    type Target = IPrintCoreHelperUni;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
+impl<Identity: windows_core::IUnknownImpl + IPrintCoreHelperUni_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintCoreHelperUni_Vtbl
+where
+    IPrintCoreHelper_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateGDLSnapshot<Identity: windows_core::IUnknownImpl + IPrintCoreHelperUni_Impl>(this: *mut core::ffi::c_void, pdevmode: *mut super::Gdi::DEVMODEA, cbsize: u32, dwflags: u32, ppsnapshotstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintCoreHelperUni_Impl::CreateGDLSnapshot(this, core::mem::transmute_copy(&pdevmode), core::mem::transmute_copy(&cbsize), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&ppsnapshotstream)).into()
+            }
+        }
+        unsafe extern "system" fn CreateDefaultGDLSnapshot<Identity: windows_core::IUnknownImpl + IPrintCoreHelperUni_Impl>(this: *mut core::ffi::c_void, dwflags: u32, ppsnapshotstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintCoreHelperUni_Impl::CreateDefaultGDLSnapshot(this, core::mem::transmute_copy(&dwflags)) {
+                    Ok(ok__) => {
+                        ppsnapshotstream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IPrintCoreHelper_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateGDLSnapshot: CreateGDLSnapshot::<Identity>,
+            CreateDefaultGDLSnapshot: CreateDefaultGDLSnapshot::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl windows_core::RuntimeName for IPrintCoreHelperUni {}
+windows_core::imp::define_interface!(IPrintCoreHelperUni2, IPrintCoreHelperUni2_Vtbl, 0x6c8afdfc_ead0_4d2d_8071_9bf0175a6c3a);
+impl core::ops::Deref for IPrintCoreHelperUni2 {
+    type Target = IPrintCoreHelperUni;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
 }
 windows_core::imp::interface_hierarchy!(IPrintCoreHelperUni2, windows_core::IUnknown, IPrintCoreHelper, IPrintCoreHelperUni);
 impl IPrintCoreHelperUni2 {
@@ -5855,6 +7034,21 @@ impl IPrintCoreHelperUni2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl<Identity: windows_core::IUnknownImpl + IPrintCoreHelperUni2_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintCoreHelperUni2_Vtbl
+where
+    IPrintCoreHelperUni_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetNamedCommand<Identity: windows_core::IUnknownImpl + IPrintCoreHelperUni2_Impl>(this: *mut core::ffi::c_void, pdevmode: *const super::Gdi::DEVMODEA, cbsize: u32, pszcommandname: windows_core::PCWSTR, ppcommandbytes: *mut *mut u8, pcbcommandsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintCoreHelperUni2_Impl::GetNamedCommand(this, core::mem::transmute_copy(&pdevmode), core::mem::transmute_copy(&cbsize), core::mem::transmute(&pszcommandname), core::mem::transmute_copy(&ppcommandbytes), core::mem::transmute_copy(&pcbcommandsize)).into()
+            }
+        }
+        Self { base__: <IPrintCoreHelperUni_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetNamedCommand: GetNamedCommand::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
 impl windows_core::RuntimeName for IPrintCoreHelperUni2 {}
 windows_core::imp::define_interface!(IPrintCoreUI2, IPrintCoreUI2_Vtbl, 0x085ccfca_3adf_4c9e_b491_d851a6edc997);
 impl core::ops::Deref for IPrintCoreUI2 {
@@ -6046,6 +7240,92 @@ impl IPrintCoreUI2_Vtbl {
         iid == &<IPrintCoreUI2 as windows_core::Interface>::IID || iid == &<IPrintOemDriverUI as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintCoreUI2_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintCoreUI2_Vtbl
+where
+    IPrintOemDriverUI_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetOptions<Identity: windows_core::IUnknownImpl + IPrintCoreUI2_Impl>(this: *mut core::ffi::c_void, poemuiobj: *const OEMUIOBJ, dwflags: u32, pmszfeaturesrequested: *const i8, cbin: u32, pmszfeatureoptionbuf: windows_core::PSTR, cbsize: u32, pcbneeded: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintCoreUI2_Impl::GetOptions(this, core::mem::transmute_copy(&poemuiobj), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pmszfeaturesrequested), core::mem::transmute_copy(&cbin), core::mem::transmute_copy(&pmszfeatureoptionbuf), core::mem::transmute_copy(&cbsize), core::mem::transmute_copy(&pcbneeded)).into()
+            }
+        }
+        unsafe extern "system" fn SetOptions<Identity: windows_core::IUnknownImpl + IPrintCoreUI2_Impl>(this: *mut core::ffi::c_void, poemuiobj: *const OEMUIOBJ, dwflags: u32, pmszfeatureoptionbuf: *const i8, cbin: u32, pdwresult: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintCoreUI2_Impl::SetOptions(this, core::mem::transmute_copy(&poemuiobj), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pmszfeatureoptionbuf), core::mem::transmute_copy(&cbin)) {
+                    Ok(ok__) => {
+                        pdwresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumConstrainedOptions<Identity: windows_core::IUnknownImpl + IPrintCoreUI2_Impl>(this: *mut core::ffi::c_void, poemuiobj: *const OEMUIOBJ, dwflags: u32, pszfeaturekeyword: windows_core::PCSTR, pmszconstrainedoptionlist: windows_core::PSTR, cbsize: u32, pcbneeded: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintCoreUI2_Impl::EnumConstrainedOptions(this, core::mem::transmute_copy(&poemuiobj), core::mem::transmute_copy(&dwflags), core::mem::transmute(&pszfeaturekeyword), core::mem::transmute_copy(&pmszconstrainedoptionlist), core::mem::transmute_copy(&cbsize), core::mem::transmute_copy(&pcbneeded)).into()
+            }
+        }
+        unsafe extern "system" fn WhyConstrained<Identity: windows_core::IUnknownImpl + IPrintCoreUI2_Impl>(this: *mut core::ffi::c_void, poemuiobj: *const OEMUIOBJ, dwflags: u32, pszfeaturekeyword: windows_core::PCSTR, pszoptionkeyword: windows_core::PCSTR, pmszreasonlist: windows_core::PSTR, cbsize: u32, pcbneeded: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintCoreUI2_Impl::WhyConstrained(this, core::mem::transmute_copy(&poemuiobj), core::mem::transmute_copy(&dwflags), core::mem::transmute(&pszfeaturekeyword), core::mem::transmute(&pszoptionkeyword), core::mem::transmute_copy(&pmszreasonlist), core::mem::transmute_copy(&cbsize), core::mem::transmute_copy(&pcbneeded)).into()
+            }
+        }
+        unsafe extern "system" fn GetGlobalAttribute<Identity: windows_core::IUnknownImpl + IPrintCoreUI2_Impl>(this: *mut core::ffi::c_void, poemuiobj: *const OEMUIOBJ, dwflags: u32, pszattribute: windows_core::PCSTR, pdwdatatype: *mut u32, pbdata: *mut u8, cbsize: u32, pcbneeded: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintCoreUI2_Impl::GetGlobalAttribute(this, core::mem::transmute_copy(&poemuiobj), core::mem::transmute_copy(&dwflags), core::mem::transmute(&pszattribute), core::mem::transmute_copy(&pdwdatatype), core::mem::transmute_copy(&pbdata), core::mem::transmute_copy(&cbsize), core::mem::transmute_copy(&pcbneeded)).into()
+            }
+        }
+        unsafe extern "system" fn GetFeatureAttribute<Identity: windows_core::IUnknownImpl + IPrintCoreUI2_Impl>(this: *mut core::ffi::c_void, poemuiobj: *const OEMUIOBJ, dwflags: u32, pszfeaturekeyword: windows_core::PCSTR, pszattribute: windows_core::PCSTR, pdwdatatype: *mut u32, pbdata: *mut u8, cbsize: u32, pcbneeded: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintCoreUI2_Impl::GetFeatureAttribute(this, core::mem::transmute_copy(&poemuiobj), core::mem::transmute_copy(&dwflags), core::mem::transmute(&pszfeaturekeyword), core::mem::transmute(&pszattribute), core::mem::transmute_copy(&pdwdatatype), core::mem::transmute_copy(&pbdata), core::mem::transmute_copy(&cbsize), core::mem::transmute_copy(&pcbneeded)).into()
+            }
+        }
+        unsafe extern "system" fn GetOptionAttribute<Identity: windows_core::IUnknownImpl + IPrintCoreUI2_Impl>(this: *mut core::ffi::c_void, poemuiobj: *const OEMUIOBJ, dwflags: u32, pszfeaturekeyword: windows_core::PCSTR, pszoptionkeyword: windows_core::PCSTR, pszattribute: windows_core::PCSTR, pdwdatatype: *mut u32, pbdata: *mut u8, cbsize: u32, pcbneeded: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintCoreUI2_Impl::GetOptionAttribute(this, core::mem::transmute_copy(&poemuiobj), core::mem::transmute_copy(&dwflags), core::mem::transmute(&pszfeaturekeyword), core::mem::transmute(&pszoptionkeyword), core::mem::transmute(&pszattribute), core::mem::transmute_copy(&pdwdatatype), core::mem::transmute_copy(&pbdata), core::mem::transmute_copy(&cbsize), core::mem::transmute_copy(&pcbneeded)).into()
+            }
+        }
+        unsafe extern "system" fn EnumFeatures<Identity: windows_core::IUnknownImpl + IPrintCoreUI2_Impl>(this: *mut core::ffi::c_void, poemuiobj: *const OEMUIOBJ, dwflags: u32, pmszfeaturelist: windows_core::PSTR, cbsize: u32, pcbneeded: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintCoreUI2_Impl::EnumFeatures(this, core::mem::transmute_copy(&poemuiobj), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pmszfeaturelist), core::mem::transmute_copy(&cbsize), core::mem::transmute_copy(&pcbneeded)).into()
+            }
+        }
+        unsafe extern "system" fn EnumOptions<Identity: windows_core::IUnknownImpl + IPrintCoreUI2_Impl>(this: *mut core::ffi::c_void, poemuiobj: *const OEMUIOBJ, dwflags: u32, pszfeaturekeyword: windows_core::PCSTR, pmszoptionlist: windows_core::PSTR, cbsize: u32, pcbneeded: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintCoreUI2_Impl::EnumOptions(this, core::mem::transmute_copy(&poemuiobj), core::mem::transmute_copy(&dwflags), core::mem::transmute(&pszfeaturekeyword), core::mem::transmute_copy(&pmszoptionlist), core::mem::transmute_copy(&cbsize), core::mem::transmute_copy(&pcbneeded)).into()
+            }
+        }
+        unsafe extern "system" fn QuerySimulationSupport<Identity: windows_core::IUnknownImpl + IPrintCoreUI2_Impl>(this: *mut core::ffi::c_void, hprinter: super::super::Foundation::HANDLE, dwlevel: u32, pcaps: *mut u8, cbsize: u32, pcbneeded: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintCoreUI2_Impl::QuerySimulationSupport(this, core::mem::transmute_copy(&hprinter), core::mem::transmute_copy(&dwlevel), core::mem::transmute_copy(&pcaps), core::mem::transmute_copy(&cbsize), core::mem::transmute_copy(&pcbneeded)).into()
+            }
+        }
+        Self {
+            base__: <IPrintOemDriverUI_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetOptions: GetOptions::<Identity>,
+            SetOptions: SetOptions::<Identity>,
+            EnumConstrainedOptions: EnumConstrainedOptions::<Identity>,
+            WhyConstrained: WhyConstrained::<Identity>,
+            GetGlobalAttribute: GetGlobalAttribute::<Identity>,
+            GetFeatureAttribute: GetFeatureAttribute::<Identity>,
+            GetOptionAttribute: GetOptionAttribute::<Identity>,
+            EnumFeatures: EnumFeatures::<Identity>,
+            EnumOptions: EnumOptions::<Identity>,
+            QuerySimulationSupport: QuerySimulationSupport::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPrintCoreUI2 {}
 windows_core::imp::define_interface!(IPrintJob, IPrintJob_Vtbl, 0xb771dab8_1282_41b7_858c_f206e4d20577);
 windows_core::imp::interface_hierarchy!(IPrintJob, windows_core::IUnknown);
@@ -6206,6 +7486,101 @@ impl IPrintJob_Vtbl {
         iid == &<IPrintJob as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintJob_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintJob_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + IPrintJob_Impl>(this: *mut core::ffi::c_void, pbstrname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintJob_Impl::Name(this) {
+                    Ok(ok__) => {
+                        pbstrname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + IPrintJob_Impl>(this: *mut core::ffi::c_void, pulid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintJob_Impl::Id(this) {
+                    Ok(ok__) => {
+                        pulid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PrintedPages<Identity: windows_core::IUnknownImpl + IPrintJob_Impl>(this: *mut core::ffi::c_void, pulpages: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintJob_Impl::PrintedPages(this) {
+                    Ok(ok__) => {
+                        pulpages.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn TotalPages<Identity: windows_core::IUnknownImpl + IPrintJob_Impl>(this: *mut core::ffi::c_void, pulpages: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintJob_Impl::TotalPages(this) {
+                    Ok(ok__) => {
+                        pulpages.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Status<Identity: windows_core::IUnknownImpl + IPrintJob_Impl>(this: *mut core::ffi::c_void, pstatus: *mut PrintJobStatus) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintJob_Impl::Status(this) {
+                    Ok(ok__) => {
+                        pstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SubmissionTime<Identity: windows_core::IUnknownImpl + IPrintJob_Impl>(this: *mut core::ffi::c_void, psubmissiontime: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintJob_Impl::SubmissionTime(this) {
+                    Ok(ok__) => {
+                        psubmissiontime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RequestCancel<Identity: windows_core::IUnknownImpl + IPrintJob_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintJob_Impl::RequestCancel(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Name: Name::<Identity>,
+            Id: Id::<Identity>,
+            PrintedPages: PrintedPages::<Identity>,
+            TotalPages: TotalPages::<Identity>,
+            Status: Status::<Identity>,
+            SubmissionTime: SubmissionTime::<Identity>,
+            RequestCancel: RequestCancel::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPrintJob {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrintJobCollection, IPrintJobCollection_Vtbl, 0x72b82a24_a598_4e87_895f_cdb23a49e9dc);
@@ -6305,6 +7680,56 @@ impl IPrintJobCollection_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrintJobCollection_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintJobCollection_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + IPrintJobCollection_Impl>(this: *mut core::ffi::c_void, pulcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintJobCollection_Impl::Count(this) {
+                    Ok(ok__) => {
+                        pulcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAt<Identity: windows_core::IUnknownImpl + IPrintJobCollection_Impl>(this: *mut core::ffi::c_void, ulindex: u32, ppjob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintJobCollection_Impl::GetAt(this, core::mem::transmute_copy(&ulindex)) {
+                    Ok(ok__) => {
+                        ppjob.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + IPrintJobCollection_Impl>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintJobCollection_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        ppunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Count: Count::<Identity>,
+            GetAt: GetAt::<Identity>,
+            _NewEnum: _NewEnum::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrintJobCollection {}
 windows_core::imp::define_interface!(IPrintOemCommon, IPrintOemCommon_Vtbl, 0x7f42285e_91d5_11d1_8820_00c04fb961ec);
 windows_core::imp::interface_hierarchy!(IPrintOemCommon, windows_core::IUnknown);
@@ -6354,6 +7779,31 @@ impl IPrintOemCommon_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IPrintOemCommon_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintOemCommon_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetInfo<Identity: windows_core::IUnknownImpl + IPrintOemCommon_Impl>(this: *mut core::ffi::c_void, dwmode: u32, pbuffer: *mut core::ffi::c_void, cbsize: u32, pcbneeded: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOemCommon_Impl::GetInfo(this, core::mem::transmute_copy(&dwmode), core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&cbsize), core::mem::transmute_copy(&pcbneeded)).into()
+            }
+        }
+        unsafe extern "system" fn DevMode<Identity: windows_core::IUnknownImpl + IPrintOemCommon_Impl>(this: *mut core::ffi::c_void, dwmode: u32, poemdmparam: *mut OEMDMPARAM) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOemCommon_Impl::DevMode(this, core::mem::transmute_copy(&dwmode), core::mem::transmute_copy(&poemdmparam)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetInfo: GetInfo::<Identity>,
+            DevMode: DevMode::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IPrintOemCommon {}
 windows_core::imp::define_interface!(IPrintOemDriverUI, IPrintOemDriverUI_Vtbl, 0x92b05d50_78bc_11d1_9480_00a0c90640b8);
 windows_core::imp::interface_hierarchy!(IPrintOemDriverUI, windows_core::IUnknown);
@@ -6419,6 +7869,37 @@ impl IPrintOemDriverUI_Vtbl {
         iid == &<IPrintOemDriverUI as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintOemDriverUI_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintOemDriverUI_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DrvGetDriverSetting<Identity: windows_core::IUnknownImpl + IPrintOemDriverUI_Impl>(this: *mut core::ffi::c_void, pci: *mut core::ffi::c_void, feature: windows_core::PCSTR, poutput: *mut core::ffi::c_void, cbsize: u32, pcbneeded: *mut u32, pdwoptionsreturned: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOemDriverUI_Impl::DrvGetDriverSetting(this, core::mem::transmute_copy(&pci), core::mem::transmute(&feature), core::mem::transmute_copy(&poutput), core::mem::transmute_copy(&cbsize), core::mem::transmute_copy(&pcbneeded), core::mem::transmute_copy(&pdwoptionsreturned)).into()
+            }
+        }
+        unsafe extern "system" fn DrvUpgradeRegistrySetting<Identity: windows_core::IUnknownImpl + IPrintOemDriverUI_Impl>(this: *mut core::ffi::c_void, hprinter: super::super::Foundation::HANDLE, pfeature: windows_core::PCSTR, poption: windows_core::PCSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOemDriverUI_Impl::DrvUpgradeRegistrySetting(this, core::mem::transmute_copy(&hprinter), core::mem::transmute(&pfeature), core::mem::transmute(&poption)).into()
+            }
+        }
+        unsafe extern "system" fn DrvUpdateUISetting<Identity: windows_core::IUnknownImpl + IPrintOemDriverUI_Impl>(this: *mut core::ffi::c_void, pci: *mut core::ffi::c_void, poptitem: *mut core::ffi::c_void, dwpreviousselection: u32, dwmode: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOemDriverUI_Impl::DrvUpdateUISetting(this, core::mem::transmute_copy(&pci), core::mem::transmute_copy(&poptitem), core::mem::transmute_copy(&dwpreviousselection), core::mem::transmute_copy(&dwmode)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DrvGetDriverSetting: DrvGetDriverSetting::<Identity>,
+            DrvUpgradeRegistrySetting: DrvUpgradeRegistrySetting::<Identity>,
+            DrvUpdateUISetting: DrvUpdateUISetting::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPrintOemDriverUI {}
 windows_core::imp::define_interface!(IPrintOemUI, IPrintOemUI_Vtbl, 0xc6a7a9d0_774c_11d1_947f_00a0c90640b8);
 impl core::ops::Deref for IPrintOemUI {
@@ -6622,41 +8103,136 @@ impl IPrintOemUI_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
-impl windows_core::RuntimeName for IPrintOemUI {}
-windows_core::imp::define_interface!(IPrintOemUI2, IPrintOemUI2_Vtbl, 0x292515f9_b54b_489b_9275_bab56821395e);
-impl core::ops::Deref for IPrintOemUI2 {
-    type Target = IPrintOemUI;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(IPrintOemUI2, windows_core::IUnknown, IPrintOemCommon, IPrintOemUI);
-impl IPrintOemUI2 {
-    #[cfg(feature = "Win32_Graphics_Gdi")]
-    pub unsafe fn QueryJobAttributes(&self, hprinter: PRINTER_HANDLE, pdevmode: *const super::Gdi::DEVMODEA, dwlevel: u32, lpattributeinfo: *const u8) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).QueryJobAttributes)(windows_core::Interface::as_raw(self), hprinter, pdevmode, dwlevel, lpattributeinfo).ok() }
-    }
-    pub unsafe fn HideStandardUI(&self, dwmode: u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).HideStandardUI)(windows_core::Interface::as_raw(self), dwmode).ok() }
-    }
-    #[cfg(feature = "Win32_Graphics_Gdi")]
-    pub unsafe fn DocumentEvent(&self, hprinter: PRINTER_HANDLE, hdc: super::Gdi::HDC, iesc: i32, cbin: u32, pvin: *mut core::ffi::c_void, cbout: u32, pvout: *mut core::ffi::c_void, piresult: *mut i32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).DocumentEvent)(windows_core::Interface::as_raw(self), hprinter, hdc, iesc, cbin, pvin as _, cbout, pvout as _, piresult as _).ok() }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IPrintOemUI2_Vtbl {
-    pub base__: IPrintOemUI_Vtbl,
-    #[cfg(feature = "Win32_Graphics_Gdi")]
-    pub QueryJobAttributes: unsafe extern "system" fn(*mut core::ffi::c_void, PRINTER_HANDLE, *const super::Gdi::DEVMODEA, u32, *const u8) -> windows_core::HRESULT,
-    #[cfg(not(feature = "Win32_Graphics_Gdi"))]
-    QueryJobAttributes: usize,
-    pub HideStandardUI: unsafe extern "system" fn(*mut core::ffi::c_void, u32) -> windows_core::HRESULT,
-    #[cfg(feature = "Win32_Graphics_Gdi")]
-    pub DocumentEvent: unsafe extern "system" fn(*mut core::ffi::c_void, PRINTER_HANDLE, super::Gdi::HDC, i32, u32, *mut core::ffi::c_void, u32, *mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
-    #[cfg(not(feature = "Win32_Graphics_Gdi"))]
-    DocumentEvent: usize,
+impl<Identity: windows_core::IUnknownImpl + IPrintOemUI_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintOemUI_Vtbl
+where
+    IPrintOemCommon_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn PublishDriverInterface<Identity: windows_core::IUnknownImpl + IPrintOemUI_Impl>(this: *mut core::ffi::c_void, piunknown: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOemUI_Impl::PublishDriverInterface(this, core::mem::transmute_copy(&piunknown)).into()
+            }
+        }
+        unsafe extern "system" fn CommonUIProp<Identity: windows_core::IUnknownImpl + IPrintOemUI_Impl>(this: *mut core::ffi::c_void, dwmode: u32, poemcuipparam: *const OEMCUIPPARAM) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOemUI_Impl::CommonUIProp(this, core::mem::transmute_copy(&dwmode), core::mem::transmute_copy(&poemcuipparam)).into()
+            }
+        }
+        unsafe extern "system" fn DocumentPropertySheets<Identity: windows_core::IUnknownImpl + IPrintOemUI_Impl>(this: *mut core::ffi::c_void, ppsuiinfo: *mut PROPSHEETUI_INFO, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOemUI_Impl::DocumentPropertySheets(this, core::mem::transmute_copy(&ppsuiinfo), core::mem::transmute_copy(&lparam)).into()
+            }
+        }
+        unsafe extern "system" fn DevicePropertySheets<Identity: windows_core::IUnknownImpl + IPrintOemUI_Impl>(this: *mut core::ffi::c_void, ppsuiinfo: *const PROPSHEETUI_INFO, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOemUI_Impl::DevicePropertySheets(this, core::mem::transmute_copy(&ppsuiinfo), core::mem::transmute_copy(&lparam)).into()
+            }
+        }
+        unsafe extern "system" fn DevQueryPrintEx<Identity: windows_core::IUnknownImpl + IPrintOemUI_Impl>(this: *mut core::ffi::c_void, poemuiobj: *const OEMUIOBJ, pdqpinfo: *const DEVQUERYPRINT_INFO, ppublicdm: *const super::Gdi::DEVMODEA, poemdm: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOemUI_Impl::DevQueryPrintEx(this, core::mem::transmute_copy(&poemuiobj), core::mem::transmute_copy(&pdqpinfo), core::mem::transmute_copy(&ppublicdm), core::mem::transmute_copy(&poemdm)).into()
+            }
+        }
+        unsafe extern "system" fn DeviceCapabilitiesA<Identity: windows_core::IUnknownImpl + IPrintOemUI_Impl>(this: *mut core::ffi::c_void, poemuiobj: *mut OEMUIOBJ, hprinter: super::super::Foundation::HANDLE, pdevicename: windows_core::PCWSTR, wcapability: u16, poutput: *mut core::ffi::c_void, ppublicdm: *const super::Gdi::DEVMODEA, poemdm: *const core::ffi::c_void, dwold: u32, dwresult: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOemUI_Impl::DeviceCapabilitiesA(this, core::mem::transmute_copy(&poemuiobj), core::mem::transmute_copy(&hprinter), core::mem::transmute(&pdevicename), core::mem::transmute_copy(&wcapability), core::mem::transmute_copy(&poutput), core::mem::transmute_copy(&ppublicdm), core::mem::transmute_copy(&poemdm), core::mem::transmute_copy(&dwold), core::mem::transmute_copy(&dwresult)).into()
+            }
+        }
+        unsafe extern "system" fn UpgradePrinter<Identity: windows_core::IUnknownImpl + IPrintOemUI_Impl>(this: *mut core::ffi::c_void, dwlevel: u32, pdriverupgradeinfo: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOemUI_Impl::UpgradePrinter(this, core::mem::transmute_copy(&dwlevel), core::mem::transmute_copy(&pdriverupgradeinfo)).into()
+            }
+        }
+        unsafe extern "system" fn PrinterEvent<Identity: windows_core::IUnknownImpl + IPrintOemUI_Impl>(this: *mut core::ffi::c_void, pprintername: windows_core::PCWSTR, idriverevent: i32, dwflags: u32, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOemUI_Impl::PrinterEvent(this, core::mem::transmute(&pprintername), core::mem::transmute_copy(&idriverevent), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&lparam)).into()
+            }
+        }
+        unsafe extern "system" fn DriverEvent<Identity: windows_core::IUnknownImpl + IPrintOemUI_Impl>(this: *mut core::ffi::c_void, dwdriverevent: u32, dwlevel: u32, pdriverinfo: *const u8, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOemUI_Impl::DriverEvent(this, core::mem::transmute_copy(&dwdriverevent), core::mem::transmute_copy(&dwlevel), core::mem::transmute_copy(&pdriverinfo), core::mem::transmute_copy(&lparam)).into()
+            }
+        }
+        unsafe extern "system" fn QueryColorProfile<Identity: windows_core::IUnknownImpl + IPrintOemUI_Impl>(this: *mut core::ffi::c_void, hprinter: PRINTER_HANDLE, poemuiobj: *const OEMUIOBJ, ppublicdm: *const super::Gdi::DEVMODEA, poemdm: *const core::ffi::c_void, ulquerymode: u32, pvprofiledata: *mut core::ffi::c_void, pcbprofiledata: *mut u32, pflprofiledata: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOemUI_Impl::QueryColorProfile(this, core::mem::transmute_copy(&hprinter), core::mem::transmute_copy(&poemuiobj), core::mem::transmute_copy(&ppublicdm), core::mem::transmute_copy(&poemdm), core::mem::transmute_copy(&ulquerymode), core::mem::transmute_copy(&pvprofiledata), core::mem::transmute_copy(&pcbprofiledata), core::mem::transmute_copy(&pflprofiledata)).into()
+            }
+        }
+        unsafe extern "system" fn FontInstallerDlgProc<Identity: windows_core::IUnknownImpl + IPrintOemUI_Impl>(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, usmsg: u32, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOemUI_Impl::FontInstallerDlgProc(this, core::mem::transmute_copy(&hwnd), core::mem::transmute_copy(&usmsg), core::mem::transmute_copy(&wparam), core::mem::transmute_copy(&lparam)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateExternalFonts<Identity: windows_core::IUnknownImpl + IPrintOemUI_Impl>(this: *mut core::ffi::c_void, hprinter: PRINTER_HANDLE, hheap: super::super::Foundation::HANDLE, pwstrcartridges: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOemUI_Impl::UpdateExternalFonts(this, core::mem::transmute_copy(&hprinter), core::mem::transmute_copy(&hheap), core::mem::transmute(&pwstrcartridges)).into()
+            }
+        }
+        Self {
+            base__: <IPrintOemCommon_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PublishDriverInterface: PublishDriverInterface::<Identity>,
+            CommonUIProp: CommonUIProp::<Identity>,
+            DocumentPropertySheets: DocumentPropertySheets::<Identity>,
+            DevicePropertySheets: DevicePropertySheets::<Identity>,
+            DevQueryPrintEx: DevQueryPrintEx::<Identity>,
+            DeviceCapabilitiesA: DeviceCapabilitiesA::<Identity>,
+            UpgradePrinter: UpgradePrinter::<Identity>,
+            PrinterEvent: PrinterEvent::<Identity>,
+            DriverEvent: DriverEvent::<Identity>,
+            QueryColorProfile: QueryColorProfile::<Identity>,
+            FontInstallerDlgProc: FontInstallerDlgProc::<Identity>,
+            UpdateExternalFonts: UpdateExternalFonts::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::RuntimeName for IPrintOemUI {}
+windows_core::imp::define_interface!(IPrintOemUI2, IPrintOemUI2_Vtbl, 0x292515f9_b54b_489b_9275_bab56821395e);
+impl core::ops::Deref for IPrintOemUI2 {
+    type Target = IPrintOemUI;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(IPrintOemUI2, windows_core::IUnknown, IPrintOemCommon, IPrintOemUI);
+impl IPrintOemUI2 {
+    #[cfg(feature = "Win32_Graphics_Gdi")]
+    pub unsafe fn QueryJobAttributes(&self, hprinter: PRINTER_HANDLE, pdevmode: *const super::Gdi::DEVMODEA, dwlevel: u32, lpattributeinfo: *const u8) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).QueryJobAttributes)(windows_core::Interface::as_raw(self), hprinter, pdevmode, dwlevel, lpattributeinfo).ok() }
+    }
+    pub unsafe fn HideStandardUI(&self, dwmode: u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).HideStandardUI)(windows_core::Interface::as_raw(self), dwmode).ok() }
+    }
+    #[cfg(feature = "Win32_Graphics_Gdi")]
+    pub unsafe fn DocumentEvent(&self, hprinter: PRINTER_HANDLE, hdc: super::Gdi::HDC, iesc: i32, cbin: u32, pvin: *mut core::ffi::c_void, cbout: u32, pvout: *mut core::ffi::c_void, piresult: *mut i32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).DocumentEvent)(windows_core::Interface::as_raw(self), hprinter, hdc, iesc, cbin, pvin as _, cbout, pvout as _, piresult as _).ok() }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IPrintOemUI2_Vtbl {
+    pub base__: IPrintOemUI_Vtbl,
+    #[cfg(feature = "Win32_Graphics_Gdi")]
+    pub QueryJobAttributes: unsafe extern "system" fn(*mut core::ffi::c_void, PRINTER_HANDLE, *const super::Gdi::DEVMODEA, u32, *const u8) -> windows_core::HRESULT,
+    #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+    QueryJobAttributes: usize,
+    pub HideStandardUI: unsafe extern "system" fn(*mut core::ffi::c_void, u32) -> windows_core::HRESULT,
+    #[cfg(feature = "Win32_Graphics_Gdi")]
+    pub DocumentEvent: unsafe extern "system" fn(*mut core::ffi::c_void, PRINTER_HANDLE, super::Gdi::HDC, i32, u32, *mut core::ffi::c_void, u32, *mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
+    #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+    DocumentEvent: usize,
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
 pub trait IPrintOemUI2_Impl: IPrintOemUI_Impl {
@@ -6697,6 +8273,38 @@ impl IPrintOemUI2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+impl<Identity: windows_core::IUnknownImpl + IPrintOemUI2_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintOemUI2_Vtbl
+where
+    IPrintOemUI_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryJobAttributes<Identity: windows_core::IUnknownImpl + IPrintOemUI2_Impl>(this: *mut core::ffi::c_void, hprinter: PRINTER_HANDLE, pdevmode: *const super::Gdi::DEVMODEA, dwlevel: u32, lpattributeinfo: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOemUI2_Impl::QueryJobAttributes(this, core::mem::transmute_copy(&hprinter), core::mem::transmute_copy(&pdevmode), core::mem::transmute_copy(&dwlevel), core::mem::transmute_copy(&lpattributeinfo)).into()
+            }
+        }
+        unsafe extern "system" fn HideStandardUI<Identity: windows_core::IUnknownImpl + IPrintOemUI2_Impl>(this: *mut core::ffi::c_void, dwmode: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOemUI2_Impl::HideStandardUI(this, core::mem::transmute_copy(&dwmode)).into()
+            }
+        }
+        unsafe extern "system" fn DocumentEvent<Identity: windows_core::IUnknownImpl + IPrintOemUI2_Impl>(this: *mut core::ffi::c_void, hprinter: PRINTER_HANDLE, hdc: super::Gdi::HDC, iesc: i32, cbin: u32, pvin: *mut core::ffi::c_void, cbout: u32, pvout: *mut core::ffi::c_void, piresult: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOemUI2_Impl::DocumentEvent(this, core::mem::transmute_copy(&hprinter), core::mem::transmute_copy(&hdc), core::mem::transmute_copy(&iesc), core::mem::transmute_copy(&cbin), core::mem::transmute_copy(&pvin), core::mem::transmute_copy(&cbout), core::mem::transmute_copy(&pvout), core::mem::transmute_copy(&piresult)).into()
+            }
+        }
+        Self {
+            base__: <IPrintOemUI_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryJobAttributes: QueryJobAttributes::<Identity>,
+            HideStandardUI: HideStandardUI::<Identity>,
+            DocumentEvent: DocumentEvent::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
 impl windows_core::RuntimeName for IPrintOemUI2 {}
 windows_core::imp::define_interface!(IPrintOemUIMXDC, IPrintOemUIMXDC_Vtbl, 0x7349d725_e2c1_4dca_afb5_c13e91bc9306);
 windows_core::imp::interface_hierarchy!(IPrintOemUIMXDC, windows_core::IUnknown);
@@ -6770,6 +8378,38 @@ impl IPrintOemUIMXDC_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IPrintOemUIMXDC_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintOemUIMXDC_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AdjustImageableArea<Identity: windows_core::IUnknownImpl + IPrintOemUIMXDC_Impl>(this: *mut core::ffi::c_void, hprinter: PRINTER_HANDLE, cbdevmode: u32, pdevmode: *const super::Gdi::DEVMODEA, cboemdm: u32, poemdm: *const core::ffi::c_void, prclimageablearea: *mut super::super::Foundation::RECTL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOemUIMXDC_Impl::AdjustImageableArea(this, core::mem::transmute_copy(&hprinter), core::mem::transmute_copy(&cbdevmode), core::mem::transmute_copy(&pdevmode), core::mem::transmute_copy(&cboemdm), core::mem::transmute_copy(&poemdm), core::mem::transmute_copy(&prclimageablearea)).into()
+            }
+        }
+        unsafe extern "system" fn AdjustImageCompression<Identity: windows_core::IUnknownImpl + IPrintOemUIMXDC_Impl>(this: *mut core::ffi::c_void, hprinter: PRINTER_HANDLE, cbdevmode: u32, pdevmode: *const super::Gdi::DEVMODEA, cboemdm: u32, poemdm: *const core::ffi::c_void, pcompressionmode: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOemUIMXDC_Impl::AdjustImageCompression(this, core::mem::transmute_copy(&hprinter), core::mem::transmute_copy(&cbdevmode), core::mem::transmute_copy(&pdevmode), core::mem::transmute_copy(&cboemdm), core::mem::transmute_copy(&poemdm), core::mem::transmute_copy(&pcompressionmode)).into()
+            }
+        }
+        unsafe extern "system" fn AdjustDPI<Identity: windows_core::IUnknownImpl + IPrintOemUIMXDC_Impl>(this: *mut core::ffi::c_void, hprinter: PRINTER_HANDLE, cbdevmode: u32, pdevmode: *const super::Gdi::DEVMODEA, cboemdm: u32, poemdm: *const core::ffi::c_void, pdpi: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintOemUIMXDC_Impl::AdjustDPI(this, core::mem::transmute_copy(&hprinter), core::mem::transmute_copy(&cbdevmode), core::mem::transmute_copy(&pdevmode), core::mem::transmute_copy(&cboemdm), core::mem::transmute_copy(&poemdm), core::mem::transmute_copy(&pdpi)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AdjustImageableArea: AdjustImageableArea::<Identity>,
+            AdjustImageCompression: AdjustImageCompression::<Identity>,
+            AdjustDPI: AdjustDPI::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IPrintOemUIMXDC {}
 windows_core::imp::define_interface!(IPrintPipelineFilter, IPrintPipelineFilter_Vtbl, 0xcdb62fc0_8bed_434e_86fb_a2cae55f19ea);
 windows_core::imp::interface_hierarchy!(IPrintPipelineFilter, windows_core::IUnknown);
@@ -6833,6 +8473,37 @@ impl IPrintPipelineFilter_Vtbl {
         iid == &<IPrintPipelineFilter as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintPipelineFilter_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintPipelineFilter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InitializeFilter<Identity: windows_core::IUnknownImpl + IPrintPipelineFilter_Impl>(this: *mut core::ffi::c_void, pinegotiation: *mut core::ffi::c_void, pipropertybag: *mut core::ffi::c_void, pipipelinecontrol: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintPipelineFilter_Impl::InitializeFilter(this, core::mem::transmute_copy(&pinegotiation), core::mem::transmute_copy(&pipropertybag), core::mem::transmute_copy(&pipipelinecontrol)).into()
+            }
+        }
+        unsafe extern "system" fn ShutdownOperation<Identity: windows_core::IUnknownImpl + IPrintPipelineFilter_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintPipelineFilter_Impl::ShutdownOperation(this).into()
+            }
+        }
+        unsafe extern "system" fn StartOperation<Identity: windows_core::IUnknownImpl + IPrintPipelineFilter_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintPipelineFilter_Impl::StartOperation(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InitializeFilter: InitializeFilter::<Identity>,
+            ShutdownOperation: ShutdownOperation::<Identity>,
+            StartOperation: StartOperation::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPrintPipelineFilter {}
 windows_core::imp::define_interface!(IPrintPipelineManagerControl, IPrintPipelineManagerControl_Vtbl, 0xaa3e4910_5889_4681_91ef_823ad4ed4e44);
 windows_core::imp::interface_hierarchy!(IPrintPipelineManagerControl, windows_core::IUnknown);
@@ -6889,6 +8560,31 @@ impl IPrintPipelineManagerControl_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IPrintPipelineManagerControl_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintPipelineManagerControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RequestShutdown<Identity: windows_core::IUnknownImpl + IPrintPipelineManagerControl_Impl>(this: *mut core::ffi::c_void, hrreason: windows_core::HRESULT, preason: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintPipelineManagerControl_Impl::RequestShutdown(this, core::mem::transmute_copy(&hrreason), core::mem::transmute_copy(&preason)).into()
+            }
+        }
+        unsafe extern "system" fn FilterFinished<Identity: windows_core::IUnknownImpl + IPrintPipelineManagerControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintPipelineManagerControl_Impl::FilterFinished(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RequestShutdown: RequestShutdown::<Identity>,
+            FilterFinished: FilterFinished::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IPrintPipelineManagerControl {}
 windows_core::imp::define_interface!(IPrintPipelineProgressReport, IPrintPipelineProgressReport_Vtbl, 0xedc12c7c_ed40_4ea5_96a6_5e4397497a61);
 windows_core::imp::interface_hierarchy!(IPrintPipelineProgressReport, windows_core::IUnknown);
@@ -6920,6 +8616,20 @@ impl IPrintPipelineProgressReport_Vtbl {
         iid == &<IPrintPipelineProgressReport as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintPipelineProgressReport_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintPipelineProgressReport_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ReportProgress<Identity: windows_core::IUnknownImpl + IPrintPipelineProgressReport_Impl>(this: *mut core::ffi::c_void, update: EXpsJobConsumption) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintPipelineProgressReport_Impl::ReportProgress(this, core::mem::transmute_copy(&update)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ReportProgress: ReportProgress::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IPrintPipelineProgressReport {}
 windows_core::imp::define_interface!(IPrintPipelinePropertyBag, IPrintPipelinePropertyBag_Vtbl, 0x8b8c99dc_7892_4a95_8a04_57422e9fbb47);
 windows_core::imp::interface_hierarchy!(IPrintPipelinePropertyBag, windows_core::IUnknown);
@@ -7007,6 +8717,44 @@ impl IPrintPipelinePropertyBag_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrintPipelinePropertyBag_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintPipelinePropertyBag_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddProperty<Identity: windows_core::IUnknownImpl + IPrintPipelinePropertyBag_Impl>(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, pvar: *const super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintPipelinePropertyBag_Impl::AddProperty(this, core::mem::transmute(&pszname), core::mem::transmute_copy(&pvar)).into()
+            }
+        }
+        unsafe extern "system" fn GetProperty<Identity: windows_core::IUnknownImpl + IPrintPipelinePropertyBag_Impl>(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, pvar: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintPipelinePropertyBag_Impl::GetProperty(this, core::mem::transmute(&pszname)) {
+                    Ok(ok__) => {
+                        pvar.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeleteProperty<Identity: windows_core::IUnknownImpl + IPrintPipelinePropertyBag_Impl>(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintPipelinePropertyBag_Impl::DeleteProperty(this, core::mem::transmute(&pszname))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddProperty: AddProperty::<Identity>,
+            GetProperty: GetProperty::<Identity>,
+            DeleteProperty: DeleteProperty::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrintPipelinePropertyBag {}
 windows_core::imp::define_interface!(IPrintPreviewDxgiPackageTarget, IPrintPreviewDxgiPackageTarget_Vtbl, 0x1a6dd0ad_1e2a_4e99_a5ba_91f17818290e);
 windows_core::imp::interface_hierarchy!(IPrintPreviewDxgiPackageTarget, windows_core::IUnknown);
@@ -7075,6 +8823,38 @@ impl IPrintPreviewDxgiPackageTarget_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Dxgi")]
+impl<Identity: windows_core::IUnknownImpl + IPrintPreviewDxgiPackageTarget_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintPreviewDxgiPackageTarget_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetJobPageCount<Identity: windows_core::IUnknownImpl + IPrintPreviewDxgiPackageTarget_Impl>(this: *mut core::ffi::c_void, counttype: PageCountType, count: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintPreviewDxgiPackageTarget_Impl::SetJobPageCount(this, core::mem::transmute_copy(&counttype), core::mem::transmute_copy(&count)).into()
+            }
+        }
+        unsafe extern "system" fn DrawPage<Identity: windows_core::IUnknownImpl + IPrintPreviewDxgiPackageTarget_Impl>(this: *mut core::ffi::c_void, jobpagenumber: u32, pageimage: *mut core::ffi::c_void, dpix: f32, dpiy: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintPreviewDxgiPackageTarget_Impl::DrawPage(this, core::mem::transmute_copy(&jobpagenumber), core::mem::transmute_copy(&pageimage), core::mem::transmute_copy(&dpix), core::mem::transmute_copy(&dpiy)).into()
+            }
+        }
+        unsafe extern "system" fn InvalidatePreview<Identity: windows_core::IUnknownImpl + IPrintPreviewDxgiPackageTarget_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintPreviewDxgiPackageTarget_Impl::InvalidatePreview(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetJobPageCount: SetJobPageCount::<Identity>,
+            DrawPage: DrawPage::<Identity>,
+            InvalidatePreview: InvalidatePreview::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Dxgi")]
 impl windows_core::RuntimeName for IPrintPreviewDxgiPackageTarget {}
 windows_core::imp::define_interface!(IPrintReadStream, IPrintReadStream_Vtbl, 0x4d47a67c_66cc_4430_850e_daf466fe5bc4);
 windows_core::imp::interface_hierarchy!(IPrintReadStream, windows_core::IUnknown);
@@ -7117,6 +8897,30 @@ impl IPrintReadStream_Vtbl {
         iid == &<IPrintReadStream as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintReadStream_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintReadStream_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Seek<Identity: windows_core::IUnknownImpl + IPrintReadStream_Impl>(this: *mut core::ffi::c_void, dlibmove: i64, dworigin: u32, plibnewposition: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintReadStream_Impl::Seek(this, core::mem::transmute_copy(&dlibmove), core::mem::transmute_copy(&dworigin), core::mem::transmute_copy(&plibnewposition)).into()
+            }
+        }
+        unsafe extern "system" fn ReadBytes<Identity: windows_core::IUnknownImpl + IPrintReadStream_Impl>(this: *mut core::ffi::c_void, pvbuffer: *mut core::ffi::c_void, cbrequested: u32, pcbread: *mut u32, pbendoffile: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintReadStream_Impl::ReadBytes(this, core::mem::transmute_copy(&pvbuffer), core::mem::transmute_copy(&cbrequested), core::mem::transmute_copy(&pcbread), core::mem::transmute_copy(&pbendoffile)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Seek: Seek::<Identity>,
+            ReadBytes: ReadBytes::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPrintReadStream {}
 windows_core::imp::define_interface!(IPrintReadStreamFactory, IPrintReadStreamFactory_Vtbl, 0xacb971e3_df8d_4fc2_bee6_0609d15f3cf9);
 windows_core::imp::interface_hierarchy!(IPrintReadStreamFactory, windows_core::IUnknown);
@@ -7157,6 +8961,26 @@ impl IPrintReadStreamFactory_Vtbl {
         iid == &<IPrintReadStreamFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintReadStreamFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintReadStreamFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStream<Identity: windows_core::IUnknownImpl + IPrintReadStreamFactory_Impl>(this: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintReadStreamFactory_Impl::GetStream(this) {
+                    Ok(ok__) => {
+                        ppstream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetStream: GetStream::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IPrintReadStreamFactory {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrintSchemaAsyncOperation, IPrintSchemaAsyncOperation_Vtbl, 0x143c8dcb_d37f_47f7_88e8_6b1d21f2c5f7);
@@ -7217,6 +9041,31 @@ impl IPrintSchemaAsyncOperation_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrintSchemaAsyncOperation_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintSchemaAsyncOperation_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Start<Identity: windows_core::IUnknownImpl + IPrintSchemaAsyncOperation_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintSchemaAsyncOperation_Impl::Start(this).into()
+            }
+        }
+        unsafe extern "system" fn Cancel<Identity: windows_core::IUnknownImpl + IPrintSchemaAsyncOperation_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintSchemaAsyncOperation_Impl::Cancel(this).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Start: Start::<Identity>,
+            Cancel: Cancel::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrintSchemaAsyncOperation {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrintSchemaAsyncOperationEvent, IPrintSchemaAsyncOperationEvent_Vtbl, 0x23adbb16_0133_4906_b29a_1dce1d026379);
@@ -7265,6 +9114,21 @@ impl IPrintSchemaAsyncOperationEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrintSchemaAsyncOperationEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintSchemaAsyncOperationEvent_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Completed<Identity: windows_core::IUnknownImpl + IPrintSchemaAsyncOperationEvent_Impl>(this: *mut core::ffi::c_void, pticket: *mut core::ffi::c_void, hroperation: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintSchemaAsyncOperationEvent_Impl::Completed(this, core::mem::transmute_copy(&pticket), core::mem::transmute_copy(&hroperation)).into()
+            }
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Completed: Completed::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrintSchemaAsyncOperationEvent {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrintSchemaCapabilities, IPrintSchemaCapabilities_Vtbl, 0x5a577640_501d_4927_bcd0_5ef57a7ed175);
@@ -7454,10 +9318,112 @@ impl IPrintSchemaCapabilities_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IPrintSchemaCapabilities {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IPrintSchemaCapabilities2, IPrintSchemaCapabilities2_Vtbl, 0xb58845f4_9970_4d87_a636_169fb82ed642);
-#[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IPrintSchemaCapabilities_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintSchemaCapabilities_Vtbl
+where
+    IPrintSchemaElement_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFeatureByKeyName<Identity: windows_core::IUnknownImpl + IPrintSchemaCapabilities_Impl>(this: *mut core::ffi::c_void, bstrkeyname: *mut core::ffi::c_void, ppfeature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaCapabilities_Impl::GetFeatureByKeyName(this, core::mem::transmute(&bstrkeyname)) {
+                    Ok(ok__) => {
+                        ppfeature.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFeature<Identity: windows_core::IUnknownImpl + IPrintSchemaCapabilities_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, bstrnamespaceuri: *mut core::ffi::c_void, ppfeature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaCapabilities_Impl::GetFeature(this, core::mem::transmute(&bstrname), core::mem::transmute(&bstrnamespaceuri)) {
+                    Ok(ok__) => {
+                        ppfeature.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PageImageableSize<Identity: windows_core::IUnknownImpl + IPrintSchemaCapabilities_Impl>(this: *mut core::ffi::c_void, pppageimageablesize: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaCapabilities_Impl::PageImageableSize(this) {
+                    Ok(ok__) => {
+                        pppageimageablesize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn JobCopiesAllDocumentsMinValue<Identity: windows_core::IUnknownImpl + IPrintSchemaCapabilities_Impl>(this: *mut core::ffi::c_void, puljobcopiesalldocumentsminvalue: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaCapabilities_Impl::JobCopiesAllDocumentsMinValue(this) {
+                    Ok(ok__) => {
+                        puljobcopiesalldocumentsminvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn JobCopiesAllDocumentsMaxValue<Identity: windows_core::IUnknownImpl + IPrintSchemaCapabilities_Impl>(this: *mut core::ffi::c_void, puljobcopiesalldocumentsmaxvalue: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaCapabilities_Impl::JobCopiesAllDocumentsMaxValue(this) {
+                    Ok(ok__) => {
+                        puljobcopiesalldocumentsmaxvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSelectedOptionInPrintTicket<Identity: windows_core::IUnknownImpl + IPrintSchemaCapabilities_Impl>(this: *mut core::ffi::c_void, pfeature: *mut core::ffi::c_void, ppoption: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaCapabilities_Impl::GetSelectedOptionInPrintTicket(this, core::mem::transmute_copy(&pfeature)) {
+                    Ok(ok__) => {
+                        ppoption.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOptions<Identity: windows_core::IUnknownImpl + IPrintSchemaCapabilities_Impl>(this: *mut core::ffi::c_void, pfeature: *mut core::ffi::c_void, ppoptioncollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaCapabilities_Impl::GetOptions(this, core::mem::transmute_copy(&pfeature)) {
+                    Ok(ok__) => {
+                        ppoptioncollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IPrintSchemaElement_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFeatureByKeyName: GetFeatureByKeyName::<Identity>,
+            GetFeature: GetFeature::<Identity>,
+            PageImageableSize: PageImageableSize::<Identity>,
+            JobCopiesAllDocumentsMinValue: JobCopiesAllDocumentsMinValue::<Identity>,
+            JobCopiesAllDocumentsMaxValue: JobCopiesAllDocumentsMaxValue::<Identity>,
+            GetSelectedOptionInPrintTicket: GetSelectedOptionInPrintTicket::<Identity>,
+            GetOptions: GetOptions::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IPrintSchemaCapabilities {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(IPrintSchemaCapabilities2, IPrintSchemaCapabilities2_Vtbl, 0xb58845f4_9970_4d87_a636_169fb82ed642);
+#[cfg(feature = "Win32_System_Com")]
 impl core::ops::Deref for IPrintSchemaCapabilities2 {
     type Target = IPrintSchemaCapabilities;
     fn deref(&self) -> &Self::Target {
@@ -7508,6 +9474,30 @@ impl IPrintSchemaCapabilities2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrintSchemaCapabilities2_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintSchemaCapabilities2_Vtbl
+where
+    IPrintSchemaCapabilities_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetParameterDefinition<Identity: windows_core::IUnknownImpl + IPrintSchemaCapabilities2_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, bstrnamespaceuri: *mut core::ffi::c_void, ppparameterdefinition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaCapabilities2_Impl::GetParameterDefinition(this, core::mem::transmute(&bstrname), core::mem::transmute(&bstrnamespaceuri)) {
+                    Ok(ok__) => {
+                        ppparameterdefinition.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IPrintSchemaCapabilities_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetParameterDefinition: GetParameterDefinition::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrintSchemaCapabilities2 {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrintSchemaDisplayableElement, IPrintSchemaDisplayableElement_Vtbl, 0xaf45af49_d6aa_407d_bf87_3912236e9d94);
@@ -7562,6 +9552,27 @@ impl IPrintSchemaDisplayableElement_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrintSchemaDisplayableElement_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintSchemaDisplayableElement_Vtbl
+where
+    IPrintSchemaElement_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DisplayName<Identity: windows_core::IUnknownImpl + IPrintSchemaDisplayableElement_Impl>(this: *mut core::ffi::c_void, pbstrdisplayname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaDisplayableElement_Impl::DisplayName(this) {
+                    Ok(ok__) => {
+                        pbstrdisplayname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IPrintSchemaElement_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, DisplayName: DisplayName::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrintSchemaDisplayableElement {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrintSchemaElement, IPrintSchemaElement_Vtbl, 0x724c1646_e64b_4bbf_8eb4_d45e4fd580da);
@@ -7661,6 +9672,56 @@ impl IPrintSchemaElement_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrintSchemaElement_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintSchemaElement_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn XmlNode<Identity: windows_core::IUnknownImpl + IPrintSchemaElement_Impl>(this: *mut core::ffi::c_void, ppxmlnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaElement_Impl::XmlNode(this) {
+                    Ok(ok__) => {
+                        ppxmlnode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + IPrintSchemaElement_Impl>(this: *mut core::ffi::c_void, pbstrname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaElement_Impl::Name(this) {
+                    Ok(ok__) => {
+                        pbstrname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NamespaceUri<Identity: windows_core::IUnknownImpl + IPrintSchemaElement_Impl>(this: *mut core::ffi::c_void, pbstrnamespaceuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaElement_Impl::NamespaceUri(this) {
+                    Ok(ok__) => {
+                        pbstrnamespaceuri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            XmlNode: XmlNode::<Identity>,
+            Name: Name::<Identity>,
+            NamespaceUri: NamespaceUri::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrintSchemaElement {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrintSchemaFeature, IPrintSchemaFeature_Vtbl, 0xef189461_5d62_4626_8e57_ff83583c4826);
@@ -7796,6 +9857,76 @@ impl IPrintSchemaFeature_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrintSchemaFeature_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintSchemaFeature_Vtbl
+where
+    IPrintSchemaDisplayableElement_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SelectedOption<Identity: windows_core::IUnknownImpl + IPrintSchemaFeature_Impl>(this: *mut core::ffi::c_void, ppoption: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaFeature_Impl::SelectedOption(this) {
+                    Ok(ok__) => {
+                        ppoption.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSelectedOption<Identity: windows_core::IUnknownImpl + IPrintSchemaFeature_Impl>(this: *mut core::ffi::c_void, poption: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintSchemaFeature_Impl::SetSelectedOption(this, core::mem::transmute_copy(&poption)).into()
+            }
+        }
+        unsafe extern "system" fn SelectionType<Identity: windows_core::IUnknownImpl + IPrintSchemaFeature_Impl>(this: *mut core::ffi::c_void, pselectiontype: *mut PrintSchemaSelectionType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaFeature_Impl::SelectionType(this) {
+                    Ok(ok__) => {
+                        pselectiontype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOption<Identity: windows_core::IUnknownImpl + IPrintSchemaFeature_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, bstrnamespaceuri: *mut core::ffi::c_void, ppoption: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaFeature_Impl::GetOption(this, core::mem::transmute(&bstrname), core::mem::transmute(&bstrnamespaceuri)) {
+                    Ok(ok__) => {
+                        ppoption.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DisplayUI<Identity: windows_core::IUnknownImpl + IPrintSchemaFeature_Impl>(this: *mut core::ffi::c_void, pbshow: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaFeature_Impl::DisplayUI(this) {
+                    Ok(ok__) => {
+                        pbshow.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IPrintSchemaDisplayableElement_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SelectedOption: SelectedOption::<Identity>,
+            SetSelectedOption: SetSelectedOption::<Identity>,
+            SelectionType: SelectionType::<Identity>,
+            GetOption: GetOption::<Identity>,
+            DisplayUI: DisplayUI::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrintSchemaFeature {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrintSchemaNUpOption, IPrintSchemaNUpOption_Vtbl, 0x1f6342f2_d848_42e3_8995_c10a9ef9a3ba);
@@ -7850,6 +9981,27 @@ impl IPrintSchemaNUpOption_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrintSchemaNUpOption_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintSchemaNUpOption_Vtbl
+where
+    IPrintSchemaOption_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn PagesPerSheet<Identity: windows_core::IUnknownImpl + IPrintSchemaNUpOption_Impl>(this: *mut core::ffi::c_void, pulpagespersheet: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaNUpOption_Impl::PagesPerSheet(this) {
+                    Ok(ok__) => {
+                        pulpagespersheet.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IPrintSchemaOption_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, PagesPerSheet: PagesPerSheet::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrintSchemaNUpOption {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrintSchemaOption, IPrintSchemaOption_Vtbl, 0x66bb2f51_5844_4997_8d70_4b7cc221cf92);
@@ -7949,6 +10101,56 @@ impl IPrintSchemaOption_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrintSchemaOption_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintSchemaOption_Vtbl
+where
+    IPrintSchemaDisplayableElement_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Selected<Identity: windows_core::IUnknownImpl + IPrintSchemaOption_Impl>(this: *mut core::ffi::c_void, pbisselected: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaOption_Impl::Selected(this) {
+                    Ok(ok__) => {
+                        pbisselected.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Constrained<Identity: windows_core::IUnknownImpl + IPrintSchemaOption_Impl>(this: *mut core::ffi::c_void, psetting: *mut PrintSchemaConstrainedSetting) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaOption_Impl::Constrained(this) {
+                    Ok(ok__) => {
+                        psetting.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPropertyValue<Identity: windows_core::IUnknownImpl + IPrintSchemaOption_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, bstrnamespaceuri: *mut core::ffi::c_void, ppxmlvaluenode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaOption_Impl::GetPropertyValue(this, core::mem::transmute(&bstrname), core::mem::transmute(&bstrnamespaceuri)) {
+                    Ok(ok__) => {
+                        ppxmlvaluenode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IPrintSchemaDisplayableElement_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Selected: Selected::<Identity>,
+            Constrained: Constrained::<Identity>,
+            GetPropertyValue: GetPropertyValue::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrintSchemaOption {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrintSchemaOptionCollection, IPrintSchemaOptionCollection_Vtbl, 0xbaecb0bd_a946_4771_bc30_e8b24f8d45c1);
@@ -8048,16 +10250,66 @@ impl IPrintSchemaOptionCollection_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IPrintSchemaOptionCollection {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IPrintSchemaPageImageableSize, IPrintSchemaPageImageableSize_Vtbl, 0x7c85bf5e_dc7c_4f61_839b_4107e1c9b68e);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IPrintSchemaPageImageableSize {
-    type Target = IPrintSchemaElement;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
+impl<Identity: windows_core::IUnknownImpl + IPrintSchemaOptionCollection_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintSchemaOptionCollection_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + IPrintSchemaOptionCollection_Impl>(this: *mut core::ffi::c_void, pulcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaOptionCollection_Impl::Count(this) {
+                    Ok(ok__) => {
+                        pulcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAt<Identity: windows_core::IUnknownImpl + IPrintSchemaOptionCollection_Impl>(this: *mut core::ffi::c_void, ulindex: u32, ppoption: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaOptionCollection_Impl::GetAt(this, core::mem::transmute_copy(&ulindex)) {
+                    Ok(ok__) => {
+                        ppoption.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + IPrintSchemaOptionCollection_Impl>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaOptionCollection_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        ppunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Count: Count::<Identity>,
+            GetAt: GetAt::<Identity>,
+            _NewEnum: _NewEnum::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IPrintSchemaOptionCollection {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(IPrintSchemaPageImageableSize, IPrintSchemaPageImageableSize_Vtbl, 0x7c85bf5e_dc7c_4f61_839b_4107e1c9b68e);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for IPrintSchemaPageImageableSize {
+    type Target = IPrintSchemaElement;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::interface_hierarchy!(IPrintSchemaPageImageableSize, windows_core::IUnknown, super::super::System::Com::IDispatch, IPrintSchemaElement);
 #[cfg(feature = "Win32_System_Com")]
@@ -8210,6 +10462,95 @@ impl IPrintSchemaPageImageableSize_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrintSchemaPageImageableSize_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintSchemaPageImageableSize_Vtbl
+where
+    IPrintSchemaElement_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ImageableSizeWidthInMicrons<Identity: windows_core::IUnknownImpl + IPrintSchemaPageImageableSize_Impl>(this: *mut core::ffi::c_void, pulimageablesizewidth: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaPageImageableSize_Impl::ImageableSizeWidthInMicrons(this) {
+                    Ok(ok__) => {
+                        pulimageablesizewidth.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ImageableSizeHeightInMicrons<Identity: windows_core::IUnknownImpl + IPrintSchemaPageImageableSize_Impl>(this: *mut core::ffi::c_void, pulimageablesizeheight: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaPageImageableSize_Impl::ImageableSizeHeightInMicrons(this) {
+                    Ok(ok__) => {
+                        pulimageablesizeheight.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OriginWidthInMicrons<Identity: windows_core::IUnknownImpl + IPrintSchemaPageImageableSize_Impl>(this: *mut core::ffi::c_void, puloriginwidth: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaPageImageableSize_Impl::OriginWidthInMicrons(this) {
+                    Ok(ok__) => {
+                        puloriginwidth.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OriginHeightInMicrons<Identity: windows_core::IUnknownImpl + IPrintSchemaPageImageableSize_Impl>(this: *mut core::ffi::c_void, puloriginheight: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaPageImageableSize_Impl::OriginHeightInMicrons(this) {
+                    Ok(ok__) => {
+                        puloriginheight.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ExtentWidthInMicrons<Identity: windows_core::IUnknownImpl + IPrintSchemaPageImageableSize_Impl>(this: *mut core::ffi::c_void, pulextentwidth: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaPageImageableSize_Impl::ExtentWidthInMicrons(this) {
+                    Ok(ok__) => {
+                        pulextentwidth.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ExtentHeightInMicrons<Identity: windows_core::IUnknownImpl + IPrintSchemaPageImageableSize_Impl>(this: *mut core::ffi::c_void, pulextentheight: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaPageImageableSize_Impl::ExtentHeightInMicrons(this) {
+                    Ok(ok__) => {
+                        pulextentheight.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IPrintSchemaElement_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ImageableSizeWidthInMicrons: ImageableSizeWidthInMicrons::<Identity>,
+            ImageableSizeHeightInMicrons: ImageableSizeHeightInMicrons::<Identity>,
+            OriginWidthInMicrons: OriginWidthInMicrons::<Identity>,
+            OriginHeightInMicrons: OriginHeightInMicrons::<Identity>,
+            ExtentWidthInMicrons: ExtentWidthInMicrons::<Identity>,
+            ExtentHeightInMicrons: ExtentHeightInMicrons::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrintSchemaPageImageableSize {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrintSchemaPageMediaSizeOption, IPrintSchemaPageMediaSizeOption_Vtbl, 0x68746729_f493_4830_a10f_69028774605d);
@@ -8288,6 +10629,43 @@ impl IPrintSchemaPageMediaSizeOption_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrintSchemaPageMediaSizeOption_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintSchemaPageMediaSizeOption_Vtbl
+where
+    IPrintSchemaOption_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn WidthInMicrons<Identity: windows_core::IUnknownImpl + IPrintSchemaPageMediaSizeOption_Impl>(this: *mut core::ffi::c_void, pulwidth: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaPageMediaSizeOption_Impl::WidthInMicrons(this) {
+                    Ok(ok__) => {
+                        pulwidth.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn HeightInMicrons<Identity: windows_core::IUnknownImpl + IPrintSchemaPageMediaSizeOption_Impl>(this: *mut core::ffi::c_void, pulheight: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaPageMediaSizeOption_Impl::HeightInMicrons(this) {
+                    Ok(ok__) => {
+                        pulheight.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IPrintSchemaOption_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            WidthInMicrons: WidthInMicrons::<Identity>,
+            HeightInMicrons: HeightInMicrons::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrintSchemaPageMediaSizeOption {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrintSchemaParameterDefinition, IPrintSchemaParameterDefinition_Vtbl, 0xb5ade81e_0e61_4fe1_81c6_c333e4ffe0f1);
@@ -8429,6 +10807,82 @@ impl IPrintSchemaParameterDefinition_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrintSchemaParameterDefinition_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintSchemaParameterDefinition_Vtbl
+where
+    IPrintSchemaDisplayableElement_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn UserInputRequired<Identity: windows_core::IUnknownImpl + IPrintSchemaParameterDefinition_Impl>(this: *mut core::ffi::c_void, pbisrequired: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaParameterDefinition_Impl::UserInputRequired(this) {
+                    Ok(ok__) => {
+                        pbisrequired.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UnitType<Identity: windows_core::IUnknownImpl + IPrintSchemaParameterDefinition_Impl>(this: *mut core::ffi::c_void, pbstrunittype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaParameterDefinition_Impl::UnitType(this) {
+                    Ok(ok__) => {
+                        pbstrunittype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DataType<Identity: windows_core::IUnknownImpl + IPrintSchemaParameterDefinition_Impl>(this: *mut core::ffi::c_void, pdatatype: *mut PrintSchemaParameterDataType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaParameterDefinition_Impl::DataType(this) {
+                    Ok(ok__) => {
+                        pdatatype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RangeMin<Identity: windows_core::IUnknownImpl + IPrintSchemaParameterDefinition_Impl>(this: *mut core::ffi::c_void, prangemin: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaParameterDefinition_Impl::RangeMin(this) {
+                    Ok(ok__) => {
+                        prangemin.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RangeMax<Identity: windows_core::IUnknownImpl + IPrintSchemaParameterDefinition_Impl>(this: *mut core::ffi::c_void, prangemax: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaParameterDefinition_Impl::RangeMax(this) {
+                    Ok(ok__) => {
+                        prangemax.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IPrintSchemaDisplayableElement_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            UserInputRequired: UserInputRequired::<Identity>,
+            UnitType: UnitType::<Identity>,
+            DataType: DataType::<Identity>,
+            RangeMin: RangeMin::<Identity>,
+            RangeMax: RangeMax::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrintSchemaParameterDefinition {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrintSchemaParameterInitializer, IPrintSchemaParameterInitializer_Vtbl, 0x52027082_0b74_4648_9564_828cc6cb656c);
@@ -8502,6 +10956,37 @@ impl IPrintSchemaParameterInitializer_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrintSchemaParameterInitializer_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintSchemaParameterInitializer_Vtbl
+where
+    IPrintSchemaElement_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Value<Identity: windows_core::IUnknownImpl + IPrintSchemaParameterInitializer_Impl>(this: *mut core::ffi::c_void, pvar: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaParameterInitializer_Impl::Value(this) {
+                    Ok(ok__) => {
+                        pvar.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetValue<Identity: windows_core::IUnknownImpl + IPrintSchemaParameterInitializer_Impl>(this: *mut core::ffi::c_void, pvar: *const super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintSchemaParameterInitializer_Impl::SetValue(this, core::mem::transmute_copy(&pvar)).into()
+            }
+        }
+        Self {
+            base__: <IPrintSchemaElement_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Value: Value::<Identity>,
+            SetValue: SetValue::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrintSchemaParameterInitializer {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrintSchemaTicket, IPrintSchemaTicket_Vtbl, 0xe480b861_4708_4e6d_a5b4_a2b4eeb9baa4);
@@ -8691,47 +11176,150 @@ impl IPrintSchemaTicket_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IPrintSchemaTicket {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IPrintSchemaTicket2, IPrintSchemaTicket2_Vtbl, 0x2ec1f844_766a_47a1_91f4_2eeb6190f80c);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IPrintSchemaTicket2 {
-    type Target = IPrintSchemaTicket;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IPrintSchemaTicket2, windows_core::IUnknown, super::super::System::Com::IDispatch, IPrintSchemaElement, IPrintSchemaTicket);
-#[cfg(feature = "Win32_System_Com")]
-impl IPrintSchemaTicket2 {
-    pub unsafe fn GetParameterInitializer(&self, bstrname: &windows_core::BSTR, bstrnamespaceuri: &windows_core::BSTR) -> windows_core::Result<IPrintSchemaParameterInitializer> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetParameterInitializer)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(bstrname), core::mem::transmute_copy(bstrnamespaceuri), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-#[repr(C)]
-#[doc(hidden)]
-pub struct IPrintSchemaTicket2_Vtbl {
-    pub base__: IPrintSchemaTicket_Vtbl,
-    pub GetParameterInitializer: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-pub trait IPrintSchemaTicket2_Impl: IPrintSchemaTicket_Impl {
-    fn GetParameterInitializer(&self, bstrname: &windows_core::BSTR, bstrnamespaceuri: &windows_core::BSTR) -> windows_core::Result<IPrintSchemaParameterInitializer>;
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl IPrintSchemaTicket2_Vtbl {
-    pub const fn new<Identity: IPrintSchemaTicket2_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn GetParameterInitializer<Identity: IPrintSchemaTicket2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, bstrnamespaceuri: *mut core::ffi::c_void, ppparameterinitializer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl<Identity: windows_core::IUnknownImpl + IPrintSchemaTicket_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintSchemaTicket_Vtbl
+where
+    IPrintSchemaElement_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFeatureByKeyName<Identity: windows_core::IUnknownImpl + IPrintSchemaTicket_Impl>(this: *mut core::ffi::c_void, bstrkeyname: *mut core::ffi::c_void, ppfeature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IPrintSchemaTicket2_Impl::GetParameterInitializer(this, core::mem::transmute(&bstrname), core::mem::transmute(&bstrnamespaceuri)) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaTicket_Impl::GetFeatureByKeyName(this, core::mem::transmute(&bstrkeyname)) {
                     Ok(ok__) => {
-                        ppparameterinitializer.write(core::mem::transmute(ok__));
+                        ppfeature.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFeature<Identity: windows_core::IUnknownImpl + IPrintSchemaTicket_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, bstrnamespaceuri: *mut core::ffi::c_void, ppfeature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaTicket_Impl::GetFeature(this, core::mem::transmute(&bstrname), core::mem::transmute(&bstrnamespaceuri)) {
+                    Ok(ok__) => {
+                        ppfeature.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ValidateAsync<Identity: windows_core::IUnknownImpl + IPrintSchemaTicket_Impl>(this: *mut core::ffi::c_void, ppasyncoperation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaTicket_Impl::ValidateAsync(this) {
+                    Ok(ok__) => {
+                        ppasyncoperation.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CommitAsync<Identity: windows_core::IUnknownImpl + IPrintSchemaTicket_Impl>(this: *mut core::ffi::c_void, pprintticketcommit: *mut core::ffi::c_void, ppasyncoperation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaTicket_Impl::CommitAsync(this, core::mem::transmute_copy(&pprintticketcommit)) {
+                    Ok(ok__) => {
+                        ppasyncoperation.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NotifyXmlChanged<Identity: windows_core::IUnknownImpl + IPrintSchemaTicket_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintSchemaTicket_Impl::NotifyXmlChanged(this).into()
+            }
+        }
+        unsafe extern "system" fn GetCapabilities<Identity: windows_core::IUnknownImpl + IPrintSchemaTicket_Impl>(this: *mut core::ffi::c_void, ppcapabilities: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaTicket_Impl::GetCapabilities(this) {
+                    Ok(ok__) => {
+                        ppcapabilities.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn JobCopiesAllDocuments<Identity: windows_core::IUnknownImpl + IPrintSchemaTicket_Impl>(this: *mut core::ffi::c_void, puljobcopiesalldocuments: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaTicket_Impl::JobCopiesAllDocuments(this) {
+                    Ok(ok__) => {
+                        puljobcopiesalldocuments.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetJobCopiesAllDocuments<Identity: windows_core::IUnknownImpl + IPrintSchemaTicket_Impl>(this: *mut core::ffi::c_void, uljobcopiesalldocuments: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintSchemaTicket_Impl::SetJobCopiesAllDocuments(this, core::mem::transmute_copy(&uljobcopiesalldocuments)).into()
+            }
+        }
+        Self {
+            base__: <IPrintSchemaElement_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFeatureByKeyName: GetFeatureByKeyName::<Identity>,
+            GetFeature: GetFeature::<Identity>,
+            ValidateAsync: ValidateAsync::<Identity>,
+            CommitAsync: CommitAsync::<Identity>,
+            NotifyXmlChanged: NotifyXmlChanged::<Identity>,
+            GetCapabilities: GetCapabilities::<Identity>,
+            JobCopiesAllDocuments: JobCopiesAllDocuments::<Identity>,
+            SetJobCopiesAllDocuments: SetJobCopiesAllDocuments::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IPrintSchemaTicket {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(IPrintSchemaTicket2, IPrintSchemaTicket2_Vtbl, 0x2ec1f844_766a_47a1_91f4_2eeb6190f80c);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for IPrintSchemaTicket2 {
+    type Target = IPrintSchemaTicket;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(IPrintSchemaTicket2, windows_core::IUnknown, super::super::System::Com::IDispatch, IPrintSchemaElement, IPrintSchemaTicket);
+#[cfg(feature = "Win32_System_Com")]
+impl IPrintSchemaTicket2 {
+    pub unsafe fn GetParameterInitializer(&self, bstrname: &windows_core::BSTR, bstrnamespaceuri: &windows_core::BSTR) -> windows_core::Result<IPrintSchemaParameterInitializer> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetParameterInitializer)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(bstrname), core::mem::transmute_copy(bstrnamespaceuri), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+#[repr(C)]
+#[doc(hidden)]
+pub struct IPrintSchemaTicket2_Vtbl {
+    pub base__: IPrintSchemaTicket_Vtbl,
+    pub GetParameterInitializer: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+pub trait IPrintSchemaTicket2_Impl: IPrintSchemaTicket_Impl {
+    fn GetParameterInitializer(&self, bstrname: &windows_core::BSTR, bstrnamespaceuri: &windows_core::BSTR) -> windows_core::Result<IPrintSchemaParameterInitializer>;
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl IPrintSchemaTicket2_Vtbl {
+    pub const fn new<Identity: IPrintSchemaTicket2_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn GetParameterInitializer<Identity: IPrintSchemaTicket2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, bstrnamespaceuri: *mut core::ffi::c_void, ppparameterinitializer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IPrintSchemaTicket2_Impl::GetParameterInitializer(this, core::mem::transmute(&bstrname), core::mem::transmute(&bstrnamespaceuri)) {
+                    Ok(ok__) => {
+                        ppparameterinitializer.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
@@ -8745,6 +11333,30 @@ impl IPrintSchemaTicket2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrintSchemaTicket2_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintSchemaTicket2_Vtbl
+where
+    IPrintSchemaTicket_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetParameterInitializer<Identity: windows_core::IUnknownImpl + IPrintSchemaTicket2_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, bstrnamespaceuri: *mut core::ffi::c_void, ppparameterinitializer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintSchemaTicket2_Impl::GetParameterInitializer(this, core::mem::transmute(&bstrname), core::mem::transmute(&bstrnamespaceuri)) {
+                    Ok(ok__) => {
+                        ppparameterinitializer.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IPrintSchemaTicket_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetParameterInitializer: GetParameterInitializer::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrintSchemaTicket2 {}
 windows_core::imp::define_interface!(IPrintTicketProvider, IPrintTicketProvider_Vtbl, 0xbb5116db_0a23_4c3a_a6b6_89e5558dfb5d);
 windows_core::imp::interface_hierarchy!(IPrintTicketProvider, windows_core::IUnknown);
@@ -8891,6 +11503,72 @@ impl IPrintTicketProvider_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl<Identity: windows_core::IUnknownImpl + IPrintTicketProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintTicketProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSupportedVersions<Identity: windows_core::IUnknownImpl + IPrintTicketProvider_Impl>(this: *mut core::ffi::c_void, hprinter: PRINTER_HANDLE, ppversions: *mut *mut i32, cversions: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintTicketProvider_Impl::GetSupportedVersions(this, core::mem::transmute_copy(&hprinter), core::mem::transmute_copy(&ppversions), core::mem::transmute_copy(&cversions)).into()
+            }
+        }
+        unsafe extern "system" fn BindPrinter<Identity: windows_core::IUnknownImpl + IPrintTicketProvider_Impl>(this: *mut core::ffi::c_void, hprinter: PRINTER_HANDLE, version: i32, poptions: *mut SHIMOPTS, pdevmodeflags: *mut u32, cnamespaces: *mut i32, ppnamespaces: *mut *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintTicketProvider_Impl::BindPrinter(this, core::mem::transmute_copy(&hprinter), core::mem::transmute_copy(&version), core::mem::transmute_copy(&poptions), core::mem::transmute_copy(&pdevmodeflags), core::mem::transmute_copy(&cnamespaces), core::mem::transmute_copy(&ppnamespaces)).into()
+            }
+        }
+        unsafe extern "system" fn QueryDeviceNamespace<Identity: windows_core::IUnknownImpl + IPrintTicketProvider_Impl>(this: *mut core::ffi::c_void, pdefaultnamespace: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintTicketProvider_Impl::QueryDeviceNamespace(this, core::mem::transmute_copy(&pdefaultnamespace)).into()
+            }
+        }
+        unsafe extern "system" fn ConvertPrintTicketToDevMode<Identity: windows_core::IUnknownImpl + IPrintTicketProvider_Impl>(this: *mut core::ffi::c_void, pprintticket: *mut core::ffi::c_void, cbdevmodein: u32, pdevmodein: *mut super::Gdi::DEVMODEA, pcbdevmodeout: *mut u32, ppdevmodeout: *mut *mut super::Gdi::DEVMODEA) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintTicketProvider_Impl::ConvertPrintTicketToDevMode(this, core::mem::transmute_copy(&pprintticket), core::mem::transmute_copy(&cbdevmodein), core::mem::transmute_copy(&pdevmodein), core::mem::transmute_copy(&pcbdevmodeout), core::mem::transmute_copy(&ppdevmodeout)).into()
+            }
+        }
+        unsafe extern "system" fn ConvertDevModeToPrintTicket<Identity: windows_core::IUnknownImpl + IPrintTicketProvider_Impl>(this: *mut core::ffi::c_void, cbdevmode: u32, pdevmode: *mut super::Gdi::DEVMODEA, pprintticket: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintTicketProvider_Impl::ConvertDevModeToPrintTicket(this, core::mem::transmute_copy(&cbdevmode), core::mem::transmute_copy(&pdevmode), core::mem::transmute_copy(&pprintticket)).into()
+            }
+        }
+        unsafe extern "system" fn GetPrintCapabilities<Identity: windows_core::IUnknownImpl + IPrintTicketProvider_Impl>(this: *mut core::ffi::c_void, pprintticket: *mut core::ffi::c_void, ppcapabilities: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintTicketProvider_Impl::GetPrintCapabilities(this, core::mem::transmute_copy(&pprintticket)) {
+                    Ok(ok__) => {
+                        ppcapabilities.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ValidatePrintTicket<Identity: windows_core::IUnknownImpl + IPrintTicketProvider_Impl>(this: *mut core::ffi::c_void, pbaseticket: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintTicketProvider_Impl::ValidatePrintTicket(this, core::mem::transmute_copy(&pbaseticket)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSupportedVersions: GetSupportedVersions::<Identity>,
+            BindPrinter: BindPrinter::<Identity>,
+            QueryDeviceNamespace: QueryDeviceNamespace::<Identity>,
+            ConvertPrintTicketToDevMode: ConvertPrintTicketToDevMode::<Identity>,
+            ConvertDevModeToPrintTicket: ConvertDevModeToPrintTicket::<Identity>,
+            GetPrintCapabilities: GetPrintCapabilities::<Identity>,
+            ValidatePrintTicket: ValidatePrintTicket::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
 impl windows_core::RuntimeName for IPrintTicketProvider {}
 windows_core::imp::define_interface!(IPrintTicketProvider2, IPrintTicketProvider2_Vtbl, 0xb8a70ab2_3dfc_4fec_a074_511b13c651cb);
 impl core::ops::Deref for IPrintTicketProvider2 {
@@ -8979,6 +11657,43 @@ impl IPrintTicketProvider2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl<Identity: windows_core::IUnknownImpl + IPrintTicketProvider2_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintTicketProvider2_Vtbl
+where
+    IPrintTicketProvider_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPrintDeviceCapabilities<Identity: windows_core::IUnknownImpl + IPrintTicketProvider2_Impl>(this: *mut core::ffi::c_void, pprintticket: *mut core::ffi::c_void, ppdevicecapabilities: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintTicketProvider2_Impl::GetPrintDeviceCapabilities(this, core::mem::transmute_copy(&pprintticket)) {
+                    Ok(ok__) => {
+                        ppdevicecapabilities.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPrintDeviceResources<Identity: windows_core::IUnknownImpl + IPrintTicketProvider2_Impl>(this: *mut core::ffi::c_void, pszlocalename: windows_core::PCWSTR, pprintticket: *mut core::ffi::c_void, ppdeviceresources: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintTicketProvider2_Impl::GetPrintDeviceResources(this, core::mem::transmute(&pszlocalename), core::mem::transmute_copy(&pprintticket)) {
+                    Ok(ok__) => {
+                        ppdeviceresources.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IPrintTicketProvider_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPrintDeviceCapabilities: GetPrintDeviceCapabilities::<Identity>,
+            GetPrintDeviceResources: GetPrintDeviceResources::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
 impl windows_core::RuntimeName for IPrintTicketProvider2 {}
 windows_core::imp::define_interface!(IPrintUnidiAsyncNotifyRegistration, IPrintUnidiAsyncNotifyRegistration_Vtbl, 0);
 impl core::ops::Deref for IPrintUnidiAsyncNotifyRegistration {
@@ -9019,6 +11734,23 @@ impl IPrintUnidiAsyncNotifyRegistration_Vtbl {
         iid == &<IPrintUnidiAsyncNotifyRegistration as windows_core::Interface>::IID || iid == &<IPrintAsyncNotifyRegistration as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintUnidiAsyncNotifyRegistration_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintUnidiAsyncNotifyRegistration_Vtbl
+where
+    IPrintAsyncNotifyRegistration_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AsyncGetNotification<Identity: windows_core::IUnknownImpl + IPrintUnidiAsyncNotifyRegistration_Impl>(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintUnidiAsyncNotifyRegistration_Impl::AsyncGetNotification(this, core::mem::transmute_copy(&param0)).into()
+            }
+        }
+        Self {
+            base__: <IPrintAsyncNotifyRegistration_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AsyncGetNotification: AsyncGetNotification::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPrintUnidiAsyncNotifyRegistration {}
 windows_core::imp::define_interface!(IPrintWriteStream, IPrintWriteStream_Vtbl, 0x65bb7f1b_371e_4571_8ac7_912f510c1a38);
 windows_core::imp::interface_hierarchy!(IPrintWriteStream, windows_core::IUnknown);
@@ -9070,6 +11802,36 @@ impl IPrintWriteStream_Vtbl {
         iid == &<IPrintWriteStream as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintWriteStream_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintWriteStream_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn WriteBytes<Identity: windows_core::IUnknownImpl + IPrintWriteStream_Impl>(this: *mut core::ffi::c_void, pvbuffer: *const core::ffi::c_void, cbbuffer: u32, pcbwritten: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrintWriteStream_Impl::WriteBytes(this, core::mem::transmute_copy(&pvbuffer), core::mem::transmute_copy(&cbbuffer)) {
+                    Ok(ok__) => {
+                        pcbwritten.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IPrintWriteStream_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintWriteStream_Impl::Close(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            WriteBytes: WriteBytes::<Identity>,
+            Close: Close::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPrintWriteStream {}
 windows_core::imp::define_interface!(IPrintWriteStreamFlush, IPrintWriteStreamFlush_Vtbl, 0x07d11ff8_1753_4873_b749_6cdaf068e4c3);
 windows_core::imp::interface_hierarchy!(IPrintWriteStreamFlush, windows_core::IUnknown);
@@ -9101,6 +11863,20 @@ impl IPrintWriteStreamFlush_Vtbl {
         iid == &<IPrintWriteStreamFlush as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrintWriteStreamFlush_Impl> windows_core::imp::TearOffVtable<Identity> for IPrintWriteStreamFlush_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FlushData<Identity: windows_core::IUnknownImpl + IPrintWriteStreamFlush_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrintWriteStreamFlush_Impl::FlushData(this).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, FlushData: FlushData::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IPrintWriteStreamFlush {}
 windows_core::imp::define_interface!(IPrinterBidiSetRequestCallback, IPrinterBidiSetRequestCallback_Vtbl, 0xc52d32dd_f2b4_4052_8502_ec4305ecb71f);
 windows_core::imp::interface_hierarchy!(IPrinterBidiSetRequestCallback, windows_core::IUnknown);
@@ -9132,6 +11908,20 @@ impl IPrinterBidiSetRequestCallback_Vtbl {
         iid == &<IPrinterBidiSetRequestCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrinterBidiSetRequestCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IPrinterBidiSetRequestCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Completed<Identity: windows_core::IUnknownImpl + IPrinterBidiSetRequestCallback_Impl>(this: *mut core::ffi::c_void, bstrresponse: *mut core::ffi::c_void, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrinterBidiSetRequestCallback_Impl::Completed(this, core::mem::transmute(&bstrresponse), core::mem::transmute_copy(&hrstatus)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Completed: Completed::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IPrinterBidiSetRequestCallback {}
 windows_core::imp::define_interface!(IPrinterExtensionAsyncOperation, IPrinterExtensionAsyncOperation_Vtbl, 0x108d6a23_6a4b_4552_9448_68b427186acd);
 windows_core::imp::interface_hierarchy!(IPrinterExtensionAsyncOperation, windows_core::IUnknown);
@@ -9163,6 +11953,20 @@ impl IPrinterExtensionAsyncOperation_Vtbl {
         iid == &<IPrinterExtensionAsyncOperation as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrinterExtensionAsyncOperation_Impl> windows_core::imp::TearOffVtable<Identity> for IPrinterExtensionAsyncOperation_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Cancel<Identity: windows_core::IUnknownImpl + IPrinterExtensionAsyncOperation_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrinterExtensionAsyncOperation_Impl::Cancel(this).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Cancel: Cancel::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IPrinterExtensionAsyncOperation {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrinterExtensionContext, IPrinterExtensionContext_Vtbl, 0x39843bf2_c4d2_41fd_b4b2_aedbee5e1900);
@@ -9283,22 +12087,85 @@ impl IPrinterExtensionContext_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IPrinterExtensionContext {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IPrinterExtensionContextCollection, IPrinterExtensionContextCollection_Vtbl, 0xfb476970_9bab_4861_811e_3e98b0c5addf);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IPrinterExtensionContextCollection {
-    type Target = super::super::System::Com::IDispatch;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IPrinterExtensionContextCollection, windows_core::IUnknown, super::super::System::Com::IDispatch);
-#[cfg(feature = "Win32_System_Com")]
-impl IPrinterExtensionContextCollection {
-    pub unsafe fn Count(&self) -> windows_core::Result<u32> {
-        unsafe {
+impl<Identity: windows_core::IUnknownImpl + IPrinterExtensionContext_Impl> windows_core::imp::TearOffVtable<Identity> for IPrinterExtensionContext_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn PrinterQueue<Identity: windows_core::IUnknownImpl + IPrinterExtensionContext_Impl>(this: *mut core::ffi::c_void, ppqueue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterExtensionContext_Impl::PrinterQueue(this) {
+                    Ok(ok__) => {
+                        ppqueue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PrintSchemaTicket<Identity: windows_core::IUnknownImpl + IPrinterExtensionContext_Impl>(this: *mut core::ffi::c_void, ppticket: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterExtensionContext_Impl::PrintSchemaTicket(this) {
+                    Ok(ok__) => {
+                        ppticket.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DriverProperties<Identity: windows_core::IUnknownImpl + IPrinterExtensionContext_Impl>(this: *mut core::ffi::c_void, pppropertybag: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterExtensionContext_Impl::DriverProperties(this) {
+                    Ok(ok__) => {
+                        pppropertybag.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UserProperties<Identity: windows_core::IUnknownImpl + IPrinterExtensionContext_Impl>(this: *mut core::ffi::c_void, pppropertybag: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterExtensionContext_Impl::UserProperties(this) {
+                    Ok(ok__) => {
+                        pppropertybag.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PrinterQueue: PrinterQueue::<Identity>,
+            PrintSchemaTicket: PrintSchemaTicket::<Identity>,
+            DriverProperties: DriverProperties::<Identity>,
+            UserProperties: UserProperties::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IPrinterExtensionContext {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(IPrinterExtensionContextCollection, IPrinterExtensionContextCollection_Vtbl, 0xfb476970_9bab_4861_811e_3e98b0c5addf);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for IPrinterExtensionContextCollection {
+    type Target = super::super::System::Com::IDispatch;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(IPrinterExtensionContextCollection, windows_core::IUnknown, super::super::System::Com::IDispatch);
+#[cfg(feature = "Win32_System_Com")]
+impl IPrinterExtensionContextCollection {
+    pub unsafe fn Count(&self) -> windows_core::Result<u32> {
+        unsafe {
             let mut result__ = core::mem::zeroed();
             (windows_core::Interface::vtable(self).Count)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
         }
@@ -9382,6 +12249,56 @@ impl IPrinterExtensionContextCollection_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrinterExtensionContextCollection_Impl> windows_core::imp::TearOffVtable<Identity> for IPrinterExtensionContextCollection_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + IPrinterExtensionContextCollection_Impl>(this: *mut core::ffi::c_void, pulcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterExtensionContextCollection_Impl::Count(this) {
+                    Ok(ok__) => {
+                        pulcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAt<Identity: windows_core::IUnknownImpl + IPrinterExtensionContextCollection_Impl>(this: *mut core::ffi::c_void, ulindex: u32, ppcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterExtensionContextCollection_Impl::GetAt(this, core::mem::transmute_copy(&ulindex)) {
+                    Ok(ok__) => {
+                        ppcontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + IPrinterExtensionContextCollection_Impl>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterExtensionContextCollection_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        ppunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Count: Count::<Identity>,
+            GetAt: GetAt::<Identity>,
+            _NewEnum: _NewEnum::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrinterExtensionContextCollection {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrinterExtensionEvent, IPrinterExtensionEvent_Vtbl, 0xc093cb63_5ef5_4585_af8e_4d5637487b57);
@@ -9448,6 +12365,31 @@ impl IPrinterExtensionEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrinterExtensionEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IPrinterExtensionEvent_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnDriverEvent<Identity: windows_core::IUnknownImpl + IPrinterExtensionEvent_Impl>(this: *mut core::ffi::c_void, peventargs: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrinterExtensionEvent_Impl::OnDriverEvent(this, core::mem::transmute_copy(&peventargs)).into()
+            }
+        }
+        unsafe extern "system" fn OnPrinterQueuesEnumerated<Identity: windows_core::IUnknownImpl + IPrinterExtensionEvent_Impl>(this: *mut core::ffi::c_void, pcontextcollection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrinterExtensionEvent_Impl::OnPrinterQueuesEnumerated(this, core::mem::transmute_copy(&pcontextcollection)).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnDriverEvent: OnDriverEvent::<Identity>,
+            OnPrinterQueuesEnumerated: OnPrinterQueuesEnumerated::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrinterExtensionEvent {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrinterExtensionEventArgs, IPrinterExtensionEventArgs_Vtbl, 0x39843bf4_c4d2_41fd_b4b2_aedbee5e1900);
@@ -9631,6 +12573,108 @@ impl IPrinterExtensionEventArgs_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrinterExtensionEventArgs_Impl> windows_core::imp::TearOffVtable<Identity> for IPrinterExtensionEventArgs_Vtbl
+where
+    IPrinterExtensionContext_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BidiNotification<Identity: windows_core::IUnknownImpl + IPrinterExtensionEventArgs_Impl>(this: *mut core::ffi::c_void, pbstrbidinotification: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterExtensionEventArgs_Impl::BidiNotification(this) {
+                    Ok(ok__) => {
+                        pbstrbidinotification.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReasonId<Identity: windows_core::IUnknownImpl + IPrinterExtensionEventArgs_Impl>(this: *mut core::ffi::c_void, preasonid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterExtensionEventArgs_Impl::ReasonId(this) {
+                    Ok(ok__) => {
+                        preasonid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Request<Identity: windows_core::IUnknownImpl + IPrinterExtensionEventArgs_Impl>(this: *mut core::ffi::c_void, pprequest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterExtensionEventArgs_Impl::Request(this) {
+                    Ok(ok__) => {
+                        pprequest.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SourceApplication<Identity: windows_core::IUnknownImpl + IPrinterExtensionEventArgs_Impl>(this: *mut core::ffi::c_void, pbstrapplication: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterExtensionEventArgs_Impl::SourceApplication(this) {
+                    Ok(ok__) => {
+                        pbstrapplication.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DetailedReasonId<Identity: windows_core::IUnknownImpl + IPrinterExtensionEventArgs_Impl>(this: *mut core::ffi::c_void, pdetailedreasonid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterExtensionEventArgs_Impl::DetailedReasonId(this) {
+                    Ok(ok__) => {
+                        pdetailedreasonid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn WindowModal<Identity: windows_core::IUnknownImpl + IPrinterExtensionEventArgs_Impl>(this: *mut core::ffi::c_void, pbmodal: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterExtensionEventArgs_Impl::WindowModal(this) {
+                    Ok(ok__) => {
+                        pbmodal.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn WindowParent<Identity: windows_core::IUnknownImpl + IPrinterExtensionEventArgs_Impl>(this: *mut core::ffi::c_void, phwndparent: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterExtensionEventArgs_Impl::WindowParent(this) {
+                    Ok(ok__) => {
+                        phwndparent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IPrinterExtensionContext_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            BidiNotification: BidiNotification::<Identity>,
+            ReasonId: ReasonId::<Identity>,
+            Request: Request::<Identity>,
+            SourceApplication: SourceApplication::<Identity>,
+            DetailedReasonId: DetailedReasonId::<Identity>,
+            WindowModal: WindowModal::<Identity>,
+            WindowParent: WindowParent::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrinterExtensionEventArgs {}
 windows_core::imp::define_interface!(IPrinterExtensionManager, IPrinterExtensionManager_Vtbl, 0x93c6eb8c_b001_4355_9629_8e8a1b3f8e77);
 windows_core::imp::interface_hierarchy!(IPrinterExtensionManager, windows_core::IUnknown);
@@ -9677,6 +12721,30 @@ impl IPrinterExtensionManager_Vtbl {
         iid == &<IPrinterExtensionManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrinterExtensionManager_Impl> windows_core::imp::TearOffVtable<Identity> for IPrinterExtensionManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnableEvents<Identity: windows_core::IUnknownImpl + IPrinterExtensionManager_Impl>(this: *mut core::ffi::c_void, printerdriverid: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrinterExtensionManager_Impl::EnableEvents(this, core::mem::transmute(&printerdriverid)).into()
+            }
+        }
+        unsafe extern "system" fn DisableEvents<Identity: windows_core::IUnknownImpl + IPrinterExtensionManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrinterExtensionManager_Impl::DisableEvents(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnableEvents: EnableEvents::<Identity>,
+            DisableEvents: DisableEvents::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPrinterExtensionManager {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrinterExtensionRequest, IPrinterExtensionRequest_Vtbl, 0x39843bf3_c4d2_41fd_b4b2_aedbee5e1900);
@@ -9737,6 +12805,31 @@ impl IPrinterExtensionRequest_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrinterExtensionRequest_Impl> windows_core::imp::TearOffVtable<Identity> for IPrinterExtensionRequest_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Cancel<Identity: windows_core::IUnknownImpl + IPrinterExtensionRequest_Impl>(this: *mut core::ffi::c_void, hrstatus: windows_core::HRESULT, bstrlogmessage: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrinterExtensionRequest_Impl::Cancel(this, core::mem::transmute_copy(&hrstatus), core::mem::transmute(&bstrlogmessage)).into()
+            }
+        }
+        unsafe extern "system" fn Complete<Identity: windows_core::IUnknownImpl + IPrinterExtensionRequest_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrinterExtensionRequest_Impl::Complete(this).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Cancel: Cancel::<Identity>,
+            Complete: Complete::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrinterExtensionRequest {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrinterPropertyBag, IPrinterPropertyBag_Vtbl, 0xfea77364_df95_4a23_a905_019b79a8e481);
@@ -9938,35 +13031,146 @@ impl IPrinterPropertyBag_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IPrinterPropertyBag {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IPrinterQueue, IPrinterQueue_Vtbl, 0x3580a828_07fe_4b94_ac1a_757d9d2d3056);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IPrinterQueue {
-    type Target = super::super::System::Com::IDispatch;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IPrinterQueue, windows_core::IUnknown, super::super::System::Com::IDispatch);
-#[cfg(feature = "Win32_System_Com")]
-impl IPrinterQueue {
-    pub unsafe fn Handle(&self) -> windows_core::Result<PRINTER_HANDLE> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).Handle)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+impl<Identity: windows_core::IUnknownImpl + IPrinterPropertyBag_Impl> windows_core::imp::TearOffVtable<Identity> for IPrinterPropertyBag_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetBool<Identity: windows_core::IUnknownImpl + IPrinterPropertyBag_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, pbvalue: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterPropertyBag_Impl::GetBool(this, core::mem::transmute(&bstrname)) {
+                    Ok(ok__) => {
+                        pbvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn Name(&self) -> windows_core::Result<windows_core::BSTR> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).Name)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        unsafe extern "system" fn SetBool<Identity: windows_core::IUnknownImpl + IPrinterPropertyBag_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, bvalue: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrinterPropertyBag_Impl::SetBool(this, core::mem::transmute(&bstrname), core::mem::transmute_copy(&bvalue)).into()
+            }
         }
-    }
-    pub unsafe fn SendBidiQuery(&self, bstrbidiquery: &windows_core::BSTR) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SendBidiQuery)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(bstrbidiquery)).ok() }
-    }
+        unsafe extern "system" fn GetInt32<Identity: windows_core::IUnknownImpl + IPrinterPropertyBag_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, pnvalue: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterPropertyBag_Impl::GetInt32(this, core::mem::transmute(&bstrname)) {
+                    Ok(ok__) => {
+                        pnvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetInt32<Identity: windows_core::IUnknownImpl + IPrinterPropertyBag_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, nvalue: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrinterPropertyBag_Impl::SetInt32(this, core::mem::transmute(&bstrname), core::mem::transmute_copy(&nvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetString<Identity: windows_core::IUnknownImpl + IPrinterPropertyBag_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, pbstrvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterPropertyBag_Impl::GetString(this, core::mem::transmute(&bstrname)) {
+                    Ok(ok__) => {
+                        pbstrvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetString<Identity: windows_core::IUnknownImpl + IPrinterPropertyBag_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, bstrvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrinterPropertyBag_Impl::SetString(this, core::mem::transmute(&bstrname), core::mem::transmute(&bstrvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetBytes<Identity: windows_core::IUnknownImpl + IPrinterPropertyBag_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, pcbvalue: *mut u32, ppvalue: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrinterPropertyBag_Impl::GetBytes(this, core::mem::transmute(&bstrname), core::mem::transmute_copy(&pcbvalue), core::mem::transmute_copy(&ppvalue)).into()
+            }
+        }
+        unsafe extern "system" fn SetBytes<Identity: windows_core::IUnknownImpl + IPrinterPropertyBag_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, cbvalue: u32, pvalue: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrinterPropertyBag_Impl::SetBytes(this, core::mem::transmute(&bstrname), core::mem::transmute_copy(&cbvalue), core::mem::transmute_copy(&pvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetReadStream<Identity: windows_core::IUnknownImpl + IPrinterPropertyBag_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterPropertyBag_Impl::GetReadStream(this, core::mem::transmute(&bstrname)) {
+                    Ok(ok__) => {
+                        ppvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetWriteStream<Identity: windows_core::IUnknownImpl + IPrinterPropertyBag_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterPropertyBag_Impl::GetWriteStream(this, core::mem::transmute(&bstrname)) {
+                    Ok(ok__) => {
+                        ppvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetBool: GetBool::<Identity>,
+            SetBool: SetBool::<Identity>,
+            GetInt32: GetInt32::<Identity>,
+            SetInt32: SetInt32::<Identity>,
+            GetString: GetString::<Identity>,
+            SetString: SetString::<Identity>,
+            GetBytes: GetBytes::<Identity>,
+            SetBytes: SetBytes::<Identity>,
+            GetReadStream: GetReadStream::<Identity>,
+            GetWriteStream: GetWriteStream::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IPrinterPropertyBag {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(IPrinterQueue, IPrinterQueue_Vtbl, 0x3580a828_07fe_4b94_ac1a_757d9d2d3056);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for IPrinterQueue {
+    type Target = super::super::System::Com::IDispatch;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(IPrinterQueue, windows_core::IUnknown, super::super::System::Com::IDispatch);
+#[cfg(feature = "Win32_System_Com")]
+impl IPrinterQueue {
+    pub unsafe fn Handle(&self) -> windows_core::Result<PRINTER_HANDLE> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).Handle)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn Name(&self) -> windows_core::Result<windows_core::BSTR> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).Name)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        }
+    }
+    pub unsafe fn SendBidiQuery(&self, bstrbidiquery: &windows_core::BSTR) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SendBidiQuery)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(bstrbidiquery)).ok() }
+    }
     pub unsafe fn GetProperties(&self) -> windows_core::Result<IPrinterPropertyBag> {
         unsafe {
             let mut result__ = core::mem::zeroed();
@@ -10049,6 +13253,63 @@ impl IPrinterQueue_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrinterQueue_Impl> windows_core::imp::TearOffVtable<Identity> for IPrinterQueue_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Handle<Identity: windows_core::IUnknownImpl + IPrinterQueue_Impl>(this: *mut core::ffi::c_void, phprinter: *mut PRINTER_HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterQueue_Impl::Handle(this) {
+                    Ok(ok__) => {
+                        phprinter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + IPrinterQueue_Impl>(this: *mut core::ffi::c_void, pbstrname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterQueue_Impl::Name(this) {
+                    Ok(ok__) => {
+                        pbstrname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SendBidiQuery<Identity: windows_core::IUnknownImpl + IPrinterQueue_Impl>(this: *mut core::ffi::c_void, bstrbidiquery: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrinterQueue_Impl::SendBidiQuery(this, core::mem::transmute(&bstrbidiquery)).into()
+            }
+        }
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + IPrinterQueue_Impl>(this: *mut core::ffi::c_void, pppropertybag: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterQueue_Impl::GetProperties(this) {
+                    Ok(ok__) => {
+                        pppropertybag.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Handle: Handle::<Identity>,
+            Name: Name::<Identity>,
+            SendBidiQuery: SendBidiQuery::<Identity>,
+            GetProperties: GetProperties::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrinterQueue {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrinterQueue2, IPrinterQueue2_Vtbl, 0x8cd444e8_c9bb_49b3_8e38_e03209416131);
@@ -10130,6 +13391,43 @@ impl IPrinterQueue2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrinterQueue2_Impl> windows_core::imp::TearOffVtable<Identity> for IPrinterQueue2_Vtbl
+where
+    IPrinterQueue_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SendBidiSetRequestAsync<Identity: windows_core::IUnknownImpl + IPrinterQueue2_Impl>(this: *mut core::ffi::c_void, bstrbidirequest: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, ppasyncoperation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterQueue2_Impl::SendBidiSetRequestAsync(this, core::mem::transmute(&bstrbidirequest), core::mem::transmute_copy(&pcallback)) {
+                    Ok(ok__) => {
+                        ppasyncoperation.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPrinterQueueView<Identity: windows_core::IUnknownImpl + IPrinterQueue2_Impl>(this: *mut core::ffi::c_void, ulviewoffset: u32, ulviewsize: u32, ppjobview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterQueue2_Impl::GetPrinterQueueView(this, core::mem::transmute_copy(&ulviewoffset), core::mem::transmute_copy(&ulviewsize)) {
+                    Ok(ok__) => {
+                        ppjobview.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IPrinterQueue_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SendBidiSetRequestAsync: SendBidiSetRequestAsync::<Identity>,
+            GetPrinterQueueView: GetPrinterQueueView::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrinterQueue2 {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrinterQueueEvent, IPrinterQueueEvent_Vtbl, 0x214685f6_7b78_4681_87e0_495f739273d1);
@@ -10175,6 +13473,24 @@ impl IPrinterQueueEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrinterQueueEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IPrinterQueueEvent_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnBidiResponseReceived<Identity: windows_core::IUnknownImpl + IPrinterQueueEvent_Impl>(this: *mut core::ffi::c_void, bstrresponse: *mut core::ffi::c_void, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrinterQueueEvent_Impl::OnBidiResponseReceived(this, core::mem::transmute(&bstrresponse), core::mem::transmute_copy(&hrstatus)).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnBidiResponseReceived: OnBidiResponseReceived::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrinterQueueEvent {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrinterQueueView, IPrinterQueueView_Vtbl, 0x476e2969_3b2b_4b3f_8277_cff6056042aa);
@@ -10220,6 +13536,24 @@ impl IPrinterQueueView_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrinterQueueView_Impl> windows_core::imp::TearOffVtable<Identity> for IPrinterQueueView_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetViewRange<Identity: windows_core::IUnknownImpl + IPrinterQueueView_Impl>(this: *mut core::ffi::c_void, ulviewoffset: u32, ulviewsize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrinterQueueView_Impl::SetViewRange(this, core::mem::transmute_copy(&ulviewoffset), core::mem::transmute_copy(&ulviewsize)).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetViewRange: SetViewRange::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrinterQueueView {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrinterQueueViewEvent, IPrinterQueueViewEvent_Vtbl, 0xc5b6042b_fd21_404a_a0ef_e2fbb52b9080);
@@ -10268,6 +13602,21 @@ impl IPrinterQueueViewEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrinterQueueViewEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IPrinterQueueViewEvent_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnChanged<Identity: windows_core::IUnknownImpl + IPrinterQueueViewEvent_Impl>(this: *mut core::ffi::c_void, pcollection: *mut core::ffi::c_void, ulviewoffset: u32, ulviewsize: u32, ulcountjobsinprintqueue: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrinterQueueViewEvent_Impl::OnChanged(this, core::mem::transmute_copy(&pcollection), core::mem::transmute_copy(&ulviewoffset), core::mem::transmute_copy(&ulviewsize), core::mem::transmute_copy(&ulcountjobsinprintqueue)).into()
+            }
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnChanged: OnChanged::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrinterQueueViewEvent {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrinterScriptContext, IPrinterScriptContext_Vtbl, 0x066acbca_8881_49c9_bb98_fae16b4889e1);
@@ -10367,6 +13716,56 @@ impl IPrinterScriptContext_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrinterScriptContext_Impl> windows_core::imp::TearOffVtable<Identity> for IPrinterScriptContext_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DriverProperties<Identity: windows_core::IUnknownImpl + IPrinterScriptContext_Impl>(this: *mut core::ffi::c_void, pppropertybag: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterScriptContext_Impl::DriverProperties(this) {
+                    Ok(ok__) => {
+                        pppropertybag.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueueProperties<Identity: windows_core::IUnknownImpl + IPrinterScriptContext_Impl>(this: *mut core::ffi::c_void, pppropertybag: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterScriptContext_Impl::QueueProperties(this) {
+                    Ok(ok__) => {
+                        pppropertybag.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UserProperties<Identity: windows_core::IUnknownImpl + IPrinterScriptContext_Impl>(this: *mut core::ffi::c_void, pppropertybag: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterScriptContext_Impl::UserProperties(this) {
+                    Ok(ok__) => {
+                        pppropertybag.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DriverProperties: DriverProperties::<Identity>,
+            QueueProperties: QueueProperties::<Identity>,
+            UserProperties: UserProperties::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrinterScriptContext {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrinterScriptablePropertyBag, IPrinterScriptablePropertyBag_Vtbl, 0x91c7765f_ed57_49ad_8b01_dc24816a5294);
@@ -10467,7 +13866,127 @@ impl IPrinterScriptablePropertyBag_Vtbl {
     pub const fn new<Identity: IPrinterScriptablePropertyBag_Impl, const OFFSET: isize>() -> Self {
         unsafe extern "system" fn GetBool<Identity: IPrinterScriptablePropertyBag_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, pbvalue: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IPrinterScriptablePropertyBag_Impl::GetBool(this, core::mem::transmute(&bstrname)) {
+                    Ok(ok__) => {
+                        pbvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBool<Identity: IPrinterScriptablePropertyBag_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, bvalue: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IPrinterScriptablePropertyBag_Impl::SetBool(this, core::mem::transmute(&bstrname), core::mem::transmute_copy(&bvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetInt32<Identity: IPrinterScriptablePropertyBag_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, pnvalue: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IPrinterScriptablePropertyBag_Impl::GetInt32(this, core::mem::transmute(&bstrname)) {
+                    Ok(ok__) => {
+                        pnvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetInt32<Identity: IPrinterScriptablePropertyBag_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, nvalue: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IPrinterScriptablePropertyBag_Impl::SetInt32(this, core::mem::transmute(&bstrname), core::mem::transmute_copy(&nvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetString<Identity: IPrinterScriptablePropertyBag_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, pbstrvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IPrinterScriptablePropertyBag_Impl::GetString(this, core::mem::transmute(&bstrname)) {
+                    Ok(ok__) => {
+                        pbstrvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetString<Identity: IPrinterScriptablePropertyBag_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, bstrvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IPrinterScriptablePropertyBag_Impl::SetString(this, core::mem::transmute(&bstrname), core::mem::transmute(&bstrvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetBytes<Identity: IPrinterScriptablePropertyBag_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, pparray: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IPrinterScriptablePropertyBag_Impl::GetBytes(this, core::mem::transmute(&bstrname)) {
+                    Ok(ok__) => {
+                        pparray.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBytes<Identity: IPrinterScriptablePropertyBag_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, parray: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IPrinterScriptablePropertyBag_Impl::SetBytes(this, core::mem::transmute(&bstrname), core::mem::transmute_copy(&parray)).into()
+            }
+        }
+        unsafe extern "system" fn GetReadStream<Identity: IPrinterScriptablePropertyBag_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IPrinterScriptablePropertyBag_Impl::GetReadStream(this, core::mem::transmute(&bstrname)) {
+                    Ok(ok__) => {
+                        ppstream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetWriteStream<Identity: IPrinterScriptablePropertyBag_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IPrinterScriptablePropertyBag_Impl::GetWriteStream(this, core::mem::transmute(&bstrname)) {
+                    Ok(ok__) => {
+                        ppstream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: super::super::System::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
+            GetBool: GetBool::<Identity, OFFSET>,
+            SetBool: SetBool::<Identity, OFFSET>,
+            GetInt32: GetInt32::<Identity, OFFSET>,
+            SetInt32: SetInt32::<Identity, OFFSET>,
+            GetString: GetString::<Identity, OFFSET>,
+            SetString: SetString::<Identity, OFFSET>,
+            GetBytes: GetBytes::<Identity, OFFSET>,
+            SetBytes: SetBytes::<Identity, OFFSET>,
+            GetReadStream: GetReadStream::<Identity, OFFSET>,
+            GetWriteStream: GetWriteStream::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinterScriptablePropertyBag as windows_core::Interface>::IID || iid == &<super::super::System::Com::IDispatch as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrinterScriptablePropertyBag_Impl> windows_core::imp::TearOffVtable<Identity> for IPrinterScriptablePropertyBag_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetBool<Identity: windows_core::IUnknownImpl + IPrinterScriptablePropertyBag_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, pbvalue: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IPrinterScriptablePropertyBag_Impl::GetBool(this, core::mem::transmute(&bstrname)) {
                     Ok(ok__) => {
                         pbvalue.write(core::mem::transmute(ok__));
@@ -10477,15 +13996,15 @@ impl IPrinterScriptablePropertyBag_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetBool<Identity: IPrinterScriptablePropertyBag_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, bvalue: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBool<Identity: windows_core::IUnknownImpl + IPrinterScriptablePropertyBag_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, bvalue: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IPrinterScriptablePropertyBag_Impl::SetBool(this, core::mem::transmute(&bstrname), core::mem::transmute_copy(&bvalue)).into()
             }
         }
-        unsafe extern "system" fn GetInt32<Identity: IPrinterScriptablePropertyBag_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, pnvalue: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetInt32<Identity: windows_core::IUnknownImpl + IPrinterScriptablePropertyBag_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, pnvalue: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IPrinterScriptablePropertyBag_Impl::GetInt32(this, core::mem::transmute(&bstrname)) {
                     Ok(ok__) => {
                         pnvalue.write(core::mem::transmute(ok__));
@@ -10495,15 +14014,15 @@ impl IPrinterScriptablePropertyBag_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetInt32<Identity: IPrinterScriptablePropertyBag_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, nvalue: i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetInt32<Identity: windows_core::IUnknownImpl + IPrinterScriptablePropertyBag_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, nvalue: i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IPrinterScriptablePropertyBag_Impl::SetInt32(this, core::mem::transmute(&bstrname), core::mem::transmute_copy(&nvalue)).into()
             }
         }
-        unsafe extern "system" fn GetString<Identity: IPrinterScriptablePropertyBag_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, pbstrvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetString<Identity: windows_core::IUnknownImpl + IPrinterScriptablePropertyBag_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, pbstrvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IPrinterScriptablePropertyBag_Impl::GetString(this, core::mem::transmute(&bstrname)) {
                     Ok(ok__) => {
                         pbstrvalue.write(core::mem::transmute(ok__));
@@ -10513,15 +14032,15 @@ impl IPrinterScriptablePropertyBag_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetString<Identity: IPrinterScriptablePropertyBag_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, bstrvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetString<Identity: windows_core::IUnknownImpl + IPrinterScriptablePropertyBag_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, bstrvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IPrinterScriptablePropertyBag_Impl::SetString(this, core::mem::transmute(&bstrname), core::mem::transmute(&bstrvalue)).into()
             }
         }
-        unsafe extern "system" fn GetBytes<Identity: IPrinterScriptablePropertyBag_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, pparray: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetBytes<Identity: windows_core::IUnknownImpl + IPrinterScriptablePropertyBag_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, pparray: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IPrinterScriptablePropertyBag_Impl::GetBytes(this, core::mem::transmute(&bstrname)) {
                     Ok(ok__) => {
                         pparray.write(core::mem::transmute(ok__));
@@ -10531,15 +14050,15 @@ impl IPrinterScriptablePropertyBag_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetBytes<Identity: IPrinterScriptablePropertyBag_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, parray: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBytes<Identity: windows_core::IUnknownImpl + IPrinterScriptablePropertyBag_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, parray: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IPrinterScriptablePropertyBag_Impl::SetBytes(this, core::mem::transmute(&bstrname), core::mem::transmute_copy(&parray)).into()
             }
         }
-        unsafe extern "system" fn GetReadStream<Identity: IPrinterScriptablePropertyBag_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetReadStream<Identity: windows_core::IUnknownImpl + IPrinterScriptablePropertyBag_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IPrinterScriptablePropertyBag_Impl::GetReadStream(this, core::mem::transmute(&bstrname)) {
                     Ok(ok__) => {
                         ppstream.write(core::mem::transmute(ok__));
@@ -10549,9 +14068,9 @@ impl IPrinterScriptablePropertyBag_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetWriteStream<Identity: IPrinterScriptablePropertyBag_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetWriteStream<Identity: windows_core::IUnknownImpl + IPrinterScriptablePropertyBag_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IPrinterScriptablePropertyBag_Impl::GetWriteStream(this, core::mem::transmute(&bstrname)) {
                     Ok(ok__) => {
                         ppstream.write(core::mem::transmute(ok__));
@@ -10562,22 +14081,19 @@ impl IPrinterScriptablePropertyBag_Vtbl {
             }
         }
         Self {
-            base__: super::super::System::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
-            GetBool: GetBool::<Identity, OFFSET>,
-            SetBool: SetBool::<Identity, OFFSET>,
-            GetInt32: GetInt32::<Identity, OFFSET>,
-            SetInt32: SetInt32::<Identity, OFFSET>,
-            GetString: GetString::<Identity, OFFSET>,
-            SetString: SetString::<Identity, OFFSET>,
-            GetBytes: GetBytes::<Identity, OFFSET>,
-            SetBytes: SetBytes::<Identity, OFFSET>,
-            GetReadStream: GetReadStream::<Identity, OFFSET>,
-            GetWriteStream: GetWriteStream::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IPrinterScriptablePropertyBag as windows_core::Interface>::IID || iid == &<super::super::System::Com::IDispatch as windows_core::Interface>::IID
-    }
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetBool: GetBool::<Identity>,
+            SetBool: SetBool::<Identity>,
+            GetInt32: GetInt32::<Identity>,
+            SetInt32: SetInt32::<Identity>,
+            GetString: GetString::<Identity>,
+            SetString: SetString::<Identity>,
+            GetBytes: GetBytes::<Identity>,
+            SetBytes: SetBytes::<Identity>,
+            GetReadStream: GetReadStream::<Identity>,
+            GetWriteStream: GetWriteStream::<Identity>,
+        }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrinterScriptablePropertyBag {}
@@ -10634,6 +14150,30 @@ impl IPrinterScriptablePropertyBag2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrinterScriptablePropertyBag2_Impl> windows_core::imp::TearOffVtable<Identity> for IPrinterScriptablePropertyBag2_Vtbl
+where
+    IPrinterScriptablePropertyBag_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetReadStreamAsXML<Identity: windows_core::IUnknownImpl + IPrinterScriptablePropertyBag2_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, ppxmlnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterScriptablePropertyBag2_Impl::GetReadStreamAsXML(this, core::mem::transmute(&bstrname)) {
+                    Ok(ok__) => {
+                        ppxmlnode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IPrinterScriptablePropertyBag_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetReadStreamAsXML: GetReadStreamAsXML::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrinterScriptablePropertyBag2 {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrinterScriptableSequentialStream, IPrinterScriptableSequentialStream_Vtbl, 0x2072838a_316f_467a_a949_27f68c44a854);
@@ -10711,6 +14251,43 @@ impl IPrinterScriptableSequentialStream_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrinterScriptableSequentialStream_Impl> windows_core::imp::TearOffVtable<Identity> for IPrinterScriptableSequentialStream_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Read<Identity: windows_core::IUnknownImpl + IPrinterScriptableSequentialStream_Impl>(this: *mut core::ffi::c_void, cbread: i32, pparray: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterScriptableSequentialStream_Impl::Read(this, core::mem::transmute_copy(&cbread)) {
+                    Ok(ok__) => {
+                        pparray.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Write<Identity: windows_core::IUnknownImpl + IPrinterScriptableSequentialStream_Impl>(this: *mut core::ffi::c_void, parray: *mut core::ffi::c_void, pcbwritten: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterScriptableSequentialStream_Impl::Write(this, core::mem::transmute_copy(&parray)) {
+                    Ok(ok__) => {
+                        pcbwritten.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Read: Read::<Identity>,
+            Write: Write::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrinterScriptableSequentialStream {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrinterScriptableStream, IPrinterScriptableStream_Vtbl, 0x7edf9a92_4750_41a5_a17f_879a6f4f7dcb);
@@ -10792,6 +14369,44 @@ impl IPrinterScriptableStream_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrinterScriptableStream_Impl> windows_core::imp::TearOffVtable<Identity> for IPrinterScriptableStream_Vtbl
+where
+    IPrinterScriptableSequentialStream_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Commit<Identity: windows_core::IUnknownImpl + IPrinterScriptableStream_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrinterScriptableStream_Impl::Commit(this).into()
+            }
+        }
+        unsafe extern "system" fn Seek<Identity: windows_core::IUnknownImpl + IPrinterScriptableStream_Impl>(this: *mut core::ffi::c_void, loffset: i32, streamseek: super::super::System::Com::STREAM_SEEK, plposition: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrinterScriptableStream_Impl::Seek(this, core::mem::transmute_copy(&loffset), core::mem::transmute_copy(&streamseek)) {
+                    Ok(ok__) => {
+                        plposition.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSize<Identity: windows_core::IUnknownImpl + IPrinterScriptableStream_Impl>(this: *mut core::ffi::c_void, lsize: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrinterScriptableStream_Impl::SetSize(this, core::mem::transmute_copy(&lsize)).into()
+            }
+        }
+        Self {
+            base__: <IPrinterScriptableSequentialStream_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Commit: Commit::<Identity>,
+            Seek: Seek::<Identity>,
+            SetSize: SetSize::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrinterScriptableStream {}
 windows_core::imp::define_interface!(IXpsDocument, IXpsDocument_Vtbl, 0xe8d907db_62a9_4a95_abe7_e01763dd30f8);
 windows_core::imp::interface_hierarchy!(IXpsDocument, windows_core::IUnknown);
@@ -10850,6 +14465,36 @@ impl IXpsDocument_Vtbl {
         iid == &<IXpsDocument as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXpsDocument_Impl> windows_core::imp::TearOffVtable<Identity> for IXpsDocument_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetThumbnail<Identity: windows_core::IUnknownImpl + IXpsDocument_Impl>(this: *mut core::ffi::c_void, ppthumbnail: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXpsDocument_Impl::GetThumbnail(this) {
+                    Ok(ok__) => {
+                        ppthumbnail.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetThumbnail<Identity: windows_core::IUnknownImpl + IXpsDocument_Impl>(this: *mut core::ffi::c_void, pthumbnail: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXpsDocument_Impl::SetThumbnail(this, core::mem::transmute_copy(&pthumbnail)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetThumbnail: GetThumbnail::<Identity>,
+            SetThumbnail: SetThumbnail::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IXpsDocument {}
 windows_core::imp::define_interface!(IXpsDocumentConsumer, IXpsDocumentConsumer_Vtbl, 0x4368d8a2_4181_4a9f_b295_3d9a38bb9ba0);
 windows_core::imp::interface_hierarchy!(IXpsDocumentConsumer, windows_core::IUnknown);
@@ -10974,6 +14619,65 @@ impl IXpsDocumentConsumer_Vtbl {
         iid == &<IXpsDocumentConsumer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXpsDocumentConsumer_Impl> windows_core::imp::TearOffVtable<Identity> for IXpsDocumentConsumer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SendXpsUnknown<Identity: windows_core::IUnknownImpl + IXpsDocumentConsumer_Impl>(this: *mut core::ffi::c_void, punknown: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXpsDocumentConsumer_Impl::SendXpsUnknown(this, core::mem::transmute_copy(&punknown)).into()
+            }
+        }
+        unsafe extern "system" fn SendXpsDocument<Identity: windows_core::IUnknownImpl + IXpsDocumentConsumer_Impl>(this: *mut core::ffi::c_void, pixpsdocument: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXpsDocumentConsumer_Impl::SendXpsDocument(this, core::mem::transmute_copy(&pixpsdocument)).into()
+            }
+        }
+        unsafe extern "system" fn SendFixedDocumentSequence<Identity: windows_core::IUnknownImpl + IXpsDocumentConsumer_Impl>(this: *mut core::ffi::c_void, pifixeddocumentsequence: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXpsDocumentConsumer_Impl::SendFixedDocumentSequence(this, core::mem::transmute_copy(&pifixeddocumentsequence)).into()
+            }
+        }
+        unsafe extern "system" fn SendFixedDocument<Identity: windows_core::IUnknownImpl + IXpsDocumentConsumer_Impl>(this: *mut core::ffi::c_void, pifixeddocument: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXpsDocumentConsumer_Impl::SendFixedDocument(this, core::mem::transmute_copy(&pifixeddocument)).into()
+            }
+        }
+        unsafe extern "system" fn SendFixedPage<Identity: windows_core::IUnknownImpl + IXpsDocumentConsumer_Impl>(this: *mut core::ffi::c_void, pifixedpage: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXpsDocumentConsumer_Impl::SendFixedPage(this, core::mem::transmute_copy(&pifixedpage)).into()
+            }
+        }
+        unsafe extern "system" fn CloseSender<Identity: windows_core::IUnknownImpl + IXpsDocumentConsumer_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXpsDocumentConsumer_Impl::CloseSender(this).into()
+            }
+        }
+        unsafe extern "system" fn GetNewEmptyPart<Identity: windows_core::IUnknownImpl + IXpsDocumentConsumer_Impl>(this: *mut core::ffi::c_void, uri: windows_core::PCWSTR, riid: *const windows_core::GUID, ppnewobject: *mut *mut core::ffi::c_void, ppwritestream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXpsDocumentConsumer_Impl::GetNewEmptyPart(this, core::mem::transmute(&uri), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppnewobject), core::mem::transmute_copy(&ppwritestream)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SendXpsUnknown: SendXpsUnknown::<Identity>,
+            SendXpsDocument: SendXpsDocument::<Identity>,
+            SendFixedDocumentSequence: SendFixedDocumentSequence::<Identity>,
+            SendFixedDocument: SendFixedDocument::<Identity>,
+            SendFixedPage: SendFixedPage::<Identity>,
+            CloseSender: CloseSender::<Identity>,
+            GetNewEmptyPart: GetNewEmptyPart::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IXpsDocumentConsumer {}
 windows_core::imp::define_interface!(IXpsDocumentProvider, IXpsDocumentProvider_Vtbl, 0xb8cf8530_5562_47c4_ab67_b1f69ecf961e);
 windows_core::imp::interface_hierarchy!(IXpsDocumentProvider, windows_core::IUnknown);
@@ -11014,6 +14718,26 @@ impl IXpsDocumentProvider_Vtbl {
         iid == &<IXpsDocumentProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXpsDocumentProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IXpsDocumentProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetXpsPart<Identity: windows_core::IUnknownImpl + IXpsDocumentProvider_Impl>(this: *mut core::ffi::c_void, ppixpspart: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXpsDocumentProvider_Impl::GetXpsPart(this) {
+                    Ok(ok__) => {
+                        ppixpspart.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetXpsPart: GetXpsPart::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IXpsDocumentProvider {}
 windows_core::imp::define_interface!(IXpsPartIterator, IXpsPartIterator_Vtbl, 0x0021d3cd_af6f_42ab_9999_14bc82a62d2e);
 windows_core::imp::interface_hierarchy!(IXpsPartIterator, windows_core::IUnknown);
@@ -11084,6 +14808,44 @@ impl IXpsPartIterator_Vtbl {
         iid == &<IXpsPartIterator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXpsPartIterator_Impl> windows_core::imp::TearOffVtable<Identity> for IXpsPartIterator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IXpsPartIterator_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXpsPartIterator_Impl::Reset(this)
+            }
+        }
+        unsafe extern "system" fn Current<Identity: windows_core::IUnknownImpl + IXpsPartIterator_Impl>(this: *mut core::ffi::c_void, puri: *mut *mut core::ffi::c_void, ppxpspart: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXpsPartIterator_Impl::Current(this, core::mem::transmute_copy(&puri), core::mem::transmute_copy(&ppxpspart)).into()
+            }
+        }
+        unsafe extern "system" fn IsDone<Identity: windows_core::IUnknownImpl + IXpsPartIterator_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXpsPartIterator_Impl::IsDone(this)
+            }
+        }
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IXpsPartIterator_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXpsPartIterator_Impl::Next(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Reset: Reset::<Identity>,
+            Current: Current::<Identity>,
+            IsDone: IsDone::<Identity>,
+            Next: Next::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IXpsPartIterator {}
 windows_core::imp::define_interface!(IXpsRasterizationFactory, IXpsRasterizationFactory_Vtbl, 0xe094808a_24c6_482b_a3a7_c21ac9b55f17);
 windows_core::imp::interface_hierarchy!(IXpsRasterizationFactory, windows_core::IUnknown);
@@ -11134,6 +14896,27 @@ impl IXpsRasterizationFactory_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Storage_Xps")]
+impl<Identity: windows_core::IUnknownImpl + IXpsRasterizationFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IXpsRasterizationFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateRasterizer<Identity: windows_core::IUnknownImpl + IXpsRasterizationFactory_Impl>(this: *mut core::ffi::c_void, xpspage: *mut core::ffi::c_void, dpi: f32, nontextrenderingmode: XPSRAS_RENDERING_MODE, textrenderingmode: XPSRAS_RENDERING_MODE, ppixpsrasterizer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXpsRasterizationFactory_Impl::CreateRasterizer(this, core::mem::transmute_copy(&xpspage), core::mem::transmute_copy(&dpi), core::mem::transmute_copy(&nontextrenderingmode), core::mem::transmute_copy(&textrenderingmode)) {
+                    Ok(ok__) => {
+                        ppixpsrasterizer.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateRasterizer: CreateRasterizer::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_Storage_Xps")]
 impl windows_core::RuntimeName for IXpsRasterizationFactory {}
 windows_core::imp::define_interface!(IXpsRasterizationFactory1, IXpsRasterizationFactory1_Vtbl, 0x2d6e5f77_6414_4a1e_a8e0_d4194ce6a26f);
 windows_core::imp::interface_hierarchy!(IXpsRasterizationFactory1, windows_core::IUnknown);
@@ -11184,6 +14967,27 @@ impl IXpsRasterizationFactory1_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Storage_Xps")]
+impl<Identity: windows_core::IUnknownImpl + IXpsRasterizationFactory1_Impl> windows_core::imp::TearOffVtable<Identity> for IXpsRasterizationFactory1_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateRasterizer<Identity: windows_core::IUnknownImpl + IXpsRasterizationFactory1_Impl>(this: *mut core::ffi::c_void, xpspage: *mut core::ffi::c_void, dpi: f32, nontextrenderingmode: XPSRAS_RENDERING_MODE, textrenderingmode: XPSRAS_RENDERING_MODE, pixelformat: XPSRAS_PIXEL_FORMAT, ppixpsrasterizer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXpsRasterizationFactory1_Impl::CreateRasterizer(this, core::mem::transmute_copy(&xpspage), core::mem::transmute_copy(&dpi), core::mem::transmute_copy(&nontextrenderingmode), core::mem::transmute_copy(&textrenderingmode), core::mem::transmute_copy(&pixelformat)) {
+                    Ok(ok__) => {
+                        ppixpsrasterizer.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateRasterizer: CreateRasterizer::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_Storage_Xps")]
 impl windows_core::RuntimeName for IXpsRasterizationFactory1 {}
 windows_core::imp::define_interface!(IXpsRasterizationFactory2, IXpsRasterizationFactory2_Vtbl, 0x9c16ce3e_10f5_41fd_9ddc_6826669c2ff6);
 windows_core::imp::interface_hierarchy!(IXpsRasterizationFactory2, windows_core::IUnknown);
@@ -11234,6 +15038,27 @@ impl IXpsRasterizationFactory2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Storage_Xps")]
+impl<Identity: windows_core::IUnknownImpl + IXpsRasterizationFactory2_Impl> windows_core::imp::TearOffVtable<Identity> for IXpsRasterizationFactory2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateRasterizer<Identity: windows_core::IUnknownImpl + IXpsRasterizationFactory2_Impl>(this: *mut core::ffi::c_void, xpspage: *mut core::ffi::c_void, dpix: f32, dpiy: f32, nontextrenderingmode: XPSRAS_RENDERING_MODE, textrenderingmode: XPSRAS_RENDERING_MODE, pixelformat: XPSRAS_PIXEL_FORMAT, backgroundcolor: XPSRAS_BACKGROUND_COLOR, ppixpsrasterizer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXpsRasterizationFactory2_Impl::CreateRasterizer(this, core::mem::transmute_copy(&xpspage), core::mem::transmute_copy(&dpix), core::mem::transmute_copy(&dpiy), core::mem::transmute_copy(&nontextrenderingmode), core::mem::transmute_copy(&textrenderingmode), core::mem::transmute_copy(&pixelformat), core::mem::transmute_copy(&backgroundcolor)) {
+                    Ok(ok__) => {
+                        ppixpsrasterizer.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateRasterizer: CreateRasterizer::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_Storage_Xps")]
 impl windows_core::RuntimeName for IXpsRasterizationFactory2 {}
 windows_core::imp::define_interface!(IXpsRasterizer, IXpsRasterizer_Vtbl, 0x7567cfc8_c156_47a8_9dac_11a2ae5bdd6b);
 windows_core::imp::interface_hierarchy!(IXpsRasterizer, windows_core::IUnknown);
@@ -11299,6 +15124,37 @@ impl IXpsRasterizer_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Imaging")]
+impl<Identity: windows_core::IUnknownImpl + IXpsRasterizer_Impl> windows_core::imp::TearOffVtable<Identity> for IXpsRasterizer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RasterizeRect<Identity: windows_core::IUnknownImpl + IXpsRasterizer_Impl>(this: *mut core::ffi::c_void, x: i32, y: i32, width: i32, height: i32, notificationcallback: *mut core::ffi::c_void, bitmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXpsRasterizer_Impl::RasterizeRect(this, core::mem::transmute_copy(&x), core::mem::transmute_copy(&y), core::mem::transmute_copy(&width), core::mem::transmute_copy(&height), core::mem::transmute_copy(&notificationcallback)) {
+                    Ok(ok__) => {
+                        bitmap.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMinimalLineWidth<Identity: windows_core::IUnknownImpl + IXpsRasterizer_Impl>(this: *mut core::ffi::c_void, width: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXpsRasterizer_Impl::SetMinimalLineWidth(this, core::mem::transmute_copy(&width)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RasterizeRect: RasterizeRect::<Identity>,
+            SetMinimalLineWidth: SetMinimalLineWidth::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Imaging")]
 impl windows_core::RuntimeName for IXpsRasterizer {}
 windows_core::imp::define_interface!(IXpsRasterizerNotificationCallback, IXpsRasterizerNotificationCallback_Vtbl, 0x9ab8fd0d_cb94_49c2_9cb0_97ec1d5469d2);
 windows_core::imp::interface_hierarchy!(IXpsRasterizerNotificationCallback, windows_core::IUnknown);
@@ -11330,6 +15186,20 @@ impl IXpsRasterizerNotificationCallback_Vtbl {
         iid == &<IXpsRasterizerNotificationCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXpsRasterizerNotificationCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IXpsRasterizerNotificationCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Continue<Identity: windows_core::IUnknownImpl + IXpsRasterizerNotificationCallback_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXpsRasterizerNotificationCallback_Impl::Continue(this).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Continue: Continue::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IXpsRasterizerNotificationCallback {}
 #[repr(C)]
 #[derive(Clone, Debug, PartialEq)]
@@ -375,6 +375,12 @@ impl IApoAcousticEchoCancellation_Vtbl {
         iid == &<IApoAcousticEchoCancellation as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IApoAcousticEchoCancellation_Impl> windows_core::imp::TearOffVtable<Identity> for IApoAcousticEchoCancellation_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IApoAcousticEchoCancellation {}
 windows_core::imp::define_interface!(IApoAuxiliaryInputConfiguration, IApoAuxiliaryInputConfiguration_Vtbl, 0x4ceb0aab_fa19_48ed_a857_87771ae1b768);
 windows_core::imp::interface_hierarchy!(IApoAuxiliaryInputConfiguration, windows_core::IUnknown);
@@ -445,6 +451,43 @@ impl IApoAuxiliaryInputConfiguration_Vtbl {
         iid == &<IApoAuxiliaryInputConfiguration as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IApoAuxiliaryInputConfiguration_Impl> windows_core::imp::TearOffVtable<Identity> for IApoAuxiliaryInputConfiguration_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddAuxiliaryInput<Identity: windows_core::IUnknownImpl + IApoAuxiliaryInputConfiguration_Impl>(this: *mut core::ffi::c_void, dwinputid: u32, cbdatasize: u32, pbydata: *const u8, pinputconnection: *const APO_CONNECTION_DESCRIPTOR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IApoAuxiliaryInputConfiguration_Impl::AddAuxiliaryInput(this, core::mem::transmute_copy(&dwinputid), core::mem::transmute_copy(&cbdatasize), core::mem::transmute_copy(&pbydata), core::mem::transmute_copy(&pinputconnection)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveAuxiliaryInput<Identity: windows_core::IUnknownImpl + IApoAuxiliaryInputConfiguration_Impl>(this: *mut core::ffi::c_void, dwinputid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IApoAuxiliaryInputConfiguration_Impl::RemoveAuxiliaryInput(this, core::mem::transmute_copy(&dwinputid)).into()
+            }
+        }
+        unsafe extern "system" fn IsInputFormatSupported<Identity: windows_core::IUnknownImpl + IApoAuxiliaryInputConfiguration_Impl>(this: *mut core::ffi::c_void, prequestedinputformat: *mut core::ffi::c_void, ppsupportedinputformat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IApoAuxiliaryInputConfiguration_Impl::IsInputFormatSupported(this, core::mem::transmute_copy(&prequestedinputformat)) {
+                    Ok(ok__) => {
+                        ppsupportedinputformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddAuxiliaryInput: AddAuxiliaryInput::<Identity>,
+            RemoveAuxiliaryInput: RemoveAuxiliaryInput::<Identity>,
+            IsInputFormatSupported: IsInputFormatSupported::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IApoAuxiliaryInputConfiguration {}
 windows_core::imp::define_interface!(IApoAuxiliaryInputRT, IApoAuxiliaryInputRT_Vtbl, 0xf851809c_c177_49a0_b1b2_b66f017943ab);
 windows_core::imp::interface_hierarchy!(IApoAuxiliaryInputRT, windows_core::IUnknown);
@@ -476,6 +519,20 @@ impl IApoAuxiliaryInputRT_Vtbl {
         iid == &<IApoAuxiliaryInputRT as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IApoAuxiliaryInputRT_Impl> windows_core::imp::TearOffVtable<Identity> for IApoAuxiliaryInputRT_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AcceptInput<Identity: windows_core::IUnknownImpl + IApoAuxiliaryInputRT_Impl>(this: *mut core::ffi::c_void, dwinputid: u32, pinputconnection: *const APO_CONNECTION_PROPERTY) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IApoAuxiliaryInputRT_Impl::AcceptInput(this, core::mem::transmute_copy(&dwinputid), core::mem::transmute_copy(&pinputconnection))
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, AcceptInput: AcceptInput::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IApoAuxiliaryInputRT {}
 windows_core::imp::define_interface!(IAudioDeviceModulesClient, IAudioDeviceModulesClient_Vtbl, 0x98f37dac_d0b6_49f5_896a_aa4d169a4c48);
 windows_core::imp::interface_hierarchy!(IAudioDeviceModulesClient, windows_core::IUnknown);
@@ -510,6 +567,23 @@ impl IAudioDeviceModulesClient_Vtbl {
         iid == &<IAudioDeviceModulesClient as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioDeviceModulesClient_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioDeviceModulesClient_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAudioDeviceModulesManager<Identity: windows_core::IUnknownImpl + IAudioDeviceModulesClient_Impl>(this: *mut core::ffi::c_void, paudiodevicemodulesmanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioDeviceModulesClient_Impl::SetAudioDeviceModulesManager(this, core::mem::transmute_copy(&paudiodevicemodulesmanager)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAudioDeviceModulesManager: SetAudioDeviceModulesManager::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioDeviceModulesClient {}
 windows_core::imp::define_interface!(IAudioMediaType, IAudioMediaType_Vtbl, 0x4e997f73_b71f_4798_873b_ed7dfcf15b4d);
 windows_core::imp::interface_hierarchy!(IAudioMediaType, windows_core::IUnknown);
@@ -601,6 +675,56 @@ impl IAudioMediaType_Vtbl {
         iid == &<IAudioMediaType as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioMediaType_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioMediaType_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsCompressedFormat<Identity: windows_core::IUnknownImpl + IAudioMediaType_Impl>(this: *mut core::ffi::c_void, pfcompressed: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioMediaType_Impl::IsCompressedFormat(this) {
+                    Ok(ok__) => {
+                        pfcompressed.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsEqual<Identity: windows_core::IUnknownImpl + IAudioMediaType_Impl>(this: *mut core::ffi::c_void, piaudiotype: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioMediaType_Impl::IsEqual(this, core::mem::transmute_copy(&piaudiotype)) {
+                    Ok(ok__) => {
+                        pdwflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAudioFormat<Identity: windows_core::IUnknownImpl + IAudioMediaType_Impl>(this: *mut core::ffi::c_void) -> *mut super::WAVEFORMATEX {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioMediaType_Impl::GetAudioFormat(this)
+            }
+        }
+        unsafe extern "system" fn GetUncompressedAudioFormat<Identity: windows_core::IUnknownImpl + IAudioMediaType_Impl>(this: *mut core::ffi::c_void, puncompressedaudioformat: *mut UNCOMPRESSEDAUDIOFORMAT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioMediaType_Impl::GetUncompressedAudioFormat(this, core::mem::transmute_copy(&puncompressedaudioformat)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsCompressedFormat: IsCompressedFormat::<Identity>,
+            IsEqual: IsEqual::<Identity>,
+            GetAudioFormat: GetAudioFormat::<Identity>,
+            GetUncompressedAudioFormat: GetUncompressedAudioFormat::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioMediaType {}
 windows_core::imp::define_interface!(IAudioProcessingObject, IAudioProcessingObject_Vtbl, 0xfd7f2b29_24d0_4b5c_b177_592c39f9ca10);
 windows_core::imp::interface_hierarchy!(IAudioProcessingObject, windows_core::IUnknown);
@@ -760,6 +884,95 @@ impl IAudioProcessingObject_Vtbl {
         iid == &<IAudioProcessingObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioProcessingObject_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioProcessingObject_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IAudioProcessingObject_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioProcessingObject_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn GetLatency<Identity: windows_core::IUnknownImpl + IAudioProcessingObject_Impl>(this: *mut core::ffi::c_void, ptime: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioProcessingObject_Impl::GetLatency(this) {
+                    Ok(ok__) => {
+                        ptime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRegistrationProperties<Identity: windows_core::IUnknownImpl + IAudioProcessingObject_Impl>(this: *mut core::ffi::c_void, ppregprops: *mut *mut APO_REG_PROPERTIES) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioProcessingObject_Impl::GetRegistrationProperties(this) {
+                    Ok(ok__) => {
+                        ppregprops.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IAudioProcessingObject_Impl>(this: *mut core::ffi::c_void, cbdatasize: u32, pbydata: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioProcessingObject_Impl::Initialize(this, core::mem::transmute_copy(&cbdatasize), core::mem::transmute_copy(&pbydata)).into()
+            }
+        }
+        unsafe extern "system" fn IsInputFormatSupported<Identity: windows_core::IUnknownImpl + IAudioProcessingObject_Impl>(this: *mut core::ffi::c_void, poppositeformat: *mut core::ffi::c_void, prequestedinputformat: *mut core::ffi::c_void, ppsupportedinputformat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioProcessingObject_Impl::IsInputFormatSupported(this, core::mem::transmute_copy(&poppositeformat), core::mem::transmute_copy(&prequestedinputformat)) {
+                    Ok(ok__) => {
+                        ppsupportedinputformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsOutputFormatSupported<Identity: windows_core::IUnknownImpl + IAudioProcessingObject_Impl>(this: *mut core::ffi::c_void, poppositeformat: *mut core::ffi::c_void, prequestedoutputformat: *mut core::ffi::c_void, ppsupportedoutputformat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioProcessingObject_Impl::IsOutputFormatSupported(this, core::mem::transmute_copy(&poppositeformat), core::mem::transmute_copy(&prequestedoutputformat)) {
+                    Ok(ok__) => {
+                        ppsupportedoutputformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetInputChannelCount<Identity: windows_core::IUnknownImpl + IAudioProcessingObject_Impl>(this: *mut core::ffi::c_void, pu32channelcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioProcessingObject_Impl::GetInputChannelCount(this) {
+                    Ok(ok__) => {
+                        pu32channelcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Reset: Reset::<Identity>,
+            GetLatency: GetLatency::<Identity>,
+            GetRegistrationProperties: GetRegistrationProperties::<Identity>,
+            Initialize: Initialize::<Identity>,
+            IsInputFormatSupported: IsInputFormatSupported::<Identity>,
+            IsOutputFormatSupported: IsOutputFormatSupported::<Identity>,
+            GetInputChannelCount: GetInputChannelCount::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioProcessingObject {}
 windows_core::imp::define_interface!(IAudioProcessingObjectConfiguration, IAudioProcessingObjectConfiguration_Vtbl, 0x0e5ed805_aba6_49c3_8f9a_2b8c889c4fa8);
 windows_core::imp::interface_hierarchy!(IAudioProcessingObjectConfiguration, windows_core::IUnknown);
@@ -806,6 +1019,30 @@ impl IAudioProcessingObjectConfiguration_Vtbl {
         iid == &<IAudioProcessingObjectConfiguration as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioProcessingObjectConfiguration_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioProcessingObjectConfiguration_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn LockForProcess<Identity: windows_core::IUnknownImpl + IAudioProcessingObjectConfiguration_Impl>(this: *mut core::ffi::c_void, u32numinputconnections: u32, ppinputconnections: *const *const APO_CONNECTION_DESCRIPTOR, u32numoutputconnections: u32, ppoutputconnections: *const *const APO_CONNECTION_DESCRIPTOR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioProcessingObjectConfiguration_Impl::LockForProcess(this, core::mem::transmute_copy(&u32numinputconnections), core::mem::transmute_copy(&ppinputconnections), core::mem::transmute_copy(&u32numoutputconnections), core::mem::transmute_copy(&ppoutputconnections)).into()
+            }
+        }
+        unsafe extern "system" fn UnlockForProcess<Identity: windows_core::IUnknownImpl + IAudioProcessingObjectConfiguration_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioProcessingObjectConfiguration_Impl::UnlockForProcess(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            LockForProcess: LockForProcess::<Identity>,
+            UnlockForProcess: UnlockForProcess::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioProcessingObjectConfiguration {}
 windows_core::imp::define_interface!(IAudioProcessingObjectLoggingService, IAudioProcessingObjectLoggingService_Vtbl, 0x698f0107_1745_4708_95a5_d84478a62a65);
 windows_core::imp::interface_hierarchy!(IAudioProcessingObjectLoggingService, windows_core::IUnknown);
@@ -840,6 +1077,20 @@ impl IAudioProcessingObjectLoggingService_Vtbl {
         iid == &<IAudioProcessingObjectLoggingService as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioProcessingObjectLoggingService_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioProcessingObjectLoggingService_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ApoLog<Identity: windows_core::IUnknownImpl + IAudioProcessingObjectLoggingService_Impl>(this: *mut core::ffi::c_void, level: APO_LOG_LEVEL, format: windows_core::PCWSTR) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioProcessingObjectLoggingService_Impl::ApoLog(this, core::mem::transmute_copy(&level), core::mem::transmute(&format))
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ApoLog: ApoLog::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IAudioProcessingObjectLoggingService {}
 windows_core::imp::define_interface!(IAudioProcessingObjectNotifications, IAudioProcessingObjectNotifications_Vtbl, 0x56b0c76f_02fd_4b21_a52e_9f8219fc86e4);
 windows_core::imp::interface_hierarchy!(IAudioProcessingObjectNotifications, windows_core::IUnknown);
@@ -893,6 +1144,31 @@ impl IAudioProcessingObjectNotifications_Vtbl {
     }
 }
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl<Identity: windows_core::IUnknownImpl + IAudioProcessingObjectNotifications_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioProcessingObjectNotifications_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetApoNotificationRegistrationInfo<Identity: windows_core::IUnknownImpl + IAudioProcessingObjectNotifications_Impl>(this: *mut core::ffi::c_void, aponotifications: *mut *mut APO_NOTIFICATION_DESCRIPTOR, count: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioProcessingObjectNotifications_Impl::GetApoNotificationRegistrationInfo(this, core::mem::transmute_copy(&aponotifications), core::mem::transmute_copy(&count)).into()
+            }
+        }
+        unsafe extern "system" fn HandleNotification<Identity: windows_core::IUnknownImpl + IAudioProcessingObjectNotifications_Impl>(this: *mut core::ffi::c_void, aponotification: *const APO_NOTIFICATION) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioProcessingObjectNotifications_Impl::HandleNotification(this, core::mem::transmute_copy(&aponotification))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetApoNotificationRegistrationInfo: GetApoNotificationRegistrationInfo::<Identity>,
+            HandleNotification: HandleNotification::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
 impl windows_core::RuntimeName for IAudioProcessingObjectNotifications {}
 windows_core::imp::define_interface!(IAudioProcessingObjectNotifications2, IAudioProcessingObjectNotifications2_Vtbl, 0xca2cfbde_a9d6_4eb0_bc95_c4d026b380f0);
 impl core::ops::Deref for IAudioProcessingObjectNotifications2 {
@@ -936,6 +1212,24 @@ impl IAudioProcessingObjectNotifications2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl<Identity: windows_core::IUnknownImpl + IAudioProcessingObjectNotifications2_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioProcessingObjectNotifications2_Vtbl
+where
+    IAudioProcessingObjectNotifications_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetApoNotificationRegistrationInfo2<Identity: windows_core::IUnknownImpl + IAudioProcessingObjectNotifications2_Impl>(this: *mut core::ffi::c_void, maxaponotificationtypesupported: APO_NOTIFICATION_TYPE, aponotifications: *mut *mut APO_NOTIFICATION_DESCRIPTOR, count: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioProcessingObjectNotifications2_Impl::GetApoNotificationRegistrationInfo2(this, core::mem::transmute_copy(&maxaponotificationtypesupported), core::mem::transmute_copy(&aponotifications), core::mem::transmute_copy(&count)).into()
+            }
+        }
+        Self {
+            base__: <IAudioProcessingObjectNotifications_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetApoNotificationRegistrationInfo2: GetApoNotificationRegistrationInfo2::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
 impl windows_core::RuntimeName for IAudioProcessingObjectNotifications2 {}
 windows_core::imp::define_interface!(IAudioProcessingObjectRT, IAudioProcessingObjectRT_Vtbl, 0x9e1d6a6d_ddbc_4e95_a4c7_ad64ba37846c);
 windows_core::imp::interface_hierarchy!(IAudioProcessingObjectRT, windows_core::IUnknown);
@@ -994,6 +1288,37 @@ impl IAudioProcessingObjectRT_Vtbl {
         iid == &<IAudioProcessingObjectRT as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioProcessingObjectRT_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioProcessingObjectRT_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn APOProcess<Identity: windows_core::IUnknownImpl + IAudioProcessingObjectRT_Impl>(this: *mut core::ffi::c_void, u32numinputconnections: u32, ppinputconnections: *const *const APO_CONNECTION_PROPERTY, u32numoutputconnections: u32, ppoutputconnections: *mut *mut APO_CONNECTION_PROPERTY) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioProcessingObjectRT_Impl::APOProcess(this, core::mem::transmute_copy(&u32numinputconnections), core::mem::transmute_copy(&ppinputconnections), core::mem::transmute_copy(&u32numoutputconnections), core::mem::transmute_copy(&ppoutputconnections))
+            }
+        }
+        unsafe extern "system" fn CalcInputFrames<Identity: windows_core::IUnknownImpl + IAudioProcessingObjectRT_Impl>(this: *mut core::ffi::c_void, u32outputframecount: u32) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioProcessingObjectRT_Impl::CalcInputFrames(this, core::mem::transmute_copy(&u32outputframecount))
+            }
+        }
+        unsafe extern "system" fn CalcOutputFrames<Identity: windows_core::IUnknownImpl + IAudioProcessingObjectRT_Impl>(this: *mut core::ffi::c_void, u32inputframecount: u32) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioProcessingObjectRT_Impl::CalcOutputFrames(this, core::mem::transmute_copy(&u32inputframecount))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            APOProcess: APOProcess::<Identity>,
+            CalcInputFrames: CalcInputFrames::<Identity>,
+            CalcOutputFrames: CalcOutputFrames::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioProcessingObjectRT {}
 windows_core::imp::define_interface!(IAudioProcessingObjectRTQueueService, IAudioProcessingObjectRTQueueService_Vtbl, 0xacd65e2f_955b_4b57_b9bf_ac297bb752c9);
 windows_core::imp::interface_hierarchy!(IAudioProcessingObjectRTQueueService, windows_core::IUnknown);
@@ -1034,6 +1359,29 @@ impl IAudioProcessingObjectRTQueueService_Vtbl {
         iid == &<IAudioProcessingObjectRTQueueService as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioProcessingObjectRTQueueService_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioProcessingObjectRTQueueService_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRealTimeWorkQueue<Identity: windows_core::IUnknownImpl + IAudioProcessingObjectRTQueueService_Impl>(this: *mut core::ffi::c_void, workqueueid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioProcessingObjectRTQueueService_Impl::GetRealTimeWorkQueue(this) {
+                    Ok(ok__) => {
+                        workqueueid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetRealTimeWorkQueue: GetRealTimeWorkQueue::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioProcessingObjectRTQueueService {}
 windows_core::imp::define_interface!(IAudioProcessingObjectVBR, IAudioProcessingObjectVBR_Vtbl, 0x7ba1db8f_78ad_49cd_9591_f79d80a17c81);
 windows_core::imp::interface_hierarchy!(IAudioProcessingObjectVBR, windows_core::IUnknown);
@@ -1098,6 +1446,42 @@ impl IAudioProcessingObjectVBR_Vtbl {
         iid == &<IAudioProcessingObjectVBR as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioProcessingObjectVBR_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioProcessingObjectVBR_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CalcMaxInputFrames<Identity: windows_core::IUnknownImpl + IAudioProcessingObjectVBR_Impl>(this: *mut core::ffi::c_void, u32maxoutputframecount: u32, pu32inputframecount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioProcessingObjectVBR_Impl::CalcMaxInputFrames(this, core::mem::transmute_copy(&u32maxoutputframecount)) {
+                    Ok(ok__) => {
+                        pu32inputframecount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CalcMaxOutputFrames<Identity: windows_core::IUnknownImpl + IAudioProcessingObjectVBR_Impl>(this: *mut core::ffi::c_void, u32maxinputframecount: u32, pu32outputframecount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioProcessingObjectVBR_Impl::CalcMaxOutputFrames(this, core::mem::transmute_copy(&u32maxinputframecount)) {
+                    Ok(ok__) => {
+                        pu32outputframecount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CalcMaxInputFrames: CalcMaxInputFrames::<Identity>,
+            CalcMaxOutputFrames: CalcMaxOutputFrames::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioProcessingObjectVBR {}
 windows_core::imp::define_interface!(IAudioSystemEffects, IAudioSystemEffects_Vtbl, 0x5fa00f27_add6_499a_8a9d_6b98521fa75b);
 windows_core::imp::interface_hierarchy!(IAudioSystemEffects, windows_core::IUnknown);
@@ -1115,6 +1499,12 @@ impl IAudioSystemEffects_Vtbl {
         iid == &<IAudioSystemEffects as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioSystemEffects_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioSystemEffects_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IAudioSystemEffects {}
 windows_core::imp::define_interface!(IAudioSystemEffects2, IAudioSystemEffects2_Vtbl, 0xbafe99d2_7436_44ce_9e0e_4d89afbfff56);
 impl core::ops::Deref for IAudioSystemEffects2 {
@@ -1152,6 +1542,20 @@ impl IAudioSystemEffects2_Vtbl {
         iid == &<IAudioSystemEffects2 as windows_core::Interface>::IID || iid == &<IAudioSystemEffects as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioSystemEffects2_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioSystemEffects2_Vtbl
+where
+    IAudioSystemEffects_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetEffectsList<Identity: windows_core::IUnknownImpl + IAudioSystemEffects2_Impl>(this: *mut core::ffi::c_void, ppeffectsids: *mut *mut windows_core::GUID, pceffects: *mut u32, event: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioSystemEffects2_Impl::GetEffectsList(this, core::mem::transmute_copy(&ppeffectsids), core::mem::transmute_copy(&pceffects), core::mem::transmute_copy(&event)).into()
+            }
+        }
+        Self { base__: <IAudioSystemEffects_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetEffectsList: GetEffectsList::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IAudioSystemEffects2 {}
 windows_core::imp::define_interface!(IAudioSystemEffects3, IAudioSystemEffects3_Vtbl, 0xc58b31cd_fc6a_4255_bc1f_ad29bb0a4a17);
 impl core::ops::Deref for IAudioSystemEffects3 {
@@ -1204,6 +1608,30 @@ impl IAudioSystemEffects3_Vtbl {
         iid == &<IAudioSystemEffects3 as windows_core::Interface>::IID || iid == &<IAudioSystemEffects as windows_core::Interface>::IID || iid == &<IAudioSystemEffects2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioSystemEffects3_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioSystemEffects3_Vtbl
+where
+    IAudioSystemEffects2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetControllableSystemEffectsList<Identity: windows_core::IUnknownImpl + IAudioSystemEffects3_Impl>(this: *mut core::ffi::c_void, effects: *mut *mut AUDIO_SYSTEMEFFECT, numeffects: *mut u32, event: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioSystemEffects3_Impl::GetControllableSystemEffectsList(this, core::mem::transmute_copy(&effects), core::mem::transmute_copy(&numeffects), core::mem::transmute_copy(&event)).into()
+            }
+        }
+        unsafe extern "system" fn SetAudioSystemEffectState<Identity: windows_core::IUnknownImpl + IAudioSystemEffects3_Impl>(this: *mut core::ffi::c_void, effectid: windows_core::GUID, state: AUDIO_SYSTEMEFFECT_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioSystemEffects3_Impl::SetAudioSystemEffectState(this, core::mem::transmute(&effectid), core::mem::transmute_copy(&state)).into()
+            }
+        }
+        Self {
+            base__: <IAudioSystemEffects2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetControllableSystemEffectsList: GetControllableSystemEffectsList::<Identity>,
+            SetAudioSystemEffectState: SetAudioSystemEffectState::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioSystemEffects3 {}
 windows_core::imp::define_interface!(IAudioSystemEffectsCustomFormats, IAudioSystemEffectsCustomFormats_Vtbl, 0xb1176e34_bb7f_4f05_bebd_1b18a534e097);
 windows_core::imp::interface_hierarchy!(IAudioSystemEffectsCustomFormats, windows_core::IUnknown);
@@ -1289,6 +1717,55 @@ impl IAudioSystemEffectsCustomFormats_Vtbl {
         iid == &<IAudioSystemEffectsCustomFormats as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioSystemEffectsCustomFormats_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioSystemEffectsCustomFormats_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFormatCount<Identity: windows_core::IUnknownImpl + IAudioSystemEffectsCustomFormats_Impl>(this: *mut core::ffi::c_void, pcformats: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioSystemEffectsCustomFormats_Impl::GetFormatCount(this) {
+                    Ok(ok__) => {
+                        pcformats.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFormat<Identity: windows_core::IUnknownImpl + IAudioSystemEffectsCustomFormats_Impl>(this: *mut core::ffi::c_void, nformat: u32, ppformat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioSystemEffectsCustomFormats_Impl::GetFormat(this, core::mem::transmute_copy(&nformat)) {
+                    Ok(ok__) => {
+                        ppformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFormatRepresentation<Identity: windows_core::IUnknownImpl + IAudioSystemEffectsCustomFormats_Impl>(this: *mut core::ffi::c_void, nformat: u32, ppwstrformatrep: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioSystemEffectsCustomFormats_Impl::GetFormatRepresentation(this, core::mem::transmute_copy(&nformat)) {
+                    Ok(ok__) => {
+                        ppwstrformatrep.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFormatCount: GetFormatCount::<Identity>,
+            GetFormat: GetFormat::<Identity>,
+            GetFormatRepresentation: GetFormatRepresentation::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioSystemEffectsCustomFormats {}
 pub const PKEY_APO_SWFallback_ProcessingModes: super::super::super::Foundation::PROPERTYKEY = super::super::super::Foundation::PROPERTYKEY { fmtid: windows_core::GUID::from_u128(0xd3993a3f_99c2_4402_b5ec_a92a0367664b), pid: 13 };
 pub const PKEY_CompositeFX_EndpointEffectClsid: super::super::super::Foundation::PROPERTYKEY = super::super::super::Foundation::PROPERTYKEY { fmtid: windows_core::GUID::from_u128(0xd04e05a6_594b_4fb6_a80d_01af5eed7d1d), pid: 15 };
@@ -835,6 +835,80 @@ impl IDirectMusic_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Media_Audio_DirectSound")]
+impl<Identity: windows_core::IUnknownImpl + IDirectMusic_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectMusic_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumPort<Identity: windows_core::IUnknownImpl + IDirectMusic_Impl>(this: *mut core::ffi::c_void, dwindex: u32, pportcaps: *mut DMUS_PORTCAPS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusic_Impl::EnumPort(this, core::mem::transmute_copy(&dwindex), core::mem::transmute_copy(&pportcaps)).into()
+            }
+        }
+        unsafe extern "system" fn CreateMusicBuffer<Identity: windows_core::IUnknownImpl + IDirectMusic_Impl>(this: *mut core::ffi::c_void, pbufferdesc: *mut DMUS_BUFFERDESC, ppbuffer: *mut *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusic_Impl::CreateMusicBuffer(this, core::mem::transmute_copy(&pbufferdesc), core::mem::transmute_copy(&ppbuffer), core::mem::transmute_copy(&punkouter)).into()
+            }
+        }
+        unsafe extern "system" fn CreatePort<Identity: windows_core::IUnknownImpl + IDirectMusic_Impl>(this: *mut core::ffi::c_void, rclsidport: *const windows_core::GUID, pportparams: *mut DMUS_PORTPARAMS8, ppport: *mut *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusic_Impl::CreatePort(this, core::mem::transmute_copy(&rclsidport), core::mem::transmute_copy(&pportparams), core::mem::transmute_copy(&ppport), core::mem::transmute_copy(&punkouter)).into()
+            }
+        }
+        unsafe extern "system" fn EnumMasterClock<Identity: windows_core::IUnknownImpl + IDirectMusic_Impl>(this: *mut core::ffi::c_void, dwindex: u32, lpclockinfo: *mut DMUS_CLOCKINFO8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusic_Impl::EnumMasterClock(this, core::mem::transmute_copy(&dwindex), core::mem::transmute_copy(&lpclockinfo)).into()
+            }
+        }
+        unsafe extern "system" fn GetMasterClock<Identity: windows_core::IUnknownImpl + IDirectMusic_Impl>(this: *mut core::ffi::c_void, pguidclock: *mut windows_core::GUID, ppreferenceclock: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusic_Impl::GetMasterClock(this, core::mem::transmute_copy(&pguidclock), core::mem::transmute_copy(&ppreferenceclock)).into()
+            }
+        }
+        unsafe extern "system" fn SetMasterClock<Identity: windows_core::IUnknownImpl + IDirectMusic_Impl>(this: *mut core::ffi::c_void, rguidclock: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusic_Impl::SetMasterClock(this, core::mem::transmute_copy(&rguidclock)).into()
+            }
+        }
+        unsafe extern "system" fn Activate<Identity: windows_core::IUnknownImpl + IDirectMusic_Impl>(this: *mut core::ffi::c_void, fenable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusic_Impl::Activate(this, core::mem::transmute_copy(&fenable)).into()
+            }
+        }
+        unsafe extern "system" fn GetDefaultPort<Identity: windows_core::IUnknownImpl + IDirectMusic_Impl>(this: *mut core::ffi::c_void, pguidport: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusic_Impl::GetDefaultPort(this, core::mem::transmute_copy(&pguidport)).into()
+            }
+        }
+        unsafe extern "system" fn SetDirectSound<Identity: windows_core::IUnknownImpl + IDirectMusic_Impl>(this: *mut core::ffi::c_void, pdirectsound: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusic_Impl::SetDirectSound(this, core::mem::transmute_copy(&pdirectsound), core::mem::transmute_copy(&hwnd)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnumPort: EnumPort::<Identity>,
+            CreateMusicBuffer: CreateMusicBuffer::<Identity>,
+            CreatePort: CreatePort::<Identity>,
+            EnumMasterClock: EnumMasterClock::<Identity>,
+            GetMasterClock: GetMasterClock::<Identity>,
+            SetMasterClock: SetMasterClock::<Identity>,
+            Activate: Activate::<Identity>,
+            GetDefaultPort: GetDefaultPort::<Identity>,
+            SetDirectSound: SetDirectSound::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Media_Audio_DirectSound")]
 impl windows_core::RuntimeName for IDirectMusic {}
 windows_core::imp::define_interface!(IDirectMusic8, IDirectMusic8_Vtbl, 0x2d3629f7_813d_4939_8508_f05c6b75fd97);
 impl core::ops::Deref for IDirectMusic8 {
@@ -878,6 +952,21 @@ impl IDirectMusic8_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Media_Audio_DirectSound")]
+impl<Identity: windows_core::IUnknownImpl + IDirectMusic8_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectMusic8_Vtbl
+where
+    IDirectMusic_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetExternalMasterClock<Identity: windows_core::IUnknownImpl + IDirectMusic8_Impl>(this: *mut core::ffi::c_void, pclock: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusic8_Impl::SetExternalMasterClock(this, core::mem::transmute_copy(&pclock)).into()
+            }
+        }
+        Self { base__: <IDirectMusic_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetExternalMasterClock: SetExternalMasterClock::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_Media_Audio_DirectSound")]
 impl windows_core::RuntimeName for IDirectMusic8 {}
 windows_core::imp::define_interface!(IDirectMusicBuffer, IDirectMusicBuffer_Vtbl, 0xd2ac2878_b39b_11d1_8704_00600893b1bd);
 windows_core::imp::interface_hierarchy!(IDirectMusicBuffer, windows_core::IUnknown);
@@ -1056,6 +1145,107 @@ impl IDirectMusicBuffer_Vtbl {
         iid == &<IDirectMusicBuffer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectMusicBuffer_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectMusicBuffer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Flush<Identity: windows_core::IUnknownImpl + IDirectMusicBuffer_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicBuffer_Impl::Flush(this).into()
+            }
+        }
+        unsafe extern "system" fn TotalTime<Identity: windows_core::IUnknownImpl + IDirectMusicBuffer_Impl>(this: *mut core::ffi::c_void, prttime: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicBuffer_Impl::TotalTime(this, core::mem::transmute_copy(&prttime)).into()
+            }
+        }
+        unsafe extern "system" fn PackStructured<Identity: windows_core::IUnknownImpl + IDirectMusicBuffer_Impl>(this: *mut core::ffi::c_void, rt: i64, dwchannelgroup: u32, dwchannelmessage: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicBuffer_Impl::PackStructured(this, core::mem::transmute_copy(&rt), core::mem::transmute_copy(&dwchannelgroup), core::mem::transmute_copy(&dwchannelmessage)).into()
+            }
+        }
+        unsafe extern "system" fn PackUnstructured<Identity: windows_core::IUnknownImpl + IDirectMusicBuffer_Impl>(this: *mut core::ffi::c_void, rt: i64, dwchannelgroup: u32, cb: u32, lpb: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicBuffer_Impl::PackUnstructured(this, core::mem::transmute_copy(&rt), core::mem::transmute_copy(&dwchannelgroup), core::mem::transmute_copy(&cb), core::mem::transmute_copy(&lpb)).into()
+            }
+        }
+        unsafe extern "system" fn ResetReadPtr<Identity: windows_core::IUnknownImpl + IDirectMusicBuffer_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicBuffer_Impl::ResetReadPtr(this).into()
+            }
+        }
+        unsafe extern "system" fn GetNextEvent<Identity: windows_core::IUnknownImpl + IDirectMusicBuffer_Impl>(this: *mut core::ffi::c_void, prt: *mut i64, pdwchannelgroup: *mut u32, pdwlength: *mut u32, ppdata: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicBuffer_Impl::GetNextEvent(this, core::mem::transmute_copy(&prt), core::mem::transmute_copy(&pdwchannelgroup), core::mem::transmute_copy(&pdwlength), core::mem::transmute_copy(&ppdata)).into()
+            }
+        }
+        unsafe extern "system" fn GetRawBufferPtr<Identity: windows_core::IUnknownImpl + IDirectMusicBuffer_Impl>(this: *mut core::ffi::c_void, ppdata: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicBuffer_Impl::GetRawBufferPtr(this, core::mem::transmute_copy(&ppdata)).into()
+            }
+        }
+        unsafe extern "system" fn GetStartTime<Identity: windows_core::IUnknownImpl + IDirectMusicBuffer_Impl>(this: *mut core::ffi::c_void, prt: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicBuffer_Impl::GetStartTime(this, core::mem::transmute_copy(&prt)).into()
+            }
+        }
+        unsafe extern "system" fn GetUsedBytes<Identity: windows_core::IUnknownImpl + IDirectMusicBuffer_Impl>(this: *mut core::ffi::c_void, pcb: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicBuffer_Impl::GetUsedBytes(this, core::mem::transmute_copy(&pcb)).into()
+            }
+        }
+        unsafe extern "system" fn GetMaxBytes<Identity: windows_core::IUnknownImpl + IDirectMusicBuffer_Impl>(this: *mut core::ffi::c_void, pcb: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicBuffer_Impl::GetMaxBytes(this, core::mem::transmute_copy(&pcb)).into()
+            }
+        }
+        unsafe extern "system" fn GetBufferFormat<Identity: windows_core::IUnknownImpl + IDirectMusicBuffer_Impl>(this: *mut core::ffi::c_void, pguidformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicBuffer_Impl::GetBufferFormat(this, core::mem::transmute_copy(&pguidformat)).into()
+            }
+        }
+        unsafe extern "system" fn SetStartTime<Identity: windows_core::IUnknownImpl + IDirectMusicBuffer_Impl>(this: *mut core::ffi::c_void, rt: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicBuffer_Impl::SetStartTime(this, core::mem::transmute_copy(&rt)).into()
+            }
+        }
+        unsafe extern "system" fn SetUsedBytes<Identity: windows_core::IUnknownImpl + IDirectMusicBuffer_Impl>(this: *mut core::ffi::c_void, cb: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicBuffer_Impl::SetUsedBytes(this, core::mem::transmute_copy(&cb)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Flush: Flush::<Identity>,
+            TotalTime: TotalTime::<Identity>,
+            PackStructured: PackStructured::<Identity>,
+            PackUnstructured: PackUnstructured::<Identity>,
+            ResetReadPtr: ResetReadPtr::<Identity>,
+            GetNextEvent: GetNextEvent::<Identity>,
+            GetRawBufferPtr: GetRawBufferPtr::<Identity>,
+            GetStartTime: GetStartTime::<Identity>,
+            GetUsedBytes: GetUsedBytes::<Identity>,
+            GetMaxBytes: GetMaxBytes::<Identity>,
+            GetBufferFormat: GetBufferFormat::<Identity>,
+            SetStartTime: SetStartTime::<Identity>,
+            SetUsedBytes: SetUsedBytes::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectMusicBuffer {}
 windows_core::imp::define_interface!(IDirectMusicCollection, IDirectMusicCollection_Vtbl, 0xd2ac287c_b39b_11d1_8704_00600893b1bd);
 windows_core::imp::interface_hierarchy!(IDirectMusicCollection, windows_core::IUnknown);
@@ -1114,6 +1304,36 @@ impl IDirectMusicCollection_Vtbl {
         iid == &<IDirectMusicCollection as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectMusicCollection_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectMusicCollection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetInstrument<Identity: windows_core::IUnknownImpl + IDirectMusicCollection_Impl>(this: *mut core::ffi::c_void, dwpatch: u32, ppinstrument: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectMusicCollection_Impl::GetInstrument(this, core::mem::transmute_copy(&dwpatch)) {
+                    Ok(ok__) => {
+                        ppinstrument.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumInstrument<Identity: windows_core::IUnknownImpl + IDirectMusicCollection_Impl>(this: *mut core::ffi::c_void, dwindex: u32, pdwpatch: *mut u32, pwszname: windows_core::PCWSTR, dwnamelen: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicCollection_Impl::EnumInstrument(this, core::mem::transmute_copy(&dwindex), core::mem::transmute_copy(&pdwpatch), core::mem::transmute(&pwszname), core::mem::transmute_copy(&dwnamelen)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetInstrument: GetInstrument::<Identity>,
+            EnumInstrument: EnumInstrument::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectMusicCollection {}
 windows_core::imp::define_interface!(IDirectMusicDownload, IDirectMusicDownload_Vtbl, 0xd2ac287b_b39b_11d1_8704_00600893b1bd);
 windows_core::imp::interface_hierarchy!(IDirectMusicDownload, windows_core::IUnknown);
@@ -1145,6 +1365,20 @@ impl IDirectMusicDownload_Vtbl {
         iid == &<IDirectMusicDownload as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectMusicDownload_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectMusicDownload_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetBuffer<Identity: windows_core::IUnknownImpl + IDirectMusicDownload_Impl>(this: *mut core::ffi::c_void, ppvbuffer: *mut *mut core::ffi::c_void, pdwsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicDownload_Impl::GetBuffer(this, core::mem::transmute_copy(&ppvbuffer), core::mem::transmute_copy(&pdwsize)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetBuffer: GetBuffer::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDirectMusicDownload {}
 windows_core::imp::define_interface!(IDirectMusicDownloadedInstrument, IDirectMusicDownloadedInstrument_Vtbl, 0xd2ac287e_b39b_11d1_8704_00600893b1bd);
 windows_core::imp::interface_hierarchy!(IDirectMusicDownloadedInstrument, windows_core::IUnknown);
@@ -1162,6 +1396,12 @@ impl IDirectMusicDownloadedInstrument_Vtbl {
         iid == &<IDirectMusicDownloadedInstrument as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectMusicDownloadedInstrument_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectMusicDownloadedInstrument_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IDirectMusicDownloadedInstrument {}
 windows_core::imp::define_interface!(IDirectMusicInstrument, IDirectMusicInstrument_Vtbl, 0xd2ac287d_b39b_11d1_8704_00600893b1bd);
 windows_core::imp::interface_hierarchy!(IDirectMusicInstrument, windows_core::IUnknown);
@@ -1204,6 +1444,30 @@ impl IDirectMusicInstrument_Vtbl {
         iid == &<IDirectMusicInstrument as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectMusicInstrument_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectMusicInstrument_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPatch<Identity: windows_core::IUnknownImpl + IDirectMusicInstrument_Impl>(this: *mut core::ffi::c_void, pdwpatch: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicInstrument_Impl::GetPatch(this, core::mem::transmute_copy(&pdwpatch)).into()
+            }
+        }
+        unsafe extern "system" fn SetPatch<Identity: windows_core::IUnknownImpl + IDirectMusicInstrument_Impl>(this: *mut core::ffi::c_void, dwpatch: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicInstrument_Impl::SetPatch(this, core::mem::transmute_copy(&dwpatch)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPatch: GetPatch::<Identity>,
+            SetPatch: SetPatch::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectMusicInstrument {}
 windows_core::imp::define_interface!(IDirectMusicPort, IDirectMusicPort_Vtbl, 0x08f2d8c9_37c2_11d2_b9f9_0000f875ac12);
 windows_core::imp::interface_hierarchy!(IDirectMusicPort, windows_core::IUnknown);
@@ -1466,6 +1730,142 @@ impl IDirectMusicPort_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Media_Audio_DirectSound", feature = "Win32_System_IO"))]
+impl<Identity: windows_core::IUnknownImpl + IDirectMusicPort_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectMusicPort_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn PlayBuffer<Identity: windows_core::IUnknownImpl + IDirectMusicPort_Impl>(this: *mut core::ffi::c_void, pbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicPort_Impl::PlayBuffer(this, core::mem::transmute_copy(&pbuffer)).into()
+            }
+        }
+        unsafe extern "system" fn SetReadNotificationHandle<Identity: windows_core::IUnknownImpl + IDirectMusicPort_Impl>(this: *mut core::ffi::c_void, hevent: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicPort_Impl::SetReadNotificationHandle(this, core::mem::transmute_copy(&hevent)).into()
+            }
+        }
+        unsafe extern "system" fn Read<Identity: windows_core::IUnknownImpl + IDirectMusicPort_Impl>(this: *mut core::ffi::c_void, pbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicPort_Impl::Read(this, core::mem::transmute_copy(&pbuffer)).into()
+            }
+        }
+        unsafe extern "system" fn DownloadInstrument<Identity: windows_core::IUnknownImpl + IDirectMusicPort_Impl>(this: *mut core::ffi::c_void, pinstrument: *mut core::ffi::c_void, ppdownloadedinstrument: *mut *mut core::ffi::c_void, pnoteranges: *mut DMUS_NOTERANGE, dwnumnoteranges: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicPort_Impl::DownloadInstrument(this, core::mem::transmute_copy(&pinstrument), core::mem::transmute_copy(&ppdownloadedinstrument), core::mem::transmute_copy(&pnoteranges), core::mem::transmute_copy(&dwnumnoteranges)).into()
+            }
+        }
+        unsafe extern "system" fn UnloadInstrument<Identity: windows_core::IUnknownImpl + IDirectMusicPort_Impl>(this: *mut core::ffi::c_void, pdownloadedinstrument: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicPort_Impl::UnloadInstrument(this, core::mem::transmute_copy(&pdownloadedinstrument)).into()
+            }
+        }
+        unsafe extern "system" fn GetLatencyClock<Identity: windows_core::IUnknownImpl + IDirectMusicPort_Impl>(this: *mut core::ffi::c_void, ppclock: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectMusicPort_Impl::GetLatencyClock(this) {
+                    Ok(ok__) => {
+                        ppclock.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRunningStats<Identity: windows_core::IUnknownImpl + IDirectMusicPort_Impl>(this: *mut core::ffi::c_void, pstats: *mut DMUS_SYNTHSTATS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicPort_Impl::GetRunningStats(this, core::mem::transmute_copy(&pstats)).into()
+            }
+        }
+        unsafe extern "system" fn Compact<Identity: windows_core::IUnknownImpl + IDirectMusicPort_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicPort_Impl::Compact(this).into()
+            }
+        }
+        unsafe extern "system" fn GetCaps<Identity: windows_core::IUnknownImpl + IDirectMusicPort_Impl>(this: *mut core::ffi::c_void, pportcaps: *mut DMUS_PORTCAPS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicPort_Impl::GetCaps(this, core::mem::transmute_copy(&pportcaps)).into()
+            }
+        }
+        unsafe extern "system" fn DeviceIoControl<Identity: windows_core::IUnknownImpl + IDirectMusicPort_Impl>(this: *mut core::ffi::c_void, dwiocontrolcode: u32, lpinbuffer: *mut core::ffi::c_void, ninbuffersize: u32, lpoutbuffer: *mut core::ffi::c_void, noutbuffersize: u32, lpbytesreturned: *mut u32, lpoverlapped: *mut super::super::super::System::IO::OVERLAPPED) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicPort_Impl::DeviceIoControl(this, core::mem::transmute_copy(&dwiocontrolcode), core::mem::transmute_copy(&lpinbuffer), core::mem::transmute_copy(&ninbuffersize), core::mem::transmute_copy(&lpoutbuffer), core::mem::transmute_copy(&noutbuffersize), core::mem::transmute_copy(&lpbytesreturned), core::mem::transmute_copy(&lpoverlapped)).into()
+            }
+        }
+        unsafe extern "system" fn SetNumChannelGroups<Identity: windows_core::IUnknownImpl + IDirectMusicPort_Impl>(this: *mut core::ffi::c_void, dwchannelgroups: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicPort_Impl::SetNumChannelGroups(this, core::mem::transmute_copy(&dwchannelgroups)).into()
+            }
+        }
+        unsafe extern "system" fn GetNumChannelGroups<Identity: windows_core::IUnknownImpl + IDirectMusicPort_Impl>(this: *mut core::ffi::c_void, pdwchannelgroups: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicPort_Impl::GetNumChannelGroups(this, core::mem::transmute_copy(&pdwchannelgroups)).into()
+            }
+        }
+        unsafe extern "system" fn Activate<Identity: windows_core::IUnknownImpl + IDirectMusicPort_Impl>(this: *mut core::ffi::c_void, factive: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicPort_Impl::Activate(this, core::mem::transmute_copy(&factive)).into()
+            }
+        }
+        unsafe extern "system" fn SetChannelPriority<Identity: windows_core::IUnknownImpl + IDirectMusicPort_Impl>(this: *mut core::ffi::c_void, dwchannelgroup: u32, dwchannel: u32, dwpriority: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicPort_Impl::SetChannelPriority(this, core::mem::transmute_copy(&dwchannelgroup), core::mem::transmute_copy(&dwchannel), core::mem::transmute_copy(&dwpriority)).into()
+            }
+        }
+        unsafe extern "system" fn GetChannelPriority<Identity: windows_core::IUnknownImpl + IDirectMusicPort_Impl>(this: *mut core::ffi::c_void, dwchannelgroup: u32, dwchannel: u32, pdwpriority: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicPort_Impl::GetChannelPriority(this, core::mem::transmute_copy(&dwchannelgroup), core::mem::transmute_copy(&dwchannel), core::mem::transmute_copy(&pdwpriority)).into()
+            }
+        }
+        unsafe extern "system" fn SetDirectSound<Identity: windows_core::IUnknownImpl + IDirectMusicPort_Impl>(this: *mut core::ffi::c_void, pdirectsound: *mut core::ffi::c_void, pdirectsoundbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicPort_Impl::SetDirectSound(this, core::mem::transmute_copy(&pdirectsound), core::mem::transmute_copy(&pdirectsoundbuffer)).into()
+            }
+        }
+        unsafe extern "system" fn GetFormat<Identity: windows_core::IUnknownImpl + IDirectMusicPort_Impl>(this: *mut core::ffi::c_void, pwaveformatex: *mut super::WAVEFORMATEX, pdwwaveformatexsize: *mut u32, pdwbuffersize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicPort_Impl::GetFormat(this, core::mem::transmute_copy(&pwaveformatex), core::mem::transmute_copy(&pdwwaveformatexsize), core::mem::transmute_copy(&pdwbuffersize)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PlayBuffer: PlayBuffer::<Identity>,
+            SetReadNotificationHandle: SetReadNotificationHandle::<Identity>,
+            Read: Read::<Identity>,
+            DownloadInstrument: DownloadInstrument::<Identity>,
+            UnloadInstrument: UnloadInstrument::<Identity>,
+            GetLatencyClock: GetLatencyClock::<Identity>,
+            GetRunningStats: GetRunningStats::<Identity>,
+            Compact: Compact::<Identity>,
+            GetCaps: GetCaps::<Identity>,
+            DeviceIoControl: DeviceIoControl::<Identity>,
+            SetNumChannelGroups: SetNumChannelGroups::<Identity>,
+            GetNumChannelGroups: GetNumChannelGroups::<Identity>,
+            Activate: Activate::<Identity>,
+            SetChannelPriority: SetChannelPriority::<Identity>,
+            GetChannelPriority: GetChannelPriority::<Identity>,
+            SetDirectSound: SetDirectSound::<Identity>,
+            GetFormat: GetFormat::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Media_Audio_DirectSound", feature = "Win32_System_IO"))]
 impl windows_core::RuntimeName for IDirectMusicPort {}
 windows_core::imp::define_interface!(IDirectMusicPortDownload, IDirectMusicPortDownload_Vtbl, 0xd2ac287a_b39b_11d1_8704_00600893b1bd);
 windows_core::imp::interface_hierarchy!(IDirectMusicPortDownload, windows_core::IUnknown);
@@ -1584,6 +1984,70 @@ impl IDirectMusicPortDownload_Vtbl {
         iid == &<IDirectMusicPortDownload as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectMusicPortDownload_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectMusicPortDownload_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetBuffer<Identity: windows_core::IUnknownImpl + IDirectMusicPortDownload_Impl>(this: *mut core::ffi::c_void, dwdlid: u32, ppidmdownload: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectMusicPortDownload_Impl::GetBuffer(this, core::mem::transmute_copy(&dwdlid)) {
+                    Ok(ok__) => {
+                        ppidmdownload.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AllocateBuffer<Identity: windows_core::IUnknownImpl + IDirectMusicPortDownload_Impl>(this: *mut core::ffi::c_void, dwsize: u32, ppidmdownload: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectMusicPortDownload_Impl::AllocateBuffer(this, core::mem::transmute_copy(&dwsize)) {
+                    Ok(ok__) => {
+                        ppidmdownload.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDLId<Identity: windows_core::IUnknownImpl + IDirectMusicPortDownload_Impl>(this: *mut core::ffi::c_void, pdwstartdlid: *mut u32, dwcount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicPortDownload_Impl::GetDLId(this, core::mem::transmute_copy(&pdwstartdlid), core::mem::transmute_copy(&dwcount)).into()
+            }
+        }
+        unsafe extern "system" fn GetAppend<Identity: windows_core::IUnknownImpl + IDirectMusicPortDownload_Impl>(this: *mut core::ffi::c_void, pdwappend: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicPortDownload_Impl::GetAppend(this, core::mem::transmute_copy(&pdwappend)).into()
+            }
+        }
+        unsafe extern "system" fn Download<Identity: windows_core::IUnknownImpl + IDirectMusicPortDownload_Impl>(this: *mut core::ffi::c_void, pidmdownload: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicPortDownload_Impl::Download(this, core::mem::transmute_copy(&pidmdownload)).into()
+            }
+        }
+        unsafe extern "system" fn Unload<Identity: windows_core::IUnknownImpl + IDirectMusicPortDownload_Impl>(this: *mut core::ffi::c_void, pidmdownload: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicPortDownload_Impl::Unload(this, core::mem::transmute_copy(&pidmdownload)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetBuffer: GetBuffer::<Identity>,
+            AllocateBuffer: AllocateBuffer::<Identity>,
+            GetDLId: GetDLId::<Identity>,
+            GetAppend: GetAppend::<Identity>,
+            Download: Download::<Identity>,
+            Unload: Unload::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectMusicPortDownload {}
 windows_core::imp::define_interface!(IDirectMusicSynth, IDirectMusicSynth_Vtbl, 0x09823661_5c85_11d2_afa6_00aa0024d8b6);
 windows_core::imp::interface_hierarchy!(IDirectMusicSynth, windows_core::IUnknown);
@@ -1825,6 +2289,141 @@ impl IDirectMusicSynth_Vtbl {
         iid == &<IDirectMusicSynth as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectMusicSynth_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectMusicSynth_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Open<Identity: windows_core::IUnknownImpl + IDirectMusicSynth_Impl>(this: *mut core::ffi::c_void, pportparams: *mut DMUS_PORTPARAMS8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynth_Impl::Open(this, core::mem::transmute_copy(&pportparams)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IDirectMusicSynth_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynth_Impl::Close(this).into()
+            }
+        }
+        unsafe extern "system" fn SetNumChannelGroups<Identity: windows_core::IUnknownImpl + IDirectMusicSynth_Impl>(this: *mut core::ffi::c_void, dwgroups: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynth_Impl::SetNumChannelGroups(this, core::mem::transmute_copy(&dwgroups)).into()
+            }
+        }
+        unsafe extern "system" fn Download<Identity: windows_core::IUnknownImpl + IDirectMusicSynth_Impl>(this: *mut core::ffi::c_void, phdownload: *mut super::super::super::Foundation::HANDLE, pvdata: *mut core::ffi::c_void, pbfree: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynth_Impl::Download(this, core::mem::transmute_copy(&phdownload), core::mem::transmute_copy(&pvdata), core::mem::transmute_copy(&pbfree)).into()
+            }
+        }
+        unsafe extern "system" fn Unload<Identity: windows_core::IUnknownImpl + IDirectMusicSynth_Impl>(this: *mut core::ffi::c_void, hdownload: super::super::super::Foundation::HANDLE, lpfreehandle: isize, huserdata: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynth_Impl::Unload(this, core::mem::transmute_copy(&hdownload), core::mem::transmute_copy(&lpfreehandle), core::mem::transmute_copy(&huserdata)).into()
+            }
+        }
+        unsafe extern "system" fn PlayBuffer<Identity: windows_core::IUnknownImpl + IDirectMusicSynth_Impl>(this: *mut core::ffi::c_void, rt: i64, pbbuffer: *mut u8, cbbuffer: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynth_Impl::PlayBuffer(this, core::mem::transmute_copy(&rt), core::mem::transmute_copy(&pbbuffer), core::mem::transmute_copy(&cbbuffer)).into()
+            }
+        }
+        unsafe extern "system" fn GetRunningStats<Identity: windows_core::IUnknownImpl + IDirectMusicSynth_Impl>(this: *mut core::ffi::c_void, pstats: *mut DMUS_SYNTHSTATS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynth_Impl::GetRunningStats(this, core::mem::transmute_copy(&pstats)).into()
+            }
+        }
+        unsafe extern "system" fn GetPortCaps<Identity: windows_core::IUnknownImpl + IDirectMusicSynth_Impl>(this: *mut core::ffi::c_void, pcaps: *mut DMUS_PORTCAPS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynth_Impl::GetPortCaps(this, core::mem::transmute_copy(&pcaps)).into()
+            }
+        }
+        unsafe extern "system" fn SetMasterClock<Identity: windows_core::IUnknownImpl + IDirectMusicSynth_Impl>(this: *mut core::ffi::c_void, pclock: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynth_Impl::SetMasterClock(this, core::mem::transmute_copy(&pclock)).into()
+            }
+        }
+        unsafe extern "system" fn GetLatencyClock<Identity: windows_core::IUnknownImpl + IDirectMusicSynth_Impl>(this: *mut core::ffi::c_void, ppclock: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectMusicSynth_Impl::GetLatencyClock(this) {
+                    Ok(ok__) => {
+                        ppclock.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Activate<Identity: windows_core::IUnknownImpl + IDirectMusicSynth_Impl>(this: *mut core::ffi::c_void, fenable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynth_Impl::Activate(this, core::mem::transmute_copy(&fenable)).into()
+            }
+        }
+        unsafe extern "system" fn SetSynthSink<Identity: windows_core::IUnknownImpl + IDirectMusicSynth_Impl>(this: *mut core::ffi::c_void, psynthsink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynth_Impl::SetSynthSink(this, core::mem::transmute_copy(&psynthsink)).into()
+            }
+        }
+        unsafe extern "system" fn Render<Identity: windows_core::IUnknownImpl + IDirectMusicSynth_Impl>(this: *mut core::ffi::c_void, pbuffer: *mut i16, dwlength: u32, llposition: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynth_Impl::Render(this, core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&dwlength), core::mem::transmute_copy(&llposition)).into()
+            }
+        }
+        unsafe extern "system" fn SetChannelPriority<Identity: windows_core::IUnknownImpl + IDirectMusicSynth_Impl>(this: *mut core::ffi::c_void, dwchannelgroup: u32, dwchannel: u32, dwpriority: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynth_Impl::SetChannelPriority(this, core::mem::transmute_copy(&dwchannelgroup), core::mem::transmute_copy(&dwchannel), core::mem::transmute_copy(&dwpriority)).into()
+            }
+        }
+        unsafe extern "system" fn GetChannelPriority<Identity: windows_core::IUnknownImpl + IDirectMusicSynth_Impl>(this: *mut core::ffi::c_void, dwchannelgroup: u32, dwchannel: u32, pdwpriority: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynth_Impl::GetChannelPriority(this, core::mem::transmute_copy(&dwchannelgroup), core::mem::transmute_copy(&dwchannel), core::mem::transmute_copy(&pdwpriority)).into()
+            }
+        }
+        unsafe extern "system" fn GetFormat<Identity: windows_core::IUnknownImpl + IDirectMusicSynth_Impl>(this: *mut core::ffi::c_void, pwaveformatex: *mut super::WAVEFORMATEX, pdwwaveformatexsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynth_Impl::GetFormat(this, core::mem::transmute_copy(&pwaveformatex), core::mem::transmute_copy(&pdwwaveformatexsize)).into()
+            }
+        }
+        unsafe extern "system" fn GetAppend<Identity: windows_core::IUnknownImpl + IDirectMusicSynth_Impl>(this: *mut core::ffi::c_void, pdwappend: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynth_Impl::GetAppend(this, core::mem::transmute_copy(&pdwappend)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Open: Open::<Identity>,
+            Close: Close::<Identity>,
+            SetNumChannelGroups: SetNumChannelGroups::<Identity>,
+            Download: Download::<Identity>,
+            Unload: Unload::<Identity>,
+            PlayBuffer: PlayBuffer::<Identity>,
+            GetRunningStats: GetRunningStats::<Identity>,
+            GetPortCaps: GetPortCaps::<Identity>,
+            SetMasterClock: SetMasterClock::<Identity>,
+            GetLatencyClock: GetLatencyClock::<Identity>,
+            Activate: Activate::<Identity>,
+            SetSynthSink: SetSynthSink::<Identity>,
+            Render: Render::<Identity>,
+            SetChannelPriority: SetChannelPriority::<Identity>,
+            GetChannelPriority: GetChannelPriority::<Identity>,
+            GetFormat: GetFormat::<Identity>,
+            GetAppend: GetAppend::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectMusicSynth {}
 windows_core::imp::define_interface!(IDirectMusicSynth8, IDirectMusicSynth8_Vtbl, 0x53cab625_2711_4c9f_9de7_1b7f925f6fc8);
 impl core::ops::Deref for IDirectMusicSynth8 {
@@ -1913,6 +2512,51 @@ impl IDirectMusicSynth8_Vtbl {
         iid == &<IDirectMusicSynth8 as windows_core::Interface>::IID || iid == &<IDirectMusicSynth as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectMusicSynth8_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectMusicSynth8_Vtbl
+where
+    IDirectMusicSynth_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn PlayVoice<Identity: windows_core::IUnknownImpl + IDirectMusicSynth8_Impl>(this: *mut core::ffi::c_void, rt: i64, dwvoiceid: u32, dwchannelgroup: u32, dwchannel: u32, dwdlid: u32, prpitch: i32, vrvolume: i32, stvoicestart: u64, stloopstart: u64, stloopend: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynth8_Impl::PlayVoice(this, core::mem::transmute_copy(&rt), core::mem::transmute_copy(&dwvoiceid), core::mem::transmute_copy(&dwchannelgroup), core::mem::transmute_copy(&dwchannel), core::mem::transmute_copy(&dwdlid), core::mem::transmute_copy(&prpitch), core::mem::transmute_copy(&vrvolume), core::mem::transmute_copy(&stvoicestart), core::mem::transmute_copy(&stloopstart), core::mem::transmute_copy(&stloopend)).into()
+            }
+        }
+        unsafe extern "system" fn StopVoice<Identity: windows_core::IUnknownImpl + IDirectMusicSynth8_Impl>(this: *mut core::ffi::c_void, rt: i64, dwvoiceid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynth8_Impl::StopVoice(this, core::mem::transmute_copy(&rt), core::mem::transmute_copy(&dwvoiceid)).into()
+            }
+        }
+        unsafe extern "system" fn GetVoiceState<Identity: windows_core::IUnknownImpl + IDirectMusicSynth8_Impl>(this: *mut core::ffi::c_void, dwvoice: *mut u32, cbvoice: u32, dwvoicestate: *mut DMUS_VOICE_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynth8_Impl::GetVoiceState(this, core::mem::transmute_copy(&dwvoice), core::mem::transmute_copy(&cbvoice), core::mem::transmute_copy(&dwvoicestate)).into()
+            }
+        }
+        unsafe extern "system" fn Refresh<Identity: windows_core::IUnknownImpl + IDirectMusicSynth8_Impl>(this: *mut core::ffi::c_void, dwdownloadid: u32, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynth8_Impl::Refresh(this, core::mem::transmute_copy(&dwdownloadid), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn AssignChannelToBuses<Identity: windows_core::IUnknownImpl + IDirectMusicSynth8_Impl>(this: *mut core::ffi::c_void, dwchannelgroup: u32, dwchannel: u32, pdwbuses: *mut u32, cbuses: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynth8_Impl::AssignChannelToBuses(this, core::mem::transmute_copy(&dwchannelgroup), core::mem::transmute_copy(&dwchannel), core::mem::transmute_copy(&pdwbuses), core::mem::transmute_copy(&cbuses)).into()
+            }
+        }
+        Self {
+            base__: <IDirectMusicSynth_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PlayVoice: PlayVoice::<Identity>,
+            StopVoice: StopVoice::<Identity>,
+            GetVoiceState: GetVoiceState::<Identity>,
+            Refresh: Refresh::<Identity>,
+            AssignChannelToBuses: AssignChannelToBuses::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectMusicSynth8 {}
 windows_core::imp::define_interface!(IDirectMusicSynthSink, IDirectMusicSynthSink_Vtbl, 0x09823663_5c85_11d2_afa6_00aa0024d8b6);
 windows_core::imp::interface_hierarchy!(IDirectMusicSynthSink, windows_core::IUnknown);
@@ -2057,6 +2701,79 @@ impl IDirectMusicSynthSink_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Media_Audio_DirectSound")]
+impl<Identity: windows_core::IUnknownImpl + IDirectMusicSynthSink_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectMusicSynthSink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Init<Identity: windows_core::IUnknownImpl + IDirectMusicSynthSink_Impl>(this: *mut core::ffi::c_void, psynth: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynthSink_Impl::Init(this, core::mem::transmute_copy(&psynth)).into()
+            }
+        }
+        unsafe extern "system" fn SetMasterClock<Identity: windows_core::IUnknownImpl + IDirectMusicSynthSink_Impl>(this: *mut core::ffi::c_void, pclock: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynthSink_Impl::SetMasterClock(this, core::mem::transmute_copy(&pclock)).into()
+            }
+        }
+        unsafe extern "system" fn GetLatencyClock<Identity: windows_core::IUnknownImpl + IDirectMusicSynthSink_Impl>(this: *mut core::ffi::c_void, ppclock: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectMusicSynthSink_Impl::GetLatencyClock(this) {
+                    Ok(ok__) => {
+                        ppclock.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Activate<Identity: windows_core::IUnknownImpl + IDirectMusicSynthSink_Impl>(this: *mut core::ffi::c_void, fenable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynthSink_Impl::Activate(this, core::mem::transmute_copy(&fenable)).into()
+            }
+        }
+        unsafe extern "system" fn SampleToRefTime<Identity: windows_core::IUnknownImpl + IDirectMusicSynthSink_Impl>(this: *mut core::ffi::c_void, llsampletime: i64, prftime: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynthSink_Impl::SampleToRefTime(this, core::mem::transmute_copy(&llsampletime), core::mem::transmute_copy(&prftime)).into()
+            }
+        }
+        unsafe extern "system" fn RefTimeToSample<Identity: windows_core::IUnknownImpl + IDirectMusicSynthSink_Impl>(this: *mut core::ffi::c_void, rftime: i64, pllsampletime: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynthSink_Impl::RefTimeToSample(this, core::mem::transmute_copy(&rftime), core::mem::transmute_copy(&pllsampletime)).into()
+            }
+        }
+        unsafe extern "system" fn SetDirectSound<Identity: windows_core::IUnknownImpl + IDirectMusicSynthSink_Impl>(this: *mut core::ffi::c_void, pdirectsound: *mut core::ffi::c_void, pdirectsoundbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynthSink_Impl::SetDirectSound(this, core::mem::transmute_copy(&pdirectsound), core::mem::transmute_copy(&pdirectsoundbuffer)).into()
+            }
+        }
+        unsafe extern "system" fn GetDesiredBufferSize<Identity: windows_core::IUnknownImpl + IDirectMusicSynthSink_Impl>(this: *mut core::ffi::c_void, pdwbuffersizeinsamples: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicSynthSink_Impl::GetDesiredBufferSize(this, core::mem::transmute_copy(&pdwbuffersizeinsamples)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Init: Init::<Identity>,
+            SetMasterClock: SetMasterClock::<Identity>,
+            GetLatencyClock: GetLatencyClock::<Identity>,
+            Activate: Activate::<Identity>,
+            SampleToRefTime: SampleToRefTime::<Identity>,
+            RefTimeToSample: RefTimeToSample::<Identity>,
+            SetDirectSound: SetDirectSound::<Identity>,
+            GetDesiredBufferSize: GetDesiredBufferSize::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Media_Audio_DirectSound")]
 impl windows_core::RuntimeName for IDirectMusicSynthSink {}
 windows_core::imp::define_interface!(IDirectMusicThru, IDirectMusicThru_Vtbl, 0xced153e7_3606_11d2_b9f9_0000f875ac12);
 windows_core::imp::interface_hierarchy!(IDirectMusicThru, windows_core::IUnknown);
@@ -2091,6 +2808,20 @@ impl IDirectMusicThru_Vtbl {
         iid == &<IDirectMusicThru as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectMusicThru_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectMusicThru_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ThruChannel<Identity: windows_core::IUnknownImpl + IDirectMusicThru_Impl>(this: *mut core::ffi::c_void, dwsourcechannelgroup: u32, dwsourcechannel: u32, dwdestinationchannelgroup: u32, dwdestinationchannel: u32, pdestinationport: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectMusicThru_Impl::ThruChannel(this, core::mem::transmute_copy(&dwsourcechannelgroup), core::mem::transmute_copy(&dwsourcechannel), core::mem::transmute_copy(&dwdestinationchannelgroup), core::mem::transmute_copy(&dwdestinationchannel), core::mem::transmute_copy(&pdestinationport)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ThruChannel: ThruChannel::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDirectMusicThru {}
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
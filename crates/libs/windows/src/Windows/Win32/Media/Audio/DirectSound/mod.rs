@@ -793,6 +793,84 @@ impl IDirectSound_Vtbl {
         iid == &<IDirectSound as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectSound_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectSound_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateSoundBuffer<Identity: windows_core::IUnknownImpl + IDirectSound_Impl>(this: *mut core::ffi::c_void, pcdsbufferdesc: *const DSBUFFERDESC, ppdsbuffer: *mut *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound_Impl::CreateSoundBuffer(this, core::mem::transmute_copy(&pcdsbufferdesc), core::mem::transmute_copy(&ppdsbuffer), core::mem::transmute_copy(&punkouter)).into()
+            }
+        }
+        unsafe extern "system" fn GetCaps<Identity: windows_core::IUnknownImpl + IDirectSound_Impl>(this: *mut core::ffi::c_void, pdscaps: *mut DSCAPS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound_Impl::GetCaps(this, core::mem::transmute_copy(&pdscaps)).into()
+            }
+        }
+        unsafe extern "system" fn DuplicateSoundBuffer<Identity: windows_core::IUnknownImpl + IDirectSound_Impl>(this: *mut core::ffi::c_void, pdsbufferoriginal: *mut core::ffi::c_void, ppdsbufferduplicate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSound_Impl::DuplicateSoundBuffer(this, core::mem::transmute_copy(&pdsbufferoriginal)) {
+                    Ok(ok__) => {
+                        ppdsbufferduplicate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCooperativeLevel<Identity: windows_core::IUnknownImpl + IDirectSound_Impl>(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, dwlevel: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound_Impl::SetCooperativeLevel(this, core::mem::transmute_copy(&hwnd), core::mem::transmute_copy(&dwlevel)).into()
+            }
+        }
+        unsafe extern "system" fn Compact<Identity: windows_core::IUnknownImpl + IDirectSound_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound_Impl::Compact(this).into()
+            }
+        }
+        unsafe extern "system" fn GetSpeakerConfig<Identity: windows_core::IUnknownImpl + IDirectSound_Impl>(this: *mut core::ffi::c_void, pdwspeakerconfig: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSound_Impl::GetSpeakerConfig(this) {
+                    Ok(ok__) => {
+                        pdwspeakerconfig.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSpeakerConfig<Identity: windows_core::IUnknownImpl + IDirectSound_Impl>(this: *mut core::ffi::c_void, dwspeakerconfig: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound_Impl::SetSpeakerConfig(this, core::mem::transmute_copy(&dwspeakerconfig)).into()
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectSound_Impl>(this: *mut core::ffi::c_void, pcguiddevice: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound_Impl::Initialize(this, core::mem::transmute_copy(&pcguiddevice)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateSoundBuffer: CreateSoundBuffer::<Identity>,
+            GetCaps: GetCaps::<Identity>,
+            DuplicateSoundBuffer: DuplicateSoundBuffer::<Identity>,
+            SetCooperativeLevel: SetCooperativeLevel::<Identity>,
+            Compact: Compact::<Identity>,
+            GetSpeakerConfig: GetSpeakerConfig::<Identity>,
+            SetSpeakerConfig: SetSpeakerConfig::<Identity>,
+            Initialize: Initialize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectSound {}
 windows_core::imp::define_interface!(IDirectSound3DBuffer, IDirectSound3DBuffer_Vtbl, 0x279afa86_4981_11ce_a521_0020af0be560);
 windows_core::imp::interface_hierarchy!(IDirectSound3DBuffer, windows_core::IUnknown);
@@ -1117,6 +1195,185 @@ impl IDirectSound3DBuffer_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct3D")]
+impl<Identity: windows_core::IUnknownImpl + IDirectSound3DBuffer_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectSound3DBuffer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSound3DBuffer_Impl>(this: *mut core::ffi::c_void, pds3dbuffer: *mut DS3DBUFFER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound3DBuffer_Impl::GetAllParameters(this, core::mem::transmute_copy(&pds3dbuffer)).into()
+            }
+        }
+        unsafe extern "system" fn GetConeAngles<Identity: windows_core::IUnknownImpl + IDirectSound3DBuffer_Impl>(this: *mut core::ffi::c_void, pdwinsideconeangle: *mut u32, pdwoutsideconeangle: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound3DBuffer_Impl::GetConeAngles(this, core::mem::transmute_copy(&pdwinsideconeangle), core::mem::transmute_copy(&pdwoutsideconeangle)).into()
+            }
+        }
+        unsafe extern "system" fn GetConeOrientation<Identity: windows_core::IUnknownImpl + IDirectSound3DBuffer_Impl>(this: *mut core::ffi::c_void, pvorientation: *mut super::super::super::Graphics::Direct3D::D3DVECTOR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSound3DBuffer_Impl::GetConeOrientation(this) {
+                    Ok(ok__) => {
+                        pvorientation.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetConeOutsideVolume<Identity: windows_core::IUnknownImpl + IDirectSound3DBuffer_Impl>(this: *mut core::ffi::c_void, plconeoutsidevolume: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSound3DBuffer_Impl::GetConeOutsideVolume(this) {
+                    Ok(ok__) => {
+                        plconeoutsidevolume.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMaxDistance<Identity: windows_core::IUnknownImpl + IDirectSound3DBuffer_Impl>(this: *mut core::ffi::c_void, pflmaxdistance: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSound3DBuffer_Impl::GetMaxDistance(this) {
+                    Ok(ok__) => {
+                        pflmaxdistance.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMinDistance<Identity: windows_core::IUnknownImpl + IDirectSound3DBuffer_Impl>(this: *mut core::ffi::c_void, pflmindistance: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSound3DBuffer_Impl::GetMinDistance(this) {
+                    Ok(ok__) => {
+                        pflmindistance.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMode<Identity: windows_core::IUnknownImpl + IDirectSound3DBuffer_Impl>(this: *mut core::ffi::c_void, pdwmode: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSound3DBuffer_Impl::GetMode(this) {
+                    Ok(ok__) => {
+                        pdwmode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPosition<Identity: windows_core::IUnknownImpl + IDirectSound3DBuffer_Impl>(this: *mut core::ffi::c_void, pvposition: *mut super::super::super::Graphics::Direct3D::D3DVECTOR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSound3DBuffer_Impl::GetPosition(this) {
+                    Ok(ok__) => {
+                        pvposition.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetVelocity<Identity: windows_core::IUnknownImpl + IDirectSound3DBuffer_Impl>(this: *mut core::ffi::c_void, pvvelocity: *mut super::super::super::Graphics::Direct3D::D3DVECTOR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSound3DBuffer_Impl::GetVelocity(this) {
+                    Ok(ok__) => {
+                        pvvelocity.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSound3DBuffer_Impl>(this: *mut core::ffi::c_void, pcds3dbuffer: *const DS3DBUFFER, dwapply: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound3DBuffer_Impl::SetAllParameters(this, core::mem::transmute_copy(&pcds3dbuffer), core::mem::transmute_copy(&dwapply)).into()
+            }
+        }
+        unsafe extern "system" fn SetConeAngles<Identity: windows_core::IUnknownImpl + IDirectSound3DBuffer_Impl>(this: *mut core::ffi::c_void, dwinsideconeangle: u32, dwoutsideconeangle: u32, dwapply: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound3DBuffer_Impl::SetConeAngles(this, core::mem::transmute_copy(&dwinsideconeangle), core::mem::transmute_copy(&dwoutsideconeangle), core::mem::transmute_copy(&dwapply)).into()
+            }
+        }
+        unsafe extern "system" fn SetConeOrientation<Identity: windows_core::IUnknownImpl + IDirectSound3DBuffer_Impl>(this: *mut core::ffi::c_void, x: f32, y: f32, z: f32, dwapply: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound3DBuffer_Impl::SetConeOrientation(this, core::mem::transmute_copy(&x), core::mem::transmute_copy(&y), core::mem::transmute_copy(&z), core::mem::transmute_copy(&dwapply)).into()
+            }
+        }
+        unsafe extern "system" fn SetConeOutsideVolume<Identity: windows_core::IUnknownImpl + IDirectSound3DBuffer_Impl>(this: *mut core::ffi::c_void, lconeoutsidevolume: i32, dwapply: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound3DBuffer_Impl::SetConeOutsideVolume(this, core::mem::transmute_copy(&lconeoutsidevolume), core::mem::transmute_copy(&dwapply)).into()
+            }
+        }
+        unsafe extern "system" fn SetMaxDistance<Identity: windows_core::IUnknownImpl + IDirectSound3DBuffer_Impl>(this: *mut core::ffi::c_void, flmaxdistance: f32, dwapply: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound3DBuffer_Impl::SetMaxDistance(this, core::mem::transmute_copy(&flmaxdistance), core::mem::transmute_copy(&dwapply)).into()
+            }
+        }
+        unsafe extern "system" fn SetMinDistance<Identity: windows_core::IUnknownImpl + IDirectSound3DBuffer_Impl>(this: *mut core::ffi::c_void, flmindistance: f32, dwapply: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound3DBuffer_Impl::SetMinDistance(this, core::mem::transmute_copy(&flmindistance), core::mem::transmute_copy(&dwapply)).into()
+            }
+        }
+        unsafe extern "system" fn SetMode<Identity: windows_core::IUnknownImpl + IDirectSound3DBuffer_Impl>(this: *mut core::ffi::c_void, dwmode: u32, dwapply: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound3DBuffer_Impl::SetMode(this, core::mem::transmute_copy(&dwmode), core::mem::transmute_copy(&dwapply)).into()
+            }
+        }
+        unsafe extern "system" fn SetPosition<Identity: windows_core::IUnknownImpl + IDirectSound3DBuffer_Impl>(this: *mut core::ffi::c_void, x: f32, y: f32, z: f32, dwapply: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound3DBuffer_Impl::SetPosition(this, core::mem::transmute_copy(&x), core::mem::transmute_copy(&y), core::mem::transmute_copy(&z), core::mem::transmute_copy(&dwapply)).into()
+            }
+        }
+        unsafe extern "system" fn SetVelocity<Identity: windows_core::IUnknownImpl + IDirectSound3DBuffer_Impl>(this: *mut core::ffi::c_void, x: f32, y: f32, z: f32, dwapply: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound3DBuffer_Impl::SetVelocity(this, core::mem::transmute_copy(&x), core::mem::transmute_copy(&y), core::mem::transmute_copy(&z), core::mem::transmute_copy(&dwapply)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAllParameters: GetAllParameters::<Identity>,
+            GetConeAngles: GetConeAngles::<Identity>,
+            GetConeOrientation: GetConeOrientation::<Identity>,
+            GetConeOutsideVolume: GetConeOutsideVolume::<Identity>,
+            GetMaxDistance: GetMaxDistance::<Identity>,
+            GetMinDistance: GetMinDistance::<Identity>,
+            GetMode: GetMode::<Identity>,
+            GetPosition: GetPosition::<Identity>,
+            GetVelocity: GetVelocity::<Identity>,
+            SetAllParameters: SetAllParameters::<Identity>,
+            SetConeAngles: SetConeAngles::<Identity>,
+            SetConeOrientation: SetConeOrientation::<Identity>,
+            SetConeOutsideVolume: SetConeOutsideVolume::<Identity>,
+            SetMaxDistance: SetMaxDistance::<Identity>,
+            SetMinDistance: SetMinDistance::<Identity>,
+            SetMode: SetMode::<Identity>,
+            SetPosition: SetPosition::<Identity>,
+            SetVelocity: SetVelocity::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct3D")]
 impl windows_core::RuntimeName for IDirectSound3DBuffer {}
 windows_core::imp::define_interface!(IDirectSound3DListener, IDirectSound3DListener_Vtbl, 0x279afa84_4981_11ce_a521_0020af0be560);
 windows_core::imp::interface_hierarchy!(IDirectSound3DListener, windows_core::IUnknown);
@@ -1387,6 +1644,152 @@ impl IDirectSound3DListener_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Direct3D")]
+impl<Identity: windows_core::IUnknownImpl + IDirectSound3DListener_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectSound3DListener_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSound3DListener_Impl>(this: *mut core::ffi::c_void, plistener: *mut DS3DLISTENER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound3DListener_Impl::GetAllParameters(this, core::mem::transmute_copy(&plistener)).into()
+            }
+        }
+        unsafe extern "system" fn GetDistanceFactor<Identity: windows_core::IUnknownImpl + IDirectSound3DListener_Impl>(this: *mut core::ffi::c_void, pfldistancefactor: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSound3DListener_Impl::GetDistanceFactor(this) {
+                    Ok(ok__) => {
+                        pfldistancefactor.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDopplerFactor<Identity: windows_core::IUnknownImpl + IDirectSound3DListener_Impl>(this: *mut core::ffi::c_void, pfldopplerfactor: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSound3DListener_Impl::GetDopplerFactor(this) {
+                    Ok(ok__) => {
+                        pfldopplerfactor.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOrientation<Identity: windows_core::IUnknownImpl + IDirectSound3DListener_Impl>(this: *mut core::ffi::c_void, pvorientfront: *mut super::super::super::Graphics::Direct3D::D3DVECTOR, pvorienttop: *mut super::super::super::Graphics::Direct3D::D3DVECTOR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound3DListener_Impl::GetOrientation(this, core::mem::transmute_copy(&pvorientfront), core::mem::transmute_copy(&pvorienttop)).into()
+            }
+        }
+        unsafe extern "system" fn GetPosition<Identity: windows_core::IUnknownImpl + IDirectSound3DListener_Impl>(this: *mut core::ffi::c_void, pvposition: *mut super::super::super::Graphics::Direct3D::D3DVECTOR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSound3DListener_Impl::GetPosition(this) {
+                    Ok(ok__) => {
+                        pvposition.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRolloffFactor<Identity: windows_core::IUnknownImpl + IDirectSound3DListener_Impl>(this: *mut core::ffi::c_void, pflrollofffactor: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSound3DListener_Impl::GetRolloffFactor(this) {
+                    Ok(ok__) => {
+                        pflrollofffactor.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetVelocity<Identity: windows_core::IUnknownImpl + IDirectSound3DListener_Impl>(this: *mut core::ffi::c_void, pvvelocity: *mut super::super::super::Graphics::Direct3D::D3DVECTOR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSound3DListener_Impl::GetVelocity(this) {
+                    Ok(ok__) => {
+                        pvvelocity.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSound3DListener_Impl>(this: *mut core::ffi::c_void, pclistener: *const DS3DLISTENER, dwapply: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound3DListener_Impl::SetAllParameters(this, core::mem::transmute_copy(&pclistener), core::mem::transmute_copy(&dwapply)).into()
+            }
+        }
+        unsafe extern "system" fn SetDistanceFactor<Identity: windows_core::IUnknownImpl + IDirectSound3DListener_Impl>(this: *mut core::ffi::c_void, fldistancefactor: f32, dwapply: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound3DListener_Impl::SetDistanceFactor(this, core::mem::transmute_copy(&fldistancefactor), core::mem::transmute_copy(&dwapply)).into()
+            }
+        }
+        unsafe extern "system" fn SetDopplerFactor<Identity: windows_core::IUnknownImpl + IDirectSound3DListener_Impl>(this: *mut core::ffi::c_void, fldopplerfactor: f32, dwapply: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound3DListener_Impl::SetDopplerFactor(this, core::mem::transmute_copy(&fldopplerfactor), core::mem::transmute_copy(&dwapply)).into()
+            }
+        }
+        unsafe extern "system" fn SetOrientation<Identity: windows_core::IUnknownImpl + IDirectSound3DListener_Impl>(this: *mut core::ffi::c_void, xfront: f32, yfront: f32, zfront: f32, xtop: f32, ytop: f32, ztop: f32, dwapply: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound3DListener_Impl::SetOrientation(this, core::mem::transmute_copy(&xfront), core::mem::transmute_copy(&yfront), core::mem::transmute_copy(&zfront), core::mem::transmute_copy(&xtop), core::mem::transmute_copy(&ytop), core::mem::transmute_copy(&ztop), core::mem::transmute_copy(&dwapply)).into()
+            }
+        }
+        unsafe extern "system" fn SetPosition<Identity: windows_core::IUnknownImpl + IDirectSound3DListener_Impl>(this: *mut core::ffi::c_void, x: f32, y: f32, z: f32, dwapply: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound3DListener_Impl::SetPosition(this, core::mem::transmute_copy(&x), core::mem::transmute_copy(&y), core::mem::transmute_copy(&z), core::mem::transmute_copy(&dwapply)).into()
+            }
+        }
+        unsafe extern "system" fn SetRolloffFactor<Identity: windows_core::IUnknownImpl + IDirectSound3DListener_Impl>(this: *mut core::ffi::c_void, flrollofffactor: f32, dwapply: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound3DListener_Impl::SetRolloffFactor(this, core::mem::transmute_copy(&flrollofffactor), core::mem::transmute_copy(&dwapply)).into()
+            }
+        }
+        unsafe extern "system" fn SetVelocity<Identity: windows_core::IUnknownImpl + IDirectSound3DListener_Impl>(this: *mut core::ffi::c_void, x: f32, y: f32, z: f32, dwapply: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound3DListener_Impl::SetVelocity(this, core::mem::transmute_copy(&x), core::mem::transmute_copy(&y), core::mem::transmute_copy(&z), core::mem::transmute_copy(&dwapply)).into()
+            }
+        }
+        unsafe extern "system" fn CommitDeferredSettings<Identity: windows_core::IUnknownImpl + IDirectSound3DListener_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSound3DListener_Impl::CommitDeferredSettings(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAllParameters: GetAllParameters::<Identity>,
+            GetDistanceFactor: GetDistanceFactor::<Identity>,
+            GetDopplerFactor: GetDopplerFactor::<Identity>,
+            GetOrientation: GetOrientation::<Identity>,
+            GetPosition: GetPosition::<Identity>,
+            GetRolloffFactor: GetRolloffFactor::<Identity>,
+            GetVelocity: GetVelocity::<Identity>,
+            SetAllParameters: SetAllParameters::<Identity>,
+            SetDistanceFactor: SetDistanceFactor::<Identity>,
+            SetDopplerFactor: SetDopplerFactor::<Identity>,
+            SetOrientation: SetOrientation::<Identity>,
+            SetPosition: SetPosition::<Identity>,
+            SetRolloffFactor: SetRolloffFactor::<Identity>,
+            SetVelocity: SetVelocity::<Identity>,
+            CommitDeferredSettings: CommitDeferredSettings::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Direct3D")]
 impl windows_core::RuntimeName for IDirectSound3DListener {}
 windows_core::imp::define_interface!(IDirectSound8, IDirectSound8_Vtbl, 0xc50a7e93_f395_4834_9ef6_7fa99de50966);
 impl core::ops::Deref for IDirectSound8 {
@@ -1433,6 +1836,26 @@ impl IDirectSound8_Vtbl {
         iid == &<IDirectSound8 as windows_core::Interface>::IID || iid == &<IDirectSound as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectSound8_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectSound8_Vtbl
+where
+    IDirectSound_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn VerifyCertification<Identity: windows_core::IUnknownImpl + IDirectSound8_Impl>(this: *mut core::ffi::c_void, pdwcertified: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSound8_Impl::VerifyCertification(this) {
+                    Ok(ok__) => {
+                        pdwcertified.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IDirectSound_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, VerifyCertification: VerifyCertification::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDirectSound8 {}
 windows_core::imp::define_interface!(IDirectSoundBuffer, IDirectSoundBuffer_Vtbl, 0x279afa85_4981_11ce_a521_0020af0be560);
 windows_core::imp::interface_hierarchy!(IDirectSoundBuffer, windows_core::IUnknown);
@@ -1710,6 +2133,166 @@ impl IDirectSoundBuffer_Vtbl {
         iid == &<IDirectSoundBuffer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectSoundBuffer_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectSoundBuffer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCaps<Identity: windows_core::IUnknownImpl + IDirectSoundBuffer_Impl>(this: *mut core::ffi::c_void, pdsbuffercaps: *mut DSBCAPS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundBuffer_Impl::GetCaps(this, core::mem::transmute_copy(&pdsbuffercaps)).into()
+            }
+        }
+        unsafe extern "system" fn GetCurrentPosition<Identity: windows_core::IUnknownImpl + IDirectSoundBuffer_Impl>(this: *mut core::ffi::c_void, pdwcurrentplaycursor: *mut u32, pdwcurrentwritecursor: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundBuffer_Impl::GetCurrentPosition(this, core::mem::transmute_copy(&pdwcurrentplaycursor), core::mem::transmute_copy(&pdwcurrentwritecursor)).into()
+            }
+        }
+        unsafe extern "system" fn GetFormat<Identity: windows_core::IUnknownImpl + IDirectSoundBuffer_Impl>(this: *mut core::ffi::c_void, pwfxformat: *mut super::WAVEFORMATEX, dwsizeallocated: u32, pdwsizewritten: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundBuffer_Impl::GetFormat(this, core::mem::transmute_copy(&pwfxformat), core::mem::transmute_copy(&dwsizeallocated), core::mem::transmute_copy(&pdwsizewritten)).into()
+            }
+        }
+        unsafe extern "system" fn GetVolume<Identity: windows_core::IUnknownImpl + IDirectSoundBuffer_Impl>(this: *mut core::ffi::c_void, plvolume: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSoundBuffer_Impl::GetVolume(this) {
+                    Ok(ok__) => {
+                        plvolume.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPan<Identity: windows_core::IUnknownImpl + IDirectSoundBuffer_Impl>(this: *mut core::ffi::c_void, plpan: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSoundBuffer_Impl::GetPan(this) {
+                    Ok(ok__) => {
+                        plpan.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFrequency<Identity: windows_core::IUnknownImpl + IDirectSoundBuffer_Impl>(this: *mut core::ffi::c_void, pdwfrequency: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSoundBuffer_Impl::GetFrequency(this) {
+                    Ok(ok__) => {
+                        pdwfrequency.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStatus<Identity: windows_core::IUnknownImpl + IDirectSoundBuffer_Impl>(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSoundBuffer_Impl::GetStatus(this) {
+                    Ok(ok__) => {
+                        pdwstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectSoundBuffer_Impl>(this: *mut core::ffi::c_void, pdirectsound: *mut core::ffi::c_void, pcdsbufferdesc: *const DSBUFFERDESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundBuffer_Impl::Initialize(this, core::mem::transmute_copy(&pdirectsound), core::mem::transmute_copy(&pcdsbufferdesc)).into()
+            }
+        }
+        unsafe extern "system" fn Lock<Identity: windows_core::IUnknownImpl + IDirectSoundBuffer_Impl>(this: *mut core::ffi::c_void, dwoffset: u32, dwbytes: u32, ppvaudioptr1: *mut *mut core::ffi::c_void, pdwaudiobytes1: *mut u32, ppvaudioptr2: *mut *mut core::ffi::c_void, pdwaudiobytes2: *mut u32, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundBuffer_Impl::Lock(this, core::mem::transmute_copy(&dwoffset), core::mem::transmute_copy(&dwbytes), core::mem::transmute_copy(&ppvaudioptr1), core::mem::transmute_copy(&pdwaudiobytes1), core::mem::transmute_copy(&ppvaudioptr2), core::mem::transmute_copy(&pdwaudiobytes2), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn Play<Identity: windows_core::IUnknownImpl + IDirectSoundBuffer_Impl>(this: *mut core::ffi::c_void, dwreserved1: u32, dwpriority: u32, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundBuffer_Impl::Play(this, core::mem::transmute_copy(&dwreserved1), core::mem::transmute_copy(&dwpriority), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn SetCurrentPosition<Identity: windows_core::IUnknownImpl + IDirectSoundBuffer_Impl>(this: *mut core::ffi::c_void, dwnewposition: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundBuffer_Impl::SetCurrentPosition(this, core::mem::transmute_copy(&dwnewposition)).into()
+            }
+        }
+        unsafe extern "system" fn SetFormat<Identity: windows_core::IUnknownImpl + IDirectSoundBuffer_Impl>(this: *mut core::ffi::c_void, pcfxformat: *const super::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundBuffer_Impl::SetFormat(this, core::mem::transmute_copy(&pcfxformat)).into()
+            }
+        }
+        unsafe extern "system" fn SetVolume<Identity: windows_core::IUnknownImpl + IDirectSoundBuffer_Impl>(this: *mut core::ffi::c_void, lvolume: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundBuffer_Impl::SetVolume(this, core::mem::transmute_copy(&lvolume)).into()
+            }
+        }
+        unsafe extern "system" fn SetPan<Identity: windows_core::IUnknownImpl + IDirectSoundBuffer_Impl>(this: *mut core::ffi::c_void, lpan: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundBuffer_Impl::SetPan(this, core::mem::transmute_copy(&lpan)).into()
+            }
+        }
+        unsafe extern "system" fn SetFrequency<Identity: windows_core::IUnknownImpl + IDirectSoundBuffer_Impl>(this: *mut core::ffi::c_void, dwfrequency: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundBuffer_Impl::SetFrequency(this, core::mem::transmute_copy(&dwfrequency)).into()
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + IDirectSoundBuffer_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundBuffer_Impl::Stop(this).into()
+            }
+        }
+        unsafe extern "system" fn Unlock<Identity: windows_core::IUnknownImpl + IDirectSoundBuffer_Impl>(this: *mut core::ffi::c_void, pvaudioptr1: *const core::ffi::c_void, dwaudiobytes1: u32, pvaudioptr2: *const core::ffi::c_void, dwaudiobytes2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundBuffer_Impl::Unlock(this, core::mem::transmute_copy(&pvaudioptr1), core::mem::transmute_copy(&dwaudiobytes1), core::mem::transmute_copy(&pvaudioptr2), core::mem::transmute_copy(&dwaudiobytes2)).into()
+            }
+        }
+        unsafe extern "system" fn Restore<Identity: windows_core::IUnknownImpl + IDirectSoundBuffer_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundBuffer_Impl::Restore(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCaps: GetCaps::<Identity>,
+            GetCurrentPosition: GetCurrentPosition::<Identity>,
+            GetFormat: GetFormat::<Identity>,
+            GetVolume: GetVolume::<Identity>,
+            GetPan: GetPan::<Identity>,
+            GetFrequency: GetFrequency::<Identity>,
+            GetStatus: GetStatus::<Identity>,
+            Initialize: Initialize::<Identity>,
+            Lock: Lock::<Identity>,
+            Play: Play::<Identity>,
+            SetCurrentPosition: SetCurrentPosition::<Identity>,
+            SetFormat: SetFormat::<Identity>,
+            SetVolume: SetVolume::<Identity>,
+            SetPan: SetPan::<Identity>,
+            SetFrequency: SetFrequency::<Identity>,
+            Stop: Stop::<Identity>,
+            Unlock: Unlock::<Identity>,
+            Restore: Restore::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectSoundBuffer {}
 windows_core::imp::define_interface!(IDirectSoundBuffer8, IDirectSoundBuffer8_Vtbl, 0x6825a449_7524_4d82_920f_50e36ab3ab1e);
 impl core::ops::Deref for IDirectSoundBuffer8 {
@@ -1774,6 +2357,37 @@ impl IDirectSoundBuffer8_Vtbl {
         iid == &<IDirectSoundBuffer8 as windows_core::Interface>::IID || iid == &<IDirectSoundBuffer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectSoundBuffer8_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectSoundBuffer8_Vtbl
+where
+    IDirectSoundBuffer_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetFX<Identity: windows_core::IUnknownImpl + IDirectSoundBuffer8_Impl>(this: *mut core::ffi::c_void, dweffectscount: u32, pdsfxdesc: *const DSEFFECTDESC, pdwresultcodes: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundBuffer8_Impl::SetFX(this, core::mem::transmute_copy(&dweffectscount), core::mem::transmute_copy(&pdsfxdesc), core::mem::transmute_copy(&pdwresultcodes)).into()
+            }
+        }
+        unsafe extern "system" fn AcquireResources<Identity: windows_core::IUnknownImpl + IDirectSoundBuffer8_Impl>(this: *mut core::ffi::c_void, dwflags: u32, dweffectscount: u32, pdwresultcodes: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundBuffer8_Impl::AcquireResources(this, core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&dweffectscount), core::mem::transmute_copy(&pdwresultcodes)).into()
+            }
+        }
+        unsafe extern "system" fn GetObjectInPath<Identity: windows_core::IUnknownImpl + IDirectSoundBuffer8_Impl>(this: *mut core::ffi::c_void, rguidobject: *const windows_core::GUID, dwindex: u32, rguidinterface: *const windows_core::GUID, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundBuffer8_Impl::GetObjectInPath(this, core::mem::transmute_copy(&rguidobject), core::mem::transmute_copy(&dwindex), core::mem::transmute_copy(&rguidinterface), core::mem::transmute_copy(&ppobject)).into()
+            }
+        }
+        Self {
+            base__: <IDirectSoundBuffer_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetFX: SetFX::<Identity>,
+            AcquireResources: AcquireResources::<Identity>,
+            GetObjectInPath: GetObjectInPath::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectSoundBuffer8 {}
 windows_core::imp::define_interface!(IDirectSoundCapture, IDirectSoundCapture_Vtbl, 0xb0210781_89cd_11d0_af08_00a0c925cd16);
 windows_core::imp::interface_hierarchy!(IDirectSoundCapture, windows_core::IUnknown);
@@ -1844,6 +2458,43 @@ impl IDirectSoundCapture_Vtbl {
         iid == &<IDirectSoundCapture as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectSoundCapture_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectSoundCapture_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateCaptureBuffer<Identity: windows_core::IUnknownImpl + IDirectSoundCapture_Impl>(this: *mut core::ffi::c_void, pcdscbufferdesc: *const DSCBUFFERDESC, ppdscbuffer: *mut *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundCapture_Impl::CreateCaptureBuffer(this, core::mem::transmute_copy(&pcdscbufferdesc), core::mem::transmute_copy(&ppdscbuffer), core::mem::transmute_copy(&punkouter)).into()
+            }
+        }
+        unsafe extern "system" fn GetCaps<Identity: windows_core::IUnknownImpl + IDirectSoundCapture_Impl>(this: *mut core::ffi::c_void, pdsccaps: *mut DSCCAPS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSoundCapture_Impl::GetCaps(this) {
+                    Ok(ok__) => {
+                        pdsccaps.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectSoundCapture_Impl>(this: *mut core::ffi::c_void, pcguiddevice: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundCapture_Impl::Initialize(this, core::mem::transmute_copy(&pcguiddevice)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateCaptureBuffer: CreateCaptureBuffer::<Identity>,
+            GetCaps: GetCaps::<Identity>,
+            Initialize: Initialize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectSoundCapture {}
 windows_core::imp::define_interface!(IDirectSoundCaptureBuffer, IDirectSoundCaptureBuffer_Vtbl, 0xb0210782_89cd_11d0_af08_00a0c925cd16);
 windows_core::imp::interface_hierarchy!(IDirectSoundCaptureBuffer, windows_core::IUnknown);
@@ -1990,10 +2641,95 @@ impl IDirectSoundCaptureBuffer_Vtbl {
             Stop: Stop::<Identity, OFFSET>,
             Unlock: Unlock::<Identity, OFFSET>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDirectSoundCaptureBuffer as windows_core::Interface>::IID
-    }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectSoundCaptureBuffer as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IDirectSoundCaptureBuffer_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectSoundCaptureBuffer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCaps<Identity: windows_core::IUnknownImpl + IDirectSoundCaptureBuffer_Impl>(this: *mut core::ffi::c_void, pdscbcaps: *mut DSCBCAPS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSoundCaptureBuffer_Impl::GetCaps(this) {
+                    Ok(ok__) => {
+                        pdscbcaps.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCurrentPosition<Identity: windows_core::IUnknownImpl + IDirectSoundCaptureBuffer_Impl>(this: *mut core::ffi::c_void, pdwcaptureposition: *mut u32, pdwreadposition: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundCaptureBuffer_Impl::GetCurrentPosition(this, core::mem::transmute_copy(&pdwcaptureposition), core::mem::transmute_copy(&pdwreadposition)).into()
+            }
+        }
+        unsafe extern "system" fn GetFormat<Identity: windows_core::IUnknownImpl + IDirectSoundCaptureBuffer_Impl>(this: *mut core::ffi::c_void, pwfxformat: *mut super::WAVEFORMATEX, dwsizeallocated: u32, pdwsizewritten: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundCaptureBuffer_Impl::GetFormat(this, core::mem::transmute_copy(&pwfxformat), core::mem::transmute_copy(&dwsizeallocated), core::mem::transmute_copy(&pdwsizewritten)).into()
+            }
+        }
+        unsafe extern "system" fn GetStatus<Identity: windows_core::IUnknownImpl + IDirectSoundCaptureBuffer_Impl>(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSoundCaptureBuffer_Impl::GetStatus(this) {
+                    Ok(ok__) => {
+                        pdwstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectSoundCaptureBuffer_Impl>(this: *mut core::ffi::c_void, pdirectsoundcapture: *mut core::ffi::c_void, pcdscbufferdesc: *const DSCBUFFERDESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundCaptureBuffer_Impl::Initialize(this, core::mem::transmute_copy(&pdirectsoundcapture), core::mem::transmute_copy(&pcdscbufferdesc)).into()
+            }
+        }
+        unsafe extern "system" fn Lock<Identity: windows_core::IUnknownImpl + IDirectSoundCaptureBuffer_Impl>(this: *mut core::ffi::c_void, dwoffset: u32, dwbytes: u32, ppvaudioptr1: *mut *mut core::ffi::c_void, pdwaudiobytes1: *mut u32, ppvaudioptr2: *mut *mut core::ffi::c_void, pdwaudiobytes2: *mut u32, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundCaptureBuffer_Impl::Lock(this, core::mem::transmute_copy(&dwoffset), core::mem::transmute_copy(&dwbytes), core::mem::transmute_copy(&ppvaudioptr1), core::mem::transmute_copy(&pdwaudiobytes1), core::mem::transmute_copy(&ppvaudioptr2), core::mem::transmute_copy(&pdwaudiobytes2), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn Start<Identity: windows_core::IUnknownImpl + IDirectSoundCaptureBuffer_Impl>(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundCaptureBuffer_Impl::Start(this, core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + IDirectSoundCaptureBuffer_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundCaptureBuffer_Impl::Stop(this).into()
+            }
+        }
+        unsafe extern "system" fn Unlock<Identity: windows_core::IUnknownImpl + IDirectSoundCaptureBuffer_Impl>(this: *mut core::ffi::c_void, pvaudioptr1: *const core::ffi::c_void, dwaudiobytes1: u32, pvaudioptr2: *const core::ffi::c_void, dwaudiobytes2: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundCaptureBuffer_Impl::Unlock(this, core::mem::transmute_copy(&pvaudioptr1), core::mem::transmute_copy(&dwaudiobytes1), core::mem::transmute_copy(&pvaudioptr2), core::mem::transmute_copy(&dwaudiobytes2)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCaps: GetCaps::<Identity>,
+            GetCurrentPosition: GetCurrentPosition::<Identity>,
+            GetFormat: GetFormat::<Identity>,
+            GetStatus: GetStatus::<Identity>,
+            Initialize: Initialize::<Identity>,
+            Lock: Lock::<Identity>,
+            Start: Start::<Identity>,
+            Stop: Stop::<Identity>,
+            Unlock: Unlock::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IDirectSoundCaptureBuffer {}
 windows_core::imp::define_interface!(IDirectSoundCaptureBuffer8, IDirectSoundCaptureBuffer8_Vtbl, 0x00990df4_0dbb_4872_833e_6d303e80aeb6);
@@ -2047,6 +2783,30 @@ impl IDirectSoundCaptureBuffer8_Vtbl {
         iid == &<IDirectSoundCaptureBuffer8 as windows_core::Interface>::IID || iid == &<IDirectSoundCaptureBuffer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectSoundCaptureBuffer8_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectSoundCaptureBuffer8_Vtbl
+where
+    IDirectSoundCaptureBuffer_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetObjectInPath<Identity: windows_core::IUnknownImpl + IDirectSoundCaptureBuffer8_Impl>(this: *mut core::ffi::c_void, rguidobject: *const windows_core::GUID, dwindex: u32, rguidinterface: *const windows_core::GUID, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundCaptureBuffer8_Impl::GetObjectInPath(this, core::mem::transmute_copy(&rguidobject), core::mem::transmute_copy(&dwindex), core::mem::transmute_copy(&rguidinterface), core::mem::transmute_copy(&ppobject)).into()
+            }
+        }
+        unsafe extern "system" fn GetFXStatus<Identity: windows_core::IUnknownImpl + IDirectSoundCaptureBuffer8_Impl>(this: *mut core::ffi::c_void, dweffectscount: u32, pdwfxstatus: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundCaptureBuffer8_Impl::GetFXStatus(this, core::mem::transmute_copy(&dweffectscount), core::mem::transmute_copy(&pdwfxstatus)).into()
+            }
+        }
+        Self {
+            base__: <IDirectSoundCaptureBuffer_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetObjectInPath: GetObjectInPath::<Identity>,
+            GetFXStatus: GetFXStatus::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectSoundCaptureBuffer8 {}
 windows_core::imp::define_interface!(IDirectSoundCaptureFXAec, IDirectSoundCaptureFXAec_Vtbl, 0xad74143d_903d_4ab7_8066_28d363036d65);
 windows_core::imp::interface_hierarchy!(IDirectSoundCaptureFXAec, windows_core::IUnknown);
@@ -2135,6 +2895,56 @@ impl IDirectSoundCaptureFXAec_Vtbl {
         iid == &<IDirectSoundCaptureFXAec as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectSoundCaptureFXAec_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectSoundCaptureFXAec_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSoundCaptureFXAec_Impl>(this: *mut core::ffi::c_void, pdscfxaec: *const DSCFXAec) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundCaptureFXAec_Impl::SetAllParameters(this, core::mem::transmute_copy(&pdscfxaec)).into()
+            }
+        }
+        unsafe extern "system" fn GetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSoundCaptureFXAec_Impl>(this: *mut core::ffi::c_void, pdscfxaec: *mut DSCFXAec) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSoundCaptureFXAec_Impl::GetAllParameters(this) {
+                    Ok(ok__) => {
+                        pdscfxaec.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStatus<Identity: windows_core::IUnknownImpl + IDirectSoundCaptureFXAec_Impl>(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSoundCaptureFXAec_Impl::GetStatus(this) {
+                    Ok(ok__) => {
+                        pdwstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IDirectSoundCaptureFXAec_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundCaptureFXAec_Impl::Reset(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAllParameters: SetAllParameters::<Identity>,
+            GetAllParameters: GetAllParameters::<Identity>,
+            GetStatus: GetStatus::<Identity>,
+            Reset: Reset::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectSoundCaptureFXAec {}
 windows_core::imp::define_interface!(IDirectSoundCaptureFXNoiseSuppress, IDirectSoundCaptureFXNoiseSuppress_Vtbl, 0xed311e41_fbae_4175_9625_cd0854f693ca);
 windows_core::imp::interface_hierarchy!(IDirectSoundCaptureFXNoiseSuppress, windows_core::IUnknown);
@@ -2202,6 +3012,43 @@ impl IDirectSoundCaptureFXNoiseSuppress_Vtbl {
         iid == &<IDirectSoundCaptureFXNoiseSuppress as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectSoundCaptureFXNoiseSuppress_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectSoundCaptureFXNoiseSuppress_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSoundCaptureFXNoiseSuppress_Impl>(this: *mut core::ffi::c_void, pcdscfxnoisesuppress: *const DSCFXNoiseSuppress) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundCaptureFXNoiseSuppress_Impl::SetAllParameters(this, core::mem::transmute_copy(&pcdscfxnoisesuppress)).into()
+            }
+        }
+        unsafe extern "system" fn GetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSoundCaptureFXNoiseSuppress_Impl>(this: *mut core::ffi::c_void, pdscfxnoisesuppress: *mut DSCFXNoiseSuppress) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSoundCaptureFXNoiseSuppress_Impl::GetAllParameters(this) {
+                    Ok(ok__) => {
+                        pdscfxnoisesuppress.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IDirectSoundCaptureFXNoiseSuppress_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundCaptureFXNoiseSuppress_Impl::Reset(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAllParameters: SetAllParameters::<Identity>,
+            GetAllParameters: GetAllParameters::<Identity>,
+            Reset: Reset::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectSoundCaptureFXNoiseSuppress {}
 windows_core::imp::define_interface!(IDirectSoundFXChorus, IDirectSoundFXChorus_Vtbl, 0x880842e3_145f_43e6_a934_a71806e50547);
 windows_core::imp::interface_hierarchy!(IDirectSoundFXChorus, windows_core::IUnknown);
@@ -2248,6 +3095,30 @@ impl IDirectSoundFXChorus_Vtbl {
         iid == &<IDirectSoundFXChorus as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectSoundFXChorus_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectSoundFXChorus_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSoundFXChorus_Impl>(this: *mut core::ffi::c_void, pcdsfxchorus: *const DSFXChorus) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundFXChorus_Impl::SetAllParameters(this, core::mem::transmute_copy(&pcdsfxchorus)).into()
+            }
+        }
+        unsafe extern "system" fn GetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSoundFXChorus_Impl>(this: *mut core::ffi::c_void, pdsfxchorus: *mut DSFXChorus) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundFXChorus_Impl::GetAllParameters(this, core::mem::transmute_copy(&pdsfxchorus)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAllParameters: SetAllParameters::<Identity>,
+            GetAllParameters: GetAllParameters::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectSoundFXChorus {}
 windows_core::imp::define_interface!(IDirectSoundFXCompressor, IDirectSoundFXCompressor_Vtbl, 0x4bbd1154_62f6_4e2c_a15c_d3b6c417f7a0);
 windows_core::imp::interface_hierarchy!(IDirectSoundFXCompressor, windows_core::IUnknown);
@@ -2294,6 +3165,30 @@ impl IDirectSoundFXCompressor_Vtbl {
         iid == &<IDirectSoundFXCompressor as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectSoundFXCompressor_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectSoundFXCompressor_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSoundFXCompressor_Impl>(this: *mut core::ffi::c_void, pcdsfxcompressor: *const DSFXCompressor) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundFXCompressor_Impl::SetAllParameters(this, core::mem::transmute_copy(&pcdsfxcompressor)).into()
+            }
+        }
+        unsafe extern "system" fn GetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSoundFXCompressor_Impl>(this: *mut core::ffi::c_void, pdsfxcompressor: *mut DSFXCompressor) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundFXCompressor_Impl::GetAllParameters(this, core::mem::transmute_copy(&pdsfxcompressor)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAllParameters: SetAllParameters::<Identity>,
+            GetAllParameters: GetAllParameters::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectSoundFXCompressor {}
 windows_core::imp::define_interface!(IDirectSoundFXDistortion, IDirectSoundFXDistortion_Vtbl, 0x8ecf4326_455f_4d8b_bda9_8d5d3e9e3e0b);
 windows_core::imp::interface_hierarchy!(IDirectSoundFXDistortion, windows_core::IUnknown);
@@ -2340,6 +3235,30 @@ impl IDirectSoundFXDistortion_Vtbl {
         iid == &<IDirectSoundFXDistortion as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectSoundFXDistortion_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectSoundFXDistortion_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSoundFXDistortion_Impl>(this: *mut core::ffi::c_void, pcdsfxdistortion: *const DSFXDistortion) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundFXDistortion_Impl::SetAllParameters(this, core::mem::transmute_copy(&pcdsfxdistortion)).into()
+            }
+        }
+        unsafe extern "system" fn GetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSoundFXDistortion_Impl>(this: *mut core::ffi::c_void, pdsfxdistortion: *mut DSFXDistortion) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundFXDistortion_Impl::GetAllParameters(this, core::mem::transmute_copy(&pdsfxdistortion)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAllParameters: SetAllParameters::<Identity>,
+            GetAllParameters: GetAllParameters::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectSoundFXDistortion {}
 windows_core::imp::define_interface!(IDirectSoundFXEcho, IDirectSoundFXEcho_Vtbl, 0x8bd28edf_50db_4e92_a2bd_445488d1ed42);
 windows_core::imp::interface_hierarchy!(IDirectSoundFXEcho, windows_core::IUnknown);
@@ -2386,6 +3305,30 @@ impl IDirectSoundFXEcho_Vtbl {
         iid == &<IDirectSoundFXEcho as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectSoundFXEcho_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectSoundFXEcho_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSoundFXEcho_Impl>(this: *mut core::ffi::c_void, pcdsfxecho: *const DSFXEcho) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundFXEcho_Impl::SetAllParameters(this, core::mem::transmute_copy(&pcdsfxecho)).into()
+            }
+        }
+        unsafe extern "system" fn GetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSoundFXEcho_Impl>(this: *mut core::ffi::c_void, pdsfxecho: *mut DSFXEcho) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundFXEcho_Impl::GetAllParameters(this, core::mem::transmute_copy(&pdsfxecho)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAllParameters: SetAllParameters::<Identity>,
+            GetAllParameters: GetAllParameters::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectSoundFXEcho {}
 windows_core::imp::define_interface!(IDirectSoundFXFlanger, IDirectSoundFXFlanger_Vtbl, 0x903e9878_2c92_4072_9b2c_ea68f5396783);
 windows_core::imp::interface_hierarchy!(IDirectSoundFXFlanger, windows_core::IUnknown);
@@ -2432,6 +3375,30 @@ impl IDirectSoundFXFlanger_Vtbl {
         iid == &<IDirectSoundFXFlanger as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectSoundFXFlanger_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectSoundFXFlanger_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSoundFXFlanger_Impl>(this: *mut core::ffi::c_void, pcdsfxflanger: *const DSFXFlanger) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundFXFlanger_Impl::SetAllParameters(this, core::mem::transmute_copy(&pcdsfxflanger)).into()
+            }
+        }
+        unsafe extern "system" fn GetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSoundFXFlanger_Impl>(this: *mut core::ffi::c_void, pdsfxflanger: *mut DSFXFlanger) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundFXFlanger_Impl::GetAllParameters(this, core::mem::transmute_copy(&pdsfxflanger)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAllParameters: SetAllParameters::<Identity>,
+            GetAllParameters: GetAllParameters::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectSoundFXFlanger {}
 windows_core::imp::define_interface!(IDirectSoundFXGargle, IDirectSoundFXGargle_Vtbl, 0xd616f352_d622_11ce_aac5_0020af0b99a3);
 windows_core::imp::interface_hierarchy!(IDirectSoundFXGargle, windows_core::IUnknown);
@@ -2487,6 +3454,36 @@ impl IDirectSoundFXGargle_Vtbl {
         iid == &<IDirectSoundFXGargle as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectSoundFXGargle_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectSoundFXGargle_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSoundFXGargle_Impl>(this: *mut core::ffi::c_void, pcdsfxgargle: *const DSFXGargle) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundFXGargle_Impl::SetAllParameters(this, core::mem::transmute_copy(&pcdsfxgargle)).into()
+            }
+        }
+        unsafe extern "system" fn GetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSoundFXGargle_Impl>(this: *mut core::ffi::c_void, pdsfxgargle: *mut DSFXGargle) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSoundFXGargle_Impl::GetAllParameters(this) {
+                    Ok(ok__) => {
+                        pdsfxgargle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAllParameters: SetAllParameters::<Identity>,
+            GetAllParameters: GetAllParameters::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectSoundFXGargle {}
 windows_core::imp::define_interface!(IDirectSoundFXI3DL2Reverb, IDirectSoundFXI3DL2Reverb_Vtbl, 0x4b166a6a_0d66_43f3_80e3_ee6280dee1a4);
 windows_core::imp::interface_hierarchy!(IDirectSoundFXI3DL2Reverb, windows_core::IUnknown);
@@ -2599,6 +3596,70 @@ impl IDirectSoundFXI3DL2Reverb_Vtbl {
         iid == &<IDirectSoundFXI3DL2Reverb as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectSoundFXI3DL2Reverb_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectSoundFXI3DL2Reverb_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSoundFXI3DL2Reverb_Impl>(this: *mut core::ffi::c_void, pcdsfxi3dl2reverb: *const DSFXI3DL2Reverb) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundFXI3DL2Reverb_Impl::SetAllParameters(this, core::mem::transmute_copy(&pcdsfxi3dl2reverb)).into()
+            }
+        }
+        unsafe extern "system" fn GetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSoundFXI3DL2Reverb_Impl>(this: *mut core::ffi::c_void, pdsfxi3dl2reverb: *mut DSFXI3DL2Reverb) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundFXI3DL2Reverb_Impl::GetAllParameters(this, core::mem::transmute_copy(&pdsfxi3dl2reverb)).into()
+            }
+        }
+        unsafe extern "system" fn SetPreset<Identity: windows_core::IUnknownImpl + IDirectSoundFXI3DL2Reverb_Impl>(this: *mut core::ffi::c_void, dwpreset: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundFXI3DL2Reverb_Impl::SetPreset(this, core::mem::transmute_copy(&dwpreset)).into()
+            }
+        }
+        unsafe extern "system" fn GetPreset<Identity: windows_core::IUnknownImpl + IDirectSoundFXI3DL2Reverb_Impl>(this: *mut core::ffi::c_void, pdwpreset: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSoundFXI3DL2Reverb_Impl::GetPreset(this) {
+                    Ok(ok__) => {
+                        pdwpreset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetQuality<Identity: windows_core::IUnknownImpl + IDirectSoundFXI3DL2Reverb_Impl>(this: *mut core::ffi::c_void, lquality: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundFXI3DL2Reverb_Impl::SetQuality(this, core::mem::transmute_copy(&lquality)).into()
+            }
+        }
+        unsafe extern "system" fn GetQuality<Identity: windows_core::IUnknownImpl + IDirectSoundFXI3DL2Reverb_Impl>(this: *mut core::ffi::c_void, plquality: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSoundFXI3DL2Reverb_Impl::GetQuality(this) {
+                    Ok(ok__) => {
+                        plquality.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAllParameters: SetAllParameters::<Identity>,
+            GetAllParameters: GetAllParameters::<Identity>,
+            SetPreset: SetPreset::<Identity>,
+            GetPreset: GetPreset::<Identity>,
+            SetQuality: SetQuality::<Identity>,
+            GetQuality: GetQuality::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectSoundFXI3DL2Reverb {}
 windows_core::imp::define_interface!(IDirectSoundFXParamEq, IDirectSoundFXParamEq_Vtbl, 0xc03ca9fe_fe90_4204_8078_82334cd177da);
 windows_core::imp::interface_hierarchy!(IDirectSoundFXParamEq, windows_core::IUnknown);
@@ -2654,6 +3715,36 @@ impl IDirectSoundFXParamEq_Vtbl {
         iid == &<IDirectSoundFXParamEq as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectSoundFXParamEq_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectSoundFXParamEq_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSoundFXParamEq_Impl>(this: *mut core::ffi::c_void, pcdsfxparameq: *const DSFXParamEq) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundFXParamEq_Impl::SetAllParameters(this, core::mem::transmute_copy(&pcdsfxparameq)).into()
+            }
+        }
+        unsafe extern "system" fn GetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSoundFXParamEq_Impl>(this: *mut core::ffi::c_void, pdsfxparameq: *mut DSFXParamEq) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSoundFXParamEq_Impl::GetAllParameters(this) {
+                    Ok(ok__) => {
+                        pdsfxparameq.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAllParameters: SetAllParameters::<Identity>,
+            GetAllParameters: GetAllParameters::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectSoundFXParamEq {}
 windows_core::imp::define_interface!(IDirectSoundFXWavesReverb, IDirectSoundFXWavesReverb_Vtbl, 0x46858c3a_0dc6_45e3_b760_d4eef16cb325);
 windows_core::imp::interface_hierarchy!(IDirectSoundFXWavesReverb, windows_core::IUnknown);
@@ -2709,6 +3800,36 @@ impl IDirectSoundFXWavesReverb_Vtbl {
         iid == &<IDirectSoundFXWavesReverb as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectSoundFXWavesReverb_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectSoundFXWavesReverb_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSoundFXWavesReverb_Impl>(this: *mut core::ffi::c_void, pcdsfxwavesreverb: *const DSFXWavesReverb) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundFXWavesReverb_Impl::SetAllParameters(this, core::mem::transmute_copy(&pcdsfxwavesreverb)).into()
+            }
+        }
+        unsafe extern "system" fn GetAllParameters<Identity: windows_core::IUnknownImpl + IDirectSoundFXWavesReverb_Impl>(this: *mut core::ffi::c_void, pdsfxwavesreverb: *mut DSFXWavesReverb) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDirectSoundFXWavesReverb_Impl::GetAllParameters(this) {
+                    Ok(ok__) => {
+                        pdsfxwavesreverb.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAllParameters: SetAllParameters::<Identity>,
+            GetAllParameters: GetAllParameters::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectSoundFXWavesReverb {}
 windows_core::imp::define_interface!(IDirectSoundFullDuplex, IDirectSoundFullDuplex_Vtbl, 0xedcb4c7a_daab_4216_a42e_6c50596ddc1d);
 windows_core::imp::interface_hierarchy!(IDirectSoundFullDuplex, windows_core::IUnknown);
@@ -2740,6 +3861,20 @@ impl IDirectSoundFullDuplex_Vtbl {
         iid == &<IDirectSoundFullDuplex as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectSoundFullDuplex_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectSoundFullDuplex_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDirectSoundFullDuplex_Impl>(this: *mut core::ffi::c_void, pcaptureguid: *const windows_core::GUID, prenderguid: *const windows_core::GUID, lpdscbufferdesc: *const DSCBUFFERDESC, lpdsbufferdesc: *const DSBUFFERDESC, hwnd: super::super::super::Foundation::HWND, dwlevel: u32, lplpdirectsoundcapturebuffer8: *mut *mut core::ffi::c_void, lplpdirectsoundbuffer8: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundFullDuplex_Impl::Initialize(this, core::mem::transmute_copy(&pcaptureguid), core::mem::transmute_copy(&prenderguid), core::mem::transmute_copy(&lpdscbufferdesc), core::mem::transmute_copy(&lpdsbufferdesc), core::mem::transmute_copy(&hwnd), core::mem::transmute_copy(&dwlevel), core::mem::transmute_copy(&lplpdirectsoundcapturebuffer8), core::mem::transmute_copy(&lplpdirectsoundbuffer8)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Initialize: Initialize::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDirectSoundFullDuplex {}
 windows_core::imp::define_interface!(IDirectSoundNotify, IDirectSoundNotify_Vtbl, 0xb0210783_89cd_11d0_af08_00a0c925cd16);
 windows_core::imp::interface_hierarchy!(IDirectSoundNotify, windows_core::IUnknown);
@@ -2771,6 +3906,23 @@ impl IDirectSoundNotify_Vtbl {
         iid == &<IDirectSoundNotify as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDirectSoundNotify_Impl> windows_core::imp::TearOffVtable<Identity> for IDirectSoundNotify_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetNotificationPositions<Identity: windows_core::IUnknownImpl + IDirectSoundNotify_Impl>(this: *mut core::ffi::c_void, dwpositionnotifies: u32, pcpositionnotifies: *const DSBPOSITIONNOTIFY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDirectSoundNotify_Impl::SetNotificationPositions(this, core::mem::transmute_copy(&dwpositionnotifies), core::mem::transmute_copy(&pcpositionnotifies)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetNotificationPositions: SetNotificationPositions::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDirectSoundNotify {}
 pub const KSPROPERTY_SUPPORT_GET: u32 = 1u32;
 pub const KSPROPERTY_SUPPORT_SET: u32 = 2u32;
@@ -44,6 +44,20 @@ impl IAudioEndpointFormatControl_Vtbl {
         iid == &<IAudioEndpointFormatControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioEndpointFormatControl_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioEndpointFormatControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ResetToDefault<Identity: windows_core::IUnknownImpl + IAudioEndpointFormatControl_Impl>(this: *mut core::ffi::c_void, resetflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointFormatControl_Impl::ResetToDefault(this, core::mem::transmute_copy(&resetflags)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ResetToDefault: ResetToDefault::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IAudioEndpointFormatControl {}
 windows_core::imp::define_interface!(IAudioEndpointLastBufferControl, IAudioEndpointLastBufferControl_Vtbl, 0xf8520dd3_8f9d_4437_9861_62f584c33dd6);
 windows_core::imp::interface_hierarchy!(IAudioEndpointLastBufferControl, windows_core::IUnknown);
@@ -97,6 +111,31 @@ impl IAudioEndpointLastBufferControl_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Media_Audio_Apo")]
+impl<Identity: windows_core::IUnknownImpl + IAudioEndpointLastBufferControl_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioEndpointLastBufferControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsLastBufferControlSupported<Identity: windows_core::IUnknownImpl + IAudioEndpointLastBufferControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointLastBufferControl_Impl::IsLastBufferControlSupported(this)
+            }
+        }
+        unsafe extern "system" fn ReleaseOutputDataPointerForLastBuffer<Identity: windows_core::IUnknownImpl + IAudioEndpointLastBufferControl_Impl>(this: *mut core::ffi::c_void, pconnectionproperty: *const super::Apo::APO_CONNECTION_PROPERTY) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointLastBufferControl_Impl::ReleaseOutputDataPointerForLastBuffer(this, core::mem::transmute_copy(&pconnectionproperty))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsLastBufferControlSupported: IsLastBufferControlSupported::<Identity>,
+            ReleaseOutputDataPointerForLastBuffer: ReleaseOutputDataPointerForLastBuffer::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Media_Audio_Apo")]
 impl windows_core::RuntimeName for IAudioEndpointLastBufferControl {}
 windows_core::imp::define_interface!(IAudioEndpointOffloadStreamMeter, IAudioEndpointOffloadStreamMeter_Vtbl, 0xe1546dce_9dd1_418b_9ab2_348ced161c86);
 windows_core::imp::interface_hierarchy!(IAudioEndpointOffloadStreamMeter, windows_core::IUnknown);
@@ -161,6 +200,42 @@ impl IAudioEndpointOffloadStreamMeter_Vtbl {
         iid == &<IAudioEndpointOffloadStreamMeter as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioEndpointOffloadStreamMeter_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioEndpointOffloadStreamMeter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMeterChannelCount<Identity: windows_core::IUnknownImpl + IAudioEndpointOffloadStreamMeter_Impl>(this: *mut core::ffi::c_void, pu32channelcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioEndpointOffloadStreamMeter_Impl::GetMeterChannelCount(this) {
+                    Ok(ok__) => {
+                        pu32channelcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMeteringData<Identity: windows_core::IUnknownImpl + IAudioEndpointOffloadStreamMeter_Impl>(this: *mut core::ffi::c_void, u32channelcount: u32, pf32peakvalues: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioEndpointOffloadStreamMeter_Impl::GetMeteringData(this, core::mem::transmute_copy(&u32channelcount)) {
+                    Ok(ok__) => {
+                        pf32peakvalues.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetMeterChannelCount: GetMeterChannelCount::<Identity>,
+            GetMeteringData: GetMeteringData::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioEndpointOffloadStreamMeter {}
 windows_core::imp::define_interface!(IAudioEndpointOffloadStreamMute, IAudioEndpointOffloadStreamMute_Vtbl, 0xdfe21355_5ec2_40e0_8d6b_710ac3c00249);
 windows_core::imp::interface_hierarchy!(IAudioEndpointOffloadStreamMute, windows_core::IUnknown);
@@ -212,6 +287,36 @@ impl IAudioEndpointOffloadStreamMute_Vtbl {
         iid == &<IAudioEndpointOffloadStreamMute as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioEndpointOffloadStreamMute_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioEndpointOffloadStreamMute_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetMute<Identity: windows_core::IUnknownImpl + IAudioEndpointOffloadStreamMute_Impl>(this: *mut core::ffi::c_void, bmuted: u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointOffloadStreamMute_Impl::SetMute(this, core::mem::transmute_copy(&bmuted)).into()
+            }
+        }
+        unsafe extern "system" fn GetMute<Identity: windows_core::IUnknownImpl + IAudioEndpointOffloadStreamMute_Impl>(this: *mut core::ffi::c_void, pbmuted: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioEndpointOffloadStreamMute_Impl::GetMute(this) {
+                    Ok(ok__) => {
+                        pbmuted.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetMute: SetMute::<Identity>,
+            GetMute: GetMute::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioEndpointOffloadStreamMute {}
 windows_core::imp::define_interface!(IAudioEndpointOffloadStreamVolume, IAudioEndpointOffloadStreamVolume_Vtbl, 0x64f1dd49_71ca_4281_8672_3a9eddd1d0b6);
 windows_core::imp::interface_hierarchy!(IAudioEndpointOffloadStreamVolume, windows_core::IUnknown);
@@ -295,6 +400,50 @@ impl IAudioEndpointOffloadStreamVolume_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Media_KernelStreaming")]
+impl<Identity: windows_core::IUnknownImpl + IAudioEndpointOffloadStreamVolume_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioEndpointOffloadStreamVolume_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetVolumeChannelCount<Identity: windows_core::IUnknownImpl + IAudioEndpointOffloadStreamVolume_Impl>(this: *mut core::ffi::c_void, pu32channelcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioEndpointOffloadStreamVolume_Impl::GetVolumeChannelCount(this) {
+                    Ok(ok__) => {
+                        pu32channelcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetChannelVolumes<Identity: windows_core::IUnknownImpl + IAudioEndpointOffloadStreamVolume_Impl>(this: *mut core::ffi::c_void, u32channelcount: u32, pf32volumes: *const f32, u32curvetype: super::super::KernelStreaming::AUDIO_CURVE_TYPE, pcurveduration: *const i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointOffloadStreamVolume_Impl::SetChannelVolumes(this, core::mem::transmute_copy(&u32channelcount), core::mem::transmute_copy(&pf32volumes), core::mem::transmute_copy(&u32curvetype), core::mem::transmute_copy(&pcurveduration)).into()
+            }
+        }
+        unsafe extern "system" fn GetChannelVolumes<Identity: windows_core::IUnknownImpl + IAudioEndpointOffloadStreamVolume_Impl>(this: *mut core::ffi::c_void, u32channelcount: u32, pf32volumes: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioEndpointOffloadStreamVolume_Impl::GetChannelVolumes(this, core::mem::transmute_copy(&u32channelcount)) {
+                    Ok(ok__) => {
+                        pf32volumes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetVolumeChannelCount: GetVolumeChannelCount::<Identity>,
+            SetChannelVolumes: SetChannelVolumes::<Identity>,
+            GetChannelVolumes: GetChannelVolumes::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Media_KernelStreaming")]
 impl windows_core::RuntimeName for IAudioEndpointOffloadStreamVolume {}
 windows_core::imp::define_interface!(IAudioEndpointVolume, IAudioEndpointVolume_Vtbl, 0x5cdf2c82_841e_4546_9722_0cf74078229a);
 windows_core::imp::interface_hierarchy!(IAudioEndpointVolume, windows_core::IUnknown);
@@ -602,6 +751,184 @@ impl IAudioEndpointVolume_Vtbl {
         iid == &<IAudioEndpointVolume as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioEndpointVolume_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioEndpointVolume_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterControlChangeNotify<Identity: windows_core::IUnknownImpl + IAudioEndpointVolume_Impl>(this: *mut core::ffi::c_void, pnotify: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointVolume_Impl::RegisterControlChangeNotify(this, core::mem::transmute_copy(&pnotify)).into()
+            }
+        }
+        unsafe extern "system" fn UnregisterControlChangeNotify<Identity: windows_core::IUnknownImpl + IAudioEndpointVolume_Impl>(this: *mut core::ffi::c_void, pnotify: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointVolume_Impl::UnregisterControlChangeNotify(this, core::mem::transmute_copy(&pnotify)).into()
+            }
+        }
+        unsafe extern "system" fn GetChannelCount<Identity: windows_core::IUnknownImpl + IAudioEndpointVolume_Impl>(this: *mut core::ffi::c_void, pnchannelcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioEndpointVolume_Impl::GetChannelCount(this) {
+                    Ok(ok__) => {
+                        pnchannelcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMasterVolumeLevel<Identity: windows_core::IUnknownImpl + IAudioEndpointVolume_Impl>(this: *mut core::ffi::c_void, fleveldb: f32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointVolume_Impl::SetMasterVolumeLevel(this, core::mem::transmute_copy(&fleveldb), core::mem::transmute_copy(&pguideventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn SetMasterVolumeLevelScalar<Identity: windows_core::IUnknownImpl + IAudioEndpointVolume_Impl>(this: *mut core::ffi::c_void, flevel: f32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointVolume_Impl::SetMasterVolumeLevelScalar(this, core::mem::transmute_copy(&flevel), core::mem::transmute_copy(&pguideventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn GetMasterVolumeLevel<Identity: windows_core::IUnknownImpl + IAudioEndpointVolume_Impl>(this: *mut core::ffi::c_void, pfleveldb: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioEndpointVolume_Impl::GetMasterVolumeLevel(this) {
+                    Ok(ok__) => {
+                        pfleveldb.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMasterVolumeLevelScalar<Identity: windows_core::IUnknownImpl + IAudioEndpointVolume_Impl>(this: *mut core::ffi::c_void, pflevel: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioEndpointVolume_Impl::GetMasterVolumeLevelScalar(this) {
+                    Ok(ok__) => {
+                        pflevel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetChannelVolumeLevel<Identity: windows_core::IUnknownImpl + IAudioEndpointVolume_Impl>(this: *mut core::ffi::c_void, nchannel: u32, fleveldb: f32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointVolume_Impl::SetChannelVolumeLevel(this, core::mem::transmute_copy(&nchannel), core::mem::transmute_copy(&fleveldb), core::mem::transmute_copy(&pguideventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn SetChannelVolumeLevelScalar<Identity: windows_core::IUnknownImpl + IAudioEndpointVolume_Impl>(this: *mut core::ffi::c_void, nchannel: u32, flevel: f32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointVolume_Impl::SetChannelVolumeLevelScalar(this, core::mem::transmute_copy(&nchannel), core::mem::transmute_copy(&flevel), core::mem::transmute_copy(&pguideventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn GetChannelVolumeLevel<Identity: windows_core::IUnknownImpl + IAudioEndpointVolume_Impl>(this: *mut core::ffi::c_void, nchannel: u32, pfleveldb: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioEndpointVolume_Impl::GetChannelVolumeLevel(this, core::mem::transmute_copy(&nchannel)) {
+                    Ok(ok__) => {
+                        pfleveldb.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetChannelVolumeLevelScalar<Identity: windows_core::IUnknownImpl + IAudioEndpointVolume_Impl>(this: *mut core::ffi::c_void, nchannel: u32, pflevel: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioEndpointVolume_Impl::GetChannelVolumeLevelScalar(this, core::mem::transmute_copy(&nchannel)) {
+                    Ok(ok__) => {
+                        pflevel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMute<Identity: windows_core::IUnknownImpl + IAudioEndpointVolume_Impl>(this: *mut core::ffi::c_void, bmute: windows_core::BOOL, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointVolume_Impl::SetMute(this, core::mem::transmute_copy(&bmute), core::mem::transmute_copy(&pguideventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn GetMute<Identity: windows_core::IUnknownImpl + IAudioEndpointVolume_Impl>(this: *mut core::ffi::c_void, pbmute: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioEndpointVolume_Impl::GetMute(this) {
+                    Ok(ok__) => {
+                        pbmute.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetVolumeStepInfo<Identity: windows_core::IUnknownImpl + IAudioEndpointVolume_Impl>(this: *mut core::ffi::c_void, pnstep: *mut u32, pnstepcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointVolume_Impl::GetVolumeStepInfo(this, core::mem::transmute_copy(&pnstep), core::mem::transmute_copy(&pnstepcount)).into()
+            }
+        }
+        unsafe extern "system" fn VolumeStepUp<Identity: windows_core::IUnknownImpl + IAudioEndpointVolume_Impl>(this: *mut core::ffi::c_void, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointVolume_Impl::VolumeStepUp(this, core::mem::transmute_copy(&pguideventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn VolumeStepDown<Identity: windows_core::IUnknownImpl + IAudioEndpointVolume_Impl>(this: *mut core::ffi::c_void, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointVolume_Impl::VolumeStepDown(this, core::mem::transmute_copy(&pguideventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn QueryHardwareSupport<Identity: windows_core::IUnknownImpl + IAudioEndpointVolume_Impl>(this: *mut core::ffi::c_void, pdwhardwaresupportmask: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioEndpointVolume_Impl::QueryHardwareSupport(this) {
+                    Ok(ok__) => {
+                        pdwhardwaresupportmask.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetVolumeRange<Identity: windows_core::IUnknownImpl + IAudioEndpointVolume_Impl>(this: *mut core::ffi::c_void, pflvolumemindb: *mut f32, pflvolumemaxdb: *mut f32, pflvolumeincrementdb: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointVolume_Impl::GetVolumeRange(this, core::mem::transmute_copy(&pflvolumemindb), core::mem::transmute_copy(&pflvolumemaxdb), core::mem::transmute_copy(&pflvolumeincrementdb)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterControlChangeNotify: RegisterControlChangeNotify::<Identity>,
+            UnregisterControlChangeNotify: UnregisterControlChangeNotify::<Identity>,
+            GetChannelCount: GetChannelCount::<Identity>,
+            SetMasterVolumeLevel: SetMasterVolumeLevel::<Identity>,
+            SetMasterVolumeLevelScalar: SetMasterVolumeLevelScalar::<Identity>,
+            GetMasterVolumeLevel: GetMasterVolumeLevel::<Identity>,
+            GetMasterVolumeLevelScalar: GetMasterVolumeLevelScalar::<Identity>,
+            SetChannelVolumeLevel: SetChannelVolumeLevel::<Identity>,
+            SetChannelVolumeLevelScalar: SetChannelVolumeLevelScalar::<Identity>,
+            GetChannelVolumeLevel: GetChannelVolumeLevel::<Identity>,
+            GetChannelVolumeLevelScalar: GetChannelVolumeLevelScalar::<Identity>,
+            SetMute: SetMute::<Identity>,
+            GetMute: GetMute::<Identity>,
+            GetVolumeStepInfo: GetVolumeStepInfo::<Identity>,
+            VolumeStepUp: VolumeStepUp::<Identity>,
+            VolumeStepDown: VolumeStepDown::<Identity>,
+            QueryHardwareSupport: QueryHardwareSupport::<Identity>,
+            GetVolumeRange: GetVolumeRange::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioEndpointVolume {}
 windows_core::imp::define_interface!(IAudioEndpointVolumeCallback, IAudioEndpointVolumeCallback_Vtbl, 0x657804fa_d6ad_4496_8a60_352752af4f89);
 windows_core::imp::interface_hierarchy!(IAudioEndpointVolumeCallback, windows_core::IUnknown);
@@ -633,6 +960,20 @@ impl IAudioEndpointVolumeCallback_Vtbl {
         iid == &<IAudioEndpointVolumeCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioEndpointVolumeCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioEndpointVolumeCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnNotify<Identity: windows_core::IUnknownImpl + IAudioEndpointVolumeCallback_Impl>(this: *mut core::ffi::c_void, pnotify: *mut super::AUDIO_VOLUME_NOTIFICATION_DATA) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointVolumeCallback_Impl::OnNotify(this, core::mem::transmute_copy(&pnotify)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnNotify: OnNotify::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IAudioEndpointVolumeCallback {}
 windows_core::imp::define_interface!(IAudioEndpointVolumeEx, IAudioEndpointVolumeEx_Vtbl, 0x66e11784_f695_4f28_a505_a7080081a78f);
 impl core::ops::Deref for IAudioEndpointVolumeEx {
@@ -670,6 +1011,23 @@ impl IAudioEndpointVolumeEx_Vtbl {
         iid == &<IAudioEndpointVolumeEx as windows_core::Interface>::IID || iid == &<IAudioEndpointVolume as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioEndpointVolumeEx_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioEndpointVolumeEx_Vtbl
+where
+    IAudioEndpointVolume_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetVolumeRangeChannel<Identity: windows_core::IUnknownImpl + IAudioEndpointVolumeEx_Impl>(this: *mut core::ffi::c_void, ichannel: u32, pflvolumemindb: *mut f32, pflvolumemaxdb: *mut f32, pflvolumeincrementdb: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointVolumeEx_Impl::GetVolumeRangeChannel(this, core::mem::transmute_copy(&ichannel), core::mem::transmute_copy(&pflvolumemindb), core::mem::transmute_copy(&pflvolumemaxdb), core::mem::transmute_copy(&pflvolumeincrementdb)).into()
+            }
+        }
+        Self {
+            base__: <IAudioEndpointVolume_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetVolumeRangeChannel: GetVolumeRangeChannel::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioEndpointVolumeEx {}
 windows_core::imp::define_interface!(IAudioLfxControl, IAudioLfxControl_Vtbl, 0x076a6922_d802_4f83_baf6_409d9ca11bfe);
 windows_core::imp::interface_hierarchy!(IAudioLfxControl, windows_core::IUnknown);
@@ -725,6 +1083,36 @@ impl IAudioLfxControl_Vtbl {
         iid == &<IAudioLfxControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioLfxControl_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioLfxControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetLocalEffectsState<Identity: windows_core::IUnknownImpl + IAudioLfxControl_Impl>(this: *mut core::ffi::c_void, benabled: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioLfxControl_Impl::SetLocalEffectsState(this, core::mem::transmute_copy(&benabled)).into()
+            }
+        }
+        unsafe extern "system" fn GetLocalEffectsState<Identity: windows_core::IUnknownImpl + IAudioLfxControl_Impl>(this: *mut core::ffi::c_void, pbenabled: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioLfxControl_Impl::GetLocalEffectsState(this) {
+                    Ok(ok__) => {
+                        pbenabled.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetLocalEffectsState: SetLocalEffectsState::<Identity>,
+            GetLocalEffectsState: GetLocalEffectsState::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioLfxControl {}
 windows_core::imp::define_interface!(IAudioMeterInformation, IAudioMeterInformation_Vtbl, 0xc02216f6_8c67_4b5b_9d00_d008e73e0064);
 windows_core::imp::interface_hierarchy!(IAudioMeterInformation, windows_core::IUnknown);
@@ -822,6 +1210,62 @@ impl IAudioMeterInformation_Vtbl {
         iid == &<IAudioMeterInformation as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioMeterInformation_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioMeterInformation_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPeakValue<Identity: windows_core::IUnknownImpl + IAudioMeterInformation_Impl>(this: *mut core::ffi::c_void, pfpeak: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioMeterInformation_Impl::GetPeakValue(this) {
+                    Ok(ok__) => {
+                        pfpeak.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMeteringChannelCount<Identity: windows_core::IUnknownImpl + IAudioMeterInformation_Impl>(this: *mut core::ffi::c_void, pnchannelcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioMeterInformation_Impl::GetMeteringChannelCount(this) {
+                    Ok(ok__) => {
+                        pnchannelcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetChannelsPeakValues<Identity: windows_core::IUnknownImpl + IAudioMeterInformation_Impl>(this: *mut core::ffi::c_void, u32channelcount: u32, afpeakvalues: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioMeterInformation_Impl::GetChannelsPeakValues(this, core::mem::transmute_copy(&u32channelcount), core::mem::transmute_copy(&afpeakvalues)).into()
+            }
+        }
+        unsafe extern "system" fn QueryHardwareSupport<Identity: windows_core::IUnknownImpl + IAudioMeterInformation_Impl>(this: *mut core::ffi::c_void, pdwhardwaresupportmask: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioMeterInformation_Impl::QueryHardwareSupport(this) {
+                    Ok(ok__) => {
+                        pdwhardwaresupportmask.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPeakValue: GetPeakValue::<Identity>,
+            GetMeteringChannelCount: GetMeteringChannelCount::<Identity>,
+            GetChannelsPeakValues: GetChannelsPeakValues::<Identity>,
+            QueryHardwareSupport: QueryHardwareSupport::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioMeterInformation {}
 windows_core::imp::define_interface!(IHardwareAudioEngineBase, IHardwareAudioEngineBase_Vtbl, 0xeddce3e4_f3c1_453a_b461_223563cbd886);
 windows_core::imp::interface_hierarchy!(IHardwareAudioEngineBase, windows_core::IUnknown);
@@ -937,6 +1381,63 @@ impl IHardwareAudioEngineBase_Vtbl {
         iid == &<IHardwareAudioEngineBase as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IHardwareAudioEngineBase_Impl> windows_core::imp::TearOffVtable<Identity> for IHardwareAudioEngineBase_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAvailableOffloadConnectorCount<Identity: windows_core::IUnknownImpl + IHardwareAudioEngineBase_Impl>(this: *mut core::ffi::c_void, _pwstrdeviceid: windows_core::PCWSTR, _uconnectorid: u32, _pavailableconnectorinstancecount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHardwareAudioEngineBase_Impl::GetAvailableOffloadConnectorCount(this, core::mem::transmute(&_pwstrdeviceid), core::mem::transmute_copy(&_uconnectorid)) {
+                    Ok(ok__) => {
+                        _pavailableconnectorinstancecount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetEngineFormat<Identity: windows_core::IUnknownImpl + IHardwareAudioEngineBase_Impl>(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, _brequestdeviceformat: windows_core::BOOL, _ppwfxformat: *mut *mut super::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHardwareAudioEngineBase_Impl::GetEngineFormat(this, core::mem::transmute_copy(&pdevice), core::mem::transmute_copy(&_brequestdeviceformat), core::mem::transmute_copy(&_ppwfxformat)).into()
+            }
+        }
+        unsafe extern "system" fn SetEngineDeviceFormat<Identity: windows_core::IUnknownImpl + IHardwareAudioEngineBase_Impl>(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, _pwfxformat: *mut super::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHardwareAudioEngineBase_Impl::SetEngineDeviceFormat(this, core::mem::transmute_copy(&pdevice), core::mem::transmute_copy(&_pwfxformat)).into()
+            }
+        }
+        unsafe extern "system" fn SetGfxState<Identity: windows_core::IUnknownImpl + IHardwareAudioEngineBase_Impl>(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, _benable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHardwareAudioEngineBase_Impl::SetGfxState(this, core::mem::transmute_copy(&pdevice), core::mem::transmute_copy(&_benable)).into()
+            }
+        }
+        unsafe extern "system" fn GetGfxState<Identity: windows_core::IUnknownImpl + IHardwareAudioEngineBase_Impl>(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, _pbenable: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHardwareAudioEngineBase_Impl::GetGfxState(this, core::mem::transmute_copy(&pdevice)) {
+                    Ok(ok__) => {
+                        _pbenable.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAvailableOffloadConnectorCount: GetAvailableOffloadConnectorCount::<Identity>,
+            GetEngineFormat: GetEngineFormat::<Identity>,
+            SetEngineDeviceFormat: SetEngineDeviceFormat::<Identity>,
+            SetGfxState: SetGfxState::<Identity>,
+            GetGfxState: GetGfxState::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IHardwareAudioEngineBase {}
 pub const eConnectorCount: EndpointConnectorType = EndpointConnectorType(4i32);
 pub const eHostProcessConnector: EndpointConnectorType = EndpointConnectorType(0i32);
@@ -342,6 +342,92 @@ impl IXAPO_Vtbl {
         iid == &<IXAPO as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXAPO_Impl> windows_core::imp::TearOffVtable<Identity> for IXAPO_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRegistrationProperties<Identity: windows_core::IUnknownImpl + IXAPO_Impl>(this: *mut core::ffi::c_void, ppregistrationproperties: *mut *mut XAPO_REGISTRATION_PROPERTIES) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXAPO_Impl::GetRegistrationProperties(this) {
+                    Ok(ok__) => {
+                        ppregistrationproperties.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsInputFormatSupported<Identity: windows_core::IUnknownImpl + IXAPO_Impl>(this: *mut core::ffi::c_void, poutputformat: *const super::WAVEFORMATEX, prequestedinputformat: *const super::WAVEFORMATEX, ppsupportedinputformat: *mut *mut super::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAPO_Impl::IsInputFormatSupported(this, core::mem::transmute_copy(&poutputformat), core::mem::transmute_copy(&prequestedinputformat), core::mem::transmute_copy(&ppsupportedinputformat)).into()
+            }
+        }
+        unsafe extern "system" fn IsOutputFormatSupported<Identity: windows_core::IUnknownImpl + IXAPO_Impl>(this: *mut core::ffi::c_void, pinputformat: *const super::WAVEFORMATEX, prequestedoutputformat: *const super::WAVEFORMATEX, ppsupportedoutputformat: *mut *mut super::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAPO_Impl::IsOutputFormatSupported(this, core::mem::transmute_copy(&pinputformat), core::mem::transmute_copy(&prequestedoutputformat), core::mem::transmute_copy(&ppsupportedoutputformat)).into()
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IXAPO_Impl>(this: *mut core::ffi::c_void, pdata: *const core::ffi::c_void, databytesize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAPO_Impl::Initialize(this, core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&databytesize)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IXAPO_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAPO_Impl::Reset(this)
+            }
+        }
+        unsafe extern "system" fn LockForProcess<Identity: windows_core::IUnknownImpl + IXAPO_Impl>(this: *mut core::ffi::c_void, inputlockedparametercount: u32, pinputlockedparameters: *const XAPO_LOCKFORPROCESS_PARAMETERS, outputlockedparametercount: u32, poutputlockedparameters: *const XAPO_LOCKFORPROCESS_PARAMETERS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAPO_Impl::LockForProcess(this, core::mem::transmute_copy(&inputlockedparametercount), core::mem::transmute_copy(&pinputlockedparameters), core::mem::transmute_copy(&outputlockedparametercount), core::mem::transmute_copy(&poutputlockedparameters)).into()
+            }
+        }
+        unsafe extern "system" fn UnlockForProcess<Identity: windows_core::IUnknownImpl + IXAPO_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAPO_Impl::UnlockForProcess(this)
+            }
+        }
+        unsafe extern "system" fn Process<Identity: windows_core::IUnknownImpl + IXAPO_Impl>(this: *mut core::ffi::c_void, inputprocessparametercount: u32, pinputprocessparameters: *const XAPO_PROCESS_BUFFER_PARAMETERS, outputprocessparametercount: u32, poutputprocessparameters: *mut XAPO_PROCESS_BUFFER_PARAMETERS, isenabled: windows_core::BOOL) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAPO_Impl::Process(this, core::mem::transmute_copy(&inputprocessparametercount), core::mem::transmute_copy(&pinputprocessparameters), core::mem::transmute_copy(&outputprocessparametercount), core::mem::transmute_copy(&poutputprocessparameters), core::mem::transmute_copy(&isenabled))
+            }
+        }
+        unsafe extern "system" fn CalcInputFrames<Identity: windows_core::IUnknownImpl + IXAPO_Impl>(this: *mut core::ffi::c_void, outputframecount: u32) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAPO_Impl::CalcInputFrames(this, core::mem::transmute_copy(&outputframecount))
+            }
+        }
+        unsafe extern "system" fn CalcOutputFrames<Identity: windows_core::IUnknownImpl + IXAPO_Impl>(this: *mut core::ffi::c_void, inputframecount: u32) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAPO_Impl::CalcOutputFrames(this, core::mem::transmute_copy(&inputframecount))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetRegistrationProperties: GetRegistrationProperties::<Identity>,
+            IsInputFormatSupported: IsInputFormatSupported::<Identity>,
+            IsOutputFormatSupported: IsOutputFormatSupported::<Identity>,
+            Initialize: Initialize::<Identity>,
+            Reset: Reset::<Identity>,
+            LockForProcess: LockForProcess::<Identity>,
+            UnlockForProcess: UnlockForProcess::<Identity>,
+            Process: Process::<Identity>,
+            CalcInputFrames: CalcInputFrames::<Identity>,
+            CalcOutputFrames: CalcOutputFrames::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IXAPO {}
 windows_core::imp::define_interface!(IXAPOHrtfParameters, IXAPOHrtfParameters_Vtbl, 0x15b3cd66_e9de_4464_b6e6_2bc3cf63d455);
 windows_core::imp::interface_hierarchy!(IXAPOHrtfParameters, windows_core::IUnknown);
@@ -412,6 +498,44 @@ impl IXAPOHrtfParameters_Vtbl {
         iid == &<IXAPOHrtfParameters as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXAPOHrtfParameters_Impl> windows_core::imp::TearOffVtable<Identity> for IXAPOHrtfParameters_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetSourcePosition<Identity: windows_core::IUnknownImpl + IXAPOHrtfParameters_Impl>(this: *mut core::ffi::c_void, position: *const HrtfPosition) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAPOHrtfParameters_Impl::SetSourcePosition(this, core::mem::transmute_copy(&position)).into()
+            }
+        }
+        unsafe extern "system" fn SetSourceOrientation<Identity: windows_core::IUnknownImpl + IXAPOHrtfParameters_Impl>(this: *mut core::ffi::c_void, orientation: *const HrtfOrientation) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAPOHrtfParameters_Impl::SetSourceOrientation(this, core::mem::transmute_copy(&orientation)).into()
+            }
+        }
+        unsafe extern "system" fn SetSourceGain<Identity: windows_core::IUnknownImpl + IXAPOHrtfParameters_Impl>(this: *mut core::ffi::c_void, gain: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAPOHrtfParameters_Impl::SetSourceGain(this, core::mem::transmute_copy(&gain)).into()
+            }
+        }
+        unsafe extern "system" fn SetEnvironment<Identity: windows_core::IUnknownImpl + IXAPOHrtfParameters_Impl>(this: *mut core::ffi::c_void, environment: HrtfEnvironment) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAPOHrtfParameters_Impl::SetEnvironment(this, core::mem::transmute_copy(&environment)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetSourcePosition: SetSourcePosition::<Identity>,
+            SetSourceOrientation: SetSourceOrientation::<Identity>,
+            SetSourceGain: SetSourceGain::<Identity>,
+            SetEnvironment: SetEnvironment::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IXAPOHrtfParameters {}
 windows_core::imp::define_interface!(IXAPOParameters, IXAPOParameters_Vtbl, 0x26d95c66_80f2_499a_ad54_5ae7f01c6d98);
 windows_core::imp::interface_hierarchy!(IXAPOParameters, windows_core::IUnknown);
@@ -458,6 +582,30 @@ impl IXAPOParameters_Vtbl {
         iid == &<IXAPOParameters as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXAPOParameters_Impl> windows_core::imp::TearOffVtable<Identity> for IXAPOParameters_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetParameters<Identity: windows_core::IUnknownImpl + IXAPOParameters_Impl>(this: *mut core::ffi::c_void, pparameters: *const core::ffi::c_void, parameterbytesize: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAPOParameters_Impl::SetParameters(this, core::mem::transmute_copy(&pparameters), core::mem::transmute_copy(&parameterbytesize))
+            }
+        }
+        unsafe extern "system" fn GetParameters<Identity: windows_core::IUnknownImpl + IXAPOParameters_Impl>(this: *mut core::ffi::c_void, pparameters: *mut core::ffi::c_void, parameterbytesize: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAPOParameters_Impl::GetParameters(this, core::mem::transmute_copy(&pparameters), core::mem::transmute_copy(&parameterbytesize))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetParameters: SetParameters::<Identity>,
+            GetParameters: GetParameters::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IXAPOParameters {}
 windows_core::imp::define_interface!(IXAudio2, IXAudio2_Vtbl, 0x2b02e3cf_2e0b_4ec3_be45_1b2a3fe7210d);
 windows_core::imp::interface_hierarchy!(IXAudio2, windows_core::IUnknown);
@@ -612,6 +760,86 @@ impl IXAudio2_Vtbl {
         iid == &<IXAudio2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXAudio2_Impl> windows_core::imp::TearOffVtable<Identity> for IXAudio2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterForCallbacks<Identity: windows_core::IUnknownImpl + IXAudio2_Impl>(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAudio2_Impl::RegisterForCallbacks(this, core::mem::transmute_copy(&pcallback)).into()
+            }
+        }
+        unsafe extern "system" fn UnregisterForCallbacks<Identity: windows_core::IUnknownImpl + IXAudio2_Impl>(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAudio2_Impl::UnregisterForCallbacks(this, core::mem::transmute_copy(&pcallback))
+            }
+        }
+        unsafe extern "system" fn CreateSourceVoice<Identity: windows_core::IUnknownImpl + IXAudio2_Impl>(this: *mut core::ffi::c_void, ppsourcevoice: *mut *mut core::ffi::c_void, psourceformat: *const super::WAVEFORMATEX, flags: u32, maxfrequencyratio: f32, pcallback: *mut core::ffi::c_void, psendlist: *const XAUDIO2_VOICE_SENDS, peffectchain: *const XAUDIO2_EFFECT_CHAIN) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAudio2_Impl::CreateSourceVoice(this, core::mem::transmute_copy(&ppsourcevoice), core::mem::transmute_copy(&psourceformat), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&maxfrequencyratio), core::mem::transmute_copy(&pcallback), core::mem::transmute_copy(&psendlist), core::mem::transmute_copy(&peffectchain)).into()
+            }
+        }
+        unsafe extern "system" fn CreateSubmixVoice<Identity: windows_core::IUnknownImpl + IXAudio2_Impl>(this: *mut core::ffi::c_void, ppsubmixvoice: *mut *mut core::ffi::c_void, inputchannels: u32, inputsamplerate: u32, flags: u32, processingstage: u32, psendlist: *const XAUDIO2_VOICE_SENDS, peffectchain: *const XAUDIO2_EFFECT_CHAIN) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAudio2_Impl::CreateSubmixVoice(this, core::mem::transmute_copy(&ppsubmixvoice), core::mem::transmute_copy(&inputchannels), core::mem::transmute_copy(&inputsamplerate), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&processingstage), core::mem::transmute_copy(&psendlist), core::mem::transmute_copy(&peffectchain)).into()
+            }
+        }
+        unsafe extern "system" fn CreateMasteringVoice<Identity: windows_core::IUnknownImpl + IXAudio2_Impl>(this: *mut core::ffi::c_void, ppmasteringvoice: *mut *mut core::ffi::c_void, inputchannels: u32, inputsamplerate: u32, flags: u32, szdeviceid: windows_core::PCWSTR, peffectchain: *const XAUDIO2_EFFECT_CHAIN, streamcategory: super::AUDIO_STREAM_CATEGORY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAudio2_Impl::CreateMasteringVoice(this, core::mem::transmute_copy(&ppmasteringvoice), core::mem::transmute_copy(&inputchannels), core::mem::transmute_copy(&inputsamplerate), core::mem::transmute_copy(&flags), core::mem::transmute(&szdeviceid), core::mem::transmute_copy(&peffectchain), core::mem::transmute_copy(&streamcategory)).into()
+            }
+        }
+        unsafe extern "system" fn StartEngine<Identity: windows_core::IUnknownImpl + IXAudio2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAudio2_Impl::StartEngine(this).into()
+            }
+        }
+        unsafe extern "system" fn StopEngine<Identity: windows_core::IUnknownImpl + IXAudio2_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAudio2_Impl::StopEngine(this)
+            }
+        }
+        unsafe extern "system" fn CommitChanges<Identity: windows_core::IUnknownImpl + IXAudio2_Impl>(this: *mut core::ffi::c_void, operationset: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAudio2_Impl::CommitChanges(this, core::mem::transmute_copy(&operationset)).into()
+            }
+        }
+        unsafe extern "system" fn GetPerformanceData<Identity: windows_core::IUnknownImpl + IXAudio2_Impl>(this: *mut core::ffi::c_void, pperfdata: *mut XAUDIO2_PERFORMANCE_DATA) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAudio2_Impl::GetPerformanceData(this, core::mem::transmute_copy(&pperfdata))
+            }
+        }
+        unsafe extern "system" fn SetDebugConfiguration<Identity: windows_core::IUnknownImpl + IXAudio2_Impl>(this: *mut core::ffi::c_void, pdebugconfiguration: *const XAUDIO2_DEBUG_CONFIGURATION, preserved: *const core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAudio2_Impl::SetDebugConfiguration(this, core::mem::transmute_copy(&pdebugconfiguration), core::mem::transmute_copy(&preserved))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterForCallbacks: RegisterForCallbacks::<Identity>,
+            UnregisterForCallbacks: UnregisterForCallbacks::<Identity>,
+            CreateSourceVoice: CreateSourceVoice::<Identity>,
+            CreateSubmixVoice: CreateSubmixVoice::<Identity>,
+            CreateMasteringVoice: CreateMasteringVoice::<Identity>,
+            StartEngine: StartEngine::<Identity>,
+            StopEngine: StopEngine::<Identity>,
+            CommitChanges: CommitChanges::<Identity>,
+            GetPerformanceData: GetPerformanceData::<Identity>,
+            SetDebugConfiguration: SetDebugConfiguration::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IXAudio2 {}
 windows_core::imp::define_interface!(IXAudio2EngineCallback, IXAudio2EngineCallback_Vtbl);
 impl IXAudio2EngineCallback {
@@ -723,6 +951,30 @@ impl IXAudio2Extension_Vtbl {
         iid == &<IXAudio2Extension as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXAudio2Extension_Impl> windows_core::imp::TearOffVtable<Identity> for IXAudio2Extension_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProcessingQuantum<Identity: windows_core::IUnknownImpl + IXAudio2Extension_Impl>(this: *mut core::ffi::c_void, quantumnumerator: *mut u32, quantumdenominator: *mut u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAudio2Extension_Impl::GetProcessingQuantum(this, core::mem::transmute_copy(&quantumnumerator), core::mem::transmute_copy(&quantumdenominator))
+            }
+        }
+        unsafe extern "system" fn GetProcessor<Identity: windows_core::IUnknownImpl + IXAudio2Extension_Impl>(this: *mut core::ffi::c_void, processor: *mut u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAudio2Extension_Impl::GetProcessor(this, core::mem::transmute_copy(&processor))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProcessingQuantum: GetProcessingQuantum::<Identity>,
+            GetProcessor: GetProcessor::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IXAudio2Extension {}
 windows_core::imp::define_interface!(IXAudio2MasteringVoice, IXAudio2MasteringVoice_Vtbl);
 impl core::ops::Deref for IXAudio2MasteringVoice {
@@ -2208,6 +2208,23 @@ impl IAcousticEchoCancellationControl_Vtbl {
         iid == &<IAcousticEchoCancellationControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAcousticEchoCancellationControl_Impl> windows_core::imp::TearOffVtable<Identity> for IAcousticEchoCancellationControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetEchoCancellationRenderEndpoint<Identity: windows_core::IUnknownImpl + IAcousticEchoCancellationControl_Impl>(this: *mut core::ffi::c_void, endpointid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAcousticEchoCancellationControl_Impl::SetEchoCancellationRenderEndpoint(this, core::mem::transmute(&endpointid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetEchoCancellationRenderEndpoint: SetEchoCancellationRenderEndpoint::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAcousticEchoCancellationControl {}
 windows_core::imp::define_interface!(IActivateAudioInterfaceAsyncOperation, IActivateAudioInterfaceAsyncOperation_Vtbl, 0x72a22d78_cde4_431d_b8cc_843a71199b6d);
 windows_core::imp::interface_hierarchy!(IActivateAudioInterfaceAsyncOperation, windows_core::IUnknown);
@@ -2239,6 +2256,20 @@ impl IActivateAudioInterfaceAsyncOperation_Vtbl {
         iid == &<IActivateAudioInterfaceAsyncOperation as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActivateAudioInterfaceAsyncOperation_Impl> windows_core::imp::TearOffVtable<Identity> for IActivateAudioInterfaceAsyncOperation_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetActivateResult<Identity: windows_core::IUnknownImpl + IActivateAudioInterfaceAsyncOperation_Impl>(this: *mut core::ffi::c_void, activateresult: *mut windows_core::HRESULT, activatedinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActivateAudioInterfaceAsyncOperation_Impl::GetActivateResult(this, core::mem::transmute_copy(&activateresult), core::mem::transmute_copy(&activatedinterface)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetActivateResult: GetActivateResult::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IActivateAudioInterfaceAsyncOperation {}
 windows_core::imp::define_interface!(IActivateAudioInterfaceCompletionHandler, IActivateAudioInterfaceCompletionHandler_Vtbl, 0x41d949ab_9862_444a_80f6_c261334da5eb);
 windows_core::imp::interface_hierarchy!(IActivateAudioInterfaceCompletionHandler, windows_core::IUnknown);
@@ -2273,6 +2304,20 @@ impl IActivateAudioInterfaceCompletionHandler_Vtbl {
         iid == &<IActivateAudioInterfaceCompletionHandler as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActivateAudioInterfaceCompletionHandler_Impl> windows_core::imp::TearOffVtable<Identity> for IActivateAudioInterfaceCompletionHandler_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ActivateCompleted<Identity: windows_core::IUnknownImpl + IActivateAudioInterfaceCompletionHandler_Impl>(this: *mut core::ffi::c_void, activateoperation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActivateAudioInterfaceCompletionHandler_Impl::ActivateCompleted(this, core::mem::transmute_copy(&activateoperation)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ActivateCompleted: ActivateCompleted::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IActivateAudioInterfaceCompletionHandler {}
 windows_core::imp::define_interface!(IAudioAmbisonicsControl, IAudioAmbisonicsControl_Vtbl, 0x28724c91_df35_4856_9f76_d6a26413f3df);
 windows_core::imp::interface_hierarchy!(IAudioAmbisonicsControl, windows_core::IUnknown);
@@ -2352,6 +2397,50 @@ impl IAudioAmbisonicsControl_Vtbl {
         iid == &<IAudioAmbisonicsControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioAmbisonicsControl_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioAmbisonicsControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetData<Identity: windows_core::IUnknownImpl + IAudioAmbisonicsControl_Impl>(this: *mut core::ffi::c_void, pambisonicsparams: *const AMBISONICS_PARAMS, cbambisonicsparams: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioAmbisonicsControl_Impl::SetData(this, core::mem::transmute_copy(&pambisonicsparams), core::mem::transmute_copy(&cbambisonicsparams)).into()
+            }
+        }
+        unsafe extern "system" fn SetHeadTracking<Identity: windows_core::IUnknownImpl + IAudioAmbisonicsControl_Impl>(this: *mut core::ffi::c_void, benableheadtracking: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioAmbisonicsControl_Impl::SetHeadTracking(this, core::mem::transmute_copy(&benableheadtracking)).into()
+            }
+        }
+        unsafe extern "system" fn GetHeadTracking<Identity: windows_core::IUnknownImpl + IAudioAmbisonicsControl_Impl>(this: *mut core::ffi::c_void, pbenableheadtracking: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioAmbisonicsControl_Impl::GetHeadTracking(this) {
+                    Ok(ok__) => {
+                        pbenableheadtracking.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRotation<Identity: windows_core::IUnknownImpl + IAudioAmbisonicsControl_Impl>(this: *mut core::ffi::c_void, x: f32, y: f32, z: f32, w: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioAmbisonicsControl_Impl::SetRotation(this, core::mem::transmute_copy(&x), core::mem::transmute_copy(&y), core::mem::transmute_copy(&z), core::mem::transmute_copy(&w)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetData: SetData::<Identity>,
+            SetHeadTracking: SetHeadTracking::<Identity>,
+            GetHeadTracking: GetHeadTracking::<Identity>,
+            SetRotation: SetRotation::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioAmbisonicsControl {}
 windows_core::imp::define_interface!(IAudioAutoGainControl, IAudioAutoGainControl_Vtbl, 0x85401fd4_6de4_4b9d_9869_2d6753a82f3c);
 windows_core::imp::interface_hierarchy!(IAudioAutoGainControl, windows_core::IUnknown);
@@ -2407,6 +2496,36 @@ impl IAudioAutoGainControl_Vtbl {
         iid == &<IAudioAutoGainControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioAutoGainControl_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioAutoGainControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetEnabled<Identity: windows_core::IUnknownImpl + IAudioAutoGainControl_Impl>(this: *mut core::ffi::c_void, pbenabled: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioAutoGainControl_Impl::GetEnabled(this) {
+                    Ok(ok__) => {
+                        pbenabled.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEnabled<Identity: windows_core::IUnknownImpl + IAudioAutoGainControl_Impl>(this: *mut core::ffi::c_void, benable: windows_core::BOOL, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioAutoGainControl_Impl::SetEnabled(this, core::mem::transmute_copy(&benable), core::mem::transmute_copy(&pguideventcontext)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetEnabled: GetEnabled::<Identity>,
+            SetEnabled: SetEnabled::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioAutoGainControl {}
 windows_core::imp::define_interface!(IAudioBass, IAudioBass_Vtbl, 0xa2b1a1d9_4db3_425d_a2b2_bd335cb3e2e5);
 impl core::ops::Deref for IAudioBass {
@@ -2430,6 +2549,12 @@ impl IAudioBass_Vtbl {
         iid == &<IAudioBass as windows_core::Interface>::IID || iid == &<IPerChannelDbLevel as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioBass_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioBass_Vtbl
+where
+    IPerChannelDbLevel_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IPerChannelDbLevel_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IAudioBass {}
 windows_core::imp::define_interface!(IAudioCaptureClient, IAudioCaptureClient_Vtbl, 0xc8adbd64_e71e_48a0_a4de_185c395cd317);
 windows_core::imp::interface_hierarchy!(IAudioCaptureClient, windows_core::IUnknown);
@@ -2497,6 +2622,43 @@ impl IAudioCaptureClient_Vtbl {
         iid == &<IAudioCaptureClient as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioCaptureClient_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioCaptureClient_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetBuffer<Identity: windows_core::IUnknownImpl + IAudioCaptureClient_Impl>(this: *mut core::ffi::c_void, ppdata: *mut *mut u8, pnumframestoread: *mut u32, pdwflags: *mut u32, pu64deviceposition: *mut u64, pu64qpcposition: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioCaptureClient_Impl::GetBuffer(this, core::mem::transmute_copy(&ppdata), core::mem::transmute_copy(&pnumframestoread), core::mem::transmute_copy(&pdwflags), core::mem::transmute_copy(&pu64deviceposition), core::mem::transmute_copy(&pu64qpcposition)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseBuffer<Identity: windows_core::IUnknownImpl + IAudioCaptureClient_Impl>(this: *mut core::ffi::c_void, numframesread: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioCaptureClient_Impl::ReleaseBuffer(this, core::mem::transmute_copy(&numframesread)).into()
+            }
+        }
+        unsafe extern "system" fn GetNextPacketSize<Identity: windows_core::IUnknownImpl + IAudioCaptureClient_Impl>(this: *mut core::ffi::c_void, pnumframesinnextpacket: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioCaptureClient_Impl::GetNextPacketSize(this) {
+                    Ok(ok__) => {
+                        pnumframesinnextpacket.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetBuffer: GetBuffer::<Identity>,
+            ReleaseBuffer: ReleaseBuffer::<Identity>,
+            GetNextPacketSize: GetNextPacketSize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioCaptureClient {}
 windows_core::imp::define_interface!(IAudioChannelConfig, IAudioChannelConfig_Vtbl, 0xbb11c46f_ec28_493c_b88a_5db88062ce98);
 windows_core::imp::interface_hierarchy!(IAudioChannelConfig, windows_core::IUnknown);
@@ -2552,6 +2714,36 @@ impl IAudioChannelConfig_Vtbl {
         iid == &<IAudioChannelConfig as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioChannelConfig_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioChannelConfig_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetChannelConfig<Identity: windows_core::IUnknownImpl + IAudioChannelConfig_Impl>(this: *mut core::ffi::c_void, dwconfig: u32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioChannelConfig_Impl::SetChannelConfig(this, core::mem::transmute_copy(&dwconfig), core::mem::transmute_copy(&pguideventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn GetChannelConfig<Identity: windows_core::IUnknownImpl + IAudioChannelConfig_Impl>(this: *mut core::ffi::c_void, pdwconfig: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioChannelConfig_Impl::GetChannelConfig(this) {
+                    Ok(ok__) => {
+                        pdwconfig.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetChannelConfig: SetChannelConfig::<Identity>,
+            GetChannelConfig: GetChannelConfig::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioChannelConfig {}
 windows_core::imp::define_interface!(IAudioClient, IAudioClient_Vtbl, 0x1cb9ad4c_dbfa_4c32_b178_c2f568a703b2);
 windows_core::imp::interface_hierarchy!(IAudioClient, windows_core::IUnknown);
@@ -2758,6 +2950,124 @@ impl IAudioClient_Vtbl {
         iid == &<IAudioClient as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioClient_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioClient_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IAudioClient_Impl>(this: *mut core::ffi::c_void, sharemode: AUDCLNT_SHAREMODE, streamflags: u32, hnsbufferduration: i64, hnsperiodicity: i64, pformat: *const WAVEFORMATEX, audiosessionguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioClient_Impl::Initialize(this, core::mem::transmute_copy(&sharemode), core::mem::transmute_copy(&streamflags), core::mem::transmute_copy(&hnsbufferduration), core::mem::transmute_copy(&hnsperiodicity), core::mem::transmute_copy(&pformat), core::mem::transmute_copy(&audiosessionguid)).into()
+            }
+        }
+        unsafe extern "system" fn GetBufferSize<Identity: windows_core::IUnknownImpl + IAudioClient_Impl>(this: *mut core::ffi::c_void, pnumbufferframes: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioClient_Impl::GetBufferSize(this) {
+                    Ok(ok__) => {
+                        pnumbufferframes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStreamLatency<Identity: windows_core::IUnknownImpl + IAudioClient_Impl>(this: *mut core::ffi::c_void, phnslatency: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioClient_Impl::GetStreamLatency(this) {
+                    Ok(ok__) => {
+                        phnslatency.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCurrentPadding<Identity: windows_core::IUnknownImpl + IAudioClient_Impl>(this: *mut core::ffi::c_void, pnumpaddingframes: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioClient_Impl::GetCurrentPadding(this) {
+                    Ok(ok__) => {
+                        pnumpaddingframes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsFormatSupported<Identity: windows_core::IUnknownImpl + IAudioClient_Impl>(this: *mut core::ffi::c_void, sharemode: AUDCLNT_SHAREMODE, pformat: *const WAVEFORMATEX, ppclosestmatch: *mut *mut WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioClient_Impl::IsFormatSupported(this, core::mem::transmute_copy(&sharemode), core::mem::transmute_copy(&pformat), core::mem::transmute_copy(&ppclosestmatch))
+            }
+        }
+        unsafe extern "system" fn GetMixFormat<Identity: windows_core::IUnknownImpl + IAudioClient_Impl>(this: *mut core::ffi::c_void, ppdeviceformat: *mut *mut WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioClient_Impl::GetMixFormat(this) {
+                    Ok(ok__) => {
+                        ppdeviceformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDevicePeriod<Identity: windows_core::IUnknownImpl + IAudioClient_Impl>(this: *mut core::ffi::c_void, phnsdefaultdeviceperiod: *mut i64, phnsminimumdeviceperiod: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioClient_Impl::GetDevicePeriod(this, core::mem::transmute_copy(&phnsdefaultdeviceperiod), core::mem::transmute_copy(&phnsminimumdeviceperiod)).into()
+            }
+        }
+        unsafe extern "system" fn Start<Identity: windows_core::IUnknownImpl + IAudioClient_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioClient_Impl::Start(this).into()
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + IAudioClient_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioClient_Impl::Stop(this).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IAudioClient_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioClient_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn SetEventHandle<Identity: windows_core::IUnknownImpl + IAudioClient_Impl>(this: *mut core::ffi::c_void, eventhandle: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioClient_Impl::SetEventHandle(this, core::mem::transmute_copy(&eventhandle)).into()
+            }
+        }
+        unsafe extern "system" fn GetService<Identity: windows_core::IUnknownImpl + IAudioClient_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioClient_Impl::GetService(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppv)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            GetBufferSize: GetBufferSize::<Identity>,
+            GetStreamLatency: GetStreamLatency::<Identity>,
+            GetCurrentPadding: GetCurrentPadding::<Identity>,
+            IsFormatSupported: IsFormatSupported::<Identity>,
+            GetMixFormat: GetMixFormat::<Identity>,
+            GetDevicePeriod: GetDevicePeriod::<Identity>,
+            Start: Start::<Identity>,
+            Stop: Stop::<Identity>,
+            Reset: Reset::<Identity>,
+            SetEventHandle: SetEventHandle::<Identity>,
+            GetService: GetService::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioClient {}
 windows_core::imp::define_interface!(IAudioClient2, IAudioClient2_Vtbl, 0x726778cd_f60a_4eda_82de_e47610cd78aa);
 impl core::ops::Deref for IAudioClient2 {
@@ -2831,6 +3141,43 @@ impl IAudioClient2_Vtbl {
         iid == &<IAudioClient2 as windows_core::Interface>::IID || iid == &<IAudioClient as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioClient2_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioClient2_Vtbl
+where
+    IAudioClient_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsOffloadCapable<Identity: windows_core::IUnknownImpl + IAudioClient2_Impl>(this: *mut core::ffi::c_void, category: AUDIO_STREAM_CATEGORY, pboffloadcapable: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioClient2_Impl::IsOffloadCapable(this, core::mem::transmute_copy(&category)) {
+                    Ok(ok__) => {
+                        pboffloadcapable.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetClientProperties<Identity: windows_core::IUnknownImpl + IAudioClient2_Impl>(this: *mut core::ffi::c_void, pproperties: *const AudioClientProperties) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioClient2_Impl::SetClientProperties(this, core::mem::transmute_copy(&pproperties)).into()
+            }
+        }
+        unsafe extern "system" fn GetBufferSizeLimits<Identity: windows_core::IUnknownImpl + IAudioClient2_Impl>(this: *mut core::ffi::c_void, pformat: *const WAVEFORMATEX, beventdriven: windows_core::BOOL, phnsminbufferduration: *mut i64, phnsmaxbufferduration: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioClient2_Impl::GetBufferSizeLimits(this, core::mem::transmute_copy(&pformat), core::mem::transmute_copy(&beventdriven), core::mem::transmute_copy(&phnsminbufferduration), core::mem::transmute_copy(&phnsmaxbufferduration)).into()
+            }
+        }
+        Self {
+            base__: <IAudioClient_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsOffloadCapable: IsOffloadCapable::<Identity>,
+            SetClientProperties: SetClientProperties::<Identity>,
+            GetBufferSizeLimits: GetBufferSizeLimits::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioClient2 {}
 windows_core::imp::define_interface!(IAudioClient3, IAudioClient3_Vtbl, 0x7ed4ee07_8e67_4cd4_8c1a_2b7a5987ad42);
 impl core::ops::Deref for IAudioClient3 {
@@ -2895,6 +3242,37 @@ impl IAudioClient3_Vtbl {
         iid == &<IAudioClient3 as windows_core::Interface>::IID || iid == &<IAudioClient as windows_core::Interface>::IID || iid == &<IAudioClient2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioClient3_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioClient3_Vtbl
+where
+    IAudioClient2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSharedModeEnginePeriod<Identity: windows_core::IUnknownImpl + IAudioClient3_Impl>(this: *mut core::ffi::c_void, pformat: *const WAVEFORMATEX, pdefaultperiodinframes: *mut u32, pfundamentalperiodinframes: *mut u32, pminperiodinframes: *mut u32, pmaxperiodinframes: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioClient3_Impl::GetSharedModeEnginePeriod(this, core::mem::transmute_copy(&pformat), core::mem::transmute_copy(&pdefaultperiodinframes), core::mem::transmute_copy(&pfundamentalperiodinframes), core::mem::transmute_copy(&pminperiodinframes), core::mem::transmute_copy(&pmaxperiodinframes)).into()
+            }
+        }
+        unsafe extern "system" fn GetCurrentSharedModeEnginePeriod<Identity: windows_core::IUnknownImpl + IAudioClient3_Impl>(this: *mut core::ffi::c_void, ppformat: *mut *mut WAVEFORMATEX, pcurrentperiodinframes: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioClient3_Impl::GetCurrentSharedModeEnginePeriod(this, core::mem::transmute_copy(&ppformat), core::mem::transmute_copy(&pcurrentperiodinframes)).into()
+            }
+        }
+        unsafe extern "system" fn InitializeSharedAudioStream<Identity: windows_core::IUnknownImpl + IAudioClient3_Impl>(this: *mut core::ffi::c_void, streamflags: u32, periodinframes: u32, pformat: *const WAVEFORMATEX, audiosessionguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioClient3_Impl::InitializeSharedAudioStream(this, core::mem::transmute_copy(&streamflags), core::mem::transmute_copy(&periodinframes), core::mem::transmute_copy(&pformat), core::mem::transmute_copy(&audiosessionguid)).into()
+            }
+        }
+        Self {
+            base__: <IAudioClient2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSharedModeEnginePeriod: GetSharedModeEnginePeriod::<Identity>,
+            GetCurrentSharedModeEnginePeriod: GetCurrentSharedModeEnginePeriod::<Identity>,
+            InitializeSharedAudioStream: InitializeSharedAudioStream::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioClient3 {}
 windows_core::imp::define_interface!(IAudioClientDuckingControl, IAudioClientDuckingControl_Vtbl, 0xc789d381_a28c_4168_b28f_d3a837924dc3);
 windows_core::imp::interface_hierarchy!(IAudioClientDuckingControl, windows_core::IUnknown);
@@ -2929,6 +3307,23 @@ impl IAudioClientDuckingControl_Vtbl {
         iid == &<IAudioClientDuckingControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioClientDuckingControl_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioClientDuckingControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetDuckingOptionsForCurrentStream<Identity: windows_core::IUnknownImpl + IAudioClientDuckingControl_Impl>(this: *mut core::ffi::c_void, options: AUDIO_DUCKING_OPTIONS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioClientDuckingControl_Impl::SetDuckingOptionsForCurrentStream(this, core::mem::transmute_copy(&options)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetDuckingOptionsForCurrentStream: SetDuckingOptionsForCurrentStream::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioClientDuckingControl {}
 windows_core::imp::define_interface!(IAudioClock, IAudioClock_Vtbl, 0xcd63314f_3fba_4a1b_812c_ef96358728e7);
 windows_core::imp::interface_hierarchy!(IAudioClock, windows_core::IUnknown);
@@ -3005,23 +3400,66 @@ impl IAudioClock_Vtbl {
         iid == &<IAudioClock as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IAudioClock {}
-windows_core::imp::define_interface!(IAudioClock2, IAudioClock2_Vtbl, 0x6f49ff73_6727_49ac_a008_d98cf5e70048);
-windows_core::imp::interface_hierarchy!(IAudioClock2, windows_core::IUnknown);
-impl IAudioClock2 {
-    pub unsafe fn GetDevicePosition(&self, deviceposition: *mut u64, qpcposition: Option<*mut u64>) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetDevicePosition)(windows_core::Interface::as_raw(self), deviceposition as _, qpcposition.unwrap_or(core::mem::zeroed()) as _).ok() }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IAudioClock2_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    pub GetDevicePosition: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u64, *mut u64) -> windows_core::HRESULT,
-}
-pub trait IAudioClock2_Impl: windows_core::IUnknownImpl {
-    fn GetDevicePosition(&self, deviceposition: *mut u64, qpcposition: *mut u64) -> windows_core::Result<()>;
-}
+impl<Identity: windows_core::IUnknownImpl + IAudioClock_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioClock_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFrequency<Identity: windows_core::IUnknownImpl + IAudioClock_Impl>(this: *mut core::ffi::c_void, pu64frequency: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioClock_Impl::GetFrequency(this) {
+                    Ok(ok__) => {
+                        pu64frequency.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPosition<Identity: windows_core::IUnknownImpl + IAudioClock_Impl>(this: *mut core::ffi::c_void, pu64position: *mut u64, pu64qpcposition: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioClock_Impl::GetPosition(this, core::mem::transmute_copy(&pu64position), core::mem::transmute_copy(&pu64qpcposition)).into()
+            }
+        }
+        unsafe extern "system" fn GetCharacteristics<Identity: windows_core::IUnknownImpl + IAudioClock_Impl>(this: *mut core::ffi::c_void, pdwcharacteristics: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioClock_Impl::GetCharacteristics(this) {
+                    Ok(ok__) => {
+                        pdwcharacteristics.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFrequency: GetFrequency::<Identity>,
+            GetPosition: GetPosition::<Identity>,
+            GetCharacteristics: GetCharacteristics::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IAudioClock {}
+windows_core::imp::define_interface!(IAudioClock2, IAudioClock2_Vtbl, 0x6f49ff73_6727_49ac_a008_d98cf5e70048);
+windows_core::imp::interface_hierarchy!(IAudioClock2, windows_core::IUnknown);
+impl IAudioClock2 {
+    pub unsafe fn GetDevicePosition(&self, deviceposition: *mut u64, qpcposition: Option<*mut u64>) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetDevicePosition)(windows_core::Interface::as_raw(self), deviceposition as _, qpcposition.unwrap_or(core::mem::zeroed()) as _).ok() }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IAudioClock2_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub GetDevicePosition: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u64, *mut u64) -> windows_core::HRESULT,
+}
+pub trait IAudioClock2_Impl: windows_core::IUnknownImpl {
+    fn GetDevicePosition(&self, deviceposition: *mut u64, qpcposition: *mut u64) -> windows_core::Result<()>;
+}
 impl IAudioClock2_Vtbl {
     pub const fn new<Identity: IAudioClock2_Impl, const OFFSET: isize>() -> Self {
         unsafe extern "system" fn GetDevicePosition<Identity: IAudioClock2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, deviceposition: *mut u64, qpcposition: *mut u64) -> windows_core::HRESULT {
@@ -3036,6 +3474,20 @@ impl IAudioClock2_Vtbl {
         iid == &<IAudioClock2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioClock2_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioClock2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDevicePosition<Identity: windows_core::IUnknownImpl + IAudioClock2_Impl>(this: *mut core::ffi::c_void, deviceposition: *mut u64, qpcposition: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioClock2_Impl::GetDevicePosition(this, core::mem::transmute_copy(&deviceposition), core::mem::transmute_copy(&qpcposition)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDevicePosition: GetDevicePosition::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IAudioClock2 {}
 windows_core::imp::define_interface!(IAudioClockAdjustment, IAudioClockAdjustment_Vtbl, 0xf6e4c0a0_46d9_4fb8_be21_57a3ef2b626c);
 windows_core::imp::interface_hierarchy!(IAudioClockAdjustment, windows_core::IUnknown);
@@ -3067,6 +3519,20 @@ impl IAudioClockAdjustment_Vtbl {
         iid == &<IAudioClockAdjustment as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioClockAdjustment_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioClockAdjustment_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetSampleRate<Identity: windows_core::IUnknownImpl + IAudioClockAdjustment_Impl>(this: *mut core::ffi::c_void, flsamplerate: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioClockAdjustment_Impl::SetSampleRate(this, core::mem::transmute_copy(&flsamplerate)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetSampleRate: SetSampleRate::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IAudioClockAdjustment {}
 windows_core::imp::define_interface!(IAudioEffectsChangedNotificationClient, IAudioEffectsChangedNotificationClient_Vtbl, 0xa5ded44f_3c5d_4b2b_bd1e_5dc1ee20bbf6);
 windows_core::imp::interface_hierarchy!(IAudioEffectsChangedNotificationClient, windows_core::IUnknown);
@@ -3098,6 +3564,23 @@ impl IAudioEffectsChangedNotificationClient_Vtbl {
         iid == &<IAudioEffectsChangedNotificationClient as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioEffectsChangedNotificationClient_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioEffectsChangedNotificationClient_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnAudioEffectsChanged<Identity: windows_core::IUnknownImpl + IAudioEffectsChangedNotificationClient_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEffectsChangedNotificationClient_Impl::OnAudioEffectsChanged(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnAudioEffectsChanged: OnAudioEffectsChanged::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioEffectsChangedNotificationClient {}
 windows_core::imp::define_interface!(IAudioEffectsManager, IAudioEffectsManager_Vtbl, 0x4460b3ae_4b44_4527_8676_7548a8acd260);
 windows_core::imp::interface_hierarchy!(IAudioEffectsManager, windows_core::IUnknown);
@@ -3174,6 +3657,44 @@ impl IAudioEffectsManager_Vtbl {
         iid == &<IAudioEffectsManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioEffectsManager_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioEffectsManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterAudioEffectsChangedNotificationCallback<Identity: windows_core::IUnknownImpl + IAudioEffectsManager_Impl>(this: *mut core::ffi::c_void, client: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEffectsManager_Impl::RegisterAudioEffectsChangedNotificationCallback(this, core::mem::transmute_copy(&client)).into()
+            }
+        }
+        unsafe extern "system" fn UnregisterAudioEffectsChangedNotificationCallback<Identity: windows_core::IUnknownImpl + IAudioEffectsManager_Impl>(this: *mut core::ffi::c_void, client: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEffectsManager_Impl::UnregisterAudioEffectsChangedNotificationCallback(this, core::mem::transmute_copy(&client)).into()
+            }
+        }
+        unsafe extern "system" fn GetAudioEffects<Identity: windows_core::IUnknownImpl + IAudioEffectsManager_Impl>(this: *mut core::ffi::c_void, effects: *mut *mut AUDIO_EFFECT, numeffects: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEffectsManager_Impl::GetAudioEffects(this, core::mem::transmute_copy(&effects), core::mem::transmute_copy(&numeffects)).into()
+            }
+        }
+        unsafe extern "system" fn SetAudioEffectState<Identity: windows_core::IUnknownImpl + IAudioEffectsManager_Impl>(this: *mut core::ffi::c_void, effectid: windows_core::GUID, state: AUDIO_EFFECT_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEffectsManager_Impl::SetAudioEffectState(this, core::mem::transmute(&effectid), core::mem::transmute_copy(&state)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterAudioEffectsChangedNotificationCallback: RegisterAudioEffectsChangedNotificationCallback::<Identity>,
+            UnregisterAudioEffectsChangedNotificationCallback: UnregisterAudioEffectsChangedNotificationCallback::<Identity>,
+            GetAudioEffects: GetAudioEffects::<Identity>,
+            SetAudioEffectState: SetAudioEffectState::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioEffectsManager {}
 windows_core::imp::define_interface!(IAudioFormatEnumerator, IAudioFormatEnumerator_Vtbl, 0xdcdaa858_895a_4a22_a5eb_67bda506096d);
 windows_core::imp::interface_hierarchy!(IAudioFormatEnumerator, windows_core::IUnknown);
@@ -3234,6 +3755,42 @@ impl IAudioFormatEnumerator_Vtbl {
         iid == &<IAudioFormatEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioFormatEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioFormatEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IAudioFormatEnumerator_Impl>(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioFormatEnumerator_Impl::GetCount(this) {
+                    Ok(ok__) => {
+                        count.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFormat<Identity: windows_core::IUnknownImpl + IAudioFormatEnumerator_Impl>(this: *mut core::ffi::c_void, index: u32, format: *mut *mut WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioFormatEnumerator_Impl::GetFormat(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        format.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCount: GetCount::<Identity>,
+            GetFormat: GetFormat::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioFormatEnumerator {}
 windows_core::imp::define_interface!(IAudioInputSelector, IAudioInputSelector_Vtbl, 0x4f03dc02_5e6e_4653_8f72_a030c123d598);
 windows_core::imp::interface_hierarchy!(IAudioInputSelector, windows_core::IUnknown);
@@ -3289,6 +3846,36 @@ impl IAudioInputSelector_Vtbl {
         iid == &<IAudioInputSelector as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioInputSelector_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioInputSelector_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSelection<Identity: windows_core::IUnknownImpl + IAudioInputSelector_Impl>(this: *mut core::ffi::c_void, pnidselected: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioInputSelector_Impl::GetSelection(this) {
+                    Ok(ok__) => {
+                        pnidselected.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSelection<Identity: windows_core::IUnknownImpl + IAudioInputSelector_Impl>(this: *mut core::ffi::c_void, nidselect: u32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioInputSelector_Impl::SetSelection(this, core::mem::transmute_copy(&nidselect), core::mem::transmute_copy(&pguideventcontext)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSelection: GetSelection::<Identity>,
+            SetSelection: SetSelection::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioInputSelector {}
 windows_core::imp::define_interface!(IAudioLoudness, IAudioLoudness_Vtbl, 0x7d8b1437_dd53_4350_9c1b_1ee2890bd938);
 windows_core::imp::interface_hierarchy!(IAudioLoudness, windows_core::IUnknown);
@@ -3344,6 +3931,36 @@ impl IAudioLoudness_Vtbl {
         iid == &<IAudioLoudness as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioLoudness_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioLoudness_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetEnabled<Identity: windows_core::IUnknownImpl + IAudioLoudness_Impl>(this: *mut core::ffi::c_void, pbenabled: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioLoudness_Impl::GetEnabled(this) {
+                    Ok(ok__) => {
+                        pbenabled.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEnabled<Identity: windows_core::IUnknownImpl + IAudioLoudness_Impl>(this: *mut core::ffi::c_void, benable: windows_core::BOOL, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioLoudness_Impl::SetEnabled(this, core::mem::transmute_copy(&benable), core::mem::transmute_copy(&pguideventcontext)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetEnabled: GetEnabled::<Identity>,
+            SetEnabled: SetEnabled::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioLoudness {}
 windows_core::imp::define_interface!(IAudioMidrange, IAudioMidrange_Vtbl, 0x5e54b6d7_b44b_40d9_9a9e_e691d9ce6edf);
 impl core::ops::Deref for IAudioMidrange {
@@ -3367,6 +3984,12 @@ impl IAudioMidrange_Vtbl {
         iid == &<IAudioMidrange as windows_core::Interface>::IID || iid == &<IPerChannelDbLevel as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioMidrange_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioMidrange_Vtbl
+where
+    IPerChannelDbLevel_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IPerChannelDbLevel_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IAudioMidrange {}
 windows_core::imp::define_interface!(IAudioMute, IAudioMute_Vtbl, 0xdf45aeea_b74a_4b6b_afad_2366b6aa012e);
 windows_core::imp::interface_hierarchy!(IAudioMute, windows_core::IUnknown);
@@ -3418,6 +4041,36 @@ impl IAudioMute_Vtbl {
         iid == &<IAudioMute as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioMute_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioMute_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetMute<Identity: windows_core::IUnknownImpl + IAudioMute_Impl>(this: *mut core::ffi::c_void, bmuted: windows_core::BOOL, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioMute_Impl::SetMute(this, core::mem::transmute_copy(&bmuted), core::mem::transmute_copy(&pguideventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn GetMute<Identity: windows_core::IUnknownImpl + IAudioMute_Impl>(this: *mut core::ffi::c_void, pbmuted: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioMute_Impl::GetMute(this) {
+                    Ok(ok__) => {
+                        pbmuted.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetMute: SetMute::<Identity>,
+            GetMute: GetMute::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioMute {}
 windows_core::imp::define_interface!(IAudioOutputSelector, IAudioOutputSelector_Vtbl, 0xbb515f69_94a7_429e_8b9c_271b3f11a3ab);
 windows_core::imp::interface_hierarchy!(IAudioOutputSelector, windows_core::IUnknown);
@@ -3473,6 +4126,36 @@ impl IAudioOutputSelector_Vtbl {
         iid == &<IAudioOutputSelector as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioOutputSelector_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioOutputSelector_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSelection<Identity: windows_core::IUnknownImpl + IAudioOutputSelector_Impl>(this: *mut core::ffi::c_void, pnidselected: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioOutputSelector_Impl::GetSelection(this) {
+                    Ok(ok__) => {
+                        pnidselected.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSelection<Identity: windows_core::IUnknownImpl + IAudioOutputSelector_Impl>(this: *mut core::ffi::c_void, nidselect: u32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioOutputSelector_Impl::SetSelection(this, core::mem::transmute_copy(&nidselect), core::mem::transmute_copy(&pguideventcontext)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSelection: GetSelection::<Identity>,
+            SetSelection: SetSelection::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioOutputSelector {}
 windows_core::imp::define_interface!(IAudioPeakMeter, IAudioPeakMeter_Vtbl, 0xdd79923c_0599_45e0_b8b6_c8df7db6e796);
 windows_core::imp::interface_hierarchy!(IAudioPeakMeter, windows_core::IUnknown);
@@ -3537,6 +4220,42 @@ impl IAudioPeakMeter_Vtbl {
         iid == &<IAudioPeakMeter as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioPeakMeter_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioPeakMeter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetChannelCount<Identity: windows_core::IUnknownImpl + IAudioPeakMeter_Impl>(this: *mut core::ffi::c_void, pcchannels: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioPeakMeter_Impl::GetChannelCount(this) {
+                    Ok(ok__) => {
+                        pcchannels.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLevel<Identity: windows_core::IUnknownImpl + IAudioPeakMeter_Impl>(this: *mut core::ffi::c_void, nchannel: u32, pflevel: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioPeakMeter_Impl::GetLevel(this, core::mem::transmute_copy(&nchannel)) {
+                    Ok(ok__) => {
+                        pflevel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetChannelCount: GetChannelCount::<Identity>,
+            GetLevel: GetLevel::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioPeakMeter {}
 windows_core::imp::define_interface!(IAudioRenderClient, IAudioRenderClient_Vtbl, 0xf294acfc_3146_4483_a7bf_addca7c260e2);
 windows_core::imp::interface_hierarchy!(IAudioRenderClient, windows_core::IUnknown);
@@ -3592,6 +4311,36 @@ impl IAudioRenderClient_Vtbl {
         iid == &<IAudioRenderClient as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioRenderClient_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioRenderClient_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetBuffer<Identity: windows_core::IUnknownImpl + IAudioRenderClient_Impl>(this: *mut core::ffi::c_void, numframesrequested: u32, ppdata: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioRenderClient_Impl::GetBuffer(this, core::mem::transmute_copy(&numframesrequested)) {
+                    Ok(ok__) => {
+                        ppdata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReleaseBuffer<Identity: windows_core::IUnknownImpl + IAudioRenderClient_Impl>(this: *mut core::ffi::c_void, numframeswritten: u32, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioRenderClient_Impl::ReleaseBuffer(this, core::mem::transmute_copy(&numframeswritten), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetBuffer: GetBuffer::<Identity>,
+            ReleaseBuffer: ReleaseBuffer::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioRenderClient {}
 windows_core::imp::define_interface!(IAudioSessionControl, IAudioSessionControl_Vtbl, 0xf4b1a599_7266_4319_a8ca_e70acb11e8cd);
 windows_core::imp::interface_hierarchy!(IAudioSessionControl, windows_core::IUnknown);
@@ -3770,24 +4519,121 @@ impl IAudioSessionControl_Vtbl {
         iid == &<IAudioSessionControl as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IAudioSessionControl {}
-windows_core::imp::define_interface!(IAudioSessionControl2, IAudioSessionControl2_Vtbl, 0xbfb7ff88_7239_4fc9_8fa2_07c950be9c6d);
-impl core::ops::Deref for IAudioSessionControl2 {
-    type Target = IAudioSessionControl;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(IAudioSessionControl2, windows_core::IUnknown, IAudioSessionControl);
-impl IAudioSessionControl2 {
-    pub unsafe fn GetSessionIdentifier(&self) -> windows_core::Result<windows_core::PWSTR> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetSessionIdentifier)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
-        }
-    }
-    pub unsafe fn GetSessionInstanceIdentifier(&self) -> windows_core::Result<windows_core::PWSTR> {
-        unsafe {
+impl<Identity: windows_core::IUnknownImpl + IAudioSessionControl_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioSessionControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetState<Identity: windows_core::IUnknownImpl + IAudioSessionControl_Impl>(this: *mut core::ffi::c_void, pretval: *mut AudioSessionState) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioSessionControl_Impl::GetState(this) {
+                    Ok(ok__) => {
+                        pretval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDisplayName<Identity: windows_core::IUnknownImpl + IAudioSessionControl_Impl>(this: *mut core::ffi::c_void, pretval: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioSessionControl_Impl::GetDisplayName(this) {
+                    Ok(ok__) => {
+                        pretval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDisplayName<Identity: windows_core::IUnknownImpl + IAudioSessionControl_Impl>(this: *mut core::ffi::c_void, value: windows_core::PCWSTR, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioSessionControl_Impl::SetDisplayName(this, core::mem::transmute(&value), core::mem::transmute_copy(&eventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn GetIconPath<Identity: windows_core::IUnknownImpl + IAudioSessionControl_Impl>(this: *mut core::ffi::c_void, pretval: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioSessionControl_Impl::GetIconPath(this) {
+                    Ok(ok__) => {
+                        pretval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetIconPath<Identity: windows_core::IUnknownImpl + IAudioSessionControl_Impl>(this: *mut core::ffi::c_void, value: windows_core::PCWSTR, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioSessionControl_Impl::SetIconPath(this, core::mem::transmute(&value), core::mem::transmute_copy(&eventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn GetGroupingParam<Identity: windows_core::IUnknownImpl + IAudioSessionControl_Impl>(this: *mut core::ffi::c_void, pretval: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioSessionControl_Impl::GetGroupingParam(this) {
+                    Ok(ok__) => {
+                        pretval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetGroupingParam<Identity: windows_core::IUnknownImpl + IAudioSessionControl_Impl>(this: *mut core::ffi::c_void, r#override: *const windows_core::GUID, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioSessionControl_Impl::SetGroupingParam(this, core::mem::transmute_copy(&r#override), core::mem::transmute_copy(&eventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn RegisterAudioSessionNotification<Identity: windows_core::IUnknownImpl + IAudioSessionControl_Impl>(this: *mut core::ffi::c_void, newnotifications: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioSessionControl_Impl::RegisterAudioSessionNotification(this, core::mem::transmute_copy(&newnotifications)).into()
+            }
+        }
+        unsafe extern "system" fn UnregisterAudioSessionNotification<Identity: windows_core::IUnknownImpl + IAudioSessionControl_Impl>(this: *mut core::ffi::c_void, newnotifications: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioSessionControl_Impl::UnregisterAudioSessionNotification(this, core::mem::transmute_copy(&newnotifications)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetState: GetState::<Identity>,
+            GetDisplayName: GetDisplayName::<Identity>,
+            SetDisplayName: SetDisplayName::<Identity>,
+            GetIconPath: GetIconPath::<Identity>,
+            SetIconPath: SetIconPath::<Identity>,
+            GetGroupingParam: GetGroupingParam::<Identity>,
+            SetGroupingParam: SetGroupingParam::<Identity>,
+            RegisterAudioSessionNotification: RegisterAudioSessionNotification::<Identity>,
+            UnregisterAudioSessionNotification: UnregisterAudioSessionNotification::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IAudioSessionControl {}
+windows_core::imp::define_interface!(IAudioSessionControl2, IAudioSessionControl2_Vtbl, 0xbfb7ff88_7239_4fc9_8fa2_07c950be9c6d);
+impl core::ops::Deref for IAudioSessionControl2 {
+    type Target = IAudioSessionControl;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(IAudioSessionControl2, windows_core::IUnknown, IAudioSessionControl);
+impl IAudioSessionControl2 {
+    pub unsafe fn GetSessionIdentifier(&self) -> windows_core::Result<windows_core::PWSTR> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetSessionIdentifier)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn GetSessionInstanceIdentifier(&self) -> windows_core::Result<windows_core::PWSTR> {
+        unsafe {
             let mut result__ = core::mem::zeroed();
             (windows_core::Interface::vtable(self).GetSessionInstanceIdentifier)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
         }
@@ -3885,6 +4731,69 @@ impl IAudioSessionControl2_Vtbl {
         iid == &<IAudioSessionControl2 as windows_core::Interface>::IID || iid == &<IAudioSessionControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioSessionControl2_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioSessionControl2_Vtbl
+where
+    IAudioSessionControl_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSessionIdentifier<Identity: windows_core::IUnknownImpl + IAudioSessionControl2_Impl>(this: *mut core::ffi::c_void, pretval: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioSessionControl2_Impl::GetSessionIdentifier(this) {
+                    Ok(ok__) => {
+                        pretval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSessionInstanceIdentifier<Identity: windows_core::IUnknownImpl + IAudioSessionControl2_Impl>(this: *mut core::ffi::c_void, pretval: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioSessionControl2_Impl::GetSessionInstanceIdentifier(this) {
+                    Ok(ok__) => {
+                        pretval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetProcessId<Identity: windows_core::IUnknownImpl + IAudioSessionControl2_Impl>(this: *mut core::ffi::c_void, pretval: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioSessionControl2_Impl::GetProcessId(this) {
+                    Ok(ok__) => {
+                        pretval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsSystemSoundsSession<Identity: windows_core::IUnknownImpl + IAudioSessionControl2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioSessionControl2_Impl::IsSystemSoundsSession(this)
+            }
+        }
+        unsafe extern "system" fn SetDuckingPreference<Identity: windows_core::IUnknownImpl + IAudioSessionControl2_Impl>(this: *mut core::ffi::c_void, optout: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioSessionControl2_Impl::SetDuckingPreference(this, core::mem::transmute_copy(&optout)).into()
+            }
+        }
+        Self {
+            base__: <IAudioSessionControl_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSessionIdentifier: GetSessionIdentifier::<Identity>,
+            GetSessionInstanceIdentifier: GetSessionInstanceIdentifier::<Identity>,
+            GetProcessId: GetProcessId::<Identity>,
+            IsSystemSoundsSession: IsSystemSoundsSession::<Identity>,
+            SetDuckingPreference: SetDuckingPreference::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioSessionControl2 {}
 windows_core::imp::define_interface!(IAudioSessionEnumerator, IAudioSessionEnumerator_Vtbl, 0xe2f5bb11_0570_40ca_acdd_3aa01277dee8);
 windows_core::imp::interface_hierarchy!(IAudioSessionEnumerator, windows_core::IUnknown);
@@ -3949,6 +4858,42 @@ impl IAudioSessionEnumerator_Vtbl {
         iid == &<IAudioSessionEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioSessionEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioSessionEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IAudioSessionEnumerator_Impl>(this: *mut core::ffi::c_void, sessioncount: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioSessionEnumerator_Impl::GetCount(this) {
+                    Ok(ok__) => {
+                        sessioncount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSession<Identity: windows_core::IUnknownImpl + IAudioSessionEnumerator_Impl>(this: *mut core::ffi::c_void, sessioncount: i32, session: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioSessionEnumerator_Impl::GetSession(this, core::mem::transmute_copy(&sessioncount)) {
+                    Ok(ok__) => {
+                        session.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCount: GetCount::<Identity>,
+            GetSession: GetSession::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioSessionEnumerator {}
 windows_core::imp::define_interface!(IAudioSessionEvents, IAudioSessionEvents_Vtbl, 0x24918acc_64b3_37c1_8ca9_74a66e9957a8);
 windows_core::imp::interface_hierarchy!(IAudioSessionEvents, windows_core::IUnknown);
@@ -4061,6 +5006,65 @@ impl IAudioSessionEvents_Vtbl {
         iid == &<IAudioSessionEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioSessionEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioSessionEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnDisplayNameChanged<Identity: windows_core::IUnknownImpl + IAudioSessionEvents_Impl>(this: *mut core::ffi::c_void, newdisplayname: windows_core::PCWSTR, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioSessionEvents_Impl::OnDisplayNameChanged(this, core::mem::transmute(&newdisplayname), core::mem::transmute_copy(&eventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn OnIconPathChanged<Identity: windows_core::IUnknownImpl + IAudioSessionEvents_Impl>(this: *mut core::ffi::c_void, newiconpath: windows_core::PCWSTR, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioSessionEvents_Impl::OnIconPathChanged(this, core::mem::transmute(&newiconpath), core::mem::transmute_copy(&eventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn OnSimpleVolumeChanged<Identity: windows_core::IUnknownImpl + IAudioSessionEvents_Impl>(this: *mut core::ffi::c_void, newvolume: f32, newmute: windows_core::BOOL, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioSessionEvents_Impl::OnSimpleVolumeChanged(this, core::mem::transmute_copy(&newvolume), core::mem::transmute_copy(&newmute), core::mem::transmute_copy(&eventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn OnChannelVolumeChanged<Identity: windows_core::IUnknownImpl + IAudioSessionEvents_Impl>(this: *mut core::ffi::c_void, channelcount: u32, newchannelvolumearray: *const f32, changedchannel: u32, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioSessionEvents_Impl::OnChannelVolumeChanged(this, core::mem::transmute_copy(&channelcount), core::mem::transmute_copy(&newchannelvolumearray), core::mem::transmute_copy(&changedchannel), core::mem::transmute_copy(&eventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn OnGroupingParamChanged<Identity: windows_core::IUnknownImpl + IAudioSessionEvents_Impl>(this: *mut core::ffi::c_void, newgroupingparam: *const windows_core::GUID, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioSessionEvents_Impl::OnGroupingParamChanged(this, core::mem::transmute_copy(&newgroupingparam), core::mem::transmute_copy(&eventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn OnStateChanged<Identity: windows_core::IUnknownImpl + IAudioSessionEvents_Impl>(this: *mut core::ffi::c_void, newstate: AudioSessionState) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioSessionEvents_Impl::OnStateChanged(this, core::mem::transmute_copy(&newstate)).into()
+            }
+        }
+        unsafe extern "system" fn OnSessionDisconnected<Identity: windows_core::IUnknownImpl + IAudioSessionEvents_Impl>(this: *mut core::ffi::c_void, disconnectreason: AudioSessionDisconnectReason) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioSessionEvents_Impl::OnSessionDisconnected(this, core::mem::transmute_copy(&disconnectreason)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnDisplayNameChanged: OnDisplayNameChanged::<Identity>,
+            OnIconPathChanged: OnIconPathChanged::<Identity>,
+            OnSimpleVolumeChanged: OnSimpleVolumeChanged::<Identity>,
+            OnChannelVolumeChanged: OnChannelVolumeChanged::<Identity>,
+            OnGroupingParamChanged: OnGroupingParamChanged::<Identity>,
+            OnStateChanged: OnStateChanged::<Identity>,
+            OnSessionDisconnected: OnSessionDisconnected::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioSessionEvents {}
 windows_core::imp::define_interface!(IAudioSessionManager, IAudioSessionManager_Vtbl, 0xbfa971f1_4d5e_40bb_935e_967039bfbee4);
 windows_core::imp::interface_hierarchy!(IAudioSessionManager, windows_core::IUnknown);
@@ -4125,6 +5129,42 @@ impl IAudioSessionManager_Vtbl {
         iid == &<IAudioSessionManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioSessionManager_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioSessionManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAudioSessionControl<Identity: windows_core::IUnknownImpl + IAudioSessionManager_Impl>(this: *mut core::ffi::c_void, audiosessionguid: *const windows_core::GUID, streamflags: u32, sessioncontrol: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioSessionManager_Impl::GetAudioSessionControl(this, core::mem::transmute_copy(&audiosessionguid), core::mem::transmute_copy(&streamflags)) {
+                    Ok(ok__) => {
+                        sessioncontrol.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSimpleAudioVolume<Identity: windows_core::IUnknownImpl + IAudioSessionManager_Impl>(this: *mut core::ffi::c_void, audiosessionguid: *const windows_core::GUID, streamflags: u32, audiovolume: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioSessionManager_Impl::GetSimpleAudioVolume(this, core::mem::transmute_copy(&audiosessionguid), core::mem::transmute_copy(&streamflags)) {
+                    Ok(ok__) => {
+                        audiovolume.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAudioSessionControl: GetAudioSessionControl::<Identity>,
+            GetSimpleAudioVolume: GetSimpleAudioVolume::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioSessionManager {}
 windows_core::imp::define_interface!(IAudioSessionManager2, IAudioSessionManager2_Vtbl, 0x77aa99a0_1bd6_484f_8bc7_2c654c9a9b6f);
 impl core::ops::Deref for IAudioSessionManager2 {
@@ -4235,6 +5275,57 @@ impl IAudioSessionManager2_Vtbl {
         iid == &<IAudioSessionManager2 as windows_core::Interface>::IID || iid == &<IAudioSessionManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioSessionManager2_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioSessionManager2_Vtbl
+where
+    IAudioSessionManager_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSessionEnumerator<Identity: windows_core::IUnknownImpl + IAudioSessionManager2_Impl>(this: *mut core::ffi::c_void, sessionenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioSessionManager2_Impl::GetSessionEnumerator(this) {
+                    Ok(ok__) => {
+                        sessionenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RegisterSessionNotification<Identity: windows_core::IUnknownImpl + IAudioSessionManager2_Impl>(this: *mut core::ffi::c_void, sessionnotification: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioSessionManager2_Impl::RegisterSessionNotification(this, core::mem::transmute_copy(&sessionnotification)).into()
+            }
+        }
+        unsafe extern "system" fn UnregisterSessionNotification<Identity: windows_core::IUnknownImpl + IAudioSessionManager2_Impl>(this: *mut core::ffi::c_void, sessionnotification: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioSessionManager2_Impl::UnregisterSessionNotification(this, core::mem::transmute_copy(&sessionnotification)).into()
+            }
+        }
+        unsafe extern "system" fn RegisterDuckNotification<Identity: windows_core::IUnknownImpl + IAudioSessionManager2_Impl>(this: *mut core::ffi::c_void, sessionid: windows_core::PCWSTR, ducknotification: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioSessionManager2_Impl::RegisterDuckNotification(this, core::mem::transmute(&sessionid), core::mem::transmute_copy(&ducknotification)).into()
+            }
+        }
+        unsafe extern "system" fn UnregisterDuckNotification<Identity: windows_core::IUnknownImpl + IAudioSessionManager2_Impl>(this: *mut core::ffi::c_void, ducknotification: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioSessionManager2_Impl::UnregisterDuckNotification(this, core::mem::transmute_copy(&ducknotification)).into()
+            }
+        }
+        Self {
+            base__: <IAudioSessionManager_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSessionEnumerator: GetSessionEnumerator::<Identity>,
+            RegisterSessionNotification: RegisterSessionNotification::<Identity>,
+            UnregisterSessionNotification: UnregisterSessionNotification::<Identity>,
+            RegisterDuckNotification: RegisterDuckNotification::<Identity>,
+            UnregisterDuckNotification: UnregisterDuckNotification::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioSessionManager2 {}
 windows_core::imp::define_interface!(IAudioSessionNotification, IAudioSessionNotification_Vtbl, 0x641dd20b_4d41_49cc_aba3_174b9477bb08);
 windows_core::imp::interface_hierarchy!(IAudioSessionNotification, windows_core::IUnknown);
@@ -4269,6 +5360,20 @@ impl IAudioSessionNotification_Vtbl {
         iid == &<IAudioSessionNotification as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioSessionNotification_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioSessionNotification_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnSessionCreated<Identity: windows_core::IUnknownImpl + IAudioSessionNotification_Impl>(this: *mut core::ffi::c_void, newsession: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioSessionNotification_Impl::OnSessionCreated(this, core::mem::transmute_copy(&newsession)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnSessionCreated: OnSessionCreated::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IAudioSessionNotification {}
 windows_core::imp::define_interface!(IAudioStateMonitor, IAudioStateMonitor_Vtbl, 0x63bd8738_e30d_4c77_bf5c_834e87c657e2);
 windows_core::imp::interface_hierarchy!(IAudioStateMonitor, windows_core::IUnknown);
@@ -4336,6 +5441,43 @@ impl IAudioStateMonitor_Vtbl {
         iid == &<IAudioStateMonitor as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioStateMonitor_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioStateMonitor_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterCallback<Identity: windows_core::IUnknownImpl + IAudioStateMonitor_Impl>(this: *mut core::ffi::c_void, callback: PAudioStateMonitorCallback, context: *const core::ffi::c_void, registration: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioStateMonitor_Impl::RegisterCallback(this, core::mem::transmute_copy(&callback), core::mem::transmute_copy(&context)) {
+                    Ok(ok__) => {
+                        registration.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UnregisterCallback<Identity: windows_core::IUnknownImpl + IAudioStateMonitor_Impl>(this: *mut core::ffi::c_void, registration: i64) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioStateMonitor_Impl::UnregisterCallback(this, core::mem::transmute_copy(&registration))
+            }
+        }
+        unsafe extern "system" fn GetSoundLevel<Identity: windows_core::IUnknownImpl + IAudioStateMonitor_Impl>(this: *mut core::ffi::c_void) -> AudioStateMonitorSoundLevel {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioStateMonitor_Impl::GetSoundLevel(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterCallback: RegisterCallback::<Identity>,
+            UnregisterCallback: UnregisterCallback::<Identity>,
+            GetSoundLevel: GetSoundLevel::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioStateMonitor {}
 windows_core::imp::define_interface!(IAudioStreamVolume, IAudioStreamVolume_Vtbl, 0x93014887_242d_4068_8a15_cf5e93b90fe3);
 windows_core::imp::interface_hierarchy!(IAudioStreamVolume, windows_core::IUnknown);
@@ -4436,6 +5578,63 @@ impl IAudioStreamVolume_Vtbl {
         iid == &<IAudioStreamVolume as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioStreamVolume_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioStreamVolume_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetChannelCount<Identity: windows_core::IUnknownImpl + IAudioStreamVolume_Impl>(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioStreamVolume_Impl::GetChannelCount(this) {
+                    Ok(ok__) => {
+                        pdwcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetChannelVolume<Identity: windows_core::IUnknownImpl + IAudioStreamVolume_Impl>(this: *mut core::ffi::c_void, dwindex: u32, flevel: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioStreamVolume_Impl::SetChannelVolume(this, core::mem::transmute_copy(&dwindex), core::mem::transmute_copy(&flevel)).into()
+            }
+        }
+        unsafe extern "system" fn GetChannelVolume<Identity: windows_core::IUnknownImpl + IAudioStreamVolume_Impl>(this: *mut core::ffi::c_void, dwindex: u32, pflevel: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioStreamVolume_Impl::GetChannelVolume(this, core::mem::transmute_copy(&dwindex)) {
+                    Ok(ok__) => {
+                        pflevel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAllVolumes<Identity: windows_core::IUnknownImpl + IAudioStreamVolume_Impl>(this: *mut core::ffi::c_void, dwcount: u32, pfvolumes: *const f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioStreamVolume_Impl::SetAllVolumes(this, core::mem::transmute_copy(&dwcount), core::mem::transmute_copy(&pfvolumes)).into()
+            }
+        }
+        unsafe extern "system" fn GetAllVolumes<Identity: windows_core::IUnknownImpl + IAudioStreamVolume_Impl>(this: *mut core::ffi::c_void, dwcount: u32, pfvolumes: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioStreamVolume_Impl::GetAllVolumes(this, core::mem::transmute_copy(&dwcount), core::mem::transmute_copy(&pfvolumes)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetChannelCount: GetChannelCount::<Identity>,
+            SetChannelVolume: SetChannelVolume::<Identity>,
+            GetChannelVolume: GetChannelVolume::<Identity>,
+            SetAllVolumes: SetAllVolumes::<Identity>,
+            GetAllVolumes: GetAllVolumes::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioStreamVolume {}
 windows_core::imp::define_interface!(IAudioSystemEffectsPropertyChangeNotificationClient, IAudioSystemEffectsPropertyChangeNotificationClient_Vtbl, 0x20049d40_56d5_400e_a2ef_385599feed49);
 windows_core::imp::interface_hierarchy!(IAudioSystemEffectsPropertyChangeNotificationClient, windows_core::IUnknown);
@@ -4467,6 +5666,20 @@ impl IAudioSystemEffectsPropertyChangeNotificationClient_Vtbl {
         iid == &<IAudioSystemEffectsPropertyChangeNotificationClient as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioSystemEffectsPropertyChangeNotificationClient_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioSystemEffectsPropertyChangeNotificationClient_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnPropertyChanged<Identity: windows_core::IUnknownImpl + IAudioSystemEffectsPropertyChangeNotificationClient_Impl>(this: *mut core::ffi::c_void, r#type: AUDIO_SYSTEMEFFECTS_PROPERTYSTORE_TYPE, key: super::super::Foundation::PROPERTYKEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioSystemEffectsPropertyChangeNotificationClient_Impl::OnPropertyChanged(this, core::mem::transmute_copy(&r#type), core::mem::transmute(&key)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnPropertyChanged: OnPropertyChanged::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IAudioSystemEffectsPropertyChangeNotificationClient {}
 windows_core::imp::define_interface!(IAudioSystemEffectsPropertyStore, IAudioSystemEffectsPropertyStore_Vtbl, 0x302ae7f9_d7e0_43e4_971b_1f8293613d2a);
 windows_core::imp::interface_hierarchy!(IAudioSystemEffectsPropertyStore, windows_core::IUnknown);
@@ -4543,11 +5756,92 @@ pub trait IAudioSystemEffectsPropertyStore_Impl: windows_core::IUnknownImpl {
     fn UnregisterPropertyChangeNotification(&self, callback: windows_core::Ref<IAudioSystemEffectsPropertyChangeNotificationClient>) -> windows_core::Result<()>;
 }
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
-impl IAudioSystemEffectsPropertyStore_Vtbl {
-    pub const fn new<Identity: IAudioSystemEffectsPropertyStore_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn OpenDefaultPropertyStore<Identity: IAudioSystemEffectsPropertyStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, stgmaccess: u32, propstore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl IAudioSystemEffectsPropertyStore_Vtbl {
+    pub const fn new<Identity: IAudioSystemEffectsPropertyStore_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn OpenDefaultPropertyStore<Identity: IAudioSystemEffectsPropertyStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, stgmaccess: u32, propstore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IAudioSystemEffectsPropertyStore_Impl::OpenDefaultPropertyStore(this, core::mem::transmute_copy(&stgmaccess)) {
+                    Ok(ok__) => {
+                        propstore.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OpenUserPropertyStore<Identity: IAudioSystemEffectsPropertyStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, stgmaccess: u32, propstore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IAudioSystemEffectsPropertyStore_Impl::OpenUserPropertyStore(this, core::mem::transmute_copy(&stgmaccess)) {
+                    Ok(ok__) => {
+                        propstore.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OpenVolatilePropertyStore<Identity: IAudioSystemEffectsPropertyStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, stgmaccess: u32, propstore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IAudioSystemEffectsPropertyStore_Impl::OpenVolatilePropertyStore(this, core::mem::transmute_copy(&stgmaccess)) {
+                    Ok(ok__) => {
+                        propstore.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ResetUserPropertyStore<Identity: IAudioSystemEffectsPropertyStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IAudioSystemEffectsPropertyStore_Impl::ResetUserPropertyStore(this).into()
+            }
+        }
+        unsafe extern "system" fn ResetVolatilePropertyStore<Identity: IAudioSystemEffectsPropertyStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IAudioSystemEffectsPropertyStore_Impl::ResetVolatilePropertyStore(this).into()
+            }
+        }
+        unsafe extern "system" fn RegisterPropertyChangeNotification<Identity: IAudioSystemEffectsPropertyStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, callback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IAudioSystemEffectsPropertyStore_Impl::RegisterPropertyChangeNotification(this, core::mem::transmute_copy(&callback)).into()
+            }
+        }
+        unsafe extern "system" fn UnregisterPropertyChangeNotification<Identity: IAudioSystemEffectsPropertyStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, callback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IAudioSystemEffectsPropertyStore_Impl::UnregisterPropertyChangeNotification(this, core::mem::transmute_copy(&callback)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            OpenDefaultPropertyStore: OpenDefaultPropertyStore::<Identity, OFFSET>,
+            OpenUserPropertyStore: OpenUserPropertyStore::<Identity, OFFSET>,
+            OpenVolatilePropertyStore: OpenVolatilePropertyStore::<Identity, OFFSET>,
+            ResetUserPropertyStore: ResetUserPropertyStore::<Identity, OFFSET>,
+            ResetVolatilePropertyStore: ResetVolatilePropertyStore::<Identity, OFFSET>,
+            RegisterPropertyChangeNotification: RegisterPropertyChangeNotification::<Identity, OFFSET>,
+            UnregisterPropertyChangeNotification: UnregisterPropertyChangeNotification::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioSystemEffectsPropertyStore as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl<Identity: windows_core::IUnknownImpl + IAudioSystemEffectsPropertyStore_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioSystemEffectsPropertyStore_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OpenDefaultPropertyStore<Identity: windows_core::IUnknownImpl + IAudioSystemEffectsPropertyStore_Impl>(this: *mut core::ffi::c_void, stgmaccess: u32, propstore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IAudioSystemEffectsPropertyStore_Impl::OpenDefaultPropertyStore(this, core::mem::transmute_copy(&stgmaccess)) {
                     Ok(ok__) => {
                         propstore.write(core::mem::transmute(ok__));
@@ -4557,9 +5851,9 @@ impl IAudioSystemEffectsPropertyStore_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn OpenUserPropertyStore<Identity: IAudioSystemEffectsPropertyStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, stgmaccess: u32, propstore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn OpenUserPropertyStore<Identity: windows_core::IUnknownImpl + IAudioSystemEffectsPropertyStore_Impl>(this: *mut core::ffi::c_void, stgmaccess: u32, propstore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IAudioSystemEffectsPropertyStore_Impl::OpenUserPropertyStore(this, core::mem::transmute_copy(&stgmaccess)) {
                     Ok(ok__) => {
                         propstore.write(core::mem::transmute(ok__));
@@ -4569,9 +5863,9 @@ impl IAudioSystemEffectsPropertyStore_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn OpenVolatilePropertyStore<Identity: IAudioSystemEffectsPropertyStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, stgmaccess: u32, propstore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn OpenVolatilePropertyStore<Identity: windows_core::IUnknownImpl + IAudioSystemEffectsPropertyStore_Impl>(this: *mut core::ffi::c_void, stgmaccess: u32, propstore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IAudioSystemEffectsPropertyStore_Impl::OpenVolatilePropertyStore(this, core::mem::transmute_copy(&stgmaccess)) {
                     Ok(ok__) => {
                         propstore.write(core::mem::transmute(ok__));
@@ -4581,44 +5875,41 @@ impl IAudioSystemEffectsPropertyStore_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ResetUserPropertyStore<Identity: IAudioSystemEffectsPropertyStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn ResetUserPropertyStore<Identity: windows_core::IUnknownImpl + IAudioSystemEffectsPropertyStore_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IAudioSystemEffectsPropertyStore_Impl::ResetUserPropertyStore(this).into()
             }
         }
-        unsafe extern "system" fn ResetVolatilePropertyStore<Identity: IAudioSystemEffectsPropertyStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn ResetVolatilePropertyStore<Identity: windows_core::IUnknownImpl + IAudioSystemEffectsPropertyStore_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IAudioSystemEffectsPropertyStore_Impl::ResetVolatilePropertyStore(this).into()
             }
         }
-        unsafe extern "system" fn RegisterPropertyChangeNotification<Identity: IAudioSystemEffectsPropertyStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, callback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn RegisterPropertyChangeNotification<Identity: windows_core::IUnknownImpl + IAudioSystemEffectsPropertyStore_Impl>(this: *mut core::ffi::c_void, callback: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IAudioSystemEffectsPropertyStore_Impl::RegisterPropertyChangeNotification(this, core::mem::transmute_copy(&callback)).into()
             }
         }
-        unsafe extern "system" fn UnregisterPropertyChangeNotification<Identity: IAudioSystemEffectsPropertyStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, callback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn UnregisterPropertyChangeNotification<Identity: windows_core::IUnknownImpl + IAudioSystemEffectsPropertyStore_Impl>(this: *mut core::ffi::c_void, callback: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IAudioSystemEffectsPropertyStore_Impl::UnregisterPropertyChangeNotification(this, core::mem::transmute_copy(&callback)).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            OpenDefaultPropertyStore: OpenDefaultPropertyStore::<Identity, OFFSET>,
-            OpenUserPropertyStore: OpenUserPropertyStore::<Identity, OFFSET>,
-            OpenVolatilePropertyStore: OpenVolatilePropertyStore::<Identity, OFFSET>,
-            ResetUserPropertyStore: ResetUserPropertyStore::<Identity, OFFSET>,
-            ResetVolatilePropertyStore: ResetVolatilePropertyStore::<Identity, OFFSET>,
-            RegisterPropertyChangeNotification: RegisterPropertyChangeNotification::<Identity, OFFSET>,
-            UnregisterPropertyChangeNotification: UnregisterPropertyChangeNotification::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OpenDefaultPropertyStore: OpenDefaultPropertyStore::<Identity>,
+            OpenUserPropertyStore: OpenUserPropertyStore::<Identity>,
+            OpenVolatilePropertyStore: OpenVolatilePropertyStore::<Identity>,
+            ResetUserPropertyStore: ResetUserPropertyStore::<Identity>,
+            ResetVolatilePropertyStore: ResetVolatilePropertyStore::<Identity>,
+            RegisterPropertyChangeNotification: RegisterPropertyChangeNotification::<Identity>,
+            UnregisterPropertyChangeNotification: UnregisterPropertyChangeNotification::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IAudioSystemEffectsPropertyStore as windows_core::Interface>::IID
-    }
+    };
 }
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
 impl windows_core::RuntimeName for IAudioSystemEffectsPropertyStore {}
@@ -4644,6 +5935,12 @@ impl IAudioTreble_Vtbl {
         iid == &<IAudioTreble as windows_core::Interface>::IID || iid == &<IPerChannelDbLevel as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioTreble_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioTreble_Vtbl
+where
+    IPerChannelDbLevel_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IPerChannelDbLevel_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IAudioTreble {}
 windows_core::imp::define_interface!(IAudioViewManagerService, IAudioViewManagerService_Vtbl, 0xa7a7ef10_1f49_45e0_ad35_612057cc8f74);
 windows_core::imp::interface_hierarchy!(IAudioViewManagerService, windows_core::IUnknown);
@@ -4675,6 +5972,23 @@ impl IAudioViewManagerService_Vtbl {
         iid == &<IAudioViewManagerService as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioViewManagerService_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioViewManagerService_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAudioStreamWindow<Identity: windows_core::IUnknownImpl + IAudioViewManagerService_Impl>(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioViewManagerService_Impl::SetAudioStreamWindow(this, core::mem::transmute_copy(&hwnd)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAudioStreamWindow: SetAudioStreamWindow::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioViewManagerService {}
 windows_core::imp::define_interface!(IAudioVolumeDuckNotification, IAudioVolumeDuckNotification_Vtbl, 0xc3b284d4_6d39_4359_b3cf_b56ddb3bb39c);
 windows_core::imp::interface_hierarchy!(IAudioVolumeDuckNotification, windows_core::IUnknown);
@@ -4727,6 +6041,30 @@ impl IAudioVolumeDuckNotification_Vtbl {
         iid == &<IAudioVolumeDuckNotification as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioVolumeDuckNotification_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioVolumeDuckNotification_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnVolumeDuckNotification<Identity: windows_core::IUnknownImpl + IAudioVolumeDuckNotification_Impl>(this: *mut core::ffi::c_void, sessionid: windows_core::PCWSTR, countcommunicationsessions: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioVolumeDuckNotification_Impl::OnVolumeDuckNotification(this, core::mem::transmute(&sessionid), core::mem::transmute_copy(&countcommunicationsessions)).into()
+            }
+        }
+        unsafe extern "system" fn OnVolumeUnduckNotification<Identity: windows_core::IUnknownImpl + IAudioVolumeDuckNotification_Impl>(this: *mut core::ffi::c_void, sessionid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioVolumeDuckNotification_Impl::OnVolumeUnduckNotification(this, core::mem::transmute(&sessionid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnVolumeDuckNotification: OnVolumeDuckNotification::<Identity>,
+            OnVolumeUnduckNotification: OnVolumeUnduckNotification::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioVolumeDuckNotification {}
 windows_core::imp::define_interface!(IAudioVolumeLevel, IAudioVolumeLevel_Vtbl, 0x7fb7b48f_531d_44a2_bcb3_5ad5a134b3dc);
 impl core::ops::Deref for IAudioVolumeLevel {
@@ -4750,6 +6088,12 @@ impl IAudioVolumeLevel_Vtbl {
         iid == &<IAudioVolumeLevel as windows_core::Interface>::IID || iid == &<IPerChannelDbLevel as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioVolumeLevel_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioVolumeLevel_Vtbl
+where
+    IPerChannelDbLevel_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IPerChannelDbLevel_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IAudioVolumeLevel {}
 windows_core::imp::define_interface!(IChannelAudioVolume, IChannelAudioVolume_Vtbl, 0x1c158861_b533_4b30_b1cf_e853e51c59b8);
 windows_core::imp::interface_hierarchy!(IChannelAudioVolume, windows_core::IUnknown);
@@ -4850,6 +6194,63 @@ impl IChannelAudioVolume_Vtbl {
         iid == &<IChannelAudioVolume as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IChannelAudioVolume_Impl> windows_core::imp::TearOffVtable<Identity> for IChannelAudioVolume_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetChannelCount<Identity: windows_core::IUnknownImpl + IChannelAudioVolume_Impl>(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IChannelAudioVolume_Impl::GetChannelCount(this) {
+                    Ok(ok__) => {
+                        pdwcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetChannelVolume<Identity: windows_core::IUnknownImpl + IChannelAudioVolume_Impl>(this: *mut core::ffi::c_void, dwindex: u32, flevel: f32, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IChannelAudioVolume_Impl::SetChannelVolume(this, core::mem::transmute_copy(&dwindex), core::mem::transmute_copy(&flevel), core::mem::transmute_copy(&eventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn GetChannelVolume<Identity: windows_core::IUnknownImpl + IChannelAudioVolume_Impl>(this: *mut core::ffi::c_void, dwindex: u32, pflevel: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IChannelAudioVolume_Impl::GetChannelVolume(this, core::mem::transmute_copy(&dwindex)) {
+                    Ok(ok__) => {
+                        pflevel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAllVolumes<Identity: windows_core::IUnknownImpl + IChannelAudioVolume_Impl>(this: *mut core::ffi::c_void, dwcount: u32, pfvolumes: *const f32, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IChannelAudioVolume_Impl::SetAllVolumes(this, core::mem::transmute_copy(&dwcount), core::mem::transmute_copy(&pfvolumes), core::mem::transmute_copy(&eventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn GetAllVolumes<Identity: windows_core::IUnknownImpl + IChannelAudioVolume_Impl>(this: *mut core::ffi::c_void, dwcount: u32, pfvolumes: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IChannelAudioVolume_Impl::GetAllVolumes(this, core::mem::transmute_copy(&dwcount), core::mem::transmute_copy(&pfvolumes)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetChannelCount: GetChannelCount::<Identity>,
+            SetChannelVolume: SetChannelVolume::<Identity>,
+            GetChannelVolume: GetChannelVolume::<Identity>,
+            SetAllVolumes: SetAllVolumes::<Identity>,
+            GetAllVolumes: GetAllVolumes::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IChannelAudioVolume {}
 windows_core::imp::define_interface!(IConnector, IConnector_Vtbl, 0x9c2c4058_23f5_41de_877a_df3af236a09e);
 windows_core::imp::interface_hierarchy!(IConnector, windows_core::IUnknown);
@@ -5025,6 +6426,108 @@ impl IConnector_Vtbl {
         iid == &<IConnector as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IConnector_Impl> windows_core::imp::TearOffVtable<Identity> for IConnector_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetType<Identity: windows_core::IUnknownImpl + IConnector_Impl>(this: *mut core::ffi::c_void, ptype: *mut ConnectorType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IConnector_Impl::GetType(this) {
+                    Ok(ok__) => {
+                        ptype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDataFlow<Identity: windows_core::IUnknownImpl + IConnector_Impl>(this: *mut core::ffi::c_void, pflow: *mut DataFlow) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IConnector_Impl::GetDataFlow(this) {
+                    Ok(ok__) => {
+                        pflow.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ConnectTo<Identity: windows_core::IUnknownImpl + IConnector_Impl>(this: *mut core::ffi::c_void, pconnectto: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IConnector_Impl::ConnectTo(this, core::mem::transmute_copy(&pconnectto)).into()
+            }
+        }
+        unsafe extern "system" fn Disconnect<Identity: windows_core::IUnknownImpl + IConnector_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IConnector_Impl::Disconnect(this).into()
+            }
+        }
+        unsafe extern "system" fn IsConnected<Identity: windows_core::IUnknownImpl + IConnector_Impl>(this: *mut core::ffi::c_void, pbconnected: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IConnector_Impl::IsConnected(this) {
+                    Ok(ok__) => {
+                        pbconnected.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetConnectedTo<Identity: windows_core::IUnknownImpl + IConnector_Impl>(this: *mut core::ffi::c_void, ppconto: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IConnector_Impl::GetConnectedTo(this) {
+                    Ok(ok__) => {
+                        ppconto.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetConnectorIdConnectedTo<Identity: windows_core::IUnknownImpl + IConnector_Impl>(this: *mut core::ffi::c_void, ppwstrconnectorid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IConnector_Impl::GetConnectorIdConnectedTo(this) {
+                    Ok(ok__) => {
+                        ppwstrconnectorid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDeviceIdConnectedTo<Identity: windows_core::IUnknownImpl + IConnector_Impl>(this: *mut core::ffi::c_void, ppwstrdeviceid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IConnector_Impl::GetDeviceIdConnectedTo(this) {
+                    Ok(ok__) => {
+                        ppwstrdeviceid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetType: GetType::<Identity>,
+            GetDataFlow: GetDataFlow::<Identity>,
+            ConnectTo: ConnectTo::<Identity>,
+            Disconnect: Disconnect::<Identity>,
+            IsConnected: IsConnected::<Identity>,
+            GetConnectedTo: GetConnectedTo::<Identity>,
+            GetConnectorIdConnectedTo: GetConnectorIdConnectedTo::<Identity>,
+            GetDeviceIdConnectedTo: GetDeviceIdConnectedTo::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IConnector {}
 windows_core::imp::define_interface!(IControlChangeNotify, IControlChangeNotify_Vtbl, 0xa09513ed_c709_4d21_bd7b_5f34c47f3947);
 windows_core::imp::interface_hierarchy!(IControlChangeNotify, windows_core::IUnknown);
@@ -5056,6 +6559,20 @@ impl IControlChangeNotify_Vtbl {
         iid == &<IControlChangeNotify as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IControlChangeNotify_Impl> windows_core::imp::TearOffVtable<Identity> for IControlChangeNotify_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnNotify<Identity: windows_core::IUnknownImpl + IControlChangeNotify_Impl>(this: *mut core::ffi::c_void, dwsenderprocessid: u32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IControlChangeNotify_Impl::OnNotify(this, core::mem::transmute_copy(&dwsenderprocessid), core::mem::transmute_copy(&pguideventcontext)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnNotify: OnNotify::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IControlChangeNotify {}
 windows_core::imp::define_interface!(IControlInterface, IControlInterface_Vtbl, 0x45d37c3f_5140_444a_ae24_400789f3cbf3);
 windows_core::imp::interface_hierarchy!(IControlInterface, windows_core::IUnknown);
@@ -5116,6 +6633,42 @@ impl IControlInterface_Vtbl {
         iid == &<IControlInterface as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IControlInterface_Impl> windows_core::imp::TearOffVtable<Identity> for IControlInterface_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IControlInterface_Impl>(this: *mut core::ffi::c_void, ppwstrname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IControlInterface_Impl::GetName(this) {
+                    Ok(ok__) => {
+                        ppwstrname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetIID<Identity: windows_core::IUnknownImpl + IControlInterface_Impl>(this: *mut core::ffi::c_void, piid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IControlInterface_Impl::GetIID(this) {
+                    Ok(ok__) => {
+                        piid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetName: GetName::<Identity>,
+            GetIID: GetIID::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IControlInterface {}
 windows_core::imp::define_interface!(IDeviceSpecificProperty, IDeviceSpecificProperty_Vtbl, 0x3b22bcbf_2586_4af0_8583_205d391b807c);
 windows_core::imp::interface_hierarchy!(IDeviceSpecificProperty, windows_core::IUnknown);
@@ -5151,11 +6704,58 @@ pub trait IDeviceSpecificProperty_Impl: windows_core::IUnknownImpl {
     fn SetValue(&self, pvvalue: *const core::ffi::c_void, cbvalue: u32, pguideventcontext: *const windows_core::GUID) -> windows_core::Result<()>;
     fn Get4BRange(&self, plmin: *mut i32, plmax: *mut i32, plstepping: *mut i32) -> windows_core::Result<()>;
 }
-impl IDeviceSpecificProperty_Vtbl {
-    pub const fn new<Identity: IDeviceSpecificProperty_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn GetType<Identity: IDeviceSpecificProperty_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pvtype: *mut u16) -> windows_core::HRESULT {
+impl IDeviceSpecificProperty_Vtbl {
+    pub const fn new<Identity: IDeviceSpecificProperty_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn GetType<Identity: IDeviceSpecificProperty_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pvtype: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDeviceSpecificProperty_Impl::GetType(this) {
+                    Ok(ok__) => {
+                        pvtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetValue<Identity: IDeviceSpecificProperty_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pvvalue: *mut core::ffi::c_void, pcbvalue: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDeviceSpecificProperty_Impl::GetValue(this, core::mem::transmute_copy(&pvvalue), core::mem::transmute_copy(&pcbvalue)).into()
+            }
+        }
+        unsafe extern "system" fn SetValue<Identity: IDeviceSpecificProperty_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pvvalue: *const core::ffi::c_void, cbvalue: u32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDeviceSpecificProperty_Impl::SetValue(this, core::mem::transmute_copy(&pvvalue), core::mem::transmute_copy(&cbvalue), core::mem::transmute_copy(&pguideventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn Get4BRange<Identity: IDeviceSpecificProperty_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, plmin: *mut i32, plmax: *mut i32, plstepping: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDeviceSpecificProperty_Impl::Get4BRange(this, core::mem::transmute_copy(&plmin), core::mem::transmute_copy(&plmax), core::mem::transmute_copy(&plstepping)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            GetType: GetType::<Identity, OFFSET>,
+            GetValue: GetValue::<Identity, OFFSET>,
+            SetValue: SetValue::<Identity, OFFSET>,
+            Get4BRange: Get4BRange::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceSpecificProperty as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IDeviceSpecificProperty_Impl> windows_core::imp::TearOffVtable<Identity> for IDeviceSpecificProperty_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetType<Identity: windows_core::IUnknownImpl + IDeviceSpecificProperty_Impl>(this: *mut core::ffi::c_void, pvtype: *mut u16) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDeviceSpecificProperty_Impl::GetType(this) {
                     Ok(ok__) => {
                         pvtype.write(core::mem::transmute(ok__));
@@ -5165,35 +6765,32 @@ impl IDeviceSpecificProperty_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetValue<Identity: IDeviceSpecificProperty_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pvvalue: *mut core::ffi::c_void, pcbvalue: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetValue<Identity: windows_core::IUnknownImpl + IDeviceSpecificProperty_Impl>(this: *mut core::ffi::c_void, pvvalue: *mut core::ffi::c_void, pcbvalue: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDeviceSpecificProperty_Impl::GetValue(this, core::mem::transmute_copy(&pvvalue), core::mem::transmute_copy(&pcbvalue)).into()
             }
         }
-        unsafe extern "system" fn SetValue<Identity: IDeviceSpecificProperty_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pvvalue: *const core::ffi::c_void, cbvalue: u32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetValue<Identity: windows_core::IUnknownImpl + IDeviceSpecificProperty_Impl>(this: *mut core::ffi::c_void, pvvalue: *const core::ffi::c_void, cbvalue: u32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDeviceSpecificProperty_Impl::SetValue(this, core::mem::transmute_copy(&pvvalue), core::mem::transmute_copy(&cbvalue), core::mem::transmute_copy(&pguideventcontext)).into()
             }
         }
-        unsafe extern "system" fn Get4BRange<Identity: IDeviceSpecificProperty_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, plmin: *mut i32, plmax: *mut i32, plstepping: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn Get4BRange<Identity: windows_core::IUnknownImpl + IDeviceSpecificProperty_Impl>(this: *mut core::ffi::c_void, plmin: *mut i32, plmax: *mut i32, plstepping: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDeviceSpecificProperty_Impl::Get4BRange(this, core::mem::transmute_copy(&plmin), core::mem::transmute_copy(&plmax), core::mem::transmute_copy(&plstepping)).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            GetType: GetType::<Identity, OFFSET>,
-            GetValue: GetValue::<Identity, OFFSET>,
-            SetValue: SetValue::<Identity, OFFSET>,
-            Get4BRange: Get4BRange::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetType: GetType::<Identity>,
+            GetValue: GetValue::<Identity>,
+            SetValue: SetValue::<Identity>,
+            Get4BRange: Get4BRange::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDeviceSpecificProperty as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IDeviceSpecificProperty {}
 windows_core::imp::define_interface!(IDeviceTopology, IDeviceTopology_Vtbl, 0x2a07407e_6497_4a18_9787_32f79bd0d98f);
@@ -5368,6 +6965,107 @@ impl IDeviceTopology_Vtbl {
         iid == &<IDeviceTopology as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDeviceTopology_Impl> windows_core::imp::TearOffVtable<Identity> for IDeviceTopology_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetConnectorCount<Identity: windows_core::IUnknownImpl + IDeviceTopology_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDeviceTopology_Impl::GetConnectorCount(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetConnector<Identity: windows_core::IUnknownImpl + IDeviceTopology_Impl>(this: *mut core::ffi::c_void, nindex: u32, ppconnector: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDeviceTopology_Impl::GetConnector(this, core::mem::transmute_copy(&nindex)) {
+                    Ok(ok__) => {
+                        ppconnector.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSubunitCount<Identity: windows_core::IUnknownImpl + IDeviceTopology_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDeviceTopology_Impl::GetSubunitCount(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSubunit<Identity: windows_core::IUnknownImpl + IDeviceTopology_Impl>(this: *mut core::ffi::c_void, nindex: u32, ppsubunit: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDeviceTopology_Impl::GetSubunit(this, core::mem::transmute_copy(&nindex)) {
+                    Ok(ok__) => {
+                        ppsubunit.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPartById<Identity: windows_core::IUnknownImpl + IDeviceTopology_Impl>(this: *mut core::ffi::c_void, nid: u32, pppart: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDeviceTopology_Impl::GetPartById(this, core::mem::transmute_copy(&nid)) {
+                    Ok(ok__) => {
+                        pppart.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDeviceId<Identity: windows_core::IUnknownImpl + IDeviceTopology_Impl>(this: *mut core::ffi::c_void, ppwstrdeviceid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDeviceTopology_Impl::GetDeviceId(this) {
+                    Ok(ok__) => {
+                        ppwstrdeviceid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSignalPath<Identity: windows_core::IUnknownImpl + IDeviceTopology_Impl>(this: *mut core::ffi::c_void, pipartfrom: *mut core::ffi::c_void, pipartto: *mut core::ffi::c_void, brejectmixedpaths: windows_core::BOOL, ppparts: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDeviceTopology_Impl::GetSignalPath(this, core::mem::transmute_copy(&pipartfrom), core::mem::transmute_copy(&pipartto), core::mem::transmute_copy(&brejectmixedpaths)) {
+                    Ok(ok__) => {
+                        ppparts.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetConnectorCount: GetConnectorCount::<Identity>,
+            GetConnector: GetConnector::<Identity>,
+            GetSubunitCount: GetSubunitCount::<Identity>,
+            GetSubunit: GetSubunit::<Identity>,
+            GetPartById: GetPartById::<Identity>,
+            GetDeviceId: GetDeviceId::<Identity>,
+            GetSignalPath: GetSignalPath::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDeviceTopology {}
 windows_core::imp::define_interface!(IMMDevice, IMMDevice_Vtbl, 0xd666063f_1587_4e43_81f1_b948e807363f);
 windows_core::imp::interface_hierarchy!(IMMDevice, windows_core::IUnknown);
@@ -5480,6 +7178,63 @@ impl IMMDevice_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant", feature = "Win32_UI_Shell_PropertiesSystem"))]
+impl<Identity: windows_core::IUnknownImpl + IMMDevice_Impl> windows_core::imp::TearOffVtable<Identity> for IMMDevice_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Activate<Identity: windows_core::IUnknownImpl + IMMDevice_Impl>(this: *mut core::ffi::c_void, iid: *const windows_core::GUID, dwclsctx: super::super::System::Com::CLSCTX, pactivationparams: *const super::super::System::Com::StructuredStorage::PROPVARIANT, ppinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMMDevice_Impl::Activate(this, core::mem::transmute_copy(&iid), core::mem::transmute_copy(&dwclsctx), core::mem::transmute_copy(&pactivationparams), core::mem::transmute_copy(&ppinterface)).into()
+            }
+        }
+        unsafe extern "system" fn OpenPropertyStore<Identity: windows_core::IUnknownImpl + IMMDevice_Impl>(this: *mut core::ffi::c_void, stgmaccess: super::super::System::Com::STGM, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMMDevice_Impl::OpenPropertyStore(this, core::mem::transmute_copy(&stgmaccess)) {
+                    Ok(ok__) => {
+                        ppproperties.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetId<Identity: windows_core::IUnknownImpl + IMMDevice_Impl>(this: *mut core::ffi::c_void, ppstrid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMMDevice_Impl::GetId(this) {
+                    Ok(ok__) => {
+                        ppstrid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetState<Identity: windows_core::IUnknownImpl + IMMDevice_Impl>(this: *mut core::ffi::c_void, pdwstate: *mut DEVICE_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMMDevice_Impl::GetState(this) {
+                    Ok(ok__) => {
+                        pdwstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Activate: Activate::<Identity>,
+            OpenPropertyStore: OpenPropertyStore::<Identity>,
+            GetId: GetId::<Identity>,
+            GetState: GetState::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant", feature = "Win32_UI_Shell_PropertiesSystem"))]
 impl windows_core::RuntimeName for IMMDevice {}
 windows_core::imp::define_interface!(IMMDeviceActivator, IMMDeviceActivator_Vtbl, 0x3b0d0ea4_d0a9_4b0e_935b_09516746fac0);
 windows_core::imp::interface_hierarchy!(IMMDeviceActivator, windows_core::IUnknown);
@@ -5521,6 +7276,21 @@ impl IMMDeviceActivator_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IMMDeviceActivator_Impl> windows_core::imp::TearOffVtable<Identity> for IMMDeviceActivator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Activate<Identity: windows_core::IUnknownImpl + IMMDeviceActivator_Impl>(this: *mut core::ffi::c_void, iid: *const windows_core::GUID, pdevice: *mut core::ffi::c_void, pactivationparams: *const super::super::System::Com::StructuredStorage::PROPVARIANT, ppinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMMDeviceActivator_Impl::Activate(this, core::mem::transmute_copy(&iid), core::mem::transmute_copy(&pdevice), core::mem::transmute_copy(&pactivationparams), core::mem::transmute_copy(&ppinterface)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Activate: Activate::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IMMDeviceActivator {}
 windows_core::imp::define_interface!(IMMDeviceCollection, IMMDeviceCollection_Vtbl, 0x0bd7a1be_7a1a_44db_8397_cc5392387b5e);
 windows_core::imp::interface_hierarchy!(IMMDeviceCollection, windows_core::IUnknown);
@@ -5581,6 +7351,42 @@ impl IMMDeviceCollection_Vtbl {
         iid == &<IMMDeviceCollection as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMMDeviceCollection_Impl> windows_core::imp::TearOffVtable<Identity> for IMMDeviceCollection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IMMDeviceCollection_Impl>(this: *mut core::ffi::c_void, pcdevices: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMMDeviceCollection_Impl::GetCount(this) {
+                    Ok(ok__) => {
+                        pcdevices.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Item<Identity: windows_core::IUnknownImpl + IMMDeviceCollection_Impl>(this: *mut core::ffi::c_void, ndevice: u32, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMMDeviceCollection_Impl::Item(this, core::mem::transmute_copy(&ndevice)) {
+                    Ok(ok__) => {
+                        ppdevice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCount: GetCount::<Identity>,
+            Item: Item::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMMDeviceCollection {}
 windows_core::imp::define_interface!(IMMDeviceEnumerator, IMMDeviceEnumerator_Vtbl, 0xa95664d2_9614_4f35_a746_de8db63617e6);
 windows_core::imp::interface_hierarchy!(IMMDeviceEnumerator, windows_core::IUnknown);
@@ -5699,6 +7505,69 @@ impl IMMDeviceEnumerator_Vtbl {
         iid == &<IMMDeviceEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMMDeviceEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IMMDeviceEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumAudioEndpoints<Identity: windows_core::IUnknownImpl + IMMDeviceEnumerator_Impl>(this: *mut core::ffi::c_void, dataflow: EDataFlow, dwstatemask: DEVICE_STATE, ppdevices: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMMDeviceEnumerator_Impl::EnumAudioEndpoints(this, core::mem::transmute_copy(&dataflow), core::mem::transmute_copy(&dwstatemask)) {
+                    Ok(ok__) => {
+                        ppdevices.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDefaultAudioEndpoint<Identity: windows_core::IUnknownImpl + IMMDeviceEnumerator_Impl>(this: *mut core::ffi::c_void, dataflow: EDataFlow, role: ERole, ppendpoint: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMMDeviceEnumerator_Impl::GetDefaultAudioEndpoint(this, core::mem::transmute_copy(&dataflow), core::mem::transmute_copy(&role)) {
+                    Ok(ok__) => {
+                        ppendpoint.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDevice<Identity: windows_core::IUnknownImpl + IMMDeviceEnumerator_Impl>(this: *mut core::ffi::c_void, pwstrid: windows_core::PCWSTR, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMMDeviceEnumerator_Impl::GetDevice(this, core::mem::transmute(&pwstrid)) {
+                    Ok(ok__) => {
+                        ppdevice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RegisterEndpointNotificationCallback<Identity: windows_core::IUnknownImpl + IMMDeviceEnumerator_Impl>(this: *mut core::ffi::c_void, pclient: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMMDeviceEnumerator_Impl::RegisterEndpointNotificationCallback(this, core::mem::transmute_copy(&pclient)).into()
+            }
+        }
+        unsafe extern "system" fn UnregisterEndpointNotificationCallback<Identity: windows_core::IUnknownImpl + IMMDeviceEnumerator_Impl>(this: *mut core::ffi::c_void, pclient: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMMDeviceEnumerator_Impl::UnregisterEndpointNotificationCallback(this, core::mem::transmute_copy(&pclient)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnumAudioEndpoints: EnumAudioEndpoints::<Identity>,
+            GetDefaultAudioEndpoint: GetDefaultAudioEndpoint::<Identity>,
+            GetDevice: GetDevice::<Identity>,
+            RegisterEndpointNotificationCallback: RegisterEndpointNotificationCallback::<Identity>,
+            UnregisterEndpointNotificationCallback: UnregisterEndpointNotificationCallback::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMMDeviceEnumerator {}
 windows_core::imp::define_interface!(IMMEndpoint, IMMEndpoint_Vtbl, 0x1be09788_6894_4089_8586_9a2a6c265ac5);
 windows_core::imp::interface_hierarchy!(IMMEndpoint, windows_core::IUnknown);
@@ -5739,6 +7608,26 @@ impl IMMEndpoint_Vtbl {
         iid == &<IMMEndpoint as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMMEndpoint_Impl> windows_core::imp::TearOffVtable<Identity> for IMMEndpoint_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDataFlow<Identity: windows_core::IUnknownImpl + IMMEndpoint_Impl>(this: *mut core::ffi::c_void, pdataflow: *mut EDataFlow) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMMEndpoint_Impl::GetDataFlow(this) {
+                    Ok(ok__) => {
+                        pdataflow.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDataFlow: GetDataFlow::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IMMEndpoint {}
 windows_core::imp::define_interface!(IMMNotificationClient, IMMNotificationClient_Vtbl, 0x7991eec9_7e89_4d85_8390_6c703cec60c0);
 windows_core::imp::interface_hierarchy!(IMMNotificationClient, windows_core::IUnknown);
@@ -5831,10 +7720,55 @@ impl IMMNotificationClient_Vtbl {
             OnDefaultDeviceChanged: OnDefaultDeviceChanged::<Identity, OFFSET>,
             OnPropertyValueChanged: OnPropertyValueChanged::<Identity, OFFSET>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IMMNotificationClient as windows_core::Interface>::IID
-    }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMMNotificationClient as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IMMNotificationClient_Impl> windows_core::imp::TearOffVtable<Identity> for IMMNotificationClient_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnDeviceStateChanged<Identity: windows_core::IUnknownImpl + IMMNotificationClient_Impl>(this: *mut core::ffi::c_void, pwstrdeviceid: windows_core::PCWSTR, dwnewstate: DEVICE_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMMNotificationClient_Impl::OnDeviceStateChanged(this, core::mem::transmute(&pwstrdeviceid), core::mem::transmute_copy(&dwnewstate)).into()
+            }
+        }
+        unsafe extern "system" fn OnDeviceAdded<Identity: windows_core::IUnknownImpl + IMMNotificationClient_Impl>(this: *mut core::ffi::c_void, pwstrdeviceid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMMNotificationClient_Impl::OnDeviceAdded(this, core::mem::transmute(&pwstrdeviceid)).into()
+            }
+        }
+        unsafe extern "system" fn OnDeviceRemoved<Identity: windows_core::IUnknownImpl + IMMNotificationClient_Impl>(this: *mut core::ffi::c_void, pwstrdeviceid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMMNotificationClient_Impl::OnDeviceRemoved(this, core::mem::transmute(&pwstrdeviceid)).into()
+            }
+        }
+        unsafe extern "system" fn OnDefaultDeviceChanged<Identity: windows_core::IUnknownImpl + IMMNotificationClient_Impl>(this: *mut core::ffi::c_void, flow: EDataFlow, role: ERole, pwstrdefaultdeviceid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMMNotificationClient_Impl::OnDefaultDeviceChanged(this, core::mem::transmute_copy(&flow), core::mem::transmute_copy(&role), core::mem::transmute(&pwstrdefaultdeviceid)).into()
+            }
+        }
+        unsafe extern "system" fn OnPropertyValueChanged<Identity: windows_core::IUnknownImpl + IMMNotificationClient_Impl>(this: *mut core::ffi::c_void, pwstrdeviceid: windows_core::PCWSTR, key: super::super::Foundation::PROPERTYKEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMMNotificationClient_Impl::OnPropertyValueChanged(this, core::mem::transmute(&pwstrdeviceid), core::mem::transmute(&key)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnDeviceStateChanged: OnDeviceStateChanged::<Identity>,
+            OnDeviceAdded: OnDeviceAdded::<Identity>,
+            OnDeviceRemoved: OnDeviceRemoved::<Identity>,
+            OnDefaultDeviceChanged: OnDefaultDeviceChanged::<Identity>,
+            OnPropertyValueChanged: OnPropertyValueChanged::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IMMNotificationClient {}
 windows_core::imp::define_interface!(IMessageFilter, IMessageFilter_Vtbl, 0x00000016_0000_0000_c000_000000000046);
@@ -5901,6 +7835,38 @@ impl IMessageFilter_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IMessageFilter_Impl> windows_core::imp::TearOffVtable<Identity> for IMessageFilter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn HandleInComingCall<Identity: windows_core::IUnknownImpl + IMessageFilter_Impl>(this: *mut core::ffi::c_void, dwcalltype: u32, htaskcaller: super::HTASK, dwtickcount: u32, lpinterfaceinfo: *const super::super::System::Com::INTERFACEINFO) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMessageFilter_Impl::HandleInComingCall(this, core::mem::transmute_copy(&dwcalltype), core::mem::transmute_copy(&htaskcaller), core::mem::transmute_copy(&dwtickcount), core::mem::transmute_copy(&lpinterfaceinfo))
+            }
+        }
+        unsafe extern "system" fn RetryRejectedCall<Identity: windows_core::IUnknownImpl + IMessageFilter_Impl>(this: *mut core::ffi::c_void, htaskcallee: super::HTASK, dwtickcount: u32, dwrejecttype: u32) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMessageFilter_Impl::RetryRejectedCall(this, core::mem::transmute_copy(&htaskcallee), core::mem::transmute_copy(&dwtickcount), core::mem::transmute_copy(&dwrejecttype))
+            }
+        }
+        unsafe extern "system" fn MessagePending<Identity: windows_core::IUnknownImpl + IMessageFilter_Impl>(this: *mut core::ffi::c_void, htaskcallee: super::HTASK, dwtickcount: u32, dwpendingtype: u32) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMessageFilter_Impl::MessagePending(this, core::mem::transmute_copy(&htaskcallee), core::mem::transmute_copy(&dwtickcount), core::mem::transmute_copy(&dwpendingtype))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            HandleInComingCall: HandleInComingCall::<Identity>,
+            RetryRejectedCall: RetryRejectedCall::<Identity>,
+            MessagePending: MessagePending::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IMessageFilter {}
 windows_core::imp::define_interface!(IPart, IPart_Vtbl, 0xae2de0e4_5bca_4f2d_aa46_5d13f8fdb3a9);
 windows_core::imp::interface_hierarchy!(IPart, windows_core::IUnknown);
@@ -6175,6 +8141,167 @@ impl IPart_Vtbl {
         iid == &<IPart as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPart_Impl> windows_core::imp::TearOffVtable<Identity> for IPart_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IPart_Impl>(this: *mut core::ffi::c_void, ppwstrname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPart_Impl::GetName(this) {
+                    Ok(ok__) => {
+                        ppwstrname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLocalId<Identity: windows_core::IUnknownImpl + IPart_Impl>(this: *mut core::ffi::c_void, pnid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPart_Impl::GetLocalId(this) {
+                    Ok(ok__) => {
+                        pnid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetGlobalId<Identity: windows_core::IUnknownImpl + IPart_Impl>(this: *mut core::ffi::c_void, ppwstrglobalid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPart_Impl::GetGlobalId(this) {
+                    Ok(ok__) => {
+                        ppwstrglobalid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPartType<Identity: windows_core::IUnknownImpl + IPart_Impl>(this: *mut core::ffi::c_void, pparttype: *mut PartType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPart_Impl::GetPartType(this) {
+                    Ok(ok__) => {
+                        pparttype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSubType<Identity: windows_core::IUnknownImpl + IPart_Impl>(this: *mut core::ffi::c_void, psubtype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPart_Impl::GetSubType(this) {
+                    Ok(ok__) => {
+                        psubtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetControlInterfaceCount<Identity: windows_core::IUnknownImpl + IPart_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPart_Impl::GetControlInterfaceCount(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetControlInterface<Identity: windows_core::IUnknownImpl + IPart_Impl>(this: *mut core::ffi::c_void, nindex: u32, ppinterfacedesc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPart_Impl::GetControlInterface(this, core::mem::transmute_copy(&nindex)) {
+                    Ok(ok__) => {
+                        ppinterfacedesc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumPartsIncoming<Identity: windows_core::IUnknownImpl + IPart_Impl>(this: *mut core::ffi::c_void, ppparts: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPart_Impl::EnumPartsIncoming(this) {
+                    Ok(ok__) => {
+                        ppparts.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumPartsOutgoing<Identity: windows_core::IUnknownImpl + IPart_Impl>(this: *mut core::ffi::c_void, ppparts: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPart_Impl::EnumPartsOutgoing(this) {
+                    Ok(ok__) => {
+                        ppparts.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTopologyObject<Identity: windows_core::IUnknownImpl + IPart_Impl>(this: *mut core::ffi::c_void, pptopology: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPart_Impl::GetTopologyObject(this) {
+                    Ok(ok__) => {
+                        pptopology.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Activate<Identity: windows_core::IUnknownImpl + IPart_Impl>(this: *mut core::ffi::c_void, dwclscontext: u32, refiid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPart_Impl::Activate(this, core::mem::transmute_copy(&dwclscontext), core::mem::transmute_copy(&refiid), core::mem::transmute_copy(&ppvobject)).into()
+            }
+        }
+        unsafe extern "system" fn RegisterControlChangeCallback<Identity: windows_core::IUnknownImpl + IPart_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, pnotify: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPart_Impl::RegisterControlChangeCallback(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&pnotify)).into()
+            }
+        }
+        unsafe extern "system" fn UnregisterControlChangeCallback<Identity: windows_core::IUnknownImpl + IPart_Impl>(this: *mut core::ffi::c_void, pnotify: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPart_Impl::UnregisterControlChangeCallback(this, core::mem::transmute_copy(&pnotify)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetName: GetName::<Identity>,
+            GetLocalId: GetLocalId::<Identity>,
+            GetGlobalId: GetGlobalId::<Identity>,
+            GetPartType: GetPartType::<Identity>,
+            GetSubType: GetSubType::<Identity>,
+            GetControlInterfaceCount: GetControlInterfaceCount::<Identity>,
+            GetControlInterface: GetControlInterface::<Identity>,
+            EnumPartsIncoming: EnumPartsIncoming::<Identity>,
+            EnumPartsOutgoing: EnumPartsOutgoing::<Identity>,
+            GetTopologyObject: GetTopologyObject::<Identity>,
+            Activate: Activate::<Identity>,
+            RegisterControlChangeCallback: RegisterControlChangeCallback::<Identity>,
+            UnregisterControlChangeCallback: UnregisterControlChangeCallback::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPart {}
 windows_core::imp::define_interface!(IPartsList, IPartsList_Vtbl, 0x6daa848c_5eb0_45cc_aea5_998a2cda1ffb);
 windows_core::imp::interface_hierarchy!(IPartsList, windows_core::IUnknown);
@@ -6235,6 +8362,42 @@ impl IPartsList_Vtbl {
         iid == &<IPartsList as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPartsList_Impl> windows_core::imp::TearOffVtable<Identity> for IPartsList_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IPartsList_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPartsList_Impl::GetCount(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPart<Identity: windows_core::IUnknownImpl + IPartsList_Impl>(this: *mut core::ffi::c_void, nindex: u32, pppart: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPartsList_Impl::GetPart(this, core::mem::transmute_copy(&nindex)) {
+                    Ok(ok__) => {
+                        pppart.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCount: GetCount::<Identity>,
+            GetPart: GetPart::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPartsList {}
 windows_core::imp::define_interface!(IPerChannelDbLevel, IPerChannelDbLevel_Vtbl, 0xc2f8e001_f205_4bc9_99bc_c13b1e048ccb);
 windows_core::imp::interface_hierarchy!(IPerChannelDbLevel, windows_core::IUnknown);
@@ -6347,6 +8510,70 @@ impl IPerChannelDbLevel_Vtbl {
         iid == &<IPerChannelDbLevel as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPerChannelDbLevel_Impl> windows_core::imp::TearOffVtable<Identity> for IPerChannelDbLevel_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetChannelCount<Identity: windows_core::IUnknownImpl + IPerChannelDbLevel_Impl>(this: *mut core::ffi::c_void, pcchannels: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPerChannelDbLevel_Impl::GetChannelCount(this) {
+                    Ok(ok__) => {
+                        pcchannels.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLevelRange<Identity: windows_core::IUnknownImpl + IPerChannelDbLevel_Impl>(this: *mut core::ffi::c_void, nchannel: u32, pfminleveldb: *mut f32, pfmaxleveldb: *mut f32, pfstepping: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPerChannelDbLevel_Impl::GetLevelRange(this, core::mem::transmute_copy(&nchannel), core::mem::transmute_copy(&pfminleveldb), core::mem::transmute_copy(&pfmaxleveldb), core::mem::transmute_copy(&pfstepping)).into()
+            }
+        }
+        unsafe extern "system" fn GetLevel<Identity: windows_core::IUnknownImpl + IPerChannelDbLevel_Impl>(this: *mut core::ffi::c_void, nchannel: u32, pfleveldb: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPerChannelDbLevel_Impl::GetLevel(this, core::mem::transmute_copy(&nchannel)) {
+                    Ok(ok__) => {
+                        pfleveldb.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLevel<Identity: windows_core::IUnknownImpl + IPerChannelDbLevel_Impl>(this: *mut core::ffi::c_void, nchannel: u32, fleveldb: f32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPerChannelDbLevel_Impl::SetLevel(this, core::mem::transmute_copy(&nchannel), core::mem::transmute_copy(&fleveldb), core::mem::transmute_copy(&pguideventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn SetLevelUniform<Identity: windows_core::IUnknownImpl + IPerChannelDbLevel_Impl>(this: *mut core::ffi::c_void, fleveldb: f32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPerChannelDbLevel_Impl::SetLevelUniform(this, core::mem::transmute_copy(&fleveldb), core::mem::transmute_copy(&pguideventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn SetLevelAllChannels<Identity: windows_core::IUnknownImpl + IPerChannelDbLevel_Impl>(this: *mut core::ffi::c_void, alevelsdb: *const f32, cchannels: u32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPerChannelDbLevel_Impl::SetLevelAllChannels(this, core::mem::transmute_copy(&alevelsdb), core::mem::transmute_copy(&cchannels), core::mem::transmute_copy(&pguideventcontext)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetChannelCount: GetChannelCount::<Identity>,
+            GetLevelRange: GetLevelRange::<Identity>,
+            GetLevel: GetLevel::<Identity>,
+            SetLevel: SetLevel::<Identity>,
+            SetLevelUniform: SetLevelUniform::<Identity>,
+            SetLevelAllChannels: SetLevelAllChannels::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPerChannelDbLevel {}
 windows_core::imp::define_interface!(ISimpleAudioVolume, ISimpleAudioVolume_Vtbl, 0x87ce5498_68d6_44e5_9215_6da47ef883d8);
 windows_core::imp::interface_hierarchy!(ISimpleAudioVolume, windows_core::IUnknown);
@@ -6389,13 +8616,66 @@ impl ISimpleAudioVolume_Vtbl {
     pub const fn new<Identity: ISimpleAudioVolume_Impl, const OFFSET: isize>() -> Self {
         unsafe extern "system" fn SetMasterVolume<Identity: ISimpleAudioVolume_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flevel: f32, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ISimpleAudioVolume_Impl::SetMasterVolume(this, core::mem::transmute_copy(&flevel), core::mem::transmute_copy(&eventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn GetMasterVolume<Identity: ISimpleAudioVolume_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pflevel: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ISimpleAudioVolume_Impl::GetMasterVolume(this) {
+                    Ok(ok__) => {
+                        pflevel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMute<Identity: ISimpleAudioVolume_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bmute: windows_core::BOOL, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ISimpleAudioVolume_Impl::SetMute(this, core::mem::transmute_copy(&bmute), core::mem::transmute_copy(&eventcontext)).into()
+            }
+        }
+        unsafe extern "system" fn GetMute<Identity: ISimpleAudioVolume_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbmute: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ISimpleAudioVolume_Impl::GetMute(this) {
+                    Ok(ok__) => {
+                        pbmute.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            SetMasterVolume: SetMasterVolume::<Identity, OFFSET>,
+            GetMasterVolume: GetMasterVolume::<Identity, OFFSET>,
+            SetMute: SetMute::<Identity, OFFSET>,
+            GetMute: GetMute::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISimpleAudioVolume as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + ISimpleAudioVolume_Impl> windows_core::imp::TearOffVtable<Identity> for ISimpleAudioVolume_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetMasterVolume<Identity: windows_core::IUnknownImpl + ISimpleAudioVolume_Impl>(this: *mut core::ffi::c_void, flevel: f32, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ISimpleAudioVolume_Impl::SetMasterVolume(this, core::mem::transmute_copy(&flevel), core::mem::transmute_copy(&eventcontext)).into()
             }
         }
-        unsafe extern "system" fn GetMasterVolume<Identity: ISimpleAudioVolume_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pflevel: *mut f32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetMasterVolume<Identity: windows_core::IUnknownImpl + ISimpleAudioVolume_Impl>(this: *mut core::ffi::c_void, pflevel: *mut f32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ISimpleAudioVolume_Impl::GetMasterVolume(this) {
                     Ok(ok__) => {
                         pflevel.write(core::mem::transmute(ok__));
@@ -6405,15 +8685,15 @@ impl ISimpleAudioVolume_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetMute<Identity: ISimpleAudioVolume_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bmute: windows_core::BOOL, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetMute<Identity: windows_core::IUnknownImpl + ISimpleAudioVolume_Impl>(this: *mut core::ffi::c_void, bmute: windows_core::BOOL, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ISimpleAudioVolume_Impl::SetMute(this, core::mem::transmute_copy(&bmute), core::mem::transmute_copy(&eventcontext)).into()
             }
         }
-        unsafe extern "system" fn GetMute<Identity: ISimpleAudioVolume_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbmute: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetMute<Identity: windows_core::IUnknownImpl + ISimpleAudioVolume_Impl>(this: *mut core::ffi::c_void, pbmute: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ISimpleAudioVolume_Impl::GetMute(this) {
                     Ok(ok__) => {
                         pbmute.write(core::mem::transmute(ok__));
@@ -6424,16 +8704,13 @@ impl ISimpleAudioVolume_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            SetMasterVolume: SetMasterVolume::<Identity, OFFSET>,
-            GetMasterVolume: GetMasterVolume::<Identity, OFFSET>,
-            SetMute: SetMute::<Identity, OFFSET>,
-            GetMute: GetMute::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetMasterVolume: SetMasterVolume::<Identity>,
+            GetMasterVolume: GetMasterVolume::<Identity>,
+            SetMute: SetMute::<Identity>,
+            GetMute: GetMute::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ISimpleAudioVolume as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for ISimpleAudioVolume {}
 windows_core::imp::define_interface!(ISpatialAudioClient, ISpatialAudioClient_Vtbl, 0xbbf8e066_aaaa_49be_9a4d_fd2a858ea27f);
@@ -6604,6 +8881,97 @@ impl ISpatialAudioClient_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpatialAudioClient_Impl> windows_core::imp::TearOffVtable<Identity> for ISpatialAudioClient_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStaticObjectPosition<Identity: windows_core::IUnknownImpl + ISpatialAudioClient_Impl>(this: *mut core::ffi::c_void, r#type: AudioObjectType, x: *mut f32, y: *mut f32, z: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioClient_Impl::GetStaticObjectPosition(this, core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&x), core::mem::transmute_copy(&y), core::mem::transmute_copy(&z)).into()
+            }
+        }
+        unsafe extern "system" fn GetNativeStaticObjectTypeMask<Identity: windows_core::IUnknownImpl + ISpatialAudioClient_Impl>(this: *mut core::ffi::c_void, mask: *mut AudioObjectType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioClient_Impl::GetNativeStaticObjectTypeMask(this) {
+                    Ok(ok__) => {
+                        mask.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMaxDynamicObjectCount<Identity: windows_core::IUnknownImpl + ISpatialAudioClient_Impl>(this: *mut core::ffi::c_void, value: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioClient_Impl::GetMaxDynamicObjectCount(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSupportedAudioObjectFormatEnumerator<Identity: windows_core::IUnknownImpl + ISpatialAudioClient_Impl>(this: *mut core::ffi::c_void, enumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioClient_Impl::GetSupportedAudioObjectFormatEnumerator(this) {
+                    Ok(ok__) => {
+                        enumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMaxFrameCount<Identity: windows_core::IUnknownImpl + ISpatialAudioClient_Impl>(this: *mut core::ffi::c_void, objectformat: *const WAVEFORMATEX, framecountperbuffer: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioClient_Impl::GetMaxFrameCount(this, core::mem::transmute_copy(&objectformat)) {
+                    Ok(ok__) => {
+                        framecountperbuffer.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsAudioObjectFormatSupported<Identity: windows_core::IUnknownImpl + ISpatialAudioClient_Impl>(this: *mut core::ffi::c_void, objectformat: *const WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioClient_Impl::IsAudioObjectFormatSupported(this, core::mem::transmute_copy(&objectformat)).into()
+            }
+        }
+        unsafe extern "system" fn IsSpatialAudioStreamAvailable<Identity: windows_core::IUnknownImpl + ISpatialAudioClient_Impl>(this: *mut core::ffi::c_void, streamuuid: *const windows_core::GUID, auxiliaryinfo: *const super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioClient_Impl::IsSpatialAudioStreamAvailable(this, core::mem::transmute_copy(&streamuuid), core::mem::transmute_copy(&auxiliaryinfo)).into()
+            }
+        }
+        unsafe extern "system" fn ActivateSpatialAudioStream<Identity: windows_core::IUnknownImpl + ISpatialAudioClient_Impl>(this: *mut core::ffi::c_void, activationparams: *const super::super::System::Com::StructuredStorage::PROPVARIANT, riid: *const windows_core::GUID, stream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioClient_Impl::ActivateSpatialAudioStream(this, core::mem::transmute_copy(&activationparams), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&stream)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStaticObjectPosition: GetStaticObjectPosition::<Identity>,
+            GetNativeStaticObjectTypeMask: GetNativeStaticObjectTypeMask::<Identity>,
+            GetMaxDynamicObjectCount: GetMaxDynamicObjectCount::<Identity>,
+            GetSupportedAudioObjectFormatEnumerator: GetSupportedAudioObjectFormatEnumerator::<Identity>,
+            GetMaxFrameCount: GetMaxFrameCount::<Identity>,
+            IsAudioObjectFormatSupported: IsAudioObjectFormatSupported::<Identity>,
+            IsSpatialAudioStreamAvailable: IsSpatialAudioStreamAvailable::<Identity>,
+            ActivateSpatialAudioStream: ActivateSpatialAudioStream::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpatialAudioClient {}
 windows_core::imp::define_interface!(ISpatialAudioClient2, ISpatialAudioClient2_Vtbl, 0xcaabe452_a66a_4bee_a93e_e320463f6a53);
 impl core::ops::Deref for ISpatialAudioClient2 {
@@ -6677,6 +9045,43 @@ impl ISpatialAudioClient2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpatialAudioClient2_Impl> windows_core::imp::TearOffVtable<Identity> for ISpatialAudioClient2_Vtbl
+where
+    ISpatialAudioClient_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsOffloadCapable<Identity: windows_core::IUnknownImpl + ISpatialAudioClient2_Impl>(this: *mut core::ffi::c_void, category: AUDIO_STREAM_CATEGORY, isoffloadcapable: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioClient2_Impl::IsOffloadCapable(this, core::mem::transmute_copy(&category)) {
+                    Ok(ok__) => {
+                        isoffloadcapable.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMaxFrameCountForCategory<Identity: windows_core::IUnknownImpl + ISpatialAudioClient2_Impl>(this: *mut core::ffi::c_void, category: AUDIO_STREAM_CATEGORY, offloadenabled: windows_core::BOOL, objectformat: *const WAVEFORMATEX, framecountperbuffer: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioClient2_Impl::GetMaxFrameCountForCategory(this, core::mem::transmute_copy(&category), core::mem::transmute_copy(&offloadenabled), core::mem::transmute_copy(&objectformat)) {
+                    Ok(ok__) => {
+                        framecountperbuffer.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ISpatialAudioClient_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsOffloadCapable: IsOffloadCapable::<Identity>,
+            GetMaxFrameCountForCategory: GetMaxFrameCountForCategory::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpatialAudioClient2 {}
 windows_core::imp::define_interface!(ISpatialAudioMetadataClient, ISpatialAudioMetadataClient_Vtbl, 0x777d4a3b_f6ff_4a26_85dc_68d7cdeda1d4);
 windows_core::imp::interface_hierarchy!(ISpatialAudioMetadataClient, windows_core::IUnknown);
@@ -6795,6 +9200,75 @@ impl ISpatialAudioMetadataClient_Vtbl {
         iid == &<ISpatialAudioMetadataClient as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataClient_Impl> windows_core::imp::TearOffVtable<Identity> for ISpatialAudioMetadataClient_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ActivateSpatialAudioMetadataItems<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataClient_Impl>(this: *mut core::ffi::c_void, maxitemcount: u16, framecount: u16, metadataitemsbuffer: *mut *mut core::ffi::c_void, metadataitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioMetadataClient_Impl::ActivateSpatialAudioMetadataItems(this, core::mem::transmute_copy(&maxitemcount), core::mem::transmute_copy(&framecount), core::mem::transmute_copy(&metadataitemsbuffer), core::mem::transmute_copy(&metadataitems)).into()
+            }
+        }
+        unsafe extern "system" fn GetSpatialAudioMetadataItemsBufferLength<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataClient_Impl>(this: *mut core::ffi::c_void, maxitemcount: u16, bufferlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioMetadataClient_Impl::GetSpatialAudioMetadataItemsBufferLength(this, core::mem::transmute_copy(&maxitemcount)) {
+                    Ok(ok__) => {
+                        bufferlength.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ActivateSpatialAudioMetadataWriter<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataClient_Impl>(this: *mut core::ffi::c_void, overflowmode: SpatialAudioMetadataWriterOverflowMode, metadatawriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioMetadataClient_Impl::ActivateSpatialAudioMetadataWriter(this, core::mem::transmute_copy(&overflowmode)) {
+                    Ok(ok__) => {
+                        metadatawriter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ActivateSpatialAudioMetadataCopier<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataClient_Impl>(this: *mut core::ffi::c_void, metadatacopier: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioMetadataClient_Impl::ActivateSpatialAudioMetadataCopier(this) {
+                    Ok(ok__) => {
+                        metadatacopier.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ActivateSpatialAudioMetadataReader<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataClient_Impl>(this: *mut core::ffi::c_void, metadatareader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioMetadataClient_Impl::ActivateSpatialAudioMetadataReader(this) {
+                    Ok(ok__) => {
+                        metadatareader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ActivateSpatialAudioMetadataItems: ActivateSpatialAudioMetadataItems::<Identity>,
+            GetSpatialAudioMetadataItemsBufferLength: GetSpatialAudioMetadataItemsBufferLength::<Identity>,
+            ActivateSpatialAudioMetadataWriter: ActivateSpatialAudioMetadataWriter::<Identity>,
+            ActivateSpatialAudioMetadataCopier: ActivateSpatialAudioMetadataCopier::<Identity>,
+            ActivateSpatialAudioMetadataReader: ActivateSpatialAudioMetadataReader::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpatialAudioMetadataClient {}
 windows_core::imp::define_interface!(ISpatialAudioMetadataCopier, ISpatialAudioMetadataCopier_Vtbl, 0xd224b233_e251_4fd0_9ca2_d5ecf9a68404);
 windows_core::imp::interface_hierarchy!(ISpatialAudioMetadataCopier, windows_core::IUnknown);
@@ -6868,6 +9342,43 @@ impl ISpatialAudioMetadataCopier_Vtbl {
         iid == &<ISpatialAudioMetadataCopier as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataCopier_Impl> windows_core::imp::TearOffVtable<Identity> for ISpatialAudioMetadataCopier_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Open<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataCopier_Impl>(this: *mut core::ffi::c_void, metadataitems: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioMetadataCopier_Impl::Open(this, core::mem::transmute_copy(&metadataitems)).into()
+            }
+        }
+        unsafe extern "system" fn CopyMetadataForFrames<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataCopier_Impl>(this: *mut core::ffi::c_void, copyframecount: u16, copymode: SpatialAudioMetadataCopyMode, dstmetadataitems: *mut core::ffi::c_void, itemscopied: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioMetadataCopier_Impl::CopyMetadataForFrames(this, core::mem::transmute_copy(&copyframecount), core::mem::transmute_copy(&copymode), core::mem::transmute_copy(&dstmetadataitems)) {
+                    Ok(ok__) => {
+                        itemscopied.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataCopier_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioMetadataCopier_Impl::Close(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Open: Open::<Identity>,
+            CopyMetadataForFrames: CopyMetadataForFrames::<Identity>,
+            Close: Close::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpatialAudioMetadataCopier {}
 windows_core::imp::define_interface!(ISpatialAudioMetadataItems, ISpatialAudioMetadataItems_Vtbl, 0xbcd7c78f_3098_4f22_b547_a2f25a381269);
 windows_core::imp::interface_hierarchy!(ISpatialAudioMetadataItems, windows_core::IUnknown);
@@ -6995,6 +9506,81 @@ impl ISpatialAudioMetadataItems_Vtbl {
         iid == &<ISpatialAudioMetadataItems as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataItems_Impl> windows_core::imp::TearOffVtable<Identity> for ISpatialAudioMetadataItems_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFrameCount<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataItems_Impl>(this: *mut core::ffi::c_void, framecount: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioMetadataItems_Impl::GetFrameCount(this) {
+                    Ok(ok__) => {
+                        framecount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetItemCount<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataItems_Impl>(this: *mut core::ffi::c_void, itemcount: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioMetadataItems_Impl::GetItemCount(this) {
+                    Ok(ok__) => {
+                        itemcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMaxItemCount<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataItems_Impl>(this: *mut core::ffi::c_void, maxitemcount: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioMetadataItems_Impl::GetMaxItemCount(this) {
+                    Ok(ok__) => {
+                        maxitemcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMaxValueBufferLength<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataItems_Impl>(this: *mut core::ffi::c_void, maxvaluebufferlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioMetadataItems_Impl::GetMaxValueBufferLength(this) {
+                    Ok(ok__) => {
+                        maxvaluebufferlength.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetInfo<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataItems_Impl>(this: *mut core::ffi::c_void, info: *mut SpatialAudioMetadataItemsInfo) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioMetadataItems_Impl::GetInfo(this) {
+                    Ok(ok__) => {
+                        info.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFrameCount: GetFrameCount::<Identity>,
+            GetItemCount: GetItemCount::<Identity>,
+            GetMaxItemCount: GetMaxItemCount::<Identity>,
+            GetMaxValueBufferLength: GetMaxValueBufferLength::<Identity>,
+            GetInfo: GetInfo::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpatialAudioMetadataItems {}
 windows_core::imp::define_interface!(ISpatialAudioMetadataItemsBuffer, ISpatialAudioMetadataItemsBuffer_Vtbl, 0x42640a16_e1bd_42d9_9ff6_031ab71a2dba);
 windows_core::imp::interface_hierarchy!(ISpatialAudioMetadataItemsBuffer, windows_core::IUnknown);
@@ -7038,20 +9624,51 @@ impl ISpatialAudioMetadataItemsBuffer_Vtbl {
         }
         unsafe extern "system" fn DetachBuffer<Identity: ISpatialAudioMetadataItemsBuffer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ISpatialAudioMetadataItemsBuffer_Impl::DetachBuffer(this).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            AttachToBuffer: AttachToBuffer::<Identity, OFFSET>,
+            AttachToPopulatedBuffer: AttachToPopulatedBuffer::<Identity, OFFSET>,
+            DetachBuffer: DetachBuffer::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioMetadataItemsBuffer as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataItemsBuffer_Impl> windows_core::imp::TearOffVtable<Identity> for ISpatialAudioMetadataItemsBuffer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AttachToBuffer<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataItemsBuffer_Impl>(this: *mut core::ffi::c_void, buffer: *mut u8, bufferlength: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioMetadataItemsBuffer_Impl::AttachToBuffer(this, core::mem::transmute_copy(&buffer), core::mem::transmute_copy(&bufferlength)).into()
+            }
+        }
+        unsafe extern "system" fn AttachToPopulatedBuffer<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataItemsBuffer_Impl>(this: *mut core::ffi::c_void, buffer: *mut u8, bufferlength: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioMetadataItemsBuffer_Impl::AttachToPopulatedBuffer(this, core::mem::transmute_copy(&buffer), core::mem::transmute_copy(&bufferlength)).into()
+            }
+        }
+        unsafe extern "system" fn DetachBuffer<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataItemsBuffer_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ISpatialAudioMetadataItemsBuffer_Impl::DetachBuffer(this).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            AttachToBuffer: AttachToBuffer::<Identity, OFFSET>,
-            AttachToPopulatedBuffer: AttachToPopulatedBuffer::<Identity, OFFSET>,
-            DetachBuffer: DetachBuffer::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AttachToBuffer: AttachToBuffer::<Identity>,
+            AttachToPopulatedBuffer: AttachToPopulatedBuffer::<Identity>,
+            DetachBuffer: DetachBuffer::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ISpatialAudioMetadataItemsBuffer as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for ISpatialAudioMetadataItemsBuffer {}
 windows_core::imp::define_interface!(ISpatialAudioMetadataReader, ISpatialAudioMetadataReader_Vtbl, 0xb78e86a2_31d9_4c32_94d2_7df40fc7ebec);
@@ -7126,6 +9743,44 @@ impl ISpatialAudioMetadataReader_Vtbl {
         iid == &<ISpatialAudioMetadataReader as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataReader_Impl> windows_core::imp::TearOffVtable<Identity> for ISpatialAudioMetadataReader_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Open<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataReader_Impl>(this: *mut core::ffi::c_void, metadataitems: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioMetadataReader_Impl::Open(this, core::mem::transmute_copy(&metadataitems)).into()
+            }
+        }
+        unsafe extern "system" fn ReadNextItem<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataReader_Impl>(this: *mut core::ffi::c_void, commandcount: *mut u8, frameoffset: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioMetadataReader_Impl::ReadNextItem(this, core::mem::transmute_copy(&commandcount), core::mem::transmute_copy(&frameoffset)).into()
+            }
+        }
+        unsafe extern "system" fn ReadNextItemCommand<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataReader_Impl>(this: *mut core::ffi::c_void, commandid: *mut u8, valuebuffer: *mut core::ffi::c_void, maxvaluebufferlength: u32, valuebufferlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioMetadataReader_Impl::ReadNextItemCommand(this, core::mem::transmute_copy(&commandid), core::mem::transmute_copy(&valuebuffer), core::mem::transmute_copy(&maxvaluebufferlength), core::mem::transmute_copy(&valuebufferlength)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataReader_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioMetadataReader_Impl::Close(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Open: Open::<Identity>,
+            ReadNextItem: ReadNextItem::<Identity>,
+            ReadNextItemCommand: ReadNextItemCommand::<Identity>,
+            Close: Close::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpatialAudioMetadataReader {}
 windows_core::imp::define_interface!(ISpatialAudioMetadataWriter, ISpatialAudioMetadataWriter_Vtbl, 0x1b17ca01_2955_444d_a430_537dc589a844);
 windows_core::imp::interface_hierarchy!(ISpatialAudioMetadataWriter, windows_core::IUnknown);
@@ -7199,6 +9854,44 @@ impl ISpatialAudioMetadataWriter_Vtbl {
         iid == &<ISpatialAudioMetadataWriter as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataWriter_Impl> windows_core::imp::TearOffVtable<Identity> for ISpatialAudioMetadataWriter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Open<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataWriter_Impl>(this: *mut core::ffi::c_void, metadataitems: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioMetadataWriter_Impl::Open(this, core::mem::transmute_copy(&metadataitems)).into()
+            }
+        }
+        unsafe extern "system" fn WriteNextItem<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataWriter_Impl>(this: *mut core::ffi::c_void, frameoffset: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioMetadataWriter_Impl::WriteNextItem(this, core::mem::transmute_copy(&frameoffset)).into()
+            }
+        }
+        unsafe extern "system" fn WriteNextItemCommand<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataWriter_Impl>(this: *mut core::ffi::c_void, commandid: u8, valuebuffer: *const core::ffi::c_void, valuebufferlength: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioMetadataWriter_Impl::WriteNextItemCommand(this, core::mem::transmute_copy(&commandid), core::mem::transmute_copy(&valuebuffer), core::mem::transmute_copy(&valuebufferlength)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + ISpatialAudioMetadataWriter_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioMetadataWriter_Impl::Close(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Open: Open::<Identity>,
+            WriteNextItem: WriteNextItem::<Identity>,
+            WriteNextItemCommand: WriteNextItemCommand::<Identity>,
+            Close: Close::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpatialAudioMetadataWriter {}
 windows_core::imp::define_interface!(ISpatialAudioObject, ISpatialAudioObject_Vtbl, 0xdde28967_521b_46e5_8f00_bd6f2bc8ab1d);
 impl core::ops::Deref for ISpatialAudioObject {
@@ -7251,6 +9944,30 @@ impl ISpatialAudioObject_Vtbl {
         iid == &<ISpatialAudioObject as windows_core::Interface>::IID || iid == &<ISpatialAudioObjectBase as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpatialAudioObject_Impl> windows_core::imp::TearOffVtable<Identity> for ISpatialAudioObject_Vtbl
+where
+    ISpatialAudioObjectBase_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetPosition<Identity: windows_core::IUnknownImpl + ISpatialAudioObject_Impl>(this: *mut core::ffi::c_void, x: f32, y: f32, z: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioObject_Impl::SetPosition(this, core::mem::transmute_copy(&x), core::mem::transmute_copy(&y), core::mem::transmute_copy(&z)).into()
+            }
+        }
+        unsafe extern "system" fn SetVolume<Identity: windows_core::IUnknownImpl + ISpatialAudioObject_Impl>(this: *mut core::ffi::c_void, volume: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioObject_Impl::SetVolume(this, core::mem::transmute_copy(&volume)).into()
+            }
+        }
+        Self {
+            base__: <ISpatialAudioObjectBase_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetPosition: SetPosition::<Identity>,
+            SetVolume: SetVolume::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpatialAudioObject {}
 windows_core::imp::define_interface!(ISpatialAudioObjectBase, ISpatialAudioObjectBase_Vtbl, 0xcce0b8f2_8d4d_4efb_a8cf_3d6ecf1c30e0);
 windows_core::imp::interface_hierarchy!(ISpatialAudioObjectBase, windows_core::IUnknown);
@@ -7339,6 +10056,56 @@ impl ISpatialAudioObjectBase_Vtbl {
         iid == &<ISpatialAudioObjectBase as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectBase_Impl> windows_core::imp::TearOffVtable<Identity> for ISpatialAudioObjectBase_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetBuffer<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectBase_Impl>(this: *mut core::ffi::c_void, buffer: *mut *mut u8, bufferlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioObjectBase_Impl::GetBuffer(this, core::mem::transmute_copy(&buffer), core::mem::transmute_copy(&bufferlength)).into()
+            }
+        }
+        unsafe extern "system" fn SetEndOfStream<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectBase_Impl>(this: *mut core::ffi::c_void, framecount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioObjectBase_Impl::SetEndOfStream(this, core::mem::transmute_copy(&framecount)).into()
+            }
+        }
+        unsafe extern "system" fn IsActive<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectBase_Impl>(this: *mut core::ffi::c_void, isactive: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioObjectBase_Impl::IsActive(this) {
+                    Ok(ok__) => {
+                        isactive.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAudioObjectType<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectBase_Impl>(this: *mut core::ffi::c_void, audioobjecttype: *mut AudioObjectType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioObjectBase_Impl::GetAudioObjectType(this) {
+                    Ok(ok__) => {
+                        audioobjecttype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetBuffer: GetBuffer::<Identity>,
+            SetEndOfStream: SetEndOfStream::<Identity>,
+            IsActive: IsActive::<Identity>,
+            GetAudioObjectType: GetAudioObjectType::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpatialAudioObjectBase {}
 windows_core::imp::define_interface!(ISpatialAudioObjectForHrtf, ISpatialAudioObjectForHrtf_Vtbl, 0xd7436ade_1978_4e14_aba0_555bd8eb83b4);
 impl core::ops::Deref for ISpatialAudioObjectForHrtf {
@@ -7439,6 +10206,58 @@ impl ISpatialAudioObjectForHrtf_Vtbl {
         iid == &<ISpatialAudioObjectForHrtf as windows_core::Interface>::IID || iid == &<ISpatialAudioObjectBase as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectForHrtf_Impl> windows_core::imp::TearOffVtable<Identity> for ISpatialAudioObjectForHrtf_Vtbl
+where
+    ISpatialAudioObjectBase_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetPosition<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectForHrtf_Impl>(this: *mut core::ffi::c_void, x: f32, y: f32, z: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioObjectForHrtf_Impl::SetPosition(this, core::mem::transmute_copy(&x), core::mem::transmute_copy(&y), core::mem::transmute_copy(&z)).into()
+            }
+        }
+        unsafe extern "system" fn SetGain<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectForHrtf_Impl>(this: *mut core::ffi::c_void, gain: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioObjectForHrtf_Impl::SetGain(this, core::mem::transmute_copy(&gain)).into()
+            }
+        }
+        unsafe extern "system" fn SetOrientation<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectForHrtf_Impl>(this: *mut core::ffi::c_void, orientation: *const *const f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioObjectForHrtf_Impl::SetOrientation(this, core::mem::transmute_copy(&orientation)).into()
+            }
+        }
+        unsafe extern "system" fn SetEnvironment<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectForHrtf_Impl>(this: *mut core::ffi::c_void, environment: SpatialAudioHrtfEnvironmentType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioObjectForHrtf_Impl::SetEnvironment(this, core::mem::transmute_copy(&environment)).into()
+            }
+        }
+        unsafe extern "system" fn SetDistanceDecay<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectForHrtf_Impl>(this: *mut core::ffi::c_void, distancedecay: *const SpatialAudioHrtfDistanceDecay) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioObjectForHrtf_Impl::SetDistanceDecay(this, core::mem::transmute_copy(&distancedecay)).into()
+            }
+        }
+        unsafe extern "system" fn SetDirectivity<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectForHrtf_Impl>(this: *mut core::ffi::c_void, directivity: *const SpatialAudioHrtfDirectivityUnion) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioObjectForHrtf_Impl::SetDirectivity(this, core::mem::transmute_copy(&directivity)).into()
+            }
+        }
+        Self {
+            base__: <ISpatialAudioObjectBase_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetPosition: SetPosition::<Identity>,
+            SetGain: SetGain::<Identity>,
+            SetOrientation: SetOrientation::<Identity>,
+            SetEnvironment: SetEnvironment::<Identity>,
+            SetDistanceDecay: SetDistanceDecay::<Identity>,
+            SetDirectivity: SetDirectivity::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpatialAudioObjectForHrtf {}
 windows_core::imp::define_interface!(ISpatialAudioObjectForMetadataCommands, ISpatialAudioObjectForMetadataCommands_Vtbl, 0x0df2c94b_f5f9_472d_af6b_c46e0ac9cd05);
 impl core::ops::Deref for ISpatialAudioObjectForMetadataCommands {
@@ -7476,6 +10295,23 @@ impl ISpatialAudioObjectForMetadataCommands_Vtbl {
         iid == &<ISpatialAudioObjectForMetadataCommands as windows_core::Interface>::IID || iid == &<ISpatialAudioObjectBase as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectForMetadataCommands_Impl> windows_core::imp::TearOffVtable<Identity> for ISpatialAudioObjectForMetadataCommands_Vtbl
+where
+    ISpatialAudioObjectBase_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn WriteNextMetadataCommand<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectForMetadataCommands_Impl>(this: *mut core::ffi::c_void, commandid: u8, valuebuffer: *const core::ffi::c_void, valuebufferlength: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioObjectForMetadataCommands_Impl::WriteNextMetadataCommand(this, core::mem::transmute_copy(&commandid), core::mem::transmute_copy(&valuebuffer), core::mem::transmute_copy(&valuebufferlength)).into()
+            }
+        }
+        Self {
+            base__: <ISpatialAudioObjectBase_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            WriteNextMetadataCommand: WriteNextMetadataCommand::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpatialAudioObjectForMetadataCommands {}
 windows_core::imp::define_interface!(ISpatialAudioObjectForMetadataItems, ISpatialAudioObjectForMetadataItems_Vtbl, 0xddea49ff_3bc0_4377_8aad_9fbcfd808566);
 impl core::ops::Deref for ISpatialAudioObjectForMetadataItems {
@@ -7522,6 +10358,29 @@ impl ISpatialAudioObjectForMetadataItems_Vtbl {
         iid == &<ISpatialAudioObjectForMetadataItems as windows_core::Interface>::IID || iid == &<ISpatialAudioObjectBase as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectForMetadataItems_Impl> windows_core::imp::TearOffVtable<Identity> for ISpatialAudioObjectForMetadataItems_Vtbl
+where
+    ISpatialAudioObjectBase_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSpatialAudioMetadataItems<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectForMetadataItems_Impl>(this: *mut core::ffi::c_void, metadataitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioObjectForMetadataItems_Impl::GetSpatialAudioMetadataItems(this) {
+                    Ok(ok__) => {
+                        metadataitems.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ISpatialAudioObjectBase_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSpatialAudioMetadataItems: GetSpatialAudioMetadataItems::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpatialAudioObjectForMetadataItems {}
 windows_core::imp::define_interface!(ISpatialAudioObjectRenderStream, ISpatialAudioObjectRenderStream_Vtbl, 0xbab5f473_b423_477b_85f5_b5a332a04153);
 impl core::ops::Deref for ISpatialAudioObjectRenderStream {
@@ -7571,6 +10430,29 @@ impl ISpatialAudioObjectRenderStream_Vtbl {
         iid == &<ISpatialAudioObjectRenderStream as windows_core::Interface>::IID || iid == &<ISpatialAudioObjectRenderStreamBase as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectRenderStream_Impl> windows_core::imp::TearOffVtable<Identity> for ISpatialAudioObjectRenderStream_Vtbl
+where
+    ISpatialAudioObjectRenderStreamBase_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ActivateSpatialAudioObject<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectRenderStream_Impl>(this: *mut core::ffi::c_void, r#type: AudioObjectType, audioobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioObjectRenderStream_Impl::ActivateSpatialAudioObject(this, core::mem::transmute_copy(&r#type)) {
+                    Ok(ok__) => {
+                        audioobject.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ISpatialAudioObjectRenderStreamBase_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ActivateSpatialAudioObject: ActivateSpatialAudioObject::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpatialAudioObjectRenderStream {}
 windows_core::imp::define_interface!(ISpatialAudioObjectRenderStreamBase, ISpatialAudioObjectRenderStreamBase_Vtbl, 0xfeaaf403_c1d8_450d_aa05_e0ccee7502a8);
 windows_core::imp::interface_hierarchy!(ISpatialAudioObjectRenderStreamBase, windows_core::IUnknown);
@@ -7690,6 +10572,71 @@ impl ISpatialAudioObjectRenderStreamBase_Vtbl {
         iid == &<ISpatialAudioObjectRenderStreamBase as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectRenderStreamBase_Impl> windows_core::imp::TearOffVtable<Identity> for ISpatialAudioObjectRenderStreamBase_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAvailableDynamicObjectCount<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectRenderStreamBase_Impl>(this: *mut core::ffi::c_void, value: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioObjectRenderStreamBase_Impl::GetAvailableDynamicObjectCount(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetService<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectRenderStreamBase_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, service: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioObjectRenderStreamBase_Impl::GetService(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&service)).into()
+            }
+        }
+        unsafe extern "system" fn Start<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectRenderStreamBase_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioObjectRenderStreamBase_Impl::Start(this).into()
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectRenderStreamBase_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioObjectRenderStreamBase_Impl::Stop(this).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectRenderStreamBase_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioObjectRenderStreamBase_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn BeginUpdatingAudioObjects<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectRenderStreamBase_Impl>(this: *mut core::ffi::c_void, availabledynamicobjectcount: *mut u32, framecountperbuffer: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioObjectRenderStreamBase_Impl::BeginUpdatingAudioObjects(this, core::mem::transmute_copy(&availabledynamicobjectcount), core::mem::transmute_copy(&framecountperbuffer)).into()
+            }
+        }
+        unsafe extern "system" fn EndUpdatingAudioObjects<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectRenderStreamBase_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioObjectRenderStreamBase_Impl::EndUpdatingAudioObjects(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAvailableDynamicObjectCount: GetAvailableDynamicObjectCount::<Identity>,
+            GetService: GetService::<Identity>,
+            Start: Start::<Identity>,
+            Stop: Stop::<Identity>,
+            Reset: Reset::<Identity>,
+            BeginUpdatingAudioObjects: BeginUpdatingAudioObjects::<Identity>,
+            EndUpdatingAudioObjects: EndUpdatingAudioObjects::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpatialAudioObjectRenderStreamBase {}
 windows_core::imp::define_interface!(ISpatialAudioObjectRenderStreamForHrtf, ISpatialAudioObjectRenderStreamForHrtf_Vtbl, 0xe08deef9_5363_406e_9fdc_080ee247bbe0);
 impl core::ops::Deref for ISpatialAudioObjectRenderStreamForHrtf {
@@ -7739,6 +10686,29 @@ impl ISpatialAudioObjectRenderStreamForHrtf_Vtbl {
         iid == &<ISpatialAudioObjectRenderStreamForHrtf as windows_core::Interface>::IID || iid == &<ISpatialAudioObjectRenderStreamBase as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectRenderStreamForHrtf_Impl> windows_core::imp::TearOffVtable<Identity> for ISpatialAudioObjectRenderStreamForHrtf_Vtbl
+where
+    ISpatialAudioObjectRenderStreamBase_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ActivateSpatialAudioObjectForHrtf<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectRenderStreamForHrtf_Impl>(this: *mut core::ffi::c_void, r#type: AudioObjectType, audioobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioObjectRenderStreamForHrtf_Impl::ActivateSpatialAudioObjectForHrtf(this, core::mem::transmute_copy(&r#type)) {
+                    Ok(ok__) => {
+                        audioobject.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ISpatialAudioObjectRenderStreamBase_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ActivateSpatialAudioObjectForHrtf: ActivateSpatialAudioObjectForHrtf::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpatialAudioObjectRenderStreamForHrtf {}
 windows_core::imp::define_interface!(ISpatialAudioObjectRenderStreamForMetadata, ISpatialAudioObjectRenderStreamForMetadata_Vtbl, 0xbbc9c907_48d5_4a2e_a0c7_f7f0d67c1fb1);
 impl core::ops::Deref for ISpatialAudioObjectRenderStreamForMetadata {
@@ -7809,6 +10779,42 @@ impl ISpatialAudioObjectRenderStreamForMetadata_Vtbl {
         iid == &<ISpatialAudioObjectRenderStreamForMetadata as windows_core::Interface>::IID || iid == &<ISpatialAudioObjectRenderStreamBase as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectRenderStreamForMetadata_Impl> windows_core::imp::TearOffVtable<Identity> for ISpatialAudioObjectRenderStreamForMetadata_Vtbl
+where
+    ISpatialAudioObjectRenderStreamBase_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ActivateSpatialAudioObjectForMetadataCommands<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectRenderStreamForMetadata_Impl>(this: *mut core::ffi::c_void, r#type: AudioObjectType, audioobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioObjectRenderStreamForMetadata_Impl::ActivateSpatialAudioObjectForMetadataCommands(this, core::mem::transmute_copy(&r#type)) {
+                    Ok(ok__) => {
+                        audioobject.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ActivateSpatialAudioObjectForMetadataItems<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectRenderStreamForMetadata_Impl>(this: *mut core::ffi::c_void, r#type: AudioObjectType, audioobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpatialAudioObjectRenderStreamForMetadata_Impl::ActivateSpatialAudioObjectForMetadataItems(this, core::mem::transmute_copy(&r#type)) {
+                    Ok(ok__) => {
+                        audioobject.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ISpatialAudioObjectRenderStreamBase_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ActivateSpatialAudioObjectForMetadataCommands: ActivateSpatialAudioObjectForMetadataCommands::<Identity>,
+            ActivateSpatialAudioObjectForMetadataItems: ActivateSpatialAudioObjectForMetadataItems::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpatialAudioObjectRenderStreamForMetadata {}
 windows_core::imp::define_interface!(ISpatialAudioObjectRenderStreamNotify, ISpatialAudioObjectRenderStreamNotify_Vtbl, 0xdddf83e6_68d7_4c70_883f_a1836afb4a50);
 windows_core::imp::interface_hierarchy!(ISpatialAudioObjectRenderStreamNotify, windows_core::IUnknown);
@@ -7846,6 +10852,23 @@ impl ISpatialAudioObjectRenderStreamNotify_Vtbl {
         iid == &<ISpatialAudioObjectRenderStreamNotify as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectRenderStreamNotify_Impl> windows_core::imp::TearOffVtable<Identity> for ISpatialAudioObjectRenderStreamNotify_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnAvailableDynamicObjectCountChange<Identity: windows_core::IUnknownImpl + ISpatialAudioObjectRenderStreamNotify_Impl>(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void, hnscompliancedeadlinetime: i64, availabledynamicobjectcountchange: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpatialAudioObjectRenderStreamNotify_Impl::OnAvailableDynamicObjectCountChange(this, core::mem::transmute_copy(&sender), core::mem::transmute_copy(&hnscompliancedeadlinetime), core::mem::transmute_copy(&availabledynamicobjectcountchange)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnAvailableDynamicObjectCountChange: OnAvailableDynamicObjectCountChange::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpatialAudioObjectRenderStreamNotify {}
 windows_core::imp::define_interface!(ISubunit, ISubunit_Vtbl, 0x82149a85_dba6_4487_86bb_ea8f7fefcc71);
 windows_core::imp::interface_hierarchy!(ISubunit, windows_core::IUnknown);
@@ -7863,6 +10886,12 @@ impl ISubunit_Vtbl {
         iid == &<ISubunit as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISubunit_Impl> windows_core::imp::TearOffVtable<Identity> for ISubunit_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for ISubunit {}
 pub const In: DataFlow = DataFlow(0i32);
 pub type LPACMDRIVERPROC = Option<unsafe extern "system" fn(param0: usize, param1: HACMDRIVERID, param2: u32, param3: super::super::Foundation::LPARAM, param4: super::super::Foundation::LPARAM) -> super::super::Foundation::LRESULT>;
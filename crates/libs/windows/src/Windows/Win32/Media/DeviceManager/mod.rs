@@ -49,6 +49,30 @@ impl IComponentAuthenticate_Vtbl {
         iid == &<IComponentAuthenticate as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IComponentAuthenticate_Impl> windows_core::imp::TearOffVtable<Identity> for IComponentAuthenticate_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SACAuth<Identity: windows_core::IUnknownImpl + IComponentAuthenticate_Impl>(this: *mut core::ffi::c_void, dwprotocolid: u32, dwpass: u32, pbdatain: *const u8, dwdatainlen: u32, ppbdataout: *mut *mut u8, pdwdataoutlen: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IComponentAuthenticate_Impl::SACAuth(this, core::mem::transmute_copy(&dwprotocolid), core::mem::transmute_copy(&dwpass), core::mem::transmute_copy(&pbdatain), core::mem::transmute_copy(&dwdatainlen), core::mem::transmute_copy(&ppbdataout), core::mem::transmute_copy(&pdwdataoutlen)).into()
+            }
+        }
+        unsafe extern "system" fn SACGetProtocols<Identity: windows_core::IUnknownImpl + IComponentAuthenticate_Impl>(this: *mut core::ffi::c_void, ppdwprotocols: *mut *mut u32, pdwprotocolcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IComponentAuthenticate_Impl::SACGetProtocols(this, core::mem::transmute_copy(&ppdwprotocols), core::mem::transmute_copy(&pdwprotocolcount)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SACAuth: SACAuth::<Identity>,
+            SACGetProtocols: SACGetProtocols::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IComponentAuthenticate {}
 windows_core::imp::define_interface!(IMDSPDevice, IMDSPDevice_Vtbl, 0x1dcb3a12_33ed_11d3_8470_00c04f79dbc0);
 windows_core::imp::interface_hierarchy!(IMDSPDevice, windows_core::IUnknown);
@@ -255,6 +279,124 @@ impl IMDSPDevice_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Media_Audio")]
+impl<Identity: windows_core::IUnknownImpl + IMDSPDevice_Impl> windows_core::imp::TearOffVtable<Identity> for IMDSPDevice_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IMDSPDevice_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, nmaxchars: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPDevice_Impl::GetName(this, core::mem::transmute_copy(&pwszname), core::mem::transmute_copy(&nmaxchars)).into()
+            }
+        }
+        unsafe extern "system" fn GetManufacturer<Identity: windows_core::IUnknownImpl + IMDSPDevice_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, nmaxchars: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPDevice_Impl::GetManufacturer(this, core::mem::transmute_copy(&pwszname), core::mem::transmute_copy(&nmaxchars)).into()
+            }
+        }
+        unsafe extern "system" fn GetVersion<Identity: windows_core::IUnknownImpl + IMDSPDevice_Impl>(this: *mut core::ffi::c_void, pdwversion: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPDevice_Impl::GetVersion(this) {
+                    Ok(ok__) => {
+                        pdwversion.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetType<Identity: windows_core::IUnknownImpl + IMDSPDevice_Impl>(this: *mut core::ffi::c_void, pdwtype: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPDevice_Impl::GetType(this) {
+                    Ok(ok__) => {
+                        pdwtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSerialNumber<Identity: windows_core::IUnknownImpl + IMDSPDevice_Impl>(this: *mut core::ffi::c_void, pserialnumber: *mut WMDMID, abmac: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPDevice_Impl::GetSerialNumber(this, core::mem::transmute_copy(&pserialnumber), core::mem::transmute_copy(&abmac)).into()
+            }
+        }
+        unsafe extern "system" fn GetPowerSource<Identity: windows_core::IUnknownImpl + IMDSPDevice_Impl>(this: *mut core::ffi::c_void, pdwpowersource: *mut u32, pdwpercentremaining: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPDevice_Impl::GetPowerSource(this, core::mem::transmute_copy(&pdwpowersource), core::mem::transmute_copy(&pdwpercentremaining)).into()
+            }
+        }
+        unsafe extern "system" fn GetStatus<Identity: windows_core::IUnknownImpl + IMDSPDevice_Impl>(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPDevice_Impl::GetStatus(this) {
+                    Ok(ok__) => {
+                        pdwstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDeviceIcon<Identity: windows_core::IUnknownImpl + IMDSPDevice_Impl>(this: *mut core::ffi::c_void, hicon: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPDevice_Impl::GetDeviceIcon(this) {
+                    Ok(ok__) => {
+                        hicon.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumStorage<Identity: windows_core::IUnknownImpl + IMDSPDevice_Impl>(this: *mut core::ffi::c_void, ppenumstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPDevice_Impl::EnumStorage(this) {
+                    Ok(ok__) => {
+                        ppenumstorage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFormatSupport<Identity: windows_core::IUnknownImpl + IMDSPDevice_Impl>(this: *mut core::ffi::c_void, pformatex: *mut *mut super::Audio::WAVEFORMATEX, pnformatcount: *mut u32, pppwszmimetype: *mut *mut windows_core::PWSTR, pnmimetypecount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPDevice_Impl::GetFormatSupport(this, core::mem::transmute_copy(&pformatex), core::mem::transmute_copy(&pnformatcount), core::mem::transmute_copy(&pppwszmimetype), core::mem::transmute_copy(&pnmimetypecount)).into()
+            }
+        }
+        unsafe extern "system" fn SendOpaqueCommand<Identity: windows_core::IUnknownImpl + IMDSPDevice_Impl>(this: *mut core::ffi::c_void, pcommand: *mut OPAQUECOMMAND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPDevice_Impl::SendOpaqueCommand(this, core::mem::transmute_copy(&pcommand)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetName: GetName::<Identity>,
+            GetManufacturer: GetManufacturer::<Identity>,
+            GetVersion: GetVersion::<Identity>,
+            GetType: GetType::<Identity>,
+            GetSerialNumber: GetSerialNumber::<Identity>,
+            GetPowerSource: GetPowerSource::<Identity>,
+            GetStatus: GetStatus::<Identity>,
+            GetDeviceIcon: GetDeviceIcon::<Identity>,
+            EnumStorage: EnumStorage::<Identity>,
+            GetFormatSupport: GetFormatSupport::<Identity>,
+            SendOpaqueCommand: SendOpaqueCommand::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Media_Audio")]
 impl windows_core::RuntimeName for IMDSPDevice {}
 windows_core::imp::define_interface!(IMDSPDevice2, IMDSPDevice2_Vtbl, 0x420d16ad_c97d_4e00_82aa_00e9f4335ddd);
 impl core::ops::Deref for IMDSPDevice2 {
@@ -354,6 +496,51 @@ impl IMDSPDevice2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Ole"))]
+impl<Identity: windows_core::IUnknownImpl + IMDSPDevice2_Impl> windows_core::imp::TearOffVtable<Identity> for IMDSPDevice2_Vtbl
+where
+    IMDSPDevice_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStorage<Identity: windows_core::IUnknownImpl + IMDSPDevice2_Impl>(this: *mut core::ffi::c_void, pszstoragename: windows_core::PCWSTR, ppstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPDevice2_Impl::GetStorage(this, core::mem::transmute(&pszstoragename)) {
+                    Ok(ok__) => {
+                        ppstorage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFormatSupport2<Identity: windows_core::IUnknownImpl + IMDSPDevice2_Impl>(this: *mut core::ffi::c_void, dwflags: u32, ppaudioformatex: *mut *mut super::Audio::WAVEFORMATEX, pnaudioformatcount: *mut u32, ppvideoformatex: *mut *mut super::MediaFoundation::VIDEOINFOHEADER, pnvideoformatcount: *mut u32, ppfiletype: *mut *mut WMFILECAPABILITIES, pnfiletypecount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPDevice2_Impl::GetFormatSupport2(this, core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&ppaudioformatex), core::mem::transmute_copy(&pnaudioformatcount), core::mem::transmute_copy(&ppvideoformatex), core::mem::transmute_copy(&pnvideoformatcount), core::mem::transmute_copy(&ppfiletype), core::mem::transmute_copy(&pnfiletypecount)).into()
+            }
+        }
+        unsafe extern "system" fn GetSpecifyPropertyPages<Identity: windows_core::IUnknownImpl + IMDSPDevice2_Impl>(this: *mut core::ffi::c_void, ppspecifyproppages: *mut *mut core::ffi::c_void, pppunknowns: *mut *mut *mut core::ffi::c_void, pcunks: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPDevice2_Impl::GetSpecifyPropertyPages(this, core::mem::transmute_copy(&ppspecifyproppages), core::mem::transmute_copy(&pppunknowns), core::mem::transmute_copy(&pcunks)).into()
+            }
+        }
+        unsafe extern "system" fn GetCanonicalName<Identity: windows_core::IUnknownImpl + IMDSPDevice2_Impl>(this: *mut core::ffi::c_void, pwszpnpname: windows_core::PWSTR, nmaxchars: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPDevice2_Impl::GetCanonicalName(this, core::mem::transmute_copy(&pwszpnpname), core::mem::transmute_copy(&nmaxchars)).into()
+            }
+        }
+        Self {
+            base__: <IMDSPDevice_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStorage: GetStorage::<Identity>,
+            GetFormatSupport2: GetFormatSupport2::<Identity>,
+            GetSpecifyPropertyPages: GetSpecifyPropertyPages::<Identity>,
+            GetCanonicalName: GetCanonicalName::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Ole"))]
 impl windows_core::RuntimeName for IMDSPDevice2 {}
 windows_core::imp::define_interface!(IMDSPDevice3, IMDSPDevice3_Vtbl, 0x1a839845_fc55_487c_976f_ee38ac0e8c4e);
 impl core::ops::Deref for IMDSPDevice3 {
@@ -493,6 +680,70 @@ impl IMDSPDevice3_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IMDSPDevice3_Impl> windows_core::imp::TearOffVtable<Identity> for IMDSPDevice3_Vtbl
+where
+    IMDSPDevice2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperty<Identity: windows_core::IUnknownImpl + IMDSPDevice3_Impl>(this: *mut core::ffi::c_void, pwszpropname: windows_core::PCWSTR, pvalue: *mut super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPDevice3_Impl::GetProperty(this, core::mem::transmute(&pwszpropname)) {
+                    Ok(ok__) => {
+                        pvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProperty<Identity: windows_core::IUnknownImpl + IMDSPDevice3_Impl>(this: *mut core::ffi::c_void, pwszpropname: windows_core::PCWSTR, pvalue: *const super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPDevice3_Impl::SetProperty(this, core::mem::transmute(&pwszpropname), core::mem::transmute_copy(&pvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetFormatCapability<Identity: windows_core::IUnknownImpl + IMDSPDevice3_Impl>(this: *mut core::ffi::c_void, format: WMDM_FORMATCODE, pformatsupport: *mut WMDM_FORMAT_CAPABILITY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPDevice3_Impl::GetFormatCapability(this, core::mem::transmute_copy(&format)) {
+                    Ok(ok__) => {
+                        pformatsupport.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeviceIoControl<Identity: windows_core::IUnknownImpl + IMDSPDevice3_Impl>(this: *mut core::ffi::c_void, dwiocontrolcode: u32, lpinbuffer: *const u8, ninbuffersize: u32, lpoutbuffer: *mut u8, pnoutbuffersize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPDevice3_Impl::DeviceIoControl(this, core::mem::transmute_copy(&dwiocontrolcode), core::mem::transmute_copy(&lpinbuffer), core::mem::transmute_copy(&ninbuffersize), core::mem::transmute_copy(&lpoutbuffer), core::mem::transmute_copy(&pnoutbuffersize)).into()
+            }
+        }
+        unsafe extern "system" fn FindStorage<Identity: windows_core::IUnknownImpl + IMDSPDevice3_Impl>(this: *mut core::ffi::c_void, findscope: WMDM_FIND_SCOPE, pwszuniqueid: windows_core::PCWSTR, ppstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPDevice3_Impl::FindStorage(this, core::mem::transmute_copy(&findscope), core::mem::transmute(&pwszuniqueid)) {
+                    Ok(ok__) => {
+                        ppstorage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IMDSPDevice2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProperty: GetProperty::<Identity>,
+            SetProperty: SetProperty::<Identity>,
+            GetFormatCapability: GetFormatCapability::<Identity>,
+            DeviceIoControl: DeviceIoControl::<Identity>,
+            FindStorage: FindStorage::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IMDSPDevice3 {}
 windows_core::imp::define_interface!(IMDSPDeviceControl, IMDSPDeviceControl_Vtbl, 0x1dcb3a14_33ed_11d3_8470_00c04f79dbc0);
 windows_core::imp::interface_hierarchy!(IMDSPDeviceControl, windows_core::IUnknown);
@@ -636,6 +887,85 @@ impl IMDSPDeviceControl_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Media_Audio")]
+impl<Identity: windows_core::IUnknownImpl + IMDSPDeviceControl_Impl> windows_core::imp::TearOffVtable<Identity> for IMDSPDeviceControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDCStatus<Identity: windows_core::IUnknownImpl + IMDSPDeviceControl_Impl>(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPDeviceControl_Impl::GetDCStatus(this) {
+                    Ok(ok__) => {
+                        pdwstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCapabilities<Identity: windows_core::IUnknownImpl + IMDSPDeviceControl_Impl>(this: *mut core::ffi::c_void, pdwcapabilitiesmask: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPDeviceControl_Impl::GetCapabilities(this) {
+                    Ok(ok__) => {
+                        pdwcapabilitiesmask.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Play<Identity: windows_core::IUnknownImpl + IMDSPDeviceControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPDeviceControl_Impl::Play(this).into()
+            }
+        }
+        unsafe extern "system" fn Record<Identity: windows_core::IUnknownImpl + IMDSPDeviceControl_Impl>(this: *mut core::ffi::c_void, pformat: *const super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPDeviceControl_Impl::Record(this, core::mem::transmute_copy(&pformat)).into()
+            }
+        }
+        unsafe extern "system" fn Pause<Identity: windows_core::IUnknownImpl + IMDSPDeviceControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPDeviceControl_Impl::Pause(this).into()
+            }
+        }
+        unsafe extern "system" fn Resume<Identity: windows_core::IUnknownImpl + IMDSPDeviceControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPDeviceControl_Impl::Resume(this).into()
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + IMDSPDeviceControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPDeviceControl_Impl::Stop(this).into()
+            }
+        }
+        unsafe extern "system" fn Seek<Identity: windows_core::IUnknownImpl + IMDSPDeviceControl_Impl>(this: *mut core::ffi::c_void, fumode: u32, noffset: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPDeviceControl_Impl::Seek(this, core::mem::transmute_copy(&fumode), core::mem::transmute_copy(&noffset)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDCStatus: GetDCStatus::<Identity>,
+            GetCapabilities: GetCapabilities::<Identity>,
+            Play: Play::<Identity>,
+            Record: Record::<Identity>,
+            Pause: Pause::<Identity>,
+            Resume: Resume::<Identity>,
+            Stop: Stop::<Identity>,
+            Seek: Seek::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Media_Audio")]
 impl windows_core::RuntimeName for IMDSPDeviceControl {}
 windows_core::imp::define_interface!(IMDSPDirectTransfer, IMDSPDirectTransfer_Vtbl, 0xc2fe57a8_9304_478c_9ee4_47e397b912d7);
 windows_core::imp::interface_hierarchy!(IMDSPDirectTransfer, windows_core::IUnknown);
@@ -683,6 +1013,26 @@ impl IMDSPDirectTransfer_Vtbl {
         iid == &<IMDSPDirectTransfer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMDSPDirectTransfer_Impl> windows_core::imp::TearOffVtable<Identity> for IMDSPDirectTransfer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn TransferToDevice<Identity: windows_core::IUnknownImpl + IMDSPDirectTransfer_Impl>(this: *mut core::ffi::c_void, pwszsourcefilepath: windows_core::PCWSTR, psourceoperation: *mut core::ffi::c_void, fuflags: u32, pwszdestinationname: windows_core::PCWSTR, psourcemetadata: *mut core::ffi::c_void, ptransferprogress: *mut core::ffi::c_void, ppnewobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPDirectTransfer_Impl::TransferToDevice(this, core::mem::transmute(&pwszsourcefilepath), core::mem::transmute_copy(&psourceoperation), core::mem::transmute_copy(&fuflags), core::mem::transmute(&pwszdestinationname), core::mem::transmute_copy(&psourcemetadata), core::mem::transmute_copy(&ptransferprogress)) {
+                    Ok(ok__) => {
+                        ppnewobject.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, TransferToDevice: TransferToDevice::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IMDSPDirectTransfer {}
 windows_core::imp::define_interface!(IMDSPEnumDevice, IMDSPEnumDevice_Vtbl, 0x1dcb3a11_33ed_11d3_8470_00c04f79dbc0);
 windows_core::imp::interface_hierarchy!(IMDSPEnumDevice, windows_core::IUnknown);
@@ -771,6 +1121,56 @@ impl IMDSPEnumDevice_Vtbl {
         iid == &<IMDSPEnumDevice as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMDSPEnumDevice_Impl> windows_core::imp::TearOffVtable<Identity> for IMDSPEnumDevice_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IMDSPEnumDevice_Impl>(this: *mut core::ffi::c_void, celt: u32, ppdevice: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPEnumDevice_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&ppdevice), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IMDSPEnumDevice_Impl>(this: *mut core::ffi::c_void, celt: u32, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPEnumDevice_Impl::Skip(this, core::mem::transmute_copy(&celt)) {
+                    Ok(ok__) => {
+                        pceltfetched.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IMDSPEnumDevice_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPEnumDevice_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IMDSPEnumDevice_Impl>(this: *mut core::ffi::c_void, ppenumdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPEnumDevice_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenumdevice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMDSPEnumDevice {}
 windows_core::imp::define_interface!(IMDSPEnumStorage, IMDSPEnumStorage_Vtbl, 0x1dcb3a15_33ed_11d3_8470_00c04f79dbc0);
 windows_core::imp::interface_hierarchy!(IMDSPEnumStorage, windows_core::IUnknown);
@@ -859,22 +1259,72 @@ impl IMDSPEnumStorage_Vtbl {
         iid == &<IMDSPEnumStorage as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IMDSPEnumStorage {}
-windows_core::imp::define_interface!(IMDSPObject, IMDSPObject_Vtbl, 0x1dcb3a18_33ed_11d3_8470_00c04f79dbc0);
-windows_core::imp::interface_hierarchy!(IMDSPObject, windows_core::IUnknown);
-impl IMDSPObject {
-    pub unsafe fn Open(&self, fumode: u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Open)(windows_core::Interface::as_raw(self), fumode).ok() }
-    }
-    pub unsafe fn Read(&self, pdata: *mut u8, pdwsize: *mut u32, abmac: &mut [u8; 8]) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Read)(windows_core::Interface::as_raw(self), pdata as _, pdwsize as _, core::mem::transmute(abmac.as_ptr())).ok() }
-    }
-    pub unsafe fn Write(&self, pdata: *const u8, pdwsize: *mut u32, abmac: &mut [u8; 8]) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Write)(windows_core::Interface::as_raw(self), pdata, pdwsize as _, core::mem::transmute(abmac.as_ptr())).ok() }
-    }
-    pub unsafe fn Delete<P1>(&self, fumode: u32, pprogress: P1) -> windows_core::Result<()>
-    where
-        P1: windows_core::Param<IWMDMProgress>,
+impl<Identity: windows_core::IUnknownImpl + IMDSPEnumStorage_Impl> windows_core::imp::TearOffVtable<Identity> for IMDSPEnumStorage_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IMDSPEnumStorage_Impl>(this: *mut core::ffi::c_void, celt: u32, ppstorage: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPEnumStorage_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&ppstorage), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IMDSPEnumStorage_Impl>(this: *mut core::ffi::c_void, celt: u32, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPEnumStorage_Impl::Skip(this, core::mem::transmute_copy(&celt)) {
+                    Ok(ok__) => {
+                        pceltfetched.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IMDSPEnumStorage_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPEnumStorage_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IMDSPEnumStorage_Impl>(this: *mut core::ffi::c_void, ppenumstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPEnumStorage_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenumstorage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IMDSPEnumStorage {}
+windows_core::imp::define_interface!(IMDSPObject, IMDSPObject_Vtbl, 0x1dcb3a18_33ed_11d3_8470_00c04f79dbc0);
+windows_core::imp::interface_hierarchy!(IMDSPObject, windows_core::IUnknown);
+impl IMDSPObject {
+    pub unsafe fn Open(&self, fumode: u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Open)(windows_core::Interface::as_raw(self), fumode).ok() }
+    }
+    pub unsafe fn Read(&self, pdata: *mut u8, pdwsize: *mut u32, abmac: &mut [u8; 8]) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Read)(windows_core::Interface::as_raw(self), pdata as _, pdwsize as _, core::mem::transmute(abmac.as_ptr())).ok() }
+    }
+    pub unsafe fn Write(&self, pdata: *const u8, pdwsize: *mut u32, abmac: &mut [u8; 8]) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Write)(windows_core::Interface::as_raw(self), pdata, pdwsize as _, core::mem::transmute(abmac.as_ptr())).ok() }
+    }
+    pub unsafe fn Delete<P1>(&self, fumode: u32, pprogress: P1) -> windows_core::Result<()>
+    where
+        P1: windows_core::Param<IWMDMProgress>,
     {
         unsafe { (windows_core::Interface::vtable(self).Delete)(windows_core::Interface::as_raw(self), fumode, pprogress.param().abi()).ok() }
     }
@@ -988,6 +1438,72 @@ impl IMDSPObject_Vtbl {
         iid == &<IMDSPObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMDSPObject_Impl> windows_core::imp::TearOffVtable<Identity> for IMDSPObject_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Open<Identity: windows_core::IUnknownImpl + IMDSPObject_Impl>(this: *mut core::ffi::c_void, fumode: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPObject_Impl::Open(this, core::mem::transmute_copy(&fumode)).into()
+            }
+        }
+        unsafe extern "system" fn Read<Identity: windows_core::IUnknownImpl + IMDSPObject_Impl>(this: *mut core::ffi::c_void, pdata: *mut u8, pdwsize: *mut u32, abmac: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPObject_Impl::Read(this, core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&pdwsize), core::mem::transmute_copy(&abmac)).into()
+            }
+        }
+        unsafe extern "system" fn Write<Identity: windows_core::IUnknownImpl + IMDSPObject_Impl>(this: *mut core::ffi::c_void, pdata: *const u8, pdwsize: *mut u32, abmac: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPObject_Impl::Write(this, core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&pdwsize), core::mem::transmute_copy(&abmac)).into()
+            }
+        }
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IMDSPObject_Impl>(this: *mut core::ffi::c_void, fumode: u32, pprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPObject_Impl::Delete(this, core::mem::transmute_copy(&fumode), core::mem::transmute_copy(&pprogress)).into()
+            }
+        }
+        unsafe extern "system" fn Seek<Identity: windows_core::IUnknownImpl + IMDSPObject_Impl>(this: *mut core::ffi::c_void, fuflags: u32, dwoffset: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPObject_Impl::Seek(this, core::mem::transmute_copy(&fuflags), core::mem::transmute_copy(&dwoffset)).into()
+            }
+        }
+        unsafe extern "system" fn Rename<Identity: windows_core::IUnknownImpl + IMDSPObject_Impl>(this: *mut core::ffi::c_void, pwsznewname: windows_core::PCWSTR, pprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPObject_Impl::Rename(this, core::mem::transmute(&pwsznewname), core::mem::transmute_copy(&pprogress)).into()
+            }
+        }
+        unsafe extern "system" fn Move<Identity: windows_core::IUnknownImpl + IMDSPObject_Impl>(this: *mut core::ffi::c_void, fumode: u32, pprogress: *mut core::ffi::c_void, ptarget: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPObject_Impl::Move(this, core::mem::transmute_copy(&fumode), core::mem::transmute_copy(&pprogress), core::mem::transmute_copy(&ptarget)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IMDSPObject_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPObject_Impl::Close(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Open: Open::<Identity>,
+            Read: Read::<Identity>,
+            Write: Write::<Identity>,
+            Delete: Delete::<Identity>,
+            Seek: Seek::<Identity>,
+            Rename: Rename::<Identity>,
+            Move: Move::<Identity>,
+            Close: Close::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMDSPObject {}
 windows_core::imp::define_interface!(IMDSPObject2, IMDSPObject2_Vtbl, 0x3f34cd3e_5907_4341_9af9_97f4187c3aa5);
 impl core::ops::Deref for IMDSPObject2 {
@@ -1040,6 +1556,30 @@ impl IMDSPObject2_Vtbl {
         iid == &<IMDSPObject2 as windows_core::Interface>::IID || iid == &<IMDSPObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMDSPObject2_Impl> windows_core::imp::TearOffVtable<Identity> for IMDSPObject2_Vtbl
+where
+    IMDSPObject_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ReadOnClearChannel<Identity: windows_core::IUnknownImpl + IMDSPObject2_Impl>(this: *mut core::ffi::c_void, pdata: *mut u8, pdwsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPObject2_Impl::ReadOnClearChannel(this, core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&pdwsize)).into()
+            }
+        }
+        unsafe extern "system" fn WriteOnClearChannel<Identity: windows_core::IUnknownImpl + IMDSPObject2_Impl>(this: *mut core::ffi::c_void, pdata: *const u8, pdwsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPObject2_Impl::WriteOnClearChannel(this, core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&pdwsize)).into()
+            }
+        }
+        Self {
+            base__: <IMDSPObject_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ReadOnClearChannel: ReadOnClearChannel::<Identity>,
+            WriteOnClearChannel: WriteOnClearChannel::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMDSPObject2 {}
 windows_core::imp::define_interface!(IMDSPObjectInfo, IMDSPObjectInfo_Vtbl, 0x1dcb3a19_33ed_11d3_8470_00c04f79dbc0);
 windows_core::imp::interface_hierarchy!(IMDSPObjectInfo, windows_core::IUnknown);
@@ -1191,6 +1731,95 @@ impl IMDSPObjectInfo_Vtbl {
         iid == &<IMDSPObjectInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMDSPObjectInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IMDSPObjectInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPlayLength<Identity: windows_core::IUnknownImpl + IMDSPObjectInfo_Impl>(this: *mut core::ffi::c_void, pdwlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPObjectInfo_Impl::GetPlayLength(this) {
+                    Ok(ok__) => {
+                        pdwlength.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPlayLength<Identity: windows_core::IUnknownImpl + IMDSPObjectInfo_Impl>(this: *mut core::ffi::c_void, dwlength: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPObjectInfo_Impl::SetPlayLength(this, core::mem::transmute_copy(&dwlength)).into()
+            }
+        }
+        unsafe extern "system" fn GetPlayOffset<Identity: windows_core::IUnknownImpl + IMDSPObjectInfo_Impl>(this: *mut core::ffi::c_void, pdwoffset: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPObjectInfo_Impl::GetPlayOffset(this) {
+                    Ok(ok__) => {
+                        pdwoffset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPlayOffset<Identity: windows_core::IUnknownImpl + IMDSPObjectInfo_Impl>(this: *mut core::ffi::c_void, dwoffset: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPObjectInfo_Impl::SetPlayOffset(this, core::mem::transmute_copy(&dwoffset)).into()
+            }
+        }
+        unsafe extern "system" fn GetTotalLength<Identity: windows_core::IUnknownImpl + IMDSPObjectInfo_Impl>(this: *mut core::ffi::c_void, pdwlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPObjectInfo_Impl::GetTotalLength(this) {
+                    Ok(ok__) => {
+                        pdwlength.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLastPlayPosition<Identity: windows_core::IUnknownImpl + IMDSPObjectInfo_Impl>(this: *mut core::ffi::c_void, pdwlastpos: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPObjectInfo_Impl::GetLastPlayPosition(this) {
+                    Ok(ok__) => {
+                        pdwlastpos.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLongestPlayPosition<Identity: windows_core::IUnknownImpl + IMDSPObjectInfo_Impl>(this: *mut core::ffi::c_void, pdwlongestpos: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPObjectInfo_Impl::GetLongestPlayPosition(this) {
+                    Ok(ok__) => {
+                        pdwlongestpos.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPlayLength: GetPlayLength::<Identity>,
+            SetPlayLength: SetPlayLength::<Identity>,
+            GetPlayOffset: GetPlayOffset::<Identity>,
+            SetPlayOffset: SetPlayOffset::<Identity>,
+            GetTotalLength: GetTotalLength::<Identity>,
+            GetLastPlayPosition: GetLastPlayPosition::<Identity>,
+            GetLongestPlayPosition: GetLongestPlayPosition::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMDSPObjectInfo {}
 windows_core::imp::define_interface!(IMDSPRevoked, IMDSPRevoked_Vtbl, 0xa4e8f2d4_3f31_464d_b53d_4fc335998184);
 windows_core::imp::interface_hierarchy!(IMDSPRevoked, windows_core::IUnknown);
@@ -1222,6 +1851,20 @@ impl IMDSPRevoked_Vtbl {
         iid == &<IMDSPRevoked as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMDSPRevoked_Impl> windows_core::imp::TearOffVtable<Identity> for IMDSPRevoked_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRevocationURL<Identity: windows_core::IUnknownImpl + IMDSPRevoked_Impl>(this: *mut core::ffi::c_void, ppwszrevocationurl: *mut windows_core::PWSTR, pdwbufferlen: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPRevoked_Impl::GetRevocationURL(this, core::mem::transmute_copy(&ppwszrevocationurl), core::mem::transmute_copy(&pdwbufferlen)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetRevocationURL: GetRevocationURL::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IMDSPRevoked {}
 windows_core::imp::define_interface!(IMDSPStorage, IMDSPStorage_Vtbl, 0x1dcb3a16_33ed_11d3_8470_00c04f79dbc0);
 windows_core::imp::interface_hierarchy!(IMDSPStorage, windows_core::IUnknown);
@@ -1418,6 +2061,111 @@ impl IMDSPStorage_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Media_Audio")]
+impl<Identity: windows_core::IUnknownImpl + IMDSPStorage_Impl> windows_core::imp::TearOffVtable<Identity> for IMDSPStorage_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAttributes<Identity: windows_core::IUnknownImpl + IMDSPStorage_Impl>(this: *mut core::ffi::c_void, dwattributes: u32, pformat: *const super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPStorage_Impl::SetAttributes(this, core::mem::transmute_copy(&dwattributes), core::mem::transmute_copy(&pformat)).into()
+            }
+        }
+        unsafe extern "system" fn GetStorageGlobals<Identity: windows_core::IUnknownImpl + IMDSPStorage_Impl>(this: *mut core::ffi::c_void, ppstorageglobals: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPStorage_Impl::GetStorageGlobals(this) {
+                    Ok(ok__) => {
+                        ppstorageglobals.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAttributes<Identity: windows_core::IUnknownImpl + IMDSPStorage_Impl>(this: *mut core::ffi::c_void, pdwattributes: *mut u32, pformat: *mut super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPStorage_Impl::GetAttributes(this, core::mem::transmute_copy(&pdwattributes), core::mem::transmute_copy(&pformat)).into()
+            }
+        }
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IMDSPStorage_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, nmaxchars: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPStorage_Impl::GetName(this, core::mem::transmute_copy(&pwszname), core::mem::transmute_copy(&nmaxchars)).into()
+            }
+        }
+        unsafe extern "system" fn GetDate<Identity: windows_core::IUnknownImpl + IMDSPStorage_Impl>(this: *mut core::ffi::c_void, pdatetimeutc: *mut WMDMDATETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPStorage_Impl::GetDate(this) {
+                    Ok(ok__) => {
+                        pdatetimeutc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSize<Identity: windows_core::IUnknownImpl + IMDSPStorage_Impl>(this: *mut core::ffi::c_void, pdwsizelow: *mut u32, pdwsizehigh: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPStorage_Impl::GetSize(this, core::mem::transmute_copy(&pdwsizelow), core::mem::transmute_copy(&pdwsizehigh)).into()
+            }
+        }
+        unsafe extern "system" fn GetRights<Identity: windows_core::IUnknownImpl + IMDSPStorage_Impl>(this: *mut core::ffi::c_void, pprights: *mut *mut WMDMRIGHTS, pnrightscount: *mut u32, abmac: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPStorage_Impl::GetRights(this, core::mem::transmute_copy(&pprights), core::mem::transmute_copy(&pnrightscount), core::mem::transmute_copy(&abmac)).into()
+            }
+        }
+        unsafe extern "system" fn CreateStorage<Identity: windows_core::IUnknownImpl + IMDSPStorage_Impl>(this: *mut core::ffi::c_void, dwattributes: u32, pformat: *const super::Audio::WAVEFORMATEX, pwszname: windows_core::PCWSTR, ppnewstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPStorage_Impl::CreateStorage(this, core::mem::transmute_copy(&dwattributes), core::mem::transmute_copy(&pformat), core::mem::transmute(&pwszname)) {
+                    Ok(ok__) => {
+                        ppnewstorage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumStorage<Identity: windows_core::IUnknownImpl + IMDSPStorage_Impl>(this: *mut core::ffi::c_void, ppenumstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPStorage_Impl::EnumStorage(this) {
+                    Ok(ok__) => {
+                        ppenumstorage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SendOpaqueCommand<Identity: windows_core::IUnknownImpl + IMDSPStorage_Impl>(this: *mut core::ffi::c_void, pcommand: *mut OPAQUECOMMAND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPStorage_Impl::SendOpaqueCommand(this, core::mem::transmute_copy(&pcommand)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAttributes: SetAttributes::<Identity>,
+            GetStorageGlobals: GetStorageGlobals::<Identity>,
+            GetAttributes: GetAttributes::<Identity>,
+            GetName: GetName::<Identity>,
+            GetDate: GetDate::<Identity>,
+            GetSize: GetSize::<Identity>,
+            GetRights: GetRights::<Identity>,
+            CreateStorage: CreateStorage::<Identity>,
+            EnumStorage: EnumStorage::<Identity>,
+            SendOpaqueCommand: SendOpaqueCommand::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Media_Audio")]
 impl windows_core::RuntimeName for IMDSPStorage {}
 windows_core::imp::define_interface!(IMDSPStorage2, IMDSPStorage2_Vtbl, 0x0a5e07a5_6454_4451_9c36_1c6ae7e2b1d6);
 impl core::ops::Deref for IMDSPStorage2 {
@@ -1533,6 +2281,57 @@ impl IMDSPStorage2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+impl<Identity: windows_core::IUnknownImpl + IMDSPStorage2_Impl> windows_core::imp::TearOffVtable<Identity> for IMDSPStorage2_Vtbl
+where
+    IMDSPStorage_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStorage<Identity: windows_core::IUnknownImpl + IMDSPStorage2_Impl>(this: *mut core::ffi::c_void, pszstoragename: windows_core::PCWSTR, ppstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPStorage2_Impl::GetStorage(this, core::mem::transmute(&pszstoragename)) {
+                    Ok(ok__) => {
+                        ppstorage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateStorage2<Identity: windows_core::IUnknownImpl + IMDSPStorage2_Impl>(this: *mut core::ffi::c_void, dwattributes: u32, dwattributesex: u32, paudioformat: *const super::Audio::WAVEFORMATEX, pvideoformat: *const super::MediaFoundation::VIDEOINFOHEADER, pwszname: windows_core::PCWSTR, qwfilesize: u64, ppnewstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPStorage2_Impl::CreateStorage2(this, core::mem::transmute_copy(&dwattributes), core::mem::transmute_copy(&dwattributesex), core::mem::transmute_copy(&paudioformat), core::mem::transmute_copy(&pvideoformat), core::mem::transmute(&pwszname), core::mem::transmute_copy(&qwfilesize)) {
+                    Ok(ok__) => {
+                        ppnewstorage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAttributes2<Identity: windows_core::IUnknownImpl + IMDSPStorage2_Impl>(this: *mut core::ffi::c_void, dwattributes: u32, dwattributesex: u32, paudioformat: *const super::Audio::WAVEFORMATEX, pvideoformat: *const super::MediaFoundation::VIDEOINFOHEADER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPStorage2_Impl::SetAttributes2(this, core::mem::transmute_copy(&dwattributes), core::mem::transmute_copy(&dwattributesex), core::mem::transmute_copy(&paudioformat), core::mem::transmute_copy(&pvideoformat)).into()
+            }
+        }
+        unsafe extern "system" fn GetAttributes2<Identity: windows_core::IUnknownImpl + IMDSPStorage2_Impl>(this: *mut core::ffi::c_void, pdwattributes: *mut u32, pdwattributesex: *mut u32, paudioformat: *mut super::Audio::WAVEFORMATEX, pvideoformat: *mut super::MediaFoundation::VIDEOINFOHEADER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPStorage2_Impl::GetAttributes2(this, core::mem::transmute_copy(&pdwattributes), core::mem::transmute_copy(&pdwattributesex), core::mem::transmute_copy(&paudioformat), core::mem::transmute_copy(&pvideoformat)).into()
+            }
+        }
+        Self {
+            base__: <IMDSPStorage_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStorage: GetStorage::<Identity>,
+            CreateStorage2: CreateStorage2::<Identity>,
+            SetAttributes2: SetAttributes2::<Identity>,
+            GetAttributes2: GetAttributes2::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
 impl windows_core::RuntimeName for IMDSPStorage2 {}
 windows_core::imp::define_interface!(IMDSPStorage3, IMDSPStorage3_Vtbl, 0x6c669867_97ed_4a67_9706_1c5529d2a414);
 impl core::ops::Deref for IMDSPStorage3 {
@@ -1594,7 +2393,32 @@ impl IMDSPStorage3_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
-impl windows_core::RuntimeName for IMDSPStorage3 {}
+impl<Identity: windows_core::IUnknownImpl + IMDSPStorage3_Impl> windows_core::imp::TearOffVtable<Identity> for IMDSPStorage3_Vtbl
+where
+    IMDSPStorage2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMetadata<Identity: windows_core::IUnknownImpl + IMDSPStorage3_Impl>(this: *mut core::ffi::c_void, pmetadata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPStorage3_Impl::GetMetadata(this, core::mem::transmute_copy(&pmetadata)).into()
+            }
+        }
+        unsafe extern "system" fn SetMetadata<Identity: windows_core::IUnknownImpl + IMDSPStorage3_Impl>(this: *mut core::ffi::c_void, pmetadata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPStorage3_Impl::SetMetadata(this, core::mem::transmute_copy(&pmetadata)).into()
+            }
+        }
+        Self {
+            base__: <IMDSPStorage2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetMetadata: GetMetadata::<Identity>,
+            SetMetadata: SetMetadata::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+impl windows_core::RuntimeName for IMDSPStorage3 {}
 windows_core::imp::define_interface!(IMDSPStorage4, IMDSPStorage4_Vtbl, 0x3133b2c4_515c_481b_b1ce_39327ecb4f74);
 impl core::ops::Deref for IMDSPStorage4 {
     type Target = IMDSPStorage3;
@@ -1734,6 +2558,77 @@ impl IMDSPStorage4_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+impl<Identity: windows_core::IUnknownImpl + IMDSPStorage4_Impl> windows_core::imp::TearOffVtable<Identity> for IMDSPStorage4_Vtbl
+where
+    IMDSPStorage3_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetReferences<Identity: windows_core::IUnknownImpl + IMDSPStorage4_Impl>(this: *mut core::ffi::c_void, dwrefs: u32, ppispstorage: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPStorage4_Impl::SetReferences(this, core::mem::transmute_copy(&dwrefs), core::mem::transmute_copy(&ppispstorage)).into()
+            }
+        }
+        unsafe extern "system" fn GetReferences<Identity: windows_core::IUnknownImpl + IMDSPStorage4_Impl>(this: *mut core::ffi::c_void, pdwrefs: *mut u32, pppispstorage: *mut *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPStorage4_Impl::GetReferences(this, core::mem::transmute_copy(&pdwrefs), core::mem::transmute_copy(&pppispstorage)).into()
+            }
+        }
+        unsafe extern "system" fn CreateStorageWithMetadata<Identity: windows_core::IUnknownImpl + IMDSPStorage4_Impl>(this: *mut core::ffi::c_void, dwattributes: u32, pwszname: windows_core::PCWSTR, pmetadata: *mut core::ffi::c_void, qwfilesize: u64, ppnewstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPStorage4_Impl::CreateStorageWithMetadata(this, core::mem::transmute_copy(&dwattributes), core::mem::transmute(&pwszname), core::mem::transmute_copy(&pmetadata), core::mem::transmute_copy(&qwfilesize)) {
+                    Ok(ok__) => {
+                        ppnewstorage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSpecifiedMetadata<Identity: windows_core::IUnknownImpl + IMDSPStorage4_Impl>(this: *mut core::ffi::c_void, cproperties: u32, ppwszpropnames: *const windows_core::PCWSTR, pmetadata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPStorage4_Impl::GetSpecifiedMetadata(this, core::mem::transmute_copy(&cproperties), core::mem::transmute_copy(&ppwszpropnames), core::mem::transmute_copy(&pmetadata)).into()
+            }
+        }
+        unsafe extern "system" fn FindStorage<Identity: windows_core::IUnknownImpl + IMDSPStorage4_Impl>(this: *mut core::ffi::c_void, findscope: WMDM_FIND_SCOPE, pwszuniqueid: windows_core::PCWSTR, ppstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPStorage4_Impl::FindStorage(this, core::mem::transmute_copy(&findscope), core::mem::transmute(&pwszuniqueid)) {
+                    Ok(ok__) => {
+                        ppstorage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetParent<Identity: windows_core::IUnknownImpl + IMDSPStorage4_Impl>(this: *mut core::ffi::c_void, ppstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPStorage4_Impl::GetParent(this) {
+                    Ok(ok__) => {
+                        ppstorage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IMDSPStorage3_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetReferences: SetReferences::<Identity>,
+            GetReferences: GetReferences::<Identity>,
+            CreateStorageWithMetadata: CreateStorageWithMetadata::<Identity>,
+            GetSpecifiedMetadata: GetSpecifiedMetadata::<Identity>,
+            FindStorage: FindStorage::<Identity>,
+            GetParent: GetParent::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
 impl windows_core::RuntimeName for IMDSPStorage4 {}
 windows_core::imp::define_interface!(IMDSPStorageGlobals, IMDSPStorageGlobals_Vtbl, 0x1dcb3a17_33ed_11d3_8470_00c04f79dbc0);
 windows_core::imp::interface_hierarchy!(IMDSPStorageGlobals, windows_core::IUnknown);
@@ -1903,6 +2798,103 @@ impl IMDSPStorageGlobals_Vtbl {
         iid == &<IMDSPStorageGlobals as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMDSPStorageGlobals_Impl> windows_core::imp::TearOffVtable<Identity> for IMDSPStorageGlobals_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCapabilities<Identity: windows_core::IUnknownImpl + IMDSPStorageGlobals_Impl>(this: *mut core::ffi::c_void, pdwcapabilities: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPStorageGlobals_Impl::GetCapabilities(this) {
+                    Ok(ok__) => {
+                        pdwcapabilities.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSerialNumber<Identity: windows_core::IUnknownImpl + IMDSPStorageGlobals_Impl>(this: *mut core::ffi::c_void, pserialnum: *mut WMDMID, abmac: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPStorageGlobals_Impl::GetSerialNumber(this, core::mem::transmute_copy(&pserialnum), core::mem::transmute_copy(&abmac)).into()
+            }
+        }
+        unsafe extern "system" fn GetTotalSize<Identity: windows_core::IUnknownImpl + IMDSPStorageGlobals_Impl>(this: *mut core::ffi::c_void, pdwtotalsizelow: *mut u32, pdwtotalsizehigh: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPStorageGlobals_Impl::GetTotalSize(this, core::mem::transmute_copy(&pdwtotalsizelow), core::mem::transmute_copy(&pdwtotalsizehigh)).into()
+            }
+        }
+        unsafe extern "system" fn GetTotalFree<Identity: windows_core::IUnknownImpl + IMDSPStorageGlobals_Impl>(this: *mut core::ffi::c_void, pdwfreelow: *mut u32, pdwfreehigh: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPStorageGlobals_Impl::GetTotalFree(this, core::mem::transmute_copy(&pdwfreelow), core::mem::transmute_copy(&pdwfreehigh)).into()
+            }
+        }
+        unsafe extern "system" fn GetTotalBad<Identity: windows_core::IUnknownImpl + IMDSPStorageGlobals_Impl>(this: *mut core::ffi::c_void, pdwbadlow: *mut u32, pdwbadhigh: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPStorageGlobals_Impl::GetTotalBad(this, core::mem::transmute_copy(&pdwbadlow), core::mem::transmute_copy(&pdwbadhigh)).into()
+            }
+        }
+        unsafe extern "system" fn GetStatus<Identity: windows_core::IUnknownImpl + IMDSPStorageGlobals_Impl>(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPStorageGlobals_Impl::GetStatus(this) {
+                    Ok(ok__) => {
+                        pdwstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IMDSPStorageGlobals_Impl>(this: *mut core::ffi::c_void, fumode: u32, pprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDSPStorageGlobals_Impl::Initialize(this, core::mem::transmute_copy(&fumode), core::mem::transmute_copy(&pprogress)).into()
+            }
+        }
+        unsafe extern "system" fn GetDevice<Identity: windows_core::IUnknownImpl + IMDSPStorageGlobals_Impl>(this: *mut core::ffi::c_void, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPStorageGlobals_Impl::GetDevice(this) {
+                    Ok(ok__) => {
+                        ppdevice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRootStorage<Identity: windows_core::IUnknownImpl + IMDSPStorageGlobals_Impl>(this: *mut core::ffi::c_void, pproot: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDSPStorageGlobals_Impl::GetRootStorage(this) {
+                    Ok(ok__) => {
+                        pproot.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCapabilities: GetCapabilities::<Identity>,
+            GetSerialNumber: GetSerialNumber::<Identity>,
+            GetTotalSize: GetTotalSize::<Identity>,
+            GetTotalFree: GetTotalFree::<Identity>,
+            GetTotalBad: GetTotalBad::<Identity>,
+            GetStatus: GetStatus::<Identity>,
+            Initialize: Initialize::<Identity>,
+            GetDevice: GetDevice::<Identity>,
+            GetRootStorage: GetRootStorage::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMDSPStorageGlobals {}
 windows_core::imp::define_interface!(IMDServiceProvider, IMDServiceProvider_Vtbl, 0x1dcb3a10_33ed_11d3_8470_00c04f79dbc0);
 windows_core::imp::interface_hierarchy!(IMDServiceProvider, windows_core::IUnknown);
@@ -1967,6 +2959,42 @@ impl IMDServiceProvider_Vtbl {
         iid == &<IMDServiceProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMDServiceProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IMDServiceProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDeviceCount<Identity: windows_core::IUnknownImpl + IMDServiceProvider_Impl>(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDServiceProvider_Impl::GetDeviceCount(this) {
+                    Ok(ok__) => {
+                        pdwcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumDevices<Identity: windows_core::IUnknownImpl + IMDServiceProvider_Impl>(this: *mut core::ffi::c_void, ppenumdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMDServiceProvider_Impl::EnumDevices(this) {
+                    Ok(ok__) => {
+                        ppenumdevice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDeviceCount: GetDeviceCount::<Identity>,
+            EnumDevices: EnumDevices::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMDServiceProvider {}
 windows_core::imp::define_interface!(IMDServiceProvider2, IMDServiceProvider2_Vtbl, 0xb2fa24b7_cda3_4694_9862_413ae1a34819);
 impl core::ops::Deref for IMDServiceProvider2 {
@@ -2007,6 +3035,20 @@ impl IMDServiceProvider2_Vtbl {
         iid == &<IMDServiceProvider2 as windows_core::Interface>::IID || iid == &<IMDServiceProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMDServiceProvider2_Impl> windows_core::imp::TearOffVtable<Identity> for IMDServiceProvider2_Vtbl
+where
+    IMDServiceProvider_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateDevice<Identity: windows_core::IUnknownImpl + IMDServiceProvider2_Impl>(this: *mut core::ffi::c_void, pwszdevicepath: windows_core::PCWSTR, pdwcount: *mut u32, pppdevicearray: *mut *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDServiceProvider2_Impl::CreateDevice(this, core::mem::transmute(&pwszdevicepath), core::mem::transmute_copy(&pdwcount), core::mem::transmute_copy(&pppdevicearray)).into()
+            }
+        }
+        Self { base__: <IMDServiceProvider_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateDevice: CreateDevice::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IMDServiceProvider2 {}
 windows_core::imp::define_interface!(IMDServiceProvider3, IMDServiceProvider3_Vtbl, 0x4ed13ef3_a971_4d19_9f51_0e1826b2da57);
 impl core::ops::Deref for IMDServiceProvider3 {
@@ -2044,6 +3086,23 @@ impl IMDServiceProvider3_Vtbl {
         iid == &<IMDServiceProvider3 as windows_core::Interface>::IID || iid == &<IMDServiceProvider as windows_core::Interface>::IID || iid == &<IMDServiceProvider2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMDServiceProvider3_Impl> windows_core::imp::TearOffVtable<Identity> for IMDServiceProvider3_Vtbl
+where
+    IMDServiceProvider2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetDeviceEnumPreference<Identity: windows_core::IUnknownImpl + IMDServiceProvider3_Impl>(this: *mut core::ffi::c_void, dwenumpref: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMDServiceProvider3_Impl::SetDeviceEnumPreference(this, core::mem::transmute_copy(&dwenumpref)).into()
+            }
+        }
+        Self {
+            base__: <IMDServiceProvider2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetDeviceEnumPreference: SetDeviceEnumPreference::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMDServiceProvider3 {}
 pub const IOCTL_MTP_CUSTOM_COMMAND: u32 = 827348045u32;
 windows_core::imp::define_interface!(ISCPSecureAuthenticate, ISCPSecureAuthenticate_Vtbl, 0x1dcb3a0f_33ed_11d3_8470_00c04f79dbc0);
@@ -2085,6 +3144,26 @@ impl ISCPSecureAuthenticate_Vtbl {
         iid == &<ISCPSecureAuthenticate as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISCPSecureAuthenticate_Impl> windows_core::imp::TearOffVtable<Identity> for ISCPSecureAuthenticate_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSecureQuery<Identity: windows_core::IUnknownImpl + ISCPSecureAuthenticate_Impl>(this: *mut core::ffi::c_void, ppsecurequery: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISCPSecureAuthenticate_Impl::GetSecureQuery(this) {
+                    Ok(ok__) => {
+                        ppsecurequery.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetSecureQuery: GetSecureQuery::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ISCPSecureAuthenticate {}
 windows_core::imp::define_interface!(ISCPSecureAuthenticate2, ISCPSecureAuthenticate2_Vtbl, 0xb580cfae_1672_47e2_acaa_44bbecbcae5b);
 impl core::ops::Deref for ISCPSecureAuthenticate2 {
@@ -2131,6 +3210,26 @@ impl ISCPSecureAuthenticate2_Vtbl {
         iid == &<ISCPSecureAuthenticate2 as windows_core::Interface>::IID || iid == &<ISCPSecureAuthenticate as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISCPSecureAuthenticate2_Impl> windows_core::imp::TearOffVtable<Identity> for ISCPSecureAuthenticate2_Vtbl
+where
+    ISCPSecureAuthenticate_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSCPSession<Identity: windows_core::IUnknownImpl + ISCPSecureAuthenticate2_Impl>(this: *mut core::ffi::c_void, ppscpsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISCPSecureAuthenticate2_Impl::GetSCPSession(this) {
+                    Ok(ok__) => {
+                        ppscpsession.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <ISCPSecureAuthenticate_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetSCPSession: GetSCPSession::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ISCPSecureAuthenticate2 {}
 windows_core::imp::define_interface!(ISCPSecureExchange, ISCPSecureExchange_Vtbl, 0x1dcb3a0e_33ed_11d3_8470_00c04f79dbc0);
 windows_core::imp::interface_hierarchy!(ISCPSecureExchange, windows_core::IUnknown);
@@ -2189,6 +3288,37 @@ impl ISCPSecureExchange_Vtbl {
         iid == &<ISCPSecureExchange as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISCPSecureExchange_Impl> windows_core::imp::TearOffVtable<Identity> for ISCPSecureExchange_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn TransferContainerData<Identity: windows_core::IUnknownImpl + ISCPSecureExchange_Impl>(this: *mut core::ffi::c_void, pdata: *const u8, dwsize: u32, pfureadyflags: *mut u32, abmac: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISCPSecureExchange_Impl::TransferContainerData(this, core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&dwsize), core::mem::transmute_copy(&pfureadyflags), core::mem::transmute_copy(&abmac)).into()
+            }
+        }
+        unsafe extern "system" fn ObjectData<Identity: windows_core::IUnknownImpl + ISCPSecureExchange_Impl>(this: *mut core::ffi::c_void, pdata: *mut u8, pdwsize: *mut u32, abmac: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISCPSecureExchange_Impl::ObjectData(this, core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&pdwsize), core::mem::transmute_copy(&abmac)).into()
+            }
+        }
+        unsafe extern "system" fn TransferComplete<Identity: windows_core::IUnknownImpl + ISCPSecureExchange_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISCPSecureExchange_Impl::TransferComplete(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            TransferContainerData: TransferContainerData::<Identity>,
+            ObjectData: ObjectData::<Identity>,
+            TransferComplete: TransferComplete::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISCPSecureExchange {}
 windows_core::imp::define_interface!(ISCPSecureExchange2, ISCPSecureExchange2_Vtbl, 0x6c62fc7b_2690_483f_9d44_0a20cb35577c);
 impl core::ops::Deref for ISCPSecureExchange2 {
@@ -2229,6 +3359,23 @@ impl ISCPSecureExchange2_Vtbl {
         iid == &<ISCPSecureExchange2 as windows_core::Interface>::IID || iid == &<ISCPSecureExchange as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISCPSecureExchange2_Impl> windows_core::imp::TearOffVtable<Identity> for ISCPSecureExchange2_Vtbl
+where
+    ISCPSecureExchange_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn TransferContainerData2<Identity: windows_core::IUnknownImpl + ISCPSecureExchange2_Impl>(this: *mut core::ffi::c_void, pdata: *const u8, dwsize: u32, pprogresscallback: *mut core::ffi::c_void, pfureadyflags: *mut u32, abmac: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISCPSecureExchange2_Impl::TransferContainerData2(this, core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&dwsize), core::mem::transmute_copy(&pprogresscallback), core::mem::transmute_copy(&pfureadyflags), core::mem::transmute_copy(&abmac)).into()
+            }
+        }
+        Self {
+            base__: <ISCPSecureExchange_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            TransferContainerData2: TransferContainerData2::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISCPSecureExchange2 {}
 windows_core::imp::define_interface!(ISCPSecureExchange3, ISCPSecureExchange3_Vtbl, 0xab4e77e4_8908_4b17_bd2a_b1dbe6dd69e1);
 impl core::ops::Deref for ISCPSecureExchange3 {
@@ -2312,6 +3459,43 @@ impl ISCPSecureExchange3_Vtbl {
         iid == &<ISCPSecureExchange3 as windows_core::Interface>::IID || iid == &<ISCPSecureExchange as windows_core::Interface>::IID || iid == &<ISCPSecureExchange2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISCPSecureExchange3_Impl> windows_core::imp::TearOffVtable<Identity> for ISCPSecureExchange3_Vtbl
+where
+    ISCPSecureExchange2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn TransferContainerDataOnClearChannel<Identity: windows_core::IUnknownImpl + ISCPSecureExchange3_Impl>(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, pdata: *const u8, dwsize: u32, pprogresscallback: *mut core::ffi::c_void, pfureadyflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISCPSecureExchange3_Impl::TransferContainerDataOnClearChannel(this, core::mem::transmute_copy(&pdevice), core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&dwsize), core::mem::transmute_copy(&pprogresscallback)) {
+                    Ok(ok__) => {
+                        pfureadyflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetObjectDataOnClearChannel<Identity: windows_core::IUnknownImpl + ISCPSecureExchange3_Impl>(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, pdata: *mut u8, pdwsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISCPSecureExchange3_Impl::GetObjectDataOnClearChannel(this, core::mem::transmute_copy(&pdevice), core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&pdwsize)).into()
+            }
+        }
+        unsafe extern "system" fn TransferCompleteForDevice<Identity: windows_core::IUnknownImpl + ISCPSecureExchange3_Impl>(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISCPSecureExchange3_Impl::TransferCompleteForDevice(this, core::mem::transmute_copy(&pdevice)).into()
+            }
+        }
+        Self {
+            base__: <ISCPSecureExchange2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            TransferContainerDataOnClearChannel: TransferContainerDataOnClearChannel::<Identity>,
+            GetObjectDataOnClearChannel: GetObjectDataOnClearChannel::<Identity>,
+            TransferCompleteForDevice: TransferCompleteForDevice::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISCPSecureExchange3 {}
 windows_core::imp::define_interface!(ISCPSecureQuery, ISCPSecureQuery_Vtbl, 0x1dcb3a0d_33ed_11d3_8470_00c04f79dbc0);
 windows_core::imp::interface_hierarchy!(ISCPSecureQuery, windows_core::IUnknown);
@@ -2391,6 +3575,44 @@ impl ISCPSecureQuery_Vtbl {
         iid == &<ISCPSecureQuery as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISCPSecureQuery_Impl> windows_core::imp::TearOffVtable<Identity> for ISCPSecureQuery_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDataDemands<Identity: windows_core::IUnknownImpl + ISCPSecureQuery_Impl>(this: *mut core::ffi::c_void, pfuflags: *mut u32, pdwminrightsdata: *mut u32, pdwminexaminedata: *mut u32, pdwmindecidedata: *mut u32, abmac: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISCPSecureQuery_Impl::GetDataDemands(this, core::mem::transmute_copy(&pfuflags), core::mem::transmute_copy(&pdwminrightsdata), core::mem::transmute_copy(&pdwminexaminedata), core::mem::transmute_copy(&pdwmindecidedata), core::mem::transmute_copy(&abmac)).into()
+            }
+        }
+        unsafe extern "system" fn ExamineData<Identity: windows_core::IUnknownImpl + ISCPSecureQuery_Impl>(this: *mut core::ffi::c_void, fuflags: u32, pwszextension: windows_core::PCWSTR, pdata: *const u8, dwsize: u32, abmac: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISCPSecureQuery_Impl::ExamineData(this, core::mem::transmute_copy(&fuflags), core::mem::transmute(&pwszextension), core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&dwsize), core::mem::transmute_copy(&abmac)).into()
+            }
+        }
+        unsafe extern "system" fn MakeDecision<Identity: windows_core::IUnknownImpl + ISCPSecureQuery_Impl>(this: *mut core::ffi::c_void, fuflags: u32, pdata: *const u8, dwsize: u32, dwappsec: u32, pbspsessionkey: *const u8, dwsessionkeylen: u32, pstorageglobals: *mut core::ffi::c_void, ppexchange: *mut *mut core::ffi::c_void, abmac: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISCPSecureQuery_Impl::MakeDecision(this, core::mem::transmute_copy(&fuflags), core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&dwsize), core::mem::transmute_copy(&dwappsec), core::mem::transmute_copy(&pbspsessionkey), core::mem::transmute_copy(&dwsessionkeylen), core::mem::transmute_copy(&pstorageglobals), core::mem::transmute_copy(&ppexchange), core::mem::transmute_copy(&abmac)).into()
+            }
+        }
+        unsafe extern "system" fn GetRights<Identity: windows_core::IUnknownImpl + ISCPSecureQuery_Impl>(this: *mut core::ffi::c_void, pdata: *const u8, dwsize: u32, pbspsessionkey: *const u8, dwsessionkeylen: u32, pstgglobals: *mut core::ffi::c_void, pprights: *mut *mut WMDMRIGHTS, pnrightscount: *mut u32, abmac: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISCPSecureQuery_Impl::GetRights(this, core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&dwsize), core::mem::transmute_copy(&pbspsessionkey), core::mem::transmute_copy(&dwsessionkeylen), core::mem::transmute_copy(&pstgglobals), core::mem::transmute_copy(&pprights), core::mem::transmute_copy(&pnrightscount), core::mem::transmute_copy(&abmac)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDataDemands: GetDataDemands::<Identity>,
+            ExamineData: ExamineData::<Identity>,
+            MakeDecision: MakeDecision::<Identity>,
+            GetRights: GetRights::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISCPSecureQuery {}
 windows_core::imp::define_interface!(ISCPSecureQuery2, ISCPSecureQuery2_Vtbl, 0xebe17e25_4fd7_4632_af46_6d93d4fcc72e);
 impl core::ops::Deref for ISCPSecureQuery2 {
@@ -2476,6 +3698,41 @@ impl ISCPSecureQuery2_Vtbl {
         iid == &<ISCPSecureQuery2 as windows_core::Interface>::IID || iid == &<ISCPSecureQuery as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISCPSecureQuery2_Impl> windows_core::imp::TearOffVtable<Identity> for ISCPSecureQuery2_Vtbl
+where
+    ISCPSecureQuery_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn MakeDecision2<Identity: windows_core::IUnknownImpl + ISCPSecureQuery2_Impl>(this: *mut core::ffi::c_void, fuflags: u32, pdata: *const u8, dwsize: u32, dwappsec: u32, pbspsessionkey: *const u8, dwsessionkeylen: u32, pstorageglobals: *mut core::ffi::c_void, pappcertapp: *const u8, dwappcertapplen: u32, pappcertsp: *const u8, dwappcertsplen: u32, pszrevocationurl: *mut windows_core::PWSTR, pdwrevocationurllen: *mut u32, pdwrevocationbitflag: *mut u32, pqwfilesize: *mut u64, punknown: *mut core::ffi::c_void, ppexchange: *mut *mut core::ffi::c_void, abmac: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISCPSecureQuery2_Impl::MakeDecision2(
+                    this,
+                    core::mem::transmute_copy(&fuflags),
+                    core::mem::transmute_copy(&pdata),
+                    core::mem::transmute_copy(&dwsize),
+                    core::mem::transmute_copy(&dwappsec),
+                    core::mem::transmute_copy(&pbspsessionkey),
+                    core::mem::transmute_copy(&dwsessionkeylen),
+                    core::mem::transmute_copy(&pstorageglobals),
+                    core::mem::transmute_copy(&pappcertapp),
+                    core::mem::transmute_copy(&dwappcertapplen),
+                    core::mem::transmute_copy(&pappcertsp),
+                    core::mem::transmute_copy(&dwappcertsplen),
+                    core::mem::transmute_copy(&pszrevocationurl),
+                    core::mem::transmute_copy(&pdwrevocationurllen),
+                    core::mem::transmute_copy(&pdwrevocationbitflag),
+                    core::mem::transmute_copy(&pqwfilesize),
+                    core::mem::transmute_copy(&punknown),
+                    core::mem::transmute_copy(&ppexchange),
+                    core::mem::transmute_copy(&abmac),
+                )
+                .into()
+            }
+        }
+        Self { base__: <ISCPSecureQuery_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, MakeDecision2: MakeDecision2::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ISCPSecureQuery2 {}
 windows_core::imp::define_interface!(ISCPSecureQuery3, ISCPSecureQuery3_Vtbl, 0xb7edd1a2_4dab_484b_b3c5_ad39b8b4c0b1);
 impl core::ops::Deref for ISCPSecureQuery3 {
@@ -2581,6 +3838,51 @@ impl ISCPSecureQuery3_Vtbl {
         iid == &<ISCPSecureQuery3 as windows_core::Interface>::IID || iid == &<ISCPSecureQuery as windows_core::Interface>::IID || iid == &<ISCPSecureQuery2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISCPSecureQuery3_Impl> windows_core::imp::TearOffVtable<Identity> for ISCPSecureQuery3_Vtbl
+where
+    ISCPSecureQuery2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRightsOnClearChannel<Identity: windows_core::IUnknownImpl + ISCPSecureQuery3_Impl>(this: *mut core::ffi::c_void, pdata: *const u8, dwsize: u32, pbspsessionkey: *const u8, dwsessionkeylen: u32, pstgglobals: *mut core::ffi::c_void, pprogresscallback: *mut core::ffi::c_void, pprights: *mut *mut WMDMRIGHTS, pnrightscount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISCPSecureQuery3_Impl::GetRightsOnClearChannel(this, core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&dwsize), core::mem::transmute_copy(&pbspsessionkey), core::mem::transmute_copy(&dwsessionkeylen), core::mem::transmute_copy(&pstgglobals), core::mem::transmute_copy(&pprogresscallback), core::mem::transmute_copy(&pprights), core::mem::transmute_copy(&pnrightscount)).into()
+            }
+        }
+        unsafe extern "system" fn MakeDecisionOnClearChannel<Identity: windows_core::IUnknownImpl + ISCPSecureQuery3_Impl>(this: *mut core::ffi::c_void, fuflags: u32, pdata: *const u8, dwsize: u32, dwappsec: u32, pbspsessionkey: *const u8, dwsessionkeylen: u32, pstorageglobals: *mut core::ffi::c_void, pprogresscallback: *mut core::ffi::c_void, pappcertapp: *const u8, dwappcertapplen: u32, pappcertsp: *const u8, dwappcertsplen: u32, pszrevocationurl: *mut windows_core::PWSTR, pdwrevocationurllen: *mut u32, pdwrevocationbitflag: *mut u32, pqwfilesize: *mut u64, punknown: *mut core::ffi::c_void, ppexchange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISCPSecureQuery3_Impl::MakeDecisionOnClearChannel(
+                    this,
+                    core::mem::transmute_copy(&fuflags),
+                    core::mem::transmute_copy(&pdata),
+                    core::mem::transmute_copy(&dwsize),
+                    core::mem::transmute_copy(&dwappsec),
+                    core::mem::transmute_copy(&pbspsessionkey),
+                    core::mem::transmute_copy(&dwsessionkeylen),
+                    core::mem::transmute_copy(&pstorageglobals),
+                    core::mem::transmute_copy(&pprogresscallback),
+                    core::mem::transmute_copy(&pappcertapp),
+                    core::mem::transmute_copy(&dwappcertapplen),
+                    core::mem::transmute_copy(&pappcertsp),
+                    core::mem::transmute_copy(&dwappcertsplen),
+                    core::mem::transmute_copy(&pszrevocationurl),
+                    core::mem::transmute_copy(&pdwrevocationurllen),
+                    core::mem::transmute_copy(&pdwrevocationbitflag),
+                    core::mem::transmute_copy(&pqwfilesize),
+                    core::mem::transmute_copy(&punknown),
+                    core::mem::transmute_copy(&ppexchange),
+                )
+                .into()
+            }
+        }
+        Self {
+            base__: <ISCPSecureQuery2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetRightsOnClearChannel: GetRightsOnClearChannel::<Identity>,
+            MakeDecisionOnClearChannel: MakeDecisionOnClearChannel::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISCPSecureQuery3 {}
 windows_core::imp::define_interface!(ISCPSession, ISCPSession_Vtbl, 0x88a3e6ed_eee4_4619_bbb3_fd4fb62715d1);
 windows_core::imp::interface_hierarchy!(ISCPSession, windows_core::IUnknown);
@@ -2651,6 +3953,43 @@ impl ISCPSession_Vtbl {
         iid == &<ISCPSession as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISCPSession_Impl> windows_core::imp::TearOffVtable<Identity> for ISCPSession_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BeginSession<Identity: windows_core::IUnknownImpl + ISCPSession_Impl>(this: *mut core::ffi::c_void, pidevice: *mut core::ffi::c_void, pctx: *const u8, dwsizectx: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISCPSession_Impl::BeginSession(this, core::mem::transmute_copy(&pidevice), core::mem::transmute_copy(&pctx), core::mem::transmute_copy(&dwsizectx)).into()
+            }
+        }
+        unsafe extern "system" fn EndSession<Identity: windows_core::IUnknownImpl + ISCPSession_Impl>(this: *mut core::ffi::c_void, pctx: *const u8, dwsizectx: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISCPSession_Impl::EndSession(this, core::mem::transmute_copy(&pctx), core::mem::transmute_copy(&dwsizectx)).into()
+            }
+        }
+        unsafe extern "system" fn GetSecureQuery<Identity: windows_core::IUnknownImpl + ISCPSession_Impl>(this: *mut core::ffi::c_void, ppsecurequery: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISCPSession_Impl::GetSecureQuery(this) {
+                    Ok(ok__) => {
+                        ppsecurequery.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            BeginSession: BeginSession::<Identity>,
+            EndSession: EndSession::<Identity>,
+            GetSecureQuery: GetSecureQuery::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISCPSession {}
 windows_core::imp::define_interface!(IWMDMDevice, IWMDMDevice_Vtbl, 0x1dcb3a02_33ed_11d3_8470_00c04f79dbc0);
 windows_core::imp::interface_hierarchy!(IWMDMDevice, windows_core::IUnknown);
@@ -2779,19 +4118,140 @@ impl IWMDMDevice_Vtbl {
         }
         unsafe extern "system" fn GetSerialNumber<Identity: IWMDMDevice_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pserialnumber: *mut WMDMID, abmac: *mut u8) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMDMDevice_Impl::GetSerialNumber(this, core::mem::transmute_copy(&pserialnumber), core::mem::transmute_copy(&abmac)).into()
+            }
+        }
+        unsafe extern "system" fn GetPowerSource<Identity: IWMDMDevice_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwpowersource: *mut u32, pdwpercentremaining: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMDMDevice_Impl::GetPowerSource(this, core::mem::transmute_copy(&pdwpowersource), core::mem::transmute_copy(&pdwpercentremaining)).into()
+            }
+        }
+        unsafe extern "system" fn GetStatus<Identity: IWMDMDevice_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWMDMDevice_Impl::GetStatus(this) {
+                    Ok(ok__) => {
+                        pdwstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDeviceIcon<Identity: IWMDMDevice_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hicon: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWMDMDevice_Impl::GetDeviceIcon(this) {
+                    Ok(ok__) => {
+                        hicon.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumStorage<Identity: IWMDMDevice_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppenumstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWMDMDevice_Impl::EnumStorage(this) {
+                    Ok(ok__) => {
+                        ppenumstorage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFormatSupport<Identity: IWMDMDevice_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppformatex: *mut *mut super::Audio::WAVEFORMATEX, pnformatcount: *mut u32, pppwszmimetype: *mut *mut windows_core::PWSTR, pnmimetypecount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMDMDevice_Impl::GetFormatSupport(this, core::mem::transmute_copy(&ppformatex), core::mem::transmute_copy(&pnformatcount), core::mem::transmute_copy(&pppwszmimetype), core::mem::transmute_copy(&pnmimetypecount)).into()
+            }
+        }
+        unsafe extern "system" fn SendOpaqueCommand<Identity: IWMDMDevice_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pcommand: *mut OPAQUECOMMAND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMDMDevice_Impl::SendOpaqueCommand(this, core::mem::transmute_copy(&pcommand)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            GetName: GetName::<Identity, OFFSET>,
+            GetManufacturer: GetManufacturer::<Identity, OFFSET>,
+            GetVersion: GetVersion::<Identity, OFFSET>,
+            GetType: GetType::<Identity, OFFSET>,
+            GetSerialNumber: GetSerialNumber::<Identity, OFFSET>,
+            GetPowerSource: GetPowerSource::<Identity, OFFSET>,
+            GetStatus: GetStatus::<Identity, OFFSET>,
+            GetDeviceIcon: GetDeviceIcon::<Identity, OFFSET>,
+            EnumStorage: EnumStorage::<Identity, OFFSET>,
+            GetFormatSupport: GetFormatSupport::<Identity, OFFSET>,
+            SendOpaqueCommand: SendOpaqueCommand::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMDevice as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_Media_Audio")]
+impl<Identity: windows_core::IUnknownImpl + IWMDMDevice_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMDevice_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IWMDMDevice_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, nmaxchars: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMDevice_Impl::GetName(this, core::mem::transmute_copy(&pwszname), core::mem::transmute_copy(&nmaxchars)).into()
+            }
+        }
+        unsafe extern "system" fn GetManufacturer<Identity: windows_core::IUnknownImpl + IWMDMDevice_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, nmaxchars: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMDevice_Impl::GetManufacturer(this, core::mem::transmute_copy(&pwszname), core::mem::transmute_copy(&nmaxchars)).into()
+            }
+        }
+        unsafe extern "system" fn GetVersion<Identity: windows_core::IUnknownImpl + IWMDMDevice_Impl>(this: *mut core::ffi::c_void, pdwversion: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMDevice_Impl::GetVersion(this) {
+                    Ok(ok__) => {
+                        pdwversion.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetType<Identity: windows_core::IUnknownImpl + IWMDMDevice_Impl>(this: *mut core::ffi::c_void, pdwtype: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMDevice_Impl::GetType(this) {
+                    Ok(ok__) => {
+                        pdwtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSerialNumber<Identity: windows_core::IUnknownImpl + IWMDMDevice_Impl>(this: *mut core::ffi::c_void, pserialnumber: *mut WMDMID, abmac: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMDMDevice_Impl::GetSerialNumber(this, core::mem::transmute_copy(&pserialnumber), core::mem::transmute_copy(&abmac)).into()
             }
         }
-        unsafe extern "system" fn GetPowerSource<Identity: IWMDMDevice_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwpowersource: *mut u32, pdwpercentremaining: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetPowerSource<Identity: windows_core::IUnknownImpl + IWMDMDevice_Impl>(this: *mut core::ffi::c_void, pdwpowersource: *mut u32, pdwpercentremaining: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMDMDevice_Impl::GetPowerSource(this, core::mem::transmute_copy(&pdwpowersource), core::mem::transmute_copy(&pdwpercentremaining)).into()
             }
         }
-        unsafe extern "system" fn GetStatus<Identity: IWMDMDevice_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetStatus<Identity: windows_core::IUnknownImpl + IWMDMDevice_Impl>(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWMDMDevice_Impl::GetStatus(this) {
                     Ok(ok__) => {
                         pdwstatus.write(core::mem::transmute(ok__));
@@ -2801,9 +4261,9 @@ impl IWMDMDevice_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetDeviceIcon<Identity: IWMDMDevice_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hicon: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetDeviceIcon<Identity: windows_core::IUnknownImpl + IWMDMDevice_Impl>(this: *mut core::ffi::c_void, hicon: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWMDMDevice_Impl::GetDeviceIcon(this) {
                     Ok(ok__) => {
                         hicon.write(core::mem::transmute(ok__));
@@ -2813,9 +4273,9 @@ impl IWMDMDevice_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn EnumStorage<Identity: IWMDMDevice_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppenumstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn EnumStorage<Identity: windows_core::IUnknownImpl + IWMDMDevice_Impl>(this: *mut core::ffi::c_void, ppenumstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWMDMDevice_Impl::EnumStorage(this) {
                     Ok(ok__) => {
                         ppenumstorage.write(core::mem::transmute(ok__));
@@ -2825,36 +4285,33 @@ impl IWMDMDevice_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetFormatSupport<Identity: IWMDMDevice_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppformatex: *mut *mut super::Audio::WAVEFORMATEX, pnformatcount: *mut u32, pppwszmimetype: *mut *mut windows_core::PWSTR, pnmimetypecount: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetFormatSupport<Identity: windows_core::IUnknownImpl + IWMDMDevice_Impl>(this: *mut core::ffi::c_void, ppformatex: *mut *mut super::Audio::WAVEFORMATEX, pnformatcount: *mut u32, pppwszmimetype: *mut *mut windows_core::PWSTR, pnmimetypecount: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMDMDevice_Impl::GetFormatSupport(this, core::mem::transmute_copy(&ppformatex), core::mem::transmute_copy(&pnformatcount), core::mem::transmute_copy(&pppwszmimetype), core::mem::transmute_copy(&pnmimetypecount)).into()
             }
         }
-        unsafe extern "system" fn SendOpaqueCommand<Identity: IWMDMDevice_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pcommand: *mut OPAQUECOMMAND) -> windows_core::HRESULT {
+        unsafe extern "system" fn SendOpaqueCommand<Identity: windows_core::IUnknownImpl + IWMDMDevice_Impl>(this: *mut core::ffi::c_void, pcommand: *mut OPAQUECOMMAND) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMDMDevice_Impl::SendOpaqueCommand(this, core::mem::transmute_copy(&pcommand)).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            GetName: GetName::<Identity, OFFSET>,
-            GetManufacturer: GetManufacturer::<Identity, OFFSET>,
-            GetVersion: GetVersion::<Identity, OFFSET>,
-            GetType: GetType::<Identity, OFFSET>,
-            GetSerialNumber: GetSerialNumber::<Identity, OFFSET>,
-            GetPowerSource: GetPowerSource::<Identity, OFFSET>,
-            GetStatus: GetStatus::<Identity, OFFSET>,
-            GetDeviceIcon: GetDeviceIcon::<Identity, OFFSET>,
-            EnumStorage: EnumStorage::<Identity, OFFSET>,
-            GetFormatSupport: GetFormatSupport::<Identity, OFFSET>,
-            SendOpaqueCommand: SendOpaqueCommand::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWMDMDevice as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetName: GetName::<Identity>,
+            GetManufacturer: GetManufacturer::<Identity>,
+            GetVersion: GetVersion::<Identity>,
+            GetType: GetType::<Identity>,
+            GetSerialNumber: GetSerialNumber::<Identity>,
+            GetPowerSource: GetPowerSource::<Identity>,
+            GetStatus: GetStatus::<Identity>,
+            GetDeviceIcon: GetDeviceIcon::<Identity>,
+            EnumStorage: EnumStorage::<Identity>,
+            GetFormatSupport: GetFormatSupport::<Identity>,
+            SendOpaqueCommand: SendOpaqueCommand::<Identity>,
+        }
+    };
 }
 #[cfg(feature = "Win32_Media_Audio")]
 impl windows_core::RuntimeName for IWMDMDevice {}
@@ -2956,6 +4413,51 @@ impl IWMDMDevice2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Ole"))]
+impl<Identity: windows_core::IUnknownImpl + IWMDMDevice2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMDevice2_Vtbl
+where
+    IWMDMDevice_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStorage<Identity: windows_core::IUnknownImpl + IWMDMDevice2_Impl>(this: *mut core::ffi::c_void, pszstoragename: windows_core::PCWSTR, ppstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMDevice2_Impl::GetStorage(this, core::mem::transmute(&pszstoragename)) {
+                    Ok(ok__) => {
+                        ppstorage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFormatSupport2<Identity: windows_core::IUnknownImpl + IWMDMDevice2_Impl>(this: *mut core::ffi::c_void, dwflags: u32, ppaudioformatex: *mut *mut super::Audio::WAVEFORMATEX, pnaudioformatcount: *mut u32, ppvideoformatex: *mut *mut super::MediaFoundation::VIDEOINFOHEADER, pnvideoformatcount: *mut u32, ppfiletype: *mut *mut WMFILECAPABILITIES, pnfiletypecount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMDevice2_Impl::GetFormatSupport2(this, core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&ppaudioformatex), core::mem::transmute_copy(&pnaudioformatcount), core::mem::transmute_copy(&ppvideoformatex), core::mem::transmute_copy(&pnvideoformatcount), core::mem::transmute_copy(&ppfiletype), core::mem::transmute_copy(&pnfiletypecount)).into()
+            }
+        }
+        unsafe extern "system" fn GetSpecifyPropertyPages<Identity: windows_core::IUnknownImpl + IWMDMDevice2_Impl>(this: *mut core::ffi::c_void, ppspecifyproppages: *mut *mut core::ffi::c_void, pppunknowns: *mut *mut *mut core::ffi::c_void, pcunks: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMDevice2_Impl::GetSpecifyPropertyPages(this, core::mem::transmute_copy(&ppspecifyproppages), core::mem::transmute_copy(&pppunknowns), core::mem::transmute_copy(&pcunks)).into()
+            }
+        }
+        unsafe extern "system" fn GetCanonicalName<Identity: windows_core::IUnknownImpl + IWMDMDevice2_Impl>(this: *mut core::ffi::c_void, pwszpnpname: windows_core::PWSTR, nmaxchars: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMDevice2_Impl::GetCanonicalName(this, core::mem::transmute_copy(&pwszpnpname), core::mem::transmute_copy(&nmaxchars)).into()
+            }
+        }
+        Self {
+            base__: <IWMDMDevice_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStorage: GetStorage::<Identity>,
+            GetFormatSupport2: GetFormatSupport2::<Identity>,
+            GetSpecifyPropertyPages: GetSpecifyPropertyPages::<Identity>,
+            GetCanonicalName: GetCanonicalName::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Ole"))]
 impl windows_core::RuntimeName for IWMDMDevice2 {}
 windows_core::imp::define_interface!(IWMDMDevice3, IWMDMDevice3_Vtbl, 0x6c03e4fe_05db_4dda_9e3c_06233a6d5d65);
 impl core::ops::Deref for IWMDMDevice3 {
@@ -3095,6 +4597,70 @@ impl IWMDMDevice3_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWMDMDevice3_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMDevice3_Vtbl
+where
+    IWMDMDevice2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperty<Identity: windows_core::IUnknownImpl + IWMDMDevice3_Impl>(this: *mut core::ffi::c_void, pwszpropname: windows_core::PCWSTR, pvalue: *mut super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMDevice3_Impl::GetProperty(this, core::mem::transmute(&pwszpropname)) {
+                    Ok(ok__) => {
+                        pvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProperty<Identity: windows_core::IUnknownImpl + IWMDMDevice3_Impl>(this: *mut core::ffi::c_void, pwszpropname: windows_core::PCWSTR, pvalue: *const super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMDevice3_Impl::SetProperty(this, core::mem::transmute(&pwszpropname), core::mem::transmute_copy(&pvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetFormatCapability<Identity: windows_core::IUnknownImpl + IWMDMDevice3_Impl>(this: *mut core::ffi::c_void, format: WMDM_FORMATCODE, pformatsupport: *mut WMDM_FORMAT_CAPABILITY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMDevice3_Impl::GetFormatCapability(this, core::mem::transmute_copy(&format)) {
+                    Ok(ok__) => {
+                        pformatsupport.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeviceIoControl<Identity: windows_core::IUnknownImpl + IWMDMDevice3_Impl>(this: *mut core::ffi::c_void, dwiocontrolcode: u32, lpinbuffer: *const u8, ninbuffersize: u32, lpoutbuffer: *mut u8, pnoutbuffersize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMDevice3_Impl::DeviceIoControl(this, core::mem::transmute_copy(&dwiocontrolcode), core::mem::transmute_copy(&lpinbuffer), core::mem::transmute_copy(&ninbuffersize), core::mem::transmute_copy(&lpoutbuffer), core::mem::transmute_copy(&pnoutbuffersize)).into()
+            }
+        }
+        unsafe extern "system" fn FindStorage<Identity: windows_core::IUnknownImpl + IWMDMDevice3_Impl>(this: *mut core::ffi::c_void, findscope: WMDM_FIND_SCOPE, pwszuniqueid: windows_core::PCWSTR, ppstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMDevice3_Impl::FindStorage(this, core::mem::transmute_copy(&findscope), core::mem::transmute(&pwszuniqueid)) {
+                    Ok(ok__) => {
+                        ppstorage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWMDMDevice2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProperty: GetProperty::<Identity>,
+            SetProperty: SetProperty::<Identity>,
+            GetFormatCapability: GetFormatCapability::<Identity>,
+            DeviceIoControl: DeviceIoControl::<Identity>,
+            FindStorage: FindStorage::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWMDMDevice3 {}
 windows_core::imp::define_interface!(IWMDMDeviceControl, IWMDMDeviceControl_Vtbl, 0x1dcb3a04_33ed_11d3_8470_00c04f79dbc0);
 windows_core::imp::interface_hierarchy!(IWMDMDeviceControl, windows_core::IUnknown);
@@ -3238,6 +4804,85 @@ impl IWMDMDeviceControl_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Media_Audio")]
+impl<Identity: windows_core::IUnknownImpl + IWMDMDeviceControl_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMDeviceControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStatus<Identity: windows_core::IUnknownImpl + IWMDMDeviceControl_Impl>(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMDeviceControl_Impl::GetStatus(this) {
+                    Ok(ok__) => {
+                        pdwstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCapabilities<Identity: windows_core::IUnknownImpl + IWMDMDeviceControl_Impl>(this: *mut core::ffi::c_void, pdwcapabilitiesmask: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMDeviceControl_Impl::GetCapabilities(this) {
+                    Ok(ok__) => {
+                        pdwcapabilitiesmask.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Play<Identity: windows_core::IUnknownImpl + IWMDMDeviceControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMDeviceControl_Impl::Play(this).into()
+            }
+        }
+        unsafe extern "system" fn Record<Identity: windows_core::IUnknownImpl + IWMDMDeviceControl_Impl>(this: *mut core::ffi::c_void, pformat: *const super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMDeviceControl_Impl::Record(this, core::mem::transmute_copy(&pformat)).into()
+            }
+        }
+        unsafe extern "system" fn Pause<Identity: windows_core::IUnknownImpl + IWMDMDeviceControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMDeviceControl_Impl::Pause(this).into()
+            }
+        }
+        unsafe extern "system" fn Resume<Identity: windows_core::IUnknownImpl + IWMDMDeviceControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMDeviceControl_Impl::Resume(this).into()
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + IWMDMDeviceControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMDeviceControl_Impl::Stop(this).into()
+            }
+        }
+        unsafe extern "system" fn Seek<Identity: windows_core::IUnknownImpl + IWMDMDeviceControl_Impl>(this: *mut core::ffi::c_void, fumode: u32, noffset: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMDeviceControl_Impl::Seek(this, core::mem::transmute_copy(&fumode), core::mem::transmute_copy(&noffset)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStatus: GetStatus::<Identity>,
+            GetCapabilities: GetCapabilities::<Identity>,
+            Play: Play::<Identity>,
+            Record: Record::<Identity>,
+            Pause: Pause::<Identity>,
+            Resume: Resume::<Identity>,
+            Stop: Stop::<Identity>,
+            Seek: Seek::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Media_Audio")]
 impl windows_core::RuntimeName for IWMDMDeviceControl {}
 windows_core::imp::define_interface!(IWMDMDeviceSession, IWMDMDeviceSession_Vtbl, 0x82af0a65_9d96_412c_83e5_3c43e4b06cc7);
 windows_core::imp::interface_hierarchy!(IWMDMDeviceSession, windows_core::IUnknown);
@@ -3284,6 +4929,30 @@ impl IWMDMDeviceSession_Vtbl {
         iid == &<IWMDMDeviceSession as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDMDeviceSession_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMDeviceSession_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BeginSession<Identity: windows_core::IUnknownImpl + IWMDMDeviceSession_Impl>(this: *mut core::ffi::c_void, r#type: WMDM_SESSION_TYPE, pctx: *const u8, dwsizectx: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMDeviceSession_Impl::BeginSession(this, core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pctx), core::mem::transmute_copy(&dwsizectx)).into()
+            }
+        }
+        unsafe extern "system" fn EndSession<Identity: windows_core::IUnknownImpl + IWMDMDeviceSession_Impl>(this: *mut core::ffi::c_void, r#type: WMDM_SESSION_TYPE, pctx: *const u8, dwsizectx: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMDeviceSession_Impl::EndSession(this, core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pctx), core::mem::transmute_copy(&dwsizectx)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            BeginSession: BeginSession::<Identity>,
+            EndSession: EndSession::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMDMDeviceSession {}
 windows_core::imp::define_interface!(IWMDMEnumDevice, IWMDMEnumDevice_Vtbl, 0x1dcb3a01_33ed_11d3_8470_00c04f79dbc0);
 windows_core::imp::interface_hierarchy!(IWMDMEnumDevice, windows_core::IUnknown);
@@ -3372,6 +5041,56 @@ impl IWMDMEnumDevice_Vtbl {
         iid == &<IWMDMEnumDevice as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDMEnumDevice_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMEnumDevice_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IWMDMEnumDevice_Impl>(this: *mut core::ffi::c_void, celt: u32, ppdevice: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMEnumDevice_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&ppdevice), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IWMDMEnumDevice_Impl>(this: *mut core::ffi::c_void, celt: u32, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMEnumDevice_Impl::Skip(this, core::mem::transmute_copy(&celt)) {
+                    Ok(ok__) => {
+                        pceltfetched.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IWMDMEnumDevice_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMEnumDevice_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IWMDMEnumDevice_Impl>(this: *mut core::ffi::c_void, ppenumdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMEnumDevice_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenumdevice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMDMEnumDevice {}
 windows_core::imp::define_interface!(IWMDMEnumStorage, IWMDMEnumStorage_Vtbl, 0x1dcb3a05_33ed_11d3_8470_00c04f79dbc0);
 windows_core::imp::interface_hierarchy!(IWMDMEnumStorage, windows_core::IUnknown);
@@ -3460,6 +5179,56 @@ impl IWMDMEnumStorage_Vtbl {
         iid == &<IWMDMEnumStorage as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDMEnumStorage_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMEnumStorage_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IWMDMEnumStorage_Impl>(this: *mut core::ffi::c_void, celt: u32, ppstorage: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMEnumStorage_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&ppstorage), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IWMDMEnumStorage_Impl>(this: *mut core::ffi::c_void, celt: u32, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMEnumStorage_Impl::Skip(this, core::mem::transmute_copy(&celt)) {
+                    Ok(ok__) => {
+                        pceltfetched.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IWMDMEnumStorage_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMEnumStorage_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IWMDMEnumStorage_Impl>(this: *mut core::ffi::c_void, ppenumstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMEnumStorage_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenumstorage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMDMEnumStorage {}
 windows_core::imp::define_interface!(IWMDMLogger, IWMDMLogger_Vtbl, 0x110a3200_5a79_11d3_8d78_444553540000);
 windows_core::imp::interface_hierarchy!(IWMDMLogger, windows_core::IUnknown);
@@ -3605,10 +5374,89 @@ impl IWMDMLogger_Vtbl {
             GetSizeParams: GetSizeParams::<Identity, OFFSET>,
             SetSizeParams: SetSizeParams::<Identity, OFFSET>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWMDMLogger as windows_core::Interface>::IID
-    }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMLogger as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IWMDMLogger_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMLogger_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsEnabled<Identity: windows_core::IUnknownImpl + IWMDMLogger_Impl>(this: *mut core::ffi::c_void, pfenabled: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMLogger_Impl::IsEnabled(this) {
+                    Ok(ok__) => {
+                        pfenabled.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Enable<Identity: windows_core::IUnknownImpl + IWMDMLogger_Impl>(this: *mut core::ffi::c_void, fenable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMLogger_Impl::Enable(this, core::mem::transmute_copy(&fenable)).into()
+            }
+        }
+        unsafe extern "system" fn GetLogFileName<Identity: windows_core::IUnknownImpl + IWMDMLogger_Impl>(this: *mut core::ffi::c_void, pszfilename: windows_core::PSTR, nmaxchars: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMLogger_Impl::GetLogFileName(this, core::mem::transmute_copy(&pszfilename), core::mem::transmute_copy(&nmaxchars)).into()
+            }
+        }
+        unsafe extern "system" fn SetLogFileName<Identity: windows_core::IUnknownImpl + IWMDMLogger_Impl>(this: *mut core::ffi::c_void, pszfilename: windows_core::PCSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMLogger_Impl::SetLogFileName(this, core::mem::transmute(&pszfilename)).into()
+            }
+        }
+        unsafe extern "system" fn LogString<Identity: windows_core::IUnknownImpl + IWMDMLogger_Impl>(this: *mut core::ffi::c_void, dwflags: u32, pszsrcname: windows_core::PCSTR, pszlog: windows_core::PCSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMLogger_Impl::LogString(this, core::mem::transmute_copy(&dwflags), core::mem::transmute(&pszsrcname), core::mem::transmute(&pszlog)).into()
+            }
+        }
+        unsafe extern "system" fn LogDword<Identity: windows_core::IUnknownImpl + IWMDMLogger_Impl>(this: *mut core::ffi::c_void, dwflags: u32, pszsrcname: windows_core::PCSTR, pszlogformat: windows_core::PCSTR, dwlog: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMLogger_Impl::LogDword(this, core::mem::transmute_copy(&dwflags), core::mem::transmute(&pszsrcname), core::mem::transmute(&pszlogformat), core::mem::transmute_copy(&dwlog)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IWMDMLogger_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMLogger_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn GetSizeParams<Identity: windows_core::IUnknownImpl + IWMDMLogger_Impl>(this: *mut core::ffi::c_void, pdwmaxsize: *mut u32, pdwshrinktosize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMLogger_Impl::GetSizeParams(this, core::mem::transmute_copy(&pdwmaxsize), core::mem::transmute_copy(&pdwshrinktosize)).into()
+            }
+        }
+        unsafe extern "system" fn SetSizeParams<Identity: windows_core::IUnknownImpl + IWMDMLogger_Impl>(this: *mut core::ffi::c_void, dwmaxsize: u32, dwshrinktosize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMLogger_Impl::SetSizeParams(this, core::mem::transmute_copy(&dwmaxsize), core::mem::transmute_copy(&dwshrinktosize)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsEnabled: IsEnabled::<Identity>,
+            Enable: Enable::<Identity>,
+            GetLogFileName: GetLogFileName::<Identity>,
+            SetLogFileName: SetLogFileName::<Identity>,
+            LogString: LogString::<Identity>,
+            LogDword: LogDword::<Identity>,
+            Reset: Reset::<Identity>,
+            GetSizeParams: GetSizeParams::<Identity>,
+            SetSizeParams: SetSizeParams::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IWMDMLogger {}
 windows_core::imp::define_interface!(IWMDMMetaData, IWMDMMetaData_Vtbl, 0xec3b0663_0951_460a_9a80_0dceed3c043c);
@@ -3695,6 +5543,50 @@ impl IWMDMMetaData_Vtbl {
         iid == &<IWMDMMetaData as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDMMetaData_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMMetaData_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddItem<Identity: windows_core::IUnknownImpl + IWMDMMetaData_Impl>(this: *mut core::ffi::c_void, r#type: WMDM_TAG_DATATYPE, pwsztagname: windows_core::PCWSTR, pvalue: *const u8, ilength: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMMetaData_Impl::AddItem(this, core::mem::transmute_copy(&r#type), core::mem::transmute(&pwsztagname), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&ilength)).into()
+            }
+        }
+        unsafe extern "system" fn QueryByName<Identity: windows_core::IUnknownImpl + IWMDMMetaData_Impl>(this: *mut core::ffi::c_void, pwsztagname: windows_core::PCWSTR, ptype: *mut WMDM_TAG_DATATYPE, pvalue: *mut *mut u8, pcblength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMMetaData_Impl::QueryByName(this, core::mem::transmute(&pwsztagname), core::mem::transmute_copy(&ptype), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&pcblength)).into()
+            }
+        }
+        unsafe extern "system" fn QueryByIndex<Identity: windows_core::IUnknownImpl + IWMDMMetaData_Impl>(this: *mut core::ffi::c_void, iindex: u32, ppwszname: *mut *mut u16, ptype: *mut WMDM_TAG_DATATYPE, ppvalue: *mut *mut u8, pcblength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMMetaData_Impl::QueryByIndex(this, core::mem::transmute_copy(&iindex), core::mem::transmute_copy(&ppwszname), core::mem::transmute_copy(&ptype), core::mem::transmute_copy(&ppvalue), core::mem::transmute_copy(&pcblength)).into()
+            }
+        }
+        unsafe extern "system" fn GetItemCount<Identity: windows_core::IUnknownImpl + IWMDMMetaData_Impl>(this: *mut core::ffi::c_void, icount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMMetaData_Impl::GetItemCount(this) {
+                    Ok(ok__) => {
+                        icount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddItem: AddItem::<Identity>,
+            QueryByName: QueryByName::<Identity>,
+            QueryByIndex: QueryByIndex::<Identity>,
+            GetItemCount: GetItemCount::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMDMMetaData {}
 windows_core::imp::define_interface!(IWMDMNotification, IWMDMNotification_Vtbl, 0x3f5e95c0_0f43_4ed4_93d2_c89a45d59b81);
 windows_core::imp::interface_hierarchy!(IWMDMNotification, windows_core::IUnknown);
@@ -3729,6 +5621,20 @@ impl IWMDMNotification_Vtbl {
         iid == &<IWMDMNotification as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDMNotification_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMNotification_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn WMDMMessage<Identity: windows_core::IUnknownImpl + IWMDMNotification_Impl>(this: *mut core::ffi::c_void, dwmessagetype: u32, pwszcanonicalname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMNotification_Impl::WMDMMessage(this, core::mem::transmute_copy(&dwmessagetype), core::mem::transmute(&pwszcanonicalname)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, WMDMMessage: WMDMMessage::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMDMNotification {}
 windows_core::imp::define_interface!(IWMDMObjectInfo, IWMDMObjectInfo_Vtbl, 0x1dcb3a09_33ed_11d3_8470_00c04f79dbc0);
 windows_core::imp::interface_hierarchy!(IWMDMObjectInfo, windows_core::IUnknown);
@@ -3880,6 +5786,95 @@ impl IWMDMObjectInfo_Vtbl {
         iid == &<IWMDMObjectInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDMObjectInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMObjectInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPlayLength<Identity: windows_core::IUnknownImpl + IWMDMObjectInfo_Impl>(this: *mut core::ffi::c_void, pdwlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMObjectInfo_Impl::GetPlayLength(this) {
+                    Ok(ok__) => {
+                        pdwlength.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPlayLength<Identity: windows_core::IUnknownImpl + IWMDMObjectInfo_Impl>(this: *mut core::ffi::c_void, dwlength: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMObjectInfo_Impl::SetPlayLength(this, core::mem::transmute_copy(&dwlength)).into()
+            }
+        }
+        unsafe extern "system" fn GetPlayOffset<Identity: windows_core::IUnknownImpl + IWMDMObjectInfo_Impl>(this: *mut core::ffi::c_void, pdwoffset: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMObjectInfo_Impl::GetPlayOffset(this) {
+                    Ok(ok__) => {
+                        pdwoffset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPlayOffset<Identity: windows_core::IUnknownImpl + IWMDMObjectInfo_Impl>(this: *mut core::ffi::c_void, dwoffset: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMObjectInfo_Impl::SetPlayOffset(this, core::mem::transmute_copy(&dwoffset)).into()
+            }
+        }
+        unsafe extern "system" fn GetTotalLength<Identity: windows_core::IUnknownImpl + IWMDMObjectInfo_Impl>(this: *mut core::ffi::c_void, pdwlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMObjectInfo_Impl::GetTotalLength(this) {
+                    Ok(ok__) => {
+                        pdwlength.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLastPlayPosition<Identity: windows_core::IUnknownImpl + IWMDMObjectInfo_Impl>(this: *mut core::ffi::c_void, pdwlastpos: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMObjectInfo_Impl::GetLastPlayPosition(this) {
+                    Ok(ok__) => {
+                        pdwlastpos.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLongestPlayPosition<Identity: windows_core::IUnknownImpl + IWMDMObjectInfo_Impl>(this: *mut core::ffi::c_void, pdwlongestpos: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMObjectInfo_Impl::GetLongestPlayPosition(this) {
+                    Ok(ok__) => {
+                        pdwlongestpos.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPlayLength: GetPlayLength::<Identity>,
+            SetPlayLength: SetPlayLength::<Identity>,
+            GetPlayOffset: GetPlayOffset::<Identity>,
+            SetPlayOffset: SetPlayOffset::<Identity>,
+            GetTotalLength: GetTotalLength::<Identity>,
+            GetLastPlayPosition: GetLastPlayPosition::<Identity>,
+            GetLongestPlayPosition: GetLongestPlayPosition::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMDMObjectInfo {}
 windows_core::imp::define_interface!(IWMDMOperation, IWMDMOperation_Vtbl, 0x1dcb3a0b_33ed_11d3_8470_00c04f79dbc0);
 windows_core::imp::interface_hierarchy!(IWMDMOperation, windows_core::IUnknown);
@@ -4036,6 +6031,87 @@ impl IWMDMOperation_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Media_Audio")]
+impl<Identity: windows_core::IUnknownImpl + IWMDMOperation_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMOperation_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BeginRead<Identity: windows_core::IUnknownImpl + IWMDMOperation_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMOperation_Impl::BeginRead(this).into()
+            }
+        }
+        unsafe extern "system" fn BeginWrite<Identity: windows_core::IUnknownImpl + IWMDMOperation_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMOperation_Impl::BeginWrite(this).into()
+            }
+        }
+        unsafe extern "system" fn GetObjectName<Identity: windows_core::IUnknownImpl + IWMDMOperation_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, nmaxchars: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMOperation_Impl::GetObjectName(this, core::mem::transmute_copy(&pwszname), core::mem::transmute_copy(&nmaxchars)).into()
+            }
+        }
+        unsafe extern "system" fn SetObjectName<Identity: windows_core::IUnknownImpl + IWMDMOperation_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR, nmaxchars: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMOperation_Impl::SetObjectName(this, core::mem::transmute(&pwszname), core::mem::transmute_copy(&nmaxchars)).into()
+            }
+        }
+        unsafe extern "system" fn GetObjectAttributes<Identity: windows_core::IUnknownImpl + IWMDMOperation_Impl>(this: *mut core::ffi::c_void, pdwattributes: *mut u32, pformat: *mut super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMOperation_Impl::GetObjectAttributes(this, core::mem::transmute_copy(&pdwattributes), core::mem::transmute_copy(&pformat)).into()
+            }
+        }
+        unsafe extern "system" fn SetObjectAttributes<Identity: windows_core::IUnknownImpl + IWMDMOperation_Impl>(this: *mut core::ffi::c_void, dwattributes: u32, pformat: *const super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMOperation_Impl::SetObjectAttributes(this, core::mem::transmute_copy(&dwattributes), core::mem::transmute_copy(&pformat)).into()
+            }
+        }
+        unsafe extern "system" fn GetObjectTotalSize<Identity: windows_core::IUnknownImpl + IWMDMOperation_Impl>(this: *mut core::ffi::c_void, pdwsize: *mut u32, pdwsizehigh: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMOperation_Impl::GetObjectTotalSize(this, core::mem::transmute_copy(&pdwsize), core::mem::transmute_copy(&pdwsizehigh)).into()
+            }
+        }
+        unsafe extern "system" fn SetObjectTotalSize<Identity: windows_core::IUnknownImpl + IWMDMOperation_Impl>(this: *mut core::ffi::c_void, dwsize: u32, dwsizehigh: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMOperation_Impl::SetObjectTotalSize(this, core::mem::transmute_copy(&dwsize), core::mem::transmute_copy(&dwsizehigh)).into()
+            }
+        }
+        unsafe extern "system" fn TransferObjectData<Identity: windows_core::IUnknownImpl + IWMDMOperation_Impl>(this: *mut core::ffi::c_void, pdata: *mut u8, pdwsize: *mut u32, abmac: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMOperation_Impl::TransferObjectData(this, core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&pdwsize), core::mem::transmute_copy(&abmac)).into()
+            }
+        }
+        unsafe extern "system" fn End<Identity: windows_core::IUnknownImpl + IWMDMOperation_Impl>(this: *mut core::ffi::c_void, phcompletioncode: *const windows_core::HRESULT, pnewobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMOperation_Impl::End(this, core::mem::transmute_copy(&phcompletioncode), core::mem::transmute_copy(&pnewobject)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            BeginRead: BeginRead::<Identity>,
+            BeginWrite: BeginWrite::<Identity>,
+            GetObjectName: GetObjectName::<Identity>,
+            SetObjectName: SetObjectName::<Identity>,
+            GetObjectAttributes: GetObjectAttributes::<Identity>,
+            SetObjectAttributes: SetObjectAttributes::<Identity>,
+            GetObjectTotalSize: GetObjectTotalSize::<Identity>,
+            SetObjectTotalSize: SetObjectTotalSize::<Identity>,
+            TransferObjectData: TransferObjectData::<Identity>,
+            End: End::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Media_Audio")]
 impl windows_core::RuntimeName for IWMDMOperation {}
 windows_core::imp::define_interface!(IWMDMOperation2, IWMDMOperation2_Vtbl, 0x33445b48_7df7_425c_ad8f_0fc6d82f9f75);
 impl core::ops::Deref for IWMDMOperation2 {
@@ -4099,6 +6175,31 @@ impl IWMDMOperation2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+impl<Identity: windows_core::IUnknownImpl + IWMDMOperation2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMOperation2_Vtbl
+where
+    IWMDMOperation_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetObjectAttributes2<Identity: windows_core::IUnknownImpl + IWMDMOperation2_Impl>(this: *mut core::ffi::c_void, dwattributes: u32, dwattributesex: u32, pformat: *const super::Audio::WAVEFORMATEX, pvideoformat: *const super::MediaFoundation::VIDEOINFOHEADER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMOperation2_Impl::SetObjectAttributes2(this, core::mem::transmute_copy(&dwattributes), core::mem::transmute_copy(&dwattributesex), core::mem::transmute_copy(&pformat), core::mem::transmute_copy(&pvideoformat)).into()
+            }
+        }
+        unsafe extern "system" fn GetObjectAttributes2<Identity: windows_core::IUnknownImpl + IWMDMOperation2_Impl>(this: *mut core::ffi::c_void, pdwattributes: *mut u32, pdwattributesex: *mut u32, paudioformat: *mut super::Audio::WAVEFORMATEX, pvideoformat: *mut super::MediaFoundation::VIDEOINFOHEADER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMOperation2_Impl::GetObjectAttributes2(this, core::mem::transmute_copy(&pdwattributes), core::mem::transmute_copy(&pdwattributesex), core::mem::transmute_copy(&paudioformat), core::mem::transmute_copy(&pvideoformat)).into()
+            }
+        }
+        Self {
+            base__: <IWMDMOperation_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetObjectAttributes2: SetObjectAttributes2::<Identity>,
+            GetObjectAttributes2: GetObjectAttributes2::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
 impl windows_core::RuntimeName for IWMDMOperation2 {}
 windows_core::imp::define_interface!(IWMDMOperation3, IWMDMOperation3_Vtbl, 0xd1f9b46a_9ca8_46d8_9d0f_1ec9bae54919);
 impl core::ops::Deref for IWMDMOperation3 {
@@ -4139,6 +6240,24 @@ impl IWMDMOperation3_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Media_Audio")]
+impl<Identity: windows_core::IUnknownImpl + IWMDMOperation3_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMOperation3_Vtbl
+where
+    IWMDMOperation_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn TransferObjectDataOnClearChannel<Identity: windows_core::IUnknownImpl + IWMDMOperation3_Impl>(this: *mut core::ffi::c_void, pdata: *mut u8, pdwsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMOperation3_Impl::TransferObjectDataOnClearChannel(this, core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&pdwsize)).into()
+            }
+        }
+        Self {
+            base__: <IWMDMOperation_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            TransferObjectDataOnClearChannel: TransferObjectDataOnClearChannel::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Media_Audio")]
 impl windows_core::RuntimeName for IWMDMOperation3 {}
 windows_core::imp::define_interface!(IWMDMProgress, IWMDMProgress_Vtbl, 0x1dcb3a0c_33ed_11d3_8470_00c04f79dbc0);
 windows_core::imp::interface_hierarchy!(IWMDMProgress, windows_core::IUnknown);
@@ -4197,6 +6316,37 @@ impl IWMDMProgress_Vtbl {
         iid == &<IWMDMProgress as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDMProgress_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMProgress_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Begin<Identity: windows_core::IUnknownImpl + IWMDMProgress_Impl>(this: *mut core::ffi::c_void, dwestimatedticks: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMProgress_Impl::Begin(this, core::mem::transmute_copy(&dwestimatedticks)).into()
+            }
+        }
+        unsafe extern "system" fn Progress<Identity: windows_core::IUnknownImpl + IWMDMProgress_Impl>(this: *mut core::ffi::c_void, dwtranspiredticks: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMProgress_Impl::Progress(this, core::mem::transmute_copy(&dwtranspiredticks)).into()
+            }
+        }
+        unsafe extern "system" fn End<Identity: windows_core::IUnknownImpl + IWMDMProgress_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMProgress_Impl::End(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Begin: Begin::<Identity>,
+            Progress: Progress::<Identity>,
+            End: End::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMDMProgress {}
 windows_core::imp::define_interface!(IWMDMProgress2, IWMDMProgress2_Vtbl, 0x3a43f550_b383_4e92_b04a_e6bbc660fefc);
 impl core::ops::Deref for IWMDMProgress2 {
@@ -4234,6 +6384,20 @@ impl IWMDMProgress2_Vtbl {
         iid == &<IWMDMProgress2 as windows_core::Interface>::IID || iid == &<IWMDMProgress as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDMProgress2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMProgress2_Vtbl
+where
+    IWMDMProgress_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn End2<Identity: windows_core::IUnknownImpl + IWMDMProgress2_Impl>(this: *mut core::ffi::c_void, hrcompletioncode: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMProgress2_Impl::End2(this, core::mem::transmute_copy(&hrcompletioncode)).into()
+            }
+        }
+        Self { base__: <IWMDMProgress_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, End2: End2::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMDMProgress2 {}
 windows_core::imp::define_interface!(IWMDMProgress3, IWMDMProgress3_Vtbl, 0x21de01cb_3bb4_4929_b21a_17af3f80f658);
 impl core::ops::Deref for IWMDMProgress3 {
@@ -4298,6 +6462,37 @@ impl IWMDMProgress3_Vtbl {
         iid == &<IWMDMProgress3 as windows_core::Interface>::IID || iid == &<IWMDMProgress as windows_core::Interface>::IID || iid == &<IWMDMProgress2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDMProgress3_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMProgress3_Vtbl
+where
+    IWMDMProgress2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Begin3<Identity: windows_core::IUnknownImpl + IWMDMProgress3_Impl>(this: *mut core::ffi::c_void, eventid: windows_core::GUID, dwestimatedticks: u32, pcontext: *mut OPAQUECOMMAND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMProgress3_Impl::Begin3(this, core::mem::transmute(&eventid), core::mem::transmute_copy(&dwestimatedticks), core::mem::transmute_copy(&pcontext)).into()
+            }
+        }
+        unsafe extern "system" fn Progress3<Identity: windows_core::IUnknownImpl + IWMDMProgress3_Impl>(this: *mut core::ffi::c_void, eventid: windows_core::GUID, dwtranspiredticks: u32, pcontext: *mut OPAQUECOMMAND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMProgress3_Impl::Progress3(this, core::mem::transmute(&eventid), core::mem::transmute_copy(&dwtranspiredticks), core::mem::transmute_copy(&pcontext)).into()
+            }
+        }
+        unsafe extern "system" fn End3<Identity: windows_core::IUnknownImpl + IWMDMProgress3_Impl>(this: *mut core::ffi::c_void, eventid: windows_core::GUID, hrcompletioncode: windows_core::HRESULT, pcontext: *mut OPAQUECOMMAND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMProgress3_Impl::End3(this, core::mem::transmute(&eventid), core::mem::transmute_copy(&hrcompletioncode), core::mem::transmute_copy(&pcontext)).into()
+            }
+        }
+        Self {
+            base__: <IWMDMProgress2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Begin3: Begin3::<Identity>,
+            Progress3: Progress3::<Identity>,
+            End3: End3::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMDMProgress3 {}
 windows_core::imp::define_interface!(IWMDMRevoked, IWMDMRevoked_Vtbl, 0xebeccedb_88ee_4e55_b6a4_8d9f07d696aa);
 windows_core::imp::interface_hierarchy!(IWMDMRevoked, windows_core::IUnknown);
@@ -4329,6 +6524,20 @@ impl IWMDMRevoked_Vtbl {
         iid == &<IWMDMRevoked as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDMRevoked_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMRevoked_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRevocationURL<Identity: windows_core::IUnknownImpl + IWMDMRevoked_Impl>(this: *mut core::ffi::c_void, ppwszrevocationurl: *mut windows_core::PWSTR, pdwbufferlen: *mut u32, pdwrevokedbitflag: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMRevoked_Impl::GetRevocationURL(this, core::mem::transmute_copy(&ppwszrevocationurl), core::mem::transmute_copy(&pdwbufferlen), core::mem::transmute_copy(&pdwrevokedbitflag)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetRevocationURL: GetRevocationURL::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMDMRevoked {}
 windows_core::imp::define_interface!(IWMDMStorage, IWMDMStorage_Vtbl, 0x1dcb3a06_33ed_11d3_8470_00c04f79dbc0);
 windows_core::imp::interface_hierarchy!(IWMDMStorage, windows_core::IUnknown);
@@ -4491,10 +6700,102 @@ impl IWMDMStorage_Vtbl {
             EnumStorage: EnumStorage::<Identity, OFFSET>,
             SendOpaqueCommand: SendOpaqueCommand::<Identity, OFFSET>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWMDMStorage as windows_core::Interface>::IID
-    }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMStorage as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_Media_Audio")]
+impl<Identity: windows_core::IUnknownImpl + IWMDMStorage_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMStorage_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAttributes<Identity: windows_core::IUnknownImpl + IWMDMStorage_Impl>(this: *mut core::ffi::c_void, dwattributes: u32, pformat: *const super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorage_Impl::SetAttributes(this, core::mem::transmute_copy(&dwattributes), core::mem::transmute_copy(&pformat)).into()
+            }
+        }
+        unsafe extern "system" fn GetStorageGlobals<Identity: windows_core::IUnknownImpl + IWMDMStorage_Impl>(this: *mut core::ffi::c_void, ppstorageglobals: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMStorage_Impl::GetStorageGlobals(this) {
+                    Ok(ok__) => {
+                        ppstorageglobals.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAttributes<Identity: windows_core::IUnknownImpl + IWMDMStorage_Impl>(this: *mut core::ffi::c_void, pdwattributes: *mut u32, pformat: *mut super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorage_Impl::GetAttributes(this, core::mem::transmute_copy(&pdwattributes), core::mem::transmute_copy(&pformat)).into()
+            }
+        }
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IWMDMStorage_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, nmaxchars: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorage_Impl::GetName(this, core::mem::transmute_copy(&pwszname), core::mem::transmute_copy(&nmaxchars)).into()
+            }
+        }
+        unsafe extern "system" fn GetDate<Identity: windows_core::IUnknownImpl + IWMDMStorage_Impl>(this: *mut core::ffi::c_void, pdatetimeutc: *mut WMDMDATETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMStorage_Impl::GetDate(this) {
+                    Ok(ok__) => {
+                        pdatetimeutc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSize<Identity: windows_core::IUnknownImpl + IWMDMStorage_Impl>(this: *mut core::ffi::c_void, pdwsizelow: *mut u32, pdwsizehigh: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorage_Impl::GetSize(this, core::mem::transmute_copy(&pdwsizelow), core::mem::transmute_copy(&pdwsizehigh)).into()
+            }
+        }
+        unsafe extern "system" fn GetRights<Identity: windows_core::IUnknownImpl + IWMDMStorage_Impl>(this: *mut core::ffi::c_void, pprights: *mut *mut WMDMRIGHTS, pnrightscount: *mut u32, abmac: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorage_Impl::GetRights(this, core::mem::transmute_copy(&pprights), core::mem::transmute_copy(&pnrightscount), core::mem::transmute_copy(&abmac)).into()
+            }
+        }
+        unsafe extern "system" fn EnumStorage<Identity: windows_core::IUnknownImpl + IWMDMStorage_Impl>(this: *mut core::ffi::c_void, penumstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMStorage_Impl::EnumStorage(this) {
+                    Ok(ok__) => {
+                        penumstorage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SendOpaqueCommand<Identity: windows_core::IUnknownImpl + IWMDMStorage_Impl>(this: *mut core::ffi::c_void, pcommand: *mut OPAQUECOMMAND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorage_Impl::SendOpaqueCommand(this, core::mem::transmute_copy(&pcommand)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAttributes: SetAttributes::<Identity>,
+            GetStorageGlobals: GetStorageGlobals::<Identity>,
+            GetAttributes: GetAttributes::<Identity>,
+            GetName: GetName::<Identity>,
+            GetDate: GetDate::<Identity>,
+            GetSize: GetSize::<Identity>,
+            GetRights: GetRights::<Identity>,
+            EnumStorage: EnumStorage::<Identity>,
+            SendOpaqueCommand: SendOpaqueCommand::<Identity>,
+        }
+    };
 }
 #[cfg(feature = "Win32_Media_Audio")]
 impl windows_core::RuntimeName for IWMDMStorage {}
@@ -4584,6 +6885,44 @@ impl IWMDMStorage2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+impl<Identity: windows_core::IUnknownImpl + IWMDMStorage2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMStorage2_Vtbl
+where
+    IWMDMStorage_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStorage<Identity: windows_core::IUnknownImpl + IWMDMStorage2_Impl>(this: *mut core::ffi::c_void, pszstoragename: windows_core::PCWSTR, ppstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMStorage2_Impl::GetStorage(this, core::mem::transmute(&pszstoragename)) {
+                    Ok(ok__) => {
+                        ppstorage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAttributes2<Identity: windows_core::IUnknownImpl + IWMDMStorage2_Impl>(this: *mut core::ffi::c_void, dwattributes: u32, dwattributesex: u32, pformat: *const super::Audio::WAVEFORMATEX, pvideoformat: *const super::MediaFoundation::VIDEOINFOHEADER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorage2_Impl::SetAttributes2(this, core::mem::transmute_copy(&dwattributes), core::mem::transmute_copy(&dwattributesex), core::mem::transmute_copy(&pformat), core::mem::transmute_copy(&pvideoformat)).into()
+            }
+        }
+        unsafe extern "system" fn GetAttributes2<Identity: windows_core::IUnknownImpl + IWMDMStorage2_Impl>(this: *mut core::ffi::c_void, pdwattributes: *mut u32, pdwattributesex: *mut u32, paudioformat: *mut super::Audio::WAVEFORMATEX, pvideoformat: *mut super::MediaFoundation::VIDEOINFOHEADER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorage2_Impl::GetAttributes2(this, core::mem::transmute_copy(&pdwattributes), core::mem::transmute_copy(&pdwattributesex), core::mem::transmute_copy(&paudioformat), core::mem::transmute_copy(&pvideoformat)).into()
+            }
+        }
+        Self {
+            base__: <IWMDMStorage_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStorage: GetStorage::<Identity>,
+            SetAttributes2: SetAttributes2::<Identity>,
+            GetAttributes2: GetAttributes2::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
 impl windows_core::RuntimeName for IWMDMStorage2 {}
 windows_core::imp::define_interface!(IWMDMStorage3, IWMDMStorage3_Vtbl, 0x97717eea_926a_464e_96a4_247b0216026e);
 impl core::ops::Deref for IWMDMStorage3 {
@@ -4684,6 +7023,57 @@ impl IWMDMStorage3_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+impl<Identity: windows_core::IUnknownImpl + IWMDMStorage3_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMStorage3_Vtbl
+where
+    IWMDMStorage2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMetadata<Identity: windows_core::IUnknownImpl + IWMDMStorage3_Impl>(this: *mut core::ffi::c_void, ppmetadata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMStorage3_Impl::GetMetadata(this) {
+                    Ok(ok__) => {
+                        ppmetadata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMetadata<Identity: windows_core::IUnknownImpl + IWMDMStorage3_Impl>(this: *mut core::ffi::c_void, pmetadata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorage3_Impl::SetMetadata(this, core::mem::transmute_copy(&pmetadata)).into()
+            }
+        }
+        unsafe extern "system" fn CreateEmptyMetadataObject<Identity: windows_core::IUnknownImpl + IWMDMStorage3_Impl>(this: *mut core::ffi::c_void, ppmetadata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMStorage3_Impl::CreateEmptyMetadataObject(this) {
+                    Ok(ok__) => {
+                        ppmetadata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEnumPreference<Identity: windows_core::IUnknownImpl + IWMDMStorage3_Impl>(this: *mut core::ffi::c_void, pmode: *mut WMDM_STORAGE_ENUM_MODE, nviews: u32, pviews: *const WMDMMetadataView) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorage3_Impl::SetEnumPreference(this, core::mem::transmute_copy(&pmode), core::mem::transmute_copy(&nviews), core::mem::transmute_copy(&pviews)).into()
+            }
+        }
+        Self {
+            base__: <IWMDMStorage2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetMetadata: GetMetadata::<Identity>,
+            SetMetadata: SetMetadata::<Identity>,
+            CreateEmptyMetadataObject: CreateEmptyMetadataObject::<Identity>,
+            SetEnumPreference: SetEnumPreference::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
 impl windows_core::RuntimeName for IWMDMStorage3 {}
 windows_core::imp::define_interface!(IWMDMStorage4, IWMDMStorage4_Vtbl, 0xc225bac5_a03a_40b8_9a23_91cf478c64a6);
 impl core::ops::Deref for IWMDMStorage4 {
@@ -4820,6 +7210,77 @@ impl IWMDMStorage4_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+impl<Identity: windows_core::IUnknownImpl + IWMDMStorage4_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMStorage4_Vtbl
+where
+    IWMDMStorage3_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetReferences<Identity: windows_core::IUnknownImpl + IWMDMStorage4_Impl>(this: *mut core::ffi::c_void, dwrefs: u32, ppiwmdmstorage: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorage4_Impl::SetReferences(this, core::mem::transmute_copy(&dwrefs), core::mem::transmute_copy(&ppiwmdmstorage)).into()
+            }
+        }
+        unsafe extern "system" fn GetReferences<Identity: windows_core::IUnknownImpl + IWMDMStorage4_Impl>(this: *mut core::ffi::c_void, pdwrefs: *mut u32, pppiwmdmstorage: *mut *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorage4_Impl::GetReferences(this, core::mem::transmute_copy(&pdwrefs), core::mem::transmute_copy(&pppiwmdmstorage)).into()
+            }
+        }
+        unsafe extern "system" fn GetRightsWithProgress<Identity: windows_core::IUnknownImpl + IWMDMStorage4_Impl>(this: *mut core::ffi::c_void, piprogresscallback: *mut core::ffi::c_void, pprights: *mut *mut WMDMRIGHTS, pnrightscount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorage4_Impl::GetRightsWithProgress(this, core::mem::transmute_copy(&piprogresscallback), core::mem::transmute_copy(&pprights), core::mem::transmute_copy(&pnrightscount)).into()
+            }
+        }
+        unsafe extern "system" fn GetSpecifiedMetadata<Identity: windows_core::IUnknownImpl + IWMDMStorage4_Impl>(this: *mut core::ffi::c_void, cproperties: u32, ppwszpropnames: *const windows_core::PCWSTR, ppmetadata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMStorage4_Impl::GetSpecifiedMetadata(this, core::mem::transmute_copy(&cproperties), core::mem::transmute_copy(&ppwszpropnames)) {
+                    Ok(ok__) => {
+                        ppmetadata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindStorage<Identity: windows_core::IUnknownImpl + IWMDMStorage4_Impl>(this: *mut core::ffi::c_void, findscope: WMDM_FIND_SCOPE, pwszuniqueid: windows_core::PCWSTR, ppstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMStorage4_Impl::FindStorage(this, core::mem::transmute_copy(&findscope), core::mem::transmute(&pwszuniqueid)) {
+                    Ok(ok__) => {
+                        ppstorage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetParent<Identity: windows_core::IUnknownImpl + IWMDMStorage4_Impl>(this: *mut core::ffi::c_void, ppstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMStorage4_Impl::GetParent(this) {
+                    Ok(ok__) => {
+                        ppstorage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWMDMStorage3_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetReferences: SetReferences::<Identity>,
+            GetReferences: GetReferences::<Identity>,
+            GetRightsWithProgress: GetRightsWithProgress::<Identity>,
+            GetSpecifiedMetadata: GetSpecifiedMetadata::<Identity>,
+            FindStorage: FindStorage::<Identity>,
+            GetParent: GetParent::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
 impl windows_core::RuntimeName for IWMDMStorage4 {}
 windows_core::imp::define_interface!(IWMDMStorageControl, IWMDMStorageControl_Vtbl, 0x1dcb3a08_33ed_11d3_8470_00c04f79dbc0);
 windows_core::imp::interface_hierarchy!(IWMDMStorageControl, windows_core::IUnknown);
@@ -4932,6 +7393,57 @@ impl IWMDMStorageControl_Vtbl {
         iid == &<IWMDMStorageControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDMStorageControl_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMStorageControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Insert<Identity: windows_core::IUnknownImpl + IWMDMStorageControl_Impl>(this: *mut core::ffi::c_void, fumode: u32, pwszfile: windows_core::PCWSTR, poperation: *mut core::ffi::c_void, pprogress: *mut core::ffi::c_void, ppnewobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMStorageControl_Impl::Insert(this, core::mem::transmute_copy(&fumode), core::mem::transmute(&pwszfile), core::mem::transmute_copy(&poperation), core::mem::transmute_copy(&pprogress)) {
+                    Ok(ok__) => {
+                        ppnewobject.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IWMDMStorageControl_Impl>(this: *mut core::ffi::c_void, fumode: u32, pprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorageControl_Impl::Delete(this, core::mem::transmute_copy(&fumode), core::mem::transmute_copy(&pprogress)).into()
+            }
+        }
+        unsafe extern "system" fn Rename<Identity: windows_core::IUnknownImpl + IWMDMStorageControl_Impl>(this: *mut core::ffi::c_void, fumode: u32, pwsznewname: windows_core::PCWSTR, pprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorageControl_Impl::Rename(this, core::mem::transmute_copy(&fumode), core::mem::transmute(&pwsznewname), core::mem::transmute_copy(&pprogress)).into()
+            }
+        }
+        unsafe extern "system" fn Read<Identity: windows_core::IUnknownImpl + IWMDMStorageControl_Impl>(this: *mut core::ffi::c_void, fumode: u32, pwszfile: windows_core::PCWSTR, pprogress: *mut core::ffi::c_void, poperation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorageControl_Impl::Read(this, core::mem::transmute_copy(&fumode), core::mem::transmute(&pwszfile), core::mem::transmute_copy(&pprogress), core::mem::transmute_copy(&poperation)).into()
+            }
+        }
+        unsafe extern "system" fn Move<Identity: windows_core::IUnknownImpl + IWMDMStorageControl_Impl>(this: *mut core::ffi::c_void, fumode: u32, ptargetobject: *mut core::ffi::c_void, pprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorageControl_Impl::Move(this, core::mem::transmute_copy(&fumode), core::mem::transmute_copy(&ptargetobject), core::mem::transmute_copy(&pprogress)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Insert: Insert::<Identity>,
+            Delete: Delete::<Identity>,
+            Rename: Rename::<Identity>,
+            Read: Read::<Identity>,
+            Move: Move::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMDMStorageControl {}
 windows_core::imp::define_interface!(IWMDMStorageControl2, IWMDMStorageControl2_Vtbl, 0x972c2e88_bd6c_4125_8e09_84f837e637b6);
 impl core::ops::Deref for IWMDMStorageControl2 {
@@ -4976,6 +7488,20 @@ impl IWMDMStorageControl2_Vtbl {
         iid == &<IWMDMStorageControl2 as windows_core::Interface>::IID || iid == &<IWMDMStorageControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDMStorageControl2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMStorageControl2_Vtbl
+where
+    IWMDMStorageControl_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Insert2<Identity: windows_core::IUnknownImpl + IWMDMStorageControl2_Impl>(this: *mut core::ffi::c_void, fumode: u32, pwszfilesource: windows_core::PCWSTR, pwszfiledest: windows_core::PCWSTR, poperation: *mut core::ffi::c_void, pprogress: *mut core::ffi::c_void, punknown: *mut core::ffi::c_void, ppnewobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorageControl2_Impl::Insert2(this, core::mem::transmute_copy(&fumode), core::mem::transmute(&pwszfilesource), core::mem::transmute(&pwszfiledest), core::mem::transmute_copy(&poperation), core::mem::transmute_copy(&pprogress), core::mem::transmute_copy(&punknown), core::mem::transmute_copy(&ppnewobject)).into()
+            }
+        }
+        Self { base__: <IWMDMStorageControl_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Insert2: Insert2::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMDMStorageControl2 {}
 windows_core::imp::define_interface!(IWMDMStorageControl3, IWMDMStorageControl3_Vtbl, 0xb3266365_d4f3_4696_8d53_bd27ec60993a);
 impl core::ops::Deref for IWMDMStorageControl3 {
@@ -5021,6 +7547,20 @@ impl IWMDMStorageControl3_Vtbl {
         iid == &<IWMDMStorageControl3 as windows_core::Interface>::IID || iid == &<IWMDMStorageControl as windows_core::Interface>::IID || iid == &<IWMDMStorageControl2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDMStorageControl3_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMStorageControl3_Vtbl
+where
+    IWMDMStorageControl2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Insert3<Identity: windows_core::IUnknownImpl + IWMDMStorageControl3_Impl>(this: *mut core::ffi::c_void, fumode: u32, futype: u32, pwszfilesource: windows_core::PCWSTR, pwszfiledest: windows_core::PCWSTR, poperation: *mut core::ffi::c_void, pprogress: *mut core::ffi::c_void, pmetadata: *mut core::ffi::c_void, punknown: *mut core::ffi::c_void, ppnewobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorageControl3_Impl::Insert3(this, core::mem::transmute_copy(&fumode), core::mem::transmute_copy(&futype), core::mem::transmute(&pwszfilesource), core::mem::transmute(&pwszfiledest), core::mem::transmute_copy(&poperation), core::mem::transmute_copy(&pprogress), core::mem::transmute_copy(&pmetadata), core::mem::transmute_copy(&punknown), core::mem::transmute_copy(&ppnewobject)).into()
+            }
+        }
+        Self { base__: <IWMDMStorageControl2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Insert3: Insert3::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMDMStorageControl3 {}
 windows_core::imp::define_interface!(IWMDMStorageGlobals, IWMDMStorageGlobals_Vtbl, 0x1dcb3a07_33ed_11d3_8470_00c04f79dbc0);
 windows_core::imp::interface_hierarchy!(IWMDMStorageGlobals, windows_core::IUnknown);
@@ -5148,6 +7688,77 @@ impl IWMDMStorageGlobals_Vtbl {
         iid == &<IWMDMStorageGlobals as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDMStorageGlobals_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDMStorageGlobals_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCapabilities<Identity: windows_core::IUnknownImpl + IWMDMStorageGlobals_Impl>(this: *mut core::ffi::c_void, pdwcapabilities: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMStorageGlobals_Impl::GetCapabilities(this) {
+                    Ok(ok__) => {
+                        pdwcapabilities.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSerialNumber<Identity: windows_core::IUnknownImpl + IWMDMStorageGlobals_Impl>(this: *mut core::ffi::c_void, pserialnum: *mut WMDMID, abmac: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorageGlobals_Impl::GetSerialNumber(this, core::mem::transmute_copy(&pserialnum), core::mem::transmute_copy(&abmac)).into()
+            }
+        }
+        unsafe extern "system" fn GetTotalSize<Identity: windows_core::IUnknownImpl + IWMDMStorageGlobals_Impl>(this: *mut core::ffi::c_void, pdwtotalsizelow: *mut u32, pdwtotalsizehigh: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorageGlobals_Impl::GetTotalSize(this, core::mem::transmute_copy(&pdwtotalsizelow), core::mem::transmute_copy(&pdwtotalsizehigh)).into()
+            }
+        }
+        unsafe extern "system" fn GetTotalFree<Identity: windows_core::IUnknownImpl + IWMDMStorageGlobals_Impl>(this: *mut core::ffi::c_void, pdwfreelow: *mut u32, pdwfreehigh: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorageGlobals_Impl::GetTotalFree(this, core::mem::transmute_copy(&pdwfreelow), core::mem::transmute_copy(&pdwfreehigh)).into()
+            }
+        }
+        unsafe extern "system" fn GetTotalBad<Identity: windows_core::IUnknownImpl + IWMDMStorageGlobals_Impl>(this: *mut core::ffi::c_void, pdwbadlow: *mut u32, pdwbadhigh: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorageGlobals_Impl::GetTotalBad(this, core::mem::transmute_copy(&pdwbadlow), core::mem::transmute_copy(&pdwbadhigh)).into()
+            }
+        }
+        unsafe extern "system" fn GetStatus<Identity: windows_core::IUnknownImpl + IWMDMStorageGlobals_Impl>(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDMStorageGlobals_Impl::GetStatus(this) {
+                    Ok(ok__) => {
+                        pdwstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IWMDMStorageGlobals_Impl>(this: *mut core::ffi::c_void, fumode: u32, pprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDMStorageGlobals_Impl::Initialize(this, core::mem::transmute_copy(&fumode), core::mem::transmute_copy(&pprogress)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCapabilities: GetCapabilities::<Identity>,
+            GetSerialNumber: GetSerialNumber::<Identity>,
+            GetTotalSize: GetTotalSize::<Identity>,
+            GetTotalFree: GetTotalFree::<Identity>,
+            GetTotalBad: GetTotalBad::<Identity>,
+            GetStatus: GetStatus::<Identity>,
+            Initialize: Initialize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMDMStorageGlobals {}
 windows_core::imp::define_interface!(IWMDeviceManager, IWMDeviceManager_Vtbl, 0x1dcb3a00_33ed_11d3_8470_00c04f79dbc0);
 windows_core::imp::interface_hierarchy!(IWMDeviceManager, windows_core::IUnknown);
@@ -5233,6 +7844,55 @@ impl IWMDeviceManager_Vtbl {
         iid == &<IWMDeviceManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDeviceManager_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDeviceManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRevision<Identity: windows_core::IUnknownImpl + IWMDeviceManager_Impl>(this: *mut core::ffi::c_void, pdwrevision: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDeviceManager_Impl::GetRevision(this) {
+                    Ok(ok__) => {
+                        pdwrevision.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDeviceCount<Identity: windows_core::IUnknownImpl + IWMDeviceManager_Impl>(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDeviceManager_Impl::GetDeviceCount(this) {
+                    Ok(ok__) => {
+                        pdwcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumDevices<Identity: windows_core::IUnknownImpl + IWMDeviceManager_Impl>(this: *mut core::ffi::c_void, ppenumdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDeviceManager_Impl::EnumDevices(this) {
+                    Ok(ok__) => {
+                        ppenumdevice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetRevision: GetRevision::<Identity>,
+            GetDeviceCount: GetDeviceCount::<Identity>,
+            EnumDevices: EnumDevices::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMDeviceManager {}
 windows_core::imp::define_interface!(IWMDeviceManager2, IWMDeviceManager2_Vtbl, 0x923e5249_8731_4c5b_9b1c_b8b60b6e46af);
 impl core::ops::Deref for IWMDeviceManager2 {
@@ -5318,6 +7978,49 @@ impl IWMDeviceManager2_Vtbl {
         iid == &<IWMDeviceManager2 as windows_core::Interface>::IID || iid == &<IWMDeviceManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDeviceManager2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDeviceManager2_Vtbl
+where
+    IWMDeviceManager_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDeviceFromCanonicalName<Identity: windows_core::IUnknownImpl + IWMDeviceManager2_Impl>(this: *mut core::ffi::c_void, pwszcanonicalname: windows_core::PCWSTR, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDeviceManager2_Impl::GetDeviceFromCanonicalName(this, core::mem::transmute(&pwszcanonicalname)) {
+                    Ok(ok__) => {
+                        ppdevice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumDevices2<Identity: windows_core::IUnknownImpl + IWMDeviceManager2_Impl>(this: *mut core::ffi::c_void, ppenumdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDeviceManager2_Impl::EnumDevices2(this) {
+                    Ok(ok__) => {
+                        ppenumdevice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Reinitialize<Identity: windows_core::IUnknownImpl + IWMDeviceManager2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDeviceManager2_Impl::Reinitialize(this).into()
+            }
+        }
+        Self {
+            base__: <IWMDeviceManager_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDeviceFromCanonicalName: GetDeviceFromCanonicalName::<Identity>,
+            EnumDevices2: EnumDevices2::<Identity>,
+            Reinitialize: Reinitialize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMDeviceManager2 {}
 windows_core::imp::define_interface!(IWMDeviceManager3, IWMDeviceManager3_Vtbl, 0xaf185c41_100d_46ed_be2e_9ce8c44594ef);
 impl core::ops::Deref for IWMDeviceManager3 {
@@ -5355,6 +8058,23 @@ impl IWMDeviceManager3_Vtbl {
         iid == &<IWMDeviceManager3 as windows_core::Interface>::IID || iid == &<IWMDeviceManager as windows_core::Interface>::IID || iid == &<IWMDeviceManager2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDeviceManager3_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDeviceManager3_Vtbl
+where
+    IWMDeviceManager2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetDeviceEnumPreference<Identity: windows_core::IUnknownImpl + IWMDeviceManager3_Impl>(this: *mut core::ffi::c_void, dwenumpref: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDeviceManager3_Impl::SetDeviceEnumPreference(this, core::mem::transmute_copy(&dwenumpref)).into()
+            }
+        }
+        Self {
+            base__: <IWMDeviceManager2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetDeviceEnumPreference: SetDeviceEnumPreference::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMDeviceManager3 {}
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -207,6 +207,43 @@ impl IDMOQualityControl_Vtbl {
         iid == &<IDMOQualityControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDMOQualityControl_Impl> windows_core::imp::TearOffVtable<Identity> for IDMOQualityControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetNow<Identity: windows_core::IUnknownImpl + IDMOQualityControl_Impl>(this: *mut core::ffi::c_void, rtnow: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMOQualityControl_Impl::SetNow(this, core::mem::transmute_copy(&rtnow)).into()
+            }
+        }
+        unsafe extern "system" fn SetStatus<Identity: windows_core::IUnknownImpl + IDMOQualityControl_Impl>(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMOQualityControl_Impl::SetStatus(this, core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn GetStatus<Identity: windows_core::IUnknownImpl + IDMOQualityControl_Impl>(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDMOQualityControl_Impl::GetStatus(this) {
+                    Ok(ok__) => {
+                        pdwflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetNow: SetNow::<Identity>,
+            SetStatus: SetStatus::<Identity>,
+            GetStatus: GetStatus::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDMOQualityControl {}
 windows_core::imp::define_interface!(IDMOVideoOutputOptimizations, IDMOVideoOutputOptimizations_Vtbl, 0xbe8f4f4e_5b16_4d29_b350_7f6b5d9298ac);
 windows_core::imp::interface_hierarchy!(IDMOVideoOutputOptimizations, windows_core::IUnknown);
@@ -304,6 +341,62 @@ impl IDMOVideoOutputOptimizations_Vtbl {
         iid == &<IDMOVideoOutputOptimizations as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDMOVideoOutputOptimizations_Impl> windows_core::imp::TearOffVtable<Identity> for IDMOVideoOutputOptimizations_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryOperationModePreferences<Identity: windows_core::IUnknownImpl + IDMOVideoOutputOptimizations_Impl>(this: *mut core::ffi::c_void, uloutputstreamindex: u32, pdwrequestedcapabilities: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDMOVideoOutputOptimizations_Impl::QueryOperationModePreferences(this, core::mem::transmute_copy(&uloutputstreamindex)) {
+                    Ok(ok__) => {
+                        pdwrequestedcapabilities.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetOperationMode<Identity: windows_core::IUnknownImpl + IDMOVideoOutputOptimizations_Impl>(this: *mut core::ffi::c_void, uloutputstreamindex: u32, dwenabledfeatures: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDMOVideoOutputOptimizations_Impl::SetOperationMode(this, core::mem::transmute_copy(&uloutputstreamindex), core::mem::transmute_copy(&dwenabledfeatures)).into()
+            }
+        }
+        unsafe extern "system" fn GetCurrentOperationMode<Identity: windows_core::IUnknownImpl + IDMOVideoOutputOptimizations_Impl>(this: *mut core::ffi::c_void, uloutputstreamindex: u32, pdwenabledfeatures: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDMOVideoOutputOptimizations_Impl::GetCurrentOperationMode(this, core::mem::transmute_copy(&uloutputstreamindex)) {
+                    Ok(ok__) => {
+                        pdwenabledfeatures.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCurrentSampleRequirements<Identity: windows_core::IUnknownImpl + IDMOVideoOutputOptimizations_Impl>(this: *mut core::ffi::c_void, uloutputstreamindex: u32, pdwrequestedfeatures: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDMOVideoOutputOptimizations_Impl::GetCurrentSampleRequirements(this, core::mem::transmute_copy(&uloutputstreamindex)) {
+                    Ok(ok__) => {
+                        pdwrequestedfeatures.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryOperationModePreferences: QueryOperationModePreferences::<Identity>,
+            SetOperationMode: SetOperationMode::<Identity>,
+            GetCurrentOperationMode: GetCurrentOperationMode::<Identity>,
+            GetCurrentSampleRequirements: GetCurrentSampleRequirements::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDMOVideoOutputOptimizations {}
 windows_core::imp::define_interface!(IEnumDMO, IEnumDMO_Vtbl, 0x2c3cd98a_2bfa_4a53_9c27_5249ba64ba0f);
 windows_core::imp::interface_hierarchy!(IEnumDMO, windows_core::IUnknown);
@@ -383,6 +476,50 @@ impl IEnumDMO_Vtbl {
         iid == &<IEnumDMO as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumDMO_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumDMO_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumDMO_Impl>(this: *mut core::ffi::c_void, citemstofetch: u32, pclsid: *mut windows_core::GUID, names: *mut windows_core::PWSTR, pcitemsfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumDMO_Impl::Next(this, core::mem::transmute_copy(&citemstofetch), core::mem::transmute_copy(&pclsid), core::mem::transmute_copy(&names), core::mem::transmute_copy(&pcitemsfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumDMO_Impl>(this: *mut core::ffi::c_void, citemstoskip: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumDMO_Impl::Skip(this, core::mem::transmute_copy(&citemstoskip)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumDMO_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumDMO_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumDMO_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumDMO_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumDMO {}
 windows_core::imp::define_interface!(IMediaBuffer, IMediaBuffer_Vtbl, 0x59eff8b9_938c_4a26_82f2_95cb84cdc837);
 windows_core::imp::interface_hierarchy!(IMediaBuffer, windows_core::IUnknown);
@@ -450,6 +587,43 @@ impl IMediaBuffer_Vtbl {
         iid == &<IMediaBuffer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMediaBuffer_Impl> windows_core::imp::TearOffVtable<Identity> for IMediaBuffer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetLength<Identity: windows_core::IUnknownImpl + IMediaBuffer_Impl>(this: *mut core::ffi::c_void, cblength: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaBuffer_Impl::SetLength(this, core::mem::transmute_copy(&cblength)).into()
+            }
+        }
+        unsafe extern "system" fn GetMaxLength<Identity: windows_core::IUnknownImpl + IMediaBuffer_Impl>(this: *mut core::ffi::c_void, pcbmaxlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaBuffer_Impl::GetMaxLength(this) {
+                    Ok(ok__) => {
+                        pcbmaxlength.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetBufferAndLength<Identity: windows_core::IUnknownImpl + IMediaBuffer_Impl>(this: *mut core::ffi::c_void, ppbuffer: *mut *mut u8, pcblength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaBuffer_Impl::GetBufferAndLength(this, core::mem::transmute_copy(&ppbuffer), core::mem::transmute_copy(&pcblength)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetLength: SetLength::<Identity>,
+            GetMaxLength: GetMaxLength::<Identity>,
+            GetBufferAndLength: GetBufferAndLength::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMediaBuffer {}
 windows_core::imp::define_interface!(IMediaObject, IMediaObject_Vtbl, 0xd8ad0f58_5494_4102_97c5_ec798e59bcf4);
 windows_core::imp::interface_hierarchy!(IMediaObject, windows_core::IUnknown);
@@ -763,6 +937,187 @@ impl IMediaObject_Vtbl {
         iid == &<IMediaObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMediaObject_Impl> windows_core::imp::TearOffVtable<Identity> for IMediaObject_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStreamCount<Identity: windows_core::IUnknownImpl + IMediaObject_Impl>(this: *mut core::ffi::c_void, pcinputstreams: *mut u32, pcoutputstreams: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaObject_Impl::GetStreamCount(this, core::mem::transmute_copy(&pcinputstreams), core::mem::transmute_copy(&pcoutputstreams)).into()
+            }
+        }
+        unsafe extern "system" fn GetInputStreamInfo<Identity: windows_core::IUnknownImpl + IMediaObject_Impl>(this: *mut core::ffi::c_void, dwinputstreamindex: u32, pdwflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaObject_Impl::GetInputStreamInfo(this, core::mem::transmute_copy(&dwinputstreamindex)) {
+                    Ok(ok__) => {
+                        pdwflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOutputStreamInfo<Identity: windows_core::IUnknownImpl + IMediaObject_Impl>(this: *mut core::ffi::c_void, dwoutputstreamindex: u32, pdwflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaObject_Impl::GetOutputStreamInfo(this, core::mem::transmute_copy(&dwoutputstreamindex)) {
+                    Ok(ok__) => {
+                        pdwflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetInputType<Identity: windows_core::IUnknownImpl + IMediaObject_Impl>(this: *mut core::ffi::c_void, dwinputstreamindex: u32, dwtypeindex: u32, pmt: *mut DMO_MEDIA_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaObject_Impl::GetInputType(this, core::mem::transmute_copy(&dwinputstreamindex), core::mem::transmute_copy(&dwtypeindex), core::mem::transmute_copy(&pmt)).into()
+            }
+        }
+        unsafe extern "system" fn GetOutputType<Identity: windows_core::IUnknownImpl + IMediaObject_Impl>(this: *mut core::ffi::c_void, dwoutputstreamindex: u32, dwtypeindex: u32, pmt: *mut DMO_MEDIA_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaObject_Impl::GetOutputType(this, core::mem::transmute_copy(&dwoutputstreamindex), core::mem::transmute_copy(&dwtypeindex), core::mem::transmute_copy(&pmt)).into()
+            }
+        }
+        unsafe extern "system" fn SetInputType<Identity: windows_core::IUnknownImpl + IMediaObject_Impl>(this: *mut core::ffi::c_void, dwinputstreamindex: u32, pmt: *const DMO_MEDIA_TYPE, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaObject_Impl::SetInputType(this, core::mem::transmute_copy(&dwinputstreamindex), core::mem::transmute_copy(&pmt), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn SetOutputType<Identity: windows_core::IUnknownImpl + IMediaObject_Impl>(this: *mut core::ffi::c_void, dwoutputstreamindex: u32, pmt: *const DMO_MEDIA_TYPE, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaObject_Impl::SetOutputType(this, core::mem::transmute_copy(&dwoutputstreamindex), core::mem::transmute_copy(&pmt), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn GetInputCurrentType<Identity: windows_core::IUnknownImpl + IMediaObject_Impl>(this: *mut core::ffi::c_void, dwinputstreamindex: u32, pmt: *mut DMO_MEDIA_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaObject_Impl::GetInputCurrentType(this, core::mem::transmute_copy(&dwinputstreamindex), core::mem::transmute_copy(&pmt)).into()
+            }
+        }
+        unsafe extern "system" fn GetOutputCurrentType<Identity: windows_core::IUnknownImpl + IMediaObject_Impl>(this: *mut core::ffi::c_void, dwoutputstreamindex: u32, pmt: *mut DMO_MEDIA_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaObject_Impl::GetOutputCurrentType(this, core::mem::transmute_copy(&dwoutputstreamindex), core::mem::transmute_copy(&pmt)).into()
+            }
+        }
+        unsafe extern "system" fn GetInputSizeInfo<Identity: windows_core::IUnknownImpl + IMediaObject_Impl>(this: *mut core::ffi::c_void, dwinputstreamindex: u32, pcbsize: *mut u32, pcbmaxlookahead: *mut u32, pcbalignment: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaObject_Impl::GetInputSizeInfo(this, core::mem::transmute_copy(&dwinputstreamindex), core::mem::transmute_copy(&pcbsize), core::mem::transmute_copy(&pcbmaxlookahead), core::mem::transmute_copy(&pcbalignment)).into()
+            }
+        }
+        unsafe extern "system" fn GetOutputSizeInfo<Identity: windows_core::IUnknownImpl + IMediaObject_Impl>(this: *mut core::ffi::c_void, dwoutputstreamindex: u32, pcbsize: *mut u32, pcbalignment: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaObject_Impl::GetOutputSizeInfo(this, core::mem::transmute_copy(&dwoutputstreamindex), core::mem::transmute_copy(&pcbsize), core::mem::transmute_copy(&pcbalignment)).into()
+            }
+        }
+        unsafe extern "system" fn GetInputMaxLatency<Identity: windows_core::IUnknownImpl + IMediaObject_Impl>(this: *mut core::ffi::c_void, dwinputstreamindex: u32, prtmaxlatency: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaObject_Impl::GetInputMaxLatency(this, core::mem::transmute_copy(&dwinputstreamindex)) {
+                    Ok(ok__) => {
+                        prtmaxlatency.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetInputMaxLatency<Identity: windows_core::IUnknownImpl + IMediaObject_Impl>(this: *mut core::ffi::c_void, dwinputstreamindex: u32, rtmaxlatency: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaObject_Impl::SetInputMaxLatency(this, core::mem::transmute_copy(&dwinputstreamindex), core::mem::transmute_copy(&rtmaxlatency)).into()
+            }
+        }
+        unsafe extern "system" fn Flush<Identity: windows_core::IUnknownImpl + IMediaObject_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaObject_Impl::Flush(this).into()
+            }
+        }
+        unsafe extern "system" fn Discontinuity<Identity: windows_core::IUnknownImpl + IMediaObject_Impl>(this: *mut core::ffi::c_void, dwinputstreamindex: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaObject_Impl::Discontinuity(this, core::mem::transmute_copy(&dwinputstreamindex)).into()
+            }
+        }
+        unsafe extern "system" fn AllocateStreamingResources<Identity: windows_core::IUnknownImpl + IMediaObject_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaObject_Impl::AllocateStreamingResources(this).into()
+            }
+        }
+        unsafe extern "system" fn FreeStreamingResources<Identity: windows_core::IUnknownImpl + IMediaObject_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaObject_Impl::FreeStreamingResources(this).into()
+            }
+        }
+        unsafe extern "system" fn GetInputStatus<Identity: windows_core::IUnknownImpl + IMediaObject_Impl>(this: *mut core::ffi::c_void, dwinputstreamindex: u32, dwflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaObject_Impl::GetInputStatus(this, core::mem::transmute_copy(&dwinputstreamindex)) {
+                    Ok(ok__) => {
+                        dwflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ProcessInput<Identity: windows_core::IUnknownImpl + IMediaObject_Impl>(this: *mut core::ffi::c_void, dwinputstreamindex: u32, pbuffer: *mut core::ffi::c_void, dwflags: u32, rttimestamp: i64, rttimelength: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaObject_Impl::ProcessInput(this, core::mem::transmute_copy(&dwinputstreamindex), core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&rttimestamp), core::mem::transmute_copy(&rttimelength)).into()
+            }
+        }
+        unsafe extern "system" fn ProcessOutput<Identity: windows_core::IUnknownImpl + IMediaObject_Impl>(this: *mut core::ffi::c_void, dwflags: u32, coutputbuffercount: u32, poutputbuffers: *mut DMO_OUTPUT_DATA_BUFFER, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaObject_Impl::ProcessOutput(this, core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&coutputbuffercount), core::mem::transmute_copy(&poutputbuffers), core::mem::transmute_copy(&pdwstatus)).into()
+            }
+        }
+        unsafe extern "system" fn Lock<Identity: windows_core::IUnknownImpl + IMediaObject_Impl>(this: *mut core::ffi::c_void, block: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaObject_Impl::Lock(this, core::mem::transmute_copy(&block)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStreamCount: GetStreamCount::<Identity>,
+            GetInputStreamInfo: GetInputStreamInfo::<Identity>,
+            GetOutputStreamInfo: GetOutputStreamInfo::<Identity>,
+            GetInputType: GetInputType::<Identity>,
+            GetOutputType: GetOutputType::<Identity>,
+            SetInputType: SetInputType::<Identity>,
+            SetOutputType: SetOutputType::<Identity>,
+            GetInputCurrentType: GetInputCurrentType::<Identity>,
+            GetOutputCurrentType: GetOutputCurrentType::<Identity>,
+            GetInputSizeInfo: GetInputSizeInfo::<Identity>,
+            GetOutputSizeInfo: GetOutputSizeInfo::<Identity>,
+            GetInputMaxLatency: GetInputMaxLatency::<Identity>,
+            SetInputMaxLatency: SetInputMaxLatency::<Identity>,
+            Flush: Flush::<Identity>,
+            Discontinuity: Discontinuity::<Identity>,
+            AllocateStreamingResources: AllocateStreamingResources::<Identity>,
+            FreeStreamingResources: FreeStreamingResources::<Identity>,
+            GetInputStatus: GetInputStatus::<Identity>,
+            ProcessInput: ProcessInput::<Identity>,
+            ProcessOutput: ProcessOutput::<Identity>,
+            Lock: Lock::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMediaObject {}
 windows_core::imp::define_interface!(IMediaObjectInPlace, IMediaObjectInPlace_Vtbl, 0x651b9ad0_0fc7_4aa9_9538_d89931010741);
 windows_core::imp::interface_hierarchy!(IMediaObjectInPlace, windows_core::IUnknown);
@@ -839,6 +1194,49 @@ impl IMediaObjectInPlace_Vtbl {
         iid == &<IMediaObjectInPlace as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMediaObjectInPlace_Impl> windows_core::imp::TearOffVtable<Identity> for IMediaObjectInPlace_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Process<Identity: windows_core::IUnknownImpl + IMediaObjectInPlace_Impl>(this: *mut core::ffi::c_void, ulsize: u32, pdata: *mut u8, reftimestart: i64, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMediaObjectInPlace_Impl::Process(this, core::mem::transmute_copy(&ulsize), core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&reftimestart), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IMediaObjectInPlace_Impl>(this: *mut core::ffi::c_void, ppmediaobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaObjectInPlace_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppmediaobject.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLatency<Identity: windows_core::IUnknownImpl + IMediaObjectInPlace_Impl>(this: *mut core::ffi::c_void, platencytime: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMediaObjectInPlace_Impl::GetLatency(this) {
+                    Ok(ok__) => {
+                        platencytime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Process: Process::<Identity>,
+            Clone: Clone::<Identity>,
+            GetLatency: GetLatency::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMediaObjectInPlace {}
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -382,6 +382,30 @@ impl IKsAggregateControl_Vtbl {
         iid == &<IKsAggregateControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IKsAggregateControl_Impl> windows_core::imp::TearOffVtable<Identity> for IKsAggregateControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn KsAddAggregate<Identity: windows_core::IUnknownImpl + IKsAggregateControl_Impl>(this: *mut core::ffi::c_void, aggregateclass: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsAggregateControl_Impl::KsAddAggregate(this, core::mem::transmute_copy(&aggregateclass)).into()
+            }
+        }
+        unsafe extern "system" fn KsRemoveAggregate<Identity: windows_core::IUnknownImpl + IKsAggregateControl_Impl>(this: *mut core::ffi::c_void, aggregateclass: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsAggregateControl_Impl::KsRemoveAggregate(this, core::mem::transmute_copy(&aggregateclass)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            KsAddAggregate: KsAddAggregate::<Identity>,
+            KsRemoveAggregate: KsRemoveAggregate::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IKsAggregateControl {}
 windows_core::imp::define_interface!(IKsAllocator, IKsAllocator_Vtbl, 0x8da64899_c0d9_11d0_8413_0000f822fe8a);
 windows_core::imp::interface_hierarchy!(IKsAllocator, windows_core::IUnknown);
@@ -452,6 +476,44 @@ impl IKsAllocator_Vtbl {
         iid == &<IKsAllocator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IKsAllocator_Impl> windows_core::imp::TearOffVtable<Identity> for IKsAllocator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn KsGetAllocatorHandle<Identity: windows_core::IUnknownImpl + IKsAllocator_Impl>(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsAllocator_Impl::KsGetAllocatorHandle(this)
+            }
+        }
+        unsafe extern "system" fn KsGetAllocatorMode<Identity: windows_core::IUnknownImpl + IKsAllocator_Impl>(this: *mut core::ffi::c_void) -> KSALLOCATORMODE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsAllocator_Impl::KsGetAllocatorMode(this)
+            }
+        }
+        unsafe extern "system" fn KsGetAllocatorStatus<Identity: windows_core::IUnknownImpl + IKsAllocator_Impl>(this: *mut core::ffi::c_void, allocatorstatus: *mut KSSTREAMALLOCATOR_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsAllocator_Impl::KsGetAllocatorStatus(this, core::mem::transmute_copy(&allocatorstatus)).into()
+            }
+        }
+        unsafe extern "system" fn KsSetAllocatorMode<Identity: windows_core::IUnknownImpl + IKsAllocator_Impl>(this: *mut core::ffi::c_void, mode: KSALLOCATORMODE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsAllocator_Impl::KsSetAllocatorMode(this, core::mem::transmute_copy(&mode))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            KsGetAllocatorHandle: KsGetAllocatorHandle::<Identity>,
+            KsGetAllocatorMode: KsGetAllocatorMode::<Identity>,
+            KsGetAllocatorStatus: KsGetAllocatorStatus::<Identity>,
+            KsSetAllocatorMode: KsSetAllocatorMode::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IKsAllocator {}
 windows_core::imp::define_interface!(IKsAllocatorEx, IKsAllocatorEx_Vtbl, 0x091bb63a_603f_11d1_b067_00a0c9062802);
 impl core::ops::Deref for IKsAllocatorEx {
@@ -531,6 +593,44 @@ impl IKsAllocatorEx_Vtbl {
         iid == &<IKsAllocatorEx as windows_core::Interface>::IID || iid == &<IKsAllocator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IKsAllocatorEx_Impl> windows_core::imp::TearOffVtable<Identity> for IKsAllocatorEx_Vtbl
+where
+    IKsAllocator_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn KsGetProperties<Identity: windows_core::IUnknownImpl + IKsAllocatorEx_Impl>(this: *mut core::ffi::c_void) -> *mut ALLOCATOR_PROPERTIES_EX {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsAllocatorEx_Impl::KsGetProperties(this)
+            }
+        }
+        unsafe extern "system" fn KsSetProperties<Identity: windows_core::IUnknownImpl + IKsAllocatorEx_Impl>(this: *mut core::ffi::c_void, param0: *const ALLOCATOR_PROPERTIES_EX) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsAllocatorEx_Impl::KsSetProperties(this, core::mem::transmute_copy(&param0))
+            }
+        }
+        unsafe extern "system" fn KsSetAllocatorHandle<Identity: windows_core::IUnknownImpl + IKsAllocatorEx_Impl>(this: *mut core::ffi::c_void, allocatorhandle: super::super::Foundation::HANDLE) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsAllocatorEx_Impl::KsSetAllocatorHandle(this, core::mem::transmute_copy(&allocatorhandle))
+            }
+        }
+        unsafe extern "system" fn KsCreateAllocatorAndGetHandle<Identity: windows_core::IUnknownImpl + IKsAllocatorEx_Impl>(this: *mut core::ffi::c_void, kspin: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsAllocatorEx_Impl::KsCreateAllocatorAndGetHandle(this, core::mem::transmute_copy(&kspin))
+            }
+        }
+        Self {
+            base__: <IKsAllocator_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            KsGetProperties: KsGetProperties::<Identity>,
+            KsSetProperties: KsSetProperties::<Identity>,
+            KsSetAllocatorHandle: KsSetAllocatorHandle::<Identity>,
+            KsCreateAllocatorAndGetHandle: KsCreateAllocatorAndGetHandle::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IKsAllocatorEx {}
 windows_core::imp::define_interface!(IKsClockPropertySet, IKsClockPropertySet_Vtbl, 0x5c5cbd84_e755_11d0_ac18_00a0c9223196);
 windows_core::imp::interface_hierarchy!(IKsClockPropertySet, windows_core::IUnknown);
@@ -727,6 +827,122 @@ impl IKsClockPropertySet_Vtbl {
         iid == &<IKsClockPropertySet as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IKsClockPropertySet_Impl> windows_core::imp::TearOffVtable<Identity> for IKsClockPropertySet_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn KsGetTime<Identity: windows_core::IUnknownImpl + IKsClockPropertySet_Impl>(this: *mut core::ffi::c_void, time: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsClockPropertySet_Impl::KsGetTime(this) {
+                    Ok(ok__) => {
+                        time.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn KsSetTime<Identity: windows_core::IUnknownImpl + IKsClockPropertySet_Impl>(this: *mut core::ffi::c_void, time: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsClockPropertySet_Impl::KsSetTime(this, core::mem::transmute_copy(&time)).into()
+            }
+        }
+        unsafe extern "system" fn KsGetPhysicalTime<Identity: windows_core::IUnknownImpl + IKsClockPropertySet_Impl>(this: *mut core::ffi::c_void, time: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsClockPropertySet_Impl::KsGetPhysicalTime(this) {
+                    Ok(ok__) => {
+                        time.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn KsSetPhysicalTime<Identity: windows_core::IUnknownImpl + IKsClockPropertySet_Impl>(this: *mut core::ffi::c_void, time: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsClockPropertySet_Impl::KsSetPhysicalTime(this, core::mem::transmute_copy(&time)).into()
+            }
+        }
+        unsafe extern "system" fn KsGetCorrelatedTime<Identity: windows_core::IUnknownImpl + IKsClockPropertySet_Impl>(this: *mut core::ffi::c_void, correlatedtime: *mut KSCORRELATED_TIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsClockPropertySet_Impl::KsGetCorrelatedTime(this) {
+                    Ok(ok__) => {
+                        correlatedtime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn KsSetCorrelatedTime<Identity: windows_core::IUnknownImpl + IKsClockPropertySet_Impl>(this: *mut core::ffi::c_void, correlatedtime: *const KSCORRELATED_TIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsClockPropertySet_Impl::KsSetCorrelatedTime(this, core::mem::transmute_copy(&correlatedtime)).into()
+            }
+        }
+        unsafe extern "system" fn KsGetCorrelatedPhysicalTime<Identity: windows_core::IUnknownImpl + IKsClockPropertySet_Impl>(this: *mut core::ffi::c_void, correlatedtime: *mut KSCORRELATED_TIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsClockPropertySet_Impl::KsGetCorrelatedPhysicalTime(this) {
+                    Ok(ok__) => {
+                        correlatedtime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn KsSetCorrelatedPhysicalTime<Identity: windows_core::IUnknownImpl + IKsClockPropertySet_Impl>(this: *mut core::ffi::c_void, correlatedtime: *const KSCORRELATED_TIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsClockPropertySet_Impl::KsSetCorrelatedPhysicalTime(this, core::mem::transmute_copy(&correlatedtime)).into()
+            }
+        }
+        unsafe extern "system" fn KsGetResolution<Identity: windows_core::IUnknownImpl + IKsClockPropertySet_Impl>(this: *mut core::ffi::c_void, resolution: *mut KSRESOLUTION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsClockPropertySet_Impl::KsGetResolution(this) {
+                    Ok(ok__) => {
+                        resolution.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn KsGetState<Identity: windows_core::IUnknownImpl + IKsClockPropertySet_Impl>(this: *mut core::ffi::c_void, state: *mut KSSTATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsClockPropertySet_Impl::KsGetState(this) {
+                    Ok(ok__) => {
+                        state.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            KsGetTime: KsGetTime::<Identity>,
+            KsSetTime: KsSetTime::<Identity>,
+            KsGetPhysicalTime: KsGetPhysicalTime::<Identity>,
+            KsSetPhysicalTime: KsSetPhysicalTime::<Identity>,
+            KsGetCorrelatedTime: KsGetCorrelatedTime::<Identity>,
+            KsSetCorrelatedTime: KsSetCorrelatedTime::<Identity>,
+            KsGetCorrelatedPhysicalTime: KsGetCorrelatedPhysicalTime::<Identity>,
+            KsSetCorrelatedPhysicalTime: KsSetCorrelatedPhysicalTime::<Identity>,
+            KsGetResolution: KsGetResolution::<Identity>,
+            KsGetState: KsGetState::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IKsClockPropertySet {}
 windows_core::imp::define_interface!(IKsControl, IKsControl_Vtbl, 0x28f54685_06fd_11d2_b27a_00a0c9223196);
 windows_core::imp::interface_hierarchy!(IKsControl, windows_core::IUnknown);
@@ -785,6 +1001,37 @@ impl IKsControl_Vtbl {
         iid == &<IKsControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IKsControl_Impl> windows_core::imp::TearOffVtable<Identity> for IKsControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn KsProperty<Identity: windows_core::IUnknownImpl + IKsControl_Impl>(this: *mut core::ffi::c_void, property: *const KSIDENTIFIER, propertylength: u32, propertydata: *mut core::ffi::c_void, datalength: u32, bytesreturned: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsControl_Impl::KsProperty(this, core::mem::transmute_copy(&property), core::mem::transmute_copy(&propertylength), core::mem::transmute_copy(&propertydata), core::mem::transmute_copy(&datalength), core::mem::transmute_copy(&bytesreturned)).into()
+            }
+        }
+        unsafe extern "system" fn KsMethod<Identity: windows_core::IUnknownImpl + IKsControl_Impl>(this: *mut core::ffi::c_void, method: *const KSIDENTIFIER, methodlength: u32, methoddata: *mut core::ffi::c_void, datalength: u32, bytesreturned: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsControl_Impl::KsMethod(this, core::mem::transmute_copy(&method), core::mem::transmute_copy(&methodlength), core::mem::transmute_copy(&methoddata), core::mem::transmute_copy(&datalength), core::mem::transmute_copy(&bytesreturned)).into()
+            }
+        }
+        unsafe extern "system" fn KsEvent<Identity: windows_core::IUnknownImpl + IKsControl_Impl>(this: *mut core::ffi::c_void, event: *const KSIDENTIFIER, eventlength: u32, eventdata: *mut core::ffi::c_void, datalength: u32, bytesreturned: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsControl_Impl::KsEvent(this, core::mem::transmute_copy(&event), core::mem::transmute_copy(&eventlength), core::mem::transmute_copy(&eventdata), core::mem::transmute_copy(&datalength), core::mem::transmute_copy(&bytesreturned)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            KsProperty: KsProperty::<Identity>,
+            KsMethod: KsMethod::<Identity>,
+            KsEvent: KsEvent::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IKsControl {}
 windows_core::imp::define_interface!(IKsDataTypeCompletion, IKsDataTypeCompletion_Vtbl, 0x827d1a0e_0f73_11d2_b27a_00a0c9223196);
 windows_core::imp::interface_hierarchy!(IKsDataTypeCompletion, windows_core::IUnknown);
@@ -823,6 +1070,24 @@ impl IKsDataTypeCompletion_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Media_MediaFoundation")]
+impl<Identity: windows_core::IUnknownImpl + IKsDataTypeCompletion_Impl> windows_core::imp::TearOffVtable<Identity> for IKsDataTypeCompletion_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn KsCompleteMediaType<Identity: windows_core::IUnknownImpl + IKsDataTypeCompletion_Impl>(this: *mut core::ffi::c_void, filterhandle: super::super::Foundation::HANDLE, pinfactoryid: u32, ammediatype: *mut super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsDataTypeCompletion_Impl::KsCompleteMediaType(this, core::mem::transmute_copy(&filterhandle), core::mem::transmute_copy(&pinfactoryid), core::mem::transmute_copy(&ammediatype)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            KsCompleteMediaType: KsCompleteMediaType::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Media_MediaFoundation")]
 impl windows_core::RuntimeName for IKsDataTypeCompletion {}
 windows_core::imp::define_interface!(IKsDataTypeHandler, IKsDataTypeHandler_Vtbl, 0x5ffbaa02_49a3_11d0_9f36_00aa00a216a1);
 windows_core::imp::interface_hierarchy!(IKsDataTypeHandler, windows_core::IUnknown);
@@ -935,6 +1200,58 @@ impl IKsDataTypeHandler_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Media_DirectShow", feature = "Win32_Media_MediaFoundation"))]
+impl<Identity: windows_core::IUnknownImpl + IKsDataTypeHandler_Impl> windows_core::imp::TearOffVtable<Identity> for IKsDataTypeHandler_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn KsCompleteIoOperation<Identity: windows_core::IUnknownImpl + IKsDataTypeHandler_Impl>(this: *mut core::ffi::c_void, sample: *mut core::ffi::c_void, streamheader: *mut core::ffi::c_void, iooperation: KSIOOPERATION, cancelled: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsDataTypeHandler_Impl::KsCompleteIoOperation(this, core::mem::transmute_copy(&sample), core::mem::transmute_copy(&streamheader), core::mem::transmute_copy(&iooperation), core::mem::transmute_copy(&cancelled)).into()
+            }
+        }
+        unsafe extern "system" fn KsIsMediaTypeInRanges<Identity: windows_core::IUnknownImpl + IKsDataTypeHandler_Impl>(this: *mut core::ffi::c_void, dataranges: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsDataTypeHandler_Impl::KsIsMediaTypeInRanges(this, core::mem::transmute_copy(&dataranges)).into()
+            }
+        }
+        unsafe extern "system" fn KsPrepareIoOperation<Identity: windows_core::IUnknownImpl + IKsDataTypeHandler_Impl>(this: *mut core::ffi::c_void, sample: *mut core::ffi::c_void, streamheader: *mut core::ffi::c_void, iooperation: KSIOOPERATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsDataTypeHandler_Impl::KsPrepareIoOperation(this, core::mem::transmute_copy(&sample), core::mem::transmute_copy(&streamheader), core::mem::transmute_copy(&iooperation)).into()
+            }
+        }
+        unsafe extern "system" fn KsQueryExtendedSize<Identity: windows_core::IUnknownImpl + IKsDataTypeHandler_Impl>(this: *mut core::ffi::c_void, extendedsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsDataTypeHandler_Impl::KsQueryExtendedSize(this) {
+                    Ok(ok__) => {
+                        extendedsize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn KsSetMediaType<Identity: windows_core::IUnknownImpl + IKsDataTypeHandler_Impl>(this: *mut core::ffi::c_void, ammediatype: *const super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsDataTypeHandler_Impl::KsSetMediaType(this, core::mem::transmute_copy(&ammediatype)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            KsCompleteIoOperation: KsCompleteIoOperation::<Identity>,
+            KsIsMediaTypeInRanges: KsIsMediaTypeInRanges::<Identity>,
+            KsPrepareIoOperation: KsPrepareIoOperation::<Identity>,
+            KsQueryExtendedSize: KsQueryExtendedSize::<Identity>,
+            KsSetMediaType: KsSetMediaType::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Media_DirectShow", feature = "Win32_Media_MediaFoundation"))]
 impl windows_core::RuntimeName for IKsDataTypeHandler {}
 windows_core::imp::define_interface!(IKsFormatSupport, IKsFormatSupport_Vtbl, 0x3cb4a69d_bb6f_4d2b_95b7_452d2c155db5);
 windows_core::imp::interface_hierarchy!(IKsFormatSupport, windows_core::IUnknown);
@@ -990,6 +1307,36 @@ impl IKsFormatSupport_Vtbl {
         iid == &<IKsFormatSupport as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IKsFormatSupport_Impl> windows_core::imp::TearOffVtable<Identity> for IKsFormatSupport_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsFormatSupported<Identity: windows_core::IUnknownImpl + IKsFormatSupport_Impl>(this: *mut core::ffi::c_void, pksformat: *mut KSDATAFORMAT, cbformat: u32, pbsupported: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsFormatSupport_Impl::IsFormatSupported(this, core::mem::transmute_copy(&pksformat), core::mem::transmute_copy(&cbformat), core::mem::transmute_copy(&pbsupported)).into()
+            }
+        }
+        unsafe extern "system" fn GetDevicePreferredFormat<Identity: windows_core::IUnknownImpl + IKsFormatSupport_Impl>(this: *mut core::ffi::c_void, ppksformat: *mut *mut KSDATAFORMAT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsFormatSupport_Impl::GetDevicePreferredFormat(this) {
+                    Ok(ok__) => {
+                        ppksformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsFormatSupported: IsFormatSupported::<Identity>,
+            GetDevicePreferredFormat: GetDevicePreferredFormat::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IKsFormatSupport {}
 windows_core::imp::define_interface!(IKsInterfaceHandler, IKsInterfaceHandler_Vtbl, 0xd3abc7e0_9a61_11d0_a40d_00a0c9223196);
 windows_core::imp::interface_hierarchy!(IKsInterfaceHandler, windows_core::IUnknown);
@@ -1061,6 +1408,38 @@ impl IKsInterfaceHandler_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Media_DirectShow")]
+impl<Identity: windows_core::IUnknownImpl + IKsInterfaceHandler_Impl> windows_core::imp::TearOffVtable<Identity> for IKsInterfaceHandler_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn KsSetPin<Identity: windows_core::IUnknownImpl + IKsInterfaceHandler_Impl>(this: *mut core::ffi::c_void, kspin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsInterfaceHandler_Impl::KsSetPin(this, core::mem::transmute_copy(&kspin)).into()
+            }
+        }
+        unsafe extern "system" fn KsProcessMediaSamples<Identity: windows_core::IUnknownImpl + IKsInterfaceHandler_Impl>(this: *mut core::ffi::c_void, ksdatatypehandler: *mut core::ffi::c_void, samplelist: *const *mut core::ffi::c_void, samplecount: *mut i32, iooperation: KSIOOPERATION, streamsegment: *mut *mut KSSTREAM_SEGMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsInterfaceHandler_Impl::KsProcessMediaSamples(this, core::mem::transmute_copy(&ksdatatypehandler), core::mem::transmute_copy(&samplelist), core::mem::transmute_copy(&samplecount), core::mem::transmute_copy(&iooperation), core::mem::transmute_copy(&streamsegment)).into()
+            }
+        }
+        unsafe extern "system" fn KsCompleteIo<Identity: windows_core::IUnknownImpl + IKsInterfaceHandler_Impl>(this: *mut core::ffi::c_void, streamsegment: *mut KSSTREAM_SEGMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsInterfaceHandler_Impl::KsCompleteIo(this, core::mem::transmute_copy(&streamsegment)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            KsSetPin: KsSetPin::<Identity>,
+            KsProcessMediaSamples: KsProcessMediaSamples::<Identity>,
+            KsCompleteIo: KsCompleteIo::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Media_DirectShow")]
 impl windows_core::RuntimeName for IKsInterfaceHandler {}
 windows_core::imp::define_interface!(IKsJackContainerId, IKsJackContainerId_Vtbl, 0xc99af463_d629_4ec4_8c00_e54d68154248);
 windows_core::imp::interface_hierarchy!(IKsJackContainerId, windows_core::IUnknown);
@@ -1101,6 +1480,26 @@ impl IKsJackContainerId_Vtbl {
         iid == &<IKsJackContainerId as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IKsJackContainerId_Impl> windows_core::imp::TearOffVtable<Identity> for IKsJackContainerId_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetJackContainerId<Identity: windows_core::IUnknownImpl + IKsJackContainerId_Impl>(this: *mut core::ffi::c_void, pjackcontainerid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsJackContainerId_Impl::GetJackContainerId(this) {
+                    Ok(ok__) => {
+                        pjackcontainerid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetJackContainerId: GetJackContainerId::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IKsJackContainerId {}
 windows_core::imp::define_interface!(IKsJackDescription, IKsJackDescription_Vtbl, 0x4509f757_2d46_4637_8e62_ce7db944f57b);
 windows_core::imp::interface_hierarchy!(IKsJackDescription, windows_core::IUnknown);
@@ -1156,6 +1555,36 @@ impl IKsJackDescription_Vtbl {
         iid == &<IKsJackDescription as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IKsJackDescription_Impl> windows_core::imp::TearOffVtable<Identity> for IKsJackDescription_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetJackCount<Identity: windows_core::IUnknownImpl + IKsJackDescription_Impl>(this: *mut core::ffi::c_void, pcjacks: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsJackDescription_Impl::GetJackCount(this) {
+                    Ok(ok__) => {
+                        pcjacks.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetJackDescription<Identity: windows_core::IUnknownImpl + IKsJackDescription_Impl>(this: *mut core::ffi::c_void, njack: u32, pdescription: *mut KSJACK_DESCRIPTION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsJackDescription_Impl::GetJackDescription(this, core::mem::transmute_copy(&njack), core::mem::transmute_copy(&pdescription)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetJackCount: GetJackCount::<Identity>,
+            GetJackDescription: GetJackDescription::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IKsJackDescription {}
 windows_core::imp::define_interface!(IKsJackDescription2, IKsJackDescription2_Vtbl, 0x478f3a9b_e0c9_4827_9228_6f5505ffe76a);
 windows_core::imp::interface_hierarchy!(IKsJackDescription2, windows_core::IUnknown);
@@ -1220,6 +1649,42 @@ impl IKsJackDescription2_Vtbl {
         iid == &<IKsJackDescription2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IKsJackDescription2_Impl> windows_core::imp::TearOffVtable<Identity> for IKsJackDescription2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetJackCount<Identity: windows_core::IUnknownImpl + IKsJackDescription2_Impl>(this: *mut core::ffi::c_void, pcjacks: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsJackDescription2_Impl::GetJackCount(this) {
+                    Ok(ok__) => {
+                        pcjacks.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetJackDescription2<Identity: windows_core::IUnknownImpl + IKsJackDescription2_Impl>(this: *mut core::ffi::c_void, njack: u32, pdescription2: *mut KSJACK_DESCRIPTION2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsJackDescription2_Impl::GetJackDescription2(this, core::mem::transmute_copy(&njack)) {
+                    Ok(ok__) => {
+                        pdescription2.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetJackCount: GetJackCount::<Identity>,
+            GetJackDescription2: GetJackDescription2::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IKsJackDescription2 {}
 windows_core::imp::define_interface!(IKsJackDescription3, IKsJackDescription3_Vtbl, 0xe3f6778b_6660_4cc8_a291_ecc4192d9967);
 windows_core::imp::interface_hierarchy!(IKsJackDescription3, windows_core::IUnknown);
@@ -1284,6 +1749,42 @@ impl IKsJackDescription3_Vtbl {
         iid == &<IKsJackDescription3 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IKsJackDescription3_Impl> windows_core::imp::TearOffVtable<Identity> for IKsJackDescription3_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetJackCount<Identity: windows_core::IUnknownImpl + IKsJackDescription3_Impl>(this: *mut core::ffi::c_void, pcjacks: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsJackDescription3_Impl::GetJackCount(this) {
+                    Ok(ok__) => {
+                        pcjacks.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetJackDescription3<Identity: windows_core::IUnknownImpl + IKsJackDescription3_Impl>(this: *mut core::ffi::c_void, njack: u32, pdescription3: *mut KSJACK_DESCRIPTION3) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsJackDescription3_Impl::GetJackDescription3(this, core::mem::transmute_copy(&njack)) {
+                    Ok(ok__) => {
+                        pdescription3.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetJackCount: GetJackCount::<Identity>,
+            GetJackDescription3: GetJackDescription3::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IKsJackDescription3 {}
 windows_core::imp::define_interface!(IKsJackSinkInformation, IKsJackSinkInformation_Vtbl, 0xd9bd72ed_290f_4581_9ff3_61027a8fe532);
 windows_core::imp::interface_hierarchy!(IKsJackSinkInformation, windows_core::IUnknown);
@@ -1315,6 +1816,23 @@ impl IKsJackSinkInformation_Vtbl {
         iid == &<IKsJackSinkInformation as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IKsJackSinkInformation_Impl> windows_core::imp::TearOffVtable<Identity> for IKsJackSinkInformation_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetJackSinkInformation<Identity: windows_core::IUnknownImpl + IKsJackSinkInformation_Impl>(this: *mut core::ffi::c_void, pjacksinkinformation: *mut KSJACK_SINK_INFORMATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsJackSinkInformation_Impl::GetJackSinkInformation(this, core::mem::transmute_copy(&pjacksinkinformation)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetJackSinkInformation: GetJackSinkInformation::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IKsJackSinkInformation {}
 windows_core::imp::define_interface!(IKsNodeControl, IKsNodeControl_Vtbl, 0x11737c14_24a7_4bb5_81a0_0d003813b0c4);
 windows_core::imp::interface_hierarchy!(IKsNodeControl, windows_core::IUnknown);
@@ -1361,6 +1879,30 @@ impl IKsNodeControl_Vtbl {
         iid == &<IKsNodeControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IKsNodeControl_Impl> windows_core::imp::TearOffVtable<Identity> for IKsNodeControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetNodeId<Identity: windows_core::IUnknownImpl + IKsNodeControl_Impl>(this: *mut core::ffi::c_void, dwnodeid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsNodeControl_Impl::SetNodeId(this, core::mem::transmute_copy(&dwnodeid)).into()
+            }
+        }
+        unsafe extern "system" fn SetKsControl<Identity: windows_core::IUnknownImpl + IKsNodeControl_Impl>(this: *mut core::ffi::c_void, pkscontrol: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsNodeControl_Impl::SetKsControl(this, core::mem::transmute_copy(&pkscontrol)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetNodeId: SetNodeId::<Identity>,
+            SetKsControl: SetKsControl::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IKsNodeControl {}
 windows_core::imp::define_interface!(IKsNotifyEvent, IKsNotifyEvent_Vtbl, 0x412bd695_f84b_46c1_ac73_54196dbc8fa7);
 windows_core::imp::interface_hierarchy!(IKsNotifyEvent, windows_core::IUnknown);
@@ -1392,6 +1934,20 @@ impl IKsNotifyEvent_Vtbl {
         iid == &<IKsNotifyEvent as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IKsNotifyEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IKsNotifyEvent_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn KsNotifyEvent<Identity: windows_core::IUnknownImpl + IKsNotifyEvent_Impl>(this: *mut core::ffi::c_void, event: u32, lparam1: usize, lparam2: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsNotifyEvent_Impl::KsNotifyEvent(this, core::mem::transmute_copy(&event), core::mem::transmute_copy(&lparam1), core::mem::transmute_copy(&lparam2)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, KsNotifyEvent: KsNotifyEvent::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IKsNotifyEvent {}
 windows_core::imp::define_interface!(IKsObject, IKsObject_Vtbl, 0x423c13a2_2070_11d0_9ef7_00aa00a216a1);
 windows_core::imp::interface_hierarchy!(IKsObject, windows_core::IUnknown);
@@ -1423,6 +1979,20 @@ impl IKsObject_Vtbl {
         iid == &<IKsObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IKsObject_Impl> windows_core::imp::TearOffVtable<Identity> for IKsObject_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn KsGetObjectHandle<Identity: windows_core::IUnknownImpl + IKsObject_Impl>(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsObject_Impl::KsGetObjectHandle(this)
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, KsGetObjectHandle: KsGetObjectHandle::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IKsObject {}
 windows_core::imp::define_interface!(IKsPin, IKsPin_Vtbl, 0xb61178d1_a2d9_11cf_9e53_00aa00a216a1);
 windows_core::imp::interface_hierarchy!(IKsPin, windows_core::IUnknown);
@@ -1640,6 +2210,120 @@ impl IKsPin_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Media_DirectShow")]
+impl<Identity: windows_core::IUnknownImpl + IKsPin_Impl> windows_core::imp::TearOffVtable<Identity> for IKsPin_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn KsQueryMediums<Identity: windows_core::IUnknownImpl + IKsPin_Impl>(this: *mut core::ffi::c_void, mediumlist: *mut *mut KSMULTIPLE_ITEM) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsPin_Impl::KsQueryMediums(this) {
+                    Ok(ok__) => {
+                        mediumlist.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn KsQueryInterfaces<Identity: windows_core::IUnknownImpl + IKsPin_Impl>(this: *mut core::ffi::c_void, interfacelist: *mut *mut KSMULTIPLE_ITEM) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsPin_Impl::KsQueryInterfaces(this) {
+                    Ok(ok__) => {
+                        interfacelist.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn KsCreateSinkPinHandle<Identity: windows_core::IUnknownImpl + IKsPin_Impl>(this: *mut core::ffi::c_void, interface: *const KSIDENTIFIER, medium: *const KSIDENTIFIER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPin_Impl::KsCreateSinkPinHandle(this, core::mem::transmute_copy(&interface), core::mem::transmute_copy(&medium)).into()
+            }
+        }
+        unsafe extern "system" fn KsGetCurrentCommunication<Identity: windows_core::IUnknownImpl + IKsPin_Impl>(this: *mut core::ffi::c_void, communication: *mut KSPIN_COMMUNICATION, interface: *mut KSIDENTIFIER, medium: *mut KSIDENTIFIER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPin_Impl::KsGetCurrentCommunication(this, core::mem::transmute_copy(&communication), core::mem::transmute_copy(&interface), core::mem::transmute_copy(&medium)).into()
+            }
+        }
+        unsafe extern "system" fn KsPropagateAcquire<Identity: windows_core::IUnknownImpl + IKsPin_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPin_Impl::KsPropagateAcquire(this).into()
+            }
+        }
+        unsafe extern "system" fn KsDeliver<Identity: windows_core::IUnknownImpl + IKsPin_Impl>(this: *mut core::ffi::c_void, sample: *mut core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPin_Impl::KsDeliver(this, core::mem::transmute_copy(&sample), core::mem::transmute_copy(&flags)).into()
+            }
+        }
+        unsafe extern "system" fn KsMediaSamplesCompleted<Identity: windows_core::IUnknownImpl + IKsPin_Impl>(this: *mut core::ffi::c_void, streamsegment: *const KSSTREAM_SEGMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPin_Impl::KsMediaSamplesCompleted(this, core::mem::transmute_copy(&streamsegment)).into()
+            }
+        }
+        unsafe extern "system" fn KsPeekAllocator<Identity: windows_core::IUnknownImpl + IKsPin_Impl>(this: *mut core::ffi::c_void, operation: KSPEEKOPERATION) -> Option<super::DirectShow::IMemAllocator> {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPin_Impl::KsPeekAllocator(this, core::mem::transmute_copy(&operation))
+            }
+        }
+        unsafe extern "system" fn KsReceiveAllocator<Identity: windows_core::IUnknownImpl + IKsPin_Impl>(this: *mut core::ffi::c_void, memallocator: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPin_Impl::KsReceiveAllocator(this, core::mem::transmute_copy(&memallocator)).into()
+            }
+        }
+        unsafe extern "system" fn KsRenegotiateAllocator<Identity: windows_core::IUnknownImpl + IKsPin_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPin_Impl::KsRenegotiateAllocator(this).into()
+            }
+        }
+        unsafe extern "system" fn KsIncrementPendingIoCount<Identity: windows_core::IUnknownImpl + IKsPin_Impl>(this: *mut core::ffi::c_void) -> i32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPin_Impl::KsIncrementPendingIoCount(this)
+            }
+        }
+        unsafe extern "system" fn KsDecrementPendingIoCount<Identity: windows_core::IUnknownImpl + IKsPin_Impl>(this: *mut core::ffi::c_void) -> i32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPin_Impl::KsDecrementPendingIoCount(this)
+            }
+        }
+        unsafe extern "system" fn KsQualityNotify<Identity: windows_core::IUnknownImpl + IKsPin_Impl>(this: *mut core::ffi::c_void, proportion: u32, timedelta: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPin_Impl::KsQualityNotify(this, core::mem::transmute_copy(&proportion), core::mem::transmute_copy(&timedelta)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            KsQueryMediums: KsQueryMediums::<Identity>,
+            KsQueryInterfaces: KsQueryInterfaces::<Identity>,
+            KsCreateSinkPinHandle: KsCreateSinkPinHandle::<Identity>,
+            KsGetCurrentCommunication: KsGetCurrentCommunication::<Identity>,
+            KsPropagateAcquire: KsPropagateAcquire::<Identity>,
+            KsDeliver: KsDeliver::<Identity>,
+            KsMediaSamplesCompleted: KsMediaSamplesCompleted::<Identity>,
+            KsPeekAllocator: KsPeekAllocator::<Identity>,
+            KsReceiveAllocator: KsReceiveAllocator::<Identity>,
+            KsRenegotiateAllocator: KsRenegotiateAllocator::<Identity>,
+            KsIncrementPendingIoCount: KsIncrementPendingIoCount::<Identity>,
+            KsDecrementPendingIoCount: KsDecrementPendingIoCount::<Identity>,
+            KsQualityNotify: KsQualityNotify::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Media_DirectShow")]
 impl windows_core::RuntimeName for IKsPin {}
 windows_core::imp::define_interface!(IKsPinEx, IKsPinEx_Vtbl, 0x7bb38260_d19c_11d2_b38a_00a0c95ec22e);
 impl core::ops::Deref for IKsPinEx {
@@ -1687,6 +2371,21 @@ impl IKsPinEx_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Media_DirectShow")]
+impl<Identity: windows_core::IUnknownImpl + IKsPinEx_Impl> windows_core::imp::TearOffVtable<Identity> for IKsPinEx_Vtbl
+where
+    IKsPin_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn KsNotifyError<Identity: windows_core::IUnknownImpl + IKsPinEx_Impl>(this: *mut core::ffi::c_void, sample: *mut core::ffi::c_void, hr: windows_core::HRESULT) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPinEx_Impl::KsNotifyError(this, core::mem::transmute_copy(&sample), core::mem::transmute_copy(&hr))
+            }
+        }
+        Self { base__: <IKsPin_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, KsNotifyError: KsNotifyError::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_Media_DirectShow")]
 impl windows_core::RuntimeName for IKsPinEx {}
 windows_core::imp::define_interface!(IKsPinFactory, IKsPinFactory_Vtbl, 0xcd5ebe6b_8b6e_11d1_8ae0_00a0c9223196);
 windows_core::imp::interface_hierarchy!(IKsPinFactory, windows_core::IUnknown);
@@ -1727,6 +2426,26 @@ impl IKsPinFactory_Vtbl {
         iid == &<IKsPinFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IKsPinFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IKsPinFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn KsPinFactory<Identity: windows_core::IUnknownImpl + IKsPinFactory_Impl>(this: *mut core::ffi::c_void, pinfactory: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsPinFactory_Impl::KsPinFactory(this) {
+                    Ok(ok__) => {
+                        pinfactory.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, KsPinFactory: KsPinFactory::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IKsPinFactory {}
 windows_core::imp::define_interface!(IKsPinPipe, IKsPinPipe_Vtbl, 0xe539cd90_a8b4_11d1_8189_00a0c9062802);
 windows_core::imp::interface_hierarchy!(IKsPinPipe, windows_core::IUnknown);
@@ -1895,6 +2614,94 @@ impl IKsPinPipe_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Media_DirectShow")]
+impl<Identity: windows_core::IUnknownImpl + IKsPinPipe_Impl> windows_core::imp::TearOffVtable<Identity> for IKsPinPipe_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn KsGetPinFramingCache<Identity: windows_core::IUnknownImpl + IKsPinPipe_Impl>(this: *mut core::ffi::c_void, framingex: *mut *mut KSALLOCATOR_FRAMING_EX, framingprop: *mut FRAMING_PROP, option: FRAMING_CACHE_OPS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPinPipe_Impl::KsGetPinFramingCache(this, core::mem::transmute_copy(&framingex), core::mem::transmute_copy(&framingprop), core::mem::transmute_copy(&option)).into()
+            }
+        }
+        unsafe extern "system" fn KsSetPinFramingCache<Identity: windows_core::IUnknownImpl + IKsPinPipe_Impl>(this: *mut core::ffi::c_void, framingex: *const KSALLOCATOR_FRAMING_EX, framingprop: *const FRAMING_PROP, option: FRAMING_CACHE_OPS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPinPipe_Impl::KsSetPinFramingCache(this, core::mem::transmute_copy(&framingex), core::mem::transmute_copy(&framingprop), core::mem::transmute_copy(&option)).into()
+            }
+        }
+        unsafe extern "system" fn KsGetConnectedPin<Identity: windows_core::IUnknownImpl + IKsPinPipe_Impl>(this: *mut core::ffi::c_void) -> Option<super::DirectShow::IPin> {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPinPipe_Impl::KsGetConnectedPin(this)
+            }
+        }
+        unsafe extern "system" fn KsGetPipe<Identity: windows_core::IUnknownImpl + IKsPinPipe_Impl>(this: *mut core::ffi::c_void, operation: KSPEEKOPERATION) -> Option<IKsAllocatorEx> {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPinPipe_Impl::KsGetPipe(this, core::mem::transmute_copy(&operation))
+            }
+        }
+        unsafe extern "system" fn KsSetPipe<Identity: windows_core::IUnknownImpl + IKsPinPipe_Impl>(this: *mut core::ffi::c_void, ksallocator: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPinPipe_Impl::KsSetPipe(this, core::mem::transmute_copy(&ksallocator)).into()
+            }
+        }
+        unsafe extern "system" fn KsGetPipeAllocatorFlag<Identity: windows_core::IUnknownImpl + IKsPinPipe_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPinPipe_Impl::KsGetPipeAllocatorFlag(this)
+            }
+        }
+        unsafe extern "system" fn KsSetPipeAllocatorFlag<Identity: windows_core::IUnknownImpl + IKsPinPipe_Impl>(this: *mut core::ffi::c_void, flag: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPinPipe_Impl::KsSetPipeAllocatorFlag(this, core::mem::transmute_copy(&flag)).into()
+            }
+        }
+        unsafe extern "system" fn KsGetPinBusCache<Identity: windows_core::IUnknownImpl + IKsPinPipe_Impl>(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                *result__ = IKsPinPipe_Impl::KsGetPinBusCache(this)
+            }
+        }
+        unsafe extern "system" fn KsSetPinBusCache<Identity: windows_core::IUnknownImpl + IKsPinPipe_Impl>(this: *mut core::ffi::c_void, bus: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPinPipe_Impl::KsSetPinBusCache(this, core::mem::transmute(&bus)).into()
+            }
+        }
+        unsafe extern "system" fn KsGetPinName<Identity: windows_core::IUnknownImpl + IKsPinPipe_Impl>(this: *mut core::ffi::c_void) -> windows_core::PWSTR {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPinPipe_Impl::KsGetPinName(this)
+            }
+        }
+        unsafe extern "system" fn KsGetFilterName<Identity: windows_core::IUnknownImpl + IKsPinPipe_Impl>(this: *mut core::ffi::c_void) -> windows_core::PWSTR {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPinPipe_Impl::KsGetFilterName(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            KsGetPinFramingCache: KsGetPinFramingCache::<Identity>,
+            KsSetPinFramingCache: KsSetPinFramingCache::<Identity>,
+            KsGetConnectedPin: KsGetConnectedPin::<Identity>,
+            KsGetPipe: KsGetPipe::<Identity>,
+            KsSetPipe: KsSetPipe::<Identity>,
+            KsGetPipeAllocatorFlag: KsGetPipeAllocatorFlag::<Identity>,
+            KsSetPipeAllocatorFlag: KsSetPipeAllocatorFlag::<Identity>,
+            KsGetPinBusCache: KsGetPinBusCache::<Identity>,
+            KsSetPinBusCache: KsSetPinBusCache::<Identity>,
+            KsGetPinName: KsGetPinName::<Identity>,
+            KsGetFilterName: KsGetFilterName::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Media_DirectShow")]
 impl windows_core::RuntimeName for IKsPinPipe {}
 windows_core::imp::define_interface!(IKsPropertySet, IKsPropertySet_Vtbl, 0x31efac30_515c_11d0_a9aa_00aa0061be93);
 windows_core::imp::interface_hierarchy!(IKsPropertySet, windows_core::IUnknown);
@@ -1962,6 +2769,43 @@ impl IKsPropertySet_Vtbl {
         iid == &<IKsPropertySet as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IKsPropertySet_Impl> windows_core::imp::TearOffVtable<Identity> for IKsPropertySet_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Set<Identity: windows_core::IUnknownImpl + IKsPropertySet_Impl>(this: *mut core::ffi::c_void, guidpropset: *const windows_core::GUID, dwpropid: u32, pinstancedata: *const core::ffi::c_void, cbinstancedata: u32, ppropdata: *const core::ffi::c_void, cbpropdata: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPropertySet_Impl::Set(this, core::mem::transmute_copy(&guidpropset), core::mem::transmute_copy(&dwpropid), core::mem::transmute_copy(&pinstancedata), core::mem::transmute_copy(&cbinstancedata), core::mem::transmute_copy(&ppropdata), core::mem::transmute_copy(&cbpropdata)).into()
+            }
+        }
+        unsafe extern "system" fn Get<Identity: windows_core::IUnknownImpl + IKsPropertySet_Impl>(this: *mut core::ffi::c_void, guidpropset: *const windows_core::GUID, dwpropid: u32, pinstancedata: *const core::ffi::c_void, cbinstancedata: u32, ppropdata: *mut core::ffi::c_void, cbpropdata: u32, pcbreturned: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsPropertySet_Impl::Get(this, core::mem::transmute_copy(&guidpropset), core::mem::transmute_copy(&dwpropid), core::mem::transmute_copy(&pinstancedata), core::mem::transmute_copy(&cbinstancedata), core::mem::transmute_copy(&ppropdata), core::mem::transmute_copy(&cbpropdata), core::mem::transmute_copy(&pcbreturned)).into()
+            }
+        }
+        unsafe extern "system" fn QuerySupported<Identity: windows_core::IUnknownImpl + IKsPropertySet_Impl>(this: *mut core::ffi::c_void, guidpropset: *const windows_core::GUID, dwpropid: u32, ptypesupport: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsPropertySet_Impl::QuerySupported(this, core::mem::transmute_copy(&guidpropset), core::mem::transmute_copy(&dwpropid)) {
+                    Ok(ok__) => {
+                        ptypesupport.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Set: Set::<Identity>,
+            Get: Get::<Identity>,
+            QuerySupported: QuerySupported::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IKsPropertySet {}
 windows_core::imp::define_interface!(IKsQualityForwarder, IKsQualityForwarder_Vtbl, 0x97ebaacb_95bd_11d0_a3ea_00a0c9223196);
 impl core::ops::Deref for IKsQualityForwarder {
@@ -2002,6 +2846,20 @@ impl IKsQualityForwarder_Vtbl {
         iid == &<IKsQualityForwarder as windows_core::Interface>::IID || iid == &<IKsObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IKsQualityForwarder_Impl> windows_core::imp::TearOffVtable<Identity> for IKsQualityForwarder_Vtbl
+where
+    IKsObject_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn KsFlushClient<Identity: windows_core::IUnknownImpl + IKsQualityForwarder_Impl>(this: *mut core::ffi::c_void, pin: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsQualityForwarder_Impl::KsFlushClient(this, core::mem::transmute_copy(&pin))
+            }
+        }
+        Self { base__: <IKsObject_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, KsFlushClient: KsFlushClient::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IKsQualityForwarder {}
 windows_core::imp::define_interface!(IKsTopology, IKsTopology_Vtbl, 0x28f54683_06fd_11d2_b27a_00a0c9223196);
 windows_core::imp::interface_hierarchy!(IKsTopology, windows_core::IUnknown);
@@ -2036,6 +2894,20 @@ impl IKsTopology_Vtbl {
         iid == &<IKsTopology as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IKsTopology_Impl> windows_core::imp::TearOffVtable<Identity> for IKsTopology_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateNodeInstance<Identity: windows_core::IUnknownImpl + IKsTopology_Impl>(this: *mut core::ffi::c_void, nodeid: u32, flags: u32, desiredaccess: u32, unkouter: *mut core::ffi::c_void, interfaceid: *const windows_core::GUID, interface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsTopology_Impl::CreateNodeInstance(this, core::mem::transmute_copy(&nodeid), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&desiredaccess), core::mem::transmute_copy(&unkouter), core::mem::transmute_copy(&interfaceid), core::mem::transmute_copy(&interface)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateNodeInstance: CreateNodeInstance::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IKsTopology {}
 windows_core::imp::define_interface!(IKsTopologyInfo, IKsTopologyInfo_Vtbl, 0x720d4ac0_7533_11d0_a5d6_28db04c10000);
 windows_core::imp::interface_hierarchy!(IKsTopologyInfo, windows_core::IUnknown);
@@ -2208,6 +3080,108 @@ impl IKsTopologyInfo_Vtbl {
         iid == &<IKsTopologyInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IKsTopologyInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IKsTopologyInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn NumCategories<Identity: windows_core::IUnknownImpl + IKsTopologyInfo_Impl>(this: *mut core::ffi::c_void, pdwnumcategories: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsTopologyInfo_Impl::NumCategories(this) {
+                    Ok(ok__) => {
+                        pdwnumcategories.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_Category<Identity: windows_core::IUnknownImpl + IKsTopologyInfo_Impl>(this: *mut core::ffi::c_void, dwindex: u32, pcategory: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsTopologyInfo_Impl::get_Category(this, core::mem::transmute_copy(&dwindex)) {
+                    Ok(ok__) => {
+                        pcategory.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NumConnections<Identity: windows_core::IUnknownImpl + IKsTopologyInfo_Impl>(this: *mut core::ffi::c_void, pdwnumconnections: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsTopologyInfo_Impl::NumConnections(this) {
+                    Ok(ok__) => {
+                        pdwnumconnections.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_ConnectionInfo<Identity: windows_core::IUnknownImpl + IKsTopologyInfo_Impl>(this: *mut core::ffi::c_void, dwindex: u32, pconnectioninfo: *mut KSTOPOLOGY_CONNECTION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsTopologyInfo_Impl::get_ConnectionInfo(this, core::mem::transmute_copy(&dwindex)) {
+                    Ok(ok__) => {
+                        pconnectioninfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_NodeName<Identity: windows_core::IUnknownImpl + IKsTopologyInfo_Impl>(this: *mut core::ffi::c_void, dwnodeid: u32, pwchnodename: windows_core::PWSTR, dwbufsize: u32, pdwnamelen: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsTopologyInfo_Impl::get_NodeName(this, core::mem::transmute_copy(&dwnodeid), core::mem::transmute_copy(&pwchnodename), core::mem::transmute_copy(&dwbufsize), core::mem::transmute_copy(&pdwnamelen)).into()
+            }
+        }
+        unsafe extern "system" fn NumNodes<Identity: windows_core::IUnknownImpl + IKsTopologyInfo_Impl>(this: *mut core::ffi::c_void, pdwnumnodes: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsTopologyInfo_Impl::NumNodes(this) {
+                    Ok(ok__) => {
+                        pdwnumnodes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_NodeType<Identity: windows_core::IUnknownImpl + IKsTopologyInfo_Impl>(this: *mut core::ffi::c_void, dwnodeid: u32, pnodetype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKsTopologyInfo_Impl::get_NodeType(this, core::mem::transmute_copy(&dwnodeid)) {
+                    Ok(ok__) => {
+                        pnodetype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateNodeInstance<Identity: windows_core::IUnknownImpl + IKsTopologyInfo_Impl>(this: *mut core::ffi::c_void, dwnodeid: u32, iid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IKsTopologyInfo_Impl::CreateNodeInstance(this, core::mem::transmute_copy(&dwnodeid), core::mem::transmute_copy(&iid), core::mem::transmute_copy(&ppvobject)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            NumCategories: NumCategories::<Identity>,
+            get_Category: get_Category::<Identity>,
+            NumConnections: NumConnections::<Identity>,
+            get_ConnectionInfo: get_ConnectionInfo::<Identity>,
+            get_NodeName: get_NodeName::<Identity>,
+            NumNodes: NumNodes::<Identity>,
+            get_NodeType: get_NodeType::<Identity>,
+            CreateNodeInstance: CreateNodeInstance::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IKsTopologyInfo {}
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
@@ -114,6 +114,50 @@ impl IPhotoAcquire_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IPhotoAcquire_Impl> windows_core::imp::TearOffVtable<Identity> for IPhotoAcquire_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreatePhotoSource<Identity: windows_core::IUnknownImpl + IPhotoAcquire_Impl>(this: *mut core::ffi::c_void, pszdevice: windows_core::PCWSTR, ppphotoacquiresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquire_Impl::CreatePhotoSource(this, core::mem::transmute(&pszdevice)) {
+                    Ok(ok__) => {
+                        ppphotoacquiresource.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Acquire<Identity: windows_core::IUnknownImpl + IPhotoAcquire_Impl>(this: *mut core::ffi::c_void, pphotoacquiresource: *mut core::ffi::c_void, fshowprogress: windows_core::BOOL, hwndparent: super::super::Foundation::HWND, pszapplicationname: windows_core::PCWSTR, pphotoacquireprogresscb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquire_Impl::Acquire(this, core::mem::transmute_copy(&pphotoacquiresource), core::mem::transmute_copy(&fshowprogress), core::mem::transmute_copy(&hwndparent), core::mem::transmute(&pszapplicationname), core::mem::transmute_copy(&pphotoacquireprogresscb)).into()
+            }
+        }
+        unsafe extern "system" fn EnumResults<Identity: windows_core::IUnknownImpl + IPhotoAcquire_Impl>(this: *mut core::ffi::c_void, ppenumfilepaths: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquire_Impl::EnumResults(this) {
+                    Ok(ok__) => {
+                        ppenumfilepaths.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreatePhotoSource: CreatePhotoSource::<Identity>,
+            Acquire: Acquire::<Identity>,
+            EnumResults: EnumResults::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IPhotoAcquire {}
 windows_core::imp::define_interface!(IPhotoAcquireDeviceSelectionDialog, IPhotoAcquireDeviceSelectionDialog_Vtbl, 0x00f28837_55dd_4f37_aaf5_6855a9640467);
 windows_core::imp::interface_hierarchy!(IPhotoAcquireDeviceSelectionDialog, windows_core::IUnknown);
@@ -178,6 +222,37 @@ impl IPhotoAcquireDeviceSelectionDialog_Vtbl {
         iid == &<IPhotoAcquireDeviceSelectionDialog as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPhotoAcquireDeviceSelectionDialog_Impl> windows_core::imp::TearOffVtable<Identity> for IPhotoAcquireDeviceSelectionDialog_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetTitle<Identity: windows_core::IUnknownImpl + IPhotoAcquireDeviceSelectionDialog_Impl>(this: *mut core::ffi::c_void, psztitle: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireDeviceSelectionDialog_Impl::SetTitle(this, core::mem::transmute(&psztitle)).into()
+            }
+        }
+        unsafe extern "system" fn SetSubmitButtonText<Identity: windows_core::IUnknownImpl + IPhotoAcquireDeviceSelectionDialog_Impl>(this: *mut core::ffi::c_void, pszsubmitbuttontext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireDeviceSelectionDialog_Impl::SetSubmitButtonText(this, core::mem::transmute(&pszsubmitbuttontext)).into()
+            }
+        }
+        unsafe extern "system" fn DoModal<Identity: windows_core::IUnknownImpl + IPhotoAcquireDeviceSelectionDialog_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, dwdeviceflags: u32, pbstrdeviceid: *mut *mut core::ffi::c_void, pndevicetype: *mut DEVICE_SELECTION_DEVICE_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireDeviceSelectionDialog_Impl::DoModal(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&dwdeviceflags), core::mem::transmute_copy(&pbstrdeviceid), core::mem::transmute_copy(&pndevicetype)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetTitle: SetTitle::<Identity>,
+            SetSubmitButtonText: SetSubmitButtonText::<Identity>,
+            DoModal: DoModal::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPhotoAcquireDeviceSelectionDialog {}
 windows_core::imp::define_interface!(IPhotoAcquireItem, IPhotoAcquireItem_Vtbl, 0x00f21c97_28bf_4c02_b842_5e4e90139a30);
 windows_core::imp::interface_hierarchy!(IPhotoAcquireItem, windows_core::IUnknown);
@@ -390,6 +465,122 @@ impl IPhotoAcquireItem_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPhotoAcquireItem_Impl> windows_core::imp::TearOffVtable<Identity> for IPhotoAcquireItem_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetItemName<Identity: windows_core::IUnknownImpl + IPhotoAcquireItem_Impl>(this: *mut core::ffi::c_void, pbstritemname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquireItem_Impl::GetItemName(this) {
+                    Ok(ok__) => {
+                        pbstritemname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetThumbnail<Identity: windows_core::IUnknownImpl + IPhotoAcquireItem_Impl>(this: *mut core::ffi::c_void, sizethumbnail: super::super::Foundation::SIZE, phbmpthumbnail: *mut super::super::Graphics::Gdi::HBITMAP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquireItem_Impl::GetThumbnail(this, core::mem::transmute(&sizethumbnail)) {
+                    Ok(ok__) => {
+                        phbmpthumbnail.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetProperty<Identity: windows_core::IUnknownImpl + IPhotoAcquireItem_Impl>(this: *mut core::ffi::c_void, key: *const super::super::Foundation::PROPERTYKEY, pv: *mut super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquireItem_Impl::GetProperty(this, core::mem::transmute_copy(&key)) {
+                    Ok(ok__) => {
+                        pv.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProperty<Identity: windows_core::IUnknownImpl + IPhotoAcquireItem_Impl>(this: *mut core::ffi::c_void, key: *const super::super::Foundation::PROPERTYKEY, pv: *const super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireItem_Impl::SetProperty(this, core::mem::transmute_copy(&key), core::mem::transmute_copy(&pv)).into()
+            }
+        }
+        unsafe extern "system" fn GetStream<Identity: windows_core::IUnknownImpl + IPhotoAcquireItem_Impl>(this: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquireItem_Impl::GetStream(this) {
+                    Ok(ok__) => {
+                        ppstream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CanDelete<Identity: windows_core::IUnknownImpl + IPhotoAcquireItem_Impl>(this: *mut core::ffi::c_void, pfcandelete: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquireItem_Impl::CanDelete(this) {
+                    Ok(ok__) => {
+                        pfcandelete.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IPhotoAcquireItem_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireItem_Impl::Delete(this).into()
+            }
+        }
+        unsafe extern "system" fn GetSubItemCount<Identity: windows_core::IUnknownImpl + IPhotoAcquireItem_Impl>(this: *mut core::ffi::c_void, pncount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquireItem_Impl::GetSubItemCount(this) {
+                    Ok(ok__) => {
+                        pncount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSubItemAt<Identity: windows_core::IUnknownImpl + IPhotoAcquireItem_Impl>(this: *mut core::ffi::c_void, nitemindex: u32, ppphotoacquireitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquireItem_Impl::GetSubItemAt(this, core::mem::transmute_copy(&nitemindex)) {
+                    Ok(ok__) => {
+                        ppphotoacquireitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetItemName: GetItemName::<Identity>,
+            GetThumbnail: GetThumbnail::<Identity>,
+            GetProperty: GetProperty::<Identity>,
+            SetProperty: SetProperty::<Identity>,
+            GetStream: GetStream::<Identity>,
+            CanDelete: CanDelete::<Identity>,
+            Delete: Delete::<Identity>,
+            GetSubItemCount: GetSubItemCount::<Identity>,
+            GetSubItemAt: GetSubItemAt::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPhotoAcquireItem {}
 windows_core::imp::define_interface!(IPhotoAcquireOptionsDialog, IPhotoAcquireOptionsDialog_Vtbl, 0x00f2b3ee_bf64_47ee_89f4_4dedd79643f2);
 windows_core::imp::interface_hierarchy!(IPhotoAcquireOptionsDialog, windows_core::IUnknown);
@@ -484,6 +675,57 @@ impl IPhotoAcquireOptionsDialog_Vtbl {
         iid == &<IPhotoAcquireOptionsDialog as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPhotoAcquireOptionsDialog_Impl> windows_core::imp::TearOffVtable<Identity> for IPhotoAcquireOptionsDialog_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IPhotoAcquireOptionsDialog_Impl>(this: *mut core::ffi::c_void, pszregistryroot: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireOptionsDialog_Impl::Initialize(this, core::mem::transmute(&pszregistryroot)).into()
+            }
+        }
+        unsafe extern "system" fn Create<Identity: windows_core::IUnknownImpl + IPhotoAcquireOptionsDialog_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, phwnddialog: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquireOptionsDialog_Impl::Create(this, core::mem::transmute_copy(&hwndparent)) {
+                    Ok(ok__) => {
+                        phwnddialog.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Destroy<Identity: windows_core::IUnknownImpl + IPhotoAcquireOptionsDialog_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireOptionsDialog_Impl::Destroy(this).into()
+            }
+        }
+        unsafe extern "system" fn DoModal<Identity: windows_core::IUnknownImpl + IPhotoAcquireOptionsDialog_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, ppnreturncode: *mut isize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireOptionsDialog_Impl::DoModal(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&ppnreturncode)).into()
+            }
+        }
+        unsafe extern "system" fn SaveData<Identity: windows_core::IUnknownImpl + IPhotoAcquireOptionsDialog_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireOptionsDialog_Impl::SaveData(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            Create: Create::<Identity>,
+            Destroy: Destroy::<Identity>,
+            DoModal: DoModal::<Identity>,
+            SaveData: SaveData::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPhotoAcquireOptionsDialog {}
 windows_core::imp::define_interface!(IPhotoAcquirePlugin, IPhotoAcquirePlugin_Vtbl, 0x00f2dceb_ecb8_4f77_8e47_e7a987c83dd0);
 windows_core::imp::interface_hierarchy!(IPhotoAcquirePlugin, windows_core::IUnknown);
@@ -571,6 +813,45 @@ impl IPhotoAcquirePlugin_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+impl<Identity: windows_core::IUnknownImpl + IPhotoAcquirePlugin_Impl> windows_core::imp::TearOffVtable<Identity> for IPhotoAcquirePlugin_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IPhotoAcquirePlugin_Impl>(this: *mut core::ffi::c_void, pphotoacquiresource: *mut core::ffi::c_void, pphotoacquireprogresscb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquirePlugin_Impl::Initialize(this, core::mem::transmute_copy(&pphotoacquiresource), core::mem::transmute_copy(&pphotoacquireprogresscb)).into()
+            }
+        }
+        unsafe extern "system" fn ProcessItem<Identity: windows_core::IUnknownImpl + IPhotoAcquirePlugin_Impl>(this: *mut core::ffi::c_void, dwacquirestage: u32, pphotoacquireitem: *mut core::ffi::c_void, poriginalitemstream: *mut core::ffi::c_void, pszfinalfilename: windows_core::PCWSTR, ppropertystore: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquirePlugin_Impl::ProcessItem(this, core::mem::transmute_copy(&dwacquirestage), core::mem::transmute_copy(&pphotoacquireitem), core::mem::transmute_copy(&poriginalitemstream), core::mem::transmute(&pszfinalfilename), core::mem::transmute_copy(&ppropertystore)).into()
+            }
+        }
+        unsafe extern "system" fn TransferComplete<Identity: windows_core::IUnknownImpl + IPhotoAcquirePlugin_Impl>(this: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquirePlugin_Impl::TransferComplete(this, core::mem::transmute_copy(&hr)).into()
+            }
+        }
+        unsafe extern "system" fn DisplayConfigureDialog<Identity: windows_core::IUnknownImpl + IPhotoAcquirePlugin_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquirePlugin_Impl::DisplayConfigureDialog(this, core::mem::transmute_copy(&hwndparent)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            ProcessItem: ProcessItem::<Identity>,
+            TransferComplete: TransferComplete::<Identity>,
+            DisplayConfigureDialog: DisplayConfigureDialog::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
 impl windows_core::RuntimeName for IPhotoAcquirePlugin {}
 windows_core::imp::define_interface!(IPhotoAcquireProgressCB, IPhotoAcquireProgressCB_Vtbl, 0x00f2ce1e_935e_4248_892c_130f32c45cb4);
 windows_core::imp::interface_hierarchy!(IPhotoAcquireProgressCB, windows_core::IUnknown);
@@ -888,78 +1169,240 @@ impl IPhotoAcquireProgressCB_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IPhotoAcquireProgressCB {}
-windows_core::imp::define_interface!(IPhotoAcquireSettings, IPhotoAcquireSettings_Vtbl, 0x00f2b868_dd67_487c_9553_049240767e91);
-windows_core::imp::interface_hierarchy!(IPhotoAcquireSettings, windows_core::IUnknown);
-impl IPhotoAcquireSettings {
-    pub unsafe fn InitializeFromRegistry<P0>(&self, pszregistrykey: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<windows_core::PCWSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).InitializeFromRegistry)(windows_core::Interface::as_raw(self), pszregistrykey.param().abi()).ok() }
-    }
-    pub unsafe fn SetFlags(&self, dwphotoacquireflags: u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetFlags)(windows_core::Interface::as_raw(self), dwphotoacquireflags).ok() }
-    }
-    pub unsafe fn SetOutputFilenameTemplate<P0>(&self, psztemplate: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<windows_core::PCWSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).SetOutputFilenameTemplate)(windows_core::Interface::as_raw(self), psztemplate.param().abi()).ok() }
-    }
-    pub unsafe fn SetSequencePaddingWidth(&self, dwwidth: u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetSequencePaddingWidth)(windows_core::Interface::as_raw(self), dwwidth).ok() }
-    }
-    pub unsafe fn SetSequenceZeroPadding(&self, fzeropad: bool) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetSequenceZeroPadding)(windows_core::Interface::as_raw(self), fzeropad.into()).ok() }
-    }
-    pub unsafe fn SetGroupTag<P0>(&self, pszgrouptag: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<windows_core::PCWSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).SetGroupTag)(windows_core::Interface::as_raw(self), pszgrouptag.param().abi()).ok() }
-    }
-    pub unsafe fn SetAcquisitionTime(&self, pftacquisitiontime: *const super::super::Foundation::FILETIME) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetAcquisitionTime)(windows_core::Interface::as_raw(self), pftacquisitiontime).ok() }
-    }
-    pub unsafe fn GetFlags(&self) -> windows_core::Result<u32> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetFlags)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+impl<Identity: windows_core::IUnknownImpl + IPhotoAcquireProgressCB_Impl> windows_core::imp::TearOffVtable<Identity> for IPhotoAcquireProgressCB_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Cancelled<Identity: windows_core::IUnknownImpl + IPhotoAcquireProgressCB_Impl>(this: *mut core::ffi::c_void, pfcancelled: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquireProgressCB_Impl::Cancelled(this) {
+                    Ok(ok__) => {
+                        pfcancelled.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn GetOutputFilenameTemplate(&self) -> windows_core::Result<windows_core::BSTR> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetOutputFilenameTemplate)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        unsafe extern "system" fn StartEnumeration<Identity: windows_core::IUnknownImpl + IPhotoAcquireProgressCB_Impl>(this: *mut core::ffi::c_void, pphotoacquiresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireProgressCB_Impl::StartEnumeration(this, core::mem::transmute_copy(&pphotoacquiresource)).into()
+            }
         }
-    }
-    pub unsafe fn GetSequencePaddingWidth(&self) -> windows_core::Result<u32> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetSequencePaddingWidth)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        unsafe extern "system" fn FoundItem<Identity: windows_core::IUnknownImpl + IPhotoAcquireProgressCB_Impl>(this: *mut core::ffi::c_void, pphotoacquireitem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireProgressCB_Impl::FoundItem(this, core::mem::transmute_copy(&pphotoacquireitem)).into()
+            }
         }
-    }
-    pub unsafe fn GetSequenceZeroPadding(&self) -> windows_core::Result<windows_core::BOOL> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetSequenceZeroPadding)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        unsafe extern "system" fn EndEnumeration<Identity: windows_core::IUnknownImpl + IPhotoAcquireProgressCB_Impl>(this: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireProgressCB_Impl::EndEnumeration(this, core::mem::transmute_copy(&hr)).into()
+            }
         }
-    }
-    pub unsafe fn GetGroupTag(&self) -> windows_core::Result<windows_core::BSTR> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetGroupTag)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        unsafe extern "system" fn StartTransfer<Identity: windows_core::IUnknownImpl + IPhotoAcquireProgressCB_Impl>(this: *mut core::ffi::c_void, pphotoacquiresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireProgressCB_Impl::StartTransfer(this, core::mem::transmute_copy(&pphotoacquiresource)).into()
+            }
         }
-    }
-    pub unsafe fn GetAcquisitionTime(&self) -> windows_core::Result<super::super::Foundation::FILETIME> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetAcquisitionTime)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        unsafe extern "system" fn StartItemTransfer<Identity: windows_core::IUnknownImpl + IPhotoAcquireProgressCB_Impl>(this: *mut core::ffi::c_void, nitemindex: u32, pphotoacquireitem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireProgressCB_Impl::StartItemTransfer(this, core::mem::transmute_copy(&nitemindex), core::mem::transmute_copy(&pphotoacquireitem)).into()
+            }
         }
-    }
-}
-#[repr(C)]
+        unsafe extern "system" fn DirectoryCreated<Identity: windows_core::IUnknownImpl + IPhotoAcquireProgressCB_Impl>(this: *mut core::ffi::c_void, pszdirectory: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireProgressCB_Impl::DirectoryCreated(this, core::mem::transmute(&pszdirectory)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateTransferPercent<Identity: windows_core::IUnknownImpl + IPhotoAcquireProgressCB_Impl>(this: *mut core::ffi::c_void, foverall: windows_core::BOOL, npercent: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireProgressCB_Impl::UpdateTransferPercent(this, core::mem::transmute_copy(&foverall), core::mem::transmute_copy(&npercent)).into()
+            }
+        }
+        unsafe extern "system" fn EndItemTransfer<Identity: windows_core::IUnknownImpl + IPhotoAcquireProgressCB_Impl>(this: *mut core::ffi::c_void, nitemindex: u32, pphotoacquireitem: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireProgressCB_Impl::EndItemTransfer(this, core::mem::transmute_copy(&nitemindex), core::mem::transmute_copy(&pphotoacquireitem), core::mem::transmute_copy(&hr)).into()
+            }
+        }
+        unsafe extern "system" fn EndTransfer<Identity: windows_core::IUnknownImpl + IPhotoAcquireProgressCB_Impl>(this: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireProgressCB_Impl::EndTransfer(this, core::mem::transmute_copy(&hr)).into()
+            }
+        }
+        unsafe extern "system" fn StartDelete<Identity: windows_core::IUnknownImpl + IPhotoAcquireProgressCB_Impl>(this: *mut core::ffi::c_void, pphotoacquiresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireProgressCB_Impl::StartDelete(this, core::mem::transmute_copy(&pphotoacquiresource)).into()
+            }
+        }
+        unsafe extern "system" fn StartItemDelete<Identity: windows_core::IUnknownImpl + IPhotoAcquireProgressCB_Impl>(this: *mut core::ffi::c_void, nitemindex: u32, pphotoacquireitem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireProgressCB_Impl::StartItemDelete(this, core::mem::transmute_copy(&nitemindex), core::mem::transmute_copy(&pphotoacquireitem)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateDeletePercent<Identity: windows_core::IUnknownImpl + IPhotoAcquireProgressCB_Impl>(this: *mut core::ffi::c_void, npercent: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireProgressCB_Impl::UpdateDeletePercent(this, core::mem::transmute_copy(&npercent)).into()
+            }
+        }
+        unsafe extern "system" fn EndItemDelete<Identity: windows_core::IUnknownImpl + IPhotoAcquireProgressCB_Impl>(this: *mut core::ffi::c_void, nitemindex: u32, pphotoacquireitem: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireProgressCB_Impl::EndItemDelete(this, core::mem::transmute_copy(&nitemindex), core::mem::transmute_copy(&pphotoacquireitem), core::mem::transmute_copy(&hr)).into()
+            }
+        }
+        unsafe extern "system" fn EndDelete<Identity: windows_core::IUnknownImpl + IPhotoAcquireProgressCB_Impl>(this: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireProgressCB_Impl::EndDelete(this, core::mem::transmute_copy(&hr)).into()
+            }
+        }
+        unsafe extern "system" fn EndSession<Identity: windows_core::IUnknownImpl + IPhotoAcquireProgressCB_Impl>(this: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireProgressCB_Impl::EndSession(this, core::mem::transmute_copy(&hr)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeleteAfterAcquire<Identity: windows_core::IUnknownImpl + IPhotoAcquireProgressCB_Impl>(this: *mut core::ffi::c_void, pfdeleteafteracquire: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquireProgressCB_Impl::GetDeleteAfterAcquire(this) {
+                    Ok(ok__) => {
+                        pfdeleteafteracquire.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ErrorAdvise<Identity: windows_core::IUnknownImpl + IPhotoAcquireProgressCB_Impl>(this: *mut core::ffi::c_void, hr: windows_core::HRESULT, pszerrormessage: windows_core::PCWSTR, nmessagetype: ERROR_ADVISE_MESSAGE_TYPE, pnerroradviseresult: *mut ERROR_ADVISE_RESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquireProgressCB_Impl::ErrorAdvise(this, core::mem::transmute_copy(&hr), core::mem::transmute(&pszerrormessage), core::mem::transmute_copy(&nmessagetype)) {
+                    Ok(ok__) => {
+                        pnerroradviseresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetUserInput<Identity: windows_core::IUnknownImpl + IPhotoAcquireProgressCB_Impl>(this: *mut core::ffi::c_void, riidtype: *const windows_core::GUID, punknown: *mut core::ffi::c_void, ppropvarresult: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, ppropvardefault: *const super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireProgressCB_Impl::GetUserInput(this, core::mem::transmute_copy(&riidtype), core::mem::transmute_copy(&punknown), core::mem::transmute_copy(&ppropvarresult), core::mem::transmute_copy(&ppropvardefault)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Cancelled: Cancelled::<Identity>,
+            StartEnumeration: StartEnumeration::<Identity>,
+            FoundItem: FoundItem::<Identity>,
+            EndEnumeration: EndEnumeration::<Identity>,
+            StartTransfer: StartTransfer::<Identity>,
+            StartItemTransfer: StartItemTransfer::<Identity>,
+            DirectoryCreated: DirectoryCreated::<Identity>,
+            UpdateTransferPercent: UpdateTransferPercent::<Identity>,
+            EndItemTransfer: EndItemTransfer::<Identity>,
+            EndTransfer: EndTransfer::<Identity>,
+            StartDelete: StartDelete::<Identity>,
+            StartItemDelete: StartItemDelete::<Identity>,
+            UpdateDeletePercent: UpdateDeletePercent::<Identity>,
+            EndItemDelete: EndItemDelete::<Identity>,
+            EndDelete: EndDelete::<Identity>,
+            EndSession: EndSession::<Identity>,
+            GetDeleteAfterAcquire: GetDeleteAfterAcquire::<Identity>,
+            ErrorAdvise: ErrorAdvise::<Identity>,
+            GetUserInput: GetUserInput::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IPhotoAcquireProgressCB {}
+windows_core::imp::define_interface!(IPhotoAcquireSettings, IPhotoAcquireSettings_Vtbl, 0x00f2b868_dd67_487c_9553_049240767e91);
+windows_core::imp::interface_hierarchy!(IPhotoAcquireSettings, windows_core::IUnknown);
+impl IPhotoAcquireSettings {
+    pub unsafe fn InitializeFromRegistry<P0>(&self, pszregistrykey: P0) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::PCWSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).InitializeFromRegistry)(windows_core::Interface::as_raw(self), pszregistrykey.param().abi()).ok() }
+    }
+    pub unsafe fn SetFlags(&self, dwphotoacquireflags: u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetFlags)(windows_core::Interface::as_raw(self), dwphotoacquireflags).ok() }
+    }
+    pub unsafe fn SetOutputFilenameTemplate<P0>(&self, psztemplate: P0) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::PCWSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).SetOutputFilenameTemplate)(windows_core::Interface::as_raw(self), psztemplate.param().abi()).ok() }
+    }
+    pub unsafe fn SetSequencePaddingWidth(&self, dwwidth: u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetSequencePaddingWidth)(windows_core::Interface::as_raw(self), dwwidth).ok() }
+    }
+    pub unsafe fn SetSequenceZeroPadding(&self, fzeropad: bool) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetSequenceZeroPadding)(windows_core::Interface::as_raw(self), fzeropad.into()).ok() }
+    }
+    pub unsafe fn SetGroupTag<P0>(&self, pszgrouptag: P0) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::PCWSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).SetGroupTag)(windows_core::Interface::as_raw(self), pszgrouptag.param().abi()).ok() }
+    }
+    pub unsafe fn SetAcquisitionTime(&self, pftacquisitiontime: *const super::super::Foundation::FILETIME) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetAcquisitionTime)(windows_core::Interface::as_raw(self), pftacquisitiontime).ok() }
+    }
+    pub unsafe fn GetFlags(&self) -> windows_core::Result<u32> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetFlags)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn GetOutputFilenameTemplate(&self) -> windows_core::Result<windows_core::BSTR> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetOutputFilenameTemplate)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        }
+    }
+    pub unsafe fn GetSequencePaddingWidth(&self) -> windows_core::Result<u32> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetSequencePaddingWidth)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn GetSequenceZeroPadding(&self) -> windows_core::Result<windows_core::BOOL> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetSequenceZeroPadding)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn GetGroupTag(&self) -> windows_core::Result<windows_core::BSTR> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetGroupTag)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        }
+    }
+    pub unsafe fn GetAcquisitionTime(&self) -> windows_core::Result<super::super::Foundation::FILETIME> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetAcquisitionTime)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+}
+#[repr(C)]
 #[doc(hidden)]
 pub struct IPhotoAcquireSettings_Vtbl {
     pub base__: windows_core::IUnknown_Vtbl,
@@ -1129,6 +1572,143 @@ impl IPhotoAcquireSettings_Vtbl {
         iid == &<IPhotoAcquireSettings as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPhotoAcquireSettings_Impl> windows_core::imp::TearOffVtable<Identity> for IPhotoAcquireSettings_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InitializeFromRegistry<Identity: windows_core::IUnknownImpl + IPhotoAcquireSettings_Impl>(this: *mut core::ffi::c_void, pszregistrykey: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireSettings_Impl::InitializeFromRegistry(this, core::mem::transmute(&pszregistrykey)).into()
+            }
+        }
+        unsafe extern "system" fn SetFlags<Identity: windows_core::IUnknownImpl + IPhotoAcquireSettings_Impl>(this: *mut core::ffi::c_void, dwphotoacquireflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireSettings_Impl::SetFlags(this, core::mem::transmute_copy(&dwphotoacquireflags)).into()
+            }
+        }
+        unsafe extern "system" fn SetOutputFilenameTemplate<Identity: windows_core::IUnknownImpl + IPhotoAcquireSettings_Impl>(this: *mut core::ffi::c_void, psztemplate: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireSettings_Impl::SetOutputFilenameTemplate(this, core::mem::transmute(&psztemplate)).into()
+            }
+        }
+        unsafe extern "system" fn SetSequencePaddingWidth<Identity: windows_core::IUnknownImpl + IPhotoAcquireSettings_Impl>(this: *mut core::ffi::c_void, dwwidth: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireSettings_Impl::SetSequencePaddingWidth(this, core::mem::transmute_copy(&dwwidth)).into()
+            }
+        }
+        unsafe extern "system" fn SetSequenceZeroPadding<Identity: windows_core::IUnknownImpl + IPhotoAcquireSettings_Impl>(this: *mut core::ffi::c_void, fzeropad: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireSettings_Impl::SetSequenceZeroPadding(this, core::mem::transmute_copy(&fzeropad)).into()
+            }
+        }
+        unsafe extern "system" fn SetGroupTag<Identity: windows_core::IUnknownImpl + IPhotoAcquireSettings_Impl>(this: *mut core::ffi::c_void, pszgrouptag: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireSettings_Impl::SetGroupTag(this, core::mem::transmute(&pszgrouptag)).into()
+            }
+        }
+        unsafe extern "system" fn SetAcquisitionTime<Identity: windows_core::IUnknownImpl + IPhotoAcquireSettings_Impl>(this: *mut core::ffi::c_void, pftacquisitiontime: *const super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireSettings_Impl::SetAcquisitionTime(this, core::mem::transmute_copy(&pftacquisitiontime)).into()
+            }
+        }
+        unsafe extern "system" fn GetFlags<Identity: windows_core::IUnknownImpl + IPhotoAcquireSettings_Impl>(this: *mut core::ffi::c_void, pdwphotoacquireflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquireSettings_Impl::GetFlags(this) {
+                    Ok(ok__) => {
+                        pdwphotoacquireflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOutputFilenameTemplate<Identity: windows_core::IUnknownImpl + IPhotoAcquireSettings_Impl>(this: *mut core::ffi::c_void, pbstrtemplate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquireSettings_Impl::GetOutputFilenameTemplate(this) {
+                    Ok(ok__) => {
+                        pbstrtemplate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSequencePaddingWidth<Identity: windows_core::IUnknownImpl + IPhotoAcquireSettings_Impl>(this: *mut core::ffi::c_void, pdwwidth: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquireSettings_Impl::GetSequencePaddingWidth(this) {
+                    Ok(ok__) => {
+                        pdwwidth.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSequenceZeroPadding<Identity: windows_core::IUnknownImpl + IPhotoAcquireSettings_Impl>(this: *mut core::ffi::c_void, pfzeropad: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquireSettings_Impl::GetSequenceZeroPadding(this) {
+                    Ok(ok__) => {
+                        pfzeropad.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetGroupTag<Identity: windows_core::IUnknownImpl + IPhotoAcquireSettings_Impl>(this: *mut core::ffi::c_void, pbstrgrouptag: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquireSettings_Impl::GetGroupTag(this) {
+                    Ok(ok__) => {
+                        pbstrgrouptag.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAcquisitionTime<Identity: windows_core::IUnknownImpl + IPhotoAcquireSettings_Impl>(this: *mut core::ffi::c_void, pftacquisitiontime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquireSettings_Impl::GetAcquisitionTime(this) {
+                    Ok(ok__) => {
+                        pftacquisitiontime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InitializeFromRegistry: InitializeFromRegistry::<Identity>,
+            SetFlags: SetFlags::<Identity>,
+            SetOutputFilenameTemplate: SetOutputFilenameTemplate::<Identity>,
+            SetSequencePaddingWidth: SetSequencePaddingWidth::<Identity>,
+            SetSequenceZeroPadding: SetSequenceZeroPadding::<Identity>,
+            SetGroupTag: SetGroupTag::<Identity>,
+            SetAcquisitionTime: SetAcquisitionTime::<Identity>,
+            GetFlags: GetFlags::<Identity>,
+            GetOutputFilenameTemplate: GetOutputFilenameTemplate::<Identity>,
+            GetSequencePaddingWidth: GetSequencePaddingWidth::<Identity>,
+            GetSequenceZeroPadding: GetSequenceZeroPadding::<Identity>,
+            GetGroupTag: GetGroupTag::<Identity>,
+            GetAcquisitionTime: GetAcquisitionTime::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPhotoAcquireSettings {}
 windows_core::imp::define_interface!(IPhotoAcquireSource, IPhotoAcquireSource_Vtbl, 0x00f2c703_8613_4282_a53b_6ec59c5883ac);
 windows_core::imp::interface_hierarchy!(IPhotoAcquireSource, windows_core::IUnknown);
@@ -1302,6 +1882,103 @@ impl IPhotoAcquireSource_Vtbl {
     }
 }
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl<Identity: windows_core::IUnknownImpl + IPhotoAcquireSource_Impl> windows_core::imp::TearOffVtable<Identity> for IPhotoAcquireSource_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFriendlyName<Identity: windows_core::IUnknownImpl + IPhotoAcquireSource_Impl>(this: *mut core::ffi::c_void, pbstrfriendlyname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquireSource_Impl::GetFriendlyName(this) {
+                    Ok(ok__) => {
+                        pbstrfriendlyname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDeviceIcons<Identity: windows_core::IUnknownImpl + IPhotoAcquireSource_Impl>(this: *mut core::ffi::c_void, nsize: u32, phlargeicon: *mut super::super::UI::WindowsAndMessaging::HICON, phsmallicon: *mut super::super::UI::WindowsAndMessaging::HICON) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireSource_Impl::GetDeviceIcons(this, core::mem::transmute_copy(&nsize), core::mem::transmute_copy(&phlargeicon), core::mem::transmute_copy(&phsmallicon)).into()
+            }
+        }
+        unsafe extern "system" fn InitializeItemList<Identity: windows_core::IUnknownImpl + IPhotoAcquireSource_Impl>(this: *mut core::ffi::c_void, fforceenumeration: windows_core::BOOL, pphotoacquireprogresscb: *mut core::ffi::c_void, pnitemcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireSource_Impl::InitializeItemList(this, core::mem::transmute_copy(&fforceenumeration), core::mem::transmute_copy(&pphotoacquireprogresscb), core::mem::transmute_copy(&pnitemcount)).into()
+            }
+        }
+        unsafe extern "system" fn GetItemCount<Identity: windows_core::IUnknownImpl + IPhotoAcquireSource_Impl>(this: *mut core::ffi::c_void, pnitemcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquireSource_Impl::GetItemCount(this) {
+                    Ok(ok__) => {
+                        pnitemcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetItemAt<Identity: windows_core::IUnknownImpl + IPhotoAcquireSource_Impl>(this: *mut core::ffi::c_void, nindex: u32, ppphotoacquireitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquireSource_Impl::GetItemAt(this, core::mem::transmute_copy(&nindex)) {
+                    Ok(ok__) => {
+                        ppphotoacquireitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPhotoAcquireSettings<Identity: windows_core::IUnknownImpl + IPhotoAcquireSource_Impl>(this: *mut core::ffi::c_void, ppphotoacquiresettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquireSource_Impl::GetPhotoAcquireSettings(this) {
+                    Ok(ok__) => {
+                        ppphotoacquiresettings.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDeviceId<Identity: windows_core::IUnknownImpl + IPhotoAcquireSource_Impl>(this: *mut core::ffi::c_void, pbstrdeviceid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoAcquireSource_Impl::GetDeviceId(this) {
+                    Ok(ok__) => {
+                        pbstrdeviceid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn BindToObject<Identity: windows_core::IUnknownImpl + IPhotoAcquireSource_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoAcquireSource_Impl::BindToObject(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppv)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFriendlyName: GetFriendlyName::<Identity>,
+            GetDeviceIcons: GetDeviceIcons::<Identity>,
+            InitializeItemList: InitializeItemList::<Identity>,
+            GetItemCount: GetItemCount::<Identity>,
+            GetItemAt: GetItemAt::<Identity>,
+            GetPhotoAcquireSettings: GetPhotoAcquireSettings::<Identity>,
+            GetDeviceId: GetDeviceId::<Identity>,
+            BindToObject: BindToObject::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
 impl windows_core::RuntimeName for IPhotoAcquireSource {}
 windows_core::imp::define_interface!(IPhotoProgressActionCB, IPhotoProgressActionCB_Vtbl, 0x00f242d0_b206_4e7d_b4c1_4755bcbb9c9f);
 windows_core::imp::interface_hierarchy!(IPhotoProgressActionCB, windows_core::IUnknown);
@@ -1333,6 +2010,20 @@ impl IPhotoProgressActionCB_Vtbl {
         iid == &<IPhotoProgressActionCB as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPhotoProgressActionCB_Impl> windows_core::imp::TearOffVtable<Identity> for IPhotoProgressActionCB_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DoAction<Identity: windows_core::IUnknownImpl + IPhotoProgressActionCB_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoProgressActionCB_Impl::DoAction(this, core::mem::transmute_copy(&hwndparent)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, DoAction: DoAction::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IPhotoProgressActionCB {}
 windows_core::imp::define_interface!(IPhotoProgressDialog, IPhotoProgressDialog_Vtbl, 0x00f246f9_0750_4f08_9381_2cd8e906a4ae);
 windows_core::imp::interface_hierarchy!(IPhotoProgressDialog, windows_core::IUnknown);
@@ -1633,6 +2324,161 @@ impl IPhotoProgressDialog_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant", feature = "Win32_UI_WindowsAndMessaging"))]
+impl<Identity: windows_core::IUnknownImpl + IPhotoProgressDialog_Impl> windows_core::imp::TearOffVtable<Identity> for IPhotoProgressDialog_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Create<Identity: windows_core::IUnknownImpl + IPhotoProgressDialog_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoProgressDialog_Impl::Create(this, core::mem::transmute_copy(&hwndparent)).into()
+            }
+        }
+        unsafe extern "system" fn GetWindow<Identity: windows_core::IUnknownImpl + IPhotoProgressDialog_Impl>(this: *mut core::ffi::c_void, phwndprogressdialog: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoProgressDialog_Impl::GetWindow(this) {
+                    Ok(ok__) => {
+                        phwndprogressdialog.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Destroy<Identity: windows_core::IUnknownImpl + IPhotoProgressDialog_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoProgressDialog_Impl::Destroy(this).into()
+            }
+        }
+        unsafe extern "system" fn SetTitle<Identity: windows_core::IUnknownImpl + IPhotoProgressDialog_Impl>(this: *mut core::ffi::c_void, psztitle: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoProgressDialog_Impl::SetTitle(this, core::mem::transmute(&psztitle)).into()
+            }
+        }
+        unsafe extern "system" fn ShowCheckbox<Identity: windows_core::IUnknownImpl + IPhotoProgressDialog_Impl>(this: *mut core::ffi::c_void, ncheckboxid: PROGRESS_DIALOG_CHECKBOX_ID, fshow: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoProgressDialog_Impl::ShowCheckbox(this, core::mem::transmute_copy(&ncheckboxid), core::mem::transmute_copy(&fshow)).into()
+            }
+        }
+        unsafe extern "system" fn SetCheckboxText<Identity: windows_core::IUnknownImpl + IPhotoProgressDialog_Impl>(this: *mut core::ffi::c_void, ncheckboxid: PROGRESS_DIALOG_CHECKBOX_ID, pszcheckboxtext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoProgressDialog_Impl::SetCheckboxText(this, core::mem::transmute_copy(&ncheckboxid), core::mem::transmute(&pszcheckboxtext)).into()
+            }
+        }
+        unsafe extern "system" fn SetCheckboxCheck<Identity: windows_core::IUnknownImpl + IPhotoProgressDialog_Impl>(this: *mut core::ffi::c_void, ncheckboxid: PROGRESS_DIALOG_CHECKBOX_ID, fchecked: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoProgressDialog_Impl::SetCheckboxCheck(this, core::mem::transmute_copy(&ncheckboxid), core::mem::transmute_copy(&fchecked)).into()
+            }
+        }
+        unsafe extern "system" fn SetCheckboxTooltip<Identity: windows_core::IUnknownImpl + IPhotoProgressDialog_Impl>(this: *mut core::ffi::c_void, ncheckboxid: PROGRESS_DIALOG_CHECKBOX_ID, pszcheckboxtooltiptext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoProgressDialog_Impl::SetCheckboxTooltip(this, core::mem::transmute_copy(&ncheckboxid), core::mem::transmute(&pszcheckboxtooltiptext)).into()
+            }
+        }
+        unsafe extern "system" fn IsCheckboxChecked<Identity: windows_core::IUnknownImpl + IPhotoProgressDialog_Impl>(this: *mut core::ffi::c_void, ncheckboxid: PROGRESS_DIALOG_CHECKBOX_ID, pfchecked: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoProgressDialog_Impl::IsCheckboxChecked(this, core::mem::transmute_copy(&ncheckboxid)) {
+                    Ok(ok__) => {
+                        pfchecked.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCaption<Identity: windows_core::IUnknownImpl + IPhotoProgressDialog_Impl>(this: *mut core::ffi::c_void, psztitle: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoProgressDialog_Impl::SetCaption(this, core::mem::transmute(&psztitle)).into()
+            }
+        }
+        unsafe extern "system" fn SetImage<Identity: windows_core::IUnknownImpl + IPhotoProgressDialog_Impl>(this: *mut core::ffi::c_void, nimagetype: PROGRESS_DIALOG_IMAGE_TYPE, hicon: super::super::UI::WindowsAndMessaging::HICON, hbitmap: super::super::Graphics::Gdi::HBITMAP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoProgressDialog_Impl::SetImage(this, core::mem::transmute_copy(&nimagetype), core::mem::transmute_copy(&hicon), core::mem::transmute_copy(&hbitmap)).into()
+            }
+        }
+        unsafe extern "system" fn SetPercentComplete<Identity: windows_core::IUnknownImpl + IPhotoProgressDialog_Impl>(this: *mut core::ffi::c_void, npercent: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoProgressDialog_Impl::SetPercentComplete(this, core::mem::transmute_copy(&npercent)).into()
+            }
+        }
+        unsafe extern "system" fn SetProgressText<Identity: windows_core::IUnknownImpl + IPhotoProgressDialog_Impl>(this: *mut core::ffi::c_void, pszprogresstext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoProgressDialog_Impl::SetProgressText(this, core::mem::transmute(&pszprogresstext)).into()
+            }
+        }
+        unsafe extern "system" fn SetActionLinkCallback<Identity: windows_core::IUnknownImpl + IPhotoProgressDialog_Impl>(this: *mut core::ffi::c_void, pphotoprogressactioncb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoProgressDialog_Impl::SetActionLinkCallback(this, core::mem::transmute_copy(&pphotoprogressactioncb)).into()
+            }
+        }
+        unsafe extern "system" fn SetActionLinkText<Identity: windows_core::IUnknownImpl + IPhotoProgressDialog_Impl>(this: *mut core::ffi::c_void, pszcaption: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoProgressDialog_Impl::SetActionLinkText(this, core::mem::transmute(&pszcaption)).into()
+            }
+        }
+        unsafe extern "system" fn ShowActionLink<Identity: windows_core::IUnknownImpl + IPhotoProgressDialog_Impl>(this: *mut core::ffi::c_void, fshow: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoProgressDialog_Impl::ShowActionLink(this, core::mem::transmute_copy(&fshow)).into()
+            }
+        }
+        unsafe extern "system" fn IsCancelled<Identity: windows_core::IUnknownImpl + IPhotoProgressDialog_Impl>(this: *mut core::ffi::c_void, pfcancelled: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPhotoProgressDialog_Impl::IsCancelled(this) {
+                    Ok(ok__) => {
+                        pfcancelled.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetUserInput<Identity: windows_core::IUnknownImpl + IPhotoProgressDialog_Impl>(this: *mut core::ffi::c_void, riidtype: *const windows_core::GUID, punknown: *mut core::ffi::c_void, ppropvarresult: *mut super::super::System::Com::StructuredStorage::PROPVARIANT, ppropvardefault: *const super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPhotoProgressDialog_Impl::GetUserInput(this, core::mem::transmute_copy(&riidtype), core::mem::transmute_copy(&punknown), core::mem::transmute_copy(&ppropvarresult), core::mem::transmute_copy(&ppropvardefault)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Create: Create::<Identity>,
+            GetWindow: GetWindow::<Identity>,
+            Destroy: Destroy::<Identity>,
+            SetTitle: SetTitle::<Identity>,
+            ShowCheckbox: ShowCheckbox::<Identity>,
+            SetCheckboxText: SetCheckboxText::<Identity>,
+            SetCheckboxCheck: SetCheckboxCheck::<Identity>,
+            SetCheckboxTooltip: SetCheckboxTooltip::<Identity>,
+            IsCheckboxChecked: IsCheckboxChecked::<Identity>,
+            SetCaption: SetCaption::<Identity>,
+            SetImage: SetImage::<Identity>,
+            SetPercentComplete: SetPercentComplete::<Identity>,
+            SetProgressText: SetProgressText::<Identity>,
+            SetActionLinkCallback: SetActionLinkCallback::<Identity>,
+            SetActionLinkText: SetActionLinkText::<Identity>,
+            ShowActionLink: ShowActionLink::<Identity>,
+            IsCancelled: IsCancelled::<Identity>,
+            GetUserInput: GetUserInput::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant", feature = "Win32_UI_WindowsAndMessaging"))]
 impl windows_core::RuntimeName for IPhotoProgressDialog {}
 windows_core::imp::define_interface!(IUserInputString, IUserInputString_Vtbl, 0x00f243a1_205b_45ba_ae26_abbc53aa7a6f);
 windows_core::imp::interface_hierarchy!(IUserInputString, windows_core::IUnknown);
@@ -1863,6 +2709,141 @@ impl IUserInputString_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+impl<Identity: windows_core::IUnknownImpl + IUserInputString_Impl> windows_core::imp::TearOffVtable<Identity> for IUserInputString_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSubmitButtonText<Identity: windows_core::IUnknownImpl + IUserInputString_Impl>(this: *mut core::ffi::c_void, pbstrsubmitbuttontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUserInputString_Impl::GetSubmitButtonText(this) {
+                    Ok(ok__) => {
+                        pbstrsubmitbuttontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPrompt<Identity: windows_core::IUnknownImpl + IUserInputString_Impl>(this: *mut core::ffi::c_void, pbstrprompttitle: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUserInputString_Impl::GetPrompt(this) {
+                    Ok(ok__) => {
+                        pbstrprompttitle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStringId<Identity: windows_core::IUnknownImpl + IUserInputString_Impl>(this: *mut core::ffi::c_void, pbstrstringid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUserInputString_Impl::GetStringId(this) {
+                    Ok(ok__) => {
+                        pbstrstringid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStringType<Identity: windows_core::IUnknownImpl + IUserInputString_Impl>(this: *mut core::ffi::c_void, pnstringtype: *mut USER_INPUT_STRING_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUserInputString_Impl::GetStringType(this) {
+                    Ok(ok__) => {
+                        pnstringtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTooltipText<Identity: windows_core::IUnknownImpl + IUserInputString_Impl>(this: *mut core::ffi::c_void, pbstrtooltiptext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUserInputString_Impl::GetTooltipText(this) {
+                    Ok(ok__) => {
+                        pbstrtooltiptext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMaxLength<Identity: windows_core::IUnknownImpl + IUserInputString_Impl>(this: *mut core::ffi::c_void, pcchmaxlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUserInputString_Impl::GetMaxLength(this) {
+                    Ok(ok__) => {
+                        pcchmaxlength.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDefault<Identity: windows_core::IUnknownImpl + IUserInputString_Impl>(this: *mut core::ffi::c_void, pbstrdefault: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUserInputString_Impl::GetDefault(this) {
+                    Ok(ok__) => {
+                        pbstrdefault.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMruCount<Identity: windows_core::IUnknownImpl + IUserInputString_Impl>(this: *mut core::ffi::c_void, pnmrucount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUserInputString_Impl::GetMruCount(this) {
+                    Ok(ok__) => {
+                        pnmrucount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMruEntryAt<Identity: windows_core::IUnknownImpl + IUserInputString_Impl>(this: *mut core::ffi::c_void, nindex: u32, pbstrmruentry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUserInputString_Impl::GetMruEntryAt(this, core::mem::transmute_copy(&nindex)) {
+                    Ok(ok__) => {
+                        pbstrmruentry.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetImage<Identity: windows_core::IUnknownImpl + IUserInputString_Impl>(this: *mut core::ffi::c_void, nsize: u32, phbitmap: *mut super::super::Graphics::Gdi::HBITMAP, phicon: *mut super::super::UI::WindowsAndMessaging::HICON) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IUserInputString_Impl::GetImage(this, core::mem::transmute_copy(&nsize), core::mem::transmute_copy(&phbitmap), core::mem::transmute_copy(&phicon)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSubmitButtonText: GetSubmitButtonText::<Identity>,
+            GetPrompt: GetPrompt::<Identity>,
+            GetStringId: GetStringId::<Identity>,
+            GetStringType: GetStringType::<Identity>,
+            GetTooltipText: GetTooltipText::<Identity>,
+            GetMaxLength: GetMaxLength::<Identity>,
+            GetDefault: GetDefault::<Identity>,
+            GetMruCount: GetMruCount::<Identity>,
+            GetMruEntryAt: GetMruEntryAt::<Identity>,
+            GetImage: GetImage::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
 impl windows_core::RuntimeName for IUserInputString {}
 pub const PAPS_CLEANUP: u32 = 2u32;
 pub const PAPS_POSTSAVE: u32 = 1u32;
@@ -604,6 +604,70 @@ impl IEnumSpObjectTokens_Vtbl {
         iid == &<IEnumSpObjectTokens as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumSpObjectTokens_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumSpObjectTokens_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumSpObjectTokens_Impl>(this: *mut core::ffi::c_void, celt: u32, pelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumSpObjectTokens_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&pelt), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumSpObjectTokens_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumSpObjectTokens_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumSpObjectTokens_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumSpObjectTokens_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumSpObjectTokens_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumSpObjectTokens_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Item<Identity: windows_core::IUnknownImpl + IEnumSpObjectTokens_Impl>(this: *mut core::ffi::c_void, index: u32, pptoken: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumSpObjectTokens_Impl::Item(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        pptoken.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IEnumSpObjectTokens_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumSpObjectTokens_Impl::GetCount(this, core::mem::transmute_copy(&pcount)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+            Item: Item::<Identity>,
+            GetCount: GetCount::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumSpObjectTokens {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpAudio, ISpAudio_Vtbl, 0xc05c768f_fae8_4ec2_8e07_338321c12452);
@@ -780,6 +844,94 @@ impl ISpAudio_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com"))]
+impl<Identity: windows_core::IUnknownImpl + ISpAudio_Impl> windows_core::imp::TearOffVtable<Identity> for ISpAudio_Vtbl
+where
+    ISpStreamFormat_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetState<Identity: windows_core::IUnknownImpl + ISpAudio_Impl>(this: *mut core::ffi::c_void, newstate: SPAUDIOSTATE, ullreserved: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpAudio_Impl::SetState(this, core::mem::transmute_copy(&newstate), core::mem::transmute_copy(&ullreserved)).into()
+            }
+        }
+        unsafe extern "system" fn SetFormat<Identity: windows_core::IUnknownImpl + ISpAudio_Impl>(this: *mut core::ffi::c_void, rguidfmtid: *const windows_core::GUID, pwaveformatex: *const super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpAudio_Impl::SetFormat(this, core::mem::transmute_copy(&rguidfmtid), core::mem::transmute_copy(&pwaveformatex)).into()
+            }
+        }
+        unsafe extern "system" fn GetStatus<Identity: windows_core::IUnknownImpl + ISpAudio_Impl>(this: *mut core::ffi::c_void, pstatus: *mut SPAUDIOSTATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpAudio_Impl::GetStatus(this, core::mem::transmute_copy(&pstatus)).into()
+            }
+        }
+        unsafe extern "system" fn SetBufferInfo<Identity: windows_core::IUnknownImpl + ISpAudio_Impl>(this: *mut core::ffi::c_void, pbuffinfo: *const SPAUDIOBUFFERINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpAudio_Impl::SetBufferInfo(this, core::mem::transmute_copy(&pbuffinfo)).into()
+            }
+        }
+        unsafe extern "system" fn GetBufferInfo<Identity: windows_core::IUnknownImpl + ISpAudio_Impl>(this: *mut core::ffi::c_void, pbuffinfo: *mut SPAUDIOBUFFERINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpAudio_Impl::GetBufferInfo(this, core::mem::transmute_copy(&pbuffinfo)).into()
+            }
+        }
+        unsafe extern "system" fn GetDefaultFormat<Identity: windows_core::IUnknownImpl + ISpAudio_Impl>(this: *mut core::ffi::c_void, pformatid: *mut windows_core::GUID, ppcomemwaveformatex: *mut *mut super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpAudio_Impl::GetDefaultFormat(this, core::mem::transmute_copy(&pformatid), core::mem::transmute_copy(&ppcomemwaveformatex)).into()
+            }
+        }
+        unsafe extern "system" fn EventHandle<Identity: windows_core::IUnknownImpl + ISpAudio_Impl>(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpAudio_Impl::EventHandle(this)
+            }
+        }
+        unsafe extern "system" fn GetVolumeLevel<Identity: windows_core::IUnknownImpl + ISpAudio_Impl>(this: *mut core::ffi::c_void, plevel: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpAudio_Impl::GetVolumeLevel(this, core::mem::transmute_copy(&plevel)).into()
+            }
+        }
+        unsafe extern "system" fn SetVolumeLevel<Identity: windows_core::IUnknownImpl + ISpAudio_Impl>(this: *mut core::ffi::c_void, level: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpAudio_Impl::SetVolumeLevel(this, core::mem::transmute_copy(&level)).into()
+            }
+        }
+        unsafe extern "system" fn GetBufferNotifySize<Identity: windows_core::IUnknownImpl + ISpAudio_Impl>(this: *mut core::ffi::c_void, pcbsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpAudio_Impl::GetBufferNotifySize(this, core::mem::transmute_copy(&pcbsize)).into()
+            }
+        }
+        unsafe extern "system" fn SetBufferNotifySize<Identity: windows_core::IUnknownImpl + ISpAudio_Impl>(this: *mut core::ffi::c_void, cbsize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpAudio_Impl::SetBufferNotifySize(this, core::mem::transmute_copy(&cbsize)).into()
+            }
+        }
+        Self {
+            base__: <ISpStreamFormat_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetState: SetState::<Identity>,
+            SetFormat: SetFormat::<Identity>,
+            GetStatus: GetStatus::<Identity>,
+            SetBufferInfo: SetBufferInfo::<Identity>,
+            GetBufferInfo: GetBufferInfo::<Identity>,
+            GetDefaultFormat: GetDefaultFormat::<Identity>,
+            EventHandle: EventHandle::<Identity>,
+            GetVolumeLevel: GetVolumeLevel::<Identity>,
+            SetVolumeLevel: SetVolumeLevel::<Identity>,
+            GetBufferNotifySize: GetBufferNotifySize::<Identity>,
+            SetBufferNotifySize: SetBufferNotifySize::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com"))]
 impl windows_core::RuntimeName for ISpAudio {}
 windows_core::imp::define_interface!(ISpCFGInterpreter, ISpCFGInterpreter_Vtbl, 0xf3d3f926_11fc_11d3_bb97_00c04f8ee6c0);
 windows_core::imp::interface_hierarchy!(ISpCFGInterpreter, windows_core::IUnknown);
@@ -833,6 +985,30 @@ impl ISpCFGInterpreter_Vtbl {
         iid == &<ISpCFGInterpreter as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpCFGInterpreter_Impl> windows_core::imp::TearOffVtable<Identity> for ISpCFGInterpreter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InitGrammar<Identity: windows_core::IUnknownImpl + ISpCFGInterpreter_Impl>(this: *mut core::ffi::c_void, pszgrammarname: windows_core::PCWSTR, pvgrammardata: *const *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpCFGInterpreter_Impl::InitGrammar(this, core::mem::transmute(&pszgrammarname), core::mem::transmute_copy(&pvgrammardata)).into()
+            }
+        }
+        unsafe extern "system" fn Interpret<Identity: windows_core::IUnknownImpl + ISpCFGInterpreter_Impl>(this: *mut core::ffi::c_void, pphrase: *mut core::ffi::c_void, ulfirstelement: u32, ulcountofelements: u32, psite: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpCFGInterpreter_Impl::Interpret(this, core::mem::transmute_copy(&pphrase), core::mem::transmute_copy(&ulfirstelement), core::mem::transmute_copy(&ulcountofelements), core::mem::transmute_copy(&psite)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InitGrammar: InitGrammar::<Identity>,
+            Interpret: Interpret::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpCFGInterpreter {}
 windows_core::imp::define_interface!(ISpCFGInterpreterSite, ISpCFGInterpreterSite_Vtbl, 0x6a6ffad8_78b6_473d_b844_98152e4fb16b);
 windows_core::imp::interface_hierarchy!(ISpCFGInterpreterSite, windows_core::IUnknown);
@@ -910,6 +1086,44 @@ impl ISpCFGInterpreterSite_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpCFGInterpreterSite_Impl> windows_core::imp::TearOffVtable<Identity> for ISpCFGInterpreterSite_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddTextReplacement<Identity: windows_core::IUnknownImpl + ISpCFGInterpreterSite_Impl>(this: *mut core::ffi::c_void, preplace: *const SPPHRASEREPLACEMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpCFGInterpreterSite_Impl::AddTextReplacement(this, core::mem::transmute_copy(&preplace)).into()
+            }
+        }
+        unsafe extern "system" fn AddProperty<Identity: windows_core::IUnknownImpl + ISpCFGInterpreterSite_Impl>(this: *mut core::ffi::c_void, pproperty: *const SPPHRASEPROPERTY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpCFGInterpreterSite_Impl::AddProperty(this, core::mem::transmute_copy(&pproperty)).into()
+            }
+        }
+        unsafe extern "system" fn GetResourceValue<Identity: windows_core::IUnknownImpl + ISpCFGInterpreterSite_Impl>(this: *mut core::ffi::c_void, pszresourcename: windows_core::PCWSTR, ppcomemresource: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpCFGInterpreterSite_Impl::GetResourceValue(this, core::mem::transmute(&pszresourcename)) {
+                    Ok(ok__) => {
+                        ppcomemresource.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddTextReplacement: AddTextReplacement::<Identity>,
+            AddProperty: AddProperty::<Identity>,
+            GetResourceValue: GetResourceValue::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpCFGInterpreterSite {}
 windows_core::imp::define_interface!(ISpContainerLexicon, ISpContainerLexicon_Vtbl, 0x8565572f_c094_41cc_b56e_10bd9c3ff044);
 impl core::ops::Deref for ISpContainerLexicon {
@@ -950,6 +1164,20 @@ impl ISpContainerLexicon_Vtbl {
         iid == &<ISpContainerLexicon as windows_core::Interface>::IID || iid == &<ISpLexicon as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpContainerLexicon_Impl> windows_core::imp::TearOffVtable<Identity> for ISpContainerLexicon_Vtbl
+where
+    ISpLexicon_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddLexicon<Identity: windows_core::IUnknownImpl + ISpContainerLexicon_Impl>(this: *mut core::ffi::c_void, paddlexicon: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpContainerLexicon_Impl::AddLexicon(this, core::mem::transmute_copy(&paddlexicon), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        Self { base__: <ISpLexicon_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, AddLexicon: AddLexicon::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ISpContainerLexicon {}
 windows_core::imp::define_interface!(ISpDataKey, ISpDataKey_Vtbl, 0x14056581_e16c_11d2_bb90_00c04f8ee6c0);
 windows_core::imp::interface_hierarchy!(ISpDataKey, windows_core::IUnknown);
@@ -1192,6 +1420,130 @@ impl ISpDataKey_Vtbl {
         iid == &<ISpDataKey as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpDataKey_Impl> windows_core::imp::TearOffVtable<Identity> for ISpDataKey_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetData<Identity: windows_core::IUnknownImpl + ISpDataKey_Impl>(this: *mut core::ffi::c_void, pszvaluename: windows_core::PCWSTR, cbdata: u32, pdata: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpDataKey_Impl::SetData(this, core::mem::transmute(&pszvaluename), core::mem::transmute_copy(&cbdata), core::mem::transmute_copy(&pdata)).into()
+            }
+        }
+        unsafe extern "system" fn GetData<Identity: windows_core::IUnknownImpl + ISpDataKey_Impl>(this: *mut core::ffi::c_void, pszvaluename: windows_core::PCWSTR, pcbdata: *mut u32, pdata: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpDataKey_Impl::GetData(this, core::mem::transmute(&pszvaluename), core::mem::transmute_copy(&pcbdata), core::mem::transmute_copy(&pdata)).into()
+            }
+        }
+        unsafe extern "system" fn SetStringValue<Identity: windows_core::IUnknownImpl + ISpDataKey_Impl>(this: *mut core::ffi::c_void, pszvaluename: windows_core::PCWSTR, pszvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpDataKey_Impl::SetStringValue(this, core::mem::transmute(&pszvaluename), core::mem::transmute(&pszvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetStringValue<Identity: windows_core::IUnknownImpl + ISpDataKey_Impl>(this: *mut core::ffi::c_void, pszvaluename: windows_core::PCWSTR, ppszvalue: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpDataKey_Impl::GetStringValue(this, core::mem::transmute(&pszvaluename)) {
+                    Ok(ok__) => {
+                        ppszvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDWORD<Identity: windows_core::IUnknownImpl + ISpDataKey_Impl>(this: *mut core::ffi::c_void, pszvaluename: windows_core::PCWSTR, dwvalue: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpDataKey_Impl::SetDWORD(this, core::mem::transmute(&pszvaluename), core::mem::transmute_copy(&dwvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetDWORD<Identity: windows_core::IUnknownImpl + ISpDataKey_Impl>(this: *mut core::ffi::c_void, pszvaluename: windows_core::PCWSTR, pdwvalue: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpDataKey_Impl::GetDWORD(this, core::mem::transmute(&pszvaluename), core::mem::transmute_copy(&pdwvalue)).into()
+            }
+        }
+        unsafe extern "system" fn OpenKey<Identity: windows_core::IUnknownImpl + ISpDataKey_Impl>(this: *mut core::ffi::c_void, pszsubkeyname: windows_core::PCWSTR, ppsubkey: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpDataKey_Impl::OpenKey(this, core::mem::transmute(&pszsubkeyname)) {
+                    Ok(ok__) => {
+                        ppsubkey.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateKey<Identity: windows_core::IUnknownImpl + ISpDataKey_Impl>(this: *mut core::ffi::c_void, pszsubkey: windows_core::PCWSTR, ppsubkey: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpDataKey_Impl::CreateKey(this, core::mem::transmute(&pszsubkey)) {
+                    Ok(ok__) => {
+                        ppsubkey.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeleteKey<Identity: windows_core::IUnknownImpl + ISpDataKey_Impl>(this: *mut core::ffi::c_void, pszsubkey: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpDataKey_Impl::DeleteKey(this, core::mem::transmute(&pszsubkey)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteValue<Identity: windows_core::IUnknownImpl + ISpDataKey_Impl>(this: *mut core::ffi::c_void, pszvaluename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpDataKey_Impl::DeleteValue(this, core::mem::transmute(&pszvaluename)).into()
+            }
+        }
+        unsafe extern "system" fn EnumKeys<Identity: windows_core::IUnknownImpl + ISpDataKey_Impl>(this: *mut core::ffi::c_void, index: u32, ppszsubkeyname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpDataKey_Impl::EnumKeys(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        ppszsubkeyname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumValues<Identity: windows_core::IUnknownImpl + ISpDataKey_Impl>(this: *mut core::ffi::c_void, index: u32, ppszvaluename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpDataKey_Impl::EnumValues(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        ppszvaluename.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetData: SetData::<Identity>,
+            GetData: GetData::<Identity>,
+            SetStringValue: SetStringValue::<Identity>,
+            GetStringValue: GetStringValue::<Identity>,
+            SetDWORD: SetDWORD::<Identity>,
+            GetDWORD: GetDWORD::<Identity>,
+            OpenKey: OpenKey::<Identity>,
+            CreateKey: CreateKey::<Identity>,
+            DeleteKey: DeleteKey::<Identity>,
+            DeleteValue: DeleteValue::<Identity>,
+            EnumKeys: EnumKeys::<Identity>,
+            EnumValues: EnumValues::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpDataKey {}
 windows_core::imp::define_interface!(ISpDisplayAlternates, ISpDisplayAlternates_Vtbl, 0xc8d7c7e2_0dde_44b7_afe3_b0c991fbeb5e);
 windows_core::imp::interface_hierarchy!(ISpDisplayAlternates, windows_core::IUnknown);
@@ -1238,6 +1590,30 @@ impl ISpDisplayAlternates_Vtbl {
         iid == &<ISpDisplayAlternates as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpDisplayAlternates_Impl> windows_core::imp::TearOffVtable<Identity> for ISpDisplayAlternates_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDisplayAlternates<Identity: windows_core::IUnknownImpl + ISpDisplayAlternates_Impl>(this: *mut core::ffi::c_void, pphrase: *const SPDISPLAYPHRASE, crequestcount: u32, ppcomemphrases: *mut *mut SPDISPLAYPHRASE, pcphrasesreturned: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpDisplayAlternates_Impl::GetDisplayAlternates(this, core::mem::transmute_copy(&pphrase), core::mem::transmute_copy(&crequestcount), core::mem::transmute_copy(&ppcomemphrases), core::mem::transmute_copy(&pcphrasesreturned)).into()
+            }
+        }
+        unsafe extern "system" fn SetFullStopTrailSpace<Identity: windows_core::IUnknownImpl + ISpDisplayAlternates_Impl>(this: *mut core::ffi::c_void, ultrailspace: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpDisplayAlternates_Impl::SetFullStopTrailSpace(this, core::mem::transmute_copy(&ultrailspace)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDisplayAlternates: GetDisplayAlternates::<Identity>,
+            SetFullStopTrailSpace: SetFullStopTrailSpace::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpDisplayAlternates {}
 windows_core::imp::define_interface!(ISpEnginePronunciation, ISpEnginePronunciation_Vtbl, 0xc360ce4b_76d1_4214_ad68_52657d5083da);
 windows_core::imp::interface_hierarchy!(ISpEnginePronunciation, windows_core::IUnknown);
@@ -1294,6 +1670,30 @@ impl ISpEnginePronunciation_Vtbl {
         iid == &<ISpEnginePronunciation as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpEnginePronunciation_Impl> windows_core::imp::TearOffVtable<Identity> for ISpEnginePronunciation_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Normalize<Identity: windows_core::IUnknownImpl + ISpEnginePronunciation_Impl>(this: *mut core::ffi::c_void, pszword: windows_core::PCWSTR, pszleftcontext: windows_core::PCWSTR, pszrightcontext: windows_core::PCWSTR, langid: u16, pnormalizationlist: *mut SPNORMALIZATIONLIST) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpEnginePronunciation_Impl::Normalize(this, core::mem::transmute(&pszword), core::mem::transmute(&pszleftcontext), core::mem::transmute(&pszrightcontext), core::mem::transmute_copy(&langid), core::mem::transmute_copy(&pnormalizationlist)).into()
+            }
+        }
+        unsafe extern "system" fn GetPronunciations<Identity: windows_core::IUnknownImpl + ISpEnginePronunciation_Impl>(this: *mut core::ffi::c_void, pszword: windows_core::PCWSTR, pszleftcontext: windows_core::PCWSTR, pszrightcontext: windows_core::PCWSTR, langid: u16, penginepronunciationlist: *mut SPWORDPRONUNCIATIONLIST) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpEnginePronunciation_Impl::GetPronunciations(this, core::mem::transmute(&pszword), core::mem::transmute(&pszleftcontext), core::mem::transmute(&pszrightcontext), core::mem::transmute_copy(&langid), core::mem::transmute_copy(&penginepronunciationlist)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Normalize: Normalize::<Identity>,
+            GetPronunciations: GetPronunciations::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpEnginePronunciation {}
 windows_core::imp::define_interface!(ISpErrorLog, ISpErrorLog_Vtbl, 0xf4711347_e608_11d2_a086_00c04f8ef9b5);
 windows_core::imp::interface_hierarchy!(ISpErrorLog, windows_core::IUnknown);
@@ -1329,6 +1729,20 @@ impl ISpErrorLog_Vtbl {
         iid == &<ISpErrorLog as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpErrorLog_Impl> windows_core::imp::TearOffVtable<Identity> for ISpErrorLog_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddError<Identity: windows_core::IUnknownImpl + ISpErrorLog_Impl>(this: *mut core::ffi::c_void, llinenumber: i32, hr: windows_core::HRESULT, pszdescription: windows_core::PCWSTR, pszhelpfile: windows_core::PCWSTR, dwhelpcontext: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpErrorLog_Impl::AddError(this, core::mem::transmute_copy(&llinenumber), core::mem::transmute_copy(&hr), core::mem::transmute(&pszdescription), core::mem::transmute(&pszhelpfile), core::mem::transmute_copy(&dwhelpcontext)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, AddError: AddError::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ISpErrorLog {}
 windows_core::imp::define_interface!(ISpEventSink, ISpEventSink_Vtbl, 0xbe7a9cc9_5f9e_11d2_960f_00c04f8ee628);
 windows_core::imp::interface_hierarchy!(ISpEventSink, windows_core::IUnknown);
@@ -1375,6 +1789,30 @@ impl ISpEventSink_Vtbl {
         iid == &<ISpEventSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpEventSink_Impl> windows_core::imp::TearOffVtable<Identity> for ISpEventSink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddEvents<Identity: windows_core::IUnknownImpl + ISpEventSink_Impl>(this: *mut core::ffi::c_void, peventarray: *const SPEVENT, ulcount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpEventSink_Impl::AddEvents(this, core::mem::transmute_copy(&peventarray), core::mem::transmute_copy(&ulcount)).into()
+            }
+        }
+        unsafe extern "system" fn GetEventInterest<Identity: windows_core::IUnknownImpl + ISpEventSink_Impl>(this: *mut core::ffi::c_void, pulleventinterest: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpEventSink_Impl::GetEventInterest(this, core::mem::transmute_copy(&pulleventinterest)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddEvents: AddEvents::<Identity>,
+            GetEventInterest: GetEventInterest::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpEventSink {}
 windows_core::imp::define_interface!(ISpEventSource, ISpEventSource_Vtbl, 0xbe7a9cce_5f9e_11d2_960f_00c04f8ee628);
 impl core::ops::Deref for ISpEventSource {
@@ -1439,6 +1877,37 @@ impl ISpEventSource_Vtbl {
         iid == &<ISpEventSource as windows_core::Interface>::IID || iid == &<ISpNotifySource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpEventSource_Impl> windows_core::imp::TearOffVtable<Identity> for ISpEventSource_Vtbl
+where
+    ISpNotifySource_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetInterest<Identity: windows_core::IUnknownImpl + ISpEventSource_Impl>(this: *mut core::ffi::c_void, ulleventinterest: u64, ullqueuedinterest: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpEventSource_Impl::SetInterest(this, core::mem::transmute_copy(&ulleventinterest), core::mem::transmute_copy(&ullqueuedinterest)).into()
+            }
+        }
+        unsafe extern "system" fn GetEvents<Identity: windows_core::IUnknownImpl + ISpEventSource_Impl>(this: *mut core::ffi::c_void, ulcount: u32, peventarray: *mut SPEVENT, pulfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpEventSource_Impl::GetEvents(this, core::mem::transmute_copy(&ulcount), core::mem::transmute_copy(&peventarray), core::mem::transmute_copy(&pulfetched)).into()
+            }
+        }
+        unsafe extern "system" fn GetInfo<Identity: windows_core::IUnknownImpl + ISpEventSource_Impl>(this: *mut core::ffi::c_void, pinfo: *mut SPEVENTSOURCEINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpEventSource_Impl::GetInfo(this, core::mem::transmute_copy(&pinfo)).into()
+            }
+        }
+        Self {
+            base__: <ISpNotifySource_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetInterest: SetInterest::<Identity>,
+            GetEvents: GetEvents::<Identity>,
+            GetInfo: GetInfo::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpEventSource {}
 windows_core::imp::define_interface!(ISpEventSource2, ISpEventSource2_Vtbl, 0x2373a435_6a4b_429e_a6ac_d4231a61975b);
 impl core::ops::Deref for ISpEventSource2 {
@@ -1476,8 +1945,22 @@ impl ISpEventSource2_Vtbl {
         iid == &<ISpEventSource2 as windows_core::Interface>::IID || iid == &<ISpNotifySource as windows_core::Interface>::IID || iid == &<ISpEventSource as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for ISpEventSource2 {}
-windows_core::imp::define_interface!(ISpGramCompBackend, ISpGramCompBackend_Vtbl, 0x3ddca27c_665c_4786_9f97_8c90c3488b61);
+impl<Identity: windows_core::IUnknownImpl + ISpEventSource2_Impl> windows_core::imp::TearOffVtable<Identity> for ISpEventSource2_Vtbl
+where
+    ISpEventSource_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetEventsEx<Identity: windows_core::IUnknownImpl + ISpEventSource2_Impl>(this: *mut core::ffi::c_void, ulcount: u32, peventarray: *mut SPEVENTEX, pulfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpEventSource2_Impl::GetEventsEx(this, core::mem::transmute_copy(&ulcount), core::mem::transmute_copy(&peventarray), core::mem::transmute_copy(&pulfetched)).into()
+            }
+        }
+        Self { base__: <ISpEventSource_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetEventsEx: GetEventsEx::<Identity> }
+    };
+}
+impl windows_core::RuntimeName for ISpEventSource2 {}
+windows_core::imp::define_interface!(ISpGramCompBackend, ISpGramCompBackend_Vtbl, 0x3ddca27c_665c_4786_9f97_8c90c3488b61);
 impl core::ops::Deref for ISpGramCompBackend {
     type Target = ISpGrammarBuilder;
     fn deref(&self) -> &Self::Target {
@@ -1539,6 +2022,31 @@ impl ISpGramCompBackend_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpGramCompBackend_Impl> windows_core::imp::TearOffVtable<Identity> for ISpGramCompBackend_Vtbl
+where
+    ISpGrammarBuilder_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetSaveObjects<Identity: windows_core::IUnknownImpl + ISpGramCompBackend_Impl>(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, perrorlog: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpGramCompBackend_Impl::SetSaveObjects(this, core::mem::transmute_copy(&pstream), core::mem::transmute_copy(&perrorlog)).into()
+            }
+        }
+        unsafe extern "system" fn InitFromBinaryGrammar<Identity: windows_core::IUnknownImpl + ISpGramCompBackend_Impl>(this: *mut core::ffi::c_void, pbinarydata: *const SPBINARYGRAMMAR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpGramCompBackend_Impl::InitFromBinaryGrammar(this, core::mem::transmute_copy(&pbinarydata)).into()
+            }
+        }
+        Self {
+            base__: <ISpGrammarBuilder_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetSaveObjects: SetSaveObjects::<Identity>,
+            InitFromBinaryGrammar: InitFromBinaryGrammar::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpGramCompBackend {}
 windows_core::imp::define_interface!(ISpGrammarBuilder, ISpGrammarBuilder_Vtbl, 0x8137828f_591a_4a42_be58_49ea7ebaac68);
 windows_core::imp::interface_hierarchy!(ISpGrammarBuilder, windows_core::IUnknown);
@@ -1679,6 +2187,73 @@ impl ISpGrammarBuilder_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpGrammarBuilder_Impl> windows_core::imp::TearOffVtable<Identity> for ISpGrammarBuilder_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ResetGrammar<Identity: windows_core::IUnknownImpl + ISpGrammarBuilder_Impl>(this: *mut core::ffi::c_void, newlanguage: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpGrammarBuilder_Impl::ResetGrammar(this, core::mem::transmute_copy(&newlanguage)).into()
+            }
+        }
+        unsafe extern "system" fn GetRule<Identity: windows_core::IUnknownImpl + ISpGrammarBuilder_Impl>(this: *mut core::ffi::c_void, pszrulename: windows_core::PCWSTR, dwruleid: u32, dwattributes: u32, fcreateifnotexist: windows_core::BOOL, phinitialstate: *mut SPSTATEHANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpGrammarBuilder_Impl::GetRule(this, core::mem::transmute(&pszrulename), core::mem::transmute_copy(&dwruleid), core::mem::transmute_copy(&dwattributes), core::mem::transmute_copy(&fcreateifnotexist), core::mem::transmute_copy(&phinitialstate)).into()
+            }
+        }
+        unsafe extern "system" fn ClearRule<Identity: windows_core::IUnknownImpl + ISpGrammarBuilder_Impl>(this: *mut core::ffi::c_void, hstate: SPSTATEHANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpGrammarBuilder_Impl::ClearRule(this, core::mem::transmute_copy(&hstate)).into()
+            }
+        }
+        unsafe extern "system" fn CreateNewState<Identity: windows_core::IUnknownImpl + ISpGrammarBuilder_Impl>(this: *mut core::ffi::c_void, hstate: SPSTATEHANDLE, phstate: *mut SPSTATEHANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpGrammarBuilder_Impl::CreateNewState(this, core::mem::transmute_copy(&hstate), core::mem::transmute_copy(&phstate)).into()
+            }
+        }
+        unsafe extern "system" fn AddWordTransition<Identity: windows_core::IUnknownImpl + ISpGrammarBuilder_Impl>(this: *mut core::ffi::c_void, hfromstate: SPSTATEHANDLE, htostate: SPSTATEHANDLE, psz: windows_core::PCWSTR, pszseparators: windows_core::PCWSTR, ewordtype: SPGRAMMARWORDTYPE, weight: f32, ppropinfo: *const SPPROPERTYINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpGrammarBuilder_Impl::AddWordTransition(this, core::mem::transmute_copy(&hfromstate), core::mem::transmute_copy(&htostate), core::mem::transmute(&psz), core::mem::transmute(&pszseparators), core::mem::transmute_copy(&ewordtype), core::mem::transmute_copy(&weight), core::mem::transmute_copy(&ppropinfo)).into()
+            }
+        }
+        unsafe extern "system" fn AddRuleTransition<Identity: windows_core::IUnknownImpl + ISpGrammarBuilder_Impl>(this: *mut core::ffi::c_void, hfromstate: SPSTATEHANDLE, htostate: SPSTATEHANDLE, hrule: SPSTATEHANDLE, weight: f32, ppropinfo: *const SPPROPERTYINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpGrammarBuilder_Impl::AddRuleTransition(this, core::mem::transmute_copy(&hfromstate), core::mem::transmute_copy(&htostate), core::mem::transmute_copy(&hrule), core::mem::transmute_copy(&weight), core::mem::transmute_copy(&ppropinfo)).into()
+            }
+        }
+        unsafe extern "system" fn AddResource<Identity: windows_core::IUnknownImpl + ISpGrammarBuilder_Impl>(this: *mut core::ffi::c_void, hrulestate: SPSTATEHANDLE, pszresourcename: windows_core::PCWSTR, pszresourcevalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpGrammarBuilder_Impl::AddResource(this, core::mem::transmute_copy(&hrulestate), core::mem::transmute(&pszresourcename), core::mem::transmute(&pszresourcevalue)).into()
+            }
+        }
+        unsafe extern "system" fn Commit<Identity: windows_core::IUnknownImpl + ISpGrammarBuilder_Impl>(this: *mut core::ffi::c_void, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpGrammarBuilder_Impl::Commit(this, core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ResetGrammar: ResetGrammar::<Identity>,
+            GetRule: GetRule::<Identity>,
+            ClearRule: ClearRule::<Identity>,
+            CreateNewState: CreateNewState::<Identity>,
+            AddWordTransition: AddWordTransition::<Identity>,
+            AddRuleTransition: AddRuleTransition::<Identity>,
+            AddResource: AddResource::<Identity>,
+            Commit: Commit::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpGrammarBuilder {}
 windows_core::imp::define_interface!(ISpGrammarBuilder2, ISpGrammarBuilder2_Vtbl, 0x8ab10026_20cc_4b20_8c22_a49c9ba78f60);
 windows_core::imp::interface_hierarchy!(ISpGrammarBuilder2, windows_core::IUnknown);
@@ -1728,6 +2303,30 @@ impl ISpGrammarBuilder2_Vtbl {
         iid == &<ISpGrammarBuilder2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpGrammarBuilder2_Impl> windows_core::imp::TearOffVtable<Identity> for ISpGrammarBuilder2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddTextSubset<Identity: windows_core::IUnknownImpl + ISpGrammarBuilder2_Impl>(this: *mut core::ffi::c_void, hfromstate: SPSTATEHANDLE, htostate: SPSTATEHANDLE, psz: windows_core::PCWSTR, ematchmode: SPMATCHINGMODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpGrammarBuilder2_Impl::AddTextSubset(this, core::mem::transmute_copy(&hfromstate), core::mem::transmute_copy(&htostate), core::mem::transmute(&psz), core::mem::transmute_copy(&ematchmode)).into()
+            }
+        }
+        unsafe extern "system" fn SetPhoneticAlphabet<Identity: windows_core::IUnknownImpl + ISpGrammarBuilder2_Impl>(this: *mut core::ffi::c_void, phoneticalphabet: PHONETICALPHABET) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpGrammarBuilder2_Impl::SetPhoneticAlphabet(this, core::mem::transmute_copy(&phoneticalphabet)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddTextSubset: AddTextSubset::<Identity>,
+            SetPhoneticAlphabet: SetPhoneticAlphabet::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpGrammarBuilder2 {}
 windows_core::imp::define_interface!(ISpGrammarCompiler, ISpGrammarCompiler_Vtbl, 0xb1e29d58_a675_11d2_8302_00c04f8ee6c0);
 windows_core::imp::interface_hierarchy!(ISpGrammarCompiler, windows_core::IUnknown);
@@ -1773,6 +2372,21 @@ impl ISpGrammarCompiler_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + ISpGrammarCompiler_Impl> windows_core::imp::TearOffVtable<Identity> for ISpGrammarCompiler_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CompileStream<Identity: windows_core::IUnknownImpl + ISpGrammarCompiler_Impl>(this: *mut core::ffi::c_void, psource: *mut core::ffi::c_void, pdest: *mut core::ffi::c_void, pheader: *mut core::ffi::c_void, preserved: *mut core::ffi::c_void, perrorlog: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpGrammarCompiler_Impl::CompileStream(this, core::mem::transmute_copy(&psource), core::mem::transmute_copy(&pdest), core::mem::transmute_copy(&pheader), core::mem::transmute_copy(&preserved), core::mem::transmute_copy(&perrorlog), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CompileStream: CompileStream::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for ISpGrammarCompiler {}
 windows_core::imp::define_interface!(ISpITNProcessor, ISpITNProcessor_Vtbl, 0x12d7360f_a1c9_11d3_bc90_00c04f72df9f);
 windows_core::imp::interface_hierarchy!(ISpITNProcessor, windows_core::IUnknown);
@@ -1825,6 +2439,30 @@ impl ISpITNProcessor_Vtbl {
         iid == &<ISpITNProcessor as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpITNProcessor_Impl> windows_core::imp::TearOffVtable<Identity> for ISpITNProcessor_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn LoadITNGrammar<Identity: windows_core::IUnknownImpl + ISpITNProcessor_Impl>(this: *mut core::ffi::c_void, pszclsid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpITNProcessor_Impl::LoadITNGrammar(this, core::mem::transmute(&pszclsid)).into()
+            }
+        }
+        unsafe extern "system" fn ITNPhrase<Identity: windows_core::IUnknownImpl + ISpITNProcessor_Impl>(this: *mut core::ffi::c_void, pphrase: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpITNProcessor_Impl::ITNPhrase(this, core::mem::transmute_copy(&pphrase)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            LoadITNGrammar: LoadITNGrammar::<Identity>,
+            ITNPhrase: ITNPhrase::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpITNProcessor {}
 windows_core::imp::define_interface!(ISpLexicon, ISpLexicon_Vtbl, 0xda41a7c2_5383_4db2_916b_6c1719e3db58);
 windows_core::imp::interface_hierarchy!(ISpLexicon, windows_core::IUnknown);
@@ -1928,6 +2566,58 @@ impl ISpLexicon_Vtbl {
         iid == &<ISpLexicon as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpLexicon_Impl> windows_core::imp::TearOffVtable<Identity> for ISpLexicon_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPronunciations<Identity: windows_core::IUnknownImpl + ISpLexicon_Impl>(this: *mut core::ffi::c_void, pszword: windows_core::PCWSTR, langid: u16, dwflags: u32, pwordpronunciationlist: *mut SPWORDPRONUNCIATIONLIST) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpLexicon_Impl::GetPronunciations(this, core::mem::transmute(&pszword), core::mem::transmute_copy(&langid), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pwordpronunciationlist)).into()
+            }
+        }
+        unsafe extern "system" fn AddPronunciation<Identity: windows_core::IUnknownImpl + ISpLexicon_Impl>(this: *mut core::ffi::c_void, pszword: windows_core::PCWSTR, langid: u16, epartofspeech: SPPARTOFSPEECH, pszpronunciation: *const u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpLexicon_Impl::AddPronunciation(this, core::mem::transmute(&pszword), core::mem::transmute_copy(&langid), core::mem::transmute_copy(&epartofspeech), core::mem::transmute_copy(&pszpronunciation)).into()
+            }
+        }
+        unsafe extern "system" fn RemovePronunciation<Identity: windows_core::IUnknownImpl + ISpLexicon_Impl>(this: *mut core::ffi::c_void, pszword: windows_core::PCWSTR, langid: u16, epartofspeech: SPPARTOFSPEECH, pszpronunciation: *const u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpLexicon_Impl::RemovePronunciation(this, core::mem::transmute(&pszword), core::mem::transmute_copy(&langid), core::mem::transmute_copy(&epartofspeech), core::mem::transmute_copy(&pszpronunciation)).into()
+            }
+        }
+        unsafe extern "system" fn GetGeneration<Identity: windows_core::IUnknownImpl + ISpLexicon_Impl>(this: *mut core::ffi::c_void, pdwgeneration: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpLexicon_Impl::GetGeneration(this, core::mem::transmute_copy(&pdwgeneration)).into()
+            }
+        }
+        unsafe extern "system" fn GetGenerationChange<Identity: windows_core::IUnknownImpl + ISpLexicon_Impl>(this: *mut core::ffi::c_void, dwflags: u32, pdwgeneration: *mut u32, pwordlist: *mut SPWORDLIST) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpLexicon_Impl::GetGenerationChange(this, core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pdwgeneration), core::mem::transmute_copy(&pwordlist)).into()
+            }
+        }
+        unsafe extern "system" fn GetWords<Identity: windows_core::IUnknownImpl + ISpLexicon_Impl>(this: *mut core::ffi::c_void, dwflags: u32, pdwgeneration: *mut u32, pdwcookie: *mut u32, pwordlist: *mut SPWORDLIST) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpLexicon_Impl::GetWords(this, core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pdwgeneration), core::mem::transmute_copy(&pdwcookie), core::mem::transmute_copy(&pwordlist)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPronunciations: GetPronunciations::<Identity>,
+            AddPronunciation: AddPronunciation::<Identity>,
+            RemovePronunciation: RemovePronunciation::<Identity>,
+            GetGeneration: GetGeneration::<Identity>,
+            GetGenerationChange: GetGenerationChange::<Identity>,
+            GetWords: GetWords::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpLexicon {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpMMSysAudio, ISpMMSysAudio_Vtbl, 0x15806f6e_1d70_4b48_98e6_3b1a007509ab);
@@ -2024,6 +2714,52 @@ impl ISpMMSysAudio_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com"))]
+impl<Identity: windows_core::IUnknownImpl + ISpMMSysAudio_Impl> windows_core::imp::TearOffVtable<Identity> for ISpMMSysAudio_Vtbl
+where
+    ISpAudio_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDeviceId<Identity: windows_core::IUnknownImpl + ISpMMSysAudio_Impl>(this: *mut core::ffi::c_void, pudeviceid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpMMSysAudio_Impl::GetDeviceId(this, core::mem::transmute_copy(&pudeviceid)).into()
+            }
+        }
+        unsafe extern "system" fn SetDeviceId<Identity: windows_core::IUnknownImpl + ISpMMSysAudio_Impl>(this: *mut core::ffi::c_void, udeviceid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpMMSysAudio_Impl::SetDeviceId(this, core::mem::transmute_copy(&udeviceid)).into()
+            }
+        }
+        unsafe extern "system" fn GetMMHandle<Identity: windows_core::IUnknownImpl + ISpMMSysAudio_Impl>(this: *mut core::ffi::c_void, phandle: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpMMSysAudio_Impl::GetMMHandle(this, core::mem::transmute_copy(&phandle)).into()
+            }
+        }
+        unsafe extern "system" fn GetLineId<Identity: windows_core::IUnknownImpl + ISpMMSysAudio_Impl>(this: *mut core::ffi::c_void, pulineid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpMMSysAudio_Impl::GetLineId(this, core::mem::transmute_copy(&pulineid)).into()
+            }
+        }
+        unsafe extern "system" fn SetLineId<Identity: windows_core::IUnknownImpl + ISpMMSysAudio_Impl>(this: *mut core::ffi::c_void, ulineid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpMMSysAudio_Impl::SetLineId(this, core::mem::transmute_copy(&ulineid)).into()
+            }
+        }
+        Self {
+            base__: <ISpAudio_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDeviceId: GetDeviceId::<Identity>,
+            SetDeviceId: SetDeviceId::<Identity>,
+            GetMMHandle: GetMMHandle::<Identity>,
+            GetLineId: GetLineId::<Identity>,
+            SetLineId: SetLineId::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com"))]
 impl windows_core::RuntimeName for ISpMMSysAudio {}
 windows_core::imp::define_interface!(ISpNotifyCallback, ISpNotifyCallback_Vtbl);
 impl ISpNotifyCallback {
@@ -2092,6 +2828,20 @@ impl ISpNotifySink_Vtbl {
         iid == &<ISpNotifySink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpNotifySink_Impl> windows_core::imp::TearOffVtable<Identity> for ISpNotifySink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Notify<Identity: windows_core::IUnknownImpl + ISpNotifySink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpNotifySink_Impl::Notify(this).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Notify: Notify::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ISpNotifySink {}
 windows_core::imp::define_interface!(ISpNotifySource, ISpNotifySource_Vtbl, 0x5eff4aef_8487_11d2_961c_00c04f8ee628);
 windows_core::imp::interface_hierarchy!(ISpNotifySource, windows_core::IUnknown);
@@ -2204,6 +2954,65 @@ impl ISpNotifySource_Vtbl {
         iid == &<ISpNotifySource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpNotifySource_Impl> windows_core::imp::TearOffVtable<Identity> for ISpNotifySource_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetNotifySink<Identity: windows_core::IUnknownImpl + ISpNotifySource_Impl>(this: *mut core::ffi::c_void, pnotifysink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpNotifySource_Impl::SetNotifySink(this, core::mem::transmute_copy(&pnotifysink)).into()
+            }
+        }
+        unsafe extern "system" fn SetNotifyWindowMessage<Identity: windows_core::IUnknownImpl + ISpNotifySource_Impl>(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, msg: u32, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpNotifySource_Impl::SetNotifyWindowMessage(this, core::mem::transmute_copy(&hwnd), core::mem::transmute_copy(&msg), core::mem::transmute_copy(&wparam), core::mem::transmute_copy(&lparam)).into()
+            }
+        }
+        unsafe extern "system" fn SetNotifyCallbackFunction<Identity: windows_core::IUnknownImpl + ISpNotifySource_Impl>(this: *mut core::ffi::c_void, pfncallback: *mut SPNOTIFYCALLBACK, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpNotifySource_Impl::SetNotifyCallbackFunction(this, core::mem::transmute_copy(&pfncallback), core::mem::transmute_copy(&wparam), core::mem::transmute_copy(&lparam)).into()
+            }
+        }
+        unsafe extern "system" fn SetNotifyCallbackInterface<Identity: windows_core::IUnknownImpl + ISpNotifySource_Impl>(this: *mut core::ffi::c_void, pspcallback: *mut core::ffi::c_void, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpNotifySource_Impl::SetNotifyCallbackInterface(this, core::mem::transmute_copy(&pspcallback), core::mem::transmute_copy(&wparam), core::mem::transmute_copy(&lparam)).into()
+            }
+        }
+        unsafe extern "system" fn SetNotifyWin32Event<Identity: windows_core::IUnknownImpl + ISpNotifySource_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpNotifySource_Impl::SetNotifyWin32Event(this).into()
+            }
+        }
+        unsafe extern "system" fn WaitForNotifyEvent<Identity: windows_core::IUnknownImpl + ISpNotifySource_Impl>(this: *mut core::ffi::c_void, dwmilliseconds: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpNotifySource_Impl::WaitForNotifyEvent(this, core::mem::transmute_copy(&dwmilliseconds)).into()
+            }
+        }
+        unsafe extern "system" fn GetNotifyEventHandle<Identity: windows_core::IUnknownImpl + ISpNotifySource_Impl>(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpNotifySource_Impl::GetNotifyEventHandle(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetNotifySink: SetNotifySink::<Identity>,
+            SetNotifyWindowMessage: SetNotifyWindowMessage::<Identity>,
+            SetNotifyCallbackFunction: SetNotifyCallbackFunction::<Identity>,
+            SetNotifyCallbackInterface: SetNotifyCallbackInterface::<Identity>,
+            SetNotifyWin32Event: SetNotifyWin32Event::<Identity>,
+            WaitForNotifyEvent: WaitForNotifyEvent::<Identity>,
+            GetNotifyEventHandle: GetNotifyEventHandle::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpNotifySource {}
 windows_core::imp::define_interface!(ISpNotifyTranslator, ISpNotifyTranslator_Vtbl, 0xaca16614_5d3d_11d2_960e_00c04f8ee628);
 impl core::ops::Deref for ISpNotifyTranslator {
@@ -2307,6 +3116,58 @@ impl ISpNotifyTranslator_Vtbl {
         iid == &<ISpNotifyTranslator as windows_core::Interface>::IID || iid == &<ISpNotifySink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpNotifyTranslator_Impl> windows_core::imp::TearOffVtable<Identity> for ISpNotifyTranslator_Vtbl
+where
+    ISpNotifySink_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InitWindowMessage<Identity: windows_core::IUnknownImpl + ISpNotifyTranslator_Impl>(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, msg: u32, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpNotifyTranslator_Impl::InitWindowMessage(this, core::mem::transmute_copy(&hwnd), core::mem::transmute_copy(&msg), core::mem::transmute_copy(&wparam), core::mem::transmute_copy(&lparam)).into()
+            }
+        }
+        unsafe extern "system" fn InitCallback<Identity: windows_core::IUnknownImpl + ISpNotifyTranslator_Impl>(this: *mut core::ffi::c_void, pfncallback: *mut SPNOTIFYCALLBACK, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpNotifyTranslator_Impl::InitCallback(this, core::mem::transmute_copy(&pfncallback), core::mem::transmute_copy(&wparam), core::mem::transmute_copy(&lparam)).into()
+            }
+        }
+        unsafe extern "system" fn InitSpNotifyCallback<Identity: windows_core::IUnknownImpl + ISpNotifyTranslator_Impl>(this: *mut core::ffi::c_void, pspcallback: *mut core::ffi::c_void, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpNotifyTranslator_Impl::InitSpNotifyCallback(this, core::mem::transmute_copy(&pspcallback), core::mem::transmute_copy(&wparam), core::mem::transmute_copy(&lparam)).into()
+            }
+        }
+        unsafe extern "system" fn InitWin32Event<Identity: windows_core::IUnknownImpl + ISpNotifyTranslator_Impl>(this: *mut core::ffi::c_void, hevent: super::super::Foundation::HANDLE, fclosehandleonrelease: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpNotifyTranslator_Impl::InitWin32Event(this, core::mem::transmute_copy(&hevent), core::mem::transmute_copy(&fclosehandleonrelease)).into()
+            }
+        }
+        unsafe extern "system" fn Wait<Identity: windows_core::IUnknownImpl + ISpNotifyTranslator_Impl>(this: *mut core::ffi::c_void, dwmilliseconds: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpNotifyTranslator_Impl::Wait(this, core::mem::transmute_copy(&dwmilliseconds)).into()
+            }
+        }
+        unsafe extern "system" fn GetEventHandle<Identity: windows_core::IUnknownImpl + ISpNotifyTranslator_Impl>(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpNotifyTranslator_Impl::GetEventHandle(this)
+            }
+        }
+        Self {
+            base__: <ISpNotifySink_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InitWindowMessage: InitWindowMessage::<Identity>,
+            InitCallback: InitCallback::<Identity>,
+            InitSpNotifyCallback: InitSpNotifyCallback::<Identity>,
+            InitWin32Event: InitWin32Event::<Identity>,
+            Wait: Wait::<Identity>,
+            GetEventHandle: GetEventHandle::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpNotifyTranslator {}
 windows_core::imp::define_interface!(ISpObjectToken, ISpObjectToken_Vtbl, 0x14056589_e16c_11d2_bb90_00c04f8ee6c0);
 impl core::ops::Deref for ISpObjectToken {
@@ -2508,6 +3369,104 @@ impl ISpObjectToken_Vtbl {
         iid == &<ISpObjectToken as windows_core::Interface>::IID || iid == &<ISpDataKey as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpObjectToken_Impl> windows_core::imp::TearOffVtable<Identity> for ISpObjectToken_Vtbl
+where
+    ISpDataKey_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetId<Identity: windows_core::IUnknownImpl + ISpObjectToken_Impl>(this: *mut core::ffi::c_void, pszcategoryid: windows_core::PCWSTR, psztokenid: windows_core::PCWSTR, fcreateifnotexist: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpObjectToken_Impl::SetId(this, core::mem::transmute(&pszcategoryid), core::mem::transmute(&psztokenid), core::mem::transmute_copy(&fcreateifnotexist)).into()
+            }
+        }
+        unsafe extern "system" fn GetId<Identity: windows_core::IUnknownImpl + ISpObjectToken_Impl>(this: *mut core::ffi::c_void, ppszcomemtokenid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpObjectToken_Impl::GetId(this) {
+                    Ok(ok__) => {
+                        ppszcomemtokenid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCategory<Identity: windows_core::IUnknownImpl + ISpObjectToken_Impl>(this: *mut core::ffi::c_void, pptokencategory: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpObjectToken_Impl::GetCategory(this) {
+                    Ok(ok__) => {
+                        pptokencategory.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateInstance<Identity: windows_core::IUnknownImpl + ISpObjectToken_Impl>(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, dwclscontext: u32, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpObjectToken_Impl::CreateInstance(this, core::mem::transmute_copy(&punkouter), core::mem::transmute_copy(&dwclscontext), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvobject)).into()
+            }
+        }
+        unsafe extern "system" fn GetStorageFileName<Identity: windows_core::IUnknownImpl + ISpObjectToken_Impl>(this: *mut core::ffi::c_void, clsidcaller: *const windows_core::GUID, pszvaluename: windows_core::PCWSTR, pszfilenamespecifier: windows_core::PCWSTR, nfolder: u32, ppszfilepath: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpObjectToken_Impl::GetStorageFileName(this, core::mem::transmute_copy(&clsidcaller), core::mem::transmute(&pszvaluename), core::mem::transmute(&pszfilenamespecifier), core::mem::transmute_copy(&nfolder)) {
+                    Ok(ok__) => {
+                        ppszfilepath.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveStorageFileName<Identity: windows_core::IUnknownImpl + ISpObjectToken_Impl>(this: *mut core::ffi::c_void, clsidcaller: *const windows_core::GUID, pszkeyname: windows_core::PCWSTR, fdeletefile: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpObjectToken_Impl::RemoveStorageFileName(this, core::mem::transmute_copy(&clsidcaller), core::mem::transmute(&pszkeyname), core::mem::transmute_copy(&fdeletefile)).into()
+            }
+        }
+        unsafe extern "system" fn Remove<Identity: windows_core::IUnknownImpl + ISpObjectToken_Impl>(this: *mut core::ffi::c_void, pclsidcaller: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpObjectToken_Impl::Remove(this, core::mem::transmute_copy(&pclsidcaller)).into()
+            }
+        }
+        unsafe extern "system" fn IsUISupported<Identity: windows_core::IUnknownImpl + ISpObjectToken_Impl>(this: *mut core::ffi::c_void, psztypeofui: windows_core::PCWSTR, pvextradata: *mut core::ffi::c_void, cbextradata: u32, punkobject: *mut core::ffi::c_void, pfsupported: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpObjectToken_Impl::IsUISupported(this, core::mem::transmute(&psztypeofui), core::mem::transmute_copy(&pvextradata), core::mem::transmute_copy(&cbextradata), core::mem::transmute_copy(&punkobject), core::mem::transmute_copy(&pfsupported)).into()
+            }
+        }
+        unsafe extern "system" fn DisplayUI<Identity: windows_core::IUnknownImpl + ISpObjectToken_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, psztitle: windows_core::PCWSTR, psztypeofui: windows_core::PCWSTR, pvextradata: *mut core::ffi::c_void, cbextradata: u32, punkobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpObjectToken_Impl::DisplayUI(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute(&psztitle), core::mem::transmute(&psztypeofui), core::mem::transmute_copy(&pvextradata), core::mem::transmute_copy(&cbextradata), core::mem::transmute_copy(&punkobject)).into()
+            }
+        }
+        unsafe extern "system" fn MatchesAttributes<Identity: windows_core::IUnknownImpl + ISpObjectToken_Impl>(this: *mut core::ffi::c_void, pszattributes: windows_core::PCWSTR, pfmatches: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpObjectToken_Impl::MatchesAttributes(this, core::mem::transmute(&pszattributes), core::mem::transmute_copy(&pfmatches)).into()
+            }
+        }
+        Self {
+            base__: <ISpDataKey_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetId: SetId::<Identity>,
+            GetId: GetId::<Identity>,
+            GetCategory: GetCategory::<Identity>,
+            CreateInstance: CreateInstance::<Identity>,
+            GetStorageFileName: GetStorageFileName::<Identity>,
+            RemoveStorageFileName: RemoveStorageFileName::<Identity>,
+            Remove: Remove::<Identity>,
+            IsUISupported: IsUISupported::<Identity>,
+            DisplayUI: DisplayUI::<Identity>,
+            MatchesAttributes: MatchesAttributes::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpObjectToken {}
 windows_core::imp::define_interface!(ISpObjectTokenCategory, ISpObjectTokenCategory_Vtbl, 0x2d3d3845_39af_4850_bbf9_40b49780011d);
 impl core::ops::Deref for ISpObjectTokenCategory {
@@ -2654,9 +3613,85 @@ impl ISpObjectTokenCategory_Vtbl {
         iid == &<ISpObjectTokenCategory as windows_core::Interface>::IID || iid == &<ISpDataKey as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for ISpObjectTokenCategory {}
-windows_core::imp::define_interface!(ISpObjectTokenEnumBuilder, ISpObjectTokenEnumBuilder_Vtbl, 0x06b64f9f_7fda_11d2_b4f2_00c04f797396);
-impl core::ops::Deref for ISpObjectTokenEnumBuilder {
+impl<Identity: windows_core::IUnknownImpl + ISpObjectTokenCategory_Impl> windows_core::imp::TearOffVtable<Identity> for ISpObjectTokenCategory_Vtbl
+where
+    ISpDataKey_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetId<Identity: windows_core::IUnknownImpl + ISpObjectTokenCategory_Impl>(this: *mut core::ffi::c_void, pszcategoryid: windows_core::PCWSTR, fcreateifnotexist: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpObjectTokenCategory_Impl::SetId(this, core::mem::transmute(&pszcategoryid), core::mem::transmute_copy(&fcreateifnotexist)).into()
+            }
+        }
+        unsafe extern "system" fn GetId<Identity: windows_core::IUnknownImpl + ISpObjectTokenCategory_Impl>(this: *mut core::ffi::c_void, ppszcomemcategoryid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpObjectTokenCategory_Impl::GetId(this) {
+                    Ok(ok__) => {
+                        ppszcomemcategoryid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDataKey<Identity: windows_core::IUnknownImpl + ISpObjectTokenCategory_Impl>(this: *mut core::ffi::c_void, spdkl: SPDATAKEYLOCATION, ppdatakey: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpObjectTokenCategory_Impl::GetDataKey(this, core::mem::transmute_copy(&spdkl)) {
+                    Ok(ok__) => {
+                        ppdatakey.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumTokens<Identity: windows_core::IUnknownImpl + ISpObjectTokenCategory_Impl>(this: *mut core::ffi::c_void, pzsreqattribs: windows_core::PCWSTR, pszoptattribs: windows_core::PCWSTR, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpObjectTokenCategory_Impl::EnumTokens(this, core::mem::transmute(&pzsreqattribs), core::mem::transmute(&pszoptattribs)) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDefaultTokenId<Identity: windows_core::IUnknownImpl + ISpObjectTokenCategory_Impl>(this: *mut core::ffi::c_void, psztokenid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpObjectTokenCategory_Impl::SetDefaultTokenId(this, core::mem::transmute(&psztokenid)).into()
+            }
+        }
+        unsafe extern "system" fn GetDefaultTokenId<Identity: windows_core::IUnknownImpl + ISpObjectTokenCategory_Impl>(this: *mut core::ffi::c_void, ppszcomemtokenid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpObjectTokenCategory_Impl::GetDefaultTokenId(this) {
+                    Ok(ok__) => {
+                        ppszcomemtokenid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ISpDataKey_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetId: SetId::<Identity>,
+            GetId: GetId::<Identity>,
+            GetDataKey: GetDataKey::<Identity>,
+            EnumTokens: EnumTokens::<Identity>,
+            SetDefaultTokenId: SetDefaultTokenId::<Identity>,
+            GetDefaultTokenId: GetDefaultTokenId::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for ISpObjectTokenCategory {}
+windows_core::imp::define_interface!(ISpObjectTokenEnumBuilder, ISpObjectTokenEnumBuilder_Vtbl, 0x06b64f9f_7fda_11d2_b4f2_00c04f797396);
+impl core::ops::Deref for ISpObjectTokenEnumBuilder {
     type Target = IEnumSpObjectTokens;
     fn deref(&self) -> &Self::Target {
         unsafe { core::mem::transmute(self) }
@@ -2757,6 +3792,51 @@ impl ISpObjectTokenEnumBuilder_Vtbl {
         iid == &<ISpObjectTokenEnumBuilder as windows_core::Interface>::IID || iid == &<IEnumSpObjectTokens as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpObjectTokenEnumBuilder_Impl> windows_core::imp::TearOffVtable<Identity> for ISpObjectTokenEnumBuilder_Vtbl
+where
+    IEnumSpObjectTokens_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAttribs<Identity: windows_core::IUnknownImpl + ISpObjectTokenEnumBuilder_Impl>(this: *mut core::ffi::c_void, pszreqattribs: windows_core::PCWSTR, pszoptattribs: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpObjectTokenEnumBuilder_Impl::SetAttribs(this, core::mem::transmute(&pszreqattribs), core::mem::transmute(&pszoptattribs)).into()
+            }
+        }
+        unsafe extern "system" fn AddTokens<Identity: windows_core::IUnknownImpl + ISpObjectTokenEnumBuilder_Impl>(this: *mut core::ffi::c_void, ctokens: u32, ptoken: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpObjectTokenEnumBuilder_Impl::AddTokens(this, core::mem::transmute_copy(&ctokens), core::mem::transmute_copy(&ptoken)).into()
+            }
+        }
+        unsafe extern "system" fn AddTokensFromDataKey<Identity: windows_core::IUnknownImpl + ISpObjectTokenEnumBuilder_Impl>(this: *mut core::ffi::c_void, pdatakey: *mut core::ffi::c_void, pszsubkey: windows_core::PCWSTR, pszcategoryid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpObjectTokenEnumBuilder_Impl::AddTokensFromDataKey(this, core::mem::transmute_copy(&pdatakey), core::mem::transmute(&pszsubkey), core::mem::transmute(&pszcategoryid)).into()
+            }
+        }
+        unsafe extern "system" fn AddTokensFromTokenEnum<Identity: windows_core::IUnknownImpl + ISpObjectTokenEnumBuilder_Impl>(this: *mut core::ffi::c_void, ptokenenum: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpObjectTokenEnumBuilder_Impl::AddTokensFromTokenEnum(this, core::mem::transmute_copy(&ptokenenum)).into()
+            }
+        }
+        unsafe extern "system" fn Sort<Identity: windows_core::IUnknownImpl + ISpObjectTokenEnumBuilder_Impl>(this: *mut core::ffi::c_void, psztokenidtolistfirst: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpObjectTokenEnumBuilder_Impl::Sort(this, core::mem::transmute(&psztokenidtolistfirst)).into()
+            }
+        }
+        Self {
+            base__: <IEnumSpObjectTokens_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAttribs: SetAttribs::<Identity>,
+            AddTokens: AddTokens::<Identity>,
+            AddTokensFromDataKey: AddTokensFromDataKey::<Identity>,
+            AddTokensFromTokenEnum: AddTokensFromTokenEnum::<Identity>,
+            Sort: Sort::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpObjectTokenEnumBuilder {}
 windows_core::imp::define_interface!(ISpObjectTokenInit, ISpObjectTokenInit_Vtbl, 0xb8aab0cf_346f_49d8_9499_c8b03f161d51);
 impl core::ops::Deref for ISpObjectTokenInit {
@@ -2799,6 +3879,20 @@ impl ISpObjectTokenInit_Vtbl {
         iid == &<ISpObjectTokenInit as windows_core::Interface>::IID || iid == &<ISpDataKey as windows_core::Interface>::IID || iid == &<ISpObjectToken as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpObjectTokenInit_Impl> windows_core::imp::TearOffVtable<Identity> for ISpObjectTokenInit_Vtbl
+where
+    ISpObjectToken_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InitFromDataKey<Identity: windows_core::IUnknownImpl + ISpObjectTokenInit_Impl>(this: *mut core::ffi::c_void, pszcategoryid: windows_core::PCWSTR, psztokenid: windows_core::PCWSTR, pdatakey: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpObjectTokenInit_Impl::InitFromDataKey(this, core::mem::transmute(&pszcategoryid), core::mem::transmute(&psztokenid), core::mem::transmute_copy(&pdatakey)).into()
+            }
+        }
+        Self { base__: <ISpObjectToken_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, InitFromDataKey: InitFromDataKey::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ISpObjectTokenInit {}
 windows_core::imp::define_interface!(ISpObjectWithToken, ISpObjectWithToken_Vtbl, 0x5b559f40_e952_11d2_bb91_00c04f8ee6c0);
 windows_core::imp::interface_hierarchy!(ISpObjectWithToken, windows_core::IUnknown);
@@ -2857,6 +3951,36 @@ impl ISpObjectWithToken_Vtbl {
         iid == &<ISpObjectWithToken as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpObjectWithToken_Impl> windows_core::imp::TearOffVtable<Identity> for ISpObjectWithToken_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetObjectToken<Identity: windows_core::IUnknownImpl + ISpObjectWithToken_Impl>(this: *mut core::ffi::c_void, ptoken: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpObjectWithToken_Impl::SetObjectToken(this, core::mem::transmute_copy(&ptoken)).into()
+            }
+        }
+        unsafe extern "system" fn GetObjectToken<Identity: windows_core::IUnknownImpl + ISpObjectWithToken_Impl>(this: *mut core::ffi::c_void, pptoken: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpObjectWithToken_Impl::GetObjectToken(this) {
+                    Ok(ok__) => {
+                        pptoken.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetObjectToken: SetObjectToken::<Identity>,
+            GetObjectToken: GetObjectToken::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpObjectWithToken {}
 windows_core::imp::define_interface!(ISpPhoneConverter, ISpPhoneConverter_Vtbl, 0x8445c581_0cac_4a38_abfe_9b2ce2826455);
 impl core::ops::Deref for ISpPhoneConverter {
@@ -2917,6 +4041,36 @@ impl ISpPhoneConverter_Vtbl {
         iid == &<ISpPhoneConverter as windows_core::Interface>::IID || iid == &<ISpObjectWithToken as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpPhoneConverter_Impl> windows_core::imp::TearOffVtable<Identity> for ISpPhoneConverter_Vtbl
+where
+    ISpObjectWithToken_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn PhoneToId<Identity: windows_core::IUnknownImpl + ISpPhoneConverter_Impl>(this: *mut core::ffi::c_void, pszphone: windows_core::PCWSTR, pid: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpPhoneConverter_Impl::PhoneToId(this, core::mem::transmute(&pszphone)) {
+                    Ok(ok__) => {
+                        pid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IdToPhone<Identity: windows_core::IUnknownImpl + ISpPhoneConverter_Impl>(this: *mut core::ffi::c_void, pid: *const u16, pszphone: windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpPhoneConverter_Impl::IdToPhone(this, core::mem::transmute_copy(&pid), core::mem::transmute_copy(&pszphone)).into()
+            }
+        }
+        Self {
+            base__: <ISpObjectWithToken_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PhoneToId: PhoneToId::<Identity>,
+            IdToPhone: IdToPhone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpPhoneConverter {}
 windows_core::imp::define_interface!(ISpPhoneticAlphabetConverter, ISpPhoneticAlphabetConverter_Vtbl, 0x133adcd4_19b4_4020_9fdc_842e78253b17);
 windows_core::imp::interface_hierarchy!(ISpPhoneticAlphabetConverter, windows_core::IUnknown);
@@ -3017,6 +4171,63 @@ impl ISpPhoneticAlphabetConverter_Vtbl {
         iid == &<ISpPhoneticAlphabetConverter as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpPhoneticAlphabetConverter_Impl> windows_core::imp::TearOffVtable<Identity> for ISpPhoneticAlphabetConverter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetLangId<Identity: windows_core::IUnknownImpl + ISpPhoneticAlphabetConverter_Impl>(this: *mut core::ffi::c_void, plangid: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpPhoneticAlphabetConverter_Impl::GetLangId(this) {
+                    Ok(ok__) => {
+                        plangid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLangId<Identity: windows_core::IUnknownImpl + ISpPhoneticAlphabetConverter_Impl>(this: *mut core::ffi::c_void, langid: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpPhoneticAlphabetConverter_Impl::SetLangId(this, core::mem::transmute_copy(&langid)).into()
+            }
+        }
+        unsafe extern "system" fn SAPI2UPS<Identity: windows_core::IUnknownImpl + ISpPhoneticAlphabetConverter_Impl>(this: *mut core::ffi::c_void, pszsapiid: *const u16, pszupsid: *mut u16, cmaxlength: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpPhoneticAlphabetConverter_Impl::SAPI2UPS(this, core::mem::transmute_copy(&pszsapiid), core::mem::transmute_copy(&pszupsid), core::mem::transmute_copy(&cmaxlength)).into()
+            }
+        }
+        unsafe extern "system" fn UPS2SAPI<Identity: windows_core::IUnknownImpl + ISpPhoneticAlphabetConverter_Impl>(this: *mut core::ffi::c_void, pszupsid: *const u16, pszsapiid: *mut u16, cmaxlength: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpPhoneticAlphabetConverter_Impl::UPS2SAPI(this, core::mem::transmute_copy(&pszupsid), core::mem::transmute_copy(&pszsapiid), core::mem::transmute_copy(&cmaxlength)).into()
+            }
+        }
+        unsafe extern "system" fn GetMaxConvertLength<Identity: windows_core::IUnknownImpl + ISpPhoneticAlphabetConverter_Impl>(this: *mut core::ffi::c_void, csrclength: u32, bsapi2ups: windows_core::BOOL, pcmaxdestlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpPhoneticAlphabetConverter_Impl::GetMaxConvertLength(this, core::mem::transmute_copy(&csrclength), core::mem::transmute_copy(&bsapi2ups)) {
+                    Ok(ok__) => {
+                        pcmaxdestlength.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetLangId: GetLangId::<Identity>,
+            SetLangId: SetLangId::<Identity>,
+            SAPI2UPS: SAPI2UPS::<Identity>,
+            UPS2SAPI: UPS2SAPI::<Identity>,
+            GetMaxConvertLength: GetMaxConvertLength::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpPhoneticAlphabetConverter {}
 windows_core::imp::define_interface!(ISpPhoneticAlphabetSelection, ISpPhoneticAlphabetSelection_Vtbl, 0xb2745efd_42ce_48ca_81f1_a96e02538a90);
 windows_core::imp::interface_hierarchy!(ISpPhoneticAlphabetSelection, windows_core::IUnknown);
@@ -3072,6 +4283,36 @@ impl ISpPhoneticAlphabetSelection_Vtbl {
         iid == &<ISpPhoneticAlphabetSelection as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpPhoneticAlphabetSelection_Impl> windows_core::imp::TearOffVtable<Identity> for ISpPhoneticAlphabetSelection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsAlphabetUPS<Identity: windows_core::IUnknownImpl + ISpPhoneticAlphabetSelection_Impl>(this: *mut core::ffi::c_void, pfisups: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpPhoneticAlphabetSelection_Impl::IsAlphabetUPS(this) {
+                    Ok(ok__) => {
+                        pfisups.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAlphabetToUPS<Identity: windows_core::IUnknownImpl + ISpPhoneticAlphabetSelection_Impl>(this: *mut core::ffi::c_void, fforceups: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpPhoneticAlphabetSelection_Impl::SetAlphabetToUPS(this, core::mem::transmute_copy(&fforceups)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsAlphabetUPS: IsAlphabetUPS::<Identity>,
+            SetAlphabetToUPS: SetAlphabetToUPS::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpPhoneticAlphabetSelection {}
 windows_core::imp::define_interface!(ISpPhrase, ISpPhrase_Vtbl, 0x1a5c0354_b621_4b5a_8791_d306ed379e53);
 windows_core::imp::interface_hierarchy!(ISpPhrase, windows_core::IUnknown);
@@ -3167,6 +4408,57 @@ impl ISpPhrase_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpPhrase_Impl> windows_core::imp::TearOffVtable<Identity> for ISpPhrase_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPhrase<Identity: windows_core::IUnknownImpl + ISpPhrase_Impl>(this: *mut core::ffi::c_void, ppcomemphrase: *mut *mut SPPHRASE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpPhrase_Impl::GetPhrase(this) {
+                    Ok(ok__) => {
+                        ppcomemphrase.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSerializedPhrase<Identity: windows_core::IUnknownImpl + ISpPhrase_Impl>(this: *mut core::ffi::c_void, ppcomemphrase: *mut *mut SPSERIALIZEDPHRASE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpPhrase_Impl::GetSerializedPhrase(this) {
+                    Ok(ok__) => {
+                        ppcomemphrase.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetText<Identity: windows_core::IUnknownImpl + ISpPhrase_Impl>(this: *mut core::ffi::c_void, ulstart: u32, ulcount: u32, fusetextreplacements: windows_core::BOOL, ppszcomemtext: *mut windows_core::PWSTR, pbdisplayattributes: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpPhrase_Impl::GetText(this, core::mem::transmute_copy(&ulstart), core::mem::transmute_copy(&ulcount), core::mem::transmute_copy(&fusetextreplacements), core::mem::transmute_copy(&ppszcomemtext), core::mem::transmute_copy(&pbdisplayattributes)).into()
+            }
+        }
+        unsafe extern "system" fn Discard<Identity: windows_core::IUnknownImpl + ISpPhrase_Impl>(this: *mut core::ffi::c_void, dwvaluetypes: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpPhrase_Impl::Discard(this, core::mem::transmute_copy(&dwvaluetypes)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPhrase: GetPhrase::<Identity>,
+            GetSerializedPhrase: GetSerializedPhrase::<Identity>,
+            GetText: GetText::<Identity>,
+            Discard: Discard::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpPhrase {}
 windows_core::imp::define_interface!(ISpPhrase2, ISpPhrase2_Vtbl, 0xf264da52_e457_4696_b856_a737b717af79);
 impl core::ops::Deref for ISpPhrase2 {
@@ -3247,6 +4539,44 @@ impl ISpPhrase2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpPhrase2_Impl> windows_core::imp::TearOffVtable<Identity> for ISpPhrase2_Vtbl
+where
+    ISpPhrase_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetXMLResult<Identity: windows_core::IUnknownImpl + ISpPhrase2_Impl>(this: *mut core::ffi::c_void, ppszcomemxmlresult: *mut windows_core::PWSTR, options: SPXMLRESULTOPTIONS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpPhrase2_Impl::GetXMLResult(this, core::mem::transmute_copy(&ppszcomemxmlresult), core::mem::transmute_copy(&options)).into()
+            }
+        }
+        unsafe extern "system" fn GetXMLErrorInfo<Identity: windows_core::IUnknownImpl + ISpPhrase2_Impl>(this: *mut core::ffi::c_void, psemanticerrorinfo: *mut SPSEMANTICERRORINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpPhrase2_Impl::GetXMLErrorInfo(this, core::mem::transmute_copy(&psemanticerrorinfo)).into()
+            }
+        }
+        unsafe extern "system" fn GetAudio<Identity: windows_core::IUnknownImpl + ISpPhrase2_Impl>(this: *mut core::ffi::c_void, ulstartelement: u32, celements: u32, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpPhrase2_Impl::GetAudio(this, core::mem::transmute_copy(&ulstartelement), core::mem::transmute_copy(&celements)) {
+                    Ok(ok__) => {
+                        ppstream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ISpPhrase_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetXMLResult: GetXMLResult::<Identity>,
+            GetXMLErrorInfo: GetXMLErrorInfo::<Identity>,
+            GetAudio: GetAudio::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpPhrase2 {}
 windows_core::imp::define_interface!(ISpPhraseAlt, ISpPhraseAlt_Vtbl, 0x8fcebc98_4e49_4067_9c6c_d86a0e092e3d);
 impl core::ops::Deref for ISpPhraseAlt {
@@ -3298,6 +4628,27 @@ impl ISpPhraseAlt_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpPhraseAlt_Impl> windows_core::imp::TearOffVtable<Identity> for ISpPhraseAlt_Vtbl
+where
+    ISpPhrase_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAltInfo<Identity: windows_core::IUnknownImpl + ISpPhraseAlt_Impl>(this: *mut core::ffi::c_void, ppparent: *mut *mut core::ffi::c_void, pulstartelementinparent: *mut u32, pcelementsinparent: *mut u32, pcelementsinalt: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpPhraseAlt_Impl::GetAltInfo(this, core::mem::transmute_copy(&ppparent), core::mem::transmute_copy(&pulstartelementinparent), core::mem::transmute_copy(&pcelementsinparent), core::mem::transmute_copy(&pcelementsinalt)).into()
+            }
+        }
+        unsafe extern "system" fn Commit<Identity: windows_core::IUnknownImpl + ISpPhraseAlt_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpPhraseAlt_Impl::Commit(this).into()
+            }
+        }
+        Self { base__: <ISpPhrase_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetAltInfo: GetAltInfo::<Identity>, Commit: Commit::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpPhraseAlt {}
 windows_core::imp::define_interface!(ISpPhraseBuilder, ISpPhraseBuilder_Vtbl, 0x88a3342a_0bed_4834_922b_88d43173162f);
 impl core::ops::Deref for ISpPhraseBuilder {
@@ -3427,33 +4778,98 @@ impl ISpPhraseBuilder_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for ISpPhraseBuilder {}
-windows_core::imp::define_interface!(ISpPrivateEngineCallEx, ISpPrivateEngineCallEx_Vtbl, 0xdefd682a_fe0a_42b9_bfa1_56d3d6cecfaf);
-windows_core::imp::interface_hierarchy!(ISpPrivateEngineCallEx, windows_core::IUnknown);
-impl ISpPrivateEngineCallEx {
-    pub unsafe fn CallEngineSynchronize(&self, pinframe: *const core::ffi::c_void, ulinframesize: u32, ppcomemoutframe: *mut *mut core::ffi::c_void, puloutframesize: *mut u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).CallEngineSynchronize)(windows_core::Interface::as_raw(self), pinframe, ulinframesize, ppcomemoutframe as _, puloutframesize as _).ok() }
-    }
-    pub unsafe fn CallEngineImmediate(&self, pinframe: *const core::ffi::c_void, ulinframesize: u32, ppcomemoutframe: *mut *mut core::ffi::c_void, puloutframesize: *mut u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).CallEngineImmediate)(windows_core::Interface::as_raw(self), pinframe, ulinframesize, ppcomemoutframe as _, puloutframesize as _).ok() }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct ISpPrivateEngineCallEx_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    pub CallEngineSynchronize: unsafe extern "system" fn(*mut core::ffi::c_void, *const core::ffi::c_void, u32, *mut *mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
-    pub CallEngineImmediate: unsafe extern "system" fn(*mut core::ffi::c_void, *const core::ffi::c_void, u32, *mut *mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
-}
-pub trait ISpPrivateEngineCallEx_Impl: windows_core::IUnknownImpl {
-    fn CallEngineSynchronize(&self, pinframe: *const core::ffi::c_void, ulinframesize: u32, ppcomemoutframe: *mut *mut core::ffi::c_void, puloutframesize: *mut u32) -> windows_core::Result<()>;
-    fn CallEngineImmediate(&self, pinframe: *const core::ffi::c_void, ulinframesize: u32, ppcomemoutframe: *mut *mut core::ffi::c_void, puloutframesize: *mut u32) -> windows_core::Result<()>;
-}
-impl ISpPrivateEngineCallEx_Vtbl {
-    pub const fn new<Identity: ISpPrivateEngineCallEx_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn CallEngineSynchronize<Identity: ISpPrivateEngineCallEx_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pinframe: *const core::ffi::c_void, ulinframesize: u32, ppcomemoutframe: *mut *mut core::ffi::c_void, puloutframesize: *mut u32) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+impl<Identity: windows_core::IUnknownImpl + ISpPhraseBuilder_Impl> windows_core::imp::TearOffVtable<Identity> for ISpPhraseBuilder_Vtbl
+where
+    ISpPhrase_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InitFromPhrase<Identity: windows_core::IUnknownImpl + ISpPhraseBuilder_Impl>(this: *mut core::ffi::c_void, pphrase: *const SPPHRASE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpPhraseBuilder_Impl::InitFromPhrase(this, core::mem::transmute_copy(&pphrase)).into()
+            }
+        }
+        unsafe extern "system" fn InitFromSerializedPhrase<Identity: windows_core::IUnknownImpl + ISpPhraseBuilder_Impl>(this: *mut core::ffi::c_void, pphrase: *const SPSERIALIZEDPHRASE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpPhraseBuilder_Impl::InitFromSerializedPhrase(this, core::mem::transmute_copy(&pphrase)).into()
+            }
+        }
+        unsafe extern "system" fn AddElements<Identity: windows_core::IUnknownImpl + ISpPhraseBuilder_Impl>(this: *mut core::ffi::c_void, celements: u32, pelement: *const SPPHRASEELEMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpPhraseBuilder_Impl::AddElements(this, core::mem::transmute_copy(&celements), core::mem::transmute_copy(&pelement)).into()
+            }
+        }
+        unsafe extern "system" fn AddRules<Identity: windows_core::IUnknownImpl + ISpPhraseBuilder_Impl>(this: *mut core::ffi::c_void, hparent: SPPHRASERULEHANDLE, prule: *const SPPHRASERULE, phnewrule: *mut SPPHRASERULEHANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpPhraseBuilder_Impl::AddRules(this, core::mem::transmute_copy(&hparent), core::mem::transmute_copy(&prule)) {
+                    Ok(ok__) => {
+                        phnewrule.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddProperties<Identity: windows_core::IUnknownImpl + ISpPhraseBuilder_Impl>(this: *mut core::ffi::c_void, hparent: SPPHRASEPROPERTYHANDLE, pproperty: *const SPPHRASEPROPERTY, phnewproperty: *mut SPPHRASEPROPERTYHANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpPhraseBuilder_Impl::AddProperties(this, core::mem::transmute_copy(&hparent), core::mem::transmute_copy(&pproperty)) {
+                    Ok(ok__) => {
+                        phnewproperty.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddReplacements<Identity: windows_core::IUnknownImpl + ISpPhraseBuilder_Impl>(this: *mut core::ffi::c_void, creplacements: u32, preplacements: *const SPPHRASEREPLACEMENT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpPhraseBuilder_Impl::AddReplacements(this, core::mem::transmute_copy(&creplacements), core::mem::transmute_copy(&preplacements)).into()
+            }
+        }
+        Self {
+            base__: <ISpPhrase_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InitFromPhrase: InitFromPhrase::<Identity>,
+            InitFromSerializedPhrase: InitFromSerializedPhrase::<Identity>,
+            AddElements: AddElements::<Identity>,
+            AddRules: AddRules::<Identity>,
+            AddProperties: AddProperties::<Identity>,
+            AddReplacements: AddReplacements::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for ISpPhraseBuilder {}
+windows_core::imp::define_interface!(ISpPrivateEngineCallEx, ISpPrivateEngineCallEx_Vtbl, 0xdefd682a_fe0a_42b9_bfa1_56d3d6cecfaf);
+windows_core::imp::interface_hierarchy!(ISpPrivateEngineCallEx, windows_core::IUnknown);
+impl ISpPrivateEngineCallEx {
+    pub unsafe fn CallEngineSynchronize(&self, pinframe: *const core::ffi::c_void, ulinframesize: u32, ppcomemoutframe: *mut *mut core::ffi::c_void, puloutframesize: *mut u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).CallEngineSynchronize)(windows_core::Interface::as_raw(self), pinframe, ulinframesize, ppcomemoutframe as _, puloutframesize as _).ok() }
+    }
+    pub unsafe fn CallEngineImmediate(&self, pinframe: *const core::ffi::c_void, ulinframesize: u32, ppcomemoutframe: *mut *mut core::ffi::c_void, puloutframesize: *mut u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).CallEngineImmediate)(windows_core::Interface::as_raw(self), pinframe, ulinframesize, ppcomemoutframe as _, puloutframesize as _).ok() }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct ISpPrivateEngineCallEx_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub CallEngineSynchronize: unsafe extern "system" fn(*mut core::ffi::c_void, *const core::ffi::c_void, u32, *mut *mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
+    pub CallEngineImmediate: unsafe extern "system" fn(*mut core::ffi::c_void, *const core::ffi::c_void, u32, *mut *mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
+}
+pub trait ISpPrivateEngineCallEx_Impl: windows_core::IUnknownImpl {
+    fn CallEngineSynchronize(&self, pinframe: *const core::ffi::c_void, ulinframesize: u32, ppcomemoutframe: *mut *mut core::ffi::c_void, puloutframesize: *mut u32) -> windows_core::Result<()>;
+    fn CallEngineImmediate(&self, pinframe: *const core::ffi::c_void, ulinframesize: u32, ppcomemoutframe: *mut *mut core::ffi::c_void, puloutframesize: *mut u32) -> windows_core::Result<()>;
+}
+impl ISpPrivateEngineCallEx_Vtbl {
+    pub const fn new<Identity: ISpPrivateEngineCallEx_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn CallEngineSynchronize<Identity: ISpPrivateEngineCallEx_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pinframe: *const core::ffi::c_void, ulinframesize: u32, ppcomemoutframe: *mut *mut core::ffi::c_void, puloutframesize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
                 ISpPrivateEngineCallEx_Impl::CallEngineSynchronize(this, core::mem::transmute_copy(&pinframe), core::mem::transmute_copy(&ulinframesize), core::mem::transmute_copy(&ppcomemoutframe), core::mem::transmute_copy(&puloutframesize)).into()
             }
         }
@@ -3473,6 +4889,30 @@ impl ISpPrivateEngineCallEx_Vtbl {
         iid == &<ISpPrivateEngineCallEx as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpPrivateEngineCallEx_Impl> windows_core::imp::TearOffVtable<Identity> for ISpPrivateEngineCallEx_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CallEngineSynchronize<Identity: windows_core::IUnknownImpl + ISpPrivateEngineCallEx_Impl>(this: *mut core::ffi::c_void, pinframe: *const core::ffi::c_void, ulinframesize: u32, ppcomemoutframe: *mut *mut core::ffi::c_void, puloutframesize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpPrivateEngineCallEx_Impl::CallEngineSynchronize(this, core::mem::transmute_copy(&pinframe), core::mem::transmute_copy(&ulinframesize), core::mem::transmute_copy(&ppcomemoutframe), core::mem::transmute_copy(&puloutframesize)).into()
+            }
+        }
+        unsafe extern "system" fn CallEngineImmediate<Identity: windows_core::IUnknownImpl + ISpPrivateEngineCallEx_Impl>(this: *mut core::ffi::c_void, pinframe: *const core::ffi::c_void, ulinframesize: u32, ppcomemoutframe: *mut *mut core::ffi::c_void, puloutframesize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpPrivateEngineCallEx_Impl::CallEngineImmediate(this, core::mem::transmute_copy(&pinframe), core::mem::transmute_copy(&ulinframesize), core::mem::transmute_copy(&ppcomemoutframe), core::mem::transmute_copy(&puloutframesize)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CallEngineSynchronize: CallEngineSynchronize::<Identity>,
+            CallEngineImmediate: CallEngineImmediate::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpPrivateEngineCallEx {}
 windows_core::imp::define_interface!(ISpProperties, ISpProperties_Vtbl, 0x5b4fb971_b115_4de1_ad97_e482e3bf6ee4);
 windows_core::imp::interface_hierarchy!(ISpProperties, windows_core::IUnknown);
@@ -3565,6 +5005,50 @@ impl ISpProperties_Vtbl {
         iid == &<ISpProperties as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpProperties_Impl> windows_core::imp::TearOffVtable<Identity> for ISpProperties_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetPropertyNum<Identity: windows_core::IUnknownImpl + ISpProperties_Impl>(this: *mut core::ffi::c_void, pname: windows_core::PCWSTR, lvalue: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpProperties_Impl::SetPropertyNum(this, core::mem::transmute(&pname), core::mem::transmute_copy(&lvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetPropertyNum<Identity: windows_core::IUnknownImpl + ISpProperties_Impl>(this: *mut core::ffi::c_void, pname: windows_core::PCWSTR, plvalue: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpProperties_Impl::GetPropertyNum(this, core::mem::transmute(&pname), core::mem::transmute_copy(&plvalue)).into()
+            }
+        }
+        unsafe extern "system" fn SetPropertyString<Identity: windows_core::IUnknownImpl + ISpProperties_Impl>(this: *mut core::ffi::c_void, pname: windows_core::PCWSTR, pvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpProperties_Impl::SetPropertyString(this, core::mem::transmute(&pname), core::mem::transmute(&pvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetPropertyString<Identity: windows_core::IUnknownImpl + ISpProperties_Impl>(this: *mut core::ffi::c_void, pname: windows_core::PCWSTR, ppcomemvalue: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpProperties_Impl::GetPropertyString(this, core::mem::transmute(&pname)) {
+                    Ok(ok__) => {
+                        ppcomemvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetPropertyNum: SetPropertyNum::<Identity>,
+            GetPropertyNum: GetPropertyNum::<Identity>,
+            SetPropertyString: SetPropertyString::<Identity>,
+            GetPropertyString: GetPropertyString::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpProperties {}
 windows_core::imp::define_interface!(ISpRecoContext, ISpRecoContext_Vtbl, 0xf740a62f_7c15_489e_8234_940a33d9272d);
 impl core::ops::Deref for ISpRecoContext {
@@ -3862,6 +5346,167 @@ impl ISpRecoContext_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Media_Audio")]
+impl<Identity: windows_core::IUnknownImpl + ISpRecoContext_Impl> windows_core::imp::TearOffVtable<Identity> for ISpRecoContext_Vtbl
+where
+    ISpEventSource_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRecognizer<Identity: windows_core::IUnknownImpl + ISpRecoContext_Impl>(this: *mut core::ffi::c_void, pprecognizer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpRecoContext_Impl::GetRecognizer(this) {
+                    Ok(ok__) => {
+                        pprecognizer.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateGrammar<Identity: windows_core::IUnknownImpl + ISpRecoContext_Impl>(this: *mut core::ffi::c_void, ullgrammarid: u64, ppgrammar: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpRecoContext_Impl::CreateGrammar(this, core::mem::transmute_copy(&ullgrammarid)) {
+                    Ok(ok__) => {
+                        ppgrammar.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStatus<Identity: windows_core::IUnknownImpl + ISpRecoContext_Impl>(this: *mut core::ffi::c_void, pstatus: *mut SPRECOCONTEXTSTATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoContext_Impl::GetStatus(this, core::mem::transmute_copy(&pstatus)).into()
+            }
+        }
+        unsafe extern "system" fn GetMaxAlternates<Identity: windows_core::IUnknownImpl + ISpRecoContext_Impl>(this: *mut core::ffi::c_void, pcalternates: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoContext_Impl::GetMaxAlternates(this, core::mem::transmute_copy(&pcalternates)).into()
+            }
+        }
+        unsafe extern "system" fn SetMaxAlternates<Identity: windows_core::IUnknownImpl + ISpRecoContext_Impl>(this: *mut core::ffi::c_void, calternates: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoContext_Impl::SetMaxAlternates(this, core::mem::transmute_copy(&calternates)).into()
+            }
+        }
+        unsafe extern "system" fn SetAudioOptions<Identity: windows_core::IUnknownImpl + ISpRecoContext_Impl>(this: *mut core::ffi::c_void, options: SPAUDIOOPTIONS, paudioformatid: *const windows_core::GUID, pwaveformatex: *const super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoContext_Impl::SetAudioOptions(this, core::mem::transmute_copy(&options), core::mem::transmute_copy(&paudioformatid), core::mem::transmute_copy(&pwaveformatex)).into()
+            }
+        }
+        unsafe extern "system" fn GetAudioOptions<Identity: windows_core::IUnknownImpl + ISpRecoContext_Impl>(this: *mut core::ffi::c_void, poptions: *mut SPAUDIOOPTIONS, paudioformatid: *mut windows_core::GUID, ppcomemwfex: *mut *mut super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoContext_Impl::GetAudioOptions(this, core::mem::transmute_copy(&poptions), core::mem::transmute_copy(&paudioformatid), core::mem::transmute_copy(&ppcomemwfex)).into()
+            }
+        }
+        unsafe extern "system" fn DeserializeResult<Identity: windows_core::IUnknownImpl + ISpRecoContext_Impl>(this: *mut core::ffi::c_void, pserializedresult: *const SPSERIALIZEDRESULT, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpRecoContext_Impl::DeserializeResult(this, core::mem::transmute_copy(&pserializedresult)) {
+                    Ok(ok__) => {
+                        ppresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Bookmark<Identity: windows_core::IUnknownImpl + ISpRecoContext_Impl>(this: *mut core::ffi::c_void, options: SPBOOKMARKOPTIONS, ullstreamposition: u64, lparamevent: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoContext_Impl::Bookmark(this, core::mem::transmute_copy(&options), core::mem::transmute_copy(&ullstreamposition), core::mem::transmute_copy(&lparamevent)).into()
+            }
+        }
+        unsafe extern "system" fn SetAdaptationData<Identity: windows_core::IUnknownImpl + ISpRecoContext_Impl>(this: *mut core::ffi::c_void, padaptationdata: windows_core::PCWSTR, cch: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoContext_Impl::SetAdaptationData(this, core::mem::transmute(&padaptationdata), core::mem::transmute_copy(&cch)).into()
+            }
+        }
+        unsafe extern "system" fn Pause<Identity: windows_core::IUnknownImpl + ISpRecoContext_Impl>(this: *mut core::ffi::c_void, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoContext_Impl::Pause(this, core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn Resume<Identity: windows_core::IUnknownImpl + ISpRecoContext_Impl>(this: *mut core::ffi::c_void, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoContext_Impl::Resume(this, core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn SetVoice<Identity: windows_core::IUnknownImpl + ISpRecoContext_Impl>(this: *mut core::ffi::c_void, pvoice: *mut core::ffi::c_void, fallowformatchanges: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoContext_Impl::SetVoice(this, core::mem::transmute_copy(&pvoice), core::mem::transmute_copy(&fallowformatchanges)).into()
+            }
+        }
+        unsafe extern "system" fn GetVoice<Identity: windows_core::IUnknownImpl + ISpRecoContext_Impl>(this: *mut core::ffi::c_void, ppvoice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpRecoContext_Impl::GetVoice(this) {
+                    Ok(ok__) => {
+                        ppvoice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetVoicePurgeEvent<Identity: windows_core::IUnknownImpl + ISpRecoContext_Impl>(this: *mut core::ffi::c_void, ulleventinterest: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoContext_Impl::SetVoicePurgeEvent(this, core::mem::transmute_copy(&ulleventinterest)).into()
+            }
+        }
+        unsafe extern "system" fn GetVoicePurgeEvent<Identity: windows_core::IUnknownImpl + ISpRecoContext_Impl>(this: *mut core::ffi::c_void, pulleventinterest: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoContext_Impl::GetVoicePurgeEvent(this, core::mem::transmute_copy(&pulleventinterest)).into()
+            }
+        }
+        unsafe extern "system" fn SetContextState<Identity: windows_core::IUnknownImpl + ISpRecoContext_Impl>(this: *mut core::ffi::c_void, econtextstate: SPCONTEXTSTATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoContext_Impl::SetContextState(this, core::mem::transmute_copy(&econtextstate)).into()
+            }
+        }
+        unsafe extern "system" fn GetContextState<Identity: windows_core::IUnknownImpl + ISpRecoContext_Impl>(this: *mut core::ffi::c_void, pecontextstate: *mut SPCONTEXTSTATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoContext_Impl::GetContextState(this, core::mem::transmute_copy(&pecontextstate)).into()
+            }
+        }
+        Self {
+            base__: <ISpEventSource_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetRecognizer: GetRecognizer::<Identity>,
+            CreateGrammar: CreateGrammar::<Identity>,
+            GetStatus: GetStatus::<Identity>,
+            GetMaxAlternates: GetMaxAlternates::<Identity>,
+            SetMaxAlternates: SetMaxAlternates::<Identity>,
+            SetAudioOptions: SetAudioOptions::<Identity>,
+            GetAudioOptions: GetAudioOptions::<Identity>,
+            DeserializeResult: DeserializeResult::<Identity>,
+            Bookmark: Bookmark::<Identity>,
+            SetAdaptationData: SetAdaptationData::<Identity>,
+            Pause: Pause::<Identity>,
+            Resume: Resume::<Identity>,
+            SetVoice: SetVoice::<Identity>,
+            GetVoice: GetVoice::<Identity>,
+            SetVoicePurgeEvent: SetVoicePurgeEvent::<Identity>,
+            GetVoicePurgeEvent: GetVoicePurgeEvent::<Identity>,
+            SetContextState: SetContextState::<Identity>,
+            GetContextState: GetContextState::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Media_Audio")]
 impl windows_core::RuntimeName for ISpRecoContext {}
 windows_core::imp::define_interface!(ISpRecoContext2, ISpRecoContext2_Vtbl, 0xbead311c_52ff_437f_9464_6b21054ca73d);
 windows_core::imp::interface_hierarchy!(ISpRecoContext2, windows_core::IUnknown);
@@ -3924,6 +5569,37 @@ impl ISpRecoContext2_Vtbl {
         iid == &<ISpRecoContext2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpRecoContext2_Impl> windows_core::imp::TearOffVtable<Identity> for ISpRecoContext2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetGrammarOptions<Identity: windows_core::IUnknownImpl + ISpRecoContext2_Impl>(this: *mut core::ffi::c_void, egrammaroptions: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoContext2_Impl::SetGrammarOptions(this, core::mem::transmute_copy(&egrammaroptions)).into()
+            }
+        }
+        unsafe extern "system" fn GetGrammarOptions<Identity: windows_core::IUnknownImpl + ISpRecoContext2_Impl>(this: *mut core::ffi::c_void, pegrammaroptions: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoContext2_Impl::GetGrammarOptions(this, core::mem::transmute_copy(&pegrammaroptions)).into()
+            }
+        }
+        unsafe extern "system" fn SetAdaptationData2<Identity: windows_core::IUnknownImpl + ISpRecoContext2_Impl>(this: *mut core::ffi::c_void, padaptationdata: windows_core::PCWSTR, cch: u32, ptopicname: windows_core::PCWSTR, eadaptationsettings: u32, erelevance: SPADAPTATIONRELEVANCE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoContext2_Impl::SetAdaptationData2(this, core::mem::transmute(&padaptationdata), core::mem::transmute_copy(&cch), core::mem::transmute(&ptopicname), core::mem::transmute_copy(&eadaptationsettings), core::mem::transmute_copy(&erelevance)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetGrammarOptions: SetGrammarOptions::<Identity>,
+            GetGrammarOptions: GetGrammarOptions::<Identity>,
+            SetAdaptationData2: SetAdaptationData2::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpRecoContext2 {}
 windows_core::imp::define_interface!(ISpRecoGrammar, ISpRecoGrammar_Vtbl, 0x2177db29_7f45_47d0_8554_067e91c80502);
 impl core::ops::Deref for ISpRecoGrammar {
@@ -4209,47 +5885,190 @@ impl ISpRecoGrammar_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for ISpRecoGrammar {}
-windows_core::imp::define_interface!(ISpRecoGrammar2, ISpRecoGrammar2_Vtbl, 0x4b37bc9e_9ed6_44a3_93d3_18f022b79ec3);
-windows_core::imp::interface_hierarchy!(ISpRecoGrammar2, windows_core::IUnknown);
-impl ISpRecoGrammar2 {
-    pub unsafe fn GetRules(&self, ppcomemrules: *mut *mut SPRULE, punumrules: *mut u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetRules)(windows_core::Interface::as_raw(self), ppcomemrules as _, punumrules as _).ok() }
-    }
-    pub unsafe fn LoadCmdFromFile2<P0, P2, P3>(&self, pszfilename: P0, options: SPLOADOPTIONS, pszsharinguri: P2, pszbaseuri: P3) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<windows_core::PCWSTR>,
-        P2: windows_core::Param<windows_core::PCWSTR>,
-        P3: windows_core::Param<windows_core::PCWSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).LoadCmdFromFile2)(windows_core::Interface::as_raw(self), pszfilename.param().abi(), options, pszsharinguri.param().abi(), pszbaseuri.param().abi()).ok() }
-    }
-    pub unsafe fn LoadCmdFromMemory2<P2, P3>(&self, pgrammar: *const SPBINARYGRAMMAR, options: SPLOADOPTIONS, pszsharinguri: P2, pszbaseuri: P3) -> windows_core::Result<()>
-    where
-        P2: windows_core::Param<windows_core::PCWSTR>,
-        P3: windows_core::Param<windows_core::PCWSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).LoadCmdFromMemory2)(windows_core::Interface::as_raw(self), pgrammar, options, pszsharinguri.param().abi(), pszbaseuri.param().abi()).ok() }
-    }
-    pub unsafe fn SetRulePriority<P0>(&self, pszrulename: P0, ulruleid: u32, nrulepriority: i32) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<windows_core::PCWSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).SetRulePriority)(windows_core::Interface::as_raw(self), pszrulename.param().abi(), ulruleid, nrulepriority).ok() }
-    }
-    pub unsafe fn SetRuleWeight<P0>(&self, pszrulename: P0, ulruleid: u32, flweight: f32) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<windows_core::PCWSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).SetRuleWeight)(windows_core::Interface::as_raw(self), pszrulename.param().abi(), ulruleid, flweight).ok() }
-    }
-    pub unsafe fn SetDictationWeight(&self, flweight: f32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetDictationWeight)(windows_core::Interface::as_raw(self), flweight).ok() }
-    }
-    #[cfg(feature = "Win32_System_Com")]
-    pub unsafe fn SetGrammarLoader<P0>(&self, ploader: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<ISpeechResourceLoader>,
+impl<Identity: windows_core::IUnknownImpl + ISpRecoGrammar_Impl> windows_core::imp::TearOffVtable<Identity> for ISpRecoGrammar_Vtbl
+where
+    ISpGrammarBuilder_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetGrammarId<Identity: windows_core::IUnknownImpl + ISpRecoGrammar_Impl>(this: *mut core::ffi::c_void, pullgrammarid: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar_Impl::GetGrammarId(this, core::mem::transmute_copy(&pullgrammarid)).into()
+            }
+        }
+        unsafe extern "system" fn GetRecoContext<Identity: windows_core::IUnknownImpl + ISpRecoGrammar_Impl>(this: *mut core::ffi::c_void, pprecoctxt: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpRecoGrammar_Impl::GetRecoContext(this) {
+                    Ok(ok__) => {
+                        pprecoctxt.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LoadCmdFromFile<Identity: windows_core::IUnknownImpl + ISpRecoGrammar_Impl>(this: *mut core::ffi::c_void, pszfilename: windows_core::PCWSTR, options: SPLOADOPTIONS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar_Impl::LoadCmdFromFile(this, core::mem::transmute(&pszfilename), core::mem::transmute_copy(&options)).into()
+            }
+        }
+        unsafe extern "system" fn LoadCmdFromObject<Identity: windows_core::IUnknownImpl + ISpRecoGrammar_Impl>(this: *mut core::ffi::c_void, rcid: *const windows_core::GUID, pszgrammarname: windows_core::PCWSTR, options: SPLOADOPTIONS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar_Impl::LoadCmdFromObject(this, core::mem::transmute_copy(&rcid), core::mem::transmute(&pszgrammarname), core::mem::transmute_copy(&options)).into()
+            }
+        }
+        unsafe extern "system" fn LoadCmdFromResource<Identity: windows_core::IUnknownImpl + ISpRecoGrammar_Impl>(this: *mut core::ffi::c_void, hmodule: super::super::Foundation::HMODULE, pszresourcename: windows_core::PCWSTR, pszresourcetype: windows_core::PCWSTR, wlanguage: u16, options: SPLOADOPTIONS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar_Impl::LoadCmdFromResource(this, core::mem::transmute_copy(&hmodule), core::mem::transmute(&pszresourcename), core::mem::transmute(&pszresourcetype), core::mem::transmute_copy(&wlanguage), core::mem::transmute_copy(&options)).into()
+            }
+        }
+        unsafe extern "system" fn LoadCmdFromMemory<Identity: windows_core::IUnknownImpl + ISpRecoGrammar_Impl>(this: *mut core::ffi::c_void, pgrammar: *const SPBINARYGRAMMAR, options: SPLOADOPTIONS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar_Impl::LoadCmdFromMemory(this, core::mem::transmute_copy(&pgrammar), core::mem::transmute_copy(&options)).into()
+            }
+        }
+        unsafe extern "system" fn LoadCmdFromProprietaryGrammar<Identity: windows_core::IUnknownImpl + ISpRecoGrammar_Impl>(this: *mut core::ffi::c_void, rguidparam: *const windows_core::GUID, pszstringparam: windows_core::PCWSTR, pvdataprarm: *const core::ffi::c_void, cbdatasize: u32, options: SPLOADOPTIONS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar_Impl::LoadCmdFromProprietaryGrammar(this, core::mem::transmute_copy(&rguidparam), core::mem::transmute(&pszstringparam), core::mem::transmute_copy(&pvdataprarm), core::mem::transmute_copy(&cbdatasize), core::mem::transmute_copy(&options)).into()
+            }
+        }
+        unsafe extern "system" fn SetRuleState<Identity: windows_core::IUnknownImpl + ISpRecoGrammar_Impl>(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, preserved: *mut core::ffi::c_void, newstate: SPRULESTATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar_Impl::SetRuleState(this, core::mem::transmute(&pszname), core::mem::transmute_copy(&preserved), core::mem::transmute_copy(&newstate)).into()
+            }
+        }
+        unsafe extern "system" fn SetRuleIdState<Identity: windows_core::IUnknownImpl + ISpRecoGrammar_Impl>(this: *mut core::ffi::c_void, ulruleid: u32, newstate: SPRULESTATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar_Impl::SetRuleIdState(this, core::mem::transmute_copy(&ulruleid), core::mem::transmute_copy(&newstate)).into()
+            }
+        }
+        unsafe extern "system" fn LoadDictation<Identity: windows_core::IUnknownImpl + ISpRecoGrammar_Impl>(this: *mut core::ffi::c_void, psztopicname: windows_core::PCWSTR, options: SPLOADOPTIONS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar_Impl::LoadDictation(this, core::mem::transmute(&psztopicname), core::mem::transmute_copy(&options)).into()
+            }
+        }
+        unsafe extern "system" fn UnloadDictation<Identity: windows_core::IUnknownImpl + ISpRecoGrammar_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar_Impl::UnloadDictation(this).into()
+            }
+        }
+        unsafe extern "system" fn SetDictationState<Identity: windows_core::IUnknownImpl + ISpRecoGrammar_Impl>(this: *mut core::ffi::c_void, newstate: SPRULESTATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar_Impl::SetDictationState(this, core::mem::transmute_copy(&newstate)).into()
+            }
+        }
+        unsafe extern "system" fn SetWordSequenceData<Identity: windows_core::IUnknownImpl + ISpRecoGrammar_Impl>(this: *mut core::ffi::c_void, ptext: windows_core::PCWSTR, cchtext: u32, pinfo: *const SPTEXTSELECTIONINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar_Impl::SetWordSequenceData(this, core::mem::transmute(&ptext), core::mem::transmute_copy(&cchtext), core::mem::transmute_copy(&pinfo)).into()
+            }
+        }
+        unsafe extern "system" fn SetTextSelection<Identity: windows_core::IUnknownImpl + ISpRecoGrammar_Impl>(this: *mut core::ffi::c_void, pinfo: *const SPTEXTSELECTIONINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar_Impl::SetTextSelection(this, core::mem::transmute_copy(&pinfo)).into()
+            }
+        }
+        unsafe extern "system" fn IsPronounceable<Identity: windows_core::IUnknownImpl + ISpRecoGrammar_Impl>(this: *mut core::ffi::c_void, pszword: windows_core::PCWSTR, pwordpronounceable: *mut SPWORDPRONOUNCEABLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar_Impl::IsPronounceable(this, core::mem::transmute(&pszword), core::mem::transmute_copy(&pwordpronounceable)).into()
+            }
+        }
+        unsafe extern "system" fn SetGrammarState<Identity: windows_core::IUnknownImpl + ISpRecoGrammar_Impl>(this: *mut core::ffi::c_void, egrammarstate: SPGRAMMARSTATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar_Impl::SetGrammarState(this, core::mem::transmute_copy(&egrammarstate)).into()
+            }
+        }
+        unsafe extern "system" fn SaveCmd<Identity: windows_core::IUnknownImpl + ISpRecoGrammar_Impl>(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, ppszcomemerrortext: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar_Impl::SaveCmd(this, core::mem::transmute_copy(&pstream), core::mem::transmute_copy(&ppszcomemerrortext)).into()
+            }
+        }
+        unsafe extern "system" fn GetGrammarState<Identity: windows_core::IUnknownImpl + ISpRecoGrammar_Impl>(this: *mut core::ffi::c_void, pegrammarstate: *mut SPGRAMMARSTATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar_Impl::GetGrammarState(this, core::mem::transmute_copy(&pegrammarstate)).into()
+            }
+        }
+        Self {
+            base__: <ISpGrammarBuilder_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetGrammarId: GetGrammarId::<Identity>,
+            GetRecoContext: GetRecoContext::<Identity>,
+            LoadCmdFromFile: LoadCmdFromFile::<Identity>,
+            LoadCmdFromObject: LoadCmdFromObject::<Identity>,
+            LoadCmdFromResource: LoadCmdFromResource::<Identity>,
+            LoadCmdFromMemory: LoadCmdFromMemory::<Identity>,
+            LoadCmdFromProprietaryGrammar: LoadCmdFromProprietaryGrammar::<Identity>,
+            SetRuleState: SetRuleState::<Identity>,
+            SetRuleIdState: SetRuleIdState::<Identity>,
+            LoadDictation: LoadDictation::<Identity>,
+            UnloadDictation: UnloadDictation::<Identity>,
+            SetDictationState: SetDictationState::<Identity>,
+            SetWordSequenceData: SetWordSequenceData::<Identity>,
+            SetTextSelection: SetTextSelection::<Identity>,
+            IsPronounceable: IsPronounceable::<Identity>,
+            SetGrammarState: SetGrammarState::<Identity>,
+            SaveCmd: SaveCmd::<Identity>,
+            GetGrammarState: GetGrammarState::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for ISpRecoGrammar {}
+windows_core::imp::define_interface!(ISpRecoGrammar2, ISpRecoGrammar2_Vtbl, 0x4b37bc9e_9ed6_44a3_93d3_18f022b79ec3);
+windows_core::imp::interface_hierarchy!(ISpRecoGrammar2, windows_core::IUnknown);
+impl ISpRecoGrammar2 {
+    pub unsafe fn GetRules(&self, ppcomemrules: *mut *mut SPRULE, punumrules: *mut u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetRules)(windows_core::Interface::as_raw(self), ppcomemrules as _, punumrules as _).ok() }
+    }
+    pub unsafe fn LoadCmdFromFile2<P0, P2, P3>(&self, pszfilename: P0, options: SPLOADOPTIONS, pszsharinguri: P2, pszbaseuri: P3) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::PCWSTR>,
+        P2: windows_core::Param<windows_core::PCWSTR>,
+        P3: windows_core::Param<windows_core::PCWSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).LoadCmdFromFile2)(windows_core::Interface::as_raw(self), pszfilename.param().abi(), options, pszsharinguri.param().abi(), pszbaseuri.param().abi()).ok() }
+    }
+    pub unsafe fn LoadCmdFromMemory2<P2, P3>(&self, pgrammar: *const SPBINARYGRAMMAR, options: SPLOADOPTIONS, pszsharinguri: P2, pszbaseuri: P3) -> windows_core::Result<()>
+    where
+        P2: windows_core::Param<windows_core::PCWSTR>,
+        P3: windows_core::Param<windows_core::PCWSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).LoadCmdFromMemory2)(windows_core::Interface::as_raw(self), pgrammar, options, pszsharinguri.param().abi(), pszbaseuri.param().abi()).ok() }
+    }
+    pub unsafe fn SetRulePriority<P0>(&self, pszrulename: P0, ulruleid: u32, nrulepriority: i32) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::PCWSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).SetRulePriority)(windows_core::Interface::as_raw(self), pszrulename.param().abi(), ulruleid, nrulepriority).ok() }
+    }
+    pub unsafe fn SetRuleWeight<P0>(&self, pszrulename: P0, ulruleid: u32, flweight: f32) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::PCWSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).SetRuleWeight)(windows_core::Interface::as_raw(self), pszrulename.param().abi(), ulruleid, flweight).ok() }
+    }
+    pub unsafe fn SetDictationWeight(&self, flweight: f32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetDictationWeight)(windows_core::Interface::as_raw(self), flweight).ok() }
+    }
+    #[cfg(feature = "Win32_System_Com")]
+    pub unsafe fn SetGrammarLoader<P0>(&self, ploader: P0) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<ISpeechResourceLoader>,
     {
         unsafe { (windows_core::Interface::vtable(self).SetGrammarLoader)(windows_core::Interface::as_raw(self), ploader.param().abi()).ok() }
     }
@@ -4359,6 +6178,73 @@ impl ISpRecoGrammar2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com_Urlmon")]
+impl<Identity: windows_core::IUnknownImpl + ISpRecoGrammar2_Impl> windows_core::imp::TearOffVtable<Identity> for ISpRecoGrammar2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRules<Identity: windows_core::IUnknownImpl + ISpRecoGrammar2_Impl>(this: *mut core::ffi::c_void, ppcomemrules: *mut *mut SPRULE, punumrules: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar2_Impl::GetRules(this, core::mem::transmute_copy(&ppcomemrules), core::mem::transmute_copy(&punumrules)).into()
+            }
+        }
+        unsafe extern "system" fn LoadCmdFromFile2<Identity: windows_core::IUnknownImpl + ISpRecoGrammar2_Impl>(this: *mut core::ffi::c_void, pszfilename: windows_core::PCWSTR, options: SPLOADOPTIONS, pszsharinguri: windows_core::PCWSTR, pszbaseuri: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar2_Impl::LoadCmdFromFile2(this, core::mem::transmute(&pszfilename), core::mem::transmute_copy(&options), core::mem::transmute(&pszsharinguri), core::mem::transmute(&pszbaseuri)).into()
+            }
+        }
+        unsafe extern "system" fn LoadCmdFromMemory2<Identity: windows_core::IUnknownImpl + ISpRecoGrammar2_Impl>(this: *mut core::ffi::c_void, pgrammar: *const SPBINARYGRAMMAR, options: SPLOADOPTIONS, pszsharinguri: windows_core::PCWSTR, pszbaseuri: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar2_Impl::LoadCmdFromMemory2(this, core::mem::transmute_copy(&pgrammar), core::mem::transmute_copy(&options), core::mem::transmute(&pszsharinguri), core::mem::transmute(&pszbaseuri)).into()
+            }
+        }
+        unsafe extern "system" fn SetRulePriority<Identity: windows_core::IUnknownImpl + ISpRecoGrammar2_Impl>(this: *mut core::ffi::c_void, pszrulename: windows_core::PCWSTR, ulruleid: u32, nrulepriority: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar2_Impl::SetRulePriority(this, core::mem::transmute(&pszrulename), core::mem::transmute_copy(&ulruleid), core::mem::transmute_copy(&nrulepriority)).into()
+            }
+        }
+        unsafe extern "system" fn SetRuleWeight<Identity: windows_core::IUnknownImpl + ISpRecoGrammar2_Impl>(this: *mut core::ffi::c_void, pszrulename: windows_core::PCWSTR, ulruleid: u32, flweight: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar2_Impl::SetRuleWeight(this, core::mem::transmute(&pszrulename), core::mem::transmute_copy(&ulruleid), core::mem::transmute_copy(&flweight)).into()
+            }
+        }
+        unsafe extern "system" fn SetDictationWeight<Identity: windows_core::IUnknownImpl + ISpRecoGrammar2_Impl>(this: *mut core::ffi::c_void, flweight: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar2_Impl::SetDictationWeight(this, core::mem::transmute_copy(&flweight)).into()
+            }
+        }
+        unsafe extern "system" fn SetGrammarLoader<Identity: windows_core::IUnknownImpl + ISpRecoGrammar2_Impl>(this: *mut core::ffi::c_void, ploader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar2_Impl::SetGrammarLoader(this, core::mem::transmute_copy(&ploader)).into()
+            }
+        }
+        unsafe extern "system" fn SetSMLSecurityManager<Identity: windows_core::IUnknownImpl + ISpRecoGrammar2_Impl>(this: *mut core::ffi::c_void, psmlsecuritymanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoGrammar2_Impl::SetSMLSecurityManager(this, core::mem::transmute_copy(&psmlsecuritymanager)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetRules: GetRules::<Identity>,
+            LoadCmdFromFile2: LoadCmdFromFile2::<Identity>,
+            LoadCmdFromMemory2: LoadCmdFromMemory2::<Identity>,
+            SetRulePriority: SetRulePriority::<Identity>,
+            SetRuleWeight: SetRuleWeight::<Identity>,
+            SetDictationWeight: SetDictationWeight::<Identity>,
+            SetGrammarLoader: SetGrammarLoader::<Identity>,
+            SetSMLSecurityManager: SetSMLSecurityManager::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com_Urlmon")]
 impl windows_core::RuntimeName for ISpRecoGrammar2 {}
 windows_core::imp::define_interface!(ISpRecoResult, ISpRecoResult_Vtbl, 0x20b053be_e235_43cd_9a2a_8d17a48b7842);
 impl core::ops::Deref for ISpRecoResult {
@@ -4500,6 +6386,78 @@ impl ISpRecoResult_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpRecoResult_Impl> windows_core::imp::TearOffVtable<Identity> for ISpRecoResult_Vtbl
+where
+    ISpPhrase_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetResultTimes<Identity: windows_core::IUnknownImpl + ISpRecoResult_Impl>(this: *mut core::ffi::c_void, ptimes: *mut SPRECORESULTTIMES) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoResult_Impl::GetResultTimes(this, core::mem::transmute_copy(&ptimes)).into()
+            }
+        }
+        unsafe extern "system" fn GetAlternates<Identity: windows_core::IUnknownImpl + ISpRecoResult_Impl>(this: *mut core::ffi::c_void, ulstartelement: u32, celements: u32, ulrequestcount: u32, ppphrases: *mut *mut core::ffi::c_void, pcphrasesreturned: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoResult_Impl::GetAlternates(this, core::mem::transmute_copy(&ulstartelement), core::mem::transmute_copy(&celements), core::mem::transmute_copy(&ulrequestcount), core::mem::transmute_copy(&ppphrases), core::mem::transmute_copy(&pcphrasesreturned)).into()
+            }
+        }
+        unsafe extern "system" fn GetAudio<Identity: windows_core::IUnknownImpl + ISpRecoResult_Impl>(this: *mut core::ffi::c_void, ulstartelement: u32, celements: u32, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpRecoResult_Impl::GetAudio(this, core::mem::transmute_copy(&ulstartelement), core::mem::transmute_copy(&celements)) {
+                    Ok(ok__) => {
+                        ppstream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SpeakAudio<Identity: windows_core::IUnknownImpl + ISpRecoResult_Impl>(this: *mut core::ffi::c_void, ulstartelement: u32, celements: u32, dwflags: u32, pulstreamnumber: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoResult_Impl::SpeakAudio(this, core::mem::transmute_copy(&ulstartelement), core::mem::transmute_copy(&celements), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pulstreamnumber)).into()
+            }
+        }
+        unsafe extern "system" fn Serialize<Identity: windows_core::IUnknownImpl + ISpRecoResult_Impl>(this: *mut core::ffi::c_void, ppcomemserializedresult: *mut *mut SPSERIALIZEDRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoResult_Impl::Serialize(this, core::mem::transmute_copy(&ppcomemserializedresult)).into()
+            }
+        }
+        unsafe extern "system" fn ScaleAudio<Identity: windows_core::IUnknownImpl + ISpRecoResult_Impl>(this: *mut core::ffi::c_void, paudioformatid: *const windows_core::GUID, pwaveformatex: *const super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoResult_Impl::ScaleAudio(this, core::mem::transmute_copy(&paudioformatid), core::mem::transmute_copy(&pwaveformatex)).into()
+            }
+        }
+        unsafe extern "system" fn GetRecoContext<Identity: windows_core::IUnknownImpl + ISpRecoResult_Impl>(this: *mut core::ffi::c_void, pprecocontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpRecoResult_Impl::GetRecoContext(this) {
+                    Ok(ok__) => {
+                        pprecocontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ISpPhrase_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetResultTimes: GetResultTimes::<Identity>,
+            GetAlternates: GetAlternates::<Identity>,
+            GetAudio: GetAudio::<Identity>,
+            SpeakAudio: SpeakAudio::<Identity>,
+            Serialize: Serialize::<Identity>,
+            ScaleAudio: ScaleAudio::<Identity>,
+            GetRecoContext: GetRecoContext::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpRecoResult {}
 windows_core::imp::define_interface!(ISpRecoResult2, ISpRecoResult2_Vtbl, 0x27cac6c4_88f2_41f2_8817_0c95e59f1e6e);
 impl core::ops::Deref for ISpRecoResult2 {
@@ -4585,6 +6543,44 @@ impl ISpRecoResult2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpRecoResult2_Impl> windows_core::imp::TearOffVtable<Identity> for ISpRecoResult2_Vtbl
+where
+    ISpRecoResult_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CommitAlternate<Identity: windows_core::IUnknownImpl + ISpRecoResult2_Impl>(this: *mut core::ffi::c_void, pphrasealt: *mut core::ffi::c_void, ppnewresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpRecoResult2_Impl::CommitAlternate(this, core::mem::transmute_copy(&pphrasealt)) {
+                    Ok(ok__) => {
+                        ppnewresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CommitText<Identity: windows_core::IUnknownImpl + ISpRecoResult2_Impl>(this: *mut core::ffi::c_void, ulstartelement: u32, celements: u32, pszcorrecteddata: windows_core::PCWSTR, ecommitflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoResult2_Impl::CommitText(this, core::mem::transmute_copy(&ulstartelement), core::mem::transmute_copy(&celements), core::mem::transmute(&pszcorrecteddata), core::mem::transmute_copy(&ecommitflags)).into()
+            }
+        }
+        unsafe extern "system" fn SetTextFeedback<Identity: windows_core::IUnknownImpl + ISpRecoResult2_Impl>(this: *mut core::ffi::c_void, pszfeedback: windows_core::PCWSTR, fsuccessful: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecoResult2_Impl::SetTextFeedback(this, core::mem::transmute(&pszfeedback), core::mem::transmute_copy(&fsuccessful)).into()
+            }
+        }
+        Self {
+            base__: <ISpRecoResult_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CommitAlternate: CommitAlternate::<Identity>,
+            CommitText: CommitText::<Identity>,
+            SetTextFeedback: SetTextFeedback::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpRecoResult2 {}
 windows_core::imp::define_interface!(ISpRecognizer, ISpRecognizer_Vtbl, 0xc2b5f241_daa0_4507_9e16_5a1eaa2b7a5c);
 impl core::ops::Deref for ISpRecognizer {
@@ -4880,42 +6876,195 @@ impl ISpRecognizer_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com"))]
-impl windows_core::RuntimeName for ISpRecognizer {}
-windows_core::imp::define_interface!(ISpRecognizer2, ISpRecognizer2_Vtbl, 0x8fc6d974_c81e_4098_93c5_0147f61ed4d3);
-windows_core::imp::interface_hierarchy!(ISpRecognizer2, windows_core::IUnknown);
-impl ISpRecognizer2 {
-    pub unsafe fn EmulateRecognitionEx<P0>(&self, pphrase: P0, dwcompareflags: u32) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<ISpPhrase>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).EmulateRecognitionEx)(windows_core::Interface::as_raw(self), pphrase.param().abi(), dwcompareflags).ok() }
-    }
-    pub unsafe fn SetTrainingState(&self, fdoingtraining: bool, fadaptfromtrainingdata: bool) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetTrainingState)(windows_core::Interface::as_raw(self), fdoingtraining.into(), fadaptfromtrainingdata.into()).ok() }
-    }
-    pub unsafe fn ResetAcousticModelAdaptation(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).ResetAcousticModelAdaptation)(windows_core::Interface::as_raw(self)).ok() }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct ISpRecognizer2_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    pub EmulateRecognitionEx: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, u32) -> windows_core::HRESULT,
-    pub SetTrainingState: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::BOOL, windows_core::BOOL) -> windows_core::HRESULT,
-    pub ResetAcousticModelAdaptation: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-pub trait ISpRecognizer2_Impl: windows_core::IUnknownImpl {
-    fn EmulateRecognitionEx(&self, pphrase: windows_core::Ref<ISpPhrase>, dwcompareflags: u32) -> windows_core::Result<()>;
-    fn SetTrainingState(&self, fdoingtraining: windows_core::BOOL, fadaptfromtrainingdata: windows_core::BOOL) -> windows_core::Result<()>;
-    fn ResetAcousticModelAdaptation(&self) -> windows_core::Result<()>;
-}
-impl ISpRecognizer2_Vtbl {
-    pub const fn new<Identity: ISpRecognizer2_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn EmulateRecognitionEx<Identity: ISpRecognizer2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pphrase: *mut core::ffi::c_void, dwcompareflags: u32) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ISpRecognizer2_Impl::EmulateRecognitionEx(this, core::mem::transmute_copy(&pphrase), core::mem::transmute_copy(&dwcompareflags)).into()
+impl<Identity: windows_core::IUnknownImpl + ISpRecognizer_Impl> windows_core::imp::TearOffVtable<Identity> for ISpRecognizer_Vtbl
+where
+    ISpProperties_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetRecognizer<Identity: windows_core::IUnknownImpl + ISpRecognizer_Impl>(this: *mut core::ffi::c_void, precognizer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecognizer_Impl::SetRecognizer(this, core::mem::transmute_copy(&precognizer)).into()
+            }
+        }
+        unsafe extern "system" fn GetRecognizer<Identity: windows_core::IUnknownImpl + ISpRecognizer_Impl>(this: *mut core::ffi::c_void, pprecognizer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpRecognizer_Impl::GetRecognizer(this) {
+                    Ok(ok__) => {
+                        pprecognizer.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetInput<Identity: windows_core::IUnknownImpl + ISpRecognizer_Impl>(this: *mut core::ffi::c_void, punkinput: *mut core::ffi::c_void, fallowformatchanges: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecognizer_Impl::SetInput(this, core::mem::transmute_copy(&punkinput), core::mem::transmute_copy(&fallowformatchanges)).into()
+            }
+        }
+        unsafe extern "system" fn GetInputObjectToken<Identity: windows_core::IUnknownImpl + ISpRecognizer_Impl>(this: *mut core::ffi::c_void, pptoken: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpRecognizer_Impl::GetInputObjectToken(this) {
+                    Ok(ok__) => {
+                        pptoken.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetInputStream<Identity: windows_core::IUnknownImpl + ISpRecognizer_Impl>(this: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpRecognizer_Impl::GetInputStream(this) {
+                    Ok(ok__) => {
+                        ppstream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateRecoContext<Identity: windows_core::IUnknownImpl + ISpRecognizer_Impl>(this: *mut core::ffi::c_void, ppnewctxt: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpRecognizer_Impl::CreateRecoContext(this) {
+                    Ok(ok__) => {
+                        ppnewctxt.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRecoProfile<Identity: windows_core::IUnknownImpl + ISpRecognizer_Impl>(this: *mut core::ffi::c_void, pptoken: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpRecognizer_Impl::GetRecoProfile(this) {
+                    Ok(ok__) => {
+                        pptoken.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRecoProfile<Identity: windows_core::IUnknownImpl + ISpRecognizer_Impl>(this: *mut core::ffi::c_void, ptoken: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecognizer_Impl::SetRecoProfile(this, core::mem::transmute_copy(&ptoken)).into()
+            }
+        }
+        unsafe extern "system" fn IsSharedInstance<Identity: windows_core::IUnknownImpl + ISpRecognizer_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecognizer_Impl::IsSharedInstance(this).into()
+            }
+        }
+        unsafe extern "system" fn GetRecoState<Identity: windows_core::IUnknownImpl + ISpRecognizer_Impl>(this: *mut core::ffi::c_void, pstate: *mut SPRECOSTATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecognizer_Impl::GetRecoState(this, core::mem::transmute_copy(&pstate)).into()
+            }
+        }
+        unsafe extern "system" fn SetRecoState<Identity: windows_core::IUnknownImpl + ISpRecognizer_Impl>(this: *mut core::ffi::c_void, newstate: SPRECOSTATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecognizer_Impl::SetRecoState(this, core::mem::transmute_copy(&newstate)).into()
+            }
+        }
+        unsafe extern "system" fn GetStatus<Identity: windows_core::IUnknownImpl + ISpRecognizer_Impl>(this: *mut core::ffi::c_void, pstatus: *mut SPRECOGNIZERSTATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecognizer_Impl::GetStatus(this, core::mem::transmute_copy(&pstatus)).into()
+            }
+        }
+        unsafe extern "system" fn GetFormat<Identity: windows_core::IUnknownImpl + ISpRecognizer_Impl>(this: *mut core::ffi::c_void, waveformattype: SPSTREAMFORMATTYPE, pformatid: *mut windows_core::GUID, ppcomemwfex: *mut *mut super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecognizer_Impl::GetFormat(this, core::mem::transmute_copy(&waveformattype), core::mem::transmute_copy(&pformatid), core::mem::transmute_copy(&ppcomemwfex)).into()
+            }
+        }
+        unsafe extern "system" fn IsUISupported<Identity: windows_core::IUnknownImpl + ISpRecognizer_Impl>(this: *mut core::ffi::c_void, psztypeofui: windows_core::PCWSTR, pvextradata: *mut core::ffi::c_void, cbextradata: u32, pfsupported: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecognizer_Impl::IsUISupported(this, core::mem::transmute(&psztypeofui), core::mem::transmute_copy(&pvextradata), core::mem::transmute_copy(&cbextradata), core::mem::transmute_copy(&pfsupported)).into()
+            }
+        }
+        unsafe extern "system" fn DisplayUI<Identity: windows_core::IUnknownImpl + ISpRecognizer_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, psztitle: windows_core::PCWSTR, psztypeofui: windows_core::PCWSTR, pvextradata: *mut core::ffi::c_void, cbextradata: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecognizer_Impl::DisplayUI(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute(&psztitle), core::mem::transmute(&psztypeofui), core::mem::transmute_copy(&pvextradata), core::mem::transmute_copy(&cbextradata)).into()
+            }
+        }
+        unsafe extern "system" fn EmulateRecognition<Identity: windows_core::IUnknownImpl + ISpRecognizer_Impl>(this: *mut core::ffi::c_void, pphrase: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecognizer_Impl::EmulateRecognition(this, core::mem::transmute_copy(&pphrase)).into()
+            }
+        }
+        Self {
+            base__: <ISpProperties_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetRecognizer: SetRecognizer::<Identity>,
+            GetRecognizer: GetRecognizer::<Identity>,
+            SetInput: SetInput::<Identity>,
+            GetInputObjectToken: GetInputObjectToken::<Identity>,
+            GetInputStream: GetInputStream::<Identity>,
+            CreateRecoContext: CreateRecoContext::<Identity>,
+            GetRecoProfile: GetRecoProfile::<Identity>,
+            SetRecoProfile: SetRecoProfile::<Identity>,
+            IsSharedInstance: IsSharedInstance::<Identity>,
+            GetRecoState: GetRecoState::<Identity>,
+            SetRecoState: SetRecoState::<Identity>,
+            GetStatus: GetStatus::<Identity>,
+            GetFormat: GetFormat::<Identity>,
+            IsUISupported: IsUISupported::<Identity>,
+            DisplayUI: DisplayUI::<Identity>,
+            EmulateRecognition: EmulateRecognition::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com"))]
+impl windows_core::RuntimeName for ISpRecognizer {}
+windows_core::imp::define_interface!(ISpRecognizer2, ISpRecognizer2_Vtbl, 0x8fc6d974_c81e_4098_93c5_0147f61ed4d3);
+windows_core::imp::interface_hierarchy!(ISpRecognizer2, windows_core::IUnknown);
+impl ISpRecognizer2 {
+    pub unsafe fn EmulateRecognitionEx<P0>(&self, pphrase: P0, dwcompareflags: u32) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<ISpPhrase>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).EmulateRecognitionEx)(windows_core::Interface::as_raw(self), pphrase.param().abi(), dwcompareflags).ok() }
+    }
+    pub unsafe fn SetTrainingState(&self, fdoingtraining: bool, fadaptfromtrainingdata: bool) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetTrainingState)(windows_core::Interface::as_raw(self), fdoingtraining.into(), fadaptfromtrainingdata.into()).ok() }
+    }
+    pub unsafe fn ResetAcousticModelAdaptation(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).ResetAcousticModelAdaptation)(windows_core::Interface::as_raw(self)).ok() }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct ISpRecognizer2_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub EmulateRecognitionEx: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, u32) -> windows_core::HRESULT,
+    pub SetTrainingState: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::BOOL, windows_core::BOOL) -> windows_core::HRESULT,
+    pub ResetAcousticModelAdaptation: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+pub trait ISpRecognizer2_Impl: windows_core::IUnknownImpl {
+    fn EmulateRecognitionEx(&self, pphrase: windows_core::Ref<ISpPhrase>, dwcompareflags: u32) -> windows_core::Result<()>;
+    fn SetTrainingState(&self, fdoingtraining: windows_core::BOOL, fadaptfromtrainingdata: windows_core::BOOL) -> windows_core::Result<()>;
+    fn ResetAcousticModelAdaptation(&self) -> windows_core::Result<()>;
+}
+impl ISpRecognizer2_Vtbl {
+    pub const fn new<Identity: ISpRecognizer2_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn EmulateRecognitionEx<Identity: ISpRecognizer2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pphrase: *mut core::ffi::c_void, dwcompareflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ISpRecognizer2_Impl::EmulateRecognitionEx(this, core::mem::transmute_copy(&pphrase), core::mem::transmute_copy(&dwcompareflags)).into()
             }
         }
         unsafe extern "system" fn SetTrainingState<Identity: ISpRecognizer2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fdoingtraining: windows_core::BOOL, fadaptfromtrainingdata: windows_core::BOOL) -> windows_core::HRESULT {
@@ -4941,6 +7090,37 @@ impl ISpRecognizer2_Vtbl {
         iid == &<ISpRecognizer2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpRecognizer2_Impl> windows_core::imp::TearOffVtable<Identity> for ISpRecognizer2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EmulateRecognitionEx<Identity: windows_core::IUnknownImpl + ISpRecognizer2_Impl>(this: *mut core::ffi::c_void, pphrase: *mut core::ffi::c_void, dwcompareflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecognizer2_Impl::EmulateRecognitionEx(this, core::mem::transmute_copy(&pphrase), core::mem::transmute_copy(&dwcompareflags)).into()
+            }
+        }
+        unsafe extern "system" fn SetTrainingState<Identity: windows_core::IUnknownImpl + ISpRecognizer2_Impl>(this: *mut core::ffi::c_void, fdoingtraining: windows_core::BOOL, fadaptfromtrainingdata: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecognizer2_Impl::SetTrainingState(this, core::mem::transmute_copy(&fdoingtraining), core::mem::transmute_copy(&fadaptfromtrainingdata)).into()
+            }
+        }
+        unsafe extern "system" fn ResetAcousticModelAdaptation<Identity: windows_core::IUnknownImpl + ISpRecognizer2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRecognizer2_Impl::ResetAcousticModelAdaptation(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EmulateRecognitionEx: EmulateRecognitionEx::<Identity>,
+            SetTrainingState: SetTrainingState::<Identity>,
+            ResetAcousticModelAdaptation: ResetAcousticModelAdaptation::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpRecognizer2 {}
 windows_core::imp::define_interface!(ISpRegDataKey, ISpRegDataKey_Vtbl, 0x92a66e2b_c830_4149_83df_6fc2ba1e7a5b);
 impl core::ops::Deref for ISpRegDataKey {
@@ -4985,6 +7165,21 @@ impl ISpRegDataKey_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Registry")]
+impl<Identity: windows_core::IUnknownImpl + ISpRegDataKey_Impl> windows_core::imp::TearOffVtable<Identity> for ISpRegDataKey_Vtbl
+where
+    ISpDataKey_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetKey<Identity: windows_core::IUnknownImpl + ISpRegDataKey_Impl>(this: *mut core::ffi::c_void, hkey: super::super::System::Registry::HKEY, freadonly: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpRegDataKey_Impl::SetKey(this, core::mem::transmute_copy(&hkey), core::mem::transmute_copy(&freadonly)).into()
+            }
+        }
+        Self { base__: <ISpDataKey_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetKey: SetKey::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Registry")]
 impl windows_core::RuntimeName for ISpRegDataKey {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpResourceManager, ISpResourceManager_Vtbl, 0x93384e18_5014_43d5_adbb_a78e055926bd);
@@ -5048,6 +7243,31 @@ impl ISpResourceManager_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + ISpResourceManager_Impl> windows_core::imp::TearOffVtable<Identity> for ISpResourceManager_Vtbl
+where
+    super::super::System::Com::IServiceProvider_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetObject<Identity: windows_core::IUnknownImpl + ISpResourceManager_Impl>(this: *mut core::ffi::c_void, guidserviceid: *const windows_core::GUID, punkobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpResourceManager_Impl::SetObject(this, core::mem::transmute_copy(&guidserviceid), core::mem::transmute_copy(&punkobject)).into()
+            }
+        }
+        unsafe extern "system" fn GetObject<Identity: windows_core::IUnknownImpl + ISpResourceManager_Impl>(this: *mut core::ffi::c_void, guidserviceid: *const windows_core::GUID, objectclsid: *const windows_core::GUID, objectiid: *const windows_core::GUID, freleasewhenlastexternalrefreleased: windows_core::BOOL, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpResourceManager_Impl::GetObject(this, core::mem::transmute_copy(&guidserviceid), core::mem::transmute_copy(&objectclsid), core::mem::transmute_copy(&objectiid), core::mem::transmute_copy(&freleasewhenlastexternalrefreleased), core::mem::transmute_copy(&ppobject)).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IServiceProvider_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetObject: SetObject::<Identity>,
+            GetObject: GetObject::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for ISpResourceManager {}
 windows_core::imp::define_interface!(ISpSRAlternates, ISpSRAlternates_Vtbl, 0xfece8294_2be1_408f_8e68_2de377092f0e);
 windows_core::imp::interface_hierarchy!(ISpSRAlternates, windows_core::IUnknown);
@@ -5094,6 +7314,30 @@ impl ISpSRAlternates_Vtbl {
         iid == &<ISpSRAlternates as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpSRAlternates_Impl> windows_core::imp::TearOffVtable<Identity> for ISpSRAlternates_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAlternates<Identity: windows_core::IUnknownImpl + ISpSRAlternates_Impl>(this: *mut core::ffi::c_void, paltrequest: *const SPPHRASEALTREQUEST, ppalts: *mut *mut SPPHRASEALT, pcalts: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSRAlternates_Impl::GetAlternates(this, core::mem::transmute_copy(&paltrequest), core::mem::transmute_copy(&ppalts), core::mem::transmute_copy(&pcalts)).into()
+            }
+        }
+        unsafe extern "system" fn Commit<Identity: windows_core::IUnknownImpl + ISpSRAlternates_Impl>(this: *mut core::ffi::c_void, paltrequest: *const SPPHRASEALTREQUEST, palt: *const SPPHRASEALT, ppvresultextra: *mut *mut core::ffi::c_void, pcbresultextra: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSRAlternates_Impl::Commit(this, core::mem::transmute_copy(&paltrequest), core::mem::transmute_copy(&palt), core::mem::transmute_copy(&ppvresultextra), core::mem::transmute_copy(&pcbresultextra)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAlternates: GetAlternates::<Identity>,
+            Commit: Commit::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpSRAlternates {}
 windows_core::imp::define_interface!(ISpSRAlternates2, ISpSRAlternates2_Vtbl, 0xf338f437_cb33_4020_9cab_c71ff9ce12d3);
 impl core::ops::Deref for ISpSRAlternates2 {
@@ -5134,6 +7378,20 @@ impl ISpSRAlternates2_Vtbl {
         iid == &<ISpSRAlternates2 as windows_core::Interface>::IID || iid == &<ISpSRAlternates as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpSRAlternates2_Impl> windows_core::imp::TearOffVtable<Identity> for ISpSRAlternates2_Vtbl
+where
+    ISpSRAlternates_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CommitText<Identity: windows_core::IUnknownImpl + ISpSRAlternates2_Impl>(this: *mut core::ffi::c_void, paltrequest: *const SPPHRASEALTREQUEST, pcsznewtext: windows_core::PCWSTR, commitflags: SPCOMMITFLAGS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSRAlternates2_Impl::CommitText(this, core::mem::transmute_copy(&paltrequest), core::mem::transmute(&pcsznewtext), core::mem::transmute_copy(&commitflags)).into()
+            }
+        }
+        Self { base__: <ISpSRAlternates_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CommitText: CommitText::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ISpSRAlternates2 {}
 windows_core::imp::define_interface!(ISpSREngine, ISpSREngine_Vtbl, 0x2f472991_854b_4465_b613_fbafb3ad8ed8);
 windows_core::imp::interface_hierarchy!(ISpSREngine, windows_core::IUnknown);
@@ -5585,83 +7843,321 @@ impl ISpSREngine_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Media_Audio")]
-impl windows_core::RuntimeName for ISpSREngine {}
-windows_core::imp::define_interface!(ISpSREngine2, ISpSREngine2_Vtbl, 0x7ba627d8_33f9_4375_90c5_9985aee5ede5);
-impl core::ops::Deref for ISpSREngine2 {
-    type Target = ISpSREngine;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(ISpSREngine2, windows_core::IUnknown, ISpSREngine);
-impl ISpSREngine2 {
-    pub unsafe fn PrivateCallImmediate(&self, pvenginecontext: *const core::ffi::c_void, pincallframe: *const core::ffi::c_void, ulincallframesize: u32, ppvcomemresponse: *mut *mut core::ffi::c_void, pulresponsesize: *mut u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).PrivateCallImmediate)(windows_core::Interface::as_raw(self), pvenginecontext, pincallframe, ulincallframesize, ppvcomemresponse as _, pulresponsesize as _).ok() }
-    }
-    pub unsafe fn SetAdaptationData2<P3>(&self, pvenginecontext: *const core::ffi::c_void, padaptationdata: &[u16], ptopicname: P3, esettings: SPADAPTATIONSETTINGS, erelevance: SPADAPTATIONRELEVANCE) -> windows_core::Result<()>
-    where
-        P3: windows_core::Param<windows_core::PCWSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).SetAdaptationData2)(windows_core::Interface::as_raw(self), pvenginecontext, core::mem::transmute(padaptationdata.as_ptr()), padaptationdata.len().try_into().unwrap(), ptopicname.param().abi(), esettings, erelevance).ok() }
-    }
-    pub unsafe fn SetGrammarPrefix<P1>(&self, pvenginegrammar: *const core::ffi::c_void, pszprefix: P1, fisprefixrequired: bool) -> windows_core::Result<()>
-    where
-        P1: windows_core::Param<windows_core::PCWSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).SetGrammarPrefix)(windows_core::Interface::as_raw(self), pvenginegrammar, pszprefix.param().abi(), fisprefixrequired.into()).ok() }
-    }
-    pub unsafe fn SetRulePriority(&self, hrule: SPRULEHANDLE, pvclientrulecontext: *const core::ffi::c_void, nrulepriority: i32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetRulePriority)(windows_core::Interface::as_raw(self), hrule, pvclientrulecontext, nrulepriority).ok() }
-    }
-    pub unsafe fn EmulateRecognition<P0>(&self, pphrase: P0, dwcompareflags: u32) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<ISpPhrase>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).EmulateRecognition)(windows_core::Interface::as_raw(self), pphrase.param().abi(), dwcompareflags).ok() }
-    }
-    pub unsafe fn SetSLMWeight(&self, pvenginegrammar: *const core::ffi::c_void, flweight: f32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetSLMWeight)(windows_core::Interface::as_raw(self), pvenginegrammar, flweight).ok() }
-    }
-    pub unsafe fn SetRuleWeight(&self, hrule: SPRULEHANDLE, pvclientrulecontext: *const core::ffi::c_void, flweight: f32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetRuleWeight)(windows_core::Interface::as_raw(self), hrule, pvclientrulecontext, flweight).ok() }
-    }
-    pub unsafe fn SetTrainingState(&self, fdoingtraining: bool, fadaptfromtrainingdata: bool) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetTrainingState)(windows_core::Interface::as_raw(self), fdoingtraining.into(), fadaptfromtrainingdata.into()).ok() }
-    }
-    pub unsafe fn ResetAcousticModelAdaptation(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).ResetAcousticModelAdaptation)(windows_core::Interface::as_raw(self)).ok() }
-    }
-    pub unsafe fn OnLoadCFG(&self, pvenginegrammar: *const core::ffi::c_void, pgrammardata: *const SPBINARYGRAMMAR, ulgrammarid: u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).OnLoadCFG)(windows_core::Interface::as_raw(self), pvenginegrammar, pgrammardata, ulgrammarid).ok() }
-    }
-    pub unsafe fn OnUnloadCFG(&self, pvenginegrammar: *const core::ffi::c_void, ulgrammarid: u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).OnUnloadCFG)(windows_core::Interface::as_raw(self), pvenginegrammar, ulgrammarid).ok() }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct ISpSREngine2_Vtbl {
-    pub base__: ISpSREngine_Vtbl,
-    pub PrivateCallImmediate: unsafe extern "system" fn(*mut core::ffi::c_void, *const core::ffi::c_void, *const core::ffi::c_void, u32, *mut *mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
-    pub SetAdaptationData2: unsafe extern "system" fn(*mut core::ffi::c_void, *const core::ffi::c_void, windows_core::PCWSTR, u32, windows_core::PCWSTR, SPADAPTATIONSETTINGS, SPADAPTATIONRELEVANCE) -> windows_core::HRESULT,
-    pub SetGrammarPrefix: unsafe extern "system" fn(*mut core::ffi::c_void, *const core::ffi::c_void, windows_core::PCWSTR, windows_core::BOOL) -> windows_core::HRESULT,
-    pub SetRulePriority: unsafe extern "system" fn(*mut core::ffi::c_void, SPRULEHANDLE, *const core::ffi::c_void, i32) -> windows_core::HRESULT,
-    pub EmulateRecognition: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, u32) -> windows_core::HRESULT,
-    pub SetSLMWeight: unsafe extern "system" fn(*mut core::ffi::c_void, *const core::ffi::c_void, f32) -> windows_core::HRESULT,
-    pub SetRuleWeight: unsafe extern "system" fn(*mut core::ffi::c_void, SPRULEHANDLE, *const core::ffi::c_void, f32) -> windows_core::HRESULT,
-    pub SetTrainingState: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::BOOL, windows_core::BOOL) -> windows_core::HRESULT,
-    pub ResetAcousticModelAdaptation: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub OnLoadCFG: unsafe extern "system" fn(*mut core::ffi::c_void, *const core::ffi::c_void, *const SPBINARYGRAMMAR, u32) -> windows_core::HRESULT,
-    pub OnUnloadCFG: unsafe extern "system" fn(*mut core::ffi::c_void, *const core::ffi::c_void, u32) -> windows_core::HRESULT,
-}
-#[cfg(feature = "Win32_Media_Audio")]
-pub trait ISpSREngine2_Impl: ISpSREngine_Impl {
-    fn PrivateCallImmediate(&self, pvenginecontext: *const core::ffi::c_void, pincallframe: *const core::ffi::c_void, ulincallframesize: u32, ppvcomemresponse: *mut *mut core::ffi::c_void, pulresponsesize: *mut u32) -> windows_core::Result<()>;
-    fn SetAdaptationData2(&self, pvenginecontext: *const core::ffi::c_void, padaptationdata: &windows_core::PCWSTR, cch: u32, ptopicname: &windows_core::PCWSTR, esettings: SPADAPTATIONSETTINGS, erelevance: SPADAPTATIONRELEVANCE) -> windows_core::Result<()>;
-    fn SetGrammarPrefix(&self, pvenginegrammar: *const core::ffi::c_void, pszprefix: &windows_core::PCWSTR, fisprefixrequired: windows_core::BOOL) -> windows_core::Result<()>;
-    fn SetRulePriority(&self, hrule: SPRULEHANDLE, pvclientrulecontext: *const core::ffi::c_void, nrulepriority: i32) -> windows_core::Result<()>;
-    fn EmulateRecognition(&self, pphrase: windows_core::Ref<ISpPhrase>, dwcompareflags: u32) -> windows_core::Result<()>;
-    fn SetSLMWeight(&self, pvenginegrammar: *const core::ffi::c_void, flweight: f32) -> windows_core::Result<()>;
+impl<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl> windows_core::imp::TearOffVtable<Identity> for ISpSREngine_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetSite<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, psite: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::SetSite(this, core::mem::transmute_copy(&psite)).into()
+            }
+        }
+        unsafe extern "system" fn GetInputAudioFormat<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, pguidsourceformatid: *const windows_core::GUID, psourcewaveformatex: *const super::Audio::WAVEFORMATEX, pguiddesiredformatid: *mut windows_core::GUID, ppcomemdesiredwaveformatex: *mut *mut super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::GetInputAudioFormat(this, core::mem::transmute_copy(&pguidsourceformatid), core::mem::transmute_copy(&psourcewaveformatex), core::mem::transmute_copy(&pguiddesiredformatid), core::mem::transmute_copy(&ppcomemdesiredwaveformatex)).into()
+            }
+        }
+        unsafe extern "system" fn RecognizeStream<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, rguidfmtid: *const windows_core::GUID, pwaveformatex: *const super::Audio::WAVEFORMATEX, hrequestsync: super::super::Foundation::HANDLE, hdataavailable: super::super::Foundation::HANDLE, hexit: super::super::Foundation::HANDLE, fnewaudiostream: windows_core::BOOL, frealtimeaudio: windows_core::BOOL, paudioobjecttoken: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::RecognizeStream(this, core::mem::transmute_copy(&rguidfmtid), core::mem::transmute_copy(&pwaveformatex), core::mem::transmute_copy(&hrequestsync), core::mem::transmute_copy(&hdataavailable), core::mem::transmute_copy(&hexit), core::mem::transmute_copy(&fnewaudiostream), core::mem::transmute_copy(&frealtimeaudio), core::mem::transmute_copy(&paudioobjecttoken)).into()
+            }
+        }
+        unsafe extern "system" fn SetRecoProfile<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::SetRecoProfile(this, core::mem::transmute_copy(&pprofile)).into()
+            }
+        }
+        unsafe extern "system" fn OnCreateGrammar<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, pvenginerecocontext: *const core::ffi::c_void, hsapigrammar: SPGRAMMARHANDLE, ppvenginegrammarcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::OnCreateGrammar(this, core::mem::transmute_copy(&pvenginerecocontext), core::mem::transmute_copy(&hsapigrammar), core::mem::transmute_copy(&ppvenginegrammarcontext)).into()
+            }
+        }
+        unsafe extern "system" fn OnDeleteGrammar<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::OnDeleteGrammar(this, core::mem::transmute_copy(&pvenginegrammar)).into()
+            }
+        }
+        unsafe extern "system" fn LoadProprietaryGrammar<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, rguidparam: *const windows_core::GUID, pszstringparam: windows_core::PCWSTR, pvdataparam: *const core::ffi::c_void, uldatasize: u32, options: SPLOADOPTIONS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::LoadProprietaryGrammar(this, core::mem::transmute_copy(&pvenginegrammar), core::mem::transmute_copy(&rguidparam), core::mem::transmute(&pszstringparam), core::mem::transmute_copy(&pvdataparam), core::mem::transmute_copy(&uldatasize), core::mem::transmute_copy(&options)).into()
+            }
+        }
+        unsafe extern "system" fn UnloadProprietaryGrammar<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::UnloadProprietaryGrammar(this, core::mem::transmute_copy(&pvenginegrammar)).into()
+            }
+        }
+        unsafe extern "system" fn SetProprietaryRuleState<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, pszname: windows_core::PCWSTR, preserved: *const core::ffi::c_void, newstate: SPRULESTATE, pcruleschanged: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpSREngine_Impl::SetProprietaryRuleState(this, core::mem::transmute_copy(&pvenginegrammar), core::mem::transmute(&pszname), core::mem::transmute_copy(&preserved), core::mem::transmute_copy(&newstate)) {
+                    Ok(ok__) => {
+                        pcruleschanged.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProprietaryRuleIdState<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, dwruleid: u32, newstate: SPRULESTATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::SetProprietaryRuleIdState(this, core::mem::transmute_copy(&pvenginegrammar), core::mem::transmute_copy(&dwruleid), core::mem::transmute_copy(&newstate)).into()
+            }
+        }
+        unsafe extern "system" fn LoadSLM<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, psztopicname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::LoadSLM(this, core::mem::transmute_copy(&pvenginegrammar), core::mem::transmute(&psztopicname)).into()
+            }
+        }
+        unsafe extern "system" fn UnloadSLM<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::UnloadSLM(this, core::mem::transmute_copy(&pvenginegrammar)).into()
+            }
+        }
+        unsafe extern "system" fn SetSLMState<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, newstate: SPRULESTATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::SetSLMState(this, core::mem::transmute_copy(&pvenginegrammar), core::mem::transmute_copy(&newstate)).into()
+            }
+        }
+        unsafe extern "system" fn SetWordSequenceData<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, ptext: windows_core::PCWSTR, cchtext: u32, pinfo: *const SPTEXTSELECTIONINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::SetWordSequenceData(this, core::mem::transmute_copy(&pvenginegrammar), core::mem::transmute(&ptext), core::mem::transmute_copy(&cchtext), core::mem::transmute_copy(&pinfo)).into()
+            }
+        }
+        unsafe extern "system" fn SetTextSelection<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, pinfo: *const SPTEXTSELECTIONINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::SetTextSelection(this, core::mem::transmute_copy(&pvenginegrammar), core::mem::transmute_copy(&pinfo)).into()
+            }
+        }
+        unsafe extern "system" fn IsPronounceable<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, pszword: windows_core::PCWSTR, pwordpronounceable: *mut SPWORDPRONOUNCEABLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpSREngine_Impl::IsPronounceable(this, core::mem::transmute_copy(&pvenginegrammar), core::mem::transmute(&pszword)) {
+                    Ok(ok__) => {
+                        pwordpronounceable.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OnCreateRecoContext<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, hsapirecocontext: SPRECOCONTEXTHANDLE, ppvenginecontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::OnCreateRecoContext(this, core::mem::transmute_copy(&hsapirecocontext), core::mem::transmute_copy(&ppvenginecontext)).into()
+            }
+        }
+        unsafe extern "system" fn OnDeleteRecoContext<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, pvenginecontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::OnDeleteRecoContext(this, core::mem::transmute_copy(&pvenginecontext)).into()
+            }
+        }
+        unsafe extern "system" fn PrivateCall<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, pvenginecontext: *const core::ffi::c_void, pcallframe: *mut core::ffi::c_void, ulcallframesize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::PrivateCall(this, core::mem::transmute_copy(&pvenginecontext), core::mem::transmute_copy(&pcallframe), core::mem::transmute_copy(&ulcallframesize)).into()
+            }
+        }
+        unsafe extern "system" fn SetAdaptationData<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, pvenginecontext: *const core::ffi::c_void, padaptationdata: windows_core::PCWSTR, cch: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::SetAdaptationData(this, core::mem::transmute_copy(&pvenginecontext), core::mem::transmute(&padaptationdata), core::mem::transmute_copy(&cch)).into()
+            }
+        }
+        unsafe extern "system" fn SetPropertyNum<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, esrc: SPPROPSRC, pvsrcobj: *const core::ffi::c_void, pname: windows_core::PCWSTR, lvalue: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::SetPropertyNum(this, core::mem::transmute_copy(&esrc), core::mem::transmute_copy(&pvsrcobj), core::mem::transmute(&pname), core::mem::transmute_copy(&lvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetPropertyNum<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, esrc: SPPROPSRC, pvsrcobj: *const core::ffi::c_void, pname: windows_core::PCWSTR, lvalue: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpSREngine_Impl::GetPropertyNum(this, core::mem::transmute_copy(&esrc), core::mem::transmute_copy(&pvsrcobj), core::mem::transmute(&pname)) {
+                    Ok(ok__) => {
+                        lvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPropertyString<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, esrc: SPPROPSRC, pvsrcobj: *const core::ffi::c_void, pname: windows_core::PCWSTR, pvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::SetPropertyString(this, core::mem::transmute_copy(&esrc), core::mem::transmute_copy(&pvsrcobj), core::mem::transmute(&pname), core::mem::transmute(&pvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetPropertyString<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, esrc: SPPROPSRC, pvsrcobj: *const core::ffi::c_void, pname: windows_core::PCWSTR, ppcomemvalue: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpSREngine_Impl::GetPropertyString(this, core::mem::transmute_copy(&esrc), core::mem::transmute_copy(&pvsrcobj), core::mem::transmute(&pname)) {
+                    Ok(ok__) => {
+                        ppcomemvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetGrammarState<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, egrammarstate: SPGRAMMARSTATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::SetGrammarState(this, core::mem::transmute_copy(&pvenginegrammar), core::mem::transmute_copy(&egrammarstate)).into()
+            }
+        }
+        unsafe extern "system" fn WordNotify<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, action: SPCFGNOTIFY, cwords: u32, pwords: *const SPWORDENTRY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::WordNotify(this, core::mem::transmute_copy(&action), core::mem::transmute_copy(&cwords), core::mem::transmute_copy(&pwords)).into()
+            }
+        }
+        unsafe extern "system" fn RuleNotify<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, action: SPCFGNOTIFY, crules: u32, prules: *const SPRULEENTRY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::RuleNotify(this, core::mem::transmute_copy(&action), core::mem::transmute_copy(&crules), core::mem::transmute_copy(&prules)).into()
+            }
+        }
+        unsafe extern "system" fn PrivateCallEx<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, pvenginecontext: *const core::ffi::c_void, pincallframe: *const core::ffi::c_void, ulincallframesize: u32, ppvcomemresponse: *mut *mut core::ffi::c_void, pulresponsesize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::PrivateCallEx(this, core::mem::transmute_copy(&pvenginecontext), core::mem::transmute_copy(&pincallframe), core::mem::transmute_copy(&ulincallframesize), core::mem::transmute_copy(&ppvcomemresponse), core::mem::transmute_copy(&pulresponsesize)).into()
+            }
+        }
+        unsafe extern "system" fn SetContextState<Identity: windows_core::IUnknownImpl + ISpSREngine_Impl>(this: *mut core::ffi::c_void, pvenginecontext: *const core::ffi::c_void, econtextstate: SPCONTEXTSTATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine_Impl::SetContextState(this, core::mem::transmute_copy(&pvenginecontext), core::mem::transmute_copy(&econtextstate)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetSite: SetSite::<Identity>,
+            GetInputAudioFormat: GetInputAudioFormat::<Identity>,
+            RecognizeStream: RecognizeStream::<Identity>,
+            SetRecoProfile: SetRecoProfile::<Identity>,
+            OnCreateGrammar: OnCreateGrammar::<Identity>,
+            OnDeleteGrammar: OnDeleteGrammar::<Identity>,
+            LoadProprietaryGrammar: LoadProprietaryGrammar::<Identity>,
+            UnloadProprietaryGrammar: UnloadProprietaryGrammar::<Identity>,
+            SetProprietaryRuleState: SetProprietaryRuleState::<Identity>,
+            SetProprietaryRuleIdState: SetProprietaryRuleIdState::<Identity>,
+            LoadSLM: LoadSLM::<Identity>,
+            UnloadSLM: UnloadSLM::<Identity>,
+            SetSLMState: SetSLMState::<Identity>,
+            SetWordSequenceData: SetWordSequenceData::<Identity>,
+            SetTextSelection: SetTextSelection::<Identity>,
+            IsPronounceable: IsPronounceable::<Identity>,
+            OnCreateRecoContext: OnCreateRecoContext::<Identity>,
+            OnDeleteRecoContext: OnDeleteRecoContext::<Identity>,
+            PrivateCall: PrivateCall::<Identity>,
+            SetAdaptationData: SetAdaptationData::<Identity>,
+            SetPropertyNum: SetPropertyNum::<Identity>,
+            GetPropertyNum: GetPropertyNum::<Identity>,
+            SetPropertyString: SetPropertyString::<Identity>,
+            GetPropertyString: GetPropertyString::<Identity>,
+            SetGrammarState: SetGrammarState::<Identity>,
+            WordNotify: WordNotify::<Identity>,
+            RuleNotify: RuleNotify::<Identity>,
+            PrivateCallEx: PrivateCallEx::<Identity>,
+            SetContextState: SetContextState::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Media_Audio")]
+impl windows_core::RuntimeName for ISpSREngine {}
+windows_core::imp::define_interface!(ISpSREngine2, ISpSREngine2_Vtbl, 0x7ba627d8_33f9_4375_90c5_9985aee5ede5);
+impl core::ops::Deref for ISpSREngine2 {
+    type Target = ISpSREngine;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(ISpSREngine2, windows_core::IUnknown, ISpSREngine);
+impl ISpSREngine2 {
+    pub unsafe fn PrivateCallImmediate(&self, pvenginecontext: *const core::ffi::c_void, pincallframe: *const core::ffi::c_void, ulincallframesize: u32, ppvcomemresponse: *mut *mut core::ffi::c_void, pulresponsesize: *mut u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).PrivateCallImmediate)(windows_core::Interface::as_raw(self), pvenginecontext, pincallframe, ulincallframesize, ppvcomemresponse as _, pulresponsesize as _).ok() }
+    }
+    pub unsafe fn SetAdaptationData2<P3>(&self, pvenginecontext: *const core::ffi::c_void, padaptationdata: &[u16], ptopicname: P3, esettings: SPADAPTATIONSETTINGS, erelevance: SPADAPTATIONRELEVANCE) -> windows_core::Result<()>
+    where
+        P3: windows_core::Param<windows_core::PCWSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).SetAdaptationData2)(windows_core::Interface::as_raw(self), pvenginecontext, core::mem::transmute(padaptationdata.as_ptr()), padaptationdata.len().try_into().unwrap(), ptopicname.param().abi(), esettings, erelevance).ok() }
+    }
+    pub unsafe fn SetGrammarPrefix<P1>(&self, pvenginegrammar: *const core::ffi::c_void, pszprefix: P1, fisprefixrequired: bool) -> windows_core::Result<()>
+    where
+        P1: windows_core::Param<windows_core::PCWSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).SetGrammarPrefix)(windows_core::Interface::as_raw(self), pvenginegrammar, pszprefix.param().abi(), fisprefixrequired.into()).ok() }
+    }
+    pub unsafe fn SetRulePriority(&self, hrule: SPRULEHANDLE, pvclientrulecontext: *const core::ffi::c_void, nrulepriority: i32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetRulePriority)(windows_core::Interface::as_raw(self), hrule, pvclientrulecontext, nrulepriority).ok() }
+    }
+    pub unsafe fn EmulateRecognition<P0>(&self, pphrase: P0, dwcompareflags: u32) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<ISpPhrase>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).EmulateRecognition)(windows_core::Interface::as_raw(self), pphrase.param().abi(), dwcompareflags).ok() }
+    }
+    pub unsafe fn SetSLMWeight(&self, pvenginegrammar: *const core::ffi::c_void, flweight: f32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetSLMWeight)(windows_core::Interface::as_raw(self), pvenginegrammar, flweight).ok() }
+    }
+    pub unsafe fn SetRuleWeight(&self, hrule: SPRULEHANDLE, pvclientrulecontext: *const core::ffi::c_void, flweight: f32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetRuleWeight)(windows_core::Interface::as_raw(self), hrule, pvclientrulecontext, flweight).ok() }
+    }
+    pub unsafe fn SetTrainingState(&self, fdoingtraining: bool, fadaptfromtrainingdata: bool) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetTrainingState)(windows_core::Interface::as_raw(self), fdoingtraining.into(), fadaptfromtrainingdata.into()).ok() }
+    }
+    pub unsafe fn ResetAcousticModelAdaptation(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).ResetAcousticModelAdaptation)(windows_core::Interface::as_raw(self)).ok() }
+    }
+    pub unsafe fn OnLoadCFG(&self, pvenginegrammar: *const core::ffi::c_void, pgrammardata: *const SPBINARYGRAMMAR, ulgrammarid: u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).OnLoadCFG)(windows_core::Interface::as_raw(self), pvenginegrammar, pgrammardata, ulgrammarid).ok() }
+    }
+    pub unsafe fn OnUnloadCFG(&self, pvenginegrammar: *const core::ffi::c_void, ulgrammarid: u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).OnUnloadCFG)(windows_core::Interface::as_raw(self), pvenginegrammar, ulgrammarid).ok() }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct ISpSREngine2_Vtbl {
+    pub base__: ISpSREngine_Vtbl,
+    pub PrivateCallImmediate: unsafe extern "system" fn(*mut core::ffi::c_void, *const core::ffi::c_void, *const core::ffi::c_void, u32, *mut *mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
+    pub SetAdaptationData2: unsafe extern "system" fn(*mut core::ffi::c_void, *const core::ffi::c_void, windows_core::PCWSTR, u32, windows_core::PCWSTR, SPADAPTATIONSETTINGS, SPADAPTATIONRELEVANCE) -> windows_core::HRESULT,
+    pub SetGrammarPrefix: unsafe extern "system" fn(*mut core::ffi::c_void, *const core::ffi::c_void, windows_core::PCWSTR, windows_core::BOOL) -> windows_core::HRESULT,
+    pub SetRulePriority: unsafe extern "system" fn(*mut core::ffi::c_void, SPRULEHANDLE, *const core::ffi::c_void, i32) -> windows_core::HRESULT,
+    pub EmulateRecognition: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, u32) -> windows_core::HRESULT,
+    pub SetSLMWeight: unsafe extern "system" fn(*mut core::ffi::c_void, *const core::ffi::c_void, f32) -> windows_core::HRESULT,
+    pub SetRuleWeight: unsafe extern "system" fn(*mut core::ffi::c_void, SPRULEHANDLE, *const core::ffi::c_void, f32) -> windows_core::HRESULT,
+    pub SetTrainingState: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::BOOL, windows_core::BOOL) -> windows_core::HRESULT,
+    pub ResetAcousticModelAdaptation: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub OnLoadCFG: unsafe extern "system" fn(*mut core::ffi::c_void, *const core::ffi::c_void, *const SPBINARYGRAMMAR, u32) -> windows_core::HRESULT,
+    pub OnUnloadCFG: unsafe extern "system" fn(*mut core::ffi::c_void, *const core::ffi::c_void, u32) -> windows_core::HRESULT,
+}
+#[cfg(feature = "Win32_Media_Audio")]
+pub trait ISpSREngine2_Impl: ISpSREngine_Impl {
+    fn PrivateCallImmediate(&self, pvenginecontext: *const core::ffi::c_void, pincallframe: *const core::ffi::c_void, ulincallframesize: u32, ppvcomemresponse: *mut *mut core::ffi::c_void, pulresponsesize: *mut u32) -> windows_core::Result<()>;
+    fn SetAdaptationData2(&self, pvenginecontext: *const core::ffi::c_void, padaptationdata: &windows_core::PCWSTR, cch: u32, ptopicname: &windows_core::PCWSTR, esettings: SPADAPTATIONSETTINGS, erelevance: SPADAPTATIONRELEVANCE) -> windows_core::Result<()>;
+    fn SetGrammarPrefix(&self, pvenginegrammar: *const core::ffi::c_void, pszprefix: &windows_core::PCWSTR, fisprefixrequired: windows_core::BOOL) -> windows_core::Result<()>;
+    fn SetRulePriority(&self, hrule: SPRULEHANDLE, pvclientrulecontext: *const core::ffi::c_void, nrulepriority: i32) -> windows_core::Result<()>;
+    fn EmulateRecognition(&self, pphrase: windows_core::Ref<ISpPhrase>, dwcompareflags: u32) -> windows_core::Result<()>;
+    fn SetSLMWeight(&self, pvenginegrammar: *const core::ffi::c_void, flweight: f32) -> windows_core::Result<()>;
     fn SetRuleWeight(&self, hrule: SPRULEHANDLE, pvclientrulecontext: *const core::ffi::c_void, flweight: f32) -> windows_core::Result<()>;
     fn SetTrainingState(&self, fdoingtraining: windows_core::BOOL, fadaptfromtrainingdata: windows_core::BOOL) -> windows_core::Result<()>;
     fn ResetAcousticModelAdaptation(&self) -> windows_core::Result<()>;
@@ -5757,38 +8253,126 @@ impl ISpSREngine2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Media_Audio")]
-impl windows_core::RuntimeName for ISpSREngine2 {}
-windows_core::imp::define_interface!(ISpSREngineSite, ISpSREngineSite_Vtbl, 0x3b414aec_720c_4883_b9ef_178cd394fb3a);
-windows_core::imp::interface_hierarchy!(ISpSREngineSite, windows_core::IUnknown);
-impl ISpSREngineSite {
-    pub unsafe fn Read(&self, pv: *const core::ffi::c_void, cb: u32) -> windows_core::Result<u32> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).Read)(windows_core::Interface::as_raw(self), pv, cb, &mut result__).map(|| result__)
+impl<Identity: windows_core::IUnknownImpl + ISpSREngine2_Impl> windows_core::imp::TearOffVtable<Identity> for ISpSREngine2_Vtbl
+where
+    ISpSREngine_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn PrivateCallImmediate<Identity: windows_core::IUnknownImpl + ISpSREngine2_Impl>(this: *mut core::ffi::c_void, pvenginecontext: *const core::ffi::c_void, pincallframe: *const core::ffi::c_void, ulincallframesize: u32, ppvcomemresponse: *mut *mut core::ffi::c_void, pulresponsesize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine2_Impl::PrivateCallImmediate(this, core::mem::transmute_copy(&pvenginecontext), core::mem::transmute_copy(&pincallframe), core::mem::transmute_copy(&ulincallframesize), core::mem::transmute_copy(&ppvcomemresponse), core::mem::transmute_copy(&pulresponsesize)).into()
+            }
         }
-    }
-    pub unsafe fn DataAvailable(&self) -> windows_core::Result<u32> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).DataAvailable)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        unsafe extern "system" fn SetAdaptationData2<Identity: windows_core::IUnknownImpl + ISpSREngine2_Impl>(this: *mut core::ffi::c_void, pvenginecontext: *const core::ffi::c_void, padaptationdata: windows_core::PCWSTR, cch: u32, ptopicname: windows_core::PCWSTR, esettings: SPADAPTATIONSETTINGS, erelevance: SPADAPTATIONRELEVANCE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine2_Impl::SetAdaptationData2(this, core::mem::transmute_copy(&pvenginecontext), core::mem::transmute(&padaptationdata), core::mem::transmute_copy(&cch), core::mem::transmute(&ptopicname), core::mem::transmute_copy(&esettings), core::mem::transmute_copy(&erelevance)).into()
+            }
         }
-    }
-    pub unsafe fn SetBufferNotifySize(&self, cbsize: u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetBufferNotifySize)(windows_core::Interface::as_raw(self), cbsize).ok() }
-    }
-    pub unsafe fn ParseFromTransitions(&self, pparseinfo: *const SPPARSEINFO) -> windows_core::Result<ISpPhraseBuilder> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).ParseFromTransitions)(windows_core::Interface::as_raw(self), pparseinfo, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn SetGrammarPrefix<Identity: windows_core::IUnknownImpl + ISpSREngine2_Impl>(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, pszprefix: windows_core::PCWSTR, fisprefixrequired: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine2_Impl::SetGrammarPrefix(this, core::mem::transmute_copy(&pvenginegrammar), core::mem::transmute(&pszprefix), core::mem::transmute_copy(&fisprefixrequired)).into()
+            }
         }
-    }
-    pub unsafe fn Recognition(&self, presultinfo: *const SPRECORESULTINFO) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Recognition)(windows_core::Interface::as_raw(self), core::mem::transmute(presultinfo)).ok() }
-    }
-    pub unsafe fn AddEvent(&self, pevent: *const SPEVENT, hsapirecocontext: SPRECOCONTEXTHANDLE) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).AddEvent)(windows_core::Interface::as_raw(self), pevent, hsapirecocontext).ok() }
-    }
-    pub unsafe fn Synchronize(&self, ullprocessedthrupos: u64) -> windows_core::Result<()> {
+        unsafe extern "system" fn SetRulePriority<Identity: windows_core::IUnknownImpl + ISpSREngine2_Impl>(this: *mut core::ffi::c_void, hrule: SPRULEHANDLE, pvclientrulecontext: *const core::ffi::c_void, nrulepriority: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine2_Impl::SetRulePriority(this, core::mem::transmute_copy(&hrule), core::mem::transmute_copy(&pvclientrulecontext), core::mem::transmute_copy(&nrulepriority)).into()
+            }
+        }
+        unsafe extern "system" fn EmulateRecognition<Identity: windows_core::IUnknownImpl + ISpSREngine2_Impl>(this: *mut core::ffi::c_void, pphrase: *mut core::ffi::c_void, dwcompareflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine2_Impl::EmulateRecognition(this, core::mem::transmute_copy(&pphrase), core::mem::transmute_copy(&dwcompareflags)).into()
+            }
+        }
+        unsafe extern "system" fn SetSLMWeight<Identity: windows_core::IUnknownImpl + ISpSREngine2_Impl>(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, flweight: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine2_Impl::SetSLMWeight(this, core::mem::transmute_copy(&pvenginegrammar), core::mem::transmute_copy(&flweight)).into()
+            }
+        }
+        unsafe extern "system" fn SetRuleWeight<Identity: windows_core::IUnknownImpl + ISpSREngine2_Impl>(this: *mut core::ffi::c_void, hrule: SPRULEHANDLE, pvclientrulecontext: *const core::ffi::c_void, flweight: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine2_Impl::SetRuleWeight(this, core::mem::transmute_copy(&hrule), core::mem::transmute_copy(&pvclientrulecontext), core::mem::transmute_copy(&flweight)).into()
+            }
+        }
+        unsafe extern "system" fn SetTrainingState<Identity: windows_core::IUnknownImpl + ISpSREngine2_Impl>(this: *mut core::ffi::c_void, fdoingtraining: windows_core::BOOL, fadaptfromtrainingdata: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine2_Impl::SetTrainingState(this, core::mem::transmute_copy(&fdoingtraining), core::mem::transmute_copy(&fadaptfromtrainingdata)).into()
+            }
+        }
+        unsafe extern "system" fn ResetAcousticModelAdaptation<Identity: windows_core::IUnknownImpl + ISpSREngine2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine2_Impl::ResetAcousticModelAdaptation(this).into()
+            }
+        }
+        unsafe extern "system" fn OnLoadCFG<Identity: windows_core::IUnknownImpl + ISpSREngine2_Impl>(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, pgrammardata: *const SPBINARYGRAMMAR, ulgrammarid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine2_Impl::OnLoadCFG(this, core::mem::transmute_copy(&pvenginegrammar), core::mem::transmute_copy(&pgrammardata), core::mem::transmute_copy(&ulgrammarid)).into()
+            }
+        }
+        unsafe extern "system" fn OnUnloadCFG<Identity: windows_core::IUnknownImpl + ISpSREngine2_Impl>(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, ulgrammarid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngine2_Impl::OnUnloadCFG(this, core::mem::transmute_copy(&pvenginegrammar), core::mem::transmute_copy(&ulgrammarid)).into()
+            }
+        }
+        Self {
+            base__: <ISpSREngine_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PrivateCallImmediate: PrivateCallImmediate::<Identity>,
+            SetAdaptationData2: SetAdaptationData2::<Identity>,
+            SetGrammarPrefix: SetGrammarPrefix::<Identity>,
+            SetRulePriority: SetRulePriority::<Identity>,
+            EmulateRecognition: EmulateRecognition::<Identity>,
+            SetSLMWeight: SetSLMWeight::<Identity>,
+            SetRuleWeight: SetRuleWeight::<Identity>,
+            SetTrainingState: SetTrainingState::<Identity>,
+            ResetAcousticModelAdaptation: ResetAcousticModelAdaptation::<Identity>,
+            OnLoadCFG: OnLoadCFG::<Identity>,
+            OnUnloadCFG: OnUnloadCFG::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Media_Audio")]
+impl windows_core::RuntimeName for ISpSREngine2 {}
+windows_core::imp::define_interface!(ISpSREngineSite, ISpSREngineSite_Vtbl, 0x3b414aec_720c_4883_b9ef_178cd394fb3a);
+windows_core::imp::interface_hierarchy!(ISpSREngineSite, windows_core::IUnknown);
+impl ISpSREngineSite {
+    pub unsafe fn Read(&self, pv: *const core::ffi::c_void, cb: u32) -> windows_core::Result<u32> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).Read)(windows_core::Interface::as_raw(self), pv, cb, &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn DataAvailable(&self) -> windows_core::Result<u32> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).DataAvailable)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn SetBufferNotifySize(&self, cbsize: u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetBufferNotifySize)(windows_core::Interface::as_raw(self), cbsize).ok() }
+    }
+    pub unsafe fn ParseFromTransitions(&self, pparseinfo: *const SPPARSEINFO) -> windows_core::Result<ISpPhraseBuilder> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).ParseFromTransitions)(windows_core::Interface::as_raw(self), pparseinfo, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn Recognition(&self, presultinfo: *const SPRECORESULTINFO) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Recognition)(windows_core::Interface::as_raw(self), core::mem::transmute(presultinfo)).ok() }
+    }
+    pub unsafe fn AddEvent(&self, pevent: *const SPEVENT, hsapirecocontext: SPRECOCONTEXTHANDLE) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).AddEvent)(windows_core::Interface::as_raw(self), pevent, hsapirecocontext).ok() }
+    }
+    pub unsafe fn Synchronize(&self, ullprocessedthrupos: u64) -> windows_core::Result<()> {
         unsafe { (windows_core::Interface::vtable(self).Synchronize)(windows_core::Interface::as_raw(self), ullprocessedthrupos).ok() }
     }
     pub unsafe fn GetWordInfo(&self, pwordentry: *mut SPWORDENTRY, options: SPWORDINFOOPT) -> windows_core::Result<()> {
@@ -6068,6 +8652,185 @@ impl ISpSREngineSite_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpSREngineSite_Impl> windows_core::imp::TearOffVtable<Identity> for ISpSREngineSite_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Read<Identity: windows_core::IUnknownImpl + ISpSREngineSite_Impl>(this: *mut core::ffi::c_void, pv: *const core::ffi::c_void, cb: u32, pcbread: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpSREngineSite_Impl::Read(this, core::mem::transmute_copy(&pv), core::mem::transmute_copy(&cb)) {
+                    Ok(ok__) => {
+                        pcbread.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DataAvailable<Identity: windows_core::IUnknownImpl + ISpSREngineSite_Impl>(this: *mut core::ffi::c_void, pcb: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpSREngineSite_Impl::DataAvailable(this) {
+                    Ok(ok__) => {
+                        pcb.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBufferNotifySize<Identity: windows_core::IUnknownImpl + ISpSREngineSite_Impl>(this: *mut core::ffi::c_void, cbsize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngineSite_Impl::SetBufferNotifySize(this, core::mem::transmute_copy(&cbsize)).into()
+            }
+        }
+        unsafe extern "system" fn ParseFromTransitions<Identity: windows_core::IUnknownImpl + ISpSREngineSite_Impl>(this: *mut core::ffi::c_void, pparseinfo: *const SPPARSEINFO, ppnewphrase: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpSREngineSite_Impl::ParseFromTransitions(this, core::mem::transmute_copy(&pparseinfo)) {
+                    Ok(ok__) => {
+                        ppnewphrase.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Recognition<Identity: windows_core::IUnknownImpl + ISpSREngineSite_Impl>(this: *mut core::ffi::c_void, presultinfo: *const SPRECORESULTINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngineSite_Impl::Recognition(this, core::mem::transmute_copy(&presultinfo)).into()
+            }
+        }
+        unsafe extern "system" fn AddEvent<Identity: windows_core::IUnknownImpl + ISpSREngineSite_Impl>(this: *mut core::ffi::c_void, pevent: *const SPEVENT, hsapirecocontext: SPRECOCONTEXTHANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngineSite_Impl::AddEvent(this, core::mem::transmute_copy(&pevent), core::mem::transmute_copy(&hsapirecocontext)).into()
+            }
+        }
+        unsafe extern "system" fn Synchronize<Identity: windows_core::IUnknownImpl + ISpSREngineSite_Impl>(this: *mut core::ffi::c_void, ullprocessedthrupos: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngineSite_Impl::Synchronize(this, core::mem::transmute_copy(&ullprocessedthrupos)).into()
+            }
+        }
+        unsafe extern "system" fn GetWordInfo<Identity: windows_core::IUnknownImpl + ISpSREngineSite_Impl>(this: *mut core::ffi::c_void, pwordentry: *mut SPWORDENTRY, options: SPWORDINFOOPT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngineSite_Impl::GetWordInfo(this, core::mem::transmute_copy(&pwordentry), core::mem::transmute_copy(&options)).into()
+            }
+        }
+        unsafe extern "system" fn SetWordClientContext<Identity: windows_core::IUnknownImpl + ISpSREngineSite_Impl>(this: *mut core::ffi::c_void, hword: SPWORDHANDLE, pvclientcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngineSite_Impl::SetWordClientContext(this, core::mem::transmute_copy(&hword), core::mem::transmute_copy(&pvclientcontext)).into()
+            }
+        }
+        unsafe extern "system" fn GetRuleInfo<Identity: windows_core::IUnknownImpl + ISpSREngineSite_Impl>(this: *mut core::ffi::c_void, pruleentry: *mut SPRULEENTRY, options: SPRULEINFOOPT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngineSite_Impl::GetRuleInfo(this, core::mem::transmute_copy(&pruleentry), core::mem::transmute_copy(&options)).into()
+            }
+        }
+        unsafe extern "system" fn SetRuleClientContext<Identity: windows_core::IUnknownImpl + ISpSREngineSite_Impl>(this: *mut core::ffi::c_void, hrule: SPRULEHANDLE, pvclientcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngineSite_Impl::SetRuleClientContext(this, core::mem::transmute_copy(&hrule), core::mem::transmute_copy(&pvclientcontext)).into()
+            }
+        }
+        unsafe extern "system" fn GetStateInfo<Identity: windows_core::IUnknownImpl + ISpSREngineSite_Impl>(this: *mut core::ffi::c_void, hstate: SPSTATEHANDLE, pstateinfo: *mut SPSTATEINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngineSite_Impl::GetStateInfo(this, core::mem::transmute_copy(&hstate), core::mem::transmute_copy(&pstateinfo)).into()
+            }
+        }
+        unsafe extern "system" fn GetResource<Identity: windows_core::IUnknownImpl + ISpSREngineSite_Impl>(this: *mut core::ffi::c_void, hrule: SPRULEHANDLE, pszresourcename: windows_core::PCWSTR, ppcomemresource: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpSREngineSite_Impl::GetResource(this, core::mem::transmute_copy(&hrule), core::mem::transmute(&pszresourcename)) {
+                    Ok(ok__) => {
+                        ppcomemresource.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTransitionProperty<Identity: windows_core::IUnknownImpl + ISpSREngineSite_Impl>(this: *mut core::ffi::c_void, id: SPTRANSITIONID, ppcomemproperty: *mut *mut SPTRANSITIONPROPERTY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpSREngineSite_Impl::GetTransitionProperty(this, core::mem::transmute_copy(&id)) {
+                    Ok(ok__) => {
+                        ppcomemproperty.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsAlternate<Identity: windows_core::IUnknownImpl + ISpSREngineSite_Impl>(this: *mut core::ffi::c_void, hrule: SPRULEHANDLE, haltrule: SPRULEHANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngineSite_Impl::IsAlternate(this, core::mem::transmute_copy(&hrule), core::mem::transmute_copy(&haltrule)).into()
+            }
+        }
+        unsafe extern "system" fn GetMaxAlternates<Identity: windows_core::IUnknownImpl + ISpSREngineSite_Impl>(this: *mut core::ffi::c_void, hrule: SPRULEHANDLE, pulnumalts: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpSREngineSite_Impl::GetMaxAlternates(this, core::mem::transmute_copy(&hrule)) {
+                    Ok(ok__) => {
+                        pulnumalts.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetContextMaxAlternates<Identity: windows_core::IUnknownImpl + ISpSREngineSite_Impl>(this: *mut core::ffi::c_void, hcontext: SPRECOCONTEXTHANDLE, pulnumalts: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpSREngineSite_Impl::GetContextMaxAlternates(this, core::mem::transmute_copy(&hcontext)) {
+                    Ok(ok__) => {
+                        pulnumalts.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UpdateRecoPos<Identity: windows_core::IUnknownImpl + ISpSREngineSite_Impl>(this: *mut core::ffi::c_void, ullcurrentrecopos: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngineSite_Impl::UpdateRecoPos(this, core::mem::transmute_copy(&ullcurrentrecopos)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Read: Read::<Identity>,
+            DataAvailable: DataAvailable::<Identity>,
+            SetBufferNotifySize: SetBufferNotifySize::<Identity>,
+            ParseFromTransitions: ParseFromTransitions::<Identity>,
+            Recognition: Recognition::<Identity>,
+            AddEvent: AddEvent::<Identity>,
+            Synchronize: Synchronize::<Identity>,
+            GetWordInfo: GetWordInfo::<Identity>,
+            SetWordClientContext: SetWordClientContext::<Identity>,
+            GetRuleInfo: GetRuleInfo::<Identity>,
+            SetRuleClientContext: SetRuleClientContext::<Identity>,
+            GetStateInfo: GetStateInfo::<Identity>,
+            GetResource: GetResource::<Identity>,
+            GetTransitionProperty: GetTransitionProperty::<Identity>,
+            IsAlternate: IsAlternate::<Identity>,
+            GetMaxAlternates: GetMaxAlternates::<Identity>,
+            GetContextMaxAlternates: GetContextMaxAlternates::<Identity>,
+            UpdateRecoPos: UpdateRecoPos::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpSREngineSite {}
 windows_core::imp::define_interface!(ISpSREngineSite2, ISpSREngineSite2_Vtbl, 0x7bc6e012_684a_493e_bdd4_2bf5fbf48cfe);
 impl core::ops::Deref for ISpSREngineSite2 {
@@ -6147,6 +8910,45 @@ impl ISpSREngineSite2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpSREngineSite2_Impl> windows_core::imp::TearOffVtable<Identity> for ISpSREngineSite2_Vtbl
+where
+    ISpSREngineSite_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddEventEx<Identity: windows_core::IUnknownImpl + ISpSREngineSite2_Impl>(this: *mut core::ffi::c_void, pevent: *const SPEVENTEX, hsapirecocontext: SPRECOCONTEXTHANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngineSite2_Impl::AddEventEx(this, core::mem::transmute_copy(&pevent), core::mem::transmute_copy(&hsapirecocontext)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateRecoPosEx<Identity: windows_core::IUnknownImpl + ISpSREngineSite2_Impl>(this: *mut core::ffi::c_void, ullcurrentrecopos: u64, ullcurrentrecotime: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngineSite2_Impl::UpdateRecoPosEx(this, core::mem::transmute_copy(&ullcurrentrecopos), core::mem::transmute_copy(&ullcurrentrecotime)).into()
+            }
+        }
+        unsafe extern "system" fn GetRuleTransition<Identity: windows_core::IUnknownImpl + ISpSREngineSite2_Impl>(this: *mut core::ffi::c_void, ulgrammarid: u32, ruleindex: u32, ptrans: *mut SPTRANSITIONENTRY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngineSite2_Impl::GetRuleTransition(this, core::mem::transmute_copy(&ulgrammarid), core::mem::transmute_copy(&ruleindex), core::mem::transmute_copy(&ptrans)).into()
+            }
+        }
+        unsafe extern "system" fn RecognitionEx<Identity: windows_core::IUnknownImpl + ISpSREngineSite2_Impl>(this: *mut core::ffi::c_void, presultinfo: *const SPRECORESULTINFOEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSREngineSite2_Impl::RecognitionEx(this, core::mem::transmute_copy(&presultinfo)).into()
+            }
+        }
+        Self {
+            base__: <ISpSREngineSite_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddEventEx: AddEventEx::<Identity>,
+            UpdateRecoPosEx: UpdateRecoPosEx::<Identity>,
+            GetRuleTransition: GetRuleTransition::<Identity>,
+            RecognitionEx: RecognitionEx::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpSREngineSite2 {}
 windows_core::imp::define_interface!(ISpSerializeState, ISpSerializeState_Vtbl, 0x21b501a0_0ec7_46c9_92c3_a2bc784c54b9);
 windows_core::imp::interface_hierarchy!(ISpSerializeState, windows_core::IUnknown);
@@ -6193,6 +8995,30 @@ impl ISpSerializeState_Vtbl {
         iid == &<ISpSerializeState as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpSerializeState_Impl> windows_core::imp::TearOffVtable<Identity> for ISpSerializeState_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSerializedState<Identity: windows_core::IUnknownImpl + ISpSerializeState_Impl>(this: *mut core::ffi::c_void, ppbdata: *mut *mut u8, pulsize: *mut u32, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSerializeState_Impl::GetSerializedState(this, core::mem::transmute_copy(&ppbdata), core::mem::transmute_copy(&pulsize), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn SetSerializedState<Identity: windows_core::IUnknownImpl + ISpSerializeState_Impl>(this: *mut core::ffi::c_void, pbdata: *const u8, ulsize: u32, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpSerializeState_Impl::SetSerializedState(this, core::mem::transmute_copy(&pbdata), core::mem::transmute_copy(&ulsize), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSerializedState: GetSerializedState::<Identity>,
+            SetSerializedState: SetSerializedState::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpSerializeState {}
 windows_core::imp::define_interface!(ISpShortcut, ISpShortcut_Vtbl, 0x3df681e2_ea56_11d9_8bde_f66bad1e3f3a);
 windows_core::imp::interface_hierarchy!(ISpShortcut, windows_core::IUnknown);
@@ -6328,6 +9154,78 @@ impl ISpShortcut_Vtbl {
         iid == &<ISpShortcut as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpShortcut_Impl> windows_core::imp::TearOffVtable<Identity> for ISpShortcut_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddShortcut<Identity: windows_core::IUnknownImpl + ISpShortcut_Impl>(this: *mut core::ffi::c_void, pszdisplay: windows_core::PCWSTR, langid: u16, pszspoken: windows_core::PCWSTR, shtype: SPSHORTCUTTYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpShortcut_Impl::AddShortcut(this, core::mem::transmute(&pszdisplay), core::mem::transmute_copy(&langid), core::mem::transmute(&pszspoken), core::mem::transmute_copy(&shtype)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveShortcut<Identity: windows_core::IUnknownImpl + ISpShortcut_Impl>(this: *mut core::ffi::c_void, pszdisplay: windows_core::PCWSTR, langid: u16, pszspoken: windows_core::PCWSTR, shtype: SPSHORTCUTTYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpShortcut_Impl::RemoveShortcut(this, core::mem::transmute(&pszdisplay), core::mem::transmute_copy(&langid), core::mem::transmute(&pszspoken), core::mem::transmute_copy(&shtype)).into()
+            }
+        }
+        unsafe extern "system" fn GetShortcuts<Identity: windows_core::IUnknownImpl + ISpShortcut_Impl>(this: *mut core::ffi::c_void, langid: u16, pshortcutpairlist: *mut SPSHORTCUTPAIRLIST) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpShortcut_Impl::GetShortcuts(this, core::mem::transmute_copy(&langid), core::mem::transmute_copy(&pshortcutpairlist)).into()
+            }
+        }
+        unsafe extern "system" fn GetGeneration<Identity: windows_core::IUnknownImpl + ISpShortcut_Impl>(this: *mut core::ffi::c_void, pdwgeneration: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpShortcut_Impl::GetGeneration(this) {
+                    Ok(ok__) => {
+                        pdwgeneration.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetWordsFromGenerationChange<Identity: windows_core::IUnknownImpl + ISpShortcut_Impl>(this: *mut core::ffi::c_void, pdwgeneration: *mut u32, pwordlist: *mut SPWORDLIST) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpShortcut_Impl::GetWordsFromGenerationChange(this, core::mem::transmute_copy(&pdwgeneration), core::mem::transmute_copy(&pwordlist)).into()
+            }
+        }
+        unsafe extern "system" fn GetWords<Identity: windows_core::IUnknownImpl + ISpShortcut_Impl>(this: *mut core::ffi::c_void, pdwgeneration: *mut u32, pdwcookie: *mut u32, pwordlist: *mut SPWORDLIST) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpShortcut_Impl::GetWords(this, core::mem::transmute_copy(&pdwgeneration), core::mem::transmute_copy(&pdwcookie), core::mem::transmute_copy(&pwordlist)).into()
+            }
+        }
+        unsafe extern "system" fn GetShortcutsForGeneration<Identity: windows_core::IUnknownImpl + ISpShortcut_Impl>(this: *mut core::ffi::c_void, pdwgeneration: *mut u32, pdwcookie: *mut u32, pshortcutpairlist: *mut SPSHORTCUTPAIRLIST) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpShortcut_Impl::GetShortcutsForGeneration(this, core::mem::transmute_copy(&pdwgeneration), core::mem::transmute_copy(&pdwcookie), core::mem::transmute_copy(&pshortcutpairlist)).into()
+            }
+        }
+        unsafe extern "system" fn GetGenerationChange<Identity: windows_core::IUnknownImpl + ISpShortcut_Impl>(this: *mut core::ffi::c_void, pdwgeneration: *mut u32, pshortcutpairlist: *mut SPSHORTCUTPAIRLIST) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpShortcut_Impl::GetGenerationChange(this, core::mem::transmute_copy(&pdwgeneration), core::mem::transmute_copy(&pshortcutpairlist)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddShortcut: AddShortcut::<Identity>,
+            RemoveShortcut: RemoveShortcut::<Identity>,
+            GetShortcuts: GetShortcuts::<Identity>,
+            GetGeneration: GetGeneration::<Identity>,
+            GetWordsFromGenerationChange: GetWordsFromGenerationChange::<Identity>,
+            GetWords: GetWords::<Identity>,
+            GetShortcutsForGeneration: GetShortcutsForGeneration::<Identity>,
+            GetGenerationChange: GetGenerationChange::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpShortcut {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpStream, ISpStream_Vtbl, 0x12e3cca9_7518_44c5_a5e7_ba5a79cb929e);
@@ -6435,9 +9333,54 @@ impl ISpStream_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com"))]
-impl windows_core::RuntimeName for ISpStream {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(ISpStreamFormat, ISpStreamFormat_Vtbl, 0xbed530be_2606_4f4d_a1c0_54c5cda5566f);
+impl<Identity: windows_core::IUnknownImpl + ISpStream_Impl> windows_core::imp::TearOffVtable<Identity> for ISpStream_Vtbl
+where
+    ISpStreamFormat_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetBaseStream<Identity: windows_core::IUnknownImpl + ISpStream_Impl>(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, rguidformat: *const windows_core::GUID, pwaveformatex: *const super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpStream_Impl::SetBaseStream(this, core::mem::transmute_copy(&pstream), core::mem::transmute_copy(&rguidformat), core::mem::transmute_copy(&pwaveformatex)).into()
+            }
+        }
+        unsafe extern "system" fn GetBaseStream<Identity: windows_core::IUnknownImpl + ISpStream_Impl>(this: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpStream_Impl::GetBaseStream(this) {
+                    Ok(ok__) => {
+                        ppstream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn BindToFile<Identity: windows_core::IUnknownImpl + ISpStream_Impl>(this: *mut core::ffi::c_void, pszfilename: windows_core::PCWSTR, emode: SPFILEMODE, pformatid: *const windows_core::GUID, pwaveformatex: *const super::Audio::WAVEFORMATEX, ulleventinterest: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpStream_Impl::BindToFile(this, core::mem::transmute(&pszfilename), core::mem::transmute_copy(&emode), core::mem::transmute_copy(&pformatid), core::mem::transmute_copy(&pwaveformatex), core::mem::transmute_copy(&ulleventinterest)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + ISpStream_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpStream_Impl::Close(this).into()
+            }
+        }
+        Self {
+            base__: <ISpStreamFormat_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetBaseStream: SetBaseStream::<Identity>,
+            GetBaseStream: GetBaseStream::<Identity>,
+            BindToFile: BindToFile::<Identity>,
+            Close: Close::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com"))]
+impl windows_core::RuntimeName for ISpStream {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(ISpStreamFormat, ISpStreamFormat_Vtbl, 0xbed530be_2606_4f4d_a1c0_54c5cda5566f);
 #[cfg(feature = "Win32_System_Com")]
 impl core::ops::Deref for ISpStreamFormat {
     type Target = super::super::System::Com::IStream;
@@ -6493,6 +9436,27 @@ impl ISpStreamFormat_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com"))]
+impl<Identity: windows_core::IUnknownImpl + ISpStreamFormat_Impl> windows_core::imp::TearOffVtable<Identity> for ISpStreamFormat_Vtbl
+where
+    super::super::System::Com::IStream_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFormat<Identity: windows_core::IUnknownImpl + ISpStreamFormat_Impl>(this: *mut core::ffi::c_void, pguidformatid: *const windows_core::GUID, ppcomemwaveformatex: *mut *mut super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpStreamFormat_Impl::GetFormat(this, core::mem::transmute_copy(&pguidformatid)) {
+                    Ok(ok__) => {
+                        ppcomemwaveformatex.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <super::super::System::Com::IStream_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetFormat: GetFormat::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com"))]
 impl windows_core::RuntimeName for ISpStreamFormat {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpStreamFormatConverter, ISpStreamFormatConverter_Vtbl, 0x678a932c_ea71_4446_9b41_78fda6280a29);
@@ -6635,6 +9599,77 @@ impl ISpStreamFormatConverter_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com"))]
+impl<Identity: windows_core::IUnknownImpl + ISpStreamFormatConverter_Impl> windows_core::imp::TearOffVtable<Identity> for ISpStreamFormatConverter_Vtbl
+where
+    ISpStreamFormat_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetBaseStream<Identity: windows_core::IUnknownImpl + ISpStreamFormatConverter_Impl>(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, fsetformattobasestreamformat: windows_core::BOOL, fwritetobasestream: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpStreamFormatConverter_Impl::SetBaseStream(this, core::mem::transmute_copy(&pstream), core::mem::transmute_copy(&fsetformattobasestreamformat), core::mem::transmute_copy(&fwritetobasestream)).into()
+            }
+        }
+        unsafe extern "system" fn GetBaseStream<Identity: windows_core::IUnknownImpl + ISpStreamFormatConverter_Impl>(this: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpStreamFormatConverter_Impl::GetBaseStream(this) {
+                    Ok(ok__) => {
+                        ppstream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetFormat<Identity: windows_core::IUnknownImpl + ISpStreamFormatConverter_Impl>(this: *mut core::ffi::c_void, rguidformatidofconvertedstream: *const windows_core::GUID, pwaveformatexofconvertedstream: *const super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpStreamFormatConverter_Impl::SetFormat(this, core::mem::transmute_copy(&rguidformatidofconvertedstream), core::mem::transmute_copy(&pwaveformatexofconvertedstream)).into()
+            }
+        }
+        unsafe extern "system" fn ResetSeekPosition<Identity: windows_core::IUnknownImpl + ISpStreamFormatConverter_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpStreamFormatConverter_Impl::ResetSeekPosition(this).into()
+            }
+        }
+        unsafe extern "system" fn ScaleConvertedToBaseOffset<Identity: windows_core::IUnknownImpl + ISpStreamFormatConverter_Impl>(this: *mut core::ffi::c_void, ulloffsetconvertedstream: u64, pulloffsetbasestream: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpStreamFormatConverter_Impl::ScaleConvertedToBaseOffset(this, core::mem::transmute_copy(&ulloffsetconvertedstream)) {
+                    Ok(ok__) => {
+                        pulloffsetbasestream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ScaleBaseToConvertedOffset<Identity: windows_core::IUnknownImpl + ISpStreamFormatConverter_Impl>(this: *mut core::ffi::c_void, ulloffsetbasestream: u64, pulloffsetconvertedstream: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpStreamFormatConverter_Impl::ScaleBaseToConvertedOffset(this, core::mem::transmute_copy(&ulloffsetbasestream)) {
+                    Ok(ok__) => {
+                        pulloffsetconvertedstream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ISpStreamFormat_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetBaseStream: SetBaseStream::<Identity>,
+            GetBaseStream: GetBaseStream::<Identity>,
+            SetFormat: SetFormat::<Identity>,
+            ResetSeekPosition: ResetSeekPosition::<Identity>,
+            ScaleConvertedToBaseOffset: ScaleConvertedToBaseOffset::<Identity>,
+            ScaleBaseToConvertedOffset: ScaleBaseToConvertedOffset::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com"))]
 impl windows_core::RuntimeName for ISpStreamFormatConverter {}
 windows_core::imp::define_interface!(ISpTTSEngine, ISpTTSEngine_Vtbl, 0xa74d7c8e_4cc5_4f2f_a6eb_804dee18500e);
 windows_core::imp::interface_hierarchy!(ISpTTSEngine, windows_core::IUnknown);
@@ -6695,6 +9730,31 @@ impl ISpTTSEngine_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Media_Audio")]
+impl<Identity: windows_core::IUnknownImpl + ISpTTSEngine_Impl> windows_core::imp::TearOffVtable<Identity> for ISpTTSEngine_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Speak<Identity: windows_core::IUnknownImpl + ISpTTSEngine_Impl>(this: *mut core::ffi::c_void, dwspeakflags: u32, rguidformatid: *const windows_core::GUID, pwaveformatex: *const super::Audio::WAVEFORMATEX, ptextfraglist: *const SPVTEXTFRAG, poutputsite: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpTTSEngine_Impl::Speak(this, core::mem::transmute_copy(&dwspeakflags), core::mem::transmute_copy(&rguidformatid), core::mem::transmute_copy(&pwaveformatex), core::mem::transmute_copy(&ptextfraglist), core::mem::transmute_copy(&poutputsite)).into()
+            }
+        }
+        unsafe extern "system" fn GetOutputFormat<Identity: windows_core::IUnknownImpl + ISpTTSEngine_Impl>(this: *mut core::ffi::c_void, ptargetfmtid: *const windows_core::GUID, ptargetwaveformatex: *const super::Audio::WAVEFORMATEX, poutputformatid: *mut windows_core::GUID, ppcomemoutputwaveformatex: *mut *mut super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpTTSEngine_Impl::GetOutputFormat(this, core::mem::transmute_copy(&ptargetfmtid), core::mem::transmute_copy(&ptargetwaveformatex), core::mem::transmute_copy(&poutputformatid), core::mem::transmute_copy(&ppcomemoutputwaveformatex)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Speak: Speak::<Identity>,
+            GetOutputFormat: GetOutputFormat::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Media_Audio")]
 impl windows_core::RuntimeName for ISpTTSEngine {}
 windows_core::imp::define_interface!(ISpTTSEngineSite, ISpTTSEngineSite_Vtbl, 0x9880499b_cce9_11d2_b503_00c04f797396);
 impl core::ops::Deref for ISpTTSEngineSite {
@@ -6822,6 +9882,76 @@ impl ISpTTSEngineSite_Vtbl {
         iid == &<ISpTTSEngineSite as windows_core::Interface>::IID || iid == &<ISpEventSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpTTSEngineSite_Impl> windows_core::imp::TearOffVtable<Identity> for ISpTTSEngineSite_Vtbl
+where
+    ISpEventSink_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetActions<Identity: windows_core::IUnknownImpl + ISpTTSEngineSite_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpTTSEngineSite_Impl::GetActions(this)
+            }
+        }
+        unsafe extern "system" fn Write<Identity: windows_core::IUnknownImpl + ISpTTSEngineSite_Impl>(this: *mut core::ffi::c_void, pbuff: *const core::ffi::c_void, cb: u32, pcbwritten: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpTTSEngineSite_Impl::Write(this, core::mem::transmute_copy(&pbuff), core::mem::transmute_copy(&cb)) {
+                    Ok(ok__) => {
+                        pcbwritten.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRate<Identity: windows_core::IUnknownImpl + ISpTTSEngineSite_Impl>(this: *mut core::ffi::c_void, prateadjust: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpTTSEngineSite_Impl::GetRate(this) {
+                    Ok(ok__) => {
+                        prateadjust.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetVolume<Identity: windows_core::IUnknownImpl + ISpTTSEngineSite_Impl>(this: *mut core::ffi::c_void, pusvolume: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpTTSEngineSite_Impl::GetVolume(this) {
+                    Ok(ok__) => {
+                        pusvolume.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSkipInfo<Identity: windows_core::IUnknownImpl + ISpTTSEngineSite_Impl>(this: *mut core::ffi::c_void, petype: *mut SPVSKIPTYPE, plnumitems: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpTTSEngineSite_Impl::GetSkipInfo(this, core::mem::transmute_copy(&petype), core::mem::transmute_copy(&plnumitems)).into()
+            }
+        }
+        unsafe extern "system" fn CompleteSkip<Identity: windows_core::IUnknownImpl + ISpTTSEngineSite_Impl>(this: *mut core::ffi::c_void, ulnumskipped: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpTTSEngineSite_Impl::CompleteSkip(this, core::mem::transmute_copy(&ulnumskipped)).into()
+            }
+        }
+        Self {
+            base__: <ISpEventSink_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetActions: GetActions::<Identity>,
+            Write: Write::<Identity>,
+            GetRate: GetRate::<Identity>,
+            GetVolume: GetVolume::<Identity>,
+            GetSkipInfo: GetSkipInfo::<Identity>,
+            CompleteSkip: CompleteSkip::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpTTSEngineSite {}
 windows_core::imp::define_interface!(ISpTask, ISpTask_Vtbl);
 impl ISpTask {
@@ -7001,6 +10131,83 @@ impl ISpTaskManager_Vtbl {
         iid == &<ISpTaskManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpTaskManager_Impl> windows_core::imp::TearOffVtable<Identity> for ISpTaskManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetThreadPoolInfo<Identity: windows_core::IUnknownImpl + ISpTaskManager_Impl>(this: *mut core::ffi::c_void, ppoolinfo: *const SPTMTHREADINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpTaskManager_Impl::SetThreadPoolInfo(this, core::mem::transmute_copy(&ppoolinfo)).into()
+            }
+        }
+        unsafe extern "system" fn GetThreadPoolInfo<Identity: windows_core::IUnknownImpl + ISpTaskManager_Impl>(this: *mut core::ffi::c_void, ppoolinfo: *mut SPTMTHREADINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpTaskManager_Impl::GetThreadPoolInfo(this) {
+                    Ok(ok__) => {
+                        ppoolinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueueTask<Identity: windows_core::IUnknownImpl + ISpTaskManager_Impl>(this: *mut core::ffi::c_void, ptask: *mut core::ffi::c_void, pvtaskdata: *const core::ffi::c_void, hcompevent: super::super::Foundation::HANDLE, pdwgroupid: *mut u32, ptaskid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpTaskManager_Impl::QueueTask(this, core::mem::transmute_copy(&ptask), core::mem::transmute_copy(&pvtaskdata), core::mem::transmute_copy(&hcompevent), core::mem::transmute_copy(&pdwgroupid), core::mem::transmute_copy(&ptaskid)).into()
+            }
+        }
+        unsafe extern "system" fn CreateReoccurringTask<Identity: windows_core::IUnknownImpl + ISpTaskManager_Impl>(this: *mut core::ffi::c_void, ptask: *mut core::ffi::c_void, pvtaskdata: *const core::ffi::c_void, hcompevent: super::super::Foundation::HANDLE, pptaskctrl: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpTaskManager_Impl::CreateReoccurringTask(this, core::mem::transmute_copy(&ptask), core::mem::transmute_copy(&pvtaskdata), core::mem::transmute_copy(&hcompevent)) {
+                    Ok(ok__) => {
+                        pptaskctrl.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateThreadControl<Identity: windows_core::IUnknownImpl + ISpTaskManager_Impl>(this: *mut core::ffi::c_void, ptask: *mut core::ffi::c_void, pvtaskdata: *const core::ffi::c_void, npriority: i32, pptaskctrl: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpTaskManager_Impl::CreateThreadControl(this, core::mem::transmute_copy(&ptask), core::mem::transmute_copy(&pvtaskdata), core::mem::transmute_copy(&npriority)) {
+                    Ok(ok__) => {
+                        pptaskctrl.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn TerminateTask<Identity: windows_core::IUnknownImpl + ISpTaskManager_Impl>(this: *mut core::ffi::c_void, dwtaskid: u32, ulwaitperiod: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpTaskManager_Impl::TerminateTask(this, core::mem::transmute_copy(&dwtaskid), core::mem::transmute_copy(&ulwaitperiod)).into()
+            }
+        }
+        unsafe extern "system" fn TerminateTaskGroup<Identity: windows_core::IUnknownImpl + ISpTaskManager_Impl>(this: *mut core::ffi::c_void, dwgroupid: u32, ulwaitperiod: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpTaskManager_Impl::TerminateTaskGroup(this, core::mem::transmute_copy(&dwgroupid), core::mem::transmute_copy(&ulwaitperiod)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetThreadPoolInfo: SetThreadPoolInfo::<Identity>,
+            GetThreadPoolInfo: GetThreadPoolInfo::<Identity>,
+            QueueTask: QueueTask::<Identity>,
+            CreateReoccurringTask: CreateReoccurringTask::<Identity>,
+            CreateThreadControl: CreateThreadControl::<Identity>,
+            TerminateTask: TerminateTask::<Identity>,
+            TerminateTaskGroup: TerminateTaskGroup::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpTaskManager {}
 windows_core::imp::define_interface!(ISpThreadControl, ISpThreadControl_Vtbl, 0xa6be4d73_4403_4358_b22d_0346e23b1764);
 impl core::ops::Deref for ISpThreadControl {
@@ -7146,6 +10353,85 @@ impl ISpThreadControl_Vtbl {
         iid == &<ISpThreadControl as windows_core::Interface>::IID || iid == &<ISpNotifySink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpThreadControl_Impl> windows_core::imp::TearOffVtable<Identity> for ISpThreadControl_Vtbl
+where
+    ISpNotifySink_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StartThread<Identity: windows_core::IUnknownImpl + ISpThreadControl_Impl>(this: *mut core::ffi::c_void, dwflags: u32, phwnd: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpThreadControl_Impl::StartThread(this, core::mem::transmute_copy(&dwflags)) {
+                    Ok(ok__) => {
+                        phwnd.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn WaitForThreadDone<Identity: windows_core::IUnknownImpl + ISpThreadControl_Impl>(this: *mut core::ffi::c_void, fforcestop: windows_core::BOOL, phrthreadresult: *mut windows_core::HRESULT, mstimeout: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpThreadControl_Impl::WaitForThreadDone(this, core::mem::transmute_copy(&fforcestop), core::mem::transmute_copy(&phrthreadresult), core::mem::transmute_copy(&mstimeout)).into()
+            }
+        }
+        unsafe extern "system" fn TerminateThread<Identity: windows_core::IUnknownImpl + ISpThreadControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpThreadControl_Impl::TerminateThread(this).into()
+            }
+        }
+        unsafe extern "system" fn ThreadHandle<Identity: windows_core::IUnknownImpl + ISpThreadControl_Impl>(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpThreadControl_Impl::ThreadHandle(this)
+            }
+        }
+        unsafe extern "system" fn ThreadId<Identity: windows_core::IUnknownImpl + ISpThreadControl_Impl>(this: *mut core::ffi::c_void) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpThreadControl_Impl::ThreadId(this)
+            }
+        }
+        unsafe extern "system" fn NotifyEvent<Identity: windows_core::IUnknownImpl + ISpThreadControl_Impl>(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpThreadControl_Impl::NotifyEvent(this)
+            }
+        }
+        unsafe extern "system" fn WindowHandle<Identity: windows_core::IUnknownImpl + ISpThreadControl_Impl>(this: *mut core::ffi::c_void) -> super::super::Foundation::HWND {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpThreadControl_Impl::WindowHandle(this)
+            }
+        }
+        unsafe extern "system" fn ThreadCompleteEvent<Identity: windows_core::IUnknownImpl + ISpThreadControl_Impl>(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpThreadControl_Impl::ThreadCompleteEvent(this)
+            }
+        }
+        unsafe extern "system" fn ExitThreadEvent<Identity: windows_core::IUnknownImpl + ISpThreadControl_Impl>(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpThreadControl_Impl::ExitThreadEvent(this)
+            }
+        }
+        Self {
+            base__: <ISpNotifySink_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            StartThread: StartThread::<Identity>,
+            WaitForThreadDone: WaitForThreadDone::<Identity>,
+            TerminateThread: TerminateThread::<Identity>,
+            ThreadHandle: ThreadHandle::<Identity>,
+            ThreadId: ThreadId::<Identity>,
+            NotifyEvent: NotifyEvent::<Identity>,
+            WindowHandle: WindowHandle::<Identity>,
+            ThreadCompleteEvent: ThreadCompleteEvent::<Identity>,
+            ExitThreadEvent: ExitThreadEvent::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpThreadControl {}
 windows_core::imp::define_interface!(ISpThreadTask, ISpThreadTask_Vtbl);
 impl ISpThreadTask {
@@ -7272,6 +10558,36 @@ impl ISpTokenUI_Vtbl {
         iid == &<ISpTokenUI as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpTokenUI_Impl> windows_core::imp::TearOffVtable<Identity> for ISpTokenUI_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsUISupported<Identity: windows_core::IUnknownImpl + ISpTokenUI_Impl>(this: *mut core::ffi::c_void, psztypeofui: windows_core::PCWSTR, pvextradata: *const core::ffi::c_void, cbextradata: u32, punkobject: *mut core::ffi::c_void, pfsupported: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpTokenUI_Impl::IsUISupported(this, core::mem::transmute(&psztypeofui), core::mem::transmute_copy(&pvextradata), core::mem::transmute_copy(&cbextradata), core::mem::transmute_copy(&punkobject)) {
+                    Ok(ok__) => {
+                        pfsupported.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DisplayUI<Identity: windows_core::IUnknownImpl + ISpTokenUI_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, psztitle: windows_core::PCWSTR, psztypeofui: windows_core::PCWSTR, pvextradata: *const core::ffi::c_void, cbextradata: u32, ptoken: *mut core::ffi::c_void, punkobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpTokenUI_Impl::DisplayUI(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute(&psztitle), core::mem::transmute(&psztypeofui), core::mem::transmute_copy(&pvextradata), core::mem::transmute_copy(&cbextradata), core::mem::transmute_copy(&ptoken), core::mem::transmute_copy(&punkobject)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsUISupported: IsUISupported::<Identity>,
+            DisplayUI: DisplayUI::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISpTokenUI {}
 windows_core::imp::define_interface!(ISpTranscript, ISpTranscript_Vtbl, 0x10f63bce_201a_11d3_ac70_00c04f8ee6c0);
 windows_core::imp::interface_hierarchy!(ISpTranscript, windows_core::IUnknown);
@@ -7330,12 +10646,42 @@ impl ISpTranscript_Vtbl {
         iid == &<ISpTranscript as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for ISpTranscript {}
-windows_core::imp::define_interface!(ISpVoice, ISpVoice_Vtbl, 0x6c44df74_72b9_4992_a1ec_ef996e0422d4);
-impl core::ops::Deref for ISpVoice {
-    type Target = ISpEventSource;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
+impl<Identity: windows_core::IUnknownImpl + ISpTranscript_Impl> windows_core::imp::TearOffVtable<Identity> for ISpTranscript_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetTranscript<Identity: windows_core::IUnknownImpl + ISpTranscript_Impl>(this: *mut core::ffi::c_void, ppsztranscript: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpTranscript_Impl::GetTranscript(this) {
+                    Ok(ok__) => {
+                        ppsztranscript.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AppendTranscript<Identity: windows_core::IUnknownImpl + ISpTranscript_Impl>(this: *mut core::ffi::c_void, psztranscript: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpTranscript_Impl::AppendTranscript(this, core::mem::transmute(&psztranscript)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetTranscript: GetTranscript::<Identity>,
+            AppendTranscript: AppendTranscript::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for ISpTranscript {}
+windows_core::imp::define_interface!(ISpVoice, ISpVoice_Vtbl, 0x6c44df74_72b9_4992_a1ec_ef996e0422d4);
+impl core::ops::Deref for ISpVoice {
+    type Target = ISpEventSource;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
     }
 }
 windows_core::imp::interface_hierarchy!(ISpVoice, windows_core::IUnknown, ISpNotifySource, ISpEventSource);
@@ -7718,6 +11064,210 @@ impl ISpVoice_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + ISpVoice_Impl> windows_core::imp::TearOffVtable<Identity> for ISpVoice_Vtbl
+where
+    ISpEventSource_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetOutput<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void, punkoutput: *mut core::ffi::c_void, fallowformatchanges: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpVoice_Impl::SetOutput(this, core::mem::transmute_copy(&punkoutput), core::mem::transmute_copy(&fallowformatchanges)).into()
+            }
+        }
+        unsafe extern "system" fn GetOutputObjectToken<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void, ppobjecttoken: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpVoice_Impl::GetOutputObjectToken(this) {
+                    Ok(ok__) => {
+                        ppobjecttoken.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOutputStream<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpVoice_Impl::GetOutputStream(this) {
+                    Ok(ok__) => {
+                        ppstream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Pause<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpVoice_Impl::Pause(this).into()
+            }
+        }
+        unsafe extern "system" fn Resume<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpVoice_Impl::Resume(this).into()
+            }
+        }
+        unsafe extern "system" fn SetVoice<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void, ptoken: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpVoice_Impl::SetVoice(this, core::mem::transmute_copy(&ptoken)).into()
+            }
+        }
+        unsafe extern "system" fn GetVoice<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void, pptoken: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpVoice_Impl::GetVoice(this) {
+                    Ok(ok__) => {
+                        pptoken.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Speak<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void, pwcs: windows_core::PCWSTR, dwflags: u32, pulstreamnumber: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpVoice_Impl::Speak(this, core::mem::transmute(&pwcs), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pulstreamnumber)).into()
+            }
+        }
+        unsafe extern "system" fn SpeakStream<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, dwflags: u32, pulstreamnumber: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpVoice_Impl::SpeakStream(this, core::mem::transmute_copy(&pstream), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pulstreamnumber)).into()
+            }
+        }
+        unsafe extern "system" fn GetStatus<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void, pstatus: *mut SPVOICESTATUS, ppszlastbookmark: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpVoice_Impl::GetStatus(this, core::mem::transmute_copy(&pstatus), core::mem::transmute_copy(&ppszlastbookmark)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void, pitemtype: windows_core::PCWSTR, lnumitems: i32, pulnumskipped: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpVoice_Impl::Skip(this, core::mem::transmute(&pitemtype), core::mem::transmute_copy(&lnumitems), core::mem::transmute_copy(&pulnumskipped)).into()
+            }
+        }
+        unsafe extern "system" fn SetPriority<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void, epriority: SPVPRIORITY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpVoice_Impl::SetPriority(this, core::mem::transmute_copy(&epriority)).into()
+            }
+        }
+        unsafe extern "system" fn GetPriority<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void, pepriority: *mut SPVPRIORITY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpVoice_Impl::GetPriority(this, core::mem::transmute_copy(&pepriority)).into()
+            }
+        }
+        unsafe extern "system" fn SetAlertBoundary<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void, eboundary: SPEVENTENUM) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpVoice_Impl::SetAlertBoundary(this, core::mem::transmute_copy(&eboundary)).into()
+            }
+        }
+        unsafe extern "system" fn GetAlertBoundary<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void, peboundary: *mut SPEVENTENUM) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpVoice_Impl::GetAlertBoundary(this, core::mem::transmute_copy(&peboundary)).into()
+            }
+        }
+        unsafe extern "system" fn SetRate<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void, rateadjust: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpVoice_Impl::SetRate(this, core::mem::transmute_copy(&rateadjust)).into()
+            }
+        }
+        unsafe extern "system" fn GetRate<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void, prateadjust: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpVoice_Impl::GetRate(this, core::mem::transmute_copy(&prateadjust)).into()
+            }
+        }
+        unsafe extern "system" fn SetVolume<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void, usvolume: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpVoice_Impl::SetVolume(this, core::mem::transmute_copy(&usvolume)).into()
+            }
+        }
+        unsafe extern "system" fn GetVolume<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void, pusvolume: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpVoice_Impl::GetVolume(this, core::mem::transmute_copy(&pusvolume)).into()
+            }
+        }
+        unsafe extern "system" fn WaitUntilDone<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void, mstimeout: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpVoice_Impl::WaitUntilDone(this, core::mem::transmute_copy(&mstimeout)).into()
+            }
+        }
+        unsafe extern "system" fn SetSyncSpeakTimeout<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void, mstimeout: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpVoice_Impl::SetSyncSpeakTimeout(this, core::mem::transmute_copy(&mstimeout)).into()
+            }
+        }
+        unsafe extern "system" fn GetSyncSpeakTimeout<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void, pmstimeout: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpVoice_Impl::GetSyncSpeakTimeout(this, core::mem::transmute_copy(&pmstimeout)).into()
+            }
+        }
+        unsafe extern "system" fn SpeakCompleteEvent<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpVoice_Impl::SpeakCompleteEvent(this)
+            }
+        }
+        unsafe extern "system" fn IsUISupported<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void, psztypeofui: windows_core::PCWSTR, pvextradata: *mut core::ffi::c_void, cbextradata: u32, pfsupported: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpVoice_Impl::IsUISupported(this, core::mem::transmute(&psztypeofui), core::mem::transmute_copy(&pvextradata), core::mem::transmute_copy(&cbextradata), core::mem::transmute_copy(&pfsupported)).into()
+            }
+        }
+        unsafe extern "system" fn DisplayUI<Identity: windows_core::IUnknownImpl + ISpVoice_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, psztitle: windows_core::PCWSTR, psztypeofui: windows_core::PCWSTR, pvextradata: *mut core::ffi::c_void, cbextradata: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpVoice_Impl::DisplayUI(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute(&psztitle), core::mem::transmute(&psztypeofui), core::mem::transmute_copy(&pvextradata), core::mem::transmute_copy(&cbextradata)).into()
+            }
+        }
+        Self {
+            base__: <ISpEventSource_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetOutput: SetOutput::<Identity>,
+            GetOutputObjectToken: GetOutputObjectToken::<Identity>,
+            GetOutputStream: GetOutputStream::<Identity>,
+            Pause: Pause::<Identity>,
+            Resume: Resume::<Identity>,
+            SetVoice: SetVoice::<Identity>,
+            GetVoice: GetVoice::<Identity>,
+            Speak: Speak::<Identity>,
+            SpeakStream: SpeakStream::<Identity>,
+            GetStatus: GetStatus::<Identity>,
+            Skip: Skip::<Identity>,
+            SetPriority: SetPriority::<Identity>,
+            GetPriority: GetPriority::<Identity>,
+            SetAlertBoundary: SetAlertBoundary::<Identity>,
+            GetAlertBoundary: GetAlertBoundary::<Identity>,
+            SetRate: SetRate::<Identity>,
+            GetRate: GetRate::<Identity>,
+            SetVolume: SetVolume::<Identity>,
+            GetVolume: GetVolume::<Identity>,
+            WaitUntilDone: WaitUntilDone::<Identity>,
+            SetSyncSpeakTimeout: SetSyncSpeakTimeout::<Identity>,
+            GetSyncSpeakTimeout: GetSyncSpeakTimeout::<Identity>,
+            SpeakCompleteEvent: SpeakCompleteEvent::<Identity>,
+            IsUISupported: IsUISupported::<Identity>,
+            DisplayUI: DisplayUI::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for ISpVoice {}
 windows_core::imp::define_interface!(ISpXMLRecoResult, ISpXMLRecoResult_Vtbl, 0xae39362b_45a8_4074_9b9e_ccf49aa2d0b6);
 impl core::ops::Deref for ISpXMLRecoResult {
@@ -7773,6 +11323,31 @@ impl ISpXMLRecoResult_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpXMLRecoResult_Impl> windows_core::imp::TearOffVtable<Identity> for ISpXMLRecoResult_Vtbl
+where
+    ISpRecoResult_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetXMLResult<Identity: windows_core::IUnknownImpl + ISpXMLRecoResult_Impl>(this: *mut core::ffi::c_void, ppszcomemxmlresult: *mut windows_core::PWSTR, options: SPXMLRESULTOPTIONS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpXMLRecoResult_Impl::GetXMLResult(this, core::mem::transmute_copy(&ppszcomemxmlresult), core::mem::transmute_copy(&options)).into()
+            }
+        }
+        unsafe extern "system" fn GetXMLErrorInfo<Identity: windows_core::IUnknownImpl + ISpXMLRecoResult_Impl>(this: *mut core::ffi::c_void, psemanticerrorinfo: *mut SPSEMANTICERRORINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpXMLRecoResult_Impl::GetXMLErrorInfo(this, core::mem::transmute_copy(&psemanticerrorinfo)).into()
+            }
+        }
+        Self {
+            base__: <ISpRecoResult_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetXMLResult: GetXMLResult::<Identity>,
+            GetXMLErrorInfo: GetXMLErrorInfo::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpXMLRecoResult {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechAudio, ISpeechAudio_Vtbl, 0xcff8e175_019e_11d3_a08e_00c04f8ef9b5);
@@ -7971,6 +11546,116 @@ impl ISpeechAudio_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechAudio_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechAudio_Vtbl
+where
+    ISpeechBaseStream_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Status<Identity: windows_core::IUnknownImpl + ISpeechAudio_Impl>(this: *mut core::ffi::c_void, status: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechAudio_Impl::Status(this) {
+                    Ok(ok__) => {
+                        status.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn BufferInfo<Identity: windows_core::IUnknownImpl + ISpeechAudio_Impl>(this: *mut core::ffi::c_void, bufferinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechAudio_Impl::BufferInfo(this) {
+                    Ok(ok__) => {
+                        bufferinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DefaultFormat<Identity: windows_core::IUnknownImpl + ISpeechAudio_Impl>(this: *mut core::ffi::c_void, streamformat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechAudio_Impl::DefaultFormat(this) {
+                    Ok(ok__) => {
+                        streamformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Volume<Identity: windows_core::IUnknownImpl + ISpeechAudio_Impl>(this: *mut core::ffi::c_void, volume: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechAudio_Impl::Volume(this) {
+                    Ok(ok__) => {
+                        volume.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetVolume<Identity: windows_core::IUnknownImpl + ISpeechAudio_Impl>(this: *mut core::ffi::c_void, volume: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechAudio_Impl::SetVolume(this, core::mem::transmute_copy(&volume)).into()
+            }
+        }
+        unsafe extern "system" fn BufferNotifySize<Identity: windows_core::IUnknownImpl + ISpeechAudio_Impl>(this: *mut core::ffi::c_void, buffernotifysize: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechAudio_Impl::BufferNotifySize(this) {
+                    Ok(ok__) => {
+                        buffernotifysize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBufferNotifySize<Identity: windows_core::IUnknownImpl + ISpeechAudio_Impl>(this: *mut core::ffi::c_void, buffernotifysize: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechAudio_Impl::SetBufferNotifySize(this, core::mem::transmute_copy(&buffernotifysize)).into()
+            }
+        }
+        unsafe extern "system" fn EventHandle<Identity: windows_core::IUnknownImpl + ISpeechAudio_Impl>(this: *mut core::ffi::c_void, eventhandle: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechAudio_Impl::EventHandle(this) {
+                    Ok(ok__) => {
+                        eventhandle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetState<Identity: windows_core::IUnknownImpl + ISpeechAudio_Impl>(this: *mut core::ffi::c_void, state: SpeechAudioState) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechAudio_Impl::SetState(this, core::mem::transmute_copy(&state)).into()
+            }
+        }
+        Self {
+            base__: <ISpeechBaseStream_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Status: Status::<Identity>,
+            BufferInfo: BufferInfo::<Identity>,
+            DefaultFormat: DefaultFormat::<Identity>,
+            Volume: Volume::<Identity>,
+            SetVolume: SetVolume::<Identity>,
+            BufferNotifySize: BufferNotifySize::<Identity>,
+            SetBufferNotifySize: SetBufferNotifySize::<Identity>,
+            EventHandle: EventHandle::<Identity>,
+            SetState: SetState::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechAudio {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechAudioBufferInfo, ISpeechAudioBufferInfo_Vtbl, 0x11b103d8_1142_4edf_a093_82fb3915f8cc);
@@ -8106,26 +11791,97 @@ impl ISpeechAudioBufferInfo_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for ISpeechAudioBufferInfo {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(ISpeechAudioFormat, ISpeechAudioFormat_Vtbl, 0xe6e9c590_3e18_40e3_8299_061f98bde7c7);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for ISpeechAudioFormat {
-    type Target = super::super::System::Com::IDispatch;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(ISpeechAudioFormat, windows_core::IUnknown, super::super::System::Com::IDispatch);
-#[cfg(feature = "Win32_System_Com")]
-impl ISpeechAudioFormat {
-    pub unsafe fn Type(&self) -> windows_core::Result<SpeechAudioFormatType> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).Type)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+impl<Identity: windows_core::IUnknownImpl + ISpeechAudioBufferInfo_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechAudioBufferInfo_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn MinNotification<Identity: windows_core::IUnknownImpl + ISpeechAudioBufferInfo_Impl>(this: *mut core::ffi::c_void, minnotification: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechAudioBufferInfo_Impl::MinNotification(this) {
+                    Ok(ok__) => {
+                        minnotification.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
+        unsafe extern "system" fn SetMinNotification<Identity: windows_core::IUnknownImpl + ISpeechAudioBufferInfo_Impl>(this: *mut core::ffi::c_void, minnotification: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechAudioBufferInfo_Impl::SetMinNotification(this, core::mem::transmute_copy(&minnotification)).into()
+            }
+        }
+        unsafe extern "system" fn BufferSize<Identity: windows_core::IUnknownImpl + ISpeechAudioBufferInfo_Impl>(this: *mut core::ffi::c_void, buffersize: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechAudioBufferInfo_Impl::BufferSize(this) {
+                    Ok(ok__) => {
+                        buffersize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBufferSize<Identity: windows_core::IUnknownImpl + ISpeechAudioBufferInfo_Impl>(this: *mut core::ffi::c_void, buffersize: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechAudioBufferInfo_Impl::SetBufferSize(this, core::mem::transmute_copy(&buffersize)).into()
+            }
+        }
+        unsafe extern "system" fn EventBias<Identity: windows_core::IUnknownImpl + ISpeechAudioBufferInfo_Impl>(this: *mut core::ffi::c_void, eventbias: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechAudioBufferInfo_Impl::EventBias(this) {
+                    Ok(ok__) => {
+                        eventbias.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEventBias<Identity: windows_core::IUnknownImpl + ISpeechAudioBufferInfo_Impl>(this: *mut core::ffi::c_void, eventbias: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechAudioBufferInfo_Impl::SetEventBias(this, core::mem::transmute_copy(&eventbias)).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MinNotification: MinNotification::<Identity>,
+            SetMinNotification: SetMinNotification::<Identity>,
+            BufferSize: BufferSize::<Identity>,
+            SetBufferSize: SetBufferSize::<Identity>,
+            EventBias: EventBias::<Identity>,
+            SetEventBias: SetEventBias::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for ISpeechAudioBufferInfo {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(ISpeechAudioFormat, ISpeechAudioFormat_Vtbl, 0xe6e9c590_3e18_40e3_8299_061f98bde7c7);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for ISpeechAudioFormat {
+    type Target = super::super::System::Com::IDispatch;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(ISpeechAudioFormat, windows_core::IUnknown, super::super::System::Com::IDispatch);
+#[cfg(feature = "Win32_System_Com")]
+impl ISpeechAudioFormat {
+    pub unsafe fn Type(&self) -> windows_core::Result<SpeechAudioFormatType> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).Type)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
     pub unsafe fn SetType(&self, audioformat: SpeechAudioFormatType) -> windows_core::Result<()> {
         unsafe { (windows_core::Interface::vtable(self).SetType)(windows_core::Interface::as_raw(self), audioformat).ok() }
     }
@@ -8244,6 +12000,77 @@ impl ISpeechAudioFormat_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechAudioFormat_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechAudioFormat_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Type<Identity: windows_core::IUnknownImpl + ISpeechAudioFormat_Impl>(this: *mut core::ffi::c_void, audioformat: *mut SpeechAudioFormatType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechAudioFormat_Impl::Type(this) {
+                    Ok(ok__) => {
+                        audioformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetType<Identity: windows_core::IUnknownImpl + ISpeechAudioFormat_Impl>(this: *mut core::ffi::c_void, audioformat: SpeechAudioFormatType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechAudioFormat_Impl::SetType(this, core::mem::transmute_copy(&audioformat)).into()
+            }
+        }
+        unsafe extern "system" fn Guid<Identity: windows_core::IUnknownImpl + ISpeechAudioFormat_Impl>(this: *mut core::ffi::c_void, guid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechAudioFormat_Impl::Guid(this) {
+                    Ok(ok__) => {
+                        guid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetGuid<Identity: windows_core::IUnknownImpl + ISpeechAudioFormat_Impl>(this: *mut core::ffi::c_void, guid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechAudioFormat_Impl::SetGuid(this, core::mem::transmute(&guid)).into()
+            }
+        }
+        unsafe extern "system" fn GetWaveFormatEx<Identity: windows_core::IUnknownImpl + ISpeechAudioFormat_Impl>(this: *mut core::ffi::c_void, speechwaveformatex: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechAudioFormat_Impl::GetWaveFormatEx(this) {
+                    Ok(ok__) => {
+                        speechwaveformatex.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetWaveFormatEx<Identity: windows_core::IUnknownImpl + ISpeechAudioFormat_Impl>(this: *mut core::ffi::c_void, speechwaveformatex: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechAudioFormat_Impl::SetWaveFormatEx(this, core::mem::transmute_copy(&speechwaveformatex)).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Type: Type::<Identity>,
+            SetType: SetType::<Identity>,
+            Guid: Guid::<Identity>,
+            SetGuid: SetGuid::<Identity>,
+            GetWaveFormatEx: GetWaveFormatEx::<Identity>,
+            SetWaveFormatEx: SetWaveFormatEx::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechAudioFormat {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechAudioStatus, ISpeechAudioStatus_Vtbl, 0xc62d9c91_7458_47f6_862d_1ef86fb0b278);
@@ -8393,6 +12220,82 @@ impl ISpeechAudioStatus_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechAudioStatus_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechAudioStatus_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FreeBufferSpace<Identity: windows_core::IUnknownImpl + ISpeechAudioStatus_Impl>(this: *mut core::ffi::c_void, freebufferspace: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechAudioStatus_Impl::FreeBufferSpace(this) {
+                    Ok(ok__) => {
+                        freebufferspace.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NonBlockingIO<Identity: windows_core::IUnknownImpl + ISpeechAudioStatus_Impl>(this: *mut core::ffi::c_void, nonblockingio: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechAudioStatus_Impl::NonBlockingIO(this) {
+                    Ok(ok__) => {
+                        nonblockingio.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn State<Identity: windows_core::IUnknownImpl + ISpeechAudioStatus_Impl>(this: *mut core::ffi::c_void, state: *mut SpeechAudioState) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechAudioStatus_Impl::State(this) {
+                    Ok(ok__) => {
+                        state.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CurrentSeekPosition<Identity: windows_core::IUnknownImpl + ISpeechAudioStatus_Impl>(this: *mut core::ffi::c_void, currentseekposition: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechAudioStatus_Impl::CurrentSeekPosition(this) {
+                    Ok(ok__) => {
+                        currentseekposition.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CurrentDevicePosition<Identity: windows_core::IUnknownImpl + ISpeechAudioStatus_Impl>(this: *mut core::ffi::c_void, currentdeviceposition: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechAudioStatus_Impl::CurrentDevicePosition(this) {
+                    Ok(ok__) => {
+                        currentdeviceposition.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            FreeBufferSpace: FreeBufferSpace::<Identity>,
+            NonBlockingIO: NonBlockingIO::<Identity>,
+            State: State::<Identity>,
+            CurrentSeekPosition: CurrentSeekPosition::<Identity>,
+            CurrentDevicePosition: CurrentDevicePosition::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechAudioStatus {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechBaseStream, ISpeechBaseStream_Vtbl, 0x6450336f_7d49_4ced_8097_49d6dee37294);
@@ -8531,6 +12434,70 @@ impl ISpeechBaseStream_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechBaseStream_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechBaseStream_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Format<Identity: windows_core::IUnknownImpl + ISpeechBaseStream_Impl>(this: *mut core::ffi::c_void, audioformat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechBaseStream_Impl::Format(this) {
+                    Ok(ok__) => {
+                        audioformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putref_Format<Identity: windows_core::IUnknownImpl + ISpeechBaseStream_Impl>(this: *mut core::ffi::c_void, audioformat: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechBaseStream_Impl::putref_Format(this, core::mem::transmute_copy(&audioformat)).into()
+            }
+        }
+        unsafe extern "system" fn Read<Identity: windows_core::IUnknownImpl + ISpeechBaseStream_Impl>(this: *mut core::ffi::c_void, buffer: *mut super::super::System::Variant::VARIANT, numberofbytes: i32, bytesread: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechBaseStream_Impl::Read(this, core::mem::transmute_copy(&buffer), core::mem::transmute_copy(&numberofbytes), core::mem::transmute_copy(&bytesread)).into()
+            }
+        }
+        unsafe extern "system" fn Write<Identity: windows_core::IUnknownImpl + ISpeechBaseStream_Impl>(this: *mut core::ffi::c_void, buffer: super::super::System::Variant::VARIANT, byteswritten: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechBaseStream_Impl::Write(this, core::mem::transmute(&buffer)) {
+                    Ok(ok__) => {
+                        byteswritten.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Seek<Identity: windows_core::IUnknownImpl + ISpeechBaseStream_Impl>(this: *mut core::ffi::c_void, position: super::super::System::Variant::VARIANT, origin: SpeechStreamSeekPositionType, newposition: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechBaseStream_Impl::Seek(this, core::mem::transmute(&position), core::mem::transmute_copy(&origin)) {
+                    Ok(ok__) => {
+                        newposition.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Format: Format::<Identity>,
+            putref_Format: putref_Format::<Identity>,
+            Read: Read::<Identity>,
+            Write: Write::<Identity>,
+            Seek: Seek::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechBaseStream {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechCustomStream, ISpeechCustomStream_Vtbl, 0x1a9e9f4f_104f_4db8_a115_efd7fd0c97ae);
@@ -8603,6 +12570,37 @@ impl ISpeechCustomStream_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechCustomStream_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechCustomStream_Vtbl
+where
+    ISpeechBaseStream_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BaseStream<Identity: windows_core::IUnknownImpl + ISpeechCustomStream_Impl>(this: *mut core::ffi::c_void, ppunkstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechCustomStream_Impl::BaseStream(this) {
+                    Ok(ok__) => {
+                        ppunkstream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putref_BaseStream<Identity: windows_core::IUnknownImpl + ISpeechCustomStream_Impl>(this: *mut core::ffi::c_void, punkstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechCustomStream_Impl::putref_BaseStream(this, core::mem::transmute_copy(&punkstream)).into()
+            }
+        }
+        Self {
+            base__: <ISpeechBaseStream_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            BaseStream: BaseStream::<Identity>,
+            putref_BaseStream: putref_BaseStream::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechCustomStream {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechDataKey, ISpeechDataKey_Vtbl, 0xce17c09b_4efa_44d5_a4c9_59d9585ab0cd);
@@ -8854,52 +12852,189 @@ impl ISpeechDataKey_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for ISpeechDataKey {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(ISpeechFileStream, ISpeechFileStream_Vtbl, 0xaf67f125_ab39_4e93_b4a2_cc2e66e182a7);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for ISpeechFileStream {
-    type Target = ISpeechBaseStream;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(ISpeechFileStream, windows_core::IUnknown, super::super::System::Com::IDispatch, ISpeechBaseStream);
-#[cfg(feature = "Win32_System_Com")]
-impl ISpeechFileStream {
-    pub unsafe fn Open(&self, filename: &windows_core::BSTR, filemode: SpeechStreamFileMode, doevents: super::super::Foundation::VARIANT_BOOL) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Open)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(filename), filemode, doevents).ok() }
-    }
-    pub unsafe fn Close(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Close)(windows_core::Interface::as_raw(self)).ok() }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-#[repr(C)]
-#[doc(hidden)]
-pub struct ISpeechFileStream_Vtbl {
-    pub base__: ISpeechBaseStream_Vtbl,
-    pub Open: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, SpeechStreamFileMode, super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT,
-    pub Close: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-pub trait ISpeechFileStream_Impl: ISpeechBaseStream_Impl {
-    fn Open(&self, filename: &windows_core::BSTR, filemode: SpeechStreamFileMode, doevents: super::super::Foundation::VARIANT_BOOL) -> windows_core::Result<()>;
-    fn Close(&self) -> windows_core::Result<()>;
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl ISpeechFileStream_Vtbl {
-    pub const fn new<Identity: ISpeechFileStream_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn Open<Identity: ISpeechFileStream_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, filename: *mut core::ffi::c_void, filemode: SpeechStreamFileMode, doevents: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                ISpeechFileStream_Impl::Open(this, core::mem::transmute(&filename), core::mem::transmute_copy(&filemode), core::mem::transmute_copy(&doevents)).into()
+impl<Identity: windows_core::IUnknownImpl + ISpeechDataKey_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechDataKey_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetBinaryValue<Identity: windows_core::IUnknownImpl + ISpeechDataKey_Impl>(this: *mut core::ffi::c_void, valuename: *mut core::ffi::c_void, value: super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechDataKey_Impl::SetBinaryValue(this, core::mem::transmute(&valuename), core::mem::transmute(&value)).into()
             }
         }
-        unsafe extern "system" fn Close<Identity: ISpeechFileStream_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetBinaryValue<Identity: windows_core::IUnknownImpl + ISpeechDataKey_Impl>(this: *mut core::ffi::c_void, valuename: *mut core::ffi::c_void, value: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechDataKey_Impl::GetBinaryValue(this, core::mem::transmute(&valuename)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetStringValue<Identity: windows_core::IUnknownImpl + ISpeechDataKey_Impl>(this: *mut core::ffi::c_void, valuename: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechDataKey_Impl::SetStringValue(this, core::mem::transmute(&valuename), core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn GetStringValue<Identity: windows_core::IUnknownImpl + ISpeechDataKey_Impl>(this: *mut core::ffi::c_void, valuename: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechDataKey_Impl::GetStringValue(this, core::mem::transmute(&valuename)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLongValue<Identity: windows_core::IUnknownImpl + ISpeechDataKey_Impl>(this: *mut core::ffi::c_void, valuename: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechDataKey_Impl::SetLongValue(this, core::mem::transmute(&valuename), core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn GetLongValue<Identity: windows_core::IUnknownImpl + ISpeechDataKey_Impl>(this: *mut core::ffi::c_void, valuename: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechDataKey_Impl::GetLongValue(this, core::mem::transmute(&valuename)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OpenKey<Identity: windows_core::IUnknownImpl + ISpeechDataKey_Impl>(this: *mut core::ffi::c_void, subkeyname: *mut core::ffi::c_void, subkey: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechDataKey_Impl::OpenKey(this, core::mem::transmute(&subkeyname)) {
+                    Ok(ok__) => {
+                        subkey.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateKey<Identity: windows_core::IUnknownImpl + ISpeechDataKey_Impl>(this: *mut core::ffi::c_void, subkeyname: *mut core::ffi::c_void, subkey: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechDataKey_Impl::CreateKey(this, core::mem::transmute(&subkeyname)) {
+                    Ok(ok__) => {
+                        subkey.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeleteKey<Identity: windows_core::IUnknownImpl + ISpeechDataKey_Impl>(this: *mut core::ffi::c_void, subkeyname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechDataKey_Impl::DeleteKey(this, core::mem::transmute(&subkeyname)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteValue<Identity: windows_core::IUnknownImpl + ISpeechDataKey_Impl>(this: *mut core::ffi::c_void, valuename: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechDataKey_Impl::DeleteValue(this, core::mem::transmute(&valuename)).into()
+            }
+        }
+        unsafe extern "system" fn EnumKeys<Identity: windows_core::IUnknownImpl + ISpeechDataKey_Impl>(this: *mut core::ffi::c_void, index: i32, subkeyname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechDataKey_Impl::EnumKeys(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        subkeyname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumValues<Identity: windows_core::IUnknownImpl + ISpeechDataKey_Impl>(this: *mut core::ffi::c_void, index: i32, valuename: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechDataKey_Impl::EnumValues(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        valuename.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetBinaryValue: SetBinaryValue::<Identity>,
+            GetBinaryValue: GetBinaryValue::<Identity>,
+            SetStringValue: SetStringValue::<Identity>,
+            GetStringValue: GetStringValue::<Identity>,
+            SetLongValue: SetLongValue::<Identity>,
+            GetLongValue: GetLongValue::<Identity>,
+            OpenKey: OpenKey::<Identity>,
+            CreateKey: CreateKey::<Identity>,
+            DeleteKey: DeleteKey::<Identity>,
+            DeleteValue: DeleteValue::<Identity>,
+            EnumKeys: EnumKeys::<Identity>,
+            EnumValues: EnumValues::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for ISpeechDataKey {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(ISpeechFileStream, ISpeechFileStream_Vtbl, 0xaf67f125_ab39_4e93_b4a2_cc2e66e182a7);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for ISpeechFileStream {
+    type Target = ISpeechBaseStream;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(ISpeechFileStream, windows_core::IUnknown, super::super::System::Com::IDispatch, ISpeechBaseStream);
+#[cfg(feature = "Win32_System_Com")]
+impl ISpeechFileStream {
+    pub unsafe fn Open(&self, filename: &windows_core::BSTR, filemode: SpeechStreamFileMode, doevents: super::super::Foundation::VARIANT_BOOL) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Open)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(filename), filemode, doevents).ok() }
+    }
+    pub unsafe fn Close(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Close)(windows_core::Interface::as_raw(self)).ok() }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+#[repr(C)]
+#[doc(hidden)]
+pub struct ISpeechFileStream_Vtbl {
+    pub base__: ISpeechBaseStream_Vtbl,
+    pub Open: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, SpeechStreamFileMode, super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT,
+    pub Close: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+pub trait ISpeechFileStream_Impl: ISpeechBaseStream_Impl {
+    fn Open(&self, filename: &windows_core::BSTR, filemode: SpeechStreamFileMode, doevents: super::super::Foundation::VARIANT_BOOL) -> windows_core::Result<()>;
+    fn Close(&self) -> windows_core::Result<()>;
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl ISpeechFileStream_Vtbl {
+    pub const fn new<Identity: ISpeechFileStream_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn Open<Identity: ISpeechFileStream_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, filename: *mut core::ffi::c_void, filemode: SpeechStreamFileMode, doevents: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ISpeechFileStream_Impl::Open(this, core::mem::transmute(&filename), core::mem::transmute_copy(&filemode), core::mem::transmute_copy(&doevents)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: ISpeechFileStream_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
                 ISpeechFileStream_Impl::Close(this).into()
             }
         }
@@ -8910,6 +13045,27 @@ impl ISpeechFileStream_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechFileStream_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechFileStream_Vtbl
+where
+    ISpeechBaseStream_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Open<Identity: windows_core::IUnknownImpl + ISpeechFileStream_Impl>(this: *mut core::ffi::c_void, filename: *mut core::ffi::c_void, filemode: SpeechStreamFileMode, doevents: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechFileStream_Impl::Open(this, core::mem::transmute(&filename), core::mem::transmute_copy(&filemode), core::mem::transmute_copy(&doevents)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + ISpeechFileStream_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechFileStream_Impl::Close(this).into()
+            }
+        }
+        Self { base__: <ISpeechBaseStream_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Open: Open::<Identity>, Close: Close::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechFileStream {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechGrammarRule, ISpeechGrammarRule_Vtbl, 0xafe719cf_5dd1_44f2_999c_7a399f1cfccc);
@@ -9075,6 +13231,96 @@ impl ISpeechGrammarRule_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechGrammarRule_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechGrammarRule_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Attributes<Identity: windows_core::IUnknownImpl + ISpeechGrammarRule_Impl>(this: *mut core::ffi::c_void, attributes: *mut SpeechRuleAttributes) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRule_Impl::Attributes(this) {
+                    Ok(ok__) => {
+                        attributes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InitialState<Identity: windows_core::IUnknownImpl + ISpeechGrammarRule_Impl>(this: *mut core::ffi::c_void, state: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRule_Impl::InitialState(this) {
+                    Ok(ok__) => {
+                        state.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + ISpeechGrammarRule_Impl>(this: *mut core::ffi::c_void, name: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRule_Impl::Name(this) {
+                    Ok(ok__) => {
+                        name.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + ISpeechGrammarRule_Impl>(this: *mut core::ffi::c_void, id: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRule_Impl::Id(this) {
+                    Ok(ok__) => {
+                        id.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Clear<Identity: windows_core::IUnknownImpl + ISpeechGrammarRule_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechGrammarRule_Impl::Clear(this).into()
+            }
+        }
+        unsafe extern "system" fn AddResource<Identity: windows_core::IUnknownImpl + ISpeechGrammarRule_Impl>(this: *mut core::ffi::c_void, resourcename: *mut core::ffi::c_void, resourcevalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechGrammarRule_Impl::AddResource(this, core::mem::transmute(&resourcename), core::mem::transmute(&resourcevalue)).into()
+            }
+        }
+        unsafe extern "system" fn AddState<Identity: windows_core::IUnknownImpl + ISpeechGrammarRule_Impl>(this: *mut core::ffi::c_void, state: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRule_Impl::AddState(this) {
+                    Ok(ok__) => {
+                        state.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Attributes: Attributes::<Identity>,
+            InitialState: InitialState::<Identity>,
+            Name: Name::<Identity>,
+            Id: Id::<Identity>,
+            Clear: Clear::<Identity>,
+            AddResource: AddResource::<Identity>,
+            AddState: AddState::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechGrammarRule {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechGrammarRuleState, ISpeechGrammarRuleState_Vtbl, 0xd4286f2c_ee67_45ae_b928_28d695362eda);
@@ -9211,6 +13457,64 @@ impl ISpeechGrammarRuleState_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechGrammarRuleState_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechGrammarRuleState_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Rule<Identity: windows_core::IUnknownImpl + ISpeechGrammarRuleState_Impl>(this: *mut core::ffi::c_void, rule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRuleState_Impl::Rule(this) {
+                    Ok(ok__) => {
+                        rule.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Transitions<Identity: windows_core::IUnknownImpl + ISpeechGrammarRuleState_Impl>(this: *mut core::ffi::c_void, transitions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRuleState_Impl::Transitions(this) {
+                    Ok(ok__) => {
+                        transitions.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddWordTransition<Identity: windows_core::IUnknownImpl + ISpeechGrammarRuleState_Impl>(this: *mut core::ffi::c_void, deststate: *mut core::ffi::c_void, words: *mut core::ffi::c_void, separators: *mut core::ffi::c_void, r#type: SpeechGrammarWordType, propertyname: *mut core::ffi::c_void, propertyid: i32, propertyvalue: *const super::super::System::Variant::VARIANT, weight: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechGrammarRuleState_Impl::AddWordTransition(this, core::mem::transmute_copy(&deststate), core::mem::transmute(&words), core::mem::transmute(&separators), core::mem::transmute_copy(&r#type), core::mem::transmute(&propertyname), core::mem::transmute_copy(&propertyid), core::mem::transmute_copy(&propertyvalue), core::mem::transmute_copy(&weight)).into()
+            }
+        }
+        unsafe extern "system" fn AddRuleTransition<Identity: windows_core::IUnknownImpl + ISpeechGrammarRuleState_Impl>(this: *mut core::ffi::c_void, destinationstate: *mut core::ffi::c_void, rule: *mut core::ffi::c_void, propertyname: *mut core::ffi::c_void, propertyid: i32, propertyvalue: *const super::super::System::Variant::VARIANT, weight: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechGrammarRuleState_Impl::AddRuleTransition(this, core::mem::transmute_copy(&destinationstate), core::mem::transmute_copy(&rule), core::mem::transmute(&propertyname), core::mem::transmute_copy(&propertyid), core::mem::transmute_copy(&propertyvalue), core::mem::transmute_copy(&weight)).into()
+            }
+        }
+        unsafe extern "system" fn AddSpecialTransition<Identity: windows_core::IUnknownImpl + ISpeechGrammarRuleState_Impl>(this: *mut core::ffi::c_void, destinationstate: *mut core::ffi::c_void, r#type: SpeechSpecialTransitionType, propertyname: *mut core::ffi::c_void, propertyid: i32, propertyvalue: *const super::super::System::Variant::VARIANT, weight: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechGrammarRuleState_Impl::AddSpecialTransition(this, core::mem::transmute_copy(&destinationstate), core::mem::transmute_copy(&r#type), core::mem::transmute(&propertyname), core::mem::transmute_copy(&propertyid), core::mem::transmute_copy(&propertyvalue), core::mem::transmute_copy(&weight)).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Rule: Rule::<Identity>,
+            Transitions: Transitions::<Identity>,
+            AddWordTransition: AddWordTransition::<Identity>,
+            AddRuleTransition: AddRuleTransition::<Identity>,
+            AddSpecialTransition: AddSpecialTransition::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechGrammarRuleState {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechGrammarRuleStateTransition, ISpeechGrammarRuleStateTransition_Vtbl, 0xcafd1db1_41d1_4a06_9863_e2e81da17a9a);
@@ -9423,23 +13727,138 @@ impl ISpeechGrammarRuleStateTransition_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for ISpeechGrammarRuleStateTransition {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(ISpeechGrammarRuleStateTransitions, ISpeechGrammarRuleStateTransitions_Vtbl, 0xeabce657_75bc_44a2_aa7f_c56476742963);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for ISpeechGrammarRuleStateTransitions {
-    type Target = super::super::System::Com::IDispatch;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(ISpeechGrammarRuleStateTransitions, windows_core::IUnknown, super::super::System::Com::IDispatch);
-#[cfg(feature = "Win32_System_Com")]
-impl ISpeechGrammarRuleStateTransitions {
-    pub unsafe fn Count(&self) -> windows_core::Result<i32> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
+impl<Identity: windows_core::IUnknownImpl + ISpeechGrammarRuleStateTransition_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechGrammarRuleStateTransition_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Type<Identity: windows_core::IUnknownImpl + ISpeechGrammarRuleStateTransition_Impl>(this: *mut core::ffi::c_void, r#type: *mut SpeechGrammarRuleStateTransitionType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRuleStateTransition_Impl::Type(this) {
+                    Ok(ok__) => {
+                        r#type.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Text<Identity: windows_core::IUnknownImpl + ISpeechGrammarRuleStateTransition_Impl>(this: *mut core::ffi::c_void, text: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRuleStateTransition_Impl::Text(this) {
+                    Ok(ok__) => {
+                        text.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Rule<Identity: windows_core::IUnknownImpl + ISpeechGrammarRuleStateTransition_Impl>(this: *mut core::ffi::c_void, rule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRuleStateTransition_Impl::Rule(this) {
+                    Ok(ok__) => {
+                        rule.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Weight<Identity: windows_core::IUnknownImpl + ISpeechGrammarRuleStateTransition_Impl>(this: *mut core::ffi::c_void, weight: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRuleStateTransition_Impl::Weight(this) {
+                    Ok(ok__) => {
+                        weight.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PropertyName<Identity: windows_core::IUnknownImpl + ISpeechGrammarRuleStateTransition_Impl>(this: *mut core::ffi::c_void, propertyname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRuleStateTransition_Impl::PropertyName(this) {
+                    Ok(ok__) => {
+                        propertyname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PropertyId<Identity: windows_core::IUnknownImpl + ISpeechGrammarRuleStateTransition_Impl>(this: *mut core::ffi::c_void, propertyid: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRuleStateTransition_Impl::PropertyId(this) {
+                    Ok(ok__) => {
+                        propertyid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PropertyValue<Identity: windows_core::IUnknownImpl + ISpeechGrammarRuleStateTransition_Impl>(this: *mut core::ffi::c_void, propertyvalue: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRuleStateTransition_Impl::PropertyValue(this) {
+                    Ok(ok__) => {
+                        propertyvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NextState<Identity: windows_core::IUnknownImpl + ISpeechGrammarRuleStateTransition_Impl>(this: *mut core::ffi::c_void, nextstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRuleStateTransition_Impl::NextState(this) {
+                    Ok(ok__) => {
+                        nextstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Type: Type::<Identity>,
+            Text: Text::<Identity>,
+            Rule: Rule::<Identity>,
+            Weight: Weight::<Identity>,
+            PropertyName: PropertyName::<Identity>,
+            PropertyId: PropertyId::<Identity>,
+            PropertyValue: PropertyValue::<Identity>,
+            NextState: NextState::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for ISpeechGrammarRuleStateTransition {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(ISpeechGrammarRuleStateTransitions, ISpeechGrammarRuleStateTransitions_Vtbl, 0xeabce657_75bc_44a2_aa7f_c56476742963);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for ISpeechGrammarRuleStateTransitions {
+    type Target = super::super::System::Com::IDispatch;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(ISpeechGrammarRuleStateTransitions, windows_core::IUnknown, super::super::System::Com::IDispatch);
+#[cfg(feature = "Win32_System_Com")]
+impl ISpeechGrammarRuleStateTransitions {
+    pub unsafe fn Count(&self) -> windows_core::Result<i32> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
             (windows_core::Interface::vtable(self).Count)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
         }
     }
@@ -9522,6 +13941,56 @@ impl ISpeechGrammarRuleStateTransitions_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechGrammarRuleStateTransitions_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechGrammarRuleStateTransitions_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + ISpeechGrammarRuleStateTransitions_Impl>(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRuleStateTransitions_Impl::Count(this) {
+                    Ok(ok__) => {
+                        count.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Item<Identity: windows_core::IUnknownImpl + ISpeechGrammarRuleStateTransitions_Impl>(this: *mut core::ffi::c_void, index: i32, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRuleStateTransitions_Impl::Item(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        transition.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + ISpeechGrammarRuleStateTransitions_Impl>(this: *mut core::ffi::c_void, enumvariant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRuleStateTransitions_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        enumvariant.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Count: Count::<Identity>,
+            Item: Item::<Identity>,
+            _NewEnum: _NewEnum::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechGrammarRuleStateTransitions {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechGrammarRules, ISpeechGrammarRules_Vtbl, 0x6ffa3b44_fc2d_40d1_8afc_32911c7f1ad1);
@@ -9716,6 +14185,109 @@ impl ISpeechGrammarRules_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechGrammarRules_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechGrammarRules_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + ISpeechGrammarRules_Impl>(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRules_Impl::Count(this) {
+                    Ok(ok__) => {
+                        count.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindRule<Identity: windows_core::IUnknownImpl + ISpeechGrammarRules_Impl>(this: *mut core::ffi::c_void, rulenameorid: super::super::System::Variant::VARIANT, rule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRules_Impl::FindRule(this, core::mem::transmute(&rulenameorid)) {
+                    Ok(ok__) => {
+                        rule.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Item<Identity: windows_core::IUnknownImpl + ISpeechGrammarRules_Impl>(this: *mut core::ffi::c_void, index: i32, rule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRules_Impl::Item(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        rule.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + ISpeechGrammarRules_Impl>(this: *mut core::ffi::c_void, enumvariant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRules_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        enumvariant.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Dynamic<Identity: windows_core::IUnknownImpl + ISpeechGrammarRules_Impl>(this: *mut core::ffi::c_void, dynamic: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRules_Impl::Dynamic(this) {
+                    Ok(ok__) => {
+                        dynamic.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Add<Identity: windows_core::IUnknownImpl + ISpeechGrammarRules_Impl>(this: *mut core::ffi::c_void, rulename: *mut core::ffi::c_void, attributes: SpeechRuleAttributes, ruleid: i32, rule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechGrammarRules_Impl::Add(this, core::mem::transmute(&rulename), core::mem::transmute_copy(&attributes), core::mem::transmute_copy(&ruleid)) {
+                    Ok(ok__) => {
+                        rule.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Commit<Identity: windows_core::IUnknownImpl + ISpeechGrammarRules_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechGrammarRules_Impl::Commit(this).into()
+            }
+        }
+        unsafe extern "system" fn CommitAndSave<Identity: windows_core::IUnknownImpl + ISpeechGrammarRules_Impl>(this: *mut core::ffi::c_void, errortext: *mut *mut core::ffi::c_void, savestream: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechGrammarRules_Impl::CommitAndSave(this, core::mem::transmute_copy(&errortext), core::mem::transmute_copy(&savestream)).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Count: Count::<Identity>,
+            FindRule: FindRule::<Identity>,
+            Item: Item::<Identity>,
+            _NewEnum: _NewEnum::<Identity>,
+            Dynamic: Dynamic::<Identity>,
+            Add: Add::<Identity>,
+            Commit: Commit::<Identity>,
+            CommitAndSave: CommitAndSave::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechGrammarRules {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechLexicon, ISpeechLexicon_Vtbl, 0x3da7627a_c7ae_4b23_8708_638c50362c25);
@@ -9874,6 +14446,85 @@ impl ISpeechLexicon_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechLexicon_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechLexicon_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GenerationId<Identity: windows_core::IUnknownImpl + ISpeechLexicon_Impl>(this: *mut core::ffi::c_void, generationid: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechLexicon_Impl::GenerationId(this) {
+                    Ok(ok__) => {
+                        generationid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetWords<Identity: windows_core::IUnknownImpl + ISpeechLexicon_Impl>(this: *mut core::ffi::c_void, flags: SpeechLexiconType, generationid: *mut i32, words: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechLexicon_Impl::GetWords(this, core::mem::transmute_copy(&flags), core::mem::transmute_copy(&generationid), core::mem::transmute_copy(&words)).into()
+            }
+        }
+        unsafe extern "system" fn AddPronunciation<Identity: windows_core::IUnknownImpl + ISpeechLexicon_Impl>(this: *mut core::ffi::c_void, bstrword: *mut core::ffi::c_void, langid: i32, partofspeech: SpeechPartOfSpeech, bstrpronunciation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechLexicon_Impl::AddPronunciation(this, core::mem::transmute(&bstrword), core::mem::transmute_copy(&langid), core::mem::transmute_copy(&partofspeech), core::mem::transmute(&bstrpronunciation)).into()
+            }
+        }
+        unsafe extern "system" fn AddPronunciationByPhoneIds<Identity: windows_core::IUnknownImpl + ISpeechLexicon_Impl>(this: *mut core::ffi::c_void, bstrword: *mut core::ffi::c_void, langid: i32, partofspeech: SpeechPartOfSpeech, phoneids: *const super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechLexicon_Impl::AddPronunciationByPhoneIds(this, core::mem::transmute(&bstrword), core::mem::transmute_copy(&langid), core::mem::transmute_copy(&partofspeech), core::mem::transmute_copy(&phoneids)).into()
+            }
+        }
+        unsafe extern "system" fn RemovePronunciation<Identity: windows_core::IUnknownImpl + ISpeechLexicon_Impl>(this: *mut core::ffi::c_void, bstrword: *mut core::ffi::c_void, langid: i32, partofspeech: SpeechPartOfSpeech, bstrpronunciation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechLexicon_Impl::RemovePronunciation(this, core::mem::transmute(&bstrword), core::mem::transmute_copy(&langid), core::mem::transmute_copy(&partofspeech), core::mem::transmute(&bstrpronunciation)).into()
+            }
+        }
+        unsafe extern "system" fn RemovePronunciationByPhoneIds<Identity: windows_core::IUnknownImpl + ISpeechLexicon_Impl>(this: *mut core::ffi::c_void, bstrword: *mut core::ffi::c_void, langid: i32, partofspeech: SpeechPartOfSpeech, phoneids: *const super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechLexicon_Impl::RemovePronunciationByPhoneIds(this, core::mem::transmute(&bstrword), core::mem::transmute_copy(&langid), core::mem::transmute_copy(&partofspeech), core::mem::transmute_copy(&phoneids)).into()
+            }
+        }
+        unsafe extern "system" fn GetPronunciations<Identity: windows_core::IUnknownImpl + ISpeechLexicon_Impl>(this: *mut core::ffi::c_void, bstrword: *mut core::ffi::c_void, langid: i32, typeflags: SpeechLexiconType, pppronunciations: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechLexicon_Impl::GetPronunciations(this, core::mem::transmute(&bstrword), core::mem::transmute_copy(&langid), core::mem::transmute_copy(&typeflags)) {
+                    Ok(ok__) => {
+                        pppronunciations.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetGenerationChange<Identity: windows_core::IUnknownImpl + ISpeechLexicon_Impl>(this: *mut core::ffi::c_void, generationid: *mut i32, ppwords: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechLexicon_Impl::GetGenerationChange(this, core::mem::transmute_copy(&generationid), core::mem::transmute_copy(&ppwords)).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GenerationId: GenerationId::<Identity>,
+            GetWords: GetWords::<Identity>,
+            AddPronunciation: AddPronunciation::<Identity>,
+            AddPronunciationByPhoneIds: AddPronunciationByPhoneIds::<Identity>,
+            RemovePronunciation: RemovePronunciation::<Identity>,
+            RemovePronunciationByPhoneIds: RemovePronunciationByPhoneIds::<Identity>,
+            GetPronunciations: GetPronunciations::<Identity>,
+            GetGenerationChange: GetGenerationChange::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechLexicon {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechLexiconPronunciation, ISpeechLexiconPronunciation_Vtbl, 0x95252c5d_9e43_4f4a_9899_48ee73352f9f);
@@ -10019,14 +14670,90 @@ impl ISpeechLexiconPronunciation_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for ISpeechLexiconPronunciation {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(ISpeechLexiconPronunciations, ISpeechLexiconPronunciations_Vtbl, 0x72829128_5682_4704_a0d4_3e2bb6f2ead3);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for ISpeechLexiconPronunciations {
-    type Target = super::super::System::Com::IDispatch;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
+impl<Identity: windows_core::IUnknownImpl + ISpeechLexiconPronunciation_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechLexiconPronunciation_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Type<Identity: windows_core::IUnknownImpl + ISpeechLexiconPronunciation_Impl>(this: *mut core::ffi::c_void, lexicontype: *mut SpeechLexiconType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechLexiconPronunciation_Impl::Type(this) {
+                    Ok(ok__) => {
+                        lexicontype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LangId<Identity: windows_core::IUnknownImpl + ISpeechLexiconPronunciation_Impl>(this: *mut core::ffi::c_void, langid: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechLexiconPronunciation_Impl::LangId(this) {
+                    Ok(ok__) => {
+                        langid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PartOfSpeech<Identity: windows_core::IUnknownImpl + ISpeechLexiconPronunciation_Impl>(this: *mut core::ffi::c_void, partofspeech: *mut SpeechPartOfSpeech) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechLexiconPronunciation_Impl::PartOfSpeech(this) {
+                    Ok(ok__) => {
+                        partofspeech.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PhoneIds<Identity: windows_core::IUnknownImpl + ISpeechLexiconPronunciation_Impl>(this: *mut core::ffi::c_void, phoneids: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechLexiconPronunciation_Impl::PhoneIds(this) {
+                    Ok(ok__) => {
+                        phoneids.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Symbolic<Identity: windows_core::IUnknownImpl + ISpeechLexiconPronunciation_Impl>(this: *mut core::ffi::c_void, symbolic: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechLexiconPronunciation_Impl::Symbolic(this) {
+                    Ok(ok__) => {
+                        symbolic.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Type: Type::<Identity>,
+            LangId: LangId::<Identity>,
+            PartOfSpeech: PartOfSpeech::<Identity>,
+            PhoneIds: PhoneIds::<Identity>,
+            Symbolic: Symbolic::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for ISpeechLexiconPronunciation {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(ISpeechLexiconPronunciations, ISpeechLexiconPronunciations_Vtbl, 0x72829128_5682_4704_a0d4_3e2bb6f2ead3);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for ISpeechLexiconPronunciations {
+    type Target = super::super::System::Com::IDispatch;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
     }
 }
 #[cfg(feature = "Win32_System_Com")]
@@ -10118,6 +14845,56 @@ impl ISpeechLexiconPronunciations_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechLexiconPronunciations_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechLexiconPronunciations_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + ISpeechLexiconPronunciations_Impl>(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechLexiconPronunciations_Impl::Count(this) {
+                    Ok(ok__) => {
+                        count.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Item<Identity: windows_core::IUnknownImpl + ISpeechLexiconPronunciations_Impl>(this: *mut core::ffi::c_void, index: i32, pronunciation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechLexiconPronunciations_Impl::Item(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        pronunciation.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + ISpeechLexiconPronunciations_Impl>(this: *mut core::ffi::c_void, enumvariant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechLexiconPronunciations_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        enumvariant.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Count: Count::<Identity>,
+            Item: Item::<Identity>,
+            _NewEnum: _NewEnum::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechLexiconPronunciations {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechLexiconWord, ISpeechLexiconWord_Vtbl, 0x4e5b933c_c9be_48ed_8842_1ee51bb1d4ff);
@@ -10238,6 +15015,69 @@ impl ISpeechLexiconWord_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechLexiconWord_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechLexiconWord_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn LangId<Identity: windows_core::IUnknownImpl + ISpeechLexiconWord_Impl>(this: *mut core::ffi::c_void, langid: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechLexiconWord_Impl::LangId(this) {
+                    Ok(ok__) => {
+                        langid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Type<Identity: windows_core::IUnknownImpl + ISpeechLexiconWord_Impl>(this: *mut core::ffi::c_void, wordtype: *mut SpeechWordType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechLexiconWord_Impl::Type(this) {
+                    Ok(ok__) => {
+                        wordtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Word<Identity: windows_core::IUnknownImpl + ISpeechLexiconWord_Impl>(this: *mut core::ffi::c_void, word: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechLexiconWord_Impl::Word(this) {
+                    Ok(ok__) => {
+                        word.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Pronunciations<Identity: windows_core::IUnknownImpl + ISpeechLexiconWord_Impl>(this: *mut core::ffi::c_void, pronunciations: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechLexiconWord_Impl::Pronunciations(this) {
+                    Ok(ok__) => {
+                        pronunciations.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            LangId: LangId::<Identity>,
+            Type: Type::<Identity>,
+            Word: Word::<Identity>,
+            Pronunciations: Pronunciations::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechLexiconWord {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechLexiconWords, ISpeechLexiconWords_Vtbl, 0x8d199862_415e_47d5_ac4f_faa608b424e6);
@@ -10337,6 +15177,56 @@ impl ISpeechLexiconWords_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechLexiconWords_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechLexiconWords_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + ISpeechLexiconWords_Impl>(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechLexiconWords_Impl::Count(this) {
+                    Ok(ok__) => {
+                        count.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Item<Identity: windows_core::IUnknownImpl + ISpeechLexiconWords_Impl>(this: *mut core::ffi::c_void, index: i32, word: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechLexiconWords_Impl::Item(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        word.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + ISpeechLexiconWords_Impl>(this: *mut core::ffi::c_void, enumvariant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechLexiconWords_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        enumvariant.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Count: Count::<Identity>,
+            Item: Item::<Identity>,
+            _NewEnum: _NewEnum::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechLexiconWords {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechMMSysAudio, ISpeechMMSysAudio_Vtbl, 0x3c76af6d_1fd7_4831_81d1_3b71d5a13c44);
@@ -10460,6 +15350,70 @@ impl ISpeechMMSysAudio_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechMMSysAudio_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechMMSysAudio_Vtbl
+where
+    ISpeechAudio_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DeviceId<Identity: windows_core::IUnknownImpl + ISpeechMMSysAudio_Impl>(this: *mut core::ffi::c_void, deviceid: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechMMSysAudio_Impl::DeviceId(this) {
+                    Ok(ok__) => {
+                        deviceid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDeviceId<Identity: windows_core::IUnknownImpl + ISpeechMMSysAudio_Impl>(this: *mut core::ffi::c_void, deviceid: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechMMSysAudio_Impl::SetDeviceId(this, core::mem::transmute_copy(&deviceid)).into()
+            }
+        }
+        unsafe extern "system" fn LineId<Identity: windows_core::IUnknownImpl + ISpeechMMSysAudio_Impl>(this: *mut core::ffi::c_void, lineid: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechMMSysAudio_Impl::LineId(this) {
+                    Ok(ok__) => {
+                        lineid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLineId<Identity: windows_core::IUnknownImpl + ISpeechMMSysAudio_Impl>(this: *mut core::ffi::c_void, lineid: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechMMSysAudio_Impl::SetLineId(this, core::mem::transmute_copy(&lineid)).into()
+            }
+        }
+        unsafe extern "system" fn MMHandle<Identity: windows_core::IUnknownImpl + ISpeechMMSysAudio_Impl>(this: *mut core::ffi::c_void, handle: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechMMSysAudio_Impl::MMHandle(this) {
+                    Ok(ok__) => {
+                        handle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ISpeechAudio_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DeviceId: DeviceId::<Identity>,
+            SetDeviceId: SetDeviceId::<Identity>,
+            LineId: LineId::<Identity>,
+            SetLineId: SetLineId::<Identity>,
+            MMHandle: MMHandle::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechMMSysAudio {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechMemoryStream, ISpeechMemoryStream_Vtbl, 0xeeb14b68_808b_4abe_a5ea_b51da7588008);
@@ -10533,6 +15487,37 @@ impl ISpeechMemoryStream_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechMemoryStream_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechMemoryStream_Vtbl
+where
+    ISpeechBaseStream_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetData<Identity: windows_core::IUnknownImpl + ISpeechMemoryStream_Impl>(this: *mut core::ffi::c_void, data: super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechMemoryStream_Impl::SetData(this, core::mem::transmute(&data)).into()
+            }
+        }
+        unsafe extern "system" fn GetData<Identity: windows_core::IUnknownImpl + ISpeechMemoryStream_Impl>(this: *mut core::ffi::c_void, pdata: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechMemoryStream_Impl::GetData(this) {
+                    Ok(ok__) => {
+                        pdata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ISpeechBaseStream_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetData: SetData::<Identity>,
+            GetData: GetData::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechMemoryStream {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechObjectToken, ISpeechObjectToken_Vtbl, 0xc74a3adc_b727_4500_a84a_b526721c8b8c);
@@ -10823,27 +15808,183 @@ impl ISpeechObjectToken_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for ISpeechObjectToken {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(ISpeechObjectTokenCategory, ISpeechObjectTokenCategory_Vtbl, 0xca7eac50_2d01_4145_86d4_5ae7d70f4469);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for ISpeechObjectTokenCategory {
-    type Target = super::super::System::Com::IDispatch;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(ISpeechObjectTokenCategory, windows_core::IUnknown, super::super::System::Com::IDispatch);
-#[cfg(feature = "Win32_System_Com")]
-impl ISpeechObjectTokenCategory {
-    pub unsafe fn Id(&self) -> windows_core::Result<windows_core::BSTR> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).Id)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+impl<Identity: windows_core::IUnknownImpl + ISpeechObjectToken_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechObjectToken_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + ISpeechObjectToken_Impl>(this: *mut core::ffi::c_void, objectid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechObjectToken_Impl::Id(this) {
+                    Ok(ok__) => {
+                        objectid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn SetDefault(&self, tokenid: &windows_core::BSTR) -> windows_core::Result<()> {
+        unsafe extern "system" fn DataKey<Identity: windows_core::IUnknownImpl + ISpeechObjectToken_Impl>(this: *mut core::ffi::c_void, datakey: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechObjectToken_Impl::DataKey(this) {
+                    Ok(ok__) => {
+                        datakey.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Category<Identity: windows_core::IUnknownImpl + ISpeechObjectToken_Impl>(this: *mut core::ffi::c_void, category: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechObjectToken_Impl::Category(this) {
+                    Ok(ok__) => {
+                        category.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDescription<Identity: windows_core::IUnknownImpl + ISpeechObjectToken_Impl>(this: *mut core::ffi::c_void, locale: i32, description: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechObjectToken_Impl::GetDescription(this, core::mem::transmute_copy(&locale)) {
+                    Ok(ok__) => {
+                        description.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetId<Identity: windows_core::IUnknownImpl + ISpeechObjectToken_Impl>(this: *mut core::ffi::c_void, id: *mut core::ffi::c_void, categoryid: *mut core::ffi::c_void, createifnotexist: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechObjectToken_Impl::SetId(this, core::mem::transmute(&id), core::mem::transmute(&categoryid), core::mem::transmute_copy(&createifnotexist)).into()
+            }
+        }
+        unsafe extern "system" fn GetAttribute<Identity: windows_core::IUnknownImpl + ISpeechObjectToken_Impl>(this: *mut core::ffi::c_void, attributename: *mut core::ffi::c_void, attributevalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechObjectToken_Impl::GetAttribute(this, core::mem::transmute(&attributename)) {
+                    Ok(ok__) => {
+                        attributevalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateInstance<Identity: windows_core::IUnknownImpl + ISpeechObjectToken_Impl>(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, clscontext: SpeechTokenContext, object: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechObjectToken_Impl::CreateInstance(this, core::mem::transmute_copy(&punkouter), core::mem::transmute_copy(&clscontext)) {
+                    Ok(ok__) => {
+                        object.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Remove<Identity: windows_core::IUnknownImpl + ISpeechObjectToken_Impl>(this: *mut core::ffi::c_void, objectstorageclsid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechObjectToken_Impl::Remove(this, core::mem::transmute(&objectstorageclsid)).into()
+            }
+        }
+        unsafe extern "system" fn GetStorageFileName<Identity: windows_core::IUnknownImpl + ISpeechObjectToken_Impl>(this: *mut core::ffi::c_void, objectstorageclsid: *mut core::ffi::c_void, keyname: *mut core::ffi::c_void, filename: *mut core::ffi::c_void, folder: SpeechTokenShellFolder, filepath: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechObjectToken_Impl::GetStorageFileName(this, core::mem::transmute(&objectstorageclsid), core::mem::transmute(&keyname), core::mem::transmute(&filename), core::mem::transmute_copy(&folder)) {
+                    Ok(ok__) => {
+                        filepath.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveStorageFileName<Identity: windows_core::IUnknownImpl + ISpeechObjectToken_Impl>(this: *mut core::ffi::c_void, objectstorageclsid: *mut core::ffi::c_void, keyname: *mut core::ffi::c_void, deletefile: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechObjectToken_Impl::RemoveStorageFileName(this, core::mem::transmute(&objectstorageclsid), core::mem::transmute(&keyname), core::mem::transmute_copy(&deletefile)).into()
+            }
+        }
+        unsafe extern "system" fn IsUISupported<Identity: windows_core::IUnknownImpl + ISpeechObjectToken_Impl>(this: *mut core::ffi::c_void, typeofui: *mut core::ffi::c_void, extradata: *const super::super::System::Variant::VARIANT, object: *mut core::ffi::c_void, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechObjectToken_Impl::IsUISupported(this, core::mem::transmute(&typeofui), core::mem::transmute_copy(&extradata), core::mem::transmute_copy(&object)) {
+                    Ok(ok__) => {
+                        supported.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DisplayUI<Identity: windows_core::IUnknownImpl + ISpeechObjectToken_Impl>(this: *mut core::ffi::c_void, hwnd: i32, title: *mut core::ffi::c_void, typeofui: *mut core::ffi::c_void, extradata: *const super::super::System::Variant::VARIANT, object: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechObjectToken_Impl::DisplayUI(this, core::mem::transmute_copy(&hwnd), core::mem::transmute(&title), core::mem::transmute(&typeofui), core::mem::transmute_copy(&extradata), core::mem::transmute_copy(&object)).into()
+            }
+        }
+        unsafe extern "system" fn MatchesAttributes<Identity: windows_core::IUnknownImpl + ISpeechObjectToken_Impl>(this: *mut core::ffi::c_void, attributes: *mut core::ffi::c_void, matches: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechObjectToken_Impl::MatchesAttributes(this, core::mem::transmute(&attributes)) {
+                    Ok(ok__) => {
+                        matches.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Id: Id::<Identity>,
+            DataKey: DataKey::<Identity>,
+            Category: Category::<Identity>,
+            GetDescription: GetDescription::<Identity>,
+            SetId: SetId::<Identity>,
+            GetAttribute: GetAttribute::<Identity>,
+            CreateInstance: CreateInstance::<Identity>,
+            Remove: Remove::<Identity>,
+            GetStorageFileName: GetStorageFileName::<Identity>,
+            RemoveStorageFileName: RemoveStorageFileName::<Identity>,
+            IsUISupported: IsUISupported::<Identity>,
+            DisplayUI: DisplayUI::<Identity>,
+            MatchesAttributes: MatchesAttributes::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for ISpeechObjectToken {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(ISpeechObjectTokenCategory, ISpeechObjectTokenCategory_Vtbl, 0xca7eac50_2d01_4145_86d4_5ae7d70f4469);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for ISpeechObjectTokenCategory {
+    type Target = super::super::System::Com::IDispatch;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(ISpeechObjectTokenCategory, windows_core::IUnknown, super::super::System::Com::IDispatch);
+#[cfg(feature = "Win32_System_Com")]
+impl ISpeechObjectTokenCategory {
+    pub unsafe fn Id(&self) -> windows_core::Result<windows_core::BSTR> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).Id)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        }
+    }
+    pub unsafe fn SetDefault(&self, tokenid: &windows_core::BSTR) -> windows_core::Result<()> {
         unsafe { (windows_core::Interface::vtable(self).SetDefault)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(tokenid)).ok() }
     }
     pub unsafe fn Default(&self) -> windows_core::Result<windows_core::BSTR> {
@@ -10967,6 +16108,83 @@ impl ISpeechObjectTokenCategory_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechObjectTokenCategory_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechObjectTokenCategory_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + ISpeechObjectTokenCategory_Impl>(this: *mut core::ffi::c_void, id: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechObjectTokenCategory_Impl::Id(this) {
+                    Ok(ok__) => {
+                        id.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDefault<Identity: windows_core::IUnknownImpl + ISpeechObjectTokenCategory_Impl>(this: *mut core::ffi::c_void, tokenid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechObjectTokenCategory_Impl::SetDefault(this, core::mem::transmute(&tokenid)).into()
+            }
+        }
+        unsafe extern "system" fn Default<Identity: windows_core::IUnknownImpl + ISpeechObjectTokenCategory_Impl>(this: *mut core::ffi::c_void, tokenid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechObjectTokenCategory_Impl::Default(this) {
+                    Ok(ok__) => {
+                        tokenid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetId<Identity: windows_core::IUnknownImpl + ISpeechObjectTokenCategory_Impl>(this: *mut core::ffi::c_void, id: *mut core::ffi::c_void, createifnotexist: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechObjectTokenCategory_Impl::SetId(this, core::mem::transmute(&id), core::mem::transmute_copy(&createifnotexist)).into()
+            }
+        }
+        unsafe extern "system" fn GetDataKey<Identity: windows_core::IUnknownImpl + ISpeechObjectTokenCategory_Impl>(this: *mut core::ffi::c_void, location: SpeechDataKeyLocation, datakey: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechObjectTokenCategory_Impl::GetDataKey(this, core::mem::transmute_copy(&location)) {
+                    Ok(ok__) => {
+                        datakey.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumerateTokens<Identity: windows_core::IUnknownImpl + ISpeechObjectTokenCategory_Impl>(this: *mut core::ffi::c_void, requiredattributes: *mut core::ffi::c_void, optionalattributes: *mut core::ffi::c_void, tokens: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechObjectTokenCategory_Impl::EnumerateTokens(this, core::mem::transmute(&requiredattributes), core::mem::transmute(&optionalattributes)) {
+                    Ok(ok__) => {
+                        tokens.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Id: Id::<Identity>,
+            SetDefault: SetDefault::<Identity>,
+            Default: Default::<Identity>,
+            SetId: SetId::<Identity>,
+            GetDataKey: GetDataKey::<Identity>,
+            EnumerateTokens: EnumerateTokens::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechObjectTokenCategory {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechObjectTokens, ISpeechObjectTokens_Vtbl, 0x9285b776_2e7b_4bc0_b53e_580eb6fa967f);
@@ -11066,6 +16284,56 @@ impl ISpeechObjectTokens_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechObjectTokens_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechObjectTokens_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + ISpeechObjectTokens_Impl>(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechObjectTokens_Impl::Count(this) {
+                    Ok(ok__) => {
+                        count.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Item<Identity: windows_core::IUnknownImpl + ISpeechObjectTokens_Impl>(this: *mut core::ffi::c_void, index: i32, token: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechObjectTokens_Impl::Item(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        token.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + ISpeechObjectTokens_Impl>(this: *mut core::ffi::c_void, ppenumvariant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechObjectTokens_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        ppenumvariant.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Count: Count::<Identity>,
+            Item: Item::<Identity>,
+            _NewEnum: _NewEnum::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechObjectTokens {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechPhoneConverter, ISpeechPhoneConverter_Vtbl, 0xc3e4f353_433f_43d6_89a1_6a62a7054c3d);
@@ -11185,6 +16453,63 @@ impl ISpeechPhoneConverter_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechPhoneConverter_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechPhoneConverter_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn LanguageId<Identity: windows_core::IUnknownImpl + ISpeechPhoneConverter_Impl>(this: *mut core::ffi::c_void, languageid: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhoneConverter_Impl::LanguageId(this) {
+                    Ok(ok__) => {
+                        languageid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLanguageId<Identity: windows_core::IUnknownImpl + ISpeechPhoneConverter_Impl>(this: *mut core::ffi::c_void, languageid: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechPhoneConverter_Impl::SetLanguageId(this, core::mem::transmute_copy(&languageid)).into()
+            }
+        }
+        unsafe extern "system" fn PhoneToId<Identity: windows_core::IUnknownImpl + ISpeechPhoneConverter_Impl>(this: *mut core::ffi::c_void, phonemes: *mut core::ffi::c_void, idarray: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhoneConverter_Impl::PhoneToId(this, core::mem::transmute(&phonemes)) {
+                    Ok(ok__) => {
+                        idarray.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IdToPhone<Identity: windows_core::IUnknownImpl + ISpeechPhoneConverter_Impl>(this: *mut core::ffi::c_void, idarray: super::super::System::Variant::VARIANT, phonemes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhoneConverter_Impl::IdToPhone(this, core::mem::transmute(&idarray)) {
+                    Ok(ok__) => {
+                        phonemes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            LanguageId: LanguageId::<Identity>,
+            SetLanguageId: SetLanguageId::<Identity>,
+            PhoneToId: PhoneToId::<Identity>,
+            IdToPhone: IdToPhone::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechPhoneConverter {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechPhraseAlternate, ISpeechPhraseAlternate_Vtbl, 0x27864a2a_2b9f_4cb8_92d3_0d2722fd1e73);
@@ -11317,21 +16642,91 @@ impl ISpeechPhraseAlternate_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for ISpeechPhraseAlternate {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(ISpeechPhraseAlternates, ISpeechPhraseAlternates_Vtbl, 0xb238b6d5_f276_4c3d_a6c1_2974801c3cc2);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for ISpeechPhraseAlternates {
-    type Target = super::super::System::Com::IDispatch;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(ISpeechPhraseAlternates, windows_core::IUnknown, super::super::System::Com::IDispatch);
-#[cfg(feature = "Win32_System_Com")]
-impl ISpeechPhraseAlternates {
-    pub unsafe fn Count(&self) -> windows_core::Result<i32> {
+impl<Identity: windows_core::IUnknownImpl + ISpeechPhraseAlternate_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechPhraseAlternate_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RecoResult<Identity: windows_core::IUnknownImpl + ISpeechPhraseAlternate_Impl>(this: *mut core::ffi::c_void, recoresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseAlternate_Impl::RecoResult(this) {
+                    Ok(ok__) => {
+                        recoresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StartElementInResult<Identity: windows_core::IUnknownImpl + ISpeechPhraseAlternate_Impl>(this: *mut core::ffi::c_void, startelement: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseAlternate_Impl::StartElementInResult(this) {
+                    Ok(ok__) => {
+                        startelement.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NumberOfElementsInResult<Identity: windows_core::IUnknownImpl + ISpeechPhraseAlternate_Impl>(this: *mut core::ffi::c_void, numberofelements: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseAlternate_Impl::NumberOfElementsInResult(this) {
+                    Ok(ok__) => {
+                        numberofelements.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PhraseInfo<Identity: windows_core::IUnknownImpl + ISpeechPhraseAlternate_Impl>(this: *mut core::ffi::c_void, phraseinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseAlternate_Impl::PhraseInfo(this) {
+                    Ok(ok__) => {
+                        phraseinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Commit<Identity: windows_core::IUnknownImpl + ISpeechPhraseAlternate_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechPhraseAlternate_Impl::Commit(this).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RecoResult: RecoResult::<Identity>,
+            StartElementInResult: StartElementInResult::<Identity>,
+            NumberOfElementsInResult: NumberOfElementsInResult::<Identity>,
+            PhraseInfo: PhraseInfo::<Identity>,
+            Commit: Commit::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for ISpeechPhraseAlternate {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(ISpeechPhraseAlternates, ISpeechPhraseAlternates_Vtbl, 0xb238b6d5_f276_4c3d_a6c1_2974801c3cc2);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for ISpeechPhraseAlternates {
+    type Target = super::super::System::Com::IDispatch;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(ISpeechPhraseAlternates, windows_core::IUnknown, super::super::System::Com::IDispatch);
+#[cfg(feature = "Win32_System_Com")]
+impl ISpeechPhraseAlternates {
+    pub unsafe fn Count(&self) -> windows_core::Result<i32> {
         unsafe {
             let mut result__ = core::mem::zeroed();
             (windows_core::Interface::vtable(self).Count)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
@@ -11416,6 +16811,56 @@ impl ISpeechPhraseAlternates_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechPhraseAlternates_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechPhraseAlternates_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + ISpeechPhraseAlternates_Impl>(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseAlternates_Impl::Count(this) {
+                    Ok(ok__) => {
+                        count.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Item<Identity: windows_core::IUnknownImpl + ISpeechPhraseAlternates_Impl>(this: *mut core::ffi::c_void, index: i32, phrasealternate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseAlternates_Impl::Item(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        phrasealternate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + ISpeechPhraseAlternates_Impl>(this: *mut core::ffi::c_void, enumvariant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseAlternates_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        enumvariant.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Count: Count::<Identity>,
+            Item: Item::<Identity>,
+            _NewEnum: _NewEnum::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechPhraseAlternates {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechPhraseElement, ISpeechPhraseElement_Vtbl, 0xe6176f96_e373_4801_b223_3b62c068c0b4);
@@ -11729,6 +17174,186 @@ impl ISpeechPhraseElement_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechPhraseElement_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechPhraseElement_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AudioTimeOffset<Identity: windows_core::IUnknownImpl + ISpeechPhraseElement_Impl>(this: *mut core::ffi::c_void, audiotimeoffset: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseElement_Impl::AudioTimeOffset(this) {
+                    Ok(ok__) => {
+                        audiotimeoffset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AudioSizeTime<Identity: windows_core::IUnknownImpl + ISpeechPhraseElement_Impl>(this: *mut core::ffi::c_void, audiosizetime: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseElement_Impl::AudioSizeTime(this) {
+                    Ok(ok__) => {
+                        audiosizetime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AudioStreamOffset<Identity: windows_core::IUnknownImpl + ISpeechPhraseElement_Impl>(this: *mut core::ffi::c_void, audiostreamoffset: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseElement_Impl::AudioStreamOffset(this) {
+                    Ok(ok__) => {
+                        audiostreamoffset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AudioSizeBytes<Identity: windows_core::IUnknownImpl + ISpeechPhraseElement_Impl>(this: *mut core::ffi::c_void, audiosizebytes: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseElement_Impl::AudioSizeBytes(this) {
+                    Ok(ok__) => {
+                        audiosizebytes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RetainedStreamOffset<Identity: windows_core::IUnknownImpl + ISpeechPhraseElement_Impl>(this: *mut core::ffi::c_void, retainedstreamoffset: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseElement_Impl::RetainedStreamOffset(this) {
+                    Ok(ok__) => {
+                        retainedstreamoffset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RetainedSizeBytes<Identity: windows_core::IUnknownImpl + ISpeechPhraseElement_Impl>(this: *mut core::ffi::c_void, retainedsizebytes: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseElement_Impl::RetainedSizeBytes(this) {
+                    Ok(ok__) => {
+                        retainedsizebytes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DisplayText<Identity: windows_core::IUnknownImpl + ISpeechPhraseElement_Impl>(this: *mut core::ffi::c_void, displaytext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseElement_Impl::DisplayText(this) {
+                    Ok(ok__) => {
+                        displaytext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LexicalForm<Identity: windows_core::IUnknownImpl + ISpeechPhraseElement_Impl>(this: *mut core::ffi::c_void, lexicalform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseElement_Impl::LexicalForm(this) {
+                    Ok(ok__) => {
+                        lexicalform.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Pronunciation<Identity: windows_core::IUnknownImpl + ISpeechPhraseElement_Impl>(this: *mut core::ffi::c_void, pronunciation: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseElement_Impl::Pronunciation(this) {
+                    Ok(ok__) => {
+                        pronunciation.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DisplayAttributes<Identity: windows_core::IUnknownImpl + ISpeechPhraseElement_Impl>(this: *mut core::ffi::c_void, displayattributes: *mut SpeechDisplayAttributes) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseElement_Impl::DisplayAttributes(this) {
+                    Ok(ok__) => {
+                        displayattributes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RequiredConfidence<Identity: windows_core::IUnknownImpl + ISpeechPhraseElement_Impl>(this: *mut core::ffi::c_void, requiredconfidence: *mut SpeechEngineConfidence) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseElement_Impl::RequiredConfidence(this) {
+                    Ok(ok__) => {
+                        requiredconfidence.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ActualConfidence<Identity: windows_core::IUnknownImpl + ISpeechPhraseElement_Impl>(this: *mut core::ffi::c_void, actualconfidence: *mut SpeechEngineConfidence) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseElement_Impl::ActualConfidence(this) {
+                    Ok(ok__) => {
+                        actualconfidence.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EngineConfidence<Identity: windows_core::IUnknownImpl + ISpeechPhraseElement_Impl>(this: *mut core::ffi::c_void, engineconfidence: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseElement_Impl::EngineConfidence(this) {
+                    Ok(ok__) => {
+                        engineconfidence.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AudioTimeOffset: AudioTimeOffset::<Identity>,
+            AudioSizeTime: AudioSizeTime::<Identity>,
+            AudioStreamOffset: AudioStreamOffset::<Identity>,
+            AudioSizeBytes: AudioSizeBytes::<Identity>,
+            RetainedStreamOffset: RetainedStreamOffset::<Identity>,
+            RetainedSizeBytes: RetainedSizeBytes::<Identity>,
+            DisplayText: DisplayText::<Identity>,
+            LexicalForm: LexicalForm::<Identity>,
+            Pronunciation: Pronunciation::<Identity>,
+            DisplayAttributes: DisplayAttributes::<Identity>,
+            RequiredConfidence: RequiredConfidence::<Identity>,
+            ActualConfidence: ActualConfidence::<Identity>,
+            EngineConfidence: EngineConfidence::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechPhraseElement {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechPhraseElements, ISpeechPhraseElements_Vtbl, 0x0626b328_3478_467d_a0b3_d0853b93dda3);
@@ -11828,28 +17453,78 @@ impl ISpeechPhraseElements_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for ISpeechPhraseElements {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(ISpeechPhraseInfo, ISpeechPhraseInfo_Vtbl, 0x961559cf_4e67_4662_8bf0_d93f1fcd61b3);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for ISpeechPhraseInfo {
-    type Target = super::super::System::Com::IDispatch;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(ISpeechPhraseInfo, windows_core::IUnknown, super::super::System::Com::IDispatch);
-#[cfg(feature = "Win32_System_Com")]
-impl ISpeechPhraseInfo {
-    pub unsafe fn LanguageId(&self) -> windows_core::Result<i32> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).LanguageId)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+impl<Identity: windows_core::IUnknownImpl + ISpeechPhraseElements_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechPhraseElements_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + ISpeechPhraseElements_Impl>(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseElements_Impl::Count(this) {
+                    Ok(ok__) => {
+                        count.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub unsafe fn GrammarId(&self) -> windows_core::Result<super::super::System::Variant::VARIANT> {
+        unsafe extern "system" fn Item<Identity: windows_core::IUnknownImpl + ISpeechPhraseElements_Impl>(this: *mut core::ffi::c_void, index: i32, element: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseElements_Impl::Item(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        element.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + ISpeechPhraseElements_Impl>(this: *mut core::ffi::c_void, enumvariant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseElements_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        enumvariant.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Count: Count::<Identity>,
+            Item: Item::<Identity>,
+            _NewEnum: _NewEnum::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for ISpeechPhraseElements {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(ISpeechPhraseInfo, ISpeechPhraseInfo_Vtbl, 0x961559cf_4e67_4662_8bf0_d93f1fcd61b3);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for ISpeechPhraseInfo {
+    type Target = super::super::System::Com::IDispatch;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(ISpeechPhraseInfo, windows_core::IUnknown, super::super::System::Com::IDispatch);
+#[cfg(feature = "Win32_System_Com")]
+impl ISpeechPhraseInfo {
+    pub unsafe fn LanguageId(&self) -> windows_core::Result<i32> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).LanguageId)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub unsafe fn GrammarId(&self) -> windows_core::Result<super::super::System::Variant::VARIANT> {
         unsafe {
             let mut result__ = core::mem::zeroed();
             (windows_core::Interface::vtable(self).GrammarId)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
@@ -12220,6 +17895,225 @@ impl ISpeechPhraseInfo_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechPhraseInfo_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechPhraseInfo_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn LanguageId<Identity: windows_core::IUnknownImpl + ISpeechPhraseInfo_Impl>(this: *mut core::ffi::c_void, languageid: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseInfo_Impl::LanguageId(this) {
+                    Ok(ok__) => {
+                        languageid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GrammarId<Identity: windows_core::IUnknownImpl + ISpeechPhraseInfo_Impl>(this: *mut core::ffi::c_void, grammarid: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseInfo_Impl::GrammarId(this) {
+                    Ok(ok__) => {
+                        grammarid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StartTime<Identity: windows_core::IUnknownImpl + ISpeechPhraseInfo_Impl>(this: *mut core::ffi::c_void, starttime: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseInfo_Impl::StartTime(this) {
+                    Ok(ok__) => {
+                        starttime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AudioStreamPosition<Identity: windows_core::IUnknownImpl + ISpeechPhraseInfo_Impl>(this: *mut core::ffi::c_void, audiostreamposition: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseInfo_Impl::AudioStreamPosition(this) {
+                    Ok(ok__) => {
+                        audiostreamposition.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AudioSizeBytes<Identity: windows_core::IUnknownImpl + ISpeechPhraseInfo_Impl>(this: *mut core::ffi::c_void, paudiosizebytes: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseInfo_Impl::AudioSizeBytes(this) {
+                    Ok(ok__) => {
+                        paudiosizebytes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RetainedSizeBytes<Identity: windows_core::IUnknownImpl + ISpeechPhraseInfo_Impl>(this: *mut core::ffi::c_void, retainedsizebytes: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseInfo_Impl::RetainedSizeBytes(this) {
+                    Ok(ok__) => {
+                        retainedsizebytes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AudioSizeTime<Identity: windows_core::IUnknownImpl + ISpeechPhraseInfo_Impl>(this: *mut core::ffi::c_void, audiosizetime: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseInfo_Impl::AudioSizeTime(this) {
+                    Ok(ok__) => {
+                        audiosizetime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Rule<Identity: windows_core::IUnknownImpl + ISpeechPhraseInfo_Impl>(this: *mut core::ffi::c_void, rule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseInfo_Impl::Rule(this) {
+                    Ok(ok__) => {
+                        rule.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Properties<Identity: windows_core::IUnknownImpl + ISpeechPhraseInfo_Impl>(this: *mut core::ffi::c_void, properties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseInfo_Impl::Properties(this) {
+                    Ok(ok__) => {
+                        properties.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Elements<Identity: windows_core::IUnknownImpl + ISpeechPhraseInfo_Impl>(this: *mut core::ffi::c_void, elements: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseInfo_Impl::Elements(this) {
+                    Ok(ok__) => {
+                        elements.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Replacements<Identity: windows_core::IUnknownImpl + ISpeechPhraseInfo_Impl>(this: *mut core::ffi::c_void, replacements: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseInfo_Impl::Replacements(this) {
+                    Ok(ok__) => {
+                        replacements.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EngineId<Identity: windows_core::IUnknownImpl + ISpeechPhraseInfo_Impl>(this: *mut core::ffi::c_void, engineidguid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseInfo_Impl::EngineId(this) {
+                    Ok(ok__) => {
+                        engineidguid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnginePrivateData<Identity: windows_core::IUnknownImpl + ISpeechPhraseInfo_Impl>(this: *mut core::ffi::c_void, privatedata: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseInfo_Impl::EnginePrivateData(this) {
+                    Ok(ok__) => {
+                        privatedata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SaveToMemory<Identity: windows_core::IUnknownImpl + ISpeechPhraseInfo_Impl>(this: *mut core::ffi::c_void, phraseblock: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseInfo_Impl::SaveToMemory(this) {
+                    Ok(ok__) => {
+                        phraseblock.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetText<Identity: windows_core::IUnknownImpl + ISpeechPhraseInfo_Impl>(this: *mut core::ffi::c_void, startelement: i32, elements: i32, usereplacements: super::super::Foundation::VARIANT_BOOL, text: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseInfo_Impl::GetText(this, core::mem::transmute_copy(&startelement), core::mem::transmute_copy(&elements), core::mem::transmute_copy(&usereplacements)) {
+                    Ok(ok__) => {
+                        text.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDisplayAttributes<Identity: windows_core::IUnknownImpl + ISpeechPhraseInfo_Impl>(this: *mut core::ffi::c_void, startelement: i32, elements: i32, usereplacements: super::super::Foundation::VARIANT_BOOL, displayattributes: *mut SpeechDisplayAttributes) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseInfo_Impl::GetDisplayAttributes(this, core::mem::transmute_copy(&startelement), core::mem::transmute_copy(&elements), core::mem::transmute_copy(&usereplacements)) {
+                    Ok(ok__) => {
+                        displayattributes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            LanguageId: LanguageId::<Identity>,
+            GrammarId: GrammarId::<Identity>,
+            StartTime: StartTime::<Identity>,
+            AudioStreamPosition: AudioStreamPosition::<Identity>,
+            AudioSizeBytes: AudioSizeBytes::<Identity>,
+            RetainedSizeBytes: RetainedSizeBytes::<Identity>,
+            AudioSizeTime: AudioSizeTime::<Identity>,
+            Rule: Rule::<Identity>,
+            Properties: Properties::<Identity>,
+            Elements: Elements::<Identity>,
+            Replacements: Replacements::<Identity>,
+            EngineId: EngineId::<Identity>,
+            EnginePrivateData: EnginePrivateData::<Identity>,
+            SaveToMemory: SaveToMemory::<Identity>,
+            GetText: GetText::<Identity>,
+            GetDisplayAttributes: GetDisplayAttributes::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechPhraseInfo {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechPhraseInfoBuilder, ISpeechPhraseInfoBuilder_Vtbl, 0x3b151836_df3a_4e0a_846c_d2adc9334333);
@@ -12281,6 +18175,30 @@ impl ISpeechPhraseInfoBuilder_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechPhraseInfoBuilder_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechPhraseInfoBuilder_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RestorePhraseFromMemory<Identity: windows_core::IUnknownImpl + ISpeechPhraseInfoBuilder_Impl>(this: *mut core::ffi::c_void, phraseinmemory: *const super::super::System::Variant::VARIANT, phraseinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseInfoBuilder_Impl::RestorePhraseFromMemory(this, core::mem::transmute_copy(&phraseinmemory)) {
+                    Ok(ok__) => {
+                        phraseinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RestorePhraseFromMemory: RestorePhraseFromMemory::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechPhraseInfoBuilder {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechPhraseProperties, ISpeechPhraseProperties_Vtbl, 0x08166b47_102e_4b23_a599_bdb98dbfd1f4);
@@ -12380,6 +18298,56 @@ impl ISpeechPhraseProperties_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechPhraseProperties_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechPhraseProperties_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + ISpeechPhraseProperties_Impl>(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseProperties_Impl::Count(this) {
+                    Ok(ok__) => {
+                        count.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Item<Identity: windows_core::IUnknownImpl + ISpeechPhraseProperties_Impl>(this: *mut core::ffi::c_void, index: i32, property: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseProperties_Impl::Item(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        property.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + ISpeechPhraseProperties_Impl>(this: *mut core::ffi::c_void, enumvariant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseProperties_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        enumvariant.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Count: Count::<Identity>,
+            Item: Item::<Identity>,
+            _NewEnum: _NewEnum::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechPhraseProperties {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechPhraseProperty, ISpeechPhraseProperty_Vtbl, 0xce563d48_961e_4732_a2e1_378a42b430be);
@@ -12609,8 +18577,136 @@ impl ISpeechPhraseProperty_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for ISpeechPhraseProperty {}
-#[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + ISpeechPhraseProperty_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechPhraseProperty_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + ISpeechPhraseProperty_Impl>(this: *mut core::ffi::c_void, name: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseProperty_Impl::Name(this) {
+                    Ok(ok__) => {
+                        name.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + ISpeechPhraseProperty_Impl>(this: *mut core::ffi::c_void, id: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseProperty_Impl::Id(this) {
+                    Ok(ok__) => {
+                        id.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Value<Identity: windows_core::IUnknownImpl + ISpeechPhraseProperty_Impl>(this: *mut core::ffi::c_void, value: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseProperty_Impl::Value(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FirstElement<Identity: windows_core::IUnknownImpl + ISpeechPhraseProperty_Impl>(this: *mut core::ffi::c_void, firstelement: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseProperty_Impl::FirstElement(this) {
+                    Ok(ok__) => {
+                        firstelement.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NumberOfElements<Identity: windows_core::IUnknownImpl + ISpeechPhraseProperty_Impl>(this: *mut core::ffi::c_void, numberofelements: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseProperty_Impl::NumberOfElements(this) {
+                    Ok(ok__) => {
+                        numberofelements.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EngineConfidence<Identity: windows_core::IUnknownImpl + ISpeechPhraseProperty_Impl>(this: *mut core::ffi::c_void, confidence: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseProperty_Impl::EngineConfidence(this) {
+                    Ok(ok__) => {
+                        confidence.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Confidence<Identity: windows_core::IUnknownImpl + ISpeechPhraseProperty_Impl>(this: *mut core::ffi::c_void, confidence: *mut SpeechEngineConfidence) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseProperty_Impl::Confidence(this) {
+                    Ok(ok__) => {
+                        confidence.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Parent<Identity: windows_core::IUnknownImpl + ISpeechPhraseProperty_Impl>(this: *mut core::ffi::c_void, parentproperty: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseProperty_Impl::Parent(this) {
+                    Ok(ok__) => {
+                        parentproperty.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Children<Identity: windows_core::IUnknownImpl + ISpeechPhraseProperty_Impl>(this: *mut core::ffi::c_void, children: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseProperty_Impl::Children(this) {
+                    Ok(ok__) => {
+                        children.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Name: Name::<Identity>,
+            Id: Id::<Identity>,
+            Value: Value::<Identity>,
+            FirstElement: FirstElement::<Identity>,
+            NumberOfElements: NumberOfElements::<Identity>,
+            EngineConfidence: EngineConfidence::<Identity>,
+            Confidence: Confidence::<Identity>,
+            Parent: Parent::<Identity>,
+            Children: Children::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for ISpeechPhraseProperty {}
+#[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechPhraseReplacement, ISpeechPhraseReplacement_Vtbl, 0x2890a410_53a7_4fb5_94ec_06d4998e3d02);
 #[cfg(feature = "Win32_System_Com")]
 impl core::ops::Deref for ISpeechPhraseReplacement {
@@ -12729,6 +18825,69 @@ impl ISpeechPhraseReplacement_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechPhraseReplacement_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechPhraseReplacement_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DisplayAttributes<Identity: windows_core::IUnknownImpl + ISpeechPhraseReplacement_Impl>(this: *mut core::ffi::c_void, displayattributes: *mut SpeechDisplayAttributes) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseReplacement_Impl::DisplayAttributes(this) {
+                    Ok(ok__) => {
+                        displayattributes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Text<Identity: windows_core::IUnknownImpl + ISpeechPhraseReplacement_Impl>(this: *mut core::ffi::c_void, text: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseReplacement_Impl::Text(this) {
+                    Ok(ok__) => {
+                        text.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FirstElement<Identity: windows_core::IUnknownImpl + ISpeechPhraseReplacement_Impl>(this: *mut core::ffi::c_void, firstelement: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseReplacement_Impl::FirstElement(this) {
+                    Ok(ok__) => {
+                        firstelement.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NumberOfElements<Identity: windows_core::IUnknownImpl + ISpeechPhraseReplacement_Impl>(this: *mut core::ffi::c_void, numberofelements: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseReplacement_Impl::NumberOfElements(this) {
+                    Ok(ok__) => {
+                        numberofelements.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DisplayAttributes: DisplayAttributes::<Identity>,
+            Text: Text::<Identity>,
+            FirstElement: FirstElement::<Identity>,
+            NumberOfElements: NumberOfElements::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechPhraseReplacement {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechPhraseReplacements, ISpeechPhraseReplacements_Vtbl, 0x38bc662f_2257_4525_959e_2069d2596c05);
@@ -12828,6 +18987,56 @@ impl ISpeechPhraseReplacements_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechPhraseReplacements_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechPhraseReplacements_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + ISpeechPhraseReplacements_Impl>(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseReplacements_Impl::Count(this) {
+                    Ok(ok__) => {
+                        count.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Item<Identity: windows_core::IUnknownImpl + ISpeechPhraseReplacements_Impl>(this: *mut core::ffi::c_void, index: i32, reps: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseReplacements_Impl::Item(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        reps.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + ISpeechPhraseReplacements_Impl>(this: *mut core::ffi::c_void, enumvariant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseReplacements_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        enumvariant.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Count: Count::<Identity>,
+            Item: Item::<Identity>,
+            _NewEnum: _NewEnum::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechPhraseReplacements {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechPhraseRule, ISpeechPhraseRule_Vtbl, 0xa7bfe112_a4a0_48d9_b602_c313843f6964);
@@ -13032,6 +19241,121 @@ impl ISpeechPhraseRule_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechPhraseRule_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechPhraseRule_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + ISpeechPhraseRule_Impl>(this: *mut core::ffi::c_void, name: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseRule_Impl::Name(this) {
+                    Ok(ok__) => {
+                        name.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + ISpeechPhraseRule_Impl>(this: *mut core::ffi::c_void, id: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseRule_Impl::Id(this) {
+                    Ok(ok__) => {
+                        id.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FirstElement<Identity: windows_core::IUnknownImpl + ISpeechPhraseRule_Impl>(this: *mut core::ffi::c_void, firstelement: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseRule_Impl::FirstElement(this) {
+                    Ok(ok__) => {
+                        firstelement.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NumberOfElements<Identity: windows_core::IUnknownImpl + ISpeechPhraseRule_Impl>(this: *mut core::ffi::c_void, numberofelements: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseRule_Impl::NumberOfElements(this) {
+                    Ok(ok__) => {
+                        numberofelements.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Parent<Identity: windows_core::IUnknownImpl + ISpeechPhraseRule_Impl>(this: *mut core::ffi::c_void, parent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseRule_Impl::Parent(this) {
+                    Ok(ok__) => {
+                        parent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Children<Identity: windows_core::IUnknownImpl + ISpeechPhraseRule_Impl>(this: *mut core::ffi::c_void, children: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseRule_Impl::Children(this) {
+                    Ok(ok__) => {
+                        children.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Confidence<Identity: windows_core::IUnknownImpl + ISpeechPhraseRule_Impl>(this: *mut core::ffi::c_void, actualconfidence: *mut SpeechEngineConfidence) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseRule_Impl::Confidence(this) {
+                    Ok(ok__) => {
+                        actualconfidence.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EngineConfidence<Identity: windows_core::IUnknownImpl + ISpeechPhraseRule_Impl>(this: *mut core::ffi::c_void, engineconfidence: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseRule_Impl::EngineConfidence(this) {
+                    Ok(ok__) => {
+                        engineconfidence.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Name: Name::<Identity>,
+            Id: Id::<Identity>,
+            FirstElement: FirstElement::<Identity>,
+            NumberOfElements: NumberOfElements::<Identity>,
+            Parent: Parent::<Identity>,
+            Children: Children::<Identity>,
+            Confidence: Confidence::<Identity>,
+            EngineConfidence: EngineConfidence::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechPhraseRule {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechPhraseRules, ISpeechPhraseRules_Vtbl, 0x9047d593_01dd_4b72_81a3_e4a0ca69f407);
@@ -13131,10 +19455,60 @@ impl ISpeechPhraseRules_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for ISpeechPhraseRules {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(ISpeechRecoContext, ISpeechRecoContext_Vtbl, 0x580aa49d_7e1e_4809_b8e2_57da806104b8);
-#[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + ISpeechPhraseRules_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechPhraseRules_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + ISpeechPhraseRules_Impl>(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseRules_Impl::Count(this) {
+                    Ok(ok__) => {
+                        count.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Item<Identity: windows_core::IUnknownImpl + ISpeechPhraseRules_Impl>(this: *mut core::ffi::c_void, index: i32, rule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseRules_Impl::Item(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        rule.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + ISpeechPhraseRules_Impl>(this: *mut core::ffi::c_void, enumvariant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechPhraseRules_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        enumvariant.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Count: Count::<Identity>,
+            Item: Item::<Identity>,
+            _NewEnum: _NewEnum::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for ISpeechPhraseRules {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(ISpeechRecoContext, ISpeechRecoContext_Vtbl, 0x580aa49d_7e1e_4809_b8e2_57da806104b8);
+#[cfg(feature = "Win32_System_Com")]
 impl core::ops::Deref for ISpeechRecoContext {
     type Target = super::super::System::Com::IDispatch;
     fn deref(&self) -> &Self::Target {
@@ -13602,6 +19976,270 @@ impl ISpeechRecoContext_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechRecoContext_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Recognizer<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void, recognizer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoContext_Impl::Recognizer(this) {
+                    Ok(ok__) => {
+                        recognizer.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AudioInputInterferenceStatus<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void, interference: *mut SpeechInterference) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoContext_Impl::AudioInputInterferenceStatus(this) {
+                    Ok(ok__) => {
+                        interference.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RequestedUIType<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void, uitype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoContext_Impl::RequestedUIType(this) {
+                    Ok(ok__) => {
+                        uitype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putref_Voice<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void, voice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoContext_Impl::putref_Voice(this, core::mem::transmute_copy(&voice)).into()
+            }
+        }
+        unsafe extern "system" fn Voice<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void, voice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoContext_Impl::Voice(this) {
+                    Ok(ok__) => {
+                        voice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAllowVoiceFormatMatchingOnNextSet<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void, allow: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoContext_Impl::SetAllowVoiceFormatMatchingOnNextSet(this, core::mem::transmute_copy(&allow)).into()
+            }
+        }
+        unsafe extern "system" fn AllowVoiceFormatMatchingOnNextSet<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void, pallow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoContext_Impl::AllowVoiceFormatMatchingOnNextSet(this) {
+                    Ok(ok__) => {
+                        pallow.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetVoicePurgeEvent<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void, eventinterest: SpeechRecoEvents) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoContext_Impl::SetVoicePurgeEvent(this, core::mem::transmute_copy(&eventinterest)).into()
+            }
+        }
+        unsafe extern "system" fn VoicePurgeEvent<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void, eventinterest: *mut SpeechRecoEvents) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoContext_Impl::VoicePurgeEvent(this) {
+                    Ok(ok__) => {
+                        eventinterest.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEventInterests<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void, eventinterest: SpeechRecoEvents) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoContext_Impl::SetEventInterests(this, core::mem::transmute_copy(&eventinterest)).into()
+            }
+        }
+        unsafe extern "system" fn EventInterests<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void, eventinterest: *mut SpeechRecoEvents) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoContext_Impl::EventInterests(this) {
+                    Ok(ok__) => {
+                        eventinterest.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCmdMaxAlternates<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void, maxalternates: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoContext_Impl::SetCmdMaxAlternates(this, core::mem::transmute_copy(&maxalternates)).into()
+            }
+        }
+        unsafe extern "system" fn CmdMaxAlternates<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void, maxalternates: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoContext_Impl::CmdMaxAlternates(this) {
+                    Ok(ok__) => {
+                        maxalternates.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetState<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void, state: SpeechRecoContextState) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoContext_Impl::SetState(this, core::mem::transmute_copy(&state)).into()
+            }
+        }
+        unsafe extern "system" fn State<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void, state: *mut SpeechRecoContextState) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoContext_Impl::State(this) {
+                    Ok(ok__) => {
+                        state.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRetainedAudio<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void, option: SpeechRetainedAudioOptions) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoContext_Impl::SetRetainedAudio(this, core::mem::transmute_copy(&option)).into()
+            }
+        }
+        unsafe extern "system" fn RetainedAudio<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void, option: *mut SpeechRetainedAudioOptions) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoContext_Impl::RetainedAudio(this) {
+                    Ok(ok__) => {
+                        option.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putref_RetainedAudioFormat<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void, format: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoContext_Impl::putref_RetainedAudioFormat(this, core::mem::transmute_copy(&format)).into()
+            }
+        }
+        unsafe extern "system" fn RetainedAudioFormat<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void, format: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoContext_Impl::RetainedAudioFormat(this) {
+                    Ok(ok__) => {
+                        format.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Pause<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoContext_Impl::Pause(this).into()
+            }
+        }
+        unsafe extern "system" fn Resume<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoContext_Impl::Resume(this).into()
+            }
+        }
+        unsafe extern "system" fn CreateGrammar<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void, grammarid: super::super::System::Variant::VARIANT, grammar: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoContext_Impl::CreateGrammar(this, core::mem::transmute(&grammarid)) {
+                    Ok(ok__) => {
+                        grammar.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateResultFromMemory<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void, resultblock: *const super::super::System::Variant::VARIANT, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoContext_Impl::CreateResultFromMemory(this, core::mem::transmute_copy(&resultblock)) {
+                    Ok(ok__) => {
+                        result.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Bookmark<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void, options: SpeechBookmarkOptions, streampos: super::super::System::Variant::VARIANT, bookmarkid: super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoContext_Impl::Bookmark(this, core::mem::transmute_copy(&options), core::mem::transmute(&streampos), core::mem::transmute(&bookmarkid)).into()
+            }
+        }
+        unsafe extern "system" fn SetAdaptationData<Identity: windows_core::IUnknownImpl + ISpeechRecoContext_Impl>(this: *mut core::ffi::c_void, adaptationstring: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoContext_Impl::SetAdaptationData(this, core::mem::transmute(&adaptationstring)).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Recognizer: Recognizer::<Identity>,
+            AudioInputInterferenceStatus: AudioInputInterferenceStatus::<Identity>,
+            RequestedUIType: RequestedUIType::<Identity>,
+            putref_Voice: putref_Voice::<Identity>,
+            Voice: Voice::<Identity>,
+            SetAllowVoiceFormatMatchingOnNextSet: SetAllowVoiceFormatMatchingOnNextSet::<Identity>,
+            AllowVoiceFormatMatchingOnNextSet: AllowVoiceFormatMatchingOnNextSet::<Identity>,
+            SetVoicePurgeEvent: SetVoicePurgeEvent::<Identity>,
+            VoicePurgeEvent: VoicePurgeEvent::<Identity>,
+            SetEventInterests: SetEventInterests::<Identity>,
+            EventInterests: EventInterests::<Identity>,
+            SetCmdMaxAlternates: SetCmdMaxAlternates::<Identity>,
+            CmdMaxAlternates: CmdMaxAlternates::<Identity>,
+            SetState: SetState::<Identity>,
+            State: State::<Identity>,
+            SetRetainedAudio: SetRetainedAudio::<Identity>,
+            RetainedAudio: RetainedAudio::<Identity>,
+            putref_RetainedAudioFormat: putref_RetainedAudioFormat::<Identity>,
+            RetainedAudioFormat: RetainedAudioFormat::<Identity>,
+            Pause: Pause::<Identity>,
+            Resume: Resume::<Identity>,
+            CreateGrammar: CreateGrammar::<Identity>,
+            CreateResultFromMemory: CreateResultFromMemory::<Identity>,
+            Bookmark: Bookmark::<Identity>,
+            SetAdaptationData: SetAdaptationData::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechRecoContext {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechRecoGrammar, ISpeechRecoGrammar_Vtbl, 0xb6d6f79f_2158_4e50_b5bc_9a9ccd852a09);
@@ -13933,38 +20571,212 @@ impl ISpeechRecoGrammar_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for ISpeechRecoGrammar {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(ISpeechRecoResult, ISpeechRecoResult_Vtbl, 0xed2879cf_ced9_4ee6_a534_de0191d5468d);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for ISpeechRecoResult {
-    type Target = super::super::System::Com::IDispatch;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(ISpeechRecoResult, windows_core::IUnknown, super::super::System::Com::IDispatch);
-#[cfg(feature = "Win32_System_Com")]
-impl ISpeechRecoResult {
-    pub unsafe fn RecoContext(&self) -> windows_core::Result<ISpeechRecoContext> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).RecoContext)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn Times(&self) -> windows_core::Result<ISpeechRecoResultTimes> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).Times)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+impl<Identity: windows_core::IUnknownImpl + ISpeechRecoGrammar_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechRecoGrammar_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + ISpeechRecoGrammar_Impl>(this: *mut core::ffi::c_void, id: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoGrammar_Impl::Id(this) {
+                    Ok(ok__) => {
+                        id.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn putref_AudioFormat<P0>(&self, format: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<ISpeechAudioFormat>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).putref_AudioFormat)(windows_core::Interface::as_raw(self), format.param().abi()).ok() }
-    }
+        unsafe extern "system" fn RecoContext<Identity: windows_core::IUnknownImpl + ISpeechRecoGrammar_Impl>(this: *mut core::ffi::c_void, recocontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoGrammar_Impl::RecoContext(this) {
+                    Ok(ok__) => {
+                        recocontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetState<Identity: windows_core::IUnknownImpl + ISpeechRecoGrammar_Impl>(this: *mut core::ffi::c_void, state: SpeechGrammarState) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoGrammar_Impl::SetState(this, core::mem::transmute_copy(&state)).into()
+            }
+        }
+        unsafe extern "system" fn State<Identity: windows_core::IUnknownImpl + ISpeechRecoGrammar_Impl>(this: *mut core::ffi::c_void, state: *mut SpeechGrammarState) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoGrammar_Impl::State(this) {
+                    Ok(ok__) => {
+                        state.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Rules<Identity: windows_core::IUnknownImpl + ISpeechRecoGrammar_Impl>(this: *mut core::ffi::c_void, rules: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoGrammar_Impl::Rules(this) {
+                    Ok(ok__) => {
+                        rules.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + ISpeechRecoGrammar_Impl>(this: *mut core::ffi::c_void, newlanguage: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoGrammar_Impl::Reset(this, core::mem::transmute_copy(&newlanguage)).into()
+            }
+        }
+        unsafe extern "system" fn CmdLoadFromFile<Identity: windows_core::IUnknownImpl + ISpeechRecoGrammar_Impl>(this: *mut core::ffi::c_void, filename: *mut core::ffi::c_void, loadoption: SpeechLoadOption) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoGrammar_Impl::CmdLoadFromFile(this, core::mem::transmute(&filename), core::mem::transmute_copy(&loadoption)).into()
+            }
+        }
+        unsafe extern "system" fn CmdLoadFromObject<Identity: windows_core::IUnknownImpl + ISpeechRecoGrammar_Impl>(this: *mut core::ffi::c_void, classid: *mut core::ffi::c_void, grammarname: *mut core::ffi::c_void, loadoption: SpeechLoadOption) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoGrammar_Impl::CmdLoadFromObject(this, core::mem::transmute(&classid), core::mem::transmute(&grammarname), core::mem::transmute_copy(&loadoption)).into()
+            }
+        }
+        unsafe extern "system" fn CmdLoadFromResource<Identity: windows_core::IUnknownImpl + ISpeechRecoGrammar_Impl>(this: *mut core::ffi::c_void, hmodule: i32, resourcename: super::super::System::Variant::VARIANT, resourcetype: super::super::System::Variant::VARIANT, languageid: i32, loadoption: SpeechLoadOption) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoGrammar_Impl::CmdLoadFromResource(this, core::mem::transmute_copy(&hmodule), core::mem::transmute(&resourcename), core::mem::transmute(&resourcetype), core::mem::transmute_copy(&languageid), core::mem::transmute_copy(&loadoption)).into()
+            }
+        }
+        unsafe extern "system" fn CmdLoadFromMemory<Identity: windows_core::IUnknownImpl + ISpeechRecoGrammar_Impl>(this: *mut core::ffi::c_void, grammardata: super::super::System::Variant::VARIANT, loadoption: SpeechLoadOption) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoGrammar_Impl::CmdLoadFromMemory(this, core::mem::transmute(&grammardata), core::mem::transmute_copy(&loadoption)).into()
+            }
+        }
+        unsafe extern "system" fn CmdLoadFromProprietaryGrammar<Identity: windows_core::IUnknownImpl + ISpeechRecoGrammar_Impl>(this: *mut core::ffi::c_void, proprietaryguid: *mut core::ffi::c_void, proprietarystring: *mut core::ffi::c_void, proprietarydata: super::super::System::Variant::VARIANT, loadoption: SpeechLoadOption) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoGrammar_Impl::CmdLoadFromProprietaryGrammar(this, core::mem::transmute(&proprietaryguid), core::mem::transmute(&proprietarystring), core::mem::transmute(&proprietarydata), core::mem::transmute_copy(&loadoption)).into()
+            }
+        }
+        unsafe extern "system" fn CmdSetRuleState<Identity: windows_core::IUnknownImpl + ISpeechRecoGrammar_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, state: SpeechRuleState) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoGrammar_Impl::CmdSetRuleState(this, core::mem::transmute(&name), core::mem::transmute_copy(&state)).into()
+            }
+        }
+        unsafe extern "system" fn CmdSetRuleIdState<Identity: windows_core::IUnknownImpl + ISpeechRecoGrammar_Impl>(this: *mut core::ffi::c_void, ruleid: i32, state: SpeechRuleState) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoGrammar_Impl::CmdSetRuleIdState(this, core::mem::transmute_copy(&ruleid), core::mem::transmute_copy(&state)).into()
+            }
+        }
+        unsafe extern "system" fn DictationLoad<Identity: windows_core::IUnknownImpl + ISpeechRecoGrammar_Impl>(this: *mut core::ffi::c_void, topicname: *mut core::ffi::c_void, loadoption: SpeechLoadOption) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoGrammar_Impl::DictationLoad(this, core::mem::transmute(&topicname), core::mem::transmute_copy(&loadoption)).into()
+            }
+        }
+        unsafe extern "system" fn DictationUnload<Identity: windows_core::IUnknownImpl + ISpeechRecoGrammar_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoGrammar_Impl::DictationUnload(this).into()
+            }
+        }
+        unsafe extern "system" fn DictationSetState<Identity: windows_core::IUnknownImpl + ISpeechRecoGrammar_Impl>(this: *mut core::ffi::c_void, state: SpeechRuleState) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoGrammar_Impl::DictationSetState(this, core::mem::transmute_copy(&state)).into()
+            }
+        }
+        unsafe extern "system" fn SetWordSequenceData<Identity: windows_core::IUnknownImpl + ISpeechRecoGrammar_Impl>(this: *mut core::ffi::c_void, text: *mut core::ffi::c_void, textlength: i32, info: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoGrammar_Impl::SetWordSequenceData(this, core::mem::transmute(&text), core::mem::transmute_copy(&textlength), core::mem::transmute_copy(&info)).into()
+            }
+        }
+        unsafe extern "system" fn SetTextSelection<Identity: windows_core::IUnknownImpl + ISpeechRecoGrammar_Impl>(this: *mut core::ffi::c_void, info: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoGrammar_Impl::SetTextSelection(this, core::mem::transmute_copy(&info)).into()
+            }
+        }
+        unsafe extern "system" fn IsPronounceable<Identity: windows_core::IUnknownImpl + ISpeechRecoGrammar_Impl>(this: *mut core::ffi::c_void, word: *mut core::ffi::c_void, wordpronounceable: *mut SpeechWordPronounceable) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoGrammar_Impl::IsPronounceable(this, core::mem::transmute(&word)) {
+                    Ok(ok__) => {
+                        wordpronounceable.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Id: Id::<Identity>,
+            RecoContext: RecoContext::<Identity>,
+            SetState: SetState::<Identity>,
+            State: State::<Identity>,
+            Rules: Rules::<Identity>,
+            Reset: Reset::<Identity>,
+            CmdLoadFromFile: CmdLoadFromFile::<Identity>,
+            CmdLoadFromObject: CmdLoadFromObject::<Identity>,
+            CmdLoadFromResource: CmdLoadFromResource::<Identity>,
+            CmdLoadFromMemory: CmdLoadFromMemory::<Identity>,
+            CmdLoadFromProprietaryGrammar: CmdLoadFromProprietaryGrammar::<Identity>,
+            CmdSetRuleState: CmdSetRuleState::<Identity>,
+            CmdSetRuleIdState: CmdSetRuleIdState::<Identity>,
+            DictationLoad: DictationLoad::<Identity>,
+            DictationUnload: DictationUnload::<Identity>,
+            DictationSetState: DictationSetState::<Identity>,
+            SetWordSequenceData: SetWordSequenceData::<Identity>,
+            SetTextSelection: SetTextSelection::<Identity>,
+            IsPronounceable: IsPronounceable::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for ISpeechRecoGrammar {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(ISpeechRecoResult, ISpeechRecoResult_Vtbl, 0xed2879cf_ced9_4ee6_a534_de0191d5468d);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for ISpeechRecoResult {
+    type Target = super::super::System::Com::IDispatch;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(ISpeechRecoResult, windows_core::IUnknown, super::super::System::Com::IDispatch);
+#[cfg(feature = "Win32_System_Com")]
+impl ISpeechRecoResult {
+    pub unsafe fn RecoContext(&self) -> windows_core::Result<ISpeechRecoContext> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).RecoContext)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn Times(&self) -> windows_core::Result<ISpeechRecoResultTimes> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).Times)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn putref_AudioFormat<P0>(&self, format: P0) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<ISpeechAudioFormat>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).putref_AudioFormat)(windows_core::Interface::as_raw(self), format.param().abi()).ok() }
+    }
     pub unsafe fn AudioFormat(&self) -> windows_core::Result<ISpeechAudioFormat> {
         unsafe {
             let mut result__ = core::mem::zeroed();
@@ -14168,6 +20980,135 @@ impl ISpeechRecoResult_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechRecoResult_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechRecoResult_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RecoContext<Identity: windows_core::IUnknownImpl + ISpeechRecoResult_Impl>(this: *mut core::ffi::c_void, recocontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoResult_Impl::RecoContext(this) {
+                    Ok(ok__) => {
+                        recocontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Times<Identity: windows_core::IUnknownImpl + ISpeechRecoResult_Impl>(this: *mut core::ffi::c_void, times: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoResult_Impl::Times(this) {
+                    Ok(ok__) => {
+                        times.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putref_AudioFormat<Identity: windows_core::IUnknownImpl + ISpeechRecoResult_Impl>(this: *mut core::ffi::c_void, format: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoResult_Impl::putref_AudioFormat(this, core::mem::transmute_copy(&format)).into()
+            }
+        }
+        unsafe extern "system" fn AudioFormat<Identity: windows_core::IUnknownImpl + ISpeechRecoResult_Impl>(this: *mut core::ffi::c_void, format: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoResult_Impl::AudioFormat(this) {
+                    Ok(ok__) => {
+                        format.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PhraseInfo<Identity: windows_core::IUnknownImpl + ISpeechRecoResult_Impl>(this: *mut core::ffi::c_void, phraseinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoResult_Impl::PhraseInfo(this) {
+                    Ok(ok__) => {
+                        phraseinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Alternates<Identity: windows_core::IUnknownImpl + ISpeechRecoResult_Impl>(this: *mut core::ffi::c_void, requestcount: i32, startelement: i32, elements: i32, alternates: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoResult_Impl::Alternates(this, core::mem::transmute_copy(&requestcount), core::mem::transmute_copy(&startelement), core::mem::transmute_copy(&elements)) {
+                    Ok(ok__) => {
+                        alternates.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Audio<Identity: windows_core::IUnknownImpl + ISpeechRecoResult_Impl>(this: *mut core::ffi::c_void, startelement: i32, elements: i32, stream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoResult_Impl::Audio(this, core::mem::transmute_copy(&startelement), core::mem::transmute_copy(&elements)) {
+                    Ok(ok__) => {
+                        stream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SpeakAudio<Identity: windows_core::IUnknownImpl + ISpeechRecoResult_Impl>(this: *mut core::ffi::c_void, startelement: i32, elements: i32, flags: SpeechVoiceSpeakFlags, streamnumber: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoResult_Impl::SpeakAudio(this, core::mem::transmute_copy(&startelement), core::mem::transmute_copy(&elements), core::mem::transmute_copy(&flags)) {
+                    Ok(ok__) => {
+                        streamnumber.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SaveToMemory<Identity: windows_core::IUnknownImpl + ISpeechRecoResult_Impl>(this: *mut core::ffi::c_void, resultblock: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoResult_Impl::SaveToMemory(this) {
+                    Ok(ok__) => {
+                        resultblock.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DiscardResultInfo<Identity: windows_core::IUnknownImpl + ISpeechRecoResult_Impl>(this: *mut core::ffi::c_void, valuetypes: SpeechDiscardType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoResult_Impl::DiscardResultInfo(this, core::mem::transmute_copy(&valuetypes)).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RecoContext: RecoContext::<Identity>,
+            Times: Times::<Identity>,
+            putref_AudioFormat: putref_AudioFormat::<Identity>,
+            AudioFormat: AudioFormat::<Identity>,
+            PhraseInfo: PhraseInfo::<Identity>,
+            Alternates: Alternates::<Identity>,
+            Audio: Audio::<Identity>,
+            SpeakAudio: SpeakAudio::<Identity>,
+            SaveToMemory: SaveToMemory::<Identity>,
+            DiscardResultInfo: DiscardResultInfo::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechRecoResult {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechRecoResult2, ISpeechRecoResult2_Vtbl, 0x8e0a246d_d3c8_45de_8657_04290c458c3c);
@@ -14213,6 +21154,21 @@ impl ISpeechRecoResult2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechRecoResult2_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechRecoResult2_Vtbl
+where
+    ISpeechRecoResult_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetTextFeedback<Identity: windows_core::IUnknownImpl + ISpeechRecoResult2_Impl>(this: *mut core::ffi::c_void, feedback: *mut core::ffi::c_void, wassuccessful: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoResult2_Impl::SetTextFeedback(this, core::mem::transmute(&feedback), core::mem::transmute_copy(&wassuccessful)).into()
+            }
+        }
+        Self { base__: <ISpeechRecoResult_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetTextFeedback: SetTextFeedback::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechRecoResult2 {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechRecoResultDispatch, ISpeechRecoResultDispatch_Vtbl, 0x6d60eb64_aced_40a6_bbf3_4e557f71dee2);
@@ -14493,29 +21449,185 @@ impl ISpeechRecoResultDispatch_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for ISpeechRecoResultDispatch {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(ISpeechRecoResultTimes, ISpeechRecoResultTimes_Vtbl, 0x62b3b8fb_f6e7_41be_bdcb_056b1c29efc0);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for ISpeechRecoResultTimes {
-    type Target = super::super::System::Com::IDispatch;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(ISpeechRecoResultTimes, windows_core::IUnknown, super::super::System::Com::IDispatch);
-#[cfg(feature = "Win32_System_Com")]
-impl ISpeechRecoResultTimes {
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub unsafe fn StreamTime(&self) -> windows_core::Result<super::super::System::Variant::VARIANT> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).StreamTime)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+impl<Identity: windows_core::IUnknownImpl + ISpeechRecoResultDispatch_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechRecoResultDispatch_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RecoContext<Identity: windows_core::IUnknownImpl + ISpeechRecoResultDispatch_Impl>(this: *mut core::ffi::c_void, recocontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoResultDispatch_Impl::RecoContext(this) {
+                    Ok(ok__) => {
+                        recocontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub unsafe fn Length(&self) -> windows_core::Result<super::super::System::Variant::VARIANT> {
+        unsafe extern "system" fn Times<Identity: windows_core::IUnknownImpl + ISpeechRecoResultDispatch_Impl>(this: *mut core::ffi::c_void, times: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoResultDispatch_Impl::Times(this) {
+                    Ok(ok__) => {
+                        times.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putref_AudioFormat<Identity: windows_core::IUnknownImpl + ISpeechRecoResultDispatch_Impl>(this: *mut core::ffi::c_void, format: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoResultDispatch_Impl::putref_AudioFormat(this, core::mem::transmute_copy(&format)).into()
+            }
+        }
+        unsafe extern "system" fn AudioFormat<Identity: windows_core::IUnknownImpl + ISpeechRecoResultDispatch_Impl>(this: *mut core::ffi::c_void, format: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoResultDispatch_Impl::AudioFormat(this) {
+                    Ok(ok__) => {
+                        format.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PhraseInfo<Identity: windows_core::IUnknownImpl + ISpeechRecoResultDispatch_Impl>(this: *mut core::ffi::c_void, phraseinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoResultDispatch_Impl::PhraseInfo(this) {
+                    Ok(ok__) => {
+                        phraseinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Alternates<Identity: windows_core::IUnknownImpl + ISpeechRecoResultDispatch_Impl>(this: *mut core::ffi::c_void, requestcount: i32, startelement: i32, elements: i32, alternates: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoResultDispatch_Impl::Alternates(this, core::mem::transmute_copy(&requestcount), core::mem::transmute_copy(&startelement), core::mem::transmute_copy(&elements)) {
+                    Ok(ok__) => {
+                        alternates.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Audio<Identity: windows_core::IUnknownImpl + ISpeechRecoResultDispatch_Impl>(this: *mut core::ffi::c_void, startelement: i32, elements: i32, stream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoResultDispatch_Impl::Audio(this, core::mem::transmute_copy(&startelement), core::mem::transmute_copy(&elements)) {
+                    Ok(ok__) => {
+                        stream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SpeakAudio<Identity: windows_core::IUnknownImpl + ISpeechRecoResultDispatch_Impl>(this: *mut core::ffi::c_void, startelement: i32, elements: i32, flags: SpeechVoiceSpeakFlags, streamnumber: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoResultDispatch_Impl::SpeakAudio(this, core::mem::transmute_copy(&startelement), core::mem::transmute_copy(&elements), core::mem::transmute_copy(&flags)) {
+                    Ok(ok__) => {
+                        streamnumber.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SaveToMemory<Identity: windows_core::IUnknownImpl + ISpeechRecoResultDispatch_Impl>(this: *mut core::ffi::c_void, resultblock: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoResultDispatch_Impl::SaveToMemory(this) {
+                    Ok(ok__) => {
+                        resultblock.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DiscardResultInfo<Identity: windows_core::IUnknownImpl + ISpeechRecoResultDispatch_Impl>(this: *mut core::ffi::c_void, valuetypes: SpeechDiscardType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoResultDispatch_Impl::DiscardResultInfo(this, core::mem::transmute_copy(&valuetypes)).into()
+            }
+        }
+        unsafe extern "system" fn GetXMLResult<Identity: windows_core::IUnknownImpl + ISpeechRecoResultDispatch_Impl>(this: *mut core::ffi::c_void, options: SPXMLRESULTOPTIONS, presult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoResultDispatch_Impl::GetXMLResult(this, core::mem::transmute_copy(&options)) {
+                    Ok(ok__) => {
+                        presult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetXMLErrorInfo<Identity: windows_core::IUnknownImpl + ISpeechRecoResultDispatch_Impl>(this: *mut core::ffi::c_void, linenumber: *mut i32, scriptline: *mut *mut core::ffi::c_void, source: *mut *mut core::ffi::c_void, description: *mut *mut core::ffi::c_void, resultcode: *mut windows_core::HRESULT, iserror: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoResultDispatch_Impl::GetXMLErrorInfo(this, core::mem::transmute_copy(&linenumber), core::mem::transmute_copy(&scriptline), core::mem::transmute_copy(&source), core::mem::transmute_copy(&description), core::mem::transmute_copy(&resultcode), core::mem::transmute_copy(&iserror)).into()
+            }
+        }
+        unsafe extern "system" fn SetTextFeedback<Identity: windows_core::IUnknownImpl + ISpeechRecoResultDispatch_Impl>(this: *mut core::ffi::c_void, feedback: *mut core::ffi::c_void, wassuccessful: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechRecoResultDispatch_Impl::SetTextFeedback(this, core::mem::transmute(&feedback), core::mem::transmute_copy(&wassuccessful)).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RecoContext: RecoContext::<Identity>,
+            Times: Times::<Identity>,
+            putref_AudioFormat: putref_AudioFormat::<Identity>,
+            AudioFormat: AudioFormat::<Identity>,
+            PhraseInfo: PhraseInfo::<Identity>,
+            Alternates: Alternates::<Identity>,
+            Audio: Audio::<Identity>,
+            SpeakAudio: SpeakAudio::<Identity>,
+            SaveToMemory: SaveToMemory::<Identity>,
+            DiscardResultInfo: DiscardResultInfo::<Identity>,
+            GetXMLResult: GetXMLResult::<Identity>,
+            GetXMLErrorInfo: GetXMLErrorInfo::<Identity>,
+            SetTextFeedback: SetTextFeedback::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for ISpeechRecoResultDispatch {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(ISpeechRecoResultTimes, ISpeechRecoResultTimes_Vtbl, 0x62b3b8fb_f6e7_41be_bdcb_056b1c29efc0);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for ISpeechRecoResultTimes {
+    type Target = super::super::System::Com::IDispatch;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(ISpeechRecoResultTimes, windows_core::IUnknown, super::super::System::Com::IDispatch);
+#[cfg(feature = "Win32_System_Com")]
+impl ISpeechRecoResultTimes {
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub unsafe fn StreamTime(&self) -> windows_core::Result<super::super::System::Variant::VARIANT> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).StreamTime)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        }
+    }
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub unsafe fn Length(&self) -> windows_core::Result<super::super::System::Variant::VARIANT> {
         unsafe {
             let mut result__ = core::mem::zeroed();
             (windows_core::Interface::vtable(self).Length)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
@@ -14625,6 +21737,69 @@ impl ISpeechRecoResultTimes_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechRecoResultTimes_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechRecoResultTimes_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StreamTime<Identity: windows_core::IUnknownImpl + ISpeechRecoResultTimes_Impl>(this: *mut core::ffi::c_void, time: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoResultTimes_Impl::StreamTime(this) {
+                    Ok(ok__) => {
+                        time.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Length<Identity: windows_core::IUnknownImpl + ISpeechRecoResultTimes_Impl>(this: *mut core::ffi::c_void, length: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoResultTimes_Impl::Length(this) {
+                    Ok(ok__) => {
+                        length.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn TickCount<Identity: windows_core::IUnknownImpl + ISpeechRecoResultTimes_Impl>(this: *mut core::ffi::c_void, tickcount: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoResultTimes_Impl::TickCount(this) {
+                    Ok(ok__) => {
+                        tickcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OffsetFromStart<Identity: windows_core::IUnknownImpl + ISpeechRecoResultTimes_Impl>(this: *mut core::ffi::c_void, offsetfromstart: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecoResultTimes_Impl::OffsetFromStart(this) {
+                    Ok(ok__) => {
+                        offsetfromstart.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            StreamTime: StreamTime::<Identity>,
+            Length: Length::<Identity>,
+            TickCount: TickCount::<Identity>,
+            OffsetFromStart: OffsetFromStart::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechRecoResultTimes {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechRecognizer, ISpeechRecognizer_Vtbl, 0x2d5f1c0c_bd75_4b08_9478_3b11fea2586c);
@@ -14852,17 +22027,309 @@ pub trait ISpeechRecognizer_Impl: super::super::System::Com::IDispatch_Impl {
     fn GetProfiles(&self, requiredattributes: &windows_core::BSTR, optionalattributes: &windows_core::BSTR) -> windows_core::Result<ISpeechObjectTokens>;
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl ISpeechRecognizer_Vtbl {
-    pub const fn new<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn putref_Recognizer<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, recognizer: *mut core::ffi::c_void) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+impl ISpeechRecognizer_Vtbl {
+    pub const fn new<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn putref_Recognizer<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, recognizer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ISpeechRecognizer_Impl::putref_Recognizer(this, core::mem::transmute_copy(&recognizer)).into()
+            }
+        }
+        unsafe extern "system" fn Recognizer<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, recognizer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ISpeechRecognizer_Impl::Recognizer(this) {
+                    Ok(ok__) => {
+                        recognizer.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAllowAudioInputFormatChangesOnNextSet<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, allow: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ISpeechRecognizer_Impl::SetAllowAudioInputFormatChangesOnNextSet(this, core::mem::transmute_copy(&allow)).into()
+            }
+        }
+        unsafe extern "system" fn AllowAudioInputFormatChangesOnNextSet<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, allow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ISpeechRecognizer_Impl::AllowAudioInputFormatChangesOnNextSet(this) {
+                    Ok(ok__) => {
+                        allow.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putref_AudioInput<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, audioinput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ISpeechRecognizer_Impl::putref_AudioInput(this, core::mem::transmute_copy(&audioinput)).into()
+            }
+        }
+        unsafe extern "system" fn AudioInput<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, audioinput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ISpeechRecognizer_Impl::AudioInput(this) {
+                    Ok(ok__) => {
+                        audioinput.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putref_AudioInputStream<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, audioinputstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ISpeechRecognizer_Impl::putref_AudioInputStream(this, core::mem::transmute_copy(&audioinputstream)).into()
+            }
+        }
+        unsafe extern "system" fn AudioInputStream<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, audioinputstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ISpeechRecognizer_Impl::AudioInputStream(this) {
+                    Ok(ok__) => {
+                        audioinputstream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsShared<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, shared: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ISpeechRecognizer_Impl::IsShared(this) {
+                    Ok(ok__) => {
+                        shared.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetState<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, state: SpeechRecognizerState) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ISpeechRecognizer_Impl::SetState(this, core::mem::transmute_copy(&state)).into()
+            }
+        }
+        unsafe extern "system" fn State<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, state: *mut SpeechRecognizerState) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ISpeechRecognizer_Impl::State(this) {
+                    Ok(ok__) => {
+                        state.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Status<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, status: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ISpeechRecognizer_Impl::Status(this) {
+                    Ok(ok__) => {
+                        status.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putref_Profile<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, profile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ISpeechRecognizer_Impl::putref_Profile(this, core::mem::transmute_copy(&profile)).into()
+            }
+        }
+        unsafe extern "system" fn Profile<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, profile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ISpeechRecognizer_Impl::Profile(this) {
+                    Ok(ok__) => {
+                        profile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EmulateRecognition<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, textelements: super::super::System::Variant::VARIANT, elementdisplayattributes: *const super::super::System::Variant::VARIANT, languageid: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ISpeechRecognizer_Impl::EmulateRecognition(this, core::mem::transmute(&textelements), core::mem::transmute_copy(&elementdisplayattributes), core::mem::transmute_copy(&languageid)).into()
+            }
+        }
+        unsafe extern "system" fn CreateRecoContext<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, newcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ISpeechRecognizer_Impl::CreateRecoContext(this) {
+                    Ok(ok__) => {
+                        newcontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFormat<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, r#type: SpeechFormatType, format: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ISpeechRecognizer_Impl::GetFormat(this, core::mem::transmute_copy(&r#type)) {
+                    Ok(ok__) => {
+                        format.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPropertyNumber<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: i32, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ISpeechRecognizer_Impl::SetPropertyNumber(this, core::mem::transmute(&name), core::mem::transmute_copy(&value)) {
+                    Ok(ok__) => {
+                        supported.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPropertyNumber<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: *mut i32, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ISpeechRecognizer_Impl::GetPropertyNumber(this, core::mem::transmute(&name), core::mem::transmute_copy(&value), core::mem::transmute_copy(&supported)).into()
+            }
+        }
+        unsafe extern "system" fn SetPropertyString<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: *mut core::ffi::c_void, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ISpeechRecognizer_Impl::SetPropertyString(this, core::mem::transmute(&name), core::mem::transmute(&value)) {
+                    Ok(ok__) => {
+                        supported.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPropertyString<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ISpeechRecognizer_Impl::GetPropertyString(this, core::mem::transmute(&name), core::mem::transmute_copy(&value), core::mem::transmute_copy(&supported)).into()
+            }
+        }
+        unsafe extern "system" fn IsUISupported<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, typeofui: *mut core::ffi::c_void, extradata: *const super::super::System::Variant::VARIANT, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ISpeechRecognizer_Impl::IsUISupported(this, core::mem::transmute(&typeofui), core::mem::transmute_copy(&extradata)) {
+                    Ok(ok__) => {
+                        supported.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DisplayUI<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hwndparent: i32, title: *mut core::ffi::c_void, typeofui: *mut core::ffi::c_void, extradata: *const super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ISpeechRecognizer_Impl::DisplayUI(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute(&title), core::mem::transmute(&typeofui), core::mem::transmute_copy(&extradata)).into()
+            }
+        }
+        unsafe extern "system" fn GetRecognizers<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, requiredattributes: *mut core::ffi::c_void, optionalattributes: *mut core::ffi::c_void, objecttokens: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ISpeechRecognizer_Impl::GetRecognizers(this, core::mem::transmute(&requiredattributes), core::mem::transmute(&optionalattributes)) {
+                    Ok(ok__) => {
+                        objecttokens.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAudioInputs<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, requiredattributes: *mut core::ffi::c_void, optionalattributes: *mut core::ffi::c_void, objecttokens: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ISpeechRecognizer_Impl::GetAudioInputs(this, core::mem::transmute(&requiredattributes), core::mem::transmute(&optionalattributes)) {
+                    Ok(ok__) => {
+                        objecttokens.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetProfiles<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, requiredattributes: *mut core::ffi::c_void, optionalattributes: *mut core::ffi::c_void, objecttokens: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ISpeechRecognizer_Impl::GetProfiles(this, core::mem::transmute(&requiredattributes), core::mem::transmute(&optionalattributes)) {
+                    Ok(ok__) => {
+                        objecttokens.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: super::super::System::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
+            putref_Recognizer: putref_Recognizer::<Identity, OFFSET>,
+            Recognizer: Recognizer::<Identity, OFFSET>,
+            SetAllowAudioInputFormatChangesOnNextSet: SetAllowAudioInputFormatChangesOnNextSet::<Identity, OFFSET>,
+            AllowAudioInputFormatChangesOnNextSet: AllowAudioInputFormatChangesOnNextSet::<Identity, OFFSET>,
+            putref_AudioInput: putref_AudioInput::<Identity, OFFSET>,
+            AudioInput: AudioInput::<Identity, OFFSET>,
+            putref_AudioInputStream: putref_AudioInputStream::<Identity, OFFSET>,
+            AudioInputStream: AudioInputStream::<Identity, OFFSET>,
+            IsShared: IsShared::<Identity, OFFSET>,
+            SetState: SetState::<Identity, OFFSET>,
+            State: State::<Identity, OFFSET>,
+            Status: Status::<Identity, OFFSET>,
+            putref_Profile: putref_Profile::<Identity, OFFSET>,
+            Profile: Profile::<Identity, OFFSET>,
+            EmulateRecognition: EmulateRecognition::<Identity, OFFSET>,
+            CreateRecoContext: CreateRecoContext::<Identity, OFFSET>,
+            GetFormat: GetFormat::<Identity, OFFSET>,
+            SetPropertyNumber: SetPropertyNumber::<Identity, OFFSET>,
+            GetPropertyNumber: GetPropertyNumber::<Identity, OFFSET>,
+            SetPropertyString: SetPropertyString::<Identity, OFFSET>,
+            GetPropertyString: GetPropertyString::<Identity, OFFSET>,
+            IsUISupported: IsUISupported::<Identity, OFFSET>,
+            DisplayUI: DisplayUI::<Identity, OFFSET>,
+            GetRecognizers: GetRecognizers::<Identity, OFFSET>,
+            GetAudioInputs: GetAudioInputs::<Identity, OFFSET>,
+            GetProfiles: GetProfiles::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognizer as windows_core::Interface>::IID || iid == &<super::super::System::Com::IDispatch as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechRecognizer_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn putref_Recognizer<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, recognizer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ISpeechRecognizer_Impl::putref_Recognizer(this, core::mem::transmute_copy(&recognizer)).into()
             }
         }
-        unsafe extern "system" fn Recognizer<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, recognizer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Recognizer<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, recognizer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ISpeechRecognizer_Impl::Recognizer(this) {
                     Ok(ok__) => {
                         recognizer.write(core::mem::transmute(ok__));
@@ -14872,15 +22339,15 @@ impl ISpeechRecognizer_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetAllowAudioInputFormatChangesOnNextSet<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, allow: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetAllowAudioInputFormatChangesOnNextSet<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, allow: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ISpeechRecognizer_Impl::SetAllowAudioInputFormatChangesOnNextSet(this, core::mem::transmute_copy(&allow)).into()
             }
         }
-        unsafe extern "system" fn AllowAudioInputFormatChangesOnNextSet<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, allow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn AllowAudioInputFormatChangesOnNextSet<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, allow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ISpeechRecognizer_Impl::AllowAudioInputFormatChangesOnNextSet(this) {
                     Ok(ok__) => {
                         allow.write(core::mem::transmute(ok__));
@@ -14890,15 +22357,15 @@ impl ISpeechRecognizer_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn putref_AudioInput<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, audioinput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn putref_AudioInput<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, audioinput: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ISpeechRecognizer_Impl::putref_AudioInput(this, core::mem::transmute_copy(&audioinput)).into()
             }
         }
-        unsafe extern "system" fn AudioInput<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, audioinput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn AudioInput<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, audioinput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ISpeechRecognizer_Impl::AudioInput(this) {
                     Ok(ok__) => {
                         audioinput.write(core::mem::transmute(ok__));
@@ -14908,15 +22375,15 @@ impl ISpeechRecognizer_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn putref_AudioInputStream<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, audioinputstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn putref_AudioInputStream<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, audioinputstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ISpeechRecognizer_Impl::putref_AudioInputStream(this, core::mem::transmute_copy(&audioinputstream)).into()
             }
         }
-        unsafe extern "system" fn AudioInputStream<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, audioinputstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn AudioInputStream<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, audioinputstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ISpeechRecognizer_Impl::AudioInputStream(this) {
                     Ok(ok__) => {
                         audioinputstream.write(core::mem::transmute(ok__));
@@ -14926,9 +22393,9 @@ impl ISpeechRecognizer_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn IsShared<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, shared: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn IsShared<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, shared: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ISpeechRecognizer_Impl::IsShared(this) {
                     Ok(ok__) => {
                         shared.write(core::mem::transmute(ok__));
@@ -14938,15 +22405,15 @@ impl ISpeechRecognizer_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetState<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, state: SpeechRecognizerState) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetState<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, state: SpeechRecognizerState) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ISpeechRecognizer_Impl::SetState(this, core::mem::transmute_copy(&state)).into()
             }
         }
-        unsafe extern "system" fn State<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, state: *mut SpeechRecognizerState) -> windows_core::HRESULT {
+        unsafe extern "system" fn State<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, state: *mut SpeechRecognizerState) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ISpeechRecognizer_Impl::State(this) {
                     Ok(ok__) => {
                         state.write(core::mem::transmute(ok__));
@@ -14956,9 +22423,9 @@ impl ISpeechRecognizer_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Status<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, status: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Status<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, status: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ISpeechRecognizer_Impl::Status(this) {
                     Ok(ok__) => {
                         status.write(core::mem::transmute(ok__));
@@ -14968,15 +22435,15 @@ impl ISpeechRecognizer_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn putref_Profile<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, profile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn putref_Profile<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, profile: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ISpeechRecognizer_Impl::putref_Profile(this, core::mem::transmute_copy(&profile)).into()
             }
         }
-        unsafe extern "system" fn Profile<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, profile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Profile<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, profile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ISpeechRecognizer_Impl::Profile(this) {
                     Ok(ok__) => {
                         profile.write(core::mem::transmute(ok__));
@@ -14986,15 +22453,15 @@ impl ISpeechRecognizer_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn EmulateRecognition<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, textelements: super::super::System::Variant::VARIANT, elementdisplayattributes: *const super::super::System::Variant::VARIANT, languageid: i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn EmulateRecognition<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, textelements: super::super::System::Variant::VARIANT, elementdisplayattributes: *const super::super::System::Variant::VARIANT, languageid: i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ISpeechRecognizer_Impl::EmulateRecognition(this, core::mem::transmute(&textelements), core::mem::transmute_copy(&elementdisplayattributes), core::mem::transmute_copy(&languageid)).into()
             }
         }
-        unsafe extern "system" fn CreateRecoContext<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, newcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateRecoContext<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, newcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ISpeechRecognizer_Impl::CreateRecoContext(this) {
                     Ok(ok__) => {
                         newcontext.write(core::mem::transmute(ok__));
@@ -15004,9 +22471,9 @@ impl ISpeechRecognizer_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetFormat<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, r#type: SpeechFormatType, format: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetFormat<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, r#type: SpeechFormatType, format: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ISpeechRecognizer_Impl::GetFormat(this, core::mem::transmute_copy(&r#type)) {
                     Ok(ok__) => {
                         format.write(core::mem::transmute(ok__));
@@ -15016,9 +22483,9 @@ impl ISpeechRecognizer_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetPropertyNumber<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: i32, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetPropertyNumber<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: i32, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ISpeechRecognizer_Impl::SetPropertyNumber(this, core::mem::transmute(&name), core::mem::transmute_copy(&value)) {
                     Ok(ok__) => {
                         supported.write(core::mem::transmute(ok__));
@@ -15028,15 +22495,15 @@ impl ISpeechRecognizer_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetPropertyNumber<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: *mut i32, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetPropertyNumber<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: *mut i32, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ISpeechRecognizer_Impl::GetPropertyNumber(this, core::mem::transmute(&name), core::mem::transmute_copy(&value), core::mem::transmute_copy(&supported)).into()
             }
         }
-        unsafe extern "system" fn SetPropertyString<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: *mut core::ffi::c_void, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetPropertyString<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: *mut core::ffi::c_void, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ISpeechRecognizer_Impl::SetPropertyString(this, core::mem::transmute(&name), core::mem::transmute(&value)) {
                     Ok(ok__) => {
                         supported.write(core::mem::transmute(ok__));
@@ -15046,15 +22513,15 @@ impl ISpeechRecognizer_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetPropertyString<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetPropertyString<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ISpeechRecognizer_Impl::GetPropertyString(this, core::mem::transmute(&name), core::mem::transmute_copy(&value), core::mem::transmute_copy(&supported)).into()
             }
         }
-        unsafe extern "system" fn IsUISupported<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, typeofui: *mut core::ffi::c_void, extradata: *const super::super::System::Variant::VARIANT, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn IsUISupported<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, typeofui: *mut core::ffi::c_void, extradata: *const super::super::System::Variant::VARIANT, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ISpeechRecognizer_Impl::IsUISupported(this, core::mem::transmute(&typeofui), core::mem::transmute_copy(&extradata)) {
                     Ok(ok__) => {
                         supported.write(core::mem::transmute(ok__));
@@ -15064,15 +22531,15 @@ impl ISpeechRecognizer_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn DisplayUI<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hwndparent: i32, title: *mut core::ffi::c_void, typeofui: *mut core::ffi::c_void, extradata: *const super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn DisplayUI<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, hwndparent: i32, title: *mut core::ffi::c_void, typeofui: *mut core::ffi::c_void, extradata: *const super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ISpeechRecognizer_Impl::DisplayUI(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute(&title), core::mem::transmute(&typeofui), core::mem::transmute_copy(&extradata)).into()
             }
         }
-        unsafe extern "system" fn GetRecognizers<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, requiredattributes: *mut core::ffi::c_void, optionalattributes: *mut core::ffi::c_void, objecttokens: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetRecognizers<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, requiredattributes: *mut core::ffi::c_void, optionalattributes: *mut core::ffi::c_void, objecttokens: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ISpeechRecognizer_Impl::GetRecognizers(this, core::mem::transmute(&requiredattributes), core::mem::transmute(&optionalattributes)) {
                     Ok(ok__) => {
                         objecttokens.write(core::mem::transmute(ok__));
@@ -15082,9 +22549,9 @@ impl ISpeechRecognizer_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetAudioInputs<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, requiredattributes: *mut core::ffi::c_void, optionalattributes: *mut core::ffi::c_void, objecttokens: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetAudioInputs<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, requiredattributes: *mut core::ffi::c_void, optionalattributes: *mut core::ffi::c_void, objecttokens: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ISpeechRecognizer_Impl::GetAudioInputs(this, core::mem::transmute(&requiredattributes), core::mem::transmute(&optionalattributes)) {
                     Ok(ok__) => {
                         objecttokens.write(core::mem::transmute(ok__));
@@ -15094,9 +22561,9 @@ impl ISpeechRecognizer_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetProfiles<Identity: ISpeechRecognizer_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, requiredattributes: *mut core::ffi::c_void, optionalattributes: *mut core::ffi::c_void, objecttokens: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetProfiles<Identity: windows_core::IUnknownImpl + ISpeechRecognizer_Impl>(this: *mut core::ffi::c_void, requiredattributes: *mut core::ffi::c_void, optionalattributes: *mut core::ffi::c_void, objecttokens: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ISpeechRecognizer_Impl::GetProfiles(this, core::mem::transmute(&requiredattributes), core::mem::transmute(&optionalattributes)) {
                     Ok(ok__) => {
                         objecttokens.write(core::mem::transmute(ok__));
@@ -15107,38 +22574,35 @@ impl ISpeechRecognizer_Vtbl {
             }
         }
         Self {
-            base__: super::super::System::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
-            putref_Recognizer: putref_Recognizer::<Identity, OFFSET>,
-            Recognizer: Recognizer::<Identity, OFFSET>,
-            SetAllowAudioInputFormatChangesOnNextSet: SetAllowAudioInputFormatChangesOnNextSet::<Identity, OFFSET>,
-            AllowAudioInputFormatChangesOnNextSet: AllowAudioInputFormatChangesOnNextSet::<Identity, OFFSET>,
-            putref_AudioInput: putref_AudioInput::<Identity, OFFSET>,
-            AudioInput: AudioInput::<Identity, OFFSET>,
-            putref_AudioInputStream: putref_AudioInputStream::<Identity, OFFSET>,
-            AudioInputStream: AudioInputStream::<Identity, OFFSET>,
-            IsShared: IsShared::<Identity, OFFSET>,
-            SetState: SetState::<Identity, OFFSET>,
-            State: State::<Identity, OFFSET>,
-            Status: Status::<Identity, OFFSET>,
-            putref_Profile: putref_Profile::<Identity, OFFSET>,
-            Profile: Profile::<Identity, OFFSET>,
-            EmulateRecognition: EmulateRecognition::<Identity, OFFSET>,
-            CreateRecoContext: CreateRecoContext::<Identity, OFFSET>,
-            GetFormat: GetFormat::<Identity, OFFSET>,
-            SetPropertyNumber: SetPropertyNumber::<Identity, OFFSET>,
-            GetPropertyNumber: GetPropertyNumber::<Identity, OFFSET>,
-            SetPropertyString: SetPropertyString::<Identity, OFFSET>,
-            GetPropertyString: GetPropertyString::<Identity, OFFSET>,
-            IsUISupported: IsUISupported::<Identity, OFFSET>,
-            DisplayUI: DisplayUI::<Identity, OFFSET>,
-            GetRecognizers: GetRecognizers::<Identity, OFFSET>,
-            GetAudioInputs: GetAudioInputs::<Identity, OFFSET>,
-            GetProfiles: GetProfiles::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ISpeechRecognizer as windows_core::Interface>::IID || iid == &<super::super::System::Com::IDispatch as windows_core::Interface>::IID
-    }
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            putref_Recognizer: putref_Recognizer::<Identity>,
+            Recognizer: Recognizer::<Identity>,
+            SetAllowAudioInputFormatChangesOnNextSet: SetAllowAudioInputFormatChangesOnNextSet::<Identity>,
+            AllowAudioInputFormatChangesOnNextSet: AllowAudioInputFormatChangesOnNextSet::<Identity>,
+            putref_AudioInput: putref_AudioInput::<Identity>,
+            AudioInput: AudioInput::<Identity>,
+            putref_AudioInputStream: putref_AudioInputStream::<Identity>,
+            AudioInputStream: AudioInputStream::<Identity>,
+            IsShared: IsShared::<Identity>,
+            SetState: SetState::<Identity>,
+            State: State::<Identity>,
+            Status: Status::<Identity>,
+            putref_Profile: putref_Profile::<Identity>,
+            Profile: Profile::<Identity>,
+            EmulateRecognition: EmulateRecognition::<Identity>,
+            CreateRecoContext: CreateRecoContext::<Identity>,
+            GetFormat: GetFormat::<Identity>,
+            SetPropertyNumber: SetPropertyNumber::<Identity>,
+            GetPropertyNumber: GetPropertyNumber::<Identity>,
+            SetPropertyString: SetPropertyString::<Identity>,
+            GetPropertyString: GetPropertyString::<Identity>,
+            IsUISupported: IsUISupported::<Identity>,
+            DisplayUI: DisplayUI::<Identity>,
+            GetRecognizers: GetRecognizers::<Identity>,
+            GetAudioInputs: GetAudioInputs::<Identity>,
+            GetProfiles: GetProfiles::<Identity>,
+        }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechRecognizer {}
@@ -15311,6 +22775,95 @@ impl ISpeechRecognizerStatus_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechRecognizerStatus_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechRecognizerStatus_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AudioStatus<Identity: windows_core::IUnknownImpl + ISpeechRecognizerStatus_Impl>(this: *mut core::ffi::c_void, audiostatus: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecognizerStatus_Impl::AudioStatus(this) {
+                    Ok(ok__) => {
+                        audiostatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CurrentStreamPosition<Identity: windows_core::IUnknownImpl + ISpeechRecognizerStatus_Impl>(this: *mut core::ffi::c_void, pcurrentstreampos: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecognizerStatus_Impl::CurrentStreamPosition(this) {
+                    Ok(ok__) => {
+                        pcurrentstreampos.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CurrentStreamNumber<Identity: windows_core::IUnknownImpl + ISpeechRecognizerStatus_Impl>(this: *mut core::ffi::c_void, streamnumber: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecognizerStatus_Impl::CurrentStreamNumber(this) {
+                    Ok(ok__) => {
+                        streamnumber.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NumberOfActiveRules<Identity: windows_core::IUnknownImpl + ISpeechRecognizerStatus_Impl>(this: *mut core::ffi::c_void, numberofactiverules: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecognizerStatus_Impl::NumberOfActiveRules(this) {
+                    Ok(ok__) => {
+                        numberofactiverules.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ClsidEngine<Identity: windows_core::IUnknownImpl + ISpeechRecognizerStatus_Impl>(this: *mut core::ffi::c_void, clsidengine: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecognizerStatus_Impl::ClsidEngine(this) {
+                    Ok(ok__) => {
+                        clsidengine.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SupportedLanguages<Identity: windows_core::IUnknownImpl + ISpeechRecognizerStatus_Impl>(this: *mut core::ffi::c_void, supportedlanguages: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechRecognizerStatus_Impl::SupportedLanguages(this) {
+                    Ok(ok__) => {
+                        supportedlanguages.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AudioStatus: AudioStatus::<Identity>,
+            CurrentStreamPosition: CurrentStreamPosition::<Identity>,
+            CurrentStreamNumber: CurrentStreamNumber::<Identity>,
+            NumberOfActiveRules: NumberOfActiveRules::<Identity>,
+            ClsidEngine: ClsidEngine::<Identity>,
+            SupportedLanguages: SupportedLanguages::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechRecognizerStatus {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechResourceLoader, ISpeechResourceLoader_Vtbl, 0xb9ac5783_fcd0_4b21_b119_b4f8da8fd2c3);
@@ -15383,6 +22936,38 @@ impl ISpeechResourceLoader_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechResourceLoader_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechResourceLoader_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn LoadResource<Identity: windows_core::IUnknownImpl + ISpeechResourceLoader_Impl>(this: *mut core::ffi::c_void, bstrresourceuri: *mut core::ffi::c_void, falwaysreload: super::super::Foundation::VARIANT_BOOL, pstream: *mut *mut core::ffi::c_void, pbstrmimetype: *mut *mut core::ffi::c_void, pfmodified: *mut super::super::Foundation::VARIANT_BOOL, pbstrredirecturl: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechResourceLoader_Impl::LoadResource(this, core::mem::transmute(&bstrresourceuri), core::mem::transmute_copy(&falwaysreload), core::mem::transmute_copy(&pstream), core::mem::transmute_copy(&pbstrmimetype), core::mem::transmute_copy(&pfmodified), core::mem::transmute_copy(&pbstrredirecturl)).into()
+            }
+        }
+        unsafe extern "system" fn GetLocalCopy<Identity: windows_core::IUnknownImpl + ISpeechResourceLoader_Impl>(this: *mut core::ffi::c_void, bstrresourceuri: *mut core::ffi::c_void, pbstrlocalpath: *mut *mut core::ffi::c_void, pbstrmimetype: *mut *mut core::ffi::c_void, pbstrredirecturl: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechResourceLoader_Impl::GetLocalCopy(this, core::mem::transmute(&bstrresourceuri), core::mem::transmute_copy(&pbstrlocalpath), core::mem::transmute_copy(&pbstrmimetype), core::mem::transmute_copy(&pbstrredirecturl)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseLocalCopy<Identity: windows_core::IUnknownImpl + ISpeechResourceLoader_Impl>(this: *mut core::ffi::c_void, pbstrlocalpath: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechResourceLoader_Impl::ReleaseLocalCopy(this, core::mem::transmute(&pbstrlocalpath)).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            LoadResource: LoadResource::<Identity>,
+            GetLocalCopy: GetLocalCopy::<Identity>,
+            ReleaseLocalCopy: ReleaseLocalCopy::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechResourceLoader {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechTextSelectionInformation, ISpeechTextSelectionInformation_Vtbl, 0x3b9c7e7a_6eee_4ded_9092_11657279adbe);
@@ -15535,20 +23120,111 @@ impl ISpeechTextSelectionInformation_Vtbl {
             }
         }
         Self {
-            base__: super::super::System::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
-            SetActiveOffset: SetActiveOffset::<Identity, OFFSET>,
-            ActiveOffset: ActiveOffset::<Identity, OFFSET>,
-            SetActiveLength: SetActiveLength::<Identity, OFFSET>,
-            ActiveLength: ActiveLength::<Identity, OFFSET>,
-            SetSelectionOffset: SetSelectionOffset::<Identity, OFFSET>,
-            SelectionOffset: SelectionOffset::<Identity, OFFSET>,
-            SetSelectionLength: SetSelectionLength::<Identity, OFFSET>,
-            SelectionLength: SelectionLength::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ISpeechTextSelectionInformation as windows_core::Interface>::IID || iid == &<super::super::System::Com::IDispatch as windows_core::Interface>::IID
-    }
+            base__: super::super::System::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
+            SetActiveOffset: SetActiveOffset::<Identity, OFFSET>,
+            ActiveOffset: ActiveOffset::<Identity, OFFSET>,
+            SetActiveLength: SetActiveLength::<Identity, OFFSET>,
+            ActiveLength: ActiveLength::<Identity, OFFSET>,
+            SetSelectionOffset: SetSelectionOffset::<Identity, OFFSET>,
+            SelectionOffset: SelectionOffset::<Identity, OFFSET>,
+            SetSelectionLength: SetSelectionLength::<Identity, OFFSET>,
+            SelectionLength: SelectionLength::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechTextSelectionInformation as windows_core::Interface>::IID || iid == &<super::super::System::Com::IDispatch as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechTextSelectionInformation_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechTextSelectionInformation_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetActiveOffset<Identity: windows_core::IUnknownImpl + ISpeechTextSelectionInformation_Impl>(this: *mut core::ffi::c_void, activeoffset: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechTextSelectionInformation_Impl::SetActiveOffset(this, core::mem::transmute_copy(&activeoffset)).into()
+            }
+        }
+        unsafe extern "system" fn ActiveOffset<Identity: windows_core::IUnknownImpl + ISpeechTextSelectionInformation_Impl>(this: *mut core::ffi::c_void, activeoffset: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechTextSelectionInformation_Impl::ActiveOffset(this) {
+                    Ok(ok__) => {
+                        activeoffset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetActiveLength<Identity: windows_core::IUnknownImpl + ISpeechTextSelectionInformation_Impl>(this: *mut core::ffi::c_void, activelength: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechTextSelectionInformation_Impl::SetActiveLength(this, core::mem::transmute_copy(&activelength)).into()
+            }
+        }
+        unsafe extern "system" fn ActiveLength<Identity: windows_core::IUnknownImpl + ISpeechTextSelectionInformation_Impl>(this: *mut core::ffi::c_void, activelength: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechTextSelectionInformation_Impl::ActiveLength(this) {
+                    Ok(ok__) => {
+                        activelength.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSelectionOffset<Identity: windows_core::IUnknownImpl + ISpeechTextSelectionInformation_Impl>(this: *mut core::ffi::c_void, selectionoffset: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechTextSelectionInformation_Impl::SetSelectionOffset(this, core::mem::transmute_copy(&selectionoffset)).into()
+            }
+        }
+        unsafe extern "system" fn SelectionOffset<Identity: windows_core::IUnknownImpl + ISpeechTextSelectionInformation_Impl>(this: *mut core::ffi::c_void, selectionoffset: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechTextSelectionInformation_Impl::SelectionOffset(this) {
+                    Ok(ok__) => {
+                        selectionoffset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSelectionLength<Identity: windows_core::IUnknownImpl + ISpeechTextSelectionInformation_Impl>(this: *mut core::ffi::c_void, selectionlength: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechTextSelectionInformation_Impl::SetSelectionLength(this, core::mem::transmute_copy(&selectionlength)).into()
+            }
+        }
+        unsafe extern "system" fn SelectionLength<Identity: windows_core::IUnknownImpl + ISpeechTextSelectionInformation_Impl>(this: *mut core::ffi::c_void, selectionlength: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechTextSelectionInformation_Impl::SelectionLength(this) {
+                    Ok(ok__) => {
+                        selectionlength.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetActiveOffset: SetActiveOffset::<Identity>,
+            ActiveOffset: ActiveOffset::<Identity>,
+            SetActiveLength: SetActiveLength::<Identity>,
+            ActiveLength: ActiveLength::<Identity>,
+            SetSelectionOffset: SetSelectionOffset::<Identity>,
+            SelectionOffset: SelectionOffset::<Identity>,
+            SetSelectionLength: SetSelectionLength::<Identity>,
+            SelectionLength: SelectionLength::<Identity>,
+        }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechTextSelectionInformation {}
@@ -16162,6 +23838,355 @@ impl ISpeechVoice_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechVoice_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Status<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, status: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoice_Impl::Status(this) {
+                    Ok(ok__) => {
+                        status.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Voice<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, voice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoice_Impl::Voice(this) {
+                    Ok(ok__) => {
+                        voice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putref_Voice<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, voice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechVoice_Impl::putref_Voice(this, core::mem::transmute_copy(&voice)).into()
+            }
+        }
+        unsafe extern "system" fn AudioOutput<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, audiooutput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoice_Impl::AudioOutput(this) {
+                    Ok(ok__) => {
+                        audiooutput.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putref_AudioOutput<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, audiooutput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechVoice_Impl::putref_AudioOutput(this, core::mem::transmute_copy(&audiooutput)).into()
+            }
+        }
+        unsafe extern "system" fn AudioOutputStream<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, audiooutputstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoice_Impl::AudioOutputStream(this) {
+                    Ok(ok__) => {
+                        audiooutputstream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn putref_AudioOutputStream<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, audiooutputstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechVoice_Impl::putref_AudioOutputStream(this, core::mem::transmute_copy(&audiooutputstream)).into()
+            }
+        }
+        unsafe extern "system" fn Rate<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, rate: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoice_Impl::Rate(this) {
+                    Ok(ok__) => {
+                        rate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRate<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, rate: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechVoice_Impl::SetRate(this, core::mem::transmute_copy(&rate)).into()
+            }
+        }
+        unsafe extern "system" fn Volume<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, volume: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoice_Impl::Volume(this) {
+                    Ok(ok__) => {
+                        volume.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetVolume<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, volume: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechVoice_Impl::SetVolume(this, core::mem::transmute_copy(&volume)).into()
+            }
+        }
+        unsafe extern "system" fn SetAllowAudioOutputFormatChangesOnNextSet<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, allow: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechVoice_Impl::SetAllowAudioOutputFormatChangesOnNextSet(this, core::mem::transmute_copy(&allow)).into()
+            }
+        }
+        unsafe extern "system" fn AllowAudioOutputFormatChangesOnNextSet<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, allow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoice_Impl::AllowAudioOutputFormatChangesOnNextSet(this) {
+                    Ok(ok__) => {
+                        allow.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EventInterests<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, eventinterestflags: *mut SpeechVoiceEvents) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoice_Impl::EventInterests(this) {
+                    Ok(ok__) => {
+                        eventinterestflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEventInterests<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, eventinterestflags: SpeechVoiceEvents) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechVoice_Impl::SetEventInterests(this, core::mem::transmute_copy(&eventinterestflags)).into()
+            }
+        }
+        unsafe extern "system" fn SetPriority<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, priority: SpeechVoicePriority) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechVoice_Impl::SetPriority(this, core::mem::transmute_copy(&priority)).into()
+            }
+        }
+        unsafe extern "system" fn Priority<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, priority: *mut SpeechVoicePriority) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoice_Impl::Priority(this) {
+                    Ok(ok__) => {
+                        priority.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAlertBoundary<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, boundary: SpeechVoiceEvents) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechVoice_Impl::SetAlertBoundary(this, core::mem::transmute_copy(&boundary)).into()
+            }
+        }
+        unsafe extern "system" fn AlertBoundary<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, boundary: *mut SpeechVoiceEvents) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoice_Impl::AlertBoundary(this) {
+                    Ok(ok__) => {
+                        boundary.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSynchronousSpeakTimeout<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, mstimeout: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechVoice_Impl::SetSynchronousSpeakTimeout(this, core::mem::transmute_copy(&mstimeout)).into()
+            }
+        }
+        unsafe extern "system" fn SynchronousSpeakTimeout<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, mstimeout: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoice_Impl::SynchronousSpeakTimeout(this) {
+                    Ok(ok__) => {
+                        mstimeout.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Speak<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, text: *mut core::ffi::c_void, flags: SpeechVoiceSpeakFlags, streamnumber: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoice_Impl::Speak(this, core::mem::transmute(&text), core::mem::transmute_copy(&flags)) {
+                    Ok(ok__) => {
+                        streamnumber.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SpeakStream<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, flags: SpeechVoiceSpeakFlags, streamnumber: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoice_Impl::SpeakStream(this, core::mem::transmute_copy(&stream), core::mem::transmute_copy(&flags)) {
+                    Ok(ok__) => {
+                        streamnumber.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Pause<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechVoice_Impl::Pause(this).into()
+            }
+        }
+        unsafe extern "system" fn Resume<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechVoice_Impl::Resume(this).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, r#type: *mut core::ffi::c_void, numitems: i32, numskipped: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoice_Impl::Skip(this, core::mem::transmute(&r#type), core::mem::transmute_copy(&numitems)) {
+                    Ok(ok__) => {
+                        numskipped.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetVoices<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, requiredattributes: *mut core::ffi::c_void, optionalattributes: *mut core::ffi::c_void, objecttokens: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoice_Impl::GetVoices(this, core::mem::transmute(&requiredattributes), core::mem::transmute(&optionalattributes)) {
+                    Ok(ok__) => {
+                        objecttokens.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAudioOutputs<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, requiredattributes: *mut core::ffi::c_void, optionalattributes: *mut core::ffi::c_void, objecttokens: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoice_Impl::GetAudioOutputs(this, core::mem::transmute(&requiredattributes), core::mem::transmute(&optionalattributes)) {
+                    Ok(ok__) => {
+                        objecttokens.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn WaitUntilDone<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, mstimeout: i32, done: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoice_Impl::WaitUntilDone(this, core::mem::transmute_copy(&mstimeout)) {
+                    Ok(ok__) => {
+                        done.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SpeakCompleteEvent<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, handle: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoice_Impl::SpeakCompleteEvent(this) {
+                    Ok(ok__) => {
+                        handle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsUISupported<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, typeofui: *mut core::ffi::c_void, extradata: *const super::super::System::Variant::VARIANT, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoice_Impl::IsUISupported(this, core::mem::transmute(&typeofui), core::mem::transmute_copy(&extradata)) {
+                    Ok(ok__) => {
+                        supported.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DisplayUI<Identity: windows_core::IUnknownImpl + ISpeechVoice_Impl>(this: *mut core::ffi::c_void, hwndparent: i32, title: *mut core::ffi::c_void, typeofui: *mut core::ffi::c_void, extradata: *const super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechVoice_Impl::DisplayUI(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute(&title), core::mem::transmute(&typeofui), core::mem::transmute_copy(&extradata)).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Status: Status::<Identity>,
+            Voice: Voice::<Identity>,
+            putref_Voice: putref_Voice::<Identity>,
+            AudioOutput: AudioOutput::<Identity>,
+            putref_AudioOutput: putref_AudioOutput::<Identity>,
+            AudioOutputStream: AudioOutputStream::<Identity>,
+            putref_AudioOutputStream: putref_AudioOutputStream::<Identity>,
+            Rate: Rate::<Identity>,
+            SetRate: SetRate::<Identity>,
+            Volume: Volume::<Identity>,
+            SetVolume: SetVolume::<Identity>,
+            SetAllowAudioOutputFormatChangesOnNextSet: SetAllowAudioOutputFormatChangesOnNextSet::<Identity>,
+            AllowAudioOutputFormatChangesOnNextSet: AllowAudioOutputFormatChangesOnNextSet::<Identity>,
+            EventInterests: EventInterests::<Identity>,
+            SetEventInterests: SetEventInterests::<Identity>,
+            SetPriority: SetPriority::<Identity>,
+            Priority: Priority::<Identity>,
+            SetAlertBoundary: SetAlertBoundary::<Identity>,
+            AlertBoundary: AlertBoundary::<Identity>,
+            SetSynchronousSpeakTimeout: SetSynchronousSpeakTimeout::<Identity>,
+            SynchronousSpeakTimeout: SynchronousSpeakTimeout::<Identity>,
+            Speak: Speak::<Identity>,
+            SpeakStream: SpeakStream::<Identity>,
+            Pause: Pause::<Identity>,
+            Resume: Resume::<Identity>,
+            Skip: Skip::<Identity>,
+            GetVoices: GetVoices::<Identity>,
+            GetAudioOutputs: GetAudioOutputs::<Identity>,
+            WaitUntilDone: WaitUntilDone::<Identity>,
+            SpeakCompleteEvent: SpeakCompleteEvent::<Identity>,
+            IsUISupported: IsUISupported::<Identity>,
+            DisplayUI: DisplayUI::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechVoice {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechVoiceStatus, ISpeechVoiceStatus_Vtbl, 0x8be47b07_57f6_11d2_9eee_00c04f797396);
@@ -16450,6 +24475,173 @@ impl ISpeechVoiceStatus_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechVoiceStatus_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechVoiceStatus_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CurrentStreamNumber<Identity: windows_core::IUnknownImpl + ISpeechVoiceStatus_Impl>(this: *mut core::ffi::c_void, streamnumber: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoiceStatus_Impl::CurrentStreamNumber(this) {
+                    Ok(ok__) => {
+                        streamnumber.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LastStreamNumberQueued<Identity: windows_core::IUnknownImpl + ISpeechVoiceStatus_Impl>(this: *mut core::ffi::c_void, streamnumber: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoiceStatus_Impl::LastStreamNumberQueued(this) {
+                    Ok(ok__) => {
+                        streamnumber.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LastHResult<Identity: windows_core::IUnknownImpl + ISpeechVoiceStatus_Impl>(this: *mut core::ffi::c_void, hresult: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoiceStatus_Impl::LastHResult(this) {
+                    Ok(ok__) => {
+                        hresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RunningState<Identity: windows_core::IUnknownImpl + ISpeechVoiceStatus_Impl>(this: *mut core::ffi::c_void, state: *mut SpeechRunState) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoiceStatus_Impl::RunningState(this) {
+                    Ok(ok__) => {
+                        state.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InputWordPosition<Identity: windows_core::IUnknownImpl + ISpeechVoiceStatus_Impl>(this: *mut core::ffi::c_void, position: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoiceStatus_Impl::InputWordPosition(this) {
+                    Ok(ok__) => {
+                        position.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InputWordLength<Identity: windows_core::IUnknownImpl + ISpeechVoiceStatus_Impl>(this: *mut core::ffi::c_void, length: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoiceStatus_Impl::InputWordLength(this) {
+                    Ok(ok__) => {
+                        length.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InputSentencePosition<Identity: windows_core::IUnknownImpl + ISpeechVoiceStatus_Impl>(this: *mut core::ffi::c_void, position: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoiceStatus_Impl::InputSentencePosition(this) {
+                    Ok(ok__) => {
+                        position.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InputSentenceLength<Identity: windows_core::IUnknownImpl + ISpeechVoiceStatus_Impl>(this: *mut core::ffi::c_void, length: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoiceStatus_Impl::InputSentenceLength(this) {
+                    Ok(ok__) => {
+                        length.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LastBookmark<Identity: windows_core::IUnknownImpl + ISpeechVoiceStatus_Impl>(this: *mut core::ffi::c_void, bookmark: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoiceStatus_Impl::LastBookmark(this) {
+                    Ok(ok__) => {
+                        bookmark.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LastBookmarkId<Identity: windows_core::IUnknownImpl + ISpeechVoiceStatus_Impl>(this: *mut core::ffi::c_void, bookmarkid: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoiceStatus_Impl::LastBookmarkId(this) {
+                    Ok(ok__) => {
+                        bookmarkid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PhonemeId<Identity: windows_core::IUnknownImpl + ISpeechVoiceStatus_Impl>(this: *mut core::ffi::c_void, phoneid: *mut i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoiceStatus_Impl::PhonemeId(this) {
+                    Ok(ok__) => {
+                        phoneid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn VisemeId<Identity: windows_core::IUnknownImpl + ISpeechVoiceStatus_Impl>(this: *mut core::ffi::c_void, visemeid: *mut i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechVoiceStatus_Impl::VisemeId(this) {
+                    Ok(ok__) => {
+                        visemeid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CurrentStreamNumber: CurrentStreamNumber::<Identity>,
+            LastStreamNumberQueued: LastStreamNumberQueued::<Identity>,
+            LastHResult: LastHResult::<Identity>,
+            RunningState: RunningState::<Identity>,
+            InputWordPosition: InputWordPosition::<Identity>,
+            InputWordLength: InputWordLength::<Identity>,
+            InputSentencePosition: InputSentencePosition::<Identity>,
+            InputSentenceLength: InputSentenceLength::<Identity>,
+            LastBookmark: LastBookmark::<Identity>,
+            LastBookmarkId: LastBookmarkId::<Identity>,
+            PhonemeId: PhonemeId::<Identity>,
+            VisemeId: VisemeId::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechVoiceStatus {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechWaveFormatEx, ISpeechWaveFormatEx_Vtbl, 0x7a1ef0d5_1581_4741_88e4_209a49f11a10);
@@ -16725,6 +24917,157 @@ impl ISpeechWaveFormatEx_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechWaveFormatEx_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechWaveFormatEx_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FormatTag<Identity: windows_core::IUnknownImpl + ISpeechWaveFormatEx_Impl>(this: *mut core::ffi::c_void, formattag: *mut i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechWaveFormatEx_Impl::FormatTag(this) {
+                    Ok(ok__) => {
+                        formattag.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetFormatTag<Identity: windows_core::IUnknownImpl + ISpeechWaveFormatEx_Impl>(this: *mut core::ffi::c_void, formattag: i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechWaveFormatEx_Impl::SetFormatTag(this, core::mem::transmute_copy(&formattag)).into()
+            }
+        }
+        unsafe extern "system" fn Channels<Identity: windows_core::IUnknownImpl + ISpeechWaveFormatEx_Impl>(this: *mut core::ffi::c_void, channels: *mut i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechWaveFormatEx_Impl::Channels(this) {
+                    Ok(ok__) => {
+                        channels.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetChannels<Identity: windows_core::IUnknownImpl + ISpeechWaveFormatEx_Impl>(this: *mut core::ffi::c_void, channels: i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechWaveFormatEx_Impl::SetChannels(this, core::mem::transmute_copy(&channels)).into()
+            }
+        }
+        unsafe extern "system" fn SamplesPerSec<Identity: windows_core::IUnknownImpl + ISpeechWaveFormatEx_Impl>(this: *mut core::ffi::c_void, samplespersec: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechWaveFormatEx_Impl::SamplesPerSec(this) {
+                    Ok(ok__) => {
+                        samplespersec.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSamplesPerSec<Identity: windows_core::IUnknownImpl + ISpeechWaveFormatEx_Impl>(this: *mut core::ffi::c_void, samplespersec: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechWaveFormatEx_Impl::SetSamplesPerSec(this, core::mem::transmute_copy(&samplespersec)).into()
+            }
+        }
+        unsafe extern "system" fn AvgBytesPerSec<Identity: windows_core::IUnknownImpl + ISpeechWaveFormatEx_Impl>(this: *mut core::ffi::c_void, avgbytespersec: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechWaveFormatEx_Impl::AvgBytesPerSec(this) {
+                    Ok(ok__) => {
+                        avgbytespersec.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAvgBytesPerSec<Identity: windows_core::IUnknownImpl + ISpeechWaveFormatEx_Impl>(this: *mut core::ffi::c_void, avgbytespersec: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechWaveFormatEx_Impl::SetAvgBytesPerSec(this, core::mem::transmute_copy(&avgbytespersec)).into()
+            }
+        }
+        unsafe extern "system" fn BlockAlign<Identity: windows_core::IUnknownImpl + ISpeechWaveFormatEx_Impl>(this: *mut core::ffi::c_void, blockalign: *mut i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechWaveFormatEx_Impl::BlockAlign(this) {
+                    Ok(ok__) => {
+                        blockalign.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBlockAlign<Identity: windows_core::IUnknownImpl + ISpeechWaveFormatEx_Impl>(this: *mut core::ffi::c_void, blockalign: i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechWaveFormatEx_Impl::SetBlockAlign(this, core::mem::transmute_copy(&blockalign)).into()
+            }
+        }
+        unsafe extern "system" fn BitsPerSample<Identity: windows_core::IUnknownImpl + ISpeechWaveFormatEx_Impl>(this: *mut core::ffi::c_void, bitspersample: *mut i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechWaveFormatEx_Impl::BitsPerSample(this) {
+                    Ok(ok__) => {
+                        bitspersample.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBitsPerSample<Identity: windows_core::IUnknownImpl + ISpeechWaveFormatEx_Impl>(this: *mut core::ffi::c_void, bitspersample: i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechWaveFormatEx_Impl::SetBitsPerSample(this, core::mem::transmute_copy(&bitspersample)).into()
+            }
+        }
+        unsafe extern "system" fn ExtraData<Identity: windows_core::IUnknownImpl + ISpeechWaveFormatEx_Impl>(this: *mut core::ffi::c_void, extradata: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechWaveFormatEx_Impl::ExtraData(this) {
+                    Ok(ok__) => {
+                        extradata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetExtraData<Identity: windows_core::IUnknownImpl + ISpeechWaveFormatEx_Impl>(this: *mut core::ffi::c_void, extradata: super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechWaveFormatEx_Impl::SetExtraData(this, core::mem::transmute(&extradata)).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            FormatTag: FormatTag::<Identity>,
+            SetFormatTag: SetFormatTag::<Identity>,
+            Channels: Channels::<Identity>,
+            SetChannels: SetChannels::<Identity>,
+            SamplesPerSec: SamplesPerSec::<Identity>,
+            SetSamplesPerSec: SetSamplesPerSec::<Identity>,
+            AvgBytesPerSec: AvgBytesPerSec::<Identity>,
+            SetAvgBytesPerSec: SetAvgBytesPerSec::<Identity>,
+            BlockAlign: BlockAlign::<Identity>,
+            SetBlockAlign: SetBlockAlign::<Identity>,
+            BitsPerSample: BitsPerSample::<Identity>,
+            SetBitsPerSample: SetBitsPerSample::<Identity>,
+            ExtraData: ExtraData::<Identity>,
+            SetExtraData: SetExtraData::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechWaveFormatEx {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISpeechXMLRecoResult, ISpeechXMLRecoResult_Vtbl, 0xaaec54af_8f85_4924_944d_b79d39d72e19);
@@ -16794,6 +25137,37 @@ impl ISpeechXMLRecoResult_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISpeechXMLRecoResult_Impl> windows_core::imp::TearOffVtable<Identity> for ISpeechXMLRecoResult_Vtbl
+where
+    ISpeechRecoResult_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetXMLResult<Identity: windows_core::IUnknownImpl + ISpeechXMLRecoResult_Impl>(this: *mut core::ffi::c_void, options: SPXMLRESULTOPTIONS, presult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpeechXMLRecoResult_Impl::GetXMLResult(this, core::mem::transmute_copy(&options)) {
+                    Ok(ok__) => {
+                        presult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetXMLErrorInfo<Identity: windows_core::IUnknownImpl + ISpeechXMLRecoResult_Impl>(this: *mut core::ffi::c_void, linenumber: *mut i32, scriptline: *mut *mut core::ffi::c_void, source: *mut *mut core::ffi::c_void, description: *mut *mut core::ffi::c_void, resultcode: *mut i32, iserror: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISpeechXMLRecoResult_Impl::GetXMLErrorInfo(this, core::mem::transmute_copy(&linenumber), core::mem::transmute_copy(&scriptline), core::mem::transmute_copy(&source), core::mem::transmute_copy(&description), core::mem::transmute_copy(&resultcode), core::mem::transmute_copy(&iserror)).into()
+            }
+        }
+        Self {
+            base__: <ISpeechRecoResult_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetXMLResult: GetXMLResult::<Identity>,
+            GetXMLErrorInfo: GetXMLErrorInfo::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISpeechXMLRecoResult {}
 pub const OrderedSubset: SPMATCHINGMODE = SPMATCHINGMODE(3i32);
 pub const OrderedSubsetContentRequired: SPMATCHINGMODE = SPMATCHINGMODE(7i32);
@@ -18613,6 +26987,30 @@ impl _ISpPrivateEngineCall_Vtbl {
         iid == &<_ISpPrivateEngineCall as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + _ISpPrivateEngineCall_Impl> windows_core::imp::TearOffVtable<Identity> for _ISpPrivateEngineCall_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CallEngine<Identity: windows_core::IUnknownImpl + _ISpPrivateEngineCall_Impl>(this: *mut core::ffi::c_void, pcallframe: *mut core::ffi::c_void, ulcallframesize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                _ISpPrivateEngineCall_Impl::CallEngine(this, core::mem::transmute_copy(&pcallframe), core::mem::transmute_copy(&ulcallframesize)).into()
+            }
+        }
+        unsafe extern "system" fn CallEngineEx<Identity: windows_core::IUnknownImpl + _ISpPrivateEngineCall_Impl>(this: *mut core::ffi::c_void, pinframe: *const core::ffi::c_void, ulinframesize: u32, ppcomemoutframe: *mut *mut core::ffi::c_void, puloutframesize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                _ISpPrivateEngineCall_Impl::CallEngineEx(this, core::mem::transmute_copy(&pinframe), core::mem::transmute_copy(&ulinframesize), core::mem::transmute_copy(&ppcomemoutframe), core::mem::transmute_copy(&puloutframesize)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CallEngine: CallEngine::<Identity>,
+            CallEngineEx: CallEngineEx::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for _ISpPrivateEngineCall {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(_ISpeechRecoContextEvents, _ISpeechRecoContextEvents_Vtbl, 0x7b8fcb42_0e9d_4f00_a048_7b04d6179d3d);
@@ -18643,6 +27041,13 @@ impl _ISpeechRecoContextEvents_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + _ISpeechRecoContextEvents_Impl> windows_core::imp::TearOffVtable<Identity> for _ISpeechRecoContextEvents_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for _ISpeechRecoContextEvents {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(_ISpeechVoiceEvents, _ISpeechVoiceEvents_Vtbl, 0xa372acd1_3bef_4bbd_8ffb_cb3e2b416af8);
@@ -18673,6 +27078,13 @@ impl _ISpeechVoiceEvents_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + _ISpeechVoiceEvents_Impl> windows_core::imp::TearOffVtable<Identity> for _ISpeechVoiceEvents_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for _ISpeechVoiceEvents {}
 pub const eLEXTYPE_APP: SPLEXICONTYPE = SPLEXICONTYPE(2i32);
 pub const eLEXTYPE_LETTERTOSOUND: SPLEXICONTYPE = SPLEXICONTYPE(8i32);
@@ -353,6 +353,97 @@ impl INSNetSourceCreator_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + INSNetSourceCreator_Impl> windows_core::imp::TearOffVtable<Identity> for INSNetSourceCreator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + INSNetSourceCreator_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INSNetSourceCreator_Impl::Initialize(this).into()
+            }
+        }
+        unsafe extern "system" fn CreateNetSource<Identity: windows_core::IUnknownImpl + INSNetSourceCreator_Impl>(this: *mut core::ffi::c_void, pszstreamname: windows_core::PCWSTR, pmonitor: *mut core::ffi::c_void, pdata: *const u8, pusercontext: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, qwcontext: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INSNetSourceCreator_Impl::CreateNetSource(this, core::mem::transmute(&pszstreamname), core::mem::transmute_copy(&pmonitor), core::mem::transmute_copy(&pdata), core::mem::transmute_copy(&pusercontext), core::mem::transmute_copy(&pcallback), core::mem::transmute_copy(&qwcontext)).into()
+            }
+        }
+        unsafe extern "system" fn GetNetSourceProperties<Identity: windows_core::IUnknownImpl + INSNetSourceCreator_Impl>(this: *mut core::ffi::c_void, pszstreamname: windows_core::PCWSTR, pppropertiesnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INSNetSourceCreator_Impl::GetNetSourceProperties(this, core::mem::transmute(&pszstreamname)) {
+                    Ok(ok__) => {
+                        pppropertiesnode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetNetSourceSharedNamespace<Identity: windows_core::IUnknownImpl + INSNetSourceCreator_Impl>(this: *mut core::ffi::c_void, ppsharednamespace: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INSNetSourceCreator_Impl::GetNetSourceSharedNamespace(this) {
+                    Ok(ok__) => {
+                        ppsharednamespace.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetNetSourceAdminInterface<Identity: windows_core::IUnknownImpl + INSNetSourceCreator_Impl>(this: *mut core::ffi::c_void, pszstreamname: windows_core::PCWSTR, pval: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INSNetSourceCreator_Impl::GetNetSourceAdminInterface(this, core::mem::transmute(&pszstreamname)) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetNumProtocolsSupported<Identity: windows_core::IUnknownImpl + INSNetSourceCreator_Impl>(this: *mut core::ffi::c_void, pcprotocols: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INSNetSourceCreator_Impl::GetNumProtocolsSupported(this) {
+                    Ok(ok__) => {
+                        pcprotocols.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetProtocolName<Identity: windows_core::IUnknownImpl + INSNetSourceCreator_Impl>(this: *mut core::ffi::c_void, dwprotocolnum: u32, pwszprotocolname: windows_core::PWSTR, pcchprotocolname: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INSNetSourceCreator_Impl::GetProtocolName(this, core::mem::transmute_copy(&dwprotocolnum), core::mem::transmute_copy(&pwszprotocolname), core::mem::transmute_copy(&pcchprotocolname)).into()
+            }
+        }
+        unsafe extern "system" fn Shutdown<Identity: windows_core::IUnknownImpl + INSNetSourceCreator_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INSNetSourceCreator_Impl::Shutdown(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            CreateNetSource: CreateNetSource::<Identity>,
+            GetNetSourceProperties: GetNetSourceProperties::<Identity>,
+            GetNetSourceSharedNamespace: GetNetSourceSharedNamespace::<Identity>,
+            GetNetSourceAdminInterface: GetNetSourceAdminInterface::<Identity>,
+            GetNumProtocolsSupported: GetNumProtocolsSupported::<Identity>,
+            GetProtocolName: GetProtocolName::<Identity>,
+            Shutdown: Shutdown::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for INSNetSourceCreator {}
 windows_core::imp::define_interface!(INSSBuffer, INSSBuffer_Vtbl, 0xe1cd3524_03d7_11d2_9eed_006097d2d7cf);
 windows_core::imp::interface_hierarchy!(INSSBuffer, windows_core::IUnknown);
@@ -462,6 +553,69 @@ impl INSSBuffer_Vtbl {
         iid == &<INSSBuffer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INSSBuffer_Impl> windows_core::imp::TearOffVtable<Identity> for INSSBuffer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetLength<Identity: windows_core::IUnknownImpl + INSSBuffer_Impl>(this: *mut core::ffi::c_void, pdwlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INSSBuffer_Impl::GetLength(this) {
+                    Ok(ok__) => {
+                        pdwlength.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLength<Identity: windows_core::IUnknownImpl + INSSBuffer_Impl>(this: *mut core::ffi::c_void, dwlength: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INSSBuffer_Impl::SetLength(this, core::mem::transmute_copy(&dwlength)).into()
+            }
+        }
+        unsafe extern "system" fn GetMaxLength<Identity: windows_core::IUnknownImpl + INSSBuffer_Impl>(this: *mut core::ffi::c_void, pdwlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INSSBuffer_Impl::GetMaxLength(this) {
+                    Ok(ok__) => {
+                        pdwlength.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetBuffer<Identity: windows_core::IUnknownImpl + INSSBuffer_Impl>(this: *mut core::ffi::c_void, ppdwbuffer: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INSSBuffer_Impl::GetBuffer(this) {
+                    Ok(ok__) => {
+                        ppdwbuffer.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetBufferAndLength<Identity: windows_core::IUnknownImpl + INSSBuffer_Impl>(this: *mut core::ffi::c_void, ppdwbuffer: *mut *mut u8, pdwlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INSSBuffer_Impl::GetBufferAndLength(this, core::mem::transmute_copy(&ppdwbuffer), core::mem::transmute_copy(&pdwlength)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetLength: GetLength::<Identity>,
+            SetLength: SetLength::<Identity>,
+            GetMaxLength: GetMaxLength::<Identity>,
+            GetBuffer: GetBuffer::<Identity>,
+            GetBufferAndLength: GetBufferAndLength::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for INSSBuffer {}
 windows_core::imp::define_interface!(INSSBuffer2, INSSBuffer2_Vtbl, 0x4f528693_1035_43fe_b428_757561ad3a68);
 impl core::ops::Deref for INSSBuffer2 {
@@ -523,6 +677,36 @@ impl INSSBuffer2_Vtbl {
         iid == &<INSSBuffer2 as windows_core::Interface>::IID || iid == &<INSSBuffer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INSSBuffer2_Impl> windows_core::imp::TearOffVtable<Identity> for INSSBuffer2_Vtbl
+where
+    INSSBuffer_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSampleProperties<Identity: windows_core::IUnknownImpl + INSSBuffer2_Impl>(this: *mut core::ffi::c_void, cbproperties: u32, pbproperties: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INSSBuffer2_Impl::GetSampleProperties(this, core::mem::transmute_copy(&cbproperties)) {
+                    Ok(ok__) => {
+                        pbproperties.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSampleProperties<Identity: windows_core::IUnknownImpl + INSSBuffer2_Impl>(this: *mut core::ffi::c_void, cbproperties: u32, pbproperties: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INSSBuffer2_Impl::SetSampleProperties(this, core::mem::transmute_copy(&cbproperties), core::mem::transmute_copy(&pbproperties)).into()
+            }
+        }
+        Self {
+            base__: <INSSBuffer_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSampleProperties: GetSampleProperties::<Identity>,
+            SetSampleProperties: SetSampleProperties::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for INSSBuffer2 {}
 windows_core::imp::define_interface!(INSSBuffer3, INSSBuffer3_Vtbl, 0xc87ceaaf_75be_4bc4_84eb_ac2798507672);
 impl core::ops::Deref for INSSBuffer3 {
@@ -571,6 +755,30 @@ impl INSSBuffer3_Vtbl {
         iid == &<INSSBuffer3 as windows_core::Interface>::IID || iid == &<INSSBuffer as windows_core::Interface>::IID || iid == &<INSSBuffer2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INSSBuffer3_Impl> windows_core::imp::TearOffVtable<Identity> for INSSBuffer3_Vtbl
+where
+    INSSBuffer2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetProperty<Identity: windows_core::IUnknownImpl + INSSBuffer3_Impl>(this: *mut core::ffi::c_void, guidbufferproperty: windows_core::GUID, pvbufferproperty: *const core::ffi::c_void, dwbufferpropertysize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INSSBuffer3_Impl::SetProperty(this, core::mem::transmute(&guidbufferproperty), core::mem::transmute_copy(&pvbufferproperty), core::mem::transmute_copy(&dwbufferpropertysize)).into()
+            }
+        }
+        unsafe extern "system" fn GetProperty<Identity: windows_core::IUnknownImpl + INSSBuffer3_Impl>(this: *mut core::ffi::c_void, guidbufferproperty: windows_core::GUID, pvbufferproperty: *mut core::ffi::c_void, pdwbufferpropertysize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INSSBuffer3_Impl::GetProperty(this, core::mem::transmute(&guidbufferproperty), core::mem::transmute_copy(&pvbufferproperty), core::mem::transmute_copy(&pdwbufferpropertysize)).into()
+            }
+        }
+        Self {
+            base__: <INSSBuffer2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetProperty: SetProperty::<Identity>,
+            GetProperty: GetProperty::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for INSSBuffer3 {}
 windows_core::imp::define_interface!(INSSBuffer4, INSSBuffer4_Vtbl, 0xb6b8fd5a_32e2_49d4_a910_c26cc85465ed);
 impl core::ops::Deref for INSSBuffer4 {
@@ -632,6 +840,36 @@ impl INSSBuffer4_Vtbl {
         iid == &<INSSBuffer4 as windows_core::Interface>::IID || iid == &<INSSBuffer as windows_core::Interface>::IID || iid == &<INSSBuffer2 as windows_core::Interface>::IID || iid == &<INSSBuffer3 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INSSBuffer4_Impl> windows_core::imp::TearOffVtable<Identity> for INSSBuffer4_Vtbl
+where
+    INSSBuffer3_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPropertyCount<Identity: windows_core::IUnknownImpl + INSSBuffer4_Impl>(this: *mut core::ffi::c_void, pcbufferproperties: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INSSBuffer4_Impl::GetPropertyCount(this) {
+                    Ok(ok__) => {
+                        pcbufferproperties.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPropertyByIndex<Identity: windows_core::IUnknownImpl + INSSBuffer4_Impl>(this: *mut core::ffi::c_void, dwbufferpropertyindex: u32, pguidbufferproperty: *mut windows_core::GUID, pvbufferproperty: *mut core::ffi::c_void, pdwbufferpropertysize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INSSBuffer4_Impl::GetPropertyByIndex(this, core::mem::transmute_copy(&dwbufferpropertyindex), core::mem::transmute_copy(&pguidbufferproperty), core::mem::transmute_copy(&pvbufferproperty), core::mem::transmute_copy(&pdwbufferpropertysize)).into()
+            }
+        }
+        Self {
+            base__: <INSSBuffer3_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPropertyCount: GetPropertyCount::<Identity>,
+            GetPropertyByIndex: GetPropertyByIndex::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for INSSBuffer4 {}
 windows_core::imp::define_interface!(IWMAddressAccess, IWMAddressAccess_Vtbl, 0xbb3c6389_1633_4e92_af14_9f3173ba39d0);
 windows_core::imp::interface_hierarchy!(IWMAddressAccess, windows_core::IUnknown);
@@ -720,6 +958,56 @@ impl IWMAddressAccess_Vtbl {
         iid == &<IWMAddressAccess as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMAddressAccess_Impl> windows_core::imp::TearOffVtable<Identity> for IWMAddressAccess_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAccessEntryCount<Identity: windows_core::IUnknownImpl + IWMAddressAccess_Impl>(this: *mut core::ffi::c_void, aetype: WM_AETYPE, pcentries: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMAddressAccess_Impl::GetAccessEntryCount(this, core::mem::transmute_copy(&aetype)) {
+                    Ok(ok__) => {
+                        pcentries.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAccessEntry<Identity: windows_core::IUnknownImpl + IWMAddressAccess_Impl>(this: *mut core::ffi::c_void, aetype: WM_AETYPE, dwentrynum: u32, paddraccessentry: *mut WM_ADDRESS_ACCESSENTRY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMAddressAccess_Impl::GetAccessEntry(this, core::mem::transmute_copy(&aetype), core::mem::transmute_copy(&dwentrynum)) {
+                    Ok(ok__) => {
+                        paddraccessentry.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddAccessEntry<Identity: windows_core::IUnknownImpl + IWMAddressAccess_Impl>(this: *mut core::ffi::c_void, aetype: WM_AETYPE, paddraccessentry: *const WM_ADDRESS_ACCESSENTRY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMAddressAccess_Impl::AddAccessEntry(this, core::mem::transmute_copy(&aetype), core::mem::transmute_copy(&paddraccessentry)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveAccessEntry<Identity: windows_core::IUnknownImpl + IWMAddressAccess_Impl>(this: *mut core::ffi::c_void, aetype: WM_AETYPE, dwentrynum: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMAddressAccess_Impl::RemoveAccessEntry(this, core::mem::transmute_copy(&aetype), core::mem::transmute_copy(&dwentrynum)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAccessEntryCount: GetAccessEntryCount::<Identity>,
+            GetAccessEntry: GetAccessEntry::<Identity>,
+            AddAccessEntry: AddAccessEntry::<Identity>,
+            RemoveAccessEntry: RemoveAccessEntry::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMAddressAccess {}
 windows_core::imp::define_interface!(IWMAddressAccess2, IWMAddressAccess2_Vtbl, 0x65a83fc2_3e98_4d4d_81b5_2a742886b33d);
 impl core::ops::Deref for IWMAddressAccess2 {
@@ -772,6 +1060,30 @@ impl IWMAddressAccess2_Vtbl {
         iid == &<IWMAddressAccess2 as windows_core::Interface>::IID || iid == &<IWMAddressAccess as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMAddressAccess2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMAddressAccess2_Vtbl
+where
+    IWMAddressAccess_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAccessEntryEx<Identity: windows_core::IUnknownImpl + IWMAddressAccess2_Impl>(this: *mut core::ffi::c_void, aetype: WM_AETYPE, dwentrynum: u32, pbstraddress: *mut *mut core::ffi::c_void, pbstrmask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMAddressAccess2_Impl::GetAccessEntryEx(this, core::mem::transmute_copy(&aetype), core::mem::transmute_copy(&dwentrynum), core::mem::transmute_copy(&pbstraddress), core::mem::transmute_copy(&pbstrmask)).into()
+            }
+        }
+        unsafe extern "system" fn AddAccessEntryEx<Identity: windows_core::IUnknownImpl + IWMAddressAccess2_Impl>(this: *mut core::ffi::c_void, aetype: WM_AETYPE, bstraddress: *mut core::ffi::c_void, bstrmask: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMAddressAccess2_Impl::AddAccessEntryEx(this, core::mem::transmute_copy(&aetype), core::mem::transmute(&bstraddress), core::mem::transmute(&bstrmask)).into()
+            }
+        }
+        Self {
+            base__: <IWMAddressAccess_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAccessEntryEx: GetAccessEntryEx::<Identity>,
+            AddAccessEntryEx: AddAccessEntryEx::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMAddressAccess2 {}
 windows_core::imp::define_interface!(IWMAuthorizer, IWMAuthorizer_Vtbl, 0xd9b67d36_a9ad_4eb4_baef_db284ef5504c);
 windows_core::imp::interface_hierarchy!(IWMAuthorizer, windows_core::IUnknown);
@@ -857,6 +1169,55 @@ impl IWMAuthorizer_Vtbl {
         iid == &<IWMAuthorizer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMAuthorizer_Impl> windows_core::imp::TearOffVtable<Identity> for IWMAuthorizer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCertCount<Identity: windows_core::IUnknownImpl + IWMAuthorizer_Impl>(this: *mut core::ffi::c_void, pccerts: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMAuthorizer_Impl::GetCertCount(this) {
+                    Ok(ok__) => {
+                        pccerts.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCert<Identity: windows_core::IUnknownImpl + IWMAuthorizer_Impl>(this: *mut core::ffi::c_void, dwindex: u32, ppbcertdata: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMAuthorizer_Impl::GetCert(this, core::mem::transmute_copy(&dwindex)) {
+                    Ok(ok__) => {
+                        ppbcertdata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSharedData<Identity: windows_core::IUnknownImpl + IWMAuthorizer_Impl>(this: *mut core::ffi::c_void, dwcertindex: u32, pbshareddata: *const u8, pbcert: *const u8, ppbshareddata: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMAuthorizer_Impl::GetSharedData(this, core::mem::transmute_copy(&dwcertindex), core::mem::transmute_copy(&pbshareddata), core::mem::transmute_copy(&pbcert)) {
+                    Ok(ok__) => {
+                        ppbshareddata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCertCount: GetCertCount::<Identity>,
+            GetCert: GetCert::<Identity>,
+            GetSharedData: GetSharedData::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMAuthorizer {}
 windows_core::imp::define_interface!(IWMBackupRestoreProps, IWMBackupRestoreProps_Vtbl, 0x3c8e0da6_996f_4ff3_a1af_4838f9377e2e);
 windows_core::imp::interface_hierarchy!(IWMBackupRestoreProps, windows_core::IUnknown);
@@ -969,26 +1330,84 @@ impl IWMBackupRestoreProps_Vtbl {
         iid == &<IWMBackupRestoreProps as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IWMBackupRestoreProps {}
-windows_core::imp::define_interface!(IWMBandwidthSharing, IWMBandwidthSharing_Vtbl, 0xad694af1_f8d9_42f8_bc47_70311b0c4f9e);
-impl core::ops::Deref for IWMBandwidthSharing {
-    type Target = IWMStreamList;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(IWMBandwidthSharing, windows_core::IUnknown, IWMStreamList);
-impl IWMBandwidthSharing {
-    pub unsafe fn GetType(&self) -> windows_core::Result<windows_core::GUID> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetType)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
-        }
-    }
-    pub unsafe fn SetType(&self, guidtype: *const windows_core::GUID) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetType)(windows_core::Interface::as_raw(self), guidtype).ok() }
-    }
-    pub unsafe fn GetBandwidth(&self, pdwbitrate: *mut u32, pmsbufferwindow: *mut u32) -> windows_core::Result<()> {
+impl<Identity: windows_core::IUnknownImpl + IWMBackupRestoreProps_Impl> windows_core::imp::TearOffVtable<Identity> for IWMBackupRestoreProps_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPropCount<Identity: windows_core::IUnknownImpl + IWMBackupRestoreProps_Impl>(this: *mut core::ffi::c_void, pcprops: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMBackupRestoreProps_Impl::GetPropCount(this) {
+                    Ok(ok__) => {
+                        pcprops.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPropByIndex<Identity: windows_core::IUnknownImpl + IWMBackupRestoreProps_Impl>(this: *mut core::ffi::c_void, windex: u16, pwszname: windows_core::PWSTR, pcchnamelen: *mut u16, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pcblength: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMBackupRestoreProps_Impl::GetPropByIndex(this, core::mem::transmute_copy(&windex), core::mem::transmute_copy(&pwszname), core::mem::transmute_copy(&pcchnamelen), core::mem::transmute_copy(&ptype), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&pcblength)).into()
+            }
+        }
+        unsafe extern "system" fn GetPropByName<Identity: windows_core::IUnknownImpl + IWMBackupRestoreProps_Impl>(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pcblength: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMBackupRestoreProps_Impl::GetPropByName(this, core::mem::transmute(&pszname), core::mem::transmute_copy(&ptype), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&pcblength)).into()
+            }
+        }
+        unsafe extern "system" fn SetProp<Identity: windows_core::IUnknownImpl + IWMBackupRestoreProps_Impl>(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, r#type: WMT_ATTR_DATATYPE, pvalue: *const u8, cblength: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMBackupRestoreProps_Impl::SetProp(this, core::mem::transmute(&pszname), core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&cblength)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveProp<Identity: windows_core::IUnknownImpl + IWMBackupRestoreProps_Impl>(this: *mut core::ffi::c_void, pcwszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMBackupRestoreProps_Impl::RemoveProp(this, core::mem::transmute(&pcwszname)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveAllProps<Identity: windows_core::IUnknownImpl + IWMBackupRestoreProps_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMBackupRestoreProps_Impl::RemoveAllProps(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPropCount: GetPropCount::<Identity>,
+            GetPropByIndex: GetPropByIndex::<Identity>,
+            GetPropByName: GetPropByName::<Identity>,
+            SetProp: SetProp::<Identity>,
+            RemoveProp: RemoveProp::<Identity>,
+            RemoveAllProps: RemoveAllProps::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IWMBackupRestoreProps {}
+windows_core::imp::define_interface!(IWMBandwidthSharing, IWMBandwidthSharing_Vtbl, 0xad694af1_f8d9_42f8_bc47_70311b0c4f9e);
+impl core::ops::Deref for IWMBandwidthSharing {
+    type Target = IWMStreamList;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(IWMBandwidthSharing, windows_core::IUnknown, IWMStreamList);
+impl IWMBandwidthSharing {
+    pub unsafe fn GetType(&self) -> windows_core::Result<windows_core::GUID> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetType)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn SetType(&self, guidtype: *const windows_core::GUID) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetType)(windows_core::Interface::as_raw(self), guidtype).ok() }
+    }
+    pub unsafe fn GetBandwidth(&self, pdwbitrate: *mut u32, pmsbufferwindow: *mut u32) -> windows_core::Result<()> {
         unsafe { (windows_core::Interface::vtable(self).GetBandwidth)(windows_core::Interface::as_raw(self), pdwbitrate as _, pmsbufferwindow as _).ok() }
     }
     pub unsafe fn SetBandwidth(&self, dwbitrate: u32, msbufferwindow: u32) -> windows_core::Result<()> {
@@ -1054,6 +1473,50 @@ impl IWMBandwidthSharing_Vtbl {
         iid == &<IWMBandwidthSharing as windows_core::Interface>::IID || iid == &<IWMStreamList as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMBandwidthSharing_Impl> windows_core::imp::TearOffVtable<Identity> for IWMBandwidthSharing_Vtbl
+where
+    IWMStreamList_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetType<Identity: windows_core::IUnknownImpl + IWMBandwidthSharing_Impl>(this: *mut core::ffi::c_void, pguidtype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMBandwidthSharing_Impl::GetType(this) {
+                    Ok(ok__) => {
+                        pguidtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetType<Identity: windows_core::IUnknownImpl + IWMBandwidthSharing_Impl>(this: *mut core::ffi::c_void, guidtype: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMBandwidthSharing_Impl::SetType(this, core::mem::transmute_copy(&guidtype)).into()
+            }
+        }
+        unsafe extern "system" fn GetBandwidth<Identity: windows_core::IUnknownImpl + IWMBandwidthSharing_Impl>(this: *mut core::ffi::c_void, pdwbitrate: *mut u32, pmsbufferwindow: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMBandwidthSharing_Impl::GetBandwidth(this, core::mem::transmute_copy(&pdwbitrate), core::mem::transmute_copy(&pmsbufferwindow)).into()
+            }
+        }
+        unsafe extern "system" fn SetBandwidth<Identity: windows_core::IUnknownImpl + IWMBandwidthSharing_Impl>(this: *mut core::ffi::c_void, dwbitrate: u32, msbufferwindow: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMBandwidthSharing_Impl::SetBandwidth(this, core::mem::transmute_copy(&dwbitrate), core::mem::transmute_copy(&msbufferwindow)).into()
+            }
+        }
+        Self {
+            base__: <IWMStreamList_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetType: GetType::<Identity>,
+            SetType: SetType::<Identity>,
+            GetBandwidth: GetBandwidth::<Identity>,
+            SetBandwidth: SetBandwidth::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMBandwidthSharing {}
 windows_core::imp::define_interface!(IWMClientConnections, IWMClientConnections_Vtbl, 0x73c66010_a299_41df_b1f0_ccf03b09c1c6);
 windows_core::imp::interface_hierarchy!(IWMClientConnections, windows_core::IUnknown);
@@ -1118,6 +1581,42 @@ impl IWMClientConnections_Vtbl {
         iid == &<IWMClientConnections as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMClientConnections_Impl> windows_core::imp::TearOffVtable<Identity> for IWMClientConnections_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetClientCount<Identity: windows_core::IUnknownImpl + IWMClientConnections_Impl>(this: *mut core::ffi::c_void, pcclients: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMClientConnections_Impl::GetClientCount(this) {
+                    Ok(ok__) => {
+                        pcclients.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetClientProperties<Identity: windows_core::IUnknownImpl + IWMClientConnections_Impl>(this: *mut core::ffi::c_void, dwclientnum: u32, pclientproperties: *mut WM_CLIENT_PROPERTIES) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMClientConnections_Impl::GetClientProperties(this, core::mem::transmute_copy(&dwclientnum)) {
+                    Ok(ok__) => {
+                        pclientproperties.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetClientCount: GetClientCount::<Identity>,
+            GetClientProperties: GetClientProperties::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMClientConnections {}
 windows_core::imp::define_interface!(IWMClientConnections2, IWMClientConnections2_Vtbl, 0x4091571e_4701_4593_bb3d_d5f5f0c74246);
 impl core::ops::Deref for IWMClientConnections2 {
@@ -1155,6 +1654,20 @@ impl IWMClientConnections2_Vtbl {
         iid == &<IWMClientConnections2 as windows_core::Interface>::IID || iid == &<IWMClientConnections as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMClientConnections2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMClientConnections2_Vtbl
+where
+    IWMClientConnections_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetClientInfo<Identity: windows_core::IUnknownImpl + IWMClientConnections2_Impl>(this: *mut core::ffi::c_void, dwclientnum: u32, pwsznetworkaddress: windows_core::PWSTR, pcchnetworkaddress: *mut u32, pwszport: windows_core::PWSTR, pcchport: *mut u32, pwszdnsname: windows_core::PWSTR, pcchdnsname: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMClientConnections2_Impl::GetClientInfo(this, core::mem::transmute_copy(&dwclientnum), core::mem::transmute_copy(&pwsznetworkaddress), core::mem::transmute_copy(&pcchnetworkaddress), core::mem::transmute_copy(&pwszport), core::mem::transmute_copy(&pcchport), core::mem::transmute_copy(&pwszdnsname), core::mem::transmute_copy(&pcchdnsname)).into()
+            }
+        }
+        Self { base__: <IWMClientConnections_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetClientInfo: GetClientInfo::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMClientConnections2 {}
 windows_core::imp::define_interface!(IWMCodecInfo, IWMCodecInfo_Vtbl, 0xa970f41e_34de_4a98_b3ba_e4b3ca7528f0);
 windows_core::imp::interface_hierarchy!(IWMCodecInfo, windows_core::IUnknown);
@@ -1240,6 +1753,55 @@ impl IWMCodecInfo_Vtbl {
         iid == &<IWMCodecInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMCodecInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IWMCodecInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCodecInfoCount<Identity: windows_core::IUnknownImpl + IWMCodecInfo_Impl>(this: *mut core::ffi::c_void, guidtype: *const windows_core::GUID, pccodecs: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMCodecInfo_Impl::GetCodecInfoCount(this, core::mem::transmute_copy(&guidtype)) {
+                    Ok(ok__) => {
+                        pccodecs.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCodecFormatCount<Identity: windows_core::IUnknownImpl + IWMCodecInfo_Impl>(this: *mut core::ffi::c_void, guidtype: *const windows_core::GUID, dwcodecindex: u32, pcformat: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMCodecInfo_Impl::GetCodecFormatCount(this, core::mem::transmute_copy(&guidtype), core::mem::transmute_copy(&dwcodecindex)) {
+                    Ok(ok__) => {
+                        pcformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCodecFormat<Identity: windows_core::IUnknownImpl + IWMCodecInfo_Impl>(this: *mut core::ffi::c_void, guidtype: *const windows_core::GUID, dwcodecindex: u32, dwformatindex: u32, ppistreamconfig: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMCodecInfo_Impl::GetCodecFormat(this, core::mem::transmute_copy(&guidtype), core::mem::transmute_copy(&dwcodecindex), core::mem::transmute_copy(&dwformatindex)) {
+                    Ok(ok__) => {
+                        ppistreamconfig.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCodecInfoCount: GetCodecInfoCount::<Identity>,
+            GetCodecFormatCount: GetCodecFormatCount::<Identity>,
+            GetCodecFormat: GetCodecFormat::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMCodecInfo {}
 windows_core::imp::define_interface!(IWMCodecInfo2, IWMCodecInfo2_Vtbl, 0xaa65e273_b686_4056_91ec_dd768d4df710);
 impl core::ops::Deref for IWMCodecInfo2 {
@@ -1292,6 +1854,30 @@ impl IWMCodecInfo2_Vtbl {
         iid == &<IWMCodecInfo2 as windows_core::Interface>::IID || iid == &<IWMCodecInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMCodecInfo2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMCodecInfo2_Vtbl
+where
+    IWMCodecInfo_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCodecName<Identity: windows_core::IUnknownImpl + IWMCodecInfo2_Impl>(this: *mut core::ffi::c_void, guidtype: *const windows_core::GUID, dwcodecindex: u32, wszname: windows_core::PWSTR, pcchname: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMCodecInfo2_Impl::GetCodecName(this, core::mem::transmute_copy(&guidtype), core::mem::transmute_copy(&dwcodecindex), core::mem::transmute_copy(&wszname), core::mem::transmute_copy(&pcchname)).into()
+            }
+        }
+        unsafe extern "system" fn GetCodecFormatDesc<Identity: windows_core::IUnknownImpl + IWMCodecInfo2_Impl>(this: *mut core::ffi::c_void, guidtype: *const windows_core::GUID, dwcodecindex: u32, dwformatindex: u32, ppistreamconfig: *mut *mut core::ffi::c_void, wszdesc: windows_core::PWSTR, pcchdesc: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMCodecInfo2_Impl::GetCodecFormatDesc(this, core::mem::transmute_copy(&guidtype), core::mem::transmute_copy(&dwcodecindex), core::mem::transmute_copy(&dwformatindex), core::mem::transmute_copy(&ppistreamconfig), core::mem::transmute_copy(&wszdesc), core::mem::transmute_copy(&pcchdesc)).into()
+            }
+        }
+        Self {
+            base__: <IWMCodecInfo_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCodecName: GetCodecName::<Identity>,
+            GetCodecFormatDesc: GetCodecFormatDesc::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMCodecInfo2 {}
 windows_core::imp::define_interface!(IWMCodecInfo3, IWMCodecInfo3_Vtbl, 0x7e51f487_4d93_4f98_8ab4_27d0565adc51);
 impl core::ops::Deref for IWMCodecInfo3 {
@@ -1380,6 +1966,44 @@ impl IWMCodecInfo3_Vtbl {
         iid == &<IWMCodecInfo3 as windows_core::Interface>::IID || iid == &<IWMCodecInfo as windows_core::Interface>::IID || iid == &<IWMCodecInfo2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMCodecInfo3_Impl> windows_core::imp::TearOffVtable<Identity> for IWMCodecInfo3_Vtbl
+where
+    IWMCodecInfo2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCodecFormatProp<Identity: windows_core::IUnknownImpl + IWMCodecInfo3_Impl>(this: *mut core::ffi::c_void, guidtype: *const windows_core::GUID, dwcodecindex: u32, dwformatindex: u32, pszname: windows_core::PCWSTR, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pdwsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMCodecInfo3_Impl::GetCodecFormatProp(this, core::mem::transmute_copy(&guidtype), core::mem::transmute_copy(&dwcodecindex), core::mem::transmute_copy(&dwformatindex), core::mem::transmute(&pszname), core::mem::transmute_copy(&ptype), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&pdwsize)).into()
+            }
+        }
+        unsafe extern "system" fn GetCodecProp<Identity: windows_core::IUnknownImpl + IWMCodecInfo3_Impl>(this: *mut core::ffi::c_void, guidtype: *const windows_core::GUID, dwcodecindex: u32, pszname: windows_core::PCWSTR, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pdwsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMCodecInfo3_Impl::GetCodecProp(this, core::mem::transmute_copy(&guidtype), core::mem::transmute_copy(&dwcodecindex), core::mem::transmute(&pszname), core::mem::transmute_copy(&ptype), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&pdwsize)).into()
+            }
+        }
+        unsafe extern "system" fn SetCodecEnumerationSetting<Identity: windows_core::IUnknownImpl + IWMCodecInfo3_Impl>(this: *mut core::ffi::c_void, guidtype: *const windows_core::GUID, dwcodecindex: u32, pszname: windows_core::PCWSTR, r#type: WMT_ATTR_DATATYPE, pvalue: *const u8, dwsize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMCodecInfo3_Impl::SetCodecEnumerationSetting(this, core::mem::transmute_copy(&guidtype), core::mem::transmute_copy(&dwcodecindex), core::mem::transmute(&pszname), core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&dwsize)).into()
+            }
+        }
+        unsafe extern "system" fn GetCodecEnumerationSetting<Identity: windows_core::IUnknownImpl + IWMCodecInfo3_Impl>(this: *mut core::ffi::c_void, guidtype: *const windows_core::GUID, dwcodecindex: u32, pszname: windows_core::PCWSTR, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pdwsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMCodecInfo3_Impl::GetCodecEnumerationSetting(this, core::mem::transmute_copy(&guidtype), core::mem::transmute_copy(&dwcodecindex), core::mem::transmute(&pszname), core::mem::transmute_copy(&ptype), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&pdwsize)).into()
+            }
+        }
+        Self {
+            base__: <IWMCodecInfo2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCodecFormatProp: GetCodecFormatProp::<Identity>,
+            GetCodecProp: GetCodecProp::<Identity>,
+            SetCodecEnumerationSetting: SetCodecEnumerationSetting::<Identity>,
+            GetCodecEnumerationSetting: GetCodecEnumerationSetting::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMCodecInfo3 {}
 windows_core::imp::define_interface!(IWMCredentialCallback, IWMCredentialCallback_Vtbl, 0x342e0eb7_e651_450c_975b_2ace2c90c48e);
 windows_core::imp::interface_hierarchy!(IWMCredentialCallback, windows_core::IUnknown);
@@ -1415,6 +2039,20 @@ impl IWMCredentialCallback_Vtbl {
         iid == &<IWMCredentialCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMCredentialCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IWMCredentialCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AcquireCredentials<Identity: windows_core::IUnknownImpl + IWMCredentialCallback_Impl>(this: *mut core::ffi::c_void, pwszrealm: windows_core::PCWSTR, pwszsite: windows_core::PCWSTR, pwszuser: windows_core::PWSTR, cchuser: u32, pwszpassword: windows_core::PWSTR, cchpassword: u32, hrstatus: windows_core::HRESULT, pdwflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMCredentialCallback_Impl::AcquireCredentials(this, core::mem::transmute(&pwszrealm), core::mem::transmute(&pwszsite), core::mem::transmute_copy(&pwszuser), core::mem::transmute_copy(&cchuser), core::mem::transmute_copy(&pwszpassword), core::mem::transmute_copy(&cchpassword), core::mem::transmute_copy(&hrstatus), core::mem::transmute_copy(&pdwflags)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, AcquireCredentials: AcquireCredentials::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMCredentialCallback {}
 windows_core::imp::define_interface!(IWMDRMEditor, IWMDRMEditor_Vtbl, 0xff130ebc_a6c3_42a6_b401_c3382c3e08b3);
 windows_core::imp::interface_hierarchy!(IWMDRMEditor, windows_core::IUnknown);
@@ -1449,6 +2087,20 @@ impl IWMDRMEditor_Vtbl {
         iid == &<IWMDRMEditor as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDRMEditor_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDRMEditor_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDRMProperty<Identity: windows_core::IUnknownImpl + IWMDRMEditor_Impl>(this: *mut core::ffi::c_void, pwstrname: windows_core::PCWSTR, pdwtype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pcblength: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMEditor_Impl::GetDRMProperty(this, core::mem::transmute(&pwstrname), core::mem::transmute_copy(&pdwtype), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&pcblength)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDRMProperty: GetDRMProperty::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMDRMEditor {}
 windows_core::imp::define_interface!(IWMDRMMessageParser, IWMDRMMessageParser_Vtbl, 0xa73a0072_25a0_4c99_b4a5_ede8101a6c39);
 windows_core::imp::interface_hierarchy!(IWMDRMMessageParser, windows_core::IUnknown);
@@ -1495,6 +2147,30 @@ impl IWMDRMMessageParser_Vtbl {
         iid == &<IWMDRMMessageParser as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDRMMessageParser_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDRMMessageParser_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ParseRegistrationReqMsg<Identity: windows_core::IUnknownImpl + IWMDRMMessageParser_Impl>(this: *mut core::ffi::c_void, pbregistrationreqmsg: *const u8, cbregistrationreqmsg: u32, ppdevicecert: *mut *mut core::ffi::c_void, pdeviceserialnumber: *mut DRM_VAL16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMMessageParser_Impl::ParseRegistrationReqMsg(this, core::mem::transmute_copy(&pbregistrationreqmsg), core::mem::transmute_copy(&cbregistrationreqmsg), core::mem::transmute_copy(&ppdevicecert), core::mem::transmute_copy(&pdeviceserialnumber)).into()
+            }
+        }
+        unsafe extern "system" fn ParseLicenseRequestMsg<Identity: windows_core::IUnknownImpl + IWMDRMMessageParser_Impl>(this: *mut core::ffi::c_void, pblicenserequestmsg: *const u8, cblicenserequestmsg: u32, ppdevicecert: *mut *mut core::ffi::c_void, pdeviceserialnumber: *mut DRM_VAL16, pbstraction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMMessageParser_Impl::ParseLicenseRequestMsg(this, core::mem::transmute_copy(&pblicenserequestmsg), core::mem::transmute_copy(&cblicenserequestmsg), core::mem::transmute_copy(&ppdevicecert), core::mem::transmute_copy(&pdeviceserialnumber), core::mem::transmute_copy(&pbstraction)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ParseRegistrationReqMsg: ParseRegistrationReqMsg::<Identity>,
+            ParseLicenseRequestMsg: ParseLicenseRequestMsg::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMDRMMessageParser {}
 windows_core::imp::define_interface!(IWMDRMReader, IWMDRMReader_Vtbl, 0xd2827540_3ee7_432c_b14c_dc17f085d3b3);
 windows_core::imp::interface_hierarchy!(IWMDRMReader, windows_core::IUnknown);
@@ -1619,6 +2295,72 @@ impl IWMDRMReader_Vtbl {
         iid == &<IWMDRMReader as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDRMReader_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDRMReader_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AcquireLicense<Identity: windows_core::IUnknownImpl + IWMDRMReader_Impl>(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMReader_Impl::AcquireLicense(this, core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn CancelLicenseAcquisition<Identity: windows_core::IUnknownImpl + IWMDRMReader_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMReader_Impl::CancelLicenseAcquisition(this).into()
+            }
+        }
+        unsafe extern "system" fn Individualize<Identity: windows_core::IUnknownImpl + IWMDRMReader_Impl>(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMReader_Impl::Individualize(this, core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn CancelIndividualization<Identity: windows_core::IUnknownImpl + IWMDRMReader_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMReader_Impl::CancelIndividualization(this).into()
+            }
+        }
+        unsafe extern "system" fn MonitorLicenseAcquisition<Identity: windows_core::IUnknownImpl + IWMDRMReader_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMReader_Impl::MonitorLicenseAcquisition(this).into()
+            }
+        }
+        unsafe extern "system" fn CancelMonitorLicenseAcquisition<Identity: windows_core::IUnknownImpl + IWMDRMReader_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMReader_Impl::CancelMonitorLicenseAcquisition(this).into()
+            }
+        }
+        unsafe extern "system" fn SetDRMProperty<Identity: windows_core::IUnknownImpl + IWMDRMReader_Impl>(this: *mut core::ffi::c_void, pwstrname: windows_core::PCWSTR, dwtype: WMT_ATTR_DATATYPE, pvalue: *const u8, cblength: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMReader_Impl::SetDRMProperty(this, core::mem::transmute(&pwstrname), core::mem::transmute_copy(&dwtype), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&cblength)).into()
+            }
+        }
+        unsafe extern "system" fn GetDRMProperty<Identity: windows_core::IUnknownImpl + IWMDRMReader_Impl>(this: *mut core::ffi::c_void, pwstrname: windows_core::PCWSTR, pdwtype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pcblength: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMReader_Impl::GetDRMProperty(this, core::mem::transmute(&pwstrname), core::mem::transmute_copy(&pdwtype), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&pcblength)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AcquireLicense: AcquireLicense::<Identity>,
+            CancelLicenseAcquisition: CancelLicenseAcquisition::<Identity>,
+            Individualize: Individualize::<Identity>,
+            CancelIndividualization: CancelIndividualization::<Identity>,
+            MonitorLicenseAcquisition: MonitorLicenseAcquisition::<Identity>,
+            CancelMonitorLicenseAcquisition: CancelMonitorLicenseAcquisition::<Identity>,
+            SetDRMProperty: SetDRMProperty::<Identity>,
+            GetDRMProperty: GetDRMProperty::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMDRMReader {}
 windows_core::imp::define_interface!(IWMDRMReader2, IWMDRMReader2_Vtbl, 0xbefe7a75_9f1d_4075_b9d9_a3c37bda49a0);
 impl core::ops::Deref for IWMDRMReader2 {
@@ -1695,16 +2437,54 @@ impl IWMDRMReader2_Vtbl {
         iid == &<IWMDRMReader2 as windows_core::Interface>::IID || iid == &<IWMDRMReader as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IWMDRMReader2 {}
-windows_core::imp::define_interface!(IWMDRMReader3, IWMDRMReader3_Vtbl, 0xe08672de_f1e7_4ff4_a0a3_fc4b08e4caf8);
-impl core::ops::Deref for IWMDRMReader3 {
-    type Target = IWMDRMReader2;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(IWMDRMReader3, windows_core::IUnknown, IWMDRMReader, IWMDRMReader2);
-impl IWMDRMReader3 {
+impl<Identity: windows_core::IUnknownImpl + IWMDRMReader2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDRMReader2_Vtbl
+where
+    IWMDRMReader_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetEvaluateOutputLevelLicenses<Identity: windows_core::IUnknownImpl + IWMDRMReader2_Impl>(this: *mut core::ffi::c_void, fevaluate: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMReader2_Impl::SetEvaluateOutputLevelLicenses(this, core::mem::transmute_copy(&fevaluate)).into()
+            }
+        }
+        unsafe extern "system" fn GetPlayOutputLevels<Identity: windows_core::IUnknownImpl + IWMDRMReader2_Impl>(this: *mut core::ffi::c_void, pplayopl: *mut DRM_PLAY_OPL, pcblength: *mut u32, pdwminappcompliancelevel: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMReader2_Impl::GetPlayOutputLevels(this, core::mem::transmute_copy(&pplayopl), core::mem::transmute_copy(&pcblength), core::mem::transmute_copy(&pdwminappcompliancelevel)).into()
+            }
+        }
+        unsafe extern "system" fn GetCopyOutputLevels<Identity: windows_core::IUnknownImpl + IWMDRMReader2_Impl>(this: *mut core::ffi::c_void, pcopyopl: *mut DRM_COPY_OPL, pcblength: *mut u32, pdwminappcompliancelevel: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMReader2_Impl::GetCopyOutputLevels(this, core::mem::transmute_copy(&pcopyopl), core::mem::transmute_copy(&pcblength), core::mem::transmute_copy(&pdwminappcompliancelevel)).into()
+            }
+        }
+        unsafe extern "system" fn TryNextLicense<Identity: windows_core::IUnknownImpl + IWMDRMReader2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMReader2_Impl::TryNextLicense(this).into()
+            }
+        }
+        Self {
+            base__: <IWMDRMReader_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetEvaluateOutputLevelLicenses: SetEvaluateOutputLevelLicenses::<Identity>,
+            GetPlayOutputLevels: GetPlayOutputLevels::<Identity>,
+            GetCopyOutputLevels: GetCopyOutputLevels::<Identity>,
+            TryNextLicense: TryNextLicense::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IWMDRMReader2 {}
+windows_core::imp::define_interface!(IWMDRMReader3, IWMDRMReader3_Vtbl, 0xe08672de_f1e7_4ff4_a0a3_fc4b08e4caf8);
+impl core::ops::Deref for IWMDRMReader3 {
+    type Target = IWMDRMReader2;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(IWMDRMReader3, windows_core::IUnknown, IWMDRMReader, IWMDRMReader2);
+impl IWMDRMReader3 {
     pub unsafe fn GetInclusionList(&self, ppguids: *mut *mut windows_core::GUID, pcguids: *mut u32) -> windows_core::Result<()> {
         unsafe { (windows_core::Interface::vtable(self).GetInclusionList)(windows_core::Interface::as_raw(self), ppguids as _, pcguids as _).ok() }
     }
@@ -1732,6 +2512,20 @@ impl IWMDRMReader3_Vtbl {
         iid == &<IWMDRMReader3 as windows_core::Interface>::IID || iid == &<IWMDRMReader as windows_core::Interface>::IID || iid == &<IWMDRMReader2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDRMReader3_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDRMReader3_Vtbl
+where
+    IWMDRMReader2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetInclusionList<Identity: windows_core::IUnknownImpl + IWMDRMReader3_Impl>(this: *mut core::ffi::c_void, ppguids: *mut *mut windows_core::GUID, pcguids: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMReader3_Impl::GetInclusionList(this, core::mem::transmute_copy(&ppguids), core::mem::transmute_copy(&pcguids)).into()
+            }
+        }
+        Self { base__: <IWMDRMReader2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetInclusionList: GetInclusionList::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMDRMReader3 {}
 windows_core::imp::define_interface!(IWMDRMTranscryptionManager, IWMDRMTranscryptionManager_Vtbl, 0xb1a887b2_a4f0_407a_b02e_efbd23bbecdf);
 windows_core::imp::interface_hierarchy!(IWMDRMTranscryptionManager, windows_core::IUnknown);
@@ -1772,6 +2566,26 @@ impl IWMDRMTranscryptionManager_Vtbl {
         iid == &<IWMDRMTranscryptionManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDRMTranscryptionManager_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDRMTranscryptionManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateTranscryptor<Identity: windows_core::IUnknownImpl + IWMDRMTranscryptionManager_Impl>(this: *mut core::ffi::c_void, pptranscryptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDRMTranscryptionManager_Impl::CreateTranscryptor(this) {
+                    Ok(ok__) => {
+                        pptranscryptor.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateTranscryptor: CreateTranscryptor::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMDRMTranscryptionManager {}
 windows_core::imp::define_interface!(IWMDRMTranscryptor, IWMDRMTranscryptor_Vtbl, 0x69059850_6e6f_4bb2_806f_71863ddfc471);
 windows_core::imp::interface_hierarchy!(IWMDRMTranscryptor, windows_core::IUnknown);
@@ -1845,6 +2659,44 @@ impl IWMDRMTranscryptor_Vtbl {
         iid == &<IWMDRMTranscryptor as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDRMTranscryptor_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDRMTranscryptor_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IWMDRMTranscryptor_Impl>(this: *mut core::ffi::c_void, bstrfilename: *mut core::ffi::c_void, pblicenserequestmsg: *mut u8, cblicenserequestmsg: u32, pplicenseresponsemsg: *mut *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMTranscryptor_Impl::Initialize(this, core::mem::transmute(&bstrfilename), core::mem::transmute_copy(&pblicenserequestmsg), core::mem::transmute_copy(&cblicenserequestmsg), core::mem::transmute_copy(&pplicenseresponsemsg), core::mem::transmute_copy(&pcallback), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        unsafe extern "system" fn Seek<Identity: windows_core::IUnknownImpl + IWMDRMTranscryptor_Impl>(this: *mut core::ffi::c_void, hnstime: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMTranscryptor_Impl::Seek(this, core::mem::transmute_copy(&hnstime)).into()
+            }
+        }
+        unsafe extern "system" fn Read<Identity: windows_core::IUnknownImpl + IWMDRMTranscryptor_Impl>(this: *mut core::ffi::c_void, pbdata: *const u8, pcbdata: *const u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMTranscryptor_Impl::Read(this, core::mem::transmute_copy(&pbdata), core::mem::transmute_copy(&pcbdata)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IWMDRMTranscryptor_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMTranscryptor_Impl::Close(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            Seek: Seek::<Identity>,
+            Read: Read::<Identity>,
+            Close: Close::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMDRMTranscryptor {}
 windows_core::imp::define_interface!(IWMDRMTranscryptor2, IWMDRMTranscryptor2_Vtbl, 0xe0da439f_d331_496a_bece_18e5bac5dd23);
 impl core::ops::Deref for IWMDRMTranscryptor2 {
@@ -1939,6 +2791,56 @@ impl IWMDRMTranscryptor2_Vtbl {
         iid == &<IWMDRMTranscryptor2 as windows_core::Interface>::IID || iid == &<IWMDRMTranscryptor as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDRMTranscryptor2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDRMTranscryptor2_Vtbl
+where
+    IWMDRMTranscryptor_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SeekEx<Identity: windows_core::IUnknownImpl + IWMDRMTranscryptor2_Impl>(this: *mut core::ffi::c_void, cnsstarttime: u64, cnsduration: u64, flrate: f32, fincludefileheader: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMTranscryptor2_Impl::SeekEx(this, core::mem::transmute_copy(&cnsstarttime), core::mem::transmute_copy(&cnsduration), core::mem::transmute_copy(&flrate), core::mem::transmute_copy(&fincludefileheader)).into()
+            }
+        }
+        unsafe extern "system" fn ZeroAdjustTimestamps<Identity: windows_core::IUnknownImpl + IWMDRMTranscryptor2_Impl>(this: *mut core::ffi::c_void, fenable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMTranscryptor2_Impl::ZeroAdjustTimestamps(this, core::mem::transmute_copy(&fenable)).into()
+            }
+        }
+        unsafe extern "system" fn GetSeekStartTime<Identity: windows_core::IUnknownImpl + IWMDRMTranscryptor2_Impl>(this: *mut core::ffi::c_void, pcnstime: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDRMTranscryptor2_Impl::GetSeekStartTime(this) {
+                    Ok(ok__) => {
+                        pcnstime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDuration<Identity: windows_core::IUnknownImpl + IWMDRMTranscryptor2_Impl>(this: *mut core::ffi::c_void, pcnsduration: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDRMTranscryptor2_Impl::GetDuration(this) {
+                    Ok(ok__) => {
+                        pcnsduration.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWMDRMTranscryptor_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SeekEx: SeekEx::<Identity>,
+            ZeroAdjustTimestamps: ZeroAdjustTimestamps::<Identity>,
+            GetSeekStartTime: GetSeekStartTime::<Identity>,
+            GetDuration: GetDuration::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMDRMTranscryptor2 {}
 windows_core::imp::define_interface!(IWMDRMWriter, IWMDRMWriter_Vtbl, 0xd6ea5dd0_12a0_43f4_90ab_a3fd451e6a07);
 windows_core::imp::interface_hierarchy!(IWMDRMWriter, windows_core::IUnknown);
@@ -2012,6 +2914,44 @@ impl IWMDRMWriter_Vtbl {
         iid == &<IWMDRMWriter as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDRMWriter_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDRMWriter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GenerateKeySeed<Identity: windows_core::IUnknownImpl + IWMDRMWriter_Impl>(this: *mut core::ffi::c_void, pwszkeyseed: windows_core::PWSTR, pcwchlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMWriter_Impl::GenerateKeySeed(this, core::mem::transmute_copy(&pwszkeyseed), core::mem::transmute_copy(&pcwchlength)).into()
+            }
+        }
+        unsafe extern "system" fn GenerateKeyID<Identity: windows_core::IUnknownImpl + IWMDRMWriter_Impl>(this: *mut core::ffi::c_void, pwszkeyid: windows_core::PWSTR, pcwchlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMWriter_Impl::GenerateKeyID(this, core::mem::transmute_copy(&pwszkeyid), core::mem::transmute_copy(&pcwchlength)).into()
+            }
+        }
+        unsafe extern "system" fn GenerateSigningKeyPair<Identity: windows_core::IUnknownImpl + IWMDRMWriter_Impl>(this: *mut core::ffi::c_void, pwszprivkey: windows_core::PWSTR, pcwchprivkeylength: *mut u32, pwszpubkey: windows_core::PWSTR, pcwchpubkeylength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMWriter_Impl::GenerateSigningKeyPair(this, core::mem::transmute_copy(&pwszprivkey), core::mem::transmute_copy(&pcwchprivkeylength), core::mem::transmute_copy(&pwszpubkey), core::mem::transmute_copy(&pcwchpubkeylength)).into()
+            }
+        }
+        unsafe extern "system" fn SetDRMAttribute<Identity: windows_core::IUnknownImpl + IWMDRMWriter_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, pszname: windows_core::PCWSTR, r#type: WMT_ATTR_DATATYPE, pvalue: *const u8, cblength: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMWriter_Impl::SetDRMAttribute(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute(&pszname), core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&cblength)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GenerateKeySeed: GenerateKeySeed::<Identity>,
+            GenerateKeyID: GenerateKeyID::<Identity>,
+            GenerateSigningKeyPair: GenerateSigningKeyPair::<Identity>,
+            SetDRMAttribute: SetDRMAttribute::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMDRMWriter {}
 windows_core::imp::define_interface!(IWMDRMWriter2, IWMDRMWriter2_Vtbl, 0x38ee7a94_40e2_4e10_aa3f_33fd3210ed5b);
 impl core::ops::Deref for IWMDRMWriter2 {
@@ -2049,6 +2989,20 @@ impl IWMDRMWriter2_Vtbl {
         iid == &<IWMDRMWriter2 as windows_core::Interface>::IID || iid == &<IWMDRMWriter as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDRMWriter2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDRMWriter2_Vtbl
+where
+    IWMDRMWriter_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetWMDRMNetEncryption<Identity: windows_core::IUnknownImpl + IWMDRMWriter2_Impl>(this: *mut core::ffi::c_void, fsamplesencrypted: windows_core::BOOL, pbkeyid: *const u8, cbkeyid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMWriter2_Impl::SetWMDRMNetEncryption(this, core::mem::transmute_copy(&fsamplesencrypted), core::mem::transmute_copy(&pbkeyid), core::mem::transmute_copy(&cbkeyid)).into()
+            }
+        }
+        Self { base__: <IWMDRMWriter_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetWMDRMNetEncryption: SetWMDRMNetEncryption::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMDRMWriter2 {}
 windows_core::imp::define_interface!(IWMDRMWriter3, IWMDRMWriter3_Vtbl, 0xa7184082_a4aa_4dde_ac9c_e75dbd1117ce);
 impl core::ops::Deref for IWMDRMWriter3 {
@@ -2086,6 +3040,20 @@ impl IWMDRMWriter3_Vtbl {
         iid == &<IWMDRMWriter3 as windows_core::Interface>::IID || iid == &<IWMDRMWriter as windows_core::Interface>::IID || iid == &<IWMDRMWriter2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDRMWriter3_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDRMWriter3_Vtbl
+where
+    IWMDRMWriter2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetProtectStreamSamples<Identity: windows_core::IUnknownImpl + IWMDRMWriter3_Impl>(this: *mut core::ffi::c_void, pimportinitstruct: *const WMDRM_IMPORT_INIT_STRUCT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDRMWriter3_Impl::SetProtectStreamSamples(this, core::mem::transmute_copy(&pimportinitstruct)).into()
+            }
+        }
+        Self { base__: <IWMDRMWriter2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetProtectStreamSamples: SetProtectStreamSamples::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMDRMWriter3 {}
 windows_core::imp::define_interface!(IWMDeviceRegistration, IWMDeviceRegistration_Vtbl, 0xf6211f03_8d21_4e94_93e6_8510805f2d99);
 windows_core::imp::interface_hierarchy!(IWMDeviceRegistration, windows_core::IUnknown);
@@ -2225,6 +3193,88 @@ impl IWMDeviceRegistration_Vtbl {
         iid == &<IWMDeviceRegistration as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMDeviceRegistration_Impl> windows_core::imp::TearOffVtable<Identity> for IWMDeviceRegistration_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterDevice<Identity: windows_core::IUnknownImpl + IWMDeviceRegistration_Impl>(this: *mut core::ffi::c_void, dwregistertype: u32, pbcertificate: *const u8, cbcertificate: u32, serialnumber: DRM_VAL16, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDeviceRegistration_Impl::RegisterDevice(this, core::mem::transmute_copy(&dwregistertype), core::mem::transmute_copy(&pbcertificate), core::mem::transmute_copy(&cbcertificate), core::mem::transmute(&serialnumber)) {
+                    Ok(ok__) => {
+                        ppdevice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UnregisterDevice<Identity: windows_core::IUnknownImpl + IWMDeviceRegistration_Impl>(this: *mut core::ffi::c_void, dwregistertype: u32, pbcertificate: *const u8, cbcertificate: u32, serialnumber: DRM_VAL16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMDeviceRegistration_Impl::UnregisterDevice(this, core::mem::transmute_copy(&dwregistertype), core::mem::transmute_copy(&pbcertificate), core::mem::transmute_copy(&cbcertificate), core::mem::transmute(&serialnumber)).into()
+            }
+        }
+        unsafe extern "system" fn GetRegistrationStats<Identity: windows_core::IUnknownImpl + IWMDeviceRegistration_Impl>(this: *mut core::ffi::c_void, dwregistertype: u32, pcregistereddevices: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDeviceRegistration_Impl::GetRegistrationStats(this, core::mem::transmute_copy(&dwregistertype)) {
+                    Ok(ok__) => {
+                        pcregistereddevices.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFirstRegisteredDevice<Identity: windows_core::IUnknownImpl + IWMDeviceRegistration_Impl>(this: *mut core::ffi::c_void, dwregistertype: u32, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDeviceRegistration_Impl::GetFirstRegisteredDevice(this, core::mem::transmute_copy(&dwregistertype)) {
+                    Ok(ok__) => {
+                        ppdevice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetNextRegisteredDevice<Identity: windows_core::IUnknownImpl + IWMDeviceRegistration_Impl>(this: *mut core::ffi::c_void, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDeviceRegistration_Impl::GetNextRegisteredDevice(this) {
+                    Ok(ok__) => {
+                        ppdevice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRegisteredDeviceByID<Identity: windows_core::IUnknownImpl + IWMDeviceRegistration_Impl>(this: *mut core::ffi::c_void, dwregistertype: u32, pbcertificate: *const u8, cbcertificate: u32, serialnumber: DRM_VAL16, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMDeviceRegistration_Impl::GetRegisteredDeviceByID(this, core::mem::transmute_copy(&dwregistertype), core::mem::transmute_copy(&pbcertificate), core::mem::transmute_copy(&cbcertificate), core::mem::transmute(&serialnumber)) {
+                    Ok(ok__) => {
+                        ppdevice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterDevice: RegisterDevice::<Identity>,
+            UnregisterDevice: UnregisterDevice::<Identity>,
+            GetRegistrationStats: GetRegistrationStats::<Identity>,
+            GetFirstRegisteredDevice: GetFirstRegisteredDevice::<Identity>,
+            GetNextRegisteredDevice: GetNextRegisteredDevice::<Identity>,
+            GetRegisteredDeviceByID: GetRegisteredDeviceByID::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMDeviceRegistration {}
 windows_core::imp::define_interface!(IWMGetSecureChannel, IWMGetSecureChannel_Vtbl, 0x94bc0598_c3d2_11d3_bedf_00c04f612986);
 windows_core::imp::interface_hierarchy!(IWMGetSecureChannel, windows_core::IUnknown);
@@ -2265,6 +3315,29 @@ impl IWMGetSecureChannel_Vtbl {
         iid == &<IWMGetSecureChannel as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMGetSecureChannel_Impl> windows_core::imp::TearOffVtable<Identity> for IWMGetSecureChannel_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPeerSecureChannelInterface<Identity: windows_core::IUnknownImpl + IWMGetSecureChannel_Impl>(this: *mut core::ffi::c_void, pppeer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMGetSecureChannel_Impl::GetPeerSecureChannelInterface(this) {
+                    Ok(ok__) => {
+                        pppeer.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPeerSecureChannelInterface: GetPeerSecureChannelInterface::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMGetSecureChannel {}
 windows_core::imp::define_interface!(IWMHeaderInfo, IWMHeaderInfo_Vtbl, 0x96406bda_2b2b_11d3_b36b_00c04f6108ff);
 windows_core::imp::interface_hierarchy!(IWMHeaderInfo, windows_core::IUnknown);
@@ -2471,52 +3544,164 @@ impl IWMHeaderInfo_Vtbl {
         iid == &<IWMHeaderInfo as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IWMHeaderInfo {}
-windows_core::imp::define_interface!(IWMHeaderInfo2, IWMHeaderInfo2_Vtbl, 0x15cf9781_454e_482e_b393_85fae487a810);
-impl core::ops::Deref for IWMHeaderInfo2 {
-    type Target = IWMHeaderInfo;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(IWMHeaderInfo2, windows_core::IUnknown, IWMHeaderInfo);
-impl IWMHeaderInfo2 {
-    pub unsafe fn GetCodecInfoCount(&self) -> windows_core::Result<u32> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetCodecInfoCount)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
-        }
-    }
-    pub unsafe fn GetCodecInfo(&self, windex: u32, pcchname: *mut u16, pwszname: windows_core::PWSTR, pcchdescription: *mut u16, pwszdescription: windows_core::PWSTR, pcodectype: *mut WMT_CODEC_INFO_TYPE, pcbcodecinfo: *mut u16, pbcodecinfo: *mut u8) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetCodecInfo)(windows_core::Interface::as_raw(self), windex, pcchname as _, core::mem::transmute(pwszname), pcchdescription as _, core::mem::transmute(pwszdescription), pcodectype as _, pcbcodecinfo as _, pbcodecinfo as _).ok() }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IWMHeaderInfo2_Vtbl {
-    pub base__: IWMHeaderInfo_Vtbl,
-    pub GetCodecInfoCount: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
-    pub GetCodecInfo: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *mut u16, windows_core::PWSTR, *mut u16, windows_core::PWSTR, *mut WMT_CODEC_INFO_TYPE, *mut u16, *mut u8) -> windows_core::HRESULT,
-}
-pub trait IWMHeaderInfo2_Impl: IWMHeaderInfo_Impl {
-    fn GetCodecInfoCount(&self) -> windows_core::Result<u32>;
-    fn GetCodecInfo(&self, windex: u32, pcchname: *mut u16, pwszname: windows_core::PWSTR, pcchdescription: *mut u16, pwszdescription: windows_core::PWSTR, pcodectype: *mut WMT_CODEC_INFO_TYPE, pcbcodecinfo: *mut u16, pbcodecinfo: *mut u8) -> windows_core::Result<()>;
-}
-impl IWMHeaderInfo2_Vtbl {
-    pub const fn new<Identity: IWMHeaderInfo2_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn GetCodecInfoCount<Identity: IWMHeaderInfo2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pccodecinfos: *mut u32) -> windows_core::HRESULT {
+impl<Identity: windows_core::IUnknownImpl + IWMHeaderInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IWMHeaderInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAttributeCount<Identity: windows_core::IUnknownImpl + IWMHeaderInfo_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, pcattributes: *mut u16) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IWMHeaderInfo2_Impl::GetCodecInfoCount(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMHeaderInfo_Impl::GetAttributeCount(this, core::mem::transmute_copy(&wstreamnum)) {
                     Ok(ok__) => {
-                        pccodecinfos.write(core::mem::transmute(ok__));
+                        pcattributes.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn GetCodecInfo<Identity: IWMHeaderInfo2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, windex: u32, pcchname: *mut u16, pwszname: windows_core::PWSTR, pcchdescription: *mut u16, pwszdescription: windows_core::PWSTR, pcodectype: *mut WMT_CODEC_INFO_TYPE, pcbcodecinfo: *mut u16, pbcodecinfo: *mut u8) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetAttributeByIndex<Identity: windows_core::IUnknownImpl + IWMHeaderInfo_Impl>(this: *mut core::ffi::c_void, windex: u16, pwstreamnum: *mut u16, pwszname: windows_core::PWSTR, pcchnamelen: *mut u16, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pcblength: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMHeaderInfo_Impl::GetAttributeByIndex(this, core::mem::transmute_copy(&windex), core::mem::transmute_copy(&pwstreamnum), core::mem::transmute_copy(&pwszname), core::mem::transmute_copy(&pcchnamelen), core::mem::transmute_copy(&ptype), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&pcblength)).into()
+            }
+        }
+        unsafe extern "system" fn GetAttributeByName<Identity: windows_core::IUnknownImpl + IWMHeaderInfo_Impl>(this: *mut core::ffi::c_void, pwstreamnum: *mut u16, pszname: windows_core::PCWSTR, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pcblength: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMHeaderInfo_Impl::GetAttributeByName(this, core::mem::transmute_copy(&pwstreamnum), core::mem::transmute(&pszname), core::mem::transmute_copy(&ptype), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&pcblength)).into()
+            }
+        }
+        unsafe extern "system" fn SetAttribute<Identity: windows_core::IUnknownImpl + IWMHeaderInfo_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, pszname: windows_core::PCWSTR, r#type: WMT_ATTR_DATATYPE, pvalue: *const u8, cblength: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMHeaderInfo_Impl::SetAttribute(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute(&pszname), core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&cblength)).into()
+            }
+        }
+        unsafe extern "system" fn GetMarkerCount<Identity: windows_core::IUnknownImpl + IWMHeaderInfo_Impl>(this: *mut core::ffi::c_void, pcmarkers: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMHeaderInfo_Impl::GetMarkerCount(this) {
+                    Ok(ok__) => {
+                        pcmarkers.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMarker<Identity: windows_core::IUnknownImpl + IWMHeaderInfo_Impl>(this: *mut core::ffi::c_void, windex: u16, pwszmarkername: windows_core::PWSTR, pcchmarkernamelen: *mut u16, pcnsmarkertime: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMHeaderInfo_Impl::GetMarker(this, core::mem::transmute_copy(&windex), core::mem::transmute_copy(&pwszmarkername), core::mem::transmute_copy(&pcchmarkernamelen), core::mem::transmute_copy(&pcnsmarkertime)).into()
+            }
+        }
+        unsafe extern "system" fn AddMarker<Identity: windows_core::IUnknownImpl + IWMHeaderInfo_Impl>(this: *mut core::ffi::c_void, pwszmarkername: windows_core::PCWSTR, cnsmarkertime: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMHeaderInfo_Impl::AddMarker(this, core::mem::transmute(&pwszmarkername), core::mem::transmute_copy(&cnsmarkertime)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveMarker<Identity: windows_core::IUnknownImpl + IWMHeaderInfo_Impl>(this: *mut core::ffi::c_void, windex: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMHeaderInfo_Impl::RemoveMarker(this, core::mem::transmute_copy(&windex)).into()
+            }
+        }
+        unsafe extern "system" fn GetScriptCount<Identity: windows_core::IUnknownImpl + IWMHeaderInfo_Impl>(this: *mut core::ffi::c_void, pcscripts: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMHeaderInfo_Impl::GetScriptCount(this) {
+                    Ok(ok__) => {
+                        pcscripts.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetScript<Identity: windows_core::IUnknownImpl + IWMHeaderInfo_Impl>(this: *mut core::ffi::c_void, windex: u16, pwsztype: windows_core::PWSTR, pcchtypelen: *mut u16, pwszcommand: windows_core::PWSTR, pcchcommandlen: *mut u16, pcnsscripttime: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMHeaderInfo_Impl::GetScript(this, core::mem::transmute_copy(&windex), core::mem::transmute_copy(&pwsztype), core::mem::transmute_copy(&pcchtypelen), core::mem::transmute_copy(&pwszcommand), core::mem::transmute_copy(&pcchcommandlen), core::mem::transmute_copy(&pcnsscripttime)).into()
+            }
+        }
+        unsafe extern "system" fn AddScript<Identity: windows_core::IUnknownImpl + IWMHeaderInfo_Impl>(this: *mut core::ffi::c_void, pwsztype: windows_core::PCWSTR, pwszcommand: windows_core::PCWSTR, cnsscripttime: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMHeaderInfo_Impl::AddScript(this, core::mem::transmute(&pwsztype), core::mem::transmute(&pwszcommand), core::mem::transmute_copy(&cnsscripttime)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveScript<Identity: windows_core::IUnknownImpl + IWMHeaderInfo_Impl>(this: *mut core::ffi::c_void, windex: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMHeaderInfo_Impl::RemoveScript(this, core::mem::transmute_copy(&windex)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAttributeCount: GetAttributeCount::<Identity>,
+            GetAttributeByIndex: GetAttributeByIndex::<Identity>,
+            GetAttributeByName: GetAttributeByName::<Identity>,
+            SetAttribute: SetAttribute::<Identity>,
+            GetMarkerCount: GetMarkerCount::<Identity>,
+            GetMarker: GetMarker::<Identity>,
+            AddMarker: AddMarker::<Identity>,
+            RemoveMarker: RemoveMarker::<Identity>,
+            GetScriptCount: GetScriptCount::<Identity>,
+            GetScript: GetScript::<Identity>,
+            AddScript: AddScript::<Identity>,
+            RemoveScript: RemoveScript::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IWMHeaderInfo {}
+windows_core::imp::define_interface!(IWMHeaderInfo2, IWMHeaderInfo2_Vtbl, 0x15cf9781_454e_482e_b393_85fae487a810);
+impl core::ops::Deref for IWMHeaderInfo2 {
+    type Target = IWMHeaderInfo;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(IWMHeaderInfo2, windows_core::IUnknown, IWMHeaderInfo);
+impl IWMHeaderInfo2 {
+    pub unsafe fn GetCodecInfoCount(&self) -> windows_core::Result<u32> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetCodecInfoCount)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn GetCodecInfo(&self, windex: u32, pcchname: *mut u16, pwszname: windows_core::PWSTR, pcchdescription: *mut u16, pwszdescription: windows_core::PWSTR, pcodectype: *mut WMT_CODEC_INFO_TYPE, pcbcodecinfo: *mut u16, pbcodecinfo: *mut u8) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetCodecInfo)(windows_core::Interface::as_raw(self), windex, pcchname as _, core::mem::transmute(pwszname), pcchdescription as _, core::mem::transmute(pwszdescription), pcodectype as _, pcbcodecinfo as _, pbcodecinfo as _).ok() }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IWMHeaderInfo2_Vtbl {
+    pub base__: IWMHeaderInfo_Vtbl,
+    pub GetCodecInfoCount: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
+    pub GetCodecInfo: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *mut u16, windows_core::PWSTR, *mut u16, windows_core::PWSTR, *mut WMT_CODEC_INFO_TYPE, *mut u16, *mut u8) -> windows_core::HRESULT,
+}
+pub trait IWMHeaderInfo2_Impl: IWMHeaderInfo_Impl {
+    fn GetCodecInfoCount(&self) -> windows_core::Result<u32>;
+    fn GetCodecInfo(&self, windex: u32, pcchname: *mut u16, pwszname: windows_core::PWSTR, pcchdescription: *mut u16, pwszdescription: windows_core::PWSTR, pcodectype: *mut WMT_CODEC_INFO_TYPE, pcbcodecinfo: *mut u16, pbcodecinfo: *mut u8) -> windows_core::Result<()>;
+}
+impl IWMHeaderInfo2_Vtbl {
+    pub const fn new<Identity: IWMHeaderInfo2_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn GetCodecInfoCount<Identity: IWMHeaderInfo2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pccodecinfos: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWMHeaderInfo2_Impl::GetCodecInfoCount(this) {
+                    Ok(ok__) => {
+                        pccodecinfos.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCodecInfo<Identity: IWMHeaderInfo2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, windex: u32, pcchname: *mut u16, pwszname: windows_core::PWSTR, pcchdescription: *mut u16, pwszdescription: windows_core::PWSTR, pcodectype: *mut WMT_CODEC_INFO_TYPE, pcbcodecinfo: *mut u16, pbcodecinfo: *mut u8) -> windows_core::HRESULT {
             unsafe {
                 let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
                 IWMHeaderInfo2_Impl::GetCodecInfo(this, core::mem::transmute_copy(&windex), core::mem::transmute_copy(&pcchname), core::mem::transmute_copy(&pwszname), core::mem::transmute_copy(&pcchdescription), core::mem::transmute_copy(&pwszdescription), core::mem::transmute_copy(&pcodectype), core::mem::transmute_copy(&pcbcodecinfo), core::mem::transmute_copy(&pbcodecinfo)).into()
@@ -2532,6 +3717,36 @@ impl IWMHeaderInfo2_Vtbl {
         iid == &<IWMHeaderInfo2 as windows_core::Interface>::IID || iid == &<IWMHeaderInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMHeaderInfo2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMHeaderInfo2_Vtbl
+where
+    IWMHeaderInfo_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCodecInfoCount<Identity: windows_core::IUnknownImpl + IWMHeaderInfo2_Impl>(this: *mut core::ffi::c_void, pccodecinfos: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMHeaderInfo2_Impl::GetCodecInfoCount(this) {
+                    Ok(ok__) => {
+                        pccodecinfos.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCodecInfo<Identity: windows_core::IUnknownImpl + IWMHeaderInfo2_Impl>(this: *mut core::ffi::c_void, windex: u32, pcchname: *mut u16, pwszname: windows_core::PWSTR, pcchdescription: *mut u16, pwszdescription: windows_core::PWSTR, pcodectype: *mut WMT_CODEC_INFO_TYPE, pcbcodecinfo: *mut u16, pbcodecinfo: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMHeaderInfo2_Impl::GetCodecInfo(this, core::mem::transmute_copy(&windex), core::mem::transmute_copy(&pcchname), core::mem::transmute_copy(&pwszname), core::mem::transmute_copy(&pcchdescription), core::mem::transmute_copy(&pwszdescription), core::mem::transmute_copy(&pcodectype), core::mem::transmute_copy(&pcbcodecinfo), core::mem::transmute_copy(&pbcodecinfo)).into()
+            }
+        }
+        Self {
+            base__: <IWMHeaderInfo_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCodecInfoCount: GetCodecInfoCount::<Identity>,
+            GetCodecInfo: GetCodecInfo::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMHeaderInfo2 {}
 windows_core::imp::define_interface!(IWMHeaderInfo3, IWMHeaderInfo3_Vtbl, 0x15cc68e3_27cc_4ecd_b222_3f5d02d80bd5);
 impl core::ops::Deref for IWMHeaderInfo3 {
@@ -2663,6 +3878,71 @@ impl IWMHeaderInfo3_Vtbl {
         iid == &<IWMHeaderInfo3 as windows_core::Interface>::IID || iid == &<IWMHeaderInfo as windows_core::Interface>::IID || iid == &<IWMHeaderInfo2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMHeaderInfo3_Impl> windows_core::imp::TearOffVtable<Identity> for IWMHeaderInfo3_Vtbl
+where
+    IWMHeaderInfo2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAttributeCountEx<Identity: windows_core::IUnknownImpl + IWMHeaderInfo3_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, pcattributes: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMHeaderInfo3_Impl::GetAttributeCountEx(this, core::mem::transmute_copy(&wstreamnum)) {
+                    Ok(ok__) => {
+                        pcattributes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAttributeIndices<Identity: windows_core::IUnknownImpl + IWMHeaderInfo3_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, pwszname: windows_core::PCWSTR, pwlangindex: *const u16, pwindices: *mut u16, pwcount: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMHeaderInfo3_Impl::GetAttributeIndices(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute(&pwszname), core::mem::transmute_copy(&pwlangindex), core::mem::transmute_copy(&pwindices), core::mem::transmute_copy(&pwcount)).into()
+            }
+        }
+        unsafe extern "system" fn GetAttributeByIndexEx<Identity: windows_core::IUnknownImpl + IWMHeaderInfo3_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, windex: u16, pwszname: windows_core::PWSTR, pwnamelen: *mut u16, ptype: *mut WMT_ATTR_DATATYPE, pwlangindex: *mut u16, pvalue: *mut u8, pdwdatalength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMHeaderInfo3_Impl::GetAttributeByIndexEx(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&windex), core::mem::transmute_copy(&pwszname), core::mem::transmute_copy(&pwnamelen), core::mem::transmute_copy(&ptype), core::mem::transmute_copy(&pwlangindex), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&pdwdatalength)).into()
+            }
+        }
+        unsafe extern "system" fn ModifyAttribute<Identity: windows_core::IUnknownImpl + IWMHeaderInfo3_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, windex: u16, r#type: WMT_ATTR_DATATYPE, wlangindex: u16, pvalue: *const u8, dwlength: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMHeaderInfo3_Impl::ModifyAttribute(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&windex), core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&wlangindex), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&dwlength)).into()
+            }
+        }
+        unsafe extern "system" fn AddAttribute<Identity: windows_core::IUnknownImpl + IWMHeaderInfo3_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, pszname: windows_core::PCWSTR, pwindex: *mut u16, r#type: WMT_ATTR_DATATYPE, wlangindex: u16, pvalue: *const u8, dwlength: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMHeaderInfo3_Impl::AddAttribute(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute(&pszname), core::mem::transmute_copy(&pwindex), core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&wlangindex), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&dwlength)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteAttribute<Identity: windows_core::IUnknownImpl + IWMHeaderInfo3_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, windex: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMHeaderInfo3_Impl::DeleteAttribute(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&windex)).into()
+            }
+        }
+        unsafe extern "system" fn AddCodecInfo<Identity: windows_core::IUnknownImpl + IWMHeaderInfo3_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR, pwszdescription: windows_core::PCWSTR, codectype: WMT_CODEC_INFO_TYPE, cbcodecinfo: u16, pbcodecinfo: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMHeaderInfo3_Impl::AddCodecInfo(this, core::mem::transmute(&pwszname), core::mem::transmute(&pwszdescription), core::mem::transmute_copy(&codectype), core::mem::transmute_copy(&cbcodecinfo), core::mem::transmute_copy(&pbcodecinfo)).into()
+            }
+        }
+        Self {
+            base__: <IWMHeaderInfo2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAttributeCountEx: GetAttributeCountEx::<Identity>,
+            GetAttributeIndices: GetAttributeIndices::<Identity>,
+            GetAttributeByIndexEx: GetAttributeByIndexEx::<Identity>,
+            ModifyAttribute: ModifyAttribute::<Identity>,
+            AddAttribute: AddAttribute::<Identity>,
+            DeleteAttribute: DeleteAttribute::<Identity>,
+            AddCodecInfo: AddCodecInfo::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMHeaderInfo3 {}
 windows_core::imp::define_interface!(IWMIStreamProps, IWMIStreamProps_Vtbl, 0x6816dad3_2b4b_4c8e_8149_874c3483a753);
 windows_core::imp::interface_hierarchy!(IWMIStreamProps, windows_core::IUnknown);
@@ -2697,6 +3977,20 @@ impl IWMIStreamProps_Vtbl {
         iid == &<IWMIStreamProps as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMIStreamProps_Impl> windows_core::imp::TearOffVtable<Identity> for IWMIStreamProps_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperty<Identity: windows_core::IUnknownImpl + IWMIStreamProps_Impl>(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pdwsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMIStreamProps_Impl::GetProperty(this, core::mem::transmute(&pszname), core::mem::transmute_copy(&ptype), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&pdwsize)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetProperty: GetProperty::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMIStreamProps {}
 windows_core::imp::define_interface!(IWMImageInfo, IWMImageInfo_Vtbl, 0x9f0aa3b6_7267_4d89_88f2_ba915aa5c4c6);
 windows_core::imp::interface_hierarchy!(IWMImageInfo, windows_core::IUnknown);
@@ -2752,6 +4046,36 @@ impl IWMImageInfo_Vtbl {
         iid == &<IWMImageInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMImageInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IWMImageInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetImageCount<Identity: windows_core::IUnknownImpl + IWMImageInfo_Impl>(this: *mut core::ffi::c_void, pcimages: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMImageInfo_Impl::GetImageCount(this) {
+                    Ok(ok__) => {
+                        pcimages.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetImage<Identity: windows_core::IUnknownImpl + IWMImageInfo_Impl>(this: *mut core::ffi::c_void, windex: u32, pcchmimetype: *mut u16, pwszmimetype: windows_core::PWSTR, pcchdescription: *mut u16, pwszdescription: windows_core::PWSTR, pimagetype: *mut u16, pcbimagedata: *mut u32, pbimagedata: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMImageInfo_Impl::GetImage(this, core::mem::transmute_copy(&windex), core::mem::transmute_copy(&pcchmimetype), core::mem::transmute_copy(&pwszmimetype), core::mem::transmute_copy(&pcchdescription), core::mem::transmute_copy(&pwszdescription), core::mem::transmute_copy(&pimagetype), core::mem::transmute_copy(&pcbimagedata), core::mem::transmute_copy(&pbimagedata)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetImageCount: GetImageCount::<Identity>,
+            GetImage: GetImage::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMImageInfo {}
 windows_core::imp::define_interface!(IWMIndexer, IWMIndexer_Vtbl, 0x6d7cdc71_9888_11d3_8edc_00c04f6109cf);
 windows_core::imp::interface_hierarchy!(IWMIndexer, windows_core::IUnknown);
@@ -2802,6 +4126,30 @@ impl IWMIndexer_Vtbl {
         iid == &<IWMIndexer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMIndexer_Impl> windows_core::imp::TearOffVtable<Identity> for IWMIndexer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StartIndexing<Identity: windows_core::IUnknownImpl + IWMIndexer_Impl>(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, pcallback: *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMIndexer_Impl::StartIndexing(this, core::mem::transmute(&pwszurl), core::mem::transmute_copy(&pcallback), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        unsafe extern "system" fn Cancel<Identity: windows_core::IUnknownImpl + IWMIndexer_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMIndexer_Impl::Cancel(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            StartIndexing: StartIndexing::<Identity>,
+            Cancel: Cancel::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMIndexer {}
 windows_core::imp::define_interface!(IWMIndexer2, IWMIndexer2_Vtbl, 0xb70f1e42_6255_4df0_a6b9_02b212d9e2bb);
 impl core::ops::Deref for IWMIndexer2 {
@@ -2839,6 +4187,20 @@ impl IWMIndexer2_Vtbl {
         iid == &<IWMIndexer2 as windows_core::Interface>::IID || iid == &<IWMIndexer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMIndexer2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMIndexer2_Vtbl
+where
+    IWMIndexer_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Configure<Identity: windows_core::IUnknownImpl + IWMIndexer2_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, nindexertype: WMT_INDEXER_TYPE, pvinterval: *const core::ffi::c_void, pvindextype: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMIndexer2_Impl::Configure(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&nindexertype), core::mem::transmute_copy(&pvinterval), core::mem::transmute_copy(&pvindextype)).into()
+            }
+        }
+        Self { base__: <IWMIndexer_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Configure: Configure::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMIndexer2 {}
 windows_core::imp::define_interface!(IWMInputMediaProps, IWMInputMediaProps_Vtbl, 0x96406bd5_2b2b_11d3_b36b_00c04f6108ff);
 impl core::ops::Deref for IWMInputMediaProps {
@@ -2891,6 +4253,30 @@ impl IWMInputMediaProps_Vtbl {
         iid == &<IWMInputMediaProps as windows_core::Interface>::IID || iid == &<IWMMediaProps as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMInputMediaProps_Impl> windows_core::imp::TearOffVtable<Identity> for IWMInputMediaProps_Vtbl
+where
+    IWMMediaProps_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetConnectionName<Identity: windows_core::IUnknownImpl + IWMInputMediaProps_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, pcchname: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMInputMediaProps_Impl::GetConnectionName(this, core::mem::transmute_copy(&pwszname), core::mem::transmute_copy(&pcchname)).into()
+            }
+        }
+        unsafe extern "system" fn GetGroupName<Identity: windows_core::IUnknownImpl + IWMInputMediaProps_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, pcchname: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMInputMediaProps_Impl::GetGroupName(this, core::mem::transmute_copy(&pwszname), core::mem::transmute_copy(&pcchname)).into()
+            }
+        }
+        Self {
+            base__: <IWMMediaProps_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetConnectionName: GetConnectionName::<Identity>,
+            GetGroupName: GetGroupName::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMInputMediaProps {}
 windows_core::imp::define_interface!(IWMLanguageList, IWMLanguageList_Vtbl, 0xdf683f00_2d49_4d8e_92b7_fb19f6a0dc57);
 windows_core::imp::interface_hierarchy!(IWMLanguageList, windows_core::IUnknown);
@@ -2970,6 +4356,49 @@ impl IWMLanguageList_Vtbl {
         iid == &<IWMLanguageList as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMLanguageList_Impl> windows_core::imp::TearOffVtable<Identity> for IWMLanguageList_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetLanguageCount<Identity: windows_core::IUnknownImpl + IWMLanguageList_Impl>(this: *mut core::ffi::c_void, pwcount: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMLanguageList_Impl::GetLanguageCount(this) {
+                    Ok(ok__) => {
+                        pwcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLanguageDetails<Identity: windows_core::IUnknownImpl + IWMLanguageList_Impl>(this: *mut core::ffi::c_void, windex: u16, pwszlanguagestring: windows_core::PWSTR, pcchlanguagestringlength: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMLanguageList_Impl::GetLanguageDetails(this, core::mem::transmute_copy(&windex), core::mem::transmute_copy(&pwszlanguagestring), core::mem::transmute_copy(&pcchlanguagestringlength)).into()
+            }
+        }
+        unsafe extern "system" fn AddLanguageByRFC1766String<Identity: windows_core::IUnknownImpl + IWMLanguageList_Impl>(this: *mut core::ffi::c_void, pwszlanguagestring: windows_core::PCWSTR, pwindex: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMLanguageList_Impl::AddLanguageByRFC1766String(this, core::mem::transmute(&pwszlanguagestring)) {
+                    Ok(ok__) => {
+                        pwindex.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetLanguageCount: GetLanguageCount::<Identity>,
+            GetLanguageDetails: GetLanguageDetails::<Identity>,
+            AddLanguageByRFC1766String: AddLanguageByRFC1766String::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMLanguageList {}
 windows_core::imp::define_interface!(IWMLicenseBackup, IWMLicenseBackup_Vtbl, 0x05e5ac9f_3fb6_4508_bb43_a4067ba1ebe8);
 windows_core::imp::interface_hierarchy!(IWMLicenseBackup, windows_core::IUnknown);
@@ -3019,12 +4448,36 @@ impl IWMLicenseBackup_Vtbl {
         iid == &<IWMLicenseBackup as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IWMLicenseBackup {}
-windows_core::imp::define_interface!(IWMLicenseRestore, IWMLicenseRestore_Vtbl, 0xc70b6334_a22e_4efb_a245_15e65a004a13);
-windows_core::imp::interface_hierarchy!(IWMLicenseRestore, windows_core::IUnknown);
-impl IWMLicenseRestore {
-    pub unsafe fn RestoreLicenses<P1>(&self, dwflags: u32, pcallback: P1) -> windows_core::Result<()>
-    where
+impl<Identity: windows_core::IUnknownImpl + IWMLicenseBackup_Impl> windows_core::imp::TearOffVtable<Identity> for IWMLicenseBackup_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BackupLicenses<Identity: windows_core::IUnknownImpl + IWMLicenseBackup_Impl>(this: *mut core::ffi::c_void, dwflags: u32, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMLicenseBackup_Impl::BackupLicenses(this, core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pcallback)).into()
+            }
+        }
+        unsafe extern "system" fn CancelLicenseBackup<Identity: windows_core::IUnknownImpl + IWMLicenseBackup_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMLicenseBackup_Impl::CancelLicenseBackup(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            BackupLicenses: BackupLicenses::<Identity>,
+            CancelLicenseBackup: CancelLicenseBackup::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IWMLicenseBackup {}
+windows_core::imp::define_interface!(IWMLicenseRestore, IWMLicenseRestore_Vtbl, 0xc70b6334_a22e_4efb_a245_15e65a004a13);
+windows_core::imp::interface_hierarchy!(IWMLicenseRestore, windows_core::IUnknown);
+impl IWMLicenseRestore {
+    pub unsafe fn RestoreLicenses<P1>(&self, dwflags: u32, pcallback: P1) -> windows_core::Result<()>
+    where
         P1: windows_core::Param<IWMStatusCallback>,
     {
         unsafe { (windows_core::Interface::vtable(self).RestoreLicenses)(windows_core::Interface::as_raw(self), dwflags, pcallback.param().abi()).ok() }
@@ -3068,6 +4521,30 @@ impl IWMLicenseRestore_Vtbl {
         iid == &<IWMLicenseRestore as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMLicenseRestore_Impl> windows_core::imp::TearOffVtable<Identity> for IWMLicenseRestore_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RestoreLicenses<Identity: windows_core::IUnknownImpl + IWMLicenseRestore_Impl>(this: *mut core::ffi::c_void, dwflags: u32, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMLicenseRestore_Impl::RestoreLicenses(this, core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pcallback)).into()
+            }
+        }
+        unsafe extern "system" fn CancelLicenseRestore<Identity: windows_core::IUnknownImpl + IWMLicenseRestore_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMLicenseRestore_Impl::CancelLicenseRestore(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RestoreLicenses: RestoreLicenses::<Identity>,
+            CancelLicenseRestore: CancelLicenseRestore::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMLicenseRestore {}
 windows_core::imp::define_interface!(IWMLicenseRevocationAgent, IWMLicenseRevocationAgent_Vtbl, 0x6967f2c9_4e26_4b57_8894_799880f7ac7b);
 windows_core::imp::interface_hierarchy!(IWMLicenseRevocationAgent, windows_core::IUnknown);
@@ -3114,6 +4591,30 @@ impl IWMLicenseRevocationAgent_Vtbl {
         iid == &<IWMLicenseRevocationAgent as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMLicenseRevocationAgent_Impl> windows_core::imp::TearOffVtable<Identity> for IWMLicenseRevocationAgent_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetLRBChallenge<Identity: windows_core::IUnknownImpl + IWMLicenseRevocationAgent_Impl>(this: *mut core::ffi::c_void, pmachineid: *const u8, dwmachineidlength: u32, pchallenge: *const u8, dwchallengelength: u32, pchallengeoutput: *mut u8, pdwchallengeoutputlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMLicenseRevocationAgent_Impl::GetLRBChallenge(this, core::mem::transmute_copy(&pmachineid), core::mem::transmute_copy(&dwmachineidlength), core::mem::transmute_copy(&pchallenge), core::mem::transmute_copy(&dwchallengelength), core::mem::transmute_copy(&pchallengeoutput), core::mem::transmute_copy(&pdwchallengeoutputlength)).into()
+            }
+        }
+        unsafe extern "system" fn ProcessLRB<Identity: windows_core::IUnknownImpl + IWMLicenseRevocationAgent_Impl>(this: *mut core::ffi::c_void, psignedlrb: *const u8, dwsignedlrblength: u32, psignedack: *mut u8, pdwsignedacklength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMLicenseRevocationAgent_Impl::ProcessLRB(this, core::mem::transmute_copy(&psignedlrb), core::mem::transmute_copy(&dwsignedlrblength), core::mem::transmute_copy(&psignedack), core::mem::transmute_copy(&pdwsignedacklength)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetLRBChallenge: GetLRBChallenge::<Identity>,
+            ProcessLRB: ProcessLRB::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMLicenseRevocationAgent {}
 windows_core::imp::define_interface!(IWMMediaProps, IWMMediaProps_Vtbl, 0x96406bce_2b2b_11d3_b36b_00c04f6108ff);
 windows_core::imp::interface_hierarchy!(IWMMediaProps, windows_core::IUnknown);
@@ -3181,6 +4682,43 @@ impl IWMMediaProps_Vtbl {
         iid == &<IWMMediaProps as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMMediaProps_Impl> windows_core::imp::TearOffVtable<Identity> for IWMMediaProps_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetType<Identity: windows_core::IUnknownImpl + IWMMediaProps_Impl>(this: *mut core::ffi::c_void, pguidtype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMMediaProps_Impl::GetType(this) {
+                    Ok(ok__) => {
+                        pguidtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMediaType<Identity: windows_core::IUnknownImpl + IWMMediaProps_Impl>(this: *mut core::ffi::c_void, ptype: *mut WM_MEDIA_TYPE, pcbtype: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMMediaProps_Impl::GetMediaType(this, core::mem::transmute_copy(&ptype), core::mem::transmute_copy(&pcbtype)).into()
+            }
+        }
+        unsafe extern "system" fn SetMediaType<Identity: windows_core::IUnknownImpl + IWMMediaProps_Impl>(this: *mut core::ffi::c_void, ptype: *const WM_MEDIA_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMMediaProps_Impl::SetMediaType(this, core::mem::transmute_copy(&ptype)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetType: GetType::<Identity>,
+            GetMediaType: GetMediaType::<Identity>,
+            SetMediaType: SetMediaType::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMMediaProps {}
 windows_core::imp::define_interface!(IWMMetadataEditor, IWMMetadataEditor_Vtbl, 0x96406bd9_2b2b_11d3_b36b_00c04f6108ff);
 windows_core::imp::interface_hierarchy!(IWMMetadataEditor, windows_core::IUnknown);
@@ -3242,6 +4780,37 @@ impl IWMMetadataEditor_Vtbl {
         iid == &<IWMMetadataEditor as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMMetadataEditor_Impl> windows_core::imp::TearOffVtable<Identity> for IWMMetadataEditor_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Open<Identity: windows_core::IUnknownImpl + IWMMetadataEditor_Impl>(this: *mut core::ffi::c_void, pwszfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMMetadataEditor_Impl::Open(this, core::mem::transmute(&pwszfilename)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IWMMetadataEditor_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMMetadataEditor_Impl::Close(this).into()
+            }
+        }
+        unsafe extern "system" fn Flush<Identity: windows_core::IUnknownImpl + IWMMetadataEditor_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMMetadataEditor_Impl::Flush(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Open: Open::<Identity>,
+            Close: Close::<Identity>,
+            Flush: Flush::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMMetadataEditor {}
 windows_core::imp::define_interface!(IWMMetadataEditor2, IWMMetadataEditor2_Vtbl, 0x203cffe3_2e18_4fdf_b59d_6e71530534cf);
 impl core::ops::Deref for IWMMetadataEditor2 {
@@ -3282,6 +4851,20 @@ impl IWMMetadataEditor2_Vtbl {
         iid == &<IWMMetadataEditor2 as windows_core::Interface>::IID || iid == &<IWMMetadataEditor as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMMetadataEditor2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMMetadataEditor2_Vtbl
+where
+    IWMMetadataEditor_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OpenEx<Identity: windows_core::IUnknownImpl + IWMMetadataEditor2_Impl>(this: *mut core::ffi::c_void, pwszfilename: windows_core::PCWSTR, dwdesiredaccess: u32, dwsharemode: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMMetadataEditor2_Impl::OpenEx(this, core::mem::transmute(&pwszfilename), core::mem::transmute_copy(&dwdesiredaccess), core::mem::transmute_copy(&dwsharemode)).into()
+            }
+        }
+        Self { base__: <IWMMetadataEditor_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OpenEx: OpenEx::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMMetadataEditor2 {}
 windows_core::imp::define_interface!(IWMMutualExclusion, IWMMutualExclusion_Vtbl, 0x96406bde_2b2b_11d3_b36b_00c04f6108ff);
 impl core::ops::Deref for IWMMutualExclusion {
@@ -3339,6 +4922,32 @@ impl IWMMutualExclusion_Vtbl {
         iid == &<IWMMutualExclusion as windows_core::Interface>::IID || iid == &<IWMStreamList as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMMutualExclusion_Impl> windows_core::imp::TearOffVtable<Identity> for IWMMutualExclusion_Vtbl
+where
+    IWMStreamList_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetType<Identity: windows_core::IUnknownImpl + IWMMutualExclusion_Impl>(this: *mut core::ffi::c_void, pguidtype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMMutualExclusion_Impl::GetType(this) {
+                    Ok(ok__) => {
+                        pguidtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetType<Identity: windows_core::IUnknownImpl + IWMMutualExclusion_Impl>(this: *mut core::ffi::c_void, guidtype: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMMutualExclusion_Impl::SetType(this, core::mem::transmute_copy(&guidtype)).into()
+            }
+        }
+        Self { base__: <IWMStreamList_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetType: GetType::<Identity>, SetType: SetType::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMMutualExclusion {}
 windows_core::imp::define_interface!(IWMMutualExclusion2, IWMMutualExclusion2_Vtbl, 0x0302b57d_89d1_4ba2_85c9_166f2c53eb91);
 impl core::ops::Deref for IWMMutualExclusion2 {
@@ -3502,6 +5111,92 @@ impl IWMMutualExclusion2_Vtbl {
         iid == &<IWMMutualExclusion2 as windows_core::Interface>::IID || iid == &<IWMStreamList as windows_core::Interface>::IID || iid == &<IWMMutualExclusion as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMMutualExclusion2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMMutualExclusion2_Vtbl
+where
+    IWMMutualExclusion_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IWMMutualExclusion2_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, pcchname: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMMutualExclusion2_Impl::GetName(this, core::mem::transmute_copy(&pwszname), core::mem::transmute_copy(&pcchname)).into()
+            }
+        }
+        unsafe extern "system" fn SetName<Identity: windows_core::IUnknownImpl + IWMMutualExclusion2_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMMutualExclusion2_Impl::SetName(this, core::mem::transmute(&pwszname)).into()
+            }
+        }
+        unsafe extern "system" fn GetRecordCount<Identity: windows_core::IUnknownImpl + IWMMutualExclusion2_Impl>(this: *mut core::ffi::c_void, pwrecordcount: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMMutualExclusion2_Impl::GetRecordCount(this) {
+                    Ok(ok__) => {
+                        pwrecordcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddRecord<Identity: windows_core::IUnknownImpl + IWMMutualExclusion2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMMutualExclusion2_Impl::AddRecord(this).into()
+            }
+        }
+        unsafe extern "system" fn RemoveRecord<Identity: windows_core::IUnknownImpl + IWMMutualExclusion2_Impl>(this: *mut core::ffi::c_void, wrecordnumber: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMMutualExclusion2_Impl::RemoveRecord(this, core::mem::transmute_copy(&wrecordnumber)).into()
+            }
+        }
+        unsafe extern "system" fn GetRecordName<Identity: windows_core::IUnknownImpl + IWMMutualExclusion2_Impl>(this: *mut core::ffi::c_void, wrecordnumber: u16, pwszrecordname: windows_core::PWSTR, pcchrecordname: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMMutualExclusion2_Impl::GetRecordName(this, core::mem::transmute_copy(&wrecordnumber), core::mem::transmute_copy(&pwszrecordname), core::mem::transmute_copy(&pcchrecordname)).into()
+            }
+        }
+        unsafe extern "system" fn SetRecordName<Identity: windows_core::IUnknownImpl + IWMMutualExclusion2_Impl>(this: *mut core::ffi::c_void, wrecordnumber: u16, pwszrecordname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMMutualExclusion2_Impl::SetRecordName(this, core::mem::transmute_copy(&wrecordnumber), core::mem::transmute(&pwszrecordname)).into()
+            }
+        }
+        unsafe extern "system" fn GetStreamsForRecord<Identity: windows_core::IUnknownImpl + IWMMutualExclusion2_Impl>(this: *mut core::ffi::c_void, wrecordnumber: u16, pwstreamnumarray: *mut u16, pcstreams: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMMutualExclusion2_Impl::GetStreamsForRecord(this, core::mem::transmute_copy(&wrecordnumber), core::mem::transmute_copy(&pwstreamnumarray), core::mem::transmute_copy(&pcstreams)).into()
+            }
+        }
+        unsafe extern "system" fn AddStreamForRecord<Identity: windows_core::IUnknownImpl + IWMMutualExclusion2_Impl>(this: *mut core::ffi::c_void, wrecordnumber: u16, wstreamnumber: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMMutualExclusion2_Impl::AddStreamForRecord(this, core::mem::transmute_copy(&wrecordnumber), core::mem::transmute_copy(&wstreamnumber)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveStreamForRecord<Identity: windows_core::IUnknownImpl + IWMMutualExclusion2_Impl>(this: *mut core::ffi::c_void, wrecordnumber: u16, wstreamnumber: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMMutualExclusion2_Impl::RemoveStreamForRecord(this, core::mem::transmute_copy(&wrecordnumber), core::mem::transmute_copy(&wstreamnumber)).into()
+            }
+        }
+        Self {
+            base__: <IWMMutualExclusion_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetName: GetName::<Identity>,
+            SetName: SetName::<Identity>,
+            GetRecordCount: GetRecordCount::<Identity>,
+            AddRecord: AddRecord::<Identity>,
+            RemoveRecord: RemoveRecord::<Identity>,
+            GetRecordName: GetRecordName::<Identity>,
+            SetRecordName: SetRecordName::<Identity>,
+            GetStreamsForRecord: GetStreamsForRecord::<Identity>,
+            AddStreamForRecord: AddStreamForRecord::<Identity>,
+            RemoveStreamForRecord: RemoveStreamForRecord::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMMutualExclusion2 {}
 windows_core::imp::define_interface!(IWMOutputMediaProps, IWMOutputMediaProps_Vtbl, 0x96406bd7_2b2b_11d3_b36b_00c04f6108ff);
 impl core::ops::Deref for IWMOutputMediaProps {
@@ -3554,6 +5249,30 @@ impl IWMOutputMediaProps_Vtbl {
         iid == &<IWMOutputMediaProps as windows_core::Interface>::IID || iid == &<IWMMediaProps as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMOutputMediaProps_Impl> windows_core::imp::TearOffVtable<Identity> for IWMOutputMediaProps_Vtbl
+where
+    IWMMediaProps_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStreamGroupName<Identity: windows_core::IUnknownImpl + IWMOutputMediaProps_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, pcchname: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMOutputMediaProps_Impl::GetStreamGroupName(this, core::mem::transmute_copy(&pwszname), core::mem::transmute_copy(&pcchname)).into()
+            }
+        }
+        unsafe extern "system" fn GetConnectionName<Identity: windows_core::IUnknownImpl + IWMOutputMediaProps_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, pcchname: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMOutputMediaProps_Impl::GetConnectionName(this, core::mem::transmute_copy(&pwszname), core::mem::transmute_copy(&pcchname)).into()
+            }
+        }
+        Self {
+            base__: <IWMMediaProps_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStreamGroupName: GetStreamGroupName::<Identity>,
+            GetConnectionName: GetConnectionName::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMOutputMediaProps {}
 windows_core::imp::define_interface!(IWMPacketSize, IWMPacketSize_Vtbl, 0xcdfb97ab_188f_40b3_b643_5b7903975c59);
 windows_core::imp::interface_hierarchy!(IWMPacketSize, windows_core::IUnknown);
@@ -3609,6 +5328,36 @@ impl IWMPacketSize_Vtbl {
         iid == &<IWMPacketSize as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMPacketSize_Impl> windows_core::imp::TearOffVtable<Identity> for IWMPacketSize_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMaxPacketSize<Identity: windows_core::IUnknownImpl + IWMPacketSize_Impl>(this: *mut core::ffi::c_void, pdwmaxpacketsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMPacketSize_Impl::GetMaxPacketSize(this) {
+                    Ok(ok__) => {
+                        pdwmaxpacketsize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMaxPacketSize<Identity: windows_core::IUnknownImpl + IWMPacketSize_Impl>(this: *mut core::ffi::c_void, dwmaxpacketsize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMPacketSize_Impl::SetMaxPacketSize(this, core::mem::transmute_copy(&dwmaxpacketsize)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetMaxPacketSize: GetMaxPacketSize::<Identity>,
+            SetMaxPacketSize: SetMaxPacketSize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMPacketSize {}
 windows_core::imp::define_interface!(IWMPacketSize2, IWMPacketSize2_Vtbl, 0x8bfc2b9e_b646_4233_a877_1c6a079669dc);
 impl core::ops::Deref for IWMPacketSize2 {
@@ -3670,6 +5419,36 @@ impl IWMPacketSize2_Vtbl {
         iid == &<IWMPacketSize2 as windows_core::Interface>::IID || iid == &<IWMPacketSize as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMPacketSize2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMPacketSize2_Vtbl
+where
+    IWMPacketSize_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMinPacketSize<Identity: windows_core::IUnknownImpl + IWMPacketSize2_Impl>(this: *mut core::ffi::c_void, pdwminpacketsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMPacketSize2_Impl::GetMinPacketSize(this) {
+                    Ok(ok__) => {
+                        pdwminpacketsize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMinPacketSize<Identity: windows_core::IUnknownImpl + IWMPacketSize2_Impl>(this: *mut core::ffi::c_void, dwminpacketsize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMPacketSize2_Impl::SetMinPacketSize(this, core::mem::transmute_copy(&dwminpacketsize)).into()
+            }
+        }
+        Self {
+            base__: <IWMPacketSize_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetMinPacketSize: GetMinPacketSize::<Identity>,
+            SetMinPacketSize: SetMinPacketSize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMPacketSize2 {}
 windows_core::imp::define_interface!(IWMPlayerHook, IWMPlayerHook_Vtbl, 0xe5b7ca9a_0f1c_4f66_9002_74ec50d8b304);
 windows_core::imp::interface_hierarchy!(IWMPlayerHook, windows_core::IUnknown);
@@ -3701,6 +5480,20 @@ impl IWMPlayerHook_Vtbl {
         iid == &<IWMPlayerHook as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMPlayerHook_Impl> windows_core::imp::TearOffVtable<Identity> for IWMPlayerHook_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn PreDecode<Identity: windows_core::IUnknownImpl + IWMPlayerHook_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMPlayerHook_Impl::PreDecode(this).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, PreDecode: PreDecode::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMPlayerHook {}
 windows_core::imp::define_interface!(IWMPlayerTimestampHook, IWMPlayerTimestampHook_Vtbl, 0x28580dda_d98e_48d0_b7ae_69e473a02825);
 windows_core::imp::interface_hierarchy!(IWMPlayerTimestampHook, windows_core::IUnknown);
@@ -3741,6 +5534,26 @@ impl IWMPlayerTimestampHook_Vtbl {
         iid == &<IWMPlayerTimestampHook as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMPlayerTimestampHook_Impl> windows_core::imp::TearOffVtable<Identity> for IWMPlayerTimestampHook_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn MapTimestamp<Identity: windows_core::IUnknownImpl + IWMPlayerTimestampHook_Impl>(this: *mut core::ffi::c_void, rtin: i64, prtout: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMPlayerTimestampHook_Impl::MapTimestamp(this, core::mem::transmute_copy(&rtin)) {
+                    Ok(ok__) => {
+                        prtout.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, MapTimestamp: MapTimestamp::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMPlayerTimestampHook {}
 windows_core::imp::define_interface!(IWMProfile, IWMProfile_Vtbl, 0x96406bdb_2b2b_11d3_b36b_00c04f6108ff);
 windows_core::imp::interface_hierarchy!(IWMProfile, windows_core::IUnknown);
@@ -4072,22 +5885,206 @@ impl IWMProfile_Vtbl {
         iid == &<IWMProfile as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IWMProfile {}
-windows_core::imp::define_interface!(IWMProfile2, IWMProfile2_Vtbl, 0x07e72d33_d94e_4be7_8843_60ae5ff7e5f5);
-impl core::ops::Deref for IWMProfile2 {
-    type Target = IWMProfile;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(IWMProfile2, windows_core::IUnknown, IWMProfile);
-impl IWMProfile2 {
-    pub unsafe fn GetProfileID(&self) -> windows_core::Result<windows_core::GUID> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetProfileID)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+impl<Identity: windows_core::IUnknownImpl + IWMProfile_Impl> windows_core::imp::TearOffVtable<Identity> for IWMProfile_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetVersion<Identity: windows_core::IUnknownImpl + IWMProfile_Impl>(this: *mut core::ffi::c_void, pdwversion: *mut WMT_VERSION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMProfile_Impl::GetVersion(this) {
+                    Ok(ok__) => {
+                        pdwversion.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IWMProfile_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, pcchname: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMProfile_Impl::GetName(this, core::mem::transmute_copy(&pwszname), core::mem::transmute_copy(&pcchname)).into()
+            }
+        }
+        unsafe extern "system" fn SetName<Identity: windows_core::IUnknownImpl + IWMProfile_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMProfile_Impl::SetName(this, core::mem::transmute(&pwszname)).into()
+            }
+        }
+        unsafe extern "system" fn GetDescription<Identity: windows_core::IUnknownImpl + IWMProfile_Impl>(this: *mut core::ffi::c_void, pwszdescription: windows_core::PWSTR, pcchdescription: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMProfile_Impl::GetDescription(this, core::mem::transmute_copy(&pwszdescription), core::mem::transmute_copy(&pcchdescription)).into()
+            }
+        }
+        unsafe extern "system" fn SetDescription<Identity: windows_core::IUnknownImpl + IWMProfile_Impl>(this: *mut core::ffi::c_void, pwszdescription: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMProfile_Impl::SetDescription(this, core::mem::transmute(&pwszdescription)).into()
+            }
+        }
+        unsafe extern "system" fn GetStreamCount<Identity: windows_core::IUnknownImpl + IWMProfile_Impl>(this: *mut core::ffi::c_void, pcstreams: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMProfile_Impl::GetStreamCount(this) {
+                    Ok(ok__) => {
+                        pcstreams.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStream<Identity: windows_core::IUnknownImpl + IWMProfile_Impl>(this: *mut core::ffi::c_void, dwstreamindex: u32, ppconfig: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMProfile_Impl::GetStream(this, core::mem::transmute_copy(&dwstreamindex)) {
+                    Ok(ok__) => {
+                        ppconfig.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStreamByNumber<Identity: windows_core::IUnknownImpl + IWMProfile_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, ppconfig: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMProfile_Impl::GetStreamByNumber(this, core::mem::transmute_copy(&wstreamnum)) {
+                    Ok(ok__) => {
+                        ppconfig.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveStream<Identity: windows_core::IUnknownImpl + IWMProfile_Impl>(this: *mut core::ffi::c_void, pconfig: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMProfile_Impl::RemoveStream(this, core::mem::transmute_copy(&pconfig)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveStreamByNumber<Identity: windows_core::IUnknownImpl + IWMProfile_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMProfile_Impl::RemoveStreamByNumber(this, core::mem::transmute_copy(&wstreamnum)).into()
+            }
+        }
+        unsafe extern "system" fn AddStream<Identity: windows_core::IUnknownImpl + IWMProfile_Impl>(this: *mut core::ffi::c_void, pconfig: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMProfile_Impl::AddStream(this, core::mem::transmute_copy(&pconfig)).into()
+            }
+        }
+        unsafe extern "system" fn ReconfigStream<Identity: windows_core::IUnknownImpl + IWMProfile_Impl>(this: *mut core::ffi::c_void, pconfig: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMProfile_Impl::ReconfigStream(this, core::mem::transmute_copy(&pconfig)).into()
+            }
+        }
+        unsafe extern "system" fn CreateNewStream<Identity: windows_core::IUnknownImpl + IWMProfile_Impl>(this: *mut core::ffi::c_void, guidstreamtype: *const windows_core::GUID, ppconfig: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMProfile_Impl::CreateNewStream(this, core::mem::transmute_copy(&guidstreamtype)) {
+                    Ok(ok__) => {
+                        ppconfig.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMutualExclusionCount<Identity: windows_core::IUnknownImpl + IWMProfile_Impl>(this: *mut core::ffi::c_void, pcme: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMProfile_Impl::GetMutualExclusionCount(this) {
+                    Ok(ok__) => {
+                        pcme.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMutualExclusion<Identity: windows_core::IUnknownImpl + IWMProfile_Impl>(this: *mut core::ffi::c_void, dwmeindex: u32, ppme: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMProfile_Impl::GetMutualExclusion(this, core::mem::transmute_copy(&dwmeindex)) {
+                    Ok(ok__) => {
+                        ppme.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveMutualExclusion<Identity: windows_core::IUnknownImpl + IWMProfile_Impl>(this: *mut core::ffi::c_void, pme: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMProfile_Impl::RemoveMutualExclusion(this, core::mem::transmute_copy(&pme)).into()
+            }
+        }
+        unsafe extern "system" fn AddMutualExclusion<Identity: windows_core::IUnknownImpl + IWMProfile_Impl>(this: *mut core::ffi::c_void, pme: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMProfile_Impl::AddMutualExclusion(this, core::mem::transmute_copy(&pme)).into()
+            }
+        }
+        unsafe extern "system" fn CreateNewMutualExclusion<Identity: windows_core::IUnknownImpl + IWMProfile_Impl>(this: *mut core::ffi::c_void, ppme: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMProfile_Impl::CreateNewMutualExclusion(this) {
+                    Ok(ok__) => {
+                        ppme.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetVersion: GetVersion::<Identity>,
+            GetName: GetName::<Identity>,
+            SetName: SetName::<Identity>,
+            GetDescription: GetDescription::<Identity>,
+            SetDescription: SetDescription::<Identity>,
+            GetStreamCount: GetStreamCount::<Identity>,
+            GetStream: GetStream::<Identity>,
+            GetStreamByNumber: GetStreamByNumber::<Identity>,
+            RemoveStream: RemoveStream::<Identity>,
+            RemoveStreamByNumber: RemoveStreamByNumber::<Identity>,
+            AddStream: AddStream::<Identity>,
+            ReconfigStream: ReconfigStream::<Identity>,
+            CreateNewStream: CreateNewStream::<Identity>,
+            GetMutualExclusionCount: GetMutualExclusionCount::<Identity>,
+            GetMutualExclusion: GetMutualExclusion::<Identity>,
+            RemoveMutualExclusion: RemoveMutualExclusion::<Identity>,
+            AddMutualExclusion: AddMutualExclusion::<Identity>,
+            CreateNewMutualExclusion: CreateNewMutualExclusion::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IWMProfile {}
+windows_core::imp::define_interface!(IWMProfile2, IWMProfile2_Vtbl, 0x07e72d33_d94e_4be7_8843_60ae5ff7e5f5);
+impl core::ops::Deref for IWMProfile2 {
+    type Target = IWMProfile;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(IWMProfile2, windows_core::IUnknown, IWMProfile);
+impl IWMProfile2 {
+    pub unsafe fn GetProfileID(&self) -> windows_core::Result<windows_core::GUID> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetProfileID)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
 }
 #[repr(C)]
 #[doc(hidden)]
@@ -4118,6 +6115,26 @@ impl IWMProfile2_Vtbl {
         iid == &<IWMProfile2 as windows_core::Interface>::IID || iid == &<IWMProfile as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMProfile2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMProfile2_Vtbl
+where
+    IWMProfile_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProfileID<Identity: windows_core::IUnknownImpl + IWMProfile2_Impl>(this: *mut core::ffi::c_void, pguidid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMProfile2_Impl::GetProfileID(this) {
+                    Ok(ok__) => {
+                        pguidid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IWMProfile_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetProfileID: GetProfileID::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMProfile2 {}
 windows_core::imp::define_interface!(IWMProfile3, IWMProfile3_Vtbl, 0x00ef96cc_a461_4546_8bcd_c9a28f0e06f5);
 impl core::ops::Deref for IWMProfile3 {
@@ -4362,6 +6379,142 @@ impl IWMProfile3_Vtbl {
         iid == &<IWMProfile3 as windows_core::Interface>::IID || iid == &<IWMProfile as windows_core::Interface>::IID || iid == &<IWMProfile2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMProfile3_Impl> windows_core::imp::TearOffVtable<Identity> for IWMProfile3_Vtbl
+where
+    IWMProfile2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStorageFormat<Identity: windows_core::IUnknownImpl + IWMProfile3_Impl>(this: *mut core::ffi::c_void, pnstorageformat: *mut WMT_STORAGE_FORMAT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMProfile3_Impl::GetStorageFormat(this) {
+                    Ok(ok__) => {
+                        pnstorageformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetStorageFormat<Identity: windows_core::IUnknownImpl + IWMProfile3_Impl>(this: *mut core::ffi::c_void, nstorageformat: WMT_STORAGE_FORMAT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMProfile3_Impl::SetStorageFormat(this, core::mem::transmute_copy(&nstorageformat)).into()
+            }
+        }
+        unsafe extern "system" fn GetBandwidthSharingCount<Identity: windows_core::IUnknownImpl + IWMProfile3_Impl>(this: *mut core::ffi::c_void, pcbs: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMProfile3_Impl::GetBandwidthSharingCount(this) {
+                    Ok(ok__) => {
+                        pcbs.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetBandwidthSharing<Identity: windows_core::IUnknownImpl + IWMProfile3_Impl>(this: *mut core::ffi::c_void, dwbsindex: u32, ppbs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMProfile3_Impl::GetBandwidthSharing(this, core::mem::transmute_copy(&dwbsindex)) {
+                    Ok(ok__) => {
+                        ppbs.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveBandwidthSharing<Identity: windows_core::IUnknownImpl + IWMProfile3_Impl>(this: *mut core::ffi::c_void, pbs: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMProfile3_Impl::RemoveBandwidthSharing(this, core::mem::transmute_copy(&pbs)).into()
+            }
+        }
+        unsafe extern "system" fn AddBandwidthSharing<Identity: windows_core::IUnknownImpl + IWMProfile3_Impl>(this: *mut core::ffi::c_void, pbs: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMProfile3_Impl::AddBandwidthSharing(this, core::mem::transmute_copy(&pbs)).into()
+            }
+        }
+        unsafe extern "system" fn CreateNewBandwidthSharing<Identity: windows_core::IUnknownImpl + IWMProfile3_Impl>(this: *mut core::ffi::c_void, ppbs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMProfile3_Impl::CreateNewBandwidthSharing(this) {
+                    Ok(ok__) => {
+                        ppbs.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStreamPrioritization<Identity: windows_core::IUnknownImpl + IWMProfile3_Impl>(this: *mut core::ffi::c_void, ppsp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMProfile3_Impl::GetStreamPrioritization(this) {
+                    Ok(ok__) => {
+                        ppsp.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetStreamPrioritization<Identity: windows_core::IUnknownImpl + IWMProfile3_Impl>(this: *mut core::ffi::c_void, psp: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMProfile3_Impl::SetStreamPrioritization(this, core::mem::transmute_copy(&psp)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveStreamPrioritization<Identity: windows_core::IUnknownImpl + IWMProfile3_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMProfile3_Impl::RemoveStreamPrioritization(this).into()
+            }
+        }
+        unsafe extern "system" fn CreateNewStreamPrioritization<Identity: windows_core::IUnknownImpl + IWMProfile3_Impl>(this: *mut core::ffi::c_void, ppsp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMProfile3_Impl::CreateNewStreamPrioritization(this) {
+                    Ok(ok__) => {
+                        ppsp.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetExpectedPacketCount<Identity: windows_core::IUnknownImpl + IWMProfile3_Impl>(this: *mut core::ffi::c_void, msduration: u64, pcpackets: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMProfile3_Impl::GetExpectedPacketCount(this, core::mem::transmute_copy(&msduration)) {
+                    Ok(ok__) => {
+                        pcpackets.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWMProfile2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStorageFormat: GetStorageFormat::<Identity>,
+            SetStorageFormat: SetStorageFormat::<Identity>,
+            GetBandwidthSharingCount: GetBandwidthSharingCount::<Identity>,
+            GetBandwidthSharing: GetBandwidthSharing::<Identity>,
+            RemoveBandwidthSharing: RemoveBandwidthSharing::<Identity>,
+            AddBandwidthSharing: AddBandwidthSharing::<Identity>,
+            CreateNewBandwidthSharing: CreateNewBandwidthSharing::<Identity>,
+            GetStreamPrioritization: GetStreamPrioritization::<Identity>,
+            SetStreamPrioritization: SetStreamPrioritization::<Identity>,
+            RemoveStreamPrioritization: RemoveStreamPrioritization::<Identity>,
+            CreateNewStreamPrioritization: CreateNewStreamPrioritization::<Identity>,
+            GetExpectedPacketCount: GetExpectedPacketCount::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMProfile3 {}
 windows_core::imp::define_interface!(IWMProfileManager, IWMProfileManager_Vtbl, 0xd16679f2_6ca0_472d_8d31_2f5d55aee155);
 windows_core::imp::interface_hierarchy!(IWMProfileManager, windows_core::IUnknown);
@@ -4508,27 +6661,109 @@ impl IWMProfileManager_Vtbl {
         iid == &<IWMProfileManager as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IWMProfileManager {}
-windows_core::imp::define_interface!(IWMProfileManager2, IWMProfileManager2_Vtbl, 0x7a924e51_73c1_494d_8019_23d37ed9b89a);
-impl core::ops::Deref for IWMProfileManager2 {
-    type Target = IWMProfileManager;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(IWMProfileManager2, windows_core::IUnknown, IWMProfileManager);
-impl IWMProfileManager2 {
-    pub unsafe fn GetSystemProfileVersion(&self, pdwversion: *mut WMT_VERSION) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetSystemProfileVersion)(windows_core::Interface::as_raw(self), pdwversion as _).ok() }
-    }
-    pub unsafe fn SetSystemProfileVersion(&self, dwversion: WMT_VERSION) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetSystemProfileVersion)(windows_core::Interface::as_raw(self), dwversion).ok() }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IWMProfileManager2_Vtbl {
-    pub base__: IWMProfileManager_Vtbl,
+impl<Identity: windows_core::IUnknownImpl + IWMProfileManager_Impl> windows_core::imp::TearOffVtable<Identity> for IWMProfileManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateEmptyProfile<Identity: windows_core::IUnknownImpl + IWMProfileManager_Impl>(this: *mut core::ffi::c_void, dwversion: WMT_VERSION, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMProfileManager_Impl::CreateEmptyProfile(this, core::mem::transmute_copy(&dwversion)) {
+                    Ok(ok__) => {
+                        ppprofile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LoadProfileByID<Identity: windows_core::IUnknownImpl + IWMProfileManager_Impl>(this: *mut core::ffi::c_void, guidprofile: *const windows_core::GUID, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMProfileManager_Impl::LoadProfileByID(this, core::mem::transmute_copy(&guidprofile)) {
+                    Ok(ok__) => {
+                        ppprofile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LoadProfileByData<Identity: windows_core::IUnknownImpl + IWMProfileManager_Impl>(this: *mut core::ffi::c_void, pwszprofile: windows_core::PCWSTR, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMProfileManager_Impl::LoadProfileByData(this, core::mem::transmute(&pwszprofile)) {
+                    Ok(ok__) => {
+                        ppprofile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SaveProfile<Identity: windows_core::IUnknownImpl + IWMProfileManager_Impl>(this: *mut core::ffi::c_void, piwmprofile: *mut core::ffi::c_void, pwszprofile: windows_core::PCWSTR, pdwlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMProfileManager_Impl::SaveProfile(this, core::mem::transmute_copy(&piwmprofile), core::mem::transmute(&pwszprofile), core::mem::transmute_copy(&pdwlength)).into()
+            }
+        }
+        unsafe extern "system" fn GetSystemProfileCount<Identity: windows_core::IUnknownImpl + IWMProfileManager_Impl>(this: *mut core::ffi::c_void, pcprofiles: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMProfileManager_Impl::GetSystemProfileCount(this) {
+                    Ok(ok__) => {
+                        pcprofiles.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LoadSystemProfile<Identity: windows_core::IUnknownImpl + IWMProfileManager_Impl>(this: *mut core::ffi::c_void, dwprofileindex: u32, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMProfileManager_Impl::LoadSystemProfile(this, core::mem::transmute_copy(&dwprofileindex)) {
+                    Ok(ok__) => {
+                        ppprofile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateEmptyProfile: CreateEmptyProfile::<Identity>,
+            LoadProfileByID: LoadProfileByID::<Identity>,
+            LoadProfileByData: LoadProfileByData::<Identity>,
+            SaveProfile: SaveProfile::<Identity>,
+            GetSystemProfileCount: GetSystemProfileCount::<Identity>,
+            LoadSystemProfile: LoadSystemProfile::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IWMProfileManager {}
+windows_core::imp::define_interface!(IWMProfileManager2, IWMProfileManager2_Vtbl, 0x7a924e51_73c1_494d_8019_23d37ed9b89a);
+impl core::ops::Deref for IWMProfileManager2 {
+    type Target = IWMProfileManager;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(IWMProfileManager2, windows_core::IUnknown, IWMProfileManager);
+impl IWMProfileManager2 {
+    pub unsafe fn GetSystemProfileVersion(&self, pdwversion: *mut WMT_VERSION) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetSystemProfileVersion)(windows_core::Interface::as_raw(self), pdwversion as _).ok() }
+    }
+    pub unsafe fn SetSystemProfileVersion(&self, dwversion: WMT_VERSION) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetSystemProfileVersion)(windows_core::Interface::as_raw(self), dwversion).ok() }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IWMProfileManager2_Vtbl {
+    pub base__: IWMProfileManager_Vtbl,
     pub GetSystemProfileVersion: unsafe extern "system" fn(*mut core::ffi::c_void, *mut WMT_VERSION) -> windows_core::HRESULT,
     pub SetSystemProfileVersion: unsafe extern "system" fn(*mut core::ffi::c_void, WMT_VERSION) -> windows_core::HRESULT,
 }
@@ -4560,6 +6795,30 @@ impl IWMProfileManager2_Vtbl {
         iid == &<IWMProfileManager2 as windows_core::Interface>::IID || iid == &<IWMProfileManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMProfileManager2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMProfileManager2_Vtbl
+where
+    IWMProfileManager_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSystemProfileVersion<Identity: windows_core::IUnknownImpl + IWMProfileManager2_Impl>(this: *mut core::ffi::c_void, pdwversion: *mut WMT_VERSION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMProfileManager2_Impl::GetSystemProfileVersion(this, core::mem::transmute_copy(&pdwversion)).into()
+            }
+        }
+        unsafe extern "system" fn SetSystemProfileVersion<Identity: windows_core::IUnknownImpl + IWMProfileManager2_Impl>(this: *mut core::ffi::c_void, dwversion: WMT_VERSION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMProfileManager2_Impl::SetSystemProfileVersion(this, core::mem::transmute_copy(&dwversion)).into()
+            }
+        }
+        Self {
+            base__: <IWMProfileManager_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSystemProfileVersion: GetSystemProfileVersion::<Identity>,
+            SetSystemProfileVersion: SetSystemProfileVersion::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMProfileManager2 {}
 windows_core::imp::define_interface!(IWMProfileManagerLanguage, IWMProfileManagerLanguage_Vtbl, 0xba4dcc78_7ee0_4ab8_b27a_dbce8bc51454);
 windows_core::imp::interface_hierarchy!(IWMProfileManagerLanguage, windows_core::IUnknown);
@@ -4606,6 +6865,30 @@ impl IWMProfileManagerLanguage_Vtbl {
         iid == &<IWMProfileManagerLanguage as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMProfileManagerLanguage_Impl> windows_core::imp::TearOffVtable<Identity> for IWMProfileManagerLanguage_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetUserLanguageID<Identity: windows_core::IUnknownImpl + IWMProfileManagerLanguage_Impl>(this: *mut core::ffi::c_void, wlangid: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMProfileManagerLanguage_Impl::GetUserLanguageID(this, core::mem::transmute_copy(&wlangid)).into()
+            }
+        }
+        unsafe extern "system" fn SetUserLanguageID<Identity: windows_core::IUnknownImpl + IWMProfileManagerLanguage_Impl>(this: *mut core::ffi::c_void, wlangid: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMProfileManagerLanguage_Impl::SetUserLanguageID(this, core::mem::transmute_copy(&wlangid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetUserLanguageID: GetUserLanguageID::<Identity>,
+            SetUserLanguageID: SetUserLanguageID::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMProfileManagerLanguage {}
 windows_core::imp::define_interface!(IWMPropertyVault, IWMPropertyVault_Vtbl, 0x72995a79_5090_42a4_9c8c_d9d0b6d34be5);
 windows_core::imp::interface_hierarchy!(IWMPropertyVault, windows_core::IUnknown);
@@ -4709,6 +6992,58 @@ impl IWMPropertyVault_Vtbl {
         iid == &<IWMPropertyVault as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMPropertyVault_Impl> windows_core::imp::TearOffVtable<Identity> for IWMPropertyVault_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPropertyCount<Identity: windows_core::IUnknownImpl + IWMPropertyVault_Impl>(this: *mut core::ffi::c_void, pdwcount: *const u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMPropertyVault_Impl::GetPropertyCount(this, core::mem::transmute_copy(&pdwcount)).into()
+            }
+        }
+        unsafe extern "system" fn GetPropertyByName<Identity: windows_core::IUnknownImpl + IWMPropertyVault_Impl>(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pdwsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMPropertyVault_Impl::GetPropertyByName(this, core::mem::transmute(&pszname), core::mem::transmute_copy(&ptype), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&pdwsize)).into()
+            }
+        }
+        unsafe extern "system" fn SetProperty<Identity: windows_core::IUnknownImpl + IWMPropertyVault_Impl>(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, ptype: WMT_ATTR_DATATYPE, pvalue: *const u8, dwsize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMPropertyVault_Impl::SetProperty(this, core::mem::transmute(&pszname), core::mem::transmute_copy(&ptype), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&dwsize)).into()
+            }
+        }
+        unsafe extern "system" fn GetPropertyByIndex<Identity: windows_core::IUnknownImpl + IWMPropertyVault_Impl>(this: *mut core::ffi::c_void, dwindex: u32, pszname: windows_core::PWSTR, pdwnamelen: *mut u32, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pdwsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMPropertyVault_Impl::GetPropertyByIndex(this, core::mem::transmute_copy(&dwindex), core::mem::transmute_copy(&pszname), core::mem::transmute_copy(&pdwnamelen), core::mem::transmute_copy(&ptype), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&pdwsize)).into()
+            }
+        }
+        unsafe extern "system" fn CopyPropertiesFrom<Identity: windows_core::IUnknownImpl + IWMPropertyVault_Impl>(this: *mut core::ffi::c_void, piwmpropertyvault: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMPropertyVault_Impl::CopyPropertiesFrom(this, core::mem::transmute_copy(&piwmpropertyvault)).into()
+            }
+        }
+        unsafe extern "system" fn Clear<Identity: windows_core::IUnknownImpl + IWMPropertyVault_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMPropertyVault_Impl::Clear(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPropertyCount: GetPropertyCount::<Identity>,
+            GetPropertyByName: GetPropertyByName::<Identity>,
+            SetProperty: SetProperty::<Identity>,
+            GetPropertyByIndex: GetPropertyByIndex::<Identity>,
+            CopyPropertiesFrom: CopyPropertiesFrom::<Identity>,
+            Clear: Clear::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMPropertyVault {}
 windows_core::imp::define_interface!(IWMProximityDetection, IWMProximityDetection_Vtbl, 0x6a9fd8ee_b651_4bf0_b849_7d4ece79a2b1);
 windows_core::imp::interface_hierarchy!(IWMProximityDetection, windows_core::IUnknown);
@@ -4743,6 +7078,20 @@ impl IWMProximityDetection_Vtbl {
         iid == &<IWMProximityDetection as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMProximityDetection_Impl> windows_core::imp::TearOffVtable<Identity> for IWMProximityDetection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StartDetection<Identity: windows_core::IUnknownImpl + IWMProximityDetection_Impl>(this: *mut core::ffi::c_void, pbregistrationmsg: *const u8, cbregistrationmsg: u32, pblocaladdress: *const u8, cblocaladdress: u32, dwextraportsallowed: u32, ppregistrationresponsemsg: *mut *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMProximityDetection_Impl::StartDetection(this, core::mem::transmute_copy(&pbregistrationmsg), core::mem::transmute_copy(&cbregistrationmsg), core::mem::transmute_copy(&pblocaladdress), core::mem::transmute_copy(&cblocaladdress), core::mem::transmute_copy(&dwextraportsallowed), core::mem::transmute_copy(&ppregistrationresponsemsg), core::mem::transmute_copy(&pcallback), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, StartDetection: StartDetection::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMProximityDetection {}
 windows_core::imp::define_interface!(IWMReader, IWMReader_Vtbl, 0x96406bd6_2b2b_11d3_b36b_00c04f6108ff);
 windows_core::imp::interface_hierarchy!(IWMReader, windows_core::IUnknown);
@@ -4940,6 +7289,117 @@ impl IWMReader_Vtbl {
         iid == &<IWMReader as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMReader_Impl> windows_core::imp::TearOffVtable<Identity> for IWMReader_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Open<Identity: windows_core::IUnknownImpl + IWMReader_Impl>(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, pcallback: *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReader_Impl::Open(this, core::mem::transmute(&pwszurl), core::mem::transmute_copy(&pcallback), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IWMReader_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReader_Impl::Close(this).into()
+            }
+        }
+        unsafe extern "system" fn GetOutputCount<Identity: windows_core::IUnknownImpl + IWMReader_Impl>(this: *mut core::ffi::c_void, pcoutputs: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReader_Impl::GetOutputCount(this) {
+                    Ok(ok__) => {
+                        pcoutputs.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOutputProps<Identity: windows_core::IUnknownImpl + IWMReader_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, ppoutput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReader_Impl::GetOutputProps(this, core::mem::transmute_copy(&dwoutputnum)) {
+                    Ok(ok__) => {
+                        ppoutput.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetOutputProps<Identity: windows_core::IUnknownImpl + IWMReader_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, poutput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReader_Impl::SetOutputProps(this, core::mem::transmute_copy(&dwoutputnum), core::mem::transmute_copy(&poutput)).into()
+            }
+        }
+        unsafe extern "system" fn GetOutputFormatCount<Identity: windows_core::IUnknownImpl + IWMReader_Impl>(this: *mut core::ffi::c_void, dwoutputnumber: u32, pcformats: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReader_Impl::GetOutputFormatCount(this, core::mem::transmute_copy(&dwoutputnumber)) {
+                    Ok(ok__) => {
+                        pcformats.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOutputFormat<Identity: windows_core::IUnknownImpl + IWMReader_Impl>(this: *mut core::ffi::c_void, dwoutputnumber: u32, dwformatnumber: u32, ppprops: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReader_Impl::GetOutputFormat(this, core::mem::transmute_copy(&dwoutputnumber), core::mem::transmute_copy(&dwformatnumber)) {
+                    Ok(ok__) => {
+                        ppprops.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Start<Identity: windows_core::IUnknownImpl + IWMReader_Impl>(this: *mut core::ffi::c_void, cnsstart: u64, cnsduration: u64, frate: f32, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReader_Impl::Start(this, core::mem::transmute_copy(&cnsstart), core::mem::transmute_copy(&cnsduration), core::mem::transmute_copy(&frate), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + IWMReader_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReader_Impl::Stop(this).into()
+            }
+        }
+        unsafe extern "system" fn Pause<Identity: windows_core::IUnknownImpl + IWMReader_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReader_Impl::Pause(this).into()
+            }
+        }
+        unsafe extern "system" fn Resume<Identity: windows_core::IUnknownImpl + IWMReader_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReader_Impl::Resume(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Open: Open::<Identity>,
+            Close: Close::<Identity>,
+            GetOutputCount: GetOutputCount::<Identity>,
+            GetOutputProps: GetOutputProps::<Identity>,
+            SetOutputProps: SetOutputProps::<Identity>,
+            GetOutputFormatCount: GetOutputFormatCount::<Identity>,
+            GetOutputFormat: GetOutputFormat::<Identity>,
+            Start: Start::<Identity>,
+            Stop: Stop::<Identity>,
+            Pause: Pause::<Identity>,
+            Resume: Resume::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMReader {}
 windows_core::imp::define_interface!(IWMReaderAccelerator, IWMReaderAccelerator_Vtbl, 0xbddc4d08_944d_4d52_a612_46c3fda07dd4);
 windows_core::imp::interface_hierarchy!(IWMReaderAccelerator, windows_core::IUnknown);
@@ -4986,6 +7446,30 @@ impl IWMReaderAccelerator_Vtbl {
         iid == &<IWMReaderAccelerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMReaderAccelerator_Impl> windows_core::imp::TearOffVtable<Identity> for IWMReaderAccelerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCodecInterface<Identity: windows_core::IUnknownImpl + IWMReaderAccelerator_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, riid: *const windows_core::GUID, ppvcodecinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAccelerator_Impl::GetCodecInterface(this, core::mem::transmute_copy(&dwoutputnum), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvcodecinterface)).into()
+            }
+        }
+        unsafe extern "system" fn Notify<Identity: windows_core::IUnknownImpl + IWMReaderAccelerator_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, psubtype: *const WM_MEDIA_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAccelerator_Impl::Notify(this, core::mem::transmute_copy(&dwoutputnum), core::mem::transmute_copy(&psubtype)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCodecInterface: GetCodecInterface::<Identity>,
+            Notify: Notify::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMReaderAccelerator {}
 windows_core::imp::define_interface!(IWMReaderAdvanced, IWMReaderAdvanced_Vtbl, 0x96406bea_2b2b_11d3_b36b_00c04f6108ff);
 windows_core::imp::interface_hierarchy!(IWMReaderAdvanced, windows_core::IUnknown);
@@ -5329,31 +7813,235 @@ impl IWMReaderAdvanced_Vtbl {
         iid == &<IWMReaderAdvanced as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IWMReaderAdvanced {}
-windows_core::imp::define_interface!(IWMReaderAdvanced2, IWMReaderAdvanced2_Vtbl, 0xae14a945_b90c_4d0d_9127_80d665f7d73e);
-impl core::ops::Deref for IWMReaderAdvanced2 {
-    type Target = IWMReaderAdvanced;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(IWMReaderAdvanced2, windows_core::IUnknown, IWMReaderAdvanced);
-impl IWMReaderAdvanced2 {
-    pub unsafe fn SetPlayMode(&self, mode: WMT_PLAY_MODE) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetPlayMode)(windows_core::Interface::as_raw(self), mode).ok() }
-    }
-    pub unsafe fn GetPlayMode(&self) -> windows_core::Result<WMT_PLAY_MODE> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetPlayMode)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+impl<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced_Impl> windows_core::imp::TearOffVtable<Identity> for IWMReaderAdvanced_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetUserProvidedClock<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced_Impl>(this: *mut core::ffi::c_void, fuserclock: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced_Impl::SetUserProvidedClock(this, core::mem::transmute_copy(&fuserclock)).into()
+            }
         }
-    }
-    pub unsafe fn GetBufferProgress(&self, pdwpercent: *mut u32, pcnsbuffering: *mut u64) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetBufferProgress)(windows_core::Interface::as_raw(self), pdwpercent as _, pcnsbuffering as _).ok() }
-    }
-    pub unsafe fn GetDownloadProgress(&self, pdwpercent: *mut u32, pqwbytesdownloaded: *mut u64, pcnsdownload: *mut u64) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetDownloadProgress)(windows_core::Interface::as_raw(self), pdwpercent as _, pqwbytesdownloaded as _, pcnsdownload as _).ok() }
-    }
+        unsafe extern "system" fn GetUserProvidedClock<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced_Impl>(this: *mut core::ffi::c_void, pfuserclock: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderAdvanced_Impl::GetUserProvidedClock(this) {
+                    Ok(ok__) => {
+                        pfuserclock.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeliverTime<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced_Impl>(this: *mut core::ffi::c_void, cnstime: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced_Impl::DeliverTime(this, core::mem::transmute_copy(&cnstime)).into()
+            }
+        }
+        unsafe extern "system" fn SetManualStreamSelection<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced_Impl>(this: *mut core::ffi::c_void, fselection: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced_Impl::SetManualStreamSelection(this, core::mem::transmute_copy(&fselection)).into()
+            }
+        }
+        unsafe extern "system" fn GetManualStreamSelection<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced_Impl>(this: *mut core::ffi::c_void, pfselection: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderAdvanced_Impl::GetManualStreamSelection(this) {
+                    Ok(ok__) => {
+                        pfselection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetStreamsSelected<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced_Impl>(this: *mut core::ffi::c_void, cstreamcount: u16, pwstreamnumbers: *const u16, pselections: *const WMT_STREAM_SELECTION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced_Impl::SetStreamsSelected(this, core::mem::transmute_copy(&cstreamcount), core::mem::transmute_copy(&pwstreamnumbers), core::mem::transmute_copy(&pselections)).into()
+            }
+        }
+        unsafe extern "system" fn GetStreamSelected<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, pselection: *mut WMT_STREAM_SELECTION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderAdvanced_Impl::GetStreamSelected(this, core::mem::transmute_copy(&wstreamnum)) {
+                    Ok(ok__) => {
+                        pselection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetReceiveSelectionCallbacks<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced_Impl>(this: *mut core::ffi::c_void, fgetcallbacks: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced_Impl::SetReceiveSelectionCallbacks(this, core::mem::transmute_copy(&fgetcallbacks)).into()
+            }
+        }
+        unsafe extern "system" fn GetReceiveSelectionCallbacks<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced_Impl>(this: *mut core::ffi::c_void, pfgetcallbacks: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderAdvanced_Impl::GetReceiveSelectionCallbacks(this) {
+                    Ok(ok__) => {
+                        pfgetcallbacks.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetReceiveStreamSamples<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, freceivestreamsamples: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced_Impl::SetReceiveStreamSamples(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&freceivestreamsamples)).into()
+            }
+        }
+        unsafe extern "system" fn GetReceiveStreamSamples<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, pfreceivestreamsamples: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderAdvanced_Impl::GetReceiveStreamSamples(this, core::mem::transmute_copy(&wstreamnum)) {
+                    Ok(ok__) => {
+                        pfreceivestreamsamples.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAllocateForOutput<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, fallocate: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced_Impl::SetAllocateForOutput(this, core::mem::transmute_copy(&dwoutputnum), core::mem::transmute_copy(&fallocate)).into()
+            }
+        }
+        unsafe extern "system" fn GetAllocateForOutput<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, pfallocate: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderAdvanced_Impl::GetAllocateForOutput(this, core::mem::transmute_copy(&dwoutputnum)) {
+                    Ok(ok__) => {
+                        pfallocate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAllocateForStream<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, fallocate: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced_Impl::SetAllocateForStream(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&fallocate)).into()
+            }
+        }
+        unsafe extern "system" fn GetAllocateForStream<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced_Impl>(this: *mut core::ffi::c_void, dwsreamnum: u16, pfallocate: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderAdvanced_Impl::GetAllocateForStream(this, core::mem::transmute_copy(&dwsreamnum)) {
+                    Ok(ok__) => {
+                        pfallocate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStatistics<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced_Impl>(this: *mut core::ffi::c_void, pstatistics: *mut WM_READER_STATISTICS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced_Impl::GetStatistics(this, core::mem::transmute_copy(&pstatistics)).into()
+            }
+        }
+        unsafe extern "system" fn SetClientInfo<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced_Impl>(this: *mut core::ffi::c_void, pclientinfo: *const WM_READER_CLIENTINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced_Impl::SetClientInfo(this, core::mem::transmute_copy(&pclientinfo)).into()
+            }
+        }
+        unsafe extern "system" fn GetMaxOutputSampleSize<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced_Impl>(this: *mut core::ffi::c_void, dwoutput: u32, pcbmax: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderAdvanced_Impl::GetMaxOutputSampleSize(this, core::mem::transmute_copy(&dwoutput)) {
+                    Ok(ok__) => {
+                        pcbmax.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMaxStreamSampleSize<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced_Impl>(this: *mut core::ffi::c_void, wstream: u16, pcbmax: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderAdvanced_Impl::GetMaxStreamSampleSize(this, core::mem::transmute_copy(&wstream)) {
+                    Ok(ok__) => {
+                        pcbmax.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NotifyLateDelivery<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced_Impl>(this: *mut core::ffi::c_void, cnslateness: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced_Impl::NotifyLateDelivery(this, core::mem::transmute_copy(&cnslateness)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetUserProvidedClock: SetUserProvidedClock::<Identity>,
+            GetUserProvidedClock: GetUserProvidedClock::<Identity>,
+            DeliverTime: DeliverTime::<Identity>,
+            SetManualStreamSelection: SetManualStreamSelection::<Identity>,
+            GetManualStreamSelection: GetManualStreamSelection::<Identity>,
+            SetStreamsSelected: SetStreamsSelected::<Identity>,
+            GetStreamSelected: GetStreamSelected::<Identity>,
+            SetReceiveSelectionCallbacks: SetReceiveSelectionCallbacks::<Identity>,
+            GetReceiveSelectionCallbacks: GetReceiveSelectionCallbacks::<Identity>,
+            SetReceiveStreamSamples: SetReceiveStreamSamples::<Identity>,
+            GetReceiveStreamSamples: GetReceiveStreamSamples::<Identity>,
+            SetAllocateForOutput: SetAllocateForOutput::<Identity>,
+            GetAllocateForOutput: GetAllocateForOutput::<Identity>,
+            SetAllocateForStream: SetAllocateForStream::<Identity>,
+            GetAllocateForStream: GetAllocateForStream::<Identity>,
+            GetStatistics: GetStatistics::<Identity>,
+            SetClientInfo: SetClientInfo::<Identity>,
+            GetMaxOutputSampleSize: GetMaxOutputSampleSize::<Identity>,
+            GetMaxStreamSampleSize: GetMaxStreamSampleSize::<Identity>,
+            NotifyLateDelivery: NotifyLateDelivery::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IWMReaderAdvanced {}
+windows_core::imp::define_interface!(IWMReaderAdvanced2, IWMReaderAdvanced2_Vtbl, 0xae14a945_b90c_4d0d_9127_80d665f7d73e);
+impl core::ops::Deref for IWMReaderAdvanced2 {
+    type Target = IWMReaderAdvanced;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(IWMReaderAdvanced2, windows_core::IUnknown, IWMReaderAdvanced);
+impl IWMReaderAdvanced2 {
+    pub unsafe fn SetPlayMode(&self, mode: WMT_PLAY_MODE) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetPlayMode)(windows_core::Interface::as_raw(self), mode).ok() }
+    }
+    pub unsafe fn GetPlayMode(&self) -> windows_core::Result<WMT_PLAY_MODE> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetPlayMode)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn GetBufferProgress(&self, pdwpercent: *mut u32, pcnsbuffering: *mut u64) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetBufferProgress)(windows_core::Interface::as_raw(self), pdwpercent as _, pcnsbuffering as _).ok() }
+    }
+    pub unsafe fn GetDownloadProgress(&self, pdwpercent: *mut u32, pqwbytesdownloaded: *mut u64, pcnsdownload: *mut u64) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetDownloadProgress)(windows_core::Interface::as_raw(self), pdwpercent as _, pqwbytesdownloaded as _, pcnsdownload as _).ok() }
+    }
     pub unsafe fn GetSaveAsProgress(&self) -> windows_core::Result<u32> {
         unsafe {
             let mut result__ = core::mem::zeroed();
@@ -5584,6 +8272,140 @@ impl IWMReaderAdvanced2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMReaderAdvanced2_Vtbl
+where
+    IWMReaderAdvanced_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetPlayMode<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced2_Impl>(this: *mut core::ffi::c_void, mode: WMT_PLAY_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced2_Impl::SetPlayMode(this, core::mem::transmute_copy(&mode)).into()
+            }
+        }
+        unsafe extern "system" fn GetPlayMode<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced2_Impl>(this: *mut core::ffi::c_void, pmode: *mut WMT_PLAY_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderAdvanced2_Impl::GetPlayMode(this) {
+                    Ok(ok__) => {
+                        pmode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetBufferProgress<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced2_Impl>(this: *mut core::ffi::c_void, pdwpercent: *mut u32, pcnsbuffering: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced2_Impl::GetBufferProgress(this, core::mem::transmute_copy(&pdwpercent), core::mem::transmute_copy(&pcnsbuffering)).into()
+            }
+        }
+        unsafe extern "system" fn GetDownloadProgress<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced2_Impl>(this: *mut core::ffi::c_void, pdwpercent: *mut u32, pqwbytesdownloaded: *mut u64, pcnsdownload: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced2_Impl::GetDownloadProgress(this, core::mem::transmute_copy(&pdwpercent), core::mem::transmute_copy(&pqwbytesdownloaded), core::mem::transmute_copy(&pcnsdownload)).into()
+            }
+        }
+        unsafe extern "system" fn GetSaveAsProgress<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced2_Impl>(this: *mut core::ffi::c_void, pdwpercent: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderAdvanced2_Impl::GetSaveAsProgress(this) {
+                    Ok(ok__) => {
+                        pdwpercent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SaveFileAs<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced2_Impl>(this: *mut core::ffi::c_void, pwszfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced2_Impl::SaveFileAs(this, core::mem::transmute(&pwszfilename)).into()
+            }
+        }
+        unsafe extern "system" fn GetProtocolName<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced2_Impl>(this: *mut core::ffi::c_void, pwszprotocol: windows_core::PWSTR, pcchprotocol: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced2_Impl::GetProtocolName(this, core::mem::transmute_copy(&pwszprotocol), core::mem::transmute_copy(&pcchprotocol)).into()
+            }
+        }
+        unsafe extern "system" fn StartAtMarker<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced2_Impl>(this: *mut core::ffi::c_void, wmarkerindex: u16, cnsduration: u64, frate: f32, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced2_Impl::StartAtMarker(this, core::mem::transmute_copy(&wmarkerindex), core::mem::transmute_copy(&cnsduration), core::mem::transmute_copy(&frate), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        unsafe extern "system" fn GetOutputSetting<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced2_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, pszname: windows_core::PCWSTR, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pcblength: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced2_Impl::GetOutputSetting(this, core::mem::transmute_copy(&dwoutputnum), core::mem::transmute(&pszname), core::mem::transmute_copy(&ptype), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&pcblength)).into()
+            }
+        }
+        unsafe extern "system" fn SetOutputSetting<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced2_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, pszname: windows_core::PCWSTR, r#type: WMT_ATTR_DATATYPE, pvalue: *const u8, cblength: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced2_Impl::SetOutputSetting(this, core::mem::transmute_copy(&dwoutputnum), core::mem::transmute(&pszname), core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&cblength)).into()
+            }
+        }
+        unsafe extern "system" fn Preroll<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced2_Impl>(this: *mut core::ffi::c_void, cnsstart: u64, cnsduration: u64, frate: f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced2_Impl::Preroll(this, core::mem::transmute_copy(&cnsstart), core::mem::transmute_copy(&cnsduration), core::mem::transmute_copy(&frate)).into()
+            }
+        }
+        unsafe extern "system" fn SetLogClientID<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced2_Impl>(this: *mut core::ffi::c_void, flogclientid: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced2_Impl::SetLogClientID(this, core::mem::transmute_copy(&flogclientid)).into()
+            }
+        }
+        unsafe extern "system" fn GetLogClientID<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced2_Impl>(this: *mut core::ffi::c_void, pflogclientid: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderAdvanced2_Impl::GetLogClientID(this) {
+                    Ok(ok__) => {
+                        pflogclientid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StopBuffering<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced2_Impl::StopBuffering(this).into()
+            }
+        }
+        unsafe extern "system" fn OpenStream<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced2_Impl>(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced2_Impl::OpenStream(this, core::mem::transmute_copy(&pstream), core::mem::transmute_copy(&pcallback), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        Self {
+            base__: <IWMReaderAdvanced_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetPlayMode: SetPlayMode::<Identity>,
+            GetPlayMode: GetPlayMode::<Identity>,
+            GetBufferProgress: GetBufferProgress::<Identity>,
+            GetDownloadProgress: GetDownloadProgress::<Identity>,
+            GetSaveAsProgress: GetSaveAsProgress::<Identity>,
+            SaveFileAs: SaveFileAs::<Identity>,
+            GetProtocolName: GetProtocolName::<Identity>,
+            StartAtMarker: StartAtMarker::<Identity>,
+            GetOutputSetting: GetOutputSetting::<Identity>,
+            SetOutputSetting: SetOutputSetting::<Identity>,
+            Preroll: Preroll::<Identity>,
+            SetLogClientID: SetLogClientID::<Identity>,
+            GetLogClientID: GetLogClientID::<Identity>,
+            StopBuffering: StopBuffering::<Identity>,
+            OpenStream: OpenStream::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IWMReaderAdvanced2 {}
 windows_core::imp::define_interface!(IWMReaderAdvanced3, IWMReaderAdvanced3_Vtbl, 0x5dc0674b_f04b_4a4e_9f2a_b1afde2c8100);
 impl core::ops::Deref for IWMReaderAdvanced3 {
@@ -5639,6 +8461,31 @@ impl IWMReaderAdvanced3_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced3_Impl> windows_core::imp::TearOffVtable<Identity> for IWMReaderAdvanced3_Vtbl
+where
+    IWMReaderAdvanced2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StopNetStreaming<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced3_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced3_Impl::StopNetStreaming(this).into()
+            }
+        }
+        unsafe extern "system" fn StartAtPosition<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced3_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, pvoffsetstart: *const core::ffi::c_void, pvduration: *const core::ffi::c_void, dwoffsetformat: WMT_OFFSET_FORMAT, frate: f32, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced3_Impl::StartAtPosition(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&pvoffsetstart), core::mem::transmute_copy(&pvduration), core::mem::transmute_copy(&dwoffsetformat), core::mem::transmute_copy(&frate), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        Self {
+            base__: <IWMReaderAdvanced2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            StopNetStreaming: StopNetStreaming::<Identity>,
+            StartAtPosition: StartAtPosition::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IWMReaderAdvanced3 {}
 windows_core::imp::define_interface!(IWMReaderAdvanced4, IWMReaderAdvanced4_Vtbl, 0x945a76a2_12ae_4d48_bd3c_cd1d90399b85);
 impl core::ops::Deref for IWMReaderAdvanced4 {
@@ -5819,8 +8666,106 @@ impl IWMReaderAdvanced4_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
-impl windows_core::RuntimeName for IWMReaderAdvanced4 {}
-windows_core::imp::define_interface!(IWMReaderAdvanced5, IWMReaderAdvanced5_Vtbl, 0x24c44db0_55d1_49ae_a5cc_f13815e36363);
+impl<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced4_Impl> windows_core::imp::TearOffVtable<Identity> for IWMReaderAdvanced4_Vtbl
+where
+    IWMReaderAdvanced3_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetLanguageCount<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced4_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, pwlanguagecount: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderAdvanced4_Impl::GetLanguageCount(this, core::mem::transmute_copy(&dwoutputnum)) {
+                    Ok(ok__) => {
+                        pwlanguagecount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLanguage<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced4_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, wlanguage: u16, pwszlanguagestring: windows_core::PWSTR, pcchlanguagestringlength: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced4_Impl::GetLanguage(this, core::mem::transmute_copy(&dwoutputnum), core::mem::transmute_copy(&wlanguage), core::mem::transmute_copy(&pwszlanguagestring), core::mem::transmute_copy(&pcchlanguagestringlength)).into()
+            }
+        }
+        unsafe extern "system" fn GetMaxSpeedFactor<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced4_Impl>(this: *mut core::ffi::c_void, pdblfactor: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderAdvanced4_Impl::GetMaxSpeedFactor(this) {
+                    Ok(ok__) => {
+                        pdblfactor.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsUsingFastCache<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced4_Impl>(this: *mut core::ffi::c_void, pfusingfastcache: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderAdvanced4_Impl::IsUsingFastCache(this) {
+                    Ok(ok__) => {
+                        pfusingfastcache.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddLogParam<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced4_Impl>(this: *mut core::ffi::c_void, wsznamespace: windows_core::PCWSTR, wszname: windows_core::PCWSTR, wszvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced4_Impl::AddLogParam(this, core::mem::transmute(&wsznamespace), core::mem::transmute(&wszname), core::mem::transmute(&wszvalue)).into()
+            }
+        }
+        unsafe extern "system" fn SendLogParams<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced4_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced4_Impl::SendLogParams(this).into()
+            }
+        }
+        unsafe extern "system" fn CanSaveFileAs<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced4_Impl>(this: *mut core::ffi::c_void, pfcansave: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderAdvanced4_Impl::CanSaveFileAs(this) {
+                    Ok(ok__) => {
+                        pfcansave.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CancelSaveFileAs<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced4_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced4_Impl::CancelSaveFileAs(this).into()
+            }
+        }
+        unsafe extern "system" fn GetURL<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced4_Impl>(this: *mut core::ffi::c_void, pwszurl: windows_core::PWSTR, pcchurl: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced4_Impl::GetURL(this, core::mem::transmute_copy(&pwszurl), core::mem::transmute_copy(&pcchurl)).into()
+            }
+        }
+        Self {
+            base__: <IWMReaderAdvanced3_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetLanguageCount: GetLanguageCount::<Identity>,
+            GetLanguage: GetLanguage::<Identity>,
+            GetMaxSpeedFactor: GetMaxSpeedFactor::<Identity>,
+            IsUsingFastCache: IsUsingFastCache::<Identity>,
+            AddLogParam: AddLogParam::<Identity>,
+            SendLogParams: SendLogParams::<Identity>,
+            CanSaveFileAs: CanSaveFileAs::<Identity>,
+            CancelSaveFileAs: CancelSaveFileAs::<Identity>,
+            GetURL: GetURL::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::RuntimeName for IWMReaderAdvanced4 {}
+windows_core::imp::define_interface!(IWMReaderAdvanced5, IWMReaderAdvanced5_Vtbl, 0x24c44db0_55d1_49ae_a5cc_f13815e36363);
 impl core::ops::Deref for IWMReaderAdvanced5 {
     type Target = IWMReaderAdvanced4;
     fn deref(&self) -> &Self::Target {
@@ -5862,6 +8807,21 @@ impl IWMReaderAdvanced5_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced5_Impl> windows_core::imp::TearOffVtable<Identity> for IWMReaderAdvanced5_Vtbl
+where
+    IWMReaderAdvanced4_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetPlayerHook<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced5_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, phook: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced5_Impl::SetPlayerHook(this, core::mem::transmute_copy(&dwoutputnum), core::mem::transmute_copy(&phook)).into()
+            }
+        }
+        Self { base__: <IWMReaderAdvanced4_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetPlayerHook: SetPlayerHook::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IWMReaderAdvanced5 {}
 windows_core::imp::define_interface!(IWMReaderAdvanced6, IWMReaderAdvanced6_Vtbl, 0x18a2e7f8_428f_4acd_8a00_e64639bc93de);
 impl core::ops::Deref for IWMReaderAdvanced6 {
@@ -5902,6 +8862,24 @@ impl IWMReaderAdvanced6_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced6_Impl> windows_core::imp::TearOffVtable<Identity> for IWMReaderAdvanced6_Vtbl
+where
+    IWMReaderAdvanced5_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetProtectStreamSamples<Identity: windows_core::IUnknownImpl + IWMReaderAdvanced6_Impl>(this: *mut core::ffi::c_void, pbcertificate: *const u8, cbcertificate: u32, dwcertificatetype: u32, dwflags: u32, pbinitializationvector: *mut u8, pcbinitializationvector: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAdvanced6_Impl::SetProtectStreamSamples(this, core::mem::transmute_copy(&pbcertificate), core::mem::transmute_copy(&cbcertificate), core::mem::transmute_copy(&dwcertificatetype), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pbinitializationvector), core::mem::transmute_copy(&pcbinitializationvector)).into()
+            }
+        }
+        Self {
+            base__: <IWMReaderAdvanced5_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetProtectStreamSamples: SetProtectStreamSamples::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IWMReaderAdvanced6 {}
 windows_core::imp::define_interface!(IWMReaderAllocatorEx, IWMReaderAllocatorEx_Vtbl, 0x9f762fa7_a22e_428d_93c9_ac82f3aafe5a);
 windows_core::imp::interface_hierarchy!(IWMReaderAllocatorEx, windows_core::IUnknown);
@@ -5948,6 +8926,30 @@ impl IWMReaderAllocatorEx_Vtbl {
         iid == &<IWMReaderAllocatorEx as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMReaderAllocatorEx_Impl> windows_core::imp::TearOffVtable<Identity> for IWMReaderAllocatorEx_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AllocateForStreamEx<Identity: windows_core::IUnknownImpl + IWMReaderAllocatorEx_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, cbbuffer: u32, ppbuffer: *mut *mut core::ffi::c_void, dwflags: u32, cnssampletime: u64, cnssampleduration: u64, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAllocatorEx_Impl::AllocateForStreamEx(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&cbbuffer), core::mem::transmute_copy(&ppbuffer), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&cnssampletime), core::mem::transmute_copy(&cnssampleduration), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        unsafe extern "system" fn AllocateForOutputEx<Identity: windows_core::IUnknownImpl + IWMReaderAllocatorEx_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, cbbuffer: u32, ppbuffer: *mut *mut core::ffi::c_void, dwflags: u32, cnssampletime: u64, cnssampleduration: u64, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderAllocatorEx_Impl::AllocateForOutputEx(this, core::mem::transmute_copy(&dwoutputnum), core::mem::transmute_copy(&cbbuffer), core::mem::transmute_copy(&ppbuffer), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&cnssampletime), core::mem::transmute_copy(&cnssampleduration), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AllocateForStreamEx: AllocateForStreamEx::<Identity>,
+            AllocateForOutputEx: AllocateForOutputEx::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMReaderAllocatorEx {}
 windows_core::imp::define_interface!(IWMReaderCallback, IWMReaderCallback_Vtbl, 0x96406bd8_2b2b_11d3_b36b_00c04f6108ff);
 impl core::ops::Deref for IWMReaderCallback {
@@ -5988,6 +8990,20 @@ impl IWMReaderCallback_Vtbl {
         iid == &<IWMReaderCallback as windows_core::Interface>::IID || iid == &<IWMStatusCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMReaderCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IWMReaderCallback_Vtbl
+where
+    IWMStatusCallback_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnSample<Identity: windows_core::IUnknownImpl + IWMReaderCallback_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, cnssampletime: u64, cnssampleduration: u64, dwflags: u32, psample: *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderCallback_Impl::OnSample(this, core::mem::transmute_copy(&dwoutputnum), core::mem::transmute_copy(&cnssampletime), core::mem::transmute_copy(&cnssampleduration), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&psample), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        Self { base__: <IWMStatusCallback_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnSample: OnSample::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMReaderCallback {}
 windows_core::imp::define_interface!(IWMReaderCallbackAdvanced, IWMReaderCallbackAdvanced_Vtbl, 0x96406beb_2b2b_11d3_b36b_00c04f6108ff);
 windows_core::imp::interface_hierarchy!(IWMReaderCallbackAdvanced, windows_core::IUnknown);
@@ -6085,6 +9101,58 @@ impl IWMReaderCallbackAdvanced_Vtbl {
         iid == &<IWMReaderCallbackAdvanced as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMReaderCallbackAdvanced_Impl> windows_core::imp::TearOffVtable<Identity> for IWMReaderCallbackAdvanced_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnStreamSample<Identity: windows_core::IUnknownImpl + IWMReaderCallbackAdvanced_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, cnssampletime: u64, cnssampleduration: u64, dwflags: u32, psample: *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderCallbackAdvanced_Impl::OnStreamSample(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&cnssampletime), core::mem::transmute_copy(&cnssampleduration), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&psample), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        unsafe extern "system" fn OnTime<Identity: windows_core::IUnknownImpl + IWMReaderCallbackAdvanced_Impl>(this: *mut core::ffi::c_void, cnscurrenttime: u64, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderCallbackAdvanced_Impl::OnTime(this, core::mem::transmute_copy(&cnscurrenttime), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        unsafe extern "system" fn OnStreamSelection<Identity: windows_core::IUnknownImpl + IWMReaderCallbackAdvanced_Impl>(this: *mut core::ffi::c_void, wstreamcount: u16, pstreamnumbers: *const u16, pselections: *const WMT_STREAM_SELECTION, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderCallbackAdvanced_Impl::OnStreamSelection(this, core::mem::transmute_copy(&wstreamcount), core::mem::transmute_copy(&pstreamnumbers), core::mem::transmute_copy(&pselections), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        unsafe extern "system" fn OnOutputPropsChanged<Identity: windows_core::IUnknownImpl + IWMReaderCallbackAdvanced_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, pmediatype: *const WM_MEDIA_TYPE, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderCallbackAdvanced_Impl::OnOutputPropsChanged(this, core::mem::transmute_copy(&dwoutputnum), core::mem::transmute_copy(&pmediatype), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        unsafe extern "system" fn AllocateForStream<Identity: windows_core::IUnknownImpl + IWMReaderCallbackAdvanced_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, cbbuffer: u32, ppbuffer: *mut *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderCallbackAdvanced_Impl::AllocateForStream(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&cbbuffer), core::mem::transmute_copy(&ppbuffer), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        unsafe extern "system" fn AllocateForOutput<Identity: windows_core::IUnknownImpl + IWMReaderCallbackAdvanced_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, cbbuffer: u32, ppbuffer: *mut *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderCallbackAdvanced_Impl::AllocateForOutput(this, core::mem::transmute_copy(&dwoutputnum), core::mem::transmute_copy(&cbbuffer), core::mem::transmute_copy(&ppbuffer), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnStreamSample: OnStreamSample::<Identity>,
+            OnTime: OnTime::<Identity>,
+            OnStreamSelection: OnStreamSelection::<Identity>,
+            OnOutputPropsChanged: OnOutputPropsChanged::<Identity>,
+            AllocateForStream: AllocateForStream::<Identity>,
+            AllocateForOutput: AllocateForOutput::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMReaderCallbackAdvanced {}
 windows_core::imp::define_interface!(IWMReaderNetworkConfig, IWMReaderNetworkConfig_Vtbl, 0x96406bec_2b2b_11d3_b36b_00c04f6108ff);
 windows_core::imp::interface_hierarchy!(IWMReaderNetworkConfig, windows_core::IUnknown);
@@ -6646,135 +9714,448 @@ impl IWMReaderNetworkConfig_Vtbl {
         iid == &<IWMReaderNetworkConfig as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IWMReaderNetworkConfig {}
-windows_core::imp::define_interface!(IWMReaderNetworkConfig2, IWMReaderNetworkConfig2_Vtbl, 0xd979a853_042b_4050_8387_c939db22013f);
-impl core::ops::Deref for IWMReaderNetworkConfig2 {
-    type Target = IWMReaderNetworkConfig;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(IWMReaderNetworkConfig2, windows_core::IUnknown, IWMReaderNetworkConfig);
-impl IWMReaderNetworkConfig2 {
-    pub unsafe fn GetEnableContentCaching(&self) -> windows_core::Result<windows_core::BOOL> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetEnableContentCaching)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
-        }
-    }
-    pub unsafe fn SetEnableContentCaching(&self, fenablecontentcaching: bool) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetEnableContentCaching)(windows_core::Interface::as_raw(self), fenablecontentcaching.into()).ok() }
-    }
-    pub unsafe fn GetEnableFastCache(&self) -> windows_core::Result<windows_core::BOOL> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetEnableFastCache)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
-        }
-    }
-    pub unsafe fn SetEnableFastCache(&self, fenablefastcache: bool) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetEnableFastCache)(windows_core::Interface::as_raw(self), fenablefastcache.into()).ok() }
-    }
-    pub unsafe fn GetAcceleratedStreamingDuration(&self) -> windows_core::Result<u64> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetAcceleratedStreamingDuration)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
-        }
-    }
-    pub unsafe fn SetAcceleratedStreamingDuration(&self, cnsaccelduration: u64) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetAcceleratedStreamingDuration)(windows_core::Interface::as_raw(self), cnsaccelduration).ok() }
-    }
-    pub unsafe fn GetAutoReconnectLimit(&self) -> windows_core::Result<u32> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetAutoReconnectLimit)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
-        }
-    }
-    pub unsafe fn SetAutoReconnectLimit(&self, dwautoreconnectlimit: u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetAutoReconnectLimit)(windows_core::Interface::as_raw(self), dwautoreconnectlimit).ok() }
-    }
-    pub unsafe fn GetEnableResends(&self) -> windows_core::Result<windows_core::BOOL> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetEnableResends)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
-        }
-    }
-    pub unsafe fn SetEnableResends(&self, fenableresends: bool) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetEnableResends)(windows_core::Interface::as_raw(self), fenableresends.into()).ok() }
-    }
-    pub unsafe fn GetEnableThinning(&self) -> windows_core::Result<windows_core::BOOL> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetEnableThinning)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
-        }
-    }
-    pub unsafe fn SetEnableThinning(&self, fenablethinning: bool) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetEnableThinning)(windows_core::Interface::as_raw(self), fenablethinning.into()).ok() }
-    }
-    pub unsafe fn GetMaxNetPacketSize(&self) -> windows_core::Result<u32> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetMaxNetPacketSize)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
-        }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IWMReaderNetworkConfig2_Vtbl {
-    pub base__: IWMReaderNetworkConfig_Vtbl,
-    pub GetEnableContentCaching: unsafe extern "system" fn(*mut core::ffi::c_void, *mut windows_core::BOOL) -> windows_core::HRESULT,
-    pub SetEnableContentCaching: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::BOOL) -> windows_core::HRESULT,
-    pub GetEnableFastCache: unsafe extern "system" fn(*mut core::ffi::c_void, *mut windows_core::BOOL) -> windows_core::HRESULT,
-    pub SetEnableFastCache: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::BOOL) -> windows_core::HRESULT,
-    pub GetAcceleratedStreamingDuration: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u64) -> windows_core::HRESULT,
-    pub SetAcceleratedStreamingDuration: unsafe extern "system" fn(*mut core::ffi::c_void, u64) -> windows_core::HRESULT,
-    pub GetAutoReconnectLimit: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
-    pub SetAutoReconnectLimit: unsafe extern "system" fn(*mut core::ffi::c_void, u32) -> windows_core::HRESULT,
-    pub GetEnableResends: unsafe extern "system" fn(*mut core::ffi::c_void, *mut windows_core::BOOL) -> windows_core::HRESULT,
-    pub SetEnableResends: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::BOOL) -> windows_core::HRESULT,
-    pub GetEnableThinning: unsafe extern "system" fn(*mut core::ffi::c_void, *mut windows_core::BOOL) -> windows_core::HRESULT,
-    pub SetEnableThinning: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::BOOL) -> windows_core::HRESULT,
-    pub GetMaxNetPacketSize: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
-}
-pub trait IWMReaderNetworkConfig2_Impl: IWMReaderNetworkConfig_Impl {
-    fn GetEnableContentCaching(&self) -> windows_core::Result<windows_core::BOOL>;
-    fn SetEnableContentCaching(&self, fenablecontentcaching: windows_core::BOOL) -> windows_core::Result<()>;
-    fn GetEnableFastCache(&self) -> windows_core::Result<windows_core::BOOL>;
-    fn SetEnableFastCache(&self, fenablefastcache: windows_core::BOOL) -> windows_core::Result<()>;
-    fn GetAcceleratedStreamingDuration(&self) -> windows_core::Result<u64>;
-    fn SetAcceleratedStreamingDuration(&self, cnsaccelduration: u64) -> windows_core::Result<()>;
-    fn GetAutoReconnectLimit(&self) -> windows_core::Result<u32>;
-    fn SetAutoReconnectLimit(&self, dwautoreconnectlimit: u32) -> windows_core::Result<()>;
-    fn GetEnableResends(&self) -> windows_core::Result<windows_core::BOOL>;
-    fn SetEnableResends(&self, fenableresends: windows_core::BOOL) -> windows_core::Result<()>;
-    fn GetEnableThinning(&self) -> windows_core::Result<windows_core::BOOL>;
-    fn SetEnableThinning(&self, fenablethinning: windows_core::BOOL) -> windows_core::Result<()>;
-    fn GetMaxNetPacketSize(&self) -> windows_core::Result<u32>;
-}
-impl IWMReaderNetworkConfig2_Vtbl {
-    pub const fn new<Identity: IWMReaderNetworkConfig2_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn GetEnableContentCaching<Identity: IWMReaderNetworkConfig2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfenablecontentcaching: *mut windows_core::BOOL) -> windows_core::HRESULT {
+impl<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl> windows_core::imp::TearOffVtable<Identity> for IWMReaderNetworkConfig_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetBufferingTime<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, pcnsbufferingtime: *mut u64) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IWMReaderNetworkConfig2_Impl::GetEnableContentCaching(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderNetworkConfig_Impl::GetBufferingTime(this) {
                     Ok(ok__) => {
-                        pfenablecontentcaching.write(core::mem::transmute(ok__));
+                        pcnsbufferingtime.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn SetEnableContentCaching<Identity: IWMReaderNetworkConfig2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fenablecontentcaching: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetBufferingTime<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, cnsbufferingtime: u64) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IWMReaderNetworkConfig2_Impl::SetEnableContentCaching(this, core::mem::transmute_copy(&fenablecontentcaching)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig_Impl::SetBufferingTime(this, core::mem::transmute_copy(&cnsbufferingtime)).into()
             }
         }
-        unsafe extern "system" fn GetEnableFastCache<Identity: IWMReaderNetworkConfig2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfenablefastcache: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetUDPPortRanges<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, prangearray: *mut WM_PORT_NUMBER_RANGE, pcranges: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IWMReaderNetworkConfig2_Impl::GetEnableFastCache(this) {
-                    Ok(ok__) => {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig_Impl::GetUDPPortRanges(this, core::mem::transmute_copy(&prangearray), core::mem::transmute_copy(&pcranges)).into()
+            }
+        }
+        unsafe extern "system" fn SetUDPPortRanges<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, prangearray: *const WM_PORT_NUMBER_RANGE, cranges: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig_Impl::SetUDPPortRanges(this, core::mem::transmute_copy(&prangearray), core::mem::transmute_copy(&cranges)).into()
+            }
+        }
+        unsafe extern "system" fn GetProxySettings<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, pwszprotocol: windows_core::PCWSTR, pproxysetting: *mut WMT_PROXY_SETTINGS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderNetworkConfig_Impl::GetProxySettings(this, core::mem::transmute(&pwszprotocol)) {
+                    Ok(ok__) => {
+                        pproxysetting.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProxySettings<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, pwszprotocol: windows_core::PCWSTR, proxysetting: WMT_PROXY_SETTINGS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig_Impl::SetProxySettings(this, core::mem::transmute(&pwszprotocol), core::mem::transmute_copy(&proxysetting)).into()
+            }
+        }
+        unsafe extern "system" fn GetProxyHostName<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, pwszprotocol: windows_core::PCWSTR, pwszhostname: windows_core::PWSTR, pcchhostname: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig_Impl::GetProxyHostName(this, core::mem::transmute(&pwszprotocol), core::mem::transmute_copy(&pwszhostname), core::mem::transmute_copy(&pcchhostname)).into()
+            }
+        }
+        unsafe extern "system" fn SetProxyHostName<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, pwszprotocol: windows_core::PCWSTR, pwszhostname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig_Impl::SetProxyHostName(this, core::mem::transmute(&pwszprotocol), core::mem::transmute(&pwszhostname)).into()
+            }
+        }
+        unsafe extern "system" fn GetProxyPort<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, pwszprotocol: windows_core::PCWSTR, pdwport: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderNetworkConfig_Impl::GetProxyPort(this, core::mem::transmute(&pwszprotocol)) {
+                    Ok(ok__) => {
+                        pdwport.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProxyPort<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, pwszprotocol: windows_core::PCWSTR, dwport: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig_Impl::SetProxyPort(this, core::mem::transmute(&pwszprotocol), core::mem::transmute_copy(&dwport)).into()
+            }
+        }
+        unsafe extern "system" fn GetProxyExceptionList<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, pwszprotocol: windows_core::PCWSTR, pwszexceptionlist: windows_core::PWSTR, pcchexceptionlist: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig_Impl::GetProxyExceptionList(this, core::mem::transmute(&pwszprotocol), core::mem::transmute_copy(&pwszexceptionlist), core::mem::transmute_copy(&pcchexceptionlist)).into()
+            }
+        }
+        unsafe extern "system" fn SetProxyExceptionList<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, pwszprotocol: windows_core::PCWSTR, pwszexceptionlist: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig_Impl::SetProxyExceptionList(this, core::mem::transmute(&pwszprotocol), core::mem::transmute(&pwszexceptionlist)).into()
+            }
+        }
+        unsafe extern "system" fn GetProxyBypassForLocal<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, pwszprotocol: windows_core::PCWSTR, pfbypassforlocal: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderNetworkConfig_Impl::GetProxyBypassForLocal(this, core::mem::transmute(&pwszprotocol)) {
+                    Ok(ok__) => {
+                        pfbypassforlocal.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProxyBypassForLocal<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, pwszprotocol: windows_core::PCWSTR, fbypassforlocal: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig_Impl::SetProxyBypassForLocal(this, core::mem::transmute(&pwszprotocol), core::mem::transmute_copy(&fbypassforlocal)).into()
+            }
+        }
+        unsafe extern "system" fn GetForceRerunAutoProxyDetection<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, pfforcererundetection: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderNetworkConfig_Impl::GetForceRerunAutoProxyDetection(this) {
+                    Ok(ok__) => {
+                        pfforcererundetection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetForceRerunAutoProxyDetection<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, fforcererundetection: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig_Impl::SetForceRerunAutoProxyDetection(this, core::mem::transmute_copy(&fforcererundetection)).into()
+            }
+        }
+        unsafe extern "system" fn GetEnableMulticast<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, pfenablemulticast: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderNetworkConfig_Impl::GetEnableMulticast(this) {
+                    Ok(ok__) => {
+                        pfenablemulticast.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEnableMulticast<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, fenablemulticast: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig_Impl::SetEnableMulticast(this, core::mem::transmute_copy(&fenablemulticast)).into()
+            }
+        }
+        unsafe extern "system" fn GetEnableHTTP<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, pfenablehttp: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderNetworkConfig_Impl::GetEnableHTTP(this) {
+                    Ok(ok__) => {
+                        pfenablehttp.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEnableHTTP<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, fenablehttp: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig_Impl::SetEnableHTTP(this, core::mem::transmute_copy(&fenablehttp)).into()
+            }
+        }
+        unsafe extern "system" fn GetEnableUDP<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, pfenableudp: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderNetworkConfig_Impl::GetEnableUDP(this) {
+                    Ok(ok__) => {
+                        pfenableudp.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEnableUDP<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, fenableudp: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig_Impl::SetEnableUDP(this, core::mem::transmute_copy(&fenableudp)).into()
+            }
+        }
+        unsafe extern "system" fn GetEnableTCP<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, pfenabletcp: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderNetworkConfig_Impl::GetEnableTCP(this) {
+                    Ok(ok__) => {
+                        pfenabletcp.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEnableTCP<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, fenabletcp: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig_Impl::SetEnableTCP(this, core::mem::transmute_copy(&fenabletcp)).into()
+            }
+        }
+        unsafe extern "system" fn ResetProtocolRollover<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig_Impl::ResetProtocolRollover(this).into()
+            }
+        }
+        unsafe extern "system" fn GetConnectionBandwidth<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, pdwconnectionbandwidth: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderNetworkConfig_Impl::GetConnectionBandwidth(this) {
+                    Ok(ok__) => {
+                        pdwconnectionbandwidth.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetConnectionBandwidth<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, dwconnectionbandwidth: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig_Impl::SetConnectionBandwidth(this, core::mem::transmute_copy(&dwconnectionbandwidth)).into()
+            }
+        }
+        unsafe extern "system" fn GetNumProtocolsSupported<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, pcprotocols: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderNetworkConfig_Impl::GetNumProtocolsSupported(this) {
+                    Ok(ok__) => {
+                        pcprotocols.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSupportedProtocolName<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, dwprotocolnum: u32, pwszprotocolname: windows_core::PWSTR, pcchprotocolname: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig_Impl::GetSupportedProtocolName(this, core::mem::transmute_copy(&dwprotocolnum), core::mem::transmute_copy(&pwszprotocolname), core::mem::transmute_copy(&pcchprotocolname)).into()
+            }
+        }
+        unsafe extern "system" fn AddLoggingUrl<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig_Impl::AddLoggingUrl(this, core::mem::transmute(&pwszurl)).into()
+            }
+        }
+        unsafe extern "system" fn GetLoggingUrl<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, dwindex: u32, pwszurl: windows_core::PWSTR, pcchurl: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig_Impl::GetLoggingUrl(this, core::mem::transmute_copy(&dwindex), core::mem::transmute_copy(&pwszurl), core::mem::transmute_copy(&pcchurl)).into()
+            }
+        }
+        unsafe extern "system" fn GetLoggingUrlCount<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void, pdwurlcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderNetworkConfig_Impl::GetLoggingUrlCount(this) {
+                    Ok(ok__) => {
+                        pdwurlcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ResetLoggingUrlList<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig_Impl::ResetLoggingUrlList(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetBufferingTime: GetBufferingTime::<Identity>,
+            SetBufferingTime: SetBufferingTime::<Identity>,
+            GetUDPPortRanges: GetUDPPortRanges::<Identity>,
+            SetUDPPortRanges: SetUDPPortRanges::<Identity>,
+            GetProxySettings: GetProxySettings::<Identity>,
+            SetProxySettings: SetProxySettings::<Identity>,
+            GetProxyHostName: GetProxyHostName::<Identity>,
+            SetProxyHostName: SetProxyHostName::<Identity>,
+            GetProxyPort: GetProxyPort::<Identity>,
+            SetProxyPort: SetProxyPort::<Identity>,
+            GetProxyExceptionList: GetProxyExceptionList::<Identity>,
+            SetProxyExceptionList: SetProxyExceptionList::<Identity>,
+            GetProxyBypassForLocal: GetProxyBypassForLocal::<Identity>,
+            SetProxyBypassForLocal: SetProxyBypassForLocal::<Identity>,
+            GetForceRerunAutoProxyDetection: GetForceRerunAutoProxyDetection::<Identity>,
+            SetForceRerunAutoProxyDetection: SetForceRerunAutoProxyDetection::<Identity>,
+            GetEnableMulticast: GetEnableMulticast::<Identity>,
+            SetEnableMulticast: SetEnableMulticast::<Identity>,
+            GetEnableHTTP: GetEnableHTTP::<Identity>,
+            SetEnableHTTP: SetEnableHTTP::<Identity>,
+            GetEnableUDP: GetEnableUDP::<Identity>,
+            SetEnableUDP: SetEnableUDP::<Identity>,
+            GetEnableTCP: GetEnableTCP::<Identity>,
+            SetEnableTCP: SetEnableTCP::<Identity>,
+            ResetProtocolRollover: ResetProtocolRollover::<Identity>,
+            GetConnectionBandwidth: GetConnectionBandwidth::<Identity>,
+            SetConnectionBandwidth: SetConnectionBandwidth::<Identity>,
+            GetNumProtocolsSupported: GetNumProtocolsSupported::<Identity>,
+            GetSupportedProtocolName: GetSupportedProtocolName::<Identity>,
+            AddLoggingUrl: AddLoggingUrl::<Identity>,
+            GetLoggingUrl: GetLoggingUrl::<Identity>,
+            GetLoggingUrlCount: GetLoggingUrlCount::<Identity>,
+            ResetLoggingUrlList: ResetLoggingUrlList::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IWMReaderNetworkConfig {}
+windows_core::imp::define_interface!(IWMReaderNetworkConfig2, IWMReaderNetworkConfig2_Vtbl, 0xd979a853_042b_4050_8387_c939db22013f);
+impl core::ops::Deref for IWMReaderNetworkConfig2 {
+    type Target = IWMReaderNetworkConfig;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(IWMReaderNetworkConfig2, windows_core::IUnknown, IWMReaderNetworkConfig);
+impl IWMReaderNetworkConfig2 {
+    pub unsafe fn GetEnableContentCaching(&self) -> windows_core::Result<windows_core::BOOL> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetEnableContentCaching)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn SetEnableContentCaching(&self, fenablecontentcaching: bool) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetEnableContentCaching)(windows_core::Interface::as_raw(self), fenablecontentcaching.into()).ok() }
+    }
+    pub unsafe fn GetEnableFastCache(&self) -> windows_core::Result<windows_core::BOOL> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetEnableFastCache)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn SetEnableFastCache(&self, fenablefastcache: bool) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetEnableFastCache)(windows_core::Interface::as_raw(self), fenablefastcache.into()).ok() }
+    }
+    pub unsafe fn GetAcceleratedStreamingDuration(&self) -> windows_core::Result<u64> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetAcceleratedStreamingDuration)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn SetAcceleratedStreamingDuration(&self, cnsaccelduration: u64) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetAcceleratedStreamingDuration)(windows_core::Interface::as_raw(self), cnsaccelduration).ok() }
+    }
+    pub unsafe fn GetAutoReconnectLimit(&self) -> windows_core::Result<u32> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetAutoReconnectLimit)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn SetAutoReconnectLimit(&self, dwautoreconnectlimit: u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetAutoReconnectLimit)(windows_core::Interface::as_raw(self), dwautoreconnectlimit).ok() }
+    }
+    pub unsafe fn GetEnableResends(&self) -> windows_core::Result<windows_core::BOOL> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetEnableResends)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn SetEnableResends(&self, fenableresends: bool) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetEnableResends)(windows_core::Interface::as_raw(self), fenableresends.into()).ok() }
+    }
+    pub unsafe fn GetEnableThinning(&self) -> windows_core::Result<windows_core::BOOL> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetEnableThinning)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn SetEnableThinning(&self, fenablethinning: bool) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetEnableThinning)(windows_core::Interface::as_raw(self), fenablethinning.into()).ok() }
+    }
+    pub unsafe fn GetMaxNetPacketSize(&self) -> windows_core::Result<u32> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetMaxNetPacketSize)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IWMReaderNetworkConfig2_Vtbl {
+    pub base__: IWMReaderNetworkConfig_Vtbl,
+    pub GetEnableContentCaching: unsafe extern "system" fn(*mut core::ffi::c_void, *mut windows_core::BOOL) -> windows_core::HRESULT,
+    pub SetEnableContentCaching: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::BOOL) -> windows_core::HRESULT,
+    pub GetEnableFastCache: unsafe extern "system" fn(*mut core::ffi::c_void, *mut windows_core::BOOL) -> windows_core::HRESULT,
+    pub SetEnableFastCache: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::BOOL) -> windows_core::HRESULT,
+    pub GetAcceleratedStreamingDuration: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u64) -> windows_core::HRESULT,
+    pub SetAcceleratedStreamingDuration: unsafe extern "system" fn(*mut core::ffi::c_void, u64) -> windows_core::HRESULT,
+    pub GetAutoReconnectLimit: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
+    pub SetAutoReconnectLimit: unsafe extern "system" fn(*mut core::ffi::c_void, u32) -> windows_core::HRESULT,
+    pub GetEnableResends: unsafe extern "system" fn(*mut core::ffi::c_void, *mut windows_core::BOOL) -> windows_core::HRESULT,
+    pub SetEnableResends: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::BOOL) -> windows_core::HRESULT,
+    pub GetEnableThinning: unsafe extern "system" fn(*mut core::ffi::c_void, *mut windows_core::BOOL) -> windows_core::HRESULT,
+    pub SetEnableThinning: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::BOOL) -> windows_core::HRESULT,
+    pub GetMaxNetPacketSize: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
+}
+pub trait IWMReaderNetworkConfig2_Impl: IWMReaderNetworkConfig_Impl {
+    fn GetEnableContentCaching(&self) -> windows_core::Result<windows_core::BOOL>;
+    fn SetEnableContentCaching(&self, fenablecontentcaching: windows_core::BOOL) -> windows_core::Result<()>;
+    fn GetEnableFastCache(&self) -> windows_core::Result<windows_core::BOOL>;
+    fn SetEnableFastCache(&self, fenablefastcache: windows_core::BOOL) -> windows_core::Result<()>;
+    fn GetAcceleratedStreamingDuration(&self) -> windows_core::Result<u64>;
+    fn SetAcceleratedStreamingDuration(&self, cnsaccelduration: u64) -> windows_core::Result<()>;
+    fn GetAutoReconnectLimit(&self) -> windows_core::Result<u32>;
+    fn SetAutoReconnectLimit(&self, dwautoreconnectlimit: u32) -> windows_core::Result<()>;
+    fn GetEnableResends(&self) -> windows_core::Result<windows_core::BOOL>;
+    fn SetEnableResends(&self, fenableresends: windows_core::BOOL) -> windows_core::Result<()>;
+    fn GetEnableThinning(&self) -> windows_core::Result<windows_core::BOOL>;
+    fn SetEnableThinning(&self, fenablethinning: windows_core::BOOL) -> windows_core::Result<()>;
+    fn GetMaxNetPacketSize(&self) -> windows_core::Result<u32>;
+}
+impl IWMReaderNetworkConfig2_Vtbl {
+    pub const fn new<Identity: IWMReaderNetworkConfig2_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn GetEnableContentCaching<Identity: IWMReaderNetworkConfig2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfenablecontentcaching: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWMReaderNetworkConfig2_Impl::GetEnableContentCaching(this) {
+                    Ok(ok__) => {
+                        pfenablecontentcaching.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEnableContentCaching<Identity: IWMReaderNetworkConfig2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fenablecontentcaching: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMReaderNetworkConfig2_Impl::SetEnableContentCaching(this, core::mem::transmute_copy(&fenablecontentcaching)).into()
+            }
+        }
+        unsafe extern "system" fn GetEnableFastCache<Identity: IWMReaderNetworkConfig2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfenablefastcache: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWMReaderNetworkConfig2_Impl::GetEnableFastCache(this) {
+                    Ok(ok__) => {
                         pfenablefastcache.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
@@ -6893,6 +10274,149 @@ impl IWMReaderNetworkConfig2_Vtbl {
         iid == &<IWMReaderNetworkConfig2 as windows_core::Interface>::IID || iid == &<IWMReaderNetworkConfig as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMReaderNetworkConfig2_Vtbl
+where
+    IWMReaderNetworkConfig_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetEnableContentCaching<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig2_Impl>(this: *mut core::ffi::c_void, pfenablecontentcaching: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderNetworkConfig2_Impl::GetEnableContentCaching(this) {
+                    Ok(ok__) => {
+                        pfenablecontentcaching.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEnableContentCaching<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig2_Impl>(this: *mut core::ffi::c_void, fenablecontentcaching: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig2_Impl::SetEnableContentCaching(this, core::mem::transmute_copy(&fenablecontentcaching)).into()
+            }
+        }
+        unsafe extern "system" fn GetEnableFastCache<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig2_Impl>(this: *mut core::ffi::c_void, pfenablefastcache: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderNetworkConfig2_Impl::GetEnableFastCache(this) {
+                    Ok(ok__) => {
+                        pfenablefastcache.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEnableFastCache<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig2_Impl>(this: *mut core::ffi::c_void, fenablefastcache: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig2_Impl::SetEnableFastCache(this, core::mem::transmute_copy(&fenablefastcache)).into()
+            }
+        }
+        unsafe extern "system" fn GetAcceleratedStreamingDuration<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig2_Impl>(this: *mut core::ffi::c_void, pcnsaccelduration: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderNetworkConfig2_Impl::GetAcceleratedStreamingDuration(this) {
+                    Ok(ok__) => {
+                        pcnsaccelduration.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAcceleratedStreamingDuration<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig2_Impl>(this: *mut core::ffi::c_void, cnsaccelduration: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig2_Impl::SetAcceleratedStreamingDuration(this, core::mem::transmute_copy(&cnsaccelduration)).into()
+            }
+        }
+        unsafe extern "system" fn GetAutoReconnectLimit<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig2_Impl>(this: *mut core::ffi::c_void, pdwautoreconnectlimit: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderNetworkConfig2_Impl::GetAutoReconnectLimit(this) {
+                    Ok(ok__) => {
+                        pdwautoreconnectlimit.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAutoReconnectLimit<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig2_Impl>(this: *mut core::ffi::c_void, dwautoreconnectlimit: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig2_Impl::SetAutoReconnectLimit(this, core::mem::transmute_copy(&dwautoreconnectlimit)).into()
+            }
+        }
+        unsafe extern "system" fn GetEnableResends<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig2_Impl>(this: *mut core::ffi::c_void, pfenableresends: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderNetworkConfig2_Impl::GetEnableResends(this) {
+                    Ok(ok__) => {
+                        pfenableresends.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEnableResends<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig2_Impl>(this: *mut core::ffi::c_void, fenableresends: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig2_Impl::SetEnableResends(this, core::mem::transmute_copy(&fenableresends)).into()
+            }
+        }
+        unsafe extern "system" fn GetEnableThinning<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig2_Impl>(this: *mut core::ffi::c_void, pfenablethinning: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderNetworkConfig2_Impl::GetEnableThinning(this) {
+                    Ok(ok__) => {
+                        pfenablethinning.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEnableThinning<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig2_Impl>(this: *mut core::ffi::c_void, fenablethinning: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderNetworkConfig2_Impl::SetEnableThinning(this, core::mem::transmute_copy(&fenablethinning)).into()
+            }
+        }
+        unsafe extern "system" fn GetMaxNetPacketSize<Identity: windows_core::IUnknownImpl + IWMReaderNetworkConfig2_Impl>(this: *mut core::ffi::c_void, pdwmaxnetpacketsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderNetworkConfig2_Impl::GetMaxNetPacketSize(this) {
+                    Ok(ok__) => {
+                        pdwmaxnetpacketsize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWMReaderNetworkConfig_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetEnableContentCaching: GetEnableContentCaching::<Identity>,
+            SetEnableContentCaching: SetEnableContentCaching::<Identity>,
+            GetEnableFastCache: GetEnableFastCache::<Identity>,
+            SetEnableFastCache: SetEnableFastCache::<Identity>,
+            GetAcceleratedStreamingDuration: GetAcceleratedStreamingDuration::<Identity>,
+            SetAcceleratedStreamingDuration: SetAcceleratedStreamingDuration::<Identity>,
+            GetAutoReconnectLimit: GetAutoReconnectLimit::<Identity>,
+            SetAutoReconnectLimit: SetAutoReconnectLimit::<Identity>,
+            GetEnableResends: GetEnableResends::<Identity>,
+            SetEnableResends: SetEnableResends::<Identity>,
+            GetEnableThinning: GetEnableThinning::<Identity>,
+            SetEnableThinning: SetEnableThinning::<Identity>,
+            GetMaxNetPacketSize: GetMaxNetPacketSize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMReaderNetworkConfig2 {}
 windows_core::imp::define_interface!(IWMReaderPlaylistBurn, IWMReaderPlaylistBurn_Vtbl, 0xf28c0300_9baa_4477_a846_1744d9cbf533);
 windows_core::imp::interface_hierarchy!(IWMReaderPlaylistBurn, windows_core::IUnknown);
@@ -6975,6 +10499,50 @@ impl IWMReaderPlaylistBurn_Vtbl {
         iid == &<IWMReaderPlaylistBurn as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMReaderPlaylistBurn_Impl> windows_core::imp::TearOffVtable<Identity> for IWMReaderPlaylistBurn_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InitPlaylistBurn<Identity: windows_core::IUnknownImpl + IWMReaderPlaylistBurn_Impl>(this: *mut core::ffi::c_void, cfiles: u32, ppwszfilenames: *const windows_core::PCWSTR, pcallback: *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderPlaylistBurn_Impl::InitPlaylistBurn(this, core::mem::transmute_copy(&cfiles), core::mem::transmute_copy(&ppwszfilenames), core::mem::transmute_copy(&pcallback), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        unsafe extern "system" fn GetInitResults<Identity: windows_core::IUnknownImpl + IWMReaderPlaylistBurn_Impl>(this: *mut core::ffi::c_void, cfiles: u32, phrstati: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderPlaylistBurn_Impl::GetInitResults(this, core::mem::transmute_copy(&cfiles)) {
+                    Ok(ok__) => {
+                        phrstati.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Cancel<Identity: windows_core::IUnknownImpl + IWMReaderPlaylistBurn_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderPlaylistBurn_Impl::Cancel(this).into()
+            }
+        }
+        unsafe extern "system" fn EndPlaylistBurn<Identity: windows_core::IUnknownImpl + IWMReaderPlaylistBurn_Impl>(this: *mut core::ffi::c_void, hrburnresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderPlaylistBurn_Impl::EndPlaylistBurn(this, core::mem::transmute_copy(&hrburnresult)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InitPlaylistBurn: InitPlaylistBurn::<Identity>,
+            GetInitResults: GetInitResults::<Identity>,
+            Cancel: Cancel::<Identity>,
+            EndPlaylistBurn: EndPlaylistBurn::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMReaderPlaylistBurn {}
 windows_core::imp::define_interface!(IWMReaderStreamClock, IWMReaderStreamClock_Vtbl, 0x96406bed_2b2b_11d3_b36b_00c04f6108ff);
 windows_core::imp::interface_hierarchy!(IWMReaderStreamClock, windows_core::IUnknown);
@@ -7042,6 +10610,43 @@ impl IWMReaderStreamClock_Vtbl {
         iid == &<IWMReaderStreamClock as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMReaderStreamClock_Impl> windows_core::imp::TearOffVtable<Identity> for IWMReaderStreamClock_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetTime<Identity: windows_core::IUnknownImpl + IWMReaderStreamClock_Impl>(this: *mut core::ffi::c_void, pcnsnow: *const u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderStreamClock_Impl::GetTime(this, core::mem::transmute_copy(&pcnsnow)).into()
+            }
+        }
+        unsafe extern "system" fn SetTimer<Identity: windows_core::IUnknownImpl + IWMReaderStreamClock_Impl>(this: *mut core::ffi::c_void, cnswhen: u64, pvparam: *const core::ffi::c_void, pdwtimerid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderStreamClock_Impl::SetTimer(this, core::mem::transmute_copy(&cnswhen), core::mem::transmute_copy(&pvparam)) {
+                    Ok(ok__) => {
+                        pdwtimerid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn KillTimer<Identity: windows_core::IUnknownImpl + IWMReaderStreamClock_Impl>(this: *mut core::ffi::c_void, dwtimerid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderStreamClock_Impl::KillTimer(this, core::mem::transmute_copy(&dwtimerid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetTime: GetTime::<Identity>,
+            SetTimer: SetTimer::<Identity>,
+            KillTimer: KillTimer::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMReaderStreamClock {}
 windows_core::imp::define_interface!(IWMReaderTimecode, IWMReaderTimecode_Vtbl, 0xf369e2f0_e081_4fe6_8450_b810b2f410d1);
 windows_core::imp::interface_hierarchy!(IWMReaderTimecode, windows_core::IUnknown);
@@ -7097,6 +10702,36 @@ impl IWMReaderTimecode_Vtbl {
         iid == &<IWMReaderTimecode as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMReaderTimecode_Impl> windows_core::imp::TearOffVtable<Identity> for IWMReaderTimecode_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetTimecodeRangeCount<Identity: windows_core::IUnknownImpl + IWMReaderTimecode_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, pwrangecount: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMReaderTimecode_Impl::GetTimecodeRangeCount(this, core::mem::transmute_copy(&wstreamnum)) {
+                    Ok(ok__) => {
+                        pwrangecount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTimecodeRangeBounds<Identity: windows_core::IUnknownImpl + IWMReaderTimecode_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, wrangenum: u16, pstarttimecode: *mut u32, pendtimecode: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderTimecode_Impl::GetTimecodeRangeBounds(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&wrangenum), core::mem::transmute_copy(&pstarttimecode), core::mem::transmute_copy(&pendtimecode)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetTimecodeRangeCount: GetTimecodeRangeCount::<Identity>,
+            GetTimecodeRangeBounds: GetTimecodeRangeBounds::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMReaderTimecode {}
 windows_core::imp::define_interface!(IWMReaderTypeNegotiation, IWMReaderTypeNegotiation_Vtbl, 0xfdbe5592_81a1_41ea_93bd_735cad1adc05);
 windows_core::imp::interface_hierarchy!(IWMReaderTypeNegotiation, windows_core::IUnknown);
@@ -7131,6 +10766,20 @@ impl IWMReaderTypeNegotiation_Vtbl {
         iid == &<IWMReaderTypeNegotiation as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMReaderTypeNegotiation_Impl> windows_core::imp::TearOffVtable<Identity> for IWMReaderTypeNegotiation_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn TryOutputProps<Identity: windows_core::IUnknownImpl + IWMReaderTypeNegotiation_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, poutput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMReaderTypeNegotiation_Impl::TryOutputProps(this, core::mem::transmute_copy(&dwoutputnum), core::mem::transmute_copy(&poutput)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, TryOutputProps: TryOutputProps::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMReaderTypeNegotiation {}
 windows_core::imp::define_interface!(IWMRegisterCallback, IWMRegisterCallback_Vtbl, 0xcf4b1f99_4de2_4e49_a363_252740d99bc1);
 windows_core::imp::interface_hierarchy!(IWMRegisterCallback, windows_core::IUnknown);
@@ -7179,6 +10828,30 @@ impl IWMRegisterCallback_Vtbl {
         iid == &<IWMRegisterCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMRegisterCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IWMRegisterCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Advise<Identity: windows_core::IUnknownImpl + IWMRegisterCallback_Impl>(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMRegisterCallback_Impl::Advise(this, core::mem::transmute_copy(&pcallback), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        unsafe extern "system" fn Unadvise<Identity: windows_core::IUnknownImpl + IWMRegisterCallback_Impl>(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMRegisterCallback_Impl::Unadvise(this, core::mem::transmute_copy(&pcallback), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Advise: Advise::<Identity>,
+            Unadvise: Unadvise::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMRegisterCallback {}
 windows_core::imp::define_interface!(IWMRegisteredDevice, IWMRegisteredDevice_Vtbl, 0xa4503bec_5508_4148_97ac_bfa75760a70d);
 windows_core::imp::interface_hierarchy!(IWMRegisteredDevice, windows_core::IUnknown);
@@ -7450,6 +11123,168 @@ impl IWMRegisteredDevice_Vtbl {
         iid == &<IWMRegisteredDevice as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMRegisteredDevice_Impl> windows_core::imp::TearOffVtable<Identity> for IWMRegisteredDevice_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDeviceSerialNumber<Identity: windows_core::IUnknownImpl + IWMRegisteredDevice_Impl>(this: *mut core::ffi::c_void, pserialnumber: *mut DRM_VAL16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMRegisteredDevice_Impl::GetDeviceSerialNumber(this) {
+                    Ok(ok__) => {
+                        pserialnumber.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDeviceCertificate<Identity: windows_core::IUnknownImpl + IWMRegisteredDevice_Impl>(this: *mut core::ffi::c_void, ppcertificate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMRegisteredDevice_Impl::GetDeviceCertificate(this) {
+                    Ok(ok__) => {
+                        ppcertificate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDeviceType<Identity: windows_core::IUnknownImpl + IWMRegisteredDevice_Impl>(this: *mut core::ffi::c_void, pdwtype: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMRegisteredDevice_Impl::GetDeviceType(this) {
+                    Ok(ok__) => {
+                        pdwtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAttributeCount<Identity: windows_core::IUnknownImpl + IWMRegisteredDevice_Impl>(this: *mut core::ffi::c_void, pcattributes: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMRegisteredDevice_Impl::GetAttributeCount(this) {
+                    Ok(ok__) => {
+                        pcattributes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAttributeByIndex<Identity: windows_core::IUnknownImpl + IWMRegisteredDevice_Impl>(this: *mut core::ffi::c_void, dwindex: u32, pbstrname: *mut *mut core::ffi::c_void, pbstrvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMRegisteredDevice_Impl::GetAttributeByIndex(this, core::mem::transmute_copy(&dwindex), core::mem::transmute_copy(&pbstrname), core::mem::transmute_copy(&pbstrvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetAttributeByName<Identity: windows_core::IUnknownImpl + IWMRegisteredDevice_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, pbstrvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMRegisteredDevice_Impl::GetAttributeByName(this, core::mem::transmute(&bstrname)) {
+                    Ok(ok__) => {
+                        pbstrvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAttributeByName<Identity: windows_core::IUnknownImpl + IWMRegisteredDevice_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, bstrvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMRegisteredDevice_Impl::SetAttributeByName(this, core::mem::transmute(&bstrname), core::mem::transmute(&bstrvalue)).into()
+            }
+        }
+        unsafe extern "system" fn Approve<Identity: windows_core::IUnknownImpl + IWMRegisteredDevice_Impl>(this: *mut core::ffi::c_void, fapprove: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMRegisteredDevice_Impl::Approve(this, core::mem::transmute_copy(&fapprove)).into()
+            }
+        }
+        unsafe extern "system" fn IsValid<Identity: windows_core::IUnknownImpl + IWMRegisteredDevice_Impl>(this: *mut core::ffi::c_void, pfvalid: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMRegisteredDevice_Impl::IsValid(this) {
+                    Ok(ok__) => {
+                        pfvalid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsApproved<Identity: windows_core::IUnknownImpl + IWMRegisteredDevice_Impl>(this: *mut core::ffi::c_void, pfapproved: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMRegisteredDevice_Impl::IsApproved(this) {
+                    Ok(ok__) => {
+                        pfapproved.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsWmdrmCompliant<Identity: windows_core::IUnknownImpl + IWMRegisteredDevice_Impl>(this: *mut core::ffi::c_void, pfcompliant: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMRegisteredDevice_Impl::IsWmdrmCompliant(this) {
+                    Ok(ok__) => {
+                        pfcompliant.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsOpened<Identity: windows_core::IUnknownImpl + IWMRegisteredDevice_Impl>(this: *mut core::ffi::c_void, pfopened: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMRegisteredDevice_Impl::IsOpened(this) {
+                    Ok(ok__) => {
+                        pfopened.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Open<Identity: windows_core::IUnknownImpl + IWMRegisteredDevice_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMRegisteredDevice_Impl::Open(this).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IWMRegisteredDevice_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMRegisteredDevice_Impl::Close(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDeviceSerialNumber: GetDeviceSerialNumber::<Identity>,
+            GetDeviceCertificate: GetDeviceCertificate::<Identity>,
+            GetDeviceType: GetDeviceType::<Identity>,
+            GetAttributeCount: GetAttributeCount::<Identity>,
+            GetAttributeByIndex: GetAttributeByIndex::<Identity>,
+            GetAttributeByName: GetAttributeByName::<Identity>,
+            SetAttributeByName: SetAttributeByName::<Identity>,
+            Approve: Approve::<Identity>,
+            IsValid: IsValid::<Identity>,
+            IsApproved: IsApproved::<Identity>,
+            IsWmdrmCompliant: IsWmdrmCompliant::<Identity>,
+            IsOpened: IsOpened::<Identity>,
+            Open: Open::<Identity>,
+            Close: Close::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMRegisteredDevice {}
 windows_core::imp::define_interface!(IWMSBufferAllocator, IWMSBufferAllocator_Vtbl, 0x61103ca4_2033_11d2_9ef1_006097d2d7cf);
 windows_core::imp::interface_hierarchy!(IWMSBufferAllocator, windows_core::IUnknown);
@@ -7514,6 +11349,42 @@ impl IWMSBufferAllocator_Vtbl {
         iid == &<IWMSBufferAllocator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMSBufferAllocator_Impl> windows_core::imp::TearOffVtable<Identity> for IWMSBufferAllocator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AllocateBuffer<Identity: windows_core::IUnknownImpl + IWMSBufferAllocator_Impl>(this: *mut core::ffi::c_void, dwmaxbuffersize: u32, ppbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMSBufferAllocator_Impl::AllocateBuffer(this, core::mem::transmute_copy(&dwmaxbuffersize)) {
+                    Ok(ok__) => {
+                        ppbuffer.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AllocatePageSizeBuffer<Identity: windows_core::IUnknownImpl + IWMSBufferAllocator_Impl>(this: *mut core::ffi::c_void, dwmaxbuffersize: u32, ppbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMSBufferAllocator_Impl::AllocatePageSizeBuffer(this, core::mem::transmute_copy(&dwmaxbuffersize)) {
+                    Ok(ok__) => {
+                        ppbuffer.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AllocateBuffer: AllocateBuffer::<Identity>,
+            AllocatePageSizeBuffer: AllocatePageSizeBuffer::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMSBufferAllocator {}
 windows_core::imp::define_interface!(IWMSInternalAdminNetSource, IWMSInternalAdminNetSource_Vtbl, 0x8bb23e5f_d127_4afb_8d02_ae5b66d54c78);
 windows_core::imp::interface_hierarchy!(IWMSInternalAdminNetSource, windows_core::IUnknown);
@@ -7700,6 +11571,111 @@ impl IWMSInternalAdminNetSource_Vtbl {
         iid == &<IWMSInternalAdminNetSource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource_Impl> windows_core::imp::TearOffVtable<Identity> for IWMSInternalAdminNetSource_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource_Impl>(this: *mut core::ffi::c_void, psharednamespace: *mut core::ffi::c_void, pnamespacenode: *mut core::ffi::c_void, pnetsourcecreator: *mut core::ffi::c_void, fembeddedinserver: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSInternalAdminNetSource_Impl::Initialize(this, core::mem::transmute_copy(&psharednamespace), core::mem::transmute_copy(&pnamespacenode), core::mem::transmute_copy(&pnetsourcecreator), core::mem::transmute_copy(&fembeddedinserver)).into()
+            }
+        }
+        unsafe extern "system" fn GetNetSourceCreator<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource_Impl>(this: *mut core::ffi::c_void, ppnetsourcecreator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMSInternalAdminNetSource_Impl::GetNetSourceCreator(this) {
+                    Ok(ok__) => {
+                        ppnetsourcecreator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCredentials<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource_Impl>(this: *mut core::ffi::c_void, bstrrealm: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, bstrpassword: *mut core::ffi::c_void, fpersist: windows_core::BOOL, fconfirmedgood: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSInternalAdminNetSource_Impl::SetCredentials(this, core::mem::transmute(&bstrrealm), core::mem::transmute(&bstrname), core::mem::transmute(&bstrpassword), core::mem::transmute_copy(&fpersist), core::mem::transmute_copy(&fconfirmedgood)).into()
+            }
+        }
+        unsafe extern "system" fn GetCredentials<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource_Impl>(this: *mut core::ffi::c_void, bstrrealm: *mut core::ffi::c_void, pbstrname: *mut *mut core::ffi::c_void, pbstrpassword: *mut *mut core::ffi::c_void, pfconfirmedgood: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSInternalAdminNetSource_Impl::GetCredentials(this, core::mem::transmute(&bstrrealm), core::mem::transmute_copy(&pbstrname), core::mem::transmute_copy(&pbstrpassword), core::mem::transmute_copy(&pfconfirmedgood)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteCredentials<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource_Impl>(this: *mut core::ffi::c_void, bstrrealm: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSInternalAdminNetSource_Impl::DeleteCredentials(this, core::mem::transmute(&bstrrealm)).into()
+            }
+        }
+        unsafe extern "system" fn GetCredentialFlags<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource_Impl>(this: *mut core::ffi::c_void, lpdwflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMSInternalAdminNetSource_Impl::GetCredentialFlags(this) {
+                    Ok(ok__) => {
+                        lpdwflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCredentialFlags<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource_Impl>(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSInternalAdminNetSource_Impl::SetCredentialFlags(this, core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn FindProxyForURL<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource_Impl>(this: *mut core::ffi::c_void, bstrprotocol: *mut core::ffi::c_void, bstrhost: *mut core::ffi::c_void, pfproxyenabled: *mut windows_core::BOOL, pbstrproxyserver: *mut *mut core::ffi::c_void, pdwproxyport: *mut u32, pdwproxycontext: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSInternalAdminNetSource_Impl::FindProxyForURL(this, core::mem::transmute(&bstrprotocol), core::mem::transmute(&bstrhost), core::mem::transmute_copy(&pfproxyenabled), core::mem::transmute_copy(&pbstrproxyserver), core::mem::transmute_copy(&pdwproxyport), core::mem::transmute_copy(&pdwproxycontext)).into()
+            }
+        }
+        unsafe extern "system" fn RegisterProxyFailure<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource_Impl>(this: *mut core::ffi::c_void, hrparam: windows_core::HRESULT, dwproxycontext: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSInternalAdminNetSource_Impl::RegisterProxyFailure(this, core::mem::transmute_copy(&hrparam), core::mem::transmute_copy(&dwproxycontext)).into()
+            }
+        }
+        unsafe extern "system" fn ShutdownProxyContext<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource_Impl>(this: *mut core::ffi::c_void, dwproxycontext: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSInternalAdminNetSource_Impl::ShutdownProxyContext(this, core::mem::transmute_copy(&dwproxycontext)).into()
+            }
+        }
+        unsafe extern "system" fn IsUsingIE<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource_Impl>(this: *mut core::ffi::c_void, dwproxycontext: u32, pfisusingie: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMSInternalAdminNetSource_Impl::IsUsingIE(this, core::mem::transmute_copy(&dwproxycontext)) {
+                    Ok(ok__) => {
+                        pfisusingie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            GetNetSourceCreator: GetNetSourceCreator::<Identity>,
+            SetCredentials: SetCredentials::<Identity>,
+            GetCredentials: GetCredentials::<Identity>,
+            DeleteCredentials: DeleteCredentials::<Identity>,
+            GetCredentialFlags: GetCredentialFlags::<Identity>,
+            SetCredentialFlags: SetCredentialFlags::<Identity>,
+            FindProxyForURL: FindProxyForURL::<Identity>,
+            RegisterProxyFailure: RegisterProxyFailure::<Identity>,
+            ShutdownProxyContext: ShutdownProxyContext::<Identity>,
+            IsUsingIE: IsUsingIE::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMSInternalAdminNetSource {}
 windows_core::imp::define_interface!(IWMSInternalAdminNetSource2, IWMSInternalAdminNetSource2_Vtbl, 0xe74d58c3_cf77_4b51_af17_744687c43eae);
 windows_core::imp::interface_hierarchy!(IWMSInternalAdminNetSource2, windows_core::IUnknown);
@@ -7736,39 +11712,77 @@ impl IWMSInternalAdminNetSource2_Vtbl {
     pub const fn new<Identity: IWMSInternalAdminNetSource2_Impl, const OFFSET: isize>() -> Self {
         unsafe extern "system" fn SetCredentialsEx<Identity: IWMSInternalAdminNetSource2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrrealm: *mut core::ffi::c_void, bstrurl: *mut core::ffi::c_void, fproxy: windows_core::BOOL, bstrname: *mut core::ffi::c_void, bstrpassword: *mut core::ffi::c_void, fpersist: windows_core::BOOL, fconfirmedgood: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMSInternalAdminNetSource2_Impl::SetCredentialsEx(this, core::mem::transmute(&bstrrealm), core::mem::transmute(&bstrurl), core::mem::transmute_copy(&fproxy), core::mem::transmute(&bstrname), core::mem::transmute(&bstrpassword), core::mem::transmute_copy(&fpersist), core::mem::transmute_copy(&fconfirmedgood)).into()
+            }
+        }
+        unsafe extern "system" fn GetCredentialsEx<Identity: IWMSInternalAdminNetSource2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrrealm: *mut core::ffi::c_void, bstrurl: *mut core::ffi::c_void, fproxy: windows_core::BOOL, pdwurlpolicy: *mut NETSOURCE_URLCREDPOLICY_SETTINGS, pbstrname: *mut *mut core::ffi::c_void, pbstrpassword: *mut *mut core::ffi::c_void, pfconfirmedgood: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMSInternalAdminNetSource2_Impl::GetCredentialsEx(this, core::mem::transmute(&bstrrealm), core::mem::transmute(&bstrurl), core::mem::transmute_copy(&fproxy), core::mem::transmute_copy(&pdwurlpolicy), core::mem::transmute_copy(&pbstrname), core::mem::transmute_copy(&pbstrpassword), core::mem::transmute_copy(&pfconfirmedgood)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteCredentialsEx<Identity: IWMSInternalAdminNetSource2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrrealm: *mut core::ffi::c_void, bstrurl: *mut core::ffi::c_void, fproxy: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMSInternalAdminNetSource2_Impl::DeleteCredentialsEx(this, core::mem::transmute(&bstrrealm), core::mem::transmute(&bstrurl), core::mem::transmute_copy(&fproxy)).into()
+            }
+        }
+        unsafe extern "system" fn FindProxyForURLEx<Identity: IWMSInternalAdminNetSource2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrprotocol: *mut core::ffi::c_void, bstrhost: *mut core::ffi::c_void, bstrurl: *mut core::ffi::c_void, pfproxyenabled: *mut windows_core::BOOL, pbstrproxyserver: *mut *mut core::ffi::c_void, pdwproxyport: *mut u32, pdwproxycontext: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMSInternalAdminNetSource2_Impl::FindProxyForURLEx(this, core::mem::transmute(&bstrprotocol), core::mem::transmute(&bstrhost), core::mem::transmute(&bstrurl), core::mem::transmute_copy(&pfproxyenabled), core::mem::transmute_copy(&pbstrproxyserver), core::mem::transmute_copy(&pdwproxyport), core::mem::transmute_copy(&pdwproxycontext)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            SetCredentialsEx: SetCredentialsEx::<Identity, OFFSET>,
+            GetCredentialsEx: GetCredentialsEx::<Identity, OFFSET>,
+            DeleteCredentialsEx: DeleteCredentialsEx::<Identity, OFFSET>,
+            FindProxyForURLEx: FindProxyForURLEx::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMSInternalAdminNetSource2 as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMSInternalAdminNetSource2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetCredentialsEx<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource2_Impl>(this: *mut core::ffi::c_void, bstrrealm: *mut core::ffi::c_void, bstrurl: *mut core::ffi::c_void, fproxy: windows_core::BOOL, bstrname: *mut core::ffi::c_void, bstrpassword: *mut core::ffi::c_void, fpersist: windows_core::BOOL, fconfirmedgood: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMSInternalAdminNetSource2_Impl::SetCredentialsEx(this, core::mem::transmute(&bstrrealm), core::mem::transmute(&bstrurl), core::mem::transmute_copy(&fproxy), core::mem::transmute(&bstrname), core::mem::transmute(&bstrpassword), core::mem::transmute_copy(&fpersist), core::mem::transmute_copy(&fconfirmedgood)).into()
             }
         }
-        unsafe extern "system" fn GetCredentialsEx<Identity: IWMSInternalAdminNetSource2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrrealm: *mut core::ffi::c_void, bstrurl: *mut core::ffi::c_void, fproxy: windows_core::BOOL, pdwurlpolicy: *mut NETSOURCE_URLCREDPOLICY_SETTINGS, pbstrname: *mut *mut core::ffi::c_void, pbstrpassword: *mut *mut core::ffi::c_void, pfconfirmedgood: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetCredentialsEx<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource2_Impl>(this: *mut core::ffi::c_void, bstrrealm: *mut core::ffi::c_void, bstrurl: *mut core::ffi::c_void, fproxy: windows_core::BOOL, pdwurlpolicy: *mut NETSOURCE_URLCREDPOLICY_SETTINGS, pbstrname: *mut *mut core::ffi::c_void, pbstrpassword: *mut *mut core::ffi::c_void, pfconfirmedgood: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMSInternalAdminNetSource2_Impl::GetCredentialsEx(this, core::mem::transmute(&bstrrealm), core::mem::transmute(&bstrurl), core::mem::transmute_copy(&fproxy), core::mem::transmute_copy(&pdwurlpolicy), core::mem::transmute_copy(&pbstrname), core::mem::transmute_copy(&pbstrpassword), core::mem::transmute_copy(&pfconfirmedgood)).into()
             }
         }
-        unsafe extern "system" fn DeleteCredentialsEx<Identity: IWMSInternalAdminNetSource2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrrealm: *mut core::ffi::c_void, bstrurl: *mut core::ffi::c_void, fproxy: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn DeleteCredentialsEx<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource2_Impl>(this: *mut core::ffi::c_void, bstrrealm: *mut core::ffi::c_void, bstrurl: *mut core::ffi::c_void, fproxy: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMSInternalAdminNetSource2_Impl::DeleteCredentialsEx(this, core::mem::transmute(&bstrrealm), core::mem::transmute(&bstrurl), core::mem::transmute_copy(&fproxy)).into()
             }
         }
-        unsafe extern "system" fn FindProxyForURLEx<Identity: IWMSInternalAdminNetSource2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrprotocol: *mut core::ffi::c_void, bstrhost: *mut core::ffi::c_void, bstrurl: *mut core::ffi::c_void, pfproxyenabled: *mut windows_core::BOOL, pbstrproxyserver: *mut *mut core::ffi::c_void, pdwproxyport: *mut u32, pdwproxycontext: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn FindProxyForURLEx<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource2_Impl>(this: *mut core::ffi::c_void, bstrprotocol: *mut core::ffi::c_void, bstrhost: *mut core::ffi::c_void, bstrurl: *mut core::ffi::c_void, pfproxyenabled: *mut windows_core::BOOL, pbstrproxyserver: *mut *mut core::ffi::c_void, pdwproxyport: *mut u32, pdwproxycontext: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMSInternalAdminNetSource2_Impl::FindProxyForURLEx(this, core::mem::transmute(&bstrprotocol), core::mem::transmute(&bstrhost), core::mem::transmute(&bstrurl), core::mem::transmute_copy(&pfproxyenabled), core::mem::transmute_copy(&pbstrproxyserver), core::mem::transmute_copy(&pdwproxyport), core::mem::transmute_copy(&pdwproxycontext)).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            SetCredentialsEx: SetCredentialsEx::<Identity, OFFSET>,
-            GetCredentialsEx: GetCredentialsEx::<Identity, OFFSET>,
-            DeleteCredentialsEx: DeleteCredentialsEx::<Identity, OFFSET>,
-            FindProxyForURLEx: FindProxyForURLEx::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetCredentialsEx: SetCredentialsEx::<Identity>,
+            GetCredentialsEx: GetCredentialsEx::<Identity>,
+            DeleteCredentialsEx: DeleteCredentialsEx::<Identity>,
+            FindProxyForURLEx: FindProxyForURLEx::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWMSInternalAdminNetSource2 as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IWMSInternalAdminNetSource2 {}
 windows_core::imp::define_interface!(IWMSInternalAdminNetSource3, IWMSInternalAdminNetSource3_Vtbl, 0x6b63d08e_4590_44af_9eb3_57ff1e73bf80);
@@ -7900,6 +11914,77 @@ impl IWMSInternalAdminNetSource3_Vtbl {
         iid == &<IWMSInternalAdminNetSource3 as windows_core::Interface>::IID || iid == &<IWMSInternalAdminNetSource2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource3_Impl> windows_core::imp::TearOffVtable<Identity> for IWMSInternalAdminNetSource3_Vtbl
+where
+    IWMSInternalAdminNetSource2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetNetSourceCreator2<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource3_Impl>(this: *mut core::ffi::c_void, ppnetsourcecreator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMSInternalAdminNetSource3_Impl::GetNetSourceCreator2(this) {
+                    Ok(ok__) => {
+                        ppnetsourcecreator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindProxyForURLEx2<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource3_Impl>(this: *mut core::ffi::c_void, bstrprotocol: *mut core::ffi::c_void, bstrhost: *mut core::ffi::c_void, bstrurl: *mut core::ffi::c_void, pfproxyenabled: *mut windows_core::BOOL, pbstrproxyserver: *mut *mut core::ffi::c_void, pdwproxyport: *mut u32, pqwproxycontext: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSInternalAdminNetSource3_Impl::FindProxyForURLEx2(this, core::mem::transmute(&bstrprotocol), core::mem::transmute(&bstrhost), core::mem::transmute(&bstrurl), core::mem::transmute_copy(&pfproxyenabled), core::mem::transmute_copy(&pbstrproxyserver), core::mem::transmute_copy(&pdwproxyport), core::mem::transmute_copy(&pqwproxycontext)).into()
+            }
+        }
+        unsafe extern "system" fn RegisterProxyFailure2<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource3_Impl>(this: *mut core::ffi::c_void, hrparam: windows_core::HRESULT, qwproxycontext: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSInternalAdminNetSource3_Impl::RegisterProxyFailure2(this, core::mem::transmute_copy(&hrparam), core::mem::transmute_copy(&qwproxycontext)).into()
+            }
+        }
+        unsafe extern "system" fn ShutdownProxyContext2<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource3_Impl>(this: *mut core::ffi::c_void, qwproxycontext: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSInternalAdminNetSource3_Impl::ShutdownProxyContext2(this, core::mem::transmute_copy(&qwproxycontext)).into()
+            }
+        }
+        unsafe extern "system" fn IsUsingIE2<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource3_Impl>(this: *mut core::ffi::c_void, qwproxycontext: u64, pfisusingie: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMSInternalAdminNetSource3_Impl::IsUsingIE2(this, core::mem::transmute_copy(&qwproxycontext)) {
+                    Ok(ok__) => {
+                        pfisusingie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCredentialsEx2<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource3_Impl>(this: *mut core::ffi::c_void, bstrrealm: *mut core::ffi::c_void, bstrurl: *mut core::ffi::c_void, fproxy: windows_core::BOOL, bstrname: *mut core::ffi::c_void, bstrpassword: *mut core::ffi::c_void, fpersist: windows_core::BOOL, fconfirmedgood: windows_core::BOOL, fcleartextauthentication: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSInternalAdminNetSource3_Impl::SetCredentialsEx2(this, core::mem::transmute(&bstrrealm), core::mem::transmute(&bstrurl), core::mem::transmute_copy(&fproxy), core::mem::transmute(&bstrname), core::mem::transmute(&bstrpassword), core::mem::transmute_copy(&fpersist), core::mem::transmute_copy(&fconfirmedgood), core::mem::transmute_copy(&fcleartextauthentication)).into()
+            }
+        }
+        unsafe extern "system" fn GetCredentialsEx2<Identity: windows_core::IUnknownImpl + IWMSInternalAdminNetSource3_Impl>(this: *mut core::ffi::c_void, bstrrealm: *mut core::ffi::c_void, bstrurl: *mut core::ffi::c_void, fproxy: windows_core::BOOL, fcleartextauthentication: windows_core::BOOL, pdwurlpolicy: *mut NETSOURCE_URLCREDPOLICY_SETTINGS, pbstrname: *mut *mut core::ffi::c_void, pbstrpassword: *mut *mut core::ffi::c_void, pfconfirmedgood: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSInternalAdminNetSource3_Impl::GetCredentialsEx2(this, core::mem::transmute(&bstrrealm), core::mem::transmute(&bstrurl), core::mem::transmute_copy(&fproxy), core::mem::transmute_copy(&fcleartextauthentication), core::mem::transmute_copy(&pdwurlpolicy), core::mem::transmute_copy(&pbstrname), core::mem::transmute_copy(&pbstrpassword), core::mem::transmute_copy(&pfconfirmedgood)).into()
+            }
+        }
+        Self {
+            base__: <IWMSInternalAdminNetSource2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetNetSourceCreator2: GetNetSourceCreator2::<Identity>,
+            FindProxyForURLEx2: FindProxyForURLEx2::<Identity>,
+            RegisterProxyFailure2: RegisterProxyFailure2::<Identity>,
+            ShutdownProxyContext2: ShutdownProxyContext2::<Identity>,
+            IsUsingIE2: IsUsingIE2::<Identity>,
+            SetCredentialsEx2: SetCredentialsEx2::<Identity>,
+            GetCredentialsEx2: GetCredentialsEx2::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMSInternalAdminNetSource3 {}
 windows_core::imp::define_interface!(IWMSecureChannel, IWMSecureChannel_Vtbl, 0x2720598a_d0f2_4189_bd10_91c46ef0936f);
 impl core::ops::Deref for IWMSecureChannel {
@@ -8075,6 +12160,99 @@ impl IWMSecureChannel_Vtbl {
         iid == &<IWMSecureChannel as windows_core::Interface>::IID || iid == &<IWMAuthorizer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMSecureChannel_Impl> windows_core::imp::TearOffVtable<Identity> for IWMSecureChannel_Vtbl
+where
+    IWMAuthorizer_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn WMSC_AddCertificate<Identity: windows_core::IUnknownImpl + IWMSecureChannel_Impl>(this: *mut core::ffi::c_void, pcert: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSecureChannel_Impl::WMSC_AddCertificate(this, core::mem::transmute_copy(&pcert)).into()
+            }
+        }
+        unsafe extern "system" fn WMSC_AddSignature<Identity: windows_core::IUnknownImpl + IWMSecureChannel_Impl>(this: *mut core::ffi::c_void, pbcertsig: *const u8, cbcertsig: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSecureChannel_Impl::WMSC_AddSignature(this, core::mem::transmute_copy(&pbcertsig), core::mem::transmute_copy(&cbcertsig)).into()
+            }
+        }
+        unsafe extern "system" fn WMSC_Connect<Identity: windows_core::IUnknownImpl + IWMSecureChannel_Impl>(this: *mut core::ffi::c_void, potherside: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSecureChannel_Impl::WMSC_Connect(this, core::mem::transmute_copy(&potherside)).into()
+            }
+        }
+        unsafe extern "system" fn WMSC_IsConnected<Identity: windows_core::IUnknownImpl + IWMSecureChannel_Impl>(this: *mut core::ffi::c_void, pfisconnected: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMSecureChannel_Impl::WMSC_IsConnected(this) {
+                    Ok(ok__) => {
+                        pfisconnected.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn WMSC_Disconnect<Identity: windows_core::IUnknownImpl + IWMSecureChannel_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSecureChannel_Impl::WMSC_Disconnect(this).into()
+            }
+        }
+        unsafe extern "system" fn WMSC_GetValidCertificate<Identity: windows_core::IUnknownImpl + IWMSecureChannel_Impl>(this: *mut core::ffi::c_void, ppbcertificate: *mut *mut u8, pdwsignature: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSecureChannel_Impl::WMSC_GetValidCertificate(this, core::mem::transmute_copy(&ppbcertificate), core::mem::transmute_copy(&pdwsignature)).into()
+            }
+        }
+        unsafe extern "system" fn WMSC_Encrypt<Identity: windows_core::IUnknownImpl + IWMSecureChannel_Impl>(this: *mut core::ffi::c_void, pbdata: *const u8, cbdata: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSecureChannel_Impl::WMSC_Encrypt(this, core::mem::transmute_copy(&pbdata), core::mem::transmute_copy(&cbdata)).into()
+            }
+        }
+        unsafe extern "system" fn WMSC_Decrypt<Identity: windows_core::IUnknownImpl + IWMSecureChannel_Impl>(this: *mut core::ffi::c_void, pbdata: *const u8, cbdata: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSecureChannel_Impl::WMSC_Decrypt(this, core::mem::transmute_copy(&pbdata), core::mem::transmute_copy(&cbdata)).into()
+            }
+        }
+        unsafe extern "system" fn WMSC_Lock<Identity: windows_core::IUnknownImpl + IWMSecureChannel_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSecureChannel_Impl::WMSC_Lock(this).into()
+            }
+        }
+        unsafe extern "system" fn WMSC_Unlock<Identity: windows_core::IUnknownImpl + IWMSecureChannel_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSecureChannel_Impl::WMSC_Unlock(this).into()
+            }
+        }
+        unsafe extern "system" fn WMSC_SetSharedData<Identity: windows_core::IUnknownImpl + IWMSecureChannel_Impl>(this: *mut core::ffi::c_void, dwcertindex: u32, pbshareddata: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSecureChannel_Impl::WMSC_SetSharedData(this, core::mem::transmute_copy(&dwcertindex), core::mem::transmute_copy(&pbshareddata)).into()
+            }
+        }
+        Self {
+            base__: <IWMAuthorizer_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            WMSC_AddCertificate: WMSC_AddCertificate::<Identity>,
+            WMSC_AddSignature: WMSC_AddSignature::<Identity>,
+            WMSC_Connect: WMSC_Connect::<Identity>,
+            WMSC_IsConnected: WMSC_IsConnected::<Identity>,
+            WMSC_Disconnect: WMSC_Disconnect::<Identity>,
+            WMSC_GetValidCertificate: WMSC_GetValidCertificate::<Identity>,
+            WMSC_Encrypt: WMSC_Encrypt::<Identity>,
+            WMSC_Decrypt: WMSC_Decrypt::<Identity>,
+            WMSC_Lock: WMSC_Lock::<Identity>,
+            WMSC_Unlock: WMSC_Unlock::<Identity>,
+            WMSC_SetSharedData: WMSC_SetSharedData::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMSecureChannel {}
 windows_core::imp::define_interface!(IWMStatusCallback, IWMStatusCallback_Vtbl, 0x6d7cdc70_9888_11d3_8edc_00c04f6109cf);
 windows_core::imp::interface_hierarchy!(IWMStatusCallback, windows_core::IUnknown);
@@ -8106,6 +12284,20 @@ impl IWMStatusCallback_Vtbl {
         iid == &<IWMStatusCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMStatusCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IWMStatusCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnStatus<Identity: windows_core::IUnknownImpl + IWMStatusCallback_Impl>(this: *mut core::ffi::c_void, status: WMT_STATUS, hr: windows_core::HRESULT, dwtype: WMT_ATTR_DATATYPE, pvalue: *const u8, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMStatusCallback_Impl::OnStatus(this, core::mem::transmute_copy(&status), core::mem::transmute_copy(&hr), core::mem::transmute_copy(&dwtype), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnStatus: OnStatus::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMStatusCallback {}
 windows_core::imp::define_interface!(IWMStreamConfig, IWMStreamConfig_Vtbl, 0x96406bdc_2b2b_11d3_b36b_00c04f6108ff);
 windows_core::imp::interface_hierarchy!(IWMStreamConfig, windows_core::IUnknown);
@@ -8302,6 +12494,117 @@ impl IWMStreamConfig_Vtbl {
         iid == &<IWMStreamConfig as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMStreamConfig_Impl> windows_core::imp::TearOffVtable<Identity> for IWMStreamConfig_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStreamType<Identity: windows_core::IUnknownImpl + IWMStreamConfig_Impl>(this: *mut core::ffi::c_void, pguidstreamtype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMStreamConfig_Impl::GetStreamType(this) {
+                    Ok(ok__) => {
+                        pguidstreamtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStreamNumber<Identity: windows_core::IUnknownImpl + IWMStreamConfig_Impl>(this: *mut core::ffi::c_void, pwstreamnum: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMStreamConfig_Impl::GetStreamNumber(this) {
+                    Ok(ok__) => {
+                        pwstreamnum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetStreamNumber<Identity: windows_core::IUnknownImpl + IWMStreamConfig_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMStreamConfig_Impl::SetStreamNumber(this, core::mem::transmute_copy(&wstreamnum)).into()
+            }
+        }
+        unsafe extern "system" fn GetStreamName<Identity: windows_core::IUnknownImpl + IWMStreamConfig_Impl>(this: *mut core::ffi::c_void, pwszstreamname: windows_core::PWSTR, pcchstreamname: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMStreamConfig_Impl::GetStreamName(this, core::mem::transmute_copy(&pwszstreamname), core::mem::transmute_copy(&pcchstreamname)).into()
+            }
+        }
+        unsafe extern "system" fn SetStreamName<Identity: windows_core::IUnknownImpl + IWMStreamConfig_Impl>(this: *mut core::ffi::c_void, pwszstreamname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMStreamConfig_Impl::SetStreamName(this, core::mem::transmute(&pwszstreamname)).into()
+            }
+        }
+        unsafe extern "system" fn GetConnectionName<Identity: windows_core::IUnknownImpl + IWMStreamConfig_Impl>(this: *mut core::ffi::c_void, pwszinputname: windows_core::PWSTR, pcchinputname: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMStreamConfig_Impl::GetConnectionName(this, core::mem::transmute_copy(&pwszinputname), core::mem::transmute_copy(&pcchinputname)).into()
+            }
+        }
+        unsafe extern "system" fn SetConnectionName<Identity: windows_core::IUnknownImpl + IWMStreamConfig_Impl>(this: *mut core::ffi::c_void, pwszinputname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMStreamConfig_Impl::SetConnectionName(this, core::mem::transmute(&pwszinputname)).into()
+            }
+        }
+        unsafe extern "system" fn GetBitrate<Identity: windows_core::IUnknownImpl + IWMStreamConfig_Impl>(this: *mut core::ffi::c_void, pdwbitrate: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMStreamConfig_Impl::GetBitrate(this) {
+                    Ok(ok__) => {
+                        pdwbitrate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBitrate<Identity: windows_core::IUnknownImpl + IWMStreamConfig_Impl>(this: *mut core::ffi::c_void, pdwbitrate: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMStreamConfig_Impl::SetBitrate(this, core::mem::transmute_copy(&pdwbitrate)).into()
+            }
+        }
+        unsafe extern "system" fn GetBufferWindow<Identity: windows_core::IUnknownImpl + IWMStreamConfig_Impl>(this: *mut core::ffi::c_void, pmsbufferwindow: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMStreamConfig_Impl::GetBufferWindow(this) {
+                    Ok(ok__) => {
+                        pmsbufferwindow.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBufferWindow<Identity: windows_core::IUnknownImpl + IWMStreamConfig_Impl>(this: *mut core::ffi::c_void, msbufferwindow: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMStreamConfig_Impl::SetBufferWindow(this, core::mem::transmute_copy(&msbufferwindow)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStreamType: GetStreamType::<Identity>,
+            GetStreamNumber: GetStreamNumber::<Identity>,
+            SetStreamNumber: SetStreamNumber::<Identity>,
+            GetStreamName: GetStreamName::<Identity>,
+            SetStreamName: SetStreamName::<Identity>,
+            GetConnectionName: GetConnectionName::<Identity>,
+            SetConnectionName: SetConnectionName::<Identity>,
+            GetBitrate: GetBitrate::<Identity>,
+            SetBitrate: SetBitrate::<Identity>,
+            GetBufferWindow: GetBufferWindow::<Identity>,
+            SetBufferWindow: SetBufferWindow::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMStreamConfig {}
 windows_core::imp::define_interface!(IWMStreamConfig2, IWMStreamConfig2_Vtbl, 0x7688d8cb_fc0d_43bd_9459_5a8dec200cfa);
 impl core::ops::Deref for IWMStreamConfig2 {
@@ -8420,6 +12723,70 @@ impl IWMStreamConfig2_Vtbl {
         iid == &<IWMStreamConfig2 as windows_core::Interface>::IID || iid == &<IWMStreamConfig as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMStreamConfig2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMStreamConfig2_Vtbl
+where
+    IWMStreamConfig_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetTransportType<Identity: windows_core::IUnknownImpl + IWMStreamConfig2_Impl>(this: *mut core::ffi::c_void, pntransporttype: *mut WMT_TRANSPORT_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMStreamConfig2_Impl::GetTransportType(this) {
+                    Ok(ok__) => {
+                        pntransporttype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTransportType<Identity: windows_core::IUnknownImpl + IWMStreamConfig2_Impl>(this: *mut core::ffi::c_void, ntransporttype: WMT_TRANSPORT_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMStreamConfig2_Impl::SetTransportType(this, core::mem::transmute_copy(&ntransporttype)).into()
+            }
+        }
+        unsafe extern "system" fn AddDataUnitExtension<Identity: windows_core::IUnknownImpl + IWMStreamConfig2_Impl>(this: *mut core::ffi::c_void, guidextensionsystemid: windows_core::GUID, cbextensiondatasize: u16, pbextensionsysteminfo: *const u8, cbextensionsysteminfo: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMStreamConfig2_Impl::AddDataUnitExtension(this, core::mem::transmute(&guidextensionsystemid), core::mem::transmute_copy(&cbextensiondatasize), core::mem::transmute_copy(&pbextensionsysteminfo), core::mem::transmute_copy(&cbextensionsysteminfo)).into()
+            }
+        }
+        unsafe extern "system" fn GetDataUnitExtensionCount<Identity: windows_core::IUnknownImpl + IWMStreamConfig2_Impl>(this: *mut core::ffi::c_void, pcdataunitextensions: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMStreamConfig2_Impl::GetDataUnitExtensionCount(this) {
+                    Ok(ok__) => {
+                        pcdataunitextensions.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDataUnitExtension<Identity: windows_core::IUnknownImpl + IWMStreamConfig2_Impl>(this: *mut core::ffi::c_void, wdataunitextensionnumber: u16, pguidextensionsystemid: *mut windows_core::GUID, pcbextensiondatasize: *mut u16, pbextensionsysteminfo: *mut u8, pcbextensionsysteminfo: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMStreamConfig2_Impl::GetDataUnitExtension(this, core::mem::transmute_copy(&wdataunitextensionnumber), core::mem::transmute_copy(&pguidextensionsystemid), core::mem::transmute_copy(&pcbextensiondatasize), core::mem::transmute_copy(&pbextensionsysteminfo), core::mem::transmute_copy(&pcbextensionsysteminfo)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveAllDataUnitExtensions<Identity: windows_core::IUnknownImpl + IWMStreamConfig2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMStreamConfig2_Impl::RemoveAllDataUnitExtensions(this).into()
+            }
+        }
+        Self {
+            base__: <IWMStreamConfig_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetTransportType: GetTransportType::<Identity>,
+            SetTransportType: SetTransportType::<Identity>,
+            AddDataUnitExtension: AddDataUnitExtension::<Identity>,
+            GetDataUnitExtensionCount: GetDataUnitExtensionCount::<Identity>,
+            GetDataUnitExtension: GetDataUnitExtension::<Identity>,
+            RemoveAllDataUnitExtensions: RemoveAllDataUnitExtensions::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMStreamConfig2 {}
 windows_core::imp::define_interface!(IWMStreamConfig3, IWMStreamConfig3_Vtbl, 0xcb164104_3aa9_45a7_9ac9_4daee131d6e1);
 impl core::ops::Deref for IWMStreamConfig3 {
@@ -8461,19 +12828,43 @@ impl IWMStreamConfig3_Vtbl {
         }
         unsafe extern "system" fn SetLanguage<Identity: IWMStreamConfig3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pwszlanguagestring: windows_core::PCWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMStreamConfig3_Impl::SetLanguage(this, core::mem::transmute(&pwszlanguagestring)).into()
+            }
+        }
+        Self {
+            base__: IWMStreamConfig2_Vtbl::new::<Identity, OFFSET>(),
+            GetLanguage: GetLanguage::<Identity, OFFSET>,
+            SetLanguage: SetLanguage::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMStreamConfig3 as windows_core::Interface>::IID || iid == &<IWMStreamConfig as windows_core::Interface>::IID || iid == &<IWMStreamConfig2 as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IWMStreamConfig3_Impl> windows_core::imp::TearOffVtable<Identity> for IWMStreamConfig3_Vtbl
+where
+    IWMStreamConfig2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetLanguage<Identity: windows_core::IUnknownImpl + IWMStreamConfig3_Impl>(this: *mut core::ffi::c_void, pwszlanguagestring: windows_core::PWSTR, pcchlanguagestringlength: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMStreamConfig3_Impl::GetLanguage(this, core::mem::transmute_copy(&pwszlanguagestring), core::mem::transmute_copy(&pcchlanguagestringlength)).into()
+            }
+        }
+        unsafe extern "system" fn SetLanguage<Identity: windows_core::IUnknownImpl + IWMStreamConfig3_Impl>(this: *mut core::ffi::c_void, pwszlanguagestring: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMStreamConfig3_Impl::SetLanguage(this, core::mem::transmute(&pwszlanguagestring)).into()
             }
         }
         Self {
-            base__: IWMStreamConfig2_Vtbl::new::<Identity, OFFSET>(),
-            GetLanguage: GetLanguage::<Identity, OFFSET>,
-            SetLanguage: SetLanguage::<Identity, OFFSET>,
+            base__: <IWMStreamConfig2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetLanguage: GetLanguage::<Identity>,
+            SetLanguage: SetLanguage::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWMStreamConfig3 as windows_core::Interface>::IID || iid == &<IWMStreamConfig as windows_core::Interface>::IID || iid == &<IWMStreamConfig2 as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IWMStreamConfig3 {}
 windows_core::imp::define_interface!(IWMStreamList, IWMStreamList_Vtbl, 0x96406bdd_2b2b_11d3_b36b_00c04f6108ff);
@@ -8533,6 +12924,37 @@ impl IWMStreamList_Vtbl {
         iid == &<IWMStreamList as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMStreamList_Impl> windows_core::imp::TearOffVtable<Identity> for IWMStreamList_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStreams<Identity: windows_core::IUnknownImpl + IWMStreamList_Impl>(this: *mut core::ffi::c_void, pwstreamnumarray: *mut u16, pcstreams: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMStreamList_Impl::GetStreams(this, core::mem::transmute_copy(&pwstreamnumarray), core::mem::transmute_copy(&pcstreams)).into()
+            }
+        }
+        unsafe extern "system" fn AddStream<Identity: windows_core::IUnknownImpl + IWMStreamList_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMStreamList_Impl::AddStream(this, core::mem::transmute_copy(&wstreamnum)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveStream<Identity: windows_core::IUnknownImpl + IWMStreamList_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMStreamList_Impl::RemoveStream(this, core::mem::transmute_copy(&wstreamnum)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStreams: GetStreams::<Identity>,
+            AddStream: AddStream::<Identity>,
+            RemoveStream: RemoveStream::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMStreamList {}
 windows_core::imp::define_interface!(IWMStreamPrioritization, IWMStreamPrioritization_Vtbl, 0x8c1c6090_f9a8_4748_8ec3_dd1108ba1e77);
 windows_core::imp::interface_hierarchy!(IWMStreamPrioritization, windows_core::IUnknown);
@@ -8579,6 +13001,30 @@ impl IWMStreamPrioritization_Vtbl {
         iid == &<IWMStreamPrioritization as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMStreamPrioritization_Impl> windows_core::imp::TearOffVtable<Identity> for IWMStreamPrioritization_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPriorityRecords<Identity: windows_core::IUnknownImpl + IWMStreamPrioritization_Impl>(this: *mut core::ffi::c_void, precordarray: *mut WM_STREAM_PRIORITY_RECORD, pcrecords: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMStreamPrioritization_Impl::GetPriorityRecords(this, core::mem::transmute_copy(&precordarray), core::mem::transmute_copy(&pcrecords)).into()
+            }
+        }
+        unsafe extern "system" fn SetPriorityRecords<Identity: windows_core::IUnknownImpl + IWMStreamPrioritization_Impl>(this: *mut core::ffi::c_void, precordarray: *const WM_STREAM_PRIORITY_RECORD, crecords: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMStreamPrioritization_Impl::SetPriorityRecords(this, core::mem::transmute_copy(&precordarray), core::mem::transmute_copy(&crecords)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPriorityRecords: GetPriorityRecords::<Identity>,
+            SetPriorityRecords: SetPriorityRecords::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMStreamPrioritization {}
 windows_core::imp::define_interface!(IWMSyncReader, IWMSyncReader_Vtbl, 0x9397f121_7705_4dc9_b049_98b698188414);
 windows_core::imp::interface_hierarchy!(IWMSyncReader, windows_core::IUnknown);
@@ -8747,47 +13193,268 @@ pub trait IWMSyncReader_Impl: windows_core::IUnknownImpl {
     fn OpenStream(&self, pstream: windows_core::Ref<super::super::System::Com::IStream>) -> windows_core::Result<()>;
 }
 #[cfg(feature = "Win32_System_Com")]
-impl IWMSyncReader_Vtbl {
-    pub const fn new<Identity: IWMSyncReader_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn Open<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pwszfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+impl IWMSyncReader_Vtbl {
+    pub const fn new<Identity: IWMSyncReader_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn Open<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pwszfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMSyncReader_Impl::Open(this, core::mem::transmute(&pwszfilename)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMSyncReader_Impl::Close(this).into()
+            }
+        }
+        unsafe extern "system" fn SetRange<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, cnsstarttime: u64, cnsduration: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMSyncReader_Impl::SetRange(this, core::mem::transmute_copy(&cnsstarttime), core::mem::transmute_copy(&cnsduration)).into()
+            }
+        }
+        unsafe extern "system" fn SetRangeByFrame<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, wstreamnum: u16, qwframenumber: u64, cframestoread: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMSyncReader_Impl::SetRangeByFrame(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&qwframenumber), core::mem::transmute_copy(&cframestoread)).into()
+            }
+        }
+        unsafe extern "system" fn GetNextSample<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, wstreamnum: u16, ppsample: *mut *mut core::ffi::c_void, pcnssampletime: *mut u64, pcnsduration: *mut u64, pdwflags: *mut u32, pdwoutputnum: *mut u32, pwstreamnum: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMSyncReader_Impl::GetNextSample(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&ppsample), core::mem::transmute_copy(&pcnssampletime), core::mem::transmute_copy(&pcnsduration), core::mem::transmute_copy(&pdwflags), core::mem::transmute_copy(&pdwoutputnum), core::mem::transmute_copy(&pwstreamnum)).into()
+            }
+        }
+        unsafe extern "system" fn SetStreamsSelected<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, cstreamcount: u16, pwstreamnumbers: *const u16, pselections: *const WMT_STREAM_SELECTION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMSyncReader_Impl::SetStreamsSelected(this, core::mem::transmute_copy(&cstreamcount), core::mem::transmute_copy(&pwstreamnumbers), core::mem::transmute_copy(&pselections)).into()
+            }
+        }
+        unsafe extern "system" fn GetStreamSelected<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, wstreamnum: u16, pselection: *mut WMT_STREAM_SELECTION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWMSyncReader_Impl::GetStreamSelected(this, core::mem::transmute_copy(&wstreamnum)) {
+                    Ok(ok__) => {
+                        pselection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetReadStreamSamples<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, wstreamnum: u16, fcompressed: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMSyncReader_Impl::SetReadStreamSamples(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&fcompressed)).into()
+            }
+        }
+        unsafe extern "system" fn GetReadStreamSamples<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, wstreamnum: u16, pfcompressed: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWMSyncReader_Impl::GetReadStreamSamples(this, core::mem::transmute_copy(&wstreamnum)) {
+                    Ok(ok__) => {
+                        pfcompressed.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOutputSetting<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwoutputnum: u32, pszname: windows_core::PCWSTR, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pcblength: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMSyncReader_Impl::GetOutputSetting(this, core::mem::transmute_copy(&dwoutputnum), core::mem::transmute(&pszname), core::mem::transmute_copy(&ptype), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&pcblength)).into()
+            }
+        }
+        unsafe extern "system" fn SetOutputSetting<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwoutputnum: u32, pszname: windows_core::PCWSTR, r#type: WMT_ATTR_DATATYPE, pvalue: *const u8, cblength: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMSyncReader_Impl::SetOutputSetting(this, core::mem::transmute_copy(&dwoutputnum), core::mem::transmute(&pszname), core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&cblength)).into()
+            }
+        }
+        unsafe extern "system" fn GetOutputCount<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pcoutputs: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWMSyncReader_Impl::GetOutputCount(this) {
+                    Ok(ok__) => {
+                        pcoutputs.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOutputProps<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwoutputnum: u32, ppoutput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWMSyncReader_Impl::GetOutputProps(this, core::mem::transmute_copy(&dwoutputnum)) {
+                    Ok(ok__) => {
+                        ppoutput.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetOutputProps<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwoutputnum: u32, poutput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMSyncReader_Impl::SetOutputProps(this, core::mem::transmute_copy(&dwoutputnum), core::mem::transmute_copy(&poutput)).into()
+            }
+        }
+        unsafe extern "system" fn GetOutputFormatCount<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwoutputnum: u32, pcformats: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWMSyncReader_Impl::GetOutputFormatCount(this, core::mem::transmute_copy(&dwoutputnum)) {
+                    Ok(ok__) => {
+                        pcformats.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOutputFormat<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwoutputnum: u32, dwformatnum: u32, ppprops: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWMSyncReader_Impl::GetOutputFormat(this, core::mem::transmute_copy(&dwoutputnum), core::mem::transmute_copy(&dwformatnum)) {
+                    Ok(ok__) => {
+                        ppprops.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOutputNumberForStream<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, wstreamnum: u16, pdwoutputnum: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWMSyncReader_Impl::GetOutputNumberForStream(this, core::mem::transmute_copy(&wstreamnum)) {
+                    Ok(ok__) => {
+                        pdwoutputnum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStreamNumberForOutput<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwoutputnum: u32, pwstreamnum: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWMSyncReader_Impl::GetStreamNumberForOutput(this, core::mem::transmute_copy(&dwoutputnum)) {
+                    Ok(ok__) => {
+                        pwstreamnum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMaxOutputSampleSize<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwoutput: u32, pcbmax: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWMSyncReader_Impl::GetMaxOutputSampleSize(this, core::mem::transmute_copy(&dwoutput)) {
+                    Ok(ok__) => {
+                        pcbmax.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMaxStreamSampleSize<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, wstream: u16, pcbmax: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWMSyncReader_Impl::GetMaxStreamSampleSize(this, core::mem::transmute_copy(&wstream)) {
+                    Ok(ok__) => {
+                        pcbmax.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OpenStream<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMSyncReader_Impl::OpenStream(this, core::mem::transmute_copy(&pstream)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            Open: Open::<Identity, OFFSET>,
+            Close: Close::<Identity, OFFSET>,
+            SetRange: SetRange::<Identity, OFFSET>,
+            SetRangeByFrame: SetRangeByFrame::<Identity, OFFSET>,
+            GetNextSample: GetNextSample::<Identity, OFFSET>,
+            SetStreamsSelected: SetStreamsSelected::<Identity, OFFSET>,
+            GetStreamSelected: GetStreamSelected::<Identity, OFFSET>,
+            SetReadStreamSamples: SetReadStreamSamples::<Identity, OFFSET>,
+            GetReadStreamSamples: GetReadStreamSamples::<Identity, OFFSET>,
+            GetOutputSetting: GetOutputSetting::<Identity, OFFSET>,
+            SetOutputSetting: SetOutputSetting::<Identity, OFFSET>,
+            GetOutputCount: GetOutputCount::<Identity, OFFSET>,
+            GetOutputProps: GetOutputProps::<Identity, OFFSET>,
+            SetOutputProps: SetOutputProps::<Identity, OFFSET>,
+            GetOutputFormatCount: GetOutputFormatCount::<Identity, OFFSET>,
+            GetOutputFormat: GetOutputFormat::<Identity, OFFSET>,
+            GetOutputNumberForStream: GetOutputNumberForStream::<Identity, OFFSET>,
+            GetStreamNumberForOutput: GetStreamNumberForOutput::<Identity, OFFSET>,
+            GetMaxOutputSampleSize: GetMaxOutputSampleSize::<Identity, OFFSET>,
+            GetMaxStreamSampleSize: GetMaxStreamSampleSize::<Identity, OFFSET>,
+            OpenStream: OpenStream::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMSyncReader as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IWMSyncReader_Impl> windows_core::imp::TearOffVtable<Identity> for IWMSyncReader_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Open<Identity: windows_core::IUnknownImpl + IWMSyncReader_Impl>(this: *mut core::ffi::c_void, pwszfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMSyncReader_Impl::Open(this, core::mem::transmute(&pwszfilename)).into()
             }
         }
-        unsafe extern "system" fn Close<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IWMSyncReader_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMSyncReader_Impl::Close(this).into()
             }
         }
-        unsafe extern "system" fn SetRange<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, cnsstarttime: u64, cnsduration: i64) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetRange<Identity: windows_core::IUnknownImpl + IWMSyncReader_Impl>(this: *mut core::ffi::c_void, cnsstarttime: u64, cnsduration: i64) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMSyncReader_Impl::SetRange(this, core::mem::transmute_copy(&cnsstarttime), core::mem::transmute_copy(&cnsduration)).into()
             }
         }
-        unsafe extern "system" fn SetRangeByFrame<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, wstreamnum: u16, qwframenumber: u64, cframestoread: i64) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetRangeByFrame<Identity: windows_core::IUnknownImpl + IWMSyncReader_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, qwframenumber: u64, cframestoread: i64) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMSyncReader_Impl::SetRangeByFrame(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&qwframenumber), core::mem::transmute_copy(&cframestoread)).into()
             }
         }
-        unsafe extern "system" fn GetNextSample<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, wstreamnum: u16, ppsample: *mut *mut core::ffi::c_void, pcnssampletime: *mut u64, pcnsduration: *mut u64, pdwflags: *mut u32, pdwoutputnum: *mut u32, pwstreamnum: *mut u16) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetNextSample<Identity: windows_core::IUnknownImpl + IWMSyncReader_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, ppsample: *mut *mut core::ffi::c_void, pcnssampletime: *mut u64, pcnsduration: *mut u64, pdwflags: *mut u32, pdwoutputnum: *mut u32, pwstreamnum: *mut u16) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMSyncReader_Impl::GetNextSample(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&ppsample), core::mem::transmute_copy(&pcnssampletime), core::mem::transmute_copy(&pcnsduration), core::mem::transmute_copy(&pdwflags), core::mem::transmute_copy(&pdwoutputnum), core::mem::transmute_copy(&pwstreamnum)).into()
             }
         }
-        unsafe extern "system" fn SetStreamsSelected<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, cstreamcount: u16, pwstreamnumbers: *const u16, pselections: *const WMT_STREAM_SELECTION) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetStreamsSelected<Identity: windows_core::IUnknownImpl + IWMSyncReader_Impl>(this: *mut core::ffi::c_void, cstreamcount: u16, pwstreamnumbers: *const u16, pselections: *const WMT_STREAM_SELECTION) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMSyncReader_Impl::SetStreamsSelected(this, core::mem::transmute_copy(&cstreamcount), core::mem::transmute_copy(&pwstreamnumbers), core::mem::transmute_copy(&pselections)).into()
             }
         }
-        unsafe extern "system" fn GetStreamSelected<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, wstreamnum: u16, pselection: *mut WMT_STREAM_SELECTION) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetStreamSelected<Identity: windows_core::IUnknownImpl + IWMSyncReader_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, pselection: *mut WMT_STREAM_SELECTION) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWMSyncReader_Impl::GetStreamSelected(this, core::mem::transmute_copy(&wstreamnum)) {
                     Ok(ok__) => {
                         pselection.write(core::mem::transmute(ok__));
@@ -8797,15 +13464,15 @@ impl IWMSyncReader_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetReadStreamSamples<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, wstreamnum: u16, fcompressed: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetReadStreamSamples<Identity: windows_core::IUnknownImpl + IWMSyncReader_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, fcompressed: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMSyncReader_Impl::SetReadStreamSamples(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&fcompressed)).into()
             }
         }
-        unsafe extern "system" fn GetReadStreamSamples<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, wstreamnum: u16, pfcompressed: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetReadStreamSamples<Identity: windows_core::IUnknownImpl + IWMSyncReader_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, pfcompressed: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWMSyncReader_Impl::GetReadStreamSamples(this, core::mem::transmute_copy(&wstreamnum)) {
                     Ok(ok__) => {
                         pfcompressed.write(core::mem::transmute(ok__));
@@ -8815,21 +13482,21 @@ impl IWMSyncReader_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetOutputSetting<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwoutputnum: u32, pszname: windows_core::PCWSTR, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pcblength: *mut u16) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetOutputSetting<Identity: windows_core::IUnknownImpl + IWMSyncReader_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, pszname: windows_core::PCWSTR, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pcblength: *mut u16) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMSyncReader_Impl::GetOutputSetting(this, core::mem::transmute_copy(&dwoutputnum), core::mem::transmute(&pszname), core::mem::transmute_copy(&ptype), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&pcblength)).into()
             }
         }
-        unsafe extern "system" fn SetOutputSetting<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwoutputnum: u32, pszname: windows_core::PCWSTR, r#type: WMT_ATTR_DATATYPE, pvalue: *const u8, cblength: u16) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetOutputSetting<Identity: windows_core::IUnknownImpl + IWMSyncReader_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, pszname: windows_core::PCWSTR, r#type: WMT_ATTR_DATATYPE, pvalue: *const u8, cblength: u16) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMSyncReader_Impl::SetOutputSetting(this, core::mem::transmute_copy(&dwoutputnum), core::mem::transmute(&pszname), core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&cblength)).into()
             }
         }
-        unsafe extern "system" fn GetOutputCount<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pcoutputs: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetOutputCount<Identity: windows_core::IUnknownImpl + IWMSyncReader_Impl>(this: *mut core::ffi::c_void, pcoutputs: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWMSyncReader_Impl::GetOutputCount(this) {
                     Ok(ok__) => {
                         pcoutputs.write(core::mem::transmute(ok__));
@@ -8839,9 +13506,9 @@ impl IWMSyncReader_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetOutputProps<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwoutputnum: u32, ppoutput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetOutputProps<Identity: windows_core::IUnknownImpl + IWMSyncReader_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, ppoutput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWMSyncReader_Impl::GetOutputProps(this, core::mem::transmute_copy(&dwoutputnum)) {
                     Ok(ok__) => {
                         ppoutput.write(core::mem::transmute(ok__));
@@ -8851,15 +13518,15 @@ impl IWMSyncReader_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetOutputProps<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwoutputnum: u32, poutput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetOutputProps<Identity: windows_core::IUnknownImpl + IWMSyncReader_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, poutput: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMSyncReader_Impl::SetOutputProps(this, core::mem::transmute_copy(&dwoutputnum), core::mem::transmute_copy(&poutput)).into()
             }
         }
-        unsafe extern "system" fn GetOutputFormatCount<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwoutputnum: u32, pcformats: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetOutputFormatCount<Identity: windows_core::IUnknownImpl + IWMSyncReader_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, pcformats: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWMSyncReader_Impl::GetOutputFormatCount(this, core::mem::transmute_copy(&dwoutputnum)) {
                     Ok(ok__) => {
                         pcformats.write(core::mem::transmute(ok__));
@@ -8869,9 +13536,9 @@ impl IWMSyncReader_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetOutputFormat<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwoutputnum: u32, dwformatnum: u32, ppprops: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetOutputFormat<Identity: windows_core::IUnknownImpl + IWMSyncReader_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, dwformatnum: u32, ppprops: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWMSyncReader_Impl::GetOutputFormat(this, core::mem::transmute_copy(&dwoutputnum), core::mem::transmute_copy(&dwformatnum)) {
                     Ok(ok__) => {
                         ppprops.write(core::mem::transmute(ok__));
@@ -8881,9 +13548,9 @@ impl IWMSyncReader_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetOutputNumberForStream<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, wstreamnum: u16, pdwoutputnum: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetOutputNumberForStream<Identity: windows_core::IUnknownImpl + IWMSyncReader_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, pdwoutputnum: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWMSyncReader_Impl::GetOutputNumberForStream(this, core::mem::transmute_copy(&wstreamnum)) {
                     Ok(ok__) => {
                         pdwoutputnum.write(core::mem::transmute(ok__));
@@ -8893,9 +13560,9 @@ impl IWMSyncReader_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetStreamNumberForOutput<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwoutputnum: u32, pwstreamnum: *mut u16) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetStreamNumberForOutput<Identity: windows_core::IUnknownImpl + IWMSyncReader_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, pwstreamnum: *mut u16) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWMSyncReader_Impl::GetStreamNumberForOutput(this, core::mem::transmute_copy(&dwoutputnum)) {
                     Ok(ok__) => {
                         pwstreamnum.write(core::mem::transmute(ok__));
@@ -8905,9 +13572,9 @@ impl IWMSyncReader_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetMaxOutputSampleSize<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwoutput: u32, pcbmax: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetMaxOutputSampleSize<Identity: windows_core::IUnknownImpl + IWMSyncReader_Impl>(this: *mut core::ffi::c_void, dwoutput: u32, pcbmax: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWMSyncReader_Impl::GetMaxOutputSampleSize(this, core::mem::transmute_copy(&dwoutput)) {
                     Ok(ok__) => {
                         pcbmax.write(core::mem::transmute(ok__));
@@ -8917,9 +13584,9 @@ impl IWMSyncReader_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetMaxStreamSampleSize<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, wstream: u16, pcbmax: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetMaxStreamSampleSize<Identity: windows_core::IUnknownImpl + IWMSyncReader_Impl>(this: *mut core::ffi::c_void, wstream: u16, pcbmax: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWMSyncReader_Impl::GetMaxStreamSampleSize(this, core::mem::transmute_copy(&wstream)) {
                     Ok(ok__) => {
                         pcbmax.write(core::mem::transmute(ok__));
@@ -8929,40 +13596,37 @@ impl IWMSyncReader_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn OpenStream<Identity: IWMSyncReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn OpenStream<Identity: windows_core::IUnknownImpl + IWMSyncReader_Impl>(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMSyncReader_Impl::OpenStream(this, core::mem::transmute_copy(&pstream)).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            Open: Open::<Identity, OFFSET>,
-            Close: Close::<Identity, OFFSET>,
-            SetRange: SetRange::<Identity, OFFSET>,
-            SetRangeByFrame: SetRangeByFrame::<Identity, OFFSET>,
-            GetNextSample: GetNextSample::<Identity, OFFSET>,
-            SetStreamsSelected: SetStreamsSelected::<Identity, OFFSET>,
-            GetStreamSelected: GetStreamSelected::<Identity, OFFSET>,
-            SetReadStreamSamples: SetReadStreamSamples::<Identity, OFFSET>,
-            GetReadStreamSamples: GetReadStreamSamples::<Identity, OFFSET>,
-            GetOutputSetting: GetOutputSetting::<Identity, OFFSET>,
-            SetOutputSetting: SetOutputSetting::<Identity, OFFSET>,
-            GetOutputCount: GetOutputCount::<Identity, OFFSET>,
-            GetOutputProps: GetOutputProps::<Identity, OFFSET>,
-            SetOutputProps: SetOutputProps::<Identity, OFFSET>,
-            GetOutputFormatCount: GetOutputFormatCount::<Identity, OFFSET>,
-            GetOutputFormat: GetOutputFormat::<Identity, OFFSET>,
-            GetOutputNumberForStream: GetOutputNumberForStream::<Identity, OFFSET>,
-            GetStreamNumberForOutput: GetStreamNumberForOutput::<Identity, OFFSET>,
-            GetMaxOutputSampleSize: GetMaxOutputSampleSize::<Identity, OFFSET>,
-            GetMaxStreamSampleSize: GetMaxStreamSampleSize::<Identity, OFFSET>,
-            OpenStream: OpenStream::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWMSyncReader as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Open: Open::<Identity>,
+            Close: Close::<Identity>,
+            SetRange: SetRange::<Identity>,
+            SetRangeByFrame: SetRangeByFrame::<Identity>,
+            GetNextSample: GetNextSample::<Identity>,
+            SetStreamsSelected: SetStreamsSelected::<Identity>,
+            GetStreamSelected: GetStreamSelected::<Identity>,
+            SetReadStreamSamples: SetReadStreamSamples::<Identity>,
+            GetReadStreamSamples: GetReadStreamSamples::<Identity>,
+            GetOutputSetting: GetOutputSetting::<Identity>,
+            SetOutputSetting: SetOutputSetting::<Identity>,
+            GetOutputCount: GetOutputCount::<Identity>,
+            GetOutputProps: GetOutputProps::<Identity>,
+            SetOutputProps: SetOutputProps::<Identity>,
+            GetOutputFormatCount: GetOutputFormatCount::<Identity>,
+            GetOutputFormat: GetOutputFormat::<Identity>,
+            GetOutputNumberForStream: GetOutputNumberForStream::<Identity>,
+            GetStreamNumberForOutput: GetStreamNumberForOutput::<Identity>,
+            GetMaxOutputSampleSize: GetMaxOutputSampleSize::<Identity>,
+            GetMaxStreamSampleSize: GetMaxStreamSampleSize::<Identity>,
+            OpenStream: OpenStream::<Identity>,
+        }
+    };
 }
 #[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IWMSyncReader {}
@@ -9101,6 +13765,77 @@ impl IWMSyncReader2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IWMSyncReader2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMSyncReader2_Vtbl
+where
+    IWMSyncReader_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetRangeByTimecode<Identity: windows_core::IUnknownImpl + IWMSyncReader2_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, pstart: *const WMT_TIMECODE_EXTENSION_DATA, pend: *const WMT_TIMECODE_EXTENSION_DATA) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSyncReader2_Impl::SetRangeByTimecode(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&pstart), core::mem::transmute_copy(&pend)).into()
+            }
+        }
+        unsafe extern "system" fn SetRangeByFrameEx<Identity: windows_core::IUnknownImpl + IWMSyncReader2_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, qwframenumber: u64, cframestoread: i64, pcnsstarttime: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMSyncReader2_Impl::SetRangeByFrameEx(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&qwframenumber), core::mem::transmute_copy(&cframestoread)) {
+                    Ok(ok__) => {
+                        pcnsstarttime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAllocateForOutput<Identity: windows_core::IUnknownImpl + IWMSyncReader2_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, pallocator: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSyncReader2_Impl::SetAllocateForOutput(this, core::mem::transmute_copy(&dwoutputnum), core::mem::transmute_copy(&pallocator)).into()
+            }
+        }
+        unsafe extern "system" fn GetAllocateForOutput<Identity: windows_core::IUnknownImpl + IWMSyncReader2_Impl>(this: *mut core::ffi::c_void, dwoutputnum: u32, ppallocator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMSyncReader2_Impl::GetAllocateForOutput(this, core::mem::transmute_copy(&dwoutputnum)) {
+                    Ok(ok__) => {
+                        ppallocator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAllocateForStream<Identity: windows_core::IUnknownImpl + IWMSyncReader2_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, pallocator: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMSyncReader2_Impl::SetAllocateForStream(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&pallocator)).into()
+            }
+        }
+        unsafe extern "system" fn GetAllocateForStream<Identity: windows_core::IUnknownImpl + IWMSyncReader2_Impl>(this: *mut core::ffi::c_void, dwsreamnum: u16, ppallocator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMSyncReader2_Impl::GetAllocateForStream(this, core::mem::transmute_copy(&dwsreamnum)) {
+                    Ok(ok__) => {
+                        ppallocator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWMSyncReader_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetRangeByTimecode: SetRangeByTimecode::<Identity>,
+            SetRangeByFrameEx: SetRangeByFrameEx::<Identity>,
+            SetAllocateForOutput: SetAllocateForOutput::<Identity>,
+            GetAllocateForOutput: GetAllocateForOutput::<Identity>,
+            SetAllocateForStream: SetAllocateForStream::<Identity>,
+            GetAllocateForStream: GetAllocateForStream::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IWMSyncReader2 {}
 windows_core::imp::define_interface!(IWMVideoMediaProps, IWMVideoMediaProps_Vtbl, 0x96406bcf_2b2b_11d3_b36b_00c04f6108ff);
 impl core::ops::Deref for IWMVideoMediaProps {
@@ -9195,6 +13930,56 @@ impl IWMVideoMediaProps_Vtbl {
         iid == &<IWMVideoMediaProps as windows_core::Interface>::IID || iid == &<IWMMediaProps as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMVideoMediaProps_Impl> windows_core::imp::TearOffVtable<Identity> for IWMVideoMediaProps_Vtbl
+where
+    IWMMediaProps_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMaxKeyFrameSpacing<Identity: windows_core::IUnknownImpl + IWMVideoMediaProps_Impl>(this: *mut core::ffi::c_void, plltime: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMVideoMediaProps_Impl::GetMaxKeyFrameSpacing(this) {
+                    Ok(ok__) => {
+                        plltime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMaxKeyFrameSpacing<Identity: windows_core::IUnknownImpl + IWMVideoMediaProps_Impl>(this: *mut core::ffi::c_void, lltime: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMVideoMediaProps_Impl::SetMaxKeyFrameSpacing(this, core::mem::transmute_copy(&lltime)).into()
+            }
+        }
+        unsafe extern "system" fn GetQuality<Identity: windows_core::IUnknownImpl + IWMVideoMediaProps_Impl>(this: *mut core::ffi::c_void, pdwquality: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMVideoMediaProps_Impl::GetQuality(this) {
+                    Ok(ok__) => {
+                        pdwquality.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetQuality<Identity: windows_core::IUnknownImpl + IWMVideoMediaProps_Impl>(this: *mut core::ffi::c_void, dwquality: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMVideoMediaProps_Impl::SetQuality(this, core::mem::transmute_copy(&dwquality)).into()
+            }
+        }
+        Self {
+            base__: <IWMMediaProps_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetMaxKeyFrameSpacing: GetMaxKeyFrameSpacing::<Identity>,
+            SetMaxKeyFrameSpacing: SetMaxKeyFrameSpacing::<Identity>,
+            GetQuality: GetQuality::<Identity>,
+            SetQuality: SetQuality::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMVideoMediaProps {}
 windows_core::imp::define_interface!(IWMWatermarkInfo, IWMWatermarkInfo_Vtbl, 0x6f497062_f2e2_4624_8ea7_9dd40d81fc8d);
 windows_core::imp::interface_hierarchy!(IWMWatermarkInfo, windows_core::IUnknown);
@@ -9250,6 +14035,36 @@ impl IWMWatermarkInfo_Vtbl {
         iid == &<IWMWatermarkInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMWatermarkInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IWMWatermarkInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetWatermarkEntryCount<Identity: windows_core::IUnknownImpl + IWMWatermarkInfo_Impl>(this: *mut core::ffi::c_void, wmettype: WMT_WATERMARK_ENTRY_TYPE, pdwcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWatermarkInfo_Impl::GetWatermarkEntryCount(this, core::mem::transmute_copy(&wmettype)) {
+                    Ok(ok__) => {
+                        pdwcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetWatermarkEntry<Identity: windows_core::IUnknownImpl + IWMWatermarkInfo_Impl>(this: *mut core::ffi::c_void, wmettype: WMT_WATERMARK_ENTRY_TYPE, dwentrynum: u32, pentry: *mut WMT_WATERMARK_ENTRY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWatermarkInfo_Impl::GetWatermarkEntry(this, core::mem::transmute_copy(&wmettype), core::mem::transmute_copy(&dwentrynum), core::mem::transmute_copy(&pentry)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetWatermarkEntryCount: GetWatermarkEntryCount::<Identity>,
+            GetWatermarkEntry: GetWatermarkEntry::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMWatermarkInfo {}
 windows_core::imp::define_interface!(IWMWriter, IWMWriter_Vtbl, 0x96406bd4_2b2b_11d3_b36b_00c04f6108ff);
 windows_core::imp::interface_hierarchy!(IWMWriter, windows_core::IUnknown);
@@ -9480,10 +14295,141 @@ impl IWMWriter_Vtbl {
             WriteSample: WriteSample::<Identity, OFFSET>,
             Flush: Flush::<Identity, OFFSET>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWMWriter as windows_core::Interface>::IID
-    }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMWriter as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IWMWriter_Impl> windows_core::imp::TearOffVtable<Identity> for IWMWriter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetProfileByID<Identity: windows_core::IUnknownImpl + IWMWriter_Impl>(this: *mut core::ffi::c_void, guidprofile: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriter_Impl::SetProfileByID(this, core::mem::transmute_copy(&guidprofile)).into()
+            }
+        }
+        unsafe extern "system" fn SetProfile<Identity: windows_core::IUnknownImpl + IWMWriter_Impl>(this: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriter_Impl::SetProfile(this, core::mem::transmute_copy(&pprofile)).into()
+            }
+        }
+        unsafe extern "system" fn SetOutputFilename<Identity: windows_core::IUnknownImpl + IWMWriter_Impl>(this: *mut core::ffi::c_void, pwszfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriter_Impl::SetOutputFilename(this, core::mem::transmute(&pwszfilename)).into()
+            }
+        }
+        unsafe extern "system" fn GetInputCount<Identity: windows_core::IUnknownImpl + IWMWriter_Impl>(this: *mut core::ffi::c_void, pcinputs: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriter_Impl::GetInputCount(this) {
+                    Ok(ok__) => {
+                        pcinputs.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetInputProps<Identity: windows_core::IUnknownImpl + IWMWriter_Impl>(this: *mut core::ffi::c_void, dwinputnum: u32, ppinput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriter_Impl::GetInputProps(this, core::mem::transmute_copy(&dwinputnum)) {
+                    Ok(ok__) => {
+                        ppinput.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetInputProps<Identity: windows_core::IUnknownImpl + IWMWriter_Impl>(this: *mut core::ffi::c_void, dwinputnum: u32, pinput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriter_Impl::SetInputProps(this, core::mem::transmute_copy(&dwinputnum), core::mem::transmute_copy(&pinput)).into()
+            }
+        }
+        unsafe extern "system" fn GetInputFormatCount<Identity: windows_core::IUnknownImpl + IWMWriter_Impl>(this: *mut core::ffi::c_void, dwinputnumber: u32, pcformats: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriter_Impl::GetInputFormatCount(this, core::mem::transmute_copy(&dwinputnumber)) {
+                    Ok(ok__) => {
+                        pcformats.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetInputFormat<Identity: windows_core::IUnknownImpl + IWMWriter_Impl>(this: *mut core::ffi::c_void, dwinputnumber: u32, dwformatnumber: u32, pprops: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriter_Impl::GetInputFormat(this, core::mem::transmute_copy(&dwinputnumber), core::mem::transmute_copy(&dwformatnumber)) {
+                    Ok(ok__) => {
+                        pprops.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn BeginWriting<Identity: windows_core::IUnknownImpl + IWMWriter_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriter_Impl::BeginWriting(this).into()
+            }
+        }
+        unsafe extern "system" fn EndWriting<Identity: windows_core::IUnknownImpl + IWMWriter_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriter_Impl::EndWriting(this).into()
+            }
+        }
+        unsafe extern "system" fn AllocateSample<Identity: windows_core::IUnknownImpl + IWMWriter_Impl>(this: *mut core::ffi::c_void, dwsamplesize: u32, ppsample: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriter_Impl::AllocateSample(this, core::mem::transmute_copy(&dwsamplesize)) {
+                    Ok(ok__) => {
+                        ppsample.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn WriteSample<Identity: windows_core::IUnknownImpl + IWMWriter_Impl>(this: *mut core::ffi::c_void, dwinputnum: u32, cnssampletime: u64, dwflags: u32, psample: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriter_Impl::WriteSample(this, core::mem::transmute_copy(&dwinputnum), core::mem::transmute_copy(&cnssampletime), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&psample)).into()
+            }
+        }
+        unsafe extern "system" fn Flush<Identity: windows_core::IUnknownImpl + IWMWriter_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriter_Impl::Flush(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetProfileByID: SetProfileByID::<Identity>,
+            SetProfile: SetProfile::<Identity>,
+            SetOutputFilename: SetOutputFilename::<Identity>,
+            GetInputCount: GetInputCount::<Identity>,
+            GetInputProps: GetInputProps::<Identity>,
+            SetInputProps: SetInputProps::<Identity>,
+            GetInputFormatCount: GetInputFormatCount::<Identity>,
+            GetInputFormat: GetInputFormat::<Identity>,
+            BeginWriting: BeginWriting::<Identity>,
+            EndWriting: EndWriting::<Identity>,
+            AllocateSample: AllocateSample::<Identity>,
+            WriteSample: WriteSample::<Identity>,
+            Flush: Flush::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IWMWriter {}
 windows_core::imp::define_interface!(IWMWriterAdvanced, IWMWriterAdvanced_Vtbl, 0x96406be3_2b2b_11d3_b36b_00c04f6108ff);
@@ -9693,6 +14639,123 @@ impl IWMWriterAdvanced_Vtbl {
         iid == &<IWMWriterAdvanced as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMWriterAdvanced_Impl> windows_core::imp::TearOffVtable<Identity> for IWMWriterAdvanced_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSinkCount<Identity: windows_core::IUnknownImpl + IWMWriterAdvanced_Impl>(this: *mut core::ffi::c_void, pcsinks: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriterAdvanced_Impl::GetSinkCount(this) {
+                    Ok(ok__) => {
+                        pcsinks.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSink<Identity: windows_core::IUnknownImpl + IWMWriterAdvanced_Impl>(this: *mut core::ffi::c_void, dwsinknum: u32, ppsink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriterAdvanced_Impl::GetSink(this, core::mem::transmute_copy(&dwsinknum)) {
+                    Ok(ok__) => {
+                        ppsink.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddSink<Identity: windows_core::IUnknownImpl + IWMWriterAdvanced_Impl>(this: *mut core::ffi::c_void, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterAdvanced_Impl::AddSink(this, core::mem::transmute_copy(&psink)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveSink<Identity: windows_core::IUnknownImpl + IWMWriterAdvanced_Impl>(this: *mut core::ffi::c_void, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterAdvanced_Impl::RemoveSink(this, core::mem::transmute_copy(&psink)).into()
+            }
+        }
+        unsafe extern "system" fn WriteStreamSample<Identity: windows_core::IUnknownImpl + IWMWriterAdvanced_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, cnssampletime: u64, mssamplesendtime: u32, cnssampleduration: u64, dwflags: u32, psample: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterAdvanced_Impl::WriteStreamSample(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&cnssampletime), core::mem::transmute_copy(&mssamplesendtime), core::mem::transmute_copy(&cnssampleduration), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&psample)).into()
+            }
+        }
+        unsafe extern "system" fn SetLiveSource<Identity: windows_core::IUnknownImpl + IWMWriterAdvanced_Impl>(this: *mut core::ffi::c_void, fislivesource: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterAdvanced_Impl::SetLiveSource(this, core::mem::transmute_copy(&fislivesource)).into()
+            }
+        }
+        unsafe extern "system" fn IsRealTime<Identity: windows_core::IUnknownImpl + IWMWriterAdvanced_Impl>(this: *mut core::ffi::c_void, pfrealtime: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriterAdvanced_Impl::IsRealTime(this) {
+                    Ok(ok__) => {
+                        pfrealtime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetWriterTime<Identity: windows_core::IUnknownImpl + IWMWriterAdvanced_Impl>(this: *mut core::ffi::c_void, pcnscurrenttime: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriterAdvanced_Impl::GetWriterTime(this) {
+                    Ok(ok__) => {
+                        pcnscurrenttime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStatistics<Identity: windows_core::IUnknownImpl + IWMWriterAdvanced_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, pstats: *mut WM_WRITER_STATISTICS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterAdvanced_Impl::GetStatistics(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&pstats)).into()
+            }
+        }
+        unsafe extern "system" fn SetSyncTolerance<Identity: windows_core::IUnknownImpl + IWMWriterAdvanced_Impl>(this: *mut core::ffi::c_void, mswindow: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterAdvanced_Impl::SetSyncTolerance(this, core::mem::transmute_copy(&mswindow)).into()
+            }
+        }
+        unsafe extern "system" fn GetSyncTolerance<Identity: windows_core::IUnknownImpl + IWMWriterAdvanced_Impl>(this: *mut core::ffi::c_void, pmswindow: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriterAdvanced_Impl::GetSyncTolerance(this) {
+                    Ok(ok__) => {
+                        pmswindow.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSinkCount: GetSinkCount::<Identity>,
+            GetSink: GetSink::<Identity>,
+            AddSink: AddSink::<Identity>,
+            RemoveSink: RemoveSink::<Identity>,
+            WriteStreamSample: WriteStreamSample::<Identity>,
+            SetLiveSource: SetLiveSource::<Identity>,
+            IsRealTime: IsRealTime::<Identity>,
+            GetWriterTime: GetWriterTime::<Identity>,
+            GetStatistics: GetStatistics::<Identity>,
+            SetSyncTolerance: SetSyncTolerance::<Identity>,
+            GetSyncTolerance: GetSyncTolerance::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMWriterAdvanced {}
 windows_core::imp::define_interface!(IWMWriterAdvanced2, IWMWriterAdvanced2_Vtbl, 0x962dc1ec_c046_4db8_9cc7_26ceae500817);
 impl core::ops::Deref for IWMWriterAdvanced2 {
@@ -9751,6 +14814,30 @@ impl IWMWriterAdvanced2_Vtbl {
         iid == &<IWMWriterAdvanced2 as windows_core::Interface>::IID || iid == &<IWMWriterAdvanced as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMWriterAdvanced2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMWriterAdvanced2_Vtbl
+where
+    IWMWriterAdvanced_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetInputSetting<Identity: windows_core::IUnknownImpl + IWMWriterAdvanced2_Impl>(this: *mut core::ffi::c_void, dwinputnum: u32, pszname: windows_core::PCWSTR, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pcblength: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterAdvanced2_Impl::GetInputSetting(this, core::mem::transmute_copy(&dwinputnum), core::mem::transmute(&pszname), core::mem::transmute_copy(&ptype), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&pcblength)).into()
+            }
+        }
+        unsafe extern "system" fn SetInputSetting<Identity: windows_core::IUnknownImpl + IWMWriterAdvanced2_Impl>(this: *mut core::ffi::c_void, dwinputnum: u32, pszname: windows_core::PCWSTR, r#type: WMT_ATTR_DATATYPE, pvalue: *const u8, cblength: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterAdvanced2_Impl::SetInputSetting(this, core::mem::transmute_copy(&dwinputnum), core::mem::transmute(&pszname), core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pvalue), core::mem::transmute_copy(&cblength)).into()
+            }
+        }
+        Self {
+            base__: <IWMWriterAdvanced_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetInputSetting: GetInputSetting::<Identity>,
+            SetInputSetting: SetInputSetting::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMWriterAdvanced2 {}
 windows_core::imp::define_interface!(IWMWriterAdvanced3, IWMWriterAdvanced3_Vtbl, 0x2cd6492d_7c37_4e76_9d3b_59261183a22e);
 impl core::ops::Deref for IWMWriterAdvanced3 {
@@ -9803,6 +14890,30 @@ impl IWMWriterAdvanced3_Vtbl {
         iid == &<IWMWriterAdvanced3 as windows_core::Interface>::IID || iid == &<IWMWriterAdvanced as windows_core::Interface>::IID || iid == &<IWMWriterAdvanced2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMWriterAdvanced3_Impl> windows_core::imp::TearOffVtable<Identity> for IWMWriterAdvanced3_Vtbl
+where
+    IWMWriterAdvanced2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStatisticsEx<Identity: windows_core::IUnknownImpl + IWMWriterAdvanced3_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, pstats: *mut WM_WRITER_STATISTICS_EX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterAdvanced3_Impl::GetStatisticsEx(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&pstats)).into()
+            }
+        }
+        unsafe extern "system" fn SetNonBlocking<Identity: windows_core::IUnknownImpl + IWMWriterAdvanced3_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterAdvanced3_Impl::SetNonBlocking(this).into()
+            }
+        }
+        Self {
+            base__: <IWMWriterAdvanced2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStatisticsEx: GetStatisticsEx::<Identity>,
+            SetNonBlocking: SetNonBlocking::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMWriterAdvanced3 {}
 windows_core::imp::define_interface!(IWMWriterFileSink, IWMWriterFileSink_Vtbl, 0x96406be5_2b2b_11d3_b36b_00c04f6108ff);
 impl core::ops::Deref for IWMWriterFileSink {
@@ -9843,6 +14954,20 @@ impl IWMWriterFileSink_Vtbl {
         iid == &<IWMWriterFileSink as windows_core::Interface>::IID || iid == &<IWMWriterSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMWriterFileSink_Impl> windows_core::imp::TearOffVtable<Identity> for IWMWriterFileSink_Vtbl
+where
+    IWMWriterSink_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Open<Identity: windows_core::IUnknownImpl + IWMWriterFileSink_Impl>(this: *mut core::ffi::c_void, pwszfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterFileSink_Impl::Open(this, core::mem::transmute(&pwszfilename)).into()
+            }
+        }
+        Self { base__: <IWMWriterSink_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Open: Open::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWMWriterFileSink {}
 windows_core::imp::define_interface!(IWMWriterFileSink2, IWMWriterFileSink2_Vtbl, 0x14282ba7_4aef_4205_8ce5_c229035a05bc);
 impl core::ops::Deref for IWMWriterFileSink2 {
@@ -9991,6 +15116,89 @@ impl IWMWriterFileSink2_Vtbl {
         iid == &<IWMWriterFileSink2 as windows_core::Interface>::IID || iid == &<IWMWriterSink as windows_core::Interface>::IID || iid == &<IWMWriterFileSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMWriterFileSink2_Impl> windows_core::imp::TearOffVtable<Identity> for IWMWriterFileSink2_Vtbl
+where
+    IWMWriterFileSink_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Start<Identity: windows_core::IUnknownImpl + IWMWriterFileSink2_Impl>(this: *mut core::ffi::c_void, cnsstarttime: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterFileSink2_Impl::Start(this, core::mem::transmute_copy(&cnsstarttime)).into()
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + IWMWriterFileSink2_Impl>(this: *mut core::ffi::c_void, cnsstoptime: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterFileSink2_Impl::Stop(this, core::mem::transmute_copy(&cnsstoptime)).into()
+            }
+        }
+        unsafe extern "system" fn IsStopped<Identity: windows_core::IUnknownImpl + IWMWriterFileSink2_Impl>(this: *mut core::ffi::c_void, pfstopped: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriterFileSink2_Impl::IsStopped(this) {
+                    Ok(ok__) => {
+                        pfstopped.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFileDuration<Identity: windows_core::IUnknownImpl + IWMWriterFileSink2_Impl>(this: *mut core::ffi::c_void, pcnsduration: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriterFileSink2_Impl::GetFileDuration(this) {
+                    Ok(ok__) => {
+                        pcnsduration.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFileSize<Identity: windows_core::IUnknownImpl + IWMWriterFileSink2_Impl>(this: *mut core::ffi::c_void, pcbfile: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriterFileSink2_Impl::GetFileSize(this) {
+                    Ok(ok__) => {
+                        pcbfile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IWMWriterFileSink2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterFileSink2_Impl::Close(this).into()
+            }
+        }
+        unsafe extern "system" fn IsClosed<Identity: windows_core::IUnknownImpl + IWMWriterFileSink2_Impl>(this: *mut core::ffi::c_void, pfclosed: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriterFileSink2_Impl::IsClosed(this) {
+                    Ok(ok__) => {
+                        pfclosed.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWMWriterFileSink_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Start: Start::<Identity>,
+            Stop: Stop::<Identity>,
+            IsStopped: IsStopped::<Identity>,
+            GetFileDuration: GetFileDuration::<Identity>,
+            GetFileSize: GetFileSize::<Identity>,
+            Close: Close::<Identity>,
+            IsClosed: IsClosed::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMWriterFileSink2 {}
 windows_core::imp::define_interface!(IWMWriterFileSink3, IWMWriterFileSink3_Vtbl, 0x3fea4feb_2945_47a7_a1dd_c53a8fc4c45c);
 impl core::ops::Deref for IWMWriterFileSink3 {
@@ -10058,17 +15266,104 @@ pub trait IWMWriterFileSink3_Impl: IWMWriterFileSink2_Impl {
     fn GetUnbufferedIO(&self) -> windows_core::Result<windows_core::BOOL>;
     fn CompleteOperations(&self) -> windows_core::Result<()>;
 }
-impl IWMWriterFileSink3_Vtbl {
-    pub const fn new<Identity: IWMWriterFileSink3_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn SetAutoIndexing<Identity: IWMWriterFileSink3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fdoautoindexing: windows_core::BOOL) -> windows_core::HRESULT {
+impl IWMWriterFileSink3_Vtbl {
+    pub const fn new<Identity: IWMWriterFileSink3_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn SetAutoIndexing<Identity: IWMWriterFileSink3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fdoautoindexing: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMWriterFileSink3_Impl::SetAutoIndexing(this, core::mem::transmute_copy(&fdoautoindexing)).into()
+            }
+        }
+        unsafe extern "system" fn GetAutoIndexing<Identity: IWMWriterFileSink3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfautoindexing: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWMWriterFileSink3_Impl::GetAutoIndexing(this) {
+                    Ok(ok__) => {
+                        pfautoindexing.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetControlStream<Identity: IWMWriterFileSink3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, wstreamnumber: u16, fshouldcontrolstartandstop: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMWriterFileSink3_Impl::SetControlStream(this, core::mem::transmute_copy(&wstreamnumber), core::mem::transmute_copy(&fshouldcontrolstartandstop)).into()
+            }
+        }
+        unsafe extern "system" fn GetMode<Identity: IWMWriterFileSink3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwfilesinkmode: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWMWriterFileSink3_Impl::GetMode(this) {
+                    Ok(ok__) => {
+                        pdwfilesinkmode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OnDataUnitEx<Identity: IWMWriterFileSink3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfilesinkdataunit: *const WMT_FILESINK_DATA_UNIT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMWriterFileSink3_Impl::OnDataUnitEx(this, core::mem::transmute_copy(&pfilesinkdataunit)).into()
+            }
+        }
+        unsafe extern "system" fn SetUnbufferedIO<Identity: IWMWriterFileSink3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, funbufferedio: windows_core::BOOL, frestrictmemusage: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMWriterFileSink3_Impl::SetUnbufferedIO(this, core::mem::transmute_copy(&funbufferedio), core::mem::transmute_copy(&frestrictmemusage)).into()
+            }
+        }
+        unsafe extern "system" fn GetUnbufferedIO<Identity: IWMWriterFileSink3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfunbufferedio: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWMWriterFileSink3_Impl::GetUnbufferedIO(this) {
+                    Ok(ok__) => {
+                        pfunbufferedio.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CompleteOperations<Identity: IWMWriterFileSink3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWMWriterFileSink3_Impl::CompleteOperations(this).into()
+            }
+        }
+        Self {
+            base__: IWMWriterFileSink2_Vtbl::new::<Identity, OFFSET>(),
+            SetAutoIndexing: SetAutoIndexing::<Identity, OFFSET>,
+            GetAutoIndexing: GetAutoIndexing::<Identity, OFFSET>,
+            SetControlStream: SetControlStream::<Identity, OFFSET>,
+            GetMode: GetMode::<Identity, OFFSET>,
+            OnDataUnitEx: OnDataUnitEx::<Identity, OFFSET>,
+            SetUnbufferedIO: SetUnbufferedIO::<Identity, OFFSET>,
+            GetUnbufferedIO: GetUnbufferedIO::<Identity, OFFSET>,
+            CompleteOperations: CompleteOperations::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMWriterFileSink3 as windows_core::Interface>::IID || iid == &<IWMWriterSink as windows_core::Interface>::IID || iid == &<IWMWriterFileSink as windows_core::Interface>::IID || iid == &<IWMWriterFileSink2 as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IWMWriterFileSink3_Impl> windows_core::imp::TearOffVtable<Identity> for IWMWriterFileSink3_Vtbl
+where
+    IWMWriterFileSink2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAutoIndexing<Identity: windows_core::IUnknownImpl + IWMWriterFileSink3_Impl>(this: *mut core::ffi::c_void, fdoautoindexing: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMWriterFileSink3_Impl::SetAutoIndexing(this, core::mem::transmute_copy(&fdoautoindexing)).into()
             }
         }
-        unsafe extern "system" fn GetAutoIndexing<Identity: IWMWriterFileSink3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfautoindexing: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetAutoIndexing<Identity: windows_core::IUnknownImpl + IWMWriterFileSink3_Impl>(this: *mut core::ffi::c_void, pfautoindexing: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWMWriterFileSink3_Impl::GetAutoIndexing(this) {
                     Ok(ok__) => {
                         pfautoindexing.write(core::mem::transmute(ok__));
@@ -10078,15 +15373,15 @@ impl IWMWriterFileSink3_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetControlStream<Identity: IWMWriterFileSink3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, wstreamnumber: u16, fshouldcontrolstartandstop: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetControlStream<Identity: windows_core::IUnknownImpl + IWMWriterFileSink3_Impl>(this: *mut core::ffi::c_void, wstreamnumber: u16, fshouldcontrolstartandstop: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMWriterFileSink3_Impl::SetControlStream(this, core::mem::transmute_copy(&wstreamnumber), core::mem::transmute_copy(&fshouldcontrolstartandstop)).into()
             }
         }
-        unsafe extern "system" fn GetMode<Identity: IWMWriterFileSink3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwfilesinkmode: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetMode<Identity: windows_core::IUnknownImpl + IWMWriterFileSink3_Impl>(this: *mut core::ffi::c_void, pdwfilesinkmode: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWMWriterFileSink3_Impl::GetMode(this) {
                     Ok(ok__) => {
                         pdwfilesinkmode.write(core::mem::transmute(ok__));
@@ -10096,21 +15391,21 @@ impl IWMWriterFileSink3_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn OnDataUnitEx<Identity: IWMWriterFileSink3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfilesinkdataunit: *const WMT_FILESINK_DATA_UNIT) -> windows_core::HRESULT {
+        unsafe extern "system" fn OnDataUnitEx<Identity: windows_core::IUnknownImpl + IWMWriterFileSink3_Impl>(this: *mut core::ffi::c_void, pfilesinkdataunit: *const WMT_FILESINK_DATA_UNIT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMWriterFileSink3_Impl::OnDataUnitEx(this, core::mem::transmute_copy(&pfilesinkdataunit)).into()
             }
         }
-        unsafe extern "system" fn SetUnbufferedIO<Identity: IWMWriterFileSink3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, funbufferedio: windows_core::BOOL, frestrictmemusage: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetUnbufferedIO<Identity: windows_core::IUnknownImpl + IWMWriterFileSink3_Impl>(this: *mut core::ffi::c_void, funbufferedio: windows_core::BOOL, frestrictmemusage: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMWriterFileSink3_Impl::SetUnbufferedIO(this, core::mem::transmute_copy(&funbufferedio), core::mem::transmute_copy(&frestrictmemusage)).into()
             }
         }
-        unsafe extern "system" fn GetUnbufferedIO<Identity: IWMWriterFileSink3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfunbufferedio: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetUnbufferedIO<Identity: windows_core::IUnknownImpl + IWMWriterFileSink3_Impl>(this: *mut core::ffi::c_void, pfunbufferedio: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWMWriterFileSink3_Impl::GetUnbufferedIO(this) {
                     Ok(ok__) => {
                         pfunbufferedio.write(core::mem::transmute(ok__));
@@ -10120,27 +15415,24 @@ impl IWMWriterFileSink3_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CompleteOperations<Identity: IWMWriterFileSink3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CompleteOperations<Identity: windows_core::IUnknownImpl + IWMWriterFileSink3_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWMWriterFileSink3_Impl::CompleteOperations(this).into()
             }
         }
         Self {
-            base__: IWMWriterFileSink2_Vtbl::new::<Identity, OFFSET>(),
-            SetAutoIndexing: SetAutoIndexing::<Identity, OFFSET>,
-            GetAutoIndexing: GetAutoIndexing::<Identity, OFFSET>,
-            SetControlStream: SetControlStream::<Identity, OFFSET>,
-            GetMode: GetMode::<Identity, OFFSET>,
-            OnDataUnitEx: OnDataUnitEx::<Identity, OFFSET>,
-            SetUnbufferedIO: SetUnbufferedIO::<Identity, OFFSET>,
-            GetUnbufferedIO: GetUnbufferedIO::<Identity, OFFSET>,
-            CompleteOperations: CompleteOperations::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWMWriterFileSink3 as windows_core::Interface>::IID || iid == &<IWMWriterSink as windows_core::Interface>::IID || iid == &<IWMWriterFileSink as windows_core::Interface>::IID || iid == &<IWMWriterFileSink2 as windows_core::Interface>::IID
-    }
+            base__: <IWMWriterFileSink2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAutoIndexing: SetAutoIndexing::<Identity>,
+            GetAutoIndexing: GetAutoIndexing::<Identity>,
+            SetControlStream: SetControlStream::<Identity>,
+            GetMode: GetMode::<Identity>,
+            OnDataUnitEx: OnDataUnitEx::<Identity>,
+            SetUnbufferedIO: SetUnbufferedIO::<Identity>,
+            GetUnbufferedIO: GetUnbufferedIO::<Identity>,
+            CompleteOperations: CompleteOperations::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IWMWriterFileSink3 {}
 windows_core::imp::define_interface!(IWMWriterNetworkSink, IWMWriterNetworkSink_Vtbl, 0x96406be7_2b2b_11d3_b36b_00c04f6108ff);
@@ -10284,6 +15576,84 @@ impl IWMWriterNetworkSink_Vtbl {
         iid == &<IWMWriterNetworkSink as windows_core::Interface>::IID || iid == &<IWMWriterSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMWriterNetworkSink_Impl> windows_core::imp::TearOffVtable<Identity> for IWMWriterNetworkSink_Vtbl
+where
+    IWMWriterSink_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetMaximumClients<Identity: windows_core::IUnknownImpl + IWMWriterNetworkSink_Impl>(this: *mut core::ffi::c_void, dwmaxclients: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterNetworkSink_Impl::SetMaximumClients(this, core::mem::transmute_copy(&dwmaxclients)).into()
+            }
+        }
+        unsafe extern "system" fn GetMaximumClients<Identity: windows_core::IUnknownImpl + IWMWriterNetworkSink_Impl>(this: *mut core::ffi::c_void, pdwmaxclients: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriterNetworkSink_Impl::GetMaximumClients(this) {
+                    Ok(ok__) => {
+                        pdwmaxclients.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNetworkProtocol<Identity: windows_core::IUnknownImpl + IWMWriterNetworkSink_Impl>(this: *mut core::ffi::c_void, protocol: WMT_NET_PROTOCOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterNetworkSink_Impl::SetNetworkProtocol(this, core::mem::transmute_copy(&protocol)).into()
+            }
+        }
+        unsafe extern "system" fn GetNetworkProtocol<Identity: windows_core::IUnknownImpl + IWMWriterNetworkSink_Impl>(this: *mut core::ffi::c_void, pprotocol: *mut WMT_NET_PROTOCOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriterNetworkSink_Impl::GetNetworkProtocol(this) {
+                    Ok(ok__) => {
+                        pprotocol.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHostURL<Identity: windows_core::IUnknownImpl + IWMWriterNetworkSink_Impl>(this: *mut core::ffi::c_void, pwszurl: windows_core::PWSTR, pcchurl: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterNetworkSink_Impl::GetHostURL(this, core::mem::transmute_copy(&pwszurl), core::mem::transmute_copy(&pcchurl)).into()
+            }
+        }
+        unsafe extern "system" fn Open<Identity: windows_core::IUnknownImpl + IWMWriterNetworkSink_Impl>(this: *mut core::ffi::c_void, pdwportnum: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterNetworkSink_Impl::Open(this, core::mem::transmute_copy(&pdwportnum)).into()
+            }
+        }
+        unsafe extern "system" fn Disconnect<Identity: windows_core::IUnknownImpl + IWMWriterNetworkSink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterNetworkSink_Impl::Disconnect(this).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IWMWriterNetworkSink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterNetworkSink_Impl::Close(this).into()
+            }
+        }
+        Self {
+            base__: <IWMWriterSink_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetMaximumClients: SetMaximumClients::<Identity>,
+            GetMaximumClients: GetMaximumClients::<Identity>,
+            SetNetworkProtocol: SetNetworkProtocol::<Identity>,
+            GetNetworkProtocol: GetNetworkProtocol::<Identity>,
+            GetHostURL: GetHostURL::<Identity>,
+            Open: Open::<Identity>,
+            Disconnect: Disconnect::<Identity>,
+            Close: Close::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMWriterNetworkSink {}
 windows_core::imp::define_interface!(IWMWriterPostView, IWMWriterPostView_Vtbl, 0x81e20ce4_75ef_491a_8004_fc53c45bdc3e);
 windows_core::imp::interface_hierarchy!(IWMWriterPostView, windows_core::IUnknown);
@@ -10465,6 +15835,109 @@ impl IWMWriterPostView_Vtbl {
         iid == &<IWMWriterPostView as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMWriterPostView_Impl> windows_core::imp::TearOffVtable<Identity> for IWMWriterPostView_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetPostViewCallback<Identity: windows_core::IUnknownImpl + IWMWriterPostView_Impl>(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, pvcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterPostView_Impl::SetPostViewCallback(this, core::mem::transmute_copy(&pcallback), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        unsafe extern "system" fn SetReceivePostViewSamples<Identity: windows_core::IUnknownImpl + IWMWriterPostView_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, freceivepostviewsamples: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterPostView_Impl::SetReceivePostViewSamples(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&freceivepostviewsamples)).into()
+            }
+        }
+        unsafe extern "system" fn GetReceivePostViewSamples<Identity: windows_core::IUnknownImpl + IWMWriterPostView_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, pfreceivepostviewsamples: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriterPostView_Impl::GetReceivePostViewSamples(this, core::mem::transmute_copy(&wstreamnum)) {
+                    Ok(ok__) => {
+                        pfreceivepostviewsamples.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPostViewProps<Identity: windows_core::IUnknownImpl + IWMWriterPostView_Impl>(this: *mut core::ffi::c_void, wstreamnumber: u16, ppoutput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriterPostView_Impl::GetPostViewProps(this, core::mem::transmute_copy(&wstreamnumber)) {
+                    Ok(ok__) => {
+                        ppoutput.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPostViewProps<Identity: windows_core::IUnknownImpl + IWMWriterPostView_Impl>(this: *mut core::ffi::c_void, wstreamnumber: u16, poutput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterPostView_Impl::SetPostViewProps(this, core::mem::transmute_copy(&wstreamnumber), core::mem::transmute_copy(&poutput)).into()
+            }
+        }
+        unsafe extern "system" fn GetPostViewFormatCount<Identity: windows_core::IUnknownImpl + IWMWriterPostView_Impl>(this: *mut core::ffi::c_void, wstreamnumber: u16, pcformats: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriterPostView_Impl::GetPostViewFormatCount(this, core::mem::transmute_copy(&wstreamnumber)) {
+                    Ok(ok__) => {
+                        pcformats.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPostViewFormat<Identity: windows_core::IUnknownImpl + IWMWriterPostView_Impl>(this: *mut core::ffi::c_void, wstreamnumber: u16, dwformatnumber: u32, ppprops: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriterPostView_Impl::GetPostViewFormat(this, core::mem::transmute_copy(&wstreamnumber), core::mem::transmute_copy(&dwformatnumber)) {
+                    Ok(ok__) => {
+                        ppprops.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAllocateForPostView<Identity: windows_core::IUnknownImpl + IWMWriterPostView_Impl>(this: *mut core::ffi::c_void, wstreamnumber: u16, fallocate: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterPostView_Impl::SetAllocateForPostView(this, core::mem::transmute_copy(&wstreamnumber), core::mem::transmute_copy(&fallocate)).into()
+            }
+        }
+        unsafe extern "system" fn GetAllocateForPostView<Identity: windows_core::IUnknownImpl + IWMWriterPostView_Impl>(this: *mut core::ffi::c_void, wstreamnumber: u16, pfallocate: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriterPostView_Impl::GetAllocateForPostView(this, core::mem::transmute_copy(&wstreamnumber)) {
+                    Ok(ok__) => {
+                        pfallocate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetPostViewCallback: SetPostViewCallback::<Identity>,
+            SetReceivePostViewSamples: SetReceivePostViewSamples::<Identity>,
+            GetReceivePostViewSamples: GetReceivePostViewSamples::<Identity>,
+            GetPostViewProps: GetPostViewProps::<Identity>,
+            SetPostViewProps: SetPostViewProps::<Identity>,
+            GetPostViewFormatCount: GetPostViewFormatCount::<Identity>,
+            GetPostViewFormat: GetPostViewFormat::<Identity>,
+            SetAllocateForPostView: SetAllocateForPostView::<Identity>,
+            GetAllocateForPostView: GetAllocateForPostView::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMWriterPostView {}
 windows_core::imp::define_interface!(IWMWriterPostViewCallback, IWMWriterPostViewCallback_Vtbl, 0xd9d6549d_a193_4f24_b308_03123d9b7f8d);
 impl core::ops::Deref for IWMWriterPostViewCallback {
@@ -10520,6 +15993,30 @@ impl IWMWriterPostViewCallback_Vtbl {
         iid == &<IWMWriterPostViewCallback as windows_core::Interface>::IID || iid == &<IWMStatusCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMWriterPostViewCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IWMWriterPostViewCallback_Vtbl
+where
+    IWMStatusCallback_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnPostViewSample<Identity: windows_core::IUnknownImpl + IWMWriterPostViewCallback_Impl>(this: *mut core::ffi::c_void, wstreamnumber: u16, cnssampletime: u64, cnssampleduration: u64, dwflags: u32, psample: *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterPostViewCallback_Impl::OnPostViewSample(this, core::mem::transmute_copy(&wstreamnumber), core::mem::transmute_copy(&cnssampletime), core::mem::transmute_copy(&cnssampleduration), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&psample), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        unsafe extern "system" fn AllocateForPostView<Identity: windows_core::IUnknownImpl + IWMWriterPostViewCallback_Impl>(this: *mut core::ffi::c_void, wstreamnum: u16, cbbuffer: u32, ppbuffer: *mut *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterPostViewCallback_Impl::AllocateForPostView(this, core::mem::transmute_copy(&wstreamnum), core::mem::transmute_copy(&cbbuffer), core::mem::transmute_copy(&ppbuffer), core::mem::transmute_copy(&pvcontext)).into()
+            }
+        }
+        Self {
+            base__: <IWMStatusCallback_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnPostViewSample: OnPostViewSample::<Identity>,
+            AllocateForPostView: AllocateForPostView::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMWriterPostViewCallback {}
 windows_core::imp::define_interface!(IWMWriterPreprocess, IWMWriterPreprocess_Vtbl, 0xfc54a285_38c4_45b5_aa23_85b9f7cb424b);
 windows_core::imp::interface_hierarchy!(IWMWriterPreprocess, windows_core::IUnknown);
@@ -10614,6 +16111,57 @@ impl IWMWriterPreprocess_Vtbl {
         iid == &<IWMWriterPreprocess as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMWriterPreprocess_Impl> windows_core::imp::TearOffVtable<Identity> for IWMWriterPreprocess_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMaxPreprocessingPasses<Identity: windows_core::IUnknownImpl + IWMWriterPreprocess_Impl>(this: *mut core::ffi::c_void, dwinputnum: u32, dwflags: u32, pdwmaxnumpasses: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriterPreprocess_Impl::GetMaxPreprocessingPasses(this, core::mem::transmute_copy(&dwinputnum), core::mem::transmute_copy(&dwflags)) {
+                    Ok(ok__) => {
+                        pdwmaxnumpasses.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNumPreprocessingPasses<Identity: windows_core::IUnknownImpl + IWMWriterPreprocess_Impl>(this: *mut core::ffi::c_void, dwinputnum: u32, dwflags: u32, dwnumpasses: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterPreprocess_Impl::SetNumPreprocessingPasses(this, core::mem::transmute_copy(&dwinputnum), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&dwnumpasses)).into()
+            }
+        }
+        unsafe extern "system" fn BeginPreprocessingPass<Identity: windows_core::IUnknownImpl + IWMWriterPreprocess_Impl>(this: *mut core::ffi::c_void, dwinputnum: u32, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterPreprocess_Impl::BeginPreprocessingPass(this, core::mem::transmute_copy(&dwinputnum), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn PreprocessSample<Identity: windows_core::IUnknownImpl + IWMWriterPreprocess_Impl>(this: *mut core::ffi::c_void, dwinputnum: u32, cnssampletime: u64, dwflags: u32, psample: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterPreprocess_Impl::PreprocessSample(this, core::mem::transmute_copy(&dwinputnum), core::mem::transmute_copy(&cnssampletime), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&psample)).into()
+            }
+        }
+        unsafe extern "system" fn EndPreprocessingPass<Identity: windows_core::IUnknownImpl + IWMWriterPreprocess_Impl>(this: *mut core::ffi::c_void, dwinputnum: u32, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterPreprocess_Impl::EndPreprocessingPass(this, core::mem::transmute_copy(&dwinputnum), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetMaxPreprocessingPasses: GetMaxPreprocessingPasses::<Identity>,
+            SetNumPreprocessingPasses: SetNumPreprocessingPasses::<Identity>,
+            BeginPreprocessingPass: BeginPreprocessingPass::<Identity>,
+            PreprocessSample: PreprocessSample::<Identity>,
+            EndPreprocessingPass: EndPreprocessingPass::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMWriterPreprocess {}
 windows_core::imp::define_interface!(IWMWriterPushSink, IWMWriterPushSink_Vtbl, 0xdc10e6a5_072c_467d_bf57_6330a9dde12a);
 impl core::ops::Deref for IWMWriterPushSink {
@@ -10682,6 +16230,37 @@ impl IWMWriterPushSink_Vtbl {
         iid == &<IWMWriterPushSink as windows_core::Interface>::IID || iid == &<IWMWriterSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMWriterPushSink_Impl> windows_core::imp::TearOffVtable<Identity> for IWMWriterPushSink_Vtbl
+where
+    IWMWriterSink_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Connect<Identity: windows_core::IUnknownImpl + IWMWriterPushSink_Impl>(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, pwsztemplateurl: windows_core::PCWSTR, fautodestroy: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterPushSink_Impl::Connect(this, core::mem::transmute(&pwszurl), core::mem::transmute(&pwsztemplateurl), core::mem::transmute_copy(&fautodestroy)).into()
+            }
+        }
+        unsafe extern "system" fn Disconnect<Identity: windows_core::IUnknownImpl + IWMWriterPushSink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterPushSink_Impl::Disconnect(this).into()
+            }
+        }
+        unsafe extern "system" fn EndSession<Identity: windows_core::IUnknownImpl + IWMWriterPushSink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterPushSink_Impl::EndSession(this).into()
+            }
+        }
+        Self {
+            base__: <IWMWriterSink_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Connect: Connect::<Identity>,
+            Disconnect: Disconnect::<Identity>,
+            EndSession: EndSession::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMWriterPushSink {}
 windows_core::imp::define_interface!(IWMWriterSink, IWMWriterSink_Vtbl, 0x96406be4_2b2b_11d3_b36b_00c04f6108ff);
 windows_core::imp::interface_hierarchy!(IWMWriterSink, windows_core::IUnknown);
@@ -10788,6 +16367,63 @@ impl IWMWriterSink_Vtbl {
         iid == &<IWMWriterSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWMWriterSink_Impl> windows_core::imp::TearOffVtable<Identity> for IWMWriterSink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnHeader<Identity: windows_core::IUnknownImpl + IWMWriterSink_Impl>(this: *mut core::ffi::c_void, pheader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterSink_Impl::OnHeader(this, core::mem::transmute_copy(&pheader)).into()
+            }
+        }
+        unsafe extern "system" fn IsRealTime<Identity: windows_core::IUnknownImpl + IWMWriterSink_Impl>(this: *mut core::ffi::c_void, pfrealtime: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriterSink_Impl::IsRealTime(this) {
+                    Ok(ok__) => {
+                        pfrealtime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AllocateDataUnit<Identity: windows_core::IUnknownImpl + IWMWriterSink_Impl>(this: *mut core::ffi::c_void, cbdataunit: u32, ppdataunit: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWMWriterSink_Impl::AllocateDataUnit(this, core::mem::transmute_copy(&cbdataunit)) {
+                    Ok(ok__) => {
+                        ppdataunit.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OnDataUnit<Identity: windows_core::IUnknownImpl + IWMWriterSink_Impl>(this: *mut core::ffi::c_void, pdataunit: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterSink_Impl::OnDataUnit(this, core::mem::transmute_copy(&pdataunit)).into()
+            }
+        }
+        unsafe extern "system" fn OnEndWriting<Identity: windows_core::IUnknownImpl + IWMWriterSink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWMWriterSink_Impl::OnEndWriting(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnHeader: OnHeader::<Identity>,
+            IsRealTime: IsRealTime::<Identity>,
+            AllocateDataUnit: AllocateDataUnit::<Identity>,
+            OnDataUnit: OnDataUnit::<Identity>,
+            OnEndWriting: OnEndWriting::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWMWriterSink {}
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
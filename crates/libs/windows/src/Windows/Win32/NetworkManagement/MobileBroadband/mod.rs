@@ -27,6 +27,13 @@ impl IDummyMBNUCMExt_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IDummyMBNUCMExt_Impl> windows_core::imp::TearOffVtable<Identity> for IDummyMBNUCMExt_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IDummyMBNUCMExt {}
 windows_core::imp::define_interface!(IMbnConnection, IMbnConnection_Vtbl, 0xdcbbbab6_200d_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnConnection, windows_core::IUnknown);
@@ -190,6 +197,101 @@ impl IMbnConnection_Vtbl {
         iid == &<IMbnConnection as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMbnConnection_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnConnection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ConnectionID<Identity: windows_core::IUnknownImpl + IMbnConnection_Impl>(this: *mut core::ffi::c_void, connectionid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnConnection_Impl::ConnectionID(this) {
+                    Ok(ok__) => {
+                        connectionid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InterfaceID<Identity: windows_core::IUnknownImpl + IMbnConnection_Impl>(this: *mut core::ffi::c_void, interfaceid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnConnection_Impl::InterfaceID(this) {
+                    Ok(ok__) => {
+                        interfaceid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Connect<Identity: windows_core::IUnknownImpl + IMbnConnection_Impl>(this: *mut core::ffi::c_void, connectionmode: MBN_CONNECTION_MODE, strprofile: windows_core::PCWSTR, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnConnection_Impl::Connect(this, core::mem::transmute_copy(&connectionmode), core::mem::transmute(&strprofile)) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Disconnect<Identity: windows_core::IUnknownImpl + IMbnConnection_Impl>(this: *mut core::ffi::c_void, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnConnection_Impl::Disconnect(this) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetConnectionState<Identity: windows_core::IUnknownImpl + IMbnConnection_Impl>(this: *mut core::ffi::c_void, connectionstate: *mut MBN_ACTIVATION_STATE, profilename: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnConnection_Impl::GetConnectionState(this, core::mem::transmute_copy(&connectionstate), core::mem::transmute_copy(&profilename)).into()
+            }
+        }
+        unsafe extern "system" fn GetVoiceCallState<Identity: windows_core::IUnknownImpl + IMbnConnection_Impl>(this: *mut core::ffi::c_void, voicecallstate: *mut MBN_VOICE_CALL_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnConnection_Impl::GetVoiceCallState(this) {
+                    Ok(ok__) => {
+                        voicecallstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetActivationNetworkError<Identity: windows_core::IUnknownImpl + IMbnConnection_Impl>(this: *mut core::ffi::c_void, networkerror: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnConnection_Impl::GetActivationNetworkError(this) {
+                    Ok(ok__) => {
+                        networkerror.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ConnectionID: ConnectionID::<Identity>,
+            InterfaceID: InterfaceID::<Identity>,
+            Connect: Connect::<Identity>,
+            Disconnect: Disconnect::<Identity>,
+            GetConnectionState: GetConnectionState::<Identity>,
+            GetVoiceCallState: GetVoiceCallState::<Identity>,
+            GetActivationNetworkError: GetActivationNetworkError::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMbnConnection {}
 windows_core::imp::define_interface!(IMbnConnectionContext, IMbnConnectionContext_Vtbl, 0xdcbbbab6_200b_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnConnectionContext, windows_core::IUnknown);
@@ -264,6 +366,43 @@ impl IMbnConnectionContext_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IMbnConnectionContext_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnConnectionContext_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProvisionedContexts<Identity: windows_core::IUnknownImpl + IMbnConnectionContext_Impl>(this: *mut core::ffi::c_void, provisionedcontexts: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnConnectionContext_Impl::GetProvisionedContexts(this) {
+                    Ok(ok__) => {
+                        provisionedcontexts.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProvisionedContext<Identity: windows_core::IUnknownImpl + IMbnConnectionContext_Impl>(this: *mut core::ffi::c_void, provisionedcontexts: MBN_CONTEXT, providerid: windows_core::PCWSTR, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnConnectionContext_Impl::SetProvisionedContext(this, core::mem::transmute(&provisionedcontexts), core::mem::transmute(&providerid)) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProvisionedContexts: GetProvisionedContexts::<Identity>,
+            SetProvisionedContext: SetProvisionedContext::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IMbnConnectionContext {}
 windows_core::imp::define_interface!(IMbnConnectionContextEvents, IMbnConnectionContextEvents_Vtbl, 0xdcbbbab6_200c_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnConnectionContextEvents, windows_core::IUnknown);
@@ -316,6 +455,30 @@ impl IMbnConnectionContextEvents_Vtbl {
         iid == &<IMbnConnectionContextEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMbnConnectionContextEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnConnectionContextEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnProvisionedContextListChange<Identity: windows_core::IUnknownImpl + IMbnConnectionContextEvents_Impl>(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnConnectionContextEvents_Impl::OnProvisionedContextListChange(this, core::mem::transmute_copy(&newinterface)).into()
+            }
+        }
+        unsafe extern "system" fn OnSetProvisionedContextComplete<Identity: windows_core::IUnknownImpl + IMbnConnectionContextEvents_Impl>(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnConnectionContextEvents_Impl::OnSetProvisionedContextComplete(this, core::mem::transmute_copy(&newinterface), core::mem::transmute_copy(&requestid), core::mem::transmute_copy(&status)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnProvisionedContextListChange: OnProvisionedContextListChange::<Identity>,
+            OnSetProvisionedContextComplete: OnSetProvisionedContextComplete::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMbnConnectionContextEvents {}
 windows_core::imp::define_interface!(IMbnConnectionEvents, IMbnConnectionEvents_Vtbl, 0xdcbbbab6_200e_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnConnectionEvents, windows_core::IUnknown);
@@ -398,6 +561,44 @@ impl IMbnConnectionEvents_Vtbl {
         iid == &<IMbnConnectionEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMbnConnectionEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnConnectionEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnConnectComplete<Identity: windows_core::IUnknownImpl + IMbnConnectionEvents_Impl>(this: *mut core::ffi::c_void, newconnection: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnConnectionEvents_Impl::OnConnectComplete(this, core::mem::transmute_copy(&newconnection), core::mem::transmute_copy(&requestid), core::mem::transmute_copy(&status)).into()
+            }
+        }
+        unsafe extern "system" fn OnDisconnectComplete<Identity: windows_core::IUnknownImpl + IMbnConnectionEvents_Impl>(this: *mut core::ffi::c_void, newconnection: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnConnectionEvents_Impl::OnDisconnectComplete(this, core::mem::transmute_copy(&newconnection), core::mem::transmute_copy(&requestid), core::mem::transmute_copy(&status)).into()
+            }
+        }
+        unsafe extern "system" fn OnConnectStateChange<Identity: windows_core::IUnknownImpl + IMbnConnectionEvents_Impl>(this: *mut core::ffi::c_void, newconnection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnConnectionEvents_Impl::OnConnectStateChange(this, core::mem::transmute_copy(&newconnection)).into()
+            }
+        }
+        unsafe extern "system" fn OnVoiceCallStateChange<Identity: windows_core::IUnknownImpl + IMbnConnectionEvents_Impl>(this: *mut core::ffi::c_void, newconnection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnConnectionEvents_Impl::OnVoiceCallStateChange(this, core::mem::transmute_copy(&newconnection)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnConnectComplete: OnConnectComplete::<Identity>,
+            OnDisconnectComplete: OnDisconnectComplete::<Identity>,
+            OnConnectStateChange: OnConnectStateChange::<Identity>,
+            OnVoiceCallStateChange: OnVoiceCallStateChange::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMbnConnectionEvents {}
 windows_core::imp::define_interface!(IMbnConnectionManager, IMbnConnectionManager_Vtbl, 0xdcbbbab6_201d_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnConnectionManager, windows_core::IUnknown);
@@ -472,6 +673,43 @@ impl IMbnConnectionManager_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IMbnConnectionManager_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnConnectionManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetConnection<Identity: windows_core::IUnknownImpl + IMbnConnectionManager_Impl>(this: *mut core::ffi::c_void, connectionid: windows_core::PCWSTR, mbnconnection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnConnectionManager_Impl::GetConnection(this, core::mem::transmute(&connectionid)) {
+                    Ok(ok__) => {
+                        mbnconnection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetConnections<Identity: windows_core::IUnknownImpl + IMbnConnectionManager_Impl>(this: *mut core::ffi::c_void, mbnconnections: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnConnectionManager_Impl::GetConnections(this) {
+                    Ok(ok__) => {
+                        mbnconnections.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetConnection: GetConnection::<Identity>,
+            GetConnections: GetConnections::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IMbnConnectionManager {}
 windows_core::imp::define_interface!(IMbnConnectionManagerEvents, IMbnConnectionManagerEvents_Vtbl, 0xdcbbbab6_201e_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnConnectionManagerEvents, windows_core::IUnknown);
@@ -524,6 +762,30 @@ impl IMbnConnectionManagerEvents_Vtbl {
         iid == &<IMbnConnectionManagerEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMbnConnectionManagerEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnConnectionManagerEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnConnectionArrival<Identity: windows_core::IUnknownImpl + IMbnConnectionManagerEvents_Impl>(this: *mut core::ffi::c_void, newconnection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnConnectionManagerEvents_Impl::OnConnectionArrival(this, core::mem::transmute_copy(&newconnection)).into()
+            }
+        }
+        unsafe extern "system" fn OnConnectionRemoval<Identity: windows_core::IUnknownImpl + IMbnConnectionManagerEvents_Impl>(this: *mut core::ffi::c_void, oldconnection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnConnectionManagerEvents_Impl::OnConnectionRemoval(this, core::mem::transmute_copy(&oldconnection)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnConnectionArrival: OnConnectionArrival::<Identity>,
+            OnConnectionRemoval: OnConnectionRemoval::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMbnConnectionManagerEvents {}
 windows_core::imp::define_interface!(IMbnConnectionProfile, IMbnConnectionProfile_Vtbl, 0xdcbbbab6_2010_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnConnectionProfile, windows_core::IUnknown);
@@ -594,6 +856,43 @@ impl IMbnConnectionProfile_Vtbl {
         iid == &<IMbnConnectionProfile as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMbnConnectionProfile_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnConnectionProfile_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProfileXmlData<Identity: windows_core::IUnknownImpl + IMbnConnectionProfile_Impl>(this: *mut core::ffi::c_void, profiledata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnConnectionProfile_Impl::GetProfileXmlData(this) {
+                    Ok(ok__) => {
+                        profiledata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UpdateProfile<Identity: windows_core::IUnknownImpl + IMbnConnectionProfile_Impl>(this: *mut core::ffi::c_void, strprofile: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnConnectionProfile_Impl::UpdateProfile(this, core::mem::transmute(&strprofile)).into()
+            }
+        }
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IMbnConnectionProfile_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnConnectionProfile_Impl::Delete(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProfileXmlData: GetProfileXmlData::<Identity>,
+            UpdateProfile: UpdateProfile::<Identity>,
+            Delete: Delete::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMbnConnectionProfile {}
 windows_core::imp::define_interface!(IMbnConnectionProfileEvents, IMbnConnectionProfileEvents_Vtbl, 0xdcbbbab6_2011_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnConnectionProfileEvents, windows_core::IUnknown);
@@ -628,6 +927,20 @@ impl IMbnConnectionProfileEvents_Vtbl {
         iid == &<IMbnConnectionProfileEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMbnConnectionProfileEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnConnectionProfileEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnProfileUpdate<Identity: windows_core::IUnknownImpl + IMbnConnectionProfileEvents_Impl>(this: *mut core::ffi::c_void, newprofile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnConnectionProfileEvents_Impl::OnProfileUpdate(this, core::mem::transmute_copy(&newprofile)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnProfileUpdate: OnProfileUpdate::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IMbnConnectionProfileEvents {}
 windows_core::imp::define_interface!(IMbnConnectionProfileManager, IMbnConnectionProfileManager_Vtbl, 0xdcbbbab6_200f_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnConnectionProfileManager, windows_core::IUnknown);
@@ -721,6 +1034,50 @@ impl IMbnConnectionProfileManager_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IMbnConnectionProfileManager_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnConnectionProfileManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetConnectionProfiles<Identity: windows_core::IUnknownImpl + IMbnConnectionProfileManager_Impl>(this: *mut core::ffi::c_void, mbninterface: *mut core::ffi::c_void, connectionprofiles: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnConnectionProfileManager_Impl::GetConnectionProfiles(this, core::mem::transmute_copy(&mbninterface)) {
+                    Ok(ok__) => {
+                        connectionprofiles.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetConnectionProfile<Identity: windows_core::IUnknownImpl + IMbnConnectionProfileManager_Impl>(this: *mut core::ffi::c_void, mbninterface: *mut core::ffi::c_void, profilename: windows_core::PCWSTR, connectionprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnConnectionProfileManager_Impl::GetConnectionProfile(this, core::mem::transmute_copy(&mbninterface), core::mem::transmute(&profilename)) {
+                    Ok(ok__) => {
+                        connectionprofile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateConnectionProfile<Identity: windows_core::IUnknownImpl + IMbnConnectionProfileManager_Impl>(this: *mut core::ffi::c_void, xmlprofile: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnConnectionProfileManager_Impl::CreateConnectionProfile(this, core::mem::transmute(&xmlprofile)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetConnectionProfiles: GetConnectionProfiles::<Identity>,
+            GetConnectionProfile: GetConnectionProfile::<Identity>,
+            CreateConnectionProfile: CreateConnectionProfile::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IMbnConnectionProfileManager {}
 windows_core::imp::define_interface!(IMbnConnectionProfileManagerEvents, IMbnConnectionProfileManagerEvents_Vtbl, 0xdcbbbab6_201f_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnConnectionProfileManagerEvents, windows_core::IUnknown);
@@ -773,6 +1130,30 @@ impl IMbnConnectionProfileManagerEvents_Vtbl {
         iid == &<IMbnConnectionProfileManagerEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMbnConnectionProfileManagerEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnConnectionProfileManagerEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnConnectionProfileArrival<Identity: windows_core::IUnknownImpl + IMbnConnectionProfileManagerEvents_Impl>(this: *mut core::ffi::c_void, newconnectionprofile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnConnectionProfileManagerEvents_Impl::OnConnectionProfileArrival(this, core::mem::transmute_copy(&newconnectionprofile)).into()
+            }
+        }
+        unsafe extern "system" fn OnConnectionProfileRemoval<Identity: windows_core::IUnknownImpl + IMbnConnectionProfileManagerEvents_Impl>(this: *mut core::ffi::c_void, oldconnectionprofile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnConnectionProfileManagerEvents_Impl::OnConnectionProfileRemoval(this, core::mem::transmute_copy(&oldconnectionprofile)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnConnectionProfileArrival: OnConnectionProfileArrival::<Identity>,
+            OnConnectionProfileRemoval: OnConnectionProfileRemoval::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMbnConnectionProfileManagerEvents {}
 windows_core::imp::define_interface!(IMbnDeviceService, IMbnDeviceService_Vtbl, 0xb3bb9a71_dc70_4be9_a4da_7886ae8b191b);
 windows_core::imp::interface_hierarchy!(IMbnDeviceService, windows_core::IUnknown);
@@ -1062,37 +1443,221 @@ impl IMbnDeviceService_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
-impl windows_core::RuntimeName for IMbnDeviceService {}
-windows_core::imp::define_interface!(IMbnDeviceServiceStateEvents, IMbnDeviceServiceStateEvents_Vtbl, 0x5d3ff196_89ee_49d8_8b60_33ffddffc58d);
-windows_core::imp::interface_hierarchy!(IMbnDeviceServiceStateEvents, windows_core::IUnknown);
-impl IMbnDeviceServiceStateEvents {
-    pub unsafe fn OnSessionsStateChange(&self, interfaceid: &windows_core::BSTR, statechange: MBN_DEVICE_SERVICE_SESSIONS_STATE) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).OnSessionsStateChange)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(interfaceid), statechange).ok() }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IMbnDeviceServiceStateEvents_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    pub OnSessionsStateChange: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, MBN_DEVICE_SERVICE_SESSIONS_STATE) -> windows_core::HRESULT,
-}
-pub trait IMbnDeviceServiceStateEvents_Impl: windows_core::IUnknownImpl {
-    fn OnSessionsStateChange(&self, interfaceid: &windows_core::BSTR, statechange: MBN_DEVICE_SERVICE_SESSIONS_STATE) -> windows_core::Result<()>;
-}
-impl IMbnDeviceServiceStateEvents_Vtbl {
-    pub const fn new<Identity: IMbnDeviceServiceStateEvents_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn OnSessionsStateChange<Identity: IMbnDeviceServiceStateEvents_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, interfaceid: *mut core::ffi::c_void, statechange: MBN_DEVICE_SERVICE_SESSIONS_STATE) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IMbnDeviceServiceStateEvents_Impl::OnSessionsStateChange(this, core::mem::transmute(&interfaceid), core::mem::transmute_copy(&statechange)).into()
+impl<Identity: windows_core::IUnknownImpl + IMbnDeviceService_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnDeviceService_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QuerySupportedCommands<Identity: windows_core::IUnknownImpl + IMbnDeviceService_Impl>(this: *mut core::ffi::c_void, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnDeviceService_Impl::QuerySupportedCommands(this) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
             }
         }
-        Self { base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(), OnSessionsStateChange: OnSessionsStateChange::<Identity, OFFSET> }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IMbnDeviceServiceStateEvents as windows_core::Interface>::IID
-    }
-}
+        unsafe extern "system" fn OpenCommandSession<Identity: windows_core::IUnknownImpl + IMbnDeviceService_Impl>(this: *mut core::ffi::c_void, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnDeviceService_Impl::OpenCommandSession(this) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CloseCommandSession<Identity: windows_core::IUnknownImpl + IMbnDeviceService_Impl>(this: *mut core::ffi::c_void, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnDeviceService_Impl::CloseCommandSession(this) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCommand<Identity: windows_core::IUnknownImpl + IMbnDeviceService_Impl>(this: *mut core::ffi::c_void, commandid: u32, deviceservicedata: *const super::super::System::Com::SAFEARRAY, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnDeviceService_Impl::SetCommand(this, core::mem::transmute_copy(&commandid), core::mem::transmute_copy(&deviceservicedata)) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryCommand<Identity: windows_core::IUnknownImpl + IMbnDeviceService_Impl>(this: *mut core::ffi::c_void, commandid: u32, deviceservicedata: *const super::super::System::Com::SAFEARRAY, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnDeviceService_Impl::QueryCommand(this, core::mem::transmute_copy(&commandid), core::mem::transmute_copy(&deviceservicedata)) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OpenDataSession<Identity: windows_core::IUnknownImpl + IMbnDeviceService_Impl>(this: *mut core::ffi::c_void, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnDeviceService_Impl::OpenDataSession(this) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CloseDataSession<Identity: windows_core::IUnknownImpl + IMbnDeviceService_Impl>(this: *mut core::ffi::c_void, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnDeviceService_Impl::CloseDataSession(this) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn WriteData<Identity: windows_core::IUnknownImpl + IMbnDeviceService_Impl>(this: *mut core::ffi::c_void, deviceservicedata: *const super::super::System::Com::SAFEARRAY, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnDeviceService_Impl::WriteData(this, core::mem::transmute_copy(&deviceservicedata)) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InterfaceID<Identity: windows_core::IUnknownImpl + IMbnDeviceService_Impl>(this: *mut core::ffi::c_void, interfaceid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnDeviceService_Impl::InterfaceID(this) {
+                    Ok(ok__) => {
+                        interfaceid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeviceServiceID<Identity: windows_core::IUnknownImpl + IMbnDeviceService_Impl>(this: *mut core::ffi::c_void, deviceserviceid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnDeviceService_Impl::DeviceServiceID(this) {
+                    Ok(ok__) => {
+                        deviceserviceid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsCommandSessionOpen<Identity: windows_core::IUnknownImpl + IMbnDeviceService_Impl>(this: *mut core::ffi::c_void, value: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnDeviceService_Impl::IsCommandSessionOpen(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsDataSessionOpen<Identity: windows_core::IUnknownImpl + IMbnDeviceService_Impl>(this: *mut core::ffi::c_void, value: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnDeviceService_Impl::IsDataSessionOpen(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QuerySupportedCommands: QuerySupportedCommands::<Identity>,
+            OpenCommandSession: OpenCommandSession::<Identity>,
+            CloseCommandSession: CloseCommandSession::<Identity>,
+            SetCommand: SetCommand::<Identity>,
+            QueryCommand: QueryCommand::<Identity>,
+            OpenDataSession: OpenDataSession::<Identity>,
+            CloseDataSession: CloseDataSession::<Identity>,
+            WriteData: WriteData::<Identity>,
+            InterfaceID: InterfaceID::<Identity>,
+            DeviceServiceID: DeviceServiceID::<Identity>,
+            IsCommandSessionOpen: IsCommandSessionOpen::<Identity>,
+            IsDataSessionOpen: IsDataSessionOpen::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::RuntimeName for IMbnDeviceService {}
+windows_core::imp::define_interface!(IMbnDeviceServiceStateEvents, IMbnDeviceServiceStateEvents_Vtbl, 0x5d3ff196_89ee_49d8_8b60_33ffddffc58d);
+windows_core::imp::interface_hierarchy!(IMbnDeviceServiceStateEvents, windows_core::IUnknown);
+impl IMbnDeviceServiceStateEvents {
+    pub unsafe fn OnSessionsStateChange(&self, interfaceid: &windows_core::BSTR, statechange: MBN_DEVICE_SERVICE_SESSIONS_STATE) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).OnSessionsStateChange)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(interfaceid), statechange).ok() }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IMbnDeviceServiceStateEvents_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub OnSessionsStateChange: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, MBN_DEVICE_SERVICE_SESSIONS_STATE) -> windows_core::HRESULT,
+}
+pub trait IMbnDeviceServiceStateEvents_Impl: windows_core::IUnknownImpl {
+    fn OnSessionsStateChange(&self, interfaceid: &windows_core::BSTR, statechange: MBN_DEVICE_SERVICE_SESSIONS_STATE) -> windows_core::Result<()>;
+}
+impl IMbnDeviceServiceStateEvents_Vtbl {
+    pub const fn new<Identity: IMbnDeviceServiceStateEvents_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn OnSessionsStateChange<Identity: IMbnDeviceServiceStateEvents_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, interfaceid: *mut core::ffi::c_void, statechange: MBN_DEVICE_SERVICE_SESSIONS_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IMbnDeviceServiceStateEvents_Impl::OnSessionsStateChange(this, core::mem::transmute(&interfaceid), core::mem::transmute_copy(&statechange)).into()
+            }
+        }
+        Self { base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(), OnSessionsStateChange: OnSessionsStateChange::<Identity, OFFSET> }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnDeviceServiceStateEvents as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IMbnDeviceServiceStateEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnDeviceServiceStateEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnSessionsStateChange<Identity: windows_core::IUnknownImpl + IMbnDeviceServiceStateEvents_Impl>(this: *mut core::ffi::c_void, interfaceid: *mut core::ffi::c_void, statechange: MBN_DEVICE_SERVICE_SESSIONS_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnDeviceServiceStateEvents_Impl::OnSessionsStateChange(this, core::mem::transmute(&interfaceid), core::mem::transmute_copy(&statechange)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnSessionsStateChange: OnSessionsStateChange::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMbnDeviceServiceStateEvents {}
 windows_core::imp::define_interface!(IMbnDeviceServicesContext, IMbnDeviceServicesContext_Vtbl, 0xfc5ac347_1592_4068_80bb_6a57580150d8);
 windows_core::imp::interface_hierarchy!(IMbnDeviceServicesContext, windows_core::IUnknown);
@@ -1206,6 +1771,69 @@ impl IMbnDeviceServicesContext_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IMbnDeviceServicesContext_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnDeviceServicesContext_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumerateDeviceServices<Identity: windows_core::IUnknownImpl + IMbnDeviceServicesContext_Impl>(this: *mut core::ffi::c_void, deviceservices: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnDeviceServicesContext_Impl::EnumerateDeviceServices(this) {
+                    Ok(ok__) => {
+                        deviceservices.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDeviceService<Identity: windows_core::IUnknownImpl + IMbnDeviceServicesContext_Impl>(this: *mut core::ffi::c_void, deviceserviceid: *mut core::ffi::c_void, mbndeviceservice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnDeviceServicesContext_Impl::GetDeviceService(this, core::mem::transmute(&deviceserviceid)) {
+                    Ok(ok__) => {
+                        mbndeviceservice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MaxCommandSize<Identity: windows_core::IUnknownImpl + IMbnDeviceServicesContext_Impl>(this: *mut core::ffi::c_void, maxcommandsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnDeviceServicesContext_Impl::MaxCommandSize(this) {
+                    Ok(ok__) => {
+                        maxcommandsize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MaxDataSize<Identity: windows_core::IUnknownImpl + IMbnDeviceServicesContext_Impl>(this: *mut core::ffi::c_void, maxdatasize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnDeviceServicesContext_Impl::MaxDataSize(this) {
+                    Ok(ok__) => {
+                        maxdatasize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnumerateDeviceServices: EnumerateDeviceServices::<Identity>,
+            GetDeviceService: GetDeviceService::<Identity>,
+            MaxCommandSize: MaxCommandSize::<Identity>,
+            MaxDataSize: MaxDataSize::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IMbnDeviceServicesContext {}
 windows_core::imp::define_interface!(IMbnDeviceServicesEvents, IMbnDeviceServicesEvents_Vtbl, 0x0a900c19_6824_4e97_b76e_cf239d0ca642);
 windows_core::imp::interface_hierarchy!(IMbnDeviceServicesEvents, windows_core::IUnknown);
@@ -1413,6 +2041,94 @@ impl IMbnDeviceServicesEvents_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IMbnDeviceServicesEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnDeviceServicesEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnQuerySupportedCommandsComplete<Identity: windows_core::IUnknownImpl + IMbnDeviceServicesEvents_Impl>(this: *mut core::ffi::c_void, deviceservice: *mut core::ffi::c_void, commandidlist: *const super::super::System::Com::SAFEARRAY, status: windows_core::HRESULT, requestid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnDeviceServicesEvents_Impl::OnQuerySupportedCommandsComplete(this, core::mem::transmute_copy(&deviceservice), core::mem::transmute_copy(&commandidlist), core::mem::transmute_copy(&status), core::mem::transmute_copy(&requestid)).into()
+            }
+        }
+        unsafe extern "system" fn OnOpenCommandSessionComplete<Identity: windows_core::IUnknownImpl + IMbnDeviceServicesEvents_Impl>(this: *mut core::ffi::c_void, deviceservice: *mut core::ffi::c_void, status: windows_core::HRESULT, requestid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnDeviceServicesEvents_Impl::OnOpenCommandSessionComplete(this, core::mem::transmute_copy(&deviceservice), core::mem::transmute_copy(&status), core::mem::transmute_copy(&requestid)).into()
+            }
+        }
+        unsafe extern "system" fn OnCloseCommandSessionComplete<Identity: windows_core::IUnknownImpl + IMbnDeviceServicesEvents_Impl>(this: *mut core::ffi::c_void, deviceservice: *mut core::ffi::c_void, status: windows_core::HRESULT, requestid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnDeviceServicesEvents_Impl::OnCloseCommandSessionComplete(this, core::mem::transmute_copy(&deviceservice), core::mem::transmute_copy(&status), core::mem::transmute_copy(&requestid)).into()
+            }
+        }
+        unsafe extern "system" fn OnSetCommandComplete<Identity: windows_core::IUnknownImpl + IMbnDeviceServicesEvents_Impl>(this: *mut core::ffi::c_void, deviceservice: *mut core::ffi::c_void, responseid: u32, deviceservicedata: *const super::super::System::Com::SAFEARRAY, status: windows_core::HRESULT, requestid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnDeviceServicesEvents_Impl::OnSetCommandComplete(this, core::mem::transmute_copy(&deviceservice), core::mem::transmute_copy(&responseid), core::mem::transmute_copy(&deviceservicedata), core::mem::transmute_copy(&status), core::mem::transmute_copy(&requestid)).into()
+            }
+        }
+        unsafe extern "system" fn OnQueryCommandComplete<Identity: windows_core::IUnknownImpl + IMbnDeviceServicesEvents_Impl>(this: *mut core::ffi::c_void, deviceservice: *mut core::ffi::c_void, responseid: u32, deviceservicedata: *const super::super::System::Com::SAFEARRAY, status: windows_core::HRESULT, requestid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnDeviceServicesEvents_Impl::OnQueryCommandComplete(this, core::mem::transmute_copy(&deviceservice), core::mem::transmute_copy(&responseid), core::mem::transmute_copy(&deviceservicedata), core::mem::transmute_copy(&status), core::mem::transmute_copy(&requestid)).into()
+            }
+        }
+        unsafe extern "system" fn OnEventNotification<Identity: windows_core::IUnknownImpl + IMbnDeviceServicesEvents_Impl>(this: *mut core::ffi::c_void, deviceservice: *mut core::ffi::c_void, eventid: u32, deviceservicedata: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnDeviceServicesEvents_Impl::OnEventNotification(this, core::mem::transmute_copy(&deviceservice), core::mem::transmute_copy(&eventid), core::mem::transmute_copy(&deviceservicedata)).into()
+            }
+        }
+        unsafe extern "system" fn OnOpenDataSessionComplete<Identity: windows_core::IUnknownImpl + IMbnDeviceServicesEvents_Impl>(this: *mut core::ffi::c_void, deviceservice: *mut core::ffi::c_void, status: windows_core::HRESULT, requestid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnDeviceServicesEvents_Impl::OnOpenDataSessionComplete(this, core::mem::transmute_copy(&deviceservice), core::mem::transmute_copy(&status), core::mem::transmute_copy(&requestid)).into()
+            }
+        }
+        unsafe extern "system" fn OnCloseDataSessionComplete<Identity: windows_core::IUnknownImpl + IMbnDeviceServicesEvents_Impl>(this: *mut core::ffi::c_void, deviceservice: *mut core::ffi::c_void, status: windows_core::HRESULT, requestid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnDeviceServicesEvents_Impl::OnCloseDataSessionComplete(this, core::mem::transmute_copy(&deviceservice), core::mem::transmute_copy(&status), core::mem::transmute_copy(&requestid)).into()
+            }
+        }
+        unsafe extern "system" fn OnWriteDataComplete<Identity: windows_core::IUnknownImpl + IMbnDeviceServicesEvents_Impl>(this: *mut core::ffi::c_void, deviceservice: *mut core::ffi::c_void, status: windows_core::HRESULT, requestid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnDeviceServicesEvents_Impl::OnWriteDataComplete(this, core::mem::transmute_copy(&deviceservice), core::mem::transmute_copy(&status), core::mem::transmute_copy(&requestid)).into()
+            }
+        }
+        unsafe extern "system" fn OnReadData<Identity: windows_core::IUnknownImpl + IMbnDeviceServicesEvents_Impl>(this: *mut core::ffi::c_void, deviceservice: *mut core::ffi::c_void, deviceservicedata: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnDeviceServicesEvents_Impl::OnReadData(this, core::mem::transmute_copy(&deviceservice), core::mem::transmute_copy(&deviceservicedata)).into()
+            }
+        }
+        unsafe extern "system" fn OnInterfaceStateChange<Identity: windows_core::IUnknownImpl + IMbnDeviceServicesEvents_Impl>(this: *mut core::ffi::c_void, interfaceid: *mut core::ffi::c_void, statechange: MBN_DEVICE_SERVICES_INTERFACE_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnDeviceServicesEvents_Impl::OnInterfaceStateChange(this, core::mem::transmute(&interfaceid), core::mem::transmute_copy(&statechange)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnQuerySupportedCommandsComplete: OnQuerySupportedCommandsComplete::<Identity>,
+            OnOpenCommandSessionComplete: OnOpenCommandSessionComplete::<Identity>,
+            OnCloseCommandSessionComplete: OnCloseCommandSessionComplete::<Identity>,
+            OnSetCommandComplete: OnSetCommandComplete::<Identity>,
+            OnQueryCommandComplete: OnQueryCommandComplete::<Identity>,
+            OnEventNotification: OnEventNotification::<Identity>,
+            OnOpenDataSessionComplete: OnOpenDataSessionComplete::<Identity>,
+            OnCloseDataSessionComplete: OnCloseDataSessionComplete::<Identity>,
+            OnWriteDataComplete: OnWriteDataComplete::<Identity>,
+            OnReadData: OnReadData::<Identity>,
+            OnInterfaceStateChange: OnInterfaceStateChange::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IMbnDeviceServicesEvents {}
 windows_core::imp::define_interface!(IMbnDeviceServicesManager, IMbnDeviceServicesManager_Vtbl, 0x20a26258_6811_4478_ac1d_13324e45e41c);
 windows_core::imp::interface_hierarchy!(IMbnDeviceServicesManager, windows_core::IUnknown);
@@ -1453,6 +2169,29 @@ impl IMbnDeviceServicesManager_Vtbl {
         iid == &<IMbnDeviceServicesManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMbnDeviceServicesManager_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnDeviceServicesManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDeviceServicesContext<Identity: windows_core::IUnknownImpl + IMbnDeviceServicesManager_Impl>(this: *mut core::ffi::c_void, networkinterfaceid: *mut core::ffi::c_void, mbndevicescontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnDeviceServicesManager_Impl::GetDeviceServicesContext(this, core::mem::transmute(&networkinterfaceid)) {
+                    Ok(ok__) => {
+                        mbndevicescontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDeviceServicesContext: GetDeviceServicesContext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMbnDeviceServicesManager {}
 windows_core::imp::define_interface!(IMbnInterface, IMbnInterface_Vtbl, 0xdcbbbab6_2001_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnInterface, windows_core::IUnknown);
@@ -1712,9 +2451,157 @@ impl IMbnInterface_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
-impl windows_core::RuntimeName for IMbnInterface {}
-windows_core::imp::define_interface!(IMbnInterfaceEvents, IMbnInterfaceEvents_Vtbl, 0xdcbbbab6_2002_4bbb_aaee_338e368af6fa);
-windows_core::imp::interface_hierarchy!(IMbnInterfaceEvents, windows_core::IUnknown);
+impl<Identity: windows_core::IUnknownImpl + IMbnInterface_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnInterface_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InterfaceID<Identity: windows_core::IUnknownImpl + IMbnInterface_Impl>(this: *mut core::ffi::c_void, interfaceid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnInterface_Impl::InterfaceID(this) {
+                    Ok(ok__) => {
+                        interfaceid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetInterfaceCapability<Identity: windows_core::IUnknownImpl + IMbnInterface_Impl>(this: *mut core::ffi::c_void, interfacecaps: *mut MBN_INTERFACE_CAPS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnInterface_Impl::GetInterfaceCapability(this, core::mem::transmute_copy(&interfacecaps)).into()
+            }
+        }
+        unsafe extern "system" fn GetSubscriberInformation<Identity: windows_core::IUnknownImpl + IMbnInterface_Impl>(this: *mut core::ffi::c_void, subscriberinformation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnInterface_Impl::GetSubscriberInformation(this) {
+                    Ok(ok__) => {
+                        subscriberinformation.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetReadyState<Identity: windows_core::IUnknownImpl + IMbnInterface_Impl>(this: *mut core::ffi::c_void, readystate: *mut MBN_READY_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnInterface_Impl::GetReadyState(this) {
+                    Ok(ok__) => {
+                        readystate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InEmergencyMode<Identity: windows_core::IUnknownImpl + IMbnInterface_Impl>(this: *mut core::ffi::c_void, emergencymode: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnInterface_Impl::InEmergencyMode(this) {
+                    Ok(ok__) => {
+                        emergencymode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHomeProvider<Identity: windows_core::IUnknownImpl + IMbnInterface_Impl>(this: *mut core::ffi::c_void, homeprovider: *mut MBN_PROVIDER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnInterface_Impl::GetHomeProvider(this) {
+                    Ok(ok__) => {
+                        homeprovider.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPreferredProviders<Identity: windows_core::IUnknownImpl + IMbnInterface_Impl>(this: *mut core::ffi::c_void, preferredproviders: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnInterface_Impl::GetPreferredProviders(this) {
+                    Ok(ok__) => {
+                        preferredproviders.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPreferredProviders<Identity: windows_core::IUnknownImpl + IMbnInterface_Impl>(this: *mut core::ffi::c_void, preferredproviders: *const super::super::System::Com::SAFEARRAY, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnInterface_Impl::SetPreferredProviders(this, core::mem::transmute_copy(&preferredproviders)) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetVisibleProviders<Identity: windows_core::IUnknownImpl + IMbnInterface_Impl>(this: *mut core::ffi::c_void, age: *mut u32, visibleproviders: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnInterface_Impl::GetVisibleProviders(this, core::mem::transmute_copy(&age)) {
+                    Ok(ok__) => {
+                        visibleproviders.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ScanNetwork<Identity: windows_core::IUnknownImpl + IMbnInterface_Impl>(this: *mut core::ffi::c_void, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnInterface_Impl::ScanNetwork(this) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetConnection<Identity: windows_core::IUnknownImpl + IMbnInterface_Impl>(this: *mut core::ffi::c_void, mbnconnection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnInterface_Impl::GetConnection(this) {
+                    Ok(ok__) => {
+                        mbnconnection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InterfaceID: InterfaceID::<Identity>,
+            GetInterfaceCapability: GetInterfaceCapability::<Identity>,
+            GetSubscriberInformation: GetSubscriberInformation::<Identity>,
+            GetReadyState: GetReadyState::<Identity>,
+            InEmergencyMode: InEmergencyMode::<Identity>,
+            GetHomeProvider: GetHomeProvider::<Identity>,
+            GetPreferredProviders: GetPreferredProviders::<Identity>,
+            SetPreferredProviders: SetPreferredProviders::<Identity>,
+            GetVisibleProviders: GetVisibleProviders::<Identity>,
+            ScanNetwork: ScanNetwork::<Identity>,
+            GetConnection: GetConnection::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::RuntimeName for IMbnInterface {}
+windows_core::imp::define_interface!(IMbnInterfaceEvents, IMbnInterfaceEvents_Vtbl, 0xdcbbbab6_2002_4bbb_aaee_338e368af6fa);
+windows_core::imp::interface_hierarchy!(IMbnInterfaceEvents, windows_core::IUnknown);
 impl IMbnInterfaceEvents {
     pub unsafe fn OnInterfaceCapabilityAvailable<P0>(&self, newinterface: P0) -> windows_core::Result<()>
     where
@@ -1854,6 +2741,72 @@ impl IMbnInterfaceEvents_Vtbl {
         iid == &<IMbnInterfaceEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMbnInterfaceEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnInterfaceEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnInterfaceCapabilityAvailable<Identity: windows_core::IUnknownImpl + IMbnInterfaceEvents_Impl>(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnInterfaceEvents_Impl::OnInterfaceCapabilityAvailable(this, core::mem::transmute_copy(&newinterface)).into()
+            }
+        }
+        unsafe extern "system" fn OnSubscriberInformationChange<Identity: windows_core::IUnknownImpl + IMbnInterfaceEvents_Impl>(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnInterfaceEvents_Impl::OnSubscriberInformationChange(this, core::mem::transmute_copy(&newinterface)).into()
+            }
+        }
+        unsafe extern "system" fn OnReadyStateChange<Identity: windows_core::IUnknownImpl + IMbnInterfaceEvents_Impl>(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnInterfaceEvents_Impl::OnReadyStateChange(this, core::mem::transmute_copy(&newinterface)).into()
+            }
+        }
+        unsafe extern "system" fn OnEmergencyModeChange<Identity: windows_core::IUnknownImpl + IMbnInterfaceEvents_Impl>(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnInterfaceEvents_Impl::OnEmergencyModeChange(this, core::mem::transmute_copy(&newinterface)).into()
+            }
+        }
+        unsafe extern "system" fn OnHomeProviderAvailable<Identity: windows_core::IUnknownImpl + IMbnInterfaceEvents_Impl>(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnInterfaceEvents_Impl::OnHomeProviderAvailable(this, core::mem::transmute_copy(&newinterface)).into()
+            }
+        }
+        unsafe extern "system" fn OnPreferredProvidersChange<Identity: windows_core::IUnknownImpl + IMbnInterfaceEvents_Impl>(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnInterfaceEvents_Impl::OnPreferredProvidersChange(this, core::mem::transmute_copy(&newinterface)).into()
+            }
+        }
+        unsafe extern "system" fn OnSetPreferredProvidersComplete<Identity: windows_core::IUnknownImpl + IMbnInterfaceEvents_Impl>(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnInterfaceEvents_Impl::OnSetPreferredProvidersComplete(this, core::mem::transmute_copy(&newinterface), core::mem::transmute_copy(&requestid), core::mem::transmute_copy(&status)).into()
+            }
+        }
+        unsafe extern "system" fn OnScanNetworkComplete<Identity: windows_core::IUnknownImpl + IMbnInterfaceEvents_Impl>(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnInterfaceEvents_Impl::OnScanNetworkComplete(this, core::mem::transmute_copy(&newinterface), core::mem::transmute_copy(&requestid), core::mem::transmute_copy(&status)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnInterfaceCapabilityAvailable: OnInterfaceCapabilityAvailable::<Identity>,
+            OnSubscriberInformationChange: OnSubscriberInformationChange::<Identity>,
+            OnReadyStateChange: OnReadyStateChange::<Identity>,
+            OnEmergencyModeChange: OnEmergencyModeChange::<Identity>,
+            OnHomeProviderAvailable: OnHomeProviderAvailable::<Identity>,
+            OnPreferredProvidersChange: OnPreferredProvidersChange::<Identity>,
+            OnSetPreferredProvidersComplete: OnSetPreferredProvidersComplete::<Identity>,
+            OnScanNetworkComplete: OnScanNetworkComplete::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMbnInterfaceEvents {}
 windows_core::imp::define_interface!(IMbnInterfaceManager, IMbnInterfaceManager_Vtbl, 0xdcbbbab6_201b_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnInterfaceManager, windows_core::IUnknown);
@@ -1928,6 +2881,43 @@ impl IMbnInterfaceManager_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IMbnInterfaceManager_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnInterfaceManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetInterface<Identity: windows_core::IUnknownImpl + IMbnInterfaceManager_Impl>(this: *mut core::ffi::c_void, interfaceid: windows_core::PCWSTR, mbninterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnInterfaceManager_Impl::GetInterface(this, core::mem::transmute(&interfaceid)) {
+                    Ok(ok__) => {
+                        mbninterface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetInterfaces<Identity: windows_core::IUnknownImpl + IMbnInterfaceManager_Impl>(this: *mut core::ffi::c_void, mbninterfaces: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnInterfaceManager_Impl::GetInterfaces(this) {
+                    Ok(ok__) => {
+                        mbninterfaces.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetInterface: GetInterface::<Identity>,
+            GetInterfaces: GetInterfaces::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IMbnInterfaceManager {}
 windows_core::imp::define_interface!(IMbnInterfaceManagerEvents, IMbnInterfaceManagerEvents_Vtbl, 0xdcbbbab6_201c_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnInterfaceManagerEvents, windows_core::IUnknown);
@@ -1980,6 +2970,30 @@ impl IMbnInterfaceManagerEvents_Vtbl {
         iid == &<IMbnInterfaceManagerEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMbnInterfaceManagerEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnInterfaceManagerEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnInterfaceArrival<Identity: windows_core::IUnknownImpl + IMbnInterfaceManagerEvents_Impl>(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnInterfaceManagerEvents_Impl::OnInterfaceArrival(this, core::mem::transmute_copy(&newinterface)).into()
+            }
+        }
+        unsafe extern "system" fn OnInterfaceRemoval<Identity: windows_core::IUnknownImpl + IMbnInterfaceManagerEvents_Impl>(this: *mut core::ffi::c_void, oldinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnInterfaceManagerEvents_Impl::OnInterfaceRemoval(this, core::mem::transmute_copy(&oldinterface)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnInterfaceArrival: OnInterfaceArrival::<Identity>,
+            OnInterfaceRemoval: OnInterfaceRemoval::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMbnInterfaceManagerEvents {}
 windows_core::imp::define_interface!(IMbnMultiCarrier, IMbnMultiCarrier_Vtbl, 0xdcbbbab6_2020_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnMultiCarrier, windows_core::IUnknown);
@@ -2143,6 +3157,95 @@ impl IMbnMultiCarrier_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IMbnMultiCarrier_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnMultiCarrier_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetHomeProvider<Identity: windows_core::IUnknownImpl + IMbnMultiCarrier_Impl>(this: *mut core::ffi::c_void, homeprovider: *const MBN_PROVIDER2, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnMultiCarrier_Impl::SetHomeProvider(this, core::mem::transmute_copy(&homeprovider)) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPreferredProviders<Identity: windows_core::IUnknownImpl + IMbnMultiCarrier_Impl>(this: *mut core::ffi::c_void, preferredmulticarrierproviders: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnMultiCarrier_Impl::GetPreferredProviders(this) {
+                    Ok(ok__) => {
+                        preferredmulticarrierproviders.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetVisibleProviders<Identity: windows_core::IUnknownImpl + IMbnMultiCarrier_Impl>(this: *mut core::ffi::c_void, age: *mut u32, visibleproviders: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnMultiCarrier_Impl::GetVisibleProviders(this, core::mem::transmute_copy(&age)) {
+                    Ok(ok__) => {
+                        visibleproviders.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSupportedCellularClasses<Identity: windows_core::IUnknownImpl + IMbnMultiCarrier_Impl>(this: *mut core::ffi::c_void, cellularclasses: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnMultiCarrier_Impl::GetSupportedCellularClasses(this) {
+                    Ok(ok__) => {
+                        cellularclasses.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCurrentCellularClass<Identity: windows_core::IUnknownImpl + IMbnMultiCarrier_Impl>(this: *mut core::ffi::c_void, currentcellularclass: *mut MBN_CELLULAR_CLASS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnMultiCarrier_Impl::GetCurrentCellularClass(this) {
+                    Ok(ok__) => {
+                        currentcellularclass.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ScanNetwork<Identity: windows_core::IUnknownImpl + IMbnMultiCarrier_Impl>(this: *mut core::ffi::c_void, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnMultiCarrier_Impl::ScanNetwork(this) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetHomeProvider: SetHomeProvider::<Identity>,
+            GetPreferredProviders: GetPreferredProviders::<Identity>,
+            GetVisibleProviders: GetVisibleProviders::<Identity>,
+            GetSupportedCellularClasses: GetSupportedCellularClasses::<Identity>,
+            GetCurrentCellularClass: GetCurrentCellularClass::<Identity>,
+            ScanNetwork: ScanNetwork::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IMbnMultiCarrier {}
 windows_core::imp::define_interface!(IMbnMultiCarrierEvents, IMbnMultiCarrierEvents_Vtbl, 0xdcdddab6_2021_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnMultiCarrierEvents, windows_core::IUnknown);
@@ -2240,20 +3343,65 @@ impl IMbnMultiCarrierEvents_Vtbl {
         iid == &<IMbnMultiCarrierEvents as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IMbnMultiCarrierEvents {}
-windows_core::imp::define_interface!(IMbnPin, IMbnPin_Vtbl, 0xdcbbbab6_2007_4bbb_aaee_338e368af6fa);
-windows_core::imp::interface_hierarchy!(IMbnPin, windows_core::IUnknown);
-impl IMbnPin {
-    pub unsafe fn PinType(&self) -> windows_core::Result<MBN_PIN_TYPE> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).PinType)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+impl<Identity: windows_core::IUnknownImpl + IMbnMultiCarrierEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnMultiCarrierEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnSetHomeProviderComplete<Identity: windows_core::IUnknownImpl + IMbnMultiCarrierEvents_Impl>(this: *mut core::ffi::c_void, mbninterface: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnMultiCarrierEvents_Impl::OnSetHomeProviderComplete(this, core::mem::transmute_copy(&mbninterface), core::mem::transmute_copy(&requestid), core::mem::transmute_copy(&status)).into()
+            }
         }
-    }
-    pub unsafe fn PinFormat(&self) -> windows_core::Result<MBN_PIN_FORMAT> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).PinFormat)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        unsafe extern "system" fn OnCurrentCellularClassChange<Identity: windows_core::IUnknownImpl + IMbnMultiCarrierEvents_Impl>(this: *mut core::ffi::c_void, mbninterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnMultiCarrierEvents_Impl::OnCurrentCellularClassChange(this, core::mem::transmute_copy(&mbninterface)).into()
+            }
+        }
+        unsafe extern "system" fn OnPreferredProvidersChange<Identity: windows_core::IUnknownImpl + IMbnMultiCarrierEvents_Impl>(this: *mut core::ffi::c_void, mbninterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnMultiCarrierEvents_Impl::OnPreferredProvidersChange(this, core::mem::transmute_copy(&mbninterface)).into()
+            }
+        }
+        unsafe extern "system" fn OnScanNetworkComplete<Identity: windows_core::IUnknownImpl + IMbnMultiCarrierEvents_Impl>(this: *mut core::ffi::c_void, mbninterface: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnMultiCarrierEvents_Impl::OnScanNetworkComplete(this, core::mem::transmute_copy(&mbninterface), core::mem::transmute_copy(&requestid), core::mem::transmute_copy(&status)).into()
+            }
+        }
+        unsafe extern "system" fn OnInterfaceCapabilityChange<Identity: windows_core::IUnknownImpl + IMbnMultiCarrierEvents_Impl>(this: *mut core::ffi::c_void, mbninterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnMultiCarrierEvents_Impl::OnInterfaceCapabilityChange(this, core::mem::transmute_copy(&mbninterface)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnSetHomeProviderComplete: OnSetHomeProviderComplete::<Identity>,
+            OnCurrentCellularClassChange: OnCurrentCellularClassChange::<Identity>,
+            OnPreferredProvidersChange: OnPreferredProvidersChange::<Identity>,
+            OnScanNetworkComplete: OnScanNetworkComplete::<Identity>,
+            OnInterfaceCapabilityChange: OnInterfaceCapabilityChange::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IMbnMultiCarrierEvents {}
+windows_core::imp::define_interface!(IMbnPin, IMbnPin_Vtbl, 0xdcbbbab6_2007_4bbb_aaee_338e368af6fa);
+windows_core::imp::interface_hierarchy!(IMbnPin, windows_core::IUnknown);
+impl IMbnPin {
+    pub unsafe fn PinType(&self) -> windows_core::Result<MBN_PIN_TYPE> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).PinType)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn PinFormat(&self) -> windows_core::Result<MBN_PIN_FORMAT> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).PinFormat)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
         }
     }
     pub unsafe fn PinLengthMin(&self) -> windows_core::Result<u32> {
@@ -2510,6 +3658,159 @@ impl IMbnPin_Vtbl {
         iid == &<IMbnPin as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMbnPin_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnPin_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn PinType<Identity: windows_core::IUnknownImpl + IMbnPin_Impl>(this: *mut core::ffi::c_void, pintype: *mut MBN_PIN_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnPin_Impl::PinType(this) {
+                    Ok(ok__) => {
+                        pintype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PinFormat<Identity: windows_core::IUnknownImpl + IMbnPin_Impl>(this: *mut core::ffi::c_void, pinformat: *mut MBN_PIN_FORMAT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnPin_Impl::PinFormat(this) {
+                    Ok(ok__) => {
+                        pinformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PinLengthMin<Identity: windows_core::IUnknownImpl + IMbnPin_Impl>(this: *mut core::ffi::c_void, pinlengthmin: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnPin_Impl::PinLengthMin(this) {
+                    Ok(ok__) => {
+                        pinlengthmin.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PinLengthMax<Identity: windows_core::IUnknownImpl + IMbnPin_Impl>(this: *mut core::ffi::c_void, pinlengthmax: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnPin_Impl::PinLengthMax(this) {
+                    Ok(ok__) => {
+                        pinlengthmax.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PinMode<Identity: windows_core::IUnknownImpl + IMbnPin_Impl>(this: *mut core::ffi::c_void, pinmode: *mut MBN_PIN_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnPin_Impl::PinMode(this) {
+                    Ok(ok__) => {
+                        pinmode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Enable<Identity: windows_core::IUnknownImpl + IMbnPin_Impl>(this: *mut core::ffi::c_void, pin: windows_core::PCWSTR, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnPin_Impl::Enable(this, core::mem::transmute(&pin)) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Disable<Identity: windows_core::IUnknownImpl + IMbnPin_Impl>(this: *mut core::ffi::c_void, pin: windows_core::PCWSTR, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnPin_Impl::Disable(this, core::mem::transmute(&pin)) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Enter<Identity: windows_core::IUnknownImpl + IMbnPin_Impl>(this: *mut core::ffi::c_void, pin: windows_core::PCWSTR, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnPin_Impl::Enter(this, core::mem::transmute(&pin)) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Change<Identity: windows_core::IUnknownImpl + IMbnPin_Impl>(this: *mut core::ffi::c_void, pin: windows_core::PCWSTR, newpin: windows_core::PCWSTR, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnPin_Impl::Change(this, core::mem::transmute(&pin), core::mem::transmute(&newpin)) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Unblock<Identity: windows_core::IUnknownImpl + IMbnPin_Impl>(this: *mut core::ffi::c_void, puk: windows_core::PCWSTR, newpin: windows_core::PCWSTR, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnPin_Impl::Unblock(this, core::mem::transmute(&puk), core::mem::transmute(&newpin)) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPinManager<Identity: windows_core::IUnknownImpl + IMbnPin_Impl>(this: *mut core::ffi::c_void, pinmanager: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnPin_Impl::GetPinManager(this) {
+                    Ok(ok__) => {
+                        pinmanager.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PinType: PinType::<Identity>,
+            PinFormat: PinFormat::<Identity>,
+            PinLengthMin: PinLengthMin::<Identity>,
+            PinLengthMax: PinLengthMax::<Identity>,
+            PinMode: PinMode::<Identity>,
+            Enable: Enable::<Identity>,
+            Disable: Disable::<Identity>,
+            Enter: Enter::<Identity>,
+            Change: Change::<Identity>,
+            Unblock: Unblock::<Identity>,
+            GetPinManager: GetPinManager::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMbnPin {}
 windows_core::imp::define_interface!(IMbnPinEvents, IMbnPinEvents_Vtbl, 0xdcbbbab6_2008_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnPinEvents, windows_core::IUnknown);
@@ -2607,6 +3908,51 @@ impl IMbnPinEvents_Vtbl {
         iid == &<IMbnPinEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMbnPinEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnPinEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnEnableComplete<Identity: windows_core::IUnknownImpl + IMbnPinEvents_Impl>(this: *mut core::ffi::c_void, pin: *mut core::ffi::c_void, pininfo: *const MBN_PIN_INFO, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnPinEvents_Impl::OnEnableComplete(this, core::mem::transmute_copy(&pin), core::mem::transmute_copy(&pininfo), core::mem::transmute_copy(&requestid), core::mem::transmute_copy(&status)).into()
+            }
+        }
+        unsafe extern "system" fn OnDisableComplete<Identity: windows_core::IUnknownImpl + IMbnPinEvents_Impl>(this: *mut core::ffi::c_void, pin: *mut core::ffi::c_void, pininfo: *const MBN_PIN_INFO, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnPinEvents_Impl::OnDisableComplete(this, core::mem::transmute_copy(&pin), core::mem::transmute_copy(&pininfo), core::mem::transmute_copy(&requestid), core::mem::transmute_copy(&status)).into()
+            }
+        }
+        unsafe extern "system" fn OnEnterComplete<Identity: windows_core::IUnknownImpl + IMbnPinEvents_Impl>(this: *mut core::ffi::c_void, pin: *mut core::ffi::c_void, pininfo: *const MBN_PIN_INFO, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnPinEvents_Impl::OnEnterComplete(this, core::mem::transmute_copy(&pin), core::mem::transmute_copy(&pininfo), core::mem::transmute_copy(&requestid), core::mem::transmute_copy(&status)).into()
+            }
+        }
+        unsafe extern "system" fn OnChangeComplete<Identity: windows_core::IUnknownImpl + IMbnPinEvents_Impl>(this: *mut core::ffi::c_void, pin: *mut core::ffi::c_void, pininfo: *const MBN_PIN_INFO, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnPinEvents_Impl::OnChangeComplete(this, core::mem::transmute_copy(&pin), core::mem::transmute_copy(&pininfo), core::mem::transmute_copy(&requestid), core::mem::transmute_copy(&status)).into()
+            }
+        }
+        unsafe extern "system" fn OnUnblockComplete<Identity: windows_core::IUnknownImpl + IMbnPinEvents_Impl>(this: *mut core::ffi::c_void, pin: *mut core::ffi::c_void, pininfo: *const MBN_PIN_INFO, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnPinEvents_Impl::OnUnblockComplete(this, core::mem::transmute_copy(&pin), core::mem::transmute_copy(&pininfo), core::mem::transmute_copy(&requestid), core::mem::transmute_copy(&status)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnEnableComplete: OnEnableComplete::<Identity>,
+            OnDisableComplete: OnDisableComplete::<Identity>,
+            OnEnterComplete: OnEnterComplete::<Identity>,
+            OnChangeComplete: OnChangeComplete::<Identity>,
+            OnUnblockComplete: OnUnblockComplete::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMbnPinEvents {}
 windows_core::imp::define_interface!(IMbnPinManager, IMbnPinManager_Vtbl, 0xdcbbbab6_2005_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnPinManager, windows_core::IUnknown);
@@ -2699,6 +4045,56 @@ impl IMbnPinManager_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IMbnPinManager_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnPinManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPinList<Identity: windows_core::IUnknownImpl + IMbnPinManager_Impl>(this: *mut core::ffi::c_void, pinlist: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnPinManager_Impl::GetPinList(this) {
+                    Ok(ok__) => {
+                        pinlist.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPin<Identity: windows_core::IUnknownImpl + IMbnPinManager_Impl>(this: *mut core::ffi::c_void, pintype: MBN_PIN_TYPE, pin: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnPinManager_Impl::GetPin(this, core::mem::transmute_copy(&pintype)) {
+                    Ok(ok__) => {
+                        pin.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPinState<Identity: windows_core::IUnknownImpl + IMbnPinManager_Impl>(this: *mut core::ffi::c_void, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnPinManager_Impl::GetPinState(this) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPinList: GetPinList::<Identity>,
+            GetPin: GetPin::<Identity>,
+            GetPinState: GetPinState::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IMbnPinManager {}
 windows_core::imp::define_interface!(IMbnPinManagerEvents, IMbnPinManagerEvents_Vtbl, 0xdcbbbab6_2006_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnPinManagerEvents, windows_core::IUnknown);
@@ -2751,6 +4147,30 @@ impl IMbnPinManagerEvents_Vtbl {
         iid == &<IMbnPinManagerEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMbnPinManagerEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnPinManagerEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnPinListAvailable<Identity: windows_core::IUnknownImpl + IMbnPinManagerEvents_Impl>(this: *mut core::ffi::c_void, pinmanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnPinManagerEvents_Impl::OnPinListAvailable(this, core::mem::transmute_copy(&pinmanager)).into()
+            }
+        }
+        unsafe extern "system" fn OnGetPinStateComplete<Identity: windows_core::IUnknownImpl + IMbnPinManagerEvents_Impl>(this: *mut core::ffi::c_void, pinmanager: *mut core::ffi::c_void, pininfo: MBN_PIN_INFO, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnPinManagerEvents_Impl::OnGetPinStateComplete(this, core::mem::transmute_copy(&pinmanager), core::mem::transmute(&pininfo), core::mem::transmute_copy(&requestid), core::mem::transmute_copy(&status)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnPinListAvailable: OnPinListAvailable::<Identity>,
+            OnGetPinStateComplete: OnGetPinStateComplete::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMbnPinManagerEvents {}
 windows_core::imp::define_interface!(IMbnRadio, IMbnRadio_Vtbl, 0xdccccab6_201f_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnRadio, windows_core::IUnknown);
@@ -2836,6 +4256,55 @@ impl IMbnRadio_Vtbl {
         iid == &<IMbnRadio as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMbnRadio_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnRadio_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SoftwareRadioState<Identity: windows_core::IUnknownImpl + IMbnRadio_Impl>(this: *mut core::ffi::c_void, softwareradiostate: *mut MBN_RADIO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnRadio_Impl::SoftwareRadioState(this) {
+                    Ok(ok__) => {
+                        softwareradiostate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn HardwareRadioState<Identity: windows_core::IUnknownImpl + IMbnRadio_Impl>(this: *mut core::ffi::c_void, hardwareradiostate: *mut MBN_RADIO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnRadio_Impl::HardwareRadioState(this) {
+                    Ok(ok__) => {
+                        hardwareradiostate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSoftwareRadioState<Identity: windows_core::IUnknownImpl + IMbnRadio_Impl>(this: *mut core::ffi::c_void, radiostate: MBN_RADIO, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnRadio_Impl::SetSoftwareRadioState(this, core::mem::transmute_copy(&radiostate)) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SoftwareRadioState: SoftwareRadioState::<Identity>,
+            HardwareRadioState: HardwareRadioState::<Identity>,
+            SetSoftwareRadioState: SetSoftwareRadioState::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMbnRadio {}
 windows_core::imp::define_interface!(IMbnRadioEvents, IMbnRadioEvents_Vtbl, 0xdcdddab6_201f_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnRadioEvents, windows_core::IUnknown);
@@ -2888,6 +4357,30 @@ impl IMbnRadioEvents_Vtbl {
         iid == &<IMbnRadioEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMbnRadioEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnRadioEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnRadioStateChange<Identity: windows_core::IUnknownImpl + IMbnRadioEvents_Impl>(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnRadioEvents_Impl::OnRadioStateChange(this, core::mem::transmute_copy(&newinterface)).into()
+            }
+        }
+        unsafe extern "system" fn OnSetSoftwareRadioStateComplete<Identity: windows_core::IUnknownImpl + IMbnRadioEvents_Impl>(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnRadioEvents_Impl::OnSetSoftwareRadioStateComplete(this, core::mem::transmute_copy(&newinterface), core::mem::transmute_copy(&requestid), core::mem::transmute_copy(&status)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnRadioStateChange: OnRadioStateChange::<Identity>,
+            OnSetSoftwareRadioStateComplete: OnSetSoftwareRadioStateComplete::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMbnRadioEvents {}
 windows_core::imp::define_interface!(IMbnRegistration, IMbnRegistration_Vtbl, 0xdcbbbab6_2009_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnRegistration, windows_core::IUnknown);
@@ -3123,6 +4616,146 @@ impl IMbnRegistration_Vtbl {
         iid == &<IMbnRegistration as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMbnRegistration_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnRegistration_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRegisterState<Identity: windows_core::IUnknownImpl + IMbnRegistration_Impl>(this: *mut core::ffi::c_void, registerstate: *mut MBN_REGISTER_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnRegistration_Impl::GetRegisterState(this) {
+                    Ok(ok__) => {
+                        registerstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRegisterMode<Identity: windows_core::IUnknownImpl + IMbnRegistration_Impl>(this: *mut core::ffi::c_void, registermode: *mut MBN_REGISTER_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnRegistration_Impl::GetRegisterMode(this) {
+                    Ok(ok__) => {
+                        registermode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetProviderID<Identity: windows_core::IUnknownImpl + IMbnRegistration_Impl>(this: *mut core::ffi::c_void, providerid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnRegistration_Impl::GetProviderID(this) {
+                    Ok(ok__) => {
+                        providerid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetProviderName<Identity: windows_core::IUnknownImpl + IMbnRegistration_Impl>(this: *mut core::ffi::c_void, providername: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnRegistration_Impl::GetProviderName(this) {
+                    Ok(ok__) => {
+                        providername.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRoamingText<Identity: windows_core::IUnknownImpl + IMbnRegistration_Impl>(this: *mut core::ffi::c_void, roamingtext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnRegistration_Impl::GetRoamingText(this) {
+                    Ok(ok__) => {
+                        roamingtext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAvailableDataClasses<Identity: windows_core::IUnknownImpl + IMbnRegistration_Impl>(this: *mut core::ffi::c_void, availabledataclasses: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnRegistration_Impl::GetAvailableDataClasses(this) {
+                    Ok(ok__) => {
+                        availabledataclasses.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCurrentDataClass<Identity: windows_core::IUnknownImpl + IMbnRegistration_Impl>(this: *mut core::ffi::c_void, currentdataclass: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnRegistration_Impl::GetCurrentDataClass(this) {
+                    Ok(ok__) => {
+                        currentdataclass.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRegistrationNetworkError<Identity: windows_core::IUnknownImpl + IMbnRegistration_Impl>(this: *mut core::ffi::c_void, registrationnetworkerror: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnRegistration_Impl::GetRegistrationNetworkError(this) {
+                    Ok(ok__) => {
+                        registrationnetworkerror.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPacketAttachNetworkError<Identity: windows_core::IUnknownImpl + IMbnRegistration_Impl>(this: *mut core::ffi::c_void, packetattachnetworkerror: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnRegistration_Impl::GetPacketAttachNetworkError(this) {
+                    Ok(ok__) => {
+                        packetattachnetworkerror.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRegisterMode<Identity: windows_core::IUnknownImpl + IMbnRegistration_Impl>(this: *mut core::ffi::c_void, registermode: MBN_REGISTER_MODE, providerid: windows_core::PCWSTR, dataclass: u32, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnRegistration_Impl::SetRegisterMode(this, core::mem::transmute_copy(&registermode), core::mem::transmute(&providerid), core::mem::transmute_copy(&dataclass)) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetRegisterState: GetRegisterState::<Identity>,
+            GetRegisterMode: GetRegisterMode::<Identity>,
+            GetProviderID: GetProviderID::<Identity>,
+            GetProviderName: GetProviderName::<Identity>,
+            GetRoamingText: GetRoamingText::<Identity>,
+            GetAvailableDataClasses: GetAvailableDataClasses::<Identity>,
+            GetCurrentDataClass: GetCurrentDataClass::<Identity>,
+            GetRegistrationNetworkError: GetRegistrationNetworkError::<Identity>,
+            GetPacketAttachNetworkError: GetPacketAttachNetworkError::<Identity>,
+            SetRegisterMode: SetRegisterMode::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMbnRegistration {}
 windows_core::imp::define_interface!(IMbnRegistrationEvents, IMbnRegistrationEvents_Vtbl, 0xdcbbbab6_200a_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnRegistrationEvents, windows_core::IUnknown);
@@ -3205,6 +4838,44 @@ impl IMbnRegistrationEvents_Vtbl {
         iid == &<IMbnRegistrationEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMbnRegistrationEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnRegistrationEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnRegisterModeAvailable<Identity: windows_core::IUnknownImpl + IMbnRegistrationEvents_Impl>(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnRegistrationEvents_Impl::OnRegisterModeAvailable(this, core::mem::transmute_copy(&newinterface)).into()
+            }
+        }
+        unsafe extern "system" fn OnRegisterStateChange<Identity: windows_core::IUnknownImpl + IMbnRegistrationEvents_Impl>(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnRegistrationEvents_Impl::OnRegisterStateChange(this, core::mem::transmute_copy(&newinterface)).into()
+            }
+        }
+        unsafe extern "system" fn OnPacketServiceStateChange<Identity: windows_core::IUnknownImpl + IMbnRegistrationEvents_Impl>(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnRegistrationEvents_Impl::OnPacketServiceStateChange(this, core::mem::transmute_copy(&newinterface)).into()
+            }
+        }
+        unsafe extern "system" fn OnSetRegisterModeComplete<Identity: windows_core::IUnknownImpl + IMbnRegistrationEvents_Impl>(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnRegistrationEvents_Impl::OnSetRegisterModeComplete(this, core::mem::transmute_copy(&newinterface), core::mem::transmute_copy(&requestid), core::mem::transmute_copy(&status)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnRegisterModeAvailable: OnRegisterModeAvailable::<Identity>,
+            OnRegisterStateChange: OnRegisterStateChange::<Identity>,
+            OnPacketServiceStateChange: OnPacketServiceStateChange::<Identity>,
+            OnSetRegisterModeComplete: OnSetRegisterModeComplete::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMbnRegistrationEvents {}
 windows_core::imp::define_interface!(IMbnServiceActivation, IMbnServiceActivation_Vtbl, 0xdcbbbab6_2017_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnServiceActivation, windows_core::IUnknown);
@@ -3252,6 +4923,27 @@ impl IMbnServiceActivation_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IMbnServiceActivation_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnServiceActivation_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Activate<Identity: windows_core::IUnknownImpl + IMbnServiceActivation_Impl>(this: *mut core::ffi::c_void, vendorspecificdata: *const super::super::System::Com::SAFEARRAY, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnServiceActivation_Impl::Activate(this, core::mem::transmute_copy(&vendorspecificdata)) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Activate: Activate::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IMbnServiceActivation {}
 windows_core::imp::define_interface!(IMbnServiceActivationEvents, IMbnServiceActivationEvents_Vtbl, 0xdcbbbab6_2018_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnServiceActivationEvents, windows_core::IUnknown);
@@ -3293,6 +4985,24 @@ impl IMbnServiceActivationEvents_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IMbnServiceActivationEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnServiceActivationEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnActivationComplete<Identity: windows_core::IUnknownImpl + IMbnServiceActivationEvents_Impl>(this: *mut core::ffi::c_void, serviceactivation: *mut core::ffi::c_void, vendorspecificdata: *const super::super::System::Com::SAFEARRAY, requestid: u32, status: windows_core::HRESULT, networkerror: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnServiceActivationEvents_Impl::OnActivationComplete(this, core::mem::transmute_copy(&serviceactivation), core::mem::transmute_copy(&vendorspecificdata), core::mem::transmute_copy(&requestid), core::mem::transmute_copy(&status), core::mem::transmute_copy(&networkerror)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnActivationComplete: OnActivationComplete::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IMbnServiceActivationEvents {}
 windows_core::imp::define_interface!(IMbnSignal, IMbnSignal_Vtbl, 0xdcbbbab6_2003_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnSignal, windows_core::IUnknown);
@@ -3357,6 +5067,42 @@ impl IMbnSignal_Vtbl {
         iid == &<IMbnSignal as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMbnSignal_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnSignal_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSignalStrength<Identity: windows_core::IUnknownImpl + IMbnSignal_Impl>(this: *mut core::ffi::c_void, signalstrength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSignal_Impl::GetSignalStrength(this) {
+                    Ok(ok__) => {
+                        signalstrength.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSignalError<Identity: windows_core::IUnknownImpl + IMbnSignal_Impl>(this: *mut core::ffi::c_void, signalerror: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSignal_Impl::GetSignalError(this) {
+                    Ok(ok__) => {
+                        signalerror.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSignalStrength: GetSignalStrength::<Identity>,
+            GetSignalError: GetSignalError::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMbnSignal {}
 windows_core::imp::define_interface!(IMbnSignalEvents, IMbnSignalEvents_Vtbl, 0xdcbbbab6_2004_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnSignalEvents, windows_core::IUnknown);
@@ -3391,6 +5137,23 @@ impl IMbnSignalEvents_Vtbl {
         iid == &<IMbnSignalEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMbnSignalEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnSignalEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnSignalStateChange<Identity: windows_core::IUnknownImpl + IMbnSignalEvents_Impl>(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnSignalEvents_Impl::OnSignalStateChange(this, core::mem::transmute_copy(&newinterface)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnSignalStateChange: OnSignalStateChange::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMbnSignalEvents {}
 windows_core::imp::define_interface!(IMbnSms, IMbnSms_Vtbl, 0xdcbbbab6_2015_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnSms, windows_core::IUnknown);
@@ -3601,6 +5364,121 @@ impl IMbnSms_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IMbnSms_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnSms_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSmsConfiguration<Identity: windows_core::IUnknownImpl + IMbnSms_Impl>(this: *mut core::ffi::c_void, smsconfiguration: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSms_Impl::GetSmsConfiguration(this) {
+                    Ok(ok__) => {
+                        smsconfiguration.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSmsConfiguration<Identity: windows_core::IUnknownImpl + IMbnSms_Impl>(this: *mut core::ffi::c_void, smsconfiguration: *mut core::ffi::c_void, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSms_Impl::SetSmsConfiguration(this, core::mem::transmute_copy(&smsconfiguration)) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SmsSendPdu<Identity: windows_core::IUnknownImpl + IMbnSms_Impl>(this: *mut core::ffi::c_void, pdudata: windows_core::PCWSTR, size: u8, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSms_Impl::SmsSendPdu(this, core::mem::transmute(&pdudata), core::mem::transmute_copy(&size)) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SmsSendCdma<Identity: windows_core::IUnknownImpl + IMbnSms_Impl>(this: *mut core::ffi::c_void, address: windows_core::PCWSTR, encoding: MBN_SMS_CDMA_ENCODING, language: MBN_SMS_CDMA_LANG, sizeincharacters: u32, message: *const super::super::System::Com::SAFEARRAY, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSms_Impl::SmsSendCdma(this, core::mem::transmute(&address), core::mem::transmute_copy(&encoding), core::mem::transmute_copy(&language), core::mem::transmute_copy(&sizeincharacters), core::mem::transmute_copy(&message)) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SmsSendCdmaPdu<Identity: windows_core::IUnknownImpl + IMbnSms_Impl>(this: *mut core::ffi::c_void, message: *const super::super::System::Com::SAFEARRAY, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSms_Impl::SmsSendCdmaPdu(this, core::mem::transmute_copy(&message)) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SmsRead<Identity: windows_core::IUnknownImpl + IMbnSms_Impl>(this: *mut core::ffi::c_void, smsfilter: *const MBN_SMS_FILTER, smsformat: MBN_SMS_FORMAT, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSms_Impl::SmsRead(this, core::mem::transmute_copy(&smsfilter), core::mem::transmute_copy(&smsformat)) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SmsDelete<Identity: windows_core::IUnknownImpl + IMbnSms_Impl>(this: *mut core::ffi::c_void, smsfilter: *const MBN_SMS_FILTER, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSms_Impl::SmsDelete(this, core::mem::transmute_copy(&smsfilter)) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSmsStatus<Identity: windows_core::IUnknownImpl + IMbnSms_Impl>(this: *mut core::ffi::c_void, smsstatusinfo: *mut MBN_SMS_STATUS_INFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSms_Impl::GetSmsStatus(this) {
+                    Ok(ok__) => {
+                        smsstatusinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSmsConfiguration: GetSmsConfiguration::<Identity>,
+            SetSmsConfiguration: SetSmsConfiguration::<Identity>,
+            SmsSendPdu: SmsSendPdu::<Identity>,
+            SmsSendCdma: SmsSendCdma::<Identity>,
+            SmsSendCdmaPdu: SmsSendCdmaPdu::<Identity>,
+            SmsRead: SmsRead::<Identity>,
+            SmsDelete: SmsDelete::<Identity>,
+            GetSmsStatus: GetSmsStatus::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IMbnSms {}
 windows_core::imp::define_interface!(IMbnSmsConfiguration, IMbnSmsConfiguration_Vtbl, 0xdcbbbab6_2012_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnSmsConfiguration, windows_core::IUnknown);
@@ -3639,30 +5517,109 @@ impl IMbnSmsConfiguration {
         unsafe { (windows_core::Interface::vtable(self).SetSmsFormat)(windows_core::Interface::as_raw(self), smsformat).ok() }
     }
 }
-#[repr(C)]
-#[doc(hidden)]
-pub struct IMbnSmsConfiguration_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    pub ServiceCenterAddress: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub SetServiceCenterAddress: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::PCWSTR) -> windows_core::HRESULT,
-    pub MaxMessageIndex: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
-    pub CdmaShortMsgSize: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
-    pub SmsFormat: unsafe extern "system" fn(*mut core::ffi::c_void, *mut MBN_SMS_FORMAT) -> windows_core::HRESULT,
-    pub SetSmsFormat: unsafe extern "system" fn(*mut core::ffi::c_void, MBN_SMS_FORMAT) -> windows_core::HRESULT,
-}
-pub trait IMbnSmsConfiguration_Impl: windows_core::IUnknownImpl {
-    fn ServiceCenterAddress(&self) -> windows_core::Result<windows_core::BSTR>;
-    fn SetServiceCenterAddress(&self, scaddress: &windows_core::PCWSTR) -> windows_core::Result<()>;
-    fn MaxMessageIndex(&self) -> windows_core::Result<u32>;
-    fn CdmaShortMsgSize(&self) -> windows_core::Result<u32>;
-    fn SmsFormat(&self) -> windows_core::Result<MBN_SMS_FORMAT>;
-    fn SetSmsFormat(&self, smsformat: MBN_SMS_FORMAT) -> windows_core::Result<()>;
-}
-impl IMbnSmsConfiguration_Vtbl {
-    pub const fn new<Identity: IMbnSmsConfiguration_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn ServiceCenterAddress<Identity: IMbnSmsConfiguration_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, scaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+#[repr(C)]
+#[doc(hidden)]
+pub struct IMbnSmsConfiguration_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub ServiceCenterAddress: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub SetServiceCenterAddress: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::PCWSTR) -> windows_core::HRESULT,
+    pub MaxMessageIndex: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
+    pub CdmaShortMsgSize: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
+    pub SmsFormat: unsafe extern "system" fn(*mut core::ffi::c_void, *mut MBN_SMS_FORMAT) -> windows_core::HRESULT,
+    pub SetSmsFormat: unsafe extern "system" fn(*mut core::ffi::c_void, MBN_SMS_FORMAT) -> windows_core::HRESULT,
+}
+pub trait IMbnSmsConfiguration_Impl: windows_core::IUnknownImpl {
+    fn ServiceCenterAddress(&self) -> windows_core::Result<windows_core::BSTR>;
+    fn SetServiceCenterAddress(&self, scaddress: &windows_core::PCWSTR) -> windows_core::Result<()>;
+    fn MaxMessageIndex(&self) -> windows_core::Result<u32>;
+    fn CdmaShortMsgSize(&self) -> windows_core::Result<u32>;
+    fn SmsFormat(&self) -> windows_core::Result<MBN_SMS_FORMAT>;
+    fn SetSmsFormat(&self, smsformat: MBN_SMS_FORMAT) -> windows_core::Result<()>;
+}
+impl IMbnSmsConfiguration_Vtbl {
+    pub const fn new<Identity: IMbnSmsConfiguration_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn ServiceCenterAddress<Identity: IMbnSmsConfiguration_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, scaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IMbnSmsConfiguration_Impl::ServiceCenterAddress(this) {
+                    Ok(ok__) => {
+                        scaddress.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetServiceCenterAddress<Identity: IMbnSmsConfiguration_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, scaddress: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IMbnSmsConfiguration_Impl::SetServiceCenterAddress(this, core::mem::transmute(&scaddress)).into()
+            }
+        }
+        unsafe extern "system" fn MaxMessageIndex<Identity: IMbnSmsConfiguration_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, index: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IMbnSmsConfiguration_Impl::MaxMessageIndex(this) {
+                    Ok(ok__) => {
+                        index.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CdmaShortMsgSize<Identity: IMbnSmsConfiguration_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, shortmsgsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IMbnSmsConfiguration_Impl::CdmaShortMsgSize(this) {
+                    Ok(ok__) => {
+                        shortmsgsize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SmsFormat<Identity: IMbnSmsConfiguration_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, smsformat: *mut MBN_SMS_FORMAT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IMbnSmsConfiguration_Impl::SmsFormat(this) {
+                    Ok(ok__) => {
+                        smsformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSmsFormat<Identity: IMbnSmsConfiguration_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, smsformat: MBN_SMS_FORMAT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IMbnSmsConfiguration_Impl::SetSmsFormat(this, core::mem::transmute_copy(&smsformat)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            ServiceCenterAddress: ServiceCenterAddress::<Identity, OFFSET>,
+            SetServiceCenterAddress: SetServiceCenterAddress::<Identity, OFFSET>,
+            MaxMessageIndex: MaxMessageIndex::<Identity, OFFSET>,
+            CdmaShortMsgSize: CdmaShortMsgSize::<Identity, OFFSET>,
+            SmsFormat: SmsFormat::<Identity, OFFSET>,
+            SetSmsFormat: SetSmsFormat::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnSmsConfiguration as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IMbnSmsConfiguration_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnSmsConfiguration_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ServiceCenterAddress<Identity: windows_core::IUnknownImpl + IMbnSmsConfiguration_Impl>(this: *mut core::ffi::c_void, scaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IMbnSmsConfiguration_Impl::ServiceCenterAddress(this) {
                     Ok(ok__) => {
                         scaddress.write(core::mem::transmute(ok__));
@@ -3672,15 +5629,15 @@ impl IMbnSmsConfiguration_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetServiceCenterAddress<Identity: IMbnSmsConfiguration_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, scaddress: windows_core::PCWSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetServiceCenterAddress<Identity: windows_core::IUnknownImpl + IMbnSmsConfiguration_Impl>(this: *mut core::ffi::c_void, scaddress: windows_core::PCWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IMbnSmsConfiguration_Impl::SetServiceCenterAddress(this, core::mem::transmute(&scaddress)).into()
             }
         }
-        unsafe extern "system" fn MaxMessageIndex<Identity: IMbnSmsConfiguration_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, index: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn MaxMessageIndex<Identity: windows_core::IUnknownImpl + IMbnSmsConfiguration_Impl>(this: *mut core::ffi::c_void, index: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IMbnSmsConfiguration_Impl::MaxMessageIndex(this) {
                     Ok(ok__) => {
                         index.write(core::mem::transmute(ok__));
@@ -3690,9 +5647,9 @@ impl IMbnSmsConfiguration_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CdmaShortMsgSize<Identity: IMbnSmsConfiguration_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, shortmsgsize: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn CdmaShortMsgSize<Identity: windows_core::IUnknownImpl + IMbnSmsConfiguration_Impl>(this: *mut core::ffi::c_void, shortmsgsize: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IMbnSmsConfiguration_Impl::CdmaShortMsgSize(this) {
                     Ok(ok__) => {
                         shortmsgsize.write(core::mem::transmute(ok__));
@@ -3702,9 +5659,9 @@ impl IMbnSmsConfiguration_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SmsFormat<Identity: IMbnSmsConfiguration_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, smsformat: *mut MBN_SMS_FORMAT) -> windows_core::HRESULT {
+        unsafe extern "system" fn SmsFormat<Identity: windows_core::IUnknownImpl + IMbnSmsConfiguration_Impl>(this: *mut core::ffi::c_void, smsformat: *mut MBN_SMS_FORMAT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IMbnSmsConfiguration_Impl::SmsFormat(this) {
                     Ok(ok__) => {
                         smsformat.write(core::mem::transmute(ok__));
@@ -3714,25 +5671,22 @@ impl IMbnSmsConfiguration_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetSmsFormat<Identity: IMbnSmsConfiguration_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, smsformat: MBN_SMS_FORMAT) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetSmsFormat<Identity: windows_core::IUnknownImpl + IMbnSmsConfiguration_Impl>(this: *mut core::ffi::c_void, smsformat: MBN_SMS_FORMAT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IMbnSmsConfiguration_Impl::SetSmsFormat(this, core::mem::transmute_copy(&smsformat)).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            ServiceCenterAddress: ServiceCenterAddress::<Identity, OFFSET>,
-            SetServiceCenterAddress: SetServiceCenterAddress::<Identity, OFFSET>,
-            MaxMessageIndex: MaxMessageIndex::<Identity, OFFSET>,
-            CdmaShortMsgSize: CdmaShortMsgSize::<Identity, OFFSET>,
-            SmsFormat: SmsFormat::<Identity, OFFSET>,
-            SetSmsFormat: SetSmsFormat::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ServiceCenterAddress: ServiceCenterAddress::<Identity>,
+            SetServiceCenterAddress: SetServiceCenterAddress::<Identity>,
+            MaxMessageIndex: MaxMessageIndex::<Identity>,
+            CdmaShortMsgSize: CdmaShortMsgSize::<Identity>,
+            SmsFormat: SmsFormat::<Identity>,
+            SetSmsFormat: SetSmsFormat::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IMbnSmsConfiguration as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IMbnSmsConfiguration {}
 windows_core::imp::define_interface!(IMbnSmsEvents, IMbnSmsEvents_Vtbl, 0xdcbbbab6_2016_4bbb_aaee_338e368af6fa);
@@ -3872,6 +5826,66 @@ impl IMbnSmsEvents_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IMbnSmsEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnSmsEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnSmsConfigurationChange<Identity: windows_core::IUnknownImpl + IMbnSmsEvents_Impl>(this: *mut core::ffi::c_void, sms: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnSmsEvents_Impl::OnSmsConfigurationChange(this, core::mem::transmute_copy(&sms)).into()
+            }
+        }
+        unsafe extern "system" fn OnSetSmsConfigurationComplete<Identity: windows_core::IUnknownImpl + IMbnSmsEvents_Impl>(this: *mut core::ffi::c_void, sms: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnSmsEvents_Impl::OnSetSmsConfigurationComplete(this, core::mem::transmute_copy(&sms), core::mem::transmute_copy(&requestid), core::mem::transmute_copy(&status)).into()
+            }
+        }
+        unsafe extern "system" fn OnSmsSendComplete<Identity: windows_core::IUnknownImpl + IMbnSmsEvents_Impl>(this: *mut core::ffi::c_void, sms: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnSmsEvents_Impl::OnSmsSendComplete(this, core::mem::transmute_copy(&sms), core::mem::transmute_copy(&requestid), core::mem::transmute_copy(&status)).into()
+            }
+        }
+        unsafe extern "system" fn OnSmsReadComplete<Identity: windows_core::IUnknownImpl + IMbnSmsEvents_Impl>(this: *mut core::ffi::c_void, sms: *mut core::ffi::c_void, smsformat: MBN_SMS_FORMAT, readmsgs: *const super::super::System::Com::SAFEARRAY, moremsgs: super::super::Foundation::VARIANT_BOOL, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnSmsEvents_Impl::OnSmsReadComplete(this, core::mem::transmute_copy(&sms), core::mem::transmute_copy(&smsformat), core::mem::transmute_copy(&readmsgs), core::mem::transmute_copy(&moremsgs), core::mem::transmute_copy(&requestid), core::mem::transmute_copy(&status)).into()
+            }
+        }
+        unsafe extern "system" fn OnSmsNewClass0Message<Identity: windows_core::IUnknownImpl + IMbnSmsEvents_Impl>(this: *mut core::ffi::c_void, sms: *mut core::ffi::c_void, smsformat: MBN_SMS_FORMAT, readmsgs: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnSmsEvents_Impl::OnSmsNewClass0Message(this, core::mem::transmute_copy(&sms), core::mem::transmute_copy(&smsformat), core::mem::transmute_copy(&readmsgs)).into()
+            }
+        }
+        unsafe extern "system" fn OnSmsDeleteComplete<Identity: windows_core::IUnknownImpl + IMbnSmsEvents_Impl>(this: *mut core::ffi::c_void, sms: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnSmsEvents_Impl::OnSmsDeleteComplete(this, core::mem::transmute_copy(&sms), core::mem::transmute_copy(&requestid), core::mem::transmute_copy(&status)).into()
+            }
+        }
+        unsafe extern "system" fn OnSmsStatusChange<Identity: windows_core::IUnknownImpl + IMbnSmsEvents_Impl>(this: *mut core::ffi::c_void, sms: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnSmsEvents_Impl::OnSmsStatusChange(this, core::mem::transmute_copy(&sms)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnSmsConfigurationChange: OnSmsConfigurationChange::<Identity>,
+            OnSetSmsConfigurationComplete: OnSetSmsConfigurationComplete::<Identity>,
+            OnSmsSendComplete: OnSmsSendComplete::<Identity>,
+            OnSmsReadComplete: OnSmsReadComplete::<Identity>,
+            OnSmsNewClass0Message: OnSmsNewClass0Message::<Identity>,
+            OnSmsDeleteComplete: OnSmsDeleteComplete::<Identity>,
+            OnSmsStatusChange: OnSmsStatusChange::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IMbnSmsEvents {}
 windows_core::imp::define_interface!(IMbnSmsReadMsgPdu, IMbnSmsReadMsgPdu_Vtbl, 0xdcbbbab6_2013_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnSmsReadMsgPdu, windows_core::IUnknown);
@@ -3985,6 +5999,69 @@ impl IMbnSmsReadMsgPdu_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IMbnSmsReadMsgPdu_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnSmsReadMsgPdu_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Index<Identity: windows_core::IUnknownImpl + IMbnSmsReadMsgPdu_Impl>(this: *mut core::ffi::c_void, index: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSmsReadMsgPdu_Impl::Index(this) {
+                    Ok(ok__) => {
+                        index.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Status<Identity: windows_core::IUnknownImpl + IMbnSmsReadMsgPdu_Impl>(this: *mut core::ffi::c_void, status: *mut MBN_MSG_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSmsReadMsgPdu_Impl::Status(this) {
+                    Ok(ok__) => {
+                        status.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PduData<Identity: windows_core::IUnknownImpl + IMbnSmsReadMsgPdu_Impl>(this: *mut core::ffi::c_void, pdudata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSmsReadMsgPdu_Impl::PduData(this) {
+                    Ok(ok__) => {
+                        pdudata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Message<Identity: windows_core::IUnknownImpl + IMbnSmsReadMsgPdu_Impl>(this: *mut core::ffi::c_void, message: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSmsReadMsgPdu_Impl::Message(this) {
+                    Ok(ok__) => {
+                        message.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Index: Index::<Identity>,
+            Status: Status::<Identity>,
+            PduData: PduData::<Identity>,
+            Message: Message::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IMbnSmsReadMsgPdu {}
 windows_core::imp::define_interface!(IMbnSmsReadMsgTextCdma, IMbnSmsReadMsgTextCdma_Vtbl, 0xdcbbbab6_2014_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnSmsReadMsgTextCdma, windows_core::IUnknown);
@@ -4182,6 +6259,121 @@ impl IMbnSmsReadMsgTextCdma_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IMbnSmsReadMsgTextCdma_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnSmsReadMsgTextCdma_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Index<Identity: windows_core::IUnknownImpl + IMbnSmsReadMsgTextCdma_Impl>(this: *mut core::ffi::c_void, index: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSmsReadMsgTextCdma_Impl::Index(this) {
+                    Ok(ok__) => {
+                        index.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Status<Identity: windows_core::IUnknownImpl + IMbnSmsReadMsgTextCdma_Impl>(this: *mut core::ffi::c_void, status: *mut MBN_MSG_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSmsReadMsgTextCdma_Impl::Status(this) {
+                    Ok(ok__) => {
+                        status.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Address<Identity: windows_core::IUnknownImpl + IMbnSmsReadMsgTextCdma_Impl>(this: *mut core::ffi::c_void, address: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSmsReadMsgTextCdma_Impl::Address(this) {
+                    Ok(ok__) => {
+                        address.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Timestamp<Identity: windows_core::IUnknownImpl + IMbnSmsReadMsgTextCdma_Impl>(this: *mut core::ffi::c_void, timestamp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSmsReadMsgTextCdma_Impl::Timestamp(this) {
+                    Ok(ok__) => {
+                        timestamp.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EncodingID<Identity: windows_core::IUnknownImpl + IMbnSmsReadMsgTextCdma_Impl>(this: *mut core::ffi::c_void, encodingid: *mut MBN_SMS_CDMA_ENCODING) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSmsReadMsgTextCdma_Impl::EncodingID(this) {
+                    Ok(ok__) => {
+                        encodingid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LanguageID<Identity: windows_core::IUnknownImpl + IMbnSmsReadMsgTextCdma_Impl>(this: *mut core::ffi::c_void, languageid: *mut MBN_SMS_CDMA_LANG) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSmsReadMsgTextCdma_Impl::LanguageID(this) {
+                    Ok(ok__) => {
+                        languageid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SizeInCharacters<Identity: windows_core::IUnknownImpl + IMbnSmsReadMsgTextCdma_Impl>(this: *mut core::ffi::c_void, sizeincharacters: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSmsReadMsgTextCdma_Impl::SizeInCharacters(this) {
+                    Ok(ok__) => {
+                        sizeincharacters.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Message<Identity: windows_core::IUnknownImpl + IMbnSmsReadMsgTextCdma_Impl>(this: *mut core::ffi::c_void, message: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSmsReadMsgTextCdma_Impl::Message(this) {
+                    Ok(ok__) => {
+                        message.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Index: Index::<Identity>,
+            Status: Status::<Identity>,
+            Address: Address::<Identity>,
+            Timestamp: Timestamp::<Identity>,
+            EncodingID: EncodingID::<Identity>,
+            LanguageID: LanguageID::<Identity>,
+            SizeInCharacters: SizeInCharacters::<Identity>,
+            Message: Message::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IMbnSmsReadMsgTextCdma {}
 windows_core::imp::define_interface!(IMbnSubscriberInformation, IMbnSubscriberInformation_Vtbl, 0x459ecc43_bcf5_11dc_a8a8_001321f1405f);
 windows_core::imp::interface_hierarchy!(IMbnSubscriberInformation, windows_core::IUnknown);
@@ -4274,6 +6466,56 @@ impl IMbnSubscriberInformation_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IMbnSubscriberInformation_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnSubscriberInformation_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SubscriberID<Identity: windows_core::IUnknownImpl + IMbnSubscriberInformation_Impl>(this: *mut core::ffi::c_void, subscriberid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSubscriberInformation_Impl::SubscriberID(this) {
+                    Ok(ok__) => {
+                        subscriberid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SimIccID<Identity: windows_core::IUnknownImpl + IMbnSubscriberInformation_Impl>(this: *mut core::ffi::c_void, simiccid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSubscriberInformation_Impl::SimIccID(this) {
+                    Ok(ok__) => {
+                        simiccid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn TelephoneNumbers<Identity: windows_core::IUnknownImpl + IMbnSubscriberInformation_Impl>(this: *mut core::ffi::c_void, telephonenumbers: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnSubscriberInformation_Impl::TelephoneNumbers(this) {
+                    Ok(ok__) => {
+                        telephonenumbers.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SubscriberID: SubscriberID::<Identity>,
+            SimIccID: SimIccID::<Identity>,
+            TelephoneNumbers: TelephoneNumbers::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IMbnSubscriberInformation {}
 windows_core::imp::define_interface!(IMbnVendorSpecificEvents, IMbnVendorSpecificEvents_Vtbl, 0xdcbbbab6_201a_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnVendorSpecificEvents, windows_core::IUnknown);
@@ -4337,6 +6579,31 @@ impl IMbnVendorSpecificEvents_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IMbnVendorSpecificEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnVendorSpecificEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnEventNotification<Identity: windows_core::IUnknownImpl + IMbnVendorSpecificEvents_Impl>(this: *mut core::ffi::c_void, vendoroperation: *mut core::ffi::c_void, vendorspecificdata: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnVendorSpecificEvents_Impl::OnEventNotification(this, core::mem::transmute_copy(&vendoroperation), core::mem::transmute_copy(&vendorspecificdata)).into()
+            }
+        }
+        unsafe extern "system" fn OnSetVendorSpecificComplete<Identity: windows_core::IUnknownImpl + IMbnVendorSpecificEvents_Impl>(this: *mut core::ffi::c_void, vendoroperation: *mut core::ffi::c_void, vendorspecificdata: *const super::super::System::Com::SAFEARRAY, requestid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMbnVendorSpecificEvents_Impl::OnSetVendorSpecificComplete(this, core::mem::transmute_copy(&vendoroperation), core::mem::transmute_copy(&vendorspecificdata), core::mem::transmute_copy(&requestid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnEventNotification: OnEventNotification::<Identity>,
+            OnSetVendorSpecificComplete: OnSetVendorSpecificComplete::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IMbnVendorSpecificEvents {}
 windows_core::imp::define_interface!(IMbnVendorSpecificOperation, IMbnVendorSpecificOperation_Vtbl, 0xdcbbbab6_2019_4bbb_aaee_338e368af6fa);
 windows_core::imp::interface_hierarchy!(IMbnVendorSpecificOperation, windows_core::IUnknown);
@@ -4384,6 +6651,27 @@ impl IMbnVendorSpecificOperation_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IMbnVendorSpecificOperation_Impl> windows_core::imp::TearOffVtable<Identity> for IMbnVendorSpecificOperation_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetVendorSpecific<Identity: windows_core::IUnknownImpl + IMbnVendorSpecificOperation_Impl>(this: *mut core::ffi::c_void, vendorspecificdata: *const super::super::System::Com::SAFEARRAY, requestid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMbnVendorSpecificOperation_Impl::SetVendorSpecific(this, core::mem::transmute_copy(&vendorspecificdata)) {
+                    Ok(ok__) => {
+                        requestid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetVendorSpecific: SetVendorSpecific::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IMbnVendorSpecificOperation {}
 pub const MBN_ACCESSSTRING_LEN: MBN_CONTEXT_CONSTANTS = MBN_CONTEXT_CONSTANTS(100i32);
 #[repr(transparent)]
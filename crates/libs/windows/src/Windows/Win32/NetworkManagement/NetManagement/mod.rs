@@ -2594,6 +2594,44 @@ impl IEnumNetCfgBindingInterface_Vtbl {
         iid == &<IEnumNetCfgBindingInterface as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumNetCfgBindingInterface_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumNetCfgBindingInterface_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumNetCfgBindingInterface_Impl>(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumNetCfgBindingInterface_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgelt), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumNetCfgBindingInterface_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumNetCfgBindingInterface_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumNetCfgBindingInterface_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumNetCfgBindingInterface_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumNetCfgBindingInterface_Impl>(this: *mut core::ffi::c_void, ppenum: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumNetCfgBindingInterface_Impl::Clone(this, core::mem::transmute_copy(&ppenum)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumNetCfgBindingInterface {}
 windows_core::imp::define_interface!(IEnumNetCfgBindingPath, IEnumNetCfgBindingPath_Vtbl, 0xc0e8ae91_306e_11d1_aacf_00805fc1270e);
 windows_core::imp::interface_hierarchy!(IEnumNetCfgBindingPath, windows_core::IUnknown);
@@ -2664,6 +2702,44 @@ impl IEnumNetCfgBindingPath_Vtbl {
         iid == &<IEnumNetCfgBindingPath as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumNetCfgBindingPath_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumNetCfgBindingPath_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumNetCfgBindingPath_Impl>(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumNetCfgBindingPath_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgelt), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumNetCfgBindingPath_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumNetCfgBindingPath_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumNetCfgBindingPath_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumNetCfgBindingPath_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumNetCfgBindingPath_Impl>(this: *mut core::ffi::c_void, ppenum: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumNetCfgBindingPath_Impl::Clone(this, core::mem::transmute_copy(&ppenum)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumNetCfgBindingPath {}
 windows_core::imp::define_interface!(IEnumNetCfgComponent, IEnumNetCfgComponent_Vtbl, 0xc0e8ae92_306e_11d1_aacf_00805fc1270e);
 windows_core::imp::interface_hierarchy!(IEnumNetCfgComponent, windows_core::IUnknown);
@@ -2734,6 +2810,44 @@ impl IEnumNetCfgComponent_Vtbl {
         iid == &<IEnumNetCfgComponent as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumNetCfgComponent_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumNetCfgComponent_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumNetCfgComponent_Impl>(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumNetCfgComponent_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgelt), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumNetCfgComponent_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumNetCfgComponent_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumNetCfgComponent_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumNetCfgComponent_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumNetCfgComponent_Impl>(this: *mut core::ffi::c_void, ppenum: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumNetCfgComponent_Impl::Clone(this, core::mem::transmute_copy(&ppenum)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumNetCfgComponent {}
 pub const INTERFACE_INFO_REVISION_1: u32 = 1u32;
 pub const INVALID_TRACEID: u32 = 4294967295u32;
@@ -2845,6 +2959,65 @@ impl INetCfg_Vtbl {
         iid == &<INetCfg as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INetCfg_Impl> windows_core::imp::TearOffVtable<Identity> for INetCfg_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + INetCfg_Impl>(this: *mut core::ffi::c_void, pvreserved: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfg_Impl::Initialize(this, core::mem::transmute_copy(&pvreserved)).into()
+            }
+        }
+        unsafe extern "system" fn Uninitialize<Identity: windows_core::IUnknownImpl + INetCfg_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfg_Impl::Uninitialize(this).into()
+            }
+        }
+        unsafe extern "system" fn Apply<Identity: windows_core::IUnknownImpl + INetCfg_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfg_Impl::Apply(this).into()
+            }
+        }
+        unsafe extern "system" fn Cancel<Identity: windows_core::IUnknownImpl + INetCfg_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfg_Impl::Cancel(this).into()
+            }
+        }
+        unsafe extern "system" fn EnumComponents<Identity: windows_core::IUnknownImpl + INetCfg_Impl>(this: *mut core::ffi::c_void, pguidclass: *const windows_core::GUID, ppenumcomponent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfg_Impl::EnumComponents(this, core::mem::transmute_copy(&pguidclass), core::mem::transmute_copy(&ppenumcomponent)).into()
+            }
+        }
+        unsafe extern "system" fn FindComponent<Identity: windows_core::IUnknownImpl + INetCfg_Impl>(this: *mut core::ffi::c_void, pszwinfid: windows_core::PCWSTR, pcomponent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfg_Impl::FindComponent(this, core::mem::transmute(&pszwinfid), core::mem::transmute_copy(&pcomponent)).into()
+            }
+        }
+        unsafe extern "system" fn QueryNetCfgClass<Identity: windows_core::IUnknownImpl + INetCfg_Impl>(this: *mut core::ffi::c_void, pguidclass: *const windows_core::GUID, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfg_Impl::QueryNetCfgClass(this, core::mem::transmute_copy(&pguidclass), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvobject)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            Uninitialize: Uninitialize::<Identity>,
+            Apply: Apply::<Identity>,
+            Cancel: Cancel::<Identity>,
+            EnumComponents: EnumComponents::<Identity>,
+            FindComponent: FindComponent::<Identity>,
+            QueryNetCfgClass: QueryNetCfgClass::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for INetCfg {}
 windows_core::imp::define_interface!(INetCfgBindingInterface, INetCfgBindingInterface_Vtbl, 0xc0e8ae94_306e_11d1_aacf_00805fc1270e);
 windows_core::imp::interface_hierarchy!(INetCfgBindingInterface, windows_core::IUnknown);
@@ -2903,6 +3076,37 @@ impl INetCfgBindingInterface_Vtbl {
         iid == &<INetCfgBindingInterface as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INetCfgBindingInterface_Impl> windows_core::imp::TearOffVtable<Identity> for INetCfgBindingInterface_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + INetCfgBindingInterface_Impl>(this: *mut core::ffi::c_void, ppszwinterfacename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgBindingInterface_Impl::GetName(this, core::mem::transmute_copy(&ppszwinterfacename)).into()
+            }
+        }
+        unsafe extern "system" fn GetUpperComponent<Identity: windows_core::IUnknownImpl + INetCfgBindingInterface_Impl>(this: *mut core::ffi::c_void, ppnccitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgBindingInterface_Impl::GetUpperComponent(this, core::mem::transmute_copy(&ppnccitem)).into()
+            }
+        }
+        unsafe extern "system" fn GetLowerComponent<Identity: windows_core::IUnknownImpl + INetCfgBindingInterface_Impl>(this: *mut core::ffi::c_void, ppnccitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgBindingInterface_Impl::GetLowerComponent(this, core::mem::transmute_copy(&ppnccitem)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetName: GetName::<Identity>,
+            GetUpperComponent: GetUpperComponent::<Identity>,
+            GetLowerComponent: GetLowerComponent::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for INetCfgBindingInterface {}
 windows_core::imp::define_interface!(INetCfgBindingPath, INetCfgBindingPath_Vtbl, 0xc0e8ae96_306e_11d1_aacf_00805fc1270e);
 windows_core::imp::interface_hierarchy!(INetCfgBindingPath, windows_core::IUnknown);
@@ -3036,6 +3240,78 @@ impl INetCfgBindingPath_Vtbl {
         iid == &<INetCfgBindingPath as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INetCfgBindingPath_Impl> windows_core::imp::TearOffVtable<Identity> for INetCfgBindingPath_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsSamePathAs<Identity: windows_core::IUnknownImpl + INetCfgBindingPath_Impl>(this: *mut core::ffi::c_void, ppath: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgBindingPath_Impl::IsSamePathAs(this, core::mem::transmute_copy(&ppath)).into()
+            }
+        }
+        unsafe extern "system" fn IsSubPathOf<Identity: windows_core::IUnknownImpl + INetCfgBindingPath_Impl>(this: *mut core::ffi::c_void, ppath: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgBindingPath_Impl::IsSubPathOf(this, core::mem::transmute_copy(&ppath)).into()
+            }
+        }
+        unsafe extern "system" fn IsEnabled<Identity: windows_core::IUnknownImpl + INetCfgBindingPath_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgBindingPath_Impl::IsEnabled(this).into()
+            }
+        }
+        unsafe extern "system" fn Enable<Identity: windows_core::IUnknownImpl + INetCfgBindingPath_Impl>(this: *mut core::ffi::c_void, fenable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgBindingPath_Impl::Enable(this, core::mem::transmute_copy(&fenable)).into()
+            }
+        }
+        unsafe extern "system" fn GetPathToken<Identity: windows_core::IUnknownImpl + INetCfgBindingPath_Impl>(this: *mut core::ffi::c_void, ppszwpathtoken: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgBindingPath_Impl::GetPathToken(this, core::mem::transmute_copy(&ppszwpathtoken)).into()
+            }
+        }
+        unsafe extern "system" fn GetOwner<Identity: windows_core::IUnknownImpl + INetCfgBindingPath_Impl>(this: *mut core::ffi::c_void, ppcomponent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgBindingPath_Impl::GetOwner(this, core::mem::transmute_copy(&ppcomponent)).into()
+            }
+        }
+        unsafe extern "system" fn GetDepth<Identity: windows_core::IUnknownImpl + INetCfgBindingPath_Impl>(this: *mut core::ffi::c_void, pcinterfaces: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INetCfgBindingPath_Impl::GetDepth(this) {
+                    Ok(ok__) => {
+                        pcinterfaces.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumBindingInterfaces<Identity: windows_core::IUnknownImpl + INetCfgBindingPath_Impl>(this: *mut core::ffi::c_void, ppenuminterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgBindingPath_Impl::EnumBindingInterfaces(this, core::mem::transmute_copy(&ppenuminterface)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsSamePathAs: IsSamePathAs::<Identity>,
+            IsSubPathOf: IsSubPathOf::<Identity>,
+            IsEnabled: IsEnabled::<Identity>,
+            Enable: Enable::<Identity>,
+            GetPathToken: GetPathToken::<Identity>,
+            GetOwner: GetOwner::<Identity>,
+            GetDepth: GetDepth::<Identity>,
+            EnumBindingInterfaces: EnumBindingInterfaces::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for INetCfgBindingPath {}
 windows_core::imp::define_interface!(INetCfgClass, INetCfgClass_Vtbl, 0xc0e8ae97_306e_11d1_aacf_00805fc1270e);
 windows_core::imp::interface_hierarchy!(INetCfgClass, windows_core::IUnknown);
@@ -3085,6 +3361,30 @@ impl INetCfgClass_Vtbl {
         iid == &<INetCfgClass as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INetCfgClass_Impl> windows_core::imp::TearOffVtable<Identity> for INetCfgClass_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FindComponent<Identity: windows_core::IUnknownImpl + INetCfgClass_Impl>(this: *mut core::ffi::c_void, pszwinfid: windows_core::PCWSTR, ppnccitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgClass_Impl::FindComponent(this, core::mem::transmute(&pszwinfid), core::mem::transmute_copy(&ppnccitem)).into()
+            }
+        }
+        unsafe extern "system" fn EnumComponents<Identity: windows_core::IUnknownImpl + INetCfgClass_Impl>(this: *mut core::ffi::c_void, ppenumcomponent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgClass_Impl::EnumComponents(this, core::mem::transmute_copy(&ppenumcomponent)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            FindComponent: FindComponent::<Identity>,
+            EnumComponents: EnumComponents::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for INetCfgClass {}
 windows_core::imp::define_interface!(INetCfgClassSetup, INetCfgClassSetup_Vtbl, 0xc0e8ae9d_306e_11d1_aacf_00805fc1270e);
 windows_core::imp::interface_hierarchy!(INetCfgClassSetup, windows_core::IUnknown);
@@ -3151,6 +3451,37 @@ impl INetCfgClassSetup_Vtbl {
         iid == &<INetCfgClassSetup as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INetCfgClassSetup_Impl> windows_core::imp::TearOffVtable<Identity> for INetCfgClassSetup_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SelectAndInstall<Identity: windows_core::IUnknownImpl + INetCfgClassSetup_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, pobotoken: *const OBO_TOKEN, ppnccitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgClassSetup_Impl::SelectAndInstall(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&pobotoken), core::mem::transmute_copy(&ppnccitem)).into()
+            }
+        }
+        unsafe extern "system" fn Install<Identity: windows_core::IUnknownImpl + INetCfgClassSetup_Impl>(this: *mut core::ffi::c_void, pszwinfid: windows_core::PCWSTR, pobotoken: *const OBO_TOKEN, dwsetupflags: u32, dwupgradefrombuildno: u32, pszwanswerfile: windows_core::PCWSTR, pszwanswersections: windows_core::PCWSTR, ppnccitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgClassSetup_Impl::Install(this, core::mem::transmute(&pszwinfid), core::mem::transmute_copy(&pobotoken), core::mem::transmute_copy(&dwsetupflags), core::mem::transmute_copy(&dwupgradefrombuildno), core::mem::transmute(&pszwanswerfile), core::mem::transmute(&pszwanswersections), core::mem::transmute_copy(&ppnccitem)).into()
+            }
+        }
+        unsafe extern "system" fn DeInstall<Identity: windows_core::IUnknownImpl + INetCfgClassSetup_Impl>(this: *mut core::ffi::c_void, pcomponent: *mut core::ffi::c_void, pobotoken: *const OBO_TOKEN, pmszwrefs: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgClassSetup_Impl::DeInstall(this, core::mem::transmute_copy(&pcomponent), core::mem::transmute_copy(&pobotoken), core::mem::transmute_copy(&pmszwrefs)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SelectAndInstall: SelectAndInstall::<Identity>,
+            Install: Install::<Identity>,
+            DeInstall: DeInstall::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for INetCfgClassSetup {}
 windows_core::imp::define_interface!(INetCfgClassSetup2, INetCfgClassSetup2_Vtbl, 0xc0e8aea0_306e_11d1_aacf_00805fc1270e);
 impl core::ops::Deref for INetCfgClassSetup2 {
@@ -3191,6 +3522,23 @@ impl INetCfgClassSetup2_Vtbl {
         iid == &<INetCfgClassSetup2 as windows_core::Interface>::IID || iid == &<INetCfgClassSetup as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INetCfgClassSetup2_Impl> windows_core::imp::TearOffVtable<Identity> for INetCfgClassSetup2_Vtbl
+where
+    INetCfgClassSetup_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn UpdateNonEnumeratedComponent<Identity: windows_core::IUnknownImpl + INetCfgClassSetup2_Impl>(this: *mut core::ffi::c_void, picomp: *mut core::ffi::c_void, dwsetupflags: u32, dwupgradefrombuildno: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgClassSetup2_Impl::UpdateNonEnumeratedComponent(this, core::mem::transmute_copy(&picomp), core::mem::transmute_copy(&dwsetupflags), core::mem::transmute_copy(&dwupgradefrombuildno)).into()
+            }
+        }
+        Self {
+            base__: <INetCfgClassSetup_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            UpdateNonEnumeratedComponent: UpdateNonEnumeratedComponent::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for INetCfgClassSetup2 {}
 windows_core::imp::define_interface!(INetCfgComponent, INetCfgComponent_Vtbl, 0xc0e8ae99_306e_11d1_aacf_00805fc1270e);
 windows_core::imp::interface_hierarchy!(INetCfgComponent, windows_core::IUnknown);
@@ -3388,6 +3736,113 @@ impl INetCfgComponent_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Registry")]
+impl<Identity: windows_core::IUnknownImpl + INetCfgComponent_Impl> windows_core::imp::TearOffVtable<Identity> for INetCfgComponent_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDisplayName<Identity: windows_core::IUnknownImpl + INetCfgComponent_Impl>(this: *mut core::ffi::c_void, ppszwdisplayname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponent_Impl::GetDisplayName(this, core::mem::transmute_copy(&ppszwdisplayname)).into()
+            }
+        }
+        unsafe extern "system" fn SetDisplayName<Identity: windows_core::IUnknownImpl + INetCfgComponent_Impl>(this: *mut core::ffi::c_void, pszwdisplayname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponent_Impl::SetDisplayName(this, core::mem::transmute(&pszwdisplayname)).into()
+            }
+        }
+        unsafe extern "system" fn GetHelpText<Identity: windows_core::IUnknownImpl + INetCfgComponent_Impl>(this: *mut core::ffi::c_void, pszwhelptext: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponent_Impl::GetHelpText(this, core::mem::transmute_copy(&pszwhelptext)).into()
+            }
+        }
+        unsafe extern "system" fn GetId<Identity: windows_core::IUnknownImpl + INetCfgComponent_Impl>(this: *mut core::ffi::c_void, ppszwid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponent_Impl::GetId(this, core::mem::transmute_copy(&ppszwid)).into()
+            }
+        }
+        unsafe extern "system" fn GetCharacteristics<Identity: windows_core::IUnknownImpl + INetCfgComponent_Impl>(this: *mut core::ffi::c_void, pdwcharacteristics: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INetCfgComponent_Impl::GetCharacteristics(this) {
+                    Ok(ok__) => {
+                        pdwcharacteristics.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetInstanceGuid<Identity: windows_core::IUnknownImpl + INetCfgComponent_Impl>(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponent_Impl::GetInstanceGuid(this, core::mem::transmute_copy(&pguid)).into()
+            }
+        }
+        unsafe extern "system" fn GetPnpDevNodeId<Identity: windows_core::IUnknownImpl + INetCfgComponent_Impl>(this: *mut core::ffi::c_void, ppszwdevnodeid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponent_Impl::GetPnpDevNodeId(this, core::mem::transmute_copy(&ppszwdevnodeid)).into()
+            }
+        }
+        unsafe extern "system" fn GetClassGuid<Identity: windows_core::IUnknownImpl + INetCfgComponent_Impl>(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponent_Impl::GetClassGuid(this, core::mem::transmute_copy(&pguid)).into()
+            }
+        }
+        unsafe extern "system" fn GetBindName<Identity: windows_core::IUnknownImpl + INetCfgComponent_Impl>(this: *mut core::ffi::c_void, ppszwbindname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponent_Impl::GetBindName(this, core::mem::transmute_copy(&ppszwbindname)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceStatus<Identity: windows_core::IUnknownImpl + INetCfgComponent_Impl>(this: *mut core::ffi::c_void, pulstatus: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INetCfgComponent_Impl::GetDeviceStatus(this) {
+                    Ok(ok__) => {
+                        pulstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OpenParamKey<Identity: windows_core::IUnknownImpl + INetCfgComponent_Impl>(this: *mut core::ffi::c_void, phkey: *mut super::super::System::Registry::HKEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponent_Impl::OpenParamKey(this, core::mem::transmute_copy(&phkey)).into()
+            }
+        }
+        unsafe extern "system" fn RaisePropertyUi<Identity: windows_core::IUnknownImpl + INetCfgComponent_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, dwflags: u32, punkcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponent_Impl::RaisePropertyUi(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&punkcontext)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDisplayName: GetDisplayName::<Identity>,
+            SetDisplayName: SetDisplayName::<Identity>,
+            GetHelpText: GetHelpText::<Identity>,
+            GetId: GetId::<Identity>,
+            GetCharacteristics: GetCharacteristics::<Identity>,
+            GetInstanceGuid: GetInstanceGuid::<Identity>,
+            GetPnpDevNodeId: GetPnpDevNodeId::<Identity>,
+            GetClassGuid: GetClassGuid::<Identity>,
+            GetBindName: GetBindName::<Identity>,
+            GetDeviceStatus: GetDeviceStatus::<Identity>,
+            OpenParamKey: OpenParamKey::<Identity>,
+            RaisePropertyUi: RaisePropertyUi::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Registry")]
 impl windows_core::RuntimeName for INetCfgComponent {}
 windows_core::imp::define_interface!(INetCfgComponentBindings, INetCfgComponentBindings_Vtbl, 0xc0e8ae9e_306e_11d1_aacf_00805fc1270e);
 windows_core::imp::interface_hierarchy!(INetCfgComponentBindings, windows_core::IUnknown);
@@ -3495,39 +3950,105 @@ impl INetCfgComponentBindings_Vtbl {
                 INetCfgComponentBindings_Impl::IsBindableTo(this, core::mem::transmute_copy(&pnccitem)).into()
             }
         }
-        unsafe extern "system" fn EnumBindingPaths<Identity: INetCfgComponentBindings_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwflags: u32, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn EnumBindingPaths<Identity: INetCfgComponentBindings_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwflags: u32, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                INetCfgComponentBindings_Impl::EnumBindingPaths(this, core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&ppienum)).into()
+            }
+        }
+        unsafe extern "system" fn MoveBefore<Identity: INetCfgComponentBindings_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pncbitemsrc: *mut core::ffi::c_void, pncbitemdest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                INetCfgComponentBindings_Impl::MoveBefore(this, core::mem::transmute_copy(&pncbitemsrc), core::mem::transmute_copy(&pncbitemdest)).into()
+            }
+        }
+        unsafe extern "system" fn MoveAfter<Identity: INetCfgComponentBindings_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pncbitemsrc: *mut core::ffi::c_void, pncbitemdest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                INetCfgComponentBindings_Impl::MoveAfter(this, core::mem::transmute_copy(&pncbitemsrc), core::mem::transmute_copy(&pncbitemdest)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            BindTo: BindTo::<Identity, OFFSET>,
+            UnbindFrom: UnbindFrom::<Identity, OFFSET>,
+            SupportsBindingInterface: SupportsBindingInterface::<Identity, OFFSET>,
+            IsBoundTo: IsBoundTo::<Identity, OFFSET>,
+            IsBindableTo: IsBindableTo::<Identity, OFFSET>,
+            EnumBindingPaths: EnumBindingPaths::<Identity, OFFSET>,
+            MoveBefore: MoveBefore::<Identity, OFFSET>,
+            MoveAfter: MoveAfter::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetCfgComponentBindings as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + INetCfgComponentBindings_Impl> windows_core::imp::TearOffVtable<Identity> for INetCfgComponentBindings_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BindTo<Identity: windows_core::IUnknownImpl + INetCfgComponentBindings_Impl>(this: *mut core::ffi::c_void, pnccitem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentBindings_Impl::BindTo(this, core::mem::transmute_copy(&pnccitem)).into()
+            }
+        }
+        unsafe extern "system" fn UnbindFrom<Identity: windows_core::IUnknownImpl + INetCfgComponentBindings_Impl>(this: *mut core::ffi::c_void, pnccitem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentBindings_Impl::UnbindFrom(this, core::mem::transmute_copy(&pnccitem)).into()
+            }
+        }
+        unsafe extern "system" fn SupportsBindingInterface<Identity: windows_core::IUnknownImpl + INetCfgComponentBindings_Impl>(this: *mut core::ffi::c_void, dwflags: u32, pszwinterfacename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentBindings_Impl::SupportsBindingInterface(this, core::mem::transmute_copy(&dwflags), core::mem::transmute(&pszwinterfacename)).into()
+            }
+        }
+        unsafe extern "system" fn IsBoundTo<Identity: windows_core::IUnknownImpl + INetCfgComponentBindings_Impl>(this: *mut core::ffi::c_void, pnccitem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentBindings_Impl::IsBoundTo(this, core::mem::transmute_copy(&pnccitem)).into()
+            }
+        }
+        unsafe extern "system" fn IsBindableTo<Identity: windows_core::IUnknownImpl + INetCfgComponentBindings_Impl>(this: *mut core::ffi::c_void, pnccitem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentBindings_Impl::IsBindableTo(this, core::mem::transmute_copy(&pnccitem)).into()
+            }
+        }
+        unsafe extern "system" fn EnumBindingPaths<Identity: windows_core::IUnknownImpl + INetCfgComponentBindings_Impl>(this: *mut core::ffi::c_void, dwflags: u32, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 INetCfgComponentBindings_Impl::EnumBindingPaths(this, core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&ppienum)).into()
             }
         }
-        unsafe extern "system" fn MoveBefore<Identity: INetCfgComponentBindings_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pncbitemsrc: *mut core::ffi::c_void, pncbitemdest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn MoveBefore<Identity: windows_core::IUnknownImpl + INetCfgComponentBindings_Impl>(this: *mut core::ffi::c_void, pncbitemsrc: *mut core::ffi::c_void, pncbitemdest: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 INetCfgComponentBindings_Impl::MoveBefore(this, core::mem::transmute_copy(&pncbitemsrc), core::mem::transmute_copy(&pncbitemdest)).into()
             }
         }
-        unsafe extern "system" fn MoveAfter<Identity: INetCfgComponentBindings_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pncbitemsrc: *mut core::ffi::c_void, pncbitemdest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn MoveAfter<Identity: windows_core::IUnknownImpl + INetCfgComponentBindings_Impl>(this: *mut core::ffi::c_void, pncbitemsrc: *mut core::ffi::c_void, pncbitemdest: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 INetCfgComponentBindings_Impl::MoveAfter(this, core::mem::transmute_copy(&pncbitemsrc), core::mem::transmute_copy(&pncbitemdest)).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            BindTo: BindTo::<Identity, OFFSET>,
-            UnbindFrom: UnbindFrom::<Identity, OFFSET>,
-            SupportsBindingInterface: SupportsBindingInterface::<Identity, OFFSET>,
-            IsBoundTo: IsBoundTo::<Identity, OFFSET>,
-            IsBindableTo: IsBindableTo::<Identity, OFFSET>,
-            EnumBindingPaths: EnumBindingPaths::<Identity, OFFSET>,
-            MoveBefore: MoveBefore::<Identity, OFFSET>,
-            MoveAfter: MoveAfter::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            BindTo: BindTo::<Identity>,
+            UnbindFrom: UnbindFrom::<Identity>,
+            SupportsBindingInterface: SupportsBindingInterface::<Identity>,
+            IsBoundTo: IsBoundTo::<Identity>,
+            IsBindableTo: IsBindableTo::<Identity>,
+            EnumBindingPaths: EnumBindingPaths::<Identity>,
+            MoveBefore: MoveBefore::<Identity>,
+            MoveAfter: MoveAfter::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<INetCfgComponentBindings as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for INetCfgComponentBindings {}
 windows_core::imp::define_interface!(INetCfgComponentControl, INetCfgComponentControl_Vtbl, 0x932238df_bea1_11d0_9298_00c04fc99dcf);
@@ -3606,6 +4127,44 @@ impl INetCfgComponentControl_Vtbl {
         iid == &<INetCfgComponentControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INetCfgComponentControl_Impl> windows_core::imp::TearOffVtable<Identity> for INetCfgComponentControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + INetCfgComponentControl_Impl>(this: *mut core::ffi::c_void, picomp: *mut core::ffi::c_void, pinetcfg: *mut core::ffi::c_void, finstalling: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentControl_Impl::Initialize(this, core::mem::transmute_copy(&picomp), core::mem::transmute_copy(&pinetcfg), core::mem::transmute_copy(&finstalling)).into()
+            }
+        }
+        unsafe extern "system" fn ApplyRegistryChanges<Identity: windows_core::IUnknownImpl + INetCfgComponentControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentControl_Impl::ApplyRegistryChanges(this).into()
+            }
+        }
+        unsafe extern "system" fn ApplyPnpChanges<Identity: windows_core::IUnknownImpl + INetCfgComponentControl_Impl>(this: *mut core::ffi::c_void, picallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentControl_Impl::ApplyPnpChanges(this, core::mem::transmute_copy(&picallback)).into()
+            }
+        }
+        unsafe extern "system" fn CancelChanges<Identity: windows_core::IUnknownImpl + INetCfgComponentControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentControl_Impl::CancelChanges(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            ApplyRegistryChanges: ApplyRegistryChanges::<Identity>,
+            ApplyPnpChanges: ApplyPnpChanges::<Identity>,
+            CancelChanges: CancelChanges::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for INetCfgComponentControl {}
 windows_core::imp::define_interface!(INetCfgComponentNotifyBinding, INetCfgComponentNotifyBinding_Vtbl, 0x932238e1_bea1_11d0_9298_00c04fc99dcf);
 windows_core::imp::interface_hierarchy!(INetCfgComponentNotifyBinding, windows_core::IUnknown);
@@ -3658,6 +4217,30 @@ impl INetCfgComponentNotifyBinding_Vtbl {
         iid == &<INetCfgComponentNotifyBinding as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INetCfgComponentNotifyBinding_Impl> windows_core::imp::TearOffVtable<Identity> for INetCfgComponentNotifyBinding_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryBindingPath<Identity: windows_core::IUnknownImpl + INetCfgComponentNotifyBinding_Impl>(this: *mut core::ffi::c_void, dwchangeflag: u32, pipath: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentNotifyBinding_Impl::QueryBindingPath(this, core::mem::transmute_copy(&dwchangeflag), core::mem::transmute_copy(&pipath)).into()
+            }
+        }
+        unsafe extern "system" fn NotifyBindingPath<Identity: windows_core::IUnknownImpl + INetCfgComponentNotifyBinding_Impl>(this: *mut core::ffi::c_void, dwchangeflag: u32, pipath: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentNotifyBinding_Impl::NotifyBindingPath(this, core::mem::transmute_copy(&dwchangeflag), core::mem::transmute_copy(&pipath)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryBindingPath: QueryBindingPath::<Identity>,
+            NotifyBindingPath: NotifyBindingPath::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for INetCfgComponentNotifyBinding {}
 windows_core::imp::define_interface!(INetCfgComponentNotifyGlobal, INetCfgComponentNotifyGlobal_Vtbl, 0x932238e2_bea1_11d0_9298_00c04fc99dcf);
 windows_core::imp::interface_hierarchy!(INetCfgComponentNotifyGlobal, windows_core::IUnknown);
@@ -3746,6 +4329,50 @@ impl INetCfgComponentNotifyGlobal_Vtbl {
         iid == &<INetCfgComponentNotifyGlobal as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INetCfgComponentNotifyGlobal_Impl> windows_core::imp::TearOffVtable<Identity> for INetCfgComponentNotifyGlobal_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSupportedNotifications<Identity: windows_core::IUnknownImpl + INetCfgComponentNotifyGlobal_Impl>(this: *mut core::ffi::c_void, dwnotifications: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INetCfgComponentNotifyGlobal_Impl::GetSupportedNotifications(this) {
+                    Ok(ok__) => {
+                        dwnotifications.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SysQueryBindingPath<Identity: windows_core::IUnknownImpl + INetCfgComponentNotifyGlobal_Impl>(this: *mut core::ffi::c_void, dwchangeflag: u32, pipath: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentNotifyGlobal_Impl::SysQueryBindingPath(this, core::mem::transmute_copy(&dwchangeflag), core::mem::transmute_copy(&pipath)).into()
+            }
+        }
+        unsafe extern "system" fn SysNotifyBindingPath<Identity: windows_core::IUnknownImpl + INetCfgComponentNotifyGlobal_Impl>(this: *mut core::ffi::c_void, dwchangeflag: u32, pipath: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentNotifyGlobal_Impl::SysNotifyBindingPath(this, core::mem::transmute_copy(&dwchangeflag), core::mem::transmute_copy(&pipath)).into()
+            }
+        }
+        unsafe extern "system" fn SysNotifyComponent<Identity: windows_core::IUnknownImpl + INetCfgComponentNotifyGlobal_Impl>(this: *mut core::ffi::c_void, dwchangeflag: u32, picomp: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentNotifyGlobal_Impl::SysNotifyComponent(this, core::mem::transmute_copy(&dwchangeflag), core::mem::transmute_copy(&picomp)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSupportedNotifications: GetSupportedNotifications::<Identity>,
+            SysQueryBindingPath: SysQueryBindingPath::<Identity>,
+            SysNotifyBindingPath: SysNotifyBindingPath::<Identity>,
+            SysNotifyComponent: SysNotifyComponent::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for INetCfgComponentNotifyGlobal {}
 windows_core::imp::define_interface!(INetCfgComponentPropertyUi, INetCfgComponentPropertyUi_Vtbl, 0x932238e0_bea1_11d0_9298_00c04fc99dcf);
 windows_core::imp::interface_hierarchy!(INetCfgComponentPropertyUi, windows_core::IUnknown);
@@ -3846,6 +4473,58 @@ impl INetCfgComponentPropertyUi_Vtbl {
         iid == &<INetCfgComponentPropertyUi as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INetCfgComponentPropertyUi_Impl> windows_core::imp::TearOffVtable<Identity> for INetCfgComponentPropertyUi_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryPropertyUi<Identity: windows_core::IUnknownImpl + INetCfgComponentPropertyUi_Impl>(this: *mut core::ffi::c_void, punkreserved: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentPropertyUi_Impl::QueryPropertyUi(this, core::mem::transmute_copy(&punkreserved)).into()
+            }
+        }
+        unsafe extern "system" fn SetContext<Identity: windows_core::IUnknownImpl + INetCfgComponentPropertyUi_Impl>(this: *mut core::ffi::c_void, punkreserved: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentPropertyUi_Impl::SetContext(this, core::mem::transmute_copy(&punkreserved)).into()
+            }
+        }
+        unsafe extern "system" fn MergePropPages<Identity: windows_core::IUnknownImpl + INetCfgComponentPropertyUi_Impl>(this: *mut core::ffi::c_void, pdwdefpages: *mut u32, pahpspprivate: *mut *mut u8, pcpages: *mut u32, hwndparent: super::super::Foundation::HWND, pszstartpage: *const windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentPropertyUi_Impl::MergePropPages(this, core::mem::transmute_copy(&pdwdefpages), core::mem::transmute_copy(&pahpspprivate), core::mem::transmute_copy(&pcpages), core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&pszstartpage)).into()
+            }
+        }
+        unsafe extern "system" fn ValidateProperties<Identity: windows_core::IUnknownImpl + INetCfgComponentPropertyUi_Impl>(this: *mut core::ffi::c_void, hwndsheet: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentPropertyUi_Impl::ValidateProperties(this, core::mem::transmute_copy(&hwndsheet)).into()
+            }
+        }
+        unsafe extern "system" fn ApplyProperties<Identity: windows_core::IUnknownImpl + INetCfgComponentPropertyUi_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentPropertyUi_Impl::ApplyProperties(this).into()
+            }
+        }
+        unsafe extern "system" fn CancelProperties<Identity: windows_core::IUnknownImpl + INetCfgComponentPropertyUi_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentPropertyUi_Impl::CancelProperties(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryPropertyUi: QueryPropertyUi::<Identity>,
+            SetContext: SetContext::<Identity>,
+            MergePropPages: MergePropPages::<Identity>,
+            ValidateProperties: ValidateProperties::<Identity>,
+            ApplyProperties: ApplyProperties::<Identity>,
+            CancelProperties: CancelProperties::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for INetCfgComponentPropertyUi {}
 windows_core::imp::define_interface!(INetCfgComponentSetup, INetCfgComponentSetup_Vtbl, 0x932238e3_bea1_11d0_9298_00c04fc99dcf);
 windows_core::imp::interface_hierarchy!(INetCfgComponentSetup, windows_core::IUnknown);
@@ -3920,6 +4599,44 @@ impl INetCfgComponentSetup_Vtbl {
         iid == &<INetCfgComponentSetup as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INetCfgComponentSetup_Impl> windows_core::imp::TearOffVtable<Identity> for INetCfgComponentSetup_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Install<Identity: windows_core::IUnknownImpl + INetCfgComponentSetup_Impl>(this: *mut core::ffi::c_void, dwsetupflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentSetup_Impl::Install(this, core::mem::transmute_copy(&dwsetupflags)).into()
+            }
+        }
+        unsafe extern "system" fn Upgrade<Identity: windows_core::IUnknownImpl + INetCfgComponentSetup_Impl>(this: *mut core::ffi::c_void, dwsetupflags: u32, dwupgradefombuildno: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentSetup_Impl::Upgrade(this, core::mem::transmute_copy(&dwsetupflags), core::mem::transmute_copy(&dwupgradefombuildno)).into()
+            }
+        }
+        unsafe extern "system" fn ReadAnswerFile<Identity: windows_core::IUnknownImpl + INetCfgComponentSetup_Impl>(this: *mut core::ffi::c_void, pszwanswerfile: windows_core::PCWSTR, pszwanswersections: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentSetup_Impl::ReadAnswerFile(this, core::mem::transmute(&pszwanswerfile), core::mem::transmute(&pszwanswersections)).into()
+            }
+        }
+        unsafe extern "system" fn Removing<Identity: windows_core::IUnknownImpl + INetCfgComponentSetup_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentSetup_Impl::Removing(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Install: Install::<Identity>,
+            Upgrade: Upgrade::<Identity>,
+            ReadAnswerFile: ReadAnswerFile::<Identity>,
+            Removing: Removing::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for INetCfgComponentSetup {}
 windows_core::imp::define_interface!(INetCfgComponentSysPrep, INetCfgComponentSysPrep_Vtbl, 0xc0e8ae9a_306e_11d1_aacf_00805fc1270e);
 windows_core::imp::interface_hierarchy!(INetCfgComponentSysPrep, windows_core::IUnknown);
@@ -3974,6 +4691,30 @@ impl INetCfgComponentSysPrep_Vtbl {
         iid == &<INetCfgComponentSysPrep as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INetCfgComponentSysPrep_Impl> windows_core::imp::TearOffVtable<Identity> for INetCfgComponentSysPrep_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SaveAdapterParameters<Identity: windows_core::IUnknownImpl + INetCfgComponentSysPrep_Impl>(this: *mut core::ffi::c_void, pncsp: *mut core::ffi::c_void, pszwanswersections: windows_core::PCWSTR, padapterinstanceguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentSysPrep_Impl::SaveAdapterParameters(this, core::mem::transmute_copy(&pncsp), core::mem::transmute(&pszwanswersections), core::mem::transmute_copy(&padapterinstanceguid)).into()
+            }
+        }
+        unsafe extern "system" fn RestoreAdapterParameters<Identity: windows_core::IUnknownImpl + INetCfgComponentSysPrep_Impl>(this: *mut core::ffi::c_void, pszwanswerfile: windows_core::PCWSTR, pszwanswersection: windows_core::PCWSTR, padapterinstanceguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentSysPrep_Impl::RestoreAdapterParameters(this, core::mem::transmute(&pszwanswerfile), core::mem::transmute(&pszwanswersection), core::mem::transmute_copy(&padapterinstanceguid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SaveAdapterParameters: SaveAdapterParameters::<Identity>,
+            RestoreAdapterParameters: RestoreAdapterParameters::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for INetCfgComponentSysPrep {}
 windows_core::imp::define_interface!(INetCfgComponentUpperEdge, INetCfgComponentUpperEdge_Vtbl, 0x932238e4_bea1_11d0_9298_00c04fc99dcf);
 windows_core::imp::interface_hierarchy!(INetCfgComponentUpperEdge, windows_core::IUnknown);
@@ -4041,6 +4782,37 @@ impl INetCfgComponentUpperEdge_Vtbl {
         iid == &<INetCfgComponentUpperEdge as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INetCfgComponentUpperEdge_Impl> windows_core::imp::TearOffVtable<Identity> for INetCfgComponentUpperEdge_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetInterfaceIdsForAdapter<Identity: windows_core::IUnknownImpl + INetCfgComponentUpperEdge_Impl>(this: *mut core::ffi::c_void, padapter: *mut core::ffi::c_void, pdwnuminterfaces: *mut u32, ppguidinterfaceids: *mut *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentUpperEdge_Impl::GetInterfaceIdsForAdapter(this, core::mem::transmute_copy(&padapter), core::mem::transmute_copy(&pdwnuminterfaces), core::mem::transmute_copy(&ppguidinterfaceids)).into()
+            }
+        }
+        unsafe extern "system" fn AddInterfacesToAdapter<Identity: windows_core::IUnknownImpl + INetCfgComponentUpperEdge_Impl>(this: *mut core::ffi::c_void, padapter: *mut core::ffi::c_void, dwnuminterfaces: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentUpperEdge_Impl::AddInterfacesToAdapter(this, core::mem::transmute_copy(&padapter), core::mem::transmute_copy(&dwnuminterfaces)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveInterfacesFromAdapter<Identity: windows_core::IUnknownImpl + INetCfgComponentUpperEdge_Impl>(this: *mut core::ffi::c_void, padapter: *mut core::ffi::c_void, dwnuminterfaces: u32, pguidinterfaceids: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgComponentUpperEdge_Impl::RemoveInterfacesFromAdapter(this, core::mem::transmute_copy(&padapter), core::mem::transmute_copy(&dwnuminterfaces), core::mem::transmute_copy(&pguidinterfaceids)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetInterfaceIdsForAdapter: GetInterfaceIdsForAdapter::<Identity>,
+            AddInterfacesToAdapter: AddInterfacesToAdapter::<Identity>,
+            RemoveInterfacesFromAdapter: RemoveInterfacesFromAdapter::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for INetCfgComponentUpperEdge {}
 windows_core::imp::define_interface!(INetCfgLock, INetCfgLock_Vtbl, 0xc0e8ae9f_306e_11d1_aacf_00805fc1270e);
 windows_core::imp::interface_hierarchy!(INetCfgLock, windows_core::IUnknown);
@@ -4102,6 +4874,37 @@ impl INetCfgLock_Vtbl {
         iid == &<INetCfgLock as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INetCfgLock_Impl> windows_core::imp::TearOffVtable<Identity> for INetCfgLock_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AcquireWriteLock<Identity: windows_core::IUnknownImpl + INetCfgLock_Impl>(this: *mut core::ffi::c_void, cmstimeout: u32, pszwclientdescription: windows_core::PCWSTR, ppszwclientdescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgLock_Impl::AcquireWriteLock(this, core::mem::transmute_copy(&cmstimeout), core::mem::transmute(&pszwclientdescription), core::mem::transmute_copy(&ppszwclientdescription)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseWriteLock<Identity: windows_core::IUnknownImpl + INetCfgLock_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgLock_Impl::ReleaseWriteLock(this).into()
+            }
+        }
+        unsafe extern "system" fn IsWriteLocked<Identity: windows_core::IUnknownImpl + INetCfgLock_Impl>(this: *mut core::ffi::c_void, ppszwclientdescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgLock_Impl::IsWriteLocked(this, core::mem::transmute_copy(&ppszwclientdescription)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AcquireWriteLock: AcquireWriteLock::<Identity>,
+            ReleaseWriteLock: ReleaseWriteLock::<Identity>,
+            IsWriteLocked: IsWriteLocked::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for INetCfgLock {}
 windows_core::imp::define_interface!(INetCfgPnpReconfigCallback, INetCfgPnpReconfigCallback_Vtbl, 0x8d84bd35_e227_11d2_b700_00a0c98a6a85);
 windows_core::imp::interface_hierarchy!(INetCfgPnpReconfigCallback, windows_core::IUnknown);
@@ -4137,6 +4940,20 @@ impl INetCfgPnpReconfigCallback_Vtbl {
         iid == &<INetCfgPnpReconfigCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INetCfgPnpReconfigCallback_Impl> windows_core::imp::TearOffVtable<Identity> for INetCfgPnpReconfigCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SendPnpReconfig<Identity: windows_core::IUnknownImpl + INetCfgPnpReconfigCallback_Impl>(this: *mut core::ffi::c_void, layer: NCPNP_RECONFIG_LAYER, pszwupper: windows_core::PCWSTR, pszwlower: windows_core::PCWSTR, pvdata: *const core::ffi::c_void, dwsizeofdata: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgPnpReconfigCallback_Impl::SendPnpReconfig(this, core::mem::transmute_copy(&layer), core::mem::transmute(&pszwupper), core::mem::transmute(&pszwlower), core::mem::transmute_copy(&pvdata), core::mem::transmute_copy(&dwsizeofdata)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SendPnpReconfig: SendPnpReconfig::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for INetCfgPnpReconfigCallback {}
 windows_core::imp::define_interface!(INetCfgSysPrep, INetCfgSysPrep_Vtbl, 0xc0e8ae98_306e_11d1_aacf_00805fc1270e);
 windows_core::imp::interface_hierarchy!(INetCfgSysPrep, windows_core::IUnknown);
@@ -4225,6 +5042,44 @@ impl INetCfgSysPrep_Vtbl {
         iid == &<INetCfgSysPrep as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INetCfgSysPrep_Impl> windows_core::imp::TearOffVtable<Identity> for INetCfgSysPrep_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn HrSetupSetFirstDword<Identity: windows_core::IUnknownImpl + INetCfgSysPrep_Impl>(this: *mut core::ffi::c_void, pwszsection: windows_core::PCWSTR, pwszkey: windows_core::PCWSTR, dwvalue: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgSysPrep_Impl::HrSetupSetFirstDword(this, core::mem::transmute(&pwszsection), core::mem::transmute(&pwszkey), core::mem::transmute_copy(&dwvalue)).into()
+            }
+        }
+        unsafe extern "system" fn HrSetupSetFirstString<Identity: windows_core::IUnknownImpl + INetCfgSysPrep_Impl>(this: *mut core::ffi::c_void, pwszsection: windows_core::PCWSTR, pwszkey: windows_core::PCWSTR, pwszvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgSysPrep_Impl::HrSetupSetFirstString(this, core::mem::transmute(&pwszsection), core::mem::transmute(&pwszkey), core::mem::transmute(&pwszvalue)).into()
+            }
+        }
+        unsafe extern "system" fn HrSetupSetFirstStringAsBool<Identity: windows_core::IUnknownImpl + INetCfgSysPrep_Impl>(this: *mut core::ffi::c_void, pwszsection: windows_core::PCWSTR, pwszkey: windows_core::PCWSTR, fvalue: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgSysPrep_Impl::HrSetupSetFirstStringAsBool(this, core::mem::transmute(&pwszsection), core::mem::transmute(&pwszkey), core::mem::transmute_copy(&fvalue)).into()
+            }
+        }
+        unsafe extern "system" fn HrSetupSetFirstMultiSzField<Identity: windows_core::IUnknownImpl + INetCfgSysPrep_Impl>(this: *mut core::ffi::c_void, pwszsection: windows_core::PCWSTR, pwszkey: windows_core::PCWSTR, pmszvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetCfgSysPrep_Impl::HrSetupSetFirstMultiSzField(this, core::mem::transmute(&pwszsection), core::mem::transmute(&pwszkey), core::mem::transmute(&pmszvalue)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            HrSetupSetFirstDword: HrSetupSetFirstDword::<Identity>,
+            HrSetupSetFirstString: HrSetupSetFirstString::<Identity>,
+            HrSetupSetFirstStringAsBool: HrSetupSetFirstStringAsBool::<Identity>,
+            HrSetupSetFirstMultiSzField: HrSetupSetFirstMultiSzField::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for INetCfgSysPrep {}
 windows_core::imp::define_interface!(INetLanConnectionUiInfo, INetLanConnectionUiInfo_Vtbl, 0xc08956a6_1cd3_11d1_b1c5_00805fc1270e);
 windows_core::imp::interface_hierarchy!(INetLanConnectionUiInfo, windows_core::IUnknown);
@@ -4265,6 +5120,26 @@ impl INetLanConnectionUiInfo_Vtbl {
         iid == &<INetLanConnectionUiInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INetLanConnectionUiInfo_Impl> windows_core::imp::TearOffVtable<Identity> for INetLanConnectionUiInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDeviceGuid<Identity: windows_core::IUnknownImpl + INetLanConnectionUiInfo_Impl>(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INetLanConnectionUiInfo_Impl::GetDeviceGuid(this) {
+                    Ok(ok__) => {
+                        pguid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDeviceGuid: GetDeviceGuid::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for INetLanConnectionUiInfo {}
 windows_core::imp::define_interface!(INetRasConnectionIpUiInfo, INetRasConnectionIpUiInfo_Vtbl, 0xfaedcf58_31fe_11d1_aad2_00805fc1270e);
 windows_core::imp::interface_hierarchy!(INetRasConnectionIpUiInfo, windows_core::IUnknown);
@@ -4296,6 +5171,20 @@ impl INetRasConnectionIpUiInfo_Vtbl {
         iid == &<INetRasConnectionIpUiInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INetRasConnectionIpUiInfo_Impl> windows_core::imp::TearOffVtable<Identity> for INetRasConnectionIpUiInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetUiInfo<Identity: windows_core::IUnknownImpl + INetRasConnectionIpUiInfo_Impl>(this: *mut core::ffi::c_void, pinfo: *mut RASCON_IPUI) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetRasConnectionIpUiInfo_Impl::GetUiInfo(this, core::mem::transmute_copy(&pinfo)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetUiInfo: GetUiInfo::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for INetRasConnectionIpUiInfo {}
 pub const IPX_PROTOCOL_BASE: u32 = 131071u32;
 pub const IPX_PROTOCOL_RIP: u32 = 131072u32;
@@ -4364,6 +5253,33 @@ impl IProvisioningDomain_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+impl<Identity: windows_core::IUnknownImpl + IProvisioningDomain_Impl> windows_core::imp::TearOffVtable<Identity> for IProvisioningDomain_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Add<Identity: windows_core::IUnknownImpl + IProvisioningDomain_Impl>(this: *mut core::ffi::c_void, pszwpathtofolder: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IProvisioningDomain_Impl::Add(this, core::mem::transmute(&pszwpathtofolder)).into()
+            }
+        }
+        unsafe extern "system" fn Query<Identity: windows_core::IUnknownImpl + IProvisioningDomain_Impl>(this: *mut core::ffi::c_void, pszwdomain: windows_core::PCWSTR, pszwlanguage: windows_core::PCWSTR, pszwxpathquery: windows_core::PCWSTR, nodes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProvisioningDomain_Impl::Query(this, core::mem::transmute(&pszwdomain), core::mem::transmute(&pszwlanguage), core::mem::transmute(&pszwxpathquery)) {
+                    Ok(ok__) => {
+                        nodes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Add: Add::<Identity>, Query: Query::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
 impl windows_core::RuntimeName for IProvisioningDomain {}
 windows_core::imp::define_interface!(IProvisioningProfileWireless, IProvisioningProfileWireless_Vtbl, 0xc96fbd51_24dd_11d8_89fb_00904b2ea9c6);
 windows_core::imp::interface_hierarchy!(IProvisioningProfileWireless, windows_core::IUnknown);
@@ -4404,6 +5320,26 @@ impl IProvisioningProfileWireless_Vtbl {
         iid == &<IProvisioningProfileWireless as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IProvisioningProfileWireless_Impl> windows_core::imp::TearOffVtable<Identity> for IProvisioningProfileWireless_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateProfile<Identity: windows_core::IUnknownImpl + IProvisioningProfileWireless_Impl>(this: *mut core::ffi::c_void, bstrxmlwirelessconfigprofile: *mut core::ffi::c_void, bstrxmlconnectionconfigprofile: *mut core::ffi::c_void, padapterinstanceguid: *const windows_core::GUID, pulstatus: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProvisioningProfileWireless_Impl::CreateProfile(this, core::mem::transmute(&bstrxmlwirelessconfigprofile), core::mem::transmute(&bstrxmlconnectionconfigprofile), core::mem::transmute_copy(&padapterinstanceguid)) {
+                    Ok(ok__) => {
+                        pulstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateProfile: CreateProfile::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IProvisioningProfileWireless {}
 pub const IR_PROMISCUOUS: u32 = 0u32;
 pub const IR_PROMISCUOUS_MULTICAST: u32 = 1u32;
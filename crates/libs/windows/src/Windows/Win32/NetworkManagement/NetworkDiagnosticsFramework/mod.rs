@@ -251,6 +251,20 @@ impl INetDiagExtensibleHelper_Vtbl {
         iid == &<INetDiagExtensibleHelper as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INetDiagExtensibleHelper_Impl> windows_core::imp::TearOffVtable<Identity> for INetDiagExtensibleHelper_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ResolveAttributes<Identity: windows_core::IUnknownImpl + INetDiagExtensibleHelper_Impl>(this: *mut core::ffi::c_void, celt: u32, rgkeyattributes: *const HELPER_ATTRIBUTE, pcelt: *mut u32, prgmatchvalues: *mut *mut HELPER_ATTRIBUTE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetDiagExtensibleHelper_Impl::ResolveAttributes(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgkeyattributes), core::mem::transmute_copy(&pcelt), core::mem::transmute_copy(&prgmatchvalues)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ResolveAttributes: ResolveAttributes::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for INetDiagExtensibleHelper {}
 windows_core::imp::define_interface!(INetDiagHelper, INetDiagHelper_Vtbl, 0xc0b35746_ebf5_11d8_bbe9_505054503030);
 windows_core::imp::interface_hierarchy!(INetDiagHelper, windows_core::IUnknown);
@@ -522,6 +536,160 @@ impl INetDiagHelper_Vtbl {
         iid == &<INetDiagHelper as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INetDiagHelper_Impl> windows_core::imp::TearOffVtable<Identity> for INetDiagHelper_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + INetDiagHelper_Impl>(this: *mut core::ffi::c_void, celt: u32, rgattributes: *const HELPER_ATTRIBUTE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetDiagHelper_Impl::Initialize(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgattributes)).into()
+            }
+        }
+        unsafe extern "system" fn GetDiagnosticsInfo<Identity: windows_core::IUnknownImpl + INetDiagHelper_Impl>(this: *mut core::ffi::c_void, ppinfo: *mut *mut DiagnosticsInfo) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INetDiagHelper_Impl::GetDiagnosticsInfo(this) {
+                    Ok(ok__) => {
+                        ppinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetKeyAttributes<Identity: windows_core::IUnknownImpl + INetDiagHelper_Impl>(this: *mut core::ffi::c_void, pcelt: *mut u32, pprgattributes: *mut *mut HELPER_ATTRIBUTE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetDiagHelper_Impl::GetKeyAttributes(this, core::mem::transmute_copy(&pcelt), core::mem::transmute_copy(&pprgattributes)).into()
+            }
+        }
+        unsafe extern "system" fn LowHealth<Identity: windows_core::IUnknownImpl + INetDiagHelper_Impl>(this: *mut core::ffi::c_void, pwszinstancedescription: windows_core::PCWSTR, ppwszdescription: *mut windows_core::PWSTR, pdeferredtime: *mut i32, pstatus: *mut DIAGNOSIS_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetDiagHelper_Impl::LowHealth(this, core::mem::transmute(&pwszinstancedescription), core::mem::transmute_copy(&ppwszdescription), core::mem::transmute_copy(&pdeferredtime), core::mem::transmute_copy(&pstatus)).into()
+            }
+        }
+        unsafe extern "system" fn HighUtilization<Identity: windows_core::IUnknownImpl + INetDiagHelper_Impl>(this: *mut core::ffi::c_void, pwszinstancedescription: windows_core::PCWSTR, ppwszdescription: *mut windows_core::PWSTR, pdeferredtime: *mut i32, pstatus: *mut DIAGNOSIS_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetDiagHelper_Impl::HighUtilization(this, core::mem::transmute(&pwszinstancedescription), core::mem::transmute_copy(&ppwszdescription), core::mem::transmute_copy(&pdeferredtime), core::mem::transmute_copy(&pstatus)).into()
+            }
+        }
+        unsafe extern "system" fn GetLowerHypotheses<Identity: windows_core::IUnknownImpl + INetDiagHelper_Impl>(this: *mut core::ffi::c_void, pcelt: *mut u32, pprghypotheses: *mut *mut HYPOTHESIS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetDiagHelper_Impl::GetLowerHypotheses(this, core::mem::transmute_copy(&pcelt), core::mem::transmute_copy(&pprghypotheses)).into()
+            }
+        }
+        unsafe extern "system" fn GetDownStreamHypotheses<Identity: windows_core::IUnknownImpl + INetDiagHelper_Impl>(this: *mut core::ffi::c_void, pcelt: *mut u32, pprghypotheses: *mut *mut HYPOTHESIS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetDiagHelper_Impl::GetDownStreamHypotheses(this, core::mem::transmute_copy(&pcelt), core::mem::transmute_copy(&pprghypotheses)).into()
+            }
+        }
+        unsafe extern "system" fn GetHigherHypotheses<Identity: windows_core::IUnknownImpl + INetDiagHelper_Impl>(this: *mut core::ffi::c_void, pcelt: *mut u32, pprghypotheses: *mut *mut HYPOTHESIS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetDiagHelper_Impl::GetHigherHypotheses(this, core::mem::transmute_copy(&pcelt), core::mem::transmute_copy(&pprghypotheses)).into()
+            }
+        }
+        unsafe extern "system" fn GetUpStreamHypotheses<Identity: windows_core::IUnknownImpl + INetDiagHelper_Impl>(this: *mut core::ffi::c_void, pcelt: *mut u32, pprghypotheses: *mut *mut HYPOTHESIS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetDiagHelper_Impl::GetUpStreamHypotheses(this, core::mem::transmute_copy(&pcelt), core::mem::transmute_copy(&pprghypotheses)).into()
+            }
+        }
+        unsafe extern "system" fn Repair<Identity: windows_core::IUnknownImpl + INetDiagHelper_Impl>(this: *mut core::ffi::c_void, pinfo: *const RepairInfo, pdeferredtime: *mut i32, pstatus: *mut REPAIR_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetDiagHelper_Impl::Repair(this, core::mem::transmute_copy(&pinfo), core::mem::transmute_copy(&pdeferredtime), core::mem::transmute_copy(&pstatus)).into()
+            }
+        }
+        unsafe extern "system" fn Validate<Identity: windows_core::IUnknownImpl + INetDiagHelper_Impl>(this: *mut core::ffi::c_void, problem: PROBLEM_TYPE, pdeferredtime: *mut i32, pstatus: *mut REPAIR_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetDiagHelper_Impl::Validate(this, core::mem::transmute_copy(&problem), core::mem::transmute_copy(&pdeferredtime), core::mem::transmute_copy(&pstatus)).into()
+            }
+        }
+        unsafe extern "system" fn GetRepairInfo<Identity: windows_core::IUnknownImpl + INetDiagHelper_Impl>(this: *mut core::ffi::c_void, problem: PROBLEM_TYPE, pcelt: *mut u32, ppinfo: *mut *mut RepairInfo) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetDiagHelper_Impl::GetRepairInfo(this, core::mem::transmute_copy(&problem), core::mem::transmute_copy(&pcelt), core::mem::transmute_copy(&ppinfo)).into()
+            }
+        }
+        unsafe extern "system" fn GetLifeTime<Identity: windows_core::IUnknownImpl + INetDiagHelper_Impl>(this: *mut core::ffi::c_void, plifetime: *mut LIFE_TIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INetDiagHelper_Impl::GetLifeTime(this) {
+                    Ok(ok__) => {
+                        plifetime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLifeTime<Identity: windows_core::IUnknownImpl + INetDiagHelper_Impl>(this: *mut core::ffi::c_void, lifetime: LIFE_TIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetDiagHelper_Impl::SetLifeTime(this, core::mem::transmute(&lifetime)).into()
+            }
+        }
+        unsafe extern "system" fn GetCacheTime<Identity: windows_core::IUnknownImpl + INetDiagHelper_Impl>(this: *mut core::ffi::c_void, pcachetime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match INetDiagHelper_Impl::GetCacheTime(this) {
+                    Ok(ok__) => {
+                        pcachetime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAttributes<Identity: windows_core::IUnknownImpl + INetDiagHelper_Impl>(this: *mut core::ffi::c_void, pcelt: *mut u32, pprgattributes: *mut *mut HELPER_ATTRIBUTE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetDiagHelper_Impl::GetAttributes(this, core::mem::transmute_copy(&pcelt), core::mem::transmute_copy(&pprgattributes)).into()
+            }
+        }
+        unsafe extern "system" fn Cancel<Identity: windows_core::IUnknownImpl + INetDiagHelper_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetDiagHelper_Impl::Cancel(this).into()
+            }
+        }
+        unsafe extern "system" fn Cleanup<Identity: windows_core::IUnknownImpl + INetDiagHelper_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetDiagHelper_Impl::Cleanup(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            GetDiagnosticsInfo: GetDiagnosticsInfo::<Identity>,
+            GetKeyAttributes: GetKeyAttributes::<Identity>,
+            LowHealth: LowHealth::<Identity>,
+            HighUtilization: HighUtilization::<Identity>,
+            GetLowerHypotheses: GetLowerHypotheses::<Identity>,
+            GetDownStreamHypotheses: GetDownStreamHypotheses::<Identity>,
+            GetHigherHypotheses: GetHigherHypotheses::<Identity>,
+            GetUpStreamHypotheses: GetUpStreamHypotheses::<Identity>,
+            Repair: Repair::<Identity>,
+            Validate: Validate::<Identity>,
+            GetRepairInfo: GetRepairInfo::<Identity>,
+            GetLifeTime: GetLifeTime::<Identity>,
+            SetLifeTime: SetLifeTime::<Identity>,
+            GetCacheTime: GetCacheTime::<Identity>,
+            GetAttributes: GetAttributes::<Identity>,
+            Cancel: Cancel::<Identity>,
+            Cleanup: Cleanup::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for INetDiagHelper {}
 windows_core::imp::define_interface!(INetDiagHelperEx, INetDiagHelperEx_Vtbl, 0x972dab4d_e4e3_4fc6_ae54_5f65ccde4a15);
 windows_core::imp::interface_hierarchy!(INetDiagHelperEx, windows_core::IUnknown);
@@ -583,6 +751,37 @@ impl INetDiagHelperEx_Vtbl {
         iid == &<INetDiagHelperEx as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INetDiagHelperEx_Impl> windows_core::imp::TearOffVtable<Identity> for INetDiagHelperEx_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ReconfirmLowHealth<Identity: windows_core::IUnknownImpl + INetDiagHelperEx_Impl>(this: *mut core::ffi::c_void, celt: u32, presults: *const HypothesisResult, ppwszupdateddescription: *mut windows_core::PWSTR, pupdatedstatus: *mut DIAGNOSIS_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetDiagHelperEx_Impl::ReconfirmLowHealth(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&presults), core::mem::transmute_copy(&ppwszupdateddescription), core::mem::transmute_copy(&pupdatedstatus)).into()
+            }
+        }
+        unsafe extern "system" fn SetUtilities<Identity: windows_core::IUnknownImpl + INetDiagHelperEx_Impl>(this: *mut core::ffi::c_void, putilities: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetDiagHelperEx_Impl::SetUtilities(this, core::mem::transmute_copy(&putilities)).into()
+            }
+        }
+        unsafe extern "system" fn ReproduceFailure<Identity: windows_core::IUnknownImpl + INetDiagHelperEx_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetDiagHelperEx_Impl::ReproduceFailure(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ReconfirmLowHealth: ReconfirmLowHealth::<Identity>,
+            SetUtilities: SetUtilities::<Identity>,
+            ReproduceFailure: ReproduceFailure::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for INetDiagHelperEx {}
 windows_core::imp::define_interface!(INetDiagHelperInfo, INetDiagHelperInfo_Vtbl, 0xc0b35747_ebf5_11d8_bbe9_505054503030);
 windows_core::imp::interface_hierarchy!(INetDiagHelperInfo, windows_core::IUnknown);
@@ -614,6 +813,20 @@ impl INetDiagHelperInfo_Vtbl {
         iid == &<INetDiagHelperInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INetDiagHelperInfo_Impl> windows_core::imp::TearOffVtable<Identity> for INetDiagHelperInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAttributeInfo<Identity: windows_core::IUnknownImpl + INetDiagHelperInfo_Impl>(this: *mut core::ffi::c_void, pcelt: *mut u32, pprgattributeinfos: *mut *mut HelperAttributeInfo) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetDiagHelperInfo_Impl::GetAttributeInfo(this, core::mem::transmute_copy(&pcelt), core::mem::transmute_copy(&pprgattributeinfos)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetAttributeInfo: GetAttributeInfo::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for INetDiagHelperInfo {}
 windows_core::imp::define_interface!(INetDiagHelperUtilFactory, INetDiagHelperUtilFactory_Vtbl, 0x104613fb_bc57_4178_95ba_88809698354a);
 windows_core::imp::interface_hierarchy!(INetDiagHelperUtilFactory, windows_core::IUnknown);
@@ -649,6 +862,23 @@ impl INetDiagHelperUtilFactory_Vtbl {
         iid == &<INetDiagHelperUtilFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INetDiagHelperUtilFactory_Impl> windows_core::imp::TearOffVtable<Identity> for INetDiagHelperUtilFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateUtilityInstance<Identity: windows_core::IUnknownImpl + INetDiagHelperUtilFactory_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetDiagHelperUtilFactory_Impl::CreateUtilityInstance(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvobject)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateUtilityInstance: CreateUtilityInstance::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for INetDiagHelperUtilFactory {}
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
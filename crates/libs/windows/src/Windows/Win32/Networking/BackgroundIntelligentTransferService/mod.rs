@@ -101,6 +101,58 @@ impl AsyncIBackgroundCopyCallback_Vtbl {
         iid == &<AsyncIBackgroundCopyCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + AsyncIBackgroundCopyCallback_Impl> windows_core::imp::TearOffVtable<Identity> for AsyncIBackgroundCopyCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Begin_JobTransferred<Identity: windows_core::IUnknownImpl + AsyncIBackgroundCopyCallback_Impl>(this: *mut core::ffi::c_void, pjob: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIBackgroundCopyCallback_Impl::Begin_JobTransferred(this, core::mem::transmute_copy(&pjob)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_JobTransferred<Identity: windows_core::IUnknownImpl + AsyncIBackgroundCopyCallback_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIBackgroundCopyCallback_Impl::Finish_JobTransferred(this).into()
+            }
+        }
+        unsafe extern "system" fn Begin_JobError<Identity: windows_core::IUnknownImpl + AsyncIBackgroundCopyCallback_Impl>(this: *mut core::ffi::c_void, pjob: *mut core::ffi::c_void, perror: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIBackgroundCopyCallback_Impl::Begin_JobError(this, core::mem::transmute_copy(&pjob), core::mem::transmute_copy(&perror)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_JobError<Identity: windows_core::IUnknownImpl + AsyncIBackgroundCopyCallback_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIBackgroundCopyCallback_Impl::Finish_JobError(this).into()
+            }
+        }
+        unsafe extern "system" fn Begin_JobModification<Identity: windows_core::IUnknownImpl + AsyncIBackgroundCopyCallback_Impl>(this: *mut core::ffi::c_void, pjob: *mut core::ffi::c_void, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIBackgroundCopyCallback_Impl::Begin_JobModification(this, core::mem::transmute_copy(&pjob), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_JobModification<Identity: windows_core::IUnknownImpl + AsyncIBackgroundCopyCallback_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIBackgroundCopyCallback_Impl::Finish_JobModification(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Begin_JobTransferred: Begin_JobTransferred::<Identity>,
+            Finish_JobTransferred: Finish_JobTransferred::<Identity>,
+            Begin_JobError: Begin_JobError::<Identity>,
+            Finish_JobError: Finish_JobError::<Identity>,
+            Begin_JobModification: Begin_JobModification::<Identity>,
+            Finish_JobModification: Finish_JobModification::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for AsyncIBackgroundCopyCallback {}
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -608,6 +660,57 @@ impl IBITSExtensionSetup_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IBITSExtensionSetup_Impl> windows_core::imp::TearOffVtable<Identity> for IBITSExtensionSetup_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnableBITSUploads<Identity: windows_core::IUnknownImpl + IBITSExtensionSetup_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBITSExtensionSetup_Impl::EnableBITSUploads(this).into()
+            }
+        }
+        unsafe extern "system" fn DisableBITSUploads<Identity: windows_core::IUnknownImpl + IBITSExtensionSetup_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBITSExtensionSetup_Impl::DisableBITSUploads(this).into()
+            }
+        }
+        unsafe extern "system" fn GetCleanupTaskName<Identity: windows_core::IUnknownImpl + IBITSExtensionSetup_Impl>(this: *mut core::ffi::c_void, ptaskname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBITSExtensionSetup_Impl::GetCleanupTaskName(this) {
+                    Ok(ok__) => {
+                        ptaskname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCleanupTask<Identity: windows_core::IUnknownImpl + IBITSExtensionSetup_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBITSExtensionSetup_Impl::GetCleanupTask(this, core::mem::transmute_copy(&riid)) {
+                    Ok(ok__) => {
+                        ppunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnableBITSUploads: EnableBITSUploads::<Identity>,
+            DisableBITSUploads: DisableBITSUploads::<Identity>,
+            GetCleanupTaskName: GetCleanupTaskName::<Identity>,
+            GetCleanupTask: GetCleanupTask::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IBITSExtensionSetup {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IBITSExtensionSetupFactory, IBITSExtensionSetupFactory_Vtbl, 0xd5d2d542_5503_4e64_8b48_72ef91a32ee1);
@@ -662,6 +765,27 @@ impl IBITSExtensionSetupFactory_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IBITSExtensionSetupFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IBITSExtensionSetupFactory_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetObject<Identity: windows_core::IUnknownImpl + IBITSExtensionSetupFactory_Impl>(this: *mut core::ffi::c_void, path: *mut core::ffi::c_void, ppextensionsetup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBITSExtensionSetupFactory_Impl::GetObject(this, core::mem::transmute(&path)) {
+                    Ok(ok__) => {
+                        ppextensionsetup.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetObject: GetObject::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IBITSExtensionSetupFactory {}
 windows_core::imp::define_interface!(IBackgroundCopyCallback, IBackgroundCopyCallback_Vtbl, 0x97ea99c7_0186_4ad4_8df9_c5b4e0ed6b22);
 windows_core::imp::interface_hierarchy!(IBackgroundCopyCallback, windows_core::IUnknown);
@@ -730,6 +854,37 @@ impl IBackgroundCopyCallback_Vtbl {
         iid == &<IBackgroundCopyCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn JobTransferred<Identity: windows_core::IUnknownImpl + IBackgroundCopyCallback_Impl>(this: *mut core::ffi::c_void, pjob: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyCallback_Impl::JobTransferred(this, core::mem::transmute_copy(&pjob)).into()
+            }
+        }
+        unsafe extern "system" fn JobError<Identity: windows_core::IUnknownImpl + IBackgroundCopyCallback_Impl>(this: *mut core::ffi::c_void, pjob: *mut core::ffi::c_void, perror: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyCallback_Impl::JobError(this, core::mem::transmute_copy(&pjob), core::mem::transmute_copy(&perror)).into()
+            }
+        }
+        unsafe extern "system" fn JobModification<Identity: windows_core::IUnknownImpl + IBackgroundCopyCallback_Impl>(this: *mut core::ffi::c_void, pjob: *mut core::ffi::c_void, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyCallback_Impl::JobModification(this, core::mem::transmute_copy(&pjob), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            JobTransferred: JobTransferred::<Identity>,
+            JobError: JobError::<Identity>,
+            JobModification: JobModification::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBackgroundCopyCallback {}
 windows_core::imp::define_interface!(IBackgroundCopyCallback1, IBackgroundCopyCallback1_Vtbl, 0x084f6593_3800_4e08_9b59_99fa59addf82);
 windows_core::imp::interface_hierarchy!(IBackgroundCopyCallback1, windows_core::IUnknown);
@@ -800,6 +955,37 @@ impl IBackgroundCopyCallback1_Vtbl {
         iid == &<IBackgroundCopyCallback1 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyCallback1_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyCallback1_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnStatus<Identity: windows_core::IUnknownImpl + IBackgroundCopyCallback1_Impl>(this: *mut core::ffi::c_void, pgroup: *mut core::ffi::c_void, pjob: *mut core::ffi::c_void, dwfileindex: u32, dwstatus: u32, dwnumofretries: u32, dwwin32result: u32, dwtransportresult: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyCallback1_Impl::OnStatus(this, core::mem::transmute_copy(&pgroup), core::mem::transmute_copy(&pjob), core::mem::transmute_copy(&dwfileindex), core::mem::transmute_copy(&dwstatus), core::mem::transmute_copy(&dwnumofretries), core::mem::transmute_copy(&dwwin32result), core::mem::transmute_copy(&dwtransportresult)).into()
+            }
+        }
+        unsafe extern "system" fn OnProgress<Identity: windows_core::IUnknownImpl + IBackgroundCopyCallback1_Impl>(this: *mut core::ffi::c_void, progresstype: u32, pgroup: *mut core::ffi::c_void, pjob: *mut core::ffi::c_void, dwfileindex: u32, dwprogressvalue: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyCallback1_Impl::OnProgress(this, core::mem::transmute_copy(&progresstype), core::mem::transmute_copy(&pgroup), core::mem::transmute_copy(&pjob), core::mem::transmute_copy(&dwfileindex), core::mem::transmute_copy(&dwprogressvalue)).into()
+            }
+        }
+        unsafe extern "system" fn OnProgressEx<Identity: windows_core::IUnknownImpl + IBackgroundCopyCallback1_Impl>(this: *mut core::ffi::c_void, progresstype: u32, pgroup: *mut core::ffi::c_void, pjob: *mut core::ffi::c_void, dwfileindex: u32, dwprogressvalue: u32, dwbytearraysize: u32, pbyte: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyCallback1_Impl::OnProgressEx(this, core::mem::transmute_copy(&progresstype), core::mem::transmute_copy(&pgroup), core::mem::transmute_copy(&pjob), core::mem::transmute_copy(&dwfileindex), core::mem::transmute_copy(&dwprogressvalue), core::mem::transmute_copy(&dwbytearraysize), core::mem::transmute_copy(&pbyte)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnStatus: OnStatus::<Identity>,
+            OnProgress: OnProgress::<Identity>,
+            OnProgressEx: OnProgressEx::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBackgroundCopyCallback1 {}
 windows_core::imp::define_interface!(IBackgroundCopyCallback2, IBackgroundCopyCallback2_Vtbl, 0x659cdeac_489e_11d9_a9cd_000d56965251);
 impl core::ops::Deref for IBackgroundCopyCallback2 {
@@ -841,6 +1027,20 @@ impl IBackgroundCopyCallback2_Vtbl {
         iid == &<IBackgroundCopyCallback2 as windows_core::Interface>::IID || iid == &<IBackgroundCopyCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyCallback2_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyCallback2_Vtbl
+where
+    IBackgroundCopyCallback_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FileTransferred<Identity: windows_core::IUnknownImpl + IBackgroundCopyCallback2_Impl>(this: *mut core::ffi::c_void, pjob: *mut core::ffi::c_void, pfile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyCallback2_Impl::FileTransferred(this, core::mem::transmute_copy(&pjob), core::mem::transmute_copy(&pfile)).into()
+            }
+        }
+        Self { base__: <IBackgroundCopyCallback_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, FileTransferred: FileTransferred::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IBackgroundCopyCallback2 {}
 windows_core::imp::define_interface!(IBackgroundCopyCallback3, IBackgroundCopyCallback3_Vtbl, 0x98c97bd2_e32b_4ad8_a528_95fd8b16bd42);
 impl core::ops::Deref for IBackgroundCopyCallback3 {
@@ -882,6 +1082,23 @@ impl IBackgroundCopyCallback3_Vtbl {
         iid == &<IBackgroundCopyCallback3 as windows_core::Interface>::IID || iid == &<IBackgroundCopyCallback as windows_core::Interface>::IID || iid == &<IBackgroundCopyCallback2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyCallback3_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyCallback3_Vtbl
+where
+    IBackgroundCopyCallback2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FileRangesTransferred<Identity: windows_core::IUnknownImpl + IBackgroundCopyCallback3_Impl>(this: *mut core::ffi::c_void, job: *mut core::ffi::c_void, file: *mut core::ffi::c_void, rangecount: u32, ranges: *const BG_FILE_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyCallback3_Impl::FileRangesTransferred(this, core::mem::transmute_copy(&job), core::mem::transmute_copy(&file), core::mem::transmute_copy(&rangecount), core::mem::transmute_copy(&ranges)).into()
+            }
+        }
+        Self {
+            base__: <IBackgroundCopyCallback2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            FileRangesTransferred: FileRangesTransferred::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBackgroundCopyCallback3 {}
 windows_core::imp::define_interface!(IBackgroundCopyError, IBackgroundCopyError_Vtbl, 0x19c613a0_fcb8_4f28_81ae_897c3d078f81);
 windows_core::imp::interface_hierarchy!(IBackgroundCopyError, windows_core::IUnknown);
@@ -1000,6 +1217,75 @@ impl IBackgroundCopyError_Vtbl {
         iid == &<IBackgroundCopyError as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyError_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyError_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetError<Identity: windows_core::IUnknownImpl + IBackgroundCopyError_Impl>(this: *mut core::ffi::c_void, pcontext: *mut BG_ERROR_CONTEXT, pcode: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyError_Impl::GetError(this, core::mem::transmute_copy(&pcontext), core::mem::transmute_copy(&pcode)).into()
+            }
+        }
+        unsafe extern "system" fn GetFile<Identity: windows_core::IUnknownImpl + IBackgroundCopyError_Impl>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyError_Impl::GetFile(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetErrorDescription<Identity: windows_core::IUnknownImpl + IBackgroundCopyError_Impl>(this: *mut core::ffi::c_void, languageid: u32, perrordescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyError_Impl::GetErrorDescription(this, core::mem::transmute_copy(&languageid)) {
+                    Ok(ok__) => {
+                        perrordescription.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetErrorContextDescription<Identity: windows_core::IUnknownImpl + IBackgroundCopyError_Impl>(this: *mut core::ffi::c_void, languageid: u32, pcontextdescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyError_Impl::GetErrorContextDescription(this, core::mem::transmute_copy(&languageid)) {
+                    Ok(ok__) => {
+                        pcontextdescription.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetProtocol<Identity: windows_core::IUnknownImpl + IBackgroundCopyError_Impl>(this: *mut core::ffi::c_void, pprotocol: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyError_Impl::GetProtocol(this) {
+                    Ok(ok__) => {
+                        pprotocol.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetError: GetError::<Identity>,
+            GetFile: GetFile::<Identity>,
+            GetErrorDescription: GetErrorDescription::<Identity>,
+            GetErrorContextDescription: GetErrorContextDescription::<Identity>,
+            GetProtocol: GetProtocol::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBackgroundCopyError {}
 windows_core::imp::define_interface!(IBackgroundCopyFile, IBackgroundCopyFile_Vtbl, 0x01b7bd23_fb88_4a77_8490_5891d3e4653a);
 windows_core::imp::interface_hierarchy!(IBackgroundCopyFile, windows_core::IUnknown);
@@ -1076,6 +1362,49 @@ impl IBackgroundCopyFile_Vtbl {
         iid == &<IBackgroundCopyFile as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyFile_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyFile_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRemoteName<Identity: windows_core::IUnknownImpl + IBackgroundCopyFile_Impl>(this: *mut core::ffi::c_void, pval: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyFile_Impl::GetRemoteName(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLocalName<Identity: windows_core::IUnknownImpl + IBackgroundCopyFile_Impl>(this: *mut core::ffi::c_void, pval: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyFile_Impl::GetLocalName(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetProgress<Identity: windows_core::IUnknownImpl + IBackgroundCopyFile_Impl>(this: *mut core::ffi::c_void, pval: *mut BG_FILE_PROGRESS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyFile_Impl::GetProgress(this, core::mem::transmute_copy(&pval)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetRemoteName: GetRemoteName::<Identity>,
+            GetLocalName: GetLocalName::<Identity>,
+            GetProgress: GetProgress::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBackgroundCopyFile {}
 windows_core::imp::define_interface!(IBackgroundCopyFile2, IBackgroundCopyFile2_Vtbl, 0x83e81b93_0873_474d_8a8c_f2018b1a939c);
 impl core::ops::Deref for IBackgroundCopyFile2 {
@@ -1131,6 +1460,30 @@ impl IBackgroundCopyFile2_Vtbl {
         iid == &<IBackgroundCopyFile2 as windows_core::Interface>::IID || iid == &<IBackgroundCopyFile as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyFile2_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyFile2_Vtbl
+where
+    IBackgroundCopyFile_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFileRanges<Identity: windows_core::IUnknownImpl + IBackgroundCopyFile2_Impl>(this: *mut core::ffi::c_void, rangecount: *mut u32, ranges: *mut *mut BG_FILE_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyFile2_Impl::GetFileRanges(this, core::mem::transmute_copy(&rangecount), core::mem::transmute_copy(&ranges)).into()
+            }
+        }
+        unsafe extern "system" fn SetRemoteName<Identity: windows_core::IUnknownImpl + IBackgroundCopyFile2_Impl>(this: *mut core::ffi::c_void, val: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyFile2_Impl::SetRemoteName(this, core::mem::transmute(&val)).into()
+            }
+        }
+        Self {
+            base__: <IBackgroundCopyFile_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFileRanges: GetFileRanges::<Identity>,
+            SetRemoteName: SetRemoteName::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBackgroundCopyFile2 {}
 windows_core::imp::define_interface!(IBackgroundCopyFile3, IBackgroundCopyFile3_Vtbl, 0x659cdeaa_489e_11d9_a9cd_000d56965251);
 impl core::ops::Deref for IBackgroundCopyFile3 {
@@ -1234,6 +1587,62 @@ impl IBackgroundCopyFile3_Vtbl {
         iid == &<IBackgroundCopyFile3 as windows_core::Interface>::IID || iid == &<IBackgroundCopyFile as windows_core::Interface>::IID || iid == &<IBackgroundCopyFile2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyFile3_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyFile3_Vtbl
+where
+    IBackgroundCopyFile2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetTemporaryName<Identity: windows_core::IUnknownImpl + IBackgroundCopyFile3_Impl>(this: *mut core::ffi::c_void, pfilename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyFile3_Impl::GetTemporaryName(this) {
+                    Ok(ok__) => {
+                        pfilename.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetValidationState<Identity: windows_core::IUnknownImpl + IBackgroundCopyFile3_Impl>(this: *mut core::ffi::c_void, state: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyFile3_Impl::SetValidationState(this, core::mem::transmute_copy(&state)).into()
+            }
+        }
+        unsafe extern "system" fn GetValidationState<Identity: windows_core::IUnknownImpl + IBackgroundCopyFile3_Impl>(this: *mut core::ffi::c_void, pstate: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyFile3_Impl::GetValidationState(this) {
+                    Ok(ok__) => {
+                        pstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsDownloadedFromPeer<Identity: windows_core::IUnknownImpl + IBackgroundCopyFile3_Impl>(this: *mut core::ffi::c_void, pval: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyFile3_Impl::IsDownloadedFromPeer(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IBackgroundCopyFile2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetTemporaryName: GetTemporaryName::<Identity>,
+            SetValidationState: SetValidationState::<Identity>,
+            GetValidationState: GetValidationState::<Identity>,
+            IsDownloadedFromPeer: IsDownloadedFromPeer::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBackgroundCopyFile3 {}
 windows_core::imp::define_interface!(IBackgroundCopyFile4, IBackgroundCopyFile4_Vtbl, 0xef7e0655_7888_4960_b0e5_730846e03492);
 impl core::ops::Deref for IBackgroundCopyFile4 {
@@ -1271,6 +1680,23 @@ impl IBackgroundCopyFile4_Vtbl {
         iid == &<IBackgroundCopyFile4 as windows_core::Interface>::IID || iid == &<IBackgroundCopyFile as windows_core::Interface>::IID || iid == &<IBackgroundCopyFile2 as windows_core::Interface>::IID || iid == &<IBackgroundCopyFile3 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyFile4_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyFile4_Vtbl
+where
+    IBackgroundCopyFile3_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPeerDownloadStats<Identity: windows_core::IUnknownImpl + IBackgroundCopyFile4_Impl>(this: *mut core::ffi::c_void, pfromorigin: *mut u64, pfrompeers: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyFile4_Impl::GetPeerDownloadStats(this, core::mem::transmute_copy(&pfromorigin), core::mem::transmute_copy(&pfrompeers)).into()
+            }
+        }
+        Self {
+            base__: <IBackgroundCopyFile3_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPeerDownloadStats: GetPeerDownloadStats::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBackgroundCopyFile4 {}
 windows_core::imp::define_interface!(IBackgroundCopyFile5, IBackgroundCopyFile5_Vtbl, 0x85c1657f_dafc_40e8_8834_df18ea25717e);
 impl core::ops::Deref for IBackgroundCopyFile5 {
@@ -1332,6 +1758,36 @@ impl IBackgroundCopyFile5_Vtbl {
         iid == &<IBackgroundCopyFile5 as windows_core::Interface>::IID || iid == &<IBackgroundCopyFile as windows_core::Interface>::IID || iid == &<IBackgroundCopyFile2 as windows_core::Interface>::IID || iid == &<IBackgroundCopyFile3 as windows_core::Interface>::IID || iid == &<IBackgroundCopyFile4 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyFile5_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyFile5_Vtbl
+where
+    IBackgroundCopyFile4_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetProperty<Identity: windows_core::IUnknownImpl + IBackgroundCopyFile5_Impl>(this: *mut core::ffi::c_void, propertyid: BITS_FILE_PROPERTY_ID, propertyvalue: BITS_FILE_PROPERTY_VALUE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyFile5_Impl::SetProperty(this, core::mem::transmute_copy(&propertyid), core::mem::transmute(&propertyvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetProperty<Identity: windows_core::IUnknownImpl + IBackgroundCopyFile5_Impl>(this: *mut core::ffi::c_void, propertyid: BITS_FILE_PROPERTY_ID, propertyvalue: *mut BITS_FILE_PROPERTY_VALUE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyFile5_Impl::GetProperty(this, core::mem::transmute_copy(&propertyid)) {
+                    Ok(ok__) => {
+                        propertyvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IBackgroundCopyFile4_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetProperty: SetProperty::<Identity>,
+            GetProperty: GetProperty::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBackgroundCopyFile5 {}
 windows_core::imp::define_interface!(IBackgroundCopyFile6, IBackgroundCopyFile6_Vtbl, 0xcf6784f7_d677_49fd_9368_cb47aee9d1ad);
 impl core::ops::Deref for IBackgroundCopyFile6 {
@@ -1396,6 +1852,37 @@ impl IBackgroundCopyFile6_Vtbl {
         iid == &<IBackgroundCopyFile6 as windows_core::Interface>::IID || iid == &<IBackgroundCopyFile as windows_core::Interface>::IID || iid == &<IBackgroundCopyFile2 as windows_core::Interface>::IID || iid == &<IBackgroundCopyFile3 as windows_core::Interface>::IID || iid == &<IBackgroundCopyFile4 as windows_core::Interface>::IID || iid == &<IBackgroundCopyFile5 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyFile6_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyFile6_Vtbl
+where
+    IBackgroundCopyFile5_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn UpdateDownloadPosition<Identity: windows_core::IUnknownImpl + IBackgroundCopyFile6_Impl>(this: *mut core::ffi::c_void, offset: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyFile6_Impl::UpdateDownloadPosition(this, core::mem::transmute_copy(&offset)).into()
+            }
+        }
+        unsafe extern "system" fn RequestFileRanges<Identity: windows_core::IUnknownImpl + IBackgroundCopyFile6_Impl>(this: *mut core::ffi::c_void, rangecount: u32, ranges: *const BG_FILE_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyFile6_Impl::RequestFileRanges(this, core::mem::transmute_copy(&rangecount), core::mem::transmute_copy(&ranges)).into()
+            }
+        }
+        unsafe extern "system" fn GetFilledFileRanges<Identity: windows_core::IUnknownImpl + IBackgroundCopyFile6_Impl>(this: *mut core::ffi::c_void, rangecount: *mut u32, ranges: *mut *mut BG_FILE_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyFile6_Impl::GetFilledFileRanges(this, core::mem::transmute_copy(&rangecount), core::mem::transmute_copy(&ranges)).into()
+            }
+        }
+        Self {
+            base__: <IBackgroundCopyFile5_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            UpdateDownloadPosition: UpdateDownloadPosition::<Identity>,
+            RequestFileRanges: RequestFileRanges::<Identity>,
+            GetFilledFileRanges: GetFilledFileRanges::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBackgroundCopyFile6 {}
 windows_core::imp::define_interface!(IBackgroundCopyGroup, IBackgroundCopyGroup_Vtbl, 0x1ded80a7_53ea_424f_8a04_17fea9adc4f5);
 windows_core::imp::interface_hierarchy!(IBackgroundCopyGroup, windows_core::IUnknown);
@@ -1684,6 +2171,170 @@ impl IBackgroundCopyGroup_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyGroup_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyGroup_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProp<Identity: windows_core::IUnknownImpl + IBackgroundCopyGroup_Impl>(this: *mut core::ffi::c_void, propid: GROUPPROP, pvarval: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyGroup_Impl::GetProp(this, core::mem::transmute_copy(&propid)) {
+                    Ok(ok__) => {
+                        pvarval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProp<Identity: windows_core::IUnknownImpl + IBackgroundCopyGroup_Impl>(this: *mut core::ffi::c_void, propid: GROUPPROP, pvarval: *const super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyGroup_Impl::SetProp(this, core::mem::transmute_copy(&propid), core::mem::transmute_copy(&pvarval)).into()
+            }
+        }
+        unsafe extern "system" fn GetProgress<Identity: windows_core::IUnknownImpl + IBackgroundCopyGroup_Impl>(this: *mut core::ffi::c_void, dwflags: u32, pdwprogress: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyGroup_Impl::GetProgress(this, core::mem::transmute_copy(&dwflags)) {
+                    Ok(ok__) => {
+                        pdwprogress.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStatus<Identity: windows_core::IUnknownImpl + IBackgroundCopyGroup_Impl>(this: *mut core::ffi::c_void, pdwstatus: *mut u32, pdwjobindex: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyGroup_Impl::GetStatus(this, core::mem::transmute_copy(&pdwstatus), core::mem::transmute_copy(&pdwjobindex)).into()
+            }
+        }
+        unsafe extern "system" fn GetJob<Identity: windows_core::IUnknownImpl + IBackgroundCopyGroup_Impl>(this: *mut core::ffi::c_void, jobid: windows_core::GUID, ppjob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyGroup_Impl::GetJob(this, core::mem::transmute(&jobid)) {
+                    Ok(ok__) => {
+                        ppjob.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SuspendGroup<Identity: windows_core::IUnknownImpl + IBackgroundCopyGroup_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyGroup_Impl::SuspendGroup(this).into()
+            }
+        }
+        unsafe extern "system" fn ResumeGroup<Identity: windows_core::IUnknownImpl + IBackgroundCopyGroup_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyGroup_Impl::ResumeGroup(this).into()
+            }
+        }
+        unsafe extern "system" fn CancelGroup<Identity: windows_core::IUnknownImpl + IBackgroundCopyGroup_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyGroup_Impl::CancelGroup(this).into()
+            }
+        }
+        unsafe extern "system" fn Size<Identity: windows_core::IUnknownImpl + IBackgroundCopyGroup_Impl>(this: *mut core::ffi::c_void, pdwsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyGroup_Impl::Size(this) {
+                    Ok(ok__) => {
+                        pdwsize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GroupID<Identity: windows_core::IUnknownImpl + IBackgroundCopyGroup_Impl>(this: *mut core::ffi::c_void, pguidgroupid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyGroup_Impl::GroupID(this) {
+                    Ok(ok__) => {
+                        pguidgroupid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateJob<Identity: windows_core::IUnknownImpl + IBackgroundCopyGroup_Impl>(this: *mut core::ffi::c_void, guidjobid: windows_core::GUID, ppjob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyGroup_Impl::CreateJob(this, core::mem::transmute(&guidjobid)) {
+                    Ok(ok__) => {
+                        ppjob.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumJobs<Identity: windows_core::IUnknownImpl + IBackgroundCopyGroup_Impl>(this: *mut core::ffi::c_void, dwflags: u32, ppenumjobs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyGroup_Impl::EnumJobs(this, core::mem::transmute_copy(&dwflags)) {
+                    Ok(ok__) => {
+                        ppenumjobs.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SwitchToForeground<Identity: windows_core::IUnknownImpl + IBackgroundCopyGroup_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyGroup_Impl::SwitchToForeground(this).into()
+            }
+        }
+        unsafe extern "system" fn QueryNewJobInterface<Identity: windows_core::IUnknownImpl + IBackgroundCopyGroup_Impl>(this: *mut core::ffi::c_void, iid: *const windows_core::GUID, punk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyGroup_Impl::QueryNewJobInterface(this, core::mem::transmute_copy(&iid)) {
+                    Ok(ok__) => {
+                        punk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNotificationPointer<Identity: windows_core::IUnknownImpl + IBackgroundCopyGroup_Impl>(this: *mut core::ffi::c_void, iid: *const windows_core::GUID, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyGroup_Impl::SetNotificationPointer(this, core::mem::transmute_copy(&iid), core::mem::transmute_copy(&punk)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProp: GetProp::<Identity>,
+            SetProp: SetProp::<Identity>,
+            GetProgress: GetProgress::<Identity>,
+            GetStatus: GetStatus::<Identity>,
+            GetJob: GetJob::<Identity>,
+            SuspendGroup: SuspendGroup::<Identity>,
+            ResumeGroup: ResumeGroup::<Identity>,
+            CancelGroup: CancelGroup::<Identity>,
+            Size: Size::<Identity>,
+            GroupID: GroupID::<Identity>,
+            CreateJob: CreateJob::<Identity>,
+            EnumJobs: EnumJobs::<Identity>,
+            SwitchToForeground: SwitchToForeground::<Identity>,
+            QueryNewJobInterface: QueryNewJobInterface::<Identity>,
+            SetNotificationPointer: SetNotificationPointer::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IBackgroundCopyGroup {}
 windows_core::imp::define_interface!(IBackgroundCopyJob, IBackgroundCopyJob_Vtbl, 0x37668d37_507e_4160_9316_26306d150b12);
 windows_core::imp::interface_hierarchy!(IBackgroundCopyJob, windows_core::IUnknown);
@@ -2233,160 +2884,568 @@ impl IBackgroundCopyJob_Vtbl {
         iid == &<IBackgroundCopyJob as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IBackgroundCopyJob {}
-windows_core::imp::define_interface!(IBackgroundCopyJob1, IBackgroundCopyJob1_Vtbl, 0x59f5553c_2031_4629_bb18_2645a6970947);
-windows_core::imp::interface_hierarchy!(IBackgroundCopyJob1, windows_core::IUnknown);
-impl IBackgroundCopyJob1 {
-    pub unsafe fn CancelJob(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).CancelJob)(windows_core::Interface::as_raw(self)).ok() }
-    }
-    pub unsafe fn GetProgress(&self, dwflags: u32) -> windows_core::Result<u32> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetProgress)(windows_core::Interface::as_raw(self), dwflags, &mut result__).map(|| result__)
-        }
-    }
-    pub unsafe fn GetStatus(&self, pdwstatus: *mut u32, pdwwin32result: *mut u32, pdwtransportresult: *mut u32, pdwnumofretries: *mut u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetStatus)(windows_core::Interface::as_raw(self), pdwstatus as _, pdwwin32result as _, pdwtransportresult as _, pdwnumofretries as _).ok() }
-    }
-    pub unsafe fn AddFiles(&self, ppfileset: &[*const FILESETINFO]) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).AddFiles)(windows_core::Interface::as_raw(self), ppfileset.len().try_into().unwrap(), core::mem::transmute(ppfileset.as_ptr())).ok() }
-    }
-    pub unsafe fn GetFile(&self, cfileindex: u32) -> windows_core::Result<FILESETINFO> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetFile)(windows_core::Interface::as_raw(self), cfileindex, &mut result__).map(|| core::mem::transmute(result__))
-        }
-    }
-    pub unsafe fn GetFileCount(&self) -> windows_core::Result<u32> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetFileCount)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
-        }
-    }
-    pub unsafe fn SwitchToForeground(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SwitchToForeground)(windows_core::Interface::as_raw(self)).ok() }
-    }
-    pub unsafe fn JobID(&self) -> windows_core::Result<windows_core::GUID> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).JobID)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyJob_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddFileSet<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, cfilecount: u32, pfileset: *const BG_FILE_INFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob_Impl::AddFileSet(this, core::mem::transmute_copy(&cfilecount), core::mem::transmute_copy(&pfileset)).into()
+            }
         }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IBackgroundCopyJob1_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    pub CancelJob: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetProgress: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *mut u32) -> windows_core::HRESULT,
-    pub GetStatus: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32, *mut u32, *mut u32, *mut u32) -> windows_core::HRESULT,
-    pub AddFiles: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *const *const FILESETINFO) -> windows_core::HRESULT,
-    pub GetFile: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *mut FILESETINFO) -> windows_core::HRESULT,
-    pub GetFileCount: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
-    pub SwitchToForeground: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub JobID: unsafe extern "system" fn(*mut core::ffi::c_void, *mut windows_core::GUID) -> windows_core::HRESULT,
-}
-pub trait IBackgroundCopyJob1_Impl: windows_core::IUnknownImpl {
-    fn CancelJob(&self) -> windows_core::Result<()>;
-    fn GetProgress(&self, dwflags: u32) -> windows_core::Result<u32>;
-    fn GetStatus(&self, pdwstatus: *mut u32, pdwwin32result: *mut u32, pdwtransportresult: *mut u32, pdwnumofretries: *mut u32) -> windows_core::Result<()>;
-    fn AddFiles(&self, cfilecount: u32, ppfileset: *const *const FILESETINFO) -> windows_core::Result<()>;
-    fn GetFile(&self, cfileindex: u32) -> windows_core::Result<FILESETINFO>;
-    fn GetFileCount(&self) -> windows_core::Result<u32>;
-    fn SwitchToForeground(&self) -> windows_core::Result<()>;
-    fn JobID(&self) -> windows_core::Result<windows_core::GUID>;
-}
-impl IBackgroundCopyJob1_Vtbl {
-    pub const fn new<Identity: IBackgroundCopyJob1_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn CancelJob<Identity: IBackgroundCopyJob1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn AddFile<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, remoteurl: windows_core::PCWSTR, localname: windows_core::PCWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IBackgroundCopyJob1_Impl::CancelJob(this).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob_Impl::AddFile(this, core::mem::transmute(&remoteurl), core::mem::transmute(&localname)).into()
             }
         }
-        unsafe extern "system" fn GetProgress<Identity: IBackgroundCopyJob1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwflags: u32, pdwprogress: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn EnumFiles<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, penum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IBackgroundCopyJob1_Impl::GetProgress(this, core::mem::transmute_copy(&dwflags)) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob_Impl::EnumFiles(this) {
                     Ok(ok__) => {
-                        pdwprogress.write(core::mem::transmute(ok__));
+                        penum.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn GetStatus<Identity: IBackgroundCopyJob1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwstatus: *mut u32, pdwwin32result: *mut u32, pdwtransportresult: *mut u32, pdwnumofretries: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn Suspend<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IBackgroundCopyJob1_Impl::GetStatus(this, core::mem::transmute_copy(&pdwstatus), core::mem::transmute_copy(&pdwwin32result), core::mem::transmute_copy(&pdwtransportresult), core::mem::transmute_copy(&pdwnumofretries)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob_Impl::Suspend(this).into()
             }
         }
-        unsafe extern "system" fn AddFiles<Identity: IBackgroundCopyJob1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, cfilecount: u32, ppfileset: *const *const FILESETINFO) -> windows_core::HRESULT {
+        unsafe extern "system" fn Resume<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IBackgroundCopyJob1_Impl::AddFiles(this, core::mem::transmute_copy(&cfilecount), core::mem::transmute_copy(&ppfileset)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob_Impl::Resume(this).into()
             }
         }
-        unsafe extern "system" fn GetFile<Identity: IBackgroundCopyJob1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, cfileindex: u32, pfileinfo: *mut FILESETINFO) -> windows_core::HRESULT {
+        unsafe extern "system" fn Cancel<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IBackgroundCopyJob1_Impl::GetFile(this, core::mem::transmute_copy(&cfileindex)) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob_Impl::Cancel(this).into()
+            }
+        }
+        unsafe extern "system" fn Complete<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob_Impl::Complete(this).into()
+            }
+        }
+        unsafe extern "system" fn GetId<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, pval: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob_Impl::GetId(this) {
                     Ok(ok__) => {
-                        pfileinfo.write(core::mem::transmute(ok__));
+                        pval.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn GetFileCount<Identity: IBackgroundCopyJob1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwfilecount: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetType<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, pval: *mut BG_JOB_TYPE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IBackgroundCopyJob1_Impl::GetFileCount(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob_Impl::GetType(this) {
                     Ok(ok__) => {
-                        pdwfilecount.write(core::mem::transmute(ok__));
+                        pval.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn SwitchToForeground<Identity: IBackgroundCopyJob1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetProgress<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, pval: *mut BG_JOB_PROGRESS) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IBackgroundCopyJob1_Impl::SwitchToForeground(this).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob_Impl::GetProgress(this, core::mem::transmute_copy(&pval)).into()
             }
         }
-        unsafe extern "system" fn JobID<Identity: IBackgroundCopyJob1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pguidjobid: *mut windows_core::GUID) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetTimes<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, pval: *mut BG_JOB_TIMES) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IBackgroundCopyJob1_Impl::JobID(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob_Impl::GetTimes(this, core::mem::transmute_copy(&pval)).into()
+            }
+        }
+        unsafe extern "system" fn GetState<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, pval: *mut BG_JOB_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob_Impl::GetState(this) {
                     Ok(ok__) => {
-                        pguidjobid.write(core::mem::transmute(ok__));
+                        pval.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            CancelJob: CancelJob::<Identity, OFFSET>,
-            GetProgress: GetProgress::<Identity, OFFSET>,
-            GetStatus: GetStatus::<Identity, OFFSET>,
-            AddFiles: AddFiles::<Identity, OFFSET>,
-            GetFile: GetFile::<Identity, OFFSET>,
-            GetFileCount: GetFileCount::<Identity, OFFSET>,
-            SwitchToForeground: SwitchToForeground::<Identity, OFFSET>,
-            JobID: JobID::<Identity, OFFSET>,
-        }
+        unsafe extern "system" fn GetError<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, pperror: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob_Impl::GetError(this) {
+                    Ok(ok__) => {
+                        pperror.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOwner<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, pval: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob_Impl::GetOwner(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDisplayName<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, val: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob_Impl::SetDisplayName(this, core::mem::transmute(&val)).into()
+            }
+        }
+        unsafe extern "system" fn GetDisplayName<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, pval: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob_Impl::GetDisplayName(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDescription<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, val: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob_Impl::SetDescription(this, core::mem::transmute(&val)).into()
+            }
+        }
+        unsafe extern "system" fn GetDescription<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, pval: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob_Impl::GetDescription(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPriority<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, val: BG_JOB_PRIORITY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob_Impl::SetPriority(this, core::mem::transmute_copy(&val)).into()
+            }
+        }
+        unsafe extern "system" fn GetPriority<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, pval: *mut BG_JOB_PRIORITY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob_Impl::GetPriority(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNotifyFlags<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, val: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob_Impl::SetNotifyFlags(this, core::mem::transmute_copy(&val)).into()
+            }
+        }
+        unsafe extern "system" fn GetNotifyFlags<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, pval: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob_Impl::GetNotifyFlags(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNotifyInterface<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, val: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob_Impl::SetNotifyInterface(this, core::mem::transmute_copy(&val)).into()
+            }
+        }
+        unsafe extern "system" fn GetNotifyInterface<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob_Impl::GetNotifyInterface(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMinimumRetryDelay<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, seconds: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob_Impl::SetMinimumRetryDelay(this, core::mem::transmute_copy(&seconds)).into()
+            }
+        }
+        unsafe extern "system" fn GetMinimumRetryDelay<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, seconds: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob_Impl::GetMinimumRetryDelay(this) {
+                    Ok(ok__) => {
+                        seconds.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNoProgressTimeout<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, seconds: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob_Impl::SetNoProgressTimeout(this, core::mem::transmute_copy(&seconds)).into()
+            }
+        }
+        unsafe extern "system" fn GetNoProgressTimeout<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, seconds: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob_Impl::GetNoProgressTimeout(this) {
+                    Ok(ok__) => {
+                        seconds.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetErrorCount<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, errors: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob_Impl::GetErrorCount(this) {
+                    Ok(ok__) => {
+                        errors.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProxySettings<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, proxyusage: BG_JOB_PROXY_USAGE, proxylist: windows_core::PCWSTR, proxybypasslist: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob_Impl::SetProxySettings(this, core::mem::transmute_copy(&proxyusage), core::mem::transmute(&proxylist), core::mem::transmute(&proxybypasslist)).into()
+            }
+        }
+        unsafe extern "system" fn GetProxySettings<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void, pproxyusage: *mut BG_JOB_PROXY_USAGE, pproxylist: *mut windows_core::PWSTR, pproxybypasslist: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob_Impl::GetProxySettings(this, core::mem::transmute_copy(&pproxyusage), core::mem::transmute_copy(&pproxylist), core::mem::transmute_copy(&pproxybypasslist)).into()
+            }
+        }
+        unsafe extern "system" fn TakeOwnership<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob_Impl::TakeOwnership(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddFileSet: AddFileSet::<Identity>,
+            AddFile: AddFile::<Identity>,
+            EnumFiles: EnumFiles::<Identity>,
+            Suspend: Suspend::<Identity>,
+            Resume: Resume::<Identity>,
+            Cancel: Cancel::<Identity>,
+            Complete: Complete::<Identity>,
+            GetId: GetId::<Identity>,
+            GetType: GetType::<Identity>,
+            GetProgress: GetProgress::<Identity>,
+            GetTimes: GetTimes::<Identity>,
+            GetState: GetState::<Identity>,
+            GetError: GetError::<Identity>,
+            GetOwner: GetOwner::<Identity>,
+            SetDisplayName: SetDisplayName::<Identity>,
+            GetDisplayName: GetDisplayName::<Identity>,
+            SetDescription: SetDescription::<Identity>,
+            GetDescription: GetDescription::<Identity>,
+            SetPriority: SetPriority::<Identity>,
+            GetPriority: GetPriority::<Identity>,
+            SetNotifyFlags: SetNotifyFlags::<Identity>,
+            GetNotifyFlags: GetNotifyFlags::<Identity>,
+            SetNotifyInterface: SetNotifyInterface::<Identity>,
+            GetNotifyInterface: GetNotifyInterface::<Identity>,
+            SetMinimumRetryDelay: SetMinimumRetryDelay::<Identity>,
+            GetMinimumRetryDelay: GetMinimumRetryDelay::<Identity>,
+            SetNoProgressTimeout: SetNoProgressTimeout::<Identity>,
+            GetNoProgressTimeout: GetNoProgressTimeout::<Identity>,
+            GetErrorCount: GetErrorCount::<Identity>,
+            SetProxySettings: SetProxySettings::<Identity>,
+            GetProxySettings: GetProxySettings::<Identity>,
+            TakeOwnership: TakeOwnership::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IBackgroundCopyJob {}
+windows_core::imp::define_interface!(IBackgroundCopyJob1, IBackgroundCopyJob1_Vtbl, 0x59f5553c_2031_4629_bb18_2645a6970947);
+windows_core::imp::interface_hierarchy!(IBackgroundCopyJob1, windows_core::IUnknown);
+impl IBackgroundCopyJob1 {
+    pub unsafe fn CancelJob(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).CancelJob)(windows_core::Interface::as_raw(self)).ok() }
+    }
+    pub unsafe fn GetProgress(&self, dwflags: u32) -> windows_core::Result<u32> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetProgress)(windows_core::Interface::as_raw(self), dwflags, &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn GetStatus(&self, pdwstatus: *mut u32, pdwwin32result: *mut u32, pdwtransportresult: *mut u32, pdwnumofretries: *mut u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetStatus)(windows_core::Interface::as_raw(self), pdwstatus as _, pdwwin32result as _, pdwtransportresult as _, pdwnumofretries as _).ok() }
+    }
+    pub unsafe fn AddFiles(&self, ppfileset: &[*const FILESETINFO]) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).AddFiles)(windows_core::Interface::as_raw(self), ppfileset.len().try_into().unwrap(), core::mem::transmute(ppfileset.as_ptr())).ok() }
+    }
+    pub unsafe fn GetFile(&self, cfileindex: u32) -> windows_core::Result<FILESETINFO> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetFile)(windows_core::Interface::as_raw(self), cfileindex, &mut result__).map(|| core::mem::transmute(result__))
+        }
+    }
+    pub unsafe fn GetFileCount(&self) -> windows_core::Result<u32> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetFileCount)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn SwitchToForeground(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SwitchToForeground)(windows_core::Interface::as_raw(self)).ok() }
+    }
+    pub unsafe fn JobID(&self) -> windows_core::Result<windows_core::GUID> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).JobID)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IBackgroundCopyJob1_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub CancelJob: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetProgress: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *mut u32) -> windows_core::HRESULT,
+    pub GetStatus: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32, *mut u32, *mut u32, *mut u32) -> windows_core::HRESULT,
+    pub AddFiles: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *const *const FILESETINFO) -> windows_core::HRESULT,
+    pub GetFile: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *mut FILESETINFO) -> windows_core::HRESULT,
+    pub GetFileCount: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
+    pub SwitchToForeground: unsafe extern "system" fn(*mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub JobID: unsafe extern "system" fn(*mut core::ffi::c_void, *mut windows_core::GUID) -> windows_core::HRESULT,
+}
+pub trait IBackgroundCopyJob1_Impl: windows_core::IUnknownImpl {
+    fn CancelJob(&self) -> windows_core::Result<()>;
+    fn GetProgress(&self, dwflags: u32) -> windows_core::Result<u32>;
+    fn GetStatus(&self, pdwstatus: *mut u32, pdwwin32result: *mut u32, pdwtransportresult: *mut u32, pdwnumofretries: *mut u32) -> windows_core::Result<()>;
+    fn AddFiles(&self, cfilecount: u32, ppfileset: *const *const FILESETINFO) -> windows_core::Result<()>;
+    fn GetFile(&self, cfileindex: u32) -> windows_core::Result<FILESETINFO>;
+    fn GetFileCount(&self) -> windows_core::Result<u32>;
+    fn SwitchToForeground(&self) -> windows_core::Result<()>;
+    fn JobID(&self) -> windows_core::Result<windows_core::GUID>;
+}
+impl IBackgroundCopyJob1_Vtbl {
+    pub const fn new<Identity: IBackgroundCopyJob1_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn CancelJob<Identity: IBackgroundCopyJob1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IBackgroundCopyJob1_Impl::CancelJob(this).into()
+            }
+        }
+        unsafe extern "system" fn GetProgress<Identity: IBackgroundCopyJob1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwflags: u32, pdwprogress: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IBackgroundCopyJob1_Impl::GetProgress(this, core::mem::transmute_copy(&dwflags)) {
+                    Ok(ok__) => {
+                        pdwprogress.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStatus<Identity: IBackgroundCopyJob1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwstatus: *mut u32, pdwwin32result: *mut u32, pdwtransportresult: *mut u32, pdwnumofretries: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IBackgroundCopyJob1_Impl::GetStatus(this, core::mem::transmute_copy(&pdwstatus), core::mem::transmute_copy(&pdwwin32result), core::mem::transmute_copy(&pdwtransportresult), core::mem::transmute_copy(&pdwnumofretries)).into()
+            }
+        }
+        unsafe extern "system" fn AddFiles<Identity: IBackgroundCopyJob1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, cfilecount: u32, ppfileset: *const *const FILESETINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IBackgroundCopyJob1_Impl::AddFiles(this, core::mem::transmute_copy(&cfilecount), core::mem::transmute_copy(&ppfileset)).into()
+            }
+        }
+        unsafe extern "system" fn GetFile<Identity: IBackgroundCopyJob1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, cfileindex: u32, pfileinfo: *mut FILESETINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IBackgroundCopyJob1_Impl::GetFile(this, core::mem::transmute_copy(&cfileindex)) {
+                    Ok(ok__) => {
+                        pfileinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFileCount<Identity: IBackgroundCopyJob1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwfilecount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IBackgroundCopyJob1_Impl::GetFileCount(this) {
+                    Ok(ok__) => {
+                        pdwfilecount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SwitchToForeground<Identity: IBackgroundCopyJob1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IBackgroundCopyJob1_Impl::SwitchToForeground(this).into()
+            }
+        }
+        unsafe extern "system" fn JobID<Identity: IBackgroundCopyJob1_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pguidjobid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IBackgroundCopyJob1_Impl::JobID(this) {
+                    Ok(ok__) => {
+                        pguidjobid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            CancelJob: CancelJob::<Identity, OFFSET>,
+            GetProgress: GetProgress::<Identity, OFFSET>,
+            GetStatus: GetStatus::<Identity, OFFSET>,
+            AddFiles: AddFiles::<Identity, OFFSET>,
+            GetFile: GetFile::<Identity, OFFSET>,
+            GetFileCount: GetFileCount::<Identity, OFFSET>,
+            SwitchToForeground: SwitchToForeground::<Identity, OFFSET>,
+            JobID: JobID::<Identity, OFFSET>,
+        }
     }
     pub fn matches(iid: &windows_core::GUID) -> bool {
         iid == &<IBackgroundCopyJob1 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob1_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyJob1_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CancelJob<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob1_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob1_Impl::CancelJob(this).into()
+            }
+        }
+        unsafe extern "system" fn GetProgress<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob1_Impl>(this: *mut core::ffi::c_void, dwflags: u32, pdwprogress: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob1_Impl::GetProgress(this, core::mem::transmute_copy(&dwflags)) {
+                    Ok(ok__) => {
+                        pdwprogress.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStatus<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob1_Impl>(this: *mut core::ffi::c_void, pdwstatus: *mut u32, pdwwin32result: *mut u32, pdwtransportresult: *mut u32, pdwnumofretries: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob1_Impl::GetStatus(this, core::mem::transmute_copy(&pdwstatus), core::mem::transmute_copy(&pdwwin32result), core::mem::transmute_copy(&pdwtransportresult), core::mem::transmute_copy(&pdwnumofretries)).into()
+            }
+        }
+        unsafe extern "system" fn AddFiles<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob1_Impl>(this: *mut core::ffi::c_void, cfilecount: u32, ppfileset: *const *const FILESETINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob1_Impl::AddFiles(this, core::mem::transmute_copy(&cfilecount), core::mem::transmute_copy(&ppfileset)).into()
+            }
+        }
+        unsafe extern "system" fn GetFile<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob1_Impl>(this: *mut core::ffi::c_void, cfileindex: u32, pfileinfo: *mut FILESETINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob1_Impl::GetFile(this, core::mem::transmute_copy(&cfileindex)) {
+                    Ok(ok__) => {
+                        pfileinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFileCount<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob1_Impl>(this: *mut core::ffi::c_void, pdwfilecount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob1_Impl::GetFileCount(this) {
+                    Ok(ok__) => {
+                        pdwfilecount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SwitchToForeground<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob1_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob1_Impl::SwitchToForeground(this).into()
+            }
+        }
+        unsafe extern "system" fn JobID<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob1_Impl>(this: *mut core::ffi::c_void, pguidjobid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob1_Impl::JobID(this) {
+                    Ok(ok__) => {
+                        pguidjobid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CancelJob: CancelJob::<Identity>,
+            GetProgress: GetProgress::<Identity>,
+            GetStatus: GetStatus::<Identity>,
+            AddFiles: AddFiles::<Identity>,
+            GetFile: GetFile::<Identity>,
+            GetFileCount: GetFileCount::<Identity>,
+            SwitchToForeground: SwitchToForeground::<Identity>,
+            JobID: JobID::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBackgroundCopyJob1 {}
 windows_core::imp::define_interface!(IBackgroundCopyJob2, IBackgroundCopyJob2_Vtbl, 0x54b50739_686f_45eb_9dff_d6a9a0faa9af);
 impl core::ops::Deref for IBackgroundCopyJob2 {
@@ -2455,41 +3514,116 @@ pub trait IBackgroundCopyJob2_Impl: IBackgroundCopyJob_Impl {
     fn SetCredentials(&self, credentials: *const BG_AUTH_CREDENTIALS) -> windows_core::Result<()>;
     fn RemoveCredentials(&self, target: BG_AUTH_TARGET, scheme: BG_AUTH_SCHEME) -> windows_core::Result<()>;
 }
-impl IBackgroundCopyJob2_Vtbl {
-    pub const fn new<Identity: IBackgroundCopyJob2_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn SetNotifyCmdLine<Identity: IBackgroundCopyJob2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, program: windows_core::PCWSTR, parameters: windows_core::PCWSTR) -> windows_core::HRESULT {
+impl IBackgroundCopyJob2_Vtbl {
+    pub const fn new<Identity: IBackgroundCopyJob2_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn SetNotifyCmdLine<Identity: IBackgroundCopyJob2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, program: windows_core::PCWSTR, parameters: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IBackgroundCopyJob2_Impl::SetNotifyCmdLine(this, core::mem::transmute(&program), core::mem::transmute(&parameters)).into()
+            }
+        }
+        unsafe extern "system" fn GetNotifyCmdLine<Identity: IBackgroundCopyJob2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pprogram: *mut windows_core::PWSTR, pparameters: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IBackgroundCopyJob2_Impl::GetNotifyCmdLine(this, core::mem::transmute_copy(&pprogram), core::mem::transmute_copy(&pparameters)).into()
+            }
+        }
+        unsafe extern "system" fn GetReplyProgress<Identity: IBackgroundCopyJob2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pprogress: *mut BG_JOB_REPLY_PROGRESS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IBackgroundCopyJob2_Impl::GetReplyProgress(this, core::mem::transmute_copy(&pprogress)).into()
+            }
+        }
+        unsafe extern "system" fn GetReplyData<Identity: IBackgroundCopyJob2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppbuffer: *mut *mut u8, plength: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IBackgroundCopyJob2_Impl::GetReplyData(this, core::mem::transmute_copy(&ppbuffer), core::mem::transmute_copy(&plength)).into()
+            }
+        }
+        unsafe extern "system" fn SetReplyFileName<Identity: IBackgroundCopyJob2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, replyfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IBackgroundCopyJob2_Impl::SetReplyFileName(this, core::mem::transmute(&replyfilename)).into()
+            }
+        }
+        unsafe extern "system" fn GetReplyFileName<Identity: IBackgroundCopyJob2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, preplyfilename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IBackgroundCopyJob2_Impl::GetReplyFileName(this) {
+                    Ok(ok__) => {
+                        preplyfilename.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCredentials<Identity: IBackgroundCopyJob2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, credentials: *const BG_AUTH_CREDENTIALS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IBackgroundCopyJob2_Impl::SetCredentials(this, core::mem::transmute_copy(&credentials)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveCredentials<Identity: IBackgroundCopyJob2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, target: BG_AUTH_TARGET, scheme: BG_AUTH_SCHEME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IBackgroundCopyJob2_Impl::RemoveCredentials(this, core::mem::transmute_copy(&target), core::mem::transmute_copy(&scheme)).into()
+            }
+        }
+        Self {
+            base__: IBackgroundCopyJob_Vtbl::new::<Identity, OFFSET>(),
+            SetNotifyCmdLine: SetNotifyCmdLine::<Identity, OFFSET>,
+            GetNotifyCmdLine: GetNotifyCmdLine::<Identity, OFFSET>,
+            GetReplyProgress: GetReplyProgress::<Identity, OFFSET>,
+            GetReplyData: GetReplyData::<Identity, OFFSET>,
+            SetReplyFileName: SetReplyFileName::<Identity, OFFSET>,
+            GetReplyFileName: GetReplyFileName::<Identity, OFFSET>,
+            SetCredentials: SetCredentials::<Identity, OFFSET>,
+            RemoveCredentials: RemoveCredentials::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyJob2 as windows_core::Interface>::IID || iid == &<IBackgroundCopyJob as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob2_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyJob2_Vtbl
+where
+    IBackgroundCopyJob_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetNotifyCmdLine<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob2_Impl>(this: *mut core::ffi::c_void, program: windows_core::PCWSTR, parameters: windows_core::PCWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IBackgroundCopyJob2_Impl::SetNotifyCmdLine(this, core::mem::transmute(&program), core::mem::transmute(&parameters)).into()
             }
         }
-        unsafe extern "system" fn GetNotifyCmdLine<Identity: IBackgroundCopyJob2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pprogram: *mut windows_core::PWSTR, pparameters: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetNotifyCmdLine<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob2_Impl>(this: *mut core::ffi::c_void, pprogram: *mut windows_core::PWSTR, pparameters: *mut windows_core::PWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IBackgroundCopyJob2_Impl::GetNotifyCmdLine(this, core::mem::transmute_copy(&pprogram), core::mem::transmute_copy(&pparameters)).into()
             }
         }
-        unsafe extern "system" fn GetReplyProgress<Identity: IBackgroundCopyJob2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pprogress: *mut BG_JOB_REPLY_PROGRESS) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetReplyProgress<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob2_Impl>(this: *mut core::ffi::c_void, pprogress: *mut BG_JOB_REPLY_PROGRESS) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IBackgroundCopyJob2_Impl::GetReplyProgress(this, core::mem::transmute_copy(&pprogress)).into()
             }
         }
-        unsafe extern "system" fn GetReplyData<Identity: IBackgroundCopyJob2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppbuffer: *mut *mut u8, plength: *mut u64) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetReplyData<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob2_Impl>(this: *mut core::ffi::c_void, ppbuffer: *mut *mut u8, plength: *mut u64) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IBackgroundCopyJob2_Impl::GetReplyData(this, core::mem::transmute_copy(&ppbuffer), core::mem::transmute_copy(&plength)).into()
             }
         }
-        unsafe extern "system" fn SetReplyFileName<Identity: IBackgroundCopyJob2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, replyfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetReplyFileName<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob2_Impl>(this: *mut core::ffi::c_void, replyfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IBackgroundCopyJob2_Impl::SetReplyFileName(this, core::mem::transmute(&replyfilename)).into()
             }
         }
-        unsafe extern "system" fn GetReplyFileName<Identity: IBackgroundCopyJob2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, preplyfilename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetReplyFileName<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob2_Impl>(this: *mut core::ffi::c_void, preplyfilename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IBackgroundCopyJob2_Impl::GetReplyFileName(this) {
                     Ok(ok__) => {
                         preplyfilename.write(core::mem::transmute(ok__));
@@ -2499,33 +3633,30 @@ impl IBackgroundCopyJob2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetCredentials<Identity: IBackgroundCopyJob2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, credentials: *const BG_AUTH_CREDENTIALS) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetCredentials<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob2_Impl>(this: *mut core::ffi::c_void, credentials: *const BG_AUTH_CREDENTIALS) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IBackgroundCopyJob2_Impl::SetCredentials(this, core::mem::transmute_copy(&credentials)).into()
             }
         }
-        unsafe extern "system" fn RemoveCredentials<Identity: IBackgroundCopyJob2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, target: BG_AUTH_TARGET, scheme: BG_AUTH_SCHEME) -> windows_core::HRESULT {
+        unsafe extern "system" fn RemoveCredentials<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob2_Impl>(this: *mut core::ffi::c_void, target: BG_AUTH_TARGET, scheme: BG_AUTH_SCHEME) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IBackgroundCopyJob2_Impl::RemoveCredentials(this, core::mem::transmute_copy(&target), core::mem::transmute_copy(&scheme)).into()
             }
         }
         Self {
-            base__: IBackgroundCopyJob_Vtbl::new::<Identity, OFFSET>(),
-            SetNotifyCmdLine: SetNotifyCmdLine::<Identity, OFFSET>,
-            GetNotifyCmdLine: GetNotifyCmdLine::<Identity, OFFSET>,
-            GetReplyProgress: GetReplyProgress::<Identity, OFFSET>,
-            GetReplyData: GetReplyData::<Identity, OFFSET>,
-            SetReplyFileName: SetReplyFileName::<Identity, OFFSET>,
-            GetReplyFileName: GetReplyFileName::<Identity, OFFSET>,
-            SetCredentials: SetCredentials::<Identity, OFFSET>,
-            RemoveCredentials: RemoveCredentials::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IBackgroundCopyJob2 as windows_core::Interface>::IID || iid == &<IBackgroundCopyJob as windows_core::Interface>::IID
-    }
+            base__: <IBackgroundCopyJob_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetNotifyCmdLine: SetNotifyCmdLine::<Identity>,
+            GetNotifyCmdLine: GetNotifyCmdLine::<Identity>,
+            GetReplyProgress: GetReplyProgress::<Identity>,
+            GetReplyData: GetReplyData::<Identity>,
+            SetReplyFileName: SetReplyFileName::<Identity>,
+            GetReplyFileName: GetReplyFileName::<Identity>,
+            SetCredentials: SetCredentials::<Identity>,
+            RemoveCredentials: RemoveCredentials::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IBackgroundCopyJob2 {}
 windows_core::imp::define_interface!(IBackgroundCopyJob3, IBackgroundCopyJob3_Vtbl, 0x443c8934_90ff_48ed_bcde_26f5c7450042);
@@ -2620,6 +3751,50 @@ impl IBackgroundCopyJob3_Vtbl {
         iid == &<IBackgroundCopyJob3 as windows_core::Interface>::IID || iid == &<IBackgroundCopyJob as windows_core::Interface>::IID || iid == &<IBackgroundCopyJob2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob3_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyJob3_Vtbl
+where
+    IBackgroundCopyJob2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ReplaceRemotePrefix<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob3_Impl>(this: *mut core::ffi::c_void, oldprefix: windows_core::PCWSTR, newprefix: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob3_Impl::ReplaceRemotePrefix(this, core::mem::transmute(&oldprefix), core::mem::transmute(&newprefix)).into()
+            }
+        }
+        unsafe extern "system" fn AddFileWithRanges<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob3_Impl>(this: *mut core::ffi::c_void, remoteurl: windows_core::PCWSTR, localname: windows_core::PCWSTR, rangecount: u32, ranges: *const BG_FILE_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob3_Impl::AddFileWithRanges(this, core::mem::transmute(&remoteurl), core::mem::transmute(&localname), core::mem::transmute_copy(&rangecount), core::mem::transmute_copy(&ranges)).into()
+            }
+        }
+        unsafe extern "system" fn SetFileACLFlags<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob3_Impl>(this: *mut core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob3_Impl::SetFileACLFlags(this, core::mem::transmute_copy(&flags)).into()
+            }
+        }
+        unsafe extern "system" fn GetFileACLFlags<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob3_Impl>(this: *mut core::ffi::c_void, flags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob3_Impl::GetFileACLFlags(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IBackgroundCopyJob2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ReplaceRemotePrefix: ReplaceRemotePrefix::<Identity>,
+            AddFileWithRanges: AddFileWithRanges::<Identity>,
+            SetFileACLFlags: SetFileACLFlags::<Identity>,
+            GetFileACLFlags: GetFileACLFlags::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBackgroundCopyJob3 {}
 windows_core::imp::define_interface!(IBackgroundCopyJob4, IBackgroundCopyJob4_Vtbl, 0x659cdeae_489e_11d9_a9cd_000d56965251);
 impl core::ops::Deref for IBackgroundCopyJob4 {
@@ -2756,6 +3931,82 @@ impl IBackgroundCopyJob4_Vtbl {
         iid == &<IBackgroundCopyJob4 as windows_core::Interface>::IID || iid == &<IBackgroundCopyJob as windows_core::Interface>::IID || iid == &<IBackgroundCopyJob2 as windows_core::Interface>::IID || iid == &<IBackgroundCopyJob3 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob4_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyJob4_Vtbl
+where
+    IBackgroundCopyJob3_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetPeerCachingFlags<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob4_Impl>(this: *mut core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob4_Impl::SetPeerCachingFlags(this, core::mem::transmute_copy(&flags)).into()
+            }
+        }
+        unsafe extern "system" fn GetPeerCachingFlags<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob4_Impl>(this: *mut core::ffi::c_void, pflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob4_Impl::GetPeerCachingFlags(this) {
+                    Ok(ok__) => {
+                        pflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOwnerIntegrityLevel<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob4_Impl>(this: *mut core::ffi::c_void, plevel: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob4_Impl::GetOwnerIntegrityLevel(this) {
+                    Ok(ok__) => {
+                        plevel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOwnerElevationState<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob4_Impl>(this: *mut core::ffi::c_void, pelevated: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob4_Impl::GetOwnerElevationState(this) {
+                    Ok(ok__) => {
+                        pelevated.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMaximumDownloadTime<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob4_Impl>(this: *mut core::ffi::c_void, timeout: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob4_Impl::SetMaximumDownloadTime(this, core::mem::transmute_copy(&timeout)).into()
+            }
+        }
+        unsafe extern "system" fn GetMaximumDownloadTime<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob4_Impl>(this: *mut core::ffi::c_void, ptimeout: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob4_Impl::GetMaximumDownloadTime(this) {
+                    Ok(ok__) => {
+                        ptimeout.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IBackgroundCopyJob3_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetPeerCachingFlags: SetPeerCachingFlags::<Identity>,
+            GetPeerCachingFlags: GetPeerCachingFlags::<Identity>,
+            GetOwnerIntegrityLevel: GetOwnerIntegrityLevel::<Identity>,
+            GetOwnerElevationState: GetOwnerElevationState::<Identity>,
+            SetMaximumDownloadTime: SetMaximumDownloadTime::<Identity>,
+            GetMaximumDownloadTime: GetMaximumDownloadTime::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBackgroundCopyJob4 {}
 windows_core::imp::define_interface!(IBackgroundCopyJob5, IBackgroundCopyJob5_Vtbl, 0xe847030c_bbba_4657_af6d_484aa42bf1fe);
 impl core::ops::Deref for IBackgroundCopyJob5 {
@@ -2817,6 +4068,36 @@ impl IBackgroundCopyJob5_Vtbl {
         iid == &<IBackgroundCopyJob5 as windows_core::Interface>::IID || iid == &<IBackgroundCopyJob as windows_core::Interface>::IID || iid == &<IBackgroundCopyJob2 as windows_core::Interface>::IID || iid == &<IBackgroundCopyJob3 as windows_core::Interface>::IID || iid == &<IBackgroundCopyJob4 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob5_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyJob5_Vtbl
+where
+    IBackgroundCopyJob4_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetProperty<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob5_Impl>(this: *mut core::ffi::c_void, propertyid: BITS_JOB_PROPERTY_ID, propertyvalue: BITS_JOB_PROPERTY_VALUE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJob5_Impl::SetProperty(this, core::mem::transmute_copy(&propertyid), core::mem::transmute(&propertyvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetProperty<Identity: windows_core::IUnknownImpl + IBackgroundCopyJob5_Impl>(this: *mut core::ffi::c_void, propertyid: BITS_JOB_PROPERTY_ID, propertyvalue: *mut BITS_JOB_PROPERTY_VALUE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJob5_Impl::GetProperty(this, core::mem::transmute_copy(&propertyid)) {
+                    Ok(ok__) => {
+                        propertyvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IBackgroundCopyJob4_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetProperty: SetProperty::<Identity>,
+            GetProperty: GetProperty::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBackgroundCopyJob5 {}
 windows_core::imp::define_interface!(IBackgroundCopyJobHttpOptions, IBackgroundCopyJobHttpOptions_Vtbl, 0xf1bd1079_9f01_4bdc_8036_f09b70095066);
 windows_core::imp::interface_hierarchy!(IBackgroundCopyJobHttpOptions, windows_core::IUnknown);
@@ -2963,6 +4244,84 @@ impl IBackgroundCopyJobHttpOptions_Vtbl {
         iid == &<IBackgroundCopyJobHttpOptions as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyJobHttpOptions_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyJobHttpOptions_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetClientCertificateByID<Identity: windows_core::IUnknownImpl + IBackgroundCopyJobHttpOptions_Impl>(this: *mut core::ffi::c_void, storelocation: BG_CERT_STORE_LOCATION, storename: windows_core::PCWSTR, pcerthashblob: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJobHttpOptions_Impl::SetClientCertificateByID(this, core::mem::transmute_copy(&storelocation), core::mem::transmute(&storename), core::mem::transmute_copy(&pcerthashblob)).into()
+            }
+        }
+        unsafe extern "system" fn SetClientCertificateByName<Identity: windows_core::IUnknownImpl + IBackgroundCopyJobHttpOptions_Impl>(this: *mut core::ffi::c_void, storelocation: BG_CERT_STORE_LOCATION, storename: windows_core::PCWSTR, subjectname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJobHttpOptions_Impl::SetClientCertificateByName(this, core::mem::transmute_copy(&storelocation), core::mem::transmute(&storename), core::mem::transmute(&subjectname)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveClientCertificate<Identity: windows_core::IUnknownImpl + IBackgroundCopyJobHttpOptions_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJobHttpOptions_Impl::RemoveClientCertificate(this).into()
+            }
+        }
+        unsafe extern "system" fn GetClientCertificate<Identity: windows_core::IUnknownImpl + IBackgroundCopyJobHttpOptions_Impl>(this: *mut core::ffi::c_void, pstorelocation: *mut BG_CERT_STORE_LOCATION, pstorename: *mut windows_core::PWSTR, ppcerthashblob: *mut *mut u8, psubjectname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJobHttpOptions_Impl::GetClientCertificate(this, core::mem::transmute_copy(&pstorelocation), core::mem::transmute_copy(&pstorename), core::mem::transmute_copy(&ppcerthashblob), core::mem::transmute_copy(&psubjectname)).into()
+            }
+        }
+        unsafe extern "system" fn SetCustomHeaders<Identity: windows_core::IUnknownImpl + IBackgroundCopyJobHttpOptions_Impl>(this: *mut core::ffi::c_void, requestheaders: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJobHttpOptions_Impl::SetCustomHeaders(this, core::mem::transmute(&requestheaders)).into()
+            }
+        }
+        unsafe extern "system" fn GetCustomHeaders<Identity: windows_core::IUnknownImpl + IBackgroundCopyJobHttpOptions_Impl>(this: *mut core::ffi::c_void, prequestheaders: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJobHttpOptions_Impl::GetCustomHeaders(this) {
+                    Ok(ok__) => {
+                        prequestheaders.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSecurityFlags<Identity: windows_core::IUnknownImpl + IBackgroundCopyJobHttpOptions_Impl>(this: *mut core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJobHttpOptions_Impl::SetSecurityFlags(this, core::mem::transmute_copy(&flags)).into()
+            }
+        }
+        unsafe extern "system" fn GetSecurityFlags<Identity: windows_core::IUnknownImpl + IBackgroundCopyJobHttpOptions_Impl>(this: *mut core::ffi::c_void, pflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJobHttpOptions_Impl::GetSecurityFlags(this) {
+                    Ok(ok__) => {
+                        pflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetClientCertificateByID: SetClientCertificateByID::<Identity>,
+            SetClientCertificateByName: SetClientCertificateByName::<Identity>,
+            RemoveClientCertificate: RemoveClientCertificate::<Identity>,
+            GetClientCertificate: GetClientCertificate::<Identity>,
+            SetCustomHeaders: SetCustomHeaders::<Identity>,
+            GetCustomHeaders: GetCustomHeaders::<Identity>,
+            SetSecurityFlags: SetSecurityFlags::<Identity>,
+            GetSecurityFlags: GetSecurityFlags::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBackgroundCopyJobHttpOptions {}
 windows_core::imp::define_interface!(IBackgroundCopyJobHttpOptions2, IBackgroundCopyJobHttpOptions2_Vtbl, 0xb591a192_a405_4fc3_8323_4c5c542578fc);
 impl core::ops::Deref for IBackgroundCopyJobHttpOptions2 {
@@ -3027,6 +4386,36 @@ impl IBackgroundCopyJobHttpOptions2_Vtbl {
         iid == &<IBackgroundCopyJobHttpOptions2 as windows_core::Interface>::IID || iid == &<IBackgroundCopyJobHttpOptions as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyJobHttpOptions2_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyJobHttpOptions2_Vtbl
+where
+    IBackgroundCopyJobHttpOptions_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetHttpMethod<Identity: windows_core::IUnknownImpl + IBackgroundCopyJobHttpOptions2_Impl>(this: *mut core::ffi::c_void, method: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJobHttpOptions2_Impl::SetHttpMethod(this, core::mem::transmute(&method)).into()
+            }
+        }
+        unsafe extern "system" fn GetHttpMethod<Identity: windows_core::IUnknownImpl + IBackgroundCopyJobHttpOptions2_Impl>(this: *mut core::ffi::c_void, method: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyJobHttpOptions2_Impl::GetHttpMethod(this) {
+                    Ok(ok__) => {
+                        method.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IBackgroundCopyJobHttpOptions_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetHttpMethod: SetHttpMethod::<Identity>,
+            GetHttpMethod: GetHttpMethod::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBackgroundCopyJobHttpOptions2 {}
 windows_core::imp::define_interface!(IBackgroundCopyJobHttpOptions3, IBackgroundCopyJobHttpOptions3_Vtbl, 0x8a9263d3_fd4c_4eda_9b28_30132a4d4e3c);
 impl core::ops::Deref for IBackgroundCopyJobHttpOptions3 {
@@ -3082,6 +4471,30 @@ impl IBackgroundCopyJobHttpOptions3_Vtbl {
         iid == &<IBackgroundCopyJobHttpOptions3 as windows_core::Interface>::IID || iid == &<IBackgroundCopyJobHttpOptions as windows_core::Interface>::IID || iid == &<IBackgroundCopyJobHttpOptions2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyJobHttpOptions3_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyJobHttpOptions3_Vtbl
+where
+    IBackgroundCopyJobHttpOptions2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetServerCertificateValidationInterface<Identity: windows_core::IUnknownImpl + IBackgroundCopyJobHttpOptions3_Impl>(this: *mut core::ffi::c_void, certvalidationcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJobHttpOptions3_Impl::SetServerCertificateValidationInterface(this, core::mem::transmute_copy(&certvalidationcallback)).into()
+            }
+        }
+        unsafe extern "system" fn MakeCustomHeadersWriteOnly<Identity: windows_core::IUnknownImpl + IBackgroundCopyJobHttpOptions3_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyJobHttpOptions3_Impl::MakeCustomHeadersWriteOnly(this).into()
+            }
+        }
+        Self {
+            base__: <IBackgroundCopyJobHttpOptions2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetServerCertificateValidationInterface: SetServerCertificateValidationInterface::<Identity>,
+            MakeCustomHeadersWriteOnly: MakeCustomHeadersWriteOnly::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBackgroundCopyJobHttpOptions3 {}
 windows_core::imp::define_interface!(IBackgroundCopyManager, IBackgroundCopyManager_Vtbl, 0x5ce34c0d_0dc9_4c1f_897c_daa1b78cee7c);
 windows_core::imp::interface_hierarchy!(IBackgroundCopyManager, windows_core::IUnknown);
@@ -3130,13 +4543,72 @@ impl IBackgroundCopyManager_Vtbl {
     pub const fn new<Identity: IBackgroundCopyManager_Impl, const OFFSET: isize>() -> Self {
         unsafe extern "system" fn CreateJob<Identity: IBackgroundCopyManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, displayname: windows_core::PCWSTR, r#type: BG_JOB_TYPE, pjobid: *mut windows_core::GUID, ppjob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IBackgroundCopyManager_Impl::CreateJob(this, core::mem::transmute(&displayname), core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pjobid), core::mem::transmute_copy(&ppjob)).into()
+            }
+        }
+        unsafe extern "system" fn GetJob<Identity: IBackgroundCopyManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, jobid: *const windows_core::GUID, ppjob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IBackgroundCopyManager_Impl::GetJob(this, core::mem::transmute_copy(&jobid)) {
+                    Ok(ok__) => {
+                        ppjob.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumJobs<Identity: IBackgroundCopyManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwflags: u32, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IBackgroundCopyManager_Impl::EnumJobs(this, core::mem::transmute_copy(&dwflags)) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetErrorDescription<Identity: IBackgroundCopyManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hresult: windows_core::HRESULT, languageid: u32, perrordescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IBackgroundCopyManager_Impl::GetErrorDescription(this, core::mem::transmute_copy(&hresult), core::mem::transmute_copy(&languageid)) {
+                    Ok(ok__) => {
+                        perrordescription.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            CreateJob: CreateJob::<Identity, OFFSET>,
+            GetJob: GetJob::<Identity, OFFSET>,
+            EnumJobs: EnumJobs::<Identity, OFFSET>,
+            GetErrorDescription: GetErrorDescription::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyManager as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyManager_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateJob<Identity: windows_core::IUnknownImpl + IBackgroundCopyManager_Impl>(this: *mut core::ffi::c_void, displayname: windows_core::PCWSTR, r#type: BG_JOB_TYPE, pjobid: *mut windows_core::GUID, ppjob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IBackgroundCopyManager_Impl::CreateJob(this, core::mem::transmute(&displayname), core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pjobid), core::mem::transmute_copy(&ppjob)).into()
             }
         }
-        unsafe extern "system" fn GetJob<Identity: IBackgroundCopyManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, jobid: *const windows_core::GUID, ppjob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetJob<Identity: windows_core::IUnknownImpl + IBackgroundCopyManager_Impl>(this: *mut core::ffi::c_void, jobid: *const windows_core::GUID, ppjob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IBackgroundCopyManager_Impl::GetJob(this, core::mem::transmute_copy(&jobid)) {
                     Ok(ok__) => {
                         ppjob.write(core::mem::transmute(ok__));
@@ -3146,9 +4618,9 @@ impl IBackgroundCopyManager_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn EnumJobs<Identity: IBackgroundCopyManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwflags: u32, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn EnumJobs<Identity: windows_core::IUnknownImpl + IBackgroundCopyManager_Impl>(this: *mut core::ffi::c_void, dwflags: u32, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IBackgroundCopyManager_Impl::EnumJobs(this, core::mem::transmute_copy(&dwflags)) {
                     Ok(ok__) => {
                         ppenum.write(core::mem::transmute(ok__));
@@ -3158,9 +4630,9 @@ impl IBackgroundCopyManager_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetErrorDescription<Identity: IBackgroundCopyManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hresult: windows_core::HRESULT, languageid: u32, perrordescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetErrorDescription<Identity: windows_core::IUnknownImpl + IBackgroundCopyManager_Impl>(this: *mut core::ffi::c_void, hresult: windows_core::HRESULT, languageid: u32, perrordescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IBackgroundCopyManager_Impl::GetErrorDescription(this, core::mem::transmute_copy(&hresult), core::mem::transmute_copy(&languageid)) {
                     Ok(ok__) => {
                         perrordescription.write(core::mem::transmute(ok__));
@@ -3171,16 +4643,13 @@ impl IBackgroundCopyManager_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            CreateJob: CreateJob::<Identity, OFFSET>,
-            GetJob: GetJob::<Identity, OFFSET>,
-            EnumJobs: EnumJobs::<Identity, OFFSET>,
-            GetErrorDescription: GetErrorDescription::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateJob: CreateJob::<Identity>,
+            GetJob: GetJob::<Identity>,
+            EnumJobs: EnumJobs::<Identity>,
+            GetErrorDescription: GetErrorDescription::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IBackgroundCopyManager as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IBackgroundCopyManager {}
 windows_core::imp::define_interface!(IBackgroundCopyQMgr, IBackgroundCopyQMgr_Vtbl, 0x16f41c69_09f5_41d2_8cd8_3c08c47bc8a8);
@@ -3267,6 +4736,55 @@ impl IBackgroundCopyQMgr_Vtbl {
         iid == &<IBackgroundCopyQMgr as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyQMgr_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyQMgr_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateGroup<Identity: windows_core::IUnknownImpl + IBackgroundCopyQMgr_Impl>(this: *mut core::ffi::c_void, guidgroupid: windows_core::GUID, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyQMgr_Impl::CreateGroup(this, core::mem::transmute(&guidgroupid)) {
+                    Ok(ok__) => {
+                        ppgroup.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetGroup<Identity: windows_core::IUnknownImpl + IBackgroundCopyQMgr_Impl>(this: *mut core::ffi::c_void, groupid: windows_core::GUID, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyQMgr_Impl::GetGroup(this, core::mem::transmute(&groupid)) {
+                    Ok(ok__) => {
+                        ppgroup.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumGroups<Identity: windows_core::IUnknownImpl + IBackgroundCopyQMgr_Impl>(this: *mut core::ffi::c_void, dwflags: u32, ppenumgroups: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBackgroundCopyQMgr_Impl::EnumGroups(this, core::mem::transmute_copy(&dwflags)) {
+                    Ok(ok__) => {
+                        ppenumgroups.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateGroup: CreateGroup::<Identity>,
+            GetGroup: GetGroup::<Identity>,
+            EnumGroups: EnumGroups::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBackgroundCopyQMgr {}
 windows_core::imp::define_interface!(IBackgroundCopyServerCertificateValidationCallback, IBackgroundCopyServerCertificateValidationCallback_Vtbl, 0x4cec0d02_def7_4158_813a_c32a46945ff7);
 windows_core::imp::interface_hierarchy!(IBackgroundCopyServerCertificateValidationCallback, windows_core::IUnknown);
@@ -3302,6 +4820,23 @@ impl IBackgroundCopyServerCertificateValidationCallback_Vtbl {
         iid == &<IBackgroundCopyServerCertificateValidationCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBackgroundCopyServerCertificateValidationCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IBackgroundCopyServerCertificateValidationCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ValidateServerCertificate<Identity: windows_core::IUnknownImpl + IBackgroundCopyServerCertificateValidationCallback_Impl>(this: *mut core::ffi::c_void, job: *mut core::ffi::c_void, file: *mut core::ffi::c_void, certlength: u32, certdata: *const u8, certencodingtype: u32, certstorelength: u32, certstoredata: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBackgroundCopyServerCertificateValidationCallback_Impl::ValidateServerCertificate(this, core::mem::transmute_copy(&job), core::mem::transmute_copy(&file), core::mem::transmute_copy(&certlength), core::mem::transmute_copy(&certdata), core::mem::transmute_copy(&certencodingtype), core::mem::transmute_copy(&certstorelength), core::mem::transmute_copy(&certstoredata)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ValidateServerCertificate: ValidateServerCertificate::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBackgroundCopyServerCertificateValidationCallback {}
 windows_core::imp::define_interface!(IBitsPeer, IBitsPeer_Vtbl, 0x659cdea2_489e_11d9_a9cd_000d56965251);
 windows_core::imp::interface_hierarchy!(IBitsPeer, windows_core::IUnknown);
@@ -3387,6 +4922,55 @@ impl IBitsPeer_Vtbl {
         iid == &<IBitsPeer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBitsPeer_Impl> windows_core::imp::TearOffVtable<Identity> for IBitsPeer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPeerName<Identity: windows_core::IUnknownImpl + IBitsPeer_Impl>(this: *mut core::ffi::c_void, pname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBitsPeer_Impl::GetPeerName(this) {
+                    Ok(ok__) => {
+                        pname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsAuthenticated<Identity: windows_core::IUnknownImpl + IBitsPeer_Impl>(this: *mut core::ffi::c_void, pauth: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBitsPeer_Impl::IsAuthenticated(this) {
+                    Ok(ok__) => {
+                        pauth.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsAvailable<Identity: windows_core::IUnknownImpl + IBitsPeer_Impl>(this: *mut core::ffi::c_void, ponline: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBitsPeer_Impl::IsAvailable(this) {
+                    Ok(ok__) => {
+                        ponline.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPeerName: GetPeerName::<Identity>,
+            IsAuthenticated: IsAuthenticated::<Identity>,
+            IsAvailable: IsAvailable::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBitsPeer {}
 windows_core::imp::define_interface!(IBitsPeerCacheAdministration, IBitsPeerCacheAdministration_Vtbl, 0x659cdead_489e_11d9_a9cd_000d56965251);
 windows_core::imp::interface_hierarchy!(IBitsPeerCacheAdministration, windows_core::IUnknown);
@@ -3634,6 +5218,150 @@ impl IBitsPeerCacheAdministration_Vtbl {
         iid == &<IBitsPeerCacheAdministration as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBitsPeerCacheAdministration_Impl> windows_core::imp::TearOffVtable<Identity> for IBitsPeerCacheAdministration_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMaximumCacheSize<Identity: windows_core::IUnknownImpl + IBitsPeerCacheAdministration_Impl>(this: *mut core::ffi::c_void, pbytes: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBitsPeerCacheAdministration_Impl::GetMaximumCacheSize(this) {
+                    Ok(ok__) => {
+                        pbytes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMaximumCacheSize<Identity: windows_core::IUnknownImpl + IBitsPeerCacheAdministration_Impl>(this: *mut core::ffi::c_void, bytes: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBitsPeerCacheAdministration_Impl::SetMaximumCacheSize(this, core::mem::transmute_copy(&bytes)).into()
+            }
+        }
+        unsafe extern "system" fn GetMaximumContentAge<Identity: windows_core::IUnknownImpl + IBitsPeerCacheAdministration_Impl>(this: *mut core::ffi::c_void, pseconds: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBitsPeerCacheAdministration_Impl::GetMaximumContentAge(this) {
+                    Ok(ok__) => {
+                        pseconds.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMaximumContentAge<Identity: windows_core::IUnknownImpl + IBitsPeerCacheAdministration_Impl>(this: *mut core::ffi::c_void, seconds: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBitsPeerCacheAdministration_Impl::SetMaximumContentAge(this, core::mem::transmute_copy(&seconds))
+            }
+        }
+        unsafe extern "system" fn GetConfigurationFlags<Identity: windows_core::IUnknownImpl + IBitsPeerCacheAdministration_Impl>(this: *mut core::ffi::c_void, pflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBitsPeerCacheAdministration_Impl::GetConfigurationFlags(this) {
+                    Ok(ok__) => {
+                        pflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetConfigurationFlags<Identity: windows_core::IUnknownImpl + IBitsPeerCacheAdministration_Impl>(this: *mut core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBitsPeerCacheAdministration_Impl::SetConfigurationFlags(this, core::mem::transmute_copy(&flags)).into()
+            }
+        }
+        unsafe extern "system" fn EnumRecords<Identity: windows_core::IUnknownImpl + IBitsPeerCacheAdministration_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBitsPeerCacheAdministration_Impl::EnumRecords(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRecord<Identity: windows_core::IUnknownImpl + IBitsPeerCacheAdministration_Impl>(this: *mut core::ffi::c_void, id: *const windows_core::GUID, pprecord: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBitsPeerCacheAdministration_Impl::GetRecord(this, core::mem::transmute_copy(&id)) {
+                    Ok(ok__) => {
+                        pprecord.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ClearRecords<Identity: windows_core::IUnknownImpl + IBitsPeerCacheAdministration_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBitsPeerCacheAdministration_Impl::ClearRecords(this).into()
+            }
+        }
+        unsafe extern "system" fn DeleteRecord<Identity: windows_core::IUnknownImpl + IBitsPeerCacheAdministration_Impl>(this: *mut core::ffi::c_void, id: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBitsPeerCacheAdministration_Impl::DeleteRecord(this, core::mem::transmute_copy(&id)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteUrl<Identity: windows_core::IUnknownImpl + IBitsPeerCacheAdministration_Impl>(this: *mut core::ffi::c_void, url: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBitsPeerCacheAdministration_Impl::DeleteUrl(this, core::mem::transmute(&url)).into()
+            }
+        }
+        unsafe extern "system" fn EnumPeers<Identity: windows_core::IUnknownImpl + IBitsPeerCacheAdministration_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBitsPeerCacheAdministration_Impl::EnumPeers(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ClearPeers<Identity: windows_core::IUnknownImpl + IBitsPeerCacheAdministration_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBitsPeerCacheAdministration_Impl::ClearPeers(this).into()
+            }
+        }
+        unsafe extern "system" fn DiscoverPeers<Identity: windows_core::IUnknownImpl + IBitsPeerCacheAdministration_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBitsPeerCacheAdministration_Impl::DiscoverPeers(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetMaximumCacheSize: GetMaximumCacheSize::<Identity>,
+            SetMaximumCacheSize: SetMaximumCacheSize::<Identity>,
+            GetMaximumContentAge: GetMaximumContentAge::<Identity>,
+            SetMaximumContentAge: SetMaximumContentAge::<Identity>,
+            GetConfigurationFlags: GetConfigurationFlags::<Identity>,
+            SetConfigurationFlags: SetConfigurationFlags::<Identity>,
+            EnumRecords: EnumRecords::<Identity>,
+            GetRecord: GetRecord::<Identity>,
+            ClearRecords: ClearRecords::<Identity>,
+            DeleteRecord: DeleteRecord::<Identity>,
+            DeleteUrl: DeleteUrl::<Identity>,
+            EnumPeers: EnumPeers::<Identity>,
+            ClearPeers: ClearPeers::<Identity>,
+            DiscoverPeers: DiscoverPeers::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBitsPeerCacheAdministration {}
 windows_core::imp::define_interface!(IBitsPeerCacheRecord, IBitsPeerCacheRecord_Vtbl, 0x659cdeaf_489e_11d9_a9cd_000d56965251);
 windows_core::imp::interface_hierarchy!(IBitsPeerCacheRecord, windows_core::IUnknown);
@@ -3771,19 +5499,108 @@ impl IBitsPeerCacheRecord_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            GetId: GetId::<Identity, OFFSET>,
-            GetOriginUrl: GetOriginUrl::<Identity, OFFSET>,
-            GetFileSize: GetFileSize::<Identity, OFFSET>,
-            GetFileModificationTime: GetFileModificationTime::<Identity, OFFSET>,
-            GetLastAccessTime: GetLastAccessTime::<Identity, OFFSET>,
-            IsFileValidated: IsFileValidated::<Identity, OFFSET>,
-            GetFileRanges: GetFileRanges::<Identity, OFFSET>,
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            GetId: GetId::<Identity, OFFSET>,
+            GetOriginUrl: GetOriginUrl::<Identity, OFFSET>,
+            GetFileSize: GetFileSize::<Identity, OFFSET>,
+            GetFileModificationTime: GetFileModificationTime::<Identity, OFFSET>,
+            GetLastAccessTime: GetLastAccessTime::<Identity, OFFSET>,
+            IsFileValidated: IsFileValidated::<Identity, OFFSET>,
+            GetFileRanges: GetFileRanges::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBitsPeerCacheRecord as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IBitsPeerCacheRecord_Impl> windows_core::imp::TearOffVtable<Identity> for IBitsPeerCacheRecord_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetId<Identity: windows_core::IUnknownImpl + IBitsPeerCacheRecord_Impl>(this: *mut core::ffi::c_void, pval: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBitsPeerCacheRecord_Impl::GetId(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOriginUrl<Identity: windows_core::IUnknownImpl + IBitsPeerCacheRecord_Impl>(this: *mut core::ffi::c_void, pval: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBitsPeerCacheRecord_Impl::GetOriginUrl(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFileSize<Identity: windows_core::IUnknownImpl + IBitsPeerCacheRecord_Impl>(this: *mut core::ffi::c_void, pval: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBitsPeerCacheRecord_Impl::GetFileSize(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFileModificationTime<Identity: windows_core::IUnknownImpl + IBitsPeerCacheRecord_Impl>(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBitsPeerCacheRecord_Impl::GetFileModificationTime(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLastAccessTime<Identity: windows_core::IUnknownImpl + IBitsPeerCacheRecord_Impl>(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBitsPeerCacheRecord_Impl::GetLastAccessTime(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsFileValidated<Identity: windows_core::IUnknownImpl + IBitsPeerCacheRecord_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBitsPeerCacheRecord_Impl::IsFileValidated(this).into()
+            }
+        }
+        unsafe extern "system" fn GetFileRanges<Identity: windows_core::IUnknownImpl + IBitsPeerCacheRecord_Impl>(this: *mut core::ffi::c_void, prangecount: *mut u32, ppranges: *mut *mut BG_FILE_RANGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBitsPeerCacheRecord_Impl::GetFileRanges(this, core::mem::transmute_copy(&prangecount), core::mem::transmute_copy(&ppranges)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetId: GetId::<Identity>,
+            GetOriginUrl: GetOriginUrl::<Identity>,
+            GetFileSize: GetFileSize::<Identity>,
+            GetFileModificationTime: GetFileModificationTime::<Identity>,
+            GetLastAccessTime: GetLastAccessTime::<Identity>,
+            IsFileValidated: IsFileValidated::<Identity>,
+            GetFileRanges: GetFileRanges::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IBitsPeerCacheRecord as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IBitsPeerCacheRecord {}
 windows_core::imp::define_interface!(IBitsTokenOptions, IBitsTokenOptions_Vtbl, 0x9a2584c3_f7d2_457a_9a5e_22b67bffc7d2);
@@ -3885,6 +5702,63 @@ impl IBitsTokenOptions_Vtbl {
         iid == &<IBitsTokenOptions as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBitsTokenOptions_Impl> windows_core::imp::TearOffVtable<Identity> for IBitsTokenOptions_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetHelperTokenFlags<Identity: windows_core::IUnknownImpl + IBitsTokenOptions_Impl>(this: *mut core::ffi::c_void, usageflags: BG_TOKEN) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBitsTokenOptions_Impl::SetHelperTokenFlags(this, core::mem::transmute_copy(&usageflags)).into()
+            }
+        }
+        unsafe extern "system" fn GetHelperTokenFlags<Identity: windows_core::IUnknownImpl + IBitsTokenOptions_Impl>(this: *mut core::ffi::c_void, pflags: *mut BG_TOKEN) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBitsTokenOptions_Impl::GetHelperTokenFlags(this) {
+                    Ok(ok__) => {
+                        pflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetHelperToken<Identity: windows_core::IUnknownImpl + IBitsTokenOptions_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBitsTokenOptions_Impl::SetHelperToken(this).into()
+            }
+        }
+        unsafe extern "system" fn ClearHelperToken<Identity: windows_core::IUnknownImpl + IBitsTokenOptions_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBitsTokenOptions_Impl::ClearHelperToken(this).into()
+            }
+        }
+        unsafe extern "system" fn GetHelperTokenSid<Identity: windows_core::IUnknownImpl + IBitsTokenOptions_Impl>(this: *mut core::ffi::c_void, psid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBitsTokenOptions_Impl::GetHelperTokenSid(this) {
+                    Ok(ok__) => {
+                        psid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetHelperTokenFlags: SetHelperTokenFlags::<Identity>,
+            GetHelperTokenFlags: GetHelperTokenFlags::<Identity>,
+            SetHelperToken: SetHelperToken::<Identity>,
+            ClearHelperToken: ClearHelperToken::<Identity>,
+            GetHelperTokenSid: GetHelperTokenSid::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IBitsTokenOptions {}
 windows_core::imp::define_interface!(IEnumBackgroundCopyFiles, IEnumBackgroundCopyFiles_Vtbl, 0xca51e165_c365_424c_8d41_24aaa4ff3c40);
 windows_core::imp::interface_hierarchy!(IEnumBackgroundCopyFiles, windows_core::IUnknown);
@@ -3985,6 +5859,63 @@ impl IEnumBackgroundCopyFiles_Vtbl {
         iid == &<IEnumBackgroundCopyFiles as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyFiles_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumBackgroundCopyFiles_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyFiles_Impl>(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumBackgroundCopyFiles_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgelt), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyFiles_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumBackgroundCopyFiles_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyFiles_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumBackgroundCopyFiles_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyFiles_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumBackgroundCopyFiles_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyFiles_Impl>(this: *mut core::ffi::c_void, pucount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumBackgroundCopyFiles_Impl::GetCount(this) {
+                    Ok(ok__) => {
+                        pucount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+            GetCount: GetCount::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumBackgroundCopyFiles {}
 windows_core::imp::define_interface!(IEnumBackgroundCopyGroups, IEnumBackgroundCopyGroups_Vtbl, 0xd993e603_4aa4_47c5_8665_c20d39c2ba4f);
 windows_core::imp::interface_hierarchy!(IEnumBackgroundCopyGroups, windows_core::IUnknown);
@@ -4085,6 +6016,63 @@ impl IEnumBackgroundCopyGroups_Vtbl {
         iid == &<IEnumBackgroundCopyGroups as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyGroups_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumBackgroundCopyGroups_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyGroups_Impl>(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut windows_core::GUID, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumBackgroundCopyGroups_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgelt), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyGroups_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumBackgroundCopyGroups_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyGroups_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumBackgroundCopyGroups_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyGroups_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumBackgroundCopyGroups_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyGroups_Impl>(this: *mut core::ffi::c_void, pucount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumBackgroundCopyGroups_Impl::GetCount(this) {
+                    Ok(ok__) => {
+                        pucount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+            GetCount: GetCount::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumBackgroundCopyGroups {}
 windows_core::imp::define_interface!(IEnumBackgroundCopyJobs, IEnumBackgroundCopyJobs_Vtbl, 0x1af4f612_3b71_466f_8f58_7b6f73ac57ad);
 windows_core::imp::interface_hierarchy!(IEnumBackgroundCopyJobs, windows_core::IUnknown);
@@ -4185,6 +6173,63 @@ impl IEnumBackgroundCopyJobs_Vtbl {
         iid == &<IEnumBackgroundCopyJobs as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyJobs_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumBackgroundCopyJobs_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyJobs_Impl>(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumBackgroundCopyJobs_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgelt), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyJobs_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumBackgroundCopyJobs_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyJobs_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumBackgroundCopyJobs_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyJobs_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumBackgroundCopyJobs_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyJobs_Impl>(this: *mut core::ffi::c_void, pucount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumBackgroundCopyJobs_Impl::GetCount(this) {
+                    Ok(ok__) => {
+                        pucount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+            GetCount: GetCount::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumBackgroundCopyJobs {}
 windows_core::imp::define_interface!(IEnumBackgroundCopyJobs1, IEnumBackgroundCopyJobs1_Vtbl, 0x8baeba9d_8f1c_42c4_b82c_09ae79980d25);
 windows_core::imp::interface_hierarchy!(IEnumBackgroundCopyJobs1, windows_core::IUnknown);
@@ -4285,6 +6330,63 @@ impl IEnumBackgroundCopyJobs1_Vtbl {
         iid == &<IEnumBackgroundCopyJobs1 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyJobs1_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumBackgroundCopyJobs1_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyJobs1_Impl>(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut windows_core::GUID, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumBackgroundCopyJobs1_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgelt), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyJobs1_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumBackgroundCopyJobs1_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyJobs1_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumBackgroundCopyJobs1_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyJobs1_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumBackgroundCopyJobs1_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IEnumBackgroundCopyJobs1_Impl>(this: *mut core::ffi::c_void, pucount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumBackgroundCopyJobs1_Impl::GetCount(this) {
+                    Ok(ok__) => {
+                        pucount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+            GetCount: GetCount::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumBackgroundCopyJobs1 {}
 windows_core::imp::define_interface!(IEnumBitsPeerCacheRecords, IEnumBitsPeerCacheRecords_Vtbl, 0x659cdea4_489e_11d9_a9cd_000d56965251);
 windows_core::imp::interface_hierarchy!(IEnumBitsPeerCacheRecords, windows_core::IUnknown);
@@ -4385,6 +6487,63 @@ impl IEnumBitsPeerCacheRecords_Vtbl {
         iid == &<IEnumBitsPeerCacheRecords as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumBitsPeerCacheRecords_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumBitsPeerCacheRecords_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumBitsPeerCacheRecords_Impl>(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumBitsPeerCacheRecords_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgelt), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumBitsPeerCacheRecords_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumBitsPeerCacheRecords_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumBitsPeerCacheRecords_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumBitsPeerCacheRecords_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumBitsPeerCacheRecords_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumBitsPeerCacheRecords_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IEnumBitsPeerCacheRecords_Impl>(this: *mut core::ffi::c_void, pucount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumBitsPeerCacheRecords_Impl::GetCount(this) {
+                    Ok(ok__) => {
+                        pucount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+            GetCount: GetCount::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumBitsPeerCacheRecords {}
 windows_core::imp::define_interface!(IEnumBitsPeers, IEnumBitsPeers_Vtbl, 0x659cdea5_489e_11d9_a9cd_000d56965251);
 windows_core::imp::interface_hierarchy!(IEnumBitsPeers, windows_core::IUnknown);
@@ -4485,6 +6644,63 @@ impl IEnumBitsPeers_Vtbl {
         iid == &<IEnumBitsPeers as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumBitsPeers_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumBitsPeers_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumBitsPeers_Impl>(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumBitsPeers_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgelt), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumBitsPeers_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumBitsPeers_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumBitsPeers_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumBitsPeers_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumBitsPeers_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumBitsPeers_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IEnumBitsPeers_Impl>(this: *mut core::ffi::c_void, pucount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumBitsPeers_Impl::GetCount(this) {
+                    Ok(ok__) => {
+                        pucount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+            GetCount: GetCount::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumBitsPeers {}
 pub const QM_E_DOWNLOADER_UNAVAILABLE: u32 = 2164264963u32;
 pub const QM_E_INVALID_STATE: u32 = 2164264961u32;
@@ -62,6 +62,36 @@ impl IFindSimilarResults_Vtbl {
         iid == &<IFindSimilarResults as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IFindSimilarResults_Impl> windows_core::imp::TearOffVtable<Identity> for IFindSimilarResults_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSize<Identity: windows_core::IUnknownImpl + IFindSimilarResults_Impl>(this: *mut core::ffi::c_void, size: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFindSimilarResults_Impl::GetSize(this) {
+                    Ok(ok__) => {
+                        size.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetNextFileId<Identity: windows_core::IUnknownImpl + IFindSimilarResults_Impl>(this: *mut core::ffi::c_void, numtraitsmatched: *mut u32, similarityfileid: *mut SimilarityFileId) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFindSimilarResults_Impl::GetNextFileId(this, core::mem::transmute_copy(&numtraitsmatched), core::mem::transmute_copy(&similarityfileid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSize: GetSize::<Identity>,
+            GetNextFileId: GetNextFileId::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IFindSimilarResults {}
 windows_core::imp::define_interface!(IRdcComparator, IRdcComparator_Vtbl, 0x96236a77_9dbc_11da_9e3f_0011114ae311);
 windows_core::imp::interface_hierarchy!(IRdcComparator, windows_core::IUnknown);
@@ -93,6 +123,20 @@ impl IRdcComparator_Vtbl {
         iid == &<IRdcComparator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRdcComparator_Impl> windows_core::imp::TearOffVtable<Identity> for IRdcComparator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Process<Identity: windows_core::IUnknownImpl + IRdcComparator_Impl>(this: *mut core::ffi::c_void, endofinput: windows_core::BOOL, endofoutput: *mut windows_core::BOOL, inputbuffer: *mut RdcBufferPointer, outputbuffer: *mut RdcNeedPointer, rdc_errorcode: *mut RDC_ErrorCode) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRdcComparator_Impl::Process(this, core::mem::transmute_copy(&endofinput), core::mem::transmute_copy(&endofoutput), core::mem::transmute_copy(&inputbuffer), core::mem::transmute_copy(&outputbuffer), core::mem::transmute_copy(&rdc_errorcode)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Process: Process::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IRdcComparator {}
 windows_core::imp::define_interface!(IRdcFileReader, IRdcFileReader_Vtbl, 0x96236a74_9dbc_11da_9e3f_0011114ae311);
 windows_core::imp::interface_hierarchy!(IRdcFileReader, windows_core::IUnknown);
@@ -169,6 +213,49 @@ impl IRdcFileReader_Vtbl {
         iid == &<IRdcFileReader as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRdcFileReader_Impl> windows_core::imp::TearOffVtable<Identity> for IRdcFileReader_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFileSize<Identity: windows_core::IUnknownImpl + IRdcFileReader_Impl>(this: *mut core::ffi::c_void, filesize: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRdcFileReader_Impl::GetFileSize(this) {
+                    Ok(ok__) => {
+                        filesize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Read<Identity: windows_core::IUnknownImpl + IRdcFileReader_Impl>(this: *mut core::ffi::c_void, offsetfilestart: u64, bytestoread: u32, bytesactuallyread: *mut u32, buffer: *mut u8, eof: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRdcFileReader_Impl::Read(this, core::mem::transmute_copy(&offsetfilestart), core::mem::transmute_copy(&bytestoread), core::mem::transmute_copy(&bytesactuallyread), core::mem::transmute_copy(&buffer), core::mem::transmute_copy(&eof)).into()
+            }
+        }
+        unsafe extern "system" fn GetFilePosition<Identity: windows_core::IUnknownImpl + IRdcFileReader_Impl>(this: *mut core::ffi::c_void, offsetfromstart: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRdcFileReader_Impl::GetFilePosition(this) {
+                    Ok(ok__) => {
+                        offsetfromstart.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFileSize: GetFileSize::<Identity>,
+            Read: Read::<Identity>,
+            GetFilePosition: GetFilePosition::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRdcFileReader {}
 windows_core::imp::define_interface!(IRdcFileWriter, IRdcFileWriter_Vtbl, 0x96236a75_9dbc_11da_9e3f_0011114ae311);
 impl core::ops::Deref for IRdcFileWriter {
@@ -242,6 +329,43 @@ impl IRdcFileWriter_Vtbl {
         iid == &<IRdcFileWriter as windows_core::Interface>::IID || iid == &<IRdcFileReader as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRdcFileWriter_Impl> windows_core::imp::TearOffVtable<Identity> for IRdcFileWriter_Vtbl
+where
+    IRdcFileReader_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Write<Identity: windows_core::IUnknownImpl + IRdcFileWriter_Impl>(this: *mut core::ffi::c_void, offsetfilestart: u64, bytestowrite: u32, buffer: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRdcFileWriter_Impl::Write(this, core::mem::transmute_copy(&offsetfilestart), core::mem::transmute_copy(&bytestowrite)) {
+                    Ok(ok__) => {
+                        buffer.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Truncate<Identity: windows_core::IUnknownImpl + IRdcFileWriter_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRdcFileWriter_Impl::Truncate(this).into()
+            }
+        }
+        unsafe extern "system" fn DeleteOnClose<Identity: windows_core::IUnknownImpl + IRdcFileWriter_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRdcFileWriter_Impl::DeleteOnClose(this).into()
+            }
+        }
+        Self {
+            base__: <IRdcFileReader_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Write: Write::<Identity>,
+            Truncate: Truncate::<Identity>,
+            DeleteOnClose: DeleteOnClose::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRdcFileWriter {}
 windows_core::imp::define_interface!(IRdcGenerator, IRdcGenerator_Vtbl, 0x96236a73_9dbc_11da_9e3f_0011114ae311);
 windows_core::imp::interface_hierarchy!(IRdcGenerator, windows_core::IUnknown);
@@ -297,6 +421,36 @@ impl IRdcGenerator_Vtbl {
         iid == &<IRdcGenerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRdcGenerator_Impl> windows_core::imp::TearOffVtable<Identity> for IRdcGenerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetGeneratorParameters<Identity: windows_core::IUnknownImpl + IRdcGenerator_Impl>(this: *mut core::ffi::c_void, level: u32, igeneratorparameters: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRdcGenerator_Impl::GetGeneratorParameters(this, core::mem::transmute_copy(&level)) {
+                    Ok(ok__) => {
+                        igeneratorparameters.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Process<Identity: windows_core::IUnknownImpl + IRdcGenerator_Impl>(this: *mut core::ffi::c_void, endofinput: windows_core::BOOL, endofoutput: *mut windows_core::BOOL, inputbuffer: *mut RdcBufferPointer, depth: u32, outputbuffers: *mut *mut RdcBufferPointer, rdc_errorcode: *mut RDC_ErrorCode) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRdcGenerator_Impl::Process(this, core::mem::transmute_copy(&endofinput), core::mem::transmute_copy(&endofoutput), core::mem::transmute_copy(&inputbuffer), core::mem::transmute_copy(&depth), core::mem::transmute_copy(&outputbuffers), core::mem::transmute_copy(&rdc_errorcode)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetGeneratorParameters: GetGeneratorParameters::<Identity>,
+            Process: Process::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRdcGenerator {}
 windows_core::imp::define_interface!(IRdcGeneratorFilterMaxParameters, IRdcGeneratorFilterMaxParameters_Vtbl, 0x96236a72_9dbc_11da_9e3f_0011114ae311);
 windows_core::imp::interface_hierarchy!(IRdcGeneratorFilterMaxParameters, windows_core::IUnknown);
@@ -385,6 +539,56 @@ impl IRdcGeneratorFilterMaxParameters_Vtbl {
         iid == &<IRdcGeneratorFilterMaxParameters as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRdcGeneratorFilterMaxParameters_Impl> windows_core::imp::TearOffVtable<Identity> for IRdcGeneratorFilterMaxParameters_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetHorizonSize<Identity: windows_core::IUnknownImpl + IRdcGeneratorFilterMaxParameters_Impl>(this: *mut core::ffi::c_void, horizonsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRdcGeneratorFilterMaxParameters_Impl::GetHorizonSize(this) {
+                    Ok(ok__) => {
+                        horizonsize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetHorizonSize<Identity: windows_core::IUnknownImpl + IRdcGeneratorFilterMaxParameters_Impl>(this: *mut core::ffi::c_void, horizonsize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRdcGeneratorFilterMaxParameters_Impl::SetHorizonSize(this, core::mem::transmute_copy(&horizonsize)).into()
+            }
+        }
+        unsafe extern "system" fn GetHashWindowSize<Identity: windows_core::IUnknownImpl + IRdcGeneratorFilterMaxParameters_Impl>(this: *mut core::ffi::c_void, hashwindowsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRdcGeneratorFilterMaxParameters_Impl::GetHashWindowSize(this) {
+                    Ok(ok__) => {
+                        hashwindowsize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetHashWindowSize<Identity: windows_core::IUnknownImpl + IRdcGeneratorFilterMaxParameters_Impl>(this: *mut core::ffi::c_void, hashwindowsize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRdcGeneratorFilterMaxParameters_Impl::SetHashWindowSize(this, core::mem::transmute_copy(&hashwindowsize)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetHorizonSize: GetHorizonSize::<Identity>,
+            SetHorizonSize: SetHorizonSize::<Identity>,
+            GetHashWindowSize: GetHashWindowSize::<Identity>,
+            SetHashWindowSize: SetHashWindowSize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRdcGeneratorFilterMaxParameters {}
 windows_core::imp::define_interface!(IRdcGeneratorParameters, IRdcGeneratorParameters_Vtbl, 0x96236a71_9dbc_11da_9e3f_0011114ae311);
 windows_core::imp::interface_hierarchy!(IRdcGeneratorParameters, windows_core::IUnknown);
@@ -473,6 +677,56 @@ impl IRdcGeneratorParameters_Vtbl {
         iid == &<IRdcGeneratorParameters as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRdcGeneratorParameters_Impl> windows_core::imp::TearOffVtable<Identity> for IRdcGeneratorParameters_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetGeneratorParametersType<Identity: windows_core::IUnknownImpl + IRdcGeneratorParameters_Impl>(this: *mut core::ffi::c_void, parameterstype: *mut GeneratorParametersType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRdcGeneratorParameters_Impl::GetGeneratorParametersType(this) {
+                    Ok(ok__) => {
+                        parameterstype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetParametersVersion<Identity: windows_core::IUnknownImpl + IRdcGeneratorParameters_Impl>(this: *mut core::ffi::c_void, currentversion: *mut u32, minimumcompatibleappversion: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRdcGeneratorParameters_Impl::GetParametersVersion(this, core::mem::transmute_copy(&currentversion), core::mem::transmute_copy(&minimumcompatibleappversion)).into()
+            }
+        }
+        unsafe extern "system" fn GetSerializeSize<Identity: windows_core::IUnknownImpl + IRdcGeneratorParameters_Impl>(this: *mut core::ffi::c_void, size: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRdcGeneratorParameters_Impl::GetSerializeSize(this) {
+                    Ok(ok__) => {
+                        size.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Serialize<Identity: windows_core::IUnknownImpl + IRdcGeneratorParameters_Impl>(this: *mut core::ffi::c_void, size: u32, parametersblob: *mut u8, byteswritten: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRdcGeneratorParameters_Impl::Serialize(this, core::mem::transmute_copy(&size), core::mem::transmute_copy(&parametersblob), core::mem::transmute_copy(&byteswritten)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetGeneratorParametersType: GetGeneratorParametersType::<Identity>,
+            GetParametersVersion: GetParametersVersion::<Identity>,
+            GetSerializeSize: GetSerializeSize::<Identity>,
+            Serialize: Serialize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRdcGeneratorParameters {}
 windows_core::imp::define_interface!(IRdcLibrary, IRdcLibrary_Vtbl, 0x96236a78_9dbc_11da_9e3f_0011114ae311);
 windows_core::imp::interface_hierarchy!(IRdcLibrary, windows_core::IUnknown);
@@ -639,6 +893,101 @@ impl IRdcLibrary_Vtbl {
         iid == &<IRdcLibrary as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRdcLibrary_Impl> windows_core::imp::TearOffVtable<Identity> for IRdcLibrary_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ComputeDefaultRecursionDepth<Identity: windows_core::IUnknownImpl + IRdcLibrary_Impl>(this: *mut core::ffi::c_void, filesize: u64, depth: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRdcLibrary_Impl::ComputeDefaultRecursionDepth(this, core::mem::transmute_copy(&filesize)) {
+                    Ok(ok__) => {
+                        depth.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateGeneratorParameters<Identity: windows_core::IUnknownImpl + IRdcLibrary_Impl>(this: *mut core::ffi::c_void, parameterstype: GeneratorParametersType, level: u32, igeneratorparameters: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRdcLibrary_Impl::CreateGeneratorParameters(this, core::mem::transmute_copy(&parameterstype), core::mem::transmute_copy(&level)) {
+                    Ok(ok__) => {
+                        igeneratorparameters.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OpenGeneratorParameters<Identity: windows_core::IUnknownImpl + IRdcLibrary_Impl>(this: *mut core::ffi::c_void, size: u32, parametersblob: *const u8, igeneratorparameters: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRdcLibrary_Impl::OpenGeneratorParameters(this, core::mem::transmute_copy(&size), core::mem::transmute_copy(&parametersblob)) {
+                    Ok(ok__) => {
+                        igeneratorparameters.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateGenerator<Identity: windows_core::IUnknownImpl + IRdcLibrary_Impl>(this: *mut core::ffi::c_void, depth: u32, igeneratorparametersarray: *const *mut core::ffi::c_void, igenerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRdcLibrary_Impl::CreateGenerator(this, core::mem::transmute_copy(&depth), core::mem::transmute_copy(&igeneratorparametersarray)) {
+                    Ok(ok__) => {
+                        igenerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateComparator<Identity: windows_core::IUnknownImpl + IRdcLibrary_Impl>(this: *mut core::ffi::c_void, iseedsignaturesfile: *mut core::ffi::c_void, comparatorbuffersize: u32, icomparator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRdcLibrary_Impl::CreateComparator(this, core::mem::transmute_copy(&iseedsignaturesfile), core::mem::transmute_copy(&comparatorbuffersize)) {
+                    Ok(ok__) => {
+                        icomparator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSignatureReader<Identity: windows_core::IUnknownImpl + IRdcLibrary_Impl>(this: *mut core::ffi::c_void, ifilereader: *mut core::ffi::c_void, isignaturereader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRdcLibrary_Impl::CreateSignatureReader(this, core::mem::transmute_copy(&ifilereader)) {
+                    Ok(ok__) => {
+                        isignaturereader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRDCVersion<Identity: windows_core::IUnknownImpl + IRdcLibrary_Impl>(this: *mut core::ffi::c_void, currentversion: *mut u32, minimumcompatibleappversion: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRdcLibrary_Impl::GetRDCVersion(this, core::mem::transmute_copy(&currentversion), core::mem::transmute_copy(&minimumcompatibleappversion)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ComputeDefaultRecursionDepth: ComputeDefaultRecursionDepth::<Identity>,
+            CreateGeneratorParameters: CreateGeneratorParameters::<Identity>,
+            OpenGeneratorParameters: OpenGeneratorParameters::<Identity>,
+            CreateGenerator: CreateGenerator::<Identity>,
+            CreateComparator: CreateComparator::<Identity>,
+            CreateSignatureReader: CreateSignatureReader::<Identity>,
+            GetRDCVersion: GetRDCVersion::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRdcLibrary {}
 windows_core::imp::define_interface!(IRdcSignatureReader, IRdcSignatureReader_Vtbl, 0x96236a76_9dbc_11da_9e3f_0011114ae311);
 windows_core::imp::interface_hierarchy!(IRdcSignatureReader, windows_core::IUnknown);
@@ -694,6 +1043,36 @@ impl IRdcSignatureReader_Vtbl {
         iid == &<IRdcSignatureReader as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRdcSignatureReader_Impl> windows_core::imp::TearOffVtable<Identity> for IRdcSignatureReader_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ReadHeader<Identity: windows_core::IUnknownImpl + IRdcSignatureReader_Impl>(this: *mut core::ffi::c_void, rdc_errorcode: *mut RDC_ErrorCode) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRdcSignatureReader_Impl::ReadHeader(this) {
+                    Ok(ok__) => {
+                        rdc_errorcode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReadSignatures<Identity: windows_core::IUnknownImpl + IRdcSignatureReader_Impl>(this: *mut core::ffi::c_void, rdcsignaturepointer: *mut RdcSignaturePointer, endofoutput: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRdcSignatureReader_Impl::ReadSignatures(this, core::mem::transmute_copy(&rdcsignaturepointer), core::mem::transmute_copy(&endofoutput)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ReadHeader: ReadHeader::<Identity>,
+            ReadSignatures: ReadSignatures::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRdcSignatureReader {}
 windows_core::imp::define_interface!(IRdcSimilarityGenerator, IRdcSimilarityGenerator_Vtbl, 0x96236a80_9dbc_11da_9e3f_0011114ae311);
 windows_core::imp::interface_hierarchy!(IRdcSimilarityGenerator, windows_core::IUnknown);
@@ -749,6 +1128,36 @@ impl IRdcSimilarityGenerator_Vtbl {
         iid == &<IRdcSimilarityGenerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRdcSimilarityGenerator_Impl> windows_core::imp::TearOffVtable<Identity> for IRdcSimilarityGenerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnableSimilarity<Identity: windows_core::IUnknownImpl + IRdcSimilarityGenerator_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRdcSimilarityGenerator_Impl::EnableSimilarity(this).into()
+            }
+        }
+        unsafe extern "system" fn Results<Identity: windows_core::IUnknownImpl + IRdcSimilarityGenerator_Impl>(this: *mut core::ffi::c_void, similaritydata: *mut SimilarityData) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRdcSimilarityGenerator_Impl::Results(this) {
+                    Ok(ok__) => {
+                        similaritydata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnableSimilarity: EnableSimilarity::<Identity>,
+            Results: Results::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRdcSimilarityGenerator {}
 windows_core::imp::define_interface!(ISimilarity, ISimilarity_Vtbl, 0x96236a83_9dbc_11da_9e3f_0011114ae311);
 windows_core::imp::interface_hierarchy!(ISimilarity, windows_core::IUnknown);
@@ -902,6 +1311,89 @@ impl ISimilarity_Vtbl {
         iid == &<ISimilarity as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISimilarity_Impl> windows_core::imp::TearOffVtable<Identity> for ISimilarity_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateTable<Identity: windows_core::IUnknownImpl + ISimilarity_Impl>(this: *mut core::ffi::c_void, path: windows_core::PCWSTR, truncate: windows_core::BOOL, securitydescriptor: *const u8, recordsize: u32, isnew: *mut RdcCreatedTables) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISimilarity_Impl::CreateTable(this, core::mem::transmute(&path), core::mem::transmute_copy(&truncate), core::mem::transmute_copy(&securitydescriptor), core::mem::transmute_copy(&recordsize)) {
+                    Ok(ok__) => {
+                        isnew.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateTableIndirect<Identity: windows_core::IUnknownImpl + ISimilarity_Impl>(this: *mut core::ffi::c_void, mapping: *mut core::ffi::c_void, fileidfile: *mut core::ffi::c_void, truncate: windows_core::BOOL, recordsize: u32, isnew: *mut RdcCreatedTables) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISimilarity_Impl::CreateTableIndirect(this, core::mem::transmute_copy(&mapping), core::mem::transmute_copy(&fileidfile), core::mem::transmute_copy(&truncate), core::mem::transmute_copy(&recordsize)) {
+                    Ok(ok__) => {
+                        isnew.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CloseTable<Identity: windows_core::IUnknownImpl + ISimilarity_Impl>(this: *mut core::ffi::c_void, isvalid: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISimilarity_Impl::CloseTable(this, core::mem::transmute_copy(&isvalid)).into()
+            }
+        }
+        unsafe extern "system" fn Append<Identity: windows_core::IUnknownImpl + ISimilarity_Impl>(this: *mut core::ffi::c_void, similarityfileid: *const SimilarityFileId, similaritydata: *const SimilarityData) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISimilarity_Impl::Append(this, core::mem::transmute_copy(&similarityfileid), core::mem::transmute_copy(&similaritydata)).into()
+            }
+        }
+        unsafe extern "system" fn FindSimilarFileId<Identity: windows_core::IUnknownImpl + ISimilarity_Impl>(this: *mut core::ffi::c_void, similaritydata: *const SimilarityData, numberofmatchesrequired: u16, resultssize: u32, findsimilarresults: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISimilarity_Impl::FindSimilarFileId(this, core::mem::transmute_copy(&similaritydata), core::mem::transmute_copy(&numberofmatchesrequired), core::mem::transmute_copy(&resultssize)) {
+                    Ok(ok__) => {
+                        findsimilarresults.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CopyAndSwap<Identity: windows_core::IUnknownImpl + ISimilarity_Impl>(this: *mut core::ffi::c_void, newsimilaritytables: *mut core::ffi::c_void, reportprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISimilarity_Impl::CopyAndSwap(this, core::mem::transmute_copy(&newsimilaritytables), core::mem::transmute_copy(&reportprogress)).into()
+            }
+        }
+        unsafe extern "system" fn GetRecordCount<Identity: windows_core::IUnknownImpl + ISimilarity_Impl>(this: *mut core::ffi::c_void, recordcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISimilarity_Impl::GetRecordCount(this) {
+                    Ok(ok__) => {
+                        recordcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateTable: CreateTable::<Identity>,
+            CreateTableIndirect: CreateTableIndirect::<Identity>,
+            CloseTable: CloseTable::<Identity>,
+            Append: Append::<Identity>,
+            FindSimilarFileId: FindSimilarFileId::<Identity>,
+            CopyAndSwap: CopyAndSwap::<Identity>,
+            GetRecordCount: GetRecordCount::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISimilarity {}
 windows_core::imp::define_interface!(ISimilarityFileIdTable, ISimilarityFileIdTable_Vtbl, 0x96236a7f_9dbc_11da_9e3f_0011114ae311);
 windows_core::imp::interface_hierarchy!(ISimilarityFileIdTable, windows_core::IUnknown);
@@ -1050,6 +1542,89 @@ impl ISimilarityFileIdTable_Vtbl {
         iid == &<ISimilarityFileIdTable as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISimilarityFileIdTable_Impl> windows_core::imp::TearOffVtable<Identity> for ISimilarityFileIdTable_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateTable<Identity: windows_core::IUnknownImpl + ISimilarityFileIdTable_Impl>(this: *mut core::ffi::c_void, path: windows_core::PCWSTR, truncate: windows_core::BOOL, securitydescriptor: *const u8, recordsize: u32, isnew: *mut RdcCreatedTables) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISimilarityFileIdTable_Impl::CreateTable(this, core::mem::transmute(&path), core::mem::transmute_copy(&truncate), core::mem::transmute_copy(&securitydescriptor), core::mem::transmute_copy(&recordsize)) {
+                    Ok(ok__) => {
+                        isnew.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateTableIndirect<Identity: windows_core::IUnknownImpl + ISimilarityFileIdTable_Impl>(this: *mut core::ffi::c_void, fileidfile: *mut core::ffi::c_void, truncate: windows_core::BOOL, recordsize: u32, isnew: *mut RdcCreatedTables) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISimilarityFileIdTable_Impl::CreateTableIndirect(this, core::mem::transmute_copy(&fileidfile), core::mem::transmute_copy(&truncate), core::mem::transmute_copy(&recordsize)) {
+                    Ok(ok__) => {
+                        isnew.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CloseTable<Identity: windows_core::IUnknownImpl + ISimilarityFileIdTable_Impl>(this: *mut core::ffi::c_void, isvalid: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISimilarityFileIdTable_Impl::CloseTable(this, core::mem::transmute_copy(&isvalid)).into()
+            }
+        }
+        unsafe extern "system" fn Append<Identity: windows_core::IUnknownImpl + ISimilarityFileIdTable_Impl>(this: *mut core::ffi::c_void, similarityfileid: *const SimilarityFileId, similarityfileindex: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISimilarityFileIdTable_Impl::Append(this, core::mem::transmute_copy(&similarityfileid)) {
+                    Ok(ok__) => {
+                        similarityfileindex.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Lookup<Identity: windows_core::IUnknownImpl + ISimilarityFileIdTable_Impl>(this: *mut core::ffi::c_void, similarityfileindex: u32, similarityfileid: *mut SimilarityFileId) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISimilarityFileIdTable_Impl::Lookup(this, core::mem::transmute_copy(&similarityfileindex), core::mem::transmute_copy(&similarityfileid)).into()
+            }
+        }
+        unsafe extern "system" fn Invalidate<Identity: windows_core::IUnknownImpl + ISimilarityFileIdTable_Impl>(this: *mut core::ffi::c_void, similarityfileindex: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISimilarityFileIdTable_Impl::Invalidate(this, core::mem::transmute_copy(&similarityfileindex)).into()
+            }
+        }
+        unsafe extern "system" fn GetRecordCount<Identity: windows_core::IUnknownImpl + ISimilarityFileIdTable_Impl>(this: *mut core::ffi::c_void, recordcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISimilarityFileIdTable_Impl::GetRecordCount(this) {
+                    Ok(ok__) => {
+                        recordcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateTable: CreateTable::<Identity>,
+            CreateTableIndirect: CreateTableIndirect::<Identity>,
+            CloseTable: CloseTable::<Identity>,
+            Append: Append::<Identity>,
+            Lookup: Lookup::<Identity>,
+            Invalidate: Invalidate::<Identity>,
+            GetRecordCount: GetRecordCount::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISimilarityFileIdTable {}
 windows_core::imp::define_interface!(ISimilarityReportProgress, ISimilarityReportProgress_Vtbl, 0x96236a7a_9dbc_11da_9e3f_0011114ae311);
 windows_core::imp::interface_hierarchy!(ISimilarityReportProgress, windows_core::IUnknown);
@@ -1081,6 +1656,20 @@ impl ISimilarityReportProgress_Vtbl {
         iid == &<ISimilarityReportProgress as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISimilarityReportProgress_Impl> windows_core::imp::TearOffVtable<Identity> for ISimilarityReportProgress_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ReportProgress<Identity: windows_core::IUnknownImpl + ISimilarityReportProgress_Impl>(this: *mut core::ffi::c_void, percentcompleted: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISimilarityReportProgress_Impl::ReportProgress(this, core::mem::transmute_copy(&percentcompleted)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ReportProgress: ReportProgress::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ISimilarityReportProgress {}
 windows_core::imp::define_interface!(ISimilarityTableDumpState, ISimilarityTableDumpState_Vtbl, 0x96236a7b_9dbc_11da_9e3f_0011114ae311);
 windows_core::imp::interface_hierarchy!(ISimilarityTableDumpState, windows_core::IUnknown);
@@ -1112,6 +1701,20 @@ impl ISimilarityTableDumpState_Vtbl {
         iid == &<ISimilarityTableDumpState as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISimilarityTableDumpState_Impl> windows_core::imp::TearOffVtable<Identity> for ISimilarityTableDumpState_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetNextData<Identity: windows_core::IUnknownImpl + ISimilarityTableDumpState_Impl>(this: *mut core::ffi::c_void, resultssize: u32, resultsused: *mut u32, eof: *mut windows_core::BOOL, results: *mut SimilarityDumpData) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISimilarityTableDumpState_Impl::GetNextData(this, core::mem::transmute_copy(&resultssize), core::mem::transmute_copy(&resultsused), core::mem::transmute_copy(&eof), core::mem::transmute_copy(&results)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetNextData: GetNextData::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ISimilarityTableDumpState {}
 windows_core::imp::define_interface!(ISimilarityTraitsMappedView, ISimilarityTraitsMappedView_Vtbl, 0x96236a7c_9dbc_11da_9e3f_0011114ae311);
 windows_core::imp::interface_hierarchy!(ISimilarityTraitsMappedView, windows_core::IUnknown);
@@ -1191,6 +1794,50 @@ impl ISimilarityTraitsMappedView_Vtbl {
         iid == &<ISimilarityTraitsMappedView as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISimilarityTraitsMappedView_Impl> windows_core::imp::TearOffVtable<Identity> for ISimilarityTraitsMappedView_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Flush<Identity: windows_core::IUnknownImpl + ISimilarityTraitsMappedView_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISimilarityTraitsMappedView_Impl::Flush(this).into()
+            }
+        }
+        unsafe extern "system" fn Unmap<Identity: windows_core::IUnknownImpl + ISimilarityTraitsMappedView_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISimilarityTraitsMappedView_Impl::Unmap(this).into()
+            }
+        }
+        unsafe extern "system" fn Get<Identity: windows_core::IUnknownImpl + ISimilarityTraitsMappedView_Impl>(this: *mut core::ffi::c_void, index: u64, dirty: windows_core::BOOL, numelements: u32, viewinfo: *mut SimilarityMappedViewInfo) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISimilarityTraitsMappedView_Impl::Get(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&dirty), core::mem::transmute_copy(&numelements)) {
+                    Ok(ok__) => {
+                        viewinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetView<Identity: windows_core::IUnknownImpl + ISimilarityTraitsMappedView_Impl>(this: *mut core::ffi::c_void, mappedpagebegin: *mut *mut u8, mappedpageend: *mut *mut u8) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISimilarityTraitsMappedView_Impl::GetView(this, core::mem::transmute_copy(&mappedpagebegin), core::mem::transmute_copy(&mappedpageend))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Flush: Flush::<Identity>,
+            Unmap: Unmap::<Identity>,
+            Get: Get::<Identity>,
+            GetView: GetView::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISimilarityTraitsMappedView {}
 windows_core::imp::define_interface!(ISimilarityTraitsMapping, ISimilarityTraitsMapping_Vtbl, 0x96236a7d_9dbc_11da_9e3f_0011114ae311);
 windows_core::imp::interface_hierarchy!(ISimilarityTraitsMapping, windows_core::IUnknown);
@@ -1337,6 +1984,89 @@ impl ISimilarityTraitsMapping_Vtbl {
         iid == &<ISimilarityTraitsMapping as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISimilarityTraitsMapping_Impl> windows_core::imp::TearOffVtable<Identity> for ISimilarityTraitsMapping_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CloseMapping<Identity: windows_core::IUnknownImpl + ISimilarityTraitsMapping_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISimilarityTraitsMapping_Impl::CloseMapping(this)
+            }
+        }
+        unsafe extern "system" fn SetFileSize<Identity: windows_core::IUnknownImpl + ISimilarityTraitsMapping_Impl>(this: *mut core::ffi::c_void, filesize: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISimilarityTraitsMapping_Impl::SetFileSize(this, core::mem::transmute_copy(&filesize)).into()
+            }
+        }
+        unsafe extern "system" fn GetFileSize<Identity: windows_core::IUnknownImpl + ISimilarityTraitsMapping_Impl>(this: *mut core::ffi::c_void, filesize: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISimilarityTraitsMapping_Impl::GetFileSize(this) {
+                    Ok(ok__) => {
+                        filesize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OpenMapping<Identity: windows_core::IUnknownImpl + ISimilarityTraitsMapping_Impl>(this: *mut core::ffi::c_void, accessmode: RdcMappingAccessMode, begin: u64, end: u64, actualend: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISimilarityTraitsMapping_Impl::OpenMapping(this, core::mem::transmute_copy(&accessmode), core::mem::transmute_copy(&begin), core::mem::transmute_copy(&end)) {
+                    Ok(ok__) => {
+                        actualend.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ResizeMapping<Identity: windows_core::IUnknownImpl + ISimilarityTraitsMapping_Impl>(this: *mut core::ffi::c_void, accessmode: RdcMappingAccessMode, begin: u64, end: u64, actualend: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISimilarityTraitsMapping_Impl::ResizeMapping(this, core::mem::transmute_copy(&accessmode), core::mem::transmute_copy(&begin), core::mem::transmute_copy(&end)) {
+                    Ok(ok__) => {
+                        actualend.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPageSize<Identity: windows_core::IUnknownImpl + ISimilarityTraitsMapping_Impl>(this: *mut core::ffi::c_void, pagesize: *mut u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISimilarityTraitsMapping_Impl::GetPageSize(this, core::mem::transmute_copy(&pagesize))
+            }
+        }
+        unsafe extern "system" fn CreateView<Identity: windows_core::IUnknownImpl + ISimilarityTraitsMapping_Impl>(this: *mut core::ffi::c_void, minimummappedpages: u32, accessmode: RdcMappingAccessMode, mappedview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISimilarityTraitsMapping_Impl::CreateView(this, core::mem::transmute_copy(&minimummappedpages), core::mem::transmute_copy(&accessmode)) {
+                    Ok(ok__) => {
+                        mappedview.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CloseMapping: CloseMapping::<Identity>,
+            SetFileSize: SetFileSize::<Identity>,
+            GetFileSize: GetFileSize::<Identity>,
+            OpenMapping: OpenMapping::<Identity>,
+            ResizeMapping: ResizeMapping::<Identity>,
+            GetPageSize: GetPageSize::<Identity>,
+            CreateView: CreateView::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISimilarityTraitsMapping {}
 windows_core::imp::define_interface!(ISimilarityTraitsTable, ISimilarityTraitsTable_Vtbl, 0x96236a7e_9dbc_11da_9e3f_0011114ae311);
 windows_core::imp::interface_hierarchy!(ISimilarityTraitsTable, windows_core::IUnknown);
@@ -1485,6 +2215,89 @@ impl ISimilarityTraitsTable_Vtbl {
         iid == &<ISimilarityTraitsTable as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISimilarityTraitsTable_Impl> windows_core::imp::TearOffVtable<Identity> for ISimilarityTraitsTable_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateTable<Identity: windows_core::IUnknownImpl + ISimilarityTraitsTable_Impl>(this: *mut core::ffi::c_void, path: windows_core::PCWSTR, truncate: windows_core::BOOL, securitydescriptor: *const u8, isnew: *mut RdcCreatedTables) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISimilarityTraitsTable_Impl::CreateTable(this, core::mem::transmute(&path), core::mem::transmute_copy(&truncate), core::mem::transmute_copy(&securitydescriptor)) {
+                    Ok(ok__) => {
+                        isnew.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateTableIndirect<Identity: windows_core::IUnknownImpl + ISimilarityTraitsTable_Impl>(this: *mut core::ffi::c_void, mapping: *mut core::ffi::c_void, truncate: windows_core::BOOL, isnew: *mut RdcCreatedTables) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISimilarityTraitsTable_Impl::CreateTableIndirect(this, core::mem::transmute_copy(&mapping), core::mem::transmute_copy(&truncate)) {
+                    Ok(ok__) => {
+                        isnew.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CloseTable<Identity: windows_core::IUnknownImpl + ISimilarityTraitsTable_Impl>(this: *mut core::ffi::c_void, isvalid: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISimilarityTraitsTable_Impl::CloseTable(this, core::mem::transmute_copy(&isvalid)).into()
+            }
+        }
+        unsafe extern "system" fn Append<Identity: windows_core::IUnknownImpl + ISimilarityTraitsTable_Impl>(this: *mut core::ffi::c_void, data: *const SimilarityData, fileindex: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISimilarityTraitsTable_Impl::Append(this, core::mem::transmute_copy(&data), core::mem::transmute_copy(&fileindex)).into()
+            }
+        }
+        unsafe extern "system" fn FindSimilarFileIndex<Identity: windows_core::IUnknownImpl + ISimilarityTraitsTable_Impl>(this: *mut core::ffi::c_void, similaritydata: *const SimilarityData, numberofmatchesrequired: u16, findsimilarfileindexresults: *mut FindSimilarFileIndexResults, resultssize: u32, resultsused: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISimilarityTraitsTable_Impl::FindSimilarFileIndex(this, core::mem::transmute_copy(&similaritydata), core::mem::transmute_copy(&numberofmatchesrequired), core::mem::transmute_copy(&findsimilarfileindexresults), core::mem::transmute_copy(&resultssize), core::mem::transmute_copy(&resultsused)).into()
+            }
+        }
+        unsafe extern "system" fn BeginDump<Identity: windows_core::IUnknownImpl + ISimilarityTraitsTable_Impl>(this: *mut core::ffi::c_void, similaritytabledumpstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISimilarityTraitsTable_Impl::BeginDump(this) {
+                    Ok(ok__) => {
+                        similaritytabledumpstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLastIndex<Identity: windows_core::IUnknownImpl + ISimilarityTraitsTable_Impl>(this: *mut core::ffi::c_void, fileindex: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISimilarityTraitsTable_Impl::GetLastIndex(this) {
+                    Ok(ok__) => {
+                        fileindex.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateTable: CreateTable::<Identity>,
+            CreateTableIndirect: CreateTableIndirect::<Identity>,
+            CloseTable: CloseTable::<Identity>,
+            Append: Append::<Identity>,
+            FindSimilarFileIndex: FindSimilarFileIndex::<Identity>,
+            BeginDump: BeginDump::<Identity>,
+            GetLastIndex: GetLastIndex::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISimilarityTraitsTable {}
 pub const MSRDC_DEFAULT_COMPAREBUFFER: u32 = 3200000u32;
 pub const MSRDC_DEFAULT_HASHWINDOWSIZE_1: u32 = 48u32;
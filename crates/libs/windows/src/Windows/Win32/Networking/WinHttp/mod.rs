@@ -822,6 +822,204 @@ impl IWinHttpRequest_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWinHttpRequest_Impl> windows_core::imp::TearOffVtable<Identity> for IWinHttpRequest_Vtbl
+where
+    super::super::System::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetProxy<Identity: windows_core::IUnknownImpl + IWinHttpRequest_Impl>(this: *mut core::ffi::c_void, proxysetting: i32, proxyserver: super::super::System::Variant::VARIANT, bypasslist: super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinHttpRequest_Impl::SetProxy(this, core::mem::transmute_copy(&proxysetting), core::mem::transmute(&proxyserver), core::mem::transmute(&bypasslist)).into()
+            }
+        }
+        unsafe extern "system" fn SetCredentials<Identity: windows_core::IUnknownImpl + IWinHttpRequest_Impl>(this: *mut core::ffi::c_void, username: *mut core::ffi::c_void, password: *mut core::ffi::c_void, flags: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinHttpRequest_Impl::SetCredentials(this, core::mem::transmute(&username), core::mem::transmute(&password), core::mem::transmute_copy(&flags)).into()
+            }
+        }
+        unsafe extern "system" fn Open<Identity: windows_core::IUnknownImpl + IWinHttpRequest_Impl>(this: *mut core::ffi::c_void, method: *mut core::ffi::c_void, url: *mut core::ffi::c_void, r#async: super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinHttpRequest_Impl::Open(this, core::mem::transmute(&method), core::mem::transmute(&url), core::mem::transmute(&r#async)).into()
+            }
+        }
+        unsafe extern "system" fn SetRequestHeader<Identity: windows_core::IUnknownImpl + IWinHttpRequest_Impl>(this: *mut core::ffi::c_void, header: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinHttpRequest_Impl::SetRequestHeader(this, core::mem::transmute(&header), core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn GetResponseHeader<Identity: windows_core::IUnknownImpl + IWinHttpRequest_Impl>(this: *mut core::ffi::c_void, header: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWinHttpRequest_Impl::GetResponseHeader(this, core::mem::transmute(&header)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAllResponseHeaders<Identity: windows_core::IUnknownImpl + IWinHttpRequest_Impl>(this: *mut core::ffi::c_void, headers: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWinHttpRequest_Impl::GetAllResponseHeaders(this) {
+                    Ok(ok__) => {
+                        headers.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Send<Identity: windows_core::IUnknownImpl + IWinHttpRequest_Impl>(this: *mut core::ffi::c_void, body: super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinHttpRequest_Impl::Send(this, core::mem::transmute(&body)).into()
+            }
+        }
+        unsafe extern "system" fn Status<Identity: windows_core::IUnknownImpl + IWinHttpRequest_Impl>(this: *mut core::ffi::c_void, status: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWinHttpRequest_Impl::Status(this) {
+                    Ok(ok__) => {
+                        status.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StatusText<Identity: windows_core::IUnknownImpl + IWinHttpRequest_Impl>(this: *mut core::ffi::c_void, status: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWinHttpRequest_Impl::StatusText(this) {
+                    Ok(ok__) => {
+                        status.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ResponseText<Identity: windows_core::IUnknownImpl + IWinHttpRequest_Impl>(this: *mut core::ffi::c_void, body: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWinHttpRequest_Impl::ResponseText(this) {
+                    Ok(ok__) => {
+                        body.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ResponseBody<Identity: windows_core::IUnknownImpl + IWinHttpRequest_Impl>(this: *mut core::ffi::c_void, body: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWinHttpRequest_Impl::ResponseBody(this) {
+                    Ok(ok__) => {
+                        body.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ResponseStream<Identity: windows_core::IUnknownImpl + IWinHttpRequest_Impl>(this: *mut core::ffi::c_void, body: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWinHttpRequest_Impl::ResponseStream(this) {
+                    Ok(ok__) => {
+                        body.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_Option<Identity: windows_core::IUnknownImpl + IWinHttpRequest_Impl>(this: *mut core::ffi::c_void, option: WinHttpRequestOption, value: *mut super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWinHttpRequest_Impl::get_Option(this, core::mem::transmute_copy(&option)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn put_Option<Identity: windows_core::IUnknownImpl + IWinHttpRequest_Impl>(this: *mut core::ffi::c_void, option: WinHttpRequestOption, value: super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinHttpRequest_Impl::put_Option(this, core::mem::transmute_copy(&option), core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn WaitForResponse<Identity: windows_core::IUnknownImpl + IWinHttpRequest_Impl>(this: *mut core::ffi::c_void, timeout: super::super::System::Variant::VARIANT, succeeded: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWinHttpRequest_Impl::WaitForResponse(this, core::mem::transmute(&timeout)) {
+                    Ok(ok__) => {
+                        succeeded.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Abort<Identity: windows_core::IUnknownImpl + IWinHttpRequest_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinHttpRequest_Impl::Abort(this).into()
+            }
+        }
+        unsafe extern "system" fn SetTimeouts<Identity: windows_core::IUnknownImpl + IWinHttpRequest_Impl>(this: *mut core::ffi::c_void, resolvetimeout: i32, connecttimeout: i32, sendtimeout: i32, receivetimeout: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinHttpRequest_Impl::SetTimeouts(this, core::mem::transmute_copy(&resolvetimeout), core::mem::transmute_copy(&connecttimeout), core::mem::transmute_copy(&sendtimeout), core::mem::transmute_copy(&receivetimeout)).into()
+            }
+        }
+        unsafe extern "system" fn SetClientCertificate<Identity: windows_core::IUnknownImpl + IWinHttpRequest_Impl>(this: *mut core::ffi::c_void, clientcertificate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinHttpRequest_Impl::SetClientCertificate(this, core::mem::transmute(&clientcertificate)).into()
+            }
+        }
+        unsafe extern "system" fn SetAutoLogonPolicy<Identity: windows_core::IUnknownImpl + IWinHttpRequest_Impl>(this: *mut core::ffi::c_void, autologonpolicy: WinHttpRequestAutoLogonPolicy) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinHttpRequest_Impl::SetAutoLogonPolicy(this, core::mem::transmute_copy(&autologonpolicy)).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetProxy: SetProxy::<Identity>,
+            SetCredentials: SetCredentials::<Identity>,
+            Open: Open::<Identity>,
+            SetRequestHeader: SetRequestHeader::<Identity>,
+            GetResponseHeader: GetResponseHeader::<Identity>,
+            GetAllResponseHeaders: GetAllResponseHeaders::<Identity>,
+            Send: Send::<Identity>,
+            Status: Status::<Identity>,
+            StatusText: StatusText::<Identity>,
+            ResponseText: ResponseText::<Identity>,
+            ResponseBody: ResponseBody::<Identity>,
+            ResponseStream: ResponseStream::<Identity>,
+            get_Option: get_Option::<Identity>,
+            put_Option: put_Option::<Identity>,
+            WaitForResponse: WaitForResponse::<Identity>,
+            Abort: Abort::<Identity>,
+            SetTimeouts: SetTimeouts::<Identity>,
+            SetClientCertificate: SetClientCertificate::<Identity>,
+            SetAutoLogonPolicy: SetAutoLogonPolicy::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWinHttpRequest {}
 windows_core::imp::define_interface!(IWinHttpRequestEvents, IWinHttpRequestEvents_Vtbl, 0xf97f4e15_b787_4212_80d1_d380cbbf982e);
 windows_core::imp::interface_hierarchy!(IWinHttpRequestEvents, windows_core::IUnknown);
@@ -899,6 +1097,45 @@ impl IWinHttpRequestEvents_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IWinHttpRequestEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IWinHttpRequestEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnResponseStart<Identity: windows_core::IUnknownImpl + IWinHttpRequestEvents_Impl>(this: *mut core::ffi::c_void, status: i32, contenttype: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinHttpRequestEvents_Impl::OnResponseStart(this, core::mem::transmute_copy(&status), core::mem::transmute(&contenttype))
+            }
+        }
+        unsafe extern "system" fn OnResponseDataAvailable<Identity: windows_core::IUnknownImpl + IWinHttpRequestEvents_Impl>(this: *mut core::ffi::c_void, data: *const *const super::super::System::Com::SAFEARRAY) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinHttpRequestEvents_Impl::OnResponseDataAvailable(this, core::mem::transmute_copy(&data))
+            }
+        }
+        unsafe extern "system" fn OnResponseFinished<Identity: windows_core::IUnknownImpl + IWinHttpRequestEvents_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinHttpRequestEvents_Impl::OnResponseFinished(this)
+            }
+        }
+        unsafe extern "system" fn OnError<Identity: windows_core::IUnknownImpl + IWinHttpRequestEvents_Impl>(this: *mut core::ffi::c_void, errornumber: i32, errordescription: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinHttpRequestEvents_Impl::OnError(this, core::mem::transmute_copy(&errornumber), core::mem::transmute(&errordescription))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnResponseStart: OnResponseStart::<Identity>,
+            OnResponseDataAvailable: OnResponseDataAvailable::<Identity>,
+            OnResponseFinished: OnResponseFinished::<Identity>,
+            OnError: OnError::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IWinHttpRequestEvents {}
 pub const NETWORKING_KEY_BUFSIZE: u32 = 128u32;
 pub const SECURITY_FLAG_IGNORE_CERT_CN_INVALID: u32 = 4096u32;
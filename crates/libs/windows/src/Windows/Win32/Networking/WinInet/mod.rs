@@ -3052,6 +3052,37 @@ impl IDialBranding_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IDialBranding_Impl> windows_core::imp::TearOffVtable<Identity> for IDialBranding_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDialBranding_Impl>(this: *mut core::ffi::c_void, pwzconnectoid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDialBranding_Impl::Initialize(this, core::mem::transmute(&pwzconnectoid)).into()
+            }
+        }
+        unsafe extern "system" fn GetBitmap<Identity: windows_core::IUnknownImpl + IDialBranding_Impl>(this: *mut core::ffi::c_void, dwindex: u32, phbitmap: *mut super::super::Graphics::Gdi::HBITMAP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDialBranding_Impl::GetBitmap(this, core::mem::transmute_copy(&dwindex)) {
+                    Ok(ok__) => {
+                        phbitmap.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            GetBitmap: GetBitmap::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IDialBranding {}
 windows_core::imp::define_interface!(IDialEngine, IDialEngine_Vtbl, 0x39fd782b_7905_40d5_9148_3c9b190423d5);
 windows_core::imp::interface_hierarchy!(IDialEngine, windows_core::IUnknown);
@@ -3188,6 +3219,77 @@ impl IDialEngine_Vtbl {
         iid == &<IDialEngine as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDialEngine_Impl> windows_core::imp::TearOffVtable<Identity> for IDialEngine_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDialEngine_Impl>(this: *mut core::ffi::c_void, pwzconnectoid: windows_core::PCWSTR, pides: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDialEngine_Impl::Initialize(this, core::mem::transmute(&pwzconnectoid), core::mem::transmute_copy(&pides)).into()
+            }
+        }
+        unsafe extern "system" fn GetProperty<Identity: windows_core::IUnknownImpl + IDialEngine_Impl>(this: *mut core::ffi::c_void, pwzproperty: windows_core::PCWSTR, pwzvalue: windows_core::PCWSTR, dwbufsize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDialEngine_Impl::GetProperty(this, core::mem::transmute(&pwzproperty), core::mem::transmute(&pwzvalue), core::mem::transmute_copy(&dwbufsize)).into()
+            }
+        }
+        unsafe extern "system" fn SetProperty<Identity: windows_core::IUnknownImpl + IDialEngine_Impl>(this: *mut core::ffi::c_void, pwzproperty: windows_core::PCWSTR, pwzvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDialEngine_Impl::SetProperty(this, core::mem::transmute(&pwzproperty), core::mem::transmute(&pwzvalue)).into()
+            }
+        }
+        unsafe extern "system" fn Dial<Identity: windows_core::IUnknownImpl + IDialEngine_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDialEngine_Impl::Dial(this).into()
+            }
+        }
+        unsafe extern "system" fn HangUp<Identity: windows_core::IUnknownImpl + IDialEngine_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDialEngine_Impl::HangUp(this).into()
+            }
+        }
+        unsafe extern "system" fn GetConnectedState<Identity: windows_core::IUnknownImpl + IDialEngine_Impl>(this: *mut core::ffi::c_void, pdwstate: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDialEngine_Impl::GetConnectedState(this) {
+                    Ok(ok__) => {
+                        pdwstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetConnectHandle<Identity: windows_core::IUnknownImpl + IDialEngine_Impl>(this: *mut core::ffi::c_void, pdwhandle: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDialEngine_Impl::GetConnectHandle(this) {
+                    Ok(ok__) => {
+                        pdwhandle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            GetProperty: GetProperty::<Identity>,
+            SetProperty: SetProperty::<Identity>,
+            Dial: Dial::<Identity>,
+            HangUp: HangUp::<Identity>,
+            GetConnectedState: GetConnectedState::<Identity>,
+            GetConnectHandle: GetConnectHandle::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDialEngine {}
 windows_core::imp::define_interface!(IDialEventSink, IDialEventSink_Vtbl, 0x2d86f4ff_6e2d_4488_b2e9_6934afd41bea);
 windows_core::imp::interface_hierarchy!(IDialEventSink, windows_core::IUnknown);
@@ -3219,6 +3321,20 @@ impl IDialEventSink_Vtbl {
         iid == &<IDialEventSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDialEventSink_Impl> windows_core::imp::TearOffVtable<Identity> for IDialEventSink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnEvent<Identity: windows_core::IUnknownImpl + IDialEventSink_Impl>(this: *mut core::ffi::c_void, dwevent: u32, dwstatus: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDialEventSink_Impl::OnEvent(this, core::mem::transmute_copy(&dwevent), core::mem::transmute_copy(&dwstatus)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnEvent: OnEvent::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDialEventSink {}
 pub const IMMUTABLE_CACHE_ENTRY: u32 = 524288u32;
 pub const INSTALLED_CACHE_ENTRY: u32 = 268435456u32;
@@ -4311,6 +4427,23 @@ impl IProofOfPossessionCookieInfoManager_Vtbl {
         iid == &<IProofOfPossessionCookieInfoManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IProofOfPossessionCookieInfoManager_Impl> windows_core::imp::TearOffVtable<Identity> for IProofOfPossessionCookieInfoManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCookieInfoForUri<Identity: windows_core::IUnknownImpl + IProofOfPossessionCookieInfoManager_Impl>(this: *mut core::ffi::c_void, uri: windows_core::PCWSTR, cookieinfocount: *mut u32, cookieinfo: *mut *mut ProofOfPossessionCookieInfo) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IProofOfPossessionCookieInfoManager_Impl::GetCookieInfoForUri(this, core::mem::transmute(&uri), core::mem::transmute_copy(&cookieinfocount), core::mem::transmute_copy(&cookieinfo)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCookieInfoForUri: GetCookieInfoForUri::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IProofOfPossessionCookieInfoManager {}
 windows_core::imp::define_interface!(IProofOfPossessionCookieInfoManager2, IProofOfPossessionCookieInfoManager2_Vtbl, 0x15e41407_b42f_4ae7_9966_34a087b2d713);
 windows_core::imp::interface_hierarchy!(IProofOfPossessionCookieInfoManager2, windows_core::IUnknown);
@@ -4349,6 +4482,23 @@ impl IProofOfPossessionCookieInfoManager2_Vtbl {
         iid == &<IProofOfPossessionCookieInfoManager2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IProofOfPossessionCookieInfoManager2_Impl> windows_core::imp::TearOffVtable<Identity> for IProofOfPossessionCookieInfoManager2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCookieInfoWithUriForAccount<Identity: windows_core::IUnknownImpl + IProofOfPossessionCookieInfoManager2_Impl>(this: *mut core::ffi::c_void, webaccount: *mut core::ffi::c_void, uri: windows_core::PCWSTR, cookieinfocount: *mut u32, cookieinfo: *mut *mut ProofOfPossessionCookieInfo) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IProofOfPossessionCookieInfoManager2_Impl::GetCookieInfoWithUriForAccount(this, core::mem::transmute_copy(&webaccount), core::mem::transmute(&uri), core::mem::transmute_copy(&cookieinfocount), core::mem::transmute_copy(&cookieinfo)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCookieInfoWithUriForAccount: GetCookieInfoWithUriForAccount::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IProofOfPossessionCookieInfoManager2 {}
 pub const IRF_ASYNC: u32 = 1u32;
 pub const IRF_NO_WAIT: u32 = 8u32;
@@ -1147,6 +1147,36 @@ impl IContentPrefetcherTaskTrigger_Vtbl {
         iid == &<IContentPrefetcherTaskTrigger as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IContentPrefetcherTaskTrigger_Impl> windows_core::imp::TearOffVtable<Identity> for IContentPrefetcherTaskTrigger_Vtbl
+where
+    windows_core::IInspectable_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn TriggerContentPrefetcherTask<Identity: windows_core::IUnknownImpl + IContentPrefetcherTaskTrigger_Impl>(this: *mut core::ffi::c_void, packagefullname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContentPrefetcherTaskTrigger_Impl::TriggerContentPrefetcherTask(this, core::mem::transmute(&packagefullname)).into()
+            }
+        }
+        unsafe extern "system" fn IsRegisteredForContentPrefetch<Identity: windows_core::IUnknownImpl + IContentPrefetcherTaskTrigger_Impl>(this: *mut core::ffi::c_void, packagefullname: windows_core::PCWSTR, isregistered: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContentPrefetcherTaskTrigger_Impl::IsRegisteredForContentPrefetch(this, core::mem::transmute(&packagefullname)) {
+                    Ok(ok__) => {
+                        isregistered.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            TriggerContentPrefetcherTask: TriggerContentPrefetcherTask::<Identity>,
+            IsRegisteredForContentPrefetch: IsRegisteredForContentPrefetch::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IContentPrefetcherTaskTrigger {}
 pub const WEBAUTHN_API_CURRENT_VERSION: u32 = 7u32;
 pub const WEBAUTHN_API_VERSION_1: u32 = 1u32;
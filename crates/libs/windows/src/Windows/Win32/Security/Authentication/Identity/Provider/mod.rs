@@ -116,6 +116,65 @@ impl AsyncIAssociatedIdentityProvider_Vtbl {
     }
 }
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl<Identity: windows_core::IUnknownImpl + AsyncIAssociatedIdentityProvider_Impl> windows_core::imp::TearOffVtable<Identity> for AsyncIAssociatedIdentityProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Begin_AssociateIdentity<Identity: windows_core::IUnknownImpl + AsyncIAssociatedIdentityProvider_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIAssociatedIdentityProvider_Impl::Begin_AssociateIdentity(this, core::mem::transmute_copy(&hwndparent)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_AssociateIdentity<Identity: windows_core::IUnknownImpl + AsyncIAssociatedIdentityProvider_Impl>(this: *mut core::ffi::c_void, pppropertystore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match AsyncIAssociatedIdentityProvider_Impl::Finish_AssociateIdentity(this) {
+                    Ok(ok__) => {
+                        pppropertystore.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Begin_DisassociateIdentity<Identity: windows_core::IUnknownImpl + AsyncIAssociatedIdentityProvider_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::super::super::Foundation::HWND, lpszuniqueid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIAssociatedIdentityProvider_Impl::Begin_DisassociateIdentity(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute(&lpszuniqueid)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_DisassociateIdentity<Identity: windows_core::IUnknownImpl + AsyncIAssociatedIdentityProvider_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIAssociatedIdentityProvider_Impl::Finish_DisassociateIdentity(this).into()
+            }
+        }
+        unsafe extern "system" fn Begin_ChangeCredential<Identity: windows_core::IUnknownImpl + AsyncIAssociatedIdentityProvider_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::super::super::Foundation::HWND, lpszuniqueid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIAssociatedIdentityProvider_Impl::Begin_ChangeCredential(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute(&lpszuniqueid)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_ChangeCredential<Identity: windows_core::IUnknownImpl + AsyncIAssociatedIdentityProvider_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIAssociatedIdentityProvider_Impl::Finish_ChangeCredential(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Begin_AssociateIdentity: Begin_AssociateIdentity::<Identity>,
+            Finish_AssociateIdentity: Finish_AssociateIdentity::<Identity>,
+            Begin_DisassociateIdentity: Begin_DisassociateIdentity::<Identity>,
+            Finish_DisassociateIdentity: Finish_DisassociateIdentity::<Identity>,
+            Begin_ChangeCredential: Begin_ChangeCredential::<Identity>,
+            Finish_ChangeCredential: Finish_ChangeCredential::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
 impl windows_core::RuntimeName for AsyncIAssociatedIdentityProvider {}
 windows_core::imp::define_interface!(AsyncIConnectedIdentityProvider, AsyncIConnectedIdentityProvider_Vtbl, 0x9ce55141_bce9_4e15_824d_43d79f512f93);
 windows_core::imp::interface_hierarchy!(AsyncIConnectedIdentityProvider, windows_core::IUnknown);
@@ -290,6 +349,99 @@ impl AsyncIConnectedIdentityProvider_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + AsyncIConnectedIdentityProvider_Impl> windows_core::imp::TearOffVtable<Identity> for AsyncIConnectedIdentityProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Begin_ConnectIdentity<Identity: windows_core::IUnknownImpl + AsyncIConnectedIdentityProvider_Impl>(this: *mut core::ffi::c_void, authbuffer: *const u8, authbuffersize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIConnectedIdentityProvider_Impl::Begin_ConnectIdentity(this, core::mem::transmute_copy(&authbuffer), core::mem::transmute_copy(&authbuffersize)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_ConnectIdentity<Identity: windows_core::IUnknownImpl + AsyncIConnectedIdentityProvider_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIConnectedIdentityProvider_Impl::Finish_ConnectIdentity(this).into()
+            }
+        }
+        unsafe extern "system" fn Begin_DisconnectIdentity<Identity: windows_core::IUnknownImpl + AsyncIConnectedIdentityProvider_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIConnectedIdentityProvider_Impl::Begin_DisconnectIdentity(this).into()
+            }
+        }
+        unsafe extern "system" fn Finish_DisconnectIdentity<Identity: windows_core::IUnknownImpl + AsyncIConnectedIdentityProvider_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIConnectedIdentityProvider_Impl::Finish_DisconnectIdentity(this).into()
+            }
+        }
+        unsafe extern "system" fn Begin_IsConnected<Identity: windows_core::IUnknownImpl + AsyncIConnectedIdentityProvider_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIConnectedIdentityProvider_Impl::Begin_IsConnected(this).into()
+            }
+        }
+        unsafe extern "system" fn Finish_IsConnected<Identity: windows_core::IUnknownImpl + AsyncIConnectedIdentityProvider_Impl>(this: *mut core::ffi::c_void, connected: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match AsyncIConnectedIdentityProvider_Impl::Finish_IsConnected(this) {
+                    Ok(ok__) => {
+                        connected.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Begin_GetUrl<Identity: windows_core::IUnknownImpl + AsyncIConnectedIdentityProvider_Impl>(this: *mut core::ffi::c_void, identifier: IDENTITY_URL, context: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIConnectedIdentityProvider_Impl::Begin_GetUrl(this, core::mem::transmute_copy(&identifier), core::mem::transmute_copy(&context)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_GetUrl<Identity: windows_core::IUnknownImpl + AsyncIConnectedIdentityProvider_Impl>(this: *mut core::ffi::c_void, postdata: *mut super::super::super::super::System::Variant::VARIANT, url: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIConnectedIdentityProvider_Impl::Finish_GetUrl(this, core::mem::transmute_copy(&postdata), core::mem::transmute_copy(&url)).into()
+            }
+        }
+        unsafe extern "system" fn Begin_GetAccountState<Identity: windows_core::IUnknownImpl + AsyncIConnectedIdentityProvider_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIConnectedIdentityProvider_Impl::Begin_GetAccountState(this).into()
+            }
+        }
+        unsafe extern "system" fn Finish_GetAccountState<Identity: windows_core::IUnknownImpl + AsyncIConnectedIdentityProvider_Impl>(this: *mut core::ffi::c_void, pstate: *mut ACCOUNT_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match AsyncIConnectedIdentityProvider_Impl::Finish_GetAccountState(this) {
+                    Ok(ok__) => {
+                        pstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Begin_ConnectIdentity: Begin_ConnectIdentity::<Identity>,
+            Finish_ConnectIdentity: Finish_ConnectIdentity::<Identity>,
+            Begin_DisconnectIdentity: Begin_DisconnectIdentity::<Identity>,
+            Finish_DisconnectIdentity: Finish_DisconnectIdentity::<Identity>,
+            Begin_IsConnected: Begin_IsConnected::<Identity>,
+            Finish_IsConnected: Finish_IsConnected::<Identity>,
+            Begin_GetUrl: Begin_GetUrl::<Identity>,
+            Finish_GetUrl: Finish_GetUrl::<Identity>,
+            Begin_GetAccountState: Begin_GetAccountState::<Identity>,
+            Finish_GetAccountState: Finish_GetAccountState::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for AsyncIConnectedIdentityProvider {}
 windows_core::imp::define_interface!(AsyncIIdentityAdvise, AsyncIIdentityAdvise_Vtbl, 0x3ab4c8da_d038_4830_8dd9_3253c55a127f);
 windows_core::imp::interface_hierarchy!(AsyncIIdentityAdvise, windows_core::IUnknown);
@@ -339,6 +491,30 @@ impl AsyncIIdentityAdvise_Vtbl {
         iid == &<AsyncIIdentityAdvise as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + AsyncIIdentityAdvise_Impl> windows_core::imp::TearOffVtable<Identity> for AsyncIIdentityAdvise_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Begin_IdentityUpdated<Identity: windows_core::IUnknownImpl + AsyncIIdentityAdvise_Impl>(this: *mut core::ffi::c_void, dwidentityupdateevents: u32, lpszuniqueid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityAdvise_Impl::Begin_IdentityUpdated(this, core::mem::transmute_copy(&dwidentityupdateevents), core::mem::transmute(&lpszuniqueid)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_IdentityUpdated<Identity: windows_core::IUnknownImpl + AsyncIIdentityAdvise_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityAdvise_Impl::Finish_IdentityUpdated(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Begin_IdentityUpdated: Begin_IdentityUpdated::<Identity>,
+            Finish_IdentityUpdated: Finish_IdentityUpdated::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for AsyncIIdentityAdvise {}
 windows_core::imp::define_interface!(AsyncIIdentityAuthentication, AsyncIIdentityAuthentication_Vtbl, 0xf9a2f918_feca_4e9c_9633_61cbf13ed34d);
 windows_core::imp::interface_hierarchy!(AsyncIIdentityAuthentication, windows_core::IUnknown);
@@ -420,6 +596,45 @@ impl AsyncIIdentityAuthentication_Vtbl {
     }
 }
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl<Identity: windows_core::IUnknownImpl + AsyncIIdentityAuthentication_Impl> windows_core::imp::TearOffVtable<Identity> for AsyncIIdentityAuthentication_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Begin_SetIdentityCredential<Identity: windows_core::IUnknownImpl + AsyncIIdentityAuthentication_Impl>(this: *mut core::ffi::c_void, credbuffer: *const u8, credbufferlength: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityAuthentication_Impl::Begin_SetIdentityCredential(this, core::mem::transmute_copy(&credbuffer), core::mem::transmute_copy(&credbufferlength)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_SetIdentityCredential<Identity: windows_core::IUnknownImpl + AsyncIIdentityAuthentication_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityAuthentication_Impl::Finish_SetIdentityCredential(this).into()
+            }
+        }
+        unsafe extern "system" fn Begin_ValidateIdentityCredential<Identity: windows_core::IUnknownImpl + AsyncIIdentityAuthentication_Impl>(this: *mut core::ffi::c_void, credbuffer: *const u8, credbufferlength: u32, ppidentityproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityAuthentication_Impl::Begin_ValidateIdentityCredential(this, core::mem::transmute_copy(&credbuffer), core::mem::transmute_copy(&credbufferlength), core::mem::transmute_copy(&ppidentityproperties)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_ValidateIdentityCredential<Identity: windows_core::IUnknownImpl + AsyncIIdentityAuthentication_Impl>(this: *mut core::ffi::c_void, ppidentityproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityAuthentication_Impl::Finish_ValidateIdentityCredential(this, core::mem::transmute_copy(&ppidentityproperties)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Begin_SetIdentityCredential: Begin_SetIdentityCredential::<Identity>,
+            Finish_SetIdentityCredential: Finish_SetIdentityCredential::<Identity>,
+            Begin_ValidateIdentityCredential: Begin_ValidateIdentityCredential::<Identity>,
+            Finish_ValidateIdentityCredential: Finish_ValidateIdentityCredential::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
 impl windows_core::RuntimeName for AsyncIIdentityAuthentication {}
 windows_core::imp::define_interface!(AsyncIIdentityProvider, AsyncIIdentityProvider_Vtbl, 0xc6fc9901_c433_4646_8f48_4e4687aae2a0);
 windows_core::imp::interface_hierarchy!(AsyncIIdentityProvider, windows_core::IUnknown);
@@ -729,6 +944,159 @@ impl AsyncIIdentityProvider_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant", feature = "Win32_UI_Shell_PropertiesSystem"))]
+impl<Identity: windows_core::IUnknownImpl + AsyncIIdentityProvider_Impl> windows_core::imp::TearOffVtable<Identity> for AsyncIIdentityProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Begin_GetIdentityEnum<Identity: windows_core::IUnknownImpl + AsyncIIdentityProvider_Impl>(this: *mut core::ffi::c_void, eidentitytype: IDENTITY_TYPE, pfilterkey: *const super::super::super::super::Foundation::PROPERTYKEY, pfilterpropvarvalue: *const super::super::super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityProvider_Impl::Begin_GetIdentityEnum(this, core::mem::transmute_copy(&eidentitytype), core::mem::transmute_copy(&pfilterkey), core::mem::transmute_copy(&pfilterpropvarvalue)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_GetIdentityEnum<Identity: windows_core::IUnknownImpl + AsyncIIdentityProvider_Impl>(this: *mut core::ffi::c_void, ppidentityenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match AsyncIIdentityProvider_Impl::Finish_GetIdentityEnum(this) {
+                    Ok(ok__) => {
+                        ppidentityenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Begin_Create<Identity: windows_core::IUnknownImpl + AsyncIIdentityProvider_Impl>(this: *mut core::ffi::c_void, lpszusername: windows_core::PCWSTR, pkeywordstoadd: *const super::super::super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityProvider_Impl::Begin_Create(this, core::mem::transmute(&lpszusername), core::mem::transmute_copy(&pkeywordstoadd)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_Create<Identity: windows_core::IUnknownImpl + AsyncIIdentityProvider_Impl>(this: *mut core::ffi::c_void, pppropertystore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match AsyncIIdentityProvider_Impl::Finish_Create(this) {
+                    Ok(ok__) => {
+                        pppropertystore.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Begin_Import<Identity: windows_core::IUnknownImpl + AsyncIIdentityProvider_Impl>(this: *mut core::ffi::c_void, ppropertystore: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityProvider_Impl::Begin_Import(this, core::mem::transmute_copy(&ppropertystore)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_Import<Identity: windows_core::IUnknownImpl + AsyncIIdentityProvider_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityProvider_Impl::Finish_Import(this).into()
+            }
+        }
+        unsafe extern "system" fn Begin_Delete<Identity: windows_core::IUnknownImpl + AsyncIIdentityProvider_Impl>(this: *mut core::ffi::c_void, lpszuniqueid: windows_core::PCWSTR, pkeywordstodelete: *const super::super::super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityProvider_Impl::Begin_Delete(this, core::mem::transmute(&lpszuniqueid), core::mem::transmute_copy(&pkeywordstodelete)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_Delete<Identity: windows_core::IUnknownImpl + AsyncIIdentityProvider_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityProvider_Impl::Finish_Delete(this).into()
+            }
+        }
+        unsafe extern "system" fn Begin_FindByUniqueID<Identity: windows_core::IUnknownImpl + AsyncIIdentityProvider_Impl>(this: *mut core::ffi::c_void, lpszuniqueid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityProvider_Impl::Begin_FindByUniqueID(this, core::mem::transmute(&lpszuniqueid)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_FindByUniqueID<Identity: windows_core::IUnknownImpl + AsyncIIdentityProvider_Impl>(this: *mut core::ffi::c_void, pppropertystore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match AsyncIIdentityProvider_Impl::Finish_FindByUniqueID(this) {
+                    Ok(ok__) => {
+                        pppropertystore.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Begin_GetProviderPropertyStore<Identity: windows_core::IUnknownImpl + AsyncIIdentityProvider_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityProvider_Impl::Begin_GetProviderPropertyStore(this).into()
+            }
+        }
+        unsafe extern "system" fn Finish_GetProviderPropertyStore<Identity: windows_core::IUnknownImpl + AsyncIIdentityProvider_Impl>(this: *mut core::ffi::c_void, pppropertystore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match AsyncIIdentityProvider_Impl::Finish_GetProviderPropertyStore(this) {
+                    Ok(ok__) => {
+                        pppropertystore.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Begin_Advise<Identity: windows_core::IUnknownImpl + AsyncIIdentityProvider_Impl>(this: *mut core::ffi::c_void, pidentityadvise: *mut core::ffi::c_void, dwidentityupdateevents: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityProvider_Impl::Begin_Advise(this, core::mem::transmute_copy(&pidentityadvise), core::mem::transmute_copy(&dwidentityupdateevents)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_Advise<Identity: windows_core::IUnknownImpl + AsyncIIdentityProvider_Impl>(this: *mut core::ffi::c_void, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match AsyncIIdentityProvider_Impl::Finish_Advise(this) {
+                    Ok(ok__) => {
+                        pdwcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Begin_UnAdvise<Identity: windows_core::IUnknownImpl + AsyncIIdentityProvider_Impl>(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityProvider_Impl::Begin_UnAdvise(this, core::mem::transmute_copy(&dwcookie)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_UnAdvise<Identity: windows_core::IUnknownImpl + AsyncIIdentityProvider_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityProvider_Impl::Finish_UnAdvise(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Begin_GetIdentityEnum: Begin_GetIdentityEnum::<Identity>,
+            Finish_GetIdentityEnum: Finish_GetIdentityEnum::<Identity>,
+            Begin_Create: Begin_Create::<Identity>,
+            Finish_Create: Finish_Create::<Identity>,
+            Begin_Import: Begin_Import::<Identity>,
+            Finish_Import: Finish_Import::<Identity>,
+            Begin_Delete: Begin_Delete::<Identity>,
+            Finish_Delete: Finish_Delete::<Identity>,
+            Begin_FindByUniqueID: Begin_FindByUniqueID::<Identity>,
+            Finish_FindByUniqueID: Finish_FindByUniqueID::<Identity>,
+            Begin_GetProviderPropertyStore: Begin_GetProviderPropertyStore::<Identity>,
+            Finish_GetProviderPropertyStore: Finish_GetProviderPropertyStore::<Identity>,
+            Begin_Advise: Begin_Advise::<Identity>,
+            Finish_Advise: Finish_Advise::<Identity>,
+            Begin_UnAdvise: Begin_UnAdvise::<Identity>,
+            Finish_UnAdvise: Finish_UnAdvise::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant", feature = "Win32_UI_Shell_PropertiesSystem"))]
 impl windows_core::RuntimeName for AsyncIIdentityProvider {}
 windows_core::imp::define_interface!(AsyncIIdentityStore, AsyncIIdentityStore_Vtbl, 0xeefa1616_48de_4872_aa64_6e6206535a51);
 windows_core::imp::interface_hierarchy!(AsyncIIdentityStore, windows_core::IUnknown);
@@ -930,6 +1298,113 @@ impl AsyncIIdentityStore_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + AsyncIIdentityStore_Impl> windows_core::imp::TearOffVtable<Identity> for AsyncIIdentityStore_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Begin_GetCount<Identity: windows_core::IUnknownImpl + AsyncIIdentityStore_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityStore_Impl::Begin_GetCount(this).into()
+            }
+        }
+        unsafe extern "system" fn Finish_GetCount<Identity: windows_core::IUnknownImpl + AsyncIIdentityStore_Impl>(this: *mut core::ffi::c_void, pdwproviders: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match AsyncIIdentityStore_Impl::Finish_GetCount(this) {
+                    Ok(ok__) => {
+                        pdwproviders.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Begin_GetAt<Identity: windows_core::IUnknownImpl + AsyncIIdentityStore_Impl>(this: *mut core::ffi::c_void, dwprovider: u32, pprovguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityStore_Impl::Begin_GetAt(this, core::mem::transmute_copy(&dwprovider), core::mem::transmute_copy(&pprovguid)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_GetAt<Identity: windows_core::IUnknownImpl + AsyncIIdentityStore_Impl>(this: *mut core::ffi::c_void, pprovguid: *mut windows_core::GUID, ppidentityprovider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityStore_Impl::Finish_GetAt(this, core::mem::transmute_copy(&pprovguid), core::mem::transmute_copy(&ppidentityprovider)).into()
+            }
+        }
+        unsafe extern "system" fn Begin_AddToCache<Identity: windows_core::IUnknownImpl + AsyncIIdentityStore_Impl>(this: *mut core::ffi::c_void, lpszuniqueid: windows_core::PCWSTR, providerguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityStore_Impl::Begin_AddToCache(this, core::mem::transmute(&lpszuniqueid), core::mem::transmute_copy(&providerguid)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_AddToCache<Identity: windows_core::IUnknownImpl + AsyncIIdentityStore_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityStore_Impl::Finish_AddToCache(this).into()
+            }
+        }
+        unsafe extern "system" fn Begin_ConvertToSid<Identity: windows_core::IUnknownImpl + AsyncIIdentityStore_Impl>(this: *mut core::ffi::c_void, lpszuniqueid: windows_core::PCWSTR, providerguid: *const windows_core::GUID, cbsid: u16, psid: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityStore_Impl::Begin_ConvertToSid(this, core::mem::transmute(&lpszuniqueid), core::mem::transmute_copy(&providerguid), core::mem::transmute_copy(&cbsid), core::mem::transmute_copy(&psid)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_ConvertToSid<Identity: windows_core::IUnknownImpl + AsyncIIdentityStore_Impl>(this: *mut core::ffi::c_void, psid: *mut u8, pcbrequiredsid: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityStore_Impl::Finish_ConvertToSid(this, core::mem::transmute_copy(&psid), core::mem::transmute_copy(&pcbrequiredsid)).into()
+            }
+        }
+        unsafe extern "system" fn Begin_EnumerateIdentities<Identity: windows_core::IUnknownImpl + AsyncIIdentityStore_Impl>(this: *mut core::ffi::c_void, eidentitytype: IDENTITY_TYPE, pfilterkey: *const super::super::super::super::Foundation::PROPERTYKEY, pfilterpropvarvalue: *const super::super::super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityStore_Impl::Begin_EnumerateIdentities(this, core::mem::transmute_copy(&eidentitytype), core::mem::transmute_copy(&pfilterkey), core::mem::transmute_copy(&pfilterpropvarvalue)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_EnumerateIdentities<Identity: windows_core::IUnknownImpl + AsyncIIdentityStore_Impl>(this: *mut core::ffi::c_void, ppidentityenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match AsyncIIdentityStore_Impl::Finish_EnumerateIdentities(this) {
+                    Ok(ok__) => {
+                        ppidentityenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Begin_Reset<Identity: windows_core::IUnknownImpl + AsyncIIdentityStore_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityStore_Impl::Begin_Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Finish_Reset<Identity: windows_core::IUnknownImpl + AsyncIIdentityStore_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityStore_Impl::Finish_Reset(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Begin_GetCount: Begin_GetCount::<Identity>,
+            Finish_GetCount: Finish_GetCount::<Identity>,
+            Begin_GetAt: Begin_GetAt::<Identity>,
+            Finish_GetAt: Finish_GetAt::<Identity>,
+            Begin_AddToCache: Begin_AddToCache::<Identity>,
+            Finish_AddToCache: Finish_AddToCache::<Identity>,
+            Begin_ConvertToSid: Begin_ConvertToSid::<Identity>,
+            Finish_ConvertToSid: Finish_ConvertToSid::<Identity>,
+            Begin_EnumerateIdentities: Begin_EnumerateIdentities::<Identity>,
+            Finish_EnumerateIdentities: Finish_EnumerateIdentities::<Identity>,
+            Begin_Reset: Begin_Reset::<Identity>,
+            Finish_Reset: Finish_Reset::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for AsyncIIdentityStore {}
 windows_core::imp::define_interface!(AsyncIIdentityStoreEx, AsyncIIdentityStoreEx_Vtbl, 0xfca3af9a_8a07_4eae_8632_ec3de658a36a);
 windows_core::imp::interface_hierarchy!(AsyncIIdentityStoreEx, windows_core::IUnknown);
@@ -1007,6 +1482,44 @@ impl AsyncIIdentityStoreEx_Vtbl {
         iid == &<AsyncIIdentityStoreEx as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + AsyncIIdentityStoreEx_Impl> windows_core::imp::TearOffVtable<Identity> for AsyncIIdentityStoreEx_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Begin_CreateConnectedIdentity<Identity: windows_core::IUnknownImpl + AsyncIIdentityStoreEx_Impl>(this: *mut core::ffi::c_void, localname: windows_core::PCWSTR, connectedname: windows_core::PCWSTR, providerguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityStoreEx_Impl::Begin_CreateConnectedIdentity(this, core::mem::transmute(&localname), core::mem::transmute(&connectedname), core::mem::transmute_copy(&providerguid)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_CreateConnectedIdentity<Identity: windows_core::IUnknownImpl + AsyncIIdentityStoreEx_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityStoreEx_Impl::Finish_CreateConnectedIdentity(this).into()
+            }
+        }
+        unsafe extern "system" fn Begin_DeleteConnectedIdentity<Identity: windows_core::IUnknownImpl + AsyncIIdentityStoreEx_Impl>(this: *mut core::ffi::c_void, connectedname: windows_core::PCWSTR, providerguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityStoreEx_Impl::Begin_DeleteConnectedIdentity(this, core::mem::transmute(&connectedname), core::mem::transmute_copy(&providerguid)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_DeleteConnectedIdentity<Identity: windows_core::IUnknownImpl + AsyncIIdentityStoreEx_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIIdentityStoreEx_Impl::Finish_DeleteConnectedIdentity(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Begin_CreateConnectedIdentity: Begin_CreateConnectedIdentity::<Identity>,
+            Finish_CreateConnectedIdentity: Finish_CreateConnectedIdentity::<Identity>,
+            Begin_DeleteConnectedIdentity: Begin_DeleteConnectedIdentity::<Identity>,
+            Finish_DeleteConnectedIdentity: Finish_DeleteConnectedIdentity::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for AsyncIIdentityStoreEx {}
 pub const CIdentityProfileHandler: windows_core::GUID = windows_core::GUID::from_u128(0xecf5bf46_e3b6_449a_b56b_43f58f867814);
 pub const CONNECTING: ACCOUNT_STATE = ACCOUNT_STATE(1i32);
@@ -1091,6 +1604,44 @@ impl IAssociatedIdentityProvider_Vtbl {
     }
 }
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl<Identity: windows_core::IUnknownImpl + IAssociatedIdentityProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IAssociatedIdentityProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AssociateIdentity<Identity: windows_core::IUnknownImpl + IAssociatedIdentityProvider_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::super::super::Foundation::HWND, pppropertystore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAssociatedIdentityProvider_Impl::AssociateIdentity(this, core::mem::transmute_copy(&hwndparent)) {
+                    Ok(ok__) => {
+                        pppropertystore.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DisassociateIdentity<Identity: windows_core::IUnknownImpl + IAssociatedIdentityProvider_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::super::super::Foundation::HWND, lpszuniqueid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAssociatedIdentityProvider_Impl::DisassociateIdentity(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute(&lpszuniqueid)).into()
+            }
+        }
+        unsafe extern "system" fn ChangeCredential<Identity: windows_core::IUnknownImpl + IAssociatedIdentityProvider_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::super::super::Foundation::HWND, lpszuniqueid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAssociatedIdentityProvider_Impl::ChangeCredential(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute(&lpszuniqueid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AssociateIdentity: AssociateIdentity::<Identity>,
+            DisassociateIdentity: DisassociateIdentity::<Identity>,
+            ChangeCredential: ChangeCredential::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
 impl windows_core::RuntimeName for IAssociatedIdentityProvider {}
 windows_core::imp::define_interface!(IConnectedIdentityProvider, IConnectedIdentityProvider_Vtbl, 0xb7417b54_e08c_429b_96c8_678d1369ecb1);
 windows_core::imp::interface_hierarchy!(IConnectedIdentityProvider, windows_core::IUnknown);
@@ -1201,6 +1752,64 @@ impl IConnectedIdentityProvider_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IConnectedIdentityProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IConnectedIdentityProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ConnectIdentity<Identity: windows_core::IUnknownImpl + IConnectedIdentityProvider_Impl>(this: *mut core::ffi::c_void, authbuffer: *const u8, authbuffersize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IConnectedIdentityProvider_Impl::ConnectIdentity(this, core::mem::transmute_copy(&authbuffer), core::mem::transmute_copy(&authbuffersize)).into()
+            }
+        }
+        unsafe extern "system" fn DisconnectIdentity<Identity: windows_core::IUnknownImpl + IConnectedIdentityProvider_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IConnectedIdentityProvider_Impl::DisconnectIdentity(this).into()
+            }
+        }
+        unsafe extern "system" fn IsConnected<Identity: windows_core::IUnknownImpl + IConnectedIdentityProvider_Impl>(this: *mut core::ffi::c_void, connected: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IConnectedIdentityProvider_Impl::IsConnected(this) {
+                    Ok(ok__) => {
+                        connected.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetUrl<Identity: windows_core::IUnknownImpl + IConnectedIdentityProvider_Impl>(this: *mut core::ffi::c_void, identifier: IDENTITY_URL, context: *mut core::ffi::c_void, postdata: *mut super::super::super::super::System::Variant::VARIANT, url: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IConnectedIdentityProvider_Impl::GetUrl(this, core::mem::transmute_copy(&identifier), core::mem::transmute_copy(&context), core::mem::transmute_copy(&postdata), core::mem::transmute_copy(&url)).into()
+            }
+        }
+        unsafe extern "system" fn GetAccountState<Identity: windows_core::IUnknownImpl + IConnectedIdentityProvider_Impl>(this: *mut core::ffi::c_void, pstate: *mut ACCOUNT_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IConnectedIdentityProvider_Impl::GetAccountState(this) {
+                    Ok(ok__) => {
+                        pstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ConnectIdentity: ConnectIdentity::<Identity>,
+            DisconnectIdentity: DisconnectIdentity::<Identity>,
+            IsConnected: IsConnected::<Identity>,
+            GetUrl: GetUrl::<Identity>,
+            GetAccountState: GetAccountState::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IConnectedIdentityProvider {}
 pub const IDENTITIES_ALL: IDENTITY_TYPE = IDENTITY_TYPE(0i32);
 pub const IDENTITIES_ME_ONLY: IDENTITY_TYPE = IDENTITY_TYPE(1i32);
@@ -1262,6 +1871,20 @@ impl IIdentityAdvise_Vtbl {
         iid == &<IIdentityAdvise as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IIdentityAdvise_Impl> windows_core::imp::TearOffVtable<Identity> for IIdentityAdvise_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IdentityUpdated<Identity: windows_core::IUnknownImpl + IIdentityAdvise_Impl>(this: *mut core::ffi::c_void, dwidentityupdateevents: u32, lpszuniqueid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIdentityAdvise_Impl::IdentityUpdated(this, core::mem::transmute(&dwidentityupdateevents), core::mem::transmute(&lpszuniqueid)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, IdentityUpdated: IdentityUpdated::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IIdentityAdvise {}
 windows_core::imp::define_interface!(IIdentityAuthentication, IIdentityAuthentication_Vtbl, 0x5e7ef254_979f_43b5_b74e_06e4eb7df0f9);
 windows_core::imp::interface_hierarchy!(IIdentityAuthentication, windows_core::IUnknown);
@@ -1315,6 +1938,31 @@ impl IIdentityAuthentication_Vtbl {
     }
 }
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl<Identity: windows_core::IUnknownImpl + IIdentityAuthentication_Impl> windows_core::imp::TearOffVtable<Identity> for IIdentityAuthentication_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetIdentityCredential<Identity: windows_core::IUnknownImpl + IIdentityAuthentication_Impl>(this: *mut core::ffi::c_void, credbuffer: *const u8, credbufferlength: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIdentityAuthentication_Impl::SetIdentityCredential(this, core::mem::transmute_copy(&credbuffer), core::mem::transmute_copy(&credbufferlength)).into()
+            }
+        }
+        unsafe extern "system" fn ValidateIdentityCredential<Identity: windows_core::IUnknownImpl + IIdentityAuthentication_Impl>(this: *mut core::ffi::c_void, credbuffer: *const u8, credbufferlength: u32, ppidentityproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIdentityAuthentication_Impl::ValidateIdentityCredential(this, core::mem::transmute_copy(&credbuffer), core::mem::transmute_copy(&credbufferlength), core::mem::transmute_copy(&ppidentityproperties)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetIdentityCredential: SetIdentityCredential::<Identity>,
+            ValidateIdentityCredential: ValidateIdentityCredential::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
 impl windows_core::RuntimeName for IIdentityAuthentication {}
 windows_core::imp::define_interface!(IIdentityProvider, IIdentityProvider_Vtbl, 0x0d1b9e0c_e8ba_4f55_a81b_bce934b948f5);
 windows_core::imp::interface_hierarchy!(IIdentityProvider, windows_core::IUnknown);
@@ -1511,6 +2159,97 @@ impl IIdentityProvider_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant", feature = "Win32_UI_Shell_PropertiesSystem"))]
+impl<Identity: windows_core::IUnknownImpl + IIdentityProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IIdentityProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetIdentityEnum<Identity: windows_core::IUnknownImpl + IIdentityProvider_Impl>(this: *mut core::ffi::c_void, eidentitytype: IDENTITY_TYPE, pfilterkey: *const super::super::super::super::Foundation::PROPERTYKEY, pfilterpropvarvalue: *const super::super::super::super::System::Com::StructuredStorage::PROPVARIANT, ppidentityenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IIdentityProvider_Impl::GetIdentityEnum(this, core::mem::transmute_copy(&eidentitytype), core::mem::transmute_copy(&pfilterkey), core::mem::transmute_copy(&pfilterpropvarvalue)) {
+                    Ok(ok__) => {
+                        ppidentityenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Create<Identity: windows_core::IUnknownImpl + IIdentityProvider_Impl>(this: *mut core::ffi::c_void, lpszusername: windows_core::PCWSTR, pppropertystore: *mut *mut core::ffi::c_void, pkeywordstoadd: *const super::super::super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIdentityProvider_Impl::Create(this, core::mem::transmute(&lpszusername), core::mem::transmute_copy(&pppropertystore), core::mem::transmute_copy(&pkeywordstoadd)).into()
+            }
+        }
+        unsafe extern "system" fn Import<Identity: windows_core::IUnknownImpl + IIdentityProvider_Impl>(this: *mut core::ffi::c_void, ppropertystore: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIdentityProvider_Impl::Import(this, core::mem::transmute_copy(&ppropertystore)).into()
+            }
+        }
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IIdentityProvider_Impl>(this: *mut core::ffi::c_void, lpszuniqueid: windows_core::PCWSTR, pkeywordstodelete: *const super::super::super::super::System::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIdentityProvider_Impl::Delete(this, core::mem::transmute(&lpszuniqueid), core::mem::transmute_copy(&pkeywordstodelete)).into()
+            }
+        }
+        unsafe extern "system" fn FindByUniqueID<Identity: windows_core::IUnknownImpl + IIdentityProvider_Impl>(this: *mut core::ffi::c_void, lpszuniqueid: windows_core::PCWSTR, pppropertystore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IIdentityProvider_Impl::FindByUniqueID(this, core::mem::transmute(&lpszuniqueid)) {
+                    Ok(ok__) => {
+                        pppropertystore.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetProviderPropertyStore<Identity: windows_core::IUnknownImpl + IIdentityProvider_Impl>(this: *mut core::ffi::c_void, pppropertystore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IIdentityProvider_Impl::GetProviderPropertyStore(this) {
+                    Ok(ok__) => {
+                        pppropertystore.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Advise<Identity: windows_core::IUnknownImpl + IIdentityProvider_Impl>(this: *mut core::ffi::c_void, pidentityadvise: *mut core::ffi::c_void, dwidentityupdateevents: u32, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IIdentityProvider_Impl::Advise(this, core::mem::transmute_copy(&pidentityadvise), core::mem::transmute(&dwidentityupdateevents)) {
+                    Ok(ok__) => {
+                        pdwcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UnAdvise<Identity: windows_core::IUnknownImpl + IIdentityProvider_Impl>(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIdentityProvider_Impl::UnAdvise(this, core::mem::transmute_copy(&dwcookie)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetIdentityEnum: GetIdentityEnum::<Identity>,
+            Create: Create::<Identity>,
+            Import: Import::<Identity>,
+            Delete: Delete::<Identity>,
+            FindByUniqueID: FindByUniqueID::<Identity>,
+            GetProviderPropertyStore: GetProviderPropertyStore::<Identity>,
+            Advise: Advise::<Identity>,
+            UnAdvise: UnAdvise::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant", feature = "Win32_UI_Shell_PropertiesSystem"))]
 impl windows_core::RuntimeName for IIdentityProvider {}
 windows_core::imp::define_interface!(IIdentityStore, IIdentityStore_Vtbl, 0xdf586fa5_6f35_44f1_b209_b38e169772eb);
 windows_core::imp::interface_hierarchy!(IIdentityStore, windows_core::IUnknown);
@@ -1636,6 +2375,71 @@ impl IIdentityStore_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IIdentityStore_Impl> windows_core::imp::TearOffVtable<Identity> for IIdentityStore_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IIdentityStore_Impl>(this: *mut core::ffi::c_void, pdwproviders: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IIdentityStore_Impl::GetCount(this) {
+                    Ok(ok__) => {
+                        pdwproviders.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAt<Identity: windows_core::IUnknownImpl + IIdentityStore_Impl>(this: *mut core::ffi::c_void, dwprovider: u32, pprovguid: *mut windows_core::GUID, ppidentityprovider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIdentityStore_Impl::GetAt(this, core::mem::transmute_copy(&dwprovider), core::mem::transmute_copy(&pprovguid), core::mem::transmute_copy(&ppidentityprovider)).into()
+            }
+        }
+        unsafe extern "system" fn AddToCache<Identity: windows_core::IUnknownImpl + IIdentityStore_Impl>(this: *mut core::ffi::c_void, lpszuniqueid: windows_core::PCWSTR, providerguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIdentityStore_Impl::AddToCache(this, core::mem::transmute(&lpszuniqueid), core::mem::transmute_copy(&providerguid)).into()
+            }
+        }
+        unsafe extern "system" fn ConvertToSid<Identity: windows_core::IUnknownImpl + IIdentityStore_Impl>(this: *mut core::ffi::c_void, lpszuniqueid: windows_core::PCWSTR, providerguid: *const windows_core::GUID, cbsid: u16, psid: *mut u8, pcbrequiredsid: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIdentityStore_Impl::ConvertToSid(this, core::mem::transmute(&lpszuniqueid), core::mem::transmute_copy(&providerguid), core::mem::transmute_copy(&cbsid), core::mem::transmute_copy(&psid), core::mem::transmute_copy(&pcbrequiredsid)).into()
+            }
+        }
+        unsafe extern "system" fn EnumerateIdentities<Identity: windows_core::IUnknownImpl + IIdentityStore_Impl>(this: *mut core::ffi::c_void, eidentitytype: IDENTITY_TYPE, pfilterkey: *const super::super::super::super::Foundation::PROPERTYKEY, pfilterpropvarvalue: *const super::super::super::super::System::Com::StructuredStorage::PROPVARIANT, ppidentityenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IIdentityStore_Impl::EnumerateIdentities(this, core::mem::transmute_copy(&eidentitytype), core::mem::transmute_copy(&pfilterkey), core::mem::transmute_copy(&pfilterpropvarvalue)) {
+                    Ok(ok__) => {
+                        ppidentityenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IIdentityStore_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIdentityStore_Impl::Reset(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCount: GetCount::<Identity>,
+            GetAt: GetAt::<Identity>,
+            AddToCache: AddToCache::<Identity>,
+            ConvertToSid: ConvertToSid::<Identity>,
+            EnumerateIdentities: EnumerateIdentities::<Identity>,
+            Reset: Reset::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IIdentityStore {}
 windows_core::imp::define_interface!(IIdentityStoreEx, IIdentityStoreEx_Vtbl, 0xf9f9eb98_8f7f_4e38_9577_6980114ce32b);
 windows_core::imp::interface_hierarchy!(IIdentityStoreEx, windows_core::IUnknown);
@@ -1689,6 +2493,30 @@ impl IIdentityStoreEx_Vtbl {
         iid == &<IIdentityStoreEx as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IIdentityStoreEx_Impl> windows_core::imp::TearOffVtable<Identity> for IIdentityStoreEx_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateConnectedIdentity<Identity: windows_core::IUnknownImpl + IIdentityStoreEx_Impl>(this: *mut core::ffi::c_void, localname: windows_core::PCWSTR, connectedname: windows_core::PCWSTR, providerguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIdentityStoreEx_Impl::CreateConnectedIdentity(this, core::mem::transmute(&localname), core::mem::transmute(&connectedname), core::mem::transmute_copy(&providerguid)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteConnectedIdentity<Identity: windows_core::IUnknownImpl + IIdentityStoreEx_Impl>(this: *mut core::ffi::c_void, connectedname: windows_core::PCWSTR, providerguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIdentityStoreEx_Impl::DeleteConnectedIdentity(this, core::mem::transmute(&connectedname), core::mem::transmute_copy(&providerguid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateConnectedIdentity: CreateConnectedIdentity::<Identity>,
+            DeleteConnectedIdentity: DeleteConnectedIdentity::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IIdentityStoreEx {}
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
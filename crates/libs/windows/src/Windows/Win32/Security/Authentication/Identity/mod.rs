@@ -1901,6 +1901,23 @@ impl ICcgDomainAuthCredentials_Vtbl {
         iid == &<ICcgDomainAuthCredentials as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICcgDomainAuthCredentials_Impl> windows_core::imp::TearOffVtable<Identity> for ICcgDomainAuthCredentials_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPasswordCredentials<Identity: windows_core::IUnknownImpl + ICcgDomainAuthCredentials_Impl>(this: *mut core::ffi::c_void, plugininput: windows_core::PCWSTR, domainname: *mut windows_core::PWSTR, username: *mut windows_core::PWSTR, password: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICcgDomainAuthCredentials_Impl::GetPasswordCredentials(this, core::mem::transmute(&plugininput), core::mem::transmute_copy(&domainname), core::mem::transmute_copy(&username), core::mem::transmute_copy(&password)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPasswordCredentials: GetPasswordCredentials::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICcgDomainAuthCredentials {}
 pub const ID_CAP_SLAPI: windows_core::PCWSTR = windows_core::w!("slapiQueryLicenseValue");
 #[cfg(feature = "Win32_Security_Credentials")]
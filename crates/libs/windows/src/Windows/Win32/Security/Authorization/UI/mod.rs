@@ -76,6 +76,23 @@ impl IEffectivePermission_Vtbl {
         iid == &<IEffectivePermission as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEffectivePermission_Impl> windows_core::imp::TearOffVtable<Identity> for IEffectivePermission_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetEffectivePermission<Identity: windows_core::IUnknownImpl + IEffectivePermission_Impl>(this: *mut core::ffi::c_void, pguidobjecttype: *const windows_core::GUID, pusersid: super::super::PSID, pszservername: windows_core::PCWSTR, psd: super::super::PSECURITY_DESCRIPTOR, ppobjecttypelist: *mut *mut super::super::OBJECT_TYPE_LIST, pcobjecttypelistlength: *mut u32, ppgrantedaccesslist: *mut *mut u32, pcgrantedaccesslistlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEffectivePermission_Impl::GetEffectivePermission(this, core::mem::transmute_copy(&pguidobjecttype), core::mem::transmute_copy(&pusersid), core::mem::transmute(&pszservername), core::mem::transmute_copy(&psd), core::mem::transmute_copy(&ppobjecttypelist), core::mem::transmute_copy(&pcobjecttypelistlength), core::mem::transmute_copy(&ppgrantedaccesslist), core::mem::transmute_copy(&pcgrantedaccesslistlength)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetEffectivePermission: GetEffectivePermission::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEffectivePermission {}
 windows_core::imp::define_interface!(IEffectivePermission2, IEffectivePermission2_Vtbl, 0x941fabca_dd47_4fca_90bb_b0e10255f20d);
 windows_core::imp::interface_hierarchy!(IEffectivePermission2, windows_core::IUnknown);
@@ -197,6 +214,56 @@ impl IEffectivePermission2_Vtbl {
         iid == &<IEffectivePermission2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEffectivePermission2_Impl> windows_core::imp::TearOffVtable<Identity> for IEffectivePermission2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ComputeEffectivePermissionWithSecondarySecurity<Identity: windows_core::IUnknownImpl + IEffectivePermission2_Impl>(
+            this: *mut core::ffi::c_void,
+            psid: super::super::PSID,
+            pdevicesid: super::super::PSID,
+            pszservername: windows_core::PCWSTR,
+            psecurityobjects: *mut SECURITY_OBJECT,
+            dwsecurityobjectcount: u32,
+            pusergroups: *const super::super::TOKEN_GROUPS,
+            pauthzusergroupsoperations: *const super::AUTHZ_SID_OPERATION,
+            pdevicegroups: *const super::super::TOKEN_GROUPS,
+            pauthzdevicegroupsoperations: *const super::AUTHZ_SID_OPERATION,
+            pauthzuserclaims: *const super::AUTHZ_SECURITY_ATTRIBUTES_INFORMATION,
+            pauthzuserclaimsoperations: *const super::AUTHZ_SECURITY_ATTRIBUTE_OPERATION,
+            pauthzdeviceclaims: *const super::AUTHZ_SECURITY_ATTRIBUTES_INFORMATION,
+            pauthzdeviceclaimsoperations: *const super::AUTHZ_SECURITY_ATTRIBUTE_OPERATION,
+            peffpermresultlists: *mut EFFPERM_RESULT_LIST,
+        ) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEffectivePermission2_Impl::ComputeEffectivePermissionWithSecondarySecurity(
+                    this,
+                    core::mem::transmute_copy(&psid),
+                    core::mem::transmute_copy(&pdevicesid),
+                    core::mem::transmute(&pszservername),
+                    core::mem::transmute_copy(&psecurityobjects),
+                    core::mem::transmute_copy(&dwsecurityobjectcount),
+                    core::mem::transmute_copy(&pusergroups),
+                    core::mem::transmute_copy(&pauthzusergroupsoperations),
+                    core::mem::transmute_copy(&pdevicegroups),
+                    core::mem::transmute_copy(&pauthzdevicegroupsoperations),
+                    core::mem::transmute_copy(&pauthzuserclaims),
+                    core::mem::transmute_copy(&pauthzuserclaimsoperations),
+                    core::mem::transmute_copy(&pauthzdeviceclaims),
+                    core::mem::transmute_copy(&pauthzdeviceclaimsoperations),
+                    core::mem::transmute_copy(&peffpermresultlists),
+                )
+                .into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ComputeEffectivePermissionWithSecondarySecurity: ComputeEffectivePermissionWithSecondarySecurity::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEffectivePermission2 {}
 windows_core::imp::define_interface!(ISecurityInformation, ISecurityInformation_Vtbl, 0x965fc360_16ff_11d0_91cb_00aa00bbb723);
 windows_core::imp::interface_hierarchy!(ISecurityInformation, windows_core::IUnknown);
@@ -310,6 +377,66 @@ impl ISecurityInformation_Vtbl {
     }
 }
 #[cfg(feature = "Win32_UI_Controls")]
+impl<Identity: windows_core::IUnknownImpl + ISecurityInformation_Impl> windows_core::imp::TearOffVtable<Identity> for ISecurityInformation_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetObjectInformation<Identity: windows_core::IUnknownImpl + ISecurityInformation_Impl>(this: *mut core::ffi::c_void, pobjectinfo: *mut SI_OBJECT_INFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISecurityInformation_Impl::GetObjectInformation(this, core::mem::transmute_copy(&pobjectinfo)).into()
+            }
+        }
+        unsafe extern "system" fn GetSecurity<Identity: windows_core::IUnknownImpl + ISecurityInformation_Impl>(this: *mut core::ffi::c_void, requestedinformation: super::super::OBJECT_SECURITY_INFORMATION, ppsecuritydescriptor: *mut super::super::PSECURITY_DESCRIPTOR, fdefault: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISecurityInformation_Impl::GetSecurity(this, core::mem::transmute_copy(&requestedinformation), core::mem::transmute_copy(&ppsecuritydescriptor), core::mem::transmute_copy(&fdefault)).into()
+            }
+        }
+        unsafe extern "system" fn SetSecurity<Identity: windows_core::IUnknownImpl + ISecurityInformation_Impl>(this: *mut core::ffi::c_void, securityinformation: super::super::OBJECT_SECURITY_INFORMATION, psecuritydescriptor: super::super::PSECURITY_DESCRIPTOR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISecurityInformation_Impl::SetSecurity(this, core::mem::transmute_copy(&securityinformation), core::mem::transmute_copy(&psecuritydescriptor)).into()
+            }
+        }
+        unsafe extern "system" fn GetAccessRights<Identity: windows_core::IUnknownImpl + ISecurityInformation_Impl>(this: *mut core::ffi::c_void, pguidobjecttype: *const windows_core::GUID, dwflags: SECURITY_INFO_PAGE_FLAGS, ppaccess: *mut *mut SI_ACCESS, pcaccesses: *mut u32, pidefaultaccess: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISecurityInformation_Impl::GetAccessRights(this, core::mem::transmute_copy(&pguidobjecttype), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&ppaccess), core::mem::transmute_copy(&pcaccesses), core::mem::transmute_copy(&pidefaultaccess)).into()
+            }
+        }
+        unsafe extern "system" fn MapGeneric<Identity: windows_core::IUnknownImpl + ISecurityInformation_Impl>(this: *mut core::ffi::c_void, pguidobjecttype: *const windows_core::GUID, paceflags: *mut u8, pmask: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISecurityInformation_Impl::MapGeneric(this, core::mem::transmute_copy(&pguidobjecttype), core::mem::transmute_copy(&paceflags), core::mem::transmute_copy(&pmask)).into()
+            }
+        }
+        unsafe extern "system" fn GetInheritTypes<Identity: windows_core::IUnknownImpl + ISecurityInformation_Impl>(this: *mut core::ffi::c_void, ppinherittypes: *mut *mut SI_INHERIT_TYPE, pcinherittypes: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISecurityInformation_Impl::GetInheritTypes(this, core::mem::transmute_copy(&ppinherittypes), core::mem::transmute_copy(&pcinherittypes)).into()
+            }
+        }
+        unsafe extern "system" fn PropertySheetPageCallback<Identity: windows_core::IUnknownImpl + ISecurityInformation_Impl>(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, umsg: super::super::super::UI::Controls::PSPCB_MESSAGE, upage: SI_PAGE_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISecurityInformation_Impl::PropertySheetPageCallback(this, core::mem::transmute_copy(&hwnd), core::mem::transmute_copy(&umsg), core::mem::transmute_copy(&upage)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetObjectInformation: GetObjectInformation::<Identity>,
+            GetSecurity: GetSecurity::<Identity>,
+            SetSecurity: SetSecurity::<Identity>,
+            GetAccessRights: GetAccessRights::<Identity>,
+            MapGeneric: MapGeneric::<Identity>,
+            GetInheritTypes: GetInheritTypes::<Identity>,
+            PropertySheetPageCallback: PropertySheetPageCallback::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_UI_Controls")]
 impl windows_core::RuntimeName for ISecurityInformation {}
 windows_core::imp::define_interface!(ISecurityInformation2, ISecurityInformation2_Vtbl, 0xc3ccfdb4_6f88_11d2_a3ce_00c04fb1782a);
 windows_core::imp::interface_hierarchy!(ISecurityInformation2, windows_core::IUnknown);
@@ -372,6 +499,37 @@ impl ISecurityInformation2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + ISecurityInformation2_Impl> windows_core::imp::TearOffVtable<Identity> for ISecurityInformation2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsDaclCanonical<Identity: windows_core::IUnknownImpl + ISecurityInformation2_Impl>(this: *mut core::ffi::c_void, pdacl: *const super::super::ACL) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISecurityInformation2_Impl::IsDaclCanonical(this, core::mem::transmute_copy(&pdacl))
+            }
+        }
+        unsafe extern "system" fn LookupSids<Identity: windows_core::IUnknownImpl + ISecurityInformation2_Impl>(this: *mut core::ffi::c_void, csids: u32, rgpsids: *const super::super::PSID, ppdo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISecurityInformation2_Impl::LookupSids(this, core::mem::transmute_copy(&csids), core::mem::transmute_copy(&rgpsids)) {
+                    Ok(ok__) => {
+                        ppdo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsDaclCanonical: IsDaclCanonical::<Identity>,
+            LookupSids: LookupSids::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for ISecurityInformation2 {}
 windows_core::imp::define_interface!(ISecurityInformation3, ISecurityInformation3_Vtbl, 0xe2cdc9cc_31bd_4f8f_8c8b_b641af516a1a);
 windows_core::imp::interface_hierarchy!(ISecurityInformation3, windows_core::IUnknown);
@@ -427,6 +585,36 @@ impl ISecurityInformation3_Vtbl {
         iid == &<ISecurityInformation3 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISecurityInformation3_Impl> windows_core::imp::TearOffVtable<Identity> for ISecurityInformation3_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFullResourceName<Identity: windows_core::IUnknownImpl + ISecurityInformation3_Impl>(this: *mut core::ffi::c_void, ppszresourcename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISecurityInformation3_Impl::GetFullResourceName(this) {
+                    Ok(ok__) => {
+                        ppszresourcename.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OpenElevatedEditor<Identity: windows_core::IUnknownImpl + ISecurityInformation3_Impl>(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, upage: SI_PAGE_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISecurityInformation3_Impl::OpenElevatedEditor(this, core::mem::transmute_copy(&hwnd), core::mem::transmute_copy(&upage)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFullResourceName: GetFullResourceName::<Identity>,
+            OpenElevatedEditor: OpenElevatedEditor::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISecurityInformation3 {}
 windows_core::imp::define_interface!(ISecurityInformation4, ISecurityInformation4_Vtbl, 0xea961070_cd14_4621_ace4_f63c03e583e4);
 windows_core::imp::interface_hierarchy!(ISecurityInformation4, windows_core::IUnknown);
@@ -458,6 +646,23 @@ impl ISecurityInformation4_Vtbl {
         iid == &<ISecurityInformation4 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISecurityInformation4_Impl> windows_core::imp::TearOffVtable<Identity> for ISecurityInformation4_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSecondarySecurity<Identity: windows_core::IUnknownImpl + ISecurityInformation4_Impl>(this: *mut core::ffi::c_void, psecurityobjects: *mut *mut SECURITY_OBJECT, psecurityobjectcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISecurityInformation4_Impl::GetSecondarySecurity(this, core::mem::transmute_copy(&psecurityobjects), core::mem::transmute_copy(&psecurityobjectcount)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSecondarySecurity: GetSecondarySecurity::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISecurityInformation4 {}
 windows_core::imp::define_interface!(ISecurityObjectTypeInfo, ISecurityObjectTypeInfo_Vtbl, 0xfc3066eb_79ef_444b_9111_d18a75ebf2fa);
 windows_core::imp::interface_hierarchy!(ISecurityObjectTypeInfo, windows_core::IUnknown);
@@ -489,6 +694,20 @@ impl ISecurityObjectTypeInfo_Vtbl {
         iid == &<ISecurityObjectTypeInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISecurityObjectTypeInfo_Impl> windows_core::imp::TearOffVtable<Identity> for ISecurityObjectTypeInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetInheritSource<Identity: windows_core::IUnknownImpl + ISecurityObjectTypeInfo_Impl>(this: *mut core::ffi::c_void, si: u32, pacl: *mut super::super::ACL, ppinheritarray: *mut *mut super::INHERITED_FROMA) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISecurityObjectTypeInfo_Impl::GetInheritSource(this, core::mem::transmute_copy(&si), core::mem::transmute_copy(&pacl), core::mem::transmute_copy(&ppinheritarray)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetInheritSource: GetInheritSource::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ISecurityObjectTypeInfo {}
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
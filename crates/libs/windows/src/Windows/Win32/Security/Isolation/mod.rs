@@ -132,6 +132,20 @@ impl IIsolatedAppLauncher_Vtbl {
         iid == &<IIsolatedAppLauncher as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IIsolatedAppLauncher_Impl> windows_core::imp::TearOffVtable<Identity> for IIsolatedAppLauncher_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Launch<Identity: windows_core::IUnknownImpl + IIsolatedAppLauncher_Impl>(this: *mut core::ffi::c_void, appusermodelid: windows_core::PCWSTR, arguments: windows_core::PCWSTR, telemetryparameters: *const IsolatedAppLauncherTelemetryParameters) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIsolatedAppLauncher_Impl::Launch(this, core::mem::transmute(&appusermodelid), core::mem::transmute(&arguments), core::mem::transmute_copy(&telemetryparameters)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Launch: Launch::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IIsolatedAppLauncher {}
 windows_core::imp::define_interface!(IIsolatedProcessLauncher, IIsolatedProcessLauncher_Vtbl, 0x1aa24232_9a91_4201_88cb_122f9d6522e0);
 windows_core::imp::interface_hierarchy!(IIsolatedProcessLauncher, windows_core::IUnknown);
@@ -241,6 +255,63 @@ impl IIsolatedProcessLauncher_Vtbl {
         iid == &<IIsolatedProcessLauncher as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IIsolatedProcessLauncher_Impl> windows_core::imp::TearOffVtable<Identity> for IIsolatedProcessLauncher_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn LaunchProcess<Identity: windows_core::IUnknownImpl + IIsolatedProcessLauncher_Impl>(this: *mut core::ffi::c_void, process: windows_core::PCWSTR, arguments: windows_core::PCWSTR, workingdirectory: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIsolatedProcessLauncher_Impl::LaunchProcess(this, core::mem::transmute(&process), core::mem::transmute(&arguments), core::mem::transmute(&workingdirectory)).into()
+            }
+        }
+        unsafe extern "system" fn ShareDirectory<Identity: windows_core::IUnknownImpl + IIsolatedProcessLauncher_Impl>(this: *mut core::ffi::c_void, hostpath: windows_core::PCWSTR, containerpath: windows_core::PCWSTR, readonly: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIsolatedProcessLauncher_Impl::ShareDirectory(this, core::mem::transmute(&hostpath), core::mem::transmute(&containerpath), core::mem::transmute_copy(&readonly)).into()
+            }
+        }
+        unsafe extern "system" fn GetContainerGuid<Identity: windows_core::IUnknownImpl + IIsolatedProcessLauncher_Impl>(this: *mut core::ffi::c_void, guid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IIsolatedProcessLauncher_Impl::GetContainerGuid(this) {
+                    Ok(ok__) => {
+                        guid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AllowSetForegroundAccess<Identity: windows_core::IUnknownImpl + IIsolatedProcessLauncher_Impl>(this: *mut core::ffi::c_void, pid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIsolatedProcessLauncher_Impl::AllowSetForegroundAccess(this, core::mem::transmute_copy(&pid)).into()
+            }
+        }
+        unsafe extern "system" fn IsContainerRunning<Identity: windows_core::IUnknownImpl + IIsolatedProcessLauncher_Impl>(this: *mut core::ffi::c_void, running: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IIsolatedProcessLauncher_Impl::IsContainerRunning(this) {
+                    Ok(ok__) => {
+                        running.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            LaunchProcess: LaunchProcess::<Identity>,
+            ShareDirectory: ShareDirectory::<Identity>,
+            GetContainerGuid: GetContainerGuid::<Identity>,
+            AllowSetForegroundAccess: AllowSetForegroundAccess::<Identity>,
+            IsContainerRunning: IsContainerRunning::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IIsolatedProcessLauncher {}
 windows_core::imp::define_interface!(IIsolatedProcessLauncher2, IIsolatedProcessLauncher2_Vtbl, 0x780e4416_5e72_4123_808e_66dc6479feef);
 impl core::ops::Deref for IIsolatedProcessLauncher2 {
@@ -283,6 +354,20 @@ impl IIsolatedProcessLauncher2_Vtbl {
         iid == &<IIsolatedProcessLauncher2 as windows_core::Interface>::IID || iid == &<IIsolatedProcessLauncher as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IIsolatedProcessLauncher2_Impl> windows_core::imp::TearOffVtable<Identity> for IIsolatedProcessLauncher2_Vtbl
+where
+    IIsolatedProcessLauncher_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn LaunchProcess2<Identity: windows_core::IUnknownImpl + IIsolatedProcessLauncher2_Impl>(this: *mut core::ffi::c_void, process: windows_core::PCWSTR, arguments: windows_core::PCWSTR, workingdirectory: windows_core::PCWSTR, correlationguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIsolatedProcessLauncher2_Impl::LaunchProcess2(this, core::mem::transmute(&process), core::mem::transmute(&arguments), core::mem::transmute(&workingdirectory), core::mem::transmute_copy(&correlationguid)).into()
+            }
+        }
+        Self { base__: <IIsolatedProcessLauncher_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, LaunchProcess2: LaunchProcess2::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IIsolatedProcessLauncher2 {}
 pub const IsolatedAppLauncher: windows_core::GUID = windows_core::GUID::from_u128(0xbc812430_e75e_4fd1_9641_1f9f1e2d9a1f);
 #[repr(C)]
@@ -77,6 +77,53 @@ impl ITpmVirtualSmartCardManager_Vtbl {
         iid == &<ITpmVirtualSmartCardManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITpmVirtualSmartCardManager_Impl> windows_core::imp::TearOffVtable<Identity> for ITpmVirtualSmartCardManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateVirtualSmartCard<Identity: windows_core::IUnknownImpl + ITpmVirtualSmartCardManager_Impl>(this: *mut core::ffi::c_void, pszfriendlyname: windows_core::PCWSTR, badminalgid: u8, pbadminkey: *const u8, cbadminkey: u32, pbadminkcv: *const u8, cbadminkcv: u32, pbpuk: *const u8, cbpuk: u32, pbpin: *const u8, cbpin: u32, fgenerate: windows_core::BOOL, pstatuscallback: *mut core::ffi::c_void, ppszinstanceid: *mut windows_core::PWSTR, pfneedreboot: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITpmVirtualSmartCardManager_Impl::CreateVirtualSmartCard(
+                    this,
+                    core::mem::transmute(&pszfriendlyname),
+                    core::mem::transmute_copy(&badminalgid),
+                    core::mem::transmute_copy(&pbadminkey),
+                    core::mem::transmute_copy(&cbadminkey),
+                    core::mem::transmute_copy(&pbadminkcv),
+                    core::mem::transmute_copy(&cbadminkcv),
+                    core::mem::transmute_copy(&pbpuk),
+                    core::mem::transmute_copy(&cbpuk),
+                    core::mem::transmute_copy(&pbpin),
+                    core::mem::transmute_copy(&cbpin),
+                    core::mem::transmute_copy(&fgenerate),
+                    core::mem::transmute_copy(&pstatuscallback),
+                    core::mem::transmute_copy(&ppszinstanceid),
+                    core::mem::transmute_copy(&pfneedreboot),
+                )
+                .into()
+            }
+        }
+        unsafe extern "system" fn DestroyVirtualSmartCard<Identity: windows_core::IUnknownImpl + ITpmVirtualSmartCardManager_Impl>(this: *mut core::ffi::c_void, pszinstanceid: windows_core::PCWSTR, pstatuscallback: *mut core::ffi::c_void, pfneedreboot: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITpmVirtualSmartCardManager_Impl::DestroyVirtualSmartCard(this, core::mem::transmute(&pszinstanceid), core::mem::transmute_copy(&pstatuscallback)) {
+                    Ok(ok__) => {
+                        pfneedreboot.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateVirtualSmartCard: CreateVirtualSmartCard::<Identity>,
+            DestroyVirtualSmartCard: DestroyVirtualSmartCard::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITpmVirtualSmartCardManager {}
 windows_core::imp::define_interface!(ITpmVirtualSmartCardManager2, ITpmVirtualSmartCardManager2_Vtbl, 0xfdf8a2b9_02de_47f4_bc26_aa85ab5e5267);
 impl core::ops::Deref for ITpmVirtualSmartCardManager2 {
@@ -161,6 +208,42 @@ impl ITpmVirtualSmartCardManager2_Vtbl {
         iid == &<ITpmVirtualSmartCardManager2 as windows_core::Interface>::IID || iid == &<ITpmVirtualSmartCardManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITpmVirtualSmartCardManager2_Impl> windows_core::imp::TearOffVtable<Identity> for ITpmVirtualSmartCardManager2_Vtbl
+where
+    ITpmVirtualSmartCardManager_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateVirtualSmartCardWithPinPolicy<Identity: windows_core::IUnknownImpl + ITpmVirtualSmartCardManager2_Impl>(this: *mut core::ffi::c_void, pszfriendlyname: windows_core::PCWSTR, badminalgid: u8, pbadminkey: *const u8, cbadminkey: u32, pbadminkcv: *const u8, cbadminkcv: u32, pbpuk: *const u8, cbpuk: u32, pbpin: *const u8, cbpin: u32, pbpinpolicy: *const u8, cbpinpolicy: u32, fgenerate: windows_core::BOOL, pstatuscallback: *mut core::ffi::c_void, ppszinstanceid: *mut windows_core::PWSTR, pfneedreboot: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITpmVirtualSmartCardManager2_Impl::CreateVirtualSmartCardWithPinPolicy(
+                    this,
+                    core::mem::transmute(&pszfriendlyname),
+                    core::mem::transmute_copy(&badminalgid),
+                    core::mem::transmute_copy(&pbadminkey),
+                    core::mem::transmute_copy(&cbadminkey),
+                    core::mem::transmute_copy(&pbadminkcv),
+                    core::mem::transmute_copy(&cbadminkcv),
+                    core::mem::transmute_copy(&pbpuk),
+                    core::mem::transmute_copy(&cbpuk),
+                    core::mem::transmute_copy(&pbpin),
+                    core::mem::transmute_copy(&cbpin),
+                    core::mem::transmute_copy(&pbpinpolicy),
+                    core::mem::transmute_copy(&cbpinpolicy),
+                    core::mem::transmute_copy(&fgenerate),
+                    core::mem::transmute_copy(&pstatuscallback),
+                    core::mem::transmute_copy(&ppszinstanceid),
+                    core::mem::transmute_copy(&pfneedreboot),
+                )
+                .into()
+            }
+        }
+        Self {
+            base__: <ITpmVirtualSmartCardManager_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateVirtualSmartCardWithPinPolicy: CreateVirtualSmartCardWithPinPolicy::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITpmVirtualSmartCardManager2 {}
 windows_core::imp::define_interface!(ITpmVirtualSmartCardManager3, ITpmVirtualSmartCardManager3_Vtbl, 0x3c745a97_f375_4150_be17_5950f694c699);
 impl core::ops::Deref for ITpmVirtualSmartCardManager3 {
@@ -250,6 +333,46 @@ impl ITpmVirtualSmartCardManager3_Vtbl {
         iid == &<ITpmVirtualSmartCardManager3 as windows_core::Interface>::IID || iid == &<ITpmVirtualSmartCardManager as windows_core::Interface>::IID || iid == &<ITpmVirtualSmartCardManager2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITpmVirtualSmartCardManager3_Impl> windows_core::imp::TearOffVtable<Identity> for ITpmVirtualSmartCardManager3_Vtbl
+where
+    ITpmVirtualSmartCardManager2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateVirtualSmartCardWithAttestation<Identity: windows_core::IUnknownImpl + ITpmVirtualSmartCardManager3_Impl>(this: *mut core::ffi::c_void, pszfriendlyname: windows_core::PCWSTR, badminalgid: u8, pbadminkey: *const u8, cbadminkey: u32, pbadminkcv: *const u8, cbadminkcv: u32, pbpuk: *const u8, cbpuk: u32, pbpin: *const u8, cbpin: u32, pbpinpolicy: *const u8, cbpinpolicy: u32, attestationtype: TPMVSC_ATTESTATION_TYPE, fgenerate: windows_core::BOOL, pstatuscallback: *mut core::ffi::c_void, ppszinstanceid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITpmVirtualSmartCardManager3_Impl::CreateVirtualSmartCardWithAttestation(
+                    this,
+                    core::mem::transmute(&pszfriendlyname),
+                    core::mem::transmute_copy(&badminalgid),
+                    core::mem::transmute_copy(&pbadminkey),
+                    core::mem::transmute_copy(&cbadminkey),
+                    core::mem::transmute_copy(&pbadminkcv),
+                    core::mem::transmute_copy(&cbadminkcv),
+                    core::mem::transmute_copy(&pbpuk),
+                    core::mem::transmute_copy(&cbpuk),
+                    core::mem::transmute_copy(&pbpin),
+                    core::mem::transmute_copy(&cbpin),
+                    core::mem::transmute_copy(&pbpinpolicy),
+                    core::mem::transmute_copy(&cbpinpolicy),
+                    core::mem::transmute_copy(&attestationtype),
+                    core::mem::transmute_copy(&fgenerate),
+                    core::mem::transmute_copy(&pstatuscallback),
+                ) {
+                    Ok(ok__) => {
+                        ppszinstanceid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ITpmVirtualSmartCardManager2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateVirtualSmartCardWithAttestation: CreateVirtualSmartCardWithAttestation::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITpmVirtualSmartCardManager3 {}
 windows_core::imp::define_interface!(ITpmVirtualSmartCardManagerStatusCallback, ITpmVirtualSmartCardManagerStatusCallback_Vtbl, 0x1a1bb35f_abb8_451c_a1ae_33d98f1bef4a);
 windows_core::imp::interface_hierarchy!(ITpmVirtualSmartCardManagerStatusCallback, windows_core::IUnknown);
@@ -296,6 +419,30 @@ impl ITpmVirtualSmartCardManagerStatusCallback_Vtbl {
         iid == &<ITpmVirtualSmartCardManagerStatusCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITpmVirtualSmartCardManagerStatusCallback_Impl> windows_core::imp::TearOffVtable<Identity> for ITpmVirtualSmartCardManagerStatusCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ReportProgress<Identity: windows_core::IUnknownImpl + ITpmVirtualSmartCardManagerStatusCallback_Impl>(this: *mut core::ffi::c_void, status: TPMVSCMGR_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITpmVirtualSmartCardManagerStatusCallback_Impl::ReportProgress(this, core::mem::transmute_copy(&status)).into()
+            }
+        }
+        unsafe extern "system" fn ReportError<Identity: windows_core::IUnknownImpl + ITpmVirtualSmartCardManagerStatusCallback_Impl>(this: *mut core::ffi::c_void, error: TPMVSCMGR_ERROR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITpmVirtualSmartCardManagerStatusCallback_Impl::ReportError(this, core::mem::transmute_copy(&error)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ReportProgress: ReportProgress::<Identity>,
+            ReportError: ReportError::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITpmVirtualSmartCardManagerStatusCallback {}
 pub const RemoteTpmVirtualSmartCardManager: windows_core::GUID = windows_core::GUID::from_u128(0x152ea2a8_70dc_4c59_8b2a_32aa3ca0dcac);
 #[repr(transparent)]
@@ -327,6 +327,76 @@ impl IEnhancedStorageACT_Vtbl {
         iid == &<IEnhancedStorageACT as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnhancedStorageACT_Impl> windows_core::imp::TearOffVtable<Identity> for IEnhancedStorageACT_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Authorize<Identity: windows_core::IUnknownImpl + IEnhancedStorageACT_Impl>(this: *mut core::ffi::c_void, hwndparent: u32, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnhancedStorageACT_Impl::Authorize(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn Unauthorize<Identity: windows_core::IUnknownImpl + IEnhancedStorageACT_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnhancedStorageACT_Impl::Unauthorize(this).into()
+            }
+        }
+        unsafe extern "system" fn GetAuthorizationState<Identity: windows_core::IUnknownImpl + IEnhancedStorageACT_Impl>(this: *mut core::ffi::c_void, pstate: *mut ACT_AUTHORIZATION_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnhancedStorageACT_Impl::GetAuthorizationState(this) {
+                    Ok(ok__) => {
+                        pstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMatchingVolume<Identity: windows_core::IUnknownImpl + IEnhancedStorageACT_Impl>(this: *mut core::ffi::c_void, ppwszvolume: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnhancedStorageACT_Impl::GetMatchingVolume(this) {
+                    Ok(ok__) => {
+                        ppwszvolume.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetUniqueIdentity<Identity: windows_core::IUnknownImpl + IEnhancedStorageACT_Impl>(this: *mut core::ffi::c_void, ppwszidentity: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnhancedStorageACT_Impl::GetUniqueIdentity(this) {
+                    Ok(ok__) => {
+                        ppwszidentity.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSilos<Identity: windows_core::IUnknownImpl + IEnhancedStorageACT_Impl>(this: *mut core::ffi::c_void, pppienhancedstoragesilos: *mut *mut *mut core::ffi::c_void, pcenhancedstoragesilos: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnhancedStorageACT_Impl::GetSilos(this, core::mem::transmute_copy(&pppienhancedstoragesilos), core::mem::transmute_copy(&pcenhancedstoragesilos)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Authorize: Authorize::<Identity>,
+            Unauthorize: Unauthorize::<Identity>,
+            GetAuthorizationState: GetAuthorizationState::<Identity>,
+            GetMatchingVolume: GetMatchingVolume::<Identity>,
+            GetUniqueIdentity: GetUniqueIdentity::<Identity>,
+            GetSilos: GetSilos::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnhancedStorageACT {}
 windows_core::imp::define_interface!(IEnhancedStorageACT2, IEnhancedStorageACT2_Vtbl, 0x4da57d2e_8eb3_41f6_a07e_98b52b88242b);
 impl core::ops::Deref for IEnhancedStorageACT2 {
@@ -397,6 +467,42 @@ impl IEnhancedStorageACT2_Vtbl {
         iid == &<IEnhancedStorageACT2 as windows_core::Interface>::IID || iid == &<IEnhancedStorageACT as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnhancedStorageACT2_Impl> windows_core::imp::TearOffVtable<Identity> for IEnhancedStorageACT2_Vtbl
+where
+    IEnhancedStorageACT_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDeviceName<Identity: windows_core::IUnknownImpl + IEnhancedStorageACT2_Impl>(this: *mut core::ffi::c_void, ppwszdevicename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnhancedStorageACT2_Impl::GetDeviceName(this) {
+                    Ok(ok__) => {
+                        ppwszdevicename.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsDeviceRemovable<Identity: windows_core::IUnknownImpl + IEnhancedStorageACT2_Impl>(this: *mut core::ffi::c_void, pisdeviceremovable: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnhancedStorageACT2_Impl::IsDeviceRemovable(this) {
+                    Ok(ok__) => {
+                        pisdeviceremovable.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IEnhancedStorageACT_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDeviceName: GetDeviceName::<Identity>,
+            IsDeviceRemovable: IsDeviceRemovable::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnhancedStorageACT2 {}
 windows_core::imp::define_interface!(IEnhancedStorageACT3, IEnhancedStorageACT3_Vtbl, 0x022150a1_113d_11df_bb61_001aa01bbc58);
 impl core::ops::Deref for IEnhancedStorageACT3 {
@@ -479,6 +585,49 @@ impl IEnhancedStorageACT3_Vtbl {
         iid == &<IEnhancedStorageACT3 as windows_core::Interface>::IID || iid == &<IEnhancedStorageACT as windows_core::Interface>::IID || iid == &<IEnhancedStorageACT2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnhancedStorageACT3_Impl> windows_core::imp::TearOffVtable<Identity> for IEnhancedStorageACT3_Vtbl
+where
+    IEnhancedStorageACT2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn UnauthorizeEx<Identity: windows_core::IUnknownImpl + IEnhancedStorageACT3_Impl>(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnhancedStorageACT3_Impl::UnauthorizeEx(this, core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn IsQueueFrozen<Identity: windows_core::IUnknownImpl + IEnhancedStorageACT3_Impl>(this: *mut core::ffi::c_void, pisqueuefrozen: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnhancedStorageACT3_Impl::IsQueueFrozen(this) {
+                    Ok(ok__) => {
+                        pisqueuefrozen.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetShellExtSupport<Identity: windows_core::IUnknownImpl + IEnhancedStorageACT3_Impl>(this: *mut core::ffi::c_void, pshellextsupport: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnhancedStorageACT3_Impl::GetShellExtSupport(this) {
+                    Ok(ok__) => {
+                        pshellextsupport.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IEnhancedStorageACT2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            UnauthorizeEx: UnauthorizeEx::<Identity>,
+            IsQueueFrozen: IsQueueFrozen::<Identity>,
+            GetShellExtSupport: GetShellExtSupport::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnhancedStorageACT3 {}
 windows_core::imp::define_interface!(IEnhancedStorageSilo, IEnhancedStorageSilo_Vtbl, 0x5aef78c6_2242_4703_bf49_44b29357a359);
 windows_core::imp::interface_hierarchy!(IEnhancedStorageSilo, windows_core::IUnknown);
@@ -595,6 +744,70 @@ impl IEnhancedStorageSilo_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Devices_PortableDevices")]
+impl<Identity: windows_core::IUnknownImpl + IEnhancedStorageSilo_Impl> windows_core::imp::TearOffVtable<Identity> for IEnhancedStorageSilo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetInfo<Identity: windows_core::IUnknownImpl + IEnhancedStorageSilo_Impl>(this: *mut core::ffi::c_void, psiloinfo: *mut SILO_INFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnhancedStorageSilo_Impl::GetInfo(this) {
+                    Ok(ok__) => {
+                        psiloinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetActions<Identity: windows_core::IUnknownImpl + IEnhancedStorageSilo_Impl>(this: *mut core::ffi::c_void, pppienhancedstoragesiloactions: *mut *mut *mut core::ffi::c_void, pcenhancedstoragesiloactions: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnhancedStorageSilo_Impl::GetActions(this, core::mem::transmute_copy(&pppienhancedstoragesiloactions), core::mem::transmute_copy(&pcenhancedstoragesiloactions)).into()
+            }
+        }
+        unsafe extern "system" fn SendCommand<Identity: windows_core::IUnknownImpl + IEnhancedStorageSilo_Impl>(this: *mut core::ffi::c_void, command: u8, pbcommandbuffer: *const u8, cbcommandbuffer: u32, pbresponsebuffer: *mut u8, pcbresponsebuffer: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnhancedStorageSilo_Impl::SendCommand(this, core::mem::transmute_copy(&command), core::mem::transmute_copy(&pbcommandbuffer), core::mem::transmute_copy(&cbcommandbuffer), core::mem::transmute_copy(&pbresponsebuffer), core::mem::transmute_copy(&pcbresponsebuffer)).into()
+            }
+        }
+        unsafe extern "system" fn GetPortableDevice<Identity: windows_core::IUnknownImpl + IEnhancedStorageSilo_Impl>(this: *mut core::ffi::c_void, ppiportabledevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnhancedStorageSilo_Impl::GetPortableDevice(this) {
+                    Ok(ok__) => {
+                        ppiportabledevice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDevicePath<Identity: windows_core::IUnknownImpl + IEnhancedStorageSilo_Impl>(this: *mut core::ffi::c_void, ppwszsilodevicepath: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnhancedStorageSilo_Impl::GetDevicePath(this) {
+                    Ok(ok__) => {
+                        ppwszsilodevicepath.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetInfo: GetInfo::<Identity>,
+            GetActions: GetActions::<Identity>,
+            SendCommand: SendCommand::<Identity>,
+            GetPortableDevice: GetPortableDevice::<Identity>,
+            GetDevicePath: GetDevicePath::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Devices_PortableDevices")]
 impl windows_core::RuntimeName for IEnhancedStorageSilo {}
 windows_core::imp::define_interface!(IEnhancedStorageSiloAction, IEnhancedStorageSiloAction_Vtbl, 0xb6f7f311_206f_4ff8_9c4b_27efee77a86f);
 windows_core::imp::interface_hierarchy!(IEnhancedStorageSiloAction, windows_core::IUnknown);
@@ -671,6 +884,49 @@ impl IEnhancedStorageSiloAction_Vtbl {
         iid == &<IEnhancedStorageSiloAction as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnhancedStorageSiloAction_Impl> windows_core::imp::TearOffVtable<Identity> for IEnhancedStorageSiloAction_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IEnhancedStorageSiloAction_Impl>(this: *mut core::ffi::c_void, ppwszactionname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnhancedStorageSiloAction_Impl::GetName(this) {
+                    Ok(ok__) => {
+                        ppwszactionname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDescription<Identity: windows_core::IUnknownImpl + IEnhancedStorageSiloAction_Impl>(this: *mut core::ffi::c_void, ppwszactiondescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnhancedStorageSiloAction_Impl::GetDescription(this) {
+                    Ok(ok__) => {
+                        ppwszactiondescription.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Invoke<Identity: windows_core::IUnknownImpl + IEnhancedStorageSiloAction_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnhancedStorageSiloAction_Impl::Invoke(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetName: GetName::<Identity>,
+            GetDescription: GetDescription::<Identity>,
+            Invoke: Invoke::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnhancedStorageSiloAction {}
 windows_core::imp::define_interface!(IEnumEnhancedStorageACT, IEnumEnhancedStorageACT_Vtbl, 0x09b224bd_1335_4631_a7ff_cfd3a92646d7);
 windows_core::imp::interface_hierarchy!(IEnumEnhancedStorageACT, windows_core::IUnknown);
@@ -729,6 +985,36 @@ impl IEnumEnhancedStorageACT_Vtbl {
         iid == &<IEnumEnhancedStorageACT as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumEnhancedStorageACT_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumEnhancedStorageACT_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetACTs<Identity: windows_core::IUnknownImpl + IEnumEnhancedStorageACT_Impl>(this: *mut core::ffi::c_void, pppienhancedstorageacts: *mut *mut *mut core::ffi::c_void, pcenhancedstorageacts: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumEnhancedStorageACT_Impl::GetACTs(this, core::mem::transmute_copy(&pppienhancedstorageacts), core::mem::transmute_copy(&pcenhancedstorageacts)).into()
+            }
+        }
+        unsafe extern "system" fn GetMatchingACT<Identity: windows_core::IUnknownImpl + IEnumEnhancedStorageACT_Impl>(this: *mut core::ffi::c_void, szvolume: windows_core::PCWSTR, ppienhancedstorageact: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumEnhancedStorageACT_Impl::GetMatchingACT(this, core::mem::transmute(&szvolume)) {
+                    Ok(ok__) => {
+                        ppienhancedstorageact.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetACTs: GetACTs::<Identity>,
+            GetMatchingACT: GetMatchingACT::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumEnhancedStorageACT {}
 pub const IMPORTANCE_HIGH_MAX: i32 = 5i32;
 pub const IMPORTANCE_HIGH_MIN: i32 = 5i32;
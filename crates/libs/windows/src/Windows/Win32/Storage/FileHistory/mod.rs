@@ -393,6 +393,144 @@ impl IFhConfigMgr_Vtbl {
         iid == &<IFhConfigMgr as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IFhConfigMgr_Impl> windows_core::imp::TearOffVtable<Identity> for IFhConfigMgr_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn LoadConfiguration<Identity: windows_core::IUnknownImpl + IFhConfigMgr_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFhConfigMgr_Impl::LoadConfiguration(this).into()
+            }
+        }
+        unsafe extern "system" fn CreateDefaultConfiguration<Identity: windows_core::IUnknownImpl + IFhConfigMgr_Impl>(this: *mut core::ffi::c_void, overwriteifexists: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFhConfigMgr_Impl::CreateDefaultConfiguration(this, core::mem::transmute_copy(&overwriteifexists)).into()
+            }
+        }
+        unsafe extern "system" fn SaveConfiguration<Identity: windows_core::IUnknownImpl + IFhConfigMgr_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFhConfigMgr_Impl::SaveConfiguration(this).into()
+            }
+        }
+        unsafe extern "system" fn AddRemoveExcludeRule<Identity: windows_core::IUnknownImpl + IFhConfigMgr_Impl>(this: *mut core::ffi::c_void, add: windows_core::BOOL, category: FH_PROTECTED_ITEM_CATEGORY, item: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFhConfigMgr_Impl::AddRemoveExcludeRule(this, core::mem::transmute_copy(&add), core::mem::transmute_copy(&category), core::mem::transmute(&item)).into()
+            }
+        }
+        unsafe extern "system" fn GetIncludeExcludeRules<Identity: windows_core::IUnknownImpl + IFhConfigMgr_Impl>(this: *mut core::ffi::c_void, include: windows_core::BOOL, category: FH_PROTECTED_ITEM_CATEGORY, iterator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFhConfigMgr_Impl::GetIncludeExcludeRules(this, core::mem::transmute_copy(&include), core::mem::transmute_copy(&category)) {
+                    Ok(ok__) => {
+                        iterator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLocalPolicy<Identity: windows_core::IUnknownImpl + IFhConfigMgr_Impl>(this: *mut core::ffi::c_void, localpolicytype: FH_LOCAL_POLICY_TYPE, policyvalue: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFhConfigMgr_Impl::GetLocalPolicy(this, core::mem::transmute_copy(&localpolicytype)) {
+                    Ok(ok__) => {
+                        policyvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLocalPolicy<Identity: windows_core::IUnknownImpl + IFhConfigMgr_Impl>(this: *mut core::ffi::c_void, localpolicytype: FH_LOCAL_POLICY_TYPE, policyvalue: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFhConfigMgr_Impl::SetLocalPolicy(this, core::mem::transmute_copy(&localpolicytype), core::mem::transmute_copy(&policyvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetBackupStatus<Identity: windows_core::IUnknownImpl + IFhConfigMgr_Impl>(this: *mut core::ffi::c_void, backupstatus: *mut FH_BACKUP_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFhConfigMgr_Impl::GetBackupStatus(this) {
+                    Ok(ok__) => {
+                        backupstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBackupStatus<Identity: windows_core::IUnknownImpl + IFhConfigMgr_Impl>(this: *mut core::ffi::c_void, backupstatus: FH_BACKUP_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFhConfigMgr_Impl::SetBackupStatus(this, core::mem::transmute_copy(&backupstatus)).into()
+            }
+        }
+        unsafe extern "system" fn GetDefaultTarget<Identity: windows_core::IUnknownImpl + IFhConfigMgr_Impl>(this: *mut core::ffi::c_void, defaulttarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFhConfigMgr_Impl::GetDefaultTarget(this) {
+                    Ok(ok__) => {
+                        defaulttarget.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ValidateTarget<Identity: windows_core::IUnknownImpl + IFhConfigMgr_Impl>(this: *mut core::ffi::c_void, targeturl: *mut core::ffi::c_void, validationresult: *mut FH_DEVICE_VALIDATION_RESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFhConfigMgr_Impl::ValidateTarget(this, core::mem::transmute(&targeturl)) {
+                    Ok(ok__) => {
+                        validationresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ProvisionAndSetNewTarget<Identity: windows_core::IUnknownImpl + IFhConfigMgr_Impl>(this: *mut core::ffi::c_void, targeturl: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFhConfigMgr_Impl::ProvisionAndSetNewTarget(this, core::mem::transmute(&targeturl), core::mem::transmute(&targetname)).into()
+            }
+        }
+        unsafe extern "system" fn ChangeDefaultTargetRecommendation<Identity: windows_core::IUnknownImpl + IFhConfigMgr_Impl>(this: *mut core::ffi::c_void, recommend: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFhConfigMgr_Impl::ChangeDefaultTargetRecommendation(this, core::mem::transmute_copy(&recommend)).into()
+            }
+        }
+        unsafe extern "system" fn QueryProtectionStatus<Identity: windows_core::IUnknownImpl + IFhConfigMgr_Impl>(this: *mut core::ffi::c_void, protectionstate: *mut u32, protecteduntiltime: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFhConfigMgr_Impl::QueryProtectionStatus(this, core::mem::transmute_copy(&protectionstate), core::mem::transmute_copy(&protecteduntiltime)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            LoadConfiguration: LoadConfiguration::<Identity>,
+            CreateDefaultConfiguration: CreateDefaultConfiguration::<Identity>,
+            SaveConfiguration: SaveConfiguration::<Identity>,
+            AddRemoveExcludeRule: AddRemoveExcludeRule::<Identity>,
+            GetIncludeExcludeRules: GetIncludeExcludeRules::<Identity>,
+            GetLocalPolicy: GetLocalPolicy::<Identity>,
+            SetLocalPolicy: SetLocalPolicy::<Identity>,
+            GetBackupStatus: GetBackupStatus::<Identity>,
+            SetBackupStatus: SetBackupStatus::<Identity>,
+            GetDefaultTarget: GetDefaultTarget::<Identity>,
+            ValidateTarget: ValidateTarget::<Identity>,
+            ProvisionAndSetNewTarget: ProvisionAndSetNewTarget::<Identity>,
+            ChangeDefaultTargetRecommendation: ChangeDefaultTargetRecommendation::<Identity>,
+            QueryProtectionStatus: QueryProtectionStatus::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IFhConfigMgr {}
 windows_core::imp::define_interface!(IFhReassociation, IFhReassociation_Vtbl, 0x6544a28a_f68d_47ac_91ef_16b2b36aa3ee);
 windows_core::imp::interface_hierarchy!(IFhReassociation, windows_core::IUnknown);
@@ -484,6 +622,57 @@ impl IFhReassociation_Vtbl {
         iid == &<IFhReassociation as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IFhReassociation_Impl> windows_core::imp::TearOffVtable<Identity> for IFhReassociation_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ValidateTarget<Identity: windows_core::IUnknownImpl + IFhReassociation_Impl>(this: *mut core::ffi::c_void, targeturl: *mut core::ffi::c_void, validationresult: *mut FH_DEVICE_VALIDATION_RESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFhReassociation_Impl::ValidateTarget(this, core::mem::transmute(&targeturl)) {
+                    Ok(ok__) => {
+                        validationresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ScanTargetForConfigurations<Identity: windows_core::IUnknownImpl + IFhReassociation_Impl>(this: *mut core::ffi::c_void, targeturl: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFhReassociation_Impl::ScanTargetForConfigurations(this, core::mem::transmute(&targeturl)).into()
+            }
+        }
+        unsafe extern "system" fn GetConfigurationDetails<Identity: windows_core::IUnknownImpl + IFhReassociation_Impl>(this: *mut core::ffi::c_void, index: u32, username: *mut *mut core::ffi::c_void, pcname: *mut *mut core::ffi::c_void, backuptime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFhReassociation_Impl::GetConfigurationDetails(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&username), core::mem::transmute_copy(&pcname), core::mem::transmute_copy(&backuptime)).into()
+            }
+        }
+        unsafe extern "system" fn SelectConfiguration<Identity: windows_core::IUnknownImpl + IFhReassociation_Impl>(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFhReassociation_Impl::SelectConfiguration(this, core::mem::transmute_copy(&index)).into()
+            }
+        }
+        unsafe extern "system" fn PerformReassociation<Identity: windows_core::IUnknownImpl + IFhReassociation_Impl>(this: *mut core::ffi::c_void, overwriteifexists: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFhReassociation_Impl::PerformReassociation(this, core::mem::transmute_copy(&overwriteifexists)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ValidateTarget: ValidateTarget::<Identity>,
+            ScanTargetForConfigurations: ScanTargetForConfigurations::<Identity>,
+            GetConfigurationDetails: GetConfigurationDetails::<Identity>,
+            SelectConfiguration: SelectConfiguration::<Identity>,
+            PerformReassociation: PerformReassociation::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IFhReassociation {}
 windows_core::imp::define_interface!(IFhScopeIterator, IFhScopeIterator_Vtbl, 0x3197abce_532a_44c6_8615_f3666566a720);
 windows_core::imp::interface_hierarchy!(IFhScopeIterator, windows_core::IUnknown);
@@ -539,6 +728,36 @@ impl IFhScopeIterator_Vtbl {
         iid == &<IFhScopeIterator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IFhScopeIterator_Impl> windows_core::imp::TearOffVtable<Identity> for IFhScopeIterator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn MoveToNextItem<Identity: windows_core::IUnknownImpl + IFhScopeIterator_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFhScopeIterator_Impl::MoveToNextItem(this).into()
+            }
+        }
+        unsafe extern "system" fn GetItem<Identity: windows_core::IUnknownImpl + IFhScopeIterator_Impl>(this: *mut core::ffi::c_void, item: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFhScopeIterator_Impl::GetItem(this) {
+                    Ok(ok__) => {
+                        item.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MoveToNextItem: MoveToNextItem::<Identity>,
+            GetItem: GetItem::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IFhScopeIterator {}
 windows_core::imp::define_interface!(IFhTarget, IFhTarget_Vtbl, 0xd87965fd_2bad_4657_bd3b_9567eb300ced);
 windows_core::imp::interface_hierarchy!(IFhTarget, windows_core::IUnknown);
@@ -603,6 +822,42 @@ impl IFhTarget_Vtbl {
         iid == &<IFhTarget as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IFhTarget_Impl> windows_core::imp::TearOffVtable<Identity> for IFhTarget_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStringProperty<Identity: windows_core::IUnknownImpl + IFhTarget_Impl>(this: *mut core::ffi::c_void, propertytype: FH_TARGET_PROPERTY_TYPE, propertyvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFhTarget_Impl::GetStringProperty(this, core::mem::transmute_copy(&propertytype)) {
+                    Ok(ok__) => {
+                        propertyvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetNumericalProperty<Identity: windows_core::IUnknownImpl + IFhTarget_Impl>(this: *mut core::ffi::c_void, propertytype: FH_TARGET_PROPERTY_TYPE, propertyvalue: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFhTarget_Impl::GetNumericalProperty(this, core::mem::transmute_copy(&propertytype)) {
+                    Ok(ok__) => {
+                        propertyvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStringProperty: GetStringProperty::<Identity>,
+            GetNumericalProperty: GetNumericalProperty::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IFhTarget {}
 pub const MAX_BACKUP_STATUS: FH_BACKUP_STATUS = FH_BACKUP_STATUS(4i32);
 pub const MAX_LOCAL_POLICY: FH_LOCAL_POLICY_TYPE = FH_LOCAL_POLICY_TYPE(3i32);
@@ -5222,6 +5222,194 @@ impl IDiskQuotaControl_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Security", feature = "Win32_System_Com"))]
+impl<Identity: windows_core::IUnknownImpl + IDiskQuotaControl_Impl> windows_core::imp::TearOffVtable<Identity> for IDiskQuotaControl_Vtbl
+where
+    super::super::System::Com::IConnectionPointContainer_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IDiskQuotaControl_Impl>(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, breadwrite: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaControl_Impl::Initialize(this, core::mem::transmute(&pszpath), core::mem::transmute_copy(&breadwrite)).into()
+            }
+        }
+        unsafe extern "system" fn SetQuotaState<Identity: windows_core::IUnknownImpl + IDiskQuotaControl_Impl>(this: *mut core::ffi::c_void, dwstate: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaControl_Impl::SetQuotaState(this, core::mem::transmute_copy(&dwstate)).into()
+            }
+        }
+        unsafe extern "system" fn GetQuotaState<Identity: windows_core::IUnknownImpl + IDiskQuotaControl_Impl>(this: *mut core::ffi::c_void, pdwstate: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaControl_Impl::GetQuotaState(this, core::mem::transmute_copy(&pdwstate)).into()
+            }
+        }
+        unsafe extern "system" fn SetQuotaLogFlags<Identity: windows_core::IUnknownImpl + IDiskQuotaControl_Impl>(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaControl_Impl::SetQuotaLogFlags(this, core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn GetQuotaLogFlags<Identity: windows_core::IUnknownImpl + IDiskQuotaControl_Impl>(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaControl_Impl::GetQuotaLogFlags(this, core::mem::transmute_copy(&pdwflags)).into()
+            }
+        }
+        unsafe extern "system" fn SetDefaultQuotaThreshold<Identity: windows_core::IUnknownImpl + IDiskQuotaControl_Impl>(this: *mut core::ffi::c_void, llthreshold: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaControl_Impl::SetDefaultQuotaThreshold(this, core::mem::transmute_copy(&llthreshold)).into()
+            }
+        }
+        unsafe extern "system" fn GetDefaultQuotaThreshold<Identity: windows_core::IUnknownImpl + IDiskQuotaControl_Impl>(this: *mut core::ffi::c_void, pllthreshold: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaControl_Impl::GetDefaultQuotaThreshold(this, core::mem::transmute_copy(&pllthreshold)).into()
+            }
+        }
+        unsafe extern "system" fn GetDefaultQuotaThresholdText<Identity: windows_core::IUnknownImpl + IDiskQuotaControl_Impl>(this: *mut core::ffi::c_void, psztext: windows_core::PCWSTR, cchtext: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaControl_Impl::GetDefaultQuotaThresholdText(this, core::mem::transmute(&psztext), core::mem::transmute_copy(&cchtext)).into()
+            }
+        }
+        unsafe extern "system" fn SetDefaultQuotaLimit<Identity: windows_core::IUnknownImpl + IDiskQuotaControl_Impl>(this: *mut core::ffi::c_void, lllimit: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaControl_Impl::SetDefaultQuotaLimit(this, core::mem::transmute_copy(&lllimit)).into()
+            }
+        }
+        unsafe extern "system" fn GetDefaultQuotaLimit<Identity: windows_core::IUnknownImpl + IDiskQuotaControl_Impl>(this: *mut core::ffi::c_void, plllimit: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaControl_Impl::GetDefaultQuotaLimit(this, core::mem::transmute_copy(&plllimit)).into()
+            }
+        }
+        unsafe extern "system" fn GetDefaultQuotaLimitText<Identity: windows_core::IUnknownImpl + IDiskQuotaControl_Impl>(this: *mut core::ffi::c_void, psztext: windows_core::PCWSTR, cchtext: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaControl_Impl::GetDefaultQuotaLimitText(this, core::mem::transmute(&psztext), core::mem::transmute_copy(&cchtext)).into()
+            }
+        }
+        unsafe extern "system" fn AddUserSid<Identity: windows_core::IUnknownImpl + IDiskQuotaControl_Impl>(this: *mut core::ffi::c_void, pusersid: super::super::Security::PSID, fnameresolution: DISKQUOTA_USERNAME_RESOLVE, ppuser: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDiskQuotaControl_Impl::AddUserSid(this, core::mem::transmute_copy(&pusersid), core::mem::transmute_copy(&fnameresolution)) {
+                    Ok(ok__) => {
+                        ppuser.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddUserName<Identity: windows_core::IUnknownImpl + IDiskQuotaControl_Impl>(this: *mut core::ffi::c_void, pszlogonname: windows_core::PCWSTR, fnameresolution: DISKQUOTA_USERNAME_RESOLVE, ppuser: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDiskQuotaControl_Impl::AddUserName(this, core::mem::transmute(&pszlogonname), core::mem::transmute_copy(&fnameresolution)) {
+                    Ok(ok__) => {
+                        ppuser.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeleteUser<Identity: windows_core::IUnknownImpl + IDiskQuotaControl_Impl>(this: *mut core::ffi::c_void, puser: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaControl_Impl::DeleteUser(this, core::mem::transmute_copy(&puser)).into()
+            }
+        }
+        unsafe extern "system" fn FindUserSid<Identity: windows_core::IUnknownImpl + IDiskQuotaControl_Impl>(this: *mut core::ffi::c_void, pusersid: super::super::Security::PSID, fnameresolution: DISKQUOTA_USERNAME_RESOLVE, ppuser: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDiskQuotaControl_Impl::FindUserSid(this, core::mem::transmute_copy(&pusersid), core::mem::transmute_copy(&fnameresolution)) {
+                    Ok(ok__) => {
+                        ppuser.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindUserName<Identity: windows_core::IUnknownImpl + IDiskQuotaControl_Impl>(this: *mut core::ffi::c_void, pszlogonname: windows_core::PCWSTR, ppuser: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDiskQuotaControl_Impl::FindUserName(this, core::mem::transmute(&pszlogonname)) {
+                    Ok(ok__) => {
+                        ppuser.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateEnumUsers<Identity: windows_core::IUnknownImpl + IDiskQuotaControl_Impl>(this: *mut core::ffi::c_void, rgpusersids: *mut super::super::Security::PSID, cpsids: u32, fnameresolution: DISKQUOTA_USERNAME_RESOLVE, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaControl_Impl::CreateEnumUsers(this, core::mem::transmute_copy(&rgpusersids), core::mem::transmute_copy(&cpsids), core::mem::transmute_copy(&fnameresolution), core::mem::transmute_copy(&ppenum)).into()
+            }
+        }
+        unsafe extern "system" fn CreateUserBatch<Identity: windows_core::IUnknownImpl + IDiskQuotaControl_Impl>(this: *mut core::ffi::c_void, ppbatch: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDiskQuotaControl_Impl::CreateUserBatch(this) {
+                    Ok(ok__) => {
+                        ppbatch.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InvalidateSidNameCache<Identity: windows_core::IUnknownImpl + IDiskQuotaControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaControl_Impl::InvalidateSidNameCache(this).into()
+            }
+        }
+        unsafe extern "system" fn GiveUserNameResolutionPriority<Identity: windows_core::IUnknownImpl + IDiskQuotaControl_Impl>(this: *mut core::ffi::c_void, puser: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaControl_Impl::GiveUserNameResolutionPriority(this, core::mem::transmute_copy(&puser)).into()
+            }
+        }
+        unsafe extern "system" fn ShutdownNameResolution<Identity: windows_core::IUnknownImpl + IDiskQuotaControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaControl_Impl::ShutdownNameResolution(this).into()
+            }
+        }
+        Self {
+            base__: <super::super::System::Com::IConnectionPointContainer_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            SetQuotaState: SetQuotaState::<Identity>,
+            GetQuotaState: GetQuotaState::<Identity>,
+            SetQuotaLogFlags: SetQuotaLogFlags::<Identity>,
+            GetQuotaLogFlags: GetQuotaLogFlags::<Identity>,
+            SetDefaultQuotaThreshold: SetDefaultQuotaThreshold::<Identity>,
+            GetDefaultQuotaThreshold: GetDefaultQuotaThreshold::<Identity>,
+            GetDefaultQuotaThresholdText: GetDefaultQuotaThresholdText::<Identity>,
+            SetDefaultQuotaLimit: SetDefaultQuotaLimit::<Identity>,
+            GetDefaultQuotaLimit: GetDefaultQuotaLimit::<Identity>,
+            GetDefaultQuotaLimitText: GetDefaultQuotaLimitText::<Identity>,
+            AddUserSid: AddUserSid::<Identity>,
+            AddUserName: AddUserName::<Identity>,
+            DeleteUser: DeleteUser::<Identity>,
+            FindUserSid: FindUserSid::<Identity>,
+            FindUserName: FindUserName::<Identity>,
+            CreateEnumUsers: CreateEnumUsers::<Identity>,
+            CreateUserBatch: CreateUserBatch::<Identity>,
+            InvalidateSidNameCache: InvalidateSidNameCache::<Identity>,
+            GiveUserNameResolutionPriority: GiveUserNameResolutionPriority::<Identity>,
+            ShutdownNameResolution: ShutdownNameResolution::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Security", feature = "Win32_System_Com"))]
 impl windows_core::RuntimeName for IDiskQuotaControl {}
 windows_core::imp::define_interface!(IDiskQuotaEvents, IDiskQuotaEvents_Vtbl, 0x7988b579_ec89_11cf_9c00_00aa00a14f56);
 windows_core::imp::interface_hierarchy!(IDiskQuotaEvents, windows_core::IUnknown);
@@ -5256,6 +5444,20 @@ impl IDiskQuotaEvents_Vtbl {
         iid == &<IDiskQuotaEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDiskQuotaEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IDiskQuotaEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnUserNameChanged<Identity: windows_core::IUnknownImpl + IDiskQuotaEvents_Impl>(this: *mut core::ffi::c_void, puser: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaEvents_Impl::OnUserNameChanged(this, core::mem::transmute_copy(&puser)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnUserNameChanged: OnUserNameChanged::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDiskQuotaEvents {}
 windows_core::imp::define_interface!(IDiskQuotaUser, IDiskQuotaUser_Vtbl, 0x7988b574_ec89_11cf_9c00_00aa00a14f56);
 windows_core::imp::interface_hierarchy!(IDiskQuotaUser, windows_core::IUnknown);
@@ -5472,6 +5674,121 @@ impl IDiskQuotaUser_Vtbl {
         iid == &<IDiskQuotaUser as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDiskQuotaUser_Impl> windows_core::imp::TearOffVtable<Identity> for IDiskQuotaUser_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetID<Identity: windows_core::IUnknownImpl + IDiskQuotaUser_Impl>(this: *mut core::ffi::c_void, pulid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaUser_Impl::GetID(this, core::mem::transmute_copy(&pulid)).into()
+            }
+        }
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IDiskQuotaUser_Impl>(this: *mut core::ffi::c_void, pszaccountcontainer: windows_core::PCWSTR, cchaccountcontainer: u32, pszlogonname: windows_core::PCWSTR, cchlogonname: u32, pszdisplayname: windows_core::PCWSTR, cchdisplayname: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaUser_Impl::GetName(this, core::mem::transmute(&pszaccountcontainer), core::mem::transmute_copy(&cchaccountcontainer), core::mem::transmute(&pszlogonname), core::mem::transmute_copy(&cchlogonname), core::mem::transmute(&pszdisplayname), core::mem::transmute_copy(&cchdisplayname)).into()
+            }
+        }
+        unsafe extern "system" fn GetSidLength<Identity: windows_core::IUnknownImpl + IDiskQuotaUser_Impl>(this: *mut core::ffi::c_void, pdwlength: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaUser_Impl::GetSidLength(this, core::mem::transmute_copy(&pdwlength)).into()
+            }
+        }
+        unsafe extern "system" fn GetSid<Identity: windows_core::IUnknownImpl + IDiskQuotaUser_Impl>(this: *mut core::ffi::c_void, pbsidbuffer: *mut u8, cbsidbuffer: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaUser_Impl::GetSid(this, core::mem::transmute_copy(&pbsidbuffer), core::mem::transmute_copy(&cbsidbuffer)).into()
+            }
+        }
+        unsafe extern "system" fn GetQuotaThreshold<Identity: windows_core::IUnknownImpl + IDiskQuotaUser_Impl>(this: *mut core::ffi::c_void, pllthreshold: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaUser_Impl::GetQuotaThreshold(this, core::mem::transmute_copy(&pllthreshold)).into()
+            }
+        }
+        unsafe extern "system" fn GetQuotaThresholdText<Identity: windows_core::IUnknownImpl + IDiskQuotaUser_Impl>(this: *mut core::ffi::c_void, psztext: windows_core::PCWSTR, cchtext: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaUser_Impl::GetQuotaThresholdText(this, core::mem::transmute(&psztext), core::mem::transmute_copy(&cchtext)).into()
+            }
+        }
+        unsafe extern "system" fn GetQuotaLimit<Identity: windows_core::IUnknownImpl + IDiskQuotaUser_Impl>(this: *mut core::ffi::c_void, plllimit: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaUser_Impl::GetQuotaLimit(this, core::mem::transmute_copy(&plllimit)).into()
+            }
+        }
+        unsafe extern "system" fn GetQuotaLimitText<Identity: windows_core::IUnknownImpl + IDiskQuotaUser_Impl>(this: *mut core::ffi::c_void, psztext: windows_core::PCWSTR, cchtext: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaUser_Impl::GetQuotaLimitText(this, core::mem::transmute(&psztext), core::mem::transmute_copy(&cchtext)).into()
+            }
+        }
+        unsafe extern "system" fn GetQuotaUsed<Identity: windows_core::IUnknownImpl + IDiskQuotaUser_Impl>(this: *mut core::ffi::c_void, pllused: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaUser_Impl::GetQuotaUsed(this, core::mem::transmute_copy(&pllused)).into()
+            }
+        }
+        unsafe extern "system" fn GetQuotaUsedText<Identity: windows_core::IUnknownImpl + IDiskQuotaUser_Impl>(this: *mut core::ffi::c_void, psztext: windows_core::PCWSTR, cchtext: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaUser_Impl::GetQuotaUsedText(this, core::mem::transmute(&psztext), core::mem::transmute_copy(&cchtext)).into()
+            }
+        }
+        unsafe extern "system" fn GetQuotaInformation<Identity: windows_core::IUnknownImpl + IDiskQuotaUser_Impl>(this: *mut core::ffi::c_void, pbquotainfo: *mut core::ffi::c_void, cbquotainfo: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaUser_Impl::GetQuotaInformation(this, core::mem::transmute_copy(&pbquotainfo), core::mem::transmute_copy(&cbquotainfo)).into()
+            }
+        }
+        unsafe extern "system" fn SetQuotaThreshold<Identity: windows_core::IUnknownImpl + IDiskQuotaUser_Impl>(this: *mut core::ffi::c_void, llthreshold: i64, fwritethrough: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaUser_Impl::SetQuotaThreshold(this, core::mem::transmute_copy(&llthreshold), core::mem::transmute_copy(&fwritethrough)).into()
+            }
+        }
+        unsafe extern "system" fn SetQuotaLimit<Identity: windows_core::IUnknownImpl + IDiskQuotaUser_Impl>(this: *mut core::ffi::c_void, lllimit: i64, fwritethrough: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaUser_Impl::SetQuotaLimit(this, core::mem::transmute_copy(&lllimit), core::mem::transmute_copy(&fwritethrough)).into()
+            }
+        }
+        unsafe extern "system" fn Invalidate<Identity: windows_core::IUnknownImpl + IDiskQuotaUser_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaUser_Impl::Invalidate(this).into()
+            }
+        }
+        unsafe extern "system" fn GetAccountStatus<Identity: windows_core::IUnknownImpl + IDiskQuotaUser_Impl>(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaUser_Impl::GetAccountStatus(this, core::mem::transmute_copy(&pdwstatus)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetID: GetID::<Identity>,
+            GetName: GetName::<Identity>,
+            GetSidLength: GetSidLength::<Identity>,
+            GetSid: GetSid::<Identity>,
+            GetQuotaThreshold: GetQuotaThreshold::<Identity>,
+            GetQuotaThresholdText: GetQuotaThresholdText::<Identity>,
+            GetQuotaLimit: GetQuotaLimit::<Identity>,
+            GetQuotaLimitText: GetQuotaLimitText::<Identity>,
+            GetQuotaUsed: GetQuotaUsed::<Identity>,
+            GetQuotaUsedText: GetQuotaUsedText::<Identity>,
+            GetQuotaInformation: GetQuotaInformation::<Identity>,
+            SetQuotaThreshold: SetQuotaThreshold::<Identity>,
+            SetQuotaLimit: SetQuotaLimit::<Identity>,
+            Invalidate: Invalidate::<Identity>,
+            GetAccountStatus: GetAccountStatus::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDiskQuotaUser {}
 windows_core::imp::define_interface!(IDiskQuotaUserBatch, IDiskQuotaUserBatch_Vtbl, 0x7988b576_ec89_11cf_9c00_00aa00a14f56);
 windows_core::imp::interface_hierarchy!(IDiskQuotaUserBatch, windows_core::IUnknown);
@@ -5548,6 +5865,44 @@ impl IDiskQuotaUserBatch_Vtbl {
         iid == &<IDiskQuotaUserBatch as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDiskQuotaUserBatch_Impl> windows_core::imp::TearOffVtable<Identity> for IDiskQuotaUserBatch_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Add<Identity: windows_core::IUnknownImpl + IDiskQuotaUserBatch_Impl>(this: *mut core::ffi::c_void, puser: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaUserBatch_Impl::Add(this, core::mem::transmute_copy(&puser)).into()
+            }
+        }
+        unsafe extern "system" fn Remove<Identity: windows_core::IUnknownImpl + IDiskQuotaUserBatch_Impl>(this: *mut core::ffi::c_void, puser: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaUserBatch_Impl::Remove(this, core::mem::transmute_copy(&puser)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveAll<Identity: windows_core::IUnknownImpl + IDiskQuotaUserBatch_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaUserBatch_Impl::RemoveAll(this).into()
+            }
+        }
+        unsafe extern "system" fn FlushToDisk<Identity: windows_core::IUnknownImpl + IDiskQuotaUserBatch_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDiskQuotaUserBatch_Impl::FlushToDisk(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Add: Add::<Identity>,
+            Remove: Remove::<Identity>,
+            RemoveAll: RemoveAll::<Identity>,
+            FlushToDisk: FlushToDisk::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDiskQuotaUserBatch {}
 windows_core::imp::define_interface!(IEnumDiskQuotaUsers, IEnumDiskQuotaUsers_Vtbl, 0x7988b577_ec89_11cf_9c00_00aa00a14f56);
 windows_core::imp::interface_hierarchy!(IEnumDiskQuotaUsers, windows_core::IUnknown);
@@ -5627,6 +5982,50 @@ impl IEnumDiskQuotaUsers_Vtbl {
         iid == &<IEnumDiskQuotaUsers as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumDiskQuotaUsers_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumDiskQuotaUsers_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumDiskQuotaUsers_Impl>(this: *mut core::ffi::c_void, cusers: u32, rgusers: *mut *mut core::ffi::c_void, pcusersfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumDiskQuotaUsers_Impl::Next(this, core::mem::transmute_copy(&cusers), core::mem::transmute_copy(&rgusers), core::mem::transmute_copy(&pcusersfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumDiskQuotaUsers_Impl>(this: *mut core::ffi::c_void, cusers: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumDiskQuotaUsers_Impl::Skip(this, core::mem::transmute_copy(&cusers)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumDiskQuotaUsers_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumDiskQuotaUsers_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumDiskQuotaUsers_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumDiskQuotaUsers_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumDiskQuotaUsers {}
 pub const INVALID_FILE_ATTRIBUTES: u32 = 4294967295u32;
 pub const INVALID_FILE_SIZE: u32 = 4294967295u32;
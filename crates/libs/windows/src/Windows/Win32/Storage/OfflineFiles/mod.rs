@@ -96,6 +96,50 @@ impl IEnumOfflineFilesItems_Vtbl {
         iid == &<IEnumOfflineFilesItems as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumOfflineFilesItems_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumOfflineFilesItems_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumOfflineFilesItems_Impl>(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumOfflineFilesItems_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgelt), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumOfflineFilesItems_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumOfflineFilesItems_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumOfflineFilesItems_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumOfflineFilesItems_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumOfflineFilesItems_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumOfflineFilesItems_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumOfflineFilesItems {}
 windows_core::imp::define_interface!(IEnumOfflineFilesSettings, IEnumOfflineFilesSettings_Vtbl, 0x729680c4_1a38_47bc_9e5c_02c51562ac30);
 windows_core::imp::interface_hierarchy!(IEnumOfflineFilesSettings, windows_core::IUnknown);
@@ -175,6 +219,50 @@ impl IEnumOfflineFilesSettings_Vtbl {
         iid == &<IEnumOfflineFilesSettings as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumOfflineFilesSettings_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumOfflineFilesSettings_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumOfflineFilesSettings_Impl>(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumOfflineFilesSettings_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgelt), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumOfflineFilesSettings_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumOfflineFilesSettings_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumOfflineFilesSettings_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumOfflineFilesSettings_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumOfflineFilesSettings_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumOfflineFilesSettings_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumOfflineFilesSettings {}
 windows_core::imp::define_interface!(IOfflineFilesCache, IOfflineFilesCache_Vtbl, 0x855d6203_7914_48b9_8d40_4c56f5acffc5);
 windows_core::imp::interface_hierarchy!(IOfflineFilesCache, windows_core::IUnknown);
@@ -490,6 +578,165 @@ impl IOfflineFilesCache_Vtbl {
         iid == &<IOfflineFilesCache as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesCache_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesCache_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Synchronize<Identity: windows_core::IUnknownImpl + IOfflineFilesCache_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, rgpszpaths: *const windows_core::PCWSTR, cpaths: u32, basync: windows_core::BOOL, dwsynccontrol: u32, pisyncconflicthandler: *mut core::ffi::c_void, piprogress: *mut core::ffi::c_void, psyncid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesCache_Impl::Synchronize(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&rgpszpaths), core::mem::transmute_copy(&cpaths), core::mem::transmute_copy(&basync), core::mem::transmute_copy(&dwsynccontrol), core::mem::transmute_copy(&pisyncconflicthandler), core::mem::transmute_copy(&piprogress), core::mem::transmute_copy(&psyncid)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteItems<Identity: windows_core::IUnknownImpl + IOfflineFilesCache_Impl>(this: *mut core::ffi::c_void, rgpszpaths: *const windows_core::PCWSTR, cpaths: u32, dwflags: u32, basync: windows_core::BOOL, piprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesCache_Impl::DeleteItems(this, core::mem::transmute_copy(&rgpszpaths), core::mem::transmute_copy(&cpaths), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&basync), core::mem::transmute_copy(&piprogress)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteItemsForUser<Identity: windows_core::IUnknownImpl + IOfflineFilesCache_Impl>(this: *mut core::ffi::c_void, pszuser: windows_core::PCWSTR, rgpszpaths: *const windows_core::PCWSTR, cpaths: u32, dwflags: u32, basync: windows_core::BOOL, piprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesCache_Impl::DeleteItemsForUser(this, core::mem::transmute(&pszuser), core::mem::transmute_copy(&rgpszpaths), core::mem::transmute_copy(&cpaths), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&basync), core::mem::transmute_copy(&piprogress)).into()
+            }
+        }
+        unsafe extern "system" fn Pin<Identity: windows_core::IUnknownImpl + IOfflineFilesCache_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, rgpszpaths: *const windows_core::PCWSTR, cpaths: u32, bdeep: windows_core::BOOL, basync: windows_core::BOOL, dwpincontrolflags: u32, piprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesCache_Impl::Pin(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&rgpszpaths), core::mem::transmute_copy(&cpaths), core::mem::transmute_copy(&bdeep), core::mem::transmute_copy(&basync), core::mem::transmute_copy(&dwpincontrolflags), core::mem::transmute_copy(&piprogress)).into()
+            }
+        }
+        unsafe extern "system" fn Unpin<Identity: windows_core::IUnknownImpl + IOfflineFilesCache_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, rgpszpaths: *const windows_core::PCWSTR, cpaths: u32, bdeep: windows_core::BOOL, basync: windows_core::BOOL, dwpincontrolflags: u32, piprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesCache_Impl::Unpin(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&rgpszpaths), core::mem::transmute_copy(&cpaths), core::mem::transmute_copy(&bdeep), core::mem::transmute_copy(&basync), core::mem::transmute_copy(&dwpincontrolflags), core::mem::transmute_copy(&piprogress)).into()
+            }
+        }
+        unsafe extern "system" fn GetEncryptionStatus<Identity: windows_core::IUnknownImpl + IOfflineFilesCache_Impl>(this: *mut core::ffi::c_void, pbencrypted: *mut windows_core::BOOL, pbpartial: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesCache_Impl::GetEncryptionStatus(this, core::mem::transmute_copy(&pbencrypted), core::mem::transmute_copy(&pbpartial)).into()
+            }
+        }
+        unsafe extern "system" fn Encrypt<Identity: windows_core::IUnknownImpl + IOfflineFilesCache_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, bencrypt: windows_core::BOOL, dwencryptioncontrolflags: u32, basync: windows_core::BOOL, piprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesCache_Impl::Encrypt(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&bencrypt), core::mem::transmute_copy(&dwencryptioncontrolflags), core::mem::transmute_copy(&basync), core::mem::transmute_copy(&piprogress)).into()
+            }
+        }
+        unsafe extern "system" fn FindItem<Identity: windows_core::IUnknownImpl + IOfflineFilesCache_Impl>(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, dwqueryflags: u32, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesCache_Impl::FindItem(this, core::mem::transmute(&pszpath), core::mem::transmute_copy(&dwqueryflags)) {
+                    Ok(ok__) => {
+                        ppitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindItemEx<Identity: windows_core::IUnknownImpl + IOfflineFilesCache_Impl>(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, pincludefilefilter: *mut core::ffi::c_void, pincludedirfilter: *mut core::ffi::c_void, pexcludefilefilter: *mut core::ffi::c_void, pexcludedirfilter: *mut core::ffi::c_void, dwqueryflags: u32, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesCache_Impl::FindItemEx(this, core::mem::transmute(&pszpath), core::mem::transmute_copy(&pincludefilefilter), core::mem::transmute_copy(&pincludedirfilter), core::mem::transmute_copy(&pexcludefilefilter), core::mem::transmute_copy(&pexcludedirfilter), core::mem::transmute_copy(&dwqueryflags)) {
+                    Ok(ok__) => {
+                        ppitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RenameItem<Identity: windows_core::IUnknownImpl + IOfflineFilesCache_Impl>(this: *mut core::ffi::c_void, pszpathoriginal: windows_core::PCWSTR, pszpathnew: windows_core::PCWSTR, breplaceifexists: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesCache_Impl::RenameItem(this, core::mem::transmute(&pszpathoriginal), core::mem::transmute(&pszpathnew), core::mem::transmute_copy(&breplaceifexists)).into()
+            }
+        }
+        unsafe extern "system" fn GetLocation<Identity: windows_core::IUnknownImpl + IOfflineFilesCache_Impl>(this: *mut core::ffi::c_void, ppszpath: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesCache_Impl::GetLocation(this) {
+                    Ok(ok__) => {
+                        ppszpath.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDiskSpaceInformation<Identity: windows_core::IUnknownImpl + IOfflineFilesCache_Impl>(this: *mut core::ffi::c_void, pcbvolumetotal: *mut u64, pcblimit: *mut u64, pcbused: *mut u64, pcbunpinnedlimit: *mut u64, pcbunpinnedused: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesCache_Impl::GetDiskSpaceInformation(this, core::mem::transmute_copy(&pcbvolumetotal), core::mem::transmute_copy(&pcblimit), core::mem::transmute_copy(&pcbused), core::mem::transmute_copy(&pcbunpinnedlimit), core::mem::transmute_copy(&pcbunpinnedused)).into()
+            }
+        }
+        unsafe extern "system" fn SetDiskSpaceLimits<Identity: windows_core::IUnknownImpl + IOfflineFilesCache_Impl>(this: *mut core::ffi::c_void, cblimit: u64, cbunpinnedlimit: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesCache_Impl::SetDiskSpaceLimits(this, core::mem::transmute_copy(&cblimit), core::mem::transmute_copy(&cbunpinnedlimit)).into()
+            }
+        }
+        unsafe extern "system" fn ProcessAdminPinPolicy<Identity: windows_core::IUnknownImpl + IOfflineFilesCache_Impl>(this: *mut core::ffi::c_void, ppinprogress: *mut core::ffi::c_void, punpinprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesCache_Impl::ProcessAdminPinPolicy(this, core::mem::transmute_copy(&ppinprogress), core::mem::transmute_copy(&punpinprogress)).into()
+            }
+        }
+        unsafe extern "system" fn GetSettingObject<Identity: windows_core::IUnknownImpl + IOfflineFilesCache_Impl>(this: *mut core::ffi::c_void, pszsettingname: windows_core::PCWSTR, ppsetting: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesCache_Impl::GetSettingObject(this, core::mem::transmute(&pszsettingname)) {
+                    Ok(ok__) => {
+                        ppsetting.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumSettingObjects<Identity: windows_core::IUnknownImpl + IOfflineFilesCache_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesCache_Impl::EnumSettingObjects(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsPathCacheable<Identity: windows_core::IUnknownImpl + IOfflineFilesCache_Impl>(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, pbcacheable: *mut windows_core::BOOL, psharecachingmode: *mut OFFLINEFILES_CACHING_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesCache_Impl::IsPathCacheable(this, core::mem::transmute(&pszpath), core::mem::transmute_copy(&pbcacheable), core::mem::transmute_copy(&psharecachingmode)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Synchronize: Synchronize::<Identity>,
+            DeleteItems: DeleteItems::<Identity>,
+            DeleteItemsForUser: DeleteItemsForUser::<Identity>,
+            Pin: Pin::<Identity>,
+            Unpin: Unpin::<Identity>,
+            GetEncryptionStatus: GetEncryptionStatus::<Identity>,
+            Encrypt: Encrypt::<Identity>,
+            FindItem: FindItem::<Identity>,
+            FindItemEx: FindItemEx::<Identity>,
+            RenameItem: RenameItem::<Identity>,
+            GetLocation: GetLocation::<Identity>,
+            GetDiskSpaceInformation: GetDiskSpaceInformation::<Identity>,
+            SetDiskSpaceLimits: SetDiskSpaceLimits::<Identity>,
+            ProcessAdminPinPolicy: ProcessAdminPinPolicy::<Identity>,
+            GetSettingObject: GetSettingObject::<Identity>,
+            EnumSettingObjects: EnumSettingObjects::<Identity>,
+            IsPathCacheable: IsPathCacheable::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesCache {}
 windows_core::imp::define_interface!(IOfflineFilesCache2, IOfflineFilesCache2_Vtbl, 0x8c075039_1551_4ed9_8781_56705c04d3c0);
 impl core::ops::Deref for IOfflineFilesCache2 {
@@ -531,6 +778,20 @@ impl IOfflineFilesCache2_Vtbl {
         iid == &<IOfflineFilesCache2 as windows_core::Interface>::IID || iid == &<IOfflineFilesCache as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesCache2_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesCache2_Vtbl
+where
+    IOfflineFilesCache_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RenameItemEx<Identity: windows_core::IUnknownImpl + IOfflineFilesCache2_Impl>(this: *mut core::ffi::c_void, pszpathoriginal: windows_core::PCWSTR, pszpathnew: windows_core::PCWSTR, breplaceifexists: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesCache2_Impl::RenameItemEx(this, core::mem::transmute(&pszpathoriginal), core::mem::transmute(&pszpathnew), core::mem::transmute_copy(&breplaceifexists)).into()
+            }
+        }
+        Self { base__: <IOfflineFilesCache_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, RenameItemEx: RenameItemEx::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesCache2 {}
 windows_core::imp::define_interface!(IOfflineFilesChangeInfo, IOfflineFilesChangeInfo_Vtbl, 0xa96e6fa4_e0d1_4c29_960b_ee508fe68c72);
 windows_core::imp::interface_hierarchy!(IOfflineFilesChangeInfo, windows_core::IUnknown);
@@ -670,6 +931,88 @@ impl IOfflineFilesChangeInfo_Vtbl {
         iid == &<IOfflineFilesChangeInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesChangeInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesChangeInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsDirty<Identity: windows_core::IUnknownImpl + IOfflineFilesChangeInfo_Impl>(this: *mut core::ffi::c_void, pbdirty: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesChangeInfo_Impl::IsDirty(this, core::mem::transmute_copy(&pbdirty))
+            }
+        }
+        unsafe extern "system" fn IsDeletedOffline<Identity: windows_core::IUnknownImpl + IOfflineFilesChangeInfo_Impl>(this: *mut core::ffi::c_void, pbdeletedoffline: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesChangeInfo_Impl::IsDeletedOffline(this) {
+                    Ok(ok__) => {
+                        pbdeletedoffline.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsCreatedOffline<Identity: windows_core::IUnknownImpl + IOfflineFilesChangeInfo_Impl>(this: *mut core::ffi::c_void, pbcreatedoffline: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesChangeInfo_Impl::IsCreatedOffline(this) {
+                    Ok(ok__) => {
+                        pbcreatedoffline.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsLocallyModifiedData<Identity: windows_core::IUnknownImpl + IOfflineFilesChangeInfo_Impl>(this: *mut core::ffi::c_void, pblocallymodifieddata: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesChangeInfo_Impl::IsLocallyModifiedData(this) {
+                    Ok(ok__) => {
+                        pblocallymodifieddata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsLocallyModifiedAttributes<Identity: windows_core::IUnknownImpl + IOfflineFilesChangeInfo_Impl>(this: *mut core::ffi::c_void, pblocallymodifiedattributes: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesChangeInfo_Impl::IsLocallyModifiedAttributes(this) {
+                    Ok(ok__) => {
+                        pblocallymodifiedattributes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsLocallyModifiedTime<Identity: windows_core::IUnknownImpl + IOfflineFilesChangeInfo_Impl>(this: *mut core::ffi::c_void, pblocallymodifiedtime: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesChangeInfo_Impl::IsLocallyModifiedTime(this) {
+                    Ok(ok__) => {
+                        pblocallymodifiedtime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsDirty: IsDirty::<Identity>,
+            IsDeletedOffline: IsDeletedOffline::<Identity>,
+            IsCreatedOffline: IsCreatedOffline::<Identity>,
+            IsLocallyModifiedData: IsLocallyModifiedData::<Identity>,
+            IsLocallyModifiedAttributes: IsLocallyModifiedAttributes::<Identity>,
+            IsLocallyModifiedTime: IsLocallyModifiedTime::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesChangeInfo {}
 windows_core::imp::define_interface!(IOfflineFilesConnectionInfo, IOfflineFilesConnectionInfo_Vtbl, 0xefb23a09_a867_4be8_83a6_86969a7d0856);
 windows_core::imp::interface_hierarchy!(IOfflineFilesConnectionInfo, windows_core::IUnknown);
@@ -749,6 +1092,50 @@ impl IOfflineFilesConnectionInfo_Vtbl {
         iid == &<IOfflineFilesConnectionInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesConnectionInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesConnectionInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetConnectState<Identity: windows_core::IUnknownImpl + IOfflineFilesConnectionInfo_Impl>(this: *mut core::ffi::c_void, pconnectstate: *mut OFFLINEFILES_CONNECT_STATE, pofflinereason: *mut OFFLINEFILES_OFFLINE_REASON) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesConnectionInfo_Impl::GetConnectState(this, core::mem::transmute_copy(&pconnectstate), core::mem::transmute_copy(&pofflinereason)).into()
+            }
+        }
+        unsafe extern "system" fn SetConnectState<Identity: windows_core::IUnknownImpl + IOfflineFilesConnectionInfo_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, dwflags: u32, connectstate: OFFLINEFILES_CONNECT_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesConnectionInfo_Impl::SetConnectState(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&connectstate)).into()
+            }
+        }
+        unsafe extern "system" fn TransitionOnline<Identity: windows_core::IUnknownImpl + IOfflineFilesConnectionInfo_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesConnectionInfo_Impl::TransitionOnline(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn TransitionOffline<Identity: windows_core::IUnknownImpl + IOfflineFilesConnectionInfo_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, dwflags: u32, bforceopenfilesclosed: windows_core::BOOL, pbopenfilespreventedtransition: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesConnectionInfo_Impl::TransitionOffline(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&bforceopenfilesclosed)) {
+                    Ok(ok__) => {
+                        pbopenfilespreventedtransition.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetConnectState: GetConnectState::<Identity>,
+            SetConnectState: SetConnectState::<Identity>,
+            TransitionOnline: TransitionOnline::<Identity>,
+            TransitionOffline: TransitionOffline::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesConnectionInfo {}
 windows_core::imp::define_interface!(IOfflineFilesDirectoryItem, IOfflineFilesDirectoryItem_Vtbl, 0x2273597a_a08c_4a00_a37a_c1ae4e9a1cfd);
 impl core::ops::Deref for IOfflineFilesDirectoryItem {
@@ -772,6 +1159,12 @@ impl IOfflineFilesDirectoryItem_Vtbl {
         iid == &<IOfflineFilesDirectoryItem as windows_core::Interface>::IID || iid == &<IOfflineFilesItem as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesDirectoryItem_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesDirectoryItem_Vtbl
+where
+    IOfflineFilesItem_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IOfflineFilesItem_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IOfflineFilesDirectoryItem {}
 windows_core::imp::define_interface!(IOfflineFilesDirtyInfo, IOfflineFilesDirtyInfo_Vtbl, 0x0f50ce33_bac9_4eaa_a11d_da0e527d047d);
 windows_core::imp::interface_hierarchy!(IOfflineFilesDirtyInfo, windows_core::IUnknown);
@@ -836,17 +1229,53 @@ impl IOfflineFilesDirtyInfo_Vtbl {
         iid == &<IOfflineFilesDirtyInfo as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IOfflineFilesDirtyInfo {}
-windows_core::imp::define_interface!(IOfflineFilesErrorInfo, IOfflineFilesErrorInfo_Vtbl, 0x7112fa5f_7571_435a_8eb7_195c7c1429bc);
-windows_core::imp::interface_hierarchy!(IOfflineFilesErrorInfo, windows_core::IUnknown);
-impl IOfflineFilesErrorInfo {
-    #[cfg(feature = "Win32_System_Com")]
-    pub unsafe fn GetRawData(&self) -> windows_core::Result<*mut super::super::System::Com::BYTE_BLOB> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetRawData)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesDirtyInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesDirtyInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn LocalDirtyByteCount<Identity: windows_core::IUnknownImpl + IOfflineFilesDirtyInfo_Impl>(this: *mut core::ffi::c_void, pdirtybytecount: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesDirtyInfo_Impl::LocalDirtyByteCount(this) {
+                    Ok(ok__) => {
+                        pdirtybytecount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
+        unsafe extern "system" fn RemoteDirtyByteCount<Identity: windows_core::IUnknownImpl + IOfflineFilesDirtyInfo_Impl>(this: *mut core::ffi::c_void, pdirtybytecount: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesDirtyInfo_Impl::RemoteDirtyByteCount(this) {
+                    Ok(ok__) => {
+                        pdirtybytecount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            LocalDirtyByteCount: LocalDirtyByteCount::<Identity>,
+            RemoteDirtyByteCount: RemoteDirtyByteCount::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IOfflineFilesDirtyInfo {}
+windows_core::imp::define_interface!(IOfflineFilesErrorInfo, IOfflineFilesErrorInfo_Vtbl, 0x7112fa5f_7571_435a_8eb7_195c7c1429bc);
+windows_core::imp::interface_hierarchy!(IOfflineFilesErrorInfo, windows_core::IUnknown);
+impl IOfflineFilesErrorInfo {
+    #[cfg(feature = "Win32_System_Com")]
+    pub unsafe fn GetRawData(&self) -> windows_core::Result<*mut super::super::System::Com::BYTE_BLOB> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetRawData)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
     pub unsafe fn GetDescription(&self) -> windows_core::Result<windows_core::PWSTR> {
         unsafe {
             let mut result__ = core::mem::zeroed();
@@ -907,6 +1336,43 @@ impl IOfflineFilesErrorInfo_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesErrorInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesErrorInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRawData<Identity: windows_core::IUnknownImpl + IOfflineFilesErrorInfo_Impl>(this: *mut core::ffi::c_void, ppblob: *mut *mut super::super::System::Com::BYTE_BLOB) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesErrorInfo_Impl::GetRawData(this) {
+                    Ok(ok__) => {
+                        ppblob.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDescription<Identity: windows_core::IUnknownImpl + IOfflineFilesErrorInfo_Impl>(this: *mut core::ffi::c_void, ppszdescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesErrorInfo_Impl::GetDescription(this) {
+                    Ok(ok__) => {
+                        ppszdescription.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetRawData: GetRawData::<Identity>,
+            GetDescription: GetDescription::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOfflineFilesErrorInfo {}
 windows_core::imp::define_interface!(IOfflineFilesEvents, IOfflineFilesEvents_Vtbl, 0xe25585c1_0caa_4eb1_873b_1cae5b77c314);
 windows_core::imp::interface_hierarchy!(IOfflineFilesEvents, windows_core::IUnknown);
@@ -1276,6 +1742,191 @@ impl IOfflineFilesEvents_Vtbl {
         iid == &<IOfflineFilesEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CacheMoved<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void, pszoldpath: windows_core::PCWSTR, psznewpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::CacheMoved(this, core::mem::transmute(&pszoldpath), core::mem::transmute(&psznewpath)).into()
+            }
+        }
+        unsafe extern "system" fn CacheIsFull<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::CacheIsFull(this).into()
+            }
+        }
+        unsafe extern "system" fn CacheIsCorrupted<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::CacheIsCorrupted(this).into()
+            }
+        }
+        unsafe extern "system" fn Enabled<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void, benabled: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::Enabled(this, core::mem::transmute_copy(&benabled)).into()
+            }
+        }
+        unsafe extern "system" fn EncryptionChanged<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void, bwasencrypted: windows_core::BOOL, bwaspartial: windows_core::BOOL, bisencrypted: windows_core::BOOL, bispartial: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::EncryptionChanged(this, core::mem::transmute_copy(&bwasencrypted), core::mem::transmute_copy(&bwaspartial), core::mem::transmute_copy(&bisencrypted), core::mem::transmute_copy(&bispartial)).into()
+            }
+        }
+        unsafe extern "system" fn SyncBegin<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void, rsyncid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::SyncBegin(this, core::mem::transmute_copy(&rsyncid)).into()
+            }
+        }
+        unsafe extern "system" fn SyncFileResult<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void, rsyncid: *const windows_core::GUID, pszfile: windows_core::PCWSTR, hrresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::SyncFileResult(this, core::mem::transmute_copy(&rsyncid), core::mem::transmute(&pszfile), core::mem::transmute_copy(&hrresult)).into()
+            }
+        }
+        unsafe extern "system" fn SyncConflictRecAdded<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void, pszconflictpath: windows_core::PCWSTR, pftconflictdatetime: *const super::super::Foundation::FILETIME, conflictsyncstate: OFFLINEFILES_SYNC_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::SyncConflictRecAdded(this, core::mem::transmute(&pszconflictpath), core::mem::transmute_copy(&pftconflictdatetime), core::mem::transmute_copy(&conflictsyncstate)).into()
+            }
+        }
+        unsafe extern "system" fn SyncConflictRecUpdated<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void, pszconflictpath: windows_core::PCWSTR, pftconflictdatetime: *const super::super::Foundation::FILETIME, conflictsyncstate: OFFLINEFILES_SYNC_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::SyncConflictRecUpdated(this, core::mem::transmute(&pszconflictpath), core::mem::transmute_copy(&pftconflictdatetime), core::mem::transmute_copy(&conflictsyncstate)).into()
+            }
+        }
+        unsafe extern "system" fn SyncConflictRecRemoved<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void, pszconflictpath: windows_core::PCWSTR, pftconflictdatetime: *const super::super::Foundation::FILETIME, conflictsyncstate: OFFLINEFILES_SYNC_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::SyncConflictRecRemoved(this, core::mem::transmute(&pszconflictpath), core::mem::transmute_copy(&pftconflictdatetime), core::mem::transmute_copy(&conflictsyncstate)).into()
+            }
+        }
+        unsafe extern "system" fn SyncEnd<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void, rsyncid: *const windows_core::GUID, hrresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::SyncEnd(this, core::mem::transmute_copy(&rsyncid), core::mem::transmute_copy(&hrresult)).into()
+            }
+        }
+        unsafe extern "system" fn NetTransportArrived<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::NetTransportArrived(this).into()
+            }
+        }
+        unsafe extern "system" fn NoNetTransports<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::NoNetTransports(this).into()
+            }
+        }
+        unsafe extern "system" fn ItemDisconnected<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, itemtype: OFFLINEFILES_ITEM_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::ItemDisconnected(this, core::mem::transmute(&pszpath), core::mem::transmute_copy(&itemtype)).into()
+            }
+        }
+        unsafe extern "system" fn ItemReconnected<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, itemtype: OFFLINEFILES_ITEM_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::ItemReconnected(this, core::mem::transmute(&pszpath), core::mem::transmute_copy(&itemtype)).into()
+            }
+        }
+        unsafe extern "system" fn ItemAvailableOffline<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, itemtype: OFFLINEFILES_ITEM_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::ItemAvailableOffline(this, core::mem::transmute(&pszpath), core::mem::transmute_copy(&itemtype)).into()
+            }
+        }
+        unsafe extern "system" fn ItemNotAvailableOffline<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, itemtype: OFFLINEFILES_ITEM_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::ItemNotAvailableOffline(this, core::mem::transmute(&pszpath), core::mem::transmute_copy(&itemtype)).into()
+            }
+        }
+        unsafe extern "system" fn ItemPinned<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, itemtype: OFFLINEFILES_ITEM_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::ItemPinned(this, core::mem::transmute(&pszpath), core::mem::transmute_copy(&itemtype)).into()
+            }
+        }
+        unsafe extern "system" fn ItemNotPinned<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, itemtype: OFFLINEFILES_ITEM_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::ItemNotPinned(this, core::mem::transmute(&pszpath), core::mem::transmute_copy(&itemtype)).into()
+            }
+        }
+        unsafe extern "system" fn ItemModified<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, itemtype: OFFLINEFILES_ITEM_TYPE, bmodifieddata: windows_core::BOOL, bmodifiedattributes: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::ItemModified(this, core::mem::transmute(&pszpath), core::mem::transmute_copy(&itemtype), core::mem::transmute_copy(&bmodifieddata), core::mem::transmute_copy(&bmodifiedattributes)).into()
+            }
+        }
+        unsafe extern "system" fn ItemAddedToCache<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, itemtype: OFFLINEFILES_ITEM_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::ItemAddedToCache(this, core::mem::transmute(&pszpath), core::mem::transmute_copy(&itemtype)).into()
+            }
+        }
+        unsafe extern "system" fn ItemDeletedFromCache<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, itemtype: OFFLINEFILES_ITEM_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::ItemDeletedFromCache(this, core::mem::transmute(&pszpath), core::mem::transmute_copy(&itemtype)).into()
+            }
+        }
+        unsafe extern "system" fn ItemRenamed<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void, pszoldpath: windows_core::PCWSTR, psznewpath: windows_core::PCWSTR, itemtype: OFFLINEFILES_ITEM_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::ItemRenamed(this, core::mem::transmute(&pszoldpath), core::mem::transmute(&psznewpath), core::mem::transmute_copy(&itemtype)).into()
+            }
+        }
+        unsafe extern "system" fn DataLost<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::DataLost(this).into()
+            }
+        }
+        unsafe extern "system" fn Ping<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents_Impl::Ping(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CacheMoved: CacheMoved::<Identity>,
+            CacheIsFull: CacheIsFull::<Identity>,
+            CacheIsCorrupted: CacheIsCorrupted::<Identity>,
+            Enabled: Enabled::<Identity>,
+            EncryptionChanged: EncryptionChanged::<Identity>,
+            SyncBegin: SyncBegin::<Identity>,
+            SyncFileResult: SyncFileResult::<Identity>,
+            SyncConflictRecAdded: SyncConflictRecAdded::<Identity>,
+            SyncConflictRecUpdated: SyncConflictRecUpdated::<Identity>,
+            SyncConflictRecRemoved: SyncConflictRecRemoved::<Identity>,
+            SyncEnd: SyncEnd::<Identity>,
+            NetTransportArrived: NetTransportArrived::<Identity>,
+            NoNetTransports: NoNetTransports::<Identity>,
+            ItemDisconnected: ItemDisconnected::<Identity>,
+            ItemReconnected: ItemReconnected::<Identity>,
+            ItemAvailableOffline: ItemAvailableOffline::<Identity>,
+            ItemNotAvailableOffline: ItemNotAvailableOffline::<Identity>,
+            ItemPinned: ItemPinned::<Identity>,
+            ItemNotPinned: ItemNotPinned::<Identity>,
+            ItemModified: ItemModified::<Identity>,
+            ItemAddedToCache: ItemAddedToCache::<Identity>,
+            ItemDeletedFromCache: ItemDeletedFromCache::<Identity>,
+            ItemRenamed: ItemRenamed::<Identity>,
+            DataLost: DataLost::<Identity>,
+            Ping: Ping::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesEvents {}
 windows_core::imp::define_interface!(IOfflineFilesEvents2, IOfflineFilesEvents2_Vtbl, 0x1ead8f56_ff76_4faa_a795_6f6ef792498b);
 impl core::ops::Deref for IOfflineFilesEvents2 {
@@ -1412,6 +2063,79 @@ impl IOfflineFilesEvents2_Vtbl {
         iid == &<IOfflineFilesEvents2 as windows_core::Interface>::IID || iid == &<IOfflineFilesEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents2_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesEvents2_Vtbl
+where
+    IOfflineFilesEvents_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ItemReconnectBegin<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents2_Impl::ItemReconnectBegin(this).into()
+            }
+        }
+        unsafe extern "system" fn ItemReconnectEnd<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents2_Impl::ItemReconnectEnd(this).into()
+            }
+        }
+        unsafe extern "system" fn CacheEvictBegin<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents2_Impl::CacheEvictBegin(this).into()
+            }
+        }
+        unsafe extern "system" fn CacheEvictEnd<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents2_Impl::CacheEvictEnd(this).into()
+            }
+        }
+        unsafe extern "system" fn BackgroundSyncBegin<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents2_Impl>(this: *mut core::ffi::c_void, dwsynccontrolflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents2_Impl::BackgroundSyncBegin(this, core::mem::transmute_copy(&dwsynccontrolflags)).into()
+            }
+        }
+        unsafe extern "system" fn BackgroundSyncEnd<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents2_Impl>(this: *mut core::ffi::c_void, dwsynccontrolflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents2_Impl::BackgroundSyncEnd(this, core::mem::transmute_copy(&dwsynccontrolflags)).into()
+            }
+        }
+        unsafe extern "system" fn PolicyChangeDetected<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents2_Impl::PolicyChangeDetected(this).into()
+            }
+        }
+        unsafe extern "system" fn PreferenceChangeDetected<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents2_Impl::PreferenceChangeDetected(this).into()
+            }
+        }
+        unsafe extern "system" fn SettingsChangesApplied<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents2_Impl::SettingsChangesApplied(this).into()
+            }
+        }
+        Self {
+            base__: <IOfflineFilesEvents_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ItemReconnectBegin: ItemReconnectBegin::<Identity>,
+            ItemReconnectEnd: ItemReconnectEnd::<Identity>,
+            CacheEvictBegin: CacheEvictBegin::<Identity>,
+            CacheEvictEnd: CacheEvictEnd::<Identity>,
+            BackgroundSyncBegin: BackgroundSyncBegin::<Identity>,
+            BackgroundSyncEnd: BackgroundSyncEnd::<Identity>,
+            PolicyChangeDetected: PolicyChangeDetected::<Identity>,
+            PreferenceChangeDetected: PreferenceChangeDetected::<Identity>,
+            SettingsChangesApplied: SettingsChangesApplied::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesEvents2 {}
 windows_core::imp::define_interface!(IOfflineFilesEvents3, IOfflineFilesEvents3_Vtbl, 0x9ba04a45_ee69_42f0_9ab1_7db5c8805808);
 impl core::ops::Deref for IOfflineFilesEvents3 {
@@ -1486,6 +2210,37 @@ impl IOfflineFilesEvents3_Vtbl {
         iid == &<IOfflineFilesEvents3 as windows_core::Interface>::IID || iid == &<IOfflineFilesEvents as windows_core::Interface>::IID || iid == &<IOfflineFilesEvents2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents3_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesEvents3_Vtbl
+where
+    IOfflineFilesEvents2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn TransparentCacheItemNotify<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents3_Impl>(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, eventtype: OFFLINEFILES_EVENTS, itemtype: OFFLINEFILES_ITEM_TYPE, bmodifieddata: windows_core::BOOL, bmodifiedattributes: windows_core::BOOL, pzsoldpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents3_Impl::TransparentCacheItemNotify(this, core::mem::transmute(&pszpath), core::mem::transmute_copy(&eventtype), core::mem::transmute_copy(&itemtype), core::mem::transmute_copy(&bmodifieddata), core::mem::transmute_copy(&bmodifiedattributes), core::mem::transmute(&pzsoldpath)).into()
+            }
+        }
+        unsafe extern "system" fn PrefetchFileBegin<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents3_Impl>(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents3_Impl::PrefetchFileBegin(this, core::mem::transmute(&pszpath)).into()
+            }
+        }
+        unsafe extern "system" fn PrefetchFileEnd<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents3_Impl>(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, hrresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents3_Impl::PrefetchFileEnd(this, core::mem::transmute(&pszpath), core::mem::transmute_copy(&hrresult)).into()
+            }
+        }
+        Self {
+            base__: <IOfflineFilesEvents2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            TransparentCacheItemNotify: TransparentCacheItemNotify::<Identity>,
+            PrefetchFileBegin: PrefetchFileBegin::<Identity>,
+            PrefetchFileEnd: PrefetchFileEnd::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesEvents3 {}
 windows_core::imp::define_interface!(IOfflineFilesEvents4, IOfflineFilesEvents4_Vtbl, 0xdbd69b1e_c7d2_473e_b35f_9d8c24c0c484);
 impl core::ops::Deref for IOfflineFilesEvents4 {
@@ -1538,6 +2293,30 @@ impl IOfflineFilesEvents4_Vtbl {
         iid == &<IOfflineFilesEvents4 as windows_core::Interface>::IID || iid == &<IOfflineFilesEvents as windows_core::Interface>::IID || iid == &<IOfflineFilesEvents2 as windows_core::Interface>::IID || iid == &<IOfflineFilesEvents3 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents4_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesEvents4_Vtbl
+where
+    IOfflineFilesEvents3_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn PrefetchCloseHandleBegin<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents4_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents4_Impl::PrefetchCloseHandleBegin(this).into()
+            }
+        }
+        unsafe extern "system" fn PrefetchCloseHandleEnd<Identity: windows_core::IUnknownImpl + IOfflineFilesEvents4_Impl>(this: *mut core::ffi::c_void, dwclosedhandlecount: u32, dwopenhandlecount: u32, hrresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEvents4_Impl::PrefetchCloseHandleEnd(this, core::mem::transmute_copy(&dwclosedhandlecount), core::mem::transmute_copy(&dwopenhandlecount), core::mem::transmute_copy(&hrresult)).into()
+            }
+        }
+        Self {
+            base__: <IOfflineFilesEvents3_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PrefetchCloseHandleBegin: PrefetchCloseHandleBegin::<Identity>,
+            PrefetchCloseHandleEnd: PrefetchCloseHandleEnd::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesEvents4 {}
 windows_core::imp::define_interface!(IOfflineFilesEventsFilter, IOfflineFilesEventsFilter_Vtbl, 0x33fc4e1b_0716_40fa_ba65_6e62a84a846f);
 windows_core::imp::interface_hierarchy!(IOfflineFilesEventsFilter, windows_core::IUnknown);
@@ -1596,6 +2375,37 @@ impl IOfflineFilesEventsFilter_Vtbl {
         iid == &<IOfflineFilesEventsFilter as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesEventsFilter_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesEventsFilter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPathFilter<Identity: windows_core::IUnknownImpl + IOfflineFilesEventsFilter_Impl>(this: *mut core::ffi::c_void, ppszfilter: *mut windows_core::PWSTR, pmatch: *mut OFFLINEFILES_PATHFILTER_MATCH) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEventsFilter_Impl::GetPathFilter(this, core::mem::transmute_copy(&ppszfilter), core::mem::transmute_copy(&pmatch)).into()
+            }
+        }
+        unsafe extern "system" fn GetIncludedEvents<Identity: windows_core::IUnknownImpl + IOfflineFilesEventsFilter_Impl>(this: *mut core::ffi::c_void, celements: u32, prgevents: *mut OFFLINEFILES_EVENTS, pcevents: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEventsFilter_Impl::GetIncludedEvents(this, core::mem::transmute_copy(&celements), core::mem::transmute_copy(&prgevents), core::mem::transmute_copy(&pcevents)).into()
+            }
+        }
+        unsafe extern "system" fn GetExcludedEvents<Identity: windows_core::IUnknownImpl + IOfflineFilesEventsFilter_Impl>(this: *mut core::ffi::c_void, celements: u32, prgevents: *mut OFFLINEFILES_EVENTS, pcevents: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesEventsFilter_Impl::GetExcludedEvents(this, core::mem::transmute_copy(&celements), core::mem::transmute_copy(&prgevents), core::mem::transmute_copy(&pcevents)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPathFilter: GetPathFilter::<Identity>,
+            GetIncludedEvents: GetIncludedEvents::<Identity>,
+            GetExcludedEvents: GetExcludedEvents::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesEventsFilter {}
 windows_core::imp::define_interface!(IOfflineFilesFileItem, IOfflineFilesFileItem_Vtbl, 0x8dfadead_26c2_4eff_8a72_6b50723d9a00);
 impl core::ops::Deref for IOfflineFilesFileItem {
@@ -1662,6 +2472,42 @@ impl IOfflineFilesFileItem_Vtbl {
         iid == &<IOfflineFilesFileItem as windows_core::Interface>::IID || iid == &<IOfflineFilesItem as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesFileItem_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesFileItem_Vtbl
+where
+    IOfflineFilesItem_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsSparse<Identity: windows_core::IUnknownImpl + IOfflineFilesFileItem_Impl>(this: *mut core::ffi::c_void, pbissparse: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesFileItem_Impl::IsSparse(this) {
+                    Ok(ok__) => {
+                        pbissparse.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsEncrypted<Identity: windows_core::IUnknownImpl + IOfflineFilesFileItem_Impl>(this: *mut core::ffi::c_void, pbisencrypted: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesFileItem_Impl::IsEncrypted(this) {
+                    Ok(ok__) => {
+                        pbisencrypted.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IOfflineFilesItem_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsSparse: IsSparse::<Identity>,
+            IsEncrypted: IsEncrypted::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesFileItem {}
 windows_core::imp::define_interface!(IOfflineFilesFileSysInfo, IOfflineFilesFileSysInfo_Vtbl, 0xbc1a163f_7bfd_4d88_9c66_96ea9a6a3d6b);
 windows_core::imp::interface_hierarchy!(IOfflineFilesFileSysInfo, windows_core::IUnknown);
@@ -1738,11 +2584,54 @@ impl IOfflineFilesFileSysInfo_Vtbl {
         iid == &<IOfflineFilesFileSysInfo as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IOfflineFilesFileSysInfo {}
-windows_core::imp::define_interface!(IOfflineFilesGhostInfo, IOfflineFilesGhostInfo_Vtbl, 0x2b09d48c_8ab5_464f_a755_a59d92f99429);
-windows_core::imp::interface_hierarchy!(IOfflineFilesGhostInfo, windows_core::IUnknown);
-impl IOfflineFilesGhostInfo {
-    pub unsafe fn IsGhosted(&self) -> windows_core::Result<windows_core::BOOL> {
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesFileSysInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesFileSysInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAttributes<Identity: windows_core::IUnknownImpl + IOfflineFilesFileSysInfo_Impl>(this: *mut core::ffi::c_void, copy: OFFLINEFILES_ITEM_COPY, pdwattributes: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesFileSysInfo_Impl::GetAttributes(this, core::mem::transmute_copy(&copy)) {
+                    Ok(ok__) => {
+                        pdwattributes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTimes<Identity: windows_core::IUnknownImpl + IOfflineFilesFileSysInfo_Impl>(this: *mut core::ffi::c_void, copy: OFFLINEFILES_ITEM_COPY, pftcreationtime: *mut super::super::Foundation::FILETIME, pftlastwritetime: *mut super::super::Foundation::FILETIME, pftchangetime: *mut super::super::Foundation::FILETIME, pftlastaccesstime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesFileSysInfo_Impl::GetTimes(this, core::mem::transmute_copy(&copy), core::mem::transmute_copy(&pftcreationtime), core::mem::transmute_copy(&pftlastwritetime), core::mem::transmute_copy(&pftchangetime), core::mem::transmute_copy(&pftlastaccesstime)).into()
+            }
+        }
+        unsafe extern "system" fn GetFileSize<Identity: windows_core::IUnknownImpl + IOfflineFilesFileSysInfo_Impl>(this: *mut core::ffi::c_void, copy: OFFLINEFILES_ITEM_COPY, psize: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesFileSysInfo_Impl::GetFileSize(this, core::mem::transmute_copy(&copy)) {
+                    Ok(ok__) => {
+                        psize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAttributes: GetAttributes::<Identity>,
+            GetTimes: GetTimes::<Identity>,
+            GetFileSize: GetFileSize::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IOfflineFilesFileSysInfo {}
+windows_core::imp::define_interface!(IOfflineFilesGhostInfo, IOfflineFilesGhostInfo_Vtbl, 0x2b09d48c_8ab5_464f_a755_a59d92f99429);
+windows_core::imp::interface_hierarchy!(IOfflineFilesGhostInfo, windows_core::IUnknown);
+impl IOfflineFilesGhostInfo {
+    pub unsafe fn IsGhosted(&self) -> windows_core::Result<windows_core::BOOL> {
         unsafe {
             let mut result__ = core::mem::zeroed();
             (windows_core::Interface::vtable(self).IsGhosted)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
@@ -1778,6 +2667,26 @@ impl IOfflineFilesGhostInfo_Vtbl {
         iid == &<IOfflineFilesGhostInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesGhostInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesGhostInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsGhosted<Identity: windows_core::IUnknownImpl + IOfflineFilesGhostInfo_Impl>(this: *mut core::ffi::c_void, pbghosted: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesGhostInfo_Impl::IsGhosted(this) {
+                    Ok(ok__) => {
+                        pbghosted.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, IsGhosted: IsGhosted::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesGhostInfo {}
 windows_core::imp::define_interface!(IOfflineFilesItem, IOfflineFilesItem_Vtbl, 0x4a753da6_e044_4f12_a718_5d14d079a906);
 windows_core::imp::interface_hierarchy!(IOfflineFilesItem, windows_core::IUnknown);
@@ -1896,6 +2805,75 @@ impl IOfflineFilesItem_Vtbl {
         iid == &<IOfflineFilesItem as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesItem_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesItem_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetItemType<Identity: windows_core::IUnknownImpl + IOfflineFilesItem_Impl>(this: *mut core::ffi::c_void, pitemtype: *mut OFFLINEFILES_ITEM_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesItem_Impl::GetItemType(this) {
+                    Ok(ok__) => {
+                        pitemtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPath<Identity: windows_core::IUnknownImpl + IOfflineFilesItem_Impl>(this: *mut core::ffi::c_void, ppszpath: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesItem_Impl::GetPath(this) {
+                    Ok(ok__) => {
+                        ppszpath.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetParentItem<Identity: windows_core::IUnknownImpl + IOfflineFilesItem_Impl>(this: *mut core::ffi::c_void, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesItem_Impl::GetParentItem(this) {
+                    Ok(ok__) => {
+                        ppitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Refresh<Identity: windows_core::IUnknownImpl + IOfflineFilesItem_Impl>(this: *mut core::ffi::c_void, dwqueryflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesItem_Impl::Refresh(this, core::mem::transmute_copy(&dwqueryflags)).into()
+            }
+        }
+        unsafe extern "system" fn IsMarkedForDeletion<Identity: windows_core::IUnknownImpl + IOfflineFilesItem_Impl>(this: *mut core::ffi::c_void, pbmarkedfordeletion: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesItem_Impl::IsMarkedForDeletion(this) {
+                    Ok(ok__) => {
+                        pbmarkedfordeletion.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetItemType: GetItemType::<Identity>,
+            GetPath: GetPath::<Identity>,
+            GetParentItem: GetParentItem::<Identity>,
+            Refresh: Refresh::<Identity>,
+            IsMarkedForDeletion: IsMarkedForDeletion::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesItem {}
 windows_core::imp::define_interface!(IOfflineFilesItemContainer, IOfflineFilesItemContainer_Vtbl, 0x3836f049_9413_45dd_bf46_b5aaa82dc310);
 windows_core::imp::interface_hierarchy!(IOfflineFilesItemContainer, windows_core::IUnknown);
@@ -1966,6 +2944,42 @@ impl IOfflineFilesItemContainer_Vtbl {
         iid == &<IOfflineFilesItemContainer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesItemContainer_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesItemContainer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumItems<Identity: windows_core::IUnknownImpl + IOfflineFilesItemContainer_Impl>(this: *mut core::ffi::c_void, dwqueryflags: u32, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesItemContainer_Impl::EnumItems(this, core::mem::transmute_copy(&dwqueryflags)) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumItemsEx<Identity: windows_core::IUnknownImpl + IOfflineFilesItemContainer_Impl>(this: *mut core::ffi::c_void, pincludefilefilter: *mut core::ffi::c_void, pincludedirfilter: *mut core::ffi::c_void, pexcludefilefilter: *mut core::ffi::c_void, pexcludedirfilter: *mut core::ffi::c_void, dwenumflags: u32, dwqueryflags: u32, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesItemContainer_Impl::EnumItemsEx(this, core::mem::transmute_copy(&pincludefilefilter), core::mem::transmute_copy(&pincludedirfilter), core::mem::transmute_copy(&pexcludefilefilter), core::mem::transmute_copy(&pexcludedirfilter), core::mem::transmute_copy(&dwenumflags), core::mem::transmute_copy(&dwqueryflags)) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnumItems: EnumItems::<Identity>,
+            EnumItemsEx: EnumItemsEx::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesItemContainer {}
 windows_core::imp::define_interface!(IOfflineFilesItemFilter, IOfflineFilesItemFilter_Vtbl, 0xf4b5a26c_dc05_4f20_ada4_551f1077be5c);
 windows_core::imp::interface_hierarchy!(IOfflineFilesItemFilter, windows_core::IUnknown);
@@ -2024,6 +3038,37 @@ impl IOfflineFilesItemFilter_Vtbl {
         iid == &<IOfflineFilesItemFilter as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesItemFilter_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesItemFilter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFilterFlags<Identity: windows_core::IUnknownImpl + IOfflineFilesItemFilter_Impl>(this: *mut core::ffi::c_void, pullflags: *mut u64, pullmask: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesItemFilter_Impl::GetFilterFlags(this, core::mem::transmute_copy(&pullflags), core::mem::transmute_copy(&pullmask)).into()
+            }
+        }
+        unsafe extern "system" fn GetTimeFilter<Identity: windows_core::IUnknownImpl + IOfflineFilesItemFilter_Impl>(this: *mut core::ffi::c_void, pfttime: *mut super::super::Foundation::FILETIME, pbevaltimeofday: *mut windows_core::BOOL, ptimetype: *mut OFFLINEFILES_ITEM_TIME, pcompare: *mut OFFLINEFILES_COMPARE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesItemFilter_Impl::GetTimeFilter(this, core::mem::transmute_copy(&pfttime), core::mem::transmute_copy(&pbevaltimeofday), core::mem::transmute_copy(&ptimetype), core::mem::transmute_copy(&pcompare)).into()
+            }
+        }
+        unsafe extern "system" fn GetPatternFilter<Identity: windows_core::IUnknownImpl + IOfflineFilesItemFilter_Impl>(this: *mut core::ffi::c_void, pszpattern: windows_core::PWSTR, cchpattern: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesItemFilter_Impl::GetPatternFilter(this, core::mem::transmute_copy(&pszpattern), core::mem::transmute_copy(&cchpattern)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFilterFlags: GetFilterFlags::<Identity>,
+            GetTimeFilter: GetTimeFilter::<Identity>,
+            GetPatternFilter: GetPatternFilter::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesItemFilter {}
 windows_core::imp::define_interface!(IOfflineFilesPinInfo, IOfflineFilesPinInfo_Vtbl, 0x5b2b0655_b3fd_497d_adeb_bd156bc8355b);
 windows_core::imp::interface_hierarchy!(IOfflineFilesPinInfo, windows_core::IUnknown);
@@ -2115,6 +3160,57 @@ impl IOfflineFilesPinInfo_Vtbl {
         iid == &<IOfflineFilesPinInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesPinInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesPinInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsPinned<Identity: windows_core::IUnknownImpl + IOfflineFilesPinInfo_Impl>(this: *mut core::ffi::c_void, pbpinned: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesPinInfo_Impl::IsPinned(this) {
+                    Ok(ok__) => {
+                        pbpinned.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsPinnedForUser<Identity: windows_core::IUnknownImpl + IOfflineFilesPinInfo_Impl>(this: *mut core::ffi::c_void, pbpinnedforuser: *mut windows_core::BOOL, pbinherit: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesPinInfo_Impl::IsPinnedForUser(this, core::mem::transmute_copy(&pbpinnedforuser), core::mem::transmute_copy(&pbinherit)).into()
+            }
+        }
+        unsafe extern "system" fn IsPinnedForUserByPolicy<Identity: windows_core::IUnknownImpl + IOfflineFilesPinInfo_Impl>(this: *mut core::ffi::c_void, pbpinnedforuser: *mut windows_core::BOOL, pbinherit: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesPinInfo_Impl::IsPinnedForUserByPolicy(this, core::mem::transmute_copy(&pbpinnedforuser), core::mem::transmute_copy(&pbinherit)).into()
+            }
+        }
+        unsafe extern "system" fn IsPinnedForComputer<Identity: windows_core::IUnknownImpl + IOfflineFilesPinInfo_Impl>(this: *mut core::ffi::c_void, pbpinnedforcomputer: *mut windows_core::BOOL, pbinherit: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesPinInfo_Impl::IsPinnedForComputer(this, core::mem::transmute_copy(&pbpinnedforcomputer), core::mem::transmute_copy(&pbinherit)).into()
+            }
+        }
+        unsafe extern "system" fn IsPinnedForFolderRedirection<Identity: windows_core::IUnknownImpl + IOfflineFilesPinInfo_Impl>(this: *mut core::ffi::c_void, pbpinnedforfolderredirection: *mut windows_core::BOOL, pbinherit: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesPinInfo_Impl::IsPinnedForFolderRedirection(this, core::mem::transmute_copy(&pbpinnedforfolderredirection), core::mem::transmute_copy(&pbinherit)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsPinned: IsPinned::<Identity>,
+            IsPinnedForUser: IsPinnedForUser::<Identity>,
+            IsPinnedForUserByPolicy: IsPinnedForUserByPolicy::<Identity>,
+            IsPinnedForComputer: IsPinnedForComputer::<Identity>,
+            IsPinnedForFolderRedirection: IsPinnedForFolderRedirection::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesPinInfo {}
 windows_core::imp::define_interface!(IOfflineFilesPinInfo2, IOfflineFilesPinInfo2_Vtbl, 0x623c58a2_42ed_4ad7_b69a_0f1b30a72d0d);
 impl core::ops::Deref for IOfflineFilesPinInfo2 {
@@ -2161,6 +3257,26 @@ impl IOfflineFilesPinInfo2_Vtbl {
         iid == &<IOfflineFilesPinInfo2 as windows_core::Interface>::IID || iid == &<IOfflineFilesPinInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesPinInfo2_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesPinInfo2_Vtbl
+where
+    IOfflineFilesPinInfo_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsPartlyPinned<Identity: windows_core::IUnknownImpl + IOfflineFilesPinInfo2_Impl>(this: *mut core::ffi::c_void, pbpartlypinned: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesPinInfo2_Impl::IsPartlyPinned(this) {
+                    Ok(ok__) => {
+                        pbpartlypinned.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IOfflineFilesPinInfo_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, IsPartlyPinned: IsPartlyPinned::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesPinInfo2 {}
 windows_core::imp::define_interface!(IOfflineFilesProgress, IOfflineFilesProgress_Vtbl, 0xfad63237_c55b_4911_9850_bcf96d4c979e);
 windows_core::imp::interface_hierarchy!(IOfflineFilesProgress, windows_core::IUnknown);
@@ -2237,6 +3353,49 @@ impl IOfflineFilesProgress_Vtbl {
         iid == &<IOfflineFilesProgress as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesProgress_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesProgress_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Begin<Identity: windows_core::IUnknownImpl + IOfflineFilesProgress_Impl>(this: *mut core::ffi::c_void, pbabort: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesProgress_Impl::Begin(this) {
+                    Ok(ok__) => {
+                        pbabort.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryAbort<Identity: windows_core::IUnknownImpl + IOfflineFilesProgress_Impl>(this: *mut core::ffi::c_void, pbabort: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesProgress_Impl::QueryAbort(this) {
+                    Ok(ok__) => {
+                        pbabort.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn End<Identity: windows_core::IUnknownImpl + IOfflineFilesProgress_Impl>(this: *mut core::ffi::c_void, hrresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesProgress_Impl::End(this, core::mem::transmute_copy(&hrresult)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Begin: Begin::<Identity>,
+            QueryAbort: QueryAbort::<Identity>,
+            End: End::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesProgress {}
 windows_core::imp::define_interface!(IOfflineFilesServerItem, IOfflineFilesServerItem_Vtbl, 0x9b1c9576_a92b_4151_8e9e_7c7b3ec2e016);
 impl core::ops::Deref for IOfflineFilesServerItem {
@@ -2260,6 +3419,12 @@ impl IOfflineFilesServerItem_Vtbl {
         iid == &<IOfflineFilesServerItem as windows_core::Interface>::IID || iid == &<IOfflineFilesItem as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesServerItem_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesServerItem_Vtbl
+where
+    IOfflineFilesItem_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IOfflineFilesItem_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IOfflineFilesServerItem {}
 windows_core::imp::define_interface!(IOfflineFilesSetting, IOfflineFilesSetting_Vtbl, 0xd871d3f7_f613_48a1_827e_7a34e560fff6);
 windows_core::imp::interface_hierarchy!(IOfflineFilesSetting, windows_core::IUnknown);
@@ -2445,6 +3610,104 @@ impl IOfflineFilesSetting_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesSetting_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesSetting_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IOfflineFilesSetting_Impl>(this: *mut core::ffi::c_void, ppszname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesSetting_Impl::GetName(this) {
+                    Ok(ok__) => {
+                        ppszname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetValueType<Identity: windows_core::IUnknownImpl + IOfflineFilesSetting_Impl>(this: *mut core::ffi::c_void, ptype: *mut OFFLINEFILES_SETTING_VALUE_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesSetting_Impl::GetValueType(this) {
+                    Ok(ok__) => {
+                        ptype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPreference<Identity: windows_core::IUnknownImpl + IOfflineFilesSetting_Impl>(this: *mut core::ffi::c_void, pvarvalue: *mut super::super::System::Variant::VARIANT, dwscope: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesSetting_Impl::GetPreference(this, core::mem::transmute_copy(&pvarvalue), core::mem::transmute_copy(&dwscope)).into()
+            }
+        }
+        unsafe extern "system" fn GetPreferenceScope<Identity: windows_core::IUnknownImpl + IOfflineFilesSetting_Impl>(this: *mut core::ffi::c_void, pdwscope: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesSetting_Impl::GetPreferenceScope(this) {
+                    Ok(ok__) => {
+                        pdwscope.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPreference<Identity: windows_core::IUnknownImpl + IOfflineFilesSetting_Impl>(this: *mut core::ffi::c_void, pvarvalue: *const super::super::System::Variant::VARIANT, dwscope: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesSetting_Impl::SetPreference(this, core::mem::transmute_copy(&pvarvalue), core::mem::transmute_copy(&dwscope)).into()
+            }
+        }
+        unsafe extern "system" fn DeletePreference<Identity: windows_core::IUnknownImpl + IOfflineFilesSetting_Impl>(this: *mut core::ffi::c_void, dwscope: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesSetting_Impl::DeletePreference(this, core::mem::transmute_copy(&dwscope)).into()
+            }
+        }
+        unsafe extern "system" fn GetPolicy<Identity: windows_core::IUnknownImpl + IOfflineFilesSetting_Impl>(this: *mut core::ffi::c_void, pvarvalue: *mut super::super::System::Variant::VARIANT, dwscope: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesSetting_Impl::GetPolicy(this, core::mem::transmute_copy(&pvarvalue), core::mem::transmute_copy(&dwscope)).into()
+            }
+        }
+        unsafe extern "system" fn GetPolicyScope<Identity: windows_core::IUnknownImpl + IOfflineFilesSetting_Impl>(this: *mut core::ffi::c_void, pdwscope: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesSetting_Impl::GetPolicyScope(this) {
+                    Ok(ok__) => {
+                        pdwscope.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetValue<Identity: windows_core::IUnknownImpl + IOfflineFilesSetting_Impl>(this: *mut core::ffi::c_void, pvarvalue: *mut super::super::System::Variant::VARIANT, pbsetbypolicy: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesSetting_Impl::GetValue(this, core::mem::transmute_copy(&pvarvalue), core::mem::transmute_copy(&pbsetbypolicy)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetName: GetName::<Identity>,
+            GetValueType: GetValueType::<Identity>,
+            GetPreference: GetPreference::<Identity>,
+            GetPreferenceScope: GetPreferenceScope::<Identity>,
+            SetPreference: SetPreference::<Identity>,
+            DeletePreference: DeletePreference::<Identity>,
+            GetPolicy: GetPolicy::<Identity>,
+            GetPolicyScope: GetPolicyScope::<Identity>,
+            GetValue: GetValue::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IOfflineFilesSetting {}
 windows_core::imp::define_interface!(IOfflineFilesShareInfo, IOfflineFilesShareInfo_Vtbl, 0x7bcc43e7_31ce_4ca4_8ccd_1cff2dc494da);
 windows_core::imp::interface_hierarchy!(IOfflineFilesShareInfo, windows_core::IUnknown);
@@ -2530,6 +3793,55 @@ impl IOfflineFilesShareInfo_Vtbl {
         iid == &<IOfflineFilesShareInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesShareInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesShareInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetShareItem<Identity: windows_core::IUnknownImpl + IOfflineFilesShareInfo_Impl>(this: *mut core::ffi::c_void, ppshareitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesShareInfo_Impl::GetShareItem(this) {
+                    Ok(ok__) => {
+                        ppshareitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetShareCachingMode<Identity: windows_core::IUnknownImpl + IOfflineFilesShareInfo_Impl>(this: *mut core::ffi::c_void, pcachingmode: *mut OFFLINEFILES_CACHING_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesShareInfo_Impl::GetShareCachingMode(this) {
+                    Ok(ok__) => {
+                        pcachingmode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsShareDfsJunction<Identity: windows_core::IUnknownImpl + IOfflineFilesShareInfo_Impl>(this: *mut core::ffi::c_void, pbisdfsjunction: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesShareInfo_Impl::IsShareDfsJunction(this) {
+                    Ok(ok__) => {
+                        pbisdfsjunction.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetShareItem: GetShareItem::<Identity>,
+            GetShareCachingMode: GetShareCachingMode::<Identity>,
+            IsShareDfsJunction: IsShareDfsJunction::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesShareInfo {}
 windows_core::imp::define_interface!(IOfflineFilesShareItem, IOfflineFilesShareItem_Vtbl, 0xbab7e48d_4804_41b5_a44d_0f199b06b145);
 impl core::ops::Deref for IOfflineFilesShareItem {
@@ -2553,6 +3865,12 @@ impl IOfflineFilesShareItem_Vtbl {
         iid == &<IOfflineFilesShareItem as windows_core::Interface>::IID || iid == &<IOfflineFilesItem as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesShareItem_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesShareItem_Vtbl
+where
+    IOfflineFilesItem_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IOfflineFilesItem_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IOfflineFilesShareItem {}
 windows_core::imp::define_interface!(IOfflineFilesSimpleProgress, IOfflineFilesSimpleProgress_Vtbl, 0xc34f7f9b_c43d_4f9d_a776_c0eb6de5d401);
 impl core::ops::Deref for IOfflineFilesSimpleProgress {
@@ -2629,6 +3947,42 @@ impl IOfflineFilesSimpleProgress_Vtbl {
         iid == &<IOfflineFilesSimpleProgress as windows_core::Interface>::IID || iid == &<IOfflineFilesProgress as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesSimpleProgress_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesSimpleProgress_Vtbl
+where
+    IOfflineFilesProgress_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ItemBegin<Identity: windows_core::IUnknownImpl + IOfflineFilesSimpleProgress_Impl>(this: *mut core::ffi::c_void, pszfile: windows_core::PCWSTR, presponse: *mut OFFLINEFILES_OP_RESPONSE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesSimpleProgress_Impl::ItemBegin(this, core::mem::transmute(&pszfile)) {
+                    Ok(ok__) => {
+                        presponse.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ItemResult<Identity: windows_core::IUnknownImpl + IOfflineFilesSimpleProgress_Impl>(this: *mut core::ffi::c_void, pszfile: windows_core::PCWSTR, hrresult: windows_core::HRESULT, presponse: *mut OFFLINEFILES_OP_RESPONSE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesSimpleProgress_Impl::ItemResult(this, core::mem::transmute(&pszfile), core::mem::transmute_copy(&hrresult)) {
+                    Ok(ok__) => {
+                        presponse.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IOfflineFilesProgress_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ItemBegin: ItemBegin::<Identity>,
+            ItemResult: ItemResult::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesSimpleProgress {}
 windows_core::imp::define_interface!(IOfflineFilesSuspend, IOfflineFilesSuspend_Vtbl, 0x62c4560f_bc0b_48ca_ad9d_34cb528d99a9);
 windows_core::imp::interface_hierarchy!(IOfflineFilesSuspend, windows_core::IUnknown);
@@ -2660,6 +4014,20 @@ impl IOfflineFilesSuspend_Vtbl {
         iid == &<IOfflineFilesSuspend as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesSuspend_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesSuspend_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SuspendRoot<Identity: windows_core::IUnknownImpl + IOfflineFilesSuspend_Impl>(this: *mut core::ffi::c_void, bsuspend: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesSuspend_Impl::SuspendRoot(this, core::mem::transmute_copy(&bsuspend)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SuspendRoot: SuspendRoot::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesSuspend {}
 windows_core::imp::define_interface!(IOfflineFilesSuspendInfo, IOfflineFilesSuspendInfo_Vtbl, 0xa457c25b_4e9c_4b04_85af_8932ccd97889);
 windows_core::imp::interface_hierarchy!(IOfflineFilesSuspendInfo, windows_core::IUnknown);
@@ -2691,6 +4059,20 @@ impl IOfflineFilesSuspendInfo_Vtbl {
         iid == &<IOfflineFilesSuspendInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesSuspendInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesSuspendInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsSuspended<Identity: windows_core::IUnknownImpl + IOfflineFilesSuspendInfo_Impl>(this: *mut core::ffi::c_void, pbsuspended: *mut windows_core::BOOL, pbsuspendedroot: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesSuspendInfo_Impl::IsSuspended(this, core::mem::transmute_copy(&pbsuspended), core::mem::transmute_copy(&pbsuspendedroot)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, IsSuspended: IsSuspended::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesSuspendInfo {}
 windows_core::imp::define_interface!(IOfflineFilesSyncConflictHandler, IOfflineFilesSyncConflictHandler_Vtbl, 0xb6dd5092_c65c_46b6_97b8_fadd08e7e1be);
 windows_core::imp::interface_hierarchy!(IOfflineFilesSyncConflictHandler, windows_core::IUnknown);
@@ -2725,6 +4107,20 @@ impl IOfflineFilesSyncConflictHandler_Vtbl {
         iid == &<IOfflineFilesSyncConflictHandler as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesSyncConflictHandler_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesSyncConflictHandler_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ResolveConflict<Identity: windows_core::IUnknownImpl + IOfflineFilesSyncConflictHandler_Impl>(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, fstateknown: u32, state: OFFLINEFILES_SYNC_STATE, fchangedetails: u32, pconflictresolution: *mut OFFLINEFILES_SYNC_CONFLICT_RESOLVE, ppsznewname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesSyncConflictHandler_Impl::ResolveConflict(this, core::mem::transmute(&pszpath), core::mem::transmute_copy(&fstateknown), core::mem::transmute_copy(&state), core::mem::transmute_copy(&fchangedetails), core::mem::transmute_copy(&pconflictresolution), core::mem::transmute_copy(&ppsznewname)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ResolveConflict: ResolveConflict::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesSyncConflictHandler {}
 windows_core::imp::define_interface!(IOfflineFilesSyncErrorInfo, IOfflineFilesSyncErrorInfo_Vtbl, 0x59f95e46_eb54_49d1_be76_de95458d01b0);
 impl core::ops::Deref for IOfflineFilesSyncErrorInfo {
@@ -2885,6 +4281,96 @@ impl IOfflineFilesSyncErrorInfo_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesSyncErrorInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesSyncErrorInfo_Vtbl
+where
+    IOfflineFilesErrorInfo_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSyncOperation<Identity: windows_core::IUnknownImpl + IOfflineFilesSyncErrorInfo_Impl>(this: *mut core::ffi::c_void, psyncop: *mut OFFLINEFILES_SYNC_OPERATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesSyncErrorInfo_Impl::GetSyncOperation(this) {
+                    Ok(ok__) => {
+                        psyncop.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetItemChangeFlags<Identity: windows_core::IUnknownImpl + IOfflineFilesSyncErrorInfo_Impl>(this: *mut core::ffi::c_void, pdwitemchangeflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesSyncErrorInfo_Impl::GetItemChangeFlags(this) {
+                    Ok(ok__) => {
+                        pdwitemchangeflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InfoEnumerated<Identity: windows_core::IUnknownImpl + IOfflineFilesSyncErrorInfo_Impl>(this: *mut core::ffi::c_void, pblocalenumerated: *mut windows_core::BOOL, pbremoteenumerated: *mut windows_core::BOOL, pboriginalenumerated: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesSyncErrorInfo_Impl::InfoEnumerated(this, core::mem::transmute_copy(&pblocalenumerated), core::mem::transmute_copy(&pbremoteenumerated), core::mem::transmute_copy(&pboriginalenumerated)).into()
+            }
+        }
+        unsafe extern "system" fn InfoAvailable<Identity: windows_core::IUnknownImpl + IOfflineFilesSyncErrorInfo_Impl>(this: *mut core::ffi::c_void, pblocalinfo: *mut windows_core::BOOL, pbremoteinfo: *mut windows_core::BOOL, pboriginalinfo: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesSyncErrorInfo_Impl::InfoAvailable(this, core::mem::transmute_copy(&pblocalinfo), core::mem::transmute_copy(&pbremoteinfo), core::mem::transmute_copy(&pboriginalinfo)).into()
+            }
+        }
+        unsafe extern "system" fn GetLocalInfo<Identity: windows_core::IUnknownImpl + IOfflineFilesSyncErrorInfo_Impl>(this: *mut core::ffi::c_void, ppinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesSyncErrorInfo_Impl::GetLocalInfo(this) {
+                    Ok(ok__) => {
+                        ppinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRemoteInfo<Identity: windows_core::IUnknownImpl + IOfflineFilesSyncErrorInfo_Impl>(this: *mut core::ffi::c_void, ppinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesSyncErrorInfo_Impl::GetRemoteInfo(this) {
+                    Ok(ok__) => {
+                        ppinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOriginalInfo<Identity: windows_core::IUnknownImpl + IOfflineFilesSyncErrorInfo_Impl>(this: *mut core::ffi::c_void, ppinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesSyncErrorInfo_Impl::GetOriginalInfo(this) {
+                    Ok(ok__) => {
+                        ppinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IOfflineFilesErrorInfo_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSyncOperation: GetSyncOperation::<Identity>,
+            GetItemChangeFlags: GetItemChangeFlags::<Identity>,
+            InfoEnumerated: InfoEnumerated::<Identity>,
+            InfoAvailable: InfoAvailable::<Identity>,
+            GetLocalInfo: GetLocalInfo::<Identity>,
+            GetRemoteInfo: GetRemoteInfo::<Identity>,
+            GetOriginalInfo: GetOriginalInfo::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOfflineFilesSyncErrorInfo {}
 windows_core::imp::define_interface!(IOfflineFilesSyncErrorItemInfo, IOfflineFilesSyncErrorItemInfo_Vtbl, 0xecdbaf0d_6a18_4d55_8017_108f7660ba44);
 windows_core::imp::interface_hierarchy!(IOfflineFilesSyncErrorItemInfo, windows_core::IUnknown);
@@ -2961,6 +4447,49 @@ impl IOfflineFilesSyncErrorItemInfo_Vtbl {
         iid == &<IOfflineFilesSyncErrorItemInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesSyncErrorItemInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesSyncErrorItemInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFileAttributes<Identity: windows_core::IUnknownImpl + IOfflineFilesSyncErrorItemInfo_Impl>(this: *mut core::ffi::c_void, pdwattributes: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesSyncErrorItemInfo_Impl::GetFileAttributes(this) {
+                    Ok(ok__) => {
+                        pdwattributes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFileTimes<Identity: windows_core::IUnknownImpl + IOfflineFilesSyncErrorItemInfo_Impl>(this: *mut core::ffi::c_void, pftlastwrite: *mut super::super::Foundation::FILETIME, pftchange: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOfflineFilesSyncErrorItemInfo_Impl::GetFileTimes(this, core::mem::transmute_copy(&pftlastwrite), core::mem::transmute_copy(&pftchange)).into()
+            }
+        }
+        unsafe extern "system" fn GetFileSize<Identity: windows_core::IUnknownImpl + IOfflineFilesSyncErrorItemInfo_Impl>(this: *mut core::ffi::c_void, psize: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesSyncErrorItemInfo_Impl::GetFileSize(this) {
+                    Ok(ok__) => {
+                        psize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFileAttributes: GetFileAttributes::<Identity>,
+            GetFileTimes: GetFileTimes::<Identity>,
+            GetFileSize: GetFileSize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesSyncErrorItemInfo {}
 windows_core::imp::define_interface!(IOfflineFilesSyncProgress, IOfflineFilesSyncProgress_Vtbl, 0x6931f49a_6fc7_4c1b_b265_56793fc451b7);
 impl core::ops::Deref for IOfflineFilesSyncProgress {
@@ -3038,6 +4567,42 @@ impl IOfflineFilesSyncProgress_Vtbl {
         iid == &<IOfflineFilesSyncProgress as windows_core::Interface>::IID || iid == &<IOfflineFilesProgress as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesSyncProgress_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesSyncProgress_Vtbl
+where
+    IOfflineFilesProgress_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SyncItemBegin<Identity: windows_core::IUnknownImpl + IOfflineFilesSyncProgress_Impl>(this: *mut core::ffi::c_void, pszfile: windows_core::PCWSTR, presponse: *mut OFFLINEFILES_OP_RESPONSE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesSyncProgress_Impl::SyncItemBegin(this, core::mem::transmute(&pszfile)) {
+                    Ok(ok__) => {
+                        presponse.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SyncItemResult<Identity: windows_core::IUnknownImpl + IOfflineFilesSyncProgress_Impl>(this: *mut core::ffi::c_void, pszfile: windows_core::PCWSTR, hrresult: windows_core::HRESULT, perrorinfo: *mut core::ffi::c_void, presponse: *mut OFFLINEFILES_OP_RESPONSE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesSyncProgress_Impl::SyncItemResult(this, core::mem::transmute(&pszfile), core::mem::transmute_copy(&hrresult), core::mem::transmute_copy(&perrorinfo)) {
+                    Ok(ok__) => {
+                        presponse.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IOfflineFilesProgress_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SyncItemBegin: SyncItemBegin::<Identity>,
+            SyncItemResult: SyncItemResult::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesSyncProgress {}
 windows_core::imp::define_interface!(IOfflineFilesTransparentCacheInfo, IOfflineFilesTransparentCacheInfo_Vtbl, 0xbcaf4a01_5b68_4b56_a6a1_8d2786ede8e3);
 windows_core::imp::interface_hierarchy!(IOfflineFilesTransparentCacheInfo, windows_core::IUnknown);
@@ -3078,6 +4643,29 @@ impl IOfflineFilesTransparentCacheInfo_Vtbl {
         iid == &<IOfflineFilesTransparentCacheInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOfflineFilesTransparentCacheInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IOfflineFilesTransparentCacheInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsTransparentlyCached<Identity: windows_core::IUnknownImpl + IOfflineFilesTransparentCacheInfo_Impl>(this: *mut core::ffi::c_void, pbtransparentlycached: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOfflineFilesTransparentCacheInfo_Impl::IsTransparentlyCached(this) {
+                    Ok(ok__) => {
+                        pbtransparentlycached.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsTransparentlyCached: IsTransparentlyCached::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOfflineFilesTransparentCacheInfo {}
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
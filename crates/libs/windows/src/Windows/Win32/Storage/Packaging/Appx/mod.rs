@@ -855,6 +855,27 @@ impl IAppxAppInstallerReader_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+impl<Identity: windows_core::IUnknownImpl + IAppxAppInstallerReader_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxAppInstallerReader_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetXmlDom<Identity: windows_core::IUnknownImpl + IAppxAppInstallerReader_Impl>(this: *mut core::ffi::c_void, dom: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxAppInstallerReader_Impl::GetXmlDom(this) {
+                    Ok(ok__) => {
+                        dom.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetXmlDom: GetXmlDom::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
 impl windows_core::RuntimeName for IAppxAppInstallerReader {}
 windows_core::imp::define_interface!(IAppxBlockMapBlock, IAppxBlockMapBlock_Vtbl, 0x75cf3930_3244_4fe0_a8c8_e0bcb270b889);
 windows_core::imp::interface_hierarchy!(IAppxBlockMapBlock, windows_core::IUnknown);
@@ -919,6 +940,42 @@ impl IAppxBlockMapBlock_Vtbl {
         iid == &<IAppxBlockMapBlock as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxBlockMapBlock_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxBlockMapBlock_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetHash<Identity: windows_core::IUnknownImpl + IAppxBlockMapBlock_Impl>(this: *mut core::ffi::c_void, buffersize: *mut u32, buffer: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBlockMapBlock_Impl::GetHash(this, core::mem::transmute_copy(&buffersize)) {
+                    Ok(ok__) => {
+                        buffer.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCompressedSize<Identity: windows_core::IUnknownImpl + IAppxBlockMapBlock_Impl>(this: *mut core::ffi::c_void, size: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBlockMapBlock_Impl::GetCompressedSize(this) {
+                    Ok(ok__) => {
+                        size.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetHash: GetHash::<Identity>,
+            GetCompressedSize: GetCompressedSize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxBlockMapBlock {}
 windows_core::imp::define_interface!(IAppxBlockMapBlocksEnumerator, IAppxBlockMapBlocksEnumerator_Vtbl, 0x6b429b5b_36ef_479e_b9eb_0c1482b49e16);
 windows_core::imp::interface_hierarchy!(IAppxBlockMapBlocksEnumerator, windows_core::IUnknown);
@@ -1004,6 +1061,55 @@ impl IAppxBlockMapBlocksEnumerator_Vtbl {
         iid == &<IAppxBlockMapBlocksEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxBlockMapBlocksEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxBlockMapBlocksEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IAppxBlockMapBlocksEnumerator_Impl>(this: *mut core::ffi::c_void, block: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBlockMapBlocksEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        block.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHasCurrent<Identity: windows_core::IUnknownImpl + IAppxBlockMapBlocksEnumerator_Impl>(this: *mut core::ffi::c_void, hascurrent: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBlockMapBlocksEnumerator_Impl::GetHasCurrent(this) {
+                    Ok(ok__) => {
+                        hascurrent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IAppxBlockMapBlocksEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBlockMapBlocksEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrent: GetCurrent::<Identity>,
+            GetHasCurrent: GetHasCurrent::<Identity>,
+            MoveNext: MoveNext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxBlockMapBlocksEnumerator {}
 windows_core::imp::define_interface!(IAppxBlockMapFile, IAppxBlockMapFile_Vtbl, 0x277672ac_4f63_42c1_8abc_beae3600eb59);
 windows_core::imp::interface_hierarchy!(IAppxBlockMapFile, windows_core::IUnknown);
@@ -1141,6 +1247,82 @@ impl IAppxBlockMapFile_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxBlockMapFile_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxBlockMapFile_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetBlocks<Identity: windows_core::IUnknownImpl + IAppxBlockMapFile_Impl>(this: *mut core::ffi::c_void, blocks: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBlockMapFile_Impl::GetBlocks(this) {
+                    Ok(ok__) => {
+                        blocks.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLocalFileHeaderSize<Identity: windows_core::IUnknownImpl + IAppxBlockMapFile_Impl>(this: *mut core::ffi::c_void, lfhsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBlockMapFile_Impl::GetLocalFileHeaderSize(this) {
+                    Ok(ok__) => {
+                        lfhsize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IAppxBlockMapFile_Impl>(this: *mut core::ffi::c_void, name: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBlockMapFile_Impl::GetName(this) {
+                    Ok(ok__) => {
+                        name.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetUncompressedSize<Identity: windows_core::IUnknownImpl + IAppxBlockMapFile_Impl>(this: *mut core::ffi::c_void, size: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBlockMapFile_Impl::GetUncompressedSize(this) {
+                    Ok(ok__) => {
+                        size.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ValidateFileHash<Identity: windows_core::IUnknownImpl + IAppxBlockMapFile_Impl>(this: *mut core::ffi::c_void, filestream: *mut core::ffi::c_void, isvalid: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBlockMapFile_Impl::ValidateFileHash(this, core::mem::transmute_copy(&filestream)) {
+                    Ok(ok__) => {
+                        isvalid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetBlocks: GetBlocks::<Identity>,
+            GetLocalFileHeaderSize: GetLocalFileHeaderSize::<Identity>,
+            GetName: GetName::<Identity>,
+            GetUncompressedSize: GetUncompressedSize::<Identity>,
+            ValidateFileHash: ValidateFileHash::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxBlockMapFile {}
 windows_core::imp::define_interface!(IAppxBlockMapFilesEnumerator, IAppxBlockMapFilesEnumerator_Vtbl, 0x02b856a2_4262_4070_bacb_1a8cbbc42305);
 windows_core::imp::interface_hierarchy!(IAppxBlockMapFilesEnumerator, windows_core::IUnknown);
@@ -1226,6 +1408,55 @@ impl IAppxBlockMapFilesEnumerator_Vtbl {
         iid == &<IAppxBlockMapFilesEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxBlockMapFilesEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxBlockMapFilesEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IAppxBlockMapFilesEnumerator_Impl>(this: *mut core::ffi::c_void, file: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBlockMapFilesEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        file.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHasCurrent<Identity: windows_core::IUnknownImpl + IAppxBlockMapFilesEnumerator_Impl>(this: *mut core::ffi::c_void, hascurrent: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBlockMapFilesEnumerator_Impl::GetHasCurrent(this) {
+                    Ok(ok__) => {
+                        hascurrent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IAppxBlockMapFilesEnumerator_Impl>(this: *mut core::ffi::c_void, hascurrent: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBlockMapFilesEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hascurrent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrent: GetCurrent::<Identity>,
+            GetHasCurrent: GetHasCurrent::<Identity>,
+            MoveNext: MoveNext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxBlockMapFilesEnumerator {}
 windows_core::imp::define_interface!(IAppxBlockMapReader, IAppxBlockMapReader_Vtbl, 0x5efec991_bca3_42d1_9ec2_e92d609ec22a);
 windows_core::imp::interface_hierarchy!(IAppxBlockMapReader, windows_core::IUnknown);
@@ -1346,6 +1577,69 @@ impl IAppxBlockMapReader_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxBlockMapReader_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxBlockMapReader_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFile<Identity: windows_core::IUnknownImpl + IAppxBlockMapReader_Impl>(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, file: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBlockMapReader_Impl::GetFile(this, core::mem::transmute(&filename)) {
+                    Ok(ok__) => {
+                        file.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFiles<Identity: windows_core::IUnknownImpl + IAppxBlockMapReader_Impl>(this: *mut core::ffi::c_void, enumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBlockMapReader_Impl::GetFiles(this) {
+                    Ok(ok__) => {
+                        enumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHashMethod<Identity: windows_core::IUnknownImpl + IAppxBlockMapReader_Impl>(this: *mut core::ffi::c_void, hashmethod: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBlockMapReader_Impl::GetHashMethod(this) {
+                    Ok(ok__) => {
+                        hashmethod.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStream<Identity: windows_core::IUnknownImpl + IAppxBlockMapReader_Impl>(this: *mut core::ffi::c_void, blockmapstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBlockMapReader_Impl::GetStream(this) {
+                    Ok(ok__) => {
+                        blockmapstream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFile: GetFile::<Identity>,
+            GetFiles: GetFiles::<Identity>,
+            GetHashMethod: GetHashMethod::<Identity>,
+            GetStream: GetStream::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxBlockMapReader {}
 windows_core::imp::define_interface!(IAppxBundleFactory, IAppxBundleFactory_Vtbl, 0xbba65864_965f_4a5f_855f_f074bdbf3a7b);
 windows_core::imp::interface_hierarchy!(IAppxBundleFactory, windows_core::IUnknown);
@@ -1455,6 +1749,56 @@ impl IAppxBundleFactory_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxBundleFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxBundleFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateBundleWriter<Identity: windows_core::IUnknownImpl + IAppxBundleFactory_Impl>(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, bundleversion: u64, bundlewriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleFactory_Impl::CreateBundleWriter(this, core::mem::transmute_copy(&outputstream), core::mem::transmute_copy(&bundleversion)) {
+                    Ok(ok__) => {
+                        bundlewriter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateBundleReader<Identity: windows_core::IUnknownImpl + IAppxBundleFactory_Impl>(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, bundlereader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleFactory_Impl::CreateBundleReader(this, core::mem::transmute_copy(&inputstream)) {
+                    Ok(ok__) => {
+                        bundlereader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateBundleManifestReader<Identity: windows_core::IUnknownImpl + IAppxBundleFactory_Impl>(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, manifestreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleFactory_Impl::CreateBundleManifestReader(this, core::mem::transmute_copy(&inputstream)) {
+                    Ok(ok__) => {
+                        manifestreader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateBundleWriter: CreateBundleWriter::<Identity>,
+            CreateBundleReader: CreateBundleReader::<Identity>,
+            CreateBundleManifestReader: CreateBundleManifestReader::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxBundleFactory {}
 windows_core::imp::define_interface!(IAppxBundleFactory2, IAppxBundleFactory2_Vtbl, 0x7325b83d_0185_42c4_82ac_be34ab1a2a8a);
 windows_core::imp::interface_hierarchy!(IAppxBundleFactory2, windows_core::IUnknown);
@@ -1506,15 +1850,39 @@ impl IAppxBundleFactory2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
-impl windows_core::RuntimeName for IAppxBundleFactory2 {}
-windows_core::imp::define_interface!(IAppxBundleManifestOptionalBundleInfo, IAppxBundleManifestOptionalBundleInfo_Vtbl, 0x515bf2e8_bcb0_4d69_8c48_e383147b6e12);
-windows_core::imp::interface_hierarchy!(IAppxBundleManifestOptionalBundleInfo, windows_core::IUnknown);
-impl IAppxBundleManifestOptionalBundleInfo {
-    pub unsafe fn GetPackageId(&self) -> windows_core::Result<IAppxManifestPackageId> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetPackageId)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
+impl<Identity: windows_core::IUnknownImpl + IAppxBundleFactory2_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxBundleFactory2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateBundleReader2<Identity: windows_core::IUnknownImpl + IAppxBundleFactory2_Impl>(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, expecteddigest: windows_core::PCWSTR, bundlereader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleFactory2_Impl::CreateBundleReader2(this, core::mem::transmute_copy(&inputstream), core::mem::transmute(&expecteddigest)) {
+                    Ok(ok__) => {
+                        bundlereader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateBundleReader2: CreateBundleReader2::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::RuntimeName for IAppxBundleFactory2 {}
+windows_core::imp::define_interface!(IAppxBundleManifestOptionalBundleInfo, IAppxBundleManifestOptionalBundleInfo_Vtbl, 0x515bf2e8_bcb0_4d69_8c48_e383147b6e12);
+windows_core::imp::interface_hierarchy!(IAppxBundleManifestOptionalBundleInfo, windows_core::IUnknown);
+impl IAppxBundleManifestOptionalBundleInfo {
+    pub unsafe fn GetPackageId(&self) -> windows_core::Result<IAppxManifestPackageId> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetPackageId)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
     }
     pub unsafe fn GetFileName(&self) -> windows_core::Result<windows_core::PWSTR> {
         unsafe {
@@ -1591,6 +1959,55 @@ impl IAppxBundleManifestOptionalBundleInfo_Vtbl {
         iid == &<IAppxBundleManifestOptionalBundleInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxBundleManifestOptionalBundleInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxBundleManifestOptionalBundleInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPackageId<Identity: windows_core::IUnknownImpl + IAppxBundleManifestOptionalBundleInfo_Impl>(this: *mut core::ffi::c_void, packageid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestOptionalBundleInfo_Impl::GetPackageId(this) {
+                    Ok(ok__) => {
+                        packageid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFileName<Identity: windows_core::IUnknownImpl + IAppxBundleManifestOptionalBundleInfo_Impl>(this: *mut core::ffi::c_void, filename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestOptionalBundleInfo_Impl::GetFileName(this) {
+                    Ok(ok__) => {
+                        filename.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPackageInfoItems<Identity: windows_core::IUnknownImpl + IAppxBundleManifestOptionalBundleInfo_Impl>(this: *mut core::ffi::c_void, packageinfoitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestOptionalBundleInfo_Impl::GetPackageInfoItems(this) {
+                    Ok(ok__) => {
+                        packageinfoitems.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPackageId: GetPackageId::<Identity>,
+            GetFileName: GetFileName::<Identity>,
+            GetPackageInfoItems: GetPackageInfoItems::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxBundleManifestOptionalBundleInfo {}
 windows_core::imp::define_interface!(IAppxBundleManifestOptionalBundleInfoEnumerator, IAppxBundleManifestOptionalBundleInfoEnumerator_Vtbl, 0x9a178793_f97e_46ac_aaca_dd5ba4c177c8);
 windows_core::imp::interface_hierarchy!(IAppxBundleManifestOptionalBundleInfoEnumerator, windows_core::IUnknown);
@@ -1676,6 +2093,55 @@ impl IAppxBundleManifestOptionalBundleInfoEnumerator_Vtbl {
         iid == &<IAppxBundleManifestOptionalBundleInfoEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxBundleManifestOptionalBundleInfoEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxBundleManifestOptionalBundleInfoEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IAppxBundleManifestOptionalBundleInfoEnumerator_Impl>(this: *mut core::ffi::c_void, optionalbundle: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestOptionalBundleInfoEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        optionalbundle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHasCurrent<Identity: windows_core::IUnknownImpl + IAppxBundleManifestOptionalBundleInfoEnumerator_Impl>(this: *mut core::ffi::c_void, hascurrent: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestOptionalBundleInfoEnumerator_Impl::GetHasCurrent(this) {
+                    Ok(ok__) => {
+                        hascurrent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IAppxBundleManifestOptionalBundleInfoEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestOptionalBundleInfoEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrent: GetCurrent::<Identity>,
+            GetHasCurrent: GetHasCurrent::<Identity>,
+            MoveNext: MoveNext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxBundleManifestOptionalBundleInfoEnumerator {}
 windows_core::imp::define_interface!(IAppxBundleManifestPackageInfo, IAppxBundleManifestPackageInfo_Vtbl, 0x54cd06c1_268f_40bb_8ed2_757a9ebaec8d);
 windows_core::imp::interface_hierarchy!(IAppxBundleManifestPackageInfo, windows_core::IUnknown);
@@ -1824,6 +2290,94 @@ impl IAppxBundleManifestPackageInfo_Vtbl {
         iid == &<IAppxBundleManifestPackageInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxBundleManifestPackageInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxBundleManifestPackageInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPackageType<Identity: windows_core::IUnknownImpl + IAppxBundleManifestPackageInfo_Impl>(this: *mut core::ffi::c_void, packagetype: *mut APPX_BUNDLE_PAYLOAD_PACKAGE_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestPackageInfo_Impl::GetPackageType(this) {
+                    Ok(ok__) => {
+                        packagetype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPackageId<Identity: windows_core::IUnknownImpl + IAppxBundleManifestPackageInfo_Impl>(this: *mut core::ffi::c_void, packageid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestPackageInfo_Impl::GetPackageId(this) {
+                    Ok(ok__) => {
+                        packageid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFileName<Identity: windows_core::IUnknownImpl + IAppxBundleManifestPackageInfo_Impl>(this: *mut core::ffi::c_void, filename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestPackageInfo_Impl::GetFileName(this) {
+                    Ok(ok__) => {
+                        filename.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOffset<Identity: windows_core::IUnknownImpl + IAppxBundleManifestPackageInfo_Impl>(this: *mut core::ffi::c_void, offset: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestPackageInfo_Impl::GetOffset(this) {
+                    Ok(ok__) => {
+                        offset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSize<Identity: windows_core::IUnknownImpl + IAppxBundleManifestPackageInfo_Impl>(this: *mut core::ffi::c_void, size: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestPackageInfo_Impl::GetSize(this) {
+                    Ok(ok__) => {
+                        size.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetResources<Identity: windows_core::IUnknownImpl + IAppxBundleManifestPackageInfo_Impl>(this: *mut core::ffi::c_void, resources: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestPackageInfo_Impl::GetResources(this) {
+                    Ok(ok__) => {
+                        resources.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPackageType: GetPackageType::<Identity>,
+            GetPackageId: GetPackageId::<Identity>,
+            GetFileName: GetFileName::<Identity>,
+            GetOffset: GetOffset::<Identity>,
+            GetSize: GetSize::<Identity>,
+            GetResources: GetResources::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxBundleManifestPackageInfo {}
 windows_core::imp::define_interface!(IAppxBundleManifestPackageInfo2, IAppxBundleManifestPackageInfo2_Vtbl, 0x44c2acbc_b2cf_4ccb_bbdb_9c6da8c3bc9e);
 windows_core::imp::interface_hierarchy!(IAppxBundleManifestPackageInfo2, windows_core::IUnknown);
@@ -1909,6 +2463,55 @@ impl IAppxBundleManifestPackageInfo2_Vtbl {
         iid == &<IAppxBundleManifestPackageInfo2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxBundleManifestPackageInfo2_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxBundleManifestPackageInfo2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetIsPackageReference<Identity: windows_core::IUnknownImpl + IAppxBundleManifestPackageInfo2_Impl>(this: *mut core::ffi::c_void, ispackagereference: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestPackageInfo2_Impl::GetIsPackageReference(this) {
+                    Ok(ok__) => {
+                        ispackagereference.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetIsNonQualifiedResourcePackage<Identity: windows_core::IUnknownImpl + IAppxBundleManifestPackageInfo2_Impl>(this: *mut core::ffi::c_void, isnonqualifiedresourcepackage: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestPackageInfo2_Impl::GetIsNonQualifiedResourcePackage(this) {
+                    Ok(ok__) => {
+                        isnonqualifiedresourcepackage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetIsDefaultApplicablePackage<Identity: windows_core::IUnknownImpl + IAppxBundleManifestPackageInfo2_Impl>(this: *mut core::ffi::c_void, isdefaultapplicablepackage: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestPackageInfo2_Impl::GetIsDefaultApplicablePackage(this) {
+                    Ok(ok__) => {
+                        isdefaultapplicablepackage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetIsPackageReference: GetIsPackageReference::<Identity>,
+            GetIsNonQualifiedResourcePackage: GetIsNonQualifiedResourcePackage::<Identity>,
+            GetIsDefaultApplicablePackage: GetIsDefaultApplicablePackage::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxBundleManifestPackageInfo2 {}
 windows_core::imp::define_interface!(IAppxBundleManifestPackageInfo3, IAppxBundleManifestPackageInfo3_Vtbl, 0x6ba74b98_bb74_4296_80d0_5f4256a99675);
 windows_core::imp::interface_hierarchy!(IAppxBundleManifestPackageInfo3, windows_core::IUnknown);
@@ -1949,6 +2552,29 @@ impl IAppxBundleManifestPackageInfo3_Vtbl {
         iid == &<IAppxBundleManifestPackageInfo3 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxBundleManifestPackageInfo3_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxBundleManifestPackageInfo3_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetTargetDeviceFamilies<Identity: windows_core::IUnknownImpl + IAppxBundleManifestPackageInfo3_Impl>(this: *mut core::ffi::c_void, targetdevicefamilies: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestPackageInfo3_Impl::GetTargetDeviceFamilies(this) {
+                    Ok(ok__) => {
+                        targetdevicefamilies.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetTargetDeviceFamilies: GetTargetDeviceFamilies::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxBundleManifestPackageInfo3 {}
 windows_core::imp::define_interface!(IAppxBundleManifestPackageInfo4, IAppxBundleManifestPackageInfo4_Vtbl, 0x5da6f13d_a8a7_4532_857c_1393d659371d);
 windows_core::imp::interface_hierarchy!(IAppxBundleManifestPackageInfo4, windows_core::IUnknown);
@@ -1989,6 +2615,26 @@ impl IAppxBundleManifestPackageInfo4_Vtbl {
         iid == &<IAppxBundleManifestPackageInfo4 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxBundleManifestPackageInfo4_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxBundleManifestPackageInfo4_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetIsStub<Identity: windows_core::IUnknownImpl + IAppxBundleManifestPackageInfo4_Impl>(this: *mut core::ffi::c_void, isstub: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestPackageInfo4_Impl::GetIsStub(this) {
+                    Ok(ok__) => {
+                        isstub.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetIsStub: GetIsStub::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IAppxBundleManifestPackageInfo4 {}
 windows_core::imp::define_interface!(IAppxBundleManifestPackageInfoEnumerator, IAppxBundleManifestPackageInfoEnumerator_Vtbl, 0xf9b856ee_49a6_4e19_b2b0_6a2406d63a32);
 windows_core::imp::interface_hierarchy!(IAppxBundleManifestPackageInfoEnumerator, windows_core::IUnknown);
@@ -2074,20 +2720,69 @@ impl IAppxBundleManifestPackageInfoEnumerator_Vtbl {
         iid == &<IAppxBundleManifestPackageInfoEnumerator as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IAppxBundleManifestPackageInfoEnumerator {}
-windows_core::imp::define_interface!(IAppxBundleManifestReader, IAppxBundleManifestReader_Vtbl, 0xcf0ebbc1_cc99_4106_91eb_e67462e04fb0);
-windows_core::imp::interface_hierarchy!(IAppxBundleManifestReader, windows_core::IUnknown);
-impl IAppxBundleManifestReader {
-    pub unsafe fn GetPackageId(&self) -> windows_core::Result<IAppxManifestPackageId> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetPackageId)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+impl<Identity: windows_core::IUnknownImpl + IAppxBundleManifestPackageInfoEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxBundleManifestPackageInfoEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IAppxBundleManifestPackageInfoEnumerator_Impl>(this: *mut core::ffi::c_void, packageinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestPackageInfoEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        packageinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn GetPackageInfoItems(&self) -> windows_core::Result<IAppxBundleManifestPackageInfoEnumerator> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetPackageInfoItems)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn GetHasCurrent<Identity: windows_core::IUnknownImpl + IAppxBundleManifestPackageInfoEnumerator_Impl>(this: *mut core::ffi::c_void, hascurrent: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestPackageInfoEnumerator_Impl::GetHasCurrent(this) {
+                    Ok(ok__) => {
+                        hascurrent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IAppxBundleManifestPackageInfoEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestPackageInfoEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrent: GetCurrent::<Identity>,
+            GetHasCurrent: GetHasCurrent::<Identity>,
+            MoveNext: MoveNext::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IAppxBundleManifestPackageInfoEnumerator {}
+windows_core::imp::define_interface!(IAppxBundleManifestReader, IAppxBundleManifestReader_Vtbl, 0xcf0ebbc1_cc99_4106_91eb_e67462e04fb0);
+windows_core::imp::interface_hierarchy!(IAppxBundleManifestReader, windows_core::IUnknown);
+impl IAppxBundleManifestReader {
+    pub unsafe fn GetPackageId(&self) -> windows_core::Result<IAppxManifestPackageId> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetPackageId)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn GetPackageInfoItems(&self) -> windows_core::Result<IAppxBundleManifestPackageInfoEnumerator> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetPackageInfoItems)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
     }
     #[cfg(feature = "Win32_System_Com")]
@@ -2166,6 +2861,56 @@ impl IAppxBundleManifestReader_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxBundleManifestReader_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxBundleManifestReader_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPackageId<Identity: windows_core::IUnknownImpl + IAppxBundleManifestReader_Impl>(this: *mut core::ffi::c_void, packageid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestReader_Impl::GetPackageId(this) {
+                    Ok(ok__) => {
+                        packageid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPackageInfoItems<Identity: windows_core::IUnknownImpl + IAppxBundleManifestReader_Impl>(this: *mut core::ffi::c_void, packageinfoitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestReader_Impl::GetPackageInfoItems(this) {
+                    Ok(ok__) => {
+                        packageinfoitems.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStream<Identity: windows_core::IUnknownImpl + IAppxBundleManifestReader_Impl>(this: *mut core::ffi::c_void, manifeststream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestReader_Impl::GetStream(this) {
+                    Ok(ok__) => {
+                        manifeststream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPackageId: GetPackageId::<Identity>,
+            GetPackageInfoItems: GetPackageInfoItems::<Identity>,
+            GetStream: GetStream::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxBundleManifestReader {}
 windows_core::imp::define_interface!(IAppxBundleManifestReader2, IAppxBundleManifestReader2_Vtbl, 0x5517df70_033f_4af2_8213_87d766805c02);
 windows_core::imp::interface_hierarchy!(IAppxBundleManifestReader2, windows_core::IUnknown);
@@ -2206,6 +2951,26 @@ impl IAppxBundleManifestReader2_Vtbl {
         iid == &<IAppxBundleManifestReader2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxBundleManifestReader2_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxBundleManifestReader2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetOptionalBundles<Identity: windows_core::IUnknownImpl + IAppxBundleManifestReader2_Impl>(this: *mut core::ffi::c_void, optionalbundles: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleManifestReader2_Impl::GetOptionalBundles(this) {
+                    Ok(ok__) => {
+                        optionalbundles.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetOptionalBundles: GetOptionalBundles::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IAppxBundleManifestReader2 {}
 windows_core::imp::define_interface!(IAppxBundleReader, IAppxBundleReader_Vtbl, 0xdd75b8c0_ba76_43b0_ae0f_68656a1dc5c8);
 windows_core::imp::interface_hierarchy!(IAppxBundleReader, windows_core::IUnknown);
@@ -2336,6 +3101,81 @@ impl IAppxBundleReader_Vtbl {
         iid == &<IAppxBundleReader as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxBundleReader_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxBundleReader_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFootprintFile<Identity: windows_core::IUnknownImpl + IAppxBundleReader_Impl>(this: *mut core::ffi::c_void, filetype: APPX_BUNDLE_FOOTPRINT_FILE_TYPE, footprintfile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleReader_Impl::GetFootprintFile(this, core::mem::transmute_copy(&filetype)) {
+                    Ok(ok__) => {
+                        footprintfile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetBlockMap<Identity: windows_core::IUnknownImpl + IAppxBundleReader_Impl>(this: *mut core::ffi::c_void, blockmapreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleReader_Impl::GetBlockMap(this) {
+                    Ok(ok__) => {
+                        blockmapreader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetManifest<Identity: windows_core::IUnknownImpl + IAppxBundleReader_Impl>(this: *mut core::ffi::c_void, manifestreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleReader_Impl::GetManifest(this) {
+                    Ok(ok__) => {
+                        manifestreader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPayloadPackages<Identity: windows_core::IUnknownImpl + IAppxBundleReader_Impl>(this: *mut core::ffi::c_void, payloadpackages: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleReader_Impl::GetPayloadPackages(this) {
+                    Ok(ok__) => {
+                        payloadpackages.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPayloadPackage<Identity: windows_core::IUnknownImpl + IAppxBundleReader_Impl>(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, payloadpackage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxBundleReader_Impl::GetPayloadPackage(this, core::mem::transmute(&filename)) {
+                    Ok(ok__) => {
+                        payloadpackage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFootprintFile: GetFootprintFile::<Identity>,
+            GetBlockMap: GetBlockMap::<Identity>,
+            GetManifest: GetManifest::<Identity>,
+            GetPayloadPackages: GetPayloadPackages::<Identity>,
+            GetPayloadPackage: GetPayloadPackage::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxBundleReader {}
 windows_core::imp::define_interface!(IAppxBundleWriter, IAppxBundleWriter_Vtbl, 0xec446fe8_bfec_4c64_ab4f_49f038f0c6d2);
 windows_core::imp::interface_hierarchy!(IAppxBundleWriter, windows_core::IUnknown);
@@ -2393,6 +3233,31 @@ impl IAppxBundleWriter_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxBundleWriter_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxBundleWriter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddPayloadPackage<Identity: windows_core::IUnknownImpl + IAppxBundleWriter_Impl>(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, packagestream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxBundleWriter_Impl::AddPayloadPackage(this, core::mem::transmute(&filename), core::mem::transmute_copy(&packagestream)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IAppxBundleWriter_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxBundleWriter_Impl::Close(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddPayloadPackage: AddPayloadPackage::<Identity>,
+            Close: Close::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxBundleWriter {}
 windows_core::imp::define_interface!(IAppxBundleWriter2, IAppxBundleWriter2_Vtbl, 0x6d8fe971_01cc_49a0_b685_233851279962);
 windows_core::imp::interface_hierarchy!(IAppxBundleWriter2, windows_core::IUnknown);
@@ -2435,6 +3300,24 @@ impl IAppxBundleWriter2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxBundleWriter2_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxBundleWriter2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddExternalPackageReference<Identity: windows_core::IUnknownImpl + IAppxBundleWriter2_Impl>(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, inputstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxBundleWriter2_Impl::AddExternalPackageReference(this, core::mem::transmute(&filename), core::mem::transmute_copy(&inputstream)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddExternalPackageReference: AddExternalPackageReference::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxBundleWriter2 {}
 windows_core::imp::define_interface!(IAppxBundleWriter3, IAppxBundleWriter3_Vtbl, 0xad711152_f969_4193_82d5_9ddf2786d21a);
 windows_core::imp::interface_hierarchy!(IAppxBundleWriter3, windows_core::IUnknown);
@@ -2495,6 +3378,31 @@ impl IAppxBundleWriter3_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxBundleWriter3_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxBundleWriter3_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddPackageReference<Identity: windows_core::IUnknownImpl + IAppxBundleWriter3_Impl>(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, inputstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxBundleWriter3_Impl::AddPackageReference(this, core::mem::transmute(&filename), core::mem::transmute_copy(&inputstream)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IAppxBundleWriter3_Impl>(this: *mut core::ffi::c_void, hashmethodstring: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxBundleWriter3_Impl::Close(this, core::mem::transmute(&hashmethodstring)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddPackageReference: AddPackageReference::<Identity>,
+            Close: Close::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxBundleWriter3 {}
 windows_core::imp::define_interface!(IAppxBundleWriter4, IAppxBundleWriter4_Vtbl, 0x9cd9d523_5009_4c01_9882_dc029fbd47a3);
 windows_core::imp::interface_hierarchy!(IAppxBundleWriter4, windows_core::IUnknown);
@@ -2580,6 +3488,38 @@ impl IAppxBundleWriter4_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxBundleWriter4_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxBundleWriter4_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddPayloadPackage<Identity: windows_core::IUnknownImpl + IAppxBundleWriter4_Impl>(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, packagestream: *mut core::ffi::c_void, isdefaultapplicablepackage: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxBundleWriter4_Impl::AddPayloadPackage(this, core::mem::transmute(&filename), core::mem::transmute_copy(&packagestream), core::mem::transmute_copy(&isdefaultapplicablepackage)).into()
+            }
+        }
+        unsafe extern "system" fn AddPackageReference<Identity: windows_core::IUnknownImpl + IAppxBundleWriter4_Impl>(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, inputstream: *mut core::ffi::c_void, isdefaultapplicablepackage: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxBundleWriter4_Impl::AddPackageReference(this, core::mem::transmute(&filename), core::mem::transmute_copy(&inputstream), core::mem::transmute_copy(&isdefaultapplicablepackage)).into()
+            }
+        }
+        unsafe extern "system" fn AddExternalPackageReference<Identity: windows_core::IUnknownImpl + IAppxBundleWriter4_Impl>(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, inputstream: *mut core::ffi::c_void, isdefaultapplicablepackage: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxBundleWriter4_Impl::AddExternalPackageReference(this, core::mem::transmute(&filename), core::mem::transmute_copy(&inputstream), core::mem::transmute_copy(&isdefaultapplicablepackage)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddPayloadPackage: AddPayloadPackage::<Identity>,
+            AddPackageReference: AddPackageReference::<Identity>,
+            AddExternalPackageReference: AddExternalPackageReference::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxBundleWriter4 {}
 windows_core::imp::define_interface!(IAppxContentGroup, IAppxContentGroup_Vtbl, 0x328f6468_c04f_4e3c_b6fa_6b8d27f3003a);
 windows_core::imp::interface_hierarchy!(IAppxContentGroup, windows_core::IUnknown);
@@ -2640,6 +3580,42 @@ impl IAppxContentGroup_Vtbl {
         iid == &<IAppxContentGroup as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxContentGroup_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxContentGroup_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IAppxContentGroup_Impl>(this: *mut core::ffi::c_void, groupname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxContentGroup_Impl::GetName(this) {
+                    Ok(ok__) => {
+                        groupname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFiles<Identity: windows_core::IUnknownImpl + IAppxContentGroup_Impl>(this: *mut core::ffi::c_void, enumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxContentGroup_Impl::GetFiles(this) {
+                    Ok(ok__) => {
+                        enumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetName: GetName::<Identity>,
+            GetFiles: GetFiles::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxContentGroup {}
 windows_core::imp::define_interface!(IAppxContentGroupFilesEnumerator, IAppxContentGroupFilesEnumerator_Vtbl, 0x1a09a2fd_7440_44eb_8c84_848205a6a1cc);
 windows_core::imp::interface_hierarchy!(IAppxContentGroupFilesEnumerator, windows_core::IUnknown);
@@ -2725,6 +3701,55 @@ impl IAppxContentGroupFilesEnumerator_Vtbl {
         iid == &<IAppxContentGroupFilesEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxContentGroupFilesEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxContentGroupFilesEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IAppxContentGroupFilesEnumerator_Impl>(this: *mut core::ffi::c_void, file: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxContentGroupFilesEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        file.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHasCurrent<Identity: windows_core::IUnknownImpl + IAppxContentGroupFilesEnumerator_Impl>(this: *mut core::ffi::c_void, hascurrent: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxContentGroupFilesEnumerator_Impl::GetHasCurrent(this) {
+                    Ok(ok__) => {
+                        hascurrent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IAppxContentGroupFilesEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxContentGroupFilesEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrent: GetCurrent::<Identity>,
+            GetHasCurrent: GetHasCurrent::<Identity>,
+            MoveNext: MoveNext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxContentGroupFilesEnumerator {}
 windows_core::imp::define_interface!(IAppxContentGroupMapReader, IAppxContentGroupMapReader_Vtbl, 0x418726d8_dd99_4f5d_9886_157add20de01);
 windows_core::imp::interface_hierarchy!(IAppxContentGroupMapReader, windows_core::IUnknown);
@@ -2789,14 +3814,50 @@ impl IAppxContentGroupMapReader_Vtbl {
         iid == &<IAppxContentGroupMapReader as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IAppxContentGroupMapReader {}
-windows_core::imp::define_interface!(IAppxContentGroupMapWriter, IAppxContentGroupMapWriter_Vtbl, 0xd07ab776_a9de_4798_8c14_3db31e687c78);
-windows_core::imp::interface_hierarchy!(IAppxContentGroupMapWriter, windows_core::IUnknown);
-impl IAppxContentGroupMapWriter {
-    pub unsafe fn AddAutomaticGroup<P0>(&self, groupname: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<windows_core::PCWSTR>,
-    {
+impl<Identity: windows_core::IUnknownImpl + IAppxContentGroupMapReader_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxContentGroupMapReader_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRequiredGroup<Identity: windows_core::IUnknownImpl + IAppxContentGroupMapReader_Impl>(this: *mut core::ffi::c_void, requiredgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxContentGroupMapReader_Impl::GetRequiredGroup(this) {
+                    Ok(ok__) => {
+                        requiredgroup.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAutomaticGroups<Identity: windows_core::IUnknownImpl + IAppxContentGroupMapReader_Impl>(this: *mut core::ffi::c_void, automaticgroupsenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxContentGroupMapReader_Impl::GetAutomaticGroups(this) {
+                    Ok(ok__) => {
+                        automaticgroupsenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetRequiredGroup: GetRequiredGroup::<Identity>,
+            GetAutomaticGroups: GetAutomaticGroups::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IAppxContentGroupMapReader {}
+windows_core::imp::define_interface!(IAppxContentGroupMapWriter, IAppxContentGroupMapWriter_Vtbl, 0xd07ab776_a9de_4798_8c14_3db31e687c78);
+windows_core::imp::interface_hierarchy!(IAppxContentGroupMapWriter, windows_core::IUnknown);
+impl IAppxContentGroupMapWriter {
+    pub unsafe fn AddAutomaticGroup<P0>(&self, groupname: P0) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::PCWSTR>,
+    {
         unsafe { (windows_core::Interface::vtable(self).AddAutomaticGroup)(windows_core::Interface::as_raw(self), groupname.param().abi()).ok() }
     }
     pub unsafe fn AddAutomaticFile<P0>(&self, filename: P0) -> windows_core::Result<()>
@@ -2853,6 +3914,37 @@ impl IAppxContentGroupMapWriter_Vtbl {
         iid == &<IAppxContentGroupMapWriter as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxContentGroupMapWriter_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxContentGroupMapWriter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddAutomaticGroup<Identity: windows_core::IUnknownImpl + IAppxContentGroupMapWriter_Impl>(this: *mut core::ffi::c_void, groupname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxContentGroupMapWriter_Impl::AddAutomaticGroup(this, core::mem::transmute(&groupname)).into()
+            }
+        }
+        unsafe extern "system" fn AddAutomaticFile<Identity: windows_core::IUnknownImpl + IAppxContentGroupMapWriter_Impl>(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxContentGroupMapWriter_Impl::AddAutomaticFile(this, core::mem::transmute(&filename)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IAppxContentGroupMapWriter_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxContentGroupMapWriter_Impl::Close(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddAutomaticGroup: AddAutomaticGroup::<Identity>,
+            AddAutomaticFile: AddAutomaticFile::<Identity>,
+            Close: Close::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxContentGroupMapWriter {}
 windows_core::imp::define_interface!(IAppxContentGroupsEnumerator, IAppxContentGroupsEnumerator_Vtbl, 0x3264e477_16d1_4d63_823e_7d2984696634);
 windows_core::imp::interface_hierarchy!(IAppxContentGroupsEnumerator, windows_core::IUnknown);
@@ -2938,6 +4030,55 @@ impl IAppxContentGroupsEnumerator_Vtbl {
         iid == &<IAppxContentGroupsEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxContentGroupsEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxContentGroupsEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IAppxContentGroupsEnumerator_Impl>(this: *mut core::ffi::c_void, stream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxContentGroupsEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        stream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHasCurrent<Identity: windows_core::IUnknownImpl + IAppxContentGroupsEnumerator_Impl>(this: *mut core::ffi::c_void, hascurrent: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxContentGroupsEnumerator_Impl::GetHasCurrent(this) {
+                    Ok(ok__) => {
+                        hascurrent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IAppxContentGroupsEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxContentGroupsEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrent: GetCurrent::<Identity>,
+            GetHasCurrent: GetHasCurrent::<Identity>,
+            MoveNext: MoveNext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxContentGroupsEnumerator {}
 windows_core::imp::define_interface!(IAppxDigestProvider, IAppxDigestProvider_Vtbl, 0x9fe2702b_7640_4659_8e6c_349e43c4cdbd);
 windows_core::imp::interface_hierarchy!(IAppxDigestProvider, windows_core::IUnknown);
@@ -2978,6 +4119,26 @@ impl IAppxDigestProvider_Vtbl {
         iid == &<IAppxDigestProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxDigestProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxDigestProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDigest<Identity: windows_core::IUnknownImpl + IAppxDigestProvider_Impl>(this: *mut core::ffi::c_void, digest: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxDigestProvider_Impl::GetDigest(this) {
+                    Ok(ok__) => {
+                        digest.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDigest: GetDigest::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IAppxDigestProvider {}
 windows_core::imp::define_interface!(IAppxEncryptedBundleWriter, IAppxEncryptedBundleWriter_Vtbl, 0x80b0902f_7bf0_4117_b8c6_4279ef81ee77);
 windows_core::imp::interface_hierarchy!(IAppxEncryptedBundleWriter, windows_core::IUnknown);
@@ -3035,6 +4196,31 @@ impl IAppxEncryptedBundleWriter_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxEncryptedBundleWriter_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxEncryptedBundleWriter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddPayloadPackageEncrypted<Identity: windows_core::IUnknownImpl + IAppxEncryptedBundleWriter_Impl>(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, packagestream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxEncryptedBundleWriter_Impl::AddPayloadPackageEncrypted(this, core::mem::transmute(&filename), core::mem::transmute_copy(&packagestream)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IAppxEncryptedBundleWriter_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxEncryptedBundleWriter_Impl::Close(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddPayloadPackageEncrypted: AddPayloadPackageEncrypted::<Identity>,
+            Close: Close::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxEncryptedBundleWriter {}
 windows_core::imp::define_interface!(IAppxEncryptedBundleWriter2, IAppxEncryptedBundleWriter2_Vtbl, 0xe644be82_f0fa_42b8_a956_8d1cb48ee379);
 windows_core::imp::interface_hierarchy!(IAppxEncryptedBundleWriter2, windows_core::IUnknown);
@@ -3077,6 +4263,24 @@ impl IAppxEncryptedBundleWriter2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxEncryptedBundleWriter2_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxEncryptedBundleWriter2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddExternalPackageReference<Identity: windows_core::IUnknownImpl + IAppxEncryptedBundleWriter2_Impl>(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, inputstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxEncryptedBundleWriter2_Impl::AddExternalPackageReference(this, core::mem::transmute(&filename), core::mem::transmute_copy(&inputstream)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddExternalPackageReference: AddExternalPackageReference::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxEncryptedBundleWriter2 {}
 windows_core::imp::define_interface!(IAppxEncryptedBundleWriter3, IAppxEncryptedBundleWriter3_Vtbl, 0x0d34deb3_5cae_4dd3_977c_504932a51d31);
 windows_core::imp::interface_hierarchy!(IAppxEncryptedBundleWriter3, windows_core::IUnknown);
@@ -3142,6 +4346,31 @@ impl IAppxEncryptedBundleWriter3_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxEncryptedBundleWriter3_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxEncryptedBundleWriter3_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddPayloadPackageEncrypted<Identity: windows_core::IUnknownImpl + IAppxEncryptedBundleWriter3_Impl>(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, packagestream: *mut core::ffi::c_void, isdefaultapplicablepackage: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxEncryptedBundleWriter3_Impl::AddPayloadPackageEncrypted(this, core::mem::transmute(&filename), core::mem::transmute_copy(&packagestream), core::mem::transmute_copy(&isdefaultapplicablepackage)).into()
+            }
+        }
+        unsafe extern "system" fn AddExternalPackageReference<Identity: windows_core::IUnknownImpl + IAppxEncryptedBundleWriter3_Impl>(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, inputstream: *mut core::ffi::c_void, isdefaultapplicablepackage: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxEncryptedBundleWriter3_Impl::AddExternalPackageReference(this, core::mem::transmute(&filename), core::mem::transmute_copy(&inputstream), core::mem::transmute_copy(&isdefaultapplicablepackage)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddPayloadPackageEncrypted: AddPayloadPackageEncrypted::<Identity>,
+            AddExternalPackageReference: AddExternalPackageReference::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxEncryptedBundleWriter3 {}
 windows_core::imp::define_interface!(IAppxEncryptedPackageWriter, IAppxEncryptedPackageWriter_Vtbl, 0xf43d0b0b_1379_40e2_9b29_682ea2bf42af);
 windows_core::imp::interface_hierarchy!(IAppxEncryptedPackageWriter, windows_core::IUnknown);
@@ -3199,6 +4428,31 @@ impl IAppxEncryptedPackageWriter_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxEncryptedPackageWriter_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxEncryptedPackageWriter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddPayloadFileEncrypted<Identity: windows_core::IUnknownImpl + IAppxEncryptedPackageWriter_Impl>(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, compressionoption: APPX_COMPRESSION_OPTION, inputstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxEncryptedPackageWriter_Impl::AddPayloadFileEncrypted(this, core::mem::transmute(&filename), core::mem::transmute_copy(&compressionoption), core::mem::transmute_copy(&inputstream)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IAppxEncryptedPackageWriter_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxEncryptedPackageWriter_Impl::Close(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddPayloadFileEncrypted: AddPayloadFileEncrypted::<Identity>,
+            Close: Close::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxEncryptedPackageWriter {}
 windows_core::imp::define_interface!(IAppxEncryptedPackageWriter2, IAppxEncryptedPackageWriter2_Vtbl, 0x3e475447_3a25_40b5_8ad2_f953ae50c92d);
 windows_core::imp::interface_hierarchy!(IAppxEncryptedPackageWriter2, windows_core::IUnknown);
@@ -3237,6 +4491,24 @@ impl IAppxEncryptedPackageWriter2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxEncryptedPackageWriter2_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxEncryptedPackageWriter2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddPayloadFilesEncrypted<Identity: windows_core::IUnknownImpl + IAppxEncryptedPackageWriter2_Impl>(this: *mut core::ffi::c_void, filecount: u32, payloadfiles: *const APPX_PACKAGE_WRITER_PAYLOAD_STREAM, memorylimit: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxEncryptedPackageWriter2_Impl::AddPayloadFilesEncrypted(this, core::mem::transmute_copy(&filecount), core::mem::transmute_copy(&payloadfiles), core::mem::transmute_copy(&memorylimit)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddPayloadFilesEncrypted: AddPayloadFilesEncrypted::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxEncryptedPackageWriter2 {}
 windows_core::imp::define_interface!(IAppxEncryptionFactory, IAppxEncryptionFactory_Vtbl, 0x80e8e04d_8c88_44ae_a011_7cadf6fb2e72);
 windows_core::imp::interface_hierarchy!(IAppxEncryptionFactory, windows_core::IUnknown);
@@ -3455,6 +4727,97 @@ impl IAppxEncryptionFactory_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxEncryptionFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxEncryptionFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EncryptPackage<Identity: windows_core::IUnknownImpl + IAppxEncryptionFactory_Impl>(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS, keyinfo: *const APPX_KEY_INFO, exemptedfiles: *const APPX_ENCRYPTED_EXEMPTIONS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxEncryptionFactory_Impl::EncryptPackage(this, core::mem::transmute_copy(&inputstream), core::mem::transmute_copy(&outputstream), core::mem::transmute_copy(&settings), core::mem::transmute_copy(&keyinfo), core::mem::transmute_copy(&exemptedfiles)).into()
+            }
+        }
+        unsafe extern "system" fn DecryptPackage<Identity: windows_core::IUnknownImpl + IAppxEncryptionFactory_Impl>(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, keyinfo: *const APPX_KEY_INFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxEncryptionFactory_Impl::DecryptPackage(this, core::mem::transmute_copy(&inputstream), core::mem::transmute_copy(&outputstream), core::mem::transmute_copy(&keyinfo)).into()
+            }
+        }
+        unsafe extern "system" fn CreateEncryptedPackageWriter<Identity: windows_core::IUnknownImpl + IAppxEncryptionFactory_Impl>(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, manifeststream: *mut core::ffi::c_void, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS, keyinfo: *const APPX_KEY_INFO, exemptedfiles: *const APPX_ENCRYPTED_EXEMPTIONS, packagewriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxEncryptionFactory_Impl::CreateEncryptedPackageWriter(this, core::mem::transmute_copy(&outputstream), core::mem::transmute_copy(&manifeststream), core::mem::transmute_copy(&settings), core::mem::transmute_copy(&keyinfo), core::mem::transmute_copy(&exemptedfiles)) {
+                    Ok(ok__) => {
+                        packagewriter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateEncryptedPackageReader<Identity: windows_core::IUnknownImpl + IAppxEncryptionFactory_Impl>(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, keyinfo: *const APPX_KEY_INFO, packagereader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxEncryptionFactory_Impl::CreateEncryptedPackageReader(this, core::mem::transmute_copy(&inputstream), core::mem::transmute_copy(&keyinfo)) {
+                    Ok(ok__) => {
+                        packagereader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EncryptBundle<Identity: windows_core::IUnknownImpl + IAppxEncryptionFactory_Impl>(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS, keyinfo: *const APPX_KEY_INFO, exemptedfiles: *const APPX_ENCRYPTED_EXEMPTIONS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxEncryptionFactory_Impl::EncryptBundle(this, core::mem::transmute_copy(&inputstream), core::mem::transmute_copy(&outputstream), core::mem::transmute_copy(&settings), core::mem::transmute_copy(&keyinfo), core::mem::transmute_copy(&exemptedfiles)).into()
+            }
+        }
+        unsafe extern "system" fn DecryptBundle<Identity: windows_core::IUnknownImpl + IAppxEncryptionFactory_Impl>(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, keyinfo: *const APPX_KEY_INFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxEncryptionFactory_Impl::DecryptBundle(this, core::mem::transmute_copy(&inputstream), core::mem::transmute_copy(&outputstream), core::mem::transmute_copy(&keyinfo)).into()
+            }
+        }
+        unsafe extern "system" fn CreateEncryptedBundleWriter<Identity: windows_core::IUnknownImpl + IAppxEncryptionFactory_Impl>(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, bundleversion: u64, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS, keyinfo: *const APPX_KEY_INFO, exemptedfiles: *const APPX_ENCRYPTED_EXEMPTIONS, bundlewriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxEncryptionFactory_Impl::CreateEncryptedBundleWriter(this, core::mem::transmute_copy(&outputstream), core::mem::transmute_copy(&bundleversion), core::mem::transmute_copy(&settings), core::mem::transmute_copy(&keyinfo), core::mem::transmute_copy(&exemptedfiles)) {
+                    Ok(ok__) => {
+                        bundlewriter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateEncryptedBundleReader<Identity: windows_core::IUnknownImpl + IAppxEncryptionFactory_Impl>(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, keyinfo: *const APPX_KEY_INFO, bundlereader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxEncryptionFactory_Impl::CreateEncryptedBundleReader(this, core::mem::transmute_copy(&inputstream), core::mem::transmute_copy(&keyinfo)) {
+                    Ok(ok__) => {
+                        bundlereader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EncryptPackage: EncryptPackage::<Identity>,
+            DecryptPackage: DecryptPackage::<Identity>,
+            CreateEncryptedPackageWriter: CreateEncryptedPackageWriter::<Identity>,
+            CreateEncryptedPackageReader: CreateEncryptedPackageReader::<Identity>,
+            EncryptBundle: EncryptBundle::<Identity>,
+            DecryptBundle: DecryptBundle::<Identity>,
+            CreateEncryptedBundleWriter: CreateEncryptedBundleWriter::<Identity>,
+            CreateEncryptedBundleReader: CreateEncryptedBundleReader::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxEncryptionFactory {}
 windows_core::imp::define_interface!(IAppxEncryptionFactory2, IAppxEncryptionFactory2_Vtbl, 0xc1b11eee_c4ba_4ab2_a55d_d015fe8ff64f);
 windows_core::imp::interface_hierarchy!(IAppxEncryptionFactory2, windows_core::IUnknown);
@@ -3507,6 +4870,30 @@ impl IAppxEncryptionFactory2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxEncryptionFactory2_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxEncryptionFactory2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateEncryptedPackageWriter<Identity: windows_core::IUnknownImpl + IAppxEncryptionFactory2_Impl>(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, manifeststream: *mut core::ffi::c_void, contentgroupmapstream: *mut core::ffi::c_void, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS, keyinfo: *const APPX_KEY_INFO, exemptedfiles: *const APPX_ENCRYPTED_EXEMPTIONS, packagewriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxEncryptionFactory2_Impl::CreateEncryptedPackageWriter(this, core::mem::transmute_copy(&outputstream), core::mem::transmute_copy(&manifeststream), core::mem::transmute_copy(&contentgroupmapstream), core::mem::transmute_copy(&settings), core::mem::transmute_copy(&keyinfo), core::mem::transmute_copy(&exemptedfiles)) {
+                    Ok(ok__) => {
+                        packagewriter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateEncryptedPackageWriter: CreateEncryptedPackageWriter::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxEncryptionFactory2 {}
 windows_core::imp::define_interface!(IAppxEncryptionFactory3, IAppxEncryptionFactory3_Vtbl, 0x09edca37_cd64_47d6_b7e8_1cb11d4f7e05);
 windows_core::imp::interface_hierarchy!(IAppxEncryptionFactory3, windows_core::IUnknown);
@@ -3630,25 +5017,76 @@ impl IAppxEncryptionFactory3_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
-impl windows_core::RuntimeName for IAppxEncryptionFactory3 {}
-windows_core::imp::define_interface!(IAppxEncryptionFactory4, IAppxEncryptionFactory4_Vtbl, 0xa879611f_12fd_41fe_85d5_06ae779bbaf5);
-windows_core::imp::interface_hierarchy!(IAppxEncryptionFactory4, windows_core::IUnknown);
-impl IAppxEncryptionFactory4 {
-    #[cfg(feature = "Win32_System_Com")]
-    pub unsafe fn EncryptPackage<P0, P1>(&self, inputstream: P0, outputstream: P1, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS2, keyinfo: *const APPX_KEY_INFO, exemptedfiles: *const APPX_ENCRYPTED_EXEMPTIONS, memorylimit: u64) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<super::super::super::System::Com::IStream>,
-        P1: windows_core::Param<super::super::super::System::Com::IStream>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).EncryptPackage)(windows_core::Interface::as_raw(self), inputstream.param().abi(), outputstream.param().abi(), core::mem::transmute(settings), keyinfo, exemptedfiles, memorylimit).ok() }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IAppxEncryptionFactory4_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    #[cfg(feature = "Win32_System_Com")]
-    pub EncryptPackage: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void, *const APPX_ENCRYPTED_PACKAGE_SETTINGS2, *const APPX_KEY_INFO, *const APPX_ENCRYPTED_EXEMPTIONS, u64) -> windows_core::HRESULT,
+impl<Identity: windows_core::IUnknownImpl + IAppxEncryptionFactory3_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxEncryptionFactory3_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EncryptPackage<Identity: windows_core::IUnknownImpl + IAppxEncryptionFactory3_Impl>(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS2, keyinfo: *const APPX_KEY_INFO, exemptedfiles: *const APPX_ENCRYPTED_EXEMPTIONS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxEncryptionFactory3_Impl::EncryptPackage(this, core::mem::transmute_copy(&inputstream), core::mem::transmute_copy(&outputstream), core::mem::transmute_copy(&settings), core::mem::transmute_copy(&keyinfo), core::mem::transmute_copy(&exemptedfiles)).into()
+            }
+        }
+        unsafe extern "system" fn CreateEncryptedPackageWriter<Identity: windows_core::IUnknownImpl + IAppxEncryptionFactory3_Impl>(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, manifeststream: *mut core::ffi::c_void, contentgroupmapstream: *mut core::ffi::c_void, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS2, keyinfo: *const APPX_KEY_INFO, exemptedfiles: *const APPX_ENCRYPTED_EXEMPTIONS, packagewriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxEncryptionFactory3_Impl::CreateEncryptedPackageWriter(this, core::mem::transmute_copy(&outputstream), core::mem::transmute_copy(&manifeststream), core::mem::transmute_copy(&contentgroupmapstream), core::mem::transmute_copy(&settings), core::mem::transmute_copy(&keyinfo), core::mem::transmute_copy(&exemptedfiles)) {
+                    Ok(ok__) => {
+                        packagewriter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EncryptBundle<Identity: windows_core::IUnknownImpl + IAppxEncryptionFactory3_Impl>(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS2, keyinfo: *const APPX_KEY_INFO, exemptedfiles: *const APPX_ENCRYPTED_EXEMPTIONS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxEncryptionFactory3_Impl::EncryptBundle(this, core::mem::transmute_copy(&inputstream), core::mem::transmute_copy(&outputstream), core::mem::transmute_copy(&settings), core::mem::transmute_copy(&keyinfo), core::mem::transmute_copy(&exemptedfiles)).into()
+            }
+        }
+        unsafe extern "system" fn CreateEncryptedBundleWriter<Identity: windows_core::IUnknownImpl + IAppxEncryptionFactory3_Impl>(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, bundleversion: u64, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS2, keyinfo: *const APPX_KEY_INFO, exemptedfiles: *const APPX_ENCRYPTED_EXEMPTIONS, bundlewriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxEncryptionFactory3_Impl::CreateEncryptedBundleWriter(this, core::mem::transmute_copy(&outputstream), core::mem::transmute_copy(&bundleversion), core::mem::transmute_copy(&settings), core::mem::transmute_copy(&keyinfo), core::mem::transmute_copy(&exemptedfiles)) {
+                    Ok(ok__) => {
+                        bundlewriter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EncryptPackage: EncryptPackage::<Identity>,
+            CreateEncryptedPackageWriter: CreateEncryptedPackageWriter::<Identity>,
+            EncryptBundle: EncryptBundle::<Identity>,
+            CreateEncryptedBundleWriter: CreateEncryptedBundleWriter::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::RuntimeName for IAppxEncryptionFactory3 {}
+windows_core::imp::define_interface!(IAppxEncryptionFactory4, IAppxEncryptionFactory4_Vtbl, 0xa879611f_12fd_41fe_85d5_06ae779bbaf5);
+windows_core::imp::interface_hierarchy!(IAppxEncryptionFactory4, windows_core::IUnknown);
+impl IAppxEncryptionFactory4 {
+    #[cfg(feature = "Win32_System_Com")]
+    pub unsafe fn EncryptPackage<P0, P1>(&self, inputstream: P0, outputstream: P1, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS2, keyinfo: *const APPX_KEY_INFO, exemptedfiles: *const APPX_ENCRYPTED_EXEMPTIONS, memorylimit: u64) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<super::super::super::System::Com::IStream>,
+        P1: windows_core::Param<super::super::super::System::Com::IStream>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).EncryptPackage)(windows_core::Interface::as_raw(self), inputstream.param().abi(), outputstream.param().abi(), core::mem::transmute(settings), keyinfo, exemptedfiles, memorylimit).ok() }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IAppxEncryptionFactory4_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    #[cfg(feature = "Win32_System_Com")]
+    pub EncryptPackage: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void, *const APPX_ENCRYPTED_PACKAGE_SETTINGS2, *const APPX_KEY_INFO, *const APPX_ENCRYPTED_EXEMPTIONS, u64) -> windows_core::HRESULT,
     #[cfg(not(feature = "Win32_System_Com"))]
     EncryptPackage: usize,
 }
@@ -3672,6 +5110,21 @@ impl IAppxEncryptionFactory4_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxEncryptionFactory4_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxEncryptionFactory4_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EncryptPackage<Identity: windows_core::IUnknownImpl + IAppxEncryptionFactory4_Impl>(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS2, keyinfo: *const APPX_KEY_INFO, exemptedfiles: *const APPX_ENCRYPTED_EXEMPTIONS, memorylimit: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxEncryptionFactory4_Impl::EncryptPackage(this, core::mem::transmute_copy(&inputstream), core::mem::transmute_copy(&outputstream), core::mem::transmute_copy(&settings), core::mem::transmute_copy(&keyinfo), core::mem::transmute_copy(&exemptedfiles), core::mem::transmute_copy(&memorylimit)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, EncryptPackage: EncryptPackage::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxEncryptionFactory4 {}
 windows_core::imp::define_interface!(IAppxEncryptionFactory5, IAppxEncryptionFactory5_Vtbl, 0x68d6e77a_f446_480f_b0f0_d91a24c60746);
 windows_core::imp::interface_hierarchy!(IAppxEncryptionFactory5, windows_core::IUnknown);
@@ -3755,6 +5208,43 @@ impl IAppxEncryptionFactory5_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxEncryptionFactory5_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxEncryptionFactory5_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateEncryptedPackageReader2<Identity: windows_core::IUnknownImpl + IAppxEncryptionFactory5_Impl>(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, keyinfo: *const APPX_KEY_INFO, expecteddigest: windows_core::PCWSTR, packagereader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxEncryptionFactory5_Impl::CreateEncryptedPackageReader2(this, core::mem::transmute_copy(&inputstream), core::mem::transmute_copy(&keyinfo), core::mem::transmute(&expecteddigest)) {
+                    Ok(ok__) => {
+                        packagereader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateEncryptedBundleReader2<Identity: windows_core::IUnknownImpl + IAppxEncryptionFactory5_Impl>(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, keyinfo: *const APPX_KEY_INFO, expecteddigest: windows_core::PCWSTR, bundlereader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxEncryptionFactory5_Impl::CreateEncryptedBundleReader2(this, core::mem::transmute_copy(&inputstream), core::mem::transmute_copy(&keyinfo), core::mem::transmute(&expecteddigest)) {
+                    Ok(ok__) => {
+                        bundlereader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateEncryptedPackageReader2: CreateEncryptedPackageReader2::<Identity>,
+            CreateEncryptedBundleReader2: CreateEncryptedBundleReader2::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxEncryptionFactory5 {}
 windows_core::imp::define_interface!(IAppxFactory, IAppxFactory_Vtbl, 0xbeb94909_e451_438b_b5a7_d79e767b75d8);
 windows_core::imp::interface_hierarchy!(IAppxFactory, windows_core::IUnknown);
@@ -3921,6 +5411,82 @@ impl IAppxFactory_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreatePackageWriter<Identity: windows_core::IUnknownImpl + IAppxFactory_Impl>(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, settings: *const APPX_PACKAGE_SETTINGS, packagewriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxFactory_Impl::CreatePackageWriter(this, core::mem::transmute_copy(&outputstream), core::mem::transmute_copy(&settings)) {
+                    Ok(ok__) => {
+                        packagewriter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreatePackageReader<Identity: windows_core::IUnknownImpl + IAppxFactory_Impl>(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, packagereader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxFactory_Impl::CreatePackageReader(this, core::mem::transmute_copy(&inputstream)) {
+                    Ok(ok__) => {
+                        packagereader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateManifestReader<Identity: windows_core::IUnknownImpl + IAppxFactory_Impl>(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, manifestreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxFactory_Impl::CreateManifestReader(this, core::mem::transmute_copy(&inputstream)) {
+                    Ok(ok__) => {
+                        manifestreader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateBlockMapReader<Identity: windows_core::IUnknownImpl + IAppxFactory_Impl>(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, blockmapreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxFactory_Impl::CreateBlockMapReader(this, core::mem::transmute_copy(&inputstream)) {
+                    Ok(ok__) => {
+                        blockmapreader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateValidatedBlockMapReader<Identity: windows_core::IUnknownImpl + IAppxFactory_Impl>(this: *mut core::ffi::c_void, blockmapstream: *mut core::ffi::c_void, signaturefilename: windows_core::PCWSTR, blockmapreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxFactory_Impl::CreateValidatedBlockMapReader(this, core::mem::transmute_copy(&blockmapstream), core::mem::transmute(&signaturefilename)) {
+                    Ok(ok__) => {
+                        blockmapreader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreatePackageWriter: CreatePackageWriter::<Identity>,
+            CreatePackageReader: CreatePackageReader::<Identity>,
+            CreateManifestReader: CreateManifestReader::<Identity>,
+            CreateBlockMapReader: CreateBlockMapReader::<Identity>,
+            CreateValidatedBlockMapReader: CreateValidatedBlockMapReader::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxFactory {}
 windows_core::imp::define_interface!(IAppxFactory2, IAppxFactory2_Vtbl, 0xf1346df2_c282_4e22_b918_743a929a8d55);
 windows_core::imp::interface_hierarchy!(IAppxFactory2, windows_core::IUnknown);
@@ -4030,6 +5596,56 @@ impl IAppxFactory2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxFactory2_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxFactory2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateContentGroupMapReader<Identity: windows_core::IUnknownImpl + IAppxFactory2_Impl>(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, contentgroupmapreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxFactory2_Impl::CreateContentGroupMapReader(this, core::mem::transmute_copy(&inputstream)) {
+                    Ok(ok__) => {
+                        contentgroupmapreader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSourceContentGroupMapReader<Identity: windows_core::IUnknownImpl + IAppxFactory2_Impl>(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, reader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxFactory2_Impl::CreateSourceContentGroupMapReader(this, core::mem::transmute_copy(&inputstream)) {
+                    Ok(ok__) => {
+                        reader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateContentGroupMapWriter<Identity: windows_core::IUnknownImpl + IAppxFactory2_Impl>(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, contentgroupmapwriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxFactory2_Impl::CreateContentGroupMapWriter(this, core::mem::transmute_copy(&stream)) {
+                    Ok(ok__) => {
+                        contentgroupmapwriter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateContentGroupMapReader: CreateContentGroupMapReader::<Identity>,
+            CreateSourceContentGroupMapReader: CreateSourceContentGroupMapReader::<Identity>,
+            CreateContentGroupMapWriter: CreateContentGroupMapWriter::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxFactory2 {}
 windows_core::imp::define_interface!(IAppxFactory3, IAppxFactory3_Vtbl, 0x776b2c05_e21d_4e24_ba1a_cd529a8bfdbb);
 windows_core::imp::interface_hierarchy!(IAppxFactory3, windows_core::IUnknown);
@@ -4142,6 +5758,56 @@ impl IAppxFactory3_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxFactory3_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxFactory3_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreatePackageReader2<Identity: windows_core::IUnknownImpl + IAppxFactory3_Impl>(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, expecteddigest: windows_core::PCWSTR, packagereader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxFactory3_Impl::CreatePackageReader2(this, core::mem::transmute_copy(&inputstream), core::mem::transmute(&expecteddigest)) {
+                    Ok(ok__) => {
+                        packagereader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateManifestReader2<Identity: windows_core::IUnknownImpl + IAppxFactory3_Impl>(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, expecteddigest: windows_core::PCWSTR, manifestreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxFactory3_Impl::CreateManifestReader2(this, core::mem::transmute_copy(&inputstream), core::mem::transmute(&expecteddigest)) {
+                    Ok(ok__) => {
+                        manifestreader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateAppInstallerReader<Identity: windows_core::IUnknownImpl + IAppxFactory3_Impl>(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, expecteddigest: windows_core::PCWSTR, appinstallerreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxFactory3_Impl::CreateAppInstallerReader(this, core::mem::transmute_copy(&inputstream), core::mem::transmute(&expecteddigest)) {
+                    Ok(ok__) => {
+                        appinstallerreader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreatePackageReader2: CreatePackageReader2::<Identity>,
+            CreateManifestReader2: CreateManifestReader2::<Identity>,
+            CreateAppInstallerReader: CreateAppInstallerReader::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxFactory3 {}
 windows_core::imp::define_interface!(IAppxFile, IAppxFile_Vtbl, 0x91df827b_94fd_468f_827b_57f41b2f6f2e);
 windows_core::imp::interface_hierarchy!(IAppxFile, windows_core::IUnknown);
@@ -4276,29 +5942,105 @@ impl IAppxFile_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
-impl windows_core::RuntimeName for IAppxFile {}
-windows_core::imp::define_interface!(IAppxFilesEnumerator, IAppxFilesEnumerator_Vtbl, 0xf007eeaf_9831_411c_9847_917cdc62d1fe);
-windows_core::imp::interface_hierarchy!(IAppxFilesEnumerator, windows_core::IUnknown);
-impl IAppxFilesEnumerator {
-    pub unsafe fn GetCurrent(&self) -> windows_core::Result<IAppxFile> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetCurrent)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn GetHasCurrent(&self) -> windows_core::Result<windows_core::BOOL> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetHasCurrent)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+impl<Identity: windows_core::IUnknownImpl + IAppxFile_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxFile_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCompressionOption<Identity: windows_core::IUnknownImpl + IAppxFile_Impl>(this: *mut core::ffi::c_void, compressionoption: *mut APPX_COMPRESSION_OPTION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxFile_Impl::GetCompressionOption(this) {
+                    Ok(ok__) => {
+                        compressionoption.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn MoveNext(&self) -> windows_core::Result<windows_core::BOOL> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).MoveNext)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        unsafe extern "system" fn GetContentType<Identity: windows_core::IUnknownImpl + IAppxFile_Impl>(this: *mut core::ffi::c_void, contenttype: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxFile_Impl::GetContentType(this) {
+                    Ok(ok__) => {
+                        contenttype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-}
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IAppxFile_Impl>(this: *mut core::ffi::c_void, filename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxFile_Impl::GetName(this) {
+                    Ok(ok__) => {
+                        filename.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSize<Identity: windows_core::IUnknownImpl + IAppxFile_Impl>(this: *mut core::ffi::c_void, size: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxFile_Impl::GetSize(this) {
+                    Ok(ok__) => {
+                        size.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStream<Identity: windows_core::IUnknownImpl + IAppxFile_Impl>(this: *mut core::ffi::c_void, stream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxFile_Impl::GetStream(this) {
+                    Ok(ok__) => {
+                        stream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCompressionOption: GetCompressionOption::<Identity>,
+            GetContentType: GetContentType::<Identity>,
+            GetName: GetName::<Identity>,
+            GetSize: GetSize::<Identity>,
+            GetStream: GetStream::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::RuntimeName for IAppxFile {}
+windows_core::imp::define_interface!(IAppxFilesEnumerator, IAppxFilesEnumerator_Vtbl, 0xf007eeaf_9831_411c_9847_917cdc62d1fe);
+windows_core::imp::interface_hierarchy!(IAppxFilesEnumerator, windows_core::IUnknown);
+impl IAppxFilesEnumerator {
+    pub unsafe fn GetCurrent(&self) -> windows_core::Result<IAppxFile> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetCurrent)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn GetHasCurrent(&self) -> windows_core::Result<windows_core::BOOL> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetHasCurrent)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn MoveNext(&self) -> windows_core::Result<windows_core::BOOL> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).MoveNext)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IAppxFilesEnumerator_Vtbl {
@@ -4361,6 +6103,55 @@ impl IAppxFilesEnumerator_Vtbl {
         iid == &<IAppxFilesEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxFilesEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxFilesEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IAppxFilesEnumerator_Impl>(this: *mut core::ffi::c_void, file: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxFilesEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        file.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHasCurrent<Identity: windows_core::IUnknownImpl + IAppxFilesEnumerator_Impl>(this: *mut core::ffi::c_void, hascurrent: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxFilesEnumerator_Impl::GetHasCurrent(this) {
+                    Ok(ok__) => {
+                        hascurrent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IAppxFilesEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxFilesEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrent: GetCurrent::<Identity>,
+            GetHasCurrent: GetHasCurrent::<Identity>,
+            MoveNext: MoveNext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxFilesEnumerator {}
 windows_core::imp::define_interface!(IAppxManifestApplication, IAppxManifestApplication_Vtbl, 0x5da89bf4_3773_46be_b650_7e744863b7e8);
 windows_core::imp::interface_hierarchy!(IAppxManifestApplication, windows_core::IUnknown);
@@ -4428,6 +6219,42 @@ impl IAppxManifestApplication_Vtbl {
         iid == &<IAppxManifestApplication as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestApplication_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestApplication_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStringValue<Identity: windows_core::IUnknownImpl + IAppxManifestApplication_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestApplication_Impl::GetStringValue(this, core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAppUserModelId<Identity: windows_core::IUnknownImpl + IAppxManifestApplication_Impl>(this: *mut core::ffi::c_void, appusermodelid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestApplication_Impl::GetAppUserModelId(this) {
+                    Ok(ok__) => {
+                        appusermodelid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStringValue: GetStringValue::<Identity>,
+            GetAppUserModelId: GetAppUserModelId::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestApplication {}
 windows_core::imp::define_interface!(IAppxManifestApplicationsEnumerator, IAppxManifestApplicationsEnumerator_Vtbl, 0x9eb8a55a_f04b_4d0d_808d_686185d4847a);
 windows_core::imp::interface_hierarchy!(IAppxManifestApplicationsEnumerator, windows_core::IUnknown);
@@ -4513,6 +6340,55 @@ impl IAppxManifestApplicationsEnumerator_Vtbl {
         iid == &<IAppxManifestApplicationsEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestApplicationsEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestApplicationsEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestApplicationsEnumerator_Impl>(this: *mut core::ffi::c_void, application: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestApplicationsEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        application.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHasCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestApplicationsEnumerator_Impl>(this: *mut core::ffi::c_void, hascurrent: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestApplicationsEnumerator_Impl::GetHasCurrent(this) {
+                    Ok(ok__) => {
+                        hascurrent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IAppxManifestApplicationsEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestApplicationsEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrent: GetCurrent::<Identity>,
+            GetHasCurrent: GetHasCurrent::<Identity>,
+            MoveNext: MoveNext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestApplicationsEnumerator {}
 windows_core::imp::define_interface!(IAppxManifestCapabilitiesEnumerator, IAppxManifestCapabilitiesEnumerator_Vtbl, 0x11d22258_f470_42c1_b291_8361c5437e41);
 windows_core::imp::interface_hierarchy!(IAppxManifestCapabilitiesEnumerator, windows_core::IUnknown);
@@ -4598,6 +6474,55 @@ impl IAppxManifestCapabilitiesEnumerator_Vtbl {
         iid == &<IAppxManifestCapabilitiesEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestCapabilitiesEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestCapabilitiesEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestCapabilitiesEnumerator_Impl>(this: *mut core::ffi::c_void, capability: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestCapabilitiesEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        capability.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHasCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestCapabilitiesEnumerator_Impl>(this: *mut core::ffi::c_void, hascurrent: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestCapabilitiesEnumerator_Impl::GetHasCurrent(this) {
+                    Ok(ok__) => {
+                        hascurrent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IAppxManifestCapabilitiesEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestCapabilitiesEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrent: GetCurrent::<Identity>,
+            GetHasCurrent: GetHasCurrent::<Identity>,
+            MoveNext: MoveNext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestCapabilitiesEnumerator {}
 windows_core::imp::define_interface!(IAppxManifestDeviceCapabilitiesEnumerator, IAppxManifestDeviceCapabilitiesEnumerator_Vtbl, 0x30204541_427b_4a1c_bacf_655bf463a540);
 windows_core::imp::interface_hierarchy!(IAppxManifestDeviceCapabilitiesEnumerator, windows_core::IUnknown);
@@ -4683,6 +6608,55 @@ impl IAppxManifestDeviceCapabilitiesEnumerator_Vtbl {
         iid == &<IAppxManifestDeviceCapabilitiesEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestDeviceCapabilitiesEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestDeviceCapabilitiesEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestDeviceCapabilitiesEnumerator_Impl>(this: *mut core::ffi::c_void, devicecapability: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestDeviceCapabilitiesEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        devicecapability.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHasCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestDeviceCapabilitiesEnumerator_Impl>(this: *mut core::ffi::c_void, hascurrent: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestDeviceCapabilitiesEnumerator_Impl::GetHasCurrent(this) {
+                    Ok(ok__) => {
+                        hascurrent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IAppxManifestDeviceCapabilitiesEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestDeviceCapabilitiesEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrent: GetCurrent::<Identity>,
+            GetHasCurrent: GetHasCurrent::<Identity>,
+            MoveNext: MoveNext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestDeviceCapabilitiesEnumerator {}
 windows_core::imp::define_interface!(IAppxManifestDriverConstraint, IAppxManifestDriverConstraint_Vtbl, 0xc031bee4_bbcc_48ea_a237_c34045c80a07);
 windows_core::imp::interface_hierarchy!(IAppxManifestDriverConstraint, windows_core::IUnknown);
@@ -4768,22 +6742,71 @@ impl IAppxManifestDriverConstraint_Vtbl {
         iid == &<IAppxManifestDriverConstraint as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IAppxManifestDriverConstraint {}
-windows_core::imp::define_interface!(IAppxManifestDriverConstraintsEnumerator, IAppxManifestDriverConstraintsEnumerator_Vtbl, 0xd402b2d1_f600_49e0_95e6_975d8da13d89);
-windows_core::imp::interface_hierarchy!(IAppxManifestDriverConstraintsEnumerator, windows_core::IUnknown);
-impl IAppxManifestDriverConstraintsEnumerator {
-    pub unsafe fn GetCurrent(&self) -> windows_core::Result<IAppxManifestDriverConstraint> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetCurrent)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestDriverConstraint_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestDriverConstraint_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IAppxManifestDriverConstraint_Impl>(this: *mut core::ffi::c_void, name: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestDriverConstraint_Impl::GetName(this) {
+                    Ok(ok__) => {
+                        name.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn GetHasCurrent(&self) -> windows_core::Result<windows_core::BOOL> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetHasCurrent)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        unsafe extern "system" fn GetMinVersion<Identity: windows_core::IUnknownImpl + IAppxManifestDriverConstraint_Impl>(this: *mut core::ffi::c_void, minversion: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestDriverConstraint_Impl::GetMinVersion(this) {
+                    Ok(ok__) => {
+                        minversion.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
+        unsafe extern "system" fn GetMinDate<Identity: windows_core::IUnknownImpl + IAppxManifestDriverConstraint_Impl>(this: *mut core::ffi::c_void, mindate: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestDriverConstraint_Impl::GetMinDate(this) {
+                    Ok(ok__) => {
+                        mindate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetName: GetName::<Identity>,
+            GetMinVersion: GetMinVersion::<Identity>,
+            GetMinDate: GetMinDate::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IAppxManifestDriverConstraint {}
+windows_core::imp::define_interface!(IAppxManifestDriverConstraintsEnumerator, IAppxManifestDriverConstraintsEnumerator_Vtbl, 0xd402b2d1_f600_49e0_95e6_975d8da13d89);
+windows_core::imp::interface_hierarchy!(IAppxManifestDriverConstraintsEnumerator, windows_core::IUnknown);
+impl IAppxManifestDriverConstraintsEnumerator {
+    pub unsafe fn GetCurrent(&self) -> windows_core::Result<IAppxManifestDriverConstraint> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetCurrent)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn GetHasCurrent(&self) -> windows_core::Result<windows_core::BOOL> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetHasCurrent)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
     pub unsafe fn MoveNext(&self) -> windows_core::Result<windows_core::BOOL> {
         unsafe {
             let mut result__ = core::mem::zeroed();
@@ -4853,6 +6876,55 @@ impl IAppxManifestDriverConstraintsEnumerator_Vtbl {
         iid == &<IAppxManifestDriverConstraintsEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestDriverConstraintsEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestDriverConstraintsEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestDriverConstraintsEnumerator_Impl>(this: *mut core::ffi::c_void, driverconstraint: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestDriverConstraintsEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        driverconstraint.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHasCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestDriverConstraintsEnumerator_Impl>(this: *mut core::ffi::c_void, hascurrent: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestDriverConstraintsEnumerator_Impl::GetHasCurrent(this) {
+                    Ok(ok__) => {
+                        hascurrent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IAppxManifestDriverConstraintsEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestDriverConstraintsEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrent: GetCurrent::<Identity>,
+            GetHasCurrent: GetHasCurrent::<Identity>,
+            MoveNext: MoveNext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestDriverConstraintsEnumerator {}
 windows_core::imp::define_interface!(IAppxManifestDriverDependenciesEnumerator, IAppxManifestDriverDependenciesEnumerator_Vtbl, 0xfe039db2_467f_4755_8404_8f5eb6865b33);
 windows_core::imp::interface_hierarchy!(IAppxManifestDriverDependenciesEnumerator, windows_core::IUnknown);
@@ -4938,6 +7010,55 @@ impl IAppxManifestDriverDependenciesEnumerator_Vtbl {
         iid == &<IAppxManifestDriverDependenciesEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestDriverDependenciesEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestDriverDependenciesEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestDriverDependenciesEnumerator_Impl>(this: *mut core::ffi::c_void, driverdependency: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestDriverDependenciesEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        driverdependency.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHasCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestDriverDependenciesEnumerator_Impl>(this: *mut core::ffi::c_void, hascurrent: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestDriverDependenciesEnumerator_Impl::GetHasCurrent(this) {
+                    Ok(ok__) => {
+                        hascurrent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IAppxManifestDriverDependenciesEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestDriverDependenciesEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrent: GetCurrent::<Identity>,
+            GetHasCurrent: GetHasCurrent::<Identity>,
+            MoveNext: MoveNext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestDriverDependenciesEnumerator {}
 windows_core::imp::define_interface!(IAppxManifestDriverDependency, IAppxManifestDriverDependency_Vtbl, 0x1210cb94_5a92_4602_be24_79f318af4af9);
 windows_core::imp::interface_hierarchy!(IAppxManifestDriverDependency, windows_core::IUnknown);
@@ -4978,6 +7099,29 @@ impl IAppxManifestDriverDependency_Vtbl {
         iid == &<IAppxManifestDriverDependency as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestDriverDependency_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestDriverDependency_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDriverConstraints<Identity: windows_core::IUnknownImpl + IAppxManifestDriverDependency_Impl>(this: *mut core::ffi::c_void, driverconstraints: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestDriverDependency_Impl::GetDriverConstraints(this) {
+                    Ok(ok__) => {
+                        driverconstraints.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDriverConstraints: GetDriverConstraints::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestDriverDependency {}
 windows_core::imp::define_interface!(IAppxManifestHostRuntimeDependenciesEnumerator, IAppxManifestHostRuntimeDependenciesEnumerator_Vtbl, 0x6427a646_7f49_433e_b1a6_0da309f6885a);
 windows_core::imp::interface_hierarchy!(IAppxManifestHostRuntimeDependenciesEnumerator, windows_core::IUnknown);
@@ -5063,6 +7207,55 @@ impl IAppxManifestHostRuntimeDependenciesEnumerator_Vtbl {
         iid == &<IAppxManifestHostRuntimeDependenciesEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestHostRuntimeDependenciesEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestHostRuntimeDependenciesEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestHostRuntimeDependenciesEnumerator_Impl>(this: *mut core::ffi::c_void, hostruntimedependency: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestHostRuntimeDependenciesEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        hostruntimedependency.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHasCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestHostRuntimeDependenciesEnumerator_Impl>(this: *mut core::ffi::c_void, hascurrent: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestHostRuntimeDependenciesEnumerator_Impl::GetHasCurrent(this) {
+                    Ok(ok__) => {
+                        hascurrent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IAppxManifestHostRuntimeDependenciesEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestHostRuntimeDependenciesEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrent: GetCurrent::<Identity>,
+            GetHasCurrent: GetHasCurrent::<Identity>,
+            MoveNext: MoveNext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestHostRuntimeDependenciesEnumerator {}
 windows_core::imp::define_interface!(IAppxManifestHostRuntimeDependency, IAppxManifestHostRuntimeDependency_Vtbl, 0x3455d234_8414_410d_95c7_7b35255b8391);
 windows_core::imp::interface_hierarchy!(IAppxManifestHostRuntimeDependency, windows_core::IUnknown);
@@ -5148,6 +7341,55 @@ impl IAppxManifestHostRuntimeDependency_Vtbl {
         iid == &<IAppxManifestHostRuntimeDependency as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestHostRuntimeDependency_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestHostRuntimeDependency_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IAppxManifestHostRuntimeDependency_Impl>(this: *mut core::ffi::c_void, name: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestHostRuntimeDependency_Impl::GetName(this) {
+                    Ok(ok__) => {
+                        name.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPublisher<Identity: windows_core::IUnknownImpl + IAppxManifestHostRuntimeDependency_Impl>(this: *mut core::ffi::c_void, publisher: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestHostRuntimeDependency_Impl::GetPublisher(this) {
+                    Ok(ok__) => {
+                        publisher.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMinVersion<Identity: windows_core::IUnknownImpl + IAppxManifestHostRuntimeDependency_Impl>(this: *mut core::ffi::c_void, minversion: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestHostRuntimeDependency_Impl::GetMinVersion(this) {
+                    Ok(ok__) => {
+                        minversion.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetName: GetName::<Identity>,
+            GetPublisher: GetPublisher::<Identity>,
+            GetMinVersion: GetMinVersion::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestHostRuntimeDependency {}
 windows_core::imp::define_interface!(IAppxManifestHostRuntimeDependency2, IAppxManifestHostRuntimeDependency2_Vtbl, 0xc26f23a8_ee10_4ad6_b898_2b4d7aebfe6a);
 windows_core::imp::interface_hierarchy!(IAppxManifestHostRuntimeDependency2, windows_core::IUnknown);
@@ -5188,6 +7430,29 @@ impl IAppxManifestHostRuntimeDependency2_Vtbl {
         iid == &<IAppxManifestHostRuntimeDependency2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestHostRuntimeDependency2_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestHostRuntimeDependency2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPackageFamilyName<Identity: windows_core::IUnknownImpl + IAppxManifestHostRuntimeDependency2_Impl>(this: *mut core::ffi::c_void, packagefamilyname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestHostRuntimeDependency2_Impl::GetPackageFamilyName(this) {
+                    Ok(ok__) => {
+                        packagefamilyname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPackageFamilyName: GetPackageFamilyName::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestHostRuntimeDependency2 {}
 windows_core::imp::define_interface!(IAppxManifestMainPackageDependenciesEnumerator, IAppxManifestMainPackageDependenciesEnumerator_Vtbl, 0xa99c4f00_51d2_4f0f_ba46_7ed5255ebdff);
 windows_core::imp::interface_hierarchy!(IAppxManifestMainPackageDependenciesEnumerator, windows_core::IUnknown);
@@ -5273,6 +7538,55 @@ impl IAppxManifestMainPackageDependenciesEnumerator_Vtbl {
         iid == &<IAppxManifestMainPackageDependenciesEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestMainPackageDependenciesEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestMainPackageDependenciesEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestMainPackageDependenciesEnumerator_Impl>(this: *mut core::ffi::c_void, mainpackagedependency: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestMainPackageDependenciesEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        mainpackagedependency.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHasCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestMainPackageDependenciesEnumerator_Impl>(this: *mut core::ffi::c_void, hascurrent: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestMainPackageDependenciesEnumerator_Impl::GetHasCurrent(this) {
+                    Ok(ok__) => {
+                        hascurrent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IAppxManifestMainPackageDependenciesEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestMainPackageDependenciesEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrent: GetCurrent::<Identity>,
+            GetHasCurrent: GetHasCurrent::<Identity>,
+            MoveNext: MoveNext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestMainPackageDependenciesEnumerator {}
 windows_core::imp::define_interface!(IAppxManifestMainPackageDependency, IAppxManifestMainPackageDependency_Vtbl, 0x05d0611c_bc29_46d5_97e2_84b9c79bd8ae);
 windows_core::imp::interface_hierarchy!(IAppxManifestMainPackageDependency, windows_core::IUnknown);
@@ -5358,18 +7672,67 @@ impl IAppxManifestMainPackageDependency_Vtbl {
         iid == &<IAppxManifestMainPackageDependency as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IAppxManifestMainPackageDependency {}
-windows_core::imp::define_interface!(IAppxManifestOSPackageDependenciesEnumerator, IAppxManifestOSPackageDependenciesEnumerator_Vtbl, 0xb84e2fc3_f8ec_4bc1_8ae2_156346f5ffea);
-windows_core::imp::interface_hierarchy!(IAppxManifestOSPackageDependenciesEnumerator, windows_core::IUnknown);
-impl IAppxManifestOSPackageDependenciesEnumerator {
-    pub unsafe fn GetCurrent(&self) -> windows_core::Result<IAppxManifestOSPackageDependency> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetCurrent)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestMainPackageDependency_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestMainPackageDependency_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IAppxManifestMainPackageDependency_Impl>(this: *mut core::ffi::c_void, name: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestMainPackageDependency_Impl::GetName(this) {
+                    Ok(ok__) => {
+                        name.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn GetHasCurrent(&self) -> windows_core::Result<windows_core::BOOL> {
-        unsafe {
+        unsafe extern "system" fn GetPublisher<Identity: windows_core::IUnknownImpl + IAppxManifestMainPackageDependency_Impl>(this: *mut core::ffi::c_void, publisher: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestMainPackageDependency_Impl::GetPublisher(this) {
+                    Ok(ok__) => {
+                        publisher.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPackageFamilyName<Identity: windows_core::IUnknownImpl + IAppxManifestMainPackageDependency_Impl>(this: *mut core::ffi::c_void, packagefamilyname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestMainPackageDependency_Impl::GetPackageFamilyName(this) {
+                    Ok(ok__) => {
+                        packagefamilyname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetName: GetName::<Identity>,
+            GetPublisher: GetPublisher::<Identity>,
+            GetPackageFamilyName: GetPackageFamilyName::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IAppxManifestMainPackageDependency {}
+windows_core::imp::define_interface!(IAppxManifestOSPackageDependenciesEnumerator, IAppxManifestOSPackageDependenciesEnumerator_Vtbl, 0xb84e2fc3_f8ec_4bc1_8ae2_156346f5ffea);
+windows_core::imp::interface_hierarchy!(IAppxManifestOSPackageDependenciesEnumerator, windows_core::IUnknown);
+impl IAppxManifestOSPackageDependenciesEnumerator {
+    pub unsafe fn GetCurrent(&self) -> windows_core::Result<IAppxManifestOSPackageDependency> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetCurrent)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn GetHasCurrent(&self) -> windows_core::Result<windows_core::BOOL> {
+        unsafe {
             let mut result__ = core::mem::zeroed();
             (windows_core::Interface::vtable(self).GetHasCurrent)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
         }
@@ -5443,6 +7806,55 @@ impl IAppxManifestOSPackageDependenciesEnumerator_Vtbl {
         iid == &<IAppxManifestOSPackageDependenciesEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestOSPackageDependenciesEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestOSPackageDependenciesEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestOSPackageDependenciesEnumerator_Impl>(this: *mut core::ffi::c_void, ospackagedependency: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestOSPackageDependenciesEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        ospackagedependency.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHasCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestOSPackageDependenciesEnumerator_Impl>(this: *mut core::ffi::c_void, hascurrent: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestOSPackageDependenciesEnumerator_Impl::GetHasCurrent(this) {
+                    Ok(ok__) => {
+                        hascurrent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IAppxManifestOSPackageDependenciesEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestOSPackageDependenciesEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrent: GetCurrent::<Identity>,
+            GetHasCurrent: GetHasCurrent::<Identity>,
+            MoveNext: MoveNext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestOSPackageDependenciesEnumerator {}
 windows_core::imp::define_interface!(IAppxManifestOSPackageDependency, IAppxManifestOSPackageDependency_Vtbl, 0x154995ee_54a6_4f14_ac97_d8cf0519644b);
 windows_core::imp::interface_hierarchy!(IAppxManifestOSPackageDependency, windows_core::IUnknown);
@@ -5503,6 +7915,42 @@ impl IAppxManifestOSPackageDependency_Vtbl {
         iid == &<IAppxManifestOSPackageDependency as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestOSPackageDependency_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestOSPackageDependency_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IAppxManifestOSPackageDependency_Impl>(this: *mut core::ffi::c_void, name: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestOSPackageDependency_Impl::GetName(this) {
+                    Ok(ok__) => {
+                        name.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetVersion<Identity: windows_core::IUnknownImpl + IAppxManifestOSPackageDependency_Impl>(this: *mut core::ffi::c_void, version: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestOSPackageDependency_Impl::GetVersion(this) {
+                    Ok(ok__) => {
+                        version.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetName: GetName::<Identity>,
+            GetVersion: GetVersion::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestOSPackageDependency {}
 windows_core::imp::define_interface!(IAppxManifestOptionalPackageInfo, IAppxManifestOptionalPackageInfo_Vtbl, 0x2634847d_5b5d_4fe5_a243_002ff95edc7e);
 windows_core::imp::interface_hierarchy!(IAppxManifestOptionalPackageInfo, windows_core::IUnknown);
@@ -5567,6 +8015,42 @@ impl IAppxManifestOptionalPackageInfo_Vtbl {
         iid == &<IAppxManifestOptionalPackageInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestOptionalPackageInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestOptionalPackageInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetIsOptionalPackage<Identity: windows_core::IUnknownImpl + IAppxManifestOptionalPackageInfo_Impl>(this: *mut core::ffi::c_void, isoptionalpackage: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestOptionalPackageInfo_Impl::GetIsOptionalPackage(this) {
+                    Ok(ok__) => {
+                        isoptionalpackage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMainPackageName<Identity: windows_core::IUnknownImpl + IAppxManifestOptionalPackageInfo_Impl>(this: *mut core::ffi::c_void, mainpackagename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestOptionalPackageInfo_Impl::GetMainPackageName(this) {
+                    Ok(ok__) => {
+                        mainpackagename.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetIsOptionalPackage: GetIsOptionalPackage::<Identity>,
+            GetMainPackageName: GetMainPackageName::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestOptionalPackageInfo {}
 windows_core::imp::define_interface!(IAppxManifestPackageDependenciesEnumerator, IAppxManifestPackageDependenciesEnumerator_Vtbl, 0xb43bbcf9_65a6_42dd_bac0_8c6741e7f5a4);
 windows_core::imp::interface_hierarchy!(IAppxManifestPackageDependenciesEnumerator, windows_core::IUnknown);
@@ -5652,6 +8136,55 @@ impl IAppxManifestPackageDependenciesEnumerator_Vtbl {
         iid == &<IAppxManifestPackageDependenciesEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestPackageDependenciesEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestPackageDependenciesEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestPackageDependenciesEnumerator_Impl>(this: *mut core::ffi::c_void, dependency: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestPackageDependenciesEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        dependency.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHasCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestPackageDependenciesEnumerator_Impl>(this: *mut core::ffi::c_void, hascurrent: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestPackageDependenciesEnumerator_Impl::GetHasCurrent(this) {
+                    Ok(ok__) => {
+                        hascurrent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IAppxManifestPackageDependenciesEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestPackageDependenciesEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrent: GetCurrent::<Identity>,
+            GetHasCurrent: GetHasCurrent::<Identity>,
+            MoveNext: MoveNext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestPackageDependenciesEnumerator {}
 windows_core::imp::define_interface!(IAppxManifestPackageDependency, IAppxManifestPackageDependency_Vtbl, 0xe4946b59_733e_43f0_a724_3bde4c1285a0);
 windows_core::imp::interface_hierarchy!(IAppxManifestPackageDependency, windows_core::IUnknown);
@@ -5737,6 +8270,55 @@ impl IAppxManifestPackageDependency_Vtbl {
         iid == &<IAppxManifestPackageDependency as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestPackageDependency_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestPackageDependency_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IAppxManifestPackageDependency_Impl>(this: *mut core::ffi::c_void, name: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestPackageDependency_Impl::GetName(this) {
+                    Ok(ok__) => {
+                        name.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPublisher<Identity: windows_core::IUnknownImpl + IAppxManifestPackageDependency_Impl>(this: *mut core::ffi::c_void, publisher: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestPackageDependency_Impl::GetPublisher(this) {
+                    Ok(ok__) => {
+                        publisher.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMinVersion<Identity: windows_core::IUnknownImpl + IAppxManifestPackageDependency_Impl>(this: *mut core::ffi::c_void, minversion: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestPackageDependency_Impl::GetMinVersion(this) {
+                    Ok(ok__) => {
+                        minversion.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetName: GetName::<Identity>,
+            GetPublisher: GetPublisher::<Identity>,
+            GetMinVersion: GetMinVersion::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestPackageDependency {}
 windows_core::imp::define_interface!(IAppxManifestPackageDependency2, IAppxManifestPackageDependency2_Vtbl, 0xdda0b713_f3ff_49d3_898a_2786780c5d98);
 impl core::ops::Deref for IAppxManifestPackageDependency2 {
@@ -5783,6 +8365,29 @@ impl IAppxManifestPackageDependency2_Vtbl {
         iid == &<IAppxManifestPackageDependency2 as windows_core::Interface>::IID || iid == &<IAppxManifestPackageDependency as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestPackageDependency2_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestPackageDependency2_Vtbl
+where
+    IAppxManifestPackageDependency_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMaxMajorVersionTested<Identity: windows_core::IUnknownImpl + IAppxManifestPackageDependency2_Impl>(this: *mut core::ffi::c_void, maxmajorversiontested: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestPackageDependency2_Impl::GetMaxMajorVersionTested(this) {
+                    Ok(ok__) => {
+                        maxmajorversiontested.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IAppxManifestPackageDependency_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetMaxMajorVersionTested: GetMaxMajorVersionTested::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestPackageDependency2 {}
 windows_core::imp::define_interface!(IAppxManifestPackageDependency3, IAppxManifestPackageDependency3_Vtbl, 0x1ac56374_6198_4d6b_92e4_749d5ab8a895);
 windows_core::imp::interface_hierarchy!(IAppxManifestPackageDependency3, windows_core::IUnknown);
@@ -5823,6 +8428,26 @@ impl IAppxManifestPackageDependency3_Vtbl {
         iid == &<IAppxManifestPackageDependency3 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestPackageDependency3_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestPackageDependency3_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetIsOptional<Identity: windows_core::IUnknownImpl + IAppxManifestPackageDependency3_Impl>(this: *mut core::ffi::c_void, isoptional: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestPackageDependency3_Impl::GetIsOptional(this) {
+                    Ok(ok__) => {
+                        isoptional.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetIsOptional: GetIsOptional::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestPackageDependency3 {}
 windows_core::imp::define_interface!(IAppxManifestPackageId, IAppxManifestPackageId_Vtbl, 0x283ce2d7_7153_4a91_9649_7a0f7240945f);
 windows_core::imp::interface_hierarchy!(IAppxManifestPackageId, windows_core::IUnknown);
@@ -6016,52 +8641,186 @@ impl IAppxManifestPackageId_Vtbl {
         iid == &<IAppxManifestPackageId as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IAppxManifestPackageId {}
-windows_core::imp::define_interface!(IAppxManifestPackageId2, IAppxManifestPackageId2_Vtbl, 0x2256999d_d617_42f1_880e_0ba4542319d5);
-impl core::ops::Deref for IAppxManifestPackageId2 {
-    type Target = IAppxManifestPackageId;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(IAppxManifestPackageId2, windows_core::IUnknown, IAppxManifestPackageId);
-impl IAppxManifestPackageId2 {
-    pub unsafe fn GetArchitecture2(&self) -> windows_core::Result<APPX_PACKAGE_ARCHITECTURE2> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetArchitecture2)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
-        }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IAppxManifestPackageId2_Vtbl {
-    pub base__: IAppxManifestPackageId_Vtbl,
-    pub GetArchitecture2: unsafe extern "system" fn(*mut core::ffi::c_void, *mut APPX_PACKAGE_ARCHITECTURE2) -> windows_core::HRESULT,
-}
-pub trait IAppxManifestPackageId2_Impl: IAppxManifestPackageId_Impl {
-    fn GetArchitecture2(&self) -> windows_core::Result<APPX_PACKAGE_ARCHITECTURE2>;
-}
-impl IAppxManifestPackageId2_Vtbl {
-    pub const fn new<Identity: IAppxManifestPackageId2_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn GetArchitecture2<Identity: IAppxManifestPackageId2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, architecture: *mut APPX_PACKAGE_ARCHITECTURE2) -> windows_core::HRESULT {
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestPackageId_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestPackageId_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IAppxManifestPackageId_Impl>(this: *mut core::ffi::c_void, name: *mut windows_core::PWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IAppxManifestPackageId2_Impl::GetArchitecture2(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestPackageId_Impl::GetName(this) {
                     Ok(ok__) => {
-                        architecture.write(core::mem::transmute(ok__));
+                        name.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        Self { base__: IAppxManifestPackageId_Vtbl::new::<Identity, OFFSET>(), GetArchitecture2: GetArchitecture2::<Identity, OFFSET> }
-    }
+        unsafe extern "system" fn GetArchitecture<Identity: windows_core::IUnknownImpl + IAppxManifestPackageId_Impl>(this: *mut core::ffi::c_void, architecture: *mut APPX_PACKAGE_ARCHITECTURE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestPackageId_Impl::GetArchitecture(this) {
+                    Ok(ok__) => {
+                        architecture.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPublisher<Identity: windows_core::IUnknownImpl + IAppxManifestPackageId_Impl>(this: *mut core::ffi::c_void, publisher: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestPackageId_Impl::GetPublisher(this) {
+                    Ok(ok__) => {
+                        publisher.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetVersion<Identity: windows_core::IUnknownImpl + IAppxManifestPackageId_Impl>(this: *mut core::ffi::c_void, packageversion: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestPackageId_Impl::GetVersion(this) {
+                    Ok(ok__) => {
+                        packageversion.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetResourceId<Identity: windows_core::IUnknownImpl + IAppxManifestPackageId_Impl>(this: *mut core::ffi::c_void, resourceid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestPackageId_Impl::GetResourceId(this) {
+                    Ok(ok__) => {
+                        resourceid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ComparePublisher<Identity: windows_core::IUnknownImpl + IAppxManifestPackageId_Impl>(this: *mut core::ffi::c_void, other: windows_core::PCWSTR, issame: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestPackageId_Impl::ComparePublisher(this, core::mem::transmute(&other)) {
+                    Ok(ok__) => {
+                        issame.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPackageFullName<Identity: windows_core::IUnknownImpl + IAppxManifestPackageId_Impl>(this: *mut core::ffi::c_void, packagefullname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestPackageId_Impl::GetPackageFullName(this) {
+                    Ok(ok__) => {
+                        packagefullname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPackageFamilyName<Identity: windows_core::IUnknownImpl + IAppxManifestPackageId_Impl>(this: *mut core::ffi::c_void, packagefamilyname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestPackageId_Impl::GetPackageFamilyName(this) {
+                    Ok(ok__) => {
+                        packagefamilyname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetName: GetName::<Identity>,
+            GetArchitecture: GetArchitecture::<Identity>,
+            GetPublisher: GetPublisher::<Identity>,
+            GetVersion: GetVersion::<Identity>,
+            GetResourceId: GetResourceId::<Identity>,
+            ComparePublisher: ComparePublisher::<Identity>,
+            GetPackageFullName: GetPackageFullName::<Identity>,
+            GetPackageFamilyName: GetPackageFamilyName::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IAppxManifestPackageId {}
+windows_core::imp::define_interface!(IAppxManifestPackageId2, IAppxManifestPackageId2_Vtbl, 0x2256999d_d617_42f1_880e_0ba4542319d5);
+impl core::ops::Deref for IAppxManifestPackageId2 {
+    type Target = IAppxManifestPackageId;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(IAppxManifestPackageId2, windows_core::IUnknown, IAppxManifestPackageId);
+impl IAppxManifestPackageId2 {
+    pub unsafe fn GetArchitecture2(&self) -> windows_core::Result<APPX_PACKAGE_ARCHITECTURE2> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetArchitecture2)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IAppxManifestPackageId2_Vtbl {
+    pub base__: IAppxManifestPackageId_Vtbl,
+    pub GetArchitecture2: unsafe extern "system" fn(*mut core::ffi::c_void, *mut APPX_PACKAGE_ARCHITECTURE2) -> windows_core::HRESULT,
+}
+pub trait IAppxManifestPackageId2_Impl: IAppxManifestPackageId_Impl {
+    fn GetArchitecture2(&self) -> windows_core::Result<APPX_PACKAGE_ARCHITECTURE2>;
+}
+impl IAppxManifestPackageId2_Vtbl {
+    pub const fn new<Identity: IAppxManifestPackageId2_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn GetArchitecture2<Identity: IAppxManifestPackageId2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, architecture: *mut APPX_PACKAGE_ARCHITECTURE2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IAppxManifestPackageId2_Impl::GetArchitecture2(this) {
+                    Ok(ok__) => {
+                        architecture.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: IAppxManifestPackageId_Vtbl::new::<Identity, OFFSET>(), GetArchitecture2: GetArchitecture2::<Identity, OFFSET> }
+    }
     pub fn matches(iid: &windows_core::GUID) -> bool {
         iid == &<IAppxManifestPackageId2 as windows_core::Interface>::IID || iid == &<IAppxManifestPackageId as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestPackageId2_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestPackageId2_Vtbl
+where
+    IAppxManifestPackageId_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetArchitecture2<Identity: windows_core::IUnknownImpl + IAppxManifestPackageId2_Impl>(this: *mut core::ffi::c_void, architecture: *mut APPX_PACKAGE_ARCHITECTURE2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestPackageId2_Impl::GetArchitecture2(this) {
+                    Ok(ok__) => {
+                        architecture.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IAppxManifestPackageId_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetArchitecture2: GetArchitecture2::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestPackageId2 {}
 windows_core::imp::define_interface!(IAppxManifestProperties, IAppxManifestProperties_Vtbl, 0x03faf64d_f26f_4b2c_aaf7_8fe7789b8bca);
 windows_core::imp::interface_hierarchy!(IAppxManifestProperties, windows_core::IUnknown);
@@ -6132,6 +8891,42 @@ impl IAppxManifestProperties_Vtbl {
         iid == &<IAppxManifestProperties as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestProperties_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestProperties_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetBoolValue<Identity: windows_core::IUnknownImpl + IAppxManifestProperties_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, value: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestProperties_Impl::GetBoolValue(this, core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStringValue<Identity: windows_core::IUnknownImpl + IAppxManifestProperties_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestProperties_Impl::GetStringValue(this, core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetBoolValue: GetBoolValue::<Identity>,
+            GetStringValue: GetStringValue::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestProperties {}
 windows_core::imp::define_interface!(IAppxManifestQualifiedResource, IAppxManifestQualifiedResource_Vtbl, 0x3b53a497_3c5c_48d1_9ea3_bb7eac8cd7d4);
 windows_core::imp::interface_hierarchy!(IAppxManifestQualifiedResource, windows_core::IUnknown);
@@ -6217,6 +9012,55 @@ impl IAppxManifestQualifiedResource_Vtbl {
         iid == &<IAppxManifestQualifiedResource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestQualifiedResource_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestQualifiedResource_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetLanguage<Identity: windows_core::IUnknownImpl + IAppxManifestQualifiedResource_Impl>(this: *mut core::ffi::c_void, language: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestQualifiedResource_Impl::GetLanguage(this) {
+                    Ok(ok__) => {
+                        language.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetScale<Identity: windows_core::IUnknownImpl + IAppxManifestQualifiedResource_Impl>(this: *mut core::ffi::c_void, scale: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestQualifiedResource_Impl::GetScale(this) {
+                    Ok(ok__) => {
+                        scale.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDXFeatureLevel<Identity: windows_core::IUnknownImpl + IAppxManifestQualifiedResource_Impl>(this: *mut core::ffi::c_void, dxfeaturelevel: *mut DX_FEATURE_LEVEL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestQualifiedResource_Impl::GetDXFeatureLevel(this) {
+                    Ok(ok__) => {
+                        dxfeaturelevel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetLanguage: GetLanguage::<Identity>,
+            GetScale: GetScale::<Identity>,
+            GetDXFeatureLevel: GetDXFeatureLevel::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestQualifiedResource {}
 windows_core::imp::define_interface!(IAppxManifestQualifiedResourcesEnumerator, IAppxManifestQualifiedResourcesEnumerator_Vtbl, 0x8ef6adfe_3762_4a8f_9373_2fc5d444c8d2);
 windows_core::imp::interface_hierarchy!(IAppxManifestQualifiedResourcesEnumerator, windows_core::IUnknown);
@@ -6302,6 +9146,55 @@ impl IAppxManifestQualifiedResourcesEnumerator_Vtbl {
         iid == &<IAppxManifestQualifiedResourcesEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestQualifiedResourcesEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestQualifiedResourcesEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestQualifiedResourcesEnumerator_Impl>(this: *mut core::ffi::c_void, resource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestQualifiedResourcesEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        resource.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHasCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestQualifiedResourcesEnumerator_Impl>(this: *mut core::ffi::c_void, hascurrent: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestQualifiedResourcesEnumerator_Impl::GetHasCurrent(this) {
+                    Ok(ok__) => {
+                        hascurrent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IAppxManifestQualifiedResourcesEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestQualifiedResourcesEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrent: GetCurrent::<Identity>,
+            GetHasCurrent: GetHasCurrent::<Identity>,
+            MoveNext: MoveNext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestQualifiedResourcesEnumerator {}
 windows_core::imp::define_interface!(IAppxManifestReader, IAppxManifestReader_Vtbl, 0x4e1bd148_55a0_4480_a3d1_15544710637c);
 windows_core::imp::interface_hierarchy!(IAppxManifestReader, windows_core::IUnknown);
@@ -6350,56 +9243,187 @@ impl IAppxManifestReader {
             let mut result__ = core::mem::zeroed();
             (windows_core::Interface::vtable(self).GetPrerequisite)(windows_core::Interface::as_raw(self), name.param().abi(), &mut result__).map(|| result__)
         }
-    }
-    pub unsafe fn GetApplications(&self) -> windows_core::Result<IAppxManifestApplicationsEnumerator> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetApplications)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+    }
+    pub unsafe fn GetApplications(&self) -> windows_core::Result<IAppxManifestApplicationsEnumerator> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetApplications)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    #[cfg(feature = "Win32_System_Com")]
+    pub unsafe fn GetStream(&self) -> windows_core::Result<super::super::super::System::Com::IStream> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetStream)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IAppxManifestReader_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub GetPackageId: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetProperties: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetPackageDependencies: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetCapabilities: unsafe extern "system" fn(*mut core::ffi::c_void, *mut APPX_CAPABILITIES) -> windows_core::HRESULT,
+    pub GetResources: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetDeviceCapabilities: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetPrerequisite: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::PCWSTR, *mut u64) -> windows_core::HRESULT,
+    pub GetApplications: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    #[cfg(feature = "Win32_System_Com")]
+    pub GetStream: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    #[cfg(not(feature = "Win32_System_Com"))]
+    GetStream: usize,
+}
+#[cfg(feature = "Win32_System_Com")]
+pub trait IAppxManifestReader_Impl: windows_core::IUnknownImpl {
+    fn GetPackageId(&self) -> windows_core::Result<IAppxManifestPackageId>;
+    fn GetProperties(&self) -> windows_core::Result<IAppxManifestProperties>;
+    fn GetPackageDependencies(&self) -> windows_core::Result<IAppxManifestPackageDependenciesEnumerator>;
+    fn GetCapabilities(&self) -> windows_core::Result<APPX_CAPABILITIES>;
+    fn GetResources(&self) -> windows_core::Result<IAppxManifestResourcesEnumerator>;
+    fn GetDeviceCapabilities(&self) -> windows_core::Result<IAppxManifestDeviceCapabilitiesEnumerator>;
+    fn GetPrerequisite(&self, name: &windows_core::PCWSTR) -> windows_core::Result<u64>;
+    fn GetApplications(&self) -> windows_core::Result<IAppxManifestApplicationsEnumerator>;
+    fn GetStream(&self) -> windows_core::Result<super::super::super::System::Com::IStream>;
+}
+#[cfg(feature = "Win32_System_Com")]
+impl IAppxManifestReader_Vtbl {
+    pub const fn new<Identity: IAppxManifestReader_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn GetPackageId<Identity: IAppxManifestReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, packageid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IAppxManifestReader_Impl::GetPackageId(this) {
+                    Ok(ok__) => {
+                        packageid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetProperties<Identity: IAppxManifestReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, packageproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IAppxManifestReader_Impl::GetProperties(this) {
+                    Ok(ok__) => {
+                        packageproperties.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPackageDependencies<Identity: IAppxManifestReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dependencies: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IAppxManifestReader_Impl::GetPackageDependencies(this) {
+                    Ok(ok__) => {
+                        dependencies.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCapabilities<Identity: IAppxManifestReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, capabilities: *mut APPX_CAPABILITIES) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IAppxManifestReader_Impl::GetCapabilities(this) {
+                    Ok(ok__) => {
+                        capabilities.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetResources<Identity: IAppxManifestReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, resources: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IAppxManifestReader_Impl::GetResources(this) {
+                    Ok(ok__) => {
+                        resources.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDeviceCapabilities<Identity: IAppxManifestReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, devicecapabilities: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IAppxManifestReader_Impl::GetDeviceCapabilities(this) {
+                    Ok(ok__) => {
+                        devicecapabilities.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPrerequisite<Identity: IAppxManifestReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, value: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IAppxManifestReader_Impl::GetPrerequisite(this, core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetApplications<Identity: IAppxManifestReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, applications: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IAppxManifestReader_Impl::GetApplications(this) {
+                    Ok(ok__) => {
+                        applications.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStream<Identity: IAppxManifestReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, manifeststream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IAppxManifestReader_Impl::GetStream(this) {
+                    Ok(ok__) => {
+                        manifeststream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            GetPackageId: GetPackageId::<Identity, OFFSET>,
+            GetProperties: GetProperties::<Identity, OFFSET>,
+            GetPackageDependencies: GetPackageDependencies::<Identity, OFFSET>,
+            GetCapabilities: GetCapabilities::<Identity, OFFSET>,
+            GetResources: GetResources::<Identity, OFFSET>,
+            GetDeviceCapabilities: GetDeviceCapabilities::<Identity, OFFSET>,
+            GetPrerequisite: GetPrerequisite::<Identity, OFFSET>,
+            GetApplications: GetApplications::<Identity, OFFSET>,
+            GetStream: GetStream::<Identity, OFFSET>,
         }
     }
-    #[cfg(feature = "Win32_System_Com")]
-    pub unsafe fn GetStream(&self) -> windows_core::Result<super::super::super::System::Com::IStream> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetStream)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestReader as windows_core::Interface>::IID
     }
 }
-#[repr(C)]
-#[doc(hidden)]
-pub struct IAppxManifestReader_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    pub GetPackageId: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetProperties: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetPackageDependencies: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetCapabilities: unsafe extern "system" fn(*mut core::ffi::c_void, *mut APPX_CAPABILITIES) -> windows_core::HRESULT,
-    pub GetResources: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetDeviceCapabilities: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetPrerequisite: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::PCWSTR, *mut u64) -> windows_core::HRESULT,
-    pub GetApplications: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    #[cfg(feature = "Win32_System_Com")]
-    pub GetStream: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    #[cfg(not(feature = "Win32_System_Com"))]
-    GetStream: usize,
-}
-#[cfg(feature = "Win32_System_Com")]
-pub trait IAppxManifestReader_Impl: windows_core::IUnknownImpl {
-    fn GetPackageId(&self) -> windows_core::Result<IAppxManifestPackageId>;
-    fn GetProperties(&self) -> windows_core::Result<IAppxManifestProperties>;
-    fn GetPackageDependencies(&self) -> windows_core::Result<IAppxManifestPackageDependenciesEnumerator>;
-    fn GetCapabilities(&self) -> windows_core::Result<APPX_CAPABILITIES>;
-    fn GetResources(&self) -> windows_core::Result<IAppxManifestResourcesEnumerator>;
-    fn GetDeviceCapabilities(&self) -> windows_core::Result<IAppxManifestDeviceCapabilitiesEnumerator>;
-    fn GetPrerequisite(&self, name: &windows_core::PCWSTR) -> windows_core::Result<u64>;
-    fn GetApplications(&self) -> windows_core::Result<IAppxManifestApplicationsEnumerator>;
-    fn GetStream(&self) -> windows_core::Result<super::super::super::System::Com::IStream>;
-}
 #[cfg(feature = "Win32_System_Com")]
-impl IAppxManifestReader_Vtbl {
-    pub const fn new<Identity: IAppxManifestReader_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn GetPackageId<Identity: IAppxManifestReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, packageid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestReader_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestReader_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPackageId<Identity: windows_core::IUnknownImpl + IAppxManifestReader_Impl>(this: *mut core::ffi::c_void, packageid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IAppxManifestReader_Impl::GetPackageId(this) {
                     Ok(ok__) => {
                         packageid.write(core::mem::transmute(ok__));
@@ -6409,9 +9433,9 @@ impl IAppxManifestReader_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetProperties<Identity: IAppxManifestReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, packageproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + IAppxManifestReader_Impl>(this: *mut core::ffi::c_void, packageproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IAppxManifestReader_Impl::GetProperties(this) {
                     Ok(ok__) => {
                         packageproperties.write(core::mem::transmute(ok__));
@@ -6421,9 +9445,9 @@ impl IAppxManifestReader_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetPackageDependencies<Identity: IAppxManifestReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dependencies: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetPackageDependencies<Identity: windows_core::IUnknownImpl + IAppxManifestReader_Impl>(this: *mut core::ffi::c_void, dependencies: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IAppxManifestReader_Impl::GetPackageDependencies(this) {
                     Ok(ok__) => {
                         dependencies.write(core::mem::transmute(ok__));
@@ -6433,9 +9457,9 @@ impl IAppxManifestReader_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetCapabilities<Identity: IAppxManifestReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, capabilities: *mut APPX_CAPABILITIES) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetCapabilities<Identity: windows_core::IUnknownImpl + IAppxManifestReader_Impl>(this: *mut core::ffi::c_void, capabilities: *mut APPX_CAPABILITIES) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IAppxManifestReader_Impl::GetCapabilities(this) {
                     Ok(ok__) => {
                         capabilities.write(core::mem::transmute(ok__));
@@ -6445,9 +9469,9 @@ impl IAppxManifestReader_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetResources<Identity: IAppxManifestReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, resources: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetResources<Identity: windows_core::IUnknownImpl + IAppxManifestReader_Impl>(this: *mut core::ffi::c_void, resources: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IAppxManifestReader_Impl::GetResources(this) {
                     Ok(ok__) => {
                         resources.write(core::mem::transmute(ok__));
@@ -6457,9 +9481,9 @@ impl IAppxManifestReader_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetDeviceCapabilities<Identity: IAppxManifestReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, devicecapabilities: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetDeviceCapabilities<Identity: windows_core::IUnknownImpl + IAppxManifestReader_Impl>(this: *mut core::ffi::c_void, devicecapabilities: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IAppxManifestReader_Impl::GetDeviceCapabilities(this) {
                     Ok(ok__) => {
                         devicecapabilities.write(core::mem::transmute(ok__));
@@ -6469,9 +9493,9 @@ impl IAppxManifestReader_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetPrerequisite<Identity: IAppxManifestReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, value: *mut u64) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetPrerequisite<Identity: windows_core::IUnknownImpl + IAppxManifestReader_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, value: *mut u64) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IAppxManifestReader_Impl::GetPrerequisite(this, core::mem::transmute(&name)) {
                     Ok(ok__) => {
                         value.write(core::mem::transmute(ok__));
@@ -6481,9 +9505,9 @@ impl IAppxManifestReader_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetApplications<Identity: IAppxManifestReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, applications: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetApplications<Identity: windows_core::IUnknownImpl + IAppxManifestReader_Impl>(this: *mut core::ffi::c_void, applications: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IAppxManifestReader_Impl::GetApplications(this) {
                     Ok(ok__) => {
                         applications.write(core::mem::transmute(ok__));
@@ -6493,9 +9517,9 @@ impl IAppxManifestReader_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetStream<Identity: IAppxManifestReader_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, manifeststream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetStream<Identity: windows_core::IUnknownImpl + IAppxManifestReader_Impl>(this: *mut core::ffi::c_void, manifeststream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IAppxManifestReader_Impl::GetStream(this) {
                     Ok(ok__) => {
                         manifeststream.write(core::mem::transmute(ok__));
@@ -6506,21 +9530,18 @@ impl IAppxManifestReader_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            GetPackageId: GetPackageId::<Identity, OFFSET>,
-            GetProperties: GetProperties::<Identity, OFFSET>,
-            GetPackageDependencies: GetPackageDependencies::<Identity, OFFSET>,
-            GetCapabilities: GetCapabilities::<Identity, OFFSET>,
-            GetResources: GetResources::<Identity, OFFSET>,
-            GetDeviceCapabilities: GetDeviceCapabilities::<Identity, OFFSET>,
-            GetPrerequisite: GetPrerequisite::<Identity, OFFSET>,
-            GetApplications: GetApplications::<Identity, OFFSET>,
-            GetStream: GetStream::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IAppxManifestReader as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPackageId: GetPackageId::<Identity>,
+            GetProperties: GetProperties::<Identity>,
+            GetPackageDependencies: GetPackageDependencies::<Identity>,
+            GetCapabilities: GetCapabilities::<Identity>,
+            GetResources: GetResources::<Identity>,
+            GetDeviceCapabilities: GetDeviceCapabilities::<Identity>,
+            GetPrerequisite: GetPrerequisite::<Identity>,
+            GetApplications: GetApplications::<Identity>,
+            GetStream: GetStream::<Identity>,
+        }
+    };
 }
 #[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxManifestReader {}
@@ -6572,6 +9593,30 @@ impl IAppxManifestReader2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestReader2_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestReader2_Vtbl
+where
+    IAppxManifestReader_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetQualifiedResources<Identity: windows_core::IUnknownImpl + IAppxManifestReader2_Impl>(this: *mut core::ffi::c_void, resources: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestReader2_Impl::GetQualifiedResources(this) {
+                    Ok(ok__) => {
+                        resources.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IAppxManifestReader_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetQualifiedResources: GetQualifiedResources::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxManifestReader2 {}
 windows_core::imp::define_interface!(IAppxManifestReader3, IAppxManifestReader3_Vtbl, 0xc43825ab_69b7_400a_9709_cc37f5a72d24);
 impl core::ops::Deref for IAppxManifestReader3 {
@@ -6645,6 +9690,43 @@ impl IAppxManifestReader3_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestReader3_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestReader3_Vtbl
+where
+    IAppxManifestReader2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCapabilitiesByCapabilityClass<Identity: windows_core::IUnknownImpl + IAppxManifestReader3_Impl>(this: *mut core::ffi::c_void, capabilityclass: APPX_CAPABILITY_CLASS_TYPE, capabilities: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestReader3_Impl::GetCapabilitiesByCapabilityClass(this, core::mem::transmute_copy(&capabilityclass)) {
+                    Ok(ok__) => {
+                        capabilities.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTargetDeviceFamilies<Identity: windows_core::IUnknownImpl + IAppxManifestReader3_Impl>(this: *mut core::ffi::c_void, targetdevicefamilies: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestReader3_Impl::GetTargetDeviceFamilies(this) {
+                    Ok(ok__) => {
+                        targetdevicefamilies.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IAppxManifestReader2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCapabilitiesByCapabilityClass: GetCapabilitiesByCapabilityClass::<Identity>,
+            GetTargetDeviceFamilies: GetTargetDeviceFamilies::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxManifestReader3 {}
 windows_core::imp::define_interface!(IAppxManifestReader4, IAppxManifestReader4_Vtbl, 0x4579bb7c_741d_4161_b5a1_47bd3b78ad9b);
 impl core::ops::Deref for IAppxManifestReader4 {
@@ -6694,6 +9776,30 @@ impl IAppxManifestReader4_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestReader4_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestReader4_Vtbl
+where
+    IAppxManifestReader3_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetOptionalPackageInfo<Identity: windows_core::IUnknownImpl + IAppxManifestReader4_Impl>(this: *mut core::ffi::c_void, optionalpackageinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestReader4_Impl::GetOptionalPackageInfo(this) {
+                    Ok(ok__) => {
+                        optionalpackageinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IAppxManifestReader3_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetOptionalPackageInfo: GetOptionalPackageInfo::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxManifestReader4 {}
 windows_core::imp::define_interface!(IAppxManifestReader5, IAppxManifestReader5_Vtbl, 0x8d7ae132_a690_4c00_b75a_6aae1feaac80);
 windows_core::imp::interface_hierarchy!(IAppxManifestReader5, windows_core::IUnknown);
@@ -6734,6 +9840,29 @@ impl IAppxManifestReader5_Vtbl {
         iid == &<IAppxManifestReader5 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestReader5_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestReader5_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMainPackageDependencies<Identity: windows_core::IUnknownImpl + IAppxManifestReader5_Impl>(this: *mut core::ffi::c_void, mainpackagedependencies: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestReader5_Impl::GetMainPackageDependencies(this) {
+                    Ok(ok__) => {
+                        mainpackagedependencies.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetMainPackageDependencies: GetMainPackageDependencies::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestReader5 {}
 windows_core::imp::define_interface!(IAppxManifestReader6, IAppxManifestReader6_Vtbl, 0x34deaca4_d3c0_4e3e_b312_e42625e3807e);
 windows_core::imp::interface_hierarchy!(IAppxManifestReader6, windows_core::IUnknown);
@@ -6777,6 +9906,29 @@ impl IAppxManifestReader6_Vtbl {
         iid == &<IAppxManifestReader6 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestReader6_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestReader6_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetIsNonQualifiedResourcePackage<Identity: windows_core::IUnknownImpl + IAppxManifestReader6_Impl>(this: *mut core::ffi::c_void, isnonqualifiedresourcepackage: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestReader6_Impl::GetIsNonQualifiedResourcePackage(this) {
+                    Ok(ok__) => {
+                        isnonqualifiedresourcepackage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetIsNonQualifiedResourcePackage: GetIsNonQualifiedResourcePackage::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestReader6 {}
 windows_core::imp::define_interface!(IAppxManifestReader7, IAppxManifestReader7_Vtbl, 0x8efe6f27_0ce0_4988_b32d_738eb63db3b7);
 windows_core::imp::interface_hierarchy!(IAppxManifestReader7, windows_core::IUnknown);
@@ -6862,6 +10014,55 @@ impl IAppxManifestReader7_Vtbl {
         iid == &<IAppxManifestReader7 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestReader7_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestReader7_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDriverDependencies<Identity: windows_core::IUnknownImpl + IAppxManifestReader7_Impl>(this: *mut core::ffi::c_void, driverdependencies: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestReader7_Impl::GetDriverDependencies(this) {
+                    Ok(ok__) => {
+                        driverdependencies.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetOSPackageDependencies<Identity: windows_core::IUnknownImpl + IAppxManifestReader7_Impl>(this: *mut core::ffi::c_void, ospackagedependencies: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestReader7_Impl::GetOSPackageDependencies(this) {
+                    Ok(ok__) => {
+                        ospackagedependencies.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHostRuntimeDependencies<Identity: windows_core::IUnknownImpl + IAppxManifestReader7_Impl>(this: *mut core::ffi::c_void, hostruntimedependencies: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestReader7_Impl::GetHostRuntimeDependencies(this) {
+                    Ok(ok__) => {
+                        hostruntimedependencies.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDriverDependencies: GetDriverDependencies::<Identity>,
+            GetOSPackageDependencies: GetOSPackageDependencies::<Identity>,
+            GetHostRuntimeDependencies: GetHostRuntimeDependencies::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestReader7 {}
 windows_core::imp::define_interface!(IAppxManifestResourcesEnumerator, IAppxManifestResourcesEnumerator_Vtbl, 0xde4dfbbd_881a_48bb_858c_d6f2baeae6ed);
 windows_core::imp::interface_hierarchy!(IAppxManifestResourcesEnumerator, windows_core::IUnknown);
@@ -6898,11 +10099,63 @@ pub trait IAppxManifestResourcesEnumerator_Impl: windows_core::IUnknownImpl {
     fn GetHasCurrent(&self) -> windows_core::Result<windows_core::BOOL>;
     fn MoveNext(&self) -> windows_core::Result<windows_core::BOOL>;
 }
-impl IAppxManifestResourcesEnumerator_Vtbl {
-    pub const fn new<Identity: IAppxManifestResourcesEnumerator_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn GetCurrent<Identity: IAppxManifestResourcesEnumerator_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, resource: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+impl IAppxManifestResourcesEnumerator_Vtbl {
+    pub const fn new<Identity: IAppxManifestResourcesEnumerator_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn GetCurrent<Identity: IAppxManifestResourcesEnumerator_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, resource: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IAppxManifestResourcesEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        resource.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHasCurrent<Identity: IAppxManifestResourcesEnumerator_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hascurrent: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IAppxManifestResourcesEnumerator_Impl::GetHasCurrent(this) {
+                    Ok(ok__) => {
+                        hascurrent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveNext<Identity: IAppxManifestResourcesEnumerator_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IAppxManifestResourcesEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            GetCurrent: GetCurrent::<Identity, OFFSET>,
+            GetHasCurrent: GetHasCurrent::<Identity, OFFSET>,
+            MoveNext: MoveNext::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestResourcesEnumerator as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestResourcesEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestResourcesEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestResourcesEnumerator_Impl>(this: *mut core::ffi::c_void, resource: *mut windows_core::PWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IAppxManifestResourcesEnumerator_Impl::GetCurrent(this) {
                     Ok(ok__) => {
                         resource.write(core::mem::transmute(ok__));
@@ -6912,9 +10165,9 @@ impl IAppxManifestResourcesEnumerator_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetHasCurrent<Identity: IAppxManifestResourcesEnumerator_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hascurrent: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetHasCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestResourcesEnumerator_Impl>(this: *mut core::ffi::c_void, hascurrent: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IAppxManifestResourcesEnumerator_Impl::GetHasCurrent(this) {
                     Ok(ok__) => {
                         hascurrent.write(core::mem::transmute(ok__));
@@ -6924,9 +10177,9 @@ impl IAppxManifestResourcesEnumerator_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn MoveNext<Identity: IAppxManifestResourcesEnumerator_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IAppxManifestResourcesEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IAppxManifestResourcesEnumerator_Impl::MoveNext(this) {
                     Ok(ok__) => {
                         hasnext.write(core::mem::transmute(ok__));
@@ -6937,15 +10190,12 @@ impl IAppxManifestResourcesEnumerator_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            GetCurrent: GetCurrent::<Identity, OFFSET>,
-            GetHasCurrent: GetHasCurrent::<Identity, OFFSET>,
-            MoveNext: MoveNext::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrent: GetCurrent::<Identity>,
+            GetHasCurrent: GetHasCurrent::<Identity>,
+            MoveNext: MoveNext::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IAppxManifestResourcesEnumerator as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IAppxManifestResourcesEnumerator {}
 windows_core::imp::define_interface!(IAppxManifestTargetDeviceFamiliesEnumerator, IAppxManifestTargetDeviceFamiliesEnumerator_Vtbl, 0x36537f36_27a4_4788_88c0_733819575017);
@@ -7032,6 +10282,55 @@ impl IAppxManifestTargetDeviceFamiliesEnumerator_Vtbl {
         iid == &<IAppxManifestTargetDeviceFamiliesEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestTargetDeviceFamiliesEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestTargetDeviceFamiliesEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestTargetDeviceFamiliesEnumerator_Impl>(this: *mut core::ffi::c_void, targetdevicefamily: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestTargetDeviceFamiliesEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        targetdevicefamily.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHasCurrent<Identity: windows_core::IUnknownImpl + IAppxManifestTargetDeviceFamiliesEnumerator_Impl>(this: *mut core::ffi::c_void, hascurrent: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestTargetDeviceFamiliesEnumerator_Impl::GetHasCurrent(this) {
+                    Ok(ok__) => {
+                        hascurrent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IAppxManifestTargetDeviceFamiliesEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestTargetDeviceFamiliesEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrent: GetCurrent::<Identity>,
+            GetHasCurrent: GetHasCurrent::<Identity>,
+            MoveNext: MoveNext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestTargetDeviceFamiliesEnumerator {}
 windows_core::imp::define_interface!(IAppxManifestTargetDeviceFamily, IAppxManifestTargetDeviceFamily_Vtbl, 0x9091b09b_c8d5_4f31_8687_a338259faefb);
 windows_core::imp::interface_hierarchy!(IAppxManifestTargetDeviceFamily, windows_core::IUnknown);
@@ -7117,6 +10416,55 @@ impl IAppxManifestTargetDeviceFamily_Vtbl {
         iid == &<IAppxManifestTargetDeviceFamily as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxManifestTargetDeviceFamily_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxManifestTargetDeviceFamily_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IAppxManifestTargetDeviceFamily_Impl>(this: *mut core::ffi::c_void, name: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestTargetDeviceFamily_Impl::GetName(this) {
+                    Ok(ok__) => {
+                        name.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMinVersion<Identity: windows_core::IUnknownImpl + IAppxManifestTargetDeviceFamily_Impl>(this: *mut core::ffi::c_void, minversion: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestTargetDeviceFamily_Impl::GetMinVersion(this) {
+                    Ok(ok__) => {
+                        minversion.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMaxVersionTested<Identity: windows_core::IUnknownImpl + IAppxManifestTargetDeviceFamily_Impl>(this: *mut core::ffi::c_void, maxversiontested: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxManifestTargetDeviceFamily_Impl::GetMaxVersionTested(this) {
+                    Ok(ok__) => {
+                        maxversiontested.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetName: GetName::<Identity>,
+            GetMinVersion: GetMinVersion::<Identity>,
+            GetMaxVersionTested: GetMaxVersionTested::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxManifestTargetDeviceFamily {}
 windows_core::imp::define_interface!(IAppxPackageEditor, IAppxPackageEditor_Vtbl, 0xe2adb6dc_5e71_4416_86b6_86e5f5291a6b);
 windows_core::imp::interface_hierarchy!(IAppxPackageEditor, windows_core::IUnknown);
@@ -7260,6 +10608,59 @@ impl IAppxPackageEditor_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxPackageEditor_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxPackageEditor_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetWorkingDirectory<Identity: windows_core::IUnknownImpl + IAppxPackageEditor_Impl>(this: *mut core::ffi::c_void, workingdirectory: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxPackageEditor_Impl::SetWorkingDirectory(this, core::mem::transmute(&workingdirectory)).into()
+            }
+        }
+        unsafe extern "system" fn CreateDeltaPackage<Identity: windows_core::IUnknownImpl + IAppxPackageEditor_Impl>(this: *mut core::ffi::c_void, updatedpackagestream: *mut core::ffi::c_void, baselinepackagestream: *mut core::ffi::c_void, deltapackagestream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxPackageEditor_Impl::CreateDeltaPackage(this, core::mem::transmute_copy(&updatedpackagestream), core::mem::transmute_copy(&baselinepackagestream), core::mem::transmute_copy(&deltapackagestream)).into()
+            }
+        }
+        unsafe extern "system" fn CreateDeltaPackageUsingBaselineBlockMap<Identity: windows_core::IUnknownImpl + IAppxPackageEditor_Impl>(this: *mut core::ffi::c_void, updatedpackagestream: *mut core::ffi::c_void, baselineblockmapstream: *mut core::ffi::c_void, baselinepackagefullname: windows_core::PCWSTR, deltapackagestream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxPackageEditor_Impl::CreateDeltaPackageUsingBaselineBlockMap(this, core::mem::transmute_copy(&updatedpackagestream), core::mem::transmute_copy(&baselineblockmapstream), core::mem::transmute(&baselinepackagefullname), core::mem::transmute_copy(&deltapackagestream)).into()
+            }
+        }
+        unsafe extern "system" fn UpdatePackage<Identity: windows_core::IUnknownImpl + IAppxPackageEditor_Impl>(this: *mut core::ffi::c_void, baselinepackagestream: *mut core::ffi::c_void, deltapackagestream: *mut core::ffi::c_void, updateoption: APPX_PACKAGE_EDITOR_UPDATE_PACKAGE_OPTION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxPackageEditor_Impl::UpdatePackage(this, core::mem::transmute_copy(&baselinepackagestream), core::mem::transmute_copy(&deltapackagestream), core::mem::transmute_copy(&updateoption)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateEncryptedPackage<Identity: windows_core::IUnknownImpl + IAppxPackageEditor_Impl>(this: *mut core::ffi::c_void, baselineencryptedpackagestream: *mut core::ffi::c_void, deltapackagestream: *mut core::ffi::c_void, updateoption: APPX_PACKAGE_EDITOR_UPDATE_PACKAGE_OPTION, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS2, keyinfo: *const APPX_KEY_INFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxPackageEditor_Impl::UpdateEncryptedPackage(this, core::mem::transmute_copy(&baselineencryptedpackagestream), core::mem::transmute_copy(&deltapackagestream), core::mem::transmute_copy(&updateoption), core::mem::transmute_copy(&settings), core::mem::transmute_copy(&keyinfo)).into()
+            }
+        }
+        unsafe extern "system" fn UpdatePackageManifest<Identity: windows_core::IUnknownImpl + IAppxPackageEditor_Impl>(this: *mut core::ffi::c_void, packagestream: *mut core::ffi::c_void, updatedmanifeststream: *mut core::ffi::c_void, ispackageencrypted: windows_core::BOOL, options: APPX_PACKAGE_EDITOR_UPDATE_PACKAGE_MANIFEST_OPTIONS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxPackageEditor_Impl::UpdatePackageManifest(this, core::mem::transmute_copy(&packagestream), core::mem::transmute_copy(&updatedmanifeststream), core::mem::transmute_copy(&ispackageencrypted), core::mem::transmute_copy(&options)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetWorkingDirectory: SetWorkingDirectory::<Identity>,
+            CreateDeltaPackage: CreateDeltaPackage::<Identity>,
+            CreateDeltaPackageUsingBaselineBlockMap: CreateDeltaPackageUsingBaselineBlockMap::<Identity>,
+            UpdatePackage: UpdatePackage::<Identity>,
+            UpdateEncryptedPackage: UpdateEncryptedPackage::<Identity>,
+            UpdatePackageManifest: UpdatePackageManifest::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxPackageEditor {}
 windows_core::imp::define_interface!(IAppxPackageReader, IAppxPackageReader_Vtbl, 0xb5c49650_99bc_481c_9a34_3d53a4106708);
 windows_core::imp::interface_hierarchy!(IAppxPackageReader, windows_core::IUnknown);
@@ -7390,6 +10791,81 @@ impl IAppxPackageReader_Vtbl {
         iid == &<IAppxPackageReader as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxPackageReader_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxPackageReader_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetBlockMap<Identity: windows_core::IUnknownImpl + IAppxPackageReader_Impl>(this: *mut core::ffi::c_void, blockmapreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxPackageReader_Impl::GetBlockMap(this) {
+                    Ok(ok__) => {
+                        blockmapreader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFootprintFile<Identity: windows_core::IUnknownImpl + IAppxPackageReader_Impl>(this: *mut core::ffi::c_void, r#type: APPX_FOOTPRINT_FILE_TYPE, file: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxPackageReader_Impl::GetFootprintFile(this, core::mem::transmute_copy(&r#type)) {
+                    Ok(ok__) => {
+                        file.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPayloadFile<Identity: windows_core::IUnknownImpl + IAppxPackageReader_Impl>(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, file: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxPackageReader_Impl::GetPayloadFile(this, core::mem::transmute(&filename)) {
+                    Ok(ok__) => {
+                        file.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPayloadFiles<Identity: windows_core::IUnknownImpl + IAppxPackageReader_Impl>(this: *mut core::ffi::c_void, filesenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxPackageReader_Impl::GetPayloadFiles(this) {
+                    Ok(ok__) => {
+                        filesenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetManifest<Identity: windows_core::IUnknownImpl + IAppxPackageReader_Impl>(this: *mut core::ffi::c_void, manifestreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxPackageReader_Impl::GetManifest(this) {
+                    Ok(ok__) => {
+                        manifestreader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetBlockMap: GetBlockMap::<Identity>,
+            GetFootprintFile: GetFootprintFile::<Identity>,
+            GetPayloadFile: GetPayloadFile::<Identity>,
+            GetPayloadFiles: GetPayloadFiles::<Identity>,
+            GetManifest: GetManifest::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxPackageReader {}
 windows_core::imp::define_interface!(IAppxPackageWriter, IAppxPackageWriter_Vtbl, 0x9099e33b_246f_41e4_881a_008eb613f858);
 windows_core::imp::interface_hierarchy!(IAppxPackageWriter, windows_core::IUnknown);
@@ -7455,6 +10931,31 @@ impl IAppxPackageWriter_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxPackageWriter_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxPackageWriter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddPayloadFile<Identity: windows_core::IUnknownImpl + IAppxPackageWriter_Impl>(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, contenttype: windows_core::PCWSTR, compressionoption: APPX_COMPRESSION_OPTION, inputstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxPackageWriter_Impl::AddPayloadFile(this, core::mem::transmute(&filename), core::mem::transmute(&contenttype), core::mem::transmute_copy(&compressionoption), core::mem::transmute_copy(&inputstream)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IAppxPackageWriter_Impl>(this: *mut core::ffi::c_void, manifest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxPackageWriter_Impl::Close(this, core::mem::transmute_copy(&manifest)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddPayloadFile: AddPayloadFile::<Identity>,
+            Close: Close::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxPackageWriter {}
 windows_core::imp::define_interface!(IAppxPackageWriter2, IAppxPackageWriter2_Vtbl, 0x2cf5c4fd_e54c_4ea5_ba4e_f8c4b105a8c8);
 windows_core::imp::interface_hierarchy!(IAppxPackageWriter2, windows_core::IUnknown);
@@ -7497,6 +10998,21 @@ impl IAppxPackageWriter2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxPackageWriter2_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxPackageWriter2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IAppxPackageWriter2_Impl>(this: *mut core::ffi::c_void, manifest: *mut core::ffi::c_void, contentgroupmap: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxPackageWriter2_Impl::Close(this, core::mem::transmute_copy(&manifest), core::mem::transmute_copy(&contentgroupmap)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Close: Close::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxPackageWriter2 {}
 windows_core::imp::define_interface!(IAppxPackageWriter3, IAppxPackageWriter3_Vtbl, 0xa83aacd3_41c0_4501_b8a3_74164f50b2fd);
 windows_core::imp::interface_hierarchy!(IAppxPackageWriter3, windows_core::IUnknown);
@@ -7535,6 +11051,21 @@ impl IAppxPackageWriter3_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IAppxPackageWriter3_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxPackageWriter3_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddPayloadFiles<Identity: windows_core::IUnknownImpl + IAppxPackageWriter3_Impl>(this: *mut core::ffi::c_void, filecount: u32, payloadfiles: *const APPX_PACKAGE_WRITER_PAYLOAD_STREAM, memorylimit: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxPackageWriter3_Impl::AddPayloadFiles(this, core::mem::transmute_copy(&filecount), core::mem::transmute_copy(&payloadfiles), core::mem::transmute_copy(&memorylimit)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, AddPayloadFiles: AddPayloadFiles::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IAppxPackageWriter3 {}
 windows_core::imp::define_interface!(IAppxPackagingDiagnosticEventSink, IAppxPackagingDiagnosticEventSink_Vtbl, 0x17239d47_6adb_45d2_80f6_f9cbc3bf059d);
 windows_core::imp::interface_hierarchy!(IAppxPackagingDiagnosticEventSink, windows_core::IUnknown);
@@ -7589,6 +11120,30 @@ impl IAppxPackagingDiagnosticEventSink_Vtbl {
         iid == &<IAppxPackagingDiagnosticEventSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxPackagingDiagnosticEventSink_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxPackagingDiagnosticEventSink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ReportContextChange<Identity: windows_core::IUnknownImpl + IAppxPackagingDiagnosticEventSink_Impl>(this: *mut core::ffi::c_void, changetype: APPX_PACKAGING_CONTEXT_CHANGE_TYPE, contextid: i32, contextname: windows_core::PCSTR, contextmessage: windows_core::PCWSTR, detailsmessage: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxPackagingDiagnosticEventSink_Impl::ReportContextChange(this, core::mem::transmute_copy(&changetype), core::mem::transmute_copy(&contextid), core::mem::transmute(&contextname), core::mem::transmute(&contextmessage), core::mem::transmute(&detailsmessage)).into()
+            }
+        }
+        unsafe extern "system" fn ReportError<Identity: windows_core::IUnknownImpl + IAppxPackagingDiagnosticEventSink_Impl>(this: *mut core::ffi::c_void, errormessage: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxPackagingDiagnosticEventSink_Impl::ReportError(this, core::mem::transmute(&errormessage)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ReportContextChange: ReportContextChange::<Identity>,
+            ReportError: ReportError::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxPackagingDiagnosticEventSink {}
 windows_core::imp::define_interface!(IAppxPackagingDiagnosticEventSinkManager, IAppxPackagingDiagnosticEventSinkManager_Vtbl, 0x369648fa_a7eb_4909_a15d_6954a078f18a);
 windows_core::imp::interface_hierarchy!(IAppxPackagingDiagnosticEventSinkManager, windows_core::IUnknown);
@@ -7623,6 +11178,20 @@ impl IAppxPackagingDiagnosticEventSinkManager_Vtbl {
         iid == &<IAppxPackagingDiagnosticEventSinkManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxPackagingDiagnosticEventSinkManager_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxPackagingDiagnosticEventSinkManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetSinkForProcess<Identity: windows_core::IUnknownImpl + IAppxPackagingDiagnosticEventSinkManager_Impl>(this: *mut core::ffi::c_void, sink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppxPackagingDiagnosticEventSinkManager_Impl::SetSinkForProcess(this, core::mem::transmute_copy(&sink)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetSinkForProcess: SetSinkForProcess::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IAppxPackagingDiagnosticEventSinkManager {}
 windows_core::imp::define_interface!(IAppxSourceContentGroupMapReader, IAppxSourceContentGroupMapReader_Vtbl, 0xf329791d_540b_4a9f_bc75_3282b7d73193);
 windows_core::imp::interface_hierarchy!(IAppxSourceContentGroupMapReader, windows_core::IUnknown);
@@ -7687,6 +11256,42 @@ impl IAppxSourceContentGroupMapReader_Vtbl {
         iid == &<IAppxSourceContentGroupMapReader as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppxSourceContentGroupMapReader_Impl> windows_core::imp::TearOffVtable<Identity> for IAppxSourceContentGroupMapReader_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRequiredGroup<Identity: windows_core::IUnknownImpl + IAppxSourceContentGroupMapReader_Impl>(this: *mut core::ffi::c_void, requiredgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxSourceContentGroupMapReader_Impl::GetRequiredGroup(this) {
+                    Ok(ok__) => {
+                        requiredgroup.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAutomaticGroups<Identity: windows_core::IUnknownImpl + IAppxSourceContentGroupMapReader_Impl>(this: *mut core::ffi::c_void, automaticgroupsenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAppxSourceContentGroupMapReader_Impl::GetAutomaticGroups(this) {
+                    Ok(ok__) => {
+                        automaticgroupsenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetRequiredGroup: GetRequiredGroup::<Identity>,
+            GetAutomaticGroups: GetAutomaticGroups::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAppxSourceContentGroupMapReader {}
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -110,6 +110,69 @@ impl IOpcCertificateEnumerator_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Security_Cryptography")]
+impl<Identity: windows_core::IUnknownImpl + IOpcCertificateEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcCertificateEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IOpcCertificateEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcCertificateEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MovePrevious<Identity: windows_core::IUnknownImpl + IOpcCertificateEnumerator_Impl>(this: *mut core::ffi::c_void, hasprevious: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcCertificateEnumerator_Impl::MovePrevious(this) {
+                    Ok(ok__) => {
+                        hasprevious.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IOpcCertificateEnumerator_Impl>(this: *mut core::ffi::c_void, certificate: *mut *mut super::super::super::Security::Cryptography::CERT_CONTEXT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcCertificateEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        certificate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IOpcCertificateEnumerator_Impl>(this: *mut core::ffi::c_void, copy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcCertificateEnumerator_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        copy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MoveNext: MoveNext::<Identity>,
+            MovePrevious: MovePrevious::<Identity>,
+            GetCurrent: GetCurrent::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Security_Cryptography")]
 impl windows_core::RuntimeName for IOpcCertificateEnumerator {}
 windows_core::imp::define_interface!(IOpcCertificateSet, IOpcCertificateSet_Vtbl, 0x56ea4325_8e2d_4167_b1a4_e486d24c8fa7);
 windows_core::imp::interface_hierarchy!(IOpcCertificateSet, windows_core::IUnknown);
@@ -188,6 +251,44 @@ impl IOpcCertificateSet_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Security_Cryptography")]
+impl<Identity: windows_core::IUnknownImpl + IOpcCertificateSet_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcCertificateSet_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Add<Identity: windows_core::IUnknownImpl + IOpcCertificateSet_Impl>(this: *mut core::ffi::c_void, certificate: *const super::super::super::Security::Cryptography::CERT_CONTEXT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcCertificateSet_Impl::Add(this, core::mem::transmute_copy(&certificate)).into()
+            }
+        }
+        unsafe extern "system" fn Remove<Identity: windows_core::IUnknownImpl + IOpcCertificateSet_Impl>(this: *mut core::ffi::c_void, certificate: *const super::super::super::Security::Cryptography::CERT_CONTEXT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcCertificateSet_Impl::Remove(this, core::mem::transmute_copy(&certificate)).into()
+            }
+        }
+        unsafe extern "system" fn GetEnumerator<Identity: windows_core::IUnknownImpl + IOpcCertificateSet_Impl>(this: *mut core::ffi::c_void, certificateenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcCertificateSet_Impl::GetEnumerator(this) {
+                    Ok(ok__) => {
+                        certificateenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Add: Add::<Identity>,
+            Remove: Remove::<Identity>,
+            GetEnumerator: GetEnumerator::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Security_Cryptography")]
 impl windows_core::RuntimeName for IOpcCertificateSet {}
 windows_core::imp::define_interface!(IOpcDigitalSignature, IOpcDigitalSignature_Vtbl, 0x52ab21dd_1cd0_4949_bc80_0c1232d00cb4);
 windows_core::imp::interface_hierarchy!(IOpcDigitalSignature, windows_core::IUnknown);
@@ -505,6 +606,194 @@ impl IOpcDigitalSignature_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOpcDigitalSignature_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcDigitalSignature_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetNamespaces<Identity: windows_core::IUnknownImpl + IOpcDigitalSignature_Impl>(this: *mut core::ffi::c_void, prefixes: *mut *mut windows_core::PWSTR, namespaces: *mut *mut windows_core::PWSTR, count: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcDigitalSignature_Impl::GetNamespaces(this, core::mem::transmute_copy(&prefixes), core::mem::transmute_copy(&namespaces), core::mem::transmute_copy(&count)).into()
+            }
+        }
+        unsafe extern "system" fn GetSignatureId<Identity: windows_core::IUnknownImpl + IOpcDigitalSignature_Impl>(this: *mut core::ffi::c_void, signatureid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcDigitalSignature_Impl::GetSignatureId(this) {
+                    Ok(ok__) => {
+                        signatureid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSignaturePartName<Identity: windows_core::IUnknownImpl + IOpcDigitalSignature_Impl>(this: *mut core::ffi::c_void, signaturepartname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcDigitalSignature_Impl::GetSignaturePartName(this) {
+                    Ok(ok__) => {
+                        signaturepartname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSignatureMethod<Identity: windows_core::IUnknownImpl + IOpcDigitalSignature_Impl>(this: *mut core::ffi::c_void, signaturemethod: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcDigitalSignature_Impl::GetSignatureMethod(this) {
+                    Ok(ok__) => {
+                        signaturemethod.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCanonicalizationMethod<Identity: windows_core::IUnknownImpl + IOpcDigitalSignature_Impl>(this: *mut core::ffi::c_void, canonicalizationmethod: *mut OPC_CANONICALIZATION_METHOD) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcDigitalSignature_Impl::GetCanonicalizationMethod(this) {
+                    Ok(ok__) => {
+                        canonicalizationmethod.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSignatureValue<Identity: windows_core::IUnknownImpl + IOpcDigitalSignature_Impl>(this: *mut core::ffi::c_void, signaturevalue: *mut *mut u8, count: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcDigitalSignature_Impl::GetSignatureValue(this, core::mem::transmute_copy(&signaturevalue), core::mem::transmute_copy(&count)).into()
+            }
+        }
+        unsafe extern "system" fn GetSignaturePartReferenceEnumerator<Identity: windows_core::IUnknownImpl + IOpcDigitalSignature_Impl>(this: *mut core::ffi::c_void, partreferenceenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcDigitalSignature_Impl::GetSignaturePartReferenceEnumerator(this) {
+                    Ok(ok__) => {
+                        partreferenceenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSignatureRelationshipReferenceEnumerator<Identity: windows_core::IUnknownImpl + IOpcDigitalSignature_Impl>(this: *mut core::ffi::c_void, relationshipreferenceenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcDigitalSignature_Impl::GetSignatureRelationshipReferenceEnumerator(this) {
+                    Ok(ok__) => {
+                        relationshipreferenceenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSigningTime<Identity: windows_core::IUnknownImpl + IOpcDigitalSignature_Impl>(this: *mut core::ffi::c_void, signingtime: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcDigitalSignature_Impl::GetSigningTime(this) {
+                    Ok(ok__) => {
+                        signingtime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTimeFormat<Identity: windows_core::IUnknownImpl + IOpcDigitalSignature_Impl>(this: *mut core::ffi::c_void, timeformat: *mut OPC_SIGNATURE_TIME_FORMAT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcDigitalSignature_Impl::GetTimeFormat(this) {
+                    Ok(ok__) => {
+                        timeformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPackageObjectReference<Identity: windows_core::IUnknownImpl + IOpcDigitalSignature_Impl>(this: *mut core::ffi::c_void, packageobjectreference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcDigitalSignature_Impl::GetPackageObjectReference(this) {
+                    Ok(ok__) => {
+                        packageobjectreference.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCertificateEnumerator<Identity: windows_core::IUnknownImpl + IOpcDigitalSignature_Impl>(this: *mut core::ffi::c_void, certificateenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcDigitalSignature_Impl::GetCertificateEnumerator(this) {
+                    Ok(ok__) => {
+                        certificateenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCustomReferenceEnumerator<Identity: windows_core::IUnknownImpl + IOpcDigitalSignature_Impl>(this: *mut core::ffi::c_void, customreferenceenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcDigitalSignature_Impl::GetCustomReferenceEnumerator(this) {
+                    Ok(ok__) => {
+                        customreferenceenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCustomObjectEnumerator<Identity: windows_core::IUnknownImpl + IOpcDigitalSignature_Impl>(this: *mut core::ffi::c_void, customobjectenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcDigitalSignature_Impl::GetCustomObjectEnumerator(this) {
+                    Ok(ok__) => {
+                        customobjectenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSignatureXml<Identity: windows_core::IUnknownImpl + IOpcDigitalSignature_Impl>(this: *mut core::ffi::c_void, signaturexml: *mut *mut u8, count: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcDigitalSignature_Impl::GetSignatureXml(this, core::mem::transmute_copy(&signaturexml), core::mem::transmute_copy(&count)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetNamespaces: GetNamespaces::<Identity>,
+            GetSignatureId: GetSignatureId::<Identity>,
+            GetSignaturePartName: GetSignaturePartName::<Identity>,
+            GetSignatureMethod: GetSignatureMethod::<Identity>,
+            GetCanonicalizationMethod: GetCanonicalizationMethod::<Identity>,
+            GetSignatureValue: GetSignatureValue::<Identity>,
+            GetSignaturePartReferenceEnumerator: GetSignaturePartReferenceEnumerator::<Identity>,
+            GetSignatureRelationshipReferenceEnumerator: GetSignatureRelationshipReferenceEnumerator::<Identity>,
+            GetSigningTime: GetSigningTime::<Identity>,
+            GetTimeFormat: GetTimeFormat::<Identity>,
+            GetPackageObjectReference: GetPackageObjectReference::<Identity>,
+            GetCertificateEnumerator: GetCertificateEnumerator::<Identity>,
+            GetCustomReferenceEnumerator: GetCustomReferenceEnumerator::<Identity>,
+            GetCustomObjectEnumerator: GetCustomObjectEnumerator::<Identity>,
+            GetSignatureXml: GetSignatureXml::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOpcDigitalSignature {}
 windows_core::imp::define_interface!(IOpcDigitalSignatureEnumerator, IOpcDigitalSignatureEnumerator_Vtbl, 0x967b6882_0ba3_4358_b9e7_b64c75063c5e);
 windows_core::imp::interface_hierarchy!(IOpcDigitalSignatureEnumerator, windows_core::IUnknown);
@@ -611,6 +900,68 @@ impl IOpcDigitalSignatureEnumerator_Vtbl {
         iid == &<IOpcDigitalSignatureEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOpcDigitalSignatureEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcDigitalSignatureEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IOpcDigitalSignatureEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcDigitalSignatureEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MovePrevious<Identity: windows_core::IUnknownImpl + IOpcDigitalSignatureEnumerator_Impl>(this: *mut core::ffi::c_void, hasprevious: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcDigitalSignatureEnumerator_Impl::MovePrevious(this) {
+                    Ok(ok__) => {
+                        hasprevious.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IOpcDigitalSignatureEnumerator_Impl>(this: *mut core::ffi::c_void, digitalsignature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcDigitalSignatureEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        digitalsignature.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IOpcDigitalSignatureEnumerator_Impl>(this: *mut core::ffi::c_void, copy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcDigitalSignatureEnumerator_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        copy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MoveNext: MoveNext::<Identity>,
+            MovePrevious: MovePrevious::<Identity>,
+            GetCurrent: GetCurrent::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOpcDigitalSignatureEnumerator {}
 windows_core::imp::define_interface!(IOpcDigitalSignatureManager, IOpcDigitalSignatureManager_Vtbl, 0xd5e62a0b_696d_462f_94df_72e33cef2659);
 windows_core::imp::interface_hierarchy!(IOpcDigitalSignatureManager, windows_core::IUnknown);
@@ -825,21 +1176,124 @@ impl IOpcDigitalSignatureManager_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Security_Cryptography", feature = "Win32_System_Com"))]
-impl windows_core::RuntimeName for IOpcDigitalSignatureManager {}
-windows_core::imp::define_interface!(IOpcFactory, IOpcFactory_Vtbl, 0x6d0b4446_cd73_4ab3_94f4_8ccdf6116154);
-windows_core::imp::interface_hierarchy!(IOpcFactory, windows_core::IUnknown);
-impl IOpcFactory {
-    #[cfg(feature = "Win32_System_Com")]
-    pub unsafe fn CreatePackageRootUri(&self) -> windows_core::Result<IOpcUri> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreatePackageRootUri)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    #[cfg(feature = "Win32_System_Com")]
-    pub unsafe fn CreatePartUri<P0>(&self, pwzuri: P0) -> windows_core::Result<IOpcPartUri>
-    where
-        P0: windows_core::Param<windows_core::PCWSTR>,
+impl<Identity: windows_core::IUnknownImpl + IOpcDigitalSignatureManager_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcDigitalSignatureManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSignatureOriginPartName<Identity: windows_core::IUnknownImpl + IOpcDigitalSignatureManager_Impl>(this: *mut core::ffi::c_void, signatureoriginpartname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcDigitalSignatureManager_Impl::GetSignatureOriginPartName(this) {
+                    Ok(ok__) => {
+                        signatureoriginpartname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSignatureOriginPartName<Identity: windows_core::IUnknownImpl + IOpcDigitalSignatureManager_Impl>(this: *mut core::ffi::c_void, signatureoriginpartname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcDigitalSignatureManager_Impl::SetSignatureOriginPartName(this, core::mem::transmute_copy(&signatureoriginpartname)).into()
+            }
+        }
+        unsafe extern "system" fn GetSignatureEnumerator<Identity: windows_core::IUnknownImpl + IOpcDigitalSignatureManager_Impl>(this: *mut core::ffi::c_void, signatureenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcDigitalSignatureManager_Impl::GetSignatureEnumerator(this) {
+                    Ok(ok__) => {
+                        signatureenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveSignature<Identity: windows_core::IUnknownImpl + IOpcDigitalSignatureManager_Impl>(this: *mut core::ffi::c_void, signaturepartname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcDigitalSignatureManager_Impl::RemoveSignature(this, core::mem::transmute_copy(&signaturepartname)).into()
+            }
+        }
+        unsafe extern "system" fn CreateSigningOptions<Identity: windows_core::IUnknownImpl + IOpcDigitalSignatureManager_Impl>(this: *mut core::ffi::c_void, signingoptions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcDigitalSignatureManager_Impl::CreateSigningOptions(this) {
+                    Ok(ok__) => {
+                        signingoptions.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Validate<Identity: windows_core::IUnknownImpl + IOpcDigitalSignatureManager_Impl>(this: *mut core::ffi::c_void, signature: *mut core::ffi::c_void, certificate: *const super::super::super::Security::Cryptography::CERT_CONTEXT, validationresult: *mut OPC_SIGNATURE_VALIDATION_RESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcDigitalSignatureManager_Impl::Validate(this, core::mem::transmute_copy(&signature), core::mem::transmute_copy(&certificate)) {
+                    Ok(ok__) => {
+                        validationresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Sign<Identity: windows_core::IUnknownImpl + IOpcDigitalSignatureManager_Impl>(this: *mut core::ffi::c_void, certificate: *const super::super::super::Security::Cryptography::CERT_CONTEXT, signingoptions: *mut core::ffi::c_void, digitalsignature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcDigitalSignatureManager_Impl::Sign(this, core::mem::transmute_copy(&certificate), core::mem::transmute_copy(&signingoptions)) {
+                    Ok(ok__) => {
+                        digitalsignature.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReplaceSignatureXml<Identity: windows_core::IUnknownImpl + IOpcDigitalSignatureManager_Impl>(this: *mut core::ffi::c_void, signaturepartname: *mut core::ffi::c_void, newsignaturexml: *const u8, count: u32, digitalsignature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcDigitalSignatureManager_Impl::ReplaceSignatureXml(this, core::mem::transmute_copy(&signaturepartname), core::mem::transmute_copy(&newsignaturexml), core::mem::transmute_copy(&count)) {
+                    Ok(ok__) => {
+                        digitalsignature.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSignatureOriginPartName: GetSignatureOriginPartName::<Identity>,
+            SetSignatureOriginPartName: SetSignatureOriginPartName::<Identity>,
+            GetSignatureEnumerator: GetSignatureEnumerator::<Identity>,
+            RemoveSignature: RemoveSignature::<Identity>,
+            CreateSigningOptions: CreateSigningOptions::<Identity>,
+            Validate: Validate::<Identity>,
+            Sign: Sign::<Identity>,
+            ReplaceSignatureXml: ReplaceSignatureXml::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Security_Cryptography", feature = "Win32_System_Com"))]
+impl windows_core::RuntimeName for IOpcDigitalSignatureManager {}
+windows_core::imp::define_interface!(IOpcFactory, IOpcFactory_Vtbl, 0x6d0b4446_cd73_4ab3_94f4_8ccdf6116154);
+windows_core::imp::interface_hierarchy!(IOpcFactory, windows_core::IUnknown);
+impl IOpcFactory {
+    #[cfg(feature = "Win32_System_Com")]
+    pub unsafe fn CreatePackageRootUri(&self) -> windows_core::Result<IOpcUri> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreatePackageRootUri)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    #[cfg(feature = "Win32_System_Com")]
+    pub unsafe fn CreatePartUri<P0>(&self, pwzuri: P0) -> windows_core::Result<IOpcPartUri>
+    where
+        P0: windows_core::Param<windows_core::PCWSTR>,
     {
         unsafe {
             let mut result__ = core::mem::zeroed();
@@ -1024,6 +1478,102 @@ impl IOpcFactory_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Security", feature = "Win32_System_Com"))]
+impl<Identity: windows_core::IUnknownImpl + IOpcFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreatePackageRootUri<Identity: windows_core::IUnknownImpl + IOpcFactory_Impl>(this: *mut core::ffi::c_void, rooturi: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcFactory_Impl::CreatePackageRootUri(this) {
+                    Ok(ok__) => {
+                        rooturi.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreatePartUri<Identity: windows_core::IUnknownImpl + IOpcFactory_Impl>(this: *mut core::ffi::c_void, pwzuri: windows_core::PCWSTR, parturi: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcFactory_Impl::CreatePartUri(this, core::mem::transmute(&pwzuri)) {
+                    Ok(ok__) => {
+                        parturi.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateStreamOnFile<Identity: windows_core::IUnknownImpl + IOpcFactory_Impl>(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, iomode: OPC_STREAM_IO_MODE, securityattributes: *const super::super::super::Security::SECURITY_ATTRIBUTES, dwflagsandattributes: u32, stream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcFactory_Impl::CreateStreamOnFile(this, core::mem::transmute(&filename), core::mem::transmute_copy(&iomode), core::mem::transmute_copy(&securityattributes), core::mem::transmute_copy(&dwflagsandattributes)) {
+                    Ok(ok__) => {
+                        stream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreatePackage<Identity: windows_core::IUnknownImpl + IOpcFactory_Impl>(this: *mut core::ffi::c_void, package: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcFactory_Impl::CreatePackage(this) {
+                    Ok(ok__) => {
+                        package.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReadPackageFromStream<Identity: windows_core::IUnknownImpl + IOpcFactory_Impl>(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, flags: OPC_READ_FLAGS, package: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcFactory_Impl::ReadPackageFromStream(this, core::mem::transmute_copy(&stream), core::mem::transmute_copy(&flags)) {
+                    Ok(ok__) => {
+                        package.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn WritePackageToStream<Identity: windows_core::IUnknownImpl + IOpcFactory_Impl>(this: *mut core::ffi::c_void, package: *mut core::ffi::c_void, flags: OPC_WRITE_FLAGS, stream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcFactory_Impl::WritePackageToStream(this, core::mem::transmute_copy(&package), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&stream)).into()
+            }
+        }
+        unsafe extern "system" fn CreateDigitalSignatureManager<Identity: windows_core::IUnknownImpl + IOpcFactory_Impl>(this: *mut core::ffi::c_void, package: *mut core::ffi::c_void, signaturemanager: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcFactory_Impl::CreateDigitalSignatureManager(this, core::mem::transmute_copy(&package)) {
+                    Ok(ok__) => {
+                        signaturemanager.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreatePackageRootUri: CreatePackageRootUri::<Identity>,
+            CreatePartUri: CreatePartUri::<Identity>,
+            CreateStreamOnFile: CreateStreamOnFile::<Identity>,
+            CreatePackage: CreatePackage::<Identity>,
+            ReadPackageFromStream: ReadPackageFromStream::<Identity>,
+            WritePackageToStream: WritePackageToStream::<Identity>,
+            CreateDigitalSignatureManager: CreateDigitalSignatureManager::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Security", feature = "Win32_System_Com"))]
 impl windows_core::RuntimeName for IOpcFactory {}
 windows_core::imp::define_interface!(IOpcPackage, IOpcPackage_Vtbl, 0x42195949_3b79_4fc8_89c6_fc7fb979ee70);
 windows_core::imp::interface_hierarchy!(IOpcPackage, windows_core::IUnknown);
@@ -1088,6 +1638,42 @@ impl IOpcPackage_Vtbl {
         iid == &<IOpcPackage as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOpcPackage_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcPackage_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPartSet<Identity: windows_core::IUnknownImpl + IOpcPackage_Impl>(this: *mut core::ffi::c_void, partset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcPackage_Impl::GetPartSet(this) {
+                    Ok(ok__) => {
+                        partset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRelationshipSet<Identity: windows_core::IUnknownImpl + IOpcPackage_Impl>(this: *mut core::ffi::c_void, relationshipset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcPackage_Impl::GetRelationshipSet(this) {
+                    Ok(ok__) => {
+                        relationshipset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPartSet: GetPartSet::<Identity>,
+            GetRelationshipSet: GetRelationshipSet::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOpcPackage {}
 windows_core::imp::define_interface!(IOpcPart, IOpcPart_Vtbl, 0x42195949_3b79_4fc8_89c6_fc7fb979ee71);
 windows_core::imp::interface_hierarchy!(IOpcPart, windows_core::IUnknown);
@@ -1226,6 +1812,82 @@ impl IOpcPart_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOpcPart_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcPart_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRelationshipSet<Identity: windows_core::IUnknownImpl + IOpcPart_Impl>(this: *mut core::ffi::c_void, relationshipset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcPart_Impl::GetRelationshipSet(this) {
+                    Ok(ok__) => {
+                        relationshipset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetContentStream<Identity: windows_core::IUnknownImpl + IOpcPart_Impl>(this: *mut core::ffi::c_void, stream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcPart_Impl::GetContentStream(this) {
+                    Ok(ok__) => {
+                        stream.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IOpcPart_Impl>(this: *mut core::ffi::c_void, name: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcPart_Impl::GetName(this) {
+                    Ok(ok__) => {
+                        name.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetContentType<Identity: windows_core::IUnknownImpl + IOpcPart_Impl>(this: *mut core::ffi::c_void, contenttype: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcPart_Impl::GetContentType(this) {
+                    Ok(ok__) => {
+                        contenttype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCompressionOptions<Identity: windows_core::IUnknownImpl + IOpcPart_Impl>(this: *mut core::ffi::c_void, compressionoptions: *mut OPC_COMPRESSION_OPTIONS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcPart_Impl::GetCompressionOptions(this) {
+                    Ok(ok__) => {
+                        compressionoptions.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetRelationshipSet: GetRelationshipSet::<Identity>,
+            GetContentStream: GetContentStream::<Identity>,
+            GetName: GetName::<Identity>,
+            GetContentType: GetContentType::<Identity>,
+            GetCompressionOptions: GetCompressionOptions::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOpcPart {}
 windows_core::imp::define_interface!(IOpcPartEnumerator, IOpcPartEnumerator_Vtbl, 0x42195949_3b79_4fc8_89c6_fc7fb979ee75);
 windows_core::imp::interface_hierarchy!(IOpcPartEnumerator, windows_core::IUnknown);
@@ -1332,26 +1994,88 @@ impl IOpcPartEnumerator_Vtbl {
         iid == &<IOpcPartEnumerator as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IOpcPartEnumerator {}
-windows_core::imp::define_interface!(IOpcPartSet, IOpcPartSet_Vtbl, 0x42195949_3b79_4fc8_89c6_fc7fb979ee73);
-windows_core::imp::interface_hierarchy!(IOpcPartSet, windows_core::IUnknown);
-impl IOpcPartSet {
-    #[cfg(feature = "Win32_System_Com")]
-    pub unsafe fn GetPart<P0>(&self, name: P0) -> windows_core::Result<IOpcPart>
-    where
-        P0: windows_core::Param<IOpcPartUri>,
-    {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetPart)(windows_core::Interface::as_raw(self), name.param().abi(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+impl<Identity: windows_core::IUnknownImpl + IOpcPartEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcPartEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IOpcPartEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcPartEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    #[cfg(feature = "Win32_System_Com")]
-    pub unsafe fn CreatePart<P0, P1>(&self, name: P0, contenttype: P1, compressionoptions: OPC_COMPRESSION_OPTIONS) -> windows_core::Result<IOpcPart>
-    where
-        P0: windows_core::Param<IOpcPartUri>,
-        P1: windows_core::Param<windows_core::PCWSTR>,
-    {
+        unsafe extern "system" fn MovePrevious<Identity: windows_core::IUnknownImpl + IOpcPartEnumerator_Impl>(this: *mut core::ffi::c_void, hasprevious: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcPartEnumerator_Impl::MovePrevious(this) {
+                    Ok(ok__) => {
+                        hasprevious.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IOpcPartEnumerator_Impl>(this: *mut core::ffi::c_void, part: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcPartEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        part.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IOpcPartEnumerator_Impl>(this: *mut core::ffi::c_void, copy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcPartEnumerator_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        copy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MoveNext: MoveNext::<Identity>,
+            MovePrevious: MovePrevious::<Identity>,
+            GetCurrent: GetCurrent::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IOpcPartEnumerator {}
+windows_core::imp::define_interface!(IOpcPartSet, IOpcPartSet_Vtbl, 0x42195949_3b79_4fc8_89c6_fc7fb979ee73);
+windows_core::imp::interface_hierarchy!(IOpcPartSet, windows_core::IUnknown);
+impl IOpcPartSet {
+    #[cfg(feature = "Win32_System_Com")]
+    pub unsafe fn GetPart<P0>(&self, name: P0) -> windows_core::Result<IOpcPart>
+    where
+        P0: windows_core::Param<IOpcPartUri>,
+    {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetPart)(windows_core::Interface::as_raw(self), name.param().abi(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    #[cfg(feature = "Win32_System_Com")]
+    pub unsafe fn CreatePart<P0, P1>(&self, name: P0, contenttype: P1, compressionoptions: OPC_COMPRESSION_OPTIONS) -> windows_core::Result<IOpcPart>
+    where
+        P0: windows_core::Param<IOpcPartUri>,
+        P1: windows_core::Param<windows_core::PCWSTR>,
+    {
         unsafe {
             let mut result__ = core::mem::zeroed();
             (windows_core::Interface::vtable(self).CreatePart)(windows_core::Interface::as_raw(self), name.param().abi(), contenttype.param().abi(), compressionoptions, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
@@ -1482,6 +2206,76 @@ impl IOpcPartSet_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOpcPartSet_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcPartSet_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPart<Identity: windows_core::IUnknownImpl + IOpcPartSet_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, part: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcPartSet_Impl::GetPart(this, core::mem::transmute_copy(&name)) {
+                    Ok(ok__) => {
+                        part.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreatePart<Identity: windows_core::IUnknownImpl + IOpcPartSet_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, contenttype: windows_core::PCWSTR, compressionoptions: OPC_COMPRESSION_OPTIONS, part: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcPartSet_Impl::CreatePart(this, core::mem::transmute_copy(&name), core::mem::transmute(&contenttype), core::mem::transmute_copy(&compressionoptions)) {
+                    Ok(ok__) => {
+                        part.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeletePart<Identity: windows_core::IUnknownImpl + IOpcPartSet_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcPartSet_Impl::DeletePart(this, core::mem::transmute_copy(&name)).into()
+            }
+        }
+        unsafe extern "system" fn PartExists<Identity: windows_core::IUnknownImpl + IOpcPartSet_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, partexists: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcPartSet_Impl::PartExists(this, core::mem::transmute_copy(&name)) {
+                    Ok(ok__) => {
+                        partexists.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetEnumerator<Identity: windows_core::IUnknownImpl + IOpcPartSet_Impl>(this: *mut core::ffi::c_void, partenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcPartSet_Impl::GetEnumerator(this) {
+                    Ok(ok__) => {
+                        partenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPart: GetPart::<Identity>,
+            CreatePart: CreatePart::<Identity>,
+            DeletePart: DeletePart::<Identity>,
+            PartExists: PartExists::<Identity>,
+            GetEnumerator: GetEnumerator::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOpcPartSet {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IOpcPartUri, IOpcPartUri_Vtbl, 0x7d3babe7_88b2_46ba_85cb_4203cb016c87);
@@ -1584,6 +2378,56 @@ impl IOpcPartUri_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOpcPartUri_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcPartUri_Vtbl
+where
+    IOpcUri_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ComparePartUri<Identity: windows_core::IUnknownImpl + IOpcPartUri_Impl>(this: *mut core::ffi::c_void, parturi: *mut core::ffi::c_void, comparisonresult: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcPartUri_Impl::ComparePartUri(this, core::mem::transmute_copy(&parturi)) {
+                    Ok(ok__) => {
+                        comparisonresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSourceUri<Identity: windows_core::IUnknownImpl + IOpcPartUri_Impl>(this: *mut core::ffi::c_void, sourceuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcPartUri_Impl::GetSourceUri(this) {
+                    Ok(ok__) => {
+                        sourceuri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsRelationshipsPartUri<Identity: windows_core::IUnknownImpl + IOpcPartUri_Impl>(this: *mut core::ffi::c_void, isrelationshipuri: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcPartUri_Impl::IsRelationshipsPartUri(this) {
+                    Ok(ok__) => {
+                        isrelationshipuri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IOpcUri_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ComparePartUri: ComparePartUri::<Identity>,
+            GetSourceUri: GetSourceUri::<Identity>,
+            IsRelationshipsPartUri: IsRelationshipsPartUri::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOpcPartUri {}
 windows_core::imp::define_interface!(IOpcRelationship, IOpcRelationship_Vtbl, 0x42195949_3b79_4fc8_89c6_fc7fb979ee72);
 windows_core::imp::interface_hierarchy!(IOpcRelationship, windows_core::IUnknown);
@@ -1722,6 +2566,82 @@ impl IOpcRelationship_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOpcRelationship_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcRelationship_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetId<Identity: windows_core::IUnknownImpl + IOpcRelationship_Impl>(this: *mut core::ffi::c_void, relationshipidentifier: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcRelationship_Impl::GetId(this) {
+                    Ok(ok__) => {
+                        relationshipidentifier.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRelationshipType<Identity: windows_core::IUnknownImpl + IOpcRelationship_Impl>(this: *mut core::ffi::c_void, relationshiptype: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcRelationship_Impl::GetRelationshipType(this) {
+                    Ok(ok__) => {
+                        relationshiptype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSourceUri<Identity: windows_core::IUnknownImpl + IOpcRelationship_Impl>(this: *mut core::ffi::c_void, sourceuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcRelationship_Impl::GetSourceUri(this) {
+                    Ok(ok__) => {
+                        sourceuri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTargetUri<Identity: windows_core::IUnknownImpl + IOpcRelationship_Impl>(this: *mut core::ffi::c_void, targeturi: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcRelationship_Impl::GetTargetUri(this) {
+                    Ok(ok__) => {
+                        targeturi.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTargetMode<Identity: windows_core::IUnknownImpl + IOpcRelationship_Impl>(this: *mut core::ffi::c_void, targetmode: *mut OPC_URI_TARGET_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcRelationship_Impl::GetTargetMode(this) {
+                    Ok(ok__) => {
+                        targetmode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetId: GetId::<Identity>,
+            GetRelationshipType: GetRelationshipType::<Identity>,
+            GetSourceUri: GetSourceUri::<Identity>,
+            GetTargetUri: GetTargetUri::<Identity>,
+            GetTargetMode: GetTargetMode::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOpcRelationship {}
 windows_core::imp::define_interface!(IOpcRelationshipEnumerator, IOpcRelationshipEnumerator_Vtbl, 0x42195949_3b79_4fc8_89c6_fc7fb979ee76);
 windows_core::imp::interface_hierarchy!(IOpcRelationshipEnumerator, windows_core::IUnknown);
@@ -1828,6 +2748,68 @@ impl IOpcRelationshipEnumerator_Vtbl {
         iid == &<IOpcRelationshipEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOpcRelationshipEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcRelationshipEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IOpcRelationshipEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcRelationshipEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MovePrevious<Identity: windows_core::IUnknownImpl + IOpcRelationshipEnumerator_Impl>(this: *mut core::ffi::c_void, hasprevious: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcRelationshipEnumerator_Impl::MovePrevious(this) {
+                    Ok(ok__) => {
+                        hasprevious.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IOpcRelationshipEnumerator_Impl>(this: *mut core::ffi::c_void, relationship: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcRelationshipEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        relationship.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IOpcRelationshipEnumerator_Impl>(this: *mut core::ffi::c_void, copy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcRelationshipEnumerator_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        copy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MoveNext: MoveNext::<Identity>,
+            MovePrevious: MovePrevious::<Identity>,
+            GetCurrent: GetCurrent::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOpcRelationshipEnumerator {}
 windows_core::imp::define_interface!(IOpcRelationshipSelector, IOpcRelationshipSelector_Vtbl, 0xf8f26c7f_b28f_4899_84c8_5d5639ede75f);
 windows_core::imp::interface_hierarchy!(IOpcRelationshipSelector, windows_core::IUnknown);
@@ -1892,6 +2874,42 @@ impl IOpcRelationshipSelector_Vtbl {
         iid == &<IOpcRelationshipSelector as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOpcRelationshipSelector_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcRelationshipSelector_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSelectorType<Identity: windows_core::IUnknownImpl + IOpcRelationshipSelector_Impl>(this: *mut core::ffi::c_void, selector: *mut OPC_RELATIONSHIP_SELECTOR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcRelationshipSelector_Impl::GetSelectorType(this) {
+                    Ok(ok__) => {
+                        selector.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSelectionCriterion<Identity: windows_core::IUnknownImpl + IOpcRelationshipSelector_Impl>(this: *mut core::ffi::c_void, selectioncriterion: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcRelationshipSelector_Impl::GetSelectionCriterion(this) {
+                    Ok(ok__) => {
+                        selectioncriterion.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSelectorType: GetSelectorType::<Identity>,
+            GetSelectionCriterion: GetSelectionCriterion::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOpcRelationshipSelector {}
 windows_core::imp::define_interface!(IOpcRelationshipSelectorEnumerator, IOpcRelationshipSelectorEnumerator_Vtbl, 0x5e50a181_a91b_48ac_88d2_bca3d8f8c0b1);
 windows_core::imp::interface_hierarchy!(IOpcRelationshipSelectorEnumerator, windows_core::IUnknown);
@@ -1914,33 +2932,98 @@ impl IOpcRelationshipSelectorEnumerator {
             (windows_core::Interface::vtable(self).GetCurrent)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
     }
-    pub unsafe fn Clone(&self) -> windows_core::Result<IOpcRelationshipSelectorEnumerator> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).Clone)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
+    pub unsafe fn Clone(&self) -> windows_core::Result<IOpcRelationshipSelectorEnumerator> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).Clone)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IOpcRelationshipSelectorEnumerator_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub MoveNext: unsafe extern "system" fn(*mut core::ffi::c_void, *mut windows_core::BOOL) -> windows_core::HRESULT,
+    pub MovePrevious: unsafe extern "system" fn(*mut core::ffi::c_void, *mut windows_core::BOOL) -> windows_core::HRESULT,
+    pub GetCurrent: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub Clone: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+pub trait IOpcRelationshipSelectorEnumerator_Impl: windows_core::IUnknownImpl {
+    fn MoveNext(&self) -> windows_core::Result<windows_core::BOOL>;
+    fn MovePrevious(&self) -> windows_core::Result<windows_core::BOOL>;
+    fn GetCurrent(&self) -> windows_core::Result<IOpcRelationshipSelector>;
+    fn Clone(&self) -> windows_core::Result<IOpcRelationshipSelectorEnumerator>;
+}
+impl IOpcRelationshipSelectorEnumerator_Vtbl {
+    pub const fn new<Identity: IOpcRelationshipSelectorEnumerator_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn MoveNext<Identity: IOpcRelationshipSelectorEnumerator_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOpcRelationshipSelectorEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MovePrevious<Identity: IOpcRelationshipSelectorEnumerator_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hasprevious: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOpcRelationshipSelectorEnumerator_Impl::MovePrevious(this) {
+                    Ok(ok__) => {
+                        hasprevious.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCurrent<Identity: IOpcRelationshipSelectorEnumerator_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, relationshipselector: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOpcRelationshipSelectorEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        relationshipselector.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: IOpcRelationshipSelectorEnumerator_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, copy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOpcRelationshipSelectorEnumerator_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        copy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            MoveNext: MoveNext::<Identity, OFFSET>,
+            MovePrevious: MovePrevious::<Identity, OFFSET>,
+            GetCurrent: GetCurrent::<Identity, OFFSET>,
+            Clone: Clone::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcRelationshipSelectorEnumerator as windows_core::Interface>::IID
     }
 }
-#[repr(C)]
-#[doc(hidden)]
-pub struct IOpcRelationshipSelectorEnumerator_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    pub MoveNext: unsafe extern "system" fn(*mut core::ffi::c_void, *mut windows_core::BOOL) -> windows_core::HRESULT,
-    pub MovePrevious: unsafe extern "system" fn(*mut core::ffi::c_void, *mut windows_core::BOOL) -> windows_core::HRESULT,
-    pub GetCurrent: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub Clone: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-pub trait IOpcRelationshipSelectorEnumerator_Impl: windows_core::IUnknownImpl {
-    fn MoveNext(&self) -> windows_core::Result<windows_core::BOOL>;
-    fn MovePrevious(&self) -> windows_core::Result<windows_core::BOOL>;
-    fn GetCurrent(&self) -> windows_core::Result<IOpcRelationshipSelector>;
-    fn Clone(&self) -> windows_core::Result<IOpcRelationshipSelectorEnumerator>;
-}
-impl IOpcRelationshipSelectorEnumerator_Vtbl {
-    pub const fn new<Identity: IOpcRelationshipSelectorEnumerator_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn MoveNext<Identity: IOpcRelationshipSelectorEnumerator_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+impl<Identity: windows_core::IUnknownImpl + IOpcRelationshipSelectorEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcRelationshipSelectorEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IOpcRelationshipSelectorEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOpcRelationshipSelectorEnumerator_Impl::MoveNext(this) {
                     Ok(ok__) => {
                         hasnext.write(core::mem::transmute(ok__));
@@ -1950,9 +3033,9 @@ impl IOpcRelationshipSelectorEnumerator_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn MovePrevious<Identity: IOpcRelationshipSelectorEnumerator_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hasprevious: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn MovePrevious<Identity: windows_core::IUnknownImpl + IOpcRelationshipSelectorEnumerator_Impl>(this: *mut core::ffi::c_void, hasprevious: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOpcRelationshipSelectorEnumerator_Impl::MovePrevious(this) {
                     Ok(ok__) => {
                         hasprevious.write(core::mem::transmute(ok__));
@@ -1962,9 +3045,9 @@ impl IOpcRelationshipSelectorEnumerator_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetCurrent<Identity: IOpcRelationshipSelectorEnumerator_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, relationshipselector: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IOpcRelationshipSelectorEnumerator_Impl>(this: *mut core::ffi::c_void, relationshipselector: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOpcRelationshipSelectorEnumerator_Impl::GetCurrent(this) {
                     Ok(ok__) => {
                         relationshipselector.write(core::mem::transmute(ok__));
@@ -1974,9 +3057,9 @@ impl IOpcRelationshipSelectorEnumerator_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Clone<Identity: IOpcRelationshipSelectorEnumerator_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, copy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IOpcRelationshipSelectorEnumerator_Impl>(this: *mut core::ffi::c_void, copy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOpcRelationshipSelectorEnumerator_Impl::Clone(this) {
                     Ok(ok__) => {
                         copy.write(core::mem::transmute(ok__));
@@ -1987,16 +3070,13 @@ impl IOpcRelationshipSelectorEnumerator_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            MoveNext: MoveNext::<Identity, OFFSET>,
-            MovePrevious: MovePrevious::<Identity, OFFSET>,
-            GetCurrent: GetCurrent::<Identity, OFFSET>,
-            Clone: Clone::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MoveNext: MoveNext::<Identity>,
+            MovePrevious: MovePrevious::<Identity>,
+            GetCurrent: GetCurrent::<Identity>,
+            Clone: Clone::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IOpcRelationshipSelectorEnumerator as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IOpcRelationshipSelectorEnumerator {}
 windows_core::imp::define_interface!(IOpcRelationshipSelectorSet, IOpcRelationshipSelectorSet_Vtbl, 0x6e34c269_a4d3_47c0_b5c4_87ff2b3b6136);
@@ -2080,6 +3160,49 @@ impl IOpcRelationshipSelectorSet_Vtbl {
         iid == &<IOpcRelationshipSelectorSet as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOpcRelationshipSelectorSet_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcRelationshipSelectorSet_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Create<Identity: windows_core::IUnknownImpl + IOpcRelationshipSelectorSet_Impl>(this: *mut core::ffi::c_void, selector: OPC_RELATIONSHIP_SELECTOR, selectioncriterion: windows_core::PCWSTR, relationshipselector: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcRelationshipSelectorSet_Impl::Create(this, core::mem::transmute_copy(&selector), core::mem::transmute(&selectioncriterion)) {
+                    Ok(ok__) => {
+                        relationshipselector.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IOpcRelationshipSelectorSet_Impl>(this: *mut core::ffi::c_void, relationshipselector: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcRelationshipSelectorSet_Impl::Delete(this, core::mem::transmute_copy(&relationshipselector)).into()
+            }
+        }
+        unsafe extern "system" fn GetEnumerator<Identity: windows_core::IUnknownImpl + IOpcRelationshipSelectorSet_Impl>(this: *mut core::ffi::c_void, relationshipselectorenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcRelationshipSelectorSet_Impl::GetEnumerator(this) {
+                    Ok(ok__) => {
+                        relationshipselectorenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Create: Create::<Identity>,
+            Delete: Delete::<Identity>,
+            GetEnumerator: GetEnumerator::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOpcRelationshipSelectorSet {}
 windows_core::imp::define_interface!(IOpcRelationshipSet, IOpcRelationshipSet_Vtbl, 0x42195949_3b79_4fc8_89c6_fc7fb979ee74);
 windows_core::imp::interface_hierarchy!(IOpcRelationshipSet, windows_core::IUnknown);
@@ -2268,6 +3391,102 @@ impl IOpcRelationshipSet_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOpcRelationshipSet_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcRelationshipSet_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRelationship<Identity: windows_core::IUnknownImpl + IOpcRelationshipSet_Impl>(this: *mut core::ffi::c_void, relationshipidentifier: windows_core::PCWSTR, relationship: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcRelationshipSet_Impl::GetRelationship(this, core::mem::transmute(&relationshipidentifier)) {
+                    Ok(ok__) => {
+                        relationship.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateRelationship<Identity: windows_core::IUnknownImpl + IOpcRelationshipSet_Impl>(this: *mut core::ffi::c_void, relationshipidentifier: windows_core::PCWSTR, relationshiptype: windows_core::PCWSTR, targeturi: *mut core::ffi::c_void, targetmode: OPC_URI_TARGET_MODE, relationship: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcRelationshipSet_Impl::CreateRelationship(this, core::mem::transmute(&relationshipidentifier), core::mem::transmute(&relationshiptype), core::mem::transmute_copy(&targeturi), core::mem::transmute_copy(&targetmode)) {
+                    Ok(ok__) => {
+                        relationship.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeleteRelationship<Identity: windows_core::IUnknownImpl + IOpcRelationshipSet_Impl>(this: *mut core::ffi::c_void, relationshipidentifier: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcRelationshipSet_Impl::DeleteRelationship(this, core::mem::transmute(&relationshipidentifier)).into()
+            }
+        }
+        unsafe extern "system" fn RelationshipExists<Identity: windows_core::IUnknownImpl + IOpcRelationshipSet_Impl>(this: *mut core::ffi::c_void, relationshipidentifier: windows_core::PCWSTR, relationshipexists: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcRelationshipSet_Impl::RelationshipExists(this, core::mem::transmute(&relationshipidentifier)) {
+                    Ok(ok__) => {
+                        relationshipexists.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetEnumerator<Identity: windows_core::IUnknownImpl + IOpcRelationshipSet_Impl>(this: *mut core::ffi::c_void, relationshipenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcRelationshipSet_Impl::GetEnumerator(this) {
+                    Ok(ok__) => {
+                        relationshipenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetEnumeratorForType<Identity: windows_core::IUnknownImpl + IOpcRelationshipSet_Impl>(this: *mut core::ffi::c_void, relationshiptype: windows_core::PCWSTR, relationshipenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcRelationshipSet_Impl::GetEnumeratorForType(this, core::mem::transmute(&relationshiptype)) {
+                    Ok(ok__) => {
+                        relationshipenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRelationshipsContentStream<Identity: windows_core::IUnknownImpl + IOpcRelationshipSet_Impl>(this: *mut core::ffi::c_void, contents: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcRelationshipSet_Impl::GetRelationshipsContentStream(this) {
+                    Ok(ok__) => {
+                        contents.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetRelationship: GetRelationship::<Identity>,
+            CreateRelationship: CreateRelationship::<Identity>,
+            DeleteRelationship: DeleteRelationship::<Identity>,
+            RelationshipExists: RelationshipExists::<Identity>,
+            GetEnumerator: GetEnumerator::<Identity>,
+            GetEnumeratorForType: GetEnumeratorForType::<Identity>,
+            GetRelationshipsContentStream: GetRelationshipsContentStream::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOpcRelationshipSet {}
 windows_core::imp::define_interface!(IOpcSignatureCustomObject, IOpcSignatureCustomObject_Vtbl, 0x5d77a19e_62c1_44e7_becd_45da5ae51a56);
 windows_core::imp::interface_hierarchy!(IOpcSignatureCustomObject, windows_core::IUnknown);
@@ -2299,6 +3518,20 @@ impl IOpcSignatureCustomObject_Vtbl {
         iid == &<IOpcSignatureCustomObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOpcSignatureCustomObject_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcSignatureCustomObject_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetXml<Identity: windows_core::IUnknownImpl + IOpcSignatureCustomObject_Impl>(this: *mut core::ffi::c_void, xmlmarkup: *mut *mut u8, count: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcSignatureCustomObject_Impl::GetXml(this, core::mem::transmute_copy(&xmlmarkup), core::mem::transmute_copy(&count)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetXml: GetXml::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IOpcSignatureCustomObject {}
 windows_core::imp::define_interface!(IOpcSignatureCustomObjectEnumerator, IOpcSignatureCustomObjectEnumerator_Vtbl, 0x5ee4fe1d_e1b0_4683_8079_7ea0fcf80b4c);
 windows_core::imp::interface_hierarchy!(IOpcSignatureCustomObjectEnumerator, windows_core::IUnknown);
@@ -2405,6 +3638,68 @@ impl IOpcSignatureCustomObjectEnumerator_Vtbl {
         iid == &<IOpcSignatureCustomObjectEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOpcSignatureCustomObjectEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcSignatureCustomObjectEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IOpcSignatureCustomObjectEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureCustomObjectEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MovePrevious<Identity: windows_core::IUnknownImpl + IOpcSignatureCustomObjectEnumerator_Impl>(this: *mut core::ffi::c_void, hasprevious: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureCustomObjectEnumerator_Impl::MovePrevious(this) {
+                    Ok(ok__) => {
+                        hasprevious.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IOpcSignatureCustomObjectEnumerator_Impl>(this: *mut core::ffi::c_void, customobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureCustomObjectEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        customobject.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IOpcSignatureCustomObjectEnumerator_Impl>(this: *mut core::ffi::c_void, copy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureCustomObjectEnumerator_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        copy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MoveNext: MoveNext::<Identity>,
+            MovePrevious: MovePrevious::<Identity>,
+            GetCurrent: GetCurrent::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOpcSignatureCustomObjectEnumerator {}
 windows_core::imp::define_interface!(IOpcSignatureCustomObjectSet, IOpcSignatureCustomObjectSet_Vtbl, 0x8f792ac5_7947_4e11_bc3d_2659ff046ae1);
 windows_core::imp::interface_hierarchy!(IOpcSignatureCustomObjectSet, windows_core::IUnknown);
@@ -2484,6 +3779,49 @@ impl IOpcSignatureCustomObjectSet_Vtbl {
         iid == &<IOpcSignatureCustomObjectSet as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOpcSignatureCustomObjectSet_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcSignatureCustomObjectSet_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Create<Identity: windows_core::IUnknownImpl + IOpcSignatureCustomObjectSet_Impl>(this: *mut core::ffi::c_void, xmlmarkup: *const u8, count: u32, customobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureCustomObjectSet_Impl::Create(this, core::mem::transmute_copy(&xmlmarkup), core::mem::transmute_copy(&count)) {
+                    Ok(ok__) => {
+                        customobject.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IOpcSignatureCustomObjectSet_Impl>(this: *mut core::ffi::c_void, customobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcSignatureCustomObjectSet_Impl::Delete(this, core::mem::transmute_copy(&customobject)).into()
+            }
+        }
+        unsafe extern "system" fn GetEnumerator<Identity: windows_core::IUnknownImpl + IOpcSignatureCustomObjectSet_Impl>(this: *mut core::ffi::c_void, customobjectenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureCustomObjectSet_Impl::GetEnumerator(this) {
+                    Ok(ok__) => {
+                        customobjectenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Create: Create::<Identity>,
+            Delete: Delete::<Identity>,
+            GetEnumerator: GetEnumerator::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOpcSignatureCustomObjectSet {}
 windows_core::imp::define_interface!(IOpcSignaturePartReference, IOpcSignaturePartReference_Vtbl, 0xe24231ca_59f4_484e_b64b_36eeda36072c);
 windows_core::imp::interface_hierarchy!(IOpcSignaturePartReference, windows_core::IUnknown);
@@ -2609,6 +3947,76 @@ impl IOpcSignaturePartReference_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOpcSignaturePartReference_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcSignaturePartReference_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPartName<Identity: windows_core::IUnknownImpl + IOpcSignaturePartReference_Impl>(this: *mut core::ffi::c_void, partname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignaturePartReference_Impl::GetPartName(this) {
+                    Ok(ok__) => {
+                        partname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetContentType<Identity: windows_core::IUnknownImpl + IOpcSignaturePartReference_Impl>(this: *mut core::ffi::c_void, contenttype: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignaturePartReference_Impl::GetContentType(this) {
+                    Ok(ok__) => {
+                        contenttype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDigestMethod<Identity: windows_core::IUnknownImpl + IOpcSignaturePartReference_Impl>(this: *mut core::ffi::c_void, digestmethod: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignaturePartReference_Impl::GetDigestMethod(this) {
+                    Ok(ok__) => {
+                        digestmethod.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDigestValue<Identity: windows_core::IUnknownImpl + IOpcSignaturePartReference_Impl>(this: *mut core::ffi::c_void, digestvalue: *mut *mut u8, count: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcSignaturePartReference_Impl::GetDigestValue(this, core::mem::transmute_copy(&digestvalue), core::mem::transmute_copy(&count)).into()
+            }
+        }
+        unsafe extern "system" fn GetTransformMethod<Identity: windows_core::IUnknownImpl + IOpcSignaturePartReference_Impl>(this: *mut core::ffi::c_void, transformmethod: *mut OPC_CANONICALIZATION_METHOD) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignaturePartReference_Impl::GetTransformMethod(this) {
+                    Ok(ok__) => {
+                        transformmethod.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPartName: GetPartName::<Identity>,
+            GetContentType: GetContentType::<Identity>,
+            GetDigestMethod: GetDigestMethod::<Identity>,
+            GetDigestValue: GetDigestValue::<Identity>,
+            GetTransformMethod: GetTransformMethod::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOpcSignaturePartReference {}
 windows_core::imp::define_interface!(IOpcSignaturePartReferenceEnumerator, IOpcSignaturePartReferenceEnumerator_Vtbl, 0x80eb1561_8c77_49cf_8266_459b356ee99a);
 windows_core::imp::interface_hierarchy!(IOpcSignaturePartReferenceEnumerator, windows_core::IUnknown);
@@ -2715,6 +4123,68 @@ impl IOpcSignaturePartReferenceEnumerator_Vtbl {
         iid == &<IOpcSignaturePartReferenceEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOpcSignaturePartReferenceEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcSignaturePartReferenceEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IOpcSignaturePartReferenceEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignaturePartReferenceEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MovePrevious<Identity: windows_core::IUnknownImpl + IOpcSignaturePartReferenceEnumerator_Impl>(this: *mut core::ffi::c_void, hasprevious: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignaturePartReferenceEnumerator_Impl::MovePrevious(this) {
+                    Ok(ok__) => {
+                        hasprevious.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IOpcSignaturePartReferenceEnumerator_Impl>(this: *mut core::ffi::c_void, partreference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignaturePartReferenceEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        partreference.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IOpcSignaturePartReferenceEnumerator_Impl>(this: *mut core::ffi::c_void, copy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignaturePartReferenceEnumerator_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        copy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MoveNext: MoveNext::<Identity>,
+            MovePrevious: MovePrevious::<Identity>,
+            GetCurrent: GetCurrent::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOpcSignaturePartReferenceEnumerator {}
 windows_core::imp::define_interface!(IOpcSignaturePartReferenceSet, IOpcSignaturePartReferenceSet_Vtbl, 0x6c9fe28c_ecd9_4b22_9d36_7fdde670fec0);
 windows_core::imp::interface_hierarchy!(IOpcSignaturePartReferenceSet, windows_core::IUnknown);
@@ -2805,6 +4275,50 @@ impl IOpcSignaturePartReferenceSet_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOpcSignaturePartReferenceSet_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcSignaturePartReferenceSet_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Create<Identity: windows_core::IUnknownImpl + IOpcSignaturePartReferenceSet_Impl>(this: *mut core::ffi::c_void, parturi: *mut core::ffi::c_void, digestmethod: windows_core::PCWSTR, transformmethod: OPC_CANONICALIZATION_METHOD, partreference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignaturePartReferenceSet_Impl::Create(this, core::mem::transmute_copy(&parturi), core::mem::transmute(&digestmethod), core::mem::transmute_copy(&transformmethod)) {
+                    Ok(ok__) => {
+                        partreference.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IOpcSignaturePartReferenceSet_Impl>(this: *mut core::ffi::c_void, partreference: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcSignaturePartReferenceSet_Impl::Delete(this, core::mem::transmute_copy(&partreference)).into()
+            }
+        }
+        unsafe extern "system" fn GetEnumerator<Identity: windows_core::IUnknownImpl + IOpcSignaturePartReferenceSet_Impl>(this: *mut core::ffi::c_void, partreferenceenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignaturePartReferenceSet_Impl::GetEnumerator(this) {
+                    Ok(ok__) => {
+                        partreferenceenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Create: Create::<Identity>,
+            Delete: Delete::<Identity>,
+            GetEnumerator: GetEnumerator::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOpcSignaturePartReferenceSet {}
 windows_core::imp::define_interface!(IOpcSignatureReference, IOpcSignatureReference_Vtbl, 0x1b47005e_3011_4edc_be6f_0f65e5ab0342);
 windows_core::imp::interface_hierarchy!(IOpcSignatureReference, windows_core::IUnknown);
@@ -2951,6 +4465,89 @@ impl IOpcSignatureReference_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOpcSignatureReference_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcSignatureReference_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetId<Identity: windows_core::IUnknownImpl + IOpcSignatureReference_Impl>(this: *mut core::ffi::c_void, referenceid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureReference_Impl::GetId(this) {
+                    Ok(ok__) => {
+                        referenceid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetUri<Identity: windows_core::IUnknownImpl + IOpcSignatureReference_Impl>(this: *mut core::ffi::c_void, referenceuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureReference_Impl::GetUri(this) {
+                    Ok(ok__) => {
+                        referenceuri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetType<Identity: windows_core::IUnknownImpl + IOpcSignatureReference_Impl>(this: *mut core::ffi::c_void, r#type: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureReference_Impl::GetType(this) {
+                    Ok(ok__) => {
+                        r#type.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTransformMethod<Identity: windows_core::IUnknownImpl + IOpcSignatureReference_Impl>(this: *mut core::ffi::c_void, transformmethod: *mut OPC_CANONICALIZATION_METHOD) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureReference_Impl::GetTransformMethod(this) {
+                    Ok(ok__) => {
+                        transformmethod.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDigestMethod<Identity: windows_core::IUnknownImpl + IOpcSignatureReference_Impl>(this: *mut core::ffi::c_void, digestmethod: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureReference_Impl::GetDigestMethod(this) {
+                    Ok(ok__) => {
+                        digestmethod.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDigestValue<Identity: windows_core::IUnknownImpl + IOpcSignatureReference_Impl>(this: *mut core::ffi::c_void, digestvalue: *mut *mut u8, count: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcSignatureReference_Impl::GetDigestValue(this, core::mem::transmute_copy(&digestvalue), core::mem::transmute_copy(&count)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetId: GetId::<Identity>,
+            GetUri: GetUri::<Identity>,
+            GetType: GetType::<Identity>,
+            GetTransformMethod: GetTransformMethod::<Identity>,
+            GetDigestMethod: GetDigestMethod::<Identity>,
+            GetDigestValue: GetDigestValue::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOpcSignatureReference {}
 windows_core::imp::define_interface!(IOpcSignatureReferenceEnumerator, IOpcSignatureReferenceEnumerator_Vtbl, 0xcfa59a45_28b1_4868_969e_fa8097fdc12a);
 windows_core::imp::interface_hierarchy!(IOpcSignatureReferenceEnumerator, windows_core::IUnknown);
@@ -3057,6 +4654,68 @@ impl IOpcSignatureReferenceEnumerator_Vtbl {
         iid == &<IOpcSignatureReferenceEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOpcSignatureReferenceEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcSignatureReferenceEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IOpcSignatureReferenceEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureReferenceEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MovePrevious<Identity: windows_core::IUnknownImpl + IOpcSignatureReferenceEnumerator_Impl>(this: *mut core::ffi::c_void, hasprevious: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureReferenceEnumerator_Impl::MovePrevious(this) {
+                    Ok(ok__) => {
+                        hasprevious.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IOpcSignatureReferenceEnumerator_Impl>(this: *mut core::ffi::c_void, reference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureReferenceEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        reference.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IOpcSignatureReferenceEnumerator_Impl>(this: *mut core::ffi::c_void, copy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureReferenceEnumerator_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        copy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MoveNext: MoveNext::<Identity>,
+            MovePrevious: MovePrevious::<Identity>,
+            GetCurrent: GetCurrent::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOpcSignatureReferenceEnumerator {}
 windows_core::imp::define_interface!(IOpcSignatureReferenceSet, IOpcSignatureReferenceSet_Vtbl, 0xf3b02d31_ab12_42dd_9e2f_2b16761c3c1e);
 windows_core::imp::interface_hierarchy!(IOpcSignatureReferenceSet, windows_core::IUnknown);
@@ -3149,6 +4808,50 @@ impl IOpcSignatureReferenceSet_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOpcSignatureReferenceSet_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcSignatureReferenceSet_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Create<Identity: windows_core::IUnknownImpl + IOpcSignatureReferenceSet_Impl>(this: *mut core::ffi::c_void, referenceuri: *mut core::ffi::c_void, referenceid: windows_core::PCWSTR, r#type: windows_core::PCWSTR, digestmethod: windows_core::PCWSTR, transformmethod: OPC_CANONICALIZATION_METHOD, reference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureReferenceSet_Impl::Create(this, core::mem::transmute_copy(&referenceuri), core::mem::transmute(&referenceid), core::mem::transmute(&r#type), core::mem::transmute(&digestmethod), core::mem::transmute_copy(&transformmethod)) {
+                    Ok(ok__) => {
+                        reference.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IOpcSignatureReferenceSet_Impl>(this: *mut core::ffi::c_void, reference: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcSignatureReferenceSet_Impl::Delete(this, core::mem::transmute_copy(&reference)).into()
+            }
+        }
+        unsafe extern "system" fn GetEnumerator<Identity: windows_core::IUnknownImpl + IOpcSignatureReferenceSet_Impl>(this: *mut core::ffi::c_void, referenceenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureReferenceSet_Impl::GetEnumerator(this) {
+                    Ok(ok__) => {
+                        referenceenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Create: Create::<Identity>,
+            Delete: Delete::<Identity>,
+            GetEnumerator: GetEnumerator::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOpcSignatureReferenceSet {}
 windows_core::imp::define_interface!(IOpcSignatureRelationshipReference, IOpcSignatureRelationshipReference_Vtbl, 0x57babac6_9d4a_4e50_8b86_e5d4051eae7c);
 windows_core::imp::interface_hierarchy!(IOpcSignatureRelationshipReference, windows_core::IUnknown);
@@ -3289,10 +4992,93 @@ impl IOpcSignatureRelationshipReference_Vtbl {
             GetRelationshipSigningOption: GetRelationshipSigningOption::<Identity, OFFSET>,
             GetRelationshipSelectorEnumerator: GetRelationshipSelectorEnumerator::<Identity, OFFSET>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IOpcSignatureRelationshipReference as windows_core::Interface>::IID
-    }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcSignatureRelationshipReference as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOpcSignatureRelationshipReference_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcSignatureRelationshipReference_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSourceUri<Identity: windows_core::IUnknownImpl + IOpcSignatureRelationshipReference_Impl>(this: *mut core::ffi::c_void, sourceuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureRelationshipReference_Impl::GetSourceUri(this) {
+                    Ok(ok__) => {
+                        sourceuri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDigestMethod<Identity: windows_core::IUnknownImpl + IOpcSignatureRelationshipReference_Impl>(this: *mut core::ffi::c_void, digestmethod: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureRelationshipReference_Impl::GetDigestMethod(this) {
+                    Ok(ok__) => {
+                        digestmethod.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDigestValue<Identity: windows_core::IUnknownImpl + IOpcSignatureRelationshipReference_Impl>(this: *mut core::ffi::c_void, digestvalue: *mut *mut u8, count: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcSignatureRelationshipReference_Impl::GetDigestValue(this, core::mem::transmute_copy(&digestvalue), core::mem::transmute_copy(&count)).into()
+            }
+        }
+        unsafe extern "system" fn GetTransformMethod<Identity: windows_core::IUnknownImpl + IOpcSignatureRelationshipReference_Impl>(this: *mut core::ffi::c_void, transformmethod: *mut OPC_CANONICALIZATION_METHOD) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureRelationshipReference_Impl::GetTransformMethod(this) {
+                    Ok(ok__) => {
+                        transformmethod.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRelationshipSigningOption<Identity: windows_core::IUnknownImpl + IOpcSignatureRelationshipReference_Impl>(this: *mut core::ffi::c_void, relationshipsigningoption: *mut OPC_RELATIONSHIPS_SIGNING_OPTION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureRelationshipReference_Impl::GetRelationshipSigningOption(this) {
+                    Ok(ok__) => {
+                        relationshipsigningoption.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRelationshipSelectorEnumerator<Identity: windows_core::IUnknownImpl + IOpcSignatureRelationshipReference_Impl>(this: *mut core::ffi::c_void, selectorenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureRelationshipReference_Impl::GetRelationshipSelectorEnumerator(this) {
+                    Ok(ok__) => {
+                        selectorenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSourceUri: GetSourceUri::<Identity>,
+            GetDigestMethod: GetDigestMethod::<Identity>,
+            GetDigestValue: GetDigestValue::<Identity>,
+            GetTransformMethod: GetTransformMethod::<Identity>,
+            GetRelationshipSigningOption: GetRelationshipSigningOption::<Identity>,
+            GetRelationshipSelectorEnumerator: GetRelationshipSelectorEnumerator::<Identity>,
+        }
+    };
 }
 #[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOpcSignatureRelationshipReference {}
@@ -3401,6 +5187,68 @@ impl IOpcSignatureRelationshipReferenceEnumerator_Vtbl {
         iid == &<IOpcSignatureRelationshipReferenceEnumerator as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOpcSignatureRelationshipReferenceEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcSignatureRelationshipReferenceEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IOpcSignatureRelationshipReferenceEnumerator_Impl>(this: *mut core::ffi::c_void, hasnext: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureRelationshipReferenceEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        hasnext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MovePrevious<Identity: windows_core::IUnknownImpl + IOpcSignatureRelationshipReferenceEnumerator_Impl>(this: *mut core::ffi::c_void, hasprevious: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureRelationshipReferenceEnumerator_Impl::MovePrevious(this) {
+                    Ok(ok__) => {
+                        hasprevious.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IOpcSignatureRelationshipReferenceEnumerator_Impl>(this: *mut core::ffi::c_void, relationshipreference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureRelationshipReferenceEnumerator_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        relationshipreference.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IOpcSignatureRelationshipReferenceEnumerator_Impl>(this: *mut core::ffi::c_void, copy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureRelationshipReferenceEnumerator_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        copy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MoveNext: MoveNext::<Identity>,
+            MovePrevious: MovePrevious::<Identity>,
+            GetCurrent: GetCurrent::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOpcSignatureRelationshipReferenceEnumerator {}
 windows_core::imp::define_interface!(IOpcSignatureRelationshipReferenceSet, IOpcSignatureRelationshipReferenceSet_Vtbl, 0x9f863ca5_3631_404c_828d_807e0715069b);
 windows_core::imp::interface_hierarchy!(IOpcSignatureRelationshipReferenceSet, windows_core::IUnknown);
@@ -3513,6 +5361,63 @@ impl IOpcSignatureRelationshipReferenceSet_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOpcSignatureRelationshipReferenceSet_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcSignatureRelationshipReferenceSet_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Create<Identity: windows_core::IUnknownImpl + IOpcSignatureRelationshipReferenceSet_Impl>(this: *mut core::ffi::c_void, sourceuri: *mut core::ffi::c_void, digestmethod: windows_core::PCWSTR, relationshipsigningoption: OPC_RELATIONSHIPS_SIGNING_OPTION, selectorset: *mut core::ffi::c_void, transformmethod: OPC_CANONICALIZATION_METHOD, relationshipreference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureRelationshipReferenceSet_Impl::Create(this, core::mem::transmute_copy(&sourceuri), core::mem::transmute(&digestmethod), core::mem::transmute_copy(&relationshipsigningoption), core::mem::transmute_copy(&selectorset), core::mem::transmute_copy(&transformmethod)) {
+                    Ok(ok__) => {
+                        relationshipreference.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateRelationshipSelectorSet<Identity: windows_core::IUnknownImpl + IOpcSignatureRelationshipReferenceSet_Impl>(this: *mut core::ffi::c_void, selectorset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureRelationshipReferenceSet_Impl::CreateRelationshipSelectorSet(this) {
+                    Ok(ok__) => {
+                        selectorset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IOpcSignatureRelationshipReferenceSet_Impl>(this: *mut core::ffi::c_void, relationshipreference: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcSignatureRelationshipReferenceSet_Impl::Delete(this, core::mem::transmute_copy(&relationshipreference)).into()
+            }
+        }
+        unsafe extern "system" fn GetEnumerator<Identity: windows_core::IUnknownImpl + IOpcSignatureRelationshipReferenceSet_Impl>(this: *mut core::ffi::c_void, relationshipreferenceenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSignatureRelationshipReferenceSet_Impl::GetEnumerator(this) {
+                    Ok(ok__) => {
+                        relationshipreferenceenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Create: Create::<Identity>,
+            CreateRelationshipSelectorSet: CreateRelationshipSelectorSet::<Identity>,
+            Delete: Delete::<Identity>,
+            GetEnumerator: GetEnumerator::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOpcSignatureRelationshipReferenceSet {}
 windows_core::imp::define_interface!(IOpcSigningOptions, IOpcSigningOptions_Vtbl, 0x50d2d6a5_7aeb_46c0_b241_43ab0e9b407e);
 windows_core::imp::interface_hierarchy!(IOpcSigningOptions, windows_core::IUnknown);
@@ -3861,6 +5766,202 @@ impl IOpcSigningOptions_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOpcSigningOptions_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcSigningOptions_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSignatureId<Identity: windows_core::IUnknownImpl + IOpcSigningOptions_Impl>(this: *mut core::ffi::c_void, signatureid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSigningOptions_Impl::GetSignatureId(this) {
+                    Ok(ok__) => {
+                        signatureid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSignatureId<Identity: windows_core::IUnknownImpl + IOpcSigningOptions_Impl>(this: *mut core::ffi::c_void, signatureid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcSigningOptions_Impl::SetSignatureId(this, core::mem::transmute(&signatureid)).into()
+            }
+        }
+        unsafe extern "system" fn GetSignatureMethod<Identity: windows_core::IUnknownImpl + IOpcSigningOptions_Impl>(this: *mut core::ffi::c_void, signaturemethod: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSigningOptions_Impl::GetSignatureMethod(this) {
+                    Ok(ok__) => {
+                        signaturemethod.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSignatureMethod<Identity: windows_core::IUnknownImpl + IOpcSigningOptions_Impl>(this: *mut core::ffi::c_void, signaturemethod: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcSigningOptions_Impl::SetSignatureMethod(this, core::mem::transmute(&signaturemethod)).into()
+            }
+        }
+        unsafe extern "system" fn GetDefaultDigestMethod<Identity: windows_core::IUnknownImpl + IOpcSigningOptions_Impl>(this: *mut core::ffi::c_void, digestmethod: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSigningOptions_Impl::GetDefaultDigestMethod(this) {
+                    Ok(ok__) => {
+                        digestmethod.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDefaultDigestMethod<Identity: windows_core::IUnknownImpl + IOpcSigningOptions_Impl>(this: *mut core::ffi::c_void, digestmethod: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcSigningOptions_Impl::SetDefaultDigestMethod(this, core::mem::transmute(&digestmethod)).into()
+            }
+        }
+        unsafe extern "system" fn GetCertificateEmbeddingOption<Identity: windows_core::IUnknownImpl + IOpcSigningOptions_Impl>(this: *mut core::ffi::c_void, embeddingoption: *mut OPC_CERTIFICATE_EMBEDDING_OPTION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSigningOptions_Impl::GetCertificateEmbeddingOption(this) {
+                    Ok(ok__) => {
+                        embeddingoption.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCertificateEmbeddingOption<Identity: windows_core::IUnknownImpl + IOpcSigningOptions_Impl>(this: *mut core::ffi::c_void, embeddingoption: OPC_CERTIFICATE_EMBEDDING_OPTION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcSigningOptions_Impl::SetCertificateEmbeddingOption(this, core::mem::transmute_copy(&embeddingoption)).into()
+            }
+        }
+        unsafe extern "system" fn GetTimeFormat<Identity: windows_core::IUnknownImpl + IOpcSigningOptions_Impl>(this: *mut core::ffi::c_void, timeformat: *mut OPC_SIGNATURE_TIME_FORMAT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSigningOptions_Impl::GetTimeFormat(this) {
+                    Ok(ok__) => {
+                        timeformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTimeFormat<Identity: windows_core::IUnknownImpl + IOpcSigningOptions_Impl>(this: *mut core::ffi::c_void, timeformat: OPC_SIGNATURE_TIME_FORMAT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcSigningOptions_Impl::SetTimeFormat(this, core::mem::transmute_copy(&timeformat)).into()
+            }
+        }
+        unsafe extern "system" fn GetSignaturePartReferenceSet<Identity: windows_core::IUnknownImpl + IOpcSigningOptions_Impl>(this: *mut core::ffi::c_void, partreferenceset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSigningOptions_Impl::GetSignaturePartReferenceSet(this) {
+                    Ok(ok__) => {
+                        partreferenceset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSignatureRelationshipReferenceSet<Identity: windows_core::IUnknownImpl + IOpcSigningOptions_Impl>(this: *mut core::ffi::c_void, relationshipreferenceset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSigningOptions_Impl::GetSignatureRelationshipReferenceSet(this) {
+                    Ok(ok__) => {
+                        relationshipreferenceset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCustomObjectSet<Identity: windows_core::IUnknownImpl + IOpcSigningOptions_Impl>(this: *mut core::ffi::c_void, customobjectset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSigningOptions_Impl::GetCustomObjectSet(this) {
+                    Ok(ok__) => {
+                        customobjectset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCustomReferenceSet<Identity: windows_core::IUnknownImpl + IOpcSigningOptions_Impl>(this: *mut core::ffi::c_void, customreferenceset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSigningOptions_Impl::GetCustomReferenceSet(this) {
+                    Ok(ok__) => {
+                        customreferenceset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCertificateSet<Identity: windows_core::IUnknownImpl + IOpcSigningOptions_Impl>(this: *mut core::ffi::c_void, certificateset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSigningOptions_Impl::GetCertificateSet(this) {
+                    Ok(ok__) => {
+                        certificateset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSignaturePartName<Identity: windows_core::IUnknownImpl + IOpcSigningOptions_Impl>(this: *mut core::ffi::c_void, signaturepartname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcSigningOptions_Impl::GetSignaturePartName(this) {
+                    Ok(ok__) => {
+                        signaturepartname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSignaturePartName<Identity: windows_core::IUnknownImpl + IOpcSigningOptions_Impl>(this: *mut core::ffi::c_void, signaturepartname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOpcSigningOptions_Impl::SetSignaturePartName(this, core::mem::transmute_copy(&signaturepartname)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSignatureId: GetSignatureId::<Identity>,
+            SetSignatureId: SetSignatureId::<Identity>,
+            GetSignatureMethod: GetSignatureMethod::<Identity>,
+            SetSignatureMethod: SetSignatureMethod::<Identity>,
+            GetDefaultDigestMethod: GetDefaultDigestMethod::<Identity>,
+            SetDefaultDigestMethod: SetDefaultDigestMethod::<Identity>,
+            GetCertificateEmbeddingOption: GetCertificateEmbeddingOption::<Identity>,
+            SetCertificateEmbeddingOption: SetCertificateEmbeddingOption::<Identity>,
+            GetTimeFormat: GetTimeFormat::<Identity>,
+            SetTimeFormat: SetTimeFormat::<Identity>,
+            GetSignaturePartReferenceSet: GetSignaturePartReferenceSet::<Identity>,
+            GetSignatureRelationshipReferenceSet: GetSignatureRelationshipReferenceSet::<Identity>,
+            GetCustomObjectSet: GetCustomObjectSet::<Identity>,
+            GetCustomReferenceSet: GetCustomReferenceSet::<Identity>,
+            GetCertificateSet: GetCertificateSet::<Identity>,
+            GetSignaturePartName: GetSignaturePartName::<Identity>,
+            SetSignaturePartName: SetSignaturePartName::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOpcSigningOptions {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IOpcUri, IOpcUri_Vtbl, 0xbc9c1b9b_d62c_49eb_aef0_3b4e0b28ebed);
@@ -3966,6 +6067,56 @@ impl IOpcUri_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOpcUri_Impl> windows_core::imp::TearOffVtable<Identity> for IOpcUri_Vtbl
+where
+    super::super::super::System::Com::IUri_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRelationshipsPartUri<Identity: windows_core::IUnknownImpl + IOpcUri_Impl>(this: *mut core::ffi::c_void, relationshipparturi: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcUri_Impl::GetRelationshipsPartUri(this) {
+                    Ok(ok__) => {
+                        relationshipparturi.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRelativeUri<Identity: windows_core::IUnknownImpl + IOpcUri_Impl>(this: *mut core::ffi::c_void, targetparturi: *mut core::ffi::c_void, relativeuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcUri_Impl::GetRelativeUri(this, core::mem::transmute_copy(&targetparturi)) {
+                    Ok(ok__) => {
+                        relativeuri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CombinePartUri<Identity: windows_core::IUnknownImpl + IOpcUri_Impl>(this: *mut core::ffi::c_void, relativeuri: *mut core::ffi::c_void, combineduri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOpcUri_Impl::CombinePartUri(this, core::mem::transmute_copy(&relativeuri)) {
+                    Ok(ok__) => {
+                        combineduri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::super::super::System::Com::IUri_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetRelationshipsPartUri: GetRelationshipsPartUri::<Identity>,
+            GetRelativeUri: GetRelativeUri::<Identity>,
+            CombinePartUri: CombinePartUri::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOpcUri {}
 pub const OPC_CACHE_ON_ACCESS: OPC_READ_FLAGS = OPC_READ_FLAGS(2i32);
 pub const OPC_CANONICALIZATION_C14N: OPC_CANONICALIZATION_METHOD = OPC_CANONICALIZATION_METHOD(1i32);
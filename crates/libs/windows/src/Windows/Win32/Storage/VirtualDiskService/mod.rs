@@ -186,6 +186,50 @@ impl IEnumVdsObject_Vtbl {
         iid == &<IEnumVdsObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumVdsObject_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumVdsObject_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumVdsObject_Impl>(this: *mut core::ffi::c_void, celt: u32, ppobjectarray: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumVdsObject_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&ppobjectarray), core::mem::transmute_copy(&pcfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumVdsObject_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumVdsObject_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumVdsObject_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumVdsObject_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumVdsObject_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumVdsObject_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumVdsObject {}
 windows_core::imp::define_interface!(IVdsAdmin, IVdsAdmin_Vtbl, 0xd188e97d_85aa_4d33_abc6_26299a10ffc1);
 windows_core::imp::interface_hierarchy!(IVdsAdmin, windows_core::IUnknown);
@@ -237,6 +281,30 @@ impl IVdsAdmin_Vtbl {
         iid == &<IVdsAdmin as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsAdmin_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsAdmin_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterProvider<Identity: windows_core::IUnknownImpl + IVdsAdmin_Impl>(this: *mut core::ffi::c_void, providerid: windows_core::GUID, providerclsid: windows_core::GUID, pwszname: windows_core::PCWSTR, r#type: VDS_PROVIDER_TYPE, pwszmachinename: windows_core::PCWSTR, pwszversion: windows_core::PCWSTR, guidversionid: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsAdmin_Impl::RegisterProvider(this, core::mem::transmute(&providerid), core::mem::transmute(&providerclsid), core::mem::transmute(&pwszname), core::mem::transmute_copy(&r#type), core::mem::transmute(&pwszmachinename), core::mem::transmute(&pwszversion), core::mem::transmute(&guidversionid)).into()
+            }
+        }
+        unsafe extern "system" fn UnregisterProvider<Identity: windows_core::IUnknownImpl + IVdsAdmin_Impl>(this: *mut core::ffi::c_void, providerid: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsAdmin_Impl::UnregisterProvider(this, core::mem::transmute(&providerid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterProvider: RegisterProvider::<Identity>,
+            UnregisterProvider: UnregisterProvider::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsAdmin {}
 windows_core::imp::define_interface!(IVdsAdvancedDisk, IVdsAdvancedDisk_Vtbl, 0x6e6f6b40_977c_4069_bddd_ac710059f8c0);
 windows_core::imp::interface_hierarchy!(IVdsAdvancedDisk, windows_core::IUnknown);
@@ -409,6 +477,104 @@ impl IVdsAdvancedDisk_Vtbl {
         iid == &<IVdsAdvancedDisk as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsAdvancedDisk_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsAdvancedDisk_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPartitionProperties<Identity: windows_core::IUnknownImpl + IVdsAdvancedDisk_Impl>(this: *mut core::ffi::c_void, ulloffset: u64, ppartitionprop: *mut VDS_PARTITION_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsAdvancedDisk_Impl::GetPartitionProperties(this, core::mem::transmute_copy(&ulloffset), core::mem::transmute_copy(&ppartitionprop)).into()
+            }
+        }
+        unsafe extern "system" fn QueryPartitions<Identity: windows_core::IUnknownImpl + IVdsAdvancedDisk_Impl>(this: *mut core::ffi::c_void, pppartitionproparray: *mut *mut VDS_PARTITION_PROP, plnumberofpartitions: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsAdvancedDisk_Impl::QueryPartitions(this, core::mem::transmute_copy(&pppartitionproparray), core::mem::transmute_copy(&plnumberofpartitions)).into()
+            }
+        }
+        unsafe extern "system" fn CreatePartition<Identity: windows_core::IUnknownImpl + IVdsAdvancedDisk_Impl>(this: *mut core::ffi::c_void, ulloffset: u64, ullsize: u64, para: *const CREATE_PARTITION_PARAMETERS, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsAdvancedDisk_Impl::CreatePartition(this, core::mem::transmute_copy(&ulloffset), core::mem::transmute_copy(&ullsize), core::mem::transmute_copy(&para)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeletePartition<Identity: windows_core::IUnknownImpl + IVdsAdvancedDisk_Impl>(this: *mut core::ffi::c_void, ulloffset: u64, bforce: windows_core::BOOL, bforceprotected: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsAdvancedDisk_Impl::DeletePartition(this, core::mem::transmute_copy(&ulloffset), core::mem::transmute_copy(&bforce), core::mem::transmute_copy(&bforceprotected)).into()
+            }
+        }
+        unsafe extern "system" fn ChangeAttributes<Identity: windows_core::IUnknownImpl + IVdsAdvancedDisk_Impl>(this: *mut core::ffi::c_void, ulloffset: u64, para: *const CHANGE_ATTRIBUTES_PARAMETERS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsAdvancedDisk_Impl::ChangeAttributes(this, core::mem::transmute_copy(&ulloffset), core::mem::transmute_copy(&para)).into()
+            }
+        }
+        unsafe extern "system" fn AssignDriveLetter<Identity: windows_core::IUnknownImpl + IVdsAdvancedDisk_Impl>(this: *mut core::ffi::c_void, ulloffset: u64, wcletter: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsAdvancedDisk_Impl::AssignDriveLetter(this, core::mem::transmute_copy(&ulloffset), core::mem::transmute_copy(&wcletter)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteDriveLetter<Identity: windows_core::IUnknownImpl + IVdsAdvancedDisk_Impl>(this: *mut core::ffi::c_void, ulloffset: u64, wcletter: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsAdvancedDisk_Impl::DeleteDriveLetter(this, core::mem::transmute_copy(&ulloffset), core::mem::transmute_copy(&wcletter)).into()
+            }
+        }
+        unsafe extern "system" fn GetDriveLetter<Identity: windows_core::IUnknownImpl + IVdsAdvancedDisk_Impl>(this: *mut core::ffi::c_void, ulloffset: u64, pwcletter: windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsAdvancedDisk_Impl::GetDriveLetter(this, core::mem::transmute_copy(&ulloffset), core::mem::transmute_copy(&pwcletter)).into()
+            }
+        }
+        unsafe extern "system" fn FormatPartition<Identity: windows_core::IUnknownImpl + IVdsAdvancedDisk_Impl>(this: *mut core::ffi::c_void, ulloffset: u64, r#type: VDS_FILE_SYSTEM_TYPE, pwszlabel: windows_core::PCWSTR, dwunitallocationsize: u32, bforce: windows_core::BOOL, bquickformat: windows_core::BOOL, benablecompression: windows_core::BOOL, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsAdvancedDisk_Impl::FormatPartition(this, core::mem::transmute_copy(&ulloffset), core::mem::transmute_copy(&r#type), core::mem::transmute(&pwszlabel), core::mem::transmute_copy(&dwunitallocationsize), core::mem::transmute_copy(&bforce), core::mem::transmute_copy(&bquickformat), core::mem::transmute_copy(&benablecompression)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Clean<Identity: windows_core::IUnknownImpl + IVdsAdvancedDisk_Impl>(this: *mut core::ffi::c_void, bforce: windows_core::BOOL, bforceoem: windows_core::BOOL, bfullclean: windows_core::BOOL, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsAdvancedDisk_Impl::Clean(this, core::mem::transmute_copy(&bforce), core::mem::transmute_copy(&bforceoem), core::mem::transmute_copy(&bfullclean)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPartitionProperties: GetPartitionProperties::<Identity>,
+            QueryPartitions: QueryPartitions::<Identity>,
+            CreatePartition: CreatePartition::<Identity>,
+            DeletePartition: DeletePartition::<Identity>,
+            ChangeAttributes: ChangeAttributes::<Identity>,
+            AssignDriveLetter: AssignDriveLetter::<Identity>,
+            DeleteDriveLetter: DeleteDriveLetter::<Identity>,
+            GetDriveLetter: GetDriveLetter::<Identity>,
+            FormatPartition: FormatPartition::<Identity>,
+            Clean: Clean::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsAdvancedDisk {}
 windows_core::imp::define_interface!(IVdsAdvancedDisk2, IVdsAdvancedDisk2_Vtbl, 0x9723f420_9355_42de_ab66_e31bb15beeac);
 windows_core::imp::interface_hierarchy!(IVdsAdvancedDisk2, windows_core::IUnknown);
@@ -440,6 +606,23 @@ impl IVdsAdvancedDisk2_Vtbl {
         iid == &<IVdsAdvancedDisk2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsAdvancedDisk2_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsAdvancedDisk2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ChangePartitionType<Identity: windows_core::IUnknownImpl + IVdsAdvancedDisk2_Impl>(this: *mut core::ffi::c_void, ulloffset: u64, bforce: windows_core::BOOL, para: *const CHANGE_PARTITION_TYPE_PARAMETERS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsAdvancedDisk2_Impl::ChangePartitionType(this, core::mem::transmute_copy(&ulloffset), core::mem::transmute_copy(&bforce), core::mem::transmute_copy(&para)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ChangePartitionType: ChangePartitionType::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsAdvancedDisk2 {}
 windows_core::imp::define_interface!(IVdsAdvancedDisk3, IVdsAdvancedDisk3_Vtbl, 0x3858c0d5_0f35_4bf5_9714_69874963bc36);
 windows_core::imp::interface_hierarchy!(IVdsAdvancedDisk3, windows_core::IUnknown);
@@ -495,6 +678,36 @@ impl IVdsAdvancedDisk3_Vtbl {
         iid == &<IVdsAdvancedDisk3 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsAdvancedDisk3_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsAdvancedDisk3_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + IVdsAdvancedDisk3_Impl>(this: *mut core::ffi::c_void, padvdiskprop: *mut VDS_ADVANCEDDISK_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsAdvancedDisk3_Impl::GetProperties(this, core::mem::transmute_copy(&padvdiskprop)).into()
+            }
+        }
+        unsafe extern "system" fn GetUniqueId<Identity: windows_core::IUnknownImpl + IVdsAdvancedDisk3_Impl>(this: *mut core::ffi::c_void, ppwszid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsAdvancedDisk3_Impl::GetUniqueId(this) {
+                    Ok(ok__) => {
+                        ppwszid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProperties: GetProperties::<Identity>,
+            GetUniqueId: GetUniqueId::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsAdvancedDisk3 {}
 windows_core::imp::define_interface!(IVdsAdviseSink, IVdsAdviseSink_Vtbl, 0x8326cd1d_cf59_4936_b786_5efc08798e25);
 windows_core::imp::interface_hierarchy!(IVdsAdviseSink, windows_core::IUnknown);
@@ -526,6 +739,20 @@ impl IVdsAdviseSink_Vtbl {
         iid == &<IVdsAdviseSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsAdviseSink_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsAdviseSink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnNotify<Identity: windows_core::IUnknownImpl + IVdsAdviseSink_Impl>(this: *mut core::ffi::c_void, lnumberofnotifications: i32, pnotificationarray: *const VDS_NOTIFICATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsAdviseSink_Impl::OnNotify(this, core::mem::transmute_copy(&lnumberofnotifications), core::mem::transmute_copy(&pnotificationarray)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnNotify: OnNotify::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IVdsAdviseSink {}
 windows_core::imp::define_interface!(IVdsAsync, IVdsAsync_Vtbl, 0xd5d23b6d_5a55_4492_9889_397a3c2d2dbc);
 windows_core::imp::interface_hierarchy!(IVdsAsync, windows_core::IUnknown);
@@ -584,6 +811,37 @@ impl IVdsAsync_Vtbl {
         iid == &<IVdsAsync as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsAsync_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsAsync_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Cancel<Identity: windows_core::IUnknownImpl + IVdsAsync_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsAsync_Impl::Cancel(this).into()
+            }
+        }
+        unsafe extern "system" fn Wait<Identity: windows_core::IUnknownImpl + IVdsAsync_Impl>(this: *mut core::ffi::c_void, phrresult: *mut windows_core::HRESULT, pasyncout: *mut VDS_ASYNC_OUTPUT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsAsync_Impl::Wait(this, core::mem::transmute_copy(&phrresult), core::mem::transmute_copy(&pasyncout)).into()
+            }
+        }
+        unsafe extern "system" fn QueryStatus<Identity: windows_core::IUnknownImpl + IVdsAsync_Impl>(this: *mut core::ffi::c_void, phrresult: *mut windows_core::HRESULT, pulpercentcompleted: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsAsync_Impl::QueryStatus(this, core::mem::transmute_copy(&phrresult), core::mem::transmute_copy(&pulpercentcompleted)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Cancel: Cancel::<Identity>,
+            Wait: Wait::<Identity>,
+            QueryStatus: QueryStatus::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsAsync {}
 windows_core::imp::define_interface!(IVdsController, IVdsController_Vtbl, 0xcb53d96e_dffb_474a_a078_790d1e2bc082);
 windows_core::imp::interface_hierarchy!(IVdsController, windows_core::IUnknown);
@@ -720,6 +978,84 @@ impl IVdsController_Vtbl {
         iid == &<IVdsController as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsController_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsController_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + IVdsController_Impl>(this: *mut core::ffi::c_void, pcontrollerprop: *mut VDS_CONTROLLER_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsController_Impl::GetProperties(this, core::mem::transmute_copy(&pcontrollerprop)).into()
+            }
+        }
+        unsafe extern "system" fn GetSubSystem<Identity: windows_core::IUnknownImpl + IVdsController_Impl>(this: *mut core::ffi::c_void, ppsubsystem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsController_Impl::GetSubSystem(this) {
+                    Ok(ok__) => {
+                        ppsubsystem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPortProperties<Identity: windows_core::IUnknownImpl + IVdsController_Impl>(this: *mut core::ffi::c_void, sportnumber: i16, pportprop: *mut VDS_PORT_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsController_Impl::GetPortProperties(this, core::mem::transmute_copy(&sportnumber), core::mem::transmute_copy(&pportprop)).into()
+            }
+        }
+        unsafe extern "system" fn FlushCache<Identity: windows_core::IUnknownImpl + IVdsController_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsController_Impl::FlushCache(this).into()
+            }
+        }
+        unsafe extern "system" fn InvalidateCache<Identity: windows_core::IUnknownImpl + IVdsController_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsController_Impl::InvalidateCache(this).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IVdsController_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsController_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn QueryAssociatedLuns<Identity: windows_core::IUnknownImpl + IVdsController_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsController_Impl::QueryAssociatedLuns(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetStatus<Identity: windows_core::IUnknownImpl + IVdsController_Impl>(this: *mut core::ffi::c_void, status: VDS_CONTROLLER_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsController_Impl::SetStatus(this, core::mem::transmute_copy(&status)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProperties: GetProperties::<Identity>,
+            GetSubSystem: GetSubSystem::<Identity>,
+            GetPortProperties: GetPortProperties::<Identity>,
+            FlushCache: FlushCache::<Identity>,
+            InvalidateCache: InvalidateCache::<Identity>,
+            Reset: Reset::<Identity>,
+            QueryAssociatedLuns: QueryAssociatedLuns::<Identity>,
+            SetStatus: SetStatus::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsController {}
 windows_core::imp::define_interface!(IVdsControllerControllerPort, IVdsControllerControllerPort_Vtbl, 0xca5d735f_6bae_42c0_b30e_f2666045ce71);
 windows_core::imp::interface_hierarchy!(IVdsControllerControllerPort, windows_core::IUnknown);
@@ -760,6 +1096,29 @@ impl IVdsControllerControllerPort_Vtbl {
         iid == &<IVdsControllerControllerPort as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsControllerControllerPort_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsControllerControllerPort_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryControllerPorts<Identity: windows_core::IUnknownImpl + IVdsControllerControllerPort_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsControllerControllerPort_Impl::QueryControllerPorts(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryControllerPorts: QueryControllerPorts::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsControllerControllerPort {}
 windows_core::imp::define_interface!(IVdsControllerPort, IVdsControllerPort_Vtbl, 0x18691d0d_4e7f_43e8_92e4_cf44beeed11c);
 windows_core::imp::interface_hierarchy!(IVdsControllerPort, windows_core::IUnknown);
@@ -860,6 +1219,63 @@ impl IVdsControllerPort_Vtbl {
         iid == &<IVdsControllerPort as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsControllerPort_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsControllerPort_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + IVdsControllerPort_Impl>(this: *mut core::ffi::c_void, pportprop: *mut VDS_PORT_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsControllerPort_Impl::GetProperties(this, core::mem::transmute_copy(&pportprop)).into()
+            }
+        }
+        unsafe extern "system" fn GetController<Identity: windows_core::IUnknownImpl + IVdsControllerPort_Impl>(this: *mut core::ffi::c_void, ppcontroller: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsControllerPort_Impl::GetController(this) {
+                    Ok(ok__) => {
+                        ppcontroller.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryAssociatedLuns<Identity: windows_core::IUnknownImpl + IVdsControllerPort_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsControllerPort_Impl::QueryAssociatedLuns(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IVdsControllerPort_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsControllerPort_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn SetStatus<Identity: windows_core::IUnknownImpl + IVdsControllerPort_Impl>(this: *mut core::ffi::c_void, status: VDS_PORT_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsControllerPort_Impl::SetStatus(this, core::mem::transmute_copy(&status)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProperties: GetProperties::<Identity>,
+            GetController: GetController::<Identity>,
+            QueryAssociatedLuns: QueryAssociatedLuns::<Identity>,
+            Reset: Reset::<Identity>,
+            SetStatus: SetStatus::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsControllerPort {}
 windows_core::imp::define_interface!(IVdsCreatePartitionEx, IVdsCreatePartitionEx_Vtbl, 0x9882f547_cfc3_420b_9750_00dfbec50662);
 windows_core::imp::interface_hierarchy!(IVdsCreatePartitionEx, windows_core::IUnknown);
@@ -900,6 +1316,26 @@ impl IVdsCreatePartitionEx_Vtbl {
         iid == &<IVdsCreatePartitionEx as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsCreatePartitionEx_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsCreatePartitionEx_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreatePartitionEx<Identity: windows_core::IUnknownImpl + IVdsCreatePartitionEx_Impl>(this: *mut core::ffi::c_void, ulloffset: u64, ullsize: u64, ulalign: u32, para: *const CREATE_PARTITION_PARAMETERS, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsCreatePartitionEx_Impl::CreatePartitionEx(this, core::mem::transmute_copy(&ulloffset), core::mem::transmute_copy(&ullsize), core::mem::transmute_copy(&ulalign), core::mem::transmute_copy(&para)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreatePartitionEx: CreatePartitionEx::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IVdsCreatePartitionEx {}
 windows_core::imp::define_interface!(IVdsDisk, IVdsDisk_Vtbl, 0x07e5c822_f00c_47a1_8fce_b244da56fd06);
 windows_core::imp::interface_hierarchy!(IVdsDisk, windows_core::IUnknown);
@@ -1015,18 +1451,83 @@ impl IVdsDisk_Vtbl {
         iid == &<IVdsDisk as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IVdsDisk {}
-windows_core::imp::define_interface!(IVdsDisk2, IVdsDisk2_Vtbl, 0x40f73c8b_687d_4a13_8d96_3d7f2e683936);
-windows_core::imp::interface_hierarchy!(IVdsDisk2, windows_core::IUnknown);
-impl IVdsDisk2 {
-    pub unsafe fn SetSANMode(&self, benable: bool) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetSANMode)(windows_core::Interface::as_raw(self), benable.into()).ok() }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IVdsDisk2_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
+impl<Identity: windows_core::IUnknownImpl + IVdsDisk_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsDisk_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + IVdsDisk_Impl>(this: *mut core::ffi::c_void, pdiskproperties: *mut VDS_DISK_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsDisk_Impl::GetProperties(this, core::mem::transmute_copy(&pdiskproperties)).into()
+            }
+        }
+        unsafe extern "system" fn GetPack<Identity: windows_core::IUnknownImpl + IVdsDisk_Impl>(this: *mut core::ffi::c_void, pppack: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsDisk_Impl::GetPack(this) {
+                    Ok(ok__) => {
+                        pppack.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetIdentificationData<Identity: windows_core::IUnknownImpl + IVdsDisk_Impl>(this: *mut core::ffi::c_void, pluninfo: *mut VDS_LUN_INFORMATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsDisk_Impl::GetIdentificationData(this, core::mem::transmute_copy(&pluninfo)).into()
+            }
+        }
+        unsafe extern "system" fn QueryExtents<Identity: windows_core::IUnknownImpl + IVdsDisk_Impl>(this: *mut core::ffi::c_void, ppextentarray: *mut *mut VDS_DISK_EXTENT, plnumberofextents: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsDisk_Impl::QueryExtents(this, core::mem::transmute_copy(&ppextentarray), core::mem::transmute_copy(&plnumberofextents)).into()
+            }
+        }
+        unsafe extern "system" fn ConvertStyle<Identity: windows_core::IUnknownImpl + IVdsDisk_Impl>(this: *mut core::ffi::c_void, newstyle: VDS_PARTITION_STYLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsDisk_Impl::ConvertStyle(this, core::mem::transmute_copy(&newstyle)).into()
+            }
+        }
+        unsafe extern "system" fn SetFlags<Identity: windows_core::IUnknownImpl + IVdsDisk_Impl>(this: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsDisk_Impl::SetFlags(this, core::mem::transmute_copy(&ulflags)).into()
+            }
+        }
+        unsafe extern "system" fn ClearFlags<Identity: windows_core::IUnknownImpl + IVdsDisk_Impl>(this: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsDisk_Impl::ClearFlags(this, core::mem::transmute_copy(&ulflags)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProperties: GetProperties::<Identity>,
+            GetPack: GetPack::<Identity>,
+            GetIdentificationData: GetIdentificationData::<Identity>,
+            QueryExtents: QueryExtents::<Identity>,
+            ConvertStyle: ConvertStyle::<Identity>,
+            SetFlags: SetFlags::<Identity>,
+            ClearFlags: ClearFlags::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IVdsDisk {}
+windows_core::imp::define_interface!(IVdsDisk2, IVdsDisk2_Vtbl, 0x40f73c8b_687d_4a13_8d96_3d7f2e683936);
+windows_core::imp::interface_hierarchy!(IVdsDisk2, windows_core::IUnknown);
+impl IVdsDisk2 {
+    pub unsafe fn SetSANMode(&self, benable: bool) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetSANMode)(windows_core::Interface::as_raw(self), benable.into()).ok() }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IVdsDisk2_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
     pub SetSANMode: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::BOOL) -> windows_core::HRESULT,
 }
 pub trait IVdsDisk2_Impl: windows_core::IUnknownImpl {
@@ -1046,6 +1547,20 @@ impl IVdsDisk2_Vtbl {
         iid == &<IVdsDisk2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsDisk2_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsDisk2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetSANMode<Identity: windows_core::IUnknownImpl + IVdsDisk2_Impl>(this: *mut core::ffi::c_void, benable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsDisk2_Impl::SetSANMode(this, core::mem::transmute_copy(&benable)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetSANMode: SetSANMode::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IVdsDisk2 {}
 windows_core::imp::define_interface!(IVdsDisk3, IVdsDisk3_Vtbl, 0x8f4b2f5d_ec15_4357_992f_473ef10975b9);
 windows_core::imp::interface_hierarchy!(IVdsDisk3, windows_core::IUnknown);
@@ -1092,6 +1607,30 @@ impl IVdsDisk3_Vtbl {
         iid == &<IVdsDisk3 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsDisk3_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsDisk3_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperties2<Identity: windows_core::IUnknownImpl + IVdsDisk3_Impl>(this: *mut core::ffi::c_void, pdiskproperties: *mut VDS_DISK_PROP2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsDisk3_Impl::GetProperties2(this, core::mem::transmute_copy(&pdiskproperties)).into()
+            }
+        }
+        unsafe extern "system" fn QueryFreeExtents<Identity: windows_core::IUnknownImpl + IVdsDisk3_Impl>(this: *mut core::ffi::c_void, ulalign: u32, ppfreeextentarray: *mut *mut VDS_DISK_FREE_EXTENT, plnumberoffreeextents: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsDisk3_Impl::QueryFreeExtents(this, core::mem::transmute_copy(&ulalign), core::mem::transmute_copy(&ppfreeextentarray), core::mem::transmute_copy(&plnumberoffreeextents)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProperties2: GetProperties2::<Identity>,
+            QueryFreeExtents: QueryFreeExtents::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsDisk3 {}
 windows_core::imp::define_interface!(IVdsDiskOnline, IVdsDiskOnline_Vtbl, 0x90681b1d_6a7f_48e8_9061_31b7aa125322);
 windows_core::imp::interface_hierarchy!(IVdsDiskOnline, windows_core::IUnknown);
@@ -1134,6 +1673,30 @@ impl IVdsDiskOnline_Vtbl {
         iid == &<IVdsDiskOnline as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsDiskOnline_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsDiskOnline_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Online<Identity: windows_core::IUnknownImpl + IVdsDiskOnline_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsDiskOnline_Impl::Online(this).into()
+            }
+        }
+        unsafe extern "system" fn Offline<Identity: windows_core::IUnknownImpl + IVdsDiskOnline_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsDiskOnline_Impl::Offline(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Online: Online::<Identity>,
+            Offline: Offline::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsDiskOnline {}
 windows_core::imp::define_interface!(IVdsDiskPartitionMF, IVdsDiskPartitionMF_Vtbl, 0x538684e0_ba3d_4bc0_aca9_164aff85c2a9);
 windows_core::imp::interface_hierarchy!(IVdsDiskPartitionMF, windows_core::IUnknown);
@@ -1226,6 +1789,56 @@ impl IVdsDiskPartitionMF_Vtbl {
         iid == &<IVdsDiskPartitionMF as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsDiskPartitionMF_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsDiskPartitionMF_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPartitionFileSystemProperties<Identity: windows_core::IUnknownImpl + IVdsDiskPartitionMF_Impl>(this: *mut core::ffi::c_void, ulloffset: u64, pfilesystemprop: *mut VDS_FILE_SYSTEM_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsDiskPartitionMF_Impl::GetPartitionFileSystemProperties(this, core::mem::transmute_copy(&ulloffset), core::mem::transmute_copy(&pfilesystemprop)).into()
+            }
+        }
+        unsafe extern "system" fn GetPartitionFileSystemTypeName<Identity: windows_core::IUnknownImpl + IVdsDiskPartitionMF_Impl>(this: *mut core::ffi::c_void, ulloffset: u64, ppwszfilesystemtypename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsDiskPartitionMF_Impl::GetPartitionFileSystemTypeName(this, core::mem::transmute_copy(&ulloffset)) {
+                    Ok(ok__) => {
+                        ppwszfilesystemtypename.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryPartitionFileSystemFormatSupport<Identity: windows_core::IUnknownImpl + IVdsDiskPartitionMF_Impl>(this: *mut core::ffi::c_void, ulloffset: u64, ppfilesystemsupportprops: *mut *mut VDS_FILE_SYSTEM_FORMAT_SUPPORT_PROP, plnumberoffilesystems: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsDiskPartitionMF_Impl::QueryPartitionFileSystemFormatSupport(this, core::mem::transmute_copy(&ulloffset), core::mem::transmute_copy(&ppfilesystemsupportprops), core::mem::transmute_copy(&plnumberoffilesystems)).into()
+            }
+        }
+        unsafe extern "system" fn FormatPartitionEx<Identity: windows_core::IUnknownImpl + IVdsDiskPartitionMF_Impl>(this: *mut core::ffi::c_void, ulloffset: u64, pwszfilesystemtypename: windows_core::PCWSTR, usfilesystemrevision: u16, uldesiredunitallocationsize: u32, pwszlabel: windows_core::PCWSTR, bforce: windows_core::BOOL, bquickformat: windows_core::BOOL, benablecompression: windows_core::BOOL, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsDiskPartitionMF_Impl::FormatPartitionEx(this, core::mem::transmute_copy(&ulloffset), core::mem::transmute(&pwszfilesystemtypename), core::mem::transmute_copy(&usfilesystemrevision), core::mem::transmute_copy(&uldesiredunitallocationsize), core::mem::transmute(&pwszlabel), core::mem::transmute_copy(&bforce), core::mem::transmute_copy(&bquickformat), core::mem::transmute_copy(&benablecompression)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPartitionFileSystemProperties: GetPartitionFileSystemProperties::<Identity>,
+            GetPartitionFileSystemTypeName: GetPartitionFileSystemTypeName::<Identity>,
+            QueryPartitionFileSystemFormatSupport: QueryPartitionFileSystemFormatSupport::<Identity>,
+            FormatPartitionEx: FormatPartitionEx::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsDiskPartitionMF {}
 windows_core::imp::define_interface!(IVdsDiskPartitionMF2, IVdsDiskPartitionMF2_Vtbl, 0x9cbe50ca_f2d2_4bf4_ace1_96896b729625);
 windows_core::imp::interface_hierarchy!(IVdsDiskPartitionMF2, windows_core::IUnknown);
@@ -1270,6 +1883,26 @@ impl IVdsDiskPartitionMF2_Vtbl {
         iid == &<IVdsDiskPartitionMF2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsDiskPartitionMF2_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsDiskPartitionMF2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FormatPartitionEx2<Identity: windows_core::IUnknownImpl + IVdsDiskPartitionMF2_Impl>(this: *mut core::ffi::c_void, ulloffset: u64, pwszfilesystemtypename: windows_core::PCWSTR, usfilesystemrevision: u16, uldesiredunitallocationsize: u32, pwszlabel: windows_core::PCWSTR, options: u32, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsDiskPartitionMF2_Impl::FormatPartitionEx2(this, core::mem::transmute_copy(&ulloffset), core::mem::transmute(&pwszfilesystemtypename), core::mem::transmute_copy(&usfilesystemrevision), core::mem::transmute_copy(&uldesiredunitallocationsize), core::mem::transmute(&pwszlabel), core::mem::transmute_copy(&options)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, FormatPartitionEx2: FormatPartitionEx2::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IVdsDiskPartitionMF2 {}
 windows_core::imp::define_interface!(IVdsDrive, IVdsDrive_Vtbl, 0xff24efa4_aade_4b6b_898b_eaa6a20887c7);
 windows_core::imp::interface_hierarchy!(IVdsDrive, windows_core::IUnknown);
@@ -1373,6 +2006,64 @@ impl IVdsDrive_Vtbl {
         iid == &<IVdsDrive as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsDrive_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsDrive_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + IVdsDrive_Impl>(this: *mut core::ffi::c_void, pdriveprop: *mut VDS_DRIVE_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsDrive_Impl::GetProperties(this, core::mem::transmute_copy(&pdriveprop)).into()
+            }
+        }
+        unsafe extern "system" fn GetSubSystem<Identity: windows_core::IUnknownImpl + IVdsDrive_Impl>(this: *mut core::ffi::c_void, ppsubsystem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsDrive_Impl::GetSubSystem(this) {
+                    Ok(ok__) => {
+                        ppsubsystem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryExtents<Identity: windows_core::IUnknownImpl + IVdsDrive_Impl>(this: *mut core::ffi::c_void, ppextentarray: *mut *mut VDS_DRIVE_EXTENT, plnumberofextents: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsDrive_Impl::QueryExtents(this, core::mem::transmute_copy(&ppextentarray), core::mem::transmute_copy(&plnumberofextents)).into()
+            }
+        }
+        unsafe extern "system" fn SetFlags<Identity: windows_core::IUnknownImpl + IVdsDrive_Impl>(this: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsDrive_Impl::SetFlags(this, core::mem::transmute_copy(&ulflags)).into()
+            }
+        }
+        unsafe extern "system" fn ClearFlags<Identity: windows_core::IUnknownImpl + IVdsDrive_Impl>(this: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsDrive_Impl::ClearFlags(this, core::mem::transmute_copy(&ulflags)).into()
+            }
+        }
+        unsafe extern "system" fn SetStatus<Identity: windows_core::IUnknownImpl + IVdsDrive_Impl>(this: *mut core::ffi::c_void, status: VDS_DRIVE_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsDrive_Impl::SetStatus(this, core::mem::transmute_copy(&status)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProperties: GetProperties::<Identity>,
+            GetSubSystem: GetSubSystem::<Identity>,
+            QueryExtents: QueryExtents::<Identity>,
+            SetFlags: SetFlags::<Identity>,
+            ClearFlags: ClearFlags::<Identity>,
+            SetStatus: SetStatus::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsDrive {}
 windows_core::imp::define_interface!(IVdsDrive2, IVdsDrive2_Vtbl, 0x60b5a730_addf_4436_8ca7_5769e2d1ffa4);
 windows_core::imp::interface_hierarchy!(IVdsDrive2, windows_core::IUnknown);
@@ -1404,6 +2095,20 @@ impl IVdsDrive2_Vtbl {
         iid == &<IVdsDrive2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsDrive2_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsDrive2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperties2<Identity: windows_core::IUnknownImpl + IVdsDrive2_Impl>(this: *mut core::ffi::c_void, pdriveprop2: *mut VDS_DRIVE_PROP2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsDrive2_Impl::GetProperties2(this, core::mem::transmute_copy(&pdriveprop2)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetProperties2: GetProperties2::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IVdsDrive2 {}
 windows_core::imp::define_interface!(IVdsHbaPort, IVdsHbaPort_Vtbl, 0x2abd757f_2851_4997_9a13_47d2a885d6ca);
 windows_core::imp::interface_hierarchy!(IVdsHbaPort, windows_core::IUnknown);
@@ -1450,6 +2155,30 @@ impl IVdsHbaPort_Vtbl {
         iid == &<IVdsHbaPort as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsHbaPort_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsHbaPort_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + IVdsHbaPort_Impl>(this: *mut core::ffi::c_void, phbaportprop: *mut VDS_HBAPORT_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsHbaPort_Impl::GetProperties(this, core::mem::transmute_copy(&phbaportprop)).into()
+            }
+        }
+        unsafe extern "system" fn SetAllPathStatuses<Identity: windows_core::IUnknownImpl + IVdsHbaPort_Impl>(this: *mut core::ffi::c_void, status: VDS_PATH_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsHbaPort_Impl::SetAllPathStatuses(this, core::mem::transmute_copy(&status)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProperties: GetProperties::<Identity>,
+            SetAllPathStatuses: SetAllPathStatuses::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsHbaPort {}
 windows_core::imp::define_interface!(IVdsHwProvider, IVdsHwProvider_Vtbl, 0xd99bdaae_b13a_4178_9fdb_e27f16b4603e);
 windows_core::imp::interface_hierarchy!(IVdsHwProvider, windows_core::IUnknown);
@@ -1517,6 +2246,43 @@ impl IVdsHwProvider_Vtbl {
         iid == &<IVdsHwProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsHwProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsHwProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QuerySubSystems<Identity: windows_core::IUnknownImpl + IVdsHwProvider_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsHwProvider_Impl::QuerySubSystems(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Reenumerate<Identity: windows_core::IUnknownImpl + IVdsHwProvider_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsHwProvider_Impl::Reenumerate(this).into()
+            }
+        }
+        unsafe extern "system" fn Refresh<Identity: windows_core::IUnknownImpl + IVdsHwProvider_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsHwProvider_Impl::Refresh(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QuerySubSystems: QuerySubSystems::<Identity>,
+            Reenumerate: Reenumerate::<Identity>,
+            Refresh: Refresh::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsHwProvider {}
 windows_core::imp::define_interface!(IVdsHwProviderPrivate, IVdsHwProviderPrivate_Vtbl, 0x98f17bf3_9f33_4f12_8714_8b4075092c2e);
 windows_core::imp::interface_hierarchy!(IVdsHwProviderPrivate, windows_core::IUnknown);
@@ -1560,6 +2326,26 @@ impl IVdsHwProviderPrivate_Vtbl {
         iid == &<IVdsHwProviderPrivate as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsHwProviderPrivate_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsHwProviderPrivate_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryIfCreatedLun<Identity: windows_core::IUnknownImpl + IVdsHwProviderPrivate_Impl>(this: *mut core::ffi::c_void, pwszdevicepath: windows_core::PCWSTR, pvdsluninformation: *const VDS_LUN_INFORMATION, plunid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsHwProviderPrivate_Impl::QueryIfCreatedLun(this, core::mem::transmute(&pwszdevicepath), core::mem::transmute_copy(&pvdsluninformation)) {
+                    Ok(ok__) => {
+                        plunid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, QueryIfCreatedLun: QueryIfCreatedLun::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IVdsHwProviderPrivate {}
 windows_core::imp::define_interface!(IVdsHwProviderPrivateMpio, IVdsHwProviderPrivateMpio_Vtbl, 0x310a7715_ac2b_4c6f_9827_3d742f351676);
 windows_core::imp::interface_hierarchy!(IVdsHwProviderPrivateMpio, windows_core::IUnknown);
@@ -1591,6 +2377,23 @@ impl IVdsHwProviderPrivateMpio_Vtbl {
         iid == &<IVdsHwProviderPrivateMpio as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsHwProviderPrivateMpio_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsHwProviderPrivateMpio_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAllPathStatusesFromHbaPort<Identity: windows_core::IUnknownImpl + IVdsHwProviderPrivateMpio_Impl>(this: *mut core::ffi::c_void, hbaportprop: VDS_HBAPORT_PROP, status: VDS_PATH_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsHwProviderPrivateMpio_Impl::SetAllPathStatusesFromHbaPort(this, core::mem::transmute(&hbaportprop), core::mem::transmute_copy(&status)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAllPathStatusesFromHbaPort: SetAllPathStatusesFromHbaPort::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsHwProviderPrivateMpio {}
 windows_core::imp::define_interface!(IVdsHwProviderStoragePools, IVdsHwProviderStoragePools_Vtbl, 0xd5b5937a_f188_4c79_b86c_11c920ad11b8);
 windows_core::imp::interface_hierarchy!(IVdsHwProviderStoragePools, windows_core::IUnknown);
@@ -1679,6 +2482,55 @@ impl IVdsHwProviderStoragePools_Vtbl {
         iid == &<IVdsHwProviderStoragePools as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsHwProviderStoragePools_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsHwProviderStoragePools_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryStoragePools<Identity: windows_core::IUnknownImpl + IVdsHwProviderStoragePools_Impl>(this: *mut core::ffi::c_void, ulflags: u32, ullremainingfreespace: u64, ppoolattributes: *const VDS_POOL_ATTRIBUTES, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsHwProviderStoragePools_Impl::QueryStoragePools(this, core::mem::transmute_copy(&ulflags), core::mem::transmute_copy(&ullremainingfreespace), core::mem::transmute_copy(&ppoolattributes)) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateLunInStoragePool<Identity: windows_core::IUnknownImpl + IVdsHwProviderStoragePools_Impl>(this: *mut core::ffi::c_void, r#type: VDS_LUN_TYPE, ullsizeinbytes: u64, storagepoolid: windows_core::GUID, pwszunmaskinglist: windows_core::PCWSTR, phints2: *const VDS_HINTS2, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsHwProviderStoragePools_Impl::CreateLunInStoragePool(this, core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&ullsizeinbytes), core::mem::transmute(&storagepoolid), core::mem::transmute(&pwszunmaskinglist), core::mem::transmute_copy(&phints2)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryMaxLunCreateSizeInStoragePool<Identity: windows_core::IUnknownImpl + IVdsHwProviderStoragePools_Impl>(this: *mut core::ffi::c_void, r#type: VDS_LUN_TYPE, storagepoolid: windows_core::GUID, phints2: *const VDS_HINTS2, pullmaxlunsize: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsHwProviderStoragePools_Impl::QueryMaxLunCreateSizeInStoragePool(this, core::mem::transmute_copy(&r#type), core::mem::transmute(&storagepoolid), core::mem::transmute_copy(&phints2)) {
+                    Ok(ok__) => {
+                        pullmaxlunsize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryStoragePools: QueryStoragePools::<Identity>,
+            CreateLunInStoragePool: CreateLunInStoragePool::<Identity>,
+            QueryMaxLunCreateSizeInStoragePool: QueryMaxLunCreateSizeInStoragePool::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsHwProviderStoragePools {}
 windows_core::imp::define_interface!(IVdsHwProviderType, IVdsHwProviderType_Vtbl, 0x3e0f5166_542d_4fc6_947a_012174240b7e);
 windows_core::imp::interface_hierarchy!(IVdsHwProviderType, windows_core::IUnknown);
@@ -1719,6 +2571,26 @@ impl IVdsHwProviderType_Vtbl {
         iid == &<IVdsHwProviderType as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsHwProviderType_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsHwProviderType_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProviderType<Identity: windows_core::IUnknownImpl + IVdsHwProviderType_Impl>(this: *mut core::ffi::c_void, ptype: *mut VDS_HWPROVIDER_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsHwProviderType_Impl::GetProviderType(this) {
+                    Ok(ok__) => {
+                        ptype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetProviderType: GetProviderType::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IVdsHwProviderType {}
 windows_core::imp::define_interface!(IVdsHwProviderType2, IVdsHwProviderType2_Vtbl, 0x8190236f_c4d0_4e81_8011_d69512fcc984);
 windows_core::imp::interface_hierarchy!(IVdsHwProviderType2, windows_core::IUnknown);
@@ -1759,12 +2631,32 @@ impl IVdsHwProviderType2_Vtbl {
         iid == &<IVdsHwProviderType2 as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IVdsHwProviderType2 {}
-windows_core::imp::define_interface!(IVdsIscsiInitiatorAdapter, IVdsIscsiInitiatorAdapter_Vtbl, 0xb07fedd4_1682_4440_9189_a39b55194dc5);
-windows_core::imp::interface_hierarchy!(IVdsIscsiInitiatorAdapter, windows_core::IUnknown);
-impl IVdsIscsiInitiatorAdapter {
-    pub unsafe fn GetProperties(&self, pinitiatoradapterprop: *mut VDS_ISCSI_INITIATOR_ADAPTER_PROP) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetProperties)(windows_core::Interface::as_raw(self), pinitiatoradapterprop as _).ok() }
+impl<Identity: windows_core::IUnknownImpl + IVdsHwProviderType2_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsHwProviderType2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProviderType2<Identity: windows_core::IUnknownImpl + IVdsHwProviderType2_Impl>(this: *mut core::ffi::c_void, ptype: *mut VDS_HWPROVIDER_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsHwProviderType2_Impl::GetProviderType2(this) {
+                    Ok(ok__) => {
+                        ptype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetProviderType2: GetProviderType2::<Identity> }
+    };
+}
+impl windows_core::RuntimeName for IVdsHwProviderType2 {}
+windows_core::imp::define_interface!(IVdsIscsiInitiatorAdapter, IVdsIscsiInitiatorAdapter_Vtbl, 0xb07fedd4_1682_4440_9189_a39b55194dc5);
+windows_core::imp::interface_hierarchy!(IVdsIscsiInitiatorAdapter, windows_core::IUnknown);
+impl IVdsIscsiInitiatorAdapter {
+    pub unsafe fn GetProperties(&self, pinitiatoradapterprop: *mut VDS_ISCSI_INITIATOR_ADAPTER_PROP) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetProperties)(windows_core::Interface::as_raw(self), pinitiatoradapterprop as _).ok() }
     }
     pub unsafe fn QueryInitiatorPortals(&self) -> windows_core::Result<IEnumVdsObject> {
         unsafe {
@@ -1856,6 +2748,62 @@ impl IVdsIscsiInitiatorAdapter_Vtbl {
         iid == &<IVdsIscsiInitiatorAdapter as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsIscsiInitiatorAdapter_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsIscsiInitiatorAdapter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + IVdsIscsiInitiatorAdapter_Impl>(this: *mut core::ffi::c_void, pinitiatoradapterprop: *mut VDS_ISCSI_INITIATOR_ADAPTER_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsIscsiInitiatorAdapter_Impl::GetProperties(this, core::mem::transmute_copy(&pinitiatoradapterprop)).into()
+            }
+        }
+        unsafe extern "system" fn QueryInitiatorPortals<Identity: windows_core::IUnknownImpl + IVdsIscsiInitiatorAdapter_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsIscsiInitiatorAdapter_Impl::QueryInitiatorPortals(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LoginToTarget<Identity: windows_core::IUnknownImpl + IVdsIscsiInitiatorAdapter_Impl>(this: *mut core::ffi::c_void, logintype: VDS_ISCSI_LOGIN_TYPE, targetid: windows_core::GUID, targetportalid: windows_core::GUID, initiatorportalid: windows_core::GUID, ulloginflags: u32, bheaderdigest: windows_core::BOOL, bdatadigest: windows_core::BOOL, authtype: VDS_ISCSI_AUTH_TYPE, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsIscsiInitiatorAdapter_Impl::LoginToTarget(this, core::mem::transmute_copy(&logintype), core::mem::transmute(&targetid), core::mem::transmute(&targetportalid), core::mem::transmute(&initiatorportalid), core::mem::transmute_copy(&ulloginflags), core::mem::transmute_copy(&bheaderdigest), core::mem::transmute_copy(&bdatadigest), core::mem::transmute_copy(&authtype)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LogoutFromTarget<Identity: windows_core::IUnknownImpl + IVdsIscsiInitiatorAdapter_Impl>(this: *mut core::ffi::c_void, targetid: windows_core::GUID, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsIscsiInitiatorAdapter_Impl::LogoutFromTarget(this, core::mem::transmute(&targetid)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProperties: GetProperties::<Identity>,
+            QueryInitiatorPortals: QueryInitiatorPortals::<Identity>,
+            LoginToTarget: LoginToTarget::<Identity>,
+            LogoutFromTarget: LogoutFromTarget::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsIscsiInitiatorAdapter {}
 windows_core::imp::define_interface!(IVdsIscsiInitiatorPortal, IVdsIscsiInitiatorPortal_Vtbl, 0x38a0a9ab_7cc8_4693_ac07_1f28bd03c3da);
 windows_core::imp::interface_hierarchy!(IVdsIscsiInitiatorPortal, windows_core::IUnknown);
@@ -1956,6 +2904,63 @@ impl IVdsIscsiInitiatorPortal_Vtbl {
         iid == &<IVdsIscsiInitiatorPortal as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsIscsiInitiatorPortal_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsIscsiInitiatorPortal_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + IVdsIscsiInitiatorPortal_Impl>(this: *mut core::ffi::c_void, pinitiatorportalprop: *mut VDS_ISCSI_INITIATOR_PORTAL_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsIscsiInitiatorPortal_Impl::GetProperties(this, core::mem::transmute_copy(&pinitiatorportalprop)).into()
+            }
+        }
+        unsafe extern "system" fn GetInitiatorAdapter<Identity: windows_core::IUnknownImpl + IVdsIscsiInitiatorPortal_Impl>(this: *mut core::ffi::c_void, ppinitiatoradapter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsIscsiInitiatorPortal_Impl::GetInitiatorAdapter(this) {
+                    Ok(ok__) => {
+                        ppinitiatoradapter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetIpsecTunnelAddress<Identity: windows_core::IUnknownImpl + IVdsIscsiInitiatorPortal_Impl>(this: *mut core::ffi::c_void, ptunneladdress: *const VDS_IPADDRESS, pdestinationaddress: *const VDS_IPADDRESS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsIscsiInitiatorPortal_Impl::SetIpsecTunnelAddress(this, core::mem::transmute_copy(&ptunneladdress), core::mem::transmute_copy(&pdestinationaddress)).into()
+            }
+        }
+        unsafe extern "system" fn GetIpsecSecurity<Identity: windows_core::IUnknownImpl + IVdsIscsiInitiatorPortal_Impl>(this: *mut core::ffi::c_void, targetportalid: windows_core::GUID, pullsecurityflags: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsIscsiInitiatorPortal_Impl::GetIpsecSecurity(this, core::mem::transmute(&targetportalid)) {
+                    Ok(ok__) => {
+                        pullsecurityflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetIpsecSecurity<Identity: windows_core::IUnknownImpl + IVdsIscsiInitiatorPortal_Impl>(this: *mut core::ffi::c_void, targetportalid: windows_core::GUID, ullsecurityflags: u64, pipseckey: *const VDS_ISCSI_IPSEC_KEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsIscsiInitiatorPortal_Impl::SetIpsecSecurity(this, core::mem::transmute(&targetportalid), core::mem::transmute_copy(&ullsecurityflags), core::mem::transmute_copy(&pipseckey)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProperties: GetProperties::<Identity>,
+            GetInitiatorAdapter: GetInitiatorAdapter::<Identity>,
+            SetIpsecTunnelAddress: SetIpsecTunnelAddress::<Identity>,
+            GetIpsecSecurity: GetIpsecSecurity::<Identity>,
+            SetIpsecSecurity: SetIpsecSecurity::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsIscsiInitiatorPortal {}
 windows_core::imp::define_interface!(IVdsIscsiPortal, IVdsIscsiPortal_Vtbl, 0x7fa1499d_ec85_4a8a_a47b_ff69201fcd34);
 windows_core::imp::interface_hierarchy!(IVdsIscsiPortal, windows_core::IUnknown);
@@ -2089,6 +3094,83 @@ impl IVdsIscsiPortal_Vtbl {
         iid == &<IVdsIscsiPortal as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsIscsiPortal_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsIscsiPortal_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + IVdsIscsiPortal_Impl>(this: *mut core::ffi::c_void, pportalprop: *mut VDS_ISCSI_PORTAL_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsIscsiPortal_Impl::GetProperties(this, core::mem::transmute_copy(&pportalprop)).into()
+            }
+        }
+        unsafe extern "system" fn GetSubSystem<Identity: windows_core::IUnknownImpl + IVdsIscsiPortal_Impl>(this: *mut core::ffi::c_void, ppsubsystem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsIscsiPortal_Impl::GetSubSystem(this) {
+                    Ok(ok__) => {
+                        ppsubsystem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryAssociatedPortalGroups<Identity: windows_core::IUnknownImpl + IVdsIscsiPortal_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsIscsiPortal_Impl::QueryAssociatedPortalGroups(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetStatus<Identity: windows_core::IUnknownImpl + IVdsIscsiPortal_Impl>(this: *mut core::ffi::c_void, status: VDS_ISCSI_PORTAL_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsIscsiPortal_Impl::SetStatus(this, core::mem::transmute_copy(&status)).into()
+            }
+        }
+        unsafe extern "system" fn SetIpsecTunnelAddress<Identity: windows_core::IUnknownImpl + IVdsIscsiPortal_Impl>(this: *mut core::ffi::c_void, ptunneladdress: *const VDS_IPADDRESS, pdestinationaddress: *const VDS_IPADDRESS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsIscsiPortal_Impl::SetIpsecTunnelAddress(this, core::mem::transmute_copy(&ptunneladdress), core::mem::transmute_copy(&pdestinationaddress)).into()
+            }
+        }
+        unsafe extern "system" fn GetIpsecSecurity<Identity: windows_core::IUnknownImpl + IVdsIscsiPortal_Impl>(this: *mut core::ffi::c_void, pinitiatorportaladdress: *const VDS_IPADDRESS, pullsecurityflags: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsIscsiPortal_Impl::GetIpsecSecurity(this, core::mem::transmute_copy(&pinitiatorportaladdress)) {
+                    Ok(ok__) => {
+                        pullsecurityflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetIpsecSecurity<Identity: windows_core::IUnknownImpl + IVdsIscsiPortal_Impl>(this: *mut core::ffi::c_void, pinitiatorportaladdress: *const VDS_IPADDRESS, ullsecurityflags: u64, pipseckey: *const VDS_ISCSI_IPSEC_KEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsIscsiPortal_Impl::SetIpsecSecurity(this, core::mem::transmute_copy(&pinitiatorportaladdress), core::mem::transmute_copy(&ullsecurityflags), core::mem::transmute_copy(&pipseckey)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProperties: GetProperties::<Identity>,
+            GetSubSystem: GetSubSystem::<Identity>,
+            QueryAssociatedPortalGroups: QueryAssociatedPortalGroups::<Identity>,
+            SetStatus: SetStatus::<Identity>,
+            SetIpsecTunnelAddress: SetIpsecTunnelAddress::<Identity>,
+            GetIpsecSecurity: GetIpsecSecurity::<Identity>,
+            SetIpsecSecurity: SetIpsecSecurity::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsIscsiPortal {}
 windows_core::imp::define_interface!(IVdsIscsiPortalGroup, IVdsIscsiPortalGroup_Vtbl, 0xfef5f89d_a3dd_4b36_bf28_e7dde045c593);
 windows_core::imp::interface_hierarchy!(IVdsIscsiPortalGroup, windows_core::IUnknown);
@@ -2228,6 +3310,88 @@ impl IVdsIscsiPortalGroup_Vtbl {
         iid == &<IVdsIscsiPortalGroup as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsIscsiPortalGroup_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsIscsiPortalGroup_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + IVdsIscsiPortalGroup_Impl>(this: *mut core::ffi::c_void, pportalgroupprop: *mut VDS_ISCSI_PORTALGROUP_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsIscsiPortalGroup_Impl::GetProperties(this, core::mem::transmute_copy(&pportalgroupprop)).into()
+            }
+        }
+        unsafe extern "system" fn GetTarget<Identity: windows_core::IUnknownImpl + IVdsIscsiPortalGroup_Impl>(this: *mut core::ffi::c_void, pptarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsIscsiPortalGroup_Impl::GetTarget(this) {
+                    Ok(ok__) => {
+                        pptarget.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryAssociatedPortals<Identity: windows_core::IUnknownImpl + IVdsIscsiPortalGroup_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsIscsiPortalGroup_Impl::QueryAssociatedPortals(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddPortal<Identity: windows_core::IUnknownImpl + IVdsIscsiPortalGroup_Impl>(this: *mut core::ffi::c_void, portalid: windows_core::GUID, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsIscsiPortalGroup_Impl::AddPortal(this, core::mem::transmute(&portalid)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemovePortal<Identity: windows_core::IUnknownImpl + IVdsIscsiPortalGroup_Impl>(this: *mut core::ffi::c_void, portalid: windows_core::GUID, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsIscsiPortalGroup_Impl::RemovePortal(this, core::mem::transmute(&portalid)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IVdsIscsiPortalGroup_Impl>(this: *mut core::ffi::c_void, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsIscsiPortalGroup_Impl::Delete(this) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProperties: GetProperties::<Identity>,
+            GetTarget: GetTarget::<Identity>,
+            QueryAssociatedPortals: QueryAssociatedPortals::<Identity>,
+            AddPortal: AddPortal::<Identity>,
+            RemovePortal: RemovePortal::<Identity>,
+            Delete: Delete::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsIscsiPortalGroup {}
 windows_core::imp::define_interface!(IVdsIscsiPortalLocal, IVdsIscsiPortalLocal_Vtbl, 0xad837c28_52c1_421d_bf04_fae7da665396);
 windows_core::imp::interface_hierarchy!(IVdsIscsiPortalLocal, windows_core::IUnknown);
@@ -2259,6 +3423,23 @@ impl IVdsIscsiPortalLocal_Vtbl {
         iid == &<IVdsIscsiPortalLocal as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsIscsiPortalLocal_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsIscsiPortalLocal_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetIpsecSecurityLocal<Identity: windows_core::IUnknownImpl + IVdsIscsiPortalLocal_Impl>(this: *mut core::ffi::c_void, ullsecurityflags: u64, pipseckey: *const VDS_ISCSI_IPSEC_KEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsIscsiPortalLocal_Impl::SetIpsecSecurityLocal(this, core::mem::transmute_copy(&ullsecurityflags), core::mem::transmute_copy(&pipseckey)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetIpsecSecurityLocal: SetIpsecSecurityLocal::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsIscsiPortalLocal {}
 windows_core::imp::define_interface!(IVdsIscsiTarget, IVdsIscsiTarget_Vtbl, 0xaa8f5055_83e5_4bcc_aa73_19851a36a849);
 windows_core::imp::interface_hierarchy!(IVdsIscsiTarget, windows_core::IUnknown);
@@ -2455,42 +3636,152 @@ impl IVdsIscsiTarget_Vtbl {
         iid == &<IVdsIscsiTarget as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IVdsIscsiTarget {}
-windows_core::imp::define_interface!(IVdsLun, IVdsLun_Vtbl, 0x3540a9c7_e60f_4111_a840_8bba6c2c83d8);
-windows_core::imp::interface_hierarchy!(IVdsLun, windows_core::IUnknown);
-impl IVdsLun {
-    pub unsafe fn GetProperties(&self, plunprop: *mut VDS_LUN_PROP) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetProperties)(windows_core::Interface::as_raw(self), plunprop as _).ok() }
-    }
-    pub unsafe fn GetSubSystem(&self) -> windows_core::Result<IVdsSubSystem> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetSubSystem)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn GetIdentificationData(&self, pluninfo: *mut VDS_LUN_INFORMATION) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetIdentificationData)(windows_core::Interface::as_raw(self), pluninfo as _).ok() }
-    }
-    pub unsafe fn QueryActiveControllers(&self) -> windows_core::Result<IEnumVdsObject> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).QueryActiveControllers)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+impl<Identity: windows_core::IUnknownImpl + IVdsIscsiTarget_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsIscsiTarget_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + IVdsIscsiTarget_Impl>(this: *mut core::ffi::c_void, ptargetprop: *mut VDS_ISCSI_TARGET_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsIscsiTarget_Impl::GetProperties(this, core::mem::transmute_copy(&ptargetprop)).into()
+            }
         }
-    }
-    pub unsafe fn Extend(&self, ullnumberofbytestoadd: u64, pdriveidarray: &[windows_core::GUID]) -> windows_core::Result<IVdsAsync> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).Extend)(windows_core::Interface::as_raw(self), ullnumberofbytestoadd, core::mem::transmute(pdriveidarray.as_ptr()), pdriveidarray.len().try_into().unwrap(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn GetSubSystem<Identity: windows_core::IUnknownImpl + IVdsIscsiTarget_Impl>(this: *mut core::ffi::c_void, ppsubsystem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsIscsiTarget_Impl::GetSubSystem(this) {
+                    Ok(ok__) => {
+                        ppsubsystem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn Shrink(&self, ullnumberofbytestoremove: u64) -> windows_core::Result<IVdsAsync> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).Shrink)(windows_core::Interface::as_raw(self), ullnumberofbytestoremove, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn QueryPortalGroups<Identity: windows_core::IUnknownImpl + IVdsIscsiTarget_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsIscsiTarget_Impl::QueryPortalGroups(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn QueryPlexes(&self) -> windows_core::Result<IEnumVdsObject> {
-        unsafe {
+        unsafe extern "system" fn QueryAssociatedLuns<Identity: windows_core::IUnknownImpl + IVdsIscsiTarget_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsIscsiTarget_Impl::QueryAssociatedLuns(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreatePortalGroup<Identity: windows_core::IUnknownImpl + IVdsIscsiTarget_Impl>(this: *mut core::ffi::c_void, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsIscsiTarget_Impl::CreatePortalGroup(this) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IVdsIscsiTarget_Impl>(this: *mut core::ffi::c_void, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsIscsiTarget_Impl::Delete(this) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetFriendlyName<Identity: windows_core::IUnknownImpl + IVdsIscsiTarget_Impl>(this: *mut core::ffi::c_void, pwszfriendlyname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsIscsiTarget_Impl::SetFriendlyName(this, core::mem::transmute(&pwszfriendlyname)).into()
+            }
+        }
+        unsafe extern "system" fn SetSharedSecret<Identity: windows_core::IUnknownImpl + IVdsIscsiTarget_Impl>(this: *mut core::ffi::c_void, ptargetsharedsecret: *const VDS_ISCSI_SHARED_SECRET, pwszinitiatorname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsIscsiTarget_Impl::SetSharedSecret(this, core::mem::transmute_copy(&ptargetsharedsecret), core::mem::transmute(&pwszinitiatorname)).into()
+            }
+        }
+        unsafe extern "system" fn RememberInitiatorSharedSecret<Identity: windows_core::IUnknownImpl + IVdsIscsiTarget_Impl>(this: *mut core::ffi::c_void, pwszinitiatorname: windows_core::PCWSTR, pinitiatorsharedsecret: *const VDS_ISCSI_SHARED_SECRET) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsIscsiTarget_Impl::RememberInitiatorSharedSecret(this, core::mem::transmute(&pwszinitiatorname), core::mem::transmute_copy(&pinitiatorsharedsecret)).into()
+            }
+        }
+        unsafe extern "system" fn GetConnectedInitiators<Identity: windows_core::IUnknownImpl + IVdsIscsiTarget_Impl>(this: *mut core::ffi::c_void, pppwszinitiatorlist: *mut *mut windows_core::PWSTR, plnumberofinitiators: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsIscsiTarget_Impl::GetConnectedInitiators(this, core::mem::transmute_copy(&pppwszinitiatorlist), core::mem::transmute_copy(&plnumberofinitiators)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProperties: GetProperties::<Identity>,
+            GetSubSystem: GetSubSystem::<Identity>,
+            QueryPortalGroups: QueryPortalGroups::<Identity>,
+            QueryAssociatedLuns: QueryAssociatedLuns::<Identity>,
+            CreatePortalGroup: CreatePortalGroup::<Identity>,
+            Delete: Delete::<Identity>,
+            SetFriendlyName: SetFriendlyName::<Identity>,
+            SetSharedSecret: SetSharedSecret::<Identity>,
+            RememberInitiatorSharedSecret: RememberInitiatorSharedSecret::<Identity>,
+            GetConnectedInitiators: GetConnectedInitiators::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IVdsIscsiTarget {}
+windows_core::imp::define_interface!(IVdsLun, IVdsLun_Vtbl, 0x3540a9c7_e60f_4111_a840_8bba6c2c83d8);
+windows_core::imp::interface_hierarchy!(IVdsLun, windows_core::IUnknown);
+impl IVdsLun {
+    pub unsafe fn GetProperties(&self, plunprop: *mut VDS_LUN_PROP) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetProperties)(windows_core::Interface::as_raw(self), plunprop as _).ok() }
+    }
+    pub unsafe fn GetSubSystem(&self) -> windows_core::Result<IVdsSubSystem> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetSubSystem)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn GetIdentificationData(&self, pluninfo: *mut VDS_LUN_INFORMATION) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetIdentificationData)(windows_core::Interface::as_raw(self), pluninfo as _).ok() }
+    }
+    pub unsafe fn QueryActiveControllers(&self) -> windows_core::Result<IEnumVdsObject> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).QueryActiveControllers)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn Extend(&self, ullnumberofbytestoadd: u64, pdriveidarray: &[windows_core::GUID]) -> windows_core::Result<IVdsAsync> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).Extend)(windows_core::Interface::as_raw(self), ullnumberofbytestoadd, core::mem::transmute(pdriveidarray.as_ptr()), pdriveidarray.len().try_into().unwrap(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn Shrink(&self, ullnumberofbytestoremove: u64) -> windows_core::Result<IVdsAsync> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).Shrink)(windows_core::Interface::as_raw(self), ullnumberofbytestoremove, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn QueryPlexes(&self) -> windows_core::Result<IEnumVdsObject> {
+        unsafe {
             let mut result__ = core::mem::zeroed();
             (windows_core::Interface::vtable(self).QueryPlexes)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
@@ -2765,6 +4056,189 @@ impl IVdsLun_Vtbl {
         iid == &<IVdsLun as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsLun_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsLun_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + IVdsLun_Impl>(this: *mut core::ffi::c_void, plunprop: *mut VDS_LUN_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsLun_Impl::GetProperties(this, core::mem::transmute_copy(&plunprop)).into()
+            }
+        }
+        unsafe extern "system" fn GetSubSystem<Identity: windows_core::IUnknownImpl + IVdsLun_Impl>(this: *mut core::ffi::c_void, ppsubsystem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsLun_Impl::GetSubSystem(this) {
+                    Ok(ok__) => {
+                        ppsubsystem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetIdentificationData<Identity: windows_core::IUnknownImpl + IVdsLun_Impl>(this: *mut core::ffi::c_void, pluninfo: *mut VDS_LUN_INFORMATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsLun_Impl::GetIdentificationData(this, core::mem::transmute_copy(&pluninfo)).into()
+            }
+        }
+        unsafe extern "system" fn QueryActiveControllers<Identity: windows_core::IUnknownImpl + IVdsLun_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsLun_Impl::QueryActiveControllers(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Extend<Identity: windows_core::IUnknownImpl + IVdsLun_Impl>(this: *mut core::ffi::c_void, ullnumberofbytestoadd: u64, pdriveidarray: *const windows_core::GUID, lnumberofdrives: i32, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsLun_Impl::Extend(this, core::mem::transmute_copy(&ullnumberofbytestoadd), core::mem::transmute_copy(&pdriveidarray), core::mem::transmute_copy(&lnumberofdrives)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Shrink<Identity: windows_core::IUnknownImpl + IVdsLun_Impl>(this: *mut core::ffi::c_void, ullnumberofbytestoremove: u64, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsLun_Impl::Shrink(this, core::mem::transmute_copy(&ullnumberofbytestoremove)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryPlexes<Identity: windows_core::IUnknownImpl + IVdsLun_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsLun_Impl::QueryPlexes(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddPlex<Identity: windows_core::IUnknownImpl + IVdsLun_Impl>(this: *mut core::ffi::c_void, lunid: windows_core::GUID, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsLun_Impl::AddPlex(this, core::mem::transmute(&lunid)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemovePlex<Identity: windows_core::IUnknownImpl + IVdsLun_Impl>(this: *mut core::ffi::c_void, plexid: windows_core::GUID, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsLun_Impl::RemovePlex(this, core::mem::transmute(&plexid)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Recover<Identity: windows_core::IUnknownImpl + IVdsLun_Impl>(this: *mut core::ffi::c_void, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsLun_Impl::Recover(this) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMask<Identity: windows_core::IUnknownImpl + IVdsLun_Impl>(this: *mut core::ffi::c_void, pwszunmaskinglist: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsLun_Impl::SetMask(this, core::mem::transmute(&pwszunmaskinglist)).into()
+            }
+        }
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IVdsLun_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsLun_Impl::Delete(this).into()
+            }
+        }
+        unsafe extern "system" fn AssociateControllers<Identity: windows_core::IUnknownImpl + IVdsLun_Impl>(this: *mut core::ffi::c_void, pactivecontrolleridarray: *const windows_core::GUID, lnumberofactivecontrollers: i32, pinactivecontrolleridarray: *const windows_core::GUID, lnumberofinactivecontrollers: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsLun_Impl::AssociateControllers(this, core::mem::transmute_copy(&pactivecontrolleridarray), core::mem::transmute_copy(&lnumberofactivecontrollers), core::mem::transmute_copy(&pinactivecontrolleridarray), core::mem::transmute_copy(&lnumberofinactivecontrollers)).into()
+            }
+        }
+        unsafe extern "system" fn QueryHints<Identity: windows_core::IUnknownImpl + IVdsLun_Impl>(this: *mut core::ffi::c_void, phints: *mut VDS_HINTS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsLun_Impl::QueryHints(this, core::mem::transmute_copy(&phints)).into()
+            }
+        }
+        unsafe extern "system" fn ApplyHints<Identity: windows_core::IUnknownImpl + IVdsLun_Impl>(this: *mut core::ffi::c_void, phints: *const VDS_HINTS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsLun_Impl::ApplyHints(this, core::mem::transmute_copy(&phints)).into()
+            }
+        }
+        unsafe extern "system" fn SetStatus<Identity: windows_core::IUnknownImpl + IVdsLun_Impl>(this: *mut core::ffi::c_void, status: VDS_LUN_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsLun_Impl::SetStatus(this, core::mem::transmute_copy(&status)).into()
+            }
+        }
+        unsafe extern "system" fn QueryMaxLunExtendSize<Identity: windows_core::IUnknownImpl + IVdsLun_Impl>(this: *mut core::ffi::c_void, pdriveidarray: *const windows_core::GUID, lnumberofdrives: i32, pullmaxbytestobeadded: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsLun_Impl::QueryMaxLunExtendSize(this, core::mem::transmute_copy(&pdriveidarray), core::mem::transmute_copy(&lnumberofdrives)) {
+                    Ok(ok__) => {
+                        pullmaxbytestobeadded.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProperties: GetProperties::<Identity>,
+            GetSubSystem: GetSubSystem::<Identity>,
+            GetIdentificationData: GetIdentificationData::<Identity>,
+            QueryActiveControllers: QueryActiveControllers::<Identity>,
+            Extend: Extend::<Identity>,
+            Shrink: Shrink::<Identity>,
+            QueryPlexes: QueryPlexes::<Identity>,
+            AddPlex: AddPlex::<Identity>,
+            RemovePlex: RemovePlex::<Identity>,
+            Recover: Recover::<Identity>,
+            SetMask: SetMask::<Identity>,
+            Delete: Delete::<Identity>,
+            AssociateControllers: AssociateControllers::<Identity>,
+            QueryHints: QueryHints::<Identity>,
+            ApplyHints: ApplyHints::<Identity>,
+            SetStatus: SetStatus::<Identity>,
+            QueryMaxLunExtendSize: QueryMaxLunExtendSize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsLun {}
 windows_core::imp::define_interface!(IVdsLun2, IVdsLun2_Vtbl, 0xe5b3a735_9efb_499a_8071_4394d9ee6fcb);
 windows_core::imp::interface_hierarchy!(IVdsLun2, windows_core::IUnknown);
@@ -2811,6 +4285,30 @@ impl IVdsLun2_Vtbl {
         iid == &<IVdsLun2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsLun2_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsLun2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryHints2<Identity: windows_core::IUnknownImpl + IVdsLun2_Impl>(this: *mut core::ffi::c_void, phints2: *mut VDS_HINTS2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsLun2_Impl::QueryHints2(this, core::mem::transmute_copy(&phints2)).into()
+            }
+        }
+        unsafe extern "system" fn ApplyHints2<Identity: windows_core::IUnknownImpl + IVdsLun2_Impl>(this: *mut core::ffi::c_void, phints2: *const VDS_HINTS2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsLun2_Impl::ApplyHints2(this, core::mem::transmute_copy(&phints2)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryHints2: QueryHints2::<Identity>,
+            ApplyHints2: ApplyHints2::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsLun2 {}
 windows_core::imp::define_interface!(IVdsLunControllerPorts, IVdsLunControllerPorts_Vtbl, 0x451fe266_da6d_406a_bb60_82e534f85aeb);
 windows_core::imp::interface_hierarchy!(IVdsLunControllerPorts, windows_core::IUnknown);
@@ -2866,6 +4364,36 @@ impl IVdsLunControllerPorts_Vtbl {
         iid == &<IVdsLunControllerPorts as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsLunControllerPorts_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsLunControllerPorts_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AssociateControllerPorts<Identity: windows_core::IUnknownImpl + IVdsLunControllerPorts_Impl>(this: *mut core::ffi::c_void, pactivecontrollerportidarray: *const windows_core::GUID, lnumberofactivecontrollerports: i32, pinactivecontrollerportidarray: *const windows_core::GUID, lnumberofinactivecontrollerports: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsLunControllerPorts_Impl::AssociateControllerPorts(this, core::mem::transmute_copy(&pactivecontrollerportidarray), core::mem::transmute_copy(&lnumberofactivecontrollerports), core::mem::transmute_copy(&pinactivecontrollerportidarray), core::mem::transmute_copy(&lnumberofinactivecontrollerports)).into()
+            }
+        }
+        unsafe extern "system" fn QueryActiveControllerPorts<Identity: windows_core::IUnknownImpl + IVdsLunControllerPorts_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsLunControllerPorts_Impl::QueryActiveControllerPorts(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AssociateControllerPorts: AssociateControllerPorts::<Identity>,
+            QueryActiveControllerPorts: QueryActiveControllerPorts::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsLunControllerPorts {}
 windows_core::imp::define_interface!(IVdsLunIscsi, IVdsLunIscsi_Vtbl, 0x0d7c1e64_b59b_45ae_b86a_2c2cc6a42067);
 windows_core::imp::interface_hierarchy!(IVdsLunIscsi, windows_core::IUnknown);
@@ -2921,6 +4449,36 @@ impl IVdsLunIscsi_Vtbl {
         iid == &<IVdsLunIscsi as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsLunIscsi_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsLunIscsi_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AssociateTargets<Identity: windows_core::IUnknownImpl + IVdsLunIscsi_Impl>(this: *mut core::ffi::c_void, ptargetidarray: *const windows_core::GUID, lnumberoftargets: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsLunIscsi_Impl::AssociateTargets(this, core::mem::transmute_copy(&ptargetidarray), core::mem::transmute_copy(&lnumberoftargets)).into()
+            }
+        }
+        unsafe extern "system" fn QueryAssociatedTargets<Identity: windows_core::IUnknownImpl + IVdsLunIscsi_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsLunIscsi_Impl::QueryAssociatedTargets(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AssociateTargets: AssociateTargets::<Identity>,
+            QueryAssociatedTargets: QueryAssociatedTargets::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsLunIscsi {}
 windows_core::imp::define_interface!(IVdsLunMpio, IVdsLunMpio_Vtbl, 0x7c5fbae3_333a_48a1_a982_33c15788cde3);
 windows_core::imp::interface_hierarchy!(IVdsLunMpio, windows_core::IUnknown);
@@ -3000,9 +4558,53 @@ impl IVdsLunMpio_Vtbl {
         iid == &<IVdsLunMpio as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IVdsLunMpio {}
-windows_core::imp::define_interface!(IVdsLunNaming, IVdsLunNaming_Vtbl, 0x907504cb_6b4e_4d88_a34d_17ba661fbb06);
-windows_core::imp::interface_hierarchy!(IVdsLunNaming, windows_core::IUnknown);
+impl<Identity: windows_core::IUnknownImpl + IVdsLunMpio_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsLunMpio_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPathInfo<Identity: windows_core::IUnknownImpl + IVdsLunMpio_Impl>(this: *mut core::ffi::c_void, pppaths: *mut *mut VDS_PATH_INFO, plnumberofpaths: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsLunMpio_Impl::GetPathInfo(this, core::mem::transmute_copy(&pppaths), core::mem::transmute_copy(&plnumberofpaths)).into()
+            }
+        }
+        unsafe extern "system" fn GetLoadBalancePolicy<Identity: windows_core::IUnknownImpl + IVdsLunMpio_Impl>(this: *mut core::ffi::c_void, ppolicy: *mut VDS_LOADBALANCE_POLICY_ENUM, pppaths: *mut *mut VDS_PATH_POLICY, plnumberofpaths: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsLunMpio_Impl::GetLoadBalancePolicy(this, core::mem::transmute_copy(&ppolicy), core::mem::transmute_copy(&pppaths), core::mem::transmute_copy(&plnumberofpaths)).into()
+            }
+        }
+        unsafe extern "system" fn SetLoadBalancePolicy<Identity: windows_core::IUnknownImpl + IVdsLunMpio_Impl>(this: *mut core::ffi::c_void, policy: VDS_LOADBALANCE_POLICY_ENUM, ppaths: *const VDS_PATH_POLICY, lnumberofpaths: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsLunMpio_Impl::SetLoadBalancePolicy(this, core::mem::transmute_copy(&policy), core::mem::transmute_copy(&ppaths), core::mem::transmute_copy(&lnumberofpaths)).into()
+            }
+        }
+        unsafe extern "system" fn GetSupportedLbPolicies<Identity: windows_core::IUnknownImpl + IVdsLunMpio_Impl>(this: *mut core::ffi::c_void, pullbflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsLunMpio_Impl::GetSupportedLbPolicies(this) {
+                    Ok(ok__) => {
+                        pullbflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPathInfo: GetPathInfo::<Identity>,
+            GetLoadBalancePolicy: GetLoadBalancePolicy::<Identity>,
+            SetLoadBalancePolicy: SetLoadBalancePolicy::<Identity>,
+            GetSupportedLbPolicies: GetSupportedLbPolicies::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IVdsLunMpio {}
+windows_core::imp::define_interface!(IVdsLunNaming, IVdsLunNaming_Vtbl, 0x907504cb_6b4e_4d88_a34d_17ba661fbb06);
+windows_core::imp::interface_hierarchy!(IVdsLunNaming, windows_core::IUnknown);
 impl IVdsLunNaming {
     pub unsafe fn SetFriendlyName<P0>(&self, pwszfriendlyname: P0) -> windows_core::Result<()>
     where
@@ -3034,6 +4636,20 @@ impl IVdsLunNaming_Vtbl {
         iid == &<IVdsLunNaming as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsLunNaming_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsLunNaming_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetFriendlyName<Identity: windows_core::IUnknownImpl + IVdsLunNaming_Impl>(this: *mut core::ffi::c_void, pwszfriendlyname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsLunNaming_Impl::SetFriendlyName(this, core::mem::transmute(&pwszfriendlyname)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetFriendlyName: SetFriendlyName::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IVdsLunNaming {}
 windows_core::imp::define_interface!(IVdsLunNumber, IVdsLunNumber_Vtbl, 0xd3f95e46_54b3_41f9_b678_0f1871443a08);
 windows_core::imp::interface_hierarchy!(IVdsLunNumber, windows_core::IUnknown);
@@ -3074,6 +4690,26 @@ impl IVdsLunNumber_Vtbl {
         iid == &<IVdsLunNumber as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsLunNumber_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsLunNumber_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetLunNumber<Identity: windows_core::IUnknownImpl + IVdsLunNumber_Impl>(this: *mut core::ffi::c_void, pullunnumber: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsLunNumber_Impl::GetLunNumber(this) {
+                    Ok(ok__) => {
+                        pullunnumber.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetLunNumber: GetLunNumber::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IVdsLunNumber {}
 windows_core::imp::define_interface!(IVdsLunPlex, IVdsLunPlex_Vtbl, 0x0ee1a790_5d2e_4abb_8c99_c481e8be2138);
 windows_core::imp::interface_hierarchy!(IVdsLunPlex, windows_core::IUnknown);
@@ -3165,6 +4801,57 @@ impl IVdsLunPlex_Vtbl {
         iid == &<IVdsLunPlex as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsLunPlex_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsLunPlex_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + IVdsLunPlex_Impl>(this: *mut core::ffi::c_void, pplexprop: *mut VDS_LUN_PLEX_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsLunPlex_Impl::GetProperties(this, core::mem::transmute_copy(&pplexprop)).into()
+            }
+        }
+        unsafe extern "system" fn GetLun<Identity: windows_core::IUnknownImpl + IVdsLunPlex_Impl>(this: *mut core::ffi::c_void, pplun: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsLunPlex_Impl::GetLun(this) {
+                    Ok(ok__) => {
+                        pplun.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryExtents<Identity: windows_core::IUnknownImpl + IVdsLunPlex_Impl>(this: *mut core::ffi::c_void, ppextentarray: *mut *mut VDS_DRIVE_EXTENT, plnumberofextents: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsLunPlex_Impl::QueryExtents(this, core::mem::transmute_copy(&ppextentarray), core::mem::transmute_copy(&plnumberofextents)).into()
+            }
+        }
+        unsafe extern "system" fn QueryHints<Identity: windows_core::IUnknownImpl + IVdsLunPlex_Impl>(this: *mut core::ffi::c_void, phints: *mut VDS_HINTS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsLunPlex_Impl::QueryHints(this, core::mem::transmute_copy(&phints)).into()
+            }
+        }
+        unsafe extern "system" fn ApplyHints<Identity: windows_core::IUnknownImpl + IVdsLunPlex_Impl>(this: *mut core::ffi::c_void, phints: *const VDS_HINTS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsLunPlex_Impl::ApplyHints(this, core::mem::transmute_copy(&phints)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProperties: GetProperties::<Identity>,
+            GetLun: GetLun::<Identity>,
+            QueryExtents: QueryExtents::<Identity>,
+            QueryHints: QueryHints::<Identity>,
+            ApplyHints: ApplyHints::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsLunPlex {}
 windows_core::imp::define_interface!(IVdsMaintenance, IVdsMaintenance_Vtbl, 0xdaebeef3_8523_47ed_a2b9_05cecce2a1ae);
 windows_core::imp::interface_hierarchy!(IVdsMaintenance, windows_core::IUnknown);
@@ -3223,6 +4910,37 @@ impl IVdsMaintenance_Vtbl {
         iid == &<IVdsMaintenance as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsMaintenance_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsMaintenance_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StartMaintenance<Identity: windows_core::IUnknownImpl + IVdsMaintenance_Impl>(this: *mut core::ffi::c_void, operation: VDS_MAINTENANCE_OPERATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsMaintenance_Impl::StartMaintenance(this, core::mem::transmute_copy(&operation)).into()
+            }
+        }
+        unsafe extern "system" fn StopMaintenance<Identity: windows_core::IUnknownImpl + IVdsMaintenance_Impl>(this: *mut core::ffi::c_void, operation: VDS_MAINTENANCE_OPERATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsMaintenance_Impl::StopMaintenance(this, core::mem::transmute_copy(&operation)).into()
+            }
+        }
+        unsafe extern "system" fn PulseMaintenance<Identity: windows_core::IUnknownImpl + IVdsMaintenance_Impl>(this: *mut core::ffi::c_void, operation: VDS_MAINTENANCE_OPERATION, ulcount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsMaintenance_Impl::PulseMaintenance(this, core::mem::transmute_copy(&operation), core::mem::transmute_copy(&ulcount)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            StartMaintenance: StartMaintenance::<Identity>,
+            StopMaintenance: StopMaintenance::<Identity>,
+            PulseMaintenance: PulseMaintenance::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsMaintenance {}
 windows_core::imp::define_interface!(IVdsOpenVDisk, IVdsOpenVDisk_Vtbl, 0x75c8f324_f715_4fe3_a28e_f9011b61a4a1);
 windows_core::imp::interface_hierarchy!(IVdsOpenVDisk, windows_core::IUnknown);
@@ -3383,6 +5101,83 @@ impl IVdsOpenVDisk_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Storage_Vhd")]
+impl<Identity: windows_core::IUnknownImpl + IVdsOpenVDisk_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsOpenVDisk_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Attach<Identity: windows_core::IUnknownImpl + IVdsOpenVDisk_Impl>(this: *mut core::ffi::c_void, pstringsecuritydescriptor: windows_core::PCWSTR, flags: super::Vhd::ATTACH_VIRTUAL_DISK_FLAG, providerspecificflags: u32, timeoutinms: u32, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsOpenVDisk_Impl::Attach(this, core::mem::transmute(&pstringsecuritydescriptor), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&providerspecificflags), core::mem::transmute_copy(&timeoutinms)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Detach<Identity: windows_core::IUnknownImpl + IVdsOpenVDisk_Impl>(this: *mut core::ffi::c_void, flags: super::Vhd::DETACH_VIRTUAL_DISK_FLAG, providerspecificflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsOpenVDisk_Impl::Detach(this, core::mem::transmute_copy(&flags), core::mem::transmute_copy(&providerspecificflags)).into()
+            }
+        }
+        unsafe extern "system" fn DetachAndDelete<Identity: windows_core::IUnknownImpl + IVdsOpenVDisk_Impl>(this: *mut core::ffi::c_void, flags: super::Vhd::DETACH_VIRTUAL_DISK_FLAG, providerspecificflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsOpenVDisk_Impl::DetachAndDelete(this, core::mem::transmute_copy(&flags), core::mem::transmute_copy(&providerspecificflags)).into()
+            }
+        }
+        unsafe extern "system" fn Compact<Identity: windows_core::IUnknownImpl + IVdsOpenVDisk_Impl>(this: *mut core::ffi::c_void, flags: super::Vhd::COMPACT_VIRTUAL_DISK_FLAG, reserved: u32, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsOpenVDisk_Impl::Compact(this, core::mem::transmute_copy(&flags), core::mem::transmute_copy(&reserved)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Merge<Identity: windows_core::IUnknownImpl + IVdsOpenVDisk_Impl>(this: *mut core::ffi::c_void, flags: super::Vhd::MERGE_VIRTUAL_DISK_FLAG, mergedepth: u32, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsOpenVDisk_Impl::Merge(this, core::mem::transmute_copy(&flags), core::mem::transmute_copy(&mergedepth)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Expand<Identity: windows_core::IUnknownImpl + IVdsOpenVDisk_Impl>(this: *mut core::ffi::c_void, flags: super::Vhd::EXPAND_VIRTUAL_DISK_FLAG, newsize: u64, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsOpenVDisk_Impl::Expand(this, core::mem::transmute_copy(&flags), core::mem::transmute_copy(&newsize)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Attach: Attach::<Identity>,
+            Detach: Detach::<Identity>,
+            DetachAndDelete: DetachAndDelete::<Identity>,
+            Compact: Compact::<Identity>,
+            Merge: Merge::<Identity>,
+            Expand: Expand::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Storage_Vhd")]
 impl windows_core::RuntimeName for IVdsOpenVDisk {}
 windows_core::imp::define_interface!(IVdsPack, IVdsPack_Vtbl, 0x3b69d7f5_9d94_4648_91ca_79939ba263bf);
 windows_core::imp::interface_hierarchy!(IVdsPack, windows_core::IUnknown);
@@ -3579,6 +5374,122 @@ impl IVdsPack_Vtbl {
         iid == &<IVdsPack as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsPack_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsPack_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + IVdsPack_Impl>(this: *mut core::ffi::c_void, ppackprop: *mut VDS_PACK_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsPack_Impl::GetProperties(this, core::mem::transmute_copy(&ppackprop)).into()
+            }
+        }
+        unsafe extern "system" fn GetProvider<Identity: windows_core::IUnknownImpl + IVdsPack_Impl>(this: *mut core::ffi::c_void, ppprovider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsPack_Impl::GetProvider(this) {
+                    Ok(ok__) => {
+                        ppprovider.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryVolumes<Identity: windows_core::IUnknownImpl + IVdsPack_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsPack_Impl::QueryVolumes(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryDisks<Identity: windows_core::IUnknownImpl + IVdsPack_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsPack_Impl::QueryDisks(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateVolume<Identity: windows_core::IUnknownImpl + IVdsPack_Impl>(this: *mut core::ffi::c_void, r#type: VDS_VOLUME_TYPE, pinputdiskarray: *const VDS_INPUT_DISK, lnumberofdisks: i32, ulstripesize: u32, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsPack_Impl::CreateVolume(this, core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pinputdiskarray), core::mem::transmute_copy(&lnumberofdisks), core::mem::transmute_copy(&ulstripesize)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddDisk<Identity: windows_core::IUnknownImpl + IVdsPack_Impl>(this: *mut core::ffi::c_void, diskid: windows_core::GUID, partitionstyle: VDS_PARTITION_STYLE, bashotspare: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsPack_Impl::AddDisk(this, core::mem::transmute(&diskid), core::mem::transmute_copy(&partitionstyle), core::mem::transmute_copy(&bashotspare)).into()
+            }
+        }
+        unsafe extern "system" fn MigrateDisks<Identity: windows_core::IUnknownImpl + IVdsPack_Impl>(this: *mut core::ffi::c_void, pdiskarray: *const windows_core::GUID, lnumberofdisks: i32, targetpack: windows_core::GUID, bforce: windows_core::BOOL, bqueryonly: windows_core::BOOL, presults: *mut windows_core::HRESULT, pbrebootneeded: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsPack_Impl::MigrateDisks(this, core::mem::transmute_copy(&pdiskarray), core::mem::transmute_copy(&lnumberofdisks), core::mem::transmute(&targetpack), core::mem::transmute_copy(&bforce), core::mem::transmute_copy(&bqueryonly), core::mem::transmute_copy(&presults), core::mem::transmute_copy(&pbrebootneeded)).into()
+            }
+        }
+        unsafe extern "system" fn ReplaceDisk<Identity: windows_core::IUnknownImpl + IVdsPack_Impl>(this: *mut core::ffi::c_void, olddiskid: windows_core::GUID, newdiskid: windows_core::GUID, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsPack_Impl::ReplaceDisk(this, core::mem::transmute(&olddiskid), core::mem::transmute(&newdiskid)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveMissingDisk<Identity: windows_core::IUnknownImpl + IVdsPack_Impl>(this: *mut core::ffi::c_void, diskid: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsPack_Impl::RemoveMissingDisk(this, core::mem::transmute(&diskid)).into()
+            }
+        }
+        unsafe extern "system" fn Recover<Identity: windows_core::IUnknownImpl + IVdsPack_Impl>(this: *mut core::ffi::c_void, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsPack_Impl::Recover(this) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProperties: GetProperties::<Identity>,
+            GetProvider: GetProvider::<Identity>,
+            QueryVolumes: QueryVolumes::<Identity>,
+            QueryDisks: QueryDisks::<Identity>,
+            CreateVolume: CreateVolume::<Identity>,
+            AddDisk: AddDisk::<Identity>,
+            MigrateDisks: MigrateDisks::<Identity>,
+            ReplaceDisk: ReplaceDisk::<Identity>,
+            RemoveMissingDisk: RemoveMissingDisk::<Identity>,
+            Recover: Recover::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsPack {}
 windows_core::imp::define_interface!(IVdsPack2, IVdsPack2_Vtbl, 0x13b50bff_290a_47dd_8558_b7c58db1a71a);
 windows_core::imp::interface_hierarchy!(IVdsPack2, windows_core::IUnknown);
@@ -3619,6 +5530,26 @@ impl IVdsPack2_Vtbl {
         iid == &<IVdsPack2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsPack2_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsPack2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateVolume2<Identity: windows_core::IUnknownImpl + IVdsPack2_Impl>(this: *mut core::ffi::c_void, r#type: VDS_VOLUME_TYPE, pinputdiskarray: *const VDS_INPUT_DISK, lnumberofdisks: i32, ulstripesize: u32, ulalign: u32, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsPack2_Impl::CreateVolume2(this, core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pinputdiskarray), core::mem::transmute_copy(&lnumberofdisks), core::mem::transmute_copy(&ulstripesize), core::mem::transmute_copy(&ulalign)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateVolume2: CreateVolume2::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IVdsPack2 {}
 windows_core::imp::define_interface!(IVdsProvider, IVdsProvider_Vtbl, 0x10c5e575_7984_4e81_a56b_431f5f92ae42);
 windows_core::imp::interface_hierarchy!(IVdsProvider, windows_core::IUnknown);
@@ -3650,6 +5581,20 @@ impl IVdsProvider_Vtbl {
         iid == &<IVdsProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + IVdsProvider_Impl>(this: *mut core::ffi::c_void, pproviderprop: *mut VDS_PROVIDER_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsProvider_Impl::GetProperties(this, core::mem::transmute_copy(&pproviderprop)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetProperties: GetProperties::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IVdsProvider {}
 windows_core::imp::define_interface!(IVdsProviderPrivate, IVdsProviderPrivate_Vtbl, 0x11f3cd41_b7e8_48ff_9472_9dff018aa292);
 windows_core::imp::interface_hierarchy!(IVdsProviderPrivate, windows_core::IUnknown);
@@ -3721,6 +5666,43 @@ impl IVdsProviderPrivate_Vtbl {
         iid == &<IVdsProviderPrivate as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsProviderPrivate_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsProviderPrivate_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetObject<Identity: windows_core::IUnknownImpl + IVdsProviderPrivate_Impl>(this: *mut core::ffi::c_void, objectid: windows_core::GUID, r#type: VDS_OBJECT_TYPE, ppobjectunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsProviderPrivate_Impl::GetObject(this, core::mem::transmute(&objectid), core::mem::transmute_copy(&r#type)) {
+                    Ok(ok__) => {
+                        ppobjectunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OnLoad<Identity: windows_core::IUnknownImpl + IVdsProviderPrivate_Impl>(this: *mut core::ffi::c_void, pwszmachinename: windows_core::PCWSTR, pcallbackobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsProviderPrivate_Impl::OnLoad(this, core::mem::transmute(&pwszmachinename), core::mem::transmute_copy(&pcallbackobject)).into()
+            }
+        }
+        unsafe extern "system" fn OnUnload<Identity: windows_core::IUnknownImpl + IVdsProviderPrivate_Impl>(this: *mut core::ffi::c_void, bforceunload: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsProviderPrivate_Impl::OnUnload(this, core::mem::transmute_copy(&bforceunload)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetObject: GetObject::<Identity>,
+            OnLoad: OnLoad::<Identity>,
+            OnUnload: OnUnload::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsProviderPrivate {}
 windows_core::imp::define_interface!(IVdsProviderSupport, IVdsProviderSupport_Vtbl, 0x1732be13_e8f9_4a03_bfbc_5f616aa66ce1);
 windows_core::imp::interface_hierarchy!(IVdsProviderSupport, windows_core::IUnknown);
@@ -3761,8 +5743,28 @@ impl IVdsProviderSupport_Vtbl {
         iid == &<IVdsProviderSupport as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IVdsProviderSupport {}
-windows_core::imp::define_interface!(IVdsRemovable, IVdsRemovable_Vtbl, 0x0316560b_5db4_4ed9_bbb5_213436ddc0d9);
+impl<Identity: windows_core::IUnknownImpl + IVdsProviderSupport_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsProviderSupport_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetVersionSupport<Identity: windows_core::IUnknownImpl + IVdsProviderSupport_Impl>(this: *mut core::ffi::c_void, ulversionsupport: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsProviderSupport_Impl::GetVersionSupport(this) {
+                    Ok(ok__) => {
+                        ulversionsupport.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetVersionSupport: GetVersionSupport::<Identity> }
+    };
+}
+impl windows_core::RuntimeName for IVdsProviderSupport {}
+windows_core::imp::define_interface!(IVdsRemovable, IVdsRemovable_Vtbl, 0x0316560b_5db4_4ed9_bbb5_213436ddc0d9);
 windows_core::imp::interface_hierarchy!(IVdsRemovable, windows_core::IUnknown);
 impl IVdsRemovable {
     pub unsafe fn QueryMedia(&self) -> windows_core::Result<()> {
@@ -3803,6 +5805,30 @@ impl IVdsRemovable_Vtbl {
         iid == &<IVdsRemovable as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsRemovable_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsRemovable_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryMedia<Identity: windows_core::IUnknownImpl + IVdsRemovable_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsRemovable_Impl::QueryMedia(this).into()
+            }
+        }
+        unsafe extern "system" fn Eject<Identity: windows_core::IUnknownImpl + IVdsRemovable_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsRemovable_Impl::Eject(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryMedia: QueryMedia::<Identity>,
+            Eject: Eject::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsRemovable {}
 windows_core::imp::define_interface!(IVdsService, IVdsService_Vtbl, 0x0818a8ef_9ba9_40d8_a6f9_e22833cc771e);
 windows_core::imp::interface_hierarchy!(IVdsService, windows_core::IUnknown);
@@ -4086,6 +6112,171 @@ impl IVdsService_Vtbl {
         iid == &<IVdsService as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsService_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsService_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsServiceReady<Identity: windows_core::IUnknownImpl + IVdsService_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsService_Impl::IsServiceReady(this).into()
+            }
+        }
+        unsafe extern "system" fn WaitForServiceReady<Identity: windows_core::IUnknownImpl + IVdsService_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsService_Impl::WaitForServiceReady(this).into()
+            }
+        }
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + IVdsService_Impl>(this: *mut core::ffi::c_void, pserviceprop: *mut VDS_SERVICE_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsService_Impl::GetProperties(this) {
+                    Ok(ok__) => {
+                        pserviceprop.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryProviders<Identity: windows_core::IUnknownImpl + IVdsService_Impl>(this: *mut core::ffi::c_void, masks: u32, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsService_Impl::QueryProviders(this, core::mem::transmute_copy(&masks)) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryMaskedDisks<Identity: windows_core::IUnknownImpl + IVdsService_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsService_Impl::QueryMaskedDisks(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryUnallocatedDisks<Identity: windows_core::IUnknownImpl + IVdsService_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsService_Impl::QueryUnallocatedDisks(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetObject<Identity: windows_core::IUnknownImpl + IVdsService_Impl>(this: *mut core::ffi::c_void, objectid: windows_core::GUID, r#type: VDS_OBJECT_TYPE, ppobjectunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsService_Impl::GetObject(this, core::mem::transmute(&objectid), core::mem::transmute_copy(&r#type)) {
+                    Ok(ok__) => {
+                        ppobjectunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryDriveLetters<Identity: windows_core::IUnknownImpl + IVdsService_Impl>(this: *mut core::ffi::c_void, wcfirstletter: u16, count: u32, pdriveletterproparray: *mut VDS_DRIVE_LETTER_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsService_Impl::QueryDriveLetters(this, core::mem::transmute_copy(&wcfirstletter), core::mem::transmute_copy(&count), core::mem::transmute_copy(&pdriveletterproparray)).into()
+            }
+        }
+        unsafe extern "system" fn QueryFileSystemTypes<Identity: windows_core::IUnknownImpl + IVdsService_Impl>(this: *mut core::ffi::c_void, ppfilesystemtypeprops: *mut *mut VDS_FILE_SYSTEM_TYPE_PROP, plnumberoffilesystems: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsService_Impl::QueryFileSystemTypes(this, core::mem::transmute_copy(&ppfilesystemtypeprops), core::mem::transmute_copy(&plnumberoffilesystems)).into()
+            }
+        }
+        unsafe extern "system" fn Reenumerate<Identity: windows_core::IUnknownImpl + IVdsService_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsService_Impl::Reenumerate(this).into()
+            }
+        }
+        unsafe extern "system" fn Refresh<Identity: windows_core::IUnknownImpl + IVdsService_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsService_Impl::Refresh(this).into()
+            }
+        }
+        unsafe extern "system" fn CleanupObsoleteMountPoints<Identity: windows_core::IUnknownImpl + IVdsService_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsService_Impl::CleanupObsoleteMountPoints(this).into()
+            }
+        }
+        unsafe extern "system" fn Advise<Identity: windows_core::IUnknownImpl + IVdsService_Impl>(this: *mut core::ffi::c_void, psink: *mut core::ffi::c_void, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsService_Impl::Advise(this, core::mem::transmute_copy(&psink)) {
+                    Ok(ok__) => {
+                        pdwcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Unadvise<Identity: windows_core::IUnknownImpl + IVdsService_Impl>(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsService_Impl::Unadvise(this, core::mem::transmute_copy(&dwcookie)).into()
+            }
+        }
+        unsafe extern "system" fn Reboot<Identity: windows_core::IUnknownImpl + IVdsService_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsService_Impl::Reboot(this).into()
+            }
+        }
+        unsafe extern "system" fn SetFlags<Identity: windows_core::IUnknownImpl + IVdsService_Impl>(this: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsService_Impl::SetFlags(this, core::mem::transmute_copy(&ulflags)).into()
+            }
+        }
+        unsafe extern "system" fn ClearFlags<Identity: windows_core::IUnknownImpl + IVdsService_Impl>(this: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsService_Impl::ClearFlags(this, core::mem::transmute_copy(&ulflags)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsServiceReady: IsServiceReady::<Identity>,
+            WaitForServiceReady: WaitForServiceReady::<Identity>,
+            GetProperties: GetProperties::<Identity>,
+            QueryProviders: QueryProviders::<Identity>,
+            QueryMaskedDisks: QueryMaskedDisks::<Identity>,
+            QueryUnallocatedDisks: QueryUnallocatedDisks::<Identity>,
+            GetObject: GetObject::<Identity>,
+            QueryDriveLetters: QueryDriveLetters::<Identity>,
+            QueryFileSystemTypes: QueryFileSystemTypes::<Identity>,
+            Reenumerate: Reenumerate::<Identity>,
+            Refresh: Refresh::<Identity>,
+            CleanupObsoleteMountPoints: CleanupObsoleteMountPoints::<Identity>,
+            Advise: Advise::<Identity>,
+            Unadvise: Unadvise::<Identity>,
+            Reboot: Reboot::<Identity>,
+            SetFlags: SetFlags::<Identity>,
+            ClearFlags: ClearFlags::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsService {}
 windows_core::imp::define_interface!(IVdsServiceHba, IVdsServiceHba_Vtbl, 0x0ac13689_3134_47c6_a17c_4669216801be);
 windows_core::imp::interface_hierarchy!(IVdsServiceHba, windows_core::IUnknown);
@@ -4126,6 +6317,26 @@ impl IVdsServiceHba_Vtbl {
         iid == &<IVdsServiceHba as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsServiceHba_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsServiceHba_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryHbaPorts<Identity: windows_core::IUnknownImpl + IVdsServiceHba_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsServiceHba_Impl::QueryHbaPorts(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, QueryHbaPorts: QueryHbaPorts::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IVdsServiceHba {}
 windows_core::imp::define_interface!(IVdsServiceInitialization, IVdsServiceInitialization_Vtbl, 0x4afc3636_db01_4052_80c3_03bbcb8d3c69);
 windows_core::imp::interface_hierarchy!(IVdsServiceInitialization, windows_core::IUnknown);
@@ -4160,6 +6371,20 @@ impl IVdsServiceInitialization_Vtbl {
         iid == &<IVdsServiceInitialization as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsServiceInitialization_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsServiceInitialization_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IVdsServiceInitialization_Impl>(this: *mut core::ffi::c_void, pwszmachinename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsServiceInitialization_Impl::Initialize(this, core::mem::transmute(&pwszmachinename)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Initialize: Initialize::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IVdsServiceInitialization {}
 windows_core::imp::define_interface!(IVdsServiceIscsi, IVdsServiceIscsi_Vtbl, 0x14fbe036_3ed7_4e10_90e9_a5ff991aff01);
 windows_core::imp::interface_hierarchy!(IVdsServiceIscsi, windows_core::IUnknown);
@@ -4284,6 +6509,77 @@ impl IVdsServiceIscsi_Vtbl {
         iid == &<IVdsServiceIscsi as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsServiceIscsi_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsServiceIscsi_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetInitiatorName<Identity: windows_core::IUnknownImpl + IVdsServiceIscsi_Impl>(this: *mut core::ffi::c_void, ppwsziscsiname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsServiceIscsi_Impl::GetInitiatorName(this) {
+                    Ok(ok__) => {
+                        ppwsziscsiname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryInitiatorAdapters<Identity: windows_core::IUnknownImpl + IVdsServiceIscsi_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsServiceIscsi_Impl::QueryInitiatorAdapters(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetIpsecGroupPresharedKey<Identity: windows_core::IUnknownImpl + IVdsServiceIscsi_Impl>(this: *mut core::ffi::c_void, pipseckey: *const VDS_ISCSI_IPSEC_KEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsServiceIscsi_Impl::SetIpsecGroupPresharedKey(this, core::mem::transmute_copy(&pipseckey)).into()
+            }
+        }
+        unsafe extern "system" fn SetAllIpsecTunnelAddresses<Identity: windows_core::IUnknownImpl + IVdsServiceIscsi_Impl>(this: *mut core::ffi::c_void, ptunneladdress: *const VDS_IPADDRESS, pdestinationaddress: *const VDS_IPADDRESS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsServiceIscsi_Impl::SetAllIpsecTunnelAddresses(this, core::mem::transmute_copy(&ptunneladdress), core::mem::transmute_copy(&pdestinationaddress)).into()
+            }
+        }
+        unsafe extern "system" fn SetAllIpsecSecurity<Identity: windows_core::IUnknownImpl + IVdsServiceIscsi_Impl>(this: *mut core::ffi::c_void, targetportalid: windows_core::GUID, ullsecurityflags: u64, pipseckey: *const VDS_ISCSI_IPSEC_KEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsServiceIscsi_Impl::SetAllIpsecSecurity(this, core::mem::transmute(&targetportalid), core::mem::transmute_copy(&ullsecurityflags), core::mem::transmute_copy(&pipseckey)).into()
+            }
+        }
+        unsafe extern "system" fn SetInitiatorSharedSecret<Identity: windows_core::IUnknownImpl + IVdsServiceIscsi_Impl>(this: *mut core::ffi::c_void, pinitiatorsharedsecret: *const VDS_ISCSI_SHARED_SECRET, targetid: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsServiceIscsi_Impl::SetInitiatorSharedSecret(this, core::mem::transmute_copy(&pinitiatorsharedsecret), core::mem::transmute(&targetid)).into()
+            }
+        }
+        unsafe extern "system" fn RememberTargetSharedSecret<Identity: windows_core::IUnknownImpl + IVdsServiceIscsi_Impl>(this: *mut core::ffi::c_void, targetid: windows_core::GUID, ptargetsharedsecret: *const VDS_ISCSI_SHARED_SECRET) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsServiceIscsi_Impl::RememberTargetSharedSecret(this, core::mem::transmute(&targetid), core::mem::transmute_copy(&ptargetsharedsecret)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetInitiatorName: GetInitiatorName::<Identity>,
+            QueryInitiatorAdapters: QueryInitiatorAdapters::<Identity>,
+            SetIpsecGroupPresharedKey: SetIpsecGroupPresharedKey::<Identity>,
+            SetAllIpsecTunnelAddresses: SetAllIpsecTunnelAddresses::<Identity>,
+            SetAllIpsecSecurity: SetAllIpsecSecurity::<Identity>,
+            SetInitiatorSharedSecret: SetInitiatorSharedSecret::<Identity>,
+            RememberTargetSharedSecret: RememberTargetSharedSecret::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsServiceIscsi {}
 windows_core::imp::define_interface!(IVdsServiceLoader, IVdsServiceLoader_Vtbl, 0xe0393303_90d4_4a97_ab71_e9b671ee2729);
 windows_core::imp::interface_hierarchy!(IVdsServiceLoader, windows_core::IUnknown);
@@ -4327,6 +6623,26 @@ impl IVdsServiceLoader_Vtbl {
         iid == &<IVdsServiceLoader as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsServiceLoader_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsServiceLoader_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn LoadService<Identity: windows_core::IUnknownImpl + IVdsServiceLoader_Impl>(this: *mut core::ffi::c_void, pwszmachinename: windows_core::PCWSTR, ppservice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsServiceLoader_Impl::LoadService(this, core::mem::transmute(&pwszmachinename)) {
+                    Ok(ok__) => {
+                        ppservice.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, LoadService: LoadService::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IVdsServiceLoader {}
 windows_core::imp::define_interface!(IVdsServiceSAN, IVdsServiceSAN_Vtbl, 0xfc5d23e8_a88b_41a5_8de0_2d2f73c5a630);
 windows_core::imp::interface_hierarchy!(IVdsServiceSAN, windows_core::IUnknown);
@@ -4382,6 +6698,36 @@ impl IVdsServiceSAN_Vtbl {
         iid == &<IVdsServiceSAN as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsServiceSAN_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsServiceSAN_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSANPolicy<Identity: windows_core::IUnknownImpl + IVdsServiceSAN_Impl>(this: *mut core::ffi::c_void, psanpolicy: *mut VDS_SAN_POLICY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsServiceSAN_Impl::GetSANPolicy(this) {
+                    Ok(ok__) => {
+                        psanpolicy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSANPolicy<Identity: windows_core::IUnknownImpl + IVdsServiceSAN_Impl>(this: *mut core::ffi::c_void, sanpolicy: VDS_SAN_POLICY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsServiceSAN_Impl::SetSANPolicy(this, core::mem::transmute_copy(&sanpolicy)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSANPolicy: GetSANPolicy::<Identity>,
+            SetSANPolicy: SetSANPolicy::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsServiceSAN {}
 windows_core::imp::define_interface!(IVdsServiceSw, IVdsServiceSw_Vtbl, 0x15fc031c_0652_4306_b2c3_f558b8f837e2);
 windows_core::imp::interface_hierarchy!(IVdsServiceSw, windows_core::IUnknown);
@@ -4425,6 +6771,26 @@ impl IVdsServiceSw_Vtbl {
         iid == &<IVdsServiceSw as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsServiceSw_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsServiceSw_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDiskObject<Identity: windows_core::IUnknownImpl + IVdsServiceSw_Impl>(this: *mut core::ffi::c_void, pwszdeviceid: windows_core::PCWSTR, ppdiskunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsServiceSw_Impl::GetDiskObject(this, core::mem::transmute(&pwszdeviceid)) {
+                    Ok(ok__) => {
+                        ppdiskunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDiskObject: GetDiskObject::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IVdsServiceSw {}
 windows_core::imp::define_interface!(IVdsServiceUninstallDisk, IVdsServiceUninstallDisk_Vtbl, 0xb6b22da8_f903_4be7_b492_c09d875ac9da);
 windows_core::imp::interface_hierarchy!(IVdsServiceUninstallDisk, windows_core::IUnknown);
@@ -4480,6 +6846,36 @@ impl IVdsServiceUninstallDisk_Vtbl {
         iid == &<IVdsServiceUninstallDisk as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsServiceUninstallDisk_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsServiceUninstallDisk_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDiskIdFromLunInfo<Identity: windows_core::IUnknownImpl + IVdsServiceUninstallDisk_Impl>(this: *mut core::ffi::c_void, pluninfo: *const VDS_LUN_INFORMATION, pdiskid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsServiceUninstallDisk_Impl::GetDiskIdFromLunInfo(this, core::mem::transmute_copy(&pluninfo)) {
+                    Ok(ok__) => {
+                        pdiskid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UninstallDisks<Identity: windows_core::IUnknownImpl + IVdsServiceUninstallDisk_Impl>(this: *mut core::ffi::c_void, pdiskidarray: *const windows_core::GUID, ulcount: u32, bforce: bool, pbreboot: *mut u8, presults: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsServiceUninstallDisk_Impl::UninstallDisks(this, core::mem::transmute_copy(&pdiskidarray), core::mem::transmute_copy(&ulcount), core::mem::transmute_copy(&bforce), core::mem::transmute_copy(&pbreboot), core::mem::transmute_copy(&presults)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDiskIdFromLunInfo: GetDiskIdFromLunInfo::<Identity>,
+            UninstallDisks: UninstallDisks::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsServiceUninstallDisk {}
 windows_core::imp::define_interface!(IVdsStoragePool, IVdsStoragePool_Vtbl, 0x932ca8cf_0eb3_4ba8_9620_22665d7f8450);
 windows_core::imp::interface_hierarchy!(IVdsStoragePool, windows_core::IUnknown);
@@ -4601,6 +6997,76 @@ impl IVdsStoragePool_Vtbl {
         iid == &<IVdsStoragePool as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsStoragePool_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsStoragePool_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProvider<Identity: windows_core::IUnknownImpl + IVdsStoragePool_Impl>(this: *mut core::ffi::c_void, ppprovider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsStoragePool_Impl::GetProvider(this) {
+                    Ok(ok__) => {
+                        ppprovider.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + IVdsStoragePool_Impl>(this: *mut core::ffi::c_void, pstoragepoolprop: *mut VDS_STORAGE_POOL_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsStoragePool_Impl::GetProperties(this, core::mem::transmute_copy(&pstoragepoolprop)).into()
+            }
+        }
+        unsafe extern "system" fn GetAttributes<Identity: windows_core::IUnknownImpl + IVdsStoragePool_Impl>(this: *mut core::ffi::c_void, pstoragepoolattributes: *mut VDS_POOL_ATTRIBUTES) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsStoragePool_Impl::GetAttributes(this, core::mem::transmute_copy(&pstoragepoolattributes)).into()
+            }
+        }
+        unsafe extern "system" fn QueryDriveExtents<Identity: windows_core::IUnknownImpl + IVdsStoragePool_Impl>(this: *mut core::ffi::c_void, ppextentarray: *mut *mut VDS_STORAGE_POOL_DRIVE_EXTENT, plnumberofextents: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsStoragePool_Impl::QueryDriveExtents(this, core::mem::transmute_copy(&ppextentarray), core::mem::transmute_copy(&plnumberofextents)).into()
+            }
+        }
+        unsafe extern "system" fn QueryAllocatedLuns<Identity: windows_core::IUnknownImpl + IVdsStoragePool_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsStoragePool_Impl::QueryAllocatedLuns(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryAllocatedStoragePools<Identity: windows_core::IUnknownImpl + IVdsStoragePool_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsStoragePool_Impl::QueryAllocatedStoragePools(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProvider: GetProvider::<Identity>,
+            GetProperties: GetProperties::<Identity>,
+            GetAttributes: GetAttributes::<Identity>,
+            QueryDriveExtents: QueryDriveExtents::<Identity>,
+            QueryAllocatedLuns: QueryAllocatedLuns::<Identity>,
+            QueryAllocatedStoragePools: QueryAllocatedStoragePools::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsStoragePool {}
 windows_core::imp::define_interface!(IVdsSubSystem, IVdsSubSystem_Vtbl, 0x6fcee2d3_6d90_4f91_80e2_a5c7caaca9d8);
 windows_core::imp::interface_hierarchy!(IVdsSubSystem, windows_core::IUnknown);
@@ -4697,17 +7163,156 @@ pub trait IVdsSubSystem_Impl: windows_core::IUnknownImpl {
     fn SetStatus(&self, status: VDS_SUB_SYSTEM_STATUS) -> windows_core::Result<()>;
     fn QueryMaxLunCreateSize(&self, r#type: VDS_LUN_TYPE, pdriveidarray: *const windows_core::GUID, lnumberofdrives: i32, phints: *const VDS_HINTS) -> windows_core::Result<u64>;
 }
-impl IVdsSubSystem_Vtbl {
-    pub const fn new<Identity: IVdsSubSystem_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn GetProperties<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, psubsystemprop: *mut VDS_SUB_SYSTEM_PROP) -> windows_core::HRESULT {
+impl IVdsSubSystem_Vtbl {
+    pub const fn new<Identity: IVdsSubSystem_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn GetProperties<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, psubsystemprop: *mut VDS_SUB_SYSTEM_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IVdsSubSystem_Impl::GetProperties(this, core::mem::transmute_copy(&psubsystemprop)).into()
+            }
+        }
+        unsafe extern "system" fn GetProvider<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppprovider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IVdsSubSystem_Impl::GetProvider(this) {
+                    Ok(ok__) => {
+                        ppprovider.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryControllers<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IVdsSubSystem_Impl::QueryControllers(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryLuns<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IVdsSubSystem_Impl::QueryLuns(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryDrives<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IVdsSubSystem_Impl::QueryDrives(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDrive<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, sbusnumber: i16, sslotnumber: i16, ppdrive: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IVdsSubSystem_Impl::GetDrive(this, core::mem::transmute_copy(&sbusnumber), core::mem::transmute_copy(&sslotnumber)) {
+                    Ok(ok__) => {
+                        ppdrive.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Reenumerate<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IVdsSubSystem_Impl::Reenumerate(this).into()
+            }
+        }
+        unsafe extern "system" fn SetControllerStatus<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ponlinecontrolleridarray: *const windows_core::GUID, lnumberofonlinecontrollers: i32, pofflinecontrolleridarray: *const windows_core::GUID, lnumberofofflinecontrollers: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IVdsSubSystem_Impl::SetControllerStatus(this, core::mem::transmute_copy(&ponlinecontrolleridarray), core::mem::transmute_copy(&lnumberofonlinecontrollers), core::mem::transmute_copy(&pofflinecontrolleridarray), core::mem::transmute_copy(&lnumberofofflinecontrollers)).into()
+            }
+        }
+        unsafe extern "system" fn CreateLun<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, r#type: VDS_LUN_TYPE, ullsizeinbytes: u64, pdriveidarray: *const windows_core::GUID, lnumberofdrives: i32, pwszunmaskinglist: windows_core::PCWSTR, phints: *const VDS_HINTS, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IVdsSubSystem_Impl::CreateLun(this, core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&ullsizeinbytes), core::mem::transmute_copy(&pdriveidarray), core::mem::transmute_copy(&lnumberofdrives), core::mem::transmute(&pwszunmaskinglist), core::mem::transmute_copy(&phints)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReplaceDrive<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, drivetobereplaced: windows_core::GUID, replacementdrive: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IVdsSubSystem_Impl::ReplaceDrive(this, core::mem::transmute(&drivetobereplaced), core::mem::transmute(&replacementdrive)).into()
+            }
+        }
+        unsafe extern "system" fn SetStatus<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, status: VDS_SUB_SYSTEM_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IVdsSubSystem_Impl::SetStatus(this, core::mem::transmute_copy(&status)).into()
+            }
+        }
+        unsafe extern "system" fn QueryMaxLunCreateSize<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, r#type: VDS_LUN_TYPE, pdriveidarray: *const windows_core::GUID, lnumberofdrives: i32, phints: *const VDS_HINTS, pullmaxlunsize: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IVdsSubSystem_Impl::QueryMaxLunCreateSize(this, core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pdriveidarray), core::mem::transmute_copy(&lnumberofdrives), core::mem::transmute_copy(&phints)) {
+                    Ok(ok__) => {
+                        pullmaxlunsize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            GetProperties: GetProperties::<Identity, OFFSET>,
+            GetProvider: GetProvider::<Identity, OFFSET>,
+            QueryControllers: QueryControllers::<Identity, OFFSET>,
+            QueryLuns: QueryLuns::<Identity, OFFSET>,
+            QueryDrives: QueryDrives::<Identity, OFFSET>,
+            GetDrive: GetDrive::<Identity, OFFSET>,
+            Reenumerate: Reenumerate::<Identity, OFFSET>,
+            SetControllerStatus: SetControllerStatus::<Identity, OFFSET>,
+            CreateLun: CreateLun::<Identity, OFFSET>,
+            ReplaceDrive: ReplaceDrive::<Identity, OFFSET>,
+            SetStatus: SetStatus::<Identity, OFFSET>,
+            QueryMaxLunCreateSize: QueryMaxLunCreateSize::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsSubSystem as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IVdsSubSystem_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsSubSystem_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + IVdsSubSystem_Impl>(this: *mut core::ffi::c_void, psubsystemprop: *mut VDS_SUB_SYSTEM_PROP) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IVdsSubSystem_Impl::GetProperties(this, core::mem::transmute_copy(&psubsystemprop)).into()
             }
         }
-        unsafe extern "system" fn GetProvider<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppprovider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetProvider<Identity: windows_core::IUnknownImpl + IVdsSubSystem_Impl>(this: *mut core::ffi::c_void, ppprovider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IVdsSubSystem_Impl::GetProvider(this) {
                     Ok(ok__) => {
                         ppprovider.write(core::mem::transmute(ok__));
@@ -4717,9 +7322,9 @@ impl IVdsSubSystem_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn QueryControllers<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn QueryControllers<Identity: windows_core::IUnknownImpl + IVdsSubSystem_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IVdsSubSystem_Impl::QueryControllers(this) {
                     Ok(ok__) => {
                         ppenum.write(core::mem::transmute(ok__));
@@ -4729,9 +7334,9 @@ impl IVdsSubSystem_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn QueryLuns<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn QueryLuns<Identity: windows_core::IUnknownImpl + IVdsSubSystem_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IVdsSubSystem_Impl::QueryLuns(this) {
                     Ok(ok__) => {
                         ppenum.write(core::mem::transmute(ok__));
@@ -4741,9 +7346,9 @@ impl IVdsSubSystem_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn QueryDrives<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn QueryDrives<Identity: windows_core::IUnknownImpl + IVdsSubSystem_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IVdsSubSystem_Impl::QueryDrives(this) {
                     Ok(ok__) => {
                         ppenum.write(core::mem::transmute(ok__));
@@ -4753,9 +7358,9 @@ impl IVdsSubSystem_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetDrive<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, sbusnumber: i16, sslotnumber: i16, ppdrive: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetDrive<Identity: windows_core::IUnknownImpl + IVdsSubSystem_Impl>(this: *mut core::ffi::c_void, sbusnumber: i16, sslotnumber: i16, ppdrive: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IVdsSubSystem_Impl::GetDrive(this, core::mem::transmute_copy(&sbusnumber), core::mem::transmute_copy(&sslotnumber)) {
                     Ok(ok__) => {
                         ppdrive.write(core::mem::transmute(ok__));
@@ -4765,21 +7370,21 @@ impl IVdsSubSystem_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Reenumerate<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Reenumerate<Identity: windows_core::IUnknownImpl + IVdsSubSystem_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IVdsSubSystem_Impl::Reenumerate(this).into()
             }
         }
-        unsafe extern "system" fn SetControllerStatus<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ponlinecontrolleridarray: *const windows_core::GUID, lnumberofonlinecontrollers: i32, pofflinecontrolleridarray: *const windows_core::GUID, lnumberofofflinecontrollers: i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetControllerStatus<Identity: windows_core::IUnknownImpl + IVdsSubSystem_Impl>(this: *mut core::ffi::c_void, ponlinecontrolleridarray: *const windows_core::GUID, lnumberofonlinecontrollers: i32, pofflinecontrolleridarray: *const windows_core::GUID, lnumberofofflinecontrollers: i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IVdsSubSystem_Impl::SetControllerStatus(this, core::mem::transmute_copy(&ponlinecontrolleridarray), core::mem::transmute_copy(&lnumberofonlinecontrollers), core::mem::transmute_copy(&pofflinecontrolleridarray), core::mem::transmute_copy(&lnumberofofflinecontrollers)).into()
             }
         }
-        unsafe extern "system" fn CreateLun<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, r#type: VDS_LUN_TYPE, ullsizeinbytes: u64, pdriveidarray: *const windows_core::GUID, lnumberofdrives: i32, pwszunmaskinglist: windows_core::PCWSTR, phints: *const VDS_HINTS, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateLun<Identity: windows_core::IUnknownImpl + IVdsSubSystem_Impl>(this: *mut core::ffi::c_void, r#type: VDS_LUN_TYPE, ullsizeinbytes: u64, pdriveidarray: *const windows_core::GUID, lnumberofdrives: i32, pwszunmaskinglist: windows_core::PCWSTR, phints: *const VDS_HINTS, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IVdsSubSystem_Impl::CreateLun(this, core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&ullsizeinbytes), core::mem::transmute_copy(&pdriveidarray), core::mem::transmute_copy(&lnumberofdrives), core::mem::transmute(&pwszunmaskinglist), core::mem::transmute_copy(&phints)) {
                     Ok(ok__) => {
                         ppasync.write(core::mem::transmute(ok__));
@@ -4789,21 +7394,21 @@ impl IVdsSubSystem_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ReplaceDrive<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, drivetobereplaced: windows_core::GUID, replacementdrive: windows_core::GUID) -> windows_core::HRESULT {
+        unsafe extern "system" fn ReplaceDrive<Identity: windows_core::IUnknownImpl + IVdsSubSystem_Impl>(this: *mut core::ffi::c_void, drivetobereplaced: windows_core::GUID, replacementdrive: windows_core::GUID) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IVdsSubSystem_Impl::ReplaceDrive(this, core::mem::transmute(&drivetobereplaced), core::mem::transmute(&replacementdrive)).into()
             }
         }
-        unsafe extern "system" fn SetStatus<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, status: VDS_SUB_SYSTEM_STATUS) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetStatus<Identity: windows_core::IUnknownImpl + IVdsSubSystem_Impl>(this: *mut core::ffi::c_void, status: VDS_SUB_SYSTEM_STATUS) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IVdsSubSystem_Impl::SetStatus(this, core::mem::transmute_copy(&status)).into()
             }
         }
-        unsafe extern "system" fn QueryMaxLunCreateSize<Identity: IVdsSubSystem_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, r#type: VDS_LUN_TYPE, pdriveidarray: *const windows_core::GUID, lnumberofdrives: i32, phints: *const VDS_HINTS, pullmaxlunsize: *mut u64) -> windows_core::HRESULT {
+        unsafe extern "system" fn QueryMaxLunCreateSize<Identity: windows_core::IUnknownImpl + IVdsSubSystem_Impl>(this: *mut core::ffi::c_void, r#type: VDS_LUN_TYPE, pdriveidarray: *const windows_core::GUID, lnumberofdrives: i32, phints: *const VDS_HINTS, pullmaxlunsize: *mut u64) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IVdsSubSystem_Impl::QueryMaxLunCreateSize(this, core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pdriveidarray), core::mem::transmute_copy(&lnumberofdrives), core::mem::transmute_copy(&phints)) {
                     Ok(ok__) => {
                         pullmaxlunsize.write(core::mem::transmute(ok__));
@@ -4814,24 +7419,21 @@ impl IVdsSubSystem_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            GetProperties: GetProperties::<Identity, OFFSET>,
-            GetProvider: GetProvider::<Identity, OFFSET>,
-            QueryControllers: QueryControllers::<Identity, OFFSET>,
-            QueryLuns: QueryLuns::<Identity, OFFSET>,
-            QueryDrives: QueryDrives::<Identity, OFFSET>,
-            GetDrive: GetDrive::<Identity, OFFSET>,
-            Reenumerate: Reenumerate::<Identity, OFFSET>,
-            SetControllerStatus: SetControllerStatus::<Identity, OFFSET>,
-            CreateLun: CreateLun::<Identity, OFFSET>,
-            ReplaceDrive: ReplaceDrive::<Identity, OFFSET>,
-            SetStatus: SetStatus::<Identity, OFFSET>,
-            QueryMaxLunCreateSize: QueryMaxLunCreateSize::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IVdsSubSystem as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProperties: GetProperties::<Identity>,
+            GetProvider: GetProvider::<Identity>,
+            QueryControllers: QueryControllers::<Identity>,
+            QueryLuns: QueryLuns::<Identity>,
+            QueryDrives: QueryDrives::<Identity>,
+            GetDrive: GetDrive::<Identity>,
+            Reenumerate: Reenumerate::<Identity>,
+            SetControllerStatus: SetControllerStatus::<Identity>,
+            CreateLun: CreateLun::<Identity>,
+            ReplaceDrive: ReplaceDrive::<Identity>,
+            SetStatus: SetStatus::<Identity>,
+            QueryMaxLunCreateSize: QueryMaxLunCreateSize::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IVdsSubSystem {}
 windows_core::imp::define_interface!(IVdsSubSystem2, IVdsSubSystem2_Vtbl, 0xbe666735_7800_4a77_9d9c_40f85b87e292);
@@ -4933,6 +7535,62 @@ impl IVdsSubSystem2_Vtbl {
         iid == &<IVdsSubSystem2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsSubSystem2_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsSubSystem2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperties2<Identity: windows_core::IUnknownImpl + IVdsSubSystem2_Impl>(this: *mut core::ffi::c_void, psubsystemprop2: *mut VDS_SUB_SYSTEM_PROP2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsSubSystem2_Impl::GetProperties2(this, core::mem::transmute_copy(&psubsystemprop2)).into()
+            }
+        }
+        unsafe extern "system" fn GetDrive2<Identity: windows_core::IUnknownImpl + IVdsSubSystem2_Impl>(this: *mut core::ffi::c_void, sbusnumber: i16, sslotnumber: i16, ulenclosurenumber: u32, ppdrive: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsSubSystem2_Impl::GetDrive2(this, core::mem::transmute_copy(&sbusnumber), core::mem::transmute_copy(&sslotnumber), core::mem::transmute_copy(&ulenclosurenumber)) {
+                    Ok(ok__) => {
+                        ppdrive.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateLun2<Identity: windows_core::IUnknownImpl + IVdsSubSystem2_Impl>(this: *mut core::ffi::c_void, r#type: VDS_LUN_TYPE, ullsizeinbytes: u64, pdriveidarray: *const windows_core::GUID, lnumberofdrives: i32, pwszunmaskinglist: windows_core::PCWSTR, phints2: *const VDS_HINTS2, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsSubSystem2_Impl::CreateLun2(this, core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&ullsizeinbytes), core::mem::transmute_copy(&pdriveidarray), core::mem::transmute_copy(&lnumberofdrives), core::mem::transmute(&pwszunmaskinglist), core::mem::transmute_copy(&phints2)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryMaxLunCreateSize2<Identity: windows_core::IUnknownImpl + IVdsSubSystem2_Impl>(this: *mut core::ffi::c_void, r#type: VDS_LUN_TYPE, pdriveidarray: *const windows_core::GUID, lnumberofdrives: i32, phints2: *const VDS_HINTS2, pullmaxlunsize: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsSubSystem2_Impl::QueryMaxLunCreateSize2(this, core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pdriveidarray), core::mem::transmute_copy(&lnumberofdrives), core::mem::transmute_copy(&phints2)) {
+                    Ok(ok__) => {
+                        pullmaxlunsize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProperties2: GetProperties2::<Identity>,
+            GetDrive2: GetDrive2::<Identity>,
+            CreateLun2: CreateLun2::<Identity>,
+            QueryMaxLunCreateSize2: QueryMaxLunCreateSize2::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsSubSystem2 {}
 windows_core::imp::define_interface!(IVdsSubSystemImportTarget, IVdsSubSystemImportTarget_Vtbl, 0x83bfb87f_43fb_4903_baa6_127f01029eec);
 windows_core::imp::interface_hierarchy!(IVdsSubSystemImportTarget, windows_core::IUnknown);
@@ -4991,6 +7649,36 @@ impl IVdsSubSystemImportTarget_Vtbl {
         iid == &<IVdsSubSystemImportTarget as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsSubSystemImportTarget_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsSubSystemImportTarget_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetImportTarget<Identity: windows_core::IUnknownImpl + IVdsSubSystemImportTarget_Impl>(this: *mut core::ffi::c_void, ppwsziscsiname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsSubSystemImportTarget_Impl::GetImportTarget(this) {
+                    Ok(ok__) => {
+                        ppwsziscsiname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetImportTarget<Identity: windows_core::IUnknownImpl + IVdsSubSystemImportTarget_Impl>(this: *mut core::ffi::c_void, pwsziscsiname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsSubSystemImportTarget_Impl::SetImportTarget(this, core::mem::transmute(&pwsziscsiname)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetImportTarget: GetImportTarget::<Identity>,
+            SetImportTarget: SetImportTarget::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsSubSystemImportTarget {}
 windows_core::imp::define_interface!(IVdsSubSystemInterconnect, IVdsSubSystemInterconnect_Vtbl, 0x9e6fa560_c141_477b_83ba_0b6c38f7febf);
 windows_core::imp::interface_hierarchy!(IVdsSubSystemInterconnect, windows_core::IUnknown);
@@ -5031,6 +7719,29 @@ impl IVdsSubSystemInterconnect_Vtbl {
         iid == &<IVdsSubSystemInterconnect as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsSubSystemInterconnect_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsSubSystemInterconnect_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSupportedInterconnects<Identity: windows_core::IUnknownImpl + IVdsSubSystemInterconnect_Impl>(this: *mut core::ffi::c_void, pulsupportedinterconnectsflag: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsSubSystemInterconnect_Impl::GetSupportedInterconnects(this) {
+                    Ok(ok__) => {
+                        pulsupportedinterconnectsflag.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSupportedInterconnects: GetSupportedInterconnects::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsSubSystemInterconnect {}
 windows_core::imp::define_interface!(IVdsSubSystemIscsi, IVdsSubSystemIscsi_Vtbl, 0x0027346f_40d0_4b45_8cec_5906dc0380c8);
 windows_core::imp::interface_hierarchy!(IVdsSubSystemIscsi, windows_core::IUnknown);
@@ -5132,6 +7843,62 @@ impl IVdsSubSystemIscsi_Vtbl {
         iid == &<IVdsSubSystemIscsi as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsSubSystemIscsi_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsSubSystemIscsi_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryTargets<Identity: windows_core::IUnknownImpl + IVdsSubSystemIscsi_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsSubSystemIscsi_Impl::QueryTargets(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryPortals<Identity: windows_core::IUnknownImpl + IVdsSubSystemIscsi_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsSubSystemIscsi_Impl::QueryPortals(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateTarget<Identity: windows_core::IUnknownImpl + IVdsSubSystemIscsi_Impl>(this: *mut core::ffi::c_void, pwsziscsiname: windows_core::PCWSTR, pwszfriendlyname: windows_core::PCWSTR, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsSubSystemIscsi_Impl::CreateTarget(this, core::mem::transmute(&pwsziscsiname), core::mem::transmute(&pwszfriendlyname)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetIpsecGroupPresharedKey<Identity: windows_core::IUnknownImpl + IVdsSubSystemIscsi_Impl>(this: *mut core::ffi::c_void, pipseckey: *const VDS_ISCSI_IPSEC_KEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsSubSystemIscsi_Impl::SetIpsecGroupPresharedKey(this, core::mem::transmute_copy(&pipseckey)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryTargets: QueryTargets::<Identity>,
+            QueryPortals: QueryPortals::<Identity>,
+            CreateTarget: CreateTarget::<Identity>,
+            SetIpsecGroupPresharedKey: SetIpsecGroupPresharedKey::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsSubSystemIscsi {}
 windows_core::imp::define_interface!(IVdsSubSystemNaming, IVdsSubSystemNaming_Vtbl, 0x0d70faa3_9cd4_4900_aa20_6981b6aafc75);
 windows_core::imp::interface_hierarchy!(IVdsSubSystemNaming, windows_core::IUnknown);
@@ -5166,6 +7933,20 @@ impl IVdsSubSystemNaming_Vtbl {
         iid == &<IVdsSubSystemNaming as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsSubSystemNaming_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsSubSystemNaming_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetFriendlyName<Identity: windows_core::IUnknownImpl + IVdsSubSystemNaming_Impl>(this: *mut core::ffi::c_void, pwszfriendlyname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsSubSystemNaming_Impl::SetFriendlyName(this, core::mem::transmute(&pwszfriendlyname)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetFriendlyName: SetFriendlyName::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IVdsSubSystemNaming {}
 windows_core::imp::define_interface!(IVdsSwProvider, IVdsSwProvider_Vtbl, 0x9aa58360_ce33_4f92_b658_ed24b14425b8);
 windows_core::imp::interface_hierarchy!(IVdsSwProvider, windows_core::IUnknown);
@@ -5230,6 +8011,42 @@ impl IVdsSwProvider_Vtbl {
         iid == &<IVdsSwProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsSwProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsSwProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryPacks<Identity: windows_core::IUnknownImpl + IVdsSwProvider_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsSwProvider_Impl::QueryPacks(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreatePack<Identity: windows_core::IUnknownImpl + IVdsSwProvider_Impl>(this: *mut core::ffi::c_void, pppack: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsSwProvider_Impl::CreatePack(this) {
+                    Ok(ok__) => {
+                        pppack.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryPacks: QueryPacks::<Identity>,
+            CreatePack: CreatePack::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsSwProvider {}
 windows_core::imp::define_interface!(IVdsVDisk, IVdsVDisk_Vtbl, 0x1e062b84_e5e6_4b4b_8a25_67b81e8f13e8);
 windows_core::imp::interface_hierarchy!(IVdsVDisk, windows_core::IUnknown);
@@ -5338,6 +8155,63 @@ impl IVdsVDisk_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Storage_Vhd")]
+impl<Identity: windows_core::IUnknownImpl + IVdsVDisk_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsVDisk_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Open<Identity: windows_core::IUnknownImpl + IVdsVDisk_Impl>(this: *mut core::ffi::c_void, accessmask: super::Vhd::VIRTUAL_DISK_ACCESS_MASK, flags: super::Vhd::OPEN_VIRTUAL_DISK_FLAG, readwritedepth: u32, ppopenvdisk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsVDisk_Impl::Open(this, core::mem::transmute_copy(&accessmask), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&readwritedepth)) {
+                    Ok(ok__) => {
+                        ppopenvdisk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + IVdsVDisk_Impl>(this: *mut core::ffi::c_void, pdiskproperties: *mut VDS_VDISK_PROPERTIES) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsVDisk_Impl::GetProperties(this, core::mem::transmute_copy(&pdiskproperties)).into()
+            }
+        }
+        unsafe extern "system" fn GetHostVolume<Identity: windows_core::IUnknownImpl + IVdsVDisk_Impl>(this: *mut core::ffi::c_void, ppvolume: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsVDisk_Impl::GetHostVolume(this) {
+                    Ok(ok__) => {
+                        ppvolume.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDeviceName<Identity: windows_core::IUnknownImpl + IVdsVDisk_Impl>(this: *mut core::ffi::c_void, ppdevicename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsVDisk_Impl::GetDeviceName(this) {
+                    Ok(ok__) => {
+                        ppdevicename.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Open: Open::<Identity>,
+            GetProperties: GetProperties::<Identity>,
+            GetHostVolume: GetHostVolume::<Identity>,
+            GetDeviceName: GetDeviceName::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Storage_Vhd")]
 impl windows_core::RuntimeName for IVdsVDisk {}
 windows_core::imp::define_interface!(IVdsVdProvider, IVdsVdProvider_Vtbl, 0xb481498c_8354_45f9_84a0_0bdd2832a91f);
 windows_core::imp::interface_hierarchy!(IVdsVdProvider, windows_core::IUnknown);
@@ -5465,10 +8339,74 @@ impl IVdsVdProvider_Vtbl {
             GetDiskFromVDisk: GetDiskFromVDisk::<Identity, OFFSET>,
             GetVDiskFromDisk: GetVDiskFromDisk::<Identity, OFFSET>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IVdsVdProvider as windows_core::Interface>::IID
-    }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsVdProvider as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_Storage_Vhd")]
+impl<Identity: windows_core::IUnknownImpl + IVdsVdProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsVdProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryVDisks<Identity: windows_core::IUnknownImpl + IVdsVdProvider_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsVdProvider_Impl::QueryVDisks(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateVDisk<Identity: windows_core::IUnknownImpl + IVdsVdProvider_Impl>(this: *mut core::ffi::c_void, virtualdevicetype: *const super::Vhd::VIRTUAL_STORAGE_TYPE, ppath: windows_core::PCWSTR, pstringsecuritydescriptor: windows_core::PCWSTR, flags: super::Vhd::CREATE_VIRTUAL_DISK_FLAG, providerspecificflags: u32, reserved: u32, pcreatediskparameters: *const VDS_CREATE_VDISK_PARAMETERS, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsVdProvider_Impl::CreateVDisk(this, core::mem::transmute_copy(&virtualdevicetype), core::mem::transmute(&ppath), core::mem::transmute(&pstringsecuritydescriptor), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&providerspecificflags), core::mem::transmute_copy(&reserved), core::mem::transmute_copy(&pcreatediskparameters), core::mem::transmute_copy(&ppasync)).into()
+            }
+        }
+        unsafe extern "system" fn AddVDisk<Identity: windows_core::IUnknownImpl + IVdsVdProvider_Impl>(this: *mut core::ffi::c_void, virtualdevicetype: *const super::Vhd::VIRTUAL_STORAGE_TYPE, ppath: windows_core::PCWSTR, ppvdisk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsVdProvider_Impl::AddVDisk(this, core::mem::transmute_copy(&virtualdevicetype), core::mem::transmute(&ppath), core::mem::transmute_copy(&ppvdisk)).into()
+            }
+        }
+        unsafe extern "system" fn GetDiskFromVDisk<Identity: windows_core::IUnknownImpl + IVdsVdProvider_Impl>(this: *mut core::ffi::c_void, pvdisk: *mut core::ffi::c_void, ppdisk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsVdProvider_Impl::GetDiskFromVDisk(this, core::mem::transmute_copy(&pvdisk)) {
+                    Ok(ok__) => {
+                        ppdisk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetVDiskFromDisk<Identity: windows_core::IUnknownImpl + IVdsVdProvider_Impl>(this: *mut core::ffi::c_void, pdisk: *mut core::ffi::c_void, ppvdisk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsVdProvider_Impl::GetVDiskFromDisk(this, core::mem::transmute_copy(&pdisk)) {
+                    Ok(ok__) => {
+                        ppvdisk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryVDisks: QueryVDisks::<Identity>,
+            CreateVDisk: CreateVDisk::<Identity>,
+            AddVDisk: AddVDisk::<Identity>,
+            GetDiskFromVDisk: GetDiskFromVDisk::<Identity>,
+            GetVDiskFromDisk: GetVDiskFromDisk::<Identity>,
+        }
+    };
 }
 #[cfg(feature = "Win32_Storage_Vhd")]
 impl windows_core::RuntimeName for IVdsVdProvider {}
@@ -5688,6 +8626,135 @@ impl IVdsVolume_Vtbl {
         iid == &<IVdsVolume as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsVolume_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsVolume_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + IVdsVolume_Impl>(this: *mut core::ffi::c_void, pvolumeproperties: *mut VDS_VOLUME_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsVolume_Impl::GetProperties(this, core::mem::transmute_copy(&pvolumeproperties)).into()
+            }
+        }
+        unsafe extern "system" fn GetPack<Identity: windows_core::IUnknownImpl + IVdsVolume_Impl>(this: *mut core::ffi::c_void, pppack: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsVolume_Impl::GetPack(this) {
+                    Ok(ok__) => {
+                        pppack.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryPlexes<Identity: windows_core::IUnknownImpl + IVdsVolume_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsVolume_Impl::QueryPlexes(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Extend<Identity: windows_core::IUnknownImpl + IVdsVolume_Impl>(this: *mut core::ffi::c_void, pinputdiskarray: *const VDS_INPUT_DISK, lnumberofdisks: i32, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsVolume_Impl::Extend(this, core::mem::transmute_copy(&pinputdiskarray), core::mem::transmute_copy(&lnumberofdisks)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Shrink<Identity: windows_core::IUnknownImpl + IVdsVolume_Impl>(this: *mut core::ffi::c_void, ullnumberofbytestoremove: u64, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsVolume_Impl::Shrink(this, core::mem::transmute_copy(&ullnumberofbytestoremove)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddPlex<Identity: windows_core::IUnknownImpl + IVdsVolume_Impl>(this: *mut core::ffi::c_void, volumeid: windows_core::GUID, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsVolume_Impl::AddPlex(this, core::mem::transmute(&volumeid)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn BreakPlex<Identity: windows_core::IUnknownImpl + IVdsVolume_Impl>(this: *mut core::ffi::c_void, plexid: windows_core::GUID, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsVolume_Impl::BreakPlex(this, core::mem::transmute(&plexid)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemovePlex<Identity: windows_core::IUnknownImpl + IVdsVolume_Impl>(this: *mut core::ffi::c_void, plexid: windows_core::GUID, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsVolume_Impl::RemovePlex(this, core::mem::transmute(&plexid)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IVdsVolume_Impl>(this: *mut core::ffi::c_void, bforce: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsVolume_Impl::Delete(this, core::mem::transmute_copy(&bforce)).into()
+            }
+        }
+        unsafe extern "system" fn SetFlags<Identity: windows_core::IUnknownImpl + IVdsVolume_Impl>(this: *mut core::ffi::c_void, ulflags: u32, brevertonclose: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsVolume_Impl::SetFlags(this, core::mem::transmute_copy(&ulflags), core::mem::transmute_copy(&brevertonclose)).into()
+            }
+        }
+        unsafe extern "system" fn ClearFlags<Identity: windows_core::IUnknownImpl + IVdsVolume_Impl>(this: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsVolume_Impl::ClearFlags(this, core::mem::transmute_copy(&ulflags)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProperties: GetProperties::<Identity>,
+            GetPack: GetPack::<Identity>,
+            QueryPlexes: QueryPlexes::<Identity>,
+            Extend: Extend::<Identity>,
+            Shrink: Shrink::<Identity>,
+            AddPlex: AddPlex::<Identity>,
+            BreakPlex: BreakPlex::<Identity>,
+            RemovePlex: RemovePlex::<Identity>,
+            Delete: Delete::<Identity>,
+            SetFlags: SetFlags::<Identity>,
+            ClearFlags: ClearFlags::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsVolume {}
 windows_core::imp::define_interface!(IVdsVolume2, IVdsVolume2_Vtbl, 0x72ae6713_dcbb_4a03_b36b_371f6ac6b53d);
 windows_core::imp::interface_hierarchy!(IVdsVolume2, windows_core::IUnknown);
@@ -5719,6 +8786,20 @@ impl IVdsVolume2_Vtbl {
         iid == &<IVdsVolume2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsVolume2_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsVolume2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperties2<Identity: windows_core::IUnknownImpl + IVdsVolume2_Impl>(this: *mut core::ffi::c_void, pvolumeproperties: *mut VDS_VOLUME_PROP2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsVolume2_Impl::GetProperties2(this, core::mem::transmute_copy(&pvolumeproperties)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetProperties2: GetProperties2::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IVdsVolume2 {}
 windows_core::imp::define_interface!(IVdsVolumeMF, IVdsVolumeMF_Vtbl, 0xee2d5ded_6236_4169_931d_b9778ce03dc6);
 windows_core::imp::interface_hierarchy!(IVdsVolumeMF, windows_core::IUnknown);
@@ -5879,6 +8960,92 @@ impl IVdsVolumeMF_Vtbl {
         iid == &<IVdsVolumeMF as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsVolumeMF_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsVolumeMF_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFileSystemProperties<Identity: windows_core::IUnknownImpl + IVdsVolumeMF_Impl>(this: *mut core::ffi::c_void, pfilesystemprop: *mut VDS_FILE_SYSTEM_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsVolumeMF_Impl::GetFileSystemProperties(this, core::mem::transmute_copy(&pfilesystemprop)).into()
+            }
+        }
+        unsafe extern "system" fn Format<Identity: windows_core::IUnknownImpl + IVdsVolumeMF_Impl>(this: *mut core::ffi::c_void, r#type: VDS_FILE_SYSTEM_TYPE, pwszlabel: windows_core::PCWSTR, dwunitallocationsize: u32, bforce: windows_core::BOOL, bquickformat: windows_core::BOOL, benablecompression: windows_core::BOOL, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsVolumeMF_Impl::Format(this, core::mem::transmute_copy(&r#type), core::mem::transmute(&pwszlabel), core::mem::transmute_copy(&dwunitallocationsize), core::mem::transmute_copy(&bforce), core::mem::transmute_copy(&bquickformat), core::mem::transmute_copy(&benablecompression)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddAccessPath<Identity: windows_core::IUnknownImpl + IVdsVolumeMF_Impl>(this: *mut core::ffi::c_void, pwszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsVolumeMF_Impl::AddAccessPath(this, core::mem::transmute(&pwszpath)).into()
+            }
+        }
+        unsafe extern "system" fn QueryAccessPaths<Identity: windows_core::IUnknownImpl + IVdsVolumeMF_Impl>(this: *mut core::ffi::c_void, pwszpatharray: *mut *mut windows_core::PWSTR, plnumberofaccesspaths: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsVolumeMF_Impl::QueryAccessPaths(this, core::mem::transmute_copy(&pwszpatharray), core::mem::transmute_copy(&plnumberofaccesspaths)).into()
+            }
+        }
+        unsafe extern "system" fn QueryReparsePoints<Identity: windows_core::IUnknownImpl + IVdsVolumeMF_Impl>(this: *mut core::ffi::c_void, ppreparsepointprops: *mut *mut VDS_REPARSE_POINT_PROP, plnumberofreparsepointprops: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsVolumeMF_Impl::QueryReparsePoints(this, core::mem::transmute_copy(&ppreparsepointprops), core::mem::transmute_copy(&plnumberofreparsepointprops)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteAccessPath<Identity: windows_core::IUnknownImpl + IVdsVolumeMF_Impl>(this: *mut core::ffi::c_void, pwszpath: windows_core::PCWSTR, bforce: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsVolumeMF_Impl::DeleteAccessPath(this, core::mem::transmute(&pwszpath), core::mem::transmute_copy(&bforce)).into()
+            }
+        }
+        unsafe extern "system" fn Mount<Identity: windows_core::IUnknownImpl + IVdsVolumeMF_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsVolumeMF_Impl::Mount(this).into()
+            }
+        }
+        unsafe extern "system" fn Dismount<Identity: windows_core::IUnknownImpl + IVdsVolumeMF_Impl>(this: *mut core::ffi::c_void, bforce: windows_core::BOOL, bpermanent: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsVolumeMF_Impl::Dismount(this, core::mem::transmute_copy(&bforce), core::mem::transmute_copy(&bpermanent)).into()
+            }
+        }
+        unsafe extern "system" fn SetFileSystemFlags<Identity: windows_core::IUnknownImpl + IVdsVolumeMF_Impl>(this: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsVolumeMF_Impl::SetFileSystemFlags(this, core::mem::transmute_copy(&ulflags)).into()
+            }
+        }
+        unsafe extern "system" fn ClearFileSystemFlags<Identity: windows_core::IUnknownImpl + IVdsVolumeMF_Impl>(this: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsVolumeMF_Impl::ClearFileSystemFlags(this, core::mem::transmute_copy(&ulflags)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFileSystemProperties: GetFileSystemProperties::<Identity>,
+            Format: Format::<Identity>,
+            AddAccessPath: AddAccessPath::<Identity>,
+            QueryAccessPaths: QueryAccessPaths::<Identity>,
+            QueryReparsePoints: QueryReparsePoints::<Identity>,
+            DeleteAccessPath: DeleteAccessPath::<Identity>,
+            Mount: Mount::<Identity>,
+            Dismount: Dismount::<Identity>,
+            SetFileSystemFlags: SetFileSystemFlags::<Identity>,
+            ClearFileSystemFlags: ClearFileSystemFlags::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsVolumeMF {}
 windows_core::imp::define_interface!(IVdsVolumeMF2, IVdsVolumeMF2_Vtbl, 0x4dbcee9a_6343_4651_b85f_5e75d74d983c);
 windows_core::imp::interface_hierarchy!(IVdsVolumeMF2, windows_core::IUnknown);
@@ -5959,6 +9126,49 @@ impl IVdsVolumeMF2_Vtbl {
         iid == &<IVdsVolumeMF2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsVolumeMF2_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsVolumeMF2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFileSystemTypeName<Identity: windows_core::IUnknownImpl + IVdsVolumeMF2_Impl>(this: *mut core::ffi::c_void, ppwszfilesystemtypename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsVolumeMF2_Impl::GetFileSystemTypeName(this) {
+                    Ok(ok__) => {
+                        ppwszfilesystemtypename.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryFileSystemFormatSupport<Identity: windows_core::IUnknownImpl + IVdsVolumeMF2_Impl>(this: *mut core::ffi::c_void, ppfilesystemsupportprops: *mut *mut VDS_FILE_SYSTEM_FORMAT_SUPPORT_PROP, plnumberoffilesystems: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsVolumeMF2_Impl::QueryFileSystemFormatSupport(this, core::mem::transmute_copy(&ppfilesystemsupportprops), core::mem::transmute_copy(&plnumberoffilesystems)).into()
+            }
+        }
+        unsafe extern "system" fn FormatEx<Identity: windows_core::IUnknownImpl + IVdsVolumeMF2_Impl>(this: *mut core::ffi::c_void, pwszfilesystemtypename: windows_core::PCWSTR, usfilesystemrevision: u16, uldesiredunitallocationsize: u32, pwszlabel: windows_core::PCWSTR, bforce: windows_core::BOOL, bquickformat: windows_core::BOOL, benablecompression: windows_core::BOOL, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsVolumeMF2_Impl::FormatEx(this, core::mem::transmute(&pwszfilesystemtypename), core::mem::transmute_copy(&usfilesystemrevision), core::mem::transmute_copy(&uldesiredunitallocationsize), core::mem::transmute(&pwszlabel), core::mem::transmute_copy(&bforce), core::mem::transmute_copy(&bquickformat), core::mem::transmute_copy(&benablecompression)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFileSystemTypeName: GetFileSystemTypeName::<Identity>,
+            QueryFileSystemFormatSupport: QueryFileSystemFormatSupport::<Identity>,
+            FormatEx: FormatEx::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsVolumeMF2 {}
 windows_core::imp::define_interface!(IVdsVolumeMF3, IVdsVolumeMF3_Vtbl, 0x6788faf9_214e_4b85_ba59_266953616e09);
 windows_core::imp::interface_hierarchy!(IVdsVolumeMF3, windows_core::IUnknown);
@@ -6030,6 +9240,43 @@ impl IVdsVolumeMF3_Vtbl {
         iid == &<IVdsVolumeMF3 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsVolumeMF3_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsVolumeMF3_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryVolumeGuidPathnames<Identity: windows_core::IUnknownImpl + IVdsVolumeMF3_Impl>(this: *mut core::ffi::c_void, pwszpatharray: *mut *mut windows_core::PWSTR, pulnumberofpaths: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsVolumeMF3_Impl::QueryVolumeGuidPathnames(this, core::mem::transmute_copy(&pwszpatharray), core::mem::transmute_copy(&pulnumberofpaths)).into()
+            }
+        }
+        unsafe extern "system" fn FormatEx2<Identity: windows_core::IUnknownImpl + IVdsVolumeMF3_Impl>(this: *mut core::ffi::c_void, pwszfilesystemtypename: windows_core::PCWSTR, usfilesystemrevision: u16, uldesiredunitallocationsize: u32, pwszlabel: windows_core::PCWSTR, options: u32, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsVolumeMF3_Impl::FormatEx2(this, core::mem::transmute(&pwszfilesystemtypename), core::mem::transmute_copy(&usfilesystemrevision), core::mem::transmute_copy(&uldesiredunitallocationsize), core::mem::transmute(&pwszlabel), core::mem::transmute_copy(&options)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OfflineVolume<Identity: windows_core::IUnknownImpl + IVdsVolumeMF3_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsVolumeMF3_Impl::OfflineVolume(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryVolumeGuidPathnames: QueryVolumeGuidPathnames::<Identity>,
+            FormatEx2: FormatEx2::<Identity>,
+            OfflineVolume: OfflineVolume::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsVolumeMF3 {}
 windows_core::imp::define_interface!(IVdsVolumeOnline, IVdsVolumeOnline_Vtbl, 0x1be2275a_b315_4f70_9e44_879b3a2a53f2);
 windows_core::imp::interface_hierarchy!(IVdsVolumeOnline, windows_core::IUnknown);
@@ -6061,6 +9308,20 @@ impl IVdsVolumeOnline_Vtbl {
         iid == &<IVdsVolumeOnline as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsVolumeOnline_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsVolumeOnline_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Online<Identity: windows_core::IUnknownImpl + IVdsVolumeOnline_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsVolumeOnline_Impl::Online(this).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Online: Online::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IVdsVolumeOnline {}
 windows_core::imp::define_interface!(IVdsVolumePlex, IVdsVolumePlex_Vtbl, 0x4daa0135_e1d1_40f1_aaa5_3cc1e53221c3);
 windows_core::imp::interface_hierarchy!(IVdsVolumePlex, windows_core::IUnknown);
@@ -6149,6 +9410,56 @@ impl IVdsVolumePlex_Vtbl {
         iid == &<IVdsVolumePlex as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsVolumePlex_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsVolumePlex_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperties<Identity: windows_core::IUnknownImpl + IVdsVolumePlex_Impl>(this: *mut core::ffi::c_void, pplexproperties: *mut VDS_VOLUME_PLEX_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsVolumePlex_Impl::GetProperties(this, core::mem::transmute_copy(&pplexproperties)).into()
+            }
+        }
+        unsafe extern "system" fn GetVolume<Identity: windows_core::IUnknownImpl + IVdsVolumePlex_Impl>(this: *mut core::ffi::c_void, ppvolume: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsVolumePlex_Impl::GetVolume(this) {
+                    Ok(ok__) => {
+                        ppvolume.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryExtents<Identity: windows_core::IUnknownImpl + IVdsVolumePlex_Impl>(this: *mut core::ffi::c_void, ppextentarray: *mut *mut VDS_DISK_EXTENT, plnumberofextents: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVdsVolumePlex_Impl::QueryExtents(this, core::mem::transmute_copy(&ppextentarray), core::mem::transmute_copy(&plnumberofextents)).into()
+            }
+        }
+        unsafe extern "system" fn Repair<Identity: windows_core::IUnknownImpl + IVdsVolumePlex_Impl>(this: *mut core::ffi::c_void, pinputdiskarray: *const VDS_INPUT_DISK, lnumberofdisks: i32, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsVolumePlex_Impl::Repair(this, core::mem::transmute_copy(&pinputdiskarray), core::mem::transmute_copy(&lnumberofdisks)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProperties: GetProperties::<Identity>,
+            GetVolume: GetVolume::<Identity>,
+            QueryExtents: QueryExtents::<Identity>,
+            Repair: Repair::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsVolumePlex {}
 windows_core::imp::define_interface!(IVdsVolumeShrink, IVdsVolumeShrink_Vtbl, 0xd68168c9_82a2_4f85_b6e9_74707c49a58f);
 windows_core::imp::interface_hierarchy!(IVdsVolumeShrink, windows_core::IUnknown);
@@ -6213,6 +9524,42 @@ impl IVdsVolumeShrink_Vtbl {
         iid == &<IVdsVolumeShrink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVdsVolumeShrink_Impl> windows_core::imp::TearOffVtable<Identity> for IVdsVolumeShrink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryMaxReclaimableBytes<Identity: windows_core::IUnknownImpl + IVdsVolumeShrink_Impl>(this: *mut core::ffi::c_void, pullmaxnumberofreclaimablebytes: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsVolumeShrink_Impl::QueryMaxReclaimableBytes(this) {
+                    Ok(ok__) => {
+                        pullmaxnumberofreclaimablebytes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Shrink<Identity: windows_core::IUnknownImpl + IVdsVolumeShrink_Impl>(this: *mut core::ffi::c_void, ulldesirednumberofreclaimablebytes: u64, ullminnumberofreclaimablebytes: u64, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVdsVolumeShrink_Impl::Shrink(this, core::mem::transmute_copy(&ulldesirednumberofreclaimablebytes), core::mem::transmute_copy(&ullminnumberofreclaimablebytes)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryMaxReclaimableBytes: QueryMaxReclaimableBytes::<Identity>,
+            Shrink: Shrink::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVdsVolumeShrink {}
 pub const MAX_FS_ALLOWED_CLUSTER_SIZES_SIZE: u32 = 32u32;
 pub const MAX_FS_FORMAT_SUPPORT_NAME_SIZE: u32 = 32u32;
@@ -84,6 +84,50 @@ impl IVssAdmin_Vtbl {
         iid == &<IVssAdmin as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVssAdmin_Impl> windows_core::imp::TearOffVtable<Identity> for IVssAdmin_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterProvider<Identity: windows_core::IUnknownImpl + IVssAdmin_Impl>(this: *mut core::ffi::c_void, pproviderid: windows_core::GUID, classid: windows_core::GUID, pwszprovidername: *const u16, eprovidertype: VSS_PROVIDER_TYPE, pwszproviderversion: *const u16, providerversionid: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssAdmin_Impl::RegisterProvider(this, core::mem::transmute(&pproviderid), core::mem::transmute(&classid), core::mem::transmute_copy(&pwszprovidername), core::mem::transmute_copy(&eprovidertype), core::mem::transmute_copy(&pwszproviderversion), core::mem::transmute(&providerversionid)).into()
+            }
+        }
+        unsafe extern "system" fn UnregisterProvider<Identity: windows_core::IUnknownImpl + IVssAdmin_Impl>(this: *mut core::ffi::c_void, providerid: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssAdmin_Impl::UnregisterProvider(this, core::mem::transmute(&providerid)).into()
+            }
+        }
+        unsafe extern "system" fn QueryProviders<Identity: windows_core::IUnknownImpl + IVssAdmin_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssAdmin_Impl::QueryProviders(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AbortAllSnapshotsInProgress<Identity: windows_core::IUnknownImpl + IVssAdmin_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssAdmin_Impl::AbortAllSnapshotsInProgress(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterProvider: RegisterProvider::<Identity>,
+            UnregisterProvider: UnregisterProvider::<Identity>,
+            QueryProviders: QueryProviders::<Identity>,
+            AbortAllSnapshotsInProgress: AbortAllSnapshotsInProgress::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVssAdmin {}
 windows_core::imp::define_interface!(IVssAdminEx, IVssAdminEx_Vtbl, 0x7858a9f8_b1fa_41a6_964f_b9b36b8cd8d8);
 impl core::ops::Deref for IVssAdminEx {
@@ -166,6 +210,49 @@ impl IVssAdminEx_Vtbl {
         iid == &<IVssAdminEx as windows_core::Interface>::IID || iid == &<IVssAdmin as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVssAdminEx_Impl> windows_core::imp::TearOffVtable<Identity> for IVssAdminEx_Vtbl
+where
+    IVssAdmin_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProviderCapability<Identity: windows_core::IUnknownImpl + IVssAdminEx_Impl>(this: *mut core::ffi::c_void, pproviderid: windows_core::GUID, plloriginalcapabilitymask: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssAdminEx_Impl::GetProviderCapability(this, core::mem::transmute(&pproviderid)) {
+                    Ok(ok__) => {
+                        plloriginalcapabilitymask.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetProviderContext<Identity: windows_core::IUnknownImpl + IVssAdminEx_Impl>(this: *mut core::ffi::c_void, providerid: windows_core::GUID, plcontext: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssAdminEx_Impl::GetProviderContext(this, core::mem::transmute(&providerid)) {
+                    Ok(ok__) => {
+                        plcontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProviderContext<Identity: windows_core::IUnknownImpl + IVssAdminEx_Impl>(this: *mut core::ffi::c_void, providerid: windows_core::GUID, lcontext: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssAdminEx_Impl::SetProviderContext(this, core::mem::transmute(&providerid), core::mem::transmute_copy(&lcontext)).into()
+            }
+        }
+        Self {
+            base__: <IVssAdmin_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProviderCapability: GetProviderCapability::<Identity>,
+            GetProviderContext: GetProviderContext::<Identity>,
+            SetProviderContext: SetProviderContext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVssAdminEx {}
 windows_core::imp::define_interface!(IVssAsync, IVssAsync_Vtbl, 0x507c37b4_cf5b_4e95_b0af_14eb9767467e);
 windows_core::imp::interface_hierarchy!(IVssAsync, windows_core::IUnknown);
@@ -224,6 +311,37 @@ impl IVssAsync_Vtbl {
         iid == &<IVssAsync as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVssAsync_Impl> windows_core::imp::TearOffVtable<Identity> for IVssAsync_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Cancel<Identity: windows_core::IUnknownImpl + IVssAsync_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssAsync_Impl::Cancel(this).into()
+            }
+        }
+        unsafe extern "system" fn Wait<Identity: windows_core::IUnknownImpl + IVssAsync_Impl>(this: *mut core::ffi::c_void, dwmilliseconds: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssAsync_Impl::Wait(this, core::mem::transmute_copy(&dwmilliseconds)).into()
+            }
+        }
+        unsafe extern "system" fn QueryStatus<Identity: windows_core::IUnknownImpl + IVssAsync_Impl>(this: *mut core::ffi::c_void, phrresult: *mut windows_core::HRESULT, preserved: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssAsync_Impl::QueryStatus(this, core::mem::transmute_copy(&phrresult), core::mem::transmute_copy(&preserved)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Cancel: Cancel::<Identity>,
+            Wait: Wait::<Identity>,
+            QueryStatus: QueryStatus::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVssAsync {}
 windows_core::imp::define_interface!(IVssComponent, IVssComponent_Vtbl, 0xd2c72c96_c121_4518_b627_e5a93d010ead);
 windows_core::imp::interface_hierarchy!(IVssComponent, windows_core::IUnknown);
@@ -757,6 +875,294 @@ impl IVssComponent_Vtbl {
         iid == &<IVssComponent as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVssComponent_Impl> windows_core::imp::TearOffVtable<Identity> for IVssComponent_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetLogicalPath<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, pbstrpath: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetLogicalPath(this, core::mem::transmute_copy(&pbstrpath)).into()
+            }
+        }
+        unsafe extern "system" fn GetComponentType<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, pct: *mut VSS_COMPONENT_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetComponentType(this, core::mem::transmute_copy(&pct)).into()
+            }
+        }
+        unsafe extern "system" fn GetComponentName<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, pbstrname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetComponentName(this, core::mem::transmute_copy(&pbstrname)).into()
+            }
+        }
+        unsafe extern "system" fn GetBackupSucceeded<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, pbsucceeded: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetBackupSucceeded(this, core::mem::transmute_copy(&pbsucceeded)).into()
+            }
+        }
+        unsafe extern "system" fn GetAlternateLocationMappingCount<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, pcmappings: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetAlternateLocationMappingCount(this, core::mem::transmute_copy(&pcmappings)).into()
+            }
+        }
+        unsafe extern "system" fn GetAlternateLocationMapping<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, imapping: u32, ppfiledesc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssComponent_Impl::GetAlternateLocationMapping(this, core::mem::transmute_copy(&imapping)) {
+                    Ok(ok__) => {
+                        ppfiledesc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBackupMetadata<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, wszdata: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::SetBackupMetadata(this, core::mem::transmute(&wszdata)).into()
+            }
+        }
+        unsafe extern "system" fn GetBackupMetadata<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, pbstrdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetBackupMetadata(this, core::mem::transmute_copy(&pbstrdata)).into()
+            }
+        }
+        unsafe extern "system" fn AddPartialFile<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, wszpath: windows_core::PCWSTR, wszfilename: windows_core::PCWSTR, wszranges: windows_core::PCWSTR, wszmetadata: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::AddPartialFile(this, core::mem::transmute(&wszpath), core::mem::transmute(&wszfilename), core::mem::transmute(&wszranges), core::mem::transmute(&wszmetadata)).into()
+            }
+        }
+        unsafe extern "system" fn GetPartialFileCount<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, pcpartialfiles: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetPartialFileCount(this, core::mem::transmute_copy(&pcpartialfiles)).into()
+            }
+        }
+        unsafe extern "system" fn GetPartialFile<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, ipartialfile: u32, pbstrpath: *mut *mut core::ffi::c_void, pbstrfilename: *mut *mut core::ffi::c_void, pbstrrange: *mut *mut core::ffi::c_void, pbstrmetadata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetPartialFile(this, core::mem::transmute_copy(&ipartialfile), core::mem::transmute_copy(&pbstrpath), core::mem::transmute_copy(&pbstrfilename), core::mem::transmute_copy(&pbstrrange), core::mem::transmute_copy(&pbstrmetadata)).into()
+            }
+        }
+        unsafe extern "system" fn IsSelectedForRestore<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, pbselectedforrestore: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::IsSelectedForRestore(this, core::mem::transmute_copy(&pbselectedforrestore)).into()
+            }
+        }
+        unsafe extern "system" fn GetAdditionalRestores<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, pbadditionalrestores: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetAdditionalRestores(this, core::mem::transmute_copy(&pbadditionalrestores)).into()
+            }
+        }
+        unsafe extern "system" fn GetNewTargetCount<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, pcnewtarget: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetNewTargetCount(this, core::mem::transmute_copy(&pcnewtarget)).into()
+            }
+        }
+        unsafe extern "system" fn GetNewTarget<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, inewtarget: u32, ppfiledesc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssComponent_Impl::GetNewTarget(this, core::mem::transmute_copy(&inewtarget)) {
+                    Ok(ok__) => {
+                        ppfiledesc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddDirectedTarget<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, wszsourcepath: windows_core::PCWSTR, wszsourcefilename: windows_core::PCWSTR, wszsourcerangelist: windows_core::PCWSTR, wszdestinationpath: windows_core::PCWSTR, wszdestinationfilename: windows_core::PCWSTR, wszdestinationrangelist: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::AddDirectedTarget(this, core::mem::transmute(&wszsourcepath), core::mem::transmute(&wszsourcefilename), core::mem::transmute(&wszsourcerangelist), core::mem::transmute(&wszdestinationpath), core::mem::transmute(&wszdestinationfilename), core::mem::transmute(&wszdestinationrangelist)).into()
+            }
+        }
+        unsafe extern "system" fn GetDirectedTargetCount<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, pcdirectedtarget: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetDirectedTargetCount(this, core::mem::transmute_copy(&pcdirectedtarget)).into()
+            }
+        }
+        unsafe extern "system" fn GetDirectedTarget<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, idirectedtarget: u32, pbstrsourcepath: *mut *mut core::ffi::c_void, pbstrsourcefilename: *mut *mut core::ffi::c_void, pbstrsourcerangelist: *mut *mut core::ffi::c_void, pbstrdestinationpath: *mut *mut core::ffi::c_void, pbstrdestinationfilename: *mut *mut core::ffi::c_void, pbstrdestinationrangelist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetDirectedTarget(this, core::mem::transmute_copy(&idirectedtarget), core::mem::transmute_copy(&pbstrsourcepath), core::mem::transmute_copy(&pbstrsourcefilename), core::mem::transmute_copy(&pbstrsourcerangelist), core::mem::transmute_copy(&pbstrdestinationpath), core::mem::transmute_copy(&pbstrdestinationfilename), core::mem::transmute_copy(&pbstrdestinationrangelist)).into()
+            }
+        }
+        unsafe extern "system" fn SetRestoreMetadata<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, wszrestoremetadata: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::SetRestoreMetadata(this, core::mem::transmute(&wszrestoremetadata)).into()
+            }
+        }
+        unsafe extern "system" fn GetRestoreMetadata<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, pbstrrestoremetadata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetRestoreMetadata(this, core::mem::transmute_copy(&pbstrrestoremetadata)).into()
+            }
+        }
+        unsafe extern "system" fn SetRestoreTarget<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, target: VSS_RESTORE_TARGET) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::SetRestoreTarget(this, core::mem::transmute_copy(&target)).into()
+            }
+        }
+        unsafe extern "system" fn GetRestoreTarget<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, ptarget: *mut VSS_RESTORE_TARGET) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetRestoreTarget(this, core::mem::transmute_copy(&ptarget)).into()
+            }
+        }
+        unsafe extern "system" fn SetPreRestoreFailureMsg<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, wszprerestorefailuremsg: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::SetPreRestoreFailureMsg(this, core::mem::transmute(&wszprerestorefailuremsg)).into()
+            }
+        }
+        unsafe extern "system" fn GetPreRestoreFailureMsg<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, pbstrprerestorefailuremsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetPreRestoreFailureMsg(this, core::mem::transmute_copy(&pbstrprerestorefailuremsg)).into()
+            }
+        }
+        unsafe extern "system" fn SetPostRestoreFailureMsg<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, wszpostrestorefailuremsg: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::SetPostRestoreFailureMsg(this, core::mem::transmute(&wszpostrestorefailuremsg)).into()
+            }
+        }
+        unsafe extern "system" fn GetPostRestoreFailureMsg<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, pbstrpostrestorefailuremsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetPostRestoreFailureMsg(this, core::mem::transmute_copy(&pbstrpostrestorefailuremsg)).into()
+            }
+        }
+        unsafe extern "system" fn SetBackupStamp<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, wszbackupstamp: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::SetBackupStamp(this, core::mem::transmute(&wszbackupstamp)).into()
+            }
+        }
+        unsafe extern "system" fn GetBackupStamp<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, pbstrbackupstamp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetBackupStamp(this, core::mem::transmute_copy(&pbstrbackupstamp)).into()
+            }
+        }
+        unsafe extern "system" fn GetPreviousBackupStamp<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, pbstrbackupstamp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetPreviousBackupStamp(this, core::mem::transmute_copy(&pbstrbackupstamp)).into()
+            }
+        }
+        unsafe extern "system" fn GetBackupOptions<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, pbstrbackupoptions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetBackupOptions(this, core::mem::transmute_copy(&pbstrbackupoptions)).into()
+            }
+        }
+        unsafe extern "system" fn GetRestoreOptions<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, pbstrrestoreoptions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetRestoreOptions(this, core::mem::transmute_copy(&pbstrrestoreoptions)).into()
+            }
+        }
+        unsafe extern "system" fn GetRestoreSubcomponentCount<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, pcrestoresubcomponent: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetRestoreSubcomponentCount(this, core::mem::transmute_copy(&pcrestoresubcomponent)).into()
+            }
+        }
+        unsafe extern "system" fn GetRestoreSubcomponent<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, icomponent: u32, pbstrlogicalpath: *mut *mut core::ffi::c_void, pbstrcomponentname: *mut *mut core::ffi::c_void, pbrepair: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetRestoreSubcomponent(this, core::mem::transmute_copy(&icomponent), core::mem::transmute_copy(&pbstrlogicalpath), core::mem::transmute_copy(&pbstrcomponentname), core::mem::transmute_copy(&pbrepair)).into()
+            }
+        }
+        unsafe extern "system" fn GetFileRestoreStatus<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, pstatus: *mut VSS_FILE_RESTORE_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetFileRestoreStatus(this, core::mem::transmute_copy(&pstatus)).into()
+            }
+        }
+        unsafe extern "system" fn AddDifferencedFilesByLastModifyTime<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, wszpath: windows_core::PCWSTR, wszfilespec: windows_core::PCWSTR, brecursive: windows_core::BOOL, ftlastmodifytime: super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::AddDifferencedFilesByLastModifyTime(this, core::mem::transmute(&wszpath), core::mem::transmute(&wszfilespec), core::mem::transmute_copy(&brecursive), core::mem::transmute(&ftlastmodifytime)).into()
+            }
+        }
+        unsafe extern "system" fn AddDifferencedFilesByLastModifyLSN<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, wszpath: windows_core::PCWSTR, wszfilespec: windows_core::PCWSTR, brecursive: windows_core::BOOL, bstrlsnstring: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::AddDifferencedFilesByLastModifyLSN(this, core::mem::transmute(&wszpath), core::mem::transmute(&wszfilespec), core::mem::transmute_copy(&brecursive), core::mem::transmute(&bstrlsnstring)).into()
+            }
+        }
+        unsafe extern "system" fn GetDifferencedFilesCount<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, pcdifferencedfiles: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetDifferencedFilesCount(this, core::mem::transmute_copy(&pcdifferencedfiles)).into()
+            }
+        }
+        unsafe extern "system" fn GetDifferencedFile<Identity: windows_core::IUnknownImpl + IVssComponent_Impl>(this: *mut core::ffi::c_void, idifferencedfile: u32, pbstrpath: *mut *mut core::ffi::c_void, pbstrfilespec: *mut *mut core::ffi::c_void, pbrecursive: *mut windows_core::BOOL, pbstrlsnstring: *mut *mut core::ffi::c_void, pftlastmodifytime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponent_Impl::GetDifferencedFile(this, core::mem::transmute_copy(&idifferencedfile), core::mem::transmute_copy(&pbstrpath), core::mem::transmute_copy(&pbstrfilespec), core::mem::transmute_copy(&pbrecursive), core::mem::transmute_copy(&pbstrlsnstring), core::mem::transmute_copy(&pftlastmodifytime)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetLogicalPath: GetLogicalPath::<Identity>,
+            GetComponentType: GetComponentType::<Identity>,
+            GetComponentName: GetComponentName::<Identity>,
+            GetBackupSucceeded: GetBackupSucceeded::<Identity>,
+            GetAlternateLocationMappingCount: GetAlternateLocationMappingCount::<Identity>,
+            GetAlternateLocationMapping: GetAlternateLocationMapping::<Identity>,
+            SetBackupMetadata: SetBackupMetadata::<Identity>,
+            GetBackupMetadata: GetBackupMetadata::<Identity>,
+            AddPartialFile: AddPartialFile::<Identity>,
+            GetPartialFileCount: GetPartialFileCount::<Identity>,
+            GetPartialFile: GetPartialFile::<Identity>,
+            IsSelectedForRestore: IsSelectedForRestore::<Identity>,
+            GetAdditionalRestores: GetAdditionalRestores::<Identity>,
+            GetNewTargetCount: GetNewTargetCount::<Identity>,
+            GetNewTarget: GetNewTarget::<Identity>,
+            AddDirectedTarget: AddDirectedTarget::<Identity>,
+            GetDirectedTargetCount: GetDirectedTargetCount::<Identity>,
+            GetDirectedTarget: GetDirectedTarget::<Identity>,
+            SetRestoreMetadata: SetRestoreMetadata::<Identity>,
+            GetRestoreMetadata: GetRestoreMetadata::<Identity>,
+            SetRestoreTarget: SetRestoreTarget::<Identity>,
+            GetRestoreTarget: GetRestoreTarget::<Identity>,
+            SetPreRestoreFailureMsg: SetPreRestoreFailureMsg::<Identity>,
+            GetPreRestoreFailureMsg: GetPreRestoreFailureMsg::<Identity>,
+            SetPostRestoreFailureMsg: SetPostRestoreFailureMsg::<Identity>,
+            GetPostRestoreFailureMsg: GetPostRestoreFailureMsg::<Identity>,
+            SetBackupStamp: SetBackupStamp::<Identity>,
+            GetBackupStamp: GetBackupStamp::<Identity>,
+            GetPreviousBackupStamp: GetPreviousBackupStamp::<Identity>,
+            GetBackupOptions: GetBackupOptions::<Identity>,
+            GetRestoreOptions: GetRestoreOptions::<Identity>,
+            GetRestoreSubcomponentCount: GetRestoreSubcomponentCount::<Identity>,
+            GetRestoreSubcomponent: GetRestoreSubcomponent::<Identity>,
+            GetFileRestoreStatus: GetFileRestoreStatus::<Identity>,
+            AddDifferencedFilesByLastModifyTime: AddDifferencedFilesByLastModifyTime::<Identity>,
+            AddDifferencedFilesByLastModifyLSN: AddDifferencedFilesByLastModifyLSN::<Identity>,
+            GetDifferencedFilesCount: GetDifferencedFilesCount::<Identity>,
+            GetDifferencedFile: GetDifferencedFile::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVssComponent {}
 windows_core::imp::define_interface!(IVssComponentEx, IVssComponentEx_Vtbl, 0x156c8b5e_f131_4bd7_9c97_d1923be7e1fa);
 impl core::ops::Deref for IVssComponentEx {
@@ -911,30 +1317,113 @@ impl IVssComponentEx_Vtbl {
         iid == &<IVssComponentEx as windows_core::Interface>::IID || iid == &<IVssComponent as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IVssComponentEx {}
-windows_core::imp::define_interface!(IVssComponentEx2, IVssComponentEx2_Vtbl, 0x3b5be0f2_07a9_4e4b_bdd3_cfdc8e2c0d2d);
-impl core::ops::Deref for IVssComponentEx2 {
-    type Target = IVssComponentEx;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(IVssComponentEx2, windows_core::IUnknown, IVssComponent, IVssComponentEx);
-impl IVssComponentEx2 {
-    pub unsafe fn SetFailure<P2>(&self, hr: windows_core::HRESULT, hrapplication: windows_core::HRESULT, wszapplicationmessage: P2, dwreserved: u32) -> windows_core::Result<()>
-    where
-        P2: windows_core::Param<windows_core::PCWSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).SetFailure)(windows_core::Interface::as_raw(self), hr, hrapplication, wszapplicationmessage.param().abi(), dwreserved).ok() }
-    }
-    pub unsafe fn GetFailure(&self, phr: *mut windows_core::HRESULT, phrapplication: *mut windows_core::HRESULT, pbstrapplicationmessage: *mut windows_core::BSTR, pdwreserved: *mut u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetFailure)(windows_core::Interface::as_raw(self), phr as _, phrapplication as _, core::mem::transmute(pbstrapplicationmessage), pdwreserved as _).ok() }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IVssComponentEx2_Vtbl {
-    pub base__: IVssComponentEx_Vtbl,
+impl<Identity: windows_core::IUnknownImpl + IVssComponentEx_Impl> windows_core::imp::TearOffVtable<Identity> for IVssComponentEx_Vtbl
+where
+    IVssComponent_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetPrepareForBackupFailureMsg<Identity: windows_core::IUnknownImpl + IVssComponentEx_Impl>(this: *mut core::ffi::c_void, wszfailuremsg: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponentEx_Impl::SetPrepareForBackupFailureMsg(this, core::mem::transmute(&wszfailuremsg)).into()
+            }
+        }
+        unsafe extern "system" fn SetPostSnapshotFailureMsg<Identity: windows_core::IUnknownImpl + IVssComponentEx_Impl>(this: *mut core::ffi::c_void, wszfailuremsg: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponentEx_Impl::SetPostSnapshotFailureMsg(this, core::mem::transmute(&wszfailuremsg)).into()
+            }
+        }
+        unsafe extern "system" fn GetPrepareForBackupFailureMsg<Identity: windows_core::IUnknownImpl + IVssComponentEx_Impl>(this: *mut core::ffi::c_void, pbstrfailuremsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssComponentEx_Impl::GetPrepareForBackupFailureMsg(this) {
+                    Ok(ok__) => {
+                        pbstrfailuremsg.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPostSnapshotFailureMsg<Identity: windows_core::IUnknownImpl + IVssComponentEx_Impl>(this: *mut core::ffi::c_void, pbstrfailuremsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssComponentEx_Impl::GetPostSnapshotFailureMsg(this) {
+                    Ok(ok__) => {
+                        pbstrfailuremsg.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAuthoritativeRestore<Identity: windows_core::IUnknownImpl + IVssComponentEx_Impl>(this: *mut core::ffi::c_void, pbauth: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssComponentEx_Impl::GetAuthoritativeRestore(this) {
+                    Ok(ok__) => {
+                        pbauth.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRollForward<Identity: windows_core::IUnknownImpl + IVssComponentEx_Impl>(this: *mut core::ffi::c_void, prolltype: *mut VSS_ROLLFORWARD_TYPE, pbstrpoint: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponentEx_Impl::GetRollForward(this, core::mem::transmute_copy(&prolltype), core::mem::transmute_copy(&pbstrpoint)).into()
+            }
+        }
+        unsafe extern "system" fn GetRestoreName<Identity: windows_core::IUnknownImpl + IVssComponentEx_Impl>(this: *mut core::ffi::c_void, pbstrname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssComponentEx_Impl::GetRestoreName(this) {
+                    Ok(ok__) => {
+                        pbstrname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IVssComponent_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetPrepareForBackupFailureMsg: SetPrepareForBackupFailureMsg::<Identity>,
+            SetPostSnapshotFailureMsg: SetPostSnapshotFailureMsg::<Identity>,
+            GetPrepareForBackupFailureMsg: GetPrepareForBackupFailureMsg::<Identity>,
+            GetPostSnapshotFailureMsg: GetPostSnapshotFailureMsg::<Identity>,
+            GetAuthoritativeRestore: GetAuthoritativeRestore::<Identity>,
+            GetRollForward: GetRollForward::<Identity>,
+            GetRestoreName: GetRestoreName::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IVssComponentEx {}
+windows_core::imp::define_interface!(IVssComponentEx2, IVssComponentEx2_Vtbl, 0x3b5be0f2_07a9_4e4b_bdd3_cfdc8e2c0d2d);
+impl core::ops::Deref for IVssComponentEx2 {
+    type Target = IVssComponentEx;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(IVssComponentEx2, windows_core::IUnknown, IVssComponent, IVssComponentEx);
+impl IVssComponentEx2 {
+    pub unsafe fn SetFailure<P2>(&self, hr: windows_core::HRESULT, hrapplication: windows_core::HRESULT, wszapplicationmessage: P2, dwreserved: u32) -> windows_core::Result<()>
+    where
+        P2: windows_core::Param<windows_core::PCWSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).SetFailure)(windows_core::Interface::as_raw(self), hr, hrapplication, wszapplicationmessage.param().abi(), dwreserved).ok() }
+    }
+    pub unsafe fn GetFailure(&self, phr: *mut windows_core::HRESULT, phrapplication: *mut windows_core::HRESULT, pbstrapplicationmessage: *mut windows_core::BSTR, pdwreserved: *mut u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetFailure)(windows_core::Interface::as_raw(self), phr as _, phrapplication as _, core::mem::transmute(pbstrapplicationmessage), pdwreserved as _).ok() }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IVssComponentEx2_Vtbl {
+    pub base__: IVssComponentEx_Vtbl,
     pub SetFailure: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::HRESULT, windows_core::HRESULT, windows_core::PCWSTR, u32) -> windows_core::HRESULT,
     pub GetFailure: unsafe extern "system" fn(*mut core::ffi::c_void, *mut windows_core::HRESULT, *mut windows_core::HRESULT, *mut *mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
 }
@@ -962,6 +1451,30 @@ impl IVssComponentEx2_Vtbl {
         iid == &<IVssComponentEx2 as windows_core::Interface>::IID || iid == &<IVssComponent as windows_core::Interface>::IID || iid == &<IVssComponentEx as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVssComponentEx2_Impl> windows_core::imp::TearOffVtable<Identity> for IVssComponentEx2_Vtbl
+where
+    IVssComponentEx_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetFailure<Identity: windows_core::IUnknownImpl + IVssComponentEx2_Impl>(this: *mut core::ffi::c_void, hr: windows_core::HRESULT, hrapplication: windows_core::HRESULT, wszapplicationmessage: windows_core::PCWSTR, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponentEx2_Impl::SetFailure(this, core::mem::transmute_copy(&hr), core::mem::transmute_copy(&hrapplication), core::mem::transmute(&wszapplicationmessage), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn GetFailure<Identity: windows_core::IUnknownImpl + IVssComponentEx2_Impl>(this: *mut core::ffi::c_void, phr: *mut windows_core::HRESULT, phrapplication: *mut windows_core::HRESULT, pbstrapplicationmessage: *mut *mut core::ffi::c_void, pdwreserved: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssComponentEx2_Impl::GetFailure(this, core::mem::transmute_copy(&phr), core::mem::transmute_copy(&phrapplication), core::mem::transmute_copy(&pbstrapplicationmessage), core::mem::transmute_copy(&pdwreserved)).into()
+            }
+        }
+        Self {
+            base__: <IVssComponentEx_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetFailure: SetFailure::<Identity>,
+            GetFailure: GetFailure::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVssComponentEx2 {}
 windows_core::imp::define_interface!(IVssCreateExpressWriterMetadata, IVssCreateExpressWriterMetadata_Vtbl, 0x9c772e77_b26e_427f_92dd_c996f41ea5e3);
 windows_core::imp::interface_hierarchy!(IVssCreateExpressWriterMetadata, windows_core::IUnknown);
@@ -1103,6 +1616,71 @@ impl IVssCreateExpressWriterMetadata_Vtbl {
         iid == &<IVssCreateExpressWriterMetadata as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVssCreateExpressWriterMetadata_Impl> windows_core::imp::TearOffVtable<Identity> for IVssCreateExpressWriterMetadata_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddExcludeFiles<Identity: windows_core::IUnknownImpl + IVssCreateExpressWriterMetadata_Impl>(this: *mut core::ffi::c_void, wszpath: windows_core::PCWSTR, wszfilespec: windows_core::PCWSTR, brecursive: u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssCreateExpressWriterMetadata_Impl::AddExcludeFiles(this, core::mem::transmute(&wszpath), core::mem::transmute(&wszfilespec), core::mem::transmute_copy(&brecursive)).into()
+            }
+        }
+        unsafe extern "system" fn AddComponent<Identity: windows_core::IUnknownImpl + IVssCreateExpressWriterMetadata_Impl>(this: *mut core::ffi::c_void, ct: VSS_COMPONENT_TYPE, wszlogicalpath: windows_core::PCWSTR, wszcomponentname: windows_core::PCWSTR, wszcaption: windows_core::PCWSTR, pbicon: *const u8, cbicon: u32, brestoremetadata: u8, bnotifyonbackupcomplete: u8, bselectable: u8, bselectableforrestore: u8, dwcomponentflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssCreateExpressWriterMetadata_Impl::AddComponent(this, core::mem::transmute_copy(&ct), core::mem::transmute(&wszlogicalpath), core::mem::transmute(&wszcomponentname), core::mem::transmute(&wszcaption), core::mem::transmute_copy(&pbicon), core::mem::transmute_copy(&cbicon), core::mem::transmute_copy(&brestoremetadata), core::mem::transmute_copy(&bnotifyonbackupcomplete), core::mem::transmute_copy(&bselectable), core::mem::transmute_copy(&bselectableforrestore), core::mem::transmute_copy(&dwcomponentflags)).into()
+            }
+        }
+        unsafe extern "system" fn AddFilesToFileGroup<Identity: windows_core::IUnknownImpl + IVssCreateExpressWriterMetadata_Impl>(this: *mut core::ffi::c_void, wszlogicalpath: windows_core::PCWSTR, wszgroupname: windows_core::PCWSTR, wszpath: windows_core::PCWSTR, wszfilespec: windows_core::PCWSTR, brecursive: u8, wszalternatelocation: windows_core::PCWSTR, dwbackuptypemask: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssCreateExpressWriterMetadata_Impl::AddFilesToFileGroup(this, core::mem::transmute(&wszlogicalpath), core::mem::transmute(&wszgroupname), core::mem::transmute(&wszpath), core::mem::transmute(&wszfilespec), core::mem::transmute_copy(&brecursive), core::mem::transmute(&wszalternatelocation), core::mem::transmute_copy(&dwbackuptypemask)).into()
+            }
+        }
+        unsafe extern "system" fn SetRestoreMethod<Identity: windows_core::IUnknownImpl + IVssCreateExpressWriterMetadata_Impl>(this: *mut core::ffi::c_void, method: VSS_RESTOREMETHOD_ENUM, wszservice: windows_core::PCWSTR, wszuserprocedure: windows_core::PCWSTR, writerrestore: VSS_WRITERRESTORE_ENUM, brebootrequired: u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssCreateExpressWriterMetadata_Impl::SetRestoreMethod(this, core::mem::transmute_copy(&method), core::mem::transmute(&wszservice), core::mem::transmute(&wszuserprocedure), core::mem::transmute_copy(&writerrestore), core::mem::transmute_copy(&brebootrequired)).into()
+            }
+        }
+        unsafe extern "system" fn AddComponentDependency<Identity: windows_core::IUnknownImpl + IVssCreateExpressWriterMetadata_Impl>(this: *mut core::ffi::c_void, wszforlogicalpath: windows_core::PCWSTR, wszforcomponentname: windows_core::PCWSTR, onwriterid: windows_core::GUID, wszonlogicalpath: windows_core::PCWSTR, wszoncomponentname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssCreateExpressWriterMetadata_Impl::AddComponentDependency(this, core::mem::transmute(&wszforlogicalpath), core::mem::transmute(&wszforcomponentname), core::mem::transmute(&onwriterid), core::mem::transmute(&wszonlogicalpath), core::mem::transmute(&wszoncomponentname)).into()
+            }
+        }
+        unsafe extern "system" fn SetBackupSchema<Identity: windows_core::IUnknownImpl + IVssCreateExpressWriterMetadata_Impl>(this: *mut core::ffi::c_void, dwschemamask: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssCreateExpressWriterMetadata_Impl::SetBackupSchema(this, core::mem::transmute_copy(&dwschemamask)).into()
+            }
+        }
+        unsafe extern "system" fn SaveAsXML<Identity: windows_core::IUnknownImpl + IVssCreateExpressWriterMetadata_Impl>(this: *mut core::ffi::c_void, pbstrxml: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssCreateExpressWriterMetadata_Impl::SaveAsXML(this) {
+                    Ok(ok__) => {
+                        pbstrxml.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddExcludeFiles: AddExcludeFiles::<Identity>,
+            AddComponent: AddComponent::<Identity>,
+            AddFilesToFileGroup: AddFilesToFileGroup::<Identity>,
+            SetRestoreMethod: SetRestoreMethod::<Identity>,
+            AddComponentDependency: AddComponentDependency::<Identity>,
+            SetBackupSchema: SetBackupSchema::<Identity>,
+            SaveAsXML: SaveAsXML::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVssCreateExpressWriterMetadata {}
 windows_core::imp::define_interface!(IVssCreateWriterMetadata, IVssCreateWriterMetadata_Vtbl);
 impl IVssCreateWriterMetadata {
@@ -1481,6 +2059,82 @@ impl IVssDifferentialSoftwareSnapshotMgmt_Vtbl {
         iid == &<IVssDifferentialSoftwareSnapshotMgmt as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVssDifferentialSoftwareSnapshotMgmt_Impl> windows_core::imp::TearOffVtable<Identity> for IVssDifferentialSoftwareSnapshotMgmt_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddDiffArea<Identity: windows_core::IUnknownImpl + IVssDifferentialSoftwareSnapshotMgmt_Impl>(this: *mut core::ffi::c_void, pwszvolumename: *const u16, pwszdiffareavolumename: *const u16, llmaximumdiffspace: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssDifferentialSoftwareSnapshotMgmt_Impl::AddDiffArea(this, core::mem::transmute_copy(&pwszvolumename), core::mem::transmute_copy(&pwszdiffareavolumename), core::mem::transmute_copy(&llmaximumdiffspace)).into()
+            }
+        }
+        unsafe extern "system" fn ChangeDiffAreaMaximumSize<Identity: windows_core::IUnknownImpl + IVssDifferentialSoftwareSnapshotMgmt_Impl>(this: *mut core::ffi::c_void, pwszvolumename: *const u16, pwszdiffareavolumename: *const u16, llmaximumdiffspace: i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssDifferentialSoftwareSnapshotMgmt_Impl::ChangeDiffAreaMaximumSize(this, core::mem::transmute_copy(&pwszvolumename), core::mem::transmute_copy(&pwszdiffareavolumename), core::mem::transmute_copy(&llmaximumdiffspace)).into()
+            }
+        }
+        unsafe extern "system" fn QueryVolumesSupportedForDiffAreas<Identity: windows_core::IUnknownImpl + IVssDifferentialSoftwareSnapshotMgmt_Impl>(this: *mut core::ffi::c_void, pwszoriginalvolumename: *const u16, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssDifferentialSoftwareSnapshotMgmt_Impl::QueryVolumesSupportedForDiffAreas(this, core::mem::transmute_copy(&pwszoriginalvolumename)) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryDiffAreasForVolume<Identity: windows_core::IUnknownImpl + IVssDifferentialSoftwareSnapshotMgmt_Impl>(this: *mut core::ffi::c_void, pwszvolumename: *const u16, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssDifferentialSoftwareSnapshotMgmt_Impl::QueryDiffAreasForVolume(this, core::mem::transmute_copy(&pwszvolumename)) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryDiffAreasOnVolume<Identity: windows_core::IUnknownImpl + IVssDifferentialSoftwareSnapshotMgmt_Impl>(this: *mut core::ffi::c_void, pwszvolumename: *const u16, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssDifferentialSoftwareSnapshotMgmt_Impl::QueryDiffAreasOnVolume(this, core::mem::transmute_copy(&pwszvolumename)) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryDiffAreasForSnapshot<Identity: windows_core::IUnknownImpl + IVssDifferentialSoftwareSnapshotMgmt_Impl>(this: *mut core::ffi::c_void, snapshotid: windows_core::GUID, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssDifferentialSoftwareSnapshotMgmt_Impl::QueryDiffAreasForSnapshot(this, core::mem::transmute(&snapshotid)) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddDiffArea: AddDiffArea::<Identity>,
+            ChangeDiffAreaMaximumSize: ChangeDiffAreaMaximumSize::<Identity>,
+            QueryVolumesSupportedForDiffAreas: QueryVolumesSupportedForDiffAreas::<Identity>,
+            QueryDiffAreasForVolume: QueryDiffAreasForVolume::<Identity>,
+            QueryDiffAreasOnVolume: QueryDiffAreasOnVolume::<Identity>,
+            QueryDiffAreasForSnapshot: QueryDiffAreasForSnapshot::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVssDifferentialSoftwareSnapshotMgmt {}
 windows_core::imp::define_interface!(IVssDifferentialSoftwareSnapshotMgmt2, IVssDifferentialSoftwareSnapshotMgmt2_Vtbl, 0x949d7353_675f_4275_8969_f044c6277815);
 impl core::ops::Deref for IVssDifferentialSoftwareSnapshotMgmt2 {
@@ -1566,6 +2220,50 @@ impl IVssDifferentialSoftwareSnapshotMgmt2_Vtbl {
         iid == &<IVssDifferentialSoftwareSnapshotMgmt2 as windows_core::Interface>::IID || iid == &<IVssDifferentialSoftwareSnapshotMgmt as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVssDifferentialSoftwareSnapshotMgmt2_Impl> windows_core::imp::TearOffVtable<Identity> for IVssDifferentialSoftwareSnapshotMgmt2_Vtbl
+where
+    IVssDifferentialSoftwareSnapshotMgmt_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ChangeDiffAreaMaximumSizeEx<Identity: windows_core::IUnknownImpl + IVssDifferentialSoftwareSnapshotMgmt2_Impl>(this: *mut core::ffi::c_void, pwszvolumename: *const u16, pwszdiffareavolumename: *const u16, llmaximumdiffspace: i64, bvolatile: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssDifferentialSoftwareSnapshotMgmt2_Impl::ChangeDiffAreaMaximumSizeEx(this, core::mem::transmute_copy(&pwszvolumename), core::mem::transmute_copy(&pwszdiffareavolumename), core::mem::transmute_copy(&llmaximumdiffspace), core::mem::transmute_copy(&bvolatile)).into()
+            }
+        }
+        unsafe extern "system" fn MigrateDiffAreas<Identity: windows_core::IUnknownImpl + IVssDifferentialSoftwareSnapshotMgmt2_Impl>(this: *mut core::ffi::c_void, pwszvolumename: *const u16, pwszdiffareavolumename: *const u16, pwsznewdiffareavolumename: *const u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssDifferentialSoftwareSnapshotMgmt2_Impl::MigrateDiffAreas(this, core::mem::transmute_copy(&pwszvolumename), core::mem::transmute_copy(&pwszdiffareavolumename), core::mem::transmute_copy(&pwsznewdiffareavolumename)).into()
+            }
+        }
+        unsafe extern "system" fn QueryMigrationStatus<Identity: windows_core::IUnknownImpl + IVssDifferentialSoftwareSnapshotMgmt2_Impl>(this: *mut core::ffi::c_void, pwszvolumename: *const u16, pwszdiffareavolumename: *const u16, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssDifferentialSoftwareSnapshotMgmt2_Impl::QueryMigrationStatus(this, core::mem::transmute_copy(&pwszvolumename), core::mem::transmute_copy(&pwszdiffareavolumename)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSnapshotPriority<Identity: windows_core::IUnknownImpl + IVssDifferentialSoftwareSnapshotMgmt2_Impl>(this: *mut core::ffi::c_void, idsnapshot: windows_core::GUID, priority: u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssDifferentialSoftwareSnapshotMgmt2_Impl::SetSnapshotPriority(this, core::mem::transmute(&idsnapshot), core::mem::transmute_copy(&priority)).into()
+            }
+        }
+        Self {
+            base__: <IVssDifferentialSoftwareSnapshotMgmt_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ChangeDiffAreaMaximumSizeEx: ChangeDiffAreaMaximumSizeEx::<Identity>,
+            MigrateDiffAreas: MigrateDiffAreas::<Identity>,
+            QueryMigrationStatus: QueryMigrationStatus::<Identity>,
+            SetSnapshotPriority: SetSnapshotPriority::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVssDifferentialSoftwareSnapshotMgmt2 {}
 windows_core::imp::define_interface!(IVssDifferentialSoftwareSnapshotMgmt3, IVssDifferentialSoftwareSnapshotMgmt3_Vtbl, 0x383f7e71_a4c5_401f_b27f_f826289f8458);
 impl core::ops::Deref for IVssDifferentialSoftwareSnapshotMgmt3 {
@@ -1654,6 +2352,51 @@ impl IVssDifferentialSoftwareSnapshotMgmt3_Vtbl {
         iid == &<IVssDifferentialSoftwareSnapshotMgmt3 as windows_core::Interface>::IID || iid == &<IVssDifferentialSoftwareSnapshotMgmt as windows_core::Interface>::IID || iid == &<IVssDifferentialSoftwareSnapshotMgmt2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVssDifferentialSoftwareSnapshotMgmt3_Impl> windows_core::imp::TearOffVtable<Identity> for IVssDifferentialSoftwareSnapshotMgmt3_Vtbl
+where
+    IVssDifferentialSoftwareSnapshotMgmt2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetVolumeProtectLevel<Identity: windows_core::IUnknownImpl + IVssDifferentialSoftwareSnapshotMgmt3_Impl>(this: *mut core::ffi::c_void, pwszvolumename: *const u16, protectionlevel: VSS_PROTECTION_LEVEL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssDifferentialSoftwareSnapshotMgmt3_Impl::SetVolumeProtectLevel(this, core::mem::transmute_copy(&pwszvolumename), core::mem::transmute_copy(&protectionlevel)).into()
+            }
+        }
+        unsafe extern "system" fn GetVolumeProtectLevel<Identity: windows_core::IUnknownImpl + IVssDifferentialSoftwareSnapshotMgmt3_Impl>(this: *mut core::ffi::c_void, pwszvolumename: *const u16, protectionlevel: *mut VSS_VOLUME_PROTECTION_INFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssDifferentialSoftwareSnapshotMgmt3_Impl::GetVolumeProtectLevel(this, core::mem::transmute_copy(&pwszvolumename), core::mem::transmute_copy(&protectionlevel)).into()
+            }
+        }
+        unsafe extern "system" fn ClearVolumeProtectFault<Identity: windows_core::IUnknownImpl + IVssDifferentialSoftwareSnapshotMgmt3_Impl>(this: *mut core::ffi::c_void, pwszvolumename: *const u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssDifferentialSoftwareSnapshotMgmt3_Impl::ClearVolumeProtectFault(this, core::mem::transmute_copy(&pwszvolumename)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteUnusedDiffAreas<Identity: windows_core::IUnknownImpl + IVssDifferentialSoftwareSnapshotMgmt3_Impl>(this: *mut core::ffi::c_void, pwszdiffareavolumename: *const u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssDifferentialSoftwareSnapshotMgmt3_Impl::DeleteUnusedDiffAreas(this, core::mem::transmute_copy(&pwszdiffareavolumename)).into()
+            }
+        }
+        unsafe extern "system" fn QuerySnapshotDeltaBitmap<Identity: windows_core::IUnknownImpl + IVssDifferentialSoftwareSnapshotMgmt3_Impl>(this: *mut core::ffi::c_void, idsnapshotolder: windows_core::GUID, idsnapshotyounger: windows_core::GUID, pcblocksizeperbit: *mut u32, pcbitmaplength: *mut u32, ppbbitmap: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssDifferentialSoftwareSnapshotMgmt3_Impl::QuerySnapshotDeltaBitmap(this, core::mem::transmute(&idsnapshotolder), core::mem::transmute(&idsnapshotyounger), core::mem::transmute_copy(&pcblocksizeperbit), core::mem::transmute_copy(&pcbitmaplength), core::mem::transmute_copy(&ppbbitmap)).into()
+            }
+        }
+        Self {
+            base__: <IVssDifferentialSoftwareSnapshotMgmt2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetVolumeProtectLevel: SetVolumeProtectLevel::<Identity>,
+            GetVolumeProtectLevel: GetVolumeProtectLevel::<Identity>,
+            ClearVolumeProtectFault: ClearVolumeProtectFault::<Identity>,
+            DeleteUnusedDiffAreas: DeleteUnusedDiffAreas::<Identity>,
+            QuerySnapshotDeltaBitmap: QuerySnapshotDeltaBitmap::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVssDifferentialSoftwareSnapshotMgmt3 {}
 windows_core::imp::define_interface!(IVssEnumMgmtObject, IVssEnumMgmtObject_Vtbl, 0x01954e6b_9254_4e6e_808c_c9e05d007696);
 windows_core::imp::interface_hierarchy!(IVssEnumMgmtObject, windows_core::IUnknown);
@@ -1724,6 +2467,44 @@ impl IVssEnumMgmtObject_Vtbl {
         iid == &<IVssEnumMgmtObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVssEnumMgmtObject_Impl> windows_core::imp::TearOffVtable<Identity> for IVssEnumMgmtObject_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IVssEnumMgmtObject_Impl>(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut VSS_MGMT_OBJECT_PROP, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssEnumMgmtObject_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgelt), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IVssEnumMgmtObject_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssEnumMgmtObject_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IVssEnumMgmtObject_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssEnumMgmtObject_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IVssEnumMgmtObject_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssEnumMgmtObject_Impl::Clone(this, core::mem::transmute_copy(&ppenum)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVssEnumMgmtObject {}
 windows_core::imp::define_interface!(IVssEnumObject, IVssEnumObject_Vtbl, 0xae1c7110_2f60_11d3_8a39_00c04f72d8e3);
 windows_core::imp::interface_hierarchy!(IVssEnumObject, windows_core::IUnknown);
@@ -1794,10 +2575,48 @@ impl IVssEnumObject_Vtbl {
         iid == &<IVssEnumObject as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IVssEnumObject {}
-windows_core::imp::define_interface!(IVssExpressWriter, IVssExpressWriter_Vtbl, 0xe33affdc_59c7_47b1_97d5_4266598f6235);
-windows_core::imp::interface_hierarchy!(IVssExpressWriter, windows_core::IUnknown);
-impl IVssExpressWriter {
+impl<Identity: windows_core::IUnknownImpl + IVssEnumObject_Impl> windows_core::imp::TearOffVtable<Identity> for IVssEnumObject_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IVssEnumObject_Impl>(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut VSS_OBJECT_PROP, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssEnumObject_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgelt), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IVssEnumObject_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssEnumObject_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IVssEnumObject_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssEnumObject_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IVssEnumObject_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssEnumObject_Impl::Clone(this, core::mem::transmute_copy(&ppenum)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IVssEnumObject {}
+windows_core::imp::define_interface!(IVssExpressWriter, IVssExpressWriter_Vtbl, 0xe33affdc_59c7_47b1_97d5_4266598f6235);
+windows_core::imp::interface_hierarchy!(IVssExpressWriter, windows_core::IUnknown);
+impl IVssExpressWriter {
     pub unsafe fn CreateMetadata<P1>(&self, writerid: windows_core::GUID, writername: P1, usagetype: VSS_USAGE_TYPE, versionmajor: u32, versionminor: u32, reserved: u32) -> windows_core::Result<IVssCreateExpressWriterMetadata>
     where
         P1: windows_core::Param<windows_core::PCWSTR>,
@@ -1879,6 +2698,50 @@ impl IVssExpressWriter_Vtbl {
         iid == &<IVssExpressWriter as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVssExpressWriter_Impl> windows_core::imp::TearOffVtable<Identity> for IVssExpressWriter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateMetadata<Identity: windows_core::IUnknownImpl + IVssExpressWriter_Impl>(this: *mut core::ffi::c_void, writerid: windows_core::GUID, writername: windows_core::PCWSTR, usagetype: VSS_USAGE_TYPE, versionmajor: u32, versionminor: u32, reserved: u32, ppmetadata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssExpressWriter_Impl::CreateMetadata(this, core::mem::transmute(&writerid), core::mem::transmute(&writername), core::mem::transmute_copy(&usagetype), core::mem::transmute_copy(&versionmajor), core::mem::transmute_copy(&versionminor), core::mem::transmute_copy(&reserved)) {
+                    Ok(ok__) => {
+                        ppmetadata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LoadMetadata<Identity: windows_core::IUnknownImpl + IVssExpressWriter_Impl>(this: *mut core::ffi::c_void, metadata: windows_core::PCWSTR, reserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssExpressWriter_Impl::LoadMetadata(this, core::mem::transmute(&metadata), core::mem::transmute_copy(&reserved)).into()
+            }
+        }
+        unsafe extern "system" fn Register<Identity: windows_core::IUnknownImpl + IVssExpressWriter_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssExpressWriter_Impl::Register(this).into()
+            }
+        }
+        unsafe extern "system" fn Unregister<Identity: windows_core::IUnknownImpl + IVssExpressWriter_Impl>(this: *mut core::ffi::c_void, writerid: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssExpressWriter_Impl::Unregister(this, core::mem::transmute(&writerid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateMetadata: CreateMetadata::<Identity>,
+            LoadMetadata: LoadMetadata::<Identity>,
+            Register: Register::<Identity>,
+            Unregister: Unregister::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVssExpressWriter {}
 windows_core::imp::define_interface!(IVssFileShareSnapshotProvider, IVssFileShareSnapshotProvider_Vtbl, 0xc8636060_7c2e_11df_8c4a_0800200c9a66);
 windows_core::imp::interface_hierarchy!(IVssFileShareSnapshotProvider, windows_core::IUnknown);
@@ -2022,6 +2885,85 @@ impl IVssFileShareSnapshotProvider_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IVssFileShareSnapshotProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IVssFileShareSnapshotProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetContext<Identity: windows_core::IUnknownImpl + IVssFileShareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, lcontext: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssFileShareSnapshotProvider_Impl::SetContext(this, core::mem::transmute_copy(&lcontext)).into()
+            }
+        }
+        unsafe extern "system" fn GetSnapshotProperties<Identity: windows_core::IUnknownImpl + IVssFileShareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, snapshotid: windows_core::GUID, pprop: *mut VSS_SNAPSHOT_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssFileShareSnapshotProvider_Impl::GetSnapshotProperties(this, core::mem::transmute(&snapshotid), core::mem::transmute_copy(&pprop)).into()
+            }
+        }
+        unsafe extern "system" fn Query<Identity: windows_core::IUnknownImpl + IVssFileShareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, queriedobjectid: windows_core::GUID, equeriedobjecttype: VSS_OBJECT_TYPE, ereturnedobjectstype: VSS_OBJECT_TYPE, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssFileShareSnapshotProvider_Impl::Query(this, core::mem::transmute(&queriedobjectid), core::mem::transmute_copy(&equeriedobjecttype), core::mem::transmute_copy(&ereturnedobjectstype)) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeleteSnapshots<Identity: windows_core::IUnknownImpl + IVssFileShareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, sourceobjectid: windows_core::GUID, esourceobjecttype: VSS_OBJECT_TYPE, bforcedelete: windows_core::BOOL, pldeletedsnapshots: *mut i32, pnondeletedsnapshotid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssFileShareSnapshotProvider_Impl::DeleteSnapshots(this, core::mem::transmute(&sourceobjectid), core::mem::transmute_copy(&esourceobjecttype), core::mem::transmute_copy(&bforcedelete), core::mem::transmute_copy(&pldeletedsnapshots), core::mem::transmute_copy(&pnondeletedsnapshotid)).into()
+            }
+        }
+        unsafe extern "system" fn BeginPrepareSnapshot<Identity: windows_core::IUnknownImpl + IVssFileShareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, snapshotsetid: windows_core::GUID, snapshotid: windows_core::GUID, pwszsharepath: *const u16, lnewcontext: i32, providerid: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssFileShareSnapshotProvider_Impl::BeginPrepareSnapshot(this, core::mem::transmute(&snapshotsetid), core::mem::transmute(&snapshotid), core::mem::transmute_copy(&pwszsharepath), core::mem::transmute_copy(&lnewcontext), core::mem::transmute(&providerid)).into()
+            }
+        }
+        unsafe extern "system" fn IsPathSupported<Identity: windows_core::IUnknownImpl + IVssFileShareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, pwszsharepath: *const u16, pbsupportedbythisprovider: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssFileShareSnapshotProvider_Impl::IsPathSupported(this, core::mem::transmute_copy(&pwszsharepath)) {
+                    Ok(ok__) => {
+                        pbsupportedbythisprovider.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsPathSnapshotted<Identity: windows_core::IUnknownImpl + IVssFileShareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, pwszsharepath: *const u16, pbsnapshotspresent: *mut windows_core::BOOL, plsnapshotcompatibility: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssFileShareSnapshotProvider_Impl::IsPathSnapshotted(this, core::mem::transmute_copy(&pwszsharepath), core::mem::transmute_copy(&pbsnapshotspresent), core::mem::transmute_copy(&plsnapshotcompatibility)).into()
+            }
+        }
+        unsafe extern "system" fn SetSnapshotProperty<Identity: windows_core::IUnknownImpl + IVssFileShareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, snapshotid: windows_core::GUID, esnapshotpropertyid: VSS_SNAPSHOT_PROPERTY_ID, vproperty: super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssFileShareSnapshotProvider_Impl::SetSnapshotProperty(this, core::mem::transmute(&snapshotid), core::mem::transmute_copy(&esnapshotpropertyid), core::mem::transmute(&vproperty)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetContext: SetContext::<Identity>,
+            GetSnapshotProperties: GetSnapshotProperties::<Identity>,
+            Query: Query::<Identity>,
+            DeleteSnapshots: DeleteSnapshots::<Identity>,
+            BeginPrepareSnapshot: BeginPrepareSnapshot::<Identity>,
+            IsPathSupported: IsPathSupported::<Identity>,
+            IsPathSnapshotted: IsPathSnapshotted::<Identity>,
+            SetSnapshotProperty: SetSnapshotProperty::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IVssFileShareSnapshotProvider {}
 windows_core::imp::define_interface!(IVssHardwareSnapshotProvider, IVssHardwareSnapshotProvider_Vtbl, 0x9593a157_44e9_4344_bbeb_44fbf9b06b10);
 windows_core::imp::interface_hierarchy!(IVssHardwareSnapshotProvider, windows_core::IUnknown);
@@ -2143,6 +3085,59 @@ impl IVssHardwareSnapshotProvider_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Storage_VirtualDiskService")]
+impl<Identity: windows_core::IUnknownImpl + IVssHardwareSnapshotProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IVssHardwareSnapshotProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AreLunsSupported<Identity: windows_core::IUnknownImpl + IVssHardwareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, lluncount: i32, lcontext: i32, rgwszdevices: *const *const u16, pluninformation: *mut super::VirtualDiskService::VDS_LUN_INFORMATION, pbissupported: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssHardwareSnapshotProvider_Impl::AreLunsSupported(this, core::mem::transmute_copy(&lluncount), core::mem::transmute_copy(&lcontext), core::mem::transmute_copy(&rgwszdevices), core::mem::transmute_copy(&pluninformation), core::mem::transmute_copy(&pbissupported)).into()
+            }
+        }
+        unsafe extern "system" fn FillInLunInfo<Identity: windows_core::IUnknownImpl + IVssHardwareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, wszdevicename: *const u16, pluninfo: *mut super::VirtualDiskService::VDS_LUN_INFORMATION, pbissupported: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssHardwareSnapshotProvider_Impl::FillInLunInfo(this, core::mem::transmute_copy(&wszdevicename), core::mem::transmute_copy(&pluninfo), core::mem::transmute_copy(&pbissupported)).into()
+            }
+        }
+        unsafe extern "system" fn BeginPrepareSnapshot<Identity: windows_core::IUnknownImpl + IVssHardwareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, snapshotsetid: windows_core::GUID, snapshotid: windows_core::GUID, lcontext: i32, lluncount: i32, rgdevicenames: *const *const u16, rgluninformation: *mut super::VirtualDiskService::VDS_LUN_INFORMATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssHardwareSnapshotProvider_Impl::BeginPrepareSnapshot(this, core::mem::transmute(&snapshotsetid), core::mem::transmute(&snapshotid), core::mem::transmute_copy(&lcontext), core::mem::transmute_copy(&lluncount), core::mem::transmute_copy(&rgdevicenames), core::mem::transmute_copy(&rgluninformation)).into()
+            }
+        }
+        unsafe extern "system" fn GetTargetLuns<Identity: windows_core::IUnknownImpl + IVssHardwareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, lluncount: i32, rgdevicenames: *const *const u16, rgsourceluns: *const super::VirtualDiskService::VDS_LUN_INFORMATION, rgdestinationluns: *mut super::VirtualDiskService::VDS_LUN_INFORMATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssHardwareSnapshotProvider_Impl::GetTargetLuns(this, core::mem::transmute_copy(&lluncount), core::mem::transmute_copy(&rgdevicenames), core::mem::transmute_copy(&rgsourceluns), core::mem::transmute_copy(&rgdestinationluns)).into()
+            }
+        }
+        unsafe extern "system" fn LocateLuns<Identity: windows_core::IUnknownImpl + IVssHardwareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, lluncount: i32, rgsourceluns: *const super::VirtualDiskService::VDS_LUN_INFORMATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssHardwareSnapshotProvider_Impl::LocateLuns(this, core::mem::transmute_copy(&lluncount), core::mem::transmute_copy(&rgsourceluns)).into()
+            }
+        }
+        unsafe extern "system" fn OnLunEmpty<Identity: windows_core::IUnknownImpl + IVssHardwareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, wszdevicename: *const u16, pinformation: *const super::VirtualDiskService::VDS_LUN_INFORMATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssHardwareSnapshotProvider_Impl::OnLunEmpty(this, core::mem::transmute_copy(&wszdevicename), core::mem::transmute_copy(&pinformation)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AreLunsSupported: AreLunsSupported::<Identity>,
+            FillInLunInfo: FillInLunInfo::<Identity>,
+            BeginPrepareSnapshot: BeginPrepareSnapshot::<Identity>,
+            GetTargetLuns: GetTargetLuns::<Identity>,
+            LocateLuns: LocateLuns::<Identity>,
+            OnLunEmpty: OnLunEmpty::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Storage_VirtualDiskService")]
 impl windows_core::RuntimeName for IVssHardwareSnapshotProvider {}
 windows_core::imp::define_interface!(IVssHardwareSnapshotProviderEx, IVssHardwareSnapshotProviderEx_Vtbl, 0x7f5ba925_cdb1_4d11_a71f_339eb7e709fd);
 impl core::ops::Deref for IVssHardwareSnapshotProviderEx {
@@ -2252,6 +3247,57 @@ impl IVssHardwareSnapshotProviderEx_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Storage_VirtualDiskService")]
+impl<Identity: windows_core::IUnknownImpl + IVssHardwareSnapshotProviderEx_Impl> windows_core::imp::TearOffVtable<Identity> for IVssHardwareSnapshotProviderEx_Vtbl
+where
+    IVssHardwareSnapshotProvider_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProviderCapabilities<Identity: windows_core::IUnknownImpl + IVssHardwareSnapshotProviderEx_Impl>(this: *mut core::ffi::c_void, plloriginalcapabilitymask: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssHardwareSnapshotProviderEx_Impl::GetProviderCapabilities(this) {
+                    Ok(ok__) => {
+                        plloriginalcapabilitymask.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OnLunStateChange<Identity: windows_core::IUnknownImpl + IVssHardwareSnapshotProviderEx_Impl>(this: *mut core::ffi::c_void, psnapshotluns: *const super::VirtualDiskService::VDS_LUN_INFORMATION, poriginalluns: *const super::VirtualDiskService::VDS_LUN_INFORMATION, dwcount: u32, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssHardwareSnapshotProviderEx_Impl::OnLunStateChange(this, core::mem::transmute_copy(&psnapshotluns), core::mem::transmute_copy(&poriginalluns), core::mem::transmute_copy(&dwcount), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn ResyncLuns<Identity: windows_core::IUnknownImpl + IVssHardwareSnapshotProviderEx_Impl>(this: *mut core::ffi::c_void, psourceluns: *const super::VirtualDiskService::VDS_LUN_INFORMATION, ptargetluns: *const super::VirtualDiskService::VDS_LUN_INFORMATION, dwcount: u32, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssHardwareSnapshotProviderEx_Impl::ResyncLuns(this, core::mem::transmute_copy(&psourceluns), core::mem::transmute_copy(&ptargetluns), core::mem::transmute_copy(&dwcount)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OnReuseLuns<Identity: windows_core::IUnknownImpl + IVssHardwareSnapshotProviderEx_Impl>(this: *mut core::ffi::c_void, psnapshotluns: *const super::VirtualDiskService::VDS_LUN_INFORMATION, poriginalluns: *const super::VirtualDiskService::VDS_LUN_INFORMATION, dwcount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssHardwareSnapshotProviderEx_Impl::OnReuseLuns(this, core::mem::transmute_copy(&psnapshotluns), core::mem::transmute_copy(&poriginalluns), core::mem::transmute_copy(&dwcount)).into()
+            }
+        }
+        Self {
+            base__: <IVssHardwareSnapshotProvider_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProviderCapabilities: GetProviderCapabilities::<Identity>,
+            OnLunStateChange: OnLunStateChange::<Identity>,
+            ResyncLuns: ResyncLuns::<Identity>,
+            OnReuseLuns: OnReuseLuns::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Storage_VirtualDiskService")]
 impl windows_core::RuntimeName for IVssHardwareSnapshotProviderEx {}
 windows_core::imp::define_interface!(IVssProviderCreateSnapshotSet, IVssProviderCreateSnapshotSet_Vtbl, 0x5f894e5b_1e39_4778_8e23_9abad9f0e08c);
 windows_core::imp::interface_hierarchy!(IVssProviderCreateSnapshotSet, windows_core::IUnknown);
@@ -2358,6 +3404,65 @@ impl IVssProviderCreateSnapshotSet_Vtbl {
         iid == &<IVssProviderCreateSnapshotSet as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVssProviderCreateSnapshotSet_Impl> windows_core::imp::TearOffVtable<Identity> for IVssProviderCreateSnapshotSet_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EndPrepareSnapshots<Identity: windows_core::IUnknownImpl + IVssProviderCreateSnapshotSet_Impl>(this: *mut core::ffi::c_void, snapshotsetid: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssProviderCreateSnapshotSet_Impl::EndPrepareSnapshots(this, core::mem::transmute(&snapshotsetid)).into()
+            }
+        }
+        unsafe extern "system" fn PreCommitSnapshots<Identity: windows_core::IUnknownImpl + IVssProviderCreateSnapshotSet_Impl>(this: *mut core::ffi::c_void, snapshotsetid: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssProviderCreateSnapshotSet_Impl::PreCommitSnapshots(this, core::mem::transmute(&snapshotsetid)).into()
+            }
+        }
+        unsafe extern "system" fn CommitSnapshots<Identity: windows_core::IUnknownImpl + IVssProviderCreateSnapshotSet_Impl>(this: *mut core::ffi::c_void, snapshotsetid: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssProviderCreateSnapshotSet_Impl::CommitSnapshots(this, core::mem::transmute(&snapshotsetid)).into()
+            }
+        }
+        unsafe extern "system" fn PostCommitSnapshots<Identity: windows_core::IUnknownImpl + IVssProviderCreateSnapshotSet_Impl>(this: *mut core::ffi::c_void, snapshotsetid: windows_core::GUID, lsnapshotscount: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssProviderCreateSnapshotSet_Impl::PostCommitSnapshots(this, core::mem::transmute(&snapshotsetid), core::mem::transmute_copy(&lsnapshotscount)).into()
+            }
+        }
+        unsafe extern "system" fn PreFinalCommitSnapshots<Identity: windows_core::IUnknownImpl + IVssProviderCreateSnapshotSet_Impl>(this: *mut core::ffi::c_void, snapshotsetid: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssProviderCreateSnapshotSet_Impl::PreFinalCommitSnapshots(this, core::mem::transmute(&snapshotsetid)).into()
+            }
+        }
+        unsafe extern "system" fn PostFinalCommitSnapshots<Identity: windows_core::IUnknownImpl + IVssProviderCreateSnapshotSet_Impl>(this: *mut core::ffi::c_void, snapshotsetid: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssProviderCreateSnapshotSet_Impl::PostFinalCommitSnapshots(this, core::mem::transmute(&snapshotsetid)).into()
+            }
+        }
+        unsafe extern "system" fn AbortSnapshots<Identity: windows_core::IUnknownImpl + IVssProviderCreateSnapshotSet_Impl>(this: *mut core::ffi::c_void, snapshotsetid: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssProviderCreateSnapshotSet_Impl::AbortSnapshots(this, core::mem::transmute(&snapshotsetid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EndPrepareSnapshots: EndPrepareSnapshots::<Identity>,
+            PreCommitSnapshots: PreCommitSnapshots::<Identity>,
+            CommitSnapshots: CommitSnapshots::<Identity>,
+            PostCommitSnapshots: PostCommitSnapshots::<Identity>,
+            PreFinalCommitSnapshots: PreFinalCommitSnapshots::<Identity>,
+            PostFinalCommitSnapshots: PostFinalCommitSnapshots::<Identity>,
+            AbortSnapshots: AbortSnapshots::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVssProviderCreateSnapshotSet {}
 windows_core::imp::define_interface!(IVssProviderNotifications, IVssProviderNotifications_Vtbl, 0xe561901f_03a5_4afe_86d0_72baeece7004);
 windows_core::imp::interface_hierarchy!(IVssProviderNotifications, windows_core::IUnknown);
@@ -2403,6 +3508,30 @@ impl IVssProviderNotifications_Vtbl {
         iid == &<IVssProviderNotifications as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVssProviderNotifications_Impl> windows_core::imp::TearOffVtable<Identity> for IVssProviderNotifications_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnLoad<Identity: windows_core::IUnknownImpl + IVssProviderNotifications_Impl>(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssProviderNotifications_Impl::OnLoad(this, core::mem::transmute_copy(&pcallback)).into()
+            }
+        }
+        unsafe extern "system" fn OnUnload<Identity: windows_core::IUnknownImpl + IVssProviderNotifications_Impl>(this: *mut core::ffi::c_void, bforceunload: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssProviderNotifications_Impl::OnUnload(this, core::mem::transmute_copy(&bforceunload)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnLoad: OnLoad::<Identity>,
+            OnUnload: OnUnload::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVssProviderNotifications {}
 windows_core::imp::define_interface!(IVssSnapshotMgmt, IVssSnapshotMgmt_Vtbl, 0xfa7df749_66e7_4986_a27f_e2f04ae53772);
 windows_core::imp::interface_hierarchy!(IVssSnapshotMgmt, windows_core::IUnknown);
@@ -2488,6 +3617,55 @@ impl IVssSnapshotMgmt_Vtbl {
         iid == &<IVssSnapshotMgmt as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVssSnapshotMgmt_Impl> windows_core::imp::TearOffVtable<Identity> for IVssSnapshotMgmt_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProviderMgmtInterface<Identity: windows_core::IUnknownImpl + IVssSnapshotMgmt_Impl>(this: *mut core::ffi::c_void, providerid: windows_core::GUID, interfaceid: *const windows_core::GUID, ppitf: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssSnapshotMgmt_Impl::GetProviderMgmtInterface(this, core::mem::transmute(&providerid), core::mem::transmute_copy(&interfaceid)) {
+                    Ok(ok__) => {
+                        ppitf.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryVolumesSupportedForSnapshots<Identity: windows_core::IUnknownImpl + IVssSnapshotMgmt_Impl>(this: *mut core::ffi::c_void, providerid: windows_core::GUID, lcontext: i32, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssSnapshotMgmt_Impl::QueryVolumesSupportedForSnapshots(this, core::mem::transmute(&providerid), core::mem::transmute_copy(&lcontext)) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QuerySnapshotsByVolume<Identity: windows_core::IUnknownImpl + IVssSnapshotMgmt_Impl>(this: *mut core::ffi::c_void, pwszvolumename: *const u16, providerid: windows_core::GUID, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssSnapshotMgmt_Impl::QuerySnapshotsByVolume(this, core::mem::transmute_copy(&pwszvolumename), core::mem::transmute(&providerid)) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProviderMgmtInterface: GetProviderMgmtInterface::<Identity>,
+            QueryVolumesSupportedForSnapshots: QueryVolumesSupportedForSnapshots::<Identity>,
+            QuerySnapshotsByVolume: QuerySnapshotsByVolume::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVssSnapshotMgmt {}
 windows_core::imp::define_interface!(IVssSnapshotMgmt2, IVssSnapshotMgmt2_Vtbl, 0x0f61ec39_fe82_45f2_a3f0_768b5d427102);
 windows_core::imp::interface_hierarchy!(IVssSnapshotMgmt2, windows_core::IUnknown);
@@ -2528,6 +3706,26 @@ impl IVssSnapshotMgmt2_Vtbl {
         iid == &<IVssSnapshotMgmt2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVssSnapshotMgmt2_Impl> windows_core::imp::TearOffVtable<Identity> for IVssSnapshotMgmt2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMinDiffAreaSize<Identity: windows_core::IUnknownImpl + IVssSnapshotMgmt2_Impl>(this: *mut core::ffi::c_void, pllmindiffareasize: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssSnapshotMgmt2_Impl::GetMinDiffAreaSize(this) {
+                    Ok(ok__) => {
+                        pllmindiffareasize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetMinDiffAreaSize: GetMinDiffAreaSize::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IVssSnapshotMgmt2 {}
 windows_core::imp::define_interface!(IVssSoftwareSnapshotProvider, IVssSoftwareSnapshotProvider_Vtbl, 0x609e123e_2c5a_44d3_8f01_0b1d9a47d1ff);
 windows_core::imp::interface_hierarchy!(IVssSoftwareSnapshotProvider, windows_core::IUnknown);
@@ -2704,6 +3902,105 @@ impl IVssSoftwareSnapshotProvider_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IVssSoftwareSnapshotProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IVssSoftwareSnapshotProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetContext<Identity: windows_core::IUnknownImpl + IVssSoftwareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, lcontext: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssSoftwareSnapshotProvider_Impl::SetContext(this, core::mem::transmute_copy(&lcontext)).into()
+            }
+        }
+        unsafe extern "system" fn GetSnapshotProperties<Identity: windows_core::IUnknownImpl + IVssSoftwareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, snapshotid: windows_core::GUID, pprop: *mut VSS_SNAPSHOT_PROP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssSoftwareSnapshotProvider_Impl::GetSnapshotProperties(this, core::mem::transmute(&snapshotid), core::mem::transmute_copy(&pprop)).into()
+            }
+        }
+        unsafe extern "system" fn Query<Identity: windows_core::IUnknownImpl + IVssSoftwareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, queriedobjectid: windows_core::GUID, equeriedobjecttype: VSS_OBJECT_TYPE, ereturnedobjectstype: VSS_OBJECT_TYPE, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssSoftwareSnapshotProvider_Impl::Query(this, core::mem::transmute(&queriedobjectid), core::mem::transmute_copy(&equeriedobjecttype), core::mem::transmute_copy(&ereturnedobjectstype)) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeleteSnapshots<Identity: windows_core::IUnknownImpl + IVssSoftwareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, sourceobjectid: windows_core::GUID, esourceobjecttype: VSS_OBJECT_TYPE, bforcedelete: windows_core::BOOL, pldeletedsnapshots: *mut i32, pnondeletedsnapshotid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssSoftwareSnapshotProvider_Impl::DeleteSnapshots(this, core::mem::transmute(&sourceobjectid), core::mem::transmute_copy(&esourceobjecttype), core::mem::transmute_copy(&bforcedelete), core::mem::transmute_copy(&pldeletedsnapshots), core::mem::transmute_copy(&pnondeletedsnapshotid)).into()
+            }
+        }
+        unsafe extern "system" fn BeginPrepareSnapshot<Identity: windows_core::IUnknownImpl + IVssSoftwareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, snapshotsetid: windows_core::GUID, snapshotid: windows_core::GUID, pwszvolumename: *const u16, lnewcontext: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssSoftwareSnapshotProvider_Impl::BeginPrepareSnapshot(this, core::mem::transmute(&snapshotsetid), core::mem::transmute(&snapshotid), core::mem::transmute_copy(&pwszvolumename), core::mem::transmute_copy(&lnewcontext)).into()
+            }
+        }
+        unsafe extern "system" fn IsVolumeSupported<Identity: windows_core::IUnknownImpl + IVssSoftwareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, pwszvolumename: *const u16, pbsupportedbythisprovider: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssSoftwareSnapshotProvider_Impl::IsVolumeSupported(this, core::mem::transmute_copy(&pwszvolumename)) {
+                    Ok(ok__) => {
+                        pbsupportedbythisprovider.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsVolumeSnapshotted<Identity: windows_core::IUnknownImpl + IVssSoftwareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, pwszvolumename: *const u16, pbsnapshotspresent: *mut windows_core::BOOL, plsnapshotcompatibility: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssSoftwareSnapshotProvider_Impl::IsVolumeSnapshotted(this, core::mem::transmute_copy(&pwszvolumename), core::mem::transmute_copy(&pbsnapshotspresent), core::mem::transmute_copy(&plsnapshotcompatibility)).into()
+            }
+        }
+        unsafe extern "system" fn SetSnapshotProperty<Identity: windows_core::IUnknownImpl + IVssSoftwareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, snapshotid: windows_core::GUID, esnapshotpropertyid: VSS_SNAPSHOT_PROPERTY_ID, vproperty: super::super::System::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssSoftwareSnapshotProvider_Impl::SetSnapshotProperty(this, core::mem::transmute(&snapshotid), core::mem::transmute_copy(&esnapshotpropertyid), core::mem::transmute(&vproperty)).into()
+            }
+        }
+        unsafe extern "system" fn RevertToSnapshot<Identity: windows_core::IUnknownImpl + IVssSoftwareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, snapshotid: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssSoftwareSnapshotProvider_Impl::RevertToSnapshot(this, core::mem::transmute(&snapshotid)).into()
+            }
+        }
+        unsafe extern "system" fn QueryRevertStatus<Identity: windows_core::IUnknownImpl + IVssSoftwareSnapshotProvider_Impl>(this: *mut core::ffi::c_void, pwszvolume: *const u16, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssSoftwareSnapshotProvider_Impl::QueryRevertStatus(this, core::mem::transmute_copy(&pwszvolume)) {
+                    Ok(ok__) => {
+                        ppasync.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetContext: SetContext::<Identity>,
+            GetSnapshotProperties: GetSnapshotProperties::<Identity>,
+            Query: Query::<Identity>,
+            DeleteSnapshots: DeleteSnapshots::<Identity>,
+            BeginPrepareSnapshot: BeginPrepareSnapshot::<Identity>,
+            IsVolumeSupported: IsVolumeSupported::<Identity>,
+            IsVolumeSnapshotted: IsVolumeSnapshotted::<Identity>,
+            SetSnapshotProperty: SetSnapshotProperty::<Identity>,
+            RevertToSnapshot: RevertToSnapshot::<Identity>,
+            QueryRevertStatus: QueryRevertStatus::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IVssSoftwareSnapshotProvider {}
 windows_core::imp::define_interface!(IVssWMDependency, IVssWMDependency_Vtbl, 0);
 windows_core::imp::interface_hierarchy!(IVssWMDependency, windows_core::IUnknown);
@@ -2762,6 +4059,37 @@ impl IVssWMDependency_Vtbl {
         iid == &<IVssWMDependency as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVssWMDependency_Impl> windows_core::imp::TearOffVtable<Identity> for IVssWMDependency_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetWriterId<Identity: windows_core::IUnknownImpl + IVssWMDependency_Impl>(this: *mut core::ffi::c_void, pwriterid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssWMDependency_Impl::GetWriterId(this, core::mem::transmute_copy(&pwriterid)).into()
+            }
+        }
+        unsafe extern "system" fn GetLogicalPath<Identity: windows_core::IUnknownImpl + IVssWMDependency_Impl>(this: *mut core::ffi::c_void, pbstrlogicalpath: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssWMDependency_Impl::GetLogicalPath(this, core::mem::transmute_copy(&pbstrlogicalpath)).into()
+            }
+        }
+        unsafe extern "system" fn GetComponentName<Identity: windows_core::IUnknownImpl + IVssWMDependency_Impl>(this: *mut core::ffi::c_void, pbstrcomponentname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVssWMDependency_Impl::GetComponentName(this, core::mem::transmute_copy(&pbstrcomponentname)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetWriterId: GetWriterId::<Identity>,
+            GetLogicalPath: GetLogicalPath::<Identity>,
+            GetComponentName: GetComponentName::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVssWMDependency {}
 windows_core::imp::define_interface!(IVssWMFiledesc, IVssWMFiledesc_Vtbl, 0);
 windows_core::imp::interface_hierarchy!(IVssWMFiledesc, windows_core::IUnknown);
@@ -2889,6 +4217,81 @@ impl IVssWMFiledesc_Vtbl {
         iid == &<IVssWMFiledesc as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IVssWMFiledesc_Impl> windows_core::imp::TearOffVtable<Identity> for IVssWMFiledesc_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPath<Identity: windows_core::IUnknownImpl + IVssWMFiledesc_Impl>(this: *mut core::ffi::c_void, pbstrpath: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssWMFiledesc_Impl::GetPath(this) {
+                    Ok(ok__) => {
+                        pbstrpath.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFilespec<Identity: windows_core::IUnknownImpl + IVssWMFiledesc_Impl>(this: *mut core::ffi::c_void, pbstrfilespec: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssWMFiledesc_Impl::GetFilespec(this) {
+                    Ok(ok__) => {
+                        pbstrfilespec.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRecursive<Identity: windows_core::IUnknownImpl + IVssWMFiledesc_Impl>(this: *mut core::ffi::c_void, pbrecursive: *mut bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssWMFiledesc_Impl::GetRecursive(this) {
+                    Ok(ok__) => {
+                        pbrecursive.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAlternateLocation<Identity: windows_core::IUnknownImpl + IVssWMFiledesc_Impl>(this: *mut core::ffi::c_void, pbstralternatelocation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssWMFiledesc_Impl::GetAlternateLocation(this) {
+                    Ok(ok__) => {
+                        pbstralternatelocation.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetBackupTypeMask<Identity: windows_core::IUnknownImpl + IVssWMFiledesc_Impl>(this: *mut core::ffi::c_void, pdwtypemask: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVssWMFiledesc_Impl::GetBackupTypeMask(this) {
+                    Ok(ok__) => {
+                        pdwtypemask.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPath: GetPath::<Identity>,
+            GetFilespec: GetFilespec::<Identity>,
+            GetRecursive: GetRecursive::<Identity>,
+            GetAlternateLocation: GetAlternateLocation::<Identity>,
+            GetBackupTypeMask: GetBackupTypeMask::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IVssWMFiledesc {}
 windows_core::imp::define_interface!(IVssWriterComponents, IVssWriterComponents_Vtbl);
 impl IVssWriterComponents {
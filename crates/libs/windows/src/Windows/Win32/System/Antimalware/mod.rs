@@ -260,6 +260,30 @@ impl IAmsiStream_Vtbl {
         iid == &<IAmsiStream as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAmsiStream_Impl> windows_core::imp::TearOffVtable<Identity> for IAmsiStream_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAttribute<Identity: windows_core::IUnknownImpl + IAmsiStream_Impl>(this: *mut core::ffi::c_void, attribute: AMSI_ATTRIBUTE, datasize: u32, data: *mut u8, retdata: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAmsiStream_Impl::GetAttribute(this, core::mem::transmute_copy(&attribute), core::mem::transmute_copy(&datasize), core::mem::transmute_copy(&data), core::mem::transmute_copy(&retdata)).into()
+            }
+        }
+        unsafe extern "system" fn Read<Identity: windows_core::IUnknownImpl + IAmsiStream_Impl>(this: *mut core::ffi::c_void, position: u64, size: u32, buffer: *mut u8, readsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAmsiStream_Impl::Read(this, core::mem::transmute_copy(&position), core::mem::transmute_copy(&size), core::mem::transmute_copy(&buffer), core::mem::transmute_copy(&readsize)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAttribute: GetAttribute::<Identity>,
+            Read: Read::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAmsiStream {}
 windows_core::imp::define_interface!(IAntimalware, IAntimalware_Vtbl, 0x82d29c2e_f062_44e6_b5c9_3d9a2f24a2df);
 windows_core::imp::interface_hierarchy!(IAntimalware, windows_core::IUnknown);
@@ -305,6 +329,30 @@ impl IAntimalware_Vtbl {
         iid == &<IAntimalware as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAntimalware_Impl> windows_core::imp::TearOffVtable<Identity> for IAntimalware_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Scan<Identity: windows_core::IUnknownImpl + IAntimalware_Impl>(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, result: *mut AMSI_RESULT, provider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAntimalware_Impl::Scan(this, core::mem::transmute_copy(&stream), core::mem::transmute_copy(&result), core::mem::transmute_copy(&provider)).into()
+            }
+        }
+        unsafe extern "system" fn CloseSession<Identity: windows_core::IUnknownImpl + IAntimalware_Impl>(this: *mut core::ffi::c_void, session: u64) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAntimalware_Impl::CloseSession(this, core::mem::transmute_copy(&session))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Scan: Scan::<Identity>,
+            CloseSession: CloseSession::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAntimalware {}
 windows_core::imp::define_interface!(IAntimalware2, IAntimalware2_Vtbl, 0x301035b5_2d42_4f56_8c65_2dcaa7fb3cdc);
 impl core::ops::Deref for IAntimalware2 {
@@ -355,6 +403,26 @@ impl IAntimalware2_Vtbl {
         iid == &<IAntimalware2 as windows_core::Interface>::IID || iid == &<IAntimalware as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAntimalware2_Impl> windows_core::imp::TearOffVtable<Identity> for IAntimalware2_Vtbl
+where
+    IAntimalware_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Notify<Identity: windows_core::IUnknownImpl + IAntimalware2_Impl>(this: *mut core::ffi::c_void, buffer: *const core::ffi::c_void, length: u32, contentname: windows_core::PCWSTR, appname: windows_core::PCWSTR, presult: *mut AMSI_RESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAntimalware2_Impl::Notify(this, core::mem::transmute_copy(&buffer), core::mem::transmute_copy(&length), core::mem::transmute(&contentname), core::mem::transmute(&appname)) {
+                    Ok(ok__) => {
+                        presult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IAntimalware_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Notify: Notify::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IAntimalware2 {}
 windows_core::imp::define_interface!(IAntimalwareProvider, IAntimalwareProvider_Vtbl, 0xb2cabfe3_fe04_42b1_a5df_08d483d4d125);
 windows_core::imp::interface_hierarchy!(IAntimalwareProvider, windows_core::IUnknown);
@@ -434,6 +502,49 @@ impl IAntimalwareProvider_Vtbl {
         iid == &<IAntimalwareProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAntimalwareProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IAntimalwareProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Scan<Identity: windows_core::IUnknownImpl + IAntimalwareProvider_Impl>(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, result: *mut AMSI_RESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAntimalwareProvider_Impl::Scan(this, core::mem::transmute_copy(&stream)) {
+                    Ok(ok__) => {
+                        result.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CloseSession<Identity: windows_core::IUnknownImpl + IAntimalwareProvider_Impl>(this: *mut core::ffi::c_void, session: u64) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAntimalwareProvider_Impl::CloseSession(this, core::mem::transmute_copy(&session))
+            }
+        }
+        unsafe extern "system" fn DisplayName<Identity: windows_core::IUnknownImpl + IAntimalwareProvider_Impl>(this: *mut core::ffi::c_void, displayname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAntimalwareProvider_Impl::DisplayName(this) {
+                    Ok(ok__) => {
+                        displayname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Scan: Scan::<Identity>,
+            CloseSession: CloseSession::<Identity>,
+            DisplayName: DisplayName::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAntimalwareProvider {}
 windows_core::imp::define_interface!(IAntimalwareProvider2, IAntimalwareProvider2_Vtbl, 0x7c1e6570_3f73_4e0f_8ad4_98b94cd3290f);
 impl core::ops::Deref for IAntimalwareProvider2 {
@@ -484,6 +595,26 @@ impl IAntimalwareProvider2_Vtbl {
         iid == &<IAntimalwareProvider2 as windows_core::Interface>::IID || iid == &<IAntimalwareProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAntimalwareProvider2_Impl> windows_core::imp::TearOffVtable<Identity> for IAntimalwareProvider2_Vtbl
+where
+    IAntimalwareProvider_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Notify<Identity: windows_core::IUnknownImpl + IAntimalwareProvider2_Impl>(this: *mut core::ffi::c_void, buffer: *const core::ffi::c_void, length: u32, contentname: windows_core::PCWSTR, appname: windows_core::PCWSTR, presult: *mut AMSI_RESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAntimalwareProvider2_Impl::Notify(this, core::mem::transmute_copy(&buffer), core::mem::transmute_copy(&length), core::mem::transmute(&contentname), core::mem::transmute(&appname)) {
+                    Ok(ok__) => {
+                        presult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IAntimalwareProvider_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Notify: Notify::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IAntimalwareProvider2 {}
 windows_core::imp::define_interface!(IAntimalwareUacProvider, IAntimalwareUacProvider_Vtbl, 0xb2cabfe4_fe04_42b1_a5df_08d483d4d125);
 windows_core::imp::interface_hierarchy!(IAntimalwareUacProvider, windows_core::IUnknown);
@@ -548,4 +679,40 @@ impl IAntimalwareUacProvider_Vtbl {
         iid == &<IAntimalwareUacProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAntimalwareUacProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IAntimalwareUacProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn UacScan<Identity: windows_core::IUnknownImpl + IAntimalwareUacProvider_Impl>(this: *mut core::ffi::c_void, context: *const AMSI_UAC_REQUEST_CONTEXT, result: *mut AMSI_RESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAntimalwareUacProvider_Impl::UacScan(this, core::mem::transmute_copy(&context)) {
+                    Ok(ok__) => {
+                        result.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DisplayName<Identity: windows_core::IUnknownImpl + IAntimalwareUacProvider_Impl>(this: *mut core::ffi::c_void, displayname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAntimalwareUacProvider_Impl::DisplayName(this) {
+                    Ok(ok__) => {
+                        displayname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            UacScan: UacScan::<Identity>,
+            DisplayName: DisplayName::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAntimalwareUacProvider {}
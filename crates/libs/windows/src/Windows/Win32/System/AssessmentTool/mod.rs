@@ -56,6 +56,24 @@ impl IAccessibleWinSAT_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant", feature = "Win32_UI_Accessibility"))]
+impl<Identity: windows_core::IUnknownImpl + IAccessibleWinSAT_Impl> windows_core::imp::TearOffVtable<Identity> for IAccessibleWinSAT_Vtbl
+where
+    super::super::UI::Accessibility::IAccessible_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAccessiblityData<Identity: windows_core::IUnknownImpl + IAccessibleWinSAT_Impl>(this: *mut core::ffi::c_void, wsname: windows_core::PCWSTR, wsvalue: windows_core::PCWSTR, wsdesc: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAccessibleWinSAT_Impl::SetAccessiblityData(this, core::mem::transmute(&wsname), core::mem::transmute(&wsvalue), core::mem::transmute(&wsdesc)).into()
+            }
+        }
+        Self {
+            base__: <super::super::UI::Accessibility::IAccessible_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAccessiblityData: SetAccessiblityData::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant", feature = "Win32_UI_Accessibility"))]
 impl windows_core::RuntimeName for IAccessibleWinSAT {}
 windows_core::imp::define_interface!(IInitiateWinSATAssessment, IInitiateWinSATAssessment_Vtbl, 0xd983fc50_f5bf_49d5_b5ed_cccb18aa7fc1);
 windows_core::imp::interface_hierarchy!(IInitiateWinSATAssessment, windows_core::IUnknown);
@@ -121,6 +139,37 @@ impl IInitiateWinSATAssessment_Vtbl {
         iid == &<IInitiateWinSATAssessment as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInitiateWinSATAssessment_Impl> windows_core::imp::TearOffVtable<Identity> for IInitiateWinSATAssessment_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InitiateAssessment<Identity: windows_core::IUnknownImpl + IInitiateWinSATAssessment_Impl>(this: *mut core::ffi::c_void, cmdline: windows_core::PCWSTR, pcallbacks: *mut core::ffi::c_void, callerhwnd: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInitiateWinSATAssessment_Impl::InitiateAssessment(this, core::mem::transmute(&cmdline), core::mem::transmute_copy(&pcallbacks), core::mem::transmute_copy(&callerhwnd)).into()
+            }
+        }
+        unsafe extern "system" fn InitiateFormalAssessment<Identity: windows_core::IUnknownImpl + IInitiateWinSATAssessment_Impl>(this: *mut core::ffi::c_void, pcallbacks: *mut core::ffi::c_void, callerhwnd: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInitiateWinSATAssessment_Impl::InitiateFormalAssessment(this, core::mem::transmute_copy(&pcallbacks), core::mem::transmute_copy(&callerhwnd)).into()
+            }
+        }
+        unsafe extern "system" fn CancelAssessment<Identity: windows_core::IUnknownImpl + IInitiateWinSATAssessment_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInitiateWinSATAssessment_Impl::CancelAssessment(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InitiateAssessment: InitiateAssessment::<Identity>,
+            InitiateFormalAssessment: InitiateFormalAssessment::<Identity>,
+            CancelAssessment: CancelAssessment::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IInitiateWinSATAssessment {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IProvideWinSATAssessmentInfo, IProvideWinSATAssessmentInfo_Vtbl, 0x0cd1c380_52d3_4678_ac6f_e929e480be9e);
@@ -220,6 +269,56 @@ impl IProvideWinSATAssessmentInfo_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IProvideWinSATAssessmentInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IProvideWinSATAssessmentInfo_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Score<Identity: windows_core::IUnknownImpl + IProvideWinSATAssessmentInfo_Impl>(this: *mut core::ffi::c_void, score: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProvideWinSATAssessmentInfo_Impl::Score(this) {
+                    Ok(ok__) => {
+                        score.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Title<Identity: windows_core::IUnknownImpl + IProvideWinSATAssessmentInfo_Impl>(this: *mut core::ffi::c_void, title: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProvideWinSATAssessmentInfo_Impl::Title(this) {
+                    Ok(ok__) => {
+                        title.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Description<Identity: windows_core::IUnknownImpl + IProvideWinSATAssessmentInfo_Impl>(this: *mut core::ffi::c_void, description: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProvideWinSATAssessmentInfo_Impl::Description(this) {
+                    Ok(ok__) => {
+                        description.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Score: Score::<Identity>,
+            Title: Title::<Identity>,
+            Description: Description::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IProvideWinSATAssessmentInfo {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IProvideWinSATResultsInfo, IProvideWinSATResultsInfo_Vtbl, 0xf8334d5d_568e_4075_875f_9df341506640);
@@ -365,6 +464,82 @@ impl IProvideWinSATResultsInfo_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IProvideWinSATResultsInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IProvideWinSATResultsInfo_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAssessmentInfo<Identity: windows_core::IUnknownImpl + IProvideWinSATResultsInfo_Impl>(this: *mut core::ffi::c_void, assessment: WINSAT_ASSESSMENT_TYPE, ppinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProvideWinSATResultsInfo_Impl::GetAssessmentInfo(this, core::mem::transmute_copy(&assessment)) {
+                    Ok(ok__) => {
+                        ppinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AssessmentState<Identity: windows_core::IUnknownImpl + IProvideWinSATResultsInfo_Impl>(this: *mut core::ffi::c_void, state: *mut WINSAT_ASSESSMENT_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProvideWinSATResultsInfo_Impl::AssessmentState(this) {
+                    Ok(ok__) => {
+                        state.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AssessmentDateTime<Identity: windows_core::IUnknownImpl + IProvideWinSATResultsInfo_Impl>(this: *mut core::ffi::c_void, filetime: *mut super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProvideWinSATResultsInfo_Impl::AssessmentDateTime(this) {
+                    Ok(ok__) => {
+                        filetime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SystemRating<Identity: windows_core::IUnknownImpl + IProvideWinSATResultsInfo_Impl>(this: *mut core::ffi::c_void, level: *mut f32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProvideWinSATResultsInfo_Impl::SystemRating(this) {
+                    Ok(ok__) => {
+                        level.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RatingStateDesc<Identity: windows_core::IUnknownImpl + IProvideWinSATResultsInfo_Impl>(this: *mut core::ffi::c_void, description: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProvideWinSATResultsInfo_Impl::RatingStateDesc(this) {
+                    Ok(ok__) => {
+                        description.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAssessmentInfo: GetAssessmentInfo::<Identity>,
+            AssessmentState: AssessmentState::<Identity>,
+            AssessmentDateTime: AssessmentDateTime::<Identity>,
+            SystemRating: SystemRating::<Identity>,
+            RatingStateDesc: RatingStateDesc::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IProvideWinSATResultsInfo {}
 windows_core::imp::define_interface!(IProvideWinSATVisuals, IProvideWinSATVisuals_Vtbl, 0xa9f4ade0_871a_42a3_b813_3078d25162c9);
 windows_core::imp::interface_hierarchy!(IProvideWinSATVisuals, windows_core::IUnknown);
@@ -412,6 +587,27 @@ impl IProvideWinSATVisuals_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Graphics_Gdi")]
+impl<Identity: windows_core::IUnknownImpl + IProvideWinSATVisuals_Impl> windows_core::imp::TearOffVtable<Identity> for IProvideWinSATVisuals_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn get_Bitmap<Identity: windows_core::IUnknownImpl + IProvideWinSATVisuals_Impl>(this: *mut core::ffi::c_void, bitmapsize: WINSAT_BITMAP_SIZE, state: WINSAT_ASSESSMENT_STATE, rating: f32, pbitmap: *mut super::super::Graphics::Gdi::HBITMAP) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProvideWinSATVisuals_Impl::get_Bitmap(this, core::mem::transmute_copy(&bitmapsize), core::mem::transmute_copy(&state), core::mem::transmute_copy(&rating)) {
+                    Ok(ok__) => {
+                        pbitmap.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, get_Bitmap: get_Bitmap::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
 impl windows_core::RuntimeName for IProvideWinSATVisuals {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IQueryAllWinSATAssessments, IQueryAllWinSATAssessments_Vtbl, 0x0b89ed1d_6398_4fea_87fc_567d8d19176f);
@@ -470,6 +666,27 @@ impl IQueryAllWinSATAssessments_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IQueryAllWinSATAssessments_Impl> windows_core::imp::TearOffVtable<Identity> for IQueryAllWinSATAssessments_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn get_AllXML<Identity: windows_core::IUnknownImpl + IQueryAllWinSATAssessments_Impl>(this: *mut core::ffi::c_void, xpath: *mut core::ffi::c_void, namespaces: *mut core::ffi::c_void, ppdomnodelist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IQueryAllWinSATAssessments_Impl::get_AllXML(this, core::mem::transmute(&xpath), core::mem::transmute(&namespaces)) {
+                    Ok(ok__) => {
+                        ppdomnodelist.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, get_AllXML: get_AllXML::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IQueryAllWinSATAssessments {}
 windows_core::imp::define_interface!(IQueryOEMWinSATCustomization, IQueryOEMWinSATCustomization_Vtbl, 0xbc9a6a9f_ad4e_420e_9953_b34671e9df22);
 windows_core::imp::interface_hierarchy!(IQueryOEMWinSATCustomization, windows_core::IUnknown);
@@ -510,6 +727,29 @@ impl IQueryOEMWinSATCustomization_Vtbl {
         iid == &<IQueryOEMWinSATCustomization as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IQueryOEMWinSATCustomization_Impl> windows_core::imp::TearOffVtable<Identity> for IQueryOEMWinSATCustomization_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetOEMPrePopulationInfo<Identity: windows_core::IUnknownImpl + IQueryOEMWinSATCustomization_Impl>(this: *mut core::ffi::c_void, state: *mut WINSAT_OEM_CUSTOMIZATION_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IQueryOEMWinSATCustomization_Impl::GetOEMPrePopulationInfo(this) {
+                    Ok(ok__) => {
+                        state.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetOEMPrePopulationInfo: GetOEMPrePopulationInfo::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IQueryOEMWinSATCustomization {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IQueryRecentWinSATAssessment, IQueryRecentWinSATAssessment_Vtbl, 0xf8ad5d1f_3b47_4bdc_9375_7c6b1da4eca7);
@@ -588,6 +828,39 @@ impl IQueryRecentWinSATAssessment_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IQueryRecentWinSATAssessment_Impl> windows_core::imp::TearOffVtable<Identity> for IQueryRecentWinSATAssessment_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn get_XML<Identity: windows_core::IUnknownImpl + IQueryRecentWinSATAssessment_Impl>(this: *mut core::ffi::c_void, xpath: *mut core::ffi::c_void, namespaces: *mut core::ffi::c_void, ppdomnodelist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IQueryRecentWinSATAssessment_Impl::get_XML(this, core::mem::transmute(&xpath), core::mem::transmute(&namespaces)) {
+                    Ok(ok__) => {
+                        ppdomnodelist.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Info<Identity: windows_core::IUnknownImpl + IQueryRecentWinSATAssessment_Impl>(this: *mut core::ffi::c_void, ppwinsatassessmentinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IQueryRecentWinSATAssessment_Impl::Info(this) {
+                    Ok(ok__) => {
+                        ppwinsatassessmentinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, get_XML: get_XML::<Identity>, Info: Info::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IQueryRecentWinSATAssessment {}
 windows_core::imp::define_interface!(IWinSATInitiateEvents, IWinSATInitiateEvents_Vtbl, 0x262a1918_ba0d_41d5_92c2_fab4633ee74f);
 windows_core::imp::interface_hierarchy!(IWinSATInitiateEvents, windows_core::IUnknown);
@@ -640,6 +913,30 @@ impl IWinSATInitiateEvents_Vtbl {
         iid == &<IWinSATInitiateEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWinSATInitiateEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IWinSATInitiateEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn WinSATComplete<Identity: windows_core::IUnknownImpl + IWinSATInitiateEvents_Impl>(this: *mut core::ffi::c_void, hresult: windows_core::HRESULT, strdescription: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinSATInitiateEvents_Impl::WinSATComplete(this, core::mem::transmute_copy(&hresult), core::mem::transmute(&strdescription)).into()
+            }
+        }
+        unsafe extern "system" fn WinSATUpdate<Identity: windows_core::IUnknownImpl + IWinSATInitiateEvents_Impl>(this: *mut core::ffi::c_void, ucurrenttick: u32, uticktotal: u32, strcurrentstate: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinSATInitiateEvents_Impl::WinSATUpdate(this, core::mem::transmute_copy(&ucurrenttick), core::mem::transmute_copy(&uticktotal), core::mem::transmute(&strcurrentstate)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            WinSATComplete: WinSATComplete::<Identity>,
+            WinSATUpdate: WinSATUpdate::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWinSATInitiateEvents {}
 pub const WINSAT_ASSESSMENT_CPU: WINSAT_ASSESSMENT_TYPE = WINSAT_ASSESSMENT_TYPE(1i32);
 pub const WINSAT_ASSESSMENT_D3D: WINSAT_ASSESSMENT_TYPE = WINSAT_ASSESSMENT_TYPE(3i32);
@@ -456,6 +456,20 @@ impl IActionOnCLREvent_Vtbl {
         iid == &<IActionOnCLREvent as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActionOnCLREvent_Impl> windows_core::imp::TearOffVtable<Identity> for IActionOnCLREvent_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnEvent<Identity: windows_core::IUnknownImpl + IActionOnCLREvent_Impl>(this: *mut core::ffi::c_void, event: EClrEvent, data: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActionOnCLREvent_Impl::OnEvent(this, core::mem::transmute_copy(&event), core::mem::transmute_copy(&data)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnEvent: OnEvent::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IActionOnCLREvent {}
 windows_core::imp::define_interface!(IApartmentCallback, IApartmentCallback_Vtbl, 0x178e5337_1528_4591_b1c9_1c6e484686d8);
 windows_core::imp::interface_hierarchy!(IApartmentCallback, windows_core::IUnknown);
@@ -487,6 +501,20 @@ impl IApartmentCallback_Vtbl {
         iid == &<IApartmentCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IApartmentCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IApartmentCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DoCallback<Identity: windows_core::IUnknownImpl + IApartmentCallback_Impl>(this: *mut core::ffi::c_void, pfunc: usize, pdata: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IApartmentCallback_Impl::DoCallback(this, core::mem::transmute_copy(&pfunc), core::mem::transmute_copy(&pdata)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, DoCallback: DoCallback::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IApartmentCallback {}
 windows_core::imp::define_interface!(IAppDomainBinding, IAppDomainBinding_Vtbl, 0x5c2b07a7_1e98_11d3_872f_00c04f79ed0d);
 windows_core::imp::interface_hierarchy!(IAppDomainBinding, windows_core::IUnknown);
@@ -521,6 +549,20 @@ impl IAppDomainBinding_Vtbl {
         iid == &<IAppDomainBinding as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAppDomainBinding_Impl> windows_core::imp::TearOffVtable<Identity> for IAppDomainBinding_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnAppDomain<Identity: windows_core::IUnknownImpl + IAppDomainBinding_Impl>(this: *mut core::ffi::c_void, pappdomain: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAppDomainBinding_Impl::OnAppDomain(this, core::mem::transmute_copy(&pappdomain)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnAppDomain: OnAppDomain::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IAppDomainBinding {}
 windows_core::imp::define_interface!(ICLRAppDomainResourceMonitor, ICLRAppDomainResourceMonitor_Vtbl, 0xc62de18c_2e23_4aea_8423_b40c1fc59eae);
 windows_core::imp::interface_hierarchy!(ICLRAppDomainResourceMonitor, windows_core::IUnknown);
@@ -579,6 +621,37 @@ impl ICLRAppDomainResourceMonitor_Vtbl {
         iid == &<ICLRAppDomainResourceMonitor as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLRAppDomainResourceMonitor_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRAppDomainResourceMonitor_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrentAllocated<Identity: windows_core::IUnknownImpl + ICLRAppDomainResourceMonitor_Impl>(this: *mut core::ffi::c_void, dwappdomainid: u32, pbytesallocated: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRAppDomainResourceMonitor_Impl::GetCurrentAllocated(this, core::mem::transmute_copy(&dwappdomainid), core::mem::transmute_copy(&pbytesallocated)).into()
+            }
+        }
+        unsafe extern "system" fn GetCurrentSurvived<Identity: windows_core::IUnknownImpl + ICLRAppDomainResourceMonitor_Impl>(this: *mut core::ffi::c_void, dwappdomainid: u32, pappdomainbytessurvived: *mut u64, ptotalbytessurvived: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRAppDomainResourceMonitor_Impl::GetCurrentSurvived(this, core::mem::transmute_copy(&dwappdomainid), core::mem::transmute_copy(&pappdomainbytessurvived), core::mem::transmute_copy(&ptotalbytessurvived)).into()
+            }
+        }
+        unsafe extern "system" fn GetCurrentCpuTime<Identity: windows_core::IUnknownImpl + ICLRAppDomainResourceMonitor_Impl>(this: *mut core::ffi::c_void, dwappdomainid: u32, pmilliseconds: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRAppDomainResourceMonitor_Impl::GetCurrentCpuTime(this, core::mem::transmute_copy(&dwappdomainid), core::mem::transmute_copy(&pmilliseconds)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrentAllocated: GetCurrentAllocated::<Identity>,
+            GetCurrentSurvived: GetCurrentSurvived::<Identity>,
+            GetCurrentCpuTime: GetCurrentCpuTime::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICLRAppDomainResourceMonitor {}
 windows_core::imp::define_interface!(ICLRAssemblyIdentityManager, ICLRAssemblyIdentityManager_Vtbl, 0x15f0a9da_3ff6_4393_9da9_fdfd284e6972);
 windows_core::imp::interface_hierarchy!(ICLRAssemblyIdentityManager, windows_core::IUnknown);
@@ -761,6 +834,96 @@ impl ICLRAssemblyIdentityManager_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + ICLRAssemblyIdentityManager_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRAssemblyIdentityManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCLRAssemblyReferenceList<Identity: windows_core::IUnknownImpl + ICLRAssemblyIdentityManager_Impl>(this: *mut core::ffi::c_void, ppwzassemblyreferences: *const windows_core::PCWSTR, dwnumofreferences: u32, ppreferencelist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRAssemblyIdentityManager_Impl::GetCLRAssemblyReferenceList(this, core::mem::transmute_copy(&ppwzassemblyreferences), core::mem::transmute_copy(&dwnumofreferences)) {
+                    Ok(ok__) => {
+                        ppreferencelist.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetBindingIdentityFromFile<Identity: windows_core::IUnknownImpl + ICLRAssemblyIdentityManager_Impl>(this: *mut core::ffi::c_void, pwzfilepath: windows_core::PCWSTR, dwflags: u32, pwzbuffer: windows_core::PWSTR, pcchbuffersize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRAssemblyIdentityManager_Impl::GetBindingIdentityFromFile(this, core::mem::transmute(&pwzfilepath), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pwzbuffer), core::mem::transmute_copy(&pcchbuffersize)).into()
+            }
+        }
+        unsafe extern "system" fn GetBindingIdentityFromStream<Identity: windows_core::IUnknownImpl + ICLRAssemblyIdentityManager_Impl>(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, dwflags: u32, pwzbuffer: windows_core::PWSTR, pcchbuffersize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRAssemblyIdentityManager_Impl::GetBindingIdentityFromStream(this, core::mem::transmute_copy(&pstream), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pwzbuffer), core::mem::transmute_copy(&pcchbuffersize)).into()
+            }
+        }
+        unsafe extern "system" fn GetReferencedAssembliesFromFile<Identity: windows_core::IUnknownImpl + ICLRAssemblyIdentityManager_Impl>(this: *mut core::ffi::c_void, pwzfilepath: windows_core::PCWSTR, dwflags: u32, pexcludeassemblieslist: *mut core::ffi::c_void, ppreferenceenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRAssemblyIdentityManager_Impl::GetReferencedAssembliesFromFile(this, core::mem::transmute(&pwzfilepath), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pexcludeassemblieslist)) {
+                    Ok(ok__) => {
+                        ppreferenceenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetReferencedAssembliesFromStream<Identity: windows_core::IUnknownImpl + ICLRAssemblyIdentityManager_Impl>(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, dwflags: u32, pexcludeassemblieslist: *mut core::ffi::c_void, ppreferenceenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRAssemblyIdentityManager_Impl::GetReferencedAssembliesFromStream(this, core::mem::transmute_copy(&pstream), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pexcludeassemblieslist)) {
+                    Ok(ok__) => {
+                        ppreferenceenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetProbingAssembliesFromReference<Identity: windows_core::IUnknownImpl + ICLRAssemblyIdentityManager_Impl>(this: *mut core::ffi::c_void, dwmachinetype: u32, dwflags: u32, pwzreferenceidentity: windows_core::PCWSTR, ppprobingassemblyenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRAssemblyIdentityManager_Impl::GetProbingAssembliesFromReference(this, core::mem::transmute_copy(&dwmachinetype), core::mem::transmute_copy(&dwflags), core::mem::transmute(&pwzreferenceidentity)) {
+                    Ok(ok__) => {
+                        ppprobingassemblyenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsStronglyNamed<Identity: windows_core::IUnknownImpl + ICLRAssemblyIdentityManager_Impl>(this: *mut core::ffi::c_void, pwzassemblyidentity: windows_core::PCWSTR, pbisstronglynamed: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRAssemblyIdentityManager_Impl::IsStronglyNamed(this, core::mem::transmute(&pwzassemblyidentity)) {
+                    Ok(ok__) => {
+                        pbisstronglynamed.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCLRAssemblyReferenceList: GetCLRAssemblyReferenceList::<Identity>,
+            GetBindingIdentityFromFile: GetBindingIdentityFromFile::<Identity>,
+            GetBindingIdentityFromStream: GetBindingIdentityFromStream::<Identity>,
+            GetReferencedAssembliesFromFile: GetReferencedAssembliesFromFile::<Identity>,
+            GetReferencedAssembliesFromStream: GetReferencedAssembliesFromStream::<Identity>,
+            GetProbingAssembliesFromReference: GetProbingAssembliesFromReference::<Identity>,
+            IsStronglyNamed: IsStronglyNamed::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for ICLRAssemblyIdentityManager {}
 windows_core::imp::define_interface!(ICLRAssemblyReferenceList, ICLRAssemblyReferenceList_Vtbl, 0x1b2c9750_2e66_4bda_8b44_0a642c5cd733);
 windows_core::imp::interface_hierarchy!(ICLRAssemblyReferenceList, windows_core::IUnknown);
@@ -813,6 +976,30 @@ impl ICLRAssemblyReferenceList_Vtbl {
         iid == &<ICLRAssemblyReferenceList as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLRAssemblyReferenceList_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRAssemblyReferenceList_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsStringAssemblyReferenceInList<Identity: windows_core::IUnknownImpl + ICLRAssemblyReferenceList_Impl>(this: *mut core::ffi::c_void, pwzassemblyname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRAssemblyReferenceList_Impl::IsStringAssemblyReferenceInList(this, core::mem::transmute(&pwzassemblyname)).into()
+            }
+        }
+        unsafe extern "system" fn IsAssemblyReferenceInList<Identity: windows_core::IUnknownImpl + ICLRAssemblyReferenceList_Impl>(this: *mut core::ffi::c_void, pname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRAssemblyReferenceList_Impl::IsAssemblyReferenceInList(this, core::mem::transmute_copy(&pname)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsStringAssemblyReferenceInList: IsStringAssemblyReferenceInList::<Identity>,
+            IsAssemblyReferenceInList: IsAssemblyReferenceInList::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICLRAssemblyReferenceList {}
 windows_core::imp::define_interface!(ICLRControl, ICLRControl_Vtbl, 0x9065597e_d1a1_4fb2_b6ba_7e1fce230f61);
 windows_core::imp::interface_hierarchy!(ICLRControl, windows_core::IUnknown);
@@ -863,6 +1050,30 @@ impl ICLRControl_Vtbl {
         iid == &<ICLRControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLRControl_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCLRManager<Identity: windows_core::IUnknownImpl + ICLRControl_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRControl_Impl::GetCLRManager(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppobject)).into()
+            }
+        }
+        unsafe extern "system" fn SetAppDomainManagerType<Identity: windows_core::IUnknownImpl + ICLRControl_Impl>(this: *mut core::ffi::c_void, pwzappdomainmanagerassembly: windows_core::PCWSTR, pwzappdomainmanagertype: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRControl_Impl::SetAppDomainManagerType(this, core::mem::transmute(&pwzappdomainmanagerassembly), core::mem::transmute(&pwzappdomainmanagertype)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCLRManager: GetCLRManager::<Identity>,
+            SetAppDomainManagerType: SetAppDomainManagerType::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICLRControl {}
 windows_core::imp::define_interface!(ICLRDebugManager, ICLRDebugManager_Vtbl, 0x00dcaec6_2ac0_43a9_acf9_1e36c139b10d);
 windows_core::imp::interface_hierarchy!(ICLRDebugManager, windows_core::IUnknown);
@@ -1001,6 +1212,78 @@ impl ICLRDebugManager_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Security")]
+impl<Identity: windows_core::IUnknownImpl + ICLRDebugManager_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRDebugManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BeginConnection<Identity: windows_core::IUnknownImpl + ICLRDebugManager_Impl>(this: *mut core::ffi::c_void, dwconnectionid: u32, szconnectionname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRDebugManager_Impl::BeginConnection(this, core::mem::transmute_copy(&dwconnectionid), core::mem::transmute(&szconnectionname)).into()
+            }
+        }
+        unsafe extern "system" fn SetConnectionTasks<Identity: windows_core::IUnknownImpl + ICLRDebugManager_Impl>(this: *mut core::ffi::c_void, id: u32, dwcount: u32, ppclrtask: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRDebugManager_Impl::SetConnectionTasks(this, core::mem::transmute_copy(&id), core::mem::transmute_copy(&dwcount), core::mem::transmute_copy(&ppclrtask)).into()
+            }
+        }
+        unsafe extern "system" fn EndConnection<Identity: windows_core::IUnknownImpl + ICLRDebugManager_Impl>(this: *mut core::ffi::c_void, dwconnectionid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRDebugManager_Impl::EndConnection(this, core::mem::transmute_copy(&dwconnectionid)).into()
+            }
+        }
+        unsafe extern "system" fn SetDacl<Identity: windows_core::IUnknownImpl + ICLRDebugManager_Impl>(this: *mut core::ffi::c_void, pacl: *const super::super::Security::ACL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRDebugManager_Impl::SetDacl(this, core::mem::transmute_copy(&pacl)).into()
+            }
+        }
+        unsafe extern "system" fn GetDacl<Identity: windows_core::IUnknownImpl + ICLRDebugManager_Impl>(this: *mut core::ffi::c_void, pacl: *mut *mut super::super::Security::ACL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRDebugManager_Impl::GetDacl(this) {
+                    Ok(ok__) => {
+                        pacl.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsDebuggerAttached<Identity: windows_core::IUnknownImpl + ICLRDebugManager_Impl>(this: *mut core::ffi::c_void, pbattached: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRDebugManager_Impl::IsDebuggerAttached(this) {
+                    Ok(ok__) => {
+                        pbattached.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSymbolReadingPolicy<Identity: windows_core::IUnknownImpl + ICLRDebugManager_Impl>(this: *mut core::ffi::c_void, policy: ESymbolReadingPolicy) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRDebugManager_Impl::SetSymbolReadingPolicy(this, core::mem::transmute_copy(&policy)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            BeginConnection: BeginConnection::<Identity>,
+            SetConnectionTasks: SetConnectionTasks::<Identity>,
+            EndConnection: EndConnection::<Identity>,
+            SetDacl: SetDacl::<Identity>,
+            GetDacl: GetDacl::<Identity>,
+            IsDebuggerAttached: IsDebuggerAttached::<Identity>,
+            SetSymbolReadingPolicy: SetSymbolReadingPolicy::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Security")]
 impl windows_core::RuntimeName for ICLRDebugManager {}
 windows_core::imp::define_interface!(ICLRDebugging, ICLRDebugging_Vtbl, 0xd28f3c5a_9634_4206_a509_477552eefb10);
 windows_core::imp::interface_hierarchy!(ICLRDebugging, windows_core::IUnknown);
@@ -1051,6 +1334,30 @@ impl ICLRDebugging_Vtbl {
         iid == &<ICLRDebugging as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLRDebugging_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRDebugging_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OpenVirtualProcess<Identity: windows_core::IUnknownImpl + ICLRDebugging_Impl>(this: *mut core::ffi::c_void, modulebaseaddress: u64, pdatatarget: *mut core::ffi::c_void, plibraryprovider: *mut core::ffi::c_void, pmaxdebuggersupportedversion: *const CLR_DEBUGGING_VERSION, riidprocess: *const windows_core::GUID, ppprocess: *mut *mut core::ffi::c_void, pversion: *mut CLR_DEBUGGING_VERSION, pdwflags: *mut CLR_DEBUGGING_PROCESS_FLAGS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRDebugging_Impl::OpenVirtualProcess(this, core::mem::transmute_copy(&modulebaseaddress), core::mem::transmute_copy(&pdatatarget), core::mem::transmute_copy(&plibraryprovider), core::mem::transmute_copy(&pmaxdebuggersupportedversion), core::mem::transmute_copy(&riidprocess), core::mem::transmute_copy(&ppprocess), core::mem::transmute_copy(&pversion), core::mem::transmute_copy(&pdwflags)).into()
+            }
+        }
+        unsafe extern "system" fn CanUnloadNow<Identity: windows_core::IUnknownImpl + ICLRDebugging_Impl>(this: *mut core::ffi::c_void, hmodule: super::super::Foundation::HMODULE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRDebugging_Impl::CanUnloadNow(this, core::mem::transmute_copy(&hmodule)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OpenVirtualProcess: OpenVirtualProcess::<Identity>,
+            CanUnloadNow: CanUnloadNow::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICLRDebugging {}
 windows_core::imp::define_interface!(ICLRDebuggingLibraryProvider, ICLRDebuggingLibraryProvider_Vtbl, 0x3151c08d_4d09_4f9b_8838_2880bf18fe51);
 windows_core::imp::interface_hierarchy!(ICLRDebuggingLibraryProvider, windows_core::IUnknown);
@@ -1094,6 +1401,26 @@ impl ICLRDebuggingLibraryProvider_Vtbl {
         iid == &<ICLRDebuggingLibraryProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLRDebuggingLibraryProvider_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRDebuggingLibraryProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ProvideLibrary<Identity: windows_core::IUnknownImpl + ICLRDebuggingLibraryProvider_Impl>(this: *mut core::ffi::c_void, pwszfilename: windows_core::PCWSTR, dwtimestamp: u32, dwsizeofimage: u32, phmodule: *mut super::super::Foundation::HMODULE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRDebuggingLibraryProvider_Impl::ProvideLibrary(this, core::mem::transmute(&pwszfilename), core::mem::transmute_copy(&dwtimestamp), core::mem::transmute_copy(&dwsizeofimage)) {
+                    Ok(ok__) => {
+                        phmodule.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ProvideLibrary: ProvideLibrary::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ICLRDebuggingLibraryProvider {}
 windows_core::imp::define_interface!(ICLRDomainManager, ICLRDomainManager_Vtbl, 0x270d00a2_8e15_4d0b_adeb_37bc3e47df77);
 windows_core::imp::interface_hierarchy!(ICLRDomainManager, windows_core::IUnknown);
@@ -1144,6 +1471,30 @@ impl ICLRDomainManager_Vtbl {
         iid == &<ICLRDomainManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLRDomainManager_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRDomainManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetAppDomainManagerType<Identity: windows_core::IUnknownImpl + ICLRDomainManager_Impl>(this: *mut core::ffi::c_void, wszappdomainmanagerassembly: windows_core::PCWSTR, wszappdomainmanagertype: windows_core::PCWSTR, dwinitializedomainflags: EInitializeNewDomainFlags) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRDomainManager_Impl::SetAppDomainManagerType(this, core::mem::transmute(&wszappdomainmanagerassembly), core::mem::transmute(&wszappdomainmanagertype), core::mem::transmute_copy(&dwinitializedomainflags)).into()
+            }
+        }
+        unsafe extern "system" fn SetPropertiesForDefaultAppDomain<Identity: windows_core::IUnknownImpl + ICLRDomainManager_Impl>(this: *mut core::ffi::c_void, nproperties: u32, pwszpropertynames: *const windows_core::PCWSTR, pwszpropertyvalues: *const windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRDomainManager_Impl::SetPropertiesForDefaultAppDomain(this, core::mem::transmute_copy(&nproperties), core::mem::transmute_copy(&pwszpropertynames), core::mem::transmute_copy(&pwszpropertyvalues)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetAppDomainManagerType: SetAppDomainManagerType::<Identity>,
+            SetPropertiesForDefaultAppDomain: SetPropertiesForDefaultAppDomain::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICLRDomainManager {}
 windows_core::imp::define_interface!(ICLRErrorReportingManager, ICLRErrorReportingManager_Vtbl, 0x980d2f1a_bf79_4c08_812a_bb9778928f78);
 windows_core::imp::interface_hierarchy!(ICLRErrorReportingManager, windows_core::IUnknown);
@@ -1202,6 +1553,37 @@ impl ICLRErrorReportingManager_Vtbl {
         iid == &<ICLRErrorReportingManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLRErrorReportingManager_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRErrorReportingManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetBucketParametersForCurrentException<Identity: windows_core::IUnknownImpl + ICLRErrorReportingManager_Impl>(this: *mut core::ffi::c_void, pparams: *mut BucketParameters) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRErrorReportingManager_Impl::GetBucketParametersForCurrentException(this, core::mem::transmute_copy(&pparams)).into()
+            }
+        }
+        unsafe extern "system" fn BeginCustomDump<Identity: windows_core::IUnknownImpl + ICLRErrorReportingManager_Impl>(this: *mut core::ffi::c_void, dwflavor: ECustomDumpFlavor, dwnumitems: u32, items: *const CustomDumpItem, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRErrorReportingManager_Impl::BeginCustomDump(this, core::mem::transmute_copy(&dwflavor), core::mem::transmute_copy(&dwnumitems), core::mem::transmute_copy(&items), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn EndCustomDump<Identity: windows_core::IUnknownImpl + ICLRErrorReportingManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRErrorReportingManager_Impl::EndCustomDump(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetBucketParametersForCurrentException: GetBucketParametersForCurrentException::<Identity>,
+            BeginCustomDump: BeginCustomDump::<Identity>,
+            EndCustomDump: EndCustomDump::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICLRErrorReportingManager {}
 windows_core::imp::define_interface!(ICLRGCManager, ICLRGCManager_Vtbl, 0x54d9007e_a8e2_4885_b7bf_f998deee4f2a);
 windows_core::imp::interface_hierarchy!(ICLRGCManager, windows_core::IUnknown);
@@ -1260,6 +1642,37 @@ impl ICLRGCManager_Vtbl {
         iid == &<ICLRGCManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLRGCManager_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRGCManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Collect<Identity: windows_core::IUnknownImpl + ICLRGCManager_Impl>(this: *mut core::ffi::c_void, generation: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRGCManager_Impl::Collect(this, core::mem::transmute_copy(&generation)).into()
+            }
+        }
+        unsafe extern "system" fn GetStats<Identity: windows_core::IUnknownImpl + ICLRGCManager_Impl>(this: *mut core::ffi::c_void, pstats: *mut COR_GC_STATS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRGCManager_Impl::GetStats(this, core::mem::transmute_copy(&pstats)).into()
+            }
+        }
+        unsafe extern "system" fn SetGCStartupLimits<Identity: windows_core::IUnknownImpl + ICLRGCManager_Impl>(this: *mut core::ffi::c_void, segmentsize: u32, maxgen0size: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRGCManager_Impl::SetGCStartupLimits(this, core::mem::transmute_copy(&segmentsize), core::mem::transmute_copy(&maxgen0size)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Collect: Collect::<Identity>,
+            GetStats: GetStats::<Identity>,
+            SetGCStartupLimits: SetGCStartupLimits::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICLRGCManager {}
 windows_core::imp::define_interface!(ICLRGCManager2, ICLRGCManager2_Vtbl, 0x0603b793_a97a_4712_9cb4_0cd1c74c0f7c);
 impl core::ops::Deref for ICLRGCManager2 {
@@ -1297,6 +1710,20 @@ impl ICLRGCManager2_Vtbl {
         iid == &<ICLRGCManager2 as windows_core::Interface>::IID || iid == &<ICLRGCManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLRGCManager2_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRGCManager2_Vtbl
+where
+    ICLRGCManager_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetGCStartupLimitsEx<Identity: windows_core::IUnknownImpl + ICLRGCManager2_Impl>(this: *mut core::ffi::c_void, segmentsize: usize, maxgen0size: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRGCManager2_Impl::SetGCStartupLimitsEx(this, core::mem::transmute_copy(&segmentsize), core::mem::transmute_copy(&maxgen0size)).into()
+            }
+        }
+        Self { base__: <ICLRGCManager_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetGCStartupLimitsEx: SetGCStartupLimitsEx::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ICLRGCManager2 {}
 windows_core::imp::define_interface!(ICLRHostBindingPolicyManager, ICLRHostBindingPolicyManager_Vtbl, 0x4b3545e7_1856_48c9_a8ba_24b21a753c09);
 windows_core::imp::interface_hierarchy!(ICLRHostBindingPolicyManager, windows_core::IUnknown);
@@ -1350,8 +1777,32 @@ impl ICLRHostBindingPolicyManager_Vtbl {
         iid == &<ICLRHostBindingPolicyManager as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for ICLRHostBindingPolicyManager {}
-windows_core::imp::define_interface!(ICLRHostProtectionManager, ICLRHostProtectionManager_Vtbl, 0x89f25f5c_ceef_43e1_9cfa_a68ce863aaac);
+impl<Identity: windows_core::IUnknownImpl + ICLRHostBindingPolicyManager_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRHostBindingPolicyManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ModifyApplicationPolicy<Identity: windows_core::IUnknownImpl + ICLRHostBindingPolicyManager_Impl>(this: *mut core::ffi::c_void, pwzsourceassemblyidentity: windows_core::PCWSTR, pwztargetassemblyidentity: windows_core::PCWSTR, pbapplicationpolicy: *const u8, cbapppolicysize: u32, dwpolicymodifyflags: u32, pbnewapplicationpolicy: *mut u8, pcbnewapppolicysize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRHostBindingPolicyManager_Impl::ModifyApplicationPolicy(this, core::mem::transmute(&pwzsourceassemblyidentity), core::mem::transmute(&pwztargetassemblyidentity), core::mem::transmute_copy(&pbapplicationpolicy), core::mem::transmute_copy(&cbapppolicysize), core::mem::transmute_copy(&dwpolicymodifyflags), core::mem::transmute_copy(&pbnewapplicationpolicy), core::mem::transmute_copy(&pcbnewapppolicysize)).into()
+            }
+        }
+        unsafe extern "system" fn EvaluatePolicy<Identity: windows_core::IUnknownImpl + ICLRHostBindingPolicyManager_Impl>(this: *mut core::ffi::c_void, pwzreferenceidentity: windows_core::PCWSTR, pbapplicationpolicy: *const u8, cbapppolicysize: u32, pwzpostpolicyreferenceidentity: windows_core::PWSTR, pcchpostpolicyreferenceidentity: *mut u32, pdwpoliciesapplied: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRHostBindingPolicyManager_Impl::EvaluatePolicy(this, core::mem::transmute(&pwzreferenceidentity), core::mem::transmute_copy(&pbapplicationpolicy), core::mem::transmute_copy(&cbapppolicysize), core::mem::transmute_copy(&pwzpostpolicyreferenceidentity), core::mem::transmute_copy(&pcchpostpolicyreferenceidentity), core::mem::transmute_copy(&pdwpoliciesapplied)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ModifyApplicationPolicy: ModifyApplicationPolicy::<Identity>,
+            EvaluatePolicy: EvaluatePolicy::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for ICLRHostBindingPolicyManager {}
+windows_core::imp::define_interface!(ICLRHostProtectionManager, ICLRHostProtectionManager_Vtbl, 0x89f25f5c_ceef_43e1_9cfa_a68ce863aaac);
 windows_core::imp::interface_hierarchy!(ICLRHostProtectionManager, windows_core::IUnknown);
 impl ICLRHostProtectionManager {
     pub unsafe fn SetProtectedCategories(&self, categories: EApiCategories) -> windows_core::Result<()> {
@@ -1396,6 +1847,30 @@ impl ICLRHostProtectionManager_Vtbl {
         iid == &<ICLRHostProtectionManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLRHostProtectionManager_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRHostProtectionManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetProtectedCategories<Identity: windows_core::IUnknownImpl + ICLRHostProtectionManager_Impl>(this: *mut core::ffi::c_void, categories: EApiCategories) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRHostProtectionManager_Impl::SetProtectedCategories(this, core::mem::transmute_copy(&categories)).into()
+            }
+        }
+        unsafe extern "system" fn SetEagerSerializeGrantSets<Identity: windows_core::IUnknownImpl + ICLRHostProtectionManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRHostProtectionManager_Impl::SetEagerSerializeGrantSets(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetProtectedCategories: SetProtectedCategories::<Identity>,
+            SetEagerSerializeGrantSets: SetEagerSerializeGrantSets::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICLRHostProtectionManager {}
 windows_core::imp::define_interface!(ICLRIoCompletionManager, ICLRIoCompletionManager_Vtbl, 0x2d74ce86_b8d6_4c84_b3a7_9768933b3c12);
 windows_core::imp::interface_hierarchy!(ICLRIoCompletionManager, windows_core::IUnknown);
@@ -1427,6 +1902,20 @@ impl ICLRIoCompletionManager_Vtbl {
         iid == &<ICLRIoCompletionManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLRIoCompletionManager_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRIoCompletionManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnComplete<Identity: windows_core::IUnknownImpl + ICLRIoCompletionManager_Impl>(this: *mut core::ffi::c_void, dwerrorcode: u32, numberofbytestransferred: u32, pvoverlapped: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRIoCompletionManager_Impl::OnComplete(this, core::mem::transmute_copy(&dwerrorcode), core::mem::transmute_copy(&numberofbytestransferred), core::mem::transmute_copy(&pvoverlapped)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnComplete: OnComplete::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ICLRIoCompletionManager {}
 windows_core::imp::define_interface!(ICLRMemoryNotificationCallback, ICLRMemoryNotificationCallback_Vtbl, 0x47eb8e57_0846_4546_af76_6f42fcfc2649);
 windows_core::imp::interface_hierarchy!(ICLRMemoryNotificationCallback, windows_core::IUnknown);
@@ -1458,6 +1947,23 @@ impl ICLRMemoryNotificationCallback_Vtbl {
         iid == &<ICLRMemoryNotificationCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLRMemoryNotificationCallback_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRMemoryNotificationCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnMemoryNotification<Identity: windows_core::IUnknownImpl + ICLRMemoryNotificationCallback_Impl>(this: *mut core::ffi::c_void, ememoryavailable: EMemoryAvailable) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRMemoryNotificationCallback_Impl::OnMemoryNotification(this, core::mem::transmute_copy(&ememoryavailable)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnMemoryNotification: OnMemoryNotification::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICLRMemoryNotificationCallback {}
 windows_core::imp::define_interface!(ICLRMetaHost, ICLRMetaHost_Vtbl, 0xd332db9e_b9b3_4125_8207_a14884f53216);
 windows_core::imp::interface_hierarchy!(ICLRMetaHost, windows_core::IUnknown);
@@ -1605,6 +2111,78 @@ impl ICLRMetaHost_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + ICLRMetaHost_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRMetaHost_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRuntime<Identity: windows_core::IUnknownImpl + ICLRMetaHost_Impl>(this: *mut core::ffi::c_void, pwzversion: windows_core::PCWSTR, riid: *const windows_core::GUID, ppruntime: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRMetaHost_Impl::GetRuntime(this, core::mem::transmute(&pwzversion), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppruntime)).into()
+            }
+        }
+        unsafe extern "system" fn GetVersionFromFile<Identity: windows_core::IUnknownImpl + ICLRMetaHost_Impl>(this: *mut core::ffi::c_void, pwzfilepath: windows_core::PCWSTR, pwzbuffer: windows_core::PWSTR, pcchbuffer: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRMetaHost_Impl::GetVersionFromFile(this, core::mem::transmute(&pwzfilepath), core::mem::transmute_copy(&pwzbuffer), core::mem::transmute_copy(&pcchbuffer)).into()
+            }
+        }
+        unsafe extern "system" fn EnumerateInstalledRuntimes<Identity: windows_core::IUnknownImpl + ICLRMetaHost_Impl>(this: *mut core::ffi::c_void, ppenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRMetaHost_Impl::EnumerateInstalledRuntimes(this) {
+                    Ok(ok__) => {
+                        ppenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumerateLoadedRuntimes<Identity: windows_core::IUnknownImpl + ICLRMetaHost_Impl>(this: *mut core::ffi::c_void, hndprocess: super::super::Foundation::HANDLE, ppenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRMetaHost_Impl::EnumerateLoadedRuntimes(this, core::mem::transmute_copy(&hndprocess)) {
+                    Ok(ok__) => {
+                        ppenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RequestRuntimeLoadedNotification<Identity: windows_core::IUnknownImpl + ICLRMetaHost_Impl>(this: *mut core::ffi::c_void, pcallbackfunction: RuntimeLoadedCallbackFnPtr) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRMetaHost_Impl::RequestRuntimeLoadedNotification(this, core::mem::transmute_copy(&pcallbackfunction)).into()
+            }
+        }
+        unsafe extern "system" fn QueryLegacyV2RuntimeBinding<Identity: windows_core::IUnknownImpl + ICLRMetaHost_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRMetaHost_Impl::QueryLegacyV2RuntimeBinding(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppunk)).into()
+            }
+        }
+        unsafe extern "system" fn ExitProcess<Identity: windows_core::IUnknownImpl + ICLRMetaHost_Impl>(this: *mut core::ffi::c_void, iexitcode: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRMetaHost_Impl::ExitProcess(this, core::mem::transmute_copy(&iexitcode)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetRuntime: GetRuntime::<Identity>,
+            GetVersionFromFile: GetVersionFromFile::<Identity>,
+            EnumerateInstalledRuntimes: EnumerateInstalledRuntimes::<Identity>,
+            EnumerateLoadedRuntimes: EnumerateLoadedRuntimes::<Identity>,
+            RequestRuntimeLoadedNotification: RequestRuntimeLoadedNotification::<Identity>,
+            QueryLegacyV2RuntimeBinding: QueryLegacyV2RuntimeBinding::<Identity>,
+            ExitProcess: ExitProcess::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for ICLRMetaHost {}
 windows_core::imp::define_interface!(ICLRMetaHostPolicy, ICLRMetaHostPolicy_Vtbl, 0xe2190695_77b2_492e_8e14_c4b3a7fdd593);
 windows_core::imp::interface_hierarchy!(ICLRMetaHostPolicy, windows_core::IUnknown);
@@ -1649,6 +2227,24 @@ impl ICLRMetaHostPolicy_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + ICLRMetaHostPolicy_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRMetaHostPolicy_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRequestedRuntime<Identity: windows_core::IUnknownImpl + ICLRMetaHostPolicy_Impl>(this: *mut core::ffi::c_void, dwpolicyflags: METAHOST_POLICY_FLAGS, pwzbinary: windows_core::PCWSTR, pcfgstream: *mut core::ffi::c_void, pwzversion: windows_core::PWSTR, pcchversion: *mut u32, pwzimageversion: windows_core::PWSTR, pcchimageversion: *mut u32, pdwconfigflags: *mut u32, riid: *const windows_core::GUID, ppruntime: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRMetaHostPolicy_Impl::GetRequestedRuntime(this, core::mem::transmute_copy(&dwpolicyflags), core::mem::transmute(&pwzbinary), core::mem::transmute_copy(&pcfgstream), core::mem::transmute_copy(&pwzversion), core::mem::transmute_copy(&pcchversion), core::mem::transmute_copy(&pwzimageversion), core::mem::transmute_copy(&pcchimageversion), core::mem::transmute_copy(&pdwconfigflags), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppruntime)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetRequestedRuntime: GetRequestedRuntime::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for ICLRMetaHostPolicy {}
 windows_core::imp::define_interface!(ICLROnEventManager, ICLROnEventManager_Vtbl, 0x1d0e0132_e64f_493d_9260_025c0e32c175);
 windows_core::imp::interface_hierarchy!(ICLROnEventManager, windows_core::IUnknown);
@@ -1701,6 +2297,30 @@ impl ICLROnEventManager_Vtbl {
         iid == &<ICLROnEventManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLROnEventManager_Impl> windows_core::imp::TearOffVtable<Identity> for ICLROnEventManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterActionOnEvent<Identity: windows_core::IUnknownImpl + ICLROnEventManager_Impl>(this: *mut core::ffi::c_void, event: EClrEvent, paction: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLROnEventManager_Impl::RegisterActionOnEvent(this, core::mem::transmute_copy(&event), core::mem::transmute_copy(&paction)).into()
+            }
+        }
+        unsafe extern "system" fn UnregisterActionOnEvent<Identity: windows_core::IUnknownImpl + ICLROnEventManager_Impl>(this: *mut core::ffi::c_void, event: EClrEvent, paction: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLROnEventManager_Impl::UnregisterActionOnEvent(this, core::mem::transmute_copy(&event), core::mem::transmute_copy(&paction)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterActionOnEvent: RegisterActionOnEvent::<Identity>,
+            UnregisterActionOnEvent: UnregisterActionOnEvent::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICLROnEventManager {}
 windows_core::imp::define_interface!(ICLRPolicyManager, ICLRPolicyManager_Vtbl, 0x7d290010_d781_45da_a6f8_aa5d711a730e);
 windows_core::imp::interface_hierarchy!(ICLRPolicyManager, windows_core::IUnknown);
@@ -1795,6 +2415,58 @@ impl ICLRPolicyManager_Vtbl {
         iid == &<ICLRPolicyManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLRPolicyManager_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRPolicyManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetDefaultAction<Identity: windows_core::IUnknownImpl + ICLRPolicyManager_Impl>(this: *mut core::ffi::c_void, operation: EClrOperation, action: EPolicyAction) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRPolicyManager_Impl::SetDefaultAction(this, core::mem::transmute_copy(&operation), core::mem::transmute_copy(&action)).into()
+            }
+        }
+        unsafe extern "system" fn SetTimeout<Identity: windows_core::IUnknownImpl + ICLRPolicyManager_Impl>(this: *mut core::ffi::c_void, operation: EClrOperation, dwmilliseconds: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRPolicyManager_Impl::SetTimeout(this, core::mem::transmute_copy(&operation), core::mem::transmute_copy(&dwmilliseconds)).into()
+            }
+        }
+        unsafe extern "system" fn SetActionOnTimeout<Identity: windows_core::IUnknownImpl + ICLRPolicyManager_Impl>(this: *mut core::ffi::c_void, operation: EClrOperation, action: EPolicyAction) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRPolicyManager_Impl::SetActionOnTimeout(this, core::mem::transmute_copy(&operation), core::mem::transmute_copy(&action)).into()
+            }
+        }
+        unsafe extern "system" fn SetTimeoutAndAction<Identity: windows_core::IUnknownImpl + ICLRPolicyManager_Impl>(this: *mut core::ffi::c_void, operation: EClrOperation, dwmilliseconds: u32, action: EPolicyAction) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRPolicyManager_Impl::SetTimeoutAndAction(this, core::mem::transmute_copy(&operation), core::mem::transmute_copy(&dwmilliseconds), core::mem::transmute_copy(&action)).into()
+            }
+        }
+        unsafe extern "system" fn SetActionOnFailure<Identity: windows_core::IUnknownImpl + ICLRPolicyManager_Impl>(this: *mut core::ffi::c_void, failure: EClrFailure, action: EPolicyAction) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRPolicyManager_Impl::SetActionOnFailure(this, core::mem::transmute_copy(&failure), core::mem::transmute_copy(&action)).into()
+            }
+        }
+        unsafe extern "system" fn SetUnhandledExceptionPolicy<Identity: windows_core::IUnknownImpl + ICLRPolicyManager_Impl>(this: *mut core::ffi::c_void, policy: EClrUnhandledException) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRPolicyManager_Impl::SetUnhandledExceptionPolicy(this, core::mem::transmute_copy(&policy)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetDefaultAction: SetDefaultAction::<Identity>,
+            SetTimeout: SetTimeout::<Identity>,
+            SetActionOnTimeout: SetActionOnTimeout::<Identity>,
+            SetTimeoutAndAction: SetTimeoutAndAction::<Identity>,
+            SetActionOnFailure: SetActionOnFailure::<Identity>,
+            SetUnhandledExceptionPolicy: SetUnhandledExceptionPolicy::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICLRPolicyManager {}
 windows_core::imp::define_interface!(ICLRProbingAssemblyEnum, ICLRProbingAssemblyEnum_Vtbl, 0xd0c5fb1f_416b_4f97_81f4_7ac7dc24dd5d);
 windows_core::imp::interface_hierarchy!(ICLRProbingAssemblyEnum, windows_core::IUnknown);
@@ -1826,6 +2498,20 @@ impl ICLRProbingAssemblyEnum_Vtbl {
         iid == &<ICLRProbingAssemblyEnum as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLRProbingAssemblyEnum_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRProbingAssemblyEnum_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Get<Identity: windows_core::IUnknownImpl + ICLRProbingAssemblyEnum_Impl>(this: *mut core::ffi::c_void, dwindex: u32, pwzbuffer: windows_core::PWSTR, pcchbuffersize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRProbingAssemblyEnum_Impl::Get(this, core::mem::transmute_copy(&dwindex), core::mem::transmute_copy(&pwzbuffer), core::mem::transmute_copy(&pcchbuffersize)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Get: Get::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ICLRProbingAssemblyEnum {}
 windows_core::imp::define_interface!(ICLRProfiling, ICLRProfiling_Vtbl, 0xb349abe3_b56f_4689_bfcd_76bf39d888ea);
 windows_core::imp::interface_hierarchy!(ICLRProfiling, windows_core::IUnknown);
@@ -1860,6 +2546,20 @@ impl ICLRProfiling_Vtbl {
         iid == &<ICLRProfiling as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLRProfiling_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRProfiling_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AttachProfiler<Identity: windows_core::IUnknownImpl + ICLRProfiling_Impl>(this: *mut core::ffi::c_void, dwprofileeprocessid: u32, dwmillisecondsmax: u32, pclsidprofiler: *const windows_core::GUID, wszprofilerpath: windows_core::PCWSTR, pvclientdata: *const core::ffi::c_void, cbclientdata: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRProfiling_Impl::AttachProfiler(this, core::mem::transmute_copy(&dwprofileeprocessid), core::mem::transmute_copy(&dwmillisecondsmax), core::mem::transmute_copy(&pclsidprofiler), core::mem::transmute(&wszprofilerpath), core::mem::transmute_copy(&pvclientdata), core::mem::transmute_copy(&cbclientdata)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, AttachProfiler: AttachProfiler::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ICLRProfiling {}
 windows_core::imp::define_interface!(ICLRReferenceAssemblyEnum, ICLRReferenceAssemblyEnum_Vtbl, 0xd509cb5d_cf32_4876_ae61_67770cf91973);
 windows_core::imp::interface_hierarchy!(ICLRReferenceAssemblyEnum, windows_core::IUnknown);
@@ -1891,6 +2591,20 @@ impl ICLRReferenceAssemblyEnum_Vtbl {
         iid == &<ICLRReferenceAssemblyEnum as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLRReferenceAssemblyEnum_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRReferenceAssemblyEnum_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Get<Identity: windows_core::IUnknownImpl + ICLRReferenceAssemblyEnum_Impl>(this: *mut core::ffi::c_void, dwindex: u32, pwzbuffer: windows_core::PWSTR, pcchbuffersize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRReferenceAssemblyEnum_Impl::Get(this, core::mem::transmute_copy(&dwindex), core::mem::transmute_copy(&pwzbuffer), core::mem::transmute_copy(&pcchbuffersize)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Get: Get::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ICLRReferenceAssemblyEnum {}
 windows_core::imp::define_interface!(ICLRRuntimeHost, ICLRRuntimeHost_Vtbl, 0x90f1a06c_7712_4762_86b5_7a5eba6bdb02);
 windows_core::imp::interface_hierarchy!(ICLRRuntimeHost, windows_core::IUnknown);
@@ -2069,6 +2783,103 @@ impl ICLRRuntimeHost_Vtbl {
         iid == &<ICLRRuntimeHost as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLRRuntimeHost_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRRuntimeHost_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Start<Identity: windows_core::IUnknownImpl + ICLRRuntimeHost_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRRuntimeHost_Impl::Start(this).into()
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + ICLRRuntimeHost_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRRuntimeHost_Impl::Stop(this).into()
+            }
+        }
+        unsafe extern "system" fn SetHostControl<Identity: windows_core::IUnknownImpl + ICLRRuntimeHost_Impl>(this: *mut core::ffi::c_void, phostcontrol: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRRuntimeHost_Impl::SetHostControl(this, core::mem::transmute_copy(&phostcontrol)).into()
+            }
+        }
+        unsafe extern "system" fn GetCLRControl<Identity: windows_core::IUnknownImpl + ICLRRuntimeHost_Impl>(this: *mut core::ffi::c_void, pclrcontrol: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRRuntimeHost_Impl::GetCLRControl(this) {
+                    Ok(ok__) => {
+                        pclrcontrol.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UnloadAppDomain<Identity: windows_core::IUnknownImpl + ICLRRuntimeHost_Impl>(this: *mut core::ffi::c_void, dwappdomainid: u32, fwaituntildone: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRRuntimeHost_Impl::UnloadAppDomain(this, core::mem::transmute_copy(&dwappdomainid), core::mem::transmute_copy(&fwaituntildone)).into()
+            }
+        }
+        unsafe extern "system" fn ExecuteInAppDomain<Identity: windows_core::IUnknownImpl + ICLRRuntimeHost_Impl>(this: *mut core::ffi::c_void, dwappdomainid: u32, pcallback: FExecuteInAppDomainCallback, cookie: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRRuntimeHost_Impl::ExecuteInAppDomain(this, core::mem::transmute_copy(&dwappdomainid), core::mem::transmute_copy(&pcallback), core::mem::transmute_copy(&cookie)).into()
+            }
+        }
+        unsafe extern "system" fn GetCurrentAppDomainId<Identity: windows_core::IUnknownImpl + ICLRRuntimeHost_Impl>(this: *mut core::ffi::c_void, pdwappdomainid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRRuntimeHost_Impl::GetCurrentAppDomainId(this) {
+                    Ok(ok__) => {
+                        pdwappdomainid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ExecuteApplication<Identity: windows_core::IUnknownImpl + ICLRRuntimeHost_Impl>(this: *mut core::ffi::c_void, pwzappfullname: windows_core::PCWSTR, dwmanifestpaths: u32, ppwzmanifestpaths: *const windows_core::PCWSTR, dwactivationdata: u32, ppwzactivationdata: *const windows_core::PCWSTR, preturnvalue: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRRuntimeHost_Impl::ExecuteApplication(this, core::mem::transmute(&pwzappfullname), core::mem::transmute_copy(&dwmanifestpaths), core::mem::transmute_copy(&ppwzmanifestpaths), core::mem::transmute_copy(&dwactivationdata), core::mem::transmute_copy(&ppwzactivationdata)) {
+                    Ok(ok__) => {
+                        preturnvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ExecuteInDefaultAppDomain<Identity: windows_core::IUnknownImpl + ICLRRuntimeHost_Impl>(this: *mut core::ffi::c_void, pwzassemblypath: windows_core::PCWSTR, pwztypename: windows_core::PCWSTR, pwzmethodname: windows_core::PCWSTR, pwzargument: windows_core::PCWSTR, preturnvalue: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRRuntimeHost_Impl::ExecuteInDefaultAppDomain(this, core::mem::transmute(&pwzassemblypath), core::mem::transmute(&pwztypename), core::mem::transmute(&pwzmethodname), core::mem::transmute(&pwzargument)) {
+                    Ok(ok__) => {
+                        preturnvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Start: Start::<Identity>,
+            Stop: Stop::<Identity>,
+            SetHostControl: SetHostControl::<Identity>,
+            GetCLRControl: GetCLRControl::<Identity>,
+            UnloadAppDomain: UnloadAppDomain::<Identity>,
+            ExecuteInAppDomain: ExecuteInAppDomain::<Identity>,
+            GetCurrentAppDomainId: GetCurrentAppDomainId::<Identity>,
+            ExecuteApplication: ExecuteApplication::<Identity>,
+            ExecuteInDefaultAppDomain: ExecuteInDefaultAppDomain::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICLRRuntimeHost {}
 windows_core::imp::define_interface!(ICLRRuntimeInfo, ICLRRuntimeInfo_Vtbl, 0xbd39d1d2_ba2f_486a_89b0_b4b0cb466891);
 windows_core::imp::interface_hierarchy!(ICLRRuntimeInfo, windows_core::IUnknown);
@@ -2284,35 +3095,153 @@ impl ICLRRuntimeInfo_Vtbl {
         iid == &<ICLRRuntimeInfo as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for ICLRRuntimeInfo {}
-windows_core::imp::define_interface!(ICLRStrongName, ICLRStrongName_Vtbl, 0x9fd93ccf_3280_4391_b3a9_96e1cde77c8d);
-windows_core::imp::interface_hierarchy!(ICLRStrongName, windows_core::IUnknown);
-impl ICLRStrongName {
-    pub unsafe fn GetHashFromAssemblyFile<P0>(&self, pszfilepath: P0, pihashalg: *mut u32, pbhash: &mut [u8], pchhash: *mut u32) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<windows_core::PCSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).GetHashFromAssemblyFile)(windows_core::Interface::as_raw(self), pszfilepath.param().abi(), pihashalg as _, core::mem::transmute(pbhash.as_ptr()), pbhash.len().try_into().unwrap(), pchhash as _).ok() }
-    }
-    pub unsafe fn GetHashFromAssemblyFileW<P0>(&self, pwzfilepath: P0, pihashalg: *mut u32, pbhash: &mut [u8], pchhash: *mut u32) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<windows_core::PCWSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).GetHashFromAssemblyFileW)(windows_core::Interface::as_raw(self), pwzfilepath.param().abi(), pihashalg as _, core::mem::transmute(pbhash.as_ptr()), pbhash.len().try_into().unwrap(), pchhash as _).ok() }
-    }
-    pub unsafe fn GetHashFromBlob(&self, pbblob: *const u8, cchblob: u32, pihashalg: *mut u32, pbhash: &mut [u8], pchhash: *mut u32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetHashFromBlob)(windows_core::Interface::as_raw(self), pbblob, cchblob, pihashalg as _, core::mem::transmute(pbhash.as_ptr()), pbhash.len().try_into().unwrap(), pchhash as _).ok() }
-    }
-    pub unsafe fn GetHashFromFile<P0>(&self, pszfilepath: P0, pihashalg: *mut u32, pbhash: &mut [u8], pchhash: *mut u32) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<windows_core::PCSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).GetHashFromFile)(windows_core::Interface::as_raw(self), pszfilepath.param().abi(), pihashalg as _, core::mem::transmute(pbhash.as_ptr()), pbhash.len().try_into().unwrap(), pchhash as _).ok() }
-    }
-    pub unsafe fn GetHashFromFileW<P0>(&self, pwzfilepath: P0, pihashalg: *mut u32, pbhash: &mut [u8], pchhash: *mut u32) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<windows_core::PCWSTR>,
-    {
+impl<Identity: windows_core::IUnknownImpl + ICLRRuntimeInfo_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRRuntimeInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetVersionString<Identity: windows_core::IUnknownImpl + ICLRRuntimeInfo_Impl>(this: *mut core::ffi::c_void, pwzbuffer: windows_core::PWSTR, pcchbuffer: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRRuntimeInfo_Impl::GetVersionString(this, core::mem::transmute_copy(&pwzbuffer), core::mem::transmute_copy(&pcchbuffer)).into()
+            }
+        }
+        unsafe extern "system" fn GetRuntimeDirectory<Identity: windows_core::IUnknownImpl + ICLRRuntimeInfo_Impl>(this: *mut core::ffi::c_void, pwzbuffer: windows_core::PWSTR, pcchbuffer: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRRuntimeInfo_Impl::GetRuntimeDirectory(this, core::mem::transmute_copy(&pwzbuffer), core::mem::transmute_copy(&pcchbuffer)).into()
+            }
+        }
+        unsafe extern "system" fn IsLoaded<Identity: windows_core::IUnknownImpl + ICLRRuntimeInfo_Impl>(this: *mut core::ffi::c_void, hndprocess: super::super::Foundation::HANDLE, pbloaded: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRRuntimeInfo_Impl::IsLoaded(this, core::mem::transmute_copy(&hndprocess)) {
+                    Ok(ok__) => {
+                        pbloaded.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LoadErrorString<Identity: windows_core::IUnknownImpl + ICLRRuntimeInfo_Impl>(this: *mut core::ffi::c_void, iresourceid: u32, pwzbuffer: windows_core::PWSTR, pcchbuffer: *mut u32, ilocaleid: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRRuntimeInfo_Impl::LoadErrorString(this, core::mem::transmute_copy(&iresourceid), core::mem::transmute_copy(&pwzbuffer), core::mem::transmute_copy(&pcchbuffer), core::mem::transmute_copy(&ilocaleid)).into()
+            }
+        }
+        unsafe extern "system" fn LoadLibraryA<Identity: windows_core::IUnknownImpl + ICLRRuntimeInfo_Impl>(this: *mut core::ffi::c_void, pwzdllname: windows_core::PCWSTR, phndmodule: *mut super::super::Foundation::HMODULE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRRuntimeInfo_Impl::LoadLibraryA(this, core::mem::transmute(&pwzdllname)) {
+                    Ok(ok__) => {
+                        phndmodule.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetProcAddress<Identity: windows_core::IUnknownImpl + ICLRRuntimeInfo_Impl>(this: *mut core::ffi::c_void, pszprocname: windows_core::PCSTR, ppproc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRRuntimeInfo_Impl::GetProcAddress(this, core::mem::transmute(&pszprocname)) {
+                    Ok(ok__) => {
+                        ppproc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetInterface<Identity: windows_core::IUnknownImpl + ICLRRuntimeInfo_Impl>(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRRuntimeInfo_Impl::GetInterface(this, core::mem::transmute_copy(&rclsid), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppunk)).into()
+            }
+        }
+        unsafe extern "system" fn IsLoadable<Identity: windows_core::IUnknownImpl + ICLRRuntimeInfo_Impl>(this: *mut core::ffi::c_void, pbloadable: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRRuntimeInfo_Impl::IsLoadable(this) {
+                    Ok(ok__) => {
+                        pbloadable.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDefaultStartupFlags<Identity: windows_core::IUnknownImpl + ICLRRuntimeInfo_Impl>(this: *mut core::ffi::c_void, dwstartupflags: u32, pwzhostconfigfile: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRRuntimeInfo_Impl::SetDefaultStartupFlags(this, core::mem::transmute_copy(&dwstartupflags), core::mem::transmute(&pwzhostconfigfile)).into()
+            }
+        }
+        unsafe extern "system" fn GetDefaultStartupFlags<Identity: windows_core::IUnknownImpl + ICLRRuntimeInfo_Impl>(this: *mut core::ffi::c_void, pdwstartupflags: *mut u32, pwzhostconfigfile: windows_core::PWSTR, pcchhostconfigfile: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRRuntimeInfo_Impl::GetDefaultStartupFlags(this, core::mem::transmute_copy(&pdwstartupflags), core::mem::transmute_copy(&pwzhostconfigfile), core::mem::transmute_copy(&pcchhostconfigfile)).into()
+            }
+        }
+        unsafe extern "system" fn BindAsLegacyV2Runtime<Identity: windows_core::IUnknownImpl + ICLRRuntimeInfo_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRRuntimeInfo_Impl::BindAsLegacyV2Runtime(this).into()
+            }
+        }
+        unsafe extern "system" fn IsStarted<Identity: windows_core::IUnknownImpl + ICLRRuntimeInfo_Impl>(this: *mut core::ffi::c_void, pbstarted: *mut windows_core::BOOL, pdwstartupflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRRuntimeInfo_Impl::IsStarted(this, core::mem::transmute_copy(&pbstarted), core::mem::transmute_copy(&pdwstartupflags)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetVersionString: GetVersionString::<Identity>,
+            GetRuntimeDirectory: GetRuntimeDirectory::<Identity>,
+            IsLoaded: IsLoaded::<Identity>,
+            LoadErrorString: LoadErrorString::<Identity>,
+            LoadLibraryA: LoadLibraryA::<Identity>,
+            GetProcAddress: GetProcAddress::<Identity>,
+            GetInterface: GetInterface::<Identity>,
+            IsLoadable: IsLoadable::<Identity>,
+            SetDefaultStartupFlags: SetDefaultStartupFlags::<Identity>,
+            GetDefaultStartupFlags: GetDefaultStartupFlags::<Identity>,
+            BindAsLegacyV2Runtime: BindAsLegacyV2Runtime::<Identity>,
+            IsStarted: IsStarted::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for ICLRRuntimeInfo {}
+windows_core::imp::define_interface!(ICLRStrongName, ICLRStrongName_Vtbl, 0x9fd93ccf_3280_4391_b3a9_96e1cde77c8d);
+windows_core::imp::interface_hierarchy!(ICLRStrongName, windows_core::IUnknown);
+impl ICLRStrongName {
+    pub unsafe fn GetHashFromAssemblyFile<P0>(&self, pszfilepath: P0, pihashalg: *mut u32, pbhash: &mut [u8], pchhash: *mut u32) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::PCSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).GetHashFromAssemblyFile)(windows_core::Interface::as_raw(self), pszfilepath.param().abi(), pihashalg as _, core::mem::transmute(pbhash.as_ptr()), pbhash.len().try_into().unwrap(), pchhash as _).ok() }
+    }
+    pub unsafe fn GetHashFromAssemblyFileW<P0>(&self, pwzfilepath: P0, pihashalg: *mut u32, pbhash: &mut [u8], pchhash: *mut u32) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::PCWSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).GetHashFromAssemblyFileW)(windows_core::Interface::as_raw(self), pwzfilepath.param().abi(), pihashalg as _, core::mem::transmute(pbhash.as_ptr()), pbhash.len().try_into().unwrap(), pchhash as _).ok() }
+    }
+    pub unsafe fn GetHashFromBlob(&self, pbblob: *const u8, cchblob: u32, pihashalg: *mut u32, pbhash: &mut [u8], pchhash: *mut u32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetHashFromBlob)(windows_core::Interface::as_raw(self), pbblob, cchblob, pihashalg as _, core::mem::transmute(pbhash.as_ptr()), pbhash.len().try_into().unwrap(), pchhash as _).ok() }
+    }
+    pub unsafe fn GetHashFromFile<P0>(&self, pszfilepath: P0, pihashalg: *mut u32, pbhash: &mut [u8], pchhash: *mut u32) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::PCSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).GetHashFromFile)(windows_core::Interface::as_raw(self), pszfilepath.param().abi(), pihashalg as _, core::mem::transmute(pbhash.as_ptr()), pbhash.len().try_into().unwrap(), pchhash as _).ok() }
+    }
+    pub unsafe fn GetHashFromFileW<P0>(&self, pwzfilepath: P0, pihashalg: *mut u32, pbhash: &mut [u8], pchhash: *mut u32) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::PCWSTR>,
+    {
         unsafe { (windows_core::Interface::vtable(self).GetHashFromFileW)(windows_core::Interface::as_raw(self), pwzfilepath.param().abi(), pihashalg as _, core::mem::transmute(pbhash.as_ptr()), pbhash.len().try_into().unwrap(), pchhash as _).ok() }
     }
     pub unsafe fn GetHashFromHandle(&self, hfile: super::super::Foundation::HANDLE, pihashalg: *mut u32, pbhash: &mut [u8], pchhash: *mut u32) -> windows_core::Result<()> {
@@ -2705,6 +3634,221 @@ impl ICLRStrongName_Vtbl {
         iid == &<ICLRStrongName as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRStrongName_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetHashFromAssemblyFile<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, pszfilepath: windows_core::PCSTR, pihashalg: *mut u32, pbhash: *mut u8, cchhash: u32, pchhash: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName_Impl::GetHashFromAssemblyFile(this, core::mem::transmute(&pszfilepath), core::mem::transmute_copy(&pihashalg), core::mem::transmute_copy(&pbhash), core::mem::transmute_copy(&cchhash), core::mem::transmute_copy(&pchhash)).into()
+            }
+        }
+        unsafe extern "system" fn GetHashFromAssemblyFileW<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, pwzfilepath: windows_core::PCWSTR, pihashalg: *mut u32, pbhash: *mut u8, cchhash: u32, pchhash: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName_Impl::GetHashFromAssemblyFileW(this, core::mem::transmute(&pwzfilepath), core::mem::transmute_copy(&pihashalg), core::mem::transmute_copy(&pbhash), core::mem::transmute_copy(&cchhash), core::mem::transmute_copy(&pchhash)).into()
+            }
+        }
+        unsafe extern "system" fn GetHashFromBlob<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, pbblob: *const u8, cchblob: u32, pihashalg: *mut u32, pbhash: *mut u8, cchhash: u32, pchhash: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName_Impl::GetHashFromBlob(this, core::mem::transmute_copy(&pbblob), core::mem::transmute_copy(&cchblob), core::mem::transmute_copy(&pihashalg), core::mem::transmute_copy(&pbhash), core::mem::transmute_copy(&cchhash), core::mem::transmute_copy(&pchhash)).into()
+            }
+        }
+        unsafe extern "system" fn GetHashFromFile<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, pszfilepath: windows_core::PCSTR, pihashalg: *mut u32, pbhash: *mut u8, cchhash: u32, pchhash: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName_Impl::GetHashFromFile(this, core::mem::transmute(&pszfilepath), core::mem::transmute_copy(&pihashalg), core::mem::transmute_copy(&pbhash), core::mem::transmute_copy(&cchhash), core::mem::transmute_copy(&pchhash)).into()
+            }
+        }
+        unsafe extern "system" fn GetHashFromFileW<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, pwzfilepath: windows_core::PCWSTR, pihashalg: *mut u32, pbhash: *mut u8, cchhash: u32, pchhash: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName_Impl::GetHashFromFileW(this, core::mem::transmute(&pwzfilepath), core::mem::transmute_copy(&pihashalg), core::mem::transmute_copy(&pbhash), core::mem::transmute_copy(&cchhash), core::mem::transmute_copy(&pchhash)).into()
+            }
+        }
+        unsafe extern "system" fn GetHashFromHandle<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, hfile: super::super::Foundation::HANDLE, pihashalg: *mut u32, pbhash: *mut u8, cchhash: u32, pchhash: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName_Impl::GetHashFromHandle(this, core::mem::transmute_copy(&hfile), core::mem::transmute_copy(&pihashalg), core::mem::transmute_copy(&pbhash), core::mem::transmute_copy(&cchhash), core::mem::transmute_copy(&pchhash)).into()
+            }
+        }
+        unsafe extern "system" fn StrongNameCompareAssemblies<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, pwzassembly1: windows_core::PCWSTR, pwzassembly2: windows_core::PCWSTR, pdwresult: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRStrongName_Impl::StrongNameCompareAssemblies(this, core::mem::transmute(&pwzassembly1), core::mem::transmute(&pwzassembly2)) {
+                    Ok(ok__) => {
+                        pdwresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StrongNameFreeBuffer<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, pbmemory: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName_Impl::StrongNameFreeBuffer(this, core::mem::transmute_copy(&pbmemory)).into()
+            }
+        }
+        unsafe extern "system" fn StrongNameGetBlob<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, pwzfilepath: windows_core::PCWSTR, pbblob: *mut u8, pcbblob: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName_Impl::StrongNameGetBlob(this, core::mem::transmute(&pwzfilepath), core::mem::transmute_copy(&pbblob), core::mem::transmute_copy(&pcbblob)).into()
+            }
+        }
+        unsafe extern "system" fn StrongNameGetBlobFromImage<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, pbbase: *const u8, dwlength: u32, pbblob: *mut u8, pcbblob: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName_Impl::StrongNameGetBlobFromImage(this, core::mem::transmute_copy(&pbbase), core::mem::transmute_copy(&dwlength), core::mem::transmute_copy(&pbblob), core::mem::transmute_copy(&pcbblob)).into()
+            }
+        }
+        unsafe extern "system" fn StrongNameGetPublicKey<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, pwzkeycontainer: windows_core::PCWSTR, pbkeyblob: *const u8, cbkeyblob: u32, ppbpublickeyblob: *mut *mut u8, pcbpublickeyblob: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName_Impl::StrongNameGetPublicKey(this, core::mem::transmute(&pwzkeycontainer), core::mem::transmute_copy(&pbkeyblob), core::mem::transmute_copy(&cbkeyblob), core::mem::transmute_copy(&ppbpublickeyblob), core::mem::transmute_copy(&pcbpublickeyblob)).into()
+            }
+        }
+        unsafe extern "system" fn StrongNameHashSize<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, ulhashalg: u32, pcbsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRStrongName_Impl::StrongNameHashSize(this, core::mem::transmute_copy(&ulhashalg)) {
+                    Ok(ok__) => {
+                        pcbsize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StrongNameKeyDelete<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, pwzkeycontainer: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName_Impl::StrongNameKeyDelete(this, core::mem::transmute(&pwzkeycontainer)).into()
+            }
+        }
+        unsafe extern "system" fn StrongNameKeyGen<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, pwzkeycontainer: windows_core::PCWSTR, dwflags: u32, ppbkeyblob: *mut *mut u8, pcbkeyblob: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName_Impl::StrongNameKeyGen(this, core::mem::transmute(&pwzkeycontainer), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&ppbkeyblob), core::mem::transmute_copy(&pcbkeyblob)).into()
+            }
+        }
+        unsafe extern "system" fn StrongNameKeyGenEx<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, pwzkeycontainer: windows_core::PCWSTR, dwflags: u32, dwkeysize: u32, ppbkeyblob: *mut *mut u8, pcbkeyblob: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName_Impl::StrongNameKeyGenEx(this, core::mem::transmute(&pwzkeycontainer), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&dwkeysize), core::mem::transmute_copy(&ppbkeyblob), core::mem::transmute_copy(&pcbkeyblob)).into()
+            }
+        }
+        unsafe extern "system" fn StrongNameKeyInstall<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, pwzkeycontainer: windows_core::PCWSTR, pbkeyblob: *const u8, cbkeyblob: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName_Impl::StrongNameKeyInstall(this, core::mem::transmute(&pwzkeycontainer), core::mem::transmute_copy(&pbkeyblob), core::mem::transmute_copy(&cbkeyblob)).into()
+            }
+        }
+        unsafe extern "system" fn StrongNameSignatureGeneration<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, pwzfilepath: windows_core::PCWSTR, pwzkeycontainer: windows_core::PCWSTR, pbkeyblob: *const u8, cbkeyblob: u32, ppbsignatureblob: *mut *mut u8, pcbsignatureblob: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName_Impl::StrongNameSignatureGeneration(this, core::mem::transmute(&pwzfilepath), core::mem::transmute(&pwzkeycontainer), core::mem::transmute_copy(&pbkeyblob), core::mem::transmute_copy(&cbkeyblob), core::mem::transmute_copy(&ppbsignatureblob), core::mem::transmute_copy(&pcbsignatureblob)).into()
+            }
+        }
+        unsafe extern "system" fn StrongNameSignatureGenerationEx<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, wszfilepath: windows_core::PCWSTR, wszkeycontainer: windows_core::PCWSTR, pbkeyblob: *const u8, cbkeyblob: u32, ppbsignatureblob: *mut *mut u8, pcbsignatureblob: *mut u32, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName_Impl::StrongNameSignatureGenerationEx(this, core::mem::transmute(&wszfilepath), core::mem::transmute(&wszkeycontainer), core::mem::transmute_copy(&pbkeyblob), core::mem::transmute_copy(&cbkeyblob), core::mem::transmute_copy(&ppbsignatureblob), core::mem::transmute_copy(&pcbsignatureblob), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn StrongNameSignatureSize<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, pbpublickeyblob: *const u8, cbpublickeyblob: u32, pcbsize: *const u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName_Impl::StrongNameSignatureSize(this, core::mem::transmute_copy(&pbpublickeyblob), core::mem::transmute_copy(&cbpublickeyblob), core::mem::transmute_copy(&pcbsize)).into()
+            }
+        }
+        unsafe extern "system" fn StrongNameSignatureVerification<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, pwzfilepath: windows_core::PCWSTR, dwinflags: u32, pdwoutflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRStrongName_Impl::StrongNameSignatureVerification(this, core::mem::transmute(&pwzfilepath), core::mem::transmute_copy(&dwinflags)) {
+                    Ok(ok__) => {
+                        pdwoutflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StrongNameSignatureVerificationEx<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, pwzfilepath: windows_core::PCWSTR, fforceverification: bool, pfwasverified: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRStrongName_Impl::StrongNameSignatureVerificationEx(this, core::mem::transmute(&pwzfilepath), core::mem::transmute_copy(&fforceverification)) {
+                    Ok(ok__) => {
+                        pfwasverified.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StrongNameSignatureVerificationFromImage<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, pbbase: *const u8, dwlength: u32, dwinflags: u32, pdwoutflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRStrongName_Impl::StrongNameSignatureVerificationFromImage(this, core::mem::transmute_copy(&pbbase), core::mem::transmute_copy(&dwlength), core::mem::transmute_copy(&dwinflags)) {
+                    Ok(ok__) => {
+                        pdwoutflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StrongNameTokenFromAssembly<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, pwzfilepath: windows_core::PCWSTR, ppbstrongnametoken: *mut *mut u8, pcbstrongnametoken: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName_Impl::StrongNameTokenFromAssembly(this, core::mem::transmute(&pwzfilepath), core::mem::transmute_copy(&ppbstrongnametoken), core::mem::transmute_copy(&pcbstrongnametoken)).into()
+            }
+        }
+        unsafe extern "system" fn StrongNameTokenFromAssemblyEx<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, pwzfilepath: windows_core::PCWSTR, ppbstrongnametoken: *mut *mut u8, pcbstrongnametoken: *mut u32, ppbpublickeyblob: *mut *mut u8, pcbpublickeyblob: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName_Impl::StrongNameTokenFromAssemblyEx(this, core::mem::transmute(&pwzfilepath), core::mem::transmute_copy(&ppbstrongnametoken), core::mem::transmute_copy(&pcbstrongnametoken), core::mem::transmute_copy(&ppbpublickeyblob), core::mem::transmute_copy(&pcbpublickeyblob)).into()
+            }
+        }
+        unsafe extern "system" fn StrongNameTokenFromPublicKey<Identity: windows_core::IUnknownImpl + ICLRStrongName_Impl>(this: *mut core::ffi::c_void, pbpublickeyblob: *const u8, cbpublickeyblob: u32, ppbstrongnametoken: *mut *mut u8, pcbstrongnametoken: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName_Impl::StrongNameTokenFromPublicKey(this, core::mem::transmute_copy(&pbpublickeyblob), core::mem::transmute_copy(&cbpublickeyblob), core::mem::transmute_copy(&ppbstrongnametoken), core::mem::transmute_copy(&pcbstrongnametoken)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetHashFromAssemblyFile: GetHashFromAssemblyFile::<Identity>,
+            GetHashFromAssemblyFileW: GetHashFromAssemblyFileW::<Identity>,
+            GetHashFromBlob: GetHashFromBlob::<Identity>,
+            GetHashFromFile: GetHashFromFile::<Identity>,
+            GetHashFromFileW: GetHashFromFileW::<Identity>,
+            GetHashFromHandle: GetHashFromHandle::<Identity>,
+            StrongNameCompareAssemblies: StrongNameCompareAssemblies::<Identity>,
+            StrongNameFreeBuffer: StrongNameFreeBuffer::<Identity>,
+            StrongNameGetBlob: StrongNameGetBlob::<Identity>,
+            StrongNameGetBlobFromImage: StrongNameGetBlobFromImage::<Identity>,
+            StrongNameGetPublicKey: StrongNameGetPublicKey::<Identity>,
+            StrongNameHashSize: StrongNameHashSize::<Identity>,
+            StrongNameKeyDelete: StrongNameKeyDelete::<Identity>,
+            StrongNameKeyGen: StrongNameKeyGen::<Identity>,
+            StrongNameKeyGenEx: StrongNameKeyGenEx::<Identity>,
+            StrongNameKeyInstall: StrongNameKeyInstall::<Identity>,
+            StrongNameSignatureGeneration: StrongNameSignatureGeneration::<Identity>,
+            StrongNameSignatureGenerationEx: StrongNameSignatureGenerationEx::<Identity>,
+            StrongNameSignatureSize: StrongNameSignatureSize::<Identity>,
+            StrongNameSignatureVerification: StrongNameSignatureVerification::<Identity>,
+            StrongNameSignatureVerificationEx: StrongNameSignatureVerificationEx::<Identity>,
+            StrongNameSignatureVerificationFromImage: StrongNameSignatureVerificationFromImage::<Identity>,
+            StrongNameTokenFromAssembly: StrongNameTokenFromAssembly::<Identity>,
+            StrongNameTokenFromAssemblyEx: StrongNameTokenFromAssemblyEx::<Identity>,
+            StrongNameTokenFromPublicKey: StrongNameTokenFromPublicKey::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICLRStrongName {}
 windows_core::imp::define_interface!(ICLRStrongName2, ICLRStrongName2_Vtbl, 0xc22ed5c5_4b59_4975_90eb_85ea55c0069b);
 windows_core::imp::interface_hierarchy!(ICLRStrongName2, windows_core::IUnknown);
@@ -2766,23 +3910,53 @@ impl ICLRStrongName2_Vtbl {
         iid == &<ICLRStrongName2 as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for ICLRStrongName2 {}
-windows_core::imp::define_interface!(ICLRStrongName3, ICLRStrongName3_Vtbl, 0x22c7089b_bbd3_414a_b698_210f263f1fed);
-windows_core::imp::interface_hierarchy!(ICLRStrongName3, windows_core::IUnknown);
-impl ICLRStrongName3 {
-    pub unsafe fn StrongNameDigestGenerate<P0>(&self, wszfilepath: P0, ppbdigestblob: *mut *mut u8, pcbdigestblob: *mut u32, dwflags: u32) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<windows_core::PCWSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).StrongNameDigestGenerate)(windows_core::Interface::as_raw(self), wszfilepath.param().abi(), ppbdigestblob as _, pcbdigestblob as _, dwflags).ok() }
-    }
-    pub unsafe fn StrongNameDigestSign<P0>(&self, wszkeycontainer: P0, pbkeyblob: &[u8], pbdigestblob: &[u8], hashalgid: u32, ppbsignatureblob: *mut *mut u8, pcbsignatureblob: *mut u32, dwflags: u32) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<windows_core::PCWSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).StrongNameDigestSign)(windows_core::Interface::as_raw(self), wszkeycontainer.param().abi(), core::mem::transmute(pbkeyblob.as_ptr()), pbkeyblob.len().try_into().unwrap(), core::mem::transmute(pbdigestblob.as_ptr()), pbdigestblob.len().try_into().unwrap(), hashalgid, ppbsignatureblob as _, pcbsignatureblob as _, dwflags).ok() }
-    }
-    pub unsafe fn StrongNameDigestEmbed<P0>(&self, wszfilepath: P0, pbsignatureblob: &[u8]) -> windows_core::Result<()>
+impl<Identity: windows_core::IUnknownImpl + ICLRStrongName2_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRStrongName2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StrongNameGetPublicKeyEx<Identity: windows_core::IUnknownImpl + ICLRStrongName2_Impl>(this: *mut core::ffi::c_void, pwzkeycontainer: windows_core::PCWSTR, pbkeyblob: *const u8, cbkeyblob: u32, ppbpublickeyblob: *mut *mut u8, pcbpublickeyblob: *mut u32, uhashalgid: u32, ureserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName2_Impl::StrongNameGetPublicKeyEx(this, core::mem::transmute(&pwzkeycontainer), core::mem::transmute_copy(&pbkeyblob), core::mem::transmute_copy(&cbkeyblob), core::mem::transmute_copy(&ppbpublickeyblob), core::mem::transmute_copy(&pcbpublickeyblob), core::mem::transmute_copy(&uhashalgid), core::mem::transmute_copy(&ureserved)).into()
+            }
+        }
+        unsafe extern "system" fn StrongNameSignatureVerificationEx2<Identity: windows_core::IUnknownImpl + ICLRStrongName2_Impl>(this: *mut core::ffi::c_void, wszfilepath: windows_core::PCWSTR, fforceverification: bool, pbecmapublickey: *const u8, cbecmapublickey: u32, pfwasverified: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRStrongName2_Impl::StrongNameSignatureVerificationEx2(this, core::mem::transmute(&wszfilepath), core::mem::transmute_copy(&fforceverification), core::mem::transmute_copy(&pbecmapublickey), core::mem::transmute_copy(&cbecmapublickey)) {
+                    Ok(ok__) => {
+                        pfwasverified.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            StrongNameGetPublicKeyEx: StrongNameGetPublicKeyEx::<Identity>,
+            StrongNameSignatureVerificationEx2: StrongNameSignatureVerificationEx2::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for ICLRStrongName2 {}
+windows_core::imp::define_interface!(ICLRStrongName3, ICLRStrongName3_Vtbl, 0x22c7089b_bbd3_414a_b698_210f263f1fed);
+windows_core::imp::interface_hierarchy!(ICLRStrongName3, windows_core::IUnknown);
+impl ICLRStrongName3 {
+    pub unsafe fn StrongNameDigestGenerate<P0>(&self, wszfilepath: P0, ppbdigestblob: *mut *mut u8, pcbdigestblob: *mut u32, dwflags: u32) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::PCWSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).StrongNameDigestGenerate)(windows_core::Interface::as_raw(self), wszfilepath.param().abi(), ppbdigestblob as _, pcbdigestblob as _, dwflags).ok() }
+    }
+    pub unsafe fn StrongNameDigestSign<P0>(&self, wszkeycontainer: P0, pbkeyblob: &[u8], pbdigestblob: &[u8], hashalgid: u32, ppbsignatureblob: *mut *mut u8, pcbsignatureblob: *mut u32, dwflags: u32) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::PCWSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).StrongNameDigestSign)(windows_core::Interface::as_raw(self), wszkeycontainer.param().abi(), core::mem::transmute(pbkeyblob.as_ptr()), pbkeyblob.len().try_into().unwrap(), core::mem::transmute(pbdigestblob.as_ptr()), pbdigestblob.len().try_into().unwrap(), hashalgid, ppbsignatureblob as _, pcbsignatureblob as _, dwflags).ok() }
+    }
+    pub unsafe fn StrongNameDigestEmbed<P0>(&self, wszfilepath: P0, pbsignatureblob: &[u8]) -> windows_core::Result<()>
     where
         P0: windows_core::Param<windows_core::PCWSTR>,
     {
@@ -2833,6 +4007,37 @@ impl ICLRStrongName3_Vtbl {
         iid == &<ICLRStrongName3 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLRStrongName3_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRStrongName3_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StrongNameDigestGenerate<Identity: windows_core::IUnknownImpl + ICLRStrongName3_Impl>(this: *mut core::ffi::c_void, wszfilepath: windows_core::PCWSTR, ppbdigestblob: *mut *mut u8, pcbdigestblob: *mut u32, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName3_Impl::StrongNameDigestGenerate(this, core::mem::transmute(&wszfilepath), core::mem::transmute_copy(&ppbdigestblob), core::mem::transmute_copy(&pcbdigestblob), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn StrongNameDigestSign<Identity: windows_core::IUnknownImpl + ICLRStrongName3_Impl>(this: *mut core::ffi::c_void, wszkeycontainer: windows_core::PCWSTR, pbkeyblob: *const u8, cbkeyblob: u32, pbdigestblob: *const u8, cbdigestblob: u32, hashalgid: u32, ppbsignatureblob: *mut *mut u8, pcbsignatureblob: *mut u32, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName3_Impl::StrongNameDigestSign(this, core::mem::transmute(&wszkeycontainer), core::mem::transmute_copy(&pbkeyblob), core::mem::transmute_copy(&cbkeyblob), core::mem::transmute_copy(&pbdigestblob), core::mem::transmute_copy(&cbdigestblob), core::mem::transmute_copy(&hashalgid), core::mem::transmute_copy(&ppbsignatureblob), core::mem::transmute_copy(&pcbsignatureblob), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn StrongNameDigestEmbed<Identity: windows_core::IUnknownImpl + ICLRStrongName3_Impl>(this: *mut core::ffi::c_void, wszfilepath: windows_core::PCWSTR, pbsignatureblob: *const u8, cbsignatureblob: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRStrongName3_Impl::StrongNameDigestEmbed(this, core::mem::transmute(&wszfilepath), core::mem::transmute_copy(&pbsignatureblob), core::mem::transmute_copy(&cbsignatureblob)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            StrongNameDigestGenerate: StrongNameDigestGenerate::<Identity>,
+            StrongNameDigestSign: StrongNameDigestSign::<Identity>,
+            StrongNameDigestEmbed: StrongNameDigestEmbed::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICLRStrongName3 {}
 windows_core::imp::define_interface!(ICLRSyncManager, ICLRSyncManager_Vtbl, 0x55ff199d_ad21_48f9_a16c_f24ebbb8727d);
 windows_core::imp::interface_hierarchy!(ICLRSyncManager, windows_core::IUnknown);
@@ -2930,6 +4135,62 @@ impl ICLRSyncManager_Vtbl {
         iid == &<ICLRSyncManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLRSyncManager_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRSyncManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMonitorOwner<Identity: windows_core::IUnknownImpl + ICLRSyncManager_Impl>(this: *mut core::ffi::c_void, cookie: usize, ppownerhosttask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRSyncManager_Impl::GetMonitorOwner(this, core::mem::transmute_copy(&cookie)) {
+                    Ok(ok__) => {
+                        ppownerhosttask.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateRWLockOwnerIterator<Identity: windows_core::IUnknownImpl + ICLRSyncManager_Impl>(this: *mut core::ffi::c_void, cookie: usize, piterator: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRSyncManager_Impl::CreateRWLockOwnerIterator(this, core::mem::transmute_copy(&cookie)) {
+                    Ok(ok__) => {
+                        piterator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRWLockOwnerNext<Identity: windows_core::IUnknownImpl + ICLRSyncManager_Impl>(this: *mut core::ffi::c_void, iterator: usize, ppownerhosttask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRSyncManager_Impl::GetRWLockOwnerNext(this, core::mem::transmute_copy(&iterator)) {
+                    Ok(ok__) => {
+                        ppownerhosttask.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeleteRWLockOwnerIterator<Identity: windows_core::IUnknownImpl + ICLRSyncManager_Impl>(this: *mut core::ffi::c_void, iterator: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRSyncManager_Impl::DeleteRWLockOwnerIterator(this, core::mem::transmute_copy(&iterator)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetMonitorOwner: GetMonitorOwner::<Identity>,
+            CreateRWLockOwnerIterator: CreateRWLockOwnerIterator::<Identity>,
+            GetRWLockOwnerNext: GetRWLockOwnerNext::<Identity>,
+            DeleteRWLockOwnerIterator: DeleteRWLockOwnerIterator::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICLRSyncManager {}
 windows_core::imp::define_interface!(ICLRTask, ICLRTask_Vtbl, 0x28e66a4a_9906_4225_b231_9187c3eb8611);
 windows_core::imp::interface_hierarchy!(ICLRTask, windows_core::IUnknown);
@@ -3111,6 +4372,111 @@ impl ICLRTask_Vtbl {
         iid == &<ICLRTask as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLRTask_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRTask_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SwitchIn<Identity: windows_core::IUnknownImpl + ICLRTask_Impl>(this: *mut core::ffi::c_void, threadhandle: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRTask_Impl::SwitchIn(this, core::mem::transmute_copy(&threadhandle)).into()
+            }
+        }
+        unsafe extern "system" fn SwitchOut<Identity: windows_core::IUnknownImpl + ICLRTask_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRTask_Impl::SwitchOut(this).into()
+            }
+        }
+        unsafe extern "system" fn GetMemStats<Identity: windows_core::IUnknownImpl + ICLRTask_Impl>(this: *mut core::ffi::c_void, memusage: *mut COR_GC_THREAD_STATS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRTask_Impl::GetMemStats(this) {
+                    Ok(ok__) => {
+                        memusage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + ICLRTask_Impl>(this: *mut core::ffi::c_void, ffull: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRTask_Impl::Reset(this, core::mem::transmute_copy(&ffull)).into()
+            }
+        }
+        unsafe extern "system" fn ExitTask<Identity: windows_core::IUnknownImpl + ICLRTask_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRTask_Impl::ExitTask(this).into()
+            }
+        }
+        unsafe extern "system" fn Abort<Identity: windows_core::IUnknownImpl + ICLRTask_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRTask_Impl::Abort(this).into()
+            }
+        }
+        unsafe extern "system" fn RudeAbort<Identity: windows_core::IUnknownImpl + ICLRTask_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRTask_Impl::RudeAbort(this).into()
+            }
+        }
+        unsafe extern "system" fn NeedsPriorityScheduling<Identity: windows_core::IUnknownImpl + ICLRTask_Impl>(this: *mut core::ffi::c_void, pbneedspriorityscheduling: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRTask_Impl::NeedsPriorityScheduling(this) {
+                    Ok(ok__) => {
+                        pbneedspriorityscheduling.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn YieldTask<Identity: windows_core::IUnknownImpl + ICLRTask_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRTask_Impl::YieldTask(this).into()
+            }
+        }
+        unsafe extern "system" fn LocksHeld<Identity: windows_core::IUnknownImpl + ICLRTask_Impl>(this: *mut core::ffi::c_void, plockcount: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRTask_Impl::LocksHeld(this) {
+                    Ok(ok__) => {
+                        plockcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTaskIdentifier<Identity: windows_core::IUnknownImpl + ICLRTask_Impl>(this: *mut core::ffi::c_void, asked: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRTask_Impl::SetTaskIdentifier(this, core::mem::transmute_copy(&asked)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SwitchIn: SwitchIn::<Identity>,
+            SwitchOut: SwitchOut::<Identity>,
+            GetMemStats: GetMemStats::<Identity>,
+            Reset: Reset::<Identity>,
+            ExitTask: ExitTask::<Identity>,
+            Abort: Abort::<Identity>,
+            RudeAbort: RudeAbort::<Identity>,
+            NeedsPriorityScheduling: NeedsPriorityScheduling::<Identity>,
+            YieldTask: YieldTask::<Identity>,
+            LocksHeld: LocksHeld::<Identity>,
+            SetTaskIdentifier: SetTaskIdentifier::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICLRTask {}
 windows_core::imp::define_interface!(ICLRTask2, ICLRTask2_Vtbl, 0x28e66a4a_9906_4225_b231_9187c3eb8612);
 impl core::ops::Deref for ICLRTask2 {
@@ -3163,6 +4529,30 @@ impl ICLRTask2_Vtbl {
         iid == &<ICLRTask2 as windows_core::Interface>::IID || iid == &<ICLRTask as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLRTask2_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRTask2_Vtbl
+where
+    ICLRTask_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BeginPreventAsyncAbort<Identity: windows_core::IUnknownImpl + ICLRTask2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRTask2_Impl::BeginPreventAsyncAbort(this).into()
+            }
+        }
+        unsafe extern "system" fn EndPreventAsyncAbort<Identity: windows_core::IUnknownImpl + ICLRTask2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRTask2_Impl::EndPreventAsyncAbort(this).into()
+            }
+        }
+        Self {
+            base__: <ICLRTask_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            BeginPreventAsyncAbort: BeginPreventAsyncAbort::<Identity>,
+            EndPreventAsyncAbort: EndPreventAsyncAbort::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICLRTask2 {}
 windows_core::imp::define_interface!(ICLRTaskManager, ICLRTaskManager_Vtbl, 0x4862efbe_3ae5_44f8_8feb_346190ee8a34);
 windows_core::imp::interface_hierarchy!(ICLRTaskManager, windows_core::IUnknown);
@@ -3272,6 +4662,69 @@ impl ICLRTaskManager_Vtbl {
         iid == &<ICLRTaskManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICLRTaskManager_Impl> windows_core::imp::TearOffVtable<Identity> for ICLRTaskManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateTask<Identity: windows_core::IUnknownImpl + ICLRTaskManager_Impl>(this: *mut core::ffi::c_void, ptask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRTaskManager_Impl::CreateTask(this) {
+                    Ok(ok__) => {
+                        ptask.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCurrentTask<Identity: windows_core::IUnknownImpl + ICLRTaskManager_Impl>(this: *mut core::ffi::c_void, ptask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRTaskManager_Impl::GetCurrentTask(this) {
+                    Ok(ok__) => {
+                        ptask.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetUILocale<Identity: windows_core::IUnknownImpl + ICLRTaskManager_Impl>(this: *mut core::ffi::c_void, lcid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRTaskManager_Impl::SetUILocale(this, core::mem::transmute_copy(&lcid)).into()
+            }
+        }
+        unsafe extern "system" fn SetLocale<Identity: windows_core::IUnknownImpl + ICLRTaskManager_Impl>(this: *mut core::ffi::c_void, lcid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICLRTaskManager_Impl::SetLocale(this, core::mem::transmute_copy(&lcid)).into()
+            }
+        }
+        unsafe extern "system" fn GetCurrentTaskType<Identity: windows_core::IUnknownImpl + ICLRTaskManager_Impl>(this: *mut core::ffi::c_void, ptasktype: *mut ETaskType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICLRTaskManager_Impl::GetCurrentTaskType(this) {
+                    Ok(ok__) => {
+                        ptasktype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateTask: CreateTask::<Identity>,
+            GetCurrentTask: GetCurrentTask::<Identity>,
+            SetUILocale: SetUILocale::<Identity>,
+            SetLocale: SetLocale::<Identity>,
+            GetCurrentTaskType: GetCurrentTaskType::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICLRTaskManager {}
 windows_core::imp::define_interface!(ICatalogServices, ICatalogServices_Vtbl, 0x04c6be1e_1db1_4058_ab7a_700cccfbf254);
 windows_core::imp::interface_hierarchy!(ICatalogServices, windows_core::IUnknown);
@@ -3318,6 +4771,30 @@ impl ICatalogServices_Vtbl {
         iid == &<ICatalogServices as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICatalogServices_Impl> windows_core::imp::TearOffVtable<Identity> for ICatalogServices_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Autodone<Identity: windows_core::IUnknownImpl + ICatalogServices_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICatalogServices_Impl::Autodone(this).into()
+            }
+        }
+        unsafe extern "system" fn NotAutodone<Identity: windows_core::IUnknownImpl + ICatalogServices_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICatalogServices_Impl::NotAutodone(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Autodone: Autodone::<Identity>,
+            NotAutodone: NotAutodone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICatalogServices {}
 windows_core::imp::define_interface!(ICorConfiguration, ICorConfiguration_Vtbl, 0x5c2b07a5_1e98_11d3_872f_00c04f79ed0d);
 windows_core::imp::interface_hierarchy!(ICorConfiguration, windows_core::IUnknown);
@@ -3397,6 +4874,44 @@ impl ICorConfiguration_Vtbl {
         iid == &<ICorConfiguration as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICorConfiguration_Impl> windows_core::imp::TearOffVtable<Identity> for ICorConfiguration_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetGCThreadControl<Identity: windows_core::IUnknownImpl + ICorConfiguration_Impl>(this: *mut core::ffi::c_void, pgcthreadcontrol: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorConfiguration_Impl::SetGCThreadControl(this, core::mem::transmute_copy(&pgcthreadcontrol)).into()
+            }
+        }
+        unsafe extern "system" fn SetGCHostControl<Identity: windows_core::IUnknownImpl + ICorConfiguration_Impl>(this: *mut core::ffi::c_void, pgchostcontrol: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorConfiguration_Impl::SetGCHostControl(this, core::mem::transmute_copy(&pgchostcontrol)).into()
+            }
+        }
+        unsafe extern "system" fn SetDebuggerThreadControl<Identity: windows_core::IUnknownImpl + ICorConfiguration_Impl>(this: *mut core::ffi::c_void, pdebuggerthreadcontrol: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorConfiguration_Impl::SetDebuggerThreadControl(this, core::mem::transmute_copy(&pdebuggerthreadcontrol)).into()
+            }
+        }
+        unsafe extern "system" fn AddDebuggerSpecialThread<Identity: windows_core::IUnknownImpl + ICorConfiguration_Impl>(this: *mut core::ffi::c_void, dwspecialthreadid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorConfiguration_Impl::AddDebuggerSpecialThread(this, core::mem::transmute_copy(&dwspecialthreadid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetGCThreadControl: SetGCThreadControl::<Identity>,
+            SetGCHostControl: SetGCHostControl::<Identity>,
+            SetDebuggerThreadControl: SetDebuggerThreadControl::<Identity>,
+            AddDebuggerSpecialThread: AddDebuggerSpecialThread::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICorConfiguration {}
 windows_core::imp::define_interface!(ICorRuntimeHost, ICorRuntimeHost_Vtbl, 0xcb2f6722_ab3a_11d2_9c40_00c04fa30a3e);
 windows_core::imp::interface_hierarchy!(ICorRuntimeHost, windows_core::IUnknown);
@@ -3758,14 +5273,223 @@ impl ICorRuntimeHost_Vtbl {
         iid == &<ICorRuntimeHost as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for ICorRuntimeHost {}
-windows_core::imp::define_interface!(ICorThreadpool, ICorThreadpool_Vtbl, 0x84680d3a_b2c1_46e8_acc2_dbc0a359159a);
-windows_core::imp::interface_hierarchy!(ICorThreadpool, windows_core::IUnknown);
-impl ICorThreadpool {
-    #[cfg(feature = "Win32_System_Threading")]
-    pub unsafe fn CorRegisterWaitForSingleObject(&self, phnewwaitobject: *const super::super::Foundation::HANDLE, hwaitobject: super::super::Foundation::HANDLE, callback: super::Threading::WAITORTIMERCALLBACK, context: *const core::ffi::c_void, timeout: u32, executeonlyonce: bool) -> windows_core::Result<windows_core::BOOL> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
+impl<Identity: windows_core::IUnknownImpl + ICorRuntimeHost_Impl> windows_core::imp::TearOffVtable<Identity> for ICorRuntimeHost_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateLogicalThreadState<Identity: windows_core::IUnknownImpl + ICorRuntimeHost_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorRuntimeHost_Impl::CreateLogicalThreadState(this).into()
+            }
+        }
+        unsafe extern "system" fn DeleteLogicalThreadState<Identity: windows_core::IUnknownImpl + ICorRuntimeHost_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorRuntimeHost_Impl::DeleteLogicalThreadState(this).into()
+            }
+        }
+        unsafe extern "system" fn SwitchInLogicalThreadState<Identity: windows_core::IUnknownImpl + ICorRuntimeHost_Impl>(this: *mut core::ffi::c_void, pfibercookie: *const u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorRuntimeHost_Impl::SwitchInLogicalThreadState(this, core::mem::transmute_copy(&pfibercookie)).into()
+            }
+        }
+        unsafe extern "system" fn SwitchOutLogicalThreadState<Identity: windows_core::IUnknownImpl + ICorRuntimeHost_Impl>(this: *mut core::ffi::c_void, pfibercookie: *mut *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorRuntimeHost_Impl::SwitchOutLogicalThreadState(this) {
+                    Ok(ok__) => {
+                        pfibercookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LocksHeldByLogicalThread<Identity: windows_core::IUnknownImpl + ICorRuntimeHost_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorRuntimeHost_Impl::LocksHeldByLogicalThread(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MapFile<Identity: windows_core::IUnknownImpl + ICorRuntimeHost_Impl>(this: *mut core::ffi::c_void, hfile: super::super::Foundation::HANDLE, hmapaddress: *mut super::super::Foundation::HMODULE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorRuntimeHost_Impl::MapFile(this, core::mem::transmute_copy(&hfile)) {
+                    Ok(ok__) => {
+                        hmapaddress.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetConfiguration<Identity: windows_core::IUnknownImpl + ICorRuntimeHost_Impl>(this: *mut core::ffi::c_void, pconfiguration: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorRuntimeHost_Impl::GetConfiguration(this) {
+                    Ok(ok__) => {
+                        pconfiguration.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Start<Identity: windows_core::IUnknownImpl + ICorRuntimeHost_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorRuntimeHost_Impl::Start(this).into()
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + ICorRuntimeHost_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorRuntimeHost_Impl::Stop(this).into()
+            }
+        }
+        unsafe extern "system" fn CreateDomain<Identity: windows_core::IUnknownImpl + ICorRuntimeHost_Impl>(this: *mut core::ffi::c_void, pwzfriendlyname: windows_core::PCWSTR, pidentityarray: *mut core::ffi::c_void, pappdomain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorRuntimeHost_Impl::CreateDomain(this, core::mem::transmute(&pwzfriendlyname), core::mem::transmute_copy(&pidentityarray)) {
+                    Ok(ok__) => {
+                        pappdomain.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDefaultDomain<Identity: windows_core::IUnknownImpl + ICorRuntimeHost_Impl>(this: *mut core::ffi::c_void, pappdomain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorRuntimeHost_Impl::GetDefaultDomain(this) {
+                    Ok(ok__) => {
+                        pappdomain.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumDomains<Identity: windows_core::IUnknownImpl + ICorRuntimeHost_Impl>(this: *mut core::ffi::c_void, henum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorRuntimeHost_Impl::EnumDomains(this, core::mem::transmute_copy(&henum)).into()
+            }
+        }
+        unsafe extern "system" fn NextDomain<Identity: windows_core::IUnknownImpl + ICorRuntimeHost_Impl>(this: *mut core::ffi::c_void, henum: *const core::ffi::c_void, pappdomain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorRuntimeHost_Impl::NextDomain(this, core::mem::transmute_copy(&henum)) {
+                    Ok(ok__) => {
+                        pappdomain.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CloseEnum<Identity: windows_core::IUnknownImpl + ICorRuntimeHost_Impl>(this: *mut core::ffi::c_void, henum: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorRuntimeHost_Impl::CloseEnum(this, core::mem::transmute_copy(&henum)).into()
+            }
+        }
+        unsafe extern "system" fn CreateDomainEx<Identity: windows_core::IUnknownImpl + ICorRuntimeHost_Impl>(this: *mut core::ffi::c_void, pwzfriendlyname: windows_core::PCWSTR, psetup: *mut core::ffi::c_void, pevidence: *mut core::ffi::c_void, pappdomain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorRuntimeHost_Impl::CreateDomainEx(this, core::mem::transmute(&pwzfriendlyname), core::mem::transmute_copy(&psetup), core::mem::transmute_copy(&pevidence)) {
+                    Ok(ok__) => {
+                        pappdomain.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateDomainSetup<Identity: windows_core::IUnknownImpl + ICorRuntimeHost_Impl>(this: *mut core::ffi::c_void, pappdomainsetup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorRuntimeHost_Impl::CreateDomainSetup(this) {
+                    Ok(ok__) => {
+                        pappdomainsetup.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateEvidence<Identity: windows_core::IUnknownImpl + ICorRuntimeHost_Impl>(this: *mut core::ffi::c_void, pevidence: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorRuntimeHost_Impl::CreateEvidence(this) {
+                    Ok(ok__) => {
+                        pevidence.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UnloadDomain<Identity: windows_core::IUnknownImpl + ICorRuntimeHost_Impl>(this: *mut core::ffi::c_void, pappdomain: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorRuntimeHost_Impl::UnloadDomain(this, core::mem::transmute_copy(&pappdomain)).into()
+            }
+        }
+        unsafe extern "system" fn CurrentDomain<Identity: windows_core::IUnknownImpl + ICorRuntimeHost_Impl>(this: *mut core::ffi::c_void, pappdomain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorRuntimeHost_Impl::CurrentDomain(this) {
+                    Ok(ok__) => {
+                        pappdomain.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateLogicalThreadState: CreateLogicalThreadState::<Identity>,
+            DeleteLogicalThreadState: DeleteLogicalThreadState::<Identity>,
+            SwitchInLogicalThreadState: SwitchInLogicalThreadState::<Identity>,
+            SwitchOutLogicalThreadState: SwitchOutLogicalThreadState::<Identity>,
+            LocksHeldByLogicalThread: LocksHeldByLogicalThread::<Identity>,
+            MapFile: MapFile::<Identity>,
+            GetConfiguration: GetConfiguration::<Identity>,
+            Start: Start::<Identity>,
+            Stop: Stop::<Identity>,
+            CreateDomain: CreateDomain::<Identity>,
+            GetDefaultDomain: GetDefaultDomain::<Identity>,
+            EnumDomains: EnumDomains::<Identity>,
+            NextDomain: NextDomain::<Identity>,
+            CloseEnum: CloseEnum::<Identity>,
+            CreateDomainEx: CreateDomainEx::<Identity>,
+            CreateDomainSetup: CreateDomainSetup::<Identity>,
+            CreateEvidence: CreateEvidence::<Identity>,
+            UnloadDomain: UnloadDomain::<Identity>,
+            CurrentDomain: CurrentDomain::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for ICorRuntimeHost {}
+windows_core::imp::define_interface!(ICorThreadpool, ICorThreadpool_Vtbl, 0x84680d3a_b2c1_46e8_acc2_dbc0a359159a);
+windows_core::imp::interface_hierarchy!(ICorThreadpool, windows_core::IUnknown);
+impl ICorThreadpool {
+    #[cfg(feature = "Win32_System_Threading")]
+    pub unsafe fn CorRegisterWaitForSingleObject(&self, phnewwaitobject: *const super::super::Foundation::HANDLE, hwaitobject: super::super::Foundation::HANDLE, callback: super::Threading::WAITORTIMERCALLBACK, context: *const core::ffi::c_void, timeout: u32, executeonlyonce: bool) -> windows_core::Result<windows_core::BOOL> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
             (windows_core::Interface::vtable(self).CorRegisterWaitForSingleObject)(windows_core::Interface::as_raw(self), phnewwaitobject, hwaitobject, callback, context, timeout, executeonlyonce.into(), &mut result__).map(|| result__)
         }
     }
@@ -3998,6 +5722,136 @@ impl ICorThreadpool_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_IO", feature = "Win32_System_Threading"))]
+impl<Identity: windows_core::IUnknownImpl + ICorThreadpool_Impl> windows_core::imp::TearOffVtable<Identity> for ICorThreadpool_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CorRegisterWaitForSingleObject<Identity: windows_core::IUnknownImpl + ICorThreadpool_Impl>(this: *mut core::ffi::c_void, phnewwaitobject: *const super::super::Foundation::HANDLE, hwaitobject: super::super::Foundation::HANDLE, callback: super::Threading::WAITORTIMERCALLBACK, context: *const core::ffi::c_void, timeout: u32, executeonlyonce: windows_core::BOOL, result: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorThreadpool_Impl::CorRegisterWaitForSingleObject(this, core::mem::transmute_copy(&phnewwaitobject), core::mem::transmute_copy(&hwaitobject), core::mem::transmute_copy(&callback), core::mem::transmute_copy(&context), core::mem::transmute_copy(&timeout), core::mem::transmute_copy(&executeonlyonce)) {
+                    Ok(ok__) => {
+                        result.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CorUnregisterWait<Identity: windows_core::IUnknownImpl + ICorThreadpool_Impl>(this: *mut core::ffi::c_void, hwaitobject: super::super::Foundation::HANDLE, completionevent: super::super::Foundation::HANDLE, result: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorThreadpool_Impl::CorUnregisterWait(this, core::mem::transmute_copy(&hwaitobject), core::mem::transmute_copy(&completionevent)) {
+                    Ok(ok__) => {
+                        result.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CorQueueUserWorkItem<Identity: windows_core::IUnknownImpl + ICorThreadpool_Impl>(this: *mut core::ffi::c_void, function: super::Threading::LPTHREAD_START_ROUTINE, context: *const core::ffi::c_void, executeonlyonce: windows_core::BOOL, result: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorThreadpool_Impl::CorQueueUserWorkItem(this, core::mem::transmute_copy(&function), core::mem::transmute_copy(&context), core::mem::transmute_copy(&executeonlyonce)) {
+                    Ok(ok__) => {
+                        result.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CorCreateTimer<Identity: windows_core::IUnknownImpl + ICorThreadpool_Impl>(this: *mut core::ffi::c_void, phnewtimer: *const super::super::Foundation::HANDLE, callback: super::Threading::WAITORTIMERCALLBACK, parameter: *const core::ffi::c_void, duetime: u32, period: u32, result: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorThreadpool_Impl::CorCreateTimer(this, core::mem::transmute_copy(&phnewtimer), core::mem::transmute_copy(&callback), core::mem::transmute_copy(&parameter), core::mem::transmute_copy(&duetime), core::mem::transmute_copy(&period)) {
+                    Ok(ok__) => {
+                        result.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CorChangeTimer<Identity: windows_core::IUnknownImpl + ICorThreadpool_Impl>(this: *mut core::ffi::c_void, timer: super::super::Foundation::HANDLE, duetime: u32, period: u32, result: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorThreadpool_Impl::CorChangeTimer(this, core::mem::transmute_copy(&timer), core::mem::transmute_copy(&duetime), core::mem::transmute_copy(&period)) {
+                    Ok(ok__) => {
+                        result.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CorDeleteTimer<Identity: windows_core::IUnknownImpl + ICorThreadpool_Impl>(this: *mut core::ffi::c_void, timer: super::super::Foundation::HANDLE, completionevent: super::super::Foundation::HANDLE, result: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorThreadpool_Impl::CorDeleteTimer(this, core::mem::transmute_copy(&timer), core::mem::transmute_copy(&completionevent)) {
+                    Ok(ok__) => {
+                        result.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CorBindIoCompletionCallback<Identity: windows_core::IUnknownImpl + ICorThreadpool_Impl>(this: *mut core::ffi::c_void, filehandle: super::super::Foundation::HANDLE, callback: super::IO::LPOVERLAPPED_COMPLETION_ROUTINE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorThreadpool_Impl::CorBindIoCompletionCallback(this, core::mem::transmute_copy(&filehandle), core::mem::transmute_copy(&callback)).into()
+            }
+        }
+        unsafe extern "system" fn CorCallOrQueueUserWorkItem<Identity: windows_core::IUnknownImpl + ICorThreadpool_Impl>(this: *mut core::ffi::c_void, function: super::Threading::LPTHREAD_START_ROUTINE, context: *const core::ffi::c_void, result: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICorThreadpool_Impl::CorCallOrQueueUserWorkItem(this, core::mem::transmute_copy(&function), core::mem::transmute_copy(&context)) {
+                    Ok(ok__) => {
+                        result.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CorSetMaxThreads<Identity: windows_core::IUnknownImpl + ICorThreadpool_Impl>(this: *mut core::ffi::c_void, maxworkerthreads: u32, maxiocompletionthreads: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorThreadpool_Impl::CorSetMaxThreads(this, core::mem::transmute_copy(&maxworkerthreads), core::mem::transmute_copy(&maxiocompletionthreads)).into()
+            }
+        }
+        unsafe extern "system" fn CorGetMaxThreads<Identity: windows_core::IUnknownImpl + ICorThreadpool_Impl>(this: *mut core::ffi::c_void, maxworkerthreads: *mut u32, maxiocompletionthreads: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorThreadpool_Impl::CorGetMaxThreads(this, core::mem::transmute_copy(&maxworkerthreads), core::mem::transmute_copy(&maxiocompletionthreads)).into()
+            }
+        }
+        unsafe extern "system" fn CorGetAvailableThreads<Identity: windows_core::IUnknownImpl + ICorThreadpool_Impl>(this: *mut core::ffi::c_void, availableworkerthreads: *mut u32, availableiocompletionthreads: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICorThreadpool_Impl::CorGetAvailableThreads(this, core::mem::transmute_copy(&availableworkerthreads), core::mem::transmute_copy(&availableiocompletionthreads)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CorRegisterWaitForSingleObject: CorRegisterWaitForSingleObject::<Identity>,
+            CorUnregisterWait: CorUnregisterWait::<Identity>,
+            CorQueueUserWorkItem: CorQueueUserWorkItem::<Identity>,
+            CorCreateTimer: CorCreateTimer::<Identity>,
+            CorChangeTimer: CorChangeTimer::<Identity>,
+            CorDeleteTimer: CorDeleteTimer::<Identity>,
+            CorBindIoCompletionCallback: CorBindIoCompletionCallback::<Identity>,
+            CorCallOrQueueUserWorkItem: CorCallOrQueueUserWorkItem::<Identity>,
+            CorSetMaxThreads: CorSetMaxThreads::<Identity>,
+            CorGetMaxThreads: CorGetMaxThreads::<Identity>,
+            CorGetAvailableThreads: CorGetAvailableThreads::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_IO", feature = "Win32_System_Threading"))]
 impl windows_core::RuntimeName for ICorThreadpool {}
 windows_core::imp::define_interface!(IDebuggerInfo, IDebuggerInfo_Vtbl, 0xbf24142d_a47d_4d24_a66d_8c2141944e44);
 windows_core::imp::interface_hierarchy!(IDebuggerInfo, windows_core::IUnknown);
@@ -4038,6 +5892,26 @@ impl IDebuggerInfo_Vtbl {
         iid == &<IDebuggerInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebuggerInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IDebuggerInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsDebuggerAttached<Identity: windows_core::IUnknownImpl + IDebuggerInfo_Impl>(this: *mut core::ffi::c_void, pbattached: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebuggerInfo_Impl::IsDebuggerAttached(this) {
+                    Ok(ok__) => {
+                        pbattached.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, IsDebuggerAttached: IsDebuggerAttached::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDebuggerInfo {}
 windows_core::imp::define_interface!(IDebuggerThreadControl, IDebuggerThreadControl_Vtbl, 0x23d86786_0bb5_4774_8fb5_e3522add6246);
 windows_core::imp::interface_hierarchy!(IDebuggerThreadControl, windows_core::IUnknown);
@@ -4096,6 +5970,37 @@ impl IDebuggerThreadControl_Vtbl {
         iid == &<IDebuggerThreadControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebuggerThreadControl_Impl> windows_core::imp::TearOffVtable<Identity> for IDebuggerThreadControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ThreadIsBlockingForDebugger<Identity: windows_core::IUnknownImpl + IDebuggerThreadControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebuggerThreadControl_Impl::ThreadIsBlockingForDebugger(this).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseAllRuntimeThreads<Identity: windows_core::IUnknownImpl + IDebuggerThreadControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebuggerThreadControl_Impl::ReleaseAllRuntimeThreads(this).into()
+            }
+        }
+        unsafe extern "system" fn StartBlockingForDebugger<Identity: windows_core::IUnknownImpl + IDebuggerThreadControl_Impl>(this: *mut core::ffi::c_void, dwunused: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebuggerThreadControl_Impl::StartBlockingForDebugger(this, core::mem::transmute_copy(&dwunused)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ThreadIsBlockingForDebugger: ThreadIsBlockingForDebugger::<Identity>,
+            ReleaseAllRuntimeThreads: ReleaseAllRuntimeThreads::<Identity>,
+            StartBlockingForDebugger: StartBlockingForDebugger::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebuggerThreadControl {}
 windows_core::imp::define_interface!(IGCHost, IGCHost_Vtbl, 0xfac34f6e_0dcd_47b5_8021_531bc5ecca63);
 windows_core::imp::interface_hierarchy!(IGCHost, windows_core::IUnknown);
@@ -4178,6 +6083,51 @@ impl IGCHost_Vtbl {
         iid == &<IGCHost as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IGCHost_Impl> windows_core::imp::TearOffVtable<Identity> for IGCHost_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetGCStartupLimits<Identity: windows_core::IUnknownImpl + IGCHost_Impl>(this: *mut core::ffi::c_void, segmentsize: u32, maxgen0size: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGCHost_Impl::SetGCStartupLimits(this, core::mem::transmute_copy(&segmentsize), core::mem::transmute_copy(&maxgen0size)).into()
+            }
+        }
+        unsafe extern "system" fn Collect<Identity: windows_core::IUnknownImpl + IGCHost_Impl>(this: *mut core::ffi::c_void, generation: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGCHost_Impl::Collect(this, core::mem::transmute_copy(&generation)).into()
+            }
+        }
+        unsafe extern "system" fn GetStats<Identity: windows_core::IUnknownImpl + IGCHost_Impl>(this: *mut core::ffi::c_void, pstats: *mut COR_GC_STATS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGCHost_Impl::GetStats(this, core::mem::transmute_copy(&pstats)).into()
+            }
+        }
+        unsafe extern "system" fn GetThreadStats<Identity: windows_core::IUnknownImpl + IGCHost_Impl>(this: *mut core::ffi::c_void, pfibercookie: *const u32, pstats: *mut COR_GC_THREAD_STATS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGCHost_Impl::GetThreadStats(this, core::mem::transmute_copy(&pfibercookie), core::mem::transmute_copy(&pstats)).into()
+            }
+        }
+        unsafe extern "system" fn SetVirtualMemLimit<Identity: windows_core::IUnknownImpl + IGCHost_Impl>(this: *mut core::ffi::c_void, sztmaxvirtualmemmb: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGCHost_Impl::SetVirtualMemLimit(this, core::mem::transmute_copy(&sztmaxvirtualmemmb)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetGCStartupLimits: SetGCStartupLimits::<Identity>,
+            Collect: Collect::<Identity>,
+            GetStats: GetStats::<Identity>,
+            GetThreadStats: GetThreadStats::<Identity>,
+            SetVirtualMemLimit: SetVirtualMemLimit::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IGCHost {}
 windows_core::imp::define_interface!(IGCHost2, IGCHost2_Vtbl, 0xa1d70cec_2dbe_4e2f_9291_fdf81438a1df);
 impl core::ops::Deref for IGCHost2 {
@@ -4215,6 +6165,20 @@ impl IGCHost2_Vtbl {
         iid == &<IGCHost2 as windows_core::Interface>::IID || iid == &<IGCHost as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IGCHost2_Impl> windows_core::imp::TearOffVtable<Identity> for IGCHost2_Vtbl
+where
+    IGCHost_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetGCStartupLimitsEx<Identity: windows_core::IUnknownImpl + IGCHost2_Impl>(this: *mut core::ffi::c_void, segmentsize: usize, maxgen0size: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGCHost2_Impl::SetGCStartupLimitsEx(this, core::mem::transmute_copy(&segmentsize), core::mem::transmute_copy(&maxgen0size)).into()
+            }
+        }
+        Self { base__: <IGCHost_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetGCStartupLimitsEx: SetGCStartupLimitsEx::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IGCHost2 {}
 windows_core::imp::define_interface!(IGCHostControl, IGCHostControl_Vtbl, 0x5513d564_8374_4cb9_aed9_0083f4160a1d);
 windows_core::imp::interface_hierarchy!(IGCHostControl, windows_core::IUnknown);
@@ -4246,6 +6210,23 @@ impl IGCHostControl_Vtbl {
         iid == &<IGCHostControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IGCHostControl_Impl> windows_core::imp::TearOffVtable<Identity> for IGCHostControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RequestVirtualMemLimit<Identity: windows_core::IUnknownImpl + IGCHostControl_Impl>(this: *mut core::ffi::c_void, sztmaxvirtualmemmb: usize, psztnewmaxvirtualmemmb: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGCHostControl_Impl::RequestVirtualMemLimit(this, core::mem::transmute_copy(&sztmaxvirtualmemmb), core::mem::transmute_copy(&psztnewmaxvirtualmemmb)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RequestVirtualMemLimit: RequestVirtualMemLimit::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IGCHostControl {}
 windows_core::imp::define_interface!(IGCThreadControl, IGCThreadControl_Vtbl, 0xf31d1788_c397_4725_87a5_6af3472c2791);
 windows_core::imp::interface_hierarchy!(IGCThreadControl, windows_core::IUnknown);
@@ -4299,10 +6280,41 @@ impl IGCThreadControl_Vtbl {
             SuspensionStarting: SuspensionStarting::<Identity, OFFSET>,
             SuspensionEnding: SuspensionEnding::<Identity, OFFSET>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IGCThreadControl as windows_core::Interface>::IID
-    }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGCThreadControl as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IGCThreadControl_Impl> windows_core::imp::TearOffVtable<Identity> for IGCThreadControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ThreadIsBlockingForSuspension<Identity: windows_core::IUnknownImpl + IGCThreadControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGCThreadControl_Impl::ThreadIsBlockingForSuspension(this).into()
+            }
+        }
+        unsafe extern "system" fn SuspensionStarting<Identity: windows_core::IUnknownImpl + IGCThreadControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGCThreadControl_Impl::SuspensionStarting(this).into()
+            }
+        }
+        unsafe extern "system" fn SuspensionEnding<Identity: windows_core::IUnknownImpl + IGCThreadControl_Impl>(this: *mut core::ffi::c_void, generation: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGCThreadControl_Impl::SuspensionEnding(this, core::mem::transmute_copy(&generation)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ThreadIsBlockingForSuspension: ThreadIsBlockingForSuspension::<Identity>,
+            SuspensionStarting: SuspensionStarting::<Identity>,
+            SuspensionEnding: SuspensionEnding::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IGCThreadControl {}
 windows_core::imp::define_interface!(IHostAssemblyManager, IHostAssemblyManager_Vtbl, 0x613dabd7_62b2_493e_9e65_c1e32a1e0c5e);
@@ -4368,6 +6380,42 @@ impl IHostAssemblyManager_Vtbl {
         iid == &<IHostAssemblyManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IHostAssemblyManager_Impl> windows_core::imp::TearOffVtable<Identity> for IHostAssemblyManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetNonHostStoreAssemblies<Identity: windows_core::IUnknownImpl + IHostAssemblyManager_Impl>(this: *mut core::ffi::c_void, ppreferencelist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHostAssemblyManager_Impl::GetNonHostStoreAssemblies(this) {
+                    Ok(ok__) => {
+                        ppreferencelist.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAssemblyStore<Identity: windows_core::IUnknownImpl + IHostAssemblyManager_Impl>(this: *mut core::ffi::c_void, ppassemblystore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHostAssemblyManager_Impl::GetAssemblyStore(this) {
+                    Ok(ok__) => {
+                        ppassemblystore.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetNonHostStoreAssemblies: GetNonHostStoreAssemblies::<Identity>,
+            GetAssemblyStore: GetAssemblyStore::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IHostAssemblyManager {}
 windows_core::imp::define_interface!(IHostAssemblyStore, IHostAssemblyStore_Vtbl, 0x7b102a88_3f7f_496d_8fa2_c35374e01af3);
 windows_core::imp::interface_hierarchy!(IHostAssemblyStore, windows_core::IUnknown);
@@ -4425,6 +6473,31 @@ impl IHostAssemblyStore_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IHostAssemblyStore_Impl> windows_core::imp::TearOffVtable<Identity> for IHostAssemblyStore_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ProvideAssembly<Identity: windows_core::IUnknownImpl + IHostAssemblyStore_Impl>(this: *mut core::ffi::c_void, pbindinfo: *const AssemblyBindInfo, passemblyid: *mut u64, pcontext: *mut u64, ppstmassemblyimage: *mut *mut core::ffi::c_void, ppstmpdb: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostAssemblyStore_Impl::ProvideAssembly(this, core::mem::transmute_copy(&pbindinfo), core::mem::transmute_copy(&passemblyid), core::mem::transmute_copy(&pcontext), core::mem::transmute_copy(&ppstmassemblyimage), core::mem::transmute_copy(&ppstmpdb)).into()
+            }
+        }
+        unsafe extern "system" fn ProvideModule<Identity: windows_core::IUnknownImpl + IHostAssemblyStore_Impl>(this: *mut core::ffi::c_void, pbindinfo: *const ModuleBindInfo, pdwmoduleid: *mut u32, ppstmmoduleimage: *mut *mut core::ffi::c_void, ppstmpdb: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostAssemblyStore_Impl::ProvideModule(this, core::mem::transmute_copy(&pbindinfo), core::mem::transmute_copy(&pdwmoduleid), core::mem::transmute_copy(&ppstmmoduleimage), core::mem::transmute_copy(&ppstmpdb)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ProvideAssembly: ProvideAssembly::<Identity>,
+            ProvideModule: ProvideModule::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IHostAssemblyStore {}
 windows_core::imp::define_interface!(IHostAutoEvent, IHostAutoEvent_Vtbl, 0x50b0cfce_4063_4278_9673_e5cb4ed0bdb8);
 windows_core::imp::interface_hierarchy!(IHostAutoEvent, windows_core::IUnknown);
@@ -4467,6 +6540,26 @@ impl IHostAutoEvent_Vtbl {
         iid == &<IHostAutoEvent as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IHostAutoEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IHostAutoEvent_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Wait<Identity: windows_core::IUnknownImpl + IHostAutoEvent_Impl>(this: *mut core::ffi::c_void, dwmilliseconds: u32, option: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostAutoEvent_Impl::Wait(this, core::mem::transmute_copy(&dwmilliseconds), core::mem::transmute_copy(&option)).into()
+            }
+        }
+        unsafe extern "system" fn Set<Identity: windows_core::IUnknownImpl + IHostAutoEvent_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostAutoEvent_Impl::Set(this).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Wait: Wait::<Identity>, Set: Set::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IHostAutoEvent {}
 windows_core::imp::define_interface!(IHostControl, IHostControl_Vtbl, 0x02ca073c_7079_4860_880a_c2f7a449c991);
 windows_core::imp::interface_hierarchy!(IHostControl, windows_core::IUnknown);
@@ -4516,6 +6609,30 @@ impl IHostControl_Vtbl {
         iid == &<IHostControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IHostControl_Impl> windows_core::imp::TearOffVtable<Identity> for IHostControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetHostManager<Identity: windows_core::IUnknownImpl + IHostControl_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostControl_Impl::GetHostManager(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppobject)).into()
+            }
+        }
+        unsafe extern "system" fn SetAppDomainManager<Identity: windows_core::IUnknownImpl + IHostControl_Impl>(this: *mut core::ffi::c_void, dwappdomainid: u32, punkappdomainmanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostControl_Impl::SetAppDomainManager(this, core::mem::transmute_copy(&dwappdomainid), core::mem::transmute_copy(&punkappdomainmanager)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetHostManager: GetHostManager::<Identity>,
+            SetAppDomainManager: SetAppDomainManager::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IHostControl {}
 windows_core::imp::define_interface!(IHostCrst, IHostCrst_Vtbl, 0x6df710a6_26a4_4a65_8cd5_7237b8bda8dc);
 windows_core::imp::interface_hierarchy!(IHostCrst, windows_core::IUnknown);
@@ -4595,6 +6712,50 @@ impl IHostCrst_Vtbl {
         iid == &<IHostCrst as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IHostCrst_Impl> windows_core::imp::TearOffVtable<Identity> for IHostCrst_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Enter<Identity: windows_core::IUnknownImpl + IHostCrst_Impl>(this: *mut core::ffi::c_void, option: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostCrst_Impl::Enter(this, core::mem::transmute_copy(&option)).into()
+            }
+        }
+        unsafe extern "system" fn Leave<Identity: windows_core::IUnknownImpl + IHostCrst_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostCrst_Impl::Leave(this).into()
+            }
+        }
+        unsafe extern "system" fn TryEnter<Identity: windows_core::IUnknownImpl + IHostCrst_Impl>(this: *mut core::ffi::c_void, option: u32, pbsucceeded: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHostCrst_Impl::TryEnter(this, core::mem::transmute_copy(&option)) {
+                    Ok(ok__) => {
+                        pbsucceeded.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSpinCount<Identity: windows_core::IUnknownImpl + IHostCrst_Impl>(this: *mut core::ffi::c_void, dwspincount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostCrst_Impl::SetSpinCount(this, core::mem::transmute_copy(&dwspincount)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Enter: Enter::<Identity>,
+            Leave: Leave::<Identity>,
+            TryEnter: TryEnter::<Identity>,
+            SetSpinCount: SetSpinCount::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IHostCrst {}
 windows_core::imp::define_interface!(IHostGCManager, IHostGCManager_Vtbl, 0x5d4ec34e_f248_457b_b603_255faaba0d21);
 windows_core::imp::interface_hierarchy!(IHostGCManager, windows_core::IUnknown);
@@ -4653,6 +6814,37 @@ impl IHostGCManager_Vtbl {
         iid == &<IHostGCManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IHostGCManager_Impl> windows_core::imp::TearOffVtable<Identity> for IHostGCManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ThreadIsBlockingForSuspension<Identity: windows_core::IUnknownImpl + IHostGCManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostGCManager_Impl::ThreadIsBlockingForSuspension(this).into()
+            }
+        }
+        unsafe extern "system" fn SuspensionStarting<Identity: windows_core::IUnknownImpl + IHostGCManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostGCManager_Impl::SuspensionStarting(this).into()
+            }
+        }
+        unsafe extern "system" fn SuspensionEnding<Identity: windows_core::IUnknownImpl + IHostGCManager_Impl>(this: *mut core::ffi::c_void, generation: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostGCManager_Impl::SuspensionEnding(this, core::mem::transmute_copy(&generation)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ThreadIsBlockingForSuspension: ThreadIsBlockingForSuspension::<Identity>,
+            SuspensionStarting: SuspensionStarting::<Identity>,
+            SuspensionEnding: SuspensionEnding::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IHostGCManager {}
 windows_core::imp::define_interface!(IHostIoCompletionManager, IHostIoCompletionManager_Vtbl, 0x8bde9d80_ec06_41d6_83e6_22580effcc20);
 windows_core::imp::interface_hierarchy!(IHostIoCompletionManager, windows_core::IUnknown);
@@ -4709,40 +6901,160 @@ impl IHostIoCompletionManager {
         }
     }
 }
-#[repr(C)]
-#[doc(hidden)]
-pub struct IHostIoCompletionManager_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    pub CreateIoCompletionPort: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT,
-    pub CloseIoCompletionPort: unsafe extern "system" fn(*mut core::ffi::c_void, super::super::Foundation::HANDLE) -> windows_core::HRESULT,
-    pub SetMaxThreads: unsafe extern "system" fn(*mut core::ffi::c_void, u32) -> windows_core::HRESULT,
-    pub GetMaxThreads: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
-    pub GetAvailableThreads: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
-    pub GetHostOverlappedSize: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
-    pub SetCLRIoCompletionManager: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub InitializeHostOverlapped: unsafe extern "system" fn(*mut core::ffi::c_void, *const core::ffi::c_void) -> windows_core::HRESULT,
-    pub Bind: unsafe extern "system" fn(*mut core::ffi::c_void, super::super::Foundation::HANDLE, super::super::Foundation::HANDLE) -> windows_core::HRESULT,
-    pub SetMinThreads: unsafe extern "system" fn(*mut core::ffi::c_void, u32) -> windows_core::HRESULT,
-    pub GetMinThreads: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
-}
-pub trait IHostIoCompletionManager_Impl: windows_core::IUnknownImpl {
-    fn CreateIoCompletionPort(&self) -> windows_core::Result<super::super::Foundation::HANDLE>;
-    fn CloseIoCompletionPort(&self, hport: super::super::Foundation::HANDLE) -> windows_core::Result<()>;
-    fn SetMaxThreads(&self, dwmaxiocompletionthreads: u32) -> windows_core::Result<()>;
-    fn GetMaxThreads(&self) -> windows_core::Result<u32>;
-    fn GetAvailableThreads(&self) -> windows_core::Result<u32>;
-    fn GetHostOverlappedSize(&self) -> windows_core::Result<u32>;
-    fn SetCLRIoCompletionManager(&self, pmanager: windows_core::Ref<ICLRIoCompletionManager>) -> windows_core::Result<()>;
-    fn InitializeHostOverlapped(&self, pvoverlapped: *const core::ffi::c_void) -> windows_core::Result<()>;
-    fn Bind(&self, hport: super::super::Foundation::HANDLE, hhandle: super::super::Foundation::HANDLE) -> windows_core::Result<()>;
-    fn SetMinThreads(&self, dwminiocompletionthreads: u32) -> windows_core::Result<()>;
-    fn GetMinThreads(&self) -> windows_core::Result<u32>;
-}
-impl IHostIoCompletionManager_Vtbl {
-    pub const fn new<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn CreateIoCompletionPort<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, phport: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+#[repr(C)]
+#[doc(hidden)]
+pub struct IHostIoCompletionManager_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub CreateIoCompletionPort: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT,
+    pub CloseIoCompletionPort: unsafe extern "system" fn(*mut core::ffi::c_void, super::super::Foundation::HANDLE) -> windows_core::HRESULT,
+    pub SetMaxThreads: unsafe extern "system" fn(*mut core::ffi::c_void, u32) -> windows_core::HRESULT,
+    pub GetMaxThreads: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
+    pub GetAvailableThreads: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
+    pub GetHostOverlappedSize: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
+    pub SetCLRIoCompletionManager: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub InitializeHostOverlapped: unsafe extern "system" fn(*mut core::ffi::c_void, *const core::ffi::c_void) -> windows_core::HRESULT,
+    pub Bind: unsafe extern "system" fn(*mut core::ffi::c_void, super::super::Foundation::HANDLE, super::super::Foundation::HANDLE) -> windows_core::HRESULT,
+    pub SetMinThreads: unsafe extern "system" fn(*mut core::ffi::c_void, u32) -> windows_core::HRESULT,
+    pub GetMinThreads: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
+}
+pub trait IHostIoCompletionManager_Impl: windows_core::IUnknownImpl {
+    fn CreateIoCompletionPort(&self) -> windows_core::Result<super::super::Foundation::HANDLE>;
+    fn CloseIoCompletionPort(&self, hport: super::super::Foundation::HANDLE) -> windows_core::Result<()>;
+    fn SetMaxThreads(&self, dwmaxiocompletionthreads: u32) -> windows_core::Result<()>;
+    fn GetMaxThreads(&self) -> windows_core::Result<u32>;
+    fn GetAvailableThreads(&self) -> windows_core::Result<u32>;
+    fn GetHostOverlappedSize(&self) -> windows_core::Result<u32>;
+    fn SetCLRIoCompletionManager(&self, pmanager: windows_core::Ref<ICLRIoCompletionManager>) -> windows_core::Result<()>;
+    fn InitializeHostOverlapped(&self, pvoverlapped: *const core::ffi::c_void) -> windows_core::Result<()>;
+    fn Bind(&self, hport: super::super::Foundation::HANDLE, hhandle: super::super::Foundation::HANDLE) -> windows_core::Result<()>;
+    fn SetMinThreads(&self, dwminiocompletionthreads: u32) -> windows_core::Result<()>;
+    fn GetMinThreads(&self) -> windows_core::Result<u32>;
+}
+impl IHostIoCompletionManager_Vtbl {
+    pub const fn new<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn CreateIoCompletionPort<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, phport: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IHostIoCompletionManager_Impl::CreateIoCompletionPort(this) {
+                    Ok(ok__) => {
+                        phport.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CloseIoCompletionPort<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hport: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IHostIoCompletionManager_Impl::CloseIoCompletionPort(this, core::mem::transmute_copy(&hport)).into()
+            }
+        }
+        unsafe extern "system" fn SetMaxThreads<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwmaxiocompletionthreads: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IHostIoCompletionManager_Impl::SetMaxThreads(this, core::mem::transmute_copy(&dwmaxiocompletionthreads)).into()
+            }
+        }
+        unsafe extern "system" fn GetMaxThreads<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwmaxiocompletionthreads: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IHostIoCompletionManager_Impl::GetMaxThreads(this) {
+                    Ok(ok__) => {
+                        pdwmaxiocompletionthreads.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAvailableThreads<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwavailableiocompletionthreads: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IHostIoCompletionManager_Impl::GetAvailableThreads(this) {
+                    Ok(ok__) => {
+                        pdwavailableiocompletionthreads.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHostOverlappedSize<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pcbsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IHostIoCompletionManager_Impl::GetHostOverlappedSize(this) {
+                    Ok(ok__) => {
+                        pcbsize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCLRIoCompletionManager<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pmanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IHostIoCompletionManager_Impl::SetCLRIoCompletionManager(this, core::mem::transmute_copy(&pmanager)).into()
+            }
+        }
+        unsafe extern "system" fn InitializeHostOverlapped<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pvoverlapped: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IHostIoCompletionManager_Impl::InitializeHostOverlapped(this, core::mem::transmute_copy(&pvoverlapped)).into()
+            }
+        }
+        unsafe extern "system" fn Bind<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hport: super::super::Foundation::HANDLE, hhandle: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IHostIoCompletionManager_Impl::Bind(this, core::mem::transmute_copy(&hport), core::mem::transmute_copy(&hhandle)).into()
+            }
+        }
+        unsafe extern "system" fn SetMinThreads<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwminiocompletionthreads: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IHostIoCompletionManager_Impl::SetMinThreads(this, core::mem::transmute_copy(&dwminiocompletionthreads)).into()
+            }
+        }
+        unsafe extern "system" fn GetMinThreads<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwminiocompletionthreads: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IHostIoCompletionManager_Impl::GetMinThreads(this) {
+                    Ok(ok__) => {
+                        pdwminiocompletionthreads.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            CreateIoCompletionPort: CreateIoCompletionPort::<Identity, OFFSET>,
+            CloseIoCompletionPort: CloseIoCompletionPort::<Identity, OFFSET>,
+            SetMaxThreads: SetMaxThreads::<Identity, OFFSET>,
+            GetMaxThreads: GetMaxThreads::<Identity, OFFSET>,
+            GetAvailableThreads: GetAvailableThreads::<Identity, OFFSET>,
+            GetHostOverlappedSize: GetHostOverlappedSize::<Identity, OFFSET>,
+            SetCLRIoCompletionManager: SetCLRIoCompletionManager::<Identity, OFFSET>,
+            InitializeHostOverlapped: InitializeHostOverlapped::<Identity, OFFSET>,
+            Bind: Bind::<Identity, OFFSET>,
+            SetMinThreads: SetMinThreads::<Identity, OFFSET>,
+            GetMinThreads: GetMinThreads::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostIoCompletionManager as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IHostIoCompletionManager_Impl> windows_core::imp::TearOffVtable<Identity> for IHostIoCompletionManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateIoCompletionPort<Identity: windows_core::IUnknownImpl + IHostIoCompletionManager_Impl>(this: *mut core::ffi::c_void, phport: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IHostIoCompletionManager_Impl::CreateIoCompletionPort(this) {
                     Ok(ok__) => {
                         phport.write(core::mem::transmute(ok__));
@@ -4752,21 +7064,21 @@ impl IHostIoCompletionManager_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CloseIoCompletionPort<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hport: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+        unsafe extern "system" fn CloseIoCompletionPort<Identity: windows_core::IUnknownImpl + IHostIoCompletionManager_Impl>(this: *mut core::ffi::c_void, hport: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IHostIoCompletionManager_Impl::CloseIoCompletionPort(this, core::mem::transmute_copy(&hport)).into()
             }
         }
-        unsafe extern "system" fn SetMaxThreads<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwmaxiocompletionthreads: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetMaxThreads<Identity: windows_core::IUnknownImpl + IHostIoCompletionManager_Impl>(this: *mut core::ffi::c_void, dwmaxiocompletionthreads: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IHostIoCompletionManager_Impl::SetMaxThreads(this, core::mem::transmute_copy(&dwmaxiocompletionthreads)).into()
             }
         }
-        unsafe extern "system" fn GetMaxThreads<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwmaxiocompletionthreads: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetMaxThreads<Identity: windows_core::IUnknownImpl + IHostIoCompletionManager_Impl>(this: *mut core::ffi::c_void, pdwmaxiocompletionthreads: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IHostIoCompletionManager_Impl::GetMaxThreads(this) {
                     Ok(ok__) => {
                         pdwmaxiocompletionthreads.write(core::mem::transmute(ok__));
@@ -4776,9 +7088,9 @@ impl IHostIoCompletionManager_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetAvailableThreads<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwavailableiocompletionthreads: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetAvailableThreads<Identity: windows_core::IUnknownImpl + IHostIoCompletionManager_Impl>(this: *mut core::ffi::c_void, pdwavailableiocompletionthreads: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IHostIoCompletionManager_Impl::GetAvailableThreads(this) {
                     Ok(ok__) => {
                         pdwavailableiocompletionthreads.write(core::mem::transmute(ok__));
@@ -4788,9 +7100,9 @@ impl IHostIoCompletionManager_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetHostOverlappedSize<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pcbsize: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetHostOverlappedSize<Identity: windows_core::IUnknownImpl + IHostIoCompletionManager_Impl>(this: *mut core::ffi::c_void, pcbsize: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IHostIoCompletionManager_Impl::GetHostOverlappedSize(this) {
                     Ok(ok__) => {
                         pcbsize.write(core::mem::transmute(ok__));
@@ -4800,33 +7112,33 @@ impl IHostIoCompletionManager_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetCLRIoCompletionManager<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pmanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetCLRIoCompletionManager<Identity: windows_core::IUnknownImpl + IHostIoCompletionManager_Impl>(this: *mut core::ffi::c_void, pmanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IHostIoCompletionManager_Impl::SetCLRIoCompletionManager(this, core::mem::transmute_copy(&pmanager)).into()
             }
         }
-        unsafe extern "system" fn InitializeHostOverlapped<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pvoverlapped: *const core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn InitializeHostOverlapped<Identity: windows_core::IUnknownImpl + IHostIoCompletionManager_Impl>(this: *mut core::ffi::c_void, pvoverlapped: *const core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IHostIoCompletionManager_Impl::InitializeHostOverlapped(this, core::mem::transmute_copy(&pvoverlapped)).into()
             }
         }
-        unsafe extern "system" fn Bind<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hport: super::super::Foundation::HANDLE, hhandle: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+        unsafe extern "system" fn Bind<Identity: windows_core::IUnknownImpl + IHostIoCompletionManager_Impl>(this: *mut core::ffi::c_void, hport: super::super::Foundation::HANDLE, hhandle: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IHostIoCompletionManager_Impl::Bind(this, core::mem::transmute_copy(&hport), core::mem::transmute_copy(&hhandle)).into()
             }
         }
-        unsafe extern "system" fn SetMinThreads<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwminiocompletionthreads: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetMinThreads<Identity: windows_core::IUnknownImpl + IHostIoCompletionManager_Impl>(this: *mut core::ffi::c_void, dwminiocompletionthreads: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IHostIoCompletionManager_Impl::SetMinThreads(this, core::mem::transmute_copy(&dwminiocompletionthreads)).into()
             }
         }
-        unsafe extern "system" fn GetMinThreads<Identity: IHostIoCompletionManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwminiocompletionthreads: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetMinThreads<Identity: windows_core::IUnknownImpl + IHostIoCompletionManager_Impl>(this: *mut core::ffi::c_void, pdwminiocompletionthreads: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IHostIoCompletionManager_Impl::GetMinThreads(this) {
                     Ok(ok__) => {
                         pdwminiocompletionthreads.write(core::mem::transmute(ok__));
@@ -4837,23 +7149,20 @@ impl IHostIoCompletionManager_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            CreateIoCompletionPort: CreateIoCompletionPort::<Identity, OFFSET>,
-            CloseIoCompletionPort: CloseIoCompletionPort::<Identity, OFFSET>,
-            SetMaxThreads: SetMaxThreads::<Identity, OFFSET>,
-            GetMaxThreads: GetMaxThreads::<Identity, OFFSET>,
-            GetAvailableThreads: GetAvailableThreads::<Identity, OFFSET>,
-            GetHostOverlappedSize: GetHostOverlappedSize::<Identity, OFFSET>,
-            SetCLRIoCompletionManager: SetCLRIoCompletionManager::<Identity, OFFSET>,
-            InitializeHostOverlapped: InitializeHostOverlapped::<Identity, OFFSET>,
-            Bind: Bind::<Identity, OFFSET>,
-            SetMinThreads: SetMinThreads::<Identity, OFFSET>,
-            GetMinThreads: GetMinThreads::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IHostIoCompletionManager as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateIoCompletionPort: CreateIoCompletionPort::<Identity>,
+            CloseIoCompletionPort: CloseIoCompletionPort::<Identity>,
+            SetMaxThreads: SetMaxThreads::<Identity>,
+            GetMaxThreads: GetMaxThreads::<Identity>,
+            GetAvailableThreads: GetAvailableThreads::<Identity>,
+            GetHostOverlappedSize: GetHostOverlappedSize::<Identity>,
+            SetCLRIoCompletionManager: SetCLRIoCompletionManager::<Identity>,
+            InitializeHostOverlapped: InitializeHostOverlapped::<Identity>,
+            Bind: Bind::<Identity>,
+            SetMinThreads: SetMinThreads::<Identity>,
+            GetMinThreads: GetMinThreads::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IHostIoCompletionManager {}
 windows_core::imp::define_interface!(IHostMalloc, IHostMalloc_Vtbl, 0x1831991c_cc53_4a31_b218_04e910446479);
@@ -4913,6 +7222,37 @@ impl IHostMalloc_Vtbl {
         iid == &<IHostMalloc as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IHostMalloc_Impl> windows_core::imp::TearOffVtable<Identity> for IHostMalloc_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Alloc<Identity: windows_core::IUnknownImpl + IHostMalloc_Impl>(this: *mut core::ffi::c_void, cbsize: usize, ecriticallevel: EMemoryCriticalLevel, ppmem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostMalloc_Impl::Alloc(this, core::mem::transmute_copy(&cbsize), core::mem::transmute_copy(&ecriticallevel), core::mem::transmute_copy(&ppmem)).into()
+            }
+        }
+        unsafe extern "system" fn DebugAlloc<Identity: windows_core::IUnknownImpl + IHostMalloc_Impl>(this: *mut core::ffi::c_void, cbsize: usize, ecriticallevel: EMemoryCriticalLevel, pszfilename: *const u8, ilineno: i32, ppmem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostMalloc_Impl::DebugAlloc(this, core::mem::transmute_copy(&cbsize), core::mem::transmute_copy(&ecriticallevel), core::mem::transmute_copy(&pszfilename), core::mem::transmute_copy(&ilineno), core::mem::transmute_copy(&ppmem)).into()
+            }
+        }
+        unsafe extern "system" fn Free<Identity: windows_core::IUnknownImpl + IHostMalloc_Impl>(this: *mut core::ffi::c_void, pmem: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostMalloc_Impl::Free(this, core::mem::transmute_copy(&pmem)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Alloc: Alloc::<Identity>,
+            DebugAlloc: DebugAlloc::<Identity>,
+            Free: Free::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IHostMalloc {}
 windows_core::imp::define_interface!(IHostManualEvent, IHostManualEvent_Vtbl, 0x1bf4ec38_affe_4fb9_85a6_525268f15b54);
 windows_core::imp::interface_hierarchy!(IHostManualEvent, windows_core::IUnknown);
@@ -4971,6 +7311,37 @@ impl IHostManualEvent_Vtbl {
         iid == &<IHostManualEvent as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IHostManualEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IHostManualEvent_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Wait<Identity: windows_core::IUnknownImpl + IHostManualEvent_Impl>(this: *mut core::ffi::c_void, dwmilliseconds: u32, option: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostManualEvent_Impl::Wait(this, core::mem::transmute_copy(&dwmilliseconds), core::mem::transmute_copy(&option)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IHostManualEvent_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostManualEvent_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Set<Identity: windows_core::IUnknownImpl + IHostManualEvent_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostManualEvent_Impl::Set(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Wait: Wait::<Identity>,
+            Reset: Reset::<Identity>,
+            Set: Set::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IHostManualEvent {}
 windows_core::imp::define_interface!(IHostMemoryManager, IHostMemoryManager_Vtbl, 0x7bc698d1_f9e3_4460_9cde_d04248e9fa25);
 windows_core::imp::interface_hierarchy!(IHostMemoryManager, windows_core::IUnknown);
@@ -5134,6 +7505,98 @@ impl IHostMemoryManager_Vtbl {
         iid == &<IHostMemoryManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IHostMemoryManager_Impl> windows_core::imp::TearOffVtable<Identity> for IHostMemoryManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateMalloc<Identity: windows_core::IUnknownImpl + IHostMemoryManager_Impl>(this: *mut core::ffi::c_void, dwmalloctype: u32, ppmalloc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHostMemoryManager_Impl::CreateMalloc(this, core::mem::transmute_copy(&dwmalloctype)) {
+                    Ok(ok__) => {
+                        ppmalloc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn VirtualAlloc<Identity: windows_core::IUnknownImpl + IHostMemoryManager_Impl>(this: *mut core::ffi::c_void, paddress: *const core::ffi::c_void, dwsize: usize, flallocationtype: u32, flprotect: u32, ecriticallevel: EMemoryCriticalLevel, ppmem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostMemoryManager_Impl::VirtualAlloc(this, core::mem::transmute_copy(&paddress), core::mem::transmute_copy(&dwsize), core::mem::transmute_copy(&flallocationtype), core::mem::transmute_copy(&flprotect), core::mem::transmute_copy(&ecriticallevel), core::mem::transmute_copy(&ppmem)).into()
+            }
+        }
+        unsafe extern "system" fn VirtualFree<Identity: windows_core::IUnknownImpl + IHostMemoryManager_Impl>(this: *mut core::ffi::c_void, lpaddress: *const core::ffi::c_void, dwsize: usize, dwfreetype: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostMemoryManager_Impl::VirtualFree(this, core::mem::transmute_copy(&lpaddress), core::mem::transmute_copy(&dwsize), core::mem::transmute_copy(&dwfreetype)).into()
+            }
+        }
+        unsafe extern "system" fn VirtualQuery<Identity: windows_core::IUnknownImpl + IHostMemoryManager_Impl>(this: *mut core::ffi::c_void, lpaddress: *const core::ffi::c_void, lpbuffer: *mut core::ffi::c_void, dwlength: usize, presult: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostMemoryManager_Impl::VirtualQuery(this, core::mem::transmute_copy(&lpaddress), core::mem::transmute_copy(&lpbuffer), core::mem::transmute_copy(&dwlength), core::mem::transmute_copy(&presult)).into()
+            }
+        }
+        unsafe extern "system" fn VirtualProtect<Identity: windows_core::IUnknownImpl + IHostMemoryManager_Impl>(this: *mut core::ffi::c_void, lpaddress: *const core::ffi::c_void, dwsize: usize, flnewprotect: u32, pfloldprotect: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHostMemoryManager_Impl::VirtualProtect(this, core::mem::transmute_copy(&lpaddress), core::mem::transmute_copy(&dwsize), core::mem::transmute_copy(&flnewprotect)) {
+                    Ok(ok__) => {
+                        pfloldprotect.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMemoryLoad<Identity: windows_core::IUnknownImpl + IHostMemoryManager_Impl>(this: *mut core::ffi::c_void, pmemoryload: *mut u32, pavailablebytes: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostMemoryManager_Impl::GetMemoryLoad(this, core::mem::transmute_copy(&pmemoryload), core::mem::transmute_copy(&pavailablebytes)).into()
+            }
+        }
+        unsafe extern "system" fn RegisterMemoryNotificationCallback<Identity: windows_core::IUnknownImpl + IHostMemoryManager_Impl>(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostMemoryManager_Impl::RegisterMemoryNotificationCallback(this, core::mem::transmute_copy(&pcallback)).into()
+            }
+        }
+        unsafe extern "system" fn NeedsVirtualAddressSpace<Identity: windows_core::IUnknownImpl + IHostMemoryManager_Impl>(this: *mut core::ffi::c_void, startaddress: *const core::ffi::c_void, size: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostMemoryManager_Impl::NeedsVirtualAddressSpace(this, core::mem::transmute_copy(&startaddress), core::mem::transmute_copy(&size)).into()
+            }
+        }
+        unsafe extern "system" fn AcquiredVirtualAddressSpace<Identity: windows_core::IUnknownImpl + IHostMemoryManager_Impl>(this: *mut core::ffi::c_void, startaddress: *const core::ffi::c_void, size: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostMemoryManager_Impl::AcquiredVirtualAddressSpace(this, core::mem::transmute_copy(&startaddress), core::mem::transmute_copy(&size)).into()
+            }
+        }
+        unsafe extern "system" fn ReleasedVirtualAddressSpace<Identity: windows_core::IUnknownImpl + IHostMemoryManager_Impl>(this: *mut core::ffi::c_void, startaddress: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostMemoryManager_Impl::ReleasedVirtualAddressSpace(this, core::mem::transmute_copy(&startaddress)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateMalloc: CreateMalloc::<Identity>,
+            VirtualAlloc: VirtualAlloc::<Identity>,
+            VirtualFree: VirtualFree::<Identity>,
+            VirtualQuery: VirtualQuery::<Identity>,
+            VirtualProtect: VirtualProtect::<Identity>,
+            GetMemoryLoad: GetMemoryLoad::<Identity>,
+            RegisterMemoryNotificationCallback: RegisterMemoryNotificationCallback::<Identity>,
+            NeedsVirtualAddressSpace: NeedsVirtualAddressSpace::<Identity>,
+            AcquiredVirtualAddressSpace: AcquiredVirtualAddressSpace::<Identity>,
+            ReleasedVirtualAddressSpace: ReleasedVirtualAddressSpace::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IHostMemoryManager {}
 windows_core::imp::define_interface!(IHostPolicyManager, IHostPolicyManager_Vtbl, 0x7ae49844_b1e3_4683_ba7c_1e8212ea3b79);
 windows_core::imp::interface_hierarchy!(IHostPolicyManager, windows_core::IUnknown);
@@ -5192,6 +7655,37 @@ impl IHostPolicyManager_Vtbl {
         iid == &<IHostPolicyManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IHostPolicyManager_Impl> windows_core::imp::TearOffVtable<Identity> for IHostPolicyManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnDefaultAction<Identity: windows_core::IUnknownImpl + IHostPolicyManager_Impl>(this: *mut core::ffi::c_void, operation: EClrOperation, action: EPolicyAction) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostPolicyManager_Impl::OnDefaultAction(this, core::mem::transmute_copy(&operation), core::mem::transmute_copy(&action)).into()
+            }
+        }
+        unsafe extern "system" fn OnTimeout<Identity: windows_core::IUnknownImpl + IHostPolicyManager_Impl>(this: *mut core::ffi::c_void, operation: EClrOperation, action: EPolicyAction) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostPolicyManager_Impl::OnTimeout(this, core::mem::transmute_copy(&operation), core::mem::transmute_copy(&action)).into()
+            }
+        }
+        unsafe extern "system" fn OnFailure<Identity: windows_core::IUnknownImpl + IHostPolicyManager_Impl>(this: *mut core::ffi::c_void, failure: EClrFailure, action: EPolicyAction) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostPolicyManager_Impl::OnFailure(this, core::mem::transmute_copy(&failure), core::mem::transmute_copy(&action)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnDefaultAction: OnDefaultAction::<Identity>,
+            OnTimeout: OnTimeout::<Identity>,
+            OnFailure: OnFailure::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IHostPolicyManager {}
 windows_core::imp::define_interface!(IHostSecurityContext, IHostSecurityContext_Vtbl, 0x7e573ce4_0343_4423_98d7_6318348a1d3c);
 windows_core::imp::interface_hierarchy!(IHostSecurityContext, windows_core::IUnknown);
@@ -5232,6 +7726,26 @@ impl IHostSecurityContext_Vtbl {
         iid == &<IHostSecurityContext as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IHostSecurityContext_Impl> windows_core::imp::TearOffVtable<Identity> for IHostSecurityContext_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Capture<Identity: windows_core::IUnknownImpl + IHostSecurityContext_Impl>(this: *mut core::ffi::c_void, ppclonedcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHostSecurityContext_Impl::Capture(this) {
+                    Ok(ok__) => {
+                        ppclonedcontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Capture: Capture::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IHostSecurityContext {}
 windows_core::imp::define_interface!(IHostSecurityManager, IHostSecurityManager_Vtbl, 0x75ad2468_a349_4d02_a764_76a68aee0c4f);
 windows_core::imp::interface_hierarchy!(IHostSecurityManager, windows_core::IUnknown);
@@ -5287,19 +7801,86 @@ impl IHostSecurityManager_Vtbl {
     pub const fn new<Identity: IHostSecurityManager_Impl, const OFFSET: isize>() -> Self {
         unsafe extern "system" fn ImpersonateLoggedOnUser<Identity: IHostSecurityManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, htoken: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IHostSecurityManager_Impl::ImpersonateLoggedOnUser(this, core::mem::transmute_copy(&htoken)).into()
+            }
+        }
+        unsafe extern "system" fn RevertToSelf<Identity: IHostSecurityManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IHostSecurityManager_Impl::RevertToSelf(this).into()
+            }
+        }
+        unsafe extern "system" fn OpenThreadToken<Identity: IHostSecurityManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwdesiredaccess: u32, bopenasself: windows_core::BOOL, phthreadtoken: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IHostSecurityManager_Impl::OpenThreadToken(this, core::mem::transmute_copy(&dwdesiredaccess), core::mem::transmute_copy(&bopenasself)) {
+                    Ok(ok__) => {
+                        phthreadtoken.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetThreadToken<Identity: IHostSecurityManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, htoken: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IHostSecurityManager_Impl::SetThreadToken(this, core::mem::transmute_copy(&htoken)).into()
+            }
+        }
+        unsafe extern "system" fn GetSecurityContext<Identity: IHostSecurityManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, econtexttype: EContextType, ppsecuritycontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IHostSecurityManager_Impl::GetSecurityContext(this, core::mem::transmute_copy(&econtexttype)) {
+                    Ok(ok__) => {
+                        ppsecuritycontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSecurityContext<Identity: IHostSecurityManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, econtexttype: EContextType, psecuritycontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IHostSecurityManager_Impl::SetSecurityContext(this, core::mem::transmute_copy(&econtexttype), core::mem::transmute_copy(&psecuritycontext)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            ImpersonateLoggedOnUser: ImpersonateLoggedOnUser::<Identity, OFFSET>,
+            RevertToSelf: RevertToSelf::<Identity, OFFSET>,
+            OpenThreadToken: OpenThreadToken::<Identity, OFFSET>,
+            SetThreadToken: SetThreadToken::<Identity, OFFSET>,
+            GetSecurityContext: GetSecurityContext::<Identity, OFFSET>,
+            SetSecurityContext: SetSecurityContext::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostSecurityManager as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IHostSecurityManager_Impl> windows_core::imp::TearOffVtable<Identity> for IHostSecurityManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ImpersonateLoggedOnUser<Identity: windows_core::IUnknownImpl + IHostSecurityManager_Impl>(this: *mut core::ffi::c_void, htoken: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IHostSecurityManager_Impl::ImpersonateLoggedOnUser(this, core::mem::transmute_copy(&htoken)).into()
             }
         }
-        unsafe extern "system" fn RevertToSelf<Identity: IHostSecurityManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn RevertToSelf<Identity: windows_core::IUnknownImpl + IHostSecurityManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IHostSecurityManager_Impl::RevertToSelf(this).into()
             }
         }
-        unsafe extern "system" fn OpenThreadToken<Identity: IHostSecurityManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwdesiredaccess: u32, bopenasself: windows_core::BOOL, phthreadtoken: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+        unsafe extern "system" fn OpenThreadToken<Identity: windows_core::IUnknownImpl + IHostSecurityManager_Impl>(this: *mut core::ffi::c_void, dwdesiredaccess: u32, bopenasself: windows_core::BOOL, phthreadtoken: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IHostSecurityManager_Impl::OpenThreadToken(this, core::mem::transmute_copy(&dwdesiredaccess), core::mem::transmute_copy(&bopenasself)) {
                     Ok(ok__) => {
                         phthreadtoken.write(core::mem::transmute(ok__));
@@ -5309,15 +7890,15 @@ impl IHostSecurityManager_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetThreadToken<Identity: IHostSecurityManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, htoken: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetThreadToken<Identity: windows_core::IUnknownImpl + IHostSecurityManager_Impl>(this: *mut core::ffi::c_void, htoken: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IHostSecurityManager_Impl::SetThreadToken(this, core::mem::transmute_copy(&htoken)).into()
             }
         }
-        unsafe extern "system" fn GetSecurityContext<Identity: IHostSecurityManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, econtexttype: EContextType, ppsecuritycontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetSecurityContext<Identity: windows_core::IUnknownImpl + IHostSecurityManager_Impl>(this: *mut core::ffi::c_void, econtexttype: EContextType, ppsecuritycontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IHostSecurityManager_Impl::GetSecurityContext(this, core::mem::transmute_copy(&econtexttype)) {
                     Ok(ok__) => {
                         ppsecuritycontext.write(core::mem::transmute(ok__));
@@ -5327,25 +7908,22 @@ impl IHostSecurityManager_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetSecurityContext<Identity: IHostSecurityManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, econtexttype: EContextType, psecuritycontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetSecurityContext<Identity: windows_core::IUnknownImpl + IHostSecurityManager_Impl>(this: *mut core::ffi::c_void, econtexttype: EContextType, psecuritycontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IHostSecurityManager_Impl::SetSecurityContext(this, core::mem::transmute_copy(&econtexttype), core::mem::transmute_copy(&psecuritycontext)).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            ImpersonateLoggedOnUser: ImpersonateLoggedOnUser::<Identity, OFFSET>,
-            RevertToSelf: RevertToSelf::<Identity, OFFSET>,
-            OpenThreadToken: OpenThreadToken::<Identity, OFFSET>,
-            SetThreadToken: SetThreadToken::<Identity, OFFSET>,
-            GetSecurityContext: GetSecurityContext::<Identity, OFFSET>,
-            SetSecurityContext: SetSecurityContext::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ImpersonateLoggedOnUser: ImpersonateLoggedOnUser::<Identity>,
+            RevertToSelf: RevertToSelf::<Identity>,
+            OpenThreadToken: OpenThreadToken::<Identity>,
+            SetThreadToken: SetThreadToken::<Identity>,
+            GetSecurityContext: GetSecurityContext::<Identity>,
+            SetSecurityContext: SetSecurityContext::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IHostSecurityManager as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IHostSecurityManager {}
 windows_core::imp::define_interface!(IHostSemaphore, IHostSemaphore_Vtbl, 0x855efd47_cc09_463a_a97d_16acab882661);
@@ -5402,6 +7980,36 @@ impl IHostSemaphore_Vtbl {
         iid == &<IHostSemaphore as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IHostSemaphore_Impl> windows_core::imp::TearOffVtable<Identity> for IHostSemaphore_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Wait<Identity: windows_core::IUnknownImpl + IHostSemaphore_Impl>(this: *mut core::ffi::c_void, dwmilliseconds: u32, option: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostSemaphore_Impl::Wait(this, core::mem::transmute_copy(&dwmilliseconds), core::mem::transmute_copy(&option)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseSemaphore<Identity: windows_core::IUnknownImpl + IHostSemaphore_Impl>(this: *mut core::ffi::c_void, lreleasecount: i32, lppreviouscount: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHostSemaphore_Impl::ReleaseSemaphore(this, core::mem::transmute_copy(&lreleasecount)) {
+                    Ok(ok__) => {
+                        lppreviouscount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Wait: Wait::<Identity>,
+            ReleaseSemaphore: ReleaseSemaphore::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IHostSemaphore {}
 windows_core::imp::define_interface!(IHostSyncManager, IHostSyncManager_Vtbl, 0x234330c7_5f10_4f20_9615_5122dab7a0ac);
 windows_core::imp::interface_hierarchy!(IHostSyncManager, windows_core::IUnknown);
@@ -5607,6 +8215,127 @@ impl IHostSyncManager_Vtbl {
         iid == &<IHostSyncManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IHostSyncManager_Impl> windows_core::imp::TearOffVtable<Identity> for IHostSyncManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetCLRSyncManager<Identity: windows_core::IUnknownImpl + IHostSyncManager_Impl>(this: *mut core::ffi::c_void, pmanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostSyncManager_Impl::SetCLRSyncManager(this, core::mem::transmute_copy(&pmanager)).into()
+            }
+        }
+        unsafe extern "system" fn CreateCrst<Identity: windows_core::IUnknownImpl + IHostSyncManager_Impl>(this: *mut core::ffi::c_void, ppcrst: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHostSyncManager_Impl::CreateCrst(this) {
+                    Ok(ok__) => {
+                        ppcrst.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateCrstWithSpinCount<Identity: windows_core::IUnknownImpl + IHostSyncManager_Impl>(this: *mut core::ffi::c_void, dwspincount: u32, ppcrst: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHostSyncManager_Impl::CreateCrstWithSpinCount(this, core::mem::transmute_copy(&dwspincount)) {
+                    Ok(ok__) => {
+                        ppcrst.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateAutoEvent<Identity: windows_core::IUnknownImpl + IHostSyncManager_Impl>(this: *mut core::ffi::c_void, ppevent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHostSyncManager_Impl::CreateAutoEvent(this) {
+                    Ok(ok__) => {
+                        ppevent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateManualEvent<Identity: windows_core::IUnknownImpl + IHostSyncManager_Impl>(this: *mut core::ffi::c_void, binitialstate: windows_core::BOOL, ppevent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHostSyncManager_Impl::CreateManualEvent(this, core::mem::transmute_copy(&binitialstate)) {
+                    Ok(ok__) => {
+                        ppevent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateMonitorEvent<Identity: windows_core::IUnknownImpl + IHostSyncManager_Impl>(this: *mut core::ffi::c_void, cookie: usize, ppevent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHostSyncManager_Impl::CreateMonitorEvent(this, core::mem::transmute_copy(&cookie)) {
+                    Ok(ok__) => {
+                        ppevent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateRWLockWriterEvent<Identity: windows_core::IUnknownImpl + IHostSyncManager_Impl>(this: *mut core::ffi::c_void, cookie: usize, ppevent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHostSyncManager_Impl::CreateRWLockWriterEvent(this, core::mem::transmute_copy(&cookie)) {
+                    Ok(ok__) => {
+                        ppevent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateRWLockReaderEvent<Identity: windows_core::IUnknownImpl + IHostSyncManager_Impl>(this: *mut core::ffi::c_void, binitialstate: windows_core::BOOL, cookie: usize, ppevent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHostSyncManager_Impl::CreateRWLockReaderEvent(this, core::mem::transmute_copy(&binitialstate), core::mem::transmute_copy(&cookie)) {
+                    Ok(ok__) => {
+                        ppevent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSemaphoreA<Identity: windows_core::IUnknownImpl + IHostSyncManager_Impl>(this: *mut core::ffi::c_void, dwinitial: u32, dwmax: u32, ppsemaphore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHostSyncManager_Impl::CreateSemaphoreA(this, core::mem::transmute_copy(&dwinitial), core::mem::transmute_copy(&dwmax)) {
+                    Ok(ok__) => {
+                        ppsemaphore.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetCLRSyncManager: SetCLRSyncManager::<Identity>,
+            CreateCrst: CreateCrst::<Identity>,
+            CreateCrstWithSpinCount: CreateCrstWithSpinCount::<Identity>,
+            CreateAutoEvent: CreateAutoEvent::<Identity>,
+            CreateManualEvent: CreateManualEvent::<Identity>,
+            CreateMonitorEvent: CreateMonitorEvent::<Identity>,
+            CreateRWLockWriterEvent: CreateRWLockWriterEvent::<Identity>,
+            CreateRWLockReaderEvent: CreateRWLockReaderEvent::<Identity>,
+            CreateSemaphoreA: CreateSemaphoreA::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IHostSyncManager {}
 windows_core::imp::define_interface!(IHostTask, IHostTask_Vtbl, 0xc2275828_c4b1_4b55_82c9_92135f74df1a);
 windows_core::imp::interface_hierarchy!(IHostTask, windows_core::IUnknown);
@@ -5713,6 +8442,64 @@ impl IHostTask_Vtbl {
         iid == &<IHostTask as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IHostTask_Impl> windows_core::imp::TearOffVtable<Identity> for IHostTask_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Start<Identity: windows_core::IUnknownImpl + IHostTask_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostTask_Impl::Start(this).into()
+            }
+        }
+        unsafe extern "system" fn Alert<Identity: windows_core::IUnknownImpl + IHostTask_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostTask_Impl::Alert(this).into()
+            }
+        }
+        unsafe extern "system" fn Join<Identity: windows_core::IUnknownImpl + IHostTask_Impl>(this: *mut core::ffi::c_void, dwmilliseconds: u32, option: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostTask_Impl::Join(this, core::mem::transmute_copy(&dwmilliseconds), core::mem::transmute_copy(&option)).into()
+            }
+        }
+        unsafe extern "system" fn SetPriority<Identity: windows_core::IUnknownImpl + IHostTask_Impl>(this: *mut core::ffi::c_void, newpriority: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostTask_Impl::SetPriority(this, core::mem::transmute_copy(&newpriority)).into()
+            }
+        }
+        unsafe extern "system" fn GetPriority<Identity: windows_core::IUnknownImpl + IHostTask_Impl>(this: *mut core::ffi::c_void, ppriority: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHostTask_Impl::GetPriority(this) {
+                    Ok(ok__) => {
+                        ppriority.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCLRTask<Identity: windows_core::IUnknownImpl + IHostTask_Impl>(this: *mut core::ffi::c_void, pclrtask: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostTask_Impl::SetCLRTask(this, core::mem::transmute_copy(&pclrtask)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Start: Start::<Identity>,
+            Alert: Alert::<Identity>,
+            Join: Join::<Identity>,
+            SetPriority: SetPriority::<Identity>,
+            GetPriority: GetPriority::<Identity>,
+            SetCLRTask: SetCLRTask::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IHostTask {}
 windows_core::imp::define_interface!(IHostTaskManager, IHostTaskManager_Vtbl, 0x997ff24c_43b7_4352_8667_0dc04fafd354);
 windows_core::imp::interface_hierarchy!(IHostTaskManager, windows_core::IUnknown);
@@ -5991,10 +8778,171 @@ impl IHostTaskManager_Vtbl {
             GetStackGuarantee: GetStackGuarantee::<Identity, OFFSET>,
             SetCLRTaskManager: SetCLRTaskManager::<Identity, OFFSET>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IHostTaskManager as windows_core::Interface>::IID
-    }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostTaskManager as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_System_Threading")]
+impl<Identity: windows_core::IUnknownImpl + IHostTaskManager_Impl> windows_core::imp::TearOffVtable<Identity> for IHostTaskManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrentTask<Identity: windows_core::IUnknownImpl + IHostTaskManager_Impl>(this: *mut core::ffi::c_void, ptask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHostTaskManager_Impl::GetCurrentTask(this) {
+                    Ok(ok__) => {
+                        ptask.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateTask<Identity: windows_core::IUnknownImpl + IHostTaskManager_Impl>(this: *mut core::ffi::c_void, dwstacksize: u32, pstartaddress: super::Threading::LPTHREAD_START_ROUTINE, pparameter: *const core::ffi::c_void, pptask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHostTaskManager_Impl::CreateTask(this, core::mem::transmute_copy(&dwstacksize), core::mem::transmute_copy(&pstartaddress), core::mem::transmute_copy(&pparameter)) {
+                    Ok(ok__) => {
+                        pptask.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Sleep<Identity: windows_core::IUnknownImpl + IHostTaskManager_Impl>(this: *mut core::ffi::c_void, dwmilliseconds: u32, option: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostTaskManager_Impl::Sleep(this, core::mem::transmute_copy(&dwmilliseconds), core::mem::transmute_copy(&option)).into()
+            }
+        }
+        unsafe extern "system" fn SwitchToTask<Identity: windows_core::IUnknownImpl + IHostTaskManager_Impl>(this: *mut core::ffi::c_void, option: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostTaskManager_Impl::SwitchToTask(this, core::mem::transmute_copy(&option)).into()
+            }
+        }
+        unsafe extern "system" fn SetUILocale<Identity: windows_core::IUnknownImpl + IHostTaskManager_Impl>(this: *mut core::ffi::c_void, lcid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostTaskManager_Impl::SetUILocale(this, core::mem::transmute_copy(&lcid)).into()
+            }
+        }
+        unsafe extern "system" fn SetLocale<Identity: windows_core::IUnknownImpl + IHostTaskManager_Impl>(this: *mut core::ffi::c_void, lcid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostTaskManager_Impl::SetLocale(this, core::mem::transmute_copy(&lcid)).into()
+            }
+        }
+        unsafe extern "system" fn CallNeedsHostHook<Identity: windows_core::IUnknownImpl + IHostTaskManager_Impl>(this: *mut core::ffi::c_void, target: usize, pbcallneedshosthook: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHostTaskManager_Impl::CallNeedsHostHook(this, core::mem::transmute_copy(&target)) {
+                    Ok(ok__) => {
+                        pbcallneedshosthook.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LeaveRuntime<Identity: windows_core::IUnknownImpl + IHostTaskManager_Impl>(this: *mut core::ffi::c_void, target: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostTaskManager_Impl::LeaveRuntime(this, core::mem::transmute_copy(&target)).into()
+            }
+        }
+        unsafe extern "system" fn EnterRuntime<Identity: windows_core::IUnknownImpl + IHostTaskManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostTaskManager_Impl::EnterRuntime(this).into()
+            }
+        }
+        unsafe extern "system" fn ReverseLeaveRuntime<Identity: windows_core::IUnknownImpl + IHostTaskManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostTaskManager_Impl::ReverseLeaveRuntime(this).into()
+            }
+        }
+        unsafe extern "system" fn ReverseEnterRuntime<Identity: windows_core::IUnknownImpl + IHostTaskManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostTaskManager_Impl::ReverseEnterRuntime(this).into()
+            }
+        }
+        unsafe extern "system" fn BeginDelayAbort<Identity: windows_core::IUnknownImpl + IHostTaskManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostTaskManager_Impl::BeginDelayAbort(this).into()
+            }
+        }
+        unsafe extern "system" fn EndDelayAbort<Identity: windows_core::IUnknownImpl + IHostTaskManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostTaskManager_Impl::EndDelayAbort(this).into()
+            }
+        }
+        unsafe extern "system" fn BeginThreadAffinity<Identity: windows_core::IUnknownImpl + IHostTaskManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostTaskManager_Impl::BeginThreadAffinity(this).into()
+            }
+        }
+        unsafe extern "system" fn EndThreadAffinity<Identity: windows_core::IUnknownImpl + IHostTaskManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostTaskManager_Impl::EndThreadAffinity(this).into()
+            }
+        }
+        unsafe extern "system" fn SetStackGuarantee<Identity: windows_core::IUnknownImpl + IHostTaskManager_Impl>(this: *mut core::ffi::c_void, guarantee: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostTaskManager_Impl::SetStackGuarantee(this, core::mem::transmute_copy(&guarantee)).into()
+            }
+        }
+        unsafe extern "system" fn GetStackGuarantee<Identity: windows_core::IUnknownImpl + IHostTaskManager_Impl>(this: *mut core::ffi::c_void, pguarantee: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHostTaskManager_Impl::GetStackGuarantee(this) {
+                    Ok(ok__) => {
+                        pguarantee.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCLRTaskManager<Identity: windows_core::IUnknownImpl + IHostTaskManager_Impl>(this: *mut core::ffi::c_void, ppmanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostTaskManager_Impl::SetCLRTaskManager(this, core::mem::transmute_copy(&ppmanager)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrentTask: GetCurrentTask::<Identity>,
+            CreateTask: CreateTask::<Identity>,
+            Sleep: Sleep::<Identity>,
+            SwitchToTask: SwitchToTask::<Identity>,
+            SetUILocale: SetUILocale::<Identity>,
+            SetLocale: SetLocale::<Identity>,
+            CallNeedsHostHook: CallNeedsHostHook::<Identity>,
+            LeaveRuntime: LeaveRuntime::<Identity>,
+            EnterRuntime: EnterRuntime::<Identity>,
+            ReverseLeaveRuntime: ReverseLeaveRuntime::<Identity>,
+            ReverseEnterRuntime: ReverseEnterRuntime::<Identity>,
+            BeginDelayAbort: BeginDelayAbort::<Identity>,
+            EndDelayAbort: EndDelayAbort::<Identity>,
+            BeginThreadAffinity: BeginThreadAffinity::<Identity>,
+            EndThreadAffinity: EndThreadAffinity::<Identity>,
+            SetStackGuarantee: SetStackGuarantee::<Identity>,
+            GetStackGuarantee: GetStackGuarantee::<Identity>,
+            SetCLRTaskManager: SetCLRTaskManager::<Identity>,
+        }
+    };
 }
 #[cfg(feature = "Win32_System_Threading")]
 impl windows_core::RuntimeName for IHostTaskManager {}
@@ -6125,6 +9073,77 @@ impl IHostThreadpoolManager_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Threading")]
+impl<Identity: windows_core::IUnknownImpl + IHostThreadpoolManager_Impl> windows_core::imp::TearOffVtable<Identity> for IHostThreadpoolManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueueUserWorkItem<Identity: windows_core::IUnknownImpl + IHostThreadpoolManager_Impl>(this: *mut core::ffi::c_void, function: super::Threading::LPTHREAD_START_ROUTINE, context: *const core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostThreadpoolManager_Impl::QueueUserWorkItem(this, core::mem::transmute_copy(&function), core::mem::transmute_copy(&context), core::mem::transmute_copy(&flags)).into()
+            }
+        }
+        unsafe extern "system" fn SetMaxThreads<Identity: windows_core::IUnknownImpl + IHostThreadpoolManager_Impl>(this: *mut core::ffi::c_void, dwmaxworkerthreads: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostThreadpoolManager_Impl::SetMaxThreads(this, core::mem::transmute_copy(&dwmaxworkerthreads)).into()
+            }
+        }
+        unsafe extern "system" fn GetMaxThreads<Identity: windows_core::IUnknownImpl + IHostThreadpoolManager_Impl>(this: *mut core::ffi::c_void, pdwmaxworkerthreads: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHostThreadpoolManager_Impl::GetMaxThreads(this) {
+                    Ok(ok__) => {
+                        pdwmaxworkerthreads.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAvailableThreads<Identity: windows_core::IUnknownImpl + IHostThreadpoolManager_Impl>(this: *mut core::ffi::c_void, pdwavailableworkerthreads: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHostThreadpoolManager_Impl::GetAvailableThreads(this) {
+                    Ok(ok__) => {
+                        pdwavailableworkerthreads.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMinThreads<Identity: windows_core::IUnknownImpl + IHostThreadpoolManager_Impl>(this: *mut core::ffi::c_void, dwminiocompletionthreads: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHostThreadpoolManager_Impl::SetMinThreads(this, core::mem::transmute_copy(&dwminiocompletionthreads)).into()
+            }
+        }
+        unsafe extern "system" fn GetMinThreads<Identity: windows_core::IUnknownImpl + IHostThreadpoolManager_Impl>(this: *mut core::ffi::c_void, pdwminiocompletionthreads: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHostThreadpoolManager_Impl::GetMinThreads(this) {
+                    Ok(ok__) => {
+                        pdwminiocompletionthreads.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueueUserWorkItem: QueueUserWorkItem::<Identity>,
+            SetMaxThreads: SetMaxThreads::<Identity>,
+            GetMaxThreads: GetMaxThreads::<Identity>,
+            GetAvailableThreads: GetAvailableThreads::<Identity>,
+            SetMinThreads: SetMinThreads::<Identity>,
+            GetMinThreads: GetMinThreads::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Threading")]
 impl windows_core::RuntimeName for IHostThreadpoolManager {}
 windows_core::imp::define_interface!(IManagedObject, IManagedObject_Vtbl, 0xc3fcc19e_a970_11d2_8b5a_00a0c9b7c9c4);
 windows_core::imp::interface_hierarchy!(IManagedObject, windows_core::IUnknown);
@@ -6180,6 +9199,36 @@ impl IManagedObject_Vtbl {
         iid == &<IManagedObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IManagedObject_Impl> windows_core::imp::TearOffVtable<Identity> for IManagedObject_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSerializedBuffer<Identity: windows_core::IUnknownImpl + IManagedObject_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IManagedObject_Impl::GetSerializedBuffer(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetObjectIdentity<Identity: windows_core::IUnknownImpl + IManagedObject_Impl>(this: *mut core::ffi::c_void, pbstrguid: *mut *mut core::ffi::c_void, appdomainid: *mut i32, pccw: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IManagedObject_Impl::GetObjectIdentity(this, core::mem::transmute_copy(&pbstrguid), core::mem::transmute_copy(&appdomainid), core::mem::transmute_copy(&pccw)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSerializedBuffer: GetSerializedBuffer::<Identity>,
+            GetObjectIdentity: GetObjectIdentity::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IManagedObject {}
 windows_core::imp::define_interface!(IObjectHandle, IObjectHandle_Vtbl, 0xc460e2b4_e199_412a_8456_84dc3e4838c3);
 windows_core::imp::interface_hierarchy!(IObjectHandle, windows_core::IUnknown);
@@ -6227,6 +9276,27 @@ impl IObjectHandle_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IObjectHandle_Impl> windows_core::imp::TearOffVtable<Identity> for IObjectHandle_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Unwrap<Identity: windows_core::IUnknownImpl + IObjectHandle_Impl>(this: *mut core::ffi::c_void, ppv: *mut super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IObjectHandle_Impl::Unwrap(this) {
+                    Ok(ok__) => {
+                        ppv.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Unwrap: Unwrap::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IObjectHandle {}
 windows_core::imp::define_interface!(ITypeName, ITypeName_Vtbl, 0xb81ff171_20f3_11d2_8dcc_00a0c9b00522);
 windows_core::imp::interface_hierarchy!(ITypeName, windows_core::IUnknown);
@@ -6396,6 +9466,107 @@ impl ITypeName_Vtbl {
         iid == &<ITypeName as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITypeName_Impl> windows_core::imp::TearOffVtable<Identity> for ITypeName_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetNameCount<Identity: windows_core::IUnknownImpl + ITypeName_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITypeName_Impl::GetNameCount(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetNames<Identity: windows_core::IUnknownImpl + ITypeName_Impl>(this: *mut core::ffi::c_void, count: u32, rgbsznames: *mut *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITypeName_Impl::GetNames(this, core::mem::transmute_copy(&count), core::mem::transmute_copy(&rgbsznames)) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTypeArgumentCount<Identity: windows_core::IUnknownImpl + ITypeName_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITypeName_Impl::GetTypeArgumentCount(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTypeArguments<Identity: windows_core::IUnknownImpl + ITypeName_Impl>(this: *mut core::ffi::c_void, count: u32, rgparguments: *mut *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITypeName_Impl::GetTypeArguments(this, core::mem::transmute_copy(&count), core::mem::transmute_copy(&rgparguments)) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetModifierLength<Identity: windows_core::IUnknownImpl + ITypeName_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITypeName_Impl::GetModifierLength(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetModifiers<Identity: windows_core::IUnknownImpl + ITypeName_Impl>(this: *mut core::ffi::c_void, count: u32, rgmodifiers: *mut u32, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITypeName_Impl::GetModifiers(this, core::mem::transmute_copy(&count), core::mem::transmute_copy(&rgmodifiers)) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAssemblyName<Identity: windows_core::IUnknownImpl + ITypeName_Impl>(this: *mut core::ffi::c_void, rgbszassemblynames: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITypeName_Impl::GetAssemblyName(this) {
+                    Ok(ok__) => {
+                        rgbszassemblynames.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetNameCount: GetNameCount::<Identity>,
+            GetNames: GetNames::<Identity>,
+            GetTypeArgumentCount: GetTypeArgumentCount::<Identity>,
+            GetTypeArguments: GetTypeArguments::<Identity>,
+            GetModifierLength: GetModifierLength::<Identity>,
+            GetModifiers: GetModifiers::<Identity>,
+            GetAssemblyName: GetAssemblyName::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITypeName {}
 windows_core::imp::define_interface!(ITypeNameBuilder, ITypeNameBuilder_Vtbl, 0xb81ff171_20f3_11d2_8dcc_00a0c9b00523);
 windows_core::imp::interface_hierarchy!(ITypeNameBuilder, windows_core::IUnknown);
@@ -6577,6 +9748,106 @@ impl ITypeNameBuilder_Vtbl {
         iid == &<ITypeNameBuilder as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITypeNameBuilder_Impl> windows_core::imp::TearOffVtable<Identity> for ITypeNameBuilder_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OpenGenericArguments<Identity: windows_core::IUnknownImpl + ITypeNameBuilder_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITypeNameBuilder_Impl::OpenGenericArguments(this).into()
+            }
+        }
+        unsafe extern "system" fn CloseGenericArguments<Identity: windows_core::IUnknownImpl + ITypeNameBuilder_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITypeNameBuilder_Impl::CloseGenericArguments(this).into()
+            }
+        }
+        unsafe extern "system" fn OpenGenericArgument<Identity: windows_core::IUnknownImpl + ITypeNameBuilder_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITypeNameBuilder_Impl::OpenGenericArgument(this).into()
+            }
+        }
+        unsafe extern "system" fn CloseGenericArgument<Identity: windows_core::IUnknownImpl + ITypeNameBuilder_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITypeNameBuilder_Impl::CloseGenericArgument(this).into()
+            }
+        }
+        unsafe extern "system" fn AddName<Identity: windows_core::IUnknownImpl + ITypeNameBuilder_Impl>(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITypeNameBuilder_Impl::AddName(this, core::mem::transmute(&szname)).into()
+            }
+        }
+        unsafe extern "system" fn AddPointer<Identity: windows_core::IUnknownImpl + ITypeNameBuilder_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITypeNameBuilder_Impl::AddPointer(this).into()
+            }
+        }
+        unsafe extern "system" fn AddByRef<Identity: windows_core::IUnknownImpl + ITypeNameBuilder_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITypeNameBuilder_Impl::AddByRef(this).into()
+            }
+        }
+        unsafe extern "system" fn AddSzArray<Identity: windows_core::IUnknownImpl + ITypeNameBuilder_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITypeNameBuilder_Impl::AddSzArray(this).into()
+            }
+        }
+        unsafe extern "system" fn AddArray<Identity: windows_core::IUnknownImpl + ITypeNameBuilder_Impl>(this: *mut core::ffi::c_void, rank: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITypeNameBuilder_Impl::AddArray(this, core::mem::transmute_copy(&rank)).into()
+            }
+        }
+        unsafe extern "system" fn AddAssemblySpec<Identity: windows_core::IUnknownImpl + ITypeNameBuilder_Impl>(this: *mut core::ffi::c_void, szassemblyspec: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITypeNameBuilder_Impl::AddAssemblySpec(this, core::mem::transmute(&szassemblyspec)).into()
+            }
+        }
+        unsafe extern "system" fn ToString<Identity: windows_core::IUnknownImpl + ITypeNameBuilder_Impl>(this: *mut core::ffi::c_void, pszstringrepresentation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITypeNameBuilder_Impl::ToString(this) {
+                    Ok(ok__) => {
+                        pszstringrepresentation.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Clear<Identity: windows_core::IUnknownImpl + ITypeNameBuilder_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITypeNameBuilder_Impl::Clear(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OpenGenericArguments: OpenGenericArguments::<Identity>,
+            CloseGenericArguments: CloseGenericArguments::<Identity>,
+            OpenGenericArgument: OpenGenericArgument::<Identity>,
+            CloseGenericArgument: CloseGenericArgument::<Identity>,
+            AddName: AddName::<Identity>,
+            AddPointer: AddPointer::<Identity>,
+            AddByRef: AddByRef::<Identity>,
+            AddSzArray: AddSzArray::<Identity>,
+            AddArray: AddArray::<Identity>,
+            AddAssemblySpec: AddAssemblySpec::<Identity>,
+            ToString: ToString::<Identity>,
+            Clear: Clear::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITypeNameBuilder {}
 windows_core::imp::define_interface!(ITypeNameFactory, ITypeNameFactory_Vtbl, 0xb81ff171_20f3_11d2_8dcc_00a0c9b00521);
 windows_core::imp::interface_hierarchy!(ITypeNameFactory, windows_core::IUnknown);
@@ -6644,6 +9915,42 @@ impl ITypeNameFactory_Vtbl {
         iid == &<ITypeNameFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITypeNameFactory_Impl> windows_core::imp::TearOffVtable<Identity> for ITypeNameFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ParseTypeName<Identity: windows_core::IUnknownImpl + ITypeNameFactory_Impl>(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR, perror: *mut u32, pptypename: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITypeNameFactory_Impl::ParseTypeName(this, core::mem::transmute(&szname), core::mem::transmute_copy(&perror)) {
+                    Ok(ok__) => {
+                        pptypename.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTypeNameBuilder<Identity: windows_core::IUnknownImpl + ITypeNameFactory_Impl>(this: *mut core::ffi::c_void, pptypebuilder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITypeNameFactory_Impl::GetTypeNameBuilder(this) {
+                    Ok(ok__) => {
+                        pptypebuilder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ParseTypeName: ParseTypeName::<Identity>,
+            GetTypeNameBuilder: GetTypeNameBuilder::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITypeNameFactory {}
 pub const InvalidBucketParamIndex: BucketParameterIndex = BucketParameterIndex(9i32);
 pub const LIBID_mscoree: windows_core::GUID = windows_core::GUID::from_u128(0x5477469e_83b1_11d2_8b49_00a0c9b7c9c4);
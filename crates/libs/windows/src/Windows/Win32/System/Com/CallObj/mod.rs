@@ -401,6 +401,180 @@ impl ICallFrame_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ICallFrame_Impl> windows_core::imp::TearOffVtable<Identity> for ICallFrame_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetInfo<Identity: windows_core::IUnknownImpl + ICallFrame_Impl>(this: *mut core::ffi::c_void, pinfo: *mut CALLFRAMEINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICallFrame_Impl::GetInfo(this, core::mem::transmute_copy(&pinfo)).into()
+            }
+        }
+        unsafe extern "system" fn GetIIDAndMethod<Identity: windows_core::IUnknownImpl + ICallFrame_Impl>(this: *mut core::ffi::c_void, piid: *mut windows_core::GUID, pimethod: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICallFrame_Impl::GetIIDAndMethod(this, core::mem::transmute_copy(&piid), core::mem::transmute_copy(&pimethod)).into()
+            }
+        }
+        unsafe extern "system" fn GetNames<Identity: windows_core::IUnknownImpl + ICallFrame_Impl>(this: *mut core::ffi::c_void, pwszinterface: *mut windows_core::PWSTR, pwszmethod: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICallFrame_Impl::GetNames(this, core::mem::transmute_copy(&pwszinterface), core::mem::transmute_copy(&pwszmethod)).into()
+            }
+        }
+        unsafe extern "system" fn GetStackLocation<Identity: windows_core::IUnknownImpl + ICallFrame_Impl>(this: *mut core::ffi::c_void) -> *mut core::ffi::c_void {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICallFrame_Impl::GetStackLocation(this)
+            }
+        }
+        unsafe extern "system" fn SetStackLocation<Identity: windows_core::IUnknownImpl + ICallFrame_Impl>(this: *mut core::ffi::c_void, pvstack: *const core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICallFrame_Impl::SetStackLocation(this, core::mem::transmute_copy(&pvstack))
+            }
+        }
+        unsafe extern "system" fn SetReturnValue<Identity: windows_core::IUnknownImpl + ICallFrame_Impl>(this: *mut core::ffi::c_void, hr: windows_core::HRESULT) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICallFrame_Impl::SetReturnValue(this, core::mem::transmute_copy(&hr))
+            }
+        }
+        unsafe extern "system" fn GetReturnValue<Identity: windows_core::IUnknownImpl + ICallFrame_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICallFrame_Impl::GetReturnValue(this).into()
+            }
+        }
+        unsafe extern "system" fn GetParamInfo<Identity: windows_core::IUnknownImpl + ICallFrame_Impl>(this: *mut core::ffi::c_void, iparam: u32, pinfo: *mut CALLFRAMEPARAMINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICallFrame_Impl::GetParamInfo(this, core::mem::transmute_copy(&iparam)) {
+                    Ok(ok__) => {
+                        pinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetParam<Identity: windows_core::IUnknownImpl + ICallFrame_Impl>(this: *mut core::ffi::c_void, iparam: u32, pvar: *const super::super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICallFrame_Impl::SetParam(this, core::mem::transmute_copy(&iparam), core::mem::transmute_copy(&pvar)).into()
+            }
+        }
+        unsafe extern "system" fn GetParam<Identity: windows_core::IUnknownImpl + ICallFrame_Impl>(this: *mut core::ffi::c_void, iparam: u32, pvar: *mut super::super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICallFrame_Impl::GetParam(this, core::mem::transmute_copy(&iparam)) {
+                    Ok(ok__) => {
+                        pvar.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Copy<Identity: windows_core::IUnknownImpl + ICallFrame_Impl>(this: *mut core::ffi::c_void, copycontrol: CALLFRAME_COPY, pwalker: *mut core::ffi::c_void, ppframe: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICallFrame_Impl::Copy(this, core::mem::transmute_copy(&copycontrol), core::mem::transmute_copy(&pwalker)) {
+                    Ok(ok__) => {
+                        ppframe.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Free<Identity: windows_core::IUnknownImpl + ICallFrame_Impl>(this: *mut core::ffi::c_void, pframeargsdest: *mut core::ffi::c_void, pwalkerdestfree: *mut core::ffi::c_void, pwalkercopy: *mut core::ffi::c_void, freeflags: u32, pwalkerfree: *mut core::ffi::c_void, nullflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICallFrame_Impl::Free(this, core::mem::transmute_copy(&pframeargsdest), core::mem::transmute_copy(&pwalkerdestfree), core::mem::transmute_copy(&pwalkercopy), core::mem::transmute_copy(&freeflags), core::mem::transmute_copy(&pwalkerfree), core::mem::transmute_copy(&nullflags)).into()
+            }
+        }
+        unsafe extern "system" fn FreeParam<Identity: windows_core::IUnknownImpl + ICallFrame_Impl>(this: *mut core::ffi::c_void, iparam: u32, freeflags: u32, pwalkerfree: *mut core::ffi::c_void, nullflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICallFrame_Impl::FreeParam(this, core::mem::transmute_copy(&iparam), core::mem::transmute_copy(&freeflags), core::mem::transmute_copy(&pwalkerfree), core::mem::transmute_copy(&nullflags)).into()
+            }
+        }
+        unsafe extern "system" fn WalkFrame<Identity: windows_core::IUnknownImpl + ICallFrame_Impl>(this: *mut core::ffi::c_void, walkwhat: u32, pwalker: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICallFrame_Impl::WalkFrame(this, core::mem::transmute_copy(&walkwhat), core::mem::transmute_copy(&pwalker)).into()
+            }
+        }
+        unsafe extern "system" fn GetMarshalSizeMax<Identity: windows_core::IUnknownImpl + ICallFrame_Impl>(this: *mut core::ffi::c_void, pmshlcontext: *const CALLFRAME_MARSHALCONTEXT, mshlflags: super::MSHLFLAGS, pcbbufferneeded: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICallFrame_Impl::GetMarshalSizeMax(this, core::mem::transmute_copy(&pmshlcontext), core::mem::transmute_copy(&mshlflags)) {
+                    Ok(ok__) => {
+                        pcbbufferneeded.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Marshal<Identity: windows_core::IUnknownImpl + ICallFrame_Impl>(this: *mut core::ffi::c_void, pmshlcontext: *const CALLFRAME_MARSHALCONTEXT, mshlflags: super::MSHLFLAGS, pbuffer: *const core::ffi::c_void, cbbuffer: u32, pcbbufferused: *mut u32, pdatarep: *mut u32, prpcflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICallFrame_Impl::Marshal(this, core::mem::transmute_copy(&pmshlcontext), core::mem::transmute_copy(&mshlflags), core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&cbbuffer), core::mem::transmute_copy(&pcbbufferused), core::mem::transmute_copy(&pdatarep), core::mem::transmute_copy(&prpcflags)).into()
+            }
+        }
+        unsafe extern "system" fn Unmarshal<Identity: windows_core::IUnknownImpl + ICallFrame_Impl>(this: *mut core::ffi::c_void, pbuffer: *const core::ffi::c_void, cbbuffer: u32, datarep: u32, pcontext: *const CALLFRAME_MARSHALCONTEXT, pcbunmarshalled: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICallFrame_Impl::Unmarshal(this, core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&cbbuffer), core::mem::transmute_copy(&datarep), core::mem::transmute_copy(&pcontext)) {
+                    Ok(ok__) => {
+                        pcbunmarshalled.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReleaseMarshalData<Identity: windows_core::IUnknownImpl + ICallFrame_Impl>(this: *mut core::ffi::c_void, pbuffer: *const core::ffi::c_void, cbbuffer: u32, ibfirstrelease: u32, datarep: u32, pcontext: *const CALLFRAME_MARSHALCONTEXT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICallFrame_Impl::ReleaseMarshalData(this, core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&cbbuffer), core::mem::transmute_copy(&ibfirstrelease), core::mem::transmute_copy(&datarep), core::mem::transmute_copy(&pcontext)).into()
+            }
+        }
+        unsafe extern "system" fn Invoke<Identity: windows_core::IUnknownImpl + ICallFrame_Impl>(this: *mut core::ffi::c_void, pvreceiver: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICallFrame_Impl::Invoke(this, core::mem::transmute_copy(&pvreceiver)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetInfo: GetInfo::<Identity>,
+            GetIIDAndMethod: GetIIDAndMethod::<Identity>,
+            GetNames: GetNames::<Identity>,
+            GetStackLocation: GetStackLocation::<Identity>,
+            SetStackLocation: SetStackLocation::<Identity>,
+            SetReturnValue: SetReturnValue::<Identity>,
+            GetReturnValue: GetReturnValue::<Identity>,
+            GetParamInfo: GetParamInfo::<Identity>,
+            SetParam: SetParam::<Identity>,
+            GetParam: GetParam::<Identity>,
+            Copy: Copy::<Identity>,
+            Free: Free::<Identity>,
+            FreeParam: FreeParam::<Identity>,
+            WalkFrame: WalkFrame::<Identity>,
+            GetMarshalSizeMax: GetMarshalSizeMax::<Identity>,
+            Marshal: Marshal::<Identity>,
+            Unmarshal: Unmarshal::<Identity>,
+            ReleaseMarshalData: ReleaseMarshalData::<Identity>,
+            Invoke: Invoke::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ICallFrame {}
 windows_core::imp::define_interface!(ICallFrameEvents, ICallFrameEvents_Vtbl, 0xfd5e0843_fc91_11d0_97d7_00c04fb9618a);
 windows_core::imp::interface_hierarchy!(ICallFrameEvents, windows_core::IUnknown);
@@ -435,6 +609,20 @@ impl ICallFrameEvents_Vtbl {
         iid == &<ICallFrameEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICallFrameEvents_Impl> windows_core::imp::TearOffVtable<Identity> for ICallFrameEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnCall<Identity: windows_core::IUnknownImpl + ICallFrameEvents_Impl>(this: *mut core::ffi::c_void, pframe: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICallFrameEvents_Impl::OnCall(this, core::mem::transmute_copy(&pframe)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnCall: OnCall::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ICallFrameEvents {}
 windows_core::imp::define_interface!(ICallFrameWalker, ICallFrameWalker_Vtbl, 0x08b23919_392d_11d2_b8a4_00c04fb9618a);
 windows_core::imp::interface_hierarchy!(ICallFrameWalker, windows_core::IUnknown);
@@ -466,6 +654,20 @@ impl ICallFrameWalker_Vtbl {
         iid == &<ICallFrameWalker as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICallFrameWalker_Impl> windows_core::imp::TearOffVtable<Identity> for ICallFrameWalker_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnWalkInterface<Identity: windows_core::IUnknownImpl + ICallFrameWalker_Impl>(this: *mut core::ffi::c_void, iid: *const windows_core::GUID, ppvinterface: *const *const core::ffi::c_void, fin: windows_core::BOOL, fout: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICallFrameWalker_Impl::OnWalkInterface(this, core::mem::transmute_copy(&iid), core::mem::transmute_copy(&ppvinterface), core::mem::transmute_copy(&fin), core::mem::transmute_copy(&fout)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnWalkInterface: OnWalkInterface::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ICallFrameWalker {}
 windows_core::imp::define_interface!(ICallIndirect, ICallIndirect_Vtbl, 0xd573b4b1_894e_11d2_b8b6_00c04fb9618a);
 windows_core::imp::interface_hierarchy!(ICallIndirect, windows_core::IUnknown);
@@ -545,6 +747,50 @@ impl ICallIndirect_Vtbl {
         iid == &<ICallIndirect as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICallIndirect_Impl> windows_core::imp::TearOffVtable<Identity> for ICallIndirect_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CallIndirect<Identity: windows_core::IUnknownImpl + ICallIndirect_Impl>(this: *mut core::ffi::c_void, phrreturn: *mut windows_core::HRESULT, imethod: u32, pvargs: *const core::ffi::c_void, cbargs: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICallIndirect_Impl::CallIndirect(this, core::mem::transmute_copy(&phrreturn), core::mem::transmute_copy(&imethod), core::mem::transmute_copy(&pvargs), core::mem::transmute_copy(&cbargs)).into()
+            }
+        }
+        unsafe extern "system" fn GetMethodInfo<Identity: windows_core::IUnknownImpl + ICallIndirect_Impl>(this: *mut core::ffi::c_void, imethod: u32, pinfo: *mut CALLFRAMEINFO, pwszmethod: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICallIndirect_Impl::GetMethodInfo(this, core::mem::transmute_copy(&imethod), core::mem::transmute_copy(&pinfo), core::mem::transmute_copy(&pwszmethod)).into()
+            }
+        }
+        unsafe extern "system" fn GetStackSize<Identity: windows_core::IUnknownImpl + ICallIndirect_Impl>(this: *mut core::ffi::c_void, imethod: u32, cbargs: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICallIndirect_Impl::GetStackSize(this, core::mem::transmute_copy(&imethod)) {
+                    Ok(ok__) => {
+                        cbargs.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetIID<Identity: windows_core::IUnknownImpl + ICallIndirect_Impl>(this: *mut core::ffi::c_void, piid: *mut windows_core::GUID, pfderivesfromidispatch: *mut windows_core::BOOL, pcmethod: *mut u32, pwszinterface: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICallIndirect_Impl::GetIID(this, core::mem::transmute_copy(&piid), core::mem::transmute_copy(&pfderivesfromidispatch), core::mem::transmute_copy(&pcmethod), core::mem::transmute_copy(&pwszinterface)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CallIndirect: CallIndirect::<Identity>,
+            GetMethodInfo: GetMethodInfo::<Identity>,
+            GetStackSize: GetStackSize::<Identity>,
+            GetIID: GetIID::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICallIndirect {}
 windows_core::imp::define_interface!(ICallInterceptor, ICallInterceptor_Vtbl, 0x60c7ca75_896d_11d2_b8b6_00c04fb9618a);
 impl core::ops::Deref for ICallInterceptor {
@@ -609,6 +855,36 @@ impl ICallInterceptor_Vtbl {
         iid == &<ICallInterceptor as windows_core::Interface>::IID || iid == &<ICallIndirect as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICallInterceptor_Impl> windows_core::imp::TearOffVtable<Identity> for ICallInterceptor_Vtbl
+where
+    ICallIndirect_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterSink<Identity: windows_core::IUnknownImpl + ICallInterceptor_Impl>(this: *mut core::ffi::c_void, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICallInterceptor_Impl::RegisterSink(this, core::mem::transmute_copy(&psink)).into()
+            }
+        }
+        unsafe extern "system" fn GetRegisteredSink<Identity: windows_core::IUnknownImpl + ICallInterceptor_Impl>(this: *mut core::ffi::c_void, ppsink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICallInterceptor_Impl::GetRegisteredSink(this) {
+                    Ok(ok__) => {
+                        ppsink.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <ICallIndirect_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterSink: RegisterSink::<Identity>,
+            GetRegisteredSink: GetRegisteredSink::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICallInterceptor {}
 windows_core::imp::define_interface!(ICallUnmarshal, ICallUnmarshal_Vtbl, 0x5333b003_2e42_11d2_b89d_00c04fb9618a);
 windows_core::imp::interface_hierarchy!(ICallUnmarshal, windows_core::IUnknown);
@@ -655,6 +931,30 @@ impl ICallUnmarshal_Vtbl {
         iid == &<ICallUnmarshal as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICallUnmarshal_Impl> windows_core::imp::TearOffVtable<Identity> for ICallUnmarshal_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Unmarshal<Identity: windows_core::IUnknownImpl + ICallUnmarshal_Impl>(this: *mut core::ffi::c_void, imethod: u32, pbuffer: *const core::ffi::c_void, cbbuffer: u32, fforcebuffercopy: windows_core::BOOL, datarep: u32, pcontext: *const CALLFRAME_MARSHALCONTEXT, pcbunmarshalled: *mut u32, ppframe: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICallUnmarshal_Impl::Unmarshal(this, core::mem::transmute_copy(&imethod), core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&cbbuffer), core::mem::transmute_copy(&fforcebuffercopy), core::mem::transmute_copy(&datarep), core::mem::transmute_copy(&pcontext), core::mem::transmute_copy(&pcbunmarshalled), core::mem::transmute_copy(&ppframe)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseMarshalData<Identity: windows_core::IUnknownImpl + ICallUnmarshal_Impl>(this: *mut core::ffi::c_void, imethod: u32, pbuffer: *const core::ffi::c_void, cbbuffer: u32, ibfirstrelease: u32, datarep: u32, pcontext: *const CALLFRAME_MARSHALCONTEXT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICallUnmarshal_Impl::ReleaseMarshalData(this, core::mem::transmute_copy(&imethod), core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&cbbuffer), core::mem::transmute_copy(&ibfirstrelease), core::mem::transmute_copy(&datarep), core::mem::transmute_copy(&pcontext)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Unmarshal: Unmarshal::<Identity>,
+            ReleaseMarshalData: ReleaseMarshalData::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICallUnmarshal {}
 windows_core::imp::define_interface!(IInterfaceRelated, IInterfaceRelated_Vtbl, 0xd1fb5a79_7706_11d1_adba_00c04fc2adc0);
 windows_core::imp::interface_hierarchy!(IInterfaceRelated, windows_core::IUnknown);
@@ -706,4 +1006,34 @@ impl IInterfaceRelated_Vtbl {
         iid == &<IInterfaceRelated as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInterfaceRelated_Impl> windows_core::imp::TearOffVtable<Identity> for IInterfaceRelated_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetIID<Identity: windows_core::IUnknownImpl + IInterfaceRelated_Impl>(this: *mut core::ffi::c_void, iid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInterfaceRelated_Impl::SetIID(this, core::mem::transmute_copy(&iid)).into()
+            }
+        }
+        unsafe extern "system" fn GetIID<Identity: windows_core::IUnknownImpl + IInterfaceRelated_Impl>(this: *mut core::ffi::c_void, piid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInterfaceRelated_Impl::GetIID(this) {
+                    Ok(ok__) => {
+                        piid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetIID: SetIID::<Identity>,
+            GetIID: GetIID::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IInterfaceRelated {}
@@ -41,6 +41,12 @@ impl IDontSupportEventSubscription_Vtbl {
         iid == &<IDontSupportEventSubscription as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDontSupportEventSubscription_Impl> windows_core::imp::TearOffVtable<Identity> for IDontSupportEventSubscription_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IDontSupportEventSubscription {}
 windows_core::imp::define_interface!(IEnumEventObject, IEnumEventObject_Vtbl, 0xf4a07d63_2e25_11d1_9964_00c04fbbb345);
 windows_core::imp::interface_hierarchy!(IEnumEventObject, windows_core::IUnknown);
@@ -120,6 +126,50 @@ impl IEnumEventObject_Vtbl {
         iid == &<IEnumEventObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumEventObject_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumEventObject_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumEventObject_Impl>(this: *mut core::ffi::c_void, ppinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumEventObject_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppinterface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumEventObject_Impl>(this: *mut core::ffi::c_void, creqelem: u32, ppinterface: *mut *mut core::ffi::c_void, cretelem: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumEventObject_Impl::Next(this, core::mem::transmute_copy(&creqelem), core::mem::transmute_copy(&ppinterface), core::mem::transmute_copy(&cretelem)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumEventObject_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumEventObject_Impl::Reset(this)
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumEventObject_Impl>(this: *mut core::ffi::c_void, cskipelem: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumEventObject_Impl::Skip(this, core::mem::transmute_copy(&cskipelem)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Clone: Clone::<Identity>,
+            Next: Next::<Identity>,
+            Reset: Reset::<Identity>,
+            Skip: Skip::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumEventObject {}
 windows_core::imp::define_interface!(IEventClass, IEventClass_Vtbl, 0xfb2b72a0_7a68_11d1_88f9_0080c7d771bf);
 impl core::ops::Deref for IEventClass {
@@ -382,6 +432,157 @@ impl IEventClass_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IEventClass_Impl> windows_core::imp::TearOffVtable<Identity> for IEventClass_Vtbl
+where
+    super::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EventClassID<Identity: windows_core::IUnknownImpl + IEventClass_Impl>(this: *mut core::ffi::c_void, pbstreventclassid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventClass_Impl::EventClassID(this) {
+                    Ok(ok__) => {
+                        pbstreventclassid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEventClassID<Identity: windows_core::IUnknownImpl + IEventClass_Impl>(this: *mut core::ffi::c_void, bstreventclassid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventClass_Impl::SetEventClassID(this, core::mem::transmute(&bstreventclassid)).into()
+            }
+        }
+        unsafe extern "system" fn EventClassName<Identity: windows_core::IUnknownImpl + IEventClass_Impl>(this: *mut core::ffi::c_void, pbstreventclassname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventClass_Impl::EventClassName(this) {
+                    Ok(ok__) => {
+                        pbstreventclassname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEventClassName<Identity: windows_core::IUnknownImpl + IEventClass_Impl>(this: *mut core::ffi::c_void, bstreventclassname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventClass_Impl::SetEventClassName(this, core::mem::transmute(&bstreventclassname)).into()
+            }
+        }
+        unsafe extern "system" fn OwnerSID<Identity: windows_core::IUnknownImpl + IEventClass_Impl>(this: *mut core::ffi::c_void, pbstrownersid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventClass_Impl::OwnerSID(this) {
+                    Ok(ok__) => {
+                        pbstrownersid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetOwnerSID<Identity: windows_core::IUnknownImpl + IEventClass_Impl>(this: *mut core::ffi::c_void, bstrownersid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventClass_Impl::SetOwnerSID(this, core::mem::transmute(&bstrownersid)).into()
+            }
+        }
+        unsafe extern "system" fn FiringInterfaceID<Identity: windows_core::IUnknownImpl + IEventClass_Impl>(this: *mut core::ffi::c_void, pbstrfiringinterfaceid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventClass_Impl::FiringInterfaceID(this) {
+                    Ok(ok__) => {
+                        pbstrfiringinterfaceid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetFiringInterfaceID<Identity: windows_core::IUnknownImpl + IEventClass_Impl>(this: *mut core::ffi::c_void, bstrfiringinterfaceid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventClass_Impl::SetFiringInterfaceID(this, core::mem::transmute(&bstrfiringinterfaceid)).into()
+            }
+        }
+        unsafe extern "system" fn Description<Identity: windows_core::IUnknownImpl + IEventClass_Impl>(this: *mut core::ffi::c_void, pbstrdescription: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventClass_Impl::Description(this) {
+                    Ok(ok__) => {
+                        pbstrdescription.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDescription<Identity: windows_core::IUnknownImpl + IEventClass_Impl>(this: *mut core::ffi::c_void, bstrdescription: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventClass_Impl::SetDescription(this, core::mem::transmute(&bstrdescription)).into()
+            }
+        }
+        unsafe extern "system" fn CustomConfigCLSID<Identity: windows_core::IUnknownImpl + IEventClass_Impl>(this: *mut core::ffi::c_void, pbstrcustomconfigclsid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventClass_Impl::CustomConfigCLSID(this) {
+                    Ok(ok__) => {
+                        pbstrcustomconfigclsid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCustomConfigCLSID<Identity: windows_core::IUnknownImpl + IEventClass_Impl>(this: *mut core::ffi::c_void, bstrcustomconfigclsid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventClass_Impl::SetCustomConfigCLSID(this, core::mem::transmute(&bstrcustomconfigclsid)).into()
+            }
+        }
+        unsafe extern "system" fn TypeLib<Identity: windows_core::IUnknownImpl + IEventClass_Impl>(this: *mut core::ffi::c_void, pbstrtypelib: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventClass_Impl::TypeLib(this) {
+                    Ok(ok__) => {
+                        pbstrtypelib.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTypeLib<Identity: windows_core::IUnknownImpl + IEventClass_Impl>(this: *mut core::ffi::c_void, bstrtypelib: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventClass_Impl::SetTypeLib(this, core::mem::transmute(&bstrtypelib)).into()
+            }
+        }
+        Self {
+            base__: <super::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EventClassID: EventClassID::<Identity>,
+            SetEventClassID: SetEventClassID::<Identity>,
+            EventClassName: EventClassName::<Identity>,
+            SetEventClassName: SetEventClassName::<Identity>,
+            OwnerSID: OwnerSID::<Identity>,
+            SetOwnerSID: SetOwnerSID::<Identity>,
+            FiringInterfaceID: FiringInterfaceID::<Identity>,
+            SetFiringInterfaceID: SetFiringInterfaceID::<Identity>,
+            Description: Description::<Identity>,
+            SetDescription: SetDescription::<Identity>,
+            CustomConfigCLSID: CustomConfigCLSID::<Identity>,
+            SetCustomConfigCLSID: SetCustomConfigCLSID::<Identity>,
+            TypeLib: TypeLib::<Identity>,
+            SetTypeLib: SetTypeLib::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IEventClass {}
 windows_core::imp::define_interface!(IEventClass2, IEventClass2_Vtbl, 0xfb2b72a1_7a68_11d1_88f9_0080c7d771bf);
 impl core::ops::Deref for IEventClass2 {
@@ -545,6 +746,97 @@ impl IEventClass2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IEventClass2_Impl> windows_core::imp::TearOffVtable<Identity> for IEventClass2_Vtbl
+where
+    IEventClass_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn PublisherID<Identity: windows_core::IUnknownImpl + IEventClass2_Impl>(this: *mut core::ffi::c_void, pbstrpublisherid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventClass2_Impl::PublisherID(this) {
+                    Ok(ok__) => {
+                        pbstrpublisherid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPublisherID<Identity: windows_core::IUnknownImpl + IEventClass2_Impl>(this: *mut core::ffi::c_void, bstrpublisherid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventClass2_Impl::SetPublisherID(this, core::mem::transmute(&bstrpublisherid)).into()
+            }
+        }
+        unsafe extern "system" fn MultiInterfacePublisherFilterCLSID<Identity: windows_core::IUnknownImpl + IEventClass2_Impl>(this: *mut core::ffi::c_void, pbstrpubfilclsid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventClass2_Impl::MultiInterfacePublisherFilterCLSID(this) {
+                    Ok(ok__) => {
+                        pbstrpubfilclsid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMultiInterfacePublisherFilterCLSID<Identity: windows_core::IUnknownImpl + IEventClass2_Impl>(this: *mut core::ffi::c_void, bstrpubfilclsid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventClass2_Impl::SetMultiInterfacePublisherFilterCLSID(this, core::mem::transmute(&bstrpubfilclsid)).into()
+            }
+        }
+        unsafe extern "system" fn AllowInprocActivation<Identity: windows_core::IUnknownImpl + IEventClass2_Impl>(this: *mut core::ffi::c_void, pfallowinprocactivation: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventClass2_Impl::AllowInprocActivation(this) {
+                    Ok(ok__) => {
+                        pfallowinprocactivation.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAllowInprocActivation<Identity: windows_core::IUnknownImpl + IEventClass2_Impl>(this: *mut core::ffi::c_void, fallowinprocactivation: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventClass2_Impl::SetAllowInprocActivation(this, core::mem::transmute_copy(&fallowinprocactivation)).into()
+            }
+        }
+        unsafe extern "system" fn FireInParallel<Identity: windows_core::IUnknownImpl + IEventClass2_Impl>(this: *mut core::ffi::c_void, pffireinparallel: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventClass2_Impl::FireInParallel(this) {
+                    Ok(ok__) => {
+                        pffireinparallel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetFireInParallel<Identity: windows_core::IUnknownImpl + IEventClass2_Impl>(this: *mut core::ffi::c_void, ffireinparallel: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventClass2_Impl::SetFireInParallel(this, core::mem::transmute_copy(&ffireinparallel)).into()
+            }
+        }
+        Self {
+            base__: <IEventClass_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PublisherID: PublisherID::<Identity>,
+            SetPublisherID: SetPublisherID::<Identity>,
+            MultiInterfacePublisherFilterCLSID: MultiInterfacePublisherFilterCLSID::<Identity>,
+            SetMultiInterfacePublisherFilterCLSID: SetMultiInterfacePublisherFilterCLSID::<Identity>,
+            AllowInprocActivation: AllowInprocActivation::<Identity>,
+            SetAllowInprocActivation: SetAllowInprocActivation::<Identity>,
+            FireInParallel: FireInParallel::<Identity>,
+            SetFireInParallel: SetFireInParallel::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IEventClass2 {}
 windows_core::imp::define_interface!(IEventControl, IEventControl_Vtbl, 0x0343e2f4_86f6_11d1_b760_00c04fb926af);
 impl core::ops::Deref for IEventControl {
@@ -666,6 +958,70 @@ impl IEventControl_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IEventControl_Impl> windows_core::imp::TearOffVtable<Identity> for IEventControl_Vtbl
+where
+    super::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetPublisherFilter<Identity: windows_core::IUnknownImpl + IEventControl_Impl>(this: *mut core::ffi::c_void, methodname: *mut core::ffi::c_void, ppublisherfilter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventControl_Impl::SetPublisherFilter(this, core::mem::transmute(&methodname), core::mem::transmute_copy(&ppublisherfilter)).into()
+            }
+        }
+        unsafe extern "system" fn AllowInprocActivation<Identity: windows_core::IUnknownImpl + IEventControl_Impl>(this: *mut core::ffi::c_void, pfallowinprocactivation: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventControl_Impl::AllowInprocActivation(this) {
+                    Ok(ok__) => {
+                        pfallowinprocactivation.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAllowInprocActivation<Identity: windows_core::IUnknownImpl + IEventControl_Impl>(this: *mut core::ffi::c_void, fallowinprocactivation: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventControl_Impl::SetAllowInprocActivation(this, core::mem::transmute_copy(&fallowinprocactivation)).into()
+            }
+        }
+        unsafe extern "system" fn GetSubscriptions<Identity: windows_core::IUnknownImpl + IEventControl_Impl>(this: *mut core::ffi::c_void, methodname: *mut core::ffi::c_void, optionalcriteria: *mut core::ffi::c_void, optionalerrorindex: *const i32, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventControl_Impl::GetSubscriptions(this, core::mem::transmute(&methodname), core::mem::transmute(&optionalcriteria), core::mem::transmute_copy(&optionalerrorindex)) {
+                    Ok(ok__) => {
+                        ppcollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDefaultQuery<Identity: windows_core::IUnknownImpl + IEventControl_Impl>(this: *mut core::ffi::c_void, methodname: *mut core::ffi::c_void, criteria: *mut core::ffi::c_void, errorindex: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventControl_Impl::SetDefaultQuery(this, core::mem::transmute(&methodname), core::mem::transmute(&criteria)) {
+                    Ok(ok__) => {
+                        errorindex.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetPublisherFilter: SetPublisherFilter::<Identity>,
+            AllowInprocActivation: AllowInprocActivation::<Identity>,
+            SetAllowInprocActivation: SetAllowInprocActivation::<Identity>,
+            GetSubscriptions: GetSubscriptions::<Identity>,
+            SetDefaultQuery: SetDefaultQuery::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IEventControl {}
 windows_core::imp::define_interface!(IEventObjectChange, IEventObjectChange_Vtbl, 0xf4a07d70_2e25_11d1_9964_00c04fbbb345);
 windows_core::imp::interface_hierarchy!(IEventObjectChange, windows_core::IUnknown);
@@ -724,6 +1080,37 @@ impl IEventObjectChange_Vtbl {
         iid == &<IEventObjectChange as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEventObjectChange_Impl> windows_core::imp::TearOffVtable<Identity> for IEventObjectChange_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ChangedSubscription<Identity: windows_core::IUnknownImpl + IEventObjectChange_Impl>(this: *mut core::ffi::c_void, changetype: EOC_ChangeType, bstrsubscriptionid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventObjectChange_Impl::ChangedSubscription(this, core::mem::transmute_copy(&changetype), core::mem::transmute(&bstrsubscriptionid)).into()
+            }
+        }
+        unsafe extern "system" fn ChangedEventClass<Identity: windows_core::IUnknownImpl + IEventObjectChange_Impl>(this: *mut core::ffi::c_void, changetype: EOC_ChangeType, bstreventclassid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventObjectChange_Impl::ChangedEventClass(this, core::mem::transmute_copy(&changetype), core::mem::transmute(&bstreventclassid)).into()
+            }
+        }
+        unsafe extern "system" fn ChangedPublisher<Identity: windows_core::IUnknownImpl + IEventObjectChange_Impl>(this: *mut core::ffi::c_void, changetype: EOC_ChangeType, bstrpublisherid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventObjectChange_Impl::ChangedPublisher(this, core::mem::transmute_copy(&changetype), core::mem::transmute(&bstrpublisherid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ChangedSubscription: ChangedSubscription::<Identity>,
+            ChangedEventClass: ChangedEventClass::<Identity>,
+            ChangedPublisher: ChangedPublisher::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEventObjectChange {}
 windows_core::imp::define_interface!(IEventObjectChange2, IEventObjectChange2_Vtbl, 0x7701a9c3_bd68_438f_83e0_67bf4f53a422);
 windows_core::imp::interface_hierarchy!(IEventObjectChange2, windows_core::IUnknown);
@@ -770,6 +1157,30 @@ impl IEventObjectChange2_Vtbl {
         iid == &<IEventObjectChange2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEventObjectChange2_Impl> windows_core::imp::TearOffVtable<Identity> for IEventObjectChange2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ChangedSubscription<Identity: windows_core::IUnknownImpl + IEventObjectChange2_Impl>(this: *mut core::ffi::c_void, pinfo: *const COMEVENTSYSCHANGEINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventObjectChange2_Impl::ChangedSubscription(this, core::mem::transmute_copy(&pinfo)).into()
+            }
+        }
+        unsafe extern "system" fn ChangedEventClass<Identity: windows_core::IUnknownImpl + IEventObjectChange2_Impl>(this: *mut core::ffi::c_void, pinfo: *const COMEVENTSYSCHANGEINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventObjectChange2_Impl::ChangedEventClass(this, core::mem::transmute_copy(&pinfo)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ChangedSubscription: ChangedSubscription::<Identity>,
+            ChangedEventClass: ChangedEventClass::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEventObjectChange2 {}
 windows_core::imp::define_interface!(IEventObjectCollection, IEventObjectCollection_Vtbl, 0xf89ac270_d4eb_11d1_b682_00805fc79216);
 impl core::ops::Deref for IEventObjectCollection {
@@ -917,11 +1328,88 @@ impl IEventObjectCollection_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IEventObjectCollection {}
-windows_core::imp::define_interface!(IEventProperty, IEventProperty_Vtbl, 0xda538ee2_f4de_11d1_b6bb_00805fc79216);
-impl core::ops::Deref for IEventProperty {
-    type Target = super::IDispatch;
-    fn deref(&self) -> &Self::Target {
+impl<Identity: windows_core::IUnknownImpl + IEventObjectCollection_Impl> windows_core::imp::TearOffVtable<Identity> for IEventObjectCollection_Vtbl
+where
+    super::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + IEventObjectCollection_Impl>(this: *mut core::ffi::c_void, ppunkenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventObjectCollection_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        ppunkenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_Item<Identity: windows_core::IUnknownImpl + IEventObjectCollection_Impl>(this: *mut core::ffi::c_void, objectid: *mut core::ffi::c_void, pitem: *mut super::super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventObjectCollection_Impl::get_Item(this, core::mem::transmute(&objectid)) {
+                    Ok(ok__) => {
+                        pitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NewEnum<Identity: windows_core::IUnknownImpl + IEventObjectCollection_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventObjectCollection_Impl::NewEnum(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + IEventObjectCollection_Impl>(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventObjectCollection_Impl::Count(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Add<Identity: windows_core::IUnknownImpl + IEventObjectCollection_Impl>(this: *mut core::ffi::c_void, item: *const super::super::Variant::VARIANT, objectid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventObjectCollection_Impl::Add(this, core::mem::transmute_copy(&item), core::mem::transmute(&objectid)).into()
+            }
+        }
+        unsafe extern "system" fn Remove<Identity: windows_core::IUnknownImpl + IEventObjectCollection_Impl>(this: *mut core::ffi::c_void, objectid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventObjectCollection_Impl::Remove(this, core::mem::transmute(&objectid)).into()
+            }
+        }
+        Self {
+            base__: <super::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            _NewEnum: _NewEnum::<Identity>,
+            get_Item: get_Item::<Identity>,
+            NewEnum: NewEnum::<Identity>,
+            Count: Count::<Identity>,
+            Add: Add::<Identity>,
+            Remove: Remove::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IEventObjectCollection {}
+windows_core::imp::define_interface!(IEventProperty, IEventProperty_Vtbl, 0xda538ee2_f4de_11d1_b6bb_00805fc79216);
+impl core::ops::Deref for IEventProperty {
+    type Target = super::IDispatch;
+    fn deref(&self) -> &Self::Target {
         unsafe { core::mem::transmute(self) }
     }
 }
@@ -1022,6 +1510,57 @@ impl IEventProperty_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IEventProperty_Impl> windows_core::imp::TearOffVtable<Identity> for IEventProperty_Vtbl
+where
+    super::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + IEventProperty_Impl>(this: *mut core::ffi::c_void, propertyname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventProperty_Impl::Name(this) {
+                    Ok(ok__) => {
+                        propertyname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetName<Identity: windows_core::IUnknownImpl + IEventProperty_Impl>(this: *mut core::ffi::c_void, propertyname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventProperty_Impl::SetName(this, core::mem::transmute(&propertyname)).into()
+            }
+        }
+        unsafe extern "system" fn Value<Identity: windows_core::IUnknownImpl + IEventProperty_Impl>(this: *mut core::ffi::c_void, propertyvalue: *mut super::super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventProperty_Impl::Value(this) {
+                    Ok(ok__) => {
+                        propertyvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetValue<Identity: windows_core::IUnknownImpl + IEventProperty_Impl>(this: *mut core::ffi::c_void, propertyvalue: *const super::super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventProperty_Impl::SetValue(this, core::mem::transmute_copy(&propertyvalue)).into()
+            }
+        }
+        Self {
+            base__: <super::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Name: Name::<Identity>,
+            SetName: SetName::<Identity>,
+            Value: Value::<Identity>,
+            SetValue: SetValue::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IEventProperty {}
 windows_core::imp::define_interface!(IEventPublisher, IEventPublisher_Vtbl, 0xe341516b_2e32_11d1_9964_00c04fbbb345);
 impl core::ops::Deref for IEventPublisher {
@@ -1292,6 +1831,157 @@ impl IEventPublisher_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IEventPublisher_Impl> windows_core::imp::TearOffVtable<Identity> for IEventPublisher_Vtbl
+where
+    super::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn PublisherID<Identity: windows_core::IUnknownImpl + IEventPublisher_Impl>(this: *mut core::ffi::c_void, pbstrpublisherid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventPublisher_Impl::PublisherID(this) {
+                    Ok(ok__) => {
+                        pbstrpublisherid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPublisherID<Identity: windows_core::IUnknownImpl + IEventPublisher_Impl>(this: *mut core::ffi::c_void, bstrpublisherid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventPublisher_Impl::SetPublisherID(this, core::mem::transmute(&bstrpublisherid)).into()
+            }
+        }
+        unsafe extern "system" fn PublisherName<Identity: windows_core::IUnknownImpl + IEventPublisher_Impl>(this: *mut core::ffi::c_void, pbstrpublishername: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventPublisher_Impl::PublisherName(this) {
+                    Ok(ok__) => {
+                        pbstrpublishername.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPublisherName<Identity: windows_core::IUnknownImpl + IEventPublisher_Impl>(this: *mut core::ffi::c_void, bstrpublishername: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventPublisher_Impl::SetPublisherName(this, core::mem::transmute(&bstrpublishername)).into()
+            }
+        }
+        unsafe extern "system" fn PublisherType<Identity: windows_core::IUnknownImpl + IEventPublisher_Impl>(this: *mut core::ffi::c_void, pbstrpublishertype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventPublisher_Impl::PublisherType(this) {
+                    Ok(ok__) => {
+                        pbstrpublishertype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPublisherType<Identity: windows_core::IUnknownImpl + IEventPublisher_Impl>(this: *mut core::ffi::c_void, bstrpublishertype: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventPublisher_Impl::SetPublisherType(this, core::mem::transmute(&bstrpublishertype)).into()
+            }
+        }
+        unsafe extern "system" fn OwnerSID<Identity: windows_core::IUnknownImpl + IEventPublisher_Impl>(this: *mut core::ffi::c_void, pbstrownersid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventPublisher_Impl::OwnerSID(this) {
+                    Ok(ok__) => {
+                        pbstrownersid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetOwnerSID<Identity: windows_core::IUnknownImpl + IEventPublisher_Impl>(this: *mut core::ffi::c_void, bstrownersid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventPublisher_Impl::SetOwnerSID(this, core::mem::transmute(&bstrownersid)).into()
+            }
+        }
+        unsafe extern "system" fn Description<Identity: windows_core::IUnknownImpl + IEventPublisher_Impl>(this: *mut core::ffi::c_void, pbstrdescription: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventPublisher_Impl::Description(this) {
+                    Ok(ok__) => {
+                        pbstrdescription.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDescription<Identity: windows_core::IUnknownImpl + IEventPublisher_Impl>(this: *mut core::ffi::c_void, bstrdescription: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventPublisher_Impl::SetDescription(this, core::mem::transmute(&bstrdescription)).into()
+            }
+        }
+        unsafe extern "system" fn GetDefaultProperty<Identity: windows_core::IUnknownImpl + IEventPublisher_Impl>(this: *mut core::ffi::c_void, bstrpropertyname: *mut core::ffi::c_void, propertyvalue: *mut super::super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventPublisher_Impl::GetDefaultProperty(this, core::mem::transmute(&bstrpropertyname)) {
+                    Ok(ok__) => {
+                        propertyvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PutDefaultProperty<Identity: windows_core::IUnknownImpl + IEventPublisher_Impl>(this: *mut core::ffi::c_void, bstrpropertyname: *mut core::ffi::c_void, propertyvalue: *const super::super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventPublisher_Impl::PutDefaultProperty(this, core::mem::transmute(&bstrpropertyname), core::mem::transmute_copy(&propertyvalue)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveDefaultProperty<Identity: windows_core::IUnknownImpl + IEventPublisher_Impl>(this: *mut core::ffi::c_void, bstrpropertyname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventPublisher_Impl::RemoveDefaultProperty(this, core::mem::transmute(&bstrpropertyname)).into()
+            }
+        }
+        unsafe extern "system" fn GetDefaultPropertyCollection<Identity: windows_core::IUnknownImpl + IEventPublisher_Impl>(this: *mut core::ffi::c_void, collection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventPublisher_Impl::GetDefaultPropertyCollection(this) {
+                    Ok(ok__) => {
+                        collection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PublisherID: PublisherID::<Identity>,
+            SetPublisherID: SetPublisherID::<Identity>,
+            PublisherName: PublisherName::<Identity>,
+            SetPublisherName: SetPublisherName::<Identity>,
+            PublisherType: PublisherType::<Identity>,
+            SetPublisherType: SetPublisherType::<Identity>,
+            OwnerSID: OwnerSID::<Identity>,
+            SetOwnerSID: SetOwnerSID::<Identity>,
+            Description: Description::<Identity>,
+            SetDescription: SetDescription::<Identity>,
+            GetDefaultProperty: GetDefaultProperty::<Identity>,
+            PutDefaultProperty: PutDefaultProperty::<Identity>,
+            RemoveDefaultProperty: RemoveDefaultProperty::<Identity>,
+            GetDefaultPropertyCollection: GetDefaultPropertyCollection::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IEventPublisher {}
 windows_core::imp::define_interface!(IEventSubscription, IEventSubscription_Vtbl, 0x4a6b0e15_2e38_11d1_9965_00c04fbbb345);
 impl core::ops::Deref for IEventSubscription {
@@ -1903,56 +2593,407 @@ impl IEventSubscription_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IEventSubscription {}
-windows_core::imp::define_interface!(IEventSystem, IEventSystem_Vtbl, 0x4e14fb9f_2e22_11d1_9964_00c04fbbb345);
-impl core::ops::Deref for IEventSystem {
-    type Target = super::IDispatch;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(IEventSystem, windows_core::IUnknown, super::IDispatch);
-impl IEventSystem {
-    pub unsafe fn Query(&self, progid: &windows_core::BSTR, querycriteria: &windows_core::BSTR, errorindex: *mut i32) -> windows_core::Result<windows_core::IUnknown> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).Query)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(progid), core::mem::transmute_copy(querycriteria), errorindex as _, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+impl<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl> windows_core::imp::TearOffVtable<Identity> for IEventSubscription_Vtbl
+where
+    super::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SubscriptionID<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, pbstrsubscriptionid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventSubscription_Impl::SubscriptionID(this) {
+                    Ok(ok__) => {
+                        pbstrsubscriptionid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn Store<P1>(&self, progid: &windows_core::BSTR, pinterface: P1) -> windows_core::Result<()>
-    where
-        P1: windows_core::Param<windows_core::IUnknown>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).Store)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(progid), pinterface.param().abi()).ok() }
-    }
-    pub unsafe fn Remove(&self, progid: &windows_core::BSTR, querycriteria: &windows_core::BSTR) -> windows_core::Result<i32> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).Remove)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(progid), core::mem::transmute_copy(querycriteria), &mut result__).map(|| result__)
+        unsafe extern "system" fn SetSubscriptionID<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, bstrsubscriptionid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventSubscription_Impl::SetSubscriptionID(this, core::mem::transmute(&bstrsubscriptionid)).into()
+            }
         }
-    }
-    pub unsafe fn EventObjectChangeEventClassID(&self) -> windows_core::Result<windows_core::BSTR> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).EventObjectChangeEventClassID)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        unsafe extern "system" fn SubscriptionName<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, pbstrsubscriptionname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventSubscription_Impl::SubscriptionName(this) {
+                    Ok(ok__) => {
+                        pbstrsubscriptionname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn QueryS(&self, progid: &windows_core::BSTR, querycriteria: &windows_core::BSTR) -> windows_core::Result<windows_core::IUnknown> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).QueryS)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(progid), core::mem::transmute_copy(querycriteria), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn SetSubscriptionName<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, bstrsubscriptionname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventSubscription_Impl::SetSubscriptionName(this, core::mem::transmute(&bstrsubscriptionname)).into()
+            }
         }
-    }
-    pub unsafe fn RemoveS(&self, progid: &windows_core::BSTR, querycriteria: &windows_core::BSTR) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).RemoveS)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(progid), core::mem::transmute_copy(querycriteria)).ok() }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IEventSystem_Vtbl {
-    pub base__: super::IDispatch_Vtbl,
-    pub Query: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void, *mut i32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub Store: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
+        unsafe extern "system" fn PublisherID<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, pbstrpublisherid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventSubscription_Impl::PublisherID(this) {
+                    Ok(ok__) => {
+                        pbstrpublisherid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPublisherID<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, bstrpublisherid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventSubscription_Impl::SetPublisherID(this, core::mem::transmute(&bstrpublisherid)).into()
+            }
+        }
+        unsafe extern "system" fn EventClassID<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, pbstreventclassid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventSubscription_Impl::EventClassID(this) {
+                    Ok(ok__) => {
+                        pbstreventclassid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEventClassID<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, bstreventclassid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventSubscription_Impl::SetEventClassID(this, core::mem::transmute(&bstreventclassid)).into()
+            }
+        }
+        unsafe extern "system" fn MethodName<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, pbstrmethodname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventSubscription_Impl::MethodName(this) {
+                    Ok(ok__) => {
+                        pbstrmethodname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMethodName<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, bstrmethodname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventSubscription_Impl::SetMethodName(this, core::mem::transmute(&bstrmethodname)).into()
+            }
+        }
+        unsafe extern "system" fn SubscriberCLSID<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, pbstrsubscriberclsid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventSubscription_Impl::SubscriberCLSID(this) {
+                    Ok(ok__) => {
+                        pbstrsubscriberclsid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSubscriberCLSID<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, bstrsubscriberclsid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventSubscription_Impl::SetSubscriberCLSID(this, core::mem::transmute(&bstrsubscriberclsid)).into()
+            }
+        }
+        unsafe extern "system" fn SubscriberInterface<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, ppsubscriberinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventSubscription_Impl::SubscriberInterface(this) {
+                    Ok(ok__) => {
+                        ppsubscriberinterface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSubscriberInterface<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, psubscriberinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventSubscription_Impl::SetSubscriberInterface(this, core::mem::transmute_copy(&psubscriberinterface)).into()
+            }
+        }
+        unsafe extern "system" fn PerUser<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, pfperuser: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventSubscription_Impl::PerUser(this) {
+                    Ok(ok__) => {
+                        pfperuser.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPerUser<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, fperuser: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventSubscription_Impl::SetPerUser(this, core::mem::transmute_copy(&fperuser)).into()
+            }
+        }
+        unsafe extern "system" fn OwnerSID<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, pbstrownersid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventSubscription_Impl::OwnerSID(this) {
+                    Ok(ok__) => {
+                        pbstrownersid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetOwnerSID<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, bstrownersid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventSubscription_Impl::SetOwnerSID(this, core::mem::transmute(&bstrownersid)).into()
+            }
+        }
+        unsafe extern "system" fn Enabled<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, pfenabled: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventSubscription_Impl::Enabled(this) {
+                    Ok(ok__) => {
+                        pfenabled.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEnabled<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, fenabled: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventSubscription_Impl::SetEnabled(this, core::mem::transmute_copy(&fenabled)).into()
+            }
+        }
+        unsafe extern "system" fn Description<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, pbstrdescription: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventSubscription_Impl::Description(this) {
+                    Ok(ok__) => {
+                        pbstrdescription.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDescription<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, bstrdescription: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventSubscription_Impl::SetDescription(this, core::mem::transmute(&bstrdescription)).into()
+            }
+        }
+        unsafe extern "system" fn MachineName<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, pbstrmachinename: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventSubscription_Impl::MachineName(this) {
+                    Ok(ok__) => {
+                        pbstrmachinename.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMachineName<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, bstrmachinename: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventSubscription_Impl::SetMachineName(this, core::mem::transmute(&bstrmachinename)).into()
+            }
+        }
+        unsafe extern "system" fn GetPublisherProperty<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, bstrpropertyname: *mut core::ffi::c_void, propertyvalue: *mut super::super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventSubscription_Impl::GetPublisherProperty(this, core::mem::transmute(&bstrpropertyname)) {
+                    Ok(ok__) => {
+                        propertyvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PutPublisherProperty<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, bstrpropertyname: *mut core::ffi::c_void, propertyvalue: *const super::super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventSubscription_Impl::PutPublisherProperty(this, core::mem::transmute(&bstrpropertyname), core::mem::transmute_copy(&propertyvalue)).into()
+            }
+        }
+        unsafe extern "system" fn RemovePublisherProperty<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, bstrpropertyname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventSubscription_Impl::RemovePublisherProperty(this, core::mem::transmute(&bstrpropertyname)).into()
+            }
+        }
+        unsafe extern "system" fn GetPublisherPropertyCollection<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, collection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventSubscription_Impl::GetPublisherPropertyCollection(this) {
+                    Ok(ok__) => {
+                        collection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSubscriberProperty<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, bstrpropertyname: *mut core::ffi::c_void, propertyvalue: *mut super::super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventSubscription_Impl::GetSubscriberProperty(this, core::mem::transmute(&bstrpropertyname)) {
+                    Ok(ok__) => {
+                        propertyvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PutSubscriberProperty<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, bstrpropertyname: *mut core::ffi::c_void, propertyvalue: *const super::super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventSubscription_Impl::PutSubscriberProperty(this, core::mem::transmute(&bstrpropertyname), core::mem::transmute_copy(&propertyvalue)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveSubscriberProperty<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, bstrpropertyname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventSubscription_Impl::RemoveSubscriberProperty(this, core::mem::transmute(&bstrpropertyname)).into()
+            }
+        }
+        unsafe extern "system" fn GetSubscriberPropertyCollection<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, collection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventSubscription_Impl::GetSubscriberPropertyCollection(this) {
+                    Ok(ok__) => {
+                        collection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InterfaceID<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, pbstrinterfaceid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventSubscription_Impl::InterfaceID(this) {
+                    Ok(ok__) => {
+                        pbstrinterfaceid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetInterfaceID<Identity: windows_core::IUnknownImpl + IEventSubscription_Impl>(this: *mut core::ffi::c_void, bstrinterfaceid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventSubscription_Impl::SetInterfaceID(this, core::mem::transmute(&bstrinterfaceid)).into()
+            }
+        }
+        Self {
+            base__: <super::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SubscriptionID: SubscriptionID::<Identity>,
+            SetSubscriptionID: SetSubscriptionID::<Identity>,
+            SubscriptionName: SubscriptionName::<Identity>,
+            SetSubscriptionName: SetSubscriptionName::<Identity>,
+            PublisherID: PublisherID::<Identity>,
+            SetPublisherID: SetPublisherID::<Identity>,
+            EventClassID: EventClassID::<Identity>,
+            SetEventClassID: SetEventClassID::<Identity>,
+            MethodName: MethodName::<Identity>,
+            SetMethodName: SetMethodName::<Identity>,
+            SubscriberCLSID: SubscriberCLSID::<Identity>,
+            SetSubscriberCLSID: SetSubscriberCLSID::<Identity>,
+            SubscriberInterface: SubscriberInterface::<Identity>,
+            SetSubscriberInterface: SetSubscriberInterface::<Identity>,
+            PerUser: PerUser::<Identity>,
+            SetPerUser: SetPerUser::<Identity>,
+            OwnerSID: OwnerSID::<Identity>,
+            SetOwnerSID: SetOwnerSID::<Identity>,
+            Enabled: Enabled::<Identity>,
+            SetEnabled: SetEnabled::<Identity>,
+            Description: Description::<Identity>,
+            SetDescription: SetDescription::<Identity>,
+            MachineName: MachineName::<Identity>,
+            SetMachineName: SetMachineName::<Identity>,
+            GetPublisherProperty: GetPublisherProperty::<Identity>,
+            PutPublisherProperty: PutPublisherProperty::<Identity>,
+            RemovePublisherProperty: RemovePublisherProperty::<Identity>,
+            GetPublisherPropertyCollection: GetPublisherPropertyCollection::<Identity>,
+            GetSubscriberProperty: GetSubscriberProperty::<Identity>,
+            PutSubscriberProperty: PutSubscriberProperty::<Identity>,
+            RemoveSubscriberProperty: RemoveSubscriberProperty::<Identity>,
+            GetSubscriberPropertyCollection: GetSubscriberPropertyCollection::<Identity>,
+            InterfaceID: InterfaceID::<Identity>,
+            SetInterfaceID: SetInterfaceID::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IEventSubscription {}
+windows_core::imp::define_interface!(IEventSystem, IEventSystem_Vtbl, 0x4e14fb9f_2e22_11d1_9964_00c04fbbb345);
+impl core::ops::Deref for IEventSystem {
+    type Target = super::IDispatch;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(IEventSystem, windows_core::IUnknown, super::IDispatch);
+impl IEventSystem {
+    pub unsafe fn Query(&self, progid: &windows_core::BSTR, querycriteria: &windows_core::BSTR, errorindex: *mut i32) -> windows_core::Result<windows_core::IUnknown> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).Query)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(progid), core::mem::transmute_copy(querycriteria), errorindex as _, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn Store<P1>(&self, progid: &windows_core::BSTR, pinterface: P1) -> windows_core::Result<()>
+    where
+        P1: windows_core::Param<windows_core::IUnknown>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).Store)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(progid), pinterface.param().abi()).ok() }
+    }
+    pub unsafe fn Remove(&self, progid: &windows_core::BSTR, querycriteria: &windows_core::BSTR) -> windows_core::Result<i32> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).Remove)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(progid), core::mem::transmute_copy(querycriteria), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn EventObjectChangeEventClassID(&self) -> windows_core::Result<windows_core::BSTR> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).EventObjectChangeEventClassID)(windows_core::Interface::as_raw(self), &mut result__).map(|| core::mem::transmute(result__))
+        }
+    }
+    pub unsafe fn QueryS(&self, progid: &windows_core::BSTR, querycriteria: &windows_core::BSTR) -> windows_core::Result<windows_core::IUnknown> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).QueryS)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(progid), core::mem::transmute_copy(querycriteria), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn RemoveS(&self, progid: &windows_core::BSTR, querycriteria: &windows_core::BSTR) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).RemoveS)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(progid), core::mem::transmute_copy(querycriteria)).ok() }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IEventSystem_Vtbl {
+    pub base__: super::IDispatch_Vtbl,
+    pub Query: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void, *mut i32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub Store: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
     pub Remove: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
     pub EventObjectChangeEventClassID: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
     pub QueryS: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
@@ -2045,6 +3086,83 @@ impl IEventSystem_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IEventSystem_Impl> windows_core::imp::TearOffVtable<Identity> for IEventSystem_Vtbl
+where
+    super::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Query<Identity: windows_core::IUnknownImpl + IEventSystem_Impl>(this: *mut core::ffi::c_void, progid: *mut core::ffi::c_void, querycriteria: *mut core::ffi::c_void, errorindex: *mut i32, ppinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventSystem_Impl::Query(this, core::mem::transmute(&progid), core::mem::transmute(&querycriteria), core::mem::transmute_copy(&errorindex)) {
+                    Ok(ok__) => {
+                        ppinterface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Store<Identity: windows_core::IUnknownImpl + IEventSystem_Impl>(this: *mut core::ffi::c_void, progid: *mut core::ffi::c_void, pinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventSystem_Impl::Store(this, core::mem::transmute(&progid), core::mem::transmute_copy(&pinterface)).into()
+            }
+        }
+        unsafe extern "system" fn Remove<Identity: windows_core::IUnknownImpl + IEventSystem_Impl>(this: *mut core::ffi::c_void, progid: *mut core::ffi::c_void, querycriteria: *mut core::ffi::c_void, errorindex: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventSystem_Impl::Remove(this, core::mem::transmute(&progid), core::mem::transmute(&querycriteria)) {
+                    Ok(ok__) => {
+                        errorindex.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EventObjectChangeEventClassID<Identity: windows_core::IUnknownImpl + IEventSystem_Impl>(this: *mut core::ffi::c_void, pbstreventclassid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventSystem_Impl::EventObjectChangeEventClassID(this) {
+                    Ok(ok__) => {
+                        pbstreventclassid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryS<Identity: windows_core::IUnknownImpl + IEventSystem_Impl>(this: *mut core::ffi::c_void, progid: *mut core::ffi::c_void, querycriteria: *mut core::ffi::c_void, ppinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventSystem_Impl::QueryS(this, core::mem::transmute(&progid), core::mem::transmute(&querycriteria)) {
+                    Ok(ok__) => {
+                        ppinterface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveS<Identity: windows_core::IUnknownImpl + IEventSystem_Impl>(this: *mut core::ffi::c_void, progid: *mut core::ffi::c_void, querycriteria: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventSystem_Impl::RemoveS(this, core::mem::transmute(&progid), core::mem::transmute(&querycriteria)).into()
+            }
+        }
+        Self {
+            base__: <super::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Query: Query::<Identity>,
+            Store: Store::<Identity>,
+            Remove: Remove::<Identity>,
+            EventObjectChangeEventClassID: EventObjectChangeEventClassID::<Identity>,
+            QueryS: QueryS::<Identity>,
+            RemoveS: RemoveS::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IEventSystem {}
 windows_core::imp::define_interface!(IFiringControl, IFiringControl_Vtbl, 0xe0498c93_4efe_11d1_9971_00c04fbbb345);
 impl core::ops::Deref for IFiringControl {
@@ -2088,6 +3206,21 @@ impl IFiringControl_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IFiringControl_Impl> windows_core::imp::TearOffVtable<Identity> for IFiringControl_Vtbl
+where
+    super::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FireSubscription<Identity: windows_core::IUnknownImpl + IFiringControl_Impl>(this: *mut core::ffi::c_void, subscription: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFiringControl_Impl::FireSubscription(this, core::mem::transmute_copy(&subscription)).into()
+            }
+        }
+        Self { base__: <super::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, FireSubscription: FireSubscription::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IFiringControl {}
 windows_core::imp::define_interface!(IMultiInterfaceEventControl, IMultiInterfaceEventControl_Vtbl, 0x0343e2f5_86f6_11d1_b760_00c04fb926af);
 windows_core::imp::interface_hierarchy!(IMultiInterfaceEventControl, windows_core::IUnknown);
@@ -2233,6 +3366,89 @@ impl IMultiInterfaceEventControl_Vtbl {
         iid == &<IMultiInterfaceEventControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMultiInterfaceEventControl_Impl> windows_core::imp::TearOffVtable<Identity> for IMultiInterfaceEventControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetMultiInterfacePublisherFilter<Identity: windows_core::IUnknownImpl + IMultiInterfaceEventControl_Impl>(this: *mut core::ffi::c_void, classfilter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiInterfaceEventControl_Impl::SetMultiInterfacePublisherFilter(this, core::mem::transmute_copy(&classfilter)).into()
+            }
+        }
+        unsafe extern "system" fn GetSubscriptions<Identity: windows_core::IUnknownImpl + IMultiInterfaceEventControl_Impl>(this: *mut core::ffi::c_void, eventiid: *const windows_core::GUID, bstrmethodname: *mut core::ffi::c_void, optionalcriteria: *mut core::ffi::c_void, optionalerrorindex: *const i32, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMultiInterfaceEventControl_Impl::GetSubscriptions(this, core::mem::transmute_copy(&eventiid), core::mem::transmute(&bstrmethodname), core::mem::transmute(&optionalcriteria), core::mem::transmute_copy(&optionalerrorindex)) {
+                    Ok(ok__) => {
+                        ppcollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDefaultQuery<Identity: windows_core::IUnknownImpl + IMultiInterfaceEventControl_Impl>(this: *mut core::ffi::c_void, eventiid: *const windows_core::GUID, bstrmethodname: *mut core::ffi::c_void, bstrcriteria: *mut core::ffi::c_void, errorindex: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMultiInterfaceEventControl_Impl::SetDefaultQuery(this, core::mem::transmute_copy(&eventiid), core::mem::transmute(&bstrmethodname), core::mem::transmute(&bstrcriteria)) {
+                    Ok(ok__) => {
+                        errorindex.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AllowInprocActivation<Identity: windows_core::IUnknownImpl + IMultiInterfaceEventControl_Impl>(this: *mut core::ffi::c_void, pfallowinprocactivation: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMultiInterfaceEventControl_Impl::AllowInprocActivation(this) {
+                    Ok(ok__) => {
+                        pfallowinprocactivation.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAllowInprocActivation<Identity: windows_core::IUnknownImpl + IMultiInterfaceEventControl_Impl>(this: *mut core::ffi::c_void, fallowinprocactivation: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiInterfaceEventControl_Impl::SetAllowInprocActivation(this, core::mem::transmute_copy(&fallowinprocactivation)).into()
+            }
+        }
+        unsafe extern "system" fn FireInParallel<Identity: windows_core::IUnknownImpl + IMultiInterfaceEventControl_Impl>(this: *mut core::ffi::c_void, pffireinparallel: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMultiInterfaceEventControl_Impl::FireInParallel(this) {
+                    Ok(ok__) => {
+                        pffireinparallel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetFireInParallel<Identity: windows_core::IUnknownImpl + IMultiInterfaceEventControl_Impl>(this: *mut core::ffi::c_void, ffireinparallel: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiInterfaceEventControl_Impl::SetFireInParallel(this, core::mem::transmute_copy(&ffireinparallel)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetMultiInterfacePublisherFilter: SetMultiInterfacePublisherFilter::<Identity>,
+            GetSubscriptions: GetSubscriptions::<Identity>,
+            SetDefaultQuery: SetDefaultQuery::<Identity>,
+            AllowInprocActivation: AllowInprocActivation::<Identity>,
+            SetAllowInprocActivation: SetAllowInprocActivation::<Identity>,
+            FireInParallel: FireInParallel::<Identity>,
+            SetFireInParallel: SetFireInParallel::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMultiInterfaceEventControl {}
 windows_core::imp::define_interface!(IMultiInterfacePublisherFilter, IMultiInterfacePublisherFilter_Vtbl, 0x465e5cc1_7b26_11d1_88fb_0080c7d771bf);
 windows_core::imp::interface_hierarchy!(IMultiInterfacePublisherFilter, windows_core::IUnknown);
@@ -2285,6 +3501,30 @@ impl IMultiInterfacePublisherFilter_Vtbl {
         iid == &<IMultiInterfacePublisherFilter as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMultiInterfacePublisherFilter_Impl> windows_core::imp::TearOffVtable<Identity> for IMultiInterfacePublisherFilter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IMultiInterfacePublisherFilter_Impl>(this: *mut core::ffi::c_void, peic: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiInterfacePublisherFilter_Impl::Initialize(this, core::mem::transmute_copy(&peic)).into()
+            }
+        }
+        unsafe extern "system" fn PrepareToFire<Identity: windows_core::IUnknownImpl + IMultiInterfacePublisherFilter_Impl>(this: *mut core::ffi::c_void, iid: *const windows_core::GUID, methodname: *mut core::ffi::c_void, firingcontrol: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMultiInterfacePublisherFilter_Impl::PrepareToFire(this, core::mem::transmute_copy(&iid), core::mem::transmute(&methodname), core::mem::transmute_copy(&firingcontrol)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            PrepareToFire: PrepareToFire::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMultiInterfacePublisherFilter {}
 windows_core::imp::define_interface!(IPublisherFilter, IPublisherFilter_Vtbl, 0x465e5cc0_7b26_11d1_88fb_0080c7d771bf);
 windows_core::imp::interface_hierarchy!(IPublisherFilter, windows_core::IUnknown);
@@ -2337,4 +3577,28 @@ impl IPublisherFilter_Vtbl {
         iid == &<IPublisherFilter as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPublisherFilter_Impl> windows_core::imp::TearOffVtable<Identity> for IPublisherFilter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IPublisherFilter_Impl>(this: *mut core::ffi::c_void, methodname: *mut core::ffi::c_void, dispuserdefined: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPublisherFilter_Impl::Initialize(this, core::mem::transmute(&methodname), core::mem::transmute_copy(&dispuserdefined)).into()
+            }
+        }
+        unsafe extern "system" fn PrepareToFire<Identity: windows_core::IUnknownImpl + IPublisherFilter_Impl>(this: *mut core::ffi::c_void, methodname: *mut core::ffi::c_void, firingcontrol: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPublisherFilter_Impl::PrepareToFire(this, core::mem::transmute(&methodname), core::mem::transmute_copy(&firingcontrol)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            PrepareToFire: PrepareToFire::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPublisherFilter {}
@@ -781,6 +781,70 @@ impl IMarshal_Vtbl {
         iid == &<IMarshal as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMarshal_Impl> windows_core::imp::TearOffVtable<Identity> for IMarshal_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetUnmarshalClass<Identity: windows_core::IUnknownImpl + IMarshal_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, pv: *const core::ffi::c_void, dwdestcontext: u32, pvdestcontext: *const core::ffi::c_void, mshlflags: u32, pcid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMarshal_Impl::GetUnmarshalClass(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&pv), core::mem::transmute_copy(&dwdestcontext), core::mem::transmute_copy(&pvdestcontext), core::mem::transmute_copy(&mshlflags)) {
+                    Ok(ok__) => {
+                        pcid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMarshalSizeMax<Identity: windows_core::IUnknownImpl + IMarshal_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, pv: *const core::ffi::c_void, dwdestcontext: u32, pvdestcontext: *const core::ffi::c_void, mshlflags: u32, psize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMarshal_Impl::GetMarshalSizeMax(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&pv), core::mem::transmute_copy(&dwdestcontext), core::mem::transmute_copy(&pvdestcontext), core::mem::transmute_copy(&mshlflags)) {
+                    Ok(ok__) => {
+                        psize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MarshalInterface<Identity: windows_core::IUnknownImpl + IMarshal_Impl>(this: *mut core::ffi::c_void, pstm: *mut core::ffi::c_void, riid: *const windows_core::GUID, pv: *const core::ffi::c_void, dwdestcontext: u32, pvdestcontext: *const core::ffi::c_void, mshlflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMarshal_Impl::MarshalInterface(this, core::mem::transmute_copy(&pstm), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&pv), core::mem::transmute_copy(&dwdestcontext), core::mem::transmute_copy(&pvdestcontext), core::mem::transmute_copy(&mshlflags)).into()
+            }
+        }
+        unsafe extern "system" fn UnmarshalInterface<Identity: windows_core::IUnknownImpl + IMarshal_Impl>(this: *mut core::ffi::c_void, pstm: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMarshal_Impl::UnmarshalInterface(this, core::mem::transmute_copy(&pstm), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppv)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseMarshalData<Identity: windows_core::IUnknownImpl + IMarshal_Impl>(this: *mut core::ffi::c_void, pstm: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMarshal_Impl::ReleaseMarshalData(this, core::mem::transmute_copy(&pstm)).into()
+            }
+        }
+        unsafe extern "system" fn DisconnectObject<Identity: windows_core::IUnknownImpl + IMarshal_Impl>(this: *mut core::ffi::c_void, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMarshal_Impl::DisconnectObject(this, core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetUnmarshalClass: GetUnmarshalClass::<Identity>,
+            GetMarshalSizeMax: GetMarshalSizeMax::<Identity>,
+            MarshalInterface: MarshalInterface::<Identity>,
+            UnmarshalInterface: UnmarshalInterface::<Identity>,
+            ReleaseMarshalData: ReleaseMarshalData::<Identity>,
+            DisconnectObject: DisconnectObject::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMarshal {}
 windows_core::imp::define_interface!(IMarshal2, IMarshal2_Vtbl, 0x000001cf_0000_0000_c000_000000000046);
 impl core::ops::Deref for IMarshal2 {
@@ -804,6 +868,12 @@ impl IMarshal2_Vtbl {
         iid == &<IMarshal2 as windows_core::Interface>::IID || iid == &<IMarshal as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMarshal2_Impl> windows_core::imp::TearOffVtable<Identity> for IMarshal2_Vtbl
+where
+    IMarshal_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IMarshal_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IMarshal2 {}
 windows_core::imp::define_interface!(IMarshalingStream, IMarshalingStream_Vtbl, 0xd8f2f5e6_6102_4863_9f26_389a4676efde);
 impl core::ops::Deref for IMarshalingStream {
@@ -850,6 +920,29 @@ impl IMarshalingStream_Vtbl {
         iid == &<IMarshalingStream as windows_core::Interface>::IID || iid == &<super::ISequentialStream as windows_core::Interface>::IID || iid == &<super::IStream as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMarshalingStream_Impl> windows_core::imp::TearOffVtable<Identity> for IMarshalingStream_Vtbl
+where
+    super::IStream_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMarshalingContextAttribute<Identity: windows_core::IUnknownImpl + IMarshalingStream_Impl>(this: *mut core::ffi::c_void, attribute: super::CO_MARSHALING_CONTEXT_ATTRIBUTES, pattributevalue: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMarshalingStream_Impl::GetMarshalingContextAttribute(this, core::mem::transmute_copy(&attribute)) {
+                    Ok(ok__) => {
+                        pattributevalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::IStream_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetMarshalingContextAttribute: GetMarshalingContextAttribute::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMarshalingStream {}
 pub const SMEXF_HANDLER: STDMSHLFLAGS = STDMSHLFLAGS(2i32);
 pub const SMEXF_SERVER: STDMSHLFLAGS = STDMSHLFLAGS(1i32);
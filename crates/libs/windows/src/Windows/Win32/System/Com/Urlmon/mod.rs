@@ -986,6 +986,26 @@ impl IBindCallbackRedirect_Vtbl {
         iid == &<IBindCallbackRedirect as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBindCallbackRedirect_Impl> windows_core::imp::TearOffVtable<Identity> for IBindCallbackRedirect_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Redirect<Identity: windows_core::IUnknownImpl + IBindCallbackRedirect_Impl>(this: *mut core::ffi::c_void, lpcurl: windows_core::PCWSTR, vbcancel: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBindCallbackRedirect_Impl::Redirect(this, core::mem::transmute(&lpcurl)) {
+                    Ok(ok__) => {
+                        vbcancel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Redirect: Redirect::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IBindCallbackRedirect {}
 windows_core::imp::define_interface!(IBindHttpSecurity, IBindHttpSecurity_Vtbl, 0xa9eda967_f50e_4a33_b358_206f6ef3086d);
 windows_core::imp::interface_hierarchy!(IBindHttpSecurity, windows_core::IUnknown);
@@ -1026,6 +1046,26 @@ impl IBindHttpSecurity_Vtbl {
         iid == &<IBindHttpSecurity as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBindHttpSecurity_Impl> windows_core::imp::TearOffVtable<Identity> for IBindHttpSecurity_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetIgnoreCertMask<Identity: windows_core::IUnknownImpl + IBindHttpSecurity_Impl>(this: *mut core::ffi::c_void, pdwignorecertmask: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBindHttpSecurity_Impl::GetIgnoreCertMask(this) {
+                    Ok(ok__) => {
+                        pdwignorecertmask.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetIgnoreCertMask: GetIgnoreCertMask::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IBindHttpSecurity {}
 windows_core::imp::define_interface!(IBindProtocol, IBindProtocol_Vtbl, 0x79eac9cd_baf9_11ce_8c82_00aa004ba90b);
 windows_core::imp::interface_hierarchy!(IBindProtocol, windows_core::IUnknown);
@@ -1070,6 +1110,26 @@ impl IBindProtocol_Vtbl {
         iid == &<IBindProtocol as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IBindProtocol_Impl> windows_core::imp::TearOffVtable<Identity> for IBindProtocol_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateBinding<Identity: windows_core::IUnknownImpl + IBindProtocol_Impl>(this: *mut core::ffi::c_void, szurl: windows_core::PCWSTR, pbc: *mut core::ffi::c_void, ppb: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IBindProtocol_Impl::CreateBinding(this, core::mem::transmute(&szurl), core::mem::transmute_copy(&pbc)) {
+                    Ok(ok__) => {
+                        ppb.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateBinding: CreateBinding::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IBindProtocol {}
 windows_core::imp::define_interface!(ICatalogFileInfo, ICatalogFileInfo_Vtbl, 0x711c7600_6b48_11d1_b403_00aa00b92af1);
 windows_core::imp::interface_hierarchy!(ICatalogFileInfo, windows_core::IUnknown);
@@ -1125,6 +1185,36 @@ impl ICatalogFileInfo_Vtbl {
         iid == &<ICatalogFileInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICatalogFileInfo_Impl> windows_core::imp::TearOffVtable<Identity> for ICatalogFileInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCatalogFile<Identity: windows_core::IUnknownImpl + ICatalogFileInfo_Impl>(this: *mut core::ffi::c_void, ppszcatalogfile: *mut windows_core::PSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICatalogFileInfo_Impl::GetCatalogFile(this) {
+                    Ok(ok__) => {
+                        ppszcatalogfile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetJavaTrust<Identity: windows_core::IUnknownImpl + ICatalogFileInfo_Impl>(this: *mut core::ffi::c_void, ppjavatrust: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICatalogFileInfo_Impl::GetJavaTrust(this, core::mem::transmute_copy(&ppjavatrust)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCatalogFile: GetCatalogFile::<Identity>,
+            GetJavaTrust: GetJavaTrust::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICatalogFileInfo {}
 windows_core::imp::define_interface!(ICodeInstall, ICodeInstall_Vtbl, 0x79eac9d1_baf9_11ce_8c82_00aa004ba90b);
 impl core::ops::Deref for ICodeInstall {
@@ -1166,6 +1256,20 @@ impl ICodeInstall_Vtbl {
         iid == &<ICodeInstall as windows_core::Interface>::IID || iid == &<IWindowForBindingUI as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICodeInstall_Impl> windows_core::imp::TearOffVtable<Identity> for ICodeInstall_Vtbl
+where
+    IWindowForBindingUI_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnCodeInstallProblem<Identity: windows_core::IUnknownImpl + ICodeInstall_Impl>(this: *mut core::ffi::c_void, ulstatuscode: u32, szdestination: windows_core::PCWSTR, szsource: windows_core::PCWSTR, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICodeInstall_Impl::OnCodeInstallProblem(this, core::mem::transmute_copy(&ulstatuscode), core::mem::transmute(&szdestination), core::mem::transmute(&szsource), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        Self { base__: <IWindowForBindingUI_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnCodeInstallProblem: OnCodeInstallProblem::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ICodeInstall {}
 windows_core::imp::define_interface!(IDataFilter, IDataFilter_Vtbl, 0x69d14c80_c18e_11d0_a9ce_006097942311);
 windows_core::imp::interface_hierarchy!(IDataFilter, windows_core::IUnknown);
@@ -1224,6 +1328,37 @@ impl IDataFilter_Vtbl {
         iid == &<IDataFilter as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDataFilter_Impl> windows_core::imp::TearOffVtable<Identity> for IDataFilter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DoEncode<Identity: windows_core::IUnknownImpl + IDataFilter_Impl>(this: *mut core::ffi::c_void, dwflags: u32, linbuffersize: i32, pbinbuffer: *const u8, loutbuffersize: i32, pboutbuffer: *mut u8, linbytesavailable: i32, plinbytesread: *mut i32, ploutbyteswritten: *mut i32, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDataFilter_Impl::DoEncode(this, core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&linbuffersize), core::mem::transmute_copy(&pbinbuffer), core::mem::transmute_copy(&loutbuffersize), core::mem::transmute_copy(&pboutbuffer), core::mem::transmute_copy(&linbytesavailable), core::mem::transmute_copy(&plinbytesread), core::mem::transmute_copy(&ploutbyteswritten), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn DoDecode<Identity: windows_core::IUnknownImpl + IDataFilter_Impl>(this: *mut core::ffi::c_void, dwflags: u32, linbuffersize: i32, pbinbuffer: *const u8, loutbuffersize: i32, pboutbuffer: *mut u8, linbytesavailable: i32, plinbytesread: *mut i32, ploutbyteswritten: *mut i32, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDataFilter_Impl::DoDecode(this, core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&linbuffersize), core::mem::transmute_copy(&pbinbuffer), core::mem::transmute_copy(&loutbuffersize), core::mem::transmute_copy(&pboutbuffer), core::mem::transmute_copy(&linbytesavailable), core::mem::transmute_copy(&plinbytesread), core::mem::transmute_copy(&ploutbyteswritten), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn SetEncodingLevel<Identity: windows_core::IUnknownImpl + IDataFilter_Impl>(this: *mut core::ffi::c_void, dwenclevel: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDataFilter_Impl::SetEncodingLevel(this, core::mem::transmute_copy(&dwenclevel)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DoEncode: DoEncode::<Identity>,
+            DoDecode: DoDecode::<Identity>,
+            SetEncodingLevel: SetEncodingLevel::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDataFilter {}
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -1307,6 +1442,42 @@ impl IEncodingFilterFactory_Vtbl {
         iid == &<IEncodingFilterFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEncodingFilterFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IEncodingFilterFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FindBestFilter<Identity: windows_core::IUnknownImpl + IEncodingFilterFactory_Impl>(this: *mut core::ffi::c_void, pwzcodein: windows_core::PCWSTR, pwzcodeout: windows_core::PCWSTR, info: DATAINFO, ppdf: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEncodingFilterFactory_Impl::FindBestFilter(this, core::mem::transmute(&pwzcodein), core::mem::transmute(&pwzcodeout), core::mem::transmute(&info)) {
+                    Ok(ok__) => {
+                        ppdf.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDefaultFilter<Identity: windows_core::IUnknownImpl + IEncodingFilterFactory_Impl>(this: *mut core::ffi::c_void, pwzcodein: windows_core::PCWSTR, pwzcodeout: windows_core::PCWSTR, ppdf: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEncodingFilterFactory_Impl::GetDefaultFilter(this, core::mem::transmute(&pwzcodein), core::mem::transmute(&pwzcodeout)) {
+                    Ok(ok__) => {
+                        ppdf.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            FindBestFilter: FindBestFilter::<Identity>,
+            GetDefaultFilter: GetDefaultFilter::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEncodingFilterFactory {}
 windows_core::imp::define_interface!(IGetBindHandle, IGetBindHandle_Vtbl, 0xaf0ff408_129d_4b20_91f0_02bd23d88352);
 windows_core::imp::interface_hierarchy!(IGetBindHandle, windows_core::IUnknown);
@@ -1347,6 +1518,26 @@ impl IGetBindHandle_Vtbl {
         iid == &<IGetBindHandle as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IGetBindHandle_Impl> windows_core::imp::TearOffVtable<Identity> for IGetBindHandle_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetBindHandle<Identity: windows_core::IUnknownImpl + IGetBindHandle_Impl>(this: *mut core::ffi::c_void, enumrequestedhandle: BINDHANDLETYPES, prethandle: *mut super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IGetBindHandle_Impl::GetBindHandle(this, core::mem::transmute_copy(&enumrequestedhandle)) {
+                    Ok(ok__) => {
+                        prethandle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetBindHandle: GetBindHandle::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IGetBindHandle {}
 windows_core::imp::define_interface!(IHttpNegotiate, IHttpNegotiate_Vtbl, 0x79eac9d2_baf9_11ce_8c82_00aa004ba90b);
 windows_core::imp::interface_hierarchy!(IHttpNegotiate, windows_core::IUnknown);
@@ -1419,6 +1610,42 @@ impl IHttpNegotiate_Vtbl {
         iid == &<IHttpNegotiate as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IHttpNegotiate_Impl> windows_core::imp::TearOffVtable<Identity> for IHttpNegotiate_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BeginningTransaction<Identity: windows_core::IUnknownImpl + IHttpNegotiate_Impl>(this: *mut core::ffi::c_void, szurl: windows_core::PCWSTR, szheaders: windows_core::PCWSTR, dwreserved: u32, pszadditionalheaders: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHttpNegotiate_Impl::BeginningTransaction(this, core::mem::transmute(&szurl), core::mem::transmute(&szheaders), core::mem::transmute_copy(&dwreserved)) {
+                    Ok(ok__) => {
+                        pszadditionalheaders.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OnResponse<Identity: windows_core::IUnknownImpl + IHttpNegotiate_Impl>(this: *mut core::ffi::c_void, dwresponsecode: u32, szresponseheaders: windows_core::PCWSTR, szrequestheaders: windows_core::PCWSTR, pszadditionalrequestheaders: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IHttpNegotiate_Impl::OnResponse(this, core::mem::transmute_copy(&dwresponsecode), core::mem::transmute(&szresponseheaders), core::mem::transmute(&szrequestheaders)) {
+                    Ok(ok__) => {
+                        pszadditionalrequestheaders.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            BeginningTransaction: BeginningTransaction::<Identity>,
+            OnResponse: OnResponse::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IHttpNegotiate {}
 windows_core::imp::define_interface!(IHttpNegotiate2, IHttpNegotiate2_Vtbl, 0x4f9f9fcb_e0f4_48eb_b7ab_fa2ea9365cb4);
 impl core::ops::Deref for IHttpNegotiate2 {
@@ -1456,6 +1683,20 @@ impl IHttpNegotiate2_Vtbl {
         iid == &<IHttpNegotiate2 as windows_core::Interface>::IID || iid == &<IHttpNegotiate as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IHttpNegotiate2_Impl> windows_core::imp::TearOffVtable<Identity> for IHttpNegotiate2_Vtbl
+where
+    IHttpNegotiate_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRootSecurityId<Identity: windows_core::IUnknownImpl + IHttpNegotiate2_Impl>(this: *mut core::ffi::c_void, pbsecurityid: *mut u8, pcbsecurityid: *mut u32, dwreserved: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHttpNegotiate2_Impl::GetRootSecurityId(this, core::mem::transmute_copy(&pbsecurityid), core::mem::transmute_copy(&pcbsecurityid), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        Self { base__: <IHttpNegotiate_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetRootSecurityId: GetRootSecurityId::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IHttpNegotiate2 {}
 windows_core::imp::define_interface!(IHttpNegotiate3, IHttpNegotiate3_Vtbl, 0x57b6c80a_34c2_4602_bc26_66a02fc57153);
 impl core::ops::Deref for IHttpNegotiate3 {
@@ -1493,6 +1734,23 @@ impl IHttpNegotiate3_Vtbl {
         iid == &<IHttpNegotiate3 as windows_core::Interface>::IID || iid == &<IHttpNegotiate as windows_core::Interface>::IID || iid == &<IHttpNegotiate2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IHttpNegotiate3_Impl> windows_core::imp::TearOffVtable<Identity> for IHttpNegotiate3_Vtbl
+where
+    IHttpNegotiate2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSerializedClientCertContext<Identity: windows_core::IUnknownImpl + IHttpNegotiate3_Impl>(this: *mut core::ffi::c_void, ppbcert: *mut *mut u8, pcbcert: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHttpNegotiate3_Impl::GetSerializedClientCertContext(this, core::mem::transmute_copy(&ppbcert), core::mem::transmute_copy(&pcbcert)).into()
+            }
+        }
+        Self {
+            base__: <IHttpNegotiate2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSerializedClientCertContext: GetSerializedClientCertContext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IHttpNegotiate3 {}
 windows_core::imp::define_interface!(IHttpSecurity, IHttpSecurity_Vtbl, 0x79eac9d7_bafa_11ce_8c82_00aa004ba90b);
 impl core::ops::Deref for IHttpSecurity {
@@ -1530,6 +1788,20 @@ impl IHttpSecurity_Vtbl {
         iid == &<IHttpSecurity as windows_core::Interface>::IID || iid == &<IWindowForBindingUI as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IHttpSecurity_Impl> windows_core::imp::TearOffVtable<Identity> for IHttpSecurity_Vtbl
+where
+    IWindowForBindingUI_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnSecurityProblem<Identity: windows_core::IUnknownImpl + IHttpSecurity_Impl>(this: *mut core::ffi::c_void, dwproblem: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IHttpSecurity_Impl::OnSecurityProblem(this, core::mem::transmute_copy(&dwproblem)).into()
+            }
+        }
+        Self { base__: <IWindowForBindingUI_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnSecurityProblem: OnSecurityProblem::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IHttpSecurity {}
 windows_core::imp::define_interface!(IInternet, IInternet_Vtbl, 0x79eac9e0_baf9_11ce_8c82_00aa004ba90b);
 windows_core::imp::interface_hierarchy!(IInternet, windows_core::IUnknown);
@@ -1547,6 +1819,12 @@ impl IInternet_Vtbl {
         iid == &<IInternet as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInternet_Impl> windows_core::imp::TearOffVtable<Identity> for IInternet_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IInternet {}
 windows_core::imp::define_interface!(IInternetBindInfo, IInternetBindInfo_Vtbl, 0x79eac9e1_baf9_11ce_8c82_00aa004ba90b);
 windows_core::imp::interface_hierarchy!(IInternetBindInfo, windows_core::IUnknown);
@@ -1600,6 +1878,31 @@ impl IInternetBindInfo_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage"))]
+impl<Identity: windows_core::IUnknownImpl + IInternetBindInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IInternetBindInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetBindInfo<Identity: windows_core::IUnknownImpl + IInternetBindInfo_Impl>(this: *mut core::ffi::c_void, grfbindf: *mut u32, pbindinfo: *mut super::BINDINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetBindInfo_Impl::GetBindInfo(this, core::mem::transmute_copy(&grfbindf), core::mem::transmute_copy(&pbindinfo)).into()
+            }
+        }
+        unsafe extern "system" fn GetBindString<Identity: windows_core::IUnknownImpl + IInternetBindInfo_Impl>(this: *mut core::ffi::c_void, ulstringtype: u32, ppwzstr: *mut windows_core::PWSTR, cel: u32, pcelfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetBindInfo_Impl::GetBindString(this, core::mem::transmute_copy(&ulstringtype), core::mem::transmute_copy(&ppwzstr), core::mem::transmute_copy(&cel), core::mem::transmute_copy(&pcelfetched)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetBindInfo: GetBindInfo::<Identity>,
+            GetBindString: GetBindString::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage"))]
 impl windows_core::RuntimeName for IInternetBindInfo {}
 windows_core::imp::define_interface!(IInternetBindInfoEx, IInternetBindInfoEx_Vtbl, 0xa3e015b7_a82c_4dcd_a150_569aeeed36ab);
 impl core::ops::Deref for IInternetBindInfoEx {
@@ -1644,6 +1947,21 @@ impl IInternetBindInfoEx_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage"))]
+impl<Identity: windows_core::IUnknownImpl + IInternetBindInfoEx_Impl> windows_core::imp::TearOffVtable<Identity> for IInternetBindInfoEx_Vtbl
+where
+    IInternetBindInfo_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetBindInfoEx<Identity: windows_core::IUnknownImpl + IInternetBindInfoEx_Impl>(this: *mut core::ffi::c_void, grfbindf: *mut u32, pbindinfo: *mut super::BINDINFO, grfbindf2: *mut u32, pdwreserved: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetBindInfoEx_Impl::GetBindInfoEx(this, core::mem::transmute_copy(&grfbindf), core::mem::transmute_copy(&pbindinfo), core::mem::transmute_copy(&grfbindf2), core::mem::transmute_copy(&pdwreserved)).into()
+            }
+        }
+        Self { base__: <IInternetBindInfo_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetBindInfoEx: GetBindInfoEx::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage"))]
 impl windows_core::RuntimeName for IInternetBindInfoEx {}
 windows_core::imp::define_interface!(IInternetHostSecurityManager, IInternetHostSecurityManager_Vtbl, 0x3af280b6_cb3f_11d0_891e_00c04fb6bfc4);
 windows_core::imp::interface_hierarchy!(IInternetHostSecurityManager, windows_core::IUnknown);
@@ -1702,6 +2020,37 @@ impl IInternetHostSecurityManager_Vtbl {
         iid == &<IInternetHostSecurityManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInternetHostSecurityManager_Impl> windows_core::imp::TearOffVtable<Identity> for IInternetHostSecurityManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSecurityId<Identity: windows_core::IUnknownImpl + IInternetHostSecurityManager_Impl>(this: *mut core::ffi::c_void, pbsecurityid: *mut u8, pcbsecurityid: *mut u32, dwreserved: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetHostSecurityManager_Impl::GetSecurityId(this, core::mem::transmute_copy(&pbsecurityid), core::mem::transmute_copy(&pcbsecurityid), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn ProcessUrlAction<Identity: windows_core::IUnknownImpl + IInternetHostSecurityManager_Impl>(this: *mut core::ffi::c_void, dwaction: u32, ppolicy: *mut u8, cbpolicy: u32, pcontext: *const u8, cbcontext: u32, dwflags: u32, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetHostSecurityManager_Impl::ProcessUrlAction(this, core::mem::transmute_copy(&dwaction), core::mem::transmute_copy(&ppolicy), core::mem::transmute_copy(&cbpolicy), core::mem::transmute_copy(&pcontext), core::mem::transmute_copy(&cbcontext), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn QueryCustomPolicy<Identity: windows_core::IUnknownImpl + IInternetHostSecurityManager_Impl>(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, pppolicy: *mut *mut u8, pcbpolicy: *mut u32, pcontext: *const u8, cbcontext: u32, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetHostSecurityManager_Impl::QueryCustomPolicy(this, core::mem::transmute_copy(&guidkey), core::mem::transmute_copy(&pppolicy), core::mem::transmute_copy(&pcbpolicy), core::mem::transmute_copy(&pcontext), core::mem::transmute_copy(&cbcontext), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSecurityId: GetSecurityId::<Identity>,
+            ProcessUrlAction: ProcessUrlAction::<Identity>,
+            QueryCustomPolicy: QueryCustomPolicy::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IInternetHostSecurityManager {}
 windows_core::imp::define_interface!(IInternetPriority, IInternetPriority_Vtbl, 0x79eac9eb_baf9_11ce_8c82_00aa004ba90b);
 windows_core::imp::interface_hierarchy!(IInternetPriority, windows_core::IUnknown);
@@ -1757,6 +2106,36 @@ impl IInternetPriority_Vtbl {
         iid == &<IInternetPriority as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInternetPriority_Impl> windows_core::imp::TearOffVtable<Identity> for IInternetPriority_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetPriority<Identity: windows_core::IUnknownImpl + IInternetPriority_Impl>(this: *mut core::ffi::c_void, npriority: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetPriority_Impl::SetPriority(this, core::mem::transmute_copy(&npriority)).into()
+            }
+        }
+        unsafe extern "system" fn GetPriority<Identity: windows_core::IUnknownImpl + IInternetPriority_Impl>(this: *mut core::ffi::c_void, pnpriority: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInternetPriority_Impl::GetPriority(this) {
+                    Ok(ok__) => {
+                        pnpriority.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetPriority: SetPriority::<Identity>,
+            GetPriority: GetPriority::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IInternetPriority {}
 windows_core::imp::define_interface!(IInternetProtocol, IInternetProtocol_Vtbl, 0x79eac9e4_baf9_11ce_8c82_00aa004ba90b);
 impl core::ops::Deref for IInternetProtocol {
@@ -1842,6 +2221,50 @@ impl IInternetProtocol_Vtbl {
         iid == &<IInternetProtocol as windows_core::Interface>::IID || iid == &<IInternetProtocolRoot as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInternetProtocol_Impl> windows_core::imp::TearOffVtable<Identity> for IInternetProtocol_Vtbl
+where
+    IInternetProtocolRoot_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Read<Identity: windows_core::IUnknownImpl + IInternetProtocol_Impl>(this: *mut core::ffi::c_void, pv: *mut core::ffi::c_void, cb: u32, pcbread: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetProtocol_Impl::Read(this, core::mem::transmute_copy(&pv), core::mem::transmute_copy(&cb), core::mem::transmute_copy(&pcbread)).into()
+            }
+        }
+        unsafe extern "system" fn Seek<Identity: windows_core::IUnknownImpl + IInternetProtocol_Impl>(this: *mut core::ffi::c_void, dlibmove: i64, dworigin: u32, plibnewposition: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInternetProtocol_Impl::Seek(this, core::mem::transmute_copy(&dlibmove), core::mem::transmute_copy(&dworigin)) {
+                    Ok(ok__) => {
+                        plibnewposition.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LockRequest<Identity: windows_core::IUnknownImpl + IInternetProtocol_Impl>(this: *mut core::ffi::c_void, dwoptions: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetProtocol_Impl::LockRequest(this, core::mem::transmute_copy(&dwoptions)).into()
+            }
+        }
+        unsafe extern "system" fn UnlockRequest<Identity: windows_core::IUnknownImpl + IInternetProtocol_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetProtocol_Impl::UnlockRequest(this).into()
+            }
+        }
+        Self {
+            base__: <IInternetProtocolRoot_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Read: Read::<Identity>,
+            Seek: Seek::<Identity>,
+            LockRequest: LockRequest::<Identity>,
+            UnlockRequest: UnlockRequest::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IInternetProtocol {}
 windows_core::imp::define_interface!(IInternetProtocolEx, IInternetProtocolEx_Vtbl, 0xc7a98e66_1010_492c_a1c8_c809e1f75905);
 impl core::ops::Deref for IInternetProtocolEx {
@@ -1884,6 +2307,20 @@ impl IInternetProtocolEx_Vtbl {
         iid == &<IInternetProtocolEx as windows_core::Interface>::IID || iid == &<IInternetProtocolRoot as windows_core::Interface>::IID || iid == &<IInternetProtocol as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInternetProtocolEx_Impl> windows_core::imp::TearOffVtable<Identity> for IInternetProtocolEx_Vtbl
+where
+    IInternetProtocol_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StartEx<Identity: windows_core::IUnknownImpl + IInternetProtocolEx_Impl>(this: *mut core::ffi::c_void, puri: *mut core::ffi::c_void, poiprotsink: *mut core::ffi::c_void, poibindinfo: *mut core::ffi::c_void, grfpi: u32, dwreserved: super::super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetProtocolEx_Impl::StartEx(this, core::mem::transmute_copy(&puri), core::mem::transmute_copy(&poiprotsink), core::mem::transmute_copy(&poibindinfo), core::mem::transmute_copy(&grfpi), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        Self { base__: <IInternetProtocol_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, StartEx: StartEx::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IInternetProtocolEx {}
 windows_core::imp::define_interface!(IInternetProtocolInfo, IInternetProtocolInfo_Vtbl, 0x79eac9ec_baf9_11ce_8c82_00aa004ba90b);
 windows_core::imp::interface_hierarchy!(IInternetProtocolInfo, windows_core::IUnknown);
@@ -1969,6 +2406,44 @@ impl IInternetProtocolInfo_Vtbl {
         iid == &<IInternetProtocolInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInternetProtocolInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IInternetProtocolInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ParseUrl<Identity: windows_core::IUnknownImpl + IInternetProtocolInfo_Impl>(this: *mut core::ffi::c_void, pwzurl: windows_core::PCWSTR, parseaction: PARSEACTION, dwparseflags: u32, pwzresult: windows_core::PWSTR, cchresult: u32, pcchresult: *mut u32, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetProtocolInfo_Impl::ParseUrl(this, core::mem::transmute(&pwzurl), core::mem::transmute_copy(&parseaction), core::mem::transmute_copy(&dwparseflags), core::mem::transmute_copy(&pwzresult), core::mem::transmute_copy(&cchresult), core::mem::transmute_copy(&pcchresult), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn CombineUrl<Identity: windows_core::IUnknownImpl + IInternetProtocolInfo_Impl>(this: *mut core::ffi::c_void, pwzbaseurl: windows_core::PCWSTR, pwzrelativeurl: windows_core::PCWSTR, dwcombineflags: u32, pwzresult: windows_core::PCWSTR, cchresult: u32, pcchresult: *mut u32, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetProtocolInfo_Impl::CombineUrl(this, core::mem::transmute(&pwzbaseurl), core::mem::transmute(&pwzrelativeurl), core::mem::transmute_copy(&dwcombineflags), core::mem::transmute(&pwzresult), core::mem::transmute_copy(&cchresult), core::mem::transmute_copy(&pcchresult), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn CompareUrl<Identity: windows_core::IUnknownImpl + IInternetProtocolInfo_Impl>(this: *mut core::ffi::c_void, pwzurl1: windows_core::PCWSTR, pwzurl2: windows_core::PCWSTR, dwcompareflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetProtocolInfo_Impl::CompareUrl(this, core::mem::transmute(&pwzurl1), core::mem::transmute(&pwzurl2), core::mem::transmute_copy(&dwcompareflags)).into()
+            }
+        }
+        unsafe extern "system" fn QueryInfo<Identity: windows_core::IUnknownImpl + IInternetProtocolInfo_Impl>(this: *mut core::ffi::c_void, pwzurl: windows_core::PCWSTR, oueryoption: QUERYOPTION, dwqueryflags: u32, pbuffer: *mut core::ffi::c_void, cbbuffer: u32, pcbbuf: *mut u32, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetProtocolInfo_Impl::QueryInfo(this, core::mem::transmute(&pwzurl), core::mem::transmute_copy(&oueryoption), core::mem::transmute_copy(&dwqueryflags), core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&cbbuffer), core::mem::transmute_copy(&pcbbuf), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ParseUrl: ParseUrl::<Identity>,
+            CombineUrl: CombineUrl::<Identity>,
+            CompareUrl: CompareUrl::<Identity>,
+            QueryInfo: QueryInfo::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IInternetProtocolInfo {}
 windows_core::imp::define_interface!(IInternetProtocolRoot, IInternetProtocolRoot_Vtbl, 0x79eac9e3_baf9_11ce_8c82_00aa004ba90b);
 windows_core::imp::interface_hierarchy!(IInternetProtocolRoot, windows_core::IUnknown);
@@ -2068,6 +2543,58 @@ impl IInternetProtocolRoot_Vtbl {
         iid == &<IInternetProtocolRoot as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInternetProtocolRoot_Impl> windows_core::imp::TearOffVtable<Identity> for IInternetProtocolRoot_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Start<Identity: windows_core::IUnknownImpl + IInternetProtocolRoot_Impl>(this: *mut core::ffi::c_void, szurl: windows_core::PCWSTR, poiprotsink: *mut core::ffi::c_void, poibindinfo: *mut core::ffi::c_void, grfpi: u32, dwreserved: super::super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetProtocolRoot_Impl::Start(this, core::mem::transmute(&szurl), core::mem::transmute_copy(&poiprotsink), core::mem::transmute_copy(&poibindinfo), core::mem::transmute_copy(&grfpi), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn Continue<Identity: windows_core::IUnknownImpl + IInternetProtocolRoot_Impl>(this: *mut core::ffi::c_void, pprotocoldata: *const PROTOCOLDATA) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetProtocolRoot_Impl::Continue(this, core::mem::transmute_copy(&pprotocoldata)).into()
+            }
+        }
+        unsafe extern "system" fn Abort<Identity: windows_core::IUnknownImpl + IInternetProtocolRoot_Impl>(this: *mut core::ffi::c_void, hrreason: windows_core::HRESULT, dwoptions: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetProtocolRoot_Impl::Abort(this, core::mem::transmute_copy(&hrreason), core::mem::transmute_copy(&dwoptions)).into()
+            }
+        }
+        unsafe extern "system" fn Terminate<Identity: windows_core::IUnknownImpl + IInternetProtocolRoot_Impl>(this: *mut core::ffi::c_void, dwoptions: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetProtocolRoot_Impl::Terminate(this, core::mem::transmute_copy(&dwoptions)).into()
+            }
+        }
+        unsafe extern "system" fn Suspend<Identity: windows_core::IUnknownImpl + IInternetProtocolRoot_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetProtocolRoot_Impl::Suspend(this).into()
+            }
+        }
+        unsafe extern "system" fn Resume<Identity: windows_core::IUnknownImpl + IInternetProtocolRoot_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetProtocolRoot_Impl::Resume(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Start: Start::<Identity>,
+            Continue: Continue::<Identity>,
+            Abort: Abort::<Identity>,
+            Terminate: Terminate::<Identity>,
+            Suspend: Suspend::<Identity>,
+            Resume: Resume::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IInternetProtocolRoot {}
 windows_core::imp::define_interface!(IInternetProtocolSink, IInternetProtocolSink_Vtbl, 0x79eac9e5_baf9_11ce_8c82_00aa004ba90b);
 windows_core::imp::interface_hierarchy!(IInternetProtocolSink, windows_core::IUnknown);
@@ -2144,6 +2671,44 @@ impl IInternetProtocolSink_Vtbl {
         iid == &<IInternetProtocolSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInternetProtocolSink_Impl> windows_core::imp::TearOffVtable<Identity> for IInternetProtocolSink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Switch<Identity: windows_core::IUnknownImpl + IInternetProtocolSink_Impl>(this: *mut core::ffi::c_void, pprotocoldata: *const PROTOCOLDATA) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetProtocolSink_Impl::Switch(this, core::mem::transmute_copy(&pprotocoldata)).into()
+            }
+        }
+        unsafe extern "system" fn ReportProgress<Identity: windows_core::IUnknownImpl + IInternetProtocolSink_Impl>(this: *mut core::ffi::c_void, ulstatuscode: u32, szstatustext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetProtocolSink_Impl::ReportProgress(this, core::mem::transmute_copy(&ulstatuscode), core::mem::transmute(&szstatustext)).into()
+            }
+        }
+        unsafe extern "system" fn ReportData<Identity: windows_core::IUnknownImpl + IInternetProtocolSink_Impl>(this: *mut core::ffi::c_void, grfbscf: u32, ulprogress: u32, ulprogressmax: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetProtocolSink_Impl::ReportData(this, core::mem::transmute_copy(&grfbscf), core::mem::transmute_copy(&ulprogress), core::mem::transmute_copy(&ulprogressmax)).into()
+            }
+        }
+        unsafe extern "system" fn ReportResult<Identity: windows_core::IUnknownImpl + IInternetProtocolSink_Impl>(this: *mut core::ffi::c_void, hrresult: windows_core::HRESULT, dwerror: u32, szresult: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetProtocolSink_Impl::ReportResult(this, core::mem::transmute_copy(&hrresult), core::mem::transmute_copy(&dwerror), core::mem::transmute(&szresult)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Switch: Switch::<Identity>,
+            ReportProgress: ReportProgress::<Identity>,
+            ReportData: ReportData::<Identity>,
+            ReportResult: ReportResult::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IInternetProtocolSink {}
 windows_core::imp::define_interface!(IInternetProtocolSinkStackable, IInternetProtocolSinkStackable_Vtbl, 0x79eac9f0_baf9_11ce_8c82_00aa004ba90b);
 windows_core::imp::interface_hierarchy!(IInternetProtocolSinkStackable, windows_core::IUnknown);
@@ -2205,6 +2770,37 @@ impl IInternetProtocolSinkStackable_Vtbl {
         iid == &<IInternetProtocolSinkStackable as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInternetProtocolSinkStackable_Impl> windows_core::imp::TearOffVtable<Identity> for IInternetProtocolSinkStackable_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SwitchSink<Identity: windows_core::IUnknownImpl + IInternetProtocolSinkStackable_Impl>(this: *mut core::ffi::c_void, poiprotsink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetProtocolSinkStackable_Impl::SwitchSink(this, core::mem::transmute_copy(&poiprotsink)).into()
+            }
+        }
+        unsafe extern "system" fn CommitSwitch<Identity: windows_core::IUnknownImpl + IInternetProtocolSinkStackable_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetProtocolSinkStackable_Impl::CommitSwitch(this).into()
+            }
+        }
+        unsafe extern "system" fn RollbackSwitch<Identity: windows_core::IUnknownImpl + IInternetProtocolSinkStackable_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetProtocolSinkStackable_Impl::RollbackSwitch(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SwitchSink: SwitchSink::<Identity>,
+            CommitSwitch: CommitSwitch::<Identity>,
+            RollbackSwitch: RollbackSwitch::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IInternetProtocolSinkStackable {}
 windows_core::imp::define_interface!(IInternetSecurityManager, IInternetSecurityManager_Vtbl, 0x79eac9ee_baf9_11ce_8c82_00aa004ba90b);
 windows_core::imp::interface_hierarchy!(IInternetSecurityManager, windows_core::IUnknown);
@@ -2350,6 +2946,78 @@ impl IInternetSecurityManager_Vtbl {
         iid == &<IInternetSecurityManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInternetSecurityManager_Impl> windows_core::imp::TearOffVtable<Identity> for IInternetSecurityManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetSecuritySite<Identity: windows_core::IUnknownImpl + IInternetSecurityManager_Impl>(this: *mut core::ffi::c_void, psite: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetSecurityManager_Impl::SetSecuritySite(this, core::mem::transmute_copy(&psite)).into()
+            }
+        }
+        unsafe extern "system" fn GetSecuritySite<Identity: windows_core::IUnknownImpl + IInternetSecurityManager_Impl>(this: *mut core::ffi::c_void, ppsite: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInternetSecurityManager_Impl::GetSecuritySite(this) {
+                    Ok(ok__) => {
+                        ppsite.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MapUrlToZone<Identity: windows_core::IUnknownImpl + IInternetSecurityManager_Impl>(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, pdwzone: *mut u32, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetSecurityManager_Impl::MapUrlToZone(this, core::mem::transmute(&pwszurl), core::mem::transmute_copy(&pdwzone), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn GetSecurityId<Identity: windows_core::IUnknownImpl + IInternetSecurityManager_Impl>(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, pbsecurityid: *mut u8, pcbsecurityid: *mut u32, dwreserved: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetSecurityManager_Impl::GetSecurityId(this, core::mem::transmute(&pwszurl), core::mem::transmute_copy(&pbsecurityid), core::mem::transmute_copy(&pcbsecurityid), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn ProcessUrlAction<Identity: windows_core::IUnknownImpl + IInternetSecurityManager_Impl>(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, dwaction: u32, ppolicy: *mut u8, cbpolicy: u32, pcontext: *const u8, cbcontext: u32, dwflags: u32, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetSecurityManager_Impl::ProcessUrlAction(this, core::mem::transmute(&pwszurl), core::mem::transmute_copy(&dwaction), core::mem::transmute_copy(&ppolicy), core::mem::transmute_copy(&cbpolicy), core::mem::transmute_copy(&pcontext), core::mem::transmute_copy(&cbcontext), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn QueryCustomPolicy<Identity: windows_core::IUnknownImpl + IInternetSecurityManager_Impl>(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, guidkey: *const windows_core::GUID, pppolicy: *mut *mut u8, pcbpolicy: *mut u32, pcontext: *const u8, cbcontext: u32, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetSecurityManager_Impl::QueryCustomPolicy(this, core::mem::transmute(&pwszurl), core::mem::transmute_copy(&guidkey), core::mem::transmute_copy(&pppolicy), core::mem::transmute_copy(&pcbpolicy), core::mem::transmute_copy(&pcontext), core::mem::transmute_copy(&cbcontext), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn SetZoneMapping<Identity: windows_core::IUnknownImpl + IInternetSecurityManager_Impl>(this: *mut core::ffi::c_void, dwzone: u32, lpszpattern: windows_core::PCWSTR, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetSecurityManager_Impl::SetZoneMapping(this, core::mem::transmute_copy(&dwzone), core::mem::transmute(&lpszpattern), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn GetZoneMappings<Identity: windows_core::IUnknownImpl + IInternetSecurityManager_Impl>(this: *mut core::ffi::c_void, dwzone: u32, ppenumstring: *mut *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetSecurityManager_Impl::GetZoneMappings(this, core::mem::transmute_copy(&dwzone), core::mem::transmute_copy(&ppenumstring), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetSecuritySite: SetSecuritySite::<Identity>,
+            GetSecuritySite: GetSecuritySite::<Identity>,
+            MapUrlToZone: MapUrlToZone::<Identity>,
+            GetSecurityId: GetSecurityId::<Identity>,
+            ProcessUrlAction: ProcessUrlAction::<Identity>,
+            QueryCustomPolicy: QueryCustomPolicy::<Identity>,
+            SetZoneMapping: SetZoneMapping::<Identity>,
+            GetZoneMappings: GetZoneMappings::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IInternetSecurityManager {}
 windows_core::imp::define_interface!(IInternetSecurityManagerEx, IInternetSecurityManagerEx_Vtbl, 0xf164edf1_cc7c_4f0d_9a94_34222625c393);
 impl core::ops::Deref for IInternetSecurityManagerEx {
@@ -2390,6 +3058,23 @@ impl IInternetSecurityManagerEx_Vtbl {
         iid == &<IInternetSecurityManagerEx as windows_core::Interface>::IID || iid == &<IInternetSecurityManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInternetSecurityManagerEx_Impl> windows_core::imp::TearOffVtable<Identity> for IInternetSecurityManagerEx_Vtbl
+where
+    IInternetSecurityManager_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ProcessUrlActionEx<Identity: windows_core::IUnknownImpl + IInternetSecurityManagerEx_Impl>(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, dwaction: u32, ppolicy: *mut u8, cbpolicy: u32, pcontext: *const u8, cbcontext: u32, dwflags: u32, dwreserved: u32, pdwoutflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetSecurityManagerEx_Impl::ProcessUrlActionEx(this, core::mem::transmute(&pwszurl), core::mem::transmute_copy(&dwaction), core::mem::transmute_copy(&ppolicy), core::mem::transmute_copy(&cbpolicy), core::mem::transmute_copy(&pcontext), core::mem::transmute_copy(&cbcontext), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&dwreserved), core::mem::transmute_copy(&pdwoutflags)).into()
+            }
+        }
+        Self {
+            base__: <IInternetSecurityManager_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ProcessUrlActionEx: ProcessUrlActionEx::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IInternetSecurityManagerEx {}
 windows_core::imp::define_interface!(IInternetSecurityManagerEx2, IInternetSecurityManagerEx2_Vtbl, 0xf1e50292_a795_4117_8e09_2b560a72ac60);
 impl core::ops::Deref for IInternetSecurityManagerEx2 {
@@ -2478,6 +3163,44 @@ impl IInternetSecurityManagerEx2_Vtbl {
         iid == &<IInternetSecurityManagerEx2 as windows_core::Interface>::IID || iid == &<IInternetSecurityManager as windows_core::Interface>::IID || iid == &<IInternetSecurityManagerEx as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInternetSecurityManagerEx2_Impl> windows_core::imp::TearOffVtable<Identity> for IInternetSecurityManagerEx2_Vtbl
+where
+    IInternetSecurityManagerEx_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn MapUrlToZoneEx2<Identity: windows_core::IUnknownImpl + IInternetSecurityManagerEx2_Impl>(this: *mut core::ffi::c_void, puri: *mut core::ffi::c_void, pdwzone: *mut u32, dwflags: u32, ppwszmappedurl: *mut windows_core::PWSTR, pdwoutflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetSecurityManagerEx2_Impl::MapUrlToZoneEx2(this, core::mem::transmute_copy(&puri), core::mem::transmute_copy(&pdwzone), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&ppwszmappedurl), core::mem::transmute_copy(&pdwoutflags)).into()
+            }
+        }
+        unsafe extern "system" fn ProcessUrlActionEx2<Identity: windows_core::IUnknownImpl + IInternetSecurityManagerEx2_Impl>(this: *mut core::ffi::c_void, puri: *mut core::ffi::c_void, dwaction: u32, ppolicy: *mut u8, cbpolicy: u32, pcontext: *const u8, cbcontext: u32, dwflags: u32, dwreserved: usize, pdwoutflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetSecurityManagerEx2_Impl::ProcessUrlActionEx2(this, core::mem::transmute_copy(&puri), core::mem::transmute_copy(&dwaction), core::mem::transmute_copy(&ppolicy), core::mem::transmute_copy(&cbpolicy), core::mem::transmute_copy(&pcontext), core::mem::transmute_copy(&cbcontext), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&dwreserved), core::mem::transmute_copy(&pdwoutflags)).into()
+            }
+        }
+        unsafe extern "system" fn GetSecurityIdEx2<Identity: windows_core::IUnknownImpl + IInternetSecurityManagerEx2_Impl>(this: *mut core::ffi::c_void, puri: *mut core::ffi::c_void, pbsecurityid: *mut u8, pcbsecurityid: *mut u32, dwreserved: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetSecurityManagerEx2_Impl::GetSecurityIdEx2(this, core::mem::transmute_copy(&puri), core::mem::transmute_copy(&pbsecurityid), core::mem::transmute_copy(&pcbsecurityid), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn QueryCustomPolicyEx2<Identity: windows_core::IUnknownImpl + IInternetSecurityManagerEx2_Impl>(this: *mut core::ffi::c_void, puri: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, pppolicy: *mut *mut u8, pcbpolicy: *mut u32, pcontext: *const u8, cbcontext: u32, dwreserved: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetSecurityManagerEx2_Impl::QueryCustomPolicyEx2(this, core::mem::transmute_copy(&puri), core::mem::transmute_copy(&guidkey), core::mem::transmute_copy(&pppolicy), core::mem::transmute_copy(&pcbpolicy), core::mem::transmute_copy(&pcontext), core::mem::transmute_copy(&cbcontext), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        Self {
+            base__: <IInternetSecurityManagerEx_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MapUrlToZoneEx2: MapUrlToZoneEx2::<Identity>,
+            ProcessUrlActionEx2: ProcessUrlActionEx2::<Identity>,
+            GetSecurityIdEx2: GetSecurityIdEx2::<Identity>,
+            QueryCustomPolicyEx2: QueryCustomPolicyEx2::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IInternetSecurityManagerEx2 {}
 windows_core::imp::define_interface!(IInternetSecurityMgrSite, IInternetSecurityMgrSite_Vtbl, 0x79eac9ed_baf9_11ce_8c82_00aa004ba90b);
 windows_core::imp::interface_hierarchy!(IInternetSecurityMgrSite, windows_core::IUnknown);
@@ -2533,6 +3256,36 @@ impl IInternetSecurityMgrSite_Vtbl {
         iid == &<IInternetSecurityMgrSite as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInternetSecurityMgrSite_Impl> windows_core::imp::TearOffVtable<Identity> for IInternetSecurityMgrSite_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetWindow<Identity: windows_core::IUnknownImpl + IInternetSecurityMgrSite_Impl>(this: *mut core::ffi::c_void, phwnd: *mut super::super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInternetSecurityMgrSite_Impl::GetWindow(this) {
+                    Ok(ok__) => {
+                        phwnd.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnableModeless<Identity: windows_core::IUnknownImpl + IInternetSecurityMgrSite_Impl>(this: *mut core::ffi::c_void, fenable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetSecurityMgrSite_Impl::EnableModeless(this, core::mem::transmute_copy(&fenable)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetWindow: GetWindow::<Identity>,
+            EnableModeless: EnableModeless::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IInternetSecurityMgrSite {}
 windows_core::imp::define_interface!(IInternetSession, IInternetSession_Vtbl, 0x79eac9e7_baf9_11ce_8c82_00aa004ba90b);
 windows_core::imp::interface_hierarchy!(IInternetSession, windows_core::IUnknown);
@@ -2660,6 +3413,65 @@ impl IInternetSession_Vtbl {
         iid == &<IInternetSession as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInternetSession_Impl> windows_core::imp::TearOffVtable<Identity> for IInternetSession_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterNameSpace<Identity: windows_core::IUnknownImpl + IInternetSession_Impl>(this: *mut core::ffi::c_void, pcf: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, pwzprotocol: windows_core::PCWSTR, cpatterns: u32, ppwzpatterns: *const windows_core::PCWSTR, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetSession_Impl::RegisterNameSpace(this, core::mem::transmute_copy(&pcf), core::mem::transmute_copy(&rclsid), core::mem::transmute(&pwzprotocol), core::mem::transmute_copy(&cpatterns), core::mem::transmute_copy(&ppwzpatterns), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn UnregisterNameSpace<Identity: windows_core::IUnknownImpl + IInternetSession_Impl>(this: *mut core::ffi::c_void, pcf: *mut core::ffi::c_void, pszprotocol: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetSession_Impl::UnregisterNameSpace(this, core::mem::transmute_copy(&pcf), core::mem::transmute(&pszprotocol)).into()
+            }
+        }
+        unsafe extern "system" fn RegisterMimeFilter<Identity: windows_core::IUnknownImpl + IInternetSession_Impl>(this: *mut core::ffi::c_void, pcf: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, pwztype: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetSession_Impl::RegisterMimeFilter(this, core::mem::transmute_copy(&pcf), core::mem::transmute_copy(&rclsid), core::mem::transmute(&pwztype)).into()
+            }
+        }
+        unsafe extern "system" fn UnregisterMimeFilter<Identity: windows_core::IUnknownImpl + IInternetSession_Impl>(this: *mut core::ffi::c_void, pcf: *mut core::ffi::c_void, pwztype: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetSession_Impl::UnregisterMimeFilter(this, core::mem::transmute_copy(&pcf), core::mem::transmute(&pwztype)).into()
+            }
+        }
+        unsafe extern "system" fn CreateBinding<Identity: windows_core::IUnknownImpl + IInternetSession_Impl>(this: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void, szurl: windows_core::PCWSTR, punkouter: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void, ppoinetprot: *mut *mut core::ffi::c_void, dwoption: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetSession_Impl::CreateBinding(this, core::mem::transmute_copy(&pbc), core::mem::transmute(&szurl), core::mem::transmute_copy(&punkouter), core::mem::transmute_copy(&ppunk), core::mem::transmute_copy(&ppoinetprot), core::mem::transmute_copy(&dwoption)).into()
+            }
+        }
+        unsafe extern "system" fn SetSessionOption<Identity: windows_core::IUnknownImpl + IInternetSession_Impl>(this: *mut core::ffi::c_void, dwoption: u32, pbuffer: *const core::ffi::c_void, dwbufferlength: u32, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetSession_Impl::SetSessionOption(this, core::mem::transmute_copy(&dwoption), core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&dwbufferlength), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn GetSessionOption<Identity: windows_core::IUnknownImpl + IInternetSession_Impl>(this: *mut core::ffi::c_void, dwoption: u32, pbuffer: *mut core::ffi::c_void, pdwbufferlength: *mut u32, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetSession_Impl::GetSessionOption(this, core::mem::transmute_copy(&dwoption), core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&pdwbufferlength), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterNameSpace: RegisterNameSpace::<Identity>,
+            UnregisterNameSpace: UnregisterNameSpace::<Identity>,
+            RegisterMimeFilter: RegisterMimeFilter::<Identity>,
+            UnregisterMimeFilter: UnregisterMimeFilter::<Identity>,
+            CreateBinding: CreateBinding::<Identity>,
+            SetSessionOption: SetSessionOption::<Identity>,
+            GetSessionOption: GetSessionOption::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IInternetSession {}
 windows_core::imp::define_interface!(IInternetThreadSwitch, IInternetThreadSwitch_Vtbl, 0x79eac9e8_baf9_11ce_8c82_00aa004ba90b);
 windows_core::imp::interface_hierarchy!(IInternetThreadSwitch, windows_core::IUnknown);
@@ -2702,6 +3514,30 @@ impl IInternetThreadSwitch_Vtbl {
         iid == &<IInternetThreadSwitch as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInternetThreadSwitch_Impl> windows_core::imp::TearOffVtable<Identity> for IInternetThreadSwitch_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Prepare<Identity: windows_core::IUnknownImpl + IInternetThreadSwitch_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetThreadSwitch_Impl::Prepare(this).into()
+            }
+        }
+        unsafe extern "system" fn Continue<Identity: windows_core::IUnknownImpl + IInternetThreadSwitch_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetThreadSwitch_Impl::Continue(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Prepare: Prepare::<Identity>,
+            Continue: Continue::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IInternetThreadSwitch {}
 windows_core::imp::define_interface!(IInternetZoneManager, IInternetZoneManager_Vtbl, 0x79eac9ef_baf9_11ce_8c82_00aa004ba90b);
 windows_core::imp::interface_hierarchy!(IInternetZoneManager, windows_core::IUnknown);
@@ -2885,6 +3721,106 @@ impl IInternetZoneManager_Vtbl {
         iid == &<IInternetZoneManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInternetZoneManager_Impl> windows_core::imp::TearOffVtable<Identity> for IInternetZoneManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetZoneAttributes<Identity: windows_core::IUnknownImpl + IInternetZoneManager_Impl>(this: *mut core::ffi::c_void, dwzone: u32, pzoneattributes: *mut ZONEATTRIBUTES) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetZoneManager_Impl::GetZoneAttributes(this, core::mem::transmute_copy(&dwzone), core::mem::transmute_copy(&pzoneattributes)).into()
+            }
+        }
+        unsafe extern "system" fn SetZoneAttributes<Identity: windows_core::IUnknownImpl + IInternetZoneManager_Impl>(this: *mut core::ffi::c_void, dwzone: u32, pzoneattributes: *const ZONEATTRIBUTES) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetZoneManager_Impl::SetZoneAttributes(this, core::mem::transmute_copy(&dwzone), core::mem::transmute_copy(&pzoneattributes)).into()
+            }
+        }
+        unsafe extern "system" fn GetZoneCustomPolicy<Identity: windows_core::IUnknownImpl + IInternetZoneManager_Impl>(this: *mut core::ffi::c_void, dwzone: u32, guidkey: *const windows_core::GUID, pppolicy: *mut *mut u8, pcbpolicy: *mut u32, urlzonereg: URLZONEREG) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetZoneManager_Impl::GetZoneCustomPolicy(this, core::mem::transmute_copy(&dwzone), core::mem::transmute_copy(&guidkey), core::mem::transmute_copy(&pppolicy), core::mem::transmute_copy(&pcbpolicy), core::mem::transmute_copy(&urlzonereg)).into()
+            }
+        }
+        unsafe extern "system" fn SetZoneCustomPolicy<Identity: windows_core::IUnknownImpl + IInternetZoneManager_Impl>(this: *mut core::ffi::c_void, dwzone: u32, guidkey: *const windows_core::GUID, ppolicy: *const u8, cbpolicy: u32, urlzonereg: URLZONEREG) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetZoneManager_Impl::SetZoneCustomPolicy(this, core::mem::transmute_copy(&dwzone), core::mem::transmute_copy(&guidkey), core::mem::transmute_copy(&ppolicy), core::mem::transmute_copy(&cbpolicy), core::mem::transmute_copy(&urlzonereg)).into()
+            }
+        }
+        unsafe extern "system" fn GetZoneActionPolicy<Identity: windows_core::IUnknownImpl + IInternetZoneManager_Impl>(this: *mut core::ffi::c_void, dwzone: u32, dwaction: u32, ppolicy: *mut u8, cbpolicy: u32, urlzonereg: URLZONEREG) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetZoneManager_Impl::GetZoneActionPolicy(this, core::mem::transmute_copy(&dwzone), core::mem::transmute_copy(&dwaction), core::mem::transmute_copy(&ppolicy), core::mem::transmute_copy(&cbpolicy), core::mem::transmute_copy(&urlzonereg)).into()
+            }
+        }
+        unsafe extern "system" fn SetZoneActionPolicy<Identity: windows_core::IUnknownImpl + IInternetZoneManager_Impl>(this: *mut core::ffi::c_void, dwzone: u32, dwaction: u32, ppolicy: *const u8, cbpolicy: u32, urlzonereg: URLZONEREG) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetZoneManager_Impl::SetZoneActionPolicy(this, core::mem::transmute_copy(&dwzone), core::mem::transmute_copy(&dwaction), core::mem::transmute_copy(&ppolicy), core::mem::transmute_copy(&cbpolicy), core::mem::transmute_copy(&urlzonereg)).into()
+            }
+        }
+        unsafe extern "system" fn PromptAction<Identity: windows_core::IUnknownImpl + IInternetZoneManager_Impl>(this: *mut core::ffi::c_void, dwaction: u32, hwndparent: super::super::super::Foundation::HWND, pwszurl: windows_core::PCWSTR, pwsztext: windows_core::PCWSTR, dwpromptflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetZoneManager_Impl::PromptAction(this, core::mem::transmute_copy(&dwaction), core::mem::transmute_copy(&hwndparent), core::mem::transmute(&pwszurl), core::mem::transmute(&pwsztext), core::mem::transmute_copy(&dwpromptflags)).into()
+            }
+        }
+        unsafe extern "system" fn LogAction<Identity: windows_core::IUnknownImpl + IInternetZoneManager_Impl>(this: *mut core::ffi::c_void, dwaction: u32, pwszurl: windows_core::PCWSTR, pwsztext: windows_core::PCWSTR, dwlogflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetZoneManager_Impl::LogAction(this, core::mem::transmute_copy(&dwaction), core::mem::transmute(&pwszurl), core::mem::transmute(&pwsztext), core::mem::transmute_copy(&dwlogflags)).into()
+            }
+        }
+        unsafe extern "system" fn CreateZoneEnumerator<Identity: windows_core::IUnknownImpl + IInternetZoneManager_Impl>(this: *mut core::ffi::c_void, pdwenum: *mut u32, pdwcount: *mut u32, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetZoneManager_Impl::CreateZoneEnumerator(this, core::mem::transmute_copy(&pdwenum), core::mem::transmute_copy(&pdwcount), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn GetZoneAt<Identity: windows_core::IUnknownImpl + IInternetZoneManager_Impl>(this: *mut core::ffi::c_void, dwenum: u32, dwindex: u32, pdwzone: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IInternetZoneManager_Impl::GetZoneAt(this, core::mem::transmute_copy(&dwenum), core::mem::transmute_copy(&dwindex)) {
+                    Ok(ok__) => {
+                        pdwzone.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DestroyZoneEnumerator<Identity: windows_core::IUnknownImpl + IInternetZoneManager_Impl>(this: *mut core::ffi::c_void, dwenum: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetZoneManager_Impl::DestroyZoneEnumerator(this, core::mem::transmute_copy(&dwenum)).into()
+            }
+        }
+        unsafe extern "system" fn CopyTemplatePoliciesToZone<Identity: windows_core::IUnknownImpl + IInternetZoneManager_Impl>(this: *mut core::ffi::c_void, dwtemplate: u32, dwzone: u32, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetZoneManager_Impl::CopyTemplatePoliciesToZone(this, core::mem::transmute_copy(&dwtemplate), core::mem::transmute_copy(&dwzone), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetZoneAttributes: GetZoneAttributes::<Identity>,
+            SetZoneAttributes: SetZoneAttributes::<Identity>,
+            GetZoneCustomPolicy: GetZoneCustomPolicy::<Identity>,
+            SetZoneCustomPolicy: SetZoneCustomPolicy::<Identity>,
+            GetZoneActionPolicy: GetZoneActionPolicy::<Identity>,
+            SetZoneActionPolicy: SetZoneActionPolicy::<Identity>,
+            PromptAction: PromptAction::<Identity>,
+            LogAction: LogAction::<Identity>,
+            CreateZoneEnumerator: CreateZoneEnumerator::<Identity>,
+            GetZoneAt: GetZoneAt::<Identity>,
+            DestroyZoneEnumerator: DestroyZoneEnumerator::<Identity>,
+            CopyTemplatePoliciesToZone: CopyTemplatePoliciesToZone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IInternetZoneManager {}
 windows_core::imp::define_interface!(IInternetZoneManagerEx, IInternetZoneManagerEx_Vtbl, 0xa4c23339_8e06_431e_9bf4_7e711c085648);
 impl core::ops::Deref for IInternetZoneManagerEx {
@@ -2937,6 +3873,30 @@ impl IInternetZoneManagerEx_Vtbl {
         iid == &<IInternetZoneManagerEx as windows_core::Interface>::IID || iid == &<IInternetZoneManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IInternetZoneManagerEx_Impl> windows_core::imp::TearOffVtable<Identity> for IInternetZoneManagerEx_Vtbl
+where
+    IInternetZoneManager_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetZoneActionPolicyEx<Identity: windows_core::IUnknownImpl + IInternetZoneManagerEx_Impl>(this: *mut core::ffi::c_void, dwzone: u32, dwaction: u32, ppolicy: *mut u8, cbpolicy: u32, urlzonereg: URLZONEREG, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetZoneManagerEx_Impl::GetZoneActionPolicyEx(this, core::mem::transmute_copy(&dwzone), core::mem::transmute_copy(&dwaction), core::mem::transmute_copy(&ppolicy), core::mem::transmute_copy(&cbpolicy), core::mem::transmute_copy(&urlzonereg), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn SetZoneActionPolicyEx<Identity: windows_core::IUnknownImpl + IInternetZoneManagerEx_Impl>(this: *mut core::ffi::c_void, dwzone: u32, dwaction: u32, ppolicy: *const u8, cbpolicy: u32, urlzonereg: URLZONEREG, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IInternetZoneManagerEx_Impl::SetZoneActionPolicyEx(this, core::mem::transmute_copy(&dwzone), core::mem::transmute_copy(&dwaction), core::mem::transmute_copy(&ppolicy), core::mem::transmute_copy(&cbpolicy), core::mem::transmute_copy(&urlzonereg), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        Self {
+            base__: <IInternetZoneManager_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetZoneActionPolicyEx: GetZoneActionPolicyEx::<Identity>,
+            SetZoneActionPolicyEx: SetZoneActionPolicyEx::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IInternetZoneManagerEx {}
 windows_core::imp::define_interface!(IInternetZoneManagerEx2, IInternetZoneManagerEx2_Vtbl, 0xedc17559_dd5d_4846_8eef_8becba5a4abf);
 impl core::ops::Deref for IInternetZoneManagerEx2 {
@@ -2979,39 +3939,77 @@ impl IInternetZoneManagerEx2_Vtbl {
     pub const fn new<Identity: IInternetZoneManagerEx2_Impl, const OFFSET: isize>() -> Self {
         unsafe extern "system" fn GetZoneAttributesEx<Identity: IInternetZoneManagerEx2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwzone: u32, pzoneattributes: *mut ZONEATTRIBUTES, dwflags: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IInternetZoneManagerEx2_Impl::GetZoneAttributesEx(this, core::mem::transmute_copy(&dwzone), core::mem::transmute_copy(&pzoneattributes), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn GetZoneSecurityState<Identity: IInternetZoneManagerEx2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwzoneindex: u32, frespectpolicy: windows_core::BOOL, pdwstate: *mut u32, pfpolicyencountered: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IInternetZoneManagerEx2_Impl::GetZoneSecurityState(this, core::mem::transmute_copy(&dwzoneindex), core::mem::transmute_copy(&frespectpolicy), core::mem::transmute_copy(&pdwstate), core::mem::transmute_copy(&pfpolicyencountered)).into()
+            }
+        }
+        unsafe extern "system" fn GetIESecurityState<Identity: IInternetZoneManagerEx2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, frespectpolicy: windows_core::BOOL, pdwstate: *mut u32, pfpolicyencountered: *mut windows_core::BOOL, fnocache: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IInternetZoneManagerEx2_Impl::GetIESecurityState(this, core::mem::transmute_copy(&frespectpolicy), core::mem::transmute_copy(&pdwstate), core::mem::transmute_copy(&pfpolicyencountered), core::mem::transmute_copy(&fnocache)).into()
+            }
+        }
+        unsafe extern "system" fn FixUnsecureSettings<Identity: IInternetZoneManagerEx2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IInternetZoneManagerEx2_Impl::FixUnsecureSettings(this).into()
+            }
+        }
+        Self {
+            base__: IInternetZoneManagerEx_Vtbl::new::<Identity, OFFSET>(),
+            GetZoneAttributesEx: GetZoneAttributesEx::<Identity, OFFSET>,
+            GetZoneSecurityState: GetZoneSecurityState::<Identity, OFFSET>,
+            GetIESecurityState: GetIESecurityState::<Identity, OFFSET>,
+            FixUnsecureSettings: FixUnsecureSettings::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternetZoneManagerEx2 as windows_core::Interface>::IID || iid == &<IInternetZoneManager as windows_core::Interface>::IID || iid == &<IInternetZoneManagerEx as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IInternetZoneManagerEx2_Impl> windows_core::imp::TearOffVtable<Identity> for IInternetZoneManagerEx2_Vtbl
+where
+    IInternetZoneManagerEx_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetZoneAttributesEx<Identity: windows_core::IUnknownImpl + IInternetZoneManagerEx2_Impl>(this: *mut core::ffi::c_void, dwzone: u32, pzoneattributes: *mut ZONEATTRIBUTES, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IInternetZoneManagerEx2_Impl::GetZoneAttributesEx(this, core::mem::transmute_copy(&dwzone), core::mem::transmute_copy(&pzoneattributes), core::mem::transmute_copy(&dwflags)).into()
             }
         }
-        unsafe extern "system" fn GetZoneSecurityState<Identity: IInternetZoneManagerEx2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwzoneindex: u32, frespectpolicy: windows_core::BOOL, pdwstate: *mut u32, pfpolicyencountered: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetZoneSecurityState<Identity: windows_core::IUnknownImpl + IInternetZoneManagerEx2_Impl>(this: *mut core::ffi::c_void, dwzoneindex: u32, frespectpolicy: windows_core::BOOL, pdwstate: *mut u32, pfpolicyencountered: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IInternetZoneManagerEx2_Impl::GetZoneSecurityState(this, core::mem::transmute_copy(&dwzoneindex), core::mem::transmute_copy(&frespectpolicy), core::mem::transmute_copy(&pdwstate), core::mem::transmute_copy(&pfpolicyencountered)).into()
             }
         }
-        unsafe extern "system" fn GetIESecurityState<Identity: IInternetZoneManagerEx2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, frespectpolicy: windows_core::BOOL, pdwstate: *mut u32, pfpolicyencountered: *mut windows_core::BOOL, fnocache: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetIESecurityState<Identity: windows_core::IUnknownImpl + IInternetZoneManagerEx2_Impl>(this: *mut core::ffi::c_void, frespectpolicy: windows_core::BOOL, pdwstate: *mut u32, pfpolicyencountered: *mut windows_core::BOOL, fnocache: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IInternetZoneManagerEx2_Impl::GetIESecurityState(this, core::mem::transmute_copy(&frespectpolicy), core::mem::transmute_copy(&pdwstate), core::mem::transmute_copy(&pfpolicyencountered), core::mem::transmute_copy(&fnocache)).into()
             }
         }
-        unsafe extern "system" fn FixUnsecureSettings<Identity: IInternetZoneManagerEx2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn FixUnsecureSettings<Identity: windows_core::IUnknownImpl + IInternetZoneManagerEx2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IInternetZoneManagerEx2_Impl::FixUnsecureSettings(this).into()
             }
         }
         Self {
-            base__: IInternetZoneManagerEx_Vtbl::new::<Identity, OFFSET>(),
-            GetZoneAttributesEx: GetZoneAttributesEx::<Identity, OFFSET>,
-            GetZoneSecurityState: GetZoneSecurityState::<Identity, OFFSET>,
-            GetIESecurityState: GetIESecurityState::<Identity, OFFSET>,
-            FixUnsecureSettings: FixUnsecureSettings::<Identity, OFFSET>,
+            base__: <IInternetZoneManagerEx_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetZoneAttributesEx: GetZoneAttributesEx::<Identity>,
+            GetZoneSecurityState: GetZoneSecurityState::<Identity>,
+            GetIESecurityState: GetIESecurityState::<Identity>,
+            FixUnsecureSettings: FixUnsecureSettings::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IInternetZoneManagerEx2 as windows_core::Interface>::IID || iid == &<IInternetZoneManager as windows_core::Interface>::IID || iid == &<IInternetZoneManagerEx as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IInternetZoneManagerEx2 {}
 windows_core::imp::define_interface!(IMonikerProp, IMonikerProp_Vtbl, 0xa5ca5f7f_1847_4d87_9c5b_918509f7511d);
@@ -3047,6 +4045,20 @@ impl IMonikerProp_Vtbl {
         iid == &<IMonikerProp as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMonikerProp_Impl> windows_core::imp::TearOffVtable<Identity> for IMonikerProp_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn PutProperty<Identity: windows_core::IUnknownImpl + IMonikerProp_Impl>(this: *mut core::ffi::c_void, mkp: MONIKERPROPERTY, val: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMonikerProp_Impl::PutProperty(this, core::mem::transmute_copy(&mkp), core::mem::transmute(&val)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, PutProperty: PutProperty::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IMonikerProp {}
 pub const INET_E_AUTHENTICATION_REQUIRED: windows_core::HRESULT = windows_core::HRESULT(0x800C0009_u32 as _);
 pub const INET_E_BLOCKED_ENHANCEDPROTECTEDMODE: windows_core::HRESULT = windows_core::HRESULT(0x800C0506_u32 as _);
@@ -3227,6 +4239,70 @@ impl IPersistMoniker_Vtbl {
         iid == &<IPersistMoniker as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPersistMoniker_Impl> windows_core::imp::TearOffVtable<Identity> for IPersistMoniker_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetClassID<Identity: windows_core::IUnknownImpl + IPersistMoniker_Impl>(this: *mut core::ffi::c_void, pclassid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPersistMoniker_Impl::GetClassID(this) {
+                    Ok(ok__) => {
+                        pclassid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsDirty<Identity: windows_core::IUnknownImpl + IPersistMoniker_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPersistMoniker_Impl::IsDirty(this)
+            }
+        }
+        unsafe extern "system" fn Load<Identity: windows_core::IUnknownImpl + IPersistMoniker_Impl>(this: *mut core::ffi::c_void, ffullyavailable: windows_core::BOOL, pimkname: *mut core::ffi::c_void, pibc: *mut core::ffi::c_void, grfmode: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPersistMoniker_Impl::Load(this, core::mem::transmute_copy(&ffullyavailable), core::mem::transmute_copy(&pimkname), core::mem::transmute_copy(&pibc), core::mem::transmute_copy(&grfmode)).into()
+            }
+        }
+        unsafe extern "system" fn Save<Identity: windows_core::IUnknownImpl + IPersistMoniker_Impl>(this: *mut core::ffi::c_void, pimkname: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void, fremember: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPersistMoniker_Impl::Save(this, core::mem::transmute_copy(&pimkname), core::mem::transmute_copy(&pbc), core::mem::transmute_copy(&fremember)).into()
+            }
+        }
+        unsafe extern "system" fn SaveCompleted<Identity: windows_core::IUnknownImpl + IPersistMoniker_Impl>(this: *mut core::ffi::c_void, pimkname: *mut core::ffi::c_void, pibc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPersistMoniker_Impl::SaveCompleted(this, core::mem::transmute_copy(&pimkname), core::mem::transmute_copy(&pibc)).into()
+            }
+        }
+        unsafe extern "system" fn GetCurMoniker<Identity: windows_core::IUnknownImpl + IPersistMoniker_Impl>(this: *mut core::ffi::c_void, ppimkname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPersistMoniker_Impl::GetCurMoniker(this) {
+                    Ok(ok__) => {
+                        ppimkname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetClassID: GetClassID::<Identity>,
+            IsDirty: IsDirty::<Identity>,
+            Load: Load::<Identity>,
+            Save: Save::<Identity>,
+            SaveCompleted: SaveCompleted::<Identity>,
+            GetCurMoniker: GetCurMoniker::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPersistMoniker {}
 windows_core::imp::define_interface!(ISoftDistExt, ISoftDistExt_Vtbl, 0xb15b8dc1_c7e1_11d0_8680_00aa00bdcb71);
 windows_core::imp::interface_hierarchy!(ISoftDistExt, windows_core::IUnknown);
@@ -3311,6 +4387,45 @@ impl ISoftDistExt_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Data_Xml_MsXml")]
+impl<Identity: windows_core::IUnknownImpl + ISoftDistExt_Impl> windows_core::imp::TearOffVtable<Identity> for ISoftDistExt_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ProcessSoftDist<Identity: windows_core::IUnknownImpl + ISoftDistExt_Impl>(this: *mut core::ffi::c_void, szcdfurl: windows_core::PCWSTR, psoftdistelement: *mut core::ffi::c_void, lpsdi: *mut SOFTDISTINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISoftDistExt_Impl::ProcessSoftDist(this, core::mem::transmute(&szcdfurl), core::mem::transmute_copy(&psoftdistelement), core::mem::transmute_copy(&lpsdi)).into()
+            }
+        }
+        unsafe extern "system" fn GetFirstCodeBase<Identity: windows_core::IUnknownImpl + ISoftDistExt_Impl>(this: *mut core::ffi::c_void, szcodebase: *const windows_core::PCWSTR, dwmaxsize: *const u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISoftDistExt_Impl::GetFirstCodeBase(this, core::mem::transmute_copy(&szcodebase), core::mem::transmute_copy(&dwmaxsize)).into()
+            }
+        }
+        unsafe extern "system" fn GetNextCodeBase<Identity: windows_core::IUnknownImpl + ISoftDistExt_Impl>(this: *mut core::ffi::c_void, szcodebase: *const windows_core::PCWSTR, dwmaxsize: *const u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISoftDistExt_Impl::GetNextCodeBase(this, core::mem::transmute_copy(&szcodebase), core::mem::transmute_copy(&dwmaxsize)).into()
+            }
+        }
+        unsafe extern "system" fn AsyncInstallDistributionUnit<Identity: windows_core::IUnknownImpl + ISoftDistExt_Impl>(this: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void, pvreserved: *const core::ffi::c_void, flags: u32, lpcbh: *const CODEBASEHOLD) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISoftDistExt_Impl::AsyncInstallDistributionUnit(this, core::mem::transmute_copy(&pbc), core::mem::transmute_copy(&pvreserved), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&lpcbh)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ProcessSoftDist: ProcessSoftDist::<Identity>,
+            GetFirstCodeBase: GetFirstCodeBase::<Identity>,
+            GetNextCodeBase: GetNextCodeBase::<Identity>,
+            AsyncInstallDistributionUnit: AsyncInstallDistributionUnit::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Data_Xml_MsXml")]
 impl windows_core::RuntimeName for ISoftDistExt {}
 windows_core::imp::define_interface!(IUriBuilderFactory, IUriBuilderFactory_Vtbl, 0xe982ce48_0b96_440c_bc37_0c869b27a29e);
 windows_core::imp::interface_hierarchy!(IUriBuilderFactory, windows_core::IUnknown);
@@ -3375,6 +4490,42 @@ impl IUriBuilderFactory_Vtbl {
         iid == &<IUriBuilderFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IUriBuilderFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IUriBuilderFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateIUriBuilder<Identity: windows_core::IUnknownImpl + IUriBuilderFactory_Impl>(this: *mut core::ffi::c_void, dwflags: u32, dwreserved: usize, ppiuribuilder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUriBuilderFactory_Impl::CreateIUriBuilder(this, core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&dwreserved)) {
+                    Ok(ok__) => {
+                        ppiuribuilder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateInitializedIUriBuilder<Identity: windows_core::IUnknownImpl + IUriBuilderFactory_Impl>(this: *mut core::ffi::c_void, dwflags: u32, dwreserved: usize, ppiuribuilder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUriBuilderFactory_Impl::CreateInitializedIUriBuilder(this, core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&dwreserved)) {
+                    Ok(ok__) => {
+                        ppiuribuilder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateIUriBuilder: CreateIUriBuilder::<Identity>,
+            CreateInitializedIUriBuilder: CreateInitializedIUriBuilder::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IUriBuilderFactory {}
 windows_core::imp::define_interface!(IUriContainer, IUriContainer_Vtbl, 0xa158a630_ed6f_45fb_b987_f68676f57752);
 windows_core::imp::interface_hierarchy!(IUriContainer, windows_core::IUnknown);
@@ -3415,6 +4566,26 @@ impl IUriContainer_Vtbl {
         iid == &<IUriContainer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IUriContainer_Impl> windows_core::imp::TearOffVtable<Identity> for IUriContainer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetIUri<Identity: windows_core::IUnknownImpl + IUriContainer_Impl>(this: *mut core::ffi::c_void, ppiuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IUriContainer_Impl::GetIUri(this) {
+                    Ok(ok__) => {
+                        ppiuri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetIUri: GetIUri::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IUriContainer {}
 windows_core::imp::define_interface!(IWinInetCacheHints, IWinInetCacheHints_Vtbl, 0xdd1ec3b3_8391_4fdb_a9e6_347c3caaa7dd);
 windows_core::imp::interface_hierarchy!(IWinInetCacheHints, windows_core::IUnknown);
@@ -3449,6 +4620,20 @@ impl IWinInetCacheHints_Vtbl {
         iid == &<IWinInetCacheHints as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWinInetCacheHints_Impl> windows_core::imp::TearOffVtable<Identity> for IWinInetCacheHints_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetCacheExtension<Identity: windows_core::IUnknownImpl + IWinInetCacheHints_Impl>(this: *mut core::ffi::c_void, pwzext: windows_core::PCWSTR, pszcachefile: *mut core::ffi::c_void, pcbcachefile: *mut u32, pdwwinineterror: *mut u32, pdwreserved: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinInetCacheHints_Impl::SetCacheExtension(this, core::mem::transmute(&pwzext), core::mem::transmute_copy(&pszcachefile), core::mem::transmute_copy(&pcbcachefile), core::mem::transmute_copy(&pdwwinineterror), core::mem::transmute_copy(&pdwreserved)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetCacheExtension: SetCacheExtension::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWinInetCacheHints {}
 windows_core::imp::define_interface!(IWinInetCacheHints2, IWinInetCacheHints2_Vtbl, 0x7857aeac_d31f_49bf_884e_dd46df36780a);
 impl core::ops::Deref for IWinInetCacheHints2 {
@@ -3489,6 +4674,20 @@ impl IWinInetCacheHints2_Vtbl {
         iid == &<IWinInetCacheHints2 as windows_core::Interface>::IID || iid == &<IWinInetCacheHints as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWinInetCacheHints2_Impl> windows_core::imp::TearOffVtable<Identity> for IWinInetCacheHints2_Vtbl
+where
+    IWinInetCacheHints_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetCacheExtension2<Identity: windows_core::IUnknownImpl + IWinInetCacheHints2_Impl>(this: *mut core::ffi::c_void, pwzext: windows_core::PCWSTR, pwzcachefile: windows_core::PWSTR, pcchcachefile: *mut u32, pdwwinineterror: *mut u32, pdwreserved: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinInetCacheHints2_Impl::SetCacheExtension2(this, core::mem::transmute(&pwzext), core::mem::transmute_copy(&pwzcachefile), core::mem::transmute_copy(&pcchcachefile), core::mem::transmute_copy(&pdwwinineterror), core::mem::transmute_copy(&pdwreserved)).into()
+            }
+        }
+        Self { base__: <IWinInetCacheHints_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetCacheExtension2: SetCacheExtension2::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWinInetCacheHints2 {}
 windows_core::imp::define_interface!(IWinInetFileStream, IWinInetFileStream_Vtbl, 0xf134c4b7_b1f8_4e75_b886_74b90943becb);
 windows_core::imp::interface_hierarchy!(IWinInetFileStream, windows_core::IUnknown);
@@ -3535,6 +4734,30 @@ impl IWinInetFileStream_Vtbl {
         iid == &<IWinInetFileStream as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWinInetFileStream_Impl> windows_core::imp::TearOffVtable<Identity> for IWinInetFileStream_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetHandleForUnlock<Identity: windows_core::IUnknownImpl + IWinInetFileStream_Impl>(this: *mut core::ffi::c_void, hwininetlockhandle: usize, dwreserved: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinInetFileStream_Impl::SetHandleForUnlock(this, core::mem::transmute_copy(&hwininetlockhandle), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn SetDeleteFile<Identity: windows_core::IUnknownImpl + IWinInetFileStream_Impl>(this: *mut core::ffi::c_void, dwreserved: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinInetFileStream_Impl::SetDeleteFile(this, core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetHandleForUnlock: SetHandleForUnlock::<Identity>,
+            SetDeleteFile: SetDeleteFile::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWinInetFileStream {}
 windows_core::imp::define_interface!(IWinInetHttpInfo, IWinInetHttpInfo_Vtbl, 0x79eac9d8_bafa_11ce_8c82_00aa004ba90b);
 impl core::ops::Deref for IWinInetHttpInfo {
@@ -3572,6 +4795,20 @@ impl IWinInetHttpInfo_Vtbl {
         iid == &<IWinInetHttpInfo as windows_core::Interface>::IID || iid == &<IWinInetInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWinInetHttpInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IWinInetHttpInfo_Vtbl
+where
+    IWinInetInfo_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryInfo<Identity: windows_core::IUnknownImpl + IWinInetHttpInfo_Impl>(this: *mut core::ffi::c_void, dwoption: u32, pbuffer: *mut core::ffi::c_void, pcbbuf: *mut u32, pdwflags: *mut u32, pdwreserved: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinInetHttpInfo_Impl::QueryInfo(this, core::mem::transmute_copy(&dwoption), core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&pcbbuf), core::mem::transmute_copy(&pdwflags), core::mem::transmute_copy(&pdwreserved)).into()
+            }
+        }
+        Self { base__: <IWinInetInfo_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, QueryInfo: QueryInfo::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWinInetHttpInfo {}
 windows_core::imp::define_interface!(IWinInetHttpTimeouts, IWinInetHttpTimeouts_Vtbl, 0xf286fa56_c1fd_4270_8e67_b3eb790a81e8);
 windows_core::imp::interface_hierarchy!(IWinInetHttpTimeouts, windows_core::IUnknown);
@@ -3603,6 +4840,20 @@ impl IWinInetHttpTimeouts_Vtbl {
         iid == &<IWinInetHttpTimeouts as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWinInetHttpTimeouts_Impl> windows_core::imp::TearOffVtable<Identity> for IWinInetHttpTimeouts_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRequestTimeouts<Identity: windows_core::IUnknownImpl + IWinInetHttpTimeouts_Impl>(this: *mut core::ffi::c_void, pdwconnecttimeout: *mut u32, pdwsendtimeout: *mut u32, pdwreceivetimeout: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinInetHttpTimeouts_Impl::GetRequestTimeouts(this, core::mem::transmute_copy(&pdwconnecttimeout), core::mem::transmute_copy(&pdwsendtimeout), core::mem::transmute_copy(&pdwreceivetimeout)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetRequestTimeouts: GetRequestTimeouts::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWinInetHttpTimeouts {}
 windows_core::imp::define_interface!(IWinInetInfo, IWinInetInfo_Vtbl, 0x79eac9d6_bafa_11ce_8c82_00aa004ba90b);
 windows_core::imp::interface_hierarchy!(IWinInetInfo, windows_core::IUnknown);
@@ -3634,6 +4885,20 @@ impl IWinInetInfo_Vtbl {
         iid == &<IWinInetInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWinInetInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IWinInetInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryOption<Identity: windows_core::IUnknownImpl + IWinInetInfo_Impl>(this: *mut core::ffi::c_void, dwoption: u32, pbuffer: *mut core::ffi::c_void, pcbbuf: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWinInetInfo_Impl::QueryOption(this, core::mem::transmute_copy(&dwoption), core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&pcbbuf)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, QueryOption: QueryOption::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWinInetInfo {}
 windows_core::imp::define_interface!(IWindowForBindingUI, IWindowForBindingUI_Vtbl, 0x79eac9d5_bafa_11ce_8c82_00aa004ba90b);
 windows_core::imp::interface_hierarchy!(IWindowForBindingUI, windows_core::IUnknown);
@@ -3674,6 +4939,26 @@ impl IWindowForBindingUI_Vtbl {
         iid == &<IWindowForBindingUI as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWindowForBindingUI_Impl> windows_core::imp::TearOffVtable<Identity> for IWindowForBindingUI_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetWindow<Identity: windows_core::IUnknownImpl + IWindowForBindingUI_Impl>(this: *mut core::ffi::c_void, rguidreason: *const windows_core::GUID, phwnd: *mut super::super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWindowForBindingUI_Impl::GetWindow(this, core::mem::transmute_copy(&rguidreason)) {
+                    Ok(ok__) => {
+                        phwnd.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetWindow: GetWindow::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWindowForBindingUI {}
 windows_core::imp::define_interface!(IWrappedProtocol, IWrappedProtocol_Vtbl, 0x53c84785_8425_4dc5_971b_e58d9c19f9b6);
 windows_core::imp::interface_hierarchy!(IWrappedProtocol, windows_core::IUnknown);
@@ -3705,6 +4990,20 @@ impl IWrappedProtocol_Vtbl {
         iid == &<IWrappedProtocol as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWrappedProtocol_Impl> windows_core::imp::TearOffVtable<Identity> for IWrappedProtocol_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetWrapperCode<Identity: windows_core::IUnknownImpl + IWrappedProtocol_Impl>(this: *mut core::ffi::c_void, pncode: *mut i32, dwreserved: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWrappedProtocol_Impl::GetWrapperCode(this, core::mem::transmute_copy(&pncode), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetWrapperCode: GetWrapperCode::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWrappedProtocol {}
 windows_core::imp::define_interface!(IZoneIdentifier, IZoneIdentifier_Vtbl, 0xcd45f185_1b21_48e2_967b_ead743a8914e);
 windows_core::imp::interface_hierarchy!(IZoneIdentifier, windows_core::IUnknown);
@@ -3772,6 +5071,43 @@ impl IZoneIdentifier_Vtbl {
         iid == &<IZoneIdentifier as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IZoneIdentifier_Impl> windows_core::imp::TearOffVtable<Identity> for IZoneIdentifier_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetId<Identity: windows_core::IUnknownImpl + IZoneIdentifier_Impl>(this: *mut core::ffi::c_void, pdwzone: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IZoneIdentifier_Impl::GetId(this) {
+                    Ok(ok__) => {
+                        pdwzone.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetId<Identity: windows_core::IUnknownImpl + IZoneIdentifier_Impl>(this: *mut core::ffi::c_void, dwzone: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IZoneIdentifier_Impl::SetId(this, core::mem::transmute_copy(&dwzone)).into()
+            }
+        }
+        unsafe extern "system" fn Remove<Identity: windows_core::IUnknownImpl + IZoneIdentifier_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IZoneIdentifier_Impl::Remove(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetId: GetId::<Identity>,
+            SetId: SetId::<Identity>,
+            Remove: Remove::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IZoneIdentifier {}
 windows_core::imp::define_interface!(IZoneIdentifier2, IZoneIdentifier2_Vtbl, 0xeb5e760c_09ef_45c0_b510_70830ce31e6a);
 impl core::ops::Deref for IZoneIdentifier2 {
@@ -3893,6 +5229,70 @@ impl IZoneIdentifier2_Vtbl {
         iid == &<IZoneIdentifier2 as windows_core::Interface>::IID || iid == &<IZoneIdentifier as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IZoneIdentifier2_Impl> windows_core::imp::TearOffVtable<Identity> for IZoneIdentifier2_Vtbl
+where
+    IZoneIdentifier_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetLastWriterPackageFamilyName<Identity: windows_core::IUnknownImpl + IZoneIdentifier2_Impl>(this: *mut core::ffi::c_void, packagefamilyname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IZoneIdentifier2_Impl::GetLastWriterPackageFamilyName(this) {
+                    Ok(ok__) => {
+                        packagefamilyname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLastWriterPackageFamilyName<Identity: windows_core::IUnknownImpl + IZoneIdentifier2_Impl>(this: *mut core::ffi::c_void, packagefamilyname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IZoneIdentifier2_Impl::SetLastWriterPackageFamilyName(this, core::mem::transmute(&packagefamilyname)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveLastWriterPackageFamilyName<Identity: windows_core::IUnknownImpl + IZoneIdentifier2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IZoneIdentifier2_Impl::RemoveLastWriterPackageFamilyName(this).into()
+            }
+        }
+        unsafe extern "system" fn GetAppZoneId<Identity: windows_core::IUnknownImpl + IZoneIdentifier2_Impl>(this: *mut core::ffi::c_void, zone: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IZoneIdentifier2_Impl::GetAppZoneId(this) {
+                    Ok(ok__) => {
+                        zone.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAppZoneId<Identity: windows_core::IUnknownImpl + IZoneIdentifier2_Impl>(this: *mut core::ffi::c_void, zone: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IZoneIdentifier2_Impl::SetAppZoneId(this, core::mem::transmute_copy(&zone)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveAppZoneId<Identity: windows_core::IUnknownImpl + IZoneIdentifier2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IZoneIdentifier2_Impl::RemoveAppZoneId(this).into()
+            }
+        }
+        Self {
+            base__: <IZoneIdentifier_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetLastWriterPackageFamilyName: GetLastWriterPackageFamilyName::<Identity>,
+            SetLastWriterPackageFamilyName: SetLastWriterPackageFamilyName::<Identity>,
+            RemoveLastWriterPackageFamilyName: RemoveLastWriterPackageFamilyName::<Identity>,
+            GetAppZoneId: GetAppZoneId::<Identity>,
+            SetAppZoneId: SetAppZoneId::<Identity>,
+            RemoveAppZoneId: RemoveAppZoneId::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IZoneIdentifier2 {}
 pub const MAX_SIZE_SECURITY_ID: u32 = 512u32;
 pub const MAX_ZONE_DESCRIPTION: INET_ZONE_MANAGER_CONSTANTS = INET_ZONE_MANAGER_CONSTANTS(200i32);
@@ -184,6 +184,37 @@ impl IContact_Vtbl {
         iid == &<IContact as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IContact_Impl> windows_core::imp::TearOffVtable<Identity> for IContact_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetContactID<Identity: windows_core::IUnknownImpl + IContact_Impl>(this: *mut core::ffi::c_void, pszcontactid: windows_core::PWSTR, cchcontactid: u32, pdwcchcontactidrequired: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContact_Impl::GetContactID(this, core::mem::transmute_copy(&pszcontactid), core::mem::transmute_copy(&cchcontactid), core::mem::transmute_copy(&pdwcchcontactidrequired)).into()
+            }
+        }
+        unsafe extern "system" fn GetPath<Identity: windows_core::IUnknownImpl + IContact_Impl>(this: *mut core::ffi::c_void, pszpath: windows_core::PWSTR, cchpath: u32, pdwcchpathrequired: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContact_Impl::GetPath(this, core::mem::transmute_copy(&pszpath), core::mem::transmute_copy(&cchpath), core::mem::transmute_copy(&pdwcchpathrequired)).into()
+            }
+        }
+        unsafe extern "system" fn CommitChanges<Identity: windows_core::IUnknownImpl + IContact_Impl>(this: *mut core::ffi::c_void, dwcommitflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContact_Impl::CommitChanges(this, core::mem::transmute_copy(&dwcommitflags)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetContactID: GetContactID::<Identity>,
+            GetPath: GetPath::<Identity>,
+            CommitChanges: CommitChanges::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IContact {}
 windows_core::imp::define_interface!(IContactAggregationAggregate, IContactAggregationAggregate_Vtbl, 0x7ed1c814_cd30_43c8_9b8d_2e489e53d54b);
 windows_core::imp::interface_hierarchy!(IContactAggregationAggregate, windows_core::IUnknown);
@@ -365,6 +396,109 @@ impl IContactAggregationAggregate_Vtbl {
         iid == &<IContactAggregationAggregate as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IContactAggregationAggregate_Impl> windows_core::imp::TearOffVtable<Identity> for IContactAggregationAggregate_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Save<Identity: windows_core::IUnknownImpl + IContactAggregationAggregate_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationAggregate_Impl::Save(this).into()
+            }
+        }
+        unsafe extern "system" fn GetComponentItems<Identity: windows_core::IUnknownImpl + IContactAggregationAggregate_Impl>(this: *mut core::ffi::c_void, pcomponentitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationAggregate_Impl::GetComponentItems(this) {
+                    Ok(ok__) => {
+                        pcomponentitems.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Link<Identity: windows_core::IUnknownImpl + IContactAggregationAggregate_Impl>(this: *mut core::ffi::c_void, paggregateid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationAggregate_Impl::Link(this, core::mem::transmute(&paggregateid)).into()
+            }
+        }
+        unsafe extern "system" fn get_Groups<Identity: windows_core::IUnknownImpl + IContactAggregationAggregate_Impl>(this: *mut core::ffi::c_void, options: CONTACT_AGGREGATION_COLLECTION_OPTIONS, ppgroups: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationAggregate_Impl::get_Groups(this, core::mem::transmute_copy(&options)) {
+                    Ok(ok__) => {
+                        ppgroups.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AntiLink<Identity: windows_core::IUnknownImpl + IContactAggregationAggregate_Impl>(this: *mut core::ffi::c_void, ppantilink: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationAggregate_Impl::AntiLink(this) {
+                    Ok(ok__) => {
+                        ppantilink.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAntiLink<Identity: windows_core::IUnknownImpl + IContactAggregationAggregate_Impl>(this: *mut core::ffi::c_void, pantilink: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationAggregate_Impl::SetAntiLink(this, core::mem::transmute(&pantilink)).into()
+            }
+        }
+        unsafe extern "system" fn FavoriteOrder<Identity: windows_core::IUnknownImpl + IContactAggregationAggregate_Impl>(this: *mut core::ffi::c_void, pfavoriteorder: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationAggregate_Impl::FavoriteOrder(this) {
+                    Ok(ok__) => {
+                        pfavoriteorder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetFavoriteOrder<Identity: windows_core::IUnknownImpl + IContactAggregationAggregate_Impl>(this: *mut core::ffi::c_void, favoriteorder: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationAggregate_Impl::SetFavoriteOrder(this, core::mem::transmute_copy(&favoriteorder)).into()
+            }
+        }
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + IContactAggregationAggregate_Impl>(this: *mut core::ffi::c_void, ppitemid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationAggregate_Impl::Id(this) {
+                    Ok(ok__) => {
+                        ppitemid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Save: Save::<Identity>,
+            GetComponentItems: GetComponentItems::<Identity>,
+            Link: Link::<Identity>,
+            get_Groups: get_Groups::<Identity>,
+            AntiLink: AntiLink::<Identity>,
+            SetAntiLink: SetAntiLink::<Identity>,
+            FavoriteOrder: FavoriteOrder::<Identity>,
+            SetFavoriteOrder: SetFavoriteOrder::<Identity>,
+            Id: Id::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IContactAggregationAggregate {}
 windows_core::imp::define_interface!(IContactAggregationAggregateCollection, IContactAggregationAggregateCollection_Vtbl, 0x2359f3a6_3a68_40af_98db_0f9eb143c3bb);
 windows_core::imp::interface_hierarchy!(IContactAggregationAggregateCollection, windows_core::IUnknown);
@@ -474,6 +608,68 @@ impl IContactAggregationAggregateCollection_Vtbl {
         iid == &<IContactAggregationAggregateCollection as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IContactAggregationAggregateCollection_Impl> windows_core::imp::TearOffVtable<Identity> for IContactAggregationAggregateCollection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FindFirst<Identity: windows_core::IUnknownImpl + IContactAggregationAggregateCollection_Impl>(this: *mut core::ffi::c_void, ppaggregate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationAggregateCollection_Impl::FindFirst(this) {
+                    Ok(ok__) => {
+                        ppaggregate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindFirstByAntiLinkId<Identity: windows_core::IUnknownImpl + IContactAggregationAggregateCollection_Impl>(this: *mut core::ffi::c_void, pantilinkid: windows_core::PCWSTR, ppaggregate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationAggregateCollection_Impl::FindFirstByAntiLinkId(this, core::mem::transmute(&pantilinkid)) {
+                    Ok(ok__) => {
+                        ppaggregate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindNext<Identity: windows_core::IUnknownImpl + IContactAggregationAggregateCollection_Impl>(this: *mut core::ffi::c_void, ppaggregate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationAggregateCollection_Impl::FindNext(this) {
+                    Ok(ok__) => {
+                        ppaggregate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + IContactAggregationAggregateCollection_Impl>(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationAggregateCollection_Impl::Count(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            FindFirst: FindFirst::<Identity>,
+            FindFirstByAntiLinkId: FindFirstByAntiLinkId::<Identity>,
+            FindNext: FindNext::<Identity>,
+            Count: Count::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IContactAggregationAggregateCollection {}
 windows_core::imp::define_interface!(IContactAggregationContact, IContactAggregationContact_Vtbl, 0x1eb22e86_4c86_41f0_9f9f_c251e9fda6c3);
 windows_core::imp::interface_hierarchy!(IContactAggregationContact, windows_core::IUnknown);
@@ -802,6 +998,196 @@ impl IContactAggregationContact_Vtbl {
         iid == &<IContactAggregationContact as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IContactAggregationContact_Impl> windows_core::imp::TearOffVtable<Identity> for IContactAggregationContact_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IContactAggregationContact_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationContact_Impl::Delete(this).into()
+            }
+        }
+        unsafe extern "system" fn Save<Identity: windows_core::IUnknownImpl + IContactAggregationContact_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationContact_Impl::Save(this).into()
+            }
+        }
+        unsafe extern "system" fn MoveToAggregate<Identity: windows_core::IUnknownImpl + IContactAggregationContact_Impl>(this: *mut core::ffi::c_void, paggregateid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationContact_Impl::MoveToAggregate(this, core::mem::transmute(&paggregateid)).into()
+            }
+        }
+        unsafe extern "system" fn Unlink<Identity: windows_core::IUnknownImpl + IContactAggregationContact_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationContact_Impl::Unlink(this).into()
+            }
+        }
+        unsafe extern "system" fn AccountId<Identity: windows_core::IUnknownImpl + IContactAggregationContact_Impl>(this: *mut core::ffi::c_void, ppaccountid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationContact_Impl::AccountId(this) {
+                    Ok(ok__) => {
+                        ppaccountid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAccountId<Identity: windows_core::IUnknownImpl + IContactAggregationContact_Impl>(this: *mut core::ffi::c_void, paccountid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationContact_Impl::SetAccountId(this, core::mem::transmute(&paccountid)).into()
+            }
+        }
+        unsafe extern "system" fn AggregateId<Identity: windows_core::IUnknownImpl + IContactAggregationContact_Impl>(this: *mut core::ffi::c_void, ppaggregateid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationContact_Impl::AggregateId(this) {
+                    Ok(ok__) => {
+                        ppaggregateid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + IContactAggregationContact_Impl>(this: *mut core::ffi::c_void, ppitemid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationContact_Impl::Id(this) {
+                    Ok(ok__) => {
+                        ppitemid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsMe<Identity: windows_core::IUnknownImpl + IContactAggregationContact_Impl>(this: *mut core::ffi::c_void, pisme: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationContact_Impl::IsMe(this) {
+                    Ok(ok__) => {
+                        pisme.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsExternal<Identity: windows_core::IUnknownImpl + IContactAggregationContact_Impl>(this: *mut core::ffi::c_void, pisexternal: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationContact_Impl::IsExternal(this) {
+                    Ok(ok__) => {
+                        pisexternal.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NetworkSourceId<Identity: windows_core::IUnknownImpl + IContactAggregationContact_Impl>(this: *mut core::ffi::c_void, pnetworksourceid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationContact_Impl::NetworkSourceId(this) {
+                    Ok(ok__) => {
+                        pnetworksourceid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNetworkSourceId<Identity: windows_core::IUnknownImpl + IContactAggregationContact_Impl>(this: *mut core::ffi::c_void, networksourceid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationContact_Impl::SetNetworkSourceId(this, core::mem::transmute_copy(&networksourceid)).into()
+            }
+        }
+        unsafe extern "system" fn NetworkSourceIdString<Identity: windows_core::IUnknownImpl + IContactAggregationContact_Impl>(this: *mut core::ffi::c_void, ppnetworksourceid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationContact_Impl::NetworkSourceIdString(this) {
+                    Ok(ok__) => {
+                        ppnetworksourceid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNetworkSourceIdString<Identity: windows_core::IUnknownImpl + IContactAggregationContact_Impl>(this: *mut core::ffi::c_void, pnetworksourceid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationContact_Impl::SetNetworkSourceIdString(this, core::mem::transmute(&pnetworksourceid)).into()
+            }
+        }
+        unsafe extern "system" fn RemoteObjectId<Identity: windows_core::IUnknownImpl + IContactAggregationContact_Impl>(this: *mut core::ffi::c_void, ppremoteobjectid: *mut *mut CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationContact_Impl::RemoteObjectId(this) {
+                    Ok(ok__) => {
+                        ppremoteobjectid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRemoteObjectId<Identity: windows_core::IUnknownImpl + IContactAggregationContact_Impl>(this: *mut core::ffi::c_void, premoteobjectid: *const CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationContact_Impl::SetRemoteObjectId(this, core::mem::transmute_copy(&premoteobjectid)).into()
+            }
+        }
+        unsafe extern "system" fn SyncIdentityHash<Identity: windows_core::IUnknownImpl + IContactAggregationContact_Impl>(this: *mut core::ffi::c_void, ppsyncidentityhash: *mut *mut CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationContact_Impl::SyncIdentityHash(this) {
+                    Ok(ok__) => {
+                        ppsyncidentityhash.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSyncIdentityHash<Identity: windows_core::IUnknownImpl + IContactAggregationContact_Impl>(this: *mut core::ffi::c_void, psyncidentityhash: *const CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationContact_Impl::SetSyncIdentityHash(this, core::mem::transmute_copy(&psyncidentityhash)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Delete: Delete::<Identity>,
+            Save: Save::<Identity>,
+            MoveToAggregate: MoveToAggregate::<Identity>,
+            Unlink: Unlink::<Identity>,
+            AccountId: AccountId::<Identity>,
+            SetAccountId: SetAccountId::<Identity>,
+            AggregateId: AggregateId::<Identity>,
+            Id: Id::<Identity>,
+            IsMe: IsMe::<Identity>,
+            IsExternal: IsExternal::<Identity>,
+            NetworkSourceId: NetworkSourceId::<Identity>,
+            SetNetworkSourceId: SetNetworkSourceId::<Identity>,
+            NetworkSourceIdString: NetworkSourceIdString::<Identity>,
+            SetNetworkSourceIdString: SetNetworkSourceIdString::<Identity>,
+            RemoteObjectId: RemoteObjectId::<Identity>,
+            SetRemoteObjectId: SetRemoteObjectId::<Identity>,
+            SyncIdentityHash: SyncIdentityHash::<Identity>,
+            SetSyncIdentityHash: SetSyncIdentityHash::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IContactAggregationContact {}
 windows_core::imp::define_interface!(IContactAggregationContactCollection, IContactAggregationContactCollection_Vtbl, 0x826e66fa_81de_43ca_a6fb_8c785cd996c6);
 windows_core::imp::interface_hierarchy!(IContactAggregationContactCollection, windows_core::IUnknown);
@@ -937,12 +1323,87 @@ impl IContactAggregationContactCollection_Vtbl {
         iid == &<IContactAggregationContactCollection as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IContactAggregationContactCollection {}
-windows_core::imp::define_interface!(IContactAggregationGroup, IContactAggregationGroup_Vtbl, 0xc93c545f_1284_499b_96af_07372af473e0);
-windows_core::imp::interface_hierarchy!(IContactAggregationGroup, windows_core::IUnknown);
-impl IContactAggregationGroup {
-    pub unsafe fn Delete(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Delete)(windows_core::Interface::as_raw(self)).ok() }
+impl<Identity: windows_core::IUnknownImpl + IContactAggregationContactCollection_Impl> windows_core::imp::TearOffVtable<Identity> for IContactAggregationContactCollection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FindFirst<Identity: windows_core::IUnknownImpl + IContactAggregationContactCollection_Impl>(this: *mut core::ffi::c_void, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationContactCollection_Impl::FindFirst(this) {
+                    Ok(ok__) => {
+                        ppitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindNext<Identity: windows_core::IUnknownImpl + IContactAggregationContactCollection_Impl>(this: *mut core::ffi::c_void, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationContactCollection_Impl::FindNext(this) {
+                    Ok(ok__) => {
+                        ppitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindFirstByIdentityHash<Identity: windows_core::IUnknownImpl + IContactAggregationContactCollection_Impl>(this: *mut core::ffi::c_void, psourcetype: windows_core::PCWSTR, paccountid: windows_core::PCWSTR, pidentityhash: *const CONTACT_AGGREGATION_BLOB, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationContactCollection_Impl::FindFirstByIdentityHash(this, core::mem::transmute(&psourcetype), core::mem::transmute(&paccountid), core::mem::transmute_copy(&pidentityhash)) {
+                    Ok(ok__) => {
+                        ppitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + IContactAggregationContactCollection_Impl>(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationContactCollection_Impl::Count(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindFirstByRemoteId<Identity: windows_core::IUnknownImpl + IContactAggregationContactCollection_Impl>(this: *mut core::ffi::c_void, psourcetype: windows_core::PCWSTR, paccountid: windows_core::PCWSTR, premoteobjectid: *const CONTACT_AGGREGATION_BLOB, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationContactCollection_Impl::FindFirstByRemoteId(this, core::mem::transmute(&psourcetype), core::mem::transmute(&paccountid), core::mem::transmute_copy(&premoteobjectid)) {
+                    Ok(ok__) => {
+                        ppitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            FindFirst: FindFirst::<Identity>,
+            FindNext: FindNext::<Identity>,
+            FindFirstByIdentityHash: FindFirstByIdentityHash::<Identity>,
+            Count: Count::<Identity>,
+            FindFirstByRemoteId: FindFirstByRemoteId::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IContactAggregationContactCollection {}
+windows_core::imp::define_interface!(IContactAggregationGroup, IContactAggregationGroup_Vtbl, 0xc93c545f_1284_499b_96af_07372af473e0);
+windows_core::imp::interface_hierarchy!(IContactAggregationGroup, windows_core::IUnknown);
+impl IContactAggregationGroup {
+    pub unsafe fn Delete(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Delete)(windows_core::Interface::as_raw(self)).ok() }
     }
     pub unsafe fn Save(&self) -> windows_core::Result<()> {
         unsafe { (windows_core::Interface::vtable(self).Save)(windows_core::Interface::as_raw(self)).ok() }
@@ -1124,6 +1585,110 @@ impl IContactAggregationGroup_Vtbl {
         iid == &<IContactAggregationGroup as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IContactAggregationGroup_Impl> windows_core::imp::TearOffVtable<Identity> for IContactAggregationGroup_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IContactAggregationGroup_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationGroup_Impl::Delete(this).into()
+            }
+        }
+        unsafe extern "system" fn Save<Identity: windows_core::IUnknownImpl + IContactAggregationGroup_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationGroup_Impl::Save(this).into()
+            }
+        }
+        unsafe extern "system" fn Add<Identity: windows_core::IUnknownImpl + IContactAggregationGroup_Impl>(this: *mut core::ffi::c_void, paggregateid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationGroup_Impl::Add(this, core::mem::transmute(&paggregateid)).into()
+            }
+        }
+        unsafe extern "system" fn Remove<Identity: windows_core::IUnknownImpl + IContactAggregationGroup_Impl>(this: *mut core::ffi::c_void, paggregateid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationGroup_Impl::Remove(this, core::mem::transmute(&paggregateid)).into()
+            }
+        }
+        unsafe extern "system" fn Members<Identity: windows_core::IUnknownImpl + IContactAggregationGroup_Impl>(this: *mut core::ffi::c_void, ppaggregatecontactcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationGroup_Impl::Members(this) {
+                    Ok(ok__) => {
+                        ppaggregatecontactcollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GlobalObjectId<Identity: windows_core::IUnknownImpl + IContactAggregationGroup_Impl>(this: *mut core::ffi::c_void, pglobalobjectid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationGroup_Impl::GlobalObjectId(this) {
+                    Ok(ok__) => {
+                        pglobalobjectid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetGlobalObjectId<Identity: windows_core::IUnknownImpl + IContactAggregationGroup_Impl>(this: *mut core::ffi::c_void, pglobalobjectid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationGroup_Impl::SetGlobalObjectId(this, core::mem::transmute_copy(&pglobalobjectid)).into()
+            }
+        }
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + IContactAggregationGroup_Impl>(this: *mut core::ffi::c_void, ppitemid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationGroup_Impl::Id(this) {
+                    Ok(ok__) => {
+                        ppitemid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + IContactAggregationGroup_Impl>(this: *mut core::ffi::c_void, ppname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationGroup_Impl::Name(this) {
+                    Ok(ok__) => {
+                        ppname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetName<Identity: windows_core::IUnknownImpl + IContactAggregationGroup_Impl>(this: *mut core::ffi::c_void, pname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationGroup_Impl::SetName(this, core::mem::transmute(&pname)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Delete: Delete::<Identity>,
+            Save: Save::<Identity>,
+            Add: Add::<Identity>,
+            Remove: Remove::<Identity>,
+            Members: Members::<Identity>,
+            GlobalObjectId: GlobalObjectId::<Identity>,
+            SetGlobalObjectId: SetGlobalObjectId::<Identity>,
+            Id: Id::<Identity>,
+            Name: Name::<Identity>,
+            SetName: SetName::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IContactAggregationGroup {}
 windows_core::imp::define_interface!(IContactAggregationGroupCollection, IContactAggregationGroupCollection_Vtbl, 0x20a19a9c_d2f3_4b83_9143_beffd2cc226d);
 windows_core::imp::interface_hierarchy!(IContactAggregationGroupCollection, windows_core::IUnknown);
@@ -1230,6 +1795,68 @@ impl IContactAggregationGroupCollection_Vtbl {
         iid == &<IContactAggregationGroupCollection as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IContactAggregationGroupCollection_Impl> windows_core::imp::TearOffVtable<Identity> for IContactAggregationGroupCollection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FindFirst<Identity: windows_core::IUnknownImpl + IContactAggregationGroupCollection_Impl>(this: *mut core::ffi::c_void, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationGroupCollection_Impl::FindFirst(this) {
+                    Ok(ok__) => {
+                        ppgroup.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindFirstByGlobalObjectId<Identity: windows_core::IUnknownImpl + IContactAggregationGroupCollection_Impl>(this: *mut core::ffi::c_void, pglobalobjectid: *const windows_core::GUID, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationGroupCollection_Impl::FindFirstByGlobalObjectId(this, core::mem::transmute_copy(&pglobalobjectid)) {
+                    Ok(ok__) => {
+                        ppgroup.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindNext<Identity: windows_core::IUnknownImpl + IContactAggregationGroupCollection_Impl>(this: *mut core::ffi::c_void, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationGroupCollection_Impl::FindNext(this) {
+                    Ok(ok__) => {
+                        ppgroup.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + IContactAggregationGroupCollection_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationGroupCollection_Impl::Count(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            FindFirst: FindFirst::<Identity>,
+            FindFirstByGlobalObjectId: FindFirstByGlobalObjectId::<Identity>,
+            FindNext: FindNext::<Identity>,
+            Count: Count::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IContactAggregationGroupCollection {}
 windows_core::imp::define_interface!(IContactAggregationLink, IContactAggregationLink_Vtbl, 0xb6813323_a183_4654_8627_79b30de3a0ec);
 windows_core::imp::interface_hierarchy!(IContactAggregationLink, windows_core::IUnknown);
@@ -1540,116 +2167,355 @@ impl IContactAggregationLink_Vtbl {
         iid == &<IContactAggregationLink as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IContactAggregationLink {}
-windows_core::imp::define_interface!(IContactAggregationLinkCollection, IContactAggregationLinkCollection_Vtbl, 0xf8bc0e93_fb55_4f28_b9fa_b1c274153292);
-windows_core::imp::interface_hierarchy!(IContactAggregationLinkCollection, windows_core::IUnknown);
-impl IContactAggregationLinkCollection {
-    pub unsafe fn FindFirst(&self) -> windows_core::Result<IContactAggregationLink> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).FindFirst)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn FindFirstByRemoteId<P0, P1>(&self, psourcetype: P0, paccountid: P1, premoteid: *const CONTACT_AGGREGATION_BLOB) -> windows_core::Result<IContactAggregationLink>
-    where
-        P0: windows_core::Param<windows_core::PCWSTR>,
-        P1: windows_core::Param<windows_core::PCWSTR>,
-    {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).FindFirstByRemoteId)(windows_core::Interface::as_raw(self), psourcetype.param().abi(), paccountid.param().abi(), premoteid, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
-    }
-    pub unsafe fn FindNext(&self) -> windows_core::Result<IContactAggregationLink> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).FindNext)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+impl<Identity: windows_core::IUnknownImpl + IContactAggregationLink_Impl> windows_core::imp::TearOffVtable<Identity> for IContactAggregationLink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IContactAggregationLink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationLink_Impl::Delete(this).into()
+            }
         }
-    }
-    pub unsafe fn Count(&self) -> windows_core::Result<u32> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).Count)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        unsafe extern "system" fn Save<Identity: windows_core::IUnknownImpl + IContactAggregationLink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationLink_Impl::Save(this).into()
+            }
         }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IContactAggregationLinkCollection_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    pub FindFirst: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub FindFirstByRemoteId: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::PCWSTR, windows_core::PCWSTR, *const CONTACT_AGGREGATION_BLOB, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub FindNext: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub Count: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
-}
-pub trait IContactAggregationLinkCollection_Impl: windows_core::IUnknownImpl {
-    fn FindFirst(&self) -> windows_core::Result<IContactAggregationLink>;
-    fn FindFirstByRemoteId(&self, psourcetype: &windows_core::PCWSTR, paccountid: &windows_core::PCWSTR, premoteid: *const CONTACT_AGGREGATION_BLOB) -> windows_core::Result<IContactAggregationLink>;
-    fn FindNext(&self) -> windows_core::Result<IContactAggregationLink>;
-    fn Count(&self) -> windows_core::Result<u32>;
-}
-impl IContactAggregationLinkCollection_Vtbl {
-    pub const fn new<Identity: IContactAggregationLinkCollection_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn FindFirst<Identity: IContactAggregationLinkCollection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppservercontactlink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn AccountId<Identity: windows_core::IUnknownImpl + IContactAggregationLink_Impl>(this: *mut core::ffi::c_void, ppaccountid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IContactAggregationLinkCollection_Impl::FindFirst(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationLink_Impl::AccountId(this) {
                     Ok(ok__) => {
-                        ppservercontactlink.write(core::mem::transmute(ok__));
+                        ppaccountid.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn FindFirstByRemoteId<Identity: IContactAggregationLinkCollection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, psourcetype: windows_core::PCWSTR, paccountid: windows_core::PCWSTR, premoteid: *const CONTACT_AGGREGATION_BLOB, ppservercontactlink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetAccountId<Identity: windows_core::IUnknownImpl + IContactAggregationLink_Impl>(this: *mut core::ffi::c_void, paccountid: windows_core::PCWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IContactAggregationLinkCollection_Impl::FindFirstByRemoteId(this, core::mem::transmute(&psourcetype), core::mem::transmute(&paccountid), core::mem::transmute_copy(&premoteid)) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationLink_Impl::SetAccountId(this, core::mem::transmute(&paccountid)).into()
+            }
+        }
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + IContactAggregationLink_Impl>(this: *mut core::ffi::c_void, ppitemid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationLink_Impl::Id(this) {
                     Ok(ok__) => {
-                        ppservercontactlink.write(core::mem::transmute(ok__));
+                        ppitemid.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn FindNext<Identity: IContactAggregationLinkCollection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppservercontactlink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn IsLinkResolved<Identity: windows_core::IUnknownImpl + IContactAggregationLink_Impl>(this: *mut core::ffi::c_void, pislinkresolved: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IContactAggregationLinkCollection_Impl::FindNext(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationLink_Impl::IsLinkResolved(this) {
                     Ok(ok__) => {
-                        ppservercontactlink.write(core::mem::transmute(ok__));
+                        pislinkresolved.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn Count<Identity: IContactAggregationLinkCollection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetIsLinkResolved<Identity: windows_core::IUnknownImpl + IContactAggregationLink_Impl>(this: *mut core::ffi::c_void, islinkresolved: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IContactAggregationLinkCollection_Impl::Count(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationLink_Impl::SetIsLinkResolved(this, core::mem::transmute_copy(&islinkresolved)).into()
+            }
+        }
+        unsafe extern "system" fn NetworkSourceIdString<Identity: windows_core::IUnknownImpl + IContactAggregationLink_Impl>(this: *mut core::ffi::c_void, ppnetworksourceid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationLink_Impl::NetworkSourceIdString(this) {
                     Ok(ok__) => {
-                        pcount.write(core::mem::transmute(ok__));
+                        ppnetworksourceid.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            FindFirst: FindFirst::<Identity, OFFSET>,
-            FindFirstByRemoteId: FindFirstByRemoteId::<Identity, OFFSET>,
-            FindNext: FindNext::<Identity, OFFSET>,
-            Count: Count::<Identity, OFFSET>,
-        }
+        unsafe extern "system" fn SetNetworkSourceIdString<Identity: windows_core::IUnknownImpl + IContactAggregationLink_Impl>(this: *mut core::ffi::c_void, pnetworksourceid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationLink_Impl::SetNetworkSourceIdString(this, core::mem::transmute(&pnetworksourceid)).into()
+            }
+        }
+        unsafe extern "system" fn RemoteObjectId<Identity: windows_core::IUnknownImpl + IContactAggregationLink_Impl>(this: *mut core::ffi::c_void, ppremoteobjectid: *mut *mut CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationLink_Impl::RemoteObjectId(this) {
+                    Ok(ok__) => {
+                        ppremoteobjectid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRemoteObjectId<Identity: windows_core::IUnknownImpl + IContactAggregationLink_Impl>(this: *mut core::ffi::c_void, premoteobjectid: *const CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationLink_Impl::SetRemoteObjectId(this, core::mem::transmute_copy(&premoteobjectid)).into()
+            }
+        }
+        unsafe extern "system" fn ServerPerson<Identity: windows_core::IUnknownImpl + IContactAggregationLink_Impl>(this: *mut core::ffi::c_void, ppserverpersonid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationLink_Impl::ServerPerson(this) {
+                    Ok(ok__) => {
+                        ppserverpersonid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetServerPerson<Identity: windows_core::IUnknownImpl + IContactAggregationLink_Impl>(this: *mut core::ffi::c_void, pserverpersonid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationLink_Impl::SetServerPerson(this, core::mem::transmute(&pserverpersonid)).into()
+            }
+        }
+        unsafe extern "system" fn ServerPersonBaseline<Identity: windows_core::IUnknownImpl + IContactAggregationLink_Impl>(this: *mut core::ffi::c_void, ppserverpersonid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationLink_Impl::ServerPersonBaseline(this) {
+                    Ok(ok__) => {
+                        ppserverpersonid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetServerPersonBaseline<Identity: windows_core::IUnknownImpl + IContactAggregationLink_Impl>(this: *mut core::ffi::c_void, pserverpersonid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationLink_Impl::SetServerPersonBaseline(this, core::mem::transmute(&pserverpersonid)).into()
+            }
+        }
+        unsafe extern "system" fn SyncIdentityHash<Identity: windows_core::IUnknownImpl + IContactAggregationLink_Impl>(this: *mut core::ffi::c_void, ppsyncidentityhash: *mut *mut CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationLink_Impl::SyncIdentityHash(this) {
+                    Ok(ok__) => {
+                        ppsyncidentityhash.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSyncIdentityHash<Identity: windows_core::IUnknownImpl + IContactAggregationLink_Impl>(this: *mut core::ffi::c_void, psyncidentityhash: *const CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationLink_Impl::SetSyncIdentityHash(this, core::mem::transmute_copy(&psyncidentityhash)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Delete: Delete::<Identity>,
+            Save: Save::<Identity>,
+            AccountId: AccountId::<Identity>,
+            SetAccountId: SetAccountId::<Identity>,
+            Id: Id::<Identity>,
+            IsLinkResolved: IsLinkResolved::<Identity>,
+            SetIsLinkResolved: SetIsLinkResolved::<Identity>,
+            NetworkSourceIdString: NetworkSourceIdString::<Identity>,
+            SetNetworkSourceIdString: SetNetworkSourceIdString::<Identity>,
+            RemoteObjectId: RemoteObjectId::<Identity>,
+            SetRemoteObjectId: SetRemoteObjectId::<Identity>,
+            ServerPerson: ServerPerson::<Identity>,
+            SetServerPerson: SetServerPerson::<Identity>,
+            ServerPersonBaseline: ServerPersonBaseline::<Identity>,
+            SetServerPersonBaseline: SetServerPersonBaseline::<Identity>,
+            SyncIdentityHash: SyncIdentityHash::<Identity>,
+            SetSyncIdentityHash: SetSyncIdentityHash::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IContactAggregationLink {}
+windows_core::imp::define_interface!(IContactAggregationLinkCollection, IContactAggregationLinkCollection_Vtbl, 0xf8bc0e93_fb55_4f28_b9fa_b1c274153292);
+windows_core::imp::interface_hierarchy!(IContactAggregationLinkCollection, windows_core::IUnknown);
+impl IContactAggregationLinkCollection {
+    pub unsafe fn FindFirst(&self) -> windows_core::Result<IContactAggregationLink> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).FindFirst)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn FindFirstByRemoteId<P0, P1>(&self, psourcetype: P0, paccountid: P1, premoteid: *const CONTACT_AGGREGATION_BLOB) -> windows_core::Result<IContactAggregationLink>
+    where
+        P0: windows_core::Param<windows_core::PCWSTR>,
+        P1: windows_core::Param<windows_core::PCWSTR>,
+    {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).FindFirstByRemoteId)(windows_core::Interface::as_raw(self), psourcetype.param().abi(), paccountid.param().abi(), premoteid, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn FindNext(&self) -> windows_core::Result<IContactAggregationLink> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).FindNext)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn Count(&self) -> windows_core::Result<u32> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).Count)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IContactAggregationLinkCollection_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub FindFirst: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub FindFirstByRemoteId: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::PCWSTR, windows_core::PCWSTR, *const CONTACT_AGGREGATION_BLOB, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub FindNext: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub Count: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u32) -> windows_core::HRESULT,
+}
+pub trait IContactAggregationLinkCollection_Impl: windows_core::IUnknownImpl {
+    fn FindFirst(&self) -> windows_core::Result<IContactAggregationLink>;
+    fn FindFirstByRemoteId(&self, psourcetype: &windows_core::PCWSTR, paccountid: &windows_core::PCWSTR, premoteid: *const CONTACT_AGGREGATION_BLOB) -> windows_core::Result<IContactAggregationLink>;
+    fn FindNext(&self) -> windows_core::Result<IContactAggregationLink>;
+    fn Count(&self) -> windows_core::Result<u32>;
+}
+impl IContactAggregationLinkCollection_Vtbl {
+    pub const fn new<Identity: IContactAggregationLinkCollection_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn FindFirst<Identity: IContactAggregationLinkCollection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppservercontactlink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IContactAggregationLinkCollection_Impl::FindFirst(this) {
+                    Ok(ok__) => {
+                        ppservercontactlink.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindFirstByRemoteId<Identity: IContactAggregationLinkCollection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, psourcetype: windows_core::PCWSTR, paccountid: windows_core::PCWSTR, premoteid: *const CONTACT_AGGREGATION_BLOB, ppservercontactlink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IContactAggregationLinkCollection_Impl::FindFirstByRemoteId(this, core::mem::transmute(&psourcetype), core::mem::transmute(&paccountid), core::mem::transmute_copy(&premoteid)) {
+                    Ok(ok__) => {
+                        ppservercontactlink.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindNext<Identity: IContactAggregationLinkCollection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppservercontactlink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IContactAggregationLinkCollection_Impl::FindNext(this) {
+                    Ok(ok__) => {
+                        ppservercontactlink.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Count<Identity: IContactAggregationLinkCollection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IContactAggregationLinkCollection_Impl::Count(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            FindFirst: FindFirst::<Identity, OFFSET>,
+            FindFirstByRemoteId: FindFirstByRemoteId::<Identity, OFFSET>,
+            FindNext: FindNext::<Identity, OFFSET>,
+            Count: Count::<Identity, OFFSET>,
+        }
     }
     pub fn matches(iid: &windows_core::GUID) -> bool {
         iid == &<IContactAggregationLinkCollection as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IContactAggregationLinkCollection_Impl> windows_core::imp::TearOffVtable<Identity> for IContactAggregationLinkCollection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FindFirst<Identity: windows_core::IUnknownImpl + IContactAggregationLinkCollection_Impl>(this: *mut core::ffi::c_void, ppservercontactlink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationLinkCollection_Impl::FindFirst(this) {
+                    Ok(ok__) => {
+                        ppservercontactlink.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindFirstByRemoteId<Identity: windows_core::IUnknownImpl + IContactAggregationLinkCollection_Impl>(this: *mut core::ffi::c_void, psourcetype: windows_core::PCWSTR, paccountid: windows_core::PCWSTR, premoteid: *const CONTACT_AGGREGATION_BLOB, ppservercontactlink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationLinkCollection_Impl::FindFirstByRemoteId(this, core::mem::transmute(&psourcetype), core::mem::transmute(&paccountid), core::mem::transmute_copy(&premoteid)) {
+                    Ok(ok__) => {
+                        ppservercontactlink.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindNext<Identity: windows_core::IUnknownImpl + IContactAggregationLinkCollection_Impl>(this: *mut core::ffi::c_void, ppservercontactlink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationLinkCollection_Impl::FindNext(this) {
+                    Ok(ok__) => {
+                        ppservercontactlink.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + IContactAggregationLinkCollection_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationLinkCollection_Impl::Count(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            FindFirst: FindFirst::<Identity>,
+            FindFirstByRemoteId: FindFirstByRemoteId::<Identity>,
+            FindNext: FindNext::<Identity>,
+            Count: Count::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IContactAggregationLinkCollection {}
 windows_core::imp::define_interface!(IContactAggregationManager, IContactAggregationManager_Vtbl, 0x1d865989_4b1f_4b60_8f34_c2ad468b2b50);
 windows_core::imp::interface_hierarchy!(IContactAggregationManager, windows_core::IUnknown);
@@ -1804,7 +2670,203 @@ impl IContactAggregationManager_Vtbl {
         }
         unsafe extern "system" fn CreateOrOpenGroup<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pgroupname: windows_core::PCWSTR, options: CONTACT_AGGREGATION_CREATE_OR_OPEN_OPTIONS, pcreatedgroup: *mut windows_core::BOOL, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IContactAggregationManager_Impl::CreateOrOpenGroup(this, core::mem::transmute(&pgroupname), core::mem::transmute_copy(&options), core::mem::transmute_copy(&pcreatedgroup)) {
+                    Ok(ok__) => {
+                        ppgroup.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateExternalContact<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IContactAggregationManager_Impl::CreateExternalContact(this) {
+                    Ok(ok__) => {
+                        ppitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateServerPerson<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppserverperson: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IContactAggregationManager_Impl::CreateServerPerson(this) {
+                    Ok(ok__) => {
+                        ppserverperson.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateServerContactLink<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppservercontactlink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IContactAggregationManager_Impl::CreateServerContactLink(this) {
+                    Ok(ok__) => {
+                        ppservercontactlink.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Flush<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IContactAggregationManager_Impl::Flush(this).into()
+            }
+        }
+        unsafe extern "system" fn OpenAggregateContact<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pitemid: windows_core::PCWSTR, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IContactAggregationManager_Impl::OpenAggregateContact(this, core::mem::transmute(&pitemid)) {
+                    Ok(ok__) => {
+                        ppitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OpenContact<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pitemid: windows_core::PCWSTR, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IContactAggregationManager_Impl::OpenContact(this, core::mem::transmute(&pitemid)) {
+                    Ok(ok__) => {
+                        ppitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OpenServerContactLink<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pitemid: windows_core::PCWSTR, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IContactAggregationManager_Impl::OpenServerContactLink(this, core::mem::transmute(&pitemid)) {
+                    Ok(ok__) => {
+                        ppitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OpenServerPerson<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pitemid: windows_core::PCWSTR, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IContactAggregationManager_Impl::OpenServerPerson(this, core::mem::transmute(&pitemid)) {
+                    Ok(ok__) => {
+                        ppitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_Contacts<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: CONTACT_AGGREGATION_COLLECTION_OPTIONS, ppitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IContactAggregationManager_Impl::get_Contacts(this, core::mem::transmute_copy(&options)) {
+                    Ok(ok__) => {
+                        ppitems.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_AggregateContacts<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: CONTACT_AGGREGATION_COLLECTION_OPTIONS, ppaggregates: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IContactAggregationManager_Impl::get_AggregateContacts(this, core::mem::transmute_copy(&options)) {
+                    Ok(ok__) => {
+                        ppaggregates.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_Groups<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: CONTACT_AGGREGATION_COLLECTION_OPTIONS, ppgroups: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IContactAggregationManager_Impl::get_Groups(this, core::mem::transmute_copy(&options)) {
+                    Ok(ok__) => {
+                        ppgroups.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ServerPersons<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppserverpersoncollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IContactAggregationManager_Impl::ServerPersons(this) {
+                    Ok(ok__) => {
+                        ppserverpersoncollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_ServerContactLinks<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppersonitemid: windows_core::PCWSTR, ppservercontactlinkcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IContactAggregationManager_Impl::get_ServerContactLinks(this, core::mem::transmute(&ppersonitemid)) {
+                    Ok(ok__) => {
+                        ppservercontactlinkcollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            GetVersionInfo: GetVersionInfo::<Identity, OFFSET>,
+            CreateOrOpenGroup: CreateOrOpenGroup::<Identity, OFFSET>,
+            CreateExternalContact: CreateExternalContact::<Identity, OFFSET>,
+            CreateServerPerson: CreateServerPerson::<Identity, OFFSET>,
+            CreateServerContactLink: CreateServerContactLink::<Identity, OFFSET>,
+            Flush: Flush::<Identity, OFFSET>,
+            OpenAggregateContact: OpenAggregateContact::<Identity, OFFSET>,
+            OpenContact: OpenContact::<Identity, OFFSET>,
+            OpenServerContactLink: OpenServerContactLink::<Identity, OFFSET>,
+            OpenServerPerson: OpenServerPerson::<Identity, OFFSET>,
+            get_Contacts: get_Contacts::<Identity, OFFSET>,
+            get_AggregateContacts: get_AggregateContacts::<Identity, OFFSET>,
+            get_Groups: get_Groups::<Identity, OFFSET>,
+            ServerPersons: ServerPersons::<Identity, OFFSET>,
+            get_ServerContactLinks: get_ServerContactLinks::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactAggregationManager as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IContactAggregationManager_Impl> windows_core::imp::TearOffVtable<Identity> for IContactAggregationManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetVersionInfo<Identity: windows_core::IUnknownImpl + IContactAggregationManager_Impl>(this: *mut core::ffi::c_void, plmajorversion: *mut i32, plminorversion: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationManager_Impl::GetVersionInfo(this, core::mem::transmute_copy(&plmajorversion), core::mem::transmute_copy(&plminorversion)).into()
+            }
+        }
+        unsafe extern "system" fn CreateOrOpenGroup<Identity: windows_core::IUnknownImpl + IContactAggregationManager_Impl>(this: *mut core::ffi::c_void, pgroupname: windows_core::PCWSTR, options: CONTACT_AGGREGATION_CREATE_OR_OPEN_OPTIONS, pcreatedgroup: *mut windows_core::BOOL, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IContactAggregationManager_Impl::CreateOrOpenGroup(this, core::mem::transmute(&pgroupname), core::mem::transmute_copy(&options), core::mem::transmute_copy(&pcreatedgroup)) {
                     Ok(ok__) => {
                         ppgroup.write(core::mem::transmute(ok__));
@@ -1814,9 +2876,9 @@ impl IContactAggregationManager_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateExternalContact<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateExternalContact<Identity: windows_core::IUnknownImpl + IContactAggregationManager_Impl>(this: *mut core::ffi::c_void, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IContactAggregationManager_Impl::CreateExternalContact(this) {
                     Ok(ok__) => {
                         ppitem.write(core::mem::transmute(ok__));
@@ -1826,9 +2888,9 @@ impl IContactAggregationManager_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateServerPerson<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppserverperson: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateServerPerson<Identity: windows_core::IUnknownImpl + IContactAggregationManager_Impl>(this: *mut core::ffi::c_void, ppserverperson: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IContactAggregationManager_Impl::CreateServerPerson(this) {
                     Ok(ok__) => {
                         ppserverperson.write(core::mem::transmute(ok__));
@@ -1838,9 +2900,9 @@ impl IContactAggregationManager_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateServerContactLink<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppservercontactlink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateServerContactLink<Identity: windows_core::IUnknownImpl + IContactAggregationManager_Impl>(this: *mut core::ffi::c_void, ppservercontactlink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IContactAggregationManager_Impl::CreateServerContactLink(this) {
                     Ok(ok__) => {
                         ppservercontactlink.write(core::mem::transmute(ok__));
@@ -1850,15 +2912,15 @@ impl IContactAggregationManager_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Flush<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Flush<Identity: windows_core::IUnknownImpl + IContactAggregationManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IContactAggregationManager_Impl::Flush(this).into()
             }
         }
-        unsafe extern "system" fn OpenAggregateContact<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pitemid: windows_core::PCWSTR, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn OpenAggregateContact<Identity: windows_core::IUnknownImpl + IContactAggregationManager_Impl>(this: *mut core::ffi::c_void, pitemid: windows_core::PCWSTR, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IContactAggregationManager_Impl::OpenAggregateContact(this, core::mem::transmute(&pitemid)) {
                     Ok(ok__) => {
                         ppitem.write(core::mem::transmute(ok__));
@@ -1868,9 +2930,9 @@ impl IContactAggregationManager_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn OpenContact<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pitemid: windows_core::PCWSTR, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn OpenContact<Identity: windows_core::IUnknownImpl + IContactAggregationManager_Impl>(this: *mut core::ffi::c_void, pitemid: windows_core::PCWSTR, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IContactAggregationManager_Impl::OpenContact(this, core::mem::transmute(&pitemid)) {
                     Ok(ok__) => {
                         ppitem.write(core::mem::transmute(ok__));
@@ -1880,9 +2942,9 @@ impl IContactAggregationManager_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn OpenServerContactLink<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pitemid: windows_core::PCWSTR, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn OpenServerContactLink<Identity: windows_core::IUnknownImpl + IContactAggregationManager_Impl>(this: *mut core::ffi::c_void, pitemid: windows_core::PCWSTR, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IContactAggregationManager_Impl::OpenServerContactLink(this, core::mem::transmute(&pitemid)) {
                     Ok(ok__) => {
                         ppitem.write(core::mem::transmute(ok__));
@@ -1892,9 +2954,9 @@ impl IContactAggregationManager_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn OpenServerPerson<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pitemid: windows_core::PCWSTR, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn OpenServerPerson<Identity: windows_core::IUnknownImpl + IContactAggregationManager_Impl>(this: *mut core::ffi::c_void, pitemid: windows_core::PCWSTR, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IContactAggregationManager_Impl::OpenServerPerson(this, core::mem::transmute(&pitemid)) {
                     Ok(ok__) => {
                         ppitem.write(core::mem::transmute(ok__));
@@ -1904,9 +2966,9 @@ impl IContactAggregationManager_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn get_Contacts<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: CONTACT_AGGREGATION_COLLECTION_OPTIONS, ppitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn get_Contacts<Identity: windows_core::IUnknownImpl + IContactAggregationManager_Impl>(this: *mut core::ffi::c_void, options: CONTACT_AGGREGATION_COLLECTION_OPTIONS, ppitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IContactAggregationManager_Impl::get_Contacts(this, core::mem::transmute_copy(&options)) {
                     Ok(ok__) => {
                         ppitems.write(core::mem::transmute(ok__));
@@ -1916,9 +2978,9 @@ impl IContactAggregationManager_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn get_AggregateContacts<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: CONTACT_AGGREGATION_COLLECTION_OPTIONS, ppaggregates: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn get_AggregateContacts<Identity: windows_core::IUnknownImpl + IContactAggregationManager_Impl>(this: *mut core::ffi::c_void, options: CONTACT_AGGREGATION_COLLECTION_OPTIONS, ppaggregates: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IContactAggregationManager_Impl::get_AggregateContacts(this, core::mem::transmute_copy(&options)) {
                     Ok(ok__) => {
                         ppaggregates.write(core::mem::transmute(ok__));
@@ -1928,9 +2990,9 @@ impl IContactAggregationManager_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn get_Groups<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, options: CONTACT_AGGREGATION_COLLECTION_OPTIONS, ppgroups: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn get_Groups<Identity: windows_core::IUnknownImpl + IContactAggregationManager_Impl>(this: *mut core::ffi::c_void, options: CONTACT_AGGREGATION_COLLECTION_OPTIONS, ppgroups: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IContactAggregationManager_Impl::get_Groups(this, core::mem::transmute_copy(&options)) {
                     Ok(ok__) => {
                         ppgroups.write(core::mem::transmute(ok__));
@@ -1940,9 +3002,9 @@ impl IContactAggregationManager_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ServerPersons<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppserverpersoncollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn ServerPersons<Identity: windows_core::IUnknownImpl + IContactAggregationManager_Impl>(this: *mut core::ffi::c_void, ppserverpersoncollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IContactAggregationManager_Impl::ServerPersons(this) {
                     Ok(ok__) => {
                         ppserverpersoncollection.write(core::mem::transmute(ok__));
@@ -1952,9 +3014,9 @@ impl IContactAggregationManager_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn get_ServerContactLinks<Identity: IContactAggregationManager_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppersonitemid: windows_core::PCWSTR, ppservercontactlinkcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn get_ServerContactLinks<Identity: windows_core::IUnknownImpl + IContactAggregationManager_Impl>(this: *mut core::ffi::c_void, ppersonitemid: windows_core::PCWSTR, ppservercontactlinkcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IContactAggregationManager_Impl::get_ServerContactLinks(this, core::mem::transmute(&ppersonitemid)) {
                     Ok(ok__) => {
                         ppservercontactlinkcollection.write(core::mem::transmute(ok__));
@@ -1965,27 +3027,24 @@ impl IContactAggregationManager_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            GetVersionInfo: GetVersionInfo::<Identity, OFFSET>,
-            CreateOrOpenGroup: CreateOrOpenGroup::<Identity, OFFSET>,
-            CreateExternalContact: CreateExternalContact::<Identity, OFFSET>,
-            CreateServerPerson: CreateServerPerson::<Identity, OFFSET>,
-            CreateServerContactLink: CreateServerContactLink::<Identity, OFFSET>,
-            Flush: Flush::<Identity, OFFSET>,
-            OpenAggregateContact: OpenAggregateContact::<Identity, OFFSET>,
-            OpenContact: OpenContact::<Identity, OFFSET>,
-            OpenServerContactLink: OpenServerContactLink::<Identity, OFFSET>,
-            OpenServerPerson: OpenServerPerson::<Identity, OFFSET>,
-            get_Contacts: get_Contacts::<Identity, OFFSET>,
-            get_AggregateContacts: get_AggregateContacts::<Identity, OFFSET>,
-            get_Groups: get_Groups::<Identity, OFFSET>,
-            ServerPersons: ServerPersons::<Identity, OFFSET>,
-            get_ServerContactLinks: get_ServerContactLinks::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IContactAggregationManager as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetVersionInfo: GetVersionInfo::<Identity>,
+            CreateOrOpenGroup: CreateOrOpenGroup::<Identity>,
+            CreateExternalContact: CreateExternalContact::<Identity>,
+            CreateServerPerson: CreateServerPerson::<Identity>,
+            CreateServerContactLink: CreateServerContactLink::<Identity>,
+            Flush: Flush::<Identity>,
+            OpenAggregateContact: OpenAggregateContact::<Identity>,
+            OpenContact: OpenContact::<Identity>,
+            OpenServerContactLink: OpenServerContactLink::<Identity>,
+            OpenServerPerson: OpenServerPerson::<Identity>,
+            get_Contacts: get_Contacts::<Identity>,
+            get_AggregateContacts: get_AggregateContacts::<Identity>,
+            get_Groups: get_Groups::<Identity>,
+            ServerPersons: ServerPersons::<Identity>,
+            get_ServerContactLinks: get_ServerContactLinks::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IContactAggregationManager {}
 windows_core::imp::define_interface!(IContactAggregationServerPerson, IContactAggregationServerPerson_Vtbl, 0x7fdc3d4b_1b82_4334_85c5_25184ee5a5f2);
@@ -2278,15 +3337,255 @@ impl IContactAggregationServerPerson_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetGroups<Identity: IContactAggregationServerPerson_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pgroups: *const CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetGroups<Identity: IContactAggregationServerPerson_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pgroups: *const CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IContactAggregationServerPerson_Impl::SetGroups(this, core::mem::transmute_copy(&pgroups)).into()
+            }
+        }
+        unsafe extern "system" fn GroupsBaseline<Identity: IContactAggregationServerPerson_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppgroups: *mut *mut CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IContactAggregationServerPerson_Impl::GroupsBaseline(this) {
+                    Ok(ok__) => {
+                        ppgroups.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetGroupsBaseline<Identity: IContactAggregationServerPerson_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pgroups: *const CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IContactAggregationServerPerson_Impl::SetGroupsBaseline(this, core::mem::transmute_copy(&pgroups)).into()
+            }
+        }
+        unsafe extern "system" fn Id<Identity: IContactAggregationServerPerson_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IContactAggregationServerPerson_Impl::Id(this) {
+                    Ok(ok__) => {
+                        ppid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsTombstone<Identity: IContactAggregationServerPerson_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pistombstone: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IContactAggregationServerPerson_Impl::IsTombstone(this) {
+                    Ok(ok__) => {
+                        pistombstone.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetIsTombstone<Identity: IContactAggregationServerPerson_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, istombstone: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IContactAggregationServerPerson_Impl::SetIsTombstone(this, core::mem::transmute_copy(&istombstone)).into()
+            }
+        }
+        unsafe extern "system" fn LinkedAggregateId<Identity: IContactAggregationServerPerson_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pplinkedaggregateid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IContactAggregationServerPerson_Impl::LinkedAggregateId(this) {
+                    Ok(ok__) => {
+                        pplinkedaggregateid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLinkedAggregateId<Identity: IContactAggregationServerPerson_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, plinkedaggregateid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IContactAggregationServerPerson_Impl::SetLinkedAggregateId(this, core::mem::transmute(&plinkedaggregateid)).into()
+            }
+        }
+        unsafe extern "system" fn ObjectId<Identity: IContactAggregationServerPerson_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppobjectid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IContactAggregationServerPerson_Impl::ObjectId(this) {
+                    Ok(ok__) => {
+                        ppobjectid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetObjectId<Identity: IContactAggregationServerPerson_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pobjectid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IContactAggregationServerPerson_Impl::SetObjectId(this, core::mem::transmute(&pobjectid)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            Delete: Delete::<Identity, OFFSET>,
+            Save: Save::<Identity, OFFSET>,
+            AggregateId: AggregateId::<Identity, OFFSET>,
+            SetAggregateId: SetAggregateId::<Identity, OFFSET>,
+            AntiLink: AntiLink::<Identity, OFFSET>,
+            SetAntiLink: SetAntiLink::<Identity, OFFSET>,
+            AntiLinkBaseline: AntiLinkBaseline::<Identity, OFFSET>,
+            SetAntiLinkBaseline: SetAntiLinkBaseline::<Identity, OFFSET>,
+            FavoriteOrder: FavoriteOrder::<Identity, OFFSET>,
+            SetFavoriteOrder: SetFavoriteOrder::<Identity, OFFSET>,
+            FavoriteOrderBaseline: FavoriteOrderBaseline::<Identity, OFFSET>,
+            SetFavoriteOrderBaseline: SetFavoriteOrderBaseline::<Identity, OFFSET>,
+            Groups: Groups::<Identity, OFFSET>,
+            SetGroups: SetGroups::<Identity, OFFSET>,
+            GroupsBaseline: GroupsBaseline::<Identity, OFFSET>,
+            SetGroupsBaseline: SetGroupsBaseline::<Identity, OFFSET>,
+            Id: Id::<Identity, OFFSET>,
+            IsTombstone: IsTombstone::<Identity, OFFSET>,
+            SetIsTombstone: SetIsTombstone::<Identity, OFFSET>,
+            LinkedAggregateId: LinkedAggregateId::<Identity, OFFSET>,
+            SetLinkedAggregateId: SetLinkedAggregateId::<Identity, OFFSET>,
+            ObjectId: ObjectId::<Identity, OFFSET>,
+            SetObjectId: SetObjectId::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactAggregationServerPerson as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl> windows_core::imp::TearOffVtable<Identity> for IContactAggregationServerPerson_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationServerPerson_Impl::Delete(this).into()
+            }
+        }
+        unsafe extern "system" fn Save<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationServerPerson_Impl::Save(this).into()
+            }
+        }
+        unsafe extern "system" fn AggregateId<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl>(this: *mut core::ffi::c_void, ppaggregateid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationServerPerson_Impl::AggregateId(this) {
+                    Ok(ok__) => {
+                        ppaggregateid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAggregateId<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl>(this: *mut core::ffi::c_void, paggregateid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationServerPerson_Impl::SetAggregateId(this, core::mem::transmute(&paggregateid)).into()
+            }
+        }
+        unsafe extern "system" fn AntiLink<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl>(this: *mut core::ffi::c_void, ppantilink: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationServerPerson_Impl::AntiLink(this) {
+                    Ok(ok__) => {
+                        ppantilink.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAntiLink<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl>(this: *mut core::ffi::c_void, pantilink: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationServerPerson_Impl::SetAntiLink(this, core::mem::transmute(&pantilink)).into()
+            }
+        }
+        unsafe extern "system" fn AntiLinkBaseline<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl>(this: *mut core::ffi::c_void, ppantilink: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationServerPerson_Impl::AntiLinkBaseline(this) {
+                    Ok(ok__) => {
+                        ppantilink.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAntiLinkBaseline<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl>(this: *mut core::ffi::c_void, pantilink: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationServerPerson_Impl::SetAntiLinkBaseline(this, core::mem::transmute(&pantilink)).into()
+            }
+        }
+        unsafe extern "system" fn FavoriteOrder<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl>(this: *mut core::ffi::c_void, pfavoriteorder: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationServerPerson_Impl::FavoriteOrder(this) {
+                    Ok(ok__) => {
+                        pfavoriteorder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetFavoriteOrder<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl>(this: *mut core::ffi::c_void, favoriteorder: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationServerPerson_Impl::SetFavoriteOrder(this, core::mem::transmute_copy(&favoriteorder)).into()
+            }
+        }
+        unsafe extern "system" fn FavoriteOrderBaseline<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl>(this: *mut core::ffi::c_void, pfavoriteorder: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationServerPerson_Impl::FavoriteOrderBaseline(this) {
+                    Ok(ok__) => {
+                        pfavoriteorder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetFavoriteOrderBaseline<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl>(this: *mut core::ffi::c_void, favoriteorder: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactAggregationServerPerson_Impl::SetFavoriteOrderBaseline(this, core::mem::transmute_copy(&favoriteorder)).into()
+            }
+        }
+        unsafe extern "system" fn Groups<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl>(this: *mut core::ffi::c_void, pgroups: *mut *mut CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationServerPerson_Impl::Groups(this) {
+                    Ok(ok__) => {
+                        pgroups.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetGroups<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl>(this: *mut core::ffi::c_void, pgroups: *const CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IContactAggregationServerPerson_Impl::SetGroups(this, core::mem::transmute_copy(&pgroups)).into()
             }
         }
-        unsafe extern "system" fn GroupsBaseline<Identity: IContactAggregationServerPerson_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppgroups: *mut *mut CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+        unsafe extern "system" fn GroupsBaseline<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl>(this: *mut core::ffi::c_void, ppgroups: *mut *mut CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IContactAggregationServerPerson_Impl::GroupsBaseline(this) {
                     Ok(ok__) => {
                         ppgroups.write(core::mem::transmute(ok__));
@@ -2296,15 +3595,15 @@ impl IContactAggregationServerPerson_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetGroupsBaseline<Identity: IContactAggregationServerPerson_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pgroups: *const CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetGroupsBaseline<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl>(this: *mut core::ffi::c_void, pgroups: *const CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IContactAggregationServerPerson_Impl::SetGroupsBaseline(this, core::mem::transmute_copy(&pgroups)).into()
             }
         }
-        unsafe extern "system" fn Id<Identity: IContactAggregationServerPerson_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl>(this: *mut core::ffi::c_void, ppid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IContactAggregationServerPerson_Impl::Id(this) {
                     Ok(ok__) => {
                         ppid.write(core::mem::transmute(ok__));
@@ -2314,9 +3613,9 @@ impl IContactAggregationServerPerson_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn IsTombstone<Identity: IContactAggregationServerPerson_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pistombstone: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn IsTombstone<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl>(this: *mut core::ffi::c_void, pistombstone: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IContactAggregationServerPerson_Impl::IsTombstone(this) {
                     Ok(ok__) => {
                         pistombstone.write(core::mem::transmute(ok__));
@@ -2326,15 +3625,15 @@ impl IContactAggregationServerPerson_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetIsTombstone<Identity: IContactAggregationServerPerson_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, istombstone: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetIsTombstone<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl>(this: *mut core::ffi::c_void, istombstone: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IContactAggregationServerPerson_Impl::SetIsTombstone(this, core::mem::transmute_copy(&istombstone)).into()
             }
         }
-        unsafe extern "system" fn LinkedAggregateId<Identity: IContactAggregationServerPerson_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pplinkedaggregateid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn LinkedAggregateId<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl>(this: *mut core::ffi::c_void, pplinkedaggregateid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IContactAggregationServerPerson_Impl::LinkedAggregateId(this) {
                     Ok(ok__) => {
                         pplinkedaggregateid.write(core::mem::transmute(ok__));
@@ -2344,15 +3643,15 @@ impl IContactAggregationServerPerson_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetLinkedAggregateId<Identity: IContactAggregationServerPerson_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, plinkedaggregateid: windows_core::PCWSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetLinkedAggregateId<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl>(this: *mut core::ffi::c_void, plinkedaggregateid: windows_core::PCWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IContactAggregationServerPerson_Impl::SetLinkedAggregateId(this, core::mem::transmute(&plinkedaggregateid)).into()
             }
         }
-        unsafe extern "system" fn ObjectId<Identity: IContactAggregationServerPerson_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppobjectid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn ObjectId<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl>(this: *mut core::ffi::c_void, ppobjectid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IContactAggregationServerPerson_Impl::ObjectId(this) {
                     Ok(ok__) => {
                         ppobjectid.write(core::mem::transmute(ok__));
@@ -2362,42 +3661,39 @@ impl IContactAggregationServerPerson_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetObjectId<Identity: IContactAggregationServerPerson_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pobjectid: windows_core::PCWSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetObjectId<Identity: windows_core::IUnknownImpl + IContactAggregationServerPerson_Impl>(this: *mut core::ffi::c_void, pobjectid: windows_core::PCWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IContactAggregationServerPerson_Impl::SetObjectId(this, core::mem::transmute(&pobjectid)).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            Delete: Delete::<Identity, OFFSET>,
-            Save: Save::<Identity, OFFSET>,
-            AggregateId: AggregateId::<Identity, OFFSET>,
-            SetAggregateId: SetAggregateId::<Identity, OFFSET>,
-            AntiLink: AntiLink::<Identity, OFFSET>,
-            SetAntiLink: SetAntiLink::<Identity, OFFSET>,
-            AntiLinkBaseline: AntiLinkBaseline::<Identity, OFFSET>,
-            SetAntiLinkBaseline: SetAntiLinkBaseline::<Identity, OFFSET>,
-            FavoriteOrder: FavoriteOrder::<Identity, OFFSET>,
-            SetFavoriteOrder: SetFavoriteOrder::<Identity, OFFSET>,
-            FavoriteOrderBaseline: FavoriteOrderBaseline::<Identity, OFFSET>,
-            SetFavoriteOrderBaseline: SetFavoriteOrderBaseline::<Identity, OFFSET>,
-            Groups: Groups::<Identity, OFFSET>,
-            SetGroups: SetGroups::<Identity, OFFSET>,
-            GroupsBaseline: GroupsBaseline::<Identity, OFFSET>,
-            SetGroupsBaseline: SetGroupsBaseline::<Identity, OFFSET>,
-            Id: Id::<Identity, OFFSET>,
-            IsTombstone: IsTombstone::<Identity, OFFSET>,
-            SetIsTombstone: SetIsTombstone::<Identity, OFFSET>,
-            LinkedAggregateId: LinkedAggregateId::<Identity, OFFSET>,
-            SetLinkedAggregateId: SetLinkedAggregateId::<Identity, OFFSET>,
-            ObjectId: ObjectId::<Identity, OFFSET>,
-            SetObjectId: SetObjectId::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IContactAggregationServerPerson as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Delete: Delete::<Identity>,
+            Save: Save::<Identity>,
+            AggregateId: AggregateId::<Identity>,
+            SetAggregateId: SetAggregateId::<Identity>,
+            AntiLink: AntiLink::<Identity>,
+            SetAntiLink: SetAntiLink::<Identity>,
+            AntiLinkBaseline: AntiLinkBaseline::<Identity>,
+            SetAntiLinkBaseline: SetAntiLinkBaseline::<Identity>,
+            FavoriteOrder: FavoriteOrder::<Identity>,
+            SetFavoriteOrder: SetFavoriteOrder::<Identity>,
+            FavoriteOrderBaseline: FavoriteOrderBaseline::<Identity>,
+            SetFavoriteOrderBaseline: SetFavoriteOrderBaseline::<Identity>,
+            Groups: Groups::<Identity>,
+            SetGroups: SetGroups::<Identity>,
+            GroupsBaseline: GroupsBaseline::<Identity>,
+            SetGroupsBaseline: SetGroupsBaseline::<Identity>,
+            Id: Id::<Identity>,
+            IsTombstone: IsTombstone::<Identity>,
+            SetIsTombstone: SetIsTombstone::<Identity>,
+            LinkedAggregateId: LinkedAggregateId::<Identity>,
+            SetLinkedAggregateId: SetLinkedAggregateId::<Identity>,
+            ObjectId: ObjectId::<Identity>,
+            SetObjectId: SetObjectId::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IContactAggregationServerPerson {}
 windows_core::imp::define_interface!(IContactAggregationServerPersonCollection, IContactAggregationServerPersonCollection_Vtbl, 0x4f730a4a_6604_47b6_a987_669ecf1e5751);
@@ -2556,6 +3852,94 @@ impl IContactAggregationServerPersonCollection_Vtbl {
         iid == &<IContactAggregationServerPersonCollection as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IContactAggregationServerPersonCollection_Impl> windows_core::imp::TearOffVtable<Identity> for IContactAggregationServerPersonCollection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FindFirst<Identity: windows_core::IUnknownImpl + IContactAggregationServerPersonCollection_Impl>(this: *mut core::ffi::c_void, ppserverperson: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationServerPersonCollection_Impl::FindFirst(this) {
+                    Ok(ok__) => {
+                        ppserverperson.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindFirstByServerId<Identity: windows_core::IUnknownImpl + IContactAggregationServerPersonCollection_Impl>(this: *mut core::ffi::c_void, pserverid: windows_core::PCWSTR, ppserverperson: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationServerPersonCollection_Impl::FindFirstByServerId(this, core::mem::transmute(&pserverid)) {
+                    Ok(ok__) => {
+                        ppserverperson.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindFirstByAggregateId<Identity: windows_core::IUnknownImpl + IContactAggregationServerPersonCollection_Impl>(this: *mut core::ffi::c_void, paggregateid: windows_core::PCWSTR, ppserverperson: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationServerPersonCollection_Impl::FindFirstByAggregateId(this, core::mem::transmute(&paggregateid)) {
+                    Ok(ok__) => {
+                        ppserverperson.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindFirstByLinkedAggregateId<Identity: windows_core::IUnknownImpl + IContactAggregationServerPersonCollection_Impl>(this: *mut core::ffi::c_void, paggregateid: windows_core::PCWSTR, ppserverperson: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationServerPersonCollection_Impl::FindFirstByLinkedAggregateId(this, core::mem::transmute(&paggregateid)) {
+                    Ok(ok__) => {
+                        ppserverperson.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FindNext<Identity: windows_core::IUnknownImpl + IContactAggregationServerPersonCollection_Impl>(this: *mut core::ffi::c_void, ppserverperson: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationServerPersonCollection_Impl::FindNext(this) {
+                    Ok(ok__) => {
+                        ppserverperson.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + IContactAggregationServerPersonCollection_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactAggregationServerPersonCollection_Impl::Count(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            FindFirst: FindFirst::<Identity>,
+            FindFirstByServerId: FindFirstByServerId::<Identity>,
+            FindFirstByAggregateId: FindFirstByAggregateId::<Identity>,
+            FindFirstByLinkedAggregateId: FindFirstByLinkedAggregateId::<Identity>,
+            FindNext: FindNext::<Identity>,
+            Count: Count::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IContactAggregationServerPersonCollection {}
 windows_core::imp::define_interface!(IContactCollection, IContactCollection_Vtbl, 0xb6afa338_d779_11d9_8bde_f66bad1e3f3a);
 windows_core::imp::interface_hierarchy!(IContactCollection, windows_core::IUnknown);
@@ -2623,6 +4007,43 @@ impl IContactCollection_Vtbl {
         iid == &<IContactCollection as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IContactCollection_Impl> windows_core::imp::TearOffVtable<Identity> for IContactCollection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IContactCollection_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactCollection_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IContactCollection_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactCollection_Impl::Next(this).into()
+            }
+        }
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IContactCollection_Impl>(this: *mut core::ffi::c_void, ppcontact: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactCollection_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        ppcontact.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Reset: Reset::<Identity>,
+            Next: Next::<Identity>,
+            GetCurrent: GetCurrent::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IContactCollection {}
 windows_core::imp::define_interface!(IContactManager, IContactManager_Vtbl, 0xad553d98_deb1_474a_8e17_fc0c2075b738);
 windows_core::imp::interface_hierarchy!(IContactManager, windows_core::IUnknown);
@@ -2758,6 +4179,76 @@ impl IContactManager_Vtbl {
         iid == &<IContactManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IContactManager_Impl> windows_core::imp::TearOffVtable<Identity> for IContactManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IContactManager_Impl>(this: *mut core::ffi::c_void, pszappname: windows_core::PCWSTR, pszappversion: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactManager_Impl::Initialize(this, core::mem::transmute(&pszappname), core::mem::transmute(&pszappversion)).into()
+            }
+        }
+        unsafe extern "system" fn Load<Identity: windows_core::IUnknownImpl + IContactManager_Impl>(this: *mut core::ffi::c_void, pszcontactid: windows_core::PCWSTR, ppcontact: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactManager_Impl::Load(this, core::mem::transmute(&pszcontactid)) {
+                    Ok(ok__) => {
+                        ppcontact.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MergeContactIDs<Identity: windows_core::IUnknownImpl + IContactManager_Impl>(this: *mut core::ffi::c_void, psznewcontactid: windows_core::PCWSTR, pszoldcontactid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactManager_Impl::MergeContactIDs(this, core::mem::transmute(&psznewcontactid), core::mem::transmute(&pszoldcontactid)).into()
+            }
+        }
+        unsafe extern "system" fn GetMeContact<Identity: windows_core::IUnknownImpl + IContactManager_Impl>(this: *mut core::ffi::c_void, ppmecontact: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactManager_Impl::GetMeContact(this) {
+                    Ok(ok__) => {
+                        ppmecontact.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMeContact<Identity: windows_core::IUnknownImpl + IContactManager_Impl>(this: *mut core::ffi::c_void, pmecontact: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactManager_Impl::SetMeContact(this, core::mem::transmute_copy(&pmecontact)).into()
+            }
+        }
+        unsafe extern "system" fn GetContactCollection<Identity: windows_core::IUnknownImpl + IContactManager_Impl>(this: *mut core::ffi::c_void, ppcontactcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IContactManager_Impl::GetContactCollection(this) {
+                    Ok(ok__) => {
+                        ppcontactcollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            Load: Load::<Identity>,
+            MergeContactIDs: MergeContactIDs::<Identity>,
+            GetMeContact: GetMeContact::<Identity>,
+            SetMeContact: SetMeContact::<Identity>,
+            GetContactCollection: GetContactCollection::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IContactManager {}
 windows_core::imp::define_interface!(IContactProperties, IContactProperties_Vtbl, 0x70dd27dd_5cbd_46e8_bef0_23b6b346288f);
 windows_core::imp::interface_hierarchy!(IContactProperties, windows_core::IUnknown);
@@ -2989,6 +4480,108 @@ impl IContactProperties_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IContactProperties_Impl> windows_core::imp::TearOffVtable<Identity> for IContactProperties_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetString<Identity: windows_core::IUnknownImpl + IContactProperties_Impl>(this: *mut core::ffi::c_void, pszpropertyname: windows_core::PCWSTR, dwflags: u32, pszvalue: windows_core::PWSTR, cchvalue: u32, pdwcchpropertyvaluerequired: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactProperties_Impl::GetString(this, core::mem::transmute(&pszpropertyname), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pszvalue), core::mem::transmute_copy(&cchvalue), core::mem::transmute_copy(&pdwcchpropertyvaluerequired)).into()
+            }
+        }
+        unsafe extern "system" fn GetDate<Identity: windows_core::IUnknownImpl + IContactProperties_Impl>(this: *mut core::ffi::c_void, pszpropertyname: windows_core::PCWSTR, dwflags: u32, pftdatetime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactProperties_Impl::GetDate(this, core::mem::transmute(&pszpropertyname), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pftdatetime)).into()
+            }
+        }
+        unsafe extern "system" fn GetBinary<Identity: windows_core::IUnknownImpl + IContactProperties_Impl>(this: *mut core::ffi::c_void, pszpropertyname: windows_core::PCWSTR, dwflags: u32, pszcontenttype: windows_core::PWSTR, cchcontenttype: u32, pdwcchcontenttyperequired: *mut u32, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactProperties_Impl::GetBinary(this, core::mem::transmute(&pszpropertyname), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pszcontenttype), core::mem::transmute_copy(&cchcontenttype), core::mem::transmute_copy(&pdwcchcontenttyperequired), core::mem::transmute_copy(&ppstream)).into()
+            }
+        }
+        unsafe extern "system" fn GetLabels<Identity: windows_core::IUnknownImpl + IContactProperties_Impl>(this: *mut core::ffi::c_void, pszarrayelementname: windows_core::PCWSTR, dwflags: u32, pszlabels: windows_core::PWSTR, cchlabels: u32, pdwcchlabelsrequired: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactProperties_Impl::GetLabels(this, core::mem::transmute(&pszarrayelementname), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pszlabels), core::mem::transmute_copy(&cchlabels), core::mem::transmute_copy(&pdwcchlabelsrequired)).into()
+            }
+        }
+        unsafe extern "system" fn SetString<Identity: windows_core::IUnknownImpl + IContactProperties_Impl>(this: *mut core::ffi::c_void, pszpropertyname: windows_core::PCWSTR, dwflags: u32, pszvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactProperties_Impl::SetString(this, core::mem::transmute(&pszpropertyname), core::mem::transmute_copy(&dwflags), core::mem::transmute(&pszvalue)).into()
+            }
+        }
+        unsafe extern "system" fn SetDate<Identity: windows_core::IUnknownImpl + IContactProperties_Impl>(this: *mut core::ffi::c_void, pszpropertyname: windows_core::PCWSTR, dwflags: u32, ftdatetime: super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactProperties_Impl::SetDate(this, core::mem::transmute(&pszpropertyname), core::mem::transmute_copy(&dwflags), core::mem::transmute(&ftdatetime)).into()
+            }
+        }
+        unsafe extern "system" fn SetBinary<Identity: windows_core::IUnknownImpl + IContactProperties_Impl>(this: *mut core::ffi::c_void, pszpropertyname: windows_core::PCWSTR, dwflags: u32, pszcontenttype: windows_core::PCWSTR, pstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactProperties_Impl::SetBinary(this, core::mem::transmute(&pszpropertyname), core::mem::transmute_copy(&dwflags), core::mem::transmute(&pszcontenttype), core::mem::transmute_copy(&pstream)).into()
+            }
+        }
+        unsafe extern "system" fn SetLabels<Identity: windows_core::IUnknownImpl + IContactProperties_Impl>(this: *mut core::ffi::c_void, pszarrayelementname: windows_core::PCWSTR, dwflags: u32, dwlabelcount: u32, ppszlabels: *const windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactProperties_Impl::SetLabels(this, core::mem::transmute(&pszarrayelementname), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&dwlabelcount), core::mem::transmute_copy(&ppszlabels)).into()
+            }
+        }
+        unsafe extern "system" fn CreateArrayNode<Identity: windows_core::IUnknownImpl + IContactProperties_Impl>(this: *mut core::ffi::c_void, pszarrayname: windows_core::PCWSTR, dwflags: u32, fappend: windows_core::BOOL, psznewarrayelementname: windows_core::PWSTR, cchnewarrayelementname: u32, pdwcchnewarrayelementnamerequired: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactProperties_Impl::CreateArrayNode(this, core::mem::transmute(&pszarrayname), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&fappend), core::mem::transmute_copy(&psznewarrayelementname), core::mem::transmute_copy(&cchnewarrayelementname), core::mem::transmute_copy(&pdwcchnewarrayelementnamerequired)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteProperty<Identity: windows_core::IUnknownImpl + IContactProperties_Impl>(this: *mut core::ffi::c_void, pszpropertyname: windows_core::PCWSTR, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactProperties_Impl::DeleteProperty(this, core::mem::transmute(&pszpropertyname), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteArrayNode<Identity: windows_core::IUnknownImpl + IContactProperties_Impl>(this: *mut core::ffi::c_void, pszarrayelementname: windows_core::PCWSTR, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactProperties_Impl::DeleteArrayNode(this, core::mem::transmute(&pszarrayelementname), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteLabels<Identity: windows_core::IUnknownImpl + IContactProperties_Impl>(this: *mut core::ffi::c_void, pszarrayelementname: windows_core::PCWSTR, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactProperties_Impl::DeleteLabels(this, core::mem::transmute(&pszarrayelementname), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn GetPropertyCollection<Identity: windows_core::IUnknownImpl + IContactProperties_Impl>(this: *mut core::ffi::c_void, pppropertycollection: *mut *mut core::ffi::c_void, dwflags: u32, pszmultivaluename: windows_core::PCWSTR, dwlabelcount: u32, ppszlabels: *const windows_core::PCWSTR, fanylabelmatches: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactProperties_Impl::GetPropertyCollection(this, core::mem::transmute_copy(&pppropertycollection), core::mem::transmute_copy(&dwflags), core::mem::transmute(&pszmultivaluename), core::mem::transmute_copy(&dwlabelcount), core::mem::transmute_copy(&ppszlabels), core::mem::transmute_copy(&fanylabelmatches)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetString: GetString::<Identity>,
+            GetDate: GetDate::<Identity>,
+            GetBinary: GetBinary::<Identity>,
+            GetLabels: GetLabels::<Identity>,
+            SetString: SetString::<Identity>,
+            SetDate: SetDate::<Identity>,
+            SetBinary: SetBinary::<Identity>,
+            SetLabels: SetLabels::<Identity>,
+            CreateArrayNode: CreateArrayNode::<Identity>,
+            DeleteProperty: DeleteProperty::<Identity>,
+            DeleteArrayNode: DeleteArrayNode::<Identity>,
+            DeleteLabels: DeleteLabels::<Identity>,
+            GetPropertyCollection: GetPropertyCollection::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IContactProperties {}
 windows_core::imp::define_interface!(IContactPropertyCollection, IContactPropertyCollection_Vtbl, 0xffd3adf8_fa64_4328_b1b6_2e0db509cb3c);
 windows_core::imp::interface_hierarchy!(IContactPropertyCollection, windows_core::IUnknown);
@@ -3095,4 +4688,63 @@ impl IContactPropertyCollection_Vtbl {
         iid == &<IContactPropertyCollection as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IContactPropertyCollection_Impl> windows_core::imp::TearOffVtable<Identity> for IContactPropertyCollection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IContactPropertyCollection_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactPropertyCollection_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IContactPropertyCollection_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactPropertyCollection_Impl::Next(this).into()
+            }
+        }
+        unsafe extern "system" fn GetPropertyName<Identity: windows_core::IUnknownImpl + IContactPropertyCollection_Impl>(this: *mut core::ffi::c_void, pszpropertyname: windows_core::PWSTR, cchpropertyname: u32, pdwcchpropertynamerequired: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactPropertyCollection_Impl::GetPropertyName(this, core::mem::transmute_copy(&pszpropertyname), core::mem::transmute_copy(&cchpropertyname), core::mem::transmute_copy(&pdwcchpropertynamerequired)).into()
+            }
+        }
+        unsafe extern "system" fn GetPropertyType<Identity: windows_core::IUnknownImpl + IContactPropertyCollection_Impl>(this: *mut core::ffi::c_void, pdwtype: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactPropertyCollection_Impl::GetPropertyType(this, core::mem::transmute_copy(&pdwtype)).into()
+            }
+        }
+        unsafe extern "system" fn GetPropertyVersion<Identity: windows_core::IUnknownImpl + IContactPropertyCollection_Impl>(this: *mut core::ffi::c_void, pdwversion: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactPropertyCollection_Impl::GetPropertyVersion(this, core::mem::transmute_copy(&pdwversion)).into()
+            }
+        }
+        unsafe extern "system" fn GetPropertyModificationDate<Identity: windows_core::IUnknownImpl + IContactPropertyCollection_Impl>(this: *mut core::ffi::c_void, pftmodificationdate: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactPropertyCollection_Impl::GetPropertyModificationDate(this, core::mem::transmute_copy(&pftmodificationdate)).into()
+            }
+        }
+        unsafe extern "system" fn GetPropertyArrayElementID<Identity: windows_core::IUnknownImpl + IContactPropertyCollection_Impl>(this: *mut core::ffi::c_void, pszarrayelementid: windows_core::PWSTR, ccharrayelementid: u32, pdwccharrayelementidrequired: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContactPropertyCollection_Impl::GetPropertyArrayElementID(this, core::mem::transmute_copy(&pszarrayelementid), core::mem::transmute_copy(&ccharrayelementid), core::mem::transmute_copy(&pdwccharrayelementidrequired)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Reset: Reset::<Identity>,
+            Next: Next::<Identity>,
+            GetPropertyName: GetPropertyName::<Identity>,
+            GetPropertyType: GetPropertyType::<Identity>,
+            GetPropertyVersion: GetPropertyVersion::<Identity>,
+            GetPropertyModificationDate: GetPropertyModificationDate::<Identity>,
+            GetPropertyArrayElementID: GetPropertyArrayElementID::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IContactPropertyCollection {}
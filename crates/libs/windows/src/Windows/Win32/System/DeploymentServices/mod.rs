@@ -700,6 +700,51 @@ impl IWdsTransportCacheable_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportCacheable_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportCacheable_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Dirty<Identity: windows_core::IUnknownImpl + IWdsTransportCacheable_Impl>(this: *mut core::ffi::c_void, pbdirty: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportCacheable_Impl::Dirty(this) {
+                    Ok(ok__) => {
+                        pbdirty.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Discard<Identity: windows_core::IUnknownImpl + IWdsTransportCacheable_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportCacheable_Impl::Discard(this).into()
+            }
+        }
+        unsafe extern "system" fn Refresh<Identity: windows_core::IUnknownImpl + IWdsTransportCacheable_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportCacheable_Impl::Refresh(this).into()
+            }
+        }
+        unsafe extern "system" fn Commit<Identity: windows_core::IUnknownImpl + IWdsTransportCacheable_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportCacheable_Impl::Commit(this).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Dirty: Dirty::<Identity>,
+            Discard: Discard::<Identity>,
+            Refresh: Refresh::<Identity>,
+            Commit: Commit::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportCacheable {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWdsTransportClient, IWdsTransportClient_Vtbl, 0xb5dbc93a_cabe_46ca_837f_3e44e93c6545);
@@ -979,6 +1024,167 @@ impl IWdsTransportClient_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportClient_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportClient_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Session<Identity: windows_core::IUnknownImpl + IWdsTransportClient_Impl>(this: *mut core::ffi::c_void, ppwdstransportsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportClient_Impl::Session(this) {
+                    Ok(ok__) => {
+                        ppwdstransportsession.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + IWdsTransportClient_Impl>(this: *mut core::ffi::c_void, pulid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportClient_Impl::Id(this) {
+                    Ok(ok__) => {
+                        pulid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + IWdsTransportClient_Impl>(this: *mut core::ffi::c_void, pbszname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportClient_Impl::Name(this) {
+                    Ok(ok__) => {
+                        pbszname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MacAddress<Identity: windows_core::IUnknownImpl + IWdsTransportClient_Impl>(this: *mut core::ffi::c_void, pbszmacaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportClient_Impl::MacAddress(this) {
+                    Ok(ok__) => {
+                        pbszmacaddress.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IpAddress<Identity: windows_core::IUnknownImpl + IWdsTransportClient_Impl>(this: *mut core::ffi::c_void, pbszipaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportClient_Impl::IpAddress(this) {
+                    Ok(ok__) => {
+                        pbszipaddress.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PercentCompletion<Identity: windows_core::IUnknownImpl + IWdsTransportClient_Impl>(this: *mut core::ffi::c_void, pulpercentcompletion: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportClient_Impl::PercentCompletion(this) {
+                    Ok(ok__) => {
+                        pulpercentcompletion.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn JoinDuration<Identity: windows_core::IUnknownImpl + IWdsTransportClient_Impl>(this: *mut core::ffi::c_void, puljoinduration: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportClient_Impl::JoinDuration(this) {
+                    Ok(ok__) => {
+                        puljoinduration.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CpuUtilization<Identity: windows_core::IUnknownImpl + IWdsTransportClient_Impl>(this: *mut core::ffi::c_void, pulcpuutilization: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportClient_Impl::CpuUtilization(this) {
+                    Ok(ok__) => {
+                        pulcpuutilization.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MemoryUtilization<Identity: windows_core::IUnknownImpl + IWdsTransportClient_Impl>(this: *mut core::ffi::c_void, pulmemoryutilization: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportClient_Impl::MemoryUtilization(this) {
+                    Ok(ok__) => {
+                        pulmemoryutilization.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NetworkUtilization<Identity: windows_core::IUnknownImpl + IWdsTransportClient_Impl>(this: *mut core::ffi::c_void, pulnetworkutilization: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportClient_Impl::NetworkUtilization(this) {
+                    Ok(ok__) => {
+                        pulnetworkutilization.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UserIdentity<Identity: windows_core::IUnknownImpl + IWdsTransportClient_Impl>(this: *mut core::ffi::c_void, pbszuseridentity: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportClient_Impl::UserIdentity(this) {
+                    Ok(ok__) => {
+                        pbszuseridentity.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Disconnect<Identity: windows_core::IUnknownImpl + IWdsTransportClient_Impl>(this: *mut core::ffi::c_void, disconnectiontype: WDSTRANSPORT_DISCONNECT_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportClient_Impl::Disconnect(this, core::mem::transmute_copy(&disconnectiontype)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Session: Session::<Identity>,
+            Id: Id::<Identity>,
+            Name: Name::<Identity>,
+            MacAddress: MacAddress::<Identity>,
+            IpAddress: IpAddress::<Identity>,
+            PercentCompletion: PercentCompletion::<Identity>,
+            JoinDuration: JoinDuration::<Identity>,
+            CpuUtilization: CpuUtilization::<Identity>,
+            MemoryUtilization: MemoryUtilization::<Identity>,
+            NetworkUtilization: NetworkUtilization::<Identity>,
+            UserIdentity: UserIdentity::<Identity>,
+            Disconnect: Disconnect::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportClient {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWdsTransportCollection, IWdsTransportCollection_Vtbl, 0xb8ba4b1a_2ff4_43ab_996c_b2b10a91a6eb);
@@ -1078,6 +1284,56 @@ impl IWdsTransportCollection_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportCollection_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportCollection_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + IWdsTransportCollection_Impl>(this: *mut core::ffi::c_void, pulcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportCollection_Impl::Count(this) {
+                    Ok(ok__) => {
+                        pulcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_Item<Identity: windows_core::IUnknownImpl + IWdsTransportCollection_Impl>(this: *mut core::ffi::c_void, ulindex: u32, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportCollection_Impl::get_Item(this, core::mem::transmute_copy(&ulindex)) {
+                    Ok(ok__) => {
+                        ppval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + IWdsTransportCollection_Impl>(this: *mut core::ffi::c_void, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportCollection_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        ppval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Count: Count::<Identity>,
+            get_Item: get_Item::<Identity>,
+            _NewEnum: _NewEnum::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportCollection {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWdsTransportConfigurationManager, IWdsTransportConfigurationManager_Vtbl, 0x84cc4779_42dd_4792_891e_1321d6d74b44);
@@ -1249,6 +1505,98 @@ impl IWdsTransportConfigurationManager_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportConfigurationManager_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportConfigurationManager_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ServicePolicy<Identity: windows_core::IUnknownImpl + IWdsTransportConfigurationManager_Impl>(this: *mut core::ffi::c_void, ppwdstransportservicepolicy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportConfigurationManager_Impl::ServicePolicy(this) {
+                    Ok(ok__) => {
+                        ppwdstransportservicepolicy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DiagnosticsPolicy<Identity: windows_core::IUnknownImpl + IWdsTransportConfigurationManager_Impl>(this: *mut core::ffi::c_void, ppwdstransportdiagnosticspolicy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportConfigurationManager_Impl::DiagnosticsPolicy(this) {
+                    Ok(ok__) => {
+                        ppwdstransportdiagnosticspolicy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_WdsTransportServicesRunning<Identity: windows_core::IUnknownImpl + IWdsTransportConfigurationManager_Impl>(this: *mut core::ffi::c_void, brealtimestatus: super::super::Foundation::VARIANT_BOOL, pbservicesrunning: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportConfigurationManager_Impl::get_WdsTransportServicesRunning(this, core::mem::transmute_copy(&brealtimestatus)) {
+                    Ok(ok__) => {
+                        pbservicesrunning.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnableWdsTransportServices<Identity: windows_core::IUnknownImpl + IWdsTransportConfigurationManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportConfigurationManager_Impl::EnableWdsTransportServices(this).into()
+            }
+        }
+        unsafe extern "system" fn DisableWdsTransportServices<Identity: windows_core::IUnknownImpl + IWdsTransportConfigurationManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportConfigurationManager_Impl::DisableWdsTransportServices(this).into()
+            }
+        }
+        unsafe extern "system" fn StartWdsTransportServices<Identity: windows_core::IUnknownImpl + IWdsTransportConfigurationManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportConfigurationManager_Impl::StartWdsTransportServices(this).into()
+            }
+        }
+        unsafe extern "system" fn StopWdsTransportServices<Identity: windows_core::IUnknownImpl + IWdsTransportConfigurationManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportConfigurationManager_Impl::StopWdsTransportServices(this).into()
+            }
+        }
+        unsafe extern "system" fn RestartWdsTransportServices<Identity: windows_core::IUnknownImpl + IWdsTransportConfigurationManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportConfigurationManager_Impl::RestartWdsTransportServices(this).into()
+            }
+        }
+        unsafe extern "system" fn NotifyWdsTransportServices<Identity: windows_core::IUnknownImpl + IWdsTransportConfigurationManager_Impl>(this: *mut core::ffi::c_void, servicenotification: WDSTRANSPORT_SERVICE_NOTIFICATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportConfigurationManager_Impl::NotifyWdsTransportServices(this, core::mem::transmute_copy(&servicenotification)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ServicePolicy: ServicePolicy::<Identity>,
+            DiagnosticsPolicy: DiagnosticsPolicy::<Identity>,
+            get_WdsTransportServicesRunning: get_WdsTransportServicesRunning::<Identity>,
+            EnableWdsTransportServices: EnableWdsTransportServices::<Identity>,
+            DisableWdsTransportServices: DisableWdsTransportServices::<Identity>,
+            StartWdsTransportServices: StartWdsTransportServices::<Identity>,
+            StopWdsTransportServices: StopWdsTransportServices::<Identity>,
+            RestartWdsTransportServices: RestartWdsTransportServices::<Identity>,
+            NotifyWdsTransportServices: NotifyWdsTransportServices::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportConfigurationManager {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWdsTransportConfigurationManager2, IWdsTransportConfigurationManager2_Vtbl, 0xd0d85caf_a153_4f1d_a9dd_96f431c50717);
@@ -1303,6 +1651,30 @@ impl IWdsTransportConfigurationManager2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportConfigurationManager2_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportConfigurationManager2_Vtbl
+where
+    IWdsTransportConfigurationManager_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn MulticastSessionPolicy<Identity: windows_core::IUnknownImpl + IWdsTransportConfigurationManager2_Impl>(this: *mut core::ffi::c_void, ppwdstransportmulticastsessionpolicy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportConfigurationManager2_Impl::MulticastSessionPolicy(this) {
+                    Ok(ok__) => {
+                        ppwdstransportmulticastsessionpolicy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWdsTransportConfigurationManager_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MulticastSessionPolicy: MulticastSessionPolicy::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportConfigurationManager2 {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWdsTransportContent, IWdsTransportContent_Vtbl, 0xd405d711_0296_4ab4_a860_ac7d32e65798);
@@ -1435,6 +1807,76 @@ impl IWdsTransportContent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportContent_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportContent_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Namespace<Identity: windows_core::IUnknownImpl + IWdsTransportContent_Impl>(this: *mut core::ffi::c_void, ppwdstransportnamespace: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportContent_Impl::Namespace(this) {
+                    Ok(ok__) => {
+                        ppwdstransportnamespace.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + IWdsTransportContent_Impl>(this: *mut core::ffi::c_void, pulid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportContent_Impl::Id(this) {
+                    Ok(ok__) => {
+                        pulid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + IWdsTransportContent_Impl>(this: *mut core::ffi::c_void, pbszname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportContent_Impl::Name(this) {
+                    Ok(ok__) => {
+                        pbszname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RetrieveSessions<Identity: windows_core::IUnknownImpl + IWdsTransportContent_Impl>(this: *mut core::ffi::c_void, ppwdstransportsessions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportContent_Impl::RetrieveSessions(this) {
+                    Ok(ok__) => {
+                        ppwdstransportsessions.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Terminate<Identity: windows_core::IUnknownImpl + IWdsTransportContent_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportContent_Impl::Terminate(this).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Namespace: Namespace::<Identity>,
+            Id: Id::<Identity>,
+            Name: Name::<Identity>,
+            RetrieveSessions: RetrieveSessions::<Identity>,
+            Terminate: Terminate::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportContent {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWdsTransportContentProvider, IWdsTransportContentProvider_Vtbl, 0xb9489f24_f219_4acf_aad7_265c7c08a6ae);
@@ -1555,6 +1997,69 @@ impl IWdsTransportContentProvider_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportContentProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportContentProvider_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + IWdsTransportContentProvider_Impl>(this: *mut core::ffi::c_void, pbszname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportContentProvider_Impl::Name(this) {
+                    Ok(ok__) => {
+                        pbszname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Description<Identity: windows_core::IUnknownImpl + IWdsTransportContentProvider_Impl>(this: *mut core::ffi::c_void, pbszdescription: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportContentProvider_Impl::Description(this) {
+                    Ok(ok__) => {
+                        pbszdescription.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FilePath<Identity: windows_core::IUnknownImpl + IWdsTransportContentProvider_Impl>(this: *mut core::ffi::c_void, pbszfilepath: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportContentProvider_Impl::FilePath(this) {
+                    Ok(ok__) => {
+                        pbszfilepath.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InitializationRoutine<Identity: windows_core::IUnknownImpl + IWdsTransportContentProvider_Impl>(this: *mut core::ffi::c_void, pbszinitializationroutine: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportContentProvider_Impl::InitializationRoutine(this) {
+                    Ok(ok__) => {
+                        pbszinitializationroutine.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Name: Name::<Identity>,
+            Description: Description::<Identity>,
+            FilePath: FilePath::<Identity>,
+            InitializationRoutine: InitializationRoutine::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportContentProvider {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWdsTransportDiagnosticsPolicy, IWdsTransportDiagnosticsPolicy_Vtbl, 0x13b33efc_7856_4f61_9a59_8de67b6b87b6);
@@ -1657,6 +2162,57 @@ impl IWdsTransportDiagnosticsPolicy_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportDiagnosticsPolicy_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportDiagnosticsPolicy_Vtbl
+where
+    IWdsTransportCacheable_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Enabled<Identity: windows_core::IUnknownImpl + IWdsTransportDiagnosticsPolicy_Impl>(this: *mut core::ffi::c_void, pbenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportDiagnosticsPolicy_Impl::Enabled(this) {
+                    Ok(ok__) => {
+                        pbenabled.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEnabled<Identity: windows_core::IUnknownImpl + IWdsTransportDiagnosticsPolicy_Impl>(this: *mut core::ffi::c_void, benabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportDiagnosticsPolicy_Impl::SetEnabled(this, core::mem::transmute_copy(&benabled)).into()
+            }
+        }
+        unsafe extern "system" fn Components<Identity: windows_core::IUnknownImpl + IWdsTransportDiagnosticsPolicy_Impl>(this: *mut core::ffi::c_void, pulcomponents: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportDiagnosticsPolicy_Impl::Components(this) {
+                    Ok(ok__) => {
+                        pulcomponents.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetComponents<Identity: windows_core::IUnknownImpl + IWdsTransportDiagnosticsPolicy_Impl>(this: *mut core::ffi::c_void, ulcomponents: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportDiagnosticsPolicy_Impl::SetComponents(this, core::mem::transmute_copy(&ulcomponents)).into()
+            }
+        }
+        Self {
+            base__: <IWdsTransportCacheable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Enabled: Enabled::<Identity>,
+            SetEnabled: SetEnabled::<Identity>,
+            Components: Components::<Identity>,
+            SetComponents: SetComponents::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportDiagnosticsPolicy {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWdsTransportManager, IWdsTransportManager_Vtbl, 0x5b0d35f5_1b13_4afd_b878_6526dc340b5d);
@@ -1711,6 +2267,30 @@ impl IWdsTransportManager_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportManager_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportManager_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetWdsTransportServer<Identity: windows_core::IUnknownImpl + IWdsTransportManager_Impl>(this: *mut core::ffi::c_void, bszservername: *mut core::ffi::c_void, ppwdstransportserver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportManager_Impl::GetWdsTransportServer(this, core::mem::transmute(&bszservername)) {
+                    Ok(ok__) => {
+                        ppwdstransportserver.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetWdsTransportServer: GetWdsTransportServer::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportManager {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWdsTransportMulticastSessionPolicy, IWdsTransportMulticastSessionPolicy_Vtbl, 0x4e5753cf_68ec_4504_a951_4a003266606b);
@@ -1788,11 +2368,105 @@ pub trait IWdsTransportMulticastSessionPolicy_Impl: IWdsTransportCacheable_Impl
     fn SetSlowClientFallback(&self, bclientfallback: super::super::Foundation::VARIANT_BOOL) -> windows_core::Result<()>;
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl IWdsTransportMulticastSessionPolicy_Vtbl {
-    pub const fn new<Identity: IWdsTransportMulticastSessionPolicy_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn SlowClientHandling<Identity: IWdsTransportMulticastSessionPolicy_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pslowclienthandling: *mut WDSTRANSPORT_SLOW_CLIENT_HANDLING_TYPE) -> windows_core::HRESULT {
+impl IWdsTransportMulticastSessionPolicy_Vtbl {
+    pub const fn new<Identity: IWdsTransportMulticastSessionPolicy_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn SlowClientHandling<Identity: IWdsTransportMulticastSessionPolicy_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pslowclienthandling: *mut WDSTRANSPORT_SLOW_CLIENT_HANDLING_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWdsTransportMulticastSessionPolicy_Impl::SlowClientHandling(this) {
+                    Ok(ok__) => {
+                        pslowclienthandling.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSlowClientHandling<Identity: IWdsTransportMulticastSessionPolicy_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, slowclienthandling: WDSTRANSPORT_SLOW_CLIENT_HANDLING_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWdsTransportMulticastSessionPolicy_Impl::SetSlowClientHandling(this, core::mem::transmute_copy(&slowclienthandling)).into()
+            }
+        }
+        unsafe extern "system" fn AutoDisconnectThreshold<Identity: IWdsTransportMulticastSessionPolicy_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pulthreshold: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWdsTransportMulticastSessionPolicy_Impl::AutoDisconnectThreshold(this) {
+                    Ok(ok__) => {
+                        pulthreshold.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAutoDisconnectThreshold<Identity: IWdsTransportMulticastSessionPolicy_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ulthreshold: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWdsTransportMulticastSessionPolicy_Impl::SetAutoDisconnectThreshold(this, core::mem::transmute_copy(&ulthreshold)).into()
+            }
+        }
+        unsafe extern "system" fn MultistreamStreamCount<Identity: IWdsTransportMulticastSessionPolicy_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pulstreamcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWdsTransportMulticastSessionPolicy_Impl::MultistreamStreamCount(this) {
+                    Ok(ok__) => {
+                        pulstreamcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMultistreamStreamCount<Identity: IWdsTransportMulticastSessionPolicy_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ulstreamcount: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWdsTransportMulticastSessionPolicy_Impl::SetMultistreamStreamCount(this, core::mem::transmute_copy(&ulstreamcount)).into()
+            }
+        }
+        unsafe extern "system" fn SlowClientFallback<Identity: IWdsTransportMulticastSessionPolicy_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbclientfallback: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWdsTransportMulticastSessionPolicy_Impl::SlowClientFallback(this) {
+                    Ok(ok__) => {
+                        pbclientfallback.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSlowClientFallback<Identity: IWdsTransportMulticastSessionPolicy_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bclientfallback: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWdsTransportMulticastSessionPolicy_Impl::SetSlowClientFallback(this, core::mem::transmute_copy(&bclientfallback)).into()
+            }
+        }
+        Self {
+            base__: IWdsTransportCacheable_Vtbl::new::<Identity, OFFSET>(),
+            SlowClientHandling: SlowClientHandling::<Identity, OFFSET>,
+            SetSlowClientHandling: SetSlowClientHandling::<Identity, OFFSET>,
+            AutoDisconnectThreshold: AutoDisconnectThreshold::<Identity, OFFSET>,
+            SetAutoDisconnectThreshold: SetAutoDisconnectThreshold::<Identity, OFFSET>,
+            MultistreamStreamCount: MultistreamStreamCount::<Identity, OFFSET>,
+            SetMultistreamStreamCount: SetMultistreamStreamCount::<Identity, OFFSET>,
+            SlowClientFallback: SlowClientFallback::<Identity, OFFSET>,
+            SetSlowClientFallback: SetSlowClientFallback::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportMulticastSessionPolicy as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID || iid == &<IWdsTransportCacheable as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportMulticastSessionPolicy_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportMulticastSessionPolicy_Vtbl
+where
+    IWdsTransportCacheable_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SlowClientHandling<Identity: windows_core::IUnknownImpl + IWdsTransportMulticastSessionPolicy_Impl>(this: *mut core::ffi::c_void, pslowclienthandling: *mut WDSTRANSPORT_SLOW_CLIENT_HANDLING_TYPE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWdsTransportMulticastSessionPolicy_Impl::SlowClientHandling(this) {
                     Ok(ok__) => {
                         pslowclienthandling.write(core::mem::transmute(ok__));
@@ -1802,15 +2476,15 @@ impl IWdsTransportMulticastSessionPolicy_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetSlowClientHandling<Identity: IWdsTransportMulticastSessionPolicy_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, slowclienthandling: WDSTRANSPORT_SLOW_CLIENT_HANDLING_TYPE) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetSlowClientHandling<Identity: windows_core::IUnknownImpl + IWdsTransportMulticastSessionPolicy_Impl>(this: *mut core::ffi::c_void, slowclienthandling: WDSTRANSPORT_SLOW_CLIENT_HANDLING_TYPE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWdsTransportMulticastSessionPolicy_Impl::SetSlowClientHandling(this, core::mem::transmute_copy(&slowclienthandling)).into()
             }
         }
-        unsafe extern "system" fn AutoDisconnectThreshold<Identity: IWdsTransportMulticastSessionPolicy_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pulthreshold: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn AutoDisconnectThreshold<Identity: windows_core::IUnknownImpl + IWdsTransportMulticastSessionPolicy_Impl>(this: *mut core::ffi::c_void, pulthreshold: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWdsTransportMulticastSessionPolicy_Impl::AutoDisconnectThreshold(this) {
                     Ok(ok__) => {
                         pulthreshold.write(core::mem::transmute(ok__));
@@ -1820,15 +2494,15 @@ impl IWdsTransportMulticastSessionPolicy_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetAutoDisconnectThreshold<Identity: IWdsTransportMulticastSessionPolicy_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ulthreshold: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetAutoDisconnectThreshold<Identity: windows_core::IUnknownImpl + IWdsTransportMulticastSessionPolicy_Impl>(this: *mut core::ffi::c_void, ulthreshold: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWdsTransportMulticastSessionPolicy_Impl::SetAutoDisconnectThreshold(this, core::mem::transmute_copy(&ulthreshold)).into()
             }
         }
-        unsafe extern "system" fn MultistreamStreamCount<Identity: IWdsTransportMulticastSessionPolicy_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pulstreamcount: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn MultistreamStreamCount<Identity: windows_core::IUnknownImpl + IWdsTransportMulticastSessionPolicy_Impl>(this: *mut core::ffi::c_void, pulstreamcount: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWdsTransportMulticastSessionPolicy_Impl::MultistreamStreamCount(this) {
                     Ok(ok__) => {
                         pulstreamcount.write(core::mem::transmute(ok__));
@@ -1838,15 +2512,15 @@ impl IWdsTransportMulticastSessionPolicy_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetMultistreamStreamCount<Identity: IWdsTransportMulticastSessionPolicy_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ulstreamcount: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetMultistreamStreamCount<Identity: windows_core::IUnknownImpl + IWdsTransportMulticastSessionPolicy_Impl>(this: *mut core::ffi::c_void, ulstreamcount: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWdsTransportMulticastSessionPolicy_Impl::SetMultistreamStreamCount(this, core::mem::transmute_copy(&ulstreamcount)).into()
             }
         }
-        unsafe extern "system" fn SlowClientFallback<Identity: IWdsTransportMulticastSessionPolicy_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbclientfallback: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SlowClientFallback<Identity: windows_core::IUnknownImpl + IWdsTransportMulticastSessionPolicy_Impl>(this: *mut core::ffi::c_void, pbclientfallback: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWdsTransportMulticastSessionPolicy_Impl::SlowClientFallback(this) {
                     Ok(ok__) => {
                         pbclientfallback.write(core::mem::transmute(ok__));
@@ -1856,27 +2530,24 @@ impl IWdsTransportMulticastSessionPolicy_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetSlowClientFallback<Identity: IWdsTransportMulticastSessionPolicy_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bclientfallback: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetSlowClientFallback<Identity: windows_core::IUnknownImpl + IWdsTransportMulticastSessionPolicy_Impl>(this: *mut core::ffi::c_void, bclientfallback: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWdsTransportMulticastSessionPolicy_Impl::SetSlowClientFallback(this, core::mem::transmute_copy(&bclientfallback)).into()
             }
         }
         Self {
-            base__: IWdsTransportCacheable_Vtbl::new::<Identity, OFFSET>(),
-            SlowClientHandling: SlowClientHandling::<Identity, OFFSET>,
-            SetSlowClientHandling: SetSlowClientHandling::<Identity, OFFSET>,
-            AutoDisconnectThreshold: AutoDisconnectThreshold::<Identity, OFFSET>,
-            SetAutoDisconnectThreshold: SetAutoDisconnectThreshold::<Identity, OFFSET>,
-            MultistreamStreamCount: MultistreamStreamCount::<Identity, OFFSET>,
-            SetMultistreamStreamCount: SetMultistreamStreamCount::<Identity, OFFSET>,
-            SlowClientFallback: SlowClientFallback::<Identity, OFFSET>,
-            SetSlowClientFallback: SetSlowClientFallback::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWdsTransportMulticastSessionPolicy as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID || iid == &<IWdsTransportCacheable as windows_core::Interface>::IID
-    }
+            base__: <IWdsTransportCacheable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SlowClientHandling: SlowClientHandling::<Identity>,
+            SetSlowClientHandling: SetSlowClientHandling::<Identity>,
+            AutoDisconnectThreshold: AutoDisconnectThreshold::<Identity>,
+            SetAutoDisconnectThreshold: SetAutoDisconnectThreshold::<Identity>,
+            MultistreamStreamCount: MultistreamStreamCount::<Identity>,
+            SetMultistreamStreamCount: SetMultistreamStreamCount::<Identity>,
+            SlowClientFallback: SlowClientFallback::<Identity>,
+            SetSlowClientFallback: SetSlowClientFallback::<Identity>,
+        }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportMulticastSessionPolicy {}
@@ -2284,6 +2955,242 @@ impl IWdsTransportNamespace_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportNamespace_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportNamespace_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Type<Identity: windows_core::IUnknownImpl + IWdsTransportNamespace_Impl>(this: *mut core::ffi::c_void, ptype: *mut WDSTRANSPORT_NAMESPACE_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportNamespace_Impl::Type(this) {
+                    Ok(ok__) => {
+                        ptype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + IWdsTransportNamespace_Impl>(this: *mut core::ffi::c_void, pulid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportNamespace_Impl::Id(this) {
+                    Ok(ok__) => {
+                        pulid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + IWdsTransportNamespace_Impl>(this: *mut core::ffi::c_void, pbszname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportNamespace_Impl::Name(this) {
+                    Ok(ok__) => {
+                        pbszname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetName<Identity: windows_core::IUnknownImpl + IWdsTransportNamespace_Impl>(this: *mut core::ffi::c_void, bszname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportNamespace_Impl::SetName(this, core::mem::transmute(&bszname)).into()
+            }
+        }
+        unsafe extern "system" fn FriendlyName<Identity: windows_core::IUnknownImpl + IWdsTransportNamespace_Impl>(this: *mut core::ffi::c_void, pbszfriendlyname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportNamespace_Impl::FriendlyName(this) {
+                    Ok(ok__) => {
+                        pbszfriendlyname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetFriendlyName<Identity: windows_core::IUnknownImpl + IWdsTransportNamespace_Impl>(this: *mut core::ffi::c_void, bszfriendlyname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportNamespace_Impl::SetFriendlyName(this, core::mem::transmute(&bszfriendlyname)).into()
+            }
+        }
+        unsafe extern "system" fn Description<Identity: windows_core::IUnknownImpl + IWdsTransportNamespace_Impl>(this: *mut core::ffi::c_void, pbszdescription: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportNamespace_Impl::Description(this) {
+                    Ok(ok__) => {
+                        pbszdescription.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDescription<Identity: windows_core::IUnknownImpl + IWdsTransportNamespace_Impl>(this: *mut core::ffi::c_void, bszdescription: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportNamespace_Impl::SetDescription(this, core::mem::transmute(&bszdescription)).into()
+            }
+        }
+        unsafe extern "system" fn ContentProvider<Identity: windows_core::IUnknownImpl + IWdsTransportNamespace_Impl>(this: *mut core::ffi::c_void, pbszcontentprovider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportNamespace_Impl::ContentProvider(this) {
+                    Ok(ok__) => {
+                        pbszcontentprovider.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetContentProvider<Identity: windows_core::IUnknownImpl + IWdsTransportNamespace_Impl>(this: *mut core::ffi::c_void, bszcontentprovider: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportNamespace_Impl::SetContentProvider(this, core::mem::transmute(&bszcontentprovider)).into()
+            }
+        }
+        unsafe extern "system" fn Configuration<Identity: windows_core::IUnknownImpl + IWdsTransportNamespace_Impl>(this: *mut core::ffi::c_void, pbszconfiguration: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportNamespace_Impl::Configuration(this) {
+                    Ok(ok__) => {
+                        pbszconfiguration.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetConfiguration<Identity: windows_core::IUnknownImpl + IWdsTransportNamespace_Impl>(this: *mut core::ffi::c_void, bszconfiguration: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportNamespace_Impl::SetConfiguration(this, core::mem::transmute(&bszconfiguration)).into()
+            }
+        }
+        unsafe extern "system" fn Registered<Identity: windows_core::IUnknownImpl + IWdsTransportNamespace_Impl>(this: *mut core::ffi::c_void, pbregistered: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportNamespace_Impl::Registered(this) {
+                    Ok(ok__) => {
+                        pbregistered.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Tombstoned<Identity: windows_core::IUnknownImpl + IWdsTransportNamespace_Impl>(this: *mut core::ffi::c_void, pbtombstoned: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportNamespace_Impl::Tombstoned(this) {
+                    Ok(ok__) => {
+                        pbtombstoned.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn TombstoneTime<Identity: windows_core::IUnknownImpl + IWdsTransportNamespace_Impl>(this: *mut core::ffi::c_void, ptombstonetime: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportNamespace_Impl::TombstoneTime(this) {
+                    Ok(ok__) => {
+                        ptombstonetime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn TransmissionStarted<Identity: windows_core::IUnknownImpl + IWdsTransportNamespace_Impl>(this: *mut core::ffi::c_void, pbtransmissionstarted: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportNamespace_Impl::TransmissionStarted(this) {
+                    Ok(ok__) => {
+                        pbtransmissionstarted.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Register<Identity: windows_core::IUnknownImpl + IWdsTransportNamespace_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportNamespace_Impl::Register(this).into()
+            }
+        }
+        unsafe extern "system" fn Deregister<Identity: windows_core::IUnknownImpl + IWdsTransportNamespace_Impl>(this: *mut core::ffi::c_void, bterminatesessions: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportNamespace_Impl::Deregister(this, core::mem::transmute_copy(&bterminatesessions)).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IWdsTransportNamespace_Impl>(this: *mut core::ffi::c_void, ppwdstransportnamespaceclone: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportNamespace_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppwdstransportnamespaceclone.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Refresh<Identity: windows_core::IUnknownImpl + IWdsTransportNamespace_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportNamespace_Impl::Refresh(this).into()
+            }
+        }
+        unsafe extern "system" fn RetrieveContents<Identity: windows_core::IUnknownImpl + IWdsTransportNamespace_Impl>(this: *mut core::ffi::c_void, ppwdstransportcontents: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportNamespace_Impl::RetrieveContents(this) {
+                    Ok(ok__) => {
+                        ppwdstransportcontents.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Type: Type::<Identity>,
+            Id: Id::<Identity>,
+            Name: Name::<Identity>,
+            SetName: SetName::<Identity>,
+            FriendlyName: FriendlyName::<Identity>,
+            SetFriendlyName: SetFriendlyName::<Identity>,
+            Description: Description::<Identity>,
+            SetDescription: SetDescription::<Identity>,
+            ContentProvider: ContentProvider::<Identity>,
+            SetContentProvider: SetContentProvider::<Identity>,
+            Configuration: Configuration::<Identity>,
+            SetConfiguration: SetConfiguration::<Identity>,
+            Registered: Registered::<Identity>,
+            Tombstoned: Tombstoned::<Identity>,
+            TombstoneTime: TombstoneTime::<Identity>,
+            TransmissionStarted: TransmissionStarted::<Identity>,
+            Register: Register::<Identity>,
+            Deregister: Deregister::<Identity>,
+            Clone: Clone::<Identity>,
+            Refresh: Refresh::<Identity>,
+            RetrieveContents: RetrieveContents::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportNamespace {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWdsTransportNamespaceAutoCast, IWdsTransportNamespaceAutoCast_Vtbl, 0xad931a72_c4bd_4c41_8fbc_59c9c748df9e);
@@ -2314,6 +3221,13 @@ impl IWdsTransportNamespaceAutoCast_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportNamespaceAutoCast_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportNamespaceAutoCast_Vtbl
+where
+    IWdsTransportNamespace_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IWdsTransportNamespace_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportNamespaceAutoCast {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWdsTransportNamespaceManager, IWdsTransportNamespaceManager_Vtbl, 0x3e22d9f6_3777_4d98_83e1_f98696717ba3);
@@ -2413,6 +3327,56 @@ impl IWdsTransportNamespaceManager_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportNamespaceManager_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportNamespaceManager_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateNamespace<Identity: windows_core::IUnknownImpl + IWdsTransportNamespaceManager_Impl>(this: *mut core::ffi::c_void, namespacetype: WDSTRANSPORT_NAMESPACE_TYPE, bsznamespacename: *mut core::ffi::c_void, bszcontentprovider: *mut core::ffi::c_void, bszconfiguration: *mut core::ffi::c_void, ppwdstransportnamespace: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportNamespaceManager_Impl::CreateNamespace(this, core::mem::transmute_copy(&namespacetype), core::mem::transmute(&bsznamespacename), core::mem::transmute(&bszcontentprovider), core::mem::transmute(&bszconfiguration)) {
+                    Ok(ok__) => {
+                        ppwdstransportnamespace.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RetrieveNamespace<Identity: windows_core::IUnknownImpl + IWdsTransportNamespaceManager_Impl>(this: *mut core::ffi::c_void, bsznamespacename: *mut core::ffi::c_void, ppwdstransportnamespace: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportNamespaceManager_Impl::RetrieveNamespace(this, core::mem::transmute(&bsznamespacename)) {
+                    Ok(ok__) => {
+                        ppwdstransportnamespace.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RetrieveNamespaces<Identity: windows_core::IUnknownImpl + IWdsTransportNamespaceManager_Impl>(this: *mut core::ffi::c_void, bszcontentprovider: *mut core::ffi::c_void, bsznamespacename: *mut core::ffi::c_void, bincludetombstones: super::super::Foundation::VARIANT_BOOL, ppwdstransportnamespaces: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportNamespaceManager_Impl::RetrieveNamespaces(this, core::mem::transmute(&bszcontentprovider), core::mem::transmute(&bsznamespacename), core::mem::transmute_copy(&bincludetombstones)) {
+                    Ok(ok__) => {
+                        ppwdstransportnamespaces.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateNamespace: CreateNamespace::<Identity>,
+            RetrieveNamespace: RetrieveNamespace::<Identity>,
+            RetrieveNamespaces: RetrieveNamespaces::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportNamespaceManager {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWdsTransportNamespaceScheduledCast, IWdsTransportNamespaceScheduledCast_Vtbl, 0x3840cecf_d76c_416e_a4cc_31c741d2874b);
@@ -2458,6 +3422,21 @@ impl IWdsTransportNamespaceScheduledCast_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportNamespaceScheduledCast_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportNamespaceScheduledCast_Vtbl
+where
+    IWdsTransportNamespace_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StartTransmission<Identity: windows_core::IUnknownImpl + IWdsTransportNamespaceScheduledCast_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportNamespaceScheduledCast_Impl::StartTransmission(this).into()
+            }
+        }
+        Self { base__: <IWdsTransportNamespace_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, StartTransmission: StartTransmission::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportNamespaceScheduledCast {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWdsTransportNamespaceScheduledCastAutoStart, IWdsTransportNamespaceScheduledCastAutoStart_Vtbl, 0xd606af3d_ea9c_4219_961e_7491d618d9b9);
@@ -2560,6 +3539,57 @@ impl IWdsTransportNamespaceScheduledCastAutoStart_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportNamespaceScheduledCastAutoStart_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportNamespaceScheduledCastAutoStart_Vtbl
+where
+    IWdsTransportNamespaceScheduledCast_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn MinimumClients<Identity: windows_core::IUnknownImpl + IWdsTransportNamespaceScheduledCastAutoStart_Impl>(this: *mut core::ffi::c_void, pulminimumclients: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportNamespaceScheduledCastAutoStart_Impl::MinimumClients(this) {
+                    Ok(ok__) => {
+                        pulminimumclients.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMinimumClients<Identity: windows_core::IUnknownImpl + IWdsTransportNamespaceScheduledCastAutoStart_Impl>(this: *mut core::ffi::c_void, ulminimumclients: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportNamespaceScheduledCastAutoStart_Impl::SetMinimumClients(this, core::mem::transmute_copy(&ulminimumclients)).into()
+            }
+        }
+        unsafe extern "system" fn StartTime<Identity: windows_core::IUnknownImpl + IWdsTransportNamespaceScheduledCastAutoStart_Impl>(this: *mut core::ffi::c_void, pstarttime: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportNamespaceScheduledCastAutoStart_Impl::StartTime(this) {
+                    Ok(ok__) => {
+                        pstarttime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetStartTime<Identity: windows_core::IUnknownImpl + IWdsTransportNamespaceScheduledCastAutoStart_Impl>(this: *mut core::ffi::c_void, starttime: f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportNamespaceScheduledCastAutoStart_Impl::SetStartTime(this, core::mem::transmute_copy(&starttime)).into()
+            }
+        }
+        Self {
+            base__: <IWdsTransportNamespaceScheduledCast_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MinimumClients: MinimumClients::<Identity>,
+            SetMinimumClients: SetMinimumClients::<Identity>,
+            StartTime: StartTime::<Identity>,
+            SetStartTime: SetStartTime::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportNamespaceScheduledCastAutoStart {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWdsTransportNamespaceScheduledCastManualStart, IWdsTransportNamespaceScheduledCastManualStart_Vtbl, 0x013e6e4c_e6a7_4fb5_b7ff_d9f5da805c31);
@@ -2590,6 +3620,13 @@ impl IWdsTransportNamespaceScheduledCastManualStart_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportNamespaceScheduledCastManualStart_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportNamespaceScheduledCastManualStart_Vtbl
+where
+    IWdsTransportNamespaceScheduledCast_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IWdsTransportNamespaceScheduledCast_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportNamespaceScheduledCastManualStart {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWdsTransportServer, IWdsTransportServer_Vtbl, 0x09ccd093_830d_4344_a30a_73ae8e8fca90);
@@ -2722,6 +3759,76 @@ impl IWdsTransportServer_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportServer_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportServer_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + IWdsTransportServer_Impl>(this: *mut core::ffi::c_void, pbszname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportServer_Impl::Name(this) {
+                    Ok(ok__) => {
+                        pbszname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetupManager<Identity: windows_core::IUnknownImpl + IWdsTransportServer_Impl>(this: *mut core::ffi::c_void, ppwdstransportsetupmanager: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportServer_Impl::SetupManager(this) {
+                    Ok(ok__) => {
+                        ppwdstransportsetupmanager.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ConfigurationManager<Identity: windows_core::IUnknownImpl + IWdsTransportServer_Impl>(this: *mut core::ffi::c_void, ppwdstransportconfigurationmanager: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportServer_Impl::ConfigurationManager(this) {
+                    Ok(ok__) => {
+                        ppwdstransportconfigurationmanager.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NamespaceManager<Identity: windows_core::IUnknownImpl + IWdsTransportServer_Impl>(this: *mut core::ffi::c_void, ppwdstransportnamespacemanager: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportServer_Impl::NamespaceManager(this) {
+                    Ok(ok__) => {
+                        ppwdstransportnamespacemanager.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DisconnectClient<Identity: windows_core::IUnknownImpl + IWdsTransportServer_Impl>(this: *mut core::ffi::c_void, ulclientid: u32, disconnectiontype: WDSTRANSPORT_DISCONNECT_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportServer_Impl::DisconnectClient(this, core::mem::transmute_copy(&ulclientid), core::mem::transmute_copy(&disconnectiontype)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Name: Name::<Identity>,
+            SetupManager: SetupManager::<Identity>,
+            ConfigurationManager: ConfigurationManager::<Identity>,
+            NamespaceManager: NamespaceManager::<Identity>,
+            DisconnectClient: DisconnectClient::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportServer {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWdsTransportServer2, IWdsTransportServer2_Vtbl, 0x256e999f_6df4_4538_81b9_857b9ab8fb47);
@@ -2776,6 +3883,27 @@ impl IWdsTransportServer2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportServer2_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportServer2_Vtbl
+where
+    IWdsTransportServer_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn TftpManager<Identity: windows_core::IUnknownImpl + IWdsTransportServer2_Impl>(this: *mut core::ffi::c_void, ppwdstransporttftpmanager: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportServer2_Impl::TftpManager(this) {
+                    Ok(ok__) => {
+                        ppwdstransporttftpmanager.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IWdsTransportServer_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, TftpManager: TftpManager::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportServer2 {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWdsTransportServicePolicy, IWdsTransportServicePolicy_Vtbl, 0xb9468578_9f2b_48cc_b27a_a60799c2750c);
@@ -3010,6 +4138,137 @@ impl IWdsTransportServicePolicy_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportServicePolicy_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportServicePolicy_Vtbl
+where
+    IWdsTransportCacheable_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn get_IpAddressSource<Identity: windows_core::IUnknownImpl + IWdsTransportServicePolicy_Impl>(this: *mut core::ffi::c_void, addresstype: WDSTRANSPORT_IP_ADDRESS_TYPE, psourcetype: *mut WDSTRANSPORT_IP_ADDRESS_SOURCE_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportServicePolicy_Impl::get_IpAddressSource(this, core::mem::transmute_copy(&addresstype)) {
+                    Ok(ok__) => {
+                        psourcetype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn put_IpAddressSource<Identity: windows_core::IUnknownImpl + IWdsTransportServicePolicy_Impl>(this: *mut core::ffi::c_void, addresstype: WDSTRANSPORT_IP_ADDRESS_TYPE, sourcetype: WDSTRANSPORT_IP_ADDRESS_SOURCE_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportServicePolicy_Impl::put_IpAddressSource(this, core::mem::transmute_copy(&addresstype), core::mem::transmute_copy(&sourcetype)).into()
+            }
+        }
+        unsafe extern "system" fn get_StartIpAddress<Identity: windows_core::IUnknownImpl + IWdsTransportServicePolicy_Impl>(this: *mut core::ffi::c_void, addresstype: WDSTRANSPORT_IP_ADDRESS_TYPE, pbszstartipaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportServicePolicy_Impl::get_StartIpAddress(this, core::mem::transmute_copy(&addresstype)) {
+                    Ok(ok__) => {
+                        pbszstartipaddress.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn put_StartIpAddress<Identity: windows_core::IUnknownImpl + IWdsTransportServicePolicy_Impl>(this: *mut core::ffi::c_void, addresstype: WDSTRANSPORT_IP_ADDRESS_TYPE, bszstartipaddress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportServicePolicy_Impl::put_StartIpAddress(this, core::mem::transmute_copy(&addresstype), core::mem::transmute(&bszstartipaddress)).into()
+            }
+        }
+        unsafe extern "system" fn get_EndIpAddress<Identity: windows_core::IUnknownImpl + IWdsTransportServicePolicy_Impl>(this: *mut core::ffi::c_void, addresstype: WDSTRANSPORT_IP_ADDRESS_TYPE, pbszendipaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportServicePolicy_Impl::get_EndIpAddress(this, core::mem::transmute_copy(&addresstype)) {
+                    Ok(ok__) => {
+                        pbszendipaddress.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn put_EndIpAddress<Identity: windows_core::IUnknownImpl + IWdsTransportServicePolicy_Impl>(this: *mut core::ffi::c_void, addresstype: WDSTRANSPORT_IP_ADDRESS_TYPE, bszendipaddress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportServicePolicy_Impl::put_EndIpAddress(this, core::mem::transmute_copy(&addresstype), core::mem::transmute(&bszendipaddress)).into()
+            }
+        }
+        unsafe extern "system" fn StartPort<Identity: windows_core::IUnknownImpl + IWdsTransportServicePolicy_Impl>(this: *mut core::ffi::c_void, pulstartport: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportServicePolicy_Impl::StartPort(this) {
+                    Ok(ok__) => {
+                        pulstartport.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetStartPort<Identity: windows_core::IUnknownImpl + IWdsTransportServicePolicy_Impl>(this: *mut core::ffi::c_void, ulstartport: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportServicePolicy_Impl::SetStartPort(this, core::mem::transmute_copy(&ulstartport)).into()
+            }
+        }
+        unsafe extern "system" fn EndPort<Identity: windows_core::IUnknownImpl + IWdsTransportServicePolicy_Impl>(this: *mut core::ffi::c_void, pulendport: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportServicePolicy_Impl::EndPort(this) {
+                    Ok(ok__) => {
+                        pulendport.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEndPort<Identity: windows_core::IUnknownImpl + IWdsTransportServicePolicy_Impl>(this: *mut core::ffi::c_void, ulendport: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportServicePolicy_Impl::SetEndPort(this, core::mem::transmute_copy(&ulendport)).into()
+            }
+        }
+        unsafe extern "system" fn NetworkProfile<Identity: windows_core::IUnknownImpl + IWdsTransportServicePolicy_Impl>(this: *mut core::ffi::c_void, pprofiletype: *mut WDSTRANSPORT_NETWORK_PROFILE_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportServicePolicy_Impl::NetworkProfile(this) {
+                    Ok(ok__) => {
+                        pprofiletype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNetworkProfile<Identity: windows_core::IUnknownImpl + IWdsTransportServicePolicy_Impl>(this: *mut core::ffi::c_void, profiletype: WDSTRANSPORT_NETWORK_PROFILE_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportServicePolicy_Impl::SetNetworkProfile(this, core::mem::transmute_copy(&profiletype)).into()
+            }
+        }
+        Self {
+            base__: <IWdsTransportCacheable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            get_IpAddressSource: get_IpAddressSource::<Identity>,
+            put_IpAddressSource: put_IpAddressSource::<Identity>,
+            get_StartIpAddress: get_StartIpAddress::<Identity>,
+            put_StartIpAddress: put_StartIpAddress::<Identity>,
+            get_EndIpAddress: get_EndIpAddress::<Identity>,
+            put_EndIpAddress: put_EndIpAddress::<Identity>,
+            StartPort: StartPort::<Identity>,
+            SetStartPort: SetStartPort::<Identity>,
+            EndPort: EndPort::<Identity>,
+            SetEndPort: SetEndPort::<Identity>,
+            NetworkProfile: NetworkProfile::<Identity>,
+            SetNetworkProfile: SetNetworkProfile::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportServicePolicy {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWdsTransportServicePolicy2, IWdsTransportServicePolicy2_Vtbl, 0x65c19e5c_aa7e_4b91_8944_91e0e5572797);
@@ -3106,15 +4365,89 @@ impl IWdsTransportServicePolicy2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetTftpMaximumBlockSize<Identity: IWdsTransportServicePolicy2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ultftpmaximumblocksize: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetTftpMaximumBlockSize<Identity: IWdsTransportServicePolicy2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ultftpmaximumblocksize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWdsTransportServicePolicy2_Impl::SetTftpMaximumBlockSize(this, core::mem::transmute_copy(&ultftpmaximumblocksize)).into()
+            }
+        }
+        unsafe extern "system" fn EnableTftpVariableWindowExtension<Identity: IWdsTransportServicePolicy2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbenabletftpvariablewindowextension: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWdsTransportServicePolicy2_Impl::EnableTftpVariableWindowExtension(this) {
+                    Ok(ok__) => {
+                        pbenabletftpvariablewindowextension.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEnableTftpVariableWindowExtension<Identity: IWdsTransportServicePolicy2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, benabletftpvariablewindowextension: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWdsTransportServicePolicy2_Impl::SetEnableTftpVariableWindowExtension(this, core::mem::transmute_copy(&benabletftpvariablewindowextension)).into()
+            }
+        }
+        Self {
+            base__: IWdsTransportServicePolicy_Vtbl::new::<Identity, OFFSET>(),
+            UdpPortPolicy: UdpPortPolicy::<Identity, OFFSET>,
+            SetUdpPortPolicy: SetUdpPortPolicy::<Identity, OFFSET>,
+            TftpMaximumBlockSize: TftpMaximumBlockSize::<Identity, OFFSET>,
+            SetTftpMaximumBlockSize: SetTftpMaximumBlockSize::<Identity, OFFSET>,
+            EnableTftpVariableWindowExtension: EnableTftpVariableWindowExtension::<Identity, OFFSET>,
+            SetEnableTftpVariableWindowExtension: SetEnableTftpVariableWindowExtension::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportServicePolicy2 as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID || iid == &<IWdsTransportCacheable as windows_core::Interface>::IID || iid == &<IWdsTransportServicePolicy as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportServicePolicy2_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportServicePolicy2_Vtbl
+where
+    IWdsTransportServicePolicy_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn UdpPortPolicy<Identity: windows_core::IUnknownImpl + IWdsTransportServicePolicy2_Impl>(this: *mut core::ffi::c_void, pudpportpolicy: *mut WDSTRANSPORT_UDP_PORT_POLICY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportServicePolicy2_Impl::UdpPortPolicy(this) {
+                    Ok(ok__) => {
+                        pudpportpolicy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetUdpPortPolicy<Identity: windows_core::IUnknownImpl + IWdsTransportServicePolicy2_Impl>(this: *mut core::ffi::c_void, udpportpolicy: WDSTRANSPORT_UDP_PORT_POLICY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportServicePolicy2_Impl::SetUdpPortPolicy(this, core::mem::transmute_copy(&udpportpolicy)).into()
+            }
+        }
+        unsafe extern "system" fn TftpMaximumBlockSize<Identity: windows_core::IUnknownImpl + IWdsTransportServicePolicy2_Impl>(this: *mut core::ffi::c_void, pultftpmaximumblocksize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportServicePolicy2_Impl::TftpMaximumBlockSize(this) {
+                    Ok(ok__) => {
+                        pultftpmaximumblocksize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTftpMaximumBlockSize<Identity: windows_core::IUnknownImpl + IWdsTransportServicePolicy2_Impl>(this: *mut core::ffi::c_void, ultftpmaximumblocksize: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWdsTransportServicePolicy2_Impl::SetTftpMaximumBlockSize(this, core::mem::transmute_copy(&ultftpmaximumblocksize)).into()
             }
         }
-        unsafe extern "system" fn EnableTftpVariableWindowExtension<Identity: IWdsTransportServicePolicy2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbenabletftpvariablewindowextension: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn EnableTftpVariableWindowExtension<Identity: windows_core::IUnknownImpl + IWdsTransportServicePolicy2_Impl>(this: *mut core::ffi::c_void, pbenabletftpvariablewindowextension: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWdsTransportServicePolicy2_Impl::EnableTftpVariableWindowExtension(this) {
                     Ok(ok__) => {
                         pbenabletftpvariablewindowextension.write(core::mem::transmute(ok__));
@@ -3124,25 +4457,22 @@ impl IWdsTransportServicePolicy2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetEnableTftpVariableWindowExtension<Identity: IWdsTransportServicePolicy2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, benabletftpvariablewindowextension: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetEnableTftpVariableWindowExtension<Identity: windows_core::IUnknownImpl + IWdsTransportServicePolicy2_Impl>(this: *mut core::ffi::c_void, benabletftpvariablewindowextension: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWdsTransportServicePolicy2_Impl::SetEnableTftpVariableWindowExtension(this, core::mem::transmute_copy(&benabletftpvariablewindowextension)).into()
             }
         }
         Self {
-            base__: IWdsTransportServicePolicy_Vtbl::new::<Identity, OFFSET>(),
-            UdpPortPolicy: UdpPortPolicy::<Identity, OFFSET>,
-            SetUdpPortPolicy: SetUdpPortPolicy::<Identity, OFFSET>,
-            TftpMaximumBlockSize: TftpMaximumBlockSize::<Identity, OFFSET>,
-            SetTftpMaximumBlockSize: SetTftpMaximumBlockSize::<Identity, OFFSET>,
-            EnableTftpVariableWindowExtension: EnableTftpVariableWindowExtension::<Identity, OFFSET>,
-            SetEnableTftpVariableWindowExtension: SetEnableTftpVariableWindowExtension::<Identity, OFFSET>,
+            base__: <IWdsTransportServicePolicy_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            UdpPortPolicy: UdpPortPolicy::<Identity>,
+            SetUdpPortPolicy: SetUdpPortPolicy::<Identity>,
+            TftpMaximumBlockSize: TftpMaximumBlockSize::<Identity>,
+            SetTftpMaximumBlockSize: SetTftpMaximumBlockSize::<Identity>,
+            EnableTftpVariableWindowExtension: EnableTftpVariableWindowExtension::<Identity>,
+            SetEnableTftpVariableWindowExtension: SetEnableTftpVariableWindowExtension::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWdsTransportServicePolicy2 as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID || iid == &<IWdsTransportCacheable as windows_core::Interface>::IID || iid == &<IWdsTransportServicePolicy as windows_core::Interface>::IID
-    }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportServicePolicy2 {}
@@ -3340,6 +4670,115 @@ impl IWdsTransportSession_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportSession_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportSession_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Content<Identity: windows_core::IUnknownImpl + IWdsTransportSession_Impl>(this: *mut core::ffi::c_void, ppwdstransportcontent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportSession_Impl::Content(this) {
+                    Ok(ok__) => {
+                        ppwdstransportcontent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + IWdsTransportSession_Impl>(this: *mut core::ffi::c_void, pulid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportSession_Impl::Id(this) {
+                    Ok(ok__) => {
+                        pulid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NetworkInterfaceName<Identity: windows_core::IUnknownImpl + IWdsTransportSession_Impl>(this: *mut core::ffi::c_void, pbsznetworkinterfacename: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportSession_Impl::NetworkInterfaceName(this) {
+                    Ok(ok__) => {
+                        pbsznetworkinterfacename.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NetworkInterfaceAddress<Identity: windows_core::IUnknownImpl + IWdsTransportSession_Impl>(this: *mut core::ffi::c_void, pbsznetworkinterfaceaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportSession_Impl::NetworkInterfaceAddress(this) {
+                    Ok(ok__) => {
+                        pbsznetworkinterfaceaddress.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn TransferRate<Identity: windows_core::IUnknownImpl + IWdsTransportSession_Impl>(this: *mut core::ffi::c_void, pultransferrate: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportSession_Impl::TransferRate(this) {
+                    Ok(ok__) => {
+                        pultransferrate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MasterClientId<Identity: windows_core::IUnknownImpl + IWdsTransportSession_Impl>(this: *mut core::ffi::c_void, pulmasterclientid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportSession_Impl::MasterClientId(this) {
+                    Ok(ok__) => {
+                        pulmasterclientid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RetrieveClients<Identity: windows_core::IUnknownImpl + IWdsTransportSession_Impl>(this: *mut core::ffi::c_void, ppwdstransportclients: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportSession_Impl::RetrieveClients(this) {
+                    Ok(ok__) => {
+                        ppwdstransportclients.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Terminate<Identity: windows_core::IUnknownImpl + IWdsTransportSession_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportSession_Impl::Terminate(this).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Content: Content::<Identity>,
+            Id: Id::<Identity>,
+            NetworkInterfaceName: NetworkInterfaceName::<Identity>,
+            NetworkInterfaceAddress: NetworkInterfaceAddress::<Identity>,
+            TransferRate: TransferRate::<Identity>,
+            MasterClientId: MasterClientId::<Identity>,
+            RetrieveClients: RetrieveClients::<Identity>,
+            Terminate: Terminate::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportSession {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWdsTransportSetupManager, IWdsTransportSetupManager_Vtbl, 0xf7238425_efa8_40a4_aef9_c98d969c0b75);
@@ -3463,6 +4902,70 @@ impl IWdsTransportSetupManager_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportSetupManager_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportSetupManager_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Version<Identity: windows_core::IUnknownImpl + IWdsTransportSetupManager_Impl>(this: *mut core::ffi::c_void, pullversion: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportSetupManager_Impl::Version(this) {
+                    Ok(ok__) => {
+                        pullversion.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InstalledFeatures<Identity: windows_core::IUnknownImpl + IWdsTransportSetupManager_Impl>(this: *mut core::ffi::c_void, pulinstalledfeatures: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportSetupManager_Impl::InstalledFeatures(this) {
+                    Ok(ok__) => {
+                        pulinstalledfeatures.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Protocols<Identity: windows_core::IUnknownImpl + IWdsTransportSetupManager_Impl>(this: *mut core::ffi::c_void, pulprotocols: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportSetupManager_Impl::Protocols(this) {
+                    Ok(ok__) => {
+                        pulprotocols.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RegisterContentProvider<Identity: windows_core::IUnknownImpl + IWdsTransportSetupManager_Impl>(this: *mut core::ffi::c_void, bszname: *mut core::ffi::c_void, bszdescription: *mut core::ffi::c_void, bszfilepath: *mut core::ffi::c_void, bszinitializationroutine: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportSetupManager_Impl::RegisterContentProvider(this, core::mem::transmute(&bszname), core::mem::transmute(&bszdescription), core::mem::transmute(&bszfilepath), core::mem::transmute(&bszinitializationroutine)).into()
+            }
+        }
+        unsafe extern "system" fn DeregisterContentProvider<Identity: windows_core::IUnknownImpl + IWdsTransportSetupManager_Impl>(this: *mut core::ffi::c_void, bszname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWdsTransportSetupManager_Impl::DeregisterContentProvider(this, core::mem::transmute(&bszname)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Version: Version::<Identity>,
+            InstalledFeatures: InstalledFeatures::<Identity>,
+            Protocols: Protocols::<Identity>,
+            RegisterContentProvider: RegisterContentProvider::<Identity>,
+            DeregisterContentProvider: DeregisterContentProvider::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportSetupManager {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWdsTransportSetupManager2, IWdsTransportSetupManager2_Vtbl, 0x02be79da_7e9e_4366_8b6e_2aa9a91be47f);
@@ -3541,6 +5044,43 @@ impl IWdsTransportSetupManager2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportSetupManager2_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportSetupManager2_Vtbl
+where
+    IWdsTransportSetupManager_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn TftpCapabilities<Identity: windows_core::IUnknownImpl + IWdsTransportSetupManager2_Impl>(this: *mut core::ffi::c_void, pultftpcapabilities: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportSetupManager2_Impl::TftpCapabilities(this) {
+                    Ok(ok__) => {
+                        pultftpcapabilities.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ContentProviders<Identity: windows_core::IUnknownImpl + IWdsTransportSetupManager2_Impl>(this: *mut core::ffi::c_void, ppprovidercollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportSetupManager2_Impl::ContentProviders(this) {
+                    Ok(ok__) => {
+                        ppprovidercollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWdsTransportSetupManager_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            TftpCapabilities: TftpCapabilities::<Identity>,
+            ContentProviders: ContentProviders::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportSetupManager2 {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWdsTransportTftpClient, IWdsTransportTftpClient_Vtbl, 0xb022d3ae_884d_4d85_b146_53320e76ef62);
@@ -3724,6 +5264,108 @@ impl IWdsTransportTftpClient_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportTftpClient_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportTftpClient_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FileName<Identity: windows_core::IUnknownImpl + IWdsTransportTftpClient_Impl>(this: *mut core::ffi::c_void, pbszfilename: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportTftpClient_Impl::FileName(this) {
+                    Ok(ok__) => {
+                        pbszfilename.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IpAddress<Identity: windows_core::IUnknownImpl + IWdsTransportTftpClient_Impl>(this: *mut core::ffi::c_void, pbszipaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportTftpClient_Impl::IpAddress(this) {
+                    Ok(ok__) => {
+                        pbszipaddress.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Timeout<Identity: windows_core::IUnknownImpl + IWdsTransportTftpClient_Impl>(this: *mut core::ffi::c_void, pultimeout: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportTftpClient_Impl::Timeout(this) {
+                    Ok(ok__) => {
+                        pultimeout.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CurrentFileOffset<Identity: windows_core::IUnknownImpl + IWdsTransportTftpClient_Impl>(this: *mut core::ffi::c_void, pul64currentoffset: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportTftpClient_Impl::CurrentFileOffset(this) {
+                    Ok(ok__) => {
+                        pul64currentoffset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FileSize<Identity: windows_core::IUnknownImpl + IWdsTransportTftpClient_Impl>(this: *mut core::ffi::c_void, pul64filesize: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportTftpClient_Impl::FileSize(this) {
+                    Ok(ok__) => {
+                        pul64filesize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn BlockSize<Identity: windows_core::IUnknownImpl + IWdsTransportTftpClient_Impl>(this: *mut core::ffi::c_void, pulblocksize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportTftpClient_Impl::BlockSize(this) {
+                    Ok(ok__) => {
+                        pulblocksize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn WindowSize<Identity: windows_core::IUnknownImpl + IWdsTransportTftpClient_Impl>(this: *mut core::ffi::c_void, pulwindowsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportTftpClient_Impl::WindowSize(this) {
+                    Ok(ok__) => {
+                        pulwindowsize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            FileName: FileName::<Identity>,
+            IpAddress: IpAddress::<Identity>,
+            Timeout: Timeout::<Identity>,
+            CurrentFileOffset: CurrentFileOffset::<Identity>,
+            FileSize: FileSize::<Identity>,
+            BlockSize: BlockSize::<Identity>,
+            WindowSize: WindowSize::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportTftpClient {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWdsTransportTftpManager, IWdsTransportTftpManager_Vtbl, 0x1327a7c8_ae8a_4fb3_8150_136227c37e9a);
@@ -3778,6 +5420,27 @@ impl IWdsTransportTftpManager_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWdsTransportTftpManager_Impl> windows_core::imp::TearOffVtable<Identity> for IWdsTransportTftpManager_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RetrieveTftpClients<Identity: windows_core::IUnknownImpl + IWdsTransportTftpManager_Impl>(this: *mut core::ffi::c_void, ppwdstransporttftpclients: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWdsTransportTftpManager_Impl::RetrieveTftpClients(this) {
+                    Ok(ok__) => {
+                        ppwdstransporttftpclients.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, RetrieveTftpClients: RetrieveTftpClients::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWdsTransportTftpManager {}
 pub const MC_SERVER_CURRENT_VERSION: u32 = 1u32;
 #[repr(transparent)]
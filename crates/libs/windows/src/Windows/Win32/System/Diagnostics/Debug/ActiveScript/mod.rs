@@ -139,6 +139,72 @@ impl AsyncIDebugApplicationNodeEvents_Vtbl {
         iid == &<AsyncIDebugApplicationNodeEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + AsyncIDebugApplicationNodeEvents_Impl> windows_core::imp::TearOffVtable<Identity> for AsyncIDebugApplicationNodeEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Begin_onAddChild<Identity: windows_core::IUnknownImpl + AsyncIDebugApplicationNodeEvents_Impl>(this: *mut core::ffi::c_void, prddpchild: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIDebugApplicationNodeEvents_Impl::Begin_onAddChild(this, core::mem::transmute_copy(&prddpchild)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_onAddChild<Identity: windows_core::IUnknownImpl + AsyncIDebugApplicationNodeEvents_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIDebugApplicationNodeEvents_Impl::Finish_onAddChild(this).into()
+            }
+        }
+        unsafe extern "system" fn Begin_onRemoveChild<Identity: windows_core::IUnknownImpl + AsyncIDebugApplicationNodeEvents_Impl>(this: *mut core::ffi::c_void, prddpchild: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIDebugApplicationNodeEvents_Impl::Begin_onRemoveChild(this, core::mem::transmute_copy(&prddpchild)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_onRemoveChild<Identity: windows_core::IUnknownImpl + AsyncIDebugApplicationNodeEvents_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIDebugApplicationNodeEvents_Impl::Finish_onRemoveChild(this).into()
+            }
+        }
+        unsafe extern "system" fn Begin_onDetach<Identity: windows_core::IUnknownImpl + AsyncIDebugApplicationNodeEvents_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIDebugApplicationNodeEvents_Impl::Begin_onDetach(this).into()
+            }
+        }
+        unsafe extern "system" fn Finish_onDetach<Identity: windows_core::IUnknownImpl + AsyncIDebugApplicationNodeEvents_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIDebugApplicationNodeEvents_Impl::Finish_onDetach(this).into()
+            }
+        }
+        unsafe extern "system" fn Begin_onAttach<Identity: windows_core::IUnknownImpl + AsyncIDebugApplicationNodeEvents_Impl>(this: *mut core::ffi::c_void, prddpparent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIDebugApplicationNodeEvents_Impl::Begin_onAttach(this, core::mem::transmute_copy(&prddpparent)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_onAttach<Identity: windows_core::IUnknownImpl + AsyncIDebugApplicationNodeEvents_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIDebugApplicationNodeEvents_Impl::Finish_onAttach(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Begin_onAddChild: Begin_onAddChild::<Identity>,
+            Finish_onAddChild: Finish_onAddChild::<Identity>,
+            Begin_onRemoveChild: Begin_onRemoveChild::<Identity>,
+            Finish_onRemoveChild: Finish_onRemoveChild::<Identity>,
+            Begin_onDetach: Begin_onDetach::<Identity>,
+            Finish_onDetach: Finish_onDetach::<Identity>,
+            Begin_onAttach: Begin_onAttach::<Identity>,
+            Finish_onAttach: Finish_onAttach::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for AsyncIDebugApplicationNodeEvents {}
 pub const BREAKPOINT_DELETED: BREAKPOINT_STATE = BREAKPOINT_STATE(0i32);
 pub const BREAKPOINT_DISABLED: BREAKPOINT_STATE = BREAKPOINT_STATE(1i32);
@@ -500,6 +566,144 @@ impl IActiveScript_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IActiveScript_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScript_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetScriptSite<Identity: windows_core::IUnknownImpl + IActiveScript_Impl>(this: *mut core::ffi::c_void, pass: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScript_Impl::SetScriptSite(this, core::mem::transmute_copy(&pass)).into()
+            }
+        }
+        unsafe extern "system" fn GetScriptSite<Identity: windows_core::IUnknownImpl + IActiveScript_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScript_Impl::GetScriptSite(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvobject)).into()
+            }
+        }
+        unsafe extern "system" fn SetScriptState<Identity: windows_core::IUnknownImpl + IActiveScript_Impl>(this: *mut core::ffi::c_void, ss: SCRIPTSTATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScript_Impl::SetScriptState(this, core::mem::transmute_copy(&ss)).into()
+            }
+        }
+        unsafe extern "system" fn GetScriptState<Identity: windows_core::IUnknownImpl + IActiveScript_Impl>(this: *mut core::ffi::c_void, pssstate: *mut SCRIPTSTATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScript_Impl::GetScriptState(this) {
+                    Ok(ok__) => {
+                        pssstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IActiveScript_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScript_Impl::Close(this).into()
+            }
+        }
+        unsafe extern "system" fn AddNamedItem<Identity: windows_core::IUnknownImpl + IActiveScript_Impl>(this: *mut core::ffi::c_void, pstrname: windows_core::PCWSTR, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScript_Impl::AddNamedItem(this, core::mem::transmute(&pstrname), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn AddTypeLib<Identity: windows_core::IUnknownImpl + IActiveScript_Impl>(this: *mut core::ffi::c_void, rguidtypelib: *const windows_core::GUID, dwmajor: u32, dwminor: u32, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScript_Impl::AddTypeLib(this, core::mem::transmute_copy(&rguidtypelib), core::mem::transmute_copy(&dwmajor), core::mem::transmute_copy(&dwminor), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn GetScriptDispatch<Identity: windows_core::IUnknownImpl + IActiveScript_Impl>(this: *mut core::ffi::c_void, pstritemname: windows_core::PCWSTR, ppdisp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScript_Impl::GetScriptDispatch(this, core::mem::transmute(&pstritemname)) {
+                    Ok(ok__) => {
+                        ppdisp.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCurrentScriptThreadID<Identity: windows_core::IUnknownImpl + IActiveScript_Impl>(this: *mut core::ffi::c_void, pstidthread: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScript_Impl::GetCurrentScriptThreadID(this) {
+                    Ok(ok__) => {
+                        pstidthread.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetScriptThreadID<Identity: windows_core::IUnknownImpl + IActiveScript_Impl>(this: *mut core::ffi::c_void, dwwin32threadid: u32, pstidthread: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScript_Impl::GetScriptThreadID(this, core::mem::transmute_copy(&dwwin32threadid)) {
+                    Ok(ok__) => {
+                        pstidthread.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetScriptThreadState<Identity: windows_core::IUnknownImpl + IActiveScript_Impl>(this: *mut core::ffi::c_void, stidthread: u32, pstsstate: *mut SCRIPTTHREADSTATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScript_Impl::GetScriptThreadState(this, core::mem::transmute_copy(&stidthread)) {
+                    Ok(ok__) => {
+                        pstsstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InterruptScriptThread<Identity: windows_core::IUnknownImpl + IActiveScript_Impl>(this: *mut core::ffi::c_void, stidthread: u32, pexcepinfo: *const super::super::super::Com::EXCEPINFO, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScript_Impl::InterruptScriptThread(this, core::mem::transmute_copy(&stidthread), core::mem::transmute_copy(&pexcepinfo), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IActiveScript_Impl>(this: *mut core::ffi::c_void, ppscript: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScript_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppscript.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetScriptSite: SetScriptSite::<Identity>,
+            GetScriptSite: GetScriptSite::<Identity>,
+            SetScriptState: SetScriptState::<Identity>,
+            GetScriptState: GetScriptState::<Identity>,
+            Close: Close::<Identity>,
+            AddNamedItem: AddNamedItem::<Identity>,
+            AddTypeLib: AddTypeLib::<Identity>,
+            GetScriptDispatch: GetScriptDispatch::<Identity>,
+            GetCurrentScriptThreadID: GetCurrentScriptThreadID::<Identity>,
+            GetScriptThreadID: GetScriptThreadID::<Identity>,
+            GetScriptThreadState: GetScriptThreadState::<Identity>,
+            InterruptScriptThread: InterruptScriptThread::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IActiveScript {}
 windows_core::imp::define_interface!(IActiveScriptAuthor, IActiveScriptAuthor_Vtbl, 0x9c109da0_7006_11d1_b36c_00a0c911e8b2);
 windows_core::imp::interface_hierarchy!(IActiveScriptAuthor, windows_core::IUnknown);
@@ -783,6 +987,145 @@ impl IActiveScriptAuthor_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptAuthor_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptAuthor_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddNamedItem<Identity: windows_core::IUnknownImpl + IActiveScriptAuthor_Impl>(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, dwflags: u32, pdisp: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptAuthor_Impl::AddNamedItem(this, core::mem::transmute(&pszname), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pdisp)).into()
+            }
+        }
+        unsafe extern "system" fn AddScriptlet<Identity: windows_core::IUnknownImpl + IActiveScriptAuthor_Impl>(this: *mut core::ffi::c_void, pszdefaultname: windows_core::PCWSTR, pszcode: windows_core::PCWSTR, pszitemname: windows_core::PCWSTR, pszsubitemname: windows_core::PCWSTR, pszeventname: windows_core::PCWSTR, pszdelimiter: windows_core::PCWSTR, dwcookie: u32, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptAuthor_Impl::AddScriptlet(this, core::mem::transmute(&pszdefaultname), core::mem::transmute(&pszcode), core::mem::transmute(&pszitemname), core::mem::transmute(&pszsubitemname), core::mem::transmute(&pszeventname), core::mem::transmute(&pszdelimiter), core::mem::transmute_copy(&dwcookie), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn ParseScriptText<Identity: windows_core::IUnknownImpl + IActiveScriptAuthor_Impl>(this: *mut core::ffi::c_void, pszcode: windows_core::PCWSTR, pszitemname: windows_core::PCWSTR, pszdelimiter: windows_core::PCWSTR, dwcookie: u32, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptAuthor_Impl::ParseScriptText(this, core::mem::transmute(&pszcode), core::mem::transmute(&pszitemname), core::mem::transmute(&pszdelimiter), core::mem::transmute_copy(&dwcookie), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn GetScriptTextAttributes<Identity: windows_core::IUnknownImpl + IActiveScriptAuthor_Impl>(this: *mut core::ffi::c_void, pszcode: windows_core::PCWSTR, cch: u32, pszdelimiter: windows_core::PCWSTR, dwflags: u32, pattr: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptAuthor_Impl::GetScriptTextAttributes(this, core::mem::transmute(&pszcode), core::mem::transmute_copy(&cch), core::mem::transmute(&pszdelimiter), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pattr)).into()
+            }
+        }
+        unsafe extern "system" fn GetScriptletTextAttributes<Identity: windows_core::IUnknownImpl + IActiveScriptAuthor_Impl>(this: *mut core::ffi::c_void, pszcode: windows_core::PCWSTR, cch: u32, pszdelimiter: windows_core::PCWSTR, dwflags: u32, pattr: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptAuthor_Impl::GetScriptletTextAttributes(this, core::mem::transmute(&pszcode), core::mem::transmute_copy(&cch), core::mem::transmute(&pszdelimiter), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pattr)).into()
+            }
+        }
+        unsafe extern "system" fn GetRoot<Identity: windows_core::IUnknownImpl + IActiveScriptAuthor_Impl>(this: *mut core::ffi::c_void, ppsp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptAuthor_Impl::GetRoot(this) {
+                    Ok(ok__) => {
+                        ppsp.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLanguageFlags<Identity: windows_core::IUnknownImpl + IActiveScriptAuthor_Impl>(this: *mut core::ffi::c_void, pgrfasa: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptAuthor_Impl::GetLanguageFlags(this) {
+                    Ok(ok__) => {
+                        pgrfasa.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetEventHandler<Identity: windows_core::IUnknownImpl + IActiveScriptAuthor_Impl>(this: *mut core::ffi::c_void, pdisp: *mut core::ffi::c_void, pszitem: windows_core::PCWSTR, pszsubitem: windows_core::PCWSTR, pszevent: windows_core::PCWSTR, ppse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptAuthor_Impl::GetEventHandler(this, core::mem::transmute_copy(&pdisp), core::mem::transmute(&pszitem), core::mem::transmute(&pszsubitem), core::mem::transmute(&pszevent)) {
+                    Ok(ok__) => {
+                        ppse.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveNamedItem<Identity: windows_core::IUnknownImpl + IActiveScriptAuthor_Impl>(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptAuthor_Impl::RemoveNamedItem(this, core::mem::transmute(&pszname)).into()
+            }
+        }
+        unsafe extern "system" fn AddTypeLib<Identity: windows_core::IUnknownImpl + IActiveScriptAuthor_Impl>(this: *mut core::ffi::c_void, rguidtypelib: *const windows_core::GUID, dwmajor: u32, dwminor: u32, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptAuthor_Impl::AddTypeLib(this, core::mem::transmute_copy(&rguidtypelib), core::mem::transmute_copy(&dwmajor), core::mem::transmute_copy(&dwminor), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveTypeLib<Identity: windows_core::IUnknownImpl + IActiveScriptAuthor_Impl>(this: *mut core::ffi::c_void, rguidtypelib: *const windows_core::GUID, dwmajor: u32, dwminor: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptAuthor_Impl::RemoveTypeLib(this, core::mem::transmute_copy(&rguidtypelib), core::mem::transmute_copy(&dwmajor), core::mem::transmute_copy(&dwminor)).into()
+            }
+        }
+        unsafe extern "system" fn GetChars<Identity: windows_core::IUnknownImpl + IActiveScriptAuthor_Impl>(this: *mut core::ffi::c_void, frequestedlist: u32, pbstrchars: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptAuthor_Impl::GetChars(this, core::mem::transmute_copy(&frequestedlist)) {
+                    Ok(ok__) => {
+                        pbstrchars.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetInfoFromContext<Identity: windows_core::IUnknownImpl + IActiveScriptAuthor_Impl>(this: *mut core::ffi::c_void, pszcode: windows_core::PCWSTR, cchcode: u32, ichcurrentposition: u32, dwlisttypesrequested: u32, pdwlisttypesprovided: *mut u32, pichlistanchorposition: *mut u32, pichfuncanchorposition: *mut u32, pmemid: *mut i32, picurrentparameter: *mut i32, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptAuthor_Impl::GetInfoFromContext(this, core::mem::transmute(&pszcode), core::mem::transmute_copy(&cchcode), core::mem::transmute_copy(&ichcurrentposition), core::mem::transmute_copy(&dwlisttypesrequested), core::mem::transmute_copy(&pdwlisttypesprovided), core::mem::transmute_copy(&pichlistanchorposition), core::mem::transmute_copy(&pichfuncanchorposition), core::mem::transmute_copy(&pmemid), core::mem::transmute_copy(&picurrentparameter), core::mem::transmute_copy(&ppunk)).into()
+            }
+        }
+        unsafe extern "system" fn IsCommitChar<Identity: windows_core::IUnknownImpl + IActiveScriptAuthor_Impl>(this: *mut core::ffi::c_void, ch: u16, pfcommit: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptAuthor_Impl::IsCommitChar(this, core::mem::transmute_copy(&ch)) {
+                    Ok(ok__) => {
+                        pfcommit.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddNamedItem: AddNamedItem::<Identity>,
+            AddScriptlet: AddScriptlet::<Identity>,
+            ParseScriptText: ParseScriptText::<Identity>,
+            GetScriptTextAttributes: GetScriptTextAttributes::<Identity>,
+            GetScriptletTextAttributes: GetScriptletTextAttributes::<Identity>,
+            GetRoot: GetRoot::<Identity>,
+            GetLanguageFlags: GetLanguageFlags::<Identity>,
+            GetEventHandler: GetEventHandler::<Identity>,
+            RemoveNamedItem: RemoveNamedItem::<Identity>,
+            AddTypeLib: AddTypeLib::<Identity>,
+            RemoveTypeLib: RemoveTypeLib::<Identity>,
+            GetChars: GetChars::<Identity>,
+            GetInfoFromContext: GetInfoFromContext::<Identity>,
+            IsCommitChar: IsCommitChar::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IActiveScriptAuthor {}
 windows_core::imp::define_interface!(IActiveScriptAuthorProcedure, IActiveScriptAuthorProcedure_Vtbl, 0x7e2d4b70_bd9a_11d0_9336_00a0c90dcaa9);
 windows_core::imp::interface_hierarchy!(IActiveScriptAuthorProcedure, windows_core::IUnknown);
@@ -829,6 +1172,21 @@ impl IActiveScriptAuthorProcedure_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptAuthorProcedure_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptAuthorProcedure_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ParseProcedureText<Identity: windows_core::IUnknownImpl + IActiveScriptAuthorProcedure_Impl>(this: *mut core::ffi::c_void, pszcode: windows_core::PCWSTR, pszformalparams: windows_core::PCWSTR, pszprocedurename: windows_core::PCWSTR, pszitemname: windows_core::PCWSTR, pszdelimiter: windows_core::PCWSTR, dwcookie: u32, dwflags: u32, pdispfor: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptAuthorProcedure_Impl::ParseProcedureText(this, core::mem::transmute(&pszcode), core::mem::transmute(&pszformalparams), core::mem::transmute(&pszprocedurename), core::mem::transmute(&pszitemname), core::mem::transmute(&pszdelimiter), core::mem::transmute_copy(&dwcookie), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pdispfor)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ParseProcedureText: ParseProcedureText::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IActiveScriptAuthorProcedure {}
 windows_core::imp::define_interface!(IActiveScriptDebug32, IActiveScriptDebug32_Vtbl, 0x51973c10_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IActiveScriptDebug32, windows_core::IUnknown);
@@ -904,6 +1262,43 @@ impl IActiveScriptDebug32_Vtbl {
         iid == &<IActiveScriptDebug32 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptDebug32_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptDebug32_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetScriptTextAttributes<Identity: windows_core::IUnknownImpl + IActiveScriptDebug32_Impl>(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, unumcodechars: u32, pstrdelimiter: windows_core::PCWSTR, dwflags: u32, pattr: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptDebug32_Impl::GetScriptTextAttributes(this, core::mem::transmute(&pstrcode), core::mem::transmute_copy(&unumcodechars), core::mem::transmute(&pstrdelimiter), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pattr)).into()
+            }
+        }
+        unsafe extern "system" fn GetScriptletTextAttributes<Identity: windows_core::IUnknownImpl + IActiveScriptDebug32_Impl>(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, unumcodechars: u32, pstrdelimiter: windows_core::PCWSTR, dwflags: u32, pattr: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptDebug32_Impl::GetScriptletTextAttributes(this, core::mem::transmute(&pstrcode), core::mem::transmute_copy(&unumcodechars), core::mem::transmute(&pstrdelimiter), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pattr)).into()
+            }
+        }
+        unsafe extern "system" fn EnumCodeContextsOfPosition<Identity: windows_core::IUnknownImpl + IActiveScriptDebug32_Impl>(this: *mut core::ffi::c_void, dwsourcecontext: u32, ucharacteroffset: u32, unumchars: u32, ppescc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptDebug32_Impl::EnumCodeContextsOfPosition(this, core::mem::transmute_copy(&dwsourcecontext), core::mem::transmute_copy(&ucharacteroffset), core::mem::transmute_copy(&unumchars)) {
+                    Ok(ok__) => {
+                        ppescc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetScriptTextAttributes: GetScriptTextAttributes::<Identity>,
+            GetScriptletTextAttributes: GetScriptletTextAttributes::<Identity>,
+            EnumCodeContextsOfPosition: EnumCodeContextsOfPosition::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IActiveScriptDebug32 {}
 windows_core::imp::define_interface!(IActiveScriptDebug64, IActiveScriptDebug64_Vtbl, 0xbc437e23_f5b8_47f4_bb79_7d1ce5483b86);
 windows_core::imp::interface_hierarchy!(IActiveScriptDebug64, windows_core::IUnknown);
@@ -979,25 +1374,62 @@ impl IActiveScriptDebug64_Vtbl {
         iid == &<IActiveScriptDebug64 as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IActiveScriptDebug64 {}
-windows_core::imp::define_interface!(IActiveScriptEncode, IActiveScriptEncode_Vtbl, 0xbb1a2ae3_a4f9_11cf_8f20_00805f2cd064);
-windows_core::imp::interface_hierarchy!(IActiveScriptEncode, windows_core::IUnknown);
-impl IActiveScriptEncode {
-    pub unsafe fn EncodeSection<P0>(&self, pchin: P0, cchin: u32, pchout: windows_core::PWSTR, cchout: u32, pcchret: *mut u32) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<windows_core::PCWSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).EncodeSection)(windows_core::Interface::as_raw(self), pchin.param().abi(), cchin, core::mem::transmute(pchout), cchout, pcchret as _).ok() }
-    }
-    pub unsafe fn DecodeScript<P0>(&self, pchin: P0, cchin: u32, pchout: windows_core::PWSTR, cchout: u32, pcchret: *mut u32) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<windows_core::PCWSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).DecodeScript)(windows_core::Interface::as_raw(self), pchin.param().abi(), cchin, core::mem::transmute(pchout), cchout, pcchret as _).ok() }
-    }
-    pub unsafe fn GetEncodeProgId(&self, pbstrout: *mut windows_core::BSTR) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetEncodeProgId)(windows_core::Interface::as_raw(self), core::mem::transmute(pbstrout)).ok() }
-    }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptDebug64_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptDebug64_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetScriptTextAttributes<Identity: windows_core::IUnknownImpl + IActiveScriptDebug64_Impl>(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, unumcodechars: u32, pstrdelimiter: windows_core::PCWSTR, dwflags: u32, pattr: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptDebug64_Impl::GetScriptTextAttributes(this, core::mem::transmute(&pstrcode), core::mem::transmute_copy(&unumcodechars), core::mem::transmute(&pstrdelimiter), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pattr)).into()
+            }
+        }
+        unsafe extern "system" fn GetScriptletTextAttributes<Identity: windows_core::IUnknownImpl + IActiveScriptDebug64_Impl>(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, unumcodechars: u32, pstrdelimiter: windows_core::PCWSTR, dwflags: u32, pattr: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptDebug64_Impl::GetScriptletTextAttributes(this, core::mem::transmute(&pstrcode), core::mem::transmute_copy(&unumcodechars), core::mem::transmute(&pstrdelimiter), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pattr)).into()
+            }
+        }
+        unsafe extern "system" fn EnumCodeContextsOfPosition<Identity: windows_core::IUnknownImpl + IActiveScriptDebug64_Impl>(this: *mut core::ffi::c_void, dwsourcecontext: u64, ucharacteroffset: u32, unumchars: u32, ppescc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptDebug64_Impl::EnumCodeContextsOfPosition(this, core::mem::transmute_copy(&dwsourcecontext), core::mem::transmute_copy(&ucharacteroffset), core::mem::transmute_copy(&unumchars)) {
+                    Ok(ok__) => {
+                        ppescc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetScriptTextAttributes: GetScriptTextAttributes::<Identity>,
+            GetScriptletTextAttributes: GetScriptletTextAttributes::<Identity>,
+            EnumCodeContextsOfPosition: EnumCodeContextsOfPosition::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IActiveScriptDebug64 {}
+windows_core::imp::define_interface!(IActiveScriptEncode, IActiveScriptEncode_Vtbl, 0xbb1a2ae3_a4f9_11cf_8f20_00805f2cd064);
+windows_core::imp::interface_hierarchy!(IActiveScriptEncode, windows_core::IUnknown);
+impl IActiveScriptEncode {
+    pub unsafe fn EncodeSection<P0>(&self, pchin: P0, cchin: u32, pchout: windows_core::PWSTR, cchout: u32, pcchret: *mut u32) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::PCWSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).EncodeSection)(windows_core::Interface::as_raw(self), pchin.param().abi(), cchin, core::mem::transmute(pchout), cchout, pcchret as _).ok() }
+    }
+    pub unsafe fn DecodeScript<P0>(&self, pchin: P0, cchin: u32, pchout: windows_core::PWSTR, cchout: u32, pcchret: *mut u32) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::PCWSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).DecodeScript)(windows_core::Interface::as_raw(self), pchin.param().abi(), cchin, core::mem::transmute(pchout), cchout, pcchret as _).ok() }
+    }
+    pub unsafe fn GetEncodeProgId(&self, pbstrout: *mut windows_core::BSTR) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetEncodeProgId)(windows_core::Interface::as_raw(self), core::mem::transmute(pbstrout)).ok() }
+    }
 }
 #[repr(C)]
 #[doc(hidden)]
@@ -1043,6 +1475,37 @@ impl IActiveScriptEncode_Vtbl {
         iid == &<IActiveScriptEncode as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptEncode_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptEncode_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EncodeSection<Identity: windows_core::IUnknownImpl + IActiveScriptEncode_Impl>(this: *mut core::ffi::c_void, pchin: windows_core::PCWSTR, cchin: u32, pchout: windows_core::PWSTR, cchout: u32, pcchret: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptEncode_Impl::EncodeSection(this, core::mem::transmute(&pchin), core::mem::transmute_copy(&cchin), core::mem::transmute_copy(&pchout), core::mem::transmute_copy(&cchout), core::mem::transmute_copy(&pcchret)).into()
+            }
+        }
+        unsafe extern "system" fn DecodeScript<Identity: windows_core::IUnknownImpl + IActiveScriptEncode_Impl>(this: *mut core::ffi::c_void, pchin: windows_core::PCWSTR, cchin: u32, pchout: windows_core::PWSTR, cchout: u32, pcchret: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptEncode_Impl::DecodeScript(this, core::mem::transmute(&pchin), core::mem::transmute_copy(&cchin), core::mem::transmute_copy(&pchout), core::mem::transmute_copy(&cchout), core::mem::transmute_copy(&pcchret)).into()
+            }
+        }
+        unsafe extern "system" fn GetEncodeProgId<Identity: windows_core::IUnknownImpl + IActiveScriptEncode_Impl>(this: *mut core::ffi::c_void, pbstrout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptEncode_Impl::GetEncodeProgId(this, core::mem::transmute_copy(&pbstrout)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EncodeSection: EncodeSection::<Identity>,
+            DecodeScript: DecodeScript::<Identity>,
+            GetEncodeProgId: GetEncodeProgId::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IActiveScriptEncode {}
 windows_core::imp::define_interface!(IActiveScriptError, IActiveScriptError_Vtbl, 0xeae1ba61_a4ed_11cf_8f20_00805f2cd064);
 windows_core::imp::interface_hierarchy!(IActiveScriptError, windows_core::IUnknown);
@@ -1117,6 +1580,44 @@ impl IActiveScriptError_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptError_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptError_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetExceptionInfo<Identity: windows_core::IUnknownImpl + IActiveScriptError_Impl>(this: *mut core::ffi::c_void, pexcepinfo: *mut super::super::super::Com::EXCEPINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptError_Impl::GetExceptionInfo(this, core::mem::transmute_copy(&pexcepinfo)).into()
+            }
+        }
+        unsafe extern "system" fn GetSourcePosition<Identity: windows_core::IUnknownImpl + IActiveScriptError_Impl>(this: *mut core::ffi::c_void, pdwsourcecontext: *mut u32, pullinenumber: *mut u32, plcharacterposition: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptError_Impl::GetSourcePosition(this, core::mem::transmute_copy(&pdwsourcecontext), core::mem::transmute_copy(&pullinenumber), core::mem::transmute_copy(&plcharacterposition)).into()
+            }
+        }
+        unsafe extern "system" fn GetSourceLineText<Identity: windows_core::IUnknownImpl + IActiveScriptError_Impl>(this: *mut core::ffi::c_void, pbstrsourceline: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptError_Impl::GetSourceLineText(this) {
+                    Ok(ok__) => {
+                        pbstrsourceline.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetExceptionInfo: GetExceptionInfo::<Identity>,
+            GetSourcePosition: GetSourcePosition::<Identity>,
+            GetSourceLineText: GetSourceLineText::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IActiveScriptError {}
 windows_core::imp::define_interface!(IActiveScriptError64, IActiveScriptError64_Vtbl, 0xb21fb2a1_5b8f_4963_8c21_21450f84ed7f);
 impl core::ops::Deref for IActiveScriptError64 {
@@ -1157,6 +1658,21 @@ impl IActiveScriptError64_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptError64_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptError64_Vtbl
+where
+    IActiveScriptError_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSourcePosition64<Identity: windows_core::IUnknownImpl + IActiveScriptError64_Impl>(this: *mut core::ffi::c_void, pdwsourcecontext: *mut u64, pullinenumber: *mut u32, plcharacterposition: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptError64_Impl::GetSourcePosition64(this, core::mem::transmute_copy(&pdwsourcecontext), core::mem::transmute_copy(&pullinenumber), core::mem::transmute_copy(&plcharacterposition)).into()
+            }
+        }
+        Self { base__: <IActiveScriptError_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetSourcePosition64: GetSourcePosition64::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IActiveScriptError64 {}
 windows_core::imp::define_interface!(IActiveScriptErrorDebug, IActiveScriptErrorDebug_Vtbl, 0x51973c12_cb0c_11d0_b5c9_00a0244a0e7a);
 impl core::ops::Deref for IActiveScriptErrorDebug {
@@ -1230,6 +1746,43 @@ impl IActiveScriptErrorDebug_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptErrorDebug_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptErrorDebug_Vtbl
+where
+    IActiveScriptError_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDocumentContext<Identity: windows_core::IUnknownImpl + IActiveScriptErrorDebug_Impl>(this: *mut core::ffi::c_void, ppssc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptErrorDebug_Impl::GetDocumentContext(this) {
+                    Ok(ok__) => {
+                        ppssc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStackFrame<Identity: windows_core::IUnknownImpl + IActiveScriptErrorDebug_Impl>(this: *mut core::ffi::c_void, ppdsf: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptErrorDebug_Impl::GetStackFrame(this) {
+                    Ok(ok__) => {
+                        ppdsf.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IActiveScriptError_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDocumentContext: GetDocumentContext::<Identity>,
+            GetStackFrame: GetStackFrame::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IActiveScriptErrorDebug {}
 windows_core::imp::define_interface!(IActiveScriptErrorDebug110, IActiveScriptErrorDebug110_Vtbl, 0x516e42b6_89a8_4530_937b_5f0708431442);
 windows_core::imp::interface_hierarchy!(IActiveScriptErrorDebug110, windows_core::IUnknown);
@@ -1270,6 +1823,29 @@ impl IActiveScriptErrorDebug110_Vtbl {
         iid == &<IActiveScriptErrorDebug110 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptErrorDebug110_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptErrorDebug110_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetExceptionThrownKind<Identity: windows_core::IUnknownImpl + IActiveScriptErrorDebug110_Impl>(this: *mut core::ffi::c_void, pexceptionkind: *mut SCRIPT_ERROR_DEBUG_EXCEPTION_THROWN_KIND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptErrorDebug110_Impl::GetExceptionThrownKind(this) {
+                    Ok(ok__) => {
+                        pexceptionkind.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetExceptionThrownKind: GetExceptionThrownKind::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IActiveScriptErrorDebug110 {}
 windows_core::imp::define_interface!(IActiveScriptGarbageCollector, IActiveScriptGarbageCollector_Vtbl, 0x6aa2c4a0_2b53_11d4_a2a0_00104bd35090);
 windows_core::imp::interface_hierarchy!(IActiveScriptGarbageCollector, windows_core::IUnknown);
@@ -1301,6 +1877,20 @@ impl IActiveScriptGarbageCollector_Vtbl {
         iid == &<IActiveScriptGarbageCollector as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptGarbageCollector_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptGarbageCollector_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CollectGarbage<Identity: windows_core::IUnknownImpl + IActiveScriptGarbageCollector_Impl>(this: *mut core::ffi::c_void, scriptgctype: SCRIPTGCTYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptGarbageCollector_Impl::CollectGarbage(this, core::mem::transmute_copy(&scriptgctype)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CollectGarbage: CollectGarbage::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IActiveScriptGarbageCollector {}
 windows_core::imp::define_interface!(IActiveScriptHostEncode, IActiveScriptHostEncode_Vtbl, 0xbee9b76e_cfe3_11d1_b747_00c04fc2b085);
 windows_core::imp::interface_hierarchy!(IActiveScriptHostEncode, windows_core::IUnknown);
@@ -1332,6 +1922,23 @@ impl IActiveScriptHostEncode_Vtbl {
         iid == &<IActiveScriptHostEncode as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptHostEncode_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptHostEncode_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EncodeScriptHostFile<Identity: windows_core::IUnknownImpl + IActiveScriptHostEncode_Impl>(this: *mut core::ffi::c_void, bstrinfile: *mut core::ffi::c_void, pbstroutfile: *mut *mut core::ffi::c_void, cflags: u32, bstrdefaultlang: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptHostEncode_Impl::EncodeScriptHostFile(this, core::mem::transmute(&bstrinfile), core::mem::transmute_copy(&pbstroutfile), core::mem::transmute_copy(&cflags), core::mem::transmute(&bstrdefaultlang)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EncodeScriptHostFile: EncodeScriptHostFile::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IActiveScriptHostEncode {}
 windows_core::imp::define_interface!(IActiveScriptParse32, IActiveScriptParse32_Vtbl, 0xbb1a2ae2_a4f9_11cf_8f20_00805f2cd064);
 windows_core::imp::interface_hierarchy!(IActiveScriptParse32, windows_core::IUnknown);
@@ -1415,6 +2022,38 @@ impl IActiveScriptParse32_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptParse32_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptParse32_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InitNew<Identity: windows_core::IUnknownImpl + IActiveScriptParse32_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptParse32_Impl::InitNew(this).into()
+            }
+        }
+        unsafe extern "system" fn AddScriptlet<Identity: windows_core::IUnknownImpl + IActiveScriptParse32_Impl>(this: *mut core::ffi::c_void, pstrdefaultname: windows_core::PCWSTR, pstrcode: windows_core::PCWSTR, pstritemname: windows_core::PCWSTR, pstrsubitemname: windows_core::PCWSTR, pstreventname: windows_core::PCWSTR, pstrdelimiter: windows_core::PCWSTR, dwsourcecontextcookie: u32, ulstartinglinenumber: u32, dwflags: u32, pbstrname: *mut *mut core::ffi::c_void, pexcepinfo: *mut super::super::super::Com::EXCEPINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptParse32_Impl::AddScriptlet(this, core::mem::transmute(&pstrdefaultname), core::mem::transmute(&pstrcode), core::mem::transmute(&pstritemname), core::mem::transmute(&pstrsubitemname), core::mem::transmute(&pstreventname), core::mem::transmute(&pstrdelimiter), core::mem::transmute_copy(&dwsourcecontextcookie), core::mem::transmute_copy(&ulstartinglinenumber), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pbstrname), core::mem::transmute_copy(&pexcepinfo)).into()
+            }
+        }
+        unsafe extern "system" fn ParseScriptText<Identity: windows_core::IUnknownImpl + IActiveScriptParse32_Impl>(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, pstritemname: windows_core::PCWSTR, punkcontext: *mut core::ffi::c_void, pstrdelimiter: windows_core::PCWSTR, dwsourcecontextcookie: u32, ulstartinglinenumber: u32, dwflags: u32, pvarresult: *mut super::super::super::Variant::VARIANT, pexcepinfo: *mut super::super::super::Com::EXCEPINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptParse32_Impl::ParseScriptText(this, core::mem::transmute(&pstrcode), core::mem::transmute(&pstritemname), core::mem::transmute_copy(&punkcontext), core::mem::transmute(&pstrdelimiter), core::mem::transmute_copy(&dwsourcecontextcookie), core::mem::transmute_copy(&ulstartinglinenumber), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pvarresult), core::mem::transmute_copy(&pexcepinfo)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InitNew: InitNew::<Identity>,
+            AddScriptlet: AddScriptlet::<Identity>,
+            ParseScriptText: ParseScriptText::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IActiveScriptParse32 {}
 windows_core::imp::define_interface!(IActiveScriptParse64, IActiveScriptParse64_Vtbl, 0xc7ef7658_e1ee_480e_97ea_d52cb4d76d17);
 windows_core::imp::interface_hierarchy!(IActiveScriptParse64, windows_core::IUnknown);
@@ -1498,6 +2137,38 @@ impl IActiveScriptParse64_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptParse64_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptParse64_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InitNew<Identity: windows_core::IUnknownImpl + IActiveScriptParse64_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptParse64_Impl::InitNew(this).into()
+            }
+        }
+        unsafe extern "system" fn AddScriptlet<Identity: windows_core::IUnknownImpl + IActiveScriptParse64_Impl>(this: *mut core::ffi::c_void, pstrdefaultname: windows_core::PCWSTR, pstrcode: windows_core::PCWSTR, pstritemname: windows_core::PCWSTR, pstrsubitemname: windows_core::PCWSTR, pstreventname: windows_core::PCWSTR, pstrdelimiter: windows_core::PCWSTR, dwsourcecontextcookie: u64, ulstartinglinenumber: u32, dwflags: u32, pbstrname: *mut *mut core::ffi::c_void, pexcepinfo: *mut super::super::super::Com::EXCEPINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptParse64_Impl::AddScriptlet(this, core::mem::transmute(&pstrdefaultname), core::mem::transmute(&pstrcode), core::mem::transmute(&pstritemname), core::mem::transmute(&pstrsubitemname), core::mem::transmute(&pstreventname), core::mem::transmute(&pstrdelimiter), core::mem::transmute_copy(&dwsourcecontextcookie), core::mem::transmute_copy(&ulstartinglinenumber), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pbstrname), core::mem::transmute_copy(&pexcepinfo)).into()
+            }
+        }
+        unsafe extern "system" fn ParseScriptText<Identity: windows_core::IUnknownImpl + IActiveScriptParse64_Impl>(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, pstritemname: windows_core::PCWSTR, punkcontext: *mut core::ffi::c_void, pstrdelimiter: windows_core::PCWSTR, dwsourcecontextcookie: u64, ulstartinglinenumber: u32, dwflags: u32, pvarresult: *mut super::super::super::Variant::VARIANT, pexcepinfo: *mut super::super::super::Com::EXCEPINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptParse64_Impl::ParseScriptText(this, core::mem::transmute(&pstrcode), core::mem::transmute(&pstritemname), core::mem::transmute_copy(&punkcontext), core::mem::transmute(&pstrdelimiter), core::mem::transmute_copy(&dwsourcecontextcookie), core::mem::transmute_copy(&ulstartinglinenumber), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pvarresult), core::mem::transmute_copy(&pexcepinfo)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InitNew: InitNew::<Identity>,
+            AddScriptlet: AddScriptlet::<Identity>,
+            ParseScriptText: ParseScriptText::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IActiveScriptParse64 {}
 windows_core::imp::define_interface!(IActiveScriptParseProcedure2_32, IActiveScriptParseProcedure2_32_Vtbl, 0x71ee5b20_fb04_11d1_b3a8_00a0c911e8b2);
 impl core::ops::Deref for IActiveScriptParseProcedure2_32 {
@@ -1524,6 +2195,13 @@ impl IActiveScriptParseProcedure2_32_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptParseProcedure2_32_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptParseProcedure2_32_Vtbl
+where
+    IActiveScriptParseProcedure32_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IActiveScriptParseProcedure32_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IActiveScriptParseProcedure2_32 {}
 windows_core::imp::define_interface!(IActiveScriptParseProcedure2_64, IActiveScriptParseProcedure2_64_Vtbl, 0xfe7c4271_210c_448d_9f54_76dab7047b28);
 impl core::ops::Deref for IActiveScriptParseProcedure2_64 {
@@ -1550,6 +2228,13 @@ impl IActiveScriptParseProcedure2_64_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptParseProcedure2_64_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptParseProcedure2_64_Vtbl
+where
+    IActiveScriptParseProcedure64_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IActiveScriptParseProcedure64_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IActiveScriptParseProcedure2_64 {}
 windows_core::imp::define_interface!(IActiveScriptParseProcedure32, IActiveScriptParseProcedure32_Vtbl, 0xaa5b6a80_b834_11d0_932f_00a0c90dcaa9);
 windows_core::imp::interface_hierarchy!(IActiveScriptParseProcedure32, windows_core::IUnknown);
@@ -1605,6 +2290,27 @@ impl IActiveScriptParseProcedure32_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptParseProcedure32_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptParseProcedure32_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ParseProcedureText<Identity: windows_core::IUnknownImpl + IActiveScriptParseProcedure32_Impl>(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, pstrformalparams: windows_core::PCWSTR, pstrprocedurename: windows_core::PCWSTR, pstritemname: windows_core::PCWSTR, punkcontext: *mut core::ffi::c_void, pstrdelimiter: windows_core::PCWSTR, dwsourcecontextcookie: u32, ulstartinglinenumber: u32, dwflags: u32, ppdisp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptParseProcedure32_Impl::ParseProcedureText(this, core::mem::transmute(&pstrcode), core::mem::transmute(&pstrformalparams), core::mem::transmute(&pstrprocedurename), core::mem::transmute(&pstritemname), core::mem::transmute_copy(&punkcontext), core::mem::transmute(&pstrdelimiter), core::mem::transmute_copy(&dwsourcecontextcookie), core::mem::transmute_copy(&ulstartinglinenumber), core::mem::transmute_copy(&dwflags)) {
+                    Ok(ok__) => {
+                        ppdisp.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ParseProcedureText: ParseProcedureText::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IActiveScriptParseProcedure32 {}
 windows_core::imp::define_interface!(IActiveScriptParseProcedure64, IActiveScriptParseProcedure64_Vtbl, 0xc64713b6_e029_4cc5_9200_438b72890b6a);
 windows_core::imp::interface_hierarchy!(IActiveScriptParseProcedure64, windows_core::IUnknown);
@@ -1660,6 +2366,27 @@ impl IActiveScriptParseProcedure64_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptParseProcedure64_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptParseProcedure64_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ParseProcedureText<Identity: windows_core::IUnknownImpl + IActiveScriptParseProcedure64_Impl>(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, pstrformalparams: windows_core::PCWSTR, pstrprocedurename: windows_core::PCWSTR, pstritemname: windows_core::PCWSTR, punkcontext: *mut core::ffi::c_void, pstrdelimiter: windows_core::PCWSTR, dwsourcecontextcookie: u64, ulstartinglinenumber: u32, dwflags: u32, ppdisp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptParseProcedure64_Impl::ParseProcedureText(this, core::mem::transmute(&pstrcode), core::mem::transmute(&pstrformalparams), core::mem::transmute(&pstrprocedurename), core::mem::transmute(&pstritemname), core::mem::transmute_copy(&punkcontext), core::mem::transmute(&pstrdelimiter), core::mem::transmute_copy(&dwsourcecontextcookie), core::mem::transmute_copy(&ulstartinglinenumber), core::mem::transmute_copy(&dwflags)) {
+                    Ok(ok__) => {
+                        ppdisp.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ParseProcedureText: ParseProcedureText::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IActiveScriptParseProcedure64 {}
 windows_core::imp::define_interface!(IActiveScriptParseProcedureOld32, IActiveScriptParseProcedureOld32_Vtbl, 0x1cff0050_6fdd_11d0_9328_00a0c90dcaa9);
 windows_core::imp::interface_hierarchy!(IActiveScriptParseProcedureOld32, windows_core::IUnknown);
@@ -1714,6 +2441,27 @@ impl IActiveScriptParseProcedureOld32_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptParseProcedureOld32_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptParseProcedureOld32_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ParseProcedureText<Identity: windows_core::IUnknownImpl + IActiveScriptParseProcedureOld32_Impl>(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, pstrformalparams: windows_core::PCWSTR, pstritemname: windows_core::PCWSTR, punkcontext: *mut core::ffi::c_void, pstrdelimiter: windows_core::PCWSTR, dwsourcecontextcookie: u32, ulstartinglinenumber: u32, dwflags: u32, ppdisp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptParseProcedureOld32_Impl::ParseProcedureText(this, core::mem::transmute(&pstrcode), core::mem::transmute(&pstrformalparams), core::mem::transmute(&pstritemname), core::mem::transmute_copy(&punkcontext), core::mem::transmute(&pstrdelimiter), core::mem::transmute_copy(&dwsourcecontextcookie), core::mem::transmute_copy(&ulstartinglinenumber), core::mem::transmute_copy(&dwflags)) {
+                    Ok(ok__) => {
+                        ppdisp.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ParseProcedureText: ParseProcedureText::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IActiveScriptParseProcedureOld32 {}
 windows_core::imp::define_interface!(IActiveScriptParseProcedureOld64, IActiveScriptParseProcedureOld64_Vtbl, 0x21f57128_08c9_4638_ba12_22d15d88dc5c);
 windows_core::imp::interface_hierarchy!(IActiveScriptParseProcedureOld64, windows_core::IUnknown);
@@ -1768,6 +2516,27 @@ impl IActiveScriptParseProcedureOld64_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptParseProcedureOld64_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptParseProcedureOld64_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ParseProcedureText<Identity: windows_core::IUnknownImpl + IActiveScriptParseProcedureOld64_Impl>(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, pstrformalparams: windows_core::PCWSTR, pstritemname: windows_core::PCWSTR, punkcontext: *mut core::ffi::c_void, pstrdelimiter: windows_core::PCWSTR, dwsourcecontextcookie: u64, ulstartinglinenumber: u32, dwflags: u32, ppdisp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptParseProcedureOld64_Impl::ParseProcedureText(this, core::mem::transmute(&pstrcode), core::mem::transmute(&pstrformalparams), core::mem::transmute(&pstritemname), core::mem::transmute_copy(&punkcontext), core::mem::transmute(&pstrdelimiter), core::mem::transmute_copy(&dwsourcecontextcookie), core::mem::transmute_copy(&ulstartinglinenumber), core::mem::transmute_copy(&dwflags)) {
+                    Ok(ok__) => {
+                        ppdisp.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ParseProcedureText: ParseProcedureText::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IActiveScriptParseProcedureOld64 {}
 windows_core::imp::define_interface!(IActiveScriptProfilerCallback, IActiveScriptProfilerCallback_Vtbl, 0x740eca23_7d9d_42e5_ba9d_f8b24b1c7a9b);
 windows_core::imp::interface_hierarchy!(IActiveScriptProfilerCallback, windows_core::IUnknown);
@@ -1870,6 +2639,58 @@ impl IActiveScriptProfilerCallback_Vtbl {
         iid == &<IActiveScriptProfilerCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptProfilerCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerCallback_Impl>(this: *mut core::ffi::c_void, dwcontext: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptProfilerCallback_Impl::Initialize(this, core::mem::transmute_copy(&dwcontext)).into()
+            }
+        }
+        unsafe extern "system" fn Shutdown<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerCallback_Impl>(this: *mut core::ffi::c_void, hrreason: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptProfilerCallback_Impl::Shutdown(this, core::mem::transmute_copy(&hrreason)).into()
+            }
+        }
+        unsafe extern "system" fn ScriptCompiled<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerCallback_Impl>(this: *mut core::ffi::c_void, scriptid: i32, r#type: PROFILER_SCRIPT_TYPE, pidebugdocumentcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptProfilerCallback_Impl::ScriptCompiled(this, core::mem::transmute_copy(&scriptid), core::mem::transmute_copy(&r#type), core::mem::transmute_copy(&pidebugdocumentcontext)).into()
+            }
+        }
+        unsafe extern "system" fn FunctionCompiled<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerCallback_Impl>(this: *mut core::ffi::c_void, functionid: i32, scriptid: i32, pwszfunctionname: windows_core::PCWSTR, pwszfunctionnamehint: windows_core::PCWSTR, pidebugdocumentcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptProfilerCallback_Impl::FunctionCompiled(this, core::mem::transmute_copy(&functionid), core::mem::transmute_copy(&scriptid), core::mem::transmute(&pwszfunctionname), core::mem::transmute(&pwszfunctionnamehint), core::mem::transmute_copy(&pidebugdocumentcontext)).into()
+            }
+        }
+        unsafe extern "system" fn OnFunctionEnter<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerCallback_Impl>(this: *mut core::ffi::c_void, scriptid: i32, functionid: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptProfilerCallback_Impl::OnFunctionEnter(this, core::mem::transmute_copy(&scriptid), core::mem::transmute_copy(&functionid)).into()
+            }
+        }
+        unsafe extern "system" fn OnFunctionExit<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerCallback_Impl>(this: *mut core::ffi::c_void, scriptid: i32, functionid: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptProfilerCallback_Impl::OnFunctionExit(this, core::mem::transmute_copy(&scriptid), core::mem::transmute_copy(&functionid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            Shutdown: Shutdown::<Identity>,
+            ScriptCompiled: ScriptCompiled::<Identity>,
+            FunctionCompiled: FunctionCompiled::<Identity>,
+            OnFunctionEnter: OnFunctionEnter::<Identity>,
+            OnFunctionExit: OnFunctionExit::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IActiveScriptProfilerCallback {}
 windows_core::imp::define_interface!(IActiveScriptProfilerCallback2, IActiveScriptProfilerCallback2_Vtbl, 0x31b7f8ad_a637_409c_b22f_040995b6103d);
 impl core::ops::Deref for IActiveScriptProfilerCallback2 {
@@ -1928,6 +2749,30 @@ impl IActiveScriptProfilerCallback2_Vtbl {
         iid == &<IActiveScriptProfilerCallback2 as windows_core::Interface>::IID || iid == &<IActiveScriptProfilerCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerCallback2_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptProfilerCallback2_Vtbl
+where
+    IActiveScriptProfilerCallback_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnFunctionEnterByName<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerCallback2_Impl>(this: *mut core::ffi::c_void, pwszfunctionname: windows_core::PCWSTR, r#type: PROFILER_SCRIPT_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptProfilerCallback2_Impl::OnFunctionEnterByName(this, core::mem::transmute(&pwszfunctionname), core::mem::transmute_copy(&r#type)).into()
+            }
+        }
+        unsafe extern "system" fn OnFunctionExitByName<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerCallback2_Impl>(this: *mut core::ffi::c_void, pwszfunctionname: windows_core::PCWSTR, r#type: PROFILER_SCRIPT_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptProfilerCallback2_Impl::OnFunctionExitByName(this, core::mem::transmute(&pwszfunctionname), core::mem::transmute_copy(&r#type)).into()
+            }
+        }
+        Self {
+            base__: <IActiveScriptProfilerCallback_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnFunctionEnterByName: OnFunctionEnterByName::<Identity>,
+            OnFunctionExitByName: OnFunctionExitByName::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IActiveScriptProfilerCallback2 {}
 windows_core::imp::define_interface!(IActiveScriptProfilerCallback3, IActiveScriptProfilerCallback3_Vtbl, 0x6ac5ad25_2037_4687_91df_b59979d93d73);
 impl core::ops::Deref for IActiveScriptProfilerCallback3 {
@@ -1965,6 +2810,20 @@ impl IActiveScriptProfilerCallback3_Vtbl {
         iid == &<IActiveScriptProfilerCallback3 as windows_core::Interface>::IID || iid == &<IActiveScriptProfilerCallback as windows_core::Interface>::IID || iid == &<IActiveScriptProfilerCallback2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerCallback3_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptProfilerCallback3_Vtbl
+where
+    IActiveScriptProfilerCallback2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetWebWorkerId<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerCallback3_Impl>(this: *mut core::ffi::c_void, webworkerid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptProfilerCallback3_Impl::SetWebWorkerId(this, core::mem::transmute_copy(&webworkerid)).into()
+            }
+        }
+        Self { base__: <IActiveScriptProfilerCallback2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetWebWorkerId: SetWebWorkerId::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IActiveScriptProfilerCallback3 {}
 windows_core::imp::define_interface!(IActiveScriptProfilerControl, IActiveScriptProfilerControl_Vtbl, 0x784b5ff0_69b0_47d1_a7dc_2518f4230e90);
 windows_core::imp::interface_hierarchy!(IActiveScriptProfilerControl, windows_core::IUnknown);
@@ -2023,12 +2882,43 @@ impl IActiveScriptProfilerControl_Vtbl {
         iid == &<IActiveScriptProfilerControl as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IActiveScriptProfilerControl {}
-windows_core::imp::define_interface!(IActiveScriptProfilerControl2, IActiveScriptProfilerControl2_Vtbl, 0x47810165_498f_40be_94f1_653557e9e7da);
-impl core::ops::Deref for IActiveScriptProfilerControl2 {
-    type Target = IActiveScriptProfilerControl;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerControl_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptProfilerControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StartProfiling<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerControl_Impl>(this: *mut core::ffi::c_void, clsidprofilerobject: *const windows_core::GUID, dweventmask: u32, dwcontext: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptProfilerControl_Impl::StartProfiling(this, core::mem::transmute_copy(&clsidprofilerobject), core::mem::transmute_copy(&dweventmask), core::mem::transmute_copy(&dwcontext)).into()
+            }
+        }
+        unsafe extern "system" fn SetProfilerEventMask<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerControl_Impl>(this: *mut core::ffi::c_void, dweventmask: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptProfilerControl_Impl::SetProfilerEventMask(this, core::mem::transmute_copy(&dweventmask)).into()
+            }
+        }
+        unsafe extern "system" fn StopProfiling<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerControl_Impl>(this: *mut core::ffi::c_void, hrshutdownreason: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptProfilerControl_Impl::StopProfiling(this, core::mem::transmute_copy(&hrshutdownreason)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            StartProfiling: StartProfiling::<Identity>,
+            SetProfilerEventMask: SetProfilerEventMask::<Identity>,
+            StopProfiling: StopProfiling::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IActiveScriptProfilerControl {}
+windows_core::imp::define_interface!(IActiveScriptProfilerControl2, IActiveScriptProfilerControl2_Vtbl, 0x47810165_498f_40be_94f1_653557e9e7da);
+impl core::ops::Deref for IActiveScriptProfilerControl2 {
+    type Target = IActiveScriptProfilerControl;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
     }
 }
 windows_core::imp::interface_hierarchy!(IActiveScriptProfilerControl2, windows_core::IUnknown, IActiveScriptProfilerControl);
@@ -2075,6 +2965,30 @@ impl IActiveScriptProfilerControl2_Vtbl {
         iid == &<IActiveScriptProfilerControl2 as windows_core::Interface>::IID || iid == &<IActiveScriptProfilerControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerControl2_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptProfilerControl2_Vtbl
+where
+    IActiveScriptProfilerControl_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CompleteProfilerStart<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerControl2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptProfilerControl2_Impl::CompleteProfilerStart(this).into()
+            }
+        }
+        unsafe extern "system" fn PrepareProfilerStop<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerControl2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptProfilerControl2_Impl::PrepareProfilerStop(this).into()
+            }
+        }
+        Self {
+            base__: <IActiveScriptProfilerControl_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CompleteProfilerStart: CompleteProfilerStart::<Identity>,
+            PrepareProfilerStop: PrepareProfilerStop::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IActiveScriptProfilerControl2 {}
 windows_core::imp::define_interface!(IActiveScriptProfilerControl3, IActiveScriptProfilerControl3_Vtbl, 0x0b403015_f381_4023_a5d0_6fed076de716);
 impl core::ops::Deref for IActiveScriptProfilerControl3 {
@@ -2121,6 +3035,26 @@ impl IActiveScriptProfilerControl3_Vtbl {
         iid == &<IActiveScriptProfilerControl3 as windows_core::Interface>::IID || iid == &<IActiveScriptProfilerControl as windows_core::Interface>::IID || iid == &<IActiveScriptProfilerControl2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerControl3_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptProfilerControl3_Vtbl
+where
+    IActiveScriptProfilerControl2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumHeap<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerControl3_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptProfilerControl3_Impl::EnumHeap(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IActiveScriptProfilerControl2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, EnumHeap: EnumHeap::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IActiveScriptProfilerControl3 {}
 windows_core::imp::define_interface!(IActiveScriptProfilerControl4, IActiveScriptProfilerControl4_Vtbl, 0x160f94fd_9dbc_40d4_9eac_2b71db3132f4);
 impl core::ops::Deref for IActiveScriptProfilerControl4 {
@@ -2158,6 +3092,20 @@ impl IActiveScriptProfilerControl4_Vtbl {
         iid == &<IActiveScriptProfilerControl4 as windows_core::Interface>::IID || iid == &<IActiveScriptProfilerControl as windows_core::Interface>::IID || iid == &<IActiveScriptProfilerControl2 as windows_core::Interface>::IID || iid == &<IActiveScriptProfilerControl3 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerControl4_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptProfilerControl4_Vtbl
+where
+    IActiveScriptProfilerControl3_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SummarizeHeap<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerControl4_Impl>(this: *mut core::ffi::c_void, heapsummary: *mut PROFILER_HEAP_SUMMARY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptProfilerControl4_Impl::SummarizeHeap(this, core::mem::transmute_copy(&heapsummary)).into()
+            }
+        }
+        Self { base__: <IActiveScriptProfilerControl3_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SummarizeHeap: SummarizeHeap::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IActiveScriptProfilerControl4 {}
 windows_core::imp::define_interface!(IActiveScriptProfilerControl5, IActiveScriptProfilerControl5_Vtbl, 0x1c01a2d1_8f0f_46a5_9720_0d7ed2c62f0a);
 impl core::ops::Deref for IActiveScriptProfilerControl5 {
@@ -2204,6 +3152,26 @@ impl IActiveScriptProfilerControl5_Vtbl {
         iid == &<IActiveScriptProfilerControl5 as windows_core::Interface>::IID || iid == &<IActiveScriptProfilerControl as windows_core::Interface>::IID || iid == &<IActiveScriptProfilerControl2 as windows_core::Interface>::IID || iid == &<IActiveScriptProfilerControl3 as windows_core::Interface>::IID || iid == &<IActiveScriptProfilerControl4 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerControl5_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptProfilerControl5_Vtbl
+where
+    IActiveScriptProfilerControl4_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumHeap2<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerControl5_Impl>(this: *mut core::ffi::c_void, enumflags: PROFILER_HEAP_ENUM_FLAGS, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptProfilerControl5_Impl::EnumHeap2(this, core::mem::transmute_copy(&enumflags)) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IActiveScriptProfilerControl4_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, EnumHeap2: EnumHeap2::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IActiveScriptProfilerControl5 {}
 windows_core::imp::define_interface!(IActiveScriptProfilerHeapEnum, IActiveScriptProfilerHeapEnum_Vtbl, 0x32e4694e_0d37_419b_b93d_fa20ded6e8ea);
 windows_core::imp::interface_hierarchy!(IActiveScriptProfilerHeapEnum, windows_core::IUnknown);
@@ -2274,6 +3242,44 @@ impl IActiveScriptProfilerHeapEnum_Vtbl {
         iid == &<IActiveScriptProfilerHeapEnum as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerHeapEnum_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptProfilerHeapEnum_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerHeapEnum_Impl>(this: *mut core::ffi::c_void, celt: u32, heapobjects: *mut *mut PROFILER_HEAP_OBJECT, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptProfilerHeapEnum_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&heapobjects), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn GetOptionalInfo<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerHeapEnum_Impl>(this: *mut core::ffi::c_void, heapobject: *const PROFILER_HEAP_OBJECT, celt: u32, optionalinfo: *mut PROFILER_HEAP_OBJECT_OPTIONAL_INFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptProfilerHeapEnum_Impl::GetOptionalInfo(this, core::mem::transmute_copy(&heapobject), core::mem::transmute_copy(&celt), core::mem::transmute_copy(&optionalinfo)).into()
+            }
+        }
+        unsafe extern "system" fn FreeObjectAndOptionalInfo<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerHeapEnum_Impl>(this: *mut core::ffi::c_void, celt: u32, heapobjects: *const *const PROFILER_HEAP_OBJECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptProfilerHeapEnum_Impl::FreeObjectAndOptionalInfo(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&heapobjects)).into()
+            }
+        }
+        unsafe extern "system" fn GetNameIdMap<Identity: windows_core::IUnknownImpl + IActiveScriptProfilerHeapEnum_Impl>(this: *mut core::ffi::c_void, pnamelist: *mut *mut *mut windows_core::PCWSTR, pcelt: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptProfilerHeapEnum_Impl::GetNameIdMap(this, core::mem::transmute_copy(&pnamelist), core::mem::transmute_copy(&pcelt)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            GetOptionalInfo: GetOptionalInfo::<Identity>,
+            FreeObjectAndOptionalInfo: FreeObjectAndOptionalInfo::<Identity>,
+            GetNameIdMap: GetNameIdMap::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IActiveScriptProfilerHeapEnum {}
 windows_core::imp::define_interface!(IActiveScriptProperty, IActiveScriptProperty_Vtbl, 0x4954e0d0_fbc7_11d1_8410_006008c3fbfc);
 windows_core::imp::interface_hierarchy!(IActiveScriptProperty, windows_core::IUnknown);
@@ -2340,6 +3346,37 @@ impl IActiveScriptProperty_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptProperty_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptProperty_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetProperty<Identity: windows_core::IUnknownImpl + IActiveScriptProperty_Impl>(this: *mut core::ffi::c_void, dwproperty: u32, pvarindex: *const super::super::super::Variant::VARIANT, pvarvalue: *mut super::super::super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptProperty_Impl::GetProperty(this, core::mem::transmute_copy(&dwproperty), core::mem::transmute_copy(&pvarindex)) {
+                    Ok(ok__) => {
+                        pvarvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProperty<Identity: windows_core::IUnknownImpl + IActiveScriptProperty_Impl>(this: *mut core::ffi::c_void, dwproperty: u32, pvarindex: *const super::super::super::Variant::VARIANT, pvarvalue: *const super::super::super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptProperty_Impl::SetProperty(this, core::mem::transmute_copy(&dwproperty), core::mem::transmute_copy(&pvarindex), core::mem::transmute_copy(&pvarvalue)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetProperty: GetProperty::<Identity>,
+            SetProperty: SetProperty::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IActiveScriptProperty {}
 windows_core::imp::define_interface!(IActiveScriptSIPInfo, IActiveScriptSIPInfo_Vtbl, 0x764651d0_38de_11d4_a2a3_00104bd35090);
 windows_core::imp::interface_hierarchy!(IActiveScriptSIPInfo, windows_core::IUnknown);
@@ -2380,6 +3417,26 @@ impl IActiveScriptSIPInfo_Vtbl {
         iid == &<IActiveScriptSIPInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptSIPInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptSIPInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSIPOID<Identity: windows_core::IUnknownImpl + IActiveScriptSIPInfo_Impl>(this: *mut core::ffi::c_void, poid_sip: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptSIPInfo_Impl::GetSIPOID(this) {
+                    Ok(ok__) => {
+                        poid_sip.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetSIPOID: GetSIPOID::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IActiveScriptSIPInfo {}
 windows_core::imp::define_interface!(IActiveScriptSite, IActiveScriptSite_Vtbl, 0xdb01a1e3_a42b_11cf_8f20_00805f2cd064);
 windows_core::imp::interface_hierarchy!(IActiveScriptSite, windows_core::IUnknown);
@@ -2533,6 +3590,85 @@ impl IActiveScriptSite_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptSite_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptSite_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetLCID<Identity: windows_core::IUnknownImpl + IActiveScriptSite_Impl>(this: *mut core::ffi::c_void, plcid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptSite_Impl::GetLCID(this) {
+                    Ok(ok__) => {
+                        plcid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetItemInfo<Identity: windows_core::IUnknownImpl + IActiveScriptSite_Impl>(this: *mut core::ffi::c_void, pstrname: windows_core::PCWSTR, dwreturnmask: u32, ppiunkitem: *mut *mut core::ffi::c_void, ppti: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptSite_Impl::GetItemInfo(this, core::mem::transmute(&pstrname), core::mem::transmute_copy(&dwreturnmask), core::mem::transmute_copy(&ppiunkitem), core::mem::transmute_copy(&ppti)).into()
+            }
+        }
+        unsafe extern "system" fn GetDocVersionString<Identity: windows_core::IUnknownImpl + IActiveScriptSite_Impl>(this: *mut core::ffi::c_void, pbstrversion: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptSite_Impl::GetDocVersionString(this) {
+                    Ok(ok__) => {
+                        pbstrversion.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OnScriptTerminate<Identity: windows_core::IUnknownImpl + IActiveScriptSite_Impl>(this: *mut core::ffi::c_void, pvarresult: *const super::super::super::Variant::VARIANT, pexcepinfo: *const super::super::super::Com::EXCEPINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptSite_Impl::OnScriptTerminate(this, core::mem::transmute_copy(&pvarresult), core::mem::transmute_copy(&pexcepinfo)).into()
+            }
+        }
+        unsafe extern "system" fn OnStateChange<Identity: windows_core::IUnknownImpl + IActiveScriptSite_Impl>(this: *mut core::ffi::c_void, ssscriptstate: SCRIPTSTATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptSite_Impl::OnStateChange(this, core::mem::transmute_copy(&ssscriptstate)).into()
+            }
+        }
+        unsafe extern "system" fn OnScriptError<Identity: windows_core::IUnknownImpl + IActiveScriptSite_Impl>(this: *mut core::ffi::c_void, pscripterror: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptSite_Impl::OnScriptError(this, core::mem::transmute_copy(&pscripterror)).into()
+            }
+        }
+        unsafe extern "system" fn OnEnterScript<Identity: windows_core::IUnknownImpl + IActiveScriptSite_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptSite_Impl::OnEnterScript(this).into()
+            }
+        }
+        unsafe extern "system" fn OnLeaveScript<Identity: windows_core::IUnknownImpl + IActiveScriptSite_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptSite_Impl::OnLeaveScript(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetLCID: GetLCID::<Identity>,
+            GetItemInfo: GetItemInfo::<Identity>,
+            GetDocVersionString: GetDocVersionString::<Identity>,
+            OnScriptTerminate: OnScriptTerminate::<Identity>,
+            OnStateChange: OnStateChange::<Identity>,
+            OnScriptError: OnScriptError::<Identity>,
+            OnEnterScript: OnEnterScript::<Identity>,
+            OnLeaveScript: OnLeaveScript::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IActiveScriptSite {}
 windows_core::imp::define_interface!(IActiveScriptSiteDebug32, IActiveScriptSiteDebug32_Vtbl, 0x51973c11_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IActiveScriptSiteDebug32, windows_core::IUnknown);
@@ -2633,6 +3769,62 @@ impl IActiveScriptSiteDebug32_Vtbl {
         iid == &<IActiveScriptSiteDebug32 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptSiteDebug32_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptSiteDebug32_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDocumentContextFromPosition<Identity: windows_core::IUnknownImpl + IActiveScriptSiteDebug32_Impl>(this: *mut core::ffi::c_void, dwsourcecontext: u32, ucharacteroffset: u32, unumchars: u32, ppsc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptSiteDebug32_Impl::GetDocumentContextFromPosition(this, core::mem::transmute_copy(&dwsourcecontext), core::mem::transmute_copy(&ucharacteroffset), core::mem::transmute_copy(&unumchars)) {
+                    Ok(ok__) => {
+                        ppsc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetApplication<Identity: windows_core::IUnknownImpl + IActiveScriptSiteDebug32_Impl>(this: *mut core::ffi::c_void, ppda: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptSiteDebug32_Impl::GetApplication(this) {
+                    Ok(ok__) => {
+                        ppda.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRootApplicationNode<Identity: windows_core::IUnknownImpl + IActiveScriptSiteDebug32_Impl>(this: *mut core::ffi::c_void, ppdanroot: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptSiteDebug32_Impl::GetRootApplicationNode(this) {
+                    Ok(ok__) => {
+                        ppdanroot.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OnScriptErrorDebug<Identity: windows_core::IUnknownImpl + IActiveScriptSiteDebug32_Impl>(this: *mut core::ffi::c_void, perrordebug: *mut core::ffi::c_void, pfenterdebugger: *mut windows_core::BOOL, pfcallonscripterrorwhencontinuing: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptSiteDebug32_Impl::OnScriptErrorDebug(this, core::mem::transmute_copy(&perrordebug), core::mem::transmute_copy(&pfenterdebugger), core::mem::transmute_copy(&pfcallonscripterrorwhencontinuing)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDocumentContextFromPosition: GetDocumentContextFromPosition::<Identity>,
+            GetApplication: GetApplication::<Identity>,
+            GetRootApplicationNode: GetRootApplicationNode::<Identity>,
+            OnScriptErrorDebug: OnScriptErrorDebug::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IActiveScriptSiteDebug32 {}
 windows_core::imp::define_interface!(IActiveScriptSiteDebug64, IActiveScriptSiteDebug64_Vtbl, 0xd6b96b0a_7463_402c_92ac_89984226942f);
 windows_core::imp::interface_hierarchy!(IActiveScriptSiteDebug64, windows_core::IUnknown);
@@ -2733,6 +3925,62 @@ impl IActiveScriptSiteDebug64_Vtbl {
         iid == &<IActiveScriptSiteDebug64 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptSiteDebug64_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptSiteDebug64_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDocumentContextFromPosition<Identity: windows_core::IUnknownImpl + IActiveScriptSiteDebug64_Impl>(this: *mut core::ffi::c_void, dwsourcecontext: u64, ucharacteroffset: u32, unumchars: u32, ppsc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptSiteDebug64_Impl::GetDocumentContextFromPosition(this, core::mem::transmute_copy(&dwsourcecontext), core::mem::transmute_copy(&ucharacteroffset), core::mem::transmute_copy(&unumchars)) {
+                    Ok(ok__) => {
+                        ppsc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetApplication<Identity: windows_core::IUnknownImpl + IActiveScriptSiteDebug64_Impl>(this: *mut core::ffi::c_void, ppda: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptSiteDebug64_Impl::GetApplication(this) {
+                    Ok(ok__) => {
+                        ppda.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRootApplicationNode<Identity: windows_core::IUnknownImpl + IActiveScriptSiteDebug64_Impl>(this: *mut core::ffi::c_void, ppdanroot: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptSiteDebug64_Impl::GetRootApplicationNode(this) {
+                    Ok(ok__) => {
+                        ppdanroot.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OnScriptErrorDebug<Identity: windows_core::IUnknownImpl + IActiveScriptSiteDebug64_Impl>(this: *mut core::ffi::c_void, perrordebug: *mut core::ffi::c_void, pfenterdebugger: *mut windows_core::BOOL, pfcallonscripterrorwhencontinuing: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptSiteDebug64_Impl::OnScriptErrorDebug(this, core::mem::transmute_copy(&perrordebug), core::mem::transmute_copy(&pfenterdebugger), core::mem::transmute_copy(&pfcallonscripterrorwhencontinuing)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDocumentContextFromPosition: GetDocumentContextFromPosition::<Identity>,
+            GetApplication: GetApplication::<Identity>,
+            GetRootApplicationNode: GetRootApplicationNode::<Identity>,
+            OnScriptErrorDebug: OnScriptErrorDebug::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IActiveScriptSiteDebug64 {}
 windows_core::imp::define_interface!(IActiveScriptSiteDebugEx, IActiveScriptSiteDebugEx_Vtbl, 0xbb722ccb_6ad2_41c6_b780_af9c03ee69f5);
 windows_core::imp::interface_hierarchy!(IActiveScriptSiteDebugEx, windows_core::IUnknown);
@@ -2776,6 +4024,29 @@ impl IActiveScriptSiteDebugEx_Vtbl {
         iid == &<IActiveScriptSiteDebugEx as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptSiteDebugEx_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptSiteDebugEx_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnCanNotJITScriptErrorDebug<Identity: windows_core::IUnknownImpl + IActiveScriptSiteDebugEx_Impl>(this: *mut core::ffi::c_void, perrordebug: *mut core::ffi::c_void, pfcallonscripterrorwhencontinuing: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptSiteDebugEx_Impl::OnCanNotJITScriptErrorDebug(this, core::mem::transmute_copy(&perrordebug)) {
+                    Ok(ok__) => {
+                        pfcallonscripterrorwhencontinuing.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnCanNotJITScriptErrorDebug: OnCanNotJITScriptErrorDebug::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IActiveScriptSiteDebugEx {}
 windows_core::imp::define_interface!(IActiveScriptSiteInterruptPoll, IActiveScriptSiteInterruptPoll_Vtbl, 0x539698a0_cdca_11cf_a5eb_00aa0047a063);
 windows_core::imp::interface_hierarchy!(IActiveScriptSiteInterruptPoll, windows_core::IUnknown);
@@ -2807,6 +4078,20 @@ impl IActiveScriptSiteInterruptPoll_Vtbl {
         iid == &<IActiveScriptSiteInterruptPoll as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptSiteInterruptPoll_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptSiteInterruptPoll_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryContinue<Identity: windows_core::IUnknownImpl + IActiveScriptSiteInterruptPoll_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptSiteInterruptPoll_Impl::QueryContinue(this).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, QueryContinue: QueryContinue::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IActiveScriptSiteInterruptPoll {}
 windows_core::imp::define_interface!(IActiveScriptSiteTraceInfo, IActiveScriptSiteTraceInfo_Vtbl, 0x4b7272ae_1955_4bfe_98b0_780621888569);
 windows_core::imp::interface_hierarchy!(IActiveScriptSiteTraceInfo, windows_core::IUnknown);
@@ -2838,6 +4123,23 @@ impl IActiveScriptSiteTraceInfo_Vtbl {
         iid == &<IActiveScriptSiteTraceInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptSiteTraceInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptSiteTraceInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SendScriptTraceInfo<Identity: windows_core::IUnknownImpl + IActiveScriptSiteTraceInfo_Impl>(this: *mut core::ffi::c_void, stieventtype: SCRIPTTRACEINFO, guidcontextid: windows_core::GUID, dwscriptcontextcookie: u32, lscriptstatementstart: i32, lscriptstatementend: i32, dwreserved: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptSiteTraceInfo_Impl::SendScriptTraceInfo(this, core::mem::transmute_copy(&stieventtype), core::mem::transmute(&guidcontextid), core::mem::transmute_copy(&dwscriptcontextcookie), core::mem::transmute_copy(&lscriptstatementstart), core::mem::transmute_copy(&lscriptstatementend), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SendScriptTraceInfo: SendScriptTraceInfo::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IActiveScriptSiteTraceInfo {}
 windows_core::imp::define_interface!(IActiveScriptSiteUIControl, IActiveScriptSiteUIControl_Vtbl, 0xaedae97e_d7ee_4796_b960_7f092ae844ab);
 windows_core::imp::interface_hierarchy!(IActiveScriptSiteUIControl, windows_core::IUnknown);
@@ -2878,11 +4180,31 @@ impl IActiveScriptSiteUIControl_Vtbl {
         iid == &<IActiveScriptSiteUIControl as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IActiveScriptSiteUIControl {}
-windows_core::imp::define_interface!(IActiveScriptSiteWindow, IActiveScriptSiteWindow_Vtbl, 0xd10f6761_83e9_11cf_8f20_00805f2cd064);
-windows_core::imp::interface_hierarchy!(IActiveScriptSiteWindow, windows_core::IUnknown);
-impl IActiveScriptSiteWindow {
-    pub unsafe fn GetWindow(&self) -> windows_core::Result<super::super::super::super::Foundation::HWND> {
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptSiteUIControl_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptSiteUIControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetUIBehavior<Identity: windows_core::IUnknownImpl + IActiveScriptSiteUIControl_Impl>(this: *mut core::ffi::c_void, uicitem: SCRIPTUICITEM, puichandling: *mut SCRIPTUICHANDLING) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptSiteUIControl_Impl::GetUIBehavior(this, core::mem::transmute_copy(&uicitem)) {
+                    Ok(ok__) => {
+                        puichandling.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetUIBehavior: GetUIBehavior::<Identity> }
+    };
+}
+impl windows_core::RuntimeName for IActiveScriptSiteUIControl {}
+windows_core::imp::define_interface!(IActiveScriptSiteWindow, IActiveScriptSiteWindow_Vtbl, 0xd10f6761_83e9_11cf_8f20_00805f2cd064);
+windows_core::imp::interface_hierarchy!(IActiveScriptSiteWindow, windows_core::IUnknown);
+impl IActiveScriptSiteWindow {
+    pub unsafe fn GetWindow(&self) -> windows_core::Result<super::super::super::super::Foundation::HWND> {
         unsafe {
             let mut result__ = core::mem::zeroed();
             (windows_core::Interface::vtable(self).GetWindow)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
@@ -2933,6 +4255,36 @@ impl IActiveScriptSiteWindow_Vtbl {
         iid == &<IActiveScriptSiteWindow as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptSiteWindow_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptSiteWindow_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetWindow<Identity: windows_core::IUnknownImpl + IActiveScriptSiteWindow_Impl>(this: *mut core::ffi::c_void, phwnd: *mut super::super::super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptSiteWindow_Impl::GetWindow(this) {
+                    Ok(ok__) => {
+                        phwnd.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnableModeless<Identity: windows_core::IUnknownImpl + IActiveScriptSiteWindow_Impl>(this: *mut core::ffi::c_void, fenable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptSiteWindow_Impl::EnableModeless(this, core::mem::transmute_copy(&fenable)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetWindow: GetWindow::<Identity>,
+            EnableModeless: EnableModeless::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IActiveScriptSiteWindow {}
 windows_core::imp::define_interface!(IActiveScriptStats, IActiveScriptStats_Vtbl, 0xb8da6310_e19b_11d0_933c_00a0c90dcaa9);
 windows_core::imp::interface_hierarchy!(IActiveScriptStats, windows_core::IUnknown);
@@ -2991,6 +4343,37 @@ impl IActiveScriptStats_Vtbl {
         iid == &<IActiveScriptStats as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptStats_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptStats_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStat<Identity: windows_core::IUnknownImpl + IActiveScriptStats_Impl>(this: *mut core::ffi::c_void, stid: u32, pluhi: *mut u32, plulo: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptStats_Impl::GetStat(this, core::mem::transmute_copy(&stid), core::mem::transmute_copy(&pluhi), core::mem::transmute_copy(&plulo)).into()
+            }
+        }
+        unsafe extern "system" fn GetStatEx<Identity: windows_core::IUnknownImpl + IActiveScriptStats_Impl>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pluhi: *mut u32, plulo: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptStats_Impl::GetStatEx(this, core::mem::transmute_copy(&guid), core::mem::transmute_copy(&pluhi), core::mem::transmute_copy(&plulo)).into()
+            }
+        }
+        unsafe extern "system" fn ResetStats<Identity: windows_core::IUnknownImpl + IActiveScriptStats_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptStats_Impl::ResetStats(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStat: GetStat::<Identity>,
+            GetStatEx: GetStatEx::<Identity>,
+            ResetStats: ResetStats::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IActiveScriptStats {}
 windows_core::imp::define_interface!(IActiveScriptStringCompare, IActiveScriptStringCompare_Vtbl, 0x58562769_ed52_42f7_8403_4963514e1f11);
 windows_core::imp::interface_hierarchy!(IActiveScriptStringCompare, windows_core::IUnknown);
@@ -3031,6 +4414,26 @@ impl IActiveScriptStringCompare_Vtbl {
         iid == &<IActiveScriptStringCompare as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptStringCompare_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptStringCompare_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StrComp<Identity: windows_core::IUnknownImpl + IActiveScriptStringCompare_Impl>(this: *mut core::ffi::c_void, bszstr1: *mut core::ffi::c_void, bszstr2: *mut core::ffi::c_void, iret: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptStringCompare_Impl::StrComp(this, core::mem::transmute(&bszstr1), core::mem::transmute(&bszstr2)) {
+                    Ok(ok__) => {
+                        iret.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, StrComp: StrComp::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IActiveScriptStringCompare {}
 windows_core::imp::define_interface!(IActiveScriptTraceInfo, IActiveScriptTraceInfo_Vtbl, 0xc35456e7_bebf_4a1b_86a9_24d56be8b369);
 windows_core::imp::interface_hierarchy!(IActiveScriptTraceInfo, windows_core::IUnknown);
@@ -3080,6 +4483,30 @@ impl IActiveScriptTraceInfo_Vtbl {
         iid == &<IActiveScriptTraceInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptTraceInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptTraceInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StartScriptTracing<Identity: windows_core::IUnknownImpl + IActiveScriptTraceInfo_Impl>(this: *mut core::ffi::c_void, psitetraceinfo: *mut core::ffi::c_void, guidcontextid: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptTraceInfo_Impl::StartScriptTracing(this, core::mem::transmute_copy(&psitetraceinfo), core::mem::transmute(&guidcontextid)).into()
+            }
+        }
+        unsafe extern "system" fn StopScriptTracing<Identity: windows_core::IUnknownImpl + IActiveScriptTraceInfo_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActiveScriptTraceInfo_Impl::StopScriptTracing(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            StartScriptTracing: StartScriptTracing::<Identity>,
+            StopScriptTracing: StopScriptTracing::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IActiveScriptTraceInfo {}
 windows_core::imp::define_interface!(IActiveScriptWinRTErrorDebug, IActiveScriptWinRTErrorDebug_Vtbl, 0x73a3f82a_0fe9_4b33_ba3b_fe095f697e0a);
 impl core::ops::Deref for IActiveScriptWinRTErrorDebug {
@@ -3174,6 +4601,56 @@ impl IActiveScriptWinRTErrorDebug_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IActiveScriptWinRTErrorDebug_Impl> windows_core::imp::TearOffVtable<Identity> for IActiveScriptWinRTErrorDebug_Vtbl
+where
+    IActiveScriptError_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRestrictedErrorString<Identity: windows_core::IUnknownImpl + IActiveScriptWinRTErrorDebug_Impl>(this: *mut core::ffi::c_void, errorstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptWinRTErrorDebug_Impl::GetRestrictedErrorString(this) {
+                    Ok(ok__) => {
+                        errorstring.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRestrictedErrorReference<Identity: windows_core::IUnknownImpl + IActiveScriptWinRTErrorDebug_Impl>(this: *mut core::ffi::c_void, referencestring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptWinRTErrorDebug_Impl::GetRestrictedErrorReference(this) {
+                    Ok(ok__) => {
+                        referencestring.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCapabilitySid<Identity: windows_core::IUnknownImpl + IActiveScriptWinRTErrorDebug_Impl>(this: *mut core::ffi::c_void, capabilitysid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActiveScriptWinRTErrorDebug_Impl::GetCapabilitySid(this) {
+                    Ok(ok__) => {
+                        capabilitysid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IActiveScriptError_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetRestrictedErrorString: GetRestrictedErrorString::<Identity>,
+            GetRestrictedErrorReference: GetRestrictedErrorReference::<Identity>,
+            GetCapabilitySid: GetCapabilitySid::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IActiveScriptWinRTErrorDebug {}
 windows_core::imp::define_interface!(IApplicationDebugger, IApplicationDebugger_Vtbl, 0x51973c2a_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IApplicationDebugger, windows_core::IUnknown);
@@ -3290,6 +4767,64 @@ impl IApplicationDebugger_Vtbl {
         iid == &<IApplicationDebugger as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IApplicationDebugger_Impl> windows_core::imp::TearOffVtable<Identity> for IApplicationDebugger_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryAlive<Identity: windows_core::IUnknownImpl + IApplicationDebugger_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IApplicationDebugger_Impl::QueryAlive(this).into()
+            }
+        }
+        unsafe extern "system" fn CreateInstanceAtDebugger<Identity: windows_core::IUnknownImpl + IApplicationDebugger_Impl>(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, punkouter: *mut core::ffi::c_void, dwclscontext: u32, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IApplicationDebugger_Impl::CreateInstanceAtDebugger(this, core::mem::transmute_copy(&rclsid), core::mem::transmute_copy(&punkouter), core::mem::transmute_copy(&dwclscontext), core::mem::transmute_copy(&riid)) {
+                    Ok(ok__) => {
+                        ppvobject.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn onDebugOutput<Identity: windows_core::IUnknownImpl + IApplicationDebugger_Impl>(this: *mut core::ffi::c_void, pstr: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IApplicationDebugger_Impl::onDebugOutput(this, core::mem::transmute(&pstr)).into()
+            }
+        }
+        unsafe extern "system" fn onHandleBreakPoint<Identity: windows_core::IUnknownImpl + IApplicationDebugger_Impl>(this: *mut core::ffi::c_void, prpt: *mut core::ffi::c_void, br: BREAKREASON, perror: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IApplicationDebugger_Impl::onHandleBreakPoint(this, core::mem::transmute_copy(&prpt), core::mem::transmute_copy(&br), core::mem::transmute_copy(&perror)).into()
+            }
+        }
+        unsafe extern "system" fn onClose<Identity: windows_core::IUnknownImpl + IApplicationDebugger_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IApplicationDebugger_Impl::onClose(this).into()
+            }
+        }
+        unsafe extern "system" fn onDebuggerEvent<Identity: windows_core::IUnknownImpl + IApplicationDebugger_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IApplicationDebugger_Impl::onDebuggerEvent(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&punk)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryAlive: QueryAlive::<Identity>,
+            CreateInstanceAtDebugger: CreateInstanceAtDebugger::<Identity>,
+            onDebugOutput: onDebugOutput::<Identity>,
+            onHandleBreakPoint: onHandleBreakPoint::<Identity>,
+            onClose: onClose::<Identity>,
+            onDebuggerEvent: onDebuggerEvent::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IApplicationDebugger {}
 windows_core::imp::define_interface!(IApplicationDebuggerUI, IApplicationDebuggerUI_Vtbl, 0x51973c2b_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IApplicationDebuggerUI, windows_core::IUnknown);
@@ -3342,6 +4877,30 @@ impl IApplicationDebuggerUI_Vtbl {
         iid == &<IApplicationDebuggerUI as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IApplicationDebuggerUI_Impl> windows_core::imp::TearOffVtable<Identity> for IApplicationDebuggerUI_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BringDocumentToTop<Identity: windows_core::IUnknownImpl + IApplicationDebuggerUI_Impl>(this: *mut core::ffi::c_void, pddt: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IApplicationDebuggerUI_Impl::BringDocumentToTop(this, core::mem::transmute_copy(&pddt)).into()
+            }
+        }
+        unsafe extern "system" fn BringDocumentContextToTop<Identity: windows_core::IUnknownImpl + IApplicationDebuggerUI_Impl>(this: *mut core::ffi::c_void, pddc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IApplicationDebuggerUI_Impl::BringDocumentContextToTop(this, core::mem::transmute_copy(&pddc)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            BringDocumentToTop: BringDocumentToTop::<Identity>,
+            BringDocumentContextToTop: BringDocumentContextToTop::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IApplicationDebuggerUI {}
 windows_core::imp::define_interface!(IBindEventHandler, IBindEventHandler_Vtbl, 0x63cdbcb0_c1b1_11d0_9336_00a0c90dcaa9);
 windows_core::imp::interface_hierarchy!(IBindEventHandler, windows_core::IUnknown);
@@ -3384,6 +4943,21 @@ impl IBindEventHandler_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IBindEventHandler_Impl> windows_core::imp::TearOffVtable<Identity> for IBindEventHandler_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BindHandler<Identity: windows_core::IUnknownImpl + IBindEventHandler_Impl>(this: *mut core::ffi::c_void, pstrevent: windows_core::PCWSTR, pdisp: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBindEventHandler_Impl::BindHandler(this, core::mem::transmute(&pstrevent), core::mem::transmute_copy(&pdisp)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, BindHandler: BindHandler::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IBindEventHandler {}
 windows_core::imp::define_interface!(IDebugApplication11032, IDebugApplication11032_Vtbl, 0xbdb3b5de_89f2_4e11_84a5_97445f941c7d);
 impl core::ops::Deref for IDebugApplication11032 {
@@ -3463,6 +5037,43 @@ impl IDebugApplication11032_Vtbl {
         iid == &<IDebugApplication11032 as windows_core::Interface>::IID || iid == &<IRemoteDebugApplication110 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugApplication11032_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugApplication11032_Vtbl
+where
+    IRemoteDebugApplication110_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SynchronousCallInMainThread<Identity: windows_core::IUnknownImpl + IDebugApplication11032_Impl>(this: *mut core::ffi::c_void, pptc: *mut core::ffi::c_void, dwparam1: usize, dwparam2: usize, dwparam3: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication11032_Impl::SynchronousCallInMainThread(this, core::mem::transmute_copy(&pptc), core::mem::transmute_copy(&dwparam1), core::mem::transmute_copy(&dwparam2), core::mem::transmute_copy(&dwparam3)).into()
+            }
+        }
+        unsafe extern "system" fn AsynchronousCallInMainThread<Identity: windows_core::IUnknownImpl + IDebugApplication11032_Impl>(this: *mut core::ffi::c_void, pptc: *mut core::ffi::c_void, dwparam1: usize, dwparam2: usize, dwparam3: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication11032_Impl::AsynchronousCallInMainThread(this, core::mem::transmute_copy(&pptc), core::mem::transmute_copy(&dwparam1), core::mem::transmute_copy(&dwparam2), core::mem::transmute_copy(&dwparam3)).into()
+            }
+        }
+        unsafe extern "system" fn CallableWaitForHandles<Identity: windows_core::IUnknownImpl + IDebugApplication11032_Impl>(this: *mut core::ffi::c_void, handlecount: u32, phandles: *const super::super::super::super::Foundation::HANDLE, pindex: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugApplication11032_Impl::CallableWaitForHandles(this, core::mem::transmute_copy(&handlecount), core::mem::transmute_copy(&phandles)) {
+                    Ok(ok__) => {
+                        pindex.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IRemoteDebugApplication110_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SynchronousCallInMainThread: SynchronousCallInMainThread::<Identity>,
+            AsynchronousCallInMainThread: AsynchronousCallInMainThread::<Identity>,
+            CallableWaitForHandles: CallableWaitForHandles::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugApplication11032 {}
 windows_core::imp::define_interface!(IDebugApplication11064, IDebugApplication11064_Vtbl, 0x2039d958_4eeb_496a_87bb_2e5201eadeef);
 impl core::ops::Deref for IDebugApplication11064 {
@@ -3542,6 +5153,43 @@ impl IDebugApplication11064_Vtbl {
         iid == &<IDebugApplication11064 as windows_core::Interface>::IID || iid == &<IRemoteDebugApplication110 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugApplication11064_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugApplication11064_Vtbl
+where
+    IRemoteDebugApplication110_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SynchronousCallInMainThread<Identity: windows_core::IUnknownImpl + IDebugApplication11064_Impl>(this: *mut core::ffi::c_void, pptc: *mut core::ffi::c_void, dwparam1: usize, dwparam2: usize, dwparam3: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication11064_Impl::SynchronousCallInMainThread(this, core::mem::transmute_copy(&pptc), core::mem::transmute_copy(&dwparam1), core::mem::transmute_copy(&dwparam2), core::mem::transmute_copy(&dwparam3)).into()
+            }
+        }
+        unsafe extern "system" fn AsynchronousCallInMainThread<Identity: windows_core::IUnknownImpl + IDebugApplication11064_Impl>(this: *mut core::ffi::c_void, pptc: *mut core::ffi::c_void, dwparam1: usize, dwparam2: usize, dwparam3: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication11064_Impl::AsynchronousCallInMainThread(this, core::mem::transmute_copy(&pptc), core::mem::transmute_copy(&dwparam1), core::mem::transmute_copy(&dwparam2), core::mem::transmute_copy(&dwparam3)).into()
+            }
+        }
+        unsafe extern "system" fn CallableWaitForHandles<Identity: windows_core::IUnknownImpl + IDebugApplication11064_Impl>(this: *mut core::ffi::c_void, handlecount: u32, phandles: *const super::super::super::super::Foundation::HANDLE, pindex: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugApplication11064_Impl::CallableWaitForHandles(this, core::mem::transmute_copy(&handlecount), core::mem::transmute_copy(&phandles)) {
+                    Ok(ok__) => {
+                        pindex.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IRemoteDebugApplication110_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SynchronousCallInMainThread: SynchronousCallInMainThread::<Identity>,
+            AsynchronousCallInMainThread: AsynchronousCallInMainThread::<Identity>,
+            CallableWaitForHandles: CallableWaitForHandles::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugApplication11064 {}
 windows_core::imp::define_interface!(IDebugApplication32, IDebugApplication32_Vtbl, 0x51973c32_cb0c_11d0_b5c9_00a0244a0e7a);
 impl core::ops::Deref for IDebugApplication32 {
@@ -3889,62 +5537,248 @@ impl IDebugApplication32_Vtbl {
         iid == &<IDebugApplication32 as windows_core::Interface>::IID || iid == &<IRemoteDebugApplication as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IDebugApplication32 {}
-windows_core::imp::define_interface!(IDebugApplication64, IDebugApplication64_Vtbl, 0x4dedc754_04c7_4f10_9e60_16a390fe6e62);
-impl core::ops::Deref for IDebugApplication64 {
-    type Target = IRemoteDebugApplication;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(IDebugApplication64, windows_core::IUnknown, IRemoteDebugApplication);
-impl IDebugApplication64 {
-    pub unsafe fn SetName<P0>(&self, pstrname: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<windows_core::PCWSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).SetName)(windows_core::Interface::as_raw(self), pstrname.param().abi()).ok() }
-    }
-    pub unsafe fn StepOutComplete(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).StepOutComplete)(windows_core::Interface::as_raw(self)).ok() }
-    }
-    pub unsafe fn DebugOutput<P0>(&self, pstr: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<windows_core::PCWSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).DebugOutput)(windows_core::Interface::as_raw(self), pstr.param().abi()).ok() }
-    }
-    pub unsafe fn StartDebugSession(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).StartDebugSession)(windows_core::Interface::as_raw(self)).ok() }
-    }
-    pub unsafe fn HandleBreakPoint(&self, br: BREAKREASON) -> windows_core::Result<BREAKRESUMEACTION> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).HandleBreakPoint)(windows_core::Interface::as_raw(self), br, &mut result__).map(|| result__)
+impl<Identity: windows_core::IUnknownImpl + IDebugApplication32_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugApplication32_Vtbl
+where
+    IRemoteDebugApplication_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetName<Identity: windows_core::IUnknownImpl + IDebugApplication32_Impl>(this: *mut core::ffi::c_void, pstrname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication32_Impl::SetName(this, core::mem::transmute(&pstrname)).into()
+            }
         }
-    }
-    pub unsafe fn Close(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Close)(windows_core::Interface::as_raw(self)).ok() }
-    }
-    pub unsafe fn GetBreakFlags(&self, pabf: *mut u32, pprdatsteppingthread: *mut Option<IRemoteDebugApplicationThread>) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetBreakFlags)(windows_core::Interface::as_raw(self), pabf as _, core::mem::transmute(pprdatsteppingthread)).ok() }
-    }
-    pub unsafe fn GetCurrentThread(&self) -> windows_core::Result<IDebugApplicationThread> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetCurrentThread)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn StepOutComplete<Identity: windows_core::IUnknownImpl + IDebugApplication32_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication32_Impl::StepOutComplete(this).into()
+            }
         }
-    }
-    pub unsafe fn CreateAsyncDebugOperation<P0>(&self, psdo: P0) -> windows_core::Result<IDebugAsyncOperation>
-    where
-        P0: windows_core::Param<IDebugSyncOperation>,
-    {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateAsyncDebugOperation)(windows_core::Interface::as_raw(self), psdo.param().abi(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn DebugOutput<Identity: windows_core::IUnknownImpl + IDebugApplication32_Impl>(this: *mut core::ffi::c_void, pstr: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication32_Impl::DebugOutput(this, core::mem::transmute(&pstr)).into()
+            }
         }
-    }
-    pub unsafe fn AddStackFrameSniffer<P0>(&self, pdsfs: P0) -> windows_core::Result<u32>
+        unsafe extern "system" fn StartDebugSession<Identity: windows_core::IUnknownImpl + IDebugApplication32_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication32_Impl::StartDebugSession(this).into()
+            }
+        }
+        unsafe extern "system" fn HandleBreakPoint<Identity: windows_core::IUnknownImpl + IDebugApplication32_Impl>(this: *mut core::ffi::c_void, br: BREAKREASON, pbra: *mut BREAKRESUMEACTION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugApplication32_Impl::HandleBreakPoint(this, core::mem::transmute_copy(&br)) {
+                    Ok(ok__) => {
+                        pbra.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IDebugApplication32_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication32_Impl::Close(this).into()
+            }
+        }
+        unsafe extern "system" fn GetBreakFlags<Identity: windows_core::IUnknownImpl + IDebugApplication32_Impl>(this: *mut core::ffi::c_void, pabf: *mut u32, pprdatsteppingthread: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication32_Impl::GetBreakFlags(this, core::mem::transmute_copy(&pabf), core::mem::transmute_copy(&pprdatsteppingthread)).into()
+            }
+        }
+        unsafe extern "system" fn GetCurrentThread<Identity: windows_core::IUnknownImpl + IDebugApplication32_Impl>(this: *mut core::ffi::c_void, pat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugApplication32_Impl::GetCurrentThread(this) {
+                    Ok(ok__) => {
+                        pat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateAsyncDebugOperation<Identity: windows_core::IUnknownImpl + IDebugApplication32_Impl>(this: *mut core::ffi::c_void, psdo: *mut core::ffi::c_void, ppado: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugApplication32_Impl::CreateAsyncDebugOperation(this, core::mem::transmute_copy(&psdo)) {
+                    Ok(ok__) => {
+                        ppado.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddStackFrameSniffer<Identity: windows_core::IUnknownImpl + IDebugApplication32_Impl>(this: *mut core::ffi::c_void, pdsfs: *mut core::ffi::c_void, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugApplication32_Impl::AddStackFrameSniffer(this, core::mem::transmute_copy(&pdsfs)) {
+                    Ok(ok__) => {
+                        pdwcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveStackFrameSniffer<Identity: windows_core::IUnknownImpl + IDebugApplication32_Impl>(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication32_Impl::RemoveStackFrameSniffer(this, core::mem::transmute_copy(&dwcookie)).into()
+            }
+        }
+        unsafe extern "system" fn QueryCurrentThreadIsDebuggerThread<Identity: windows_core::IUnknownImpl + IDebugApplication32_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication32_Impl::QueryCurrentThreadIsDebuggerThread(this).into()
+            }
+        }
+        unsafe extern "system" fn SynchronousCallInDebuggerThread<Identity: windows_core::IUnknownImpl + IDebugApplication32_Impl>(this: *mut core::ffi::c_void, pptc: *mut core::ffi::c_void, dwparam1: u32, dwparam2: u32, dwparam3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication32_Impl::SynchronousCallInDebuggerThread(this, core::mem::transmute_copy(&pptc), core::mem::transmute_copy(&dwparam1), core::mem::transmute_copy(&dwparam2), core::mem::transmute_copy(&dwparam3)).into()
+            }
+        }
+        unsafe extern "system" fn CreateApplicationNode<Identity: windows_core::IUnknownImpl + IDebugApplication32_Impl>(this: *mut core::ffi::c_void, ppdannew: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugApplication32_Impl::CreateApplicationNode(this) {
+                    Ok(ok__) => {
+                        ppdannew.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FireDebuggerEvent<Identity: windows_core::IUnknownImpl + IDebugApplication32_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication32_Impl::FireDebuggerEvent(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&punk)).into()
+            }
+        }
+        unsafe extern "system" fn HandleRuntimeError<Identity: windows_core::IUnknownImpl + IDebugApplication32_Impl>(this: *mut core::ffi::c_void, perrordebug: *mut core::ffi::c_void, pscriptsite: *mut core::ffi::c_void, pbra: *mut BREAKRESUMEACTION, perra: *mut ERRORRESUMEACTION, pfcallonscripterror: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication32_Impl::HandleRuntimeError(this, core::mem::transmute_copy(&perrordebug), core::mem::transmute_copy(&pscriptsite), core::mem::transmute_copy(&pbra), core::mem::transmute_copy(&perra), core::mem::transmute_copy(&pfcallonscripterror)).into()
+            }
+        }
+        unsafe extern "system" fn FCanJitDebug<Identity: windows_core::IUnknownImpl + IDebugApplication32_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication32_Impl::FCanJitDebug(this)
+            }
+        }
+        unsafe extern "system" fn FIsAutoJitDebugEnabled<Identity: windows_core::IUnknownImpl + IDebugApplication32_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication32_Impl::FIsAutoJitDebugEnabled(this)
+            }
+        }
+        unsafe extern "system" fn AddGlobalExpressionContextProvider<Identity: windows_core::IUnknownImpl + IDebugApplication32_Impl>(this: *mut core::ffi::c_void, pdsfs: *mut core::ffi::c_void, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugApplication32_Impl::AddGlobalExpressionContextProvider(this, core::mem::transmute_copy(&pdsfs)) {
+                    Ok(ok__) => {
+                        pdwcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveGlobalExpressionContextProvider<Identity: windows_core::IUnknownImpl + IDebugApplication32_Impl>(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication32_Impl::RemoveGlobalExpressionContextProvider(this, core::mem::transmute_copy(&dwcookie)).into()
+            }
+        }
+        Self {
+            base__: <IRemoteDebugApplication_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetName: SetName::<Identity>,
+            StepOutComplete: StepOutComplete::<Identity>,
+            DebugOutput: DebugOutput::<Identity>,
+            StartDebugSession: StartDebugSession::<Identity>,
+            HandleBreakPoint: HandleBreakPoint::<Identity>,
+            Close: Close::<Identity>,
+            GetBreakFlags: GetBreakFlags::<Identity>,
+            GetCurrentThread: GetCurrentThread::<Identity>,
+            CreateAsyncDebugOperation: CreateAsyncDebugOperation::<Identity>,
+            AddStackFrameSniffer: AddStackFrameSniffer::<Identity>,
+            RemoveStackFrameSniffer: RemoveStackFrameSniffer::<Identity>,
+            QueryCurrentThreadIsDebuggerThread: QueryCurrentThreadIsDebuggerThread::<Identity>,
+            SynchronousCallInDebuggerThread: SynchronousCallInDebuggerThread::<Identity>,
+            CreateApplicationNode: CreateApplicationNode::<Identity>,
+            FireDebuggerEvent: FireDebuggerEvent::<Identity>,
+            HandleRuntimeError: HandleRuntimeError::<Identity>,
+            FCanJitDebug: FCanJitDebug::<Identity>,
+            FIsAutoJitDebugEnabled: FIsAutoJitDebugEnabled::<Identity>,
+            AddGlobalExpressionContextProvider: AddGlobalExpressionContextProvider::<Identity>,
+            RemoveGlobalExpressionContextProvider: RemoveGlobalExpressionContextProvider::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IDebugApplication32 {}
+windows_core::imp::define_interface!(IDebugApplication64, IDebugApplication64_Vtbl, 0x4dedc754_04c7_4f10_9e60_16a390fe6e62);
+impl core::ops::Deref for IDebugApplication64 {
+    type Target = IRemoteDebugApplication;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(IDebugApplication64, windows_core::IUnknown, IRemoteDebugApplication);
+impl IDebugApplication64 {
+    pub unsafe fn SetName<P0>(&self, pstrname: P0) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::PCWSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).SetName)(windows_core::Interface::as_raw(self), pstrname.param().abi()).ok() }
+    }
+    pub unsafe fn StepOutComplete(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).StepOutComplete)(windows_core::Interface::as_raw(self)).ok() }
+    }
+    pub unsafe fn DebugOutput<P0>(&self, pstr: P0) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::PCWSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).DebugOutput)(windows_core::Interface::as_raw(self), pstr.param().abi()).ok() }
+    }
+    pub unsafe fn StartDebugSession(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).StartDebugSession)(windows_core::Interface::as_raw(self)).ok() }
+    }
+    pub unsafe fn HandleBreakPoint(&self, br: BREAKREASON) -> windows_core::Result<BREAKRESUMEACTION> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).HandleBreakPoint)(windows_core::Interface::as_raw(self), br, &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn Close(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Close)(windows_core::Interface::as_raw(self)).ok() }
+    }
+    pub unsafe fn GetBreakFlags(&self, pabf: *mut u32, pprdatsteppingthread: *mut Option<IRemoteDebugApplicationThread>) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetBreakFlags)(windows_core::Interface::as_raw(self), pabf as _, core::mem::transmute(pprdatsteppingthread)).ok() }
+    }
+    pub unsafe fn GetCurrentThread(&self) -> windows_core::Result<IDebugApplicationThread> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetCurrentThread)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn CreateAsyncDebugOperation<P0>(&self, psdo: P0) -> windows_core::Result<IDebugAsyncOperation>
+    where
+        P0: windows_core::Param<IDebugSyncOperation>,
+    {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateAsyncDebugOperation)(windows_core::Interface::as_raw(self), psdo.param().abi(), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn AddStackFrameSniffer<P0>(&self, pdsfs: P0) -> windows_core::Result<u32>
     where
         P0: windows_core::Param<IDebugStackFrameSniffer>,
     {
@@ -4236,6 +6070,192 @@ impl IDebugApplication64_Vtbl {
         iid == &<IDebugApplication64 as windows_core::Interface>::IID || iid == &<IRemoteDebugApplication as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugApplication64_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugApplication64_Vtbl
+where
+    IRemoteDebugApplication_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetName<Identity: windows_core::IUnknownImpl + IDebugApplication64_Impl>(this: *mut core::ffi::c_void, pstrname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication64_Impl::SetName(this, core::mem::transmute(&pstrname)).into()
+            }
+        }
+        unsafe extern "system" fn StepOutComplete<Identity: windows_core::IUnknownImpl + IDebugApplication64_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication64_Impl::StepOutComplete(this).into()
+            }
+        }
+        unsafe extern "system" fn DebugOutput<Identity: windows_core::IUnknownImpl + IDebugApplication64_Impl>(this: *mut core::ffi::c_void, pstr: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication64_Impl::DebugOutput(this, core::mem::transmute(&pstr)).into()
+            }
+        }
+        unsafe extern "system" fn StartDebugSession<Identity: windows_core::IUnknownImpl + IDebugApplication64_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication64_Impl::StartDebugSession(this).into()
+            }
+        }
+        unsafe extern "system" fn HandleBreakPoint<Identity: windows_core::IUnknownImpl + IDebugApplication64_Impl>(this: *mut core::ffi::c_void, br: BREAKREASON, pbra: *mut BREAKRESUMEACTION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugApplication64_Impl::HandleBreakPoint(this, core::mem::transmute_copy(&br)) {
+                    Ok(ok__) => {
+                        pbra.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IDebugApplication64_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication64_Impl::Close(this).into()
+            }
+        }
+        unsafe extern "system" fn GetBreakFlags<Identity: windows_core::IUnknownImpl + IDebugApplication64_Impl>(this: *mut core::ffi::c_void, pabf: *mut u32, pprdatsteppingthread: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication64_Impl::GetBreakFlags(this, core::mem::transmute_copy(&pabf), core::mem::transmute_copy(&pprdatsteppingthread)).into()
+            }
+        }
+        unsafe extern "system" fn GetCurrentThread<Identity: windows_core::IUnknownImpl + IDebugApplication64_Impl>(this: *mut core::ffi::c_void, pat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugApplication64_Impl::GetCurrentThread(this) {
+                    Ok(ok__) => {
+                        pat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateAsyncDebugOperation<Identity: windows_core::IUnknownImpl + IDebugApplication64_Impl>(this: *mut core::ffi::c_void, psdo: *mut core::ffi::c_void, ppado: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugApplication64_Impl::CreateAsyncDebugOperation(this, core::mem::transmute_copy(&psdo)) {
+                    Ok(ok__) => {
+                        ppado.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddStackFrameSniffer<Identity: windows_core::IUnknownImpl + IDebugApplication64_Impl>(this: *mut core::ffi::c_void, pdsfs: *mut core::ffi::c_void, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugApplication64_Impl::AddStackFrameSniffer(this, core::mem::transmute_copy(&pdsfs)) {
+                    Ok(ok__) => {
+                        pdwcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveStackFrameSniffer<Identity: windows_core::IUnknownImpl + IDebugApplication64_Impl>(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication64_Impl::RemoveStackFrameSniffer(this, core::mem::transmute_copy(&dwcookie)).into()
+            }
+        }
+        unsafe extern "system" fn QueryCurrentThreadIsDebuggerThread<Identity: windows_core::IUnknownImpl + IDebugApplication64_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication64_Impl::QueryCurrentThreadIsDebuggerThread(this).into()
+            }
+        }
+        unsafe extern "system" fn SynchronousCallInDebuggerThread<Identity: windows_core::IUnknownImpl + IDebugApplication64_Impl>(this: *mut core::ffi::c_void, pptc: *mut core::ffi::c_void, dwparam1: u64, dwparam2: u64, dwparam3: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication64_Impl::SynchronousCallInDebuggerThread(this, core::mem::transmute_copy(&pptc), core::mem::transmute_copy(&dwparam1), core::mem::transmute_copy(&dwparam2), core::mem::transmute_copy(&dwparam3)).into()
+            }
+        }
+        unsafe extern "system" fn CreateApplicationNode<Identity: windows_core::IUnknownImpl + IDebugApplication64_Impl>(this: *mut core::ffi::c_void, ppdannew: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugApplication64_Impl::CreateApplicationNode(this) {
+                    Ok(ok__) => {
+                        ppdannew.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FireDebuggerEvent<Identity: windows_core::IUnknownImpl + IDebugApplication64_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication64_Impl::FireDebuggerEvent(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&punk)).into()
+            }
+        }
+        unsafe extern "system" fn HandleRuntimeError<Identity: windows_core::IUnknownImpl + IDebugApplication64_Impl>(this: *mut core::ffi::c_void, perrordebug: *mut core::ffi::c_void, pscriptsite: *mut core::ffi::c_void, pbra: *mut BREAKRESUMEACTION, perra: *mut ERRORRESUMEACTION, pfcallonscripterror: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication64_Impl::HandleRuntimeError(this, core::mem::transmute_copy(&perrordebug), core::mem::transmute_copy(&pscriptsite), core::mem::transmute_copy(&pbra), core::mem::transmute_copy(&perra), core::mem::transmute_copy(&pfcallonscripterror)).into()
+            }
+        }
+        unsafe extern "system" fn FCanJitDebug<Identity: windows_core::IUnknownImpl + IDebugApplication64_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication64_Impl::FCanJitDebug(this)
+            }
+        }
+        unsafe extern "system" fn FIsAutoJitDebugEnabled<Identity: windows_core::IUnknownImpl + IDebugApplication64_Impl>(this: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication64_Impl::FIsAutoJitDebugEnabled(this)
+            }
+        }
+        unsafe extern "system" fn AddGlobalExpressionContextProvider<Identity: windows_core::IUnknownImpl + IDebugApplication64_Impl>(this: *mut core::ffi::c_void, pdsfs: *mut core::ffi::c_void, pdwcookie: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugApplication64_Impl::AddGlobalExpressionContextProvider(this, core::mem::transmute_copy(&pdsfs)) {
+                    Ok(ok__) => {
+                        pdwcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveGlobalExpressionContextProvider<Identity: windows_core::IUnknownImpl + IDebugApplication64_Impl>(this: *mut core::ffi::c_void, dwcookie: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplication64_Impl::RemoveGlobalExpressionContextProvider(this, core::mem::transmute_copy(&dwcookie)).into()
+            }
+        }
+        Self {
+            base__: <IRemoteDebugApplication_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetName: SetName::<Identity>,
+            StepOutComplete: StepOutComplete::<Identity>,
+            DebugOutput: DebugOutput::<Identity>,
+            StartDebugSession: StartDebugSession::<Identity>,
+            HandleBreakPoint: HandleBreakPoint::<Identity>,
+            Close: Close::<Identity>,
+            GetBreakFlags: GetBreakFlags::<Identity>,
+            GetCurrentThread: GetCurrentThread::<Identity>,
+            CreateAsyncDebugOperation: CreateAsyncDebugOperation::<Identity>,
+            AddStackFrameSniffer: AddStackFrameSniffer::<Identity>,
+            RemoveStackFrameSniffer: RemoveStackFrameSniffer::<Identity>,
+            QueryCurrentThreadIsDebuggerThread: QueryCurrentThreadIsDebuggerThread::<Identity>,
+            SynchronousCallInDebuggerThread: SynchronousCallInDebuggerThread::<Identity>,
+            CreateApplicationNode: CreateApplicationNode::<Identity>,
+            FireDebuggerEvent: FireDebuggerEvent::<Identity>,
+            HandleRuntimeError: HandleRuntimeError::<Identity>,
+            FCanJitDebug: FCanJitDebug::<Identity>,
+            FIsAutoJitDebugEnabled: FIsAutoJitDebugEnabled::<Identity>,
+            AddGlobalExpressionContextProvider: AddGlobalExpressionContextProvider::<Identity>,
+            RemoveGlobalExpressionContextProvider: RemoveGlobalExpressionContextProvider::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugApplication64 {}
 windows_core::imp::define_interface!(IDebugApplicationNode, IDebugApplicationNode_Vtbl, 0x51973c34_cb0c_11d0_b5c9_00a0244a0e7a);
 impl core::ops::Deref for IDebugApplicationNode {
@@ -4360,6 +6380,70 @@ impl IDebugApplicationNode_Vtbl {
         iid == &<IDebugApplicationNode as windows_core::Interface>::IID || iid == &<IDebugDocumentInfo as windows_core::Interface>::IID || iid == &<IDebugDocumentProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugApplicationNode_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugApplicationNode_Vtbl
+where
+    IDebugDocumentProvider_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumChildren<Identity: windows_core::IUnknownImpl + IDebugApplicationNode_Impl>(this: *mut core::ffi::c_void, pperddp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugApplicationNode_Impl::EnumChildren(this) {
+                    Ok(ok__) => {
+                        pperddp.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetParent<Identity: windows_core::IUnknownImpl + IDebugApplicationNode_Impl>(this: *mut core::ffi::c_void, pprddp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugApplicationNode_Impl::GetParent(this) {
+                    Ok(ok__) => {
+                        pprddp.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDocumentProvider<Identity: windows_core::IUnknownImpl + IDebugApplicationNode_Impl>(this: *mut core::ffi::c_void, pddp: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplicationNode_Impl::SetDocumentProvider(this, core::mem::transmute_copy(&pddp)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IDebugApplicationNode_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplicationNode_Impl::Close(this).into()
+            }
+        }
+        unsafe extern "system" fn Attach<Identity: windows_core::IUnknownImpl + IDebugApplicationNode_Impl>(this: *mut core::ffi::c_void, pdanparent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplicationNode_Impl::Attach(this, core::mem::transmute_copy(&pdanparent)).into()
+            }
+        }
+        unsafe extern "system" fn Detach<Identity: windows_core::IUnknownImpl + IDebugApplicationNode_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplicationNode_Impl::Detach(this).into()
+            }
+        }
+        Self {
+            base__: <IDebugDocumentProvider_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnumChildren: EnumChildren::<Identity>,
+            GetParent: GetParent::<Identity>,
+            SetDocumentProvider: SetDocumentProvider::<Identity>,
+            Close: Close::<Identity>,
+            Attach: Attach::<Identity>,
+            Detach: Detach::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugApplicationNode {}
 windows_core::imp::define_interface!(IDebugApplicationNode100, IDebugApplicationNode100_Vtbl, 0x90a7734e_841b_4f77_9384_a2891e76e7e2);
 windows_core::imp::interface_hierarchy!(IDebugApplicationNode100, windows_core::IUnknown);
@@ -4430,6 +6514,43 @@ impl IDebugApplicationNode100_Vtbl {
         iid == &<IDebugApplicationNode100 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugApplicationNode100_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugApplicationNode100_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetFilterForEventSink<Identity: windows_core::IUnknownImpl + IDebugApplicationNode100_Impl>(this: *mut core::ffi::c_void, dwcookie: u32, filter: APPLICATION_NODE_EVENT_FILTER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplicationNode100_Impl::SetFilterForEventSink(this, core::mem::transmute_copy(&dwcookie), core::mem::transmute_copy(&filter)).into()
+            }
+        }
+        unsafe extern "system" fn GetExcludedDocuments<Identity: windows_core::IUnknownImpl + IDebugApplicationNode100_Impl>(this: *mut core::ffi::c_void, filter: APPLICATION_NODE_EVENT_FILTER, pdocuments: *mut TEXT_DOCUMENT_ARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugApplicationNode100_Impl::GetExcludedDocuments(this, core::mem::transmute_copy(&filter)) {
+                    Ok(ok__) => {
+                        pdocuments.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryIsChildNode<Identity: windows_core::IUnknownImpl + IDebugApplicationNode100_Impl>(this: *mut core::ffi::c_void, psearchkey: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplicationNode100_Impl::QueryIsChildNode(this, core::mem::transmute_copy(&psearchkey)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetFilterForEventSink: SetFilterForEventSink::<Identity>,
+            GetExcludedDocuments: GetExcludedDocuments::<Identity>,
+            QueryIsChildNode: QueryIsChildNode::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugApplicationNode100 {}
 windows_core::imp::define_interface!(IDebugApplicationNodeEvents, IDebugApplicationNodeEvents_Vtbl, 0x51973c35_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IDebugApplicationNodeEvents, windows_core::IUnknown);
@@ -4509,6 +6630,44 @@ impl IDebugApplicationNodeEvents_Vtbl {
         iid == &<IDebugApplicationNodeEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugApplicationNodeEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugApplicationNodeEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn onAddChild<Identity: windows_core::IUnknownImpl + IDebugApplicationNodeEvents_Impl>(this: *mut core::ffi::c_void, prddpchild: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplicationNodeEvents_Impl::onAddChild(this, core::mem::transmute_copy(&prddpchild)).into()
+            }
+        }
+        unsafe extern "system" fn onRemoveChild<Identity: windows_core::IUnknownImpl + IDebugApplicationNodeEvents_Impl>(this: *mut core::ffi::c_void, prddpchild: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplicationNodeEvents_Impl::onRemoveChild(this, core::mem::transmute_copy(&prddpchild)).into()
+            }
+        }
+        unsafe extern "system" fn onDetach<Identity: windows_core::IUnknownImpl + IDebugApplicationNodeEvents_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplicationNodeEvents_Impl::onDetach(this).into()
+            }
+        }
+        unsafe extern "system" fn onAttach<Identity: windows_core::IUnknownImpl + IDebugApplicationNodeEvents_Impl>(this: *mut core::ffi::c_void, prddpparent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplicationNodeEvents_Impl::onAttach(this, core::mem::transmute_copy(&prddpparent)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            onAddChild: onAddChild::<Identity>,
+            onRemoveChild: onRemoveChild::<Identity>,
+            onDetach: onDetach::<Identity>,
+            onAttach: onAttach::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugApplicationNodeEvents {}
 windows_core::imp::define_interface!(IDebugApplicationThread, IDebugApplicationThread_Vtbl, 0x51973c38_cb0c_11d0_b5c9_00a0244a0e7a);
 impl core::ops::Deref for IDebugApplicationThread {
@@ -4606,6 +6765,51 @@ impl IDebugApplicationThread_Vtbl {
         iid == &<IDebugApplicationThread as windows_core::Interface>::IID || iid == &<IRemoteDebugApplicationThread as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugApplicationThread_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugApplicationThread_Vtbl
+where
+    IRemoteDebugApplicationThread_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SynchronousCallIntoThread32<Identity: windows_core::IUnknownImpl + IDebugApplicationThread_Impl>(this: *mut core::ffi::c_void, pstcb: *mut core::ffi::c_void, dwparam1: u32, dwparam2: u32, dwparam3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplicationThread_Impl::SynchronousCallIntoThread32(this, core::mem::transmute_copy(&pstcb), core::mem::transmute_copy(&dwparam1), core::mem::transmute_copy(&dwparam2), core::mem::transmute_copy(&dwparam3)).into()
+            }
+        }
+        unsafe extern "system" fn QueryIsCurrentThread<Identity: windows_core::IUnknownImpl + IDebugApplicationThread_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplicationThread_Impl::QueryIsCurrentThread(this).into()
+            }
+        }
+        unsafe extern "system" fn QueryIsDebuggerThread<Identity: windows_core::IUnknownImpl + IDebugApplicationThread_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplicationThread_Impl::QueryIsDebuggerThread(this).into()
+            }
+        }
+        unsafe extern "system" fn SetDescription<Identity: windows_core::IUnknownImpl + IDebugApplicationThread_Impl>(this: *mut core::ffi::c_void, pstrdescription: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplicationThread_Impl::SetDescription(this, core::mem::transmute(&pstrdescription)).into()
+            }
+        }
+        unsafe extern "system" fn SetStateString<Identity: windows_core::IUnknownImpl + IDebugApplicationThread_Impl>(this: *mut core::ffi::c_void, pstrstate: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplicationThread_Impl::SetStateString(this, core::mem::transmute(&pstrstate)).into()
+            }
+        }
+        Self {
+            base__: <IRemoteDebugApplicationThread_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SynchronousCallIntoThread32: SynchronousCallIntoThread32::<Identity>,
+            QueryIsCurrentThread: QueryIsCurrentThread::<Identity>,
+            QueryIsDebuggerThread: QueryIsDebuggerThread::<Identity>,
+            SetDescription: SetDescription::<Identity>,
+            SetStateString: SetStateString::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugApplicationThread {}
 windows_core::imp::define_interface!(IDebugApplicationThread11032, IDebugApplicationThread11032_Vtbl, 0x2194ac5c_6561_404a_a2e9_f57d72de3702);
 windows_core::imp::interface_hierarchy!(IDebugApplicationThread11032, windows_core::IUnknown);
@@ -4706,6 +6910,62 @@ impl IDebugApplicationThread11032_Vtbl {
         iid == &<IDebugApplicationThread11032 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugApplicationThread11032_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugApplicationThread11032_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetActiveThreadRequestCount<Identity: windows_core::IUnknownImpl + IDebugApplicationThread11032_Impl>(this: *mut core::ffi::c_void, puithreadrequests: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugApplicationThread11032_Impl::GetActiveThreadRequestCount(this) {
+                    Ok(ok__) => {
+                        puithreadrequests.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsSuspendedForBreakPoint<Identity: windows_core::IUnknownImpl + IDebugApplicationThread11032_Impl>(this: *mut core::ffi::c_void, pfissuspended: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugApplicationThread11032_Impl::IsSuspendedForBreakPoint(this) {
+                    Ok(ok__) => {
+                        pfissuspended.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsThreadCallable<Identity: windows_core::IUnknownImpl + IDebugApplicationThread11032_Impl>(this: *mut core::ffi::c_void, pfiscallable: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugApplicationThread11032_Impl::IsThreadCallable(this) {
+                    Ok(ok__) => {
+                        pfiscallable.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AsynchronousCallIntoThread<Identity: windows_core::IUnknownImpl + IDebugApplicationThread11032_Impl>(this: *mut core::ffi::c_void, pptc: *mut core::ffi::c_void, dwparam1: usize, dwparam2: usize, dwparam3: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplicationThread11032_Impl::AsynchronousCallIntoThread(this, core::mem::transmute_copy(&pptc), core::mem::transmute_copy(&dwparam1), core::mem::transmute_copy(&dwparam2), core::mem::transmute_copy(&dwparam3)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetActiveThreadRequestCount: GetActiveThreadRequestCount::<Identity>,
+            IsSuspendedForBreakPoint: IsSuspendedForBreakPoint::<Identity>,
+            IsThreadCallable: IsThreadCallable::<Identity>,
+            AsynchronousCallIntoThread: AsynchronousCallIntoThread::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugApplicationThread11032 {}
 windows_core::imp::define_interface!(IDebugApplicationThread11064, IDebugApplicationThread11064_Vtbl, 0x420aa4cc_efd8_4dac_983b_47127826917d);
 windows_core::imp::interface_hierarchy!(IDebugApplicationThread11064, windows_core::IUnknown);
@@ -4764,9 +7024,68 @@ impl IDebugApplicationThread11064_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn IsSuspendedForBreakPoint<Identity: IDebugApplicationThread11064_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfissuspended: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn IsSuspendedForBreakPoint<Identity: IDebugApplicationThread11064_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfissuspended: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDebugApplicationThread11064_Impl::IsSuspendedForBreakPoint(this) {
+                    Ok(ok__) => {
+                        pfissuspended.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsThreadCallable<Identity: IDebugApplicationThread11064_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfiscallable: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDebugApplicationThread11064_Impl::IsThreadCallable(this) {
+                    Ok(ok__) => {
+                        pfiscallable.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AsynchronousCallIntoThread<Identity: IDebugApplicationThread11064_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pptc: *mut core::ffi::c_void, dwparam1: usize, dwparam2: usize, dwparam3: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDebugApplicationThread11064_Impl::AsynchronousCallIntoThread(this, core::mem::transmute_copy(&pptc), core::mem::transmute_copy(&dwparam1), core::mem::transmute_copy(&dwparam2), core::mem::transmute_copy(&dwparam3)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            GetActiveThreadRequestCount: GetActiveThreadRequestCount::<Identity, OFFSET>,
+            IsSuspendedForBreakPoint: IsSuspendedForBreakPoint::<Identity, OFFSET>,
+            IsThreadCallable: IsThreadCallable::<Identity, OFFSET>,
+            AsynchronousCallIntoThread: AsynchronousCallIntoThread::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugApplicationThread11064 as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IDebugApplicationThread11064_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugApplicationThread11064_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetActiveThreadRequestCount<Identity: windows_core::IUnknownImpl + IDebugApplicationThread11064_Impl>(this: *mut core::ffi::c_void, puithreadrequests: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugApplicationThread11064_Impl::GetActiveThreadRequestCount(this) {
+                    Ok(ok__) => {
+                        puithreadrequests.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsSuspendedForBreakPoint<Identity: windows_core::IUnknownImpl + IDebugApplicationThread11064_Impl>(this: *mut core::ffi::c_void, pfissuspended: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDebugApplicationThread11064_Impl::IsSuspendedForBreakPoint(this) {
                     Ok(ok__) => {
                         pfissuspended.write(core::mem::transmute(ok__));
@@ -4776,9 +7095,9 @@ impl IDebugApplicationThread11064_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn IsThreadCallable<Identity: IDebugApplicationThread11064_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfiscallable: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn IsThreadCallable<Identity: windows_core::IUnknownImpl + IDebugApplicationThread11064_Impl>(this: *mut core::ffi::c_void, pfiscallable: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDebugApplicationThread11064_Impl::IsThreadCallable(this) {
                     Ok(ok__) => {
                         pfiscallable.write(core::mem::transmute(ok__));
@@ -4788,23 +7107,20 @@ impl IDebugApplicationThread11064_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn AsynchronousCallIntoThread<Identity: IDebugApplicationThread11064_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pptc: *mut core::ffi::c_void, dwparam1: usize, dwparam2: usize, dwparam3: usize) -> windows_core::HRESULT {
+        unsafe extern "system" fn AsynchronousCallIntoThread<Identity: windows_core::IUnknownImpl + IDebugApplicationThread11064_Impl>(this: *mut core::ffi::c_void, pptc: *mut core::ffi::c_void, dwparam1: usize, dwparam2: usize, dwparam3: usize) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDebugApplicationThread11064_Impl::AsynchronousCallIntoThread(this, core::mem::transmute_copy(&pptc), core::mem::transmute_copy(&dwparam1), core::mem::transmute_copy(&dwparam2), core::mem::transmute_copy(&dwparam3)).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            GetActiveThreadRequestCount: GetActiveThreadRequestCount::<Identity, OFFSET>,
-            IsSuspendedForBreakPoint: IsSuspendedForBreakPoint::<Identity, OFFSET>,
-            IsThreadCallable: IsThreadCallable::<Identity, OFFSET>,
-            AsynchronousCallIntoThread: AsynchronousCallIntoThread::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetActiveThreadRequestCount: GetActiveThreadRequestCount::<Identity>,
+            IsSuspendedForBreakPoint: IsSuspendedForBreakPoint::<Identity>,
+            IsThreadCallable: IsThreadCallable::<Identity>,
+            AsynchronousCallIntoThread: AsynchronousCallIntoThread::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDebugApplicationThread11064 as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IDebugApplicationThread11064 {}
 windows_core::imp::define_interface!(IDebugApplicationThread64, IDebugApplicationThread64_Vtbl, 0x9dac5886_dbad_456d_9dee_5dec39ab3dda);
@@ -4846,6 +7162,23 @@ impl IDebugApplicationThread64_Vtbl {
         iid == &<IDebugApplicationThread64 as windows_core::Interface>::IID || iid == &<IRemoteDebugApplicationThread as windows_core::Interface>::IID || iid == &<IDebugApplicationThread as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugApplicationThread64_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugApplicationThread64_Vtbl
+where
+    IDebugApplicationThread_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SynchronousCallIntoThread64<Identity: windows_core::IUnknownImpl + IDebugApplicationThread64_Impl>(this: *mut core::ffi::c_void, pstcb: *mut core::ffi::c_void, dwparam1: u64, dwparam2: u64, dwparam3: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplicationThread64_Impl::SynchronousCallIntoThread64(this, core::mem::transmute_copy(&pstcb), core::mem::transmute_copy(&dwparam1), core::mem::transmute_copy(&dwparam2), core::mem::transmute_copy(&dwparam3)).into()
+            }
+        }
+        Self {
+            base__: <IDebugApplicationThread_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SynchronousCallIntoThread64: SynchronousCallIntoThread64::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugApplicationThread64 {}
 windows_core::imp::define_interface!(IDebugApplicationThreadEvents110, IDebugApplicationThreadEvents110_Vtbl, 0x84e5e468_d5da_48a8_83f4_40366429007b);
 windows_core::imp::interface_hierarchy!(IDebugApplicationThreadEvents110, windows_core::IUnknown);
@@ -4916,6 +7249,44 @@ impl IDebugApplicationThreadEvents110_Vtbl {
         iid == &<IDebugApplicationThreadEvents110 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugApplicationThreadEvents110_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugApplicationThreadEvents110_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnSuspendForBreakPoint<Identity: windows_core::IUnknownImpl + IDebugApplicationThreadEvents110_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplicationThreadEvents110_Impl::OnSuspendForBreakPoint(this).into()
+            }
+        }
+        unsafe extern "system" fn OnResumeFromBreakPoint<Identity: windows_core::IUnknownImpl + IDebugApplicationThreadEvents110_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplicationThreadEvents110_Impl::OnResumeFromBreakPoint(this).into()
+            }
+        }
+        unsafe extern "system" fn OnThreadRequestComplete<Identity: windows_core::IUnknownImpl + IDebugApplicationThreadEvents110_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplicationThreadEvents110_Impl::OnThreadRequestComplete(this).into()
+            }
+        }
+        unsafe extern "system" fn OnBeginThreadRequest<Identity: windows_core::IUnknownImpl + IDebugApplicationThreadEvents110_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugApplicationThreadEvents110_Impl::OnBeginThreadRequest(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnSuspendForBreakPoint: OnSuspendForBreakPoint::<Identity>,
+            OnResumeFromBreakPoint: OnResumeFromBreakPoint::<Identity>,
+            OnThreadRequestComplete: OnThreadRequestComplete::<Identity>,
+            OnBeginThreadRequest: OnBeginThreadRequest::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugApplicationThreadEvents110 {}
 windows_core::imp::define_interface!(IDebugAsyncOperation, IDebugAsyncOperation_Vtbl, 0x51973c1b_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IDebugAsyncOperation, windows_core::IUnknown);
@@ -5010,6 +7381,57 @@ impl IDebugAsyncOperation_Vtbl {
         iid == &<IDebugAsyncOperation as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugAsyncOperation_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugAsyncOperation_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSyncDebugOperation<Identity: windows_core::IUnknownImpl + IDebugAsyncOperation_Impl>(this: *mut core::ffi::c_void, ppsdo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugAsyncOperation_Impl::GetSyncDebugOperation(this) {
+                    Ok(ok__) => {
+                        ppsdo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Start<Identity: windows_core::IUnknownImpl + IDebugAsyncOperation_Impl>(this: *mut core::ffi::c_void, padocb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugAsyncOperation_Impl::Start(this, core::mem::transmute_copy(&padocb)).into()
+            }
+        }
+        unsafe extern "system" fn Abort<Identity: windows_core::IUnknownImpl + IDebugAsyncOperation_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugAsyncOperation_Impl::Abort(this).into()
+            }
+        }
+        unsafe extern "system" fn QueryIsComplete<Identity: windows_core::IUnknownImpl + IDebugAsyncOperation_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugAsyncOperation_Impl::QueryIsComplete(this).into()
+            }
+        }
+        unsafe extern "system" fn GetResult<Identity: windows_core::IUnknownImpl + IDebugAsyncOperation_Impl>(this: *mut core::ffi::c_void, phrresult: *mut windows_core::HRESULT, ppunkresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugAsyncOperation_Impl::GetResult(this, core::mem::transmute_copy(&phrresult), core::mem::transmute_copy(&ppunkresult)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSyncDebugOperation: GetSyncDebugOperation::<Identity>,
+            Start: Start::<Identity>,
+            Abort: Abort::<Identity>,
+            QueryIsComplete: QueryIsComplete::<Identity>,
+            GetResult: GetResult::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugAsyncOperation {}
 windows_core::imp::define_interface!(IDebugAsyncOperationCallBack, IDebugAsyncOperationCallBack_Vtbl, 0x51973c1c_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IDebugAsyncOperationCallBack, windows_core::IUnknown);
@@ -5041,6 +7463,20 @@ impl IDebugAsyncOperationCallBack_Vtbl {
         iid == &<IDebugAsyncOperationCallBack as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugAsyncOperationCallBack_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugAsyncOperationCallBack_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn onComplete<Identity: windows_core::IUnknownImpl + IDebugAsyncOperationCallBack_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugAsyncOperationCallBack_Impl::onComplete(this).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, onComplete: onComplete::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDebugAsyncOperationCallBack {}
 windows_core::imp::define_interface!(IDebugCodeContext, IDebugCodeContext_Vtbl, 0x51973c13_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IDebugCodeContext, windows_core::IUnknown);
@@ -5096,6 +7532,36 @@ impl IDebugCodeContext_Vtbl {
         iid == &<IDebugCodeContext as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugCodeContext_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugCodeContext_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDocumentContext<Identity: windows_core::IUnknownImpl + IDebugCodeContext_Impl>(this: *mut core::ffi::c_void, ppsc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugCodeContext_Impl::GetDocumentContext(this) {
+                    Ok(ok__) => {
+                        ppsc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBreakPoint<Identity: windows_core::IUnknownImpl + IDebugCodeContext_Impl>(this: *mut core::ffi::c_void, bps: BREAKPOINT_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugCodeContext_Impl::SetBreakPoint(this, core::mem::transmute_copy(&bps)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDocumentContext: GetDocumentContext::<Identity>,
+            SetBreakPoint: SetBreakPoint::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugCodeContext {}
 windows_core::imp::define_interface!(IDebugCookie, IDebugCookie_Vtbl, 0x51973c39_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IDebugCookie, windows_core::IUnknown);
@@ -5127,6 +7593,20 @@ impl IDebugCookie_Vtbl {
         iid == &<IDebugCookie as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugCookie_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugCookie_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetDebugCookie<Identity: windows_core::IUnknownImpl + IDebugCookie_Impl>(this: *mut core::ffi::c_void, dwdebugappcookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugCookie_Impl::SetDebugCookie(this, core::mem::transmute_copy(&dwdebugappcookie)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetDebugCookie: SetDebugCookie::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDebugCookie {}
 windows_core::imp::define_interface!(IDebugDocument, IDebugDocument_Vtbl, 0x51973c21_cb0c_11d0_b5c9_00a0244a0e7a);
 impl core::ops::Deref for IDebugDocument {
@@ -5150,6 +7630,12 @@ impl IDebugDocument_Vtbl {
         iid == &<IDebugDocument as windows_core::Interface>::IID || iid == &<IDebugDocumentInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugDocument_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugDocument_Vtbl
+where
+    IDebugDocumentInfo_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IDebugDocumentInfo_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IDebugDocument {}
 windows_core::imp::define_interface!(IDebugDocumentContext, IDebugDocumentContext_Vtbl, 0x51973c28_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IDebugDocumentContext, windows_core::IUnknown);
@@ -5214,6 +7700,42 @@ impl IDebugDocumentContext_Vtbl {
         iid == &<IDebugDocumentContext as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugDocumentContext_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugDocumentContext_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDocument<Identity: windows_core::IUnknownImpl + IDebugDocumentContext_Impl>(this: *mut core::ffi::c_void, ppsd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugDocumentContext_Impl::GetDocument(this) {
+                    Ok(ok__) => {
+                        ppsd.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumCodeContexts<Identity: windows_core::IUnknownImpl + IDebugDocumentContext_Impl>(this: *mut core::ffi::c_void, ppescc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugDocumentContext_Impl::EnumCodeContexts(this) {
+                    Ok(ok__) => {
+                        ppescc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDocument: GetDocument::<Identity>,
+            EnumCodeContexts: EnumCodeContexts::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugDocumentContext {}
 windows_core::imp::define_interface!(IDebugDocumentHelper32, IDebugDocumentHelper32_Vtbl, 0x51973c26_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IDebugDocumentHelper32, windows_core::IUnknown);
@@ -5478,35 +8000,189 @@ impl IDebugDocumentHelper32_Vtbl {
         }
         unsafe extern "system" fn BringDocumentContextToTop<Identity: IDebugDocumentHelper32_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pddc: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDebugDocumentHelper32_Impl::BringDocumentContextToTop(this, core::mem::transmute_copy(&pddc)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            Init: Init::<Identity, OFFSET>,
+            Attach: Attach::<Identity, OFFSET>,
+            Detach: Detach::<Identity, OFFSET>,
+            AddUnicodeText: AddUnicodeText::<Identity, OFFSET>,
+            AddDBCSText: AddDBCSText::<Identity, OFFSET>,
+            SetDebugDocumentHost: SetDebugDocumentHost::<Identity, OFFSET>,
+            AddDeferredText: AddDeferredText::<Identity, OFFSET>,
+            DefineScriptBlock: DefineScriptBlock::<Identity, OFFSET>,
+            SetDefaultTextAttr: SetDefaultTextAttr::<Identity, OFFSET>,
+            SetTextAttributes: SetTextAttributes::<Identity, OFFSET>,
+            SetLongName: SetLongName::<Identity, OFFSET>,
+            SetShortName: SetShortName::<Identity, OFFSET>,
+            SetDocumentAttr: SetDocumentAttr::<Identity, OFFSET>,
+            GetDebugApplicationNode: GetDebugApplicationNode::<Identity, OFFSET>,
+            GetScriptBlockInfo: GetScriptBlockInfo::<Identity, OFFSET>,
+            CreateDebugDocumentContext: CreateDebugDocumentContext::<Identity, OFFSET>,
+            BringDocumentToTop: BringDocumentToTop::<Identity, OFFSET>,
+            BringDocumentContextToTop: BringDocumentContextToTop::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugDocumentHelper32 as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper32_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugDocumentHelper32_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Init<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper32_Impl>(this: *mut core::ffi::c_void, pda: *mut core::ffi::c_void, pszshortname: windows_core::PCWSTR, pszlongname: windows_core::PCWSTR, docattr: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper32_Impl::Init(this, core::mem::transmute_copy(&pda), core::mem::transmute(&pszshortname), core::mem::transmute(&pszlongname), core::mem::transmute_copy(&docattr)).into()
+            }
+        }
+        unsafe extern "system" fn Attach<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper32_Impl>(this: *mut core::ffi::c_void, pddhparent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper32_Impl::Attach(this, core::mem::transmute_copy(&pddhparent)).into()
+            }
+        }
+        unsafe extern "system" fn Detach<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper32_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper32_Impl::Detach(this).into()
+            }
+        }
+        unsafe extern "system" fn AddUnicodeText<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper32_Impl>(this: *mut core::ffi::c_void, psztext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper32_Impl::AddUnicodeText(this, core::mem::transmute(&psztext)).into()
+            }
+        }
+        unsafe extern "system" fn AddDBCSText<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper32_Impl>(this: *mut core::ffi::c_void, psztext: windows_core::PCSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper32_Impl::AddDBCSText(this, core::mem::transmute(&psztext)).into()
+            }
+        }
+        unsafe extern "system" fn SetDebugDocumentHost<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper32_Impl>(this: *mut core::ffi::c_void, pddh: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper32_Impl::SetDebugDocumentHost(this, core::mem::transmute_copy(&pddh)).into()
+            }
+        }
+        unsafe extern "system" fn AddDeferredText<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper32_Impl>(this: *mut core::ffi::c_void, cchars: u32, dwtextstartcookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper32_Impl::AddDeferredText(this, core::mem::transmute_copy(&cchars), core::mem::transmute_copy(&dwtextstartcookie)).into()
+            }
+        }
+        unsafe extern "system" fn DefineScriptBlock<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper32_Impl>(this: *mut core::ffi::c_void, ulcharoffset: u32, cchars: u32, pas: *mut core::ffi::c_void, fscriptlet: windows_core::BOOL, pdwsourcecontext: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugDocumentHelper32_Impl::DefineScriptBlock(this, core::mem::transmute_copy(&ulcharoffset), core::mem::transmute_copy(&cchars), core::mem::transmute_copy(&pas), core::mem::transmute_copy(&fscriptlet)) {
+                    Ok(ok__) => {
+                        pdwsourcecontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDefaultTextAttr<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper32_Impl>(this: *mut core::ffi::c_void, statextattr: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper32_Impl::SetDefaultTextAttr(this, core::mem::transmute_copy(&statextattr)).into()
+            }
+        }
+        unsafe extern "system" fn SetTextAttributes<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper32_Impl>(this: *mut core::ffi::c_void, ulcharoffset: u32, cchars: u32, pstatextattr: *const u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper32_Impl::SetTextAttributes(this, core::mem::transmute_copy(&ulcharoffset), core::mem::transmute_copy(&cchars), core::mem::transmute_copy(&pstatextattr)).into()
+            }
+        }
+        unsafe extern "system" fn SetLongName<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper32_Impl>(this: *mut core::ffi::c_void, pszlongname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper32_Impl::SetLongName(this, core::mem::transmute(&pszlongname)).into()
+            }
+        }
+        unsafe extern "system" fn SetShortName<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper32_Impl>(this: *mut core::ffi::c_void, pszshortname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper32_Impl::SetShortName(this, core::mem::transmute(&pszshortname)).into()
+            }
+        }
+        unsafe extern "system" fn SetDocumentAttr<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper32_Impl>(this: *mut core::ffi::c_void, pszattributes: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper32_Impl::SetDocumentAttr(this, core::mem::transmute_copy(&pszattributes)).into()
+            }
+        }
+        unsafe extern "system" fn GetDebugApplicationNode<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper32_Impl>(this: *mut core::ffi::c_void, ppdan: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugDocumentHelper32_Impl::GetDebugApplicationNode(this) {
+                    Ok(ok__) => {
+                        ppdan.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetScriptBlockInfo<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper32_Impl>(this: *mut core::ffi::c_void, dwsourcecontext: u32, ppasd: *mut *mut core::ffi::c_void, picharpos: *mut u32, pcchars: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper32_Impl::GetScriptBlockInfo(this, core::mem::transmute_copy(&dwsourcecontext), core::mem::transmute_copy(&ppasd), core::mem::transmute_copy(&picharpos), core::mem::transmute_copy(&pcchars)).into()
+            }
+        }
+        unsafe extern "system" fn CreateDebugDocumentContext<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper32_Impl>(this: *mut core::ffi::c_void, icharpos: u32, cchars: u32, ppddc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugDocumentHelper32_Impl::CreateDebugDocumentContext(this, core::mem::transmute_copy(&icharpos), core::mem::transmute_copy(&cchars)) {
+                    Ok(ok__) => {
+                        ppddc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn BringDocumentToTop<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper32_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper32_Impl::BringDocumentToTop(this).into()
+            }
+        }
+        unsafe extern "system" fn BringDocumentContextToTop<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper32_Impl>(this: *mut core::ffi::c_void, pddc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IDebugDocumentHelper32_Impl::BringDocumentContextToTop(this, core::mem::transmute_copy(&pddc)).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            Init: Init::<Identity, OFFSET>,
-            Attach: Attach::<Identity, OFFSET>,
-            Detach: Detach::<Identity, OFFSET>,
-            AddUnicodeText: AddUnicodeText::<Identity, OFFSET>,
-            AddDBCSText: AddDBCSText::<Identity, OFFSET>,
-            SetDebugDocumentHost: SetDebugDocumentHost::<Identity, OFFSET>,
-            AddDeferredText: AddDeferredText::<Identity, OFFSET>,
-            DefineScriptBlock: DefineScriptBlock::<Identity, OFFSET>,
-            SetDefaultTextAttr: SetDefaultTextAttr::<Identity, OFFSET>,
-            SetTextAttributes: SetTextAttributes::<Identity, OFFSET>,
-            SetLongName: SetLongName::<Identity, OFFSET>,
-            SetShortName: SetShortName::<Identity, OFFSET>,
-            SetDocumentAttr: SetDocumentAttr::<Identity, OFFSET>,
-            GetDebugApplicationNode: GetDebugApplicationNode::<Identity, OFFSET>,
-            GetScriptBlockInfo: GetScriptBlockInfo::<Identity, OFFSET>,
-            CreateDebugDocumentContext: CreateDebugDocumentContext::<Identity, OFFSET>,
-            BringDocumentToTop: BringDocumentToTop::<Identity, OFFSET>,
-            BringDocumentContextToTop: BringDocumentContextToTop::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDebugDocumentHelper32 as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Init: Init::<Identity>,
+            Attach: Attach::<Identity>,
+            Detach: Detach::<Identity>,
+            AddUnicodeText: AddUnicodeText::<Identity>,
+            AddDBCSText: AddDBCSText::<Identity>,
+            SetDebugDocumentHost: SetDebugDocumentHost::<Identity>,
+            AddDeferredText: AddDeferredText::<Identity>,
+            DefineScriptBlock: DefineScriptBlock::<Identity>,
+            SetDefaultTextAttr: SetDefaultTextAttr::<Identity>,
+            SetTextAttributes: SetTextAttributes::<Identity>,
+            SetLongName: SetLongName::<Identity>,
+            SetShortName: SetShortName::<Identity>,
+            SetDocumentAttr: SetDocumentAttr::<Identity>,
+            GetDebugApplicationNode: GetDebugApplicationNode::<Identity>,
+            GetScriptBlockInfo: GetScriptBlockInfo::<Identity>,
+            CreateDebugDocumentContext: CreateDebugDocumentContext::<Identity>,
+            BringDocumentToTop: BringDocumentToTop::<Identity>,
+            BringDocumentContextToTop: BringDocumentContextToTop::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IDebugDocumentHelper32 {}
 windows_core::imp::define_interface!(IDebugDocumentHelper64, IDebugDocumentHelper64_Vtbl, 0xc4c7363c_20fd_47f9_bd82_4855e0150871);
@@ -5802,6 +8478,160 @@ impl IDebugDocumentHelper64_Vtbl {
         iid == &<IDebugDocumentHelper64 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper64_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugDocumentHelper64_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Init<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper64_Impl>(this: *mut core::ffi::c_void, pda: *mut core::ffi::c_void, pszshortname: windows_core::PCWSTR, pszlongname: windows_core::PCWSTR, docattr: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper64_Impl::Init(this, core::mem::transmute_copy(&pda), core::mem::transmute(&pszshortname), core::mem::transmute(&pszlongname), core::mem::transmute_copy(&docattr)).into()
+            }
+        }
+        unsafe extern "system" fn Attach<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper64_Impl>(this: *mut core::ffi::c_void, pddhparent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper64_Impl::Attach(this, core::mem::transmute_copy(&pddhparent)).into()
+            }
+        }
+        unsafe extern "system" fn Detach<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper64_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper64_Impl::Detach(this).into()
+            }
+        }
+        unsafe extern "system" fn AddUnicodeText<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper64_Impl>(this: *mut core::ffi::c_void, psztext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper64_Impl::AddUnicodeText(this, core::mem::transmute(&psztext)).into()
+            }
+        }
+        unsafe extern "system" fn AddDBCSText<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper64_Impl>(this: *mut core::ffi::c_void, psztext: windows_core::PCSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper64_Impl::AddDBCSText(this, core::mem::transmute(&psztext)).into()
+            }
+        }
+        unsafe extern "system" fn SetDebugDocumentHost<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper64_Impl>(this: *mut core::ffi::c_void, pddh: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper64_Impl::SetDebugDocumentHost(this, core::mem::transmute_copy(&pddh)).into()
+            }
+        }
+        unsafe extern "system" fn AddDeferredText<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper64_Impl>(this: *mut core::ffi::c_void, cchars: u32, dwtextstartcookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper64_Impl::AddDeferredText(this, core::mem::transmute_copy(&cchars), core::mem::transmute_copy(&dwtextstartcookie)).into()
+            }
+        }
+        unsafe extern "system" fn DefineScriptBlock<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper64_Impl>(this: *mut core::ffi::c_void, ulcharoffset: u32, cchars: u32, pas: *mut core::ffi::c_void, fscriptlet: windows_core::BOOL, pdwsourcecontext: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugDocumentHelper64_Impl::DefineScriptBlock(this, core::mem::transmute_copy(&ulcharoffset), core::mem::transmute_copy(&cchars), core::mem::transmute_copy(&pas), core::mem::transmute_copy(&fscriptlet)) {
+                    Ok(ok__) => {
+                        pdwsourcecontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDefaultTextAttr<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper64_Impl>(this: *mut core::ffi::c_void, statextattr: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper64_Impl::SetDefaultTextAttr(this, core::mem::transmute_copy(&statextattr)).into()
+            }
+        }
+        unsafe extern "system" fn SetTextAttributes<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper64_Impl>(this: *mut core::ffi::c_void, ulcharoffset: u32, cchars: u32, pstatextattr: *const u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper64_Impl::SetTextAttributes(this, core::mem::transmute_copy(&ulcharoffset), core::mem::transmute_copy(&cchars), core::mem::transmute_copy(&pstatextattr)).into()
+            }
+        }
+        unsafe extern "system" fn SetLongName<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper64_Impl>(this: *mut core::ffi::c_void, pszlongname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper64_Impl::SetLongName(this, core::mem::transmute(&pszlongname)).into()
+            }
+        }
+        unsafe extern "system" fn SetShortName<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper64_Impl>(this: *mut core::ffi::c_void, pszshortname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper64_Impl::SetShortName(this, core::mem::transmute(&pszshortname)).into()
+            }
+        }
+        unsafe extern "system" fn SetDocumentAttr<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper64_Impl>(this: *mut core::ffi::c_void, pszattributes: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper64_Impl::SetDocumentAttr(this, core::mem::transmute_copy(&pszattributes)).into()
+            }
+        }
+        unsafe extern "system" fn GetDebugApplicationNode<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper64_Impl>(this: *mut core::ffi::c_void, ppdan: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugDocumentHelper64_Impl::GetDebugApplicationNode(this) {
+                    Ok(ok__) => {
+                        ppdan.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetScriptBlockInfo<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper64_Impl>(this: *mut core::ffi::c_void, dwsourcecontext: u64, ppasd: *mut *mut core::ffi::c_void, picharpos: *mut u32, pcchars: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper64_Impl::GetScriptBlockInfo(this, core::mem::transmute_copy(&dwsourcecontext), core::mem::transmute_copy(&ppasd), core::mem::transmute_copy(&picharpos), core::mem::transmute_copy(&pcchars)).into()
+            }
+        }
+        unsafe extern "system" fn CreateDebugDocumentContext<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper64_Impl>(this: *mut core::ffi::c_void, icharpos: u32, cchars: u32, ppddc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugDocumentHelper64_Impl::CreateDebugDocumentContext(this, core::mem::transmute_copy(&icharpos), core::mem::transmute_copy(&cchars)) {
+                    Ok(ok__) => {
+                        ppddc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn BringDocumentToTop<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper64_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper64_Impl::BringDocumentToTop(this).into()
+            }
+        }
+        unsafe extern "system" fn BringDocumentContextToTop<Identity: windows_core::IUnknownImpl + IDebugDocumentHelper64_Impl>(this: *mut core::ffi::c_void, pddc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHelper64_Impl::BringDocumentContextToTop(this, core::mem::transmute_copy(&pddc)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Init: Init::<Identity>,
+            Attach: Attach::<Identity>,
+            Detach: Detach::<Identity>,
+            AddUnicodeText: AddUnicodeText::<Identity>,
+            AddDBCSText: AddDBCSText::<Identity>,
+            SetDebugDocumentHost: SetDebugDocumentHost::<Identity>,
+            AddDeferredText: AddDeferredText::<Identity>,
+            DefineScriptBlock: DefineScriptBlock::<Identity>,
+            SetDefaultTextAttr: SetDefaultTextAttr::<Identity>,
+            SetTextAttributes: SetTextAttributes::<Identity>,
+            SetLongName: SetLongName::<Identity>,
+            SetShortName: SetShortName::<Identity>,
+            SetDocumentAttr: SetDocumentAttr::<Identity>,
+            GetDebugApplicationNode: GetDebugApplicationNode::<Identity>,
+            GetScriptBlockInfo: GetScriptBlockInfo::<Identity>,
+            CreateDebugDocumentContext: CreateDebugDocumentContext::<Identity>,
+            BringDocumentToTop: BringDocumentToTop::<Identity>,
+            BringDocumentContextToTop: BringDocumentContextToTop::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugDocumentHelper64 {}
 windows_core::imp::define_interface!(IDebugDocumentHost, IDebugDocumentHost_Vtbl, 0x51973c27_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IDebugDocumentHost, windows_core::IUnknown);
@@ -5918,6 +8748,70 @@ impl IDebugDocumentHost_Vtbl {
         iid == &<IDebugDocumentHost as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugDocumentHost_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugDocumentHost_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDeferredText<Identity: windows_core::IUnknownImpl + IDebugDocumentHost_Impl>(this: *mut core::ffi::c_void, dwtextstartcookie: u32, pchartext: windows_core::PWSTR, pstatextattr: *mut u16, pcnumchars: *mut u32, cmaxchars: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHost_Impl::GetDeferredText(this, core::mem::transmute_copy(&dwtextstartcookie), core::mem::transmute_copy(&pchartext), core::mem::transmute_copy(&pstatextattr), core::mem::transmute_copy(&pcnumchars), core::mem::transmute_copy(&cmaxchars)).into()
+            }
+        }
+        unsafe extern "system" fn GetScriptTextAttributes<Identity: windows_core::IUnknownImpl + IDebugDocumentHost_Impl>(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, unumcodechars: u32, pstrdelimiter: windows_core::PCWSTR, dwflags: u32, pattr: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHost_Impl::GetScriptTextAttributes(this, core::mem::transmute(&pstrcode), core::mem::transmute_copy(&unumcodechars), core::mem::transmute(&pstrdelimiter), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&pattr)).into()
+            }
+        }
+        unsafe extern "system" fn OnCreateDocumentContext<Identity: windows_core::IUnknownImpl + IDebugDocumentHost_Impl>(this: *mut core::ffi::c_void, ppunkouter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugDocumentHost_Impl::OnCreateDocumentContext(this) {
+                    Ok(ok__) => {
+                        ppunkouter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPathName<Identity: windows_core::IUnknownImpl + IDebugDocumentHost_Impl>(this: *mut core::ffi::c_void, pbstrlongname: *mut *mut core::ffi::c_void, pfisoriginalfile: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHost_Impl::GetPathName(this, core::mem::transmute_copy(&pbstrlongname), core::mem::transmute_copy(&pfisoriginalfile)).into()
+            }
+        }
+        unsafe extern "system" fn GetFileName<Identity: windows_core::IUnknownImpl + IDebugDocumentHost_Impl>(this: *mut core::ffi::c_void, pbstrshortname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugDocumentHost_Impl::GetFileName(this) {
+                    Ok(ok__) => {
+                        pbstrshortname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NotifyChanged<Identity: windows_core::IUnknownImpl + IDebugDocumentHost_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentHost_Impl::NotifyChanged(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDeferredText: GetDeferredText::<Identity>,
+            GetScriptTextAttributes: GetScriptTextAttributes::<Identity>,
+            OnCreateDocumentContext: OnCreateDocumentContext::<Identity>,
+            GetPathName: GetPathName::<Identity>,
+            GetFileName: GetFileName::<Identity>,
+            NotifyChanged: NotifyChanged::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugDocumentHost {}
 windows_core::imp::define_interface!(IDebugDocumentInfo, IDebugDocumentInfo_Vtbl, 0x51973c1f_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IDebugDocumentInfo, windows_core::IUnknown);
@@ -5982,6 +8876,42 @@ impl IDebugDocumentInfo_Vtbl {
         iid == &<IDebugDocumentInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugDocumentInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugDocumentInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IDebugDocumentInfo_Impl>(this: *mut core::ffi::c_void, dnt: DOCUMENTNAMETYPE, pbstrname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugDocumentInfo_Impl::GetName(this, core::mem::transmute_copy(&dnt)) {
+                    Ok(ok__) => {
+                        pbstrname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDocumentClassId<Identity: windows_core::IUnknownImpl + IDebugDocumentInfo_Impl>(this: *mut core::ffi::c_void, pclsiddocument: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugDocumentInfo_Impl::GetDocumentClassId(this) {
+                    Ok(ok__) => {
+                        pclsiddocument.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetName: GetName::<Identity>,
+            GetDocumentClassId: GetDocumentClassId::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugDocumentInfo {}
 windows_core::imp::define_interface!(IDebugDocumentProvider, IDebugDocumentProvider_Vtbl, 0x51973c20_cb0c_11d0_b5c9_00a0244a0e7a);
 impl core::ops::Deref for IDebugDocumentProvider {
@@ -6028,6 +8958,26 @@ impl IDebugDocumentProvider_Vtbl {
         iid == &<IDebugDocumentProvider as windows_core::Interface>::IID || iid == &<IDebugDocumentInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugDocumentProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugDocumentProvider_Vtbl
+where
+    IDebugDocumentInfo_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDocument<Identity: windows_core::IUnknownImpl + IDebugDocumentProvider_Impl>(this: *mut core::ffi::c_void, ppssd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugDocumentProvider_Impl::GetDocument(this) {
+                    Ok(ok__) => {
+                        ppssd.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IDebugDocumentInfo_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDocument: GetDocument::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDebugDocumentProvider {}
 windows_core::imp::define_interface!(IDebugDocumentText, IDebugDocumentText_Vtbl, 0x51973c22_cb0c_11d0_b5c9_00a0244a0e7a);
 impl core::ops::Deref for IDebugDocumentText {
@@ -6170,6 +9120,83 @@ impl IDebugDocumentText_Vtbl {
         iid == &<IDebugDocumentText as windows_core::Interface>::IID || iid == &<IDebugDocumentInfo as windows_core::Interface>::IID || iid == &<IDebugDocument as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugDocumentText_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugDocumentText_Vtbl
+where
+    IDebugDocument_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDocumentAttributes<Identity: windows_core::IUnknownImpl + IDebugDocumentText_Impl>(this: *mut core::ffi::c_void, ptextdocattr: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugDocumentText_Impl::GetDocumentAttributes(this) {
+                    Ok(ok__) => {
+                        ptextdocattr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSize<Identity: windows_core::IUnknownImpl + IDebugDocumentText_Impl>(this: *mut core::ffi::c_void, pcnumlines: *mut u32, pcnumchars: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentText_Impl::GetSize(this, core::mem::transmute_copy(&pcnumlines), core::mem::transmute_copy(&pcnumchars)).into()
+            }
+        }
+        unsafe extern "system" fn GetPositionOfLine<Identity: windows_core::IUnknownImpl + IDebugDocumentText_Impl>(this: *mut core::ffi::c_void, clinenumber: u32, pccharacterposition: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugDocumentText_Impl::GetPositionOfLine(this, core::mem::transmute_copy(&clinenumber)) {
+                    Ok(ok__) => {
+                        pccharacterposition.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLineOfPosition<Identity: windows_core::IUnknownImpl + IDebugDocumentText_Impl>(this: *mut core::ffi::c_void, ccharacterposition: u32, pclinenumber: *mut u32, pccharacteroffsetinline: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentText_Impl::GetLineOfPosition(this, core::mem::transmute_copy(&ccharacterposition), core::mem::transmute_copy(&pclinenumber), core::mem::transmute_copy(&pccharacteroffsetinline)).into()
+            }
+        }
+        unsafe extern "system" fn GetText<Identity: windows_core::IUnknownImpl + IDebugDocumentText_Impl>(this: *mut core::ffi::c_void, ccharacterposition: u32, pchartext: windows_core::PWSTR, pstatextattr: *mut u16, pcnumchars: *mut u32, cmaxchars: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentText_Impl::GetText(this, core::mem::transmute_copy(&ccharacterposition), core::mem::transmute_copy(&pchartext), core::mem::transmute_copy(&pstatextattr), core::mem::transmute_copy(&pcnumchars), core::mem::transmute_copy(&cmaxchars)).into()
+            }
+        }
+        unsafe extern "system" fn GetPositionOfContext<Identity: windows_core::IUnknownImpl + IDebugDocumentText_Impl>(this: *mut core::ffi::c_void, psc: *mut core::ffi::c_void, pccharacterposition: *mut u32, cnumchars: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentText_Impl::GetPositionOfContext(this, core::mem::transmute_copy(&psc), core::mem::transmute_copy(&pccharacterposition), core::mem::transmute_copy(&cnumchars)).into()
+            }
+        }
+        unsafe extern "system" fn GetContextOfPosition<Identity: windows_core::IUnknownImpl + IDebugDocumentText_Impl>(this: *mut core::ffi::c_void, ccharacterposition: u32, cnumchars: u32, ppsc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugDocumentText_Impl::GetContextOfPosition(this, core::mem::transmute_copy(&ccharacterposition), core::mem::transmute_copy(&cnumchars)) {
+                    Ok(ok__) => {
+                        ppsc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDebugDocument_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDocumentAttributes: GetDocumentAttributes::<Identity>,
+            GetSize: GetSize::<Identity>,
+            GetPositionOfLine: GetPositionOfLine::<Identity>,
+            GetLineOfPosition: GetLineOfPosition::<Identity>,
+            GetText: GetText::<Identity>,
+            GetPositionOfContext: GetPositionOfContext::<Identity>,
+            GetContextOfPosition: GetContextOfPosition::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugDocumentText {}
 windows_core::imp::define_interface!(IDebugDocumentTextAuthor, IDebugDocumentTextAuthor_Vtbl, 0x51973c24_cb0c_11d0_b5c9_00a0244a0e7a);
 impl core::ops::Deref for IDebugDocumentTextAuthor {
@@ -6229,10 +9256,41 @@ impl IDebugDocumentTextAuthor_Vtbl {
             RemoveText: RemoveText::<Identity, OFFSET>,
             ReplaceText: ReplaceText::<Identity, OFFSET>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDebugDocumentTextAuthor as windows_core::Interface>::IID || iid == &<IDebugDocumentInfo as windows_core::Interface>::IID || iid == &<IDebugDocument as windows_core::Interface>::IID || iid == &<IDebugDocumentText as windows_core::Interface>::IID
-    }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugDocumentTextAuthor as windows_core::Interface>::IID || iid == &<IDebugDocumentInfo as windows_core::Interface>::IID || iid == &<IDebugDocument as windows_core::Interface>::IID || iid == &<IDebugDocumentText as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IDebugDocumentTextAuthor_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugDocumentTextAuthor_Vtbl
+where
+    IDebugDocumentText_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InsertText<Identity: windows_core::IUnknownImpl + IDebugDocumentTextAuthor_Impl>(this: *mut core::ffi::c_void, ccharacterposition: u32, cnumtoinsert: u32, pchartext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentTextAuthor_Impl::InsertText(this, core::mem::transmute_copy(&ccharacterposition), core::mem::transmute_copy(&cnumtoinsert), core::mem::transmute(&pchartext)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveText<Identity: windows_core::IUnknownImpl + IDebugDocumentTextAuthor_Impl>(this: *mut core::ffi::c_void, ccharacterposition: u32, cnumtoremove: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentTextAuthor_Impl::RemoveText(this, core::mem::transmute_copy(&ccharacterposition), core::mem::transmute_copy(&cnumtoremove)).into()
+            }
+        }
+        unsafe extern "system" fn ReplaceText<Identity: windows_core::IUnknownImpl + IDebugDocumentTextAuthor_Impl>(this: *mut core::ffi::c_void, ccharacterposition: u32, cnumtoreplace: u32, pchartext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentTextAuthor_Impl::ReplaceText(this, core::mem::transmute_copy(&ccharacterposition), core::mem::transmute_copy(&cnumtoreplace), core::mem::transmute(&pchartext)).into()
+            }
+        }
+        Self {
+            base__: <IDebugDocumentText_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InsertText: InsertText::<Identity>,
+            RemoveText: RemoveText::<Identity>,
+            ReplaceText: ReplaceText::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IDebugDocumentTextAuthor {}
 windows_core::imp::define_interface!(IDebugDocumentTextEvents, IDebugDocumentTextEvents_Vtbl, 0x51973c23_cb0c_11d0_b5c9_00a0244a0e7a);
@@ -6328,6 +9386,58 @@ impl IDebugDocumentTextEvents_Vtbl {
         iid == &<IDebugDocumentTextEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugDocumentTextEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugDocumentTextEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn onDestroy<Identity: windows_core::IUnknownImpl + IDebugDocumentTextEvents_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentTextEvents_Impl::onDestroy(this).into()
+            }
+        }
+        unsafe extern "system" fn onInsertText<Identity: windows_core::IUnknownImpl + IDebugDocumentTextEvents_Impl>(this: *mut core::ffi::c_void, ccharacterposition: u32, cnumtoinsert: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentTextEvents_Impl::onInsertText(this, core::mem::transmute_copy(&ccharacterposition), core::mem::transmute_copy(&cnumtoinsert)).into()
+            }
+        }
+        unsafe extern "system" fn onRemoveText<Identity: windows_core::IUnknownImpl + IDebugDocumentTextEvents_Impl>(this: *mut core::ffi::c_void, ccharacterposition: u32, cnumtoremove: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentTextEvents_Impl::onRemoveText(this, core::mem::transmute_copy(&ccharacterposition), core::mem::transmute_copy(&cnumtoremove)).into()
+            }
+        }
+        unsafe extern "system" fn onReplaceText<Identity: windows_core::IUnknownImpl + IDebugDocumentTextEvents_Impl>(this: *mut core::ffi::c_void, ccharacterposition: u32, cnumtoreplace: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentTextEvents_Impl::onReplaceText(this, core::mem::transmute_copy(&ccharacterposition), core::mem::transmute_copy(&cnumtoreplace)).into()
+            }
+        }
+        unsafe extern "system" fn onUpdateTextAttributes<Identity: windows_core::IUnknownImpl + IDebugDocumentTextEvents_Impl>(this: *mut core::ffi::c_void, ccharacterposition: u32, cnumtoupdate: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentTextEvents_Impl::onUpdateTextAttributes(this, core::mem::transmute_copy(&ccharacterposition), core::mem::transmute_copy(&cnumtoupdate)).into()
+            }
+        }
+        unsafe extern "system" fn onUpdateDocumentAttributes<Identity: windows_core::IUnknownImpl + IDebugDocumentTextEvents_Impl>(this: *mut core::ffi::c_void, textdocattr: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentTextEvents_Impl::onUpdateDocumentAttributes(this, core::mem::transmute_copy(&textdocattr)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            onDestroy: onDestroy::<Identity>,
+            onInsertText: onInsertText::<Identity>,
+            onRemoveText: onRemoveText::<Identity>,
+            onReplaceText: onReplaceText::<Identity>,
+            onUpdateTextAttributes: onUpdateTextAttributes::<Identity>,
+            onUpdateDocumentAttributes: onUpdateDocumentAttributes::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugDocumentTextEvents {}
 windows_core::imp::define_interface!(IDebugDocumentTextExternalAuthor, IDebugDocumentTextExternalAuthor_Vtbl, 0x51973c25_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IDebugDocumentTextExternalAuthor, windows_core::IUnknown);
@@ -6395,6 +9505,43 @@ impl IDebugDocumentTextExternalAuthor_Vtbl {
         iid == &<IDebugDocumentTextExternalAuthor as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugDocumentTextExternalAuthor_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugDocumentTextExternalAuthor_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPathName<Identity: windows_core::IUnknownImpl + IDebugDocumentTextExternalAuthor_Impl>(this: *mut core::ffi::c_void, pbstrlongname: *mut *mut core::ffi::c_void, pfisoriginalfile: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentTextExternalAuthor_Impl::GetPathName(this, core::mem::transmute_copy(&pbstrlongname), core::mem::transmute_copy(&pfisoriginalfile)).into()
+            }
+        }
+        unsafe extern "system" fn GetFileName<Identity: windows_core::IUnknownImpl + IDebugDocumentTextExternalAuthor_Impl>(this: *mut core::ffi::c_void, pbstrshortname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugDocumentTextExternalAuthor_Impl::GetFileName(this) {
+                    Ok(ok__) => {
+                        pbstrshortname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NotifyChanged<Identity: windows_core::IUnknownImpl + IDebugDocumentTextExternalAuthor_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugDocumentTextExternalAuthor_Impl::NotifyChanged(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPathName: GetPathName::<Identity>,
+            GetFileName: GetFileName::<Identity>,
+            NotifyChanged: NotifyChanged::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugDocumentTextExternalAuthor {}
 windows_core::imp::define_interface!(IDebugExpression, IDebugExpression_Vtbl, 0x51973c14_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IDebugExpression, windows_core::IUnknown);
@@ -6480,6 +9627,51 @@ impl IDebugExpression_Vtbl {
         iid == &<IDebugExpression as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugExpression_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugExpression_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Start<Identity: windows_core::IUnknownImpl + IDebugExpression_Impl>(this: *mut core::ffi::c_void, pdecb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugExpression_Impl::Start(this, core::mem::transmute_copy(&pdecb)).into()
+            }
+        }
+        unsafe extern "system" fn Abort<Identity: windows_core::IUnknownImpl + IDebugExpression_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugExpression_Impl::Abort(this).into()
+            }
+        }
+        unsafe extern "system" fn QueryIsComplete<Identity: windows_core::IUnknownImpl + IDebugExpression_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugExpression_Impl::QueryIsComplete(this).into()
+            }
+        }
+        unsafe extern "system" fn GetResultAsString<Identity: windows_core::IUnknownImpl + IDebugExpression_Impl>(this: *mut core::ffi::c_void, phrresult: *mut windows_core::HRESULT, pbstrresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugExpression_Impl::GetResultAsString(this, core::mem::transmute_copy(&phrresult), core::mem::transmute_copy(&pbstrresult)).into()
+            }
+        }
+        unsafe extern "system" fn GetResultAsDebugProperty<Identity: windows_core::IUnknownImpl + IDebugExpression_Impl>(this: *mut core::ffi::c_void, phrresult: *mut windows_core::HRESULT, ppdp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugExpression_Impl::GetResultAsDebugProperty(this, core::mem::transmute_copy(&phrresult), core::mem::transmute_copy(&ppdp)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Start: Start::<Identity>,
+            Abort: Abort::<Identity>,
+            QueryIsComplete: QueryIsComplete::<Identity>,
+            GetResultAsString: GetResultAsString::<Identity>,
+            GetResultAsDebugProperty: GetResultAsDebugProperty::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugExpression {}
 windows_core::imp::define_interface!(IDebugExpressionCallBack, IDebugExpressionCallBack_Vtbl, 0x51973c16_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IDebugExpressionCallBack, windows_core::IUnknown);
@@ -6511,6 +9703,20 @@ impl IDebugExpressionCallBack_Vtbl {
         iid == &<IDebugExpressionCallBack as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugExpressionCallBack_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugExpressionCallBack_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn onComplete<Identity: windows_core::IUnknownImpl + IDebugExpressionCallBack_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugExpressionCallBack_Impl::onComplete(this).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, onComplete: onComplete::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDebugExpressionCallBack {}
 windows_core::imp::define_interface!(IDebugExpressionContext, IDebugExpressionContext_Vtbl, 0x51973c15_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IDebugExpressionContext, windows_core::IUnknown);
@@ -6570,6 +9776,36 @@ impl IDebugExpressionContext_Vtbl {
         iid == &<IDebugExpressionContext as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugExpressionContext_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugExpressionContext_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ParseLanguageText<Identity: windows_core::IUnknownImpl + IDebugExpressionContext_Impl>(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, nradix: u32, pstrdelimiter: windows_core::PCWSTR, dwflags: u32, ppe: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugExpressionContext_Impl::ParseLanguageText(this, core::mem::transmute(&pstrcode), core::mem::transmute_copy(&nradix), core::mem::transmute(&pstrdelimiter), core::mem::transmute_copy(&dwflags)) {
+                    Ok(ok__) => {
+                        ppe.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLanguageInfo<Identity: windows_core::IUnknownImpl + IDebugExpressionContext_Impl>(this: *mut core::ffi::c_void, pbstrlanguagename: *mut *mut core::ffi::c_void, planguageid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugExpressionContext_Impl::GetLanguageInfo(this, core::mem::transmute_copy(&pbstrlanguagename), core::mem::transmute_copy(&planguageid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ParseLanguageText: ParseLanguageText::<Identity>,
+            GetLanguageInfo: GetLanguageInfo::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugExpressionContext {}
 windows_core::imp::define_interface!(IDebugFormatter, IDebugFormatter_Vtbl, 0x51973c05_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IDebugFormatter, windows_core::IUnknown);
@@ -6673,6 +9909,56 @@ impl IDebugFormatter_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IDebugFormatter_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugFormatter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStringForVariant<Identity: windows_core::IUnknownImpl + IDebugFormatter_Impl>(this: *mut core::ffi::c_void, pvar: *const super::super::super::Variant::VARIANT, nradix: u32, pbstrvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugFormatter_Impl::GetStringForVariant(this, core::mem::transmute_copy(&pvar), core::mem::transmute_copy(&nradix)) {
+                    Ok(ok__) => {
+                        pbstrvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetVariantForString<Identity: windows_core::IUnknownImpl + IDebugFormatter_Impl>(this: *mut core::ffi::c_void, pwstrvalue: windows_core::PCWSTR, pvar: *mut super::super::super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugFormatter_Impl::GetVariantForString(this, core::mem::transmute(&pwstrvalue)) {
+                    Ok(ok__) => {
+                        pvar.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStringForVarType<Identity: windows_core::IUnknownImpl + IDebugFormatter_Impl>(this: *mut core::ffi::c_void, vt: super::super::super::Variant::VARENUM, ptdescarraytype: *const super::super::super::Com::TYPEDESC, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugFormatter_Impl::GetStringForVarType(this, core::mem::transmute_copy(&vt), core::mem::transmute_copy(&ptdescarraytype)) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStringForVariant: GetStringForVariant::<Identity>,
+            GetVariantForString: GetVariantForString::<Identity>,
+            GetStringForVarType: GetStringForVarType::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IDebugFormatter {}
 windows_core::imp::define_interface!(IDebugHelper, IDebugHelper_Vtbl, 0x51973c3f_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IDebugHelper, windows_core::IUnknown);
@@ -6785,6 +10071,56 @@ impl IDebugHelper_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IDebugHelper_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugHelper_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreatePropertyBrowser<Identity: windows_core::IUnknownImpl + IDebugHelper_Impl>(this: *mut core::ffi::c_void, pvar: *const super::super::super::Variant::VARIANT, bstrname: windows_core::PCWSTR, pdat: *mut core::ffi::c_void, ppdob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugHelper_Impl::CreatePropertyBrowser(this, core::mem::transmute_copy(&pvar), core::mem::transmute(&bstrname), core::mem::transmute_copy(&pdat)) {
+                    Ok(ok__) => {
+                        ppdob.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreatePropertyBrowserEx<Identity: windows_core::IUnknownImpl + IDebugHelper_Impl>(this: *mut core::ffi::c_void, pvar: *const super::super::super::Variant::VARIANT, bstrname: windows_core::PCWSTR, pdat: *mut core::ffi::c_void, pdf: *mut core::ffi::c_void, ppdob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugHelper_Impl::CreatePropertyBrowserEx(this, core::mem::transmute_copy(&pvar), core::mem::transmute(&bstrname), core::mem::transmute_copy(&pdat), core::mem::transmute_copy(&pdf)) {
+                    Ok(ok__) => {
+                        ppdob.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSimpleConnectionPoint<Identity: windows_core::IUnknownImpl + IDebugHelper_Impl>(this: *mut core::ffi::c_void, pdisp: *mut core::ffi::c_void, ppscp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugHelper_Impl::CreateSimpleConnectionPoint(this, core::mem::transmute_copy(&pdisp)) {
+                    Ok(ok__) => {
+                        ppscp.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreatePropertyBrowser: CreatePropertyBrowser::<Identity>,
+            CreatePropertyBrowserEx: CreatePropertyBrowserEx::<Identity>,
+            CreateSimpleConnectionPoint: CreateSimpleConnectionPoint::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IDebugHelper {}
 windows_core::imp::define_interface!(IDebugSessionProvider, IDebugSessionProvider_Vtbl, 0x51973c29_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IDebugSessionProvider, windows_core::IUnknown);
@@ -6819,6 +10155,20 @@ impl IDebugSessionProvider_Vtbl {
         iid == &<IDebugSessionProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugSessionProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugSessionProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StartDebugSession<Identity: windows_core::IUnknownImpl + IDebugSessionProvider_Impl>(this: *mut core::ffi::c_void, pda: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugSessionProvider_Impl::StartDebugSession(this, core::mem::transmute_copy(&pda)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, StartDebugSession: StartDebugSession::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDebugSessionProvider {}
 windows_core::imp::define_interface!(IDebugStackFrame, IDebugStackFrame_Vtbl, 0x51973c17_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IDebugStackFrame, windows_core::IUnknown);
@@ -6854,28 +10204,106 @@ impl IDebugStackFrame {
         }
     }
 }
-#[repr(C)]
-#[doc(hidden)]
-pub struct IDebugStackFrame_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    pub GetCodeContext: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetDescriptionString: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::BOOL, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetLanguageString: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::BOOL, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetThread: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetDebugProperty: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-pub trait IDebugStackFrame_Impl: windows_core::IUnknownImpl {
-    fn GetCodeContext(&self) -> windows_core::Result<IDebugCodeContext>;
-    fn GetDescriptionString(&self, flong: windows_core::BOOL) -> windows_core::Result<windows_core::BSTR>;
-    fn GetLanguageString(&self, flong: windows_core::BOOL) -> windows_core::Result<windows_core::BSTR>;
-    fn GetThread(&self) -> windows_core::Result<IDebugApplicationThread>;
-    fn GetDebugProperty(&self) -> windows_core::Result<super::IDebugProperty>;
-}
-impl IDebugStackFrame_Vtbl {
-    pub const fn new<Identity: IDebugStackFrame_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn GetCodeContext<Identity: IDebugStackFrame_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppcc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+#[repr(C)]
+#[doc(hidden)]
+pub struct IDebugStackFrame_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub GetCodeContext: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetDescriptionString: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::BOOL, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetLanguageString: unsafe extern "system" fn(*mut core::ffi::c_void, windows_core::BOOL, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetThread: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetDebugProperty: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+pub trait IDebugStackFrame_Impl: windows_core::IUnknownImpl {
+    fn GetCodeContext(&self) -> windows_core::Result<IDebugCodeContext>;
+    fn GetDescriptionString(&self, flong: windows_core::BOOL) -> windows_core::Result<windows_core::BSTR>;
+    fn GetLanguageString(&self, flong: windows_core::BOOL) -> windows_core::Result<windows_core::BSTR>;
+    fn GetThread(&self) -> windows_core::Result<IDebugApplicationThread>;
+    fn GetDebugProperty(&self) -> windows_core::Result<super::IDebugProperty>;
+}
+impl IDebugStackFrame_Vtbl {
+    pub const fn new<Identity: IDebugStackFrame_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn GetCodeContext<Identity: IDebugStackFrame_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppcc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDebugStackFrame_Impl::GetCodeContext(this) {
+                    Ok(ok__) => {
+                        ppcc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDescriptionString<Identity: IDebugStackFrame_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flong: windows_core::BOOL, pbstrdescription: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDebugStackFrame_Impl::GetDescriptionString(this, core::mem::transmute_copy(&flong)) {
+                    Ok(ok__) => {
+                        pbstrdescription.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLanguageString<Identity: IDebugStackFrame_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flong: windows_core::BOOL, pbstrlanguage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDebugStackFrame_Impl::GetLanguageString(this, core::mem::transmute_copy(&flong)) {
+                    Ok(ok__) => {
+                        pbstrlanguage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetThread<Identity: IDebugStackFrame_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDebugStackFrame_Impl::GetThread(this) {
+                    Ok(ok__) => {
+                        ppat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDebugProperty<Identity: IDebugStackFrame_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppdebugprop: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IDebugStackFrame_Impl::GetDebugProperty(this) {
+                    Ok(ok__) => {
+                        ppdebugprop.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            GetCodeContext: GetCodeContext::<Identity, OFFSET>,
+            GetDescriptionString: GetDescriptionString::<Identity, OFFSET>,
+            GetLanguageString: GetLanguageString::<Identity, OFFSET>,
+            GetThread: GetThread::<Identity, OFFSET>,
+            GetDebugProperty: GetDebugProperty::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugStackFrame as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IDebugStackFrame_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugStackFrame_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCodeContext<Identity: windows_core::IUnknownImpl + IDebugStackFrame_Impl>(this: *mut core::ffi::c_void, ppcc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDebugStackFrame_Impl::GetCodeContext(this) {
                     Ok(ok__) => {
                         ppcc.write(core::mem::transmute(ok__));
@@ -6885,9 +10313,9 @@ impl IDebugStackFrame_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetDescriptionString<Identity: IDebugStackFrame_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flong: windows_core::BOOL, pbstrdescription: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetDescriptionString<Identity: windows_core::IUnknownImpl + IDebugStackFrame_Impl>(this: *mut core::ffi::c_void, flong: windows_core::BOOL, pbstrdescription: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDebugStackFrame_Impl::GetDescriptionString(this, core::mem::transmute_copy(&flong)) {
                     Ok(ok__) => {
                         pbstrdescription.write(core::mem::transmute(ok__));
@@ -6897,9 +10325,9 @@ impl IDebugStackFrame_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetLanguageString<Identity: IDebugStackFrame_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flong: windows_core::BOOL, pbstrlanguage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetLanguageString<Identity: windows_core::IUnknownImpl + IDebugStackFrame_Impl>(this: *mut core::ffi::c_void, flong: windows_core::BOOL, pbstrlanguage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDebugStackFrame_Impl::GetLanguageString(this, core::mem::transmute_copy(&flong)) {
                     Ok(ok__) => {
                         pbstrlanguage.write(core::mem::transmute(ok__));
@@ -6909,9 +10337,9 @@ impl IDebugStackFrame_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetThread<Identity: IDebugStackFrame_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetThread<Identity: windows_core::IUnknownImpl + IDebugStackFrame_Impl>(this: *mut core::ffi::c_void, ppat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDebugStackFrame_Impl::GetThread(this) {
                     Ok(ok__) => {
                         ppat.write(core::mem::transmute(ok__));
@@ -6921,9 +10349,9 @@ impl IDebugStackFrame_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetDebugProperty<Identity: IDebugStackFrame_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppdebugprop: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetDebugProperty<Identity: windows_core::IUnknownImpl + IDebugStackFrame_Impl>(this: *mut core::ffi::c_void, ppdebugprop: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IDebugStackFrame_Impl::GetDebugProperty(this) {
                     Ok(ok__) => {
                         ppdebugprop.write(core::mem::transmute(ok__));
@@ -6934,17 +10362,14 @@ impl IDebugStackFrame_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            GetCodeContext: GetCodeContext::<Identity, OFFSET>,
-            GetDescriptionString: GetDescriptionString::<Identity, OFFSET>,
-            GetLanguageString: GetLanguageString::<Identity, OFFSET>,
-            GetThread: GetThread::<Identity, OFFSET>,
-            GetDebugProperty: GetDebugProperty::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCodeContext: GetCodeContext::<Identity>,
+            GetDescriptionString: GetDescriptionString::<Identity>,
+            GetLanguageString: GetLanguageString::<Identity>,
+            GetThread: GetThread::<Identity>,
+            GetDebugProperty: GetDebugProperty::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDebugStackFrame as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IDebugStackFrame {}
 windows_core::imp::define_interface!(IDebugStackFrame110, IDebugStackFrame110_Vtbl, 0x4b509611_b6ea_4b24_adcb_d0ccfd1a7e33);
@@ -7016,6 +10441,42 @@ impl IDebugStackFrame110_Vtbl {
         iid == &<IDebugStackFrame110 as windows_core::Interface>::IID || iid == &<IDebugStackFrame as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugStackFrame110_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugStackFrame110_Vtbl
+where
+    IDebugStackFrame_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStackFrameType<Identity: windows_core::IUnknownImpl + IDebugStackFrame110_Impl>(this: *mut core::ffi::c_void, pstackframekind: *mut DEBUG_STACKFRAME_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugStackFrame110_Impl::GetStackFrameType(this) {
+                    Ok(ok__) => {
+                        pstackframekind.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetScriptInvocationContext<Identity: windows_core::IUnknownImpl + IDebugStackFrame110_Impl>(this: *mut core::ffi::c_void, ppinvocationcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugStackFrame110_Impl::GetScriptInvocationContext(this) {
+                    Ok(ok__) => {
+                        ppinvocationcontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDebugStackFrame_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStackFrameType: GetStackFrameType::<Identity>,
+            GetScriptInvocationContext: GetScriptInvocationContext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugStackFrame110 {}
 windows_core::imp::define_interface!(IDebugStackFrameSniffer, IDebugStackFrameSniffer_Vtbl, 0x51973c18_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IDebugStackFrameSniffer, windows_core::IUnknown);
@@ -7056,6 +10517,26 @@ impl IDebugStackFrameSniffer_Vtbl {
         iid == &<IDebugStackFrameSniffer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugStackFrameSniffer_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugStackFrameSniffer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumStackFrames<Identity: windows_core::IUnknownImpl + IDebugStackFrameSniffer_Impl>(this: *mut core::ffi::c_void, ppedsf: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugStackFrameSniffer_Impl::EnumStackFrames(this) {
+                    Ok(ok__) => {
+                        ppedsf.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, EnumStackFrames: EnumStackFrames::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDebugStackFrameSniffer {}
 windows_core::imp::define_interface!(IDebugStackFrameSnifferEx32, IDebugStackFrameSnifferEx32_Vtbl, 0x51973c19_cb0c_11d0_b5c9_00a0244a0e7a);
 impl core::ops::Deref for IDebugStackFrameSnifferEx32 {
@@ -7102,6 +10583,29 @@ impl IDebugStackFrameSnifferEx32_Vtbl {
         iid == &<IDebugStackFrameSnifferEx32 as windows_core::Interface>::IID || iid == &<IDebugStackFrameSniffer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugStackFrameSnifferEx32_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugStackFrameSnifferEx32_Vtbl
+where
+    IDebugStackFrameSniffer_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumStackFramesEx32<Identity: windows_core::IUnknownImpl + IDebugStackFrameSnifferEx32_Impl>(this: *mut core::ffi::c_void, dwspmin: u32, ppedsf: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugStackFrameSnifferEx32_Impl::EnumStackFramesEx32(this, core::mem::transmute_copy(&dwspmin)) {
+                    Ok(ok__) => {
+                        ppedsf.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDebugStackFrameSniffer_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnumStackFramesEx32: EnumStackFramesEx32::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugStackFrameSnifferEx32 {}
 windows_core::imp::define_interface!(IDebugStackFrameSnifferEx64, IDebugStackFrameSnifferEx64_Vtbl, 0x8cd12af4_49c1_4d52_8d8a_c146f47581aa);
 impl core::ops::Deref for IDebugStackFrameSnifferEx64 {
@@ -7148,6 +10652,29 @@ impl IDebugStackFrameSnifferEx64_Vtbl {
         iid == &<IDebugStackFrameSnifferEx64 as windows_core::Interface>::IID || iid == &<IDebugStackFrameSniffer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugStackFrameSnifferEx64_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugStackFrameSnifferEx64_Vtbl
+where
+    IDebugStackFrameSniffer_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumStackFramesEx64<Identity: windows_core::IUnknownImpl + IDebugStackFrameSnifferEx64_Impl>(this: *mut core::ffi::c_void, dwspmin: u64, ppedsf: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugStackFrameSnifferEx64_Impl::EnumStackFramesEx64(this, core::mem::transmute_copy(&dwspmin)) {
+                    Ok(ok__) => {
+                        ppedsf.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IDebugStackFrameSniffer_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnumStackFramesEx64: EnumStackFramesEx64::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugStackFrameSnifferEx64 {}
 windows_core::imp::define_interface!(IDebugSyncOperation, IDebugSyncOperation_Vtbl, 0x51973c1a_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IDebugSyncOperation, windows_core::IUnknown);
@@ -7224,6 +10751,49 @@ impl IDebugSyncOperation_Vtbl {
         iid == &<IDebugSyncOperation as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugSyncOperation_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugSyncOperation_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetTargetThread<Identity: windows_core::IUnknownImpl + IDebugSyncOperation_Impl>(this: *mut core::ffi::c_void, ppattarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugSyncOperation_Impl::GetTargetThread(this) {
+                    Ok(ok__) => {
+                        ppattarget.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Execute<Identity: windows_core::IUnknownImpl + IDebugSyncOperation_Impl>(this: *mut core::ffi::c_void, ppunkresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDebugSyncOperation_Impl::Execute(this) {
+                    Ok(ok__) => {
+                        ppunkresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InProgressAbort<Identity: windows_core::IUnknownImpl + IDebugSyncOperation_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugSyncOperation_Impl::InProgressAbort(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetTargetThread: GetTargetThread::<Identity>,
+            Execute: Execute::<Identity>,
+            InProgressAbort: InProgressAbort::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDebugSyncOperation {}
 windows_core::imp::define_interface!(IDebugThreadCall32, IDebugThreadCall32_Vtbl, 0x51973c36_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IDebugThreadCall32, windows_core::IUnknown);
@@ -7255,6 +10825,20 @@ impl IDebugThreadCall32_Vtbl {
         iid == &<IDebugThreadCall32 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugThreadCall32_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugThreadCall32_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ThreadCallHandler<Identity: windows_core::IUnknownImpl + IDebugThreadCall32_Impl>(this: *mut core::ffi::c_void, dwparam1: u32, dwparam2: u32, dwparam3: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugThreadCall32_Impl::ThreadCallHandler(this, core::mem::transmute_copy(&dwparam1), core::mem::transmute_copy(&dwparam2), core::mem::transmute_copy(&dwparam3)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ThreadCallHandler: ThreadCallHandler::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDebugThreadCall32 {}
 windows_core::imp::define_interface!(IDebugThreadCall64, IDebugThreadCall64_Vtbl, 0xcb3fa335_e979_42fd_9fcf_a7546a0f3905);
 windows_core::imp::interface_hierarchy!(IDebugThreadCall64, windows_core::IUnknown);
@@ -7286,6 +10870,20 @@ impl IDebugThreadCall64_Vtbl {
         iid == &<IDebugThreadCall64 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDebugThreadCall64_Impl> windows_core::imp::TearOffVtable<Identity> for IDebugThreadCall64_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ThreadCallHandler<Identity: windows_core::IUnknownImpl + IDebugThreadCall64_Impl>(this: *mut core::ffi::c_void, dwparam1: u64, dwparam2: u64, dwparam3: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDebugThreadCall64_Impl::ThreadCallHandler(this, core::mem::transmute_copy(&dwparam1), core::mem::transmute_copy(&dwparam2), core::mem::transmute_copy(&dwparam3)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ThreadCallHandler: ThreadCallHandler::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDebugThreadCall64 {}
 windows_core::imp::define_interface!(IEnumDebugApplicationNodes, IEnumDebugApplicationNodes_Vtbl, 0x51973c3a_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IEnumDebugApplicationNodes, windows_core::IUnknown);
@@ -7365,6 +10963,50 @@ impl IEnumDebugApplicationNodes_Vtbl {
         iid == &<IEnumDebugApplicationNodes as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumDebugApplicationNodes_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumDebugApplicationNodes_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumDebugApplicationNodes_Impl>(this: *mut core::ffi::c_void, celt: u32, pprddp: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumDebugApplicationNodes_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&pprddp), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumDebugApplicationNodes_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumDebugApplicationNodes_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumDebugApplicationNodes_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumDebugApplicationNodes_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumDebugApplicationNodes_Impl>(this: *mut core::ffi::c_void, pperddp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumDebugApplicationNodes_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        pperddp.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumDebugApplicationNodes {}
 windows_core::imp::define_interface!(IEnumDebugCodeContexts, IEnumDebugCodeContexts_Vtbl, 0x51973c1d_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IEnumDebugCodeContexts, windows_core::IUnknown);
@@ -7444,6 +11086,50 @@ impl IEnumDebugCodeContexts_Vtbl {
         iid == &<IEnumDebugCodeContexts as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumDebugCodeContexts_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumDebugCodeContexts_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumDebugCodeContexts_Impl>(this: *mut core::ffi::c_void, celt: u32, pscc: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumDebugCodeContexts_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&pscc), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumDebugCodeContexts_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumDebugCodeContexts_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumDebugCodeContexts_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumDebugCodeContexts_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumDebugCodeContexts_Impl>(this: *mut core::ffi::c_void, ppescc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumDebugCodeContexts_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppescc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumDebugCodeContexts {}
 windows_core::imp::define_interface!(IEnumDebugExpressionContexts, IEnumDebugExpressionContexts_Vtbl, 0x51973c40_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IEnumDebugExpressionContexts, windows_core::IUnknown);
@@ -7523,6 +11209,50 @@ impl IEnumDebugExpressionContexts_Vtbl {
         iid == &<IEnumDebugExpressionContexts as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumDebugExpressionContexts_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumDebugExpressionContexts_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumDebugExpressionContexts_Impl>(this: *mut core::ffi::c_void, celt: u32, ppdec: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumDebugExpressionContexts_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&ppdec), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumDebugExpressionContexts_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumDebugExpressionContexts_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumDebugExpressionContexts_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumDebugExpressionContexts_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumDebugExpressionContexts_Impl>(this: *mut core::ffi::c_void, ppedec: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumDebugExpressionContexts_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppedec.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumDebugExpressionContexts {}
 windows_core::imp::define_interface!(IEnumDebugStackFrames, IEnumDebugStackFrames_Vtbl, 0x51973c1e_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IEnumDebugStackFrames, windows_core::IUnknown);
@@ -7558,29 +11288,76 @@ pub trait IEnumDebugStackFrames_Impl: windows_core::IUnknownImpl {
     fn Reset(&self) -> windows_core::Result<()>;
     fn Clone(&self) -> windows_core::Result<IEnumDebugStackFrames>;
 }
-impl IEnumDebugStackFrames_Vtbl {
-    pub const fn new<Identity: IEnumDebugStackFrames_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn Next<Identity: IEnumDebugStackFrames_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, celt: u32, prgdsfd: *mut DebugStackFrameDescriptor, pceltfetched: *mut u32) -> windows_core::HRESULT {
+impl IEnumDebugStackFrames_Vtbl {
+    pub const fn new<Identity: IEnumDebugStackFrames_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn Next<Identity: IEnumDebugStackFrames_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, celt: u32, prgdsfd: *mut DebugStackFrameDescriptor, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IEnumDebugStackFrames_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&prgdsfd), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: IEnumDebugStackFrames_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IEnumDebugStackFrames_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: IEnumDebugStackFrames_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IEnumDebugStackFrames_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: IEnumDebugStackFrames_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppedsf: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IEnumDebugStackFrames_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppedsf.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            Next: Next::<Identity, OFFSET>,
+            Skip: Skip::<Identity, OFFSET>,
+            Reset: Reset::<Identity, OFFSET>,
+            Clone: Clone::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumDebugStackFrames as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IEnumDebugStackFrames_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumDebugStackFrames_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumDebugStackFrames_Impl>(this: *mut core::ffi::c_void, celt: u32, prgdsfd: *mut DebugStackFrameDescriptor, pceltfetched: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IEnumDebugStackFrames_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&prgdsfd), core::mem::transmute_copy(&pceltfetched)).into()
             }
         }
-        unsafe extern "system" fn Skip<Identity: IEnumDebugStackFrames_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumDebugStackFrames_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IEnumDebugStackFrames_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
             }
         }
-        unsafe extern "system" fn Reset<Identity: IEnumDebugStackFrames_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumDebugStackFrames_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IEnumDebugStackFrames_Impl::Reset(this).into()
             }
         }
-        unsafe extern "system" fn Clone<Identity: IEnumDebugStackFrames_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppedsf: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumDebugStackFrames_Impl>(this: *mut core::ffi::c_void, ppedsf: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IEnumDebugStackFrames_Impl::Clone(this) {
                     Ok(ok__) => {
                         ppedsf.write(core::mem::transmute(ok__));
@@ -7591,16 +11368,13 @@ impl IEnumDebugStackFrames_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            Next: Next::<Identity, OFFSET>,
-            Skip: Skip::<Identity, OFFSET>,
-            Reset: Reset::<Identity, OFFSET>,
-            Clone: Clone::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IEnumDebugStackFrames as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IEnumDebugStackFrames {}
 windows_core::imp::define_interface!(IEnumDebugStackFrames64, IEnumDebugStackFrames64_Vtbl, 0x0dc38853_c1b0_4176_a984_b298361027af);
@@ -7639,6 +11413,20 @@ impl IEnumDebugStackFrames64_Vtbl {
         iid == &<IEnumDebugStackFrames64 as windows_core::Interface>::IID || iid == &<IEnumDebugStackFrames as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumDebugStackFrames64_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumDebugStackFrames64_Vtbl
+where
+    IEnumDebugStackFrames_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next64<Identity: windows_core::IUnknownImpl + IEnumDebugStackFrames64_Impl>(this: *mut core::ffi::c_void, celt: u32, prgdsfd: *mut DebugStackFrameDescriptor64, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumDebugStackFrames64_Impl::Next64(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&prgdsfd), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        Self { base__: <IEnumDebugStackFrames_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Next64: Next64::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IEnumDebugStackFrames64 {}
 windows_core::imp::define_interface!(IEnumJsStackFrames, IEnumJsStackFrames_Vtbl, 0x5e7da34b_fb51_4791_abe7_cb5bdf419755);
 windows_core::imp::interface_hierarchy!(IEnumJsStackFrames, windows_core::IUnknown);
@@ -7681,6 +11469,26 @@ impl IEnumJsStackFrames_Vtbl {
         iid == &<IEnumJsStackFrames as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumJsStackFrames_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumJsStackFrames_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumJsStackFrames_Impl>(this: *mut core::ffi::c_void, cframecount: u32, pframes: *mut JS_NATIVE_FRAME, pcfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumJsStackFrames_Impl::Next(this, core::mem::transmute_copy(&cframecount), core::mem::transmute_copy(&pframes), core::mem::transmute_copy(&pcfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumJsStackFrames_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumJsStackFrames_Impl::Reset(this).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Next: Next::<Identity>, Reset: Reset::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IEnumJsStackFrames {}
 windows_core::imp::define_interface!(IEnumRemoteDebugApplicationThreads, IEnumRemoteDebugApplicationThreads_Vtbl, 0x51973c3c_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IEnumRemoteDebugApplicationThreads, windows_core::IUnknown);
@@ -7760,6 +11568,50 @@ impl IEnumRemoteDebugApplicationThreads_Vtbl {
         iid == &<IEnumRemoteDebugApplicationThreads as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumRemoteDebugApplicationThreads_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumRemoteDebugApplicationThreads_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumRemoteDebugApplicationThreads_Impl>(this: *mut core::ffi::c_void, celt: u32, pprdat: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumRemoteDebugApplicationThreads_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&pprdat), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumRemoteDebugApplicationThreads_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumRemoteDebugApplicationThreads_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumRemoteDebugApplicationThreads_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumRemoteDebugApplicationThreads_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumRemoteDebugApplicationThreads_Impl>(this: *mut core::ffi::c_void, pperdat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumRemoteDebugApplicationThreads_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        pperdat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumRemoteDebugApplicationThreads {}
 windows_core::imp::define_interface!(IEnumRemoteDebugApplications, IEnumRemoteDebugApplications_Vtbl, 0x51973c3b_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IEnumRemoteDebugApplications, windows_core::IUnknown);
@@ -7839,6 +11691,50 @@ impl IEnumRemoteDebugApplications_Vtbl {
         iid == &<IEnumRemoteDebugApplications as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumRemoteDebugApplications_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumRemoteDebugApplications_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumRemoteDebugApplications_Impl>(this: *mut core::ffi::c_void, celt: u32, ppda: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumRemoteDebugApplications_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&ppda), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumRemoteDebugApplications_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumRemoteDebugApplications_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumRemoteDebugApplications_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumRemoteDebugApplications_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumRemoteDebugApplications_Impl>(this: *mut core::ffi::c_void, ppessd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumRemoteDebugApplications_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppessd.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumRemoteDebugApplications {}
 windows_core::imp::define_interface!(IJsDebug, IJsDebug_Vtbl, 0xbe0e89da_2ac5_4c04_ac5e_59956aae3613);
 windows_core::imp::interface_hierarchy!(IJsDebug, windows_core::IUnknown);
@@ -7882,6 +11778,26 @@ impl IJsDebug_Vtbl {
         iid == &<IJsDebug as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IJsDebug_Impl> windows_core::imp::TearOffVtable<Identity> for IJsDebug_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OpenVirtualProcess<Identity: windows_core::IUnknownImpl + IJsDebug_Impl>(this: *mut core::ffi::c_void, processid: u32, runtimejsbaseaddress: u64, pdatatarget: *mut core::ffi::c_void, ppprocess: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IJsDebug_Impl::OpenVirtualProcess(this, core::mem::transmute_copy(&processid), core::mem::transmute_copy(&runtimejsbaseaddress), core::mem::transmute_copy(&pdatatarget)) {
+                    Ok(ok__) => {
+                        ppprocess.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OpenVirtualProcess: OpenVirtualProcess::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IJsDebug {}
 windows_core::imp::define_interface!(IJsDebugBreakPoint, IJsDebugBreakPoint_Vtbl, 0xdf6773e3_ed8d_488b_8a3e_5812577d1542);
 windows_core::imp::interface_hierarchy!(IJsDebugBreakPoint, windows_core::IUnknown);
@@ -7973,6 +11889,57 @@ impl IJsDebugBreakPoint_Vtbl {
         iid == &<IJsDebugBreakPoint as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IJsDebugBreakPoint_Impl> windows_core::imp::TearOffVtable<Identity> for IJsDebugBreakPoint_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsEnabled<Identity: windows_core::IUnknownImpl + IJsDebugBreakPoint_Impl>(this: *mut core::ffi::c_void, pisenabled: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IJsDebugBreakPoint_Impl::IsEnabled(this) {
+                    Ok(ok__) => {
+                        pisenabled.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Enable<Identity: windows_core::IUnknownImpl + IJsDebugBreakPoint_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IJsDebugBreakPoint_Impl::Enable(this).into()
+            }
+        }
+        unsafe extern "system" fn Disable<Identity: windows_core::IUnknownImpl + IJsDebugBreakPoint_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IJsDebugBreakPoint_Impl::Disable(this).into()
+            }
+        }
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IJsDebugBreakPoint_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IJsDebugBreakPoint_Impl::Delete(this).into()
+            }
+        }
+        unsafe extern "system" fn GetDocumentPosition<Identity: windows_core::IUnknownImpl + IJsDebugBreakPoint_Impl>(this: *mut core::ffi::c_void, pdocumentid: *mut u64, pcharacteroffset: *mut u32, pstatementcharcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IJsDebugBreakPoint_Impl::GetDocumentPosition(this, core::mem::transmute_copy(&pdocumentid), core::mem::transmute_copy(&pcharacteroffset), core::mem::transmute_copy(&pstatementcharcount)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsEnabled: IsEnabled::<Identity>,
+            Enable: Enable::<Identity>,
+            Disable: Disable::<Identity>,
+            Delete: Delete::<Identity>,
+            GetDocumentPosition: GetDocumentPosition::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IJsDebugBreakPoint {}
 windows_core::imp::define_interface!(IJsDebugDataTarget, IJsDebugDataTarget_Vtbl, 0x53b28977_53a1_48e5_9000_5d0dfa893931);
 windows_core::imp::interface_hierarchy!(IJsDebugDataTarget, windows_core::IUnknown);
@@ -8020,48 +11987,154 @@ impl IJsDebugDataTarget {
         unsafe { (windows_core::Interface::vtable(self).GetThreadContext)(windows_core::Interface::as_raw(self), threadid, contextflags, pcontext.len().try_into().unwrap(), core::mem::transmute(pcontext.as_ptr())).ok() }
     }
 }
-#[repr(C)]
-#[doc(hidden)]
-pub struct IJsDebugDataTarget_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    pub ReadMemory: unsafe extern "system" fn(*mut core::ffi::c_void, u64, JsDebugReadMemoryFlags, *mut u8, u32, *mut u32) -> windows_core::HRESULT,
-    pub WriteMemory: unsafe extern "system" fn(*mut core::ffi::c_void, u64, *const u8, u32) -> windows_core::HRESULT,
-    pub AllocateVirtualMemory: unsafe extern "system" fn(*mut core::ffi::c_void, u64, u32, u32, u32, *mut u64) -> windows_core::HRESULT,
-    pub FreeVirtualMemory: unsafe extern "system" fn(*mut core::ffi::c_void, u64, u32, u32) -> windows_core::HRESULT,
-    pub GetTlsValue: unsafe extern "system" fn(*mut core::ffi::c_void, u32, u32, *mut u64) -> windows_core::HRESULT,
-    pub ReadBSTR: unsafe extern "system" fn(*mut core::ffi::c_void, u64, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub ReadNullTerminatedString: unsafe extern "system" fn(*mut core::ffi::c_void, u64, u16, u32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub CreateStackFrameEnumerator: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub GetThreadContext: unsafe extern "system" fn(*mut core::ffi::c_void, u32, u32, u32, *mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-pub trait IJsDebugDataTarget_Impl: windows_core::IUnknownImpl {
-    fn ReadMemory(&self, address: u64, flags: JsDebugReadMemoryFlags, pbuffer: *mut u8, size: u32, pbytesread: *mut u32) -> windows_core::Result<()>;
-    fn WriteMemory(&self, address: u64, pmemory: *const u8, size: u32) -> windows_core::Result<()>;
-    fn AllocateVirtualMemory(&self, address: u64, size: u32, allocationtype: u32, pageprotection: u32) -> windows_core::Result<u64>;
-    fn FreeVirtualMemory(&self, address: u64, size: u32, freetype: u32) -> windows_core::Result<()>;
-    fn GetTlsValue(&self, threadid: u32, tlsindex: u32) -> windows_core::Result<u64>;
-    fn ReadBSTR(&self, address: u64) -> windows_core::Result<windows_core::BSTR>;
-    fn ReadNullTerminatedString(&self, address: u64, charactersize: u16, maxcharacters: u32) -> windows_core::Result<windows_core::BSTR>;
-    fn CreateStackFrameEnumerator(&self, threadid: u32) -> windows_core::Result<IEnumJsStackFrames>;
-    fn GetThreadContext(&self, threadid: u32, contextflags: u32, contextsize: u32, pcontext: *mut core::ffi::c_void) -> windows_core::Result<()>;
-}
-impl IJsDebugDataTarget_Vtbl {
-    pub const fn new<Identity: IJsDebugDataTarget_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn ReadMemory<Identity: IJsDebugDataTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, address: u64, flags: JsDebugReadMemoryFlags, pbuffer: *mut u8, size: u32, pbytesread: *mut u32) -> windows_core::HRESULT {
+#[repr(C)]
+#[doc(hidden)]
+pub struct IJsDebugDataTarget_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub ReadMemory: unsafe extern "system" fn(*mut core::ffi::c_void, u64, JsDebugReadMemoryFlags, *mut u8, u32, *mut u32) -> windows_core::HRESULT,
+    pub WriteMemory: unsafe extern "system" fn(*mut core::ffi::c_void, u64, *const u8, u32) -> windows_core::HRESULT,
+    pub AllocateVirtualMemory: unsafe extern "system" fn(*mut core::ffi::c_void, u64, u32, u32, u32, *mut u64) -> windows_core::HRESULT,
+    pub FreeVirtualMemory: unsafe extern "system" fn(*mut core::ffi::c_void, u64, u32, u32) -> windows_core::HRESULT,
+    pub GetTlsValue: unsafe extern "system" fn(*mut core::ffi::c_void, u32, u32, *mut u64) -> windows_core::HRESULT,
+    pub ReadBSTR: unsafe extern "system" fn(*mut core::ffi::c_void, u64, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub ReadNullTerminatedString: unsafe extern "system" fn(*mut core::ffi::c_void, u64, u16, u32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub CreateStackFrameEnumerator: unsafe extern "system" fn(*mut core::ffi::c_void, u32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub GetThreadContext: unsafe extern "system" fn(*mut core::ffi::c_void, u32, u32, u32, *mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+pub trait IJsDebugDataTarget_Impl: windows_core::IUnknownImpl {
+    fn ReadMemory(&self, address: u64, flags: JsDebugReadMemoryFlags, pbuffer: *mut u8, size: u32, pbytesread: *mut u32) -> windows_core::Result<()>;
+    fn WriteMemory(&self, address: u64, pmemory: *const u8, size: u32) -> windows_core::Result<()>;
+    fn AllocateVirtualMemory(&self, address: u64, size: u32, allocationtype: u32, pageprotection: u32) -> windows_core::Result<u64>;
+    fn FreeVirtualMemory(&self, address: u64, size: u32, freetype: u32) -> windows_core::Result<()>;
+    fn GetTlsValue(&self, threadid: u32, tlsindex: u32) -> windows_core::Result<u64>;
+    fn ReadBSTR(&self, address: u64) -> windows_core::Result<windows_core::BSTR>;
+    fn ReadNullTerminatedString(&self, address: u64, charactersize: u16, maxcharacters: u32) -> windows_core::Result<windows_core::BSTR>;
+    fn CreateStackFrameEnumerator(&self, threadid: u32) -> windows_core::Result<IEnumJsStackFrames>;
+    fn GetThreadContext(&self, threadid: u32, contextflags: u32, contextsize: u32, pcontext: *mut core::ffi::c_void) -> windows_core::Result<()>;
+}
+impl IJsDebugDataTarget_Vtbl {
+    pub const fn new<Identity: IJsDebugDataTarget_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn ReadMemory<Identity: IJsDebugDataTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, address: u64, flags: JsDebugReadMemoryFlags, pbuffer: *mut u8, size: u32, pbytesread: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IJsDebugDataTarget_Impl::ReadMemory(this, core::mem::transmute_copy(&address), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&size), core::mem::transmute_copy(&pbytesread)).into()
+            }
+        }
+        unsafe extern "system" fn WriteMemory<Identity: IJsDebugDataTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, address: u64, pmemory: *const u8, size: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IJsDebugDataTarget_Impl::WriteMemory(this, core::mem::transmute_copy(&address), core::mem::transmute_copy(&pmemory), core::mem::transmute_copy(&size)).into()
+            }
+        }
+        unsafe extern "system" fn AllocateVirtualMemory<Identity: IJsDebugDataTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, address: u64, size: u32, allocationtype: u32, pageprotection: u32, pallocatedaddress: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IJsDebugDataTarget_Impl::AllocateVirtualMemory(this, core::mem::transmute_copy(&address), core::mem::transmute_copy(&size), core::mem::transmute_copy(&allocationtype), core::mem::transmute_copy(&pageprotection)) {
+                    Ok(ok__) => {
+                        pallocatedaddress.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FreeVirtualMemory<Identity: IJsDebugDataTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, address: u64, size: u32, freetype: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IJsDebugDataTarget_Impl::FreeVirtualMemory(this, core::mem::transmute_copy(&address), core::mem::transmute_copy(&size), core::mem::transmute_copy(&freetype)).into()
+            }
+        }
+        unsafe extern "system" fn GetTlsValue<Identity: IJsDebugDataTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, threadid: u32, tlsindex: u32, pvalue: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IJsDebugDataTarget_Impl::GetTlsValue(this, core::mem::transmute_copy(&threadid), core::mem::transmute_copy(&tlsindex)) {
+                    Ok(ok__) => {
+                        pvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReadBSTR<Identity: IJsDebugDataTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, address: u64, pstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IJsDebugDataTarget_Impl::ReadBSTR(this, core::mem::transmute_copy(&address)) {
+                    Ok(ok__) => {
+                        pstring.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReadNullTerminatedString<Identity: IJsDebugDataTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, address: u64, charactersize: u16, maxcharacters: u32, pstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IJsDebugDataTarget_Impl::ReadNullTerminatedString(this, core::mem::transmute_copy(&address), core::mem::transmute_copy(&charactersize), core::mem::transmute_copy(&maxcharacters)) {
+                    Ok(ok__) => {
+                        pstring.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateStackFrameEnumerator<Identity: IJsDebugDataTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, threadid: u32, ppenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IJsDebugDataTarget_Impl::CreateStackFrameEnumerator(this, core::mem::transmute_copy(&threadid)) {
+                    Ok(ok__) => {
+                        ppenumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetThreadContext<Identity: IJsDebugDataTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, threadid: u32, contextflags: u32, contextsize: u32, pcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IJsDebugDataTarget_Impl::GetThreadContext(this, core::mem::transmute_copy(&threadid), core::mem::transmute_copy(&contextflags), core::mem::transmute_copy(&contextsize), core::mem::transmute_copy(&pcontext)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            ReadMemory: ReadMemory::<Identity, OFFSET>,
+            WriteMemory: WriteMemory::<Identity, OFFSET>,
+            AllocateVirtualMemory: AllocateVirtualMemory::<Identity, OFFSET>,
+            FreeVirtualMemory: FreeVirtualMemory::<Identity, OFFSET>,
+            GetTlsValue: GetTlsValue::<Identity, OFFSET>,
+            ReadBSTR: ReadBSTR::<Identity, OFFSET>,
+            ReadNullTerminatedString: ReadNullTerminatedString::<Identity, OFFSET>,
+            CreateStackFrameEnumerator: CreateStackFrameEnumerator::<Identity, OFFSET>,
+            GetThreadContext: GetThreadContext::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJsDebugDataTarget as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IJsDebugDataTarget_Impl> windows_core::imp::TearOffVtable<Identity> for IJsDebugDataTarget_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ReadMemory<Identity: windows_core::IUnknownImpl + IJsDebugDataTarget_Impl>(this: *mut core::ffi::c_void, address: u64, flags: JsDebugReadMemoryFlags, pbuffer: *mut u8, size: u32, pbytesread: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IJsDebugDataTarget_Impl::ReadMemory(this, core::mem::transmute_copy(&address), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&size), core::mem::transmute_copy(&pbytesread)).into()
             }
         }
-        unsafe extern "system" fn WriteMemory<Identity: IJsDebugDataTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, address: u64, pmemory: *const u8, size: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn WriteMemory<Identity: windows_core::IUnknownImpl + IJsDebugDataTarget_Impl>(this: *mut core::ffi::c_void, address: u64, pmemory: *const u8, size: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IJsDebugDataTarget_Impl::WriteMemory(this, core::mem::transmute_copy(&address), core::mem::transmute_copy(&pmemory), core::mem::transmute_copy(&size)).into()
             }
         }
-        unsafe extern "system" fn AllocateVirtualMemory<Identity: IJsDebugDataTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, address: u64, size: u32, allocationtype: u32, pageprotection: u32, pallocatedaddress: *mut u64) -> windows_core::HRESULT {
+        unsafe extern "system" fn AllocateVirtualMemory<Identity: windows_core::IUnknownImpl + IJsDebugDataTarget_Impl>(this: *mut core::ffi::c_void, address: u64, size: u32, allocationtype: u32, pageprotection: u32, pallocatedaddress: *mut u64) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IJsDebugDataTarget_Impl::AllocateVirtualMemory(this, core::mem::transmute_copy(&address), core::mem::transmute_copy(&size), core::mem::transmute_copy(&allocationtype), core::mem::transmute_copy(&pageprotection)) {
                     Ok(ok__) => {
                         pallocatedaddress.write(core::mem::transmute(ok__));
@@ -8071,15 +12144,15 @@ impl IJsDebugDataTarget_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn FreeVirtualMemory<Identity: IJsDebugDataTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, address: u64, size: u32, freetype: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn FreeVirtualMemory<Identity: windows_core::IUnknownImpl + IJsDebugDataTarget_Impl>(this: *mut core::ffi::c_void, address: u64, size: u32, freetype: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IJsDebugDataTarget_Impl::FreeVirtualMemory(this, core::mem::transmute_copy(&address), core::mem::transmute_copy(&size), core::mem::transmute_copy(&freetype)).into()
             }
         }
-        unsafe extern "system" fn GetTlsValue<Identity: IJsDebugDataTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, threadid: u32, tlsindex: u32, pvalue: *mut u64) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetTlsValue<Identity: windows_core::IUnknownImpl + IJsDebugDataTarget_Impl>(this: *mut core::ffi::c_void, threadid: u32, tlsindex: u32, pvalue: *mut u64) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IJsDebugDataTarget_Impl::GetTlsValue(this, core::mem::transmute_copy(&threadid), core::mem::transmute_copy(&tlsindex)) {
                     Ok(ok__) => {
                         pvalue.write(core::mem::transmute(ok__));
@@ -8089,9 +12162,9 @@ impl IJsDebugDataTarget_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ReadBSTR<Identity: IJsDebugDataTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, address: u64, pstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn ReadBSTR<Identity: windows_core::IUnknownImpl + IJsDebugDataTarget_Impl>(this: *mut core::ffi::c_void, address: u64, pstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IJsDebugDataTarget_Impl::ReadBSTR(this, core::mem::transmute_copy(&address)) {
                     Ok(ok__) => {
                         pstring.write(core::mem::transmute(ok__));
@@ -8101,9 +12174,9 @@ impl IJsDebugDataTarget_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ReadNullTerminatedString<Identity: IJsDebugDataTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, address: u64, charactersize: u16, maxcharacters: u32, pstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn ReadNullTerminatedString<Identity: windows_core::IUnknownImpl + IJsDebugDataTarget_Impl>(this: *mut core::ffi::c_void, address: u64, charactersize: u16, maxcharacters: u32, pstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IJsDebugDataTarget_Impl::ReadNullTerminatedString(this, core::mem::transmute_copy(&address), core::mem::transmute_copy(&charactersize), core::mem::transmute_copy(&maxcharacters)) {
                     Ok(ok__) => {
                         pstring.write(core::mem::transmute(ok__));
@@ -8113,9 +12186,9 @@ impl IJsDebugDataTarget_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CreateStackFrameEnumerator<Identity: IJsDebugDataTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, threadid: u32, ppenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateStackFrameEnumerator<Identity: windows_core::IUnknownImpl + IJsDebugDataTarget_Impl>(this: *mut core::ffi::c_void, threadid: u32, ppenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IJsDebugDataTarget_Impl::CreateStackFrameEnumerator(this, core::mem::transmute_copy(&threadid)) {
                     Ok(ok__) => {
                         ppenumerator.write(core::mem::transmute(ok__));
@@ -8125,28 +12198,25 @@ impl IJsDebugDataTarget_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetThreadContext<Identity: IJsDebugDataTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, threadid: u32, contextflags: u32, contextsize: u32, pcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetThreadContext<Identity: windows_core::IUnknownImpl + IJsDebugDataTarget_Impl>(this: *mut core::ffi::c_void, threadid: u32, contextflags: u32, contextsize: u32, pcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IJsDebugDataTarget_Impl::GetThreadContext(this, core::mem::transmute_copy(&threadid), core::mem::transmute_copy(&contextflags), core::mem::transmute_copy(&contextsize), core::mem::transmute_copy(&pcontext)).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            ReadMemory: ReadMemory::<Identity, OFFSET>,
-            WriteMemory: WriteMemory::<Identity, OFFSET>,
-            AllocateVirtualMemory: AllocateVirtualMemory::<Identity, OFFSET>,
-            FreeVirtualMemory: FreeVirtualMemory::<Identity, OFFSET>,
-            GetTlsValue: GetTlsValue::<Identity, OFFSET>,
-            ReadBSTR: ReadBSTR::<Identity, OFFSET>,
-            ReadNullTerminatedString: ReadNullTerminatedString::<Identity, OFFSET>,
-            CreateStackFrameEnumerator: CreateStackFrameEnumerator::<Identity, OFFSET>,
-            GetThreadContext: GetThreadContext::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IJsDebugDataTarget as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ReadMemory: ReadMemory::<Identity>,
+            WriteMemory: WriteMemory::<Identity>,
+            AllocateVirtualMemory: AllocateVirtualMemory::<Identity>,
+            FreeVirtualMemory: FreeVirtualMemory::<Identity>,
+            GetTlsValue: GetTlsValue::<Identity>,
+            ReadBSTR: ReadBSTR::<Identity>,
+            ReadNullTerminatedString: ReadNullTerminatedString::<Identity>,
+            CreateStackFrameEnumerator: CreateStackFrameEnumerator::<Identity>,
+            GetThreadContext: GetThreadContext::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IJsDebugDataTarget {}
 windows_core::imp::define_interface!(IJsDebugFrame, IJsDebugFrame_Vtbl, 0xc9196637_ab9d_44b2_bad2_13b95b3f390e);
@@ -8284,6 +12354,83 @@ impl IJsDebugFrame_Vtbl {
         iid == &<IJsDebugFrame as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IJsDebugFrame_Impl> windows_core::imp::TearOffVtable<Identity> for IJsDebugFrame_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetStackRange<Identity: windows_core::IUnknownImpl + IJsDebugFrame_Impl>(this: *mut core::ffi::c_void, pstart: *mut u64, pend: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IJsDebugFrame_Impl::GetStackRange(this, core::mem::transmute_copy(&pstart), core::mem::transmute_copy(&pend)).into()
+            }
+        }
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IJsDebugFrame_Impl>(this: *mut core::ffi::c_void, pname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IJsDebugFrame_Impl::GetName(this) {
+                    Ok(ok__) => {
+                        pname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDocumentPositionWithId<Identity: windows_core::IUnknownImpl + IJsDebugFrame_Impl>(this: *mut core::ffi::c_void, pdocumentid: *mut u64, pcharacteroffset: *mut u32, pstatementcharcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IJsDebugFrame_Impl::GetDocumentPositionWithId(this, core::mem::transmute_copy(&pdocumentid), core::mem::transmute_copy(&pcharacteroffset), core::mem::transmute_copy(&pstatementcharcount)).into()
+            }
+        }
+        unsafe extern "system" fn GetDocumentPositionWithName<Identity: windows_core::IUnknownImpl + IJsDebugFrame_Impl>(this: *mut core::ffi::c_void, pdocumentname: *mut *mut core::ffi::c_void, pline: *mut u32, pcolumn: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IJsDebugFrame_Impl::GetDocumentPositionWithName(this, core::mem::transmute_copy(&pdocumentname), core::mem::transmute_copy(&pline), core::mem::transmute_copy(&pcolumn)).into()
+            }
+        }
+        unsafe extern "system" fn GetDebugProperty<Identity: windows_core::IUnknownImpl + IJsDebugFrame_Impl>(this: *mut core::ffi::c_void, ppdebugproperty: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IJsDebugFrame_Impl::GetDebugProperty(this) {
+                    Ok(ok__) => {
+                        ppdebugproperty.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetReturnAddress<Identity: windows_core::IUnknownImpl + IJsDebugFrame_Impl>(this: *mut core::ffi::c_void, preturnaddress: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IJsDebugFrame_Impl::GetReturnAddress(this) {
+                    Ok(ok__) => {
+                        preturnaddress.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Evaluate<Identity: windows_core::IUnknownImpl + IJsDebugFrame_Impl>(this: *mut core::ffi::c_void, pexpressiontext: windows_core::PCWSTR, ppdebugproperty: *mut *mut core::ffi::c_void, perror: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IJsDebugFrame_Impl::Evaluate(this, core::mem::transmute(&pexpressiontext), core::mem::transmute_copy(&ppdebugproperty), core::mem::transmute_copy(&perror)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetStackRange: GetStackRange::<Identity>,
+            GetName: GetName::<Identity>,
+            GetDocumentPositionWithId: GetDocumentPositionWithId::<Identity>,
+            GetDocumentPositionWithName: GetDocumentPositionWithName::<Identity>,
+            GetDebugProperty: GetDebugProperty::<Identity>,
+            GetReturnAddress: GetReturnAddress::<Identity>,
+            Evaluate: Evaluate::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IJsDebugFrame {}
 windows_core::imp::define_interface!(IJsDebugProcess, IJsDebugProcess_Vtbl, 0x3d587168_6a2d_4041_bd3b_0de674502862);
 windows_core::imp::interface_hierarchy!(IJsDebugProcess, windows_core::IUnknown);
@@ -8381,6 +12528,62 @@ impl IJsDebugProcess_Vtbl {
         iid == &<IJsDebugProcess as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IJsDebugProcess_Impl> windows_core::imp::TearOffVtable<Identity> for IJsDebugProcess_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateStackWalker<Identity: windows_core::IUnknownImpl + IJsDebugProcess_Impl>(this: *mut core::ffi::c_void, threadid: u32, ppstackwalker: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IJsDebugProcess_Impl::CreateStackWalker(this, core::mem::transmute_copy(&threadid)) {
+                    Ok(ok__) => {
+                        ppstackwalker.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateBreakPoint<Identity: windows_core::IUnknownImpl + IJsDebugProcess_Impl>(this: *mut core::ffi::c_void, documentid: u64, characteroffset: u32, charactercount: u32, isenabled: windows_core::BOOL, ppdebugbreakpoint: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IJsDebugProcess_Impl::CreateBreakPoint(this, core::mem::transmute_copy(&documentid), core::mem::transmute_copy(&characteroffset), core::mem::transmute_copy(&charactercount), core::mem::transmute_copy(&isenabled)) {
+                    Ok(ok__) => {
+                        ppdebugbreakpoint.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PerformAsyncBreak<Identity: windows_core::IUnknownImpl + IJsDebugProcess_Impl>(this: *mut core::ffi::c_void, threadid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IJsDebugProcess_Impl::PerformAsyncBreak(this, core::mem::transmute_copy(&threadid)).into()
+            }
+        }
+        unsafe extern "system" fn GetExternalStepAddress<Identity: windows_core::IUnknownImpl + IJsDebugProcess_Impl>(this: *mut core::ffi::c_void, pcodeaddress: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IJsDebugProcess_Impl::GetExternalStepAddress(this) {
+                    Ok(ok__) => {
+                        pcodeaddress.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateStackWalker: CreateStackWalker::<Identity>,
+            CreateBreakPoint: CreateBreakPoint::<Identity>,
+            PerformAsyncBreak: PerformAsyncBreak::<Identity>,
+            GetExternalStepAddress: GetExternalStepAddress::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IJsDebugProcess {}
 windows_core::imp::define_interface!(IJsDebugProperty, IJsDebugProperty_Vtbl, 0xf8ffcf2b_3aa4_4320_85c3_52a312ba9633);
 windows_core::imp::interface_hierarchy!(IJsDebugProperty, windows_core::IUnknown);
@@ -8436,6 +12639,36 @@ impl IJsDebugProperty_Vtbl {
         iid == &<IJsDebugProperty as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IJsDebugProperty_Impl> windows_core::imp::TearOffVtable<Identity> for IJsDebugProperty_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPropertyInfo<Identity: windows_core::IUnknownImpl + IJsDebugProperty_Impl>(this: *mut core::ffi::c_void, nradix: u32, ppropertyinfo: *mut JsDebugPropertyInfo) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IJsDebugProperty_Impl::GetPropertyInfo(this, core::mem::transmute_copy(&nradix), core::mem::transmute_copy(&ppropertyinfo)).into()
+            }
+        }
+        unsafe extern "system" fn GetMembers<Identity: windows_core::IUnknownImpl + IJsDebugProperty_Impl>(this: *mut core::ffi::c_void, members: JS_PROPERTY_MEMBERS, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IJsDebugProperty_Impl::GetMembers(this, core::mem::transmute_copy(&members)) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPropertyInfo: GetPropertyInfo::<Identity>,
+            GetMembers: GetMembers::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IJsDebugProperty {}
 windows_core::imp::define_interface!(IJsDebugStackWalker, IJsDebugStackWalker_Vtbl, 0xdb24b094_73c4_456c_a4ec_e90ea00bdfe3);
 windows_core::imp::interface_hierarchy!(IJsDebugStackWalker, windows_core::IUnknown);
@@ -8476,6 +12709,26 @@ impl IJsDebugStackWalker_Vtbl {
         iid == &<IJsDebugStackWalker as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IJsDebugStackWalker_Impl> windows_core::imp::TearOffVtable<Identity> for IJsDebugStackWalker_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetNext<Identity: windows_core::IUnknownImpl + IJsDebugStackWalker_Impl>(this: *mut core::ffi::c_void, ppframe: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IJsDebugStackWalker_Impl::GetNext(this) {
+                    Ok(ok__) => {
+                        ppframe.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetNext: GetNext::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IJsDebugStackWalker {}
 windows_core::imp::define_interface!(IJsEnumDebugProperty, IJsEnumDebugProperty_Vtbl, 0x4092432f_2f0f_4fe1_b638_5b74a52cdcbe);
 windows_core::imp::interface_hierarchy!(IJsEnumDebugProperty, windows_core::IUnknown);
@@ -8527,6 +12780,36 @@ impl IJsEnumDebugProperty_Vtbl {
         iid == &<IJsEnumDebugProperty as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IJsEnumDebugProperty_Impl> windows_core::imp::TearOffVtable<Identity> for IJsEnumDebugProperty_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IJsEnumDebugProperty_Impl>(this: *mut core::ffi::c_void, count: u32, ppdebugproperty: *mut *mut core::ffi::c_void, pactualcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IJsEnumDebugProperty_Impl::Next(this, core::mem::transmute_copy(&count), core::mem::transmute_copy(&ppdebugproperty), core::mem::transmute_copy(&pactualcount)).into()
+            }
+        }
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + IJsEnumDebugProperty_Impl>(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IJsEnumDebugProperty_Impl::GetCount(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            GetCount: GetCount::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IJsEnumDebugProperty {}
 windows_core::imp::define_interface!(IMachineDebugManager, IMachineDebugManager_Vtbl, 0x51973c2c_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IMachineDebugManager, windows_core::IUnknown);
@@ -8606,6 +12889,49 @@ impl IMachineDebugManager_Vtbl {
         iid == &<IMachineDebugManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMachineDebugManager_Impl> windows_core::imp::TearOffVtable<Identity> for IMachineDebugManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddApplication<Identity: windows_core::IUnknownImpl + IMachineDebugManager_Impl>(this: *mut core::ffi::c_void, pda: *mut core::ffi::c_void, pdwappcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMachineDebugManager_Impl::AddApplication(this, core::mem::transmute_copy(&pda)) {
+                    Ok(ok__) => {
+                        pdwappcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveApplication<Identity: windows_core::IUnknownImpl + IMachineDebugManager_Impl>(this: *mut core::ffi::c_void, dwappcookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMachineDebugManager_Impl::RemoveApplication(this, core::mem::transmute_copy(&dwappcookie)).into()
+            }
+        }
+        unsafe extern "system" fn EnumApplications<Identity: windows_core::IUnknownImpl + IMachineDebugManager_Impl>(this: *mut core::ffi::c_void, ppeda: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMachineDebugManager_Impl::EnumApplications(this) {
+                    Ok(ok__) => {
+                        ppeda.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddApplication: AddApplication::<Identity>,
+            RemoveApplication: RemoveApplication::<Identity>,
+            EnumApplications: EnumApplications::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMachineDebugManager {}
 windows_core::imp::define_interface!(IMachineDebugManagerCookie, IMachineDebugManagerCookie_Vtbl, 0x51973c2d_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IMachineDebugManagerCookie, windows_core::IUnknown);
@@ -8642,11 +12968,57 @@ pub trait IMachineDebugManagerCookie_Impl: windows_core::IUnknownImpl {
     fn RemoveApplication(&self, dwdebugappcookie: u32, dwappcookie: u32) -> windows_core::Result<()>;
     fn EnumApplications(&self) -> windows_core::Result<IEnumRemoteDebugApplications>;
 }
-impl IMachineDebugManagerCookie_Vtbl {
-    pub const fn new<Identity: IMachineDebugManagerCookie_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn AddApplication<Identity: IMachineDebugManagerCookie_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pda: *mut core::ffi::c_void, dwdebugappcookie: u32, pdwappcookie: *mut u32) -> windows_core::HRESULT {
+impl IMachineDebugManagerCookie_Vtbl {
+    pub const fn new<Identity: IMachineDebugManagerCookie_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn AddApplication<Identity: IMachineDebugManagerCookie_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pda: *mut core::ffi::c_void, dwdebugappcookie: u32, pdwappcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IMachineDebugManagerCookie_Impl::AddApplication(this, core::mem::transmute_copy(&pda), core::mem::transmute_copy(&dwdebugappcookie)) {
+                    Ok(ok__) => {
+                        pdwappcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveApplication<Identity: IMachineDebugManagerCookie_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwdebugappcookie: u32, dwappcookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IMachineDebugManagerCookie_Impl::RemoveApplication(this, core::mem::transmute_copy(&dwdebugappcookie), core::mem::transmute_copy(&dwappcookie)).into()
+            }
+        }
+        unsafe extern "system" fn EnumApplications<Identity: IMachineDebugManagerCookie_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppeda: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IMachineDebugManagerCookie_Impl::EnumApplications(this) {
+                    Ok(ok__) => {
+                        ppeda.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            AddApplication: AddApplication::<Identity, OFFSET>,
+            RemoveApplication: RemoveApplication::<Identity, OFFSET>,
+            EnumApplications: EnumApplications::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMachineDebugManagerCookie as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IMachineDebugManagerCookie_Impl> windows_core::imp::TearOffVtable<Identity> for IMachineDebugManagerCookie_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddApplication<Identity: windows_core::IUnknownImpl + IMachineDebugManagerCookie_Impl>(this: *mut core::ffi::c_void, pda: *mut core::ffi::c_void, dwdebugappcookie: u32, pdwappcookie: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IMachineDebugManagerCookie_Impl::AddApplication(this, core::mem::transmute_copy(&pda), core::mem::transmute_copy(&dwdebugappcookie)) {
                     Ok(ok__) => {
                         pdwappcookie.write(core::mem::transmute(ok__));
@@ -8656,15 +13028,15 @@ impl IMachineDebugManagerCookie_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn RemoveApplication<Identity: IMachineDebugManagerCookie_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwdebugappcookie: u32, dwappcookie: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn RemoveApplication<Identity: windows_core::IUnknownImpl + IMachineDebugManagerCookie_Impl>(this: *mut core::ffi::c_void, dwdebugappcookie: u32, dwappcookie: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IMachineDebugManagerCookie_Impl::RemoveApplication(this, core::mem::transmute_copy(&dwdebugappcookie), core::mem::transmute_copy(&dwappcookie)).into()
             }
         }
-        unsafe extern "system" fn EnumApplications<Identity: IMachineDebugManagerCookie_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppeda: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn EnumApplications<Identity: windows_core::IUnknownImpl + IMachineDebugManagerCookie_Impl>(this: *mut core::ffi::c_void, ppeda: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IMachineDebugManagerCookie_Impl::EnumApplications(this) {
                     Ok(ok__) => {
                         ppeda.write(core::mem::transmute(ok__));
@@ -8675,15 +13047,12 @@ impl IMachineDebugManagerCookie_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            AddApplication: AddApplication::<Identity, OFFSET>,
-            RemoveApplication: RemoveApplication::<Identity, OFFSET>,
-            EnumApplications: EnumApplications::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddApplication: AddApplication::<Identity>,
+            RemoveApplication: RemoveApplication::<Identity>,
+            EnumApplications: EnumApplications::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IMachineDebugManagerCookie as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IMachineDebugManagerCookie {}
 windows_core::imp::define_interface!(IMachineDebugManagerEvents, IMachineDebugManagerEvents_Vtbl, 0x51973c2e_cb0c_11d0_b5c9_00a0244a0e7a);
@@ -8737,6 +13106,30 @@ impl IMachineDebugManagerEvents_Vtbl {
         iid == &<IMachineDebugManagerEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMachineDebugManagerEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IMachineDebugManagerEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn onAddApplication<Identity: windows_core::IUnknownImpl + IMachineDebugManagerEvents_Impl>(this: *mut core::ffi::c_void, pda: *mut core::ffi::c_void, dwappcookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMachineDebugManagerEvents_Impl::onAddApplication(this, core::mem::transmute_copy(&pda), core::mem::transmute_copy(&dwappcookie)).into()
+            }
+        }
+        unsafe extern "system" fn onRemoveApplication<Identity: windows_core::IUnknownImpl + IMachineDebugManagerEvents_Impl>(this: *mut core::ffi::c_void, pda: *mut core::ffi::c_void, dwappcookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMachineDebugManagerEvents_Impl::onRemoveApplication(this, core::mem::transmute_copy(&pda), core::mem::transmute_copy(&dwappcookie)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            onAddApplication: onAddApplication::<Identity>,
+            onRemoveApplication: onRemoveApplication::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMachineDebugManagerEvents {}
 windows_core::imp::define_interface!(IProcessDebugManager32, IProcessDebugManager32_Vtbl, 0x51973c2f_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IProcessDebugManager32, windows_core::IUnknown);
@@ -8861,6 +13254,75 @@ impl IProcessDebugManager32_Vtbl {
         iid == &<IProcessDebugManager32 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IProcessDebugManager32_Impl> windows_core::imp::TearOffVtable<Identity> for IProcessDebugManager32_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateApplication<Identity: windows_core::IUnknownImpl + IProcessDebugManager32_Impl>(this: *mut core::ffi::c_void, ppda: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProcessDebugManager32_Impl::CreateApplication(this) {
+                    Ok(ok__) => {
+                        ppda.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDefaultApplication<Identity: windows_core::IUnknownImpl + IProcessDebugManager32_Impl>(this: *mut core::ffi::c_void, ppda: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProcessDebugManager32_Impl::GetDefaultApplication(this) {
+                    Ok(ok__) => {
+                        ppda.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddApplication<Identity: windows_core::IUnknownImpl + IProcessDebugManager32_Impl>(this: *mut core::ffi::c_void, pda: *mut core::ffi::c_void, pdwappcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProcessDebugManager32_Impl::AddApplication(this, core::mem::transmute_copy(&pda)) {
+                    Ok(ok__) => {
+                        pdwappcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveApplication<Identity: windows_core::IUnknownImpl + IProcessDebugManager32_Impl>(this: *mut core::ffi::c_void, dwappcookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IProcessDebugManager32_Impl::RemoveApplication(this, core::mem::transmute_copy(&dwappcookie)).into()
+            }
+        }
+        unsafe extern "system" fn CreateDebugDocumentHelper<Identity: windows_core::IUnknownImpl + IProcessDebugManager32_Impl>(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, pddh: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProcessDebugManager32_Impl::CreateDebugDocumentHelper(this, core::mem::transmute_copy(&punkouter)) {
+                    Ok(ok__) => {
+                        pddh.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateApplication: CreateApplication::<Identity>,
+            GetDefaultApplication: GetDefaultApplication::<Identity>,
+            AddApplication: AddApplication::<Identity>,
+            RemoveApplication: RemoveApplication::<Identity>,
+            CreateDebugDocumentHelper: CreateDebugDocumentHelper::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IProcessDebugManager32 {}
 windows_core::imp::define_interface!(IProcessDebugManager64, IProcessDebugManager64_Vtbl, 0x56b9fc1c_63a9_4cc1_ac21_087d69a17fab);
 windows_core::imp::interface_hierarchy!(IProcessDebugManager64, windows_core::IUnknown);
@@ -8985,6 +13447,75 @@ impl IProcessDebugManager64_Vtbl {
         iid == &<IProcessDebugManager64 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IProcessDebugManager64_Impl> windows_core::imp::TearOffVtable<Identity> for IProcessDebugManager64_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateApplication<Identity: windows_core::IUnknownImpl + IProcessDebugManager64_Impl>(this: *mut core::ffi::c_void, ppda: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProcessDebugManager64_Impl::CreateApplication(this) {
+                    Ok(ok__) => {
+                        ppda.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDefaultApplication<Identity: windows_core::IUnknownImpl + IProcessDebugManager64_Impl>(this: *mut core::ffi::c_void, ppda: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProcessDebugManager64_Impl::GetDefaultApplication(this) {
+                    Ok(ok__) => {
+                        ppda.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddApplication<Identity: windows_core::IUnknownImpl + IProcessDebugManager64_Impl>(this: *mut core::ffi::c_void, pda: *mut core::ffi::c_void, pdwappcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProcessDebugManager64_Impl::AddApplication(this, core::mem::transmute_copy(&pda)) {
+                    Ok(ok__) => {
+                        pdwappcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveApplication<Identity: windows_core::IUnknownImpl + IProcessDebugManager64_Impl>(this: *mut core::ffi::c_void, dwappcookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IProcessDebugManager64_Impl::RemoveApplication(this, core::mem::transmute_copy(&dwappcookie)).into()
+            }
+        }
+        unsafe extern "system" fn CreateDebugDocumentHelper<Identity: windows_core::IUnknownImpl + IProcessDebugManager64_Impl>(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, pddh: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProcessDebugManager64_Impl::CreateDebugDocumentHelper(this, core::mem::transmute_copy(&punkouter)) {
+                    Ok(ok__) => {
+                        pddh.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateApplication: CreateApplication::<Identity>,
+            GetDefaultApplication: GetDefaultApplication::<Identity>,
+            AddApplication: AddApplication::<Identity>,
+            RemoveApplication: RemoveApplication::<Identity>,
+            CreateDebugDocumentHelper: CreateDebugDocumentHelper::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IProcessDebugManager64 {}
 windows_core::imp::define_interface!(IProvideExpressionContexts, IProvideExpressionContexts_Vtbl, 0x51973c41_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IProvideExpressionContexts, windows_core::IUnknown);
@@ -9025,6 +13556,29 @@ impl IProvideExpressionContexts_Vtbl {
         iid == &<IProvideExpressionContexts as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IProvideExpressionContexts_Impl> windows_core::imp::TearOffVtable<Identity> for IProvideExpressionContexts_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumExpressionContexts<Identity: windows_core::IUnknownImpl + IProvideExpressionContexts_Impl>(this: *mut core::ffi::c_void, ppedec: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProvideExpressionContexts_Impl::EnumExpressionContexts(this) {
+                    Ok(ok__) => {
+                        ppedec.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnumExpressionContexts: EnumExpressionContexts::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IProvideExpressionContexts {}
 windows_core::imp::define_interface!(IRemoteDebugApplication, IRemoteDebugApplication_Vtbl, 0x51973c30_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IRemoteDebugApplication, windows_core::IUnknown);
@@ -9242,6 +13796,129 @@ impl IRemoteDebugApplication_Vtbl {
         iid == &<IRemoteDebugApplication as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRemoteDebugApplication_Impl> windows_core::imp::TearOffVtable<Identity> for IRemoteDebugApplication_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ResumeFromBreakPoint<Identity: windows_core::IUnknownImpl + IRemoteDebugApplication_Impl>(this: *mut core::ffi::c_void, prptfocus: *mut core::ffi::c_void, bra: BREAKRESUMEACTION, era: ERRORRESUMEACTION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDebugApplication_Impl::ResumeFromBreakPoint(this, core::mem::transmute_copy(&prptfocus), core::mem::transmute_copy(&bra), core::mem::transmute_copy(&era)).into()
+            }
+        }
+        unsafe extern "system" fn CauseBreak<Identity: windows_core::IUnknownImpl + IRemoteDebugApplication_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDebugApplication_Impl::CauseBreak(this).into()
+            }
+        }
+        unsafe extern "system" fn ConnectDebugger<Identity: windows_core::IUnknownImpl + IRemoteDebugApplication_Impl>(this: *mut core::ffi::c_void, pad: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDebugApplication_Impl::ConnectDebugger(this, core::mem::transmute_copy(&pad)).into()
+            }
+        }
+        unsafe extern "system" fn DisconnectDebugger<Identity: windows_core::IUnknownImpl + IRemoteDebugApplication_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDebugApplication_Impl::DisconnectDebugger(this).into()
+            }
+        }
+        unsafe extern "system" fn GetDebugger<Identity: windows_core::IUnknownImpl + IRemoteDebugApplication_Impl>(this: *mut core::ffi::c_void, pad: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRemoteDebugApplication_Impl::GetDebugger(this) {
+                    Ok(ok__) => {
+                        pad.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateInstanceAtApplication<Identity: windows_core::IUnknownImpl + IRemoteDebugApplication_Impl>(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, punkouter: *mut core::ffi::c_void, dwclscontext: u32, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRemoteDebugApplication_Impl::CreateInstanceAtApplication(this, core::mem::transmute_copy(&rclsid), core::mem::transmute_copy(&punkouter), core::mem::transmute_copy(&dwclscontext), core::mem::transmute_copy(&riid)) {
+                    Ok(ok__) => {
+                        ppvobject.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryAlive<Identity: windows_core::IUnknownImpl + IRemoteDebugApplication_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDebugApplication_Impl::QueryAlive(this).into()
+            }
+        }
+        unsafe extern "system" fn EnumThreads<Identity: windows_core::IUnknownImpl + IRemoteDebugApplication_Impl>(this: *mut core::ffi::c_void, pperdat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRemoteDebugApplication_Impl::EnumThreads(this) {
+                    Ok(ok__) => {
+                        pperdat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IRemoteDebugApplication_Impl>(this: *mut core::ffi::c_void, pbstrname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRemoteDebugApplication_Impl::GetName(this) {
+                    Ok(ok__) => {
+                        pbstrname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRootNode<Identity: windows_core::IUnknownImpl + IRemoteDebugApplication_Impl>(this: *mut core::ffi::c_void, ppdanroot: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRemoteDebugApplication_Impl::GetRootNode(this) {
+                    Ok(ok__) => {
+                        ppdanroot.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumGlobalExpressionContexts<Identity: windows_core::IUnknownImpl + IRemoteDebugApplication_Impl>(this: *mut core::ffi::c_void, ppedec: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRemoteDebugApplication_Impl::EnumGlobalExpressionContexts(this) {
+                    Ok(ok__) => {
+                        ppedec.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ResumeFromBreakPoint: ResumeFromBreakPoint::<Identity>,
+            CauseBreak: CauseBreak::<Identity>,
+            ConnectDebugger: ConnectDebugger::<Identity>,
+            DisconnectDebugger: DisconnectDebugger::<Identity>,
+            GetDebugger: GetDebugger::<Identity>,
+            CreateInstanceAtApplication: CreateInstanceAtApplication::<Identity>,
+            QueryAlive: QueryAlive::<Identity>,
+            EnumThreads: EnumThreads::<Identity>,
+            GetName: GetName::<Identity>,
+            GetRootNode: GetRootNode::<Identity>,
+            EnumGlobalExpressionContexts: EnumGlobalExpressionContexts::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRemoteDebugApplication {}
 windows_core::imp::define_interface!(IRemoteDebugApplication110, IRemoteDebugApplication110_Vtbl, 0xd5fe005b_2836_485e_b1f9_89d91aa24fd4);
 windows_core::imp::interface_hierarchy!(IRemoteDebugApplication110, windows_core::IUnknown);
@@ -9275,17 +13952,63 @@ pub trait IRemoteDebugApplication110_Impl: windows_core::IUnknownImpl {
     fn GetCurrentDebuggerOptions(&self) -> windows_core::Result<SCRIPT_DEBUGGER_OPTIONS>;
     fn GetMainThread(&self) -> windows_core::Result<IRemoteDebugApplicationThread>;
 }
-impl IRemoteDebugApplication110_Vtbl {
-    pub const fn new<Identity: IRemoteDebugApplication110_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn SetDebuggerOptions<Identity: IRemoteDebugApplication110_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, mask: SCRIPT_DEBUGGER_OPTIONS, value: SCRIPT_DEBUGGER_OPTIONS) -> windows_core::HRESULT {
+impl IRemoteDebugApplication110_Vtbl {
+    pub const fn new<Identity: IRemoteDebugApplication110_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn SetDebuggerOptions<Identity: IRemoteDebugApplication110_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, mask: SCRIPT_DEBUGGER_OPTIONS, value: SCRIPT_DEBUGGER_OPTIONS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IRemoteDebugApplication110_Impl::SetDebuggerOptions(this, core::mem::transmute_copy(&mask), core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn GetCurrentDebuggerOptions<Identity: IRemoteDebugApplication110_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pcurrentoptions: *mut SCRIPT_DEBUGGER_OPTIONS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRemoteDebugApplication110_Impl::GetCurrentDebuggerOptions(this) {
+                    Ok(ok__) => {
+                        pcurrentoptions.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMainThread<Identity: IRemoteDebugApplication110_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppthread: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRemoteDebugApplication110_Impl::GetMainThread(this) {
+                    Ok(ok__) => {
+                        ppthread.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            SetDebuggerOptions: SetDebuggerOptions::<Identity, OFFSET>,
+            GetCurrentDebuggerOptions: GetCurrentDebuggerOptions::<Identity, OFFSET>,
+            GetMainThread: GetMainThread::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteDebugApplication110 as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IRemoteDebugApplication110_Impl> windows_core::imp::TearOffVtable<Identity> for IRemoteDebugApplication110_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetDebuggerOptions<Identity: windows_core::IUnknownImpl + IRemoteDebugApplication110_Impl>(this: *mut core::ffi::c_void, mask: SCRIPT_DEBUGGER_OPTIONS, value: SCRIPT_DEBUGGER_OPTIONS) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IRemoteDebugApplication110_Impl::SetDebuggerOptions(this, core::mem::transmute_copy(&mask), core::mem::transmute_copy(&value)).into()
             }
         }
-        unsafe extern "system" fn GetCurrentDebuggerOptions<Identity: IRemoteDebugApplication110_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pcurrentoptions: *mut SCRIPT_DEBUGGER_OPTIONS) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetCurrentDebuggerOptions<Identity: windows_core::IUnknownImpl + IRemoteDebugApplication110_Impl>(this: *mut core::ffi::c_void, pcurrentoptions: *mut SCRIPT_DEBUGGER_OPTIONS) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRemoteDebugApplication110_Impl::GetCurrentDebuggerOptions(this) {
                     Ok(ok__) => {
                         pcurrentoptions.write(core::mem::transmute(ok__));
@@ -9295,9 +14018,9 @@ impl IRemoteDebugApplication110_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetMainThread<Identity: IRemoteDebugApplication110_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppthread: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetMainThread<Identity: windows_core::IUnknownImpl + IRemoteDebugApplication110_Impl>(this: *mut core::ffi::c_void, ppthread: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRemoteDebugApplication110_Impl::GetMainThread(this) {
                     Ok(ok__) => {
                         ppthread.write(core::mem::transmute(ok__));
@@ -9308,15 +14031,12 @@ impl IRemoteDebugApplication110_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            SetDebuggerOptions: SetDebuggerOptions::<Identity, OFFSET>,
-            GetCurrentDebuggerOptions: GetCurrentDebuggerOptions::<Identity, OFFSET>,
-            GetMainThread: GetMainThread::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetDebuggerOptions: SetDebuggerOptions::<Identity>,
+            GetCurrentDebuggerOptions: GetCurrentDebuggerOptions::<Identity>,
+            GetMainThread: GetMainThread::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IRemoteDebugApplication110 as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IRemoteDebugApplication110 {}
 windows_core::imp::define_interface!(IRemoteDebugApplicationEvents, IRemoteDebugApplicationEvents_Vtbl, 0x51973c33_cb0c_11d0_b5c9_00a0244a0e7a);
@@ -9484,6 +14204,86 @@ impl IRemoteDebugApplicationEvents_Vtbl {
         iid == &<IRemoteDebugApplicationEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRemoteDebugApplicationEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IRemoteDebugApplicationEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnConnectDebugger<Identity: windows_core::IUnknownImpl + IRemoteDebugApplicationEvents_Impl>(this: *mut core::ffi::c_void, pad: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDebugApplicationEvents_Impl::OnConnectDebugger(this, core::mem::transmute_copy(&pad)).into()
+            }
+        }
+        unsafe extern "system" fn OnDisconnectDebugger<Identity: windows_core::IUnknownImpl + IRemoteDebugApplicationEvents_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDebugApplicationEvents_Impl::OnDisconnectDebugger(this).into()
+            }
+        }
+        unsafe extern "system" fn OnSetName<Identity: windows_core::IUnknownImpl + IRemoteDebugApplicationEvents_Impl>(this: *mut core::ffi::c_void, pstrname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDebugApplicationEvents_Impl::OnSetName(this, core::mem::transmute(&pstrname)).into()
+            }
+        }
+        unsafe extern "system" fn OnDebugOutput<Identity: windows_core::IUnknownImpl + IRemoteDebugApplicationEvents_Impl>(this: *mut core::ffi::c_void, pstr: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDebugApplicationEvents_Impl::OnDebugOutput(this, core::mem::transmute(&pstr)).into()
+            }
+        }
+        unsafe extern "system" fn OnClose<Identity: windows_core::IUnknownImpl + IRemoteDebugApplicationEvents_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDebugApplicationEvents_Impl::OnClose(this).into()
+            }
+        }
+        unsafe extern "system" fn OnEnterBreakPoint<Identity: windows_core::IUnknownImpl + IRemoteDebugApplicationEvents_Impl>(this: *mut core::ffi::c_void, prdat: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDebugApplicationEvents_Impl::OnEnterBreakPoint(this, core::mem::transmute_copy(&prdat)).into()
+            }
+        }
+        unsafe extern "system" fn OnLeaveBreakPoint<Identity: windows_core::IUnknownImpl + IRemoteDebugApplicationEvents_Impl>(this: *mut core::ffi::c_void, prdat: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDebugApplicationEvents_Impl::OnLeaveBreakPoint(this, core::mem::transmute_copy(&prdat)).into()
+            }
+        }
+        unsafe extern "system" fn OnCreateThread<Identity: windows_core::IUnknownImpl + IRemoteDebugApplicationEvents_Impl>(this: *mut core::ffi::c_void, prdat: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDebugApplicationEvents_Impl::OnCreateThread(this, core::mem::transmute_copy(&prdat)).into()
+            }
+        }
+        unsafe extern "system" fn OnDestroyThread<Identity: windows_core::IUnknownImpl + IRemoteDebugApplicationEvents_Impl>(this: *mut core::ffi::c_void, prdat: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDebugApplicationEvents_Impl::OnDestroyThread(this, core::mem::transmute_copy(&prdat)).into()
+            }
+        }
+        unsafe extern "system" fn OnBreakFlagChange<Identity: windows_core::IUnknownImpl + IRemoteDebugApplicationEvents_Impl>(this: *mut core::ffi::c_void, abf: u32, prdatsteppingthread: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDebugApplicationEvents_Impl::OnBreakFlagChange(this, core::mem::transmute_copy(&abf), core::mem::transmute_copy(&prdatsteppingthread)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnConnectDebugger: OnConnectDebugger::<Identity>,
+            OnDisconnectDebugger: OnDisconnectDebugger::<Identity>,
+            OnSetName: OnSetName::<Identity>,
+            OnDebugOutput: OnDebugOutput::<Identity>,
+            OnClose: OnClose::<Identity>,
+            OnEnterBreakPoint: OnEnterBreakPoint::<Identity>,
+            OnLeaveBreakPoint: OnLeaveBreakPoint::<Identity>,
+            OnCreateThread: OnCreateThread::<Identity>,
+            OnDestroyThread: OnDestroyThread::<Identity>,
+            OnBreakFlagChange: OnBreakFlagChange::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRemoteDebugApplicationEvents {}
 windows_core::imp::define_interface!(IRemoteDebugApplicationThread, IRemoteDebugApplicationThread_Vtbl, 0x51973c37_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(IRemoteDebugApplicationThread, windows_core::IUnknown);
@@ -9681,6 +14481,121 @@ impl IRemoteDebugApplicationThread_Vtbl {
         iid == &<IRemoteDebugApplicationThread as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRemoteDebugApplicationThread_Impl> windows_core::imp::TearOffVtable<Identity> for IRemoteDebugApplicationThread_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSystemThreadId<Identity: windows_core::IUnknownImpl + IRemoteDebugApplicationThread_Impl>(this: *mut core::ffi::c_void, dwthreadid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRemoteDebugApplicationThread_Impl::GetSystemThreadId(this) {
+                    Ok(ok__) => {
+                        dwthreadid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetApplication<Identity: windows_core::IUnknownImpl + IRemoteDebugApplicationThread_Impl>(this: *mut core::ffi::c_void, pprda: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRemoteDebugApplicationThread_Impl::GetApplication(this) {
+                    Ok(ok__) => {
+                        pprda.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumStackFrames<Identity: windows_core::IUnknownImpl + IRemoteDebugApplicationThread_Impl>(this: *mut core::ffi::c_void, ppedsf: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRemoteDebugApplicationThread_Impl::EnumStackFrames(this) {
+                    Ok(ok__) => {
+                        ppedsf.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDescription<Identity: windows_core::IUnknownImpl + IRemoteDebugApplicationThread_Impl>(this: *mut core::ffi::c_void, pbstrdescription: *mut *mut core::ffi::c_void, pbstrstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDebugApplicationThread_Impl::GetDescription(this, core::mem::transmute_copy(&pbstrdescription), core::mem::transmute_copy(&pbstrstate)).into()
+            }
+        }
+        unsafe extern "system" fn SetNextStatement<Identity: windows_core::IUnknownImpl + IRemoteDebugApplicationThread_Impl>(this: *mut core::ffi::c_void, pstackframe: *mut core::ffi::c_void, pcodecontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDebugApplicationThread_Impl::SetNextStatement(this, core::mem::transmute_copy(&pstackframe), core::mem::transmute_copy(&pcodecontext)).into()
+            }
+        }
+        unsafe extern "system" fn GetState<Identity: windows_core::IUnknownImpl + IRemoteDebugApplicationThread_Impl>(this: *mut core::ffi::c_void, pstate: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRemoteDebugApplicationThread_Impl::GetState(this) {
+                    Ok(ok__) => {
+                        pstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Suspend<Identity: windows_core::IUnknownImpl + IRemoteDebugApplicationThread_Impl>(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRemoteDebugApplicationThread_Impl::Suspend(this) {
+                    Ok(ok__) => {
+                        pdwcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Resume<Identity: windows_core::IUnknownImpl + IRemoteDebugApplicationThread_Impl>(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRemoteDebugApplicationThread_Impl::Resume(this) {
+                    Ok(ok__) => {
+                        pdwcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSuspendCount<Identity: windows_core::IUnknownImpl + IRemoteDebugApplicationThread_Impl>(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRemoteDebugApplicationThread_Impl::GetSuspendCount(this) {
+                    Ok(ok__) => {
+                        pdwcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSystemThreadId: GetSystemThreadId::<Identity>,
+            GetApplication: GetApplication::<Identity>,
+            EnumStackFrames: EnumStackFrames::<Identity>,
+            GetDescription: GetDescription::<Identity>,
+            SetNextStatement: SetNextStatement::<Identity>,
+            GetState: GetState::<Identity>,
+            Suspend: Suspend::<Identity>,
+            Resume: Resume::<Identity>,
+            GetSuspendCount: GetSuspendCount::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRemoteDebugApplicationThread {}
 windows_core::imp::define_interface!(IRemoteDebugCriticalErrorEvent110, IRemoteDebugCriticalErrorEvent110_Vtbl, 0x2f69c611_6b14_47e8_9260_4bb7c52f504b);
 windows_core::imp::interface_hierarchy!(IRemoteDebugCriticalErrorEvent110, windows_core::IUnknown);
@@ -9712,6 +14627,20 @@ impl IRemoteDebugCriticalErrorEvent110_Vtbl {
         iid == &<IRemoteDebugCriticalErrorEvent110 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRemoteDebugCriticalErrorEvent110_Impl> windows_core::imp::TearOffVtable<Identity> for IRemoteDebugCriticalErrorEvent110_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetErrorInfo<Identity: windows_core::IUnknownImpl + IRemoteDebugCriticalErrorEvent110_Impl>(this: *mut core::ffi::c_void, pbstrsource: *mut *mut core::ffi::c_void, pmessageid: *mut i32, pbstrmessage: *mut *mut core::ffi::c_void, pplocation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDebugCriticalErrorEvent110_Impl::GetErrorInfo(this, core::mem::transmute_copy(&pbstrsource), core::mem::transmute_copy(&pmessageid), core::mem::transmute_copy(&pbstrmessage), core::mem::transmute_copy(&pplocation)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetErrorInfo: GetErrorInfo::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IRemoteDebugCriticalErrorEvent110 {}
 windows_core::imp::define_interface!(IRemoteDebugInfoEvent110, IRemoteDebugInfoEvent110_Vtbl, 0x9ff56bb6_eb89_4c0f_8823_cc2a4c0b7f26);
 windows_core::imp::interface_hierarchy!(IRemoteDebugInfoEvent110, windows_core::IUnknown);
@@ -9743,6 +14672,20 @@ impl IRemoteDebugInfoEvent110_Vtbl {
         iid == &<IRemoteDebugInfoEvent110 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRemoteDebugInfoEvent110_Impl> windows_core::imp::TearOffVtable<Identity> for IRemoteDebugInfoEvent110_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetEventInfo<Identity: windows_core::IUnknownImpl + IRemoteDebugInfoEvent110_Impl>(this: *mut core::ffi::c_void, pmessagetype: *mut DEBUG_EVENT_INFO_TYPE, pbstrmessage: *mut *mut core::ffi::c_void, pbstrurl: *mut *mut core::ffi::c_void, pplocation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDebugInfoEvent110_Impl::GetEventInfo(this, core::mem::transmute_copy(&pmessagetype), core::mem::transmute_copy(&pbstrmessage), core::mem::transmute_copy(&pbstrurl), core::mem::transmute_copy(&pplocation)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetEventInfo: GetEventInfo::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IRemoteDebugInfoEvent110 {}
 windows_core::imp::define_interface!(IScriptEntry, IScriptEntry_Vtbl, 0x0aee2a95_bcbb_11d0_8c72_00c04fc2b085);
 impl core::ops::Deref for IScriptEntry {
@@ -9893,7 +14836,122 @@ impl IScriptEntry_Vtbl {
         }
         unsafe extern "system" fn GetName<Identity: IScriptEntry_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IScriptEntry_Impl::GetName(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetName<Identity: IScriptEntry_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, psz: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IScriptEntry_Impl::SetName(this, core::mem::transmute(&psz)).into()
+            }
+        }
+        unsafe extern "system" fn GetItemName<Identity: IScriptEntry_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IScriptEntry_Impl::GetItemName(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetItemName<Identity: IScriptEntry_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, psz: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IScriptEntry_Impl::SetItemName(this, core::mem::transmute(&psz)).into()
+            }
+        }
+        unsafe extern "system" fn GetSignature<Identity: IScriptEntry_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppti: *mut *mut core::ffi::c_void, pimethod: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IScriptEntry_Impl::GetSignature(this, core::mem::transmute_copy(&ppti), core::mem::transmute_copy(&pimethod)).into()
+            }
+        }
+        unsafe extern "system" fn SetSignature<Identity: IScriptEntry_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pti: *mut core::ffi::c_void, imethod: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IScriptEntry_Impl::SetSignature(this, core::mem::transmute_copy(&pti), core::mem::transmute_copy(&imethod)).into()
+            }
+        }
+        unsafe extern "system" fn GetRange<Identity: IScriptEntry_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pichmin: *mut u32, pcch: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IScriptEntry_Impl::GetRange(this, core::mem::transmute_copy(&pichmin), core::mem::transmute_copy(&pcch)).into()
+            }
+        }
+        Self {
+            base__: IScriptNode_Vtbl::new::<Identity, OFFSET>(),
+            GetText: GetText::<Identity, OFFSET>,
+            SetText: SetText::<Identity, OFFSET>,
+            GetBody: GetBody::<Identity, OFFSET>,
+            SetBody: SetBody::<Identity, OFFSET>,
+            GetName: GetName::<Identity, OFFSET>,
+            SetName: SetName::<Identity, OFFSET>,
+            GetItemName: GetItemName::<Identity, OFFSET>,
+            SetItemName: SetItemName::<Identity, OFFSET>,
+            GetSignature: GetSignature::<Identity, OFFSET>,
+            SetSignature: SetSignature::<Identity, OFFSET>,
+            GetRange: GetRange::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScriptEntry as windows_core::Interface>::IID || iid == &<IScriptNode as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IScriptEntry_Impl> windows_core::imp::TearOffVtable<Identity> for IScriptEntry_Vtbl
+where
+    IScriptNode_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetText<Identity: windows_core::IUnknownImpl + IScriptEntry_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScriptEntry_Impl::GetText(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetText<Identity: windows_core::IUnknownImpl + IScriptEntry_Impl>(this: *mut core::ffi::c_void, psz: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScriptEntry_Impl::SetText(this, core::mem::transmute(&psz)).into()
+            }
+        }
+        unsafe extern "system" fn GetBody<Identity: windows_core::IUnknownImpl + IScriptEntry_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScriptEntry_Impl::GetBody(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBody<Identity: windows_core::IUnknownImpl + IScriptEntry_Impl>(this: *mut core::ffi::c_void, psz: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScriptEntry_Impl::SetBody(this, core::mem::transmute(&psz)).into()
+            }
+        }
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IScriptEntry_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IScriptEntry_Impl::GetName(this) {
                     Ok(ok__) => {
                         pbstr.write(core::mem::transmute(ok__));
@@ -9903,15 +14961,15 @@ impl IScriptEntry_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetName<Identity: IScriptEntry_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, psz: windows_core::PCWSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetName<Identity: windows_core::IUnknownImpl + IScriptEntry_Impl>(this: *mut core::ffi::c_void, psz: windows_core::PCWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IScriptEntry_Impl::SetName(this, core::mem::transmute(&psz)).into()
             }
         }
-        unsafe extern "system" fn GetItemName<Identity: IScriptEntry_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetItemName<Identity: windows_core::IUnknownImpl + IScriptEntry_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IScriptEntry_Impl::GetItemName(this) {
                     Ok(ok__) => {
                         pbstr.write(core::mem::transmute(ok__));
@@ -9921,48 +14979,45 @@ impl IScriptEntry_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetItemName<Identity: IScriptEntry_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, psz: windows_core::PCWSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetItemName<Identity: windows_core::IUnknownImpl + IScriptEntry_Impl>(this: *mut core::ffi::c_void, psz: windows_core::PCWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IScriptEntry_Impl::SetItemName(this, core::mem::transmute(&psz)).into()
             }
         }
-        unsafe extern "system" fn GetSignature<Identity: IScriptEntry_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppti: *mut *mut core::ffi::c_void, pimethod: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetSignature<Identity: windows_core::IUnknownImpl + IScriptEntry_Impl>(this: *mut core::ffi::c_void, ppti: *mut *mut core::ffi::c_void, pimethod: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IScriptEntry_Impl::GetSignature(this, core::mem::transmute_copy(&ppti), core::mem::transmute_copy(&pimethod)).into()
             }
         }
-        unsafe extern "system" fn SetSignature<Identity: IScriptEntry_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pti: *mut core::ffi::c_void, imethod: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetSignature<Identity: windows_core::IUnknownImpl + IScriptEntry_Impl>(this: *mut core::ffi::c_void, pti: *mut core::ffi::c_void, imethod: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IScriptEntry_Impl::SetSignature(this, core::mem::transmute_copy(&pti), core::mem::transmute_copy(&imethod)).into()
             }
         }
-        unsafe extern "system" fn GetRange<Identity: IScriptEntry_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pichmin: *mut u32, pcch: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetRange<Identity: windows_core::IUnknownImpl + IScriptEntry_Impl>(this: *mut core::ffi::c_void, pichmin: *mut u32, pcch: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IScriptEntry_Impl::GetRange(this, core::mem::transmute_copy(&pichmin), core::mem::transmute_copy(&pcch)).into()
             }
         }
         Self {
-            base__: IScriptNode_Vtbl::new::<Identity, OFFSET>(),
-            GetText: GetText::<Identity, OFFSET>,
-            SetText: SetText::<Identity, OFFSET>,
-            GetBody: GetBody::<Identity, OFFSET>,
-            SetBody: SetBody::<Identity, OFFSET>,
-            GetName: GetName::<Identity, OFFSET>,
-            SetName: SetName::<Identity, OFFSET>,
-            GetItemName: GetItemName::<Identity, OFFSET>,
-            SetItemName: SetItemName::<Identity, OFFSET>,
-            GetSignature: GetSignature::<Identity, OFFSET>,
-            SetSignature: SetSignature::<Identity, OFFSET>,
-            GetRange: GetRange::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IScriptEntry as windows_core::Interface>::IID || iid == &<IScriptNode as windows_core::Interface>::IID
-    }
+            base__: <IScriptNode_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetText: GetText::<Identity>,
+            SetText: SetText::<Identity>,
+            GetBody: GetBody::<Identity>,
+            SetBody: SetBody::<Identity>,
+            GetName: GetName::<Identity>,
+            SetName: SetName::<Identity>,
+            GetItemName: GetItemName::<Identity>,
+            SetItemName: SetItemName::<Identity>,
+            GetSignature: GetSignature::<Identity>,
+            SetSignature: SetSignature::<Identity>,
+            GetRange: GetRange::<Identity>,
+        }
+    };
 }
 #[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IScriptEntry {}
@@ -10050,6 +15105,55 @@ impl IScriptInvocationContext_Vtbl {
         iid == &<IScriptInvocationContext as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IScriptInvocationContext_Impl> windows_core::imp::TearOffVtable<Identity> for IScriptInvocationContext_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetContextType<Identity: windows_core::IUnknownImpl + IScriptInvocationContext_Impl>(this: *mut core::ffi::c_void, pinvocationcontexttype: *mut SCRIPT_INVOCATION_CONTEXT_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScriptInvocationContext_Impl::GetContextType(this) {
+                    Ok(ok__) => {
+                        pinvocationcontexttype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetContextDescription<Identity: windows_core::IUnknownImpl + IScriptInvocationContext_Impl>(this: *mut core::ffi::c_void, pdescription: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScriptInvocationContext_Impl::GetContextDescription(this) {
+                    Ok(ok__) => {
+                        pdescription.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetContextObject<Identity: windows_core::IUnknownImpl + IScriptInvocationContext_Impl>(this: *mut core::ffi::c_void, ppcontextobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScriptInvocationContext_Impl::GetContextObject(this) {
+                    Ok(ok__) => {
+                        ppcontextobject.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetContextType: GetContextType::<Identity>,
+            GetContextDescription: GetContextDescription::<Identity>,
+            GetContextObject: GetContextObject::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IScriptInvocationContext {}
 windows_core::imp::define_interface!(IScriptNode, IScriptNode_Vtbl, 0x0aee2a94_bcbb_11d0_8c72_00c04fc2b085);
 windows_core::imp::interface_hierarchy!(IScriptNode, windows_core::IUnknown);
@@ -10280,6 +15384,135 @@ impl IScriptNode_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IScriptNode_Impl> windows_core::imp::TearOffVtable<Identity> for IScriptNode_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Alive<Identity: windows_core::IUnknownImpl + IScriptNode_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScriptNode_Impl::Alive(this).into()
+            }
+        }
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IScriptNode_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScriptNode_Impl::Delete(this).into()
+            }
+        }
+        unsafe extern "system" fn GetParent<Identity: windows_core::IUnknownImpl + IScriptNode_Impl>(this: *mut core::ffi::c_void, ppsnparent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScriptNode_Impl::GetParent(this) {
+                    Ok(ok__) => {
+                        ppsnparent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetIndexInParent<Identity: windows_core::IUnknownImpl + IScriptNode_Impl>(this: *mut core::ffi::c_void, pisn: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScriptNode_Impl::GetIndexInParent(this) {
+                    Ok(ok__) => {
+                        pisn.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetCookie<Identity: windows_core::IUnknownImpl + IScriptNode_Impl>(this: *mut core::ffi::c_void, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScriptNode_Impl::GetCookie(this) {
+                    Ok(ok__) => {
+                        pdwcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetNumberOfChildren<Identity: windows_core::IUnknownImpl + IScriptNode_Impl>(this: *mut core::ffi::c_void, pcsn: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScriptNode_Impl::GetNumberOfChildren(this) {
+                    Ok(ok__) => {
+                        pcsn.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetChild<Identity: windows_core::IUnknownImpl + IScriptNode_Impl>(this: *mut core::ffi::c_void, isn: u32, ppsn: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScriptNode_Impl::GetChild(this, core::mem::transmute_copy(&isn)) {
+                    Ok(ok__) => {
+                        ppsn.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLanguage<Identity: windows_core::IUnknownImpl + IScriptNode_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScriptNode_Impl::GetLanguage(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateChildEntry<Identity: windows_core::IUnknownImpl + IScriptNode_Impl>(this: *mut core::ffi::c_void, isn: u32, dwcookie: u32, pszdelimiter: windows_core::PCWSTR, ppse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScriptNode_Impl::CreateChildEntry(this, core::mem::transmute_copy(&isn), core::mem::transmute_copy(&dwcookie), core::mem::transmute(&pszdelimiter)) {
+                    Ok(ok__) => {
+                        ppse.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateChildHandler<Identity: windows_core::IUnknownImpl + IScriptNode_Impl>(this: *mut core::ffi::c_void, pszdefaultname: windows_core::PCWSTR, prgpsznames: *const windows_core::PCWSTR, cpsznames: u32, pszevent: windows_core::PCWSTR, pszdelimiter: windows_core::PCWSTR, ptisignature: *mut core::ffi::c_void, imethodsignature: u32, isn: u32, dwcookie: u32, ppse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScriptNode_Impl::CreateChildHandler(this, core::mem::transmute(&pszdefaultname), core::mem::transmute_copy(&prgpsznames), core::mem::transmute_copy(&cpsznames), core::mem::transmute(&pszevent), core::mem::transmute(&pszdelimiter), core::mem::transmute_copy(&ptisignature), core::mem::transmute_copy(&imethodsignature), core::mem::transmute_copy(&isn), core::mem::transmute_copy(&dwcookie)) {
+                    Ok(ok__) => {
+                        ppse.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Alive: Alive::<Identity>,
+            Delete: Delete::<Identity>,
+            GetParent: GetParent::<Identity>,
+            GetIndexInParent: GetIndexInParent::<Identity>,
+            GetCookie: GetCookie::<Identity>,
+            GetNumberOfChildren: GetNumberOfChildren::<Identity>,
+            GetChild: GetChild::<Identity>,
+            GetLanguage: GetLanguage::<Identity>,
+            CreateChildEntry: CreateChildEntry::<Identity>,
+            CreateChildHandler: CreateChildHandler::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IScriptNode {}
 windows_core::imp::define_interface!(IScriptScriptlet, IScriptScriptlet_Vtbl, 0x0aee2a96_bcbb_11d0_8c72_00c04fc2b085);
 impl core::ops::Deref for IScriptScriptlet {
@@ -10419,6 +15652,77 @@ impl IScriptScriptlet_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IScriptScriptlet_Impl> windows_core::imp::TearOffVtable<Identity> for IScriptScriptlet_Vtbl
+where
+    IScriptEntry_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSubItemName<Identity: windows_core::IUnknownImpl + IScriptScriptlet_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScriptScriptlet_Impl::GetSubItemName(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSubItemName<Identity: windows_core::IUnknownImpl + IScriptScriptlet_Impl>(this: *mut core::ffi::c_void, psz: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScriptScriptlet_Impl::SetSubItemName(this, core::mem::transmute(&psz)).into()
+            }
+        }
+        unsafe extern "system" fn GetEventName<Identity: windows_core::IUnknownImpl + IScriptScriptlet_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScriptScriptlet_Impl::GetEventName(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEventName<Identity: windows_core::IUnknownImpl + IScriptScriptlet_Impl>(this: *mut core::ffi::c_void, psz: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScriptScriptlet_Impl::SetEventName(this, core::mem::transmute(&psz)).into()
+            }
+        }
+        unsafe extern "system" fn GetSimpleEventName<Identity: windows_core::IUnknownImpl + IScriptScriptlet_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScriptScriptlet_Impl::GetSimpleEventName(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSimpleEventName<Identity: windows_core::IUnknownImpl + IScriptScriptlet_Impl>(this: *mut core::ffi::c_void, psz: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScriptScriptlet_Impl::SetSimpleEventName(this, core::mem::transmute(&psz)).into()
+            }
+        }
+        Self {
+            base__: <IScriptEntry_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSubItemName: GetSubItemName::<Identity>,
+            SetSubItemName: SetSubItemName::<Identity>,
+            GetEventName: GetEventName::<Identity>,
+            SetEventName: SetEventName::<Identity>,
+            GetSimpleEventName: GetSimpleEventName::<Identity>,
+            SetSimpleEventName: SetSimpleEventName::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IScriptScriptlet {}
 windows_core::imp::define_interface!(ISimpleConnectionPoint, ISimpleConnectionPoint_Vtbl, 0x51973c3e_cb0c_11d0_b5c9_00a0244a0e7a);
 windows_core::imp::interface_hierarchy!(ISimpleConnectionPoint, windows_core::IUnknown);
@@ -10517,6 +15821,57 @@ impl ISimpleConnectionPoint_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + ISimpleConnectionPoint_Impl> windows_core::imp::TearOffVtable<Identity> for ISimpleConnectionPoint_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetEventCount<Identity: windows_core::IUnknownImpl + ISimpleConnectionPoint_Impl>(this: *mut core::ffi::c_void, pulcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISimpleConnectionPoint_Impl::GetEventCount(this) {
+                    Ok(ok__) => {
+                        pulcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DescribeEvents<Identity: windows_core::IUnknownImpl + ISimpleConnectionPoint_Impl>(this: *mut core::ffi::c_void, ievent: u32, cevents: u32, prgid: *mut i32, prgbstr: *mut *mut core::ffi::c_void, pceventsfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISimpleConnectionPoint_Impl::DescribeEvents(this, core::mem::transmute_copy(&ievent), core::mem::transmute_copy(&cevents), core::mem::transmute_copy(&prgid), core::mem::transmute_copy(&prgbstr), core::mem::transmute_copy(&pceventsfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Advise<Identity: windows_core::IUnknownImpl + ISimpleConnectionPoint_Impl>(this: *mut core::ffi::c_void, pdisp: *mut core::ffi::c_void, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISimpleConnectionPoint_Impl::Advise(this, core::mem::transmute_copy(&pdisp)) {
+                    Ok(ok__) => {
+                        pdwcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Unadvise<Identity: windows_core::IUnknownImpl + ISimpleConnectionPoint_Impl>(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISimpleConnectionPoint_Impl::Unadvise(this, core::mem::transmute_copy(&dwcookie)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetEventCount: GetEventCount::<Identity>,
+            DescribeEvents: DescribeEvents::<Identity>,
+            Advise: Advise::<Identity>,
+            Unadvise: Unadvise::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for ISimpleConnectionPoint {}
 windows_core::imp::define_interface!(ITridentEventSink, ITridentEventSink_Vtbl, 0x1dc9ca50_06ef_11d2_8415_006008c3fbfc);
 windows_core::imp::interface_hierarchy!(ITridentEventSink, windows_core::IUnknown);
@@ -10558,6 +15913,21 @@ impl ITridentEventSink_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ITridentEventSink_Impl> windows_core::imp::TearOffVtable<Identity> for ITridentEventSink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FireEvent<Identity: windows_core::IUnknownImpl + ITridentEventSink_Impl>(this: *mut core::ffi::c_void, pstrevent: windows_core::PCWSTR, pdp: *const super::super::super::Com::DISPPARAMS, pvarres: *mut super::super::super::Variant::VARIANT, pei: *mut super::super::super::Com::EXCEPINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITridentEventSink_Impl::FireEvent(this, core::mem::transmute(&pstrevent), core::mem::transmute_copy(&pdp), core::mem::transmute_copy(&pvarres), core::mem::transmute_copy(&pei)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, FireEvent: FireEvent::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ITridentEventSink {}
 windows_core::imp::define_interface!(IWebAppDiagnosticsObjectInitialization, IWebAppDiagnosticsObjectInitialization_Vtbl, 0x16ff3a42_a5f5_432b_b625_8e8e16f57e15);
 windows_core::imp::interface_hierarchy!(IWebAppDiagnosticsObjectInitialization, windows_core::IUnknown);
@@ -10592,6 +15962,20 @@ impl IWebAppDiagnosticsObjectInitialization_Vtbl {
         iid == &<IWebAppDiagnosticsObjectInitialization as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWebAppDiagnosticsObjectInitialization_Impl> windows_core::imp::TearOffVtable<Identity> for IWebAppDiagnosticsObjectInitialization_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IWebAppDiagnosticsObjectInitialization_Impl>(this: *mut core::ffi::c_void, hpassedhandle: super::super::super::super::Foundation::HANDLE_PTR, pdebugapplication: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebAppDiagnosticsObjectInitialization_Impl::Initialize(this, core::mem::transmute_copy(&hpassedhandle), core::mem::transmute_copy(&pdebugapplication)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Initialize: Initialize::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWebAppDiagnosticsObjectInitialization {}
 windows_core::imp::define_interface!(IWebAppDiagnosticsSetup, IWebAppDiagnosticsSetup_Vtbl, 0x379bfbe1_c6c9_432a_93e1_6d17656c538c);
 windows_core::imp::interface_hierarchy!(IWebAppDiagnosticsSetup, windows_core::IUnknown);
@@ -10647,6 +16031,36 @@ impl IWebAppDiagnosticsSetup_Vtbl {
         iid == &<IWebAppDiagnosticsSetup as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWebAppDiagnosticsSetup_Impl> windows_core::imp::TearOffVtable<Identity> for IWebAppDiagnosticsSetup_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DiagnosticsSupported<Identity: windows_core::IUnknownImpl + IWebAppDiagnosticsSetup_Impl>(this: *mut core::ffi::c_void, pretval: *mut super::super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWebAppDiagnosticsSetup_Impl::DiagnosticsSupported(this) {
+                    Ok(ok__) => {
+                        pretval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateObjectWithSiteAtWebApp<Identity: windows_core::IUnknownImpl + IWebAppDiagnosticsSetup_Impl>(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, dwclscontext: u32, riid: *const windows_core::GUID, hpasstoobject: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWebAppDiagnosticsSetup_Impl::CreateObjectWithSiteAtWebApp(this, core::mem::transmute_copy(&rclsid), core::mem::transmute_copy(&dwclscontext), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&hpasstoobject)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DiagnosticsSupported: DiagnosticsSupported::<Identity>,
+            CreateObjectWithSiteAtWebApp: CreateObjectWithSiteAtWebApp::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWebAppDiagnosticsSetup {}
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
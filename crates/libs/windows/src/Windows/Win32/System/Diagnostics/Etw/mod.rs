@@ -2085,6 +2085,118 @@ impl ITraceEvent_Vtbl {
         iid == &<ITraceEvent as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITraceEvent_Impl> windows_core::imp::TearOffVtable<Identity> for ITraceEvent_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + ITraceEvent_Impl>(this: *mut core::ffi::c_void, newevent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITraceEvent_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        newevent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetUserContext<Identity: windows_core::IUnknownImpl + ITraceEvent_Impl>(this: *mut core::ffi::c_void, usercontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITraceEvent_Impl::GetUserContext(this) {
+                    Ok(ok__) => {
+                        usercontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetEventRecord<Identity: windows_core::IUnknownImpl + ITraceEvent_Impl>(this: *mut core::ffi::c_void, eventrecord: *mut *mut EVENT_RECORD) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITraceEvent_Impl::GetEventRecord(this) {
+                    Ok(ok__) => {
+                        eventrecord.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPayload<Identity: windows_core::IUnknownImpl + ITraceEvent_Impl>(this: *mut core::ffi::c_void, payload: *const u8, payloadsize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITraceEvent_Impl::SetPayload(this, core::mem::transmute_copy(&payload), core::mem::transmute_copy(&payloadsize)).into()
+            }
+        }
+        unsafe extern "system" fn SetEventDescriptor<Identity: windows_core::IUnknownImpl + ITraceEvent_Impl>(this: *mut core::ffi::c_void, eventdescriptor: *const EVENT_DESCRIPTOR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITraceEvent_Impl::SetEventDescriptor(this, core::mem::transmute_copy(&eventdescriptor)).into()
+            }
+        }
+        unsafe extern "system" fn SetProcessId<Identity: windows_core::IUnknownImpl + ITraceEvent_Impl>(this: *mut core::ffi::c_void, processid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITraceEvent_Impl::SetProcessId(this, core::mem::transmute_copy(&processid)).into()
+            }
+        }
+        unsafe extern "system" fn SetProcessorIndex<Identity: windows_core::IUnknownImpl + ITraceEvent_Impl>(this: *mut core::ffi::c_void, processorindex: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITraceEvent_Impl::SetProcessorIndex(this, core::mem::transmute_copy(&processorindex)).into()
+            }
+        }
+        unsafe extern "system" fn SetThreadId<Identity: windows_core::IUnknownImpl + ITraceEvent_Impl>(this: *mut core::ffi::c_void, threadid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITraceEvent_Impl::SetThreadId(this, core::mem::transmute_copy(&threadid)).into()
+            }
+        }
+        unsafe extern "system" fn SetThreadTimes<Identity: windows_core::IUnknownImpl + ITraceEvent_Impl>(this: *mut core::ffi::c_void, kerneltime: u32, usertime: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITraceEvent_Impl::SetThreadTimes(this, core::mem::transmute_copy(&kerneltime), core::mem::transmute_copy(&usertime)).into()
+            }
+        }
+        unsafe extern "system" fn SetActivityId<Identity: windows_core::IUnknownImpl + ITraceEvent_Impl>(this: *mut core::ffi::c_void, activityid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITraceEvent_Impl::SetActivityId(this, core::mem::transmute_copy(&activityid)).into()
+            }
+        }
+        unsafe extern "system" fn SetTimeStamp<Identity: windows_core::IUnknownImpl + ITraceEvent_Impl>(this: *mut core::ffi::c_void, timestamp: *const i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITraceEvent_Impl::SetTimeStamp(this, core::mem::transmute_copy(&timestamp)).into()
+            }
+        }
+        unsafe extern "system" fn SetProviderId<Identity: windows_core::IUnknownImpl + ITraceEvent_Impl>(this: *mut core::ffi::c_void, providerid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITraceEvent_Impl::SetProviderId(this, core::mem::transmute_copy(&providerid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Clone: Clone::<Identity>,
+            GetUserContext: GetUserContext::<Identity>,
+            GetEventRecord: GetEventRecord::<Identity>,
+            SetPayload: SetPayload::<Identity>,
+            SetEventDescriptor: SetEventDescriptor::<Identity>,
+            SetProcessId: SetProcessId::<Identity>,
+            SetProcessorIndex: SetProcessorIndex::<Identity>,
+            SetThreadId: SetThreadId::<Identity>,
+            SetThreadTimes: SetThreadTimes::<Identity>,
+            SetActivityId: SetActivityId::<Identity>,
+            SetTimeStamp: SetTimeStamp::<Identity>,
+            SetProviderId: SetProviderId::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITraceEvent {}
 windows_core::imp::define_interface!(ITraceEventCallback, ITraceEventCallback_Vtbl, 0x3ed25501_593f_43e9_8f38_3ab46f5a4a52);
 windows_core::imp::interface_hierarchy!(ITraceEventCallback, windows_core::IUnknown);
@@ -2154,6 +2266,37 @@ impl ITraceEventCallback_Vtbl {
         iid == &<ITraceEventCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITraceEventCallback_Impl> windows_core::imp::TearOffVtable<Identity> for ITraceEventCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnBeginProcessTrace<Identity: windows_core::IUnknownImpl + ITraceEventCallback_Impl>(this: *mut core::ffi::c_void, headerevent: *mut core::ffi::c_void, relogger: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITraceEventCallback_Impl::OnBeginProcessTrace(this, core::mem::transmute_copy(&headerevent), core::mem::transmute_copy(&relogger)).into()
+            }
+        }
+        unsafe extern "system" fn OnFinalizeProcessTrace<Identity: windows_core::IUnknownImpl + ITraceEventCallback_Impl>(this: *mut core::ffi::c_void, relogger: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITraceEventCallback_Impl::OnFinalizeProcessTrace(this, core::mem::transmute_copy(&relogger)).into()
+            }
+        }
+        unsafe extern "system" fn OnEvent<Identity: windows_core::IUnknownImpl + ITraceEventCallback_Impl>(this: *mut core::ffi::c_void, event: *mut core::ffi::c_void, relogger: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITraceEventCallback_Impl::OnEvent(this, core::mem::transmute_copy(&event), core::mem::transmute_copy(&relogger)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnBeginProcessTrace: OnBeginProcessTrace::<Identity>,
+            OnFinalizeProcessTrace: OnFinalizeProcessTrace::<Identity>,
+            OnEvent: OnEvent::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITraceEventCallback {}
 windows_core::imp::define_interface!(ITraceRelogger, ITraceRelogger_Vtbl, 0xf754ad43_3bcc_4286_8009_9c5da214e84e);
 windows_core::imp::interface_hierarchy!(ITraceRelogger, windows_core::IUnknown);
@@ -2317,6 +2460,97 @@ impl ITraceRelogger_Vtbl {
         iid == &<ITraceRelogger as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITraceRelogger_Impl> windows_core::imp::TearOffVtable<Identity> for ITraceRelogger_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddLogfileTraceStream<Identity: windows_core::IUnknownImpl + ITraceRelogger_Impl>(this: *mut core::ffi::c_void, logfilename: *mut core::ffi::c_void, usercontext: *const core::ffi::c_void, tracehandle: *mut RELOGSTREAM_HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITraceRelogger_Impl::AddLogfileTraceStream(this, core::mem::transmute(&logfilename), core::mem::transmute_copy(&usercontext)) {
+                    Ok(ok__) => {
+                        tracehandle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddRealtimeTraceStream<Identity: windows_core::IUnknownImpl + ITraceRelogger_Impl>(this: *mut core::ffi::c_void, loggername: *mut core::ffi::c_void, usercontext: *const core::ffi::c_void, tracehandle: *mut RELOGSTREAM_HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITraceRelogger_Impl::AddRealtimeTraceStream(this, core::mem::transmute(&loggername), core::mem::transmute_copy(&usercontext)) {
+                    Ok(ok__) => {
+                        tracehandle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RegisterCallback<Identity: windows_core::IUnknownImpl + ITraceRelogger_Impl>(this: *mut core::ffi::c_void, callback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITraceRelogger_Impl::RegisterCallback(this, core::mem::transmute_copy(&callback)).into()
+            }
+        }
+        unsafe extern "system" fn Inject<Identity: windows_core::IUnknownImpl + ITraceRelogger_Impl>(this: *mut core::ffi::c_void, event: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITraceRelogger_Impl::Inject(this, core::mem::transmute_copy(&event)).into()
+            }
+        }
+        unsafe extern "system" fn CreateEventInstance<Identity: windows_core::IUnknownImpl + ITraceRelogger_Impl>(this: *mut core::ffi::c_void, tracehandle: RELOGSTREAM_HANDLE, flags: u32, event: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITraceRelogger_Impl::CreateEventInstance(this, core::mem::transmute_copy(&tracehandle), core::mem::transmute_copy(&flags)) {
+                    Ok(ok__) => {
+                        event.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ProcessTrace<Identity: windows_core::IUnknownImpl + ITraceRelogger_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITraceRelogger_Impl::ProcessTrace(this).into()
+            }
+        }
+        unsafe extern "system" fn SetOutputFilename<Identity: windows_core::IUnknownImpl + ITraceRelogger_Impl>(this: *mut core::ffi::c_void, logfilename: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITraceRelogger_Impl::SetOutputFilename(this, core::mem::transmute(&logfilename)).into()
+            }
+        }
+        unsafe extern "system" fn SetCompressionMode<Identity: windows_core::IUnknownImpl + ITraceRelogger_Impl>(this: *mut core::ffi::c_void, compressionmode: bool) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITraceRelogger_Impl::SetCompressionMode(this, core::mem::transmute_copy(&compressionmode)).into()
+            }
+        }
+        unsafe extern "system" fn Cancel<Identity: windows_core::IUnknownImpl + ITraceRelogger_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITraceRelogger_Impl::Cancel(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddLogfileTraceStream: AddLogfileTraceStream::<Identity>,
+            AddRealtimeTraceStream: AddRealtimeTraceStream::<Identity>,
+            RegisterCallback: RegisterCallback::<Identity>,
+            Inject: Inject::<Identity>,
+            CreateEventInstance: CreateEventInstance::<Identity>,
+            ProcessTrace: ProcessTrace::<Identity>,
+            SetOutputFilename: SetOutputFilename::<Identity>,
+            SetCompressionMode: SetCompressionMode::<Identity>,
+            Cancel: Cancel::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITraceRelogger {}
 pub const ImageLoadGuid: windows_core::GUID = windows_core::GUID::from_u128(0x2cb15d1d_5fc1_11d2_abe1_00a0c911f518);
 pub const KERNEL_LOGGER_NAME: windows_core::PCWSTR = windows_core::w!("NT Kernel Logger");
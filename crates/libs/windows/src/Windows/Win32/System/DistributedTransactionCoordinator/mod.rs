@@ -169,6 +169,26 @@ impl IDtcLuConfigure_Vtbl {
         iid == &<IDtcLuConfigure as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDtcLuConfigure_Impl> windows_core::imp::TearOffVtable<Identity> for IDtcLuConfigure_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Add<Identity: windows_core::IUnknownImpl + IDtcLuConfigure_Impl>(this: *mut core::ffi::c_void, puclupair: *const u8, cblupair: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuConfigure_Impl::Add(this, core::mem::transmute_copy(&puclupair), core::mem::transmute_copy(&cblupair)).into()
+            }
+        }
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IDtcLuConfigure_Impl>(this: *mut core::ffi::c_void, puclupair: *const u8, cblupair: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuConfigure_Impl::Delete(this, core::mem::transmute_copy(&puclupair), core::mem::transmute_copy(&cblupair)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Add: Add::<Identity>, Delete: Delete::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDtcLuConfigure {}
 windows_core::imp::define_interface!(IDtcLuRecovery, IDtcLuRecovery_Vtbl, 0xac2b8ad2_d6f0_11d0_b386_00a0c9083365);
 windows_core::imp::interface_hierarchy!(IDtcLuRecovery, windows_core::IUnknown);
@@ -186,6 +206,12 @@ impl IDtcLuRecovery_Vtbl {
         iid == &<IDtcLuRecovery as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDtcLuRecovery_Impl> windows_core::imp::TearOffVtable<Identity> for IDtcLuRecovery_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IDtcLuRecovery {}
 windows_core::imp::define_interface!(IDtcLuRecoveryFactory, IDtcLuRecoveryFactory_Vtbl, 0x4131e762_1aea_11d0_944b_00a0c905416e);
 windows_core::imp::interface_hierarchy!(IDtcLuRecoveryFactory, windows_core::IUnknown);
@@ -226,6 +252,26 @@ impl IDtcLuRecoveryFactory_Vtbl {
         iid == &<IDtcLuRecoveryFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IDtcLuRecoveryFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Create<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryFactory_Impl>(this: *mut core::ffi::c_void, puclupair: *const u8, cblupair: u32, pprecovery: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDtcLuRecoveryFactory_Impl::Create(this, core::mem::transmute_copy(&puclupair), core::mem::transmute_copy(&cblupair)) {
+                    Ok(ok__) => {
+                        pprecovery.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Create: Create::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDtcLuRecoveryFactory {}
 windows_core::imp::define_interface!(IDtcLuRecoveryInitiatedByDtc, IDtcLuRecoveryInitiatedByDtc_Vtbl, 0x4131e764_1aea_11d0_944b_00a0c905416e);
 windows_core::imp::interface_hierarchy!(IDtcLuRecoveryInitiatedByDtc, windows_core::IUnknown);
@@ -257,6 +303,20 @@ impl IDtcLuRecoveryInitiatedByDtc_Vtbl {
         iid == &<IDtcLuRecoveryInitiatedByDtc as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByDtc_Impl> windows_core::imp::TearOffVtable<Identity> for IDtcLuRecoveryInitiatedByDtc_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetWork<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByDtc_Impl>(this: *mut core::ffi::c_void, pwork: *mut DTCINITIATEDRECOVERYWORK, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRecoveryInitiatedByDtc_Impl::GetWork(this, core::mem::transmute_copy(&pwork), core::mem::transmute_copy(&ppv)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetWork: GetWork::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDtcLuRecoveryInitiatedByDtc {}
 windows_core::imp::define_interface!(IDtcLuRecoveryInitiatedByDtcStatusWork, IDtcLuRecoveryInitiatedByDtcStatusWork_Vtbl, 0x4131e766_1aea_11d0_944b_00a0c905416e);
 windows_core::imp::interface_hierarchy!(IDtcLuRecoveryInitiatedByDtcStatusWork, windows_core::IUnknown);
@@ -288,6 +348,23 @@ impl IDtcLuRecoveryInitiatedByDtcStatusWork_Vtbl {
         iid == &<IDtcLuRecoveryInitiatedByDtcStatusWork as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByDtcStatusWork_Impl> windows_core::imp::TearOffVtable<Identity> for IDtcLuRecoveryInitiatedByDtcStatusWork_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn HandleCheckLuStatus<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByDtcStatusWork_Impl>(this: *mut core::ffi::c_void, lrecoveryseqnum: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRecoveryInitiatedByDtcStatusWork_Impl::HandleCheckLuStatus(this, core::mem::transmute_copy(&lrecoveryseqnum)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            HandleCheckLuStatus: HandleCheckLuStatus::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDtcLuRecoveryInitiatedByDtcStatusWork {}
 windows_core::imp::define_interface!(IDtcLuRecoveryInitiatedByDtcTransWork, IDtcLuRecoveryInitiatedByDtcTransWork_Vtbl, 0x4131e765_1aea_11d0_944b_00a0c905416e);
 windows_core::imp::interface_hierarchy!(IDtcLuRecoveryInitiatedByDtcTransWork, windows_core::IUnknown);
@@ -466,6 +543,107 @@ impl IDtcLuRecoveryInitiatedByDtcTransWork_Vtbl {
         iid == &<IDtcLuRecoveryInitiatedByDtcTransWork as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByDtcTransWork_Impl> windows_core::imp::TearOffVtable<Identity> for IDtcLuRecoveryInitiatedByDtcTransWork_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetLogNameSizes<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByDtcTransWork_Impl>(this: *mut core::ffi::c_void, pcbourlogname: *mut u32, pcbremotelogname: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRecoveryInitiatedByDtcTransWork_Impl::GetLogNameSizes(this, core::mem::transmute_copy(&pcbourlogname), core::mem::transmute_copy(&pcbremotelogname)).into()
+            }
+        }
+        unsafe extern "system" fn GetOurXln<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByDtcTransWork_Impl>(this: *mut core::ffi::c_void, pxln: *mut DTCLUXLN, pourlogname: *mut u8, premotelogname: *mut u8, pdwprotocol: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRecoveryInitiatedByDtcTransWork_Impl::GetOurXln(this, core::mem::transmute_copy(&pxln), core::mem::transmute_copy(&pourlogname), core::mem::transmute_copy(&premotelogname), core::mem::transmute_copy(&pdwprotocol)).into()
+            }
+        }
+        unsafe extern "system" fn HandleConfirmationFromOurXln<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByDtcTransWork_Impl>(this: *mut core::ffi::c_void, confirmation: DTCLUXLNCONFIRMATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRecoveryInitiatedByDtcTransWork_Impl::HandleConfirmationFromOurXln(this, core::mem::transmute_copy(&confirmation)).into()
+            }
+        }
+        unsafe extern "system" fn HandleTheirXlnResponse<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByDtcTransWork_Impl>(this: *mut core::ffi::c_void, xln: DTCLUXLN, premotelogname: *mut u8, cbremotelogname: u32, dwprotocol: u32, pconfirmation: *mut DTCLUXLNCONFIRMATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRecoveryInitiatedByDtcTransWork_Impl::HandleTheirXlnResponse(this, core::mem::transmute_copy(&xln), core::mem::transmute_copy(&premotelogname), core::mem::transmute_copy(&cbremotelogname), core::mem::transmute_copy(&dwprotocol), core::mem::transmute_copy(&pconfirmation)).into()
+            }
+        }
+        unsafe extern "system" fn HandleErrorFromOurXln<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByDtcTransWork_Impl>(this: *mut core::ffi::c_void, error: DTCLUXLNERROR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRecoveryInitiatedByDtcTransWork_Impl::HandleErrorFromOurXln(this, core::mem::transmute_copy(&error)).into()
+            }
+        }
+        unsafe extern "system" fn CheckForCompareStates<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByDtcTransWork_Impl>(this: *mut core::ffi::c_void, fcomparestates: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRecoveryInitiatedByDtcTransWork_Impl::CheckForCompareStates(this, core::mem::transmute_copy(&fcomparestates)).into()
+            }
+        }
+        unsafe extern "system" fn GetOurTransIdSize<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByDtcTransWork_Impl>(this: *mut core::ffi::c_void, pcbourtransid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRecoveryInitiatedByDtcTransWork_Impl::GetOurTransIdSize(this, core::mem::transmute_copy(&pcbourtransid)).into()
+            }
+        }
+        unsafe extern "system" fn GetOurCompareStates<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByDtcTransWork_Impl>(this: *mut core::ffi::c_void, pourtransid: *mut u8, pcomparestate: *mut DTCLUCOMPARESTATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRecoveryInitiatedByDtcTransWork_Impl::GetOurCompareStates(this, core::mem::transmute_copy(&pourtransid), core::mem::transmute_copy(&pcomparestate)).into()
+            }
+        }
+        unsafe extern "system" fn HandleTheirCompareStatesResponse<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByDtcTransWork_Impl>(this: *mut core::ffi::c_void, comparestate: DTCLUCOMPARESTATE, pconfirmation: *mut DTCLUCOMPARESTATESCONFIRMATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRecoveryInitiatedByDtcTransWork_Impl::HandleTheirCompareStatesResponse(this, core::mem::transmute_copy(&comparestate), core::mem::transmute_copy(&pconfirmation)).into()
+            }
+        }
+        unsafe extern "system" fn HandleErrorFromOurCompareStates<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByDtcTransWork_Impl>(this: *mut core::ffi::c_void, error: DTCLUCOMPARESTATESERROR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRecoveryInitiatedByDtcTransWork_Impl::HandleErrorFromOurCompareStates(this, core::mem::transmute_copy(&error)).into()
+            }
+        }
+        unsafe extern "system" fn ConversationLost<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByDtcTransWork_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRecoveryInitiatedByDtcTransWork_Impl::ConversationLost(this).into()
+            }
+        }
+        unsafe extern "system" fn GetRecoverySeqNum<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByDtcTransWork_Impl>(this: *mut core::ffi::c_void, plrecoveryseqnum: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRecoveryInitiatedByDtcTransWork_Impl::GetRecoverySeqNum(this, core::mem::transmute_copy(&plrecoveryseqnum)).into()
+            }
+        }
+        unsafe extern "system" fn ObsoleteRecoverySeqNum<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByDtcTransWork_Impl>(this: *mut core::ffi::c_void, lnewrecoveryseqnum: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRecoveryInitiatedByDtcTransWork_Impl::ObsoleteRecoverySeqNum(this, core::mem::transmute_copy(&lnewrecoveryseqnum)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetLogNameSizes: GetLogNameSizes::<Identity>,
+            GetOurXln: GetOurXln::<Identity>,
+            HandleConfirmationFromOurXln: HandleConfirmationFromOurXln::<Identity>,
+            HandleTheirXlnResponse: HandleTheirXlnResponse::<Identity>,
+            HandleErrorFromOurXln: HandleErrorFromOurXln::<Identity>,
+            CheckForCompareStates: CheckForCompareStates::<Identity>,
+            GetOurTransIdSize: GetOurTransIdSize::<Identity>,
+            GetOurCompareStates: GetOurCompareStates::<Identity>,
+            HandleTheirCompareStatesResponse: HandleTheirCompareStatesResponse::<Identity>,
+            HandleErrorFromOurCompareStates: HandleErrorFromOurCompareStates::<Identity>,
+            ConversationLost: ConversationLost::<Identity>,
+            GetRecoverySeqNum: GetRecoverySeqNum::<Identity>,
+            ObsoleteRecoverySeqNum: ObsoleteRecoverySeqNum::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDtcLuRecoveryInitiatedByDtcTransWork {}
 windows_core::imp::define_interface!(IDtcLuRecoveryInitiatedByLu, IDtcLuRecoveryInitiatedByLu_Vtbl, 0x4131e768_1aea_11d0_944b_00a0c905416e);
 windows_core::imp::interface_hierarchy!(IDtcLuRecoveryInitiatedByLu, windows_core::IUnknown);
@@ -506,6 +684,29 @@ impl IDtcLuRecoveryInitiatedByLu_Vtbl {
         iid == &<IDtcLuRecoveryInitiatedByLu as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByLu_Impl> windows_core::imp::TearOffVtable<Identity> for IDtcLuRecoveryInitiatedByLu_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetObjectToHandleWorkFromLu<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByLu_Impl>(this: *mut core::ffi::c_void, ppwork: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDtcLuRecoveryInitiatedByLu_Impl::GetObjectToHandleWorkFromLu(this) {
+                    Ok(ok__) => {
+                        ppwork.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetObjectToHandleWorkFromLu: GetObjectToHandleWorkFromLu::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDtcLuRecoveryInitiatedByLu {}
 windows_core::imp::define_interface!(IDtcLuRecoveryInitiatedByLuWork, IDtcLuRecoveryInitiatedByLuWork_Vtbl, 0xac2b8ad1_d6f0_11d0_b386_00a0c9083365);
 windows_core::imp::interface_hierarchy!(IDtcLuRecoveryInitiatedByLuWork, windows_core::IUnknown);
@@ -624,6 +825,72 @@ impl IDtcLuRecoveryInitiatedByLuWork_Vtbl {
         iid == &<IDtcLuRecoveryInitiatedByLuWork as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByLuWork_Impl> windows_core::imp::TearOffVtable<Identity> for IDtcLuRecoveryInitiatedByLuWork_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn HandleTheirXln<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByLuWork_Impl>(this: *mut core::ffi::c_void, lrecoveryseqnum: i32, xln: DTCLUXLN, premotelogname: *mut u8, cbremotelogname: u32, pourlogname: *mut u8, cbourlogname: u32, dwprotocol: u32, presponse: *mut DTCLUXLNRESPONSE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRecoveryInitiatedByLuWork_Impl::HandleTheirXln(this, core::mem::transmute_copy(&lrecoveryseqnum), core::mem::transmute_copy(&xln), core::mem::transmute_copy(&premotelogname), core::mem::transmute_copy(&cbremotelogname), core::mem::transmute_copy(&pourlogname), core::mem::transmute_copy(&cbourlogname), core::mem::transmute_copy(&dwprotocol), core::mem::transmute_copy(&presponse)).into()
+            }
+        }
+        unsafe extern "system" fn GetOurLogNameSize<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByLuWork_Impl>(this: *mut core::ffi::c_void, pcbourlogname: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRecoveryInitiatedByLuWork_Impl::GetOurLogNameSize(this, core::mem::transmute_copy(&pcbourlogname)).into()
+            }
+        }
+        unsafe extern "system" fn GetOurXln<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByLuWork_Impl>(this: *mut core::ffi::c_void, pxln: *mut DTCLUXLN, pourlogname: *mut u8, pdwprotocol: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRecoveryInitiatedByLuWork_Impl::GetOurXln(this, core::mem::transmute_copy(&pxln), core::mem::transmute_copy(&pourlogname), core::mem::transmute_copy(&pdwprotocol)).into()
+            }
+        }
+        unsafe extern "system" fn HandleConfirmationOfOurXln<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByLuWork_Impl>(this: *mut core::ffi::c_void, confirmation: DTCLUXLNCONFIRMATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRecoveryInitiatedByLuWork_Impl::HandleConfirmationOfOurXln(this, core::mem::transmute_copy(&confirmation)).into()
+            }
+        }
+        unsafe extern "system" fn HandleTheirCompareStates<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByLuWork_Impl>(this: *mut core::ffi::c_void, premotetransid: *mut u8, cbremotetransid: u32, comparestate: DTCLUCOMPARESTATE, presponse: *mut DTCLUCOMPARESTATESRESPONSE, pcomparestate: *mut DTCLUCOMPARESTATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRecoveryInitiatedByLuWork_Impl::HandleTheirCompareStates(this, core::mem::transmute_copy(&premotetransid), core::mem::transmute_copy(&cbremotetransid), core::mem::transmute_copy(&comparestate), core::mem::transmute_copy(&presponse), core::mem::transmute_copy(&pcomparestate)).into()
+            }
+        }
+        unsafe extern "system" fn HandleConfirmationOfOurCompareStates<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByLuWork_Impl>(this: *mut core::ffi::c_void, confirmation: DTCLUCOMPARESTATESCONFIRMATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRecoveryInitiatedByLuWork_Impl::HandleConfirmationOfOurCompareStates(this, core::mem::transmute_copy(&confirmation)).into()
+            }
+        }
+        unsafe extern "system" fn HandleErrorFromOurCompareStates<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByLuWork_Impl>(this: *mut core::ffi::c_void, error: DTCLUCOMPARESTATESERROR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRecoveryInitiatedByLuWork_Impl::HandleErrorFromOurCompareStates(this, core::mem::transmute_copy(&error)).into()
+            }
+        }
+        unsafe extern "system" fn ConversationLost<Identity: windows_core::IUnknownImpl + IDtcLuRecoveryInitiatedByLuWork_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRecoveryInitiatedByLuWork_Impl::ConversationLost(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            HandleTheirXln: HandleTheirXln::<Identity>,
+            GetOurLogNameSize: GetOurLogNameSize::<Identity>,
+            GetOurXln: GetOurXln::<Identity>,
+            HandleConfirmationOfOurXln: HandleConfirmationOfOurXln::<Identity>,
+            HandleTheirCompareStates: HandleTheirCompareStates::<Identity>,
+            HandleConfirmationOfOurCompareStates: HandleConfirmationOfOurCompareStates::<Identity>,
+            HandleErrorFromOurCompareStates: HandleErrorFromOurCompareStates::<Identity>,
+            ConversationLost: ConversationLost::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDtcLuRecoveryInitiatedByLuWork {}
 windows_core::imp::define_interface!(IDtcLuRmEnlistment, IDtcLuRmEnlistment_Vtbl, 0x4131e769_1aea_11d0_944b_00a0c905416e);
 windows_core::imp::interface_hierarchy!(IDtcLuRmEnlistment, windows_core::IUnknown);
@@ -718,6 +985,58 @@ impl IDtcLuRmEnlistment_Vtbl {
         iid == &<IDtcLuRmEnlistment as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDtcLuRmEnlistment_Impl> windows_core::imp::TearOffVtable<Identity> for IDtcLuRmEnlistment_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Unplug<Identity: windows_core::IUnknownImpl + IDtcLuRmEnlistment_Impl>(this: *mut core::ffi::c_void, fconversationlost: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRmEnlistment_Impl::Unplug(this, core::mem::transmute_copy(&fconversationlost)).into()
+            }
+        }
+        unsafe extern "system" fn BackedOut<Identity: windows_core::IUnknownImpl + IDtcLuRmEnlistment_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRmEnlistment_Impl::BackedOut(this).into()
+            }
+        }
+        unsafe extern "system" fn BackOut<Identity: windows_core::IUnknownImpl + IDtcLuRmEnlistment_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRmEnlistment_Impl::BackOut(this).into()
+            }
+        }
+        unsafe extern "system" fn Committed<Identity: windows_core::IUnknownImpl + IDtcLuRmEnlistment_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRmEnlistment_Impl::Committed(this).into()
+            }
+        }
+        unsafe extern "system" fn Forget<Identity: windows_core::IUnknownImpl + IDtcLuRmEnlistment_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRmEnlistment_Impl::Forget(this).into()
+            }
+        }
+        unsafe extern "system" fn RequestCommit<Identity: windows_core::IUnknownImpl + IDtcLuRmEnlistment_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRmEnlistment_Impl::RequestCommit(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Unplug: Unplug::<Identity>,
+            BackedOut: BackedOut::<Identity>,
+            BackOut: BackOut::<Identity>,
+            Committed: Committed::<Identity>,
+            Forget: Forget::<Identity>,
+            RequestCommit: RequestCommit::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDtcLuRmEnlistment {}
 windows_core::imp::define_interface!(IDtcLuRmEnlistmentFactory, IDtcLuRmEnlistmentFactory_Vtbl, 0x4131e771_1aea_11d0_944b_00a0c905416e);
 windows_core::imp::interface_hierarchy!(IDtcLuRmEnlistmentFactory, windows_core::IUnknown);
@@ -753,6 +1072,20 @@ impl IDtcLuRmEnlistmentFactory_Vtbl {
         iid == &<IDtcLuRmEnlistmentFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDtcLuRmEnlistmentFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IDtcLuRmEnlistmentFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Create<Identity: windows_core::IUnknownImpl + IDtcLuRmEnlistmentFactory_Impl>(this: *mut core::ffi::c_void, puclupair: *mut u8, cblupair: u32, pitransaction: *mut core::ffi::c_void, ptransid: *mut u8, cbtransid: u32, prmenlistmentsink: *mut core::ffi::c_void, pprmenlistment: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRmEnlistmentFactory_Impl::Create(this, core::mem::transmute_copy(&puclupair), core::mem::transmute_copy(&cblupair), core::mem::transmute_copy(&pitransaction), core::mem::transmute_copy(&ptransid), core::mem::transmute_copy(&cbtransid), core::mem::transmute_copy(&prmenlistmentsink), core::mem::transmute_copy(&pprmenlistment)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Create: Create::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDtcLuRmEnlistmentFactory {}
 windows_core::imp::define_interface!(IDtcLuRmEnlistmentSink, IDtcLuRmEnlistmentSink_Vtbl, 0x4131e770_1aea_11d0_944b_00a0c905416e);
 windows_core::imp::interface_hierarchy!(IDtcLuRmEnlistmentSink, windows_core::IUnknown);
@@ -883,6 +1216,79 @@ impl IDtcLuRmEnlistmentSink_Vtbl {
         iid == &<IDtcLuRmEnlistmentSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDtcLuRmEnlistmentSink_Impl> windows_core::imp::TearOffVtable<Identity> for IDtcLuRmEnlistmentSink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AckUnplug<Identity: windows_core::IUnknownImpl + IDtcLuRmEnlistmentSink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRmEnlistmentSink_Impl::AckUnplug(this).into()
+            }
+        }
+        unsafe extern "system" fn TmDown<Identity: windows_core::IUnknownImpl + IDtcLuRmEnlistmentSink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRmEnlistmentSink_Impl::TmDown(this).into()
+            }
+        }
+        unsafe extern "system" fn SessionLost<Identity: windows_core::IUnknownImpl + IDtcLuRmEnlistmentSink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRmEnlistmentSink_Impl::SessionLost(this).into()
+            }
+        }
+        unsafe extern "system" fn BackedOut<Identity: windows_core::IUnknownImpl + IDtcLuRmEnlistmentSink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRmEnlistmentSink_Impl::BackedOut(this).into()
+            }
+        }
+        unsafe extern "system" fn BackOut<Identity: windows_core::IUnknownImpl + IDtcLuRmEnlistmentSink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRmEnlistmentSink_Impl::BackOut(this).into()
+            }
+        }
+        unsafe extern "system" fn Committed<Identity: windows_core::IUnknownImpl + IDtcLuRmEnlistmentSink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRmEnlistmentSink_Impl::Committed(this).into()
+            }
+        }
+        unsafe extern "system" fn Forget<Identity: windows_core::IUnknownImpl + IDtcLuRmEnlistmentSink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRmEnlistmentSink_Impl::Forget(this).into()
+            }
+        }
+        unsafe extern "system" fn Prepare<Identity: windows_core::IUnknownImpl + IDtcLuRmEnlistmentSink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRmEnlistmentSink_Impl::Prepare(this).into()
+            }
+        }
+        unsafe extern "system" fn RequestCommit<Identity: windows_core::IUnknownImpl + IDtcLuRmEnlistmentSink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuRmEnlistmentSink_Impl::RequestCommit(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AckUnplug: AckUnplug::<Identity>,
+            TmDown: TmDown::<Identity>,
+            SessionLost: SessionLost::<Identity>,
+            BackedOut: BackedOut::<Identity>,
+            BackOut: BackOut::<Identity>,
+            Committed: Committed::<Identity>,
+            Forget: Forget::<Identity>,
+            Prepare: Prepare::<Identity>,
+            RequestCommit: RequestCommit::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDtcLuRmEnlistmentSink {}
 windows_core::imp::define_interface!(IDtcLuSubordinateDtc, IDtcLuSubordinateDtc_Vtbl, 0x4131e773_1aea_11d0_944b_00a0c905416e);
 windows_core::imp::interface_hierarchy!(IDtcLuSubordinateDtc, windows_core::IUnknown);
@@ -989,34 +1395,93 @@ impl IDtcLuSubordinateDtc_Vtbl {
         iid == &<IDtcLuSubordinateDtc as windows_core::Interface>::IID
     }
 }
-impl windows_core::RuntimeName for IDtcLuSubordinateDtc {}
-windows_core::imp::define_interface!(IDtcLuSubordinateDtcFactory, IDtcLuSubordinateDtcFactory_Vtbl, 0x4131e775_1aea_11d0_944b_00a0c905416e);
-windows_core::imp::interface_hierarchy!(IDtcLuSubordinateDtcFactory, windows_core::IUnknown);
-impl IDtcLuSubordinateDtcFactory {
-    pub unsafe fn Create<P2, P5, P9>(&self, puclupair: *mut u8, cblupair: u32, punktransactionouter: P2, isolevel: i32, isoflags: u32, poptions: P5, pptransaction: *mut Option<ITransaction>, ptransid: *mut u8, cbtransid: u32, psubordinatedtcsink: P9, ppsubordinatedtc: *mut Option<IDtcLuSubordinateDtc>) -> windows_core::Result<()>
-    where
-        P2: windows_core::Param<windows_core::IUnknown>,
-        P5: windows_core::Param<ITransactionOptions>,
-        P9: windows_core::Param<IDtcLuSubordinateDtcSink>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).Create)(windows_core::Interface::as_raw(self), puclupair as _, cblupair, punktransactionouter.param().abi(), isolevel, isoflags, poptions.param().abi(), core::mem::transmute(pptransaction), ptransid as _, cbtransid, psubordinatedtcsink.param().abi(), core::mem::transmute(ppsubordinatedtc)).ok() }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IDtcLuSubordinateDtcFactory_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    pub Create: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u8, u32, *mut core::ffi::c_void, i32, u32, *mut core::ffi::c_void, *mut *mut core::ffi::c_void, *mut u8, u32, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-pub trait IDtcLuSubordinateDtcFactory_Impl: windows_core::IUnknownImpl {
-    fn Create(&self, puclupair: *mut u8, cblupair: u32, punktransactionouter: windows_core::Ref<windows_core::IUnknown>, isolevel: i32, isoflags: u32, poptions: windows_core::Ref<ITransactionOptions>, pptransaction: windows_core::OutRef<ITransaction>, ptransid: *mut u8, cbtransid: u32, psubordinatedtcsink: windows_core::Ref<IDtcLuSubordinateDtcSink>, ppsubordinatedtc: windows_core::OutRef<IDtcLuSubordinateDtc>) -> windows_core::Result<()>;
-}
-impl IDtcLuSubordinateDtcFactory_Vtbl {
-    pub const fn new<Identity: IDtcLuSubordinateDtcFactory_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn Create<Identity: IDtcLuSubordinateDtcFactory_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, puclupair: *mut u8, cblupair: u32, punktransactionouter: *mut core::ffi::c_void, isolevel: i32, isoflags: u32, poptions: *mut core::ffi::c_void, pptransaction: *mut *mut core::ffi::c_void, ptransid: *mut u8, cbtransid: u32, psubordinatedtcsink: *mut core::ffi::c_void, ppsubordinatedtc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl<Identity: windows_core::IUnknownImpl + IDtcLuSubordinateDtc_Impl> windows_core::imp::TearOffVtable<Identity> for IDtcLuSubordinateDtc_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Unplug<Identity: windows_core::IUnknownImpl + IDtcLuSubordinateDtc_Impl>(this: *mut core::ffi::c_void, fconversationlost: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IDtcLuSubordinateDtcFactory_Impl::Create(this, core::mem::transmute_copy(&puclupair), core::mem::transmute_copy(&cblupair), core::mem::transmute_copy(&punktransactionouter), core::mem::transmute_copy(&isolevel), core::mem::transmute_copy(&isoflags), core::mem::transmute_copy(&poptions), core::mem::transmute_copy(&pptransaction), core::mem::transmute_copy(&ptransid), core::mem::transmute_copy(&cbtransid), core::mem::transmute_copy(&psubordinatedtcsink), core::mem::transmute_copy(&ppsubordinatedtc)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuSubordinateDtc_Impl::Unplug(this, core::mem::transmute_copy(&fconversationlost)).into()
+            }
+        }
+        unsafe extern "system" fn BackedOut<Identity: windows_core::IUnknownImpl + IDtcLuSubordinateDtc_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuSubordinateDtc_Impl::BackedOut(this).into()
+            }
+        }
+        unsafe extern "system" fn BackOut<Identity: windows_core::IUnknownImpl + IDtcLuSubordinateDtc_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuSubordinateDtc_Impl::BackOut(this).into()
+            }
+        }
+        unsafe extern "system" fn Committed<Identity: windows_core::IUnknownImpl + IDtcLuSubordinateDtc_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuSubordinateDtc_Impl::Committed(this).into()
+            }
+        }
+        unsafe extern "system" fn Forget<Identity: windows_core::IUnknownImpl + IDtcLuSubordinateDtc_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuSubordinateDtc_Impl::Forget(this).into()
+            }
+        }
+        unsafe extern "system" fn Prepare<Identity: windows_core::IUnknownImpl + IDtcLuSubordinateDtc_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuSubordinateDtc_Impl::Prepare(this).into()
+            }
+        }
+        unsafe extern "system" fn RequestCommit<Identity: windows_core::IUnknownImpl + IDtcLuSubordinateDtc_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuSubordinateDtc_Impl::RequestCommit(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Unplug: Unplug::<Identity>,
+            BackedOut: BackedOut::<Identity>,
+            BackOut: BackOut::<Identity>,
+            Committed: Committed::<Identity>,
+            Forget: Forget::<Identity>,
+            Prepare: Prepare::<Identity>,
+            RequestCommit: RequestCommit::<Identity>,
+        }
+    };
+}
+impl windows_core::RuntimeName for IDtcLuSubordinateDtc {}
+windows_core::imp::define_interface!(IDtcLuSubordinateDtcFactory, IDtcLuSubordinateDtcFactory_Vtbl, 0x4131e775_1aea_11d0_944b_00a0c905416e);
+windows_core::imp::interface_hierarchy!(IDtcLuSubordinateDtcFactory, windows_core::IUnknown);
+impl IDtcLuSubordinateDtcFactory {
+    pub unsafe fn Create<P2, P5, P9>(&self, puclupair: *mut u8, cblupair: u32, punktransactionouter: P2, isolevel: i32, isoflags: u32, poptions: P5, pptransaction: *mut Option<ITransaction>, ptransid: *mut u8, cbtransid: u32, psubordinatedtcsink: P9, ppsubordinatedtc: *mut Option<IDtcLuSubordinateDtc>) -> windows_core::Result<()>
+    where
+        P2: windows_core::Param<windows_core::IUnknown>,
+        P5: windows_core::Param<ITransactionOptions>,
+        P9: windows_core::Param<IDtcLuSubordinateDtcSink>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).Create)(windows_core::Interface::as_raw(self), puclupair as _, cblupair, punktransactionouter.param().abi(), isolevel, isoflags, poptions.param().abi(), core::mem::transmute(pptransaction), ptransid as _, cbtransid, psubordinatedtcsink.param().abi(), core::mem::transmute(ppsubordinatedtc)).ok() }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IDtcLuSubordinateDtcFactory_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub Create: unsafe extern "system" fn(*mut core::ffi::c_void, *mut u8, u32, *mut core::ffi::c_void, i32, u32, *mut core::ffi::c_void, *mut *mut core::ffi::c_void, *mut u8, u32, *mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+pub trait IDtcLuSubordinateDtcFactory_Impl: windows_core::IUnknownImpl {
+    fn Create(&self, puclupair: *mut u8, cblupair: u32, punktransactionouter: windows_core::Ref<windows_core::IUnknown>, isolevel: i32, isoflags: u32, poptions: windows_core::Ref<ITransactionOptions>, pptransaction: windows_core::OutRef<ITransaction>, ptransid: *mut u8, cbtransid: u32, psubordinatedtcsink: windows_core::Ref<IDtcLuSubordinateDtcSink>, ppsubordinatedtc: windows_core::OutRef<IDtcLuSubordinateDtc>) -> windows_core::Result<()>;
+}
+impl IDtcLuSubordinateDtcFactory_Vtbl {
+    pub const fn new<Identity: IDtcLuSubordinateDtcFactory_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn Create<Identity: IDtcLuSubordinateDtcFactory_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, puclupair: *mut u8, cblupair: u32, punktransactionouter: *mut core::ffi::c_void, isolevel: i32, isoflags: u32, poptions: *mut core::ffi::c_void, pptransaction: *mut *mut core::ffi::c_void, ptransid: *mut u8, cbtransid: u32, psubordinatedtcsink: *mut core::ffi::c_void, ppsubordinatedtc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IDtcLuSubordinateDtcFactory_Impl::Create(this, core::mem::transmute_copy(&puclupair), core::mem::transmute_copy(&cblupair), core::mem::transmute_copy(&punktransactionouter), core::mem::transmute_copy(&isolevel), core::mem::transmute_copy(&isoflags), core::mem::transmute_copy(&poptions), core::mem::transmute_copy(&pptransaction), core::mem::transmute_copy(&ptransid), core::mem::transmute_copy(&cbtransid), core::mem::transmute_copy(&psubordinatedtcsink), core::mem::transmute_copy(&ppsubordinatedtc)).into()
             }
         }
         Self { base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(), Create: Create::<Identity, OFFSET> }
@@ -1025,6 +1490,20 @@ impl IDtcLuSubordinateDtcFactory_Vtbl {
         iid == &<IDtcLuSubordinateDtcFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDtcLuSubordinateDtcFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IDtcLuSubordinateDtcFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Create<Identity: windows_core::IUnknownImpl + IDtcLuSubordinateDtcFactory_Impl>(this: *mut core::ffi::c_void, puclupair: *mut u8, cblupair: u32, punktransactionouter: *mut core::ffi::c_void, isolevel: i32, isoflags: u32, poptions: *mut core::ffi::c_void, pptransaction: *mut *mut core::ffi::c_void, ptransid: *mut u8, cbtransid: u32, psubordinatedtcsink: *mut core::ffi::c_void, ppsubordinatedtc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuSubordinateDtcFactory_Impl::Create(this, core::mem::transmute_copy(&puclupair), core::mem::transmute_copy(&cblupair), core::mem::transmute_copy(&punktransactionouter), core::mem::transmute_copy(&isolevel), core::mem::transmute_copy(&isoflags), core::mem::transmute_copy(&poptions), core::mem::transmute_copy(&pptransaction), core::mem::transmute_copy(&ptransid), core::mem::transmute_copy(&cbtransid), core::mem::transmute_copy(&psubordinatedtcsink), core::mem::transmute_copy(&ppsubordinatedtc)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Create: Create::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDtcLuSubordinateDtcFactory {}
 windows_core::imp::define_interface!(IDtcLuSubordinateDtcSink, IDtcLuSubordinateDtcSink_Vtbl, 0x4131e774_1aea_11d0_944b_00a0c905416e);
 windows_core::imp::interface_hierarchy!(IDtcLuSubordinateDtcSink, windows_core::IUnknown);
@@ -1143,6 +1622,72 @@ impl IDtcLuSubordinateDtcSink_Vtbl {
         iid == &<IDtcLuSubordinateDtcSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDtcLuSubordinateDtcSink_Impl> windows_core::imp::TearOffVtable<Identity> for IDtcLuSubordinateDtcSink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AckUnplug<Identity: windows_core::IUnknownImpl + IDtcLuSubordinateDtcSink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuSubordinateDtcSink_Impl::AckUnplug(this).into()
+            }
+        }
+        unsafe extern "system" fn TmDown<Identity: windows_core::IUnknownImpl + IDtcLuSubordinateDtcSink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuSubordinateDtcSink_Impl::TmDown(this).into()
+            }
+        }
+        unsafe extern "system" fn SessionLost<Identity: windows_core::IUnknownImpl + IDtcLuSubordinateDtcSink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuSubordinateDtcSink_Impl::SessionLost(this).into()
+            }
+        }
+        unsafe extern "system" fn BackedOut<Identity: windows_core::IUnknownImpl + IDtcLuSubordinateDtcSink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuSubordinateDtcSink_Impl::BackedOut(this).into()
+            }
+        }
+        unsafe extern "system" fn BackOut<Identity: windows_core::IUnknownImpl + IDtcLuSubordinateDtcSink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuSubordinateDtcSink_Impl::BackOut(this).into()
+            }
+        }
+        unsafe extern "system" fn Committed<Identity: windows_core::IUnknownImpl + IDtcLuSubordinateDtcSink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuSubordinateDtcSink_Impl::Committed(this).into()
+            }
+        }
+        unsafe extern "system" fn Forget<Identity: windows_core::IUnknownImpl + IDtcLuSubordinateDtcSink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuSubordinateDtcSink_Impl::Forget(this).into()
+            }
+        }
+        unsafe extern "system" fn RequestCommit<Identity: windows_core::IUnknownImpl + IDtcLuSubordinateDtcSink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcLuSubordinateDtcSink_Impl::RequestCommit(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AckUnplug: AckUnplug::<Identity>,
+            TmDown: TmDown::<Identity>,
+            SessionLost: SessionLost::<Identity>,
+            BackedOut: BackedOut::<Identity>,
+            BackOut: BackOut::<Identity>,
+            Committed: Committed::<Identity>,
+            Forget: Forget::<Identity>,
+            RequestCommit: RequestCommit::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDtcLuSubordinateDtcSink {}
 windows_core::imp::define_interface!(IDtcNetworkAccessConfig, IDtcNetworkAccessConfig_Vtbl, 0x9797c15d_a428_4291_87b6_0995031a678d);
 windows_core::imp::interface_hierarchy!(IDtcNetworkAccessConfig, windows_core::IUnknown);
@@ -1375,6 +1920,143 @@ impl IDtcNetworkAccessConfig_Vtbl {
         iid == &<IDtcNetworkAccessConfig as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig_Impl> windows_core::imp::TearOffVtable<Identity> for IDtcNetworkAccessConfig_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAnyNetworkAccess<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig_Impl>(this: *mut core::ffi::c_void, pbanynetworkaccess: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDtcNetworkAccessConfig_Impl::GetAnyNetworkAccess(this) {
+                    Ok(ok__) => {
+                        pbanynetworkaccess.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAnyNetworkAccess<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig_Impl>(this: *mut core::ffi::c_void, banynetworkaccess: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcNetworkAccessConfig_Impl::SetAnyNetworkAccess(this, core::mem::transmute_copy(&banynetworkaccess)).into()
+            }
+        }
+        unsafe extern "system" fn GetNetworkAdministrationAccess<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig_Impl>(this: *mut core::ffi::c_void, pbnetworkadministrationaccess: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDtcNetworkAccessConfig_Impl::GetNetworkAdministrationAccess(this) {
+                    Ok(ok__) => {
+                        pbnetworkadministrationaccess.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNetworkAdministrationAccess<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig_Impl>(this: *mut core::ffi::c_void, bnetworkadministrationaccess: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcNetworkAccessConfig_Impl::SetNetworkAdministrationAccess(this, core::mem::transmute_copy(&bnetworkadministrationaccess)).into()
+            }
+        }
+        unsafe extern "system" fn GetNetworkTransactionAccess<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig_Impl>(this: *mut core::ffi::c_void, pbnetworktransactionaccess: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDtcNetworkAccessConfig_Impl::GetNetworkTransactionAccess(this) {
+                    Ok(ok__) => {
+                        pbnetworktransactionaccess.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNetworkTransactionAccess<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig_Impl>(this: *mut core::ffi::c_void, bnetworktransactionaccess: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcNetworkAccessConfig_Impl::SetNetworkTransactionAccess(this, core::mem::transmute_copy(&bnetworktransactionaccess)).into()
+            }
+        }
+        unsafe extern "system" fn GetNetworkClientAccess<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig_Impl>(this: *mut core::ffi::c_void, pbnetworkclientaccess: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDtcNetworkAccessConfig_Impl::GetNetworkClientAccess(this) {
+                    Ok(ok__) => {
+                        pbnetworkclientaccess.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNetworkClientAccess<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig_Impl>(this: *mut core::ffi::c_void, bnetworkclientaccess: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcNetworkAccessConfig_Impl::SetNetworkClientAccess(this, core::mem::transmute_copy(&bnetworkclientaccess)).into()
+            }
+        }
+        unsafe extern "system" fn GetNetworkTIPAccess<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig_Impl>(this: *mut core::ffi::c_void, pbnetworktipaccess: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDtcNetworkAccessConfig_Impl::GetNetworkTIPAccess(this) {
+                    Ok(ok__) => {
+                        pbnetworktipaccess.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNetworkTIPAccess<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig_Impl>(this: *mut core::ffi::c_void, bnetworktipaccess: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcNetworkAccessConfig_Impl::SetNetworkTIPAccess(this, core::mem::transmute_copy(&bnetworktipaccess)).into()
+            }
+        }
+        unsafe extern "system" fn GetXAAccess<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig_Impl>(this: *mut core::ffi::c_void, pbxaaccess: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDtcNetworkAccessConfig_Impl::GetXAAccess(this) {
+                    Ok(ok__) => {
+                        pbxaaccess.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetXAAccess<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig_Impl>(this: *mut core::ffi::c_void, bxaaccess: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcNetworkAccessConfig_Impl::SetXAAccess(this, core::mem::transmute_copy(&bxaaccess)).into()
+            }
+        }
+        unsafe extern "system" fn RestartDtcService<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcNetworkAccessConfig_Impl::RestartDtcService(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAnyNetworkAccess: GetAnyNetworkAccess::<Identity>,
+            SetAnyNetworkAccess: SetAnyNetworkAccess::<Identity>,
+            GetNetworkAdministrationAccess: GetNetworkAdministrationAccess::<Identity>,
+            SetNetworkAdministrationAccess: SetNetworkAdministrationAccess::<Identity>,
+            GetNetworkTransactionAccess: GetNetworkTransactionAccess::<Identity>,
+            SetNetworkTransactionAccess: SetNetworkTransactionAccess::<Identity>,
+            GetNetworkClientAccess: GetNetworkClientAccess::<Identity>,
+            SetNetworkClientAccess: SetNetworkClientAccess::<Identity>,
+            GetNetworkTIPAccess: GetNetworkTIPAccess::<Identity>,
+            SetNetworkTIPAccess: SetNetworkTIPAccess::<Identity>,
+            GetXAAccess: GetXAAccess::<Identity>,
+            SetXAAccess: SetXAAccess::<Identity>,
+            RestartDtcService: RestartDtcService::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDtcNetworkAccessConfig {}
 windows_core::imp::define_interface!(IDtcNetworkAccessConfig2, IDtcNetworkAccessConfig2_Vtbl, 0xa7aa013b_eb7d_4f42_b41c_b2dec09ae034);
 impl core::ops::Deref for IDtcNetworkAccessConfig2 {
@@ -1502,6 +2184,76 @@ impl IDtcNetworkAccessConfig2_Vtbl {
         iid == &<IDtcNetworkAccessConfig2 as windows_core::Interface>::IID || iid == &<IDtcNetworkAccessConfig as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig2_Impl> windows_core::imp::TearOffVtable<Identity> for IDtcNetworkAccessConfig2_Vtbl
+where
+    IDtcNetworkAccessConfig_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetNetworkInboundAccess<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig2_Impl>(this: *mut core::ffi::c_void, pbinbound: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDtcNetworkAccessConfig2_Impl::GetNetworkInboundAccess(this) {
+                    Ok(ok__) => {
+                        pbinbound.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetNetworkOutboundAccess<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig2_Impl>(this: *mut core::ffi::c_void, pboutbound: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDtcNetworkAccessConfig2_Impl::GetNetworkOutboundAccess(this) {
+                    Ok(ok__) => {
+                        pboutbound.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNetworkInboundAccess<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig2_Impl>(this: *mut core::ffi::c_void, binbound: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcNetworkAccessConfig2_Impl::SetNetworkInboundAccess(this, core::mem::transmute_copy(&binbound)).into()
+            }
+        }
+        unsafe extern "system" fn SetNetworkOutboundAccess<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig2_Impl>(this: *mut core::ffi::c_void, boutbound: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcNetworkAccessConfig2_Impl::SetNetworkOutboundAccess(this, core::mem::transmute_copy(&boutbound)).into()
+            }
+        }
+        unsafe extern "system" fn GetAuthenticationLevel<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig2_Impl>(this: *mut core::ffi::c_void, pauthlevel: *mut AUTHENTICATION_LEVEL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDtcNetworkAccessConfig2_Impl::GetAuthenticationLevel(this) {
+                    Ok(ok__) => {
+                        pauthlevel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAuthenticationLevel<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig2_Impl>(this: *mut core::ffi::c_void, authlevel: AUTHENTICATION_LEVEL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcNetworkAccessConfig2_Impl::SetAuthenticationLevel(this, core::mem::transmute_copy(&authlevel)).into()
+            }
+        }
+        Self {
+            base__: <IDtcNetworkAccessConfig_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetNetworkInboundAccess: GetNetworkInboundAccess::<Identity>,
+            GetNetworkOutboundAccess: GetNetworkOutboundAccess::<Identity>,
+            SetNetworkInboundAccess: SetNetworkInboundAccess::<Identity>,
+            SetNetworkOutboundAccess: SetNetworkOutboundAccess::<Identity>,
+            GetAuthenticationLevel: GetAuthenticationLevel::<Identity>,
+            SetAuthenticationLevel: SetAuthenticationLevel::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDtcNetworkAccessConfig2 {}
 windows_core::imp::define_interface!(IDtcNetworkAccessConfig3, IDtcNetworkAccessConfig3_Vtbl, 0x76e4b4f3_2ca5_466b_89d5_fd218ee75b49);
 impl core::ops::Deref for IDtcNetworkAccessConfig3 {
@@ -1563,6 +2315,36 @@ impl IDtcNetworkAccessConfig3_Vtbl {
         iid == &<IDtcNetworkAccessConfig3 as windows_core::Interface>::IID || iid == &<IDtcNetworkAccessConfig as windows_core::Interface>::IID || iid == &<IDtcNetworkAccessConfig2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig3_Impl> windows_core::imp::TearOffVtable<Identity> for IDtcNetworkAccessConfig3_Vtbl
+where
+    IDtcNetworkAccessConfig2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetLUAccess<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig3_Impl>(this: *mut core::ffi::c_void, pbluaccess: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDtcNetworkAccessConfig3_Impl::GetLUAccess(this) {
+                    Ok(ok__) => {
+                        pbluaccess.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLUAccess<Identity: windows_core::IUnknownImpl + IDtcNetworkAccessConfig3_Impl>(this: *mut core::ffi::c_void, bluaccess: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcNetworkAccessConfig3_Impl::SetLUAccess(this, core::mem::transmute_copy(&bluaccess)).into()
+            }
+        }
+        Self {
+            base__: <IDtcNetworkAccessConfig2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetLUAccess: GetLUAccess::<Identity>,
+            SetLUAccess: SetLUAccess::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDtcNetworkAccessConfig3 {}
 windows_core::imp::define_interface!(IDtcToXaHelper, IDtcToXaHelper_Vtbl, 0xa9861611_304a_11d1_9813_00a0c905416e);
 windows_core::imp::interface_hierarchy!(IDtcToXaHelper, windows_core::IUnknown);
@@ -1612,6 +2394,30 @@ impl IDtcToXaHelper_Vtbl {
         iid == &<IDtcToXaHelper as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDtcToXaHelper_Impl> windows_core::imp::TearOffVtable<Identity> for IDtcToXaHelper_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IDtcToXaHelper_Impl>(this: *mut core::ffi::c_void, i_fdorecovery: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcToXaHelper_Impl::Close(this, core::mem::transmute_copy(&i_fdorecovery)).into()
+            }
+        }
+        unsafe extern "system" fn TranslateTridToXid<Identity: windows_core::IUnknownImpl + IDtcToXaHelper_Impl>(this: *mut core::ffi::c_void, pitransaction: *mut core::ffi::c_void, pguidbqual: *const windows_core::GUID, pxid: *mut XID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcToXaHelper_Impl::TranslateTridToXid(this, core::mem::transmute_copy(&pitransaction), core::mem::transmute_copy(&pguidbqual), core::mem::transmute_copy(&pxid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Close: Close::<Identity>,
+            TranslateTridToXid: TranslateTridToXid::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDtcToXaHelper {}
 windows_core::imp::define_interface!(IDtcToXaHelperFactory, IDtcToXaHelperFactory_Vtbl, 0xa9861610_304a_11d1_9813_00a0c905416e);
 windows_core::imp::interface_hierarchy!(IDtcToXaHelperFactory, windows_core::IUnknown);
@@ -1647,6 +2453,20 @@ impl IDtcToXaHelperFactory_Vtbl {
         iid == &<IDtcToXaHelperFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDtcToXaHelperFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IDtcToXaHelperFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Create<Identity: windows_core::IUnknownImpl + IDtcToXaHelperFactory_Impl>(this: *mut core::ffi::c_void, pszdsn: windows_core::PCSTR, pszclientdllname: windows_core::PCSTR, pguidrm: *mut windows_core::GUID, ppxahelper: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcToXaHelperFactory_Impl::Create(this, core::mem::transmute(&pszdsn), core::mem::transmute(&pszclientdllname), core::mem::transmute_copy(&pguidrm), core::mem::transmute_copy(&ppxahelper)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Create: Create::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IDtcToXaHelperFactory {}
 windows_core::imp::define_interface!(IDtcToXaHelperSinglePipe, IDtcToXaHelperSinglePipe_Vtbl, 0x47ed4971_53b3_11d1_bbb9_00c04fd658f6);
 windows_core::imp::interface_hierarchy!(IDtcToXaHelperSinglePipe, windows_core::IUnknown);
@@ -1734,6 +2554,50 @@ impl IDtcToXaHelperSinglePipe_Vtbl {
         iid == &<IDtcToXaHelperSinglePipe as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDtcToXaHelperSinglePipe_Impl> windows_core::imp::TearOffVtable<Identity> for IDtcToXaHelperSinglePipe_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn XARMCreate<Identity: windows_core::IUnknownImpl + IDtcToXaHelperSinglePipe_Impl>(this: *mut core::ffi::c_void, pszdsn: windows_core::PCSTR, pszclientdll: windows_core::PCSTR, pdwrmcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcToXaHelperSinglePipe_Impl::XARMCreate(this, core::mem::transmute(&pszdsn), core::mem::transmute(&pszclientdll), core::mem::transmute_copy(&pdwrmcookie)).into()
+            }
+        }
+        unsafe extern "system" fn ConvertTridToXID<Identity: windows_core::IUnknownImpl + IDtcToXaHelperSinglePipe_Impl>(this: *mut core::ffi::c_void, pdwitrans: *mut u32, dwrmcookie: u32, pxid: *mut XID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcToXaHelperSinglePipe_Impl::ConvertTridToXID(this, core::mem::transmute_copy(&pdwitrans), core::mem::transmute_copy(&dwrmcookie), core::mem::transmute_copy(&pxid)).into()
+            }
+        }
+        unsafe extern "system" fn EnlistWithRM<Identity: windows_core::IUnknownImpl + IDtcToXaHelperSinglePipe_Impl>(this: *mut core::ffi::c_void, dwrmcookie: u32, i_pitransaction: *mut core::ffi::c_void, i_pitransres: *mut core::ffi::c_void, o_ppitransenslitment: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDtcToXaHelperSinglePipe_Impl::EnlistWithRM(this, core::mem::transmute_copy(&dwrmcookie), core::mem::transmute_copy(&i_pitransaction), core::mem::transmute_copy(&i_pitransres)) {
+                    Ok(ok__) => {
+                        o_ppitransenslitment.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReleaseRMCookie<Identity: windows_core::IUnknownImpl + IDtcToXaHelperSinglePipe_Impl>(this: *mut core::ffi::c_void, i_dwrmcookie: u32, i_fnormal: windows_core::BOOL) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcToXaHelperSinglePipe_Impl::ReleaseRMCookie(this, core::mem::transmute_copy(&i_dwrmcookie), core::mem::transmute_copy(&i_fnormal))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            XARMCreate: XARMCreate::<Identity>,
+            ConvertTridToXID: ConvertTridToXID::<Identity>,
+            EnlistWithRM: EnlistWithRM::<Identity>,
+            ReleaseRMCookie: ReleaseRMCookie::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDtcToXaHelperSinglePipe {}
 windows_core::imp::define_interface!(IDtcToXaMapper, IDtcToXaMapper_Vtbl, 0x64ffabe0_7ce9_11d0_8ce6_00c04fdc877e);
 windows_core::imp::interface_hierarchy!(IDtcToXaMapper, windows_core::IUnknown);
@@ -1803,10 +2667,48 @@ impl IDtcToXaMapper_Vtbl {
             EnlistResourceManager: EnlistResourceManager::<Identity, OFFSET>,
             ReleaseResourceManager: ReleaseResourceManager::<Identity, OFFSET>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IDtcToXaMapper as windows_core::Interface>::IID
-    }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtcToXaMapper as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IDtcToXaMapper_Impl> windows_core::imp::TearOffVtable<Identity> for IDtcToXaMapper_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RequestNewResourceManager<Identity: windows_core::IUnknownImpl + IDtcToXaMapper_Impl>(this: *mut core::ffi::c_void, pszdsn: windows_core::PCSTR, pszclientdllname: windows_core::PCSTR, pdwrmcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcToXaMapper_Impl::RequestNewResourceManager(this, core::mem::transmute(&pszdsn), core::mem::transmute(&pszclientdllname), core::mem::transmute_copy(&pdwrmcookie)).into()
+            }
+        }
+        unsafe extern "system" fn TranslateTridToXid<Identity: windows_core::IUnknownImpl + IDtcToXaMapper_Impl>(this: *mut core::ffi::c_void, pdwitransaction: *const u32, dwrmcookie: u32, pxid: *mut XID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcToXaMapper_Impl::TranslateTridToXid(this, core::mem::transmute_copy(&pdwitransaction), core::mem::transmute_copy(&dwrmcookie), core::mem::transmute_copy(&pxid)).into()
+            }
+        }
+        unsafe extern "system" fn EnlistResourceManager<Identity: windows_core::IUnknownImpl + IDtcToXaMapper_Impl>(this: *mut core::ffi::c_void, dwrmcookie: u32, pdwitransaction: *const u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcToXaMapper_Impl::EnlistResourceManager(this, core::mem::transmute_copy(&dwrmcookie), core::mem::transmute_copy(&pdwitransaction)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseResourceManager<Identity: windows_core::IUnknownImpl + IDtcToXaMapper_Impl>(this: *mut core::ffi::c_void, dwrmcookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDtcToXaMapper_Impl::ReleaseResourceManager(this, core::mem::transmute_copy(&dwrmcookie)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RequestNewResourceManager: RequestNewResourceManager::<Identity>,
+            TranslateTridToXid: TranslateTridToXid::<Identity>,
+            EnlistResourceManager: EnlistResourceManager::<Identity>,
+            ReleaseResourceManager: ReleaseResourceManager::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IDtcToXaMapper {}
 windows_core::imp::define_interface!(IGetDispenser, IGetDispenser_Vtbl, 0xc23cc370_87ef_11ce_8081_0080c758527e);
@@ -1839,6 +2741,20 @@ impl IGetDispenser_Vtbl {
         iid == &<IGetDispenser as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IGetDispenser_Impl> windows_core::imp::TearOffVtable<Identity> for IGetDispenser_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDispenser<Identity: windows_core::IUnknownImpl + IGetDispenser_Impl>(this: *mut core::ffi::c_void, iid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGetDispenser_Impl::GetDispenser(this, core::mem::transmute_copy(&iid), core::mem::transmute_copy(&ppvobject)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetDispenser: GetDispenser::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IGetDispenser {}
 windows_core::imp::define_interface!(IKernelTransaction, IKernelTransaction_Vtbl, 0x79427a2b_f895_40e0_be79_b57dc82ed231);
 windows_core::imp::interface_hierarchy!(IKernelTransaction, windows_core::IUnknown);
@@ -1879,6 +2795,26 @@ impl IKernelTransaction_Vtbl {
         iid == &<IKernelTransaction as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IKernelTransaction_Impl> windows_core::imp::TearOffVtable<Identity> for IKernelTransaction_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetHandle<Identity: windows_core::IUnknownImpl + IKernelTransaction_Impl>(this: *mut core::ffi::c_void, phandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IKernelTransaction_Impl::GetHandle(this) {
+                    Ok(ok__) => {
+                        phandle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetHandle: GetHandle::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IKernelTransaction {}
 windows_core::imp::define_interface!(ILastResourceManager, ILastResourceManager_Vtbl, 0x4d964ad4_5b33_11d3_8a91_00c04f79eb6d);
 windows_core::imp::interface_hierarchy!(ILastResourceManager, windows_core::IUnknown);
@@ -1925,6 +2861,30 @@ impl ILastResourceManager_Vtbl {
         iid == &<ILastResourceManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ILastResourceManager_Impl> windows_core::imp::TearOffVtable<Identity> for ILastResourceManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn TransactionCommitted<Identity: windows_core::IUnknownImpl + ILastResourceManager_Impl>(this: *mut core::ffi::c_void, pprepinfo: *const u8, cbprepinfo: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILastResourceManager_Impl::TransactionCommitted(this, core::mem::transmute_copy(&pprepinfo), core::mem::transmute_copy(&cbprepinfo)).into()
+            }
+        }
+        unsafe extern "system" fn RecoveryDone<Identity: windows_core::IUnknownImpl + ILastResourceManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILastResourceManager_Impl::RecoveryDone(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            TransactionCommitted: TransactionCommitted::<Identity>,
+            RecoveryDone: RecoveryDone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ILastResourceManager {}
 pub const INCOMING_AUTHENTICATION_REQUIRED: AUTHENTICATION_LEVEL = AUTHENTICATION_LEVEL(1i32);
 windows_core::imp::define_interface!(IPrepareInfo, IPrepareInfo_Vtbl, 0x80c7bfd0_87ee_11ce_8081_0080c758527e);
@@ -1972,6 +2932,30 @@ impl IPrepareInfo_Vtbl {
         iid == &<IPrepareInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrepareInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IPrepareInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPrepareInfoSize<Identity: windows_core::IUnknownImpl + IPrepareInfo_Impl>(this: *mut core::ffi::c_void, pcbprepinfo: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrepareInfo_Impl::GetPrepareInfoSize(this, core::mem::transmute_copy(&pcbprepinfo)).into()
+            }
+        }
+        unsafe extern "system" fn GetPrepareInfo<Identity: windows_core::IUnknownImpl + IPrepareInfo_Impl>(this: *mut core::ffi::c_void, pprepinfo: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrepareInfo_Impl::GetPrepareInfo(this, core::mem::transmute_copy(&pprepinfo)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPrepareInfoSize: GetPrepareInfoSize::<Identity>,
+            GetPrepareInfo: GetPrepareInfo::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPrepareInfo {}
 windows_core::imp::define_interface!(IPrepareInfo2, IPrepareInfo2_Vtbl, 0x5fab2547_9779_11d1_b886_00c04fb9618a);
 windows_core::imp::interface_hierarchy!(IPrepareInfo2, windows_core::IUnknown);
@@ -2027,6 +3011,36 @@ impl IPrepareInfo2_Vtbl {
         iid == &<IPrepareInfo2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPrepareInfo2_Impl> windows_core::imp::TearOffVtable<Identity> for IPrepareInfo2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPrepareInfoSize<Identity: windows_core::IUnknownImpl + IPrepareInfo2_Impl>(this: *mut core::ffi::c_void, pcbprepinfo: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPrepareInfo2_Impl::GetPrepareInfoSize(this) {
+                    Ok(ok__) => {
+                        pcbprepinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPrepareInfo<Identity: windows_core::IUnknownImpl + IPrepareInfo2_Impl>(this: *mut core::ffi::c_void, cbprepareinfo: u32, pprepinfo: *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrepareInfo2_Impl::GetPrepareInfo(this, core::mem::transmute_copy(&cbprepareinfo), core::mem::transmute_copy(&pprepinfo)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetPrepareInfoSize: GetPrepareInfoSize::<Identity>,
+            GetPrepareInfo: GetPrepareInfo::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPrepareInfo2 {}
 windows_core::imp::define_interface!(IRMHelper, IRMHelper_Vtbl, 0xe793f6d1_f53d_11cf_a60d_00a0c905416e);
 windows_core::imp::interface_hierarchy!(IRMHelper, windows_core::IUnknown);
@@ -2073,6 +3087,30 @@ impl IRMHelper_Vtbl {
         iid == &<IRMHelper as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRMHelper_Impl> windows_core::imp::TearOffVtable<Identity> for IRMHelper_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RMCount<Identity: windows_core::IUnknownImpl + IRMHelper_Impl>(this: *mut core::ffi::c_void, dwctotalnumberofrms: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRMHelper_Impl::RMCount(this, core::mem::transmute_copy(&dwctotalnumberofrms)).into()
+            }
+        }
+        unsafe extern "system" fn RMInfo<Identity: windows_core::IUnknownImpl + IRMHelper_Impl>(this: *mut core::ffi::c_void, pxa_switch: *mut xa_switch_t, fcdeclcallingconv: windows_core::BOOL, pszopenstring: windows_core::PCSTR, pszclosestring: windows_core::PCSTR, guidrmrecovery: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRMHelper_Impl::RMInfo(this, core::mem::transmute_copy(&pxa_switch), core::mem::transmute_copy(&fcdeclcallingconv), core::mem::transmute(&pszopenstring), core::mem::transmute(&pszclosestring), core::mem::transmute(&guidrmrecovery)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RMCount: RMCount::<Identity>,
+            RMInfo: RMInfo::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRMHelper {}
 windows_core::imp::define_interface!(IResourceManager, IResourceManager_Vtbl, 0x13741d21_87eb_11ce_8081_0080c758527e);
 windows_core::imp::interface_hierarchy!(IResourceManager, windows_core::IUnknown);
@@ -2156,6 +3194,50 @@ impl IResourceManager_Vtbl {
         iid == &<IResourceManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IResourceManager_Impl> windows_core::imp::TearOffVtable<Identity> for IResourceManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Enlist<Identity: windows_core::IUnknownImpl + IResourceManager_Impl>(this: *mut core::ffi::c_void, ptransaction: *mut core::ffi::c_void, pres: *mut core::ffi::c_void, puow: *mut BOID, pisolevel: *mut i32, ppenlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IResourceManager_Impl::Enlist(this, core::mem::transmute_copy(&ptransaction), core::mem::transmute_copy(&pres), core::mem::transmute_copy(&puow), core::mem::transmute_copy(&pisolevel), core::mem::transmute_copy(&ppenlist)).into()
+            }
+        }
+        unsafe extern "system" fn Reenlist<Identity: windows_core::IUnknownImpl + IResourceManager_Impl>(this: *mut core::ffi::c_void, pprepinfo: *const u8, cbprepinfo: u32, ltimeout: u32, pxactstat: *mut XACTSTAT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IResourceManager_Impl::Reenlist(this, core::mem::transmute_copy(&pprepinfo), core::mem::transmute_copy(&cbprepinfo), core::mem::transmute_copy(&ltimeout)) {
+                    Ok(ok__) => {
+                        pxactstat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReenlistmentComplete<Identity: windows_core::IUnknownImpl + IResourceManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IResourceManager_Impl::ReenlistmentComplete(this).into()
+            }
+        }
+        unsafe extern "system" fn GetDistributedTransactionManager<Identity: windows_core::IUnknownImpl + IResourceManager_Impl>(this: *mut core::ffi::c_void, iid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IResourceManager_Impl::GetDistributedTransactionManager(this, core::mem::transmute_copy(&iid), core::mem::transmute_copy(&ppvobject)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Enlist: Enlist::<Identity>,
+            Reenlist: Reenlist::<Identity>,
+            ReenlistmentComplete: ReenlistmentComplete::<Identity>,
+            GetDistributedTransactionManager: GetDistributedTransactionManager::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IResourceManager {}
 windows_core::imp::define_interface!(IResourceManager2, IResourceManager2_Vtbl, 0xd136c69a_f749_11d1_8f47_00c04f8ee57d);
 impl core::ops::Deref for IResourceManager2 {
@@ -2217,6 +3299,36 @@ impl IResourceManager2_Vtbl {
         iid == &<IResourceManager2 as windows_core::Interface>::IID || iid == &<IResourceManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IResourceManager2_Impl> windows_core::imp::TearOffVtable<Identity> for IResourceManager2_Vtbl
+where
+    IResourceManager_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Enlist2<Identity: windows_core::IUnknownImpl + IResourceManager2_Impl>(this: *mut core::ffi::c_void, ptransaction: *mut core::ffi::c_void, presasync: *mut core::ffi::c_void, puow: *mut BOID, pisolevel: *mut i32, pxid: *mut XID, ppenlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IResourceManager2_Impl::Enlist2(this, core::mem::transmute_copy(&ptransaction), core::mem::transmute_copy(&presasync), core::mem::transmute_copy(&puow), core::mem::transmute_copy(&pisolevel), core::mem::transmute_copy(&pxid), core::mem::transmute_copy(&ppenlist)).into()
+            }
+        }
+        unsafe extern "system" fn Reenlist2<Identity: windows_core::IUnknownImpl + IResourceManager2_Impl>(this: *mut core::ffi::c_void, pxid: *const XID, dwtimeout: u32, pxactstat: *mut XACTSTAT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IResourceManager2_Impl::Reenlist2(this, core::mem::transmute_copy(&pxid), core::mem::transmute_copy(&dwtimeout)) {
+                    Ok(ok__) => {
+                        pxactstat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IResourceManager_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Enlist2: Enlist2::<Identity>,
+            Reenlist2: Reenlist2::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IResourceManager2 {}
 windows_core::imp::define_interface!(IResourceManagerFactory, IResourceManagerFactory_Vtbl, 0x13741d20_87eb_11ce_8081_0080c758527e);
 windows_core::imp::interface_hierarchy!(IResourceManagerFactory, windows_core::IUnknown);
@@ -2261,6 +3373,26 @@ impl IResourceManagerFactory_Vtbl {
         iid == &<IResourceManagerFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IResourceManagerFactory_Impl> windows_core::imp::TearOffVtable<Identity> for IResourceManagerFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Create<Identity: windows_core::IUnknownImpl + IResourceManagerFactory_Impl>(this: *mut core::ffi::c_void, pguidrm: *const windows_core::GUID, pszrmname: windows_core::PCSTR, piresmgrsink: *mut core::ffi::c_void, ppresmgr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IResourceManagerFactory_Impl::Create(this, core::mem::transmute_copy(&pguidrm), core::mem::transmute(&pszrmname), core::mem::transmute_copy(&piresmgrsink)) {
+                    Ok(ok__) => {
+                        ppresmgr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Create: Create::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IResourceManagerFactory {}
 windows_core::imp::define_interface!(IResourceManagerFactory2, IResourceManagerFactory2_Vtbl, 0x6b369c21_fbd2_11d1_8f47_00c04f8ee57d);
 impl core::ops::Deref for IResourceManagerFactory2 {
@@ -2302,6 +3434,20 @@ impl IResourceManagerFactory2_Vtbl {
         iid == &<IResourceManagerFactory2 as windows_core::Interface>::IID || iid == &<IResourceManagerFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IResourceManagerFactory2_Impl> windows_core::imp::TearOffVtable<Identity> for IResourceManagerFactory2_Vtbl
+where
+    IResourceManagerFactory_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateEx<Identity: windows_core::IUnknownImpl + IResourceManagerFactory2_Impl>(this: *mut core::ffi::c_void, pguidrm: *const windows_core::GUID, pszrmname: windows_core::PCSTR, piresmgrsink: *mut core::ffi::c_void, riidrequested: *const windows_core::GUID, ppvresmgr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IResourceManagerFactory2_Impl::CreateEx(this, core::mem::transmute_copy(&pguidrm), core::mem::transmute(&pszrmname), core::mem::transmute_copy(&piresmgrsink), core::mem::transmute_copy(&riidrequested), core::mem::transmute_copy(&ppvresmgr)).into()
+            }
+        }
+        Self { base__: <IResourceManagerFactory_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateEx: CreateEx::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IResourceManagerFactory2 {}
 windows_core::imp::define_interface!(IResourceManagerRejoinable, IResourceManagerRejoinable_Vtbl, 0x6f6de620_b5df_4f3e_9cfa_c8aebd05172b);
 impl core::ops::Deref for IResourceManagerRejoinable {
@@ -2348,6 +3494,26 @@ impl IResourceManagerRejoinable_Vtbl {
         iid == &<IResourceManagerRejoinable as windows_core::Interface>::IID || iid == &<IResourceManager as windows_core::Interface>::IID || iid == &<IResourceManager2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IResourceManagerRejoinable_Impl> windows_core::imp::TearOffVtable<Identity> for IResourceManagerRejoinable_Vtbl
+where
+    IResourceManager2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Rejoin<Identity: windows_core::IUnknownImpl + IResourceManagerRejoinable_Impl>(this: *mut core::ffi::c_void, pprepinfo: *const u8, cbprepinfo: u32, ltimeout: u32, pxactstat: *mut XACTSTAT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IResourceManagerRejoinable_Impl::Rejoin(this, core::mem::transmute_copy(&pprepinfo), core::mem::transmute_copy(&cbprepinfo), core::mem::transmute_copy(&ltimeout)) {
+                    Ok(ok__) => {
+                        pxactstat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IResourceManager2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Rejoin: Rejoin::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IResourceManagerRejoinable {}
 windows_core::imp::define_interface!(IResourceManagerSink, IResourceManagerSink_Vtbl, 0x0d563181_defb_11ce_aed1_00aa0051e2c4);
 windows_core::imp::interface_hierarchy!(IResourceManagerSink, windows_core::IUnknown);
@@ -2379,6 +3545,20 @@ impl IResourceManagerSink_Vtbl {
         iid == &<IResourceManagerSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IResourceManagerSink_Impl> windows_core::imp::TearOffVtable<Identity> for IResourceManagerSink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn TMDown<Identity: windows_core::IUnknownImpl + IResourceManagerSink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IResourceManagerSink_Impl::TMDown(this).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, TMDown: TMDown::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IResourceManagerSink {}
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -2493,6 +3673,55 @@ impl ITipHelper_Vtbl {
         iid == &<ITipHelper as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITipHelper_Impl> windows_core::imp::TearOffVtable<Identity> for ITipHelper_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Pull<Identity: windows_core::IUnknownImpl + ITipHelper_Impl>(this: *mut core::ffi::c_void, i_psztxurl: *const u8, o_ppitransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITipHelper_Impl::Pull(this, core::mem::transmute_copy(&i_psztxurl)) {
+                    Ok(ok__) => {
+                        o_ppitransaction.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PullAsync<Identity: windows_core::IUnknownImpl + ITipHelper_Impl>(this: *mut core::ffi::c_void, i_psztxurl: *const u8, i_ptippullsink: *mut core::ffi::c_void, o_ppitransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITipHelper_Impl::PullAsync(this, core::mem::transmute_copy(&i_psztxurl), core::mem::transmute_copy(&i_ptippullsink)) {
+                    Ok(ok__) => {
+                        o_ppitransaction.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLocalTmUrl<Identity: windows_core::IUnknownImpl + ITipHelper_Impl>(this: *mut core::ffi::c_void, o_ppszlocaltmurl: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITipHelper_Impl::GetLocalTmUrl(this) {
+                    Ok(ok__) => {
+                        o_ppszlocaltmurl.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Pull: Pull::<Identity>,
+            PullAsync: PullAsync::<Identity>,
+            GetLocalTmUrl: GetLocalTmUrl::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITipHelper {}
 windows_core::imp::define_interface!(ITipPullSink, ITipPullSink_Vtbl, 0x17cf72d2_bac5_11d1_b1bf_00c04fc2f3ef);
 windows_core::imp::interface_hierarchy!(ITipPullSink, windows_core::IUnknown);
@@ -2524,6 +3753,20 @@ impl ITipPullSink_Vtbl {
         iid == &<ITipPullSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITipPullSink_Impl> windows_core::imp::TearOffVtable<Identity> for ITipPullSink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn PullComplete<Identity: windows_core::IUnknownImpl + ITipPullSink_Impl>(this: *mut core::ffi::c_void, i_hrpull: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITipPullSink_Impl::PullComplete(this, core::mem::transmute_copy(&i_hrpull)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, PullComplete: PullComplete::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ITipPullSink {}
 windows_core::imp::define_interface!(ITipTransaction, ITipTransaction_Vtbl, 0x17cf72d0_bac5_11d1_b1bf_00c04fc2f3ef);
 windows_core::imp::interface_hierarchy!(ITipTransaction, windows_core::IUnknown);
@@ -2588,6 +3831,42 @@ impl ITipTransaction_Vtbl {
         iid == &<ITipTransaction as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITipTransaction_Impl> windows_core::imp::TearOffVtable<Identity> for ITipTransaction_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Push<Identity: windows_core::IUnknownImpl + ITipTransaction_Impl>(this: *mut core::ffi::c_void, i_pszremotetmurl: *const u8, o_ppszremotetxurl: *mut windows_core::PSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITipTransaction_Impl::Push(this, core::mem::transmute_copy(&i_pszremotetmurl)) {
+                    Ok(ok__) => {
+                        o_ppszremotetxurl.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTransactionUrl<Identity: windows_core::IUnknownImpl + ITipTransaction_Impl>(this: *mut core::ffi::c_void, o_ppszlocaltxurl: *mut windows_core::PSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITipTransaction_Impl::GetTransactionUrl(this) {
+                    Ok(ok__) => {
+                        o_ppszlocaltxurl.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Push: Push::<Identity>,
+            GetTransactionUrl: GetTransactionUrl::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITipTransaction {}
 windows_core::imp::define_interface!(ITmNodeName, ITmNodeName_Vtbl, 0x30274f88_6ee4_474e_9b95_7807bc9ef8cf);
 windows_core::imp::interface_hierarchy!(ITmNodeName, windows_core::IUnknown);
@@ -2643,6 +3922,36 @@ impl ITmNodeName_Vtbl {
         iid == &<ITmNodeName as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITmNodeName_Impl> windows_core::imp::TearOffVtable<Identity> for ITmNodeName_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetNodeNameSize<Identity: windows_core::IUnknownImpl + ITmNodeName_Impl>(this: *mut core::ffi::c_void, pcbnodenamesize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITmNodeName_Impl::GetNodeNameSize(this) {
+                    Ok(ok__) => {
+                        pcbnodenamesize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetNodeName<Identity: windows_core::IUnknownImpl + ITmNodeName_Impl>(this: *mut core::ffi::c_void, cbnodenamebuffersize: u32, pnodenamebuffer: windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITmNodeName_Impl::GetNodeName(this, core::mem::transmute_copy(&cbnodenamebuffersize), core::mem::transmute_copy(&pnodenamebuffer)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetNodeNameSize: GetNodeNameSize::<Identity>,
+            GetNodeName: GetNodeName::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITmNodeName {}
 windows_core::imp::define_interface!(ITransaction, ITransaction_Vtbl, 0x0fb15084_af41_11ce_bd2b_204c4f4f5020);
 windows_core::imp::interface_hierarchy!(ITransaction, windows_core::IUnknown);
@@ -2701,6 +4010,37 @@ impl ITransaction_Vtbl {
         iid == &<ITransaction as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITransaction_Impl> windows_core::imp::TearOffVtable<Identity> for ITransaction_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Commit<Identity: windows_core::IUnknownImpl + ITransaction_Impl>(this: *mut core::ffi::c_void, fretaining: windows_core::BOOL, grftc: u32, grfrm: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransaction_Impl::Commit(this, core::mem::transmute_copy(&fretaining), core::mem::transmute_copy(&grftc), core::mem::transmute_copy(&grfrm)).into()
+            }
+        }
+        unsafe extern "system" fn Abort<Identity: windows_core::IUnknownImpl + ITransaction_Impl>(this: *mut core::ffi::c_void, pboidreason: *const BOID, fretaining: windows_core::BOOL, fasync: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransaction_Impl::Abort(this, core::mem::transmute_copy(&pboidreason), core::mem::transmute_copy(&fretaining), core::mem::transmute_copy(&fasync)).into()
+            }
+        }
+        unsafe extern "system" fn GetTransactionInfo<Identity: windows_core::IUnknownImpl + ITransaction_Impl>(this: *mut core::ffi::c_void, pinfo: *mut XACTTRANSINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransaction_Impl::GetTransactionInfo(this, core::mem::transmute_copy(&pinfo)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Commit: Commit::<Identity>,
+            Abort: Abort::<Identity>,
+            GetTransactionInfo: GetTransactionInfo::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITransaction {}
 windows_core::imp::define_interface!(ITransaction2, ITransaction2_Vtbl, 0x34021548_0065_11d3_bac1_00c04f797be2);
 impl core::ops::Deref for ITransaction2 {
@@ -2738,6 +4078,20 @@ impl ITransaction2_Vtbl {
         iid == &<ITransaction2 as windows_core::Interface>::IID || iid == &<ITransaction as windows_core::Interface>::IID || iid == &<ITransactionCloner as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITransaction2_Impl> windows_core::imp::TearOffVtable<Identity> for ITransaction2_Vtbl
+where
+    ITransactionCloner_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetTransactionInfo2<Identity: windows_core::IUnknownImpl + ITransaction2_Impl>(this: *mut core::ffi::c_void, pinfo: *mut XACTTRANSINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransaction2_Impl::GetTransactionInfo2(this, core::mem::transmute_copy(&pinfo)).into()
+            }
+        }
+        Self { base__: <ITransactionCloner_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetTransactionInfo2: GetTransactionInfo2::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ITransaction2 {}
 windows_core::imp::define_interface!(ITransactionCloner, ITransactionCloner_Vtbl, 0x02656950_2152_11d0_944c_00a0c905416e);
 impl core::ops::Deref for ITransactionCloner {
@@ -2784,6 +4138,26 @@ impl ITransactionCloner_Vtbl {
         iid == &<ITransactionCloner as windows_core::Interface>::IID || iid == &<ITransaction as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITransactionCloner_Impl> windows_core::imp::TearOffVtable<Identity> for ITransactionCloner_Vtbl
+where
+    ITransaction_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CloneWithCommitDisabled<Identity: windows_core::IUnknownImpl + ITransactionCloner_Impl>(this: *mut core::ffi::c_void, ppitransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITransactionCloner_Impl::CloneWithCommitDisabled(this) {
+                    Ok(ok__) => {
+                        ppitransaction.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <ITransaction_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CloneWithCommitDisabled: CloneWithCommitDisabled::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ITransactionCloner {}
 windows_core::imp::define_interface!(ITransactionDispenser, ITransactionDispenser_Vtbl, 0x3a6ad9e1_23b9_11cf_ad60_00aa00a74ccd);
 windows_core::imp::interface_hierarchy!(ITransactionDispenser, windows_core::IUnknown);
@@ -2852,6 +4226,42 @@ impl ITransactionDispenser_Vtbl {
         iid == &<ITransactionDispenser as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITransactionDispenser_Impl> windows_core::imp::TearOffVtable<Identity> for ITransactionDispenser_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetOptionsObject<Identity: windows_core::IUnknownImpl + ITransactionDispenser_Impl>(this: *mut core::ffi::c_void, ppoptions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITransactionDispenser_Impl::GetOptionsObject(this) {
+                    Ok(ok__) => {
+                        ppoptions.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn BeginTransaction<Identity: windows_core::IUnknownImpl + ITransactionDispenser_Impl>(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, isolevel: i32, isoflags: u32, poptions: *mut core::ffi::c_void, pptransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITransactionDispenser_Impl::BeginTransaction(this, core::mem::transmute_copy(&punkouter), core::mem::transmute_copy(&isolevel), core::mem::transmute_copy(&isoflags), core::mem::transmute_copy(&poptions)) {
+                    Ok(ok__) => {
+                        pptransaction.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetOptionsObject: GetOptionsObject::<Identity>,
+            BeginTransaction: BeginTransaction::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITransactionDispenser {}
 windows_core::imp::define_interface!(ITransactionEnlistmentAsync, ITransactionEnlistmentAsync_Vtbl, 0x0fb15081_af41_11ce_bd2b_204c4f4f5020);
 windows_core::imp::interface_hierarchy!(ITransactionEnlistmentAsync, windows_core::IUnknown);
@@ -2920,6 +4330,38 @@ impl ITransactionEnlistmentAsync_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + ITransactionEnlistmentAsync_Impl> windows_core::imp::TearOffVtable<Identity> for ITransactionEnlistmentAsync_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn PrepareRequestDone<Identity: windows_core::IUnknownImpl + ITransactionEnlistmentAsync_Impl>(this: *mut core::ffi::c_void, hr: windows_core::HRESULT, pmk: *mut core::ffi::c_void, pboidreason: *const BOID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionEnlistmentAsync_Impl::PrepareRequestDone(this, core::mem::transmute_copy(&hr), core::mem::transmute_copy(&pmk), core::mem::transmute_copy(&pboidreason)).into()
+            }
+        }
+        unsafe extern "system" fn CommitRequestDone<Identity: windows_core::IUnknownImpl + ITransactionEnlistmentAsync_Impl>(this: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionEnlistmentAsync_Impl::CommitRequestDone(this, core::mem::transmute_copy(&hr)).into()
+            }
+        }
+        unsafe extern "system" fn AbortRequestDone<Identity: windows_core::IUnknownImpl + ITransactionEnlistmentAsync_Impl>(this: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionEnlistmentAsync_Impl::AbortRequestDone(this, core::mem::transmute_copy(&hr)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PrepareRequestDone: PrepareRequestDone::<Identity>,
+            CommitRequestDone: CommitRequestDone::<Identity>,
+            AbortRequestDone: AbortRequestDone::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for ITransactionEnlistmentAsync {}
 windows_core::imp::define_interface!(ITransactionExport, ITransactionExport_Vtbl, 0x0141fda5_8fc0_11ce_bd18_204c4f4f5020);
 windows_core::imp::interface_hierarchy!(ITransactionExport, windows_core::IUnknown);
@@ -2981,6 +4423,36 @@ impl ITransactionExport_Vtbl {
         iid == &<ITransactionExport as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITransactionExport_Impl> windows_core::imp::TearOffVtable<Identity> for ITransactionExport_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Export<Identity: windows_core::IUnknownImpl + ITransactionExport_Impl>(this: *mut core::ffi::c_void, punktransaction: *mut core::ffi::c_void, pcbtransactioncookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITransactionExport_Impl::Export(this, core::mem::transmute_copy(&punktransaction)) {
+                    Ok(ok__) => {
+                        pcbtransactioncookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTransactionCookie<Identity: windows_core::IUnknownImpl + ITransactionExport_Impl>(this: *mut core::ffi::c_void, punktransaction: *mut core::ffi::c_void, cbtransactioncookie: u32, rgbtransactioncookie: *mut u8, pcbused: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionExport_Impl::GetTransactionCookie(this, core::mem::transmute_copy(&punktransaction), core::mem::transmute_copy(&cbtransactioncookie), core::mem::transmute_copy(&rgbtransactioncookie), core::mem::transmute_copy(&pcbused)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Export: Export::<Identity>,
+            GetTransactionCookie: GetTransactionCookie::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITransactionExport {}
 windows_core::imp::define_interface!(ITransactionExportFactory, ITransactionExportFactory_Vtbl, 0xe1cf9b53_8745_11ce_a9ba_00aa006c3706);
 windows_core::imp::interface_hierarchy!(ITransactionExportFactory, windows_core::IUnknown);
@@ -3045,6 +4517,42 @@ impl ITransactionExportFactory_Vtbl {
         iid == &<ITransactionExportFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITransactionExportFactory_Impl> windows_core::imp::TearOffVtable<Identity> for ITransactionExportFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRemoteClassId<Identity: windows_core::IUnknownImpl + ITransactionExportFactory_Impl>(this: *mut core::ffi::c_void, pclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITransactionExportFactory_Impl::GetRemoteClassId(this) {
+                    Ok(ok__) => {
+                        pclsid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Create<Identity: windows_core::IUnknownImpl + ITransactionExportFactory_Impl>(this: *mut core::ffi::c_void, cbwhereabouts: u32, rgbwhereabouts: *const u8, ppexport: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITransactionExportFactory_Impl::Create(this, core::mem::transmute_copy(&cbwhereabouts), core::mem::transmute_copy(&rgbwhereabouts)) {
+                    Ok(ok__) => {
+                        ppexport.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetRemoteClassId: GetRemoteClassId::<Identity>,
+            Create: Create::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITransactionExportFactory {}
 windows_core::imp::define_interface!(ITransactionImport, ITransactionImport_Vtbl, 0xe1cf9b5a_8745_11ce_a9ba_00aa006c3706);
 windows_core::imp::interface_hierarchy!(ITransactionImport, windows_core::IUnknown);
@@ -3080,6 +4588,20 @@ impl ITransactionImport_Vtbl {
         iid == &<ITransactionImport as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITransactionImport_Impl> windows_core::imp::TearOffVtable<Identity> for ITransactionImport_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Import<Identity: windows_core::IUnknownImpl + ITransactionImport_Impl>(this: *mut core::ffi::c_void, cbtransactioncookie: u32, rgbtransactioncookie: *const u8, piid: *const windows_core::GUID, ppvtransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionImport_Impl::Import(this, core::mem::transmute_copy(&cbtransactioncookie), core::mem::transmute_copy(&rgbtransactioncookie), core::mem::transmute_copy(&piid), core::mem::transmute_copy(&ppvtransaction)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Import: Import::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ITransactionImport {}
 windows_core::imp::define_interface!(ITransactionImportWhereabouts, ITransactionImportWhereabouts_Vtbl, 0x0141fda4_8fc0_11ce_bd18_204c4f4f5020);
 windows_core::imp::interface_hierarchy!(ITransactionImportWhereabouts, windows_core::IUnknown);
@@ -3135,6 +4657,36 @@ impl ITransactionImportWhereabouts_Vtbl {
         iid == &<ITransactionImportWhereabouts as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITransactionImportWhereabouts_Impl> windows_core::imp::TearOffVtable<Identity> for ITransactionImportWhereabouts_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetWhereaboutsSize<Identity: windows_core::IUnknownImpl + ITransactionImportWhereabouts_Impl>(this: *mut core::ffi::c_void, pcbwhereabouts: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITransactionImportWhereabouts_Impl::GetWhereaboutsSize(this) {
+                    Ok(ok__) => {
+                        pcbwhereabouts.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetWhereabouts<Identity: windows_core::IUnknownImpl + ITransactionImportWhereabouts_Impl>(this: *mut core::ffi::c_void, cbwhereabouts: u32, rgbwhereabouts: *mut u8, pcbused: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionImportWhereabouts_Impl::GetWhereabouts(this, core::mem::transmute_copy(&cbwhereabouts), core::mem::transmute_copy(&rgbwhereabouts), core::mem::transmute_copy(&pcbused)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetWhereaboutsSize: GetWhereaboutsSize::<Identity>,
+            GetWhereabouts: GetWhereabouts::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITransactionImportWhereabouts {}
 windows_core::imp::define_interface!(ITransactionLastEnlistmentAsync, ITransactionLastEnlistmentAsync_Vtbl, 0xc82bd533_5b30_11d3_8a91_00c04f79eb6d);
 windows_core::imp::interface_hierarchy!(ITransactionLastEnlistmentAsync, windows_core::IUnknown);
@@ -3166,6 +4718,20 @@ impl ITransactionLastEnlistmentAsync_Vtbl {
         iid == &<ITransactionLastEnlistmentAsync as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITransactionLastEnlistmentAsync_Impl> windows_core::imp::TearOffVtable<Identity> for ITransactionLastEnlistmentAsync_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn TransactionOutcome<Identity: windows_core::IUnknownImpl + ITransactionLastEnlistmentAsync_Impl>(this: *mut core::ffi::c_void, xactstat: XACTSTAT, pboidreason: *const BOID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionLastEnlistmentAsync_Impl::TransactionOutcome(this, core::mem::transmute_copy(&xactstat), core::mem::transmute_copy(&pboidreason)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, TransactionOutcome: TransactionOutcome::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ITransactionLastEnlistmentAsync {}
 windows_core::imp::define_interface!(ITransactionLastResourceAsync, ITransactionLastResourceAsync_Vtbl, 0xc82bd532_5b30_11d3_8a91_00c04f79eb6d);
 windows_core::imp::interface_hierarchy!(ITransactionLastResourceAsync, windows_core::IUnknown);
@@ -3212,6 +4778,30 @@ impl ITransactionLastResourceAsync_Vtbl {
         iid == &<ITransactionLastResourceAsync as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITransactionLastResourceAsync_Impl> windows_core::imp::TearOffVtable<Identity> for ITransactionLastResourceAsync_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DelegateCommit<Identity: windows_core::IUnknownImpl + ITransactionLastResourceAsync_Impl>(this: *mut core::ffi::c_void, grfrm: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionLastResourceAsync_Impl::DelegateCommit(this, core::mem::transmute_copy(&grfrm)).into()
+            }
+        }
+        unsafe extern "system" fn ForgetRequest<Identity: windows_core::IUnknownImpl + ITransactionLastResourceAsync_Impl>(this: *mut core::ffi::c_void, pnewuow: *const BOID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionLastResourceAsync_Impl::ForgetRequest(this, core::mem::transmute_copy(&pnewuow)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DelegateCommit: DelegateCommit::<Identity>,
+            ForgetRequest: ForgetRequest::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITransactionLastResourceAsync {}
 windows_core::imp::define_interface!(ITransactionOptions, ITransactionOptions_Vtbl, 0x3a6ad9e0_23b9_11cf_ad60_00aa00a74ccd);
 windows_core::imp::interface_hierarchy!(ITransactionOptions, windows_core::IUnknown);
@@ -3258,6 +4848,30 @@ impl ITransactionOptions_Vtbl {
         iid == &<ITransactionOptions as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITransactionOptions_Impl> windows_core::imp::TearOffVtable<Identity> for ITransactionOptions_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetOptions<Identity: windows_core::IUnknownImpl + ITransactionOptions_Impl>(this: *mut core::ffi::c_void, poptions: *const XACTOPT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionOptions_Impl::SetOptions(this, core::mem::transmute_copy(&poptions)).into()
+            }
+        }
+        unsafe extern "system" fn GetOptions<Identity: windows_core::IUnknownImpl + ITransactionOptions_Impl>(this: *mut core::ffi::c_void, poptions: *mut XACTOPT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionOptions_Impl::GetOptions(this, core::mem::transmute_copy(&poptions)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetOptions: SetOptions::<Identity>,
+            GetOptions: GetOptions::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITransactionOptions {}
 windows_core::imp::define_interface!(ITransactionOutcomeEvents, ITransactionOutcomeEvents_Vtbl, 0x3a6ad9e2_23b9_11cf_ad60_00aa00a74ccd);
 windows_core::imp::interface_hierarchy!(ITransactionOutcomeEvents, windows_core::IUnknown);
@@ -3328,6 +4942,44 @@ impl ITransactionOutcomeEvents_Vtbl {
         iid == &<ITransactionOutcomeEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITransactionOutcomeEvents_Impl> windows_core::imp::TearOffVtable<Identity> for ITransactionOutcomeEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Committed<Identity: windows_core::IUnknownImpl + ITransactionOutcomeEvents_Impl>(this: *mut core::ffi::c_void, fretaining: windows_core::BOOL, pnewuow: *const BOID, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionOutcomeEvents_Impl::Committed(this, core::mem::transmute_copy(&fretaining), core::mem::transmute_copy(&pnewuow), core::mem::transmute_copy(&hr)).into()
+            }
+        }
+        unsafe extern "system" fn Aborted<Identity: windows_core::IUnknownImpl + ITransactionOutcomeEvents_Impl>(this: *mut core::ffi::c_void, pboidreason: *const BOID, fretaining: windows_core::BOOL, pnewuow: *const BOID, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionOutcomeEvents_Impl::Aborted(this, core::mem::transmute_copy(&pboidreason), core::mem::transmute_copy(&fretaining), core::mem::transmute_copy(&pnewuow), core::mem::transmute_copy(&hr)).into()
+            }
+        }
+        unsafe extern "system" fn HeuristicDecision<Identity: windows_core::IUnknownImpl + ITransactionOutcomeEvents_Impl>(this: *mut core::ffi::c_void, dwdecision: u32, pboidreason: *const BOID, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionOutcomeEvents_Impl::HeuristicDecision(this, core::mem::transmute_copy(&dwdecision), core::mem::transmute_copy(&pboidreason), core::mem::transmute_copy(&hr)).into()
+            }
+        }
+        unsafe extern "system" fn Indoubt<Identity: windows_core::IUnknownImpl + ITransactionOutcomeEvents_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionOutcomeEvents_Impl::Indoubt(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Committed: Committed::<Identity>,
+            Aborted: Aborted::<Identity>,
+            HeuristicDecision: HeuristicDecision::<Identity>,
+            Indoubt: Indoubt::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITransactionOutcomeEvents {}
 windows_core::imp::define_interface!(ITransactionPhase0EnlistmentAsync, ITransactionPhase0EnlistmentAsync_Vtbl, 0x82dc88e1_a954_11d1_8f88_00600895e7d5);
 windows_core::imp::interface_hierarchy!(ITransactionPhase0EnlistmentAsync, windows_core::IUnknown);
@@ -3368,35 +5020,89 @@ pub trait ITransactionPhase0EnlistmentAsync_Impl: windows_core::IUnknownImpl {
     fn Unenlist(&self) -> windows_core::Result<()>;
     fn GetTransaction(&self) -> windows_core::Result<ITransaction>;
 }
-impl ITransactionPhase0EnlistmentAsync_Vtbl {
-    pub const fn new<Identity: ITransactionPhase0EnlistmentAsync_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn Enable<Identity: ITransactionPhase0EnlistmentAsync_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl ITransactionPhase0EnlistmentAsync_Vtbl {
+    pub const fn new<Identity: ITransactionPhase0EnlistmentAsync_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn Enable<Identity: ITransactionPhase0EnlistmentAsync_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITransactionPhase0EnlistmentAsync_Impl::Enable(this).into()
+            }
+        }
+        unsafe extern "system" fn WaitForEnlistment<Identity: ITransactionPhase0EnlistmentAsync_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITransactionPhase0EnlistmentAsync_Impl::WaitForEnlistment(this).into()
+            }
+        }
+        unsafe extern "system" fn Phase0Done<Identity: ITransactionPhase0EnlistmentAsync_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITransactionPhase0EnlistmentAsync_Impl::Phase0Done(this).into()
+            }
+        }
+        unsafe extern "system" fn Unenlist<Identity: ITransactionPhase0EnlistmentAsync_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITransactionPhase0EnlistmentAsync_Impl::Unenlist(this).into()
+            }
+        }
+        unsafe extern "system" fn GetTransaction<Identity: ITransactionPhase0EnlistmentAsync_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppitransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITransactionPhase0EnlistmentAsync_Impl::GetTransaction(this) {
+                    Ok(ok__) => {
+                        ppitransaction.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            Enable: Enable::<Identity, OFFSET>,
+            WaitForEnlistment: WaitForEnlistment::<Identity, OFFSET>,
+            Phase0Done: Phase0Done::<Identity, OFFSET>,
+            Unenlist: Unenlist::<Identity, OFFSET>,
+            GetTransaction: GetTransaction::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionPhase0EnlistmentAsync as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + ITransactionPhase0EnlistmentAsync_Impl> windows_core::imp::TearOffVtable<Identity> for ITransactionPhase0EnlistmentAsync_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Enable<Identity: windows_core::IUnknownImpl + ITransactionPhase0EnlistmentAsync_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITransactionPhase0EnlistmentAsync_Impl::Enable(this).into()
             }
         }
-        unsafe extern "system" fn WaitForEnlistment<Identity: ITransactionPhase0EnlistmentAsync_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn WaitForEnlistment<Identity: windows_core::IUnknownImpl + ITransactionPhase0EnlistmentAsync_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITransactionPhase0EnlistmentAsync_Impl::WaitForEnlistment(this).into()
             }
         }
-        unsafe extern "system" fn Phase0Done<Identity: ITransactionPhase0EnlistmentAsync_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Phase0Done<Identity: windows_core::IUnknownImpl + ITransactionPhase0EnlistmentAsync_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITransactionPhase0EnlistmentAsync_Impl::Phase0Done(this).into()
             }
         }
-        unsafe extern "system" fn Unenlist<Identity: ITransactionPhase0EnlistmentAsync_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Unenlist<Identity: windows_core::IUnknownImpl + ITransactionPhase0EnlistmentAsync_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITransactionPhase0EnlistmentAsync_Impl::Unenlist(this).into()
             }
         }
-        unsafe extern "system" fn GetTransaction<Identity: ITransactionPhase0EnlistmentAsync_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppitransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetTransaction<Identity: windows_core::IUnknownImpl + ITransactionPhase0EnlistmentAsync_Impl>(this: *mut core::ffi::c_void, ppitransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITransactionPhase0EnlistmentAsync_Impl::GetTransaction(this) {
                     Ok(ok__) => {
                         ppitransaction.write(core::mem::transmute(ok__));
@@ -3407,17 +5113,14 @@ impl ITransactionPhase0EnlistmentAsync_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            Enable: Enable::<Identity, OFFSET>,
-            WaitForEnlistment: WaitForEnlistment::<Identity, OFFSET>,
-            Phase0Done: Phase0Done::<Identity, OFFSET>,
-            Unenlist: Unenlist::<Identity, OFFSET>,
-            GetTransaction: GetTransaction::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Enable: Enable::<Identity>,
+            WaitForEnlistment: WaitForEnlistment::<Identity>,
+            Phase0Done: Phase0Done::<Identity>,
+            Unenlist: Unenlist::<Identity>,
+            GetTransaction: GetTransaction::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ITransactionPhase0EnlistmentAsync as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for ITransactionPhase0EnlistmentAsync {}
 windows_core::imp::define_interface!(ITransactionPhase0Factory, ITransactionPhase0Factory_Vtbl, 0x82dc88e0_a954_11d1_8f88_00600895e7d5);
@@ -3462,6 +5165,26 @@ impl ITransactionPhase0Factory_Vtbl {
         iid == &<ITransactionPhase0Factory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITransactionPhase0Factory_Impl> windows_core::imp::TearOffVtable<Identity> for ITransactionPhase0Factory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Create<Identity: windows_core::IUnknownImpl + ITransactionPhase0Factory_Impl>(this: *mut core::ffi::c_void, pphase0notify: *mut core::ffi::c_void, ppphase0enlistment: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITransactionPhase0Factory_Impl::Create(this, core::mem::transmute_copy(&pphase0notify)) {
+                    Ok(ok__) => {
+                        ppphase0enlistment.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Create: Create::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ITransactionPhase0Factory {}
 windows_core::imp::define_interface!(ITransactionPhase0NotifyAsync, ITransactionPhase0NotifyAsync_Vtbl, 0xef081809_0c76_11d2_87a6_00c04f990f34);
 windows_core::imp::interface_hierarchy!(ITransactionPhase0NotifyAsync, windows_core::IUnknown);
@@ -3508,6 +5231,30 @@ impl ITransactionPhase0NotifyAsync_Vtbl {
         iid == &<ITransactionPhase0NotifyAsync as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITransactionPhase0NotifyAsync_Impl> windows_core::imp::TearOffVtable<Identity> for ITransactionPhase0NotifyAsync_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Phase0Request<Identity: windows_core::IUnknownImpl + ITransactionPhase0NotifyAsync_Impl>(this: *mut core::ffi::c_void, fabortinghint: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionPhase0NotifyAsync_Impl::Phase0Request(this, core::mem::transmute_copy(&fabortinghint)).into()
+            }
+        }
+        unsafe extern "system" fn EnlistCompleted<Identity: windows_core::IUnknownImpl + ITransactionPhase0NotifyAsync_Impl>(this: *mut core::ffi::c_void, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionPhase0NotifyAsync_Impl::EnlistCompleted(this, core::mem::transmute_copy(&status)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Phase0Request: Phase0Request::<Identity>,
+            EnlistCompleted: EnlistCompleted::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITransactionPhase0NotifyAsync {}
 windows_core::imp::define_interface!(ITransactionReceiver, ITransactionReceiver_Vtbl, 0x59313e03_b36c_11cf_a539_00aa006887c3);
 windows_core::imp::interface_hierarchy!(ITransactionReceiver, windows_core::IUnknown);
@@ -3596,6 +5343,56 @@ impl ITransactionReceiver_Vtbl {
         iid == &<ITransactionReceiver as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITransactionReceiver_Impl> windows_core::imp::TearOffVtable<Identity> for ITransactionReceiver_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn UnmarshalPropagationToken<Identity: windows_core::IUnknownImpl + ITransactionReceiver_Impl>(this: *mut core::ffi::c_void, cbtoken: u32, rgbtoken: *const u8, pptransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITransactionReceiver_Impl::UnmarshalPropagationToken(this, core::mem::transmute_copy(&cbtoken), core::mem::transmute_copy(&rgbtoken)) {
+                    Ok(ok__) => {
+                        pptransaction.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetReturnTokenSize<Identity: windows_core::IUnknownImpl + ITransactionReceiver_Impl>(this: *mut core::ffi::c_void, pcbreturntoken: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITransactionReceiver_Impl::GetReturnTokenSize(this) {
+                    Ok(ok__) => {
+                        pcbreturntoken.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MarshalReturnToken<Identity: windows_core::IUnknownImpl + ITransactionReceiver_Impl>(this: *mut core::ffi::c_void, cbreturntoken: u32, rgbreturntoken: *mut u8, pcbused: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionReceiver_Impl::MarshalReturnToken(this, core::mem::transmute_copy(&cbreturntoken), core::mem::transmute_copy(&rgbreturntoken), core::mem::transmute_copy(&pcbused)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + ITransactionReceiver_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionReceiver_Impl::Reset(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            UnmarshalPropagationToken: UnmarshalPropagationToken::<Identity>,
+            GetReturnTokenSize: GetReturnTokenSize::<Identity>,
+            MarshalReturnToken: MarshalReturnToken::<Identity>,
+            Reset: Reset::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITransactionReceiver {}
 windows_core::imp::define_interface!(ITransactionReceiverFactory, ITransactionReceiverFactory_Vtbl, 0x59313e02_b36c_11cf_a539_00aa006887c3);
 windows_core::imp::interface_hierarchy!(ITransactionReceiverFactory, windows_core::IUnknown);
@@ -3636,6 +5433,26 @@ impl ITransactionReceiverFactory_Vtbl {
         iid == &<ITransactionReceiverFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITransactionReceiverFactory_Impl> windows_core::imp::TearOffVtable<Identity> for ITransactionReceiverFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Create<Identity: windows_core::IUnknownImpl + ITransactionReceiverFactory_Impl>(this: *mut core::ffi::c_void, ppreceiver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITransactionReceiverFactory_Impl::Create(this) {
+                    Ok(ok__) => {
+                        ppreceiver.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Create: Create::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ITransactionReceiverFactory {}
 windows_core::imp::define_interface!(ITransactionResource, ITransactionResource_Vtbl, 0xee5ff7b3_4572_11d0_9452_00a0c905416e);
 windows_core::imp::interface_hierarchy!(ITransactionResource, windows_core::IUnknown);
@@ -3706,6 +5523,44 @@ impl ITransactionResource_Vtbl {
         iid == &<ITransactionResource as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITransactionResource_Impl> windows_core::imp::TearOffVtable<Identity> for ITransactionResource_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn PrepareRequest<Identity: windows_core::IUnknownImpl + ITransactionResource_Impl>(this: *mut core::ffi::c_void, fretaining: windows_core::BOOL, grfrm: u32, fwantmoniker: windows_core::BOOL, fsinglephase: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionResource_Impl::PrepareRequest(this, core::mem::transmute_copy(&fretaining), core::mem::transmute_copy(&grfrm), core::mem::transmute_copy(&fwantmoniker), core::mem::transmute_copy(&fsinglephase)).into()
+            }
+        }
+        unsafe extern "system" fn CommitRequest<Identity: windows_core::IUnknownImpl + ITransactionResource_Impl>(this: *mut core::ffi::c_void, grfrm: u32, pnewuow: *const BOID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionResource_Impl::CommitRequest(this, core::mem::transmute_copy(&grfrm), core::mem::transmute_copy(&pnewuow)).into()
+            }
+        }
+        unsafe extern "system" fn AbortRequest<Identity: windows_core::IUnknownImpl + ITransactionResource_Impl>(this: *mut core::ffi::c_void, pboidreason: *const BOID, fretaining: windows_core::BOOL, pnewuow: *const BOID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionResource_Impl::AbortRequest(this, core::mem::transmute_copy(&pboidreason), core::mem::transmute_copy(&fretaining), core::mem::transmute_copy(&pnewuow)).into()
+            }
+        }
+        unsafe extern "system" fn TMDown<Identity: windows_core::IUnknownImpl + ITransactionResource_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionResource_Impl::TMDown(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PrepareRequest: PrepareRequest::<Identity>,
+            CommitRequest: CommitRequest::<Identity>,
+            AbortRequest: AbortRequest::<Identity>,
+            TMDown: TMDown::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITransactionResource {}
 windows_core::imp::define_interface!(ITransactionResourceAsync, ITransactionResourceAsync_Vtbl, 0x69e971f0_23ce_11cf_ad60_00aa00a74ccd);
 windows_core::imp::interface_hierarchy!(ITransactionResourceAsync, windows_core::IUnknown);
@@ -3776,6 +5631,44 @@ impl ITransactionResourceAsync_Vtbl {
         iid == &<ITransactionResourceAsync as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITransactionResourceAsync_Impl> windows_core::imp::TearOffVtable<Identity> for ITransactionResourceAsync_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn PrepareRequest<Identity: windows_core::IUnknownImpl + ITransactionResourceAsync_Impl>(this: *mut core::ffi::c_void, fretaining: windows_core::BOOL, grfrm: u32, fwantmoniker: windows_core::BOOL, fsinglephase: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionResourceAsync_Impl::PrepareRequest(this, core::mem::transmute_copy(&fretaining), core::mem::transmute_copy(&grfrm), core::mem::transmute_copy(&fwantmoniker), core::mem::transmute_copy(&fsinglephase)).into()
+            }
+        }
+        unsafe extern "system" fn CommitRequest<Identity: windows_core::IUnknownImpl + ITransactionResourceAsync_Impl>(this: *mut core::ffi::c_void, grfrm: u32, pnewuow: *const BOID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionResourceAsync_Impl::CommitRequest(this, core::mem::transmute_copy(&grfrm), core::mem::transmute_copy(&pnewuow)).into()
+            }
+        }
+        unsafe extern "system" fn AbortRequest<Identity: windows_core::IUnknownImpl + ITransactionResourceAsync_Impl>(this: *mut core::ffi::c_void, pboidreason: *const BOID, fretaining: windows_core::BOOL, pnewuow: *const BOID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionResourceAsync_Impl::AbortRequest(this, core::mem::transmute_copy(&pboidreason), core::mem::transmute_copy(&fretaining), core::mem::transmute_copy(&pnewuow)).into()
+            }
+        }
+        unsafe extern "system" fn TMDown<Identity: windows_core::IUnknownImpl + ITransactionResourceAsync_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionResourceAsync_Impl::TMDown(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PrepareRequest: PrepareRequest::<Identity>,
+            CommitRequest: CommitRequest::<Identity>,
+            AbortRequest: AbortRequest::<Identity>,
+            TMDown: TMDown::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITransactionResourceAsync {}
 windows_core::imp::define_interface!(ITransactionTransmitter, ITransactionTransmitter_Vtbl, 0x59313e01_b36c_11cf_a539_00aa006887c3);
 windows_core::imp::interface_hierarchy!(ITransactionTransmitter, windows_core::IUnknown);
@@ -3870,6 +5763,57 @@ impl ITransactionTransmitter_Vtbl {
         iid == &<ITransactionTransmitter as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITransactionTransmitter_Impl> windows_core::imp::TearOffVtable<Identity> for ITransactionTransmitter_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Set<Identity: windows_core::IUnknownImpl + ITransactionTransmitter_Impl>(this: *mut core::ffi::c_void, ptransaction: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionTransmitter_Impl::Set(this, core::mem::transmute_copy(&ptransaction)).into()
+            }
+        }
+        unsafe extern "system" fn GetPropagationTokenSize<Identity: windows_core::IUnknownImpl + ITransactionTransmitter_Impl>(this: *mut core::ffi::c_void, pcbtoken: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITransactionTransmitter_Impl::GetPropagationTokenSize(this) {
+                    Ok(ok__) => {
+                        pcbtoken.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MarshalPropagationToken<Identity: windows_core::IUnknownImpl + ITransactionTransmitter_Impl>(this: *mut core::ffi::c_void, cbtoken: u32, rgbtoken: *mut u8, pcbused: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionTransmitter_Impl::MarshalPropagationToken(this, core::mem::transmute_copy(&cbtoken), core::mem::transmute_copy(&rgbtoken), core::mem::transmute_copy(&pcbused)).into()
+            }
+        }
+        unsafe extern "system" fn UnmarshalReturnToken<Identity: windows_core::IUnknownImpl + ITransactionTransmitter_Impl>(this: *mut core::ffi::c_void, cbreturntoken: u32, rgbreturntoken: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionTransmitter_Impl::UnmarshalReturnToken(this, core::mem::transmute_copy(&cbreturntoken), core::mem::transmute_copy(&rgbreturntoken)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + ITransactionTransmitter_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionTransmitter_Impl::Reset(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Set: Set::<Identity>,
+            GetPropagationTokenSize: GetPropagationTokenSize::<Identity>,
+            MarshalPropagationToken: MarshalPropagationToken::<Identity>,
+            UnmarshalReturnToken: UnmarshalReturnToken::<Identity>,
+            Reset: Reset::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITransactionTransmitter {}
 windows_core::imp::define_interface!(ITransactionTransmitterFactory, ITransactionTransmitterFactory_Vtbl, 0x59313e00_b36c_11cf_a539_00aa006887c3);
 windows_core::imp::interface_hierarchy!(ITransactionTransmitterFactory, windows_core::IUnknown);
@@ -3910,6 +5854,26 @@ impl ITransactionTransmitterFactory_Vtbl {
         iid == &<ITransactionTransmitterFactory as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITransactionTransmitterFactory_Impl> windows_core::imp::TearOffVtable<Identity> for ITransactionTransmitterFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Create<Identity: windows_core::IUnknownImpl + ITransactionTransmitterFactory_Impl>(this: *mut core::ffi::c_void, pptransmitter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITransactionTransmitterFactory_Impl::Create(this) {
+                    Ok(ok__) => {
+                        pptransmitter.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Create: Create::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ITransactionTransmitterFactory {}
 windows_core::imp::define_interface!(ITransactionVoterBallotAsync2, ITransactionVoterBallotAsync2_Vtbl, 0x5433376c_414d_11d3_b206_00c04fc2f3ef);
 windows_core::imp::interface_hierarchy!(ITransactionVoterBallotAsync2, windows_core::IUnknown);
@@ -3941,6 +5905,20 @@ impl ITransactionVoterBallotAsync2_Vtbl {
         iid == &<ITransactionVoterBallotAsync2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITransactionVoterBallotAsync2_Impl> windows_core::imp::TearOffVtable<Identity> for ITransactionVoterBallotAsync2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn VoteRequestDone<Identity: windows_core::IUnknownImpl + ITransactionVoterBallotAsync2_Impl>(this: *mut core::ffi::c_void, hr: windows_core::HRESULT, pboidreason: *const BOID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionVoterBallotAsync2_Impl::VoteRequestDone(this, core::mem::transmute_copy(&hr), core::mem::transmute_copy(&pboidreason)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, VoteRequestDone: VoteRequestDone::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ITransactionVoterBallotAsync2 {}
 windows_core::imp::define_interface!(ITransactionVoterFactory2, ITransactionVoterFactory2_Vtbl, 0x5433376a_414d_11d3_b206_00c04fc2f3ef);
 windows_core::imp::interface_hierarchy!(ITransactionVoterFactory2, windows_core::IUnknown);
@@ -3985,6 +5963,26 @@ impl ITransactionVoterFactory2_Vtbl {
         iid == &<ITransactionVoterFactory2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITransactionVoterFactory2_Impl> windows_core::imp::TearOffVtable<Identity> for ITransactionVoterFactory2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Create<Identity: windows_core::IUnknownImpl + ITransactionVoterFactory2_Impl>(this: *mut core::ffi::c_void, ptransaction: *mut core::ffi::c_void, pvoternotify: *mut core::ffi::c_void, ppvoterballot: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITransactionVoterFactory2_Impl::Create(this, core::mem::transmute_copy(&ptransaction), core::mem::transmute_copy(&pvoternotify)) {
+                    Ok(ok__) => {
+                        ppvoterballot.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Create: Create::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ITransactionVoterFactory2 {}
 windows_core::imp::define_interface!(ITransactionVoterNotifyAsync2, ITransactionVoterNotifyAsync2_Vtbl, 0x5433376b_414d_11d3_b206_00c04fc2f3ef);
 impl core::ops::Deref for ITransactionVoterNotifyAsync2 {
@@ -4022,6 +6020,20 @@ impl ITransactionVoterNotifyAsync2_Vtbl {
         iid == &<ITransactionVoterNotifyAsync2 as windows_core::Interface>::IID || iid == &<ITransactionOutcomeEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITransactionVoterNotifyAsync2_Impl> windows_core::imp::TearOffVtable<Identity> for ITransactionVoterNotifyAsync2_Vtbl
+where
+    ITransactionOutcomeEvents_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn VoteRequest<Identity: windows_core::IUnknownImpl + ITransactionVoterNotifyAsync2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransactionVoterNotifyAsync2_Impl::VoteRequest(this).into()
+            }
+        }
+        Self { base__: <ITransactionOutcomeEvents_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, VoteRequest: VoteRequest::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ITransactionVoterNotifyAsync2 {}
 windows_core::imp::define_interface!(IXAConfig, IXAConfig_Vtbl, 0xc8a6e3a1_9a8c_11cf_a308_00a0c905416e);
 windows_core::imp::interface_hierarchy!(IXAConfig, windows_core::IUnknown);
@@ -4068,6 +6080,30 @@ impl IXAConfig_Vtbl {
         iid == &<IXAConfig as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXAConfig_Impl> windows_core::imp::TearOffVtable<Identity> for IXAConfig_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IXAConfig_Impl>(this: *mut core::ffi::c_void, clsidhelperdll: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAConfig_Impl::Initialize(this, core::mem::transmute(&clsidhelperdll)).into()
+            }
+        }
+        unsafe extern "system" fn Terminate<Identity: windows_core::IUnknownImpl + IXAConfig_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAConfig_Impl::Terminate(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            Terminate: Terminate::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IXAConfig {}
 windows_core::imp::define_interface!(IXAObtainRMInfo, IXAObtainRMInfo_Vtbl, 0xe793f6d2_f53d_11cf_a60d_00a0c905416e);
 windows_core::imp::interface_hierarchy!(IXAObtainRMInfo, windows_core::IUnknown);
@@ -4102,6 +6138,20 @@ impl IXAObtainRMInfo_Vtbl {
         iid == &<IXAObtainRMInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXAObtainRMInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IXAObtainRMInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ObtainRMInfo<Identity: windows_core::IUnknownImpl + IXAObtainRMInfo_Impl>(this: *mut core::ffi::c_void, pirmhelper: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IXAObtainRMInfo_Impl::ObtainRMInfo(this, core::mem::transmute_copy(&pirmhelper)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ObtainRMInfo: ObtainRMInfo::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IXAObtainRMInfo {}
 windows_core::imp::define_interface!(IXATransLookup, IXATransLookup_Vtbl, 0xf3b1f131_eeda_11ce_aed4_00aa0051e2c4);
 windows_core::imp::interface_hierarchy!(IXATransLookup, windows_core::IUnknown);
@@ -4142,6 +6192,26 @@ impl IXATransLookup_Vtbl {
         iid == &<IXATransLookup as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXATransLookup_Impl> windows_core::imp::TearOffVtable<Identity> for IXATransLookup_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Lookup<Identity: windows_core::IUnknownImpl + IXATransLookup_Impl>(this: *mut core::ffi::c_void, pptransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXATransLookup_Impl::Lookup(this) {
+                    Ok(ok__) => {
+                        pptransaction.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Lookup: Lookup::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IXATransLookup {}
 windows_core::imp::define_interface!(IXATransLookup2, IXATransLookup2_Vtbl, 0xbf193c85_0d1a_4290_b88f_d2cb8873d1e7);
 windows_core::imp::interface_hierarchy!(IXATransLookup2, windows_core::IUnknown);
@@ -4182,6 +6252,26 @@ impl IXATransLookup2_Vtbl {
         iid == &<IXATransLookup2 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IXATransLookup2_Impl> windows_core::imp::TearOffVtable<Identity> for IXATransLookup2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Lookup<Identity: windows_core::IUnknownImpl + IXATransLookup2_Impl>(this: *mut core::ffi::c_void, pxid: *const XID, pptransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IXATransLookup2_Impl::Lookup(this, core::mem::transmute_copy(&pxid)) {
+                    Ok(ok__) => {
+                        pptransaction.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Lookup: Lookup::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IXATransLookup2 {}
 pub const LOCAL_APPLICATIONTYPE: APPLICATIONTYPE = APPLICATIONTYPE(0i32);
 pub const MAXBQUALSIZE: u32 = 64u32;
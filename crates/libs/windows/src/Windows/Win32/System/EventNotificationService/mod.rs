@@ -141,6 +141,66 @@ impl ISensLogon_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISensLogon_Impl> windows_core::imp::TearOffVtable<Identity> for ISensLogon_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Logon<Identity: windows_core::IUnknownImpl + ISensLogon_Impl>(this: *mut core::ffi::c_void, bstrusername: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensLogon_Impl::Logon(this, core::mem::transmute(&bstrusername)).into()
+            }
+        }
+        unsafe extern "system" fn Logoff<Identity: windows_core::IUnknownImpl + ISensLogon_Impl>(this: *mut core::ffi::c_void, bstrusername: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensLogon_Impl::Logoff(this, core::mem::transmute(&bstrusername)).into()
+            }
+        }
+        unsafe extern "system" fn StartShell<Identity: windows_core::IUnknownImpl + ISensLogon_Impl>(this: *mut core::ffi::c_void, bstrusername: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensLogon_Impl::StartShell(this, core::mem::transmute(&bstrusername)).into()
+            }
+        }
+        unsafe extern "system" fn DisplayLock<Identity: windows_core::IUnknownImpl + ISensLogon_Impl>(this: *mut core::ffi::c_void, bstrusername: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensLogon_Impl::DisplayLock(this, core::mem::transmute(&bstrusername)).into()
+            }
+        }
+        unsafe extern "system" fn DisplayUnlock<Identity: windows_core::IUnknownImpl + ISensLogon_Impl>(this: *mut core::ffi::c_void, bstrusername: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensLogon_Impl::DisplayUnlock(this, core::mem::transmute(&bstrusername)).into()
+            }
+        }
+        unsafe extern "system" fn StartScreenSaver<Identity: windows_core::IUnknownImpl + ISensLogon_Impl>(this: *mut core::ffi::c_void, bstrusername: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensLogon_Impl::StartScreenSaver(this, core::mem::transmute(&bstrusername)).into()
+            }
+        }
+        unsafe extern "system" fn StopScreenSaver<Identity: windows_core::IUnknownImpl + ISensLogon_Impl>(this: *mut core::ffi::c_void, bstrusername: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensLogon_Impl::StopScreenSaver(this, core::mem::transmute(&bstrusername)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Logon: Logon::<Identity>,
+            Logoff: Logoff::<Identity>,
+            StartShell: StartShell::<Identity>,
+            DisplayLock: DisplayLock::<Identity>,
+            DisplayUnlock: DisplayUnlock::<Identity>,
+            StartScreenSaver: StartScreenSaver::<Identity>,
+            StopScreenSaver: StopScreenSaver::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISensLogon {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISensLogon2, ISensLogon2_Vtbl, 0xd597bab4_5b9f_11d1_8dd2_00aa004abd5e);
@@ -237,6 +297,52 @@ impl ISensLogon2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISensLogon2_Impl> windows_core::imp::TearOffVtable<Identity> for ISensLogon2_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Logon<Identity: windows_core::IUnknownImpl + ISensLogon2_Impl>(this: *mut core::ffi::c_void, bstrusername: *mut core::ffi::c_void, dwsessionid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensLogon2_Impl::Logon(this, core::mem::transmute(&bstrusername), core::mem::transmute_copy(&dwsessionid)).into()
+            }
+        }
+        unsafe extern "system" fn Logoff<Identity: windows_core::IUnknownImpl + ISensLogon2_Impl>(this: *mut core::ffi::c_void, bstrusername: *mut core::ffi::c_void, dwsessionid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensLogon2_Impl::Logoff(this, core::mem::transmute(&bstrusername), core::mem::transmute_copy(&dwsessionid)).into()
+            }
+        }
+        unsafe extern "system" fn SessionDisconnect<Identity: windows_core::IUnknownImpl + ISensLogon2_Impl>(this: *mut core::ffi::c_void, bstrusername: *mut core::ffi::c_void, dwsessionid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensLogon2_Impl::SessionDisconnect(this, core::mem::transmute(&bstrusername), core::mem::transmute_copy(&dwsessionid)).into()
+            }
+        }
+        unsafe extern "system" fn SessionReconnect<Identity: windows_core::IUnknownImpl + ISensLogon2_Impl>(this: *mut core::ffi::c_void, bstrusername: *mut core::ffi::c_void, dwsessionid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensLogon2_Impl::SessionReconnect(this, core::mem::transmute(&bstrusername), core::mem::transmute_copy(&dwsessionid)).into()
+            }
+        }
+        unsafe extern "system" fn PostShell<Identity: windows_core::IUnknownImpl + ISensLogon2_Impl>(this: *mut core::ffi::c_void, bstrusername: *mut core::ffi::c_void, dwsessionid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensLogon2_Impl::PostShell(this, core::mem::transmute(&bstrusername), core::mem::transmute_copy(&dwsessionid)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Logon: Logon::<Identity>,
+            Logoff: Logoff::<Identity>,
+            SessionDisconnect: SessionDisconnect::<Identity>,
+            SessionReconnect: SessionReconnect::<Identity>,
+            PostShell: PostShell::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISensLogon2 {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISensNetwork, ISensNetwork_Vtbl, 0xd597bab1_5b9f_11d1_8dd2_00aa004abd5e);
@@ -333,6 +439,52 @@ impl ISensNetwork_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISensNetwork_Impl> windows_core::imp::TearOffVtable<Identity> for ISensNetwork_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ConnectionMade<Identity: windows_core::IUnknownImpl + ISensNetwork_Impl>(this: *mut core::ffi::c_void, bstrconnection: *mut core::ffi::c_void, ultype: u32, lpqocinfo: *const SENS_QOCINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensNetwork_Impl::ConnectionMade(this, core::mem::transmute(&bstrconnection), core::mem::transmute_copy(&ultype), core::mem::transmute_copy(&lpqocinfo)).into()
+            }
+        }
+        unsafe extern "system" fn ConnectionMadeNoQOCInfo<Identity: windows_core::IUnknownImpl + ISensNetwork_Impl>(this: *mut core::ffi::c_void, bstrconnection: *mut core::ffi::c_void, ultype: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensNetwork_Impl::ConnectionMadeNoQOCInfo(this, core::mem::transmute(&bstrconnection), core::mem::transmute_copy(&ultype)).into()
+            }
+        }
+        unsafe extern "system" fn ConnectionLost<Identity: windows_core::IUnknownImpl + ISensNetwork_Impl>(this: *mut core::ffi::c_void, bstrconnection: *mut core::ffi::c_void, ultype: SENS_CONNECTION_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensNetwork_Impl::ConnectionLost(this, core::mem::transmute(&bstrconnection), core::mem::transmute_copy(&ultype)).into()
+            }
+        }
+        unsafe extern "system" fn DestinationReachable<Identity: windows_core::IUnknownImpl + ISensNetwork_Impl>(this: *mut core::ffi::c_void, bstrdestination: *mut core::ffi::c_void, bstrconnection: *mut core::ffi::c_void, ultype: u32, lpqocinfo: *const SENS_QOCINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensNetwork_Impl::DestinationReachable(this, core::mem::transmute(&bstrdestination), core::mem::transmute(&bstrconnection), core::mem::transmute_copy(&ultype), core::mem::transmute_copy(&lpqocinfo)).into()
+            }
+        }
+        unsafe extern "system" fn DestinationReachableNoQOCInfo<Identity: windows_core::IUnknownImpl + ISensNetwork_Impl>(this: *mut core::ffi::c_void, bstrdestination: *mut core::ffi::c_void, bstrconnection: *mut core::ffi::c_void, ultype: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensNetwork_Impl::DestinationReachableNoQOCInfo(this, core::mem::transmute(&bstrdestination), core::mem::transmute(&bstrconnection), core::mem::transmute_copy(&ultype)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ConnectionMade: ConnectionMade::<Identity>,
+            ConnectionMadeNoQOCInfo: ConnectionMadeNoQOCInfo::<Identity>,
+            ConnectionLost: ConnectionLost::<Identity>,
+            DestinationReachable: DestinationReachable::<Identity>,
+            DestinationReachableNoQOCInfo: DestinationReachableNoQOCInfo::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISensNetwork {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISensOnNow, ISensOnNow_Vtbl, 0xd597bab2_5b9f_11d1_8dd2_00aa004abd5e);
@@ -405,6 +557,38 @@ impl ISensOnNow_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISensOnNow_Impl> windows_core::imp::TearOffVtable<Identity> for ISensOnNow_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnACPower<Identity: windows_core::IUnknownImpl + ISensOnNow_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensOnNow_Impl::OnACPower(this).into()
+            }
+        }
+        unsafe extern "system" fn OnBatteryPower<Identity: windows_core::IUnknownImpl + ISensOnNow_Impl>(this: *mut core::ffi::c_void, dwbatterylifepercent: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensOnNow_Impl::OnBatteryPower(this, core::mem::transmute_copy(&dwbatterylifepercent)).into()
+            }
+        }
+        unsafe extern "system" fn BatteryLow<Identity: windows_core::IUnknownImpl + ISensOnNow_Impl>(this: *mut core::ffi::c_void, dwbatterylifepercent: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISensOnNow_Impl::BatteryLow(this, core::mem::transmute_copy(&dwbatterylifepercent)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnACPower: OnACPower::<Identity>,
+            OnBatteryPower: OnBatteryPower::<Identity>,
+            BatteryLow: BatteryLow::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISensOnNow {}
 pub const NETWORK_ALIVE_AOL: u32 = 4u32;
 pub const NETWORK_ALIVE_INTERNET: u32 = 8u32;
@@ -80,6 +80,30 @@ impl AsyncIFtpAuthenticationProvider_Vtbl {
         iid == &<AsyncIFtpAuthenticationProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + AsyncIFtpAuthenticationProvider_Impl> windows_core::imp::TearOffVtable<Identity> for AsyncIFtpAuthenticationProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Begin_AuthenticateUser<Identity: windows_core::IUnknownImpl + AsyncIFtpAuthenticationProvider_Impl>(this: *mut core::ffi::c_void, pszsessionid: windows_core::PCWSTR, pszsitename: windows_core::PCWSTR, pszusername: windows_core::PCWSTR, pszpassword: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIFtpAuthenticationProvider_Impl::Begin_AuthenticateUser(this, core::mem::transmute(&pszsessionid), core::mem::transmute(&pszsitename), core::mem::transmute(&pszusername), core::mem::transmute(&pszpassword)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_AuthenticateUser<Identity: windows_core::IUnknownImpl + AsyncIFtpAuthenticationProvider_Impl>(this: *mut core::ffi::c_void, ppszcanonicalusername: *mut windows_core::PWSTR, pfauthenticated: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIFtpAuthenticationProvider_Impl::Finish_AuthenticateUser(this, core::mem::transmute_copy(&ppszcanonicalusername), core::mem::transmute_copy(&pfauthenticated)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Begin_AuthenticateUser: Begin_AuthenticateUser::<Identity>,
+            Finish_AuthenticateUser: Finish_AuthenticateUser::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for AsyncIFtpAuthenticationProvider {}
 windows_core::imp::define_interface!(AsyncIFtpAuthorizationProvider, AsyncIFtpAuthorizationProvider_Vtbl, 0x860dc339_07e5_4a5c_9c61_8820cea012bc);
 windows_core::imp::interface_hierarchy!(AsyncIFtpAuthorizationProvider, windows_core::IUnknown);
@@ -141,6 +165,36 @@ impl AsyncIFtpAuthorizationProvider_Vtbl {
         iid == &<AsyncIFtpAuthorizationProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + AsyncIFtpAuthorizationProvider_Impl> windows_core::imp::TearOffVtable<Identity> for AsyncIFtpAuthorizationProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Begin_GetUserAccessPermission<Identity: windows_core::IUnknownImpl + AsyncIFtpAuthorizationProvider_Impl>(this: *mut core::ffi::c_void, pszsessionid: windows_core::PCWSTR, pszsitename: windows_core::PCWSTR, pszvirtualpath: windows_core::PCWSTR, pszusername: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIFtpAuthorizationProvider_Impl::Begin_GetUserAccessPermission(this, core::mem::transmute(&pszsessionid), core::mem::transmute(&pszsitename), core::mem::transmute(&pszvirtualpath), core::mem::transmute(&pszusername)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_GetUserAccessPermission<Identity: windows_core::IUnknownImpl + AsyncIFtpAuthorizationProvider_Impl>(this: *mut core::ffi::c_void, pftpaccess: *mut FTP_ACCESS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match AsyncIFtpAuthorizationProvider_Impl::Finish_GetUserAccessPermission(this) {
+                    Ok(ok__) => {
+                        pftpaccess.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Begin_GetUserAccessPermission: Begin_GetUserAccessPermission::<Identity>,
+            Finish_GetUserAccessPermission: Finish_GetUserAccessPermission::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for AsyncIFtpAuthorizationProvider {}
 windows_core::imp::define_interface!(AsyncIFtpHomeDirectoryProvider, AsyncIFtpHomeDirectoryProvider_Vtbl, 0x73f81638_6295_42bd_a2be_4a657f7c479c);
 windows_core::imp::interface_hierarchy!(AsyncIFtpHomeDirectoryProvider, windows_core::IUnknown);
@@ -201,6 +255,36 @@ impl AsyncIFtpHomeDirectoryProvider_Vtbl {
         iid == &<AsyncIFtpHomeDirectoryProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + AsyncIFtpHomeDirectoryProvider_Impl> windows_core::imp::TearOffVtable<Identity> for AsyncIFtpHomeDirectoryProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Begin_GetUserHomeDirectoryData<Identity: windows_core::IUnknownImpl + AsyncIFtpHomeDirectoryProvider_Impl>(this: *mut core::ffi::c_void, pszsessionid: windows_core::PCWSTR, pszsitename: windows_core::PCWSTR, pszusername: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIFtpHomeDirectoryProvider_Impl::Begin_GetUserHomeDirectoryData(this, core::mem::transmute(&pszsessionid), core::mem::transmute(&pszsitename), core::mem::transmute(&pszusername)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_GetUserHomeDirectoryData<Identity: windows_core::IUnknownImpl + AsyncIFtpHomeDirectoryProvider_Impl>(this: *mut core::ffi::c_void, ppszhomedirectorydata: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match AsyncIFtpHomeDirectoryProvider_Impl::Finish_GetUserHomeDirectoryData(this) {
+                    Ok(ok__) => {
+                        ppszhomedirectorydata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Begin_GetUserHomeDirectoryData: Begin_GetUserHomeDirectoryData::<Identity>,
+            Finish_GetUserHomeDirectoryData: Finish_GetUserHomeDirectoryData::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for AsyncIFtpHomeDirectoryProvider {}
 windows_core::imp::define_interface!(AsyncIFtpLogProvider, AsyncIFtpLogProvider_Vtbl, 0x00a0ae46_2498_48b2_95e6_df678ed7d49f);
 windows_core::imp::interface_hierarchy!(AsyncIFtpLogProvider, windows_core::IUnknown);
@@ -247,6 +331,30 @@ impl AsyncIFtpLogProvider_Vtbl {
         iid == &<AsyncIFtpLogProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + AsyncIFtpLogProvider_Impl> windows_core::imp::TearOffVtable<Identity> for AsyncIFtpLogProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Begin_Log<Identity: windows_core::IUnknownImpl + AsyncIFtpLogProvider_Impl>(this: *mut core::ffi::c_void, ploggingparameters: *const LOGGING_PARAMETERS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIFtpLogProvider_Impl::Begin_Log(this, core::mem::transmute_copy(&ploggingparameters)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_Log<Identity: windows_core::IUnknownImpl + AsyncIFtpLogProvider_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIFtpLogProvider_Impl::Finish_Log(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Begin_Log: Begin_Log::<Identity>,
+            Finish_Log: Finish_Log::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for AsyncIFtpLogProvider {}
 windows_core::imp::define_interface!(AsyncIFtpPostprocessProvider, AsyncIFtpPostprocessProvider_Vtbl, 0xa16b2542_9694_4eb1_a564_6c2e91fdc133);
 windows_core::imp::interface_hierarchy!(AsyncIFtpPostprocessProvider, windows_core::IUnknown);
@@ -302,6 +410,36 @@ impl AsyncIFtpPostprocessProvider_Vtbl {
         iid == &<AsyncIFtpPostprocessProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + AsyncIFtpPostprocessProvider_Impl> windows_core::imp::TearOffVtable<Identity> for AsyncIFtpPostprocessProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Begin_HandlePostprocess<Identity: windows_core::IUnknownImpl + AsyncIFtpPostprocessProvider_Impl>(this: *mut core::ffi::c_void, ppostprocessparameters: *const POST_PROCESS_PARAMETERS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIFtpPostprocessProvider_Impl::Begin_HandlePostprocess(this, core::mem::transmute_copy(&ppostprocessparameters)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_HandlePostprocess<Identity: windows_core::IUnknownImpl + AsyncIFtpPostprocessProvider_Impl>(this: *mut core::ffi::c_void, pftpprocessstatus: *mut FTP_PROCESS_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match AsyncIFtpPostprocessProvider_Impl::Finish_HandlePostprocess(this) {
+                    Ok(ok__) => {
+                        pftpprocessstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Begin_HandlePostprocess: Begin_HandlePostprocess::<Identity>,
+            Finish_HandlePostprocess: Finish_HandlePostprocess::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for AsyncIFtpPostprocessProvider {}
 windows_core::imp::define_interface!(AsyncIFtpPreprocessProvider, AsyncIFtpPreprocessProvider_Vtbl, 0x6ff5fd8f_fd8e_48b1_a3e0_bf7073db4db5);
 windows_core::imp::interface_hierarchy!(AsyncIFtpPreprocessProvider, windows_core::IUnknown);
@@ -357,6 +495,36 @@ impl AsyncIFtpPreprocessProvider_Vtbl {
         iid == &<AsyncIFtpPreprocessProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + AsyncIFtpPreprocessProvider_Impl> windows_core::imp::TearOffVtable<Identity> for AsyncIFtpPreprocessProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Begin_HandlePreprocess<Identity: windows_core::IUnknownImpl + AsyncIFtpPreprocessProvider_Impl>(this: *mut core::ffi::c_void, ppreprocessparameters: *const PRE_PROCESS_PARAMETERS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIFtpPreprocessProvider_Impl::Begin_HandlePreprocess(this, core::mem::transmute_copy(&ppreprocessparameters)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_HandlePreprocess<Identity: windows_core::IUnknownImpl + AsyncIFtpPreprocessProvider_Impl>(this: *mut core::ffi::c_void, pftpprocessstatus: *mut FTP_PROCESS_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match AsyncIFtpPreprocessProvider_Impl::Finish_HandlePreprocess(this) {
+                    Ok(ok__) => {
+                        pftpprocessstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Begin_HandlePreprocess: Begin_HandlePreprocess::<Identity>,
+            Finish_HandlePreprocess: Finish_HandlePreprocess::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for AsyncIFtpPreprocessProvider {}
 windows_core::imp::define_interface!(AsyncIFtpRoleProvider, AsyncIFtpRoleProvider_Vtbl, 0x3e83bf99_70ec_41ca_84b6_aca7c7a62caf);
 windows_core::imp::interface_hierarchy!(AsyncIFtpRoleProvider, windows_core::IUnknown);
@@ -418,6 +586,36 @@ impl AsyncIFtpRoleProvider_Vtbl {
         iid == &<AsyncIFtpRoleProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + AsyncIFtpRoleProvider_Impl> windows_core::imp::TearOffVtable<Identity> for AsyncIFtpRoleProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Begin_IsUserInRole<Identity: windows_core::IUnknownImpl + AsyncIFtpRoleProvider_Impl>(this: *mut core::ffi::c_void, pszsessionid: windows_core::PCWSTR, pszsitename: windows_core::PCWSTR, pszusername: windows_core::PCWSTR, pszrole: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIFtpRoleProvider_Impl::Begin_IsUserInRole(this, core::mem::transmute(&pszsessionid), core::mem::transmute(&pszsitename), core::mem::transmute(&pszusername), core::mem::transmute(&pszrole)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_IsUserInRole<Identity: windows_core::IUnknownImpl + AsyncIFtpRoleProvider_Impl>(this: *mut core::ffi::c_void, pfisinrole: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match AsyncIFtpRoleProvider_Impl::Finish_IsUserInRole(this) {
+                    Ok(ok__) => {
+                        pfisinrole.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Begin_IsUserInRole: Begin_IsUserInRole::<Identity>,
+            Finish_IsUserInRole: Finish_IsUserInRole::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for AsyncIFtpRoleProvider {}
 windows_core::imp::define_interface!(AsyncIMSAdminBaseSinkW, AsyncIMSAdminBaseSinkW_Vtbl, 0xa9e69613_b80d_11d0_b9b9_00a0c922e750);
 windows_core::imp::interface_hierarchy!(AsyncIMSAdminBaseSinkW, windows_core::IUnknown);
@@ -488,6 +686,44 @@ impl AsyncIMSAdminBaseSinkW_Vtbl {
         iid == &<AsyncIMSAdminBaseSinkW as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + AsyncIMSAdminBaseSinkW_Impl> windows_core::imp::TearOffVtable<Identity> for AsyncIMSAdminBaseSinkW_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Begin_SinkNotify<Identity: windows_core::IUnknownImpl + AsyncIMSAdminBaseSinkW_Impl>(this: *mut core::ffi::c_void, dwmdnumelements: u32, pcochangelist: *const MD_CHANGE_OBJECT_W) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIMSAdminBaseSinkW_Impl::Begin_SinkNotify(this, core::mem::transmute_copy(&dwmdnumelements), core::mem::transmute_copy(&pcochangelist)).into()
+            }
+        }
+        unsafe extern "system" fn Finish_SinkNotify<Identity: windows_core::IUnknownImpl + AsyncIMSAdminBaseSinkW_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIMSAdminBaseSinkW_Impl::Finish_SinkNotify(this).into()
+            }
+        }
+        unsafe extern "system" fn Begin_ShutdownNotify<Identity: windows_core::IUnknownImpl + AsyncIMSAdminBaseSinkW_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIMSAdminBaseSinkW_Impl::Begin_ShutdownNotify(this).into()
+            }
+        }
+        unsafe extern "system" fn Finish_ShutdownNotify<Identity: windows_core::IUnknownImpl + AsyncIMSAdminBaseSinkW_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                AsyncIMSAdminBaseSinkW_Impl::Finish_ShutdownNotify(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Begin_SinkNotify: Begin_SinkNotify::<Identity>,
+            Finish_SinkNotify: Finish_SinkNotify::<Identity>,
+            Begin_ShutdownNotify: Begin_ShutdownNotify::<Identity>,
+            Finish_ShutdownNotify: Finish_ShutdownNotify::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for AsyncIMSAdminBaseSinkW {}
 pub const BINARY_METADATA: METADATATYPES = METADATATYPES(3i32);
 #[repr(C)]
@@ -1100,6 +1336,37 @@ impl IADMEXT_Vtbl {
         iid == &<IADMEXT as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IADMEXT_Impl> windows_core::imp::TearOffVtable<Identity> for IADMEXT_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IADMEXT_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IADMEXT_Impl::Initialize(this).into()
+            }
+        }
+        unsafe extern "system" fn EnumDcomCLSIDs<Identity: windows_core::IUnknownImpl + IADMEXT_Impl>(this: *mut core::ffi::c_void, pclsiddcom: *mut windows_core::GUID, dwenumindex: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IADMEXT_Impl::EnumDcomCLSIDs(this, core::mem::transmute_copy(&pclsiddcom), core::mem::transmute_copy(&dwenumindex)).into()
+            }
+        }
+        unsafe extern "system" fn Terminate<Identity: windows_core::IUnknownImpl + IADMEXT_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IADMEXT_Impl::Terminate(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            EnumDcomCLSIDs: EnumDcomCLSIDs::<Identity>,
+            Terminate: Terminate::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IADMEXT {}
 windows_core::imp::define_interface!(IFtpAuthenticationProvider, IFtpAuthenticationProvider_Vtbl, 0x4659f95c_d5a8_4707_b2fc_6fd5794246cf);
 windows_core::imp::interface_hierarchy!(IFtpAuthenticationProvider, windows_core::IUnknown);
@@ -1137,6 +1404,20 @@ impl IFtpAuthenticationProvider_Vtbl {
         iid == &<IFtpAuthenticationProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IFtpAuthenticationProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IFtpAuthenticationProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AuthenticateUser<Identity: windows_core::IUnknownImpl + IFtpAuthenticationProvider_Impl>(this: *mut core::ffi::c_void, pszsessionid: windows_core::PCWSTR, pszsitename: windows_core::PCWSTR, pszusername: windows_core::PCWSTR, pszpassword: windows_core::PCWSTR, ppszcanonicalusername: *mut windows_core::PWSTR, pfauthenticated: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFtpAuthenticationProvider_Impl::AuthenticateUser(this, core::mem::transmute(&pszsessionid), core::mem::transmute(&pszsitename), core::mem::transmute(&pszusername), core::mem::transmute(&pszpassword), core::mem::transmute_copy(&ppszcanonicalusername), core::mem::transmute_copy(&pfauthenticated)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, AuthenticateUser: AuthenticateUser::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IFtpAuthenticationProvider {}
 windows_core::imp::define_interface!(IFtpAuthorizationProvider, IFtpAuthorizationProvider_Vtbl, 0xa50ae7a1_a35a_42b4_a4f3_f4f7057a05d1);
 windows_core::imp::interface_hierarchy!(IFtpAuthorizationProvider, windows_core::IUnknown);
@@ -1183,6 +1464,29 @@ impl IFtpAuthorizationProvider_Vtbl {
         iid == &<IFtpAuthorizationProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IFtpAuthorizationProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IFtpAuthorizationProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetUserAccessPermission<Identity: windows_core::IUnknownImpl + IFtpAuthorizationProvider_Impl>(this: *mut core::ffi::c_void, pszsessionid: windows_core::PCWSTR, pszsitename: windows_core::PCWSTR, pszvirtualpath: windows_core::PCWSTR, pszusername: windows_core::PCWSTR, pftpaccess: *mut FTP_ACCESS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFtpAuthorizationProvider_Impl::GetUserAccessPermission(this, core::mem::transmute(&pszsessionid), core::mem::transmute(&pszsitename), core::mem::transmute(&pszvirtualpath), core::mem::transmute(&pszusername)) {
+                    Ok(ok__) => {
+                        pftpaccess.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetUserAccessPermission: GetUserAccessPermission::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IFtpAuthorizationProvider {}
 windows_core::imp::define_interface!(IFtpHomeDirectoryProvider, IFtpHomeDirectoryProvider_Vtbl, 0x0933b392_18dd_4097_8b9c_83325c35d9a6);
 windows_core::imp::interface_hierarchy!(IFtpHomeDirectoryProvider, windows_core::IUnknown);
@@ -1228,6 +1532,29 @@ impl IFtpHomeDirectoryProvider_Vtbl {
         iid == &<IFtpHomeDirectoryProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IFtpHomeDirectoryProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IFtpHomeDirectoryProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetUserHomeDirectoryData<Identity: windows_core::IUnknownImpl + IFtpHomeDirectoryProvider_Impl>(this: *mut core::ffi::c_void, pszsessionid: windows_core::PCWSTR, pszsitename: windows_core::PCWSTR, pszusername: windows_core::PCWSTR, ppszhomedirectorydata: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFtpHomeDirectoryProvider_Impl::GetUserHomeDirectoryData(this, core::mem::transmute(&pszsessionid), core::mem::transmute(&pszsitename), core::mem::transmute(&pszusername)) {
+                    Ok(ok__) => {
+                        ppszhomedirectorydata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetUserHomeDirectoryData: GetUserHomeDirectoryData::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IFtpHomeDirectoryProvider {}
 windows_core::imp::define_interface!(IFtpLogProvider, IFtpLogProvider_Vtbl, 0xa18a94cc_8299_4408_816c_7c3baca1a40e);
 windows_core::imp::interface_hierarchy!(IFtpLogProvider, windows_core::IUnknown);
@@ -1259,6 +1586,20 @@ impl IFtpLogProvider_Vtbl {
         iid == &<IFtpLogProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IFtpLogProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IFtpLogProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Log<Identity: windows_core::IUnknownImpl + IFtpLogProvider_Impl>(this: *mut core::ffi::c_void, ploggingparameters: *const LOGGING_PARAMETERS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFtpLogProvider_Impl::Log(this, core::mem::transmute_copy(&ploggingparameters)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Log: Log::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IFtpLogProvider {}
 windows_core::imp::define_interface!(IFtpPostprocessProvider, IFtpPostprocessProvider_Vtbl, 0x4522cbc6_16cd_49ad_8653_9a2c579e4280);
 windows_core::imp::interface_hierarchy!(IFtpPostprocessProvider, windows_core::IUnknown);
@@ -1299,6 +1640,26 @@ impl IFtpPostprocessProvider_Vtbl {
         iid == &<IFtpPostprocessProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IFtpPostprocessProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IFtpPostprocessProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn HandlePostprocess<Identity: windows_core::IUnknownImpl + IFtpPostprocessProvider_Impl>(this: *mut core::ffi::c_void, ppostprocessparameters: *const POST_PROCESS_PARAMETERS, pftpprocessstatus: *mut FTP_PROCESS_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFtpPostprocessProvider_Impl::HandlePostprocess(this, core::mem::transmute_copy(&ppostprocessparameters)) {
+                    Ok(ok__) => {
+                        pftpprocessstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, HandlePostprocess: HandlePostprocess::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IFtpPostprocessProvider {}
 windows_core::imp::define_interface!(IFtpPreprocessProvider, IFtpPreprocessProvider_Vtbl, 0xa3c19b60_5a28_471a_8f93_ab30411cee82);
 windows_core::imp::interface_hierarchy!(IFtpPreprocessProvider, windows_core::IUnknown);
@@ -1339,6 +1700,26 @@ impl IFtpPreprocessProvider_Vtbl {
         iid == &<IFtpPreprocessProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IFtpPreprocessProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IFtpPreprocessProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn HandlePreprocess<Identity: windows_core::IUnknownImpl + IFtpPreprocessProvider_Impl>(this: *mut core::ffi::c_void, ppreprocessparameters: *const PRE_PROCESS_PARAMETERS, pftpprocessstatus: *mut FTP_PROCESS_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFtpPreprocessProvider_Impl::HandlePreprocess(this, core::mem::transmute_copy(&ppreprocessparameters)) {
+                    Ok(ok__) => {
+                        pftpprocessstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, HandlePreprocess: HandlePreprocess::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IFtpPreprocessProvider {}
 windows_core::imp::define_interface!(IFtpProviderConstruct, IFtpProviderConstruct_Vtbl, 0x4d1a3f7b_412d_447c_b199_64f967e9a2da);
 windows_core::imp::interface_hierarchy!(IFtpProviderConstruct, windows_core::IUnknown);
@@ -1377,6 +1758,21 @@ impl IFtpProviderConstruct_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IFtpProviderConstruct_Impl> windows_core::imp::TearOffVtable<Identity> for IFtpProviderConstruct_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Construct<Identity: windows_core::IUnknownImpl + IFtpProviderConstruct_Impl>(this: *mut core::ffi::c_void, configurationentries: *const super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFtpProviderConstruct_Impl::Construct(this, core::mem::transmute_copy(&configurationentries)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Construct: Construct::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IFtpProviderConstruct {}
 windows_core::imp::define_interface!(IFtpRoleProvider, IFtpRoleProvider_Vtbl, 0x909c850d_8ca0_4674_96b8_cc2941535725);
 windows_core::imp::interface_hierarchy!(IFtpRoleProvider, windows_core::IUnknown);
@@ -1423,6 +1819,26 @@ impl IFtpRoleProvider_Vtbl {
         iid == &<IFtpRoleProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IFtpRoleProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IFtpRoleProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsUserInRole<Identity: windows_core::IUnknownImpl + IFtpRoleProvider_Impl>(this: *mut core::ffi::c_void, pszsessionid: windows_core::PCWSTR, pszsitename: windows_core::PCWSTR, pszusername: windows_core::PCWSTR, pszrole: windows_core::PCWSTR, pfisinrole: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFtpRoleProvider_Impl::IsUserInRole(this, core::mem::transmute(&pszsessionid), core::mem::transmute(&pszsitename), core::mem::transmute(&pszusername), core::mem::transmute(&pszrole)) {
+                    Ok(ok__) => {
+                        pfisinrole.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, IsUserInRole: IsUserInRole::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IFtpRoleProvider {}
 pub const IISADMIN_EXTENSIONS_CLSID_MD_KEY: windows_core::PCWSTR = windows_core::w!("LM/IISADMIN/EXTENSIONS/DCOMCLSIDS");
 pub const IISADMIN_EXTENSIONS_CLSID_MD_KEYA: windows_core::PCSTR = windows_core::s!("LM/IISADMIN/EXTENSIONS/DCOMCLSIDS");
@@ -1638,6 +2054,58 @@ impl IMSAdminBase2W_Vtbl {
         iid == &<IMSAdminBase2W as windows_core::Interface>::IID || iid == &<IMSAdminBaseW as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMSAdminBase2W_Impl> windows_core::imp::TearOffVtable<Identity> for IMSAdminBase2W_Vtbl
+where
+    IMSAdminBaseW_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn BackupWithPasswd<Identity: windows_core::IUnknownImpl + IMSAdminBase2W_Impl>(this: *mut core::ffi::c_void, pszmdbackuplocation: windows_core::PCWSTR, dwmdversion: u32, dwmdflags: u32, pszpasswd: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBase2W_Impl::BackupWithPasswd(this, core::mem::transmute(&pszmdbackuplocation), core::mem::transmute_copy(&dwmdversion), core::mem::transmute_copy(&dwmdflags), core::mem::transmute(&pszpasswd)).into()
+            }
+        }
+        unsafe extern "system" fn RestoreWithPasswd<Identity: windows_core::IUnknownImpl + IMSAdminBase2W_Impl>(this: *mut core::ffi::c_void, pszmdbackuplocation: windows_core::PCWSTR, dwmdversion: u32, dwmdflags: u32, pszpasswd: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBase2W_Impl::RestoreWithPasswd(this, core::mem::transmute(&pszmdbackuplocation), core::mem::transmute_copy(&dwmdversion), core::mem::transmute_copy(&dwmdflags), core::mem::transmute(&pszpasswd)).into()
+            }
+        }
+        unsafe extern "system" fn Export<Identity: windows_core::IUnknownImpl + IMSAdminBase2W_Impl>(this: *mut core::ffi::c_void, pszpasswd: windows_core::PCWSTR, pszfilename: windows_core::PCWSTR, pszsourcepath: windows_core::PCWSTR, dwmdflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBase2W_Impl::Export(this, core::mem::transmute(&pszpasswd), core::mem::transmute(&pszfilename), core::mem::transmute(&pszsourcepath), core::mem::transmute_copy(&dwmdflags)).into()
+            }
+        }
+        unsafe extern "system" fn Import<Identity: windows_core::IUnknownImpl + IMSAdminBase2W_Impl>(this: *mut core::ffi::c_void, pszpasswd: windows_core::PCWSTR, pszfilename: windows_core::PCWSTR, pszsourcepath: windows_core::PCWSTR, pszdestpath: windows_core::PCWSTR, dwmdflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBase2W_Impl::Import(this, core::mem::transmute(&pszpasswd), core::mem::transmute(&pszfilename), core::mem::transmute(&pszsourcepath), core::mem::transmute(&pszdestpath), core::mem::transmute_copy(&dwmdflags)).into()
+            }
+        }
+        unsafe extern "system" fn RestoreHistory<Identity: windows_core::IUnknownImpl + IMSAdminBase2W_Impl>(this: *mut core::ffi::c_void, pszmdhistorylocation: windows_core::PCWSTR, dwmdmajorversion: u32, dwmdminorversion: u32, dwmdflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBase2W_Impl::RestoreHistory(this, core::mem::transmute(&pszmdhistorylocation), core::mem::transmute_copy(&dwmdmajorversion), core::mem::transmute_copy(&dwmdminorversion), core::mem::transmute_copy(&dwmdflags)).into()
+            }
+        }
+        unsafe extern "system" fn EnumHistory<Identity: windows_core::IUnknownImpl + IMSAdminBase2W_Impl>(this: *mut core::ffi::c_void, pszmdhistorylocation: windows_core::PWSTR, pdwmdmajorversion: *mut u32, pdwmdminorversion: *mut u32, pftmdhistorytime: *mut super::super::Foundation::FILETIME, dwmdenumindex: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBase2W_Impl::EnumHistory(this, core::mem::transmute_copy(&pszmdhistorylocation), core::mem::transmute_copy(&pdwmdmajorversion), core::mem::transmute_copy(&pdwmdminorversion), core::mem::transmute_copy(&pftmdhistorytime), core::mem::transmute_copy(&dwmdenumindex)).into()
+            }
+        }
+        Self {
+            base__: <IMSAdminBaseW_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            BackupWithPasswd: BackupWithPasswd::<Identity>,
+            RestoreWithPasswd: RestoreWithPasswd::<Identity>,
+            Export: Export::<Identity>,
+            Import: Import::<Identity>,
+            RestoreHistory: RestoreHistory::<Identity>,
+            EnumHistory: EnumHistory::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMSAdminBase2W {}
 windows_core::imp::define_interface!(IMSAdminBase3W, IMSAdminBase3W_Vtbl, 0xf612954d_3b0b_4c56_9563_227b7be624b4);
 impl core::ops::Deref for IMSAdminBase3W {
@@ -1678,6 +2146,20 @@ impl IMSAdminBase3W_Vtbl {
         iid == &<IMSAdminBase3W as windows_core::Interface>::IID || iid == &<IMSAdminBaseW as windows_core::Interface>::IID || iid == &<IMSAdminBase2W as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMSAdminBase3W_Impl> windows_core::imp::TearOffVtable<Identity> for IMSAdminBase3W_Vtbl
+where
+    IMSAdminBase2W_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetChildPaths<Identity: windows_core::IUnknownImpl + IMSAdminBase3W_Impl>(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, cchmdbuffersize: u32, pszbuffer: windows_core::PWSTR, pcchmdrequiredbuffersize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBase3W_Impl::GetChildPaths(this, core::mem::transmute_copy(&hmdhandle), core::mem::transmute(&pszmdpath), core::mem::transmute_copy(&cchmdbuffersize), core::mem::transmute_copy(&pszbuffer), core::mem::transmute_copy(&pcchmdrequiredbuffersize)).into()
+            }
+        }
+        Self { base__: <IMSAdminBase2W_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetChildPaths: GetChildPaths::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IMSAdminBase3W {}
 windows_core::imp::define_interface!(IMSAdminBaseSinkW, IMSAdminBaseSinkW_Vtbl, 0xa9e69612_b80d_11d0_b9b9_00a0c922e750);
 windows_core::imp::interface_hierarchy!(IMSAdminBaseSinkW, windows_core::IUnknown);
@@ -1724,6 +2206,30 @@ impl IMSAdminBaseSinkW_Vtbl {
         iid == &<IMSAdminBaseSinkW as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMSAdminBaseSinkW_Impl> windows_core::imp::TearOffVtable<Identity> for IMSAdminBaseSinkW_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SinkNotify<Identity: windows_core::IUnknownImpl + IMSAdminBaseSinkW_Impl>(this: *mut core::ffi::c_void, dwmdnumelements: u32, pcochangelist: *const MD_CHANGE_OBJECT_W) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseSinkW_Impl::SinkNotify(this, core::mem::transmute_copy(&dwmdnumelements), core::mem::transmute_copy(&pcochangelist)).into()
+            }
+        }
+        unsafe extern "system" fn ShutdownNotify<Identity: windows_core::IUnknownImpl + IMSAdminBaseSinkW_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseSinkW_Impl::ShutdownNotify(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SinkNotify: SinkNotify::<Identity>,
+            ShutdownNotify: ShutdownNotify::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMSAdminBaseSinkW {}
 windows_core::imp::define_interface!(IMSAdminBaseW, IMSAdminBaseW_Vtbl, 0x70b51430_b6ca_11d0_b9b9_00a0c922e750);
 windows_core::imp::interface_hierarchy!(IMSAdminBaseW, windows_core::IUnknown);
@@ -2229,6 +2735,263 @@ impl IMSAdminBaseW_Vtbl {
         iid == &<IMSAdminBaseW as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl> windows_core::imp::TearOffVtable<Identity> for IMSAdminBaseW_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddKey<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::AddKey(this, core::mem::transmute_copy(&hmdhandle), core::mem::transmute(&pszmdpath)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteKey<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::DeleteKey(this, core::mem::transmute_copy(&hmdhandle), core::mem::transmute(&pszmdpath)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteChildKeys<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::DeleteChildKeys(this, core::mem::transmute_copy(&hmdhandle), core::mem::transmute(&pszmdpath)).into()
+            }
+        }
+        unsafe extern "system" fn EnumKeys<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, pszmdname: windows_core::PWSTR, dwmdenumobjectindex: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::EnumKeys(this, core::mem::transmute_copy(&hmdhandle), core::mem::transmute(&pszmdpath), core::mem::transmute_copy(&pszmdname), core::mem::transmute_copy(&dwmdenumobjectindex)).into()
+            }
+        }
+        unsafe extern "system" fn CopyKey<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, hmdsourcehandle: u32, pszmdsourcepath: windows_core::PCWSTR, hmddesthandle: u32, pszmddestpath: windows_core::PCWSTR, bmdoverwriteflag: windows_core::BOOL, bmdcopyflag: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::CopyKey(this, core::mem::transmute_copy(&hmdsourcehandle), core::mem::transmute(&pszmdsourcepath), core::mem::transmute_copy(&hmddesthandle), core::mem::transmute(&pszmddestpath), core::mem::transmute_copy(&bmdoverwriteflag), core::mem::transmute_copy(&bmdcopyflag)).into()
+            }
+        }
+        unsafe extern "system" fn RenameKey<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, pszmdnewname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::RenameKey(this, core::mem::transmute_copy(&hmdhandle), core::mem::transmute(&pszmdpath), core::mem::transmute(&pszmdnewname)).into()
+            }
+        }
+        unsafe extern "system" fn SetData<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, pmdrmddata: *mut METADATA_RECORD) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::SetData(this, core::mem::transmute_copy(&hmdhandle), core::mem::transmute(&pszmdpath), core::mem::transmute_copy(&pmdrmddata)).into()
+            }
+        }
+        unsafe extern "system" fn GetData<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, pmdrmddata: *mut METADATA_RECORD, pdwmdrequireddatalen: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::GetData(this, core::mem::transmute_copy(&hmdhandle), core::mem::transmute(&pszmdpath), core::mem::transmute_copy(&pmdrmddata), core::mem::transmute_copy(&pdwmdrequireddatalen)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteData<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, dwmdidentifier: u32, dwmddatatype: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::DeleteData(this, core::mem::transmute_copy(&hmdhandle), core::mem::transmute(&pszmdpath), core::mem::transmute_copy(&dwmdidentifier), core::mem::transmute_copy(&dwmddatatype)).into()
+            }
+        }
+        unsafe extern "system" fn EnumData<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, pmdrmddata: *mut METADATA_RECORD, dwmdenumdataindex: u32, pdwmdrequireddatalen: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::EnumData(this, core::mem::transmute_copy(&hmdhandle), core::mem::transmute(&pszmdpath), core::mem::transmute_copy(&pmdrmddata), core::mem::transmute_copy(&dwmdenumdataindex), core::mem::transmute_copy(&pdwmdrequireddatalen)).into()
+            }
+        }
+        unsafe extern "system" fn GetAllData<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, dwmdattributes: u32, dwmdusertype: u32, dwmddatatype: u32, pdwmdnumdataentries: *mut u32, pdwmddatasetnumber: *mut u32, dwmdbuffersize: u32, pbmdbuffer: *mut u8, pdwmdrequiredbuffersize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::GetAllData(this, core::mem::transmute_copy(&hmdhandle), core::mem::transmute(&pszmdpath), core::mem::transmute_copy(&dwmdattributes), core::mem::transmute_copy(&dwmdusertype), core::mem::transmute_copy(&dwmddatatype), core::mem::transmute_copy(&pdwmdnumdataentries), core::mem::transmute_copy(&pdwmddatasetnumber), core::mem::transmute_copy(&dwmdbuffersize), core::mem::transmute_copy(&pbmdbuffer), core::mem::transmute_copy(&pdwmdrequiredbuffersize)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteAllData<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, dwmdusertype: u32, dwmddatatype: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::DeleteAllData(this, core::mem::transmute_copy(&hmdhandle), core::mem::transmute(&pszmdpath), core::mem::transmute_copy(&dwmdusertype), core::mem::transmute_copy(&dwmddatatype)).into()
+            }
+        }
+        unsafe extern "system" fn CopyData<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, hmdsourcehandle: u32, pszmdsourcepath: windows_core::PCWSTR, hmddesthandle: u32, pszmddestpath: windows_core::PCWSTR, dwmdattributes: u32, dwmdusertype: u32, dwmddatatype: u32, bmdcopyflag: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::CopyData(this, core::mem::transmute_copy(&hmdsourcehandle), core::mem::transmute(&pszmdsourcepath), core::mem::transmute_copy(&hmddesthandle), core::mem::transmute(&pszmddestpath), core::mem::transmute_copy(&dwmdattributes), core::mem::transmute_copy(&dwmdusertype), core::mem::transmute_copy(&dwmddatatype), core::mem::transmute_copy(&bmdcopyflag)).into()
+            }
+        }
+        unsafe extern "system" fn GetDataPaths<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, dwmdidentifier: u32, dwmddatatype: u32, dwmdbuffersize: u32, pszbuffer: windows_core::PWSTR, pdwmdrequiredbuffersize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::GetDataPaths(this, core::mem::transmute_copy(&hmdhandle), core::mem::transmute(&pszmdpath), core::mem::transmute_copy(&dwmdidentifier), core::mem::transmute_copy(&dwmddatatype), core::mem::transmute_copy(&dwmdbuffersize), core::mem::transmute_copy(&pszbuffer), core::mem::transmute_copy(&pdwmdrequiredbuffersize)).into()
+            }
+        }
+        unsafe extern "system" fn OpenKey<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, dwmdaccessrequested: u32, dwmdtimeout: u32, phmdnewhandle: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMSAdminBaseW_Impl::OpenKey(this, core::mem::transmute_copy(&hmdhandle), core::mem::transmute(&pszmdpath), core::mem::transmute_copy(&dwmdaccessrequested), core::mem::transmute_copy(&dwmdtimeout)) {
+                    Ok(ok__) => {
+                        phmdnewhandle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CloseKey<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, hmdhandle: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::CloseKey(this, core::mem::transmute_copy(&hmdhandle)).into()
+            }
+        }
+        unsafe extern "system" fn ChangePermissions<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, hmdhandle: u32, dwmdtimeout: u32, dwmdaccessrequested: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::ChangePermissions(this, core::mem::transmute_copy(&hmdhandle), core::mem::transmute_copy(&dwmdtimeout), core::mem::transmute_copy(&dwmdaccessrequested)).into()
+            }
+        }
+        unsafe extern "system" fn SaveData<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::SaveData(this).into()
+            }
+        }
+        unsafe extern "system" fn GetHandleInfo<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, hmdhandle: u32, pmdhiinfo: *mut METADATA_HANDLE_INFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMSAdminBaseW_Impl::GetHandleInfo(this, core::mem::transmute_copy(&hmdhandle)) {
+                    Ok(ok__) => {
+                        pmdhiinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSystemChangeNumber<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, pdwsystemchangenumber: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMSAdminBaseW_Impl::GetSystemChangeNumber(this) {
+                    Ok(ok__) => {
+                        pdwsystemchangenumber.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDataSetNumber<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, pdwmddatasetnumber: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMSAdminBaseW_Impl::GetDataSetNumber(this, core::mem::transmute_copy(&hmdhandle), core::mem::transmute(&pszmdpath)) {
+                    Ok(ok__) => {
+                        pdwmddatasetnumber.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLastChangeTime<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, pftmdlastchangetime: *const super::super::Foundation::FILETIME, blocaltime: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::SetLastChangeTime(this, core::mem::transmute_copy(&hmdhandle), core::mem::transmute(&pszmdpath), core::mem::transmute_copy(&pftmdlastchangetime), core::mem::transmute_copy(&blocaltime)).into()
+            }
+        }
+        unsafe extern "system" fn GetLastChangeTime<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, pftmdlastchangetime: *mut super::super::Foundation::FILETIME, blocaltime: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::GetLastChangeTime(this, core::mem::transmute_copy(&hmdhandle), core::mem::transmute(&pszmdpath), core::mem::transmute_copy(&pftmdlastchangetime), core::mem::transmute_copy(&blocaltime)).into()
+            }
+        }
+        unsafe extern "system" fn KeyExchangePhase1<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::KeyExchangePhase1(this).into()
+            }
+        }
+        unsafe extern "system" fn KeyExchangePhase2<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::KeyExchangePhase2(this).into()
+            }
+        }
+        unsafe extern "system" fn Backup<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, pszmdbackuplocation: windows_core::PCWSTR, dwmdversion: u32, dwmdflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::Backup(this, core::mem::transmute(&pszmdbackuplocation), core::mem::transmute_copy(&dwmdversion), core::mem::transmute_copy(&dwmdflags)).into()
+            }
+        }
+        unsafe extern "system" fn Restore<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, pszmdbackuplocation: windows_core::PCWSTR, dwmdversion: u32, dwmdflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::Restore(this, core::mem::transmute(&pszmdbackuplocation), core::mem::transmute_copy(&dwmdversion), core::mem::transmute_copy(&dwmdflags)).into()
+            }
+        }
+        unsafe extern "system" fn EnumBackups<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, pszmdbackuplocation: windows_core::PWSTR, pdwmdversion: *mut u32, pftmdbackuptime: *mut super::super::Foundation::FILETIME, dwmdenumindex: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::EnumBackups(this, core::mem::transmute_copy(&pszmdbackuplocation), core::mem::transmute_copy(&pdwmdversion), core::mem::transmute_copy(&pftmdbackuptime), core::mem::transmute_copy(&dwmdenumindex)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteBackup<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, pszmdbackuplocation: windows_core::PCWSTR, dwmdversion: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::DeleteBackup(this, core::mem::transmute(&pszmdbackuplocation), core::mem::transmute_copy(&dwmdversion)).into()
+            }
+        }
+        unsafe extern "system" fn UnmarshalInterface<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void, piadmbwinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IMSAdminBaseW_Impl::UnmarshalInterface(this) {
+                    Ok(ok__) => {
+                        piadmbwinterface.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetServerGuid<Identity: windows_core::IUnknownImpl + IMSAdminBaseW_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSAdminBaseW_Impl::GetServerGuid(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddKey: AddKey::<Identity>,
+            DeleteKey: DeleteKey::<Identity>,
+            DeleteChildKeys: DeleteChildKeys::<Identity>,
+            EnumKeys: EnumKeys::<Identity>,
+            CopyKey: CopyKey::<Identity>,
+            RenameKey: RenameKey::<Identity>,
+            SetData: SetData::<Identity>,
+            GetData: GetData::<Identity>,
+            DeleteData: DeleteData::<Identity>,
+            EnumData: EnumData::<Identity>,
+            GetAllData: GetAllData::<Identity>,
+            DeleteAllData: DeleteAllData::<Identity>,
+            CopyData: CopyData::<Identity>,
+            GetDataPaths: GetDataPaths::<Identity>,
+            OpenKey: OpenKey::<Identity>,
+            CloseKey: CloseKey::<Identity>,
+            ChangePermissions: ChangePermissions::<Identity>,
+            SaveData: SaveData::<Identity>,
+            GetHandleInfo: GetHandleInfo::<Identity>,
+            GetSystemChangeNumber: GetSystemChangeNumber::<Identity>,
+            GetDataSetNumber: GetDataSetNumber::<Identity>,
+            SetLastChangeTime: SetLastChangeTime::<Identity>,
+            GetLastChangeTime: GetLastChangeTime::<Identity>,
+            KeyExchangePhase1: KeyExchangePhase1::<Identity>,
+            KeyExchangePhase2: KeyExchangePhase2::<Identity>,
+            Backup: Backup::<Identity>,
+            Restore: Restore::<Identity>,
+            EnumBackups: EnumBackups::<Identity>,
+            DeleteBackup: DeleteBackup::<Identity>,
+            UnmarshalInterface: UnmarshalInterface::<Identity>,
+            GetServerGuid: GetServerGuid::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMSAdminBaseW {}
 windows_core::imp::define_interface!(IMSImpExpHelpW, IMSImpExpHelpW_Vtbl, 0x29ff67ff_8050_480f_9f30_cc41635f2f9d);
 windows_core::imp::interface_hierarchy!(IMSImpExpHelpW, windows_core::IUnknown);
@@ -2264,6 +3027,23 @@ impl IMSImpExpHelpW_Vtbl {
         iid == &<IMSImpExpHelpW as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IMSImpExpHelpW_Impl> windows_core::imp::TearOffVtable<Identity> for IMSImpExpHelpW_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumeratePathsInFile<Identity: windows_core::IUnknownImpl + IMSImpExpHelpW_Impl>(this: *mut core::ffi::c_void, pszfilename: windows_core::PCWSTR, pszkeytype: windows_core::PCWSTR, dwmdbuffersize: u32, pszbuffer: windows_core::PWSTR, pdwmdrequiredbuffersize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMSImpExpHelpW_Impl::EnumeratePathsInFile(this, core::mem::transmute(&pszfilename), core::mem::transmute(&pszkeytype), core::mem::transmute_copy(&dwmdbuffersize), core::mem::transmute_copy(&pszbuffer), core::mem::transmute_copy(&pdwmdrequiredbuffersize)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnumeratePathsInFile: EnumeratePathsInFile::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IMSImpExpHelpW {}
 pub const INVALID_END_METADATA: METADATATYPES = METADATATYPES(6i32);
 pub const LIBID_ASPTypeLibrary: windows_core::GUID = windows_core::GUID::from_u128(0xd97a6da0_a85c_11cf_83ae_00a0c90c2bd8);
@@ -4361,6 +4361,21 @@ impl IAdviseSinkEx_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+impl<Identity: windows_core::IUnknownImpl + IAdviseSinkEx_Impl> windows_core::imp::TearOffVtable<Identity> for IAdviseSinkEx_Vtbl
+where
+    super::Com::IAdviseSink_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnViewStatusChange<Identity: windows_core::IUnknownImpl + IAdviseSinkEx_Impl>(this: *mut core::ffi::c_void, dwviewstatus: u32) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAdviseSinkEx_Impl::OnViewStatusChange(this, core::mem::transmute_copy(&dwviewstatus))
+            }
+        }
+        Self { base__: <super::Com::IAdviseSink_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnViewStatusChange: OnViewStatusChange::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
 impl windows_core::RuntimeName for IAdviseSinkEx {}
 windows_core::imp::define_interface!(ICanHandleException, ICanHandleException_Vtbl, 0xc5598e60_b307_11d1_b27d_006008c3fbfb);
 windows_core::imp::interface_hierarchy!(ICanHandleException, windows_core::IUnknown);
@@ -4399,6 +4414,21 @@ impl ICanHandleException_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ICanHandleException_Impl> windows_core::imp::TearOffVtable<Identity> for ICanHandleException_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CanHandleException<Identity: windows_core::IUnknownImpl + ICanHandleException_Impl>(this: *mut core::ffi::c_void, pexcepinfo: *const super::Com::EXCEPINFO, pvar: *const super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICanHandleException_Impl::CanHandleException(this, core::mem::transmute_copy(&pexcepinfo), core::mem::transmute_copy(&pvar)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CanHandleException: CanHandleException::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ICanHandleException {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IClassFactory2, IClassFactory2_Vtbl, 0xb196b28f_bab4_101a_b69c_00aa00341d07);
@@ -4486,6 +4516,44 @@ impl IClassFactory2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IClassFactory2_Impl> windows_core::imp::TearOffVtable<Identity> for IClassFactory2_Vtbl
+where
+    super::Com::IClassFactory_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetLicInfo<Identity: windows_core::IUnknownImpl + IClassFactory2_Impl>(this: *mut core::ffi::c_void, plicinfo: *mut LICINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IClassFactory2_Impl::GetLicInfo(this, core::mem::transmute_copy(&plicinfo)).into()
+            }
+        }
+        unsafe extern "system" fn RequestLicKey<Identity: windows_core::IUnknownImpl + IClassFactory2_Impl>(this: *mut core::ffi::c_void, dwreserved: u32, pbstrkey: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IClassFactory2_Impl::RequestLicKey(this, core::mem::transmute_copy(&dwreserved)) {
+                    Ok(ok__) => {
+                        pbstrkey.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateInstanceLic<Identity: windows_core::IUnknownImpl + IClassFactory2_Impl>(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, punkreserved: *mut core::ffi::c_void, riid: *const windows_core::GUID, bstrkey: *mut core::ffi::c_void, ppvobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IClassFactory2_Impl::CreateInstanceLic(this, core::mem::transmute_copy(&punkouter), core::mem::transmute_copy(&punkreserved), core::mem::transmute_copy(&riid), core::mem::transmute(&bstrkey), core::mem::transmute_copy(&ppvobj)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IClassFactory_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetLicInfo: GetLicInfo::<Identity>,
+            RequestLicKey: RequestLicKey::<Identity>,
+            CreateInstanceLic: CreateInstanceLic::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IClassFactory2 {}
 windows_core::imp::define_interface!(IContinue, IContinue_Vtbl, 0x0000012a_0000_0000_c000_000000000046);
 windows_core::imp::interface_hierarchy!(IContinue, windows_core::IUnknown);
@@ -4517,6 +4585,20 @@ impl IContinue_Vtbl {
         iid == &<IContinue as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IContinue_Impl> windows_core::imp::TearOffVtable<Identity> for IContinue_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FContinue<Identity: windows_core::IUnknownImpl + IContinue_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContinue_Impl::FContinue(this).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, FContinue: FContinue::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IContinue {}
 windows_core::imp::define_interface!(IContinueCallback, IContinueCallback_Vtbl, 0xb722bcca_4e68_101b_a2bc_00aa00404770);
 windows_core::imp::interface_hierarchy!(IContinueCallback, windows_core::IUnknown);
@@ -4566,6 +4648,30 @@ impl IContinueCallback_Vtbl {
         iid == &<IContinueCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IContinueCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IContinueCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn FContinue<Identity: windows_core::IUnknownImpl + IContinueCallback_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContinueCallback_Impl::FContinue(this).into()
+            }
+        }
+        unsafe extern "system" fn FContinuePrinting<Identity: windows_core::IUnknownImpl + IContinueCallback_Impl>(this: *mut core::ffi::c_void, ncntprinted: i32, ncurpage: i32, pwszprintstatus: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IContinueCallback_Impl::FContinuePrinting(this, core::mem::transmute_copy(&ncntprinted), core::mem::transmute_copy(&ncurpage), core::mem::transmute(&pwszprintstatus)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            FContinue: FContinue::<Identity>,
+            FContinuePrinting: FContinuePrinting::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IContinueCallback {}
 windows_core::imp::define_interface!(ICreateErrorInfo, ICreateErrorInfo_Vtbl, 0x22f03340_547d_101b_8e65_08002b2bd119);
 windows_core::imp::interface_hierarchy!(ICreateErrorInfo, windows_core::IUnknown);
@@ -4657,6 +4763,51 @@ impl ICreateErrorInfo_Vtbl {
         iid == &<ICreateErrorInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ICreateErrorInfo_Impl> windows_core::imp::TearOffVtable<Identity> for ICreateErrorInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetGUID<Identity: windows_core::IUnknownImpl + ICreateErrorInfo_Impl>(this: *mut core::ffi::c_void, rguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateErrorInfo_Impl::SetGUID(this, core::mem::transmute_copy(&rguid)).into()
+            }
+        }
+        unsafe extern "system" fn SetSource<Identity: windows_core::IUnknownImpl + ICreateErrorInfo_Impl>(this: *mut core::ffi::c_void, szsource: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateErrorInfo_Impl::SetSource(this, core::mem::transmute(&szsource)).into()
+            }
+        }
+        unsafe extern "system" fn SetDescription<Identity: windows_core::IUnknownImpl + ICreateErrorInfo_Impl>(this: *mut core::ffi::c_void, szdescription: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateErrorInfo_Impl::SetDescription(this, core::mem::transmute(&szdescription)).into()
+            }
+        }
+        unsafe extern "system" fn SetHelpFile<Identity: windows_core::IUnknownImpl + ICreateErrorInfo_Impl>(this: *mut core::ffi::c_void, szhelpfile: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateErrorInfo_Impl::SetHelpFile(this, core::mem::transmute(&szhelpfile)).into()
+            }
+        }
+        unsafe extern "system" fn SetHelpContext<Identity: windows_core::IUnknownImpl + ICreateErrorInfo_Impl>(this: *mut core::ffi::c_void, dwhelpcontext: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateErrorInfo_Impl::SetHelpContext(this, core::mem::transmute_copy(&dwhelpcontext)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetGUID: SetGUID::<Identity>,
+            SetSource: SetSource::<Identity>,
+            SetDescription: SetDescription::<Identity>,
+            SetHelpFile: SetHelpFile::<Identity>,
+            SetHelpContext: SetHelpContext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ICreateErrorInfo {}
 windows_core::imp::define_interface!(ICreateTypeInfo, ICreateTypeInfo_Vtbl, 0x00020405_0000_0000_c000_000000000046);
 windows_core::imp::interface_hierarchy!(ICreateTypeInfo, windows_core::IUnknown);
@@ -5004,6 +5155,178 @@ impl ICreateTypeInfo_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl> windows_core::imp::TearOffVtable<Identity> for ICreateTypeInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetGuid<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo_Impl::SetGuid(this, core::mem::transmute_copy(&guid)).into()
+            }
+        }
+        unsafe extern "system" fn SetTypeFlags<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl>(this: *mut core::ffi::c_void, utypeflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo_Impl::SetTypeFlags(this, core::mem::transmute_copy(&utypeflags)).into()
+            }
+        }
+        unsafe extern "system" fn SetDocString<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl>(this: *mut core::ffi::c_void, pstrdoc: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo_Impl::SetDocString(this, core::mem::transmute(&pstrdoc)).into()
+            }
+        }
+        unsafe extern "system" fn SetHelpContext<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl>(this: *mut core::ffi::c_void, dwhelpcontext: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo_Impl::SetHelpContext(this, core::mem::transmute_copy(&dwhelpcontext)).into()
+            }
+        }
+        unsafe extern "system" fn SetVersion<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl>(this: *mut core::ffi::c_void, wmajorvernum: u16, wminorvernum: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo_Impl::SetVersion(this, core::mem::transmute_copy(&wmajorvernum), core::mem::transmute_copy(&wminorvernum)).into()
+            }
+        }
+        unsafe extern "system" fn AddRefTypeInfo<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl>(this: *mut core::ffi::c_void, ptinfo: *mut core::ffi::c_void, phreftype: *const u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo_Impl::AddRefTypeInfo(this, core::mem::transmute_copy(&ptinfo), core::mem::transmute_copy(&phreftype)).into()
+            }
+        }
+        unsafe extern "system" fn AddFuncDesc<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl>(this: *mut core::ffi::c_void, index: u32, pfuncdesc: *const super::Com::FUNCDESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo_Impl::AddFuncDesc(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&pfuncdesc)).into()
+            }
+        }
+        unsafe extern "system" fn AddImplType<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl>(this: *mut core::ffi::c_void, index: u32, hreftype: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo_Impl::AddImplType(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&hreftype)).into()
+            }
+        }
+        unsafe extern "system" fn SetImplTypeFlags<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl>(this: *mut core::ffi::c_void, index: u32, impltypeflags: super::Com::IMPLTYPEFLAGS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo_Impl::SetImplTypeFlags(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&impltypeflags)).into()
+            }
+        }
+        unsafe extern "system" fn SetAlignment<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl>(this: *mut core::ffi::c_void, cbalignment: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo_Impl::SetAlignment(this, core::mem::transmute_copy(&cbalignment)).into()
+            }
+        }
+        unsafe extern "system" fn SetSchema<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl>(this: *mut core::ffi::c_void, pstrschema: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo_Impl::SetSchema(this, core::mem::transmute(&pstrschema)).into()
+            }
+        }
+        unsafe extern "system" fn AddVarDesc<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl>(this: *mut core::ffi::c_void, index: u32, pvardesc: *const super::Com::VARDESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo_Impl::AddVarDesc(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&pvardesc)).into()
+            }
+        }
+        unsafe extern "system" fn SetFuncAndParamNames<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl>(this: *mut core::ffi::c_void, index: u32, rgsznames: *const windows_core::PCWSTR, cnames: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo_Impl::SetFuncAndParamNames(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&rgsznames), core::mem::transmute_copy(&cnames)).into()
+            }
+        }
+        unsafe extern "system" fn SetVarName<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl>(this: *mut core::ffi::c_void, index: u32, szname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo_Impl::SetVarName(this, core::mem::transmute_copy(&index), core::mem::transmute(&szname)).into()
+            }
+        }
+        unsafe extern "system" fn SetTypeDescAlias<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl>(this: *mut core::ffi::c_void, ptdescalias: *const super::Com::TYPEDESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo_Impl::SetTypeDescAlias(this, core::mem::transmute_copy(&ptdescalias)).into()
+            }
+        }
+        unsafe extern "system" fn DefineFuncAsDllEntry<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl>(this: *mut core::ffi::c_void, index: u32, szdllname: windows_core::PCWSTR, szprocname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo_Impl::DefineFuncAsDllEntry(this, core::mem::transmute_copy(&index), core::mem::transmute(&szdllname), core::mem::transmute(&szprocname)).into()
+            }
+        }
+        unsafe extern "system" fn SetFuncDocString<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl>(this: *mut core::ffi::c_void, index: u32, szdocstring: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo_Impl::SetFuncDocString(this, core::mem::transmute_copy(&index), core::mem::transmute(&szdocstring)).into()
+            }
+        }
+        unsafe extern "system" fn SetVarDocString<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl>(this: *mut core::ffi::c_void, index: u32, szdocstring: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo_Impl::SetVarDocString(this, core::mem::transmute_copy(&index), core::mem::transmute(&szdocstring)).into()
+            }
+        }
+        unsafe extern "system" fn SetFuncHelpContext<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl>(this: *mut core::ffi::c_void, index: u32, dwhelpcontext: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo_Impl::SetFuncHelpContext(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&dwhelpcontext)).into()
+            }
+        }
+        unsafe extern "system" fn SetVarHelpContext<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl>(this: *mut core::ffi::c_void, index: u32, dwhelpcontext: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo_Impl::SetVarHelpContext(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&dwhelpcontext)).into()
+            }
+        }
+        unsafe extern "system" fn SetMops<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl>(this: *mut core::ffi::c_void, index: u32, bstrmops: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo_Impl::SetMops(this, core::mem::transmute_copy(&index), core::mem::transmute(&bstrmops)).into()
+            }
+        }
+        unsafe extern "system" fn SetTypeIdldesc<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl>(this: *mut core::ffi::c_void, pidldesc: *const super::Com::IDLDESC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo_Impl::SetTypeIdldesc(this, core::mem::transmute_copy(&pidldesc)).into()
+            }
+        }
+        unsafe extern "system" fn LayOut<Identity: windows_core::IUnknownImpl + ICreateTypeInfo_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo_Impl::LayOut(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetGuid: SetGuid::<Identity>,
+            SetTypeFlags: SetTypeFlags::<Identity>,
+            SetDocString: SetDocString::<Identity>,
+            SetHelpContext: SetHelpContext::<Identity>,
+            SetVersion: SetVersion::<Identity>,
+            AddRefTypeInfo: AddRefTypeInfo::<Identity>,
+            AddFuncDesc: AddFuncDesc::<Identity>,
+            AddImplType: AddImplType::<Identity>,
+            SetImplTypeFlags: SetImplTypeFlags::<Identity>,
+            SetAlignment: SetAlignment::<Identity>,
+            SetSchema: SetSchema::<Identity>,
+            AddVarDesc: AddVarDesc::<Identity>,
+            SetFuncAndParamNames: SetFuncAndParamNames::<Identity>,
+            SetVarName: SetVarName::<Identity>,
+            SetTypeDescAlias: SetTypeDescAlias::<Identity>,
+            DefineFuncAsDllEntry: DefineFuncAsDllEntry::<Identity>,
+            SetFuncDocString: SetFuncDocString::<Identity>,
+            SetVarDocString: SetVarDocString::<Identity>,
+            SetFuncHelpContext: SetFuncHelpContext::<Identity>,
+            SetVarHelpContext: SetVarHelpContext::<Identity>,
+            SetMops: SetMops::<Identity>,
+            SetTypeIdldesc: SetTypeIdldesc::<Identity>,
+            LayOut: LayOut::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ICreateTypeInfo {}
 windows_core::imp::define_interface!(ICreateTypeInfo2, ICreateTypeInfo2_Vtbl, 0x0002040e_0000_0000_c000_000000000046);
 impl core::ops::Deref for ICreateTypeInfo2 {
@@ -5242,6 +5565,122 @@ impl ICreateTypeInfo2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ICreateTypeInfo2_Impl> windows_core::imp::TearOffVtable<Identity> for ICreateTypeInfo2_Vtbl
+where
+    ICreateTypeInfo_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DeleteFuncDesc<Identity: windows_core::IUnknownImpl + ICreateTypeInfo2_Impl>(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo2_Impl::DeleteFuncDesc(this, core::mem::transmute_copy(&index)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteFuncDescByMemId<Identity: windows_core::IUnknownImpl + ICreateTypeInfo2_Impl>(this: *mut core::ffi::c_void, memid: i32, invkind: super::Com::INVOKEKIND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo2_Impl::DeleteFuncDescByMemId(this, core::mem::transmute_copy(&memid), core::mem::transmute_copy(&invkind)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteVarDesc<Identity: windows_core::IUnknownImpl + ICreateTypeInfo2_Impl>(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo2_Impl::DeleteVarDesc(this, core::mem::transmute_copy(&index)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteVarDescByMemId<Identity: windows_core::IUnknownImpl + ICreateTypeInfo2_Impl>(this: *mut core::ffi::c_void, memid: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo2_Impl::DeleteVarDescByMemId(this, core::mem::transmute_copy(&memid)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteImplType<Identity: windows_core::IUnknownImpl + ICreateTypeInfo2_Impl>(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo2_Impl::DeleteImplType(this, core::mem::transmute_copy(&index)).into()
+            }
+        }
+        unsafe extern "system" fn SetCustData<Identity: windows_core::IUnknownImpl + ICreateTypeInfo2_Impl>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pvarval: *const super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo2_Impl::SetCustData(this, core::mem::transmute_copy(&guid), core::mem::transmute_copy(&pvarval)).into()
+            }
+        }
+        unsafe extern "system" fn SetFuncCustData<Identity: windows_core::IUnknownImpl + ICreateTypeInfo2_Impl>(this: *mut core::ffi::c_void, index: u32, guid: *const windows_core::GUID, pvarval: *const super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo2_Impl::SetFuncCustData(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&guid), core::mem::transmute_copy(&pvarval)).into()
+            }
+        }
+        unsafe extern "system" fn SetParamCustData<Identity: windows_core::IUnknownImpl + ICreateTypeInfo2_Impl>(this: *mut core::ffi::c_void, indexfunc: u32, indexparam: u32, guid: *const windows_core::GUID, pvarval: *const super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo2_Impl::SetParamCustData(this, core::mem::transmute_copy(&indexfunc), core::mem::transmute_copy(&indexparam), core::mem::transmute_copy(&guid), core::mem::transmute_copy(&pvarval)).into()
+            }
+        }
+        unsafe extern "system" fn SetVarCustData<Identity: windows_core::IUnknownImpl + ICreateTypeInfo2_Impl>(this: *mut core::ffi::c_void, index: u32, guid: *const windows_core::GUID, pvarval: *const super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo2_Impl::SetVarCustData(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&guid), core::mem::transmute_copy(&pvarval)).into()
+            }
+        }
+        unsafe extern "system" fn SetImplTypeCustData<Identity: windows_core::IUnknownImpl + ICreateTypeInfo2_Impl>(this: *mut core::ffi::c_void, index: u32, guid: *const windows_core::GUID, pvarval: *const super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo2_Impl::SetImplTypeCustData(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&guid), core::mem::transmute_copy(&pvarval)).into()
+            }
+        }
+        unsafe extern "system" fn SetHelpStringContext<Identity: windows_core::IUnknownImpl + ICreateTypeInfo2_Impl>(this: *mut core::ffi::c_void, dwhelpstringcontext: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo2_Impl::SetHelpStringContext(this, core::mem::transmute_copy(&dwhelpstringcontext)).into()
+            }
+        }
+        unsafe extern "system" fn SetFuncHelpStringContext<Identity: windows_core::IUnknownImpl + ICreateTypeInfo2_Impl>(this: *mut core::ffi::c_void, index: u32, dwhelpstringcontext: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo2_Impl::SetFuncHelpStringContext(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&dwhelpstringcontext)).into()
+            }
+        }
+        unsafe extern "system" fn SetVarHelpStringContext<Identity: windows_core::IUnknownImpl + ICreateTypeInfo2_Impl>(this: *mut core::ffi::c_void, index: u32, dwhelpstringcontext: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo2_Impl::SetVarHelpStringContext(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&dwhelpstringcontext)).into()
+            }
+        }
+        unsafe extern "system" fn Invalidate<Identity: windows_core::IUnknownImpl + ICreateTypeInfo2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo2_Impl::Invalidate(this).into()
+            }
+        }
+        unsafe extern "system" fn SetName<Identity: windows_core::IUnknownImpl + ICreateTypeInfo2_Impl>(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeInfo2_Impl::SetName(this, core::mem::transmute(&szname)).into()
+            }
+        }
+        Self {
+            base__: <ICreateTypeInfo_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DeleteFuncDesc: DeleteFuncDesc::<Identity>,
+            DeleteFuncDescByMemId: DeleteFuncDescByMemId::<Identity>,
+            DeleteVarDesc: DeleteVarDesc::<Identity>,
+            DeleteVarDescByMemId: DeleteVarDescByMemId::<Identity>,
+            DeleteImplType: DeleteImplType::<Identity>,
+            SetCustData: SetCustData::<Identity>,
+            SetFuncCustData: SetFuncCustData::<Identity>,
+            SetParamCustData: SetParamCustData::<Identity>,
+            SetVarCustData: SetVarCustData::<Identity>,
+            SetImplTypeCustData: SetImplTypeCustData::<Identity>,
+            SetHelpStringContext: SetHelpStringContext::<Identity>,
+            SetFuncHelpStringContext: SetFuncHelpStringContext::<Identity>,
+            SetVarHelpStringContext: SetVarHelpStringContext::<Identity>,
+            Invalidate: Invalidate::<Identity>,
+            SetName: SetName::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ICreateTypeInfo2 {}
 windows_core::imp::define_interface!(ICreateTypeLib, ICreateTypeLib_Vtbl, 0x00020406_0000_0000_c000_000000000046);
 windows_core::imp::interface_hierarchy!(ICreateTypeLib, windows_core::IUnknown);
@@ -5412,15 +5851,102 @@ impl ICreateTypeLib_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
-impl windows_core::RuntimeName for ICreateTypeLib {}
-windows_core::imp::define_interface!(ICreateTypeLib2, ICreateTypeLib2_Vtbl, 0x0002040f_0000_0000_c000_000000000046);
-impl core::ops::Deref for ICreateTypeLib2 {
-    type Target = ICreateTypeLib;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(ICreateTypeLib2, windows_core::IUnknown, ICreateTypeLib);
+impl<Identity: windows_core::IUnknownImpl + ICreateTypeLib_Impl> windows_core::imp::TearOffVtable<Identity> for ICreateTypeLib_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateTypeInfo<Identity: windows_core::IUnknownImpl + ICreateTypeLib_Impl>(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR, tkind: super::Com::TYPEKIND, ppctinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ICreateTypeLib_Impl::CreateTypeInfo(this, core::mem::transmute(&szname), core::mem::transmute_copy(&tkind)) {
+                    Ok(ok__) => {
+                        ppctinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetName<Identity: windows_core::IUnknownImpl + ICreateTypeLib_Impl>(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeLib_Impl::SetName(this, core::mem::transmute(&szname)).into()
+            }
+        }
+        unsafe extern "system" fn SetVersion<Identity: windows_core::IUnknownImpl + ICreateTypeLib_Impl>(this: *mut core::ffi::c_void, wmajorvernum: u16, wminorvernum: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeLib_Impl::SetVersion(this, core::mem::transmute_copy(&wmajorvernum), core::mem::transmute_copy(&wminorvernum)).into()
+            }
+        }
+        unsafe extern "system" fn SetGuid<Identity: windows_core::IUnknownImpl + ICreateTypeLib_Impl>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeLib_Impl::SetGuid(this, core::mem::transmute_copy(&guid)).into()
+            }
+        }
+        unsafe extern "system" fn SetDocString<Identity: windows_core::IUnknownImpl + ICreateTypeLib_Impl>(this: *mut core::ffi::c_void, szdoc: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeLib_Impl::SetDocString(this, core::mem::transmute(&szdoc)).into()
+            }
+        }
+        unsafe extern "system" fn SetHelpFileName<Identity: windows_core::IUnknownImpl + ICreateTypeLib_Impl>(this: *mut core::ffi::c_void, szhelpfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeLib_Impl::SetHelpFileName(this, core::mem::transmute(&szhelpfilename)).into()
+            }
+        }
+        unsafe extern "system" fn SetHelpContext<Identity: windows_core::IUnknownImpl + ICreateTypeLib_Impl>(this: *mut core::ffi::c_void, dwhelpcontext: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeLib_Impl::SetHelpContext(this, core::mem::transmute_copy(&dwhelpcontext)).into()
+            }
+        }
+        unsafe extern "system" fn SetLcid<Identity: windows_core::IUnknownImpl + ICreateTypeLib_Impl>(this: *mut core::ffi::c_void, lcid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeLib_Impl::SetLcid(this, core::mem::transmute_copy(&lcid)).into()
+            }
+        }
+        unsafe extern "system" fn SetLibFlags<Identity: windows_core::IUnknownImpl + ICreateTypeLib_Impl>(this: *mut core::ffi::c_void, ulibflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeLib_Impl::SetLibFlags(this, core::mem::transmute_copy(&ulibflags)).into()
+            }
+        }
+        unsafe extern "system" fn SaveAllChanges<Identity: windows_core::IUnknownImpl + ICreateTypeLib_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeLib_Impl::SaveAllChanges(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateTypeInfo: CreateTypeInfo::<Identity>,
+            SetName: SetName::<Identity>,
+            SetVersion: SetVersion::<Identity>,
+            SetGuid: SetGuid::<Identity>,
+            SetDocString: SetDocString::<Identity>,
+            SetHelpFileName: SetHelpFileName::<Identity>,
+            SetHelpContext: SetHelpContext::<Identity>,
+            SetLcid: SetLcid::<Identity>,
+            SetLibFlags: SetLibFlags::<Identity>,
+            SaveAllChanges: SaveAllChanges::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::RuntimeName for ICreateTypeLib {}
+windows_core::imp::define_interface!(ICreateTypeLib2, ICreateTypeLib2_Vtbl, 0x0002040f_0000_0000_c000_000000000046);
+impl core::ops::Deref for ICreateTypeLib2 {
+    type Target = ICreateTypeLib;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(ICreateTypeLib2, windows_core::IUnknown, ICreateTypeLib);
 impl ICreateTypeLib2 {
     pub unsafe fn DeleteTypeInfo<P0>(&self, szname: P0) -> windows_core::Result<()>
     where
@@ -5501,6 +6027,45 @@ impl ICreateTypeLib2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ICreateTypeLib2_Impl> windows_core::imp::TearOffVtable<Identity> for ICreateTypeLib2_Vtbl
+where
+    ICreateTypeLib_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DeleteTypeInfo<Identity: windows_core::IUnknownImpl + ICreateTypeLib2_Impl>(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeLib2_Impl::DeleteTypeInfo(this, core::mem::transmute(&szname)).into()
+            }
+        }
+        unsafe extern "system" fn SetCustData<Identity: windows_core::IUnknownImpl + ICreateTypeLib2_Impl>(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pvarval: *const super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeLib2_Impl::SetCustData(this, core::mem::transmute_copy(&guid), core::mem::transmute_copy(&pvarval)).into()
+            }
+        }
+        unsafe extern "system" fn SetHelpStringContext<Identity: windows_core::IUnknownImpl + ICreateTypeLib2_Impl>(this: *mut core::ffi::c_void, dwhelpstringcontext: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeLib2_Impl::SetHelpStringContext(this, core::mem::transmute_copy(&dwhelpstringcontext)).into()
+            }
+        }
+        unsafe extern "system" fn SetHelpStringDll<Identity: windows_core::IUnknownImpl + ICreateTypeLib2_Impl>(this: *mut core::ffi::c_void, szfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ICreateTypeLib2_Impl::SetHelpStringDll(this, core::mem::transmute(&szfilename)).into()
+            }
+        }
+        Self {
+            base__: <ICreateTypeLib_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DeleteTypeInfo: DeleteTypeInfo::<Identity>,
+            SetCustData: SetCustData::<Identity>,
+            SetHelpStringContext: SetHelpStringContext::<Identity>,
+            SetHelpStringDll: SetHelpStringDll::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ICreateTypeLib2 {}
 pub const IDC_BZ_ICON: u32 = 601u32;
 pub const IDC_BZ_MESSAGE1: u32 = 602u32;
@@ -5774,6 +6339,88 @@ impl IDispError_Vtbl {
         iid == &<IDispError as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDispError_Impl> windows_core::imp::TearOffVtable<Identity> for IDispError_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryErrorInfo<Identity: windows_core::IUnknownImpl + IDispError_Impl>(this: *mut core::ffi::c_void, guiderrortype: windows_core::GUID, ppde: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispError_Impl::QueryErrorInfo(this, core::mem::transmute(&guiderrortype)) {
+                    Ok(ok__) => {
+                        ppde.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetNext<Identity: windows_core::IUnknownImpl + IDispError_Impl>(this: *mut core::ffi::c_void, ppde: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispError_Impl::GetNext(this) {
+                    Ok(ok__) => {
+                        ppde.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHresult<Identity: windows_core::IUnknownImpl + IDispError_Impl>(this: *mut core::ffi::c_void, phr: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispError_Impl::GetHresult(this) {
+                    Ok(ok__) => {
+                        phr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSource<Identity: windows_core::IUnknownImpl + IDispError_Impl>(this: *mut core::ffi::c_void, pbstrsource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispError_Impl::GetSource(this) {
+                    Ok(ok__) => {
+                        pbstrsource.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetHelpInfo<Identity: windows_core::IUnknownImpl + IDispError_Impl>(this: *mut core::ffi::c_void, pbstrfilename: *mut *mut core::ffi::c_void, pdwcontext: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDispError_Impl::GetHelpInfo(this, core::mem::transmute_copy(&pbstrfilename), core::mem::transmute_copy(&pdwcontext)).into()
+            }
+        }
+        unsafe extern "system" fn GetDescription<Identity: windows_core::IUnknownImpl + IDispError_Impl>(this: *mut core::ffi::c_void, pbstrdescription: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispError_Impl::GetDescription(this) {
+                    Ok(ok__) => {
+                        pbstrdescription.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryErrorInfo: QueryErrorInfo::<Identity>,
+            GetNext: GetNext::<Identity>,
+            GetHresult: GetHresult::<Identity>,
+            GetSource: GetSource::<Identity>,
+            GetHelpInfo: GetHelpInfo::<Identity>,
+            GetDescription: GetDescription::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDispError {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IDispatchEx, IDispatchEx_Vtbl, 0xa6ef9860_c720_11d0_9337_00a0c90dcaa9);
@@ -5958,6 +6605,103 @@ impl IDispatchEx_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IDispatchEx_Impl> windows_core::imp::TearOffVtable<Identity> for IDispatchEx_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDispID<Identity: windows_core::IUnknownImpl + IDispatchEx_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, grfdex: u32, pid: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispatchEx_Impl::GetDispID(this, core::mem::transmute(&bstrname), core::mem::transmute_copy(&grfdex)) {
+                    Ok(ok__) => {
+                        pid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InvokeEx<Identity: windows_core::IUnknownImpl + IDispatchEx_Impl>(this: *mut core::ffi::c_void, id: i32, lcid: u32, wflags: u16, pdp: *const super::Com::DISPPARAMS, pvarres: *mut super::Variant::VARIANT, pei: *mut super::Com::EXCEPINFO, pspcaller: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDispatchEx_Impl::InvokeEx(this, core::mem::transmute_copy(&id), core::mem::transmute_copy(&lcid), core::mem::transmute_copy(&wflags), core::mem::transmute_copy(&pdp), core::mem::transmute_copy(&pvarres), core::mem::transmute_copy(&pei), core::mem::transmute_copy(&pspcaller)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteMemberByName<Identity: windows_core::IUnknownImpl + IDispatchEx_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, grfdex: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDispatchEx_Impl::DeleteMemberByName(this, core::mem::transmute(&bstrname), core::mem::transmute_copy(&grfdex)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteMemberByDispID<Identity: windows_core::IUnknownImpl + IDispatchEx_Impl>(this: *mut core::ffi::c_void, id: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDispatchEx_Impl::DeleteMemberByDispID(this, core::mem::transmute_copy(&id)).into()
+            }
+        }
+        unsafe extern "system" fn GetMemberProperties<Identity: windows_core::IUnknownImpl + IDispatchEx_Impl>(this: *mut core::ffi::c_void, id: i32, grfdexfetch: u32, pgrfdex: *mut FDEX_PROP_FLAGS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispatchEx_Impl::GetMemberProperties(this, core::mem::transmute_copy(&id), core::mem::transmute_copy(&grfdexfetch)) {
+                    Ok(ok__) => {
+                        pgrfdex.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMemberName<Identity: windows_core::IUnknownImpl + IDispatchEx_Impl>(this: *mut core::ffi::c_void, id: i32, pbstrname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispatchEx_Impl::GetMemberName(this, core::mem::transmute_copy(&id)) {
+                    Ok(ok__) => {
+                        pbstrname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetNextDispID<Identity: windows_core::IUnknownImpl + IDispatchEx_Impl>(this: *mut core::ffi::c_void, grfdex: u32, id: i32, pid: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispatchEx_Impl::GetNextDispID(this, core::mem::transmute_copy(&grfdex), core::mem::transmute_copy(&id)) {
+                    Ok(ok__) => {
+                        pid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetNameSpaceParent<Identity: windows_core::IUnknownImpl + IDispatchEx_Impl>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IDispatchEx_Impl::GetNameSpaceParent(this) {
+                    Ok(ok__) => {
+                        ppunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDispID: GetDispID::<Identity>,
+            InvokeEx: InvokeEx::<Identity>,
+            DeleteMemberByName: DeleteMemberByName::<Identity>,
+            DeleteMemberByDispID: DeleteMemberByDispID::<Identity>,
+            GetMemberProperties: GetMemberProperties::<Identity>,
+            GetMemberName: GetMemberName::<Identity>,
+            GetNextDispID: GetNextDispID::<Identity>,
+            GetNameSpaceParent: GetNameSpaceParent::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IDispatchEx {}
 windows_core::imp::define_interface!(IDropSource, IDropSource_Vtbl, 0x00000121_0000_0000_c000_000000000046);
 windows_core::imp::interface_hierarchy!(IDropSource, windows_core::IUnknown);
@@ -6011,6 +6755,31 @@ impl IDropSource_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_SystemServices")]
+impl<Identity: windows_core::IUnknownImpl + IDropSource_Impl> windows_core::imp::TearOffVtable<Identity> for IDropSource_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryContinueDrag<Identity: windows_core::IUnknownImpl + IDropSource_Impl>(this: *mut core::ffi::c_void, fescapepressed: windows_core::BOOL, grfkeystate: super::SystemServices::MODIFIERKEYS_FLAGS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDropSource_Impl::QueryContinueDrag(this, core::mem::transmute_copy(&fescapepressed), core::mem::transmute_copy(&grfkeystate))
+            }
+        }
+        unsafe extern "system" fn GiveFeedback<Identity: windows_core::IUnknownImpl + IDropSource_Impl>(this: *mut core::ffi::c_void, dweffect: DROPEFFECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDropSource_Impl::GiveFeedback(this, core::mem::transmute_copy(&dweffect))
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryContinueDrag: QueryContinueDrag::<Identity>,
+            GiveFeedback: GiveFeedback::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_SystemServices")]
 impl windows_core::RuntimeName for IDropSource {}
 windows_core::imp::define_interface!(IDropSourceNotify, IDropSourceNotify_Vtbl, 0x0000012b_0000_0000_c000_000000000046);
 windows_core::imp::interface_hierarchy!(IDropSourceNotify, windows_core::IUnknown);
@@ -6057,6 +6826,30 @@ impl IDropSourceNotify_Vtbl {
         iid == &<IDropSourceNotify as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IDropSourceNotify_Impl> windows_core::imp::TearOffVtable<Identity> for IDropSourceNotify_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DragEnterTarget<Identity: windows_core::IUnknownImpl + IDropSourceNotify_Impl>(this: *mut core::ffi::c_void, hwndtarget: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDropSourceNotify_Impl::DragEnterTarget(this, core::mem::transmute_copy(&hwndtarget)).into()
+            }
+        }
+        unsafe extern "system" fn DragLeaveTarget<Identity: windows_core::IUnknownImpl + IDropSourceNotify_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDropSourceNotify_Impl::DragLeaveTarget(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DragEnterTarget: DragEnterTarget::<Identity>,
+            DragLeaveTarget: DragLeaveTarget::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IDropSourceNotify {}
 windows_core::imp::define_interface!(IDropTarget, IDropTarget_Vtbl, 0x00000122_0000_0000_c000_000000000046);
 windows_core::imp::interface_hierarchy!(IDropTarget, windows_core::IUnknown);
@@ -6148,23 +6941,62 @@ impl IDropTarget_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_SystemServices"))]
-impl windows_core::RuntimeName for IDropTarget {}
-windows_core::imp::define_interface!(IEnterpriseDropTarget, IEnterpriseDropTarget_Vtbl, 0x390e3878_fd55_4e18_819d_4682081c0cfd);
-windows_core::imp::interface_hierarchy!(IEnterpriseDropTarget, windows_core::IUnknown);
-impl IEnterpriseDropTarget {
-    pub unsafe fn SetDropSourceEnterpriseId<P0>(&self, identity: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<windows_core::PCWSTR>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).SetDropSourceEnterpriseId)(windows_core::Interface::as_raw(self), identity.param().abi()).ok() }
-    }
-    pub unsafe fn IsEvaluatingEdpPolicy(&self) -> windows_core::Result<windows_core::BOOL> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).IsEvaluatingEdpPolicy)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+impl<Identity: windows_core::IUnknownImpl + IDropTarget_Impl> windows_core::imp::TearOffVtable<Identity> for IDropTarget_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DragEnter<Identity: windows_core::IUnknownImpl + IDropTarget_Impl>(this: *mut core::ffi::c_void, pdataobj: *mut core::ffi::c_void, grfkeystate: super::SystemServices::MODIFIERKEYS_FLAGS, pt: super::super::Foundation::POINTL, pdweffect: *mut DROPEFFECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDropTarget_Impl::DragEnter(this, core::mem::transmute_copy(&pdataobj), core::mem::transmute_copy(&grfkeystate), core::mem::transmute(&pt), core::mem::transmute_copy(&pdweffect)).into()
+            }
         }
-    }
-}
+        unsafe extern "system" fn DragOver<Identity: windows_core::IUnknownImpl + IDropTarget_Impl>(this: *mut core::ffi::c_void, grfkeystate: super::SystemServices::MODIFIERKEYS_FLAGS, pt: super::super::Foundation::POINTL, pdweffect: *mut DROPEFFECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDropTarget_Impl::DragOver(this, core::mem::transmute_copy(&grfkeystate), core::mem::transmute(&pt), core::mem::transmute_copy(&pdweffect)).into()
+            }
+        }
+        unsafe extern "system" fn DragLeave<Identity: windows_core::IUnknownImpl + IDropTarget_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDropTarget_Impl::DragLeave(this).into()
+            }
+        }
+        unsafe extern "system" fn Drop<Identity: windows_core::IUnknownImpl + IDropTarget_Impl>(this: *mut core::ffi::c_void, pdataobj: *mut core::ffi::c_void, grfkeystate: super::SystemServices::MODIFIERKEYS_FLAGS, pt: super::super::Foundation::POINTL, pdweffect: *mut DROPEFFECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDropTarget_Impl::Drop(this, core::mem::transmute_copy(&pdataobj), core::mem::transmute_copy(&grfkeystate), core::mem::transmute(&pt), core::mem::transmute_copy(&pdweffect)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DragEnter: DragEnter::<Identity>,
+            DragOver: DragOver::<Identity>,
+            DragLeave: DragLeave::<Identity>,
+            Drop: Drop::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_SystemServices"))]
+impl windows_core::RuntimeName for IDropTarget {}
+windows_core::imp::define_interface!(IEnterpriseDropTarget, IEnterpriseDropTarget_Vtbl, 0x390e3878_fd55_4e18_819d_4682081c0cfd);
+windows_core::imp::interface_hierarchy!(IEnterpriseDropTarget, windows_core::IUnknown);
+impl IEnterpriseDropTarget {
+    pub unsafe fn SetDropSourceEnterpriseId<P0>(&self, identity: P0) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<windows_core::PCWSTR>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).SetDropSourceEnterpriseId)(windows_core::Interface::as_raw(self), identity.param().abi()).ok() }
+    }
+    pub unsafe fn IsEvaluatingEdpPolicy(&self) -> windows_core::Result<windows_core::BOOL> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).IsEvaluatingEdpPolicy)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+}
 #[repr(C)]
 #[doc(hidden)]
 pub struct IEnterpriseDropTarget_Vtbl {
@@ -6206,6 +7038,36 @@ impl IEnterpriseDropTarget_Vtbl {
         iid == &<IEnterpriseDropTarget as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnterpriseDropTarget_Impl> windows_core::imp::TearOffVtable<Identity> for IEnterpriseDropTarget_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetDropSourceEnterpriseId<Identity: windows_core::IUnknownImpl + IEnterpriseDropTarget_Impl>(this: *mut core::ffi::c_void, identity: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnterpriseDropTarget_Impl::SetDropSourceEnterpriseId(this, core::mem::transmute(&identity)).into()
+            }
+        }
+        unsafe extern "system" fn IsEvaluatingEdpPolicy<Identity: windows_core::IUnknownImpl + IEnterpriseDropTarget_Impl>(this: *mut core::ffi::c_void, value: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnterpriseDropTarget_Impl::IsEvaluatingEdpPolicy(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetDropSourceEnterpriseId: SetDropSourceEnterpriseId::<Identity>,
+            IsEvaluatingEdpPolicy: IsEvaluatingEdpPolicy::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnterpriseDropTarget {}
 windows_core::imp::define_interface!(IEnumOLEVERB, IEnumOLEVERB_Vtbl, 0x00000104_0000_0000_c000_000000000046);
 windows_core::imp::interface_hierarchy!(IEnumOLEVERB, windows_core::IUnknown);
@@ -6292,6 +7154,51 @@ impl IEnumOLEVERB_Vtbl {
     }
 }
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl<Identity: windows_core::IUnknownImpl + IEnumOLEVERB_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumOLEVERB_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumOLEVERB_Impl>(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut OLEVERB, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumOLEVERB_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgelt), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumOLEVERB_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumOLEVERB_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumOLEVERB_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumOLEVERB_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumOLEVERB_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumOLEVERB_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
 impl windows_core::RuntimeName for IEnumOLEVERB {}
 windows_core::imp::define_interface!(IEnumOleDocumentViews, IEnumOleDocumentViews_Vtbl, 0xb722bcc8_4e68_101b_a2bc_00aa00404770);
 windows_core::imp::interface_hierarchy!(IEnumOleDocumentViews, windows_core::IUnknown);
@@ -6371,6 +7278,50 @@ impl IEnumOleDocumentViews_Vtbl {
         iid == &<IEnumOleDocumentViews as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumOleDocumentViews_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumOleDocumentViews_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumOleDocumentViews_Impl>(this: *mut core::ffi::c_void, cviews: u32, rgpview: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumOleDocumentViews_Impl::Next(this, core::mem::transmute_copy(&cviews), core::mem::transmute_copy(&rgpview), core::mem::transmute_copy(&pcfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumOleDocumentViews_Impl>(this: *mut core::ffi::c_void, cviews: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumOleDocumentViews_Impl::Skip(this, core::mem::transmute_copy(&cviews)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumOleDocumentViews_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumOleDocumentViews_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumOleDocumentViews_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumOleDocumentViews_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumOleDocumentViews {}
 windows_core::imp::define_interface!(IEnumOleUndoUnits, IEnumOleUndoUnits_Vtbl, 0xb3e7c340_ef97_11ce_9bc9_00aa00608e01);
 windows_core::imp::interface_hierarchy!(IEnumOleUndoUnits, windows_core::IUnknown);
@@ -6450,6 +7401,50 @@ impl IEnumOleUndoUnits_Vtbl {
         iid == &<IEnumOleUndoUnits as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumOleUndoUnits_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumOleUndoUnits_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumOleUndoUnits_Impl>(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumOleUndoUnits_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgelt), core::mem::transmute_copy(&pceltfetched))
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumOleUndoUnits_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumOleUndoUnits_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumOleUndoUnits_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumOleUndoUnits_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumOleUndoUnits_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumOleUndoUnits_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumOleUndoUnits {}
 windows_core::imp::define_interface!(IEnumVARIANT, IEnumVARIANT_Vtbl, 0x00020404_0000_0000_c000_000000000046);
 windows_core::imp::interface_hierarchy!(IEnumVARIANT, windows_core::IUnknown);
@@ -6536,6 +7531,51 @@ impl IEnumVARIANT_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IEnumVARIANT_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumVARIANT_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumVARIANT_Impl>(this: *mut core::ffi::c_void, celt: u32, rgvar: *mut super::Variant::VARIANT, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumVARIANT_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgvar), core::mem::transmute_copy(&pceltfetched))
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumVARIANT_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumVARIANT_Impl::Skip(this, core::mem::transmute_copy(&celt))
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumVARIANT_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumVARIANT_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumVARIANT_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumVARIANT_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IEnumVARIANT {}
 windows_core::imp::define_interface!(IFont, IFont_Vtbl, 0xbef6e002_a874_101a_8bba_00aa00300cab);
 windows_core::imp::interface_hierarchy!(IFont, windows_core::IUnknown);
@@ -6970,102 +8010,369 @@ impl IFont_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
-impl windows_core::RuntimeName for IFont {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IFontDisp, IFontDisp_Vtbl, 0xbef6e003_a874_101a_8bba_00aa00300cab);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IFontDisp {
-    type Target = super::Com::IDispatch;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IFontDisp, windows_core::IUnknown, super::Com::IDispatch);
-#[cfg(feature = "Win32_System_Com")]
-#[repr(C)]
-#[doc(hidden)]
-pub struct IFontDisp_Vtbl {
-    pub base__: super::Com::IDispatch_Vtbl,
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
-pub trait IFontDisp_Impl: super::Com::IDispatch_Impl {}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
-impl IFontDisp_Vtbl {
-    pub const fn new<Identity: IFontDisp_Impl, const OFFSET: isize>() -> Self {
-        Self { base__: super::Com::IDispatch_Vtbl::new::<Identity, OFFSET>() }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IFontDisp as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID
-    }
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IFontDisp {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IFontEventsDisp, IFontEventsDisp_Vtbl, 0x4ef6100a_af88_11d0_9846_00c04fc29993);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IFontEventsDisp {
-    type Target = super::Com::IDispatch;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IFontEventsDisp, windows_core::IUnknown, super::Com::IDispatch);
-#[cfg(feature = "Win32_System_Com")]
-#[repr(C)]
-#[doc(hidden)]
-pub struct IFontEventsDisp_Vtbl {
-    pub base__: super::Com::IDispatch_Vtbl,
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
-pub trait IFontEventsDisp_Impl: super::Com::IDispatch_Impl {}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
-impl IFontEventsDisp_Vtbl {
-    pub const fn new<Identity: IFontEventsDisp_Impl, const OFFSET: isize>() -> Self {
-        Self { base__: super::Com::IDispatch_Vtbl::new::<Identity, OFFSET>() }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IFontEventsDisp as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID
-    }
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IFontEventsDisp {}
-#[repr(transparent)]
-#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
-pub struct IGNOREMIME(pub i32);
-pub const IGNOREMIME_PROMPT: IGNOREMIME = IGNOREMIME(1i32);
-pub const IGNOREMIME_TEXT: IGNOREMIME = IGNOREMIME(2i32);
-windows_core::imp::define_interface!(IGetOleObject, IGetOleObject_Vtbl, 0x8a701da0_4feb_101b_a82e_08002b2b2337);
-windows_core::imp::interface_hierarchy!(IGetOleObject, windows_core::IUnknown);
-impl IGetOleObject {
-    pub unsafe fn GetOleObject(&self, riid: *const windows_core::GUID, ppvobj: *mut *mut core::ffi::c_void) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).GetOleObject)(windows_core::Interface::as_raw(self), riid, ppvobj as _).ok() }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IGetOleObject_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    pub GetOleObject: unsafe extern "system" fn(*mut core::ffi::c_void, *const windows_core::GUID, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-pub trait IGetOleObject_Impl: windows_core::IUnknownImpl {
-    fn GetOleObject(&self, riid: *const windows_core::GUID, ppvobj: *mut *mut core::ffi::c_void) -> windows_core::Result<()>;
-}
-impl IGetOleObject_Vtbl {
-    pub const fn new<Identity: IGetOleObject_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn GetOleObject<Identity: IGetOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl<Identity: windows_core::IUnknownImpl + IFont_Impl> windows_core::imp::TearOffVtable<Identity> for IFont_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, pname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IGetOleObject_Impl::GetOleObject(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvobj)).into()
-            }
-        }
-        Self { base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(), GetOleObject: GetOleObject::<Identity, OFFSET> }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFont_Impl::Name(this) {
+                    Ok(ok__) => {
+                        pname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetName<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFont_Impl::SetName(this, core::mem::transmute(&name)).into()
+            }
+        }
+        unsafe extern "system" fn Size<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, psize: *mut super::Com::CY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFont_Impl::Size(this) {
+                    Ok(ok__) => {
+                        psize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSize<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, size: super::Com::CY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFont_Impl::SetSize(this, core::mem::transmute(&size)).into()
+            }
+        }
+        unsafe extern "system" fn Bold<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, pbold: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFont_Impl::Bold(this) {
+                    Ok(ok__) => {
+                        pbold.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBold<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, bold: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFont_Impl::SetBold(this, core::mem::transmute_copy(&bold)).into()
+            }
+        }
+        unsafe extern "system" fn Italic<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, pitalic: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFont_Impl::Italic(this) {
+                    Ok(ok__) => {
+                        pitalic.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetItalic<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, italic: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFont_Impl::SetItalic(this, core::mem::transmute_copy(&italic)).into()
+            }
+        }
+        unsafe extern "system" fn Underline<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, punderline: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFont_Impl::Underline(this) {
+                    Ok(ok__) => {
+                        punderline.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetUnderline<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, underline: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFont_Impl::SetUnderline(this, core::mem::transmute_copy(&underline)).into()
+            }
+        }
+        unsafe extern "system" fn Strikethrough<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, pstrikethrough: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFont_Impl::Strikethrough(this) {
+                    Ok(ok__) => {
+                        pstrikethrough.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetStrikethrough<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, strikethrough: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFont_Impl::SetStrikethrough(this, core::mem::transmute_copy(&strikethrough)).into()
+            }
+        }
+        unsafe extern "system" fn Weight<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, pweight: *mut i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFont_Impl::Weight(this) {
+                    Ok(ok__) => {
+                        pweight.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetWeight<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, weight: i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFont_Impl::SetWeight(this, core::mem::transmute_copy(&weight)).into()
+            }
+        }
+        unsafe extern "system" fn Charset<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, pcharset: *mut i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFont_Impl::Charset(this) {
+                    Ok(ok__) => {
+                        pcharset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCharset<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, charset: i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFont_Impl::SetCharset(this, core::mem::transmute_copy(&charset)).into()
+            }
+        }
+        unsafe extern "system" fn hFont<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, phfont: *mut super::super::Graphics::Gdi::HFONT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFont_Impl::hFont(this) {
+                    Ok(ok__) => {
+                        phfont.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, ppfont: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IFont_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppfont.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsEqual<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, pfontother: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFont_Impl::IsEqual(this, core::mem::transmute_copy(&pfontother)).into()
+            }
+        }
+        unsafe extern "system" fn SetRatio<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, cylogical: i32, cyhimetric: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFont_Impl::SetRatio(this, core::mem::transmute_copy(&cylogical), core::mem::transmute_copy(&cyhimetric)).into()
+            }
+        }
+        unsafe extern "system" fn QueryTextMetrics<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, ptm: *mut super::super::Graphics::Gdi::TEXTMETRICW) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFont_Impl::QueryTextMetrics(this, core::mem::transmute_copy(&ptm)).into()
+            }
+        }
+        unsafe extern "system" fn AddRefHfont<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, hfont: super::super::Graphics::Gdi::HFONT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFont_Impl::AddRefHfont(this, core::mem::transmute_copy(&hfont)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseHfont<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, hfont: super::super::Graphics::Gdi::HFONT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFont_Impl::ReleaseHfont(this, core::mem::transmute_copy(&hfont)).into()
+            }
+        }
+        unsafe extern "system" fn SetHdc<Identity: windows_core::IUnknownImpl + IFont_Impl>(this: *mut core::ffi::c_void, hdc: super::super::Graphics::Gdi::HDC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IFont_Impl::SetHdc(this, core::mem::transmute_copy(&hdc)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Name: Name::<Identity>,
+            SetName: SetName::<Identity>,
+            Size: Size::<Identity>,
+            SetSize: SetSize::<Identity>,
+            Bold: Bold::<Identity>,
+            SetBold: SetBold::<Identity>,
+            Italic: Italic::<Identity>,
+            SetItalic: SetItalic::<Identity>,
+            Underline: Underline::<Identity>,
+            SetUnderline: SetUnderline::<Identity>,
+            Strikethrough: Strikethrough::<Identity>,
+            SetStrikethrough: SetStrikethrough::<Identity>,
+            Weight: Weight::<Identity>,
+            SetWeight: SetWeight::<Identity>,
+            Charset: Charset::<Identity>,
+            SetCharset: SetCharset::<Identity>,
+            hFont: hFont::<Identity>,
+            Clone: Clone::<Identity>,
+            IsEqual: IsEqual::<Identity>,
+            SetRatio: SetRatio::<Identity>,
+            QueryTextMetrics: QueryTextMetrics::<Identity>,
+            AddRefHfont: AddRefHfont::<Identity>,
+            ReleaseHfont: ReleaseHfont::<Identity>,
+            SetHdc: SetHdc::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl windows_core::RuntimeName for IFont {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(IFontDisp, IFontDisp_Vtbl, 0xbef6e003_a874_101a_8bba_00aa00300cab);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for IFontDisp {
+    type Target = super::Com::IDispatch;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(IFontDisp, windows_core::IUnknown, super::Com::IDispatch);
+#[cfg(feature = "Win32_System_Com")]
+#[repr(C)]
+#[doc(hidden)]
+pub struct IFontDisp_Vtbl {
+    pub base__: super::Com::IDispatch_Vtbl,
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+pub trait IFontDisp_Impl: super::Com::IDispatch_Impl {}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+impl IFontDisp_Vtbl {
+    pub const fn new<Identity: IFontDisp_Impl, const OFFSET: isize>() -> Self {
+        Self { base__: super::Com::IDispatch_Vtbl::new::<Identity, OFFSET>() }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFontDisp as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IFontDisp_Impl> windows_core::imp::TearOffVtable<Identity> for IFontDisp_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IFontDisp {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(IFontEventsDisp, IFontEventsDisp_Vtbl, 0x4ef6100a_af88_11d0_9846_00c04fc29993);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for IFontEventsDisp {
+    type Target = super::Com::IDispatch;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(IFontEventsDisp, windows_core::IUnknown, super::Com::IDispatch);
+#[cfg(feature = "Win32_System_Com")]
+#[repr(C)]
+#[doc(hidden)]
+pub struct IFontEventsDisp_Vtbl {
+    pub base__: super::Com::IDispatch_Vtbl,
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+pub trait IFontEventsDisp_Impl: super::Com::IDispatch_Impl {}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+impl IFontEventsDisp_Vtbl {
+    pub const fn new<Identity: IFontEventsDisp_Impl, const OFFSET: isize>() -> Self {
+        Self { base__: super::Com::IDispatch_Vtbl::new::<Identity, OFFSET>() }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFontEventsDisp as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IFontEventsDisp_Impl> windows_core::imp::TearOffVtable<Identity> for IFontEventsDisp_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IFontEventsDisp {}
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct IGNOREMIME(pub i32);
+pub const IGNOREMIME_PROMPT: IGNOREMIME = IGNOREMIME(1i32);
+pub const IGNOREMIME_TEXT: IGNOREMIME = IGNOREMIME(2i32);
+windows_core::imp::define_interface!(IGetOleObject, IGetOleObject_Vtbl, 0x8a701da0_4feb_101b_a82e_08002b2b2337);
+windows_core::imp::interface_hierarchy!(IGetOleObject, windows_core::IUnknown);
+impl IGetOleObject {
+    pub unsafe fn GetOleObject(&self, riid: *const windows_core::GUID, ppvobj: *mut *mut core::ffi::c_void) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).GetOleObject)(windows_core::Interface::as_raw(self), riid, ppvobj as _).ok() }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IGetOleObject_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub GetOleObject: unsafe extern "system" fn(*mut core::ffi::c_void, *const windows_core::GUID, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+pub trait IGetOleObject_Impl: windows_core::IUnknownImpl {
+    fn GetOleObject(&self, riid: *const windows_core::GUID, ppvobj: *mut *mut core::ffi::c_void) -> windows_core::Result<()>;
+}
+impl IGetOleObject_Vtbl {
+    pub const fn new<Identity: IGetOleObject_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn GetOleObject<Identity: IGetOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IGetOleObject_Impl::GetOleObject(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvobj)).into()
+            }
+        }
+        Self { base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(), GetOleObject: GetOleObject::<Identity, OFFSET> }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
         iid == &<IGetOleObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IGetOleObject_Impl> windows_core::imp::TearOffVtable<Identity> for IGetOleObject_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetOleObject<Identity: windows_core::IUnknownImpl + IGetOleObject_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGetOleObject_Impl::GetOleObject(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvobj)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetOleObject: GetOleObject::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IGetOleObject {}
 windows_core::imp::define_interface!(IGetVBAObject, IGetVBAObject_Vtbl, 0x91733a60_3f4c_101b_a3f6_00aa0034e4e9);
 windows_core::imp::interface_hierarchy!(IGetVBAObject, windows_core::IUnknown);
@@ -7097,6 +8404,20 @@ impl IGetVBAObject_Vtbl {
         iid == &<IGetVBAObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IGetVBAObject_Impl> windows_core::imp::TearOffVtable<Identity> for IGetVBAObject_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetObject<Identity: windows_core::IUnknownImpl + IGetVBAObject_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvobj: *mut *mut core::ffi::c_void, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IGetVBAObject_Impl::GetObject(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvobj), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetObject: GetObject::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IGetVBAObject {}
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -7197,6 +8518,20 @@ impl IObjectIdentity_Vtbl {
         iid == &<IObjectIdentity as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IObjectIdentity_Impl> windows_core::imp::TearOffVtable<Identity> for IObjectIdentity_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsEqualObject<Identity: windows_core::IUnknownImpl + IObjectIdentity_Impl>(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IObjectIdentity_Impl::IsEqualObject(this, core::mem::transmute_copy(&punk)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, IsEqualObject: IsEqualObject::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IObjectIdentity {}
 windows_core::imp::define_interface!(IObjectWithSite, IObjectWithSite_Vtbl, 0xfc4801a3_2ba9_11cf_a229_00aa003d7352);
 windows_core::imp::interface_hierarchy!(IObjectWithSite, windows_core::IUnknown);
@@ -7246,6 +8581,30 @@ impl IObjectWithSite_Vtbl {
         iid == &<IObjectWithSite as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IObjectWithSite_Impl> windows_core::imp::TearOffVtable<Identity> for IObjectWithSite_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetSite<Identity: windows_core::IUnknownImpl + IObjectWithSite_Impl>(this: *mut core::ffi::c_void, punksite: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IObjectWithSite_Impl::SetSite(this, core::mem::transmute_copy(&punksite)).into()
+            }
+        }
+        unsafe extern "system" fn GetSite<Identity: windows_core::IUnknownImpl + IObjectWithSite_Impl>(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvsite: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IObjectWithSite_Impl::GetSite(this, core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvsite)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetSite: SetSite::<Identity>,
+            GetSite: GetSite::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IObjectWithSite {}
 windows_core::imp::define_interface!(IOleAdviseHolder, IOleAdviseHolder_Vtbl, 0x00000111_0000_0000_c000_000000000046);
 windows_core::imp::interface_hierarchy!(IOleAdviseHolder, windows_core::IUnknown);
@@ -7379,6 +8738,71 @@ impl IOleAdviseHolder_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOleAdviseHolder_Impl> windows_core::imp::TearOffVtable<Identity> for IOleAdviseHolder_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Advise<Identity: windows_core::IUnknownImpl + IOleAdviseHolder_Impl>(this: *mut core::ffi::c_void, padvise: *mut core::ffi::c_void, pdwconnection: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleAdviseHolder_Impl::Advise(this, core::mem::transmute_copy(&padvise)) {
+                    Ok(ok__) => {
+                        pdwconnection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Unadvise<Identity: windows_core::IUnknownImpl + IOleAdviseHolder_Impl>(this: *mut core::ffi::c_void, dwconnection: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleAdviseHolder_Impl::Unadvise(this, core::mem::transmute_copy(&dwconnection)).into()
+            }
+        }
+        unsafe extern "system" fn EnumAdvise<Identity: windows_core::IUnknownImpl + IOleAdviseHolder_Impl>(this: *mut core::ffi::c_void, ppenumadvise: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleAdviseHolder_Impl::EnumAdvise(this) {
+                    Ok(ok__) => {
+                        ppenumadvise.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SendOnRename<Identity: windows_core::IUnknownImpl + IOleAdviseHolder_Impl>(this: *mut core::ffi::c_void, pmk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleAdviseHolder_Impl::SendOnRename(this, core::mem::transmute_copy(&pmk)).into()
+            }
+        }
+        unsafe extern "system" fn SendOnSave<Identity: windows_core::IUnknownImpl + IOleAdviseHolder_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleAdviseHolder_Impl::SendOnSave(this).into()
+            }
+        }
+        unsafe extern "system" fn SendOnClose<Identity: windows_core::IUnknownImpl + IOleAdviseHolder_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleAdviseHolder_Impl::SendOnClose(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Advise: Advise::<Identity>,
+            Unadvise: Unadvise::<Identity>,
+            EnumAdvise: EnumAdvise::<Identity>,
+            SendOnRename: SendOnRename::<Identity>,
+            SendOnSave: SendOnSave::<Identity>,
+            SendOnClose: SendOnClose::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOleAdviseHolder {}
 windows_core::imp::define_interface!(IOleCache, IOleCache_Vtbl, 0x0000011e_0000_0000_c000_000000000046);
 windows_core::imp::interface_hierarchy!(IOleCache, windows_core::IUnknown);
@@ -7501,6 +8925,64 @@ impl IOleCache_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+impl<Identity: windows_core::IUnknownImpl + IOleCache_Impl> windows_core::imp::TearOffVtable<Identity> for IOleCache_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Cache<Identity: windows_core::IUnknownImpl + IOleCache_Impl>(this: *mut core::ffi::c_void, pformatetc: *const super::Com::FORMATETC, advf: u32, pdwconnection: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleCache_Impl::Cache(this, core::mem::transmute_copy(&pformatetc), core::mem::transmute_copy(&advf)) {
+                    Ok(ok__) => {
+                        pdwconnection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Uncache<Identity: windows_core::IUnknownImpl + IOleCache_Impl>(this: *mut core::ffi::c_void, dwconnection: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleCache_Impl::Uncache(this, core::mem::transmute_copy(&dwconnection)).into()
+            }
+        }
+        unsafe extern "system" fn EnumCache<Identity: windows_core::IUnknownImpl + IOleCache_Impl>(this: *mut core::ffi::c_void, ppenumstatdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleCache_Impl::EnumCache(this) {
+                    Ok(ok__) => {
+                        ppenumstatdata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InitCache<Identity: windows_core::IUnknownImpl + IOleCache_Impl>(this: *mut core::ffi::c_void, pdataobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleCache_Impl::InitCache(this, core::mem::transmute_copy(&pdataobject)).into()
+            }
+        }
+        unsafe extern "system" fn SetData<Identity: windows_core::IUnknownImpl + IOleCache_Impl>(this: *mut core::ffi::c_void, pformatetc: *const super::Com::FORMATETC, pmedium: *const super::Com::STGMEDIUM, frelease: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleCache_Impl::SetData(this, core::mem::transmute_copy(&pformatetc), core::mem::transmute_copy(&pmedium), core::mem::transmute_copy(&frelease)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Cache: Cache::<Identity>,
+            Uncache: Uncache::<Identity>,
+            EnumCache: EnumCache::<Identity>,
+            InitCache: InitCache::<Identity>,
+            SetData: SetData::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
 impl windows_core::RuntimeName for IOleCache {}
 windows_core::imp::define_interface!(IOleCache2, IOleCache2_Vtbl, 0x00000128_0000_0000_c000_000000000046);
 impl core::ops::Deref for IOleCache2 {
@@ -7559,6 +9041,31 @@ impl IOleCache2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+impl<Identity: windows_core::IUnknownImpl + IOleCache2_Impl> windows_core::imp::TearOffVtable<Identity> for IOleCache2_Vtbl
+where
+    IOleCache_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn UpdateCache<Identity: windows_core::IUnknownImpl + IOleCache2_Impl>(this: *mut core::ffi::c_void, pdataobject: *mut core::ffi::c_void, grfupdf: UPDFCACHE_FLAGS, preserved: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleCache2_Impl::UpdateCache(this, core::mem::transmute_copy(&pdataobject), core::mem::transmute_copy(&grfupdf), core::mem::transmute_copy(&preserved)).into()
+            }
+        }
+        unsafe extern "system" fn DiscardCache<Identity: windows_core::IUnknownImpl + IOleCache2_Impl>(this: *mut core::ffi::c_void, dwdiscardoptions: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleCache2_Impl::DiscardCache(this, core::mem::transmute_copy(&dwdiscardoptions)).into()
+            }
+        }
+        Self {
+            base__: <IOleCache_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            UpdateCache: UpdateCache::<Identity>,
+            DiscardCache: DiscardCache::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
 impl windows_core::RuntimeName for IOleCache2 {}
 windows_core::imp::define_interface!(IOleCacheControl, IOleCacheControl_Vtbl, 0x00000129_0000_0000_c000_000000000046);
 windows_core::imp::interface_hierarchy!(IOleCacheControl, windows_core::IUnknown);
@@ -7611,6 +9118,31 @@ impl IOleCacheControl_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOleCacheControl_Impl> windows_core::imp::TearOffVtable<Identity> for IOleCacheControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnRun<Identity: windows_core::IUnknownImpl + IOleCacheControl_Impl>(this: *mut core::ffi::c_void, pdataobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleCacheControl_Impl::OnRun(this, core::mem::transmute_copy(&pdataobject)).into()
+            }
+        }
+        unsafe extern "system" fn OnStop<Identity: windows_core::IUnknownImpl + IOleCacheControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleCacheControl_Impl::OnStop(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnRun: OnRun::<Identity>,
+            OnStop: OnStop::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOleCacheControl {}
 windows_core::imp::define_interface!(IOleClientSite, IOleClientSite_Vtbl, 0x00000118_0000_0000_c000_000000000046);
 windows_core::imp::interface_hierarchy!(IOleClientSite, windows_core::IUnknown);
@@ -7665,17 +9197,85 @@ pub trait IOleClientSite_Impl: windows_core::IUnknownImpl {
     fn RequestNewObjectLayout(&self) -> windows_core::Result<()>;
 }
 #[cfg(feature = "Win32_System_Com")]
-impl IOleClientSite_Vtbl {
-    pub const fn new<Identity: IOleClientSite_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn SaveObject<Identity: IOleClientSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl IOleClientSite_Vtbl {
+    pub const fn new<Identity: IOleClientSite_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn SaveObject<Identity: IOleClientSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleClientSite_Impl::SaveObject(this).into()
+            }
+        }
+        unsafe extern "system" fn GetMoniker<Identity: IOleClientSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwassign: u32, dwwhichmoniker: u32, ppmk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOleClientSite_Impl::GetMoniker(this, core::mem::transmute(&dwassign), core::mem::transmute(&dwwhichmoniker)) {
+                    Ok(ok__) => {
+                        ppmk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetContainer<Identity: IOleClientSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppcontainer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOleClientSite_Impl::GetContainer(this) {
+                    Ok(ok__) => {
+                        ppcontainer.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ShowObject<Identity: IOleClientSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleClientSite_Impl::ShowObject(this).into()
+            }
+        }
+        unsafe extern "system" fn OnShowWindow<Identity: IOleClientSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fshow: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleClientSite_Impl::OnShowWindow(this, core::mem::transmute_copy(&fshow)).into()
+            }
+        }
+        unsafe extern "system" fn RequestNewObjectLayout<Identity: IOleClientSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleClientSite_Impl::RequestNewObjectLayout(this).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            SaveObject: SaveObject::<Identity, OFFSET>,
+            GetMoniker: GetMoniker::<Identity, OFFSET>,
+            GetContainer: GetContainer::<Identity, OFFSET>,
+            ShowObject: ShowObject::<Identity, OFFSET>,
+            OnShowWindow: OnShowWindow::<Identity, OFFSET>,
+            RequestNewObjectLayout: RequestNewObjectLayout::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleClientSite as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOleClientSite_Impl> windows_core::imp::TearOffVtable<Identity> for IOleClientSite_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SaveObject<Identity: windows_core::IUnknownImpl + IOleClientSite_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleClientSite_Impl::SaveObject(this).into()
             }
         }
-        unsafe extern "system" fn GetMoniker<Identity: IOleClientSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwassign: u32, dwwhichmoniker: u32, ppmk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetMoniker<Identity: windows_core::IUnknownImpl + IOleClientSite_Impl>(this: *mut core::ffi::c_void, dwassign: u32, dwwhichmoniker: u32, ppmk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOleClientSite_Impl::GetMoniker(this, core::mem::transmute(&dwassign), core::mem::transmute(&dwwhichmoniker)) {
                     Ok(ok__) => {
                         ppmk.write(core::mem::transmute(ok__));
@@ -7685,9 +9285,9 @@ impl IOleClientSite_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetContainer<Identity: IOleClientSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppcontainer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetContainer<Identity: windows_core::IUnknownImpl + IOleClientSite_Impl>(this: *mut core::ffi::c_void, ppcontainer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOleClientSite_Impl::GetContainer(this) {
                     Ok(ok__) => {
                         ppcontainer.write(core::mem::transmute(ok__));
@@ -7697,37 +9297,34 @@ impl IOleClientSite_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ShowObject<Identity: IOleClientSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn ShowObject<Identity: windows_core::IUnknownImpl + IOleClientSite_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleClientSite_Impl::ShowObject(this).into()
             }
         }
-        unsafe extern "system" fn OnShowWindow<Identity: IOleClientSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fshow: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn OnShowWindow<Identity: windows_core::IUnknownImpl + IOleClientSite_Impl>(this: *mut core::ffi::c_void, fshow: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleClientSite_Impl::OnShowWindow(this, core::mem::transmute_copy(&fshow)).into()
             }
         }
-        unsafe extern "system" fn RequestNewObjectLayout<Identity: IOleClientSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn RequestNewObjectLayout<Identity: windows_core::IUnknownImpl + IOleClientSite_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleClientSite_Impl::RequestNewObjectLayout(this).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            SaveObject: SaveObject::<Identity, OFFSET>,
-            GetMoniker: GetMoniker::<Identity, OFFSET>,
-            GetContainer: GetContainer::<Identity, OFFSET>,
-            ShowObject: ShowObject::<Identity, OFFSET>,
-            OnShowWindow: OnShowWindow::<Identity, OFFSET>,
-            RequestNewObjectLayout: RequestNewObjectLayout::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SaveObject: SaveObject::<Identity>,
+            GetMoniker: GetMoniker::<Identity>,
+            GetContainer: GetContainer::<Identity>,
+            ShowObject: ShowObject::<Identity>,
+            OnShowWindow: OnShowWindow::<Identity>,
+            RequestNewObjectLayout: RequestNewObjectLayout::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IOleClientSite as windows_core::Interface>::IID
-    }
+    };
 }
 #[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOleClientSite {}
@@ -7779,6 +9376,31 @@ impl IOleCommandTarget_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IOleCommandTarget_Impl> windows_core::imp::TearOffVtable<Identity> for IOleCommandTarget_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryStatus<Identity: windows_core::IUnknownImpl + IOleCommandTarget_Impl>(this: *mut core::ffi::c_void, pguidcmdgroup: *const windows_core::GUID, ccmds: u32, prgcmds: *mut OLECMD, pcmdtext: *mut OLECMDTEXT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleCommandTarget_Impl::QueryStatus(this, core::mem::transmute_copy(&pguidcmdgroup), core::mem::transmute_copy(&ccmds), core::mem::transmute_copy(&prgcmds), core::mem::transmute_copy(&pcmdtext)).into()
+            }
+        }
+        unsafe extern "system" fn Exec<Identity: windows_core::IUnknownImpl + IOleCommandTarget_Impl>(this: *mut core::ffi::c_void, pguidcmdgroup: *const windows_core::GUID, ncmdid: u32, ncmdexecopt: u32, pvain: *const super::Variant::VARIANT, pvaout: *mut super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleCommandTarget_Impl::Exec(this, core::mem::transmute_copy(&pguidcmdgroup), core::mem::transmute_copy(&ncmdid), core::mem::transmute_copy(&ncmdexecopt), core::mem::transmute_copy(&pvain), core::mem::transmute_copy(&pvaout)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryStatus: QueryStatus::<Identity>,
+            Exec: Exec::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IOleCommandTarget {}
 windows_core::imp::define_interface!(IOleContainer, IOleContainer_Vtbl, 0x0000011b_0000_0000_c000_000000000046);
 impl core::ops::Deref for IOleContainer {
@@ -7847,6 +9469,37 @@ impl IOleContainer_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOleContainer_Impl> windows_core::imp::TearOffVtable<Identity> for IOleContainer_Vtbl
+where
+    IParseDisplayName_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumObjects<Identity: windows_core::IUnknownImpl + IOleContainer_Impl>(this: *mut core::ffi::c_void, grfflags: u32, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleContainer_Impl::EnumObjects(this, core::mem::transmute(&grfflags)) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LockContainer<Identity: windows_core::IUnknownImpl + IOleContainer_Impl>(this: *mut core::ffi::c_void, flock: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleContainer_Impl::LockContainer(this, core::mem::transmute_copy(&flock)).into()
+            }
+        }
+        Self {
+            base__: <IParseDisplayName_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnumObjects: EnumObjects::<Identity>,
+            LockContainer: LockContainer::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOleContainer {}
 windows_core::imp::define_interface!(IOleControl, IOleControl_Vtbl, 0xb196b288_bab4_101a_b69c_00aa00341d07);
 windows_core::imp::interface_hierarchy!(IOleControl, windows_core::IUnknown);
@@ -7928,6 +9581,45 @@ impl IOleControl_Vtbl {
     }
 }
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl<Identity: windows_core::IUnknownImpl + IOleControl_Impl> windows_core::imp::TearOffVtable<Identity> for IOleControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetControlInfo<Identity: windows_core::IUnknownImpl + IOleControl_Impl>(this: *mut core::ffi::c_void, pci: *mut CONTROLINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleControl_Impl::GetControlInfo(this, core::mem::transmute_copy(&pci)).into()
+            }
+        }
+        unsafe extern "system" fn OnMnemonic<Identity: windows_core::IUnknownImpl + IOleControl_Impl>(this: *mut core::ffi::c_void, pmsg: *const super::super::UI::WindowsAndMessaging::MSG) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleControl_Impl::OnMnemonic(this, core::mem::transmute_copy(&pmsg)).into()
+            }
+        }
+        unsafe extern "system" fn OnAmbientPropertyChange<Identity: windows_core::IUnknownImpl + IOleControl_Impl>(this: *mut core::ffi::c_void, dispid: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleControl_Impl::OnAmbientPropertyChange(this, core::mem::transmute_copy(&dispid)).into()
+            }
+        }
+        unsafe extern "system" fn FreezeEvents<Identity: windows_core::IUnknownImpl + IOleControl_Impl>(this: *mut core::ffi::c_void, bfreeze: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleControl_Impl::FreezeEvents(this, core::mem::transmute_copy(&bfreeze)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetControlInfo: GetControlInfo::<Identity>,
+            OnMnemonic: OnMnemonic::<Identity>,
+            OnAmbientPropertyChange: OnAmbientPropertyChange::<Identity>,
+            FreezeEvents: FreezeEvents::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
 impl windows_core::RuntimeName for IOleControl {}
 windows_core::imp::define_interface!(IOleControlSite, IOleControlSite_Vtbl, 0xb196b289_bab4_101a_b69c_00aa00341d07);
 windows_core::imp::interface_hierarchy!(IOleControlSite, windows_core::IUnknown);
@@ -8054,6 +9746,72 @@ impl IOleControlSite_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_WindowsAndMessaging"))]
+impl<Identity: windows_core::IUnknownImpl + IOleControlSite_Impl> windows_core::imp::TearOffVtable<Identity> for IOleControlSite_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnControlInfoChanged<Identity: windows_core::IUnknownImpl + IOleControlSite_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleControlSite_Impl::OnControlInfoChanged(this).into()
+            }
+        }
+        unsafe extern "system" fn LockInPlaceActive<Identity: windows_core::IUnknownImpl + IOleControlSite_Impl>(this: *mut core::ffi::c_void, flock: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleControlSite_Impl::LockInPlaceActive(this, core::mem::transmute_copy(&flock)).into()
+            }
+        }
+        unsafe extern "system" fn GetExtendedControl<Identity: windows_core::IUnknownImpl + IOleControlSite_Impl>(this: *mut core::ffi::c_void, ppdisp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleControlSite_Impl::GetExtendedControl(this) {
+                    Ok(ok__) => {
+                        ppdisp.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn TransformCoords<Identity: windows_core::IUnknownImpl + IOleControlSite_Impl>(this: *mut core::ffi::c_void, pptlhimetric: *mut super::super::Foundation::POINTL, pptfcontainer: *mut POINTF, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleControlSite_Impl::TransformCoords(this, core::mem::transmute_copy(&pptlhimetric), core::mem::transmute_copy(&pptfcontainer), core::mem::transmute(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn TranslateAccelerator<Identity: windows_core::IUnknownImpl + IOleControlSite_Impl>(this: *mut core::ffi::c_void, pmsg: *const super::super::UI::WindowsAndMessaging::MSG, grfmodifiers: KEYMODIFIERS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleControlSite_Impl::TranslateAccelerator(this, core::mem::transmute_copy(&pmsg), core::mem::transmute_copy(&grfmodifiers)).into()
+            }
+        }
+        unsafe extern "system" fn OnFocus<Identity: windows_core::IUnknownImpl + IOleControlSite_Impl>(this: *mut core::ffi::c_void, fgotfocus: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleControlSite_Impl::OnFocus(this, core::mem::transmute_copy(&fgotfocus)).into()
+            }
+        }
+        unsafe extern "system" fn ShowPropertyFrame<Identity: windows_core::IUnknownImpl + IOleControlSite_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleControlSite_Impl::ShowPropertyFrame(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnControlInfoChanged: OnControlInfoChanged::<Identity>,
+            LockInPlaceActive: LockInPlaceActive::<Identity>,
+            GetExtendedControl: GetExtendedControl::<Identity>,
+            TransformCoords: TransformCoords::<Identity>,
+            TranslateAccelerator: TranslateAccelerator::<Identity>,
+            OnFocus: OnFocus::<Identity>,
+            ShowPropertyFrame: ShowPropertyFrame::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_WindowsAndMessaging"))]
 impl windows_core::RuntimeName for IOleControlSite {}
 windows_core::imp::define_interface!(IOleDocument, IOleDocument_Vtbl, 0xb722bcc5_4e68_101b_a2bc_00aa00404770);
 windows_core::imp::interface_hierarchy!(IOleDocument, windows_core::IUnknown);
@@ -8141,6 +9899,50 @@ impl IOleDocument_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOleDocument_Impl> windows_core::imp::TearOffVtable<Identity> for IOleDocument_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateView<Identity: windows_core::IUnknownImpl + IOleDocument_Impl>(this: *mut core::ffi::c_void, pipsite: *mut core::ffi::c_void, pstm: *mut core::ffi::c_void, dwreserved: u32, ppview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleDocument_Impl::CreateView(this, core::mem::transmute_copy(&pipsite), core::mem::transmute_copy(&pstm), core::mem::transmute_copy(&dwreserved)) {
+                    Ok(ok__) => {
+                        ppview.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDocMiscStatus<Identity: windows_core::IUnknownImpl + IOleDocument_Impl>(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleDocument_Impl::GetDocMiscStatus(this) {
+                    Ok(ok__) => {
+                        pdwstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumViews<Identity: windows_core::IUnknownImpl + IOleDocument_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void, ppview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleDocument_Impl::EnumViews(this, core::mem::transmute_copy(&ppenum), core::mem::transmute_copy(&ppview)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateView: CreateView::<Identity>,
+            GetDocMiscStatus: GetDocMiscStatus::<Identity>,
+            EnumViews: EnumViews::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOleDocument {}
 windows_core::imp::define_interface!(IOleDocumentSite, IOleDocumentSite_Vtbl, 0xb722bcc7_4e68_101b_a2bc_00aa00404770);
 windows_core::imp::interface_hierarchy!(IOleDocumentSite, windows_core::IUnknown);
@@ -8175,6 +9977,20 @@ impl IOleDocumentSite_Vtbl {
         iid == &<IOleDocumentSite as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOleDocumentSite_Impl> windows_core::imp::TearOffVtable<Identity> for IOleDocumentSite_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ActivateMe<Identity: windows_core::IUnknownImpl + IOleDocumentSite_Impl>(this: *mut core::ffi::c_void, pviewtoactivate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleDocumentSite_Impl::ActivateMe(this, core::mem::transmute_copy(&pviewtoactivate)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ActivateMe: ActivateMe::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IOleDocumentSite {}
 windows_core::imp::define_interface!(IOleDocumentView, IOleDocumentView_Vtbl, 0xb722bcc6_4e68_101b_a2bc_00aa00404770);
 windows_core::imp::interface_hierarchy!(IOleDocumentView, windows_core::IUnknown);
@@ -8286,17 +10102,146 @@ pub trait IOleDocumentView_Impl: windows_core::IUnknownImpl {
     fn Clone(&self, pipsitenew: windows_core::Ref<IOleInPlaceSite>) -> windows_core::Result<IOleDocumentView>;
 }
 #[cfg(feature = "Win32_System_Com")]
-impl IOleDocumentView_Vtbl {
-    pub const fn new<Identity: IOleDocumentView_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn SetInPlaceSite<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pipsite: *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl IOleDocumentView_Vtbl {
+    pub const fn new<Identity: IOleDocumentView_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn SetInPlaceSite<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pipsite: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleDocumentView_Impl::SetInPlaceSite(this, core::mem::transmute_copy(&pipsite)).into()
+            }
+        }
+        unsafe extern "system" fn GetInPlaceSite<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppipsite: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOleDocumentView_Impl::GetInPlaceSite(this) {
+                    Ok(ok__) => {
+                        ppipsite.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDocument<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOleDocumentView_Impl::GetDocument(this) {
+                    Ok(ok__) => {
+                        ppunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRect<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, prcview: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleDocumentView_Impl::SetRect(this, core::mem::transmute_copy(&prcview)).into()
+            }
+        }
+        unsafe extern "system" fn GetRect<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, prcview: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOleDocumentView_Impl::GetRect(this) {
+                    Ok(ok__) => {
+                        prcview.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRectComplex<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, prcview: *const super::super::Foundation::RECT, prchscroll: *const super::super::Foundation::RECT, prcvscroll: *const super::super::Foundation::RECT, prcsizebox: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleDocumentView_Impl::SetRectComplex(this, core::mem::transmute_copy(&prcview), core::mem::transmute_copy(&prchscroll), core::mem::transmute_copy(&prcvscroll), core::mem::transmute_copy(&prcsizebox)).into()
+            }
+        }
+        unsafe extern "system" fn Show<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fshow: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleDocumentView_Impl::Show(this, core::mem::transmute_copy(&fshow)).into()
+            }
+        }
+        unsafe extern "system" fn UIActivate<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fuiactivate: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleDocumentView_Impl::UIActivate(this, core::mem::transmute_copy(&fuiactivate)).into()
+            }
+        }
+        unsafe extern "system" fn Open<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleDocumentView_Impl::Open(this).into()
+            }
+        }
+        unsafe extern "system" fn CloseView<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleDocumentView_Impl::CloseView(this, core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn SaveViewState<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pstm: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleDocumentView_Impl::SaveViewState(this, core::mem::transmute_copy(&pstm)).into()
+            }
+        }
+        unsafe extern "system" fn ApplyViewState<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pstm: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleDocumentView_Impl::ApplyViewState(this, core::mem::transmute_copy(&pstm)).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pipsitenew: *mut core::ffi::c_void, ppviewnew: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOleDocumentView_Impl::Clone(this, core::mem::transmute_copy(&pipsitenew)) {
+                    Ok(ok__) => {
+                        ppviewnew.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            SetInPlaceSite: SetInPlaceSite::<Identity, OFFSET>,
+            GetInPlaceSite: GetInPlaceSite::<Identity, OFFSET>,
+            GetDocument: GetDocument::<Identity, OFFSET>,
+            SetRect: SetRect::<Identity, OFFSET>,
+            GetRect: GetRect::<Identity, OFFSET>,
+            SetRectComplex: SetRectComplex::<Identity, OFFSET>,
+            Show: Show::<Identity, OFFSET>,
+            UIActivate: UIActivate::<Identity, OFFSET>,
+            Open: Open::<Identity, OFFSET>,
+            CloseView: CloseView::<Identity, OFFSET>,
+            SaveViewState: SaveViewState::<Identity, OFFSET>,
+            ApplyViewState: ApplyViewState::<Identity, OFFSET>,
+            Clone: Clone::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleDocumentView as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOleDocumentView_Impl> windows_core::imp::TearOffVtable<Identity> for IOleDocumentView_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetInPlaceSite<Identity: windows_core::IUnknownImpl + IOleDocumentView_Impl>(this: *mut core::ffi::c_void, pipsite: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleDocumentView_Impl::SetInPlaceSite(this, core::mem::transmute_copy(&pipsite)).into()
             }
         }
-        unsafe extern "system" fn GetInPlaceSite<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppipsite: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetInPlaceSite<Identity: windows_core::IUnknownImpl + IOleDocumentView_Impl>(this: *mut core::ffi::c_void, ppipsite: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOleDocumentView_Impl::GetInPlaceSite(this) {
                     Ok(ok__) => {
                         ppipsite.write(core::mem::transmute(ok__));
@@ -8306,9 +10251,9 @@ impl IOleDocumentView_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetDocument<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetDocument<Identity: windows_core::IUnknownImpl + IOleDocumentView_Impl>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOleDocumentView_Impl::GetDocument(this) {
                     Ok(ok__) => {
                         ppunk.write(core::mem::transmute(ok__));
@@ -8318,15 +10263,15 @@ impl IOleDocumentView_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetRect<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, prcview: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetRect<Identity: windows_core::IUnknownImpl + IOleDocumentView_Impl>(this: *mut core::ffi::c_void, prcview: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleDocumentView_Impl::SetRect(this, core::mem::transmute_copy(&prcview)).into()
             }
         }
-        unsafe extern "system" fn GetRect<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, prcview: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetRect<Identity: windows_core::IUnknownImpl + IOleDocumentView_Impl>(this: *mut core::ffi::c_void, prcview: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOleDocumentView_Impl::GetRect(this) {
                     Ok(ok__) => {
                         prcview.write(core::mem::transmute(ok__));
@@ -8336,51 +10281,51 @@ impl IOleDocumentView_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetRectComplex<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, prcview: *const super::super::Foundation::RECT, prchscroll: *const super::super::Foundation::RECT, prcvscroll: *const super::super::Foundation::RECT, prcsizebox: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetRectComplex<Identity: windows_core::IUnknownImpl + IOleDocumentView_Impl>(this: *mut core::ffi::c_void, prcview: *const super::super::Foundation::RECT, prchscroll: *const super::super::Foundation::RECT, prcvscroll: *const super::super::Foundation::RECT, prcsizebox: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleDocumentView_Impl::SetRectComplex(this, core::mem::transmute_copy(&prcview), core::mem::transmute_copy(&prchscroll), core::mem::transmute_copy(&prcvscroll), core::mem::transmute_copy(&prcsizebox)).into()
             }
         }
-        unsafe extern "system" fn Show<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fshow: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn Show<Identity: windows_core::IUnknownImpl + IOleDocumentView_Impl>(this: *mut core::ffi::c_void, fshow: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleDocumentView_Impl::Show(this, core::mem::transmute_copy(&fshow)).into()
             }
         }
-        unsafe extern "system" fn UIActivate<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fuiactivate: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn UIActivate<Identity: windows_core::IUnknownImpl + IOleDocumentView_Impl>(this: *mut core::ffi::c_void, fuiactivate: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleDocumentView_Impl::UIActivate(this, core::mem::transmute_copy(&fuiactivate)).into()
             }
         }
-        unsafe extern "system" fn Open<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Open<Identity: windows_core::IUnknownImpl + IOleDocumentView_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleDocumentView_Impl::Open(this).into()
             }
         }
-        unsafe extern "system" fn CloseView<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwreserved: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn CloseView<Identity: windows_core::IUnknownImpl + IOleDocumentView_Impl>(this: *mut core::ffi::c_void, dwreserved: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleDocumentView_Impl::CloseView(this, core::mem::transmute_copy(&dwreserved)).into()
             }
         }
-        unsafe extern "system" fn SaveViewState<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pstm: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SaveViewState<Identity: windows_core::IUnknownImpl + IOleDocumentView_Impl>(this: *mut core::ffi::c_void, pstm: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleDocumentView_Impl::SaveViewState(this, core::mem::transmute_copy(&pstm)).into()
             }
         }
-        unsafe extern "system" fn ApplyViewState<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pstm: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn ApplyViewState<Identity: windows_core::IUnknownImpl + IOleDocumentView_Impl>(this: *mut core::ffi::c_void, pstm: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleDocumentView_Impl::ApplyViewState(this, core::mem::transmute_copy(&pstm)).into()
             }
         }
-        unsafe extern "system" fn Clone<Identity: IOleDocumentView_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pipsitenew: *mut core::ffi::c_void, ppviewnew: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IOleDocumentView_Impl>(this: *mut core::ffi::c_void, pipsitenew: *mut core::ffi::c_void, ppviewnew: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOleDocumentView_Impl::Clone(this, core::mem::transmute_copy(&pipsitenew)) {
                     Ok(ok__) => {
                         ppviewnew.write(core::mem::transmute(ok__));
@@ -8391,25 +10336,22 @@ impl IOleDocumentView_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            SetInPlaceSite: SetInPlaceSite::<Identity, OFFSET>,
-            GetInPlaceSite: GetInPlaceSite::<Identity, OFFSET>,
-            GetDocument: GetDocument::<Identity, OFFSET>,
-            SetRect: SetRect::<Identity, OFFSET>,
-            GetRect: GetRect::<Identity, OFFSET>,
-            SetRectComplex: SetRectComplex::<Identity, OFFSET>,
-            Show: Show::<Identity, OFFSET>,
-            UIActivate: UIActivate::<Identity, OFFSET>,
-            Open: Open::<Identity, OFFSET>,
-            CloseView: CloseView::<Identity, OFFSET>,
-            SaveViewState: SaveViewState::<Identity, OFFSET>,
-            ApplyViewState: ApplyViewState::<Identity, OFFSET>,
-            Clone: Clone::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IOleDocumentView as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetInPlaceSite: SetInPlaceSite::<Identity>,
+            GetInPlaceSite: GetInPlaceSite::<Identity>,
+            GetDocument: GetDocument::<Identity>,
+            SetRect: SetRect::<Identity>,
+            GetRect: GetRect::<Identity>,
+            SetRectComplex: SetRectComplex::<Identity>,
+            Show: Show::<Identity>,
+            UIActivate: UIActivate::<Identity>,
+            Open: Open::<Identity>,
+            CloseView: CloseView::<Identity>,
+            SaveViewState: SaveViewState::<Identity>,
+            ApplyViewState: ApplyViewState::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
 }
 #[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOleDocumentView {}
@@ -8510,6 +10452,52 @@ impl IOleInPlaceActiveObject_Vtbl {
     }
 }
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl<Identity: windows_core::IUnknownImpl + IOleInPlaceActiveObject_Impl> windows_core::imp::TearOffVtable<Identity> for IOleInPlaceActiveObject_Vtbl
+where
+    IOleWindow_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn TranslateAccelerator<Identity: windows_core::IUnknownImpl + IOleInPlaceActiveObject_Impl>(this: *mut core::ffi::c_void, lpmsg: *const super::super::UI::WindowsAndMessaging::MSG) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceActiveObject_Impl::TranslateAccelerator(this, core::mem::transmute_copy(&lpmsg)).into()
+            }
+        }
+        unsafe extern "system" fn OnFrameWindowActivate<Identity: windows_core::IUnknownImpl + IOleInPlaceActiveObject_Impl>(this: *mut core::ffi::c_void, factivate: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceActiveObject_Impl::OnFrameWindowActivate(this, core::mem::transmute_copy(&factivate)).into()
+            }
+        }
+        unsafe extern "system" fn OnDocWindowActivate<Identity: windows_core::IUnknownImpl + IOleInPlaceActiveObject_Impl>(this: *mut core::ffi::c_void, factivate: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceActiveObject_Impl::OnDocWindowActivate(this, core::mem::transmute_copy(&factivate)).into()
+            }
+        }
+        unsafe extern "system" fn ResizeBorder<Identity: windows_core::IUnknownImpl + IOleInPlaceActiveObject_Impl>(this: *mut core::ffi::c_void, prcborder: *const super::super::Foundation::RECT, puiwindow: *mut core::ffi::c_void, fframewindow: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceActiveObject_Impl::ResizeBorder(this, core::mem::transmute_copy(&prcborder), core::mem::transmute_copy(&puiwindow), core::mem::transmute_copy(&fframewindow)).into()
+            }
+        }
+        unsafe extern "system" fn EnableModeless<Identity: windows_core::IUnknownImpl + IOleInPlaceActiveObject_Impl>(this: *mut core::ffi::c_void, fenable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceActiveObject_Impl::EnableModeless(this, core::mem::transmute_copy(&fenable)).into()
+            }
+        }
+        Self {
+            base__: <IOleWindow_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            TranslateAccelerator: TranslateAccelerator::<Identity>,
+            OnFrameWindowActivate: OnFrameWindowActivate::<Identity>,
+            OnDocWindowActivate: OnDocWindowActivate::<Identity>,
+            ResizeBorder: ResizeBorder::<Identity>,
+            EnableModeless: EnableModeless::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
 impl windows_core::RuntimeName for IOleInPlaceActiveObject {}
 windows_core::imp::define_interface!(IOleInPlaceFrame, IOleInPlaceFrame_Vtbl, 0x00000116_0000_0000_c000_000000000046);
 impl core::ops::Deref for IOleInPlaceFrame {
@@ -8632,6 +10620,59 @@ impl IOleInPlaceFrame_Vtbl {
     }
 }
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl<Identity: windows_core::IUnknownImpl + IOleInPlaceFrame_Impl> windows_core::imp::TearOffVtable<Identity> for IOleInPlaceFrame_Vtbl
+where
+    IOleInPlaceUIWindow_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InsertMenus<Identity: windows_core::IUnknownImpl + IOleInPlaceFrame_Impl>(this: *mut core::ffi::c_void, hmenushared: super::super::UI::WindowsAndMessaging::HMENU, lpmenuwidths: *mut OLEMENUGROUPWIDTHS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceFrame_Impl::InsertMenus(this, core::mem::transmute_copy(&hmenushared), core::mem::transmute_copy(&lpmenuwidths)).into()
+            }
+        }
+        unsafe extern "system" fn SetMenu<Identity: windows_core::IUnknownImpl + IOleInPlaceFrame_Impl>(this: *mut core::ffi::c_void, hmenushared: super::super::UI::WindowsAndMessaging::HMENU, holemenu: isize, hwndactiveobject: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceFrame_Impl::SetMenu(this, core::mem::transmute_copy(&hmenushared), core::mem::transmute_copy(&holemenu), core::mem::transmute_copy(&hwndactiveobject)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveMenus<Identity: windows_core::IUnknownImpl + IOleInPlaceFrame_Impl>(this: *mut core::ffi::c_void, hmenushared: super::super::UI::WindowsAndMessaging::HMENU) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceFrame_Impl::RemoveMenus(this, core::mem::transmute_copy(&hmenushared)).into()
+            }
+        }
+        unsafe extern "system" fn SetStatusText<Identity: windows_core::IUnknownImpl + IOleInPlaceFrame_Impl>(this: *mut core::ffi::c_void, pszstatustext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceFrame_Impl::SetStatusText(this, core::mem::transmute(&pszstatustext)).into()
+            }
+        }
+        unsafe extern "system" fn EnableModeless<Identity: windows_core::IUnknownImpl + IOleInPlaceFrame_Impl>(this: *mut core::ffi::c_void, fenable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceFrame_Impl::EnableModeless(this, core::mem::transmute_copy(&fenable)).into()
+            }
+        }
+        unsafe extern "system" fn TranslateAccelerator<Identity: windows_core::IUnknownImpl + IOleInPlaceFrame_Impl>(this: *mut core::ffi::c_void, lpmsg: *const super::super::UI::WindowsAndMessaging::MSG, wid: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceFrame_Impl::TranslateAccelerator(this, core::mem::transmute_copy(&lpmsg), core::mem::transmute_copy(&wid)).into()
+            }
+        }
+        Self {
+            base__: <IOleInPlaceUIWindow_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InsertMenus: InsertMenus::<Identity>,
+            SetMenu: SetMenu::<Identity>,
+            RemoveMenus: RemoveMenus::<Identity>,
+            SetStatusText: SetStatusText::<Identity>,
+            EnableModeless: EnableModeless::<Identity>,
+            TranslateAccelerator: TranslateAccelerator::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
 impl windows_core::RuntimeName for IOleInPlaceFrame {}
 windows_core::imp::define_interface!(IOleInPlaceObject, IOleInPlaceObject_Vtbl, 0x00000113_0000_0000_c000_000000000046);
 impl core::ops::Deref for IOleInPlaceObject {
@@ -8708,6 +10749,44 @@ impl IOleInPlaceObject_Vtbl {
         iid == &<IOleInPlaceObject as windows_core::Interface>::IID || iid == &<IOleWindow as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOleInPlaceObject_Impl> windows_core::imp::TearOffVtable<Identity> for IOleInPlaceObject_Vtbl
+where
+    IOleWindow_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InPlaceDeactivate<Identity: windows_core::IUnknownImpl + IOleInPlaceObject_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceObject_Impl::InPlaceDeactivate(this).into()
+            }
+        }
+        unsafe extern "system" fn UIDeactivate<Identity: windows_core::IUnknownImpl + IOleInPlaceObject_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceObject_Impl::UIDeactivate(this).into()
+            }
+        }
+        unsafe extern "system" fn SetObjectRects<Identity: windows_core::IUnknownImpl + IOleInPlaceObject_Impl>(this: *mut core::ffi::c_void, lprcposrect: *const super::super::Foundation::RECT, lprccliprect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceObject_Impl::SetObjectRects(this, core::mem::transmute_copy(&lprcposrect), core::mem::transmute_copy(&lprccliprect)).into()
+            }
+        }
+        unsafe extern "system" fn ReactivateAndUndo<Identity: windows_core::IUnknownImpl + IOleInPlaceObject_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceObject_Impl::ReactivateAndUndo(this).into()
+            }
+        }
+        Self {
+            base__: <IOleWindow_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InPlaceDeactivate: InPlaceDeactivate::<Identity>,
+            UIDeactivate: UIDeactivate::<Identity>,
+            SetObjectRects: SetObjectRects::<Identity>,
+            ReactivateAndUndo: ReactivateAndUndo::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOleInPlaceObject {}
 windows_core::imp::define_interface!(IOleInPlaceObjectWindowless, IOleInPlaceObjectWindowless_Vtbl, 0x1c2056cc_5ef4_101b_8bc8_00aa003e3b29);
 impl core::ops::Deref for IOleInPlaceObjectWindowless {
@@ -8778,6 +10857,42 @@ impl IOleInPlaceObjectWindowless_Vtbl {
         iid == &<IOleInPlaceObjectWindowless as windows_core::Interface>::IID || iid == &<IOleWindow as windows_core::Interface>::IID || iid == &<IOleInPlaceObject as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOleInPlaceObjectWindowless_Impl> windows_core::imp::TearOffVtable<Identity> for IOleInPlaceObjectWindowless_Vtbl
+where
+    IOleInPlaceObject_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnWindowMessage<Identity: windows_core::IUnknownImpl + IOleInPlaceObjectWindowless_Impl>(this: *mut core::ffi::c_void, msg: u32, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM, plresult: *mut super::super::Foundation::LRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleInPlaceObjectWindowless_Impl::OnWindowMessage(this, core::mem::transmute_copy(&msg), core::mem::transmute_copy(&wparam), core::mem::transmute_copy(&lparam)) {
+                    Ok(ok__) => {
+                        plresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDropTarget<Identity: windows_core::IUnknownImpl + IOleInPlaceObjectWindowless_Impl>(this: *mut core::ffi::c_void, ppdroptarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleInPlaceObjectWindowless_Impl::GetDropTarget(this) {
+                    Ok(ok__) => {
+                        ppdroptarget.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IOleInPlaceObject_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnWindowMessage: OnWindowMessage::<Identity>,
+            GetDropTarget: GetDropTarget::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOleInPlaceObjectWindowless {}
 windows_core::imp::define_interface!(IOleInPlaceSite, IOleInPlaceSite_Vtbl, 0x00000119_0000_0000_c000_000000000046);
 impl core::ops::Deref for IOleInPlaceSite {
@@ -8874,63 +10989,144 @@ impl IOleInPlaceSite_Vtbl {
         }
         unsafe extern "system" fn GetWindowContext<Identity: IOleInPlaceSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppframe: *mut *mut core::ffi::c_void, ppdoc: *mut *mut core::ffi::c_void, lprcposrect: *mut super::super::Foundation::RECT, lprccliprect: *mut super::super::Foundation::RECT, lpframeinfo: *mut OLEINPLACEFRAMEINFO) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleInPlaceSite_Impl::GetWindowContext(this, core::mem::transmute_copy(&ppframe), core::mem::transmute_copy(&ppdoc), core::mem::transmute_copy(&lprcposrect), core::mem::transmute_copy(&lprccliprect), core::mem::transmute_copy(&lpframeinfo)).into()
+            }
+        }
+        unsafe extern "system" fn Scroll<Identity: IOleInPlaceSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, scrollextant: super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleInPlaceSite_Impl::Scroll(this, core::mem::transmute(&scrollextant)).into()
+            }
+        }
+        unsafe extern "system" fn OnUIDeactivate<Identity: IOleInPlaceSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fundoable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleInPlaceSite_Impl::OnUIDeactivate(this, core::mem::transmute_copy(&fundoable)).into()
+            }
+        }
+        unsafe extern "system" fn OnInPlaceDeactivate<Identity: IOleInPlaceSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleInPlaceSite_Impl::OnInPlaceDeactivate(this).into()
+            }
+        }
+        unsafe extern "system" fn DiscardUndoState<Identity: IOleInPlaceSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleInPlaceSite_Impl::DiscardUndoState(this).into()
+            }
+        }
+        unsafe extern "system" fn DeactivateAndUndo<Identity: IOleInPlaceSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleInPlaceSite_Impl::DeactivateAndUndo(this).into()
+            }
+        }
+        unsafe extern "system" fn OnPosRectChange<Identity: IOleInPlaceSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lprcposrect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleInPlaceSite_Impl::OnPosRectChange(this, core::mem::transmute_copy(&lprcposrect)).into()
+            }
+        }
+        Self {
+            base__: IOleWindow_Vtbl::new::<Identity, OFFSET>(),
+            CanInPlaceActivate: CanInPlaceActivate::<Identity, OFFSET>,
+            OnInPlaceActivate: OnInPlaceActivate::<Identity, OFFSET>,
+            OnUIActivate: OnUIActivate::<Identity, OFFSET>,
+            GetWindowContext: GetWindowContext::<Identity, OFFSET>,
+            Scroll: Scroll::<Identity, OFFSET>,
+            OnUIDeactivate: OnUIDeactivate::<Identity, OFFSET>,
+            OnInPlaceDeactivate: OnInPlaceDeactivate::<Identity, OFFSET>,
+            DiscardUndoState: DiscardUndoState::<Identity, OFFSET>,
+            DeactivateAndUndo: DeactivateAndUndo::<Identity, OFFSET>,
+            OnPosRectChange: OnPosRectChange::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleInPlaceSite as windows_core::Interface>::IID || iid == &<IOleWindow as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl<Identity: windows_core::IUnknownImpl + IOleInPlaceSite_Impl> windows_core::imp::TearOffVtable<Identity> for IOleInPlaceSite_Vtbl
+where
+    IOleWindow_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CanInPlaceActivate<Identity: windows_core::IUnknownImpl + IOleInPlaceSite_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceSite_Impl::CanInPlaceActivate(this).into()
+            }
+        }
+        unsafe extern "system" fn OnInPlaceActivate<Identity: windows_core::IUnknownImpl + IOleInPlaceSite_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceSite_Impl::OnInPlaceActivate(this).into()
+            }
+        }
+        unsafe extern "system" fn OnUIActivate<Identity: windows_core::IUnknownImpl + IOleInPlaceSite_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceSite_Impl::OnUIActivate(this).into()
+            }
+        }
+        unsafe extern "system" fn GetWindowContext<Identity: windows_core::IUnknownImpl + IOleInPlaceSite_Impl>(this: *mut core::ffi::c_void, ppframe: *mut *mut core::ffi::c_void, ppdoc: *mut *mut core::ffi::c_void, lprcposrect: *mut super::super::Foundation::RECT, lprccliprect: *mut super::super::Foundation::RECT, lpframeinfo: *mut OLEINPLACEFRAMEINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleInPlaceSite_Impl::GetWindowContext(this, core::mem::transmute_copy(&ppframe), core::mem::transmute_copy(&ppdoc), core::mem::transmute_copy(&lprcposrect), core::mem::transmute_copy(&lprccliprect), core::mem::transmute_copy(&lpframeinfo)).into()
             }
         }
-        unsafe extern "system" fn Scroll<Identity: IOleInPlaceSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, scrollextant: super::super::Foundation::SIZE) -> windows_core::HRESULT {
+        unsafe extern "system" fn Scroll<Identity: windows_core::IUnknownImpl + IOleInPlaceSite_Impl>(this: *mut core::ffi::c_void, scrollextant: super::super::Foundation::SIZE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleInPlaceSite_Impl::Scroll(this, core::mem::transmute(&scrollextant)).into()
             }
         }
-        unsafe extern "system" fn OnUIDeactivate<Identity: IOleInPlaceSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, fundoable: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn OnUIDeactivate<Identity: windows_core::IUnknownImpl + IOleInPlaceSite_Impl>(this: *mut core::ffi::c_void, fundoable: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleInPlaceSite_Impl::OnUIDeactivate(this, core::mem::transmute_copy(&fundoable)).into()
             }
         }
-        unsafe extern "system" fn OnInPlaceDeactivate<Identity: IOleInPlaceSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn OnInPlaceDeactivate<Identity: windows_core::IUnknownImpl + IOleInPlaceSite_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleInPlaceSite_Impl::OnInPlaceDeactivate(this).into()
             }
         }
-        unsafe extern "system" fn DiscardUndoState<Identity: IOleInPlaceSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn DiscardUndoState<Identity: windows_core::IUnknownImpl + IOleInPlaceSite_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleInPlaceSite_Impl::DiscardUndoState(this).into()
             }
         }
-        unsafe extern "system" fn DeactivateAndUndo<Identity: IOleInPlaceSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn DeactivateAndUndo<Identity: windows_core::IUnknownImpl + IOleInPlaceSite_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleInPlaceSite_Impl::DeactivateAndUndo(this).into()
             }
         }
-        unsafe extern "system" fn OnPosRectChange<Identity: IOleInPlaceSite_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lprcposrect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+        unsafe extern "system" fn OnPosRectChange<Identity: windows_core::IUnknownImpl + IOleInPlaceSite_Impl>(this: *mut core::ffi::c_void, lprcposrect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleInPlaceSite_Impl::OnPosRectChange(this, core::mem::transmute_copy(&lprcposrect)).into()
             }
         }
         Self {
-            base__: IOleWindow_Vtbl::new::<Identity, OFFSET>(),
-            CanInPlaceActivate: CanInPlaceActivate::<Identity, OFFSET>,
-            OnInPlaceActivate: OnInPlaceActivate::<Identity, OFFSET>,
-            OnUIActivate: OnUIActivate::<Identity, OFFSET>,
-            GetWindowContext: GetWindowContext::<Identity, OFFSET>,
-            Scroll: Scroll::<Identity, OFFSET>,
-            OnUIDeactivate: OnUIDeactivate::<Identity, OFFSET>,
-            OnInPlaceDeactivate: OnInPlaceDeactivate::<Identity, OFFSET>,
-            DiscardUndoState: DiscardUndoState::<Identity, OFFSET>,
-            DeactivateAndUndo: DeactivateAndUndo::<Identity, OFFSET>,
-            OnPosRectChange: OnPosRectChange::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IOleInPlaceSite as windows_core::Interface>::IID || iid == &<IOleWindow as windows_core::Interface>::IID
-    }
+            base__: <IOleWindow_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CanInPlaceActivate: CanInPlaceActivate::<Identity>,
+            OnInPlaceActivate: OnInPlaceActivate::<Identity>,
+            OnUIActivate: OnUIActivate::<Identity>,
+            GetWindowContext: GetWindowContext::<Identity>,
+            Scroll: Scroll::<Identity>,
+            OnUIDeactivate: OnUIDeactivate::<Identity>,
+            OnInPlaceDeactivate: OnInPlaceDeactivate::<Identity>,
+            DiscardUndoState: DiscardUndoState::<Identity>,
+            DeactivateAndUndo: DeactivateAndUndo::<Identity>,
+            OnPosRectChange: OnPosRectChange::<Identity>,
+        }
+    };
 }
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
 impl windows_core::RuntimeName for IOleInPlaceSite {}
@@ -9000,6 +11196,38 @@ impl IOleInPlaceSiteEx_Vtbl {
     }
 }
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl<Identity: windows_core::IUnknownImpl + IOleInPlaceSiteEx_Impl> windows_core::imp::TearOffVtable<Identity> for IOleInPlaceSiteEx_Vtbl
+where
+    IOleInPlaceSite_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnInPlaceActivateEx<Identity: windows_core::IUnknownImpl + IOleInPlaceSiteEx_Impl>(this: *mut core::ffi::c_void, pfnoredraw: *mut windows_core::BOOL, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceSiteEx_Impl::OnInPlaceActivateEx(this, core::mem::transmute_copy(&pfnoredraw), core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn OnInPlaceDeactivateEx<Identity: windows_core::IUnknownImpl + IOleInPlaceSiteEx_Impl>(this: *mut core::ffi::c_void, fnoredraw: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceSiteEx_Impl::OnInPlaceDeactivateEx(this, core::mem::transmute_copy(&fnoredraw)).into()
+            }
+        }
+        unsafe extern "system" fn RequestUIActivate<Identity: windows_core::IUnknownImpl + IOleInPlaceSiteEx_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceSiteEx_Impl::RequestUIActivate(this).into()
+            }
+        }
+        Self {
+            base__: <IOleInPlaceSite_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnInPlaceActivateEx: OnInPlaceActivateEx::<Identity>,
+            OnInPlaceDeactivateEx: OnInPlaceDeactivateEx::<Identity>,
+            RequestUIActivate: RequestUIActivate::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
 impl windows_core::RuntimeName for IOleInPlaceSiteEx {}
 windows_core::imp::define_interface!(IOleInPlaceSiteWindowless, IOleInPlaceSiteWindowless_Vtbl, 0x922eada0_3424_11cf_b670_00aa004cd6d8);
 impl core::ops::Deref for IOleInPlaceSiteWindowless {
@@ -9205,6 +11433,113 @@ impl IOleInPlaceSiteWindowless_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+impl<Identity: windows_core::IUnknownImpl + IOleInPlaceSiteWindowless_Impl> windows_core::imp::TearOffVtable<Identity> for IOleInPlaceSiteWindowless_Vtbl
+where
+    IOleInPlaceSiteEx_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CanWindowlessActivate<Identity: windows_core::IUnknownImpl + IOleInPlaceSiteWindowless_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceSiteWindowless_Impl::CanWindowlessActivate(this).into()
+            }
+        }
+        unsafe extern "system" fn GetCapture<Identity: windows_core::IUnknownImpl + IOleInPlaceSiteWindowless_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceSiteWindowless_Impl::GetCapture(this).into()
+            }
+        }
+        unsafe extern "system" fn SetCapture<Identity: windows_core::IUnknownImpl + IOleInPlaceSiteWindowless_Impl>(this: *mut core::ffi::c_void, fcapture: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceSiteWindowless_Impl::SetCapture(this, core::mem::transmute_copy(&fcapture)).into()
+            }
+        }
+        unsafe extern "system" fn GetFocus<Identity: windows_core::IUnknownImpl + IOleInPlaceSiteWindowless_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceSiteWindowless_Impl::GetFocus(this).into()
+            }
+        }
+        unsafe extern "system" fn SetFocus<Identity: windows_core::IUnknownImpl + IOleInPlaceSiteWindowless_Impl>(this: *mut core::ffi::c_void, ffocus: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceSiteWindowless_Impl::SetFocus(this, core::mem::transmute_copy(&ffocus)).into()
+            }
+        }
+        unsafe extern "system" fn GetDC<Identity: windows_core::IUnknownImpl + IOleInPlaceSiteWindowless_Impl>(this: *mut core::ffi::c_void, prect: *const super::super::Foundation::RECT, grfflags: u32, phdc: *mut super::super::Graphics::Gdi::HDC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleInPlaceSiteWindowless_Impl::GetDC(this, core::mem::transmute_copy(&prect), core::mem::transmute_copy(&grfflags)) {
+                    Ok(ok__) => {
+                        phdc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReleaseDC<Identity: windows_core::IUnknownImpl + IOleInPlaceSiteWindowless_Impl>(this: *mut core::ffi::c_void, hdc: super::super::Graphics::Gdi::HDC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceSiteWindowless_Impl::ReleaseDC(this, core::mem::transmute_copy(&hdc)).into()
+            }
+        }
+        unsafe extern "system" fn InvalidateRect<Identity: windows_core::IUnknownImpl + IOleInPlaceSiteWindowless_Impl>(this: *mut core::ffi::c_void, prect: *const super::super::Foundation::RECT, ferase: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceSiteWindowless_Impl::InvalidateRect(this, core::mem::transmute_copy(&prect), core::mem::transmute_copy(&ferase)).into()
+            }
+        }
+        unsafe extern "system" fn InvalidateRgn<Identity: windows_core::IUnknownImpl + IOleInPlaceSiteWindowless_Impl>(this: *mut core::ffi::c_void, hrgn: super::super::Graphics::Gdi::HRGN, ferase: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceSiteWindowless_Impl::InvalidateRgn(this, core::mem::transmute_copy(&hrgn), core::mem::transmute_copy(&ferase)).into()
+            }
+        }
+        unsafe extern "system" fn ScrollRect<Identity: windows_core::IUnknownImpl + IOleInPlaceSiteWindowless_Impl>(this: *mut core::ffi::c_void, dx: i32, dy: i32, prectscroll: *const super::super::Foundation::RECT, prectclip: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceSiteWindowless_Impl::ScrollRect(this, core::mem::transmute_copy(&dx), core::mem::transmute_copy(&dy), core::mem::transmute_copy(&prectscroll), core::mem::transmute_copy(&prectclip)).into()
+            }
+        }
+        unsafe extern "system" fn AdjustRect<Identity: windows_core::IUnknownImpl + IOleInPlaceSiteWindowless_Impl>(this: *mut core::ffi::c_void, prc: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceSiteWindowless_Impl::AdjustRect(this, core::mem::transmute_copy(&prc)).into()
+            }
+        }
+        unsafe extern "system" fn OnDefWindowMessage<Identity: windows_core::IUnknownImpl + IOleInPlaceSiteWindowless_Impl>(this: *mut core::ffi::c_void, msg: u32, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM, plresult: *mut super::super::Foundation::LRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleInPlaceSiteWindowless_Impl::OnDefWindowMessage(this, core::mem::transmute_copy(&msg), core::mem::transmute_copy(&wparam), core::mem::transmute_copy(&lparam)) {
+                    Ok(ok__) => {
+                        plresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IOleInPlaceSiteEx_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CanWindowlessActivate: CanWindowlessActivate::<Identity>,
+            GetCapture: GetCapture::<Identity>,
+            SetCapture: SetCapture::<Identity>,
+            GetFocus: GetFocus::<Identity>,
+            SetFocus: SetFocus::<Identity>,
+            GetDC: GetDC::<Identity>,
+            ReleaseDC: ReleaseDC::<Identity>,
+            InvalidateRect: InvalidateRect::<Identity>,
+            InvalidateRgn: InvalidateRgn::<Identity>,
+            ScrollRect: ScrollRect::<Identity>,
+            AdjustRect: AdjustRect::<Identity>,
+            OnDefWindowMessage: OnDefWindowMessage::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
 impl windows_core::RuntimeName for IOleInPlaceSiteWindowless {}
 windows_core::imp::define_interface!(IOleInPlaceUIWindow, IOleInPlaceUIWindow_Vtbl, 0x00000115_0000_0000_c000_000000000046);
 impl core::ops::Deref for IOleInPlaceUIWindow {
@@ -9294,6 +11629,50 @@ impl IOleInPlaceUIWindow_Vtbl {
         iid == &<IOleInPlaceUIWindow as windows_core::Interface>::IID || iid == &<IOleWindow as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOleInPlaceUIWindow_Impl> windows_core::imp::TearOffVtable<Identity> for IOleInPlaceUIWindow_Vtbl
+where
+    IOleWindow_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetBorder<Identity: windows_core::IUnknownImpl + IOleInPlaceUIWindow_Impl>(this: *mut core::ffi::c_void, lprectborder: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleInPlaceUIWindow_Impl::GetBorder(this) {
+                    Ok(ok__) => {
+                        lprectborder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RequestBorderSpace<Identity: windows_core::IUnknownImpl + IOleInPlaceUIWindow_Impl>(this: *mut core::ffi::c_void, pborderwidths: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceUIWindow_Impl::RequestBorderSpace(this, core::mem::transmute_copy(&pborderwidths)).into()
+            }
+        }
+        unsafe extern "system" fn SetBorderSpace<Identity: windows_core::IUnknownImpl + IOleInPlaceUIWindow_Impl>(this: *mut core::ffi::c_void, pborderwidths: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceUIWindow_Impl::SetBorderSpace(this, core::mem::transmute_copy(&pborderwidths)).into()
+            }
+        }
+        unsafe extern "system" fn SetActiveObject<Identity: windows_core::IUnknownImpl + IOleInPlaceUIWindow_Impl>(this: *mut core::ffi::c_void, pactiveobject: *mut core::ffi::c_void, pszobjname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleInPlaceUIWindow_Impl::SetActiveObject(this, core::mem::transmute_copy(&pactiveobject), core::mem::transmute(&pszobjname)).into()
+            }
+        }
+        Self {
+            base__: <IOleWindow_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetBorder: GetBorder::<Identity>,
+            RequestBorderSpace: RequestBorderSpace::<Identity>,
+            SetBorderSpace: SetBorderSpace::<Identity>,
+            SetActiveObject: SetActiveObject::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOleInPlaceUIWindow {}
 windows_core::imp::define_interface!(IOleItemContainer, IOleItemContainer_Vtbl, 0x0000011c_0000_0000_c000_000000000046);
 impl core::ops::Deref for IOleItemContainer {
@@ -9384,6 +11763,38 @@ impl IOleItemContainer_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOleItemContainer_Impl> windows_core::imp::TearOffVtable<Identity> for IOleItemContainer_Vtbl
+where
+    IOleContainer_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetObject<Identity: windows_core::IUnknownImpl + IOleItemContainer_Impl>(this: *mut core::ffi::c_void, pszitem: windows_core::PCWSTR, dwspeedneeded: u32, pbc: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleItemContainer_Impl::GetObject(this, core::mem::transmute(&pszitem), core::mem::transmute_copy(&dwspeedneeded), core::mem::transmute_copy(&pbc), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvobject)).into()
+            }
+        }
+        unsafe extern "system" fn GetObjectStorage<Identity: windows_core::IUnknownImpl + IOleItemContainer_Impl>(this: *mut core::ffi::c_void, pszitem: windows_core::PCWSTR, pbc: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleItemContainer_Impl::GetObjectStorage(this, core::mem::transmute(&pszitem), core::mem::transmute_copy(&pbc), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&ppvstorage)).into()
+            }
+        }
+        unsafe extern "system" fn IsRunning<Identity: windows_core::IUnknownImpl + IOleItemContainer_Impl>(this: *mut core::ffi::c_void, pszitem: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleItemContainer_Impl::IsRunning(this, core::mem::transmute(&pszitem)).into()
+            }
+        }
+        Self {
+            base__: <IOleContainer_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetObject: GetObject::<Identity>,
+            GetObjectStorage: GetObjectStorage::<Identity>,
+            IsRunning: IsRunning::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOleItemContainer {}
 windows_core::imp::define_interface!(IOleLink, IOleLink_Vtbl, 0x0000011d_0000_0000_c000_000000000046);
 windows_core::imp::interface_hierarchy!(IOleLink, windows_core::IUnknown);
@@ -9493,17 +11904,132 @@ pub trait IOleLink_Impl: windows_core::IUnknownImpl {
     fn Update(&self, pbc: windows_core::Ref<super::Com::IBindCtx>) -> windows_core::Result<()>;
 }
 #[cfg(feature = "Win32_System_Com")]
-impl IOleLink_Vtbl {
-    pub const fn new<Identity: IOleLink_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn SetUpdateOptions<Identity: IOleLink_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwupdateopt: u32) -> windows_core::HRESULT {
+impl IOleLink_Vtbl {
+    pub const fn new<Identity: IOleLink_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn SetUpdateOptions<Identity: IOleLink_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwupdateopt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleLink_Impl::SetUpdateOptions(this, core::mem::transmute_copy(&dwupdateopt)).into()
+            }
+        }
+        unsafe extern "system" fn GetUpdateOptions<Identity: IOleLink_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwupdateopt: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOleLink_Impl::GetUpdateOptions(this) {
+                    Ok(ok__) => {
+                        pdwupdateopt.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSourceMoniker<Identity: IOleLink_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pmk: *mut core::ffi::c_void, rclsid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleLink_Impl::SetSourceMoniker(this, core::mem::transmute_copy(&pmk), core::mem::transmute_copy(&rclsid)).into()
+            }
+        }
+        unsafe extern "system" fn GetSourceMoniker<Identity: IOleLink_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppmk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOleLink_Impl::GetSourceMoniker(this) {
+                    Ok(ok__) => {
+                        ppmk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSourceDisplayName<Identity: IOleLink_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pszstatustext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleLink_Impl::SetSourceDisplayName(this, core::mem::transmute(&pszstatustext)).into()
+            }
+        }
+        unsafe extern "system" fn GetSourceDisplayName<Identity: IOleLink_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppszdisplayname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOleLink_Impl::GetSourceDisplayName(this) {
+                    Ok(ok__) => {
+                        ppszdisplayname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn BindToSource<Identity: IOleLink_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bindflags: u32, pbc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleLink_Impl::BindToSource(this, core::mem::transmute_copy(&bindflags), core::mem::transmute_copy(&pbc)).into()
+            }
+        }
+        unsafe extern "system" fn BindIfRunning<Identity: IOleLink_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleLink_Impl::BindIfRunning(this).into()
+            }
+        }
+        unsafe extern "system" fn GetBoundSource<Identity: IOleLink_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOleLink_Impl::GetBoundSource(this) {
+                    Ok(ok__) => {
+                        ppunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UnbindSource<Identity: IOleLink_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleLink_Impl::UnbindSource(this).into()
+            }
+        }
+        unsafe extern "system" fn Update<Identity: IOleLink_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleLink_Impl::Update(this, core::mem::transmute_copy(&pbc)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            SetUpdateOptions: SetUpdateOptions::<Identity, OFFSET>,
+            GetUpdateOptions: GetUpdateOptions::<Identity, OFFSET>,
+            SetSourceMoniker: SetSourceMoniker::<Identity, OFFSET>,
+            GetSourceMoniker: GetSourceMoniker::<Identity, OFFSET>,
+            SetSourceDisplayName: SetSourceDisplayName::<Identity, OFFSET>,
+            GetSourceDisplayName: GetSourceDisplayName::<Identity, OFFSET>,
+            BindToSource: BindToSource::<Identity, OFFSET>,
+            BindIfRunning: BindIfRunning::<Identity, OFFSET>,
+            GetBoundSource: GetBoundSource::<Identity, OFFSET>,
+            UnbindSource: UnbindSource::<Identity, OFFSET>,
+            Update: Update::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleLink as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IOleLink_Impl> windows_core::imp::TearOffVtable<Identity> for IOleLink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetUpdateOptions<Identity: windows_core::IUnknownImpl + IOleLink_Impl>(this: *mut core::ffi::c_void, dwupdateopt: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleLink_Impl::SetUpdateOptions(this, core::mem::transmute_copy(&dwupdateopt)).into()
             }
         }
-        unsafe extern "system" fn GetUpdateOptions<Identity: IOleLink_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwupdateopt: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetUpdateOptions<Identity: windows_core::IUnknownImpl + IOleLink_Impl>(this: *mut core::ffi::c_void, pdwupdateopt: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOleLink_Impl::GetUpdateOptions(this) {
                     Ok(ok__) => {
                         pdwupdateopt.write(core::mem::transmute(ok__));
@@ -9513,15 +12039,15 @@ impl IOleLink_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetSourceMoniker<Identity: IOleLink_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pmk: *mut core::ffi::c_void, rclsid: *const windows_core::GUID) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetSourceMoniker<Identity: windows_core::IUnknownImpl + IOleLink_Impl>(this: *mut core::ffi::c_void, pmk: *mut core::ffi::c_void, rclsid: *const windows_core::GUID) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleLink_Impl::SetSourceMoniker(this, core::mem::transmute_copy(&pmk), core::mem::transmute_copy(&rclsid)).into()
             }
         }
-        unsafe extern "system" fn GetSourceMoniker<Identity: IOleLink_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppmk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetSourceMoniker<Identity: windows_core::IUnknownImpl + IOleLink_Impl>(this: *mut core::ffi::c_void, ppmk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOleLink_Impl::GetSourceMoniker(this) {
                     Ok(ok__) => {
                         ppmk.write(core::mem::transmute(ok__));
@@ -9531,15 +12057,15 @@ impl IOleLink_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetSourceDisplayName<Identity: IOleLink_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pszstatustext: windows_core::PCWSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetSourceDisplayName<Identity: windows_core::IUnknownImpl + IOleLink_Impl>(this: *mut core::ffi::c_void, pszstatustext: windows_core::PCWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleLink_Impl::SetSourceDisplayName(this, core::mem::transmute(&pszstatustext)).into()
             }
         }
-        unsafe extern "system" fn GetSourceDisplayName<Identity: IOleLink_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppszdisplayname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetSourceDisplayName<Identity: windows_core::IUnknownImpl + IOleLink_Impl>(this: *mut core::ffi::c_void, ppszdisplayname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOleLink_Impl::GetSourceDisplayName(this) {
                     Ok(ok__) => {
                         ppszdisplayname.write(core::mem::transmute(ok__));
@@ -9549,21 +12075,21 @@ impl IOleLink_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn BindToSource<Identity: IOleLink_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bindflags: u32, pbc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn BindToSource<Identity: windows_core::IUnknownImpl + IOleLink_Impl>(this: *mut core::ffi::c_void, bindflags: u32, pbc: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleLink_Impl::BindToSource(this, core::mem::transmute_copy(&bindflags), core::mem::transmute_copy(&pbc)).into()
             }
         }
-        unsafe extern "system" fn BindIfRunning<Identity: IOleLink_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn BindIfRunning<Identity: windows_core::IUnknownImpl + IOleLink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleLink_Impl::BindIfRunning(this).into()
             }
         }
-        unsafe extern "system" fn GetBoundSource<Identity: IOleLink_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetBoundSource<Identity: windows_core::IUnknownImpl + IOleLink_Impl>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOleLink_Impl::GetBoundSource(this) {
                     Ok(ok__) => {
                         ppunk.write(core::mem::transmute(ok__));
@@ -9573,36 +12099,33 @@ impl IOleLink_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn UnbindSource<Identity: IOleLink_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn UnbindSource<Identity: windows_core::IUnknownImpl + IOleLink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleLink_Impl::UnbindSource(this).into()
             }
         }
-        unsafe extern "system" fn Update<Identity: IOleLink_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Update<Identity: windows_core::IUnknownImpl + IOleLink_Impl>(this: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleLink_Impl::Update(this, core::mem::transmute_copy(&pbc)).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            SetUpdateOptions: SetUpdateOptions::<Identity, OFFSET>,
-            GetUpdateOptions: GetUpdateOptions::<Identity, OFFSET>,
-            SetSourceMoniker: SetSourceMoniker::<Identity, OFFSET>,
-            GetSourceMoniker: GetSourceMoniker::<Identity, OFFSET>,
-            SetSourceDisplayName: SetSourceDisplayName::<Identity, OFFSET>,
-            GetSourceDisplayName: GetSourceDisplayName::<Identity, OFFSET>,
-            BindToSource: BindToSource::<Identity, OFFSET>,
-            BindIfRunning: BindIfRunning::<Identity, OFFSET>,
-            GetBoundSource: GetBoundSource::<Identity, OFFSET>,
-            UnbindSource: UnbindSource::<Identity, OFFSET>,
-            Update: Update::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IOleLink as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetUpdateOptions: SetUpdateOptions::<Identity>,
+            GetUpdateOptions: GetUpdateOptions::<Identity>,
+            SetSourceMoniker: SetSourceMoniker::<Identity>,
+            GetSourceMoniker: GetSourceMoniker::<Identity>,
+            SetSourceDisplayName: SetSourceDisplayName::<Identity>,
+            GetSourceDisplayName: GetSourceDisplayName::<Identity>,
+            BindToSource: BindToSource::<Identity>,
+            BindIfRunning: BindIfRunning::<Identity>,
+            GetBoundSource: GetBoundSource::<Identity>,
+            UnbindSource: UnbindSource::<Identity>,
+            Update: Update::<Identity>,
+        }
+    };
 }
 #[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IOleLink {}
@@ -9817,17 +12340,238 @@ pub trait IOleObject_Impl: windows_core::IUnknownImpl {
     fn SetColorScheme(&self, plogpal: *const super::super::Graphics::Gdi::LOGPALETTE) -> windows_core::Result<()>;
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com", feature = "Win32_UI_WindowsAndMessaging"))]
-impl IOleObject_Vtbl {
-    pub const fn new<Identity: IOleObject_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn SetClientSite<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pclientsite: *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl IOleObject_Vtbl {
+    pub const fn new<Identity: IOleObject_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn SetClientSite<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pclientsite: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleObject_Impl::SetClientSite(this, core::mem::transmute_copy(&pclientsite)).into()
+            }
+        }
+        unsafe extern "system" fn GetClientSite<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppclientsite: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOleObject_Impl::GetClientSite(this) {
+                    Ok(ok__) => {
+                        ppclientsite.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetHostNames<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, szcontainerapp: windows_core::PCWSTR, szcontainerobj: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleObject_Impl::SetHostNames(this, core::mem::transmute(&szcontainerapp), core::mem::transmute(&szcontainerobj)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwsaveoption: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleObject_Impl::Close(this, core::mem::transmute(&dwsaveoption)).into()
+            }
+        }
+        unsafe extern "system" fn SetMoniker<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwwhichmoniker: u32, pmk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleObject_Impl::SetMoniker(this, core::mem::transmute(&dwwhichmoniker), core::mem::transmute_copy(&pmk)).into()
+            }
+        }
+        unsafe extern "system" fn GetMoniker<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwassign: u32, dwwhichmoniker: u32, ppmk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOleObject_Impl::GetMoniker(this, core::mem::transmute(&dwassign), core::mem::transmute(&dwwhichmoniker)) {
+                    Ok(ok__) => {
+                        ppmk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InitFromData<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdataobject: *mut core::ffi::c_void, fcreation: windows_core::BOOL, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleObject_Impl::InitFromData(this, core::mem::transmute_copy(&pdataobject), core::mem::transmute_copy(&fcreation), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn GetClipboardData<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwreserved: u32, ppdataobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOleObject_Impl::GetClipboardData(this, core::mem::transmute_copy(&dwreserved)) {
+                    Ok(ok__) => {
+                        ppdataobject.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DoVerb<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, iverb: i32, lpmsg: *const super::super::UI::WindowsAndMessaging::MSG, pactivesite: *mut core::ffi::c_void, lindex: i32, hwndparent: super::super::Foundation::HWND, lprcposrect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleObject_Impl::DoVerb(this, core::mem::transmute_copy(&iverb), core::mem::transmute_copy(&lpmsg), core::mem::transmute_copy(&pactivesite), core::mem::transmute_copy(&lindex), core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&lprcposrect)).into()
+            }
+        }
+        unsafe extern "system" fn EnumVerbs<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppenumoleverb: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOleObject_Impl::EnumVerbs(this) {
+                    Ok(ok__) => {
+                        ppenumoleverb.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Update<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleObject_Impl::Update(this).into()
+            }
+        }
+        unsafe extern "system" fn IsUpToDate<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleObject_Impl::IsUpToDate(this).into()
+            }
+        }
+        unsafe extern "system" fn GetUserClassID<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOleObject_Impl::GetUserClassID(this) {
+                    Ok(ok__) => {
+                        pclsid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetUserType<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwformoftype: u32, pszusertype: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOleObject_Impl::GetUserType(this, core::mem::transmute(&dwformoftype)) {
+                    Ok(ok__) => {
+                        pszusertype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetExtent<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwdrawaspect: super::Com::DVASPECT, psizel: *const super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleObject_Impl::SetExtent(this, core::mem::transmute_copy(&dwdrawaspect), core::mem::transmute_copy(&psizel)).into()
+            }
+        }
+        unsafe extern "system" fn GetExtent<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwdrawaspect: super::Com::DVASPECT, psizel: *mut super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOleObject_Impl::GetExtent(this, core::mem::transmute_copy(&dwdrawaspect)) {
+                    Ok(ok__) => {
+                        psizel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Advise<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, padvsink: *mut core::ffi::c_void, pdwconnection: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOleObject_Impl::Advise(this, core::mem::transmute_copy(&padvsink)) {
+                    Ok(ok__) => {
+                        pdwconnection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Unadvise<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwconnection: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleObject_Impl::Unadvise(this, core::mem::transmute_copy(&dwconnection)).into()
+            }
+        }
+        unsafe extern "system" fn EnumAdvise<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppenumadvise: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOleObject_Impl::EnumAdvise(this) {
+                    Ok(ok__) => {
+                        ppenumadvise.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetMiscStatus<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwaspect: super::Com::DVASPECT, pdwstatus: *mut OLEMISC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IOleObject_Impl::GetMiscStatus(this, core::mem::transmute_copy(&dwaspect)) {
+                    Ok(ok__) => {
+                        pdwstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetColorScheme<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, plogpal: *const super::super::Graphics::Gdi::LOGPALETTE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IOleObject_Impl::SetColorScheme(this, core::mem::transmute_copy(&plogpal)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            SetClientSite: SetClientSite::<Identity, OFFSET>,
+            GetClientSite: GetClientSite::<Identity, OFFSET>,
+            SetHostNames: SetHostNames::<Identity, OFFSET>,
+            Close: Close::<Identity, OFFSET>,
+            SetMoniker: SetMoniker::<Identity, OFFSET>,
+            GetMoniker: GetMoniker::<Identity, OFFSET>,
+            InitFromData: InitFromData::<Identity, OFFSET>,
+            GetClipboardData: GetClipboardData::<Identity, OFFSET>,
+            DoVerb: DoVerb::<Identity, OFFSET>,
+            EnumVerbs: EnumVerbs::<Identity, OFFSET>,
+            Update: Update::<Identity, OFFSET>,
+            IsUpToDate: IsUpToDate::<Identity, OFFSET>,
+            GetUserClassID: GetUserClassID::<Identity, OFFSET>,
+            GetUserType: GetUserType::<Identity, OFFSET>,
+            SetExtent: SetExtent::<Identity, OFFSET>,
+            GetExtent: GetExtent::<Identity, OFFSET>,
+            Advise: Advise::<Identity, OFFSET>,
+            Unadvise: Unadvise::<Identity, OFFSET>,
+            EnumAdvise: EnumAdvise::<Identity, OFFSET>,
+            GetMiscStatus: GetMiscStatus::<Identity, OFFSET>,
+            SetColorScheme: SetColorScheme::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleObject as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com", feature = "Win32_UI_WindowsAndMessaging"))]
+impl<Identity: windows_core::IUnknownImpl + IOleObject_Impl> windows_core::imp::TearOffVtable<Identity> for IOleObject_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetClientSite<Identity: windows_core::IUnknownImpl + IOleObject_Impl>(this: *mut core::ffi::c_void, pclientsite: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleObject_Impl::SetClientSite(this, core::mem::transmute_copy(&pclientsite)).into()
             }
         }
-        unsafe extern "system" fn GetClientSite<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppclientsite: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetClientSite<Identity: windows_core::IUnknownImpl + IOleObject_Impl>(this: *mut core::ffi::c_void, ppclientsite: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOleObject_Impl::GetClientSite(this) {
                     Ok(ok__) => {
                         ppclientsite.write(core::mem::transmute(ok__));
@@ -9837,27 +12581,27 @@ impl IOleObject_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetHostNames<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, szcontainerapp: windows_core::PCWSTR, szcontainerobj: windows_core::PCWSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetHostNames<Identity: windows_core::IUnknownImpl + IOleObject_Impl>(this: *mut core::ffi::c_void, szcontainerapp: windows_core::PCWSTR, szcontainerobj: windows_core::PCWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleObject_Impl::SetHostNames(this, core::mem::transmute(&szcontainerapp), core::mem::transmute(&szcontainerobj)).into()
             }
         }
-        unsafe extern "system" fn Close<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwsaveoption: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IOleObject_Impl>(this: *mut core::ffi::c_void, dwsaveoption: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleObject_Impl::Close(this, core::mem::transmute(&dwsaveoption)).into()
             }
         }
-        unsafe extern "system" fn SetMoniker<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwwhichmoniker: u32, pmk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetMoniker<Identity: windows_core::IUnknownImpl + IOleObject_Impl>(this: *mut core::ffi::c_void, dwwhichmoniker: u32, pmk: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleObject_Impl::SetMoniker(this, core::mem::transmute(&dwwhichmoniker), core::mem::transmute_copy(&pmk)).into()
             }
         }
-        unsafe extern "system" fn GetMoniker<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwassign: u32, dwwhichmoniker: u32, ppmk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetMoniker<Identity: windows_core::IUnknownImpl + IOleObject_Impl>(this: *mut core::ffi::c_void, dwassign: u32, dwwhichmoniker: u32, ppmk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOleObject_Impl::GetMoniker(this, core::mem::transmute(&dwassign), core::mem::transmute(&dwwhichmoniker)) {
                     Ok(ok__) => {
                         ppmk.write(core::mem::transmute(ok__));
@@ -9867,15 +12611,15 @@ impl IOleObject_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn InitFromData<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdataobject: *mut core::ffi::c_void, fcreation: windows_core::BOOL, dwreserved: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn InitFromData<Identity: windows_core::IUnknownImpl + IOleObject_Impl>(this: *mut core::ffi::c_void, pdataobject: *mut core::ffi::c_void, fcreation: windows_core::BOOL, dwreserved: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleObject_Impl::InitFromData(this, core::mem::transmute_copy(&pdataobject), core::mem::transmute_copy(&fcreation), core::mem::transmute_copy(&dwreserved)).into()
             }
         }
-        unsafe extern "system" fn GetClipboardData<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwreserved: u32, ppdataobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetClipboardData<Identity: windows_core::IUnknownImpl + IOleObject_Impl>(this: *mut core::ffi::c_void, dwreserved: u32, ppdataobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOleObject_Impl::GetClipboardData(this, core::mem::transmute_copy(&dwreserved)) {
                     Ok(ok__) => {
                         ppdataobject.write(core::mem::transmute(ok__));
@@ -9885,15 +12629,15 @@ impl IOleObject_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn DoVerb<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, iverb: i32, lpmsg: *const super::super::UI::WindowsAndMessaging::MSG, pactivesite: *mut core::ffi::c_void, lindex: i32, hwndparent: super::super::Foundation::HWND, lprcposrect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+        unsafe extern "system" fn DoVerb<Identity: windows_core::IUnknownImpl + IOleObject_Impl>(this: *mut core::ffi::c_void, iverb: i32, lpmsg: *const super::super::UI::WindowsAndMessaging::MSG, pactivesite: *mut core::ffi::c_void, lindex: i32, hwndparent: super::super::Foundation::HWND, lprcposrect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleObject_Impl::DoVerb(this, core::mem::transmute_copy(&iverb), core::mem::transmute_copy(&lpmsg), core::mem::transmute_copy(&pactivesite), core::mem::transmute_copy(&lindex), core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&lprcposrect)).into()
             }
         }
-        unsafe extern "system" fn EnumVerbs<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppenumoleverb: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn EnumVerbs<Identity: windows_core::IUnknownImpl + IOleObject_Impl>(this: *mut core::ffi::c_void, ppenumoleverb: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOleObject_Impl::EnumVerbs(this) {
                     Ok(ok__) => {
                         ppenumoleverb.write(core::mem::transmute(ok__));
@@ -9903,21 +12647,21 @@ impl IOleObject_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Update<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Update<Identity: windows_core::IUnknownImpl + IOleObject_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleObject_Impl::Update(this).into()
             }
         }
-        unsafe extern "system" fn IsUpToDate<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn IsUpToDate<Identity: windows_core::IUnknownImpl + IOleObject_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleObject_Impl::IsUpToDate(this).into()
             }
         }
-        unsafe extern "system" fn GetUserClassID<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetUserClassID<Identity: windows_core::IUnknownImpl + IOleObject_Impl>(this: *mut core::ffi::c_void, pclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOleObject_Impl::GetUserClassID(this) {
                     Ok(ok__) => {
                         pclsid.write(core::mem::transmute(ok__));
@@ -9927,9 +12671,9 @@ impl IOleObject_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetUserType<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwformoftype: u32, pszusertype: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetUserType<Identity: windows_core::IUnknownImpl + IOleObject_Impl>(this: *mut core::ffi::c_void, dwformoftype: u32, pszusertype: *mut windows_core::PWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOleObject_Impl::GetUserType(this, core::mem::transmute(&dwformoftype)) {
                     Ok(ok__) => {
                         pszusertype.write(core::mem::transmute(ok__));
@@ -9939,15 +12683,15 @@ impl IOleObject_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetExtent<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwdrawaspect: super::Com::DVASPECT, psizel: *const super::super::Foundation::SIZE) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetExtent<Identity: windows_core::IUnknownImpl + IOleObject_Impl>(this: *mut core::ffi::c_void, dwdrawaspect: super::Com::DVASPECT, psizel: *const super::super::Foundation::SIZE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleObject_Impl::SetExtent(this, core::mem::transmute_copy(&dwdrawaspect), core::mem::transmute_copy(&psizel)).into()
             }
         }
-        unsafe extern "system" fn GetExtent<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwdrawaspect: super::Com::DVASPECT, psizel: *mut super::super::Foundation::SIZE) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetExtent<Identity: windows_core::IUnknownImpl + IOleObject_Impl>(this: *mut core::ffi::c_void, dwdrawaspect: super::Com::DVASPECT, psizel: *mut super::super::Foundation::SIZE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOleObject_Impl::GetExtent(this, core::mem::transmute_copy(&dwdrawaspect)) {
                     Ok(ok__) => {
                         psizel.write(core::mem::transmute(ok__));
@@ -9957,9 +12701,9 @@ impl IOleObject_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Advise<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, padvsink: *mut core::ffi::c_void, pdwconnection: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn Advise<Identity: windows_core::IUnknownImpl + IOleObject_Impl>(this: *mut core::ffi::c_void, padvsink: *mut core::ffi::c_void, pdwconnection: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOleObject_Impl::Advise(this, core::mem::transmute_copy(&padvsink)) {
                     Ok(ok__) => {
                         pdwconnection.write(core::mem::transmute(ok__));
@@ -9969,15 +12713,15 @@ impl IOleObject_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Unadvise<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwconnection: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn Unadvise<Identity: windows_core::IUnknownImpl + IOleObject_Impl>(this: *mut core::ffi::c_void, dwconnection: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleObject_Impl::Unadvise(this, core::mem::transmute_copy(&dwconnection)).into()
             }
         }
-        unsafe extern "system" fn EnumAdvise<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppenumadvise: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn EnumAdvise<Identity: windows_core::IUnknownImpl + IOleObject_Impl>(this: *mut core::ffi::c_void, ppenumadvise: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOleObject_Impl::EnumAdvise(this) {
                     Ok(ok__) => {
                         ppenumadvise.write(core::mem::transmute(ok__));
@@ -9987,9 +12731,9 @@ impl IOleObject_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetMiscStatus<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwaspect: super::Com::DVASPECT, pdwstatus: *mut OLEMISC) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetMiscStatus<Identity: windows_core::IUnknownImpl + IOleObject_Impl>(this: *mut core::ffi::c_void, dwaspect: super::Com::DVASPECT, pdwstatus: *mut OLEMISC) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IOleObject_Impl::GetMiscStatus(this, core::mem::transmute_copy(&dwaspect)) {
                     Ok(ok__) => {
                         pdwstatus.write(core::mem::transmute(ok__));
@@ -9999,40 +12743,37 @@ impl IOleObject_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetColorScheme<Identity: IOleObject_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, plogpal: *const super::super::Graphics::Gdi::LOGPALETTE) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetColorScheme<Identity: windows_core::IUnknownImpl + IOleObject_Impl>(this: *mut core::ffi::c_void, plogpal: *const super::super::Graphics::Gdi::LOGPALETTE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IOleObject_Impl::SetColorScheme(this, core::mem::transmute_copy(&plogpal)).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            SetClientSite: SetClientSite::<Identity, OFFSET>,
-            GetClientSite: GetClientSite::<Identity, OFFSET>,
-            SetHostNames: SetHostNames::<Identity, OFFSET>,
-            Close: Close::<Identity, OFFSET>,
-            SetMoniker: SetMoniker::<Identity, OFFSET>,
-            GetMoniker: GetMoniker::<Identity, OFFSET>,
-            InitFromData: InitFromData::<Identity, OFFSET>,
-            GetClipboardData: GetClipboardData::<Identity, OFFSET>,
-            DoVerb: DoVerb::<Identity, OFFSET>,
-            EnumVerbs: EnumVerbs::<Identity, OFFSET>,
-            Update: Update::<Identity, OFFSET>,
-            IsUpToDate: IsUpToDate::<Identity, OFFSET>,
-            GetUserClassID: GetUserClassID::<Identity, OFFSET>,
-            GetUserType: GetUserType::<Identity, OFFSET>,
-            SetExtent: SetExtent::<Identity, OFFSET>,
-            GetExtent: GetExtent::<Identity, OFFSET>,
-            Advise: Advise::<Identity, OFFSET>,
-            Unadvise: Unadvise::<Identity, OFFSET>,
-            EnumAdvise: EnumAdvise::<Identity, OFFSET>,
-            GetMiscStatus: GetMiscStatus::<Identity, OFFSET>,
-            SetColorScheme: SetColorScheme::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IOleObject as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetClientSite: SetClientSite::<Identity>,
+            GetClientSite: GetClientSite::<Identity>,
+            SetHostNames: SetHostNames::<Identity>,
+            Close: Close::<Identity>,
+            SetMoniker: SetMoniker::<Identity>,
+            GetMoniker: GetMoniker::<Identity>,
+            InitFromData: InitFromData::<Identity>,
+            GetClipboardData: GetClipboardData::<Identity>,
+            DoVerb: DoVerb::<Identity>,
+            EnumVerbs: EnumVerbs::<Identity>,
+            Update: Update::<Identity>,
+            IsUpToDate: IsUpToDate::<Identity>,
+            GetUserClassID: GetUserClassID::<Identity>,
+            GetUserType: GetUserType::<Identity>,
+            SetExtent: SetExtent::<Identity>,
+            GetExtent: GetExtent::<Identity>,
+            Advise: Advise::<Identity>,
+            Unadvise: Unadvise::<Identity>,
+            EnumAdvise: EnumAdvise::<Identity>,
+            GetMiscStatus: GetMiscStatus::<Identity>,
+            SetColorScheme: SetColorScheme::<Identity>,
+        }
+    };
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com", feature = "Win32_UI_WindowsAndMessaging"))]
 impl windows_core::RuntimeName for IOleObject {}
@@ -10144,6 +12885,57 @@ impl IOleParentUndoUnit_Vtbl {
         iid == &<IOleParentUndoUnit as windows_core::Interface>::IID || iid == &<IOleUndoUnit as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOleParentUndoUnit_Impl> windows_core::imp::TearOffVtable<Identity> for IOleParentUndoUnit_Vtbl
+where
+    IOleUndoUnit_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Open<Identity: windows_core::IUnknownImpl + IOleParentUndoUnit_Impl>(this: *mut core::ffi::c_void, ppuu: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleParentUndoUnit_Impl::Open(this, core::mem::transmute_copy(&ppuu)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IOleParentUndoUnit_Impl>(this: *mut core::ffi::c_void, ppuu: *mut core::ffi::c_void, fcommit: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleParentUndoUnit_Impl::Close(this, core::mem::transmute_copy(&ppuu), core::mem::transmute_copy(&fcommit)).into()
+            }
+        }
+        unsafe extern "system" fn Add<Identity: windows_core::IUnknownImpl + IOleParentUndoUnit_Impl>(this: *mut core::ffi::c_void, puu: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleParentUndoUnit_Impl::Add(this, core::mem::transmute_copy(&puu)).into()
+            }
+        }
+        unsafe extern "system" fn FindUnit<Identity: windows_core::IUnknownImpl + IOleParentUndoUnit_Impl>(this: *mut core::ffi::c_void, puu: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleParentUndoUnit_Impl::FindUnit(this, core::mem::transmute_copy(&puu)).into()
+            }
+        }
+        unsafe extern "system" fn GetParentState<Identity: windows_core::IUnknownImpl + IOleParentUndoUnit_Impl>(this: *mut core::ffi::c_void, pdwstate: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleParentUndoUnit_Impl::GetParentState(this) {
+                    Ok(ok__) => {
+                        pdwstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IOleUndoUnit_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Open: Open::<Identity>,
+            Close: Close::<Identity>,
+            Add: Add::<Identity>,
+            FindUnit: FindUnit::<Identity>,
+            GetParentState: GetParentState::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOleParentUndoUnit {}
 windows_core::imp::define_interface!(IOleUILinkContainerA, IOleUILinkContainerA_Vtbl, 0);
 windows_core::imp::interface_hierarchy!(IOleUILinkContainerA, windows_core::IUnknown);
@@ -10274,6 +13066,78 @@ impl IOleUILinkContainerA_Vtbl {
         iid == &<IOleUILinkContainerA as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOleUILinkContainerA_Impl> windows_core::imp::TearOffVtable<Identity> for IOleUILinkContainerA_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetNextLink<Identity: windows_core::IUnknownImpl + IOleUILinkContainerA_Impl>(this: *mut core::ffi::c_void, dwlink: u32) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUILinkContainerA_Impl::GetNextLink(this, core::mem::transmute_copy(&dwlink))
+            }
+        }
+        unsafe extern "system" fn SetLinkUpdateOptions<Identity: windows_core::IUnknownImpl + IOleUILinkContainerA_Impl>(this: *mut core::ffi::c_void, dwlink: u32, dwupdateopt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUILinkContainerA_Impl::SetLinkUpdateOptions(this, core::mem::transmute_copy(&dwlink), core::mem::transmute_copy(&dwupdateopt)).into()
+            }
+        }
+        unsafe extern "system" fn GetLinkUpdateOptions<Identity: windows_core::IUnknownImpl + IOleUILinkContainerA_Impl>(this: *mut core::ffi::c_void, dwlink: u32, lpdwupdateopt: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleUILinkContainerA_Impl::GetLinkUpdateOptions(this, core::mem::transmute_copy(&dwlink)) {
+                    Ok(ok__) => {
+                        lpdwupdateopt.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLinkSource<Identity: windows_core::IUnknownImpl + IOleUILinkContainerA_Impl>(this: *mut core::ffi::c_void, dwlink: u32, lpszdisplayname: windows_core::PCSTR, lenfilename: u32, pcheaten: *mut u32, fvalidatesource: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUILinkContainerA_Impl::SetLinkSource(this, core::mem::transmute_copy(&dwlink), core::mem::transmute(&lpszdisplayname), core::mem::transmute_copy(&lenfilename), core::mem::transmute_copy(&pcheaten), core::mem::transmute_copy(&fvalidatesource)).into()
+            }
+        }
+        unsafe extern "system" fn GetLinkSource<Identity: windows_core::IUnknownImpl + IOleUILinkContainerA_Impl>(this: *mut core::ffi::c_void, dwlink: u32, lplpszdisplayname: *mut windows_core::PSTR, lplenfilename: *mut u32, lplpszfulllinktype: *mut windows_core::PSTR, lplpszshortlinktype: *mut windows_core::PSTR, lpfsourceavailable: *mut windows_core::BOOL, lpfisselected: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUILinkContainerA_Impl::GetLinkSource(this, core::mem::transmute_copy(&dwlink), core::mem::transmute_copy(&lplpszdisplayname), core::mem::transmute_copy(&lplenfilename), core::mem::transmute_copy(&lplpszfulllinktype), core::mem::transmute_copy(&lplpszshortlinktype), core::mem::transmute_copy(&lpfsourceavailable), core::mem::transmute_copy(&lpfisselected)).into()
+            }
+        }
+        unsafe extern "system" fn OpenLinkSource<Identity: windows_core::IUnknownImpl + IOleUILinkContainerA_Impl>(this: *mut core::ffi::c_void, dwlink: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUILinkContainerA_Impl::OpenLinkSource(this, core::mem::transmute_copy(&dwlink)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateLink<Identity: windows_core::IUnknownImpl + IOleUILinkContainerA_Impl>(this: *mut core::ffi::c_void, dwlink: u32, ferrormessage: windows_core::BOOL, freserved: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUILinkContainerA_Impl::UpdateLink(this, core::mem::transmute_copy(&dwlink), core::mem::transmute_copy(&ferrormessage), core::mem::transmute_copy(&freserved)).into()
+            }
+        }
+        unsafe extern "system" fn CancelLink<Identity: windows_core::IUnknownImpl + IOleUILinkContainerA_Impl>(this: *mut core::ffi::c_void, dwlink: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUILinkContainerA_Impl::CancelLink(this, core::mem::transmute_copy(&dwlink)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetNextLink: GetNextLink::<Identity>,
+            SetLinkUpdateOptions: SetLinkUpdateOptions::<Identity>,
+            GetLinkUpdateOptions: GetLinkUpdateOptions::<Identity>,
+            SetLinkSource: SetLinkSource::<Identity>,
+            GetLinkSource: GetLinkSource::<Identity>,
+            OpenLinkSource: OpenLinkSource::<Identity>,
+            UpdateLink: UpdateLink::<Identity>,
+            CancelLink: CancelLink::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOleUILinkContainerA {}
 windows_core::imp::define_interface!(IOleUILinkContainerW, IOleUILinkContainerW_Vtbl, 0);
 windows_core::imp::interface_hierarchy!(IOleUILinkContainerW, windows_core::IUnknown);
@@ -10404,6 +13268,78 @@ impl IOleUILinkContainerW_Vtbl {
         iid == &<IOleUILinkContainerW as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOleUILinkContainerW_Impl> windows_core::imp::TearOffVtable<Identity> for IOleUILinkContainerW_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetNextLink<Identity: windows_core::IUnknownImpl + IOleUILinkContainerW_Impl>(this: *mut core::ffi::c_void, dwlink: u32) -> u32 {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUILinkContainerW_Impl::GetNextLink(this, core::mem::transmute_copy(&dwlink))
+            }
+        }
+        unsafe extern "system" fn SetLinkUpdateOptions<Identity: windows_core::IUnknownImpl + IOleUILinkContainerW_Impl>(this: *mut core::ffi::c_void, dwlink: u32, dwupdateopt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUILinkContainerW_Impl::SetLinkUpdateOptions(this, core::mem::transmute_copy(&dwlink), core::mem::transmute_copy(&dwupdateopt)).into()
+            }
+        }
+        unsafe extern "system" fn GetLinkUpdateOptions<Identity: windows_core::IUnknownImpl + IOleUILinkContainerW_Impl>(this: *mut core::ffi::c_void, dwlink: u32, lpdwupdateopt: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleUILinkContainerW_Impl::GetLinkUpdateOptions(this, core::mem::transmute_copy(&dwlink)) {
+                    Ok(ok__) => {
+                        lpdwupdateopt.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLinkSource<Identity: windows_core::IUnknownImpl + IOleUILinkContainerW_Impl>(this: *mut core::ffi::c_void, dwlink: u32, lpszdisplayname: windows_core::PCWSTR, lenfilename: u32, pcheaten: *mut u32, fvalidatesource: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUILinkContainerW_Impl::SetLinkSource(this, core::mem::transmute_copy(&dwlink), core::mem::transmute(&lpszdisplayname), core::mem::transmute_copy(&lenfilename), core::mem::transmute_copy(&pcheaten), core::mem::transmute_copy(&fvalidatesource)).into()
+            }
+        }
+        unsafe extern "system" fn GetLinkSource<Identity: windows_core::IUnknownImpl + IOleUILinkContainerW_Impl>(this: *mut core::ffi::c_void, dwlink: u32, lplpszdisplayname: *mut windows_core::PWSTR, lplenfilename: *mut u32, lplpszfulllinktype: *mut windows_core::PWSTR, lplpszshortlinktype: *mut windows_core::PWSTR, lpfsourceavailable: *mut windows_core::BOOL, lpfisselected: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUILinkContainerW_Impl::GetLinkSource(this, core::mem::transmute_copy(&dwlink), core::mem::transmute_copy(&lplpszdisplayname), core::mem::transmute_copy(&lplenfilename), core::mem::transmute_copy(&lplpszfulllinktype), core::mem::transmute_copy(&lplpszshortlinktype), core::mem::transmute_copy(&lpfsourceavailable), core::mem::transmute_copy(&lpfisselected)).into()
+            }
+        }
+        unsafe extern "system" fn OpenLinkSource<Identity: windows_core::IUnknownImpl + IOleUILinkContainerW_Impl>(this: *mut core::ffi::c_void, dwlink: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUILinkContainerW_Impl::OpenLinkSource(this, core::mem::transmute_copy(&dwlink)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateLink<Identity: windows_core::IUnknownImpl + IOleUILinkContainerW_Impl>(this: *mut core::ffi::c_void, dwlink: u32, ferrormessage: windows_core::BOOL, freserved: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUILinkContainerW_Impl::UpdateLink(this, core::mem::transmute_copy(&dwlink), core::mem::transmute_copy(&ferrormessage), core::mem::transmute_copy(&freserved)).into()
+            }
+        }
+        unsafe extern "system" fn CancelLink<Identity: windows_core::IUnknownImpl + IOleUILinkContainerW_Impl>(this: *mut core::ffi::c_void, dwlink: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUILinkContainerW_Impl::CancelLink(this, core::mem::transmute_copy(&dwlink)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetNextLink: GetNextLink::<Identity>,
+            SetLinkUpdateOptions: SetLinkUpdateOptions::<Identity>,
+            GetLinkUpdateOptions: GetLinkUpdateOptions::<Identity>,
+            SetLinkSource: SetLinkSource::<Identity>,
+            GetLinkSource: GetLinkSource::<Identity>,
+            OpenLinkSource: OpenLinkSource::<Identity>,
+            UpdateLink: UpdateLink::<Identity>,
+            CancelLink: CancelLink::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOleUILinkContainerW {}
 windows_core::imp::define_interface!(IOleUILinkInfoA, IOleUILinkInfoA_Vtbl, 0);
 impl core::ops::Deref for IOleUILinkInfoA {
@@ -10450,6 +13386,26 @@ impl IOleUILinkInfoA_Vtbl {
         iid == &<IOleUILinkInfoA as windows_core::Interface>::IID || iid == &<IOleUILinkContainerA as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOleUILinkInfoA_Impl> windows_core::imp::TearOffVtable<Identity> for IOleUILinkInfoA_Vtbl
+where
+    IOleUILinkContainerA_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetLastUpdate<Identity: windows_core::IUnknownImpl + IOleUILinkInfoA_Impl>(this: *mut core::ffi::c_void, dwlink: u32, lplastupdate: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleUILinkInfoA_Impl::GetLastUpdate(this, core::mem::transmute_copy(&dwlink)) {
+                    Ok(ok__) => {
+                        lplastupdate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IOleUILinkContainerA_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetLastUpdate: GetLastUpdate::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IOleUILinkInfoA {}
 windows_core::imp::define_interface!(IOleUILinkInfoW, IOleUILinkInfoW_Vtbl, 0);
 impl core::ops::Deref for IOleUILinkInfoW {
@@ -10496,6 +13452,26 @@ impl IOleUILinkInfoW_Vtbl {
         iid == &<IOleUILinkInfoW as windows_core::Interface>::IID || iid == &<IOleUILinkContainerW as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOleUILinkInfoW_Impl> windows_core::imp::TearOffVtable<Identity> for IOleUILinkInfoW_Vtbl
+where
+    IOleUILinkContainerW_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetLastUpdate<Identity: windows_core::IUnknownImpl + IOleUILinkInfoW_Impl>(this: *mut core::ffi::c_void, dwlink: u32, lplastupdate: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleUILinkInfoW_Impl::GetLastUpdate(this, core::mem::transmute_copy(&dwlink)) {
+                    Ok(ok__) => {
+                        lplastupdate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IOleUILinkContainerW_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetLastUpdate: GetLastUpdate::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IOleUILinkInfoW {}
 windows_core::imp::define_interface!(IOleUIObjInfoA, IOleUIObjInfoA_Vtbl, 0);
 windows_core::imp::interface_hierarchy!(IOleUIObjInfoA, windows_core::IUnknown);
@@ -10578,6 +13554,51 @@ impl IOleUIObjInfoA_Vtbl {
         iid == &<IOleUIObjInfoA as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOleUIObjInfoA_Impl> windows_core::imp::TearOffVtable<Identity> for IOleUIObjInfoA_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetObjectInfo<Identity: windows_core::IUnknownImpl + IOleUIObjInfoA_Impl>(this: *mut core::ffi::c_void, dwobject: u32, lpdwobjsize: *mut u32, lplpszlabel: *mut windows_core::PSTR, lplpsztype: *mut windows_core::PSTR, lplpszshorttype: *mut windows_core::PSTR, lplpszlocation: *mut windows_core::PSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUIObjInfoA_Impl::GetObjectInfo(this, core::mem::transmute_copy(&dwobject), core::mem::transmute_copy(&lpdwobjsize), core::mem::transmute_copy(&lplpszlabel), core::mem::transmute_copy(&lplpsztype), core::mem::transmute_copy(&lplpszshorttype), core::mem::transmute_copy(&lplpszlocation)).into()
+            }
+        }
+        unsafe extern "system" fn GetConvertInfo<Identity: windows_core::IUnknownImpl + IOleUIObjInfoA_Impl>(this: *mut core::ffi::c_void, dwobject: u32, lpclassid: *mut windows_core::GUID, lpwformat: *mut u16, lpconvertdefaultclassid: *mut windows_core::GUID, lplpclsidexclude: *mut *mut windows_core::GUID, lpcclsidexclude: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUIObjInfoA_Impl::GetConvertInfo(this, core::mem::transmute_copy(&dwobject), core::mem::transmute_copy(&lpclassid), core::mem::transmute_copy(&lpwformat), core::mem::transmute_copy(&lpconvertdefaultclassid), core::mem::transmute_copy(&lplpclsidexclude), core::mem::transmute_copy(&lpcclsidexclude)).into()
+            }
+        }
+        unsafe extern "system" fn ConvertObject<Identity: windows_core::IUnknownImpl + IOleUIObjInfoA_Impl>(this: *mut core::ffi::c_void, dwobject: u32, clsidnew: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUIObjInfoA_Impl::ConvertObject(this, core::mem::transmute_copy(&dwobject), core::mem::transmute_copy(&clsidnew)).into()
+            }
+        }
+        unsafe extern "system" fn GetViewInfo<Identity: windows_core::IUnknownImpl + IOleUIObjInfoA_Impl>(this: *mut core::ffi::c_void, dwobject: u32, phmetapict: *const super::super::Foundation::HGLOBAL, pdvaspect: *const u32, pncurrentscale: *const i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUIObjInfoA_Impl::GetViewInfo(this, core::mem::transmute_copy(&dwobject), core::mem::transmute_copy(&phmetapict), core::mem::transmute_copy(&pdvaspect), core::mem::transmute_copy(&pncurrentscale)).into()
+            }
+        }
+        unsafe extern "system" fn SetViewInfo<Identity: windows_core::IUnknownImpl + IOleUIObjInfoA_Impl>(this: *mut core::ffi::c_void, dwobject: u32, hmetapict: super::super::Foundation::HGLOBAL, dvaspect: u32, ncurrentscale: i32, brelativetoorig: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUIObjInfoA_Impl::SetViewInfo(this, core::mem::transmute_copy(&dwobject), core::mem::transmute_copy(&hmetapict), core::mem::transmute_copy(&dvaspect), core::mem::transmute_copy(&ncurrentscale), core::mem::transmute_copy(&brelativetoorig)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetObjectInfo: GetObjectInfo::<Identity>,
+            GetConvertInfo: GetConvertInfo::<Identity>,
+            ConvertObject: ConvertObject::<Identity>,
+            GetViewInfo: GetViewInfo::<Identity>,
+            SetViewInfo: SetViewInfo::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOleUIObjInfoA {}
 windows_core::imp::define_interface!(IOleUIObjInfoW, IOleUIObjInfoW_Vtbl, 0);
 windows_core::imp::interface_hierarchy!(IOleUIObjInfoW, windows_core::IUnknown);
@@ -10660,6 +13681,51 @@ impl IOleUIObjInfoW_Vtbl {
         iid == &<IOleUIObjInfoW as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOleUIObjInfoW_Impl> windows_core::imp::TearOffVtable<Identity> for IOleUIObjInfoW_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetObjectInfo<Identity: windows_core::IUnknownImpl + IOleUIObjInfoW_Impl>(this: *mut core::ffi::c_void, dwobject: u32, lpdwobjsize: *mut u32, lplpszlabel: *mut windows_core::PWSTR, lplpsztype: *mut windows_core::PWSTR, lplpszshorttype: *mut windows_core::PWSTR, lplpszlocation: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUIObjInfoW_Impl::GetObjectInfo(this, core::mem::transmute_copy(&dwobject), core::mem::transmute_copy(&lpdwobjsize), core::mem::transmute_copy(&lplpszlabel), core::mem::transmute_copy(&lplpsztype), core::mem::transmute_copy(&lplpszshorttype), core::mem::transmute_copy(&lplpszlocation)).into()
+            }
+        }
+        unsafe extern "system" fn GetConvertInfo<Identity: windows_core::IUnknownImpl + IOleUIObjInfoW_Impl>(this: *mut core::ffi::c_void, dwobject: u32, lpclassid: *mut windows_core::GUID, lpwformat: *mut u16, lpconvertdefaultclassid: *mut windows_core::GUID, lplpclsidexclude: *mut *mut windows_core::GUID, lpcclsidexclude: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUIObjInfoW_Impl::GetConvertInfo(this, core::mem::transmute_copy(&dwobject), core::mem::transmute_copy(&lpclassid), core::mem::transmute_copy(&lpwformat), core::mem::transmute_copy(&lpconvertdefaultclassid), core::mem::transmute_copy(&lplpclsidexclude), core::mem::transmute_copy(&lpcclsidexclude)).into()
+            }
+        }
+        unsafe extern "system" fn ConvertObject<Identity: windows_core::IUnknownImpl + IOleUIObjInfoW_Impl>(this: *mut core::ffi::c_void, dwobject: u32, clsidnew: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUIObjInfoW_Impl::ConvertObject(this, core::mem::transmute_copy(&dwobject), core::mem::transmute_copy(&clsidnew)).into()
+            }
+        }
+        unsafe extern "system" fn GetViewInfo<Identity: windows_core::IUnknownImpl + IOleUIObjInfoW_Impl>(this: *mut core::ffi::c_void, dwobject: u32, phmetapict: *const super::super::Foundation::HGLOBAL, pdvaspect: *const u32, pncurrentscale: *const i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUIObjInfoW_Impl::GetViewInfo(this, core::mem::transmute_copy(&dwobject), core::mem::transmute_copy(&phmetapict), core::mem::transmute_copy(&pdvaspect), core::mem::transmute_copy(&pncurrentscale)).into()
+            }
+        }
+        unsafe extern "system" fn SetViewInfo<Identity: windows_core::IUnknownImpl + IOleUIObjInfoW_Impl>(this: *mut core::ffi::c_void, dwobject: u32, hmetapict: super::super::Foundation::HGLOBAL, dvaspect: u32, ncurrentscale: i32, brelativetoorig: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUIObjInfoW_Impl::SetViewInfo(this, core::mem::transmute_copy(&dwobject), core::mem::transmute_copy(&hmetapict), core::mem::transmute_copy(&dvaspect), core::mem::transmute_copy(&ncurrentscale), core::mem::transmute_copy(&brelativetoorig)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetObjectInfo: GetObjectInfo::<Identity>,
+            GetConvertInfo: GetConvertInfo::<Identity>,
+            ConvertObject: ConvertObject::<Identity>,
+            GetViewInfo: GetViewInfo::<Identity>,
+            SetViewInfo: SetViewInfo::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOleUIObjInfoW {}
 windows_core::imp::define_interface!(IOleUndoManager, IOleUndoManager_Vtbl, 0xd001f200_ef97_11ce_9bc9_00aa00608e01);
 windows_core::imp::interface_hierarchy!(IOleUndoManager, windows_core::IUnknown);
@@ -10889,6 +13955,130 @@ impl IOleUndoManager_Vtbl {
         iid == &<IOleUndoManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOleUndoManager_Impl> windows_core::imp::TearOffVtable<Identity> for IOleUndoManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Open<Identity: windows_core::IUnknownImpl + IOleUndoManager_Impl>(this: *mut core::ffi::c_void, ppuu: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUndoManager_Impl::Open(this, core::mem::transmute_copy(&ppuu)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IOleUndoManager_Impl>(this: *mut core::ffi::c_void, ppuu: *mut core::ffi::c_void, fcommit: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUndoManager_Impl::Close(this, core::mem::transmute_copy(&ppuu), core::mem::transmute_copy(&fcommit))
+            }
+        }
+        unsafe extern "system" fn Add<Identity: windows_core::IUnknownImpl + IOleUndoManager_Impl>(this: *mut core::ffi::c_void, puu: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUndoManager_Impl::Add(this, core::mem::transmute_copy(&puu)).into()
+            }
+        }
+        unsafe extern "system" fn GetOpenParentState<Identity: windows_core::IUnknownImpl + IOleUndoManager_Impl>(this: *mut core::ffi::c_void, pdwstate: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleUndoManager_Impl::GetOpenParentState(this) {
+                    Ok(ok__) => {
+                        pdwstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DiscardFrom<Identity: windows_core::IUnknownImpl + IOleUndoManager_Impl>(this: *mut core::ffi::c_void, puu: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUndoManager_Impl::DiscardFrom(this, core::mem::transmute_copy(&puu)).into()
+            }
+        }
+        unsafe extern "system" fn UndoTo<Identity: windows_core::IUnknownImpl + IOleUndoManager_Impl>(this: *mut core::ffi::c_void, puu: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUndoManager_Impl::UndoTo(this, core::mem::transmute_copy(&puu)).into()
+            }
+        }
+        unsafe extern "system" fn RedoTo<Identity: windows_core::IUnknownImpl + IOleUndoManager_Impl>(this: *mut core::ffi::c_void, puu: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUndoManager_Impl::RedoTo(this, core::mem::transmute_copy(&puu)).into()
+            }
+        }
+        unsafe extern "system" fn EnumUndoable<Identity: windows_core::IUnknownImpl + IOleUndoManager_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleUndoManager_Impl::EnumUndoable(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumRedoable<Identity: windows_core::IUnknownImpl + IOleUndoManager_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleUndoManager_Impl::EnumRedoable(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLastUndoDescription<Identity: windows_core::IUnknownImpl + IOleUndoManager_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleUndoManager_Impl::GetLastUndoDescription(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLastRedoDescription<Identity: windows_core::IUnknownImpl + IOleUndoManager_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleUndoManager_Impl::GetLastRedoDescription(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Enable<Identity: windows_core::IUnknownImpl + IOleUndoManager_Impl>(this: *mut core::ffi::c_void, fenable: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUndoManager_Impl::Enable(this, core::mem::transmute_copy(&fenable)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Open: Open::<Identity>,
+            Close: Close::<Identity>,
+            Add: Add::<Identity>,
+            GetOpenParentState: GetOpenParentState::<Identity>,
+            DiscardFrom: DiscardFrom::<Identity>,
+            UndoTo: UndoTo::<Identity>,
+            RedoTo: RedoTo::<Identity>,
+            EnumUndoable: EnumUndoable::<Identity>,
+            EnumRedoable: EnumRedoable::<Identity>,
+            GetLastUndoDescription: GetLastUndoDescription::<Identity>,
+            GetLastRedoDescription: GetLastRedoDescription::<Identity>,
+            Enable: Enable::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOleUndoManager {}
 windows_core::imp::define_interface!(IOleUndoUnit, IOleUndoUnit_Vtbl, 0x894ad3b0_ef97_11ce_9bc9_00aa00608e01);
 windows_core::imp::interface_hierarchy!(IOleUndoUnit, windows_core::IUnknown);
@@ -10971,6 +14161,50 @@ impl IOleUndoUnit_Vtbl {
         iid == &<IOleUndoUnit as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOleUndoUnit_Impl> windows_core::imp::TearOffVtable<Identity> for IOleUndoUnit_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Do<Identity: windows_core::IUnknownImpl + IOleUndoUnit_Impl>(this: *mut core::ffi::c_void, pundomanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUndoUnit_Impl::Do(this, core::mem::transmute_copy(&pundomanager)).into()
+            }
+        }
+        unsafe extern "system" fn GetDescription<Identity: windows_core::IUnknownImpl + IOleUndoUnit_Impl>(this: *mut core::ffi::c_void, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleUndoUnit_Impl::GetDescription(this) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetUnitType<Identity: windows_core::IUnknownImpl + IOleUndoUnit_Impl>(this: *mut core::ffi::c_void, pclsid: *mut windows_core::GUID, plid: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUndoUnit_Impl::GetUnitType(this, core::mem::transmute_copy(&pclsid), core::mem::transmute_copy(&plid)).into()
+            }
+        }
+        unsafe extern "system" fn OnNextAdd<Identity: windows_core::IUnknownImpl + IOleUndoUnit_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleUndoUnit_Impl::OnNextAdd(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Do: Do::<Identity>,
+            GetDescription: GetDescription::<Identity>,
+            GetUnitType: GetUnitType::<Identity>,
+            OnNextAdd: OnNextAdd::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOleUndoUnit {}
 windows_core::imp::define_interface!(IOleWindow, IOleWindow_Vtbl, 0x00000114_0000_0000_c000_000000000046);
 windows_core::imp::interface_hierarchy!(IOleWindow, windows_core::IUnknown);
@@ -11026,6 +14260,36 @@ impl IOleWindow_Vtbl {
         iid == &<IOleWindow as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IOleWindow_Impl> windows_core::imp::TearOffVtable<Identity> for IOleWindow_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetWindow<Identity: windows_core::IUnknownImpl + IOleWindow_Impl>(this: *mut core::ffi::c_void, phwnd: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IOleWindow_Impl::GetWindow(this) {
+                    Ok(ok__) => {
+                        phwnd.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ContextSensitiveHelp<Identity: windows_core::IUnknownImpl + IOleWindow_Impl>(this: *mut core::ffi::c_void, fentermode: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IOleWindow_Impl::ContextSensitiveHelp(this, core::mem::transmute_copy(&fentermode)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetWindow: GetWindow::<Identity>,
+            ContextSensitiveHelp: ContextSensitiveHelp::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IOleWindow {}
 windows_core::imp::define_interface!(IParseDisplayName, IParseDisplayName_Vtbl, 0x0000011a_0000_0000_c000_000000000046);
 windows_core::imp::interface_hierarchy!(IParseDisplayName, windows_core::IUnknown);
@@ -11068,6 +14332,21 @@ impl IParseDisplayName_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IParseDisplayName_Impl> windows_core::imp::TearOffVtable<Identity> for IParseDisplayName_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ParseDisplayName<Identity: windows_core::IUnknownImpl + IParseDisplayName_Impl>(this: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void, pszdisplayname: windows_core::PCWSTR, pcheaten: *mut u32, ppmkout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IParseDisplayName_Impl::ParseDisplayName(this, core::mem::transmute_copy(&pbc), core::mem::transmute(&pszdisplayname), core::mem::transmute_copy(&pcheaten), core::mem::transmute_copy(&ppmkout)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ParseDisplayName: ParseDisplayName::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IParseDisplayName {}
 windows_core::imp::define_interface!(IPerPropertyBrowsing, IPerPropertyBrowsing_Vtbl, 0x376bd3aa_3845_101b_84ed_08002b2ec713);
 windows_core::imp::interface_hierarchy!(IPerPropertyBrowsing, windows_core::IUnknown);
@@ -11172,6 +14451,63 @@ impl IPerPropertyBrowsing_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPerPropertyBrowsing_Impl> windows_core::imp::TearOffVtable<Identity> for IPerPropertyBrowsing_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDisplayString<Identity: windows_core::IUnknownImpl + IPerPropertyBrowsing_Impl>(this: *mut core::ffi::c_void, dispid: i32, pbstr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPerPropertyBrowsing_Impl::GetDisplayString(this, core::mem::transmute_copy(&dispid)) {
+                    Ok(ok__) => {
+                        pbstr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MapPropertyToPage<Identity: windows_core::IUnknownImpl + IPerPropertyBrowsing_Impl>(this: *mut core::ffi::c_void, dispid: i32, pclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPerPropertyBrowsing_Impl::MapPropertyToPage(this, core::mem::transmute_copy(&dispid)) {
+                    Ok(ok__) => {
+                        pclsid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPredefinedStrings<Identity: windows_core::IUnknownImpl + IPerPropertyBrowsing_Impl>(this: *mut core::ffi::c_void, dispid: i32, pcastringsout: *mut CALPOLESTR, pcacookiesout: *mut CADWORD) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPerPropertyBrowsing_Impl::GetPredefinedStrings(this, core::mem::transmute_copy(&dispid), core::mem::transmute_copy(&pcastringsout), core::mem::transmute_copy(&pcacookiesout)).into()
+            }
+        }
+        unsafe extern "system" fn GetPredefinedValue<Identity: windows_core::IUnknownImpl + IPerPropertyBrowsing_Impl>(this: *mut core::ffi::c_void, dispid: i32, dwcookie: u32, pvarout: *mut super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPerPropertyBrowsing_Impl::GetPredefinedValue(this, core::mem::transmute_copy(&dispid), core::mem::transmute_copy(&dwcookie)) {
+                    Ok(ok__) => {
+                        pvarout.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDisplayString: GetDisplayString::<Identity>,
+            MapPropertyToPage: MapPropertyToPage::<Identity>,
+            GetPredefinedStrings: GetPredefinedStrings::<Identity>,
+            GetPredefinedValue: GetPredefinedValue::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPerPropertyBrowsing {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPersistPropertyBag, IPersistPropertyBag_Vtbl, 0x37d84f60_42cb_11ce_8135_00aa004bb851);
@@ -11259,6 +14595,38 @@ impl IPersistPropertyBag_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl<Identity: windows_core::IUnknownImpl + IPersistPropertyBag_Impl> windows_core::imp::TearOffVtable<Identity> for IPersistPropertyBag_Vtbl
+where
+    super::Com::IPersist_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InitNew<Identity: windows_core::IUnknownImpl + IPersistPropertyBag_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPersistPropertyBag_Impl::InitNew(this).into()
+            }
+        }
+        unsafe extern "system" fn Load<Identity: windows_core::IUnknownImpl + IPersistPropertyBag_Impl>(this: *mut core::ffi::c_void, ppropbag: *mut core::ffi::c_void, perrorlog: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPersistPropertyBag_Impl::Load(this, core::mem::transmute_copy(&ppropbag), core::mem::transmute_copy(&perrorlog)).into()
+            }
+        }
+        unsafe extern "system" fn Save<Identity: windows_core::IUnknownImpl + IPersistPropertyBag_Impl>(this: *mut core::ffi::c_void, ppropbag: *mut core::ffi::c_void, fcleardirty: windows_core::BOOL, fsaveallproperties: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPersistPropertyBag_Impl::Save(this, core::mem::transmute_copy(&ppropbag), core::mem::transmute_copy(&fcleardirty), core::mem::transmute_copy(&fsaveallproperties)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IPersist_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InitNew: InitNew::<Identity>,
+            Load: Load::<Identity>,
+            Save: Save::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
 impl windows_core::RuntimeName for IPersistPropertyBag {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPersistPropertyBag2, IPersistPropertyBag2_Vtbl, 0x22f55881_280b_11d0_a8a9_00a0c90c2004);
@@ -11323,39 +14691,78 @@ impl IPersistPropertyBag2_Vtbl {
     pub const fn new<Identity: IPersistPropertyBag2_Impl, const OFFSET: isize>() -> Self {
         unsafe extern "system" fn InitNew<Identity: IPersistPropertyBag2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IPersistPropertyBag2_Impl::InitNew(this).into()
+            }
+        }
+        unsafe extern "system" fn Load<Identity: IPersistPropertyBag2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppropbag: *mut core::ffi::c_void, perrlog: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IPersistPropertyBag2_Impl::Load(this, core::mem::transmute_copy(&ppropbag), core::mem::transmute_copy(&perrlog)).into()
+            }
+        }
+        unsafe extern "system" fn Save<Identity: IPersistPropertyBag2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppropbag: *mut core::ffi::c_void, fcleardirty: windows_core::BOOL, fsaveallproperties: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IPersistPropertyBag2_Impl::Save(this, core::mem::transmute_copy(&ppropbag), core::mem::transmute_copy(&fcleardirty), core::mem::transmute_copy(&fsaveallproperties)).into()
+            }
+        }
+        unsafe extern "system" fn IsDirty<Identity: IPersistPropertyBag2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IPersistPropertyBag2_Impl::IsDirty(this)
+            }
+        }
+        Self {
+            base__: super::Com::IPersist_Vtbl::new::<Identity, OFFSET>(),
+            InitNew: InitNew::<Identity, OFFSET>,
+            Load: Load::<Identity, OFFSET>,
+            Save: Save::<Identity, OFFSET>,
+            IsDirty: IsDirty::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPersistPropertyBag2 as windows_core::Interface>::IID || iid == &<super::Com::IPersist as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl<Identity: windows_core::IUnknownImpl + IPersistPropertyBag2_Impl> windows_core::imp::TearOffVtable<Identity> for IPersistPropertyBag2_Vtbl
+where
+    super::Com::IPersist_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InitNew<Identity: windows_core::IUnknownImpl + IPersistPropertyBag2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IPersistPropertyBag2_Impl::InitNew(this).into()
             }
         }
-        unsafe extern "system" fn Load<Identity: IPersistPropertyBag2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppropbag: *mut core::ffi::c_void, perrlog: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Load<Identity: windows_core::IUnknownImpl + IPersistPropertyBag2_Impl>(this: *mut core::ffi::c_void, ppropbag: *mut core::ffi::c_void, perrlog: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IPersistPropertyBag2_Impl::Load(this, core::mem::transmute_copy(&ppropbag), core::mem::transmute_copy(&perrlog)).into()
             }
         }
-        unsafe extern "system" fn Save<Identity: IPersistPropertyBag2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppropbag: *mut core::ffi::c_void, fcleardirty: windows_core::BOOL, fsaveallproperties: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn Save<Identity: windows_core::IUnknownImpl + IPersistPropertyBag2_Impl>(this: *mut core::ffi::c_void, ppropbag: *mut core::ffi::c_void, fcleardirty: windows_core::BOOL, fsaveallproperties: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IPersistPropertyBag2_Impl::Save(this, core::mem::transmute_copy(&ppropbag), core::mem::transmute_copy(&fcleardirty), core::mem::transmute_copy(&fsaveallproperties)).into()
             }
         }
-        unsafe extern "system" fn IsDirty<Identity: IPersistPropertyBag2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn IsDirty<Identity: windows_core::IUnknownImpl + IPersistPropertyBag2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IPersistPropertyBag2_Impl::IsDirty(this)
             }
         }
         Self {
-            base__: super::Com::IPersist_Vtbl::new::<Identity, OFFSET>(),
-            InitNew: InitNew::<Identity, OFFSET>,
-            Load: Load::<Identity, OFFSET>,
-            Save: Save::<Identity, OFFSET>,
-            IsDirty: IsDirty::<Identity, OFFSET>,
+            base__: <super::Com::IPersist_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InitNew: InitNew::<Identity>,
+            Load: Load::<Identity>,
+            Save: Save::<Identity>,
+            IsDirty: IsDirty::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IPersistPropertyBag2 as windows_core::Interface>::IID || iid == &<super::Com::IPersist as windows_core::Interface>::IID
-    }
+    };
 }
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
 impl windows_core::RuntimeName for IPersistPropertyBag2 {}
@@ -11651,6 +15058,169 @@ impl IPicture_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl<Identity: windows_core::IUnknownImpl + IPicture_Impl> windows_core::imp::TearOffVtable<Identity> for IPicture_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Handle<Identity: windows_core::IUnknownImpl + IPicture_Impl>(this: *mut core::ffi::c_void, phandle: *mut OLE_HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPicture_Impl::Handle(this) {
+                    Ok(ok__) => {
+                        phandle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn hPal<Identity: windows_core::IUnknownImpl + IPicture_Impl>(this: *mut core::ffi::c_void, phpal: *mut OLE_HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPicture_Impl::hPal(this) {
+                    Ok(ok__) => {
+                        phpal.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Type<Identity: windows_core::IUnknownImpl + IPicture_Impl>(this: *mut core::ffi::c_void, ptype: *mut PICTYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPicture_Impl::Type(this) {
+                    Ok(ok__) => {
+                        ptype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Width<Identity: windows_core::IUnknownImpl + IPicture_Impl>(this: *mut core::ffi::c_void, pwidth: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPicture_Impl::Width(this) {
+                    Ok(ok__) => {
+                        pwidth.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Height<Identity: windows_core::IUnknownImpl + IPicture_Impl>(this: *mut core::ffi::c_void, pheight: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPicture_Impl::Height(this) {
+                    Ok(ok__) => {
+                        pheight.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Render<Identity: windows_core::IUnknownImpl + IPicture_Impl>(this: *mut core::ffi::c_void, hdc: super::super::Graphics::Gdi::HDC, x: i32, y: i32, cx: i32, cy: i32, xsrc: i32, ysrc: i32, cxsrc: i32, cysrc: i32, prcwbounds: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPicture_Impl::Render(this, core::mem::transmute_copy(&hdc), core::mem::transmute_copy(&x), core::mem::transmute_copy(&y), core::mem::transmute_copy(&cx), core::mem::transmute_copy(&cy), core::mem::transmute_copy(&xsrc), core::mem::transmute_copy(&ysrc), core::mem::transmute_copy(&cxsrc), core::mem::transmute_copy(&cysrc), core::mem::transmute_copy(&prcwbounds)).into()
+            }
+        }
+        unsafe extern "system" fn set_hPal<Identity: windows_core::IUnknownImpl + IPicture_Impl>(this: *mut core::ffi::c_void, hpal: OLE_HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPicture_Impl::set_hPal(this, core::mem::transmute_copy(&hpal)).into()
+            }
+        }
+        unsafe extern "system" fn CurDC<Identity: windows_core::IUnknownImpl + IPicture_Impl>(this: *mut core::ffi::c_void, phdc: *mut super::super::Graphics::Gdi::HDC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPicture_Impl::CurDC(this) {
+                    Ok(ok__) => {
+                        phdc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SelectPicture<Identity: windows_core::IUnknownImpl + IPicture_Impl>(this: *mut core::ffi::c_void, hdcin: super::super::Graphics::Gdi::HDC, phdcout: *mut super::super::Graphics::Gdi::HDC, phbmpout: *mut OLE_HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPicture_Impl::SelectPicture(this, core::mem::transmute_copy(&hdcin), core::mem::transmute_copy(&phdcout), core::mem::transmute_copy(&phbmpout)).into()
+            }
+        }
+        unsafe extern "system" fn KeepOriginalFormat<Identity: windows_core::IUnknownImpl + IPicture_Impl>(this: *mut core::ffi::c_void, pkeep: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPicture_Impl::KeepOriginalFormat(this) {
+                    Ok(ok__) => {
+                        pkeep.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetKeepOriginalFormat<Identity: windows_core::IUnknownImpl + IPicture_Impl>(this: *mut core::ffi::c_void, keep: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPicture_Impl::SetKeepOriginalFormat(this, core::mem::transmute_copy(&keep)).into()
+            }
+        }
+        unsafe extern "system" fn PictureChanged<Identity: windows_core::IUnknownImpl + IPicture_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPicture_Impl::PictureChanged(this).into()
+            }
+        }
+        unsafe extern "system" fn SaveAsFile<Identity: windows_core::IUnknownImpl + IPicture_Impl>(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, fsavememcopy: windows_core::BOOL, pcbsize: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPicture_Impl::SaveAsFile(this, core::mem::transmute_copy(&pstream), core::mem::transmute_copy(&fsavememcopy)) {
+                    Ok(ok__) => {
+                        pcbsize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Attributes<Identity: windows_core::IUnknownImpl + IPicture_Impl>(this: *mut core::ffi::c_void, pdwattr: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPicture_Impl::Attributes(this) {
+                    Ok(ok__) => {
+                        pdwattr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Handle: Handle::<Identity>,
+            hPal: hPal::<Identity>,
+            Type: Type::<Identity>,
+            Width: Width::<Identity>,
+            Height: Height::<Identity>,
+            Render: Render::<Identity>,
+            set_hPal: set_hPal::<Identity>,
+            CurDC: CurDC::<Identity>,
+            SelectPicture: SelectPicture::<Identity>,
+            KeepOriginalFormat: KeepOriginalFormat::<Identity>,
+            SetKeepOriginalFormat: SetKeepOriginalFormat::<Identity>,
+            PictureChanged: PictureChanged::<Identity>,
+            SaveAsFile: SaveAsFile::<Identity>,
+            Attributes: Attributes::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
 impl windows_core::RuntimeName for IPicture {}
 windows_core::imp::define_interface!(IPicture2, IPicture2_Vtbl, 0xf5185dd8_2012_4b0b_aad9_f052c6bd482b);
 windows_core::imp::interface_hierarchy!(IPicture2, windows_core::IUnknown);
@@ -11781,11 +15351,177 @@ pub trait IPicture2_Impl: windows_core::IUnknownImpl {
     fn Attributes(&self) -> windows_core::Result<u32>;
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
-impl IPicture2_Vtbl {
-    pub const fn new<Identity: IPicture2_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn Handle<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, phandle: *mut usize) -> windows_core::HRESULT {
+impl IPicture2_Vtbl {
+    pub const fn new<Identity: IPicture2_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn Handle<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, phandle: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IPicture2_Impl::Handle(this) {
+                    Ok(ok__) => {
+                        phandle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn hPal<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, phpal: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IPicture2_Impl::hPal(this) {
+                    Ok(ok__) => {
+                        phpal.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Type<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ptype: *mut i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IPicture2_Impl::Type(this) {
+                    Ok(ok__) => {
+                        ptype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Width<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pwidth: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IPicture2_Impl::Width(this) {
+                    Ok(ok__) => {
+                        pwidth.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Height<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pheight: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IPicture2_Impl::Height(this) {
+                    Ok(ok__) => {
+                        pheight.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Render<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hdc: super::super::Graphics::Gdi::HDC, x: i32, y: i32, cx: i32, cy: i32, xsrc: i32, ysrc: i32, cxsrc: i32, cysrc: i32, prcwbounds: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IPicture2_Impl::Render(this, core::mem::transmute_copy(&hdc), core::mem::transmute_copy(&x), core::mem::transmute_copy(&y), core::mem::transmute_copy(&cx), core::mem::transmute_copy(&cy), core::mem::transmute_copy(&xsrc), core::mem::transmute_copy(&ysrc), core::mem::transmute_copy(&cxsrc), core::mem::transmute_copy(&cysrc), core::mem::transmute_copy(&prcwbounds)).into()
+            }
+        }
+        unsafe extern "system" fn set_hPal<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hpal: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IPicture2_Impl::set_hPal(this, core::mem::transmute_copy(&hpal)).into()
+            }
+        }
+        unsafe extern "system" fn CurDC<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, phdc: *mut super::super::Graphics::Gdi::HDC) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IPicture2_Impl::CurDC(this) {
+                    Ok(ok__) => {
+                        phdc.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SelectPicture<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hdcin: super::super::Graphics::Gdi::HDC, phdcout: *mut super::super::Graphics::Gdi::HDC, phbmpout: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IPicture2_Impl::SelectPicture(this, core::mem::transmute_copy(&hdcin), core::mem::transmute_copy(&phdcout), core::mem::transmute_copy(&phbmpout)).into()
+            }
+        }
+        unsafe extern "system" fn KeepOriginalFormat<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pkeep: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IPicture2_Impl::KeepOriginalFormat(this) {
+                    Ok(ok__) => {
+                        pkeep.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetKeepOriginalFormat<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, keep: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IPicture2_Impl::SetKeepOriginalFormat(this, core::mem::transmute_copy(&keep)).into()
+            }
+        }
+        unsafe extern "system" fn PictureChanged<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IPicture2_Impl::PictureChanged(this).into()
+            }
+        }
+        unsafe extern "system" fn SaveAsFile<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, fsavememcopy: windows_core::BOOL, pcbsize: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IPicture2_Impl::SaveAsFile(this, core::mem::transmute_copy(&pstream), core::mem::transmute_copy(&fsavememcopy)) {
+                    Ok(ok__) => {
+                        pcbsize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Attributes<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwattr: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IPicture2_Impl::Attributes(this) {
+                    Ok(ok__) => {
+                        pdwattr.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            Handle: Handle::<Identity, OFFSET>,
+            hPal: hPal::<Identity, OFFSET>,
+            Type: Type::<Identity, OFFSET>,
+            Width: Width::<Identity, OFFSET>,
+            Height: Height::<Identity, OFFSET>,
+            Render: Render::<Identity, OFFSET>,
+            set_hPal: set_hPal::<Identity, OFFSET>,
+            CurDC: CurDC::<Identity, OFFSET>,
+            SelectPicture: SelectPicture::<Identity, OFFSET>,
+            KeepOriginalFormat: KeepOriginalFormat::<Identity, OFFSET>,
+            SetKeepOriginalFormat: SetKeepOriginalFormat::<Identity, OFFSET>,
+            PictureChanged: PictureChanged::<Identity, OFFSET>,
+            SaveAsFile: SaveAsFile::<Identity, OFFSET>,
+            Attributes: Attributes::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPicture2 as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl<Identity: windows_core::IUnknownImpl + IPicture2_Impl> windows_core::imp::TearOffVtable<Identity> for IPicture2_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Handle<Identity: windows_core::IUnknownImpl + IPicture2_Impl>(this: *mut core::ffi::c_void, phandle: *mut usize) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IPicture2_Impl::Handle(this) {
                     Ok(ok__) => {
                         phandle.write(core::mem::transmute(ok__));
@@ -11795,9 +15531,9 @@ impl IPicture2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn hPal<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, phpal: *mut usize) -> windows_core::HRESULT {
+        unsafe extern "system" fn hPal<Identity: windows_core::IUnknownImpl + IPicture2_Impl>(this: *mut core::ffi::c_void, phpal: *mut usize) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IPicture2_Impl::hPal(this) {
                     Ok(ok__) => {
                         phpal.write(core::mem::transmute(ok__));
@@ -11807,9 +15543,9 @@ impl IPicture2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Type<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ptype: *mut i16) -> windows_core::HRESULT {
+        unsafe extern "system" fn Type<Identity: windows_core::IUnknownImpl + IPicture2_Impl>(this: *mut core::ffi::c_void, ptype: *mut i16) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IPicture2_Impl::Type(this) {
                     Ok(ok__) => {
                         ptype.write(core::mem::transmute(ok__));
@@ -11819,9 +15555,9 @@ impl IPicture2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Width<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pwidth: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn Width<Identity: windows_core::IUnknownImpl + IPicture2_Impl>(this: *mut core::ffi::c_void, pwidth: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IPicture2_Impl::Width(this) {
                     Ok(ok__) => {
                         pwidth.write(core::mem::transmute(ok__));
@@ -11831,9 +15567,9 @@ impl IPicture2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Height<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pheight: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn Height<Identity: windows_core::IUnknownImpl + IPicture2_Impl>(this: *mut core::ffi::c_void, pheight: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IPicture2_Impl::Height(this) {
                     Ok(ok__) => {
                         pheight.write(core::mem::transmute(ok__));
@@ -11843,21 +15579,21 @@ impl IPicture2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Render<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hdc: super::super::Graphics::Gdi::HDC, x: i32, y: i32, cx: i32, cy: i32, xsrc: i32, ysrc: i32, cxsrc: i32, cysrc: i32, prcwbounds: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+        unsafe extern "system" fn Render<Identity: windows_core::IUnknownImpl + IPicture2_Impl>(this: *mut core::ffi::c_void, hdc: super::super::Graphics::Gdi::HDC, x: i32, y: i32, cx: i32, cy: i32, xsrc: i32, ysrc: i32, cxsrc: i32, cysrc: i32, prcwbounds: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IPicture2_Impl::Render(this, core::mem::transmute_copy(&hdc), core::mem::transmute_copy(&x), core::mem::transmute_copy(&y), core::mem::transmute_copy(&cx), core::mem::transmute_copy(&cy), core::mem::transmute_copy(&xsrc), core::mem::transmute_copy(&ysrc), core::mem::transmute_copy(&cxsrc), core::mem::transmute_copy(&cysrc), core::mem::transmute_copy(&prcwbounds)).into()
             }
         }
-        unsafe extern "system" fn set_hPal<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hpal: usize) -> windows_core::HRESULT {
+        unsafe extern "system" fn set_hPal<Identity: windows_core::IUnknownImpl + IPicture2_Impl>(this: *mut core::ffi::c_void, hpal: usize) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IPicture2_Impl::set_hPal(this, core::mem::transmute_copy(&hpal)).into()
             }
         }
-        unsafe extern "system" fn CurDC<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, phdc: *mut super::super::Graphics::Gdi::HDC) -> windows_core::HRESULT {
+        unsafe extern "system" fn CurDC<Identity: windows_core::IUnknownImpl + IPicture2_Impl>(this: *mut core::ffi::c_void, phdc: *mut super::super::Graphics::Gdi::HDC) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IPicture2_Impl::CurDC(this) {
                     Ok(ok__) => {
                         phdc.write(core::mem::transmute(ok__));
@@ -11867,15 +15603,15 @@ impl IPicture2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SelectPicture<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hdcin: super::super::Graphics::Gdi::HDC, phdcout: *mut super::super::Graphics::Gdi::HDC, phbmpout: *mut usize) -> windows_core::HRESULT {
+        unsafe extern "system" fn SelectPicture<Identity: windows_core::IUnknownImpl + IPicture2_Impl>(this: *mut core::ffi::c_void, hdcin: super::super::Graphics::Gdi::HDC, phdcout: *mut super::super::Graphics::Gdi::HDC, phbmpout: *mut usize) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IPicture2_Impl::SelectPicture(this, core::mem::transmute_copy(&hdcin), core::mem::transmute_copy(&phdcout), core::mem::transmute_copy(&phbmpout)).into()
             }
         }
-        unsafe extern "system" fn KeepOriginalFormat<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pkeep: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn KeepOriginalFormat<Identity: windows_core::IUnknownImpl + IPicture2_Impl>(this: *mut core::ffi::c_void, pkeep: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IPicture2_Impl::KeepOriginalFormat(this) {
                     Ok(ok__) => {
                         pkeep.write(core::mem::transmute(ok__));
@@ -11885,21 +15621,21 @@ impl IPicture2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetKeepOriginalFormat<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, keep: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetKeepOriginalFormat<Identity: windows_core::IUnknownImpl + IPicture2_Impl>(this: *mut core::ffi::c_void, keep: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IPicture2_Impl::SetKeepOriginalFormat(this, core::mem::transmute_copy(&keep)).into()
             }
         }
-        unsafe extern "system" fn PictureChanged<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn PictureChanged<Identity: windows_core::IUnknownImpl + IPicture2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IPicture2_Impl::PictureChanged(this).into()
             }
         }
-        unsafe extern "system" fn SaveAsFile<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, fsavememcopy: windows_core::BOOL, pcbsize: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SaveAsFile<Identity: windows_core::IUnknownImpl + IPicture2_Impl>(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, fsavememcopy: windows_core::BOOL, pcbsize: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IPicture2_Impl::SaveAsFile(this, core::mem::transmute_copy(&pstream), core::mem::transmute_copy(&fsavememcopy)) {
                     Ok(ok__) => {
                         pcbsize.write(core::mem::transmute(ok__));
@@ -11909,9 +15645,9 @@ impl IPicture2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Attributes<Identity: IPicture2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwattr: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn Attributes<Identity: windows_core::IUnknownImpl + IPicture2_Impl>(this: *mut core::ffi::c_void, pdwattr: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IPicture2_Impl::Attributes(this) {
                     Ok(ok__) => {
                         pdwattr.write(core::mem::transmute(ok__));
@@ -11922,26 +15658,23 @@ impl IPicture2_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            Handle: Handle::<Identity, OFFSET>,
-            hPal: hPal::<Identity, OFFSET>,
-            Type: Type::<Identity, OFFSET>,
-            Width: Width::<Identity, OFFSET>,
-            Height: Height::<Identity, OFFSET>,
-            Render: Render::<Identity, OFFSET>,
-            set_hPal: set_hPal::<Identity, OFFSET>,
-            CurDC: CurDC::<Identity, OFFSET>,
-            SelectPicture: SelectPicture::<Identity, OFFSET>,
-            KeepOriginalFormat: KeepOriginalFormat::<Identity, OFFSET>,
-            SetKeepOriginalFormat: SetKeepOriginalFormat::<Identity, OFFSET>,
-            PictureChanged: PictureChanged::<Identity, OFFSET>,
-            SaveAsFile: SaveAsFile::<Identity, OFFSET>,
-            Attributes: Attributes::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IPicture2 as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Handle: Handle::<Identity>,
+            hPal: hPal::<Identity>,
+            Type: Type::<Identity>,
+            Width: Width::<Identity>,
+            Height: Height::<Identity>,
+            Render: Render::<Identity>,
+            set_hPal: set_hPal::<Identity>,
+            CurDC: CurDC::<Identity>,
+            SelectPicture: SelectPicture::<Identity>,
+            KeepOriginalFormat: KeepOriginalFormat::<Identity>,
+            SetKeepOriginalFormat: SetKeepOriginalFormat::<Identity>,
+            PictureChanged: PictureChanged::<Identity>,
+            SaveAsFile: SaveAsFile::<Identity>,
+            Attributes: Attributes::<Identity>,
+        }
+    };
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
 impl windows_core::RuntimeName for IPicture2 {}
@@ -11974,6 +15707,13 @@ impl IPictureDisp_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPictureDisp_Impl> windows_core::imp::TearOffVtable<Identity> for IPictureDisp_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPictureDisp {}
 windows_core::imp::define_interface!(IPointerInactive, IPointerInactive_Vtbl, 0x55980ba0_35aa_11cf_b671_00aa004cd6d8);
 windows_core::imp::interface_hierarchy!(IPointerInactive, windows_core::IUnknown);
@@ -12041,6 +15781,43 @@ impl IPointerInactive_Vtbl {
         iid == &<IPointerInactive as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPointerInactive_Impl> windows_core::imp::TearOffVtable<Identity> for IPointerInactive_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetActivationPolicy<Identity: windows_core::IUnknownImpl + IPointerInactive_Impl>(this: *mut core::ffi::c_void, pdwpolicy: *mut POINTERINACTIVE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPointerInactive_Impl::GetActivationPolicy(this) {
+                    Ok(ok__) => {
+                        pdwpolicy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn OnInactiveMouseMove<Identity: windows_core::IUnknownImpl + IPointerInactive_Impl>(this: *mut core::ffi::c_void, prectbounds: *const super::super::Foundation::RECT, x: i32, y: i32, grfkeystate: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPointerInactive_Impl::OnInactiveMouseMove(this, core::mem::transmute_copy(&prectbounds), core::mem::transmute_copy(&x), core::mem::transmute_copy(&y), core::mem::transmute_copy(&grfkeystate)).into()
+            }
+        }
+        unsafe extern "system" fn OnInactiveSetCursor<Identity: windows_core::IUnknownImpl + IPointerInactive_Impl>(this: *mut core::ffi::c_void, prectbounds: *const super::super::Foundation::RECT, x: i32, y: i32, dwmousemsg: u32, fsetalways: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPointerInactive_Impl::OnInactiveSetCursor(this, core::mem::transmute_copy(&prectbounds), core::mem::transmute_copy(&x), core::mem::transmute_copy(&y), core::mem::transmute_copy(&dwmousemsg), core::mem::transmute_copy(&fsetalways)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetActivationPolicy: GetActivationPolicy::<Identity>,
+            OnInactiveMouseMove: OnInactiveMouseMove::<Identity>,
+            OnInactiveSetCursor: OnInactiveSetCursor::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPointerInactive {}
 windows_core::imp::define_interface!(IPrint, IPrint_Vtbl, 0xb722bcc9_4e68_101b_a2bc_00aa00404770);
 windows_core::imp::interface_hierarchy!(IPrint, windows_core::IUnknown);
@@ -12109,6 +15886,38 @@ impl IPrint_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+impl<Identity: windows_core::IUnknownImpl + IPrint_Impl> windows_core::imp::TearOffVtable<Identity> for IPrint_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetInitialPageNum<Identity: windows_core::IUnknownImpl + IPrint_Impl>(this: *mut core::ffi::c_void, nfirstpage: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrint_Impl::SetInitialPageNum(this, core::mem::transmute_copy(&nfirstpage)).into()
+            }
+        }
+        unsafe extern "system" fn GetPageInfo<Identity: windows_core::IUnknownImpl + IPrint_Impl>(this: *mut core::ffi::c_void, pnfirstpage: *mut i32, pcpages: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrint_Impl::GetPageInfo(this, core::mem::transmute_copy(&pnfirstpage), core::mem::transmute_copy(&pcpages)).into()
+            }
+        }
+        unsafe extern "system" fn Print<Identity: windows_core::IUnknownImpl + IPrint_Impl>(this: *mut core::ffi::c_void, grfflags: u32, pptd: *mut *mut super::Com::DVTARGETDEVICE, pppageset: *mut *mut PAGESET, pstgmoptions: *mut super::Com::STGMEDIUM, pcallback: *mut core::ffi::c_void, nfirstpage: i32, pcpagesprinted: *mut i32, pnlastpage: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrint_Impl::Print(this, core::mem::transmute_copy(&grfflags), core::mem::transmute_copy(&pptd), core::mem::transmute_copy(&pppageset), core::mem::transmute_copy(&pstgmoptions), core::mem::transmute_copy(&pcallback), core::mem::transmute_copy(&nfirstpage), core::mem::transmute_copy(&pcpagesprinted), core::mem::transmute_copy(&pnlastpage)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetInitialPageNum: SetInitialPageNum::<Identity>,
+            GetPageInfo: GetPageInfo::<Identity>,
+            Print: Print::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
 impl windows_core::RuntimeName for IPrint {}
 windows_core::imp::define_interface!(IPropertyNotifySink, IPropertyNotifySink_Vtbl, 0x9bfbbc02_eff1_101a_84ed_00aa00341d07);
 windows_core::imp::interface_hierarchy!(IPropertyNotifySink, windows_core::IUnknown);
@@ -12155,6 +15964,30 @@ impl IPropertyNotifySink_Vtbl {
         iid == &<IPropertyNotifySink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IPropertyNotifySink_Impl> windows_core::imp::TearOffVtable<Identity> for IPropertyNotifySink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnChanged<Identity: windows_core::IUnknownImpl + IPropertyNotifySink_Impl>(this: *mut core::ffi::c_void, dispid: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyNotifySink_Impl::OnChanged(this, core::mem::transmute_copy(&dispid)).into()
+            }
+        }
+        unsafe extern "system" fn OnRequestEdit<Identity: windows_core::IUnknownImpl + IPropertyNotifySink_Impl>(this: *mut core::ffi::c_void, dispid: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyNotifySink_Impl::OnRequestEdit(this, core::mem::transmute_copy(&dispid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnChanged: OnChanged::<Identity>,
+            OnRequestEdit: OnRequestEdit::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IPropertyNotifySink {}
 windows_core::imp::define_interface!(IPropertyPage, IPropertyPage_Vtbl, 0xb196b28d_bab4_101a_b69c_00aa00341d07);
 windows_core::imp::interface_hierarchy!(IPropertyPage, windows_core::IUnknown);
@@ -12322,6 +16155,94 @@ impl IPropertyPage_Vtbl {
     }
 }
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl<Identity: windows_core::IUnknownImpl + IPropertyPage_Impl> windows_core::imp::TearOffVtable<Identity> for IPropertyPage_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetPageSite<Identity: windows_core::IUnknownImpl + IPropertyPage_Impl>(this: *mut core::ffi::c_void, ppagesite: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyPage_Impl::SetPageSite(this, core::mem::transmute_copy(&ppagesite)).into()
+            }
+        }
+        unsafe extern "system" fn Activate<Identity: windows_core::IUnknownImpl + IPropertyPage_Impl>(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, prect: *const super::super::Foundation::RECT, bmodal: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyPage_Impl::Activate(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&prect), core::mem::transmute_copy(&bmodal)).into()
+            }
+        }
+        unsafe extern "system" fn Deactivate<Identity: windows_core::IUnknownImpl + IPropertyPage_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyPage_Impl::Deactivate(this).into()
+            }
+        }
+        unsafe extern "system" fn GetPageInfo<Identity: windows_core::IUnknownImpl + IPropertyPage_Impl>(this: *mut core::ffi::c_void, ppageinfo: *mut PROPPAGEINFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyPage_Impl::GetPageInfo(this, core::mem::transmute_copy(&ppageinfo)).into()
+            }
+        }
+        unsafe extern "system" fn SetObjects<Identity: windows_core::IUnknownImpl + IPropertyPage_Impl>(this: *mut core::ffi::c_void, cobjects: u32, ppunk: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyPage_Impl::SetObjects(this, core::mem::transmute_copy(&cobjects), core::mem::transmute_copy(&ppunk)).into()
+            }
+        }
+        unsafe extern "system" fn Show<Identity: windows_core::IUnknownImpl + IPropertyPage_Impl>(this: *mut core::ffi::c_void, ncmdshow: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyPage_Impl::Show(this, core::mem::transmute_copy(&ncmdshow)).into()
+            }
+        }
+        unsafe extern "system" fn Move<Identity: windows_core::IUnknownImpl + IPropertyPage_Impl>(this: *mut core::ffi::c_void, prect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyPage_Impl::Move(this, core::mem::transmute_copy(&prect)).into()
+            }
+        }
+        unsafe extern "system" fn IsPageDirty<Identity: windows_core::IUnknownImpl + IPropertyPage_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyPage_Impl::IsPageDirty(this).into()
+            }
+        }
+        unsafe extern "system" fn Apply<Identity: windows_core::IUnknownImpl + IPropertyPage_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyPage_Impl::Apply(this).into()
+            }
+        }
+        unsafe extern "system" fn Help<Identity: windows_core::IUnknownImpl + IPropertyPage_Impl>(this: *mut core::ffi::c_void, pszhelpdir: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyPage_Impl::Help(this, core::mem::transmute(&pszhelpdir)).into()
+            }
+        }
+        unsafe extern "system" fn TranslateAccelerator<Identity: windows_core::IUnknownImpl + IPropertyPage_Impl>(this: *mut core::ffi::c_void, pmsg: *const super::super::UI::WindowsAndMessaging::MSG) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyPage_Impl::TranslateAccelerator(this, core::mem::transmute_copy(&pmsg)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetPageSite: SetPageSite::<Identity>,
+            Activate: Activate::<Identity>,
+            Deactivate: Deactivate::<Identity>,
+            GetPageInfo: GetPageInfo::<Identity>,
+            SetObjects: SetObjects::<Identity>,
+            Show: Show::<Identity>,
+            Move: Move::<Identity>,
+            IsPageDirty: IsPageDirty::<Identity>,
+            Apply: Apply::<Identity>,
+            Help: Help::<Identity>,
+            TranslateAccelerator: TranslateAccelerator::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
 impl windows_core::RuntimeName for IPropertyPage {}
 windows_core::imp::define_interface!(IPropertyPage2, IPropertyPage2_Vtbl, 0x01e44665_24ac_101b_84ed_08002b2ec713);
 impl core::ops::Deref for IPropertyPage2 {
@@ -12362,6 +16283,21 @@ impl IPropertyPage2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl<Identity: windows_core::IUnknownImpl + IPropertyPage2_Impl> windows_core::imp::TearOffVtable<Identity> for IPropertyPage2_Vtbl
+where
+    IPropertyPage_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EditProperty<Identity: windows_core::IUnknownImpl + IPropertyPage2_Impl>(this: *mut core::ffi::c_void, dispid: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyPage2_Impl::EditProperty(this, core::mem::transmute_copy(&dispid)).into()
+            }
+        }
+        Self { base__: <IPropertyPage_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, EditProperty: EditProperty::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
 impl windows_core::RuntimeName for IPropertyPage2 {}
 windows_core::imp::define_interface!(IPropertyPageSite, IPropertyPageSite_Vtbl, 0xb196b28c_bab4_101a_b69c_00aa00341d07);
 windows_core::imp::interface_hierarchy!(IPropertyPageSite, windows_core::IUnknown);
@@ -12457,6 +16393,57 @@ impl IPropertyPageSite_Vtbl {
     }
 }
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl<Identity: windows_core::IUnknownImpl + IPropertyPageSite_Impl> windows_core::imp::TearOffVtable<Identity> for IPropertyPageSite_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnStatusChange<Identity: windows_core::IUnknownImpl + IPropertyPageSite_Impl>(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyPageSite_Impl::OnStatusChange(this, core::mem::transmute(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn GetLocaleID<Identity: windows_core::IUnknownImpl + IPropertyPageSite_Impl>(this: *mut core::ffi::c_void, plocaleid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPropertyPageSite_Impl::GetLocaleID(this) {
+                    Ok(ok__) => {
+                        plocaleid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPageContainer<Identity: windows_core::IUnknownImpl + IPropertyPageSite_Impl>(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IPropertyPageSite_Impl::GetPageContainer(this) {
+                    Ok(ok__) => {
+                        ppunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn TranslateAccelerator<Identity: windows_core::IUnknownImpl + IPropertyPageSite_Impl>(this: *mut core::ffi::c_void, pmsg: *const super::super::UI::WindowsAndMessaging::MSG) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPropertyPageSite_Impl::TranslateAccelerator(this, core::mem::transmute_copy(&pmsg)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnStatusChange: OnStatusChange::<Identity>,
+            GetLocaleID: GetLocaleID::<Identity>,
+            GetPageContainer: GetPageContainer::<Identity>,
+            TranslateAccelerator: TranslateAccelerator::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
 impl windows_core::RuntimeName for IPropertyPageSite {}
 windows_core::imp::define_interface!(IProtectFocus, IProtectFocus_Vtbl, 0xd81f90a3_8156_44f7_ad28_5abb87003274);
 windows_core::imp::interface_hierarchy!(IProtectFocus, windows_core::IUnknown);
@@ -12481,7 +16468,30 @@ impl IProtectFocus_Vtbl {
     pub const fn new<Identity: IProtectFocus_Impl, const OFFSET: isize>() -> Self {
         unsafe extern "system" fn AllowFocusChange<Identity: IProtectFocus_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfallow: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IProtectFocus_Impl::AllowFocusChange(this) {
+                    Ok(ok__) => {
+                        pfallow.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(), AllowFocusChange: AllowFocusChange::<Identity, OFFSET> }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtectFocus as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IProtectFocus_Impl> windows_core::imp::TearOffVtable<Identity> for IProtectFocus_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AllowFocusChange<Identity: windows_core::IUnknownImpl + IProtectFocus_Impl>(this: *mut core::ffi::c_void, pfallow: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IProtectFocus_Impl::AllowFocusChange(this) {
                     Ok(ok__) => {
                         pfallow.write(core::mem::transmute(ok__));
@@ -12491,11 +16501,8 @@ impl IProtectFocus_Vtbl {
                 }
             }
         }
-        Self { base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(), AllowFocusChange: AllowFocusChange::<Identity, OFFSET> }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IProtectFocus as windows_core::Interface>::IID
-    }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, AllowFocusChange: AllowFocusChange::<Identity> }
+    };
 }
 impl windows_core::RuntimeName for IProtectFocus {}
 windows_core::imp::define_interface!(IProtectedModeMenuServices, IProtectedModeMenuServices_Vtbl, 0x73c105ee_9dff_4a07_b83c_7eff290c266e);
@@ -12604,6 +16611,56 @@ impl IProtectedModeMenuServices_Vtbl {
     }
 }
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl<Identity: windows_core::IUnknownImpl + IProtectedModeMenuServices_Impl> windows_core::imp::TearOffVtable<Identity> for IProtectedModeMenuServices_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateMenu<Identity: windows_core::IUnknownImpl + IProtectedModeMenuServices_Impl>(this: *mut core::ffi::c_void, phmenu: *mut super::super::UI::WindowsAndMessaging::HMENU) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProtectedModeMenuServices_Impl::CreateMenu(this) {
+                    Ok(ok__) => {
+                        phmenu.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LoadMenu<Identity: windows_core::IUnknownImpl + IProtectedModeMenuServices_Impl>(this: *mut core::ffi::c_void, pszmodulename: windows_core::PCWSTR, pszmenuname: windows_core::PCWSTR, phmenu: *mut super::super::UI::WindowsAndMessaging::HMENU) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProtectedModeMenuServices_Impl::LoadMenu(this, core::mem::transmute(&pszmodulename), core::mem::transmute(&pszmenuname)) {
+                    Ok(ok__) => {
+                        phmenu.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LoadMenuID<Identity: windows_core::IUnknownImpl + IProtectedModeMenuServices_Impl>(this: *mut core::ffi::c_void, pszmodulename: windows_core::PCWSTR, wresourceid: u16, phmenu: *mut super::super::UI::WindowsAndMessaging::HMENU) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProtectedModeMenuServices_Impl::LoadMenuID(this, core::mem::transmute(&pszmodulename), core::mem::transmute_copy(&wresourceid)) {
+                    Ok(ok__) => {
+                        phmenu.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateMenu: CreateMenu::<Identity>,
+            LoadMenu: LoadMenu::<Identity>,
+            LoadMenuID: LoadMenuID::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
 impl windows_core::RuntimeName for IProtectedModeMenuServices {}
 windows_core::imp::define_interface!(IProvideClassInfo, IProvideClassInfo_Vtbl, 0xb196b283_bab4_101a_b69c_00aa00341d07);
 windows_core::imp::interface_hierarchy!(IProvideClassInfo, windows_core::IUnknown);
@@ -12651,6 +16708,27 @@ impl IProvideClassInfo_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IProvideClassInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IProvideClassInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetClassInfo<Identity: windows_core::IUnknownImpl + IProvideClassInfo_Impl>(this: *mut core::ffi::c_void, ppti: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProvideClassInfo_Impl::GetClassInfo(this) {
+                    Ok(ok__) => {
+                        ppti.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetClassInfo: GetClassInfo::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IProvideClassInfo {}
 windows_core::imp::define_interface!(IProvideClassInfo2, IProvideClassInfo2_Vtbl, 0xa6bc3ac0_dbaa_11ce_9de3_00aa004bb851);
 impl core::ops::Deref for IProvideClassInfo2 {
@@ -12700,6 +16778,27 @@ impl IProvideClassInfo2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IProvideClassInfo2_Impl> windows_core::imp::TearOffVtable<Identity> for IProvideClassInfo2_Vtbl
+where
+    IProvideClassInfo_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetGUID<Identity: windows_core::IUnknownImpl + IProvideClassInfo2_Impl>(this: *mut core::ffi::c_void, dwguidkind: u32, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProvideClassInfo2_Impl::GetGUID(this, core::mem::transmute_copy(&dwguidkind)) {
+                    Ok(ok__) => {
+                        pguid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IProvideClassInfo_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetGUID: GetGUID::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IProvideClassInfo2 {}
 windows_core::imp::define_interface!(IProvideMultipleClassInfo, IProvideMultipleClassInfo_Vtbl, 0xa7aba9c1_8983_11cf_8f20_00805f2cd064);
 impl core::ops::Deref for IProvideMultipleClassInfo {
@@ -12768,6 +16867,37 @@ impl IProvideMultipleClassInfo_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IProvideMultipleClassInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IProvideMultipleClassInfo_Vtbl
+where
+    IProvideClassInfo2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMultiTypeInfoCount<Identity: windows_core::IUnknownImpl + IProvideMultipleClassInfo_Impl>(this: *mut core::ffi::c_void, pcti: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProvideMultipleClassInfo_Impl::GetMultiTypeInfoCount(this) {
+                    Ok(ok__) => {
+                        pcti.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetInfoOfIndex<Identity: windows_core::IUnknownImpl + IProvideMultipleClassInfo_Impl>(this: *mut core::ffi::c_void, iti: u32, dwflags: MULTICLASSINFO_FLAGS, ppticoclass: *mut *mut core::ffi::c_void, pdwtiflags: *mut u32, pcdispidreserved: *mut u32, piidprimary: *mut windows_core::GUID, piidsource: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IProvideMultipleClassInfo_Impl::GetInfoOfIndex(this, core::mem::transmute_copy(&iti), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&ppticoclass), core::mem::transmute_copy(&pdwtiflags), core::mem::transmute_copy(&pcdispidreserved), core::mem::transmute_copy(&piidprimary), core::mem::transmute_copy(&piidsource)).into()
+            }
+        }
+        Self {
+            base__: <IProvideClassInfo2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetMultiTypeInfoCount: GetMultiTypeInfoCount::<Identity>,
+            GetInfoOfIndex: GetInfoOfIndex::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IProvideMultipleClassInfo {}
 windows_core::imp::define_interface!(IProvideRuntimeContext, IProvideRuntimeContext_Vtbl, 0x10e2414a_ec59_49d2_bc51_5add2c36febc);
 windows_core::imp::interface_hierarchy!(IProvideRuntimeContext, windows_core::IUnknown);
@@ -12799,6 +16929,23 @@ impl IProvideRuntimeContext_Vtbl {
         iid == &<IProvideRuntimeContext as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IProvideRuntimeContext_Impl> windows_core::imp::TearOffVtable<Identity> for IProvideRuntimeContext_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrentSourceContext<Identity: windows_core::IUnknownImpl + IProvideRuntimeContext_Impl>(this: *mut core::ffi::c_void, pdwcontext: *mut usize, pfexecutingglobalcode: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IProvideRuntimeContext_Impl::GetCurrentSourceContext(this, core::mem::transmute_copy(&pdwcontext), core::mem::transmute_copy(&pfexecutingglobalcode)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrentSourceContext: GetCurrentSourceContext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IProvideRuntimeContext {}
 windows_core::imp::define_interface!(IQuickActivate, IQuickActivate_Vtbl, 0xcf51ed10_62fe_11cf_bf86_00a0c9034836);
 windows_core::imp::interface_hierarchy!(IQuickActivate, windows_core::IUnknown);
@@ -12873,6 +17020,44 @@ impl IQuickActivate_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl<Identity: windows_core::IUnknownImpl + IQuickActivate_Impl> windows_core::imp::TearOffVtable<Identity> for IQuickActivate_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QuickActivate<Identity: windows_core::IUnknownImpl + IQuickActivate_Impl>(this: *mut core::ffi::c_void, pqacontainer: *const QACONTAINER, pqacontrol: *mut QACONTROL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IQuickActivate_Impl::QuickActivate(this, core::mem::transmute_copy(&pqacontainer), core::mem::transmute_copy(&pqacontrol)).into()
+            }
+        }
+        unsafe extern "system" fn SetContentExtent<Identity: windows_core::IUnknownImpl + IQuickActivate_Impl>(this: *mut core::ffi::c_void, psizel: *const super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IQuickActivate_Impl::SetContentExtent(this, core::mem::transmute_copy(&psizel)).into()
+            }
+        }
+        unsafe extern "system" fn GetContentExtent<Identity: windows_core::IUnknownImpl + IQuickActivate_Impl>(this: *mut core::ffi::c_void, psizel: *mut super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IQuickActivate_Impl::GetContentExtent(this) {
+                    Ok(ok__) => {
+                        psizel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QuickActivate: QuickActivate::<Identity>,
+            SetContentExtent: SetContentExtent::<Identity>,
+            GetContentExtent: GetContentExtent::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
 impl windows_core::RuntimeName for IQuickActivate {}
 windows_core::imp::define_interface!(IRecordInfo, IRecordInfo_Vtbl, 0x0000002f_0000_0000_c000_000000000046);
 windows_core::imp::interface_hierarchy!(IRecordInfo, windows_core::IUnknown);
@@ -13121,53 +17306,206 @@ impl IRecordInfo_Vtbl {
                 IRecordInfo_Impl::GetFieldNames(this, core::mem::transmute_copy(&pcnames), core::mem::transmute_copy(&rgbstrnames)).into()
             }
         }
-        unsafe extern "system" fn IsMatchingType<Identity: IRecordInfo_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, precordinfo: *mut core::ffi::c_void) -> windows_core::BOOL {
+        unsafe extern "system" fn IsMatchingType<Identity: IRecordInfo_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, precordinfo: *mut core::ffi::c_void) -> windows_core::BOOL {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IRecordInfo_Impl::IsMatchingType(this, core::mem::transmute_copy(&precordinfo))
+            }
+        }
+        unsafe extern "system" fn RecordCreate<Identity: IRecordInfo_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> *mut core::ffi::c_void {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IRecordInfo_Impl::RecordCreate(this)
+            }
+        }
+        unsafe extern "system" fn RecordCreateCopy<Identity: IRecordInfo_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pvsource: *const core::ffi::c_void, ppvdest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IRecordInfo_Impl::RecordCreateCopy(this, core::mem::transmute_copy(&pvsource), core::mem::transmute_copy(&ppvdest)).into()
+            }
+        }
+        unsafe extern "system" fn RecordDestroy<Identity: IRecordInfo_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pvrecord: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IRecordInfo_Impl::RecordDestroy(this, core::mem::transmute_copy(&pvrecord)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            RecordInit: RecordInit::<Identity, OFFSET>,
+            RecordClear: RecordClear::<Identity, OFFSET>,
+            RecordCopy: RecordCopy::<Identity, OFFSET>,
+            GetGuid: GetGuid::<Identity, OFFSET>,
+            GetName: GetName::<Identity, OFFSET>,
+            GetSize: GetSize::<Identity, OFFSET>,
+            GetTypeInfo: GetTypeInfo::<Identity, OFFSET>,
+            GetField: GetField::<Identity, OFFSET>,
+            GetFieldNoCopy: GetFieldNoCopy::<Identity, OFFSET>,
+            PutField: PutField::<Identity, OFFSET>,
+            PutFieldNoCopy: PutFieldNoCopy::<Identity, OFFSET>,
+            GetFieldNames: GetFieldNames::<Identity, OFFSET>,
+            IsMatchingType: IsMatchingType::<Identity, OFFSET>,
+            RecordCreate: RecordCreate::<Identity, OFFSET>,
+            RecordCreateCopy: RecordCreateCopy::<Identity, OFFSET>,
+            RecordDestroy: RecordDestroy::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRecordInfo as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRecordInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IRecordInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RecordInit<Identity: windows_core::IUnknownImpl + IRecordInfo_Impl>(this: *mut core::ffi::c_void, pvnew: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRecordInfo_Impl::RecordInit(this, core::mem::transmute_copy(&pvnew)).into()
+            }
+        }
+        unsafe extern "system" fn RecordClear<Identity: windows_core::IUnknownImpl + IRecordInfo_Impl>(this: *mut core::ffi::c_void, pvexisting: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRecordInfo_Impl::RecordClear(this, core::mem::transmute_copy(&pvexisting)).into()
+            }
+        }
+        unsafe extern "system" fn RecordCopy<Identity: windows_core::IUnknownImpl + IRecordInfo_Impl>(this: *mut core::ffi::c_void, pvexisting: *const core::ffi::c_void, pvnew: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRecordInfo_Impl::RecordCopy(this, core::mem::transmute_copy(&pvexisting), core::mem::transmute_copy(&pvnew)).into()
+            }
+        }
+        unsafe extern "system" fn GetGuid<Identity: windows_core::IUnknownImpl + IRecordInfo_Impl>(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRecordInfo_Impl::GetGuid(this) {
+                    Ok(ok__) => {
+                        pguid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + IRecordInfo_Impl>(this: *mut core::ffi::c_void, pbstrname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRecordInfo_Impl::GetName(this) {
+                    Ok(ok__) => {
+                        pbstrname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSize<Identity: windows_core::IUnknownImpl + IRecordInfo_Impl>(this: *mut core::ffi::c_void, pcbsize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRecordInfo_Impl::GetSize(this) {
+                    Ok(ok__) => {
+                        pcbsize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTypeInfo<Identity: windows_core::IUnknownImpl + IRecordInfo_Impl>(this: *mut core::ffi::c_void, pptypeinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRecordInfo_Impl::GetTypeInfo(this) {
+                    Ok(ok__) => {
+                        pptypeinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetField<Identity: windows_core::IUnknownImpl + IRecordInfo_Impl>(this: *mut core::ffi::c_void, pvdata: *const core::ffi::c_void, szfieldname: windows_core::PCWSTR, pvarfield: *mut super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRecordInfo_Impl::GetField(this, core::mem::transmute_copy(&pvdata), core::mem::transmute(&szfieldname)) {
+                    Ok(ok__) => {
+                        pvarfield.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFieldNoCopy<Identity: windows_core::IUnknownImpl + IRecordInfo_Impl>(this: *mut core::ffi::c_void, pvdata: *const core::ffi::c_void, szfieldname: windows_core::PCWSTR, pvarfield: *mut super::Variant::VARIANT, ppvdatacarray: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRecordInfo_Impl::GetFieldNoCopy(this, core::mem::transmute_copy(&pvdata), core::mem::transmute(&szfieldname), core::mem::transmute_copy(&pvarfield), core::mem::transmute_copy(&ppvdatacarray)).into()
+            }
+        }
+        unsafe extern "system" fn PutField<Identity: windows_core::IUnknownImpl + IRecordInfo_Impl>(this: *mut core::ffi::c_void, wflags: u32, pvdata: *mut core::ffi::c_void, szfieldname: windows_core::PCWSTR, pvarfield: *const super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRecordInfo_Impl::PutField(this, core::mem::transmute_copy(&wflags), core::mem::transmute_copy(&pvdata), core::mem::transmute(&szfieldname), core::mem::transmute_copy(&pvarfield)).into()
+            }
+        }
+        unsafe extern "system" fn PutFieldNoCopy<Identity: windows_core::IUnknownImpl + IRecordInfo_Impl>(this: *mut core::ffi::c_void, wflags: u32, pvdata: *mut core::ffi::c_void, szfieldname: windows_core::PCWSTR, pvarfield: *const super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRecordInfo_Impl::PutFieldNoCopy(this, core::mem::transmute_copy(&wflags), core::mem::transmute_copy(&pvdata), core::mem::transmute(&szfieldname), core::mem::transmute_copy(&pvarfield)).into()
+            }
+        }
+        unsafe extern "system" fn GetFieldNames<Identity: windows_core::IUnknownImpl + IRecordInfo_Impl>(this: *mut core::ffi::c_void, pcnames: *mut u32, rgbstrnames: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRecordInfo_Impl::GetFieldNames(this, core::mem::transmute_copy(&pcnames), core::mem::transmute_copy(&rgbstrnames)).into()
+            }
+        }
+        unsafe extern "system" fn IsMatchingType<Identity: windows_core::IUnknownImpl + IRecordInfo_Impl>(this: *mut core::ffi::c_void, precordinfo: *mut core::ffi::c_void) -> windows_core::BOOL {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IRecordInfo_Impl::IsMatchingType(this, core::mem::transmute_copy(&precordinfo))
             }
         }
-        unsafe extern "system" fn RecordCreate<Identity: IRecordInfo_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> *mut core::ffi::c_void {
+        unsafe extern "system" fn RecordCreate<Identity: windows_core::IUnknownImpl + IRecordInfo_Impl>(this: *mut core::ffi::c_void) -> *mut core::ffi::c_void {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IRecordInfo_Impl::RecordCreate(this)
             }
         }
-        unsafe extern "system" fn RecordCreateCopy<Identity: IRecordInfo_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pvsource: *const core::ffi::c_void, ppvdest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn RecordCreateCopy<Identity: windows_core::IUnknownImpl + IRecordInfo_Impl>(this: *mut core::ffi::c_void, pvsource: *const core::ffi::c_void, ppvdest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IRecordInfo_Impl::RecordCreateCopy(this, core::mem::transmute_copy(&pvsource), core::mem::transmute_copy(&ppvdest)).into()
             }
         }
-        unsafe extern "system" fn RecordDestroy<Identity: IRecordInfo_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pvrecord: *const core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn RecordDestroy<Identity: windows_core::IUnknownImpl + IRecordInfo_Impl>(this: *mut core::ffi::c_void, pvrecord: *const core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IRecordInfo_Impl::RecordDestroy(this, core::mem::transmute_copy(&pvrecord)).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            RecordInit: RecordInit::<Identity, OFFSET>,
-            RecordClear: RecordClear::<Identity, OFFSET>,
-            RecordCopy: RecordCopy::<Identity, OFFSET>,
-            GetGuid: GetGuid::<Identity, OFFSET>,
-            GetName: GetName::<Identity, OFFSET>,
-            GetSize: GetSize::<Identity, OFFSET>,
-            GetTypeInfo: GetTypeInfo::<Identity, OFFSET>,
-            GetField: GetField::<Identity, OFFSET>,
-            GetFieldNoCopy: GetFieldNoCopy::<Identity, OFFSET>,
-            PutField: PutField::<Identity, OFFSET>,
-            PutFieldNoCopy: PutFieldNoCopy::<Identity, OFFSET>,
-            GetFieldNames: GetFieldNames::<Identity, OFFSET>,
-            IsMatchingType: IsMatchingType::<Identity, OFFSET>,
-            RecordCreate: RecordCreate::<Identity, OFFSET>,
-            RecordCreateCopy: RecordCreateCopy::<Identity, OFFSET>,
-            RecordDestroy: RecordDestroy::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IRecordInfo as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RecordInit: RecordInit::<Identity>,
+            RecordClear: RecordClear::<Identity>,
+            RecordCopy: RecordCopy::<Identity>,
+            GetGuid: GetGuid::<Identity>,
+            GetName: GetName::<Identity>,
+            GetSize: GetSize::<Identity>,
+            GetTypeInfo: GetTypeInfo::<Identity>,
+            GetField: GetField::<Identity>,
+            GetFieldNoCopy: GetFieldNoCopy::<Identity>,
+            PutField: PutField::<Identity>,
+            PutFieldNoCopy: PutFieldNoCopy::<Identity>,
+            GetFieldNames: GetFieldNames::<Identity>,
+            IsMatchingType: IsMatchingType::<Identity>,
+            RecordCreate: RecordCreate::<Identity>,
+            RecordCreateCopy: RecordCreateCopy::<Identity>,
+            RecordDestroy: RecordDestroy::<Identity>,
+        }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRecordInfo {}
@@ -13216,6 +17554,30 @@ impl ISimpleFrameSite_Vtbl {
         iid == &<ISimpleFrameSite as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISimpleFrameSite_Impl> windows_core::imp::TearOffVtable<Identity> for ISimpleFrameSite_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn PreMessageFilter<Identity: windows_core::IUnknownImpl + ISimpleFrameSite_Impl>(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, msg: u32, wp: super::super::Foundation::WPARAM, lp: super::super::Foundation::LPARAM, plresult: *mut super::super::Foundation::LRESULT, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISimpleFrameSite_Impl::PreMessageFilter(this, core::mem::transmute_copy(&hwnd), core::mem::transmute_copy(&msg), core::mem::transmute_copy(&wp), core::mem::transmute_copy(&lp), core::mem::transmute_copy(&plresult), core::mem::transmute_copy(&pdwcookie)).into()
+            }
+        }
+        unsafe extern "system" fn PostMessageFilter<Identity: windows_core::IUnknownImpl + ISimpleFrameSite_Impl>(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, msg: u32, wp: super::super::Foundation::WPARAM, lp: super::super::Foundation::LPARAM, plresult: *mut super::super::Foundation::LRESULT, dwcookie: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISimpleFrameSite_Impl::PostMessageFilter(this, core::mem::transmute_copy(&hwnd), core::mem::transmute_copy(&msg), core::mem::transmute_copy(&wp), core::mem::transmute_copy(&lp), core::mem::transmute_copy(&plresult), core::mem::transmute_copy(&dwcookie)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PreMessageFilter: PreMessageFilter::<Identity>,
+            PostMessageFilter: PostMessageFilter::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISimpleFrameSite {}
 windows_core::imp::define_interface!(ISpecifyPropertyPages, ISpecifyPropertyPages_Vtbl, 0xb196b28b_bab4_101a_b69c_00aa00341d07);
 windows_core::imp::interface_hierarchy!(ISpecifyPropertyPages, windows_core::IUnknown);
@@ -13256,6 +17618,26 @@ impl ISpecifyPropertyPages_Vtbl {
         iid == &<ISpecifyPropertyPages as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISpecifyPropertyPages_Impl> windows_core::imp::TearOffVtable<Identity> for ISpecifyPropertyPages_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPages<Identity: windows_core::IUnknownImpl + ISpecifyPropertyPages_Impl>(this: *mut core::ffi::c_void, ppages: *mut CAUUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISpecifyPropertyPages_Impl::GetPages(this) {
+                    Ok(ok__) => {
+                        ppages.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetPages: GetPages::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ISpecifyPropertyPages {}
 windows_core::imp::define_interface!(ITypeChangeEvents, ITypeChangeEvents_Vtbl, 0x00020410_0000_0000_c000_000000000046);
 windows_core::imp::interface_hierarchy!(ITypeChangeEvents, windows_core::IUnknown);
@@ -13330,6 +17712,37 @@ impl ITypeChangeEvents_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + ITypeChangeEvents_Impl> windows_core::imp::TearOffVtable<Identity> for ITypeChangeEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RequestTypeChange<Identity: windows_core::IUnknownImpl + ITypeChangeEvents_Impl>(this: *mut core::ffi::c_void, changekind: CHANGEKIND, ptinfobefore: *mut core::ffi::c_void, pstrname: windows_core::PCWSTR, pfcancel: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITypeChangeEvents_Impl::RequestTypeChange(this, core::mem::transmute_copy(&changekind), core::mem::transmute_copy(&ptinfobefore), core::mem::transmute(&pstrname)) {
+                    Ok(ok__) => {
+                        pfcancel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AfterTypeChange<Identity: windows_core::IUnknownImpl + ITypeChangeEvents_Impl>(this: *mut core::ffi::c_void, changekind: CHANGEKIND, ptinfoafter: *mut core::ffi::c_void, pstrname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITypeChangeEvents_Impl::AfterTypeChange(this, core::mem::transmute_copy(&changekind), core::mem::transmute_copy(&ptinfoafter), core::mem::transmute(&pstrname)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RequestTypeChange: RequestTypeChange::<Identity>,
+            AfterTypeChange: AfterTypeChange::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for ITypeChangeEvents {}
 windows_core::imp::define_interface!(ITypeFactory, ITypeFactory_Vtbl, 0x0000002e_0000_0000_c000_000000000046);
 windows_core::imp::interface_hierarchy!(ITypeFactory, windows_core::IUnknown);
@@ -13380,6 +17793,27 @@ impl ITypeFactory_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + ITypeFactory_Impl> windows_core::imp::TearOffVtable<Identity> for ITypeFactory_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateFromTypeInfo<Identity: windows_core::IUnknownImpl + ITypeFactory_Impl>(this: *mut core::ffi::c_void, ptypeinfo: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITypeFactory_Impl::CreateFromTypeInfo(this, core::mem::transmute_copy(&ptypeinfo), core::mem::transmute_copy(&riid)) {
+                    Ok(ok__) => {
+                        ppv.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateFromTypeInfo: CreateFromTypeInfo::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for ITypeFactory {}
 windows_core::imp::define_interface!(ITypeMarshal, ITypeMarshal_Vtbl, 0x0000002d_0000_0000_c000_000000000046);
 windows_core::imp::interface_hierarchy!(ITypeMarshal, windows_core::IUnknown);
@@ -13459,6 +17893,50 @@ impl ITypeMarshal_Vtbl {
         iid == &<ITypeMarshal as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITypeMarshal_Impl> windows_core::imp::TearOffVtable<Identity> for ITypeMarshal_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Size<Identity: windows_core::IUnknownImpl + ITypeMarshal_Impl>(this: *mut core::ffi::c_void, pvtype: *const core::ffi::c_void, dwdestcontext: u32, pvdestcontext: *const core::ffi::c_void, psize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITypeMarshal_Impl::Size(this, core::mem::transmute_copy(&pvtype), core::mem::transmute_copy(&dwdestcontext), core::mem::transmute_copy(&pvdestcontext)) {
+                    Ok(ok__) => {
+                        psize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Marshal<Identity: windows_core::IUnknownImpl + ITypeMarshal_Impl>(this: *mut core::ffi::c_void, pvtype: *const core::ffi::c_void, dwdestcontext: u32, pvdestcontext: *const core::ffi::c_void, cbbufferlength: u32, pbuffer: *mut u8, pcbwritten: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITypeMarshal_Impl::Marshal(this, core::mem::transmute_copy(&pvtype), core::mem::transmute_copy(&dwdestcontext), core::mem::transmute_copy(&pvdestcontext), core::mem::transmute_copy(&cbbufferlength), core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&pcbwritten)).into()
+            }
+        }
+        unsafe extern "system" fn Unmarshal<Identity: windows_core::IUnknownImpl + ITypeMarshal_Impl>(this: *mut core::ffi::c_void, pvtype: *mut core::ffi::c_void, dwflags: u32, cbbufferlength: u32, pbuffer: *const u8, pcbread: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITypeMarshal_Impl::Unmarshal(this, core::mem::transmute_copy(&pvtype), core::mem::transmute_copy(&dwflags), core::mem::transmute_copy(&cbbufferlength), core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&pcbread)).into()
+            }
+        }
+        unsafe extern "system" fn Free<Identity: windows_core::IUnknownImpl + ITypeMarshal_Impl>(this: *mut core::ffi::c_void, pvtype: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITypeMarshal_Impl::Free(this, core::mem::transmute_copy(&pvtype)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Size: Size::<Identity>,
+            Marshal: Marshal::<Identity>,
+            Unmarshal: Unmarshal::<Identity>,
+            Free: Free::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITypeMarshal {}
 windows_core::imp::define_interface!(IVBFormat, IVBFormat_Vtbl, 0x9849fd60_3768_101b_8d72_ae6164ffe3cf);
 windows_core::imp::interface_hierarchy!(IVBFormat, windows_core::IUnknown);
@@ -13497,6 +17975,21 @@ impl IVBFormat_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IVBFormat_Impl> windows_core::imp::TearOffVtable<Identity> for IVBFormat_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Format<Identity: windows_core::IUnknownImpl + IVBFormat_Impl>(this: *mut core::ffi::c_void, vdata: *mut super::Variant::VARIANT, bstrformat: *mut core::ffi::c_void, lpbuffer: *mut core::ffi::c_void, cb: u16, lcid: i32, sfirstdayofweek: i16, sfirstweekofyear: u16, rcb: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVBFormat_Impl::Format(this, core::mem::transmute_copy(&vdata), core::mem::transmute(&bstrformat), core::mem::transmute_copy(&lpbuffer), core::mem::transmute_copy(&cb), core::mem::transmute_copy(&lcid), core::mem::transmute_copy(&sfirstdayofweek), core::mem::transmute_copy(&sfirstweekofyear), core::mem::transmute_copy(&rcb)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Format: Format::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IVBFormat {}
 windows_core::imp::define_interface!(IVBGetControl, IVBGetControl_Vtbl, 0x40a050a0_3c31_101b_a82e_08002b2b2337);
 windows_core::imp::interface_hierarchy!(IVBGetControl, windows_core::IUnknown);
@@ -13544,6 +18037,27 @@ impl IVBGetControl_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IVBGetControl_Impl> windows_core::imp::TearOffVtable<Identity> for IVBGetControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumControls<Identity: windows_core::IUnknownImpl + IVBGetControl_Impl>(this: *mut core::ffi::c_void, dwolecontf: u32, dwwhich: ENUM_CONTROLS_WHICH_FLAGS, ppenumunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IVBGetControl_Impl::EnumControls(this, core::mem::transmute(&dwolecontf), core::mem::transmute_copy(&dwwhich)) {
+                    Ok(ok__) => {
+                        ppenumunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, EnumControls: EnumControls::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IVBGetControl {}
 windows_core::imp::define_interface!(IVariantChangeType, IVariantChangeType_Vtbl, 0xa6ef9862_c720_11d0_9337_00a0c90dcaa9);
 windows_core::imp::interface_hierarchy!(IVariantChangeType, windows_core::IUnknown);
@@ -13582,6 +18096,21 @@ impl IVariantChangeType_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IVariantChangeType_Impl> windows_core::imp::TearOffVtable<Identity> for IVariantChangeType_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ChangeType<Identity: windows_core::IUnknownImpl + IVariantChangeType_Impl>(this: *mut core::ffi::c_void, pvardst: *mut super::Variant::VARIANT, pvarsrc: *const super::Variant::VARIANT, lcid: u32, vtnew: super::Variant::VARENUM) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IVariantChangeType_Impl::ChangeType(this, core::mem::transmute_copy(&pvardst), core::mem::transmute_copy(&pvarsrc), core::mem::transmute_copy(&lcid), core::mem::transmute_copy(&vtnew)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ChangeType: ChangeType::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IVariantChangeType {}
 windows_core::imp::define_interface!(IViewObject, IViewObject_Vtbl, 0x0000010d_0000_0000_c000_000000000046);
 windows_core::imp::interface_hierarchy!(IViewObject, windows_core::IUnknown);
@@ -13702,6 +18231,59 @@ impl IViewObject_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl<Identity: windows_core::IUnknownImpl + IViewObject_Impl> windows_core::imp::TearOffVtable<Identity> for IViewObject_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Draw<Identity: windows_core::IUnknownImpl + IViewObject_Impl>(this: *mut core::ffi::c_void, dwdrawaspect: super::Com::DVASPECT, lindex: i32, pvaspect: *mut core::ffi::c_void, ptd: *const super::Com::DVTARGETDEVICE, hdctargetdev: super::super::Graphics::Gdi::HDC, hdcdraw: super::super::Graphics::Gdi::HDC, lprcbounds: *const super::super::Foundation::RECTL, lprcwbounds: *const super::super::Foundation::RECTL, pfncontinue: isize, dwcontinue: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IViewObject_Impl::Draw(this, core::mem::transmute_copy(&dwdrawaspect), core::mem::transmute_copy(&lindex), core::mem::transmute_copy(&pvaspect), core::mem::transmute_copy(&ptd), core::mem::transmute_copy(&hdctargetdev), core::mem::transmute_copy(&hdcdraw), core::mem::transmute_copy(&lprcbounds), core::mem::transmute_copy(&lprcwbounds), core::mem::transmute_copy(&pfncontinue), core::mem::transmute_copy(&dwcontinue)).into()
+            }
+        }
+        unsafe extern "system" fn GetColorSet<Identity: windows_core::IUnknownImpl + IViewObject_Impl>(this: *mut core::ffi::c_void, dwdrawaspect: super::Com::DVASPECT, lindex: i32, pvaspect: *mut core::ffi::c_void, ptd: *const super::Com::DVTARGETDEVICE, hictargetdev: super::super::Graphics::Gdi::HDC, ppcolorset: *mut *mut super::super::Graphics::Gdi::LOGPALETTE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IViewObject_Impl::GetColorSet(this, core::mem::transmute_copy(&dwdrawaspect), core::mem::transmute_copy(&lindex), core::mem::transmute_copy(&pvaspect), core::mem::transmute_copy(&ptd), core::mem::transmute_copy(&hictargetdev), core::mem::transmute_copy(&ppcolorset)).into()
+            }
+        }
+        unsafe extern "system" fn Freeze<Identity: windows_core::IUnknownImpl + IViewObject_Impl>(this: *mut core::ffi::c_void, dwdrawaspect: super::Com::DVASPECT, lindex: i32, pvaspect: *mut core::ffi::c_void, pdwfreeze: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IViewObject_Impl::Freeze(this, core::mem::transmute_copy(&dwdrawaspect), core::mem::transmute_copy(&lindex), core::mem::transmute_copy(&pvaspect), core::mem::transmute_copy(&pdwfreeze)).into()
+            }
+        }
+        unsafe extern "system" fn Unfreeze<Identity: windows_core::IUnknownImpl + IViewObject_Impl>(this: *mut core::ffi::c_void, dwfreeze: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IViewObject_Impl::Unfreeze(this, core::mem::transmute_copy(&dwfreeze)).into()
+            }
+        }
+        unsafe extern "system" fn SetAdvise<Identity: windows_core::IUnknownImpl + IViewObject_Impl>(this: *mut core::ffi::c_void, aspects: super::Com::DVASPECT, advf: u32, padvsink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IViewObject_Impl::SetAdvise(this, core::mem::transmute_copy(&aspects), core::mem::transmute_copy(&advf), core::mem::transmute_copy(&padvsink)).into()
+            }
+        }
+        unsafe extern "system" fn GetAdvise<Identity: windows_core::IUnknownImpl + IViewObject_Impl>(this: *mut core::ffi::c_void, paspects: *mut u32, padvf: *mut u32, ppadvsink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IViewObject_Impl::GetAdvise(this, core::mem::transmute_copy(&paspects), core::mem::transmute_copy(&padvf), core::mem::transmute_copy(&ppadvsink)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Draw: Draw::<Identity>,
+            GetColorSet: GetColorSet::<Identity>,
+            Freeze: Freeze::<Identity>,
+            Unfreeze: Unfreeze::<Identity>,
+            SetAdvise: SetAdvise::<Identity>,
+            GetAdvise: GetAdvise::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
 impl windows_core::RuntimeName for IViewObject {}
 windows_core::imp::define_interface!(IViewObject2, IViewObject2_Vtbl, 0x00000127_0000_0000_c000_000000000046);
 impl core::ops::Deref for IViewObject2 {
@@ -13755,6 +18337,27 @@ impl IViewObject2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl<Identity: windows_core::IUnknownImpl + IViewObject2_Impl> windows_core::imp::TearOffVtable<Identity> for IViewObject2_Vtbl
+where
+    IViewObject_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetExtent<Identity: windows_core::IUnknownImpl + IViewObject2_Impl>(this: *mut core::ffi::c_void, dwdrawaspect: super::Com::DVASPECT, lindex: i32, ptd: *const super::Com::DVTARGETDEVICE, lpsizel: *mut super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IViewObject2_Impl::GetExtent(this, core::mem::transmute_copy(&dwdrawaspect), core::mem::transmute_copy(&lindex), core::mem::transmute_copy(&ptd)) {
+                    Ok(ok__) => {
+                        lpsizel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IViewObject_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetExtent: GetExtent::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
 impl windows_core::RuntimeName for IViewObject2 {}
 windows_core::imp::define_interface!(IViewObjectEx, IViewObjectEx_Vtbl, 0x3af24292_0c96_11ce_a0cf_00aa00600ab8);
 impl core::ops::Deref for IViewObjectEx {
@@ -13895,6 +18498,82 @@ impl IViewObjectEx_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl<Identity: windows_core::IUnknownImpl + IViewObjectEx_Impl> windows_core::imp::TearOffVtable<Identity> for IViewObjectEx_Vtbl
+where
+    IViewObject2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetRect<Identity: windows_core::IUnknownImpl + IViewObjectEx_Impl>(this: *mut core::ffi::c_void, dwaspect: u32, prect: *mut super::super::Foundation::RECTL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IViewObjectEx_Impl::GetRect(this, core::mem::transmute_copy(&dwaspect)) {
+                    Ok(ok__) => {
+                        prect.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetViewStatus<Identity: windows_core::IUnknownImpl + IViewObjectEx_Impl>(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IViewObjectEx_Impl::GetViewStatus(this) {
+                    Ok(ok__) => {
+                        pdwstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryHitPoint<Identity: windows_core::IUnknownImpl + IViewObjectEx_Impl>(this: *mut core::ffi::c_void, dwaspect: u32, prectbounds: *const super::super::Foundation::RECT, ptlloc: super::super::Foundation::POINT, lclosehint: i32, phitresult: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IViewObjectEx_Impl::QueryHitPoint(this, core::mem::transmute_copy(&dwaspect), core::mem::transmute_copy(&prectbounds), core::mem::transmute(&ptlloc), core::mem::transmute_copy(&lclosehint)) {
+                    Ok(ok__) => {
+                        phitresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryHitRect<Identity: windows_core::IUnknownImpl + IViewObjectEx_Impl>(this: *mut core::ffi::c_void, dwaspect: u32, prectbounds: *const super::super::Foundation::RECT, prectloc: *const super::super::Foundation::RECT, lclosehint: i32, phitresult: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IViewObjectEx_Impl::QueryHitRect(this, core::mem::transmute_copy(&dwaspect), core::mem::transmute_copy(&prectbounds), core::mem::transmute_copy(&prectloc), core::mem::transmute_copy(&lclosehint)) {
+                    Ok(ok__) => {
+                        phitresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetNaturalExtent<Identity: windows_core::IUnknownImpl + IViewObjectEx_Impl>(this: *mut core::ffi::c_void, dwaspect: super::Com::DVASPECT, lindex: i32, ptd: *const super::Com::DVTARGETDEVICE, hictargetdev: super::super::Graphics::Gdi::HDC, pextentinfo: *const DVEXTENTINFO, psizel: *mut super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IViewObjectEx_Impl::GetNaturalExtent(this, core::mem::transmute_copy(&dwaspect), core::mem::transmute_copy(&lindex), core::mem::transmute_copy(&ptd), core::mem::transmute_copy(&hictargetdev), core::mem::transmute_copy(&pextentinfo)) {
+                    Ok(ok__) => {
+                        psizel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IViewObject2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetRect: GetRect::<Identity>,
+            GetViewStatus: GetViewStatus::<Identity>,
+            QueryHitPoint: QueryHitPoint::<Identity>,
+            QueryHitRect: QueryHitRect::<Identity>,
+            GetNaturalExtent: GetNaturalExtent::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
 impl windows_core::RuntimeName for IViewObjectEx {}
 windows_core::imp::define_interface!(IZoomEvents, IZoomEvents_Vtbl, 0x41b68150_904c_4e17_a0ba_a438182e359d);
 windows_core::imp::interface_hierarchy!(IZoomEvents, windows_core::IUnknown);
@@ -13926,6 +18605,23 @@ impl IZoomEvents_Vtbl {
         iid == &<IZoomEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IZoomEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IZoomEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnZoomPercentChanged<Identity: windows_core::IUnknownImpl + IZoomEvents_Impl>(this: *mut core::ffi::c_void, ulzoompercent: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IZoomEvents_Impl::OnZoomPercentChanged(this, core::mem::transmute_copy(&ulzoompercent)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnZoomPercentChanged: OnZoomPercentChanged::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IZoomEvents {}
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
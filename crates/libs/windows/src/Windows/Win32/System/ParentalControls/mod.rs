@@ -45,6 +45,26 @@ impl IWPCGamesSettings_Vtbl {
         iid == &<IWPCGamesSettings as windows_core::Interface>::IID || iid == &<IWPCSettings as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWPCGamesSettings_Impl> windows_core::imp::TearOffVtable<Identity> for IWPCGamesSettings_Vtbl
+where
+    IWPCSettings_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsBlocked<Identity: windows_core::IUnknownImpl + IWPCGamesSettings_Impl>(this: *mut core::ffi::c_void, guidappid: windows_core::GUID, pdwreasons: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWPCGamesSettings_Impl::IsBlocked(this, core::mem::transmute(&guidappid)) {
+                    Ok(ok__) => {
+                        pdwreasons.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IWPCSettings_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, IsBlocked: IsBlocked::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWPCGamesSettings {}
 windows_core::imp::define_interface!(IWPCProviderConfig, IWPCProviderConfig_Vtbl, 0xbef54196_2d02_4a26_b6e5_d65af295d0f1);
 windows_core::imp::interface_hierarchy!(IWPCProviderConfig, windows_core::IUnknown);
@@ -112,6 +132,43 @@ impl IWPCProviderConfig_Vtbl {
         iid == &<IWPCProviderConfig as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWPCProviderConfig_Impl> windows_core::imp::TearOffVtable<Identity> for IWPCProviderConfig_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetUserSummary<Identity: windows_core::IUnknownImpl + IWPCProviderConfig_Impl>(this: *mut core::ffi::c_void, bstrsid: *mut core::ffi::c_void, pbstrusersummary: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWPCProviderConfig_Impl::GetUserSummary(this, core::mem::transmute(&bstrsid)) {
+                    Ok(ok__) => {
+                        pbstrusersummary.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Configure<Identity: windows_core::IUnknownImpl + IWPCProviderConfig_Impl>(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, bstrsid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWPCProviderConfig_Impl::Configure(this, core::mem::transmute_copy(&hwnd), core::mem::transmute(&bstrsid)).into()
+            }
+        }
+        unsafe extern "system" fn RequestOverride<Identity: windows_core::IUnknownImpl + IWPCProviderConfig_Impl>(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, bstrpath: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWPCProviderConfig_Impl::RequestOverride(this, core::mem::transmute_copy(&hwnd), core::mem::transmute(&bstrpath), core::mem::transmute(&dwflags)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetUserSummary: GetUserSummary::<Identity>,
+            Configure: Configure::<Identity>,
+            RequestOverride: RequestOverride::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWPCProviderConfig {}
 windows_core::imp::define_interface!(IWPCProviderState, IWPCProviderState_Vtbl, 0x50b6a267_c4bd_450b_adb5_759073837c9e);
 windows_core::imp::interface_hierarchy!(IWPCProviderState, windows_core::IUnknown);
@@ -154,6 +211,30 @@ impl IWPCProviderState_Vtbl {
         iid == &<IWPCProviderState as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWPCProviderState_Impl> windows_core::imp::TearOffVtable<Identity> for IWPCProviderState_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Enable<Identity: windows_core::IUnknownImpl + IWPCProviderState_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWPCProviderState_Impl::Enable(this).into()
+            }
+        }
+        unsafe extern "system" fn Disable<Identity: windows_core::IUnknownImpl + IWPCProviderState_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWPCProviderState_Impl::Disable(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Enable: Enable::<Identity>,
+            Disable: Disable::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWPCProviderState {}
 windows_core::imp::define_interface!(IWPCProviderSupport, IWPCProviderSupport_Vtbl, 0x41eba572_23ed_4779_bec1_8df96206c44c);
 windows_core::imp::interface_hierarchy!(IWPCProviderSupport, windows_core::IUnknown);
@@ -194,6 +275,26 @@ impl IWPCProviderSupport_Vtbl {
         iid == &<IWPCProviderSupport as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWPCProviderSupport_Impl> windows_core::imp::TearOffVtable<Identity> for IWPCProviderSupport_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrent<Identity: windows_core::IUnknownImpl + IWPCProviderSupport_Impl>(this: *mut core::ffi::c_void, pguidprovider: *mut windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWPCProviderSupport_Impl::GetCurrent(this) {
+                    Ok(ok__) => {
+                        pguidprovider.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetCurrent: GetCurrent::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWPCProviderSupport {}
 windows_core::imp::define_interface!(IWPCSettings, IWPCSettings_Vtbl, 0x8fdf6ca1_0189_47e4_b670_1a8a4636e340);
 windows_core::imp::interface_hierarchy!(IWPCSettings, windows_core::IUnknown);
@@ -279,6 +380,55 @@ impl IWPCSettings_Vtbl {
         iid == &<IWPCSettings as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWPCSettings_Impl> windows_core::imp::TearOffVtable<Identity> for IWPCSettings_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IsLoggingRequired<Identity: windows_core::IUnknownImpl + IWPCSettings_Impl>(this: *mut core::ffi::c_void, pfrequired: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWPCSettings_Impl::IsLoggingRequired(this) {
+                    Ok(ok__) => {
+                        pfrequired.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLastSettingsChangeTime<Identity: windows_core::IUnknownImpl + IWPCSettings_Impl>(this: *mut core::ffi::c_void, ptime: *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWPCSettings_Impl::GetLastSettingsChangeTime(this) {
+                    Ok(ok__) => {
+                        ptime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRestrictions<Identity: windows_core::IUnknownImpl + IWPCSettings_Impl>(this: *mut core::ffi::c_void, pdwrestrictions: *mut WPCFLAG_RESTRICTION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWPCSettings_Impl::GetRestrictions(this) {
+                    Ok(ok__) => {
+                        pdwrestrictions.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IsLoggingRequired: IsLoggingRequired::<Identity>,
+            GetLastSettingsChangeTime: GetLastSettingsChangeTime::<Identity>,
+            GetRestrictions: GetRestrictions::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWPCSettings {}
 windows_core::imp::define_interface!(IWPCWebSettings, IWPCWebSettings_Vtbl, 0xffccbdb8_0992_4c30_b0f1_1cbb09c240aa);
 impl core::ops::Deref for IWPCWebSettings {
@@ -352,6 +502,42 @@ impl IWPCWebSettings_Vtbl {
         iid == &<IWPCWebSettings as windows_core::Interface>::IID || iid == &<IWPCSettings as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWPCWebSettings_Impl> windows_core::imp::TearOffVtable<Identity> for IWPCWebSettings_Vtbl
+where
+    IWPCSettings_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSettings<Identity: windows_core::IUnknownImpl + IWPCWebSettings_Impl>(this: *mut core::ffi::c_void, pdwsettings: *mut WPCFLAG_WEB_SETTING) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWPCWebSettings_Impl::GetSettings(this) {
+                    Ok(ok__) => {
+                        pdwsettings.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RequestURLOverride<Identity: windows_core::IUnknownImpl + IWPCWebSettings_Impl>(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, pcszurl: windows_core::PCWSTR, curls: u32, ppcszsuburls: *const windows_core::PCWSTR, pfchanged: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWPCWebSettings_Impl::RequestURLOverride(this, core::mem::transmute_copy(&hwnd), core::mem::transmute(&pcszurl), core::mem::transmute_copy(&curls), core::mem::transmute_copy(&ppcszsuburls)) {
+                    Ok(ok__) => {
+                        pfchanged.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWPCSettings_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSettings: GetSettings::<Identity>,
+            RequestURLOverride: RequestURLOverride::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWPCWebSettings {}
 windows_core::imp::define_interface!(IWindowsParentalControls, IWindowsParentalControls_Vtbl, 0x28b4d88b_e072_49e6_804d_26edbe21a7b9);
 impl core::ops::Deref for IWindowsParentalControls {
@@ -401,6 +587,29 @@ impl IWindowsParentalControls_Vtbl {
         iid == &<IWindowsParentalControls as windows_core::Interface>::IID || iid == &<IWindowsParentalControlsCore as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWindowsParentalControls_Impl> windows_core::imp::TearOffVtable<Identity> for IWindowsParentalControls_Vtbl
+where
+    IWindowsParentalControlsCore_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetGamesSettings<Identity: windows_core::IUnknownImpl + IWindowsParentalControls_Impl>(this: *mut core::ffi::c_void, pcszsid: windows_core::PCWSTR, ppsettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWindowsParentalControls_Impl::GetGamesSettings(this, core::mem::transmute(&pcszsid)) {
+                    Ok(ok__) => {
+                        ppsettings.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWindowsParentalControlsCore_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetGamesSettings: GetGamesSettings::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWindowsParentalControls {}
 windows_core::imp::define_interface!(IWindowsParentalControlsCore, IWindowsParentalControlsCore_Vtbl, 0x4ff40a0f_3f3b_4d7c_a41b_4f39d7b44d05);
 windows_core::imp::interface_hierarchy!(IWindowsParentalControlsCore, windows_core::IUnknown);
@@ -504,6 +713,62 @@ impl IWindowsParentalControlsCore_Vtbl {
         iid == &<IWindowsParentalControlsCore as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWindowsParentalControlsCore_Impl> windows_core::imp::TearOffVtable<Identity> for IWindowsParentalControlsCore_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetVisibility<Identity: windows_core::IUnknownImpl + IWindowsParentalControlsCore_Impl>(this: *mut core::ffi::c_void, pevisibility: *mut WPCFLAG_VISIBILITY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWindowsParentalControlsCore_Impl::GetVisibility(this) {
+                    Ok(ok__) => {
+                        pevisibility.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetUserSettings<Identity: windows_core::IUnknownImpl + IWindowsParentalControlsCore_Impl>(this: *mut core::ffi::c_void, pcszsid: windows_core::PCWSTR, ppsettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWindowsParentalControlsCore_Impl::GetUserSettings(this, core::mem::transmute(&pcszsid)) {
+                    Ok(ok__) => {
+                        ppsettings.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetWebSettings<Identity: windows_core::IUnknownImpl + IWindowsParentalControlsCore_Impl>(this: *mut core::ffi::c_void, pcszsid: windows_core::PCWSTR, ppsettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWindowsParentalControlsCore_Impl::GetWebSettings(this, core::mem::transmute(&pcszsid)) {
+                    Ok(ok__) => {
+                        ppsettings.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetWebFilterInfo<Identity: windows_core::IUnknownImpl + IWindowsParentalControlsCore_Impl>(this: *mut core::ffi::c_void, pguidid: *mut windows_core::GUID, ppszname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWindowsParentalControlsCore_Impl::GetWebFilterInfo(this, core::mem::transmute_copy(&pguidid), core::mem::transmute_copy(&ppszname)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetVisibility: GetVisibility::<Identity>,
+            GetUserSettings: GetUserSettings::<Identity>,
+            GetWebSettings: GetWebSettings::<Identity>,
+            GetWebFilterInfo: GetWebFilterInfo::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWindowsParentalControlsCore {}
 pub const MSG_Event_AppBlocked: i32 = -1342177264i32;
 pub const MSG_Event_AppOverride: i32 = -1342177263i32;
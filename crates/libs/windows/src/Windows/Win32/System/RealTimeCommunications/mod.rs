@@ -57,6 +57,31 @@ impl INetworkTransportSettings_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Networking_WinSock")]
+impl<Identity: windows_core::IUnknownImpl + INetworkTransportSettings_Impl> windows_core::imp::TearOffVtable<Identity> for INetworkTransportSettings_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ApplySetting<Identity: windows_core::IUnknownImpl + INetworkTransportSettings_Impl>(this: *mut core::ffi::c_void, settingid: *const super::super::Networking::WinSock::TRANSPORT_SETTING_ID, lengthin: u32, valuein: *const u8, lengthout: *mut u32, valueout: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetworkTransportSettings_Impl::ApplySetting(this, core::mem::transmute_copy(&settingid), core::mem::transmute_copy(&lengthin), core::mem::transmute_copy(&valuein), core::mem::transmute_copy(&lengthout), core::mem::transmute_copy(&valueout)).into()
+            }
+        }
+        unsafe extern "system" fn QuerySetting<Identity: windows_core::IUnknownImpl + INetworkTransportSettings_Impl>(this: *mut core::ffi::c_void, settingid: *const super::super::Networking::WinSock::TRANSPORT_SETTING_ID, lengthin: u32, valuein: *const u8, lengthout: *mut u32, valueout: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetworkTransportSettings_Impl::QuerySetting(this, core::mem::transmute_copy(&settingid), core::mem::transmute_copy(&lengthin), core::mem::transmute_copy(&valuein), core::mem::transmute_copy(&lengthout), core::mem::transmute_copy(&valueout)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ApplySetting: ApplySetting::<Identity>,
+            QuerySetting: QuerySetting::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Networking_WinSock")]
 impl windows_core::RuntimeName for INetworkTransportSettings {}
 windows_core::imp::define_interface!(INotificationTransportSync, INotificationTransportSync_Vtbl, 0x79eb1402_0ab8_49c0_9e14_a1ae4ba93058);
 windows_core::imp::interface_hierarchy!(INotificationTransportSync, windows_core::IUnknown);
@@ -103,6 +128,30 @@ impl INotificationTransportSync_Vtbl {
         iid == &<INotificationTransportSync as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + INotificationTransportSync_Impl> windows_core::imp::TearOffVtable<Identity> for INotificationTransportSync_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CompleteDelivery<Identity: windows_core::IUnknownImpl + INotificationTransportSync_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INotificationTransportSync_Impl::CompleteDelivery(this).into()
+            }
+        }
+        unsafe extern "system" fn Flush<Identity: windows_core::IUnknownImpl + INotificationTransportSync_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INotificationTransportSync_Impl::Flush(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CompleteDelivery: CompleteDelivery::<Identity>,
+            Flush: Flush::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for INotificationTransportSync {}
 windows_core::imp::define_interface!(IRTCBuddy, IRTCBuddy_Vtbl, 0xfcb136c8_7b90_4e0c_befe_56edf0ba6f1c);
 impl core::ops::Deref for IRTCBuddy {
@@ -169,6 +218,38 @@ impl IRTCBuddy_Vtbl {
         iid == &<IRTCBuddy as windows_core::Interface>::IID || iid == &<IRTCPresenceContact as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRTCBuddy_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCBuddy_Vtbl
+where
+    IRTCPresenceContact_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Status<Identity: windows_core::IUnknownImpl + IRTCBuddy_Impl>(this: *mut core::ffi::c_void, penstatus: *mut RTC_PRESENCE_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCBuddy_Impl::Status(this) {
+                    Ok(ok__) => {
+                        penstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Notes<Identity: windows_core::IUnknownImpl + IRTCBuddy_Impl>(this: *mut core::ffi::c_void, pbstrnotes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCBuddy_Impl::Notes(this) {
+                    Ok(ok__) => {
+                        pbstrnotes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IRTCPresenceContact_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Status: Status::<Identity>, Notes: Notes::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IRTCBuddy {}
 windows_core::imp::define_interface!(IRTCBuddy2, IRTCBuddy2_Vtbl, 0x102f9588_23e7_40e3_954d_cd7a1d5c0361);
 impl core::ops::Deref for IRTCBuddy2 {
@@ -367,6 +448,115 @@ impl IRTCBuddy2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IRTCBuddy2_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCBuddy2_Vtbl
+where
+    IRTCBuddy_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Profile<Identity: windows_core::IUnknownImpl + IRTCBuddy2_Impl>(this: *mut core::ffi::c_void, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCBuddy2_Impl::Profile(this) {
+                    Ok(ok__) => {
+                        ppprofile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Refresh<Identity: windows_core::IUnknownImpl + IRTCBuddy2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCBuddy2_Impl::Refresh(this).into()
+            }
+        }
+        unsafe extern "system" fn EnumerateGroups<Identity: windows_core::IUnknownImpl + IRTCBuddy2_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCBuddy2_Impl::EnumerateGroups(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Groups<Identity: windows_core::IUnknownImpl + IRTCBuddy2_Impl>(this: *mut core::ffi::c_void, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCBuddy2_Impl::Groups(this) {
+                    Ok(ok__) => {
+                        ppcollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_PresenceProperty<Identity: windows_core::IUnknownImpl + IRTCBuddy2_Impl>(this: *mut core::ffi::c_void, enproperty: RTC_PRESENCE_PROPERTY, pbstrproperty: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCBuddy2_Impl::get_PresenceProperty(this, core::mem::transmute_copy(&enproperty)) {
+                    Ok(ok__) => {
+                        pbstrproperty.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumeratePresenceDevices<Identity: windows_core::IUnknownImpl + IRTCBuddy2_Impl>(this: *mut core::ffi::c_void, ppenumdevices: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCBuddy2_Impl::EnumeratePresenceDevices(this) {
+                    Ok(ok__) => {
+                        ppenumdevices.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PresenceDevices<Identity: windows_core::IUnknownImpl + IRTCBuddy2_Impl>(this: *mut core::ffi::c_void, ppdevicescollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCBuddy2_Impl::PresenceDevices(this) {
+                    Ok(ok__) => {
+                        ppdevicescollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SubscriptionType<Identity: windows_core::IUnknownImpl + IRTCBuddy2_Impl>(this: *mut core::ffi::c_void, pensubscriptiontype: *mut RTC_BUDDY_SUBSCRIPTION_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCBuddy2_Impl::SubscriptionType(this) {
+                    Ok(ok__) => {
+                        pensubscriptiontype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IRTCBuddy_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Profile: Profile::<Identity>,
+            Refresh: Refresh::<Identity>,
+            EnumerateGroups: EnumerateGroups::<Identity>,
+            Groups: Groups::<Identity>,
+            get_PresenceProperty: get_PresenceProperty::<Identity>,
+            EnumeratePresenceDevices: EnumeratePresenceDevices::<Identity>,
+            PresenceDevices: PresenceDevices::<Identity>,
+            SubscriptionType: SubscriptionType::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IRTCBuddy2 {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCBuddyEvent, IRTCBuddyEvent_Vtbl, 0xf36d755d_17e6_404e_954f_0fc07574c78d);
@@ -421,6 +611,27 @@ impl IRTCBuddyEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCBuddyEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCBuddyEvent_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Buddy<Identity: windows_core::IUnknownImpl + IRTCBuddyEvent_Impl>(this: *mut core::ffi::c_void, ppbuddy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCBuddyEvent_Impl::Buddy(this) {
+                    Ok(ok__) => {
+                        ppbuddy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Buddy: Buddy::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCBuddyEvent {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCBuddyEvent2, IRTCBuddyEvent2_Vtbl, 0x484a7f1e_73f0_4990_bfc2_60bc3978a720);
@@ -520,6 +731,56 @@ impl IRTCBuddyEvent2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCBuddyEvent2_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCBuddyEvent2_Vtbl
+where
+    IRTCBuddyEvent_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EventType<Identity: windows_core::IUnknownImpl + IRTCBuddyEvent2_Impl>(this: *mut core::ffi::c_void, peventtype: *mut RTC_BUDDY_EVENT_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCBuddyEvent2_Impl::EventType(this) {
+                    Ok(ok__) => {
+                        peventtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StatusCode<Identity: windows_core::IUnknownImpl + IRTCBuddyEvent2_Impl>(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCBuddyEvent2_Impl::StatusCode(this) {
+                    Ok(ok__) => {
+                        plstatuscode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StatusText<Identity: windows_core::IUnknownImpl + IRTCBuddyEvent2_Impl>(this: *mut core::ffi::c_void, pbstrstatustext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCBuddyEvent2_Impl::StatusText(this) {
+                    Ok(ok__) => {
+                        pbstrstatustext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IRTCBuddyEvent_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EventType: EventType::<Identity>,
+            StatusCode: StatusCode::<Identity>,
+            StatusText: StatusText::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCBuddyEvent2 {}
 windows_core::imp::define_interface!(IRTCBuddyGroup, IRTCBuddyGroup_Vtbl, 0x60361e68_9164_4389_a4c6_d0b3925bda5e);
 windows_core::imp::interface_hierarchy!(IRTCBuddyGroup, windows_core::IUnknown);
@@ -708,6 +969,110 @@ impl IRTCBuddyGroup_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IRTCBuddyGroup_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCBuddyGroup_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + IRTCBuddyGroup_Impl>(this: *mut core::ffi::c_void, pbstrgroupname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCBuddyGroup_Impl::Name(this) {
+                    Ok(ok__) => {
+                        pbstrgroupname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetName<Identity: windows_core::IUnknownImpl + IRTCBuddyGroup_Impl>(this: *mut core::ffi::c_void, bstrgroupname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCBuddyGroup_Impl::SetName(this, core::mem::transmute(&bstrgroupname)).into()
+            }
+        }
+        unsafe extern "system" fn AddBuddy<Identity: windows_core::IUnknownImpl + IRTCBuddyGroup_Impl>(this: *mut core::ffi::c_void, pbuddy: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCBuddyGroup_Impl::AddBuddy(this, core::mem::transmute_copy(&pbuddy)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveBuddy<Identity: windows_core::IUnknownImpl + IRTCBuddyGroup_Impl>(this: *mut core::ffi::c_void, pbuddy: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCBuddyGroup_Impl::RemoveBuddy(this, core::mem::transmute_copy(&pbuddy)).into()
+            }
+        }
+        unsafe extern "system" fn EnumerateBuddies<Identity: windows_core::IUnknownImpl + IRTCBuddyGroup_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCBuddyGroup_Impl::EnumerateBuddies(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Buddies<Identity: windows_core::IUnknownImpl + IRTCBuddyGroup_Impl>(this: *mut core::ffi::c_void, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCBuddyGroup_Impl::Buddies(this) {
+                    Ok(ok__) => {
+                        ppcollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Data<Identity: windows_core::IUnknownImpl + IRTCBuddyGroup_Impl>(this: *mut core::ffi::c_void, pbstrdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCBuddyGroup_Impl::Data(this) {
+                    Ok(ok__) => {
+                        pbstrdata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetData<Identity: windows_core::IUnknownImpl + IRTCBuddyGroup_Impl>(this: *mut core::ffi::c_void, bstrdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCBuddyGroup_Impl::SetData(this, core::mem::transmute(&bstrdata)).into()
+            }
+        }
+        unsafe extern "system" fn Profile<Identity: windows_core::IUnknownImpl + IRTCBuddyGroup_Impl>(this: *mut core::ffi::c_void, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCBuddyGroup_Impl::Profile(this) {
+                    Ok(ok__) => {
+                        ppprofile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Name: Name::<Identity>,
+            SetName: SetName::<Identity>,
+            AddBuddy: AddBuddy::<Identity>,
+            RemoveBuddy: RemoveBuddy::<Identity>,
+            EnumerateBuddies: EnumerateBuddies::<Identity>,
+            Buddies: Buddies::<Identity>,
+            Data: Data::<Identity>,
+            SetData: SetData::<Identity>,
+            Profile: Profile::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IRTCBuddyGroup {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCBuddyGroupEvent, IRTCBuddyGroupEvent_Vtbl, 0x3a79e1d1_b736_4414_96f8_bbc7f08863e4);
@@ -828,27 +1193,90 @@ impl IRTCBuddyGroupEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IRTCBuddyGroupEvent {}
-windows_core::imp::define_interface!(IRTCClient, IRTCClient_Vtbl, 0x07829e45_9a34_408e_a011_bddf13487cd1);
-windows_core::imp::interface_hierarchy!(IRTCClient, windows_core::IUnknown);
-impl IRTCClient {
-    pub unsafe fn Initialize(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Initialize)(windows_core::Interface::as_raw(self)).ok() }
-    }
-    pub unsafe fn Shutdown(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Shutdown)(windows_core::Interface::as_raw(self)).ok() }
-    }
-    pub unsafe fn PrepareForShutdown(&self) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).PrepareForShutdown)(windows_core::Interface::as_raw(self)).ok() }
-    }
-    pub unsafe fn SetEventFilter(&self, lfilter: i32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetEventFilter)(windows_core::Interface::as_raw(self), lfilter).ok() }
-    }
-    pub unsafe fn EventFilter(&self) -> windows_core::Result<i32> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).EventFilter)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
-        }
+impl<Identity: windows_core::IUnknownImpl + IRTCBuddyGroupEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCBuddyGroupEvent_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EventType<Identity: windows_core::IUnknownImpl + IRTCBuddyGroupEvent_Impl>(this: *mut core::ffi::c_void, peventtype: *mut RTC_GROUP_EVENT_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCBuddyGroupEvent_Impl::EventType(this) {
+                    Ok(ok__) => {
+                        peventtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Group<Identity: windows_core::IUnknownImpl + IRTCBuddyGroupEvent_Impl>(this: *mut core::ffi::c_void, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCBuddyGroupEvent_Impl::Group(this) {
+                    Ok(ok__) => {
+                        ppgroup.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Buddy<Identity: windows_core::IUnknownImpl + IRTCBuddyGroupEvent_Impl>(this: *mut core::ffi::c_void, ppbuddy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCBuddyGroupEvent_Impl::Buddy(this) {
+                    Ok(ok__) => {
+                        ppbuddy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StatusCode<Identity: windows_core::IUnknownImpl + IRTCBuddyGroupEvent_Impl>(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCBuddyGroupEvent_Impl::StatusCode(this) {
+                    Ok(ok__) => {
+                        plstatuscode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EventType: EventType::<Identity>,
+            Group: Group::<Identity>,
+            Buddy: Buddy::<Identity>,
+            StatusCode: StatusCode::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IRTCBuddyGroupEvent {}
+windows_core::imp::define_interface!(IRTCClient, IRTCClient_Vtbl, 0x07829e45_9a34_408e_a011_bddf13487cd1);
+windows_core::imp::interface_hierarchy!(IRTCClient, windows_core::IUnknown);
+impl IRTCClient {
+    pub unsafe fn Initialize(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Initialize)(windows_core::Interface::as_raw(self)).ok() }
+    }
+    pub unsafe fn Shutdown(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Shutdown)(windows_core::Interface::as_raw(self)).ok() }
+    }
+    pub unsafe fn PrepareForShutdown(&self) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).PrepareForShutdown)(windows_core::Interface::as_raw(self)).ok() }
+    }
+    pub unsafe fn SetEventFilter(&self, lfilter: i32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetEventFilter)(windows_core::Interface::as_raw(self), lfilter).ok() }
+    }
+    pub unsafe fn EventFilter(&self) -> windows_core::Result<i32> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).EventFilter)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
     }
     pub unsafe fn SetPreferredMediaTypes(&self, lmediatypes: i32, fpersistent: super::super::Foundation::VARIANT_BOOL) -> windows_core::Result<()> {
         unsafe { (windows_core::Interface::vtable(self).SetPreferredMediaTypes)(windows_core::Interface::as_raw(self), lmediatypes, fpersistent).ok() }
@@ -1557,223 +1985,654 @@ impl IRTCClient_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Media_DirectShow", feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IRTCClient {}
-windows_core::imp::define_interface!(IRTCClient2, IRTCClient2_Vtbl, 0x0c91d71d_1064_42da_bfa5_572beb8eea84);
-impl core::ops::Deref for IRTCClient2 {
-    type Target = IRTCClient;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-windows_core::imp::interface_hierarchy!(IRTCClient2, windows_core::IUnknown, IRTCClient);
-impl IRTCClient2 {
-    pub unsafe fn put_AnswerMode(&self, entype: RTC_SESSION_TYPE, enmode: RTC_ANSWER_MODE) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).put_AnswerMode)(windows_core::Interface::as_raw(self), entype, enmode).ok() }
-    }
-    pub unsafe fn get_AnswerMode(&self, entype: RTC_SESSION_TYPE) -> windows_core::Result<RTC_ANSWER_MODE> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).get_AnswerMode)(windows_core::Interface::as_raw(self), entype, &mut result__).map(|| result__)
-        }
-    }
-    pub unsafe fn InvokeTuningWizardEx(&self, hwndparent: isize, fallowaudio: super::super::Foundation::VARIANT_BOOL, fallowvideo: super::super::Foundation::VARIANT_BOOL) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).InvokeTuningWizardEx)(windows_core::Interface::as_raw(self), hwndparent, fallowaudio, fallowvideo).ok() }
-    }
-    pub unsafe fn Version(&self) -> windows_core::Result<i32> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).Version)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
-        }
-    }
-    pub unsafe fn SetClientName(&self, bstrclientname: &windows_core::BSTR) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetClientName)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(bstrclientname)).ok() }
-    }
-    pub unsafe fn SetClientCurVer(&self, bstrclientcurver: &windows_core::BSTR) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetClientCurVer)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(bstrclientcurver)).ok() }
-    }
-    pub unsafe fn InitializeEx(&self, lflags: i32) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).InitializeEx)(windows_core::Interface::as_raw(self), lflags).ok() }
-    }
-    pub unsafe fn CreateSessionWithDescription<P2>(&self, bstrcontenttype: &windows_core::BSTR, bstrsessiondescription: &windows_core::BSTR, pprofile: P2, lflags: i32) -> windows_core::Result<IRTCSession2>
-    where
-        P2: windows_core::Param<IRTCProfile>,
-    {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).CreateSessionWithDescription)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(bstrcontenttype), core::mem::transmute_copy(bstrsessiondescription), pprofile.param().abi(), lflags, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+impl<Identity: windows_core::IUnknownImpl + IRTCClient_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCClient_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient_Impl::Initialize(this).into()
+            }
         }
-    }
-    pub unsafe fn SetSessionDescriptionManager<P0>(&self, psessiondescriptionmanager: P0) -> windows_core::Result<()>
-    where
-        P0: windows_core::Param<IRTCSessionDescriptionManager>,
-    {
-        unsafe { (windows_core::Interface::vtable(self).SetSessionDescriptionManager)(windows_core::Interface::as_raw(self), psessiondescriptionmanager.param().abi()).ok() }
-    }
-    pub unsafe fn put_PreferredSecurityLevel(&self, ensecuritytype: RTC_SECURITY_TYPE, ensecuritylevel: RTC_SECURITY_LEVEL) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).put_PreferredSecurityLevel)(windows_core::Interface::as_raw(self), ensecuritytype, ensecuritylevel).ok() }
-    }
-    pub unsafe fn get_PreferredSecurityLevel(&self, ensecuritytype: RTC_SECURITY_TYPE) -> windows_core::Result<RTC_SECURITY_LEVEL> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).get_PreferredSecurityLevel)(windows_core::Interface::as_raw(self), ensecuritytype, &mut result__).map(|| result__)
+        unsafe extern "system" fn Shutdown<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient_Impl::Shutdown(this).into()
+            }
         }
-    }
-    pub unsafe fn put_AllowedPorts(&self, ltransport: i32, enlistenmode: RTC_LISTEN_MODE) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).put_AllowedPorts)(windows_core::Interface::as_raw(self), ltransport, enlistenmode).ok() }
-    }
-    pub unsafe fn get_AllowedPorts(&self, ltransport: i32) -> windows_core::Result<RTC_LISTEN_MODE> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).get_AllowedPorts)(windows_core::Interface::as_raw(self), ltransport, &mut result__).map(|| result__)
+        unsafe extern "system" fn PrepareForShutdown<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient_Impl::PrepareForShutdown(this).into()
+            }
         }
-    }
-}
-#[repr(C)]
-#[doc(hidden)]
-pub struct IRTCClient2_Vtbl {
-    pub base__: IRTCClient_Vtbl,
-    pub put_AnswerMode: unsafe extern "system" fn(*mut core::ffi::c_void, RTC_SESSION_TYPE, RTC_ANSWER_MODE) -> windows_core::HRESULT,
-    pub get_AnswerMode: unsafe extern "system" fn(*mut core::ffi::c_void, RTC_SESSION_TYPE, *mut RTC_ANSWER_MODE) -> windows_core::HRESULT,
-    pub InvokeTuningWizardEx: unsafe extern "system" fn(*mut core::ffi::c_void, isize, super::super::Foundation::VARIANT_BOOL, super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT,
-    pub Version: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
-    pub SetClientName: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub SetClientCurVer: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub InitializeEx: unsafe extern "system" fn(*mut core::ffi::c_void, i32) -> windows_core::HRESULT,
-    pub CreateSessionWithDescription: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void, i32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub SetSessionDescriptionManager: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub put_PreferredSecurityLevel: unsafe extern "system" fn(*mut core::ffi::c_void, RTC_SECURITY_TYPE, RTC_SECURITY_LEVEL) -> windows_core::HRESULT,
-    pub get_PreferredSecurityLevel: unsafe extern "system" fn(*mut core::ffi::c_void, RTC_SECURITY_TYPE, *mut RTC_SECURITY_LEVEL) -> windows_core::HRESULT,
-    pub put_AllowedPorts: unsafe extern "system" fn(*mut core::ffi::c_void, i32, RTC_LISTEN_MODE) -> windows_core::HRESULT,
-    pub get_AllowedPorts: unsafe extern "system" fn(*mut core::ffi::c_void, i32, *mut RTC_LISTEN_MODE) -> windows_core::HRESULT,
-}
-#[cfg(all(feature = "Win32_Media_DirectShow", feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-pub trait IRTCClient2_Impl: IRTCClient_Impl {
-    fn put_AnswerMode(&self, entype: RTC_SESSION_TYPE, enmode: RTC_ANSWER_MODE) -> windows_core::Result<()>;
-    fn get_AnswerMode(&self, entype: RTC_SESSION_TYPE) -> windows_core::Result<RTC_ANSWER_MODE>;
-    fn InvokeTuningWizardEx(&self, hwndparent: isize, fallowaudio: super::super::Foundation::VARIANT_BOOL, fallowvideo: super::super::Foundation::VARIANT_BOOL) -> windows_core::Result<()>;
-    fn Version(&self) -> windows_core::Result<i32>;
-    fn SetClientName(&self, bstrclientname: &windows_core::BSTR) -> windows_core::Result<()>;
-    fn SetClientCurVer(&self, bstrclientcurver: &windows_core::BSTR) -> windows_core::Result<()>;
-    fn InitializeEx(&self, lflags: i32) -> windows_core::Result<()>;
-    fn CreateSessionWithDescription(&self, bstrcontenttype: &windows_core::BSTR, bstrsessiondescription: &windows_core::BSTR, pprofile: windows_core::Ref<IRTCProfile>, lflags: i32) -> windows_core::Result<IRTCSession2>;
-    fn SetSessionDescriptionManager(&self, psessiondescriptionmanager: windows_core::Ref<IRTCSessionDescriptionManager>) -> windows_core::Result<()>;
-    fn put_PreferredSecurityLevel(&self, ensecuritytype: RTC_SECURITY_TYPE, ensecuritylevel: RTC_SECURITY_LEVEL) -> windows_core::Result<()>;
-    fn get_PreferredSecurityLevel(&self, ensecuritytype: RTC_SECURITY_TYPE) -> windows_core::Result<RTC_SECURITY_LEVEL>;
-    fn put_AllowedPorts(&self, ltransport: i32, enlistenmode: RTC_LISTEN_MODE) -> windows_core::Result<()>;
-    fn get_AllowedPorts(&self, ltransport: i32) -> windows_core::Result<RTC_LISTEN_MODE>;
-}
-#[cfg(all(feature = "Win32_Media_DirectShow", feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl IRTCClient2_Vtbl {
-    pub const fn new<Identity: IRTCClient2_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn put_AnswerMode<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, entype: RTC_SESSION_TYPE, enmode: RTC_ANSWER_MODE) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetEventFilter<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, lfilter: i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IRTCClient2_Impl::put_AnswerMode(this, core::mem::transmute_copy(&entype), core::mem::transmute_copy(&enmode)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient_Impl::SetEventFilter(this, core::mem::transmute_copy(&lfilter)).into()
             }
         }
-        unsafe extern "system" fn get_AnswerMode<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, entype: RTC_SESSION_TYPE, penmode: *mut RTC_ANSWER_MODE) -> windows_core::HRESULT {
+        unsafe extern "system" fn EventFilter<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, plfilter: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IRTCClient2_Impl::get_AnswerMode(this, core::mem::transmute_copy(&entype)) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient_Impl::EventFilter(this) {
                     Ok(ok__) => {
-                        penmode.write(core::mem::transmute(ok__));
+                        plfilter.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn InvokeTuningWizardEx<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hwndparent: isize, fallowaudio: super::super::Foundation::VARIANT_BOOL, fallowvideo: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetPreferredMediaTypes<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, lmediatypes: i32, fpersistent: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IRTCClient2_Impl::InvokeTuningWizardEx(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&fallowaudio), core::mem::transmute_copy(&fallowvideo)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient_Impl::SetPreferredMediaTypes(this, core::mem::transmute_copy(&lmediatypes), core::mem::transmute_copy(&fpersistent)).into()
             }
         }
-        unsafe extern "system" fn Version<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, plversion: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn PreferredMediaTypes<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, plmediatypes: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IRTCClient2_Impl::Version(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient_Impl::PreferredMediaTypes(this) {
                     Ok(ok__) => {
-                        plversion.write(core::mem::transmute(ok__));
+                        plmediatypes.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn SetClientName<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrclientname: *mut core::ffi::c_void) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IRTCClient2_Impl::SetClientName(this, core::mem::transmute(&bstrclientname)).into()
-            }
-        }
-        unsafe extern "system" fn SetClientCurVer<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrclientcurver: *mut core::ffi::c_void) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IRTCClient2_Impl::SetClientCurVer(this, core::mem::transmute(&bstrclientcurver)).into()
-            }
-        }
-        unsafe extern "system" fn InitializeEx<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn MediaCapabilities<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, plmediatypes: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IRTCClient2_Impl::InitializeEx(this, core::mem::transmute_copy(&lflags)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient_Impl::MediaCapabilities(this) {
+                    Ok(ok__) => {
+                        plmediatypes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
             }
         }
-        unsafe extern "system" fn CreateSessionWithDescription<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrcontenttype: *mut core::ffi::c_void, bstrsessiondescription: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void, lflags: i32, ppsession2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateSession<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, entype: RTC_SESSION_TYPE, bstrlocalphoneuri: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void, lflags: i32, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IRTCClient2_Impl::CreateSessionWithDescription(this, core::mem::transmute(&bstrcontenttype), core::mem::transmute(&bstrsessiondescription), core::mem::transmute_copy(&pprofile), core::mem::transmute_copy(&lflags)) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient_Impl::CreateSession(this, core::mem::transmute_copy(&entype), core::mem::transmute(&bstrlocalphoneuri), core::mem::transmute_copy(&pprofile), core::mem::transmute_copy(&lflags)) {
                     Ok(ok__) => {
-                        ppsession2.write(core::mem::transmute(ok__));
+                        ppsession.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn SetSessionDescriptionManager<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, psessiondescriptionmanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetListenForIncomingSessions<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, enlisten: RTC_LISTEN_MODE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IRTCClient2_Impl::SetSessionDescriptionManager(this, core::mem::transmute_copy(&psessiondescriptionmanager)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient_Impl::SetListenForIncomingSessions(this, core::mem::transmute_copy(&enlisten)).into()
             }
         }
-        unsafe extern "system" fn put_PreferredSecurityLevel<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ensecuritytype: RTC_SECURITY_TYPE, ensecuritylevel: RTC_SECURITY_LEVEL) -> windows_core::HRESULT {
+        unsafe extern "system" fn ListenForIncomingSessions<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, penlisten: *mut RTC_LISTEN_MODE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IRTCClient2_Impl::put_PreferredSecurityLevel(this, core::mem::transmute_copy(&ensecuritytype), core::mem::transmute_copy(&ensecuritylevel)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient_Impl::ListenForIncomingSessions(this) {
+                    Ok(ok__) => {
+                        penlisten.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
             }
         }
-        unsafe extern "system" fn get_PreferredSecurityLevel<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ensecuritytype: RTC_SECURITY_TYPE, pensecuritylevel: *mut RTC_SECURITY_LEVEL) -> windows_core::HRESULT {
+        unsafe extern "system" fn get_NetworkAddresses<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, ftcp: super::super::Foundation::VARIANT_BOOL, fexternal: super::super::Foundation::VARIANT_BOOL, pvaddresses: *mut super::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IRTCClient2_Impl::get_PreferredSecurityLevel(this, core::mem::transmute_copy(&ensecuritytype)) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient_Impl::get_NetworkAddresses(this, core::mem::transmute_copy(&ftcp), core::mem::transmute_copy(&fexternal)) {
                     Ok(ok__) => {
-                        pensecuritylevel.write(core::mem::transmute(ok__));
+                        pvaddresses.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn put_AllowedPorts<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ltransport: i32, enlistenmode: RTC_LISTEN_MODE) -> windows_core::HRESULT {
+        unsafe extern "system" fn put_Volume<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, endevice: RTC_AUDIO_DEVICE, lvolume: i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                IRTCClient2_Impl::put_AllowedPorts(this, core::mem::transmute_copy(&ltransport), core::mem::transmute_copy(&enlistenmode)).into()
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient_Impl::put_Volume(this, core::mem::transmute_copy(&endevice), core::mem::transmute_copy(&lvolume)).into()
             }
         }
-        unsafe extern "system" fn get_AllowedPorts<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ltransport: i32, penlistenmode: *mut RTC_LISTEN_MODE) -> windows_core::HRESULT {
+        unsafe extern "system" fn get_Volume<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, endevice: RTC_AUDIO_DEVICE, plvolume: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IRTCClient2_Impl::get_AllowedPorts(this, core::mem::transmute_copy(&ltransport)) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient_Impl::get_Volume(this, core::mem::transmute_copy(&endevice)) {
                     Ok(ok__) => {
-                        penlistenmode.write(core::mem::transmute(ok__));
+                        plvolume.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        Self {
+        unsafe extern "system" fn put_AudioMuted<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, endevice: RTC_AUDIO_DEVICE, fmuted: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient_Impl::put_AudioMuted(this, core::mem::transmute_copy(&endevice), core::mem::transmute_copy(&fmuted)).into()
+            }
+        }
+        unsafe extern "system" fn get_AudioMuted<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, endevice: RTC_AUDIO_DEVICE, pfmuted: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient_Impl::get_AudioMuted(this, core::mem::transmute_copy(&endevice)) {
+                    Ok(ok__) => {
+                        pfmuted.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_IVideoWindow<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, endevice: RTC_VIDEO_DEVICE, ppivideowindow: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient_Impl::get_IVideoWindow(this, core::mem::transmute_copy(&endevice)) {
+                    Ok(ok__) => {
+                        ppivideowindow.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn put_PreferredAudioDevice<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, endevice: RTC_AUDIO_DEVICE, bstrdevicename: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient_Impl::put_PreferredAudioDevice(this, core::mem::transmute_copy(&endevice), core::mem::transmute(&bstrdevicename)).into()
+            }
+        }
+        unsafe extern "system" fn get_PreferredAudioDevice<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, endevice: RTC_AUDIO_DEVICE, pbstrdevicename: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient_Impl::get_PreferredAudioDevice(this, core::mem::transmute_copy(&endevice)) {
+                    Ok(ok__) => {
+                        pbstrdevicename.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn put_PreferredVolume<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, endevice: RTC_AUDIO_DEVICE, lvolume: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient_Impl::put_PreferredVolume(this, core::mem::transmute_copy(&endevice), core::mem::transmute_copy(&lvolume)).into()
+            }
+        }
+        unsafe extern "system" fn get_PreferredVolume<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, endevice: RTC_AUDIO_DEVICE, plvolume: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient_Impl::get_PreferredVolume(this, core::mem::transmute_copy(&endevice)) {
+                    Ok(ok__) => {
+                        plvolume.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPreferredAEC<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, benable: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient_Impl::SetPreferredAEC(this, core::mem::transmute_copy(&benable)).into()
+            }
+        }
+        unsafe extern "system" fn PreferredAEC<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, pbenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient_Impl::PreferredAEC(this) {
+                    Ok(ok__) => {
+                        pbenabled.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPreferredVideoDevice<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, bstrdevicename: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient_Impl::SetPreferredVideoDevice(this, core::mem::transmute(&bstrdevicename)).into()
+            }
+        }
+        unsafe extern "system" fn PreferredVideoDevice<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, pbstrdevicename: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient_Impl::PreferredVideoDevice(this) {
+                    Ok(ok__) => {
+                        pbstrdevicename.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ActiveMedia<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, plmediatype: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient_Impl::ActiveMedia(this) {
+                    Ok(ok__) => {
+                        plmediatype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMaxBitrate<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, lmaxbitrate: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient_Impl::SetMaxBitrate(this, core::mem::transmute_copy(&lmaxbitrate)).into()
+            }
+        }
+        unsafe extern "system" fn MaxBitrate<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, plmaxbitrate: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient_Impl::MaxBitrate(this) {
+                    Ok(ok__) => {
+                        plmaxbitrate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTemporalSpatialTradeOff<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, lvalue: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient_Impl::SetTemporalSpatialTradeOff(this, core::mem::transmute_copy(&lvalue)).into()
+            }
+        }
+        unsafe extern "system" fn TemporalSpatialTradeOff<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, plvalue: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient_Impl::TemporalSpatialTradeOff(this) {
+                    Ok(ok__) => {
+                        plvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NetworkQuality<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, plnetworkquality: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient_Impl::NetworkQuality(this) {
+                    Ok(ok__) => {
+                        plnetworkquality.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StartT120Applet<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, enapplet: RTC_T120_APPLET) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient_Impl::StartT120Applet(this, core::mem::transmute_copy(&enapplet)).into()
+            }
+        }
+        unsafe extern "system" fn StopT120Applets<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient_Impl::StopT120Applets(this).into()
+            }
+        }
+        unsafe extern "system" fn get_IsT120AppletRunning<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, enapplet: RTC_T120_APPLET, pfrunning: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient_Impl::get_IsT120AppletRunning(this, core::mem::transmute_copy(&enapplet)) {
+                    Ok(ok__) => {
+                        pfrunning.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LocalUserURI<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, pbstruseruri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient_Impl::LocalUserURI(this) {
+                    Ok(ok__) => {
+                        pbstruseruri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLocalUserURI<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, bstruseruri: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient_Impl::SetLocalUserURI(this, core::mem::transmute(&bstruseruri)).into()
+            }
+        }
+        unsafe extern "system" fn LocalUserName<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, pbstrusername: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient_Impl::LocalUserName(this) {
+                    Ok(ok__) => {
+                        pbstrusername.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetLocalUserName<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, bstrusername: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient_Impl::SetLocalUserName(this, core::mem::transmute(&bstrusername)).into()
+            }
+        }
+        unsafe extern "system" fn PlayRing<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, entype: RTC_RING_TYPE, bplay: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient_Impl::PlayRing(this, core::mem::transmute_copy(&entype), core::mem::transmute_copy(&bplay)).into()
+            }
+        }
+        unsafe extern "system" fn SendDTMF<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, endtmf: RTC_DTMF) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient_Impl::SendDTMF(this, core::mem::transmute_copy(&endtmf)).into()
+            }
+        }
+        unsafe extern "system" fn InvokeTuningWizard<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, hwndparent: isize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient_Impl::InvokeTuningWizard(this, core::mem::transmute_copy(&hwndparent)).into()
+            }
+        }
+        unsafe extern "system" fn IsTuned<Identity: windows_core::IUnknownImpl + IRTCClient_Impl>(this: *mut core::ffi::c_void, pftuned: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient_Impl::IsTuned(this) {
+                    Ok(ok__) => {
+                        pftuned.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            Shutdown: Shutdown::<Identity>,
+            PrepareForShutdown: PrepareForShutdown::<Identity>,
+            SetEventFilter: SetEventFilter::<Identity>,
+            EventFilter: EventFilter::<Identity>,
+            SetPreferredMediaTypes: SetPreferredMediaTypes::<Identity>,
+            PreferredMediaTypes: PreferredMediaTypes::<Identity>,
+            MediaCapabilities: MediaCapabilities::<Identity>,
+            CreateSession: CreateSession::<Identity>,
+            SetListenForIncomingSessions: SetListenForIncomingSessions::<Identity>,
+            ListenForIncomingSessions: ListenForIncomingSessions::<Identity>,
+            get_NetworkAddresses: get_NetworkAddresses::<Identity>,
+            put_Volume: put_Volume::<Identity>,
+            get_Volume: get_Volume::<Identity>,
+            put_AudioMuted: put_AudioMuted::<Identity>,
+            get_AudioMuted: get_AudioMuted::<Identity>,
+            get_IVideoWindow: get_IVideoWindow::<Identity>,
+            put_PreferredAudioDevice: put_PreferredAudioDevice::<Identity>,
+            get_PreferredAudioDevice: get_PreferredAudioDevice::<Identity>,
+            put_PreferredVolume: put_PreferredVolume::<Identity>,
+            get_PreferredVolume: get_PreferredVolume::<Identity>,
+            SetPreferredAEC: SetPreferredAEC::<Identity>,
+            PreferredAEC: PreferredAEC::<Identity>,
+            SetPreferredVideoDevice: SetPreferredVideoDevice::<Identity>,
+            PreferredVideoDevice: PreferredVideoDevice::<Identity>,
+            ActiveMedia: ActiveMedia::<Identity>,
+            SetMaxBitrate: SetMaxBitrate::<Identity>,
+            MaxBitrate: MaxBitrate::<Identity>,
+            SetTemporalSpatialTradeOff: SetTemporalSpatialTradeOff::<Identity>,
+            TemporalSpatialTradeOff: TemporalSpatialTradeOff::<Identity>,
+            NetworkQuality: NetworkQuality::<Identity>,
+            StartT120Applet: StartT120Applet::<Identity>,
+            StopT120Applets: StopT120Applets::<Identity>,
+            get_IsT120AppletRunning: get_IsT120AppletRunning::<Identity>,
+            LocalUserURI: LocalUserURI::<Identity>,
+            SetLocalUserURI: SetLocalUserURI::<Identity>,
+            LocalUserName: LocalUserName::<Identity>,
+            SetLocalUserName: SetLocalUserName::<Identity>,
+            PlayRing: PlayRing::<Identity>,
+            SendDTMF: SendDTMF::<Identity>,
+            InvokeTuningWizard: InvokeTuningWizard::<Identity>,
+            IsTuned: IsTuned::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Media_DirectShow", feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IRTCClient {}
+windows_core::imp::define_interface!(IRTCClient2, IRTCClient2_Vtbl, 0x0c91d71d_1064_42da_bfa5_572beb8eea84);
+impl core::ops::Deref for IRTCClient2 {
+    type Target = IRTCClient;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+windows_core::imp::interface_hierarchy!(IRTCClient2, windows_core::IUnknown, IRTCClient);
+impl IRTCClient2 {
+    pub unsafe fn put_AnswerMode(&self, entype: RTC_SESSION_TYPE, enmode: RTC_ANSWER_MODE) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).put_AnswerMode)(windows_core::Interface::as_raw(self), entype, enmode).ok() }
+    }
+    pub unsafe fn get_AnswerMode(&self, entype: RTC_SESSION_TYPE) -> windows_core::Result<RTC_ANSWER_MODE> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).get_AnswerMode)(windows_core::Interface::as_raw(self), entype, &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn InvokeTuningWizardEx(&self, hwndparent: isize, fallowaudio: super::super::Foundation::VARIANT_BOOL, fallowvideo: super::super::Foundation::VARIANT_BOOL) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).InvokeTuningWizardEx)(windows_core::Interface::as_raw(self), hwndparent, fallowaudio, fallowvideo).ok() }
+    }
+    pub unsafe fn Version(&self) -> windows_core::Result<i32> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).Version)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn SetClientName(&self, bstrclientname: &windows_core::BSTR) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetClientName)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(bstrclientname)).ok() }
+    }
+    pub unsafe fn SetClientCurVer(&self, bstrclientcurver: &windows_core::BSTR) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetClientCurVer)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(bstrclientcurver)).ok() }
+    }
+    pub unsafe fn InitializeEx(&self, lflags: i32) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).InitializeEx)(windows_core::Interface::as_raw(self), lflags).ok() }
+    }
+    pub unsafe fn CreateSessionWithDescription<P2>(&self, bstrcontenttype: &windows_core::BSTR, bstrsessiondescription: &windows_core::BSTR, pprofile: P2, lflags: i32) -> windows_core::Result<IRTCSession2>
+    where
+        P2: windows_core::Param<IRTCProfile>,
+    {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).CreateSessionWithDescription)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(bstrcontenttype), core::mem::transmute_copy(bstrsessiondescription), pprofile.param().abi(), lflags, &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn SetSessionDescriptionManager<P0>(&self, psessiondescriptionmanager: P0) -> windows_core::Result<()>
+    where
+        P0: windows_core::Param<IRTCSessionDescriptionManager>,
+    {
+        unsafe { (windows_core::Interface::vtable(self).SetSessionDescriptionManager)(windows_core::Interface::as_raw(self), psessiondescriptionmanager.param().abi()).ok() }
+    }
+    pub unsafe fn put_PreferredSecurityLevel(&self, ensecuritytype: RTC_SECURITY_TYPE, ensecuritylevel: RTC_SECURITY_LEVEL) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).put_PreferredSecurityLevel)(windows_core::Interface::as_raw(self), ensecuritytype, ensecuritylevel).ok() }
+    }
+    pub unsafe fn get_PreferredSecurityLevel(&self, ensecuritytype: RTC_SECURITY_TYPE) -> windows_core::Result<RTC_SECURITY_LEVEL> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).get_PreferredSecurityLevel)(windows_core::Interface::as_raw(self), ensecuritytype, &mut result__).map(|| result__)
+        }
+    }
+    pub unsafe fn put_AllowedPorts(&self, ltransport: i32, enlistenmode: RTC_LISTEN_MODE) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).put_AllowedPorts)(windows_core::Interface::as_raw(self), ltransport, enlistenmode).ok() }
+    }
+    pub unsafe fn get_AllowedPorts(&self, ltransport: i32) -> windows_core::Result<RTC_LISTEN_MODE> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).get_AllowedPorts)(windows_core::Interface::as_raw(self), ltransport, &mut result__).map(|| result__)
+        }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IRTCClient2_Vtbl {
+    pub base__: IRTCClient_Vtbl,
+    pub put_AnswerMode: unsafe extern "system" fn(*mut core::ffi::c_void, RTC_SESSION_TYPE, RTC_ANSWER_MODE) -> windows_core::HRESULT,
+    pub get_AnswerMode: unsafe extern "system" fn(*mut core::ffi::c_void, RTC_SESSION_TYPE, *mut RTC_ANSWER_MODE) -> windows_core::HRESULT,
+    pub InvokeTuningWizardEx: unsafe extern "system" fn(*mut core::ffi::c_void, isize, super::super::Foundation::VARIANT_BOOL, super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT,
+    pub Version: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
+    pub SetClientName: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub SetClientCurVer: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub InitializeEx: unsafe extern "system" fn(*mut core::ffi::c_void, i32) -> windows_core::HRESULT,
+    pub CreateSessionWithDescription: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void, *mut core::ffi::c_void, i32, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub SetSessionDescriptionManager: unsafe extern "system" fn(*mut core::ffi::c_void, *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub put_PreferredSecurityLevel: unsafe extern "system" fn(*mut core::ffi::c_void, RTC_SECURITY_TYPE, RTC_SECURITY_LEVEL) -> windows_core::HRESULT,
+    pub get_PreferredSecurityLevel: unsafe extern "system" fn(*mut core::ffi::c_void, RTC_SECURITY_TYPE, *mut RTC_SECURITY_LEVEL) -> windows_core::HRESULT,
+    pub put_AllowedPorts: unsafe extern "system" fn(*mut core::ffi::c_void, i32, RTC_LISTEN_MODE) -> windows_core::HRESULT,
+    pub get_AllowedPorts: unsafe extern "system" fn(*mut core::ffi::c_void, i32, *mut RTC_LISTEN_MODE) -> windows_core::HRESULT,
+}
+#[cfg(all(feature = "Win32_Media_DirectShow", feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+pub trait IRTCClient2_Impl: IRTCClient_Impl {
+    fn put_AnswerMode(&self, entype: RTC_SESSION_TYPE, enmode: RTC_ANSWER_MODE) -> windows_core::Result<()>;
+    fn get_AnswerMode(&self, entype: RTC_SESSION_TYPE) -> windows_core::Result<RTC_ANSWER_MODE>;
+    fn InvokeTuningWizardEx(&self, hwndparent: isize, fallowaudio: super::super::Foundation::VARIANT_BOOL, fallowvideo: super::super::Foundation::VARIANT_BOOL) -> windows_core::Result<()>;
+    fn Version(&self) -> windows_core::Result<i32>;
+    fn SetClientName(&self, bstrclientname: &windows_core::BSTR) -> windows_core::Result<()>;
+    fn SetClientCurVer(&self, bstrclientcurver: &windows_core::BSTR) -> windows_core::Result<()>;
+    fn InitializeEx(&self, lflags: i32) -> windows_core::Result<()>;
+    fn CreateSessionWithDescription(&self, bstrcontenttype: &windows_core::BSTR, bstrsessiondescription: &windows_core::BSTR, pprofile: windows_core::Ref<IRTCProfile>, lflags: i32) -> windows_core::Result<IRTCSession2>;
+    fn SetSessionDescriptionManager(&self, psessiondescriptionmanager: windows_core::Ref<IRTCSessionDescriptionManager>) -> windows_core::Result<()>;
+    fn put_PreferredSecurityLevel(&self, ensecuritytype: RTC_SECURITY_TYPE, ensecuritylevel: RTC_SECURITY_LEVEL) -> windows_core::Result<()>;
+    fn get_PreferredSecurityLevel(&self, ensecuritytype: RTC_SECURITY_TYPE) -> windows_core::Result<RTC_SECURITY_LEVEL>;
+    fn put_AllowedPorts(&self, ltransport: i32, enlistenmode: RTC_LISTEN_MODE) -> windows_core::Result<()>;
+    fn get_AllowedPorts(&self, ltransport: i32) -> windows_core::Result<RTC_LISTEN_MODE>;
+}
+#[cfg(all(feature = "Win32_Media_DirectShow", feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl IRTCClient2_Vtbl {
+    pub const fn new<Identity: IRTCClient2_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn put_AnswerMode<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, entype: RTC_SESSION_TYPE, enmode: RTC_ANSWER_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IRTCClient2_Impl::put_AnswerMode(this, core::mem::transmute_copy(&entype), core::mem::transmute_copy(&enmode)).into()
+            }
+        }
+        unsafe extern "system" fn get_AnswerMode<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, entype: RTC_SESSION_TYPE, penmode: *mut RTC_ANSWER_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCClient2_Impl::get_AnswerMode(this, core::mem::transmute_copy(&entype)) {
+                    Ok(ok__) => {
+                        penmode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InvokeTuningWizardEx<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hwndparent: isize, fallowaudio: super::super::Foundation::VARIANT_BOOL, fallowvideo: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IRTCClient2_Impl::InvokeTuningWizardEx(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&fallowaudio), core::mem::transmute_copy(&fallowvideo)).into()
+            }
+        }
+        unsafe extern "system" fn Version<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, plversion: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCClient2_Impl::Version(this) {
+                    Ok(ok__) => {
+                        plversion.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetClientName<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrclientname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IRTCClient2_Impl::SetClientName(this, core::mem::transmute(&bstrclientname)).into()
+            }
+        }
+        unsafe extern "system" fn SetClientCurVer<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrclientcurver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IRTCClient2_Impl::SetClientCurVer(this, core::mem::transmute(&bstrclientcurver)).into()
+            }
+        }
+        unsafe extern "system" fn InitializeEx<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IRTCClient2_Impl::InitializeEx(this, core::mem::transmute_copy(&lflags)).into()
+            }
+        }
+        unsafe extern "system" fn CreateSessionWithDescription<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrcontenttype: *mut core::ffi::c_void, bstrsessiondescription: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void, lflags: i32, ppsession2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCClient2_Impl::CreateSessionWithDescription(this, core::mem::transmute(&bstrcontenttype), core::mem::transmute(&bstrsessiondescription), core::mem::transmute_copy(&pprofile), core::mem::transmute_copy(&lflags)) {
+                    Ok(ok__) => {
+                        ppsession2.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSessionDescriptionManager<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, psessiondescriptionmanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IRTCClient2_Impl::SetSessionDescriptionManager(this, core::mem::transmute_copy(&psessiondescriptionmanager)).into()
+            }
+        }
+        unsafe extern "system" fn put_PreferredSecurityLevel<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ensecuritytype: RTC_SECURITY_TYPE, ensecuritylevel: RTC_SECURITY_LEVEL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IRTCClient2_Impl::put_PreferredSecurityLevel(this, core::mem::transmute_copy(&ensecuritytype), core::mem::transmute_copy(&ensecuritylevel)).into()
+            }
+        }
+        unsafe extern "system" fn get_PreferredSecurityLevel<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ensecuritytype: RTC_SECURITY_TYPE, pensecuritylevel: *mut RTC_SECURITY_LEVEL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCClient2_Impl::get_PreferredSecurityLevel(this, core::mem::transmute_copy(&ensecuritytype)) {
+                    Ok(ok__) => {
+                        pensecuritylevel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn put_AllowedPorts<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ltransport: i32, enlistenmode: RTC_LISTEN_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IRTCClient2_Impl::put_AllowedPorts(this, core::mem::transmute_copy(&ltransport), core::mem::transmute_copy(&enlistenmode)).into()
+            }
+        }
+        unsafe extern "system" fn get_AllowedPorts<Identity: IRTCClient2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ltransport: i32, penlistenmode: *mut RTC_LISTEN_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCClient2_Impl::get_AllowedPorts(this, core::mem::transmute_copy(&ltransport)) {
+                    Ok(ok__) => {
+                        penlistenmode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
             base__: IRTCClient_Vtbl::new::<Identity, OFFSET>(),
             put_AnswerMode: put_AnswerMode::<Identity, OFFSET>,
             get_AnswerMode: get_AnswerMode::<Identity, OFFSET>,
@@ -1795,6 +2654,138 @@ impl IRTCClient2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_Media_DirectShow", feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCClient2_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCClient2_Vtbl
+where
+    IRTCClient_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn put_AnswerMode<Identity: windows_core::IUnknownImpl + IRTCClient2_Impl>(this: *mut core::ffi::c_void, entype: RTC_SESSION_TYPE, enmode: RTC_ANSWER_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient2_Impl::put_AnswerMode(this, core::mem::transmute_copy(&entype), core::mem::transmute_copy(&enmode)).into()
+            }
+        }
+        unsafe extern "system" fn get_AnswerMode<Identity: windows_core::IUnknownImpl + IRTCClient2_Impl>(this: *mut core::ffi::c_void, entype: RTC_SESSION_TYPE, penmode: *mut RTC_ANSWER_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient2_Impl::get_AnswerMode(this, core::mem::transmute_copy(&entype)) {
+                    Ok(ok__) => {
+                        penmode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InvokeTuningWizardEx<Identity: windows_core::IUnknownImpl + IRTCClient2_Impl>(this: *mut core::ffi::c_void, hwndparent: isize, fallowaudio: super::super::Foundation::VARIANT_BOOL, fallowvideo: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient2_Impl::InvokeTuningWizardEx(this, core::mem::transmute_copy(&hwndparent), core::mem::transmute_copy(&fallowaudio), core::mem::transmute_copy(&fallowvideo)).into()
+            }
+        }
+        unsafe extern "system" fn Version<Identity: windows_core::IUnknownImpl + IRTCClient2_Impl>(this: *mut core::ffi::c_void, plversion: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient2_Impl::Version(this) {
+                    Ok(ok__) => {
+                        plversion.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetClientName<Identity: windows_core::IUnknownImpl + IRTCClient2_Impl>(this: *mut core::ffi::c_void, bstrclientname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient2_Impl::SetClientName(this, core::mem::transmute(&bstrclientname)).into()
+            }
+        }
+        unsafe extern "system" fn SetClientCurVer<Identity: windows_core::IUnknownImpl + IRTCClient2_Impl>(this: *mut core::ffi::c_void, bstrclientcurver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient2_Impl::SetClientCurVer(this, core::mem::transmute(&bstrclientcurver)).into()
+            }
+        }
+        unsafe extern "system" fn InitializeEx<Identity: windows_core::IUnknownImpl + IRTCClient2_Impl>(this: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient2_Impl::InitializeEx(this, core::mem::transmute_copy(&lflags)).into()
+            }
+        }
+        unsafe extern "system" fn CreateSessionWithDescription<Identity: windows_core::IUnknownImpl + IRTCClient2_Impl>(this: *mut core::ffi::c_void, bstrcontenttype: *mut core::ffi::c_void, bstrsessiondescription: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void, lflags: i32, ppsession2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient2_Impl::CreateSessionWithDescription(this, core::mem::transmute(&bstrcontenttype), core::mem::transmute(&bstrsessiondescription), core::mem::transmute_copy(&pprofile), core::mem::transmute_copy(&lflags)) {
+                    Ok(ok__) => {
+                        ppsession2.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSessionDescriptionManager<Identity: windows_core::IUnknownImpl + IRTCClient2_Impl>(this: *mut core::ffi::c_void, psessiondescriptionmanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient2_Impl::SetSessionDescriptionManager(this, core::mem::transmute_copy(&psessiondescriptionmanager)).into()
+            }
+        }
+        unsafe extern "system" fn put_PreferredSecurityLevel<Identity: windows_core::IUnknownImpl + IRTCClient2_Impl>(this: *mut core::ffi::c_void, ensecuritytype: RTC_SECURITY_TYPE, ensecuritylevel: RTC_SECURITY_LEVEL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient2_Impl::put_PreferredSecurityLevel(this, core::mem::transmute_copy(&ensecuritytype), core::mem::transmute_copy(&ensecuritylevel)).into()
+            }
+        }
+        unsafe extern "system" fn get_PreferredSecurityLevel<Identity: windows_core::IUnknownImpl + IRTCClient2_Impl>(this: *mut core::ffi::c_void, ensecuritytype: RTC_SECURITY_TYPE, pensecuritylevel: *mut RTC_SECURITY_LEVEL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient2_Impl::get_PreferredSecurityLevel(this, core::mem::transmute_copy(&ensecuritytype)) {
+                    Ok(ok__) => {
+                        pensecuritylevel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn put_AllowedPorts<Identity: windows_core::IUnknownImpl + IRTCClient2_Impl>(this: *mut core::ffi::c_void, ltransport: i32, enlistenmode: RTC_LISTEN_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClient2_Impl::put_AllowedPorts(this, core::mem::transmute_copy(&ltransport), core::mem::transmute_copy(&enlistenmode)).into()
+            }
+        }
+        unsafe extern "system" fn get_AllowedPorts<Identity: windows_core::IUnknownImpl + IRTCClient2_Impl>(this: *mut core::ffi::c_void, ltransport: i32, penlistenmode: *mut RTC_LISTEN_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClient2_Impl::get_AllowedPorts(this, core::mem::transmute_copy(&ltransport)) {
+                    Ok(ok__) => {
+                        penlistenmode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IRTCClient_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            put_AnswerMode: put_AnswerMode::<Identity>,
+            get_AnswerMode: get_AnswerMode::<Identity>,
+            InvokeTuningWizardEx: InvokeTuningWizardEx::<Identity>,
+            Version: Version::<Identity>,
+            SetClientName: SetClientName::<Identity>,
+            SetClientCurVer: SetClientCurVer::<Identity>,
+            InitializeEx: InitializeEx::<Identity>,
+            CreateSessionWithDescription: CreateSessionWithDescription::<Identity>,
+            SetSessionDescriptionManager: SetSessionDescriptionManager::<Identity>,
+            put_PreferredSecurityLevel: put_PreferredSecurityLevel::<Identity>,
+            get_PreferredSecurityLevel: get_PreferredSecurityLevel::<Identity>,
+            put_AllowedPorts: put_AllowedPorts::<Identity>,
+            get_AllowedPorts: get_AllowedPorts::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_Media_DirectShow", feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCClient2 {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCClientEvent, IRTCClientEvent_Vtbl, 0x2b493b7a_3cba_4170_9c8b_76a9dacdd644);
@@ -1869,6 +2860,43 @@ impl IRTCClientEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCClientEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCClientEvent_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EventType<Identity: windows_core::IUnknownImpl + IRTCClientEvent_Impl>(this: *mut core::ffi::c_void, peneventtype: *mut RTC_CLIENT_EVENT_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientEvent_Impl::EventType(this) {
+                    Ok(ok__) => {
+                        peneventtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Client<Identity: windows_core::IUnknownImpl + IRTCClientEvent_Impl>(this: *mut core::ffi::c_void, ppclient: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientEvent_Impl::Client(this) {
+                    Ok(ok__) => {
+                        ppclient.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EventType: EventType::<Identity>,
+            Client: Client::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCClientEvent {}
 windows_core::imp::define_interface!(IRTCClientPortManagement, IRTCClientPortManagement_Vtbl, 0xd5df3f03_4bde_4417_aefe_71177bdaea66);
 windows_core::imp::interface_hierarchy!(IRTCClientPortManagement, windows_core::IUnknown);
@@ -1927,6 +2955,37 @@ impl IRTCClientPortManagement_Vtbl {
         iid == &<IRTCClientPortManagement as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRTCClientPortManagement_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCClientPortManagement_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StartListenAddressAndPort<Identity: windows_core::IUnknownImpl + IRTCClientPortManagement_Impl>(this: *mut core::ffi::c_void, bstrinternallocaladdress: *mut core::ffi::c_void, linternallocalport: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClientPortManagement_Impl::StartListenAddressAndPort(this, core::mem::transmute(&bstrinternallocaladdress), core::mem::transmute_copy(&linternallocalport)).into()
+            }
+        }
+        unsafe extern "system" fn StopListenAddressAndPort<Identity: windows_core::IUnknownImpl + IRTCClientPortManagement_Impl>(this: *mut core::ffi::c_void, bstrinternallocaladdress: *mut core::ffi::c_void, linternallocalport: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClientPortManagement_Impl::StopListenAddressAndPort(this, core::mem::transmute(&bstrinternallocaladdress), core::mem::transmute_copy(&linternallocalport)).into()
+            }
+        }
+        unsafe extern "system" fn GetPortRange<Identity: windows_core::IUnknownImpl + IRTCClientPortManagement_Impl>(this: *mut core::ffi::c_void, enporttype: RTC_PORT_TYPE, plminvalue: *mut i32, plmaxvalue: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClientPortManagement_Impl::GetPortRange(this, core::mem::transmute_copy(&enporttype), core::mem::transmute_copy(&plminvalue), core::mem::transmute_copy(&plmaxvalue)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            StartListenAddressAndPort: StartListenAddressAndPort::<Identity>,
+            StopListenAddressAndPort: StopListenAddressAndPort::<Identity>,
+            GetPortRange: GetPortRange::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRTCClientPortManagement {}
 windows_core::imp::define_interface!(IRTCClientPresence, IRTCClientPresence_Vtbl, 0x11c3cbcc_0744_42d1_968a_51aa1bb274c6);
 windows_core::imp::interface_hierarchy!(IRTCClientPresence, windows_core::IUnknown);
@@ -2281,10 +3340,207 @@ impl IRTCClientPresence_Vtbl {
             PrivacyMode: PrivacyMode::<Identity, OFFSET>,
             SetPrivacyMode: SetPrivacyMode::<Identity, OFFSET>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IRTCClientPresence as windows_core::Interface>::IID
-    }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCClientPresence as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCClientPresence_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCClientPresence_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnablePresence<Identity: windows_core::IUnknownImpl + IRTCClientPresence_Impl>(this: *mut core::ffi::c_void, fusestorage: super::super::Foundation::VARIANT_BOOL, varstorage: super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClientPresence_Impl::EnablePresence(this, core::mem::transmute_copy(&fusestorage), core::mem::transmute(&varstorage)).into()
+            }
+        }
+        unsafe extern "system" fn Export<Identity: windows_core::IUnknownImpl + IRTCClientPresence_Impl>(this: *mut core::ffi::c_void, varstorage: super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClientPresence_Impl::Export(this, core::mem::transmute(&varstorage)).into()
+            }
+        }
+        unsafe extern "system" fn Import<Identity: windows_core::IUnknownImpl + IRTCClientPresence_Impl>(this: *mut core::ffi::c_void, varstorage: super::Variant::VARIANT, freplaceall: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClientPresence_Impl::Import(this, core::mem::transmute(&varstorage), core::mem::transmute_copy(&freplaceall)).into()
+            }
+        }
+        unsafe extern "system" fn EnumerateBuddies<Identity: windows_core::IUnknownImpl + IRTCClientPresence_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientPresence_Impl::EnumerateBuddies(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Buddies<Identity: windows_core::IUnknownImpl + IRTCClientPresence_Impl>(this: *mut core::ffi::c_void, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientPresence_Impl::Buddies(this) {
+                    Ok(ok__) => {
+                        ppcollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_Buddy<Identity: windows_core::IUnknownImpl + IRTCClientPresence_Impl>(this: *mut core::ffi::c_void, bstrpresentityuri: *mut core::ffi::c_void, ppbuddy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientPresence_Impl::get_Buddy(this, core::mem::transmute(&bstrpresentityuri)) {
+                    Ok(ok__) => {
+                        ppbuddy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddBuddy<Identity: windows_core::IUnknownImpl + IRTCClientPresence_Impl>(this: *mut core::ffi::c_void, bstrpresentityuri: *mut core::ffi::c_void, bstrusername: *mut core::ffi::c_void, bstrdata: *mut core::ffi::c_void, fpersistent: super::super::Foundation::VARIANT_BOOL, pprofile: *mut core::ffi::c_void, lflags: i32, ppbuddy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientPresence_Impl::AddBuddy(this, core::mem::transmute(&bstrpresentityuri), core::mem::transmute(&bstrusername), core::mem::transmute(&bstrdata), core::mem::transmute_copy(&fpersistent), core::mem::transmute_copy(&pprofile), core::mem::transmute_copy(&lflags)) {
+                    Ok(ok__) => {
+                        ppbuddy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveBuddy<Identity: windows_core::IUnknownImpl + IRTCClientPresence_Impl>(this: *mut core::ffi::c_void, pbuddy: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClientPresence_Impl::RemoveBuddy(this, core::mem::transmute_copy(&pbuddy)).into()
+            }
+        }
+        unsafe extern "system" fn EnumerateWatchers<Identity: windows_core::IUnknownImpl + IRTCClientPresence_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientPresence_Impl::EnumerateWatchers(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Watchers<Identity: windows_core::IUnknownImpl + IRTCClientPresence_Impl>(this: *mut core::ffi::c_void, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientPresence_Impl::Watchers(this) {
+                    Ok(ok__) => {
+                        ppcollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_Watcher<Identity: windows_core::IUnknownImpl + IRTCClientPresence_Impl>(this: *mut core::ffi::c_void, bstrpresentityuri: *mut core::ffi::c_void, ppwatcher: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientPresence_Impl::get_Watcher(this, core::mem::transmute(&bstrpresentityuri)) {
+                    Ok(ok__) => {
+                        ppwatcher.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddWatcher<Identity: windows_core::IUnknownImpl + IRTCClientPresence_Impl>(this: *mut core::ffi::c_void, bstrpresentityuri: *mut core::ffi::c_void, bstrusername: *mut core::ffi::c_void, bstrdata: *mut core::ffi::c_void, fblocked: super::super::Foundation::VARIANT_BOOL, fpersistent: super::super::Foundation::VARIANT_BOOL, ppwatcher: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientPresence_Impl::AddWatcher(this, core::mem::transmute(&bstrpresentityuri), core::mem::transmute(&bstrusername), core::mem::transmute(&bstrdata), core::mem::transmute_copy(&fblocked), core::mem::transmute_copy(&fpersistent)) {
+                    Ok(ok__) => {
+                        ppwatcher.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveWatcher<Identity: windows_core::IUnknownImpl + IRTCClientPresence_Impl>(this: *mut core::ffi::c_void, pwatcher: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClientPresence_Impl::RemoveWatcher(this, core::mem::transmute_copy(&pwatcher)).into()
+            }
+        }
+        unsafe extern "system" fn SetLocalPresenceInfo<Identity: windows_core::IUnknownImpl + IRTCClientPresence_Impl>(this: *mut core::ffi::c_void, enstatus: RTC_PRESENCE_STATUS, bstrnotes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClientPresence_Impl::SetLocalPresenceInfo(this, core::mem::transmute_copy(&enstatus), core::mem::transmute(&bstrnotes)).into()
+            }
+        }
+        unsafe extern "system" fn OfferWatcherMode<Identity: windows_core::IUnknownImpl + IRTCClientPresence_Impl>(this: *mut core::ffi::c_void, penmode: *mut RTC_OFFER_WATCHER_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientPresence_Impl::OfferWatcherMode(this) {
+                    Ok(ok__) => {
+                        penmode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetOfferWatcherMode<Identity: windows_core::IUnknownImpl + IRTCClientPresence_Impl>(this: *mut core::ffi::c_void, enmode: RTC_OFFER_WATCHER_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClientPresence_Impl::SetOfferWatcherMode(this, core::mem::transmute_copy(&enmode)).into()
+            }
+        }
+        unsafe extern "system" fn PrivacyMode<Identity: windows_core::IUnknownImpl + IRTCClientPresence_Impl>(this: *mut core::ffi::c_void, penmode: *mut RTC_PRIVACY_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientPresence_Impl::PrivacyMode(this) {
+                    Ok(ok__) => {
+                        penmode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPrivacyMode<Identity: windows_core::IUnknownImpl + IRTCClientPresence_Impl>(this: *mut core::ffi::c_void, enmode: RTC_PRIVACY_MODE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClientPresence_Impl::SetPrivacyMode(this, core::mem::transmute_copy(&enmode)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnablePresence: EnablePresence::<Identity>,
+            Export: Export::<Identity>,
+            Import: Import::<Identity>,
+            EnumerateBuddies: EnumerateBuddies::<Identity>,
+            Buddies: Buddies::<Identity>,
+            get_Buddy: get_Buddy::<Identity>,
+            AddBuddy: AddBuddy::<Identity>,
+            RemoveBuddy: RemoveBuddy::<Identity>,
+            EnumerateWatchers: EnumerateWatchers::<Identity>,
+            Watchers: Watchers::<Identity>,
+            get_Watcher: get_Watcher::<Identity>,
+            AddWatcher: AddWatcher::<Identity>,
+            RemoveWatcher: RemoveWatcher::<Identity>,
+            SetLocalPresenceInfo: SetLocalPresenceInfo::<Identity>,
+            OfferWatcherMode: OfferWatcherMode::<Identity>,
+            SetOfferWatcherMode: SetOfferWatcherMode::<Identity>,
+            PrivacyMode: PrivacyMode::<Identity>,
+            SetPrivacyMode: SetPrivacyMode::<Identity>,
+        }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCClientPresence {}
@@ -2593,6 +3849,170 @@ impl IRTCClientPresence2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCClientPresence2_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCClientPresence2_Vtbl
+where
+    IRTCClientPresence_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnablePresenceEx<Identity: windows_core::IUnknownImpl + IRTCClientPresence2_Impl>(this: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void, varstorage: super::Variant::VARIANT, lflags: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClientPresence2_Impl::EnablePresenceEx(this, core::mem::transmute_copy(&pprofile), core::mem::transmute(&varstorage), core::mem::transmute_copy(&lflags)).into()
+            }
+        }
+        unsafe extern "system" fn DisablePresence<Identity: windows_core::IUnknownImpl + IRTCClientPresence2_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClientPresence2_Impl::DisablePresence(this).into()
+            }
+        }
+        unsafe extern "system" fn AddGroup<Identity: windows_core::IUnknownImpl + IRTCClientPresence2_Impl>(this: *mut core::ffi::c_void, bstrgroupname: *mut core::ffi::c_void, bstrdata: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void, lflags: i32, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientPresence2_Impl::AddGroup(this, core::mem::transmute(&bstrgroupname), core::mem::transmute(&bstrdata), core::mem::transmute_copy(&pprofile), core::mem::transmute_copy(&lflags)) {
+                    Ok(ok__) => {
+                        ppgroup.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveGroup<Identity: windows_core::IUnknownImpl + IRTCClientPresence2_Impl>(this: *mut core::ffi::c_void, pgroup: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClientPresence2_Impl::RemoveGroup(this, core::mem::transmute_copy(&pgroup)).into()
+            }
+        }
+        unsafe extern "system" fn EnumerateGroups<Identity: windows_core::IUnknownImpl + IRTCClientPresence2_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientPresence2_Impl::EnumerateGroups(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Groups<Identity: windows_core::IUnknownImpl + IRTCClientPresence2_Impl>(this: *mut core::ffi::c_void, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientPresence2_Impl::Groups(this) {
+                    Ok(ok__) => {
+                        ppcollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_Group<Identity: windows_core::IUnknownImpl + IRTCClientPresence2_Impl>(this: *mut core::ffi::c_void, bstrgroupname: *mut core::ffi::c_void, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientPresence2_Impl::get_Group(this, core::mem::transmute(&bstrgroupname)) {
+                    Ok(ok__) => {
+                        ppgroup.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddWatcherEx<Identity: windows_core::IUnknownImpl + IRTCClientPresence2_Impl>(this: *mut core::ffi::c_void, bstrpresentityuri: *mut core::ffi::c_void, bstrusername: *mut core::ffi::c_void, bstrdata: *mut core::ffi::c_void, enstate: RTC_WATCHER_STATE, fpersistent: super::super::Foundation::VARIANT_BOOL, enscope: RTC_ACE_SCOPE, pprofile: *mut core::ffi::c_void, lflags: i32, ppwatcher: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientPresence2_Impl::AddWatcherEx(this, core::mem::transmute(&bstrpresentityuri), core::mem::transmute(&bstrusername), core::mem::transmute(&bstrdata), core::mem::transmute_copy(&enstate), core::mem::transmute_copy(&fpersistent), core::mem::transmute_copy(&enscope), core::mem::transmute_copy(&pprofile), core::mem::transmute_copy(&lflags)) {
+                    Ok(ok__) => {
+                        ppwatcher.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_WatcherEx<Identity: windows_core::IUnknownImpl + IRTCClientPresence2_Impl>(this: *mut core::ffi::c_void, enmode: RTC_WATCHER_MATCH_MODE, bstrpresentityuri: *mut core::ffi::c_void, ppwatcher: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientPresence2_Impl::get_WatcherEx(this, core::mem::transmute_copy(&enmode), core::mem::transmute(&bstrpresentityuri)) {
+                    Ok(ok__) => {
+                        ppwatcher.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn put_PresenceProperty<Identity: windows_core::IUnknownImpl + IRTCClientPresence2_Impl>(this: *mut core::ffi::c_void, enproperty: RTC_PRESENCE_PROPERTY, bstrproperty: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClientPresence2_Impl::put_PresenceProperty(this, core::mem::transmute_copy(&enproperty), core::mem::transmute(&bstrproperty)).into()
+            }
+        }
+        unsafe extern "system" fn get_PresenceProperty<Identity: windows_core::IUnknownImpl + IRTCClientPresence2_Impl>(this: *mut core::ffi::c_void, enproperty: RTC_PRESENCE_PROPERTY, pbstrproperty: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientPresence2_Impl::get_PresenceProperty(this, core::mem::transmute_copy(&enproperty)) {
+                    Ok(ok__) => {
+                        pbstrproperty.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPresenceData<Identity: windows_core::IUnknownImpl + IRTCClientPresence2_Impl>(this: *mut core::ffi::c_void, bstrnamespace: *mut core::ffi::c_void, bstrdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClientPresence2_Impl::SetPresenceData(this, core::mem::transmute(&bstrnamespace), core::mem::transmute(&bstrdata)).into()
+            }
+        }
+        unsafe extern "system" fn GetPresenceData<Identity: windows_core::IUnknownImpl + IRTCClientPresence2_Impl>(this: *mut core::ffi::c_void, pbstrnamespace: *mut *mut core::ffi::c_void, pbstrdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClientPresence2_Impl::GetPresenceData(this, core::mem::transmute_copy(&pbstrnamespace), core::mem::transmute_copy(&pbstrdata)).into()
+            }
+        }
+        unsafe extern "system" fn GetLocalPresenceInfo<Identity: windows_core::IUnknownImpl + IRTCClientPresence2_Impl>(this: *mut core::ffi::c_void, penstatus: *mut RTC_PRESENCE_STATUS, pbstrnotes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClientPresence2_Impl::GetLocalPresenceInfo(this, core::mem::transmute_copy(&penstatus), core::mem::transmute_copy(&pbstrnotes)).into()
+            }
+        }
+        unsafe extern "system" fn AddBuddyEx<Identity: windows_core::IUnknownImpl + IRTCClientPresence2_Impl>(this: *mut core::ffi::c_void, bstrpresentityuri: *mut core::ffi::c_void, bstrusername: *mut core::ffi::c_void, bstrdata: *mut core::ffi::c_void, fpersistent: super::super::Foundation::VARIANT_BOOL, ensubscriptiontype: RTC_BUDDY_SUBSCRIPTION_TYPE, pprofile: *mut core::ffi::c_void, lflags: i32, ppbuddy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientPresence2_Impl::AddBuddyEx(this, core::mem::transmute(&bstrpresentityuri), core::mem::transmute(&bstrusername), core::mem::transmute(&bstrdata), core::mem::transmute_copy(&fpersistent), core::mem::transmute_copy(&ensubscriptiontype), core::mem::transmute_copy(&pprofile), core::mem::transmute_copy(&lflags)) {
+                    Ok(ok__) => {
+                        ppbuddy.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IRTCClientPresence_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnablePresenceEx: EnablePresenceEx::<Identity>,
+            DisablePresence: DisablePresence::<Identity>,
+            AddGroup: AddGroup::<Identity>,
+            RemoveGroup: RemoveGroup::<Identity>,
+            EnumerateGroups: EnumerateGroups::<Identity>,
+            Groups: Groups::<Identity>,
+            get_Group: get_Group::<Identity>,
+            AddWatcherEx: AddWatcherEx::<Identity>,
+            get_WatcherEx: get_WatcherEx::<Identity>,
+            put_PresenceProperty: put_PresenceProperty::<Identity>,
+            get_PresenceProperty: get_PresenceProperty::<Identity>,
+            SetPresenceData: SetPresenceData::<Identity>,
+            GetPresenceData: GetPresenceData::<Identity>,
+            GetLocalPresenceInfo: GetLocalPresenceInfo::<Identity>,
+            AddBuddyEx: AddBuddyEx::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCClientPresence2 {}
 windows_core::imp::define_interface!(IRTCClientProvisioning, IRTCClientProvisioning_Vtbl, 0xb9f5cf06_65b9_4a80_a0e6_73cae3ef3822);
 windows_core::imp::interface_hierarchy!(IRTCClientProvisioning, windows_core::IUnknown);
@@ -2748,6 +4168,90 @@ impl IRTCClientProvisioning_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IRTCClientProvisioning_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCClientProvisioning_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateProfile<Identity: windows_core::IUnknownImpl + IRTCClientProvisioning_Impl>(this: *mut core::ffi::c_void, bstrprofilexml: *mut core::ffi::c_void, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientProvisioning_Impl::CreateProfile(this, core::mem::transmute(&bstrprofilexml)) {
+                    Ok(ok__) => {
+                        ppprofile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnableProfile<Identity: windows_core::IUnknownImpl + IRTCClientProvisioning_Impl>(this: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void, lregisterflags: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClientProvisioning_Impl::EnableProfile(this, core::mem::transmute_copy(&pprofile), core::mem::transmute_copy(&lregisterflags)).into()
+            }
+        }
+        unsafe extern "system" fn DisableProfile<Identity: windows_core::IUnknownImpl + IRTCClientProvisioning_Impl>(this: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClientProvisioning_Impl::DisableProfile(this, core::mem::transmute_copy(&pprofile)).into()
+            }
+        }
+        unsafe extern "system" fn EnumerateProfiles<Identity: windows_core::IUnknownImpl + IRTCClientProvisioning_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientProvisioning_Impl::EnumerateProfiles(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Profiles<Identity: windows_core::IUnknownImpl + IRTCClientProvisioning_Impl>(this: *mut core::ffi::c_void, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientProvisioning_Impl::Profiles(this) {
+                    Ok(ok__) => {
+                        ppcollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetProfile<Identity: windows_core::IUnknownImpl + IRTCClientProvisioning_Impl>(this: *mut core::ffi::c_void, bstruseraccount: *mut core::ffi::c_void, bstruserpassword: *mut core::ffi::c_void, bstruseruri: *mut core::ffi::c_void, bstrserver: *mut core::ffi::c_void, ltransport: i32, lcookie: isize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClientProvisioning_Impl::GetProfile(this, core::mem::transmute(&bstruseraccount), core::mem::transmute(&bstruserpassword), core::mem::transmute(&bstruseruri), core::mem::transmute(&bstrserver), core::mem::transmute_copy(&ltransport), core::mem::transmute_copy(&lcookie)).into()
+            }
+        }
+        unsafe extern "system" fn SessionCapabilities<Identity: windows_core::IUnknownImpl + IRTCClientProvisioning_Impl>(this: *mut core::ffi::c_void, plsupportedsessions: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCClientProvisioning_Impl::SessionCapabilities(this) {
+                    Ok(ok__) => {
+                        plsupportedsessions.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateProfile: CreateProfile::<Identity>,
+            EnableProfile: EnableProfile::<Identity>,
+            DisableProfile: DisableProfile::<Identity>,
+            EnumerateProfiles: EnumerateProfiles::<Identity>,
+            Profiles: Profiles::<Identity>,
+            GetProfile: GetProfile::<Identity>,
+            SessionCapabilities: SessionCapabilities::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IRTCClientProvisioning {}
 windows_core::imp::define_interface!(IRTCClientProvisioning2, IRTCClientProvisioning2_Vtbl, 0xa70909b5_f40e_4587_bb75_e6bc0845023e);
 impl core::ops::Deref for IRTCClientProvisioning2 {
@@ -2791,6 +4295,21 @@ impl IRTCClientProvisioning2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IRTCClientProvisioning2_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCClientProvisioning2_Vtbl
+where
+    IRTCClientProvisioning_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnableProfileEx<Identity: windows_core::IUnknownImpl + IRTCClientProvisioning2_Impl>(this: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void, lregisterflags: i32, lroamingflags: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCClientProvisioning2_Impl::EnableProfileEx(this, core::mem::transmute_copy(&pprofile), core::mem::transmute_copy(&lregisterflags), core::mem::transmute_copy(&lroamingflags)).into()
+            }
+        }
+        Self { base__: <IRTCClientProvisioning_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, EnableProfileEx: EnableProfileEx::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IRTCClientProvisioning2 {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCCollection, IRTCCollection_Vtbl, 0xec7c8096_b918_4044_94f1_e4fba0361d5c);
@@ -2894,6 +4413,56 @@ impl IRTCCollection_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCCollection_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCCollection_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + IRTCCollection_Impl>(this: *mut core::ffi::c_void, lcount: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCCollection_Impl::Count(this) {
+                    Ok(ok__) => {
+                        lcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_Item<Identity: windows_core::IUnknownImpl + IRTCCollection_Impl>(this: *mut core::ffi::c_void, index: i32, pvariant: *mut super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCCollection_Impl::get_Item(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        pvariant.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + IRTCCollection_Impl>(this: *mut core::ffi::c_void, ppnewenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCCollection_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        ppnewenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Count: Count::<Identity>,
+            get_Item: get_Item::<Identity>,
+            _NewEnum: _NewEnum::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCCollection {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCDispatchEventNotification, IRTCDispatchEventNotification_Vtbl, 0x176ddfbe_fec0_4d55_bc87_84cff1ef7f91);
@@ -2924,6 +4493,13 @@ impl IRTCDispatchEventNotification_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCDispatchEventNotification_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCDispatchEventNotification_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCDispatchEventNotification {}
 windows_core::imp::define_interface!(IRTCEnumBuddies, IRTCEnumBuddies_Vtbl, 0xf7296917_5569_4b3b_b3af_98d1144b2b87);
 windows_core::imp::interface_hierarchy!(IRTCEnumBuddies, windows_core::IUnknown);
@@ -2981,7 +4557,54 @@ impl IRTCEnumBuddies_Vtbl {
         }
         unsafe extern "system" fn Clone<Identity: IRTCEnumBuddies_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCEnumBuddies_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            Next: Next::<Identity, OFFSET>,
+            Reset: Reset::<Identity, OFFSET>,
+            Skip: Skip::<Identity, OFFSET>,
+            Clone: Clone::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCEnumBuddies as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IRTCEnumBuddies_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCEnumBuddies_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IRTCEnumBuddies_Impl>(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCEnumBuddies_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&ppelements), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IRTCEnumBuddies_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCEnumBuddies_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IRTCEnumBuddies_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCEnumBuddies_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IRTCEnumBuddies_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRTCEnumBuddies_Impl::Clone(this) {
                     Ok(ok__) => {
                         ppenum.write(core::mem::transmute(ok__));
@@ -2992,16 +4615,13 @@ impl IRTCEnumBuddies_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            Next: Next::<Identity, OFFSET>,
-            Reset: Reset::<Identity, OFFSET>,
-            Skip: Skip::<Identity, OFFSET>,
-            Clone: Clone::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Reset: Reset::<Identity>,
+            Skip: Skip::<Identity>,
+            Clone: Clone::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IRTCEnumBuddies as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IRTCEnumBuddies {}
 windows_core::imp::define_interface!(IRTCEnumGroups, IRTCEnumGroups_Vtbl, 0x742378d6_a141_4415_8f27_35d99076cf5d);
@@ -3082,6 +4702,50 @@ impl IRTCEnumGroups_Vtbl {
         iid == &<IRTCEnumGroups as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRTCEnumGroups_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCEnumGroups_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IRTCEnumGroups_Impl>(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCEnumGroups_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&ppelements), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IRTCEnumGroups_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCEnumGroups_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IRTCEnumGroups_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCEnumGroups_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IRTCEnumGroups_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCEnumGroups_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Reset: Reset::<Identity>,
+            Skip: Skip::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRTCEnumGroups {}
 windows_core::imp::define_interface!(IRTCEnumParticipants, IRTCEnumParticipants_Vtbl, 0xfcd56f29_4a4f_41b2_ba5c_f5bccc060bf6);
 windows_core::imp::interface_hierarchy!(IRTCEnumParticipants, windows_core::IUnknown);
@@ -3161,6 +4825,50 @@ impl IRTCEnumParticipants_Vtbl {
         iid == &<IRTCEnumParticipants as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRTCEnumParticipants_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCEnumParticipants_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IRTCEnumParticipants_Impl>(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCEnumParticipants_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&ppelements), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IRTCEnumParticipants_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCEnumParticipants_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IRTCEnumParticipants_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCEnumParticipants_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IRTCEnumParticipants_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCEnumParticipants_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Reset: Reset::<Identity>,
+            Skip: Skip::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRTCEnumParticipants {}
 windows_core::imp::define_interface!(IRTCEnumPresenceDevices, IRTCEnumPresenceDevices_Vtbl, 0x708c2ab7_8bf8_42f8_8c7d_635197ad5539);
 windows_core::imp::interface_hierarchy!(IRTCEnumPresenceDevices, windows_core::IUnknown);
@@ -3240,6 +4948,50 @@ impl IRTCEnumPresenceDevices_Vtbl {
         iid == &<IRTCEnumPresenceDevices as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRTCEnumPresenceDevices_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCEnumPresenceDevices_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IRTCEnumPresenceDevices_Impl>(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCEnumPresenceDevices_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&ppelements), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IRTCEnumPresenceDevices_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCEnumPresenceDevices_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IRTCEnumPresenceDevices_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCEnumPresenceDevices_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IRTCEnumPresenceDevices_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCEnumPresenceDevices_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Reset: Reset::<Identity>,
+            Skip: Skip::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRTCEnumPresenceDevices {}
 windows_core::imp::define_interface!(IRTCEnumProfiles, IRTCEnumProfiles_Vtbl, 0x29b7c41c_ed82_4bca_84ad_39d5101b58e3);
 windows_core::imp::interface_hierarchy!(IRTCEnumProfiles, windows_core::IUnknown);
@@ -3319,6 +5071,50 @@ impl IRTCEnumProfiles_Vtbl {
         iid == &<IRTCEnumProfiles as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRTCEnumProfiles_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCEnumProfiles_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IRTCEnumProfiles_Impl>(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCEnumProfiles_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&ppelements), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IRTCEnumProfiles_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCEnumProfiles_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IRTCEnumProfiles_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCEnumProfiles_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IRTCEnumProfiles_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCEnumProfiles_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Reset: Reset::<Identity>,
+            Skip: Skip::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRTCEnumProfiles {}
 windows_core::imp::define_interface!(IRTCEnumUserSearchResults, IRTCEnumUserSearchResults_Vtbl, 0x83d4d877_aa5d_4a5b_8d0e_002a8067e0e8);
 windows_core::imp::interface_hierarchy!(IRTCEnumUserSearchResults, windows_core::IUnknown);
@@ -3398,6 +5194,50 @@ impl IRTCEnumUserSearchResults_Vtbl {
         iid == &<IRTCEnumUserSearchResults as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRTCEnumUserSearchResults_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCEnumUserSearchResults_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IRTCEnumUserSearchResults_Impl>(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCEnumUserSearchResults_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&ppelements), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IRTCEnumUserSearchResults_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCEnumUserSearchResults_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IRTCEnumUserSearchResults_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCEnumUserSearchResults_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IRTCEnumUserSearchResults_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCEnumUserSearchResults_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Reset: Reset::<Identity>,
+            Skip: Skip::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRTCEnumUserSearchResults {}
 windows_core::imp::define_interface!(IRTCEnumWatchers, IRTCEnumWatchers_Vtbl, 0xa87d55d7_db74_4ed1_9ca4_77a0e41b413e);
 windows_core::imp::interface_hierarchy!(IRTCEnumWatchers, windows_core::IUnknown);
@@ -3477,6 +5317,50 @@ impl IRTCEnumWatchers_Vtbl {
         iid == &<IRTCEnumWatchers as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRTCEnumWatchers_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCEnumWatchers_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IRTCEnumWatchers_Impl>(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCEnumWatchers_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&ppelements), core::mem::transmute_copy(&pceltfetched)).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IRTCEnumWatchers_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCEnumWatchers_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IRTCEnumWatchers_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCEnumWatchers_Impl::Skip(this, core::mem::transmute_copy(&celt)).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IRTCEnumWatchers_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCEnumWatchers_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Reset: Reset::<Identity>,
+            Skip: Skip::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRTCEnumWatchers {}
 windows_core::imp::define_interface!(IRTCEventNotification, IRTCEventNotification_Vtbl, 0x13fa24c7_5748_4b21_91f5_7397609ce747);
 windows_core::imp::interface_hierarchy!(IRTCEventNotification, windows_core::IUnknown);
@@ -3518,6 +5402,21 @@ impl IRTCEventNotification_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IRTCEventNotification_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCEventNotification_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Event<Identity: windows_core::IUnknownImpl + IRTCEventNotification_Impl>(this: *mut core::ffi::c_void, rtcevent: RTC_EVENT, pevent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCEventNotification_Impl::Event(this, core::mem::transmute_copy(&rtcevent), core::mem::transmute_copy(&pevent)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Event: Event::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IRTCEventNotification {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCInfoEvent, IRTCInfoEvent_Vtbl, 0x4e1d68ae_1912_4f49_b2c3_594fadfd425f);
@@ -3638,6 +5537,69 @@ impl IRTCInfoEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCInfoEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCInfoEvent_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Session<Identity: windows_core::IUnknownImpl + IRTCInfoEvent_Impl>(this: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCInfoEvent_Impl::Session(this) {
+                    Ok(ok__) => {
+                        ppsession.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Participant<Identity: windows_core::IUnknownImpl + IRTCInfoEvent_Impl>(this: *mut core::ffi::c_void, ppparticipant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCInfoEvent_Impl::Participant(this) {
+                    Ok(ok__) => {
+                        ppparticipant.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Info<Identity: windows_core::IUnknownImpl + IRTCInfoEvent_Impl>(this: *mut core::ffi::c_void, pbstrinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCInfoEvent_Impl::Info(this) {
+                    Ok(ok__) => {
+                        pbstrinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InfoHeader<Identity: windows_core::IUnknownImpl + IRTCInfoEvent_Impl>(this: *mut core::ffi::c_void, pbstrinfoheader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCInfoEvent_Impl::InfoHeader(this) {
+                    Ok(ok__) => {
+                        pbstrinfoheader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Session: Session::<Identity>,
+            Participant: Participant::<Identity>,
+            Info: Info::<Identity>,
+            InfoHeader: InfoHeader::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCInfoEvent {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCIntensityEvent, IRTCIntensityEvent_Vtbl, 0x4c23bf51_390c_4992_a41d_41eec05b2a4b);
@@ -3752,10 +5714,73 @@ impl IRTCIntensityEvent_Vtbl {
             Max: Max::<Identity, OFFSET>,
             Direction: Direction::<Identity, OFFSET>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IRTCIntensityEvent as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID
-    }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCIntensityEvent as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCIntensityEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCIntensityEvent_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Level<Identity: windows_core::IUnknownImpl + IRTCIntensityEvent_Impl>(this: *mut core::ffi::c_void, pllevel: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCIntensityEvent_Impl::Level(this) {
+                    Ok(ok__) => {
+                        pllevel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Min<Identity: windows_core::IUnknownImpl + IRTCIntensityEvent_Impl>(this: *mut core::ffi::c_void, plmin: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCIntensityEvent_Impl::Min(this) {
+                    Ok(ok__) => {
+                        plmin.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Max<Identity: windows_core::IUnknownImpl + IRTCIntensityEvent_Impl>(this: *mut core::ffi::c_void, plmax: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCIntensityEvent_Impl::Max(this) {
+                    Ok(ok__) => {
+                        plmax.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Direction<Identity: windows_core::IUnknownImpl + IRTCIntensityEvent_Impl>(this: *mut core::ffi::c_void, pendirection: *mut RTC_AUDIO_DEVICE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCIntensityEvent_Impl::Direction(this) {
+                    Ok(ok__) => {
+                        pendirection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Level: Level::<Identity>,
+            Min: Min::<Identity>,
+            Max: Max::<Identity>,
+            Direction: Direction::<Identity>,
+        }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCIntensityEvent {}
@@ -3857,6 +5882,56 @@ impl IRTCMediaEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCMediaEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCMediaEvent_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn MediaType<Identity: windows_core::IUnknownImpl + IRTCMediaEvent_Impl>(this: *mut core::ffi::c_void, pmediatype: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCMediaEvent_Impl::MediaType(this) {
+                    Ok(ok__) => {
+                        pmediatype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EventType<Identity: windows_core::IUnknownImpl + IRTCMediaEvent_Impl>(this: *mut core::ffi::c_void, peneventtype: *mut RTC_MEDIA_EVENT_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCMediaEvent_Impl::EventType(this) {
+                    Ok(ok__) => {
+                        peneventtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EventReason<Identity: windows_core::IUnknownImpl + IRTCMediaEvent_Impl>(this: *mut core::ffi::c_void, peneventreason: *mut RTC_MEDIA_EVENT_REASON) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCMediaEvent_Impl::EventReason(this) {
+                    Ok(ok__) => {
+                        peneventreason.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MediaType: MediaType::<Identity>,
+            EventType: EventType::<Identity>,
+            EventReason: EventReason::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCMediaEvent {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCMediaRequestEvent, IRTCMediaRequestEvent_Vtbl, 0x52572d15_148c_4d97_a36c_2da55c289d63);
@@ -4022,6 +6097,96 @@ impl IRTCMediaRequestEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCMediaRequestEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCMediaRequestEvent_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Session<Identity: windows_core::IUnknownImpl + IRTCMediaRequestEvent_Impl>(this: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCMediaRequestEvent_Impl::Session(this) {
+                    Ok(ok__) => {
+                        ppsession.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ProposedMedia<Identity: windows_core::IUnknownImpl + IRTCMediaRequestEvent_Impl>(this: *mut core::ffi::c_void, plmediatypes: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCMediaRequestEvent_Impl::ProposedMedia(this) {
+                    Ok(ok__) => {
+                        plmediatypes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CurrentMedia<Identity: windows_core::IUnknownImpl + IRTCMediaRequestEvent_Impl>(this: *mut core::ffi::c_void, plmediatypes: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCMediaRequestEvent_Impl::CurrentMedia(this) {
+                    Ok(ok__) => {
+                        plmediatypes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Accept<Identity: windows_core::IUnknownImpl + IRTCMediaRequestEvent_Impl>(this: *mut core::ffi::c_void, lmediatypes: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCMediaRequestEvent_Impl::Accept(this, core::mem::transmute_copy(&lmediatypes)).into()
+            }
+        }
+        unsafe extern "system" fn get_RemotePreferredSecurityLevel<Identity: windows_core::IUnknownImpl + IRTCMediaRequestEvent_Impl>(this: *mut core::ffi::c_void, ensecuritytype: RTC_SECURITY_TYPE, pensecuritylevel: *mut RTC_SECURITY_LEVEL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCMediaRequestEvent_Impl::get_RemotePreferredSecurityLevel(this, core::mem::transmute_copy(&ensecuritytype)) {
+                    Ok(ok__) => {
+                        pensecuritylevel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Reject<Identity: windows_core::IUnknownImpl + IRTCMediaRequestEvent_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCMediaRequestEvent_Impl::Reject(this).into()
+            }
+        }
+        unsafe extern "system" fn State<Identity: windows_core::IUnknownImpl + IRTCMediaRequestEvent_Impl>(this: *mut core::ffi::c_void, pstate: *mut RTC_REINVITE_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCMediaRequestEvent_Impl::State(this) {
+                    Ok(ok__) => {
+                        pstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Session: Session::<Identity>,
+            ProposedMedia: ProposedMedia::<Identity>,
+            CurrentMedia: CurrentMedia::<Identity>,
+            Accept: Accept::<Identity>,
+            get_RemotePreferredSecurityLevel: get_RemotePreferredSecurityLevel::<Identity>,
+            Reject: Reject::<Identity>,
+            State: State::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCMediaRequestEvent {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCMessagingEvent, IRTCMessagingEvent_Vtbl, 0xd3609541_1b29_4de5_a4ad_5aebaf319512);
@@ -4184,6 +6349,95 @@ impl IRTCMessagingEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCMessagingEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCMessagingEvent_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Session<Identity: windows_core::IUnknownImpl + IRTCMessagingEvent_Impl>(this: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCMessagingEvent_Impl::Session(this) {
+                    Ok(ok__) => {
+                        ppsession.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Participant<Identity: windows_core::IUnknownImpl + IRTCMessagingEvent_Impl>(this: *mut core::ffi::c_void, ppparticipant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCMessagingEvent_Impl::Participant(this) {
+                    Ok(ok__) => {
+                        ppparticipant.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EventType<Identity: windows_core::IUnknownImpl + IRTCMessagingEvent_Impl>(this: *mut core::ffi::c_void, peneventtype: *mut RTC_MESSAGING_EVENT_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCMessagingEvent_Impl::EventType(this) {
+                    Ok(ok__) => {
+                        peneventtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Message<Identity: windows_core::IUnknownImpl + IRTCMessagingEvent_Impl>(this: *mut core::ffi::c_void, pbstrmessage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCMessagingEvent_Impl::Message(this) {
+                    Ok(ok__) => {
+                        pbstrmessage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MessageHeader<Identity: windows_core::IUnknownImpl + IRTCMessagingEvent_Impl>(this: *mut core::ffi::c_void, pbstrmessageheader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCMessagingEvent_Impl::MessageHeader(this) {
+                    Ok(ok__) => {
+                        pbstrmessageheader.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UserStatus<Identity: windows_core::IUnknownImpl + IRTCMessagingEvent_Impl>(this: *mut core::ffi::c_void, penuserstatus: *mut RTC_MESSAGING_USER_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCMessagingEvent_Impl::UserStatus(this) {
+                    Ok(ok__) => {
+                        penuserstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Session: Session::<Identity>,
+            Participant: Participant::<Identity>,
+            EventType: EventType::<Identity>,
+            Message: Message::<Identity>,
+            MessageHeader: MessageHeader::<Identity>,
+            UserStatus: UserStatus::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCMessagingEvent {}
 windows_core::imp::define_interface!(IRTCParticipant, IRTCParticipant_Vtbl, 0xae86add5_26b1_4414_af1d_b94cd938d739);
 windows_core::imp::interface_hierarchy!(IRTCParticipant, windows_core::IUnknown);
@@ -4212,35 +6466,113 @@ impl IRTCParticipant {
             (windows_core::Interface::vtable(self).State)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
         }
     }
-    pub unsafe fn Session(&self) -> windows_core::Result<IRTCSession> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).Session)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
-        }
+    pub unsafe fn Session(&self) -> windows_core::Result<IRTCSession> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).Session)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IRTCParticipant_Vtbl {
+    pub base__: windows_core::IUnknown_Vtbl,
+    pub UserURI: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub Name: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    pub Removable: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT,
+    pub State: unsafe extern "system" fn(*mut core::ffi::c_void, *mut RTC_PARTICIPANT_STATE) -> windows_core::HRESULT,
+    pub Session: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+pub trait IRTCParticipant_Impl: windows_core::IUnknownImpl {
+    fn UserURI(&self) -> windows_core::Result<windows_core::BSTR>;
+    fn Name(&self) -> windows_core::Result<windows_core::BSTR>;
+    fn Removable(&self) -> windows_core::Result<super::super::Foundation::VARIANT_BOOL>;
+    fn State(&self) -> windows_core::Result<RTC_PARTICIPANT_STATE>;
+    fn Session(&self) -> windows_core::Result<IRTCSession>;
+}
+impl IRTCParticipant_Vtbl {
+    pub const fn new<Identity: IRTCParticipant_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn UserURI<Identity: IRTCParticipant_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstruseruri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCParticipant_Impl::UserURI(this) {
+                    Ok(ok__) => {
+                        pbstruseruri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Name<Identity: IRTCParticipant_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstrname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCParticipant_Impl::Name(this) {
+                    Ok(ok__) => {
+                        pbstrname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Removable<Identity: IRTCParticipant_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfremovable: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCParticipant_Impl::Removable(this) {
+                    Ok(ok__) => {
+                        pfremovable.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn State<Identity: IRTCParticipant_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, penstate: *mut RTC_PARTICIPANT_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCParticipant_Impl::State(this) {
+                    Ok(ok__) => {
+                        penstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Session<Identity: IRTCParticipant_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCParticipant_Impl::Session(this) {
+                    Ok(ok__) => {
+                        ppsession.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            UserURI: UserURI::<Identity, OFFSET>,
+            Name: Name::<Identity, OFFSET>,
+            Removable: Removable::<Identity, OFFSET>,
+            State: State::<Identity, OFFSET>,
+            Session: Session::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCParticipant as windows_core::Interface>::IID
     }
 }
-#[repr(C)]
-#[doc(hidden)]
-pub struct IRTCParticipant_Vtbl {
-    pub base__: windows_core::IUnknown_Vtbl,
-    pub UserURI: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub Name: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    pub Removable: unsafe extern "system" fn(*mut core::ffi::c_void, *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT,
-    pub State: unsafe extern "system" fn(*mut core::ffi::c_void, *mut RTC_PARTICIPANT_STATE) -> windows_core::HRESULT,
-    pub Session: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-pub trait IRTCParticipant_Impl: windows_core::IUnknownImpl {
-    fn UserURI(&self) -> windows_core::Result<windows_core::BSTR>;
-    fn Name(&self) -> windows_core::Result<windows_core::BSTR>;
-    fn Removable(&self) -> windows_core::Result<super::super::Foundation::VARIANT_BOOL>;
-    fn State(&self) -> windows_core::Result<RTC_PARTICIPANT_STATE>;
-    fn Session(&self) -> windows_core::Result<IRTCSession>;
-}
-impl IRTCParticipant_Vtbl {
-    pub const fn new<Identity: IRTCParticipant_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn UserURI<Identity: IRTCParticipant_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstruseruri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl<Identity: windows_core::IUnknownImpl + IRTCParticipant_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCParticipant_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn UserURI<Identity: windows_core::IUnknownImpl + IRTCParticipant_Impl>(this: *mut core::ffi::c_void, pbstruseruri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRTCParticipant_Impl::UserURI(this) {
                     Ok(ok__) => {
                         pbstruseruri.write(core::mem::transmute(ok__));
@@ -4250,9 +6582,9 @@ impl IRTCParticipant_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Name<Identity: IRTCParticipant_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstrname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + IRTCParticipant_Impl>(this: *mut core::ffi::c_void, pbstrname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRTCParticipant_Impl::Name(this) {
                     Ok(ok__) => {
                         pbstrname.write(core::mem::transmute(ok__));
@@ -4262,9 +6594,9 @@ impl IRTCParticipant_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Removable<Identity: IRTCParticipant_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfremovable: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn Removable<Identity: windows_core::IUnknownImpl + IRTCParticipant_Impl>(this: *mut core::ffi::c_void, pfremovable: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRTCParticipant_Impl::Removable(this) {
                     Ok(ok__) => {
                         pfremovable.write(core::mem::transmute(ok__));
@@ -4274,9 +6606,9 @@ impl IRTCParticipant_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn State<Identity: IRTCParticipant_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, penstate: *mut RTC_PARTICIPANT_STATE) -> windows_core::HRESULT {
+        unsafe extern "system" fn State<Identity: windows_core::IUnknownImpl + IRTCParticipant_Impl>(this: *mut core::ffi::c_void, penstate: *mut RTC_PARTICIPANT_STATE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRTCParticipant_Impl::State(this) {
                     Ok(ok__) => {
                         penstate.write(core::mem::transmute(ok__));
@@ -4286,9 +6618,9 @@ impl IRTCParticipant_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Session<Identity: IRTCParticipant_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Session<Identity: windows_core::IUnknownImpl + IRTCParticipant_Impl>(this: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRTCParticipant_Impl::Session(this) {
                     Ok(ok__) => {
                         ppsession.write(core::mem::transmute(ok__));
@@ -4299,17 +6631,14 @@ impl IRTCParticipant_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            UserURI: UserURI::<Identity, OFFSET>,
-            Name: Name::<Identity, OFFSET>,
-            Removable: Removable::<Identity, OFFSET>,
-            State: State::<Identity, OFFSET>,
-            Session: Session::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            UserURI: UserURI::<Identity>,
+            Name: Name::<Identity>,
+            Removable: Removable::<Identity>,
+            State: State::<Identity>,
+            Session: Session::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IRTCParticipant as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IRTCParticipant {}
 #[cfg(feature = "Win32_System_Com")]
@@ -4410,6 +6739,56 @@ impl IRTCParticipantStateChangeEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCParticipantStateChangeEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCParticipantStateChangeEvent_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Participant<Identity: windows_core::IUnknownImpl + IRTCParticipantStateChangeEvent_Impl>(this: *mut core::ffi::c_void, ppparticipant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCParticipantStateChangeEvent_Impl::Participant(this) {
+                    Ok(ok__) => {
+                        ppparticipant.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn State<Identity: windows_core::IUnknownImpl + IRTCParticipantStateChangeEvent_Impl>(this: *mut core::ffi::c_void, penstate: *mut RTC_PARTICIPANT_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCParticipantStateChangeEvent_Impl::State(this) {
+                    Ok(ok__) => {
+                        penstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StatusCode<Identity: windows_core::IUnknownImpl + IRTCParticipantStateChangeEvent_Impl>(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCParticipantStateChangeEvent_Impl::StatusCode(this) {
+                    Ok(ok__) => {
+                        plstatuscode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Participant: Participant::<Identity>,
+            State: State::<Identity>,
+            StatusCode: StatusCode::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCParticipantStateChangeEvent {}
 windows_core::imp::define_interface!(IRTCPortManager, IRTCPortManager_Vtbl, 0xda77c14b_6208_43ca_8ddf_5b60a0a69fac);
 windows_core::imp::interface_hierarchy!(IRTCPortManager, windows_core::IUnknown);
@@ -4468,6 +6847,37 @@ impl IRTCPortManager_Vtbl {
         iid == &<IRTCPortManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRTCPortManager_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCPortManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMapping<Identity: windows_core::IUnknownImpl + IRTCPortManager_Impl>(this: *mut core::ffi::c_void, bstrremoteaddress: *mut core::ffi::c_void, enporttype: RTC_PORT_TYPE, pbstrinternallocaladdress: *mut *mut core::ffi::c_void, plinternallocalport: *mut i32, pbstrexternallocaladdress: *mut *mut core::ffi::c_void, plexternallocalport: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCPortManager_Impl::GetMapping(this, core::mem::transmute(&bstrremoteaddress), core::mem::transmute_copy(&enporttype), core::mem::transmute_copy(&pbstrinternallocaladdress), core::mem::transmute_copy(&plinternallocalport), core::mem::transmute_copy(&pbstrexternallocaladdress), core::mem::transmute_copy(&plexternallocalport)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateRemoteAddress<Identity: windows_core::IUnknownImpl + IRTCPortManager_Impl>(this: *mut core::ffi::c_void, bstrremoteaddress: *mut core::ffi::c_void, bstrinternallocaladdress: *mut core::ffi::c_void, linternallocalport: i32, bstrexternallocaladdress: *mut core::ffi::c_void, lexternallocalport: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCPortManager_Impl::UpdateRemoteAddress(this, core::mem::transmute(&bstrremoteaddress), core::mem::transmute(&bstrinternallocaladdress), core::mem::transmute_copy(&linternallocalport), core::mem::transmute(&bstrexternallocaladdress), core::mem::transmute_copy(&lexternallocalport)).into()
+            }
+        }
+        unsafe extern "system" fn ReleaseMapping<Identity: windows_core::IUnknownImpl + IRTCPortManager_Impl>(this: *mut core::ffi::c_void, bstrinternallocaladdress: *mut core::ffi::c_void, linternallocalport: i32, bstrexternallocaladdress: *mut core::ffi::c_void, lexternallocaladdress: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCPortManager_Impl::ReleaseMapping(this, core::mem::transmute(&bstrinternallocaladdress), core::mem::transmute_copy(&linternallocalport), core::mem::transmute(&bstrexternallocaladdress), core::mem::transmute_copy(&lexternallocaladdress)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetMapping: GetMapping::<Identity>,
+            UpdateRemoteAddress: UpdateRemoteAddress::<Identity>,
+            ReleaseMapping: ReleaseMapping::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRTCPortManager {}
 windows_core::imp::define_interface!(IRTCPresenceContact, IRTCPresenceContact_Vtbl, 0x8b22f92c_cd90_42db_a733_212205c3e3df);
 windows_core::imp::interface_hierarchy!(IRTCPresenceContact, windows_core::IUnknown);
@@ -4622,6 +7032,96 @@ impl IRTCPresenceContact_Vtbl {
         iid == &<IRTCPresenceContact as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRTCPresenceContact_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCPresenceContact_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn PresentityURI<Identity: windows_core::IUnknownImpl + IRTCPresenceContact_Impl>(this: *mut core::ffi::c_void, pbstrpresentityuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCPresenceContact_Impl::PresentityURI(this) {
+                    Ok(ok__) => {
+                        pbstrpresentityuri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPresentityURI<Identity: windows_core::IUnknownImpl + IRTCPresenceContact_Impl>(this: *mut core::ffi::c_void, bstrpresentityuri: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCPresenceContact_Impl::SetPresentityURI(this, core::mem::transmute(&bstrpresentityuri)).into()
+            }
+        }
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + IRTCPresenceContact_Impl>(this: *mut core::ffi::c_void, pbstrname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCPresenceContact_Impl::Name(this) {
+                    Ok(ok__) => {
+                        pbstrname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetName<Identity: windows_core::IUnknownImpl + IRTCPresenceContact_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCPresenceContact_Impl::SetName(this, core::mem::transmute(&bstrname)).into()
+            }
+        }
+        unsafe extern "system" fn Data<Identity: windows_core::IUnknownImpl + IRTCPresenceContact_Impl>(this: *mut core::ffi::c_void, pbstrdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCPresenceContact_Impl::Data(this) {
+                    Ok(ok__) => {
+                        pbstrdata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetData<Identity: windows_core::IUnknownImpl + IRTCPresenceContact_Impl>(this: *mut core::ffi::c_void, bstrdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCPresenceContact_Impl::SetData(this, core::mem::transmute(&bstrdata)).into()
+            }
+        }
+        unsafe extern "system" fn Persistent<Identity: windows_core::IUnknownImpl + IRTCPresenceContact_Impl>(this: *mut core::ffi::c_void, pfpersistent: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCPresenceContact_Impl::Persistent(this) {
+                    Ok(ok__) => {
+                        pfpersistent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPersistent<Identity: windows_core::IUnknownImpl + IRTCPresenceContact_Impl>(this: *mut core::ffi::c_void, fpersistent: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCPresenceContact_Impl::SetPersistent(this, core::mem::transmute_copy(&fpersistent)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            PresentityURI: PresentityURI::<Identity>,
+            SetPresentityURI: SetPresentityURI::<Identity>,
+            Name: Name::<Identity>,
+            SetName: SetName::<Identity>,
+            Data: Data::<Identity>,
+            SetData: SetData::<Identity>,
+            Persistent: Persistent::<Identity>,
+            SetPersistent: SetPersistent::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRTCPresenceContact {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCPresenceDataEvent, IRTCPresenceDataEvent_Vtbl, 0x38f0e78c_8b87_4c04_a82d_aedd83c909bb);
@@ -4712,6 +7212,50 @@ impl IRTCPresenceDataEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCPresenceDataEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCPresenceDataEvent_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StatusCode<Identity: windows_core::IUnknownImpl + IRTCPresenceDataEvent_Impl>(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCPresenceDataEvent_Impl::StatusCode(this) {
+                    Ok(ok__) => {
+                        plstatuscode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StatusText<Identity: windows_core::IUnknownImpl + IRTCPresenceDataEvent_Impl>(this: *mut core::ffi::c_void, pbstrstatustext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCPresenceDataEvent_Impl::StatusText(this) {
+                    Ok(ok__) => {
+                        pbstrstatustext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPresenceData<Identity: windows_core::IUnknownImpl + IRTCPresenceDataEvent_Impl>(this: *mut core::ffi::c_void, pbstrnamespace: *mut *mut core::ffi::c_void, pbstrdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCPresenceDataEvent_Impl::GetPresenceData(this, core::mem::transmute_copy(&pbstrnamespace), core::mem::transmute_copy(&pbstrdata)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            StatusCode: StatusCode::<Identity>,
+            StatusText: StatusText::<Identity>,
+            GetPresenceData: GetPresenceData::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCPresenceDataEvent {}
 windows_core::imp::define_interface!(IRTCPresenceDevice, IRTCPresenceDevice_Vtbl, 0xbc6a90dd_ad9a_48da_9b0c_2515e38521ad);
 windows_core::imp::interface_hierarchy!(IRTCPresenceDevice, windows_core::IUnknown);
@@ -4809,6 +7353,62 @@ impl IRTCPresenceDevice_Vtbl {
         iid == &<IRTCPresenceDevice as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRTCPresenceDevice_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCPresenceDevice_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Status<Identity: windows_core::IUnknownImpl + IRTCPresenceDevice_Impl>(this: *mut core::ffi::c_void, penstatus: *mut RTC_PRESENCE_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCPresenceDevice_Impl::Status(this) {
+                    Ok(ok__) => {
+                        penstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Notes<Identity: windows_core::IUnknownImpl + IRTCPresenceDevice_Impl>(this: *mut core::ffi::c_void, pbstrnotes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCPresenceDevice_Impl::Notes(this) {
+                    Ok(ok__) => {
+                        pbstrnotes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_PresenceProperty<Identity: windows_core::IUnknownImpl + IRTCPresenceDevice_Impl>(this: *mut core::ffi::c_void, enproperty: RTC_PRESENCE_PROPERTY, pbstrproperty: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCPresenceDevice_Impl::get_PresenceProperty(this, core::mem::transmute_copy(&enproperty)) {
+                    Ok(ok__) => {
+                        pbstrproperty.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPresenceData<Identity: windows_core::IUnknownImpl + IRTCPresenceDevice_Impl>(this: *mut core::ffi::c_void, pbstrnamespace: *mut *mut core::ffi::c_void, pbstrdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCPresenceDevice_Impl::GetPresenceData(this, core::mem::transmute_copy(&pbstrnamespace), core::mem::transmute_copy(&pbstrdata)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Status: Status::<Identity>,
+            Notes: Notes::<Identity>,
+            get_PresenceProperty: get_PresenceProperty::<Identity>,
+            GetPresenceData: GetPresenceData::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRTCPresenceDevice {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCPresencePropertyEvent, IRTCPresencePropertyEvent_Vtbl, 0xf777f570_a820_49d5_86bd_e099493f1518);
@@ -4929,6 +7529,69 @@ impl IRTCPresencePropertyEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCPresencePropertyEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCPresencePropertyEvent_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StatusCode<Identity: windows_core::IUnknownImpl + IRTCPresencePropertyEvent_Impl>(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCPresencePropertyEvent_Impl::StatusCode(this) {
+                    Ok(ok__) => {
+                        plstatuscode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StatusText<Identity: windows_core::IUnknownImpl + IRTCPresencePropertyEvent_Impl>(this: *mut core::ffi::c_void, pbstrstatustext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCPresencePropertyEvent_Impl::StatusText(this) {
+                    Ok(ok__) => {
+                        pbstrstatustext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PresenceProperty<Identity: windows_core::IUnknownImpl + IRTCPresencePropertyEvent_Impl>(this: *mut core::ffi::c_void, penpresprop: *mut RTC_PRESENCE_PROPERTY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCPresencePropertyEvent_Impl::PresenceProperty(this) {
+                    Ok(ok__) => {
+                        penpresprop.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Value<Identity: windows_core::IUnknownImpl + IRTCPresencePropertyEvent_Impl>(this: *mut core::ffi::c_void, pbstrvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCPresencePropertyEvent_Impl::Value(this) {
+                    Ok(ok__) => {
+                        pbstrvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            StatusCode: StatusCode::<Identity>,
+            StatusText: StatusText::<Identity>,
+            PresenceProperty: PresenceProperty::<Identity>,
+            Value: Value::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCPresencePropertyEvent {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCPresenceStatusEvent, IRTCPresenceStatusEvent_Vtbl, 0x78673f32_4a0f_462c_89aa_ee7706707678);
@@ -5019,6 +7682,50 @@ impl IRTCPresenceStatusEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCPresenceStatusEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCPresenceStatusEvent_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StatusCode<Identity: windows_core::IUnknownImpl + IRTCPresenceStatusEvent_Impl>(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCPresenceStatusEvent_Impl::StatusCode(this) {
+                    Ok(ok__) => {
+                        plstatuscode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StatusText<Identity: windows_core::IUnknownImpl + IRTCPresenceStatusEvent_Impl>(this: *mut core::ffi::c_void, pbstrstatustext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCPresenceStatusEvent_Impl::StatusText(this) {
+                    Ok(ok__) => {
+                        pbstrstatustext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLocalPresenceInfo<Identity: windows_core::IUnknownImpl + IRTCPresenceStatusEvent_Impl>(this: *mut core::ffi::c_void, penstatus: *mut RTC_PRESENCE_STATUS, pbstrnotes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCPresenceStatusEvent_Impl::GetLocalPresenceInfo(this, core::mem::transmute_copy(&penstatus), core::mem::transmute_copy(&pbstrnotes)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            StatusCode: StatusCode::<Identity>,
+            StatusText: StatusText::<Identity>,
+            GetLocalPresenceInfo: GetLocalPresenceInfo::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCPresenceStatusEvent {}
 windows_core::imp::define_interface!(IRTCProfile, IRTCProfile_Vtbl, 0xd07eca9e_4062_4dd4_9e7d_722a49ba7303);
 windows_core::imp::interface_hierarchy!(IRTCProfile, windows_core::IUnknown);
@@ -5234,9 +7941,250 @@ impl IRTCProfile_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ProviderData<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstrdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn ProviderData<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstrdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCProfile_Impl::ProviderData(this) {
+                    Ok(ok__) => {
+                        pbstrdata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ClientName<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstrname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCProfile_Impl::ClientName(this) {
+                    Ok(ok__) => {
+                        pbstrname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ClientBanner<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfbanner: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCProfile_Impl::ClientBanner(this) {
+                    Ok(ok__) => {
+                        pfbanner.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ClientMinVer<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstrminver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCProfile_Impl::ClientMinVer(this) {
+                    Ok(ok__) => {
+                        pbstrminver.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ClientCurVer<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstrcurver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCProfile_Impl::ClientCurVer(this) {
+                    Ok(ok__) => {
+                        pbstrcurver.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ClientUpdateURI<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstrupdateuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCProfile_Impl::ClientUpdateURI(this) {
+                    Ok(ok__) => {
+                        pbstrupdateuri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ClientData<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstrdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCProfile_Impl::ClientData(this) {
+                    Ok(ok__) => {
+                        pbstrdata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UserURI<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstruseruri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCProfile_Impl::UserURI(this) {
+                    Ok(ok__) => {
+                        pbstruseruri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UserName<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstrusername: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCProfile_Impl::UserName(this) {
+                    Ok(ok__) => {
+                        pbstrusername.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UserAccount<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstruseraccount: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCProfile_Impl::UserAccount(this) {
+                    Ok(ok__) => {
+                        pbstruseraccount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCredentials<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstruseruri: *mut core::ffi::c_void, bstruseraccount: *mut core::ffi::c_void, bstrpassword: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IRTCProfile_Impl::SetCredentials(this, core::mem::transmute(&bstruseruri), core::mem::transmute(&bstruseraccount), core::mem::transmute(&bstrpassword)).into()
+            }
+        }
+        unsafe extern "system" fn SessionCapabilities<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, plsupportedsessions: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCProfile_Impl::SessionCapabilities(this) {
+                    Ok(ok__) => {
+                        plsupportedsessions.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn State<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, penstate: *mut RTC_REGISTRATION_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCProfile_Impl::State(this) {
+                    Ok(ok__) => {
+                        penstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            Key: Key::<Identity, OFFSET>,
+            Name: Name::<Identity, OFFSET>,
+            XML: XML::<Identity, OFFSET>,
+            ProviderName: ProviderName::<Identity, OFFSET>,
+            get_ProviderURI: get_ProviderURI::<Identity, OFFSET>,
+            ProviderData: ProviderData::<Identity, OFFSET>,
+            ClientName: ClientName::<Identity, OFFSET>,
+            ClientBanner: ClientBanner::<Identity, OFFSET>,
+            ClientMinVer: ClientMinVer::<Identity, OFFSET>,
+            ClientCurVer: ClientCurVer::<Identity, OFFSET>,
+            ClientUpdateURI: ClientUpdateURI::<Identity, OFFSET>,
+            ClientData: ClientData::<Identity, OFFSET>,
+            UserURI: UserURI::<Identity, OFFSET>,
+            UserName: UserName::<Identity, OFFSET>,
+            UserAccount: UserAccount::<Identity, OFFSET>,
+            SetCredentials: SetCredentials::<Identity, OFFSET>,
+            SessionCapabilities: SessionCapabilities::<Identity, OFFSET>,
+            State: State::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCProfile as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IRTCProfile_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCProfile_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Key<Identity: windows_core::IUnknownImpl + IRTCProfile_Impl>(this: *mut core::ffi::c_void, pbstrkey: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCProfile_Impl::Key(this) {
+                    Ok(ok__) => {
+                        pbstrkey.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + IRTCProfile_Impl>(this: *mut core::ffi::c_void, pbstrname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCProfile_Impl::Name(this) {
+                    Ok(ok__) => {
+                        pbstrname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn XML<Identity: windows_core::IUnknownImpl + IRTCProfile_Impl>(this: *mut core::ffi::c_void, pbstrxml: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCProfile_Impl::XML(this) {
+                    Ok(ok__) => {
+                        pbstrxml.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ProviderName<Identity: windows_core::IUnknownImpl + IRTCProfile_Impl>(this: *mut core::ffi::c_void, pbstrname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCProfile_Impl::ProviderName(this) {
+                    Ok(ok__) => {
+                        pbstrname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_ProviderURI<Identity: windows_core::IUnknownImpl + IRTCProfile_Impl>(this: *mut core::ffi::c_void, enuri: RTC_PROVIDER_URI, pbstruri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCProfile_Impl::get_ProviderURI(this, core::mem::transmute_copy(&enuri)) {
+                    Ok(ok__) => {
+                        pbstruri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ProviderData<Identity: windows_core::IUnknownImpl + IRTCProfile_Impl>(this: *mut core::ffi::c_void, pbstrdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRTCProfile_Impl::ProviderData(this) {
                     Ok(ok__) => {
                         pbstrdata.write(core::mem::transmute(ok__));
@@ -5246,9 +8194,9 @@ impl IRTCProfile_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ClientName<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstrname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn ClientName<Identity: windows_core::IUnknownImpl + IRTCProfile_Impl>(this: *mut core::ffi::c_void, pbstrname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRTCProfile_Impl::ClientName(this) {
                     Ok(ok__) => {
                         pbstrname.write(core::mem::transmute(ok__));
@@ -5258,9 +8206,9 @@ impl IRTCProfile_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ClientBanner<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfbanner: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn ClientBanner<Identity: windows_core::IUnknownImpl + IRTCProfile_Impl>(this: *mut core::ffi::c_void, pfbanner: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRTCProfile_Impl::ClientBanner(this) {
                     Ok(ok__) => {
                         pfbanner.write(core::mem::transmute(ok__));
@@ -5270,9 +8218,9 @@ impl IRTCProfile_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ClientMinVer<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstrminver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn ClientMinVer<Identity: windows_core::IUnknownImpl + IRTCProfile_Impl>(this: *mut core::ffi::c_void, pbstrminver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRTCProfile_Impl::ClientMinVer(this) {
                     Ok(ok__) => {
                         pbstrminver.write(core::mem::transmute(ok__));
@@ -5282,9 +8230,9 @@ impl IRTCProfile_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ClientCurVer<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstrcurver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn ClientCurVer<Identity: windows_core::IUnknownImpl + IRTCProfile_Impl>(this: *mut core::ffi::c_void, pbstrcurver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRTCProfile_Impl::ClientCurVer(this) {
                     Ok(ok__) => {
                         pbstrcurver.write(core::mem::transmute(ok__));
@@ -5294,9 +8242,9 @@ impl IRTCProfile_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ClientUpdateURI<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstrupdateuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn ClientUpdateURI<Identity: windows_core::IUnknownImpl + IRTCProfile_Impl>(this: *mut core::ffi::c_void, pbstrupdateuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRTCProfile_Impl::ClientUpdateURI(this) {
                     Ok(ok__) => {
                         pbstrupdateuri.write(core::mem::transmute(ok__));
@@ -5306,9 +8254,9 @@ impl IRTCProfile_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ClientData<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstrdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn ClientData<Identity: windows_core::IUnknownImpl + IRTCProfile_Impl>(this: *mut core::ffi::c_void, pbstrdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRTCProfile_Impl::ClientData(this) {
                     Ok(ok__) => {
                         pbstrdata.write(core::mem::transmute(ok__));
@@ -5318,9 +8266,9 @@ impl IRTCProfile_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn UserURI<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstruseruri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn UserURI<Identity: windows_core::IUnknownImpl + IRTCProfile_Impl>(this: *mut core::ffi::c_void, pbstruseruri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRTCProfile_Impl::UserURI(this) {
                     Ok(ok__) => {
                         pbstruseruri.write(core::mem::transmute(ok__));
@@ -5330,9 +8278,9 @@ impl IRTCProfile_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn UserName<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstrusername: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn UserName<Identity: windows_core::IUnknownImpl + IRTCProfile_Impl>(this: *mut core::ffi::c_void, pbstrusername: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRTCProfile_Impl::UserName(this) {
                     Ok(ok__) => {
                         pbstrusername.write(core::mem::transmute(ok__));
@@ -5342,9 +8290,9 @@ impl IRTCProfile_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn UserAccount<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstruseraccount: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn UserAccount<Identity: windows_core::IUnknownImpl + IRTCProfile_Impl>(this: *mut core::ffi::c_void, pbstruseraccount: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRTCProfile_Impl::UserAccount(this) {
                     Ok(ok__) => {
                         pbstruseraccount.write(core::mem::transmute(ok__));
@@ -5354,15 +8302,15 @@ impl IRTCProfile_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetCredentials<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstruseruri: *mut core::ffi::c_void, bstruseraccount: *mut core::ffi::c_void, bstrpassword: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetCredentials<Identity: windows_core::IUnknownImpl + IRTCProfile_Impl>(this: *mut core::ffi::c_void, bstruseruri: *mut core::ffi::c_void, bstruseraccount: *mut core::ffi::c_void, bstrpassword: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IRTCProfile_Impl::SetCredentials(this, core::mem::transmute(&bstruseruri), core::mem::transmute(&bstruseraccount), core::mem::transmute(&bstrpassword)).into()
             }
         }
-        unsafe extern "system" fn SessionCapabilities<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, plsupportedsessions: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SessionCapabilities<Identity: windows_core::IUnknownImpl + IRTCProfile_Impl>(this: *mut core::ffi::c_void, plsupportedsessions: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRTCProfile_Impl::SessionCapabilities(this) {
                     Ok(ok__) => {
                         plsupportedsessions.write(core::mem::transmute(ok__));
@@ -5372,9 +8320,9 @@ impl IRTCProfile_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn State<Identity: IRTCProfile_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, penstate: *mut RTC_REGISTRATION_STATE) -> windows_core::HRESULT {
+        unsafe extern "system" fn State<Identity: windows_core::IUnknownImpl + IRTCProfile_Impl>(this: *mut core::ffi::c_void, penstate: *mut RTC_REGISTRATION_STATE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRTCProfile_Impl::State(this) {
                     Ok(ok__) => {
                         penstate.write(core::mem::transmute(ok__));
@@ -5385,30 +8333,27 @@ impl IRTCProfile_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            Key: Key::<Identity, OFFSET>,
-            Name: Name::<Identity, OFFSET>,
-            XML: XML::<Identity, OFFSET>,
-            ProviderName: ProviderName::<Identity, OFFSET>,
-            get_ProviderURI: get_ProviderURI::<Identity, OFFSET>,
-            ProviderData: ProviderData::<Identity, OFFSET>,
-            ClientName: ClientName::<Identity, OFFSET>,
-            ClientBanner: ClientBanner::<Identity, OFFSET>,
-            ClientMinVer: ClientMinVer::<Identity, OFFSET>,
-            ClientCurVer: ClientCurVer::<Identity, OFFSET>,
-            ClientUpdateURI: ClientUpdateURI::<Identity, OFFSET>,
-            ClientData: ClientData::<Identity, OFFSET>,
-            UserURI: UserURI::<Identity, OFFSET>,
-            UserName: UserName::<Identity, OFFSET>,
-            UserAccount: UserAccount::<Identity, OFFSET>,
-            SetCredentials: SetCredentials::<Identity, OFFSET>,
-            SessionCapabilities: SessionCapabilities::<Identity, OFFSET>,
-            State: State::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IRTCProfile as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Key: Key::<Identity>,
+            Name: Name::<Identity>,
+            XML: XML::<Identity>,
+            ProviderName: ProviderName::<Identity>,
+            get_ProviderURI: get_ProviderURI::<Identity>,
+            ProviderData: ProviderData::<Identity>,
+            ClientName: ClientName::<Identity>,
+            ClientBanner: ClientBanner::<Identity>,
+            ClientMinVer: ClientMinVer::<Identity>,
+            ClientCurVer: ClientCurVer::<Identity>,
+            ClientUpdateURI: ClientUpdateURI::<Identity>,
+            ClientData: ClientData::<Identity>,
+            UserURI: UserURI::<Identity>,
+            UserName: UserName::<Identity>,
+            UserAccount: UserAccount::<Identity>,
+            SetCredentials: SetCredentials::<Identity>,
+            SessionCapabilities: SessionCapabilities::<Identity>,
+            State: State::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IRTCProfile {}
 windows_core::imp::define_interface!(IRTCProfile2, IRTCProfile2_Vtbl, 0x4b81f84e_bdc7_4184_9154_3cb2dd7917fb);
@@ -5504,6 +8449,56 @@ impl IRTCProfile2_Vtbl {
         iid == &<IRTCProfile2 as windows_core::Interface>::IID || iid == &<IRTCProfile as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRTCProfile2_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCProfile2_Vtbl
+where
+    IRTCProfile_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Realm<Identity: windows_core::IUnknownImpl + IRTCProfile2_Impl>(this: *mut core::ffi::c_void, pbstrrealm: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCProfile2_Impl::Realm(this) {
+                    Ok(ok__) => {
+                        pbstrrealm.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRealm<Identity: windows_core::IUnknownImpl + IRTCProfile2_Impl>(this: *mut core::ffi::c_void, bstrrealm: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCProfile2_Impl::SetRealm(this, core::mem::transmute(&bstrrealm)).into()
+            }
+        }
+        unsafe extern "system" fn AllowedAuth<Identity: windows_core::IUnknownImpl + IRTCProfile2_Impl>(this: *mut core::ffi::c_void, plallowedauth: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCProfile2_Impl::AllowedAuth(this) {
+                    Ok(ok__) => {
+                        plallowedauth.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAllowedAuth<Identity: windows_core::IUnknownImpl + IRTCProfile2_Impl>(this: *mut core::ffi::c_void, lallowedauth: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCProfile2_Impl::SetAllowedAuth(this, core::mem::transmute_copy(&lallowedauth)).into()
+            }
+        }
+        Self {
+            base__: <IRTCProfile_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Realm: Realm::<Identity>,
+            SetRealm: SetRealm::<Identity>,
+            AllowedAuth: AllowedAuth::<Identity>,
+            SetAllowedAuth: SetAllowedAuth::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRTCProfile2 {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCProfileEvent, IRTCProfileEvent_Vtbl, 0xd6d5ab3b_770e_43e8_800a_79b062395fca);
@@ -5603,6 +8598,56 @@ impl IRTCProfileEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCProfileEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCProfileEvent_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Profile<Identity: windows_core::IUnknownImpl + IRTCProfileEvent_Impl>(this: *mut core::ffi::c_void, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCProfileEvent_Impl::Profile(this) {
+                    Ok(ok__) => {
+                        ppprofile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Cookie<Identity: windows_core::IUnknownImpl + IRTCProfileEvent_Impl>(this: *mut core::ffi::c_void, plcookie: *mut isize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCProfileEvent_Impl::Cookie(this) {
+                    Ok(ok__) => {
+                        plcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StatusCode<Identity: windows_core::IUnknownImpl + IRTCProfileEvent_Impl>(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCProfileEvent_Impl::StatusCode(this) {
+                    Ok(ok__) => {
+                        plstatuscode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Profile: Profile::<Identity>,
+            Cookie: Cookie::<Identity>,
+            StatusCode: StatusCode::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCProfileEvent {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCProfileEvent2, IRTCProfileEvent2_Vtbl, 0x62e56edc_03fa_4121_94fb_23493fd0ae64);
@@ -5657,6 +8702,27 @@ impl IRTCProfileEvent2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCProfileEvent2_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCProfileEvent2_Vtbl
+where
+    IRTCProfileEvent_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EventType<Identity: windows_core::IUnknownImpl + IRTCProfileEvent2_Impl>(this: *mut core::ffi::c_void, peventtype: *mut RTC_PROFILE_EVENT_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCProfileEvent2_Impl::EventType(this) {
+                    Ok(ok__) => {
+                        peventtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IRTCProfileEvent_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, EventType: EventType::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCProfileEvent2 {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCReInviteEvent, IRTCReInviteEvent_Vtbl, 0x11558d84_204c_43e7_99b0_2034e9417f7d);
@@ -5771,6 +8837,64 @@ impl IRTCReInviteEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCReInviteEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCReInviteEvent_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Session<Identity: windows_core::IUnknownImpl + IRTCReInviteEvent_Impl>(this: *mut core::ffi::c_void, ppsession2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCReInviteEvent_Impl::Session(this) {
+                    Ok(ok__) => {
+                        ppsession2.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Accept<Identity: windows_core::IUnknownImpl + IRTCReInviteEvent_Impl>(this: *mut core::ffi::c_void, bstrcontenttype: *mut core::ffi::c_void, bstrsessiondescription: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCReInviteEvent_Impl::Accept(this, core::mem::transmute(&bstrcontenttype), core::mem::transmute(&bstrsessiondescription)).into()
+            }
+        }
+        unsafe extern "system" fn Reject<Identity: windows_core::IUnknownImpl + IRTCReInviteEvent_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCReInviteEvent_Impl::Reject(this).into()
+            }
+        }
+        unsafe extern "system" fn State<Identity: windows_core::IUnknownImpl + IRTCReInviteEvent_Impl>(this: *mut core::ffi::c_void, pstate: *mut RTC_REINVITE_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCReInviteEvent_Impl::State(this) {
+                    Ok(ok__) => {
+                        pstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRemoteSessionDescription<Identity: windows_core::IUnknownImpl + IRTCReInviteEvent_Impl>(this: *mut core::ffi::c_void, pbstrcontenttype: *mut *mut core::ffi::c_void, pbstrsessiondescription: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCReInviteEvent_Impl::GetRemoteSessionDescription(this, core::mem::transmute_copy(&pbstrcontenttype), core::mem::transmute_copy(&pbstrsessiondescription)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Session: Session::<Identity>,
+            Accept: Accept::<Identity>,
+            Reject: Reject::<Identity>,
+            State: State::<Identity>,
+            GetRemoteSessionDescription: GetRemoteSessionDescription::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCReInviteEvent {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCRegistrationStateChangeEvent, IRTCRegistrationStateChangeEvent_Vtbl, 0x62d0991b_50ab_4f02_b948_ca94f26f8f95);
@@ -5891,6 +9015,69 @@ impl IRTCRegistrationStateChangeEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCRegistrationStateChangeEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCRegistrationStateChangeEvent_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Profile<Identity: windows_core::IUnknownImpl + IRTCRegistrationStateChangeEvent_Impl>(this: *mut core::ffi::c_void, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCRegistrationStateChangeEvent_Impl::Profile(this) {
+                    Ok(ok__) => {
+                        ppprofile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn State<Identity: windows_core::IUnknownImpl + IRTCRegistrationStateChangeEvent_Impl>(this: *mut core::ffi::c_void, penstate: *mut RTC_REGISTRATION_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCRegistrationStateChangeEvent_Impl::State(this) {
+                    Ok(ok__) => {
+                        penstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StatusCode<Identity: windows_core::IUnknownImpl + IRTCRegistrationStateChangeEvent_Impl>(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCRegistrationStateChangeEvent_Impl::StatusCode(this) {
+                    Ok(ok__) => {
+                        plstatuscode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StatusText<Identity: windows_core::IUnknownImpl + IRTCRegistrationStateChangeEvent_Impl>(this: *mut core::ffi::c_void, pbstrstatustext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCRegistrationStateChangeEvent_Impl::StatusText(this) {
+                    Ok(ok__) => {
+                        pbstrstatustext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Profile: Profile::<Identity>,
+            State: State::<Identity>,
+            StatusCode: StatusCode::<Identity>,
+            StatusText: StatusText::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCRegistrationStateChangeEvent {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCRoamingEvent, IRTCRoamingEvent_Vtbl, 0x79960a6b_0cb1_4dc8_a805_7318e99902e8);
@@ -5988,7 +9175,73 @@ impl IRTCRoamingEvent_Vtbl {
         }
         unsafe extern "system" fn StatusText<Identity: IRTCRoamingEvent_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstrstatustext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCRoamingEvent_Impl::StatusText(this) {
+                    Ok(ok__) => {
+                        pbstrstatustext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: super::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
+            EventType: EventType::<Identity, OFFSET>,
+            Profile: Profile::<Identity, OFFSET>,
+            StatusCode: StatusCode::<Identity, OFFSET>,
+            StatusText: StatusText::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCRoamingEvent as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCRoamingEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCRoamingEvent_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EventType<Identity: windows_core::IUnknownImpl + IRTCRoamingEvent_Impl>(this: *mut core::ffi::c_void, peventtype: *mut RTC_ROAMING_EVENT_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCRoamingEvent_Impl::EventType(this) {
+                    Ok(ok__) => {
+                        peventtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Profile<Identity: windows_core::IUnknownImpl + IRTCRoamingEvent_Impl>(this: *mut core::ffi::c_void, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCRoamingEvent_Impl::Profile(this) {
+                    Ok(ok__) => {
+                        ppprofile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StatusCode<Identity: windows_core::IUnknownImpl + IRTCRoamingEvent_Impl>(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCRoamingEvent_Impl::StatusCode(this) {
+                    Ok(ok__) => {
+                        plstatuscode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StatusText<Identity: windows_core::IUnknownImpl + IRTCRoamingEvent_Impl>(this: *mut core::ffi::c_void, pbstrstatustext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRTCRoamingEvent_Impl::StatusText(this) {
                     Ok(ok__) => {
                         pbstrstatustext.write(core::mem::transmute(ok__));
@@ -5999,16 +9252,13 @@ impl IRTCRoamingEvent_Vtbl {
             }
         }
         Self {
-            base__: super::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
-            EventType: EventType::<Identity, OFFSET>,
-            Profile: Profile::<Identity, OFFSET>,
-            StatusCode: StatusCode::<Identity, OFFSET>,
-            StatusText: StatusText::<Identity, OFFSET>,
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EventType: EventType::<Identity>,
+            Profile: Profile::<Identity>,
+            StatusCode: StatusCode::<Identity>,
+            StatusText: StatusText::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IRTCRoamingEvent as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID
-    }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCRoamingEvent {}
@@ -6376,6 +9626,217 @@ impl IRTCSession_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IRTCSession_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCSession_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Client<Identity: windows_core::IUnknownImpl + IRTCSession_Impl>(this: *mut core::ffi::c_void, ppclient: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSession_Impl::Client(this) {
+                    Ok(ok__) => {
+                        ppclient.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn State<Identity: windows_core::IUnknownImpl + IRTCSession_Impl>(this: *mut core::ffi::c_void, penstate: *mut RTC_SESSION_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSession_Impl::State(this) {
+                    Ok(ok__) => {
+                        penstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Type<Identity: windows_core::IUnknownImpl + IRTCSession_Impl>(this: *mut core::ffi::c_void, pentype: *mut RTC_SESSION_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSession_Impl::Type(this) {
+                    Ok(ok__) => {
+                        pentype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Profile<Identity: windows_core::IUnknownImpl + IRTCSession_Impl>(this: *mut core::ffi::c_void, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSession_Impl::Profile(this) {
+                    Ok(ok__) => {
+                        ppprofile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Participants<Identity: windows_core::IUnknownImpl + IRTCSession_Impl>(this: *mut core::ffi::c_void, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSession_Impl::Participants(this) {
+                    Ok(ok__) => {
+                        ppcollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Answer<Identity: windows_core::IUnknownImpl + IRTCSession_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSession_Impl::Answer(this).into()
+            }
+        }
+        unsafe extern "system" fn Terminate<Identity: windows_core::IUnknownImpl + IRTCSession_Impl>(this: *mut core::ffi::c_void, enreason: RTC_TERMINATE_REASON) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSession_Impl::Terminate(this, core::mem::transmute_copy(&enreason)).into()
+            }
+        }
+        unsafe extern "system" fn Redirect<Identity: windows_core::IUnknownImpl + IRTCSession_Impl>(this: *mut core::ffi::c_void, entype: RTC_SESSION_TYPE, bstrlocalphoneuri: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSession_Impl::Redirect(this, core::mem::transmute_copy(&entype), core::mem::transmute(&bstrlocalphoneuri), core::mem::transmute_copy(&pprofile), core::mem::transmute_copy(&lflags)).into()
+            }
+        }
+        unsafe extern "system" fn AddParticipant<Identity: windows_core::IUnknownImpl + IRTCSession_Impl>(this: *mut core::ffi::c_void, bstraddress: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, ppparticipant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSession_Impl::AddParticipant(this, core::mem::transmute(&bstraddress), core::mem::transmute(&bstrname)) {
+                    Ok(ok__) => {
+                        ppparticipant.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveParticipant<Identity: windows_core::IUnknownImpl + IRTCSession_Impl>(this: *mut core::ffi::c_void, pparticipant: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSession_Impl::RemoveParticipant(this, core::mem::transmute_copy(&pparticipant)).into()
+            }
+        }
+        unsafe extern "system" fn EnumerateParticipants<Identity: windows_core::IUnknownImpl + IRTCSession_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSession_Impl::EnumerateParticipants(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CanAddParticipants<Identity: windows_core::IUnknownImpl + IRTCSession_Impl>(this: *mut core::ffi::c_void, pfcanadd: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSession_Impl::CanAddParticipants(this) {
+                    Ok(ok__) => {
+                        pfcanadd.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RedirectedUserURI<Identity: windows_core::IUnknownImpl + IRTCSession_Impl>(this: *mut core::ffi::c_void, pbstruseruri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSession_Impl::RedirectedUserURI(this) {
+                    Ok(ok__) => {
+                        pbstruseruri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RedirectedUserName<Identity: windows_core::IUnknownImpl + IRTCSession_Impl>(this: *mut core::ffi::c_void, pbstrusername: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSession_Impl::RedirectedUserName(this) {
+                    Ok(ok__) => {
+                        pbstrusername.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NextRedirectedUser<Identity: windows_core::IUnknownImpl + IRTCSession_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSession_Impl::NextRedirectedUser(this).into()
+            }
+        }
+        unsafe extern "system" fn SendMessage<Identity: windows_core::IUnknownImpl + IRTCSession_Impl>(this: *mut core::ffi::c_void, bstrmessageheader: *mut core::ffi::c_void, bstrmessage: *mut core::ffi::c_void, lcookie: isize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSession_Impl::SendMessage(this, core::mem::transmute(&bstrmessageheader), core::mem::transmute(&bstrmessage), core::mem::transmute_copy(&lcookie)).into()
+            }
+        }
+        unsafe extern "system" fn SendMessageStatus<Identity: windows_core::IUnknownImpl + IRTCSession_Impl>(this: *mut core::ffi::c_void, enuserstatus: RTC_MESSAGING_USER_STATUS, lcookie: isize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSession_Impl::SendMessageStatus(this, core::mem::transmute_copy(&enuserstatus), core::mem::transmute_copy(&lcookie)).into()
+            }
+        }
+        unsafe extern "system" fn AddStream<Identity: windows_core::IUnknownImpl + IRTCSession_Impl>(this: *mut core::ffi::c_void, lmediatype: i32, lcookie: isize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSession_Impl::AddStream(this, core::mem::transmute_copy(&lmediatype), core::mem::transmute_copy(&lcookie)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveStream<Identity: windows_core::IUnknownImpl + IRTCSession_Impl>(this: *mut core::ffi::c_void, lmediatype: i32, lcookie: isize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSession_Impl::RemoveStream(this, core::mem::transmute_copy(&lmediatype), core::mem::transmute_copy(&lcookie)).into()
+            }
+        }
+        unsafe extern "system" fn put_EncryptionKey<Identity: windows_core::IUnknownImpl + IRTCSession_Impl>(this: *mut core::ffi::c_void, lmediatype: i32, encryptionkey: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSession_Impl::put_EncryptionKey(this, core::mem::transmute_copy(&lmediatype), core::mem::transmute(&encryptionkey)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Client: Client::<Identity>,
+            State: State::<Identity>,
+            Type: Type::<Identity>,
+            Profile: Profile::<Identity>,
+            Participants: Participants::<Identity>,
+            Answer: Answer::<Identity>,
+            Terminate: Terminate::<Identity>,
+            Redirect: Redirect::<Identity>,
+            AddParticipant: AddParticipant::<Identity>,
+            RemoveParticipant: RemoveParticipant::<Identity>,
+            EnumerateParticipants: EnumerateParticipants::<Identity>,
+            CanAddParticipants: CanAddParticipants::<Identity>,
+            RedirectedUserURI: RedirectedUserURI::<Identity>,
+            RedirectedUserName: RedirectedUserName::<Identity>,
+            NextRedirectedUser: NextRedirectedUser::<Identity>,
+            SendMessage: SendMessage::<Identity>,
+            SendMessageStatus: SendMessageStatus::<Identity>,
+            AddStream: AddStream::<Identity>,
+            RemoveStream: RemoveStream::<Identity>,
+            put_EncryptionKey: put_EncryptionKey::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IRTCSession {}
 windows_core::imp::define_interface!(IRTCSession2, IRTCSession2_Vtbl, 0x17d7cdfc_b007_484c_99d2_86a8a820991d);
 impl core::ops::Deref for IRTCSession2 {
@@ -6497,6 +9958,71 @@ impl IRTCSession2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IRTCSession2_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCSession2_Vtbl
+where
+    IRTCSession_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SendInfo<Identity: windows_core::IUnknownImpl + IRTCSession2_Impl>(this: *mut core::ffi::c_void, bstrinfoheader: *mut core::ffi::c_void, bstrinfo: *mut core::ffi::c_void, lcookie: isize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSession2_Impl::SendInfo(this, core::mem::transmute(&bstrinfoheader), core::mem::transmute(&bstrinfo), core::mem::transmute_copy(&lcookie)).into()
+            }
+        }
+        unsafe extern "system" fn put_PreferredSecurityLevel<Identity: windows_core::IUnknownImpl + IRTCSession2_Impl>(this: *mut core::ffi::c_void, ensecuritytype: RTC_SECURITY_TYPE, ensecuritylevel: RTC_SECURITY_LEVEL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSession2_Impl::put_PreferredSecurityLevel(this, core::mem::transmute_copy(&ensecuritytype), core::mem::transmute_copy(&ensecuritylevel)).into()
+            }
+        }
+        unsafe extern "system" fn get_PreferredSecurityLevel<Identity: windows_core::IUnknownImpl + IRTCSession2_Impl>(this: *mut core::ffi::c_void, ensecuritytype: RTC_SECURITY_TYPE, pensecuritylevel: *mut RTC_SECURITY_LEVEL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSession2_Impl::get_PreferredSecurityLevel(this, core::mem::transmute_copy(&ensecuritytype)) {
+                    Ok(ok__) => {
+                        pensecuritylevel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsSecurityEnabled<Identity: windows_core::IUnknownImpl + IRTCSession2_Impl>(this: *mut core::ffi::c_void, ensecuritytype: RTC_SECURITY_TYPE, pfsecurityenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSession2_Impl::IsSecurityEnabled(this, core::mem::transmute_copy(&ensecuritytype)) {
+                    Ok(ok__) => {
+                        pfsecurityenabled.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AnswerWithSessionDescription<Identity: windows_core::IUnknownImpl + IRTCSession2_Impl>(this: *mut core::ffi::c_void, bstrcontenttype: *mut core::ffi::c_void, bstrsessiondescription: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSession2_Impl::AnswerWithSessionDescription(this, core::mem::transmute(&bstrcontenttype), core::mem::transmute(&bstrsessiondescription)).into()
+            }
+        }
+        unsafe extern "system" fn ReInviteWithSessionDescription<Identity: windows_core::IUnknownImpl + IRTCSession2_Impl>(this: *mut core::ffi::c_void, bstrcontenttype: *mut core::ffi::c_void, bstrsessiondescription: *mut core::ffi::c_void, lcookie: isize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSession2_Impl::ReInviteWithSessionDescription(this, core::mem::transmute(&bstrcontenttype), core::mem::transmute(&bstrsessiondescription), core::mem::transmute_copy(&lcookie)).into()
+            }
+        }
+        Self {
+            base__: <IRTCSession_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SendInfo: SendInfo::<Identity>,
+            put_PreferredSecurityLevel: put_PreferredSecurityLevel::<Identity>,
+            get_PreferredSecurityLevel: get_PreferredSecurityLevel::<Identity>,
+            IsSecurityEnabled: IsSecurityEnabled::<Identity>,
+            AnswerWithSessionDescription: AnswerWithSessionDescription::<Identity>,
+            ReInviteWithSessionDescription: ReInviteWithSessionDescription::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IRTCSession2 {}
 windows_core::imp::define_interface!(IRTCSessionCallControl, IRTCSessionCallControl_Vtbl, 0xe9a50d94_190b_4f82_9530_3b8ebf60758a);
 windows_core::imp::interface_hierarchy!(IRTCSessionCallControl, windows_core::IUnknown);
@@ -6654,6 +10180,97 @@ impl IRTCSessionCallControl_Vtbl {
         iid == &<IRTCSessionCallControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRTCSessionCallControl_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCSessionCallControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Hold<Identity: windows_core::IUnknownImpl + IRTCSessionCallControl_Impl>(this: *mut core::ffi::c_void, lcookie: isize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSessionCallControl_Impl::Hold(this, core::mem::transmute_copy(&lcookie)).into()
+            }
+        }
+        unsafe extern "system" fn UnHold<Identity: windows_core::IUnknownImpl + IRTCSessionCallControl_Impl>(this: *mut core::ffi::c_void, lcookie: isize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSessionCallControl_Impl::UnHold(this, core::mem::transmute_copy(&lcookie)).into()
+            }
+        }
+        unsafe extern "system" fn Forward<Identity: windows_core::IUnknownImpl + IRTCSessionCallControl_Impl>(this: *mut core::ffi::c_void, bstrforwardtouri: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSessionCallControl_Impl::Forward(this, core::mem::transmute(&bstrforwardtouri)).into()
+            }
+        }
+        unsafe extern "system" fn Refer<Identity: windows_core::IUnknownImpl + IRTCSessionCallControl_Impl>(this: *mut core::ffi::c_void, bstrrefertouri: *mut core::ffi::c_void, bstrrefercookie: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSessionCallControl_Impl::Refer(this, core::mem::transmute(&bstrrefertouri), core::mem::transmute(&bstrrefercookie)).into()
+            }
+        }
+        unsafe extern "system" fn SetReferredByURI<Identity: windows_core::IUnknownImpl + IRTCSessionCallControl_Impl>(this: *mut core::ffi::c_void, bstrreferredbyuri: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSessionCallControl_Impl::SetReferredByURI(this, core::mem::transmute(&bstrreferredbyuri)).into()
+            }
+        }
+        unsafe extern "system" fn ReferredByURI<Identity: windows_core::IUnknownImpl + IRTCSessionCallControl_Impl>(this: *mut core::ffi::c_void, pbstrreferredbyuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSessionCallControl_Impl::ReferredByURI(this) {
+                    Ok(ok__) => {
+                        pbstrreferredbyuri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetReferCookie<Identity: windows_core::IUnknownImpl + IRTCSessionCallControl_Impl>(this: *mut core::ffi::c_void, bstrrefercookie: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSessionCallControl_Impl::SetReferCookie(this, core::mem::transmute(&bstrrefercookie)).into()
+            }
+        }
+        unsafe extern "system" fn ReferCookie<Identity: windows_core::IUnknownImpl + IRTCSessionCallControl_Impl>(this: *mut core::ffi::c_void, pbstrrefercookie: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSessionCallControl_Impl::ReferCookie(this) {
+                    Ok(ok__) => {
+                        pbstrrefercookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsReferred<Identity: windows_core::IUnknownImpl + IRTCSessionCallControl_Impl>(this: *mut core::ffi::c_void, pfisreferred: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSessionCallControl_Impl::IsReferred(this) {
+                    Ok(ok__) => {
+                        pfisreferred.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Hold: Hold::<Identity>,
+            UnHold: UnHold::<Identity>,
+            Forward: Forward::<Identity>,
+            Refer: Refer::<Identity>,
+            SetReferredByURI: SetReferredByURI::<Identity>,
+            ReferredByURI: ReferredByURI::<Identity>,
+            SetReferCookie: SetReferCookie::<Identity>,
+            ReferCookie: ReferCookie::<Identity>,
+            IsReferred: IsReferred::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRTCSessionCallControl {}
 windows_core::imp::define_interface!(IRTCSessionDescriptionManager, IRTCSessionDescriptionManager_Vtbl, 0xba7f518e_d336_4070_93a6_865395c843f9);
 windows_core::imp::interface_hierarchy!(IRTCSessionDescriptionManager, windows_core::IUnknown);
@@ -6685,6 +10302,23 @@ impl IRTCSessionDescriptionManager_Vtbl {
         iid == &<IRTCSessionDescriptionManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRTCSessionDescriptionManager_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCSessionDescriptionManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EvaluateSessionDescription<Identity: windows_core::IUnknownImpl + IRTCSessionDescriptionManager_Impl>(this: *mut core::ffi::c_void, bstrcontenttype: *mut core::ffi::c_void, bstrsessiondescription: *mut core::ffi::c_void, pfapplicationsession: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSessionDescriptionManager_Impl::EvaluateSessionDescription(this, core::mem::transmute(&bstrcontenttype), core::mem::transmute(&bstrsessiondescription), core::mem::transmute_copy(&pfapplicationsession)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EvaluateSessionDescription: EvaluateSessionDescription::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRTCSessionDescriptionManager {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCSessionOperationCompleteEvent, IRTCSessionOperationCompleteEvent_Vtbl, 0xa6bff4c0_f7c8_4d3c_9a41_3550f78a95b0);
@@ -6805,6 +10439,69 @@ impl IRTCSessionOperationCompleteEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCSessionOperationCompleteEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCSessionOperationCompleteEvent_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Session<Identity: windows_core::IUnknownImpl + IRTCSessionOperationCompleteEvent_Impl>(this: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSessionOperationCompleteEvent_Impl::Session(this) {
+                    Ok(ok__) => {
+                        ppsession.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Cookie<Identity: windows_core::IUnknownImpl + IRTCSessionOperationCompleteEvent_Impl>(this: *mut core::ffi::c_void, plcookie: *mut isize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSessionOperationCompleteEvent_Impl::Cookie(this) {
+                    Ok(ok__) => {
+                        plcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StatusCode<Identity: windows_core::IUnknownImpl + IRTCSessionOperationCompleteEvent_Impl>(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSessionOperationCompleteEvent_Impl::StatusCode(this) {
+                    Ok(ok__) => {
+                        plstatuscode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StatusText<Identity: windows_core::IUnknownImpl + IRTCSessionOperationCompleteEvent_Impl>(this: *mut core::ffi::c_void, pbstrstatustext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSessionOperationCompleteEvent_Impl::StatusText(this) {
+                    Ok(ok__) => {
+                        pbstrstatustext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Session: Session::<Identity>,
+            Cookie: Cookie::<Identity>,
+            StatusCode: StatusCode::<Identity>,
+            StatusText: StatusText::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCSessionOperationCompleteEvent {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCSessionOperationCompleteEvent2, IRTCSessionOperationCompleteEvent2_Vtbl, 0xf6fc2a9b_d5bc_4241_b436_1b8460c13832);
@@ -6874,6 +10571,37 @@ impl IRTCSessionOperationCompleteEvent2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCSessionOperationCompleteEvent2_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCSessionOperationCompleteEvent2_Vtbl
+where
+    IRTCSessionOperationCompleteEvent_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Participant<Identity: windows_core::IUnknownImpl + IRTCSessionOperationCompleteEvent2_Impl>(this: *mut core::ffi::c_void, ppparticipant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSessionOperationCompleteEvent2_Impl::Participant(this) {
+                    Ok(ok__) => {
+                        ppparticipant.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRemoteSessionDescription<Identity: windows_core::IUnknownImpl + IRTCSessionOperationCompleteEvent2_Impl>(this: *mut core::ffi::c_void, pbstrcontenttype: *mut *mut core::ffi::c_void, pbstrsessiondescription: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSessionOperationCompleteEvent2_Impl::GetRemoteSessionDescription(this, core::mem::transmute_copy(&pbstrcontenttype), core::mem::transmute_copy(&pbstrsessiondescription)).into()
+            }
+        }
+        Self {
+            base__: <IRTCSessionOperationCompleteEvent_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Participant: Participant::<Identity>,
+            GetRemoteSessionDescription: GetRemoteSessionDescription::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCSessionOperationCompleteEvent2 {}
 windows_core::imp::define_interface!(IRTCSessionPortManagement, IRTCSessionPortManagement_Vtbl, 0xa072f1d6_0286_4e1f_85f2_17a2948456ec);
 windows_core::imp::interface_hierarchy!(IRTCSessionPortManagement, windows_core::IUnknown);
@@ -6908,6 +10636,20 @@ impl IRTCSessionPortManagement_Vtbl {
         iid == &<IRTCSessionPortManagement as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRTCSessionPortManagement_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCSessionPortManagement_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetPortManager<Identity: windows_core::IUnknownImpl + IRTCSessionPortManagement_Impl>(this: *mut core::ffi::c_void, pportmanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSessionPortManagement_Impl::SetPortManager(this, core::mem::transmute_copy(&pportmanager)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, SetPortManager: SetPortManager::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IRTCSessionPortManagement {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCSessionReferStatusEvent, IRTCSessionReferStatusEvent_Vtbl, 0x3d8fc2cd_5d76_44ab_bb68_2a80353b34a2);
@@ -7028,6 +10770,69 @@ impl IRTCSessionReferStatusEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCSessionReferStatusEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCSessionReferStatusEvent_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Session<Identity: windows_core::IUnknownImpl + IRTCSessionReferStatusEvent_Impl>(this: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSessionReferStatusEvent_Impl::Session(this) {
+                    Ok(ok__) => {
+                        ppsession.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReferStatus<Identity: windows_core::IUnknownImpl + IRTCSessionReferStatusEvent_Impl>(this: *mut core::ffi::c_void, penreferstatus: *mut RTC_SESSION_REFER_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSessionReferStatusEvent_Impl::ReferStatus(this) {
+                    Ok(ok__) => {
+                        penreferstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StatusCode<Identity: windows_core::IUnknownImpl + IRTCSessionReferStatusEvent_Impl>(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSessionReferStatusEvent_Impl::StatusCode(this) {
+                    Ok(ok__) => {
+                        plstatuscode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StatusText<Identity: windows_core::IUnknownImpl + IRTCSessionReferStatusEvent_Impl>(this: *mut core::ffi::c_void, pbstrstatustext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSessionReferStatusEvent_Impl::StatusText(this) {
+                    Ok(ok__) => {
+                        pbstrstatustext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Session: Session::<Identity>,
+            ReferStatus: ReferStatus::<Identity>,
+            StatusCode: StatusCode::<Identity>,
+            StatusText: StatusText::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCSessionReferStatusEvent {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCSessionReferredEvent, IRTCSessionReferredEvent_Vtbl, 0x176a6828_4fcc_4f28_a862_04597a6cf1c4);
@@ -7184,6 +10989,90 @@ impl IRTCSessionReferredEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCSessionReferredEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCSessionReferredEvent_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Session<Identity: windows_core::IUnknownImpl + IRTCSessionReferredEvent_Impl>(this: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSessionReferredEvent_Impl::Session(this) {
+                    Ok(ok__) => {
+                        ppsession.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReferredByURI<Identity: windows_core::IUnknownImpl + IRTCSessionReferredEvent_Impl>(this: *mut core::ffi::c_void, pbstrreferredbyuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSessionReferredEvent_Impl::ReferredByURI(this) {
+                    Ok(ok__) => {
+                        pbstrreferredbyuri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReferToURI<Identity: windows_core::IUnknownImpl + IRTCSessionReferredEvent_Impl>(this: *mut core::ffi::c_void, pbstrreferouri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSessionReferredEvent_Impl::ReferToURI(this) {
+                    Ok(ok__) => {
+                        pbstrreferouri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReferCookie<Identity: windows_core::IUnknownImpl + IRTCSessionReferredEvent_Impl>(this: *mut core::ffi::c_void, pbstrrefercookie: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSessionReferredEvent_Impl::ReferCookie(this) {
+                    Ok(ok__) => {
+                        pbstrrefercookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Accept<Identity: windows_core::IUnknownImpl + IRTCSessionReferredEvent_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSessionReferredEvent_Impl::Accept(this).into()
+            }
+        }
+        unsafe extern "system" fn Reject<Identity: windows_core::IUnknownImpl + IRTCSessionReferredEvent_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSessionReferredEvent_Impl::Reject(this).into()
+            }
+        }
+        unsafe extern "system" fn SetReferredSessionState<Identity: windows_core::IUnknownImpl + IRTCSessionReferredEvent_Impl>(this: *mut core::ffi::c_void, enstate: RTC_SESSION_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCSessionReferredEvent_Impl::SetReferredSessionState(this, core::mem::transmute_copy(&enstate)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Session: Session::<Identity>,
+            ReferredByURI: ReferredByURI::<Identity>,
+            ReferToURI: ReferToURI::<Identity>,
+            ReferCookie: ReferCookie::<Identity>,
+            Accept: Accept::<Identity>,
+            Reject: Reject::<Identity>,
+            SetReferredSessionState: SetReferredSessionState::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCSessionReferredEvent {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCSessionStateChangeEvent, IRTCSessionStateChangeEvent_Vtbl, 0xb5bad703_5952_48b3_9321_7f4500521506);
@@ -7304,6 +11193,69 @@ impl IRTCSessionStateChangeEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCSessionStateChangeEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCSessionStateChangeEvent_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Session<Identity: windows_core::IUnknownImpl + IRTCSessionStateChangeEvent_Impl>(this: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSessionStateChangeEvent_Impl::Session(this) {
+                    Ok(ok__) => {
+                        ppsession.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn State<Identity: windows_core::IUnknownImpl + IRTCSessionStateChangeEvent_Impl>(this: *mut core::ffi::c_void, penstate: *mut RTC_SESSION_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSessionStateChangeEvent_Impl::State(this) {
+                    Ok(ok__) => {
+                        penstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StatusCode<Identity: windows_core::IUnknownImpl + IRTCSessionStateChangeEvent_Impl>(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSessionStateChangeEvent_Impl::StatusCode(this) {
+                    Ok(ok__) => {
+                        plstatuscode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StatusText<Identity: windows_core::IUnknownImpl + IRTCSessionStateChangeEvent_Impl>(this: *mut core::ffi::c_void, pbstrstatustext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSessionStateChangeEvent_Impl::StatusText(this) {
+                    Ok(ok__) => {
+                        pbstrstatustext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Session: Session::<Identity>,
+            State: State::<Identity>,
+            StatusCode: StatusCode::<Identity>,
+            StatusText: StatusText::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCSessionStateChangeEvent {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCSessionStateChangeEvent2, IRTCSessionStateChangeEvent2_Vtbl, 0x4f933171_6f95_4880_80d9_2ec8d495d261);
@@ -7372,9 +11324,69 @@ impl IRTCSessionStateChangeEvent2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn get_RemotePreferredSecurityLevel<Identity: IRTCSessionStateChangeEvent2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ensecuritytype: RTC_SECURITY_TYPE, pensecuritylevel: *mut RTC_SECURITY_LEVEL) -> windows_core::HRESULT {
+        unsafe extern "system" fn get_RemotePreferredSecurityLevel<Identity: IRTCSessionStateChangeEvent2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ensecuritytype: RTC_SECURITY_TYPE, pensecuritylevel: *mut RTC_SECURITY_LEVEL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCSessionStateChangeEvent2_Impl::get_RemotePreferredSecurityLevel(this, core::mem::transmute_copy(&ensecuritytype)) {
+                    Ok(ok__) => {
+                        pensecuritylevel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsForked<Identity: IRTCSessionStateChangeEvent2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfisforked: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRTCSessionStateChangeEvent2_Impl::IsForked(this) {
+                    Ok(ok__) => {
+                        pfisforked.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRemoteSessionDescription<Identity: IRTCSessionStateChangeEvent2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstrcontenttype: *mut *mut core::ffi::c_void, pbstrsessiondescription: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IRTCSessionStateChangeEvent2_Impl::GetRemoteSessionDescription(this, core::mem::transmute_copy(&pbstrcontenttype), core::mem::transmute_copy(&pbstrsessiondescription)).into()
+            }
+        }
+        Self {
+            base__: IRTCSessionStateChangeEvent_Vtbl::new::<Identity, OFFSET>(),
+            MediaTypes: MediaTypes::<Identity, OFFSET>,
+            get_RemotePreferredSecurityLevel: get_RemotePreferredSecurityLevel::<Identity, OFFSET>,
+            IsForked: IsForked::<Identity, OFFSET>,
+            GetRemoteSessionDescription: GetRemoteSessionDescription::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCSessionStateChangeEvent2 as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID || iid == &<IRTCSessionStateChangeEvent as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCSessionStateChangeEvent2_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCSessionStateChangeEvent2_Vtbl
+where
+    IRTCSessionStateChangeEvent_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn MediaTypes<Identity: windows_core::IUnknownImpl + IRTCSessionStateChangeEvent2_Impl>(this: *mut core::ffi::c_void, pmediatypes: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCSessionStateChangeEvent2_Impl::MediaTypes(this) {
+                    Ok(ok__) => {
+                        pmediatypes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_RemotePreferredSecurityLevel<Identity: windows_core::IUnknownImpl + IRTCSessionStateChangeEvent2_Impl>(this: *mut core::ffi::c_void, ensecuritytype: RTC_SECURITY_TYPE, pensecuritylevel: *mut RTC_SECURITY_LEVEL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRTCSessionStateChangeEvent2_Impl::get_RemotePreferredSecurityLevel(this, core::mem::transmute_copy(&ensecuritytype)) {
                     Ok(ok__) => {
                         pensecuritylevel.write(core::mem::transmute(ok__));
@@ -7384,9 +11396,9 @@ impl IRTCSessionStateChangeEvent2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn IsForked<Identity: IRTCSessionStateChangeEvent2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pfisforked: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn IsForked<Identity: windows_core::IUnknownImpl + IRTCSessionStateChangeEvent2_Impl>(this: *mut core::ffi::c_void, pfisforked: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRTCSessionStateChangeEvent2_Impl::IsForked(this) {
                     Ok(ok__) => {
                         pfisforked.write(core::mem::transmute(ok__));
@@ -7396,23 +11408,20 @@ impl IRTCSessionStateChangeEvent2_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetRemoteSessionDescription<Identity: IRTCSessionStateChangeEvent2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pbstrcontenttype: *mut *mut core::ffi::c_void, pbstrsessiondescription: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetRemoteSessionDescription<Identity: windows_core::IUnknownImpl + IRTCSessionStateChangeEvent2_Impl>(this: *mut core::ffi::c_void, pbstrcontenttype: *mut *mut core::ffi::c_void, pbstrsessiondescription: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IRTCSessionStateChangeEvent2_Impl::GetRemoteSessionDescription(this, core::mem::transmute_copy(&pbstrcontenttype), core::mem::transmute_copy(&pbstrsessiondescription)).into()
             }
         }
         Self {
-            base__: IRTCSessionStateChangeEvent_Vtbl::new::<Identity, OFFSET>(),
-            MediaTypes: MediaTypes::<Identity, OFFSET>,
-            get_RemotePreferredSecurityLevel: get_RemotePreferredSecurityLevel::<Identity, OFFSET>,
-            IsForked: IsForked::<Identity, OFFSET>,
-            GetRemoteSessionDescription: GetRemoteSessionDescription::<Identity, OFFSET>,
+            base__: <IRTCSessionStateChangeEvent_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            MediaTypes: MediaTypes::<Identity>,
+            get_RemotePreferredSecurityLevel: get_RemotePreferredSecurityLevel::<Identity>,
+            IsForked: IsForked::<Identity>,
+            GetRemoteSessionDescription: GetRemoteSessionDescription::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IRTCSessionStateChangeEvent2 as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID || iid == &<IRTCSessionStateChangeEvent as windows_core::Interface>::IID
-    }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCSessionStateChangeEvent2 {}
@@ -7474,6 +11483,36 @@ impl IRTCUserSearch_Vtbl {
         iid == &<IRTCUserSearch as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRTCUserSearch_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCUserSearch_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateQuery<Identity: windows_core::IUnknownImpl + IRTCUserSearch_Impl>(this: *mut core::ffi::c_void, ppquery: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCUserSearch_Impl::CreateQuery(this) {
+                    Ok(ok__) => {
+                        ppquery.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ExecuteSearch<Identity: windows_core::IUnknownImpl + IRTCUserSearch_Impl>(this: *mut core::ffi::c_void, pquery: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void, lcookie: isize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCUserSearch_Impl::ExecuteSearch(this, core::mem::transmute_copy(&pquery), core::mem::transmute_copy(&pprofile), core::mem::transmute_copy(&lcookie)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateQuery: CreateQuery::<Identity>,
+            ExecuteSearch: ExecuteSearch::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRTCUserSearch {}
 windows_core::imp::define_interface!(IRTCUserSearchQuery, IRTCUserSearchQuery_Vtbl, 0x288300f5_d23a_4365_9a73_9985c98c2881);
 windows_core::imp::interface_hierarchy!(IRTCUserSearchQuery, windows_core::IUnknown);
@@ -7616,6 +11655,89 @@ impl IRTCUserSearchQuery_Vtbl {
         iid == &<IRTCUserSearchQuery as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRTCUserSearchQuery_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCUserSearchQuery_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn put_SearchTerm<Identity: windows_core::IUnknownImpl + IRTCUserSearchQuery_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, bstrvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCUserSearchQuery_Impl::put_SearchTerm(this, core::mem::transmute(&bstrname), core::mem::transmute(&bstrvalue)).into()
+            }
+        }
+        unsafe extern "system" fn get_SearchTerm<Identity: windows_core::IUnknownImpl + IRTCUserSearchQuery_Impl>(this: *mut core::ffi::c_void, bstrname: *mut core::ffi::c_void, pbstrvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCUserSearchQuery_Impl::get_SearchTerm(this, core::mem::transmute(&bstrname)) {
+                    Ok(ok__) => {
+                        pbstrvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SearchTerms<Identity: windows_core::IUnknownImpl + IRTCUserSearchQuery_Impl>(this: *mut core::ffi::c_void, pbstrnames: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCUserSearchQuery_Impl::SearchTerms(this) {
+                    Ok(ok__) => {
+                        pbstrnames.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn put_SearchPreference<Identity: windows_core::IUnknownImpl + IRTCUserSearchQuery_Impl>(this: *mut core::ffi::c_void, enpreference: RTC_USER_SEARCH_PREFERENCE, lvalue: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCUserSearchQuery_Impl::put_SearchPreference(this, core::mem::transmute_copy(&enpreference), core::mem::transmute_copy(&lvalue)).into()
+            }
+        }
+        unsafe extern "system" fn get_SearchPreference<Identity: windows_core::IUnknownImpl + IRTCUserSearchQuery_Impl>(this: *mut core::ffi::c_void, enpreference: RTC_USER_SEARCH_PREFERENCE, plvalue: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCUserSearchQuery_Impl::get_SearchPreference(this, core::mem::transmute_copy(&enpreference)) {
+                    Ok(ok__) => {
+                        plvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSearchDomain<Identity: windows_core::IUnknownImpl + IRTCUserSearchQuery_Impl>(this: *mut core::ffi::c_void, bstrdomain: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCUserSearchQuery_Impl::SetSearchDomain(this, core::mem::transmute(&bstrdomain)).into()
+            }
+        }
+        unsafe extern "system" fn SearchDomain<Identity: windows_core::IUnknownImpl + IRTCUserSearchQuery_Impl>(this: *mut core::ffi::c_void, pbstrdomain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCUserSearchQuery_Impl::SearchDomain(this) {
+                    Ok(ok__) => {
+                        pbstrdomain.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            put_SearchTerm: put_SearchTerm::<Identity>,
+            get_SearchTerm: get_SearchTerm::<Identity>,
+            SearchTerms: SearchTerms::<Identity>,
+            put_SearchPreference: put_SearchPreference::<Identity>,
+            get_SearchPreference: get_SearchPreference::<Identity>,
+            SetSearchDomain: SetSearchDomain::<Identity>,
+            SearchDomain: SearchDomain::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRTCUserSearchQuery {}
 windows_core::imp::define_interface!(IRTCUserSearchResult, IRTCUserSearchResult_Vtbl, 0x851278b2_9592_480f_8db5_2de86b26b54d);
 windows_core::imp::interface_hierarchy!(IRTCUserSearchResult, windows_core::IUnknown);
@@ -7656,6 +11778,26 @@ impl IRTCUserSearchResult_Vtbl {
         iid == &<IRTCUserSearchResult as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRTCUserSearchResult_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCUserSearchResult_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn get_Value<Identity: windows_core::IUnknownImpl + IRTCUserSearchResult_Impl>(this: *mut core::ffi::c_void, encolumn: RTC_USER_SEARCH_COLUMN, pbstrvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCUserSearchResult_Impl::get_Value(this, core::mem::transmute_copy(&encolumn)) {
+                    Ok(ok__) => {
+                        pbstrvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, get_Value: get_Value::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IRTCUserSearchResult {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCUserSearchResultsEvent, IRTCUserSearchResultsEvent_Vtbl, 0xd8c8c3cd_7fac_4088_81c5_c24cbc0938e3);
@@ -7839,6 +11981,108 @@ impl IRTCUserSearchResultsEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCUserSearchResultsEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCUserSearchResultsEvent_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EnumerateResults<Identity: windows_core::IUnknownImpl + IRTCUserSearchResultsEvent_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCUserSearchResultsEvent_Impl::EnumerateResults(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Results<Identity: windows_core::IUnknownImpl + IRTCUserSearchResultsEvent_Impl>(this: *mut core::ffi::c_void, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCUserSearchResultsEvent_Impl::Results(this) {
+                    Ok(ok__) => {
+                        ppcollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Profile<Identity: windows_core::IUnknownImpl + IRTCUserSearchResultsEvent_Impl>(this: *mut core::ffi::c_void, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCUserSearchResultsEvent_Impl::Profile(this) {
+                    Ok(ok__) => {
+                        ppprofile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Query<Identity: windows_core::IUnknownImpl + IRTCUserSearchResultsEvent_Impl>(this: *mut core::ffi::c_void, ppquery: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCUserSearchResultsEvent_Impl::Query(this) {
+                    Ok(ok__) => {
+                        ppquery.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Cookie<Identity: windows_core::IUnknownImpl + IRTCUserSearchResultsEvent_Impl>(this: *mut core::ffi::c_void, plcookie: *mut isize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCUserSearchResultsEvent_Impl::Cookie(this) {
+                    Ok(ok__) => {
+                        plcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StatusCode<Identity: windows_core::IUnknownImpl + IRTCUserSearchResultsEvent_Impl>(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCUserSearchResultsEvent_Impl::StatusCode(this) {
+                    Ok(ok__) => {
+                        plstatuscode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoreAvailable<Identity: windows_core::IUnknownImpl + IRTCUserSearchResultsEvent_Impl>(this: *mut core::ffi::c_void, pfmoreavailable: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCUserSearchResultsEvent_Impl::MoreAvailable(this) {
+                    Ok(ok__) => {
+                        pfmoreavailable.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EnumerateResults: EnumerateResults::<Identity>,
+            Results: Results::<Identity>,
+            Profile: Profile::<Identity>,
+            Query: Query::<Identity>,
+            Cookie: Cookie::<Identity>,
+            StatusCode: StatusCode::<Identity>,
+            MoreAvailable: MoreAvailable::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCUserSearchResultsEvent {}
 windows_core::imp::define_interface!(IRTCWatcher, IRTCWatcher_Vtbl, 0xc7cedad8_346b_4d1b_ac02_a2088df9be4f);
 impl core::ops::Deref for IRTCWatcher {
@@ -7896,6 +12140,36 @@ impl IRTCWatcher_Vtbl {
         iid == &<IRTCWatcher as windows_core::Interface>::IID || iid == &<IRTCPresenceContact as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRTCWatcher_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCWatcher_Vtbl
+where
+    IRTCPresenceContact_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn State<Identity: windows_core::IUnknownImpl + IRTCWatcher_Impl>(this: *mut core::ffi::c_void, penstate: *mut RTC_WATCHER_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCWatcher_Impl::State(this) {
+                    Ok(ok__) => {
+                        penstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetState<Identity: windows_core::IUnknownImpl + IRTCWatcher_Impl>(this: *mut core::ffi::c_void, enstate: RTC_WATCHER_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRTCWatcher_Impl::SetState(this, core::mem::transmute_copy(&enstate)).into()
+            }
+        }
+        Self {
+            base__: <IRTCPresenceContact_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            State: State::<Identity>,
+            SetState: SetState::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRTCWatcher {}
 windows_core::imp::define_interface!(IRTCWatcher2, IRTCWatcher2_Vtbl, 0xd4d9967f_d011_4b1d_91e3_aba78f96393d);
 impl core::ops::Deref for IRTCWatcher2 {
@@ -7962,6 +12236,38 @@ impl IRTCWatcher2_Vtbl {
         iid == &<IRTCWatcher2 as windows_core::Interface>::IID || iid == &<IRTCPresenceContact as windows_core::Interface>::IID || iid == &<IRTCWatcher as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRTCWatcher2_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCWatcher2_Vtbl
+where
+    IRTCWatcher_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Profile<Identity: windows_core::IUnknownImpl + IRTCWatcher2_Impl>(this: *mut core::ffi::c_void, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCWatcher2_Impl::Profile(this) {
+                    Ok(ok__) => {
+                        ppprofile.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Scope<Identity: windows_core::IUnknownImpl + IRTCWatcher2_Impl>(this: *mut core::ffi::c_void, penscope: *mut RTC_ACE_SCOPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCWatcher2_Impl::Scope(this) {
+                    Ok(ok__) => {
+                        penscope.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <IRTCWatcher_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Profile: Profile::<Identity>, Scope: Scope::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IRTCWatcher2 {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCWatcherEvent, IRTCWatcherEvent_Vtbl, 0xf30d7261_587a_424f_822c_312788f43548);
@@ -8016,6 +12322,27 @@ impl IRTCWatcherEvent_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCWatcherEvent_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCWatcherEvent_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Watcher<Identity: windows_core::IUnknownImpl + IRTCWatcherEvent_Impl>(this: *mut core::ffi::c_void, ppwatcher: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCWatcherEvent_Impl::Watcher(this) {
+                    Ok(ok__) => {
+                        ppwatcher.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Watcher: Watcher::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCWatcherEvent {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRTCWatcherEvent2, IRTCWatcherEvent2_Vtbl, 0xe52891e8_188c_49af_b005_98ed13f83f9c);
@@ -8090,6 +12417,43 @@ impl IRTCWatcherEvent2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRTCWatcherEvent2_Impl> windows_core::imp::TearOffVtable<Identity> for IRTCWatcherEvent2_Vtbl
+where
+    IRTCWatcherEvent_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn EventType<Identity: windows_core::IUnknownImpl + IRTCWatcherEvent2_Impl>(this: *mut core::ffi::c_void, peventtype: *mut RTC_WATCHER_EVENT_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCWatcherEvent2_Impl::EventType(this) {
+                    Ok(ok__) => {
+                        peventtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StatusCode<Identity: windows_core::IUnknownImpl + IRTCWatcherEvent2_Impl>(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRTCWatcherEvent2_Impl::StatusCode(this) {
+                    Ok(ok__) => {
+                        plstatuscode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IRTCWatcherEvent_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            EventType: EventType::<Identity>,
+            StatusCode: StatusCode::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRTCWatcherEvent2 {}
 windows_core::imp::define_interface!(ITransportSettingsInternal, ITransportSettingsInternal_Vtbl, 0x5123e076_29e3_4bfd_84fe_0192d411e3e8);
 windows_core::imp::interface_hierarchy!(ITransportSettingsInternal, windows_core::IUnknown);
@@ -8147,6 +12511,31 @@ impl ITransportSettingsInternal_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Networking_WinSock")]
+impl<Identity: windows_core::IUnknownImpl + ITransportSettingsInternal_Impl> windows_core::imp::TearOffVtable<Identity> for ITransportSettingsInternal_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ApplySetting<Identity: windows_core::IUnknownImpl + ITransportSettingsInternal_Impl>(this: *mut core::ffi::c_void, setting: *mut TRANSPORT_SETTING) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransportSettingsInternal_Impl::ApplySetting(this, core::mem::transmute_copy(&setting)).into()
+            }
+        }
+        unsafe extern "system" fn QuerySetting<Identity: windows_core::IUnknownImpl + ITransportSettingsInternal_Impl>(this: *mut core::ffi::c_void, setting: *mut TRANSPORT_SETTING) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITransportSettingsInternal_Impl::QuerySetting(this, core::mem::transmute_copy(&setting)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ApplySetting: ApplySetting::<Identity>,
+            QuerySetting: QuerySetting::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Networking_WinSock")]
 impl windows_core::RuntimeName for ITransportSettingsInternal {}
 pub const RTCAD_MICROPHONE: RTC_AUDIO_DEVICE = RTC_AUDIO_DEVICE(1i32);
 pub const RTCAD_SPEAKER: RTC_AUDIO_DEVICE = RTC_AUDIO_DEVICE(0i32);
@@ -31,6 +31,13 @@ impl DRendezvousSessionEvents_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + DRendezvousSessionEvents_Impl> windows_core::imp::TearOffVtable<Identity> for DRendezvousSessionEvents_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for DRendezvousSessionEvents {}
 windows_core::imp::define_interface!(IRendezvousApplication, IRendezvousApplication_Vtbl, 0x4f4d070b_a275_49fb_b10d_8ec26387b50d);
 windows_core::imp::interface_hierarchy!(IRendezvousApplication, windows_core::IUnknown);
@@ -65,6 +72,23 @@ impl IRendezvousApplication_Vtbl {
         iid == &<IRendezvousApplication as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRendezvousApplication_Impl> windows_core::imp::TearOffVtable<Identity> for IRendezvousApplication_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetRendezvousSession<Identity: windows_core::IUnknownImpl + IRendezvousApplication_Impl>(this: *mut core::ffi::c_void, prendezvoussession: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRendezvousApplication_Impl::SetRendezvousSession(this, core::mem::transmute_copy(&prendezvoussession)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetRendezvousSession: SetRendezvousSession::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRendezvousApplication {}
 windows_core::imp::define_interface!(IRendezvousSession, IRendezvousSession_Vtbl, 0x9ba4b1dd_8b0c_48b7_9e7c_2f25857c8df5);
 windows_core::imp::interface_hierarchy!(IRendezvousSession, windows_core::IUnknown);
@@ -174,6 +198,69 @@ impl IRendezvousSession_Vtbl {
         iid == &<IRendezvousSession as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRendezvousSession_Impl> windows_core::imp::TearOffVtable<Identity> for IRendezvousSession_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn State<Identity: windows_core::IUnknownImpl + IRendezvousSession_Impl>(this: *mut core::ffi::c_void, psessionstate: *mut RENDEZVOUS_SESSION_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRendezvousSession_Impl::State(this) {
+                    Ok(ok__) => {
+                        psessionstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoteUser<Identity: windows_core::IUnknownImpl + IRendezvousSession_Impl>(this: *mut core::ffi::c_void, bstrusername: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRendezvousSession_Impl::RemoteUser(this) {
+                    Ok(ok__) => {
+                        bstrusername.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Flags<Identity: windows_core::IUnknownImpl + IRendezvousSession_Impl>(this: *mut core::ffi::c_void, pflags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRendezvousSession_Impl::Flags(this) {
+                    Ok(ok__) => {
+                        pflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SendContextData<Identity: windows_core::IUnknownImpl + IRendezvousSession_Impl>(this: *mut core::ffi::c_void, bstrdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRendezvousSession_Impl::SendContextData(this, core::mem::transmute(&bstrdata)).into()
+            }
+        }
+        unsafe extern "system" fn Terminate<Identity: windows_core::IUnknownImpl + IRendezvousSession_Impl>(this: *mut core::ffi::c_void, hr: windows_core::HRESULT, bstrappdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRendezvousSession_Impl::Terminate(this, core::mem::transmute_copy(&hr), core::mem::transmute(&bstrappdata)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            State: State::<Identity>,
+            RemoteUser: RemoteUser::<Identity>,
+            Flags: Flags::<Identity>,
+            SendContextData: SendContextData::<Identity>,
+            Terminate: Terminate::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRendezvousSession {}
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
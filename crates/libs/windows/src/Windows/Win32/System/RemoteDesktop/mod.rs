@@ -1157,6 +1157,317 @@ impl IADsTSUserEx_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl> windows_core::imp::TearOffVtable<Identity> for IADsTSUserEx_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn TerminalServicesProfilePath<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IADsTSUserEx_Impl::TerminalServicesProfilePath(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTerminalServicesProfilePath<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, pnewval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IADsTSUserEx_Impl::SetTerminalServicesProfilePath(this, core::mem::transmute(&pnewval)).into()
+            }
+        }
+        unsafe extern "system" fn TerminalServicesHomeDirectory<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IADsTSUserEx_Impl::TerminalServicesHomeDirectory(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTerminalServicesHomeDirectory<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, pnewval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IADsTSUserEx_Impl::SetTerminalServicesHomeDirectory(this, core::mem::transmute(&pnewval)).into()
+            }
+        }
+        unsafe extern "system" fn TerminalServicesHomeDrive<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IADsTSUserEx_Impl::TerminalServicesHomeDrive(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTerminalServicesHomeDrive<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, pnewval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IADsTSUserEx_Impl::SetTerminalServicesHomeDrive(this, core::mem::transmute(&pnewval)).into()
+            }
+        }
+        unsafe extern "system" fn AllowLogon<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IADsTSUserEx_Impl::AllowLogon(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAllowLogon<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IADsTSUserEx_Impl::SetAllowLogon(this, core::mem::transmute_copy(&newval)).into()
+            }
+        }
+        unsafe extern "system" fn EnableRemoteControl<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IADsTSUserEx_Impl::EnableRemoteControl(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEnableRemoteControl<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IADsTSUserEx_Impl::SetEnableRemoteControl(this, core::mem::transmute_copy(&newval)).into()
+            }
+        }
+        unsafe extern "system" fn MaxDisconnectionTime<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IADsTSUserEx_Impl::MaxDisconnectionTime(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMaxDisconnectionTime<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IADsTSUserEx_Impl::SetMaxDisconnectionTime(this, core::mem::transmute_copy(&newval)).into()
+            }
+        }
+        unsafe extern "system" fn MaxConnectionTime<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IADsTSUserEx_Impl::MaxConnectionTime(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMaxConnectionTime<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IADsTSUserEx_Impl::SetMaxConnectionTime(this, core::mem::transmute_copy(&newval)).into()
+            }
+        }
+        unsafe extern "system" fn MaxIdleTime<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IADsTSUserEx_Impl::MaxIdleTime(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMaxIdleTime<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IADsTSUserEx_Impl::SetMaxIdleTime(this, core::mem::transmute_copy(&newval)).into()
+            }
+        }
+        unsafe extern "system" fn ReconnectionAction<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, pnewval: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IADsTSUserEx_Impl::ReconnectionAction(this) {
+                    Ok(ok__) => {
+                        pnewval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetReconnectionAction<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IADsTSUserEx_Impl::SetReconnectionAction(this, core::mem::transmute_copy(&newval)).into()
+            }
+        }
+        unsafe extern "system" fn BrokenConnectionAction<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, pnewval: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IADsTSUserEx_Impl::BrokenConnectionAction(this) {
+                    Ok(ok__) => {
+                        pnewval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBrokenConnectionAction<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IADsTSUserEx_Impl::SetBrokenConnectionAction(this, core::mem::transmute_copy(&newval)).into()
+            }
+        }
+        unsafe extern "system" fn ConnectClientDrivesAtLogon<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, pnewval: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IADsTSUserEx_Impl::ConnectClientDrivesAtLogon(this) {
+                    Ok(ok__) => {
+                        pnewval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetConnectClientDrivesAtLogon<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IADsTSUserEx_Impl::SetConnectClientDrivesAtLogon(this, core::mem::transmute_copy(&newval)).into()
+            }
+        }
+        unsafe extern "system" fn ConnectClientPrintersAtLogon<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IADsTSUserEx_Impl::ConnectClientPrintersAtLogon(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetConnectClientPrintersAtLogon<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IADsTSUserEx_Impl::SetConnectClientPrintersAtLogon(this, core::mem::transmute_copy(&newval)).into()
+            }
+        }
+        unsafe extern "system" fn DefaultToMainPrinter<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IADsTSUserEx_Impl::DefaultToMainPrinter(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDefaultToMainPrinter<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IADsTSUserEx_Impl::SetDefaultToMainPrinter(this, core::mem::transmute_copy(&newval)).into()
+            }
+        }
+        unsafe extern "system" fn TerminalServicesWorkDirectory<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IADsTSUserEx_Impl::TerminalServicesWorkDirectory(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTerminalServicesWorkDirectory<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, pnewval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IADsTSUserEx_Impl::SetTerminalServicesWorkDirectory(this, core::mem::transmute(&pnewval)).into()
+            }
+        }
+        unsafe extern "system" fn TerminalServicesInitialProgram<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IADsTSUserEx_Impl::TerminalServicesInitialProgram(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTerminalServicesInitialProgram<Identity: windows_core::IUnknownImpl + IADsTSUserEx_Impl>(this: *mut core::ffi::c_void, pnewval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IADsTSUserEx_Impl::SetTerminalServicesInitialProgram(this, core::mem::transmute(&pnewval)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            TerminalServicesProfilePath: TerminalServicesProfilePath::<Identity>,
+            SetTerminalServicesProfilePath: SetTerminalServicesProfilePath::<Identity>,
+            TerminalServicesHomeDirectory: TerminalServicesHomeDirectory::<Identity>,
+            SetTerminalServicesHomeDirectory: SetTerminalServicesHomeDirectory::<Identity>,
+            TerminalServicesHomeDrive: TerminalServicesHomeDrive::<Identity>,
+            SetTerminalServicesHomeDrive: SetTerminalServicesHomeDrive::<Identity>,
+            AllowLogon: AllowLogon::<Identity>,
+            SetAllowLogon: SetAllowLogon::<Identity>,
+            EnableRemoteControl: EnableRemoteControl::<Identity>,
+            SetEnableRemoteControl: SetEnableRemoteControl::<Identity>,
+            MaxDisconnectionTime: MaxDisconnectionTime::<Identity>,
+            SetMaxDisconnectionTime: SetMaxDisconnectionTime::<Identity>,
+            MaxConnectionTime: MaxConnectionTime::<Identity>,
+            SetMaxConnectionTime: SetMaxConnectionTime::<Identity>,
+            MaxIdleTime: MaxIdleTime::<Identity>,
+            SetMaxIdleTime: SetMaxIdleTime::<Identity>,
+            ReconnectionAction: ReconnectionAction::<Identity>,
+            SetReconnectionAction: SetReconnectionAction::<Identity>,
+            BrokenConnectionAction: BrokenConnectionAction::<Identity>,
+            SetBrokenConnectionAction: SetBrokenConnectionAction::<Identity>,
+            ConnectClientDrivesAtLogon: ConnectClientDrivesAtLogon::<Identity>,
+            SetConnectClientDrivesAtLogon: SetConnectClientDrivesAtLogon::<Identity>,
+            ConnectClientPrintersAtLogon: ConnectClientPrintersAtLogon::<Identity>,
+            SetConnectClientPrintersAtLogon: SetConnectClientPrintersAtLogon::<Identity>,
+            DefaultToMainPrinter: DefaultToMainPrinter::<Identity>,
+            SetDefaultToMainPrinter: SetDefaultToMainPrinter::<Identity>,
+            TerminalServicesWorkDirectory: TerminalServicesWorkDirectory::<Identity>,
+            SetTerminalServicesWorkDirectory: SetTerminalServicesWorkDirectory::<Identity>,
+            TerminalServicesInitialProgram: TerminalServicesInitialProgram::<Identity>,
+            SetTerminalServicesInitialProgram: SetTerminalServicesInitialProgram::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IADsTSUserEx {}
 windows_core::imp::define_interface!(IAudioDeviceEndpoint, IAudioDeviceEndpoint_Vtbl, 0xd4952f5a_a0b2_4cc4_8b82_9358488dd8ac);
 windows_core::imp::interface_hierarchy!(IAudioDeviceEndpoint, windows_core::IUnknown);
@@ -1245,6 +1556,56 @@ impl IAudioDeviceEndpoint_Vtbl {
         iid == &<IAudioDeviceEndpoint as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioDeviceEndpoint_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioDeviceEndpoint_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetBuffer<Identity: windows_core::IUnknownImpl + IAudioDeviceEndpoint_Impl>(this: *mut core::ffi::c_void, maxperiod: i64, u32latencycoefficient: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioDeviceEndpoint_Impl::SetBuffer(this, core::mem::transmute_copy(&maxperiod), core::mem::transmute_copy(&u32latencycoefficient)).into()
+            }
+        }
+        unsafe extern "system" fn GetRTCaps<Identity: windows_core::IUnknownImpl + IAudioDeviceEndpoint_Impl>(this: *mut core::ffi::c_void, pbisrtcapable: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioDeviceEndpoint_Impl::GetRTCaps(this) {
+                    Ok(ok__) => {
+                        pbisrtcapable.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetEventDrivenCapable<Identity: windows_core::IUnknownImpl + IAudioDeviceEndpoint_Impl>(this: *mut core::ffi::c_void, pbiseventcapable: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioDeviceEndpoint_Impl::GetEventDrivenCapable(this) {
+                    Ok(ok__) => {
+                        pbiseventcapable.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn WriteExclusiveModeParametersToSharedMemory<Identity: windows_core::IUnknownImpl + IAudioDeviceEndpoint_Impl>(this: *mut core::ffi::c_void, htargetprocess: usize, hnsperiod: i64, hnsbufferduration: i64, u32latencycoefficient: u32, pu32sharedmemorysize: *mut u32, phsharedmemory: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioDeviceEndpoint_Impl::WriteExclusiveModeParametersToSharedMemory(this, core::mem::transmute_copy(&htargetprocess), core::mem::transmute_copy(&hnsperiod), core::mem::transmute_copy(&hnsbufferduration), core::mem::transmute_copy(&u32latencycoefficient), core::mem::transmute_copy(&pu32sharedmemorysize), core::mem::transmute_copy(&phsharedmemory)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetBuffer: SetBuffer::<Identity>,
+            GetRTCaps: GetRTCaps::<Identity>,
+            GetEventDrivenCapable: GetEventDrivenCapable::<Identity>,
+            WriteExclusiveModeParametersToSharedMemory: WriteExclusiveModeParametersToSharedMemory::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioDeviceEndpoint {}
 windows_core::imp::define_interface!(IAudioEndpoint, IAudioEndpoint_Vtbl, 0x30a99515_1527_4451_af9f_00c5f0234daf);
 windows_core::imp::interface_hierarchy!(IAudioEndpoint, windows_core::IUnknown);
@@ -1361,6 +1722,70 @@ impl IAudioEndpoint_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Media_Audio")]
+impl<Identity: windows_core::IUnknownImpl + IAudioEndpoint_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioEndpoint_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFrameFormat<Identity: windows_core::IUnknownImpl + IAudioEndpoint_Impl>(this: *mut core::ffi::c_void, ppformat: *mut *mut super::super::Media::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioEndpoint_Impl::GetFrameFormat(this) {
+                    Ok(ok__) => {
+                        ppformat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFramesPerPacket<Identity: windows_core::IUnknownImpl + IAudioEndpoint_Impl>(this: *mut core::ffi::c_void, pframesperpacket: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioEndpoint_Impl::GetFramesPerPacket(this) {
+                    Ok(ok__) => {
+                        pframesperpacket.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLatency<Identity: windows_core::IUnknownImpl + IAudioEndpoint_Impl>(this: *mut core::ffi::c_void, platency: *mut i64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IAudioEndpoint_Impl::GetLatency(this) {
+                    Ok(ok__) => {
+                        platency.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetStreamFlags<Identity: windows_core::IUnknownImpl + IAudioEndpoint_Impl>(this: *mut core::ffi::c_void, streamflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpoint_Impl::SetStreamFlags(this, core::mem::transmute_copy(&streamflags)).into()
+            }
+        }
+        unsafe extern "system" fn SetEventHandle<Identity: windows_core::IUnknownImpl + IAudioEndpoint_Impl>(this: *mut core::ffi::c_void, eventhandle: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpoint_Impl::SetEventHandle(this, core::mem::transmute_copy(&eventhandle)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFrameFormat: GetFrameFormat::<Identity>,
+            GetFramesPerPacket: GetFramesPerPacket::<Identity>,
+            GetLatency: GetLatency::<Identity>,
+            SetStreamFlags: SetStreamFlags::<Identity>,
+            SetEventHandle: SetEventHandle::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Media_Audio")]
 impl windows_core::RuntimeName for IAudioEndpoint {}
 windows_core::imp::define_interface!(IAudioEndpointControl, IAudioEndpointControl_Vtbl, 0xc684b72a_6df4_4774_bdf9_76b77509b653);
 windows_core::imp::interface_hierarchy!(IAudioEndpointControl, windows_core::IUnknown);
@@ -1419,6 +1844,37 @@ impl IAudioEndpointControl_Vtbl {
         iid == &<IAudioEndpointControl as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioEndpointControl_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioEndpointControl_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Start<Identity: windows_core::IUnknownImpl + IAudioEndpointControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointControl_Impl::Start(this).into()
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IAudioEndpointControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointControl_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + IAudioEndpointControl_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointControl_Impl::Stop(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Start: Start::<Identity>,
+            Reset: Reset::<Identity>,
+            Stop: Stop::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioEndpointControl {}
 windows_core::imp::define_interface!(IAudioEndpointRT, IAudioEndpointRT_Vtbl, 0xdfd2005f_a6e5_4d39_a265_939ada9fbb4d);
 windows_core::imp::interface_hierarchy!(IAudioEndpointRT, windows_core::IUnknown);
@@ -1489,6 +1945,44 @@ impl IAudioEndpointRT_Vtbl {
         iid == &<IAudioEndpointRT as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IAudioEndpointRT_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioEndpointRT_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCurrentPadding<Identity: windows_core::IUnknownImpl + IAudioEndpointRT_Impl>(this: *mut core::ffi::c_void, ppadding: *mut i64, paecurrentposition: *mut AE_CURRENT_POSITION) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointRT_Impl::GetCurrentPadding(this, core::mem::transmute_copy(&ppadding), core::mem::transmute_copy(&paecurrentposition))
+            }
+        }
+        unsafe extern "system" fn ProcessingComplete<Identity: windows_core::IUnknownImpl + IAudioEndpointRT_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointRT_Impl::ProcessingComplete(this)
+            }
+        }
+        unsafe extern "system" fn SetPinInactive<Identity: windows_core::IUnknownImpl + IAudioEndpointRT_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointRT_Impl::SetPinInactive(this).into()
+            }
+        }
+        unsafe extern "system" fn SetPinActive<Identity: windows_core::IUnknownImpl + IAudioEndpointRT_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioEndpointRT_Impl::SetPinActive(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCurrentPadding: GetCurrentPadding::<Identity>,
+            ProcessingComplete: ProcessingComplete::<Identity>,
+            SetPinInactive: SetPinInactive::<Identity>,
+            SetPinActive: SetPinActive::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IAudioEndpointRT {}
 windows_core::imp::define_interface!(IAudioInputEndpointRT, IAudioInputEndpointRT_Vtbl, 0x8026ab61_92b2_43c1_a1df_5c37ebd08d82);
 windows_core::imp::interface_hierarchy!(IAudioInputEndpointRT, windows_core::IUnknown);
@@ -1543,15 +2037,47 @@ impl IAudioInputEndpointRT_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            GetInputDataPointer: GetInputDataPointer::<Identity, OFFSET>,
-            ReleaseInputDataPointer: ReleaseInputDataPointer::<Identity, OFFSET>,
-            PulseEndpoint: PulseEndpoint::<Identity, OFFSET>,
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            GetInputDataPointer: GetInputDataPointer::<Identity, OFFSET>,
+            ReleaseInputDataPointer: ReleaseInputDataPointer::<Identity, OFFSET>,
+            PulseEndpoint: PulseEndpoint::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioInputEndpointRT as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_Media_Audio_Apo")]
+impl<Identity: windows_core::IUnknownImpl + IAudioInputEndpointRT_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioInputEndpointRT_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetInputDataPointer<Identity: windows_core::IUnknownImpl + IAudioInputEndpointRT_Impl>(this: *mut core::ffi::c_void, pconnectionproperty: *mut super::super::Media::Audio::Apo::APO_CONNECTION_PROPERTY, paetimestamp: *mut AE_CURRENT_POSITION) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioInputEndpointRT_Impl::GetInputDataPointer(this, core::mem::transmute_copy(&pconnectionproperty), core::mem::transmute_copy(&paetimestamp))
+            }
+        }
+        unsafe extern "system" fn ReleaseInputDataPointer<Identity: windows_core::IUnknownImpl + IAudioInputEndpointRT_Impl>(this: *mut core::ffi::c_void, u32framecount: u32, pdatapointer: usize) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioInputEndpointRT_Impl::ReleaseInputDataPointer(this, core::mem::transmute_copy(&u32framecount), core::mem::transmute_copy(&pdatapointer))
+            }
+        }
+        unsafe extern "system" fn PulseEndpoint<Identity: windows_core::IUnknownImpl + IAudioInputEndpointRT_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioInputEndpointRT_Impl::PulseEndpoint(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetInputDataPointer: GetInputDataPointer::<Identity>,
+            ReleaseInputDataPointer: ReleaseInputDataPointer::<Identity>,
+            PulseEndpoint: PulseEndpoint::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IAudioInputEndpointRT as windows_core::Interface>::IID
-    }
+    };
 }
 #[cfg(feature = "Win32_Media_Audio_Apo")]
 impl windows_core::RuntimeName for IAudioInputEndpointRT {}
@@ -1619,6 +2145,38 @@ impl IAudioOutputEndpointRT_Vtbl {
     }
 }
 #[cfg(feature = "Win32_Media_Audio_Apo")]
+impl<Identity: windows_core::IUnknownImpl + IAudioOutputEndpointRT_Impl> windows_core::imp::TearOffVtable<Identity> for IAudioOutputEndpointRT_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetOutputDataPointer<Identity: windows_core::IUnknownImpl + IAudioOutputEndpointRT_Impl>(this: *mut core::ffi::c_void, u32framecount: u32, paetimestamp: *const AE_CURRENT_POSITION) -> usize {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioOutputEndpointRT_Impl::GetOutputDataPointer(this, core::mem::transmute_copy(&u32framecount), core::mem::transmute_copy(&paetimestamp))
+            }
+        }
+        unsafe extern "system" fn ReleaseOutputDataPointer<Identity: windows_core::IUnknownImpl + IAudioOutputEndpointRT_Impl>(this: *mut core::ffi::c_void, pconnectionproperty: *const super::super::Media::Audio::Apo::APO_CONNECTION_PROPERTY) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioOutputEndpointRT_Impl::ReleaseOutputDataPointer(this, core::mem::transmute_copy(&pconnectionproperty))
+            }
+        }
+        unsafe extern "system" fn PulseEndpoint<Identity: windows_core::IUnknownImpl + IAudioOutputEndpointRT_Impl>(this: *mut core::ffi::c_void) {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAudioOutputEndpointRT_Impl::PulseEndpoint(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetOutputDataPointer: GetOutputDataPointer::<Identity>,
+            ReleaseOutputDataPointer: ReleaseOutputDataPointer::<Identity>,
+            PulseEndpoint: PulseEndpoint::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_Media_Audio_Apo")]
 impl windows_core::RuntimeName for IAudioOutputEndpointRT {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRemoteDesktopClient, IRemoteDesktopClient_Vtbl, 0x57d25668_625a_4905_be4e_304caa13f89c);
@@ -1808,6 +2366,105 @@ impl IRemoteDesktopClient_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRemoteDesktopClient_Impl> windows_core::imp::TearOffVtable<Identity> for IRemoteDesktopClient_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Connect<Identity: windows_core::IUnknownImpl + IRemoteDesktopClient_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDesktopClient_Impl::Connect(this).into()
+            }
+        }
+        unsafe extern "system" fn Disconnect<Identity: windows_core::IUnknownImpl + IRemoteDesktopClient_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDesktopClient_Impl::Disconnect(this).into()
+            }
+        }
+        unsafe extern "system" fn Reconnect<Identity: windows_core::IUnknownImpl + IRemoteDesktopClient_Impl>(this: *mut core::ffi::c_void, width: u32, height: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDesktopClient_Impl::Reconnect(this, core::mem::transmute_copy(&width), core::mem::transmute_copy(&height)).into()
+            }
+        }
+        unsafe extern "system" fn Settings<Identity: windows_core::IUnknownImpl + IRemoteDesktopClient_Impl>(this: *mut core::ffi::c_void, settings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRemoteDesktopClient_Impl::Settings(this) {
+                    Ok(ok__) => {
+                        settings.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Actions<Identity: windows_core::IUnknownImpl + IRemoteDesktopClient_Impl>(this: *mut core::ffi::c_void, actions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRemoteDesktopClient_Impl::Actions(this) {
+                    Ok(ok__) => {
+                        actions.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn TouchPointer<Identity: windows_core::IUnknownImpl + IRemoteDesktopClient_Impl>(this: *mut core::ffi::c_void, touchpointer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRemoteDesktopClient_Impl::TouchPointer(this) {
+                    Ok(ok__) => {
+                        touchpointer.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeleteSavedCredentials<Identity: windows_core::IUnknownImpl + IRemoteDesktopClient_Impl>(this: *mut core::ffi::c_void, servername: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDesktopClient_Impl::DeleteSavedCredentials(this, core::mem::transmute(&servername)).into()
+            }
+        }
+        unsafe extern "system" fn UpdateSessionDisplaySettings<Identity: windows_core::IUnknownImpl + IRemoteDesktopClient_Impl>(this: *mut core::ffi::c_void, width: u32, height: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDesktopClient_Impl::UpdateSessionDisplaySettings(this, core::mem::transmute_copy(&width), core::mem::transmute_copy(&height)).into()
+            }
+        }
+        unsafe extern "system" fn attachEvent<Identity: windows_core::IUnknownImpl + IRemoteDesktopClient_Impl>(this: *mut core::ffi::c_void, eventname: *mut core::ffi::c_void, callback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDesktopClient_Impl::attachEvent(this, core::mem::transmute(&eventname), core::mem::transmute_copy(&callback)).into()
+            }
+        }
+        unsafe extern "system" fn detachEvent<Identity: windows_core::IUnknownImpl + IRemoteDesktopClient_Impl>(this: *mut core::ffi::c_void, eventname: *mut core::ffi::c_void, callback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDesktopClient_Impl::detachEvent(this, core::mem::transmute(&eventname), core::mem::transmute_copy(&callback)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Connect: Connect::<Identity>,
+            Disconnect: Disconnect::<Identity>,
+            Reconnect: Reconnect::<Identity>,
+            Settings: Settings::<Identity>,
+            Actions: Actions::<Identity>,
+            TouchPointer: TouchPointer::<Identity>,
+            DeleteSavedCredentials: DeleteSavedCredentials::<Identity>,
+            UpdateSessionDisplaySettings: UpdateSessionDisplaySettings::<Identity>,
+            attachEvent: attachEvent::<Identity>,
+            detachEvent: detachEvent::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRemoteDesktopClient {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRemoteDesktopClientActions, IRemoteDesktopClientActions_Vtbl, 0x7d54bc4e_1028_45d4_8b0a_b9b6bffba176);
@@ -1901,6 +2558,51 @@ impl IRemoteDesktopClientActions_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRemoteDesktopClientActions_Impl> windows_core::imp::TearOffVtable<Identity> for IRemoteDesktopClientActions_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SuspendScreenUpdates<Identity: windows_core::IUnknownImpl + IRemoteDesktopClientActions_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDesktopClientActions_Impl::SuspendScreenUpdates(this).into()
+            }
+        }
+        unsafe extern "system" fn ResumeScreenUpdates<Identity: windows_core::IUnknownImpl + IRemoteDesktopClientActions_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDesktopClientActions_Impl::ResumeScreenUpdates(this).into()
+            }
+        }
+        unsafe extern "system" fn ExecuteRemoteAction<Identity: windows_core::IUnknownImpl + IRemoteDesktopClientActions_Impl>(this: *mut core::ffi::c_void, remoteaction: RemoteActionType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDesktopClientActions_Impl::ExecuteRemoteAction(this, core::mem::transmute_copy(&remoteaction)).into()
+            }
+        }
+        unsafe extern "system" fn GetSnapshot<Identity: windows_core::IUnknownImpl + IRemoteDesktopClientActions_Impl>(this: *mut core::ffi::c_void, snapshotencoding: SnapshotEncodingType, snapshotformat: SnapshotFormatType, snapshotwidth: u32, snapshotheight: u32, snapshotdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRemoteDesktopClientActions_Impl::GetSnapshot(this, core::mem::transmute_copy(&snapshotencoding), core::mem::transmute_copy(&snapshotformat), core::mem::transmute_copy(&snapshotwidth), core::mem::transmute_copy(&snapshotheight)) {
+                    Ok(ok__) => {
+                        snapshotdata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SuspendScreenUpdates: SuspendScreenUpdates::<Identity>,
+            ResumeScreenUpdates: ResumeScreenUpdates::<Identity>,
+            ExecuteRemoteAction: ExecuteRemoteAction::<Identity>,
+            GetSnapshot: GetSnapshot::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRemoteDesktopClientActions {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRemoteDesktopClientSettings, IRemoteDesktopClientSettings_Vtbl, 0x48a0f2a7_2713_431f_bbac_6f4558e7d64d);
@@ -2011,6 +2713,57 @@ impl IRemoteDesktopClientSettings_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRemoteDesktopClientSettings_Impl> windows_core::imp::TearOffVtable<Identity> for IRemoteDesktopClientSettings_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ApplySettings<Identity: windows_core::IUnknownImpl + IRemoteDesktopClientSettings_Impl>(this: *mut core::ffi::c_void, rdpfilecontents: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDesktopClientSettings_Impl::ApplySettings(this, core::mem::transmute(&rdpfilecontents)).into()
+            }
+        }
+        unsafe extern "system" fn RetrieveSettings<Identity: windows_core::IUnknownImpl + IRemoteDesktopClientSettings_Impl>(this: *mut core::ffi::c_void, rdpfilecontents: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRemoteDesktopClientSettings_Impl::RetrieveSettings(this) {
+                    Ok(ok__) => {
+                        rdpfilecontents.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRdpProperty<Identity: windows_core::IUnknownImpl + IRemoteDesktopClientSettings_Impl>(this: *mut core::ffi::c_void, propertyname: *mut core::ffi::c_void, value: *mut super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRemoteDesktopClientSettings_Impl::GetRdpProperty(this, core::mem::transmute(&propertyname)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRdpProperty<Identity: windows_core::IUnknownImpl + IRemoteDesktopClientSettings_Impl>(this: *mut core::ffi::c_void, propertyname: *mut core::ffi::c_void, value: super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDesktopClientSettings_Impl::SetRdpProperty(this, core::mem::transmute(&propertyname), core::mem::transmute(&value)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ApplySettings: ApplySettings::<Identity>,
+            RetrieveSettings: RetrieveSettings::<Identity>,
+            GetRdpProperty: GetRdpProperty::<Identity>,
+            SetRdpProperty: SetRdpProperty::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRemoteDesktopClientSettings {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRemoteDesktopClientTouchPointer, IRemoteDesktopClientTouchPointer_Vtbl, 0x260ec22d_8cbc_44b5_9e88_2a37f6c93ae9);
@@ -2146,6 +2899,77 @@ impl IRemoteDesktopClientTouchPointer_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRemoteDesktopClientTouchPointer_Impl> windows_core::imp::TearOffVtable<Identity> for IRemoteDesktopClientTouchPointer_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetEnabled<Identity: windows_core::IUnknownImpl + IRemoteDesktopClientTouchPointer_Impl>(this: *mut core::ffi::c_void, enabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDesktopClientTouchPointer_Impl::SetEnabled(this, core::mem::transmute_copy(&enabled)).into()
+            }
+        }
+        unsafe extern "system" fn Enabled<Identity: windows_core::IUnknownImpl + IRemoteDesktopClientTouchPointer_Impl>(this: *mut core::ffi::c_void, enabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRemoteDesktopClientTouchPointer_Impl::Enabled(this) {
+                    Ok(ok__) => {
+                        enabled.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEventsEnabled<Identity: windows_core::IUnknownImpl + IRemoteDesktopClientTouchPointer_Impl>(this: *mut core::ffi::c_void, eventsenabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDesktopClientTouchPointer_Impl::SetEventsEnabled(this, core::mem::transmute_copy(&eventsenabled)).into()
+            }
+        }
+        unsafe extern "system" fn EventsEnabled<Identity: windows_core::IUnknownImpl + IRemoteDesktopClientTouchPointer_Impl>(this: *mut core::ffi::c_void, eventsenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRemoteDesktopClientTouchPointer_Impl::EventsEnabled(this) {
+                    Ok(ok__) => {
+                        eventsenabled.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPointerSpeed<Identity: windows_core::IUnknownImpl + IRemoteDesktopClientTouchPointer_Impl>(this: *mut core::ffi::c_void, pointerspeed: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteDesktopClientTouchPointer_Impl::SetPointerSpeed(this, core::mem::transmute_copy(&pointerspeed)).into()
+            }
+        }
+        unsafe extern "system" fn PointerSpeed<Identity: windows_core::IUnknownImpl + IRemoteDesktopClientTouchPointer_Impl>(this: *mut core::ffi::c_void, pointerspeed: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRemoteDesktopClientTouchPointer_Impl::PointerSpeed(this) {
+                    Ok(ok__) => {
+                        pointerspeed.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetEnabled: SetEnabled::<Identity>,
+            Enabled: Enabled::<Identity>,
+            SetEventsEnabled: SetEventsEnabled::<Identity>,
+            EventsEnabled: EventsEnabled::<Identity>,
+            SetPointerSpeed: SetPointerSpeed::<Identity>,
+            PointerSpeed: PointerSpeed::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRemoteDesktopClientTouchPointer {}
 windows_core::imp::define_interface!(IRemoteSystemAdditionalInfoProvider, IRemoteSystemAdditionalInfoProvider_Vtbl, 0xeeaa3d5f_ec63_4d27_af38_e86b1d7292cb);
 windows_core::imp::interface_hierarchy!(IRemoteSystemAdditionalInfoProvider, windows_core::IUnknown);
@@ -2181,6 +3005,20 @@ impl IRemoteSystemAdditionalInfoProvider_Vtbl {
         iid == &<IRemoteSystemAdditionalInfoProvider as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRemoteSystemAdditionalInfoProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IRemoteSystemAdditionalInfoProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAdditionalInfo<Identity: windows_core::IUnknownImpl + IRemoteSystemAdditionalInfoProvider_Impl>(this: *mut core::ffi::c_void, deduplicationid: *mut *mut core::ffi::c_void, riid: *const windows_core::GUID, mapview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRemoteSystemAdditionalInfoProvider_Impl::GetAdditionalInfo(this, core::mem::transmute_copy(&deduplicationid), core::mem::transmute_copy(&riid), core::mem::transmute_copy(&mapview)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetAdditionalInfo: GetAdditionalInfo::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IRemoteSystemAdditionalInfoProvider {}
 windows_core::imp::define_interface!(ITSGAccountingEngine, ITSGAccountingEngine_Vtbl, 0x4ce2a0c9_e874_4f1a_86f4_06bbb9115338);
 windows_core::imp::interface_hierarchy!(ITSGAccountingEngine, windows_core::IUnknown);
@@ -2212,6 +3050,20 @@ impl ITSGAccountingEngine_Vtbl {
         iid == &<ITSGAccountingEngine as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITSGAccountingEngine_Impl> windows_core::imp::TearOffVtable<Identity> for ITSGAccountingEngine_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DoAccounting<Identity: windows_core::IUnknownImpl + ITSGAccountingEngine_Impl>(this: *mut core::ffi::c_void, accountingdatatype: AAAccountingDataType, accountingdata: AAAccountingData) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITSGAccountingEngine_Impl::DoAccounting(this, core::mem::transmute_copy(&accountingdatatype), core::mem::transmute(&accountingdata)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, DoAccounting: DoAccounting::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ITSGAccountingEngine {}
 windows_core::imp::define_interface!(ITSGAuthenticateUserSink, ITSGAuthenticateUserSink_Vtbl, 0x2c3e2e73_a782_47f9_8dfb_77ee1ed27a03);
 windows_core::imp::interface_hierarchy!(ITSGAuthenticateUserSink, windows_core::IUnknown);
@@ -2282,6 +3134,44 @@ impl ITSGAuthenticateUserSink_Vtbl {
         iid == &<ITSGAuthenticateUserSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITSGAuthenticateUserSink_Impl> windows_core::imp::TearOffVtable<Identity> for ITSGAuthenticateUserSink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnUserAuthenticated<Identity: windows_core::IUnknownImpl + ITSGAuthenticateUserSink_Impl>(this: *mut core::ffi::c_void, username: *mut core::ffi::c_void, userdomain: *mut core::ffi::c_void, context: usize, usertoken: super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITSGAuthenticateUserSink_Impl::OnUserAuthenticated(this, core::mem::transmute(&username), core::mem::transmute(&userdomain), core::mem::transmute_copy(&context), core::mem::transmute_copy(&usertoken)).into()
+            }
+        }
+        unsafe extern "system" fn OnUserAuthenticationFailed<Identity: windows_core::IUnknownImpl + ITSGAuthenticateUserSink_Impl>(this: *mut core::ffi::c_void, context: usize, genericerrorcode: windows_core::HRESULT, specificerrorcode: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITSGAuthenticateUserSink_Impl::OnUserAuthenticationFailed(this, core::mem::transmute_copy(&context), core::mem::transmute_copy(&genericerrorcode), core::mem::transmute_copy(&specificerrorcode)).into()
+            }
+        }
+        unsafe extern "system" fn ReauthenticateUser<Identity: windows_core::IUnknownImpl + ITSGAuthenticateUserSink_Impl>(this: *mut core::ffi::c_void, context: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITSGAuthenticateUserSink_Impl::ReauthenticateUser(this, core::mem::transmute_copy(&context)).into()
+            }
+        }
+        unsafe extern "system" fn DisconnectUser<Identity: windows_core::IUnknownImpl + ITSGAuthenticateUserSink_Impl>(this: *mut core::ffi::c_void, context: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITSGAuthenticateUserSink_Impl::DisconnectUser(this, core::mem::transmute_copy(&context)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnUserAuthenticated: OnUserAuthenticated::<Identity>,
+            OnUserAuthenticationFailed: OnUserAuthenticationFailed::<Identity>,
+            ReauthenticateUser: ReauthenticateUser::<Identity>,
+            DisconnectUser: DisconnectUser::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITSGAuthenticateUserSink {}
 windows_core::imp::define_interface!(ITSGAuthenticationEngine, ITSGAuthenticationEngine_Vtbl, 0x9ee3e5bf_04ab_4691_998c_d7f622321a56);
 windows_core::imp::interface_hierarchy!(ITSGAuthenticationEngine, windows_core::IUnknown);
@@ -2315,21 +3205,45 @@ impl ITSGAuthenticationEngine_Vtbl {
                 ITSGAuthenticationEngine_Impl::AuthenticateUser(this, core::mem::transmute(&mainsessionid), core::mem::transmute_copy(&cookiedata), core::mem::transmute_copy(&numcookiebytes), core::mem::transmute_copy(&context), core::mem::transmute_copy(&psink)).into()
             }
         }
-        unsafe extern "system" fn CancelAuthentication<Identity: ITSGAuthenticationEngine_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, mainsessionid: windows_core::GUID, context: usize) -> windows_core::HRESULT {
+        unsafe extern "system" fn CancelAuthentication<Identity: ITSGAuthenticationEngine_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, mainsessionid: windows_core::GUID, context: usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITSGAuthenticationEngine_Impl::CancelAuthentication(this, core::mem::transmute(&mainsessionid), core::mem::transmute_copy(&context)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            AuthenticateUser: AuthenticateUser::<Identity, OFFSET>,
+            CancelAuthentication: CancelAuthentication::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITSGAuthenticationEngine as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + ITSGAuthenticationEngine_Impl> windows_core::imp::TearOffVtable<Identity> for ITSGAuthenticationEngine_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AuthenticateUser<Identity: windows_core::IUnknownImpl + ITSGAuthenticationEngine_Impl>(this: *mut core::ffi::c_void, mainsessionid: windows_core::GUID, cookiedata: *const u8, numcookiebytes: u32, context: usize, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITSGAuthenticationEngine_Impl::AuthenticateUser(this, core::mem::transmute(&mainsessionid), core::mem::transmute_copy(&cookiedata), core::mem::transmute_copy(&numcookiebytes), core::mem::transmute_copy(&context), core::mem::transmute_copy(&psink)).into()
+            }
+        }
+        unsafe extern "system" fn CancelAuthentication<Identity: windows_core::IUnknownImpl + ITSGAuthenticationEngine_Impl>(this: *mut core::ffi::c_void, mainsessionid: windows_core::GUID, context: usize) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITSGAuthenticationEngine_Impl::CancelAuthentication(this, core::mem::transmute(&mainsessionid), core::mem::transmute_copy(&context)).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            AuthenticateUser: AuthenticateUser::<Identity, OFFSET>,
-            CancelAuthentication: CancelAuthentication::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AuthenticateUser: AuthenticateUser::<Identity>,
+            CancelAuthentication: CancelAuthentication::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ITSGAuthenticationEngine as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for ITSGAuthenticationEngine {}
 windows_core::imp::define_interface!(ITSGAuthorizeConnectionSink, ITSGAuthorizeConnectionSink_Vtbl, 0xc27ece33_7781_4318_98ef_1cf2da7b7005);
@@ -2362,6 +3276,23 @@ impl ITSGAuthorizeConnectionSink_Vtbl {
         iid == &<ITSGAuthorizeConnectionSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITSGAuthorizeConnectionSink_Impl> windows_core::imp::TearOffVtable<Identity> for ITSGAuthorizeConnectionSink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnConnectionAuthorized<Identity: windows_core::IUnknownImpl + ITSGAuthorizeConnectionSink_Impl>(this: *mut core::ffi::c_void, hrin: windows_core::HRESULT, mainsessionid: windows_core::GUID, cbsohresponse: u32, pbsohresponse: *const u8, idletimeout: u32, sessiontimeout: u32, sessiontimeoutaction: SESSION_TIMEOUT_ACTION_TYPE, trustclass: AATrustClassID, policyattributes: *const u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITSGAuthorizeConnectionSink_Impl::OnConnectionAuthorized(this, core::mem::transmute_copy(&hrin), core::mem::transmute(&mainsessionid), core::mem::transmute_copy(&cbsohresponse), core::mem::transmute_copy(&pbsohresponse), core::mem::transmute_copy(&idletimeout), core::mem::transmute_copy(&sessiontimeout), core::mem::transmute_copy(&sessiontimeoutaction), core::mem::transmute_copy(&trustclass), core::mem::transmute_copy(&policyattributes)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnConnectionAuthorized: OnConnectionAuthorized::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITSGAuthorizeConnectionSink {}
 windows_core::imp::define_interface!(ITSGAuthorizeResourceSink, ITSGAuthorizeResourceSink_Vtbl, 0xfeddfcd4_fa12_4435_ae55_7ad1a9779af7);
 windows_core::imp::interface_hierarchy!(ITSGAuthorizeResourceSink, windows_core::IUnknown);
@@ -2393,6 +3324,23 @@ impl ITSGAuthorizeResourceSink_Vtbl {
         iid == &<ITSGAuthorizeResourceSink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITSGAuthorizeResourceSink_Impl> windows_core::imp::TearOffVtable<Identity> for ITSGAuthorizeResourceSink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnChannelAuthorized<Identity: windows_core::IUnknownImpl + ITSGAuthorizeResourceSink_Impl>(this: *mut core::ffi::c_void, hrin: windows_core::HRESULT, mainsessionid: windows_core::GUID, subsessionid: i32, allowedresourcenames: *const *mut core::ffi::c_void, numallowedresourcenames: u32, failedresourcenames: *const *mut core::ffi::c_void, numfailedresourcenames: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITSGAuthorizeResourceSink_Impl::OnChannelAuthorized(this, core::mem::transmute_copy(&hrin), core::mem::transmute(&mainsessionid), core::mem::transmute_copy(&subsessionid), core::mem::transmute_copy(&allowedresourcenames), core::mem::transmute_copy(&numallowedresourcenames), core::mem::transmute_copy(&failedresourcenames), core::mem::transmute_copy(&numfailedresourcenames)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnChannelAuthorized: OnChannelAuthorized::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITSGAuthorizeResourceSink {}
 windows_core::imp::define_interface!(ITSGPolicyEngine, ITSGPolicyEngine_Vtbl, 0x8bc24f08_6223_42f4_a5b4_8e37cd135bbd);
 windows_core::imp::interface_hierarchy!(ITSGPolicyEngine, windows_core::IUnknown);
@@ -2493,6 +3441,65 @@ impl ITSGPolicyEngine_Vtbl {
         iid == &<ITSGPolicyEngine as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITSGPolicyEngine_Impl> windows_core::imp::TearOffVtable<Identity> for ITSGPolicyEngine_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AuthorizeConnection<Identity: windows_core::IUnknownImpl + ITSGPolicyEngine_Impl>(this: *mut core::ffi::c_void, mainsessionid: windows_core::GUID, username: *mut core::ffi::c_void, authtype: AAAuthSchemes, clientmachineip: *mut core::ffi::c_void, clientmachinename: *mut core::ffi::c_void, sohdata: *const u8, numsohbytes: u32, cookiedata: *const u8, numcookiebytes: u32, usertoken: super::super::Foundation::HANDLE_PTR, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITSGPolicyEngine_Impl::AuthorizeConnection(this, core::mem::transmute(&mainsessionid), core::mem::transmute(&username), core::mem::transmute_copy(&authtype), core::mem::transmute(&clientmachineip), core::mem::transmute(&clientmachinename), core::mem::transmute_copy(&sohdata), core::mem::transmute_copy(&numsohbytes), core::mem::transmute_copy(&cookiedata), core::mem::transmute_copy(&numcookiebytes), core::mem::transmute_copy(&usertoken), core::mem::transmute_copy(&psink)).into()
+            }
+        }
+        unsafe extern "system" fn AuthorizeResource<Identity: windows_core::IUnknownImpl + ITSGPolicyEngine_Impl>(this: *mut core::ffi::c_void, mainsessionid: windows_core::GUID, subsessionid: i32, username: *mut core::ffi::c_void, resourcenames: *const *mut core::ffi::c_void, numresources: u32, alternateresourcenames: *const *mut core::ffi::c_void, numalternateresourcename: u32, portnumber: u32, operation: *mut core::ffi::c_void, cookie: *const u8, numbytesincookie: u32, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITSGPolicyEngine_Impl::AuthorizeResource(
+                    this,
+                    core::mem::transmute(&mainsessionid),
+                    core::mem::transmute_copy(&subsessionid),
+                    core::mem::transmute(&username),
+                    core::mem::transmute_copy(&resourcenames),
+                    core::mem::transmute_copy(&numresources),
+                    core::mem::transmute_copy(&alternateresourcenames),
+                    core::mem::transmute_copy(&numalternateresourcename),
+                    core::mem::transmute_copy(&portnumber),
+                    core::mem::transmute(&operation),
+                    core::mem::transmute_copy(&cookie),
+                    core::mem::transmute_copy(&numbytesincookie),
+                    core::mem::transmute_copy(&psink),
+                )
+                .into()
+            }
+        }
+        unsafe extern "system" fn Refresh<Identity: windows_core::IUnknownImpl + ITSGPolicyEngine_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITSGPolicyEngine_Impl::Refresh(this).into()
+            }
+        }
+        unsafe extern "system" fn IsQuarantineEnabled<Identity: windows_core::IUnknownImpl + ITSGPolicyEngine_Impl>(this: *mut core::ffi::c_void, quarantineenabled: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITSGPolicyEngine_Impl::IsQuarantineEnabled(this) {
+                    Ok(ok__) => {
+                        quarantineenabled.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AuthorizeConnection: AuthorizeConnection::<Identity>,
+            AuthorizeResource: AuthorizeResource::<Identity>,
+            Refresh: Refresh::<Identity>,
+            IsQuarantineEnabled: IsQuarantineEnabled::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITSGPolicyEngine {}
 windows_core::imp::define_interface!(ITsSbBaseNotifySink, ITsSbBaseNotifySink_Vtbl, 0x808a6537_1282_4989_9e09_f43938b71722);
 windows_core::imp::interface_hierarchy!(ITsSbBaseNotifySink, windows_core::IUnknown);
@@ -2539,6 +3546,30 @@ impl ITsSbBaseNotifySink_Vtbl {
         iid == &<ITsSbBaseNotifySink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITsSbBaseNotifySink_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbBaseNotifySink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnError<Identity: windows_core::IUnknownImpl + ITsSbBaseNotifySink_Impl>(this: *mut core::ffi::c_void, hrerror: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbBaseNotifySink_Impl::OnError(this, core::mem::transmute_copy(&hrerror)).into()
+            }
+        }
+        unsafe extern "system" fn OnReportStatus<Identity: windows_core::IUnknownImpl + ITsSbBaseNotifySink_Impl>(this: *mut core::ffi::c_void, messagetype: CLIENT_MESSAGE_TYPE, messageid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbBaseNotifySink_Impl::OnReportStatus(this, core::mem::transmute_copy(&messagetype), core::mem::transmute_copy(&messageid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnError: OnError::<Identity>,
+            OnReportStatus: OnReportStatus::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITsSbBaseNotifySink {}
 windows_core::imp::define_interface!(ITsSbClientConnection, ITsSbClientConnection_Vtbl, 0x18857499_ad61_4b1b_b7df_cbcd41fb8338);
 windows_core::imp::interface_hierarchy!(ITsSbClientConnection, windows_core::IUnknown);
@@ -2679,11 +3710,208 @@ pub trait ITsSbClientConnection_Impl: windows_core::IUnknownImpl {
     fn GetDisconnectedSession(&self) -> windows_core::Result<ITsSbSession>;
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl ITsSbClientConnection_Vtbl {
-    pub const fn new<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn UserName<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl ITsSbClientConnection_Vtbl {
+    pub const fn new<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn UserName<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbClientConnection_Impl::UserName(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Domain<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbClientConnection_Impl::Domain(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InitialProgram<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbClientConnection_Impl::InitialProgram(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LoadBalanceResult<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbClientConnection_Impl::LoadBalanceResult(this) {
+                    Ok(ok__) => {
+                        ppval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn FarmName<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbClientConnection_Impl::FarmName(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn PutContext<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, contextid: *mut core::ffi::c_void, context: super::Variant::VARIANT, existingcontext: *mut super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbClientConnection_Impl::PutContext(this, core::mem::transmute(&contextid), core::mem::transmute(&context), core::mem::transmute_copy(&existingcontext)).into()
+            }
+        }
+        unsafe extern "system" fn GetContext<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, contextid: *mut core::ffi::c_void, context: *mut super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbClientConnection_Impl::GetContext(this, core::mem::transmute(&contextid)) {
+                    Ok(ok__) => {
+                        context.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Environment<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppenvironment: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbClientConnection_Impl::Environment(this) {
+                    Ok(ok__) => {
+                        ppenvironment.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_ConnectionError<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbClientConnection_Impl::get_ConnectionError(this).into()
+            }
+        }
+        unsafe extern "system" fn SamUserAccount<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbClientConnection_Impl::SamUserAccount(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ClientConnectionPropertySet<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pppropertyset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbClientConnection_Impl::ClientConnectionPropertySet(this) {
+                    Ok(ok__) => {
+                        pppropertyset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsFirstAssignment<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppval: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbClientConnection_Impl::IsFirstAssignment(this) {
+                    Ok(ok__) => {
+                        ppval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RdFarmType<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, prdfarmtype: *mut RD_FARM_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbClientConnection_Impl::RdFarmType(this) {
+                    Ok(ok__) => {
+                        prdfarmtype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UserSidString<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pszusersidstring: *mut *mut i8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbClientConnection_Impl::UserSidString(this) {
+                    Ok(ok__) => {
+                        pszusersidstring.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDisconnectedSession<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbClientConnection_Impl::GetDisconnectedSession(this) {
+                    Ok(ok__) => {
+                        ppsession.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            UserName: UserName::<Identity, OFFSET>,
+            Domain: Domain::<Identity, OFFSET>,
+            InitialProgram: InitialProgram::<Identity, OFFSET>,
+            LoadBalanceResult: LoadBalanceResult::<Identity, OFFSET>,
+            FarmName: FarmName::<Identity, OFFSET>,
+            PutContext: PutContext::<Identity, OFFSET>,
+            GetContext: GetContext::<Identity, OFFSET>,
+            Environment: Environment::<Identity, OFFSET>,
+            get_ConnectionError: get_ConnectionError::<Identity, OFFSET>,
+            SamUserAccount: SamUserAccount::<Identity, OFFSET>,
+            ClientConnectionPropertySet: ClientConnectionPropertySet::<Identity, OFFSET>,
+            IsFirstAssignment: IsFirstAssignment::<Identity, OFFSET>,
+            RdFarmType: RdFarmType::<Identity, OFFSET>,
+            UserSidString: UserSidString::<Identity, OFFSET>,
+            GetDisconnectedSession: GetDisconnectedSession::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbClientConnection as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ITsSbClientConnection_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbClientConnection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn UserName<Identity: windows_core::IUnknownImpl + ITsSbClientConnection_Impl>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbClientConnection_Impl::UserName(this) {
                     Ok(ok__) => {
                         pval.write(core::mem::transmute(ok__));
@@ -2693,9 +3921,9 @@ impl ITsSbClientConnection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Domain<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Domain<Identity: windows_core::IUnknownImpl + ITsSbClientConnection_Impl>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbClientConnection_Impl::Domain(this) {
                     Ok(ok__) => {
                         pval.write(core::mem::transmute(ok__));
@@ -2705,9 +3933,9 @@ impl ITsSbClientConnection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn InitialProgram<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn InitialProgram<Identity: windows_core::IUnknownImpl + ITsSbClientConnection_Impl>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbClientConnection_Impl::InitialProgram(this) {
                     Ok(ok__) => {
                         pval.write(core::mem::transmute(ok__));
@@ -2717,9 +3945,9 @@ impl ITsSbClientConnection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn LoadBalanceResult<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn LoadBalanceResult<Identity: windows_core::IUnknownImpl + ITsSbClientConnection_Impl>(this: *mut core::ffi::c_void, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbClientConnection_Impl::LoadBalanceResult(this) {
                     Ok(ok__) => {
                         ppval.write(core::mem::transmute(ok__));
@@ -2729,9 +3957,9 @@ impl ITsSbClientConnection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn FarmName<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn FarmName<Identity: windows_core::IUnknownImpl + ITsSbClientConnection_Impl>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbClientConnection_Impl::FarmName(this) {
                     Ok(ok__) => {
                         pval.write(core::mem::transmute(ok__));
@@ -2741,15 +3969,15 @@ impl ITsSbClientConnection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn PutContext<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, contextid: *mut core::ffi::c_void, context: super::Variant::VARIANT, existingcontext: *mut super::Variant::VARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn PutContext<Identity: windows_core::IUnknownImpl + ITsSbClientConnection_Impl>(this: *mut core::ffi::c_void, contextid: *mut core::ffi::c_void, context: super::Variant::VARIANT, existingcontext: *mut super::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbClientConnection_Impl::PutContext(this, core::mem::transmute(&contextid), core::mem::transmute(&context), core::mem::transmute_copy(&existingcontext)).into()
             }
         }
-        unsafe extern "system" fn GetContext<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, contextid: *mut core::ffi::c_void, context: *mut super::Variant::VARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetContext<Identity: windows_core::IUnknownImpl + ITsSbClientConnection_Impl>(this: *mut core::ffi::c_void, contextid: *mut core::ffi::c_void, context: *mut super::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbClientConnection_Impl::GetContext(this, core::mem::transmute(&contextid)) {
                     Ok(ok__) => {
                         context.write(core::mem::transmute(ok__));
@@ -2759,9 +3987,9 @@ impl ITsSbClientConnection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Environment<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppenvironment: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Environment<Identity: windows_core::IUnknownImpl + ITsSbClientConnection_Impl>(this: *mut core::ffi::c_void, ppenvironment: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbClientConnection_Impl::Environment(this) {
                     Ok(ok__) => {
                         ppenvironment.write(core::mem::transmute(ok__));
@@ -2771,15 +3999,15 @@ impl ITsSbClientConnection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn get_ConnectionError<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn get_ConnectionError<Identity: windows_core::IUnknownImpl + ITsSbClientConnection_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbClientConnection_Impl::get_ConnectionError(this).into()
             }
         }
-        unsafe extern "system" fn SamUserAccount<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SamUserAccount<Identity: windows_core::IUnknownImpl + ITsSbClientConnection_Impl>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbClientConnection_Impl::SamUserAccount(this) {
                     Ok(ok__) => {
                         pval.write(core::mem::transmute(ok__));
@@ -2789,9 +4017,9 @@ impl ITsSbClientConnection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ClientConnectionPropertySet<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pppropertyset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn ClientConnectionPropertySet<Identity: windows_core::IUnknownImpl + ITsSbClientConnection_Impl>(this: *mut core::ffi::c_void, pppropertyset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbClientConnection_Impl::ClientConnectionPropertySet(this) {
                     Ok(ok__) => {
                         pppropertyset.write(core::mem::transmute(ok__));
@@ -2801,9 +4029,9 @@ impl ITsSbClientConnection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn IsFirstAssignment<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppval: *mut windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn IsFirstAssignment<Identity: windows_core::IUnknownImpl + ITsSbClientConnection_Impl>(this: *mut core::ffi::c_void, ppval: *mut windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbClientConnection_Impl::IsFirstAssignment(this) {
                     Ok(ok__) => {
                         ppval.write(core::mem::transmute(ok__));
@@ -2813,9 +4041,9 @@ impl ITsSbClientConnection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn RdFarmType<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, prdfarmtype: *mut RD_FARM_TYPE) -> windows_core::HRESULT {
+        unsafe extern "system" fn RdFarmType<Identity: windows_core::IUnknownImpl + ITsSbClientConnection_Impl>(this: *mut core::ffi::c_void, prdfarmtype: *mut RD_FARM_TYPE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbClientConnection_Impl::RdFarmType(this) {
                     Ok(ok__) => {
                         prdfarmtype.write(core::mem::transmute(ok__));
@@ -2825,9 +4053,9 @@ impl ITsSbClientConnection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn UserSidString<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pszusersidstring: *mut *mut i8) -> windows_core::HRESULT {
+        unsafe extern "system" fn UserSidString<Identity: windows_core::IUnknownImpl + ITsSbClientConnection_Impl>(this: *mut core::ffi::c_void, pszusersidstring: *mut *mut i8) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbClientConnection_Impl::UserSidString(this) {
                     Ok(ok__) => {
                         pszusersidstring.write(core::mem::transmute(ok__));
@@ -2837,9 +4065,9 @@ impl ITsSbClientConnection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetDisconnectedSession<Identity: ITsSbClientConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetDisconnectedSession<Identity: windows_core::IUnknownImpl + ITsSbClientConnection_Impl>(this: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbClientConnection_Impl::GetDisconnectedSession(this) {
                     Ok(ok__) => {
                         ppsession.write(core::mem::transmute(ok__));
@@ -2850,27 +4078,24 @@ impl ITsSbClientConnection_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            UserName: UserName::<Identity, OFFSET>,
-            Domain: Domain::<Identity, OFFSET>,
-            InitialProgram: InitialProgram::<Identity, OFFSET>,
-            LoadBalanceResult: LoadBalanceResult::<Identity, OFFSET>,
-            FarmName: FarmName::<Identity, OFFSET>,
-            PutContext: PutContext::<Identity, OFFSET>,
-            GetContext: GetContext::<Identity, OFFSET>,
-            Environment: Environment::<Identity, OFFSET>,
-            get_ConnectionError: get_ConnectionError::<Identity, OFFSET>,
-            SamUserAccount: SamUserAccount::<Identity, OFFSET>,
-            ClientConnectionPropertySet: ClientConnectionPropertySet::<Identity, OFFSET>,
-            IsFirstAssignment: IsFirstAssignment::<Identity, OFFSET>,
-            RdFarmType: RdFarmType::<Identity, OFFSET>,
-            UserSidString: UserSidString::<Identity, OFFSET>,
-            GetDisconnectedSession: GetDisconnectedSession::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ITsSbClientConnection as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            UserName: UserName::<Identity>,
+            Domain: Domain::<Identity>,
+            InitialProgram: InitialProgram::<Identity>,
+            LoadBalanceResult: LoadBalanceResult::<Identity>,
+            FarmName: FarmName::<Identity>,
+            PutContext: PutContext::<Identity>,
+            GetContext: GetContext::<Identity>,
+            Environment: Environment::<Identity>,
+            get_ConnectionError: get_ConnectionError::<Identity>,
+            SamUserAccount: SamUserAccount::<Identity>,
+            ClientConnectionPropertySet: ClientConnectionPropertySet::<Identity>,
+            IsFirstAssignment: IsFirstAssignment::<Identity>,
+            RdFarmType: RdFarmType::<Identity>,
+            UserSidString: UserSidString::<Identity>,
+            GetDisconnectedSession: GetDisconnectedSession::<Identity>,
+        }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ITsSbClientConnection {}
@@ -2903,6 +4128,13 @@ impl ITsSbClientConnectionPropertySet_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ITsSbClientConnectionPropertySet_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbClientConnectionPropertySet_Vtbl
+where
+    ITsSbPropertySet_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <ITsSbPropertySet_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ITsSbClientConnectionPropertySet {}
 windows_core::imp::define_interface!(ITsSbEnvironment, ITsSbEnvironment_Vtbl, 0x8c87f7f7_bf51_4a5c_87bf_8e94fb6e2256);
 windows_core::imp::interface_hierarchy!(ITsSbEnvironment, windows_core::IUnknown);
@@ -3014,6 +4246,63 @@ impl ITsSbEnvironment_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl<Identity: windows_core::IUnknownImpl + ITsSbEnvironment_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbEnvironment_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + ITsSbEnvironment_Impl>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbEnvironment_Impl::Name(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ServerWeight<Identity: windows_core::IUnknownImpl + ITsSbEnvironment_Impl>(this: *mut core::ffi::c_void, pval: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbEnvironment_Impl::ServerWeight(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnvironmentPropertySet<Identity: windows_core::IUnknownImpl + ITsSbEnvironment_Impl>(this: *mut core::ffi::c_void, pppropertyset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbEnvironment_Impl::EnvironmentPropertySet(this) {
+                    Ok(ok__) => {
+                        pppropertyset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEnvironmentPropertySet<Identity: windows_core::IUnknownImpl + ITsSbEnvironment_Impl>(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbEnvironment_Impl::SetEnvironmentPropertySet(this, core::mem::transmute_copy(&pval)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Name: Name::<Identity>,
+            ServerWeight: ServerWeight::<Identity>,
+            EnvironmentPropertySet: EnvironmentPropertySet::<Identity>,
+            SetEnvironmentPropertySet: SetEnvironmentPropertySet::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
 impl windows_core::RuntimeName for ITsSbEnvironment {}
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
 windows_core::imp::define_interface!(ITsSbEnvironmentPropertySet, ITsSbEnvironmentPropertySet_Vtbl, 0xd0d1bf7e_7acf_11dd_a243_e51156d89593);
@@ -3044,6 +4333,13 @@ impl ITsSbEnvironmentPropertySet_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ITsSbEnvironmentPropertySet_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbEnvironmentPropertySet_Vtbl
+where
+    ITsSbPropertySet_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <ITsSbPropertySet_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ITsSbEnvironmentPropertySet {}
 windows_core::imp::define_interface!(ITsSbFilterPluginStore, ITsSbFilterPluginStore_Vtbl, 0x85b44b0f_ed78_413f_9702_fa6d3b5ee755);
 windows_core::imp::interface_hierarchy!(ITsSbFilterPluginStore, windows_core::IUnknown);
@@ -3125,6 +4421,44 @@ impl ITsSbFilterPluginStore_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl<Identity: windows_core::IUnknownImpl + ITsSbFilterPluginStore_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbFilterPluginStore_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SaveProperties<Identity: windows_core::IUnknownImpl + ITsSbFilterPluginStore_Impl>(this: *mut core::ffi::c_void, ppropertyset: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbFilterPluginStore_Impl::SaveProperties(this, core::mem::transmute_copy(&ppropertyset)).into()
+            }
+        }
+        unsafe extern "system" fn EnumerateProperties<Identity: windows_core::IUnknownImpl + ITsSbFilterPluginStore_Impl>(this: *mut core::ffi::c_void, pppropertyset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbFilterPluginStore_Impl::EnumerateProperties(this) {
+                    Ok(ok__) => {
+                        pppropertyset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeleteProperties<Identity: windows_core::IUnknownImpl + ITsSbFilterPluginStore_Impl>(this: *mut core::ffi::c_void, propertyname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbFilterPluginStore_Impl::DeleteProperties(this, core::mem::transmute(&propertyname)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SaveProperties: SaveProperties::<Identity>,
+            EnumerateProperties: EnumerateProperties::<Identity>,
+            DeleteProperties: DeleteProperties::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
 impl windows_core::RuntimeName for ITsSbFilterPluginStore {}
 windows_core::imp::define_interface!(ITsSbGenericNotifySink, ITsSbGenericNotifySink_Vtbl, 0x4c4c8c4f_300b_46ad_9164_8468a7e7568c);
 windows_core::imp::interface_hierarchy!(ITsSbGenericNotifySink, windows_core::IUnknown);
@@ -3180,6 +4514,36 @@ impl ITsSbGenericNotifySink_Vtbl {
         iid == &<ITsSbGenericNotifySink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITsSbGenericNotifySink_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbGenericNotifySink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnCompleted<Identity: windows_core::IUnknownImpl + ITsSbGenericNotifySink_Impl>(this: *mut core::ffi::c_void, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbGenericNotifySink_Impl::OnCompleted(this, core::mem::transmute_copy(&status)).into()
+            }
+        }
+        unsafe extern "system" fn GetWaitTimeout<Identity: windows_core::IUnknownImpl + ITsSbGenericNotifySink_Impl>(this: *mut core::ffi::c_void, pfttimeout: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbGenericNotifySink_Impl::GetWaitTimeout(this) {
+                    Ok(ok__) => {
+                        pfttimeout.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnCompleted: OnCompleted::<Identity>,
+            GetWaitTimeout: GetWaitTimeout::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITsSbGenericNotifySink {}
 windows_core::imp::define_interface!(ITsSbGlobalStore, ITsSbGlobalStore_Vtbl, 0x9ab60f7b_bd72_4d9f_8a3a_a0ea5574e635);
 windows_core::imp::interface_hierarchy!(ITsSbGlobalStore, windows_core::IUnknown);
@@ -3315,6 +4679,78 @@ impl ITsSbGlobalStore_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ITsSbGlobalStore_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbGlobalStore_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryTarget<Identity: windows_core::IUnknownImpl + ITsSbGlobalStore_Impl>(this: *mut core::ffi::c_void, providername: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, farmname: *mut core::ffi::c_void, pptarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbGlobalStore_Impl::QueryTarget(this, core::mem::transmute(&providername), core::mem::transmute(&targetname), core::mem::transmute(&farmname)) {
+                    Ok(ok__) => {
+                        pptarget.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QuerySessionBySessionId<Identity: windows_core::IUnknownImpl + ITsSbGlobalStore_Impl>(this: *mut core::ffi::c_void, providername: *mut core::ffi::c_void, dwsessionid: u32, targetname: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbGlobalStore_Impl::QuerySessionBySessionId(this, core::mem::transmute(&providername), core::mem::transmute_copy(&dwsessionid), core::mem::transmute(&targetname)) {
+                    Ok(ok__) => {
+                        ppsession.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumerateFarms<Identity: windows_core::IUnknownImpl + ITsSbGlobalStore_Impl>(this: *mut core::ffi::c_void, providername: *mut core::ffi::c_void, pdwcount: *mut u32, pval: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbGlobalStore_Impl::EnumerateFarms(this, core::mem::transmute(&providername), core::mem::transmute_copy(&pdwcount), core::mem::transmute_copy(&pval)).into()
+            }
+        }
+        unsafe extern "system" fn EnumerateTargets<Identity: windows_core::IUnknownImpl + ITsSbGlobalStore_Impl>(this: *mut core::ffi::c_void, providername: *mut core::ffi::c_void, farmname: *mut core::ffi::c_void, envname: *mut core::ffi::c_void, pdwcount: *mut u32, pval: *mut *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbGlobalStore_Impl::EnumerateTargets(this, core::mem::transmute(&providername), core::mem::transmute(&farmname), core::mem::transmute(&envname), core::mem::transmute_copy(&pdwcount), core::mem::transmute_copy(&pval)).into()
+            }
+        }
+        unsafe extern "system" fn EnumerateEnvironmentsByProvider<Identity: windows_core::IUnknownImpl + ITsSbGlobalStore_Impl>(this: *mut core::ffi::c_void, providername: *mut core::ffi::c_void, pdwcount: *mut u32, ppval: *mut *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbGlobalStore_Impl::EnumerateEnvironmentsByProvider(this, core::mem::transmute(&providername), core::mem::transmute_copy(&pdwcount), core::mem::transmute_copy(&ppval)).into()
+            }
+        }
+        unsafe extern "system" fn EnumerateSessions<Identity: windows_core::IUnknownImpl + ITsSbGlobalStore_Impl>(this: *mut core::ffi::c_void, providername: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, username: *mut core::ffi::c_void, userdomain: *mut core::ffi::c_void, poolname: *mut core::ffi::c_void, initialprogram: *mut core::ffi::c_void, psessionstate: *const TSSESSION_STATE, pdwcount: *mut u32, ppval: *mut *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbGlobalStore_Impl::EnumerateSessions(this, core::mem::transmute(&providername), core::mem::transmute(&targetname), core::mem::transmute(&username), core::mem::transmute(&userdomain), core::mem::transmute(&poolname), core::mem::transmute(&initialprogram), core::mem::transmute_copy(&psessionstate), core::mem::transmute_copy(&pdwcount), core::mem::transmute_copy(&ppval)).into()
+            }
+        }
+        unsafe extern "system" fn GetFarmProperty<Identity: windows_core::IUnknownImpl + ITsSbGlobalStore_Impl>(this: *mut core::ffi::c_void, farmname: *mut core::ffi::c_void, propertyname: *mut core::ffi::c_void, pvarvalue: *const super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbGlobalStore_Impl::GetFarmProperty(this, core::mem::transmute(&farmname), core::mem::transmute(&propertyname), core::mem::transmute_copy(&pvarvalue)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryTarget: QueryTarget::<Identity>,
+            QuerySessionBySessionId: QuerySessionBySessionId::<Identity>,
+            EnumerateFarms: EnumerateFarms::<Identity>,
+            EnumerateTargets: EnumerateTargets::<Identity>,
+            EnumerateEnvironmentsByProvider: EnumerateEnvironmentsByProvider::<Identity>,
+            EnumerateSessions: EnumerateSessions::<Identity>,
+            GetFarmProperty: GetFarmProperty::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ITsSbGlobalStore {}
 windows_core::imp::define_interface!(ITsSbLoadBalanceResult, ITsSbLoadBalanceResult_Vtbl, 0x24fdb7ac_fea6_11dc_9672_9a8956d89593);
 windows_core::imp::interface_hierarchy!(ITsSbLoadBalanceResult, windows_core::IUnknown);
@@ -3355,6 +4791,26 @@ impl ITsSbLoadBalanceResult_Vtbl {
         iid == &<ITsSbLoadBalanceResult as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITsSbLoadBalanceResult_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbLoadBalanceResult_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn TargetName<Identity: windows_core::IUnknownImpl + ITsSbLoadBalanceResult_Impl>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbLoadBalanceResult_Impl::TargetName(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, TargetName: TargetName::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ITsSbLoadBalanceResult {}
 windows_core::imp::define_interface!(ITsSbLoadBalancing, ITsSbLoadBalancing_Vtbl, 0x24329274_9eb7_11dc_ae98_f2b456d89593);
 impl core::ops::Deref for ITsSbLoadBalancing {
@@ -3399,6 +4855,21 @@ impl ITsSbLoadBalancing_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl<Identity: windows_core::IUnknownImpl + ITsSbLoadBalancing_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbLoadBalancing_Vtbl
+where
+    ITsSbPlugin_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMostSuitableTarget<Identity: windows_core::IUnknownImpl + ITsSbLoadBalancing_Impl>(this: *mut core::ffi::c_void, pconnection: *mut core::ffi::c_void, plbsink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbLoadBalancing_Impl::GetMostSuitableTarget(this, core::mem::transmute_copy(&pconnection), core::mem::transmute_copy(&plbsink)).into()
+            }
+        }
+        Self { base__: <ITsSbPlugin_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetMostSuitableTarget: GetMostSuitableTarget::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
 impl windows_core::RuntimeName for ITsSbLoadBalancing {}
 windows_core::imp::define_interface!(ITsSbLoadBalancingNotifySink, ITsSbLoadBalancingNotifySink_Vtbl, 0x5f8a8297_3244_4e6a_958a_27c822c1e141);
 impl core::ops::Deref for ITsSbLoadBalancingNotifySink {
@@ -3439,6 +4910,23 @@ impl ITsSbLoadBalancingNotifySink_Vtbl {
         iid == &<ITsSbLoadBalancingNotifySink as windows_core::Interface>::IID || iid == &<ITsSbBaseNotifySink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITsSbLoadBalancingNotifySink_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbLoadBalancingNotifySink_Vtbl
+where
+    ITsSbBaseNotifySink_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnGetMostSuitableTarget<Identity: windows_core::IUnknownImpl + ITsSbLoadBalancingNotifySink_Impl>(this: *mut core::ffi::c_void, plbresult: *mut core::ffi::c_void, fisnewconnection: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbLoadBalancingNotifySink_Impl::OnGetMostSuitableTarget(this, core::mem::transmute_copy(&plbresult), core::mem::transmute_copy(&fisnewconnection)).into()
+            }
+        }
+        Self {
+            base__: <ITsSbBaseNotifySink_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnGetMostSuitableTarget: OnGetMostSuitableTarget::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITsSbLoadBalancingNotifySink {}
 windows_core::imp::define_interface!(ITsSbOrchestration, ITsSbOrchestration_Vtbl, 0x64fc1172_9eb7_11dc_8b00_3aba56d89593);
 impl core::ops::Deref for ITsSbOrchestration {
@@ -3483,6 +4971,21 @@ impl ITsSbOrchestration_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl<Identity: windows_core::IUnknownImpl + ITsSbOrchestration_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbOrchestration_Vtbl
+where
+    ITsSbPlugin_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn PrepareTargetForConnect<Identity: windows_core::IUnknownImpl + ITsSbOrchestration_Impl>(this: *mut core::ffi::c_void, pconnection: *mut core::ffi::c_void, porchestrationnotifysink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbOrchestration_Impl::PrepareTargetForConnect(this, core::mem::transmute_copy(&pconnection), core::mem::transmute_copy(&porchestrationnotifysink)).into()
+            }
+        }
+        Self { base__: <ITsSbPlugin_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, PrepareTargetForConnect: PrepareTargetForConnect::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
 impl windows_core::RuntimeName for ITsSbOrchestration {}
 windows_core::imp::define_interface!(ITsSbOrchestrationNotifySink, ITsSbOrchestrationNotifySink_Vtbl, 0x36c37d61_926b_442f_bca5_118c6d50dcf2);
 impl core::ops::Deref for ITsSbOrchestrationNotifySink {
@@ -3523,6 +5026,20 @@ impl ITsSbOrchestrationNotifySink_Vtbl {
         iid == &<ITsSbOrchestrationNotifySink as windows_core::Interface>::IID || iid == &<ITsSbBaseNotifySink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITsSbOrchestrationNotifySink_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbOrchestrationNotifySink_Vtbl
+where
+    ITsSbBaseNotifySink_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnReadyToConnect<Identity: windows_core::IUnknownImpl + ITsSbOrchestrationNotifySink_Impl>(this: *mut core::ffi::c_void, ptarget: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbOrchestrationNotifySink_Impl::OnReadyToConnect(this, core::mem::transmute_copy(&ptarget)).into()
+            }
+        }
+        Self { base__: <ITsSbBaseNotifySink_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnReadyToConnect: OnReadyToConnect::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for ITsSbOrchestrationNotifySink {}
 windows_core::imp::define_interface!(ITsSbPlacement, ITsSbPlacement_Vtbl, 0xdaadee5f_6d32_480e_9e36_ddab2329f06d);
 impl core::ops::Deref for ITsSbPlacement {
@@ -3567,6 +5084,24 @@ impl ITsSbPlacement_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl<Identity: windows_core::IUnknownImpl + ITsSbPlacement_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbPlacement_Vtbl
+where
+    ITsSbPlugin_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryEnvironmentForTarget<Identity: windows_core::IUnknownImpl + ITsSbPlacement_Impl>(this: *mut core::ffi::c_void, pconnection: *mut core::ffi::c_void, pplacementsink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbPlacement_Impl::QueryEnvironmentForTarget(this, core::mem::transmute_copy(&pconnection), core::mem::transmute_copy(&pplacementsink)).into()
+            }
+        }
+        Self {
+            base__: <ITsSbPlugin_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryEnvironmentForTarget: QueryEnvironmentForTarget::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
 impl windows_core::RuntimeName for ITsSbPlacement {}
 windows_core::imp::define_interface!(ITsSbPlacementNotifySink, ITsSbPlacementNotifySink_Vtbl, 0x68a0c487_2b4f_46c2_94a1_6ce685183634);
 impl core::ops::Deref for ITsSbPlacementNotifySink {
@@ -3607,6 +5142,23 @@ impl ITsSbPlacementNotifySink_Vtbl {
         iid == &<ITsSbPlacementNotifySink as windows_core::Interface>::IID || iid == &<ITsSbBaseNotifySink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITsSbPlacementNotifySink_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbPlacementNotifySink_Vtbl
+where
+    ITsSbBaseNotifySink_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnQueryEnvironmentCompleted<Identity: windows_core::IUnknownImpl + ITsSbPlacementNotifySink_Impl>(this: *mut core::ffi::c_void, penvironment: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbPlacementNotifySink_Impl::OnQueryEnvironmentCompleted(this, core::mem::transmute_copy(&penvironment)).into()
+            }
+        }
+        Self {
+            base__: <ITsSbBaseNotifySink_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnQueryEnvironmentCompleted: OnQueryEnvironmentCompleted::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITsSbPlacementNotifySink {}
 windows_core::imp::define_interface!(ITsSbPlugin, ITsSbPlugin_Vtbl, 0x48cd7406_caab_465f_a5d6_baa863b9ea4f);
 windows_core::imp::interface_hierarchy!(ITsSbPlugin, windows_core::IUnknown);
@@ -3665,6 +5217,31 @@ impl ITsSbPlugin_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl<Identity: windows_core::IUnknownImpl + ITsSbPlugin_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbPlugin_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + ITsSbPlugin_Impl>(this: *mut core::ffi::c_void, pprovider: *mut core::ffi::c_void, pnotifysink: *mut core::ffi::c_void, ppropertyset: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbPlugin_Impl::Initialize(this, core::mem::transmute_copy(&pprovider), core::mem::transmute_copy(&pnotifysink), core::mem::transmute_copy(&ppropertyset)).into()
+            }
+        }
+        unsafe extern "system" fn Terminate<Identity: windows_core::IUnknownImpl + ITsSbPlugin_Impl>(this: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbPlugin_Impl::Terminate(this, core::mem::transmute_copy(&hr)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            Terminate: Terminate::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
 impl windows_core::RuntimeName for ITsSbPlugin {}
 windows_core::imp::define_interface!(ITsSbPluginNotifySink, ITsSbPluginNotifySink_Vtbl, 0x44dfe30b_c3be_40f5_bf82_7a95bb795adf);
 impl core::ops::Deref for ITsSbPluginNotifySink {
@@ -3717,6 +5294,30 @@ impl ITsSbPluginNotifySink_Vtbl {
         iid == &<ITsSbPluginNotifySink as windows_core::Interface>::IID || iid == &<ITsSbBaseNotifySink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITsSbPluginNotifySink_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbPluginNotifySink_Vtbl
+where
+    ITsSbBaseNotifySink_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnInitialized<Identity: windows_core::IUnknownImpl + ITsSbPluginNotifySink_Impl>(this: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbPluginNotifySink_Impl::OnInitialized(this, core::mem::transmute_copy(&hr)).into()
+            }
+        }
+        unsafe extern "system" fn OnTerminated<Identity: windows_core::IUnknownImpl + ITsSbPluginNotifySink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbPluginNotifySink_Impl::OnTerminated(this).into()
+            }
+        }
+        Self {
+            base__: <ITsSbBaseNotifySink_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnInitialized: OnInitialized::<Identity>,
+            OnTerminated: OnTerminated::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITsSbPluginNotifySink {}
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
 windows_core::imp::define_interface!(ITsSbPluginPropertySet, ITsSbPluginPropertySet_Vtbl, 0x95006e34_7eff_4b6c_bb40_49a4fda7cea6);
@@ -3747,6 +5348,13 @@ impl ITsSbPluginPropertySet_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ITsSbPluginPropertySet_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbPluginPropertySet_Vtbl
+where
+    ITsSbPropertySet_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <ITsSbPropertySet_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ITsSbPluginPropertySet {}
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
 windows_core::imp::define_interface!(ITsSbPropertySet, ITsSbPropertySet_Vtbl, 0x5c025171_bb1e_4baf_a212_6d5e9774b33b);
@@ -3777,6 +5385,13 @@ impl ITsSbPropertySet_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ITsSbPropertySet_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbPropertySet_Vtbl
+where
+    super::Com::StructuredStorage::IPropertyBag_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <super::Com::StructuredStorage::IPropertyBag_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ITsSbPropertySet {}
 windows_core::imp::define_interface!(ITsSbProvider, ITsSbProvider_Vtbl, 0x87a4098f_6d7b_44dd_bc17_8ce44e370d52);
 windows_core::imp::interface_hierarchy!(ITsSbProvider, windows_core::IUnknown);
@@ -4051,6 +5666,161 @@ impl ITsSbProvider_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl<Identity: windows_core::IUnknownImpl + ITsSbProvider_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateTargetObject<Identity: windows_core::IUnknownImpl + ITsSbProvider_Impl>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, environmentname: *mut core::ffi::c_void, pptarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbProvider_Impl::CreateTargetObject(this, core::mem::transmute(&targetname), core::mem::transmute(&environmentname)) {
+                    Ok(ok__) => {
+                        pptarget.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateLoadBalanceResultObject<Identity: windows_core::IUnknownImpl + ITsSbProvider_Impl>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, pplbresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbProvider_Impl::CreateLoadBalanceResultObject(this, core::mem::transmute(&targetname)) {
+                    Ok(ok__) => {
+                        pplbresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSessionObject<Identity: windows_core::IUnknownImpl + ITsSbProvider_Impl>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, username: *mut core::ffi::c_void, domain: *mut core::ffi::c_void, sessionid: u32, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbProvider_Impl::CreateSessionObject(this, core::mem::transmute(&targetname), core::mem::transmute(&username), core::mem::transmute(&domain), core::mem::transmute_copy(&sessionid)) {
+                    Ok(ok__) => {
+                        ppsession.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreatePluginPropertySet<Identity: windows_core::IUnknownImpl + ITsSbProvider_Impl>(this: *mut core::ffi::c_void, pppropertyset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbProvider_Impl::CreatePluginPropertySet(this) {
+                    Ok(ok__) => {
+                        pppropertyset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateTargetPropertySetObject<Identity: windows_core::IUnknownImpl + ITsSbProvider_Impl>(this: *mut core::ffi::c_void, pppropertyset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbProvider_Impl::CreateTargetPropertySetObject(this) {
+                    Ok(ok__) => {
+                        pppropertyset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateEnvironmentObject<Identity: windows_core::IUnknownImpl + ITsSbProvider_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, serverweight: u32, ppenvironment: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbProvider_Impl::CreateEnvironmentObject(this, core::mem::transmute(&name), core::mem::transmute_copy(&serverweight)) {
+                    Ok(ok__) => {
+                        ppenvironment.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetResourcePluginStore<Identity: windows_core::IUnknownImpl + ITsSbProvider_Impl>(this: *mut core::ffi::c_void, ppstore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbProvider_Impl::GetResourcePluginStore(this) {
+                    Ok(ok__) => {
+                        ppstore.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFilterPluginStore<Identity: windows_core::IUnknownImpl + ITsSbProvider_Impl>(this: *mut core::ffi::c_void, ppstore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbProvider_Impl::GetFilterPluginStore(this) {
+                    Ok(ok__) => {
+                        ppstore.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RegisterForNotification<Identity: windows_core::IUnknownImpl + ITsSbProvider_Impl>(this: *mut core::ffi::c_void, notificationtype: u32, resourcetomonitor: *mut core::ffi::c_void, ppluginnotification: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbProvider_Impl::RegisterForNotification(this, core::mem::transmute_copy(&notificationtype), core::mem::transmute(&resourcetomonitor), core::mem::transmute_copy(&ppluginnotification)).into()
+            }
+        }
+        unsafe extern "system" fn UnRegisterForNotification<Identity: windows_core::IUnknownImpl + ITsSbProvider_Impl>(this: *mut core::ffi::c_void, notificationtype: u32, resourcetomonitor: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbProvider_Impl::UnRegisterForNotification(this, core::mem::transmute_copy(&notificationtype), core::mem::transmute(&resourcetomonitor)).into()
+            }
+        }
+        unsafe extern "system" fn GetInstanceOfGlobalStore<Identity: windows_core::IUnknownImpl + ITsSbProvider_Impl>(this: *mut core::ffi::c_void, ppglobalstore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbProvider_Impl::GetInstanceOfGlobalStore(this) {
+                    Ok(ok__) => {
+                        ppglobalstore.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateEnvironmentPropertySetObject<Identity: windows_core::IUnknownImpl + ITsSbProvider_Impl>(this: *mut core::ffi::c_void, pppropertyset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbProvider_Impl::CreateEnvironmentPropertySetObject(this) {
+                    Ok(ok__) => {
+                        pppropertyset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateTargetObject: CreateTargetObject::<Identity>,
+            CreateLoadBalanceResultObject: CreateLoadBalanceResultObject::<Identity>,
+            CreateSessionObject: CreateSessionObject::<Identity>,
+            CreatePluginPropertySet: CreatePluginPropertySet::<Identity>,
+            CreateTargetPropertySetObject: CreateTargetPropertySetObject::<Identity>,
+            CreateEnvironmentObject: CreateEnvironmentObject::<Identity>,
+            GetResourcePluginStore: GetResourcePluginStore::<Identity>,
+            GetFilterPluginStore: GetFilterPluginStore::<Identity>,
+            RegisterForNotification: RegisterForNotification::<Identity>,
+            UnRegisterForNotification: UnRegisterForNotification::<Identity>,
+            GetInstanceOfGlobalStore: GetInstanceOfGlobalStore::<Identity>,
+            CreateEnvironmentPropertySetObject: CreateEnvironmentPropertySetObject::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
 impl windows_core::RuntimeName for ITsSbProvider {}
 windows_core::imp::define_interface!(ITsSbProvisioning, ITsSbProvisioning_Vtbl, 0x2f6f0dbb_9e4f_462b_9c3f_fccc3dcb6232);
 impl core::ops::Deref for ITsSbProvisioning {
@@ -4139,6 +5909,45 @@ impl ITsSbProvisioning_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl<Identity: windows_core::IUnknownImpl + ITsSbProvisioning_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbProvisioning_Vtbl
+where
+    ITsSbPlugin_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateVirtualMachines<Identity: windows_core::IUnknownImpl + ITsSbProvisioning_Impl>(this: *mut core::ffi::c_void, jobxmlstring: *mut core::ffi::c_void, jobguid: *mut core::ffi::c_void, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbProvisioning_Impl::CreateVirtualMachines(this, core::mem::transmute(&jobxmlstring), core::mem::transmute(&jobguid), core::mem::transmute_copy(&psink)).into()
+            }
+        }
+        unsafe extern "system" fn PatchVirtualMachines<Identity: windows_core::IUnknownImpl + ITsSbProvisioning_Impl>(this: *mut core::ffi::c_void, jobxmlstring: *mut core::ffi::c_void, jobguid: *mut core::ffi::c_void, psink: *mut core::ffi::c_void, pvmpatchinfo: *const VM_PATCH_INFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbProvisioning_Impl::PatchVirtualMachines(this, core::mem::transmute(&jobxmlstring), core::mem::transmute(&jobguid), core::mem::transmute_copy(&psink), core::mem::transmute_copy(&pvmpatchinfo)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteVirtualMachines<Identity: windows_core::IUnknownImpl + ITsSbProvisioning_Impl>(this: *mut core::ffi::c_void, jobxmlstring: *mut core::ffi::c_void, jobguid: *mut core::ffi::c_void, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbProvisioning_Impl::DeleteVirtualMachines(this, core::mem::transmute(&jobxmlstring), core::mem::transmute(&jobguid), core::mem::transmute_copy(&psink)).into()
+            }
+        }
+        unsafe extern "system" fn CancelJob<Identity: windows_core::IUnknownImpl + ITsSbProvisioning_Impl>(this: *mut core::ffi::c_void, jobguid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbProvisioning_Impl::CancelJob(this, core::mem::transmute(&jobguid)).into()
+            }
+        }
+        Self {
+            base__: <ITsSbPlugin_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateVirtualMachines: CreateVirtualMachines::<Identity>,
+            PatchVirtualMachines: PatchVirtualMachines::<Identity>,
+            DeleteVirtualMachines: DeleteVirtualMachines::<Identity>,
+            CancelJob: CancelJob::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
 impl windows_core::RuntimeName for ITsSbProvisioning {}
 windows_core::imp::define_interface!(ITsSbProvisioningPluginNotifySink, ITsSbProvisioningPluginNotifySink_Vtbl, 0xaca87a8e_818b_4581_a032_49c3dfb9c701);
 windows_core::imp::interface_hierarchy!(ITsSbProvisioningPluginNotifySink, windows_core::IUnknown);
@@ -4233,6 +6042,58 @@ impl ITsSbProvisioningPluginNotifySink_Vtbl {
         iid == &<ITsSbProvisioningPluginNotifySink as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITsSbProvisioningPluginNotifySink_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbProvisioningPluginNotifySink_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnJobCreated<Identity: windows_core::IUnknownImpl + ITsSbProvisioningPluginNotifySink_Impl>(this: *mut core::ffi::c_void, pvmnotifyinfo: *const VM_NOTIFY_INFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbProvisioningPluginNotifySink_Impl::OnJobCreated(this, core::mem::transmute_copy(&pvmnotifyinfo)).into()
+            }
+        }
+        unsafe extern "system" fn OnVirtualMachineStatusChanged<Identity: windows_core::IUnknownImpl + ITsSbProvisioningPluginNotifySink_Impl>(this: *mut core::ffi::c_void, pvmnotifyentry: *const VM_NOTIFY_ENTRY, vmnotifystatus: VM_NOTIFY_STATUS, errorcode: windows_core::HRESULT, errordescr: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbProvisioningPluginNotifySink_Impl::OnVirtualMachineStatusChanged(this, core::mem::transmute_copy(&pvmnotifyentry), core::mem::transmute_copy(&vmnotifystatus), core::mem::transmute_copy(&errorcode), core::mem::transmute(&errordescr)).into()
+            }
+        }
+        unsafe extern "system" fn OnJobCompleted<Identity: windows_core::IUnknownImpl + ITsSbProvisioningPluginNotifySink_Impl>(this: *mut core::ffi::c_void, resultcode: windows_core::HRESULT, resultdescription: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbProvisioningPluginNotifySink_Impl::OnJobCompleted(this, core::mem::transmute_copy(&resultcode), core::mem::transmute(&resultdescription)).into()
+            }
+        }
+        unsafe extern "system" fn OnJobCancelled<Identity: windows_core::IUnknownImpl + ITsSbProvisioningPluginNotifySink_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbProvisioningPluginNotifySink_Impl::OnJobCancelled(this).into()
+            }
+        }
+        unsafe extern "system" fn LockVirtualMachine<Identity: windows_core::IUnknownImpl + ITsSbProvisioningPluginNotifySink_Impl>(this: *mut core::ffi::c_void, pvmnotifyentry: *const VM_NOTIFY_ENTRY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbProvisioningPluginNotifySink_Impl::LockVirtualMachine(this, core::mem::transmute_copy(&pvmnotifyentry)).into()
+            }
+        }
+        unsafe extern "system" fn OnVirtualMachineHostStatusChanged<Identity: windows_core::IUnknownImpl + ITsSbProvisioningPluginNotifySink_Impl>(this: *mut core::ffi::c_void, vmhost: *mut core::ffi::c_void, vmhostnotifystatus: VM_HOST_NOTIFY_STATUS, errorcode: windows_core::HRESULT, errordescr: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbProvisioningPluginNotifySink_Impl::OnVirtualMachineHostStatusChanged(this, core::mem::transmute(&vmhost), core::mem::transmute_copy(&vmhostnotifystatus), core::mem::transmute_copy(&errorcode), core::mem::transmute(&errordescr)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnJobCreated: OnJobCreated::<Identity>,
+            OnVirtualMachineStatusChanged: OnVirtualMachineStatusChanged::<Identity>,
+            OnJobCompleted: OnJobCompleted::<Identity>,
+            OnJobCancelled: OnJobCancelled::<Identity>,
+            LockVirtualMachine: LockVirtualMachine::<Identity>,
+            OnVirtualMachineHostStatusChanged: OnVirtualMachineHostStatusChanged::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITsSbProvisioningPluginNotifySink {}
 windows_core::imp::define_interface!(ITsSbResourceNotification, ITsSbResourceNotification_Vtbl, 0x65d3e85a_c39b_11dc_b92d_3cd255d89593);
 windows_core::imp::interface_hierarchy!(ITsSbResourceNotification, windows_core::IUnknown);
@@ -4300,6 +6161,37 @@ impl ITsSbResourceNotification_Vtbl {
         iid == &<ITsSbResourceNotification as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITsSbResourceNotification_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbResourceNotification_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn NotifySessionChange<Identity: windows_core::IUnknownImpl + ITsSbResourceNotification_Impl>(this: *mut core::ffi::c_void, changetype: TSSESSION_STATE, psession: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbResourceNotification_Impl::NotifySessionChange(this, core::mem::transmute_copy(&changetype), core::mem::transmute_copy(&psession)).into()
+            }
+        }
+        unsafe extern "system" fn NotifyTargetChange<Identity: windows_core::IUnknownImpl + ITsSbResourceNotification_Impl>(this: *mut core::ffi::c_void, targetchangetype: u32, ptarget: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbResourceNotification_Impl::NotifyTargetChange(this, core::mem::transmute_copy(&targetchangetype), core::mem::transmute_copy(&ptarget)).into()
+            }
+        }
+        unsafe extern "system" fn NotifyClientConnectionStateChange<Identity: windows_core::IUnknownImpl + ITsSbResourceNotification_Impl>(this: *mut core::ffi::c_void, changetype: CONNECTION_CHANGE_NOTIFICATION, pconnection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbResourceNotification_Impl::NotifyClientConnectionStateChange(this, core::mem::transmute_copy(&changetype), core::mem::transmute_copy(&pconnection)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            NotifySessionChange: NotifySessionChange::<Identity>,
+            NotifyTargetChange: NotifyTargetChange::<Identity>,
+            NotifyClientConnectionStateChange: NotifyClientConnectionStateChange::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITsSbResourceNotification {}
 windows_core::imp::define_interface!(ITsSbResourceNotificationEx, ITsSbResourceNotificationEx_Vtbl, 0xa8a47fde_ca91_44d2_b897_3aa28a43b2b7);
 windows_core::imp::interface_hierarchy!(ITsSbResourceNotificationEx, windows_core::IUnknown);
@@ -4353,10 +6245,41 @@ impl ITsSbResourceNotificationEx_Vtbl {
             NotifyTargetChangeEx: NotifyTargetChangeEx::<Identity, OFFSET>,
             NotifyClientConnectionStateChangeEx: NotifyClientConnectionStateChangeEx::<Identity, OFFSET>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ITsSbResourceNotificationEx as windows_core::Interface>::IID
-    }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbResourceNotificationEx as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + ITsSbResourceNotificationEx_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbResourceNotificationEx_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn NotifySessionChangeEx<Identity: windows_core::IUnknownImpl + ITsSbResourceNotificationEx_Impl>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, username: *mut core::ffi::c_void, domain: *mut core::ffi::c_void, sessionid: u32, sessionstate: TSSESSION_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbResourceNotificationEx_Impl::NotifySessionChangeEx(this, core::mem::transmute(&targetname), core::mem::transmute(&username), core::mem::transmute(&domain), core::mem::transmute_copy(&sessionid), core::mem::transmute_copy(&sessionstate)).into()
+            }
+        }
+        unsafe extern "system" fn NotifyTargetChangeEx<Identity: windows_core::IUnknownImpl + ITsSbResourceNotificationEx_Impl>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, targetchangetype: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbResourceNotificationEx_Impl::NotifyTargetChangeEx(this, core::mem::transmute(&targetname), core::mem::transmute_copy(&targetchangetype)).into()
+            }
+        }
+        unsafe extern "system" fn NotifyClientConnectionStateChangeEx<Identity: windows_core::IUnknownImpl + ITsSbResourceNotificationEx_Impl>(this: *mut core::ffi::c_void, username: *mut core::ffi::c_void, domain: *mut core::ffi::c_void, initialprogram: *mut core::ffi::c_void, poolname: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, connectionchangetype: CONNECTION_CHANGE_NOTIFICATION) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbResourceNotificationEx_Impl::NotifyClientConnectionStateChangeEx(this, core::mem::transmute(&username), core::mem::transmute(&domain), core::mem::transmute(&initialprogram), core::mem::transmute(&poolname), core::mem::transmute(&targetname), core::mem::transmute_copy(&connectionchangetype)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            NotifySessionChangeEx: NotifySessionChangeEx::<Identity>,
+            NotifyTargetChangeEx: NotifyTargetChangeEx::<Identity>,
+            NotifyClientConnectionStateChangeEx: NotifyClientConnectionStateChangeEx::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for ITsSbResourceNotificationEx {}
 windows_core::imp::define_interface!(ITsSbResourcePlugin, ITsSbResourcePlugin_Vtbl, 0xea8db42c_98ed_4535_a88b_2a164f35490f);
@@ -4384,6 +6307,13 @@ impl ITsSbResourcePlugin_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl<Identity: windows_core::IUnknownImpl + ITsSbResourcePlugin_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbResourcePlugin_Vtbl
+where
+    ITsSbPlugin_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <ITsSbPlugin_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
 impl windows_core::RuntimeName for ITsSbResourcePlugin {}
 windows_core::imp::define_interface!(ITsSbResourcePluginStore, ITsSbResourcePluginStore_Vtbl, 0x5c38f65f_bcf1_4036_a6bf_9e3cccae0b63);
 windows_core::imp::interface_hierarchy!(ITsSbResourcePluginStore, windows_core::IUnknown);
@@ -4627,9 +6557,261 @@ impl ITsSbResourcePluginStore_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn QuerySessionBySessionId<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwsessionid: u32, targetname: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn QuerySessionBySessionId<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, dwsessionid: u32, targetname: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbResourcePluginStore_Impl::QuerySessionBySessionId(this, core::mem::transmute_copy(&dwsessionid), core::mem::transmute(&targetname)) {
+                    Ok(ok__) => {
+                        ppsession.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddTargetToStore<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ptarget: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbResourcePluginStore_Impl::AddTargetToStore(this, core::mem::transmute_copy(&ptarget)).into()
+            }
+        }
+        unsafe extern "system" fn AddSessionToStore<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, psession: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbResourcePluginStore_Impl::AddSessionToStore(this, core::mem::transmute_copy(&psession)).into()
+            }
+        }
+        unsafe extern "system" fn AddEnvironmentToStore<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, penvironment: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbResourcePluginStore_Impl::AddEnvironmentToStore(this, core::mem::transmute_copy(&penvironment)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveEnvironmentFromStore<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, environmentname: *mut core::ffi::c_void, bignoreowner: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbResourcePluginStore_Impl::RemoveEnvironmentFromStore(this, core::mem::transmute(&environmentname), core::mem::transmute_copy(&bignoreowner)).into()
+            }
+        }
+        unsafe extern "system" fn EnumerateFarms<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwcount: *mut u32, pval: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbResourcePluginStore_Impl::EnumerateFarms(this, core::mem::transmute_copy(&pdwcount), core::mem::transmute_copy(&pval)).into()
+            }
+        }
+        unsafe extern "system" fn QueryEnvironment<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, environmentname: *mut core::ffi::c_void, ppenvironment: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbResourcePluginStore_Impl::QueryEnvironment(this, core::mem::transmute(&environmentname)) {
+                    Ok(ok__) => {
+                        ppenvironment.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumerateEnvironments<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwcount: *mut u32, pval: *mut *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbResourcePluginStore_Impl::EnumerateEnvironments(this, core::mem::transmute_copy(&pdwcount), core::mem::transmute_copy(&pval)).into()
+            }
+        }
+        unsafe extern "system" fn SaveTarget<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ptarget: *mut core::ffi::c_void, bforcewrite: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbResourcePluginStore_Impl::SaveTarget(this, core::mem::transmute_copy(&ptarget), core::mem::transmute_copy(&bforcewrite)).into()
+            }
+        }
+        unsafe extern "system" fn SaveEnvironment<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, penvironment: *mut core::ffi::c_void, bforcewrite: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbResourcePluginStore_Impl::SaveEnvironment(this, core::mem::transmute_copy(&penvironment), core::mem::transmute_copy(&bforcewrite)).into()
+            }
+        }
+        unsafe extern "system" fn SaveSession<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, psession: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbResourcePluginStore_Impl::SaveSession(this, core::mem::transmute_copy(&psession)).into()
+            }
+        }
+        unsafe extern "system" fn SetTargetProperty<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, propertyname: *mut core::ffi::c_void, pproperty: *const super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbResourcePluginStore_Impl::SetTargetProperty(this, core::mem::transmute(&targetname), core::mem::transmute(&propertyname), core::mem::transmute_copy(&pproperty)).into()
+            }
+        }
+        unsafe extern "system" fn SetEnvironmentProperty<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, environmentname: *mut core::ffi::c_void, propertyname: *mut core::ffi::c_void, pproperty: *const super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbResourcePluginStore_Impl::SetEnvironmentProperty(this, core::mem::transmute(&environmentname), core::mem::transmute(&propertyname), core::mem::transmute_copy(&pproperty)).into()
+            }
+        }
+        unsafe extern "system" fn SetTargetState<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, newstate: TARGET_STATE, poldstate: *mut TARGET_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbResourcePluginStore_Impl::SetTargetState(this, core::mem::transmute(&targetname), core::mem::transmute_copy(&newstate)) {
+                    Ok(ok__) => {
+                        poldstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSessionState<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, sbsession: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbResourcePluginStore_Impl::SetSessionState(this, core::mem::transmute_copy(&sbsession)).into()
+            }
+        }
+        unsafe extern "system" fn EnumerateTargets<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, farmname: *mut core::ffi::c_void, envname: *mut core::ffi::c_void, sortbyfieldid: TS_SB_SORT_BY, sortybypropname: *mut core::ffi::c_void, pdwcount: *mut u32, pval: *mut *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbResourcePluginStore_Impl::EnumerateTargets(this, core::mem::transmute(&farmname), core::mem::transmute(&envname), core::mem::transmute_copy(&sortbyfieldid), core::mem::transmute(&sortybypropname), core::mem::transmute_copy(&pdwcount), core::mem::transmute_copy(&pval)).into()
+            }
+        }
+        unsafe extern "system" fn EnumerateSessions<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, username: *mut core::ffi::c_void, userdomain: *mut core::ffi::c_void, poolname: *mut core::ffi::c_void, initialprogram: *mut core::ffi::c_void, psessionstate: *const TSSESSION_STATE, pdwcount: *mut u32, ppval: *mut *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbResourcePluginStore_Impl::EnumerateSessions(this, core::mem::transmute(&targetname), core::mem::transmute(&username), core::mem::transmute(&userdomain), core::mem::transmute(&poolname), core::mem::transmute(&initialprogram), core::mem::transmute_copy(&psessionstate), core::mem::transmute_copy(&pdwcount), core::mem::transmute_copy(&ppval)).into()
+            }
+        }
+        unsafe extern "system" fn GetFarmProperty<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, farmname: *mut core::ffi::c_void, propertyname: *mut core::ffi::c_void, pvarvalue: *const super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbResourcePluginStore_Impl::GetFarmProperty(this, core::mem::transmute(&farmname), core::mem::transmute(&propertyname), core::mem::transmute_copy(&pvarvalue)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteTarget<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, hostname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbResourcePluginStore_Impl::DeleteTarget(this, core::mem::transmute(&targetname), core::mem::transmute(&hostname)).into()
+            }
+        }
+        unsafe extern "system" fn SetTargetPropertyWithVersionCheck<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ptarget: *mut core::ffi::c_void, propertyname: *mut core::ffi::c_void, pproperty: *const super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbResourcePluginStore_Impl::SetTargetPropertyWithVersionCheck(this, core::mem::transmute_copy(&ptarget), core::mem::transmute(&propertyname), core::mem::transmute_copy(&pproperty)).into()
+            }
+        }
+        unsafe extern "system" fn SetEnvironmentPropertyWithVersionCheck<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, penvironment: *mut core::ffi::c_void, propertyname: *mut core::ffi::c_void, pproperty: *const super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbResourcePluginStore_Impl::SetEnvironmentPropertyWithVersionCheck(this, core::mem::transmute_copy(&penvironment), core::mem::transmute(&propertyname), core::mem::transmute_copy(&pproperty)).into()
+            }
+        }
+        unsafe extern "system" fn AcquireTargetLock<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, dwtimeout: u32, ppcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbResourcePluginStore_Impl::AcquireTargetLock(this, core::mem::transmute(&targetname), core::mem::transmute_copy(&dwtimeout)) {
+                    Ok(ok__) => {
+                        ppcontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ReleaseTargetLock<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbResourcePluginStore_Impl::ReleaseTargetLock(this, core::mem::transmute_copy(&pcontext)).into()
+            }
+        }
+        unsafe extern "system" fn TestAndSetServerState<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, poolname: *mut core::ffi::c_void, serverfqdn: *mut core::ffi::c_void, newstate: TARGET_STATE, teststate: TARGET_STATE, pinitstate: *mut TARGET_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbResourcePluginStore_Impl::TestAndSetServerState(this, core::mem::transmute(&poolname), core::mem::transmute(&serverfqdn), core::mem::transmute_copy(&newstate), core::mem::transmute_copy(&teststate)) {
+                    Ok(ok__) => {
+                        pinitstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetServerWaitingToStart<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, poolname: *mut core::ffi::c_void, servername: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbResourcePluginStore_Impl::SetServerWaitingToStart(this, core::mem::transmute(&poolname), core::mem::transmute(&servername)).into()
+            }
+        }
+        unsafe extern "system" fn GetServerState<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, poolname: *mut core::ffi::c_void, serverfqdn: *mut core::ffi::c_void, pstate: *mut TARGET_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbResourcePluginStore_Impl::GetServerState(this, core::mem::transmute(&poolname), core::mem::transmute(&serverfqdn)) {
+                    Ok(ok__) => {
+                        pstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetServerDrainMode<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, serverfqdn: *mut core::ffi::c_void, drainmode: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbResourcePluginStore_Impl::SetServerDrainMode(this, core::mem::transmute(&serverfqdn), core::mem::transmute_copy(&drainmode)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            QueryTarget: QueryTarget::<Identity, OFFSET>,
+            QuerySessionBySessionId: QuerySessionBySessionId::<Identity, OFFSET>,
+            AddTargetToStore: AddTargetToStore::<Identity, OFFSET>,
+            AddSessionToStore: AddSessionToStore::<Identity, OFFSET>,
+            AddEnvironmentToStore: AddEnvironmentToStore::<Identity, OFFSET>,
+            RemoveEnvironmentFromStore: RemoveEnvironmentFromStore::<Identity, OFFSET>,
+            EnumerateFarms: EnumerateFarms::<Identity, OFFSET>,
+            QueryEnvironment: QueryEnvironment::<Identity, OFFSET>,
+            EnumerateEnvironments: EnumerateEnvironments::<Identity, OFFSET>,
+            SaveTarget: SaveTarget::<Identity, OFFSET>,
+            SaveEnvironment: SaveEnvironment::<Identity, OFFSET>,
+            SaveSession: SaveSession::<Identity, OFFSET>,
+            SetTargetProperty: SetTargetProperty::<Identity, OFFSET>,
+            SetEnvironmentProperty: SetEnvironmentProperty::<Identity, OFFSET>,
+            SetTargetState: SetTargetState::<Identity, OFFSET>,
+            SetSessionState: SetSessionState::<Identity, OFFSET>,
+            EnumerateTargets: EnumerateTargets::<Identity, OFFSET>,
+            EnumerateSessions: EnumerateSessions::<Identity, OFFSET>,
+            GetFarmProperty: GetFarmProperty::<Identity, OFFSET>,
+            DeleteTarget: DeleteTarget::<Identity, OFFSET>,
+            SetTargetPropertyWithVersionCheck: SetTargetPropertyWithVersionCheck::<Identity, OFFSET>,
+            SetEnvironmentPropertyWithVersionCheck: SetEnvironmentPropertyWithVersionCheck::<Identity, OFFSET>,
+            AcquireTargetLock: AcquireTargetLock::<Identity, OFFSET>,
+            ReleaseTargetLock: ReleaseTargetLock::<Identity, OFFSET>,
+            TestAndSetServerState: TestAndSetServerState::<Identity, OFFSET>,
+            SetServerWaitingToStart: SetServerWaitingToStart::<Identity, OFFSET>,
+            GetServerState: GetServerState::<Identity, OFFSET>,
+            SetServerDrainMode: SetServerDrainMode::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbResourcePluginStore as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbResourcePluginStore_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn QueryTarget<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, farmname: *mut core::ffi::c_void, pptarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbResourcePluginStore_Impl::QueryTarget(this, core::mem::transmute(&targetname), core::mem::transmute(&farmname)) {
+                    Ok(ok__) => {
+                        pptarget.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QuerySessionBySessionId<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, dwsessionid: u32, targetname: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbResourcePluginStore_Impl::QuerySessionBySessionId(this, core::mem::transmute_copy(&dwsessionid), core::mem::transmute(&targetname)) {
                     Ok(ok__) => {
                         ppsession.write(core::mem::transmute(ok__));
@@ -4639,39 +6821,39 @@ impl ITsSbResourcePluginStore_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn AddTargetToStore<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ptarget: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn AddTargetToStore<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, ptarget: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbResourcePluginStore_Impl::AddTargetToStore(this, core::mem::transmute_copy(&ptarget)).into()
             }
         }
-        unsafe extern "system" fn AddSessionToStore<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, psession: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn AddSessionToStore<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, psession: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbResourcePluginStore_Impl::AddSessionToStore(this, core::mem::transmute_copy(&psession)).into()
             }
         }
-        unsafe extern "system" fn AddEnvironmentToStore<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, penvironment: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn AddEnvironmentToStore<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, penvironment: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbResourcePluginStore_Impl::AddEnvironmentToStore(this, core::mem::transmute_copy(&penvironment)).into()
             }
         }
-        unsafe extern "system" fn RemoveEnvironmentFromStore<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, environmentname: *mut core::ffi::c_void, bignoreowner: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn RemoveEnvironmentFromStore<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, environmentname: *mut core::ffi::c_void, bignoreowner: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbResourcePluginStore_Impl::RemoveEnvironmentFromStore(this, core::mem::transmute(&environmentname), core::mem::transmute_copy(&bignoreowner)).into()
             }
         }
-        unsafe extern "system" fn EnumerateFarms<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwcount: *mut u32, pval: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+        unsafe extern "system" fn EnumerateFarms<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, pdwcount: *mut u32, pval: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbResourcePluginStore_Impl::EnumerateFarms(this, core::mem::transmute_copy(&pdwcount), core::mem::transmute_copy(&pval)).into()
             }
         }
-        unsafe extern "system" fn QueryEnvironment<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, environmentname: *mut core::ffi::c_void, ppenvironment: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn QueryEnvironment<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, environmentname: *mut core::ffi::c_void, ppenvironment: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbResourcePluginStore_Impl::QueryEnvironment(this, core::mem::transmute(&environmentname)) {
                     Ok(ok__) => {
                         ppenvironment.write(core::mem::transmute(ok__));
@@ -4681,45 +6863,45 @@ impl ITsSbResourcePluginStore_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn EnumerateEnvironments<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdwcount: *mut u32, pval: *mut *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn EnumerateEnvironments<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, pdwcount: *mut u32, pval: *mut *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbResourcePluginStore_Impl::EnumerateEnvironments(this, core::mem::transmute_copy(&pdwcount), core::mem::transmute_copy(&pval)).into()
             }
         }
-        unsafe extern "system" fn SaveTarget<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ptarget: *mut core::ffi::c_void, bforcewrite: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SaveTarget<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, ptarget: *mut core::ffi::c_void, bforcewrite: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbResourcePluginStore_Impl::SaveTarget(this, core::mem::transmute_copy(&ptarget), core::mem::transmute_copy(&bforcewrite)).into()
             }
         }
-        unsafe extern "system" fn SaveEnvironment<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, penvironment: *mut core::ffi::c_void, bforcewrite: windows_core::BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SaveEnvironment<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, penvironment: *mut core::ffi::c_void, bforcewrite: windows_core::BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbResourcePluginStore_Impl::SaveEnvironment(this, core::mem::transmute_copy(&penvironment), core::mem::transmute_copy(&bforcewrite)).into()
             }
         }
-        unsafe extern "system" fn SaveSession<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, psession: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SaveSession<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, psession: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbResourcePluginStore_Impl::SaveSession(this, core::mem::transmute_copy(&psession)).into()
             }
         }
-        unsafe extern "system" fn SetTargetProperty<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, propertyname: *mut core::ffi::c_void, pproperty: *const super::Variant::VARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetTargetProperty<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, propertyname: *mut core::ffi::c_void, pproperty: *const super::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbResourcePluginStore_Impl::SetTargetProperty(this, core::mem::transmute(&targetname), core::mem::transmute(&propertyname), core::mem::transmute_copy(&pproperty)).into()
             }
         }
-        unsafe extern "system" fn SetEnvironmentProperty<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, environmentname: *mut core::ffi::c_void, propertyname: *mut core::ffi::c_void, pproperty: *const super::Variant::VARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetEnvironmentProperty<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, environmentname: *mut core::ffi::c_void, propertyname: *mut core::ffi::c_void, pproperty: *const super::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbResourcePluginStore_Impl::SetEnvironmentProperty(this, core::mem::transmute(&environmentname), core::mem::transmute(&propertyname), core::mem::transmute_copy(&pproperty)).into()
             }
         }
-        unsafe extern "system" fn SetTargetState<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, newstate: TARGET_STATE, poldstate: *mut TARGET_STATE) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetTargetState<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, newstate: TARGET_STATE, poldstate: *mut TARGET_STATE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbResourcePluginStore_Impl::SetTargetState(this, core::mem::transmute(&targetname), core::mem::transmute_copy(&newstate)) {
                     Ok(ok__) => {
                         poldstate.write(core::mem::transmute(ok__));
@@ -4729,51 +6911,51 @@ impl ITsSbResourcePluginStore_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetSessionState<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, sbsession: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetSessionState<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, sbsession: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbResourcePluginStore_Impl::SetSessionState(this, core::mem::transmute_copy(&sbsession)).into()
             }
         }
-        unsafe extern "system" fn EnumerateTargets<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, farmname: *mut core::ffi::c_void, envname: *mut core::ffi::c_void, sortbyfieldid: TS_SB_SORT_BY, sortybypropname: *mut core::ffi::c_void, pdwcount: *mut u32, pval: *mut *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn EnumerateTargets<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, farmname: *mut core::ffi::c_void, envname: *mut core::ffi::c_void, sortbyfieldid: TS_SB_SORT_BY, sortybypropname: *mut core::ffi::c_void, pdwcount: *mut u32, pval: *mut *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbResourcePluginStore_Impl::EnumerateTargets(this, core::mem::transmute(&farmname), core::mem::transmute(&envname), core::mem::transmute_copy(&sortbyfieldid), core::mem::transmute(&sortybypropname), core::mem::transmute_copy(&pdwcount), core::mem::transmute_copy(&pval)).into()
             }
         }
-        unsafe extern "system" fn EnumerateSessions<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, username: *mut core::ffi::c_void, userdomain: *mut core::ffi::c_void, poolname: *mut core::ffi::c_void, initialprogram: *mut core::ffi::c_void, psessionstate: *const TSSESSION_STATE, pdwcount: *mut u32, ppval: *mut *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn EnumerateSessions<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, username: *mut core::ffi::c_void, userdomain: *mut core::ffi::c_void, poolname: *mut core::ffi::c_void, initialprogram: *mut core::ffi::c_void, psessionstate: *const TSSESSION_STATE, pdwcount: *mut u32, ppval: *mut *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbResourcePluginStore_Impl::EnumerateSessions(this, core::mem::transmute(&targetname), core::mem::transmute(&username), core::mem::transmute(&userdomain), core::mem::transmute(&poolname), core::mem::transmute(&initialprogram), core::mem::transmute_copy(&psessionstate), core::mem::transmute_copy(&pdwcount), core::mem::transmute_copy(&ppval)).into()
             }
         }
-        unsafe extern "system" fn GetFarmProperty<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, farmname: *mut core::ffi::c_void, propertyname: *mut core::ffi::c_void, pvarvalue: *const super::Variant::VARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetFarmProperty<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, farmname: *mut core::ffi::c_void, propertyname: *mut core::ffi::c_void, pvarvalue: *const super::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbResourcePluginStore_Impl::GetFarmProperty(this, core::mem::transmute(&farmname), core::mem::transmute(&propertyname), core::mem::transmute_copy(&pvarvalue)).into()
             }
         }
-        unsafe extern "system" fn DeleteTarget<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, hostname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn DeleteTarget<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, hostname: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbResourcePluginStore_Impl::DeleteTarget(this, core::mem::transmute(&targetname), core::mem::transmute(&hostname)).into()
             }
         }
-        unsafe extern "system" fn SetTargetPropertyWithVersionCheck<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ptarget: *mut core::ffi::c_void, propertyname: *mut core::ffi::c_void, pproperty: *const super::Variant::VARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetTargetPropertyWithVersionCheck<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, ptarget: *mut core::ffi::c_void, propertyname: *mut core::ffi::c_void, pproperty: *const super::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbResourcePluginStore_Impl::SetTargetPropertyWithVersionCheck(this, core::mem::transmute_copy(&ptarget), core::mem::transmute(&propertyname), core::mem::transmute_copy(&pproperty)).into()
             }
         }
-        unsafe extern "system" fn SetEnvironmentPropertyWithVersionCheck<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, penvironment: *mut core::ffi::c_void, propertyname: *mut core::ffi::c_void, pproperty: *const super::Variant::VARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetEnvironmentPropertyWithVersionCheck<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, penvironment: *mut core::ffi::c_void, propertyname: *mut core::ffi::c_void, pproperty: *const super::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbResourcePluginStore_Impl::SetEnvironmentPropertyWithVersionCheck(this, core::mem::transmute_copy(&penvironment), core::mem::transmute(&propertyname), core::mem::transmute_copy(&pproperty)).into()
             }
         }
-        unsafe extern "system" fn AcquireTargetLock<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, dwtimeout: u32, ppcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn AcquireTargetLock<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void, dwtimeout: u32, ppcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbResourcePluginStore_Impl::AcquireTargetLock(this, core::mem::transmute(&targetname), core::mem::transmute_copy(&dwtimeout)) {
                     Ok(ok__) => {
                         ppcontext.write(core::mem::transmute(ok__));
@@ -4783,15 +6965,15 @@ impl ITsSbResourcePluginStore_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ReleaseTargetLock<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn ReleaseTargetLock<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, pcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbResourcePluginStore_Impl::ReleaseTargetLock(this, core::mem::transmute_copy(&pcontext)).into()
             }
         }
-        unsafe extern "system" fn TestAndSetServerState<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, poolname: *mut core::ffi::c_void, serverfqdn: *mut core::ffi::c_void, newstate: TARGET_STATE, teststate: TARGET_STATE, pinitstate: *mut TARGET_STATE) -> windows_core::HRESULT {
+        unsafe extern "system" fn TestAndSetServerState<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, poolname: *mut core::ffi::c_void, serverfqdn: *mut core::ffi::c_void, newstate: TARGET_STATE, teststate: TARGET_STATE, pinitstate: *mut TARGET_STATE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbResourcePluginStore_Impl::TestAndSetServerState(this, core::mem::transmute(&poolname), core::mem::transmute(&serverfqdn), core::mem::transmute_copy(&newstate), core::mem::transmute_copy(&teststate)) {
                     Ok(ok__) => {
                         pinitstate.write(core::mem::transmute(ok__));
@@ -4801,15 +6983,15 @@ impl ITsSbResourcePluginStore_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetServerWaitingToStart<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, poolname: *mut core::ffi::c_void, servername: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetServerWaitingToStart<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, poolname: *mut core::ffi::c_void, servername: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbResourcePluginStore_Impl::SetServerWaitingToStart(this, core::mem::transmute(&poolname), core::mem::transmute(&servername)).into()
             }
         }
-        unsafe extern "system" fn GetServerState<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, poolname: *mut core::ffi::c_void, serverfqdn: *mut core::ffi::c_void, pstate: *mut TARGET_STATE) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetServerState<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, poolname: *mut core::ffi::c_void, serverfqdn: *mut core::ffi::c_void, pstate: *mut TARGET_STATE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbResourcePluginStore_Impl::GetServerState(this, core::mem::transmute(&poolname), core::mem::transmute(&serverfqdn)) {
                     Ok(ok__) => {
                         pstate.write(core::mem::transmute(ok__));
@@ -4819,47 +7001,44 @@ impl ITsSbResourcePluginStore_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetServerDrainMode<Identity: ITsSbResourcePluginStore_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, serverfqdn: *mut core::ffi::c_void, drainmode: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetServerDrainMode<Identity: windows_core::IUnknownImpl + ITsSbResourcePluginStore_Impl>(this: *mut core::ffi::c_void, serverfqdn: *mut core::ffi::c_void, drainmode: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbResourcePluginStore_Impl::SetServerDrainMode(this, core::mem::transmute(&serverfqdn), core::mem::transmute_copy(&drainmode)).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            QueryTarget: QueryTarget::<Identity, OFFSET>,
-            QuerySessionBySessionId: QuerySessionBySessionId::<Identity, OFFSET>,
-            AddTargetToStore: AddTargetToStore::<Identity, OFFSET>,
-            AddSessionToStore: AddSessionToStore::<Identity, OFFSET>,
-            AddEnvironmentToStore: AddEnvironmentToStore::<Identity, OFFSET>,
-            RemoveEnvironmentFromStore: RemoveEnvironmentFromStore::<Identity, OFFSET>,
-            EnumerateFarms: EnumerateFarms::<Identity, OFFSET>,
-            QueryEnvironment: QueryEnvironment::<Identity, OFFSET>,
-            EnumerateEnvironments: EnumerateEnvironments::<Identity, OFFSET>,
-            SaveTarget: SaveTarget::<Identity, OFFSET>,
-            SaveEnvironment: SaveEnvironment::<Identity, OFFSET>,
-            SaveSession: SaveSession::<Identity, OFFSET>,
-            SetTargetProperty: SetTargetProperty::<Identity, OFFSET>,
-            SetEnvironmentProperty: SetEnvironmentProperty::<Identity, OFFSET>,
-            SetTargetState: SetTargetState::<Identity, OFFSET>,
-            SetSessionState: SetSessionState::<Identity, OFFSET>,
-            EnumerateTargets: EnumerateTargets::<Identity, OFFSET>,
-            EnumerateSessions: EnumerateSessions::<Identity, OFFSET>,
-            GetFarmProperty: GetFarmProperty::<Identity, OFFSET>,
-            DeleteTarget: DeleteTarget::<Identity, OFFSET>,
-            SetTargetPropertyWithVersionCheck: SetTargetPropertyWithVersionCheck::<Identity, OFFSET>,
-            SetEnvironmentPropertyWithVersionCheck: SetEnvironmentPropertyWithVersionCheck::<Identity, OFFSET>,
-            AcquireTargetLock: AcquireTargetLock::<Identity, OFFSET>,
-            ReleaseTargetLock: ReleaseTargetLock::<Identity, OFFSET>,
-            TestAndSetServerState: TestAndSetServerState::<Identity, OFFSET>,
-            SetServerWaitingToStart: SetServerWaitingToStart::<Identity, OFFSET>,
-            GetServerState: GetServerState::<Identity, OFFSET>,
-            SetServerDrainMode: SetServerDrainMode::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ITsSbResourcePluginStore as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            QueryTarget: QueryTarget::<Identity>,
+            QuerySessionBySessionId: QuerySessionBySessionId::<Identity>,
+            AddTargetToStore: AddTargetToStore::<Identity>,
+            AddSessionToStore: AddSessionToStore::<Identity>,
+            AddEnvironmentToStore: AddEnvironmentToStore::<Identity>,
+            RemoveEnvironmentFromStore: RemoveEnvironmentFromStore::<Identity>,
+            EnumerateFarms: EnumerateFarms::<Identity>,
+            QueryEnvironment: QueryEnvironment::<Identity>,
+            EnumerateEnvironments: EnumerateEnvironments::<Identity>,
+            SaveTarget: SaveTarget::<Identity>,
+            SaveEnvironment: SaveEnvironment::<Identity>,
+            SaveSession: SaveSession::<Identity>,
+            SetTargetProperty: SetTargetProperty::<Identity>,
+            SetEnvironmentProperty: SetEnvironmentProperty::<Identity>,
+            SetTargetState: SetTargetState::<Identity>,
+            SetSessionState: SetSessionState::<Identity>,
+            EnumerateTargets: EnumerateTargets::<Identity>,
+            EnumerateSessions: EnumerateSessions::<Identity>,
+            GetFarmProperty: GetFarmProperty::<Identity>,
+            DeleteTarget: DeleteTarget::<Identity>,
+            SetTargetPropertyWithVersionCheck: SetTargetPropertyWithVersionCheck::<Identity>,
+            SetEnvironmentPropertyWithVersionCheck: SetEnvironmentPropertyWithVersionCheck::<Identity>,
+            AcquireTargetLock: AcquireTargetLock::<Identity>,
+            ReleaseTargetLock: ReleaseTargetLock::<Identity>,
+            TestAndSetServerState: TestAndSetServerState::<Identity>,
+            SetServerWaitingToStart: SetServerWaitingToStart::<Identity>,
+            GetServerState: GetServerState::<Identity>,
+            SetServerDrainMode: SetServerDrainMode::<Identity>,
+        }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ITsSbResourcePluginStore {}
@@ -4908,6 +7087,30 @@ impl ITsSbServiceNotification_Vtbl {
         iid == &<ITsSbServiceNotification as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITsSbServiceNotification_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbServiceNotification_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn NotifyServiceFailure<Identity: windows_core::IUnknownImpl + ITsSbServiceNotification_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbServiceNotification_Impl::NotifyServiceFailure(this).into()
+            }
+        }
+        unsafe extern "system" fn NotifyServiceSuccess<Identity: windows_core::IUnknownImpl + ITsSbServiceNotification_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbServiceNotification_Impl::NotifyServiceSuccess(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            NotifyServiceFailure: NotifyServiceFailure::<Identity>,
+            NotifyServiceSuccess: NotifyServiceSuccess::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITsSbServiceNotification {}
 windows_core::imp::define_interface!(ITsSbSession, ITsSbSession_Vtbl, 0xd453aac7_b1d8_4c5e_ba34_9afb4c8c5510);
 windows_core::imp::interface_hierarchy!(ITsSbSession, windows_core::IUnknown);
@@ -5049,9 +7252,201 @@ impl ITsSbSession_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn TargetName<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, targetname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn TargetName<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, targetname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbSession_Impl::TargetName(this) {
+                    Ok(ok__) => {
+                        targetname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTargetName<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbSession_Impl::SetTargetName(this, core::mem::transmute(&targetname)).into()
+            }
+        }
+        unsafe extern "system" fn Username<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, username: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbSession_Impl::Username(this) {
+                    Ok(ok__) => {
+                        username.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Domain<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, domain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbSession_Impl::Domain(this) {
+                    Ok(ok__) => {
+                        domain.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn State<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pstate: *mut TSSESSION_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbSession_Impl::State(this) {
+                    Ok(ok__) => {
+                        pstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetState<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, state: TSSESSION_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbSession_Impl::SetState(this, core::mem::transmute_copy(&state)).into()
+            }
+        }
+        unsafe extern "system" fn CreateTime<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ptime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbSession_Impl::CreateTime(this) {
+                    Ok(ok__) => {
+                        ptime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCreateTime<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, time: super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbSession_Impl::SetCreateTime(this, core::mem::transmute(&time)).into()
+            }
+        }
+        unsafe extern "system" fn DisconnectTime<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ptime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbSession_Impl::DisconnectTime(this) {
+                    Ok(ok__) => {
+                        ptime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetDisconnectTime<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, time: super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbSession_Impl::SetDisconnectTime(this, core::mem::transmute(&time)).into()
+            }
+        }
+        unsafe extern "system" fn InitialProgram<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, app: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbSession_Impl::InitialProgram(this) {
+                    Ok(ok__) => {
+                        app.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetInitialProgram<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, application: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbSession_Impl::SetInitialProgram(this, core::mem::transmute(&application)).into()
+            }
+        }
+        unsafe extern "system" fn ClientDisplay<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pclientdisplay: *mut CLIENT_DISPLAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbSession_Impl::ClientDisplay(this) {
+                    Ok(ok__) => {
+                        pclientdisplay.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetClientDisplay<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pclientdisplay: CLIENT_DISPLAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbSession_Impl::SetClientDisplay(this, core::mem::transmute(&pclientdisplay)).into()
+            }
+        }
+        unsafe extern "system" fn ProtocolType<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pval: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbSession_Impl::ProtocolType(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProtocolType<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, val: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbSession_Impl::SetProtocolType(this, core::mem::transmute_copy(&val)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            SessionId: SessionId::<Identity, OFFSET>,
+            TargetName: TargetName::<Identity, OFFSET>,
+            SetTargetName: SetTargetName::<Identity, OFFSET>,
+            Username: Username::<Identity, OFFSET>,
+            Domain: Domain::<Identity, OFFSET>,
+            State: State::<Identity, OFFSET>,
+            SetState: SetState::<Identity, OFFSET>,
+            CreateTime: CreateTime::<Identity, OFFSET>,
+            SetCreateTime: SetCreateTime::<Identity, OFFSET>,
+            DisconnectTime: DisconnectTime::<Identity, OFFSET>,
+            SetDisconnectTime: SetDisconnectTime::<Identity, OFFSET>,
+            InitialProgram: InitialProgram::<Identity, OFFSET>,
+            SetInitialProgram: SetInitialProgram::<Identity, OFFSET>,
+            ClientDisplay: ClientDisplay::<Identity, OFFSET>,
+            SetClientDisplay: SetClientDisplay::<Identity, OFFSET>,
+            ProtocolType: ProtocolType::<Identity, OFFSET>,
+            SetProtocolType: SetProtocolType::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbSession as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + ITsSbSession_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbSession_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SessionId<Identity: windows_core::IUnknownImpl + ITsSbSession_Impl>(this: *mut core::ffi::c_void, pval: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbSession_Impl::SessionId(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn TargetName<Identity: windows_core::IUnknownImpl + ITsSbSession_Impl>(this: *mut core::ffi::c_void, targetname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbSession_Impl::TargetName(this) {
                     Ok(ok__) => {
                         targetname.write(core::mem::transmute(ok__));
@@ -5061,15 +7456,15 @@ impl ITsSbSession_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetTargetName<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetTargetName<Identity: windows_core::IUnknownImpl + ITsSbSession_Impl>(this: *mut core::ffi::c_void, targetname: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbSession_Impl::SetTargetName(this, core::mem::transmute(&targetname)).into()
             }
         }
-        unsafe extern "system" fn Username<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, username: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Username<Identity: windows_core::IUnknownImpl + ITsSbSession_Impl>(this: *mut core::ffi::c_void, username: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbSession_Impl::Username(this) {
                     Ok(ok__) => {
                         username.write(core::mem::transmute(ok__));
@@ -5079,9 +7474,9 @@ impl ITsSbSession_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Domain<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, domain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Domain<Identity: windows_core::IUnknownImpl + ITsSbSession_Impl>(this: *mut core::ffi::c_void, domain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbSession_Impl::Domain(this) {
                     Ok(ok__) => {
                         domain.write(core::mem::transmute(ok__));
@@ -5091,9 +7486,9 @@ impl ITsSbSession_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn State<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pstate: *mut TSSESSION_STATE) -> windows_core::HRESULT {
+        unsafe extern "system" fn State<Identity: windows_core::IUnknownImpl + ITsSbSession_Impl>(this: *mut core::ffi::c_void, pstate: *mut TSSESSION_STATE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbSession_Impl::State(this) {
                     Ok(ok__) => {
                         pstate.write(core::mem::transmute(ok__));
@@ -5103,15 +7498,15 @@ impl ITsSbSession_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetState<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, state: TSSESSION_STATE) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetState<Identity: windows_core::IUnknownImpl + ITsSbSession_Impl>(this: *mut core::ffi::c_void, state: TSSESSION_STATE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbSession_Impl::SetState(this, core::mem::transmute_copy(&state)).into()
             }
         }
-        unsafe extern "system" fn CreateTime<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ptime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateTime<Identity: windows_core::IUnknownImpl + ITsSbSession_Impl>(this: *mut core::ffi::c_void, ptime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbSession_Impl::CreateTime(this) {
                     Ok(ok__) => {
                         ptime.write(core::mem::transmute(ok__));
@@ -5121,15 +7516,15 @@ impl ITsSbSession_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetCreateTime<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, time: super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetCreateTime<Identity: windows_core::IUnknownImpl + ITsSbSession_Impl>(this: *mut core::ffi::c_void, time: super::super::Foundation::FILETIME) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbSession_Impl::SetCreateTime(this, core::mem::transmute(&time)).into()
             }
         }
-        unsafe extern "system" fn DisconnectTime<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ptime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+        unsafe extern "system" fn DisconnectTime<Identity: windows_core::IUnknownImpl + ITsSbSession_Impl>(this: *mut core::ffi::c_void, ptime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbSession_Impl::DisconnectTime(this) {
                     Ok(ok__) => {
                         ptime.write(core::mem::transmute(ok__));
@@ -5139,15 +7534,15 @@ impl ITsSbSession_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetDisconnectTime<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, time: super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetDisconnectTime<Identity: windows_core::IUnknownImpl + ITsSbSession_Impl>(this: *mut core::ffi::c_void, time: super::super::Foundation::FILETIME) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbSession_Impl::SetDisconnectTime(this, core::mem::transmute(&time)).into()
             }
         }
-        unsafe extern "system" fn InitialProgram<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, app: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn InitialProgram<Identity: windows_core::IUnknownImpl + ITsSbSession_Impl>(this: *mut core::ffi::c_void, app: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbSession_Impl::InitialProgram(this) {
                     Ok(ok__) => {
                         app.write(core::mem::transmute(ok__));
@@ -5157,15 +7552,15 @@ impl ITsSbSession_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetInitialProgram<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, application: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetInitialProgram<Identity: windows_core::IUnknownImpl + ITsSbSession_Impl>(this: *mut core::ffi::c_void, application: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbSession_Impl::SetInitialProgram(this, core::mem::transmute(&application)).into()
             }
         }
-        unsafe extern "system" fn ClientDisplay<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pclientdisplay: *mut CLIENT_DISPLAY) -> windows_core::HRESULT {
+        unsafe extern "system" fn ClientDisplay<Identity: windows_core::IUnknownImpl + ITsSbSession_Impl>(this: *mut core::ffi::c_void, pclientdisplay: *mut CLIENT_DISPLAY) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbSession_Impl::ClientDisplay(this) {
                     Ok(ok__) => {
                         pclientdisplay.write(core::mem::transmute(ok__));
@@ -5175,15 +7570,15 @@ impl ITsSbSession_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetClientDisplay<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pclientdisplay: CLIENT_DISPLAY) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetClientDisplay<Identity: windows_core::IUnknownImpl + ITsSbSession_Impl>(this: *mut core::ffi::c_void, pclientdisplay: CLIENT_DISPLAY) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbSession_Impl::SetClientDisplay(this, core::mem::transmute(&pclientdisplay)).into()
             }
         }
-        unsafe extern "system" fn ProtocolType<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pval: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn ProtocolType<Identity: windows_core::IUnknownImpl + ITsSbSession_Impl>(this: *mut core::ffi::c_void, pval: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbSession_Impl::ProtocolType(this) {
                     Ok(ok__) => {
                         pval.write(core::mem::transmute(ok__));
@@ -5193,36 +7588,33 @@ impl ITsSbSession_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetProtocolType<Identity: ITsSbSession_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, val: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetProtocolType<Identity: windows_core::IUnknownImpl + ITsSbSession_Impl>(this: *mut core::ffi::c_void, val: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbSession_Impl::SetProtocolType(this, core::mem::transmute_copy(&val)).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            SessionId: SessionId::<Identity, OFFSET>,
-            TargetName: TargetName::<Identity, OFFSET>,
-            SetTargetName: SetTargetName::<Identity, OFFSET>,
-            Username: Username::<Identity, OFFSET>,
-            Domain: Domain::<Identity, OFFSET>,
-            State: State::<Identity, OFFSET>,
-            SetState: SetState::<Identity, OFFSET>,
-            CreateTime: CreateTime::<Identity, OFFSET>,
-            SetCreateTime: SetCreateTime::<Identity, OFFSET>,
-            DisconnectTime: DisconnectTime::<Identity, OFFSET>,
-            SetDisconnectTime: SetDisconnectTime::<Identity, OFFSET>,
-            InitialProgram: InitialProgram::<Identity, OFFSET>,
-            SetInitialProgram: SetInitialProgram::<Identity, OFFSET>,
-            ClientDisplay: ClientDisplay::<Identity, OFFSET>,
-            SetClientDisplay: SetClientDisplay::<Identity, OFFSET>,
-            ProtocolType: ProtocolType::<Identity, OFFSET>,
-            SetProtocolType: SetProtocolType::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ITsSbSession as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SessionId: SessionId::<Identity>,
+            TargetName: TargetName::<Identity>,
+            SetTargetName: SetTargetName::<Identity>,
+            Username: Username::<Identity>,
+            Domain: Domain::<Identity>,
+            State: State::<Identity>,
+            SetState: SetState::<Identity>,
+            CreateTime: CreateTime::<Identity>,
+            SetCreateTime: SetCreateTime::<Identity>,
+            DisconnectTime: DisconnectTime::<Identity>,
+            SetDisconnectTime: SetDisconnectTime::<Identity>,
+            InitialProgram: InitialProgram::<Identity>,
+            SetInitialProgram: SetInitialProgram::<Identity>,
+            ClientDisplay: ClientDisplay::<Identity>,
+            SetClientDisplay: SetClientDisplay::<Identity>,
+            ProtocolType: ProtocolType::<Identity>,
+            SetProtocolType: SetProtocolType::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for ITsSbSession {}
 windows_core::imp::define_interface!(ITsSbTarget, ITsSbTarget_Vtbl, 0x16616ecc_272d_411d_b324_126893033856);
@@ -5374,11 +7766,218 @@ pub trait ITsSbTarget_Impl: windows_core::IUnknownImpl {
     fn TargetLoad(&self) -> windows_core::Result<u32>;
 }
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
-impl ITsSbTarget_Vtbl {
-    pub const fn new<Identity: ITsSbTarget_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn TargetName<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl ITsSbTarget_Vtbl {
+    pub const fn new<Identity: ITsSbTarget_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn TargetName<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbTarget_Impl::TargetName(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTargetName<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, val: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbTarget_Impl::SetTargetName(this, core::mem::transmute(&val)).into()
+            }
+        }
+        unsafe extern "system" fn FarmName<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbTarget_Impl::FarmName(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetFarmName<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, val: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbTarget_Impl::SetFarmName(this, core::mem::transmute(&val)).into()
+            }
+        }
+        unsafe extern "system" fn TargetFQDN<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, targetfqdnname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbTarget_Impl::TargetFQDN(this) {
+                    Ok(ok__) => {
+                        targetfqdnname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTargetFQDN<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, val: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbTarget_Impl::SetTargetFQDN(this, core::mem::transmute(&val)).into()
+            }
+        }
+        unsafe extern "system" fn TargetNetbios<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, targetnetbiosname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbTarget_Impl::TargetNetbios(this) {
+                    Ok(ok__) => {
+                        targetnetbiosname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTargetNetbios<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, val: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbTarget_Impl::SetTargetNetbios(this, core::mem::transmute(&val)).into()
+            }
+        }
+        unsafe extern "system" fn get_IpAddresses<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, sockaddr: *mut TSSD_ConnectionPoint, numaddresses: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbTarget_Impl::get_IpAddresses(this, core::mem::transmute_copy(&sockaddr), core::mem::transmute_copy(&numaddresses)).into()
+            }
+        }
+        unsafe extern "system" fn put_IpAddresses<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, sockaddr: *const TSSD_ConnectionPoint, numaddresses: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbTarget_Impl::put_IpAddresses(this, core::mem::transmute_copy(&sockaddr), core::mem::transmute_copy(&numaddresses)).into()
+            }
+        }
+        unsafe extern "system" fn TargetState<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pstate: *mut TARGET_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbTarget_Impl::TargetState(this) {
+                    Ok(ok__) => {
+                        pstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTargetState<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, state: TARGET_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbTarget_Impl::SetTargetState(this, core::mem::transmute_copy(&state)).into()
+            }
+        }
+        unsafe extern "system" fn TargetPropertySet<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pppropertyset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbTarget_Impl::TargetPropertySet(this) {
+                    Ok(ok__) => {
+                        pppropertyset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTargetPropertySet<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbTarget_Impl::SetTargetPropertySet(this, core::mem::transmute_copy(&pval)).into()
+            }
+        }
+        unsafe extern "system" fn EnvironmentName<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbTarget_Impl::EnvironmentName(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetEnvironmentName<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, val: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITsSbTarget_Impl::SetEnvironmentName(this, core::mem::transmute(&val)).into()
+            }
+        }
+        unsafe extern "system" fn NumSessions<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pnumsessions: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbTarget_Impl::NumSessions(this) {
+                    Ok(ok__) => {
+                        pnumsessions.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NumPendingConnections<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pnumpendingconnections: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbTarget_Impl::NumPendingConnections(this) {
+                    Ok(ok__) => {
+                        pnumpendingconnections.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn TargetLoad<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ptargetload: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITsSbTarget_Impl::TargetLoad(this) {
+                    Ok(ok__) => {
+                        ptargetload.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            TargetName: TargetName::<Identity, OFFSET>,
+            SetTargetName: SetTargetName::<Identity, OFFSET>,
+            FarmName: FarmName::<Identity, OFFSET>,
+            SetFarmName: SetFarmName::<Identity, OFFSET>,
+            TargetFQDN: TargetFQDN::<Identity, OFFSET>,
+            SetTargetFQDN: SetTargetFQDN::<Identity, OFFSET>,
+            TargetNetbios: TargetNetbios::<Identity, OFFSET>,
+            SetTargetNetbios: SetTargetNetbios::<Identity, OFFSET>,
+            get_IpAddresses: get_IpAddresses::<Identity, OFFSET>,
+            put_IpAddresses: put_IpAddresses::<Identity, OFFSET>,
+            TargetState: TargetState::<Identity, OFFSET>,
+            SetTargetState: SetTargetState::<Identity, OFFSET>,
+            TargetPropertySet: TargetPropertySet::<Identity, OFFSET>,
+            SetTargetPropertySet: SetTargetPropertySet::<Identity, OFFSET>,
+            EnvironmentName: EnvironmentName::<Identity, OFFSET>,
+            SetEnvironmentName: SetEnvironmentName::<Identity, OFFSET>,
+            NumSessions: NumSessions::<Identity, OFFSET>,
+            NumPendingConnections: NumPendingConnections::<Identity, OFFSET>,
+            TargetLoad: TargetLoad::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbTarget as windows_core::Interface>::IID
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl<Identity: windows_core::IUnknownImpl + ITsSbTarget_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbTarget_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn TargetName<Identity: windows_core::IUnknownImpl + ITsSbTarget_Impl>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbTarget_Impl::TargetName(this) {
                     Ok(ok__) => {
                         pval.write(core::mem::transmute(ok__));
@@ -5388,15 +7987,15 @@ impl ITsSbTarget_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetTargetName<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, val: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetTargetName<Identity: windows_core::IUnknownImpl + ITsSbTarget_Impl>(this: *mut core::ffi::c_void, val: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbTarget_Impl::SetTargetName(this, core::mem::transmute(&val)).into()
             }
         }
-        unsafe extern "system" fn FarmName<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn FarmName<Identity: windows_core::IUnknownImpl + ITsSbTarget_Impl>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbTarget_Impl::FarmName(this) {
                     Ok(ok__) => {
                         pval.write(core::mem::transmute(ok__));
@@ -5406,15 +8005,15 @@ impl ITsSbTarget_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetFarmName<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, val: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetFarmName<Identity: windows_core::IUnknownImpl + ITsSbTarget_Impl>(this: *mut core::ffi::c_void, val: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbTarget_Impl::SetFarmName(this, core::mem::transmute(&val)).into()
             }
         }
-        unsafe extern "system" fn TargetFQDN<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, targetfqdnname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn TargetFQDN<Identity: windows_core::IUnknownImpl + ITsSbTarget_Impl>(this: *mut core::ffi::c_void, targetfqdnname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbTarget_Impl::TargetFQDN(this) {
                     Ok(ok__) => {
                         targetfqdnname.write(core::mem::transmute(ok__));
@@ -5424,15 +8023,15 @@ impl ITsSbTarget_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetTargetFQDN<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, val: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetTargetFQDN<Identity: windows_core::IUnknownImpl + ITsSbTarget_Impl>(this: *mut core::ffi::c_void, val: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbTarget_Impl::SetTargetFQDN(this, core::mem::transmute(&val)).into()
             }
         }
-        unsafe extern "system" fn TargetNetbios<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, targetnetbiosname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn TargetNetbios<Identity: windows_core::IUnknownImpl + ITsSbTarget_Impl>(this: *mut core::ffi::c_void, targetnetbiosname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbTarget_Impl::TargetNetbios(this) {
                     Ok(ok__) => {
                         targetnetbiosname.write(core::mem::transmute(ok__));
@@ -5442,27 +8041,27 @@ impl ITsSbTarget_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetTargetNetbios<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, val: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetTargetNetbios<Identity: windows_core::IUnknownImpl + ITsSbTarget_Impl>(this: *mut core::ffi::c_void, val: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbTarget_Impl::SetTargetNetbios(this, core::mem::transmute(&val)).into()
             }
         }
-        unsafe extern "system" fn get_IpAddresses<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, sockaddr: *mut TSSD_ConnectionPoint, numaddresses: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn get_IpAddresses<Identity: windows_core::IUnknownImpl + ITsSbTarget_Impl>(this: *mut core::ffi::c_void, sockaddr: *mut TSSD_ConnectionPoint, numaddresses: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbTarget_Impl::get_IpAddresses(this, core::mem::transmute_copy(&sockaddr), core::mem::transmute_copy(&numaddresses)).into()
             }
         }
-        unsafe extern "system" fn put_IpAddresses<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, sockaddr: *const TSSD_ConnectionPoint, numaddresses: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn put_IpAddresses<Identity: windows_core::IUnknownImpl + ITsSbTarget_Impl>(this: *mut core::ffi::c_void, sockaddr: *const TSSD_ConnectionPoint, numaddresses: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbTarget_Impl::put_IpAddresses(this, core::mem::transmute_copy(&sockaddr), core::mem::transmute_copy(&numaddresses)).into()
             }
         }
-        unsafe extern "system" fn TargetState<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pstate: *mut TARGET_STATE) -> windows_core::HRESULT {
+        unsafe extern "system" fn TargetState<Identity: windows_core::IUnknownImpl + ITsSbTarget_Impl>(this: *mut core::ffi::c_void, pstate: *mut TARGET_STATE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbTarget_Impl::TargetState(this) {
                     Ok(ok__) => {
                         pstate.write(core::mem::transmute(ok__));
@@ -5472,15 +8071,15 @@ impl ITsSbTarget_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetTargetState<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, state: TARGET_STATE) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetTargetState<Identity: windows_core::IUnknownImpl + ITsSbTarget_Impl>(this: *mut core::ffi::c_void, state: TARGET_STATE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbTarget_Impl::SetTargetState(this, core::mem::transmute_copy(&state)).into()
             }
         }
-        unsafe extern "system" fn TargetPropertySet<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pppropertyset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn TargetPropertySet<Identity: windows_core::IUnknownImpl + ITsSbTarget_Impl>(this: *mut core::ffi::c_void, pppropertyset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbTarget_Impl::TargetPropertySet(this) {
                     Ok(ok__) => {
                         pppropertyset.write(core::mem::transmute(ok__));
@@ -5490,15 +8089,15 @@ impl ITsSbTarget_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetTargetPropertySet<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetTargetPropertySet<Identity: windows_core::IUnknownImpl + ITsSbTarget_Impl>(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbTarget_Impl::SetTargetPropertySet(this, core::mem::transmute_copy(&pval)).into()
             }
         }
-        unsafe extern "system" fn EnvironmentName<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn EnvironmentName<Identity: windows_core::IUnknownImpl + ITsSbTarget_Impl>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbTarget_Impl::EnvironmentName(this) {
                     Ok(ok__) => {
                         pval.write(core::mem::transmute(ok__));
@@ -5508,15 +8107,15 @@ impl ITsSbTarget_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetEnvironmentName<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, val: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetEnvironmentName<Identity: windows_core::IUnknownImpl + ITsSbTarget_Impl>(this: *mut core::ffi::c_void, val: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITsSbTarget_Impl::SetEnvironmentName(this, core::mem::transmute(&val)).into()
             }
         }
-        unsafe extern "system" fn NumSessions<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pnumsessions: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn NumSessions<Identity: windows_core::IUnknownImpl + ITsSbTarget_Impl>(this: *mut core::ffi::c_void, pnumsessions: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbTarget_Impl::NumSessions(this) {
                     Ok(ok__) => {
                         pnumsessions.write(core::mem::transmute(ok__));
@@ -5526,9 +8125,9 @@ impl ITsSbTarget_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn NumPendingConnections<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pnumpendingconnections: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn NumPendingConnections<Identity: windows_core::IUnknownImpl + ITsSbTarget_Impl>(this: *mut core::ffi::c_void, pnumpendingconnections: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbTarget_Impl::NumPendingConnections(this) {
                     Ok(ok__) => {
                         pnumpendingconnections.write(core::mem::transmute(ok__));
@@ -5538,9 +8137,9 @@ impl ITsSbTarget_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn TargetLoad<Identity: ITsSbTarget_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ptargetload: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn TargetLoad<Identity: windows_core::IUnknownImpl + ITsSbTarget_Impl>(this: *mut core::ffi::c_void, ptargetload: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITsSbTarget_Impl::TargetLoad(this) {
                     Ok(ok__) => {
                         ptargetload.write(core::mem::transmute(ok__));
@@ -5551,31 +8150,28 @@ impl ITsSbTarget_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            TargetName: TargetName::<Identity, OFFSET>,
-            SetTargetName: SetTargetName::<Identity, OFFSET>,
-            FarmName: FarmName::<Identity, OFFSET>,
-            SetFarmName: SetFarmName::<Identity, OFFSET>,
-            TargetFQDN: TargetFQDN::<Identity, OFFSET>,
-            SetTargetFQDN: SetTargetFQDN::<Identity, OFFSET>,
-            TargetNetbios: TargetNetbios::<Identity, OFFSET>,
-            SetTargetNetbios: SetTargetNetbios::<Identity, OFFSET>,
-            get_IpAddresses: get_IpAddresses::<Identity, OFFSET>,
-            put_IpAddresses: put_IpAddresses::<Identity, OFFSET>,
-            TargetState: TargetState::<Identity, OFFSET>,
-            SetTargetState: SetTargetState::<Identity, OFFSET>,
-            TargetPropertySet: TargetPropertySet::<Identity, OFFSET>,
-            SetTargetPropertySet: SetTargetPropertySet::<Identity, OFFSET>,
-            EnvironmentName: EnvironmentName::<Identity, OFFSET>,
-            SetEnvironmentName: SetEnvironmentName::<Identity, OFFSET>,
-            NumSessions: NumSessions::<Identity, OFFSET>,
-            NumPendingConnections: NumPendingConnections::<Identity, OFFSET>,
-            TargetLoad: TargetLoad::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ITsSbTarget as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            TargetName: TargetName::<Identity>,
+            SetTargetName: SetTargetName::<Identity>,
+            FarmName: FarmName::<Identity>,
+            SetFarmName: SetFarmName::<Identity>,
+            TargetFQDN: TargetFQDN::<Identity>,
+            SetTargetFQDN: SetTargetFQDN::<Identity>,
+            TargetNetbios: TargetNetbios::<Identity>,
+            SetTargetNetbios: SetTargetNetbios::<Identity>,
+            get_IpAddresses: get_IpAddresses::<Identity>,
+            put_IpAddresses: put_IpAddresses::<Identity>,
+            TargetState: TargetState::<Identity>,
+            SetTargetState: SetTargetState::<Identity>,
+            TargetPropertySet: TargetPropertySet::<Identity>,
+            SetTargetPropertySet: SetTargetPropertySet::<Identity>,
+            EnvironmentName: EnvironmentName::<Identity>,
+            SetEnvironmentName: SetEnvironmentName::<Identity>,
+            NumSessions: NumSessions::<Identity>,
+            NumPendingConnections: NumPendingConnections::<Identity>,
+            TargetLoad: TargetLoad::<Identity>,
+        }
+    };
 }
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
 impl windows_core::RuntimeName for ITsSbTarget {}
@@ -5608,6 +8204,13 @@ impl ITsSbTargetPropertySet_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ITsSbTargetPropertySet_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbTargetPropertySet_Vtbl
+where
+    ITsSbPropertySet_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <ITsSbPropertySet_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ITsSbTargetPropertySet {}
 windows_core::imp::define_interface!(ITsSbTaskInfo, ITsSbTaskInfo_Vtbl, 0x523d1083_89be_48dd_99ea_04e82ffa7265);
 windows_core::imp::interface_hierarchy!(ITsSbTaskInfo, windows_core::IUnknown);
@@ -5826,6 +8429,134 @@ impl ITsSbTaskInfo_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + ITsSbTaskInfo_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbTaskInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn TargetId<Identity: windows_core::IUnknownImpl + ITsSbTaskInfo_Impl>(this: *mut core::ffi::c_void, pname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbTaskInfo_Impl::TargetId(this) {
+                    Ok(ok__) => {
+                        pname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StartTime<Identity: windows_core::IUnknownImpl + ITsSbTaskInfo_Impl>(this: *mut core::ffi::c_void, pstarttime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbTaskInfo_Impl::StartTime(this) {
+                    Ok(ok__) => {
+                        pstarttime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EndTime<Identity: windows_core::IUnknownImpl + ITsSbTaskInfo_Impl>(this: *mut core::ffi::c_void, pendtime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbTaskInfo_Impl::EndTime(this) {
+                    Ok(ok__) => {
+                        pendtime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Deadline<Identity: windows_core::IUnknownImpl + ITsSbTaskInfo_Impl>(this: *mut core::ffi::c_void, pdeadline: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbTaskInfo_Impl::Deadline(this) {
+                    Ok(ok__) => {
+                        pdeadline.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Identifier<Identity: windows_core::IUnknownImpl + ITsSbTaskInfo_Impl>(this: *mut core::ffi::c_void, pidentifier: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbTaskInfo_Impl::Identifier(this) {
+                    Ok(ok__) => {
+                        pidentifier.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Label<Identity: windows_core::IUnknownImpl + ITsSbTaskInfo_Impl>(this: *mut core::ffi::c_void, plabel: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbTaskInfo_Impl::Label(this) {
+                    Ok(ok__) => {
+                        plabel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Context<Identity: windows_core::IUnknownImpl + ITsSbTaskInfo_Impl>(this: *mut core::ffi::c_void, pcontext: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbTaskInfo_Impl::Context(this) {
+                    Ok(ok__) => {
+                        pcontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Plugin<Identity: windows_core::IUnknownImpl + ITsSbTaskInfo_Impl>(this: *mut core::ffi::c_void, pplugin: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbTaskInfo_Impl::Plugin(this) {
+                    Ok(ok__) => {
+                        pplugin.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Status<Identity: windows_core::IUnknownImpl + ITsSbTaskInfo_Impl>(this: *mut core::ffi::c_void, pstatus: *mut RDV_TASK_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITsSbTaskInfo_Impl::Status(this) {
+                    Ok(ok__) => {
+                        pstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            TargetId: TargetId::<Identity>,
+            StartTime: StartTime::<Identity>,
+            EndTime: EndTime::<Identity>,
+            Deadline: Deadline::<Identity>,
+            Identifier: Identifier::<Identity>,
+            Label: Label::<Identity>,
+            Context: Context::<Identity>,
+            Plugin: Plugin::<Identity>,
+            Status: Status::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for ITsSbTaskInfo {}
 windows_core::imp::define_interface!(ITsSbTaskPlugin, ITsSbTaskPlugin_Vtbl, 0xfa22ef0f_8705_41be_93bc_44bdbcf1c9c4);
 impl core::ops::Deref for ITsSbTaskPlugin {
@@ -5884,6 +8615,31 @@ impl ITsSbTaskPlugin_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl<Identity: windows_core::IUnknownImpl + ITsSbTaskPlugin_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbTaskPlugin_Vtbl
+where
+    ITsSbPlugin_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InitializeTaskPlugin<Identity: windows_core::IUnknownImpl + ITsSbTaskPlugin_Impl>(this: *mut core::ffi::c_void, pitssbtaskpluginnotifysink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbTaskPlugin_Impl::InitializeTaskPlugin(this, core::mem::transmute_copy(&pitssbtaskpluginnotifysink)).into()
+            }
+        }
+        unsafe extern "system" fn SetTaskQueue<Identity: windows_core::IUnknownImpl + ITsSbTaskPlugin_Impl>(this: *mut core::ffi::c_void, pszhostname: *mut core::ffi::c_void, sbtaskinfosize: u32, pitssbtaskinfo: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbTaskPlugin_Impl::SetTaskQueue(this, core::mem::transmute(&pszhostname), core::mem::transmute_copy(&sbtaskinfosize), core::mem::transmute_copy(&pitssbtaskinfo)).into()
+            }
+        }
+        Self {
+            base__: <ITsSbPlugin_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InitializeTaskPlugin: InitializeTaskPlugin::<Identity>,
+            SetTaskQueue: SetTaskQueue::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
 impl windows_core::RuntimeName for ITsSbTaskPlugin {}
 windows_core::imp::define_interface!(ITsSbTaskPluginNotifySink, ITsSbTaskPluginNotifySink_Vtbl, 0x6aaf899e_c2ec_45ee_aa37_45e60895261a);
 impl core::ops::Deref for ITsSbTaskPluginNotifySink {
@@ -5967,6 +8723,45 @@ impl ITsSbTaskPluginNotifySink_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + ITsSbTaskPluginNotifySink_Impl> windows_core::imp::TearOffVtable<Identity> for ITsSbTaskPluginNotifySink_Vtbl
+where
+    ITsSbBaseNotifySink_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnSetTaskTime<Identity: windows_core::IUnknownImpl + ITsSbTaskPluginNotifySink_Impl>(this: *mut core::ffi::c_void, sztargetname: *mut core::ffi::c_void, taskstarttime: super::super::Foundation::FILETIME, taskendtime: super::super::Foundation::FILETIME, taskdeadline: super::super::Foundation::FILETIME, sztasklabel: *mut core::ffi::c_void, sztaskidentifier: *mut core::ffi::c_void, sztaskplugin: *mut core::ffi::c_void, dwtaskstatus: u32, sacontext: *const super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbTaskPluginNotifySink_Impl::OnSetTaskTime(this, core::mem::transmute(&sztargetname), core::mem::transmute(&taskstarttime), core::mem::transmute(&taskendtime), core::mem::transmute(&taskdeadline), core::mem::transmute(&sztasklabel), core::mem::transmute(&sztaskidentifier), core::mem::transmute(&sztaskplugin), core::mem::transmute_copy(&dwtaskstatus), core::mem::transmute_copy(&sacontext)).into()
+            }
+        }
+        unsafe extern "system" fn OnDeleteTaskTime<Identity: windows_core::IUnknownImpl + ITsSbTaskPluginNotifySink_Impl>(this: *mut core::ffi::c_void, sztargetname: *mut core::ffi::c_void, sztaskidentifier: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbTaskPluginNotifySink_Impl::OnDeleteTaskTime(this, core::mem::transmute(&sztargetname), core::mem::transmute(&sztaskidentifier)).into()
+            }
+        }
+        unsafe extern "system" fn OnUpdateTaskStatus<Identity: windows_core::IUnknownImpl + ITsSbTaskPluginNotifySink_Impl>(this: *mut core::ffi::c_void, sztargetname: *mut core::ffi::c_void, taskidentifier: *mut core::ffi::c_void, taskstatus: RDV_TASK_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbTaskPluginNotifySink_Impl::OnUpdateTaskStatus(this, core::mem::transmute(&sztargetname), core::mem::transmute(&taskidentifier), core::mem::transmute_copy(&taskstatus)).into()
+            }
+        }
+        unsafe extern "system" fn OnReportTasks<Identity: windows_core::IUnknownImpl + ITsSbTaskPluginNotifySink_Impl>(this: *mut core::ffi::c_void, szhostname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITsSbTaskPluginNotifySink_Impl::OnReportTasks(this, core::mem::transmute(&szhostname)).into()
+            }
+        }
+        Self {
+            base__: <ITsSbBaseNotifySink_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnSetTaskTime: OnSetTaskTime::<Identity>,
+            OnDeleteTaskTime: OnDeleteTaskTime::<Identity>,
+            OnUpdateTaskStatus: OnUpdateTaskStatus::<Identity>,
+            OnReportTasks: OnReportTasks::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for ITsSbTaskPluginNotifySink {}
 windows_core::imp::define_interface!(IWRdsEnhancedFastReconnectArbitrator, IWRdsEnhancedFastReconnectArbitrator_Vtbl, 0x5718ae9b_47f2_499f_b634_d8175bd51131);
 windows_core::imp::interface_hierarchy!(IWRdsEnhancedFastReconnectArbitrator, windows_core::IUnknown);
@@ -5991,7 +8786,33 @@ impl IWRdsEnhancedFastReconnectArbitrator_Vtbl {
     pub const fn new<Identity: IWRdsEnhancedFastReconnectArbitrator_Impl, const OFFSET: isize>() -> Self {
         unsafe extern "system" fn GetSessionForEnhancedFastReconnect<Identity: IWRdsEnhancedFastReconnectArbitrator_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, psessionidarray: *const i32, dwsessioncount: u32, presultsessionid: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWRdsEnhancedFastReconnectArbitrator_Impl::GetSessionForEnhancedFastReconnect(this, core::mem::transmute_copy(&psessionidarray), core::mem::transmute_copy(&dwsessioncount)) {
+                    Ok(ok__) => {
+                        presultsessionid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            GetSessionForEnhancedFastReconnect: GetSessionForEnhancedFastReconnect::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWRdsEnhancedFastReconnectArbitrator as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IWRdsEnhancedFastReconnectArbitrator_Impl> windows_core::imp::TearOffVtable<Identity> for IWRdsEnhancedFastReconnectArbitrator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSessionForEnhancedFastReconnect<Identity: windows_core::IUnknownImpl + IWRdsEnhancedFastReconnectArbitrator_Impl>(this: *mut core::ffi::c_void, psessionidarray: *const i32, dwsessioncount: u32, presultsessionid: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWRdsEnhancedFastReconnectArbitrator_Impl::GetSessionForEnhancedFastReconnect(this, core::mem::transmute_copy(&psessionidarray), core::mem::transmute_copy(&dwsessioncount)) {
                     Ok(ok__) => {
                         presultsessionid.write(core::mem::transmute(ok__));
@@ -6002,13 +8823,10 @@ impl IWRdsEnhancedFastReconnectArbitrator_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            GetSessionForEnhancedFastReconnect: GetSessionForEnhancedFastReconnect::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSessionForEnhancedFastReconnect: GetSessionForEnhancedFastReconnect::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWRdsEnhancedFastReconnectArbitrator as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IWRdsEnhancedFastReconnectArbitrator {}
 windows_core::imp::define_interface!(IWRdsGraphicsChannel, IWRdsGraphicsChannel_Vtbl, 0x684b7a0b_edff_43ad_d5a2_4a8d5388f401);
@@ -6075,6 +8893,37 @@ impl IWRdsGraphicsChannel_Vtbl {
         iid == &<IWRdsGraphicsChannel as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWRdsGraphicsChannel_Impl> windows_core::imp::TearOffVtable<Identity> for IWRdsGraphicsChannel_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Write<Identity: windows_core::IUnknownImpl + IWRdsGraphicsChannel_Impl>(this: *mut core::ffi::c_void, cbsize: u32, pbuffer: *const u8, pcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsGraphicsChannel_Impl::Write(this, core::mem::transmute_copy(&cbsize), core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&pcontext)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IWRdsGraphicsChannel_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsGraphicsChannel_Impl::Close(this).into()
+            }
+        }
+        unsafe extern "system" fn Open<Identity: windows_core::IUnknownImpl + IWRdsGraphicsChannel_Impl>(this: *mut core::ffi::c_void, pchannelevents: *mut core::ffi::c_void, popencontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsGraphicsChannel_Impl::Open(this, core::mem::transmute_copy(&pchannelevents), core::mem::transmute_copy(&popencontext)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Write: Write::<Identity>,
+            Close: Close::<Identity>,
+            Open: Open::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWRdsGraphicsChannel {}
 windows_core::imp::define_interface!(IWRdsGraphicsChannelEvents, IWRdsGraphicsChannelEvents_Vtbl, 0x67f2368c_d674_4fae_66a5_d20628a640d2);
 windows_core::imp::interface_hierarchy!(IWRdsGraphicsChannelEvents, windows_core::IUnknown);
@@ -6163,6 +9012,51 @@ impl IWRdsGraphicsChannelEvents_Vtbl {
         iid == &<IWRdsGraphicsChannelEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWRdsGraphicsChannelEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IWRdsGraphicsChannelEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnDataReceived<Identity: windows_core::IUnknownImpl + IWRdsGraphicsChannelEvents_Impl>(this: *mut core::ffi::c_void, cbsize: u32, pbuffer: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsGraphicsChannelEvents_Impl::OnDataReceived(this, core::mem::transmute_copy(&cbsize), core::mem::transmute_copy(&pbuffer)).into()
+            }
+        }
+        unsafe extern "system" fn OnClose<Identity: windows_core::IUnknownImpl + IWRdsGraphicsChannelEvents_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsGraphicsChannelEvents_Impl::OnClose(this).into()
+            }
+        }
+        unsafe extern "system" fn OnChannelOpened<Identity: windows_core::IUnknownImpl + IWRdsGraphicsChannelEvents_Impl>(this: *mut core::ffi::c_void, openresult: windows_core::HRESULT, popencontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsGraphicsChannelEvents_Impl::OnChannelOpened(this, core::mem::transmute_copy(&openresult), core::mem::transmute_copy(&popencontext)).into()
+            }
+        }
+        unsafe extern "system" fn OnDataSent<Identity: windows_core::IUnknownImpl + IWRdsGraphicsChannelEvents_Impl>(this: *mut core::ffi::c_void, pwritecontext: *mut core::ffi::c_void, bcancelled: windows_core::BOOL, pbuffer: *const u8, cbbuffer: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsGraphicsChannelEvents_Impl::OnDataSent(this, core::mem::transmute_copy(&pwritecontext), core::mem::transmute_copy(&bcancelled), core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&cbbuffer)).into()
+            }
+        }
+        unsafe extern "system" fn OnMetricsUpdate<Identity: windows_core::IUnknownImpl + IWRdsGraphicsChannelEvents_Impl>(this: *mut core::ffi::c_void, bandwidth: u32, rtt: u32, lastsentbyteindex: u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsGraphicsChannelEvents_Impl::OnMetricsUpdate(this, core::mem::transmute_copy(&bandwidth), core::mem::transmute_copy(&rtt), core::mem::transmute_copy(&lastsentbyteindex)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnDataReceived: OnDataReceived::<Identity>,
+            OnClose: OnClose::<Identity>,
+            OnChannelOpened: OnChannelOpened::<Identity>,
+            OnDataSent: OnDataSent::<Identity>,
+            OnMetricsUpdate: OnMetricsUpdate::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWRdsGraphicsChannelEvents {}
 windows_core::imp::define_interface!(IWRdsGraphicsChannelManager, IWRdsGraphicsChannelManager_Vtbl, 0x0fd57159_e83e_476a_a8b9_4a7976e71e18);
 windows_core::imp::interface_hierarchy!(IWRdsGraphicsChannelManager, windows_core::IUnknown);
@@ -6203,6 +9097,26 @@ impl IWRdsGraphicsChannelManager_Vtbl {
         iid == &<IWRdsGraphicsChannelManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWRdsGraphicsChannelManager_Impl> windows_core::imp::TearOffVtable<Identity> for IWRdsGraphicsChannelManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateChannel<Identity: windows_core::IUnknownImpl + IWRdsGraphicsChannelManager_Impl>(this: *mut core::ffi::c_void, pszchannelname: *const u8, channeltype: WRdsGraphicsChannelType, ppvirtualchannel: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWRdsGraphicsChannelManager_Impl::CreateChannel(this, core::mem::transmute_copy(&pszchannelname), core::mem::transmute_copy(&channeltype)) {
+                    Ok(ok__) => {
+                        ppvirtualchannel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateChannel: CreateChannel::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWRdsGraphicsChannelManager {}
 windows_core::imp::define_interface!(IWRdsProtocolConnection, IWRdsProtocolConnection_Vtbl, 0x324ed94f_fdaf_4ff6_81a8_42abe755830b);
 windows_core::imp::interface_hierarchy!(IWRdsProtocolConnection, windows_core::IUnknown);
@@ -6364,11 +9278,228 @@ pub trait IWRdsProtocolConnection_Impl: windows_core::IUnknownImpl {
     fn GetShadowConnection(&self) -> windows_core::Result<IWRdsProtocolShadowConnection>;
     fn NotifyCommandProcessCreated(&self, sessionid: u32) -> windows_core::Result<()>;
 }
-impl IWRdsProtocolConnection_Vtbl {
-    pub const fn new<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn GetLogonErrorRedirector<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pplogonerrorredir: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl IWRdsProtocolConnection_Vtbl {
+    pub const fn new<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn GetLogonErrorRedirector<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pplogonerrorredir: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWRdsProtocolConnection_Impl::GetLogonErrorRedirector(this) {
+                    Ok(ok__) => {
+                        pplogonerrorredir.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AcceptConnection<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWRdsProtocolConnection_Impl::AcceptConnection(this).into()
+            }
+        }
+        unsafe extern "system" fn GetClientData<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pclientdata: *mut WTS_CLIENT_DATA) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWRdsProtocolConnection_Impl::GetClientData(this, core::mem::transmute_copy(&pclientdata)).into()
+            }
+        }
+        unsafe extern "system" fn GetClientMonitorData<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pnummonitors: *mut u32, pprimarymonitor: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWRdsProtocolConnection_Impl::GetClientMonitorData(this, core::mem::transmute_copy(&pnummonitors), core::mem::transmute_copy(&pprimarymonitor)).into()
+            }
+        }
+        unsafe extern "system" fn GetUserCredentials<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pusercreds: *mut WTS_USER_CREDENTIAL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWRdsProtocolConnection_Impl::GetUserCredentials(this, core::mem::transmute_copy(&pusercreds)).into()
+            }
+        }
+        unsafe extern "system" fn GetLicenseConnection<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pplicenseconnection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWRdsProtocolConnection_Impl::GetLicenseConnection(this) {
+                    Ok(ok__) => {
+                        pplicenseconnection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AuthenticateClientToSession<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, sessionid: *mut WTS_SESSION_ID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWRdsProtocolConnection_Impl::AuthenticateClientToSession(this, core::mem::transmute_copy(&sessionid)).into()
+            }
+        }
+        unsafe extern "system" fn NotifySessionId<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, sessionid: *const WTS_SESSION_ID, sessionhandle: super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWRdsProtocolConnection_Impl::NotifySessionId(this, core::mem::transmute_copy(&sessionid), core::mem::transmute_copy(&sessionhandle)).into()
+            }
+        }
+        unsafe extern "system" fn GetInputHandles<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pkeyboardhandle: *mut super::super::Foundation::HANDLE_PTR, pmousehandle: *mut super::super::Foundation::HANDLE_PTR, pbeephandle: *mut super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWRdsProtocolConnection_Impl::GetInputHandles(this, core::mem::transmute_copy(&pkeyboardhandle), core::mem::transmute_copy(&pmousehandle), core::mem::transmute_copy(&pbeephandle)).into()
+            }
+        }
+        unsafe extern "system" fn GetVideoHandle<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pvideohandle: *mut super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWRdsProtocolConnection_Impl::GetVideoHandle(this) {
+                    Ok(ok__) => {
+                        pvideohandle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ConnectNotify<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, sessionid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWRdsProtocolConnection_Impl::ConnectNotify(this, core::mem::transmute_copy(&sessionid)).into()
+            }
+        }
+        unsafe extern "system" fn IsUserAllowedToLogon<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, sessionid: u32, usertoken: super::super::Foundation::HANDLE_PTR, pdomainname: windows_core::PCWSTR, pusername: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWRdsProtocolConnection_Impl::IsUserAllowedToLogon(this, core::mem::transmute_copy(&sessionid), core::mem::transmute_copy(&usertoken), core::mem::transmute(&pdomainname), core::mem::transmute(&pusername)).into()
+            }
+        }
+        unsafe extern "system" fn SessionArbitrationEnumeration<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, husertoken: super::super::Foundation::HANDLE_PTR, bsinglesessionperuserenabled: windows_core::BOOL, psessionidarray: *mut u32, pdwsessionidentifiercount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWRdsProtocolConnection_Impl::SessionArbitrationEnumeration(this, core::mem::transmute_copy(&husertoken), core::mem::transmute_copy(&bsinglesessionperuserenabled), core::mem::transmute_copy(&psessionidarray), core::mem::transmute_copy(&pdwsessionidentifiercount)).into()
+            }
+        }
+        unsafe extern "system" fn LogonNotify<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hclienttoken: super::super::Foundation::HANDLE_PTR, wszusername: windows_core::PCWSTR, wszdomainname: windows_core::PCWSTR, sessionid: *const WTS_SESSION_ID, pwrdsconnectionsettings: *mut WRDS_CONNECTION_SETTINGS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWRdsProtocolConnection_Impl::LogonNotify(this, core::mem::transmute_copy(&hclienttoken), core::mem::transmute(&wszusername), core::mem::transmute(&wszdomainname), core::mem::transmute_copy(&sessionid), core::mem::transmute_copy(&pwrdsconnectionsettings)).into()
+            }
+        }
+        unsafe extern "system" fn PreDisconnect<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, disconnectreason: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWRdsProtocolConnection_Impl::PreDisconnect(this, core::mem::transmute_copy(&disconnectreason)).into()
+            }
+        }
+        unsafe extern "system" fn DisconnectNotify<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWRdsProtocolConnection_Impl::DisconnectNotify(this).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWRdsProtocolConnection_Impl::Close(this).into()
+            }
+        }
+        unsafe extern "system" fn GetProtocolStatus<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pprotocolstatus: *mut WTS_PROTOCOL_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWRdsProtocolConnection_Impl::GetProtocolStatus(this, core::mem::transmute_copy(&pprotocolstatus)).into()
+            }
+        }
+        unsafe extern "system" fn GetLastInputTime<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, plastinputtime: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWRdsProtocolConnection_Impl::GetLastInputTime(this) {
+                    Ok(ok__) => {
+                        plastinputtime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetErrorInfo<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ulerror: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWRdsProtocolConnection_Impl::SetErrorInfo(this, core::mem::transmute_copy(&ulerror)).into()
+            }
+        }
+        unsafe extern "system" fn CreateVirtualChannel<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, szendpointname: windows_core::PCSTR, bstatic: windows_core::BOOL, requestedpriority: u32, phchannel: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWRdsProtocolConnection_Impl::CreateVirtualChannel(this, core::mem::transmute(&szendpointname), core::mem::transmute_copy(&bstatic), core::mem::transmute_copy(&requestedpriority)) {
+                    Ok(ok__) => {
+                        phchannel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryProperty<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, querytype: windows_core::GUID, ulnumentriesin: u32, ulnumentriesout: u32, ppropertyentriesin: *const WTS_PROPERTY_VALUE, ppropertyentriesout: *mut WTS_PROPERTY_VALUE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWRdsProtocolConnection_Impl::QueryProperty(this, core::mem::transmute(&querytype), core::mem::transmute_copy(&ulnumentriesin), core::mem::transmute_copy(&ulnumentriesout), core::mem::transmute_copy(&ppropertyentriesin), core::mem::transmute_copy(&ppropertyentriesout)).into()
+            }
+        }
+        unsafe extern "system" fn GetShadowConnection<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppshadowconnection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWRdsProtocolConnection_Impl::GetShadowConnection(this) {
+                    Ok(ok__) => {
+                        ppshadowconnection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NotifyCommandProcessCreated<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, sessionid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWRdsProtocolConnection_Impl::NotifyCommandProcessCreated(this, core::mem::transmute_copy(&sessionid)).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            GetLogonErrorRedirector: GetLogonErrorRedirector::<Identity, OFFSET>,
+            AcceptConnection: AcceptConnection::<Identity, OFFSET>,
+            GetClientData: GetClientData::<Identity, OFFSET>,
+            GetClientMonitorData: GetClientMonitorData::<Identity, OFFSET>,
+            GetUserCredentials: GetUserCredentials::<Identity, OFFSET>,
+            GetLicenseConnection: GetLicenseConnection::<Identity, OFFSET>,
+            AuthenticateClientToSession: AuthenticateClientToSession::<Identity, OFFSET>,
+            NotifySessionId: NotifySessionId::<Identity, OFFSET>,
+            GetInputHandles: GetInputHandles::<Identity, OFFSET>,
+            GetVideoHandle: GetVideoHandle::<Identity, OFFSET>,
+            ConnectNotify: ConnectNotify::<Identity, OFFSET>,
+            IsUserAllowedToLogon: IsUserAllowedToLogon::<Identity, OFFSET>,
+            SessionArbitrationEnumeration: SessionArbitrationEnumeration::<Identity, OFFSET>,
+            LogonNotify: LogonNotify::<Identity, OFFSET>,
+            PreDisconnect: PreDisconnect::<Identity, OFFSET>,
+            DisconnectNotify: DisconnectNotify::<Identity, OFFSET>,
+            Close: Close::<Identity, OFFSET>,
+            GetProtocolStatus: GetProtocolStatus::<Identity, OFFSET>,
+            GetLastInputTime: GetLastInputTime::<Identity, OFFSET>,
+            SetErrorInfo: SetErrorInfo::<Identity, OFFSET>,
+            CreateVirtualChannel: CreateVirtualChannel::<Identity, OFFSET>,
+            QueryProperty: QueryProperty::<Identity, OFFSET>,
+            GetShadowConnection: GetShadowConnection::<Identity, OFFSET>,
+            NotifyCommandProcessCreated: NotifyCommandProcessCreated::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWRdsProtocolConnection as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl> windows_core::imp::TearOffVtable<Identity> for IWRdsProtocolConnection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetLogonErrorRedirector<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void, pplogonerrorredir: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWRdsProtocolConnection_Impl::GetLogonErrorRedirector(this) {
                     Ok(ok__) => {
                         pplogonerrorredir.write(core::mem::transmute(ok__));
@@ -6378,33 +9509,33 @@ impl IWRdsProtocolConnection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn AcceptConnection<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn AcceptConnection<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWRdsProtocolConnection_Impl::AcceptConnection(this).into()
             }
         }
-        unsafe extern "system" fn GetClientData<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pclientdata: *mut WTS_CLIENT_DATA) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetClientData<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void, pclientdata: *mut WTS_CLIENT_DATA) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWRdsProtocolConnection_Impl::GetClientData(this, core::mem::transmute_copy(&pclientdata)).into()
             }
         }
-        unsafe extern "system" fn GetClientMonitorData<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pnummonitors: *mut u32, pprimarymonitor: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetClientMonitorData<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void, pnummonitors: *mut u32, pprimarymonitor: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWRdsProtocolConnection_Impl::GetClientMonitorData(this, core::mem::transmute_copy(&pnummonitors), core::mem::transmute_copy(&pprimarymonitor)).into()
             }
         }
-        unsafe extern "system" fn GetUserCredentials<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pusercreds: *mut WTS_USER_CREDENTIAL) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetUserCredentials<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void, pusercreds: *mut WTS_USER_CREDENTIAL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWRdsProtocolConnection_Impl::GetUserCredentials(this, core::mem::transmute_copy(&pusercreds)).into()
             }
         }
-        unsafe extern "system" fn GetLicenseConnection<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pplicenseconnection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetLicenseConnection<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void, pplicenseconnection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWRdsProtocolConnection_Impl::GetLicenseConnection(this) {
                     Ok(ok__) => {
                         pplicenseconnection.write(core::mem::transmute(ok__));
@@ -6414,27 +9545,27 @@ impl IWRdsProtocolConnection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn AuthenticateClientToSession<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, sessionid: *mut WTS_SESSION_ID) -> windows_core::HRESULT {
+        unsafe extern "system" fn AuthenticateClientToSession<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void, sessionid: *mut WTS_SESSION_ID) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWRdsProtocolConnection_Impl::AuthenticateClientToSession(this, core::mem::transmute_copy(&sessionid)).into()
             }
         }
-        unsafe extern "system" fn NotifySessionId<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, sessionid: *const WTS_SESSION_ID, sessionhandle: super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn NotifySessionId<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void, sessionid: *const WTS_SESSION_ID, sessionhandle: super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWRdsProtocolConnection_Impl::NotifySessionId(this, core::mem::transmute_copy(&sessionid), core::mem::transmute_copy(&sessionhandle)).into()
             }
         }
-        unsafe extern "system" fn GetInputHandles<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pkeyboardhandle: *mut super::super::Foundation::HANDLE_PTR, pmousehandle: *mut super::super::Foundation::HANDLE_PTR, pbeephandle: *mut super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetInputHandles<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void, pkeyboardhandle: *mut super::super::Foundation::HANDLE_PTR, pmousehandle: *mut super::super::Foundation::HANDLE_PTR, pbeephandle: *mut super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWRdsProtocolConnection_Impl::GetInputHandles(this, core::mem::transmute_copy(&pkeyboardhandle), core::mem::transmute_copy(&pmousehandle), core::mem::transmute_copy(&pbeephandle)).into()
             }
         }
-        unsafe extern "system" fn GetVideoHandle<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pvideohandle: *mut super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetVideoHandle<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void, pvideohandle: *mut super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWRdsProtocolConnection_Impl::GetVideoHandle(this) {
                     Ok(ok__) => {
                         pvideohandle.write(core::mem::transmute(ok__));
@@ -6444,57 +9575,57 @@ impl IWRdsProtocolConnection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ConnectNotify<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, sessionid: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn ConnectNotify<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void, sessionid: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWRdsProtocolConnection_Impl::ConnectNotify(this, core::mem::transmute_copy(&sessionid)).into()
             }
         }
-        unsafe extern "system" fn IsUserAllowedToLogon<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, sessionid: u32, usertoken: super::super::Foundation::HANDLE_PTR, pdomainname: windows_core::PCWSTR, pusername: windows_core::PCWSTR) -> windows_core::HRESULT {
+        unsafe extern "system" fn IsUserAllowedToLogon<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void, sessionid: u32, usertoken: super::super::Foundation::HANDLE_PTR, pdomainname: windows_core::PCWSTR, pusername: windows_core::PCWSTR) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWRdsProtocolConnection_Impl::IsUserAllowedToLogon(this, core::mem::transmute_copy(&sessionid), core::mem::transmute_copy(&usertoken), core::mem::transmute(&pdomainname), core::mem::transmute(&pusername)).into()
             }
         }
-        unsafe extern "system" fn SessionArbitrationEnumeration<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, husertoken: super::super::Foundation::HANDLE_PTR, bsinglesessionperuserenabled: windows_core::BOOL, psessionidarray: *mut u32, pdwsessionidentifiercount: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SessionArbitrationEnumeration<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void, husertoken: super::super::Foundation::HANDLE_PTR, bsinglesessionperuserenabled: windows_core::BOOL, psessionidarray: *mut u32, pdwsessionidentifiercount: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWRdsProtocolConnection_Impl::SessionArbitrationEnumeration(this, core::mem::transmute_copy(&husertoken), core::mem::transmute_copy(&bsinglesessionperuserenabled), core::mem::transmute_copy(&psessionidarray), core::mem::transmute_copy(&pdwsessionidentifiercount)).into()
             }
         }
-        unsafe extern "system" fn LogonNotify<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, hclienttoken: super::super::Foundation::HANDLE_PTR, wszusername: windows_core::PCWSTR, wszdomainname: windows_core::PCWSTR, sessionid: *const WTS_SESSION_ID, pwrdsconnectionsettings: *mut WRDS_CONNECTION_SETTINGS) -> windows_core::HRESULT {
+        unsafe extern "system" fn LogonNotify<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void, hclienttoken: super::super::Foundation::HANDLE_PTR, wszusername: windows_core::PCWSTR, wszdomainname: windows_core::PCWSTR, sessionid: *const WTS_SESSION_ID, pwrdsconnectionsettings: *mut WRDS_CONNECTION_SETTINGS) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWRdsProtocolConnection_Impl::LogonNotify(this, core::mem::transmute_copy(&hclienttoken), core::mem::transmute(&wszusername), core::mem::transmute(&wszdomainname), core::mem::transmute_copy(&sessionid), core::mem::transmute_copy(&pwrdsconnectionsettings)).into()
             }
         }
-        unsafe extern "system" fn PreDisconnect<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, disconnectreason: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn PreDisconnect<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void, disconnectreason: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWRdsProtocolConnection_Impl::PreDisconnect(this, core::mem::transmute_copy(&disconnectreason)).into()
             }
         }
-        unsafe extern "system" fn DisconnectNotify<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn DisconnectNotify<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWRdsProtocolConnection_Impl::DisconnectNotify(this).into()
             }
         }
-        unsafe extern "system" fn Close<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWRdsProtocolConnection_Impl::Close(this).into()
             }
         }
-        unsafe extern "system" fn GetProtocolStatus<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pprotocolstatus: *mut WTS_PROTOCOL_STATUS) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetProtocolStatus<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void, pprotocolstatus: *mut WTS_PROTOCOL_STATUS) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWRdsProtocolConnection_Impl::GetProtocolStatus(this, core::mem::transmute_copy(&pprotocolstatus)).into()
             }
         }
-        unsafe extern "system" fn GetLastInputTime<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, plastinputtime: *mut u64) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetLastInputTime<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void, plastinputtime: *mut u64) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWRdsProtocolConnection_Impl::GetLastInputTime(this) {
                     Ok(ok__) => {
                         plastinputtime.write(core::mem::transmute(ok__));
@@ -6504,15 +9635,15 @@ impl IWRdsProtocolConnection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetErrorInfo<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ulerror: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetErrorInfo<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void, ulerror: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWRdsProtocolConnection_Impl::SetErrorInfo(this, core::mem::transmute_copy(&ulerror)).into()
             }
         }
-        unsafe extern "system" fn CreateVirtualChannel<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, szendpointname: windows_core::PCSTR, bstatic: windows_core::BOOL, requestedpriority: u32, phchannel: *mut usize) -> windows_core::HRESULT {
+        unsafe extern "system" fn CreateVirtualChannel<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void, szendpointname: windows_core::PCSTR, bstatic: windows_core::BOOL, requestedpriority: u32, phchannel: *mut usize) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWRdsProtocolConnection_Impl::CreateVirtualChannel(this, core::mem::transmute(&szendpointname), core::mem::transmute_copy(&bstatic), core::mem::transmute_copy(&requestedpriority)) {
                     Ok(ok__) => {
                         phchannel.write(core::mem::transmute(ok__));
@@ -6522,15 +9653,15 @@ impl IWRdsProtocolConnection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn QueryProperty<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, querytype: windows_core::GUID, ulnumentriesin: u32, ulnumentriesout: u32, ppropertyentriesin: *const WTS_PROPERTY_VALUE, ppropertyentriesout: *mut WTS_PROPERTY_VALUE) -> windows_core::HRESULT {
+        unsafe extern "system" fn QueryProperty<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void, querytype: windows_core::GUID, ulnumentriesin: u32, ulnumentriesout: u32, ppropertyentriesin: *const WTS_PROPERTY_VALUE, ppropertyentriesout: *mut WTS_PROPERTY_VALUE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWRdsProtocolConnection_Impl::QueryProperty(this, core::mem::transmute(&querytype), core::mem::transmute_copy(&ulnumentriesin), core::mem::transmute_copy(&ulnumentriesout), core::mem::transmute_copy(&ppropertyentriesin), core::mem::transmute_copy(&ppropertyentriesout)).into()
             }
         }
-        unsafe extern "system" fn GetShadowConnection<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppshadowconnection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetShadowConnection<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void, ppshadowconnection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWRdsProtocolConnection_Impl::GetShadowConnection(this) {
                     Ok(ok__) => {
                         ppshadowconnection.write(core::mem::transmute(ok__));
@@ -6540,43 +9671,40 @@ impl IWRdsProtocolConnection_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn NotifyCommandProcessCreated<Identity: IWRdsProtocolConnection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, sessionid: u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn NotifyCommandProcessCreated<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnection_Impl>(this: *mut core::ffi::c_void, sessionid: u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWRdsProtocolConnection_Impl::NotifyCommandProcessCreated(this, core::mem::transmute_copy(&sessionid)).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            GetLogonErrorRedirector: GetLogonErrorRedirector::<Identity, OFFSET>,
-            AcceptConnection: AcceptConnection::<Identity, OFFSET>,
-            GetClientData: GetClientData::<Identity, OFFSET>,
-            GetClientMonitorData: GetClientMonitorData::<Identity, OFFSET>,
-            GetUserCredentials: GetUserCredentials::<Identity, OFFSET>,
-            GetLicenseConnection: GetLicenseConnection::<Identity, OFFSET>,
-            AuthenticateClientToSession: AuthenticateClientToSession::<Identity, OFFSET>,
-            NotifySessionId: NotifySessionId::<Identity, OFFSET>,
-            GetInputHandles: GetInputHandles::<Identity, OFFSET>,
-            GetVideoHandle: GetVideoHandle::<Identity, OFFSET>,
-            ConnectNotify: ConnectNotify::<Identity, OFFSET>,
-            IsUserAllowedToLogon: IsUserAllowedToLogon::<Identity, OFFSET>,
-            SessionArbitrationEnumeration: SessionArbitrationEnumeration::<Identity, OFFSET>,
-            LogonNotify: LogonNotify::<Identity, OFFSET>,
-            PreDisconnect: PreDisconnect::<Identity, OFFSET>,
-            DisconnectNotify: DisconnectNotify::<Identity, OFFSET>,
-            Close: Close::<Identity, OFFSET>,
-            GetProtocolStatus: GetProtocolStatus::<Identity, OFFSET>,
-            GetLastInputTime: GetLastInputTime::<Identity, OFFSET>,
-            SetErrorInfo: SetErrorInfo::<Identity, OFFSET>,
-            CreateVirtualChannel: CreateVirtualChannel::<Identity, OFFSET>,
-            QueryProperty: QueryProperty::<Identity, OFFSET>,
-            GetShadowConnection: GetShadowConnection::<Identity, OFFSET>,
-            NotifyCommandProcessCreated: NotifyCommandProcessCreated::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWRdsProtocolConnection as windows_core::Interface>::IID
-    }
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetLogonErrorRedirector: GetLogonErrorRedirector::<Identity>,
+            AcceptConnection: AcceptConnection::<Identity>,
+            GetClientData: GetClientData::<Identity>,
+            GetClientMonitorData: GetClientMonitorData::<Identity>,
+            GetUserCredentials: GetUserCredentials::<Identity>,
+            GetLicenseConnection: GetLicenseConnection::<Identity>,
+            AuthenticateClientToSession: AuthenticateClientToSession::<Identity>,
+            NotifySessionId: NotifySessionId::<Identity>,
+            GetInputHandles: GetInputHandles::<Identity>,
+            GetVideoHandle: GetVideoHandle::<Identity>,
+            ConnectNotify: ConnectNotify::<Identity>,
+            IsUserAllowedToLogon: IsUserAllowedToLogon::<Identity>,
+            SessionArbitrationEnumeration: SessionArbitrationEnumeration::<Identity>,
+            LogonNotify: LogonNotify::<Identity>,
+            PreDisconnect: PreDisconnect::<Identity>,
+            DisconnectNotify: DisconnectNotify::<Identity>,
+            Close: Close::<Identity>,
+            GetProtocolStatus: GetProtocolStatus::<Identity>,
+            GetLastInputTime: GetLastInputTime::<Identity>,
+            SetErrorInfo: SetErrorInfo::<Identity>,
+            CreateVirtualChannel: CreateVirtualChannel::<Identity>,
+            QueryProperty: QueryProperty::<Identity>,
+            GetShadowConnection: GetShadowConnection::<Identity>,
+            NotifyCommandProcessCreated: NotifyCommandProcessCreated::<Identity>,
+        }
+    };
 }
 impl windows_core::RuntimeName for IWRdsProtocolConnection {}
 windows_core::imp::define_interface!(IWRdsProtocolConnectionCallback, IWRdsProtocolConnectionCallback_Vtbl, 0xf1d70332_d070_4ef1_a088_78313536c2d6);
@@ -6669,6 +9797,57 @@ impl IWRdsProtocolConnectionCallback_Vtbl {
         iid == &<IWRdsProtocolConnectionCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnectionCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IWRdsProtocolConnectionCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnReady<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnectionCallback_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolConnectionCallback_Impl::OnReady(this).into()
+            }
+        }
+        unsafe extern "system" fn BrokenConnection<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnectionCallback_Impl>(this: *mut core::ffi::c_void, reason: u32, source: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolConnectionCallback_Impl::BrokenConnection(this, core::mem::transmute_copy(&reason), core::mem::transmute_copy(&source)).into()
+            }
+        }
+        unsafe extern "system" fn StopScreenUpdates<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnectionCallback_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolConnectionCallback_Impl::StopScreenUpdates(this).into()
+            }
+        }
+        unsafe extern "system" fn RedrawWindow<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnectionCallback_Impl>(this: *mut core::ffi::c_void, rect: *const WTS_SMALL_RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolConnectionCallback_Impl::RedrawWindow(this, core::mem::transmute_copy(&rect)).into()
+            }
+        }
+        unsafe extern "system" fn GetConnectionId<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnectionCallback_Impl>(this: *mut core::ffi::c_void, pconnectionid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWRdsProtocolConnectionCallback_Impl::GetConnectionId(this) {
+                    Ok(ok__) => {
+                        pconnectionid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnReady: OnReady::<Identity>,
+            BrokenConnection: BrokenConnection::<Identity>,
+            StopScreenUpdates: StopScreenUpdates::<Identity>,
+            RedrawWindow: RedrawWindow::<Identity>,
+            GetConnectionId: GetConnectionId::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWRdsProtocolConnectionCallback {}
 windows_core::imp::define_interface!(IWRdsProtocolConnectionSettings, IWRdsProtocolConnectionSettings_Vtbl, 0x83fcf5d3_f6f4_ea94_9cd2_32f280e1e510);
 windows_core::imp::interface_hierarchy!(IWRdsProtocolConnectionSettings, windows_core::IUnknown);
@@ -6715,6 +9894,30 @@ impl IWRdsProtocolConnectionSettings_Vtbl {
         iid == &<IWRdsProtocolConnectionSettings as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnectionSettings_Impl> windows_core::imp::TearOffVtable<Identity> for IWRdsProtocolConnectionSettings_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetConnectionSetting<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnectionSettings_Impl>(this: *mut core::ffi::c_void, propertyid: windows_core::GUID, ppropertyentriesin: *const WTS_PROPERTY_VALUE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolConnectionSettings_Impl::SetConnectionSetting(this, core::mem::transmute(&propertyid), core::mem::transmute_copy(&ppropertyentriesin)).into()
+            }
+        }
+        unsafe extern "system" fn GetConnectionSetting<Identity: windows_core::IUnknownImpl + IWRdsProtocolConnectionSettings_Impl>(this: *mut core::ffi::c_void, propertyid: windows_core::GUID, ppropertyentriesout: *mut WTS_PROPERTY_VALUE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolConnectionSettings_Impl::GetConnectionSetting(this, core::mem::transmute(&propertyid), core::mem::transmute_copy(&ppropertyentriesout)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetConnectionSetting: SetConnectionSetting::<Identity>,
+            GetConnectionSetting: GetConnectionSetting::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWRdsProtocolConnectionSettings {}
 windows_core::imp::define_interface!(IWRdsProtocolLicenseConnection, IWRdsProtocolLicenseConnection_Vtbl, 0x1d6a145f_d095_4424_957a_407fae822d84);
 windows_core::imp::interface_hierarchy!(IWRdsProtocolLicenseConnection, windows_core::IUnknown);
@@ -6785,6 +9988,44 @@ impl IWRdsProtocolLicenseConnection_Vtbl {
         iid == &<IWRdsProtocolLicenseConnection as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWRdsProtocolLicenseConnection_Impl> windows_core::imp::TearOffVtable<Identity> for IWRdsProtocolLicenseConnection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RequestLicensingCapabilities<Identity: windows_core::IUnknownImpl + IWRdsProtocolLicenseConnection_Impl>(this: *mut core::ffi::c_void, pplicensecapabilities: *mut WTS_LICENSE_CAPABILITIES, pcblicensecapabilities: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolLicenseConnection_Impl::RequestLicensingCapabilities(this, core::mem::transmute_copy(&pplicensecapabilities), core::mem::transmute_copy(&pcblicensecapabilities)).into()
+            }
+        }
+        unsafe extern "system" fn SendClientLicense<Identity: windows_core::IUnknownImpl + IWRdsProtocolLicenseConnection_Impl>(this: *mut core::ffi::c_void, pclientlicense: *const u8, cbclientlicense: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolLicenseConnection_Impl::SendClientLicense(this, core::mem::transmute_copy(&pclientlicense), core::mem::transmute_copy(&cbclientlicense)).into()
+            }
+        }
+        unsafe extern "system" fn RequestClientLicense<Identity: windows_core::IUnknownImpl + IWRdsProtocolLicenseConnection_Impl>(this: *mut core::ffi::c_void, reserve1: *const u8, reserve2: u32, ppclientlicense: *mut u8, pcbclientlicense: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolLicenseConnection_Impl::RequestClientLicense(this, core::mem::transmute_copy(&reserve1), core::mem::transmute_copy(&reserve2), core::mem::transmute_copy(&ppclientlicense), core::mem::transmute_copy(&pcbclientlicense)).into()
+            }
+        }
+        unsafe extern "system" fn ProtocolComplete<Identity: windows_core::IUnknownImpl + IWRdsProtocolLicenseConnection_Impl>(this: *mut core::ffi::c_void, ulcomplete: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolLicenseConnection_Impl::ProtocolComplete(this, core::mem::transmute_copy(&ulcomplete)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RequestLicensingCapabilities: RequestLicensingCapabilities::<Identity>,
+            SendClientLicense: SendClientLicense::<Identity>,
+            RequestClientLicense: RequestClientLicense::<Identity>,
+            ProtocolComplete: ProtocolComplete::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWRdsProtocolLicenseConnection {}
 windows_core::imp::define_interface!(IWRdsProtocolListener, IWRdsProtocolListener_Vtbl, 0xfcbc131b_c686_451d_a773_e279e230f540);
 windows_core::imp::interface_hierarchy!(IWRdsProtocolListener, windows_core::IUnknown);
@@ -6855,6 +10096,43 @@ impl IWRdsProtocolListener_Vtbl {
         iid == &<IWRdsProtocolListener as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWRdsProtocolListener_Impl> windows_core::imp::TearOffVtable<Identity> for IWRdsProtocolListener_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSettings<Identity: windows_core::IUnknownImpl + IWRdsProtocolListener_Impl>(this: *mut core::ffi::c_void, wrdslistenersettinglevel: WRDS_LISTENER_SETTING_LEVEL, pwrdslistenersettings: *mut WRDS_LISTENER_SETTINGS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWRdsProtocolListener_Impl::GetSettings(this, core::mem::transmute_copy(&wrdslistenersettinglevel)) {
+                    Ok(ok__) => {
+                        pwrdslistenersettings.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StartListen<Identity: windows_core::IUnknownImpl + IWRdsProtocolListener_Impl>(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolListener_Impl::StartListen(this, core::mem::transmute_copy(&pcallback)).into()
+            }
+        }
+        unsafe extern "system" fn StopListen<Identity: windows_core::IUnknownImpl + IWRdsProtocolListener_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolListener_Impl::StopListen(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSettings: GetSettings::<Identity>,
+            StartListen: StartListen::<Identity>,
+            StopListen: StopListen::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWRdsProtocolListener {}
 windows_core::imp::define_interface!(IWRdsProtocolListenerCallback, IWRdsProtocolListenerCallback_Vtbl, 0x3ab27e5b_4449_4dc1_b74a_91621d4fe984);
 windows_core::imp::interface_hierarchy!(IWRdsProtocolListenerCallback, windows_core::IUnknown);
@@ -6898,6 +10176,26 @@ impl IWRdsProtocolListenerCallback_Vtbl {
         iid == &<IWRdsProtocolListenerCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWRdsProtocolListenerCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IWRdsProtocolListenerCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnConnected<Identity: windows_core::IUnknownImpl + IWRdsProtocolListenerCallback_Impl>(this: *mut core::ffi::c_void, pconnection: *mut core::ffi::c_void, pwrdsconnectionsettings: *const WRDS_CONNECTION_SETTINGS, pcallback: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWRdsProtocolListenerCallback_Impl::OnConnected(this, core::mem::transmute_copy(&pconnection), core::mem::transmute_copy(&pwrdsconnectionsettings)) {
+                    Ok(ok__) => {
+                        pcallback.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnConnected: OnConnected::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWRdsProtocolListenerCallback {}
 windows_core::imp::define_interface!(IWRdsProtocolLogonErrorRedirector, IWRdsProtocolLogonErrorRedirector_Vtbl, 0x519fe83b_142a_4120_a3d5_a405d315281a);
 windows_core::imp::interface_hierarchy!(IWRdsProtocolLogonErrorRedirector, windows_core::IUnknown);
@@ -6950,17 +10248,76 @@ pub trait IWRdsProtocolLogonErrorRedirector_Impl: windows_core::IUnknownImpl {
     fn RedirectMessage(&self, pszcaption: &windows_core::PCWSTR, pszmessage: &windows_core::PCWSTR, utype: u32) -> windows_core::Result<WTS_LOGON_ERROR_REDIRECTOR_RESPONSE>;
     fn RedirectLogonError(&self, ntsstatus: i32, ntssubstatus: i32, pszcaption: &windows_core::PCWSTR, pszmessage: &windows_core::PCWSTR, utype: u32) -> windows_core::Result<WTS_LOGON_ERROR_REDIRECTOR_RESPONSE>;
 }
-impl IWRdsProtocolLogonErrorRedirector_Vtbl {
-    pub const fn new<Identity: IWRdsProtocolLogonErrorRedirector_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn OnBeginPainting<Identity: IWRdsProtocolLogonErrorRedirector_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl IWRdsProtocolLogonErrorRedirector_Vtbl {
+    pub const fn new<Identity: IWRdsProtocolLogonErrorRedirector_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn OnBeginPainting<Identity: IWRdsProtocolLogonErrorRedirector_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWRdsProtocolLogonErrorRedirector_Impl::OnBeginPainting(this).into()
+            }
+        }
+        unsafe extern "system" fn RedirectStatus<Identity: IWRdsProtocolLogonErrorRedirector_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pszmessage: windows_core::PCWSTR, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWRdsProtocolLogonErrorRedirector_Impl::RedirectStatus(this, core::mem::transmute(&pszmessage)) {
+                    Ok(ok__) => {
+                        presponse.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RedirectMessage<Identity: IWRdsProtocolLogonErrorRedirector_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pszcaption: windows_core::PCWSTR, pszmessage: windows_core::PCWSTR, utype: u32, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWRdsProtocolLogonErrorRedirector_Impl::RedirectMessage(this, core::mem::transmute(&pszcaption), core::mem::transmute(&pszmessage), core::mem::transmute_copy(&utype)) {
+                    Ok(ok__) => {
+                        presponse.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RedirectLogonError<Identity: IWRdsProtocolLogonErrorRedirector_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ntsstatus: i32, ntssubstatus: i32, pszcaption: windows_core::PCWSTR, pszmessage: windows_core::PCWSTR, utype: u32, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWRdsProtocolLogonErrorRedirector_Impl::RedirectLogonError(this, core::mem::transmute_copy(&ntsstatus), core::mem::transmute_copy(&ntssubstatus), core::mem::transmute(&pszcaption), core::mem::transmute(&pszmessage), core::mem::transmute_copy(&utype)) {
+                    Ok(ok__) => {
+                        presponse.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            OnBeginPainting: OnBeginPainting::<Identity, OFFSET>,
+            RedirectStatus: RedirectStatus::<Identity, OFFSET>,
+            RedirectMessage: RedirectMessage::<Identity, OFFSET>,
+            RedirectLogonError: RedirectLogonError::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWRdsProtocolLogonErrorRedirector as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IWRdsProtocolLogonErrorRedirector_Impl> windows_core::imp::TearOffVtable<Identity> for IWRdsProtocolLogonErrorRedirector_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnBeginPainting<Identity: windows_core::IUnknownImpl + IWRdsProtocolLogonErrorRedirector_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWRdsProtocolLogonErrorRedirector_Impl::OnBeginPainting(this).into()
             }
         }
-        unsafe extern "system" fn RedirectStatus<Identity: IWRdsProtocolLogonErrorRedirector_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pszmessage: windows_core::PCWSTR, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
+        unsafe extern "system" fn RedirectStatus<Identity: windows_core::IUnknownImpl + IWRdsProtocolLogonErrorRedirector_Impl>(this: *mut core::ffi::c_void, pszmessage: windows_core::PCWSTR, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWRdsProtocolLogonErrorRedirector_Impl::RedirectStatus(this, core::mem::transmute(&pszmessage)) {
                     Ok(ok__) => {
                         presponse.write(core::mem::transmute(ok__));
@@ -6970,9 +10327,9 @@ impl IWRdsProtocolLogonErrorRedirector_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn RedirectMessage<Identity: IWRdsProtocolLogonErrorRedirector_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pszcaption: windows_core::PCWSTR, pszmessage: windows_core::PCWSTR, utype: u32, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
+        unsafe extern "system" fn RedirectMessage<Identity: windows_core::IUnknownImpl + IWRdsProtocolLogonErrorRedirector_Impl>(this: *mut core::ffi::c_void, pszcaption: windows_core::PCWSTR, pszmessage: windows_core::PCWSTR, utype: u32, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWRdsProtocolLogonErrorRedirector_Impl::RedirectMessage(this, core::mem::transmute(&pszcaption), core::mem::transmute(&pszmessage), core::mem::transmute_copy(&utype)) {
                     Ok(ok__) => {
                         presponse.write(core::mem::transmute(ok__));
@@ -6982,9 +10339,9 @@ impl IWRdsProtocolLogonErrorRedirector_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn RedirectLogonError<Identity: IWRdsProtocolLogonErrorRedirector_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ntsstatus: i32, ntssubstatus: i32, pszcaption: windows_core::PCWSTR, pszmessage: windows_core::PCWSTR, utype: u32, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
+        unsafe extern "system" fn RedirectLogonError<Identity: windows_core::IUnknownImpl + IWRdsProtocolLogonErrorRedirector_Impl>(this: *mut core::ffi::c_void, ntsstatus: i32, ntssubstatus: i32, pszcaption: windows_core::PCWSTR, pszmessage: windows_core::PCWSTR, utype: u32, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWRdsProtocolLogonErrorRedirector_Impl::RedirectLogonError(this, core::mem::transmute_copy(&ntsstatus), core::mem::transmute_copy(&ntssubstatus), core::mem::transmute(&pszcaption), core::mem::transmute(&pszmessage), core::mem::transmute_copy(&utype)) {
                     Ok(ok__) => {
                         presponse.write(core::mem::transmute(ok__));
@@ -6995,16 +10352,13 @@ impl IWRdsProtocolLogonErrorRedirector_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            OnBeginPainting: OnBeginPainting::<Identity, OFFSET>,
-            RedirectStatus: RedirectStatus::<Identity, OFFSET>,
-            RedirectMessage: RedirectMessage::<Identity, OFFSET>,
-            RedirectLogonError: RedirectLogonError::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnBeginPainting: OnBeginPainting::<Identity>,
+            RedirectStatus: RedirectStatus::<Identity>,
+            RedirectMessage: RedirectMessage::<Identity>,
+            RedirectLogonError: RedirectLogonError::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWRdsProtocolLogonErrorRedirector as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IWRdsProtocolLogonErrorRedirector {}
 windows_core::imp::define_interface!(IWRdsProtocolManager, IWRdsProtocolManager_Vtbl, 0xdc796967_3abb_40cd_a446_105276b58950);
@@ -7139,6 +10493,78 @@ impl IWRdsProtocolManager_Vtbl {
         iid == &<IWRdsProtocolManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWRdsProtocolManager_Impl> windows_core::imp::TearOffVtable<Identity> for IWRdsProtocolManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IWRdsProtocolManager_Impl>(this: *mut core::ffi::c_void, piwrdssettings: *mut core::ffi::c_void, pwrdssettings: *const WRDS_SETTINGS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolManager_Impl::Initialize(this, core::mem::transmute_copy(&piwrdssettings), core::mem::transmute_copy(&pwrdssettings)).into()
+            }
+        }
+        unsafe extern "system" fn CreateListener<Identity: windows_core::IUnknownImpl + IWRdsProtocolManager_Impl>(this: *mut core::ffi::c_void, wszlistenername: windows_core::PCWSTR, pprotocollistener: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWRdsProtocolManager_Impl::CreateListener(this, core::mem::transmute(&wszlistenername)) {
+                    Ok(ok__) => {
+                        pprotocollistener.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NotifyServiceStateChange<Identity: windows_core::IUnknownImpl + IWRdsProtocolManager_Impl>(this: *mut core::ffi::c_void, ptsservicestatechange: *const WTS_SERVICE_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolManager_Impl::NotifyServiceStateChange(this, core::mem::transmute_copy(&ptsservicestatechange)).into()
+            }
+        }
+        unsafe extern "system" fn NotifySessionOfServiceStart<Identity: windows_core::IUnknownImpl + IWRdsProtocolManager_Impl>(this: *mut core::ffi::c_void, sessionid: *const WTS_SESSION_ID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolManager_Impl::NotifySessionOfServiceStart(this, core::mem::transmute_copy(&sessionid)).into()
+            }
+        }
+        unsafe extern "system" fn NotifySessionOfServiceStop<Identity: windows_core::IUnknownImpl + IWRdsProtocolManager_Impl>(this: *mut core::ffi::c_void, sessionid: *const WTS_SESSION_ID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolManager_Impl::NotifySessionOfServiceStop(this, core::mem::transmute_copy(&sessionid)).into()
+            }
+        }
+        unsafe extern "system" fn NotifySessionStateChange<Identity: windows_core::IUnknownImpl + IWRdsProtocolManager_Impl>(this: *mut core::ffi::c_void, sessionid: *const WTS_SESSION_ID, eventid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolManager_Impl::NotifySessionStateChange(this, core::mem::transmute_copy(&sessionid), core::mem::transmute_copy(&eventid)).into()
+            }
+        }
+        unsafe extern "system" fn NotifySettingsChange<Identity: windows_core::IUnknownImpl + IWRdsProtocolManager_Impl>(this: *mut core::ffi::c_void, pwrdssettings: *const WRDS_SETTINGS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolManager_Impl::NotifySettingsChange(this, core::mem::transmute_copy(&pwrdssettings)).into()
+            }
+        }
+        unsafe extern "system" fn Uninitialize<Identity: windows_core::IUnknownImpl + IWRdsProtocolManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolManager_Impl::Uninitialize(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            CreateListener: CreateListener::<Identity>,
+            NotifyServiceStateChange: NotifyServiceStateChange::<Identity>,
+            NotifySessionOfServiceStart: NotifySessionOfServiceStart::<Identity>,
+            NotifySessionOfServiceStop: NotifySessionOfServiceStop::<Identity>,
+            NotifySessionStateChange: NotifySessionStateChange::<Identity>,
+            NotifySettingsChange: NotifySettingsChange::<Identity>,
+            Uninitialize: Uninitialize::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWRdsProtocolManager {}
 windows_core::imp::define_interface!(IWRdsProtocolSettings, IWRdsProtocolSettings_Vtbl, 0x654a5a6a_2550_47eb_b6f7_ebd637475265);
 windows_core::imp::interface_hierarchy!(IWRdsProtocolSettings, windows_core::IUnknown);
@@ -7185,6 +10611,30 @@ impl IWRdsProtocolSettings_Vtbl {
         iid == &<IWRdsProtocolSettings as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWRdsProtocolSettings_Impl> windows_core::imp::TearOffVtable<Identity> for IWRdsProtocolSettings_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetSettings<Identity: windows_core::IUnknownImpl + IWRdsProtocolSettings_Impl>(this: *mut core::ffi::c_void, wrdssettingtype: WRDS_SETTING_TYPE, wrdssettinglevel: WRDS_SETTING_LEVEL, pwrdssettings: *mut WRDS_SETTINGS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolSettings_Impl::GetSettings(this, core::mem::transmute_copy(&wrdssettingtype), core::mem::transmute_copy(&wrdssettinglevel), core::mem::transmute_copy(&pwrdssettings)).into()
+            }
+        }
+        unsafe extern "system" fn MergeSettings<Identity: windows_core::IUnknownImpl + IWRdsProtocolSettings_Impl>(this: *mut core::ffi::c_void, pwrdssettings: *const WRDS_SETTINGS, wrdsconnectionsettinglevel: WRDS_CONNECTION_SETTING_LEVEL, pwrdsconnectionsettings: *mut WRDS_CONNECTION_SETTINGS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolSettings_Impl::MergeSettings(this, core::mem::transmute_copy(&pwrdssettings), core::mem::transmute_copy(&wrdsconnectionsettinglevel), core::mem::transmute_copy(&pwrdsconnectionsettings)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetSettings: GetSettings::<Identity>,
+            MergeSettings: MergeSettings::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWRdsProtocolSettings {}
 windows_core::imp::define_interface!(IWRdsProtocolShadowCallback, IWRdsProtocolShadowCallback_Vtbl, 0xe0667ce0_0372_40d6_adb2_a0f3322674d6);
 windows_core::imp::interface_hierarchy!(IWRdsProtocolShadowCallback, windows_core::IUnknown);
@@ -7235,6 +10685,30 @@ impl IWRdsProtocolShadowCallback_Vtbl {
         iid == &<IWRdsProtocolShadowCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWRdsProtocolShadowCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IWRdsProtocolShadowCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StopShadow<Identity: windows_core::IUnknownImpl + IWRdsProtocolShadowCallback_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolShadowCallback_Impl::StopShadow(this).into()
+            }
+        }
+        unsafe extern "system" fn InvokeTargetShadow<Identity: windows_core::IUnknownImpl + IWRdsProtocolShadowCallback_Impl>(this: *mut core::ffi::c_void, ptargetservername: windows_core::PCWSTR, targetsessionid: u32, pparam1: *const u8, param1size: u32, pparam2: *const u8, param2size: u32, pparam3: *const u8, param3size: u32, pparam4: *const u8, param4size: u32, pclientname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolShadowCallback_Impl::InvokeTargetShadow(this, core::mem::transmute(&ptargetservername), core::mem::transmute_copy(&targetsessionid), core::mem::transmute_copy(&pparam1), core::mem::transmute_copy(&param1size), core::mem::transmute_copy(&pparam2), core::mem::transmute_copy(&param2size), core::mem::transmute_copy(&pparam3), core::mem::transmute_copy(&param3size), core::mem::transmute_copy(&pparam4), core::mem::transmute_copy(&param4size), core::mem::transmute(&pclientname)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            StopShadow: StopShadow::<Identity>,
+            InvokeTargetShadow: InvokeTargetShadow::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWRdsProtocolShadowCallback {}
 windows_core::imp::define_interface!(IWRdsProtocolShadowConnection, IWRdsProtocolShadowConnection_Vtbl, 0x9ae85ce6_cade_4548_8feb_99016597f60a);
 windows_core::imp::interface_hierarchy!(IWRdsProtocolShadowConnection, windows_core::IUnknown);
@@ -7300,6 +10774,37 @@ impl IWRdsProtocolShadowConnection_Vtbl {
         iid == &<IWRdsProtocolShadowConnection as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWRdsProtocolShadowConnection_Impl> windows_core::imp::TearOffVtable<Identity> for IWRdsProtocolShadowConnection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Start<Identity: windows_core::IUnknownImpl + IWRdsProtocolShadowConnection_Impl>(this: *mut core::ffi::c_void, ptargetservername: windows_core::PCWSTR, targetsessionid: u32, hotkeyvk: u8, hotkeymodifiers: u16, pshadowcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolShadowConnection_Impl::Start(this, core::mem::transmute(&ptargetservername), core::mem::transmute_copy(&targetsessionid), core::mem::transmute_copy(&hotkeyvk), core::mem::transmute_copy(&hotkeymodifiers), core::mem::transmute_copy(&pshadowcallback)).into()
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + IWRdsProtocolShadowConnection_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolShadowConnection_Impl::Stop(this).into()
+            }
+        }
+        unsafe extern "system" fn DoTarget<Identity: windows_core::IUnknownImpl + IWRdsProtocolShadowConnection_Impl>(this: *mut core::ffi::c_void, pparam1: *const u8, param1size: u32, pparam2: *const u8, param2size: u32, pparam3: *const u8, param3size: u32, pparam4: *const u8, param4size: u32, pclientname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsProtocolShadowConnection_Impl::DoTarget(this, core::mem::transmute_copy(&pparam1), core::mem::transmute_copy(&param1size), core::mem::transmute_copy(&pparam2), core::mem::transmute_copy(&param2size), core::mem::transmute_copy(&pparam3), core::mem::transmute_copy(&param3size), core::mem::transmute_copy(&pparam4), core::mem::transmute_copy(&param4size), core::mem::transmute(&pclientname)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Start: Start::<Identity>,
+            Stop: Stop::<Identity>,
+            DoTarget: DoTarget::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWRdsProtocolShadowConnection {}
 windows_core::imp::define_interface!(IWRdsWddmIddProps, IWRdsWddmIddProps_Vtbl, 0x1382df4d_a289_43d1_a184_144726f9af90);
 windows_core::imp::interface_hierarchy!(IWRdsWddmIddProps, windows_core::IUnknown);
@@ -7370,6 +10875,44 @@ impl IWRdsWddmIddProps_Vtbl {
         iid == &<IWRdsWddmIddProps as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWRdsWddmIddProps_Impl> windows_core::imp::TearOffVtable<Identity> for IWRdsWddmIddProps_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetHardwareId<Identity: windows_core::IUnknownImpl + IWRdsWddmIddProps_Impl>(this: *mut core::ffi::c_void, pdisplaydriverhardwareid: windows_core::PCWSTR, count: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsWddmIddProps_Impl::GetHardwareId(this, core::mem::transmute(&pdisplaydriverhardwareid), core::mem::transmute_copy(&count)).into()
+            }
+        }
+        unsafe extern "system" fn OnDriverLoad<Identity: windows_core::IUnknownImpl + IWRdsWddmIddProps_Impl>(this: *mut core::ffi::c_void, sessionid: u32, driverhandle: super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsWddmIddProps_Impl::OnDriverLoad(this, core::mem::transmute_copy(&sessionid), core::mem::transmute_copy(&driverhandle)).into()
+            }
+        }
+        unsafe extern "system" fn OnDriverUnload<Identity: windows_core::IUnknownImpl + IWRdsWddmIddProps_Impl>(this: *mut core::ffi::c_void, sessionid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsWddmIddProps_Impl::OnDriverUnload(this, core::mem::transmute_copy(&sessionid)).into()
+            }
+        }
+        unsafe extern "system" fn EnableWddmIdd<Identity: windows_core::IUnknownImpl + IWRdsWddmIddProps_Impl>(this: *mut core::ffi::c_void, enabled: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsWddmIddProps_Impl::EnableWddmIdd(this, core::mem::transmute_copy(&enabled)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetHardwareId: GetHardwareId::<Identity>,
+            OnDriverLoad: OnDriverLoad::<Identity>,
+            OnDriverUnload: OnDriverUnload::<Identity>,
+            EnableWddmIdd: EnableWddmIdd::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWRdsWddmIddProps {}
 windows_core::imp::define_interface!(IWRdsWddmIddProps1, IWRdsWddmIddProps1_Vtbl, 0x60f71b1a_3682_4bc7_997e_4e4f02a08148);
 windows_core::imp::interface_hierarchy!(IWRdsWddmIddProps1, windows_core::IUnknown);
@@ -7431,6 +10974,37 @@ impl IWRdsWddmIddProps1_Vtbl {
         iid == &<IWRdsWddmIddProps1 as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWRdsWddmIddProps1_Impl> windows_core::imp::TearOffVtable<Identity> for IWRdsWddmIddProps1_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetHardwareId<Identity: windows_core::IUnknownImpl + IWRdsWddmIddProps1_Impl>(this: *mut core::ffi::c_void, pdisplaydriverhardwareid: windows_core::PCWSTR, count: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsWddmIddProps1_Impl::GetHardwareId(this, core::mem::transmute(&pdisplaydriverhardwareid), core::mem::transmute_copy(&count)).into()
+            }
+        }
+        unsafe extern "system" fn OnDriverLoad<Identity: windows_core::IUnknownImpl + IWRdsWddmIddProps1_Impl>(this: *mut core::ffi::c_void, sessionid: u32, deviceinstance: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsWddmIddProps1_Impl::OnDriverLoad(this, core::mem::transmute_copy(&sessionid), core::mem::transmute(&deviceinstance)).into()
+            }
+        }
+        unsafe extern "system" fn OnDriverUnload<Identity: windows_core::IUnknownImpl + IWRdsWddmIddProps1_Impl>(this: *mut core::ffi::c_void, sessionid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWRdsWddmIddProps1_Impl::OnDriverUnload(this, core::mem::transmute_copy(&sessionid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetHardwareId: GetHardwareId::<Identity>,
+            OnDriverLoad: OnDriverLoad::<Identity>,
+            OnDriverUnload: OnDriverUnload::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWRdsWddmIddProps1 {}
 windows_core::imp::define_interface!(IWTSBitmapRenderService, IWTSBitmapRenderService_Vtbl, 0xea326091_05fe_40c1_b49c_3d2ef4626a0e);
 windows_core::imp::interface_hierarchy!(IWTSBitmapRenderService, windows_core::IUnknown);
@@ -7474,6 +11048,26 @@ impl IWTSBitmapRenderService_Vtbl {
         iid == &<IWTSBitmapRenderService as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWTSBitmapRenderService_Impl> windows_core::imp::TearOffVtable<Identity> for IWTSBitmapRenderService_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetMappedRenderer<Identity: windows_core::IUnknownImpl + IWTSBitmapRenderService_Impl>(this: *mut core::ffi::c_void, mappingid: u64, pmappedrenderercallback: *mut core::ffi::c_void, ppmappedrenderer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWTSBitmapRenderService_Impl::GetMappedRenderer(this, core::mem::transmute_copy(&mappingid), core::mem::transmute_copy(&pmappedrenderercallback)) {
+                    Ok(ok__) => {
+                        ppmappedrenderer.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetMappedRenderer: GetMappedRenderer::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWTSBitmapRenderService {}
 windows_core::imp::define_interface!(IWTSBitmapRenderer, IWTSBitmapRenderer_Vtbl, 0x5b7acc97_f3c9_46f7_8c5b_fa685d3441b1);
 windows_core::imp::interface_hierarchy!(IWTSBitmapRenderer, windows_core::IUnknown);
@@ -7541,6 +11135,43 @@ impl IWTSBitmapRenderer_Vtbl {
         iid == &<IWTSBitmapRenderer as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWTSBitmapRenderer_Impl> windows_core::imp::TearOffVtable<Identity> for IWTSBitmapRenderer_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Render<Identity: windows_core::IUnknownImpl + IWTSBitmapRenderer_Impl>(this: *mut core::ffi::c_void, imageformat: windows_core::GUID, dwwidth: u32, dwheight: u32, cbstride: i32, cbimagebuffer: u32, pimagebuffer: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSBitmapRenderer_Impl::Render(this, core::mem::transmute(&imageformat), core::mem::transmute_copy(&dwwidth), core::mem::transmute_copy(&dwheight), core::mem::transmute_copy(&cbstride), core::mem::transmute_copy(&cbimagebuffer), core::mem::transmute_copy(&pimagebuffer)).into()
+            }
+        }
+        unsafe extern "system" fn GetRendererStatistics<Identity: windows_core::IUnknownImpl + IWTSBitmapRenderer_Impl>(this: *mut core::ffi::c_void, pstatistics: *mut BITMAP_RENDERER_STATISTICS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWTSBitmapRenderer_Impl::GetRendererStatistics(this) {
+                    Ok(ok__) => {
+                        pstatistics.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveMapping<Identity: windows_core::IUnknownImpl + IWTSBitmapRenderer_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSBitmapRenderer_Impl::RemoveMapping(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Render: Render::<Identity>,
+            GetRendererStatistics: GetRendererStatistics::<Identity>,
+            RemoveMapping: RemoveMapping::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWTSBitmapRenderer {}
 windows_core::imp::define_interface!(IWTSBitmapRendererCallback, IWTSBitmapRendererCallback_Vtbl, 0xd782928e_fe4e_4e77_ae90_9cd0b3e3b353);
 windows_core::imp::interface_hierarchy!(IWTSBitmapRendererCallback, windows_core::IUnknown);
@@ -7572,6 +11203,23 @@ impl IWTSBitmapRendererCallback_Vtbl {
         iid == &<IWTSBitmapRendererCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWTSBitmapRendererCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IWTSBitmapRendererCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnTargetSizeChanged<Identity: windows_core::IUnknownImpl + IWTSBitmapRendererCallback_Impl>(this: *mut core::ffi::c_void, rcnewsize: super::super::Foundation::RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSBitmapRendererCallback_Impl::OnTargetSizeChanged(this, core::mem::transmute(&rcnewsize)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnTargetSizeChanged: OnTargetSizeChanged::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWTSBitmapRendererCallback {}
 windows_core::imp::define_interface!(IWTSListener, IWTSListener_Vtbl, 0xa1230206_9a39_4d58_8674_cdb4dff4e73b);
 windows_core::imp::interface_hierarchy!(IWTSListener, windows_core::IUnknown);
@@ -7619,6 +11267,27 @@ impl IWTSListener_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl<Identity: windows_core::IUnknownImpl + IWTSListener_Impl> windows_core::imp::TearOffVtable<Identity> for IWTSListener_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetConfiguration<Identity: windows_core::IUnknownImpl + IWTSListener_Impl>(this: *mut core::ffi::c_void, pppropertybag: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWTSListener_Impl::GetConfiguration(this) {
+                    Ok(ok__) => {
+                        pppropertybag.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetConfiguration: GetConfiguration::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
 impl windows_core::RuntimeName for IWTSListener {}
 windows_core::imp::define_interface!(IWTSListenerCallback, IWTSListenerCallback_Vtbl, 0xa1230203_d6a7_11d8_b9fd_000bdbd1f198);
 windows_core::imp::interface_hierarchy!(IWTSListenerCallback, windows_core::IUnknown);
@@ -7653,6 +11322,23 @@ impl IWTSListenerCallback_Vtbl {
         iid == &<IWTSListenerCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWTSListenerCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IWTSListenerCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnNewChannelConnection<Identity: windows_core::IUnknownImpl + IWTSListenerCallback_Impl>(this: *mut core::ffi::c_void, pchannel: *mut core::ffi::c_void, data: *mut core::ffi::c_void, pbaccept: *mut windows_core::BOOL, ppcallback: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSListenerCallback_Impl::OnNewChannelConnection(this, core::mem::transmute_copy(&pchannel), core::mem::transmute(&data), core::mem::transmute_copy(&pbaccept), core::mem::transmute_copy(&ppcallback)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnNewChannelConnection: OnNewChannelConnection::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWTSListenerCallback {}
 windows_core::imp::define_interface!(IWTSPlugin, IWTSPlugin_Vtbl, 0xa1230201_1439_4e62_a414_190d0ac3d40e);
 windows_core::imp::interface_hierarchy!(IWTSPlugin, windows_core::IUnknown);
@@ -7726,6 +11412,44 @@ impl IWTSPlugin_Vtbl {
         iid == &<IWTSPlugin as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWTSPlugin_Impl> windows_core::imp::TearOffVtable<Identity> for IWTSPlugin_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IWTSPlugin_Impl>(this: *mut core::ffi::c_void, pchannelmgr: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSPlugin_Impl::Initialize(this, core::mem::transmute_copy(&pchannelmgr)).into()
+            }
+        }
+        unsafe extern "system" fn Connected<Identity: windows_core::IUnknownImpl + IWTSPlugin_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSPlugin_Impl::Connected(this).into()
+            }
+        }
+        unsafe extern "system" fn Disconnected<Identity: windows_core::IUnknownImpl + IWTSPlugin_Impl>(this: *mut core::ffi::c_void, dwdisconnectcode: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSPlugin_Impl::Disconnected(this, core::mem::transmute_copy(&dwdisconnectcode)).into()
+            }
+        }
+        unsafe extern "system" fn Terminated<Identity: windows_core::IUnknownImpl + IWTSPlugin_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSPlugin_Impl::Terminated(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            Connected: Connected::<Identity>,
+            Disconnected: Disconnected::<Identity>,
+            Terminated: Terminated::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWTSPlugin {}
 windows_core::imp::define_interface!(IWTSPluginServiceProvider, IWTSPluginServiceProvider_Vtbl, 0xd3e07363_087c_476c_86a7_dbb15f46ddb4);
 windows_core::imp::interface_hierarchy!(IWTSPluginServiceProvider, windows_core::IUnknown);
@@ -7750,7 +11474,30 @@ impl IWTSPluginServiceProvider_Vtbl {
     pub const fn new<Identity: IWTSPluginServiceProvider_Impl, const OFFSET: isize>() -> Self {
         unsafe extern "system" fn GetService<Identity: IWTSPluginServiceProvider_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, serviceid: windows_core::GUID, ppunkobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWTSPluginServiceProvider_Impl::GetService(this, core::mem::transmute(&serviceid)) {
+                    Ok(ok__) => {
+                        ppunkobject.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(), GetService: GetService::<Identity, OFFSET> }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWTSPluginServiceProvider as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IWTSPluginServiceProvider_Impl> windows_core::imp::TearOffVtable<Identity> for IWTSPluginServiceProvider_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetService<Identity: windows_core::IUnknownImpl + IWTSPluginServiceProvider_Impl>(this: *mut core::ffi::c_void, serviceid: windows_core::GUID, ppunkobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWTSPluginServiceProvider_Impl::GetService(this, core::mem::transmute(&serviceid)) {
                     Ok(ok__) => {
                         ppunkobject.write(core::mem::transmute(ok__));
@@ -7760,11 +11507,8 @@ impl IWTSPluginServiceProvider_Vtbl {
                 }
             }
         }
-        Self { base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(), GetService: GetService::<Identity, OFFSET> }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWTSPluginServiceProvider as windows_core::Interface>::IID
-    }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetService: GetService::<Identity> }
+    };
 }
 impl windows_core::RuntimeName for IWTSPluginServiceProvider {}
 windows_core::imp::define_interface!(IWTSProtocolConnection, IWTSProtocolConnection_Vtbl, 0x23083765_9095_4648_98bf_ef81c914032d);
@@ -8120,6 +11864,207 @@ impl IWTSProtocolConnection_Vtbl {
         iid == &<IWTSProtocolConnection as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl> windows_core::imp::TearOffVtable<Identity> for IWTSProtocolConnection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetLogonErrorRedirector<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl>(this: *mut core::ffi::c_void, pplogonerrorredir: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWTSProtocolConnection_Impl::GetLogonErrorRedirector(this) {
+                    Ok(ok__) => {
+                        pplogonerrorredir.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SendPolicyData<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl>(this: *mut core::ffi::c_void, ppolicydata: *const WTS_POLICY_DATA) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolConnection_Impl::SendPolicyData(this, core::mem::transmute_copy(&ppolicydata)).into()
+            }
+        }
+        unsafe extern "system" fn AcceptConnection<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolConnection_Impl::AcceptConnection(this).into()
+            }
+        }
+        unsafe extern "system" fn GetClientData<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl>(this: *mut core::ffi::c_void, pclientdata: *mut WTS_CLIENT_DATA) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolConnection_Impl::GetClientData(this, core::mem::transmute_copy(&pclientdata)).into()
+            }
+        }
+        unsafe extern "system" fn GetUserCredentials<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl>(this: *mut core::ffi::c_void, pusercreds: *mut WTS_USER_CREDENTIAL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolConnection_Impl::GetUserCredentials(this, core::mem::transmute_copy(&pusercreds)).into()
+            }
+        }
+        unsafe extern "system" fn GetLicenseConnection<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl>(this: *mut core::ffi::c_void, pplicenseconnection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWTSProtocolConnection_Impl::GetLicenseConnection(this) {
+                    Ok(ok__) => {
+                        pplicenseconnection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AuthenticateClientToSession<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl>(this: *mut core::ffi::c_void, sessionid: *mut WTS_SESSION_ID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolConnection_Impl::AuthenticateClientToSession(this, core::mem::transmute_copy(&sessionid)).into()
+            }
+        }
+        unsafe extern "system" fn NotifySessionId<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl>(this: *mut core::ffi::c_void, sessionid: *const WTS_SESSION_ID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolConnection_Impl::NotifySessionId(this, core::mem::transmute_copy(&sessionid)).into()
+            }
+        }
+        unsafe extern "system" fn GetProtocolHandles<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl>(this: *mut core::ffi::c_void, pkeyboardhandle: *mut super::super::Foundation::HANDLE_PTR, pmousehandle: *mut super::super::Foundation::HANDLE_PTR, pbeephandle: *mut super::super::Foundation::HANDLE_PTR, pvideohandle: *mut super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolConnection_Impl::GetProtocolHandles(this, core::mem::transmute_copy(&pkeyboardhandle), core::mem::transmute_copy(&pmousehandle), core::mem::transmute_copy(&pbeephandle), core::mem::transmute_copy(&pvideohandle)).into()
+            }
+        }
+        unsafe extern "system" fn ConnectNotify<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl>(this: *mut core::ffi::c_void, sessionid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolConnection_Impl::ConnectNotify(this, core::mem::transmute_copy(&sessionid)).into()
+            }
+        }
+        unsafe extern "system" fn IsUserAllowedToLogon<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl>(this: *mut core::ffi::c_void, sessionid: u32, usertoken: super::super::Foundation::HANDLE_PTR, pdomainname: windows_core::PCWSTR, pusername: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolConnection_Impl::IsUserAllowedToLogon(this, core::mem::transmute_copy(&sessionid), core::mem::transmute_copy(&usertoken), core::mem::transmute(&pdomainname), core::mem::transmute(&pusername)).into()
+            }
+        }
+        unsafe extern "system" fn SessionArbitrationEnumeration<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl>(this: *mut core::ffi::c_void, husertoken: super::super::Foundation::HANDLE_PTR, bsinglesessionperuserenabled: windows_core::BOOL, psessionidarray: *mut u32, pdwsessionidentifiercount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolConnection_Impl::SessionArbitrationEnumeration(this, core::mem::transmute_copy(&husertoken), core::mem::transmute_copy(&bsinglesessionperuserenabled), core::mem::transmute_copy(&psessionidarray), core::mem::transmute_copy(&pdwsessionidentifiercount)).into()
+            }
+        }
+        unsafe extern "system" fn LogonNotify<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl>(this: *mut core::ffi::c_void, hclienttoken: super::super::Foundation::HANDLE_PTR, wszusername: windows_core::PCWSTR, wszdomainname: windows_core::PCWSTR, sessionid: *const WTS_SESSION_ID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolConnection_Impl::LogonNotify(this, core::mem::transmute_copy(&hclienttoken), core::mem::transmute(&wszusername), core::mem::transmute(&wszdomainname), core::mem::transmute_copy(&sessionid)).into()
+            }
+        }
+        unsafe extern "system" fn GetUserData<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl>(this: *mut core::ffi::c_void, ppolicydata: *const WTS_POLICY_DATA, pclientdata: *mut WTS_USER_DATA) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolConnection_Impl::GetUserData(this, core::mem::transmute_copy(&ppolicydata), core::mem::transmute_copy(&pclientdata)).into()
+            }
+        }
+        unsafe extern "system" fn DisconnectNotify<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolConnection_Impl::DisconnectNotify(this).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolConnection_Impl::Close(this).into()
+            }
+        }
+        unsafe extern "system" fn GetProtocolStatus<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl>(this: *mut core::ffi::c_void, pprotocolstatus: *mut WTS_PROTOCOL_STATUS) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolConnection_Impl::GetProtocolStatus(this, core::mem::transmute_copy(&pprotocolstatus)).into()
+            }
+        }
+        unsafe extern "system" fn GetLastInputTime<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl>(this: *mut core::ffi::c_void, plastinputtime: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWTSProtocolConnection_Impl::GetLastInputTime(this) {
+                    Ok(ok__) => {
+                        plastinputtime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetErrorInfo<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl>(this: *mut core::ffi::c_void, ulerror: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolConnection_Impl::SetErrorInfo(this, core::mem::transmute_copy(&ulerror)).into()
+            }
+        }
+        unsafe extern "system" fn SendBeep<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl>(this: *mut core::ffi::c_void, frequency: u32, duration: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolConnection_Impl::SendBeep(this, core::mem::transmute_copy(&frequency), core::mem::transmute_copy(&duration)).into()
+            }
+        }
+        unsafe extern "system" fn CreateVirtualChannel<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl>(this: *mut core::ffi::c_void, szendpointname: windows_core::PCSTR, bstatic: windows_core::BOOL, requestedpriority: u32, phchannel: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWTSProtocolConnection_Impl::CreateVirtualChannel(this, core::mem::transmute(&szendpointname), core::mem::transmute_copy(&bstatic), core::mem::transmute_copy(&requestedpriority)) {
+                    Ok(ok__) => {
+                        phchannel.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn QueryProperty<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl>(this: *mut core::ffi::c_void, querytype: windows_core::GUID, ulnumentriesin: u32, ulnumentriesout: u32, ppropertyentriesin: *const WTS_PROPERTY_VALUE, ppropertyentriesout: *mut WTS_PROPERTY_VALUE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolConnection_Impl::QueryProperty(this, core::mem::transmute(&querytype), core::mem::transmute_copy(&ulnumentriesin), core::mem::transmute_copy(&ulnumentriesout), core::mem::transmute_copy(&ppropertyentriesin), core::mem::transmute_copy(&ppropertyentriesout)).into()
+            }
+        }
+        unsafe extern "system" fn GetShadowConnection<Identity: windows_core::IUnknownImpl + IWTSProtocolConnection_Impl>(this: *mut core::ffi::c_void, ppshadowconnection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWTSProtocolConnection_Impl::GetShadowConnection(this) {
+                    Ok(ok__) => {
+                        ppshadowconnection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetLogonErrorRedirector: GetLogonErrorRedirector::<Identity>,
+            SendPolicyData: SendPolicyData::<Identity>,
+            AcceptConnection: AcceptConnection::<Identity>,
+            GetClientData: GetClientData::<Identity>,
+            GetUserCredentials: GetUserCredentials::<Identity>,
+            GetLicenseConnection: GetLicenseConnection::<Identity>,
+            AuthenticateClientToSession: AuthenticateClientToSession::<Identity>,
+            NotifySessionId: NotifySessionId::<Identity>,
+            GetProtocolHandles: GetProtocolHandles::<Identity>,
+            ConnectNotify: ConnectNotify::<Identity>,
+            IsUserAllowedToLogon: IsUserAllowedToLogon::<Identity>,
+            SessionArbitrationEnumeration: SessionArbitrationEnumeration::<Identity>,
+            LogonNotify: LogonNotify::<Identity>,
+            GetUserData: GetUserData::<Identity>,
+            DisconnectNotify: DisconnectNotify::<Identity>,
+            Close: Close::<Identity>,
+            GetProtocolStatus: GetProtocolStatus::<Identity>,
+            GetLastInputTime: GetLastInputTime::<Identity>,
+            SetErrorInfo: SetErrorInfo::<Identity>,
+            SendBeep: SendBeep::<Identity>,
+            CreateVirtualChannel: CreateVirtualChannel::<Identity>,
+            QueryProperty: QueryProperty::<Identity>,
+            GetShadowConnection: GetShadowConnection::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWTSProtocolConnection {}
 windows_core::imp::define_interface!(IWTSProtocolConnectionCallback, IWTSProtocolConnectionCallback_Vtbl, 0x23083765_75eb_41fe_b4fb_e086242afa0f);
 windows_core::imp::interface_hierarchy!(IWTSProtocolConnectionCallback, windows_core::IUnknown);
@@ -8202,6 +12147,51 @@ impl IWTSProtocolConnectionCallback_Vtbl {
         iid == &<IWTSProtocolConnectionCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWTSProtocolConnectionCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IWTSProtocolConnectionCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnReady<Identity: windows_core::IUnknownImpl + IWTSProtocolConnectionCallback_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolConnectionCallback_Impl::OnReady(this).into()
+            }
+        }
+        unsafe extern "system" fn BrokenConnection<Identity: windows_core::IUnknownImpl + IWTSProtocolConnectionCallback_Impl>(this: *mut core::ffi::c_void, reason: u32, source: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolConnectionCallback_Impl::BrokenConnection(this, core::mem::transmute_copy(&reason), core::mem::transmute_copy(&source)).into()
+            }
+        }
+        unsafe extern "system" fn StopScreenUpdates<Identity: windows_core::IUnknownImpl + IWTSProtocolConnectionCallback_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolConnectionCallback_Impl::StopScreenUpdates(this).into()
+            }
+        }
+        unsafe extern "system" fn RedrawWindow<Identity: windows_core::IUnknownImpl + IWTSProtocolConnectionCallback_Impl>(this: *mut core::ffi::c_void, rect: *const WTS_SMALL_RECT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolConnectionCallback_Impl::RedrawWindow(this, core::mem::transmute_copy(&rect)).into()
+            }
+        }
+        unsafe extern "system" fn DisplayIOCtl<Identity: windows_core::IUnknownImpl + IWTSProtocolConnectionCallback_Impl>(this: *mut core::ffi::c_void, displayioctl: *const WTS_DISPLAY_IOCTL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolConnectionCallback_Impl::DisplayIOCtl(this, core::mem::transmute_copy(&displayioctl)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnReady: OnReady::<Identity>,
+            BrokenConnection: BrokenConnection::<Identity>,
+            StopScreenUpdates: StopScreenUpdates::<Identity>,
+            RedrawWindow: RedrawWindow::<Identity>,
+            DisplayIOCtl: DisplayIOCtl::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWTSProtocolConnectionCallback {}
 windows_core::imp::define_interface!(IWTSProtocolLicenseConnection, IWTSProtocolLicenseConnection_Vtbl, 0x23083765_178c_4079_8e4a_fea6496a4d70);
 windows_core::imp::interface_hierarchy!(IWTSProtocolLicenseConnection, windows_core::IUnknown);
@@ -8272,6 +12262,44 @@ impl IWTSProtocolLicenseConnection_Vtbl {
         iid == &<IWTSProtocolLicenseConnection as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWTSProtocolLicenseConnection_Impl> windows_core::imp::TearOffVtable<Identity> for IWTSProtocolLicenseConnection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RequestLicensingCapabilities<Identity: windows_core::IUnknownImpl + IWTSProtocolLicenseConnection_Impl>(this: *mut core::ffi::c_void, pplicensecapabilities: *mut WTS_LICENSE_CAPABILITIES, pcblicensecapabilities: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolLicenseConnection_Impl::RequestLicensingCapabilities(this, core::mem::transmute_copy(&pplicensecapabilities), core::mem::transmute_copy(&pcblicensecapabilities)).into()
+            }
+        }
+        unsafe extern "system" fn SendClientLicense<Identity: windows_core::IUnknownImpl + IWTSProtocolLicenseConnection_Impl>(this: *mut core::ffi::c_void, pclientlicense: *const u8, cbclientlicense: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolLicenseConnection_Impl::SendClientLicense(this, core::mem::transmute_copy(&pclientlicense), core::mem::transmute_copy(&cbclientlicense)).into()
+            }
+        }
+        unsafe extern "system" fn RequestClientLicense<Identity: windows_core::IUnknownImpl + IWTSProtocolLicenseConnection_Impl>(this: *mut core::ffi::c_void, reserve1: *const u8, reserve2: u32, ppclientlicense: *mut u8, pcbclientlicense: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolLicenseConnection_Impl::RequestClientLicense(this, core::mem::transmute_copy(&reserve1), core::mem::transmute_copy(&reserve2), core::mem::transmute_copy(&ppclientlicense), core::mem::transmute_copy(&pcbclientlicense)).into()
+            }
+        }
+        unsafe extern "system" fn ProtocolComplete<Identity: windows_core::IUnknownImpl + IWTSProtocolLicenseConnection_Impl>(this: *mut core::ffi::c_void, ulcomplete: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolLicenseConnection_Impl::ProtocolComplete(this, core::mem::transmute_copy(&ulcomplete)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RequestLicensingCapabilities: RequestLicensingCapabilities::<Identity>,
+            SendClientLicense: SendClientLicense::<Identity>,
+            RequestClientLicense: RequestClientLicense::<Identity>,
+            ProtocolComplete: ProtocolComplete::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWTSProtocolLicenseConnection {}
 windows_core::imp::define_interface!(IWTSProtocolListener, IWTSProtocolListener_Vtbl, 0x23083765_45f0_4394_8f69_32b2bc0ef4ca);
 windows_core::imp::interface_hierarchy!(IWTSProtocolListener, windows_core::IUnknown);
@@ -8321,6 +12349,30 @@ impl IWTSProtocolListener_Vtbl {
         iid == &<IWTSProtocolListener as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWTSProtocolListener_Impl> windows_core::imp::TearOffVtable<Identity> for IWTSProtocolListener_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StartListen<Identity: windows_core::IUnknownImpl + IWTSProtocolListener_Impl>(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolListener_Impl::StartListen(this, core::mem::transmute_copy(&pcallback)).into()
+            }
+        }
+        unsafe extern "system" fn StopListen<Identity: windows_core::IUnknownImpl + IWTSProtocolListener_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolListener_Impl::StopListen(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            StartListen: StartListen::<Identity>,
+            StopListen: StopListen::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWTSProtocolListener {}
 windows_core::imp::define_interface!(IWTSProtocolListenerCallback, IWTSProtocolListenerCallback_Vtbl, 0x23083765_1a2d_4de2_97de_4a35f260f0b3);
 windows_core::imp::interface_hierarchy!(IWTSProtocolListenerCallback, windows_core::IUnknown);
@@ -8364,6 +12416,26 @@ impl IWTSProtocolListenerCallback_Vtbl {
         iid == &<IWTSProtocolListenerCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWTSProtocolListenerCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IWTSProtocolListenerCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnConnected<Identity: windows_core::IUnknownImpl + IWTSProtocolListenerCallback_Impl>(this: *mut core::ffi::c_void, pconnection: *mut core::ffi::c_void, pcallback: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWTSProtocolListenerCallback_Impl::OnConnected(this, core::mem::transmute_copy(&pconnection)) {
+                    Ok(ok__) => {
+                        pcallback.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, OnConnected: OnConnected::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWTSProtocolListenerCallback {}
 windows_core::imp::define_interface!(IWTSProtocolLogonErrorRedirector, IWTSProtocolLogonErrorRedirector_Vtbl, 0xfd9b61a7_2916_4627_8dee_4328711ad6cb);
 windows_core::imp::interface_hierarchy!(IWTSProtocolLogonErrorRedirector, windows_core::IUnknown);
@@ -8416,17 +12488,76 @@ pub trait IWTSProtocolLogonErrorRedirector_Impl: windows_core::IUnknownImpl {
     fn RedirectMessage(&self, pszcaption: &windows_core::PCWSTR, pszmessage: &windows_core::PCWSTR, utype: u32) -> windows_core::Result<WTS_LOGON_ERROR_REDIRECTOR_RESPONSE>;
     fn RedirectLogonError(&self, ntsstatus: i32, ntssubstatus: i32, pszcaption: &windows_core::PCWSTR, pszmessage: &windows_core::PCWSTR, utype: u32) -> windows_core::Result<WTS_LOGON_ERROR_REDIRECTOR_RESPONSE>;
 }
-impl IWTSProtocolLogonErrorRedirector_Vtbl {
-    pub const fn new<Identity: IWTSProtocolLogonErrorRedirector_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn OnBeginPainting<Identity: IWTSProtocolLogonErrorRedirector_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl IWTSProtocolLogonErrorRedirector_Vtbl {
+    pub const fn new<Identity: IWTSProtocolLogonErrorRedirector_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn OnBeginPainting<Identity: IWTSProtocolLogonErrorRedirector_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWTSProtocolLogonErrorRedirector_Impl::OnBeginPainting(this).into()
+            }
+        }
+        unsafe extern "system" fn RedirectStatus<Identity: IWTSProtocolLogonErrorRedirector_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pszmessage: windows_core::PCWSTR, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWTSProtocolLogonErrorRedirector_Impl::RedirectStatus(this, core::mem::transmute(&pszmessage)) {
+                    Ok(ok__) => {
+                        presponse.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RedirectMessage<Identity: IWTSProtocolLogonErrorRedirector_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pszcaption: windows_core::PCWSTR, pszmessage: windows_core::PCWSTR, utype: u32, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWTSProtocolLogonErrorRedirector_Impl::RedirectMessage(this, core::mem::transmute(&pszcaption), core::mem::transmute(&pszmessage), core::mem::transmute_copy(&utype)) {
+                    Ok(ok__) => {
+                        presponse.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RedirectLogonError<Identity: IWTSProtocolLogonErrorRedirector_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ntsstatus: i32, ntssubstatus: i32, pszcaption: windows_core::PCWSTR, pszmessage: windows_core::PCWSTR, utype: u32, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWTSProtocolLogonErrorRedirector_Impl::RedirectLogonError(this, core::mem::transmute_copy(&ntsstatus), core::mem::transmute_copy(&ntssubstatus), core::mem::transmute(&pszcaption), core::mem::transmute(&pszmessage), core::mem::transmute_copy(&utype)) {
+                    Ok(ok__) => {
+                        presponse.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            OnBeginPainting: OnBeginPainting::<Identity, OFFSET>,
+            RedirectStatus: RedirectStatus::<Identity, OFFSET>,
+            RedirectMessage: RedirectMessage::<Identity, OFFSET>,
+            RedirectLogonError: RedirectLogonError::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWTSProtocolLogonErrorRedirector as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IWTSProtocolLogonErrorRedirector_Impl> windows_core::imp::TearOffVtable<Identity> for IWTSProtocolLogonErrorRedirector_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnBeginPainting<Identity: windows_core::IUnknownImpl + IWTSProtocolLogonErrorRedirector_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWTSProtocolLogonErrorRedirector_Impl::OnBeginPainting(this).into()
             }
         }
-        unsafe extern "system" fn RedirectStatus<Identity: IWTSProtocolLogonErrorRedirector_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pszmessage: windows_core::PCWSTR, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
+        unsafe extern "system" fn RedirectStatus<Identity: windows_core::IUnknownImpl + IWTSProtocolLogonErrorRedirector_Impl>(this: *mut core::ffi::c_void, pszmessage: windows_core::PCWSTR, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWTSProtocolLogonErrorRedirector_Impl::RedirectStatus(this, core::mem::transmute(&pszmessage)) {
                     Ok(ok__) => {
                         presponse.write(core::mem::transmute(ok__));
@@ -8436,9 +12567,9 @@ impl IWTSProtocolLogonErrorRedirector_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn RedirectMessage<Identity: IWTSProtocolLogonErrorRedirector_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pszcaption: windows_core::PCWSTR, pszmessage: windows_core::PCWSTR, utype: u32, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
+        unsafe extern "system" fn RedirectMessage<Identity: windows_core::IUnknownImpl + IWTSProtocolLogonErrorRedirector_Impl>(this: *mut core::ffi::c_void, pszcaption: windows_core::PCWSTR, pszmessage: windows_core::PCWSTR, utype: u32, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWTSProtocolLogonErrorRedirector_Impl::RedirectMessage(this, core::mem::transmute(&pszcaption), core::mem::transmute(&pszmessage), core::mem::transmute_copy(&utype)) {
                     Ok(ok__) => {
                         presponse.write(core::mem::transmute(ok__));
@@ -8448,9 +12579,9 @@ impl IWTSProtocolLogonErrorRedirector_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn RedirectLogonError<Identity: IWTSProtocolLogonErrorRedirector_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ntsstatus: i32, ntssubstatus: i32, pszcaption: windows_core::PCWSTR, pszmessage: windows_core::PCWSTR, utype: u32, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
+        unsafe extern "system" fn RedirectLogonError<Identity: windows_core::IUnknownImpl + IWTSProtocolLogonErrorRedirector_Impl>(this: *mut core::ffi::c_void, ntsstatus: i32, ntssubstatus: i32, pszcaption: windows_core::PCWSTR, pszmessage: windows_core::PCWSTR, utype: u32, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWTSProtocolLogonErrorRedirector_Impl::RedirectLogonError(this, core::mem::transmute_copy(&ntsstatus), core::mem::transmute_copy(&ntssubstatus), core::mem::transmute(&pszcaption), core::mem::transmute(&pszmessage), core::mem::transmute_copy(&utype)) {
                     Ok(ok__) => {
                         presponse.write(core::mem::transmute(ok__));
@@ -8461,16 +12592,13 @@ impl IWTSProtocolLogonErrorRedirector_Vtbl {
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            OnBeginPainting: OnBeginPainting::<Identity, OFFSET>,
-            RedirectStatus: RedirectStatus::<Identity, OFFSET>,
-            RedirectMessage: RedirectMessage::<Identity, OFFSET>,
-            RedirectLogonError: RedirectLogonError::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnBeginPainting: OnBeginPainting::<Identity>,
+            RedirectStatus: RedirectStatus::<Identity>,
+            RedirectMessage: RedirectMessage::<Identity>,
+            RedirectLogonError: RedirectLogonError::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWTSProtocolLogonErrorRedirector as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IWTSProtocolLogonErrorRedirector {}
 windows_core::imp::define_interface!(IWTSProtocolManager, IWTSProtocolManager_Vtbl, 0xf9eaf6cc_ed79_4f01_821d_1f881b9f66cc);
@@ -8566,6 +12694,57 @@ impl IWTSProtocolManager_Vtbl {
         iid == &<IWTSProtocolManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWTSProtocolManager_Impl> windows_core::imp::TearOffVtable<Identity> for IWTSProtocolManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateListener<Identity: windows_core::IUnknownImpl + IWTSProtocolManager_Impl>(this: *mut core::ffi::c_void, wszlistenername: windows_core::PCWSTR, pprotocollistener: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWTSProtocolManager_Impl::CreateListener(this, core::mem::transmute(&wszlistenername)) {
+                    Ok(ok__) => {
+                        pprotocollistener.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NotifyServiceStateChange<Identity: windows_core::IUnknownImpl + IWTSProtocolManager_Impl>(this: *mut core::ffi::c_void, ptsservicestatechange: *const WTS_SERVICE_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolManager_Impl::NotifyServiceStateChange(this, core::mem::transmute_copy(&ptsservicestatechange)).into()
+            }
+        }
+        unsafe extern "system" fn NotifySessionOfServiceStart<Identity: windows_core::IUnknownImpl + IWTSProtocolManager_Impl>(this: *mut core::ffi::c_void, sessionid: *const WTS_SESSION_ID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolManager_Impl::NotifySessionOfServiceStart(this, core::mem::transmute_copy(&sessionid)).into()
+            }
+        }
+        unsafe extern "system" fn NotifySessionOfServiceStop<Identity: windows_core::IUnknownImpl + IWTSProtocolManager_Impl>(this: *mut core::ffi::c_void, sessionid: *const WTS_SESSION_ID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolManager_Impl::NotifySessionOfServiceStop(this, core::mem::transmute_copy(&sessionid)).into()
+            }
+        }
+        unsafe extern "system" fn NotifySessionStateChange<Identity: windows_core::IUnknownImpl + IWTSProtocolManager_Impl>(this: *mut core::ffi::c_void, sessionid: *const WTS_SESSION_ID, eventid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolManager_Impl::NotifySessionStateChange(this, core::mem::transmute_copy(&sessionid), core::mem::transmute_copy(&eventid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateListener: CreateListener::<Identity>,
+            NotifyServiceStateChange: NotifyServiceStateChange::<Identity>,
+            NotifySessionOfServiceStart: NotifySessionOfServiceStart::<Identity>,
+            NotifySessionOfServiceStop: NotifySessionOfServiceStop::<Identity>,
+            NotifySessionStateChange: NotifySessionStateChange::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWTSProtocolManager {}
 windows_core::imp::define_interface!(IWTSProtocolShadowCallback, IWTSProtocolShadowCallback_Vtbl, 0x503a2504_aae5_4ab1_93e0_6d1c4bc6f71a);
 windows_core::imp::interface_hierarchy!(IWTSProtocolShadowCallback, windows_core::IUnknown);
@@ -8616,6 +12795,30 @@ impl IWTSProtocolShadowCallback_Vtbl {
         iid == &<IWTSProtocolShadowCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWTSProtocolShadowCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IWTSProtocolShadowCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StopShadow<Identity: windows_core::IUnknownImpl + IWTSProtocolShadowCallback_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolShadowCallback_Impl::StopShadow(this).into()
+            }
+        }
+        unsafe extern "system" fn InvokeTargetShadow<Identity: windows_core::IUnknownImpl + IWTSProtocolShadowCallback_Impl>(this: *mut core::ffi::c_void, ptargetservername: windows_core::PCWSTR, targetsessionid: u32, pparam1: *const u8, param1size: u32, pparam2: *const u8, param2size: u32, pparam3: *const u8, param3size: u32, pparam4: *const u8, param4size: u32, pclientname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolShadowCallback_Impl::InvokeTargetShadow(this, core::mem::transmute(&ptargetservername), core::mem::transmute_copy(&targetsessionid), core::mem::transmute_copy(&pparam1), core::mem::transmute_copy(&param1size), core::mem::transmute_copy(&pparam2), core::mem::transmute_copy(&param2size), core::mem::transmute_copy(&pparam3), core::mem::transmute_copy(&param3size), core::mem::transmute_copy(&pparam4), core::mem::transmute_copy(&param4size), core::mem::transmute(&pclientname)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            StopShadow: StopShadow::<Identity>,
+            InvokeTargetShadow: InvokeTargetShadow::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWTSProtocolShadowCallback {}
 windows_core::imp::define_interface!(IWTSProtocolShadowConnection, IWTSProtocolShadowConnection_Vtbl, 0xee3b0c14_37fb_456b_bab3_6d6cd51e13bf);
 windows_core::imp::interface_hierarchy!(IWTSProtocolShadowConnection, windows_core::IUnknown);
@@ -8681,6 +12884,37 @@ impl IWTSProtocolShadowConnection_Vtbl {
         iid == &<IWTSProtocolShadowConnection as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWTSProtocolShadowConnection_Impl> windows_core::imp::TearOffVtable<Identity> for IWTSProtocolShadowConnection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Start<Identity: windows_core::IUnknownImpl + IWTSProtocolShadowConnection_Impl>(this: *mut core::ffi::c_void, ptargetservername: windows_core::PCWSTR, targetsessionid: u32, hotkeyvk: u8, hotkeymodifiers: u16, pshadowcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolShadowConnection_Impl::Start(this, core::mem::transmute(&ptargetservername), core::mem::transmute_copy(&targetsessionid), core::mem::transmute_copy(&hotkeyvk), core::mem::transmute_copy(&hotkeymodifiers), core::mem::transmute_copy(&pshadowcallback)).into()
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + IWTSProtocolShadowConnection_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolShadowConnection_Impl::Stop(this).into()
+            }
+        }
+        unsafe extern "system" fn DoTarget<Identity: windows_core::IUnknownImpl + IWTSProtocolShadowConnection_Impl>(this: *mut core::ffi::c_void, pparam1: *const u8, param1size: u32, pparam2: *const u8, param2size: u32, pparam3: *const u8, param3size: u32, pparam4: *const u8, param4size: u32, pclientname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSProtocolShadowConnection_Impl::DoTarget(this, core::mem::transmute_copy(&pparam1), core::mem::transmute_copy(&param1size), core::mem::transmute_copy(&pparam2), core::mem::transmute_copy(&param2size), core::mem::transmute_copy(&pparam3), core::mem::transmute_copy(&param3size), core::mem::transmute_copy(&pparam4), core::mem::transmute_copy(&param4size), core::mem::transmute(&pclientname)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Start: Start::<Identity>,
+            Stop: Stop::<Identity>,
+            DoTarget: DoTarget::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWTSProtocolShadowConnection {}
 windows_core::imp::define_interface!(IWTSSBPlugin, IWTSSBPlugin_Vtbl, 0xdc44be78_b18d_4399_b210_641bf67a002c);
 windows_core::imp::interface_hierarchy!(IWTSSBPlugin, windows_core::IUnknown);
@@ -8795,6 +13029,64 @@ impl IWTSSBPlugin_Vtbl {
         iid == &<IWTSSBPlugin as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWTSSBPlugin_Impl> windows_core::imp::TearOffVtable<Identity> for IWTSSBPlugin_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Initialize<Identity: windows_core::IUnknownImpl + IWTSSBPlugin_Impl>(this: *mut core::ffi::c_void, plugincapabilities: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWTSSBPlugin_Impl::Initialize(this) {
+                    Ok(ok__) => {
+                        plugincapabilities.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn WTSSBX_MachineChangeNotification<Identity: windows_core::IUnknownImpl + IWTSSBPlugin_Impl>(this: *mut core::ffi::c_void, notificationtype: WTSSBX_NOTIFICATION_TYPE, machineid: i32, pmachineinfo: *const WTSSBX_MACHINE_INFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSSBPlugin_Impl::WTSSBX_MachineChangeNotification(this, core::mem::transmute_copy(&notificationtype), core::mem::transmute_copy(&machineid), core::mem::transmute_copy(&pmachineinfo)).into()
+            }
+        }
+        unsafe extern "system" fn WTSSBX_SessionChangeNotification<Identity: windows_core::IUnknownImpl + IWTSSBPlugin_Impl>(this: *mut core::ffi::c_void, notificationtype: WTSSBX_NOTIFICATION_TYPE, machineid: i32, numofsessions: u32, sessioninfo: *const WTSSBX_SESSION_INFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSSBPlugin_Impl::WTSSBX_SessionChangeNotification(this, core::mem::transmute_copy(&notificationtype), core::mem::transmute_copy(&machineid), core::mem::transmute_copy(&numofsessions), core::mem::transmute_copy(&sessioninfo)).into()
+            }
+        }
+        unsafe extern "system" fn WTSSBX_GetMostSuitableServer<Identity: windows_core::IUnknownImpl + IWTSSBPlugin_Impl>(this: *mut core::ffi::c_void, username: windows_core::PCWSTR, domainname: windows_core::PCWSTR, applicationtype: windows_core::PCWSTR, farmname: windows_core::PCWSTR, pmachineid: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSSBPlugin_Impl::WTSSBX_GetMostSuitableServer(this, core::mem::transmute(&username), core::mem::transmute(&domainname), core::mem::transmute(&applicationtype), core::mem::transmute(&farmname), core::mem::transmute_copy(&pmachineid)).into()
+            }
+        }
+        unsafe extern "system" fn Terminated<Identity: windows_core::IUnknownImpl + IWTSSBPlugin_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSSBPlugin_Impl::Terminated(this).into()
+            }
+        }
+        unsafe extern "system" fn WTSSBX_GetUserExternalSession<Identity: windows_core::IUnknownImpl + IWTSSBPlugin_Impl>(this: *mut core::ffi::c_void, username: windows_core::PCWSTR, domainname: windows_core::PCWSTR, applicationtype: windows_core::PCWSTR, redirectorinternalip: *const WTSSBX_IP_ADDRESS, psessionid: *mut u32, pmachineconnectinfo: *mut WTSSBX_MACHINE_CONNECT_INFO) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSSBPlugin_Impl::WTSSBX_GetUserExternalSession(this, core::mem::transmute(&username), core::mem::transmute(&domainname), core::mem::transmute(&applicationtype), core::mem::transmute_copy(&redirectorinternalip), core::mem::transmute_copy(&psessionid), core::mem::transmute_copy(&pmachineconnectinfo)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Initialize: Initialize::<Identity>,
+            WTSSBX_MachineChangeNotification: WTSSBX_MachineChangeNotification::<Identity>,
+            WTSSBX_SessionChangeNotification: WTSSBX_SessionChangeNotification::<Identity>,
+            WTSSBX_GetMostSuitableServer: WTSSBX_GetMostSuitableServer::<Identity>,
+            Terminated: Terminated::<Identity>,
+            WTSSBX_GetUserExternalSession: WTSSBX_GetUserExternalSession::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWTSSBPlugin {}
 windows_core::imp::define_interface!(IWTSVirtualChannel, IWTSVirtualChannel_Vtbl, 0xa1230207_d6a7_11d8_b9fd_000bdbd1f198);
 windows_core::imp::interface_hierarchy!(IWTSVirtualChannel, windows_core::IUnknown);
@@ -8840,6 +13132,26 @@ impl IWTSVirtualChannel_Vtbl {
         iid == &<IWTSVirtualChannel as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWTSVirtualChannel_Impl> windows_core::imp::TearOffVtable<Identity> for IWTSVirtualChannel_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Write<Identity: windows_core::IUnknownImpl + IWTSVirtualChannel_Impl>(this: *mut core::ffi::c_void, cbsize: u32, pbuffer: *const u8, preserved: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSVirtualChannel_Impl::Write(this, core::mem::transmute_copy(&cbsize), core::mem::transmute_copy(&pbuffer), core::mem::transmute_copy(&preserved)).into()
+            }
+        }
+        unsafe extern "system" fn Close<Identity: windows_core::IUnknownImpl + IWTSVirtualChannel_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSVirtualChannel_Impl::Close(this).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Write: Write::<Identity>, Close: Close::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWTSVirtualChannel {}
 windows_core::imp::define_interface!(IWTSVirtualChannelCallback, IWTSVirtualChannelCallback_Vtbl, 0xa1230204_d6a7_11d8_b9fd_000bdbd1f198);
 windows_core::imp::interface_hierarchy!(IWTSVirtualChannelCallback, windows_core::IUnknown);
@@ -8886,6 +13198,30 @@ impl IWTSVirtualChannelCallback_Vtbl {
         iid == &<IWTSVirtualChannelCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWTSVirtualChannelCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IWTSVirtualChannelCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn OnDataReceived<Identity: windows_core::IUnknownImpl + IWTSVirtualChannelCallback_Impl>(this: *mut core::ffi::c_void, cbsize: u32, pbuffer: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSVirtualChannelCallback_Impl::OnDataReceived(this, core::mem::transmute_copy(&cbsize), core::mem::transmute_copy(&pbuffer)).into()
+            }
+        }
+        unsafe extern "system" fn OnClose<Identity: windows_core::IUnknownImpl + IWTSVirtualChannelCallback_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWTSVirtualChannelCallback_Impl::OnClose(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            OnDataReceived: OnDataReceived::<Identity>,
+            OnClose: OnClose::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWTSVirtualChannelCallback {}
 windows_core::imp::define_interface!(IWTSVirtualChannelManager, IWTSVirtualChannelManager_Vtbl, 0xa1230205_d6a7_11d8_b9fd_000bdbd1f198);
 windows_core::imp::interface_hierarchy!(IWTSVirtualChannelManager, windows_core::IUnknown);
@@ -8930,6 +13266,26 @@ impl IWTSVirtualChannelManager_Vtbl {
         iid == &<IWTSVirtualChannelManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWTSVirtualChannelManager_Impl> windows_core::imp::TearOffVtable<Identity> for IWTSVirtualChannelManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateListener<Identity: windows_core::IUnknownImpl + IWTSVirtualChannelManager_Impl>(this: *mut core::ffi::c_void, pszchannelname: windows_core::PCSTR, uflags: u32, plistenercallback: *mut core::ffi::c_void, pplistener: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWTSVirtualChannelManager_Impl::CreateListener(this, core::mem::transmute(&pszchannelname), core::mem::transmute_copy(&uflags), core::mem::transmute_copy(&plistenercallback)) {
+                    Ok(ok__) => {
+                        pplistener.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, CreateListener: CreateListener::<Identity> }
+    };
+}
 impl windows_core::RuntimeName for IWTSVirtualChannelManager {}
 windows_core::imp::define_interface!(IWorkspace, IWorkspace_Vtbl, 0xb922bbb8_4c55_4fea_8496_beb0b44285e5);
 windows_core::imp::interface_hierarchy!(IWorkspace, windows_core::IUnknown);
@@ -9017,6 +13373,50 @@ impl IWorkspace_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IWorkspace_Impl> windows_core::imp::TearOffVtable<Identity> for IWorkspace_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetWorkspaceNames<Identity: windows_core::IUnknownImpl + IWorkspace_Impl>(this: *mut core::ffi::c_void, psawkspnames: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWorkspace_Impl::GetWorkspaceNames(this) {
+                    Ok(ok__) => {
+                        psawkspnames.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn StartRemoteApplication<Identity: windows_core::IUnknownImpl + IWorkspace_Impl>(this: *mut core::ffi::c_void, bstrworkspaceid: *mut core::ffi::c_void, psaparams: *const super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWorkspace_Impl::StartRemoteApplication(this, core::mem::transmute(&bstrworkspaceid), core::mem::transmute_copy(&psaparams)).into()
+            }
+        }
+        unsafe extern "system" fn GetProcessId<Identity: windows_core::IUnknownImpl + IWorkspace_Impl>(this: *mut core::ffi::c_void, pulprocessid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWorkspace_Impl::GetProcessId(this) {
+                    Ok(ok__) => {
+                        pulprocessid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetWorkspaceNames: GetWorkspaceNames::<Identity>,
+            StartRemoteApplication: StartRemoteApplication::<Identity>,
+            GetProcessId: GetProcessId::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IWorkspace {}
 windows_core::imp::define_interface!(IWorkspace2, IWorkspace2_Vtbl, 0x96d8d7cf_783e_4286_834c_ebc0e95f783c);
 impl core::ops::Deref for IWorkspace2 {
@@ -9061,6 +13461,21 @@ impl IWorkspace2_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IWorkspace2_Impl> windows_core::imp::TearOffVtable<Identity> for IWorkspace2_Vtbl
+where
+    IWorkspace_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StartRemoteApplicationEx<Identity: windows_core::IUnknownImpl + IWorkspace2_Impl>(this: *mut core::ffi::c_void, bstrworkspaceid: *mut core::ffi::c_void, bstrrequestingappid: *mut core::ffi::c_void, bstrrequestingappfamilyname: *mut core::ffi::c_void, blaunchintoimmersiveclient: super::super::Foundation::VARIANT_BOOL, bstrimmersiveclientactivationcontext: *mut core::ffi::c_void, psaparams: *const super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWorkspace2_Impl::StartRemoteApplicationEx(this, core::mem::transmute(&bstrworkspaceid), core::mem::transmute(&bstrrequestingappid), core::mem::transmute(&bstrrequestingappfamilyname), core::mem::transmute_copy(&blaunchintoimmersiveclient), core::mem::transmute(&bstrimmersiveclientactivationcontext), core::mem::transmute_copy(&psaparams)).into()
+            }
+        }
+        Self { base__: <IWorkspace_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, StartRemoteApplicationEx: StartRemoteApplicationEx::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IWorkspace2 {}
 windows_core::imp::define_interface!(IWorkspace3, IWorkspace3_Vtbl, 0x1becbe4a_d654_423b_afeb_be8d532c13c6);
 impl core::ops::Deref for IWorkspace3 {
@@ -9125,6 +13540,37 @@ impl IWorkspace3_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + IWorkspace3_Impl> windows_core::imp::TearOffVtable<Identity> for IWorkspace3_Vtbl
+where
+    IWorkspace2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetClaimsToken2<Identity: windows_core::IUnknownImpl + IWorkspace3_Impl>(this: *mut core::ffi::c_void, bstrclaimshint: *mut core::ffi::c_void, bstruserhint: *mut core::ffi::c_void, claimcookie: u32, hwndcreduiparent: u32, rectcreduiparent: super::super::Foundation::RECT, pbstraccesstoken: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWorkspace3_Impl::GetClaimsToken2(this, core::mem::transmute(&bstrclaimshint), core::mem::transmute(&bstruserhint), core::mem::transmute_copy(&claimcookie), core::mem::transmute_copy(&hwndcreduiparent), core::mem::transmute(&rectcreduiparent)) {
+                    Ok(ok__) => {
+                        pbstraccesstoken.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetClaimsToken<Identity: windows_core::IUnknownImpl + IWorkspace3_Impl>(this: *mut core::ffi::c_void, bstraccesstoken: *mut core::ffi::c_void, ullaccesstokenexpiration: u64, bstrrefreshtoken: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWorkspace3_Impl::SetClaimsToken(this, core::mem::transmute(&bstraccesstoken), core::mem::transmute_copy(&ullaccesstokenexpiration), core::mem::transmute(&bstrrefreshtoken)).into()
+            }
+        }
+        Self {
+            base__: <IWorkspace2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetClaimsToken2: GetClaimsToken2::<Identity>,
+            SetClaimsToken: SetClaimsToken::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for IWorkspace3 {}
 windows_core::imp::define_interface!(IWorkspaceClientExt, IWorkspaceClientExt_Vtbl, 0x12b952f4_41ca_4f21_a829_a6d07d9a16e5);
 windows_core::imp::interface_hierarchy!(IWorkspaceClientExt, windows_core::IUnknown);
@@ -9158,11 +13604,57 @@ pub trait IWorkspaceClientExt_Impl: windows_core::IUnknownImpl {
     fn GetResourceDisplayName(&self) -> windows_core::Result<windows_core::BSTR>;
     fn IssueDisconnect(&self) -> windows_core::Result<()>;
 }
-impl IWorkspaceClientExt_Vtbl {
-    pub const fn new<Identity: IWorkspaceClientExt_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn GetResourceId<Identity: IWorkspaceClientExt_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrworkspaceid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+impl IWorkspaceClientExt_Vtbl {
+    pub const fn new<Identity: IWorkspaceClientExt_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn GetResourceId<Identity: IWorkspaceClientExt_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrworkspaceid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWorkspaceClientExt_Impl::GetResourceId(this) {
+                    Ok(ok__) => {
+                        bstrworkspaceid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetResourceDisplayName<Identity: IWorkspaceClientExt_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrworkspacedisplayname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWorkspaceClientExt_Impl::GetResourceDisplayName(this) {
+                    Ok(ok__) => {
+                        bstrworkspacedisplayname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IssueDisconnect<Identity: IWorkspaceClientExt_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IWorkspaceClientExt_Impl::IssueDisconnect(this).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            GetResourceId: GetResourceId::<Identity, OFFSET>,
+            GetResourceDisplayName: GetResourceDisplayName::<Identity, OFFSET>,
+            IssueDisconnect: IssueDisconnect::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWorkspaceClientExt as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + IWorkspaceClientExt_Impl> windows_core::imp::TearOffVtable<Identity> for IWorkspaceClientExt_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetResourceId<Identity: windows_core::IUnknownImpl + IWorkspaceClientExt_Impl>(this: *mut core::ffi::c_void, bstrworkspaceid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWorkspaceClientExt_Impl::GetResourceId(this) {
                     Ok(ok__) => {
                         bstrworkspaceid.write(core::mem::transmute(ok__));
@@ -9172,9 +13664,9 @@ impl IWorkspaceClientExt_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetResourceDisplayName<Identity: IWorkspaceClientExt_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, bstrworkspacedisplayname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetResourceDisplayName<Identity: windows_core::IUnknownImpl + IWorkspaceClientExt_Impl>(this: *mut core::ffi::c_void, bstrworkspacedisplayname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWorkspaceClientExt_Impl::GetResourceDisplayName(this) {
                     Ok(ok__) => {
                         bstrworkspacedisplayname.write(core::mem::transmute(ok__));
@@ -9184,22 +13676,19 @@ impl IWorkspaceClientExt_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn IssueDisconnect<Identity: IWorkspaceClientExt_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn IssueDisconnect<Identity: windows_core::IUnknownImpl + IWorkspaceClientExt_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IWorkspaceClientExt_Impl::IssueDisconnect(this).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            GetResourceId: GetResourceId::<Identity, OFFSET>,
-            GetResourceDisplayName: GetResourceDisplayName::<Identity, OFFSET>,
-            IssueDisconnect: IssueDisconnect::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetResourceId: GetResourceId::<Identity>,
+            GetResourceDisplayName: GetResourceDisplayName::<Identity>,
+            IssueDisconnect: IssueDisconnect::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWorkspaceClientExt as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for IWorkspaceClientExt {}
 windows_core::imp::define_interface!(IWorkspaceRegistration, IWorkspaceRegistration_Vtbl, 0xb922bbb8_4c55_4fea_8496_beb0b44285e6);
@@ -9259,6 +13748,36 @@ impl IWorkspaceRegistration_Vtbl {
         iid == &<IWorkspaceRegistration as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWorkspaceRegistration_Impl> windows_core::imp::TearOffVtable<Identity> for IWorkspaceRegistration_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddResource<Identity: windows_core::IUnknownImpl + IWorkspaceRegistration_Impl>(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWorkspaceRegistration_Impl::AddResource(this, core::mem::transmute_copy(&punk)) {
+                    Ok(ok__) => {
+                        pdwcookie.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveResource<Identity: windows_core::IUnknownImpl + IWorkspaceRegistration_Impl>(this: *mut core::ffi::c_void, dwcookieconnection: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWorkspaceRegistration_Impl::RemoveResource(this, core::mem::transmute_copy(&dwcookieconnection)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddResource: AddResource::<Identity>,
+            RemoveResource: RemoveResource::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWorkspaceRegistration {}
 windows_core::imp::define_interface!(IWorkspaceRegistration2, IWorkspaceRegistration2_Vtbl, 0xcf59f654_39bb_44d8_94d0_4635728957e9);
 impl core::ops::Deref for IWorkspaceRegistration2 {
@@ -9314,6 +13833,30 @@ impl IWorkspaceRegistration2_Vtbl {
         iid == &<IWorkspaceRegistration2 as windows_core::Interface>::IID || iid == &<IWorkspaceRegistration as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWorkspaceRegistration2_Impl> windows_core::imp::TearOffVtable<Identity> for IWorkspaceRegistration2_Vtbl
+where
+    IWorkspaceRegistration_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddResourceEx<Identity: windows_core::IUnknownImpl + IWorkspaceRegistration2_Impl>(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void, bstreventloguploadaddress: *mut core::ffi::c_void, pdwcookie: *mut u32, correlationid: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWorkspaceRegistration2_Impl::AddResourceEx(this, core::mem::transmute_copy(&punk), core::mem::transmute(&bstreventloguploadaddress), core::mem::transmute_copy(&pdwcookie), core::mem::transmute(&correlationid)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveResourceEx<Identity: windows_core::IUnknownImpl + IWorkspaceRegistration2_Impl>(this: *mut core::ffi::c_void, dwcookieconnection: u32, correlationid: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWorkspaceRegistration2_Impl::RemoveResourceEx(this, core::mem::transmute_copy(&dwcookieconnection), core::mem::transmute(&correlationid)).into()
+            }
+        }
+        Self {
+            base__: <IWorkspaceRegistration_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddResourceEx: AddResourceEx::<Identity>,
+            RemoveResourceEx: RemoveResourceEx::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWorkspaceRegistration2 {}
 windows_core::imp::define_interface!(IWorkspaceReportMessage, IWorkspaceReportMessage_Vtbl, 0xa7c06739_500f_4e8c_99a8_2bd6955899eb);
 windows_core::imp::interface_hierarchy!(IWorkspaceReportMessage, windows_core::IUnknown);
@@ -9381,6 +13924,43 @@ impl IWorkspaceReportMessage_Vtbl {
         iid == &<IWorkspaceReportMessage as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWorkspaceReportMessage_Impl> windows_core::imp::TearOffVtable<Identity> for IWorkspaceReportMessage_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterErrorLogMessage<Identity: windows_core::IUnknownImpl + IWorkspaceReportMessage_Impl>(this: *mut core::ffi::c_void, bstrmessage: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWorkspaceReportMessage_Impl::RegisterErrorLogMessage(this, core::mem::transmute(&bstrmessage)).into()
+            }
+        }
+        unsafe extern "system" fn IsErrorMessageRegistered<Identity: windows_core::IUnknownImpl + IWorkspaceReportMessage_Impl>(this: *mut core::ffi::c_void, bstrwkspid: *mut core::ffi::c_void, dwerrortype: u32, bstrerrormessagetype: *mut core::ffi::c_void, dwerrorcode: u32, pferrorexist: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWorkspaceReportMessage_Impl::IsErrorMessageRegistered(this, core::mem::transmute(&bstrwkspid), core::mem::transmute_copy(&dwerrortype), core::mem::transmute(&bstrerrormessagetype), core::mem::transmute_copy(&dwerrorcode)) {
+                    Ok(ok__) => {
+                        pferrorexist.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RegisterErrorEvent<Identity: windows_core::IUnknownImpl + IWorkspaceReportMessage_Impl>(this: *mut core::ffi::c_void, bstrwkspid: *mut core::ffi::c_void, dwerrortype: u32, bstrerrormessagetype: *mut core::ffi::c_void, dwerrorcode: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWorkspaceReportMessage_Impl::RegisterErrorEvent(this, core::mem::transmute(&bstrwkspid), core::mem::transmute_copy(&dwerrortype), core::mem::transmute(&bstrerrormessagetype), core::mem::transmute_copy(&dwerrorcode)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterErrorLogMessage: RegisterErrorLogMessage::<Identity>,
+            IsErrorMessageRegistered: IsErrorMessageRegistered::<Identity>,
+            RegisterErrorEvent: RegisterErrorEvent::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IWorkspaceReportMessage {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWorkspaceResTypeRegistry, IWorkspaceResTypeRegistry_Vtbl, 0x1d428c79_6e2e_4351_a361_c0401a03a0ba);
@@ -9495,6 +14075,64 @@ impl IWorkspaceResTypeRegistry_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWorkspaceResTypeRegistry_Impl> windows_core::imp::TearOffVtable<Identity> for IWorkspaceResTypeRegistry_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AddResourceType<Identity: windows_core::IUnknownImpl + IWorkspaceResTypeRegistry_Impl>(this: *mut core::ffi::c_void, fmachinewide: super::super::Foundation::VARIANT_BOOL, bstrfileextension: *mut core::ffi::c_void, bstrlauncher: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWorkspaceResTypeRegistry_Impl::AddResourceType(this, core::mem::transmute_copy(&fmachinewide), core::mem::transmute(&bstrfileextension), core::mem::transmute(&bstrlauncher)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteResourceType<Identity: windows_core::IUnknownImpl + IWorkspaceResTypeRegistry_Impl>(this: *mut core::ffi::c_void, fmachinewide: super::super::Foundation::VARIANT_BOOL, bstrfileextension: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWorkspaceResTypeRegistry_Impl::DeleteResourceType(this, core::mem::transmute_copy(&fmachinewide), core::mem::transmute(&bstrfileextension)).into()
+            }
+        }
+        unsafe extern "system" fn GetRegisteredFileExtensions<Identity: windows_core::IUnknownImpl + IWorkspaceResTypeRegistry_Impl>(this: *mut core::ffi::c_void, fmachinewide: super::super::Foundation::VARIANT_BOOL, psafileextensions: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWorkspaceResTypeRegistry_Impl::GetRegisteredFileExtensions(this, core::mem::transmute_copy(&fmachinewide)) {
+                    Ok(ok__) => {
+                        psafileextensions.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetResourceTypeInfo<Identity: windows_core::IUnknownImpl + IWorkspaceResTypeRegistry_Impl>(this: *mut core::ffi::c_void, fmachinewide: super::super::Foundation::VARIANT_BOOL, bstrfileextension: *mut core::ffi::c_void, pbstrlauncher: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWorkspaceResTypeRegistry_Impl::GetResourceTypeInfo(this, core::mem::transmute_copy(&fmachinewide), core::mem::transmute(&bstrfileextension)) {
+                    Ok(ok__) => {
+                        pbstrlauncher.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ModifyResourceType<Identity: windows_core::IUnknownImpl + IWorkspaceResTypeRegistry_Impl>(this: *mut core::ffi::c_void, fmachinewide: super::super::Foundation::VARIANT_BOOL, bstrfileextension: *mut core::ffi::c_void, bstrlauncher: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWorkspaceResTypeRegistry_Impl::ModifyResourceType(this, core::mem::transmute_copy(&fmachinewide), core::mem::transmute(&bstrfileextension), core::mem::transmute(&bstrlauncher)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AddResourceType: AddResourceType::<Identity>,
+            DeleteResourceType: DeleteResourceType::<Identity>,
+            GetRegisteredFileExtensions: GetRegisteredFileExtensions::<Identity>,
+            GetResourceTypeInfo: GetResourceTypeInfo::<Identity>,
+            ModifyResourceType: ModifyResourceType::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWorkspaceResTypeRegistry {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWorkspaceScriptable, IWorkspaceScriptable_Vtbl, 0xefea49a2_dda5_429d_8f42_b23b92c4c347);
@@ -9633,6 +14271,78 @@ impl IWorkspaceScriptable_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWorkspaceScriptable_Impl> windows_core::imp::TearOffVtable<Identity> for IWorkspaceScriptable_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DisconnectWorkspace<Identity: windows_core::IUnknownImpl + IWorkspaceScriptable_Impl>(this: *mut core::ffi::c_void, bstrworkspaceid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWorkspaceScriptable_Impl::DisconnectWorkspace(this, core::mem::transmute(&bstrworkspaceid)).into()
+            }
+        }
+        unsafe extern "system" fn StartWorkspace<Identity: windows_core::IUnknownImpl + IWorkspaceScriptable_Impl>(this: *mut core::ffi::c_void, bstrworkspaceid: *mut core::ffi::c_void, bstrusername: *mut core::ffi::c_void, bstrpassword: *mut core::ffi::c_void, bstrworkspaceparams: *mut core::ffi::c_void, ltimeout: i32, lflags: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWorkspaceScriptable_Impl::StartWorkspace(this, core::mem::transmute(&bstrworkspaceid), core::mem::transmute(&bstrusername), core::mem::transmute(&bstrpassword), core::mem::transmute(&bstrworkspaceparams), core::mem::transmute_copy(&ltimeout), core::mem::transmute_copy(&lflags)).into()
+            }
+        }
+        unsafe extern "system" fn IsWorkspaceCredentialSpecified<Identity: windows_core::IUnknownImpl + IWorkspaceScriptable_Impl>(this: *mut core::ffi::c_void, bstrworkspaceid: *mut core::ffi::c_void, bcountunauthenticatedcredentials: super::super::Foundation::VARIANT_BOOL, pbcredexist: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWorkspaceScriptable_Impl::IsWorkspaceCredentialSpecified(this, core::mem::transmute(&bstrworkspaceid), core::mem::transmute_copy(&bcountunauthenticatedcredentials)) {
+                    Ok(ok__) => {
+                        pbcredexist.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn IsWorkspaceSSOEnabled<Identity: windows_core::IUnknownImpl + IWorkspaceScriptable_Impl>(this: *mut core::ffi::c_void, pbssoenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWorkspaceScriptable_Impl::IsWorkspaceSSOEnabled(this) {
+                    Ok(ok__) => {
+                        pbssoenabled.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ClearWorkspaceCredential<Identity: windows_core::IUnknownImpl + IWorkspaceScriptable_Impl>(this: *mut core::ffi::c_void, bstrworkspaceid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWorkspaceScriptable_Impl::ClearWorkspaceCredential(this, core::mem::transmute(&bstrworkspaceid)).into()
+            }
+        }
+        unsafe extern "system" fn OnAuthenticated<Identity: windows_core::IUnknownImpl + IWorkspaceScriptable_Impl>(this: *mut core::ffi::c_void, bstrworkspaceid: *mut core::ffi::c_void, bstrusername: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWorkspaceScriptable_Impl::OnAuthenticated(this, core::mem::transmute(&bstrworkspaceid), core::mem::transmute(&bstrusername)).into()
+            }
+        }
+        unsafe extern "system" fn DisconnectWorkspaceByFriendlyName<Identity: windows_core::IUnknownImpl + IWorkspaceScriptable_Impl>(this: *mut core::ffi::c_void, bstrworkspacefriendlyname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWorkspaceScriptable_Impl::DisconnectWorkspaceByFriendlyName(this, core::mem::transmute(&bstrworkspacefriendlyname)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DisconnectWorkspace: DisconnectWorkspace::<Identity>,
+            StartWorkspace: StartWorkspace::<Identity>,
+            IsWorkspaceCredentialSpecified: IsWorkspaceCredentialSpecified::<Identity>,
+            IsWorkspaceSSOEnabled: IsWorkspaceSSOEnabled::<Identity>,
+            ClearWorkspaceCredential: ClearWorkspaceCredential::<Identity>,
+            OnAuthenticated: OnAuthenticated::<Identity>,
+            DisconnectWorkspaceByFriendlyName: DisconnectWorkspaceByFriendlyName::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWorkspaceScriptable {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWorkspaceScriptable2, IWorkspaceScriptable2_Vtbl, 0xefea49a2_dda5_429d_8f42_b33ba2c4c348);
@@ -9693,6 +14403,31 @@ impl IWorkspaceScriptable2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWorkspaceScriptable2_Impl> windows_core::imp::TearOffVtable<Identity> for IWorkspaceScriptable2_Vtbl
+where
+    IWorkspaceScriptable_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StartWorkspaceEx<Identity: windows_core::IUnknownImpl + IWorkspaceScriptable2_Impl>(this: *mut core::ffi::c_void, bstrworkspaceid: *mut core::ffi::c_void, bstrworkspacefriendlyname: *mut core::ffi::c_void, bstrredirectorname: *mut core::ffi::c_void, bstrusername: *mut core::ffi::c_void, bstrpassword: *mut core::ffi::c_void, bstrappcontainer: *mut core::ffi::c_void, bstrworkspaceparams: *mut core::ffi::c_void, ltimeout: i32, lflags: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWorkspaceScriptable2_Impl::StartWorkspaceEx(this, core::mem::transmute(&bstrworkspaceid), core::mem::transmute(&bstrworkspacefriendlyname), core::mem::transmute(&bstrredirectorname), core::mem::transmute(&bstrusername), core::mem::transmute(&bstrpassword), core::mem::transmute(&bstrappcontainer), core::mem::transmute(&bstrworkspaceparams), core::mem::transmute_copy(&ltimeout), core::mem::transmute_copy(&lflags)).into()
+            }
+        }
+        unsafe extern "system" fn ResourceDismissed<Identity: windows_core::IUnknownImpl + IWorkspaceScriptable2_Impl>(this: *mut core::ffi::c_void, bstrworkspaceid: *mut core::ffi::c_void, bstrworkspacefriendlyname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWorkspaceScriptable2_Impl::ResourceDismissed(this, core::mem::transmute(&bstrworkspaceid), core::mem::transmute(&bstrworkspacefriendlyname)).into()
+            }
+        }
+        Self {
+            base__: <IWorkspaceScriptable_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            StartWorkspaceEx: StartWorkspaceEx::<Identity>,
+            ResourceDismissed: ResourceDismissed::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWorkspaceScriptable2 {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWorkspaceScriptable3, IWorkspaceScriptable3_Vtbl, 0x531e6512_2cbf_4bd2_80a5_d90a71636a9a);
@@ -9738,6 +14473,21 @@ impl IWorkspaceScriptable3_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWorkspaceScriptable3_Impl> windows_core::imp::TearOffVtable<Identity> for IWorkspaceScriptable3_Vtbl
+where
+    IWorkspaceScriptable2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn StartWorkspaceEx2<Identity: windows_core::IUnknownImpl + IWorkspaceScriptable3_Impl>(this: *mut core::ffi::c_void, bstrworkspaceid: *mut core::ffi::c_void, bstrworkspacefriendlyname: *mut core::ffi::c_void, bstrredirectorname: *mut core::ffi::c_void, bstrusername: *mut core::ffi::c_void, bstrpassword: *mut core::ffi::c_void, bstrappcontainer: *mut core::ffi::c_void, bstrworkspaceparams: *mut core::ffi::c_void, ltimeout: i32, lflags: i32, bstreventloguploadaddress: *mut core::ffi::c_void, correlationid: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWorkspaceScriptable3_Impl::StartWorkspaceEx2(this, core::mem::transmute(&bstrworkspaceid), core::mem::transmute(&bstrworkspacefriendlyname), core::mem::transmute(&bstrredirectorname), core::mem::transmute(&bstrusername), core::mem::transmute(&bstrpassword), core::mem::transmute(&bstrappcontainer), core::mem::transmute(&bstrworkspaceparams), core::mem::transmute_copy(&ltimeout), core::mem::transmute_copy(&lflags), core::mem::transmute(&bstreventloguploadaddress), core::mem::transmute(&correlationid)).into()
+            }
+        }
+        Self { base__: <IWorkspaceScriptable2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, StartWorkspaceEx2: StartWorkspaceEx2::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWorkspaceScriptable3 {}
 windows_core::imp::define_interface!(ItsPubPlugin, ItsPubPlugin_Vtbl, 0x70c04b05_f347_412b_822f_36c99c54ca45);
 windows_core::imp::interface_hierarchy!(ItsPubPlugin, windows_core::IUnknown);
@@ -9869,6 +14619,76 @@ impl ItsPubPlugin_Vtbl {
         iid == &<ItsPubPlugin as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ItsPubPlugin_Impl> windows_core::imp::TearOffVtable<Identity> for ItsPubPlugin_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetResourceList<Identity: windows_core::IUnknownImpl + ItsPubPlugin_Impl>(this: *mut core::ffi::c_void, userid: windows_core::PCWSTR, pceapplistsize: *mut i32, resourcelist: *mut *mut pluginResource) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ItsPubPlugin_Impl::GetResourceList(this, core::mem::transmute(&userid), core::mem::transmute_copy(&pceapplistsize), core::mem::transmute_copy(&resourcelist)).into()
+            }
+        }
+        unsafe extern "system" fn GetResource<Identity: windows_core::IUnknownImpl + ItsPubPlugin_Impl>(this: *mut core::ffi::c_void, alias: windows_core::PCWSTR, flags: i32, resource: *mut pluginResource) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ItsPubPlugin_Impl::GetResource(this, core::mem::transmute(&alias), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&resource)).into()
+            }
+        }
+        unsafe extern "system" fn GetCacheLastUpdateTime<Identity: windows_core::IUnknownImpl + ItsPubPlugin_Impl>(this: *mut core::ffi::c_void, lastupdatetime: *mut u64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ItsPubPlugin_Impl::GetCacheLastUpdateTime(this) {
+                    Ok(ok__) => {
+                        lastupdatetime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn pluginName<Identity: windows_core::IUnknownImpl + ItsPubPlugin_Impl>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ItsPubPlugin_Impl::pluginName(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn pluginVersion<Identity: windows_core::IUnknownImpl + ItsPubPlugin_Impl>(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ItsPubPlugin_Impl::pluginVersion(this) {
+                    Ok(ok__) => {
+                        pval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ResolveResource<Identity: windows_core::IUnknownImpl + ItsPubPlugin_Impl>(this: *mut core::ffi::c_void, resourcetype: *mut u32, resourcelocation: windows_core::PWSTR, endpointname: windows_core::PWSTR, userid: windows_core::PCWSTR, alias: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ItsPubPlugin_Impl::ResolveResource(this, core::mem::transmute_copy(&resourcetype), core::mem::transmute_copy(&resourcelocation), core::mem::transmute_copy(&endpointname), core::mem::transmute(&userid), core::mem::transmute(&alias)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetResourceList: GetResourceList::<Identity>,
+            GetResource: GetResource::<Identity>,
+            GetCacheLastUpdateTime: GetCacheLastUpdateTime::<Identity>,
+            pluginName: pluginName::<Identity>,
+            pluginVersion: pluginVersion::<Identity>,
+            ResolveResource: ResolveResource::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ItsPubPlugin {}
 windows_core::imp::define_interface!(ItsPubPlugin2, ItsPubPlugin2_Vtbl, 0xfa4ce418_aad7_4ec6_bad1_0a321ba465d5);
 impl core::ops::Deref for ItsPubPlugin2 {
@@ -9960,6 +14780,44 @@ impl ItsPubPlugin2_Vtbl {
         iid == &<ItsPubPlugin2 as windows_core::Interface>::IID || iid == &<ItsPubPlugin as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ItsPubPlugin2_Impl> windows_core::imp::TearOffVtable<Identity> for ItsPubPlugin2_Vtbl
+where
+    ItsPubPlugin_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetResource2List<Identity: windows_core::IUnknownImpl + ItsPubPlugin2_Impl>(this: *mut core::ffi::c_void, userid: windows_core::PCWSTR, pceapplistsize: *mut i32, resourcelist: *mut *mut pluginResource2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ItsPubPlugin2_Impl::GetResource2List(this, core::mem::transmute(&userid), core::mem::transmute_copy(&pceapplistsize), core::mem::transmute_copy(&resourcelist)).into()
+            }
+        }
+        unsafe extern "system" fn GetResource2<Identity: windows_core::IUnknownImpl + ItsPubPlugin2_Impl>(this: *mut core::ffi::c_void, alias: windows_core::PCWSTR, flags: i32, resource: *mut pluginResource2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ItsPubPlugin2_Impl::GetResource2(this, core::mem::transmute(&alias), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&resource)).into()
+            }
+        }
+        unsafe extern "system" fn ResolvePersonalDesktop<Identity: windows_core::IUnknownImpl + ItsPubPlugin2_Impl>(this: *mut core::ffi::c_void, userid: windows_core::PCWSTR, poolid: windows_core::PCWSTR, epdresolutiontype: TSPUB_PLUGIN_PD_RESOLUTION_TYPE, ppdassignmenttype: *mut TSPUB_PLUGIN_PD_ASSIGNMENT_TYPE, endpointname: windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ItsPubPlugin2_Impl::ResolvePersonalDesktop(this, core::mem::transmute(&userid), core::mem::transmute(&poolid), core::mem::transmute_copy(&epdresolutiontype), core::mem::transmute_copy(&ppdassignmenttype), core::mem::transmute_copy(&endpointname)).into()
+            }
+        }
+        unsafe extern "system" fn DeletePersonalDesktopAssignment<Identity: windows_core::IUnknownImpl + ItsPubPlugin2_Impl>(this: *mut core::ffi::c_void, userid: windows_core::PCWSTR, poolid: windows_core::PCWSTR, endpointname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ItsPubPlugin2_Impl::DeletePersonalDesktopAssignment(this, core::mem::transmute(&userid), core::mem::transmute(&poolid), core::mem::transmute(&endpointname)).into()
+            }
+        }
+        Self {
+            base__: <ItsPubPlugin_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetResource2List: GetResource2List::<Identity>,
+            GetResource2: GetResource2::<Identity>,
+            ResolvePersonalDesktop: ResolvePersonalDesktop::<Identity>,
+            DeletePersonalDesktopAssignment: DeletePersonalDesktopAssignment::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ItsPubPlugin2 {}
 pub const KEEP_EXISTING_SESSIONS: u32 = 8u32;
 pub const KeyCombinationDown: KeyCombinationType = KeyCombinationType(4i32);
@@ -11869,6 +16727,13 @@ impl _ITSWkspEvents_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + _ITSWkspEvents_Impl> windows_core::imp::TearOffVtable<Identity> for _ITSWkspEvents_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for _ITSWkspEvents {}
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq)]
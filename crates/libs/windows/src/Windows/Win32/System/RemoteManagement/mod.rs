@@ -882,6 +882,69 @@ impl IWSMan_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWSMan_Impl> windows_core::imp::TearOffVtable<Identity> for IWSMan_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateSession<Identity: windows_core::IUnknownImpl + IWSMan_Impl>(this: *mut core::ffi::c_void, connection: *mut core::ffi::c_void, flags: i32, connectionoptions: *mut core::ffi::c_void, session: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSMan_Impl::CreateSession(this, core::mem::transmute(&connection), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&connectionoptions)) {
+                    Ok(ok__) => {
+                        session.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateConnectionOptions<Identity: windows_core::IUnknownImpl + IWSMan_Impl>(this: *mut core::ffi::c_void, connectionoptions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSMan_Impl::CreateConnectionOptions(this) {
+                    Ok(ok__) => {
+                        connectionoptions.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CommandLine<Identity: windows_core::IUnknownImpl + IWSMan_Impl>(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSMan_Impl::CommandLine(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Error<Identity: windows_core::IUnknownImpl + IWSMan_Impl>(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSMan_Impl::Error(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateSession: CreateSession::<Identity>,
+            CreateConnectionOptions: CreateConnectionOptions::<Identity>,
+            CommandLine: CommandLine::<Identity>,
+            Error: Error::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWSMan {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWSManConnectionOptions, IWSManConnectionOptions_Vtbl, 0xf704e861_9e52_464f_b786_da5eb2320fdd);
@@ -963,6 +1026,44 @@ impl IWSManConnectionOptions_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWSManConnectionOptions_Impl> windows_core::imp::TearOffVtable<Identity> for IWSManConnectionOptions_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn UserName<Identity: windows_core::IUnknownImpl + IWSManConnectionOptions_Impl>(this: *mut core::ffi::c_void, name: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManConnectionOptions_Impl::UserName(this) {
+                    Ok(ok__) => {
+                        name.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetUserName<Identity: windows_core::IUnknownImpl + IWSManConnectionOptions_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSManConnectionOptions_Impl::SetUserName(this, core::mem::transmute(&name)).into()
+            }
+        }
+        unsafe extern "system" fn SetPassword<Identity: windows_core::IUnknownImpl + IWSManConnectionOptions_Impl>(this: *mut core::ffi::c_void, password: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSManConnectionOptions_Impl::SetPassword(this, core::mem::transmute(&password)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            UserName: UserName::<Identity>,
+            SetUserName: SetUserName::<Identity>,
+            SetPassword: SetPassword::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWSManConnectionOptions {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWSManConnectionOptionsEx, IWSManConnectionOptionsEx_Vtbl, 0xef43edf7_2a48_4d93_9526_8bd6ab6d4a6b);
@@ -1032,6 +1133,37 @@ impl IWSManConnectionOptionsEx_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWSManConnectionOptionsEx_Impl> windows_core::imp::TearOffVtable<Identity> for IWSManConnectionOptionsEx_Vtbl
+where
+    IWSManConnectionOptions_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CertificateThumbprint<Identity: windows_core::IUnknownImpl + IWSManConnectionOptionsEx_Impl>(this: *mut core::ffi::c_void, thumbprint: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManConnectionOptionsEx_Impl::CertificateThumbprint(this) {
+                    Ok(ok__) => {
+                        thumbprint.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCertificateThumbprint<Identity: windows_core::IUnknownImpl + IWSManConnectionOptionsEx_Impl>(this: *mut core::ffi::c_void, thumbprint: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSManConnectionOptionsEx_Impl::SetCertificateThumbprint(this, core::mem::transmute(&thumbprint)).into()
+            }
+        }
+        Self {
+            base__: <IWSManConnectionOptions_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CertificateThumbprint: CertificateThumbprint::<Identity>,
+            SetCertificateThumbprint: SetCertificateThumbprint::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWSManConnectionOptionsEx {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWSManConnectionOptionsEx2, IWSManConnectionOptionsEx2_Vtbl, 0xf500c9ec_24ee_48ab_b38d_fc9a164c658e);
@@ -1227,6 +1359,115 @@ impl IWSManConnectionOptionsEx2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWSManConnectionOptionsEx2_Impl> windows_core::imp::TearOffVtable<Identity> for IWSManConnectionOptionsEx2_Vtbl
+where
+    IWSManConnectionOptionsEx_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetProxy<Identity: windows_core::IUnknownImpl + IWSManConnectionOptionsEx2_Impl>(this: *mut core::ffi::c_void, accesstype: i32, authenticationmechanism: i32, username: *mut core::ffi::c_void, password: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSManConnectionOptionsEx2_Impl::SetProxy(this, core::mem::transmute_copy(&accesstype), core::mem::transmute_copy(&authenticationmechanism), core::mem::transmute(&username), core::mem::transmute(&password)).into()
+            }
+        }
+        unsafe extern "system" fn ProxyIEConfig<Identity: windows_core::IUnknownImpl + IWSManConnectionOptionsEx2_Impl>(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManConnectionOptionsEx2_Impl::ProxyIEConfig(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ProxyWinHttpConfig<Identity: windows_core::IUnknownImpl + IWSManConnectionOptionsEx2_Impl>(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManConnectionOptionsEx2_Impl::ProxyWinHttpConfig(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ProxyAutoDetect<Identity: windows_core::IUnknownImpl + IWSManConnectionOptionsEx2_Impl>(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManConnectionOptionsEx2_Impl::ProxyAutoDetect(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ProxyNoProxyServer<Identity: windows_core::IUnknownImpl + IWSManConnectionOptionsEx2_Impl>(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManConnectionOptionsEx2_Impl::ProxyNoProxyServer(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ProxyAuthenticationUseNegotiate<Identity: windows_core::IUnknownImpl + IWSManConnectionOptionsEx2_Impl>(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManConnectionOptionsEx2_Impl::ProxyAuthenticationUseNegotiate(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ProxyAuthenticationUseBasic<Identity: windows_core::IUnknownImpl + IWSManConnectionOptionsEx2_Impl>(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManConnectionOptionsEx2_Impl::ProxyAuthenticationUseBasic(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ProxyAuthenticationUseDigest<Identity: windows_core::IUnknownImpl + IWSManConnectionOptionsEx2_Impl>(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManConnectionOptionsEx2_Impl::ProxyAuthenticationUseDigest(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWSManConnectionOptionsEx_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetProxy: SetProxy::<Identity>,
+            ProxyIEConfig: ProxyIEConfig::<Identity>,
+            ProxyWinHttpConfig: ProxyWinHttpConfig::<Identity>,
+            ProxyAutoDetect: ProxyAutoDetect::<Identity>,
+            ProxyNoProxyServer: ProxyNoProxyServer::<Identity>,
+            ProxyAuthenticationUseNegotiate: ProxyAuthenticationUseNegotiate::<Identity>,
+            ProxyAuthenticationUseBasic: ProxyAuthenticationUseBasic::<Identity>,
+            ProxyAuthenticationUseDigest: ProxyAuthenticationUseDigest::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWSManConnectionOptionsEx2 {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWSManEnumerator, IWSManEnumerator_Vtbl, 0xf3457ca9_abb9_4fa5_b850_90e8ca300e7f);
@@ -1326,6 +1567,56 @@ impl IWSManEnumerator_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWSManEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IWSManEnumerator_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ReadItem<Identity: windows_core::IUnknownImpl + IWSManEnumerator_Impl>(this: *mut core::ffi::c_void, resource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEnumerator_Impl::ReadItem(this) {
+                    Ok(ok__) => {
+                        resource.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AtEndOfStream<Identity: windows_core::IUnknownImpl + IWSManEnumerator_Impl>(this: *mut core::ffi::c_void, eos: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEnumerator_Impl::AtEndOfStream(this) {
+                    Ok(ok__) => {
+                        eos.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Error<Identity: windows_core::IUnknownImpl + IWSManEnumerator_Impl>(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEnumerator_Impl::Error(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ReadItem: ReadItem::<Identity>,
+            AtEndOfStream: AtEndOfStream::<Identity>,
+            Error: Error::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWSManEnumerator {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWSManEx, IWSManEx_Vtbl, 0x2d53bdaa_798e_49e6_a1aa_74d01256f411);
@@ -1782,45 +2073,27 @@ impl IWSManEx_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IWSManEx {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IWSManEx2, IWSManEx2_Vtbl, 0x1d1b5ae0_42d9_4021_8261_3987619512e9);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IWSManEx2 {
-    type Target = IWSManEx;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IWSManEx2, windows_core::IUnknown, super::Com::IDispatch, IWSMan, IWSManEx);
-#[cfg(feature = "Win32_System_Com")]
-impl IWSManEx2 {
-    pub unsafe fn SessionFlagUseClientCertificate(&self) -> windows_core::Result<i32> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).SessionFlagUseClientCertificate)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+impl<Identity: windows_core::IUnknownImpl + IWSManEx_Impl> windows_core::imp::TearOffVtable<Identity> for IWSManEx_Vtbl
+where
+    IWSMan_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateResourceLocator<Identity: windows_core::IUnknownImpl + IWSManEx_Impl>(this: *mut core::ffi::c_void, strresourcelocator: *mut core::ffi::c_void, newresourcelocator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEx_Impl::CreateResourceLocator(this, core::mem::transmute(&strresourcelocator)) {
+                    Ok(ok__) => {
+                        newresourcelocator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-#[repr(C)]
-#[doc(hidden)]
-pub struct IWSManEx2_Vtbl {
-    pub base__: IWSManEx_Vtbl,
-    pub SessionFlagUseClientCertificate: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-pub trait IWSManEx2_Impl: IWSManEx_Impl {
-    fn SessionFlagUseClientCertificate(&self) -> windows_core::Result<i32>;
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl IWSManEx2_Vtbl {
-    pub const fn new<Identity: IWSManEx2_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn SessionFlagUseClientCertificate<Identity: IWSManEx2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SessionFlagUTF8<Identity: windows_core::IUnknownImpl + IWSManEx_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IWSManEx2_Impl::SessionFlagUseClientCertificate(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEx_Impl::SessionFlagUTF8(this) {
                     Ok(ok__) => {
                         flags.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
@@ -1829,13 +2102,326 @@ impl IWSManEx2_Vtbl {
                 }
             }
         }
-        Self { base__: IWSManEx_Vtbl::new::<Identity, OFFSET>(), SessionFlagUseClientCertificate: SessionFlagUseClientCertificate::<Identity, OFFSET> }
-    }
+        unsafe extern "system" fn SessionFlagCredUsernamePassword<Identity: windows_core::IUnknownImpl + IWSManEx_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEx_Impl::SessionFlagCredUsernamePassword(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SessionFlagSkipCACheck<Identity: windows_core::IUnknownImpl + IWSManEx_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEx_Impl::SessionFlagSkipCACheck(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SessionFlagSkipCNCheck<Identity: windows_core::IUnknownImpl + IWSManEx_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEx_Impl::SessionFlagSkipCNCheck(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SessionFlagUseDigest<Identity: windows_core::IUnknownImpl + IWSManEx_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEx_Impl::SessionFlagUseDigest(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SessionFlagUseNegotiate<Identity: windows_core::IUnknownImpl + IWSManEx_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEx_Impl::SessionFlagUseNegotiate(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SessionFlagUseBasic<Identity: windows_core::IUnknownImpl + IWSManEx_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEx_Impl::SessionFlagUseBasic(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SessionFlagUseKerberos<Identity: windows_core::IUnknownImpl + IWSManEx_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEx_Impl::SessionFlagUseKerberos(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SessionFlagNoEncryption<Identity: windows_core::IUnknownImpl + IWSManEx_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEx_Impl::SessionFlagNoEncryption(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SessionFlagEnableSPNServerPort<Identity: windows_core::IUnknownImpl + IWSManEx_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEx_Impl::SessionFlagEnableSPNServerPort(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SessionFlagUseNoAuthentication<Identity: windows_core::IUnknownImpl + IWSManEx_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEx_Impl::SessionFlagUseNoAuthentication(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumerationFlagNonXmlText<Identity: windows_core::IUnknownImpl + IWSManEx_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEx_Impl::EnumerationFlagNonXmlText(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumerationFlagReturnEPR<Identity: windows_core::IUnknownImpl + IWSManEx_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEx_Impl::EnumerationFlagReturnEPR(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumerationFlagReturnObjectAndEPR<Identity: windows_core::IUnknownImpl + IWSManEx_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEx_Impl::EnumerationFlagReturnObjectAndEPR(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetErrorMessage<Identity: windows_core::IUnknownImpl + IWSManEx_Impl>(this: *mut core::ffi::c_void, errornumber: u32, errormessage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEx_Impl::GetErrorMessage(this, core::mem::transmute_copy(&errornumber)) {
+                    Ok(ok__) => {
+                        errormessage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumerationFlagHierarchyDeep<Identity: windows_core::IUnknownImpl + IWSManEx_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEx_Impl::EnumerationFlagHierarchyDeep(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumerationFlagHierarchyShallow<Identity: windows_core::IUnknownImpl + IWSManEx_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEx_Impl::EnumerationFlagHierarchyShallow(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumerationFlagHierarchyDeepBasePropsOnly<Identity: windows_core::IUnknownImpl + IWSManEx_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEx_Impl::EnumerationFlagHierarchyDeepBasePropsOnly(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumerationFlagReturnObject<Identity: windows_core::IUnknownImpl + IWSManEx_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEx_Impl::EnumerationFlagReturnObject(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWSMan_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateResourceLocator: CreateResourceLocator::<Identity>,
+            SessionFlagUTF8: SessionFlagUTF8::<Identity>,
+            SessionFlagCredUsernamePassword: SessionFlagCredUsernamePassword::<Identity>,
+            SessionFlagSkipCACheck: SessionFlagSkipCACheck::<Identity>,
+            SessionFlagSkipCNCheck: SessionFlagSkipCNCheck::<Identity>,
+            SessionFlagUseDigest: SessionFlagUseDigest::<Identity>,
+            SessionFlagUseNegotiate: SessionFlagUseNegotiate::<Identity>,
+            SessionFlagUseBasic: SessionFlagUseBasic::<Identity>,
+            SessionFlagUseKerberos: SessionFlagUseKerberos::<Identity>,
+            SessionFlagNoEncryption: SessionFlagNoEncryption::<Identity>,
+            SessionFlagEnableSPNServerPort: SessionFlagEnableSPNServerPort::<Identity>,
+            SessionFlagUseNoAuthentication: SessionFlagUseNoAuthentication::<Identity>,
+            EnumerationFlagNonXmlText: EnumerationFlagNonXmlText::<Identity>,
+            EnumerationFlagReturnEPR: EnumerationFlagReturnEPR::<Identity>,
+            EnumerationFlagReturnObjectAndEPR: EnumerationFlagReturnObjectAndEPR::<Identity>,
+            GetErrorMessage: GetErrorMessage::<Identity>,
+            EnumerationFlagHierarchyDeep: EnumerationFlagHierarchyDeep::<Identity>,
+            EnumerationFlagHierarchyShallow: EnumerationFlagHierarchyShallow::<Identity>,
+            EnumerationFlagHierarchyDeepBasePropsOnly: EnumerationFlagHierarchyDeepBasePropsOnly::<Identity>,
+            EnumerationFlagReturnObject: EnumerationFlagReturnObject::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IWSManEx {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(IWSManEx2, IWSManEx2_Vtbl, 0x1d1b5ae0_42d9_4021_8261_3987619512e9);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for IWSManEx2 {
+    type Target = IWSManEx;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(IWSManEx2, windows_core::IUnknown, super::Com::IDispatch, IWSMan, IWSManEx);
+#[cfg(feature = "Win32_System_Com")]
+impl IWSManEx2 {
+    pub unsafe fn SessionFlagUseClientCertificate(&self) -> windows_core::Result<i32> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).SessionFlagUseClientCertificate)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+#[repr(C)]
+#[doc(hidden)]
+pub struct IWSManEx2_Vtbl {
+    pub base__: IWSManEx_Vtbl,
+    pub SessionFlagUseClientCertificate: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+pub trait IWSManEx2_Impl: IWSManEx_Impl {
+    fn SessionFlagUseClientCertificate(&self) -> windows_core::Result<i32>;
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl IWSManEx2_Vtbl {
+    pub const fn new<Identity: IWSManEx2_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn SessionFlagUseClientCertificate<Identity: IWSManEx2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWSManEx2_Impl::SessionFlagUseClientCertificate(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: IWSManEx_Vtbl::new::<Identity, OFFSET>(), SessionFlagUseClientCertificate: SessionFlagUseClientCertificate::<Identity, OFFSET> }
+    }
     pub fn matches(iid: &windows_core::GUID) -> bool {
         iid == &<IWSManEx2 as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID || iid == &<IWSMan as windows_core::Interface>::IID || iid == &<IWSManEx as windows_core::Interface>::IID
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWSManEx2_Impl> windows_core::imp::TearOffVtable<Identity> for IWSManEx2_Vtbl
+where
+    IWSManEx_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SessionFlagUseClientCertificate<Identity: windows_core::IUnknownImpl + IWSManEx2_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManEx2_Impl::SessionFlagUseClientCertificate(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IWSManEx_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SessionFlagUseClientCertificate: SessionFlagUseClientCertificate::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWSManEx2 {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWSManEx3, IWSManEx3_Vtbl, 0x6400e966_011d_4eac_8474_049e0848afad);
@@ -1917,11 +2503,116 @@ pub trait IWSManEx3_Impl: IWSManEx2_Impl {
     fn SessionFlagUseSsl(&self) -> windows_core::Result<i32>;
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl IWSManEx3_Vtbl {
-    pub const fn new<Identity: IWSManEx3_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn SessionFlagUTF16<Identity: IWSManEx3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+impl IWSManEx3_Vtbl {
+    pub const fn new<Identity: IWSManEx3_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn SessionFlagUTF16<Identity: IWSManEx3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWSManEx3_Impl::SessionFlagUTF16(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SessionFlagUseCredSsp<Identity: IWSManEx3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWSManEx3_Impl::SessionFlagUseCredSsp(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumerationFlagAssociationInstance<Identity: IWSManEx3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWSManEx3_Impl::EnumerationFlagAssociationInstance(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn EnumerationFlagAssociatedInstance<Identity: IWSManEx3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWSManEx3_Impl::EnumerationFlagAssociatedInstance(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SessionFlagSkipRevocationCheck<Identity: IWSManEx3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWSManEx3_Impl::SessionFlagSkipRevocationCheck(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SessionFlagAllowNegotiateImplicitCredentials<Identity: IWSManEx3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWSManEx3_Impl::SessionFlagAllowNegotiateImplicitCredentials(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SessionFlagUseSsl<Identity: IWSManEx3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IWSManEx3_Impl::SessionFlagUseSsl(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: IWSManEx2_Vtbl::new::<Identity, OFFSET>(),
+            SessionFlagUTF16: SessionFlagUTF16::<Identity, OFFSET>,
+            SessionFlagUseCredSsp: SessionFlagUseCredSsp::<Identity, OFFSET>,
+            EnumerationFlagAssociationInstance: EnumerationFlagAssociationInstance::<Identity, OFFSET>,
+            EnumerationFlagAssociatedInstance: EnumerationFlagAssociatedInstance::<Identity, OFFSET>,
+            SessionFlagSkipRevocationCheck: SessionFlagSkipRevocationCheck::<Identity, OFFSET>,
+            SessionFlagAllowNegotiateImplicitCredentials: SessionFlagAllowNegotiateImplicitCredentials::<Identity, OFFSET>,
+            SessionFlagUseSsl: SessionFlagUseSsl::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSManEx3 as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID || iid == &<IWSMan as windows_core::Interface>::IID || iid == &<IWSManEx as windows_core::Interface>::IID || iid == &<IWSManEx2 as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWSManEx3_Impl> windows_core::imp::TearOffVtable<Identity> for IWSManEx3_Vtbl
+where
+    IWSManEx2_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SessionFlagUTF16<Identity: windows_core::IUnknownImpl + IWSManEx3_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWSManEx3_Impl::SessionFlagUTF16(this) {
                     Ok(ok__) => {
                         flags.write(core::mem::transmute(ok__));
@@ -1931,9 +2622,9 @@ impl IWSManEx3_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SessionFlagUseCredSsp<Identity: IWSManEx3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SessionFlagUseCredSsp<Identity: windows_core::IUnknownImpl + IWSManEx3_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWSManEx3_Impl::SessionFlagUseCredSsp(this) {
                     Ok(ok__) => {
                         flags.write(core::mem::transmute(ok__));
@@ -1943,9 +2634,9 @@ impl IWSManEx3_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn EnumerationFlagAssociationInstance<Identity: IWSManEx3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn EnumerationFlagAssociationInstance<Identity: windows_core::IUnknownImpl + IWSManEx3_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWSManEx3_Impl::EnumerationFlagAssociationInstance(this) {
                     Ok(ok__) => {
                         flags.write(core::mem::transmute(ok__));
@@ -1955,9 +2646,9 @@ impl IWSManEx3_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn EnumerationFlagAssociatedInstance<Identity: IWSManEx3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn EnumerationFlagAssociatedInstance<Identity: windows_core::IUnknownImpl + IWSManEx3_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWSManEx3_Impl::EnumerationFlagAssociatedInstance(this) {
                     Ok(ok__) => {
                         flags.write(core::mem::transmute(ok__));
@@ -1967,9 +2658,9 @@ impl IWSManEx3_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SessionFlagSkipRevocationCheck<Identity: IWSManEx3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SessionFlagSkipRevocationCheck<Identity: windows_core::IUnknownImpl + IWSManEx3_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWSManEx3_Impl::SessionFlagSkipRevocationCheck(this) {
                     Ok(ok__) => {
                         flags.write(core::mem::transmute(ok__));
@@ -1979,9 +2670,9 @@ impl IWSManEx3_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SessionFlagAllowNegotiateImplicitCredentials<Identity: IWSManEx3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SessionFlagAllowNegotiateImplicitCredentials<Identity: windows_core::IUnknownImpl + IWSManEx3_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWSManEx3_Impl::SessionFlagAllowNegotiateImplicitCredentials(this) {
                     Ok(ok__) => {
                         flags.write(core::mem::transmute(ok__));
@@ -1991,9 +2682,9 @@ impl IWSManEx3_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SessionFlagUseSsl<Identity: IWSManEx3_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn SessionFlagUseSsl<Identity: windows_core::IUnknownImpl + IWSManEx3_Impl>(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IWSManEx3_Impl::SessionFlagUseSsl(this) {
                     Ok(ok__) => {
                         flags.write(core::mem::transmute(ok__));
@@ -2004,19 +2695,16 @@ impl IWSManEx3_Vtbl {
             }
         }
         Self {
-            base__: IWSManEx2_Vtbl::new::<Identity, OFFSET>(),
-            SessionFlagUTF16: SessionFlagUTF16::<Identity, OFFSET>,
-            SessionFlagUseCredSsp: SessionFlagUseCredSsp::<Identity, OFFSET>,
-            EnumerationFlagAssociationInstance: EnumerationFlagAssociationInstance::<Identity, OFFSET>,
-            EnumerationFlagAssociatedInstance: EnumerationFlagAssociatedInstance::<Identity, OFFSET>,
-            SessionFlagSkipRevocationCheck: SessionFlagSkipRevocationCheck::<Identity, OFFSET>,
-            SessionFlagAllowNegotiateImplicitCredentials: SessionFlagAllowNegotiateImplicitCredentials::<Identity, OFFSET>,
-            SessionFlagUseSsl: SessionFlagUseSsl::<Identity, OFFSET>,
+            base__: <IWSManEx2_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SessionFlagUTF16: SessionFlagUTF16::<Identity>,
+            SessionFlagUseCredSsp: SessionFlagUseCredSsp::<Identity>,
+            EnumerationFlagAssociationInstance: EnumerationFlagAssociationInstance::<Identity>,
+            EnumerationFlagAssociatedInstance: EnumerationFlagAssociatedInstance::<Identity>,
+            SessionFlagSkipRevocationCheck: SessionFlagSkipRevocationCheck::<Identity>,
+            SessionFlagAllowNegotiateImplicitCredentials: SessionFlagAllowNegotiateImplicitCredentials::<Identity>,
+            SessionFlagUseSsl: SessionFlagUseSsl::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IWSManEx3 as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID || iid == &<IWSMan as windows_core::Interface>::IID || iid == &<IWSManEx as windows_core::Interface>::IID || iid == &<IWSManEx2 as windows_core::Interface>::IID
-    }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWSManEx3 {}
@@ -2080,6 +2768,27 @@ impl IWSManInternal_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWSManInternal_Impl> windows_core::imp::TearOffVtable<Identity> for IWSManInternal_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ConfigSDDL<Identity: windows_core::IUnknownImpl + IWSManInternal_Impl>(this: *mut core::ffi::c_void, session: *mut core::ffi::c_void, resourceuri: super::Variant::VARIANT, flags: i32, resource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManInternal_Impl::ConfigSDDL(this, core::mem::transmute_copy(&session), core::mem::transmute(&resourceuri), core::mem::transmute_copy(&flags)) {
+                    Ok(ok__) => {
+                        resource.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, ConfigSDDL: ConfigSDDL::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWSManInternal {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWSManResourceLocator, IWSManResourceLocator_Vtbl, 0xa7a1ba28_de41_466a_ad0a_c4059ead7428);
@@ -2325,6 +3034,138 @@ impl IWSManResourceLocator_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWSManResourceLocator_Impl> windows_core::imp::TearOffVtable<Identity> for IWSManResourceLocator_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetResourceURI<Identity: windows_core::IUnknownImpl + IWSManResourceLocator_Impl>(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSManResourceLocator_Impl::SetResourceURI(this, core::mem::transmute(&uri)).into()
+            }
+        }
+        unsafe extern "system" fn ResourceURI<Identity: windows_core::IUnknownImpl + IWSManResourceLocator_Impl>(this: *mut core::ffi::c_void, uri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManResourceLocator_Impl::ResourceURI(this) {
+                    Ok(ok__) => {
+                        uri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddSelector<Identity: windows_core::IUnknownImpl + IWSManResourceLocator_Impl>(this: *mut core::ffi::c_void, resourceselname: *mut core::ffi::c_void, selvalue: super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSManResourceLocator_Impl::AddSelector(this, core::mem::transmute(&resourceselname), core::mem::transmute(&selvalue)).into()
+            }
+        }
+        unsafe extern "system" fn ClearSelectors<Identity: windows_core::IUnknownImpl + IWSManResourceLocator_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSManResourceLocator_Impl::ClearSelectors(this).into()
+            }
+        }
+        unsafe extern "system" fn FragmentPath<Identity: windows_core::IUnknownImpl + IWSManResourceLocator_Impl>(this: *mut core::ffi::c_void, text: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManResourceLocator_Impl::FragmentPath(this) {
+                    Ok(ok__) => {
+                        text.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetFragmentPath<Identity: windows_core::IUnknownImpl + IWSManResourceLocator_Impl>(this: *mut core::ffi::c_void, text: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSManResourceLocator_Impl::SetFragmentPath(this, core::mem::transmute(&text)).into()
+            }
+        }
+        unsafe extern "system" fn FragmentDialect<Identity: windows_core::IUnknownImpl + IWSManResourceLocator_Impl>(this: *mut core::ffi::c_void, text: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManResourceLocator_Impl::FragmentDialect(this) {
+                    Ok(ok__) => {
+                        text.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetFragmentDialect<Identity: windows_core::IUnknownImpl + IWSManResourceLocator_Impl>(this: *mut core::ffi::c_void, text: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSManResourceLocator_Impl::SetFragmentDialect(this, core::mem::transmute(&text)).into()
+            }
+        }
+        unsafe extern "system" fn AddOption<Identity: windows_core::IUnknownImpl + IWSManResourceLocator_Impl>(this: *mut core::ffi::c_void, optionname: *mut core::ffi::c_void, optionvalue: super::Variant::VARIANT, mustcomply: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSManResourceLocator_Impl::AddOption(this, core::mem::transmute(&optionname), core::mem::transmute(&optionvalue), core::mem::transmute_copy(&mustcomply)).into()
+            }
+        }
+        unsafe extern "system" fn SetMustUnderstandOptions<Identity: windows_core::IUnknownImpl + IWSManResourceLocator_Impl>(this: *mut core::ffi::c_void, mustunderstand: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSManResourceLocator_Impl::SetMustUnderstandOptions(this, core::mem::transmute_copy(&mustunderstand)).into()
+            }
+        }
+        unsafe extern "system" fn MustUnderstandOptions<Identity: windows_core::IUnknownImpl + IWSManResourceLocator_Impl>(this: *mut core::ffi::c_void, mustunderstand: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManResourceLocator_Impl::MustUnderstandOptions(this) {
+                    Ok(ok__) => {
+                        mustunderstand.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ClearOptions<Identity: windows_core::IUnknownImpl + IWSManResourceLocator_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSManResourceLocator_Impl::ClearOptions(this).into()
+            }
+        }
+        unsafe extern "system" fn Error<Identity: windows_core::IUnknownImpl + IWSManResourceLocator_Impl>(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManResourceLocator_Impl::Error(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetResourceURI: SetResourceURI::<Identity>,
+            ResourceURI: ResourceURI::<Identity>,
+            AddSelector: AddSelector::<Identity>,
+            ClearSelectors: ClearSelectors::<Identity>,
+            FragmentPath: FragmentPath::<Identity>,
+            SetFragmentPath: SetFragmentPath::<Identity>,
+            FragmentDialect: FragmentDialect::<Identity>,
+            SetFragmentDialect: SetFragmentDialect::<Identity>,
+            AddOption: AddOption::<Identity>,
+            SetMustUnderstandOptions: SetMustUnderstandOptions::<Identity>,
+            MustUnderstandOptions: MustUnderstandOptions::<Identity>,
+            ClearOptions: ClearOptions::<Identity>,
+            Error: Error::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWSManResourceLocator {}
 windows_core::imp::define_interface!(IWSManResourceLocatorInternal, IWSManResourceLocatorInternal_Vtbl, 0xeffaead7_7ec8_4716_b9be_f2e7e9fb4adb);
 windows_core::imp::interface_hierarchy!(IWSManResourceLocatorInternal, windows_core::IUnknown);
@@ -2342,6 +3183,12 @@ impl IWSManResourceLocatorInternal_Vtbl {
         iid == &<IWSManResourceLocatorInternal as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IWSManResourceLocatorInternal_Impl> windows_core::imp::TearOffVtable<Identity> for IWSManResourceLocatorInternal_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for IWSManResourceLocatorInternal {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWSManSession, IWSManSession_Vtbl, 0xfc84fc58_1286_40c4_9da0_c8ef6ec241e0);
@@ -2627,6 +3474,155 @@ impl IWSManSession_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWSManSession_Impl> windows_core::imp::TearOffVtable<Identity> for IWSManSession_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Get<Identity: windows_core::IUnknownImpl + IWSManSession_Impl>(this: *mut core::ffi::c_void, resourceuri: super::Variant::VARIANT, flags: i32, resource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManSession_Impl::Get(this, core::mem::transmute(&resourceuri), core::mem::transmute_copy(&flags)) {
+                    Ok(ok__) => {
+                        resource.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Put<Identity: windows_core::IUnknownImpl + IWSManSession_Impl>(this: *mut core::ffi::c_void, resourceuri: super::Variant::VARIANT, resource: *mut core::ffi::c_void, flags: i32, resultresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManSession_Impl::Put(this, core::mem::transmute(&resourceuri), core::mem::transmute(&resource), core::mem::transmute_copy(&flags)) {
+                    Ok(ok__) => {
+                        resultresource.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Create<Identity: windows_core::IUnknownImpl + IWSManSession_Impl>(this: *mut core::ffi::c_void, resourceuri: super::Variant::VARIANT, resource: *mut core::ffi::c_void, flags: i32, newuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManSession_Impl::Create(this, core::mem::transmute(&resourceuri), core::mem::transmute(&resource), core::mem::transmute_copy(&flags)) {
+                    Ok(ok__) => {
+                        newuri.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + IWSManSession_Impl>(this: *mut core::ffi::c_void, resourceuri: super::Variant::VARIANT, flags: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSManSession_Impl::Delete(this, core::mem::transmute(&resourceuri), core::mem::transmute_copy(&flags)).into()
+            }
+        }
+        unsafe extern "system" fn Invoke<Identity: windows_core::IUnknownImpl + IWSManSession_Impl>(this: *mut core::ffi::c_void, actionuri: *mut core::ffi::c_void, resourceuri: super::Variant::VARIANT, parameters: *mut core::ffi::c_void, flags: i32, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManSession_Impl::Invoke(this, core::mem::transmute(&actionuri), core::mem::transmute(&resourceuri), core::mem::transmute(&parameters), core::mem::transmute_copy(&flags)) {
+                    Ok(ok__) => {
+                        result.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Enumerate<Identity: windows_core::IUnknownImpl + IWSManSession_Impl>(this: *mut core::ffi::c_void, resourceuri: super::Variant::VARIANT, filter: *mut core::ffi::c_void, dialect: *mut core::ffi::c_void, flags: i32, resultset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManSession_Impl::Enumerate(this, core::mem::transmute(&resourceuri), core::mem::transmute(&filter), core::mem::transmute(&dialect), core::mem::transmute_copy(&flags)) {
+                    Ok(ok__) => {
+                        resultset.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Identify<Identity: windows_core::IUnknownImpl + IWSManSession_Impl>(this: *mut core::ffi::c_void, flags: i32, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManSession_Impl::Identify(this, core::mem::transmute_copy(&flags)) {
+                    Ok(ok__) => {
+                        result.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Error<Identity: windows_core::IUnknownImpl + IWSManSession_Impl>(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManSession_Impl::Error(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn BatchItems<Identity: windows_core::IUnknownImpl + IWSManSession_Impl>(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManSession_Impl::BatchItems(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetBatchItems<Identity: windows_core::IUnknownImpl + IWSManSession_Impl>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSManSession_Impl::SetBatchItems(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Timeout<Identity: windows_core::IUnknownImpl + IWSManSession_Impl>(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IWSManSession_Impl::Timeout(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTimeout<Identity: windows_core::IUnknownImpl + IWSManSession_Impl>(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWSManSession_Impl::SetTimeout(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Get: Get::<Identity>,
+            Put: Put::<Identity>,
+            Create: Create::<Identity>,
+            Delete: Delete::<Identity>,
+            Invoke: Invoke::<Identity>,
+            Enumerate: Enumerate::<Identity>,
+            Identify: Identify::<Identity>,
+            Error: Error::<Identity>,
+            BatchItems: BatchItems::<Identity>,
+            SetBatchItems: SetBatchItems::<Identity>,
+            Timeout: Timeout::<Identity>,
+            SetTimeout: SetTimeout::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWSManSession {}
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
@@ -81,6 +81,50 @@ impl IItemEnumerator_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IItemEnumerator_Impl> windows_core::imp::TearOffVtable<Identity> for IItemEnumerator_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Current<Identity: windows_core::IUnknownImpl + IItemEnumerator_Impl>(this: *mut core::ffi::c_void, item: *mut super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IItemEnumerator_Impl::Current(this) {
+                    Ok(ok__) => {
+                        item.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn MoveNext<Identity: windows_core::IUnknownImpl + IItemEnumerator_Impl>(this: *mut core::ffi::c_void, itemvalid: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IItemEnumerator_Impl::MoveNext(this) {
+                    Ok(ok__) => {
+                        itemvalid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IItemEnumerator_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IItemEnumerator_Impl::Reset(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Current: Current::<Identity>,
+            MoveNext: MoveNext::<Identity>,
+            Reset: Reset::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IItemEnumerator {}
 windows_core::imp::define_interface!(ISettingsContext, ISettingsContext_Vtbl, 0x9f7d7bbd_20b3_11da_81a5_0030f1642e3c);
 windows_core::imp::interface_hierarchy!(ISettingsContext, windows_core::IUnknown);
@@ -240,6 +284,84 @@ impl ISettingsContext_Vtbl {
     }
 }
 #[cfg(feature = "Win32_System_Com")]
+impl<Identity: windows_core::IUnknownImpl + ISettingsContext_Impl> windows_core::imp::TearOffVtable<Identity> for ISettingsContext_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Serialize<Identity: windows_core::IUnknownImpl + ISettingsContext_Impl>(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, ptarget: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISettingsContext_Impl::Serialize(this, core::mem::transmute_copy(&pstream), core::mem::transmute_copy(&ptarget)).into()
+            }
+        }
+        unsafe extern "system" fn Deserialize<Identity: windows_core::IUnknownImpl + ISettingsContext_Impl>(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, ptarget: *mut core::ffi::c_void, pppresults: *mut *mut *mut core::ffi::c_void, pcresultcount: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsContext_Impl::Deserialize(this, core::mem::transmute_copy(&pstream), core::mem::transmute_copy(&ptarget), core::mem::transmute_copy(&pppresults)) {
+                    Ok(ok__) => {
+                        pcresultcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetUserData<Identity: windows_core::IUnknownImpl + ISettingsContext_Impl>(this: *mut core::ffi::c_void, puserdata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISettingsContext_Impl::SetUserData(this, core::mem::transmute_copy(&puserdata)).into()
+            }
+        }
+        unsafe extern "system" fn GetUserData<Identity: windows_core::IUnknownImpl + ISettingsContext_Impl>(this: *mut core::ffi::c_void, puserdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsContext_Impl::GetUserData(this) {
+                    Ok(ok__) => {
+                        puserdata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetNamespaces<Identity: windows_core::IUnknownImpl + ISettingsContext_Impl>(this: *mut core::ffi::c_void, ppnamespaceids: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsContext_Impl::GetNamespaces(this) {
+                    Ok(ok__) => {
+                        ppnamespaceids.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStoredSettings<Identity: windows_core::IUnknownImpl + ISettingsContext_Impl>(this: *mut core::ffi::c_void, pidentity: *mut core::ffi::c_void, ppaddedsettings: *mut *mut core::ffi::c_void, ppmodifiedsettings: *mut *mut core::ffi::c_void, ppdeletedsettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISettingsContext_Impl::GetStoredSettings(this, core::mem::transmute_copy(&pidentity), core::mem::transmute_copy(&ppaddedsettings), core::mem::transmute_copy(&ppmodifiedsettings), core::mem::transmute_copy(&ppdeletedsettings)).into()
+            }
+        }
+        unsafe extern "system" fn RevertSetting<Identity: windows_core::IUnknownImpl + ISettingsContext_Impl>(this: *mut core::ffi::c_void, pidentity: *mut core::ffi::c_void, pwzsetting: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISettingsContext_Impl::RevertSetting(this, core::mem::transmute_copy(&pidentity), core::mem::transmute(&pwzsetting)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Serialize: Serialize::<Identity>,
+            Deserialize: Deserialize::<Identity>,
+            SetUserData: SetUserData::<Identity>,
+            GetUserData: GetUserData::<Identity>,
+            GetNamespaces: GetNamespaces::<Identity>,
+            GetStoredSettings: GetStoredSettings::<Identity>,
+            RevertSetting: RevertSetting::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_System_Com")]
 impl windows_core::RuntimeName for ISettingsContext {}
 windows_core::imp::define_interface!(ISettingsEngine, ISettingsEngine_Vtbl, 0x9f7d7bb9_20b3_11da_81a5_0030f1642e3c);
 windows_core::imp::interface_hierarchy!(ISettingsEngine, windows_core::IUnknown);
@@ -579,6 +701,195 @@ impl ISettingsEngine_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISettingsEngine_Impl> windows_core::imp::TearOffVtable<Identity> for ISettingsEngine_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetNamespaces<Identity: windows_core::IUnknownImpl + ISettingsEngine_Impl>(this: *mut core::ffi::c_void, flags: WcmNamespaceEnumerationFlags, reserved: *const core::ffi::c_void, namespaces: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsEngine_Impl::GetNamespaces(this, core::mem::transmute_copy(&flags), core::mem::transmute_copy(&reserved)) {
+                    Ok(ok__) => {
+                        namespaces.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetNamespace<Identity: windows_core::IUnknownImpl + ISettingsEngine_Impl>(this: *mut core::ffi::c_void, settingsid: *mut core::ffi::c_void, access: WcmNamespaceAccess, reserved: *const core::ffi::c_void, namespaceitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsEngine_Impl::GetNamespace(this, core::mem::transmute_copy(&settingsid), core::mem::transmute_copy(&access), core::mem::transmute_copy(&reserved)) {
+                    Ok(ok__) => {
+                        namespaceitem.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetErrorDescription<Identity: windows_core::IUnknownImpl + ISettingsEngine_Impl>(this: *mut core::ffi::c_void, hresult: i32, message: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsEngine_Impl::GetErrorDescription(this, core::mem::transmute_copy(&hresult)) {
+                    Ok(ok__) => {
+                        message.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSettingsIdentity<Identity: windows_core::IUnknownImpl + ISettingsEngine_Impl>(this: *mut core::ffi::c_void, settingsid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsEngine_Impl::CreateSettingsIdentity(this) {
+                    Ok(ok__) => {
+                        settingsid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStoreStatus<Identity: windows_core::IUnknownImpl + ISettingsEngine_Impl>(this: *mut core::ffi::c_void, reserved: *const core::ffi::c_void, status: *mut WcmUserStatus) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsEngine_Impl::GetStoreStatus(this, core::mem::transmute_copy(&reserved)) {
+                    Ok(ok__) => {
+                        status.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LoadStore<Identity: windows_core::IUnknownImpl + ISettingsEngine_Impl>(this: *mut core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISettingsEngine_Impl::LoadStore(this, core::mem::transmute_copy(&flags)).into()
+            }
+        }
+        unsafe extern "system" fn UnloadStore<Identity: windows_core::IUnknownImpl + ISettingsEngine_Impl>(this: *mut core::ffi::c_void, reserved: *const core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISettingsEngine_Impl::UnloadStore(this, core::mem::transmute_copy(&reserved)).into()
+            }
+        }
+        unsafe extern "system" fn RegisterNamespace<Identity: windows_core::IUnknownImpl + ISettingsEngine_Impl>(this: *mut core::ffi::c_void, settingsid: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, pushsettings: windows_core::BOOL, results: *mut super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsEngine_Impl::RegisterNamespace(this, core::mem::transmute_copy(&settingsid), core::mem::transmute_copy(&stream), core::mem::transmute_copy(&pushsettings)) {
+                    Ok(ok__) => {
+                        results.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn UnregisterNamespace<Identity: windows_core::IUnknownImpl + ISettingsEngine_Impl>(this: *mut core::ffi::c_void, settingsid: *mut core::ffi::c_void, removesettings: windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISettingsEngine_Impl::UnregisterNamespace(this, core::mem::transmute_copy(&settingsid), core::mem::transmute_copy(&removesettings)).into()
+            }
+        }
+        unsafe extern "system" fn CreateTargetInfo<Identity: windows_core::IUnknownImpl + ISettingsEngine_Impl>(this: *mut core::ffi::c_void, target: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsEngine_Impl::CreateTargetInfo(this) {
+                    Ok(ok__) => {
+                        target.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTargetInfo<Identity: windows_core::IUnknownImpl + ISettingsEngine_Impl>(this: *mut core::ffi::c_void, target: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsEngine_Impl::GetTargetInfo(this) {
+                    Ok(ok__) => {
+                        target.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTargetInfo<Identity: windows_core::IUnknownImpl + ISettingsEngine_Impl>(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISettingsEngine_Impl::SetTargetInfo(this, core::mem::transmute_copy(&target)).into()
+            }
+        }
+        unsafe extern "system" fn CreateSettingsContext<Identity: windows_core::IUnknownImpl + ISettingsEngine_Impl>(this: *mut core::ffi::c_void, flags: u32, reserved: *const core::ffi::c_void, settingscontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsEngine_Impl::CreateSettingsContext(this, core::mem::transmute_copy(&flags), core::mem::transmute_copy(&reserved)) {
+                    Ok(ok__) => {
+                        settingscontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSettingsContext<Identity: windows_core::IUnknownImpl + ISettingsEngine_Impl>(this: *mut core::ffi::c_void, settingscontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISettingsEngine_Impl::SetSettingsContext(this, core::mem::transmute_copy(&settingscontext)).into()
+            }
+        }
+        unsafe extern "system" fn ApplySettingsContext<Identity: windows_core::IUnknownImpl + ISettingsEngine_Impl>(this: *mut core::ffi::c_void, settingscontext: *mut core::ffi::c_void, pppwzidentities: *mut *mut windows_core::PWSTR, pcidentities: *mut usize) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsEngine_Impl::ApplySettingsContext(this, core::mem::transmute_copy(&settingscontext), core::mem::transmute_copy(&pppwzidentities)) {
+                    Ok(ok__) => {
+                        pcidentities.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSettingsContext<Identity: windows_core::IUnknownImpl + ISettingsEngine_Impl>(this: *mut core::ffi::c_void, settingscontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsEngine_Impl::GetSettingsContext(this) {
+                    Ok(ok__) => {
+                        settingscontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetNamespaces: GetNamespaces::<Identity>,
+            GetNamespace: GetNamespace::<Identity>,
+            GetErrorDescription: GetErrorDescription::<Identity>,
+            CreateSettingsIdentity: CreateSettingsIdentity::<Identity>,
+            GetStoreStatus: GetStoreStatus::<Identity>,
+            LoadStore: LoadStore::<Identity>,
+            UnloadStore: UnloadStore::<Identity>,
+            RegisterNamespace: RegisterNamespace::<Identity>,
+            UnregisterNamespace: UnregisterNamespace::<Identity>,
+            CreateTargetInfo: CreateTargetInfo::<Identity>,
+            GetTargetInfo: GetTargetInfo::<Identity>,
+            SetTargetInfo: SetTargetInfo::<Identity>,
+            CreateSettingsContext: CreateSettingsContext::<Identity>,
+            SetSettingsContext: SetSettingsContext::<Identity>,
+            ApplySettingsContext: ApplySettingsContext::<Identity>,
+            GetSettingsContext: GetSettingsContext::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISettingsEngine {}
 windows_core::imp::define_interface!(ISettingsIdentity, ISettingsIdentity_Vtbl, 0x9f7d7bb6_20b3_11da_81a5_0030f1642e3c);
 windows_core::imp::interface_hierarchy!(ISettingsIdentity, windows_core::IUnknown);
@@ -674,6 +985,56 @@ impl ISettingsIdentity_Vtbl {
         iid == &<ISettingsIdentity as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISettingsIdentity_Impl> windows_core::imp::TearOffVtable<Identity> for ISettingsIdentity_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetAttribute<Identity: windows_core::IUnknownImpl + ISettingsIdentity_Impl>(this: *mut core::ffi::c_void, reserved: *const core::ffi::c_void, name: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsIdentity_Impl::GetAttribute(this, core::mem::transmute_copy(&reserved), core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAttribute<Identity: windows_core::IUnknownImpl + ISettingsIdentity_Impl>(this: *mut core::ffi::c_void, reserved: *const core::ffi::c_void, name: windows_core::PCWSTR, value: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISettingsIdentity_Impl::SetAttribute(this, core::mem::transmute_copy(&reserved), core::mem::transmute(&name), core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn GetFlags<Identity: windows_core::IUnknownImpl + ISettingsIdentity_Impl>(this: *mut core::ffi::c_void, flags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsIdentity_Impl::GetFlags(this) {
+                    Ok(ok__) => {
+                        flags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetFlags<Identity: windows_core::IUnknownImpl + ISettingsIdentity_Impl>(this: *mut core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISettingsIdentity_Impl::SetFlags(this, core::mem::transmute_copy(&flags)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetAttribute: GetAttribute::<Identity>,
+            SetAttribute: SetAttribute::<Identity>,
+            GetFlags: GetFlags::<Identity>,
+            SetFlags: SetFlags::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISettingsIdentity {}
 windows_core::imp::define_interface!(ISettingsItem, ISettingsItem_Vtbl, 0x9f7d7bbb_20b3_11da_81a5_0030f1642e3c);
 windows_core::imp::interface_hierarchy!(ISettingsItem, windows_core::IUnknown);
@@ -1167,18 +1528,291 @@ impl ISettingsItem_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for ISettingsItem {}
-windows_core::imp::define_interface!(ISettingsNamespace, ISettingsNamespace_Vtbl, 0x9f7d7bba_20b3_11da_81a5_0030f1642e3c);
-windows_core::imp::interface_hierarchy!(ISettingsNamespace, windows_core::IUnknown);
-impl ISettingsNamespace {
-    pub unsafe fn GetIdentity(&self) -> windows_core::Result<ISettingsIdentity> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).GetIdentity)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+impl<Identity: windows_core::IUnknownImpl + ISettingsItem_Impl> windows_core::imp::TearOffVtable<Identity> for ISettingsItem_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetName<Identity: windows_core::IUnknownImpl + ISettingsItem_Impl>(this: *mut core::ffi::c_void, name: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsItem_Impl::GetName(this) {
+                    Ok(ok__) => {
+                        name.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn Settings(&self) -> windows_core::Result<IItemEnumerator> {
-        unsafe {
+        unsafe extern "system" fn GetValue<Identity: windows_core::IUnknownImpl + ISettingsItem_Impl>(this: *mut core::ffi::c_void, value: *mut super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsItem_Impl::GetValue(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetValue<Identity: windows_core::IUnknownImpl + ISettingsItem_Impl>(this: *mut core::ffi::c_void, value: *const super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISettingsItem_Impl::SetValue(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn GetSettingType<Identity: windows_core::IUnknownImpl + ISettingsItem_Impl>(this: *mut core::ffi::c_void, r#type: *mut WcmSettingType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsItem_Impl::GetSettingType(this) {
+                    Ok(ok__) => {
+                        r#type.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetDataType<Identity: windows_core::IUnknownImpl + ISettingsItem_Impl>(this: *mut core::ffi::c_void, r#type: *mut WcmDataType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsItem_Impl::GetDataType(this) {
+                    Ok(ok__) => {
+                        r#type.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetValueRaw<Identity: windows_core::IUnknownImpl + ISettingsItem_Impl>(this: *mut core::ffi::c_void, data: *mut *mut u8, datasize: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsItem_Impl::GetValueRaw(this, core::mem::transmute_copy(&data)) {
+                    Ok(ok__) => {
+                        datasize.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetValueRaw<Identity: windows_core::IUnknownImpl + ISettingsItem_Impl>(this: *mut core::ffi::c_void, datatype: i32, data: *const u8, datasize: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISettingsItem_Impl::SetValueRaw(this, core::mem::transmute_copy(&datatype), core::mem::transmute_copy(&data), core::mem::transmute_copy(&datasize)).into()
+            }
+        }
+        unsafe extern "system" fn HasChild<Identity: windows_core::IUnknownImpl + ISettingsItem_Impl>(this: *mut core::ffi::c_void, itemhaschild: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsItem_Impl::HasChild(this) {
+                    Ok(ok__) => {
+                        itemhaschild.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Children<Identity: windows_core::IUnknownImpl + ISettingsItem_Impl>(this: *mut core::ffi::c_void, children: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsItem_Impl::Children(this) {
+                    Ok(ok__) => {
+                        children.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetChild<Identity: windows_core::IUnknownImpl + ISettingsItem_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, child: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsItem_Impl::GetChild(this, core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        child.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSettingByPath<Identity: windows_core::IUnknownImpl + ISettingsItem_Impl>(this: *mut core::ffi::c_void, path: windows_core::PCWSTR, setting: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsItem_Impl::GetSettingByPath(this, core::mem::transmute(&path)) {
+                    Ok(ok__) => {
+                        setting.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSettingByPath<Identity: windows_core::IUnknownImpl + ISettingsItem_Impl>(this: *mut core::ffi::c_void, path: windows_core::PCWSTR, setting: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsItem_Impl::CreateSettingByPath(this, core::mem::transmute(&path)) {
+                    Ok(ok__) => {
+                        setting.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveSettingByPath<Identity: windows_core::IUnknownImpl + ISettingsItem_Impl>(this: *mut core::ffi::c_void, path: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISettingsItem_Impl::RemoveSettingByPath(this, core::mem::transmute(&path)).into()
+            }
+        }
+        unsafe extern "system" fn GetListKeyInformation<Identity: windows_core::IUnknownImpl + ISettingsItem_Impl>(this: *mut core::ffi::c_void, keyname: *mut *mut core::ffi::c_void, datatype: *mut WcmDataType) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsItem_Impl::GetListKeyInformation(this, core::mem::transmute_copy(&keyname)) {
+                    Ok(ok__) => {
+                        datatype.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateListElement<Identity: windows_core::IUnknownImpl + ISettingsItem_Impl>(this: *mut core::ffi::c_void, keydata: *const super::Variant::VARIANT, child: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsItem_Impl::CreateListElement(this, core::mem::transmute_copy(&keydata)) {
+                    Ok(ok__) => {
+                        child.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveListElement<Identity: windows_core::IUnknownImpl + ISettingsItem_Impl>(this: *mut core::ffi::c_void, elementname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISettingsItem_Impl::RemoveListElement(this, core::mem::transmute(&elementname)).into()
+            }
+        }
+        unsafe extern "system" fn Attributes<Identity: windows_core::IUnknownImpl + ISettingsItem_Impl>(this: *mut core::ffi::c_void, attributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsItem_Impl::Attributes(this) {
+                    Ok(ok__) => {
+                        attributes.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAttribute<Identity: windows_core::IUnknownImpl + ISettingsItem_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, value: *mut super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsItem_Impl::GetAttribute(this, core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetPath<Identity: windows_core::IUnknownImpl + ISettingsItem_Impl>(this: *mut core::ffi::c_void, path: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsItem_Impl::GetPath(this) {
+                    Ok(ok__) => {
+                        path.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRestrictionFacets<Identity: windows_core::IUnknownImpl + ISettingsItem_Impl>(this: *mut core::ffi::c_void, restrictionfacets: *mut WcmRestrictionFacets) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsItem_Impl::GetRestrictionFacets(this) {
+                    Ok(ok__) => {
+                        restrictionfacets.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRestriction<Identity: windows_core::IUnknownImpl + ISettingsItem_Impl>(this: *mut core::ffi::c_void, restrictionfacet: WcmRestrictionFacets, facetdata: *mut super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsItem_Impl::GetRestriction(this, core::mem::transmute_copy(&restrictionfacet)) {
+                    Ok(ok__) => {
+                        facetdata.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetKeyValue<Identity: windows_core::IUnknownImpl + ISettingsItem_Impl>(this: *mut core::ffi::c_void, value: *mut super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsItem_Impl::GetKeyValue(this) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetName: GetName::<Identity>,
+            GetValue: GetValue::<Identity>,
+            SetValue: SetValue::<Identity>,
+            GetSettingType: GetSettingType::<Identity>,
+            GetDataType: GetDataType::<Identity>,
+            GetValueRaw: GetValueRaw::<Identity>,
+            SetValueRaw: SetValueRaw::<Identity>,
+            HasChild: HasChild::<Identity>,
+            Children: Children::<Identity>,
+            GetChild: GetChild::<Identity>,
+            GetSettingByPath: GetSettingByPath::<Identity>,
+            CreateSettingByPath: CreateSettingByPath::<Identity>,
+            RemoveSettingByPath: RemoveSettingByPath::<Identity>,
+            GetListKeyInformation: GetListKeyInformation::<Identity>,
+            CreateListElement: CreateListElement::<Identity>,
+            RemoveListElement: RemoveListElement::<Identity>,
+            Attributes: Attributes::<Identity>,
+            GetAttribute: GetAttribute::<Identity>,
+            GetPath: GetPath::<Identity>,
+            GetRestrictionFacets: GetRestrictionFacets::<Identity>,
+            GetRestriction: GetRestriction::<Identity>,
+            GetKeyValue: GetKeyValue::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for ISettingsItem {}
+windows_core::imp::define_interface!(ISettingsNamespace, ISettingsNamespace_Vtbl, 0x9f7d7bba_20b3_11da_81a5_0030f1642e3c);
+windows_core::imp::interface_hierarchy!(ISettingsNamespace, windows_core::IUnknown);
+impl ISettingsNamespace {
+    pub unsafe fn GetIdentity(&self) -> windows_core::Result<ISettingsIdentity> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).GetIdentity)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn Settings(&self) -> windows_core::Result<IItemEnumerator> {
+        unsafe {
             let mut result__ = core::mem::zeroed();
             (windows_core::Interface::vtable(self).Settings)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
         }
@@ -1346,6 +1980,102 @@ impl ISettingsNamespace_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISettingsNamespace_Impl> windows_core::imp::TearOffVtable<Identity> for ISettingsNamespace_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetIdentity<Identity: windows_core::IUnknownImpl + ISettingsNamespace_Impl>(this: *mut core::ffi::c_void, settingsid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsNamespace_Impl::GetIdentity(this) {
+                    Ok(ok__) => {
+                        settingsid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Settings<Identity: windows_core::IUnknownImpl + ISettingsNamespace_Impl>(this: *mut core::ffi::c_void, settings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsNamespace_Impl::Settings(this) {
+                    Ok(ok__) => {
+                        settings.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Save<Identity: windows_core::IUnknownImpl + ISettingsNamespace_Impl>(this: *mut core::ffi::c_void, pushsettings: windows_core::BOOL, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsNamespace_Impl::Save(this, core::mem::transmute_copy(&pushsettings)) {
+                    Ok(ok__) => {
+                        result.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSettingByPath<Identity: windows_core::IUnknownImpl + ISettingsNamespace_Impl>(this: *mut core::ffi::c_void, path: windows_core::PCWSTR, setting: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsNamespace_Impl::GetSettingByPath(this, core::mem::transmute(&path)) {
+                    Ok(ok__) => {
+                        setting.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateSettingByPath<Identity: windows_core::IUnknownImpl + ISettingsNamespace_Impl>(this: *mut core::ffi::c_void, path: windows_core::PCWSTR, setting: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsNamespace_Impl::CreateSettingByPath(this, core::mem::transmute(&path)) {
+                    Ok(ok__) => {
+                        setting.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RemoveSettingByPath<Identity: windows_core::IUnknownImpl + ISettingsNamespace_Impl>(this: *mut core::ffi::c_void, path: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISettingsNamespace_Impl::RemoveSettingByPath(this, core::mem::transmute(&path)).into()
+            }
+        }
+        unsafe extern "system" fn GetAttribute<Identity: windows_core::IUnknownImpl + ISettingsNamespace_Impl>(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, value: *mut super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsNamespace_Impl::GetAttribute(this, core::mem::transmute(&name)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetIdentity: GetIdentity::<Identity>,
+            Settings: Settings::<Identity>,
+            Save: Save::<Identity>,
+            GetSettingByPath: GetSettingByPath::<Identity>,
+            CreateSettingByPath: CreateSettingByPath::<Identity>,
+            RemoveSettingByPath: RemoveSettingByPath::<Identity>,
+            GetAttribute: GetAttribute::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISettingsNamespace {}
 windows_core::imp::define_interface!(ISettingsResult, ISettingsResult_Vtbl, 0x9f7d7bbc_20b3_11da_81a5_0030f1642e3c);
 windows_core::imp::interface_hierarchy!(ISettingsResult, windows_core::IUnknown);
@@ -1494,6 +2224,94 @@ impl ISettingsResult_Vtbl {
         iid == &<ISettingsResult as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISettingsResult_Impl> windows_core::imp::TearOffVtable<Identity> for ISettingsResult_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetDescription<Identity: windows_core::IUnknownImpl + ISettingsResult_Impl>(this: *mut core::ffi::c_void, description: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsResult_Impl::GetDescription(this) {
+                    Ok(ok__) => {
+                        description.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetErrorCode<Identity: windows_core::IUnknownImpl + ISettingsResult_Impl>(this: *mut core::ffi::c_void, hrout: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsResult_Impl::GetErrorCode(this) {
+                    Ok(ok__) => {
+                        hrout.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetContextDescription<Identity: windows_core::IUnknownImpl + ISettingsResult_Impl>(this: *mut core::ffi::c_void, description: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsResult_Impl::GetContextDescription(this) {
+                    Ok(ok__) => {
+                        description.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetLine<Identity: windows_core::IUnknownImpl + ISettingsResult_Impl>(this: *mut core::ffi::c_void, dwline: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsResult_Impl::GetLine(this) {
+                    Ok(ok__) => {
+                        dwline.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetColumn<Identity: windows_core::IUnknownImpl + ISettingsResult_Impl>(this: *mut core::ffi::c_void, dwcolumn: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsResult_Impl::GetColumn(this) {
+                    Ok(ok__) => {
+                        dwcolumn.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSource<Identity: windows_core::IUnknownImpl + ISettingsResult_Impl>(this: *mut core::ffi::c_void, file: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISettingsResult_Impl::GetSource(this) {
+                    Ok(ok__) => {
+                        file.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetDescription: GetDescription::<Identity>,
+            GetErrorCode: GetErrorCode::<Identity>,
+            GetContextDescription: GetContextDescription::<Identity>,
+            GetLine: GetLine::<Identity>,
+            GetColumn: GetColumn::<Identity>,
+            GetSource: GetSource::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISettingsResult {}
 windows_core::imp::define_interface!(ITargetInfo, ITargetInfo_Vtbl, 0x9f7d7bb8_20b3_11da_81a5_0030f1642e3c);
 windows_core::imp::interface_hierarchy!(ITargetInfo, windows_core::IUnknown);
@@ -1915,6 +2733,235 @@ impl ITargetInfo_Vtbl {
         iid == &<ITargetInfo as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITargetInfo_Impl> windows_core::imp::TearOffVtable<Identity> for ITargetInfo_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetTargetMode<Identity: windows_core::IUnknownImpl + ITargetInfo_Impl>(this: *mut core::ffi::c_void, targetmode: *mut WcmTargetMode) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITargetInfo_Impl::GetTargetMode(this) {
+                    Ok(ok__) => {
+                        targetmode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTargetMode<Identity: windows_core::IUnknownImpl + ITargetInfo_Impl>(this: *mut core::ffi::c_void, targetmode: WcmTargetMode) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITargetInfo_Impl::SetTargetMode(this, core::mem::transmute_copy(&targetmode)).into()
+            }
+        }
+        unsafe extern "system" fn GetTemporaryStoreLocation<Identity: windows_core::IUnknownImpl + ITargetInfo_Impl>(this: *mut core::ffi::c_void, temporarystorelocation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITargetInfo_Impl::GetTemporaryStoreLocation(this) {
+                    Ok(ok__) => {
+                        temporarystorelocation.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTemporaryStoreLocation<Identity: windows_core::IUnknownImpl + ITargetInfo_Impl>(this: *mut core::ffi::c_void, temporarystorelocation: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITargetInfo_Impl::SetTemporaryStoreLocation(this, core::mem::transmute(&temporarystorelocation)).into()
+            }
+        }
+        unsafe extern "system" fn GetTargetID<Identity: windows_core::IUnknownImpl + ITargetInfo_Impl>(this: *mut core::ffi::c_void, targetid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITargetInfo_Impl::GetTargetID(this) {
+                    Ok(ok__) => {
+                        targetid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTargetID<Identity: windows_core::IUnknownImpl + ITargetInfo_Impl>(this: *mut core::ffi::c_void, targetid: windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITargetInfo_Impl::SetTargetID(this, core::mem::transmute(&targetid)).into()
+            }
+        }
+        unsafe extern "system" fn GetTargetProcessorArchitecture<Identity: windows_core::IUnknownImpl + ITargetInfo_Impl>(this: *mut core::ffi::c_void, processorarchitecture: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITargetInfo_Impl::GetTargetProcessorArchitecture(this) {
+                    Ok(ok__) => {
+                        processorarchitecture.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTargetProcessorArchitecture<Identity: windows_core::IUnknownImpl + ITargetInfo_Impl>(this: *mut core::ffi::c_void, processorarchitecture: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITargetInfo_Impl::SetTargetProcessorArchitecture(this, core::mem::transmute(&processorarchitecture)).into()
+            }
+        }
+        unsafe extern "system" fn GetProperty<Identity: windows_core::IUnknownImpl + ITargetInfo_Impl>(this: *mut core::ffi::c_void, offline: windows_core::BOOL, property: windows_core::PCWSTR, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITargetInfo_Impl::GetProperty(this, core::mem::transmute_copy(&offline), core::mem::transmute(&property)) {
+                    Ok(ok__) => {
+                        value.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetProperty<Identity: windows_core::IUnknownImpl + ITargetInfo_Impl>(this: *mut core::ffi::c_void, offline: windows_core::BOOL, property: windows_core::PCWSTR, value: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITargetInfo_Impl::SetProperty(this, core::mem::transmute_copy(&offline), core::mem::transmute(&property), core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn GetEnumerator<Identity: windows_core::IUnknownImpl + ITargetInfo_Impl>(this: *mut core::ffi::c_void, enumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITargetInfo_Impl::GetEnumerator(this) {
+                    Ok(ok__) => {
+                        enumerator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ExpandTarget<Identity: windows_core::IUnknownImpl + ITargetInfo_Impl>(this: *mut core::ffi::c_void, offline: windows_core::BOOL, location: windows_core::PCWSTR, expandedlocation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITargetInfo_Impl::ExpandTarget(this, core::mem::transmute_copy(&offline), core::mem::transmute(&location)) {
+                    Ok(ok__) => {
+                        expandedlocation.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ExpandTargetPath<Identity: windows_core::IUnknownImpl + ITargetInfo_Impl>(this: *mut core::ffi::c_void, offline: windows_core::BOOL, location: windows_core::PCWSTR, expandedlocation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITargetInfo_Impl::ExpandTargetPath(this, core::mem::transmute_copy(&offline), core::mem::transmute(&location)) {
+                    Ok(ok__) => {
+                        expandedlocation.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetModulePath<Identity: windows_core::IUnknownImpl + ITargetInfo_Impl>(this: *mut core::ffi::c_void, module: windows_core::PCWSTR, path: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITargetInfo_Impl::SetModulePath(this, core::mem::transmute(&module), core::mem::transmute(&path)).into()
+            }
+        }
+        unsafe extern "system" fn LoadModule<Identity: windows_core::IUnknownImpl + ITargetInfo_Impl>(this: *mut core::ffi::c_void, module: windows_core::PCWSTR, modulehandle: *mut super::super::Foundation::HMODULE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITargetInfo_Impl::LoadModule(this, core::mem::transmute(&module)) {
+                    Ok(ok__) => {
+                        modulehandle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetWow64Context<Identity: windows_core::IUnknownImpl + ITargetInfo_Impl>(this: *mut core::ffi::c_void, installermodule: windows_core::PCWSTR, wow64context: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITargetInfo_Impl::SetWow64Context(this, core::mem::transmute(&installermodule), core::mem::transmute_copy(&wow64context)).into()
+            }
+        }
+        unsafe extern "system" fn TranslateWow64<Identity: windows_core::IUnknownImpl + ITargetInfo_Impl>(this: *mut core::ffi::c_void, clientarchitecture: windows_core::PCWSTR, value: windows_core::PCWSTR, translatedvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITargetInfo_Impl::TranslateWow64(this, core::mem::transmute(&clientarchitecture), core::mem::transmute(&value)) {
+                    Ok(ok__) => {
+                        translatedvalue.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSchemaHiveLocation<Identity: windows_core::IUnknownImpl + ITargetInfo_Impl>(this: *mut core::ffi::c_void, pwzhivedir: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITargetInfo_Impl::SetSchemaHiveLocation(this, core::mem::transmute(&pwzhivedir)).into()
+            }
+        }
+        unsafe extern "system" fn GetSchemaHiveLocation<Identity: windows_core::IUnknownImpl + ITargetInfo_Impl>(this: *mut core::ffi::c_void, phivelocation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITargetInfo_Impl::GetSchemaHiveLocation(this) {
+                    Ok(ok__) => {
+                        phivelocation.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSchemaHiveMountName<Identity: windows_core::IUnknownImpl + ITargetInfo_Impl>(this: *mut core::ffi::c_void, pwzmountname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITargetInfo_Impl::SetSchemaHiveMountName(this, core::mem::transmute(&pwzmountname)).into()
+            }
+        }
+        unsafe extern "system" fn GetSchemaHiveMountName<Identity: windows_core::IUnknownImpl + ITargetInfo_Impl>(this: *mut core::ffi::c_void, pmountname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITargetInfo_Impl::GetSchemaHiveMountName(this) {
+                    Ok(ok__) => {
+                        pmountname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetTargetMode: GetTargetMode::<Identity>,
+            SetTargetMode: SetTargetMode::<Identity>,
+            GetTemporaryStoreLocation: GetTemporaryStoreLocation::<Identity>,
+            SetTemporaryStoreLocation: SetTemporaryStoreLocation::<Identity>,
+            GetTargetID: GetTargetID::<Identity>,
+            SetTargetID: SetTargetID::<Identity>,
+            GetTargetProcessorArchitecture: GetTargetProcessorArchitecture::<Identity>,
+            SetTargetProcessorArchitecture: SetTargetProcessorArchitecture::<Identity>,
+            GetProperty: GetProperty::<Identity>,
+            SetProperty: SetProperty::<Identity>,
+            GetEnumerator: GetEnumerator::<Identity>,
+            ExpandTarget: ExpandTarget::<Identity>,
+            ExpandTargetPath: ExpandTargetPath::<Identity>,
+            SetModulePath: SetModulePath::<Identity>,
+            LoadModule: LoadModule::<Identity>,
+            SetWow64Context: SetWow64Context::<Identity>,
+            TranslateWow64: TranslateWow64::<Identity>,
+            SetSchemaHiveLocation: SetSchemaHiveLocation::<Identity>,
+            GetSchemaHiveLocation: GetSchemaHiveLocation::<Identity>,
+            SetSchemaHiveMountName: SetSchemaHiveMountName::<Identity>,
+            GetSchemaHiveMountName: GetSchemaHiveMountName::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITargetInfo {}
 pub const LIMITED_VALIDATION_MODE: u32 = 1u32;
 pub const LINK_STORE_TO_ENGINE_INSTANCE: u32 = 1u32;
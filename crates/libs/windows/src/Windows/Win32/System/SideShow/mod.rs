@@ -80,6 +80,21 @@ impl ISideShowBulkCapabilities_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISideShowBulkCapabilities_Impl> windows_core::imp::TearOffVtable<Identity> for ISideShowBulkCapabilities_Vtbl
+where
+    ISideShowCapabilities_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCapabilities<Identity: windows_core::IUnknownImpl + ISideShowBulkCapabilities_Impl>(this: *mut core::ffi::c_void, in_keycollection: *mut core::ffi::c_void, inout_pvalues: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowBulkCapabilities_Impl::GetCapabilities(this, core::mem::transmute_copy(&in_keycollection), core::mem::transmute_copy(&inout_pvalues)).into()
+            }
+        }
+        Self { base__: <ISideShowCapabilities_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetCapabilities: GetCapabilities::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISideShowBulkCapabilities {}
 windows_core::imp::define_interface!(ISideShowCapabilities, ISideShowCapabilities_Vtbl, 0x535e1379_c09e_4a54_a511_597bab3a72b8);
 windows_core::imp::interface_hierarchy!(ISideShowCapabilities, windows_core::IUnknown);
@@ -118,6 +133,21 @@ impl ISideShowCapabilities_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISideShowCapabilities_Impl> windows_core::imp::TearOffVtable<Identity> for ISideShowCapabilities_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCapability<Identity: windows_core::IUnknownImpl + ISideShowCapabilities_Impl>(this: *mut core::ffi::c_void, in_keycapability: *const super::super::Foundation::PROPERTYKEY, inout_pvalue: *mut super::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowCapabilities_Impl::GetCapability(this, core::mem::transmute_copy(&in_keycapability), core::mem::transmute_copy(&inout_pvalue)).into()
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetCapability: GetCapability::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISideShowCapabilities {}
 windows_core::imp::define_interface!(ISideShowCapabilitiesCollection, ISideShowCapabilitiesCollection_Vtbl, 0x50305597_5e0d_4ff7_b3af_33d0d9bd52dd);
 windows_core::imp::interface_hierarchy!(ISideShowCapabilitiesCollection, windows_core::IUnknown);
@@ -178,6 +208,42 @@ impl ISideShowCapabilitiesCollection_Vtbl {
         iid == &<ISideShowCapabilitiesCollection as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISideShowCapabilitiesCollection_Impl> windows_core::imp::TearOffVtable<Identity> for ISideShowCapabilitiesCollection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + ISideShowCapabilitiesCollection_Impl>(this: *mut core::ffi::c_void, out_pdwcount: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISideShowCapabilitiesCollection_Impl::GetCount(this) {
+                    Ok(ok__) => {
+                        out_pdwcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetAt<Identity: windows_core::IUnknownImpl + ISideShowCapabilitiesCollection_Impl>(this: *mut core::ffi::c_void, in_dwindex: u32, out_ppcapabilities: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISideShowCapabilitiesCollection_Impl::GetAt(this, core::mem::transmute_copy(&in_dwindex)) {
+                    Ok(ok__) => {
+                        out_ppcapabilities.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetCount: GetCount::<Identity>,
+            GetAt: GetAt::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISideShowCapabilitiesCollection {}
 windows_core::imp::define_interface!(ISideShowContent, ISideShowContent_Vtbl, 0xc18552ed_74ff_4fec_be07_4cfed29d4887);
 windows_core::imp::interface_hierarchy!(ISideShowContent, windows_core::IUnknown);
@@ -257,6 +323,49 @@ impl ISideShowContent_Vtbl {
         iid == &<ISideShowContent as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISideShowContent_Impl> windows_core::imp::TearOffVtable<Identity> for ISideShowContent_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetContent<Identity: windows_core::IUnknownImpl + ISideShowContent_Impl>(this: *mut core::ffi::c_void, in_picapabilities: *mut core::ffi::c_void, out_pdwsize: *mut u32, out_ppbdata: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowContent_Impl::GetContent(this, core::mem::transmute_copy(&in_picapabilities), core::mem::transmute_copy(&out_pdwsize), core::mem::transmute_copy(&out_ppbdata)).into()
+            }
+        }
+        unsafe extern "system" fn ContentId<Identity: windows_core::IUnknownImpl + ISideShowContent_Impl>(this: *mut core::ffi::c_void, out_pcontentid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISideShowContent_Impl::ContentId(this) {
+                    Ok(ok__) => {
+                        out_pcontentid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DifferentiateContent<Identity: windows_core::IUnknownImpl + ISideShowContent_Impl>(this: *mut core::ffi::c_void, out_pfdifferentiatecontent: *mut windows_core::BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISideShowContent_Impl::DifferentiateContent(this) {
+                    Ok(ok__) => {
+                        out_pfdifferentiatecontent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetContent: GetContent::<Identity>,
+            ContentId: ContentId::<Identity>,
+            DifferentiateContent: DifferentiateContent::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISideShowContent {}
 windows_core::imp::define_interface!(ISideShowContentManager, ISideShowContentManager_Vtbl, 0xa5d5b66b_eef9_41db_8d7e_e17c33ab10b0);
 windows_core::imp::interface_hierarchy!(ISideShowContentManager, windows_core::IUnknown);
@@ -354,6 +463,57 @@ impl ISideShowContentManager_Vtbl {
         iid == &<ISideShowContentManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISideShowContentManager_Impl> windows_core::imp::TearOffVtable<Identity> for ISideShowContentManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Add<Identity: windows_core::IUnknownImpl + ISideShowContentManager_Impl>(this: *mut core::ffi::c_void, in_picontent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowContentManager_Impl::Add(this, core::mem::transmute_copy(&in_picontent)).into()
+            }
+        }
+        unsafe extern "system" fn Remove<Identity: windows_core::IUnknownImpl + ISideShowContentManager_Impl>(this: *mut core::ffi::c_void, in_contentid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowContentManager_Impl::Remove(this, core::mem::transmute_copy(&in_contentid)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveAll<Identity: windows_core::IUnknownImpl + ISideShowContentManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowContentManager_Impl::RemoveAll(this).into()
+            }
+        }
+        unsafe extern "system" fn SetEventSink<Identity: windows_core::IUnknownImpl + ISideShowContentManager_Impl>(this: *mut core::ffi::c_void, in_pievents: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowContentManager_Impl::SetEventSink(this, core::mem::transmute_copy(&in_pievents)).into()
+            }
+        }
+        unsafe extern "system" fn GetDeviceCapabilities<Identity: windows_core::IUnknownImpl + ISideShowContentManager_Impl>(this: *mut core::ffi::c_void, out_ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISideShowContentManager_Impl::GetDeviceCapabilities(this) {
+                    Ok(ok__) => {
+                        out_ppcollection.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Add: Add::<Identity>,
+            Remove: Remove::<Identity>,
+            RemoveAll: RemoveAll::<Identity>,
+            SetEventSink: SetEventSink::<Identity>,
+            GetDeviceCapabilities: GetDeviceCapabilities::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISideShowContentManager {}
 windows_core::imp::define_interface!(ISideShowEvents, ISideShowEvents_Vtbl, 0x61feca4c_deb4_4a7e_8d75_51f1132d615b);
 windows_core::imp::interface_hierarchy!(ISideShowEvents, windows_core::IUnknown);
@@ -442,6 +602,50 @@ impl ISideShowEvents_Vtbl {
         iid == &<ISideShowEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISideShowEvents_Impl> windows_core::imp::TearOffVtable<Identity> for ISideShowEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ContentMissing<Identity: windows_core::IUnknownImpl + ISideShowEvents_Impl>(this: *mut core::ffi::c_void, in_contentid: u32, out_ppicontent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISideShowEvents_Impl::ContentMissing(this, core::mem::transmute_copy(&in_contentid)) {
+                    Ok(ok__) => {
+                        out_ppicontent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ApplicationEvent<Identity: windows_core::IUnknownImpl + ISideShowEvents_Impl>(this: *mut core::ffi::c_void, in_picapabilities: *mut core::ffi::c_void, in_dweventid: u32, in_dweventsize: u32, in_pbeventdata: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowEvents_Impl::ApplicationEvent(this, core::mem::transmute_copy(&in_picapabilities), core::mem::transmute_copy(&in_dweventid), core::mem::transmute_copy(&in_dweventsize), core::mem::transmute_copy(&in_pbeventdata)).into()
+            }
+        }
+        unsafe extern "system" fn DeviceAdded<Identity: windows_core::IUnknownImpl + ISideShowEvents_Impl>(this: *mut core::ffi::c_void, in_pidevice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowEvents_Impl::DeviceAdded(this, core::mem::transmute_copy(&in_pidevice)).into()
+            }
+        }
+        unsafe extern "system" fn DeviceRemoved<Identity: windows_core::IUnknownImpl + ISideShowEvents_Impl>(this: *mut core::ffi::c_void, in_pidevice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowEvents_Impl::DeviceRemoved(this, core::mem::transmute_copy(&in_pidevice)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ContentMissing: ContentMissing::<Identity>,
+            ApplicationEvent: ApplicationEvent::<Identity>,
+            DeviceAdded: DeviceAdded::<Identity>,
+            DeviceRemoved: DeviceRemoved::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISideShowEvents {}
 windows_core::imp::define_interface!(ISideShowKeyCollection, ISideShowKeyCollection_Vtbl, 0x045473bc_a37b_4957_b144_68105411ed8e);
 windows_core::imp::interface_hierarchy!(ISideShowKeyCollection, windows_core::IUnknown);
@@ -524,6 +728,51 @@ impl ISideShowKeyCollection_Vtbl {
         iid == &<ISideShowKeyCollection as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISideShowKeyCollection_Impl> windows_core::imp::TearOffVtable<Identity> for ISideShowKeyCollection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Add<Identity: windows_core::IUnknownImpl + ISideShowKeyCollection_Impl>(this: *mut core::ffi::c_void, key: *const super::super::Foundation::PROPERTYKEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowKeyCollection_Impl::Add(this, core::mem::transmute_copy(&key)).into()
+            }
+        }
+        unsafe extern "system" fn Clear<Identity: windows_core::IUnknownImpl + ISideShowKeyCollection_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowKeyCollection_Impl::Clear(this).into()
+            }
+        }
+        unsafe extern "system" fn GetAt<Identity: windows_core::IUnknownImpl + ISideShowKeyCollection_Impl>(this: *mut core::ffi::c_void, dwindex: u32, pkey: *mut super::super::Foundation::PROPERTYKEY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowKeyCollection_Impl::GetAt(this, core::mem::transmute_copy(&dwindex), core::mem::transmute_copy(&pkey)).into()
+            }
+        }
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + ISideShowKeyCollection_Impl>(this: *mut core::ffi::c_void, pcelems: *const u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowKeyCollection_Impl::GetCount(this, core::mem::transmute_copy(&pcelems)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveAt<Identity: windows_core::IUnknownImpl + ISideShowKeyCollection_Impl>(this: *mut core::ffi::c_void, dwindex: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowKeyCollection_Impl::RemoveAt(this, core::mem::transmute_copy(&dwindex)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Add: Add::<Identity>,
+            Clear: Clear::<Identity>,
+            GetAt: GetAt::<Identity>,
+            GetCount: GetCount::<Identity>,
+            RemoveAt: RemoveAt::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISideShowKeyCollection {}
 windows_core::imp::define_interface!(ISideShowNotification, ISideShowNotification_Vtbl, 0x03c93300_8ab2_41c5_9b79_46127a30e148);
 windows_core::imp::interface_hierarchy!(ISideShowNotification, windows_core::IUnknown);
@@ -728,6 +977,117 @@ impl ISideShowNotification_Vtbl {
     }
 }
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl<Identity: windows_core::IUnknownImpl + ISideShowNotification_Impl> windows_core::imp::TearOffVtable<Identity> for ISideShowNotification_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn NotificationId<Identity: windows_core::IUnknownImpl + ISideShowNotification_Impl>(this: *mut core::ffi::c_void, out_pnotificationid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISideShowNotification_Impl::NotificationId(this) {
+                    Ok(ok__) => {
+                        out_pnotificationid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNotificationId<Identity: windows_core::IUnknownImpl + ISideShowNotification_Impl>(this: *mut core::ffi::c_void, in_notificationid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowNotification_Impl::SetNotificationId(this, core::mem::transmute_copy(&in_notificationid)).into()
+            }
+        }
+        unsafe extern "system" fn Title<Identity: windows_core::IUnknownImpl + ISideShowNotification_Impl>(this: *mut core::ffi::c_void, out_ppwsztitle: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISideShowNotification_Impl::Title(this) {
+                    Ok(ok__) => {
+                        out_ppwsztitle.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTitle<Identity: windows_core::IUnknownImpl + ISideShowNotification_Impl>(this: *mut core::ffi::c_void, in_pwsztitle: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowNotification_Impl::SetTitle(this, core::mem::transmute(&in_pwsztitle)).into()
+            }
+        }
+        unsafe extern "system" fn Message<Identity: windows_core::IUnknownImpl + ISideShowNotification_Impl>(this: *mut core::ffi::c_void, out_ppwszmessage: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISideShowNotification_Impl::Message(this) {
+                    Ok(ok__) => {
+                        out_ppwszmessage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMessage<Identity: windows_core::IUnknownImpl + ISideShowNotification_Impl>(this: *mut core::ffi::c_void, in_pwszmessage: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowNotification_Impl::SetMessage(this, core::mem::transmute(&in_pwszmessage)).into()
+            }
+        }
+        unsafe extern "system" fn Image<Identity: windows_core::IUnknownImpl + ISideShowNotification_Impl>(this: *mut core::ffi::c_void, out_phicon: *mut super::super::UI::WindowsAndMessaging::HICON) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISideShowNotification_Impl::Image(this) {
+                    Ok(ok__) => {
+                        out_phicon.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetImage<Identity: windows_core::IUnknownImpl + ISideShowNotification_Impl>(this: *mut core::ffi::c_void, in_hicon: super::super::UI::WindowsAndMessaging::HICON) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowNotification_Impl::SetImage(this, core::mem::transmute_copy(&in_hicon)).into()
+            }
+        }
+        unsafe extern "system" fn ExpirationTime<Identity: windows_core::IUnknownImpl + ISideShowNotification_Impl>(this: *mut core::ffi::c_void, out_ptime: *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISideShowNotification_Impl::ExpirationTime(this) {
+                    Ok(ok__) => {
+                        out_ptime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetExpirationTime<Identity: windows_core::IUnknownImpl + ISideShowNotification_Impl>(this: *mut core::ffi::c_void, in_ptime: *const super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowNotification_Impl::SetExpirationTime(this, core::mem::transmute_copy(&in_ptime)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            NotificationId: NotificationId::<Identity>,
+            SetNotificationId: SetNotificationId::<Identity>,
+            Title: Title::<Identity>,
+            SetTitle: SetTitle::<Identity>,
+            Message: Message::<Identity>,
+            SetMessage: SetMessage::<Identity>,
+            Image: Image::<Identity>,
+            SetImage: SetImage::<Identity>,
+            ExpirationTime: ExpirationTime::<Identity>,
+            SetExpirationTime: SetExpirationTime::<Identity>,
+        }
+    };
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
 impl windows_core::RuntimeName for ISideShowNotification {}
 windows_core::imp::define_interface!(ISideShowNotificationManager, ISideShowNotificationManager_Vtbl, 0x63cea909_f2b9_4302_b5e1_c68e6d9ab833);
 windows_core::imp::interface_hierarchy!(ISideShowNotificationManager, windows_core::IUnknown);
@@ -789,6 +1149,37 @@ impl ISideShowNotificationManager_Vtbl {
         iid == &<ISideShowNotificationManager as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISideShowNotificationManager_Impl> windows_core::imp::TearOffVtable<Identity> for ISideShowNotificationManager_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Show<Identity: windows_core::IUnknownImpl + ISideShowNotificationManager_Impl>(this: *mut core::ffi::c_void, in_pinotification: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowNotificationManager_Impl::Show(this, core::mem::transmute_copy(&in_pinotification)).into()
+            }
+        }
+        unsafe extern "system" fn Revoke<Identity: windows_core::IUnknownImpl + ISideShowNotificationManager_Impl>(this: *mut core::ffi::c_void, in_notificationid: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowNotificationManager_Impl::Revoke(this, core::mem::transmute_copy(&in_notificationid)).into()
+            }
+        }
+        unsafe extern "system" fn RevokeAll<Identity: windows_core::IUnknownImpl + ISideShowNotificationManager_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowNotificationManager_Impl::RevokeAll(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Show: Show::<Identity>,
+            Revoke: Revoke::<Identity>,
+            RevokeAll: RevokeAll::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISideShowNotificationManager {}
 windows_core::imp::define_interface!(ISideShowPropVariantCollection, ISideShowPropVariantCollection_Vtbl, 0x2ea7a549_7bff_4aae_bab0_22d43111de49);
 windows_core::imp::interface_hierarchy!(ISideShowPropVariantCollection, windows_core::IUnknown);
@@ -882,6 +1273,52 @@ impl ISideShowPropVariantCollection_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISideShowPropVariantCollection_Impl> windows_core::imp::TearOffVtable<Identity> for ISideShowPropVariantCollection_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Add<Identity: windows_core::IUnknownImpl + ISideShowPropVariantCollection_Impl>(this: *mut core::ffi::c_void, pvalue: *const super::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowPropVariantCollection_Impl::Add(this, core::mem::transmute_copy(&pvalue)).into()
+            }
+        }
+        unsafe extern "system" fn Clear<Identity: windows_core::IUnknownImpl + ISideShowPropVariantCollection_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowPropVariantCollection_Impl::Clear(this).into()
+            }
+        }
+        unsafe extern "system" fn GetAt<Identity: windows_core::IUnknownImpl + ISideShowPropVariantCollection_Impl>(this: *mut core::ffi::c_void, dwindex: u32, pvalue: *mut super::Com::StructuredStorage::PROPVARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowPropVariantCollection_Impl::GetAt(this, core::mem::transmute_copy(&dwindex), core::mem::transmute_copy(&pvalue)).into()
+            }
+        }
+        unsafe extern "system" fn GetCount<Identity: windows_core::IUnknownImpl + ISideShowPropVariantCollection_Impl>(this: *mut core::ffi::c_void, pcelems: *const u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowPropVariantCollection_Impl::GetCount(this, core::mem::transmute_copy(&pcelems)).into()
+            }
+        }
+        unsafe extern "system" fn RemoveAt<Identity: windows_core::IUnknownImpl + ISideShowPropVariantCollection_Impl>(this: *mut core::ffi::c_void, dwindex: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISideShowPropVariantCollection_Impl::RemoveAt(this, core::mem::transmute_copy(&dwindex)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Add: Add::<Identity>,
+            Clear: Clear::<Identity>,
+            GetAt: GetAt::<Identity>,
+            GetCount: GetCount::<Identity>,
+            RemoveAt: RemoveAt::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISideShowPropVariantCollection {}
 windows_core::imp::define_interface!(ISideShowSession, ISideShowSession_Vtbl, 0xe22331ee_9e7d_4922_9fc2_ab7aa41ce491);
 windows_core::imp::interface_hierarchy!(ISideShowSession, windows_core::IUnknown);
@@ -946,6 +1383,42 @@ impl ISideShowSession_Vtbl {
         iid == &<ISideShowSession as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ISideShowSession_Impl> windows_core::imp::TearOffVtable<Identity> for ISideShowSession_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegisterContent<Identity: windows_core::IUnknownImpl + ISideShowSession_Impl>(this: *mut core::ffi::c_void, in_applicationid: *const windows_core::GUID, in_endpointid: *const windows_core::GUID, out_ppicontent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISideShowSession_Impl::RegisterContent(this, core::mem::transmute_copy(&in_applicationid), core::mem::transmute_copy(&in_endpointid)) {
+                    Ok(ok__) => {
+                        out_ppicontent.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RegisterNotifications<Identity: windows_core::IUnknownImpl + ISideShowSession_Impl>(this: *mut core::ffi::c_void, in_applicationid: *const windows_core::GUID, out_ppinotification: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ISideShowSession_Impl::RegisterNotifications(this, core::mem::transmute_copy(&in_applicationid)) {
+                    Ok(ok__) => {
+                        out_ppinotification.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegisterContent: RegisterContent::<Identity>,
+            RegisterNotifications: RegisterNotifications::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ISideShowSession {}
 #[repr(C, packed(1))]
 #[derive(Clone, Copy, Default)]
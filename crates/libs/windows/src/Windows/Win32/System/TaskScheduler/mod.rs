@@ -76,6 +76,38 @@ impl IAction_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IAction_Impl> windows_core::imp::TearOffVtable<Identity> for IAction_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + IAction_Impl>(this: *mut core::ffi::c_void, pid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAction_Impl::Id(this, core::mem::transmute_copy(&pid)).into()
+            }
+        }
+        unsafe extern "system" fn SetId<Identity: windows_core::IUnknownImpl + IAction_Impl>(this: *mut core::ffi::c_void, id: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAction_Impl::SetId(this, core::mem::transmute(&id)).into()
+            }
+        }
+        unsafe extern "system" fn Type<Identity: windows_core::IUnknownImpl + IAction_Impl>(this: *mut core::ffi::c_void, ptype: *mut TASK_ACTION_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IAction_Impl::Type(this, core::mem::transmute_copy(&ptype)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Id: Id::<Identity>,
+            SetId: SetId::<Identity>,
+            Type: Type::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IAction {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IActionCollection, IActionCollection_Vtbl, 0x02820e19_7b98_4ed2_b2e8_fdccceff619b);
@@ -263,6 +295,105 @@ impl IActionCollection_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IActionCollection_Impl> windows_core::imp::TearOffVtable<Identity> for IActionCollection_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + IActionCollection_Impl>(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActionCollection_Impl::Count(this, core::mem::transmute_copy(&pcount)).into()
+            }
+        }
+        unsafe extern "system" fn get_Item<Identity: windows_core::IUnknownImpl + IActionCollection_Impl>(this: *mut core::ffi::c_void, index: i32, ppaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActionCollection_Impl::get_Item(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        ppaction.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + IActionCollection_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActionCollection_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn XmlText<Identity: windows_core::IUnknownImpl + IActionCollection_Impl>(this: *mut core::ffi::c_void, ptext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActionCollection_Impl::XmlText(this, core::mem::transmute_copy(&ptext)).into()
+            }
+        }
+        unsafe extern "system" fn SetXmlText<Identity: windows_core::IUnknownImpl + IActionCollection_Impl>(this: *mut core::ffi::c_void, text: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActionCollection_Impl::SetXmlText(this, core::mem::transmute(&text)).into()
+            }
+        }
+        unsafe extern "system" fn Create<Identity: windows_core::IUnknownImpl + IActionCollection_Impl>(this: *mut core::ffi::c_void, r#type: TASK_ACTION_TYPE, ppaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IActionCollection_Impl::Create(this, core::mem::transmute_copy(&r#type)) {
+                    Ok(ok__) => {
+                        ppaction.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Remove<Identity: windows_core::IUnknownImpl + IActionCollection_Impl>(this: *mut core::ffi::c_void, index: super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActionCollection_Impl::Remove(this, core::mem::transmute(&index)).into()
+            }
+        }
+        unsafe extern "system" fn Clear<Identity: windows_core::IUnknownImpl + IActionCollection_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActionCollection_Impl::Clear(this).into()
+            }
+        }
+        unsafe extern "system" fn Context<Identity: windows_core::IUnknownImpl + IActionCollection_Impl>(this: *mut core::ffi::c_void, pcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActionCollection_Impl::Context(this, core::mem::transmute_copy(&pcontext)).into()
+            }
+        }
+        unsafe extern "system" fn SetContext<Identity: windows_core::IUnknownImpl + IActionCollection_Impl>(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IActionCollection_Impl::SetContext(this, core::mem::transmute(&context)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Count: Count::<Identity>,
+            get_Item: get_Item::<Identity>,
+            _NewEnum: _NewEnum::<Identity>,
+            XmlText: XmlText::<Identity>,
+            SetXmlText: SetXmlText::<Identity>,
+            Create: Create::<Identity>,
+            Remove: Remove::<Identity>,
+            Clear: Clear::<Identity>,
+            Context: Context::<Identity>,
+            SetContext: SetContext::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IActionCollection {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IBootTrigger, IBootTrigger_Vtbl, 0x2a9c35da_d357_41f4_bbc1_207ac1b1f3cb);
@@ -319,6 +450,27 @@ impl IBootTrigger_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IBootTrigger_Impl> windows_core::imp::TearOffVtable<Identity> for IBootTrigger_Vtbl
+where
+    ITrigger_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Delay<Identity: windows_core::IUnknownImpl + IBootTrigger_Impl>(this: *mut core::ffi::c_void, pdelay: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBootTrigger_Impl::Delay(this, core::mem::transmute_copy(&pdelay)).into()
+            }
+        }
+        unsafe extern "system" fn SetDelay<Identity: windows_core::IUnknownImpl + IBootTrigger_Impl>(this: *mut core::ffi::c_void, delay: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IBootTrigger_Impl::SetDelay(this, core::mem::transmute(&delay)).into()
+            }
+        }
+        Self { base__: <ITrigger_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Delay: Delay::<Identity>, SetDelay: SetDelay::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IBootTrigger {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IComHandlerAction, IComHandlerAction_Vtbl, 0x6d2fd252_75c5_4f66_90ba_2a7d8cc3039f);
@@ -403,6 +555,45 @@ impl IComHandlerAction_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IComHandlerAction_Impl> windows_core::imp::TearOffVtable<Identity> for IComHandlerAction_Vtbl
+where
+    IAction_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ClassId<Identity: windows_core::IUnknownImpl + IComHandlerAction_Impl>(this: *mut core::ffi::c_void, pclsid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IComHandlerAction_Impl::ClassId(this, core::mem::transmute_copy(&pclsid)).into()
+            }
+        }
+        unsafe extern "system" fn SetClassId<Identity: windows_core::IUnknownImpl + IComHandlerAction_Impl>(this: *mut core::ffi::c_void, clsid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IComHandlerAction_Impl::SetClassId(this, core::mem::transmute(&clsid)).into()
+            }
+        }
+        unsafe extern "system" fn Data<Identity: windows_core::IUnknownImpl + IComHandlerAction_Impl>(this: *mut core::ffi::c_void, pdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IComHandlerAction_Impl::Data(this, core::mem::transmute_copy(&pdata)).into()
+            }
+        }
+        unsafe extern "system" fn SetData<Identity: windows_core::IUnknownImpl + IComHandlerAction_Impl>(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IComHandlerAction_Impl::SetData(this, core::mem::transmute(&data)).into()
+            }
+        }
+        Self {
+            base__: <IAction_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ClassId: ClassId::<Identity>,
+            SetClassId: SetClassId::<Identity>,
+            Data: Data::<Identity>,
+            SetData: SetData::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IComHandlerAction {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IDailyTrigger, IDailyTrigger_Vtbl, 0x126c5cd8_b288_41d5_8dbf_e491446adc5c);
@@ -487,6 +678,45 @@ impl IDailyTrigger_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IDailyTrigger_Impl> windows_core::imp::TearOffVtable<Identity> for IDailyTrigger_Vtbl
+where
+    ITrigger_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DaysInterval<Identity: windows_core::IUnknownImpl + IDailyTrigger_Impl>(this: *mut core::ffi::c_void, pdays: *mut i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDailyTrigger_Impl::DaysInterval(this, core::mem::transmute_copy(&pdays)).into()
+            }
+        }
+        unsafe extern "system" fn SetDaysInterval<Identity: windows_core::IUnknownImpl + IDailyTrigger_Impl>(this: *mut core::ffi::c_void, days: i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDailyTrigger_Impl::SetDaysInterval(this, core::mem::transmute_copy(&days)).into()
+            }
+        }
+        unsafe extern "system" fn RandomDelay<Identity: windows_core::IUnknownImpl + IDailyTrigger_Impl>(this: *mut core::ffi::c_void, prandomdelay: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDailyTrigger_Impl::RandomDelay(this, core::mem::transmute_copy(&prandomdelay)).into()
+            }
+        }
+        unsafe extern "system" fn SetRandomDelay<Identity: windows_core::IUnknownImpl + IDailyTrigger_Impl>(this: *mut core::ffi::c_void, randomdelay: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IDailyTrigger_Impl::SetRandomDelay(this, core::mem::transmute(&randomdelay)).into()
+            }
+        }
+        Self {
+            base__: <ITrigger_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DaysInterval: DaysInterval::<Identity>,
+            SetDaysInterval: SetDaysInterval::<Identity>,
+            RandomDelay: RandomDelay::<Identity>,
+            SetRandomDelay: SetRandomDelay::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IDailyTrigger {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IEmailAction, IEmailAction_Vtbl, 0x10f62c64_7e16_4314_a0c2_0c3683f99d40);
@@ -775,6 +1005,163 @@ impl IEmailAction_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IEmailAction_Impl> windows_core::imp::TearOffVtable<Identity> for IEmailAction_Vtbl
+where
+    IAction_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Server<Identity: windows_core::IUnknownImpl + IEmailAction_Impl>(this: *mut core::ffi::c_void, pserver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEmailAction_Impl::Server(this, core::mem::transmute_copy(&pserver)).into()
+            }
+        }
+        unsafe extern "system" fn SetServer<Identity: windows_core::IUnknownImpl + IEmailAction_Impl>(this: *mut core::ffi::c_void, server: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEmailAction_Impl::SetServer(this, core::mem::transmute(&server)).into()
+            }
+        }
+        unsafe extern "system" fn Subject<Identity: windows_core::IUnknownImpl + IEmailAction_Impl>(this: *mut core::ffi::c_void, psubject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEmailAction_Impl::Subject(this, core::mem::transmute_copy(&psubject)).into()
+            }
+        }
+        unsafe extern "system" fn SetSubject<Identity: windows_core::IUnknownImpl + IEmailAction_Impl>(this: *mut core::ffi::c_void, subject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEmailAction_Impl::SetSubject(this, core::mem::transmute(&subject)).into()
+            }
+        }
+        unsafe extern "system" fn To<Identity: windows_core::IUnknownImpl + IEmailAction_Impl>(this: *mut core::ffi::c_void, pto: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEmailAction_Impl::To(this, core::mem::transmute_copy(&pto)).into()
+            }
+        }
+        unsafe extern "system" fn SetTo<Identity: windows_core::IUnknownImpl + IEmailAction_Impl>(this: *mut core::ffi::c_void, to: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEmailAction_Impl::SetTo(this, core::mem::transmute(&to)).into()
+            }
+        }
+        unsafe extern "system" fn Cc<Identity: windows_core::IUnknownImpl + IEmailAction_Impl>(this: *mut core::ffi::c_void, pcc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEmailAction_Impl::Cc(this, core::mem::transmute_copy(&pcc)).into()
+            }
+        }
+        unsafe extern "system" fn SetCc<Identity: windows_core::IUnknownImpl + IEmailAction_Impl>(this: *mut core::ffi::c_void, cc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEmailAction_Impl::SetCc(this, core::mem::transmute(&cc)).into()
+            }
+        }
+        unsafe extern "system" fn Bcc<Identity: windows_core::IUnknownImpl + IEmailAction_Impl>(this: *mut core::ffi::c_void, pbcc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEmailAction_Impl::Bcc(this, core::mem::transmute_copy(&pbcc)).into()
+            }
+        }
+        unsafe extern "system" fn SetBcc<Identity: windows_core::IUnknownImpl + IEmailAction_Impl>(this: *mut core::ffi::c_void, bcc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEmailAction_Impl::SetBcc(this, core::mem::transmute(&bcc)).into()
+            }
+        }
+        unsafe extern "system" fn ReplyTo<Identity: windows_core::IUnknownImpl + IEmailAction_Impl>(this: *mut core::ffi::c_void, preplyto: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEmailAction_Impl::ReplyTo(this, core::mem::transmute_copy(&preplyto)).into()
+            }
+        }
+        unsafe extern "system" fn SetReplyTo<Identity: windows_core::IUnknownImpl + IEmailAction_Impl>(this: *mut core::ffi::c_void, replyto: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEmailAction_Impl::SetReplyTo(this, core::mem::transmute(&replyto)).into()
+            }
+        }
+        unsafe extern "system" fn From<Identity: windows_core::IUnknownImpl + IEmailAction_Impl>(this: *mut core::ffi::c_void, pfrom: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEmailAction_Impl::From(this, core::mem::transmute_copy(&pfrom)).into()
+            }
+        }
+        unsafe extern "system" fn SetFrom<Identity: windows_core::IUnknownImpl + IEmailAction_Impl>(this: *mut core::ffi::c_void, from: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEmailAction_Impl::SetFrom(this, core::mem::transmute(&from)).into()
+            }
+        }
+        unsafe extern "system" fn HeaderFields<Identity: windows_core::IUnknownImpl + IEmailAction_Impl>(this: *mut core::ffi::c_void, ppheaderfields: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEmailAction_Impl::HeaderFields(this) {
+                    Ok(ok__) => {
+                        ppheaderfields.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetHeaderFields<Identity: windows_core::IUnknownImpl + IEmailAction_Impl>(this: *mut core::ffi::c_void, pheaderfields: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEmailAction_Impl::SetHeaderFields(this, core::mem::transmute_copy(&pheaderfields)).into()
+            }
+        }
+        unsafe extern "system" fn Body<Identity: windows_core::IUnknownImpl + IEmailAction_Impl>(this: *mut core::ffi::c_void, pbody: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEmailAction_Impl::Body(this, core::mem::transmute_copy(&pbody)).into()
+            }
+        }
+        unsafe extern "system" fn SetBody<Identity: windows_core::IUnknownImpl + IEmailAction_Impl>(this: *mut core::ffi::c_void, body: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEmailAction_Impl::SetBody(this, core::mem::transmute(&body)).into()
+            }
+        }
+        unsafe extern "system" fn Attachments<Identity: windows_core::IUnknownImpl + IEmailAction_Impl>(this: *mut core::ffi::c_void, pattachements: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEmailAction_Impl::Attachments(this, core::mem::transmute_copy(&pattachements)).into()
+            }
+        }
+        unsafe extern "system" fn SetAttachments<Identity: windows_core::IUnknownImpl + IEmailAction_Impl>(this: *mut core::ffi::c_void, pattachements: *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEmailAction_Impl::SetAttachments(this, core::mem::transmute_copy(&pattachements)).into()
+            }
+        }
+        Self {
+            base__: <IAction_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Server: Server::<Identity>,
+            SetServer: SetServer::<Identity>,
+            Subject: Subject::<Identity>,
+            SetSubject: SetSubject::<Identity>,
+            To: To::<Identity>,
+            SetTo: SetTo::<Identity>,
+            Cc: Cc::<Identity>,
+            SetCc: SetCc::<Identity>,
+            Bcc: Bcc::<Identity>,
+            SetBcc: SetBcc::<Identity>,
+            ReplyTo: ReplyTo::<Identity>,
+            SetReplyTo: SetReplyTo::<Identity>,
+            From: From::<Identity>,
+            SetFrom: SetFrom::<Identity>,
+            HeaderFields: HeaderFields::<Identity>,
+            SetHeaderFields: SetHeaderFields::<Identity>,
+            Body: Body::<Identity>,
+            SetBody: SetBody::<Identity>,
+            Attachments: Attachments::<Identity>,
+            SetAttachments: SetAttachments::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IEmailAction {}
 windows_core::imp::define_interface!(IEnumWorkItems, IEnumWorkItems_Vtbl, 0x148bd528_a2ab_11ce_b11f_00aa00530503);
 windows_core::imp::interface_hierarchy!(IEnumWorkItems, windows_core::IUnknown);
@@ -854,6 +1241,50 @@ impl IEnumWorkItems_Vtbl {
         iid == &<IEnumWorkItems as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IEnumWorkItems_Impl> windows_core::imp::TearOffVtable<Identity> for IEnumWorkItems_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Next<Identity: windows_core::IUnknownImpl + IEnumWorkItems_Impl>(this: *mut core::ffi::c_void, celt: u32, rgpwsznames: *mut *mut windows_core::PWSTR, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumWorkItems_Impl::Next(this, core::mem::transmute_copy(&celt), core::mem::transmute_copy(&rgpwsznames), core::mem::transmute_copy(&pceltfetched))
+            }
+        }
+        unsafe extern "system" fn Skip<Identity: windows_core::IUnknownImpl + IEnumWorkItems_Impl>(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumWorkItems_Impl::Skip(this, core::mem::transmute_copy(&celt))
+            }
+        }
+        unsafe extern "system" fn Reset<Identity: windows_core::IUnknownImpl + IEnumWorkItems_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEnumWorkItems_Impl::Reset(this).into()
+            }
+        }
+        unsafe extern "system" fn Clone<Identity: windows_core::IUnknownImpl + IEnumWorkItems_Impl>(this: *mut core::ffi::c_void, ppenumworkitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEnumWorkItems_Impl::Clone(this) {
+                    Ok(ok__) => {
+                        ppenumworkitems.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Next: Next::<Identity>,
+            Skip: Skip::<Identity>,
+            Reset: Reset::<Identity>,
+            Clone: Clone::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IEnumWorkItems {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IEventTrigger, IEventTrigger_Vtbl, 0xd45b0167_9653_4eef_b94f_0732ca7af251);
@@ -974,27 +1405,86 @@ impl IEventTrigger_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IEventTrigger {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IExecAction, IExecAction_Vtbl, 0x4c3d624d_fd6b_49a3_b9b7_09cb3cd3f047);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IExecAction {
-    type Target = IAction;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IExecAction, windows_core::IUnknown, super::Com::IDispatch, IAction);
-#[cfg(feature = "Win32_System_Com")]
-impl IExecAction {
-    pub unsafe fn Path(&self, ppath: *mut windows_core::BSTR) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Path)(windows_core::Interface::as_raw(self), core::mem::transmute(ppath)).ok() }
-    }
-    pub unsafe fn SetPath(&self, path: &windows_core::BSTR) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetPath)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(path)).ok() }
-    }
-    pub unsafe fn Arguments(&self, pargument: *mut windows_core::BSTR) -> windows_core::Result<()> {
+impl<Identity: windows_core::IUnknownImpl + IEventTrigger_Impl> windows_core::imp::TearOffVtable<Identity> for IEventTrigger_Vtbl
+where
+    ITrigger_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Subscription<Identity: windows_core::IUnknownImpl + IEventTrigger_Impl>(this: *mut core::ffi::c_void, pquery: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventTrigger_Impl::Subscription(this, core::mem::transmute_copy(&pquery)).into()
+            }
+        }
+        unsafe extern "system" fn SetSubscription<Identity: windows_core::IUnknownImpl + IEventTrigger_Impl>(this: *mut core::ffi::c_void, query: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventTrigger_Impl::SetSubscription(this, core::mem::transmute(&query)).into()
+            }
+        }
+        unsafe extern "system" fn Delay<Identity: windows_core::IUnknownImpl + IEventTrigger_Impl>(this: *mut core::ffi::c_void, pdelay: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventTrigger_Impl::Delay(this, core::mem::transmute_copy(&pdelay)).into()
+            }
+        }
+        unsafe extern "system" fn SetDelay<Identity: windows_core::IUnknownImpl + IEventTrigger_Impl>(this: *mut core::ffi::c_void, delay: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventTrigger_Impl::SetDelay(this, core::mem::transmute(&delay)).into()
+            }
+        }
+        unsafe extern "system" fn ValueQueries<Identity: windows_core::IUnknownImpl + IEventTrigger_Impl>(this: *mut core::ffi::c_void, ppnamedxpaths: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IEventTrigger_Impl::ValueQueries(this) {
+                    Ok(ok__) => {
+                        ppnamedxpaths.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetValueQueries<Identity: windows_core::IUnknownImpl + IEventTrigger_Impl>(this: *mut core::ffi::c_void, pnamedxpaths: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IEventTrigger_Impl::SetValueQueries(this, core::mem::transmute_copy(&pnamedxpaths)).into()
+            }
+        }
+        Self {
+            base__: <ITrigger_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Subscription: Subscription::<Identity>,
+            SetSubscription: SetSubscription::<Identity>,
+            Delay: Delay::<Identity>,
+            SetDelay: SetDelay::<Identity>,
+            ValueQueries: ValueQueries::<Identity>,
+            SetValueQueries: SetValueQueries::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IEventTrigger {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(IExecAction, IExecAction_Vtbl, 0x4c3d624d_fd6b_49a3_b9b7_09cb3cd3f047);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for IExecAction {
+    type Target = IAction;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(IExecAction, windows_core::IUnknown, super::Com::IDispatch, IAction);
+#[cfg(feature = "Win32_System_Com")]
+impl IExecAction {
+    pub unsafe fn Path(&self, ppath: *mut windows_core::BSTR) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Path)(windows_core::Interface::as_raw(self), core::mem::transmute(ppath)).ok() }
+    }
+    pub unsafe fn SetPath(&self, path: &windows_core::BSTR) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetPath)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(path)).ok() }
+    }
+    pub unsafe fn Arguments(&self, pargument: *mut windows_core::BSTR) -> windows_core::Result<()> {
         unsafe { (windows_core::Interface::vtable(self).Arguments)(windows_core::Interface::as_raw(self), core::mem::transmute(pargument)).ok() }
     }
     pub unsafe fn SetArguments(&self, argument: &windows_core::BSTR) -> windows_core::Result<()> {
@@ -1082,6 +1572,59 @@ impl IExecAction_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IExecAction_Impl> windows_core::imp::TearOffVtable<Identity> for IExecAction_Vtbl
+where
+    IAction_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Path<Identity: windows_core::IUnknownImpl + IExecAction_Impl>(this: *mut core::ffi::c_void, ppath: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IExecAction_Impl::Path(this, core::mem::transmute_copy(&ppath)).into()
+            }
+        }
+        unsafe extern "system" fn SetPath<Identity: windows_core::IUnknownImpl + IExecAction_Impl>(this: *mut core::ffi::c_void, path: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IExecAction_Impl::SetPath(this, core::mem::transmute(&path)).into()
+            }
+        }
+        unsafe extern "system" fn Arguments<Identity: windows_core::IUnknownImpl + IExecAction_Impl>(this: *mut core::ffi::c_void, pargument: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IExecAction_Impl::Arguments(this, core::mem::transmute_copy(&pargument)).into()
+            }
+        }
+        unsafe extern "system" fn SetArguments<Identity: windows_core::IUnknownImpl + IExecAction_Impl>(this: *mut core::ffi::c_void, argument: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IExecAction_Impl::SetArguments(this, core::mem::transmute(&argument)).into()
+            }
+        }
+        unsafe extern "system" fn WorkingDirectory<Identity: windows_core::IUnknownImpl + IExecAction_Impl>(this: *mut core::ffi::c_void, pworkingdirectory: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IExecAction_Impl::WorkingDirectory(this, core::mem::transmute_copy(&pworkingdirectory)).into()
+            }
+        }
+        unsafe extern "system" fn SetWorkingDirectory<Identity: windows_core::IUnknownImpl + IExecAction_Impl>(this: *mut core::ffi::c_void, workingdirectory: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IExecAction_Impl::SetWorkingDirectory(this, core::mem::transmute(&workingdirectory)).into()
+            }
+        }
+        Self {
+            base__: <IAction_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Path: Path::<Identity>,
+            SetPath: SetPath::<Identity>,
+            Arguments: Arguments::<Identity>,
+            SetArguments: SetArguments::<Identity>,
+            WorkingDirectory: WorkingDirectory::<Identity>,
+            SetWorkingDirectory: SetWorkingDirectory::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IExecAction {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IExecAction2, IExecAction2_Vtbl, 0xf2a82542_bda5_4e6b_9143_e2bf4f8987b6);
@@ -1142,6 +1685,31 @@ impl IExecAction2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IExecAction2_Impl> windows_core::imp::TearOffVtable<Identity> for IExecAction2_Vtbl
+where
+    IExecAction_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn HideAppWindow<Identity: windows_core::IUnknownImpl + IExecAction2_Impl>(this: *mut core::ffi::c_void, phideappwindow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IExecAction2_Impl::HideAppWindow(this, core::mem::transmute_copy(&phideappwindow)).into()
+            }
+        }
+        unsafe extern "system" fn SetHideAppWindow<Identity: windows_core::IUnknownImpl + IExecAction2_Impl>(this: *mut core::ffi::c_void, hideappwindow: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IExecAction2_Impl::SetHideAppWindow(this, core::mem::transmute_copy(&hideappwindow)).into()
+            }
+        }
+        Self {
+            base__: <IExecAction_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            HideAppWindow: HideAppWindow::<Identity>,
+            SetHideAppWindow: SetHideAppWindow::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IExecAction2 {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IIdleSettings, IIdleSettings_Vtbl, 0x84594461_0053_4342_a8fd_088fabf11f32);
@@ -1274,6 +1842,73 @@ impl IIdleSettings_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IIdleSettings_Impl> windows_core::imp::TearOffVtable<Identity> for IIdleSettings_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn IdleDuration<Identity: windows_core::IUnknownImpl + IIdleSettings_Impl>(this: *mut core::ffi::c_void, pdelay: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIdleSettings_Impl::IdleDuration(this, core::mem::transmute_copy(&pdelay)).into()
+            }
+        }
+        unsafe extern "system" fn SetIdleDuration<Identity: windows_core::IUnknownImpl + IIdleSettings_Impl>(this: *mut core::ffi::c_void, delay: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIdleSettings_Impl::SetIdleDuration(this, core::mem::transmute(&delay)).into()
+            }
+        }
+        unsafe extern "system" fn WaitTimeout<Identity: windows_core::IUnknownImpl + IIdleSettings_Impl>(this: *mut core::ffi::c_void, ptimeout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIdleSettings_Impl::WaitTimeout(this, core::mem::transmute_copy(&ptimeout)).into()
+            }
+        }
+        unsafe extern "system" fn SetWaitTimeout<Identity: windows_core::IUnknownImpl + IIdleSettings_Impl>(this: *mut core::ffi::c_void, timeout: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIdleSettings_Impl::SetWaitTimeout(this, core::mem::transmute(&timeout)).into()
+            }
+        }
+        unsafe extern "system" fn StopOnIdleEnd<Identity: windows_core::IUnknownImpl + IIdleSettings_Impl>(this: *mut core::ffi::c_void, pstop: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIdleSettings_Impl::StopOnIdleEnd(this, core::mem::transmute_copy(&pstop)).into()
+            }
+        }
+        unsafe extern "system" fn SetStopOnIdleEnd<Identity: windows_core::IUnknownImpl + IIdleSettings_Impl>(this: *mut core::ffi::c_void, stop: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIdleSettings_Impl::SetStopOnIdleEnd(this, core::mem::transmute_copy(&stop)).into()
+            }
+        }
+        unsafe extern "system" fn RestartOnIdle<Identity: windows_core::IUnknownImpl + IIdleSettings_Impl>(this: *mut core::ffi::c_void, prestart: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIdleSettings_Impl::RestartOnIdle(this, core::mem::transmute_copy(&prestart)).into()
+            }
+        }
+        unsafe extern "system" fn SetRestartOnIdle<Identity: windows_core::IUnknownImpl + IIdleSettings_Impl>(this: *mut core::ffi::c_void, restart: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IIdleSettings_Impl::SetRestartOnIdle(this, core::mem::transmute_copy(&restart)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            IdleDuration: IdleDuration::<Identity>,
+            SetIdleDuration: SetIdleDuration::<Identity>,
+            WaitTimeout: WaitTimeout::<Identity>,
+            SetWaitTimeout: SetWaitTimeout::<Identity>,
+            StopOnIdleEnd: StopOnIdleEnd::<Identity>,
+            SetStopOnIdleEnd: SetStopOnIdleEnd::<Identity>,
+            RestartOnIdle: RestartOnIdle::<Identity>,
+            SetRestartOnIdle: SetRestartOnIdle::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IIdleSettings {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IIdleTrigger, IIdleTrigger_Vtbl, 0xd537d2b0_9fb3_4d34_9739_1ff5ce7b1ef3);
@@ -1304,6 +1939,13 @@ impl IIdleTrigger_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IIdleTrigger_Impl> windows_core::imp::TearOffVtable<Identity> for IIdleTrigger_Vtbl
+where
+    ITrigger_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <ITrigger_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IIdleTrigger {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ILogonTrigger, ILogonTrigger_Vtbl, 0x72dade38_fae4_4b3e_baf4_5d009af02b1c);
@@ -1388,6 +2030,45 @@ impl ILogonTrigger_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ILogonTrigger_Impl> windows_core::imp::TearOffVtable<Identity> for ILogonTrigger_Vtbl
+where
+    ITrigger_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Delay<Identity: windows_core::IUnknownImpl + ILogonTrigger_Impl>(this: *mut core::ffi::c_void, pdelay: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILogonTrigger_Impl::Delay(this, core::mem::transmute_copy(&pdelay)).into()
+            }
+        }
+        unsafe extern "system" fn SetDelay<Identity: windows_core::IUnknownImpl + ILogonTrigger_Impl>(this: *mut core::ffi::c_void, delay: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILogonTrigger_Impl::SetDelay(this, core::mem::transmute(&delay)).into()
+            }
+        }
+        unsafe extern "system" fn UserId<Identity: windows_core::IUnknownImpl + ILogonTrigger_Impl>(this: *mut core::ffi::c_void, puser: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILogonTrigger_Impl::UserId(this, core::mem::transmute_copy(&puser)).into()
+            }
+        }
+        unsafe extern "system" fn SetUserId<Identity: windows_core::IUnknownImpl + ILogonTrigger_Impl>(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ILogonTrigger_Impl::SetUserId(this, core::mem::transmute(&user)).into()
+            }
+        }
+        Self {
+            base__: <ITrigger_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Delay: Delay::<Identity>,
+            SetDelay: SetDelay::<Identity>,
+            UserId: UserId::<Identity>,
+            SetUserId: SetUserId::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ILogonTrigger {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IMaintenanceSettings, IMaintenanceSettings_Vtbl, 0xa6024fa8_9652_4adb_a6bf_5cfcd877a7ba);
@@ -1496,6 +2177,59 @@ impl IMaintenanceSettings_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IMaintenanceSettings_Impl> windows_core::imp::TearOffVtable<Identity> for IMaintenanceSettings_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetPeriod<Identity: windows_core::IUnknownImpl + IMaintenanceSettings_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMaintenanceSettings_Impl::SetPeriod(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Period<Identity: windows_core::IUnknownImpl + IMaintenanceSettings_Impl>(this: *mut core::ffi::c_void, target: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMaintenanceSettings_Impl::Period(this, core::mem::transmute_copy(&target)).into()
+            }
+        }
+        unsafe extern "system" fn SetDeadline<Identity: windows_core::IUnknownImpl + IMaintenanceSettings_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMaintenanceSettings_Impl::SetDeadline(this, core::mem::transmute(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Deadline<Identity: windows_core::IUnknownImpl + IMaintenanceSettings_Impl>(this: *mut core::ffi::c_void, target: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMaintenanceSettings_Impl::Deadline(this, core::mem::transmute_copy(&target)).into()
+            }
+        }
+        unsafe extern "system" fn SetExclusive<Identity: windows_core::IUnknownImpl + IMaintenanceSettings_Impl>(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMaintenanceSettings_Impl::SetExclusive(this, core::mem::transmute_copy(&value)).into()
+            }
+        }
+        unsafe extern "system" fn Exclusive<Identity: windows_core::IUnknownImpl + IMaintenanceSettings_Impl>(this: *mut core::ffi::c_void, target: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMaintenanceSettings_Impl::Exclusive(this, core::mem::transmute_copy(&target)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetPeriod: SetPeriod::<Identity>,
+            Period: Period::<Identity>,
+            SetDeadline: SetDeadline::<Identity>,
+            Deadline: Deadline::<Identity>,
+            SetExclusive: SetExclusive::<Identity>,
+            Exclusive: Exclusive::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IMaintenanceSettings {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IMonthlyDOWTrigger, IMonthlyDOWTrigger_Vtbl, 0x77d025a3_90fa_43aa_b52e_cda5499b946a);
@@ -1652,6 +2386,87 @@ impl IMonthlyDOWTrigger_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IMonthlyDOWTrigger_Impl> windows_core::imp::TearOffVtable<Identity> for IMonthlyDOWTrigger_Vtbl
+where
+    ITrigger_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DaysOfWeek<Identity: windows_core::IUnknownImpl + IMonthlyDOWTrigger_Impl>(this: *mut core::ffi::c_void, pdays: *mut i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMonthlyDOWTrigger_Impl::DaysOfWeek(this, core::mem::transmute_copy(&pdays)).into()
+            }
+        }
+        unsafe extern "system" fn SetDaysOfWeek<Identity: windows_core::IUnknownImpl + IMonthlyDOWTrigger_Impl>(this: *mut core::ffi::c_void, days: i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMonthlyDOWTrigger_Impl::SetDaysOfWeek(this, core::mem::transmute_copy(&days)).into()
+            }
+        }
+        unsafe extern "system" fn WeeksOfMonth<Identity: windows_core::IUnknownImpl + IMonthlyDOWTrigger_Impl>(this: *mut core::ffi::c_void, pweeks: *mut i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMonthlyDOWTrigger_Impl::WeeksOfMonth(this, core::mem::transmute_copy(&pweeks)).into()
+            }
+        }
+        unsafe extern "system" fn SetWeeksOfMonth<Identity: windows_core::IUnknownImpl + IMonthlyDOWTrigger_Impl>(this: *mut core::ffi::c_void, weeks: i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMonthlyDOWTrigger_Impl::SetWeeksOfMonth(this, core::mem::transmute_copy(&weeks)).into()
+            }
+        }
+        unsafe extern "system" fn MonthsOfYear<Identity: windows_core::IUnknownImpl + IMonthlyDOWTrigger_Impl>(this: *mut core::ffi::c_void, pmonths: *mut i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMonthlyDOWTrigger_Impl::MonthsOfYear(this, core::mem::transmute_copy(&pmonths)).into()
+            }
+        }
+        unsafe extern "system" fn SetMonthsOfYear<Identity: windows_core::IUnknownImpl + IMonthlyDOWTrigger_Impl>(this: *mut core::ffi::c_void, months: i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMonthlyDOWTrigger_Impl::SetMonthsOfYear(this, core::mem::transmute_copy(&months)).into()
+            }
+        }
+        unsafe extern "system" fn RunOnLastWeekOfMonth<Identity: windows_core::IUnknownImpl + IMonthlyDOWTrigger_Impl>(this: *mut core::ffi::c_void, plastweek: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMonthlyDOWTrigger_Impl::RunOnLastWeekOfMonth(this, core::mem::transmute_copy(&plastweek)).into()
+            }
+        }
+        unsafe extern "system" fn SetRunOnLastWeekOfMonth<Identity: windows_core::IUnknownImpl + IMonthlyDOWTrigger_Impl>(this: *mut core::ffi::c_void, lastweek: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMonthlyDOWTrigger_Impl::SetRunOnLastWeekOfMonth(this, core::mem::transmute_copy(&lastweek)).into()
+            }
+        }
+        unsafe extern "system" fn RandomDelay<Identity: windows_core::IUnknownImpl + IMonthlyDOWTrigger_Impl>(this: *mut core::ffi::c_void, prandomdelay: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMonthlyDOWTrigger_Impl::RandomDelay(this, core::mem::transmute_copy(&prandomdelay)).into()
+            }
+        }
+        unsafe extern "system" fn SetRandomDelay<Identity: windows_core::IUnknownImpl + IMonthlyDOWTrigger_Impl>(this: *mut core::ffi::c_void, randomdelay: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMonthlyDOWTrigger_Impl::SetRandomDelay(this, core::mem::transmute(&randomdelay)).into()
+            }
+        }
+        Self {
+            base__: <ITrigger_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DaysOfWeek: DaysOfWeek::<Identity>,
+            SetDaysOfWeek: SetDaysOfWeek::<Identity>,
+            WeeksOfMonth: WeeksOfMonth::<Identity>,
+            SetWeeksOfMonth: SetWeeksOfMonth::<Identity>,
+            MonthsOfYear: MonthsOfYear::<Identity>,
+            SetMonthsOfYear: SetMonthsOfYear::<Identity>,
+            RunOnLastWeekOfMonth: RunOnLastWeekOfMonth::<Identity>,
+            SetRunOnLastWeekOfMonth: SetRunOnLastWeekOfMonth::<Identity>,
+            RandomDelay: RandomDelay::<Identity>,
+            SetRandomDelay: SetRandomDelay::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IMonthlyDOWTrigger {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IMonthlyTrigger, IMonthlyTrigger_Vtbl, 0x97c45ef1_6b02_4a1a_9c0e_1ebfba1500ac);
@@ -1784,6 +2599,73 @@ impl IMonthlyTrigger_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IMonthlyTrigger_Impl> windows_core::imp::TearOffVtable<Identity> for IMonthlyTrigger_Vtbl
+where
+    ITrigger_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DaysOfMonth<Identity: windows_core::IUnknownImpl + IMonthlyTrigger_Impl>(this: *mut core::ffi::c_void, pdays: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMonthlyTrigger_Impl::DaysOfMonth(this, core::mem::transmute_copy(&pdays)).into()
+            }
+        }
+        unsafe extern "system" fn SetDaysOfMonth<Identity: windows_core::IUnknownImpl + IMonthlyTrigger_Impl>(this: *mut core::ffi::c_void, days: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMonthlyTrigger_Impl::SetDaysOfMonth(this, core::mem::transmute_copy(&days)).into()
+            }
+        }
+        unsafe extern "system" fn MonthsOfYear<Identity: windows_core::IUnknownImpl + IMonthlyTrigger_Impl>(this: *mut core::ffi::c_void, pmonths: *mut i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMonthlyTrigger_Impl::MonthsOfYear(this, core::mem::transmute_copy(&pmonths)).into()
+            }
+        }
+        unsafe extern "system" fn SetMonthsOfYear<Identity: windows_core::IUnknownImpl + IMonthlyTrigger_Impl>(this: *mut core::ffi::c_void, months: i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMonthlyTrigger_Impl::SetMonthsOfYear(this, core::mem::transmute_copy(&months)).into()
+            }
+        }
+        unsafe extern "system" fn RunOnLastDayOfMonth<Identity: windows_core::IUnknownImpl + IMonthlyTrigger_Impl>(this: *mut core::ffi::c_void, plastday: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMonthlyTrigger_Impl::RunOnLastDayOfMonth(this, core::mem::transmute_copy(&plastday)).into()
+            }
+        }
+        unsafe extern "system" fn SetRunOnLastDayOfMonth<Identity: windows_core::IUnknownImpl + IMonthlyTrigger_Impl>(this: *mut core::ffi::c_void, lastday: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMonthlyTrigger_Impl::SetRunOnLastDayOfMonth(this, core::mem::transmute_copy(&lastday)).into()
+            }
+        }
+        unsafe extern "system" fn RandomDelay<Identity: windows_core::IUnknownImpl + IMonthlyTrigger_Impl>(this: *mut core::ffi::c_void, prandomdelay: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMonthlyTrigger_Impl::RandomDelay(this, core::mem::transmute_copy(&prandomdelay)).into()
+            }
+        }
+        unsafe extern "system" fn SetRandomDelay<Identity: windows_core::IUnknownImpl + IMonthlyTrigger_Impl>(this: *mut core::ffi::c_void, randomdelay: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IMonthlyTrigger_Impl::SetRandomDelay(this, core::mem::transmute(&randomdelay)).into()
+            }
+        }
+        Self {
+            base__: <ITrigger_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DaysOfMonth: DaysOfMonth::<Identity>,
+            SetDaysOfMonth: SetDaysOfMonth::<Identity>,
+            MonthsOfYear: MonthsOfYear::<Identity>,
+            SetMonthsOfYear: SetMonthsOfYear::<Identity>,
+            RunOnLastDayOfMonth: RunOnLastDayOfMonth::<Identity>,
+            SetRunOnLastDayOfMonth: SetRunOnLastDayOfMonth::<Identity>,
+            RandomDelay: RandomDelay::<Identity>,
+            SetRandomDelay: SetRandomDelay::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IMonthlyTrigger {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(INetworkSettings, INetworkSettings_Vtbl, 0x9f7dea84_c30b_4245_80b6_00e9f646f1b4);
@@ -1868,12 +2750,51 @@ impl INetworkSettings_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for INetworkSettings {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IPrincipal, IPrincipal_Vtbl, 0xd98d51e5_c9b4_496a_a9c1_18980261cf0f);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IPrincipal {
-    type Target = super::Com::IDispatch;
+impl<Identity: windows_core::IUnknownImpl + INetworkSettings_Impl> windows_core::imp::TearOffVtable<Identity> for INetworkSettings_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + INetworkSettings_Impl>(this: *mut core::ffi::c_void, pname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetworkSettings_Impl::Name(this, core::mem::transmute_copy(&pname)).into()
+            }
+        }
+        unsafe extern "system" fn SetName<Identity: windows_core::IUnknownImpl + INetworkSettings_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetworkSettings_Impl::SetName(this, core::mem::transmute(&name)).into()
+            }
+        }
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + INetworkSettings_Impl>(this: *mut core::ffi::c_void, pid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetworkSettings_Impl::Id(this, core::mem::transmute_copy(&pid)).into()
+            }
+        }
+        unsafe extern "system" fn SetId<Identity: windows_core::IUnknownImpl + INetworkSettings_Impl>(this: *mut core::ffi::c_void, id: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                INetworkSettings_Impl::SetId(this, core::mem::transmute(&id)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Name: Name::<Identity>,
+            SetName: SetName::<Identity>,
+            Id: Id::<Identity>,
+            SetId: SetId::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for INetworkSettings {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(IPrincipal, IPrincipal_Vtbl, 0xd98d51e5_c9b4_496a_a9c1_18980261cf0f);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for IPrincipal {
+    type Target = super::Com::IDispatch;
     fn deref(&self) -> &Self::Target {
         unsafe { core::mem::transmute(self) }
     }
@@ -2048,6 +2969,101 @@ impl IPrincipal_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrincipal_Impl> windows_core::imp::TearOffVtable<Identity> for IPrincipal_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + IPrincipal_Impl>(this: *mut core::ffi::c_void, pid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrincipal_Impl::Id(this, core::mem::transmute_copy(&pid)).into()
+            }
+        }
+        unsafe extern "system" fn SetId<Identity: windows_core::IUnknownImpl + IPrincipal_Impl>(this: *mut core::ffi::c_void, id: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrincipal_Impl::SetId(this, core::mem::transmute(&id)).into()
+            }
+        }
+        unsafe extern "system" fn DisplayName<Identity: windows_core::IUnknownImpl + IPrincipal_Impl>(this: *mut core::ffi::c_void, pname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrincipal_Impl::DisplayName(this, core::mem::transmute_copy(&pname)).into()
+            }
+        }
+        unsafe extern "system" fn SetDisplayName<Identity: windows_core::IUnknownImpl + IPrincipal_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrincipal_Impl::SetDisplayName(this, core::mem::transmute(&name)).into()
+            }
+        }
+        unsafe extern "system" fn UserId<Identity: windows_core::IUnknownImpl + IPrincipal_Impl>(this: *mut core::ffi::c_void, puser: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrincipal_Impl::UserId(this, core::mem::transmute_copy(&puser)).into()
+            }
+        }
+        unsafe extern "system" fn SetUserId<Identity: windows_core::IUnknownImpl + IPrincipal_Impl>(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrincipal_Impl::SetUserId(this, core::mem::transmute(&user)).into()
+            }
+        }
+        unsafe extern "system" fn LogonType<Identity: windows_core::IUnknownImpl + IPrincipal_Impl>(this: *mut core::ffi::c_void, plogon: *mut TASK_LOGON_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrincipal_Impl::LogonType(this, core::mem::transmute_copy(&plogon)).into()
+            }
+        }
+        unsafe extern "system" fn SetLogonType<Identity: windows_core::IUnknownImpl + IPrincipal_Impl>(this: *mut core::ffi::c_void, logon: TASK_LOGON_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrincipal_Impl::SetLogonType(this, core::mem::transmute_copy(&logon)).into()
+            }
+        }
+        unsafe extern "system" fn GroupId<Identity: windows_core::IUnknownImpl + IPrincipal_Impl>(this: *mut core::ffi::c_void, pgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrincipal_Impl::GroupId(this, core::mem::transmute_copy(&pgroup)).into()
+            }
+        }
+        unsafe extern "system" fn SetGroupId<Identity: windows_core::IUnknownImpl + IPrincipal_Impl>(this: *mut core::ffi::c_void, group: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrincipal_Impl::SetGroupId(this, core::mem::transmute(&group)).into()
+            }
+        }
+        unsafe extern "system" fn RunLevel<Identity: windows_core::IUnknownImpl + IPrincipal_Impl>(this: *mut core::ffi::c_void, prunlevel: *mut TASK_RUNLEVEL_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrincipal_Impl::RunLevel(this, core::mem::transmute_copy(&prunlevel)).into()
+            }
+        }
+        unsafe extern "system" fn SetRunLevel<Identity: windows_core::IUnknownImpl + IPrincipal_Impl>(this: *mut core::ffi::c_void, runlevel: TASK_RUNLEVEL_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrincipal_Impl::SetRunLevel(this, core::mem::transmute_copy(&runlevel)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Id: Id::<Identity>,
+            SetId: SetId::<Identity>,
+            DisplayName: DisplayName::<Identity>,
+            SetDisplayName: SetDisplayName::<Identity>,
+            UserId: UserId::<Identity>,
+            SetUserId: SetUserId::<Identity>,
+            LogonType: LogonType::<Identity>,
+            SetLogonType: SetLogonType::<Identity>,
+            GroupId: GroupId::<Identity>,
+            SetGroupId: SetGroupId::<Identity>,
+            RunLevel: RunLevel::<Identity>,
+            SetRunLevel: SetRunLevel::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrincipal {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IPrincipal2, IPrincipal2_Vtbl, 0x248919ae_e345_4a6d_8aeb_e0d3165c904e);
@@ -2144,6 +3160,52 @@ impl IPrincipal2_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IPrincipal2_Impl> windows_core::imp::TearOffVtable<Identity> for IPrincipal2_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn ProcessTokenSidType<Identity: windows_core::IUnknownImpl + IPrincipal2_Impl>(this: *mut core::ffi::c_void, pprocesstokensidtype: *mut TASK_PROCESSTOKENSID_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrincipal2_Impl::ProcessTokenSidType(this, core::mem::transmute_copy(&pprocesstokensidtype)).into()
+            }
+        }
+        unsafe extern "system" fn SetProcessTokenSidType<Identity: windows_core::IUnknownImpl + IPrincipal2_Impl>(this: *mut core::ffi::c_void, processtokensidtype: TASK_PROCESSTOKENSID_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrincipal2_Impl::SetProcessTokenSidType(this, core::mem::transmute_copy(&processtokensidtype)).into()
+            }
+        }
+        unsafe extern "system" fn RequiredPrivilegeCount<Identity: windows_core::IUnknownImpl + IPrincipal2_Impl>(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrincipal2_Impl::RequiredPrivilegeCount(this, core::mem::transmute_copy(&pcount)).into()
+            }
+        }
+        unsafe extern "system" fn get_RequiredPrivilege<Identity: windows_core::IUnknownImpl + IPrincipal2_Impl>(this: *mut core::ffi::c_void, index: i32, pprivilege: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrincipal2_Impl::get_RequiredPrivilege(this, core::mem::transmute_copy(&index), core::mem::transmute_copy(&pprivilege)).into()
+            }
+        }
+        unsafe extern "system" fn AddRequiredPrivilege<Identity: windows_core::IUnknownImpl + IPrincipal2_Impl>(this: *mut core::ffi::c_void, privilege: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IPrincipal2_Impl::AddRequiredPrivilege(this, core::mem::transmute(&privilege)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            ProcessTokenSidType: ProcessTokenSidType::<Identity>,
+            SetProcessTokenSidType: SetProcessTokenSidType::<Identity>,
+            RequiredPrivilegeCount: RequiredPrivilegeCount::<Identity>,
+            get_RequiredPrivilege: get_RequiredPrivilege::<Identity>,
+            AddRequiredPrivilege: AddRequiredPrivilege::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IPrincipal2 {}
 windows_core::imp::define_interface!(IProvideTaskPage, IProvideTaskPage_Vtbl, 0x4086658a_cbbb_11cf_b604_00c04fd8d565);
 windows_core::imp::interface_hierarchy!(IProvideTaskPage, windows_core::IUnknown);
@@ -2191,6 +3253,27 @@ impl IProvideTaskPage_Vtbl {
     }
 }
 #[cfg(feature = "Win32_UI_Controls")]
+impl<Identity: windows_core::IUnknownImpl + IProvideTaskPage_Impl> windows_core::imp::TearOffVtable<Identity> for IProvideTaskPage_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetPage<Identity: windows_core::IUnknownImpl + IProvideTaskPage_Impl>(this: *mut core::ffi::c_void, tptype: TASKPAGE, fpersistchanges: windows_core::BOOL, phpage: *mut super::super::UI::Controls::HPROPSHEETPAGE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IProvideTaskPage_Impl::GetPage(this, core::mem::transmute_copy(&tptype), core::mem::transmute_copy(&fpersistchanges)) {
+                    Ok(ok__) => {
+                        phpage.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, GetPage: GetPage::<Identity> }
+    };
+}
+#[cfg(feature = "Win32_UI_Controls")]
 impl windows_core::RuntimeName for IProvideTaskPage {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRegisteredTask, IRegisteredTask_Vtbl, 0x9c86f320_dee3_4dd1_b972_a303f26b061e);
@@ -2577,136 +3660,407 @@ impl IRegisteredTask_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IRegisteredTask {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IRegisteredTaskCollection, IRegisteredTaskCollection_Vtbl, 0x86627eb4_42a7_41e4_a4d9_ac33a72f2d52);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IRegisteredTaskCollection {
-    type Target = super::Com::IDispatch;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IRegisteredTaskCollection, windows_core::IUnknown, super::Com::IDispatch);
-#[cfg(feature = "Win32_System_Com")]
-impl IRegisteredTaskCollection {
-    pub unsafe fn Count(&self) -> windows_core::Result<i32> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).Count)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+impl<Identity: windows_core::IUnknownImpl + IRegisteredTask_Impl> windows_core::imp::TearOffVtable<Identity> for IRegisteredTask_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + IRegisteredTask_Impl>(this: *mut core::ffi::c_void, pname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRegisteredTask_Impl::Name(this) {
+                    Ok(ok__) => {
+                        pname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub unsafe fn get_Item(&self, index: &super::Variant::VARIANT) -> windows_core::Result<IRegisteredTask> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self).get_Item)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(index), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn Path<Identity: windows_core::IUnknownImpl + IRegisteredTask_Impl>(this: *mut core::ffi::c_void, ppath: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRegisteredTask_Impl::Path(this) {
+                    Ok(ok__) => {
+                        ppath.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub unsafe fn _NewEnum(&self) -> windows_core::Result<windows_core::IUnknown> {
-        unsafe {
-            let mut result__ = core::mem::zeroed();
-            (windows_core::Interface::vtable(self)._NewEnum)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        unsafe extern "system" fn State<Identity: windows_core::IUnknownImpl + IRegisteredTask_Impl>(this: *mut core::ffi::c_void, pstate: *mut TASK_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRegisteredTask_Impl::State(this) {
+                    Ok(ok__) => {
+                        pstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-#[repr(C)]
-#[doc(hidden)]
-pub struct IRegisteredTaskCollection_Vtbl {
-    pub base__: super::Com::IDispatch_Vtbl,
-    pub Count: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
-    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-    pub get_Item: unsafe extern "system" fn(*mut core::ffi::c_void, super::Variant::VARIANT, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
-    get_Item: usize,
-    pub _NewEnum: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-pub trait IRegisteredTaskCollection_Impl: super::Com::IDispatch_Impl {
-    fn Count(&self) -> windows_core::Result<i32>;
-    fn get_Item(&self, index: &super::Variant::VARIANT) -> windows_core::Result<IRegisteredTask>;
-    fn _NewEnum(&self) -> windows_core::Result<windows_core::IUnknown>;
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl IRegisteredTaskCollection_Vtbl {
-    pub const fn new<Identity: IRegisteredTaskCollection_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn Count<Identity: IRegisteredTaskCollection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+        unsafe extern "system" fn Enabled<Identity: windows_core::IUnknownImpl + IRegisteredTask_Impl>(this: *mut core::ffi::c_void, penabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IRegisteredTaskCollection_Impl::Count(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRegisteredTask_Impl::Enabled(this) {
                     Ok(ok__) => {
-                        pcount.write(core::mem::transmute(ok__));
+                        penabled.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn get_Item<Identity: IRegisteredTaskCollection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, index: super::Variant::VARIANT, ppregisteredtask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetEnabled<Identity: windows_core::IUnknownImpl + IRegisteredTask_Impl>(this: *mut core::ffi::c_void, enabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IRegisteredTaskCollection_Impl::get_Item(this, core::mem::transmute(&index)) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegisteredTask_Impl::SetEnabled(this, core::mem::transmute_copy(&enabled)).into()
+            }
+        }
+        unsafe extern "system" fn Run<Identity: windows_core::IUnknownImpl + IRegisteredTask_Impl>(this: *mut core::ffi::c_void, params: super::Variant::VARIANT, pprunningtask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRegisteredTask_Impl::Run(this, core::mem::transmute(&params)) {
                     Ok(ok__) => {
-                        ppregisteredtask.write(core::mem::transmute(ok__));
+                        pprunningtask.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        unsafe extern "system" fn _NewEnum<Identity: IRegisteredTaskCollection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn RunEx<Identity: windows_core::IUnknownImpl + IRegisteredTask_Impl>(this: *mut core::ffi::c_void, params: super::Variant::VARIANT, flags: i32, sessionid: i32, user: *mut core::ffi::c_void, pprunningtask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
-                match IRegisteredTaskCollection_Impl::_NewEnum(this) {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRegisteredTask_Impl::RunEx(this, core::mem::transmute(&params), core::mem::transmute_copy(&flags), core::mem::transmute_copy(&sessionid), core::mem::transmute(&user)) {
                     Ok(ok__) => {
-                        ppenum.write(core::mem::transmute(ok__));
+                        pprunningtask.write(core::mem::transmute(ok__));
                         windows_core::HRESULT(0)
                     }
                     Err(err) => err.into(),
                 }
             }
         }
-        Self {
-            base__: super::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
-            Count: Count::<Identity, OFFSET>,
-            get_Item: get_Item::<Identity, OFFSET>,
-            _NewEnum: _NewEnum::<Identity, OFFSET>,
+        unsafe extern "system" fn GetInstances<Identity: windows_core::IUnknownImpl + IRegisteredTask_Impl>(this: *mut core::ffi::c_void, flags: i32, pprunningtasks: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRegisteredTask_Impl::GetInstances(this, core::mem::transmute_copy(&flags)) {
+                    Ok(ok__) => {
+                        pprunningtasks.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IRegisteredTaskCollection as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID
-    }
-}
-#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl windows_core::RuntimeName for IRegisteredTaskCollection {}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::define_interface!(IRegistrationInfo, IRegistrationInfo_Vtbl, 0x416d8b73_cb41_4ea1_805c_9be9a5ac4a74);
-#[cfg(feature = "Win32_System_Com")]
-impl core::ops::Deref for IRegistrationInfo {
-    type Target = super::Com::IDispatch;
-    fn deref(&self) -> &Self::Target {
-        unsafe { core::mem::transmute(self) }
-    }
-}
-#[cfg(feature = "Win32_System_Com")]
-windows_core::imp::interface_hierarchy!(IRegistrationInfo, windows_core::IUnknown, super::Com::IDispatch);
-#[cfg(feature = "Win32_System_Com")]
-impl IRegistrationInfo {
-    pub unsafe fn Description(&self, pdescription: *mut windows_core::BSTR) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Description)(windows_core::Interface::as_raw(self), core::mem::transmute(pdescription)).ok() }
-    }
-    pub unsafe fn SetDescription(&self, description: &windows_core::BSTR) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetDescription)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(description)).ok() }
-    }
-    pub unsafe fn Author(&self, pauthor: *mut windows_core::BSTR) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).Author)(windows_core::Interface::as_raw(self), core::mem::transmute(pauthor)).ok() }
-    }
-    pub unsafe fn SetAuthor(&self, author: &windows_core::BSTR) -> windows_core::Result<()> {
-        unsafe { (windows_core::Interface::vtable(self).SetAuthor)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(author)).ok() }
-    }
-    pub unsafe fn Version(&self, pversion: *mut windows_core::BSTR) -> windows_core::Result<()> {
+        unsafe extern "system" fn LastRunTime<Identity: windows_core::IUnknownImpl + IRegisteredTask_Impl>(this: *mut core::ffi::c_void, plastruntime: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRegisteredTask_Impl::LastRunTime(this) {
+                    Ok(ok__) => {
+                        plastruntime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn LastTaskResult<Identity: windows_core::IUnknownImpl + IRegisteredTask_Impl>(this: *mut core::ffi::c_void, plasttaskresult: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRegisteredTask_Impl::LastTaskResult(this) {
+                    Ok(ok__) => {
+                        plasttaskresult.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NumberOfMissedRuns<Identity: windows_core::IUnknownImpl + IRegisteredTask_Impl>(this: *mut core::ffi::c_void, pnumberofmissedruns: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRegisteredTask_Impl::NumberOfMissedRuns(this) {
+                    Ok(ok__) => {
+                        pnumberofmissedruns.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NextRunTime<Identity: windows_core::IUnknownImpl + IRegisteredTask_Impl>(this: *mut core::ffi::c_void, pnextruntime: *mut f64) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRegisteredTask_Impl::NextRunTime(this) {
+                    Ok(ok__) => {
+                        pnextruntime.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Definition<Identity: windows_core::IUnknownImpl + IRegisteredTask_Impl>(this: *mut core::ffi::c_void, ppdefinition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRegisteredTask_Impl::Definition(this) {
+                    Ok(ok__) => {
+                        ppdefinition.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Xml<Identity: windows_core::IUnknownImpl + IRegisteredTask_Impl>(this: *mut core::ffi::c_void, pxml: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRegisteredTask_Impl::Xml(this) {
+                    Ok(ok__) => {
+                        pxml.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSecurityDescriptor<Identity: windows_core::IUnknownImpl + IRegisteredTask_Impl>(this: *mut core::ffi::c_void, securityinformation: i32, psddl: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRegisteredTask_Impl::GetSecurityDescriptor(this, core::mem::transmute_copy(&securityinformation)) {
+                    Ok(ok__) => {
+                        psddl.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSecurityDescriptor<Identity: windows_core::IUnknownImpl + IRegisteredTask_Impl>(this: *mut core::ffi::c_void, sddl: *mut core::ffi::c_void, flags: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegisteredTask_Impl::SetSecurityDescriptor(this, core::mem::transmute(&sddl), core::mem::transmute_copy(&flags)).into()
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + IRegisteredTask_Impl>(this: *mut core::ffi::c_void, flags: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegisteredTask_Impl::Stop(this, core::mem::transmute_copy(&flags)).into()
+            }
+        }
+        unsafe extern "system" fn GetRunTimes<Identity: windows_core::IUnknownImpl + IRegisteredTask_Impl>(this: *mut core::ffi::c_void, pststart: *const super::super::Foundation::SYSTEMTIME, pstend: *const super::super::Foundation::SYSTEMTIME, pcount: *mut u32, pruntimes: *mut *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegisteredTask_Impl::GetRunTimes(this, core::mem::transmute_copy(&pststart), core::mem::transmute_copy(&pstend), core::mem::transmute_copy(&pcount), core::mem::transmute_copy(&pruntimes)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Name: Name::<Identity>,
+            Path: Path::<Identity>,
+            State: State::<Identity>,
+            Enabled: Enabled::<Identity>,
+            SetEnabled: SetEnabled::<Identity>,
+            Run: Run::<Identity>,
+            RunEx: RunEx::<Identity>,
+            GetInstances: GetInstances::<Identity>,
+            LastRunTime: LastRunTime::<Identity>,
+            LastTaskResult: LastTaskResult::<Identity>,
+            NumberOfMissedRuns: NumberOfMissedRuns::<Identity>,
+            NextRunTime: NextRunTime::<Identity>,
+            Definition: Definition::<Identity>,
+            Xml: Xml::<Identity>,
+            GetSecurityDescriptor: GetSecurityDescriptor::<Identity>,
+            SetSecurityDescriptor: SetSecurityDescriptor::<Identity>,
+            Stop: Stop::<Identity>,
+            GetRunTimes: GetRunTimes::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IRegisteredTask {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(IRegisteredTaskCollection, IRegisteredTaskCollection_Vtbl, 0x86627eb4_42a7_41e4_a4d9_ac33a72f2d52);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for IRegisteredTaskCollection {
+    type Target = super::Com::IDispatch;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(IRegisteredTaskCollection, windows_core::IUnknown, super::Com::IDispatch);
+#[cfg(feature = "Win32_System_Com")]
+impl IRegisteredTaskCollection {
+    pub unsafe fn Count(&self) -> windows_core::Result<i32> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).Count)(windows_core::Interface::as_raw(self), &mut result__).map(|| result__)
+        }
+    }
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub unsafe fn get_Item(&self, index: &super::Variant::VARIANT) -> windows_core::Result<IRegisteredTask> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self).get_Item)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(index), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+    pub unsafe fn _NewEnum(&self) -> windows_core::Result<windows_core::IUnknown> {
+        unsafe {
+            let mut result__ = core::mem::zeroed();
+            (windows_core::Interface::vtable(self)._NewEnum)(windows_core::Interface::as_raw(self), &mut result__).and_then(|| windows_core::Type::from_abi(result__))
+        }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+#[repr(C)]
+#[doc(hidden)]
+pub struct IRegisteredTaskCollection_Vtbl {
+    pub base__: super::Com::IDispatch_Vtbl,
+    pub Count: unsafe extern "system" fn(*mut core::ffi::c_void, *mut i32) -> windows_core::HRESULT,
+    #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+    pub get_Item: unsafe extern "system" fn(*mut core::ffi::c_void, super::Variant::VARIANT, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+    #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
+    get_Item: usize,
+    pub _NewEnum: unsafe extern "system" fn(*mut core::ffi::c_void, *mut *mut core::ffi::c_void) -> windows_core::HRESULT,
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+pub trait IRegisteredTaskCollection_Impl: super::Com::IDispatch_Impl {
+    fn Count(&self) -> windows_core::Result<i32>;
+    fn get_Item(&self, index: &super::Variant::VARIANT) -> windows_core::Result<IRegisteredTask>;
+    fn _NewEnum(&self) -> windows_core::Result<windows_core::IUnknown>;
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl IRegisteredTaskCollection_Vtbl {
+    pub const fn new<Identity: IRegisteredTaskCollection_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn Count<Identity: IRegisteredTaskCollection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRegisteredTaskCollection_Impl::Count(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_Item<Identity: IRegisteredTaskCollection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, index: super::Variant::VARIANT, ppregisteredtask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRegisteredTaskCollection_Impl::get_Item(this, core::mem::transmute(&index)) {
+                    Ok(ok__) => {
+                        ppregisteredtask.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: IRegisteredTaskCollection_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRegisteredTaskCollection_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: super::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
+            Count: Count::<Identity, OFFSET>,
+            get_Item: get_Item::<Identity, OFFSET>,
+            _NewEnum: _NewEnum::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRegisteredTaskCollection as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRegisteredTaskCollection_Impl> windows_core::imp::TearOffVtable<Identity> for IRegisteredTaskCollection_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + IRegisteredTaskCollection_Impl>(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRegisteredTaskCollection_Impl::Count(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_Item<Identity: windows_core::IUnknownImpl + IRegisteredTaskCollection_Impl>(this: *mut core::ffi::c_void, index: super::Variant::VARIANT, ppregisteredtask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRegisteredTaskCollection_Impl::get_Item(this, core::mem::transmute(&index)) {
+                    Ok(ok__) => {
+                        ppregisteredtask.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + IRegisteredTaskCollection_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRegisteredTaskCollection_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Count: Count::<Identity>,
+            get_Item: get_Item::<Identity>,
+            _NewEnum: _NewEnum::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::RuntimeName for IRegisteredTaskCollection {}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::define_interface!(IRegistrationInfo, IRegistrationInfo_Vtbl, 0x416d8b73_cb41_4ea1_805c_9be9a5ac4a74);
+#[cfg(feature = "Win32_System_Com")]
+impl core::ops::Deref for IRegistrationInfo {
+    type Target = super::Com::IDispatch;
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+windows_core::imp::interface_hierarchy!(IRegistrationInfo, windows_core::IUnknown, super::Com::IDispatch);
+#[cfg(feature = "Win32_System_Com")]
+impl IRegistrationInfo {
+    pub unsafe fn Description(&self, pdescription: *mut windows_core::BSTR) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Description)(windows_core::Interface::as_raw(self), core::mem::transmute(pdescription)).ok() }
+    }
+    pub unsafe fn SetDescription(&self, description: &windows_core::BSTR) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetDescription)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(description)).ok() }
+    }
+    pub unsafe fn Author(&self, pauthor: *mut windows_core::BSTR) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).Author)(windows_core::Interface::as_raw(self), core::mem::transmute(pauthor)).ok() }
+    }
+    pub unsafe fn SetAuthor(&self, author: &windows_core::BSTR) -> windows_core::Result<()> {
+        unsafe { (windows_core::Interface::vtable(self).SetAuthor)(windows_core::Interface::as_raw(self), core::mem::transmute_copy(author)).ok() }
+    }
+    pub unsafe fn Version(&self, pversion: *mut windows_core::BSTR) -> windows_core::Result<()> {
         unsafe { (windows_core::Interface::vtable(self).Version)(windows_core::Interface::as_raw(self), core::mem::transmute(pversion)).ok() }
     }
     pub unsafe fn SetVersion(&self, version: &windows_core::BSTR) -> windows_core::Result<()> {
@@ -2940,6 +4294,143 @@ impl IRegistrationInfo_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRegistrationInfo_Impl> windows_core::imp::TearOffVtable<Identity> for IRegistrationInfo_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Description<Identity: windows_core::IUnknownImpl + IRegistrationInfo_Impl>(this: *mut core::ffi::c_void, pdescription: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegistrationInfo_Impl::Description(this, core::mem::transmute_copy(&pdescription)).into()
+            }
+        }
+        unsafe extern "system" fn SetDescription<Identity: windows_core::IUnknownImpl + IRegistrationInfo_Impl>(this: *mut core::ffi::c_void, description: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegistrationInfo_Impl::SetDescription(this, core::mem::transmute(&description)).into()
+            }
+        }
+        unsafe extern "system" fn Author<Identity: windows_core::IUnknownImpl + IRegistrationInfo_Impl>(this: *mut core::ffi::c_void, pauthor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegistrationInfo_Impl::Author(this, core::mem::transmute_copy(&pauthor)).into()
+            }
+        }
+        unsafe extern "system" fn SetAuthor<Identity: windows_core::IUnknownImpl + IRegistrationInfo_Impl>(this: *mut core::ffi::c_void, author: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegistrationInfo_Impl::SetAuthor(this, core::mem::transmute(&author)).into()
+            }
+        }
+        unsafe extern "system" fn Version<Identity: windows_core::IUnknownImpl + IRegistrationInfo_Impl>(this: *mut core::ffi::c_void, pversion: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegistrationInfo_Impl::Version(this, core::mem::transmute_copy(&pversion)).into()
+            }
+        }
+        unsafe extern "system" fn SetVersion<Identity: windows_core::IUnknownImpl + IRegistrationInfo_Impl>(this: *mut core::ffi::c_void, version: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegistrationInfo_Impl::SetVersion(this, core::mem::transmute(&version)).into()
+            }
+        }
+        unsafe extern "system" fn Date<Identity: windows_core::IUnknownImpl + IRegistrationInfo_Impl>(this: *mut core::ffi::c_void, pdate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegistrationInfo_Impl::Date(this, core::mem::transmute_copy(&pdate)).into()
+            }
+        }
+        unsafe extern "system" fn SetDate<Identity: windows_core::IUnknownImpl + IRegistrationInfo_Impl>(this: *mut core::ffi::c_void, date: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegistrationInfo_Impl::SetDate(this, core::mem::transmute(&date)).into()
+            }
+        }
+        unsafe extern "system" fn Documentation<Identity: windows_core::IUnknownImpl + IRegistrationInfo_Impl>(this: *mut core::ffi::c_void, pdocumentation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegistrationInfo_Impl::Documentation(this, core::mem::transmute_copy(&pdocumentation)).into()
+            }
+        }
+        unsafe extern "system" fn SetDocumentation<Identity: windows_core::IUnknownImpl + IRegistrationInfo_Impl>(this: *mut core::ffi::c_void, documentation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegistrationInfo_Impl::SetDocumentation(this, core::mem::transmute(&documentation)).into()
+            }
+        }
+        unsafe extern "system" fn XmlText<Identity: windows_core::IUnknownImpl + IRegistrationInfo_Impl>(this: *mut core::ffi::c_void, ptext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegistrationInfo_Impl::XmlText(this, core::mem::transmute_copy(&ptext)).into()
+            }
+        }
+        unsafe extern "system" fn SetXmlText<Identity: windows_core::IUnknownImpl + IRegistrationInfo_Impl>(this: *mut core::ffi::c_void, text: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegistrationInfo_Impl::SetXmlText(this, core::mem::transmute(&text)).into()
+            }
+        }
+        unsafe extern "system" fn URI<Identity: windows_core::IUnknownImpl + IRegistrationInfo_Impl>(this: *mut core::ffi::c_void, puri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegistrationInfo_Impl::URI(this, core::mem::transmute_copy(&puri)).into()
+            }
+        }
+        unsafe extern "system" fn SetURI<Identity: windows_core::IUnknownImpl + IRegistrationInfo_Impl>(this: *mut core::ffi::c_void, uri: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegistrationInfo_Impl::SetURI(this, core::mem::transmute(&uri)).into()
+            }
+        }
+        unsafe extern "system" fn SecurityDescriptor<Identity: windows_core::IUnknownImpl + IRegistrationInfo_Impl>(this: *mut core::ffi::c_void, psddl: *mut super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegistrationInfo_Impl::SecurityDescriptor(this, core::mem::transmute_copy(&psddl)).into()
+            }
+        }
+        unsafe extern "system" fn SetSecurityDescriptor<Identity: windows_core::IUnknownImpl + IRegistrationInfo_Impl>(this: *mut core::ffi::c_void, sddl: super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegistrationInfo_Impl::SetSecurityDescriptor(this, core::mem::transmute(&sddl)).into()
+            }
+        }
+        unsafe extern "system" fn Source<Identity: windows_core::IUnknownImpl + IRegistrationInfo_Impl>(this: *mut core::ffi::c_void, psource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegistrationInfo_Impl::Source(this, core::mem::transmute_copy(&psource)).into()
+            }
+        }
+        unsafe extern "system" fn SetSource<Identity: windows_core::IUnknownImpl + IRegistrationInfo_Impl>(this: *mut core::ffi::c_void, source: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegistrationInfo_Impl::SetSource(this, core::mem::transmute(&source)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Description: Description::<Identity>,
+            SetDescription: SetDescription::<Identity>,
+            Author: Author::<Identity>,
+            SetAuthor: SetAuthor::<Identity>,
+            Version: Version::<Identity>,
+            SetVersion: SetVersion::<Identity>,
+            Date: Date::<Identity>,
+            SetDate: SetDate::<Identity>,
+            Documentation: Documentation::<Identity>,
+            SetDocumentation: SetDocumentation::<Identity>,
+            XmlText: XmlText::<Identity>,
+            SetXmlText: SetXmlText::<Identity>,
+            URI: URI::<Identity>,
+            SetURI: SetURI::<Identity>,
+            SecurityDescriptor: SecurityDescriptor::<Identity>,
+            SetSecurityDescriptor: SetSecurityDescriptor::<Identity>,
+            Source: Source::<Identity>,
+            SetSource: SetSource::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRegistrationInfo {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRegistrationTrigger, IRegistrationTrigger_Vtbl, 0x4c8fec3a_c218_4e0c_b23d_629024db91a2);
@@ -2996,6 +4487,27 @@ impl IRegistrationTrigger_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRegistrationTrigger_Impl> windows_core::imp::TearOffVtable<Identity> for IRegistrationTrigger_Vtbl
+where
+    ITrigger_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Delay<Identity: windows_core::IUnknownImpl + IRegistrationTrigger_Impl>(this: *mut core::ffi::c_void, pdelay: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegistrationTrigger_Impl::Delay(this, core::mem::transmute_copy(&pdelay)).into()
+            }
+        }
+        unsafe extern "system" fn SetDelay<Identity: windows_core::IUnknownImpl + IRegistrationTrigger_Impl>(this: *mut core::ffi::c_void, delay: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRegistrationTrigger_Impl::SetDelay(this, core::mem::transmute(&delay)).into()
+            }
+        }
+        Self { base__: <ITrigger_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE, Delay: Delay::<Identity>, SetDelay: SetDelay::<Identity> }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRegistrationTrigger {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRepetitionPattern, IRepetitionPattern_Vtbl, 0x7fb9acf1_26be_400e_85b5_294b9c75dfd6);
@@ -3104,6 +4616,59 @@ impl IRepetitionPattern_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRepetitionPattern_Impl> windows_core::imp::TearOffVtable<Identity> for IRepetitionPattern_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Interval<Identity: windows_core::IUnknownImpl + IRepetitionPattern_Impl>(this: *mut core::ffi::c_void, pinterval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRepetitionPattern_Impl::Interval(this, core::mem::transmute_copy(&pinterval)).into()
+            }
+        }
+        unsafe extern "system" fn SetInterval<Identity: windows_core::IUnknownImpl + IRepetitionPattern_Impl>(this: *mut core::ffi::c_void, interval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRepetitionPattern_Impl::SetInterval(this, core::mem::transmute(&interval)).into()
+            }
+        }
+        unsafe extern "system" fn Duration<Identity: windows_core::IUnknownImpl + IRepetitionPattern_Impl>(this: *mut core::ffi::c_void, pduration: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRepetitionPattern_Impl::Duration(this, core::mem::transmute_copy(&pduration)).into()
+            }
+        }
+        unsafe extern "system" fn SetDuration<Identity: windows_core::IUnknownImpl + IRepetitionPattern_Impl>(this: *mut core::ffi::c_void, duration: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRepetitionPattern_Impl::SetDuration(this, core::mem::transmute(&duration)).into()
+            }
+        }
+        unsafe extern "system" fn StopAtDurationEnd<Identity: windows_core::IUnknownImpl + IRepetitionPattern_Impl>(this: *mut core::ffi::c_void, pstop: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRepetitionPattern_Impl::StopAtDurationEnd(this, core::mem::transmute_copy(&pstop)).into()
+            }
+        }
+        unsafe extern "system" fn SetStopAtDurationEnd<Identity: windows_core::IUnknownImpl + IRepetitionPattern_Impl>(this: *mut core::ffi::c_void, stop: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRepetitionPattern_Impl::SetStopAtDurationEnd(this, core::mem::transmute_copy(&stop)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Interval: Interval::<Identity>,
+            SetInterval: SetInterval::<Identity>,
+            Duration: Duration::<Identity>,
+            SetDuration: SetDuration::<Identity>,
+            StopAtDurationEnd: StopAtDurationEnd::<Identity>,
+            SetStopAtDurationEnd: SetStopAtDurationEnd::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRepetitionPattern {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IRunningTask, IRunningTask_Vtbl, 0x653758fb_7b9a_4f1e_a471_beeb8e9b834e);
@@ -3187,11 +4752,117 @@ pub trait IRunningTask_Impl: super::Com::IDispatch_Impl {
     fn EnginePID(&self) -> windows_core::Result<u32>;
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl IRunningTask_Vtbl {
-    pub const fn new<Identity: IRunningTask_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn Name<Identity: IRunningTask_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+impl IRunningTask_Vtbl {
+    pub const fn new<Identity: IRunningTask_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn Name<Identity: IRunningTask_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRunningTask_Impl::Name(this) {
+                    Ok(ok__) => {
+                        pname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn InstanceGuid<Identity: IRunningTask_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pguid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRunningTask_Impl::InstanceGuid(this) {
+                    Ok(ok__) => {
+                        pguid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Path<Identity: IRunningTask_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppath: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRunningTask_Impl::Path(this) {
+                    Ok(ok__) => {
+                        ppath.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn State<Identity: IRunningTask_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pstate: *mut TASK_STATE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRunningTask_Impl::State(this) {
+                    Ok(ok__) => {
+                        pstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CurrentAction<Identity: IRunningTask_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRunningTask_Impl::CurrentAction(this) {
+                    Ok(ok__) => {
+                        pname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: IRunningTask_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IRunningTask_Impl::Stop(this).into()
+            }
+        }
+        unsafe extern "system" fn Refresh<Identity: IRunningTask_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                IRunningTask_Impl::Refresh(this).into()
+            }
+        }
+        unsafe extern "system" fn EnginePID<Identity: IRunningTask_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppid: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match IRunningTask_Impl::EnginePID(this) {
+                    Ok(ok__) => {
+                        ppid.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: super::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
+            Name: Name::<Identity, OFFSET>,
+            InstanceGuid: InstanceGuid::<Identity, OFFSET>,
+            Path: Path::<Identity, OFFSET>,
+            State: State::<Identity, OFFSET>,
+            CurrentAction: CurrentAction::<Identity, OFFSET>,
+            Stop: Stop::<Identity, OFFSET>,
+            Refresh: Refresh::<Identity, OFFSET>,
+            EnginePID: EnginePID::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRunningTask as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRunningTask_Impl> windows_core::imp::TearOffVtable<Identity> for IRunningTask_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + IRunningTask_Impl>(this: *mut core::ffi::c_void, pname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRunningTask_Impl::Name(this) {
                     Ok(ok__) => {
                         pname.write(core::mem::transmute(ok__));
@@ -3201,9 +4872,9 @@ impl IRunningTask_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn InstanceGuid<Identity: IRunningTask_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pguid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn InstanceGuid<Identity: windows_core::IUnknownImpl + IRunningTask_Impl>(this: *mut core::ffi::c_void, pguid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRunningTask_Impl::InstanceGuid(this) {
                     Ok(ok__) => {
                         pguid.write(core::mem::transmute(ok__));
@@ -3213,9 +4884,9 @@ impl IRunningTask_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Path<Identity: IRunningTask_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppath: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Path<Identity: windows_core::IUnknownImpl + IRunningTask_Impl>(this: *mut core::ffi::c_void, ppath: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRunningTask_Impl::Path(this) {
                     Ok(ok__) => {
                         ppath.write(core::mem::transmute(ok__));
@@ -3225,9 +4896,9 @@ impl IRunningTask_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn State<Identity: IRunningTask_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pstate: *mut TASK_STATE) -> windows_core::HRESULT {
+        unsafe extern "system" fn State<Identity: windows_core::IUnknownImpl + IRunningTask_Impl>(this: *mut core::ffi::c_void, pstate: *mut TASK_STATE) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRunningTask_Impl::State(this) {
                     Ok(ok__) => {
                         pstate.write(core::mem::transmute(ok__));
@@ -3237,9 +4908,9 @@ impl IRunningTask_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn CurrentAction<Identity: IRunningTask_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn CurrentAction<Identity: windows_core::IUnknownImpl + IRunningTask_Impl>(this: *mut core::ffi::c_void, pname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRunningTask_Impl::CurrentAction(this) {
                     Ok(ok__) => {
                         pname.write(core::mem::transmute(ok__));
@@ -3249,21 +4920,21 @@ impl IRunningTask_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Stop<Identity: IRunningTask_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + IRunningTask_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IRunningTask_Impl::Stop(this).into()
             }
         }
-        unsafe extern "system" fn Refresh<Identity: IRunningTask_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Refresh<Identity: windows_core::IUnknownImpl + IRunningTask_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 IRunningTask_Impl::Refresh(this).into()
             }
         }
-        unsafe extern "system" fn EnginePID<Identity: IRunningTask_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppid: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn EnginePID<Identity: windows_core::IUnknownImpl + IRunningTask_Impl>(this: *mut core::ffi::c_void, ppid: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match IRunningTask_Impl::EnginePID(this) {
                     Ok(ok__) => {
                         ppid.write(core::mem::transmute(ok__));
@@ -3274,20 +4945,17 @@ impl IRunningTask_Vtbl {
             }
         }
         Self {
-            base__: super::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
-            Name: Name::<Identity, OFFSET>,
-            InstanceGuid: InstanceGuid::<Identity, OFFSET>,
-            Path: Path::<Identity, OFFSET>,
-            State: State::<Identity, OFFSET>,
-            CurrentAction: CurrentAction::<Identity, OFFSET>,
-            Stop: Stop::<Identity, OFFSET>,
-            Refresh: Refresh::<Identity, OFFSET>,
-            EnginePID: EnginePID::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<IRunningTask as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID
-    }
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Name: Name::<Identity>,
+            InstanceGuid: InstanceGuid::<Identity>,
+            Path: Path::<Identity>,
+            State: State::<Identity>,
+            CurrentAction: CurrentAction::<Identity>,
+            Stop: Stop::<Identity>,
+            Refresh: Refresh::<Identity>,
+            EnginePID: EnginePID::<Identity>,
+        }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRunningTask {}
@@ -3393,6 +5061,56 @@ impl IRunningTaskCollection_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IRunningTaskCollection_Impl> windows_core::imp::TearOffVtable<Identity> for IRunningTaskCollection_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + IRunningTaskCollection_Impl>(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRunningTaskCollection_Impl::Count(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_Item<Identity: windows_core::IUnknownImpl + IRunningTaskCollection_Impl>(this: *mut core::ffi::c_void, index: super::Variant::VARIANT, pprunningtask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRunningTaskCollection_Impl::get_Item(this, core::mem::transmute(&index)) {
+                    Ok(ok__) => {
+                        pprunningtask.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + IRunningTaskCollection_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRunningTaskCollection_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Count: Count::<Identity>,
+            get_Item: get_Item::<Identity>,
+            _NewEnum: _NewEnum::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IRunningTaskCollection {}
 windows_core::imp::define_interface!(IScheduledWorkItem, IScheduledWorkItem_Vtbl, 0xa6b952f0_a4b1_11d0_997d_00aa006887ec);
 windows_core::imp::interface_hierarchy!(IScheduledWorkItem, windows_core::IUnknown);
@@ -3881,6 +5599,291 @@ impl IScheduledWorkItem_Vtbl {
         iid == &<IScheduledWorkItem as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl> windows_core::imp::TearOffVtable<Identity> for IScheduledWorkItem_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn CreateTrigger<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, pinewtrigger: *mut u16, pptrigger: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScheduledWorkItem_Impl::CreateTrigger(this, core::mem::transmute_copy(&pinewtrigger), core::mem::transmute_copy(&pptrigger)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteTrigger<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, itrigger: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScheduledWorkItem_Impl::DeleteTrigger(this, core::mem::transmute_copy(&itrigger)).into()
+            }
+        }
+        unsafe extern "system" fn GetTriggerCount<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, pwcount: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScheduledWorkItem_Impl::GetTriggerCount(this) {
+                    Ok(ok__) => {
+                        pwcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTrigger<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, itrigger: u16, pptrigger: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScheduledWorkItem_Impl::GetTrigger(this, core::mem::transmute_copy(&itrigger)) {
+                    Ok(ok__) => {
+                        pptrigger.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTriggerString<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, itrigger: u16, ppwsztrigger: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScheduledWorkItem_Impl::GetTriggerString(this, core::mem::transmute_copy(&itrigger)) {
+                    Ok(ok__) => {
+                        ppwsztrigger.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRunTimes<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, pstbegin: *const super::super::Foundation::SYSTEMTIME, pstend: *const super::super::Foundation::SYSTEMTIME, pcount: *mut u16, rgsttasktimes: *mut *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScheduledWorkItem_Impl::GetRunTimes(this, core::mem::transmute_copy(&pstbegin), core::mem::transmute_copy(&pstend), core::mem::transmute_copy(&pcount), core::mem::transmute_copy(&rgsttasktimes)).into()
+            }
+        }
+        unsafe extern "system" fn GetNextRunTime<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, pstnextrun: *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScheduledWorkItem_Impl::GetNextRunTime(this, core::mem::transmute_copy(&pstnextrun)).into()
+            }
+        }
+        unsafe extern "system" fn SetIdleWait<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, widleminutes: u16, wdeadlineminutes: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScheduledWorkItem_Impl::SetIdleWait(this, core::mem::transmute_copy(&widleminutes), core::mem::transmute_copy(&wdeadlineminutes)).into()
+            }
+        }
+        unsafe extern "system" fn GetIdleWait<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, pwidleminutes: *mut u16, pwdeadlineminutes: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScheduledWorkItem_Impl::GetIdleWait(this, core::mem::transmute_copy(&pwidleminutes), core::mem::transmute_copy(&pwdeadlineminutes)).into()
+            }
+        }
+        unsafe extern "system" fn Run<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScheduledWorkItem_Impl::Run(this).into()
+            }
+        }
+        unsafe extern "system" fn Terminate<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScheduledWorkItem_Impl::Terminate(this).into()
+            }
+        }
+        unsafe extern "system" fn EditWorkItem<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, hparent: super::super::Foundation::HWND, dwreserved: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScheduledWorkItem_Impl::EditWorkItem(this, core::mem::transmute_copy(&hparent), core::mem::transmute_copy(&dwreserved)).into()
+            }
+        }
+        unsafe extern "system" fn GetMostRecentRunTime<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, pstlastrun: *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScheduledWorkItem_Impl::GetMostRecentRunTime(this) {
+                    Ok(ok__) => {
+                        pstlastrun.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStatus<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, phrstatus: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScheduledWorkItem_Impl::GetStatus(this) {
+                    Ok(ok__) => {
+                        phrstatus.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetExitCode<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, pdwexitcode: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScheduledWorkItem_Impl::GetExitCode(this) {
+                    Ok(ok__) => {
+                        pdwexitcode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetComment<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, pwszcomment: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScheduledWorkItem_Impl::SetComment(this, core::mem::transmute(&pwszcomment)).into()
+            }
+        }
+        unsafe extern "system" fn GetComment<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, ppwszcomment: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScheduledWorkItem_Impl::GetComment(this) {
+                    Ok(ok__) => {
+                        ppwszcomment.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetCreator<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, pwszcreator: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScheduledWorkItem_Impl::SetCreator(this, core::mem::transmute(&pwszcreator)).into()
+            }
+        }
+        unsafe extern "system" fn GetCreator<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, ppwszcreator: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScheduledWorkItem_Impl::GetCreator(this) {
+                    Ok(ok__) => {
+                        ppwszcreator.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetWorkItemData<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, cbdata: u16, rgbdata: *const u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScheduledWorkItem_Impl::SetWorkItemData(this, core::mem::transmute_copy(&cbdata), core::mem::transmute_copy(&rgbdata)).into()
+            }
+        }
+        unsafe extern "system" fn GetWorkItemData<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, pcbdata: *mut u16, prgbdata: *mut *mut u8) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScheduledWorkItem_Impl::GetWorkItemData(this, core::mem::transmute_copy(&pcbdata), core::mem::transmute_copy(&prgbdata)).into()
+            }
+        }
+        unsafe extern "system" fn SetErrorRetryCount<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, wretrycount: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScheduledWorkItem_Impl::SetErrorRetryCount(this, core::mem::transmute_copy(&wretrycount)).into()
+            }
+        }
+        unsafe extern "system" fn GetErrorRetryCount<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, pwretrycount: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScheduledWorkItem_Impl::GetErrorRetryCount(this) {
+                    Ok(ok__) => {
+                        pwretrycount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetErrorRetryInterval<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, wretryinterval: u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScheduledWorkItem_Impl::SetErrorRetryInterval(this, core::mem::transmute_copy(&wretryinterval)).into()
+            }
+        }
+        unsafe extern "system" fn GetErrorRetryInterval<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, pwretryinterval: *mut u16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScheduledWorkItem_Impl::GetErrorRetryInterval(this) {
+                    Ok(ok__) => {
+                        pwretryinterval.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetFlags<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScheduledWorkItem_Impl::SetFlags(this, core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn GetFlags<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScheduledWorkItem_Impl::GetFlags(this) {
+                    Ok(ok__) => {
+                        pdwflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetAccountInformation<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, pwszaccountname: windows_core::PCWSTR, pwszpassword: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IScheduledWorkItem_Impl::SetAccountInformation(this, core::mem::transmute(&pwszaccountname), core::mem::transmute(&pwszpassword)).into()
+            }
+        }
+        unsafe extern "system" fn GetAccountInformation<Identity: windows_core::IUnknownImpl + IScheduledWorkItem_Impl>(this: *mut core::ffi::c_void, ppwszaccountname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IScheduledWorkItem_Impl::GetAccountInformation(this) {
+                    Ok(ok__) => {
+                        ppwszaccountname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            CreateTrigger: CreateTrigger::<Identity>,
+            DeleteTrigger: DeleteTrigger::<Identity>,
+            GetTriggerCount: GetTriggerCount::<Identity>,
+            GetTrigger: GetTrigger::<Identity>,
+            GetTriggerString: GetTriggerString::<Identity>,
+            GetRunTimes: GetRunTimes::<Identity>,
+            GetNextRunTime: GetNextRunTime::<Identity>,
+            SetIdleWait: SetIdleWait::<Identity>,
+            GetIdleWait: GetIdleWait::<Identity>,
+            Run: Run::<Identity>,
+            Terminate: Terminate::<Identity>,
+            EditWorkItem: EditWorkItem::<Identity>,
+            GetMostRecentRunTime: GetMostRecentRunTime::<Identity>,
+            GetStatus: GetStatus::<Identity>,
+            GetExitCode: GetExitCode::<Identity>,
+            SetComment: SetComment::<Identity>,
+            GetComment: GetComment::<Identity>,
+            SetCreator: SetCreator::<Identity>,
+            GetCreator: GetCreator::<Identity>,
+            SetWorkItemData: SetWorkItemData::<Identity>,
+            GetWorkItemData: GetWorkItemData::<Identity>,
+            SetErrorRetryCount: SetErrorRetryCount::<Identity>,
+            GetErrorRetryCount: GetErrorRetryCount::<Identity>,
+            SetErrorRetryInterval: SetErrorRetryInterval::<Identity>,
+            GetErrorRetryInterval: GetErrorRetryInterval::<Identity>,
+            SetFlags: SetFlags::<Identity>,
+            GetFlags: GetFlags::<Identity>,
+            SetAccountInformation: SetAccountInformation::<Identity>,
+            GetAccountInformation: GetAccountInformation::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IScheduledWorkItem {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ISessionStateChangeTrigger, ISessionStateChangeTrigger_Vtbl, 0x754da71b_4385_4475_9dd9_598294fa3641);
@@ -3989,6 +5992,59 @@ impl ISessionStateChangeTrigger_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ISessionStateChangeTrigger_Impl> windows_core::imp::TearOffVtable<Identity> for ISessionStateChangeTrigger_Vtbl
+where
+    ITrigger_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Delay<Identity: windows_core::IUnknownImpl + ISessionStateChangeTrigger_Impl>(this: *mut core::ffi::c_void, pdelay: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISessionStateChangeTrigger_Impl::Delay(this, core::mem::transmute_copy(&pdelay)).into()
+            }
+        }
+        unsafe extern "system" fn SetDelay<Identity: windows_core::IUnknownImpl + ISessionStateChangeTrigger_Impl>(this: *mut core::ffi::c_void, delay: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISessionStateChangeTrigger_Impl::SetDelay(this, core::mem::transmute(&delay)).into()
+            }
+        }
+        unsafe extern "system" fn UserId<Identity: windows_core::IUnknownImpl + ISessionStateChangeTrigger_Impl>(this: *mut core::ffi::c_void, puser: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISessionStateChangeTrigger_Impl::UserId(this, core::mem::transmute_copy(&puser)).into()
+            }
+        }
+        unsafe extern "system" fn SetUserId<Identity: windows_core::IUnknownImpl + ISessionStateChangeTrigger_Impl>(this: *mut core::ffi::c_void, user: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISessionStateChangeTrigger_Impl::SetUserId(this, core::mem::transmute(&user)).into()
+            }
+        }
+        unsafe extern "system" fn StateChange<Identity: windows_core::IUnknownImpl + ISessionStateChangeTrigger_Impl>(this: *mut core::ffi::c_void, ptype: *mut TASK_SESSION_STATE_CHANGE_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISessionStateChangeTrigger_Impl::StateChange(this, core::mem::transmute_copy(&ptype)).into()
+            }
+        }
+        unsafe extern "system" fn SetStateChange<Identity: windows_core::IUnknownImpl + ISessionStateChangeTrigger_Impl>(this: *mut core::ffi::c_void, r#type: TASK_SESSION_STATE_CHANGE_TYPE) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ISessionStateChangeTrigger_Impl::SetStateChange(this, core::mem::transmute_copy(&r#type)).into()
+            }
+        }
+        Self {
+            base__: <ITrigger_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Delay: Delay::<Identity>,
+            SetDelay: SetDelay::<Identity>,
+            UserId: UserId::<Identity>,
+            SetUserId: SetUserId::<Identity>,
+            StateChange: StateChange::<Identity>,
+            SetStateChange: SetStateChange::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ISessionStateChangeTrigger {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IShowMessageAction, IShowMessageAction_Vtbl, 0x505e9e68_af89_46b8_a30f_56162a83d537);
@@ -4073,6 +6129,45 @@ impl IShowMessageAction_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IShowMessageAction_Impl> windows_core::imp::TearOffVtable<Identity> for IShowMessageAction_Vtbl
+where
+    IAction_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Title<Identity: windows_core::IUnknownImpl + IShowMessageAction_Impl>(this: *mut core::ffi::c_void, ptitle: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IShowMessageAction_Impl::Title(this, core::mem::transmute_copy(&ptitle)).into()
+            }
+        }
+        unsafe extern "system" fn SetTitle<Identity: windows_core::IUnknownImpl + IShowMessageAction_Impl>(this: *mut core::ffi::c_void, title: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IShowMessageAction_Impl::SetTitle(this, core::mem::transmute(&title)).into()
+            }
+        }
+        unsafe extern "system" fn MessageBody<Identity: windows_core::IUnknownImpl + IShowMessageAction_Impl>(this: *mut core::ffi::c_void, pmessagebody: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IShowMessageAction_Impl::MessageBody(this, core::mem::transmute_copy(&pmessagebody)).into()
+            }
+        }
+        unsafe extern "system" fn SetMessageBody<Identity: windows_core::IUnknownImpl + IShowMessageAction_Impl>(this: *mut core::ffi::c_void, messagebody: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IShowMessageAction_Impl::SetMessageBody(this, core::mem::transmute(&messagebody)).into()
+            }
+        }
+        Self {
+            base__: <IAction_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Title: Title::<Identity>,
+            SetTitle: SetTitle::<Identity>,
+            MessageBody: MessageBody::<Identity>,
+            SetMessageBody: SetMessageBody::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IShowMessageAction {}
 windows_core::imp::define_interface!(ITask, ITask_Vtbl, 0x148bd524_a2ab_11ce_b11f_00aa00530503);
 impl core::ops::Deref for ITask {
@@ -4308,6 +6403,136 @@ impl ITask_Vtbl {
         iid == &<ITask as windows_core::Interface>::IID || iid == &<IScheduledWorkItem as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITask_Impl> windows_core::imp::TearOffVtable<Identity> for ITask_Vtbl
+where
+    IScheduledWorkItem_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetApplicationName<Identity: windows_core::IUnknownImpl + ITask_Impl>(this: *mut core::ffi::c_void, pwszapplicationname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITask_Impl::SetApplicationName(this, core::mem::transmute(&pwszapplicationname)).into()
+            }
+        }
+        unsafe extern "system" fn GetApplicationName<Identity: windows_core::IUnknownImpl + ITask_Impl>(this: *mut core::ffi::c_void, ppwszapplicationname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITask_Impl::GetApplicationName(this) {
+                    Ok(ok__) => {
+                        ppwszapplicationname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetParameters<Identity: windows_core::IUnknownImpl + ITask_Impl>(this: *mut core::ffi::c_void, pwszparameters: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITask_Impl::SetParameters(this, core::mem::transmute(&pwszparameters)).into()
+            }
+        }
+        unsafe extern "system" fn GetParameters<Identity: windows_core::IUnknownImpl + ITask_Impl>(this: *mut core::ffi::c_void, ppwszparameters: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITask_Impl::GetParameters(this) {
+                    Ok(ok__) => {
+                        ppwszparameters.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetWorkingDirectory<Identity: windows_core::IUnknownImpl + ITask_Impl>(this: *mut core::ffi::c_void, pwszworkingdirectory: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITask_Impl::SetWorkingDirectory(this, core::mem::transmute(&pwszworkingdirectory)).into()
+            }
+        }
+        unsafe extern "system" fn GetWorkingDirectory<Identity: windows_core::IUnknownImpl + ITask_Impl>(this: *mut core::ffi::c_void, ppwszworkingdirectory: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITask_Impl::GetWorkingDirectory(this) {
+                    Ok(ok__) => {
+                        ppwszworkingdirectory.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPriority<Identity: windows_core::IUnknownImpl + ITask_Impl>(this: *mut core::ffi::c_void, dwpriority: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITask_Impl::SetPriority(this, core::mem::transmute_copy(&dwpriority)).into()
+            }
+        }
+        unsafe extern "system" fn GetPriority<Identity: windows_core::IUnknownImpl + ITask_Impl>(this: *mut core::ffi::c_void, pdwpriority: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITask_Impl::GetPriority(this) {
+                    Ok(ok__) => {
+                        pdwpriority.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTaskFlags<Identity: windows_core::IUnknownImpl + ITask_Impl>(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITask_Impl::SetTaskFlags(this, core::mem::transmute_copy(&dwflags)).into()
+            }
+        }
+        unsafe extern "system" fn GetTaskFlags<Identity: windows_core::IUnknownImpl + ITask_Impl>(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITask_Impl::GetTaskFlags(this) {
+                    Ok(ok__) => {
+                        pdwflags.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMaxRunTime<Identity: windows_core::IUnknownImpl + ITask_Impl>(this: *mut core::ffi::c_void, dwmaxruntimems: u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITask_Impl::SetMaxRunTime(this, core::mem::transmute_copy(&dwmaxruntimems)).into()
+            }
+        }
+        unsafe extern "system" fn GetMaxRunTime<Identity: windows_core::IUnknownImpl + ITask_Impl>(this: *mut core::ffi::c_void, pdwmaxruntimems: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITask_Impl::GetMaxRunTime(this) {
+                    Ok(ok__) => {
+                        pdwmaxruntimems.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <IScheduledWorkItem_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetApplicationName: SetApplicationName::<Identity>,
+            GetApplicationName: GetApplicationName::<Identity>,
+            SetParameters: SetParameters::<Identity>,
+            GetParameters: GetParameters::<Identity>,
+            SetWorkingDirectory: SetWorkingDirectory::<Identity>,
+            GetWorkingDirectory: GetWorkingDirectory::<Identity>,
+            SetPriority: SetPriority::<Identity>,
+            GetPriority: GetPriority::<Identity>,
+            SetTaskFlags: SetTaskFlags::<Identity>,
+            GetTaskFlags: GetTaskFlags::<Identity>,
+            SetMaxRunTime: SetMaxRunTime::<Identity>,
+            GetMaxRunTime: GetMaxRunTime::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITask {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ITaskDefinition, ITaskDefinition_Vtbl, 0xf5bc8fc5_536d_4f77_b852_fbc1356fdeb6);
@@ -4433,11 +6658,153 @@ pub trait ITaskDefinition_Impl: super::Com::IDispatch_Impl {
     fn SetXmlText(&self, xml: &windows_core::BSTR) -> windows_core::Result<()>;
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl ITaskDefinition_Vtbl {
-    pub const fn new<Identity: ITaskDefinition_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn RegistrationInfo<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppregistrationinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+impl ITaskDefinition_Vtbl {
+    pub const fn new<Identity: ITaskDefinition_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn RegistrationInfo<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppregistrationinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITaskDefinition_Impl::RegistrationInfo(this) {
+                    Ok(ok__) => {
+                        ppregistrationinfo.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRegistrationInfo<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pregistrationinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITaskDefinition_Impl::SetRegistrationInfo(this, core::mem::transmute_copy(&pregistrationinfo)).into()
+            }
+        }
+        unsafe extern "system" fn Triggers<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pptriggers: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITaskDefinition_Impl::Triggers(this) {
+                    Ok(ok__) => {
+                        pptriggers.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetTriggers<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ptriggers: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITaskDefinition_Impl::SetTriggers(this, core::mem::transmute_copy(&ptriggers)).into()
+            }
+        }
+        unsafe extern "system" fn Settings<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppsettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITaskDefinition_Impl::Settings(this) {
+                    Ok(ok__) => {
+                        ppsettings.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSettings<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, psettings: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITaskDefinition_Impl::SetSettings(this, core::mem::transmute_copy(&psettings)).into()
+            }
+        }
+        unsafe extern "system" fn Data<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITaskDefinition_Impl::Data(this, core::mem::transmute_copy(&pdata)).into()
+            }
+        }
+        unsafe extern "system" fn SetData<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITaskDefinition_Impl::SetData(this, core::mem::transmute(&data)).into()
+            }
+        }
+        unsafe extern "system" fn Principal<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppprincipal: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITaskDefinition_Impl::Principal(this) {
+                    Ok(ok__) => {
+                        ppprincipal.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetPrincipal<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pprincipal: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITaskDefinition_Impl::SetPrincipal(this, core::mem::transmute_copy(&pprincipal)).into()
+            }
+        }
+        unsafe extern "system" fn Actions<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppactions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITaskDefinition_Impl::Actions(this) {
+                    Ok(ok__) => {
+                        ppactions.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetActions<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pactions: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITaskDefinition_Impl::SetActions(this, core::mem::transmute_copy(&pactions)).into()
+            }
+        }
+        unsafe extern "system" fn XmlText<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pxml: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITaskDefinition_Impl::XmlText(this, core::mem::transmute_copy(&pxml)).into()
+            }
+        }
+        unsafe extern "system" fn SetXmlText<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, xml: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITaskDefinition_Impl::SetXmlText(this, core::mem::transmute(&xml)).into()
+            }
+        }
+        Self {
+            base__: super::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
+            RegistrationInfo: RegistrationInfo::<Identity, OFFSET>,
+            SetRegistrationInfo: SetRegistrationInfo::<Identity, OFFSET>,
+            Triggers: Triggers::<Identity, OFFSET>,
+            SetTriggers: SetTriggers::<Identity, OFFSET>,
+            Settings: Settings::<Identity, OFFSET>,
+            SetSettings: SetSettings::<Identity, OFFSET>,
+            Data: Data::<Identity, OFFSET>,
+            SetData: SetData::<Identity, OFFSET>,
+            Principal: Principal::<Identity, OFFSET>,
+            SetPrincipal: SetPrincipal::<Identity, OFFSET>,
+            Actions: Actions::<Identity, OFFSET>,
+            SetActions: SetActions::<Identity, OFFSET>,
+            XmlText: XmlText::<Identity, OFFSET>,
+            SetXmlText: SetXmlText::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITaskDefinition as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ITaskDefinition_Impl> windows_core::imp::TearOffVtable<Identity> for ITaskDefinition_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RegistrationInfo<Identity: windows_core::IUnknownImpl + ITaskDefinition_Impl>(this: *mut core::ffi::c_void, ppregistrationinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITaskDefinition_Impl::RegistrationInfo(this) {
                     Ok(ok__) => {
                         ppregistrationinfo.write(core::mem::transmute(ok__));
@@ -4447,15 +6814,15 @@ impl ITaskDefinition_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetRegistrationInfo<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pregistrationinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetRegistrationInfo<Identity: windows_core::IUnknownImpl + ITaskDefinition_Impl>(this: *mut core::ffi::c_void, pregistrationinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITaskDefinition_Impl::SetRegistrationInfo(this, core::mem::transmute_copy(&pregistrationinfo)).into()
             }
         }
-        unsafe extern "system" fn Triggers<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pptriggers: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Triggers<Identity: windows_core::IUnknownImpl + ITaskDefinition_Impl>(this: *mut core::ffi::c_void, pptriggers: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITaskDefinition_Impl::Triggers(this) {
                     Ok(ok__) => {
                         pptriggers.write(core::mem::transmute(ok__));
@@ -4465,15 +6832,15 @@ impl ITaskDefinition_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetTriggers<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ptriggers: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetTriggers<Identity: windows_core::IUnknownImpl + ITaskDefinition_Impl>(this: *mut core::ffi::c_void, ptriggers: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITaskDefinition_Impl::SetTriggers(this, core::mem::transmute_copy(&ptriggers)).into()
             }
         }
-        unsafe extern "system" fn Settings<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppsettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Settings<Identity: windows_core::IUnknownImpl + ITaskDefinition_Impl>(this: *mut core::ffi::c_void, ppsettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITaskDefinition_Impl::Settings(this) {
                     Ok(ok__) => {
                         ppsettings.write(core::mem::transmute(ok__));
@@ -4483,27 +6850,27 @@ impl ITaskDefinition_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetSettings<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, psettings: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetSettings<Identity: windows_core::IUnknownImpl + ITaskDefinition_Impl>(this: *mut core::ffi::c_void, psettings: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITaskDefinition_Impl::SetSettings(this, core::mem::transmute_copy(&psettings)).into()
             }
         }
-        unsafe extern "system" fn Data<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Data<Identity: windows_core::IUnknownImpl + ITaskDefinition_Impl>(this: *mut core::ffi::c_void, pdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITaskDefinition_Impl::Data(this, core::mem::transmute_copy(&pdata)).into()
             }
         }
-        unsafe extern "system" fn SetData<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetData<Identity: windows_core::IUnknownImpl + ITaskDefinition_Impl>(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITaskDefinition_Impl::SetData(this, core::mem::transmute(&data)).into()
             }
         }
-        unsafe extern "system" fn Principal<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppprincipal: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Principal<Identity: windows_core::IUnknownImpl + ITaskDefinition_Impl>(this: *mut core::ffi::c_void, ppprincipal: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITaskDefinition_Impl::Principal(this) {
                     Ok(ok__) => {
                         ppprincipal.write(core::mem::transmute(ok__));
@@ -4513,15 +6880,15 @@ impl ITaskDefinition_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetPrincipal<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pprincipal: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetPrincipal<Identity: windows_core::IUnknownImpl + ITaskDefinition_Impl>(this: *mut core::ffi::c_void, pprincipal: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITaskDefinition_Impl::SetPrincipal(this, core::mem::transmute_copy(&pprincipal)).into()
             }
         }
-        unsafe extern "system" fn Actions<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, ppactions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Actions<Identity: windows_core::IUnknownImpl + ITaskDefinition_Impl>(this: *mut core::ffi::c_void, ppactions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITaskDefinition_Impl::Actions(this) {
                     Ok(ok__) => {
                         ppactions.write(core::mem::transmute(ok__));
@@ -4531,45 +6898,42 @@ impl ITaskDefinition_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn SetActions<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pactions: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetActions<Identity: windows_core::IUnknownImpl + ITaskDefinition_Impl>(this: *mut core::ffi::c_void, pactions: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITaskDefinition_Impl::SetActions(this, core::mem::transmute_copy(&pactions)).into()
             }
         }
-        unsafe extern "system" fn XmlText<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pxml: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn XmlText<Identity: windows_core::IUnknownImpl + ITaskDefinition_Impl>(this: *mut core::ffi::c_void, pxml: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITaskDefinition_Impl::XmlText(this, core::mem::transmute_copy(&pxml)).into()
             }
         }
-        unsafe extern "system" fn SetXmlText<Identity: ITaskDefinition_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, xml: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetXmlText<Identity: windows_core::IUnknownImpl + ITaskDefinition_Impl>(this: *mut core::ffi::c_void, xml: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITaskDefinition_Impl::SetXmlText(this, core::mem::transmute(&xml)).into()
             }
         }
         Self {
-            base__: super::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
-            RegistrationInfo: RegistrationInfo::<Identity, OFFSET>,
-            SetRegistrationInfo: SetRegistrationInfo::<Identity, OFFSET>,
-            Triggers: Triggers::<Identity, OFFSET>,
-            SetTriggers: SetTriggers::<Identity, OFFSET>,
-            Settings: Settings::<Identity, OFFSET>,
-            SetSettings: SetSettings::<Identity, OFFSET>,
-            Data: Data::<Identity, OFFSET>,
-            SetData: SetData::<Identity, OFFSET>,
-            Principal: Principal::<Identity, OFFSET>,
-            SetPrincipal: SetPrincipal::<Identity, OFFSET>,
-            Actions: Actions::<Identity, OFFSET>,
-            SetActions: SetActions::<Identity, OFFSET>,
-            XmlText: XmlText::<Identity, OFFSET>,
-            SetXmlText: SetXmlText::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ITaskDefinition as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID
-    }
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RegistrationInfo: RegistrationInfo::<Identity>,
+            SetRegistrationInfo: SetRegistrationInfo::<Identity>,
+            Triggers: Triggers::<Identity>,
+            SetTriggers: SetTriggers::<Identity>,
+            Settings: Settings::<Identity>,
+            SetSettings: SetSettings::<Identity>,
+            Data: Data::<Identity>,
+            SetData: SetData::<Identity>,
+            Principal: Principal::<Identity>,
+            SetPrincipal: SetPrincipal::<Identity>,
+            Actions: Actions::<Identity>,
+            SetActions: SetActions::<Identity>,
+            XmlText: XmlText::<Identity>,
+            SetXmlText: SetXmlText::<Identity>,
+        }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ITaskDefinition {}
@@ -4869,6 +7233,168 @@ impl ITaskFolder_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ITaskFolder_Impl> windows_core::imp::TearOffVtable<Identity> for ITaskFolder_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + ITaskFolder_Impl>(this: *mut core::ffi::c_void, pname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskFolder_Impl::Name(this) {
+                    Ok(ok__) => {
+                        pname.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Path<Identity: windows_core::IUnknownImpl + ITaskFolder_Impl>(this: *mut core::ffi::c_void, ppath: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskFolder_Impl::Path(this) {
+                    Ok(ok__) => {
+                        ppath.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFolder<Identity: windows_core::IUnknownImpl + ITaskFolder_Impl>(this: *mut core::ffi::c_void, path: *mut core::ffi::c_void, ppfolder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskFolder_Impl::GetFolder(this, core::mem::transmute(&path)) {
+                    Ok(ok__) => {
+                        ppfolder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetFolders<Identity: windows_core::IUnknownImpl + ITaskFolder_Impl>(this: *mut core::ffi::c_void, flags: i32, ppfolders: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskFolder_Impl::GetFolders(this, core::mem::transmute_copy(&flags)) {
+                    Ok(ok__) => {
+                        ppfolders.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn CreateFolder<Identity: windows_core::IUnknownImpl + ITaskFolder_Impl>(this: *mut core::ffi::c_void, subfoldername: *mut core::ffi::c_void, sddl: super::Variant::VARIANT, ppfolder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskFolder_Impl::CreateFolder(this, core::mem::transmute(&subfoldername), core::mem::transmute(&sddl)) {
+                    Ok(ok__) => {
+                        ppfolder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeleteFolder<Identity: windows_core::IUnknownImpl + ITaskFolder_Impl>(this: *mut core::ffi::c_void, subfoldername: *mut core::ffi::c_void, flags: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskFolder_Impl::DeleteFolder(this, core::mem::transmute(&subfoldername), core::mem::transmute_copy(&flags)).into()
+            }
+        }
+        unsafe extern "system" fn GetTask<Identity: windows_core::IUnknownImpl + ITaskFolder_Impl>(this: *mut core::ffi::c_void, path: *mut core::ffi::c_void, pptask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskFolder_Impl::GetTask(this, core::mem::transmute(&path)) {
+                    Ok(ok__) => {
+                        pptask.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetTasks<Identity: windows_core::IUnknownImpl + ITaskFolder_Impl>(this: *mut core::ffi::c_void, flags: i32, pptasks: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskFolder_Impl::GetTasks(this, core::mem::transmute_copy(&flags)) {
+                    Ok(ok__) => {
+                        pptasks.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn DeleteTask<Identity: windows_core::IUnknownImpl + ITaskFolder_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, flags: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskFolder_Impl::DeleteTask(this, core::mem::transmute(&name), core::mem::transmute_copy(&flags)).into()
+            }
+        }
+        unsafe extern "system" fn RegisterTask<Identity: windows_core::IUnknownImpl + ITaskFolder_Impl>(this: *mut core::ffi::c_void, path: *mut core::ffi::c_void, xmltext: *mut core::ffi::c_void, flags: i32, userid: super::Variant::VARIANT, password: super::Variant::VARIANT, logontype: TASK_LOGON_TYPE, sddl: super::Variant::VARIANT, pptask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskFolder_Impl::RegisterTask(this, core::mem::transmute(&path), core::mem::transmute(&xmltext), core::mem::transmute_copy(&flags), core::mem::transmute(&userid), core::mem::transmute(&password), core::mem::transmute_copy(&logontype), core::mem::transmute(&sddl)) {
+                    Ok(ok__) => {
+                        pptask.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn RegisterTaskDefinition<Identity: windows_core::IUnknownImpl + ITaskFolder_Impl>(this: *mut core::ffi::c_void, path: *mut core::ffi::c_void, pdefinition: *mut core::ffi::c_void, flags: i32, userid: super::Variant::VARIANT, password: super::Variant::VARIANT, logontype: TASK_LOGON_TYPE, sddl: super::Variant::VARIANT, pptask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskFolder_Impl::RegisterTaskDefinition(this, core::mem::transmute(&path), core::mem::transmute_copy(&pdefinition), core::mem::transmute_copy(&flags), core::mem::transmute(&userid), core::mem::transmute(&password), core::mem::transmute_copy(&logontype), core::mem::transmute(&sddl)) {
+                    Ok(ok__) => {
+                        pptask.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetSecurityDescriptor<Identity: windows_core::IUnknownImpl + ITaskFolder_Impl>(this: *mut core::ffi::c_void, securityinformation: i32, psddl: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskFolder_Impl::GetSecurityDescriptor(this, core::mem::transmute_copy(&securityinformation)) {
+                    Ok(ok__) => {
+                        psddl.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetSecurityDescriptor<Identity: windows_core::IUnknownImpl + ITaskFolder_Impl>(this: *mut core::ffi::c_void, sddl: *mut core::ffi::c_void, flags: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskFolder_Impl::SetSecurityDescriptor(this, core::mem::transmute(&sddl), core::mem::transmute_copy(&flags)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Name: Name::<Identity>,
+            Path: Path::<Identity>,
+            GetFolder: GetFolder::<Identity>,
+            GetFolders: GetFolders::<Identity>,
+            CreateFolder: CreateFolder::<Identity>,
+            DeleteFolder: DeleteFolder::<Identity>,
+            GetTask: GetTask::<Identity>,
+            GetTasks: GetTasks::<Identity>,
+            DeleteTask: DeleteTask::<Identity>,
+            RegisterTask: RegisterTask::<Identity>,
+            RegisterTaskDefinition: RegisterTaskDefinition::<Identity>,
+            GetSecurityDescriptor: GetSecurityDescriptor::<Identity>,
+            SetSecurityDescriptor: SetSecurityDescriptor::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ITaskFolder {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ITaskFolderCollection, ITaskFolderCollection_Vtbl, 0x79184a66_8664_423f_97f1_637356a5d812);
@@ -4972,6 +7498,56 @@ impl ITaskFolderCollection_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ITaskFolderCollection_Impl> windows_core::imp::TearOffVtable<Identity> for ITaskFolderCollection_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + ITaskFolderCollection_Impl>(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskFolderCollection_Impl::Count(this) {
+                    Ok(ok__) => {
+                        pcount.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn get_Item<Identity: windows_core::IUnknownImpl + ITaskFolderCollection_Impl>(this: *mut core::ffi::c_void, index: super::Variant::VARIANT, ppfolder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskFolderCollection_Impl::get_Item(this, core::mem::transmute(&index)) {
+                    Ok(ok__) => {
+                        ppfolder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + ITaskFolderCollection_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskFolderCollection_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Count: Count::<Identity>,
+            get_Item: get_Item::<Identity>,
+            _NewEnum: _NewEnum::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ITaskFolderCollection {}
 windows_core::imp::define_interface!(ITaskHandler, ITaskHandler_Vtbl, 0x839d7762_5121_4009_9234_4f0d19394f04);
 windows_core::imp::interface_hierarchy!(ITaskHandler, windows_core::IUnknown);
@@ -5020,7 +7596,54 @@ impl ITaskHandler_Vtbl {
         }
         unsafe extern "system" fn Stop<Identity: ITaskHandler_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pretcode: *mut windows_core::HRESULT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITaskHandler_Impl::Stop(this) {
+                    Ok(ok__) => {
+                        pretcode.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Pause<Identity: ITaskHandler_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITaskHandler_Impl::Pause(this).into()
+            }
+        }
+        unsafe extern "system" fn Resume<Identity: ITaskHandler_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITaskHandler_Impl::Resume(this).into()
+            }
+        }
+        Self {
+            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
+            Start: Start::<Identity, OFFSET>,
+            Stop: Stop::<Identity, OFFSET>,
+            Pause: Pause::<Identity, OFFSET>,
+            Resume: Resume::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITaskHandler as windows_core::Interface>::IID
+    }
+}
+impl<Identity: windows_core::IUnknownImpl + ITaskHandler_Impl> windows_core::imp::TearOffVtable<Identity> for ITaskHandler_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Start<Identity: windows_core::IUnknownImpl + ITaskHandler_Impl>(this: *mut core::ffi::c_void, phandlerservices: *mut core::ffi::c_void, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskHandler_Impl::Start(this, core::mem::transmute_copy(&phandlerservices), core::mem::transmute(&data)).into()
+            }
+        }
+        unsafe extern "system" fn Stop<Identity: windows_core::IUnknownImpl + ITaskHandler_Impl>(this: *mut core::ffi::c_void, pretcode: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITaskHandler_Impl::Stop(this) {
                     Ok(ok__) => {
                         pretcode.write(core::mem::transmute(ok__));
@@ -5030,29 +7653,26 @@ impl ITaskHandler_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Pause<Identity: ITaskHandler_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Pause<Identity: windows_core::IUnknownImpl + ITaskHandler_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITaskHandler_Impl::Pause(this).into()
             }
         }
-        unsafe extern "system" fn Resume<Identity: ITaskHandler_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn Resume<Identity: windows_core::IUnknownImpl + ITaskHandler_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITaskHandler_Impl::Resume(this).into()
             }
         }
         Self {
-            base__: windows_core::IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            Start: Start::<Identity, OFFSET>,
-            Stop: Stop::<Identity, OFFSET>,
-            Pause: Pause::<Identity, OFFSET>,
-            Resume: Resume::<Identity, OFFSET>,
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Start: Start::<Identity>,
+            Stop: Stop::<Identity>,
+            Pause: Pause::<Identity>,
+            Resume: Resume::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ITaskHandler as windows_core::Interface>::IID
-    }
+    };
 }
 impl windows_core::RuntimeName for ITaskHandler {}
 windows_core::imp::define_interface!(ITaskHandlerStatus, ITaskHandlerStatus_Vtbl, 0xeaec7a8f_27a0_4ddc_8675_14726a01a38a);
@@ -5100,6 +7720,30 @@ impl ITaskHandlerStatus_Vtbl {
         iid == &<ITaskHandlerStatus as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITaskHandlerStatus_Impl> windows_core::imp::TearOffVtable<Identity> for ITaskHandlerStatus_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn UpdateStatus<Identity: windows_core::IUnknownImpl + ITaskHandlerStatus_Impl>(this: *mut core::ffi::c_void, percentcomplete: i16, statusmessage: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskHandlerStatus_Impl::UpdateStatus(this, core::mem::transmute_copy(&percentcomplete), core::mem::transmute(&statusmessage)).into()
+            }
+        }
+        unsafe extern "system" fn TaskCompleted<Identity: windows_core::IUnknownImpl + ITaskHandlerStatus_Impl>(this: *mut core::ffi::c_void, taskerrcode: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskHandlerStatus_Impl::TaskCompleted(this, core::mem::transmute_copy(&taskerrcode)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            UpdateStatus: UpdateStatus::<Identity>,
+            TaskCompleted: TaskCompleted::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITaskHandlerStatus {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ITaskNamedValueCollection, ITaskNamedValueCollection_Vtbl, 0xb4ef826b_63c3_46e4_a504_ef69e4f7ea4d);
@@ -5235,6 +7879,77 @@ impl ITaskNamedValueCollection_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ITaskNamedValueCollection_Impl> windows_core::imp::TearOffVtable<Identity> for ITaskNamedValueCollection_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + ITaskNamedValueCollection_Impl>(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskNamedValueCollection_Impl::Count(this, core::mem::transmute_copy(&pcount)).into()
+            }
+        }
+        unsafe extern "system" fn get_Item<Identity: windows_core::IUnknownImpl + ITaskNamedValueCollection_Impl>(this: *mut core::ffi::c_void, index: i32, pppair: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskNamedValueCollection_Impl::get_Item(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        pppair.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + ITaskNamedValueCollection_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskNamedValueCollection_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Create<Identity: windows_core::IUnknownImpl + ITaskNamedValueCollection_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, value: *mut core::ffi::c_void, pppair: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskNamedValueCollection_Impl::Create(this, core::mem::transmute(&name), core::mem::transmute(&value)) {
+                    Ok(ok__) => {
+                        pppair.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Remove<Identity: windows_core::IUnknownImpl + ITaskNamedValueCollection_Impl>(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskNamedValueCollection_Impl::Remove(this, core::mem::transmute_copy(&index)).into()
+            }
+        }
+        unsafe extern "system" fn Clear<Identity: windows_core::IUnknownImpl + ITaskNamedValueCollection_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskNamedValueCollection_Impl::Clear(this).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Count: Count::<Identity>,
+            get_Item: get_Item::<Identity>,
+            _NewEnum: _NewEnum::<Identity>,
+            Create: Create::<Identity>,
+            Remove: Remove::<Identity>,
+            Clear: Clear::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ITaskNamedValueCollection {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ITaskNamedValuePair, ITaskNamedValuePair_Vtbl, 0x39038068_2b46_4afd_8662_7bb6f868d221);
@@ -5319,6 +8034,45 @@ impl ITaskNamedValuePair_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ITaskNamedValuePair_Impl> windows_core::imp::TearOffVtable<Identity> for ITaskNamedValuePair_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Name<Identity: windows_core::IUnknownImpl + ITaskNamedValuePair_Impl>(this: *mut core::ffi::c_void, pname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskNamedValuePair_Impl::Name(this, core::mem::transmute_copy(&pname)).into()
+            }
+        }
+        unsafe extern "system" fn SetName<Identity: windows_core::IUnknownImpl + ITaskNamedValuePair_Impl>(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskNamedValuePair_Impl::SetName(this, core::mem::transmute(&name)).into()
+            }
+        }
+        unsafe extern "system" fn Value<Identity: windows_core::IUnknownImpl + ITaskNamedValuePair_Impl>(this: *mut core::ffi::c_void, pvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskNamedValuePair_Impl::Value(this, core::mem::transmute_copy(&pvalue)).into()
+            }
+        }
+        unsafe extern "system" fn SetValue<Identity: windows_core::IUnknownImpl + ITaskNamedValuePair_Impl>(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskNamedValuePair_Impl::SetValue(this, core::mem::transmute(&value)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Name: Name::<Identity>,
+            SetName: SetName::<Identity>,
+            Value: Value::<Identity>,
+            SetValue: SetValue::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ITaskNamedValuePair {}
 windows_core::imp::define_interface!(ITaskScheduler, ITaskScheduler_Vtbl, 0x148bd527_a2ab_11ce_b11f_00aa00530503);
 windows_core::imp::interface_hierarchy!(ITaskScheduler, windows_core::IUnknown);
@@ -5492,6 +8246,96 @@ impl ITaskScheduler_Vtbl {
         iid == &<ITaskScheduler as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITaskScheduler_Impl> windows_core::imp::TearOffVtable<Identity> for ITaskScheduler_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetTargetComputer<Identity: windows_core::IUnknownImpl + ITaskScheduler_Impl>(this: *mut core::ffi::c_void, pwszcomputer: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskScheduler_Impl::SetTargetComputer(this, core::mem::transmute(&pwszcomputer)).into()
+            }
+        }
+        unsafe extern "system" fn GetTargetComputer<Identity: windows_core::IUnknownImpl + ITaskScheduler_Impl>(this: *mut core::ffi::c_void, ppwszcomputer: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskScheduler_Impl::GetTargetComputer(this) {
+                    Ok(ok__) => {
+                        ppwszcomputer.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Enum<Identity: windows_core::IUnknownImpl + ITaskScheduler_Impl>(this: *mut core::ffi::c_void, ppenumworkitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskScheduler_Impl::Enum(this) {
+                    Ok(ok__) => {
+                        ppenumworkitems.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Activate<Identity: windows_core::IUnknownImpl + ITaskScheduler_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskScheduler_Impl::Activate(this, core::mem::transmute(&pwszname), core::mem::transmute_copy(&riid)) {
+                    Ok(ok__) => {
+                        ppunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Delete<Identity: windows_core::IUnknownImpl + ITaskScheduler_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskScheduler_Impl::Delete(this, core::mem::transmute(&pwszname)).into()
+            }
+        }
+        unsafe extern "system" fn NewWorkItem<Identity: windows_core::IUnknownImpl + ITaskScheduler_Impl>(this: *mut core::ffi::c_void, pwsztaskname: windows_core::PCWSTR, rclsid: *const windows_core::GUID, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskScheduler_Impl::NewWorkItem(this, core::mem::transmute(&pwsztaskname), core::mem::transmute_copy(&rclsid), core::mem::transmute_copy(&riid)) {
+                    Ok(ok__) => {
+                        ppunk.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn AddWorkItem<Identity: windows_core::IUnknownImpl + ITaskScheduler_Impl>(this: *mut core::ffi::c_void, pwsztaskname: windows_core::PCWSTR, pworkitem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskScheduler_Impl::AddWorkItem(this, core::mem::transmute(&pwsztaskname), core::mem::transmute_copy(&pworkitem)).into()
+            }
+        }
+        unsafe extern "system" fn IsOfType<Identity: windows_core::IUnknownImpl + ITaskScheduler_Impl>(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR, riid: *const windows_core::GUID) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskScheduler_Impl::IsOfType(this, core::mem::transmute(&pwszname), core::mem::transmute_copy(&riid)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetTargetComputer: SetTargetComputer::<Identity>,
+            GetTargetComputer: GetTargetComputer::<Identity>,
+            Enum: Enum::<Identity>,
+            Activate: Activate::<Identity>,
+            Delete: Delete::<Identity>,
+            NewWorkItem: NewWorkItem::<Identity>,
+            AddWorkItem: AddWorkItem::<Identity>,
+            IsOfType: IsOfType::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITaskScheduler {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ITaskService, ITaskService_Vtbl, 0x2faba4c7_4da9_4013_9697_20cc3fd40f85);
@@ -5590,11 +8434,136 @@ pub trait ITaskService_Impl: super::Com::IDispatch_Impl {
     fn HighestVersion(&self) -> windows_core::Result<u32>;
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
-impl ITaskService_Vtbl {
-    pub const fn new<Identity: ITaskService_Impl, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn GetFolder<Identity: ITaskService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, path: *mut core::ffi::c_void, ppfolder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
-            unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+impl ITaskService_Vtbl {
+    pub const fn new<Identity: ITaskService_Impl, const OFFSET: isize>() -> Self {
+        unsafe extern "system" fn GetFolder<Identity: ITaskService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, path: *mut core::ffi::c_void, ppfolder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITaskService_Impl::GetFolder(this, core::mem::transmute(&path)) {
+                    Ok(ok__) => {
+                        ppfolder.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetRunningTasks<Identity: ITaskService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flags: i32, pprunningtasks: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITaskService_Impl::GetRunningTasks(this, core::mem::transmute_copy(&flags)) {
+                    Ok(ok__) => {
+                        pprunningtasks.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn NewTask<Identity: ITaskService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flags: u32, ppdefinition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITaskService_Impl::NewTask(this, core::mem::transmute_copy(&flags)) {
+                    Ok(ok__) => {
+                        ppdefinition.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Connect<Identity: ITaskService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, servername: super::Variant::VARIANT, user: super::Variant::VARIANT, domain: super::Variant::VARIANT, password: super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITaskService_Impl::Connect(this, core::mem::transmute(&servername), core::mem::transmute(&user), core::mem::transmute(&domain), core::mem::transmute(&password)).into()
+            }
+        }
+        unsafe extern "system" fn Connected<Identity: ITaskService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pconnected: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITaskService_Impl::Connected(this) {
+                    Ok(ok__) => {
+                        pconnected.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn TargetServer<Identity: ITaskService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pserver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITaskService_Impl::TargetServer(this) {
+                    Ok(ok__) => {
+                        pserver.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ConnectedUser<Identity: ITaskService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, puser: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITaskService_Impl::ConnectedUser(this) {
+                    Ok(ok__) => {
+                        puser.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn ConnectedDomain<Identity: ITaskService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdomain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITaskService_Impl::ConnectedDomain(this) {
+                    Ok(ok__) => {
+                        pdomain.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn HighestVersion<Identity: ITaskService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pversion: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                match ITaskService_Impl::HighestVersion(this) {
+                    Ok(ok__) => {
+                        pversion.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: super::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
+            GetFolder: GetFolder::<Identity, OFFSET>,
+            GetRunningTasks: GetRunningTasks::<Identity, OFFSET>,
+            NewTask: NewTask::<Identity, OFFSET>,
+            Connect: Connect::<Identity, OFFSET>,
+            Connected: Connected::<Identity, OFFSET>,
+            TargetServer: TargetServer::<Identity, OFFSET>,
+            ConnectedUser: ConnectedUser::<Identity, OFFSET>,
+            ConnectedDomain: ConnectedDomain::<Identity, OFFSET>,
+            HighestVersion: HighestVersion::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITaskService as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ITaskService_Impl> windows_core::imp::TearOffVtable<Identity> for ITaskService_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetFolder<Identity: windows_core::IUnknownImpl + ITaskService_Impl>(this: *mut core::ffi::c_void, path: *mut core::ffi::c_void, ppfolder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITaskService_Impl::GetFolder(this, core::mem::transmute(&path)) {
                     Ok(ok__) => {
                         ppfolder.write(core::mem::transmute(ok__));
@@ -5604,9 +8573,9 @@ impl ITaskService_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn GetRunningTasks<Identity: ITaskService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flags: i32, pprunningtasks: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn GetRunningTasks<Identity: windows_core::IUnknownImpl + ITaskService_Impl>(this: *mut core::ffi::c_void, flags: i32, pprunningtasks: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITaskService_Impl::GetRunningTasks(this, core::mem::transmute_copy(&flags)) {
                     Ok(ok__) => {
                         pprunningtasks.write(core::mem::transmute(ok__));
@@ -5616,9 +8585,9 @@ impl ITaskService_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn NewTask<Identity: ITaskService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, flags: u32, ppdefinition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn NewTask<Identity: windows_core::IUnknownImpl + ITaskService_Impl>(this: *mut core::ffi::c_void, flags: u32, ppdefinition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITaskService_Impl::NewTask(this, core::mem::transmute_copy(&flags)) {
                     Ok(ok__) => {
                         ppdefinition.write(core::mem::transmute(ok__));
@@ -5628,15 +8597,15 @@ impl ITaskService_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn Connect<Identity: ITaskService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, servername: super::Variant::VARIANT, user: super::Variant::VARIANT, domain: super::Variant::VARIANT, password: super::Variant::VARIANT) -> windows_core::HRESULT {
+        unsafe extern "system" fn Connect<Identity: windows_core::IUnknownImpl + ITaskService_Impl>(this: *mut core::ffi::c_void, servername: super::Variant::VARIANT, user: super::Variant::VARIANT, domain: super::Variant::VARIANT, password: super::Variant::VARIANT) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITaskService_Impl::Connect(this, core::mem::transmute(&servername), core::mem::transmute(&user), core::mem::transmute(&domain), core::mem::transmute(&password)).into()
             }
         }
-        unsafe extern "system" fn Connected<Identity: ITaskService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pconnected: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn Connected<Identity: windows_core::IUnknownImpl + ITaskService_Impl>(this: *mut core::ffi::c_void, pconnected: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITaskService_Impl::Connected(this) {
                     Ok(ok__) => {
                         pconnected.write(core::mem::transmute(ok__));
@@ -5646,9 +8615,9 @@ impl ITaskService_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn TargetServer<Identity: ITaskService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pserver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn TargetServer<Identity: windows_core::IUnknownImpl + ITaskService_Impl>(this: *mut core::ffi::c_void, pserver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITaskService_Impl::TargetServer(this) {
                     Ok(ok__) => {
                         pserver.write(core::mem::transmute(ok__));
@@ -5658,9 +8627,9 @@ impl ITaskService_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ConnectedUser<Identity: ITaskService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, puser: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn ConnectedUser<Identity: windows_core::IUnknownImpl + ITaskService_Impl>(this: *mut core::ffi::c_void, puser: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITaskService_Impl::ConnectedUser(this) {
                     Ok(ok__) => {
                         puser.write(core::mem::transmute(ok__));
@@ -5670,9 +8639,9 @@ impl ITaskService_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn ConnectedDomain<Identity: ITaskService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pdomain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+        unsafe extern "system" fn ConnectedDomain<Identity: windows_core::IUnknownImpl + ITaskService_Impl>(this: *mut core::ffi::c_void, pdomain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITaskService_Impl::ConnectedDomain(this) {
                     Ok(ok__) => {
                         pdomain.write(core::mem::transmute(ok__));
@@ -5682,9 +8651,9 @@ impl ITaskService_Vtbl {
                 }
             }
         }
-        unsafe extern "system" fn HighestVersion<Identity: ITaskService_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, pversion: *mut u32) -> windows_core::HRESULT {
+        unsafe extern "system" fn HighestVersion<Identity: windows_core::IUnknownImpl + ITaskService_Impl>(this: *mut core::ffi::c_void, pversion: *mut u32) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 match ITaskService_Impl::HighestVersion(this) {
                     Ok(ok__) => {
                         pversion.write(core::mem::transmute(ok__));
@@ -5695,21 +8664,18 @@ impl ITaskService_Vtbl {
             }
         }
         Self {
-            base__: super::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
-            GetFolder: GetFolder::<Identity, OFFSET>,
-            GetRunningTasks: GetRunningTasks::<Identity, OFFSET>,
-            NewTask: NewTask::<Identity, OFFSET>,
-            Connect: Connect::<Identity, OFFSET>,
-            Connected: Connected::<Identity, OFFSET>,
-            TargetServer: TargetServer::<Identity, OFFSET>,
-            ConnectedUser: ConnectedUser::<Identity, OFFSET>,
-            ConnectedDomain: ConnectedDomain::<Identity, OFFSET>,
-            HighestVersion: HighestVersion::<Identity, OFFSET>,
-        }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ITaskService as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID
-    }
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetFolder: GetFolder::<Identity>,
+            GetRunningTasks: GetRunningTasks::<Identity>,
+            NewTask: NewTask::<Identity>,
+            Connect: Connect::<Identity>,
+            Connected: Connected::<Identity>,
+            TargetServer: TargetServer::<Identity>,
+            ConnectedUser: ConnectedUser::<Identity>,
+            ConnectedDomain: ConnectedDomain::<Identity>,
+            HighestVersion: HighestVersion::<Identity>,
+        }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ITaskService {}
@@ -6252,6 +9218,309 @@ impl ITaskSettings_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl> windows_core::imp::TearOffVtable<Identity> for ITaskSettings_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn AllowDemandStart<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, pallowdemandstart: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::AllowDemandStart(this, core::mem::transmute_copy(&pallowdemandstart)).into()
+            }
+        }
+        unsafe extern "system" fn SetAllowDemandStart<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, allowdemandstart: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::SetAllowDemandStart(this, core::mem::transmute_copy(&allowdemandstart)).into()
+            }
+        }
+        unsafe extern "system" fn RestartInterval<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, prestartinterval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::RestartInterval(this, core::mem::transmute_copy(&prestartinterval)).into()
+            }
+        }
+        unsafe extern "system" fn SetRestartInterval<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, restartinterval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::SetRestartInterval(this, core::mem::transmute(&restartinterval)).into()
+            }
+        }
+        unsafe extern "system" fn RestartCount<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, prestartcount: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::RestartCount(this, core::mem::transmute_copy(&prestartcount)).into()
+            }
+        }
+        unsafe extern "system" fn SetRestartCount<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, restartcount: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::SetRestartCount(this, core::mem::transmute_copy(&restartcount)).into()
+            }
+        }
+        unsafe extern "system" fn MultipleInstances<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, ppolicy: *mut TASK_INSTANCES_POLICY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::MultipleInstances(this, core::mem::transmute_copy(&ppolicy)).into()
+            }
+        }
+        unsafe extern "system" fn SetMultipleInstances<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, policy: TASK_INSTANCES_POLICY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::SetMultipleInstances(this, core::mem::transmute_copy(&policy)).into()
+            }
+        }
+        unsafe extern "system" fn StopIfGoingOnBatteries<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, pstopifonbatteries: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::StopIfGoingOnBatteries(this, core::mem::transmute_copy(&pstopifonbatteries)).into()
+            }
+        }
+        unsafe extern "system" fn SetStopIfGoingOnBatteries<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, stopifonbatteries: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::SetStopIfGoingOnBatteries(this, core::mem::transmute_copy(&stopifonbatteries)).into()
+            }
+        }
+        unsafe extern "system" fn DisallowStartIfOnBatteries<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, pdisallowstart: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::DisallowStartIfOnBatteries(this, core::mem::transmute_copy(&pdisallowstart)).into()
+            }
+        }
+        unsafe extern "system" fn SetDisallowStartIfOnBatteries<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, disallowstart: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::SetDisallowStartIfOnBatteries(this, core::mem::transmute_copy(&disallowstart)).into()
+            }
+        }
+        unsafe extern "system" fn AllowHardTerminate<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, pallowhardterminate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::AllowHardTerminate(this, core::mem::transmute_copy(&pallowhardterminate)).into()
+            }
+        }
+        unsafe extern "system" fn SetAllowHardTerminate<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, allowhardterminate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::SetAllowHardTerminate(this, core::mem::transmute_copy(&allowhardterminate)).into()
+            }
+        }
+        unsafe extern "system" fn StartWhenAvailable<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, pstartwhenavailable: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::StartWhenAvailable(this, core::mem::transmute_copy(&pstartwhenavailable)).into()
+            }
+        }
+        unsafe extern "system" fn SetStartWhenAvailable<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, startwhenavailable: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::SetStartWhenAvailable(this, core::mem::transmute_copy(&startwhenavailable)).into()
+            }
+        }
+        unsafe extern "system" fn XmlText<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, ptext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::XmlText(this, core::mem::transmute_copy(&ptext)).into()
+            }
+        }
+        unsafe extern "system" fn SetXmlText<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, text: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::SetXmlText(this, core::mem::transmute(&text)).into()
+            }
+        }
+        unsafe extern "system" fn RunOnlyIfNetworkAvailable<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, prunonlyifnetworkavailable: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::RunOnlyIfNetworkAvailable(this, core::mem::transmute_copy(&prunonlyifnetworkavailable)).into()
+            }
+        }
+        unsafe extern "system" fn SetRunOnlyIfNetworkAvailable<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, runonlyifnetworkavailable: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::SetRunOnlyIfNetworkAvailable(this, core::mem::transmute_copy(&runonlyifnetworkavailable)).into()
+            }
+        }
+        unsafe extern "system" fn ExecutionTimeLimit<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, pexecutiontimelimit: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::ExecutionTimeLimit(this, core::mem::transmute_copy(&pexecutiontimelimit)).into()
+            }
+        }
+        unsafe extern "system" fn SetExecutionTimeLimit<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, executiontimelimit: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::SetExecutionTimeLimit(this, core::mem::transmute(&executiontimelimit)).into()
+            }
+        }
+        unsafe extern "system" fn Enabled<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, penabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::Enabled(this, core::mem::transmute_copy(&penabled)).into()
+            }
+        }
+        unsafe extern "system" fn SetEnabled<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, enabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::SetEnabled(this, core::mem::transmute_copy(&enabled)).into()
+            }
+        }
+        unsafe extern "system" fn DeleteExpiredTaskAfter<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, pexpirationdelay: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::DeleteExpiredTaskAfter(this, core::mem::transmute_copy(&pexpirationdelay)).into()
+            }
+        }
+        unsafe extern "system" fn SetDeleteExpiredTaskAfter<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, expirationdelay: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::SetDeleteExpiredTaskAfter(this, core::mem::transmute(&expirationdelay)).into()
+            }
+        }
+        unsafe extern "system" fn Priority<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, ppriority: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::Priority(this, core::mem::transmute_copy(&ppriority)).into()
+            }
+        }
+        unsafe extern "system" fn SetPriority<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, priority: i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::SetPriority(this, core::mem::transmute_copy(&priority)).into()
+            }
+        }
+        unsafe extern "system" fn Compatibility<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, pcompatlevel: *mut TASK_COMPATIBILITY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::Compatibility(this, core::mem::transmute_copy(&pcompatlevel)).into()
+            }
+        }
+        unsafe extern "system" fn SetCompatibility<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, compatlevel: TASK_COMPATIBILITY) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::SetCompatibility(this, core::mem::transmute_copy(&compatlevel)).into()
+            }
+        }
+        unsafe extern "system" fn Hidden<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, phidden: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::Hidden(this, core::mem::transmute_copy(&phidden)).into()
+            }
+        }
+        unsafe extern "system" fn SetHidden<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, hidden: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::SetHidden(this, core::mem::transmute_copy(&hidden)).into()
+            }
+        }
+        unsafe extern "system" fn IdleSettings<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, ppidlesettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskSettings_Impl::IdleSettings(this) {
+                    Ok(ok__) => {
+                        ppidlesettings.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetIdleSettings<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, pidlesettings: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::SetIdleSettings(this, core::mem::transmute_copy(&pidlesettings)).into()
+            }
+        }
+        unsafe extern "system" fn RunOnlyIfIdle<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, prunonlyifidle: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::RunOnlyIfIdle(this, core::mem::transmute_copy(&prunonlyifidle)).into()
+            }
+        }
+        unsafe extern "system" fn SetRunOnlyIfIdle<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, runonlyifidle: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::SetRunOnlyIfIdle(this, core::mem::transmute_copy(&runonlyifidle)).into()
+            }
+        }
+        unsafe extern "system" fn WakeToRun<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, pwake: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::WakeToRun(this, core::mem::transmute_copy(&pwake)).into()
+            }
+        }
+        unsafe extern "system" fn SetWakeToRun<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, wake: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::SetWakeToRun(this, core::mem::transmute_copy(&wake)).into()
+            }
+        }
+        unsafe extern "system" fn NetworkSettings<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, ppnetworksettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskSettings_Impl::NetworkSettings(this) {
+                    Ok(ok__) => {
+                        ppnetworksettings.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetNetworkSettings<Identity: windows_core::IUnknownImpl + ITaskSettings_Impl>(this: *mut core::ffi::c_void, pnetworksettings: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings_Impl::SetNetworkSettings(this, core::mem::transmute_copy(&pnetworksettings)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            AllowDemandStart: AllowDemandStart::<Identity>,
+            SetAllowDemandStart: SetAllowDemandStart::<Identity>,
+            RestartInterval: RestartInterval::<Identity>,
+            SetRestartInterval: SetRestartInterval::<Identity>,
+            RestartCount: RestartCount::<Identity>,
+            SetRestartCount: SetRestartCount::<Identity>,
+            MultipleInstances: MultipleInstances::<Identity>,
+            SetMultipleInstances: SetMultipleInstances::<Identity>,
+            StopIfGoingOnBatteries: StopIfGoingOnBatteries::<Identity>,
+            SetStopIfGoingOnBatteries: SetStopIfGoingOnBatteries::<Identity>,
+            DisallowStartIfOnBatteries: DisallowStartIfOnBatteries::<Identity>,
+            SetDisallowStartIfOnBatteries: SetDisallowStartIfOnBatteries::<Identity>,
+            AllowHardTerminate: AllowHardTerminate::<Identity>,
+            SetAllowHardTerminate: SetAllowHardTerminate::<Identity>,
+            StartWhenAvailable: StartWhenAvailable::<Identity>,
+            SetStartWhenAvailable: SetStartWhenAvailable::<Identity>,
+            XmlText: XmlText::<Identity>,
+            SetXmlText: SetXmlText::<Identity>,
+            RunOnlyIfNetworkAvailable: RunOnlyIfNetworkAvailable::<Identity>,
+            SetRunOnlyIfNetworkAvailable: SetRunOnlyIfNetworkAvailable::<Identity>,
+            ExecutionTimeLimit: ExecutionTimeLimit::<Identity>,
+            SetExecutionTimeLimit: SetExecutionTimeLimit::<Identity>,
+            Enabled: Enabled::<Identity>,
+            SetEnabled: SetEnabled::<Identity>,
+            DeleteExpiredTaskAfter: DeleteExpiredTaskAfter::<Identity>,
+            SetDeleteExpiredTaskAfter: SetDeleteExpiredTaskAfter::<Identity>,
+            Priority: Priority::<Identity>,
+            SetPriority: SetPriority::<Identity>,
+            Compatibility: Compatibility::<Identity>,
+            SetCompatibility: SetCompatibility::<Identity>,
+            Hidden: Hidden::<Identity>,
+            SetHidden: SetHidden::<Identity>,
+            IdleSettings: IdleSettings::<Identity>,
+            SetIdleSettings: SetIdleSettings::<Identity>,
+            RunOnlyIfIdle: RunOnlyIfIdle::<Identity>,
+            SetRunOnlyIfIdle: SetRunOnlyIfIdle::<Identity>,
+            WakeToRun: WakeToRun::<Identity>,
+            SetWakeToRun: SetWakeToRun::<Identity>,
+            NetworkSettings: NetworkSettings::<Identity>,
+            SetNetworkSettings: SetNetworkSettings::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ITaskSettings {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ITaskSettings2, ITaskSettings2_Vtbl, 0x2c05c3f0_6eed_4c05_a15f_ed7d7a98a369);
@@ -6305,35 +9574,74 @@ impl ITaskSettings2_Vtbl {
                 ITaskSettings2_Impl::DisallowStartOnRemoteAppSession(this, core::mem::transmute_copy(&pdisallowstart)).into()
             }
         }
-        unsafe extern "system" fn SetDisallowStartOnRemoteAppSession<Identity: ITaskSettings2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, disallowstart: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetDisallowStartOnRemoteAppSession<Identity: ITaskSettings2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, disallowstart: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITaskSettings2_Impl::SetDisallowStartOnRemoteAppSession(this, core::mem::transmute_copy(&disallowstart)).into()
+            }
+        }
+        unsafe extern "system" fn UseUnifiedSchedulingEngine<Identity: ITaskSettings2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, puseunifiedengine: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITaskSettings2_Impl::UseUnifiedSchedulingEngine(this, core::mem::transmute_copy(&puseunifiedengine)).into()
+            }
+        }
+        unsafe extern "system" fn SetUseUnifiedSchedulingEngine<Identity: ITaskSettings2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, useunifiedengine: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                ITaskSettings2_Impl::SetUseUnifiedSchedulingEngine(this, core::mem::transmute_copy(&useunifiedengine)).into()
+            }
+        }
+        Self {
+            base__: super::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
+            DisallowStartOnRemoteAppSession: DisallowStartOnRemoteAppSession::<Identity, OFFSET>,
+            SetDisallowStartOnRemoteAppSession: SetDisallowStartOnRemoteAppSession::<Identity, OFFSET>,
+            UseUnifiedSchedulingEngine: UseUnifiedSchedulingEngine::<Identity, OFFSET>,
+            SetUseUnifiedSchedulingEngine: SetUseUnifiedSchedulingEngine::<Identity, OFFSET>,
+        }
+    }
+    pub fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITaskSettings2 as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ITaskSettings2_Impl> windows_core::imp::TearOffVtable<Identity> for ITaskSettings2_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DisallowStartOnRemoteAppSession<Identity: windows_core::IUnknownImpl + ITaskSettings2_Impl>(this: *mut core::ffi::c_void, pdisallowstart: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings2_Impl::DisallowStartOnRemoteAppSession(this, core::mem::transmute_copy(&pdisallowstart)).into()
+            }
+        }
+        unsafe extern "system" fn SetDisallowStartOnRemoteAppSession<Identity: windows_core::IUnknownImpl + ITaskSettings2_Impl>(this: *mut core::ffi::c_void, disallowstart: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITaskSettings2_Impl::SetDisallowStartOnRemoteAppSession(this, core::mem::transmute_copy(&disallowstart)).into()
             }
         }
-        unsafe extern "system" fn UseUnifiedSchedulingEngine<Identity: ITaskSettings2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, puseunifiedengine: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn UseUnifiedSchedulingEngine<Identity: windows_core::IUnknownImpl + ITaskSettings2_Impl>(this: *mut core::ffi::c_void, puseunifiedengine: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITaskSettings2_Impl::UseUnifiedSchedulingEngine(this, core::mem::transmute_copy(&puseunifiedengine)).into()
             }
         }
-        unsafe extern "system" fn SetUseUnifiedSchedulingEngine<Identity: ITaskSettings2_Impl, const OFFSET: isize>(this: *mut core::ffi::c_void, useunifiedengine: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+        unsafe extern "system" fn SetUseUnifiedSchedulingEngine<Identity: windows_core::IUnknownImpl + ITaskSettings2_Impl>(this: *mut core::ffi::c_void, useunifiedengine: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
             unsafe {
-                let this: &Identity = &*((this as *const *const ()).offset(OFFSET) as *const Identity);
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
                 ITaskSettings2_Impl::SetUseUnifiedSchedulingEngine(this, core::mem::transmute_copy(&useunifiedengine)).into()
             }
         }
         Self {
-            base__: super::Com::IDispatch_Vtbl::new::<Identity, OFFSET>(),
-            DisallowStartOnRemoteAppSession: DisallowStartOnRemoteAppSession::<Identity, OFFSET>,
-            SetDisallowStartOnRemoteAppSession: SetDisallowStartOnRemoteAppSession::<Identity, OFFSET>,
-            UseUnifiedSchedulingEngine: UseUnifiedSchedulingEngine::<Identity, OFFSET>,
-            SetUseUnifiedSchedulingEngine: SetUseUnifiedSchedulingEngine::<Identity, OFFSET>,
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DisallowStartOnRemoteAppSession: DisallowStartOnRemoteAppSession::<Identity>,
+            SetDisallowStartOnRemoteAppSession: SetDisallowStartOnRemoteAppSession::<Identity>,
+            UseUnifiedSchedulingEngine: UseUnifiedSchedulingEngine::<Identity>,
+            SetUseUnifiedSchedulingEngine: SetUseUnifiedSchedulingEngine::<Identity>,
         }
-    }
-    pub fn matches(iid: &windows_core::GUID) -> bool {
-        iid == &<ITaskSettings2 as windows_core::Interface>::IID || iid == &<super::Com::IDispatch as windows_core::Interface>::IID
-    }
+    };
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ITaskSettings2 {}
@@ -6501,6 +9809,92 @@ impl ITaskSettings3_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ITaskSettings3_Impl> windows_core::imp::TearOffVtable<Identity> for ITaskSettings3_Vtbl
+where
+    ITaskSettings_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DisallowStartOnRemoteAppSession<Identity: windows_core::IUnknownImpl + ITaskSettings3_Impl>(this: *mut core::ffi::c_void, pdisallowstart: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings3_Impl::DisallowStartOnRemoteAppSession(this, core::mem::transmute_copy(&pdisallowstart)).into()
+            }
+        }
+        unsafe extern "system" fn SetDisallowStartOnRemoteAppSession<Identity: windows_core::IUnknownImpl + ITaskSettings3_Impl>(this: *mut core::ffi::c_void, disallowstart: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings3_Impl::SetDisallowStartOnRemoteAppSession(this, core::mem::transmute_copy(&disallowstart)).into()
+            }
+        }
+        unsafe extern "system" fn UseUnifiedSchedulingEngine<Identity: windows_core::IUnknownImpl + ITaskSettings3_Impl>(this: *mut core::ffi::c_void, puseunifiedengine: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings3_Impl::UseUnifiedSchedulingEngine(this, core::mem::transmute_copy(&puseunifiedengine)).into()
+            }
+        }
+        unsafe extern "system" fn SetUseUnifiedSchedulingEngine<Identity: windows_core::IUnknownImpl + ITaskSettings3_Impl>(this: *mut core::ffi::c_void, useunifiedengine: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings3_Impl::SetUseUnifiedSchedulingEngine(this, core::mem::transmute_copy(&useunifiedengine)).into()
+            }
+        }
+        unsafe extern "system" fn MaintenanceSettings<Identity: windows_core::IUnknownImpl + ITaskSettings3_Impl>(this: *mut core::ffi::c_void, ppmaintenancesettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskSettings3_Impl::MaintenanceSettings(this) {
+                    Ok(ok__) => {
+                        ppmaintenancesettings.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetMaintenanceSettings<Identity: windows_core::IUnknownImpl + ITaskSettings3_Impl>(this: *mut core::ffi::c_void, pmaintenancesettings: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings3_Impl::SetMaintenanceSettings(this, core::mem::transmute_copy(&pmaintenancesettings)).into()
+            }
+        }
+        unsafe extern "system" fn CreateMaintenanceSettings<Identity: windows_core::IUnknownImpl + ITaskSettings3_Impl>(this: *mut core::ffi::c_void, ppmaintenancesettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskSettings3_Impl::CreateMaintenanceSettings(this) {
+                    Ok(ok__) => {
+                        ppmaintenancesettings.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Volatile<Identity: windows_core::IUnknownImpl + ITaskSettings3_Impl>(this: *mut core::ffi::c_void, pvolatile: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings3_Impl::Volatile(this, core::mem::transmute_copy(&pvolatile)).into()
+            }
+        }
+        unsafe extern "system" fn SetVolatile<Identity: windows_core::IUnknownImpl + ITaskSettings3_Impl>(this: *mut core::ffi::c_void, volatile: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskSettings3_Impl::SetVolatile(this, core::mem::transmute_copy(&volatile)).into()
+            }
+        }
+        Self {
+            base__: <ITaskSettings_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DisallowStartOnRemoteAppSession: DisallowStartOnRemoteAppSession::<Identity>,
+            SetDisallowStartOnRemoteAppSession: SetDisallowStartOnRemoteAppSession::<Identity>,
+            UseUnifiedSchedulingEngine: UseUnifiedSchedulingEngine::<Identity>,
+            SetUseUnifiedSchedulingEngine: SetUseUnifiedSchedulingEngine::<Identity>,
+            MaintenanceSettings: MaintenanceSettings::<Identity>,
+            SetMaintenanceSettings: SetMaintenanceSettings::<Identity>,
+            CreateMaintenanceSettings: CreateMaintenanceSettings::<Identity>,
+            Volatile: Volatile::<Identity>,
+            SetVolatile: SetVolatile::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ITaskSettings3 {}
 windows_core::imp::define_interface!(ITaskTrigger, ITaskTrigger_Vtbl, 0x148bd52b_a2ab_11ce_b11f_00aa00530503);
 windows_core::imp::interface_hierarchy!(ITaskTrigger, windows_core::IUnknown);
@@ -6568,6 +9962,43 @@ impl ITaskTrigger_Vtbl {
         iid == &<ITaskTrigger as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITaskTrigger_Impl> windows_core::imp::TearOffVtable<Identity> for ITaskTrigger_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn SetTrigger<Identity: windows_core::IUnknownImpl + ITaskTrigger_Impl>(this: *mut core::ffi::c_void, ptrigger: *const TASK_TRIGGER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskTrigger_Impl::SetTrigger(this, core::mem::transmute_copy(&ptrigger)).into()
+            }
+        }
+        unsafe extern "system" fn GetTrigger<Identity: windows_core::IUnknownImpl + ITaskTrigger_Impl>(this: *mut core::ffi::c_void, ptrigger: *mut TASK_TRIGGER) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskTrigger_Impl::GetTrigger(this, core::mem::transmute_copy(&ptrigger)).into()
+            }
+        }
+        unsafe extern "system" fn GetTriggerString<Identity: windows_core::IUnknownImpl + ITaskTrigger_Impl>(this: *mut core::ffi::c_void, ppwsztrigger: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskTrigger_Impl::GetTriggerString(this) {
+                    Ok(ok__) => {
+                        ppwsztrigger.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            SetTrigger: SetTrigger::<Identity>,
+            GetTrigger: GetTrigger::<Identity>,
+            GetTriggerString: GetTriggerString::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITaskTrigger {}
 windows_core::imp::define_interface!(ITaskVariables, ITaskVariables_Vtbl, 0x3e4c9351_d966_4b8b_bb87_ceba68bb0107);
 windows_core::imp::interface_hierarchy!(ITaskVariables, windows_core::IUnknown);
@@ -6644,6 +10075,49 @@ impl ITaskVariables_Vtbl {
         iid == &<ITaskVariables as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + ITaskVariables_Impl> windows_core::imp::TearOffVtable<Identity> for ITaskVariables_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetInput<Identity: windows_core::IUnknownImpl + ITaskVariables_Impl>(this: *mut core::ffi::c_void, pinput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskVariables_Impl::GetInput(this) {
+                    Ok(ok__) => {
+                        pinput.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetOutput<Identity: windows_core::IUnknownImpl + ITaskVariables_Impl>(this: *mut core::ffi::c_void, input: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITaskVariables_Impl::SetOutput(this, core::mem::transmute(&input)).into()
+            }
+        }
+        unsafe extern "system" fn GetContext<Identity: windows_core::IUnknownImpl + ITaskVariables_Impl>(this: *mut core::ffi::c_void, pcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITaskVariables_Impl::GetContext(this) {
+                    Ok(ok__) => {
+                        pcontext.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetInput: GetInput::<Identity>,
+            SetOutput: SetOutput::<Identity>,
+            GetContext: GetContext::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for ITaskVariables {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ITimeTrigger, ITimeTrigger_Vtbl, 0xb45747e0_eba7_4276_9f29_85c5bb300006);
@@ -6704,6 +10178,31 @@ impl ITimeTrigger_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ITimeTrigger_Impl> windows_core::imp::TearOffVtable<Identity> for ITimeTrigger_Vtbl
+where
+    ITrigger_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn RandomDelay<Identity: windows_core::IUnknownImpl + ITimeTrigger_Impl>(this: *mut core::ffi::c_void, prandomdelay: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITimeTrigger_Impl::RandomDelay(this, core::mem::transmute_copy(&prandomdelay)).into()
+            }
+        }
+        unsafe extern "system" fn SetRandomDelay<Identity: windows_core::IUnknownImpl + ITimeTrigger_Impl>(this: *mut core::ffi::c_void, randomdelay: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITimeTrigger_Impl::SetRandomDelay(this, core::mem::transmute(&randomdelay)).into()
+            }
+        }
+        Self {
+            base__: <ITrigger_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            RandomDelay: RandomDelay::<Identity>,
+            SetRandomDelay: SetRandomDelay::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ITimeTrigger {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ITrigger, ITrigger_Vtbl, 0x09941815_ea89_4b5b_89e0_2a773801fac3);
@@ -6908,6 +10407,114 @@ impl ITrigger_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ITrigger_Impl> windows_core::imp::TearOffVtable<Identity> for ITrigger_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Type<Identity: windows_core::IUnknownImpl + ITrigger_Impl>(this: *mut core::ffi::c_void, ptype: *mut TASK_TRIGGER_TYPE2) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITrigger_Impl::Type(this, core::mem::transmute_copy(&ptype)).into()
+            }
+        }
+        unsafe extern "system" fn Id<Identity: windows_core::IUnknownImpl + ITrigger_Impl>(this: *mut core::ffi::c_void, pid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITrigger_Impl::Id(this, core::mem::transmute_copy(&pid)).into()
+            }
+        }
+        unsafe extern "system" fn SetId<Identity: windows_core::IUnknownImpl + ITrigger_Impl>(this: *mut core::ffi::c_void, id: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITrigger_Impl::SetId(this, core::mem::transmute(&id)).into()
+            }
+        }
+        unsafe extern "system" fn Repetition<Identity: windows_core::IUnknownImpl + ITrigger_Impl>(this: *mut core::ffi::c_void, pprepeat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITrigger_Impl::Repetition(this) {
+                    Ok(ok__) => {
+                        pprepeat.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn SetRepetition<Identity: windows_core::IUnknownImpl + ITrigger_Impl>(this: *mut core::ffi::c_void, prepeat: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITrigger_Impl::SetRepetition(this, core::mem::transmute_copy(&prepeat)).into()
+            }
+        }
+        unsafe extern "system" fn ExecutionTimeLimit<Identity: windows_core::IUnknownImpl + ITrigger_Impl>(this: *mut core::ffi::c_void, ptimelimit: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITrigger_Impl::ExecutionTimeLimit(this, core::mem::transmute_copy(&ptimelimit)).into()
+            }
+        }
+        unsafe extern "system" fn SetExecutionTimeLimit<Identity: windows_core::IUnknownImpl + ITrigger_Impl>(this: *mut core::ffi::c_void, timelimit: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITrigger_Impl::SetExecutionTimeLimit(this, core::mem::transmute(&timelimit)).into()
+            }
+        }
+        unsafe extern "system" fn StartBoundary<Identity: windows_core::IUnknownImpl + ITrigger_Impl>(this: *mut core::ffi::c_void, pstart: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITrigger_Impl::StartBoundary(this, core::mem::transmute_copy(&pstart)).into()
+            }
+        }
+        unsafe extern "system" fn SetStartBoundary<Identity: windows_core::IUnknownImpl + ITrigger_Impl>(this: *mut core::ffi::c_void, start: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITrigger_Impl::SetStartBoundary(this, core::mem::transmute(&start)).into()
+            }
+        }
+        unsafe extern "system" fn EndBoundary<Identity: windows_core::IUnknownImpl + ITrigger_Impl>(this: *mut core::ffi::c_void, pend: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITrigger_Impl::EndBoundary(this, core::mem::transmute_copy(&pend)).into()
+            }
+        }
+        unsafe extern "system" fn SetEndBoundary<Identity: windows_core::IUnknownImpl + ITrigger_Impl>(this: *mut core::ffi::c_void, end: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITrigger_Impl::SetEndBoundary(this, core::mem::transmute(&end)).into()
+            }
+        }
+        unsafe extern "system" fn Enabled<Identity: windows_core::IUnknownImpl + ITrigger_Impl>(this: *mut core::ffi::c_void, penabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITrigger_Impl::Enabled(this, core::mem::transmute_copy(&penabled)).into()
+            }
+        }
+        unsafe extern "system" fn SetEnabled<Identity: windows_core::IUnknownImpl + ITrigger_Impl>(this: *mut core::ffi::c_void, enabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITrigger_Impl::SetEnabled(this, core::mem::transmute_copy(&enabled)).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Type: Type::<Identity>,
+            Id: Id::<Identity>,
+            SetId: SetId::<Identity>,
+            Repetition: Repetition::<Identity>,
+            SetRepetition: SetRepetition::<Identity>,
+            ExecutionTimeLimit: ExecutionTimeLimit::<Identity>,
+            SetExecutionTimeLimit: SetExecutionTimeLimit::<Identity>,
+            StartBoundary: StartBoundary::<Identity>,
+            SetStartBoundary: SetStartBoundary::<Identity>,
+            EndBoundary: EndBoundary::<Identity>,
+            SetEndBoundary: SetEndBoundary::<Identity>,
+            Enabled: Enabled::<Identity>,
+            SetEnabled: SetEnabled::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ITrigger {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(ITriggerCollection, ITriggerCollection_Vtbl, 0x85df5081_1b24_4f32_878a_d9d14df4cb77);
@@ -7047,6 +10654,77 @@ impl ITriggerCollection_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + ITriggerCollection_Impl> windows_core::imp::TearOffVtable<Identity> for ITriggerCollection_Vtbl
+where
+    super::Com::IDispatch_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn Count<Identity: windows_core::IUnknownImpl + ITriggerCollection_Impl>(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITriggerCollection_Impl::Count(this, core::mem::transmute_copy(&pcount)).into()
+            }
+        }
+        unsafe extern "system" fn get_Item<Identity: windows_core::IUnknownImpl + ITriggerCollection_Impl>(this: *mut core::ffi::c_void, index: i32, pptrigger: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITriggerCollection_Impl::get_Item(this, core::mem::transmute_copy(&index)) {
+                    Ok(ok__) => {
+                        pptrigger.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn _NewEnum<Identity: windows_core::IUnknownImpl + ITriggerCollection_Impl>(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITriggerCollection_Impl::_NewEnum(this) {
+                    Ok(ok__) => {
+                        ppenum.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Create<Identity: windows_core::IUnknownImpl + ITriggerCollection_Impl>(this: *mut core::ffi::c_void, r#type: TASK_TRIGGER_TYPE2, pptrigger: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match ITriggerCollection_Impl::Create(this, core::mem::transmute_copy(&r#type)) {
+                    Ok(ok__) => {
+                        pptrigger.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn Remove<Identity: windows_core::IUnknownImpl + ITriggerCollection_Impl>(this: *mut core::ffi::c_void, index: super::Variant::VARIANT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITriggerCollection_Impl::Remove(this, core::mem::transmute(&index)).into()
+            }
+        }
+        unsafe extern "system" fn Clear<Identity: windows_core::IUnknownImpl + ITriggerCollection_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                ITriggerCollection_Impl::Clear(this).into()
+            }
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            Count: Count::<Identity>,
+            get_Item: get_Item::<Identity>,
+            _NewEnum: _NewEnum::<Identity>,
+            Create: Create::<Identity>,
+            Remove: Remove::<Identity>,
+            Clear: Clear::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for ITriggerCollection {}
 #[cfg(feature = "Win32_System_Com")]
 windows_core::imp::define_interface!(IWeeklyTrigger, IWeeklyTrigger_Vtbl, 0x5038fc98_82ff_436d_8728_a512a57c9dc1);
@@ -7155,6 +10833,59 @@ impl IWeeklyTrigger_Vtbl {
     }
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl<Identity: windows_core::IUnknownImpl + IWeeklyTrigger_Impl> windows_core::imp::TearOffVtable<Identity> for IWeeklyTrigger_Vtbl
+where
+    ITrigger_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn DaysOfWeek<Identity: windows_core::IUnknownImpl + IWeeklyTrigger_Impl>(this: *mut core::ffi::c_void, pdays: *mut i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWeeklyTrigger_Impl::DaysOfWeek(this, core::mem::transmute_copy(&pdays)).into()
+            }
+        }
+        unsafe extern "system" fn SetDaysOfWeek<Identity: windows_core::IUnknownImpl + IWeeklyTrigger_Impl>(this: *mut core::ffi::c_void, days: i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWeeklyTrigger_Impl::SetDaysOfWeek(this, core::mem::transmute_copy(&days)).into()
+            }
+        }
+        unsafe extern "system" fn WeeksInterval<Identity: windows_core::IUnknownImpl + IWeeklyTrigger_Impl>(this: *mut core::ffi::c_void, pweeks: *mut i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWeeklyTrigger_Impl::WeeksInterval(this, core::mem::transmute_copy(&pweeks)).into()
+            }
+        }
+        unsafe extern "system" fn SetWeeksInterval<Identity: windows_core::IUnknownImpl + IWeeklyTrigger_Impl>(this: *mut core::ffi::c_void, weeks: i16) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWeeklyTrigger_Impl::SetWeeksInterval(this, core::mem::transmute_copy(&weeks)).into()
+            }
+        }
+        unsafe extern "system" fn RandomDelay<Identity: windows_core::IUnknownImpl + IWeeklyTrigger_Impl>(this: *mut core::ffi::c_void, prandomdelay: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWeeklyTrigger_Impl::RandomDelay(this, core::mem::transmute_copy(&prandomdelay)).into()
+            }
+        }
+        unsafe extern "system" fn SetRandomDelay<Identity: windows_core::IUnknownImpl + IWeeklyTrigger_Impl>(this: *mut core::ffi::c_void, randomdelay: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IWeeklyTrigger_Impl::SetRandomDelay(this, core::mem::transmute(&randomdelay)).into()
+            }
+        }
+        Self {
+            base__: <ITrigger_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            DaysOfWeek: DaysOfWeek::<Identity>,
+            SetDaysOfWeek: SetDaysOfWeek::<Identity>,
+            WeeksInterval: WeeksInterval::<Identity>,
+            SetWeeksInterval: SetWeeksInterval::<Identity>,
+            RandomDelay: RandomDelay::<Identity>,
+            SetRandomDelay: SetRandomDelay::<Identity>,
+        }
+    };
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
 impl windows_core::RuntimeName for IWeeklyTrigger {}
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
@@ -2218,6 +2218,30 @@ impl IRtwqAsyncCallback_Vtbl {
         iid == &<IRtwqAsyncCallback as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRtwqAsyncCallback_Impl> windows_core::imp::TearOffVtable<Identity> for IRtwqAsyncCallback_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetParameters<Identity: windows_core::IUnknownImpl + IRtwqAsyncCallback_Impl>(this: *mut core::ffi::c_void, pdwflags: *mut u32, pdwqueue: *mut u32) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRtwqAsyncCallback_Impl::GetParameters(this, core::mem::transmute_copy(&pdwflags), core::mem::transmute_copy(&pdwqueue)).into()
+            }
+        }
+        unsafe extern "system" fn Invoke<Identity: windows_core::IUnknownImpl + IRtwqAsyncCallback_Impl>(this: *mut core::ffi::c_void, pasyncresult: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRtwqAsyncCallback_Impl::Invoke(this, core::mem::transmute_copy(&pasyncresult)).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetParameters: GetParameters::<Identity>,
+            Invoke: Invoke::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRtwqAsyncCallback {}
 windows_core::imp::define_interface!(IRtwqAsyncResult, IRtwqAsyncResult_Vtbl, 0xac6b7889_0740_4d51_8619_905994a55cc6);
 windows_core::imp::interface_hierarchy!(IRtwqAsyncResult, windows_core::IUnknown);
@@ -2318,6 +2342,63 @@ impl IRtwqAsyncResult_Vtbl {
         iid == &<IRtwqAsyncResult as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRtwqAsyncResult_Impl> windows_core::imp::TearOffVtable<Identity> for IRtwqAsyncResult_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn GetState<Identity: windows_core::IUnknownImpl + IRtwqAsyncResult_Impl>(this: *mut core::ffi::c_void, ppunkstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRtwqAsyncResult_Impl::GetState(this) {
+                    Ok(ok__) => {
+                        ppunkstate.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStatus<Identity: windows_core::IUnknownImpl + IRtwqAsyncResult_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRtwqAsyncResult_Impl::GetStatus(this).into()
+            }
+        }
+        unsafe extern "system" fn SetStatus<Identity: windows_core::IUnknownImpl + IRtwqAsyncResult_Impl>(this: *mut core::ffi::c_void, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRtwqAsyncResult_Impl::SetStatus(this, core::mem::transmute_copy(&hrstatus)).into()
+            }
+        }
+        unsafe extern "system" fn GetObject<Identity: windows_core::IUnknownImpl + IRtwqAsyncResult_Impl>(this: *mut core::ffi::c_void, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                match IRtwqAsyncResult_Impl::GetObject(this) {
+                    Ok(ok__) => {
+                        ppobject.write(core::mem::transmute(ok__));
+                        windows_core::HRESULT(0)
+                    }
+                    Err(err) => err.into(),
+                }
+            }
+        }
+        unsafe extern "system" fn GetStateNoAddRef<Identity: windows_core::IUnknownImpl + IRtwqAsyncResult_Impl>(this: *mut core::ffi::c_void) -> Option<windows_core::IUnknown> {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRtwqAsyncResult_Impl::GetStateNoAddRef(this)
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            GetState: GetState::<Identity>,
+            GetStatus: GetStatus::<Identity>,
+            SetStatus: SetStatus::<Identity>,
+            GetObject: GetObject::<Identity>,
+            GetStateNoAddRef: GetStateNoAddRef::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRtwqAsyncResult {}
 windows_core::imp::define_interface!(IRtwqPlatformEvents, IRtwqPlatformEvents_Vtbl, 0x63d9255a_7ff1_4b61_8faf_ed6460dacf2b);
 windows_core::imp::interface_hierarchy!(IRtwqPlatformEvents, windows_core::IUnknown);
@@ -2376,6 +2457,37 @@ impl IRtwqPlatformEvents_Vtbl {
         iid == &<IRtwqPlatformEvents as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + IRtwqPlatformEvents_Impl> windows_core::imp::TearOffVtable<Identity> for IRtwqPlatformEvents_Vtbl
+where
+    windows_core::IUnknown_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = {
+        unsafe extern "system" fn InitializationComplete<Identity: windows_core::IUnknownImpl + IRtwqPlatformEvents_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRtwqPlatformEvents_Impl::InitializationComplete(this).into()
+            }
+        }
+        unsafe extern "system" fn ShutdownStart<Identity: windows_core::IUnknownImpl + IRtwqPlatformEvents_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRtwqPlatformEvents_Impl::ShutdownStart(this).into()
+            }
+        }
+        unsafe extern "system" fn ShutdownComplete<Identity: windows_core::IUnknownImpl + IRtwqPlatformEvents_Impl>(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            unsafe {
+                let this: &Identity = windows_core::imp::InterfaceTearOff::<Identity>::object(this);
+                IRtwqPlatformEvents_Impl::ShutdownComplete(this).into()
+            }
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE,
+            InitializationComplete: InitializationComplete::<Identity>,
+            ShutdownStart: ShutdownStart::<Identity>,
+            ShutdownComplete: ShutdownComplete::<Identity>,
+        }
+    };
+}
 impl windows_core::RuntimeName for IRtwqPlatformEvents {}
 pub const KernelEnabled: MACHINE_ATTRIBUTES = MACHINE_ATTRIBUTES(2i32);
 pub const LOGON_NETCREDENTIALS_ONLY: CREATE_PROCESS_LOGON_FLAGS = CREATE_PROCESS_LOGON_FLAGS(2u32);
@@ -3134,6 +3246,12 @@ impl RTWQASYNCRESULT_Vtbl {
         iid == &<RTWQASYNCRESULT as windows_core::Interface>::IID || iid == &<IRtwqAsyncResult as windows_core::Interface>::IID
     }
 }
+impl<Identity: windows_core::IUnknownImpl + RTWQASYNCRESULT_Impl> windows_core::imp::TearOffVtable<Identity> for RTWQASYNCRESULT_Vtbl
+where
+    IRtwqAsyncResult_Vtbl: windows_core::imp::TearOffVtable<Identity>,
+{
+    const VTABLE: Self = { Self { base__: <IRtwqAsyncResult_Vtbl as windows_core::imp::TearOffVtable<Identity>>::VTABLE } };
+}
 impl windows_core::RuntimeName for RTWQASYNCRESULT {}
 pub type RTWQPERIODICCALLBACK = Option<unsafe extern "system" fn(context: windows_core::Ref<windows_core::IUnknown>)>;
 pub const RTWQ_MULTITHREADED_WORKQUEUE: RTWQ_WORKQUEUE_TYPE = RTWQ_WORKQUEUE_TYPE(2i32);